@@ -27,7 +27,9 @@ fn should_handle_validation_errors_gracefully() {
     request.identity_pool_id = "invalid".to_string();
 
     match client.list_identities(request).sync() {
-        Err(RusotoError::Validation(msg)) => assert!(msg.contains("identityPoolId")),
+        Err(RusotoError::Validation { message: msg, .. }) => {
+            assert!(msg.contains("identityPoolId"))
+        }
         err @ _ => panic!("Expected Validation error - got {:#?}", err),
     };
 }
@@ -18,7 +18,7 @@ fn should_parse_error_type() {
 
     let response = client.list_tables(request).sync();
     match response {
-        Err(RusotoError::Validation(msg)) => {
+        Err(RusotoError::Validation { message: msg, .. }) => {
             // local dynamodb gives a different error, this matches both:
             assert!(msg.contains("greater than or equal to 1"))
         }
@@ -20,7 +20,7 @@ fn should_handle_deprecation_gracefully() {
     let request = DescribeJobFlowsInput::default();
 
     match client.describe_job_flows(request).sync() {
-        Err(RusotoError::Validation(msg)) => {
+        Err(RusotoError::Validation { message: msg, .. }) => {
             assert!(msg.contains("DescribeJobFlows API is deprecated."))
         }
         err @ _ => panic!("Expected OK response, got {:#?}", err),
@@ -0,0 +1,285 @@
+//! Injection of failures in front of another dispatcher, for testing retry and error handling
+//!
+//! A `FaultInjectingDispatcher` answers some requests with a `Fault`, such as a 503 response
+//! or a connection error, according to its `FaultPolicy`, and passes the others on to the
+//! dispatcher it wraps, which may be a `MockRequestDispatcher` or an `HttpClient`. Policies
+//! are deterministic, so a test sees the same failures on every run.
+//!
+//! ```rust
+//! extern crate futures;
+//! extern crate rusoto_core;
+//! extern crate rusoto_mock;
+//!
+//! use futures::Future;
+//! use rusoto_core::signature::SignedRequest;
+//! use rusoto_core::{DispatchSignedRequest, Region};
+//! use rusoto_mock::fault::{Fault, FaultInjectingDispatcher, FaultPolicy};
+//! use rusoto_mock::MockRequestDispatcher;
+//!
+//! // fail the first 2 attempts with 503, then succeed
+//! let dispatcher = FaultInjectingDispatcher::new(
+//!     MockRequestDispatcher::with_status(200),
+//!     FaultPolicy::first(2, Fault::status(503)),
+//! );
+//! let status = |dispatcher: &FaultInjectingDispatcher<_>| {
+//!     let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+//!     dispatcher.dispatch(request, None).wait().unwrap().status.as_u16()
+//! };
+//!
+//! assert_eq!(status(&dispatcher), 503);
+//! assert_eq!(status(&dispatcher), 503);
+//! assert_eq!(status(&dispatcher), 200);
+//! assert_eq!(dispatcher.injected(), 2);
+//! ```
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use futures::future::{err, ok, Either, FutureResult};
+use http::{header::HeaderName, HeaderMap, HttpTryFrom, StatusCode};
+use rusoto_core::request::HttpResponse;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{ByteStream, DispatchSignedRequest, HttpDispatchError};
+
+/// A failure answering a request in place of the wrapped dispatcher.
+#[derive(Clone, Debug)]
+pub enum Fault {
+    /// A response, usually with an error status such as 503 or 429.
+    Response {
+        /// The status of the response.
+        status: StatusCode,
+        /// The headers of the response.
+        headers: HeaderMap<String>,
+        /// The body of the response.
+        body: Vec<u8>,
+    },
+    /// A failure to dispatch the request, such as a connection error or a timeout.
+    DispatchError(HttpDispatchError),
+}
+
+impl Fault {
+    /// A response with the given status and an empty body.
+    pub fn status(status: u16) -> Fault {
+        Fault::Response {
+            status: StatusCode::try_from(status).unwrap(),
+            headers: HeaderMap::default(),
+            body: Vec::new(),
+        }
+    }
+
+    /// A 400 response with the JSON error document of a throttled request to a JSON
+    /// protocol service.
+    pub fn throttling() -> Fault {
+        Fault::status(400)
+            .with_header("content-type", "application/x-amz-json-1.1")
+            .with_body(r#"{"__type":"ThrottlingException","message":"Rate exceeded"}"#)
+    }
+
+    /// A failure to dispatch the request with `error`.
+    pub fn dispatch_error(error: HttpDispatchError) -> Fault {
+        Fault::DispatchError(error)
+    }
+
+    /// Sets the body of a response. Does nothing to a dispatch error.
+    pub fn with_body(mut self, body: &str) -> Fault {
+        if let Fault::Response {
+            body: ref mut b, ..
+        } = self
+        {
+            *b = body.as_bytes().to_vec();
+        }
+        self
+    }
+
+    /// Sets a header of a response, such as `retry-after`. Does nothing to a dispatch error.
+    pub fn with_header(mut self, key: &str, value: &str) -> Fault {
+        if let Fault::Response {
+            ref mut headers, ..
+        } = self
+        {
+            headers.insert(key.parse::<HeaderName>().unwrap(), value.into());
+        }
+        self
+    }
+
+    fn outcome(&self) -> FutureResult<HttpResponse, HttpDispatchError> {
+        match *self {
+            Fault::Response {
+                status,
+                ref headers,
+                ref body,
+            } => ok(HttpResponse {
+                status,
+                body: ByteStream::from(body.clone()),
+                headers: headers.clone(),
+                url: None,
+            }),
+            Fault::DispatchError(ref error) => err(error.clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Schedule {
+    First(usize),
+    Random { probability: f64, seed: u64 },
+    Always,
+}
+
+/// Which requests a `FaultInjectingDispatcher` answers with its fault.
+#[derive(Clone, Debug)]
+pub struct FaultPolicy {
+    fault: Fault,
+    schedule: Schedule,
+    operation: Option<String>,
+}
+
+impl FaultPolicy {
+    /// Injects `fault` into the first `count` requests, and lets the following ones through.
+    pub fn first(count: usize, fault: Fault) -> FaultPolicy {
+        FaultPolicy::new(fault, Schedule::First(count))
+    }
+
+    /// Injects `fault` into each request with the given probability, between 0 and 1.
+    ///
+    /// Requests are picked by a pseudo-random generator started from `seed`, so the same
+    /// seed picks the same requests on every run.
+    ///
+    /// ```rust
+    /// extern crate futures;
+    /// extern crate rusoto_core;
+    /// extern crate rusoto_mock;
+    ///
+    /// use futures::Future;
+    /// use rusoto_core::signature::SignedRequest;
+    /// use rusoto_core::{DispatchSignedRequest, Region};
+    /// use rusoto_mock::fault::{Fault, FaultInjectingDispatcher, FaultPolicy};
+    /// use rusoto_mock::MockRequestDispatcher;
+    ///
+    /// // inject a 429 10% of the time
+    /// let statuses = || {
+    ///     let dispatcher = FaultInjectingDispatcher::new(
+    ///         MockRequestDispatcher::with_status(200),
+    ///         FaultPolicy::random(0.1, 42, Fault::status(429)),
+    ///     );
+    ///     (0..1000)
+    ///         .map(|_| {
+    ///             let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+    ///             dispatcher.dispatch(request, None).wait().unwrap().status.as_u16()
+    ///         })
+    ///         .collect::<Vec<_>>()
+    /// };
+    ///
+    /// let throttled = statuses().iter().filter(|status| **status == 429).count();
+    /// assert!(throttled > 50 && throttled < 150);
+    /// assert_eq!(statuses(), statuses());
+    /// ```
+    pub fn random(probability: f64, seed: u64, fault: Fault) -> FaultPolicy {
+        FaultPolicy::new(fault, Schedule::Random { probability, seed })
+    }
+
+    /// Injects `fault` into every request.
+    pub fn always(fault: Fault) -> FaultPolicy {
+        FaultPolicy::new(fault, Schedule::Always)
+    }
+
+    /// Only injects the fault into requests of the named operation, such as `PutObject`.
+    /// Requests of other operations are let through and aren't counted by the policy.
+    pub fn for_operation(mut self, operation: &str) -> FaultPolicy {
+        self.operation = Some(operation.to_owned());
+        self
+    }
+
+    fn new(fault: Fault, schedule: Schedule) -> FaultPolicy {
+        FaultPolicy {
+            fault,
+            schedule,
+            operation: None,
+        }
+    }
+}
+
+struct FaultState {
+    /// The number of requests the policy applied to.
+    requests: usize,
+    /// The number of requests answered with the fault.
+    injected: usize,
+    /// The state of the xorshift generator of random policies.
+    random: u64,
+}
+
+/// A dispatcher injecting faults into some of the requests it is given, according to its
+/// policy, and passing the others on to the dispatcher it wraps.
+pub struct FaultInjectingDispatcher<D> {
+    inner: D,
+    policy: FaultPolicy,
+    state: Mutex<FaultState>,
+}
+
+impl<D> FaultInjectingDispatcher<D> {
+    /// Wraps `inner`, injecting faults according to `policy`.
+    pub fn new(inner: D, policy: FaultPolicy) -> FaultInjectingDispatcher<D> {
+        let seed = match policy.schedule {
+            Schedule::Random { seed, .. } => seed,
+            _ => 0,
+        };
+        FaultInjectingDispatcher {
+            inner,
+            policy,
+            state: Mutex::new(FaultState {
+                requests: 0,
+                injected: 0,
+                // xorshift would stay stuck at zero
+                random: (seed ^ 0x9e37_79b9_7f4a_7c15).max(1),
+            }),
+        }
+    }
+
+    /// The number of requests the policy applied to, whether or not they got the fault.
+    pub fn requests(&self) -> usize {
+        self.state.lock().unwrap().requests
+    }
+
+    /// The number of requests answered with the fault.
+    pub fn injected(&self) -> usize {
+        self.state.lock().unwrap().injected
+    }
+
+    /// Whether the fault is injected into `request`, counting it.
+    fn injects(&self, request: &SignedRequest) -> bool {
+        if let Some(ref operation) = self.policy.operation {
+            if request.operation() != Some(operation.as_str()) {
+                return false;
+            }
+        }
+        let mut state = self.state.lock().unwrap();
+        state.requests += 1;
+        let inject = match self.policy.schedule {
+            Schedule::First(count) => state.requests <= count,
+            Schedule::Random { probability, .. } => {
+                state.random ^= state.random << 13;
+                state.random ^= state.random >> 7;
+                state.random ^= state.random << 17;
+                // the top 53 bits make a uniform float in [0, 1)
+                ((state.random >> 11) as f64 / (1u64 << 53) as f64) < probability
+            }
+            Schedule::Always => true,
+        };
+        if inject {
+            state.injected += 1;
+        }
+        inject
+    }
+}
+
+impl<D: DispatchSignedRequest> DispatchSignedRequest for FaultInjectingDispatcher<D> {
+    type Future = Either<FutureResult<HttpResponse, HttpDispatchError>, D::Future>;
+
+    fn dispatch(&self, request: SignedRequest, timeout: Option<Duration>) -> Self::Future {
+        if self.injects(&request) {
+            Either::A(self.policy.fault.outcome())
+        } else {
+            Either::B(self.inner.dispatch(request, timeout))
+        }
+    }
+}
@@ -7,7 +7,8 @@
 //! * A `rusoto_core::credential::ProvideAwsCredentials` implementation
 //! * A `rusoto_core::Region`
 //!
-//! This crate provides mock implementations to satisfy the first two.
+//! This crate provides mock implementations to satisfy the first two, and in the
+//! [`fault`](fault/index.html) module a dispatcher injecting failures in front of another.
 //!
 //! # Example
 //!
@@ -39,6 +40,9 @@ extern crate rusoto_core;
 extern crate serde;
 extern crate serde_json;
 
+pub mod fault;
+pub mod snapshot;
+
 use std::fs::File;
 use std::io::Read;
 use std::time::Duration;
@@ -135,9 +139,11 @@ impl MockRequestDispatcher {
     }
 
     /// Mocks a single service header that would be returned from AWS
+    ///
+    /// Mocking a header again adds another value, as when it is repeated in a response.
     pub fn with_header(mut self, key: &str, value: &str) -> MockRequestDispatcher {
         self.headers
-            .insert(key.parse::<HeaderName>().unwrap(), value.into());
+            .append(key.parse::<HeaderName>().unwrap(), value.into());
         self
     }
 }
@@ -154,6 +160,7 @@ impl DispatchSignedRequest for MockRequestDispatcher {
                 status: *status,
                 body: ByteStream::from(self.body.clone()),
                 headers: self.headers.clone(),
+                url: None,
             }),
             RequestOutcome::Failed(ref error) => err(error.clone()),
         }
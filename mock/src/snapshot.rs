@@ -0,0 +1,182 @@
+//! Stable textual representations of requests and responses for snapshot tests
+//!
+//! Headers and query parameters are sorted, values which change with every request such as
+//! signatures, dates and request ids are redacted, and JSON bodies are pretty-printed with
+//! sorted keys, so the same call always produces the same text.
+//!
+//! Responses are taken buffered, as returned by `HttpResponse::buffer`.
+//!
+//! ```rust
+//! extern crate rusoto_core;
+//! extern crate rusoto_mock;
+//!
+//! use rusoto_core::signature::SignedRequest;
+//! use rusoto_core::Region;
+//! use rusoto_mock::snapshot::request_snapshot;
+//!
+//! let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+//! request.add_header("x-amz-target", "DynamoDB_20120810.ListTables");
+//! request.add_header("x-amz-date", "20190101T000000Z");
+//! request.set_payload(Some(r#"{"Limit":10}"#));
+//!
+//! assert_eq!(
+//!     request_snapshot(&request),
+//!     "POST https://dynamodb.us-east-1.amazonaws.com/\n\
+//!      x-amz-date: <redacted>\n\
+//!      x-amz-target: DynamoDB_20120810.ListTables\n\
+//!      \n\
+//!      {\n  \"Limit\": 10\n}\n"
+//! );
+//! ```
+
+use std::collections::BTreeMap;
+use std::str;
+
+use rusoto_core::request::BufferedHttpResponse;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+
+/// Placeholder of the values which are redacted.
+pub const REDACTED: &str = "<redacted>";
+
+/// Request headers whose values differ on each request or hold credentials.
+const REDACTED_REQUEST_HEADERS: &[&str] = &[
+    "authorization",
+    "user-agent",
+    "x-amz-date",
+    "x-amz-security-token",
+];
+
+/// Query parameters of presigned requests whose values differ on each request or hold
+/// credentials.
+const REDACTED_PARAMS: &[&str] = &[
+    "X-Amz-Credential",
+    "X-Amz-Date",
+    "X-Amz-Security-Token",
+    "X-Amz-Signature",
+];
+
+/// Response headers whose values differ on each response.
+const REDACTED_RESPONSE_HEADERS: &[&str] =
+    &["date", "x-amz-id-2", "x-amz-request-id", "x-amzn-requestid"];
+
+/// Represents a request and the response it got, separated by a blank line.
+pub fn snapshot(request: &SignedRequest, response: &BufferedHttpResponse) -> String {
+    format!(
+        "{}\n{}",
+        request_snapshot(request),
+        response_snapshot(response)
+    )
+}
+
+/// Represents the method, URL, headers and body of a request.
+pub fn request_snapshot(request: &SignedRequest) -> String {
+    let mut snapshot = format!(
+        "{} {}://{}{}",
+        request.method(),
+        request.scheme(),
+        request.hostname(),
+        request.path()
+    );
+    let params: Vec<String> = request
+        .params
+        .iter()
+        .map(|(key, value)| match *value {
+            _ if REDACTED_PARAMS.contains(&key.as_str()) => format!("{}={}", key, REDACTED),
+            Some(ref value) => format!("{}={}", key, value),
+            None => key.to_owned(),
+        })
+        .collect();
+    if !params.is_empty() {
+        snapshot.push('?');
+        snapshot.push_str(&params.join("&"));
+    }
+    snapshot.push('\n');
+
+    for (name, values) in request.headers() {
+        for value in values {
+            let value = if REDACTED_REQUEST_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_owned()
+            } else {
+                String::from_utf8_lossy(value).into_owned()
+            };
+            snapshot.push_str(&format!("{}: {}\n", name, value));
+        }
+    }
+
+    match request.payload {
+        None => {}
+        Some(SignedRequestPayload::Buffer(ref body)) => push_body(&mut snapshot, body),
+        Some(SignedRequestPayload::Stream(_)) => snapshot.push_str("\n<streaming body>\n"),
+    }
+    snapshot
+}
+
+/// Represents the status, headers and body of a response.
+///
+/// ```rust
+/// extern crate http;
+/// extern crate rusoto_core;
+/// extern crate rusoto_mock;
+///
+/// use http::{HeaderMap, StatusCode};
+/// use rusoto_core::request::BufferedHttpResponse;
+/// use rusoto_mock::snapshot::response_snapshot;
+///
+/// let mut headers = HeaderMap::<String>::default();
+/// headers.insert("x-amzn-requestid", "4KBNVRGD25RG".to_owned());
+/// headers.insert("content-type", "application/x-amz-json-1.0".to_owned());
+/// let response = BufferedHttpResponse {
+///     status: StatusCode::BAD_REQUEST,
+///     body: r#"{"message":"Requested resource not found","__type":"ResourceNotFoundException"}"#.into(),
+///     headers,
+/// };
+///
+/// assert_eq!(
+///     response_snapshot(&response),
+///     "HTTP 400 Bad Request\n\
+///      content-type: application/x-amz-json-1.0\n\
+///      x-amzn-requestid: <redacted>\n\
+///      \n\
+///      {\n  \"__type\": \"ResourceNotFoundException\",\n  \"message\": \"Requested resource not found\"\n}\n"
+/// );
+/// ```
+pub fn response_snapshot(response: &BufferedHttpResponse) -> String {
+    let mut snapshot = format!("HTTP {}\n", response.status);
+
+    let mut headers = BTreeMap::new();
+    for (name, value) in response.headers.iter() {
+        headers
+            .entry(name.as_str())
+            .or_insert_with(Vec::new)
+            .push(value.as_str());
+    }
+    for (name, values) in headers {
+        for value in values {
+            let value = if REDACTED_RESPONSE_HEADERS.contains(&name) {
+                REDACTED
+            } else {
+                value
+            };
+            snapshot.push_str(&format!("{}: {}\n", name, value));
+        }
+    }
+
+    push_body(&mut snapshot, &response.body);
+    snapshot
+}
+
+/// Appends a blank line and `body`, pretty-printed if it is JSON.
+fn push_body(snapshot: &mut String, body: &[u8]) {
+    if body.is_empty() {
+        return;
+    }
+    snapshot.push('\n');
+    if let Ok(json) = serde_json::from_slice::<serde_json::Value>(body) {
+        snapshot.push_str(&serde_json::to_string_pretty(&json).unwrap_or_default());
+    } else if let Ok(text) = str::from_utf8(body) {
+        snapshot.push_str(text.trim_end());
+    } else {
+        snapshot.push_str(&format!("<{} bytes of binary data>", body.len()));
+    }
+    snapshot.push('\n');
+}
@@ -0,0 +1,44 @@
+//! Compares the throughput of the CRC32C implementations.
+//!
+//! Run with `cargo bench -p rusoto_core --bench crc32c`.
+
+use std::time::{Duration, Instant};
+
+use rusoto_core::checksum::Crc32c;
+
+const BUFFER_SIZE: usize = 64 * 1024 * 1024;
+const ROUNDS: usize = 5;
+
+/// The best throughput over a few rounds, in MiB per second.
+fn throughput(new: fn() -> Crc32c, buffer: &[u8]) -> f64 {
+    let mut best: Option<Duration> = None;
+    for _ in 0..ROUNDS {
+        let started = Instant::now();
+        let mut crc32c = new();
+        for chunk in buffer.chunks(64 * 1024) {
+            crc32c.update(chunk);
+        }
+        // keeps the checksum from being optimized away
+        assert_ne!(crc32c.finish(), 1);
+        let elapsed = started.elapsed();
+        best = Some(best.map_or(elapsed, |best| best.min(elapsed)));
+    }
+    let best = best.unwrap();
+    let seconds = best.as_secs() as f64 + f64::from(best.subsec_nanos()) / 1e9;
+    BUFFER_SIZE as f64 / (1024.0 * 1024.0) / seconds
+}
+
+fn main() {
+    let buffer = (0..BUFFER_SIZE)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+        .collect::<Vec<u8>>();
+
+    let portable = throughput(Crc32c::portable, &buffer);
+    let fastest = throughput(Crc32c::new, &buffer);
+    println!("portable: {:>8.0} MiB/s", portable);
+    println!(
+        "fastest:  {:>8.0} MiB/s ({:.1}x)",
+        fastest,
+        fastest / portable
+    );
+}
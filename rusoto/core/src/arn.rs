@@ -0,0 +1,222 @@
+//! Amazon Resource Names.
+//!
+//! Outputs name resources with ARNs such as `arn:aws:iam::123456789012:user/alice`, which
+//! are kept as strings. An [`Arn`](struct.Arn.html) parsed from one of them gives its parts.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rusoto_core::arn::Arn;
+//!
+//! let arn: Arn = "arn:aws:rds:us-west-2:123456789012:cluster-snapshot:orders"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(arn.region(), Some("us-west-2"));
+//! assert_eq!(arn.account_id(), Some("123456789012"));
+//! assert_eq!(arn.resource_type(), Some("cluster-snapshot"));
+//! assert_eq!(arn.resource_id(), "orders");
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An Amazon Resource Name, formatted as
+/// `arn:partition:service:region:account-id:resource`.
+///
+/// The region and account ID are empty for resources which aren't in one, such as IAM
+/// users or S3 buckets. The resource is a resource ID, or a resource type followed by a
+/// resource ID after a `/` or a `:`, as in `user/alice` or `function:resize`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Arn {
+    partition: String,
+    service: String,
+    region: String,
+    account_id: String,
+    resource: String,
+}
+
+impl Arn {
+    /// An ARN from its parts, with an empty string for a region or account ID it lacks.
+    pub fn new<S>(partition: S, service: S, region: S, account_id: S, resource: S) -> Arn
+    where
+        S: Into<String>,
+    {
+        Arn {
+            partition: partition.into(),
+            service: service.into(),
+            region: region.into(),
+            account_id: account_id.into(),
+            resource: resource.into(),
+        }
+    }
+
+    /// The partition of the resource, such as `aws` or `aws-cn`.
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The namespace of the service owning the resource, such as `iam`.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// The region of the resource, unless it is global.
+    pub fn region(&self) -> Option<&str> {
+        non_empty(&self.region)
+    }
+
+    /// The ID of the account owning the resource, unless the ARN leaves it out.
+    pub fn account_id(&self) -> Option<&str> {
+        non_empty(&self.account_id)
+    }
+
+    /// The whole resource part of the ARN, after the account ID.
+    pub fn resource(&self) -> &str {
+        &self.resource
+    }
+
+    /// The type of the resource, which is what comes before the first `/` or `:` of the
+    /// resource part, if there is one.
+    ///
+    /// Resource IDs may themselves hold a `/`, as the keys of S3 objects do: the bucket of
+    /// `arn:aws:s3:::my-bucket/photos/cat.jpg` is its resource type.
+    pub fn resource_type(&self) -> Option<&str> {
+        self.resource_separator()
+            .map(|separator| &self.resource[..separator])
+    }
+
+    /// The ID of the resource, which is the whole resource part when it has no type.
+    pub fn resource_id(&self) -> &str {
+        match self.resource_separator() {
+            Some(separator) => &self.resource[separator + 1..],
+            None => &self.resource,
+        }
+    }
+
+    fn resource_separator(&self) -> Option<usize> {
+        self.resource.find(&['/', ':'][..])
+    }
+}
+
+fn non_empty(part: &str) -> Option<&str> {
+    if part.is_empty() {
+        None
+    } else {
+        Some(part)
+    }
+}
+
+impl FromStr for Arn {
+    type Err = ParseArnError;
+
+    fn from_str(s: &str) -> Result<Arn, ParseArnError> {
+        let parts: Vec<&str> = s.splitn(6, ':').collect();
+        if parts.len() < 6 || parts[0] != "arn" {
+            return Err(ParseArnError::new(s, "expected six parts separated by `:`"));
+        }
+        if parts[1].is_empty() {
+            return Err(ParseArnError::new(s, "the partition is empty"));
+        }
+        if parts[2].is_empty() {
+            return Err(ParseArnError::new(s, "the service is empty"));
+        }
+        if parts[5].is_empty() {
+            return Err(ParseArnError::new(s, "the resource is empty"));
+        }
+        Ok(Arn::new(parts[1], parts[2], parts[3], parts[4], parts[5]))
+    }
+}
+
+impl fmt::Display for Arn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:{}:{}:{}:{}",
+            self.partition, self.service, self.region, self.account_id, self.resource
+        )
+    }
+}
+
+/// An error produced when a string isn't a valid ARN.
+#[derive(Debug, PartialEq)]
+pub struct ParseArnError {
+    message: String,
+}
+
+impl ParseArnError {
+    fn new(input: &str, reason: &str) -> Self {
+        ParseArnError {
+            message: format!("Not a valid ARN: {}: {}", input, reason),
+        }
+    }
+}
+
+impl Error for ParseArnError {}
+
+impl fmt::Display for ParseArnError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arn;
+
+    fn parse(arn: &str) -> Arn {
+        let parsed: Arn = arn.parse().unwrap();
+        assert_eq!(parsed.to_string(), arn);
+        parsed
+    }
+
+    #[test]
+    fn resources_are_split_on_their_first_separator() {
+        let user = parse("arn:aws:iam::123456789012:user/division/alice");
+        assert_eq!(user.partition(), "aws");
+        assert_eq!(user.service(), "iam");
+        assert_eq!(user.region(), None);
+        assert_eq!(user.account_id(), Some("123456789012"));
+        assert_eq!(user.resource_type(), Some("user"));
+        assert_eq!(user.resource_id(), "division/alice");
+
+        let alias = parse("arn:aws-cn:lambda:cn-north-1:123456789012:function:resize:live");
+        assert_eq!(alias.partition(), "aws-cn");
+        assert_eq!(alias.region(), Some("cn-north-1"));
+        assert_eq!(alias.resource(), "function:resize:live");
+        assert_eq!(alias.resource_type(), Some("function"));
+        assert_eq!(alias.resource_id(), "resize:live");
+
+        let event = parse("arn:aws:health:us-east-1::event/EC2/AWS_EC2_MAINTENANCE/123");
+        assert_eq!(event.account_id(), None);
+        assert_eq!(event.resource_type(), Some("event"));
+
+        let bucket = parse("arn:aws:s3:::my-bucket");
+        assert_eq!(bucket.region(), None);
+        assert_eq!(bucket.account_id(), None);
+        assert_eq!(bucket.resource_type(), None);
+        assert_eq!(bucket.resource_id(), "my-bucket");
+    }
+
+    #[test]
+    fn arns_are_formatted_from_their_parts() {
+        let arn = Arn::new("aws", "sns", "eu-west-1", "123456789012", "alerts");
+        assert_eq!(arn.to_string(), "arn:aws:sns:eu-west-1:123456789012:alerts");
+        assert_eq!(arn.to_string().parse::<Arn>().unwrap(), arn);
+    }
+
+    #[test]
+    fn invalid_arns_are_rejected() {
+        for invalid in &[
+            "",
+            "arn:aws:iam::123456789012",
+            "urn:aws:iam::123456789012:user/alice",
+            "arn::iam::123456789012:user/alice",
+            "arn:aws:::123456789012:user/alice",
+            "arn:aws:iam::123456789012:",
+        ] {
+            let err = invalid.parse::<Arn>().unwrap_err();
+            assert!(err.to_string().starts_with("Not a valid ARN: "));
+        }
+    }
+}
@@ -0,0 +1,352 @@
+//! Waiting for the asynchronous jobs of services.
+//!
+//! Services such as Amazon Transcribe or Amazon Textract process their inputs in jobs: a
+//! call starts the job, and another one describes its status until it completes. Service
+//! crates implement [`AsyncJob`](trait.AsyncJob.html) for their jobs, and
+//! [`JobHandle::wait`](struct.JobHandle.html#method.wait) polls them until they are done.
+
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use futures::future::{self, Loop};
+use futures::Future;
+use tokio_timer::Delay;
+
+/// The status of a job.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JobStatus<T> {
+    /// The job is still running.
+    InProgress,
+    /// The job completed, with its output.
+    Succeeded(T),
+    /// The job completed without processing all of its input.
+    PartiallySucceeded {
+        /// The output of the part of the input which was processed.
+        output: T,
+        /// Why the rest of the input was not processed.
+        reason: String,
+    },
+    /// The job failed.
+    Failed(String),
+}
+
+/// A kind of asynchronous job of a service.
+pub trait AsyncJob {
+    /// The output of completed jobs.
+    type Output;
+    /// The error describing a job.
+    type Error;
+
+    /// Describes the job with the given id, fetching its output once it has completed.
+    fn status(
+        &self,
+        job_id: &str,
+    ) -> Box<dyn Future<Item = JobStatus<Self::Output>, Error = Self::Error> + Send>;
+}
+
+/// How often and how long to poll the status of a job.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaitOptions {
+    initial_delay: Duration,
+    max_delay: Duration,
+    timeout: Option<Duration>,
+}
+
+impl WaitOptions {
+    /// Polls after 1 second, doubling the delay between polls up to 30 seconds, for as long
+    /// as the job runs.
+    pub fn new() -> WaitOptions {
+        WaitOptions {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            timeout: None,
+        }
+    }
+
+    /// Sets the delay before the second poll.
+    pub fn with_initial_delay(mut self, delay: Duration) -> WaitOptions {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Sets the maximum delay between polls.
+    pub fn with_max_delay(mut self, delay: Duration) -> WaitOptions {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Gives up waiting once the job has run for `timeout` since waiting started.
+    pub fn with_timeout(mut self, timeout: Duration) -> WaitOptions {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+impl Default for WaitOptions {
+    fn default() -> WaitOptions {
+        WaitOptions::new()
+    }
+}
+
+/// The output of a completed job.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JobOutput<T> {
+    /// All of the input was processed.
+    Complete(T),
+    /// Only part of the input was processed.
+    Partial {
+        /// The output of the part of the input which was processed.
+        output: T,
+        /// Why the rest of the input was not processed.
+        reason: String,
+    },
+}
+
+impl<T> JobOutput<T> {
+    /// Whether only part of the input was processed.
+    pub fn is_partial(&self) -> bool {
+        match *self {
+            JobOutput::Complete(_) => false,
+            JobOutput::Partial { .. } => true,
+        }
+    }
+
+    /// The output, complete or not.
+    pub fn into_output(self) -> T {
+        match self {
+            JobOutput::Complete(output) | JobOutput::Partial { output, .. } => output,
+        }
+    }
+}
+
+/// An error waiting for a job.
+#[derive(Debug, PartialEq)]
+pub enum JobError<E> {
+    /// The job failed.
+    Failed {
+        /// The id of the job.
+        job_id: String,
+        /// Why the job failed, as reported by the service.
+        reason: String,
+    },
+    /// The job did not complete within the timeout.
+    TimedOut {
+        /// The id of the job.
+        job_id: String,
+        /// How long the job was waited for.
+        waited: Duration,
+    },
+    /// Describing the job failed.
+    Status(E),
+}
+
+impl<E: fmt::Display> fmt::Display for JobError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JobError::Failed {
+                ref job_id,
+                ref reason,
+            } => write!(f, "job {} failed: {}", job_id, reason),
+            JobError::TimedOut { ref job_id, waited } => write!(
+                f,
+                "job {} did not complete within {}s",
+                job_id,
+                waited.as_secs()
+            ),
+            JobError::Status(ref error) => write!(f, "failed to describe job: {}", error),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for JobError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            JobError::Status(ref error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A started job.
+#[derive(Clone, Debug)]
+pub struct JobHandle<J> {
+    job: J,
+    job_id: String,
+}
+
+impl<J> JobHandle<J>
+where
+    J: AsyncJob + Send + Sync + 'static,
+    J::Output: Send + 'static,
+    J::Error: Send + 'static,
+{
+    /// The job of kind `job` with the id `job_id`, to wait for a job started elsewhere.
+    pub fn new<S: Into<String>>(job: J, job_id: S) -> JobHandle<J> {
+        JobHandle {
+            job,
+            job_id: job_id.into(),
+        }
+    }
+
+    /// The id of the job.
+    pub fn job_id(&self) -> &str {
+        &self.job_id
+    }
+
+    /// Polls the status of the job until it completes, fails or `options` give up on it.
+    pub fn wait(
+        self,
+        options: WaitOptions,
+    ) -> Box<dyn Future<Item = JobOutput<J::Output>, Error = JobError<J::Error>> + Send> {
+        let JobHandle { job, job_id } = self;
+        let started = Instant::now();
+
+        let polls = future::loop_fn(options.initial_delay, move |delay| {
+            let job_id = job_id.clone();
+            let max_delay = options.max_delay;
+            let timeout = options.timeout;
+            job.status(&job_id).map_err(JobError::Status).and_then(
+                move |status| -> Box<dyn Future<Item = _, Error = _> + Send> {
+                    let output = match status {
+                        JobStatus::InProgress => {
+                            let waited = started.elapsed();
+                            let next_delay = (delay * 2).min(max_delay);
+                            let delay = match timeout {
+                                Some(timeout) if waited >= timeout => {
+                                    return Box::new(future::err(JobError::TimedOut {
+                                        job_id,
+                                        waited,
+                                    }));
+                                }
+                                Some(timeout) => delay.min(timeout - waited),
+                                None => delay,
+                            };
+                            return Box::new(
+                                Delay::new(Instant::now() + delay)
+                                    .then(move |_| Ok(Loop::Continue(next_delay))),
+                            );
+                        }
+                        JobStatus::Succeeded(output) => JobOutput::Complete(output),
+                        JobStatus::PartiallySucceeded { output, reason } => {
+                            JobOutput::Partial { output, reason }
+                        }
+                        JobStatus::Failed(reason) => {
+                            return Box::new(future::err(JobError::Failed { job_id, reason }));
+                        }
+                    };
+                    Box::new(future::ok(Loop::Break(output)))
+                },
+            )
+        });
+        Box::new(polls)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// Reports the given statuses in order, then keeps reporting the job in progress.
+    struct ScriptedJob {
+        statuses: Mutex<Vec<JobStatus<&'static str>>>,
+        polls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl ScriptedJob {
+        fn new(statuses: Vec<JobStatus<&'static str>>) -> (ScriptedJob, Arc<Mutex<Vec<String>>>) {
+            let polls = Arc::new(Mutex::new(Vec::new()));
+            let job = ScriptedJob {
+                statuses: Mutex::new(statuses.into_iter().rev().collect()),
+                polls: polls.clone(),
+            };
+            (job, polls)
+        }
+    }
+
+    impl AsyncJob for ScriptedJob {
+        type Output = &'static str;
+        type Error = String;
+
+        fn status(
+            &self,
+            job_id: &str,
+        ) -> Box<dyn Future<Item = JobStatus<&'static str>, Error = String> + Send> {
+            self.polls.lock().unwrap().push(job_id.to_owned());
+            let status = self
+                .statuses
+                .lock()
+                .unwrap()
+                .pop()
+                .unwrap_or(JobStatus::InProgress);
+            Box::new(future::ok(status))
+        }
+    }
+
+    fn wait(
+        job: ScriptedJob,
+        options: WaitOptions,
+    ) -> Result<JobOutput<&'static str>, JobError<String>> {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(JobHandle::new(job, "job-1").wait(options))
+    }
+
+    fn fast() -> WaitOptions {
+        WaitOptions::new()
+            .with_initial_delay(Duration::from_millis(1))
+            .with_max_delay(Duration::from_millis(4))
+    }
+
+    #[test]
+    fn polls_until_the_job_completes() {
+        let (job, polls) = ScriptedJob::new(vec![
+            JobStatus::InProgress,
+            JobStatus::InProgress,
+            JobStatus::Succeeded("output"),
+        ]);
+        assert_eq!(wait(job, fast()), Ok(JobOutput::Complete("output")));
+        assert_eq!(*polls.lock().unwrap(), vec!["job-1", "job-1", "job-1"]);
+    }
+
+    #[test]
+    fn reports_partial_output() {
+        let (job, _) = ScriptedJob::new(vec![JobStatus::PartiallySucceeded {
+            output: "some",
+            reason: "page 3 is unreadable".to_owned(),
+        }]);
+        let output = wait(job, fast()).unwrap();
+        assert!(output.is_partial());
+        assert_eq!(output.into_output(), "some");
+    }
+
+    #[test]
+    fn reports_failed_jobs() {
+        let (job, _) = ScriptedJob::new(vec![
+            JobStatus::InProgress,
+            JobStatus::Failed("unsupported media".to_owned()),
+        ]);
+        assert_eq!(
+            wait(job, fast()),
+            Err(JobError::Failed {
+                job_id: "job-1".to_owned(),
+                reason: "unsupported media".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn gives_up_after_the_timeout() {
+        let (job, polls) = ScriptedJob::new(Vec::new());
+        match wait(job, fast().with_timeout(Duration::from_millis(50))) {
+            Err(JobError::TimedOut { job_id, waited }) => {
+                assert_eq!(job_id, "job-1");
+                assert!(waited >= Duration::from_millis(50));
+            }
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+        assert!(polls.lock().unwrap().len() > 2);
+    }
+}
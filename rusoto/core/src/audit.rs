@@ -0,0 +1,352 @@
+//! Local audit records of the requests made by a client.
+//!
+//! A client given an [`AuditSink`](trait.AuditSink.html) with `Client::with_audit_sink`
+//! hands it an [`AuditRecord`](struct.AuditRecord.html) after each request completes,
+//! whether it succeeded or not. Records name the operation and the resources it acted on,
+//! but none of the other fields of the request, so they are safe to keep around.
+//!
+//! A client given a [`RequestRecordSink`](trait.RequestRecordSink.html) with
+//! `Client::with_request_record_sink` hands it a
+//! [`RequestRecord`](struct.RequestRecord.html) of what it signed and sent for each call,
+//! to prove what was requested and when. Records hold the hashes of the canonical request
+//! and of the payload, but neither the signature nor the credentials, so a request can't
+//! be sent again from its record.
+//!
+//! ```rust,no_run
+//! use rusoto_core::audit::{AuditSink, JsonLinesAuditSink};
+//! use rusoto_core::{Client, HttpClient};
+//! use rusoto_core::credential::DefaultCredentialsProvider;
+//!
+//! let sink = JsonLinesAuditSink::create("/var/log/aws-audit.jsonl")
+//!     .unwrap()
+//!     .filter(|record| !record.is_read_only());
+//! let client = Client::new_with(
+//!     DefaultCredentialsProvider::new().unwrap(),
+//!     HttpClient::new().unwrap(),
+//! )
+//! .with_audit_sink(sink);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::signature::{to_hexdigest, SignedRequest};
+
+/// The prefixes of the names of operations which only read.
+const READ_ONLY_PREFIXES: &[&str] = &["Describe", "Get", "Head", "List"];
+
+/// What a client did with a request, and how it went.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AuditRecord {
+    /// The signing name of the service, such as `s3`.
+    pub service: String,
+    /// The name of the operation, such as `PutObject`, when known.
+    pub operation: Option<String>,
+    /// The name of the region the request was sent to.
+    pub region: String,
+    /// The HTTP method of the request.
+    pub method: String,
+    /// The fields of the request identifying the resources it acted on.
+    pub resource_ids: BTreeMap<String, String>,
+    /// The access key id the request was signed with, if it was signed.
+    pub access_key_id: Option<String>,
+    /// The AWS request id of the response, if there was one.
+    pub request_id: Option<String>,
+    /// The HTTP status of the response, if there was one.
+    pub status: Option<u16>,
+    /// Why the request failed without a response.
+    pub error: Option<String>,
+    /// How long the request took, retries included, in milliseconds.
+    pub duration_ms: u64,
+}
+
+impl AuditRecord {
+    /// A record of `request`, yet to be completed with its outcome.
+    pub(crate) fn new(request: &SignedRequest) -> AuditRecord {
+        AuditRecord {
+            service: request.service.clone(),
+            operation: request.operation.clone(),
+            region: request.region.name().to_owned(),
+            method: request.method.clone(),
+            resource_ids: request.resource_ids.clone(),
+            access_key_id: None,
+            request_id: None,
+            status: None,
+            error: None,
+            duration_ms: 0,
+        }
+    }
+
+    pub(crate) fn set_duration(&mut self, duration: Duration) {
+        self.duration_ms = duration.as_secs() * 1000 + u64::from(duration.subsec_millis());
+    }
+
+    /// Whether the request only read, going by its HTTP method or the name of its
+    /// operation, such as `GetObject` or `DescribeInstances`.
+    pub fn is_read_only(&self) -> bool {
+        if self.method == "GET" || self.method == "HEAD" {
+            return true;
+        }
+        self.operation.iter().any(|operation| {
+            READ_ONLY_PREFIXES
+                .iter()
+                .any(|prefix| operation.starts_with(prefix))
+        })
+    }
+
+    /// Whether the request got a successful response.
+    pub fn is_success(&self) -> bool {
+        self.status.into_iter().any(|status| status < 300)
+    }
+}
+
+/// What a client signed and sent for a call, and the response it got.
+///
+/// The record describes the last attempt at sending the request, which the response
+/// answered. Fields about signing are `None` for requests sent without credentials.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RequestRecord {
+    /// The signing name of the service, such as `s3`.
+    pub service: String,
+    /// The name of the operation, such as `PutObject`, when known.
+    pub operation: Option<String>,
+    /// The HTTP method of the request.
+    pub method: String,
+    /// The scheme and host the request was sent to, such as
+    /// `https://s3.eu-west-1.amazonaws.com`.
+    pub endpoint: String,
+    /// The `x-amz-date` the request was signed with, such as `20150830T123600Z`.
+    pub amz_date: Option<String>,
+    /// The credential scope of the signature, `date/region/service/aws4_request`.
+    pub credential_scope: Option<String>,
+    /// The names of the headers covered by the signature.
+    pub signed_headers: Vec<String>,
+    /// The hex encoded SHA-256 hash of the canonical request, which the signature signs.
+    pub canonical_request_sha256: Option<String>,
+    /// The `x-amz-content-sha256` of the request: the hex encoded SHA-256 hash of its
+    /// payload, or `UNSIGNED-PAYLOAD`.
+    pub payload_sha256: Option<String>,
+    /// The number of attempts at sending the request.
+    pub attempts: u32,
+    /// The AWS request id of the response, if there was one.
+    pub request_id: Option<String>,
+    /// The HTTP status of the response, if there was one.
+    pub status: Option<u16>,
+    /// Why the request failed without a response.
+    pub error: Option<String>,
+}
+
+impl RequestRecord {
+    /// A record of `request`, yet to be completed with its signing and its outcome.
+    pub(crate) fn new(request: &SignedRequest) -> RequestRecord {
+        RequestRecord {
+            service: request.service.clone(),
+            operation: request.operation.clone(),
+            method: request.method.clone(),
+            endpoint: String::new(),
+            amz_date: None,
+            credential_scope: None,
+            signed_headers: Vec::new(),
+            canonical_request_sha256: None,
+            payload_sha256: None,
+            attempts: 0,
+            request_id: None,
+            status: None,
+            error: None,
+        }
+    }
+
+    /// Records the attempt at sending `request`, signed from `canonical_request` if it
+    /// was signed.
+    pub(crate) fn sent(&mut self, request: &SignedRequest, canonical_request: Option<&str>) {
+        let header = |name: &str| {
+            request
+                .headers()
+                .get(name)
+                .and_then(|values| values.last())
+                .map(|value| String::from_utf8_lossy(value).into_owned())
+        };
+        self.endpoint = format!("{}://{}", request.scheme(), request.hostname());
+        self.attempts += 1;
+        match canonical_request {
+            Some(canonical_request) => {
+                self.amz_date = header("x-amz-date");
+                self.credential_scope = request.credential_scope();
+                self.signed_headers = request.signed_header_names();
+                self.canonical_request_sha256 = Some(to_hexdigest(canonical_request));
+                self.payload_sha256 = header("x-amz-content-sha256");
+            }
+            None => {
+                self.amz_date = None;
+                self.credential_scope = None;
+                self.signed_headers = Vec::new();
+                self.canonical_request_sha256 = None;
+                self.payload_sha256 = None;
+            }
+        }
+    }
+}
+
+/// Where a client hands the records of its requests.
+pub trait AuditSink: Send + Sync {
+    /// Keeps the record of a completed request.
+    fn record(&self, record: &AuditRecord);
+
+    /// A sink keeping only the records for which `predicate` is true.
+    fn filter<F>(self, predicate: F) -> FilteredAuditSink<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&AuditRecord) -> bool + Send + Sync,
+    {
+        FilteredAuditSink {
+            sink: self,
+            predicate,
+        }
+    }
+}
+
+impl<F> AuditSink for F
+where
+    F: Fn(&AuditRecord) + Send + Sync,
+{
+    fn record(&self, record: &AuditRecord) {
+        self(record)
+    }
+}
+
+/// Where a client hands the records of what it sent.
+pub trait RequestRecordSink: Send + Sync {
+    /// Keeps the record of a completed call.
+    fn record(&self, record: &RequestRecord);
+}
+
+impl<F> RequestRecordSink for F
+where
+    F: Fn(&RequestRecord) + Send + Sync,
+{
+    fn record(&self, record: &RequestRecord) {
+        self(record)
+    }
+}
+
+/// A sink keeping only some of the records, returned by `AuditSink::filter`.
+pub struct FilteredAuditSink<S, F> {
+    sink: S,
+    predicate: F,
+}
+
+impl<S, F> AuditSink for FilteredAuditSink<S, F>
+where
+    S: AuditSink,
+    F: Fn(&AuditRecord) -> bool + Send + Sync,
+{
+    fn record(&self, record: &AuditRecord) {
+        if (self.predicate)(record) {
+            self.sink.record(record);
+        }
+    }
+}
+
+/// A sink writing each record as a line of JSON.
+pub struct JsonLinesAuditSink<W = File> {
+    writer: Mutex<W>,
+}
+
+impl JsonLinesAuditSink<File> {
+    /// A sink appending to the file at `path`, created if it doesn't exist.
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<JsonLinesAuditSink<File>> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(JsonLinesAuditSink::new(file))
+    }
+}
+
+impl<W: Write + Send> JsonLinesAuditSink<W> {
+    /// A sink writing to `writer`.
+    pub fn new(writer: W) -> JsonLinesAuditSink<W> {
+        JsonLinesAuditSink {
+            writer: Mutex::new(writer),
+        }
+    }
+}
+
+impl<W: Write + Send> JsonLinesAuditSink<W> {
+    fn write<T: Serialize>(&self, record: &T) {
+        let mut line = serde_json::to_vec(record).expect("audit records serialize to JSON");
+        line.push(b'\n');
+        let mut writer = self.writer.lock().unwrap();
+        if let Err(err) = writer.write_all(&line).and_then(|()| writer.flush()) {
+            warn!("Couldn't write audit record: {}", err);
+        }
+    }
+}
+
+impl<W: Write + Send> AuditSink for JsonLinesAuditSink<W> {
+    fn record(&self, record: &AuditRecord) {
+        self.write(record)
+    }
+}
+
+impl<W: Write + Send> RequestRecordSink for JsonLinesAuditSink<W> {
+    fn record(&self, record: &RequestRecord) {
+        self.write(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Region;
+
+    fn record(method: &str, operation: Option<&str>) -> AuditRecord {
+        let mut request = SignedRequest::new(method, "dynamodb", &Region::EuWest1, "/");
+        if let Some(operation) = operation {
+            request.set_operation(operation);
+        }
+        request.add_resource_id("TableName", "orders");
+        AuditRecord::new(&request)
+    }
+
+    #[test]
+    fn read_only_requests_are_recognized() {
+        assert!(record("GET", None).is_read_only());
+        assert!(record("HEAD", Some("HeadObject")).is_read_only());
+        assert!(record("POST", Some("DescribeTable")).is_read_only());
+        assert!(record("POST", Some("ListTables")).is_read_only());
+        assert!(!record("POST", Some("PutItem")).is_read_only());
+        assert!(!record("DELETE", None).is_read_only());
+    }
+
+    #[test]
+    fn json_lines_sink_writes_a_line_per_record() {
+        let path = std::env::temp_dir().join(format!(
+            "rusoto-audit-{}-{}.jsonl",
+            std::process::id(),
+            line!()
+        ));
+        let sink = JsonLinesAuditSink::create(&path)
+            .unwrap()
+            .filter(|record: &AuditRecord| !record.is_read_only());
+
+        let mut put = record("POST", Some("PutItem"));
+        put.status = Some(200);
+        put.set_duration(Duration::from_millis(1500));
+        sink.record(&put);
+        sink.record(&record("POST", Some("GetItem")));
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            written,
+            "{\"service\":\"dynamodb\",\"operation\":\"PutItem\",\"region\":\"eu-west-1\",\
+             \"method\":\"POST\",\"resource_ids\":{\"TableName\":\"orders\"},\
+             \"access_key_id\":null,\"request_id\":null,\"status\":200,\"error\":null,\
+             \"duration_ms\":1500}\n"
+        );
+    }
+}
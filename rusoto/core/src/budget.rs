@@ -0,0 +1,464 @@
+//! Budgets of calls to each operation, guarding against runaway loops.
+//!
+//! A `CallBudget` counts the calls made to each operation over a sliding window, such as
+//! the last minute. Once a call would go over the budget of its operation, or over the
+//! budget of all calls, the budget either lets it through with a warning, delays it until
+//! the window has room for it, or fails it with a `BudgetExceeded` error:
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use rusoto_core::budget::{BudgetAction, CallBudget};
+//!
+//! let budget = CallBudget::new(Duration::from_secs(60))
+//!     .with_operation_limit("s3", "ListObjectsV2", 100)
+//!     .with_global_limit(10_000)
+//!     .allow("sts", "AssumeRole")
+//!     .with_action(BudgetAction::Fail)
+//!     .on_exceeded(|exceeded| eprintln!("over budget: {}", exceeded));
+//! ```
+//!
+//! Budgets are set on a `Client` with `Client::with_call_budget`, and are checked before
+//! credentials are fetched, so calls failed by the budget cost nothing. Clones of a budget
+//! share their counts, so the same budget can be given to the clients of several services.
+//! Services are named as in the signature of their requests, like `s3` or `dynamodb`, and
+//! operations as in the service model, like `ListObjectsV2`.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// What a budget does with the calls going over it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BudgetAction {
+    /// Let the calls through, logging a warning when the budget is first exceeded.
+    Warn,
+    /// Delay the calls until the window has room for them.
+    Throttle,
+    /// Fail the calls with a `BudgetExceeded` error, without sending them.
+    Fail,
+}
+
+/// Which limit of a budget a call went over.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BudgetScope {
+    /// The limit of the operation of the call.
+    Operation,
+    /// The limit of all calls.
+    Global,
+}
+
+/// A call going over a budget.
+///
+/// Calls failed by a budget fail with an `HttpDispatchError` of kind `BudgetExceeded`,
+/// whose `source` is this error.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BudgetExceeded {
+    /// The service of the call.
+    pub service: String,
+    /// The operation of the call, when known.
+    pub operation: Option<String>,
+    /// Which limit the call went over.
+    pub scope: BudgetScope,
+    /// The most calls allowed within the window.
+    pub limit: usize,
+    /// The window the calls are counted over.
+    pub window: Duration,
+}
+
+impl Error for BudgetExceeded {}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operation = self.operation.as_ref().map_or("an operation", |op| &op[..]);
+        match self.scope {
+            BudgetScope::Operation => write!(
+                f,
+                "the call to {} of {} went over its budget of {} calls in {:?}",
+                operation, self.service, self.limit, self.window
+            ),
+            BudgetScope::Global => write!(
+                f,
+                "the call to {} of {} went over the budget of {} calls in {:?}",
+                operation, self.service, self.limit, self.window
+            ),
+        }
+    }
+}
+
+/// What a budget decided for a call.
+pub(crate) enum Verdict {
+    /// Send the call now.
+    Allow,
+    /// Send the call after the delay.
+    Delay(Duration),
+    /// Fail the call.
+    Fail(BudgetExceeded),
+}
+
+/// What is called when a limit is exceeded.
+type Callback = Arc<dyn Fn(&BudgetExceeded) + Send + Sync>;
+
+/// Budgets of calls per operation and of all calls, over a sliding window.
+///
+/// See the module documentation.
+#[derive(Clone)]
+pub struct CallBudget {
+    window: Duration,
+    action: BudgetAction,
+    global_limit: Option<usize>,
+    operation_limits: HashMap<(String, String), usize>,
+    allowed: HashSet<(String, String)>,
+    on_exceeded: Option<Callback>,
+    state: Arc<Mutex<BudgetState>>,
+}
+
+#[derive(Default)]
+struct BudgetState {
+    global: Calls,
+    operations: HashMap<(String, String), Calls>,
+}
+
+/// The latest calls counted against a limit, oldest first.
+///
+/// No more calls than the limit are kept: whether another call fits in the window only
+/// depends on the oldest of the last `limit` calls.
+#[derive(Default)]
+struct Calls {
+    times: VecDeque<Instant>,
+    /// Whether the limit has been exceeded since a call last fit in it.
+    exceeded: bool,
+}
+
+impl Calls {
+    /// When another call fits in the window, if it doesn't now.
+    fn next_slot(&mut self, now: Instant, window: Duration, limit: usize) -> Option<Instant> {
+        while self
+            .times
+            .front()
+            .map_or(false, |&time| time + window <= now)
+        {
+            self.times.pop_front();
+        }
+        if self.times.len() < limit {
+            return None;
+        }
+        Some(self.times[self.times.len() - limit] + window)
+    }
+
+    fn record(&mut self, time: Instant, limit: usize) {
+        let position = self
+            .times
+            .iter()
+            .rposition(|&other| other <= time)
+            .map_or(0, |position| position + 1);
+        self.times.insert(position, time);
+        while self.times.len() > limit {
+            self.times.pop_front();
+        }
+    }
+}
+
+impl CallBudget {
+    /// A budget counting calls over the last `window`, without limits.
+    pub fn new(window: Duration) -> Self {
+        CallBudget {
+            window,
+            action: BudgetAction::Fail,
+            global_limit: None,
+            operation_limits: HashMap::new(),
+            allowed: HashSet::new(),
+            on_exceeded: None,
+            state: Arc::default(),
+        }
+    }
+
+    /// Allows at most `max_calls` calls to `operation` of `service` within the window.
+    ///
+    /// Only calls whose request names its operation, as those of generated clients do, are
+    /// counted against operation limits.
+    pub fn with_operation_limit(
+        mut self,
+        service: &str,
+        operation: &str,
+        max_calls: usize,
+    ) -> Self {
+        self.operation_limits
+            .insert((service.to_owned(), operation.to_owned()), max_calls);
+        self
+    }
+
+    /// Allows at most `max_calls` calls within the window, whatever their operation.
+    pub fn with_global_limit(mut self, max_calls: usize) -> Self {
+        self.global_limit = Some(max_calls);
+        self
+    }
+
+    /// Lets the calls to `operation` of `service` through without counting them.
+    pub fn allow(mut self, service: &str, operation: &str) -> Self {
+        self.allowed
+            .insert((service.to_owned(), operation.to_owned()));
+        self
+    }
+
+    /// Does `action` with the calls going over the budget. Defaults to failing them.
+    pub fn with_action(mut self, action: BudgetAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// Calls `callback` when a limit is exceeded, once until calls fit in it again.
+    pub fn on_exceeded<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&BudgetExceeded) + Send + Sync + 'static,
+    {
+        self.on_exceeded = Some(Arc::new(callback));
+        self
+    }
+
+    /// Counts a call to `operation` of `service` made at `now`, deciding what to do with it.
+    ///
+    /// Calls whose request doesn't name its operation are only counted against the global
+    /// limit, as there is no telling which operation limit or allowance applies to them.
+    pub(crate) fn charge(&self, service: &str, operation: Option<&str>, now: Instant) -> Verdict {
+        let key = operation.map(|operation| (service.to_owned(), operation.to_owned()));
+        let operation_limit = match key {
+            Some(ref key) if self.allowed.contains(key) => return Verdict::Allow,
+            Some(ref key) => self.operation_limits.get(key).cloned(),
+            None => None,
+        };
+        if operation_limit.is_none() && self.global_limit.is_none() {
+            return Verdict::Allow;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let BudgetState {
+            ref mut global,
+            ref mut operations,
+        } = *state;
+        let mut limited = Vec::with_capacity(2);
+        if let (Some(key), Some(limit)) = (key, operation_limit) {
+            limited.push((
+                operations.entry(key).or_insert_with(Calls::default),
+                limit,
+                BudgetScope::Operation,
+            ));
+        }
+        if let Some(limit) = self.global_limit {
+            limited.push((global, limit, BudgetScope::Global));
+        }
+
+        let mut slot = None;
+        let mut exceeded = Vec::new();
+        let mut crossed = Vec::new();
+        for &mut (ref mut calls, limit, ref scope) in &mut limited {
+            let next_slot = match calls.next_slot(now, self.window, limit) {
+                Some(next_slot) => next_slot,
+                None => {
+                    calls.exceeded = false;
+                    continue;
+                }
+            };
+            slot = Some(slot.map_or(next_slot, |slot: Instant| slot.max(next_slot)));
+            let error = BudgetExceeded {
+                service: service.to_owned(),
+                operation: operation.map(ToOwned::to_owned),
+                scope: scope.clone(),
+                limit,
+                window: self.window,
+            };
+            if !calls.exceeded {
+                calls.exceeded = true;
+                crossed.push(error.clone());
+            }
+            exceeded.push(error);
+        }
+
+        let verdict = match (slot, self.action) {
+            (None, _) | (_, BudgetAction::Warn) => {
+                for (calls, limit, _) in limited {
+                    calls.record(now, limit);
+                }
+                Verdict::Allow
+            }
+            (Some(slot), BudgetAction::Throttle) => {
+                for (calls, limit, _) in limited {
+                    calls.record(slot, limit);
+                }
+                Verdict::Delay(slot - now)
+            }
+            // calls which aren't sent don't count
+            (Some(_), BudgetAction::Fail) => Verdict::Fail(exceeded.swap_remove(0)),
+        };
+        drop(state);
+
+        // the callback may call through the budget again
+        for exceeded in &crossed {
+            if self.action == BudgetAction::Warn {
+                warn!("{}", exceeded);
+            }
+            if let Some(ref callback) = self.on_exceeded {
+                callback(exceeded);
+            }
+        }
+        verdict
+    }
+}
+
+impl fmt::Debug for CallBudget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CallBudget")
+            .field("window", &self.window)
+            .field("action", &self.action)
+            .field("global_limit", &self.global_limit)
+            .field("operation_limits", &self.operation_limits)
+            .field("allowed", &self.allowed)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use super::{BudgetAction, BudgetExceeded, BudgetScope, CallBudget, Verdict};
+
+    fn minute() -> Duration {
+        Duration::from_secs(60)
+    }
+
+    fn charge(
+        budget: &CallBudget,
+        operation: &str,
+        now: Instant,
+    ) -> Result<Duration, BudgetExceeded> {
+        match budget.charge("s3", Some(operation), now) {
+            Verdict::Allow => Ok(Duration::from_secs(0)),
+            Verdict::Delay(delay) => Ok(delay),
+            Verdict::Fail(exceeded) => Err(exceeded),
+        }
+    }
+
+    #[test]
+    fn calls_over_the_budget_of_their_operation_fail() {
+        let budget = CallBudget::new(minute()).with_operation_limit("s3", "ListObjectsV2", 100);
+        let start = Instant::now();
+        for n in 0..100 {
+            let now = start + Duration::from_millis(n * 10);
+            assert!(charge(&budget, "ListObjectsV2", now).is_ok());
+        }
+
+        let now = start + Duration::from_secs(30);
+        assert_eq!(
+            charge(&budget, "ListObjectsV2", now),
+            Err(BudgetExceeded {
+                service: "s3".to_owned(),
+                operation: Some("ListObjectsV2".to_owned()),
+                scope: BudgetScope::Operation,
+                limit: 100,
+                window: minute(),
+            })
+        );
+        // failed calls don't count, and other operations aren't limited
+        assert!(charge(&budget, "GetObject", now).is_ok());
+        // the window slides past the first calls
+        assert!(charge(&budget, "ListObjectsV2", start + minute()).is_ok());
+        assert!(charge(&budget, "ListObjectsV2", start + minute()).is_err());
+    }
+
+    #[test]
+    fn global_limits_count_every_operation() {
+        let budget = CallBudget::new(minute())
+            .with_global_limit(3)
+            .allow("s3", "HeadObject");
+        let now = Instant::now();
+        assert!(charge(&budget, "GetObject", now).is_ok());
+        assert!(charge(&budget, "PutObject", now).is_ok());
+        assert!(charge(&budget, "ListObjectsV2", now).is_ok());
+        for _ in 0..10 {
+            assert!(charge(&budget, "HeadObject", now).is_ok());
+        }
+        let exceeded = charge(&budget, "GetObject", now).unwrap_err();
+        assert_eq!(exceeded.scope, BudgetScope::Global);
+        assert_eq!(
+            exceeded.to_string(),
+            "the call to GetObject of s3 went over the budget of 3 calls in 60s"
+        );
+    }
+
+    #[test]
+    fn calls_without_an_operation_only_count_against_the_global_limit() {
+        let budget = CallBudget::new(minute())
+            .with_operation_limit("s3", "", 1)
+            .allow("s3", "")
+            .with_global_limit(2);
+        let now = Instant::now();
+        let charge_unnamed = || match budget.charge("s3", None, now) {
+            Verdict::Allow => Ok(()),
+            Verdict::Delay(_) => panic!("calls aren't throttled by default"),
+            Verdict::Fail(exceeded) => Err(exceeded),
+        };
+        assert!(charge_unnamed().is_ok());
+        assert!(charge_unnamed().is_ok());
+        let exceeded = charge_unnamed().unwrap_err();
+        assert_eq!(exceeded.operation, None);
+        assert_eq!(exceeded.scope, BudgetScope::Global);
+        assert_eq!(
+            exceeded.to_string(),
+            "the call to an operation of s3 went over the budget of 2 calls in 60s"
+        );
+    }
+
+    #[test]
+    fn throttled_calls_wait_for_room_in_the_window() {
+        let budget = CallBudget::new(Duration::from_secs(1))
+            .with_operation_limit("s3", "GetObject", 2)
+            .with_action(BudgetAction::Throttle);
+        let now = Instant::now();
+        assert_eq!(
+            charge(&budget, "GetObject", now),
+            Ok(Duration::from_secs(0))
+        );
+        let later = now + Duration::from_millis(100);
+        assert_eq!(
+            charge(&budget, "GetObject", later),
+            Ok(Duration::from_secs(0))
+        );
+        assert_eq!(
+            charge(&budget, "GetObject", later),
+            Ok(Duration::from_millis(900))
+        );
+        assert_eq!(
+            charge(&budget, "GetObject", later),
+            Ok(Duration::from_millis(1000))
+        );
+    }
+
+    #[test]
+    fn crossings_are_told_once_and_counts_are_shared_by_clones() {
+        let crossings = Arc::new(AtomicUsize::new(0));
+        let counter = crossings.clone();
+        let budget = CallBudget::new(Duration::from_secs(1))
+            .with_operation_limit("s3", "GetObject", 1)
+            .with_action(BudgetAction::Warn)
+            .on_exceeded(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        let clone = budget.clone();
+        let now = Instant::now();
+        assert!(charge(&budget, "GetObject", now).is_ok());
+        for _ in 0..5 {
+            assert!(charge(&clone, "GetObject", now).is_ok());
+        }
+        assert_eq!(crossings.load(Ordering::SeqCst), 1);
+
+        // the limit is crossed again once calls fit in it again
+        let later = now + Duration::from_secs(2);
+        assert!(charge(&budget, "GetObject", later).is_ok());
+        assert!(charge(&budget, "GetObject", later).is_ok());
+        assert_eq!(crossings.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,201 @@
+//! Cooperative cancellation of requests and of the operations built on them.
+//!
+//! Dropping a `RusotoFuture` stops its request, but an operation made of many requests,
+//! such as a paginated listing or a multipart upload, may need to clean up before it
+//! stops. A [`CancellationToken`](struct.CancellationToken.html) is handed to the futures,
+//! streams and helpers which should stop together. Once it is cancelled they stop polling
+//! what they have in flight, abandon pending retries, run their cleanup and fail with a
+//! [`Cancelled`](struct.Cancelled.html) error, which becomes `RusotoError::Cancelled` for
+//! requests.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! # // TODO: remove ignore when the cyclic dependency issue has been fixed
+//! # // https://github.com/rusoto/rusoto/pull/1141#issuecomment-421865362
+//! use futures::{Future, Stream};
+//! use rusoto_core::cancellation::CancellationToken;
+//! use rusoto_core::pagination::Paginator;
+//! use rusoto_core::{Region, RusotoError};
+//! use rusoto_s3::{ListObjectsV2Request, S3, S3Client};
+//!
+//! let token = CancellationToken::new();
+//! let client = S3Client::new(Region::UsEast1);
+//! let listing = Paginator::new(move |continuation_token| {
+//!     let request = ListObjectsV2Request {
+//!         bucket: "my-bucket".to_owned(),
+//!         continuation_token,
+//!         ..Default::default()
+//!     };
+//!     client
+//!         .list_objects_v2(request)
+//!         .map(|page| (page.contents.unwrap_or_default(), page.next_continuation_token))
+//! })
+//! .with_cancellation(token.clone());
+//!
+//! // from any thread, for instance on shutdown
+//! token.cancel();
+//! assert_eq!(listing.collect().wait().err(), Some(RusotoError::Cancelled));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use futures::task::{self, Task};
+use futures::{Async, Future, Poll};
+
+/// The error of an operation stopped by its `CancellationToken`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "operation was cancelled")
+    }
+}
+
+impl Error for Cancelled {}
+
+/// A signal to stop, shared by the futures and streams it is attached to.
+///
+/// Clones share their state, so canceling any clone cancels them all. Tokens can be sent
+/// to and cancelled from other threads. A token can't be reset once cancelled.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    inner: Arc<TokenState>,
+}
+
+#[derive(Debug, Default)]
+struct TokenState {
+    cancelled: AtomicBool,
+    /// The tasks to wake up when the token is cancelled.
+    tasks: Mutex<Vec<Task>>,
+}
+
+impl CancellationToken {
+    /// A token which isn't cancelled yet.
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Cancels everything the token is attached to, waking up the tasks polling it.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        let tasks: Vec<Task> = self.inner.tasks.lock().unwrap().drain(..).collect();
+        for task in tasks {
+            task.notify();
+        }
+    }
+
+    /// Whether the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Ready once the token is cancelled. Otherwise the current task is woken up when it
+    /// is, so this must be called from within a task, as in the `poll` of a future.
+    pub fn poll_cancelled(&self) -> Async<()> {
+        if self.is_cancelled() {
+            return Async::Ready(());
+        }
+        {
+            let mut tasks = self.inner.tasks.lock().unwrap();
+            if !tasks.iter().any(Task::will_notify_current) {
+                tasks.push(task::current());
+            }
+        }
+        // the token may have been cancelled before the task was registered
+        if self.is_cancelled() {
+            Async::Ready(())
+        } else {
+            Async::NotReady
+        }
+    }
+
+    /// Runs `future` until it resolves or the token is cancelled, in which case `future`
+    /// is dropped and the result is a `Cancelled` error.
+    pub fn guard<F>(&self, future: F) -> Cancelable<F>
+    where
+        F: Future,
+        F::Error: From<Cancelled>,
+    {
+        Cancelable {
+            future,
+            token: self.clone(),
+        }
+    }
+}
+
+/// A future stopped by a `CancellationToken`, see `CancellationToken::guard`.
+pub struct Cancelable<F> {
+    future: F,
+    token: CancellationToken,
+}
+
+impl<F> Future for Cancelable<F>
+where
+    F: Future,
+    F::Error: From<Cancelled>,
+{
+    type Item = F::Item;
+    type Error = F::Error;
+
+    fn poll(&mut self) -> Poll<F::Item, F::Error> {
+        if self.token.poll_cancelled().is_ready() {
+            return Err(Cancelled.into());
+        }
+        self.future.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use futures::future;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum TestError {
+        Cancelled,
+    }
+
+    impl From<Cancelled> for TestError {
+        fn from(_: Cancelled) -> TestError {
+            TestError::Cancelled
+        }
+    }
+
+    #[test]
+    fn clones_share_the_cancellation() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+    }
+
+    #[test]
+    fn guarded_futures_resolve_unless_cancelled() {
+        let token = CancellationToken::new();
+        let guarded = token.guard(future::ok::<_, TestError>(42));
+        assert_eq!(guarded.wait(), Ok(42));
+    }
+
+    #[test]
+    fn canceling_from_another_thread_wakes_up_a_pending_future() {
+        let token = CancellationToken::new();
+        let canceler = token.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            canceler.cancel();
+        });
+
+        let guarded = token.guard(future::empty::<(), TestError>());
+        assert_eq!(guarded.wait(), Err(TestError::Cancelled));
+    }
+}
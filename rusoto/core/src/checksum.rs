@@ -0,0 +1,234 @@
+//! CRC32C checksums, which S3 uses to check the integrity of objects.
+//!
+//! [`Crc32c`](struct.Crc32c.html) computes the checksum of bytes given a piece at a time,
+//! with the CRC32 instruction of SSE 4.2 when the processor has it, and a portable
+//! implementation otherwise. The checksum of a buffered payload is sent with
+//! `SignedRequest::set_checksum_crc32c_header`; that of a stream is computed as it flows
+//! with `ByteStream::crc32c`.
+
+use std::sync::{Arc, Mutex};
+
+/// The CRC32C (Castagnoli) polynomial, reversed.
+const POLYNOMIAL: u32 = 0x82f6_3b78;
+
+lazy_static! {
+    /// Tables of the portable implementation, processing 8 bytes at a time.
+    static ref TABLES: [[u32; 256]; 8] = {
+        let mut tables = [[0u32; 256]; 8];
+        for (i, entry) in tables[0].iter_mut().enumerate() {
+            let mut crc = i as u32;
+            for _ in 0..8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ POLYNOMIAL
+                } else {
+                    crc >> 1
+                };
+            }
+            *entry = crc;
+        }
+        for i in 0..256 {
+            for k in 1..8 {
+                let previous = tables[k - 1][i];
+                tables[k][i] = (previous >> 8) ^ tables[0][(previous & 0xff) as usize];
+            }
+        }
+        tables
+    };
+}
+
+/// The CRC32C checksum of `bytes`.
+pub fn crc32c(bytes: &[u8]) -> u32 {
+    let mut crc32c = Crc32c::new();
+    crc32c.update(bytes);
+    crc32c.finish()
+}
+
+/// The CRC32C checksum of bytes given a piece at a time.
+#[derive(Clone, Copy, Debug)]
+pub struct Crc32c {
+    crc: u32,
+    hardware: bool,
+}
+
+impl Crc32c {
+    /// The checksum of no bytes, to be updated with the fastest implementation available.
+    pub fn new() -> Crc32c {
+        Crc32c {
+            crc: !0,
+            hardware: hardware_available(),
+        }
+    }
+
+    /// The checksum of no bytes, to be updated with the portable implementation.
+    #[doc(hidden)]
+    pub fn portable() -> Crc32c {
+        Crc32c {
+            crc: !0,
+            hardware: false,
+        }
+    }
+
+    /// Adds `bytes` to the checksum.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.crc = if self.hardware {
+            // only set when the processor supports the instructions
+            unsafe { update_hardware(self.crc, bytes) }
+        } else {
+            update_portable(self.crc, bytes)
+        };
+    }
+
+    /// The checksum of the bytes added so far.
+    pub fn finish(&self) -> u32 {
+        !self.crc
+    }
+
+    /// The checksum of the bytes added so far as the value of an `x-amz-checksum-crc32c`
+    /// header: its big-endian bytes, base64 encoded.
+    pub fn header_value(&self) -> String {
+        base64::encode(&self.finish().to_be_bytes())
+    }
+}
+
+impl Default for Crc32c {
+    fn default() -> Crc32c {
+        Crc32c::new()
+    }
+}
+
+/// The checksum of the bytes which went through a stream, returned by
+/// `ByteStream::crc32c`.
+#[derive(Clone, Debug)]
+pub struct StreamChecksum {
+    inner: Arc<Mutex<StreamChecksumState>>,
+}
+
+#[derive(Debug)]
+struct StreamChecksumState {
+    crc32c: Crc32c,
+    complete: bool,
+}
+
+impl StreamChecksum {
+    pub(crate) fn new() -> StreamChecksum {
+        StreamChecksum {
+            inner: Arc::new(Mutex::new(StreamChecksumState {
+                crc32c: Crc32c::new(),
+                complete: false,
+            })),
+        }
+    }
+
+    pub(crate) fn update(&self, bytes: &[u8]) {
+        self.inner.lock().unwrap().crc32c.update(bytes);
+    }
+
+    pub(crate) fn complete(&self) {
+        self.inner.lock().unwrap().complete = true;
+    }
+
+    /// The checksum of the stream, once it has ended.
+    pub fn crc32c(&self) -> Option<Crc32c> {
+        let state = self.inner.lock().unwrap();
+        if state.complete {
+            Some(state.crc32c)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn hardware_available() -> bool {
+    is_x86_feature_detected!("sse4.2")
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn hardware_available() -> bool {
+    false
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse4.2")]
+unsafe fn update_hardware(crc: u32, bytes: &[u8]) -> u32 {
+    use std::arch::x86_64::{_mm_crc32_u64, _mm_crc32_u8};
+
+    let mut chunks = bytes.chunks_exact(8);
+    let mut crc = u64::from(crc);
+    for chunk in &mut chunks {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(chunk);
+        crc = _mm_crc32_u64(crc, u64::from_le_bytes(word));
+    }
+    chunks
+        .remainder()
+        .iter()
+        .fold(crc as u32, |crc, byte| _mm_crc32_u8(crc, *byte))
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+unsafe fn update_hardware(crc: u32, bytes: &[u8]) -> u32 {
+    update_portable(crc, bytes)
+}
+
+fn update_portable(mut crc: u32, bytes: &[u8]) -> u32 {
+    let tables = &*TABLES;
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        let low = crc ^ u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        let high = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+        crc = tables[7][(low & 0xff) as usize]
+            ^ tables[6][((low >> 8) & 0xff) as usize]
+            ^ tables[5][((low >> 16) & 0xff) as usize]
+            ^ tables[4][(low >> 24) as usize]
+            ^ tables[3][(high & 0xff) as usize]
+            ^ tables[2][((high >> 8) & 0xff) as usize]
+            ^ tables[1][((high >> 16) & 0xff) as usize]
+            ^ tables[0][(high >> 24) as usize];
+    }
+    chunks.remainder().iter().fold(crc, |crc, byte| {
+        tables[0][((crc ^ u32::from(*byte)) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32c_matches_known_checksums() {
+        assert_eq!(crc32c(b""), 0);
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+        // from RFC 3720, B.4
+        assert_eq!(crc32c(&[0u8; 32]), 0x8a91_36aa);
+        assert_eq!(crc32c(&[0xffu8; 32]), 0x62a8_ab43);
+        let ascending = (0..32).collect::<Vec<u8>>();
+        assert_eq!(crc32c(&ascending), 0x46dd_794e);
+    }
+
+    #[test]
+    fn implementations_agree_whichever_the_pieces() {
+        let bytes = (0..10_000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect::<Vec<u8>>();
+        let expected = crc32c(&bytes);
+
+        for &piece in &[1, 3, 8, 13, 4096] {
+            let mut hardware = Crc32c::new();
+            let mut portable = Crc32c::portable();
+            for chunk in bytes.chunks(piece) {
+                hardware.update(chunk);
+                portable.update(chunk);
+            }
+            assert_eq!(hardware.finish(), expected);
+            assert_eq!(portable.finish(), expected);
+        }
+    }
+
+    #[test]
+    fn header_value_is_base64_of_big_endian_bytes() {
+        let mut crc32c = Crc32c::new();
+        crc32c.update(b"123456789");
+        assert_eq!(crc32c.header_value(), "4waSgw==");
+    }
+}
@@ -1,43 +1,114 @@
+use std::collections::HashMap;
+use std::io;
+use std::mem;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, Weak};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use futures::{Async, Future, Poll};
+use bytes::Bytes;
+use futures::future::join_all;
+use futures::stream::Concat2;
+use futures::task::{self, Task};
+use futures::{Async, Future, Poll, Stream};
+use http::{StatusCode, Uri};
+use tokio_timer::Delay;
 
+use crate::audit::{AuditRecord, AuditSink, RequestRecord, RequestRecordSink};
+use crate::budget::{CallBudget, Verdict};
+use crate::concurrency::{ConcurrencyLimits, Permit, PermitFuture};
 use crate::credential::{
-    CredentialsError, DefaultCredentialsProvider, ProvideAwsCredentials, StaticProvider,
+    AwsCredentials, Clock, CredentialsError, DefaultCredentialsProvider, ProvideAwsCredentials,
+    StaticProvider, SystemClock,
 };
 use crate::error::RusotoError;
 use crate::future::{self, RusotoFuture};
-use crate::request::{DispatchSignedRequest, HttpClient, HttpDispatchError, HttpResponse};
-use crate::signature::SignedRequest;
+use crate::region::Region;
+use crate::request::{
+    DispatchSignedRequest, HttpClient, HttpDispatchError, HttpDispatchErrorKind, HttpResponse,
+};
+use crate::retry::{ResponseRecord, RetryObserver, RetryPolicy, RetryRecord, TransferRecord};
+use crate::signature::{clock_time, SignedRequest, SignedRequestPayload, SigningAlgorithm};
+use crate::stream::ByteStream;
+
+/// How many temporary redirects are followed by default.
+const DEFAULT_MAX_REDIRECTS: usize = 3;
+
+/// How long `Client::warm_up` waits for each endpoint to answer.
+const WARM_UP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How many seconds before they expire credentials fetched by `Client::warm_up` stop
+/// being used.
+const WARM_CREDENTIALS_MARGIN_SECS: i64 = 300;
 
 lazy_static! {
     static ref SHARED_CLIENT: Mutex<Weak<ClientInner<DefaultCredentialsProvider, HttpClient>>> =
         Mutex::new(Weak::new());
 }
 
+/// Settings for a single call, overriding those of the client it is made with.
+///
+/// A configuration is attached to a request with `SignedRequest::set_operation_config`,
+/// or to the future of any generated call with `RusotoFuture::with_operation_config`:
+///
+/// ```rust,ignore
+/// # // TODO: remove ignore when the cyclic dependency issue has been fixed
+/// # // https://github.com/rusoto/rusoto/pull/1141#issuecomment-421865362
+/// # extern crate rusoto_core;
+/// # extern crate rusoto_dynamodb;
+/// #
+/// # use std::time::Duration;
+/// # use rusoto_core::{OperationConfig, Region, RetryPolicy};
+/// # use rusoto_dynamodb::{DynamoDb, DynamoDbClient, GetItemInput};
+/// #
+/// # let dynamodb = DynamoDbClient::new(Region::default());
+/// # let input = GetItemInput::default();
+/// let future = dynamodb.get_item(input).with_operation_config(OperationConfig {
+///     timeout: Some(Duration::from_millis(500)),
+///     retry_policy_override: Some(RetryPolicy::new(4)),
+/// });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OperationConfig {
+    /// The timeout of each attempt at dispatching the request.
+    pub timeout: Option<Duration>,
+    /// The policy to retry the request with instead of the one of the client.
+    pub retry_policy_override: Option<RetryPolicy>,
+}
+
 /// Re-usable logic for all clients.
 #[derive(Clone)]
 pub struct Client {
     inner: Arc<dyn SignAndDispatch + Send + Sync>,
+    retry_policy: RetryPolicy,
+    validate_requests: bool,
+    max_redirects: usize,
+    clock: Arc<dyn Clock>,
+    concurrency_limits: Option<ConcurrencyLimits>,
+    audit_sink: Option<Arc<dyn AuditSink>>,
+    request_record_sink: Option<Arc<dyn RequestRecordSink>>,
+    retry_observer: Option<Arc<dyn RetryObserver>>,
+    signing_algorithm: Option<SigningAlgorithm>,
+    unsigned_payload: bool,
+    call_budget: Option<CallBudget>,
 }
 
 impl Client {
     /// Return the shared default client.
     pub fn shared() -> Self {
         let mut lock = SHARED_CLIENT.lock().unwrap();
-        if let Some(inner) = lock.upgrade() {
-            return Client { inner };
+        // a closed shared client is replaced rather than handed out again
+        if let Some(inner) = lock.upgrade().filter(|inner| !inner.lifecycle.is_closed()) {
+            return Client::from_inner(inner);
         }
         let credentials_provider =
             DefaultCredentialsProvider::new().expect("failed to create credentials provider");
         let dispatcher = HttpClient::new().expect("failed to create request dispatcher");
-        let inner = Arc::new(ClientInner {
-            credentials_provider: Some(Arc::new(credentials_provider)),
-            dispatcher: Arc::new(dispatcher),
-        });
+        let inner = Arc::new(ClientInner::new(
+            Some(Arc::new(credentials_provider)),
+            dispatcher,
+        ));
         *lock = Arc::downgrade(&inner);
-        Client { inner }
+        Client::from_inner(inner)
     }
 
     /// Create a client from a credentials provider and request dispatcher.
@@ -48,13 +119,8 @@ impl Client {
         D: DispatchSignedRequest + Send + Sync + 'static,
         D::Future: Send,
     {
-        let inner = ClientInner {
-            credentials_provider: Some(Arc::new(credentials_provider)),
-            dispatcher: Arc::new(dispatcher),
-        };
-        Client {
-            inner: Arc::new(inner),
-        }
+        let inner = ClientInner::new(Some(Arc::new(credentials_provider)), dispatcher);
+        Client::from_inner(Arc::new(inner))
     }
 
     /// Create a client from a request dispatcher without a credentials provider. The client will
@@ -67,24 +133,476 @@ impl Client {
         D: DispatchSignedRequest + Send + Sync + 'static,
         D::Future: Send,
     {
-        let inner = ClientInner::<StaticProvider, D> {
-            credentials_provider: None,
-            dispatcher: Arc::new(dispatcher),
-        };
+        let inner = ClientInner::<StaticProvider, D>::new(None, dispatcher);
+        Client::from_inner(Arc::new(inner))
+    }
+
+    /// A client with the default settings, signing and dispatching requests with `inner`.
+    fn from_inner(inner: Arc<dyn SignAndDispatch + Send + Sync>) -> Self {
         Client {
-            inner: Arc::new(inner),
+            inner,
+            retry_policy: RetryPolicy::default(),
+            validate_requests: false,
+            max_redirects: DEFAULT_MAX_REDIRECTS,
+            clock: Arc::new(SystemClock),
+            concurrency_limits: None,
+            audit_sink: None,
+            request_record_sink: None,
+            retry_observer: None,
+            signing_algorithm: None,
+            unsigned_payload: false,
+            call_budget: None,
+        }
+    }
+
+    /// Use the given policy to retry requests which failed.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Follow at most `max_redirects` temporary redirects (307) per request.
+    ///
+    /// S3 answers with temporary redirects to requests for newly created buckets outside
+    /// of us-east-1, until their DNS name has propagated. Redirected requests are signed
+    /// again for the host given by the `Location` header. Requests with a streaming
+    /// payload can't be sent again and are never redirected. Defaults to 3; 0 returns
+    /// redirect responses as they are.
+    pub fn with_max_redirects(mut self, max_redirects: usize) -> Self {
+        self.max_redirects = max_redirects;
+        self
+    }
+
+    /// Date signed requests with the time of `clock` instead of the system clock.
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Wait for the given limits before sending requests to the services they limit.
+    ///
+    /// The limits apply to the requests of this client and of its clones, and of any other
+    /// client given a clone of the same limits. See `ConcurrencyLimits`.
+    pub fn with_concurrency_limits(mut self, limits: ConcurrencyLimits) -> Self {
+        self.concurrency_limits = Some(limits);
+        self
+    }
+
+    /// Hand `sink` a record of each request once it completes.
+    ///
+    /// See the `audit` module.
+    pub fn with_audit_sink<S: AuditSink + 'static>(mut self, sink: S) -> Self {
+        self.audit_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Hand `sink` a record of what was signed and sent for each request once it
+    /// completes, without its signature or credentials.
+    ///
+    /// See the `audit` module.
+    pub fn with_request_record_sink<S: RequestRecordSink + 'static>(mut self, sink: S) -> Self {
+        self.request_record_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Hand `observer` a record of each retry, telling why and for how long the client
+    /// waits before attempting the request again, and a record of the final response to
+    /// each request, telling how many retries it took.
+    pub fn with_retry_observer<O: RetryObserver + 'static>(mut self, observer: O) -> Self {
+        self.retry_observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Sign requests with `algorithm`, unless they were given one with
+    /// `SignedRequest::set_signing_algorithm`.
+    ///
+    /// Services are called with signature version 4 by default. Signature version 4A,
+    /// available with the `sigv4a` feature, is needed to call Multi-Region Access Points.
+    pub fn with_signing_algorithm(mut self, algorithm: SigningAlgorithm) -> Self {
+        self.signing_algorithm = Some(algorithm);
+        self
+    }
+
+    /// Sign the requests which allow it with `UNSIGNED-PAYLOAD` in place of the hash of
+    /// their payload, unless they were told otherwise with
+    /// `SignedRequest::set_unsigned_payload`.
+    ///
+    /// **Warning:** the payloads of unsigned requests are only protected from tampering by
+    /// TLS. Only use this where every request goes over HTTPS, to save the CPU time of
+    /// hashing them. Only S3 accepts unsigned payloads, and only over HTTPS: requests to
+    /// other services, or to endpoints over plain HTTP, are always signed with the hash of
+    /// their payload. Off by default.
+    pub fn with_unsigned_payload(mut self, unsigned: bool) -> Self {
+        self.unsigned_payload = unsigned;
+        self
+    }
+
+    /// Count calls against `budget`, warning about, delaying or failing those which go
+    /// over it before their credentials are fetched.
+    ///
+    /// The budget is shared by the clones of this client, and by any other client given a
+    /// clone of it. Calls it fails end with an `HttpDispatchError` of kind
+    /// `BudgetExceeded`. See the `budget` module.
+    pub fn with_call_budget(mut self, budget: CallBudget) -> Self {
+        self.call_budget = Some(budget);
+        self
+    }
+
+    /// Check requests against every constraint of the service model before sending them.
+    ///
+    /// Length constraints, such as the most items in a batch, are always checked. This also
+    /// checks the range and pattern constraints. Requests which break a constraint fail with
+    /// `RusotoError::Validation` listing every violation instead of being sent. Range and
+    /// pattern validation is off by default.
+    pub fn validate_requests(mut self, validate: bool) -> Self {
+        self.validate_requests = validate;
+        self
+    }
+
+    /// Whether requests are checked against the range and pattern constraints of the service
+    /// model, as well as its length constraints, before they are sent.
+    pub fn validates_requests(&self) -> bool {
+        self.validate_requests
+    }
+
+    /// Stop sending requests, returning a future which resolves once the requests in
+    /// flight have their responses.
+    ///
+    /// Closing a client closes its clones too, including other holders of the shared
+    /// client; `Client::shared` creates a new shared client once it has been closed.
+    /// Requests made with a closed client fail with an `HttpDispatchError` of kind
+    /// `Closed`. Give the future a grace period with `Drain::with_grace_period` to cancel
+    /// the requests still in flight after it.
+    pub fn close(&self) -> Drain {
+        self.inner.lifecycle().close();
+        Drain {
+            lifecycle: self.inner.lifecycle().clone(),
+            grace_period: None,
         }
     }
 
+    /// Whether this client, or one of its clones, has been closed.
+    pub fn is_closed(&self) -> bool {
+        self.inner.lifecycle().is_closed()
+    }
+
+    /// Get the client ready for its first requests to `services` in `region`, returning a
+    /// future which resolves once it is.
+    ///
+    /// Credentials are fetched from the provider. Those which expire are kept by the client
+    /// and its clones, which sign requests with them instead of asking the provider again
+    /// until they are five minutes from expiring, while those which don't are left for the
+    /// provider to cache. An unsigned `HEAD` request is sent to the endpoint of
+    /// each service, such as `"sqs"`, opening a connection the dispatcher can pool; give no
+    /// services to only fetch credentials. Warming up can go on alongside other requests,
+    /// and never fails: the future resolves with a `WarmUpReport` of what failed.
+    pub fn warm_up(&self, region: &Region, services: &[&str]) -> WarmUp {
+        self.inner.warm_up(region, services)
+    }
+
     /// Fetch credentials, sign the request and dispatch it.
     pub fn sign_and_dispatch<T, E>(
         &self,
-        request: SignedRequest,
+        mut request: SignedRequest,
         response_handler: fn(
             HttpResponse,
         ) -> Box<dyn Future<Item = T, Error = RusotoError<E>> + Send>,
     ) -> RusotoFuture<T, E> {
-        future::new(self.inner.sign_and_dispatch(request), response_handler)
+        if request.signing_algorithm.is_none() {
+            request.signing_algorithm = self.signing_algorithm.clone();
+        }
+        if request.unsigned_payload.is_none() {
+            request.unsigned_payload = Some(self.unsigned_payload);
+        }
+        let verdict = match self.call_budget {
+            Some(ref budget) => {
+                budget.charge(&request.service, request.operation(), Instant::now())
+            }
+            None => Verdict::Allow,
+        };
+        let delay = match verdict {
+            Verdict::Allow => None,
+            Verdict::Delay(delay) => Some(Delay::new(Instant::now() + delay)),
+            Verdict::Fail(exceeded) => {
+                let blocked = OverBudget {
+                    error: Some(HttpDispatchError::budget_exceeded(exceeded)),
+                };
+                return future::new(Box::new(blocked), response_handler);
+            }
+        };
+        let future = self.inner.sign_and_dispatch(
+            request,
+            self.retry_policy.clone(),
+            self.max_redirects,
+            self.clock.clone(),
+            self.concurrency_limits.clone(),
+            self.audit_sink.clone(),
+            self.request_record_sink.clone(),
+            self.retry_observer.clone(),
+        );
+        match delay {
+            Some(delay) => future::new(Box::new(Throttled { delay, future }), response_handler),
+            None => future::new(future, response_handler),
+        }
+    }
+}
+
+/// A call failed by the budget of its client, without being sent.
+struct OverBudget {
+    error: Option<HttpDispatchError>,
+}
+
+impl Future for OverBudget {
+    type Item = HttpResponse;
+    type Error = SignAndDispatchError;
+
+    fn poll(&mut self) -> Poll<HttpResponse, SignAndDispatchError> {
+        let error = self.error.take().expect("polled after completion");
+        Err(SignAndDispatchError::Dispatch(error))
+    }
+}
+
+impl TimeoutFuture for OverBudget {
+    fn set_timeout(&mut self, _timeout: Duration) {}
+    fn clear_timeout(&mut self) {}
+    fn set_retry_policy(&mut self, _retry_policy: RetryPolicy) {}
+    fn add_header(&mut self, _name: &str, _value: &str) {}
+    fn set_accept(&mut self, _accept: &str) {}
+    fn add_param(&mut self, _key: &str, _value: Option<&str>) {}
+    fn customize(&mut self, _customize: &mut dyn FnMut(&mut SignedRequest)) {}
+}
+
+/// A call delayed by the budget of its client until the window has room for it.
+struct Throttled {
+    delay: Delay,
+    future: Box<dyn TimeoutFuture<Item = HttpResponse, Error = SignAndDispatchError> + Send>,
+}
+
+impl Future for Throttled {
+    type Item = HttpResponse;
+    type Error = SignAndDispatchError;
+
+    fn poll(&mut self) -> Poll<HttpResponse, SignAndDispatchError> {
+        match self.delay.poll() {
+            Ok(Async::NotReady) => return Ok(Async::NotReady),
+            Ok(Async::Ready(())) => {}
+            Err(err) => {
+                return Err(SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                    format!("budget delay error: {}", err),
+                )))
+            }
+        }
+        self.future.poll()
+    }
+}
+
+impl TimeoutFuture for Throttled {
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.future.set_timeout(timeout);
+    }
+
+    fn clear_timeout(&mut self) {
+        self.future.clear_timeout();
+    }
+
+    fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.future.set_retry_policy(retry_policy);
+    }
+
+    fn add_header(&mut self, name: &str, value: &str) {
+        self.future.add_header(name, value);
+    }
+
+    fn set_accept(&mut self, accept: &str) {
+        self.future.set_accept(accept);
+    }
+
+    fn add_param(&mut self, key: &str, value: Option<&str>) {
+        self.future.add_param(key, value);
+    }
+
+    fn customize(&mut self, customize: &mut dyn FnMut(&mut SignedRequest)) {
+        self.future.customize(customize);
+    }
+}
+
+/// A future which resolves once the requests in flight through a closed client have
+/// their responses, returned by `Client::close`.
+pub struct Drain {
+    lifecycle: Arc<Lifecycle>,
+    grace_period: Option<Delay>,
+}
+
+impl Drain {
+    /// Cancel the requests still in flight once `grace_period` has passed.
+    pub fn with_grace_period(mut self, grace_period: Duration) -> Self {
+        self.grace_period = Some(Delay::new(Instant::now() + grace_period));
+        self
+    }
+}
+
+/// A future which resolves once a client is ready for its first requests, returned by
+/// `Client::warm_up`.
+pub struct WarmUp {
+    inner: Box<dyn Future<Item = WarmUpReport, Error = ()> + Send>,
+}
+
+impl Future for WarmUp {
+    type Item = WarmUpReport;
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+/// What `Client::warm_up` got ready.
+#[derive(Debug)]
+pub struct WarmUpReport {
+    /// Whether credentials were fetched, or `None` for a client which doesn't sign requests.
+    pub credentials: Option<Result<(), CredentialsError>>,
+    /// Whether the endpoint of each service answered, in the order the services were given.
+    pub endpoints: Vec<EndpointWarmUp>,
+}
+
+impl WarmUpReport {
+    /// Whether credentials were fetched, if needed, and every endpoint answered.
+    pub fn is_ready(&self) -> bool {
+        self.credentials.as_ref().map_or(true, Result::is_ok)
+            && self
+                .endpoints
+                .iter()
+                .all(|endpoint| endpoint.result.is_ok())
+    }
+}
+
+/// Whether the endpoint of a service answered `Client::warm_up`.
+#[derive(Debug)]
+pub struct EndpointWarmUp {
+    /// The name of the service, as given to `Client::warm_up`.
+    pub service: String,
+    /// `Ok` if the endpoint answered, whatever its status.
+    pub result: Result<(), HttpDispatchError>,
+}
+
+/// How the requests in flight through a closed client came to an end.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DrainOutcome {
+    /// Every request had its response.
+    Completed,
+    /// The grace period passed, cancelling this many requests.
+    Cancelled(usize),
+}
+
+impl Future for Drain {
+    type Item = DrainOutcome;
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        if self.lifecycle.park_drain() {
+            return Ok(Async::Ready(DrainOutcome::Completed));
+        }
+        match self.grace_period.as_mut().map(Delay::poll) {
+            None | Some(Ok(Async::NotReady)) => Ok(Async::NotReady),
+            Some(Ok(Async::Ready(()))) | Some(Err(_)) => Ok(Async::Ready(DrainOutcome::Cancelled(
+                self.lifecycle.cancel(),
+            ))),
+        }
+    }
+}
+
+/// Whether a client has been closed, and the requests in flight through it.
+#[derive(Default)]
+struct Lifecycle {
+    state: Mutex<LifecycleState>,
+}
+
+#[derive(Default)]
+struct LifecycleState {
+    closed: bool,
+    cancelled: bool,
+    next_id: usize,
+    /// The requests in flight, with the task of the last one to poll them.
+    requests: HashMap<usize, Option<Task>>,
+    /// The tasks waiting for the requests in flight to end.
+    drains: Vec<Task>,
+}
+
+impl Lifecycle {
+    fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+    }
+
+    fn is_closed(&self) -> bool {
+        self.state.lock().unwrap().closed
+    }
+
+    /// Records a request in flight, unless the client is closed.
+    fn start(lifecycle: &Arc<Lifecycle>) -> Option<InFlight> {
+        let mut state = lifecycle.state.lock().unwrap();
+        if state.closed {
+            return None;
+        }
+        let id = state.next_id;
+        state.next_id += 1;
+        state.requests.insert(id, None);
+        Some(InFlight {
+            lifecycle: lifecycle.clone(),
+            id,
+        })
+    }
+
+    /// Whether no request is in flight, registering the current task to be notified
+    /// once none is otherwise.
+    fn park_drain(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.requests.is_empty() {
+            return true;
+        }
+        state.drains.push(task::current());
+        false
+    }
+
+    /// Cancels the requests in flight, returning how many there were.
+    fn cancel(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+        state.cancelled = true;
+        for task in state.requests.values().flatten() {
+            task.notify();
+        }
+        state.requests.len()
+    }
+}
+
+/// A request in flight, until it is dropped.
+struct InFlight {
+    lifecycle: Arc<Lifecycle>,
+    id: usize,
+}
+
+impl InFlight {
+    fn is_cancelled(&self) -> bool {
+        self.lifecycle.state.lock().unwrap().cancelled
+    }
+
+    /// Registers the current task to be notified if the request is cancelled.
+    fn park(&self) {
+        let mut state = self.lifecycle.state.lock().unwrap();
+        if let Some(task) = state.requests.get_mut(&self.id) {
+            *task = Some(task::current());
+        }
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        let mut state = self.lifecycle.state.lock().unwrap();
+        state.requests.remove(&self.id);
+        if state.requests.is_empty() {
+            for task in state.drains.drain(..) {
+                task.notify();
+            }
+        }
     }
 }
 
@@ -97,17 +615,65 @@ trait SignAndDispatch {
     fn sign_and_dispatch(
         &self,
         request: SignedRequest,
+        retry_policy: RetryPolicy,
+        max_redirects: usize,
+        clock: Arc<dyn Clock>,
+        concurrency_limits: Option<ConcurrencyLimits>,
+        audit_sink: Option<Arc<dyn AuditSink>>,
+        request_record_sink: Option<Arc<dyn RequestRecordSink>>,
+        retry_observer: Option<Arc<dyn RetryObserver>>,
     ) -> Box<dyn TimeoutFuture<Item = HttpResponse, Error = SignAndDispatchError> + Send>;
+
+    fn warm_up(&self, region: &Region, services: &[&str]) -> WarmUp;
+
+    fn lifecycle(&self) -> &Arc<Lifecycle>;
 }
 
 pub trait TimeoutFuture: Future {
     fn set_timeout(&mut self, timeout: Duration);
     fn clear_timeout(&mut self);
+    fn set_retry_policy(&mut self, retry_policy: RetryPolicy);
+    fn add_header(&mut self, name: &str, value: &str);
+    fn set_accept(&mut self, accept: &str);
+    fn add_param(&mut self, key: &str, value: Option<&str>);
+    fn customize(&mut self, customize: &mut dyn FnMut(&mut SignedRequest));
 }
 
 struct ClientInner<P, D> {
     credentials_provider: Option<Arc<P>>,
     dispatcher: Arc<D>,
+    lifecycle: Arc<Lifecycle>,
+    /// The credentials fetched by the last warm-up, used instead of the provider's.
+    warm_credentials: Arc<Mutex<Option<AwsCredentials>>>,
+}
+
+impl<P, D> ClientInner<P, D> {
+    fn new(credentials_provider: Option<Arc<P>>, dispatcher: D) -> Self {
+        ClientInner {
+            credentials_provider,
+            dispatcher: Arc::new(dispatcher),
+            lifecycle: Arc::default(),
+            warm_credentials: Arc::default(),
+        }
+    }
+
+    /// The credentials fetched by the last warm-up, unless they are about to expire.
+    ///
+    /// Only credentials with an expiry are kept, as nothing tells when the provider would
+    /// give others in place of those without one.
+    fn warm_credentials(&self, clock: &dyn Clock) -> Option<AwsCredentials> {
+        let mut warm_credentials = self.warm_credentials.lock().unwrap();
+        let fresh = match *warm_credentials {
+            Some(ref credentials) => credentials.expires_at().map_or(false, |expires_at| {
+                expires_at.timestamp() - clock.now().timestamp() > WARM_CREDENTIALS_MARGIN_SECS
+            }),
+            None => return None,
+        };
+        if !fresh {
+            *warm_credentials = None;
+        }
+        warm_credentials.clone()
+    }
 }
 
 impl<P, D> Clone for ClientInner<P, D> {
@@ -115,6 +681,8 @@ impl<P, D> Clone for ClientInner<P, D> {
         ClientInner {
             credentials_provider: self.credentials_provider.clone(),
             dispatcher: self.dispatcher.clone(),
+            lifecycle: self.lifecycle.clone(),
+            warm_credentials: self.warm_credentials.clone(),
         }
     }
 }
@@ -129,19 +697,236 @@ where
     fn sign_and_dispatch(
         &self,
         request: SignedRequest,
+        retry_policy: RetryPolicy,
+        max_redirects: usize,
+        clock: Arc<dyn Clock>,
+        concurrency_limits: Option<ConcurrencyLimits>,
+        audit_sink: Option<Arc<dyn AuditSink>>,
+        request_record_sink: Option<Arc<dyn RequestRecordSink>>,
+        retry_observer: Option<Arc<dyn RetryObserver>>,
     ) -> Box<dyn TimeoutFuture<Item = HttpResponse, Error = SignAndDispatchError> + Send> {
+        let config = request.operation_config().clone();
+        let audit = audit_sink.map(|sink| Audit {
+            sink,
+            record: AuditRecord::new(&request),
+            started: None,
+        });
+        let request_record = request_record_sink.map(|sink| (sink, RequestRecord::new(&request)));
+        let response_record = retry_observer
+            .as_ref()
+            .map(|_| ResponseRecord::new(&request));
         Box::new(SignAndDispatchFuture {
             inner: self.clone(),
             state: Some(SignAndDispatchState::Lazy { request }),
-            timeout: None,
+            timeout: config.timeout,
+            retry_policy: config.retry_policy_override.unwrap_or(retry_policy),
+            attempt: 0,
+            max_redirects,
+            redirects: 0,
+            clock,
+            retry_request: None,
+            unreplayable_payload: false,
+            url: None,
+            concurrency_limits,
+            permit: None,
+            in_flight: Lifecycle::start(&self.lifecycle),
+            audit,
+            request_record,
+            retry_observer,
+            bytes_sent: response_record.as_ref().map(|_| Arc::default()),
+            response_record,
         })
     }
+
+    fn warm_up(&self, region: &Region, services: &[&str]) -> WarmUp {
+        let credentials = match self.credentials_provider {
+            Some(ref provider) => {
+                let warm_credentials = self.warm_credentials.clone();
+                let future = provider.credentials().then(move |result| {
+                    Ok(Some(result.map(|credentials| {
+                        *warm_credentials.lock().unwrap() = Some(credentials);
+                    })))
+                });
+                Box::new(future) as Box<dyn Future<Item = _, Error = ()> + Send>
+            }
+            None => Box::new(futures::future::ok(None)),
+        };
+        let closed = self.lifecycle.is_closed();
+        let endpoints = services.iter().map(|service| {
+            let service = (*service).to_owned();
+            let future = if closed {
+                let err = HttpDispatchError::with_kind(
+                    "the client is closed".to_owned(),
+                    HttpDispatchErrorKind::Closed,
+                );
+                Box::new(futures::future::err(err)) as Box<dyn Future<Item = _, Error = _> + Send>
+            } else {
+                let mut request = SignedRequest::new("HEAD", &service, region, "/");
+                request.complement_with_plus(true);
+                Box::new(
+                    self.dispatcher
+                        .dispatch(request, Some(WARM_UP_TIMEOUT))
+                        .map(|_| ()),
+                )
+            };
+            future.then(move |result| {
+                Ok(EndpointWarmUp {
+                    service,
+                    result: result.map(|_| ()),
+                })
+            })
+        });
+        let endpoints = join_all(endpoints.collect::<Vec<_>>());
+        WarmUp {
+            inner: Box::new(credentials.join(endpoints).map(|(credentials, endpoints)| {
+                WarmUpReport {
+                    credentials,
+                    endpoints,
+                }
+            })),
+        }
+    }
+
+    fn lifecycle(&self) -> &Arc<Lifecycle> {
+        &self.lifecycle
+    }
 }
 
 pub struct SignAndDispatchFuture<P: ProvideAwsCredentials, D: DispatchSignedRequest> {
     inner: ClientInner<P, D>,
     state: Option<SignAndDispatchState<P, D>>,
     timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    /// The number of the current attempt, starting at 1.
+    attempt: usize,
+    max_redirects: usize,
+    /// The number of redirects followed so far.
+    redirects: usize,
+    /// The clock dating signed requests.
+    clock: Arc<dyn Clock>,
+    /// An unsigned copy of the request, kept while it can still be retried or redirected.
+    retry_request: Option<SignedRequest>,
+    /// Whether the request can't be copied because its payload isn't replayable.
+    unreplayable_payload: bool,
+    /// The URL of the request being dispatched.
+    url: Option<String>,
+    /// The limits the request waits for before being sent.
+    concurrency_limits: Option<ConcurrencyLimits>,
+    /// The permit of the limits for the request in flight.
+    permit: Option<Permit>,
+    /// The record of the request in flight, or `None` if the client was closed.
+    in_flight: Option<InFlight>,
+    /// The audit record of the request, if the client has an audit sink.
+    audit: Option<Audit>,
+    /// The record of what was sent, if the client has a request record sink.
+    request_record: Option<(Arc<dyn RequestRecordSink>, RequestRecord)>,
+    retry_observer: Option<Arc<dyn RetryObserver>>,
+    /// The record of the response handed to the retry observer, if the client has one.
+    response_record: Option<ResponseRecord>,
+    /// The bytes of the payloads sent so far, counted if the client has a retry observer.
+    bytes_sent: Option<Arc<AtomicU64>>,
+}
+
+/// The audit record of a request in flight.
+struct Audit {
+    sink: Arc<dyn AuditSink>,
+    record: AuditRecord,
+    /// When the request was first polled.
+    started: Option<Instant>,
+}
+
+impl Audit {
+    /// Completes the record with the outcome of the request and hands it to the sink.
+    fn finish(mut self, result: Result<&HttpResponse, &SignAndDispatchError>) {
+        let started = self.started.unwrap_or_else(Instant::now);
+        self.record.set_duration(started.elapsed());
+        match result {
+            Ok(response) => {
+                self.record.status = Some(response.status.as_u16());
+                self.record.request_id = response.request_id().map(ToOwned::to_owned);
+            }
+            Err(SignAndDispatchError::Credentials(err)) => {
+                self.record.error = Some(err.to_string())
+            }
+            Err(SignAndDispatchError::Dispatch(err)) => self.record.error = Some(err.to_string()),
+        }
+        self.sink.record(&self.record);
+    }
+}
+
+impl<P, D> SignAndDispatchFuture<P, D>
+where
+    P: ProvideAwsCredentials,
+    D: DispatchSignedRequest,
+{
+    /// Waits before making the next attempt after the current one got `failure`, if the
+    /// request can be sent again.
+    fn backoff(&mut self, failure: Result<&HttpResponse, &HttpDispatchError>) -> bool {
+        let request = match self.retry_request {
+            Some(ref request) => request,
+            None => return false,
+        };
+        if !self.retry_policy.spend_retry() {
+            debug!(
+                "Not retrying after attempt {}: retry budget exhausted",
+                self.attempt
+            );
+            return false;
+        }
+        let (delay, retry_after) = match failure {
+            Ok(response) => self
+                .retry_policy
+                .backoff_after(self.attempt, &response.headers),
+            Err(_) => (self.retry_policy.backoff(self.attempt), false),
+        };
+        if let Some(ref observer) = self.retry_observer {
+            let mut record = RetryRecord::new(request, self.attempt, delay, retry_after);
+            match failure {
+                Ok(response) => {
+                    record.status = Some(response.status.as_u16());
+                    record.request_id = response.request_id().map(ToOwned::to_owned);
+                }
+                Err(err) => record.error = Some(err.to_string()),
+            }
+            observer.retry(&record);
+        }
+        let delay = Delay::new(Instant::now() + delay);
+        self.state = Some(SignAndDispatchState::Backoff { delay });
+        true
+    }
+
+    /// Sends the request again to the location of a temporary redirect, if it can be.
+    fn redirect(&mut self, response: &HttpResponse) -> bool {
+        if response.status != StatusCode::TEMPORARY_REDIRECT
+            || self.redirects >= self.max_redirects
+            || self.retry_request.is_none()
+        {
+            return false;
+        }
+        let location = match response
+            .headers
+            .get("location")
+            .and_then(|location| location.parse::<Uri>().ok())
+        {
+            Some(location) => location,
+            None => return false,
+        };
+        let host = match location.authority_part() {
+            Some(authority) => authority.as_str().to_owned(),
+            None => return false,
+        };
+
+        let mut request = self.retry_request.take().unwrap();
+        request.set_hostname(Some(host));
+        if let Some(scheme) = location.scheme_part() {
+            request.scheme = Some(scheme.as_str().to_owned());
+        }
+        self.redirects += 1;
+        // the redirected request is part of the same attempt
+        self.attempt -= 1;
+        self.state = Some(SignAndDispatchState::Lazy { request });
+        true
+    }
 }
 
 impl<P, D> TimeoutFuture for SignAndDispatchFuture<P, D>
@@ -156,6 +941,52 @@ where
     fn clear_timeout(&mut self) {
         self.timeout = None;
     }
+
+    fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    fn add_header(&mut self, name: &str, value: &str) {
+        if let Some(SignAndDispatchState::Lazy { ref mut request })
+        | Some(SignAndDispatchState::Queued {
+            ref mut request, ..
+        }) = self.state
+        {
+            request.add_header(name, value);
+        }
+    }
+
+    fn set_accept(&mut self, accept: &str) {
+        if let Some(SignAndDispatchState::Lazy { ref mut request })
+        | Some(SignAndDispatchState::Queued {
+            ref mut request, ..
+        }) = self.state
+        {
+            request.set_accept(accept.to_owned());
+        }
+    }
+
+    fn add_param(&mut self, key: &str, value: Option<&str>) {
+        if let Some(SignAndDispatchState::Lazy { ref mut request })
+        | Some(SignAndDispatchState::Queued {
+            ref mut request, ..
+        }) = self.state
+        {
+            request
+                .params
+                .insert(key.to_owned(), value.map(ToOwned::to_owned));
+        }
+    }
+
+    fn customize(&mut self, customize: &mut dyn FnMut(&mut SignedRequest)) {
+        if let Some(SignAndDispatchState::Lazy { ref mut request })
+        | Some(SignAndDispatchState::Queued {
+            ref mut request, ..
+        }) = self.state
+        {
+            customize(request);
+        }
+    }
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -163,6 +994,10 @@ enum SignAndDispatchState<P: ProvideAwsCredentials, D: DispatchSignedRequest> {
     Lazy {
         request: SignedRequest,
     },
+    Queued {
+        future: PermitFuture,
+        request: SignedRequest,
+    },
     FetchingCredentials {
         future: P::Future,
         request: SignedRequest,
@@ -170,6 +1005,14 @@ enum SignAndDispatchState<P: ProvideAwsCredentials, D: DispatchSignedRequest> {
     Dispatching {
         future: D::Future,
     },
+    /// Reading the body of a response within its attempt.
+    Reading {
+        response: HttpResponse,
+        body: Concat2<ByteStream>,
+    },
+    Backoff {
+        delay: Delay,
+    },
 }
 
 impl<P, D> Future for SignAndDispatchFuture<P, D>
@@ -181,54 +1024,1930 @@ where
     type Error = SignAndDispatchError;
 
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
-        match self.state.take().unwrap() {
-            SignAndDispatchState::Lazy { mut request } => {
-                match self.inner.credentials_provider.as_ref() {
-                    Some(p) => {
-                        let future = p.credentials();
-                        self.state =
-                            Some(SignAndDispatchState::FetchingCredentials { future, request });
-                    }
-                    None => {
-                        request.complement_with_plus(true);
-                        let future = self.inner.dispatcher.dispatch(request, self.timeout);
-                        self.state = Some(SignAndDispatchState::Dispatching { future });
-                    }
+        if let Some(ref mut audit) = self.audit {
+            audit.started.get_or_insert_with(Instant::now);
+        }
+        let result = self.poll_in_flight();
+        let outcome = match result {
+            Ok(Async::NotReady) => return result,
+            Ok(Async::Ready(ref response)) => Ok(response),
+            Err(ref err) => Err(err),
+        };
+        if let Some(audit) = self.audit.take() {
+            audit.finish(outcome);
+        }
+        if let Some((sink, mut record)) = self.request_record.take() {
+            match outcome {
+                Ok(response) => {
+                    record.status = Some(response.status.as_u16());
+                    record.request_id = response.request_id().map(ToOwned::to_owned);
                 }
-                self.poll()
+                Err(SignAndDispatchError::Credentials(err)) => record.error = Some(err.to_string()),
+                Err(SignAndDispatchError::Dispatch(err)) => record.error = Some(err.to_string()),
+            }
+            sink.record(&record);
+        }
+        let mut transfer = None;
+        if let (Ok(response), Some(mut record)) = (outcome, self.response_record.take()) {
+            record.retries = self.attempt.saturating_sub(1);
+            record.status = response.status.as_u16();
+            record.request_id = response.request_id().map(ToOwned::to_owned);
+            if let Some(ref observer) = self.retry_observer {
+                observer.responded(&record);
+                transfer = Some((observer.clone(), record));
             }
-            SignAndDispatchState::FetchingCredentials {
-                mut future,
-                mut request,
-            } => match future.poll() {
-                Err(err) => Err(SignAndDispatchError::Credentials(err)),
-                Ok(Async::NotReady) => {
-                    self.state =
-                        Some(SignAndDispatchState::FetchingCredentials { future, request });
-                    Ok(Async::NotReady)
-                }
-                Ok(Async::Ready(credentials)) => {
-                    request.sign_with_plus(&credentials, true);
-                    let future = self.inner.dispatcher.dispatch(request, self.timeout);
-                    self.state = Some(SignAndDispatchState::Dispatching { future });
-                    self.poll()
-                }
-            },
-            SignAndDispatchState::Dispatching { mut future } => match future.poll() {
-                Err(err) => Err(SignAndDispatchError::Dispatch(err)),
-                Ok(Async::NotReady) => {
-                    self.state = Some(SignAndDispatchState::Dispatching { future });
-                    Ok(Async::NotReady)
-                }
-                Ok(Async::Ready(response)) => Ok(Async::Ready(response)),
-            },
+        }
+        match (result, transfer) {
+            (Ok(Async::Ready(mut response)), Some((observer, record))) => {
+                let bytes_sent = self
+                    .bytes_sent
+                    .as_ref()
+                    .map_or(0, |bytes_sent| bytes_sent.load(Ordering::Relaxed));
+                let size_hint = response.body.size_hint();
+                let body = ObservedBody {
+                    inner: mem::replace(&mut response.body, ByteStream::from(Vec::new())),
+                    observer,
+                    record: Some(TransferRecord {
+                        service: record.service,
+                        operation: record.operation,
+                        status: record.status,
+                        request_id: record.request_id,
+                        bytes_sent,
+                        bytes_received: 0,
+                        complete: false,
+                    }),
+                };
+                response.body = match size_hint {
+                    Some(size) => ByteStream::new(body).with_size(size),
+                    None => ByteStream::new(body),
+                };
+                Ok(Async::Ready(response))
+            }
+            (result, _) => result,
         }
     }
 }
 
-#[test]
-fn client_is_send_and_sync() {
-    fn is_send_and_sync<T: Send + Sync>() {}
+/// The body of a final response, telling the retry observer of the bytes transferred by
+/// its request once it has been read or dropped.
+struct ObservedBody {
+    inner: ByteStream,
+    observer: Arc<dyn RetryObserver>,
+    record: Option<TransferRecord>,
+}
 
-    is_send_and_sync::<Client>();
+impl ObservedBody {
+    fn finish(&mut self, complete: bool) {
+        if let Some(mut record) = self.record.take() {
+            record.complete = complete;
+            self.observer.transferred(&record);
+        }
+    }
+}
+
+impl Stream for ObservedBody {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Bytes>, io::Error> {
+        let polled = self.inner.poll()?;
+        match polled {
+            Async::Ready(Some(ref bytes)) => {
+                if let Some(ref mut record) = self.record {
+                    record.bytes_received += bytes.len() as u64;
+                }
+            }
+            Async::Ready(None) => self.finish(true),
+            Async::NotReady => {}
+        }
+        Ok(polled)
+    }
+}
+
+impl Drop for ObservedBody {
+    fn drop(&mut self) {
+        self.finish(false);
+    }
+}
+
+impl<P, D> SignAndDispatchFuture<P, D>
+where
+    P: ProvideAwsCredentials,
+    D: DispatchSignedRequest,
+{
+    /// Polls the request unless its client was closed, keeping track of it while in flight.
+    fn poll_in_flight(&mut self) -> Poll<HttpResponse, SignAndDispatchError> {
+        let cancelled = match self.in_flight {
+            None => {
+                return Err(SignAndDispatchError::Dispatch(
+                    HttpDispatchError::with_kind(
+                        "the client is closed".to_owned(),
+                        HttpDispatchErrorKind::Closed,
+                    ),
+                ))
+            }
+            Some(ref in_flight) => in_flight.is_cancelled(),
+        };
+        if cancelled {
+            self.state = None;
+            self.permit = None;
+            self.in_flight = None;
+            return Err(SignAndDispatchError::Dispatch(
+                HttpDispatchError::with_kind(
+                    "the request was cancelled by closing its client".to_owned(),
+                    HttpDispatchErrorKind::Cancelled,
+                ),
+            ));
+        }
+        let result = self.poll_state();
+        match result {
+            Ok(Async::NotReady) => {
+                if let Some(ref in_flight) = self.in_flight {
+                    in_flight.park();
+                }
+            }
+            _ => self.in_flight = None,
+        }
+        result
+    }
+}
+
+impl<P, D> SignAndDispatchFuture<P, D>
+where
+    P: ProvideAwsCredentials,
+    D: DispatchSignedRequest,
+{
+    /// Signs the request with `credentials` and dispatches it.
+    fn sign_and_send(&mut self, mut request: SignedRequest, credentials: &AwsCredentials) {
+        if let Some(ref mut audit) = self.audit {
+            audit.record.access_key_id = Some(credentials.aws_access_key_id().to_owned());
+        }
+        match self.request_record {
+            Some((_, ref mut record)) => {
+                let signing =
+                    request.sign_traced(credentials, true, clock_time(&*self.clock), None);
+                record.sent(&request, Some(&signing.canonical_request));
+            }
+            None => request.sign_with_clock(credentials, true, &*self.clock),
+        }
+        self.dispatch(request);
+    }
+
+    /// Dispatches the request, counting the bytes of its payload for the retry observer.
+    fn dispatch(&mut self, mut request: SignedRequest) {
+        if let Some(ref bytes_sent) = self.bytes_sent {
+            request.payload = match request.payload.take() {
+                Some(SignedRequestPayload::Buffer(buffer)) => {
+                    bytes_sent.fetch_add(buffer.len() as u64, Ordering::Relaxed);
+                    Some(SignedRequestPayload::Buffer(buffer))
+                }
+                Some(SignedRequestPayload::Stream(stream)) => Some(SignedRequestPayload::Stream(
+                    stream.counted(bytes_sent.clone()),
+                )),
+                None => None,
+            };
+        }
+        self.url = Some(request.url());
+        let future = self.inner.dispatcher.dispatch(request, self.timeout);
+        self.state = Some(SignAndDispatchState::Dispatching { future });
+    }
+
+    /// Retries the request after the current attempt failed with `err`, if it can be.
+    fn failed(&mut self, err: HttpDispatchError) -> Poll<HttpResponse, SignAndDispatchError> {
+        if !self.retry_policy.retries_dispatch_error(self.attempt, &err) {
+            return Err(SignAndDispatchError::Dispatch(err));
+        }
+        if self.backoff(Err(&err)) {
+            debug!(
+                "Retrying request after attempt {} failed: {}",
+                self.attempt, err
+            );
+            return self.poll_state();
+        }
+        if self.unreplayable_payload {
+            return Err(SignAndDispatchError::Dispatch(
+                HttpDispatchError::payload_not_replayable(err),
+            ));
+        }
+        Err(SignAndDispatchError::Dispatch(err))
+    }
+
+    fn poll_state(&mut self) -> Poll<HttpResponse, SignAndDispatchError> {
+        match self.state.take().unwrap() {
+            SignAndDispatchState::Lazy { mut request } => {
+                if self.permit.is_none() {
+                    let permit = self
+                        .concurrency_limits
+                        .as_ref()
+                        .and_then(|limits| limits.acquire(&request.service));
+                    if let Some(future) = permit {
+                        self.state = Some(SignAndDispatchState::Queued { future, request });
+                        return self.poll_state();
+                    }
+                }
+                self.attempt += 1;
+                self.retry_request = if self.attempt < self.retry_policy.max_attempts()
+                    || self.redirects < self.max_redirects
+                {
+                    let retry_request = request.try_clone();
+                    if retry_request.is_none() && !self.unreplayable_payload {
+                        self.unreplayable_payload = true;
+                        warn!(
+                            "Request payload is a stream which isn't replayable, \
+                             so the request won't be retried"
+                        );
+                    }
+                    retry_request
+                } else {
+                    None
+                };
+                match self.inner.credentials_provider.as_ref() {
+                    Some(p) => match self.inner.warm_credentials(&*self.clock) {
+                        Some(credentials) => self.sign_and_send(request, &credentials),
+                        None => {
+                            let future = p.credentials();
+                            self.state =
+                                Some(SignAndDispatchState::FetchingCredentials { future, request });
+                        }
+                    },
+                    None => {
+                        request.complement_with_plus(true);
+                        if let Some((_, ref mut record)) = self.request_record {
+                            record.sent(&request, None);
+                        }
+                        self.dispatch(request);
+                    }
+                }
+                self.poll_state()
+            }
+            SignAndDispatchState::Queued {
+                mut future,
+                request,
+            } => match future.poll() {
+                Err(()) => Err(SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                    "concurrency limit error".to_owned(),
+                ))),
+                Ok(Async::NotReady) => {
+                    self.state = Some(SignAndDispatchState::Queued { future, request });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(permit)) => {
+                    self.permit = Some(permit);
+                    self.state = Some(SignAndDispatchState::Lazy { request });
+                    self.poll_state()
+                }
+            },
+            SignAndDispatchState::FetchingCredentials {
+                mut future,
+                request,
+            } => match future.poll() {
+                Err(err) => Err(SignAndDispatchError::Credentials(err)),
+                Ok(Async::NotReady) => {
+                    self.state =
+                        Some(SignAndDispatchState::FetchingCredentials { future, request });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(credentials)) => {
+                    self.sign_and_send(request, &credentials);
+                    self.poll_state()
+                }
+            },
+            SignAndDispatchState::Dispatching { mut future } => match future.poll() {
+                Err(err) => {
+                    self.permit = None;
+                    self.failed(err)
+                }
+                Ok(Async::NotReady) => {
+                    self.state = Some(SignAndDispatchState::Dispatching { future });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(mut response)) => {
+                    self.permit = None;
+                    if response.url.is_none() {
+                        response.url = self.url.take();
+                    }
+                    if self.redirect(&response) {
+                        debug!("Following temporary redirect {}", self.redirects);
+                        return self.poll_state();
+                    }
+                    if self
+                        .retry_policy
+                        .retries_status(self.attempt, response.status)
+                        && self.backoff(Ok(&response))
+                    {
+                        debug!(
+                            "Retrying request after attempt {} got status {}",
+                            self.attempt, response.status
+                        );
+                        return self.poll_state();
+                    }
+                    let reads_body = self.retry_request.as_ref().map_or(false, |request| {
+                        self.retry_policy
+                            .reads_body_within_attempt(self.attempt, request.method())
+                    });
+                    if reads_body {
+                        let body = mem::replace(&mut response.body, ByteStream::from(Vec::new()));
+                        self.state = Some(SignAndDispatchState::Reading {
+                            response,
+                            body: body.concat2(),
+                        });
+                        return self.poll_state();
+                    }
+                    Ok(Async::Ready(response))
+                }
+            },
+            SignAndDispatchState::Reading {
+                mut response,
+                mut body,
+            } => match body.poll() {
+                Err(err) => self.failed(HttpDispatchError::from(err)),
+                Ok(Async::NotReady) => {
+                    self.state = Some(SignAndDispatchState::Reading { response, body });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(body)) => {
+                    response.body = ByteStream::from(body);
+                    Ok(Async::Ready(response))
+                }
+            },
+            SignAndDispatchState::Backoff { mut delay } => match delay.poll() {
+                Err(err) => Err(SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                    format!("retry backoff error: {}", err),
+                ))),
+                Ok(Async::NotReady) => {
+                    self.state = Some(SignAndDispatchState::Backoff { delay });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(())) => {
+                    let request = self
+                        .retry_request
+                        .take()
+                        .expect("retried request without a copy of it");
+                    self.state = Some(SignAndDispatchState::Lazy { request });
+                    self.poll_state()
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budget::{BudgetAction, BudgetExceeded, BudgetScope};
+    use crate::cancellation::CancellationToken;
+    use crate::concurrency::ServiceLimit;
+    use crate::credential::{AutoRefreshingProvider, AwsCredentials, ManualClock};
+    use crate::region::Region;
+    use crate::request::{HttpConfig, HttpDispatchErrorKind};
+    use crate::retry::RetryBudget;
+    use crate::signature::{to_hexdigest, SignedRequestPayload};
+    use crate::stream::{ByteStream, FileBody};
+    use bytes::Bytes;
+    use chrono::{DateTime, Utc};
+    use futures::future::{self, FutureResult};
+    use futures::Stream;
+    use http::{HeaderMap, StatusCode};
+    use std::collections::BTreeMap;
+    use std::error::Error;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::{env, fs, process, str};
+
+    #[test]
+    fn client_is_send_and_sync() {
+        fn is_send_and_sync<T: Send + Sync>() {}
+
+        is_send_and_sync::<Client>();
+    }
+
+    /// Answers with the given statuses in order, then with 200.
+    struct StatusDispatcher {
+        statuses: Mutex<Vec<u16>>,
+        attempts: Arc<AtomicUsize>,
+    }
+
+    impl StatusDispatcher {
+        fn new(statuses: &[u16]) -> (Self, Arc<AtomicUsize>) {
+            let attempts = Arc::new(AtomicUsize::new(0));
+            let dispatcher = StatusDispatcher {
+                statuses: Mutex::new(statuses.iter().rev().cloned().collect()),
+                attempts: attempts.clone(),
+            };
+            (dispatcher, attempts)
+        }
+    }
+
+    impl DispatchSignedRequest for StatusDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, _request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            let status = self.statuses.lock().unwrap().pop().unwrap_or(200);
+            let mut headers = HeaderMap::default();
+            if status == 429 {
+                headers.insert("retry-after", "0".to_owned());
+            }
+            future::ok(HttpResponse {
+                status: StatusCode::from_u16(status).unwrap(),
+                body: ByteStream::from(Vec::new()),
+                headers,
+                url: None,
+            })
+        }
+    }
+
+    /// Reads the payload of each attempt, failing the first attempt with the given error.
+    struct PayloadDispatcher {
+        first_error: Mutex<Option<&'static str>>,
+        payloads: Arc<Mutex<Vec<Vec<u8>>>>,
+    }
+
+    impl DispatchSignedRequest for PayloadDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            let payload = match request.payload {
+                Some(SignedRequestPayload::Stream(stream)) => stream.concat2().wait().unwrap(),
+                _ => panic!("expected a streamed payload"),
+            };
+            self.payloads.lock().unwrap().push(payload.to_vec());
+            if let Some(err) = self.first_error.lock().unwrap().take() {
+                return future::err(HttpDispatchError::new(err.to_owned()));
+            }
+            future::ok(HttpResponse {
+                status: StatusCode::OK,
+                body: ByteStream::from(Vec::new()),
+                headers: HeaderMap::default(),
+                url: None,
+            })
+        }
+    }
+
+    /// Never answers, failing each attempt once its timeout has elapsed.
+    struct StallingDispatcher {
+        timeouts: Arc<Mutex<Vec<Option<Duration>>>>,
+    }
+
+    impl DispatchSignedRequest for StallingDispatcher {
+        type Future = Box<dyn Future<Item = HttpResponse, Error = HttpDispatchError> + Send>;
+
+        fn dispatch(&self, _request: SignedRequest, timeout: Option<Duration>) -> Self::Future {
+            self.timeouts.lock().unwrap().push(timeout);
+            let stall = timeout.unwrap_or_else(|| Duration::from_secs(60));
+            Box::new(
+                Delay::new(Instant::now() + stall)
+                    .then(|_| Err(HttpDispatchError::new("request timed out".to_owned()))),
+            )
+        }
+    }
+
+    /// Records the session token each request is signed with.
+    struct TokenDispatcher {
+        tokens: Arc<Mutex<Vec<Option<String>>>>,
+    }
+
+    impl DispatchSignedRequest for TokenDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            let token = request
+                .headers()
+                .get("x-amz-security-token")
+                .map(|values| String::from_utf8_lossy(&values[0]).into_owned());
+            self.tokens.lock().unwrap().push(token);
+            future::ok(HttpResponse {
+                status: StatusCode::OK,
+                body: ByteStream::from(Vec::new()),
+                headers: HeaderMap::default(),
+                url: None,
+            })
+        }
+    }
+
+    type Headers = BTreeMap<String, Vec<Vec<u8>>>;
+
+    /// Answers with a temporary redirect to `redirected.example.com` the given number of
+    /// times, then with 200, recording the headers of each request.
+    struct RedirectDispatcher {
+        redirects: AtomicUsize,
+        requests: Arc<Mutex<Vec<Headers>>>,
+    }
+
+    impl RedirectDispatcher {
+        fn new(redirects: usize) -> (Self, Arc<Mutex<Vec<Headers>>>) {
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let dispatcher = RedirectDispatcher {
+                redirects: AtomicUsize::new(redirects),
+                requests: requests.clone(),
+            };
+            (dispatcher, requests)
+        }
+    }
+
+    impl DispatchSignedRequest for RedirectDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            self.requests
+                .lock()
+                .unwrap()
+                .push(request.headers().clone());
+            let mut headers = HeaderMap::default();
+            let status = if self.redirects.load(Ordering::SeqCst) > 0 {
+                self.redirects.fetch_sub(1, Ordering::SeqCst);
+                headers.insert("location", "https://redirected.example.com/path".to_owned());
+                StatusCode::TEMPORARY_REDIRECT
+            } else {
+                StatusCode::OK
+            };
+            future::ok(HttpResponse {
+                status,
+                body: ByteStream::from(Vec::new()),
+                headers,
+                url: None,
+            })
+        }
+    }
+
+    /// Records the canonical query string each request is signed with.
+    struct QueryDispatcher {
+        queries: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl DispatchSignedRequest for QueryDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            self.queries
+                .lock()
+                .unwrap()
+                .push(request.canonical_query_string.clone());
+            future::ok(HttpResponse {
+                status: StatusCode::OK,
+                body: ByteStream::from(Vec::new()),
+                headers: HeaderMap::default(),
+                url: None,
+            })
+        }
+    }
+
+    /// Answers after a short delay, recording the paths of the requests in the order they
+    /// were sent and the most requests in flight at once.
+    #[derive(Clone, Default)]
+    struct CountingDispatcher {
+        paths: Arc<Mutex<Vec<String>>>,
+        running: Arc<Mutex<usize>>,
+        max_running: Arc<Mutex<usize>>,
+    }
+
+    impl DispatchSignedRequest for CountingDispatcher {
+        type Future = Box<dyn Future<Item = HttpResponse, Error = HttpDispatchError> + Send>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            self.paths.lock().unwrap().push(request.path.clone());
+            let mut running = self.running.lock().unwrap();
+            *running += 1;
+            let mut max_running = self.max_running.lock().unwrap();
+            *max_running = (*max_running).max(*running);
+
+            let done = self.running.clone();
+            Box::new(
+                Delay::new(Instant::now() + Duration::from_millis(10)).then(move |_| {
+                    *done.lock().unwrap() -= 1;
+                    Ok(HttpResponse {
+                        status: StatusCode::OK,
+                        body: ByteStream::from(Vec::new()),
+                        headers: HeaderMap::default(),
+                        url: None,
+                    })
+                }),
+            )
+        }
+    }
+
+    /// Never provides credentials.
+    struct PendingProvider;
+
+    impl ProvideAwsCredentials for PendingProvider {
+        type Future = future::Empty<AwsCredentials, CredentialsError>;
+
+        fn credentials(&self) -> Self::Future {
+            future::empty()
+        }
+    }
+
+    /// Counts the credentials it provides, which expire at `expires_at`.
+    struct CountingProvider {
+        expires_at: Option<DateTime<Utc>>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl ProvideAwsCredentials for CountingProvider {
+        type Future = FutureResult<AwsCredentials, CredentialsError>;
+
+        fn credentials(&self) -> Self::Future {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            future::ok(AwsCredentials::new(
+                format!("key{}", call),
+                "secret",
+                None,
+                self.expires_at,
+            ))
+        }
+    }
+
+    /// Fails to provide credentials.
+    struct FailingProvider;
+
+    impl ProvideAwsCredentials for FailingProvider {
+        type Future = FutureResult<AwsCredentials, CredentialsError>;
+
+        fn credentials(&self) -> Self::Future {
+            future::err(CredentialsError::new("no credentials"))
+        }
+    }
+
+    fn status_handler(
+        response: HttpResponse,
+    ) -> Box<dyn Future<Item = u16, Error = RusotoError<()>> + Send> {
+        Box::new(future::ok(response.status.as_u16()))
+    }
+
+    fn request() -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        request.set_payload(Some("payload"));
+        request
+    }
+
+    fn client<D>(dispatcher: D, retry_policy: RetryPolicy) -> Client
+    where
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        )
+        .with_retry_policy(retry_policy.with_base_delay(Duration::from_millis(1)))
+    }
+
+    #[test]
+    fn retries_server_errors() {
+        let (dispatcher, attempts) = StatusDispatcher::new(&[503, 500]);
+        let status = client(dispatcher, RetryPolicy::new(3))
+            .sign_and_dispatch(request(), status_handler)
+            .sync();
+        assert_eq!(status, Ok(200));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let (dispatcher, attempts) = StatusDispatcher::new(&[503, 503, 503]);
+        let status = client(dispatcher, RetryPolicy::new(2))
+            .sign_and_dispatch(request(), status_handler)
+            .sync();
+        assert_eq!(status, Ok(503));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn stops_retrying_once_the_retry_budget_is_spent() {
+        let (dispatcher, attempts) = StatusDispatcher::new(&[503, 503, 503]);
+        let policy = RetryPolicy::new(3).with_budget(RetryBudget::new(1, 0.0));
+        let client = client(dispatcher, policy);
+
+        let status = client.sign_and_dispatch(request(), status_handler).sync();
+        assert_eq!(status, Ok(503));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // clones of the client share the budget
+        let status = client
+            .clone()
+            .sign_and_dispatch(request(), status_handler)
+            .sync();
+        assert_eq!(status, Ok(503));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn does_not_retry_by_default_or_client_errors() {
+        let (dispatcher, attempts) = StatusDispatcher::new(&[503]);
+        let status = client(dispatcher, RetryPolicy::default())
+            .sign_and_dispatch(request(), status_handler)
+            .sync();
+        assert_eq!(status, Ok(503));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        let (dispatcher, attempts) = StatusDispatcher::new(&[400]);
+        let status = client(dispatcher, RetryPolicy::new(3))
+            .sign_and_dispatch(request(), status_handler)
+            .sync();
+        assert_eq!(status, Ok(400));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn audit_sink_records_completed_requests() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let recorded = records.clone();
+        let (dispatcher, _) = StatusDispatcher::new(&[503]);
+        let client =
+            client(dispatcher, RetryPolicy::new(2)).with_audit_sink(move |record: &AuditRecord| {
+                recorded.lock().unwrap().push(record.clone())
+            });
+
+        let mut request = request();
+        request.set_operation("SendMessage");
+        request.add_resource_id("QueueUrl", "https://sqs/queue");
+        assert_eq!(
+            client.sign_and_dispatch(request, status_handler).sync(),
+            Ok(200)
+        );
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1, "one record per request, not per attempt");
+        assert_eq!(records[0].service, "sqs");
+        assert_eq!(records[0].operation, Some("SendMessage".to_owned()));
+        assert_eq!(records[0].region, "us-east-1");
+        assert_eq!(records[0].resource_ids["QueueUrl"], "https://sqs/queue");
+        assert_eq!(records[0].access_key_id, Some("key".to_owned()));
+        assert_eq!(records[0].status, Some(200));
+        assert_eq!(records[0].error, None);
+    }
+
+    #[test]
+    fn request_record_sink_records_what_was_signed() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let recorded = records.clone();
+        let (dispatcher, requests) = RedirectDispatcher::new(0);
+        let clock = ManualClock::new("2019-01-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap());
+        let client = Client::new_with(
+            StaticProvider::new(
+                "key".into(),
+                "the-secret".into(),
+                Some("the-session-token".into()),
+                None,
+            ),
+            dispatcher,
+        )
+        .with_clock(clock)
+        .with_request_record_sink(move |record: &RequestRecord| {
+            recorded.lock().unwrap().push(record.clone())
+        });
+
+        for _ in 0..2 {
+            let mut request = request();
+            request.set_operation("SendMessage");
+            assert_eq!(
+                client.sign_and_dispatch(request, status_handler).sync(),
+                Ok(200)
+            );
+        }
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[0], records[1],
+            "records of the same call at the same time match"
+        );
+        let record = &records[0];
+        assert_eq!(record.service, "sqs");
+        assert_eq!(record.operation, Some("SendMessage".to_owned()));
+        assert_eq!(record.method, "POST");
+        assert_eq!(record.endpoint, "https://sqs.us-east-1.amazonaws.com");
+        assert_eq!(record.amz_date, Some("20190101T120000Z".to_owned()));
+        assert_eq!(
+            record.credential_scope,
+            Some("20190101/us-east-1/sqs/aws4_request".to_owned())
+        );
+        assert!(record
+            .signed_headers
+            .contains(&"x-amz-security-token".to_owned()));
+        assert_eq!(
+            record.canonical_request_sha256.as_ref().map(String::len),
+            Some(64)
+        );
+        assert_eq!(record.payload_sha256, Some(to_hexdigest("payload")));
+        assert_eq!(record.attempts, 1);
+        assert_eq!(record.status, Some(200));
+
+        let authorization =
+            String::from_utf8(requests.lock().unwrap()[0]["authorization"][0].clone()).unwrap();
+        let signature = authorization.rsplit('=').next().unwrap();
+        let json = serde_json::to_string(record).unwrap();
+        for secret in &["the-secret", "the-session-token", signature] {
+            assert!(!json.contains(secret), "{} leaked into {}", secret, json);
+        }
+        assert!(!json.to_lowercase().contains("authorization"));
+    }
+
+    #[test]
+    fn retry_observer_records_each_retry() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let recorded = records.clone();
+        let (dispatcher, _) = StatusDispatcher::new(&[503, 429]);
+        let client = client(dispatcher, RetryPolicy::new(3)).with_retry_observer(
+            move |record: &RetryRecord| recorded.lock().unwrap().push(record.clone()),
+        );
+
+        let mut request = request();
+        request.set_operation("SendMessage");
+        assert_eq!(
+            client.sign_and_dispatch(request, status_handler).sync(),
+            Ok(200)
+        );
+
+        let records = records.lock().unwrap();
+        assert_eq!(
+            *records,
+            vec![
+                RetryRecord {
+                    service: "sqs".to_owned(),
+                    operation: Some("SendMessage".to_owned()),
+                    attempt: 1,
+                    status: Some(503),
+                    request_id: None,
+                    error: None,
+                    delay_ms: 1,
+                    retry_after: false,
+                },
+                RetryRecord {
+                    service: "sqs".to_owned(),
+                    operation: Some("SendMessage".to_owned()),
+                    attempt: 2,
+                    status: Some(429),
+                    request_id: None,
+                    error: None,
+                    delay_ms: 0,
+                    retry_after: true,
+                },
+            ]
+        );
+    }
+
+    /// Keeps the responses it observes.
+    struct ResponseObserver(Arc<Mutex<Vec<ResponseRecord>>>);
+
+    impl RetryObserver for ResponseObserver {
+        fn retry(&self, _record: &RetryRecord) {}
+
+        fn responded(&self, record: &ResponseRecord) {
+            self.0.lock().unwrap().push(record.clone());
+        }
+    }
+
+    #[test]
+    fn retry_observer_records_the_retries_of_each_response() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let (dispatcher, _) = StatusDispatcher::new(&[503, 500]);
+        let client = client(dispatcher, RetryPolicy::new(3))
+            .with_retry_observer(ResponseObserver(records.clone()));
+
+        let mut retried = request();
+        retried.set_operation("SendMessage");
+        assert_eq!(
+            client.sign_and_dispatch(retried, status_handler).sync(),
+            Ok(200)
+        );
+        assert_eq!(
+            client.sign_and_dispatch(request(), status_handler).sync(),
+            Ok(200)
+        );
+
+        let records = records.lock().unwrap();
+        assert_eq!(
+            *records,
+            vec![
+                ResponseRecord {
+                    service: "sqs".to_owned(),
+                    operation: Some("SendMessage".to_owned()),
+                    retries: 2,
+                    status: 200,
+                    request_id: None,
+                },
+                ResponseRecord {
+                    service: "sqs".to_owned(),
+                    operation: None,
+                    retries: 0,
+                    status: 200,
+                    request_id: None,
+                },
+            ]
+        );
+        assert_eq!(records[0].attempt(), 3);
+    }
+
+    /// Answers the first attempt with a 503, and the others with their payload twice.
+    struct EchoDispatcher {
+        attempts: AtomicUsize,
+    }
+
+    impl DispatchSignedRequest for EchoDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            let payload = match request.payload {
+                Some(SignedRequestPayload::Buffer(buffer)) => buffer,
+                Some(SignedRequestPayload::Stream(stream)) => stream.concat2().wait().unwrap(),
+                None => Bytes::new(),
+            };
+            let status = if self.attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                StatusCode::SERVICE_UNAVAILABLE
+            } else {
+                StatusCode::OK
+            };
+            future::ok(HttpResponse {
+                status,
+                body: ByteStream::from([&payload[..], &payload[..]].concat()),
+                headers: HeaderMap::default(),
+                url: None,
+            })
+        }
+    }
+
+    /// Keeps the transfers it observes.
+    struct TransferObserver(Arc<Mutex<Vec<TransferRecord>>>);
+
+    impl RetryObserver for TransferObserver {
+        fn retry(&self, _record: &RetryRecord) {}
+
+        fn transferred(&self, record: &TransferRecord) {
+            self.0.lock().unwrap().push(record.clone());
+        }
+    }
+
+    fn body_handler(
+        response: HttpResponse,
+    ) -> Box<dyn Future<Item = usize, Error = RusotoError<()>> + Send> {
+        Box::new(
+            response
+                .body
+                .concat2()
+                .map(|body| body.len())
+                .map_err(|err| RusotoError::ParseError(err.to_string())),
+        )
+    }
+
+    #[test]
+    fn retry_observer_records_the_bytes_sent_and_received() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = EchoDispatcher {
+            attempts: AtomicUsize::new(0),
+        };
+        let client = client(dispatcher, RetryPolicy::new(2))
+            .with_retry_observer(TransferObserver(records.clone()));
+
+        let mut request = request();
+        request.set_operation("SendMessage");
+        assert_eq!(
+            client.sign_and_dispatch(request, body_handler).sync(),
+            Ok(14)
+        );
+
+        let records = records.lock().unwrap();
+        assert_eq!(
+            *records,
+            vec![TransferRecord {
+                service: "sqs".to_owned(),
+                operation: Some("SendMessage".to_owned()),
+                status: 200,
+                request_id: None,
+                // the payload was sent by both attempts
+                bytes_sent: 14,
+                bytes_received: 14,
+                complete: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn retry_observer_records_streamed_payloads_and_unread_bodies() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = EchoDispatcher {
+            attempts: AtomicUsize::new(1),
+        };
+        let client = client(dispatcher, RetryPolicy::new(2))
+            .with_retry_observer(TransferObserver(records.clone()));
+
+        let mut request = request();
+        let chunks = (0..4).map(|_| Bytes::from(vec![0; 1024]));
+        request.set_payload_stream(ByteStream::new(futures::stream::iter_ok(chunks)));
+        assert_eq!(
+            client.sign_and_dispatch(request, status_handler).sync(),
+            Ok(200)
+        );
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].bytes_sent, 4096);
+        // the body was dropped without being read
+        assert_eq!(records[0].bytes_received, 0);
+        assert!(!records[0].complete);
+    }
+
+    #[test]
+    fn retry_observer_records_final_error_responses() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let (dispatcher, _) = StatusDispatcher::new(&[503, 503]);
+        let client = client(dispatcher, RetryPolicy::new(2))
+            .with_retry_observer(ResponseObserver(records.clone()));
+
+        assert_eq!(
+            client.sign_and_dispatch(request(), status_handler).sync(),
+            Ok(503)
+        );
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].retries, 1);
+        assert_eq!(records[0].status, 503);
+    }
+
+    #[test]
+    fn audit_sink_records_failed_requests() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let recorded = records.clone();
+        let (dispatcher, _) = StatusDispatcher::new(&[]);
+        let client = client(dispatcher, RetryPolicy::default()).with_audit_sink(
+            move |record: &AuditRecord| recorded.lock().unwrap().push(record.clone()),
+        );
+        client.close();
+
+        assert!(client
+            .sign_and_dispatch(request(), status_handler)
+            .sync()
+            .is_err());
+        let records = records.lock().unwrap();
+        assert_eq!(records[0].status, None);
+        assert_eq!(records[0].error, Some("the client is closed".to_owned()));
+    }
+
+    /// Serves a response with a body of 100 bytes to each of `attempts` connections, of
+    /// which all but the last stall after 10 bytes.
+    fn stalling_server(attempts: usize) -> Region {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        ::std::thread::spawn(move || {
+            let mut stalled = Vec::new();
+            for attempt in 1..=attempts {
+                let (mut socket, _) = listener.accept().unwrap();
+                let mut buf = [0; 4096];
+                let _ = socket.read(&mut buf);
+                let _ = socket.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n");
+                let sent = if attempt < attempts { 10 } else { 100 };
+                let _ = socket.write_all(&[b'a'; 100][..sent]);
+                let _ = socket.flush();
+                // keep the stalled connections open
+                stalled.push(socket);
+            }
+            ::std::thread::sleep(Duration::from_secs(5));
+        });
+        Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint,
+        }
+    }
+
+    fn stalling_client(retry_policy: RetryPolicy) -> Client {
+        let mut config = HttpConfig::new();
+        config.body_read_timeout(Duration::from_millis(200));
+        client(HttpClient::new_with_config(config).unwrap(), retry_policy)
+    }
+
+    #[test]
+    fn retries_idempotent_requests_whose_body_stalls() {
+        let region = stalling_server(2);
+        let request = SignedRequest::new("GET", "s3", &region, "/bucket/key");
+        let length = stalling_client(RetryPolicy::new(2))
+            .sign_and_dispatch(request, body_handler)
+            .sync();
+        assert_eq!(length, Ok(100));
+    }
+
+    #[test]
+    fn does_not_retry_stalled_bodies_of_other_requests() {
+        let region = stalling_server(2);
+        let request = SignedRequest::new("PUT", "s3", &region, "/bucket/key");
+        let result = stalling_client(RetryPolicy::new(2))
+            .sign_and_dispatch(request, body_handler)
+            .sync();
+        match result {
+            Err(RusotoError::ParseError(message)) => {
+                assert!(message.contains("after 10 bytes"), "{}", message)
+            }
+            other => panic!("expected the stalled body to fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn does_not_retry_streaming_payloads() {
+        let (dispatcher, attempts) = StatusDispatcher::new(&[503]);
+        let mut request = request();
+        let chunks = vec![Bytes::from_static(b"pay"), Bytes::from_static(b"load")];
+        request.set_payload_stream(ByteStream::new(futures::stream::iter_ok(chunks)));
+        let status = client(dispatcher, RetryPolicy::new(3))
+            .sign_and_dispatch(request, status_handler)
+            .sync();
+        assert_eq!(status, Ok(503));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn retries_file_bodies_with_identical_bytes() {
+        let path = env::temp_dir().join(format!("rusoto-file-body-{}", process::id()));
+        let contents: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(&path, &contents).unwrap();
+
+        let payloads = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = PayloadDispatcher {
+            first_error: Mutex::new(Some("connection reset")),
+            payloads: payloads.clone(),
+        };
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload_stream(FileBody::new(&path).unwrap());
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        )
+        .with_retry_policy(RetryPolicy::new(2).with_base_delay(Duration::from_millis(1)));
+        let status = client.sign_and_dispatch(request, status_handler).sync();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(status, Ok(200));
+        let payloads = payloads.lock().unwrap();
+        assert_eq!(payloads.len(), 2);
+        assert_eq!(payloads[0], contents);
+        assert_eq!(payloads[1], contents);
+    }
+
+    #[test]
+    fn unreplayable_payloads_fail_fast_after_a_dispatch_failure() {
+        let payloads = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = PayloadDispatcher {
+            first_error: Mutex::new(Some("connection reset")),
+            payloads: payloads.clone(),
+        };
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        let chunks = vec![Bytes::from_static(b"pay"), Bytes::from_static(b"load")];
+        request.set_payload_stream(ByteStream::new(futures::stream::iter_ok(chunks)));
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        )
+        .with_retry_policy(RetryPolicy::new(3).with_base_delay(Duration::from_millis(1)));
+
+        match client.sign_and_dispatch(request, status_handler).sync() {
+            Err(RusotoError::HttpDispatch(err)) => {
+                assert_eq!(err.kind(), HttpDispatchErrorKind::PayloadNotReplayable);
+                assert_eq!(
+                    err.to_string(),
+                    "connection reset (not retried, as the request payload is a stream \
+                     which isn't replayable)"
+                );
+                assert_eq!(err.source().unwrap().to_string(), "connection reset");
+            }
+            other => panic!("expected a dispatch error, got {:?}", other),
+        }
+        assert_eq!(payloads.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn deadline_spans_retries() {
+        let (dispatcher, attempts) = StatusDispatcher::new(&[503; 100]);
+        let retry_policy = RetryPolicy::new(100).with_max_delay(Duration::from_millis(50));
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        )
+        .with_retry_policy(retry_policy.with_base_delay(Duration::from_millis(50)));
+        let started = Instant::now();
+        let status = client
+            .sign_and_dispatch(request(), status_handler)
+            .with_deadline(Duration::from_millis(200))
+            .sync();
+        assert_eq!(status, Err(RusotoError::Timeout));
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert!(attempts.load(Ordering::SeqCst) < 10);
+    }
+
+    #[test]
+    fn cancellation_abandons_pending_retries() {
+        let (dispatcher, attempts) = StatusDispatcher::new(&[503; 100]);
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        )
+        .with_retry_policy(RetryPolicy::new(100).with_base_delay(Duration::from_secs(10)));
+        let token = CancellationToken::new();
+        let canceler = token.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            canceler.cancel();
+        });
+
+        let started = Instant::now();
+        let status = client
+            .sign_and_dispatch(request(), status_handler)
+            .with_cancellation(token)
+            .sync();
+        assert_eq!(status, Err(RusotoError::Cancelled));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn deadline_covers_credentials() {
+        let (dispatcher, attempts) = StatusDispatcher::new(&[]);
+        let status = Client::new_with(PendingProvider, dispatcher)
+            .sign_and_dispatch(request(), status_handler)
+            .with_deadline(Duration::from_millis(50))
+            .sync();
+        assert_eq!(status, Err(RusotoError::Timeout));
+        assert_eq!(attempts.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn concurrent_calls_honor_their_own_timeouts() {
+        let timeouts = Arc::new(Mutex::new(Vec::new()));
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            StallingDispatcher {
+                timeouts: timeouts.clone(),
+            },
+        )
+        .with_retry_policy(RetryPolicy::new(3));
+
+        let mut short_request = request();
+        short_request.set_operation_config(OperationConfig {
+            timeout: Some(Duration::from_millis(50)),
+            retry_policy_override: Some(RetryPolicy::none()),
+        });
+        let started = Instant::now();
+        let short = client
+            .sign_and_dispatch(short_request, status_handler)
+            .then(move |result| Ok::<_, ()>((result, started.elapsed())));
+        let long = client
+            .sign_and_dispatch(request(), status_handler)
+            .with_operation_config(OperationConfig {
+                timeout: Some(Duration::from_millis(400)),
+                retry_policy_override: Some(RetryPolicy::none()),
+            })
+            .then(move |result| Ok::<_, ()>((result, started.elapsed())));
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let ((short_result, short_elapsed), (long_result, long_elapsed)) =
+            runtime.block_on(short.join(long)).unwrap();
+
+        assert!(short_result.is_err());
+        assert!(long_result.is_err());
+        assert!(short_elapsed < Duration::from_millis(400));
+        assert!(long_elapsed >= Duration::from_millis(400));
+        assert_eq!(
+            *timeouts.lock().unwrap(),
+            vec![
+                Some(Duration::from_millis(50)),
+                Some(Duration::from_millis(400))
+            ]
+        );
+    }
+
+    #[test]
+    fn operation_config_overrides_client_retry_policy() {
+        let (dispatcher, attempts) = StatusDispatcher::new(&[503, 500]);
+        let mut retried = request();
+        retried.set_operation_config(OperationConfig {
+            timeout: None,
+            retry_policy_override: Some(
+                RetryPolicy::new(3).with_base_delay(Duration::from_millis(1)),
+            ),
+        });
+        let status = client(dispatcher, RetryPolicy::none())
+            .sign_and_dispatch(retried, status_handler)
+            .sync();
+        assert_eq!(status, Ok(200));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let (dispatcher, attempts) = StatusDispatcher::new(&[503]);
+        let status = client(dispatcher, RetryPolicy::new(3))
+            .sign_and_dispatch(request(), status_handler)
+            .with_operation_config(OperationConfig {
+                timeout: None,
+                retry_policy_override: Some(RetryPolicy::none()),
+            })
+            .sync();
+        assert_eq!(status, Ok(503));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn session_token_of_refreshing_provider_is_signed() {
+        let tokens = Arc::new(Mutex::new(Vec::new()));
+        let provider = AutoRefreshingProvider::new(StaticProvider::new(
+            "key".into(),
+            "secret".into(),
+            Some("session-token".into()),
+            Some(3600),
+        ))
+        .unwrap();
+        let client = Client::new_with(
+            provider,
+            TokenDispatcher {
+                tokens: tokens.clone(),
+            },
+        );
+
+        for _ in 0..2 {
+            let status = client.sign_and_dispatch(request(), status_handler).sync();
+            assert_eq!(status, Ok(200));
+        }
+        assert_eq!(
+            *tokens.lock().unwrap(),
+            vec![Some("session-token".to_owned()); 2]
+        );
+    }
+
+    #[test]
+    fn follows_temporary_redirects_with_signer_headers_regenerated() {
+        let (dispatcher, requests) = RedirectDispatcher::new(1);
+        let client = Client::new_with(
+            StaticProvider::new(
+                "key".into(),
+                "the-secret".into(),
+                Some("the-session-token".into()),
+                None,
+            ),
+            dispatcher,
+        );
+        let status = client.sign_and_dispatch(request(), status_handler).sync();
+        assert_eq!(status, Ok(200));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[1]["host"],
+            vec![b"redirected.example.com".to_vec()]
+        );
+        for (name, values) in &requests[1] {
+            assert_eq!(values.len(), 1, "{} has several values", name);
+        }
+        assert_ne!(requests[0]["authorization"], requests[1]["authorization"]);
+    }
+
+    fn url_handler(
+        response: HttpResponse,
+    ) -> Box<dyn Future<Item = Option<String>, Error = RusotoError<()>> + Send> {
+        Box::new(future::ok(response.url))
+    }
+
+    #[test]
+    fn responses_carry_the_url_which_served_them() {
+        let (dispatcher, _) = RedirectDispatcher::new(0);
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        );
+        let mut list_queues = request();
+        list_queues.add_param("Action", "ListQueues");
+        let url = client.sign_and_dispatch(list_queues, url_handler).sync();
+        assert_eq!(
+            url,
+            Ok(Some(
+                "https://sqs.us-east-1.amazonaws.com/?Action=ListQueues".to_owned()
+            ))
+        );
+
+        let (dispatcher, _) = RedirectDispatcher::new(1);
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        );
+        let url = client.sign_and_dispatch(request(), url_handler).sync();
+        assert_eq!(url, Ok(Some("https://redirected.example.com/".to_owned())));
+    }
+
+    #[test]
+    fn follows_at_most_max_redirects() {
+        let (dispatcher, requests) = RedirectDispatcher::new(10);
+        let status = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        )
+        .with_max_redirects(2)
+        .sign_and_dispatch(request(), status_handler)
+        .sync();
+        assert_eq!(status, Ok(307));
+        assert_eq!(requests.lock().unwrap().len(), 3);
+
+        let (dispatcher, requests) = RedirectDispatcher::new(1);
+        let status = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        )
+        .with_max_redirects(0)
+        .sign_and_dispatch(request(), status_handler)
+        .sync();
+        assert_eq!(status, Ok(307));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn requests_are_dated_with_the_client_clock() {
+        let (dispatcher, requests) = RedirectDispatcher::new(0);
+        let clock = ManualClock::new("2019-01-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap());
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        )
+        .with_clock(clock.clone());
+
+        client
+            .sign_and_dispatch(request(), status_handler)
+            .sync()
+            .unwrap();
+        clock.advance(chrono::Duration::minutes(5));
+        client
+            .sign_and_dispatch(request(), status_handler)
+            .sync()
+            .unwrap();
+
+        let dates: Vec<Vec<u8>> = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|headers| headers["x-amz-date"][0].clone())
+            .collect();
+        assert_eq!(
+            dates,
+            vec![b"20190101T120000Z".to_vec(), b"20190101T120500Z".to_vec()]
+        );
+    }
+
+    #[test]
+    fn clients_sign_unsigned_payloads_unless_requests_say_otherwise() {
+        let (dispatcher, requests) = RedirectDispatcher::new(0);
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        )
+        .with_unsigned_payload(true);
+        let s3_request = || {
+            let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+            request.set_payload(Some("payload"));
+            request
+        };
+
+        let mut signed = s3_request();
+        signed.set_unsigned_payload(false);
+        for request in vec![s3_request(), signed, request()] {
+            client
+                .sign_and_dispatch(request, status_handler)
+                .sync()
+                .unwrap();
+        }
+
+        let digests: Vec<Vec<u8>> = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|headers| headers["x-amz-content-sha256"][0].clone())
+            .collect();
+        assert_eq!(digests[0], b"UNSIGNED-PAYLOAD".to_vec());
+        assert_eq!(digests[1].len(), 64);
+        assert_eq!(digests[2].len(), 64);
+    }
+
+    #[test]
+    fn params_added_before_dispatch_are_signed() {
+        let queries = Arc::new(Mutex::new(Vec::new()));
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            QueryDispatcher {
+                queries: queries.clone(),
+            },
+        );
+        let mut request = request();
+        request.add_param("versionId", "3");
+
+        client
+            .sign_and_dispatch(request, status_handler)
+            .with_param("tagging", None)
+            .with_param("max-keys", Some("10"))
+            .sync()
+            .unwrap();
+
+        assert_eq!(
+            *queries.lock().unwrap(),
+            vec!["max-keys=10&tagging=&versionId=3".to_owned()]
+        );
+    }
+
+    #[test]
+    fn accept_set_before_dispatch_replaces_the_default_and_is_signed() {
+        let (dispatcher, requests) = RedirectDispatcher::new(0);
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        );
+        let mut request = request();
+        request.set_accept("application/xml".to_owned());
+
+        client
+            .sign_and_dispatch(request, status_handler)
+            .with_accept("application/json")
+            .sync()
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0]["accept"], vec![b"application/json".to_vec()]);
+        let authorization = String::from_utf8(requests[0]["authorization"][0].clone()).unwrap();
+        assert!(
+            authorization.contains("SignedHeaders=accept;"),
+            "{}",
+            authorization
+        );
+    }
+
+    #[test]
+    fn customized_requests_are_signed_and_kept_when_redirected() {
+        let (dispatcher, requests) = RedirectDispatcher::new(1);
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        );
+
+        client
+            .sign_and_dispatch(request(), status_handler)
+            .customize(|request| request.add_header("x-amz-expected-bucket-owner", "123456789012"))
+            .sync()
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        for request in requests.iter() {
+            assert_eq!(
+                request["x-amz-expected-bucket-owner"],
+                vec![b"123456789012".to_vec()]
+            );
+            let authorization = String::from_utf8(request["authorization"][0].clone()).unwrap();
+            assert!(
+                authorization.contains(";x-amz-expected-bucket-owner"),
+                "{}",
+                authorization
+            );
+        }
+    }
+
+    fn send_burst(clients: &[Client], requests: usize) -> Vec<u16> {
+        let futures: Vec<_> = (0..requests)
+            .map(|n| {
+                let request =
+                    SignedRequest::new("POST", "ec2", &Region::UsEast1, &format!("/{}", n));
+                clients[n % clients.len()].sign_and_dispatch(request, status_handler)
+            })
+            .collect();
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(future::join_all(futures))
+            .unwrap()
+    }
+
+    fn limited_client(dispatcher: CountingDispatcher, limit: ServiceLimit) -> Client {
+        Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        )
+        .with_concurrency_limits(ConcurrencyLimits::new().with_limit("ec2", limit))
+    }
+
+    #[test]
+    fn concurrency_limits_cap_the_requests_in_flight() {
+        let dispatcher = CountingDispatcher::default();
+        let client = limited_client(
+            dispatcher.clone(),
+            ServiceLimit::new().with_max_in_flight(2),
+        );
+
+        assert_eq!(send_burst(&[client], 10), vec![200; 10]);
+        assert_eq!(dispatcher.paths.lock().unwrap().len(), 10);
+        assert_eq!(*dispatcher.max_running.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn concurrency_limits_are_shared_by_clones() {
+        let dispatcher = CountingDispatcher::default();
+        let client = limited_client(
+            dispatcher.clone(),
+            ServiceLimit::new().with_max_in_flight(2),
+        );
+
+        assert_eq!(
+            send_burst(&[client.clone(), client.clone(), client], 12),
+            vec![200; 12]
+        );
+        assert_eq!(*dispatcher.max_running.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn concurrency_limits_send_requests_in_order_and_measure_their_wait() {
+        let dispatcher = CountingDispatcher::default();
+        let limits =
+            ConcurrencyLimits::new().with_limit("ec2", ServiceLimit::new().with_max_in_flight(1));
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher.clone(),
+        )
+        .with_concurrency_limits(limits.clone());
+
+        send_burst(&[client], 5);
+        assert_eq!(
+            *dispatcher.paths.lock().unwrap(),
+            vec!["/0", "/1", "/2", "/3", "/4"]
+        );
+        let metrics = limits.metrics("ec2").unwrap();
+        assert_eq!(metrics.requests, 5);
+        assert_eq!(metrics.in_flight, 0);
+        assert_eq!(metrics.queued, 0);
+        // the last request waited for the four before it
+        assert!(metrics.max_wait >= Duration::from_millis(40));
+        assert!(metrics.average_wait() < metrics.max_wait);
+    }
+
+    #[test]
+    fn concurrency_limits_space_requests_by_their_rate() {
+        let dispatcher = CountingDispatcher::default();
+        let client = limited_client(
+            dispatcher.clone(),
+            ServiceLimit::new().with_max_requests_per_second(100.0),
+        );
+
+        let started = Instant::now();
+        assert_eq!(send_burst(&[client], 5), vec![200; 5]);
+        assert!(started.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[test]
+    fn concurrency_limits_ignore_other_services() {
+        let dispatcher = CountingDispatcher::default();
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher.clone(),
+        )
+        .with_concurrency_limits(
+            ConcurrencyLimits::new().with_limit("rds", ServiceLimit::new().with_max_in_flight(1)),
+        );
+
+        send_burst(&[client], 4);
+        assert_eq!(*dispatcher.max_running.lock().unwrap(), 4);
+    }
+
+    fn budgeted_client(
+        dispatcher: CountingDispatcher,
+        budget: CallBudget,
+    ) -> (Client, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = CountingProvider {
+            expires_at: None,
+            calls: calls.clone(),
+        };
+        let client = Client::new_with(provider, dispatcher).with_call_budget(budget);
+        (client, calls)
+    }
+
+    fn list_objects(client: &Client) -> RusotoFuture<u16, ()> {
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket");
+        request.set_operation("ListObjectsV2");
+        client.sign_and_dispatch(request, status_handler)
+    }
+
+    #[test]
+    fn calls_over_budget_fail_before_fetching_credentials() {
+        let dispatcher = CountingDispatcher::default();
+        let budget = CallBudget::new(Duration::from_secs(60)).with_operation_limit(
+            "s3",
+            "ListObjectsV2",
+            100,
+        );
+        let (client, credentials) = budgeted_client(dispatcher.clone(), budget);
+
+        let calls: Vec<_> = (0..100).map(|_| list_objects(&client.clone())).collect();
+        let statuses = tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(future::join_all(calls))
+            .unwrap();
+        assert_eq!(statuses, vec![200; 100]);
+        assert_eq!(credentials.load(Ordering::SeqCst), 100);
+
+        let err = match list_objects(&client).sync() {
+            Err(RusotoError::HttpDispatch(err)) => err,
+            other => panic!("expected a dispatch error, got {:?}", other),
+        };
+        assert_eq!(err.kind(), HttpDispatchErrorKind::BudgetExceeded);
+        let exceeded = err
+            .source()
+            .and_then(|source| source.downcast_ref::<BudgetExceeded>())
+            .unwrap();
+        assert_eq!(exceeded.operation, Some("ListObjectsV2".to_owned()));
+        assert_eq!(exceeded.scope, BudgetScope::Operation);
+        assert_eq!(exceeded.limit, 100);
+        assert_eq!(
+            err.to_string(),
+            "the call to ListObjectsV2 of s3 went over its budget of 100 calls in 60s"
+        );
+        // the blocked call neither fetched credentials nor was sent
+        assert_eq!(credentials.load(Ordering::SeqCst), 100);
+        assert_eq!(dispatcher.paths.lock().unwrap().len(), 100);
+    }
+
+    #[test]
+    fn throttled_calls_are_sent_once_the_budget_has_room() {
+        let dispatcher = CountingDispatcher::default();
+        let budget = CallBudget::new(Duration::from_millis(100))
+            .with_global_limit(2)
+            .with_action(BudgetAction::Throttle);
+        let (client, _) = budgeted_client(dispatcher.clone(), budget);
+
+        let started = Instant::now();
+        let calls: Vec<_> = (0..4).map(|_| list_objects(&client)).collect();
+        let statuses = tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(future::join_all(calls))
+            .unwrap();
+        assert_eq!(statuses, vec![200; 4]);
+        assert!(started.elapsed() >= Duration::from_millis(100));
+        assert_eq!(dispatcher.paths.lock().unwrap().len(), 4);
+    }
+
+    fn dispatch_error_kind<T: std::fmt::Debug>(
+        result: Result<T, RusotoError<()>>,
+    ) -> HttpDispatchErrorKind {
+        match result {
+            Err(RusotoError::HttpDispatch(err)) => err.kind(),
+            other => panic!("expected a dispatch error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn closed_clients_reject_requests() {
+        let client = limited_client(CountingDispatcher::default(), ServiceLimit::new());
+        let clone = client.clone();
+        let drain = client.close();
+
+        assert!(clone.is_closed());
+        let status = clone.sign_and_dispatch(request(), status_handler).sync();
+        assert_eq!(dispatch_error_kind(status), HttpDispatchErrorKind::Closed);
+        assert_eq!(drain.wait(), Ok(DrainOutcome::Completed));
+    }
+
+    #[test]
+    fn closing_waits_for_the_requests_in_flight() {
+        let dispatcher = CountingDispatcher::default();
+        let client = limited_client(dispatcher.clone(), ServiceLimit::new());
+        let requests: Vec<_> = (0..3)
+            .map(|_| client.sign_and_dispatch(request(), status_handler))
+            .collect();
+        let drain = client.close();
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let (statuses, outcome) = runtime
+            .block_on(future::join_all(requests).join(drain.map_err(|()| RusotoError::Timeout)))
+            .unwrap();
+        assert_eq!(statuses, vec![200; 3]);
+        assert_eq!(outcome, DrainOutcome::Completed);
+        assert_eq!(dispatcher.paths.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn closing_cancels_requests_after_the_grace_period() {
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            StallingDispatcher {
+                timeouts: Arc::default(),
+            },
+        );
+        let stalled = client
+            .sign_and_dispatch(request(), status_handler)
+            .then(Ok::<_, ()>);
+        let drain = client.close().with_grace_period(Duration::from_millis(50));
+
+        let started = Instant::now();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let (status, outcome) = runtime.block_on(stalled.join(drain)).unwrap();
+        assert_eq!(
+            dispatch_error_kind(status),
+            HttpDispatchErrorKind::Cancelled
+        );
+        assert_eq!(outcome, DrainOutcome::Cancelled(1));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn shared_client_is_replaced_once_closed() {
+        let shared = Client::shared();
+        shared.close();
+
+        assert!(shared.is_closed());
+        assert!(!Client::shared().is_closed());
+    }
+
+    fn counting_client(expires_at: Option<DateTime<Utc>>) -> (Client, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = CountingProvider {
+            expires_at,
+            calls: calls.clone(),
+        };
+        let clock = ManualClock::new("2019-01-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap());
+        let client = Client::new_with(provider, CountingDispatcher::default()).with_clock(clock);
+        (client, calls)
+    }
+
+    #[test]
+    fn warmed_clients_skip_the_credentials_provider() {
+        let expires_at = "2019-01-01T13:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let (client, calls) = counting_client(Some(expires_at));
+        let report = client.warm_up(&Region::UsEast1, &[]).wait().unwrap();
+        assert!(report.is_ready());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let status = client.sign_and_dispatch(request(), status_handler).sync();
+        assert_eq!(status.unwrap(), 200);
+        let status = client
+            .clone()
+            .sign_and_dispatch(request(), status_handler)
+            .sync();
+        assert_eq!(status.unwrap(), 200);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn warmed_credentials_about_to_expire_are_fetched_again() {
+        let expires_at = "2019-01-01T12:01:00Z".parse::<DateTime<Utc>>().unwrap();
+        let (client, calls) = counting_client(Some(expires_at));
+        client.warm_up(&Region::UsEast1, &[]).wait().unwrap();
+
+        let status = client.sign_and_dispatch(request(), status_handler).sync();
+        assert_eq!(status.unwrap(), 200);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn warmed_credentials_without_expiry_are_fetched_again() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = CountingProvider {
+            expires_at: None,
+            calls: calls.clone(),
+        };
+        let (dispatcher, requests) = RedirectDispatcher::new(0);
+        let client = Client::new_with(provider, dispatcher);
+        client.warm_up(&Region::UsEast1, &[]).wait().unwrap();
+
+        let status = client.sign_and_dispatch(request(), status_handler).sync();
+        assert_eq!(status.unwrap(), 200);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        // signed with what the provider gave the second time rather than the warmed credentials
+        let authorization = &requests.lock().unwrap()[0]["authorization"][0];
+        let authorization = str::from_utf8(authorization).unwrap();
+        assert!(
+            authorization.contains("Credential=key2/"),
+            "{}",
+            authorization
+        );
+    }
+
+    #[test]
+    fn warm_up_opens_a_connection_to_each_service() {
+        let dispatcher = CountingDispatcher::default();
+        let client = Client::new_not_signing(dispatcher.clone());
+        let report = client
+            .warm_up(&Region::UsEast1, &["sqs", "dynamodb"])
+            .wait()
+            .unwrap();
+
+        assert!(report.credentials.is_none());
+        let services: Vec<&str> = report
+            .endpoints
+            .iter()
+            .map(|endpoint| &endpoint.service[..])
+            .collect();
+        assert_eq!(services, vec!["sqs", "dynamodb"]);
+        assert!(report.is_ready());
+        assert_eq!(dispatcher.paths.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn warm_up_reports_failures_instead_of_failing() {
+        let client = Client::new_with(FailingProvider, CountingDispatcher::default());
+        client.close();
+        let report = client.warm_up(&Region::UsEast1, &["sqs"]).wait().unwrap();
+
+        assert!(!report.is_ready());
+        assert!(report.credentials.unwrap().is_err());
+        match report.endpoints[0].result {
+            Err(ref err) => assert_eq!(err.kind(), HttpDispatchErrorKind::Closed),
+            Ok(()) => panic!("expected the closed client to fail"),
+        }
+    }
 }
@@ -0,0 +1,416 @@
+//! Limiting the requests in flight to each service, and their rate.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::task::{self, Task};
+use futures::{Async, Future, Poll};
+use tokio_timer::Delay;
+
+/// The limits on the requests to a single service.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceLimit {
+    max_in_flight: Option<usize>,
+    max_requests_per_second: Option<f64>,
+}
+
+impl ServiceLimit {
+    /// A limit letting every request through.
+    pub fn new() -> Self {
+        ServiceLimit {
+            max_in_flight: None,
+            max_requests_per_second: None,
+        }
+    }
+
+    /// Sends at most `max_in_flight` requests at a time, the others waiting for an answer
+    /// to one of them.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(max_in_flight.max(1));
+        self
+    }
+
+    /// Sends at most `max_requests_per_second` requests each second, evenly spaced.
+    pub fn with_max_requests_per_second(mut self, max_requests_per_second: f64) -> Self {
+        self.max_requests_per_second = Some(max_requests_per_second);
+        self
+    }
+
+    /// The time between two requests, when their rate is limited.
+    fn interval(&self) -> Option<Duration> {
+        match self.max_requests_per_second {
+            Some(rate) if rate > 0.0 => {
+                let nanos = 1_000_000_000.0 / rate;
+                Some(Duration::new(
+                    (nanos / 1_000_000_000.0) as u64,
+                    (nanos % 1_000_000_000.0) as u32,
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for ServiceLimit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How long the requests to a service waited for the limits.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LimitMetrics {
+    /// The number of requests sent after waiting in the queue.
+    pub requests: u64,
+    /// The number of requests in flight.
+    pub in_flight: usize,
+    /// The number of requests waiting in the queue.
+    pub queued: usize,
+    /// The total time requests waited in the queue.
+    pub total_wait: Duration,
+    /// The longest time a request waited in the queue.
+    pub max_wait: Duration,
+}
+
+impl LimitMetrics {
+    /// The average time requests waited in the queue.
+    pub fn average_wait(&self) -> Duration {
+        if self.requests == 0 {
+            return Duration::from_secs(0);
+        }
+        let nanos = self.total_wait.as_secs() as u128 * 1_000_000_000
+            + u128::from(self.total_wait.subsec_nanos());
+        let average = nanos / u128::from(self.requests);
+        Duration::new(
+            (average / 1_000_000_000) as u64,
+            (average % 1_000_000_000) as u32,
+        )
+    }
+}
+
+/// Limits on the requests in flight to each service, and on their rate.
+///
+/// Requests to a limited service wait in a queue, before their credentials are fetched,
+/// until they can be sent without going over the limits of the service. They leave the
+/// queue in the order they joined it, so a caller sending bursts of requests doesn't
+/// starve the others. A request stops counting as in flight when its response arrives.
+///
+/// Limits are set on a `Client` with `Client::with_concurrency_limits`, and are shared by
+/// its clones. Clones of the limits share their queues too, so the same limits can be
+/// given to the clients of several services, or to several clients of the same service:
+///
+/// ```rust
+/// use rusoto_core::concurrency::{ConcurrencyLimits, ServiceLimit};
+///
+/// let limits = ConcurrencyLimits::new()
+///     .with_limit("ec2", ServiceLimit::new().with_max_in_flight(4).with_max_requests_per_second(20.0))
+///     .with_limit("rds", ServiceLimit::new().with_max_in_flight(2));
+/// ```
+///
+/// Services are named as in the signature of their requests, like `ec2` or `rds`.
+#[derive(Clone, Default)]
+pub struct ConcurrencyLimits {
+    services: Arc<Mutex<HashMap<String, ServiceQueue>>>,
+}
+
+impl ConcurrencyLimits {
+    /// Limits letting every request through.
+    pub fn new() -> Self {
+        ConcurrencyLimits::default()
+    }
+
+    /// Limits the requests to `service`.
+    pub fn with_limit<S: Into<String>>(self, service: S, limit: ServiceLimit) -> Self {
+        self.services
+            .lock()
+            .unwrap()
+            .insert(service.into(), ServiceQueue::new(limit));
+        self
+    }
+
+    /// The limit on the requests to `service`, if any.
+    pub fn limit(&self, service: &str) -> Option<ServiceLimit> {
+        self.services
+            .lock()
+            .unwrap()
+            .get(service)
+            .map(|queue| queue.limit.clone())
+    }
+
+    /// How long the requests to `service` waited for its limit, if it has one.
+    pub fn metrics(&self, service: &str) -> Option<LimitMetrics> {
+        self.services.lock().unwrap().get(service).map(|queue| {
+            let mut metrics = queue.metrics.clone();
+            metrics.in_flight = queue.in_flight;
+            metrics.queued = queue.waiters.len();
+            metrics
+        })
+    }
+
+    /// Waits for the limits of `service` to let one more request through.
+    pub(crate) fn acquire(&self, service: &str) -> Option<PermitFuture> {
+        if !self.services.lock().unwrap().contains_key(service) {
+            return None;
+        }
+        Some(PermitFuture {
+            limits: self.clone(),
+            service: service.to_owned(),
+            id: None,
+            queued_at: Instant::now(),
+            delay: None,
+        })
+    }
+}
+
+impl fmt::Debug for ConcurrencyLimits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let services = self.services.lock().unwrap();
+        f.debug_map()
+            .entries(
+                services
+                    .iter()
+                    .map(|(service, queue)| (service, &queue.limit)),
+            )
+            .finish()
+    }
+}
+
+struct ServiceQueue {
+    limit: ServiceLimit,
+    in_flight: usize,
+    /// When the next request may be sent, when their rate is limited.
+    next_send: Option<Instant>,
+    waiters: VecDeque<Waiter>,
+    next_id: u64,
+    metrics: LimitMetrics,
+}
+
+struct Waiter {
+    id: u64,
+    task: Option<Task>,
+}
+
+impl ServiceQueue {
+    fn new(limit: ServiceLimit) -> Self {
+        ServiceQueue {
+            limit,
+            in_flight: 0,
+            next_send: None,
+            waiters: VecDeque::new(),
+            next_id: 0,
+            metrics: LimitMetrics::default(),
+        }
+    }
+
+    /// Wakes the request at the head of the queue up, so that it checks the limits again.
+    fn notify_head(&self) {
+        if let Some(task) = self.waiters.front().and_then(|waiter| waiter.task.as_ref()) {
+            task.notify();
+        }
+    }
+}
+
+/// Resolves to a `Permit` once the limits of a service let one more request through.
+pub(crate) struct PermitFuture {
+    limits: ConcurrencyLimits,
+    service: String,
+    id: Option<u64>,
+    queued_at: Instant,
+    delay: Option<Delay>,
+}
+
+impl Future for PermitFuture {
+    type Item = Permit;
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Permit, ()> {
+        loop {
+            let now = Instant::now();
+            {
+                let mut services = self.limits.services.lock().unwrap();
+                let queue = services
+                    .get_mut(&self.service)
+                    .expect("limits are never removed");
+                let id = match self.id {
+                    Some(id) => id,
+                    None => {
+                        let id = queue.next_id;
+                        queue.next_id += 1;
+                        queue.waiters.push_back(Waiter { id, task: None });
+                        self.id = Some(id);
+                        id
+                    }
+                };
+
+                let is_head = queue.waiters.front().map(|waiter| waiter.id) == Some(id);
+                let has_room = match queue.limit.max_in_flight {
+                    Some(max_in_flight) => queue.in_flight < max_in_flight,
+                    None => true,
+                };
+                let send_at = match queue.next_send {
+                    Some(next_send) if next_send > now => Some(next_send),
+                    _ => None,
+                };
+                if is_head && has_room && send_at.is_none() {
+                    queue.waiters.pop_front();
+                    queue.in_flight += 1;
+                    queue.next_send = queue.limit.interval().map(|interval| now + interval);
+                    let wait = now - self.queued_at;
+                    queue.metrics.requests += 1;
+                    queue.metrics.total_wait += wait;
+                    queue.metrics.max_wait = queue.metrics.max_wait.max(wait);
+                    queue.notify_head();
+                    self.id = None;
+                    return Ok(Async::Ready(Permit {
+                        limits: self.limits.clone(),
+                        service: self.service.clone(),
+                    }));
+                }
+
+                if let Some(waiter) = queue.waiters.iter_mut().find(|waiter| waiter.id == id) {
+                    waiter.task = Some(task::current());
+                }
+                match send_at {
+                    // the head of the queue waits for the rate to allow the next request
+                    Some(send_at) if is_head && has_room => {
+                        if self.delay.as_ref().map(Delay::deadline) != Some(send_at) {
+                            self.delay = Some(Delay::new(send_at));
+                        }
+                    }
+                    _ => return Ok(Async::NotReady),
+                }
+            }
+            match self.delay.as_mut().unwrap().poll() {
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                // an unavailable timer is retried after the deadline like an elapsed one
+                Ok(Async::Ready(())) | Err(_) => self.delay = None,
+            }
+        }
+    }
+}
+
+impl Drop for PermitFuture {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            let mut services = self.limits.services.lock().unwrap();
+            if let Some(queue) = services.get_mut(&self.service) {
+                queue.waiters.retain(|waiter| waiter.id != id);
+                queue.notify_head();
+            }
+        }
+    }
+}
+
+/// A request counted as in flight, until the permit is dropped.
+pub(crate) struct Permit {
+    limits: ConcurrencyLimits,
+    service: String,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let mut services = self.limits.services.lock().unwrap();
+        if let Some(queue) = services.get_mut(&self.service) {
+            queue.in_flight -= 1;
+            queue.notify_head();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future;
+
+    #[test]
+    fn intervals_are_the_inverse_of_rates() {
+        assert_eq!(ServiceLimit::new().interval(), None);
+        assert_eq!(
+            ServiceLimit::new()
+                .with_max_requests_per_second(4.0)
+                .interval(),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(
+            ServiceLimit::new()
+                .with_max_requests_per_second(0.5)
+                .interval(),
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn unlimited_services_need_no_permit() {
+        let limits = ConcurrencyLimits::new().with_limit("ec2", ServiceLimit::new());
+        assert!(limits.acquire("ec2").is_some());
+        assert!(limits.acquire("s3").is_none());
+        assert_eq!(limits.metrics("s3"), None);
+    }
+
+    #[test]
+    fn permits_are_granted_in_order_as_others_are_released() {
+        let limits =
+            ConcurrencyLimits::new().with_limit("ec2", ServiceLimit::new().with_max_in_flight(1));
+        future::lazy(move || {
+            let mut first = limits.acquire("ec2").unwrap();
+            let mut second = limits.acquire("ec2").unwrap();
+            let mut third = limits.acquire("ec2").unwrap();
+
+            let permit = match first.poll() {
+                Ok(Async::Ready(permit)) => permit,
+                _ => panic!("the first request waited"),
+            };
+            assert_eq!(second.poll(), Ok(Async::NotReady));
+            assert_eq!(third.poll(), Ok(Async::NotReady));
+            assert_eq!(limits.metrics("ec2").unwrap().queued, 2);
+
+            drop(permit);
+            assert_eq!(third.poll(), Ok(Async::NotReady));
+            let _permit = match second.poll() {
+                Ok(Async::Ready(permit)) => permit,
+                _ => panic!("the second request waited after the first one"),
+            };
+
+            let metrics = limits.metrics("ec2").unwrap();
+            assert_eq!(metrics.requests, 2);
+            assert_eq!(metrics.in_flight, 1);
+            assert_eq!(metrics.queued, 1);
+            Ok::<_, ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    #[test]
+    fn abandoned_requests_leave_the_queue() {
+        let limits =
+            ConcurrencyLimits::new().with_limit("ec2", ServiceLimit::new().with_max_in_flight(1));
+        future::lazy(move || {
+            let permit = limits.acquire("ec2").unwrap().wait();
+            let mut waiting = limits.acquire("ec2").unwrap();
+            assert_eq!(waiting.poll(), Ok(Async::NotReady));
+            drop(waiting);
+            assert_eq!(limits.metrics("ec2").unwrap().queued, 0);
+
+            drop(permit);
+            assert_eq!(limits.metrics("ec2").unwrap().in_flight, 0);
+            Ok::<_, ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    impl PartialEq for Permit {
+        fn eq(&self, other: &Permit) -> bool {
+            self.service == other.service
+        }
+    }
+
+    impl fmt::Debug for Permit {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "Permit({})", self.service)
+        }
+    }
+}
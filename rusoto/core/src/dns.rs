@@ -0,0 +1,343 @@
+//! Resolving the hostnames of endpoints.
+//!
+//! Clients created with `HttpClient::new_with_config` look hostnames up with the
+//! [`DnsResolver`](trait.DnsResolver.html) of their `HttpConfig`, a
+//! [`SystemResolver`](struct.SystemResolver.html) calling `getaddrinfo` on a pool of
+//! threads by default. Any asynchronous resolver, such as the `AsyncResolver` of
+//! trust-dns, can take its place, so that lookups don't wait for a free thread.
+//!
+//! Addresses can be kept for a while with `HttpConfig::dns_cache_ttl`, and connections
+//! to hostnames with both IPv6 and IPv4 addresses race the two families, see
+//! `HttpConfig::happy_eyeballs_timeout`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::net::IpAddr;
+//! use std::time::Duration;
+//!
+//! use futures::future;
+//! use rusoto_core::dns::{DnsAnswer, DnsFuture};
+//! use rusoto_core::{HttpClient, HttpConfig};
+//!
+//! let mut config = HttpConfig::new();
+//! config.dns_resolver(|host: &str| -> DnsFuture {
+//!     let localhost: IpAddr = "127.0.0.1".parse().unwrap();
+//!     println!("resolving {}", host);
+//!     Box::new(future::ok(DnsAnswer::new(vec![localhost])))
+//! });
+//! config.dns_cache_ttl(Some(Duration::from_secs(30)));
+//! let client = HttpClient::new_with_config(config).unwrap();
+//! ```
+
+use std::collections::HashMap;
+use std::io;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use std::vec;
+
+use futures::{future, Future};
+use hyper::client::connect::dns::{GaiResolver, Name, Resolve};
+
+/// How long connecting to the addresses of the family a hostname resolved to first may
+/// take before the other family is tried as well.
+pub const DEFAULT_HAPPY_EYEBALLS_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// The addresses a hostname resolved to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DnsAnswer {
+    /// The addresses, in the order they should be tried.
+    pub addresses: Vec<IpAddr>,
+    /// How long the addresses may be kept, if the resolver knows.
+    pub ttl: Option<Duration>,
+}
+
+impl DnsAnswer {
+    /// An answer with `addresses` and no TTL.
+    pub fn new(addresses: Vec<IpAddr>) -> Self {
+        DnsAnswer {
+            addresses,
+            ttl: None,
+        }
+    }
+
+    /// The same answer, to be kept for `ttl` at most.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+}
+
+/// The future addresses of a hostname.
+pub type DnsFuture = Box<dyn Future<Item = DnsAnswer, Error = io::Error> + Send>;
+
+/// Looks the hostnames of endpoints up.
+pub trait DnsResolver: Send + Sync {
+    /// Resolves `host` into the addresses to connect to.
+    fn resolve(&self, host: &str) -> DnsFuture;
+}
+
+impl<F> DnsResolver for F
+where
+    F: Fn(&str) -> DnsFuture + Send + Sync,
+{
+    fn resolve(&self, host: &str) -> DnsFuture {
+        self(host)
+    }
+}
+
+/// Resolves hostnames with blocking `getaddrinfo` calls on a pool of threads.
+///
+/// Lookups wait for a free thread when there are more of them than threads, and the
+/// system doesn't tell how long addresses may be kept.
+#[derive(Clone)]
+pub struct SystemResolver {
+    inner: GaiResolver,
+}
+
+impl SystemResolver {
+    /// A resolver calling `getaddrinfo` on a pool of `threads` threads.
+    pub fn new(threads: usize) -> Self {
+        SystemResolver {
+            inner: GaiResolver::new(threads),
+        }
+    }
+}
+
+impl DnsResolver for SystemResolver {
+    fn resolve(&self, host: &str) -> DnsFuture {
+        let name: Name = match host.parse() {
+            Ok(name) => name,
+            Err(err) => {
+                return Box::new(future::err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{}: {}", host, err),
+                )))
+            }
+        };
+        Box::new(
+            self.inner
+                .resolve(name)
+                .map(|addresses| DnsAnswer::new(addresses.collect())),
+        )
+    }
+}
+
+/// Addresses of hostnames kept until they expire.
+struct DnsCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Vec<IpAddr>, Instant)>>,
+}
+
+impl DnsCache {
+    fn get(&self, host: &str) -> Option<Vec<IpAddr>> {
+        let mut entries = self.entries.lock().unwrap();
+        let expired = match entries.get(host) {
+            Some(&(ref addresses, expires_at)) if Instant::now() < expires_at => {
+                return Some(addresses.clone())
+            }
+            Some(_) => true,
+            None => false,
+        };
+        if expired {
+            entries.remove(host);
+        }
+        None
+    }
+
+    /// Keeps the addresses of an answer for its TTL, or that of the cache if shorter.
+    fn insert(&self, host: String, answer: &DnsAnswer) {
+        let ttl = match answer.ttl {
+            Some(ttl) if ttl < self.ttl => ttl,
+            _ => self.ttl,
+        };
+        if answer.addresses.is_empty() || ttl == Duration::from_secs(0) {
+            return;
+        }
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(host, (answer.addresses.clone(), Instant::now() + ttl));
+    }
+}
+
+/// The resolver of the connectors of `HttpClient`, looking hostnames up with a
+/// `DnsResolver` and keeping their addresses if configured to.
+#[derive(Clone)]
+pub struct CachingResolver {
+    resolver: Arc<dyn DnsResolver>,
+    cache: Option<Arc<DnsCache>>,
+}
+
+impl CachingResolver {
+    /// Resolves with `resolver`, keeping addresses for `ttl` at most if there is one.
+    pub fn new(resolver: Arc<dyn DnsResolver>, ttl: Option<Duration>) -> Self {
+        CachingResolver {
+            resolver,
+            cache: ttl.map(|ttl| {
+                Arc::new(DnsCache {
+                    ttl,
+                    entries: Mutex::new(HashMap::new()),
+                })
+            }),
+        }
+    }
+}
+
+impl Resolve for CachingResolver {
+    type Addrs = vec::IntoIter<IpAddr>;
+    type Future = Box<dyn Future<Item = Self::Addrs, Error = io::Error> + Send>;
+
+    fn resolve(&self, name: Name) -> Self::Future {
+        let host = name.as_str().to_owned();
+        if let Some(addresses) = self.cache.as_ref().and_then(|cache| cache.get(&host)) {
+            return Box::new(future::ok(addresses.into_iter()));
+        }
+        let cache = self.cache.clone();
+        Box::new(self.resolver.resolve(&host).map(move |answer| {
+            if let Some(cache) = cache {
+                cache.insert(host, &answer);
+            }
+            answer.addresses.into_iter()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::net::{IpAddr, TcpListener};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use futures::{future, Future};
+    use hyper::client::connect::dns::Resolve;
+    use hyper::client::connect::{Connect, Destination};
+
+    use super::{CachingResolver, DnsAnswer, DnsFuture, DnsResolver};
+    use crate::HttpConfig;
+
+    /// Answers with the addresses it is programmed with, counting lookups.
+    #[derive(Default)]
+    struct StubResolver {
+        answer: Mutex<Option<DnsAnswer>>,
+        lookups: AtomicUsize,
+    }
+
+    impl StubResolver {
+        fn answer(&self, addresses: &[&str], ttl: Option<Duration>) {
+            let addresses = addresses
+                .iter()
+                .map(|address| address.parse().unwrap())
+                .collect();
+            *self.answer.lock().unwrap() = Some(DnsAnswer { addresses, ttl });
+        }
+    }
+
+    impl DnsResolver for StubResolver {
+        fn resolve(&self, _host: &str) -> DnsFuture {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            match *self.answer.lock().unwrap() {
+                Some(ref answer) => Box::new(future::ok(answer.clone())),
+                None => Box::new(future::err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "no answer",
+                ))),
+            }
+        }
+    }
+
+    fn resolve(resolver: &CachingResolver) -> io::Result<Vec<IpAddr>> {
+        resolver
+            .resolve("s3-website.test".parse().unwrap())
+            .map(|addresses| addresses.collect())
+            .wait()
+    }
+
+    fn address(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn cached_addresses_are_looked_up_again_once_expired() {
+        let stub = Arc::new(StubResolver::default());
+        stub.answer(&["192.0.2.1"], None);
+        let resolver = CachingResolver::new(stub.clone(), Some(Duration::from_millis(100)));
+
+        assert_eq!(resolve(&resolver).unwrap(), vec![address("192.0.2.1")]);
+        stub.answer(&["192.0.2.2"], None);
+        assert_eq!(resolve(&resolver).unwrap(), vec![address("192.0.2.1")]);
+        assert_eq!(stub.lookups.load(Ordering::SeqCst), 1);
+
+        thread::sleep(Duration::from_millis(150));
+        assert_eq!(resolve(&resolver).unwrap(), vec![address("192.0.2.2")]);
+        assert_eq!(stub.lookups.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn shorter_ttls_of_answers_are_honored() {
+        let stub = Arc::new(StubResolver::default());
+        stub.answer(&["192.0.2.1"], Some(Duration::from_millis(50)));
+        let resolver = CachingResolver::new(stub.clone(), Some(Duration::from_secs(60)));
+
+        resolve(&resolver).unwrap();
+        resolve(&resolver).unwrap();
+        assert_eq!(stub.lookups.load(Ordering::SeqCst), 1);
+        thread::sleep(Duration::from_millis(100));
+        resolve(&resolver).unwrap();
+        assert_eq!(stub.lookups.load(Ordering::SeqCst), 2);
+
+        let stub = Arc::new(StubResolver::default());
+        stub.answer(&["192.0.2.1"], Some(Duration::from_secs(0)));
+        let resolver = CachingResolver::new(stub.clone(), Some(Duration::from_secs(60)));
+        resolve(&resolver).unwrap();
+        resolve(&resolver).unwrap();
+        assert_eq!(stub.lookups.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn failed_lookups_and_uncached_resolvers_always_look_up() {
+        let stub = Arc::new(StubResolver::default());
+        let resolver = CachingResolver::new(stub.clone(), Some(Duration::from_secs(60)));
+        assert_eq!(
+            resolve(&resolver).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+        stub.answer(&["192.0.2.1"], None);
+        assert_eq!(resolve(&resolver).unwrap(), vec![address("192.0.2.1")]);
+
+        let uncached = CachingResolver::new(stub.clone(), None);
+        resolve(&uncached).unwrap();
+        resolve(&uncached).unwrap();
+        assert_eq!(stub.lookups.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn unreachable_ipv6_addresses_fall_back_to_ipv4_after_the_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let stub = Arc::new(StubResolver::default());
+        // 2001:db8::/32 is reserved for documentation, so connecting to it hangs or fails
+        stub.answer(&["2001:db8::1", "127.0.0.1"], None);
+
+        let mut config = HttpConfig::new();
+        let shared = stub.clone();
+        config.dns_resolver(move |host: &str| shared.resolve(host));
+        config.happy_eyeballs_timeout(Some(Duration::from_millis(100)));
+        let connector = crate::request::http_connector(&config);
+        let uri = format!("http://dual-stack.test:{}/", port).parse().unwrap();
+        let destination = Destination::try_from_uri(uri).unwrap();
+
+        let started = Instant::now();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let (stream, _) = runtime
+            .block_on(Connect::connect(&connector, destination))
+            .unwrap();
+        assert_eq!(stream.peer_addr().unwrap().ip(), address("127.0.0.1"));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(stub.lookups.load(Ordering::SeqCst), 1);
+    }
+}
@@ -2,10 +2,16 @@ use std::error::Error;
 use std::fmt;
 use std::io;
 
+use http::StatusCode;
+
+use crate::cancellation::Cancelled;
 use crate::credential::CredentialsError;
 
+use super::event_stream::EventStreamError;
+use super::proto::body_prefix;
 use super::proto::xml::util::XmlParseError;
 use super::request::{BufferedHttpResponse, HttpDispatchError};
+use super::validation::{ValidationError, ValidationErrors};
 
 /// Generic error type returned by all rusoto requests.
 #[derive(Debug, PartialEq)]
@@ -16,12 +22,38 @@ pub enum RusotoError<E> {
     HttpDispatch(HttpDispatchError),
     /// An error was encountered with AWS credentials.
     Credentials(CredentialsError),
-    /// A validation error occurred.  Details from AWS are provided.
-    Validation(String),
+    /// The request is not valid, as reported by the service or found before sending it.
+    Validation {
+        /// The description of the error.
+        message: String,
+        /// The fields which failed validation, when known.
+        ///
+        /// These are the fields listed by the service, in its own naming, or the paths of
+        /// the fields which broke a constraint of the service model before sending.
+        fields: Vec<ValidationError>,
+    },
     /// An error occurred parsing the response payload.
     ParseError(String),
     /// An unknown error occurred.  The raw HTTP response is provided.
+    ///
+    /// This is the error of responses which failed without naming an error of the service
+    /// in a form it could be parsed from, such as the HTML page of a proxy. It displays as
+    /// their status and the start of their body, as `HTTP 403: <html>...`.
     Unknown(BufferedHttpResponse),
+    /// The response isn't in the format of the service's protocol, as when a proxy or a
+    /// captive portal answers with an HTML page in place of the service.
+    UnexpectedContentType {
+        /// The HTTP status of the response.
+        status: StatusCode,
+        /// The content type of the response.
+        content_type: String,
+        /// The start of the body of the response.
+        body: String,
+    },
+    /// The request didn't complete before its deadline.
+    Timeout,
+    /// The request was stopped by its `CancellationToken`.
+    Cancelled,
 }
 
 /// Result carrying a generic `RusotoError`.
@@ -40,6 +72,15 @@ impl<E> From<serde_json::error::Error> for RusotoError<E> {
     }
 }
 
+impl<E> From<ValidationErrors> for RusotoError<E> {
+    fn from(errors: ValidationErrors) -> Self {
+        RusotoError::Validation {
+            message: errors.to_string(),
+            fields: errors.errors().to_vec(),
+        }
+    }
+}
+
 impl<E> From<CredentialsError> for RusotoError<E> {
     fn from(err: CredentialsError) -> Self {
         RusotoError::Credentials(err)
@@ -52,6 +93,18 @@ impl<E> From<HttpDispatchError> for RusotoError<E> {
     }
 }
 
+impl<E> From<EventStreamError> for RusotoError<E> {
+    fn from(err: EventStreamError) -> Self {
+        RusotoError::ParseError(err.to_string())
+    }
+}
+
+impl<E> From<Cancelled> for RusotoError<E> {
+    fn from(_: Cancelled) -> Self {
+        RusotoError::Cancelled
+    }
+}
+
 impl<E> From<io::Error> for RusotoError<E> {
     fn from(err: io::Error) -> Self {
         RusotoError::HttpDispatch(HttpDispatchError::from(err))
@@ -60,7 +113,30 @@ impl<E> From<io::Error> for RusotoError<E> {
 
 impl<E: Error + 'static> fmt::Display for RusotoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            RusotoError::UnexpectedContentType {
+                status,
+                ref content_type,
+                ref body,
+            } => write!(
+                f,
+                "{} ({} {}): {}",
+                self.description(),
+                status.as_u16(),
+                content_type,
+                body
+            ),
+            RusotoError::Unknown(ref response) if response.body.is_empty() => {
+                write!(f, "HTTP {}", response.status.as_u16())
+            }
+            RusotoError::Unknown(ref response) => write!(
+                f,
+                "HTTP {}: {}",
+                response.status.as_u16(),
+                body_prefix(&response.body)
+            ),
+            _ => write!(f, "{}", self.description()),
+        }
     }
 }
 
@@ -68,11 +144,16 @@ impl<E: Error + 'static> Error for RusotoError<E> {
     fn description(&self) -> &str {
         match *self {
             RusotoError::Service(ref err) => err.description(),
-            RusotoError::Validation(ref cause) => cause,
+            RusotoError::Validation { ref message, .. } => message,
             RusotoError::Credentials(ref err) => err.description(),
             RusotoError::HttpDispatch(ref dispatch_error) => dispatch_error.description(),
             RusotoError::ParseError(ref cause) => cause,
             RusotoError::Unknown(ref cause) => cause.body_as_str(),
+            RusotoError::UnexpectedContentType { .. } => {
+                "response is not in the format of the service, it may come from a proxy"
+            }
+            RusotoError::Timeout => "request did not complete before its deadline",
+            RusotoError::Cancelled => "request was cancelled",
         }
     }
 
@@ -85,3 +166,30 @@ impl<E: Error + 'static> Error for RusotoError<E> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::HeaderMap;
+
+    fn unknown(status: u16, body: &[u8]) -> RusotoError<io::Error> {
+        RusotoError::Unknown(BufferedHttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            body: Bytes::from(body.to_vec()),
+            headers: HeaderMap::default(),
+        })
+    }
+
+    #[test]
+    fn unknown_errors_display_their_status_and_the_start_of_their_body() {
+        assert_eq!(
+            unknown(403, b"<html>Access denied</html>").to_string(),
+            "HTTP 403: <html>Access denied</html>"
+        );
+        assert_eq!(unknown(502, b"").to_string(), "HTTP 502");
+
+        let message = unknown(500, "x".repeat(1000).as_bytes()).to_string();
+        assert_eq!(message, format!("HTTP 500: {}...", "x".repeat(256)));
+    }
+}
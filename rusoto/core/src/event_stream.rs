@@ -0,0 +1,736 @@
+//! Messages of event streams, and the signing of the frames carrying them.
+//!
+//! Event streams carry a sequence of messages in a request or response body, each
+//! message being made of headers and a payload, and framed with its length and CRC32
+//! checksums. See the
+//! [description of the encoding](https://docs.aws.amazon.com/transcribe/latest/dg/event-stream.html).
+//!
+//! The events sent to a service are wrapped in signed frames with
+//! [`SignedRequest::sign_event_stream`](../signature/struct.SignedRequest.html#method.sign_event_stream),
+//! and the events received are read from the response body with
+//! [`MessageStream`](struct.MessageStream.html).
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut, IntoBuf};
+use futures::{try_ready, Async, Poll, Stream};
+use hmac::Mac;
+use time::Tm;
+
+use crate::credential::Clock;
+use crate::signature::{clock_time, hmac, signing_key, to_hexdigest};
+
+/// The length of the total length, headers length and prelude checksum of a message.
+const PRELUDE_LENGTH: usize = 12;
+/// The length of the checksum ending a message.
+const CHECKSUM_LENGTH: usize = 4;
+/// The largest message accepted by the services.
+const MAX_MESSAGE_LENGTH: usize = 16 * 1024 * 1024;
+
+lazy_static! {
+    static ref CRC32_TABLE: [u32; 256] = {
+        let mut table = [0; 256];
+        for (n, entry) in table.iter_mut().enumerate() {
+            let mut c = n as u32;
+            for _ in 0..8 {
+                c = if c & 1 == 1 {
+                    0xedb8_8320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                };
+            }
+            *entry = c;
+        }
+        table
+    };
+}
+
+/// The CRC32 (IEEE) checksum of `bytes`.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, byte| {
+        CRC32_TABLE[((crc ^ u32::from(*byte)) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+/// The value of a message header.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HeaderValue {
+    /// A boolean.
+    Bool(bool),
+    /// A signed byte.
+    Byte(i8),
+    /// A 16 bit integer.
+    Short(i16),
+    /// A 32 bit integer.
+    Integer(i32),
+    /// A 64 bit integer.
+    Long(i64),
+    /// Bytes.
+    ByteArray(Bytes),
+    /// A string.
+    String(String),
+    /// A time, in milliseconds since the epoch.
+    Timestamp(i64),
+    /// A UUID.
+    Uuid([u8; 16]),
+}
+
+impl HeaderValue {
+    fn encode(&self, buffer: &mut BytesMut) {
+        match *self {
+            HeaderValue::Bool(true) => buffer.put_u8(0),
+            HeaderValue::Bool(false) => buffer.put_u8(1),
+            HeaderValue::Byte(value) => {
+                buffer.put_u8(2);
+                buffer.put_i8(value);
+            }
+            HeaderValue::Short(value) => {
+                buffer.put_u8(3);
+                buffer.put_i16_be(value);
+            }
+            HeaderValue::Integer(value) => {
+                buffer.put_u8(4);
+                buffer.put_i32_be(value);
+            }
+            HeaderValue::Long(value) => {
+                buffer.put_u8(5);
+                buffer.put_i64_be(value);
+            }
+            HeaderValue::ByteArray(ref value) => {
+                buffer.put_u8(6);
+                buffer.put_u16_be(value.len() as u16);
+                buffer.put_slice(value);
+            }
+            HeaderValue::String(ref value) => {
+                buffer.put_u8(7);
+                buffer.put_u16_be(value.len() as u16);
+                buffer.put_slice(value.as_bytes());
+            }
+            HeaderValue::Timestamp(value) => {
+                buffer.put_u8(8);
+                buffer.put_i64_be(value);
+            }
+            HeaderValue::Uuid(ref value) => {
+                buffer.put_u8(9);
+                buffer.put_slice(value);
+            }
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        1 + match *self {
+            HeaderValue::Bool(_) => 0,
+            HeaderValue::Byte(_) => 1,
+            HeaderValue::Short(_) => 2,
+            HeaderValue::Integer(_) => 4,
+            HeaderValue::Long(_) | HeaderValue::Timestamp(_) => 8,
+            HeaderValue::ByteArray(ref value) => 2 + value.len(),
+            HeaderValue::String(ref value) => 2 + value.len(),
+            HeaderValue::Uuid(_) => 16,
+        }
+    }
+
+    fn decode(buffer: &mut io::Cursor<&[u8]>) -> Result<HeaderValue, EventStreamError> {
+        let value_type = read(buffer, 1)?.get_u8();
+        let value =
+            match value_type {
+                0 => HeaderValue::Bool(true),
+                1 => HeaderValue::Bool(false),
+                2 => HeaderValue::Byte(read(buffer, 1)?.get_i8()),
+                3 => HeaderValue::Short(read(buffer, 2)?.get_i16_be()),
+                4 => HeaderValue::Integer(read(buffer, 4)?.get_i32_be()),
+                5 => HeaderValue::Long(read(buffer, 8)?.get_i64_be()),
+                6 | 7 => {
+                    let len = read(buffer, 2)?.get_u16_be() as usize;
+                    let bytes = read(buffer, len)?;
+                    let value = Bytes::from(bytes.bytes());
+                    if value_type == 6 {
+                        HeaderValue::ByteArray(value)
+                    } else {
+                        HeaderValue::String(String::from_utf8(value.to_vec()).map_err(|_| {
+                            EventStreamError::new("header string is not valid UTF-8")
+                        })?)
+                    }
+                }
+                8 => HeaderValue::Timestamp(read(buffer, 8)?.get_i64_be()),
+                9 => {
+                    let mut value = [0; 16];
+                    read(buffer, 16)?.copy_to_slice(&mut value);
+                    HeaderValue::Uuid(value)
+                }
+                other => {
+                    return Err(EventStreamError::new(format!(
+                        "unknown header value type {}",
+                        other
+                    )))
+                }
+            };
+        Ok(value)
+    }
+}
+
+/// Takes the next `len` bytes of `buffer`, failing if it is shorter.
+fn read<'a>(
+    buffer: &mut io::Cursor<&'a [u8]>,
+    len: usize,
+) -> Result<io::Cursor<&'a [u8]>, EventStreamError> {
+    if buffer.remaining() < len {
+        return Err(EventStreamError::new("headers are truncated"));
+    }
+    let start = buffer.position() as usize;
+    buffer.advance(len);
+    Ok(io::Cursor::new(&buffer.get_ref()[start..start + len]))
+}
+
+/// A header of a message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Header {
+    /// The name of the header.
+    pub name: String,
+    /// The value of the header.
+    pub value: HeaderValue,
+}
+
+impl Header {
+    /// A header named `name` with `value`.
+    pub fn new<N: Into<String>>(name: N, value: HeaderValue) -> Header {
+        Header {
+            name: name.into(),
+            value,
+        }
+    }
+
+    fn encode(&self, buffer: &mut BytesMut) {
+        buffer.put_u8(self.name.len() as u8);
+        buffer.put_slice(self.name.as_bytes());
+        self.value.encode(buffer);
+    }
+
+    fn encoded_len(&self) -> usize {
+        1 + self.name.len() + self.value.encoded_len()
+    }
+}
+
+/// A message of an event stream.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Message {
+    /// The headers of the message, like `:message-type` and `:event-type`.
+    pub headers: Vec<Header>,
+    /// The payload of the message.
+    pub payload: Bytes,
+}
+
+impl Message {
+    /// A message without headers carrying `payload`.
+    pub fn new<B: Into<Bytes>>(payload: B) -> Message {
+        Message {
+            headers: Vec::new(),
+            payload: payload.into(),
+        }
+    }
+
+    /// Adds a header to the message.
+    pub fn with_header<N: Into<String>>(mut self, name: N, value: HeaderValue) -> Message {
+        self.headers.push(Header::new(name, value));
+        self
+    }
+
+    /// The value of the first header named `name`.
+    pub fn header(&self, name: &str) -> Option<&HeaderValue> {
+        self.headers
+            .iter()
+            .find(|header| header.name == name)
+            .map(|header| &header.value)
+    }
+
+    /// The value of the first header named `name`, if it is a string.
+    pub fn header_str(&self, name: &str) -> Option<&str> {
+        match self.header(name) {
+            Some(HeaderValue::String(ref value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Encodes the message, with its prelude and checksums.
+    pub fn encode(&self) -> Bytes {
+        let headers_len: usize = self.headers.iter().map(Header::encoded_len).sum();
+        let total_len = PRELUDE_LENGTH + headers_len + self.payload.len() + CHECKSUM_LENGTH;
+        let mut buffer = BytesMut::with_capacity(total_len);
+        buffer.put_u32_be(total_len as u32);
+        buffer.put_u32_be(headers_len as u32);
+        let prelude_checksum = crc32(&buffer[..8]);
+        buffer.put_u32_be(prelude_checksum);
+        for header in &self.headers {
+            header.encode(&mut buffer);
+        }
+        buffer.put_slice(&self.payload);
+        let checksum = crc32(&buffer);
+        buffer.put_u32_be(checksum);
+        buffer.freeze()
+    }
+
+    /// Decodes the message at the start of `buffer`, removing it from the buffer.
+    ///
+    /// Returns `None` while the buffer doesn't hold the whole message.
+    pub fn decode(buffer: &mut BytesMut) -> Result<Option<Message>, EventStreamError> {
+        if buffer.len() < PRELUDE_LENGTH {
+            return Ok(None);
+        }
+        let (total_len, headers_len, prelude_checksum) = {
+            let mut prelude = io::Cursor::new(&buffer[..PRELUDE_LENGTH]);
+            (
+                prelude.get_u32_be() as usize,
+                prelude.get_u32_be() as usize,
+                prelude.get_u32_be(),
+            )
+        };
+        if crc32(&buffer[..8]) != prelude_checksum {
+            return Err(EventStreamError::new("prelude checksum mismatch"));
+        }
+        if total_len > MAX_MESSAGE_LENGTH
+            || total_len < PRELUDE_LENGTH + headers_len + CHECKSUM_LENGTH
+        {
+            return Err(EventStreamError::new(format!(
+                "invalid message length {}",
+                total_len
+            )));
+        }
+        if buffer.len() < total_len {
+            return Ok(None);
+        }
+
+        let frame = buffer.split_to(total_len);
+        let checksum = (&frame[total_len - CHECKSUM_LENGTH..])
+            .into_buf()
+            .get_u32_be();
+        if crc32(&frame[..total_len - CHECKSUM_LENGTH]) != checksum {
+            return Err(EventStreamError::new("message checksum mismatch"));
+        }
+        let headers_end = PRELUDE_LENGTH + headers_len;
+        let mut headers = Vec::new();
+        let mut cursor = io::Cursor::new(&frame[PRELUDE_LENGTH..headers_end]);
+        while cursor.has_remaining() {
+            let name_len = read(&mut cursor, 1)?.get_u8() as usize;
+            let name = String::from_utf8(read(&mut cursor, name_len)?.bytes().to_vec())
+                .map_err(|_| EventStreamError::new("header name is not valid UTF-8"))?;
+            let value = HeaderValue::decode(&mut cursor)?;
+            headers.push(Header { name, value });
+        }
+        let payload = frame
+            .freeze()
+            .slice(headers_end, total_len - CHECKSUM_LENGTH);
+        Ok(Some(Message { headers, payload }))
+    }
+}
+
+/// An error reading an event stream.
+#[derive(Debug, PartialEq)]
+pub struct EventStreamError {
+    message: String,
+}
+
+impl EventStreamError {
+    /// An error described by `message`.
+    pub fn new<S: Into<String>>(message: S) -> EventStreamError {
+        EventStreamError {
+            message: message.into(),
+        }
+    }
+}
+
+impl Error for EventStreamError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for EventStreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// The messages of an event stream, read from a stream of bytes such as a response body.
+pub struct MessageStream<S> {
+    inner: S,
+    buffer: BytesMut,
+    done: bool,
+}
+
+impl<S> MessageStream<S> {
+    /// Reads the messages of `inner`.
+    pub fn new(inner: S) -> MessageStream<S> {
+        MessageStream {
+            inner,
+            buffer: BytesMut::new(),
+            done: false,
+        }
+    }
+}
+
+impl<S> Stream for MessageStream<S>
+where
+    S: Stream<Item = Bytes>,
+    S::Error: fmt::Display,
+{
+    type Item = Message;
+    type Error = EventStreamError;
+
+    fn poll(&mut self) -> Poll<Option<Message>, EventStreamError> {
+        loop {
+            if let Some(message) = Message::decode(&mut self.buffer)? {
+                return Ok(Async::Ready(Some(message)));
+            }
+            if self.done {
+                if self.buffer.is_empty() {
+                    return Ok(Async::Ready(None));
+                }
+                return Err(EventStreamError::new("the stream ended within a message"));
+            }
+            match try_ready!(self
+                .inner
+                .poll()
+                .map_err(|error| EventStreamError::new(error.to_string())))
+            {
+                Some(bytes) => self.buffer.extend_from_slice(&bytes),
+                None => self.done = true,
+            }
+        }
+    }
+}
+
+/// Signs the frames of an event stream, each signature being chained to the previous one.
+pub struct EventStreamSigner {
+    prior_signature: String,
+    secret: String,
+    region: String,
+    service: String,
+}
+
+impl fmt::Debug for EventStreamSigner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EventStreamSigner")
+            .field("prior_signature", &self.prior_signature)
+            .field("region", &self.region)
+            .field("service", &self.service)
+            .finish()
+    }
+}
+
+impl EventStreamSigner {
+    /// A signer chaining the frames from `seed_signature`, the signature of the request.
+    pub fn new(
+        seed_signature: String,
+        secret: &str,
+        region: &str,
+        service: &str,
+    ) -> EventStreamSigner {
+        EventStreamSigner {
+            prior_signature: seed_signature,
+            secret: secret.to_owned(),
+            region: region.to_owned(),
+            service: service.to_owned(),
+        }
+    }
+
+    /// Wraps `payload`, usually an encoded message, in a frame signed at `date`.
+    ///
+    /// The frame carries the date in its `:date` header and the signature in its
+    /// `:chunk-signature` header. An empty payload signs the frame ending the stream.
+    pub fn sign(&mut self, payload: Bytes, date: Tm) -> Message {
+        let timestamp = date.to_timespec();
+        let date_header = Header::new(
+            ":date",
+            HeaderValue::Timestamp(timestamp.sec * 1000 + i64::from(timestamp.nsec / 1_000_000)),
+        );
+        let mut encoded_date_header = BytesMut::with_capacity(date_header.encoded_len());
+        date_header.encode(&mut encoded_date_header);
+
+        let scope = format!(
+            "{}/{}/{}/aws4_request",
+            date.strftime("%Y%m%d").unwrap(),
+            self.region,
+            self.service
+        );
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256-PAYLOAD\n{}\n{}\n{}\n{}\n{}",
+            date.strftime("%Y%m%dT%H%M%SZ").unwrap(),
+            scope,
+            self.prior_signature,
+            to_hexdigest(&encoded_date_header),
+            to_hexdigest(&payload)
+        );
+        let key = signing_key(&self.secret, date, &self.region, &self.service);
+        let signature = hmac(&key, string_to_sign.as_bytes()).result().code();
+        self.prior_signature = hex::encode(signature.as_ref());
+
+        Message {
+            headers: vec![
+                date_header,
+                Header::new(
+                    ":chunk-signature",
+                    HeaderValue::ByteArray(Bytes::from(signature.as_ref())),
+                ),
+            ],
+            payload,
+        }
+    }
+}
+
+/// The signed frames of a stream of messages, ended by an empty frame.
+///
+/// Messages are only taken from the inner stream when the next frame is asked for, so a
+/// consumer sending the frames as the connection allows, like an HTTP/2 request body, slows
+/// the producer of the messages down to its pace.
+pub struct SignedEventStream<S> {
+    inner: S,
+    signer: EventStreamSigner,
+    clock: Arc<dyn Clock>,
+    done: bool,
+}
+
+impl<S> SignedEventStream<S> {
+    /// Signs the messages of `inner` with `signer`, dating the frames with `clock`.
+    pub fn new(inner: S, signer: EventStreamSigner, clock: Arc<dyn Clock>) -> SignedEventStream<S> {
+        SignedEventStream {
+            inner,
+            signer,
+            clock,
+            done: false,
+        }
+    }
+}
+
+impl<S> Stream for SignedEventStream<S>
+where
+    S: Stream<Item = Message>,
+{
+    type Item = Bytes;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<Bytes>, S::Error> {
+        if self.done {
+            return Ok(Async::Ready(None));
+        }
+        let payload = match try_ready!(self.inner.poll()) {
+            Some(message) => message.encode(),
+            None => {
+                self.done = true;
+                Bytes::new()
+            }
+        };
+        let date = clock_time(&*self.clock);
+        Ok(Async::Ready(Some(self.signer.sign(payload, date).encode())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::{AwsCredentials, ManualClock};
+    use crate::region::Region;
+    use crate::signature::{SignedRequest, SignedRequestPayload};
+    use chrono::{DateTime, Utc};
+    use futures::{stream, Future};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn audio_event(chunk: &'static [u8]) -> Message {
+        Message::new(chunk)
+            .with_header(":message-type", HeaderValue::String("event".to_owned()))
+            .with_header(":event-type", HeaderValue::String("AudioEvent".to_owned()))
+    }
+
+    fn clock() -> Arc<ManualClock> {
+        Arc::new(ManualClock::new(
+            "2019-01-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        ))
+    }
+
+    #[test]
+    fn crc32_of_known_values() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn messages_without_headers_or_payload_are_16_bytes() {
+        let encoded = Message::default().encode();
+        assert_eq!(
+            encoded.to_vec(),
+            vec![0, 0, 0, 16, 0, 0, 0, 0, 0x05, 0xc2, 0x48, 0xeb, 0x7d, 0x98, 0xc8, 0xff]
+        );
+    }
+
+    #[test]
+    fn messages_are_decoded_as_encoded() {
+        let message = Message::new(&b"{\"foo\":\"bar\"}"[..])
+            .with_header("bool", HeaderValue::Bool(false))
+            .with_header("byte", HeaderValue::Byte(-1))
+            .with_header("short", HeaderValue::Short(300))
+            .with_header("integer", HeaderValue::Integer(-70_000))
+            .with_header("long", HeaderValue::Long(1 << 40))
+            .with_header(
+                "bytes",
+                HeaderValue::ByteArray(Bytes::from(&b"\x00\x01"[..])),
+            )
+            .with_header("string", HeaderValue::String("événement".to_owned()))
+            .with_header("timestamp", HeaderValue::Timestamp(1_546_344_000_000))
+            .with_header("uuid", HeaderValue::Uuid([7; 16]));
+        let mut buffer = BytesMut::from(message.encode());
+        buffer.extend_from_slice(&Message::default().encode());
+
+        assert_eq!(Message::decode(&mut buffer), Ok(Some(message)));
+        assert_eq!(Message::decode(&mut buffer), Ok(Some(Message::default())));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn partial_messages_are_left_in_the_buffer() {
+        let encoded = audio_event(b"audio").encode();
+        let mut buffer = BytesMut::from(&encoded[..encoded.len() - 1]);
+
+        assert_eq!(Message::decode(&mut buffer), Ok(None));
+        assert_eq!(buffer.len(), encoded.len() - 1);
+    }
+
+    #[test]
+    fn corrupted_messages_are_rejected() {
+        let mut encoded = audio_event(b"audio").encode().to_vec();
+        let last = encoded.len() - 5;
+        encoded[last] ^= 1;
+
+        assert_eq!(
+            Message::decode(&mut BytesMut::from(encoded)),
+            Err(EventStreamError::new("message checksum mismatch"))
+        );
+    }
+
+    #[test]
+    fn message_stream_reassembles_messages_split_across_chunks() {
+        let mut encoded = audio_event(b"first").encode().to_vec();
+        encoded.extend_from_slice(&audio_event(b"second").encode());
+        let chunks: Vec<Result<Bytes, io::Error>> = encoded
+            .chunks(7)
+            .map(|chunk| Ok(Bytes::from(chunk)))
+            .collect();
+
+        let messages = MessageStream::new(stream::iter_result(chunks))
+            .collect()
+            .wait()
+            .unwrap();
+        assert_eq!(
+            messages,
+            vec![audio_event(b"first"), audio_event(b"second")]
+        );
+    }
+
+    #[test]
+    fn message_stream_fails_on_a_truncated_message() {
+        let encoded = audio_event(b"audio").encode();
+        let chunks: Vec<Result<Bytes, io::Error>> = vec![Ok(encoded.slice_to(10))];
+
+        assert_eq!(
+            MessageStream::new(stream::iter_result(chunks))
+                .collect()
+                .wait(),
+            Err(EventStreamError::new("the stream ended within a message"))
+        );
+    }
+
+    #[test]
+    fn frames_are_signed_with_the_payload_string_to_sign() {
+        let mut signer =
+            EventStreamSigner::new("seed".to_owned(), "secret", "us-east-1", "transcribe");
+        let date = clock_time(&*clock());
+        let frame = signer.sign(Bytes::from(&b"payload"[..]), date);
+
+        let mut date_header = BytesMut::new();
+        frame.headers[0].encode(&mut date_header);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256-PAYLOAD\n20190101T120000Z\n20190101/us-east-1/transcribe/aws4_request\nseed\n{}\n{}",
+            to_hexdigest(&date_header),
+            to_hexdigest(b"payload")
+        );
+        let key = signing_key("secret", date, "us-east-1", "transcribe");
+        let expected = hmac(&key, string_to_sign.as_bytes()).result().code();
+
+        assert_eq!(frame.headers[0].name, ":date");
+        assert_eq!(
+            frame.header(":date"),
+            Some(&HeaderValue::Timestamp(1_546_344_000_000))
+        );
+        assert_eq!(
+            frame.header(":chunk-signature"),
+            Some(&HeaderValue::ByteArray(Bytes::from(expected.as_ref())))
+        );
+        assert_eq!(signer.prior_signature, hex::encode(expected.as_ref()));
+    }
+
+    #[test]
+    fn event_stream_frames_are_chained_from_the_request_signature() {
+        let clock = clock();
+        let mut request = SignedRequest::new(
+            "POST",
+            "transcribe",
+            &Region::UsEast1,
+            "/stream-transcription",
+        );
+        let events = stream::iter_ok(vec![audio_event(b"first"), audio_event(b"second")]);
+        request.sign_event_stream(
+            &AwsCredentials::new("key", "secret", None, None),
+            clock.clone(),
+            events,
+        );
+
+        let authorization =
+            String::from_utf8(request.headers()["authorization"][0].clone()).unwrap();
+        let seed = authorization
+            .rsplit("Signature=")
+            .next()
+            .unwrap()
+            .to_owned();
+        assert_eq!(
+            request.headers()["x-amz-content-sha256"],
+            vec![b"STREAMING-AWS4-HMAC-SHA256-EVENTS".to_vec()]
+        );
+        assert!(!request.headers().contains_key("content-length"));
+
+        let body = match request.payload {
+            Some(SignedRequestPayload::Stream(stream)) => stream,
+            _ => panic!("the request has no event stream"),
+        };
+        let frames = MessageStream::new(body).collect().wait().unwrap();
+        assert_eq!(frames.len(), 3);
+
+        let date = clock_time(&*clock);
+        let mut signer = EventStreamSigner::new(seed, "secret", "us-east-1", "transcribe");
+        for (frame, event) in frames.iter().zip(vec![
+            audio_event(b"first").encode(),
+            audio_event(b"second").encode(),
+            Bytes::new(),
+        ]) {
+            assert_eq!(frame.payload, event);
+            assert_eq!(*frame, signer.sign(event, date));
+        }
+    }
+
+    #[test]
+    fn events_are_only_taken_when_frames_are_asked_for() {
+        let taken = Arc::new(AtomicUsize::new(0));
+        let counter = taken.clone();
+        let events =
+            stream::iter_ok::<_, io::Error>(vec![audio_event(b"first"), audio_event(b"second")])
+                .inspect(move |_| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                });
+        let signer = EventStreamSigner::new("seed".to_owned(), "secret", "us-east-1", "transcribe");
+        let mut frames = SignedEventStream::new(events, signer, clock());
+
+        assert_eq!(taken.load(Ordering::SeqCst), 0);
+        assert!(frames.poll().unwrap().is_ready());
+        assert_eq!(taken.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,276 @@
+//! Holding back request bodies until the server agrees to receive them.
+//!
+//! A request sent with an `Expect: 100-continue` header asks the server to answer with an
+//! interim `100 Continue` response before its body is sent, so that the body of a request
+//! the server rejects from its head alone, such as for its signature or a missing bucket,
+//! isn't sent for nothing. hyper skips interim responses on its way to the final one, so
+//! the transports of its connections are wrapped to watch the status line of the response
+//! to a request whose body is held back. The body is sent once the server agrees, or after
+//! a timeout for servers which don't answer with interim responses, and never once the
+//! server has given its final response.
+//!
+//! hyper polls the body of an HTTP/1.1 request, and reads its response, within the task
+//! of its connection: a body held back leaves its signal in that task for the transport
+//! to find.
+
+use std::cell::RefCell;
+use std::cmp;
+use std::io::{self, Read, Write};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use bytes::Buf;
+use futures::future::Map;
+use futures::task::{self, AtomicTask};
+use futures::{task_local, Async, Future, Poll};
+use hyper::client::connect::{Connect, Connected, Destination};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_timer::Delay;
+
+/// The size of the smallest body held back by default.
+pub(crate) const DEFAULT_THRESHOLD: u64 = 1024 * 1024;
+
+/// How long a body is held back by default for a server which doesn't answer.
+pub(crate) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The start of a status line, up to its status code, as in `HTTP/1.1 100`.
+const STATUS_LINE_PREFIX_LEN: usize = 12;
+
+// The signal of the body held back in the task of a connection, if any.
+task_local! {
+    static WAITING: RefCell<Option<Arc<ContinueSignal>>> = RefCell::new(None)
+}
+
+/// Which bodies are held back, and for how long.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ExpectContinue {
+    /// The size of the smallest body held back.
+    pub threshold: u64,
+    /// How long a body is held back for a server which doesn't answer.
+    pub timeout: Duration,
+}
+
+impl Default for ExpectContinue {
+    fn default() -> ExpectContinue {
+        ExpectContinue {
+            threshold: DEFAULT_THRESHOLD,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+/// What the server answered to the head of a request whose body is held back.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Answer {
+    /// `100 Continue`: the body can be sent.
+    Continue,
+    /// A final response: the body must not be sent.
+    Rejected,
+    /// Another interim response, or something other than HTTP/1.x, leaving it to the
+    /// timeout.
+    Unknown,
+}
+
+#[derive(Debug, Default)]
+struct SignalState {
+    /// The start of the status line of the response read so far.
+    status_line: Vec<u8>,
+    answer: Option<Answer>,
+}
+
+/// Shared by a body held back and the transport reading its response.
+#[derive(Debug, Default)]
+struct ContinueSignal {
+    state: Mutex<SignalState>,
+    task: AtomicTask,
+}
+
+impl ContinueSignal {
+    /// Looks for the status of the response in the bytes read from the connection.
+    fn observe(&self, bytes: &[u8]) {
+        let mut state = self.state.lock().unwrap();
+        if state.answer.is_some() {
+            return;
+        }
+        let missing = STATUS_LINE_PREFIX_LEN - state.status_line.len();
+        state
+            .status_line
+            .extend_from_slice(&bytes[..cmp::min(missing, bytes.len())]);
+        if state.status_line.len() < STATUS_LINE_PREFIX_LEN {
+            return;
+        }
+        let status = &state.status_line[9..STATUS_LINE_PREFIX_LEN];
+        let answer = if !state.status_line.starts_with(b"HTTP/1.") {
+            Answer::Unknown
+        } else if status == b"100" {
+            Answer::Continue
+        } else if status[0] == b'1' {
+            Answer::Unknown
+        } else {
+            Answer::Rejected
+        };
+        state.answer = Some(answer);
+        drop(state);
+        self.task.notify();
+    }
+
+    fn answer(&self) -> Option<Answer> {
+        self.state.lock().unwrap().answer
+    }
+}
+
+/// Holds back the body of a request sent with `Expect: 100-continue`.
+#[derive(Debug)]
+pub(crate) struct ContinueWait {
+    signal: Arc<ContinueSignal>,
+    timeout: Duration,
+    /// Started on the first poll, once the head of the request is on its way.
+    deadline: Option<Delay>,
+}
+
+impl ContinueWait {
+    pub(crate) fn new(timeout: Duration) -> ContinueWait {
+        ContinueWait {
+            signal: Arc::default(),
+            timeout,
+            deadline: None,
+        }
+    }
+
+    /// Ready once the body can be sent, because the server agreed or didn't answer in
+    /// time. It is never ready once the server rejected the request, as the server closes
+    /// the connection after its final response.
+    pub(crate) fn poll(&mut self) -> Async<()> {
+        self.signal.task.register();
+        if self.deadline.is_none() {
+            let signal = self.signal.clone();
+            WAITING.with(|waiting| *waiting.borrow_mut() = Some(signal));
+            self.deadline = Some(Delay::new(Instant::now() + self.timeout));
+        }
+
+        match self.signal.answer() {
+            Some(Answer::Continue) => {}
+            Some(Answer::Rejected) => return Async::NotReady,
+            Some(Answer::Unknown) | None => {
+                if let Some(Ok(Async::NotReady)) = self.deadline.as_mut().map(Delay::poll) {
+                    return Async::NotReady;
+                }
+                // without a timer the body is sent right away, as it would be without
+                // `Expect: 100-continue`
+            }
+        }
+        self.stop_waiting();
+        Async::Ready(())
+    }
+
+    fn stop_waiting(&self) {
+        if self.deadline.is_some() && task::is_in_task() {
+            WAITING.with(|waiting| {
+                let mut waiting = waiting.borrow_mut();
+                let is_this_signal = waiting
+                    .as_ref()
+                    .map_or(false, |signal| Arc::ptr_eq(signal, &self.signal));
+                if is_this_signal {
+                    waiting.take();
+                }
+            });
+        }
+    }
+}
+
+impl Drop for ContinueWait {
+    fn drop(&mut self) {
+        self.stop_waiting();
+    }
+}
+
+/// Wraps the transports of another connector to watch the responses they read.
+pub(crate) struct ContinueConnector<C>(pub C);
+
+type WrapTransport<T> = fn((T, Connected)) -> (ContinueIo<T>, Connected);
+
+impl<C: Connect> Connect for ContinueConnector<C> {
+    type Transport = ContinueIo<C::Transport>;
+    type Error = C::Error;
+    type Future = Map<C::Future, WrapTransport<C::Transport>>;
+
+    fn connect(&self, dst: Destination) -> Self::Future {
+        let wrap: WrapTransport<C::Transport> = |(io, connected)| (ContinueIo(io), connected);
+        self.0.connect(dst).map(wrap)
+    }
+}
+
+/// A transport handing what it reads to the body held back in its task, if any.
+#[derive(Debug)]
+pub(crate) struct ContinueIo<T>(T);
+
+impl<T: Read> Read for ContinueIo<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.0.read(buf)?;
+        if read > 0 && task::is_in_task() {
+            WAITING.with(|waiting| {
+                if let Some(ref signal) = *waiting.borrow() {
+                    signal.observe(&buf[..read]);
+                }
+            });
+        }
+        Ok(read)
+    }
+}
+
+impl<T: Write> Write for ContinueIo<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl<T: AsyncRead> AsyncRead for ContinueIo<T> {
+    unsafe fn prepare_uninitialized_buffer(&self, buf: &mut [u8]) -> bool {
+        self.0.prepare_uninitialized_buffer(buf)
+    }
+}
+
+impl<T: AsyncWrite> AsyncWrite for ContinueIo<T> {
+    fn shutdown(&mut self) -> Poll<(), io::Error> {
+        self.0.shutdown()
+    }
+
+    fn write_buf<B: Buf>(&mut self, buf: &mut B) -> Poll<usize, io::Error> {
+        self.0.write_buf(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, ContinueSignal};
+
+    fn answer_to(chunks: &[&[u8]]) -> Option<Answer> {
+        let signal = ContinueSignal::default();
+        for chunk in chunks {
+            signal.observe(chunk);
+        }
+        signal.answer()
+    }
+
+    #[test]
+    fn status_lines_are_read_across_chunks() {
+        assert_eq!(
+            answer_to(&[b"HTTP/1.1 1", b"00 Continue\r\n"]),
+            Some(Answer::Continue)
+        );
+        assert_eq!(answer_to(&[b"HTTP/1.1 40"]), None);
+        assert_eq!(
+            answer_to(&[b"HTTP/1.1 403 Forbidden\r\n", b"HTTP/1.1 100"]),
+            Some(Answer::Rejected)
+        );
+        assert_eq!(
+            answer_to(&[b"HTTP/1.1 102 Processing\r\n"]),
+            Some(Answer::Unknown)
+        );
+        assert_eq!(answer_to(&[b"garbage garbage"]), Some(Answer::Unknown));
+    }
+}
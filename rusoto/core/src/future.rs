@@ -1,12 +1,15 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use futures::sync::oneshot::spawn;
 use futures::{Async, Future, IntoFuture, Poll};
 use tokio::runtime::Runtime;
+use tokio_timer::Delay;
 
-use super::client::{SignAndDispatchError, TimeoutFuture};
+use super::cancellation::CancellationToken;
+use super::client::{OperationConfig, SignAndDispatchError, TimeoutFuture};
 use super::error::{RusotoError, RusotoResult};
-use super::request::HttpResponse;
+use super::request::{HttpDispatchError, HttpResponse};
+use super::signature::SignedRequest;
 
 lazy_static! {
     static ref FALLBACK_RUNTIME: Runtime = Runtime::new().unwrap();
@@ -143,6 +146,9 @@ lazy_static! {
 /// ```
 pub struct RusotoFuture<T, E> {
     state: Option<RusotoFutureState<T, E>>,
+    deadline: Option<Duration>,
+    deadline_delay: Option<Delay>,
+    cancellation: Option<CancellationToken>,
 }
 
 pub fn new<T, E>(
@@ -151,6 +157,9 @@ pub fn new<T, E>(
 ) -> RusotoFuture<T, E> {
     RusotoFuture {
         state: Some(RusotoFutureState::SignAndDispatch { future, handler }),
+        deadline: None,
+        deadline_delay: None,
+        cancellation: None,
     }
 }
 
@@ -202,6 +211,139 @@ impl<T, E> RusotoFuture<T, E> {
         }
     }
 
+    /// Override the timeout and retry policy of the client for this call.
+    ///
+    /// Settings left as `None` in `config` keep their current value. This is only
+    /// guaranteed to take effect when called before the future is polled for the
+    /// first time.
+    pub fn with_operation_config(mut self, config: OperationConfig) -> Self {
+        if let Some(RusotoFutureState::SignAndDispatch { ref mut future, .. }) = self.state {
+            if let Some(timeout) = config.timeout {
+                future.set_timeout(timeout);
+            }
+            if let Some(retry_policy) = config.retry_policy_override {
+                future.set_retry_policy(retry_policy);
+            }
+        }
+        self
+    }
+
+    /// Add a header to the request before it is signed.
+    ///
+    /// This is how headers which the generated input types have no field for are sent
+    /// with a call. The header is signed along with the others, and kept when the request
+    /// is retried. The value is added to those the request already has for the header.
+    /// This is only guaranteed to take effect when called before the future is
+    /// polled for the first time.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        if let Some(RusotoFutureState::SignAndDispatch { ref mut future, .. }) = self.state {
+            future.add_header(name, value);
+        }
+        self
+    }
+
+    /// Set the `Accept` header of the request before it is signed.
+    ///
+    /// This replaces the media type the generated code asks for, for endpoints which
+    /// need another one to answer in the format rusoto parses. This is only guaranteed
+    /// to take effect when called before the future is polled for the first time.
+    pub fn with_accept(mut self, accept: &str) -> Self {
+        if let Some(RusotoFutureState::SignAndDispatch { ref mut future, .. }) = self.state {
+            future.set_accept(accept);
+        }
+        self
+    }
+
+    /// Change the request with `customize` before it is signed.
+    ///
+    /// `customize` is given the request once the input has been serialized into it, for
+    /// last-mile changes the generated code has no way to make, such as setting a header
+    /// like `x-amz-expected-bucket-owner` on every call. The changes are signed with the
+    /// rest of the request, and kept when the request is retried. This is only guaranteed
+    /// to take effect when called before the future is polled for the first time.
+    pub fn customize<F>(mut self, customize: F) -> Self
+    where
+        F: FnOnce(&mut SignedRequest),
+    {
+        if let Some(RusotoFutureState::SignAndDispatch { ref mut future, .. }) = self.state {
+            let mut customize = Some(customize);
+            future.customize(&mut |request| {
+                if let Some(customize) = customize.take() {
+                    customize(request);
+                }
+            });
+        }
+        self
+    }
+
+    /// Add a query parameter to the request before it is signed.
+    ///
+    /// This is how query parameters which the generated input types have no field for,
+    /// such as sub-resources selecting an operation, are sent with a call. A parameter
+    /// without a value is sent as a bare key, like `?acl`. The parameter is part of the
+    /// canonical query string, and replaces any value the request already has for it.
+    /// This is only guaranteed to take effect when called before the future is
+    /// polled for the first time.
+    pub fn with_param(mut self, key: &str, value: Option<&str>) -> Self {
+        if let Some(RusotoFutureState::SignAndDispatch { ref mut future, .. }) = self.state {
+            future.add_param(key, value);
+        }
+        self
+    }
+
+    /// Set a deadline on the whole request, after which the future resolves to
+    /// `RusotoError::Timeout`.
+    ///
+    /// Unlike the timeout, which applies to each attempt at dispatching the
+    /// request, the deadline covers fetching credentials, every attempt along with
+    /// the backoff between them, and reading the response. It starts when the
+    /// future is first polled.
+    ///
+    /// ```rust,ignore
+    /// # // TODO: remove ignore when the cyclic dependency issue has been fixed
+    /// # // https://github.com/rusoto/rusoto/pull/1141#issuecomment-421865362
+    /// # extern crate rusoto_core;
+    /// # extern crate rusoto_s3;
+    /// #
+    /// # use std::time::Duration;
+    /// # use rusoto_core::Region;
+    /// # use rusoto_s3::{S3, S3Client};
+    /// #
+    /// # let s3 = S3Client::new(Region::default());
+    /// #
+    /// let future = s3.list_buckets()
+    ///     .with_timeout(Duration::from_secs(1))
+    ///     .with_deadline(Duration::from_secs(5));
+    /// ```
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.set_deadline(deadline);
+        self
+    }
+
+    /// Set a deadline on the whole request, see `with_deadline`.
+    ///
+    /// This is only guaranteed to take effect when called before the future
+    /// is polled for the first time.
+    pub fn set_deadline(&mut self, deadline: Duration) {
+        self.deadline = Some(deadline);
+    }
+
+    /// Clear the deadline on the future.
+    pub fn clear_deadline(&mut self) {
+        self.deadline = None;
+        self.deadline_delay = None;
+    }
+
+    /// Stop the request when `token` is cancelled, resolving to `RusotoError::Cancelled`.
+    ///
+    /// Whatever the request is doing when the token is cancelled, whether fetching
+    /// credentials, waiting for a response or backing off before a retry, is abandoned
+    /// and no further attempt is made.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
     /// Blocks the current thread until the future has resolved.
     ///
     /// This is meant to provide a simple way for non-async consumers
@@ -228,6 +370,9 @@ impl<T, E> RusotoFuture<T, E> {
         let fut = fut.into_future();
         RusotoFuture {
             state: Some(RusotoFutureState::RunningResponseHandler(Box::new(fut))),
+            deadline: None,
+            deadline_delay: None,
+            cancellation: None,
         }
     }
 }
@@ -237,6 +382,28 @@ impl<T, E> Future for RusotoFuture<T, E> {
     type Error = RusotoError<E>;
 
     fn poll(&mut self) -> Poll<T, RusotoError<E>> {
+        if let Some(ref token) = self.cancellation {
+            if token.poll_cancelled().is_ready() {
+                return Err(RusotoError::Cancelled);
+            }
+        }
+
+        if let Some(deadline) = self.deadline {
+            let delay = self
+                .deadline_delay
+                .get_or_insert_with(|| Delay::new(Instant::now() + deadline));
+            match delay.poll() {
+                Ok(Async::NotReady) => {}
+                Ok(Async::Ready(())) => return Err(RusotoError::Timeout),
+                Err(err) => {
+                    return Err(RusotoError::HttpDispatch(HttpDispatchError::new(format!(
+                        "deadline error: {}",
+                        err
+                    ))))
+                }
+            }
+        }
+
         match self.state.take().unwrap() {
             RusotoFutureState::SignAndDispatch {
                 mut future,
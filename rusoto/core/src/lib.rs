@@ -20,6 +20,8 @@
 //! A high level overview is available in `README.md` at <https://github.com/rusoto/rusoto>.
 
 extern crate bytes;
+#[cfg(test)]
+extern crate chrono;
 extern crate futures;
 extern crate hyper;
 #[cfg(feature = "rustls")]
@@ -44,20 +46,44 @@ extern crate time;
 extern crate tokio;
 extern crate tokio_timer;
 extern crate percent_encoding;
+extern crate regex;
 extern crate xml;
 
 mod client;
 mod error;
+mod expect_continue;
 mod future;
 mod stream;
 
+pub mod arn;
+pub mod async_job;
+pub mod audit;
+pub mod budget;
+pub mod cancellation;
+pub mod checksum;
+pub mod concurrency;
+pub mod dns;
+pub mod event_stream;
+pub mod multi_region;
+pub mod pagination;
 pub mod param;
+pub mod progress;
 pub mod region;
 pub mod request;
+pub mod retry;
 pub mod signature;
+#[cfg(feature = "sigv4a")]
+mod sigv4a;
+#[cfg(any(test, feature = "test-util"))]
+pub mod testing;
+pub mod validation;
 
 #[doc(hidden)]
-pub use crate::client::Client;
+pub use crate::client::{
+    Client, Drain, DrainOutcome, EndpointWarmUp, OperationConfig, WarmUp, WarmUpReport,
+};
+pub use crate::arn::Arn;
+pub use crate::concurrency::ConcurrencyLimits;
 #[doc(hidden)]
 pub mod proto;
 #[doc(hidden)]
@@ -67,5 +93,10 @@ pub use crate::credential::{CredentialsError, DefaultCredentialsProvider, Provid
 pub use crate::error::{RusotoError, RusotoResult};
 pub use crate::future::RusotoFuture;
 pub use crate::region::Region;
-pub use crate::request::{DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError};
-pub use crate::stream::ByteStream;
+pub use crate::request::{
+    DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError, HttpVersionPolicy,
+};
+pub use crate::retry::{
+    ResponseRecord, RetryBudget, RetryObserver, RetryPolicy, RetryRecord, TransferRecord,
+};
+pub use crate::stream::{ByteStream, FileBody, ReplayableBody};
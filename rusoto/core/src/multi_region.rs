@@ -0,0 +1,313 @@
+//! Running the same call in many regions at once.
+//!
+//! ```rust,ignore
+//! # // TODO: remove ignore when the cyclic dependency issue has been fixed
+//! # // https://github.com/rusoto/rusoto/pull/1141#issuecomment-421865362
+//! # extern crate rusoto_core;
+//! # extern crate rusoto_s3;
+//! #
+//! # use futures::Future;
+//! # use rusoto_core::multi_region::MultiRegion;
+//! # use rusoto_core::{Client, Region};
+//! # use rusoto_s3::{S3, S3Client};
+//! #
+//! let regions = vec![Region::UsEast1, Region::EuWest1, Region::ApEast1];
+//! let results = MultiRegion::new(regions)
+//!     .with_concurrency(2)
+//!     .run(&Client::shared(), |client, region| {
+//!         S3Client::new_with_client(client, region).list_buckets()
+//!     })
+//!     .wait()
+//!     .unwrap();
+//! for (region, result) in results {
+//!     match result {
+//!         Ok(output) => println!("{}: {:?}", region.name(), output.buckets),
+//!         Err(error) => println!("{} failed: {}", region.name(), error),
+//!     }
+//! }
+//! ```
+
+use futures::future::{self, Loop};
+use futures::{stream, Future, IntoFuture, Stream};
+
+use crate::client::Client;
+use crate::error::{RusotoError, RusotoResult};
+use crate::region::Region;
+
+/// The number of regions called at once by default.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// The result of the call in each region, in the order the regions were given.
+pub type RegionResults<T, E> = Vec<(Region, RusotoResult<T, E>)>;
+
+/// Runs a call in several regions, a bounded number of them at a time.
+///
+/// The call failing in a region does not affect the other regions, so regions which
+/// are not enabled for an account simply report their error. Optionally, the remaining
+/// calls can be cancelled as soon as one of them fails.
+#[derive(Clone, Debug)]
+pub struct MultiRegion {
+    regions: Vec<Region>,
+    concurrency: usize,
+    cancel_on_error: bool,
+}
+
+impl MultiRegion {
+    /// Calls each of `regions`, `DEFAULT_CONCURRENCY` of them at a time.
+    pub fn new<I>(regions: I) -> MultiRegion
+    where
+        I: IntoIterator<Item = Region>,
+    {
+        MultiRegion {
+            regions: regions.into_iter().collect(),
+            concurrency: DEFAULT_CONCURRENCY,
+            cancel_on_error: false,
+        }
+    }
+
+    /// Sets the number of regions called at once, at least one.
+    pub fn with_concurrency(mut self, concurrency: usize) -> MultiRegion {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets whether the calls still running or not started yet are cancelled once a
+    /// call fails.
+    ///
+    /// The results then only include the regions whose call completed, up to and
+    /// including the failed one.
+    pub fn cancel_on_error(mut self, cancel_on_error: bool) -> MultiRegion {
+        self.cancel_on_error = cancel_on_error;
+        self
+    }
+
+    /// Runs `call` with a clone of `client` for each region.
+    ///
+    /// The returned future never fails, the outcome of each call is in its results.
+    pub fn run<F, R, T, E>(
+        self,
+        client: &Client,
+        call: F,
+    ) -> Box<dyn Future<Item = RegionResults<T, E>, Error = ()> + Send>
+    where
+        F: Fn(Client, Region) -> R + Send + 'static,
+        R: IntoFuture<Item = T, Error = RusotoError<E>>,
+        R::Future: Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let MultiRegion {
+            regions,
+            concurrency,
+            cancel_on_error,
+        } = self;
+        let client = client.clone();
+
+        let calls = stream::iter_ok::<_, ()>(regions.into_iter().enumerate())
+            .map(move |(index, region)| {
+                call(client.clone(), region.clone())
+                    .into_future()
+                    .then(move |result| Ok((index, region, result)))
+            })
+            .buffer_unordered(concurrency);
+
+        // dropping the stream of calls cancels those still running
+        let results = future::loop_fn((calls, Vec::new()), move |(calls, mut results)| {
+            calls
+                .into_future()
+                .map_err(|(error, _)| error)
+                .map(move |(completed, calls)| match completed {
+                    Some((index, region, result)) => {
+                        let failed = result.is_err();
+                        results.push((index, region, result));
+                        if failed && cancel_on_error {
+                            Loop::Break(results)
+                        } else {
+                            Loop::Continue((calls, results))
+                        }
+                    }
+                    None => Loop::Break(results),
+                })
+        })
+        .map(|mut results| {
+            results.sort_by_key(|&(index, _, _)| index);
+            results
+                .into_iter()
+                .map(|(_, region, result)| (region, result))
+                .collect()
+        });
+
+        Box::new(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use futures::Future;
+    use http::{HeaderMap, StatusCode};
+    use tokio_timer::Delay;
+
+    use super::*;
+    use crate::credential::StaticProvider;
+    use crate::request::{DispatchSignedRequest, HttpDispatchError, HttpResponse};
+    use crate::signature::SignedRequest;
+    use crate::stream::ByteStream;
+
+    /// Answers after a short delay, with 403 for the hosts of `denied` regions and 200
+    /// for the others.
+    struct HostDispatcher {
+        denied: Vec<String>,
+        hosts: Arc<Mutex<Vec<String>>>,
+        running: Arc<Mutex<usize>>,
+        max_running: Arc<Mutex<usize>>,
+    }
+
+    impl HostDispatcher {
+        fn new(denied: &[Region]) -> HostDispatcher {
+            HostDispatcher {
+                denied: denied
+                    .iter()
+                    .map(|region| format!("sqs.{}.amazonaws.com", region.name()))
+                    .collect(),
+                hosts: Arc::new(Mutex::new(Vec::new())),
+                running: Arc::new(Mutex::new(0)),
+                max_running: Arc::new(Mutex::new(0)),
+            }
+        }
+    }
+
+    impl DispatchSignedRequest for HostDispatcher {
+        type Future = Box<dyn Future<Item = HttpResponse, Error = HttpDispatchError> + Send>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            let host = request.hostname();
+            let status = if self.denied.contains(&host) {
+                StatusCode::FORBIDDEN
+            } else {
+                StatusCode::OK
+            };
+            self.hosts.lock().unwrap().push(host);
+            let mut running = self.running.lock().unwrap();
+            *running += 1;
+            let mut max_running = self.max_running.lock().unwrap();
+            *max_running = (*max_running).max(*running);
+
+            let done = self.running.clone();
+            Box::new(
+                Delay::new(Instant::now() + Duration::from_millis(20)).then(move |_| {
+                    *done.lock().unwrap() -= 1;
+                    Ok(HttpResponse {
+                        status,
+                        body: ByteStream::from(Vec::new()),
+                        headers: HeaderMap::default(),
+                        url: None,
+                    })
+                }),
+            )
+        }
+    }
+
+    fn status_handler(
+        response: HttpResponse,
+    ) -> Box<dyn Future<Item = u16, Error = RusotoError<()>> + Send> {
+        if response.status.is_success() {
+            Box::new(future::ok(response.status.as_u16()))
+        } else {
+            Box::new(
+                response
+                    .buffer()
+                    .from_err()
+                    .and_then(|response| Err(RusotoError::Unknown(response))),
+            )
+        }
+    }
+
+    fn run(
+        dispatcher: HostDispatcher,
+        multi_region: MultiRegion,
+    ) -> Vec<(Region, Result<u16, u16>)> {
+        let client = Client::new_with(
+            StaticProvider::new_minimal("key".into(), "secret".into()),
+            dispatcher,
+        );
+        let work = multi_region.run(&client, |client, region| {
+            client.sign_and_dispatch(
+                SignedRequest::new("POST", "sqs", &region, "/"),
+                status_handler,
+            )
+        });
+        let results = tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(work)
+            .unwrap();
+        results
+            .into_iter()
+            .map(|(region, result)| {
+                let result = result.map_err(|error| match error {
+                    RusotoError::Unknown(response) => response.status.as_u16(),
+                    other => panic!("unexpected error {:?}", other),
+                });
+                (region, result)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn failed_regions_do_not_fail_the_others() {
+        let dispatcher = HostDispatcher::new(&[Region::ApEast1]);
+        let regions = vec![Region::UsEast1, Region::ApEast1, Region::EuWest1];
+
+        let results = run(dispatcher, MultiRegion::new(regions));
+        assert_eq!(
+            results,
+            vec![
+                (Region::UsEast1, Ok(200)),
+                (Region::ApEast1, Err(403)),
+                (Region::EuWest1, Ok(200)),
+            ]
+        );
+    }
+
+    #[test]
+    fn calls_are_bounded_by_the_concurrency() {
+        let dispatcher = HostDispatcher::new(&[]);
+        let max_running = dispatcher.max_running.clone();
+        let regions = vec![
+            Region::UsEast1,
+            Region::UsEast2,
+            Region::UsWest1,
+            Region::UsWest2,
+            Region::EuWest1,
+        ];
+
+        let results = run(dispatcher, MultiRegion::new(regions).with_concurrency(2));
+        assert_eq!(results.len(), 5);
+        assert!(results.iter().all(|(_, result)| result.is_ok()));
+        assert_eq!(*max_running.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn remaining_calls_can_be_cancelled_on_error() {
+        let dispatcher = HostDispatcher::new(&[Region::ApEast1]);
+        let hosts = dispatcher.hosts.clone();
+        let regions = vec![Region::UsEast1, Region::ApEast1, Region::EuWest1];
+
+        let results = run(
+            dispatcher,
+            MultiRegion::new(regions)
+                .with_concurrency(1)
+                .cancel_on_error(true),
+        );
+        assert_eq!(
+            results,
+            vec![(Region::UsEast1, Ok(200)), (Region::ApEast1, Err(403))]
+        );
+        assert_eq!(
+            *hosts.lock().unwrap(),
+            vec!["sqs.us-east-1.amazonaws.com", "sqs.ap-east-1.amazonaws.com"]
+        );
+    }
+}
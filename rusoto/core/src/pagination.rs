@@ -0,0 +1,693 @@
+//! Iterating over the results of paginated operations.
+//!
+//! Operations such as `ListObjectsV2` return their results a page at a time, along with a
+//! token to request the next page with. A [`Paginator`](struct.Paginator.html) requests the
+//! pages one after the other and streams their items. Paging through a huge result set can
+//! take a long time and many requests, so a paginator can stop after a number of pages or
+//! items, telling through its [`PaginationProgress`](struct.PaginationProgress.html) whether
+//! it stopped before the last page.
+//!
+//! The items of a paginator can be projected down to what's needed of them with
+//! [`select`](struct.Paginator.html#method.select), or with a path such as
+//! `instances[*].instance_id` given to
+//! [`select_path`](struct.Paginator.html#method.select_path), still fetching the pages one
+//! after the other as the projected values are consumed.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! # // TODO: remove ignore when the cyclic dependency issue has been fixed
+//! # // https://github.com/rusoto/rusoto/pull/1141#issuecomment-421865362
+//! use futures::{Future, Stream};
+//! use rusoto_core::pagination::Paginator;
+//! use rusoto_core::Region;
+//! use rusoto_s3::{ListObjectsV2Request, S3, S3Client};
+//!
+//! let client = S3Client::new(Region::UsEast1);
+//! let paginator = Paginator::new(move |continuation_token| {
+//!     let request = ListObjectsV2Request {
+//!         bucket: "my-bucket".to_owned(),
+//!         continuation_token,
+//!         ..Default::default()
+//!     };
+//!     client
+//!         .list_objects_v2(request)
+//!         .map(|page| (page.contents.unwrap_or_default(), page.next_continuation_token))
+//! })
+//! .with_max_items(10_000);
+//! let progress = paginator.progress();
+//! let objects = paginator.collect().wait().unwrap();
+//! if progress.is_truncated() {
+//!     println!("stopped after {} pages", progress.pages());
+//! }
+//! ```
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use futures::{Async, Future, Poll, Stream};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cancellation::{CancellationToken, Cancelled};
+
+/// The future of a page: its items, and the token of the next page if there is one.
+type PageFuture<T, E> = Box<dyn Future<Item = (Vec<T>, Option<String>), Error = E> + Send>;
+
+/// The token stopping a paginator, with the conversion of its error.
+type Cancellation<E> = (CancellationToken, fn(Cancelled) -> E);
+
+/// How far a `Paginator` got.
+///
+/// Clones share their counts, so a clone taken before consuming the paginator tells how
+/// far it got afterwards.
+#[derive(Clone, Debug, Default)]
+pub struct PaginationProgress {
+    inner: Arc<Mutex<ProgressCounts>>,
+}
+
+#[derive(Debug, Default)]
+struct ProgressCounts {
+    pages: usize,
+    items: usize,
+    truncated: bool,
+}
+
+impl PaginationProgress {
+    /// The number of pages fetched so far.
+    pub fn pages(&self) -> usize {
+        self.inner.lock().unwrap().pages
+    }
+
+    /// The number of items fetched so far, not counting those beyond the item limit.
+    pub fn items(&self) -> usize {
+        self.inner.lock().unwrap().items
+    }
+
+    /// Whether the paginator stopped at its page or item limit while more results remained.
+    pub fn is_truncated(&self) -> bool {
+        self.inner.lock().unwrap().truncated
+    }
+}
+
+/// A stream of the items of the pages of an operation.
+///
+/// The first page is requested without a token, and each following page with the token
+/// returned by the previous one, until a page is returned without a token or a limit is
+/// reached. Tokens are opaque: they are handed back exactly as returned, without decoding
+/// or trimming them.
+pub struct Paginator<T, E> {
+    fetch: Box<dyn FnMut(Option<String>) -> PageFuture<T, E> + Send>,
+    max_pages: Option<usize>,
+    max_items: Option<usize>,
+    cancellation: Option<Cancellation<E>>,
+    progress: PaginationProgress,
+    state: PaginatorState<T, E>,
+}
+
+enum PaginatorState<T, E> {
+    /// The first page is yet to be requested.
+    Start,
+    /// Items of the last page, and the token of the next one.
+    Buffered(VecDeque<T>, Option<String>),
+    Fetching(PageFuture<T, E>),
+    Done,
+}
+
+impl<T, E> Paginator<T, E> {
+    /// A paginator requesting each page with `fetch`, given the token of the page.
+    pub fn new<F, P>(mut fetch: F) -> Paginator<T, E>
+    where
+        F: FnMut(Option<String>) -> P + Send + 'static,
+        P: Future<Item = (Vec<T>, Option<String>), Error = E> + Send + 'static,
+    {
+        Paginator {
+            fetch: Box::new(move |token| -> PageFuture<T, E> { Box::new(fetch(token)) }),
+            max_pages: None,
+            max_items: None,
+            cancellation: None,
+            progress: PaginationProgress::default(),
+            state: PaginatorState::Start,
+        }
+    }
+
+    /// Stop after fetching `max_pages` pages.
+    pub fn with_max_pages(mut self, max_pages: usize) -> Paginator<T, E> {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Stop after `max_items` items, leaving out the rest of the page reaching the limit.
+    pub fn with_max_items(mut self, max_items: usize) -> Paginator<T, E> {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Stop when `token` is cancelled, ending the stream with a `Cancelled` error.
+    ///
+    /// The page being fetched is abandoned, and neither the items already fetched nor
+    /// the following pages are streamed.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Paginator<T, E>
+    where
+        E: From<Cancelled>,
+    {
+        self.cancellation = Some((token, E::from));
+        self
+    }
+
+    /// A handle on how far the paginator got.
+    pub fn progress(&self) -> PaginationProgress {
+        self.progress.clone()
+    }
+
+    /// Streams the values `selector` picks out of each item, such as the IDs of the
+    /// instances of each reservation of `DescribeInstances`.
+    ///
+    /// A closure selects the values it returns in any `IntoIterator`, an `Option` for at
+    /// most one value per item.
+    pub fn select<S>(self, selector: S) -> Select<Self, S, S::Values>
+    where
+        S: Selector<T>,
+    {
+        Select {
+            stream: self,
+            selector,
+            values: None,
+        }
+    }
+
+    /// Streams the values `path` picks out of each item, as a `SelectPath` does.
+    pub fn select_path(
+        self,
+        path: &str,
+    ) -> Result<Select<Self, SelectPath, Vec<Value>>, ParseSelectPathError>
+    where
+        T: Serialize,
+    {
+        Ok(self.select(path.parse::<SelectPath>()?))
+    }
+
+    /// Records a fetched page, keeping the items and token within the limits.
+    fn add_page(&mut self, mut items: Vec<T>, mut next_token: Option<String>) {
+        let mut progress = self.progress.inner.lock().unwrap();
+        progress.pages += 1;
+        if let Some(max_items) = self.max_items {
+            let remaining = max_items.saturating_sub(progress.items);
+            if items.len() > remaining {
+                items.truncate(remaining);
+                progress.truncated = true;
+                next_token = None;
+            }
+        }
+        progress.items += items.len();
+
+        let at_limit = self.max_pages.into_iter().any(|max| progress.pages >= max)
+            || self.max_items.into_iter().any(|max| progress.items >= max);
+        if at_limit && next_token.is_some() {
+            progress.truncated = true;
+            next_token = None;
+        }
+        self.state = PaginatorState::Buffered(items.into(), next_token);
+    }
+}
+
+impl<T, E> Stream for Paginator<T, E> {
+    type Item = T;
+    type Error = E;
+
+    fn poll(&mut self) -> Poll<Option<T>, E> {
+        if let PaginatorState::Done = self.state {
+            return Ok(Async::Ready(None));
+        }
+        if let Some((ref token, cancelled)) = self.cancellation {
+            if token.poll_cancelled().is_ready() {
+                self.state = PaginatorState::Done;
+                return Err(cancelled(Cancelled));
+            }
+        }
+
+        loop {
+            match self.state {
+                PaginatorState::Start => {
+                    self.state = PaginatorState::Fetching((self.fetch)(None));
+                }
+                PaginatorState::Buffered(ref mut items, ref mut next_token) => {
+                    if let Some(item) = items.pop_front() {
+                        return Ok(Async::Ready(Some(item)));
+                    }
+                    self.state = match next_token.take() {
+                        Some(token) => PaginatorState::Fetching((self.fetch)(Some(token))),
+                        None => PaginatorState::Done,
+                    };
+                }
+                PaginatorState::Fetching(ref mut future) => match future.poll() {
+                    Ok(Async::NotReady) => return Ok(Async::NotReady),
+                    Ok(Async::Ready((items, next_token))) => self.add_page(items, next_token),
+                    Err(err) => {
+                        self.state = PaginatorState::Done;
+                        return Err(err);
+                    }
+                },
+                PaginatorState::Done => return Ok(Async::Ready(None)),
+            }
+        }
+    }
+}
+
+/// Picks values out of the items of a stream, for `Paginator::select`.
+pub trait Selector<T> {
+    /// The values picked out of an item.
+    type Values: IntoIterator;
+
+    /// The values of `item`.
+    fn select(&mut self, item: T) -> Self::Values;
+}
+
+impl<T, F, I> Selector<T> for F
+where
+    F: FnMut(T) -> I,
+    I: IntoIterator,
+{
+    type Values = I;
+
+    fn select(&mut self, item: T) -> I {
+        self(item)
+    }
+}
+
+/// A stream of the values picked out of the items of another stream.
+pub struct Select<S, F, I: IntoIterator> {
+    stream: S,
+    selector: F,
+    /// The values of the last item which are yet to be streamed.
+    values: Option<I::IntoIter>,
+}
+
+impl<S, F> Stream for Select<S, F, F::Values>
+where
+    S: Stream,
+    F: Selector<S::Item>,
+{
+    type Item = <F::Values as IntoIterator>::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, S::Error> {
+        loop {
+            if let Some(value) = self.values.as_mut().and_then(Iterator::next) {
+                return Ok(Async::Ready(Some(value)));
+            }
+            match self.stream.poll()? {
+                Async::Ready(Some(item)) => {
+                    self.values = Some(self.selector.select(item).into_iter());
+                }
+                Async::Ready(None) => return Ok(Async::Ready(None)),
+                Async::NotReady => return Ok(Async::NotReady),
+            }
+        }
+    }
+}
+
+/// A path picking values out of items, in the manner of a small part of JMESPath.
+///
+/// A path is a list of fields separated by `.`, each of them followed by any number of
+/// `[*]`, projecting each element of an array, or `[n]`, taking its element `n`:
+/// `reservations[*].instances[*].instance_id` picks the IDs of every instance of every
+/// reservation. Fields are named as items are serialized, and missing fields and null
+/// values are skipped.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectPath {
+    segments: Vec<Segment>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Field(String),
+    Index(usize),
+    Each,
+}
+
+impl SelectPath {
+    /// The values of `item` picked by the path.
+    pub fn values<T: Serialize + ?Sized>(&self, item: &T) -> Vec<Value> {
+        let mut values = match serde_json::to_value(item) {
+            Ok(value) => vec![value],
+            Err(_) => return Vec::new(),
+        };
+        for segment in &self.segments {
+            values = values
+                .into_iter()
+                .flat_map(|value| match (segment, value) {
+                    (Segment::Field(name), Value::Object(mut fields)) => {
+                        fields.remove(name).into_iter().collect()
+                    }
+                    (Segment::Index(index), Value::Array(mut elements)) => {
+                        if *index < elements.len() {
+                            vec![elements.swap_remove(*index)]
+                        } else {
+                            Vec::new()
+                        }
+                    }
+                    (Segment::Each, Value::Array(elements)) => elements,
+                    _ => Vec::new(),
+                })
+                .filter(|value| !value.is_null())
+                .collect();
+        }
+        values
+    }
+}
+
+impl<T: Serialize> Selector<T> for SelectPath {
+    type Values = Vec<Value>;
+
+    fn select(&mut self, item: T) -> Vec<Value> {
+        self.values(&item)
+    }
+}
+
+impl FromStr for SelectPath {
+    type Err = ParseSelectPathError;
+
+    fn from_str(path: &str) -> Result<SelectPath, ParseSelectPathError> {
+        let mut segments = Vec::new();
+        for field in path.split('.') {
+            let name_end = field.find('[').unwrap_or(field.len());
+            let name = &field[..name_end];
+            if name.is_empty() {
+                return Err(ParseSelectPathError::new(path, "a field has no name"));
+            }
+            segments.push(Segment::Field(name.to_owned()));
+
+            let mut brackets = &field[name_end..];
+            while !brackets.is_empty() {
+                let close = match brackets.find(']') {
+                    Some(close) if brackets.starts_with('[') => close,
+                    _ => return Err(ParseSelectPathError::new(path, "expected `[*]` or `[n]`")),
+                };
+                segments.push(match &brackets[1..close] {
+                    "*" => Segment::Each,
+                    index => match index.parse() {
+                        Ok(index) => Segment::Index(index),
+                        Err(_) => {
+                            return Err(ParseSelectPathError::new(path, "expected `[*]` or `[n]`"))
+                        }
+                    },
+                });
+                brackets = &brackets[close + 1..];
+            }
+        }
+        Ok(SelectPath { segments })
+    }
+}
+
+/// An error produced when a string isn't a valid `SelectPath`.
+#[derive(Debug, PartialEq)]
+pub struct ParseSelectPathError {
+    message: String,
+}
+
+impl ParseSelectPathError {
+    fn new(path: &str, reason: &str) -> Self {
+        ParseSelectPathError {
+            message: format!("Not a valid path: {}: {}", path, reason),
+        }
+    }
+}
+
+impl Error for ParseSelectPathError {}
+
+impl fmt::Display for ParseSelectPathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::future::{self, FutureResult};
+
+    use super::*;
+    use crate::RusotoError;
+
+    type Requests = Arc<Mutex<Vec<Option<String>>>>;
+
+    /// Pages of `page_size` numbers up to `total`, with the first number of the next page as
+    /// its token, recording the tokens requested.
+    fn numbers(total: usize, page_size: usize) -> (Paginator<usize, String>, Requests) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let paginator = Paginator::new(
+            move |token: Option<String>| -> FutureResult<(Vec<usize>, Option<String>), String> {
+                recorded.lock().unwrap().push(token.clone());
+                let start = token.map_or(0, |token| token.parse().unwrap());
+                let end = (start + page_size).min(total);
+                let next_token = if end < total {
+                    Some(end.to_string())
+                } else {
+                    None
+                };
+                future::ok(((start..end).collect(), next_token))
+            },
+        );
+        (paginator, requests)
+    }
+
+    #[test]
+    fn paginator_streams_the_items_of_every_page() {
+        let (paginator, requests) = numbers(10, 4);
+        let progress = paginator.progress();
+
+        assert_eq!(paginator.collect().wait(), Ok((0..10).collect::<Vec<_>>()));
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![None, Some("4".to_owned()), Some("8".to_owned())]
+        );
+        assert_eq!(progress.pages(), 3);
+        assert_eq!(progress.items(), 10);
+        assert!(!progress.is_truncated());
+    }
+
+    #[test]
+    fn paginator_stops_at_max_pages() {
+        let (paginator, requests) = numbers(10, 4);
+        let paginator = paginator.with_max_pages(2);
+        let progress = paginator.progress();
+
+        assert_eq!(paginator.collect().wait(), Ok((0..8).collect::<Vec<_>>()));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        assert_eq!(progress.pages(), 2);
+        assert!(progress.is_truncated());
+    }
+
+    #[test]
+    fn paginator_stops_at_max_items_within_a_page() {
+        let (paginator, requests) = numbers(10, 4);
+        let paginator = paginator.with_max_items(6);
+        let progress = paginator.progress();
+
+        assert_eq!(paginator.collect().wait(), Ok((0..6).collect::<Vec<_>>()));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        assert_eq!(progress.items(), 6);
+        assert!(progress.is_truncated());
+    }
+
+    #[test]
+    fn paginator_is_not_truncated_by_limits_matching_the_results() {
+        let (paginator, _) = numbers(8, 4);
+        let paginator = paginator.with_max_pages(2).with_max_items(8);
+        let progress = paginator.progress();
+
+        assert_eq!(paginator.collect().wait(), Ok((0..8).collect::<Vec<_>>()));
+        assert!(!progress.is_truncated());
+    }
+
+    #[test]
+    fn paginator_hands_tokens_back_verbatim() {
+        let token = " a+b/c==\n\t%2B ".to_owned();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let returned = token.clone();
+        let paginator = Paginator::new(move |requested: Option<String>| {
+            let next_token = if requested.is_none() {
+                Some(returned.clone())
+            } else {
+                None
+            };
+            recorded.lock().unwrap().push(requested);
+            future::ok::<_, String>((vec![()], next_token))
+        });
+
+        assert_eq!(paginator.collect().wait().map(|items| items.len()), Ok(2));
+        assert_eq!(*requests.lock().unwrap(), vec![None, Some(token)]);
+    }
+
+    #[test]
+    fn paginator_stops_when_cancelled() {
+        // three pages of one item
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let token = CancellationToken::new();
+        let paginator = Paginator::new(move |token: Option<String>| {
+            recorded.lock().unwrap().push(token.clone());
+            let page = token.map_or(1, |token| token.parse().unwrap());
+            let next_token = if page < 3 {
+                Some((page + 1).to_string())
+            } else {
+                None
+            };
+            future::ok::<_, RusotoError<()>>((vec![page], next_token))
+        })
+        .with_cancellation(token.clone());
+        let progress = paginator.progress();
+
+        let mut stream = paginator.wait();
+        assert_eq!(stream.next(), Some(Ok(1)));
+        token.cancel();
+        assert_eq!(stream.next(), Some(Err(RusotoError::Cancelled)));
+        assert_eq!(stream.next(), None);
+        assert_eq!(*requests.lock().unwrap(), vec![None]);
+        assert_eq!(progress.pages(), 1);
+    }
+
+    #[derive(Serialize)]
+    struct Reservation {
+        instances: Vec<Instance>,
+    }
+
+    #[derive(Serialize)]
+    struct Instance {
+        instance_id: Option<String>,
+        tags: Vec<String>,
+    }
+
+    /// Two pages of two reservations, of two instances each except for the last one.
+    fn reservations() -> Paginator<Reservation, String> {
+        Paginator::new(|token: Option<String>| {
+            let (page, next_token): (&[&[&str]], _) = match token {
+                None => (
+                    &[&["i-0a", "i-0b"], &["i-1a", "i-1b"]],
+                    Some("next".to_owned()),
+                ),
+                Some(_) => (&[&["i-2a", "i-2b"], &["i-3a"]], None),
+            };
+            let page = page
+                .iter()
+                .map(|ids| Reservation {
+                    instances: ids
+                        .iter()
+                        .map(|id| Instance {
+                            instance_id: Some((*id).to_owned()),
+                            tags: vec![format!("{}-a", id), format!("{}-b", id)],
+                        })
+                        .collect(),
+                })
+                .collect();
+            future::ok::<_, String>((page, next_token))
+        })
+    }
+
+    #[test]
+    fn select_streams_the_values_of_every_item() {
+        let paginator = reservations();
+        let ids = paginator.select(|reservation: Reservation| {
+            reservation
+                .instances
+                .into_iter()
+                .filter_map(|instance| instance.instance_id)
+        });
+        assert_eq!(
+            ids.collect().wait().unwrap(),
+            vec!["i-0a", "i-0b", "i-1a", "i-1b", "i-2a", "i-2b", "i-3a"]
+        );
+    }
+
+    #[test]
+    fn select_fetches_pages_as_values_are_consumed() {
+        let requests = Arc::new(Mutex::new(0));
+        let recorded = requests.clone();
+        let paginator = Paginator::new(move |token: Option<String>| {
+            *recorded.lock().unwrap() += 1;
+            let next_token = if token.is_none() {
+                Some("next".to_owned())
+            } else {
+                None
+            };
+            future::ok::<_, String>((vec![vec![1, 2], vec![]], next_token))
+        });
+
+        let mut values = paginator.select(|item: Vec<u32>| item).wait();
+        assert_eq!(values.next(), Some(Ok(1)));
+        assert_eq!(values.next(), Some(Ok(2)));
+        assert_eq!(*requests.lock().unwrap(), 1);
+        assert_eq!(values.next(), Some(Ok(1)));
+        assert_eq!(*requests.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn select_path_projects_serialized_items() {
+        let paginator = reservations();
+        let ids = paginator
+            .select_path("instances[*].instance_id")
+            .unwrap()
+            .collect()
+            .wait()
+            .unwrap();
+        assert_eq!(
+            ids,
+            vec!["i-0a", "i-0b", "i-1a", "i-1b", "i-2a", "i-2b", "i-3a"]
+        );
+
+        let paginator = reservations();
+        let tags = paginator
+            .select_path("instances[1].tags[0]")
+            .unwrap()
+            .collect()
+            .wait()
+            .unwrap();
+        assert_eq!(tags, vec!["i-0b-a", "i-1b-a", "i-2b-a"]);
+
+        let paginator = reservations();
+        let missing = paginator.select_path("instances[*].missing").unwrap();
+        assert_eq!(missing.collect().wait().unwrap(), Vec::<Value>::new());
+    }
+
+    #[test]
+    fn invalid_select_paths_are_rejected() {
+        for invalid in &["", "a..b", "a[", "a[x]", "a[*]b", "[0]"] {
+            let err = invalid.parse::<SelectPath>().unwrap_err();
+            assert!(err.to_string().starts_with("Not a valid path: "));
+        }
+        assert_eq!(
+            "a.b[*][2]".parse::<SelectPath>().unwrap().segments,
+            vec![
+                Segment::Field("a".to_owned()),
+                Segment::Field("b".to_owned()),
+                Segment::Each,
+                Segment::Index(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn paginator_ends_with_the_error_of_a_page() {
+        let mut pages = 0;
+        let paginator = Paginator::new(move |_| {
+            pages += 1;
+            if pages == 1 {
+                future::ok((vec![1], Some("next".to_owned())))
+            } else {
+                future::err("throttled".to_owned())
+            }
+        });
+        let progress = paginator.progress();
+
+        let mut stream = paginator.wait();
+        assert_eq!(stream.next(), Some(Ok(1)));
+        assert_eq!(stream.next(), Some(Err("throttled".to_owned())));
+        assert_eq!(stream.next(), None);
+        assert_eq!(progress.pages(), 1);
+    }
+}
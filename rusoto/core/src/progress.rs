@@ -0,0 +1,243 @@
+//! Reporting how many bytes of a stream have been transferred.
+//!
+//! A [`ProgressListener`](trait.ProgressListener.html) is attached to a `ByteStream` with
+//! [`ByteStream::with_progress`](../struct.ByteStream.html#method.with_progress), whether
+//! it is the payload of a request or the body of a response, such as that of an S3
+//! `GetObject` call. The listener is told how many bytes went through the stream every
+//! time a given number of them did, and once more when the stream ends.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use futures::{Future, Stream};
+//! use rusoto_core::progress::Progress;
+//! use rusoto_core::ByteStream;
+//!
+//! let body = ByteStream::from(vec![0; 1024 * 1024]).with_progress(|progress: &Progress| {
+//!     println!("{} of {:?} bytes", progress.transferred, progress.total);
+//! });
+//! body.concat2().wait().unwrap();
+//! ```
+
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use futures::{Async, Poll, Stream};
+
+/// The number of bytes between two reports of `ByteStream::with_progress`.
+pub const DEFAULT_GRANULARITY: usize = 64 * 1024;
+
+/// How far along a stream is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Progress {
+    /// The number of bytes which went through the stream so far.
+    pub transferred: u64,
+    /// The number of bytes of the whole stream, if known.
+    pub total: Option<u64>,
+    /// The time since the stream was first polled.
+    pub elapsed: Duration,
+}
+
+/// Told of the progress of a stream.
+///
+/// Listeners are called from the task polling the stream, which they shouldn't block. A
+/// listener which panics is logged and otherwise ignored: the stream goes on.
+pub trait ProgressListener: Send + Sync {
+    /// Called with the progress of the stream so far.
+    fn progress(&self, progress: &Progress);
+}
+
+impl<F> ProgressListener for F
+where
+    F: Fn(&Progress) + Send + Sync,
+{
+    fn progress(&self, progress: &Progress) {
+        self(progress)
+    }
+}
+
+/// Calls `listener`, keeping a panic from unwinding into the transfer.
+pub(crate) fn notify(listener: &dyn ProgressListener, progress: &Progress) {
+    let called = panic::catch_unwind(AssertUnwindSafe(|| listener.progress(progress)));
+    if called.is_err() {
+        warn!("A progress listener panicked, the transfer goes on");
+    }
+}
+
+/// Counts the bytes going through a stream for its listener.
+pub(crate) struct ProgressStream<S> {
+    inner: S,
+    listener: Arc<dyn ProgressListener>,
+    granularity: u64,
+    total: Option<u64>,
+    transferred: u64,
+    /// The bytes transferred when the listener was last called, if it was.
+    reported: Option<u64>,
+    /// Set on the first poll.
+    started: Option<Instant>,
+}
+
+impl<S> ProgressStream<S> {
+    pub(crate) fn new(
+        inner: S,
+        listener: Arc<dyn ProgressListener>,
+        granularity: usize,
+        total: Option<usize>,
+    ) -> Self {
+        ProgressStream {
+            inner,
+            listener,
+            granularity: granularity as u64,
+            total: total.map(|total| total as u64),
+            transferred: 0,
+            reported: None,
+            started: None,
+        }
+    }
+
+    fn report(&mut self) {
+        let elapsed = self
+            .started
+            .map(|started| started.elapsed())
+            .unwrap_or_default();
+        let progress = Progress {
+            transferred: self.transferred,
+            total: self.total,
+            elapsed,
+        };
+        self.reported = Some(self.transferred);
+        notify(&*self.listener, &progress);
+    }
+}
+
+impl<S> Stream for ProgressStream<S>
+where
+    S: Stream<Item = Bytes, Error = io::Error>,
+{
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if self.started.is_none() {
+            self.started = Some(Instant::now());
+        }
+        match self.inner.poll()? {
+            Async::Ready(Some(bytes)) => {
+                self.transferred += bytes.len() as u64;
+                let unreported = self.transferred - self.reported.unwrap_or(0);
+                if !bytes.is_empty() && unreported >= self.granularity {
+                    self.report();
+                }
+                Ok(Async::Ready(Some(bytes)))
+            }
+            Async::Ready(None) => {
+                // the end is always reported, if only to say that an empty stream is done
+                if self.reported != Some(self.transferred) {
+                    self.report();
+                }
+                Ok(Async::Ready(None))
+            }
+            Async::NotReady => Ok(Async::NotReady),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use bytes::Bytes;
+    use futures::{stream, Future, Stream};
+
+    use super::Progress;
+    use crate::ByteStream;
+
+    fn chunks(count: usize, size: usize) -> ByteStream {
+        let chunks = (0..count).map(move |_| Bytes::from(vec![0; size]));
+        ByteStream::new(stream::iter_ok(chunks))
+    }
+
+    fn recorder() -> (
+        Arc<Mutex<Vec<Progress>>>,
+        impl Fn(&Progress) + Send + Sync + 'static,
+    ) {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let recorded = reports.clone();
+        let listener = move |progress: &Progress| recorded.lock().unwrap().push(*progress);
+        (reports, listener)
+    }
+
+    #[test]
+    fn progress_is_reported_monotonically_up_to_the_total() {
+        let (reports, listener) = recorder();
+        let size = 10 * 1024 * 1024;
+        let body = chunks(160, 64 * 1024)
+            .with_size(size)
+            .with_progress_every(1024 * 1024, listener);
+
+        assert_eq!(body.concat2().wait().unwrap().len(), size);
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 10);
+        for pair in reports.windows(2) {
+            assert!(pair[0].transferred < pair[1].transferred);
+            assert!(pair[0].elapsed <= pair[1].elapsed);
+        }
+        assert!(reports
+            .iter()
+            .all(|progress| progress.total == Some(size as u64)));
+        assert_eq!(reports.last().unwrap().transferred, size as u64);
+    }
+
+    #[test]
+    fn the_end_of_a_stream_is_always_reported() {
+        let (reports, listener) = recorder();
+        chunks(3, 10)
+            .with_progress_every(25, listener)
+            .concat2()
+            .wait()
+            .unwrap();
+        let transferred: Vec<u64> = reports
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|progress| progress.transferred)
+            .collect();
+        assert_eq!(transferred, vec![30]);
+
+        let (reports, listener) = recorder();
+        chunks(0, 10)
+            .with_progress(listener)
+            .concat2()
+            .wait()
+            .unwrap();
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].transferred, 0);
+        assert_eq!(reports[0].total, None);
+    }
+
+    #[test]
+    fn panicking_listeners_leave_the_stream_alone() {
+        let body = chunks(4, 1024).with_progress_every(1, |_: &Progress| panic!("listener"));
+        assert_eq!(body.concat2().wait().unwrap().len(), 4096);
+    }
+
+    #[test]
+    fn replays_report_their_progress_again() {
+        let (reports, listener) = recorder();
+        let body = ByteStream::from(vec![0; 100]).with_progress(listener);
+        let replay = body.replay().unwrap();
+        body.concat2().wait().unwrap();
+        replay.concat2().wait().unwrap();
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 2);
+        assert!(reports
+            .iter()
+            .all(|progress| progress.transferred == 100 && progress.total == Some(100)));
+    }
+}
@@ -0,0 +1,272 @@
+use std::error::Error;
+use std::fmt;
+use std::str;
+
+use serde_json::{Map, Number, Value};
+
+/// How deeply arrays, maps and tags may be nested, so that a malicious body can't
+/// overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Decodes a CBOR data item into the JSON value deserialized into outputs.
+///
+/// Byte strings become base64 strings, as blobs are in JSON, and tagged items become the
+/// item they tag, so that epoch-based date/times are the seconds since the epoch.
+pub fn decode(body: &[u8]) -> Result<Value, DecodeError> {
+    let mut decoder = Decoder {
+        input: body,
+        offset: 0,
+    };
+    let value = decoder.item(0)?;
+    if decoder.offset < body.len() {
+        return Err(decoder.error("trailing bytes after the data item"));
+    }
+    Ok(value)
+}
+
+/// An error decoding a CBOR data item, with the offset of the byte where it was found.
+#[derive(Debug, PartialEq)]
+pub struct DecodeError {
+    message: String,
+    offset: usize,
+}
+
+impl DecodeError {
+    /// The offset of the byte where the error was found.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Error for DecodeError {}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+/// The start of a data item: its major type, and its argument unless its length is
+/// indefinite.
+struct Head {
+    major: u8,
+    info: u8,
+    argument: Option<u64>,
+}
+
+struct Decoder<'a> {
+    input: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn error(&self, message: &str) -> DecodeError {
+        DecodeError {
+            message: message.to_owned(),
+            offset: self.offset,
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if self.input.len() - self.offset < len {
+            return Err(self.error("unexpected end of the body"));
+        }
+        let bytes = &self.input[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn uint(&mut self, len: usize) -> Result<u64, DecodeError> {
+        Ok(self
+            .take(len)?
+            .iter()
+            .fold(0, |value, byte| value << 8 | u64::from(*byte)))
+    }
+
+    fn head(&mut self) -> Result<Head, DecodeError> {
+        let initial = self.take(1)?[0];
+        let major = initial >> 5;
+        let info = initial & 0x1f;
+        let argument = match info {
+            0..=23 => Some(u64::from(info)),
+            24 => Some(self.uint(1)?),
+            25 => Some(self.uint(2)?),
+            26 => Some(self.uint(4)?),
+            27 => Some(self.uint(8)?),
+            31 => None,
+            _ => {
+                self.offset -= 1;
+                return Err(self.error("reserved additional information"));
+            }
+        };
+        Ok(Head {
+            major,
+            info,
+            argument,
+        })
+    }
+
+    /// Whether the next byte ends an item of indefinite length, which it is skipped if so.
+    fn at_break(&mut self) -> Result<bool, DecodeError> {
+        match self.input.get(self.offset) {
+            Some(0xff) => {
+                self.offset += 1;
+                Ok(true)
+            }
+            Some(_) => Ok(false),
+            None => Err(self.error("unexpected end of the body")),
+        }
+    }
+
+    fn length(&self, argument: u64) -> Result<usize, DecodeError> {
+        // every element takes at least a byte, which bounds what is allocated up front
+        if argument > (self.input.len() - self.offset) as u64 {
+            return Err(self.error("length longer than the body"));
+        }
+        Ok(argument as usize)
+    }
+
+    fn item(&mut self, depth: usize) -> Result<Value, DecodeError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("data items nested too deeply"));
+        }
+        let start = self.offset;
+        let head = self.head()?;
+        match (head.major, head.argument) {
+            (0, Some(value)) => Ok(Value::Number(value.into())),
+            (1, Some(value)) => Ok(if value <= i64::max_value() as u64 {
+                Value::Number((-1 - value as i64).into())
+            } else {
+                number(-1.0 - value as f64)
+            }),
+            (2, _) => {
+                let bytes = self.string(2, head.argument)?;
+                Ok(Value::String(base64::encode(&bytes)))
+            }
+            (3, _) => {
+                let bytes = self.string(3, head.argument)?;
+                String::from_utf8(bytes)
+                    .map(Value::String)
+                    .map_err(|_| DecodeError {
+                        message: "invalid UTF-8 in a text string".to_owned(),
+                        offset: start,
+                    })
+            }
+            (4, Some(len)) => {
+                let len = self.length(len)?;
+                let mut elements = Vec::with_capacity(len);
+                for _ in 0..len {
+                    elements.push(self.item(depth + 1)?);
+                }
+                Ok(Value::Array(elements))
+            }
+            (4, None) => {
+                let mut elements = Vec::new();
+                while !self.at_break()? {
+                    elements.push(self.item(depth + 1)?);
+                }
+                Ok(Value::Array(elements))
+            }
+            (5, Some(len)) => {
+                let len = self.length(len)?;
+                let mut entries = Map::new();
+                for _ in 0..len {
+                    let (key, value) = self.entry(depth)?;
+                    entries.insert(key, value);
+                }
+                Ok(Value::Object(entries))
+            }
+            (5, None) => {
+                let mut entries = Map::new();
+                while !self.at_break()? {
+                    let (key, value) = self.entry(depth)?;
+                    entries.insert(key, value);
+                }
+                Ok(Value::Object(entries))
+            }
+            // tags, such as that of epoch-based date/times, only annotate what they tag
+            (6, Some(_)) => self.item(depth + 1),
+            (7, _) => self.simple(head, start),
+            _ => Err(DecodeError {
+                message: "indefinite length of an item which can't have one".to_owned(),
+                offset: start,
+            }),
+        }
+    }
+
+    fn entry(&mut self, depth: usize) -> Result<(String, Value), DecodeError> {
+        let start = self.offset;
+        let key = match self.item(depth + 1)? {
+            Value::String(key) => key,
+            _ => {
+                return Err(DecodeError {
+                    message: "map key which isn't a text string".to_owned(),
+                    offset: start,
+                })
+            }
+        };
+        Ok((key, self.item(depth + 1)?))
+    }
+
+    /// The bytes of a byte or text string, concatenating the chunks of one of
+    /// indefinite length.
+    fn string(&mut self, major: u8, argument: Option<u64>) -> Result<Vec<u8>, DecodeError> {
+        if let Some(len) = argument {
+            let len = self.length(len)?;
+            return Ok(self.take(len)?.to_vec());
+        }
+        let mut bytes = Vec::new();
+        while !self.at_break()? {
+            let chunk = self.head()?;
+            match (chunk.major, chunk.argument) {
+                (chunk_major, Some(len)) if chunk_major == major => {
+                    let len = self.length(len)?;
+                    bytes.extend_from_slice(self.take(len)?);
+                }
+                _ => return Err(self.error("invalid chunk of a string of indefinite length")),
+            }
+        }
+        Ok(bytes)
+    }
+
+    fn simple(&mut self, head: Head, start: usize) -> Result<Value, DecodeError> {
+        let float = match (head.info, head.argument) {
+            (20, _) => return Ok(Value::Bool(false)),
+            (21, _) => return Ok(Value::Bool(true)),
+            (22, _) | (23, _) => return Ok(Value::Null),
+            (25, Some(bits)) => half_to_f64(bits as u16),
+            (26, Some(bits)) => f64::from(f32::from_bits(bits as u32)),
+            (27, Some(bits)) => f64::from_bits(bits),
+            _ => {
+                return Err(DecodeError {
+                    message: "unexpected simple value".to_owned(),
+                    offset: start,
+                })
+            }
+        };
+        if !float.is_finite() {
+            return Err(DecodeError {
+                message: format!("{} can't be deserialized", float),
+                offset: start,
+            });
+        }
+        Ok(number(float))
+    }
+}
+
+fn number(float: f64) -> Value {
+    Number::from_f64(float).map_or(Value::Null, Value::Number)
+}
+
+/// The value of an IEEE 754 half-precision float.
+fn half_to_f64(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1f);
+    let mantissa = f64::from(bits & 0x3ff);
+    sign * match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        31 if mantissa == 0.0 => ::std::f64::INFINITY,
+        31 => ::std::f64::NAN,
+        _ => (1.0 + mantissa / 1024.0) * 2f64.powi(exponent - 15),
+    }
+}
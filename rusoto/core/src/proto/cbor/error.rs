@@ -0,0 +1,46 @@
+use serde_json::Value;
+
+use super::super::super::request::BufferedHttpResponse;
+use super::super::super::validation::ValidationError;
+use super::super::json::{error_type_header, field_errors};
+use super::decode;
+
+/// The error a service answered with, from the CBOR map of its body.
+pub struct Error {
+    pub typ: String,
+    pub msg: String,
+    /// The members which failed validation, from the `fieldList` of the error or its message
+    pub fields: Vec<ValidationError>,
+}
+
+impl Error {
+    /// Parses the error of `res`, whose `__type` is the ID of the shape of the error, such
+    /// as `com.amazonaws.cloudwatch#ResourceNotFound`, or the name of the shape alone.
+    pub fn parse(res: &BufferedHttpResponse) -> Option<Error> {
+        let body = match decode(&res.body) {
+            Ok(body @ Value::Object(_)) => body,
+            _ => return None,
+        };
+        let raw_error_type = body
+            .get("__type")
+            .and_then(Value::as_str)
+            .map(str::to_owned)
+            .or_else(|| error_type_header(res))
+            .unwrap_or_else(|| "Unknown".to_owned());
+        // the namespace of the shape is left out, and so is anything after a `:`
+        let typ = raw_error_type
+            .rsplit('#')
+            .next()
+            .and_then(|typ| typ.split(':').next())
+            .unwrap_or_default()
+            .to_owned();
+        let msg = body
+            .get("message")
+            .or_else(|| body.get("Message"))
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_owned();
+        let fields = field_errors(&body, &msg);
+        Some(Error { typ, msg, fields })
+    }
+}
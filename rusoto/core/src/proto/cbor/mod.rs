@@ -0,0 +1,275 @@
+//! The RPC v2 CBOR protocol, whose requests and responses have CBOR bodies.
+//!
+//! Requests to an operation are `POST`s to `/service/{service}/operation/{operation}`.
+//! Blobs are sent as byte strings and timestamps as epoch-based date/times, while
+//! responses are decoded into the JSON values the outputs of the JSON protocols are
+//! deserialized from.
+
+mod de;
+mod error;
+mod payload;
+mod ser;
+
+pub use self::de::{decode, DecodeError};
+pub use self::error::Error;
+pub use self::payload::{
+    request_path, set_empty_payload, set_payload, ResponsePayload, CONTENT_TYPE,
+};
+pub use self::ser::{to_vec, EncodeError};
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use bytes::Bytes;
+    use http::{HeaderMap, StatusCode};
+    use serde_json::json;
+
+    use super::{decode, to_vec, Error, ResponsePayload};
+    use crate::request::BufferedHttpResponse;
+    use crate::validation::ValidationError;
+    use crate::RusotoError;
+
+    #[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+    struct Metric {
+        #[serde(rename = "Name")]
+        name: String,
+        #[serde(rename = "Count")]
+        count: i64,
+        #[serde(rename = "Offset")]
+        offset: i32,
+        #[serde(rename = "Ratio")]
+        ratio: f64,
+        #[serde(rename = "Enabled")]
+        enabled: bool,
+        #[serde(
+            rename = "Payload",
+            deserialize_with = "crate::serialization::SerdeBlob::deserialize_blob",
+            serialize_with = "crate::serialization::SerdeBlob::serialize_blob",
+            default
+        )]
+        payload: Bytes,
+        #[serde(
+            rename = "Timestamp",
+            serialize_with = "crate::serialization::SerdeTimestamp::serialize_timestamp",
+            skip_serializing_if = "Option::is_none"
+        )]
+        timestamp: Option<f64>,
+        #[serde(rename = "Dimensions", skip_serializing_if = "Option::is_none")]
+        dimensions: Option<Vec<String>>,
+        #[serde(rename = "Tags", skip_serializing_if = "Option::is_none")]
+        tags: Option<BTreeMap<String, String>>,
+    }
+
+    fn hex(bytes: &str) -> Vec<u8> {
+        (0..bytes.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&bytes[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn response(status: StatusCode, content_type: &str, body: Vec<u8>) -> BufferedHttpResponse {
+        let mut headers = HeaderMap::<String>::default();
+        headers.insert("content-type", content_type.to_owned());
+        BufferedHttpResponse {
+            status,
+            body: Bytes::from(body),
+            headers,
+        }
+    }
+
+    #[test]
+    fn values_are_encoded_in_their_shortest_form() {
+        // examples of appendix A of RFC 8949
+        assert_eq!(to_vec(&0u8).unwrap(), hex("00"));
+        assert_eq!(to_vec(&23u8).unwrap(), hex("17"));
+        assert_eq!(to_vec(&24u8).unwrap(), hex("1818"));
+        assert_eq!(to_vec(&1000u32).unwrap(), hex("1903e8"));
+        assert_eq!(to_vec(&1_000_000i64).unwrap(), hex("1a000f4240"));
+        assert_eq!(
+            to_vec(&1_000_000_000_000u64).unwrap(),
+            hex("1b000000e8d4a51000")
+        );
+        assert_eq!(to_vec(&-1i8).unwrap(), hex("20"));
+        assert_eq!(to_vec(&-1000i16).unwrap(), hex("3903e7"));
+        assert_eq!(to_vec(&1.1f64).unwrap(), hex("fb3ff199999999999a"));
+        assert_eq!(to_vec(&true).unwrap(), hex("f5"));
+        assert_eq!(to_vec(&None::<i32>).unwrap(), hex("f6"));
+        assert_eq!(to_vec("IETF").unwrap(), hex("6449455446"));
+        assert_eq!(to_vec(&vec![1, 2, 3]).unwrap(), hex("83010203"));
+        let mut map = BTreeMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        assert_eq!(to_vec(&map).unwrap(), hex("a2616101616202"));
+    }
+
+    #[test]
+    fn structures_are_encoded_and_decoded_again() {
+        let mut tags = BTreeMap::new();
+        tags.insert("team".to_owned(), "storage".to_owned());
+        let metric = Metric {
+            name: "Latency".to_owned(),
+            count: -3_000_000_000,
+            offset: 7,
+            ratio: 0.25,
+            enabled: true,
+            payload: Bytes::from_static(b"\x00\xffbinary"),
+            timestamp: Some(1_568_000_000.5),
+            dimensions: Some(vec!["Host".to_owned(), "Region".to_owned()]),
+            tags: Some(tags),
+        };
+        let encoded = to_vec(&metric).unwrap();
+
+        // blobs are byte strings and timestamps are tagged as epoch-based date/times
+        let payload_at = encoded
+            .windows(9)
+            .position(|window| window == &hex("4800ff62696e617279")[..]);
+        assert!(payload_at.is_some());
+        assert!(encoded
+            .windows(10)
+            .any(|window| window == &hex("c1fb41d75d7200200000")[..]));
+
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(decoded["Payload"], json!("AP9iaW5hcnk="));
+        assert_eq!(decoded["Timestamp"], json!(1_568_000_000.5));
+        assert_eq!(serde_json::from_value::<Metric>(decoded).unwrap(), metric);
+
+        let empty = Metric::default();
+        assert_eq!(
+            serde_json::from_value::<Metric>(decode(&to_vec(&empty).unwrap()).unwrap()).unwrap(),
+            empty
+        );
+    }
+
+    #[test]
+    fn every_form_of_data_items_is_decoded() {
+        for (encoded, expected) in vec![
+            ("f93c00", json!(1.0)),
+            ("f97bff", json!(65504.0)),
+            ("f90001", json!(0.000_000_059_604_644_775_390_625)),
+            ("fa47c35000", json!(100_000.0)),
+            ("3bffffffffffffffff", json!(-18_446_744_073_709_551_616.0)),
+            ("c11a514b67b0", json!(1_363_896_240)),
+            ("7f657374726561646d696e67ff", json!("streaming")),
+            ("5f42010243030405ff", json!("AQIDBAU=")),
+            ("9f018202039f0405ffff", json!([1, [2, 3], [4, 5]])),
+            ("bf6346756ef563416d7421ff", json!({"Fun": true, "Amt": -2})),
+            ("a16161f7", json!({ "a": null })),
+        ] {
+            assert_eq!(decode(&hex(encoded)).unwrap(), expected, "{}", encoded);
+        }
+    }
+
+    #[test]
+    fn malformed_data_items_are_rejected() {
+        for &(encoded, message) in &[
+            ("", "unexpected end of the body at offset 0"),
+            ("1903", "unexpected end of the body at offset 1"),
+            (
+                "6449455446ff",
+                "trailing bytes after the data item at offset 5",
+            ),
+            ("a10101", "map key which isn't a text string at offset 1"),
+            ("62c328", "invalid UTF-8 in a text string at offset 0"),
+            ("9f01", "unexpected end of the body at offset 2"),
+            ("1c", "reserved additional information at offset 0"),
+            ("f97c00", "inf can't be deserialized at offset 0"),
+            (
+                "9bffffffffffffffff",
+                "length longer than the body at offset 9",
+            ),
+        ] {
+            assert_eq!(
+                decode(&hex(encoded)).unwrap_err().to_string(),
+                message,
+                "{}",
+                encoded
+            );
+        }
+        let nested = vec![0x81; 200];
+        assert!(decode(&nested)
+            .unwrap_err()
+            .to_string()
+            .starts_with("data items nested too deeply"));
+    }
+
+    #[test]
+    fn responses_are_deserialized_from_cbor() {
+        let mut body = BTreeMap::new();
+        body.insert("Name", "Latency");
+        let ok = response(StatusCode::OK, "application/cbor", to_vec(&body).unwrap());
+        let deserialized: BTreeMap<String, String> =
+            ResponsePayload::new(&ok).deserialize::<_, ()>().unwrap();
+        assert_eq!(deserialized["Name"], "Latency");
+
+        let empty = response(StatusCode::OK, "application/cbor", Vec::new());
+        let deserialized: BTreeMap<String, String> =
+            ResponsePayload::new(&empty).deserialize::<_, ()>().unwrap();
+        assert!(deserialized.is_empty());
+
+        let html = response(StatusCode::OK, "text/html", b"<html></html>".to_vec());
+        match ResponsePayload::new(&html).deserialize::<Metric, ()>() {
+            Err(RusotoError::UnexpectedContentType { content_type, .. }) => {
+                assert_eq!(content_type, "text/html")
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let truncated = response(StatusCode::OK, "application/cbor", hex("a1644e616d65"));
+        assert_eq!(
+            ResponsePayload::new(&truncated)
+                .with_operation("GetMetricData")
+                .deserialize::<Metric, ()>()
+                .unwrap_err(),
+            RusotoError::ParseError(
+                "Failed to parse the response of GetMetricData: \
+                 unexpected end of the body at offset 6"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn errors_are_parsed_from_their_shape_id() {
+        let mut body = BTreeMap::new();
+        body.insert("__type", "com.amazonaws.cloudwatch#ResourceNotFound");
+        body.insert("message", "Metric stream not found");
+        let error = Error::parse(&response(
+            StatusCode::BAD_REQUEST,
+            "application/cbor",
+            to_vec(&body).unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(error.typ, "ResourceNotFound");
+        assert_eq!(error.msg, "Metric stream not found");
+
+        let mut body = BTreeMap::new();
+        body.insert("__type", "ValidationException:http://internal.amazon.com/");
+        body.insert(
+            "Message",
+            "1 validation error detected: Value '0' at 'limit' failed to satisfy constraint: \
+             Member must have value greater than or equal to 1",
+        );
+        let error = Error::parse(&response(
+            StatusCode::BAD_REQUEST,
+            "application/cbor",
+            to_vec(&body).unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(error.typ, "ValidationException");
+        assert_eq!(
+            error.fields,
+            vec![ValidationError {
+                field: "limit".to_owned(),
+                message: "Member must have value greater than or equal to 1".to_owned(),
+            }]
+        );
+
+        assert!(Error::parse(&response(
+            StatusCode::BAD_GATEWAY,
+            "text/html",
+            b"<html></html>".to_vec()
+        ))
+        .is_none());
+    }
+}
@@ -0,0 +1,96 @@
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{from_value, Value};
+
+use super::super::super::request::BufferedHttpResponse;
+use super::super::super::signature::SignedRequest;
+use super::super::super::RusotoError;
+use super::super::{parse_error, UnexpectedContentType};
+use super::{decode, to_vec};
+
+/// The content type of the bodies of requests and responses.
+pub const CONTENT_TYPE: &str = "application/cbor";
+
+/// The header naming the protocol of requests, and its value.
+const PROTOCOL_HEADER: &str = "smithy-protocol";
+const PROTOCOL: &str = "rpc-v2-cbor";
+
+/// The path requests to `operation` are sent to, `service` being the name of the shape of
+/// the service, such as `GraniteServiceVersion20100801`.
+pub fn request_path(service: &str, operation: &str) -> String {
+    format!("/service/{}/operation/{}", service, operation)
+}
+
+/// Sets `input` as the CBOR payload of `request`, with the headers of the protocol.
+///
+/// # Panics
+///
+/// Panics if `input` can't be encoded, which the inputs of operations always can.
+pub fn set_payload<T: Serialize + ?Sized>(request: &mut SignedRequest, input: &T) {
+    let encoded = to_vec(input).expect("CBOR encoding of the input");
+    set_protocol_headers(request);
+    request.set_content_type(CONTENT_TYPE.to_owned());
+    request.set_payload(Some(encoded));
+}
+
+/// Sets the headers of the protocol on `request`, for an operation without input, whose
+/// requests have no body.
+pub fn set_empty_payload(request: &mut SignedRequest) {
+    set_protocol_headers(request);
+    request.set_payload::<Bytes>(None);
+}
+
+fn set_protocol_headers(request: &mut SignedRequest) {
+    request.add_header(PROTOCOL_HEADER, PROTOCOL);
+    request.set_accept(CONTENT_TYPE.to_owned());
+}
+
+pub struct ResponsePayload {
+    body: Bytes,
+    unexpected: Option<UnexpectedContentType>,
+    operation: Option<String>,
+}
+
+impl ResponsePayload {
+    pub fn new(res: &BufferedHttpResponse) -> Self {
+        debug!("Response body: {:?}", res.body);
+        debug!("Response status: {}", res.status);
+
+        ResponsePayload {
+            body: res.body.clone(),
+            unexpected: UnexpectedContentType::check(res, "cbor"),
+            operation: None,
+        }
+    }
+
+    /// Names `operation` as the one the response answers in the errors deserializing it.
+    pub fn with_operation(mut self, operation: &str) -> Self {
+        self.operation = Some(operation.to_owned());
+        self
+    }
+
+    /// Deserializes the response, unless it isn't CBOR, as when a proxy answers with an
+    /// HTML page in place of the service. An empty body deserializes like an empty map.
+    pub fn deserialize<T: DeserializeOwned, E>(&self) -> Result<T, RusotoError<E>> {
+        let value = if self.body.is_empty() {
+            Value::Object(Default::default())
+        } else {
+            if let Some(ref unexpected) = self.unexpected {
+                return Err(unexpected.clone().into_error());
+            }
+            decode(&self.body).map_err(|err| self.parse_error(&err.to_string()))?
+        };
+        from_value(value).map_err(|err| self.parse_error(&err.to_string()))
+    }
+
+    /// A `ParseError` with `message`, leaving the binary body out.
+    fn parse_error<E>(&self, message: &str) -> RusotoError<E> {
+        parse_error(
+            self.operation.as_ref().map(|operation| &operation[..]),
+            None,
+            message,
+            None,
+        )
+    }
+}
@@ -0,0 +1,86 @@
+//! Reading the members of outputs bound to several response headers.
+//!
+//! A header may be repeated in a response, each time with one of its values. The headers
+//! of responses keep all of them, in the order they were received.
+
+use std::collections::HashMap;
+
+use http::HeaderMap;
+
+/// Every value of the header `name`, as for a list bound to a header.
+pub fn values(headers: &HeaderMap<String>, name: &str) -> Vec<String> {
+    headers.get_all(name).iter().cloned().collect()
+}
+
+/// The values of the headers whose names start with `prefix`, by the rest of their names,
+/// as for a map bound to headers such as the `x-amz-meta-` user metadata of S3 objects.
+///
+/// The values of a repeated header are joined with commas, which is what they mean in
+/// HTTP.
+pub fn prefixed(headers: &HeaderMap<String>, prefix: &str) -> HashMap<String, String> {
+    let prefix = prefix.to_ascii_lowercase();
+    let mut values = HashMap::new();
+    for name in headers.keys() {
+        if !name.as_str().starts_with(&prefix) {
+            continue;
+        }
+        let joined = headers
+            .get_all(name)
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(", ");
+        values.insert(name.as_str()[prefix.len()..].to_owned(), joined);
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::HeaderName;
+    use http::HeaderMap;
+
+    use super::{prefixed, values};
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap<String> {
+        pairs
+            .iter()
+            .map(|&(name, value)| (HeaderName::from_static(name), value.to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn headers_sharing_a_prefix_are_kept_apart() {
+        let headers = headers(&[
+            ("x-amz-meta-color", "blue"),
+            ("x-amz-meta-color-name", "navy"),
+            ("x-amz-meta-colors", "3"),
+            ("x-amz-version-id", "v1"),
+        ]);
+
+        let metadata = prefixed(&headers, "x-amz-meta-");
+        assert_eq!(metadata.len(), 3);
+        assert_eq!(metadata["color"], "blue");
+        assert_eq!(metadata["color-name"], "navy");
+        assert_eq!(metadata["colors"], "3");
+
+        let names = prefixed(&headers, "X-Amz-Meta-Color-");
+        assert_eq!(names.len(), 1);
+        assert_eq!(names["name"], "navy");
+    }
+
+    #[test]
+    fn repeated_headers_keep_every_value() {
+        let headers = headers(&[
+            ("x-amz-meta-tag", "a"),
+            ("x-amz-meta-tag", "b"),
+            ("x-amz-meta-tag", "c"),
+        ]);
+
+        assert_eq!(values(&headers, "x-amz-meta-tag"), vec!["a", "b", "c"]);
+        assert!(values(&headers, "x-amz-meta-other").is_empty());
+        assert_eq!(prefixed(&headers, "x-amz-meta-")["tag"], "a, b, c");
+        // the first value, for members bound to a single header
+        assert_eq!(headers.get("x-amz-meta-tag").map(String::as_str), Some("a"));
+    }
+}
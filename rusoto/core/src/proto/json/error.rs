@@ -1,6 +1,15 @@
+use regex::Regex;
 use serde_json::{from_slice, Value};
 
 use super::super::super::request::BufferedHttpResponse;
+use super::super::super::validation::ValidationError;
+
+lazy_static! {
+    /// A constraint broken by a member, as listed in the messages of validation errors:
+    /// `Value null at 'tableName' failed to satisfy constraint: Member must not be null`
+    static ref CONSTRAINT_VIOLATION: Regex =
+        Regex::new(r"at '([^']+)' failed to satisfy constraint: (.+?)(?:; Value |$)").unwrap();
+}
 
 #[derive(Deserialize)]
 struct RawError {
@@ -13,20 +22,32 @@ struct RawError {
 pub struct Error {
     pub typ: String,
     pub msg: String,
+    /// The members which failed validation, from the `fieldList` of the error or its message
+    pub fields: Vec<ValidationError>,
 }
 
 impl Error {
     pub fn parse(res: &BufferedHttpResponse) -> Option<Error> {
         if let Ok(raw_err) = from_slice::<RawError>(&res.body) {
-            let raw_error_type = raw_err.typ.unwrap_or_else(|| "Unknown".to_owned());
+            // JSON 1.0 services may leave the type out of the body and only name it in
+            // the header, as some REST JSON ones do
+            let raw_error_type = raw_err
+                .typ
+                .or_else(|| error_type_header(res))
+                .unwrap_or_else(|| "Unknown".to_owned());
             let msg = raw_err.message.unwrap_or_default();
 
             let pieces: Vec<&str> = raw_error_type.split('#').collect();
             let typ = pieces.last().expect("Expected error type");
+            let fields = match from_slice::<Value>(&res.body) {
+                Ok(json) => field_errors(&json, &msg),
+                Err(_) => Vec::new(),
+            };
 
             Some(Error {
                 typ: typ.to_string(),
                 msg,
+                fields,
             })
         } else {
             None
@@ -37,17 +58,13 @@ impl Error {
     // https://github.com/boto/botocore/blob/4dff78c840403d1d17db9b3f800b20d3bd9fbf9f/botocore/parsers.py#L838-L850
     pub fn parse_rest(res: &BufferedHttpResponse) -> Option<Error> {
         if let Ok(json) = from_slice::<Value>(&res.body) {
-            let typ = match res.headers.get("x-amzn-errortype") {
-                Some(raw_error_type) => raw_error_type
-                    .split(':')
-                    .next()
-                    .unwrap_or_else(|| "Unknown"),
-                _ => json
-                    .get("code")
+            let typ = error_type_header(res).unwrap_or_else(|| {
+                json.get("code")
                     .or_else(|| json.get("Code"))
                     .and_then(serde_json::Value::as_str)
-                    .unwrap_or("Unknown"),
-            };
+                    .unwrap_or("Unknown")
+                    .to_owned()
+            });
 
             // message can come in either \"message\" or \"Message\"
             // see boto BaseJSONParser impl for parsing message
@@ -58,17 +75,61 @@ impl Error {
                 .and_then(serde_json::Value::as_str)
                 .unwrap_or("")
                 .to_string();
+            let fields = field_errors(&json, &msg);
 
-            Some(Error {
-                typ: typ.to_string(),
-                msg,
-            })
+            Some(Error { typ, msg, fields })
         } else {
             None
         }
     }
 }
 
+/// The type of the error as named by the `x-amzn-errortype` header, without the
+/// `:http://...` suffix locating its documentation.
+pub(crate) fn error_type_header(res: &BufferedHttpResponse) -> Option<String> {
+    res.headers
+        .get("x-amzn-errortype")
+        .and_then(|raw_error_type| raw_error_type.split(':').next())
+        .filter(|typ| !typ.is_empty())
+        .map(str::to_owned)
+}
+
+/// The members which failed validation, listed in the `fieldList` of the error document
+/// or, failing that, enumerated in its message.
+pub(crate) fn field_errors(json: &Value, message: &str) -> Vec<ValidationError> {
+    let field_list = json
+        .get("fieldList")
+        .or_else(|| json.get("FieldList"))
+        .and_then(Value::as_array);
+    if let Some(field_list) = field_list {
+        return field_list
+            .iter()
+            .map(|field| {
+                let member = |names: &[&str]| {
+                    names
+                        .iter()
+                        .filter_map(|name| field.get(*name).and_then(Value::as_str))
+                        .next()
+                        .unwrap_or("")
+                        .to_owned()
+                };
+                ValidationError {
+                    field: member(&["name", "Name", "path", "Path"]),
+                    message: member(&["message", "Message"]),
+                }
+            })
+            .collect();
+    }
+
+    CONSTRAINT_VIOLATION
+        .captures_iter(message)
+        .map(|captures| ValidationError {
+            field: captures[1].to_owned(),
+            message: captures[2].to_owned(),
+        })
+        .collect()
+}
+
 #[test]
 fn deserialize_dynamodb_error() {
     use http::StatusCode;
@@ -109,3 +170,95 @@ fn deserialize_athena_error() {
         r#"line 6:18: mismatched input '.' expecting {<EOF>, ',', 'ADD', 'AS', 'ALL', 'SOME', 'ANY', 'WHERE', 'GROUP', 'ORDER', 'HAVING', 'LIMIT', 'AT', 'NO', 'SUBSTRING', 'POSITION', 'TINYINT', 'SMALLINT', 'INTEGER', 'DATE', 'TIME', 'TIMESTAMP', 'INTERVAL', 'YEAR', 'MONTH', 'DAY', 'HOUR', 'MINUTE', 'SECOND', 'ZONE', 'JOIN', 'CROSS', 'INNER', 'LEFT', 'RIGHT', 'FULL', 'NATURAL', 'FILTER', 'OVER', 'PARTITION', 'RANGE', 'ROWS', 'PRECEDING', 'FOLLOWING', 'CURRENT', 'ROW', 'SCHEMA', 'COMMENT', 'VIEW', 'REPLACE', 'GRANT', 'REVOKE', 'PRIVILEGES', 'PUBLIC', 'OPTION', 'EXPLAIN', 'ANALYZE', 'FORMAT', 'TYPE', 'TEXT', 'GRAPHVIZ', 'LOGICAL', 'DISTRIBUTED', 'VALIDATE', 'SHOW', 'TABLES', 'VIEWS', 'SCHEMAS', 'CATALOGS', 'COLUMNS', 'COLUMN', 'USE', 'PARTITIONS', 'FUNCTIONS', 'UNION', 'EXCEPT', 'INTERSECT', 'TO', 'SYSTEM', 'BERNOULLI', 'POISSONIZED', 'TABLESAMPLE', 'ARRAY', 'MAP', 'SET', 'RESET', 'SESSION', 'DATA', 'START', 'TRANSACTION', 'COMMIT', 'ROLLBACK', 'WORK', 'ISOLATION', 'LEVEL', 'SERIALIZABLE', 'REPEATABLE', 'COMMITTED', 'UNCOMMITTED', 'READ', 'WRITE', 'ONLY', 'CALL', 'INPUT', 'OUTPUT', 'CASCADE', 'RESTRICT', 'INCLUDING', 'EXCLUDING', 'PROPERTIES', 'FUNCTION', 'RETURNS', 'LANGUAGE', 'OPTIONS', 'SCALAR', 'AGGREGATE', 'WINDOW', 'NFD', 'NFC', 'NFKD', 'NFKC', 'IF', 'NULLIF', 'COALESCE', IDENTIFIER, DIGIT_IDENTIFIER, QUOTED_IDENTIFIER, BACKQUOTED_IDENTIFIER}"#
     );
 }
+
+#[test]
+fn deserialize_dynamodb_validation_error_fields() {
+    use http::StatusCode;
+
+    let payload = r#"{"__type":"com.amazon.coral.validate#ValidationException",
+"message":"2 validation errors detected: Value null at 'tableName' failed to satisfy constraint: Member must not be null; Value '0' at 'limit' failed to satisfy constraint: Member must have value greater than or equal to 1"}"#;
+    let response = BufferedHttpResponse {
+        status: StatusCode::BAD_REQUEST,
+        body: payload.into(),
+        headers: Default::default(),
+    };
+
+    let error = Error::parse(&response).unwrap();
+
+    assert_eq!(error.typ, "ValidationException");
+    assert_eq!(
+        error.fields,
+        vec![
+            ValidationError {
+                field: "tableName".to_owned(),
+                message: "Member must not be null".to_owned(),
+            },
+            ValidationError {
+                field: "limit".to_owned(),
+                message: "Member must have value greater than or equal to 1".to_owned(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn deserialize_validation_error_field_list() {
+    use http::StatusCode;
+
+    let payload = r#"{"__type":"ValidationException","message":"Invalid request",
+"reason":"FIELD_VALIDATION_FAILED","fieldList":[{"name":"eventArns","message":"Too many values"}]}"#;
+    let response = BufferedHttpResponse {
+        status: StatusCode::BAD_REQUEST,
+        body: payload.into(),
+        headers: Default::default(),
+    };
+
+    let error = Error::parse(&response).unwrap();
+
+    assert_eq!(
+        error.fields,
+        vec![ValidationError {
+            field: "eventArns".to_owned(),
+            message: "Too many values".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn deserialize_validation_error_without_fields() {
+    use http::StatusCode;
+
+    let payload = r#"{"__type":"ValidationException","message":"The request is invalid"}"#;
+    let response = BufferedHttpResponse {
+        status: StatusCode::BAD_REQUEST,
+        body: payload.into(),
+        headers: Default::default(),
+    };
+
+    let error = Error::parse(&response).unwrap();
+
+    assert_eq!(error.msg, "The request is invalid");
+    assert!(error.fields.is_empty());
+}
+
+#[test]
+fn deserialize_error_typed_by_header() {
+    use http::{HeaderMap, StatusCode};
+
+    let mut headers = HeaderMap::default();
+    headers.insert(
+        "x-amzn-errortype",
+        "ThrottlingException:http://internal.amazon.com/coral/com.amazon.coral.availability/"
+            .to_owned(),
+    );
+    let response = BufferedHttpResponse {
+        status: StatusCode::BAD_REQUEST,
+        body: r#"{"message":"Rate exceeded"}"#.into(),
+        headers,
+    };
+
+    let error = Error::parse(&response).unwrap();
+
+    assert_eq!(error.typ, "ThrottlingException");
+    assert_eq!(error.msg, "Rate exceeded");
+}
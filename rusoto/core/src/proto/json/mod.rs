@@ -2,4 +2,5 @@ mod error;
 mod payload;
 
 pub use self::error::Error;
-pub use self::payload::ResponsePayload;
+pub(crate) use self::error::{error_type_header, field_errors};
+pub use self::payload::{deserialize_streaming, ResponsePayload, STREAMING_THRESHOLD};
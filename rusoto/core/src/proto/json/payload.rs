@@ -1,32 +1,491 @@
+use std::io::{self, BufReader};
+use std::thread;
+
 use bytes::Bytes;
+use futures::sync::oneshot;
+use futures::Future;
 use serde::de::DeserializeOwned;
-use serde_json::from_slice;
+use serde_json::{from_reader, from_slice, from_value, Value};
 
-use super::super::super::request::BufferedHttpResponse;
+use super::super::super::request::{BufferedHttpResponse, HttpResponse};
 use super::super::super::RusotoError;
+use super::super::{is_format, parse_error, UnexpectedContentType};
+
+/// The size from which `deserialize_streaming` parses response bodies as they are read.
+pub const STREAMING_THRESHOLD: usize = 1024 * 1024;
+
+/// How many bytes of a streamed response body are read at once.
+const STREAMING_READ_SIZE: usize = 64 * 1024;
+
+/// Deserializes a successful response, parsing its body as it is read when it declares a
+/// length of at least `STREAMING_THRESHOLD` bytes.
+///
+/// Buffering a body before parsing it holds both the body and what is parsed from it in
+/// memory, which for the megabytes of JSON of some list operations is twice the memory
+/// the output takes. Large bodies are parsed on a thread of their own instead, reading
+/// them with blocking i/o, and are only ever held a chunk at a time. Smaller bodies, those
+/// of unknown length and those which aren't JSON are deserialized like
+/// `ResponsePayload::deserialize` does. Malformed bodies fail with a `ParseError` naming
+/// `operation` either way, though only those which were buffered tell the path of the
+/// field which couldn't be parsed.
+pub fn deserialize_streaming<T, E>(
+    response: HttpResponse,
+    operation: &'static str,
+) -> Box<dyn Future<Item = T, Error = RusotoError<E>> + Send>
+where
+    T: DeserializeOwned + Send + 'static,
+    E: Send + 'static,
+{
+    let is_json = response
+        .headers
+        .get("content-type")
+        .map_or(true, |content_type| is_format(content_type, "json"));
+    let large = response
+        .body
+        .size_hint()
+        .map_or(false, |size| size >= STREAMING_THRESHOLD);
+    if !is_json || !large {
+        return Box::new(response.buffer().from_err().and_then(move |response| {
+            ResponsePayload::new(&response)
+                .with_operation(operation)
+                .deserialize::<T, E>()
+        }));
+    }
+
+    let (sender, receiver) = oneshot::channel();
+    let body = response.body;
+    thread::spawn(move || {
+        let reader = BufReader::with_capacity(STREAMING_READ_SIZE, body.into_blocking_read());
+        let _ = sender.send(from_reader::<_, T>(reader));
+    });
+    Box::new(
+        receiver
+            .map_err(|_| RusotoError::ParseError("the response parser stopped".to_owned()))
+            .and_then(move |result| {
+                result.map_err(|err| {
+                    if err.is_io() {
+                        RusotoError::from(io::Error::from(err))
+                    } else {
+                        parse_error(Some(operation), None, &err.to_string(), None)
+                    }
+                })
+            }),
+    )
+}
 
 pub struct ResponsePayload {
     body: Bytes,
+    request_id: Option<String>,
+    unexpected: Option<UnexpectedContentType>,
+    operation: Option<String>,
 }
 
 impl ResponsePayload {
     pub fn new(res: &BufferedHttpResponse) -> Self {
         let mut body = res.body.clone();
+        let mut unexpected = UnexpectedContentType::check(res, "json");
 
         // `serde-json` serializes field-less structs as "null", but AWS returns
         // "{}" for a field-less response, so we must check for this result
         // and convert it if necessary.
-        if body.is_empty() || body.as_ref() == b"null" {
+        if body.as_ref() == b"null" {
+            body = Bytes::from_static(b"{}");
+        }
+        // Operations such as deletes may answer with no body at all, often with a 204,
+        // which deserializes like an empty object into outputs without required fields.
+        if body.iter().all(u8::is_ascii_whitespace) {
             body = Bytes::from_static(b"{}");
+            unexpected = None;
         }
 
         debug!("Response body: {:?}", body);
         debug!("Response status: {}", res.status);
 
-        Self { body }
+        Self {
+            body,
+            request_id: res.request_id().map(str::to_owned),
+            unexpected,
+            operation: None,
+        }
     }
 
+    /// Names `operation` as the one the response answers in the errors deserializing it.
+    pub fn with_operation(mut self, operation: &str) -> Self {
+        self.operation = Some(operation.to_owned());
+        self
+    }
+
+    /// Deserializes the response, unless it isn't JSON, as when a proxy answers with an
+    /// HTML page in place of the service.
     pub fn deserialize<T: DeserializeOwned, E>(&self) -> Result<T, RusotoError<E>> {
-        Ok(from_slice(&self.body)?)
+        self.expect_json()?;
+        from_slice(&self.body).map_err(|err| self.parse_error(&err))
+    }
+
+    /// Deserializes a response whose payload is nested in an operation-specific
+    /// result element, such as `{"DescribeThingsResult": {...}, "ResponseMetadata": {...}}`.
+    /// Responses without the wrapper element are deserialized as-is. The request id of
+    /// the `ResponseMetadata` element, if there is one, becomes that of the response.
+    pub fn deserialize_wrapped<T: DeserializeOwned, E>(
+        &mut self,
+        result_wrapper: &str,
+    ) -> Result<T, RusotoError<E>> {
+        self.expect_json()?;
+        let mut value: Value = from_slice(&self.body).map_err(|err| self.parse_error(&err))?;
+        if let Some(request_id) = value
+            .pointer("/ResponseMetadata/RequestId")
+            .and_then(Value::as_str)
+        {
+            self.request_id = Some(request_id.to_owned());
+        }
+        let inner = match value.get_mut(result_wrapper) {
+            Some(inner) => inner.take(),
+            None => value,
+        };
+        if inner.is_null() {
+            return from_slice(b"{}").map_err(|err| self.parse_error(&err));
+        }
+        from_value(inner).map_err(|err| self.parse_error(&err))
+    }
+
+    /// The request id of the response, taken from the `ResponseMetadata` element of
+    /// wrapped responses once deserialized, and otherwise from the request id header.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_ref().map(|request_id| &request_id[..])
+    }
+
+    /// A `ParseError` for `err`, telling the path of the field which couldn't be parsed
+    /// when `err` says where in the body it is.
+    fn parse_error<E>(&self, err: &serde_json::Error) -> RusotoError<E> {
+        let path = if err.line() > 0 {
+            Some(json_path(&self.body, err.line(), err.column()))
+        } else {
+            None
+        };
+        parse_error(
+            self.operation.as_ref().map(|operation| &operation[..]),
+            path.as_ref().map(|path| &path[..]),
+            &err.to_string(),
+            Some(&self.body),
+        )
+    }
+
+    fn expect_json<E>(&self) -> Result<(), RusotoError<E>> {
+        match self.unexpected {
+            Some(ref unexpected) => Err(unexpected.clone().into_error()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A container of the JSON body being scanned by `json_path`.
+enum Container {
+    /// An object, with the key of the value being read, if past it.
+    Object(Option<String>),
+    /// An array, with the index of the value being read.
+    Array(usize),
+}
+
+/// The path of the value at `line` and `column` of `body`, as given by the errors of
+/// `serde_json`, such as `Events[3].StatusCode`.
+fn json_path(body: &[u8], line: usize, column: usize) -> String {
+    // both count from 1, and the column is that of the last byte read
+    let line_start: usize = body
+        .split(|&byte| byte == b'\n')
+        .take(line - 1)
+        .map(|line| line.len() + 1)
+        .sum();
+    let end = (line_start + column).min(body.len());
+
+    let mut containers = Vec::new();
+    let mut position = 0;
+    while position < end {
+        match body[position] {
+            b'{' => containers.push(Container::Object(None)),
+            b'[' => containers.push(Container::Array(0)),
+            b'}' | b']' => {
+                containers.pop();
+            }
+            b',' => match containers.last_mut() {
+                Some(Container::Object(ref mut key)) => *key = None,
+                Some(Container::Array(ref mut index)) => *index += 1,
+                None => {}
+            },
+            b'"' => {
+                let start = position + 1;
+                position = start;
+                while position < body.len() && body[position] != b'"' {
+                    // skips the escaped byte, which may be a quote
+                    if body[position] == b'\\' {
+                        position += 1;
+                    }
+                    position += 1;
+                }
+                if let Some(Container::Object(ref mut key)) = containers.last_mut() {
+                    if key.is_none() {
+                        let name = &body[start..position.min(body.len())];
+                        *key = Some(String::from_utf8_lossy(name).into_owned());
+                    }
+                }
+            }
+            _ => {}
+        }
+        position += 1;
+    }
+
+    let mut path = String::new();
+    for container in &containers {
+        match *container {
+            Container::Object(Some(ref key)) => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+            }
+            Container::Object(None) => {}
+            Container::Array(index) => path.push_str(&format!("[{}]", index)),
+        }
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ByteStream;
+    use http::{HeaderMap, StatusCode};
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    struct Output {
+        #[serde(rename = "Name")]
+        name: Option<String>,
+    }
+
+    fn payload(body: &'static str, headers: HeaderMap<String>) -> ResponsePayload {
+        ResponsePayload::new(&BufferedHttpResponse {
+            status: StatusCode::OK,
+            body: Bytes::from_static(body.as_bytes()),
+            headers,
+        })
+    }
+
+    #[test]
+    fn deserialize_wrapped_unwraps_result_element() {
+        let mut payload = payload(
+            r#"{"GetThingResult": {"Name": "thing"}, "ResponseMetadata": {"RequestId": "abc-123"}}"#,
+            HeaderMap::default(),
+        );
+        let output: Output = payload
+            .deserialize_wrapped::<_, ()>("GetThingResult")
+            .unwrap();
+        assert_eq!(output.name, Some("thing".to_owned()));
+        assert_eq!(payload.request_id(), Some("abc-123"));
+    }
+
+    #[test]
+    fn deserialize_wrapped_accepts_unwrapped_and_empty_results() {
+        let output: Output = payload(r#"{"Name": "thing"}"#, HeaderMap::default())
+            .deserialize_wrapped::<_, ()>("GetThingResult")
+            .unwrap();
+        assert_eq!(output.name, Some("thing".to_owned()));
+
+        let output: Output = payload(r#"{"GetThingResult": null}"#, HeaderMap::default())
+            .deserialize_wrapped::<_, ()>("GetThingResult")
+            .unwrap();
+        assert_eq!(output, Output::default());
+    }
+
+    #[test]
+    fn request_id_falls_back_to_header() {
+        let mut headers = HeaderMap::default();
+        headers.insert("x-amzn-requestid", "from-header".to_owned());
+        let mut payload = payload("{}", headers);
+        assert_eq!(payload.request_id(), Some("from-header"));
+        payload
+            .deserialize_wrapped::<Output, ()>("GetThingResult")
+            .unwrap();
+        assert_eq!(payload.request_id(), Some("from-header"));
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct RequiredOutput {
+        #[serde(rename = "Name")]
+        _name: String,
+    }
+
+    #[test]
+    fn deserialize_accepts_empty_bodies_for_outputs_without_required_fields() {
+        let mut headers = HeaderMap::default();
+        headers.insert("content-type", "text/plain".to_owned());
+        for body in &["", "\n", " \r\n"] {
+            let output: Output = payload(body, headers.clone())
+                .deserialize::<_, ()>()
+                .unwrap();
+            assert_eq!(output, Output::default());
+        }
+
+        assert!(payload("", HeaderMap::default())
+            .deserialize::<RequiredOutput, ()>()
+            .is_err());
+    }
+
+    #[test]
+    fn deserialize_rejects_responses_which_are_not_json() {
+        let mut headers = HeaderMap::default();
+        headers.insert("content-type", "application/x-amz-json-1.1".to_owned());
+        let output: Output = payload(r#"{"Name": "thing"}"#, headers)
+            .deserialize::<_, ()>()
+            .unwrap();
+        assert_eq!(output.name, Some("thing".to_owned()));
+
+        let mut headers = HeaderMap::default();
+        headers.insert("content-type", "text/html; charset=utf-8".to_owned());
+        let result =
+            payload("<html><body>Please log in</body></html>", headers).deserialize::<Output, ()>();
+        assert_eq!(
+            result,
+            Err(RusotoError::UnexpectedContentType {
+                status: StatusCode::OK,
+                content_type: "text/html; charset=utf-8".to_owned(),
+                body: "<html><body>Please log in</body></html>".to_owned(),
+            })
+        );
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct ListOutput {
+        #[serde(rename = "Names")]
+        names: Vec<String>,
+    }
+
+    fn streaming_response(body: String, content_type: &str) -> HttpResponse {
+        let size = body.len();
+        let chunks: Vec<Bytes> = body
+            .into_bytes()
+            .chunks(16 * 1024)
+            .map(Bytes::from)
+            .collect();
+        let mut headers = HeaderMap::default();
+        headers.insert("content-type", content_type.to_owned());
+        HttpResponse {
+            status: StatusCode::OK,
+            body: ByteStream::new(futures::stream::iter_ok(chunks)).with_size(size),
+            headers,
+            url: None,
+        }
+    }
+
+    fn names(count: usize) -> String {
+        let names: Vec<String> = (0..count).map(|n| format!("\"name-{}\"", n)).collect();
+        format!(r#"{{"Names": [{}]}}"#, names.join(", "))
+    }
+
+    #[test]
+    fn deserialize_streaming_parses_large_bodies() {
+        let body = names(200_000);
+        assert!(body.len() >= STREAMING_THRESHOLD);
+        let output = deserialize_streaming::<ListOutput, ()>(
+            streaming_response(body, "application/x-amz-json-1.0"),
+            "ListNames",
+        )
+        .wait()
+        .unwrap();
+        assert_eq!(output.names.len(), 200_000);
+        assert_eq!(output.names[199_999], "name-199999");
+
+        let output = deserialize_streaming::<ListOutput, ()>(
+            streaming_response(names(3), "application/x-amz-json-1.0"),
+            "ListNames",
+        )
+        .wait()
+        .unwrap();
+        assert_eq!(output.names, vec!["name-0", "name-1", "name-2"]);
+    }
+
+    #[test]
+    fn deserialize_streaming_surfaces_typed_errors() {
+        let mut body = names(200_000);
+        body.truncate(body.len() - 10);
+        let result = deserialize_streaming::<ListOutput, ()>(
+            streaming_response(body, "application/x-amz-json-1.0"),
+            "ListNames",
+        )
+        .wait();
+        match result {
+            Err(RusotoError::ParseError(message)) => {
+                assert!(message.starts_with("Failed to parse the response of ListNames: "))
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+
+        let body = format!("<html>{}</html>", "x".repeat(STREAMING_THRESHOLD));
+        let result = deserialize_streaming::<ListOutput, ()>(
+            streaming_response(body, "text/html"),
+            "ListNames",
+        )
+        .wait();
+        match result {
+            Err(RusotoError::UnexpectedContentType { content_type, .. }) => {
+                assert_eq!(content_type, "text/html")
+            }
+            other => panic!("expected an unexpected content type, got {:?}", other),
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct EventsOutput {
+        #[serde(rename = "Events")]
+        _events: Vec<Event>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Event {
+        #[serde(rename = "Arn")]
+        _arn: String,
+        #[serde(rename = "Count")]
+        _count: Option<i64>,
+    }
+
+    fn parse_error_message(body: &str) -> String {
+        let result = ResponsePayload::new(&BufferedHttpResponse {
+            status: StatusCode::OK,
+            body: Bytes::from(body.to_owned()),
+            headers: HeaderMap::default(),
+        })
+        .with_operation("DescribeEvents")
+        .deserialize::<EventsOutput, ()>();
+        match result {
+            Err(RusotoError::ParseError(message)) => message,
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_errors_tell_the_operation_and_the_path_of_the_field() {
+        assert_eq!(
+            parse_error_message(r#"{"Events": [{"Arn": "a"}, {"Arn": null}]}"#),
+            "Failed to parse the response of DescribeEvents at `Events[1].Arn`: \
+             invalid type: null, expected a string at line 1 column 38; \
+             body: {\"Events\": [{\"Arn\": \"a\"}, {\"Arn\": null}]}"
+        );
+        assert!(
+            parse_error_message("{\"Events\": [\n  {\"Arn\": \"a\", \"Count\": \"two\"}\n]}")
+                .contains(" at `Events[0].Count`: invalid type: string \"two\"")
+        );
+        assert!(
+            parse_error_message(r#"{"Events": [{"Arn": "a"}, {"Count": 2}]}"#)
+                .contains(" at `Events[1]`: missing field `Arn`")
+        );
+        assert!(parse_error_message(r#"{"Events": {"Arn": "a"}}"#)
+            .contains(" at `Events`: invalid type: map, expected a sequence"));
+    }
+
+    #[test]
+    fn parse_errors_keep_the_start_of_the_body() {
+        let message = parse_error_message(&format!(r#"{{"Events": [{}"#, "1, ".repeat(200)));
+        assert!(message.starts_with(
+            "Failed to parse the response of DescribeEvents at `Events[0]`: invalid type: integer"
+        ));
+        assert!(message.ends_with("1, 1, 1..."));
     }
 }
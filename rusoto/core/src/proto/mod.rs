@@ -1,2 +1,91 @@
+pub mod cbor;
+pub mod headers;
 pub mod json;
 pub mod xml;
+
+use http::StatusCode;
+
+use crate::error::RusotoError;
+use crate::request::BufferedHttpResponse;
+
+/// How many bytes of the body of a response of unexpected content type, or which couldn't
+/// be parsed, its error keeps.
+const UNEXPECTED_BODY_PREFIX_LENGTH: usize = 256;
+
+/// A response whose content type isn't that of the protocol of the service.
+#[derive(Clone, Debug)]
+pub(crate) struct UnexpectedContentType {
+    status: StatusCode,
+    content_type: String,
+    body: String,
+}
+
+impl UnexpectedContentType {
+    /// Checks that `response`, if it has a body and a content type, is in `format`, such as
+    /// `json` or `xml`: `application/x-amz-json-1.1` and `text/xml` both are, `text/html`
+    /// is neither.
+    pub(crate) fn check(response: &BufferedHttpResponse, format: &str) -> Option<Self> {
+        if response.body.is_empty() {
+            return None;
+        }
+        let content_type = response.headers.get("content-type")?;
+        if is_format(content_type, format) {
+            return None;
+        }
+        let prefix = &response.body[..response.body.len().min(UNEXPECTED_BODY_PREFIX_LENGTH)];
+        Some(UnexpectedContentType {
+            status: response.status,
+            content_type: content_type.to_owned(),
+            body: String::from_utf8_lossy(prefix).into_owned(),
+        })
+    }
+
+    pub(crate) fn into_error<E>(self) -> RusotoError<E> {
+        RusotoError::UnexpectedContentType {
+            status: self.status,
+            content_type: self.content_type,
+            body: self.body,
+        }
+    }
+}
+
+/// Whether `content_type` is in `format`, or doesn't say.
+pub(crate) fn is_format(content_type: &str, format: &str) -> bool {
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    media_type.is_empty() || media_type.contains(format)
+}
+
+/// A `ParseError` for a response which couldn't be parsed, naming the operation it answers
+/// and the path of the field which couldn't be parsed when they are known, and giving the
+/// start of its body when it was kept.
+pub(crate) fn parse_error<E>(
+    operation: Option<&str>,
+    path: Option<&str>,
+    message: &str,
+    body: Option<&[u8]>,
+) -> RusotoError<E> {
+    let mut error = "Failed to parse the response".to_owned();
+    if let Some(operation) = operation {
+        error.push_str(&format!(" of {}", operation));
+    }
+    if let Some(path) = path.filter(|path| !path.is_empty()) {
+        error.push_str(&format!(" at `{}`", path));
+    }
+    error.push_str(&format!(": {}", message));
+    if let Some(body) = body {
+        error.push_str(&format!("; body: {}", body_prefix(body)));
+    }
+    RusotoError::ParseError(error)
+}
+
+/// The start of `body`, followed by an ellipsis if it goes on.
+pub(crate) fn body_prefix(body: &[u8]) -> String {
+    let prefix = &body[..body.len().min(UNEXPECTED_BODY_PREFIX_LENGTH)];
+    let ellipsis = if prefix.len() < body.len() { "..." } else { "" };
+    format!("{}{}", String::from_utf8_lossy(prefix), ellipsis)
+}
@@ -4,11 +4,17 @@
 //! Also provides a method of supplying an XML stack from a file for testing purposes.
 
 use std::collections::HashMap;
+use std::fmt;
 use std::iter::Peekable;
 use std::num::ParseIntError;
+use std::str::FromStr;
 use xml;
 use xml::reader::{Events, XmlEvent};
 
+use crate::error::RusotoError;
+use crate::proto::{parse_error, UnexpectedContentType};
+use crate::request::BufferedHttpResponse;
+
 /// generic Error for XML parsing
 #[derive(Debug)]
 pub struct XmlParseError(pub String);
@@ -35,11 +41,33 @@ pub trait Next {
 /// Wraps the Hyper Response type
 pub struct XmlResponse<'b> {
     xml_stack: Peekable<Events<&'b [u8]>>, // refactor to use XmlStack type?
+    /// The names of the elements started and not yet ended.
+    path: Vec<String>,
 }
 
 impl<'b> XmlResponse<'b> {
     pub fn new(stack: Peekable<Events<&'b [u8]>>) -> XmlResponse {
-        XmlResponse { xml_stack: stack }
+        XmlResponse {
+            xml_stack: stack,
+            path: Vec::new(),
+        }
+    }
+
+    /// The path of the element being read, such as `ListBucketResult/Contents/Size`.
+    pub fn path(&self) -> String {
+        self.path.join("/")
+    }
+
+    /// A `ParseError` for `err`, failing to parse `body` as the response of `operation`,
+    /// telling the path of the element being read.
+    pub fn parse_error<E>(
+        &self,
+        operation: &str,
+        err: XmlParseError,
+        body: &[u8],
+    ) -> RusotoError<E> {
+        let XmlParseError(message) = err;
+        parse_error(Some(operation), Some(&self.path()), &message, Some(body))
     }
 }
 
@@ -62,16 +90,45 @@ impl<'b> Next for XmlResponse<'b> {
                 _ => break,
             }
         }
+        match maybe_event {
+            Some(Ok(XmlEvent::StartElement { ref name, .. })) => {
+                self.path.push(name.local_name.clone())
+            }
+            Some(Ok(XmlEvent::EndElement { .. })) => {
+                self.path.pop();
+            }
+            _ => {}
+        }
         maybe_event
     }
 }
 
+/// Fails with `RusotoError::UnexpectedContentType` unless `response` is XML, so that a
+/// page from a proxy answering in place of the service isn't parsed as a response of it.
+pub fn expect_xml<E>(response: &BufferedHttpResponse) -> Result<(), RusotoError<E>> {
+    match UnexpectedContentType::check(response, "xml") {
+        Some(unexpected) => Err(unexpected.into_error()),
+        None => Ok(()),
+    }
+}
+
 impl From<ParseIntError> for XmlParseError {
     fn from(_e: ParseIntError) -> XmlParseError {
         XmlParseError::new("ParseIntError")
     }
 }
 
+/// Parses the characters of an element as a number or a boolean, or throws a parse error
+/// telling what they are.
+pub fn parse_primitive<T>(characters: &str) -> Result<T, XmlParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    T::from_str(characters)
+        .map_err(|err| XmlParseError(format!("invalid value `{}`: {}", characters, err)))
+}
+
 /// return a string field with the right name or throw a parse error
 pub fn string_field<T: Peek + Next>(name: &str, stack: &mut T) -> Result<String, XmlParseError> {
     start_element(name, stack)?;
@@ -337,6 +394,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_errors_tell_the_path_of_the_element_being_read() {
+        let body = b"<Result><Count>3</Count><Size>large</Size></Result>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser.into_iter().peekable());
+        reader.next();
+
+        start_element("Result", &mut reader).unwrap();
+        start_element("Count", &mut reader).unwrap();
+        assert_eq!(
+            parse_primitive::<i64>(&characters(&mut reader).unwrap()).unwrap(),
+            3
+        );
+        end_element("Count", &mut reader).unwrap();
+        start_element("Size", &mut reader).unwrap();
+        let err = parse_primitive::<i64>(&characters(&mut reader).unwrap()).unwrap_err();
+
+        match reader.parse_error::<()>("GetThing", err, body) {
+            RusotoError::ParseError(message) => assert_eq!(
+                message,
+                "Failed to parse the response of GetThing at `Result/Size`: \
+                 invalid value `large`: invalid digit found in string; \
+                 body: <Result><Count>3</Count><Size>large</Size></Result>"
+            ),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_find_start_element() {
         let body = include_bytes!("../../../test_resources/list_queues_with_queue.xml");
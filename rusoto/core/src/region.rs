@@ -5,6 +5,7 @@
 //! For example: `UsEast1` to "us-east-1"
 
 use crate::credential::ProfileProvider;
+use http::Uri;
 use serde::ser::SerializeTuple;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std;
@@ -24,13 +25,18 @@ use std::str::FromStr;
 /// # AWS-compatible services
 ///
 /// `Region::Custom` can be used to connect to AWS-compatible services such as DynamoDB Local or Ceph.
+/// Prefer creating it with `Region::custom`, which checks the endpoint is a valid URL.
 ///
 /// ```
 ///     # use rusoto_core::Region;
-///     Region::Custom {
-///         name: "eu-east-3".to_owned(),
-///         endpoint: "http://localhost:8000".to_owned(),
-///     };
+///     let region = Region::custom("eu-east-3", "http://localhost:8000/").unwrap();
+///     assert_eq!(
+///         region,
+///         Region::Custom {
+///             name: "eu-east-3".to_owned(),
+///             endpoint: "http://localhost:8000".to_owned(),
+///         }
+///     );
 /// ```
 ///
 /// # Caveats
@@ -107,7 +113,8 @@ pub enum Region {
     /// Region that covers North-Western  part of China
     CnNorthwest1,
 
-    /// Specifies a custom region, such as a local Ceph target
+    /// Specifies a custom region, such as a local Ceph target. The endpoint isn't checked
+    /// when building this variant directly, use `Region::custom` to catch mistakes early.
     Custom {
         /// Name of the endpoint (e.g. `"eu-east-2"`).
         name: String,
@@ -119,6 +126,69 @@ pub enum Region {
 }
 
 impl Region {
+    /// A custom region, such as a local Ceph target, checking its endpoint.
+    ///
+    /// The endpoint must be an `http` or `https` URL without a query, for example
+    /// `"https://s3.my-provider.net"`. A trailing slash is removed.
+    ///
+    /// ```
+    ///     # use rusoto_core::Region;
+    ///     assert!(Region::custom("eu-east-3", "https://s3.my-provider.net").is_ok());
+    ///     assert!(Region::custom("eu-east-3", "s3.my-provider.net").is_err());
+    /// ```
+    pub fn custom<N, E>(name: N, endpoint: E) -> Result<Region, RegionError>
+    where
+        N: Into<String>,
+        E: AsRef<str>,
+    {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return Err(RegionError::new(
+                "the name of a custom region can't be empty",
+            ));
+        }
+
+        let endpoint = endpoint.as_ref().trim_end_matches('/');
+        let uri = endpoint.parse::<Uri>().map_err(|e| {
+            RegionError::new(format!(
+                "invalid custom region endpoint {:?}: {}",
+                endpoint, e
+            ))
+        })?;
+        match uri.scheme_str() {
+            Some("http") | Some("https") => {}
+            Some(scheme) => {
+                return Err(RegionError::new(format!(
+                    "invalid custom region endpoint {:?}: unsupported scheme {}, expected http or https",
+                    endpoint, scheme
+                )))
+            }
+            None => {
+                return Err(RegionError::new(format!(
+                    "invalid custom region endpoint {:?}: missing scheme, expected http:// or https://",
+                    endpoint
+                )))
+            }
+        }
+        if uri.host().unwrap_or("").is_empty() {
+            return Err(RegionError::new(format!(
+                "invalid custom region endpoint {:?}: missing host",
+                endpoint
+            )));
+        }
+        if uri.query().is_some() {
+            return Err(RegionError::new(format!(
+                "invalid custom region endpoint {:?}: endpoints can't have a query",
+                endpoint
+            )));
+        }
+
+        Ok(Region::Custom {
+            name,
+            endpoint: endpoint.to_owned(),
+        })
+    }
+
     /// Name of the region
     ///
     /// ```
@@ -278,6 +348,32 @@ impl Display for ParseRegionError {
     }
 }
 
+/// An error produced when the endpoint of a custom region is invalid.
+#[derive(Debug, PartialEq)]
+pub struct RegionError {
+    message: String,
+}
+
+impl RegionError {
+    fn new<S: Into<String>>(message: S) -> Self {
+        RegionError {
+            message: message.into(),
+        }
+    }
+}
+
+impl Error for RegionError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for RegionError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        write!(f, "{}", self.message)
+    }
+}
+
 impl Default for Region {
     fn default() -> Region {
         match std::env::var("AWS_DEFAULT_REGION").or_else(|_| std::env::var("AWS_REGION")) {
@@ -410,4 +506,54 @@ mod tests {
         let from_json = serde_json::de::from_str(&only_region_name).unwrap();
         assert_eq!(r, from_json);
     }
+
+    #[test]
+    fn custom_region_normalizes_endpoint() {
+        assert_eq!(
+            Region::custom("eu-east-1", "http://localhost:8000/"),
+            Ok(Region::Custom {
+                name: "eu-east-1".to_owned(),
+                endpoint: "http://localhost:8000".to_owned(),
+            })
+        );
+        assert_eq!(
+            Region::custom("eu-east-1", "https://ceph.example.com/rgw"),
+            Ok(Region::Custom {
+                name: "eu-east-1".to_owned(),
+                endpoint: "https://ceph.example.com/rgw".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn custom_region_rejects_invalid_endpoints() {
+        for endpoint in &[
+            "localhost:8000",
+            "ftp://localhost",
+            "http://local host:8000",
+            "http://",
+            "https://s3.example.com?x=1",
+            "",
+        ] {
+            assert!(
+                Region::custom("eu-east-1", endpoint).is_err(),
+                "{} should be rejected",
+                endpoint
+            );
+        }
+    }
+
+    #[test]
+    fn custom_region_requires_a_name() {
+        assert!(Region::custom(" ", "http://localhost:8000").is_err());
+    }
+
+    #[test]
+    fn custom_region_error_names_the_problem() {
+        let error = Region::custom("eu-east-1", "localhost:8000").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid custom region endpoint \"localhost:8000\": missing scheme, expected http:// or https://"
+        );
+    }
 }
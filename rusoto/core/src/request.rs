@@ -4,20 +4,23 @@
 
 //extern crate lazy_static;
 
+use std::collections::BTreeMap;
 use std::env;
 use std::error::Error;
 use std::fmt;
+use std::fs;
 use std::io;
 use std::io::Error as IoError;
 use std::mem;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::tls::HttpsConnector;
 use bytes::Bytes;
 use futures::{Async, Future, Poll, Stream};
-use http::header::{HeaderName, HeaderValue};
+use http::header::{HeaderName, HeaderValue, EXPECT};
 use http::{HeaderMap, Method, StatusCode};
 use hyper::body::Payload;
 use hyper::client::connect::Connect;
@@ -26,10 +29,13 @@ use hyper::client::HttpConnector;
 use hyper::client::ResponseFuture as HyperResponseFuture;
 use hyper::Error as HyperError;
 use hyper::{Body, Client as HyperClient, Request as HyperRequest, Response as HyperResponse};
-use tokio_timer::Timeout;
+use tokio_timer::{Delay, Timeout};
 
 use log::Level::Debug;
 
+use crate::budget::BudgetExceeded;
+use crate::dns::{self, CachingResolver, DnsResolver, SystemResolver};
+use crate::expect_continue::{self, ContinueConnector, ContinueWait, ExpectContinue};
 use crate::signature::{SignedRequest, SignedRequestPayload};
 use crate::stream::ByteStream;
 
@@ -53,8 +59,11 @@ pub struct HttpResponse {
     pub status: StatusCode,
     /// Contents of Response
     pub body: ByteStream,
-    /// Response headers
+    /// Response headers, keeping every value of the headers repeated in the response:
+    /// `get` gives the first value of a header and `get_all` all of them, in order
     pub headers: HeaderMap<String>,
+    /// The URL which served the response, after any redirects, when known
+    pub url: Option<String>,
 }
 
 /// Stores the buffered response from a HTTP request.
@@ -64,7 +73,8 @@ pub struct BufferedHttpResponse {
     pub status: StatusCode,
     /// Contents of Response
     pub body: Bytes,
-    /// Response headers
+    /// Response headers, keeping every value of the headers repeated in the response:
+    /// `get` gives the first value of a header and `get_all` all of them, in order
     pub headers: HeaderMap<String>,
 }
 
@@ -76,6 +86,14 @@ impl BufferedHttpResponse {
             _ => "unknown error",
         }
     }
+
+    /// The AWS request id of the response, if it carries one in its headers.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get("x-amzn-requestid")
+            .or_else(|| self.headers.get("x-amz-request-id"))
+            .map(String::as_str)
+    }
 }
 
 /// Best effort based Debug implementation to make generic error's body more readable.
@@ -122,6 +140,14 @@ impl Future for BufferedHttpResponseFuture {
 }
 
 impl HttpResponse {
+    /// The AWS request id of the response, if it carries one in its headers.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get("x-amzn-requestid")
+            .or_else(|| self.headers.get("x-amz-request-id"))
+            .map(String::as_str)
+    }
+
     /// Buffer the full response body in memory, resulting in a `BufferedHttpResponse`.
     pub fn buffer(self) -> BufferedHttpResponseFuture {
         BufferedHttpResponseFuture {
@@ -131,39 +157,273 @@ impl HttpResponse {
         }
     }
 
-    fn from_hyper(hyper_response: HyperResponse<Body>) -> HttpResponse {
+    fn from_hyper(hyper_response: HyperResponse<Body>, limits: BodyLimits) -> HttpResponse {
         let status = hyper_response.status();
         let headers = hyper_response
             .headers()
             .iter()
             .map(|(h, v)| {
-                let value_string = v.to_str().unwrap().to_owned();
+                // header values aren't always ASCII, which mustn't bring down the caller
+                let value_string = String::from_utf8_lossy(v.as_bytes()).into_owned();
                 (h.clone(), value_string)
             })
             .collect();
+        // hyper's decoded length, unlike the header, knows when a response
+        // such as that of a HEAD request has no body
+        let content_length = hyper_response
+            .body()
+            .content_length()
+            .map(|length| length as usize);
         let body = hyper_response
             .into_body()
             .map(hyper::Chunk::into_bytes)
             .map_err(|err| io::Error::new(io::ErrorKind::Other, err));
 
+        let body = ByteStream::new(LimitedBody::new(body, limits, content_length));
+        // so that the progress of the body can be told against its length
+        let body = match content_length {
+            Some(length) => body.with_size(length),
+            None => body,
+        };
+
         HttpResponse {
             status,
             headers,
-            body: ByteStream::new(body),
+            body,
+            url: None,
+        }
+    }
+}
+
+/// Limits enforced while streaming a response body, see `HttpConfig`.
+#[derive(Clone, Copy, Debug, Default)]
+struct BodyLimits {
+    read_timeout: Option<Duration>,
+    max_size: Option<usize>,
+}
+
+/// Response body stream which fails when the next chunk takes longer than the
+/// configured read timeout to arrive, when the body grows past the maximum size,
+/// or when the connection closes before the whole body has been received.
+struct LimitedBody<S> {
+    inner: S,
+    limits: BodyLimits,
+    received: usize,
+    delay: Option<Delay>,
+    content_length: Option<usize>,
+}
+
+impl<S> LimitedBody<S> {
+    fn new(inner: S, limits: BodyLimits, content_length: Option<usize>) -> Self {
+        LimitedBody {
+            inner,
+            limits,
+            received: 0,
+            delay: None,
+            content_length,
+        }
+    }
+
+    fn too_large(&self, size: usize) -> io::Error {
+        let max_size = self.limits.max_size.unwrap_or_default();
+        HttpDispatchError {
+            message: format!(
+                "Response body of at least {} bytes exceeds the maximum of {} bytes",
+                size, max_size
+            ),
+            kind: HttpDispatchErrorKind::BodyTooLarge,
+            source: None,
+        }
+        .into()
+    }
+
+    fn truncated(&self) -> io::Error {
+        let message = match self.content_length {
+            Some(length) => format!(
+                "Response body ended after {} of {} bytes",
+                self.received, length
+            ),
+            None => format!(
+                "Response body ended after {} bytes without its terminating chunk",
+                self.received
+            ),
+        };
+        HttpDispatchError {
+            message,
+            kind: HttpDispatchErrorKind::TruncatedBody,
+            source: None,
+        }
+        .into()
+    }
+}
+
+/// Whether a response body stream failed because the connection closed before
+/// the end of the body, as hyper reports for short or unterminated bodies.
+fn is_early_end(err: &io::Error) -> bool {
+    let hyper_error = match err
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<HyperError>())
+    {
+        Some(hyper_error) => hyper_error,
+        None => return false,
+    };
+    hyper_error.is_incomplete_message()
+        || hyper_error
+            .source()
+            .and_then(|cause| cause.downcast_ref::<IoError>())
+            .map_or(false, |io_error| {
+                io_error.kind() == io::ErrorKind::UnexpectedEof
+            })
+}
+
+impl<S> Stream for LimitedBody<S>
+where
+    S: Stream<Item = Bytes, Error = io::Error>,
+{
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if let (Some(max_size), Some(length)) = (self.limits.max_size, self.content_length) {
+            if length > max_size {
+                return Err(self.too_large(length));
+            }
+        }
+
+        let polled = match self.inner.poll() {
+            Ok(polled) => polled,
+            Err(ref err) if is_early_end(err) => return Err(self.truncated()),
+            Err(err) => return Err(err),
+        };
+        match polled {
+            Async::Ready(Some(chunk)) => {
+                self.delay = None;
+                self.received += chunk.len();
+                match self.limits.max_size {
+                    Some(max_size) if self.received > max_size => {
+                        Err(self.too_large(self.received))
+                    }
+                    _ => Ok(Async::Ready(Some(chunk))),
+                }
+            }
+            Async::Ready(None) => match self.content_length {
+                Some(length) if self.received < length => Err(self.truncated()),
+                _ => Ok(Async::Ready(None)),
+            },
+            Async::NotReady => {
+                let read_timeout = match self.limits.read_timeout {
+                    Some(read_timeout) => read_timeout,
+                    None => return Ok(Async::NotReady),
+                };
+                let delay = self
+                    .delay
+                    .get_or_insert_with(|| Delay::new(Instant::now() + read_timeout));
+                match delay.poll() {
+                    Ok(Async::NotReady) => Ok(Async::NotReady),
+                    Ok(Async::Ready(())) => Err(HttpDispatchError {
+                        message: format!(
+                            "No response body data received for {:?} after {} bytes",
+                            read_timeout, self.received
+                        ),
+                        kind: HttpDispatchErrorKind::BodyReadTimeout,
+                        source: None,
+                    }
+                    .into()),
+                    Err(err) => Err(io::Error::new(io::ErrorKind::Other, err)),
+                }
+            }
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The kind of failure described by an `HttpDispatchError`.
+pub enum HttpDispatchErrorKind {
+    /// The request didn't complete within the timeout given to `dispatch`.
+    Timeout,
+    /// No response body data arrived within the configured body read timeout.
+    BodyReadTimeout,
+    /// The response body exceeded the configured maximum body size.
+    BodyTooLarge,
+    /// The connection closed before the whole response body was received, either
+    /// short of its `Content-Length` or without the terminating chunk of a chunked body.
+    TruncatedBody,
+    /// The request was made with a client which had been closed.
+    Closed,
+    /// The request was cancelled when its closed client stopped waiting for it.
+    Cancelled,
+    /// The request body stream produced more or fewer bytes than its declared size.
+    PayloadSizeMismatch,
+    /// The request failed and wasn't retried because its body is a stream which isn't
+    /// replayable. The error it failed with is the `source` of this one.
+    PayloadNotReplayable,
+    /// The request wasn't sent because it would have gone over the `CallBudget` of its
+    /// client. The `BudgetExceeded` error is the `source` of this one.
+    BudgetExceeded,
+    /// Any other failure, such as a connection or protocol error.
+    Other,
+}
+
+#[derive(Clone, Debug)]
 /// An error produced when sending the request, such as a timeout error.
+///
+/// Connection failures, such as DNS, TLS or refused connections, keep the underlying
+/// error as their `source`.
 pub struct HttpDispatchError {
     message: String,
+    kind: HttpDispatchErrorKind,
+    source: Option<Arc<dyn Error + Send + Sync>>,
 }
 
 impl HttpDispatchError {
     /// Construct a new HttpDispatchError for testing purposes
     pub fn new(message: String) -> HttpDispatchError {
-        HttpDispatchError { message }
+        HttpDispatchError {
+            message,
+            kind: HttpDispatchErrorKind::Other,
+            source: None,
+        }
+    }
+
+    pub(crate) fn with_kind(message: String, kind: HttpDispatchErrorKind) -> HttpDispatchError {
+        HttpDispatchError {
+            message,
+            kind,
+            source: None,
+        }
+    }
+
+    /// The error of a request which would have been retried after failing with `err`, if
+    /// its payload had been replayable.
+    pub(crate) fn payload_not_replayable(err: HttpDispatchError) -> HttpDispatchError {
+        HttpDispatchError {
+            message: format!(
+                "{} (not retried, as the request payload is a stream which isn't replayable)",
+                err
+            ),
+            kind: HttpDispatchErrorKind::PayloadNotReplayable,
+            source: Some(Arc::new(err)),
+        }
+    }
+
+    /// The error of a request which wasn't sent because it went over a budget.
+    pub(crate) fn budget_exceeded(err: BudgetExceeded) -> HttpDispatchError {
+        HttpDispatchError {
+            message: err.to_string(),
+            kind: HttpDispatchErrorKind::BudgetExceeded,
+            source: Some(Arc::new(err)),
+        }
+    }
+
+    /// The kind of failure this error describes.
+    pub fn kind(&self) -> HttpDispatchErrorKind {
+        self.kind
+    }
+}
+
+impl PartialEq for HttpDispatchError {
+    fn eq(&self, other: &HttpDispatchError) -> bool {
+        self.message == other.message && self.kind == other.kind
     }
 }
 
@@ -171,6 +431,13 @@ impl Error for HttpDispatchError {
     fn description(&self) -> &str {
         &self.message
     }
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.source {
+            Some(ref source) => Some(&**source),
+            None => None,
+        }
+    }
 }
 
 impl fmt::Display for HttpDispatchError {
@@ -181,20 +448,54 @@ impl fmt::Display for HttpDispatchError {
 
 impl From<HyperError> for HttpDispatchError {
     fn from(err: HyperError) -> HttpDispatchError {
+        // errors raised while streaming the request body come back wrapped by hyper
+        let payload_error = err
+            .source()
+            .and_then(|source| source.downcast_ref::<IoError>())
+            .and_then(|io_error| io_error.get_ref())
+            .and_then(|inner| inner.downcast_ref::<HttpDispatchError>());
+        if let Some(dispatch_error) = payload_error {
+            return dispatch_error.clone();
+        }
         HttpDispatchError {
             message: err.to_string(),
+            kind: HttpDispatchErrorKind::Other,
+            source: Some(Arc::new(err)),
         }
     }
 }
 
 impl From<IoError> for HttpDispatchError {
     fn from(err: IoError) -> HttpDispatchError {
-        HttpDispatchError {
-            message: err.to_string(),
+        // errors raised while streaming a response body travel as `io::Error`s,
+        // so recover the original error to keep its kind
+        match err
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<HttpDispatchError>())
+        {
+            Some(dispatch_error) => dispatch_error.clone(),
+            None => HttpDispatchError {
+                message: err.to_string(),
+                kind: HttpDispatchErrorKind::Other,
+                source: Some(Arc::new(err)),
+            },
         }
     }
 }
 
+impl From<HttpDispatchError> for IoError {
+    fn from(err: HttpDispatchError) -> IoError {
+        let kind = match err.kind {
+            HttpDispatchErrorKind::Timeout | HttpDispatchErrorKind::BodyReadTimeout => {
+                io::ErrorKind::TimedOut
+            }
+            HttpDispatchErrorKind::TruncatedBody => io::ErrorKind::UnexpectedEof,
+            _ => io::ErrorKind::Other,
+        };
+        IoError::new(kind, err)
+    }
+}
+
 /// Trait for implementing HTTP Request/Response
 pub trait DispatchSignedRequest {
     /// The future response value.
@@ -218,7 +519,7 @@ impl<D: DispatchSignedRequest> DispatchSignedRequest for Arc<D> {
 }
 
 /// A future that will resolve to an `HttpResponse`.
-pub struct HttpClientFuture(ClientFutureInner);
+pub struct HttpClientFuture(ClientFutureInner, BodyLimits);
 
 enum ClientFutureInner {
     Hyper(HyperResponseFuture),
@@ -231,31 +532,33 @@ impl Future for HttpClientFuture {
     type Error = HttpDispatchError;
 
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let limits = self.1;
         match self.0 {
-            ClientFutureInner::Error(ref message) => Err(HttpDispatchError {
-                message: message.clone(),
-            }),
-            ClientFutureInner::Hyper(ref mut hyper_future) => {
-                Ok(hyper_future.poll()?.map(HttpResponse::from_hyper))
-            }
+            ClientFutureInner::Error(ref message) => Err(HttpDispatchError::new(message.clone())),
+            ClientFutureInner::Hyper(ref mut hyper_future) => Ok(hyper_future
+                .poll()?
+                .map(|res| HttpResponse::from_hyper(res, limits))),
             ClientFutureInner::HyperWithTimeout(ref mut deadline_future) => {
                 match deadline_future.poll() {
                     Err(deadline_err) => {
                         if deadline_err.is_elapsed() {
                             Err(HttpDispatchError {
                                 message: "Request timed out".into(),
+                                kind: HttpDispatchErrorKind::Timeout,
+                                source: None,
                             })
                         } else if deadline_err.is_inner() {
                             Err(deadline_err.into_inner().unwrap().into())
                         } else {
-                            Err(HttpDispatchError {
-                                message: format!("deadline error: {}", deadline_err),
-                            })
+                            Err(HttpDispatchError::new(format!(
+                                "deadline error: {}",
+                                deadline_err
+                            )))
                         }
                     }
                     Ok(Async::NotReady) => Ok(Async::NotReady),
                     Ok(Async::Ready(hyper_res)) => {
-                        Ok(Async::Ready(HttpResponse::from_hyper(hyper_res)))
+                        Ok(Async::Ready(HttpResponse::from_hyper(hyper_res, limits)))
                     }
                 }
             }
@@ -265,6 +568,24 @@ impl Future for HttpClientFuture {
 
 struct HttpClientPayload {
     inner: Option<SignedRequestPayload>,
+    /// Bytes of a stream sent so far, to check them against its declared size.
+    sent: usize,
+    /// Holds the payload back until the server answers `Expect: 100-continue`.
+    continue_wait: Option<ContinueWait>,
+}
+
+impl HttpClientPayload {
+    fn new(inner: Option<SignedRequestPayload>) -> Self {
+        HttpClientPayload {
+            inner,
+            sent: 0,
+            continue_wait: None,
+        }
+    }
+}
+
+fn payload_size_mismatch(message: String) -> io::Error {
+    HttpDispatchError::with_kind(message, HttpDispatchErrorKind::PayloadSizeMismatch).into()
 }
 
 impl Payload for HttpClientPayload {
@@ -272,6 +593,12 @@ impl Payload for HttpClientPayload {
     type Error = io::Error;
 
     fn poll_data(&mut self) -> Poll<Option<Self::Data>, Self::Error> {
+        if let Some(ref mut continue_wait) = self.continue_wait {
+            if continue_wait.poll().is_not_ready() {
+                return Ok(Async::NotReady);
+            }
+        }
+        self.continue_wait = None;
         match self.inner {
             None => Ok(Async::Ready(None)),
             Some(SignedRequestPayload::Buffer(ref mut buffer)) => {
@@ -281,10 +608,27 @@ impl Payload for HttpClientPayload {
                     Ok(Async::Ready(Some(io::Cursor::new(buffer.split_off(0)))))
                 }
             }
+            // hyper silently truncates a body longer than its content length, and a shorter
+            // one leaves the server waiting, so a sized stream must match its size exactly
             Some(SignedRequestPayload::Stream(ref mut stream)) => match stream.poll()? {
                 Async::NotReady => Ok(Async::NotReady),
-                Async::Ready(None) => Ok(Async::Ready(None)),
-                Async::Ready(Some(buffer)) => Ok(Async::Ready(Some(io::Cursor::new(buffer)))),
+                Async::Ready(None) => match stream.size_hint() {
+                    Some(size) if self.sent < size => Err(payload_size_mismatch(format!(
+                        "Request body stream ended after {} bytes, short of its declared size of {} bytes",
+                        self.sent, size
+                    ))),
+                    _ => Ok(Async::Ready(None)),
+                },
+                Async::Ready(Some(buffer)) => {
+                    self.sent += buffer.len();
+                    match stream.size_hint() {
+                        Some(size) if self.sent > size => Err(payload_size_mismatch(format!(
+                            "Request body stream produced at least {} bytes, more than its declared size of {} bytes",
+                            self.sent, size
+                        ))),
+                        _ => Ok(Async::Ready(Some(io::Cursor::new(buffer)))),
+                    }
+                }
             },
         }
     }
@@ -307,46 +651,168 @@ impl Payload for HttpClientPayload {
 }
 
 /// Http client for use with AWS services.
-pub struct HttpClient<C = HttpsConnector<HttpConnector>> {
-    inner: HyperClient<C, HttpClientPayload>,
+pub struct HttpClient<C = HttpsConnector<HttpConnector<CachingResolver>>> {
+    inner: HyperClient<ContinueConnector<C>, HttpClientPayload>,
+    body_limits: BodyLimits,
+    http2_only: bool,
+    vpc_endpoints: BTreeMap<String, String>,
+    expect_continue: Option<ExpectContinue>,
 }
 
 impl HttpClient {
     /// Create a tls-enabled http client.
     pub fn new() -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = match HttpsConnector::new(4) {
-            Ok(connector) => connector,
-            Err(tls_error) => {
-                return Err(TlsError {
-                    message: format!("Couldn't create NativeTlsClient: {}", tls_error),
-                })
-            }
-        };
-
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::new(4);
-
-        Ok(Self::from_connector(connector))
+        Self::new_with_config(HttpConfig::new())
     }
 
     /// Create a tls-enabled http client.
+    ///
+    /// Fails if the root certificates added to the configuration can't be read or parsed.
     pub fn new_with_config(config: HttpConfig) -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = match HttpsConnector::new(4) {
-            Ok(connector) => connector,
-            Err(tls_error) => {
-                return Err(TlsError {
-                    message: format!("Couldn't create NativeTlsClient: {}", tls_error),
-                })
+        let connector = https_connector(
+            http_connector(&config),
+            &config.root_certificates,
+            config.http_version,
+        )?;
+        Ok(Self::from_connector_with_config(connector, config))
+    }
+}
+
+/// Root certificates trusted on top of the default ones, as given to `HttpConfig`.
+#[derive(Clone, Debug)]
+enum RootCertificates {
+    Pem(Vec<u8>),
+    File(PathBuf),
+}
+
+impl RootCertificates {
+    /// Where the certificates come from, for error messages.
+    fn source(&self) -> String {
+        match *self {
+            RootCertificates::Pem(_) => "PEM bytes".to_owned(),
+            RootCertificates::File(ref path) => path.display().to_string(),
+        }
+    }
+
+    /// The PEM blocks of the certificates.
+    fn pem_blocks(&self) -> Result<Vec<String>, TlsError> {
+        let file;
+        let pem = match *self {
+            RootCertificates::Pem(ref pem) => pem,
+            RootCertificates::File(ref path) => {
+                file = fs::read(path).map_err(|err| TlsError {
+                    message: format!(
+                        "Couldn't read root certificates from {}: {}",
+                        self.source(),
+                        err
+                    ),
+                })?;
+                &file
             }
         };
+        let pem = String::from_utf8_lossy(pem);
+        let mut blocks = Vec::new();
+        let mut rest = &pem[..];
+        while let Some(start) = rest.find(PEM_CERTIFICATE_BEGIN) {
+            let block = &rest[start..];
+            let end = match block.find(PEM_CERTIFICATE_END) {
+                Some(end) => end + PEM_CERTIFICATE_END.len(),
+                None => return Err(self.invalid("unterminated certificate")),
+            };
+            blocks.push(block[..end].to_owned());
+            rest = &block[end..];
+        }
+        if blocks.is_empty() {
+            return Err(self.invalid("no certificate found"));
+        }
+        Ok(blocks)
+    }
+
+    fn invalid<E: fmt::Display>(&self, err: E) -> TlsError {
+        TlsError {
+            message: format!(
+                "Couldn't parse root certificates from {}: {}",
+                self.source(),
+                err
+            ),
+        }
+    }
+}
 
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::new(4);
+/// The connector of `HttpClient::new_with_config`, resolving hostnames as configured.
+pub(crate) fn http_connector(config: &HttpConfig) -> HttpConnector<CachingResolver> {
+    let resolver = CachingResolver::new(config.dns_resolver.clone(), config.dns_cache_ttl);
+    let mut http = HttpConnector::new_with_resolver(resolver);
+    http.enforce_http(false);
+    http.set_happy_eyeballs_timeout(config.happy_eyeballs_timeout);
+    http.set_nodelay(config.tcp_nodelay);
+    http.set_keepalive(config.tcp_keepalive);
+    http
+}
 
-        Ok(Self::from_connector_with_config(connector, config))
+const PEM_CERTIFICATE_BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+const PEM_CERTIFICATE_END: &str = "-----END CERTIFICATE-----";
+
+#[cfg(feature = "native-tls")]
+fn https_connector(
+    http: HttpConnector<CachingResolver>,
+    root_certificates: &[RootCertificates],
+    http_version: HttpVersionPolicy,
+) -> Result<HttpsConnector<HttpConnector<CachingResolver>>, TlsError> {
+    use native_tls_crate::{Certificate, TlsConnector};
+
+    let mut builder = TlsConnector::builder();
+    // the connector can't tell hyper which protocol was negotiated, so HTTP/2 is only
+    // offered when it is the one spoken
+    if http_version == HttpVersionPolicy::RequireHttp2 {
+        builder.request_alpns(&["h2"]);
+    }
+    for certificates in root_certificates {
+        for block in certificates.pem_blocks()? {
+            let certificate =
+                Certificate::from_pem(block.as_bytes()).map_err(|err| certificates.invalid(err))?;
+            builder.add_root_certificate(certificate);
+        }
+    }
+    let tls = builder.build().map_err(|err| TlsError {
+        message: format!("Couldn't create NativeTlsClient: {}", err),
+    })?;
+
+    Ok(HttpsConnector::from((http, tls)))
+}
+
+#[cfg(feature = "rustls")]
+fn https_connector(
+    http: HttpConnector<CachingResolver>,
+    root_certificates: &[RootCertificates],
+    http_version: HttpVersionPolicy,
+) -> Result<HttpsConnector<HttpConnector<CachingResolver>>, TlsError> {
+    use rustls_crate::internal::pemfile;
+    use rustls_crate::ClientConfig;
+
+    // the defaults of `HttpsConnector::new`
+    let mut tls = ClientConfig::new();
+    tls.root_store
+        .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+    tls.ct_logs = Some(&ct_logs::LOGS);
+    tls.alpn_protocols = match http_version {
+        HttpVersionPolicy::Http1Only => Vec::new(),
+        HttpVersionPolicy::PreferHttp2 => vec![b"h2".to_vec(), b"http/1.1".to_vec()],
+        HttpVersionPolicy::RequireHttp2 => vec![b"h2".to_vec()],
+    };
+    for certificates in root_certificates {
+        for block in certificates.pem_blocks()? {
+            let ders = pemfile::certs(&mut block.as_bytes())
+                .map_err(|()| certificates.invalid("invalid PEM"))?;
+            for der in ders {
+                tls.root_store
+                    .add(&der)
+                    .map_err(|err| certificates.invalid(format!("{:?}", err)))?;
+            }
+        }
     }
+
+    Ok(HttpsConnector::from((http, tls)))
 }
 
 impl<C> HttpClient<C>
@@ -356,8 +822,14 @@ where
 {
     /// Allows for a custom connector to be used with the HttpClient
     pub fn from_connector(connector: C) -> Self {
-        let inner = HyperClient::builder().build(connector);
-        HttpClient { inner }
+        let inner = HyperClient::builder().build(ContinueConnector(connector));
+        HttpClient {
+            inner,
+            body_limits: BodyLimits::default(),
+            http2_only: false,
+            vpc_endpoints: BTreeMap::new(),
+            expect_continue: Some(ExpectContinue::default()),
+        }
     }
 
     /// Allows for a custom connector to be used with the HttpClient
@@ -367,21 +839,83 @@ where
         config
             .read_buf_size
             .map(|sz| builder.http1_read_buf_exact_size(sz));
-        let inner = builder.build(connector);
+        let http2_only = config.http_version == HttpVersionPolicy::RequireHttp2;
+        builder.http2_only(http2_only);
+        let inner = builder.build(ContinueConnector(connector));
+        // the answers to `Expect: 100-continue` are only watched for on HTTP/1.1
+        let expect_continue = match config.http_version {
+            HttpVersionPolicy::Http1Only => {
+                config
+                    .expect_continue_threshold
+                    .map(|threshold| ExpectContinue {
+                        threshold,
+                        timeout: config.expect_continue_timeout,
+                    })
+            }
+            _ => None,
+        };
 
-        HttpClient { inner }
+        HttpClient {
+            inner,
+            body_limits: BodyLimits {
+                read_timeout: config.body_read_timeout,
+                max_size: config.max_body_size,
+            },
+            http2_only,
+            vpc_endpoints: config.vpc_endpoints,
+            expect_continue,
+        }
     }
 
     /// Alows for a custom builder and connector to be used with the HttpClient
     pub fn from_builder(builder: HyperBuilder, connector: C) -> Self {
-        let inner = builder.build(connector);
-        HttpClient { inner }
+        let inner = builder.build(ContinueConnector(connector));
+        HttpClient {
+            inner,
+            body_limits: BodyLimits::default(),
+            http2_only: false,
+            vpc_endpoints: BTreeMap::new(),
+            expect_continue: Some(ExpectContinue::default()),
+        }
     }
 }
 
+/// The versions of HTTP an `HttpClient` speaks to endpoints.
+///
+/// Over TLS, HTTP/2 is negotiated through ALPN by clients created with
+/// `HttpClient::new_with_config`, while clients created from a custom connector speak
+/// what their connector negotiates. Among AWS endpoints, those of Kinesis support HTTP/2,
+/// which `SubscribeToShard` needs, and those of Transcribe streaming only speak HTTP/2.
+/// Endpoints which don't support it are spoken to in HTTP/1.1 when it is only preferred.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HttpVersionPolicy {
+    /// Only speak HTTP/1.1, the default.
+    Http1Only,
+    /// Speak HTTP/2 to endpoints agreeing to it through ALPN, and HTTP/1.1 otherwise.
+    ///
+    /// With the `native-tls` feature, whose connector can't tell which protocol was
+    /// negotiated, and over plain TCP, this speaks HTTP/1.1.
+    PreferHttp2,
+    /// Only speak HTTP/2, offering nothing else through ALPN and speaking it with prior
+    /// knowledge over plain TCP, so that requests to endpoints without it fail.
+    RequireHttp2,
+}
+
 /// Configuration options for the HTTP Client
 pub struct HttpConfig {
     read_buf_size: Option<usize>,
+    body_read_timeout: Option<Duration>,
+    max_body_size: Option<usize>,
+    http_version: HttpVersionPolicy,
+    root_certificates: Vec<RootCertificates>,
+    vpc_endpoints: BTreeMap<String, String>,
+    expect_continue_threshold: Option<u64>,
+    expect_continue_timeout: Duration,
+    dns_resolver: Arc<dyn DnsResolver>,
+    dns_cache_ttl: Option<Duration>,
+    happy_eyeballs_timeout: Option<Duration>,
+    tcp_nodelay: bool,
+    tcp_keepalive: Option<Duration>,
 }
 
 impl HttpConfig {
@@ -389,6 +923,18 @@ impl HttpConfig {
     pub fn new() -> HttpConfig {
         HttpConfig {
             read_buf_size: None,
+            body_read_timeout: None,
+            max_body_size: None,
+            http_version: HttpVersionPolicy::Http1Only,
+            root_certificates: Vec::new(),
+            vpc_endpoints: BTreeMap::new(),
+            expect_continue_threshold: Some(expect_continue::DEFAULT_THRESHOLD),
+            expect_continue_timeout: expect_continue::DEFAULT_TIMEOUT,
+            dns_resolver: Arc::new(SystemResolver::new(4)),
+            dns_cache_ttl: None,
+            happy_eyeballs_timeout: Some(dns::DEFAULT_HAPPY_EYEBALLS_TIMEOUT),
+            tcp_nodelay: true,
+            tcp_keepalive: None,
         }
     }
     /// Sets the size of the read buffer for inbound data
@@ -397,6 +943,114 @@ impl HttpConfig {
     pub fn read_buf_size(&mut self, sz: usize) {
         self.read_buf_size = Some(sz);
     }
+    /// Sets the maximum time to wait for the next chunk of a response body.
+    /// Reading the body fails with `HttpDispatchErrorKind::BodyReadTimeout`
+    /// when the server stalls for longer than this.
+    pub fn body_read_timeout(&mut self, timeout: Duration) {
+        self.body_read_timeout = Some(timeout);
+    }
+    /// Sets the maximum size in bytes of a response body.
+    /// Reading the body fails with `HttpDispatchErrorKind::BodyTooLarge`
+    /// as soon as it's known to be larger than this.
+    pub fn max_body_size(&mut self, sz: usize) {
+        self.max_body_size = Some(sz);
+    }
+    /// Only speak HTTP/2 (with prior knowledge) to endpoints, as required by
+    /// some streaming APIs such as Kinesis enhanced fan-out.
+    /// Same as `http_version(HttpVersionPolicy::RequireHttp2)`, or `Http1Only` if false.
+    pub fn http2_only(&mut self, value: bool) {
+        self.http_version = if value {
+            HttpVersionPolicy::RequireHttp2
+        } else {
+            HttpVersionPolicy::Http1Only
+        };
+    }
+    /// Sets the versions of HTTP spoken to endpoints, HTTP/1.1 only by default.
+    /// Multiplexing many concurrent calls over a single HTTP/2 connection saves
+    /// opening a connection for each.
+    pub fn http_version(&mut self, policy: HttpVersionPolicy) {
+        self.http_version = policy;
+    }
+    /// Trusts the root certificates of a PEM bundle on top of the default roots,
+    /// such as the CA of a TLS-intercepting proxy.
+    /// `HttpClient::new_with_config` fails if they can't be parsed; clients created
+    /// from a custom connector ignore them.
+    pub fn add_root_certificates_pem(&mut self, pem: &[u8]) {
+        self.root_certificates
+            .push(RootCertificates::Pem(pem.to_vec()));
+    }
+    /// Trusts the root certificates of the PEM bundle at `path` on top of the default
+    /// roots. `HttpClient::new_with_config` fails if it can't be read or parsed.
+    pub fn add_root_certificates_file<P: Into<PathBuf>>(&mut self, path: P) {
+        self.root_certificates
+            .push(RootCertificates::File(path.into()));
+    }
+    /// Sends the requests of `service`, named as in its signatures such as
+    /// `execute-api` or `sqs`, to the `hostname` of a VPC interface endpoint, such as
+    /// `vpce-0123-abcd.execute-api.us-east-1.vpce.amazonaws.com`, optionally with a port.
+    ///
+    /// Requests are still signed for, and carry the `Host` header of, the standard
+    /// endpoint of their service and region, with the standard signing scope; only the
+    /// connection goes to the VPC endpoint. To sign for the endpoint's own name
+    /// instead, use a `Region::Custom` with it as the endpoint.
+    ///
+    /// VPC endpoints are spoken to in HTTP/1.1, which sends the signed host apart from
+    /// the URI: with `HttpVersionPolicy::RequireHttp2`, requests to them fail.
+    pub fn vpc_endpoint<S: Into<String>, H: Into<String>>(&mut self, service: S, hostname: H) {
+        self.vpc_endpoints.insert(service.into(), hostname.into());
+    }
+    /// Sends request bodies of at least `threshold` bytes with an `Expect: 100-continue`
+    /// header, holding them back until the server agrees to receive them, so that the
+    /// body of a request the server rejects from its head, such as for a wrong region,
+    /// a bad signature or a missing bucket, isn't sent. Bodies are sent anyway once the
+    /// `expect_continue_timeout` has passed, for servers which don't answer.
+    ///
+    /// Defaults to 1 MiB; `None` sends every body right away. Only the bodies of known
+    /// size of HTTP/1.1 requests are held back, so `HttpVersionPolicy::Http1Only` is
+    /// required. The `Expect` header isn't signed.
+    pub fn expect_continue(&mut self, threshold: Option<u64>) {
+        self.expect_continue_threshold = threshold;
+    }
+    /// Sets how long a body sent with `Expect: 100-continue` is held back for a server
+    /// which doesn't answer, 1 second by default.
+    pub fn expect_continue_timeout(&mut self, timeout: Duration) {
+        self.expect_continue_timeout = timeout;
+    }
+    /// Looks the hostnames of endpoints up with `resolver` instead of calling
+    /// `getaddrinfo` on a pool of 4 threads, which lookups wait for under load.
+    /// Clients created from a custom connector ignore it.
+    pub fn dns_resolver<R: DnsResolver + 'static>(&mut self, resolver: R) {
+        self.dns_resolver = Arc::new(resolver);
+    }
+    /// Keeps the addresses hostnames resolved to for `ttl` at most, or for the TTL of
+    /// their answer if the resolver tells a shorter one, so that endpoints whose
+    /// addresses change, such as during the failover of an S3 website, are connected to
+    /// at their new addresses within `ttl`. Failed lookups aren't kept.
+    ///
+    /// `None`, the default, looks hostnames up for every new connection.
+    pub fn dns_cache_ttl(&mut self, ttl: Option<Duration>) {
+        self.dns_cache_ttl = ttl;
+    }
+    /// Sets how long connecting to the addresses of the family a hostname resolved to
+    /// first, usually IPv6, may take before the addresses of the other family are
+    /// raced against them, 300 milliseconds by default. `None` tries the addresses one
+    /// after the other, which waits for unreachable ones to time out.
+    pub fn happy_eyeballs_timeout(&mut self, timeout: Option<Duration>) {
+        self.happy_eyeballs_timeout = timeout;
+    }
+    /// Sets `TCP_NODELAY` on connections, sending small requests right away instead of
+    /// holding them back by Nagle's algorithm to be sent with more data. On by default.
+    /// Clients created from a custom connector ignore it.
+    pub fn tcp_nodelay(&mut self, nodelay: bool) {
+        self.tcp_nodelay = nodelay;
+    }
+    /// Sends TCP keepalive probes on connections idle for `idle`, so that pooled
+    /// connections to dead peers, or dropped by a NAT gateway, are found broken rather
+    /// than used. `None`, the default, leaves keepalive off. Clients created from a
+    /// custom connector ignore it.
+    pub fn tcp_keepalive(&mut self, idle: Option<Duration>) {
+        self.tcp_keepalive = idle;
+    }
 }
 
 impl Default for HttpConfig {
@@ -421,53 +1075,84 @@ where
             "GET" => Method::GET,
             "HEAD" => Method::HEAD,
             v => {
-                return HttpClientFuture(ClientFutureInner::Error(format!(
-                    "Unsupported HTTP verb {}",
-                    v
-                )))
+                return HttpClientFuture(
+                    ClientFutureInner::Error(format!("Unsupported HTTP verb {}", v)),
+                    self.body_limits,
+                )
             }
         };
 
         // translate the headers map to a format Hyper likes
         let mut hyper_headers = HeaderMap::new();
         for h in request.headers().iter() {
+            // the host is set from the signed hostname below
+            if h.0 == "host" {
+                continue;
+            }
             let header_name = match h.0.parse::<HeaderName>() {
                 Ok(name) => name,
                 Err(err) => {
-                    return HttpClientFuture(ClientFutureInner::Error(format!(
-                        "error parsing header name: {}",
-                        err
-                    )));
+                    return HttpClientFuture(
+                        ClientFutureInner::Error(format!("error parsing header name: {}", err)),
+                        self.body_limits,
+                    );
                 }
             };
             for v in h.1.iter() {
                 let header_value = match HeaderValue::from_bytes(v) {
                     Ok(value) => value,
                     Err(err) => {
-                        return HttpClientFuture(ClientFutureInner::Error(format!(
-                            "error parsing header value: {}",
-                            err
-                        )));
+                        return HttpClientFuture(
+                            ClientFutureInner::Error(format!(
+                                "error parsing header value: {}",
+                                err
+                            )),
+                            self.body_limits,
+                        );
                     }
                 };
                 hyper_headers.append(&header_name, header_value);
             }
         }
 
+        let vpc_endpoint = self.vpc_endpoints.get(&request.service);
+        if vpc_endpoint.is_some() && self.http2_only {
+            return HttpClientFuture(
+                ClientFutureInner::Error(format!(
+                    "can't send {} requests to a VPC endpoint over HTTP/2",
+                    request.service
+                )),
+                self.body_limits,
+            );
+        }
+
+        // The signature covers `request.hostname()` as the host, which is also the
+        // authority of the URI unless the request goes to a VPC endpoint. HTTP/2
+        // sends that authority as the `:authority` pseudo-header, where a `host`
+        // header would be redundant.
+        if !self.http2_only {
+            match HeaderValue::from_str(&request.hostname()) {
+                Ok(host) => {
+                    hyper_headers.insert(http::header::HOST, host);
+                }
+                Err(err) => {
+                    return HttpClientFuture(
+                        ClientFutureInner::Error(format!("error parsing host: {}", err)),
+                        self.body_limits,
+                    );
+                }
+            }
+        }
+
         // Add a default user-agent header if one is not already present.
         if !hyper_headers.contains_key("user-agent") {
             hyper_headers.insert("user-agent", DEFAULT_USER_AGENT.parse().unwrap());
         }
 
-        let mut final_uri = format!(
-            "{}://{}{}",
-            request.scheme(),
-            request.hostname(),
-            request.canonical_path()
-        );
-        if !request.canonical_query_string().is_empty() {
-            final_uri = final_uri + &format!("?{}", request.canonical_query_string());
-        }
+        let final_uri = match vpc_endpoint {
+            Some(hostname) => request.url_with_hostname(hostname),
+            None => request.url(),
+        };
 
         if log_enabled!(Debug) {
             let payload = match request.payload {
@@ -494,16 +1179,23 @@ where
         http_request_builder.method(hyper_method);
         http_request_builder.uri(final_uri);
 
-        let body = HttpClientPayload {
-            inner: request.payload,
-        };
+        let mut body = HttpClientPayload::new(request.payload);
+        if let Some(expect_continue) = self.expect_continue {
+            let held_back = body
+                .content_length()
+                .map_or(false, |len| len > 0 && len >= expect_continue.threshold);
+            if held_back && !hyper_headers.contains_key(EXPECT) {
+                hyper_headers.insert(EXPECT, HeaderValue::from_static("100-continue"));
+                body.continue_wait = Some(ContinueWait::new(expect_continue.timeout));
+            }
+        }
         let mut http_request = match http_request_builder.body(body) {
             Ok(request) => request,
             Err(err) => {
-                return HttpClientFuture(ClientFutureInner::Error(format!(
-                    "error building request: {}",
-                    err
-                )));
+                return HttpClientFuture(
+                    ClientFutureInner::Error(format!("error building request: {}", err)),
+                    self.body_limits,
+                );
             }
         };
 
@@ -517,7 +1209,7 @@ where
             }
         };
 
-        HttpClientFuture(inner)
+        HttpClientFuture(inner, self.body_limits)
     }
 }
 
@@ -603,6 +1295,857 @@ mod tests {
         assert_eq!("localhost", request.hostname());
     }
 
+    /// Serves a single response with the given head, then writes `body` and
+    /// keeps the connection open without sending anything else.
+    fn stalling_server(head: &'static str, body: Vec<u8>) -> Region {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        ::std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut buf = [0; 4096];
+            let _ = socket.read(&mut buf);
+            let _ = socket.write_all(head.as_bytes());
+            let _ = socket.write_all(&body);
+            let _ = socket.flush();
+            ::std::thread::sleep(Duration::from_secs(5));
+        });
+        Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint,
+        }
+    }
+
+    /// Serves a single response with the given head, then writes `body` and
+    /// closes the connection.
+    fn closing_server(head: &'static str, body: &'static [u8]) -> Region {
+        use std::io::{Read, Write};
+        use std::net::{Shutdown, TcpListener};
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        ::std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut buf = [0; 4096];
+            let _ = socket.read(&mut buf);
+            let _ = socket.write_all(head.as_bytes());
+            let _ = socket.write_all(body);
+            let _ = socket.flush();
+            let _ = socket.shutdown(Shutdown::Both);
+        });
+        Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint,
+        }
+    }
+
+    fn dispatch_and_buffer(
+        config: HttpConfig,
+        region: &Region,
+    ) -> Result<BufferedHttpResponse, HttpDispatchError> {
+        dispatch_method_and_buffer("GET", config, region)
+    }
+
+    fn dispatch_method_and_buffer(
+        method: &str,
+        config: HttpConfig,
+        region: &Region,
+    ) -> Result<BufferedHttpResponse, HttpDispatchError> {
+        let client = HttpClient::from_connector_with_config(HttpConnector::new(1), config);
+        let request = SignedRequest::new(method, "s3", region, "/");
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(
+            client
+                .dispatch(request, None)
+                .and_then(HttpResponse::buffer),
+        )
+    }
+
+    fn connect(config: &HttpConfig) -> tokio::net::TcpStream {
+        use hyper::client::connect::{Connect, Destination};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let uri = format!("http://{}/", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        let destination = Destination::try_from_uri(uri).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let (stream, _) = runtime
+            .block_on(Connect::connect(&http_connector(config), destination))
+            .unwrap();
+        stream
+    }
+
+    #[test]
+    fn connections_have_the_configured_tcp_options() {
+        let stream = connect(&HttpConfig::new());
+        assert!(stream.nodelay().unwrap());
+        assert_eq!(stream.keepalive().unwrap(), None);
+
+        let mut config = HttpConfig::new();
+        config.tcp_nodelay(false);
+        config.tcp_keepalive(Some(Duration::from_secs(60)));
+        let stream = connect(&config);
+        assert!(!stream.nodelay().unwrap());
+        assert_eq!(stream.keepalive().unwrap(), Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn body_read_timeout_fails_stalled_body() {
+        let region = stalling_server(
+            "HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n",
+            vec![b'a'; 10],
+        );
+        let mut config = HttpConfig::new();
+        config.body_read_timeout(Duration::from_millis(200));
+
+        let err = dispatch_and_buffer(config, &region).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::BodyReadTimeout);
+        assert!(err.to_string().contains("after 10 bytes"), "{}", err);
+    }
+
+    #[test]
+    fn max_body_size_fails_oversized_body() {
+        let region = stalling_server(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n",
+            b"400\r\n"
+                .iter()
+                .cloned()
+                .chain(vec![b'a'; 1024])
+                .chain(b"\r\n0\r\n\r\n".iter().cloned())
+                .collect(),
+        );
+        let mut config = HttpConfig::new();
+        config.max_body_size(512);
+
+        let err = dispatch_and_buffer(config, &region).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::BodyTooLarge);
+    }
+
+    #[test]
+    fn max_body_size_checks_content_length_up_front() {
+        let region = stalling_server(
+            "HTTP/1.1 200 OK\r\nContent-Length: 4096\r\n\r\n",
+            Vec::new(),
+        );
+        let mut config = HttpConfig::new();
+        config.max_body_size(512);
+
+        let err = dispatch_and_buffer(config, &region).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::BodyTooLarge);
+    }
+
+    #[test]
+    fn body_within_limits_is_read() {
+        let region = stalling_server(
+            "HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n",
+            b"hello".to_vec(),
+        );
+        let mut config = HttpConfig::new();
+        config.body_read_timeout(Duration::from_secs(5));
+        config.max_body_size(5);
+
+        let response = dispatch_and_buffer(config, &region).unwrap();
+        assert_eq!(response.body_as_str(), "hello");
+    }
+
+    #[test]
+    fn body_shorter_than_content_length_is_truncated() {
+        let region = closing_server(
+            "HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n",
+            &[b'a'; 10],
+        );
+
+        let err = dispatch_and_buffer(HttpConfig::new(), &region).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::TruncatedBody);
+        assert!(err.to_string().contains("after 10 of 100 bytes"), "{}", err);
+    }
+
+    #[test]
+    fn missing_body_is_truncated() {
+        let region = closing_server("HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n", b"");
+
+        let err = dispatch_and_buffer(HttpConfig::new(), &region).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::TruncatedBody);
+        assert!(err.to_string().contains("after 0 of 5 bytes"), "{}", err);
+    }
+
+    #[test]
+    fn truncated_body_is_detected_with_limits() {
+        let region = closing_server(
+            "HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n",
+            &[b'a'; 10],
+        );
+        let mut config = HttpConfig::new();
+        config.body_read_timeout(Duration::from_secs(5));
+        config.max_body_size(1024);
+
+        let err = dispatch_and_buffer(config, &region).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::TruncatedBody);
+    }
+
+    #[test]
+    fn chunked_body_without_terminating_chunk_is_truncated() {
+        let region = closing_server(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n",
+            b"5\r\nhello\r\n",
+        );
+
+        let err = dispatch_and_buffer(HttpConfig::new(), &region).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::TruncatedBody);
+        assert!(
+            err.to_string()
+                .contains("after 5 bytes without its terminating chunk"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn chunked_body_ending_mid_chunk_is_truncated() {
+        let region = closing_server(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n",
+            b"a\r\nhello",
+        );
+
+        let err = dispatch_and_buffer(HttpConfig::new(), &region).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::TruncatedBody);
+    }
+
+    #[test]
+    fn complete_bodies_of_closed_connections_are_read() {
+        let region = closing_server("HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n", b"hello");
+        let response = dispatch_and_buffer(HttpConfig::new(), &region).unwrap();
+        assert_eq!(response.body_as_str(), "hello");
+
+        let region = closing_server(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n",
+            b"5\r\nhello\r\n0\r\n\r\n",
+        );
+        let response = dispatch_and_buffer(HttpConfig::new(), &region).unwrap();
+        assert_eq!(response.body_as_str(), "hello");
+    }
+
+    #[test]
+    fn head_response_content_length_is_not_enforced() {
+        let region = closing_server("HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n", b"");
+        let mut config = HttpConfig::new();
+        config.max_body_size(10);
+
+        let response = dispatch_method_and_buffer("HEAD", config, &region).unwrap();
+        assert_eq!(response.body_as_str(), "");
+    }
+
+    #[test]
+    fn truncated_body_is_an_unexpected_eof() {
+        let io_error = IoError::from(HttpDispatchError::with_kind(
+            "Response body ended after 10 of 100 bytes".to_owned(),
+            HttpDispatchErrorKind::TruncatedBody,
+        ));
+        assert_eq!(io_error.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            HttpDispatchError::from(io_error).kind(),
+            HttpDispatchErrorKind::TruncatedBody
+        );
+    }
+
+    /// Dispatches a signed request with `http_version` to a local server, only
+    /// speaking HTTP/2 if `http2_only`, which answers with the protocol version,
+    /// `host` header and URI authority it received, and returns them along with
+    /// the host that was signed.
+    fn dispatch_to_echo_server(
+        http2_only: bool,
+        http_version: HttpVersionPolicy,
+    ) -> (String, String) {
+        use crate::credential::AwsCredentials;
+        use hyper::service::service_fn_ok;
+        use hyper::Server;
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = Server::bind(&([127, 0, 0, 1], 0).into())
+            .http2_only(http2_only)
+            .serve(|| {
+                service_fn_ok(|req: HyperRequest<Body>| {
+                    let host = req
+                        .headers()
+                        .get_all("host")
+                        .iter()
+                        .map(|v| v.to_str().unwrap())
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    let authority = req
+                        .uri()
+                        .authority_part()
+                        .map(|a| a.as_str().to_owned())
+                        .unwrap_or_default();
+                    HyperResponse::new(Body::from(format!(
+                        "{:?} host={} authority={}",
+                        req.version(),
+                        host,
+                        authority
+                    )))
+                })
+            });
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: format!("http://{}", server.local_addr()),
+        };
+        runtime.spawn(server.map_err(|_| ()));
+
+        let mut config = HttpConfig::new();
+        config.http_version(http_version);
+        let client = HttpClient::from_connector_with_config(HttpConnector::new(1), config);
+        let mut request = SignedRequest::new("GET", "s3", &region, "/");
+        // a stale host, e.g. left over from before a redirect, must not be sent
+        request.add_header("Host", "stale.example.com");
+        request.sign(&AwsCredentials::new("key", "secret", None, None));
+        let signed_host = String::from_utf8(request.headers()["host"][0].clone()).unwrap();
+
+        let response = runtime
+            .block_on(
+                client
+                    .dispatch(request, None)
+                    .and_then(HttpResponse::buffer),
+            )
+            .unwrap();
+        (response.body_as_str().to_owned(), signed_host)
+    }
+
+    #[test]
+    fn http1_sends_the_signed_host() {
+        let (received, signed_host) = dispatch_to_echo_server(false, HttpVersionPolicy::Http1Only);
+        assert!(signed_host.starts_with("127.0.0.1:"));
+        assert_eq!(
+            received,
+            format!("HTTP/1.1 host={} authority=", signed_host)
+        );
+    }
+
+    #[test]
+    fn http2_sends_the_signed_host_as_authority() {
+        let (received, signed_host) =
+            dispatch_to_echo_server(true, HttpVersionPolicy::RequireHttp2);
+        assert!(signed_host.starts_with("127.0.0.1:"));
+        assert_eq!(
+            received,
+            format!("HTTP/2.0 host= authority={}", signed_host)
+        );
+    }
+
+    #[test]
+    fn preferring_http2_falls_back_to_http1() {
+        let (received, signed_host) =
+            dispatch_to_echo_server(false, HttpVersionPolicy::PreferHttp2);
+        assert_eq!(
+            received,
+            format!("HTTP/1.1 host={} authority=", signed_host)
+        );
+    }
+
+    #[test]
+    fn http2_only_requires_http2() {
+        let mut config = HttpConfig::new();
+        config.http2_only(true);
+        assert_eq!(config.http_version, HttpVersionPolicy::RequireHttp2);
+        config.http2_only(false);
+        assert_eq!(config.http_version, HttpVersionPolicy::Http1Only);
+    }
+
+    #[test]
+    fn tls_clients_are_created_for_every_http_version() {
+        for &http_version in &[
+            HttpVersionPolicy::Http1Only,
+            HttpVersionPolicy::PreferHttp2,
+            HttpVersionPolicy::RequireHttp2,
+        ] {
+            let mut config = HttpConfig::new();
+            config.http_version(http_version);
+            assert!(HttpClient::new_with_config(config).is_ok());
+        }
+    }
+
+    /// Signs a GET request to `service` in `region`, over plain HTTP, and dispatches it
+    /// with a client configured by `configure` with the address of a local server,
+    /// which answers with the `host` header and URI it received. Returns its answer
+    /// and the signed request's `authorization` header.
+    fn dispatch_to_vpc_endpoint_server<F>(
+        service: &str,
+        region: Option<Region>,
+        configure: F,
+    ) -> Result<(String, String), HttpDispatchError>
+    where
+        F: FnOnce(&mut HttpConfig, String),
+    {
+        use crate::credential::AwsCredentials;
+        use hyper::service::service_fn_ok;
+        use hyper::Server;
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(|| {
+            service_fn_ok(|req: HyperRequest<Body>| {
+                let host = req
+                    .headers()
+                    .get_all("host")
+                    .iter()
+                    .map(|v| v.to_str().unwrap())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                HyperResponse::new(Body::from(format!("host={} uri={}", host, req.uri())))
+            })
+        });
+        let address = server.local_addr().to_string();
+        runtime.spawn(server.map_err(|_| ()));
+
+        let mut config = HttpConfig::new();
+        configure(&mut config, address.clone());
+        let client = HttpClient::from_connector_with_config(HttpConnector::new(1), config);
+        let region = region.unwrap_or_else(|| Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: format!("http://{}", address),
+        });
+        let mut request = SignedRequest::new("GET", service, &region, "/bucket/key");
+        request.scheme = Some("http".to_owned());
+        request.add_param("list-type", "2");
+        request.sign(&AwsCredentials::new("key", "secret", None, None));
+        let authorization =
+            String::from_utf8(request.headers()["authorization"][0].clone()).unwrap();
+
+        runtime
+            .block_on(
+                client
+                    .dispatch(request, None)
+                    .and_then(HttpResponse::buffer),
+            )
+            .map(|response| (response.body_as_str().to_owned(), authorization))
+    }
+
+    #[test]
+    fn vpc_endpoint_is_sent_requests_signed_for_the_standard_host() {
+        let (received, authorization) =
+            dispatch_to_vpc_endpoint_server("s3", Some(Region::UsEast1), |config, address| {
+                config.vpc_endpoint("s3", address)
+            })
+            .unwrap();
+        assert_eq!(
+            received,
+            "host=s3.amazonaws.com uri=/bucket/key?list-type=2"
+        );
+        assert!(
+            authorization.contains("/us-east-1/s3/aws4_request"),
+            "{}",
+            authorization
+        );
+    }
+
+    #[test]
+    fn vpc_endpoint_keeps_the_signing_region_of_global_services() {
+        let (received, authorization) =
+            dispatch_to_vpc_endpoint_server("iam", Some(Region::EuWest1), |config, address| {
+                config.vpc_endpoint("iam", address)
+            })
+            .unwrap();
+        assert_eq!(
+            received,
+            "host=iam.amazonaws.com uri=/bucket/key?list-type=2"
+        );
+        assert!(
+            authorization.contains("/us-east-1/iam/aws4_request"),
+            "{}",
+            authorization
+        );
+    }
+
+    #[test]
+    fn vpc_endpoint_only_applies_to_its_service() {
+        let (received, _) = dispatch_to_vpc_endpoint_server("s3", None, |config, _| {
+            config.vpc_endpoint("sqs", "vpce-0123.sqs.us-east-1.vpce.amazonaws.com")
+        })
+        .unwrap();
+        assert!(received.starts_with("host=127.0.0.1:"), "{}", received);
+    }
+
+    #[test]
+    fn vpc_endpoint_over_http2_only_fails() {
+        let err =
+            dispatch_to_vpc_endpoint_server("s3", Some(Region::UsEast1), |config, address| {
+                config.http_version(HttpVersionPolicy::RequireHttp2);
+                config.vpc_endpoint("s3", address);
+            })
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "can't send s3 requests to a VPC endpoint over HTTP/2"
+        );
+    }
+
+    /// Dispatches a PUT of `payload` to a local server which answers with the
+    /// `content-length` and `transfer-encoding` headers and body size it received.
+    fn dispatch_upload(payload: ByteStream) -> Result<String, HttpDispatchError> {
+        use hyper::service::service_fn;
+        use hyper::Server;
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(|| {
+            service_fn(|req: HyperRequest<Body>| {
+                let header = |name| {
+                    req.headers()
+                        .get(name)
+                        .map(|v| v.to_str().unwrap().to_owned())
+                };
+                let content_length = header("content-length");
+                let transfer_encoding = header("transfer-encoding");
+                req.into_body().concat2().map(move |body| {
+                    HyperResponse::new(Body::from(format!(
+                        "content-length={:?} transfer-encoding={:?} received={}",
+                        content_length,
+                        transfer_encoding,
+                        body.len()
+                    )))
+                })
+            })
+        });
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: format!("http://{}", server.local_addr()),
+        };
+        runtime.spawn(server.map_err(|_| ()));
+
+        let client =
+            HttpClient::from_connector_with_config(HttpConnector::new(1), HttpConfig::new());
+        let mut request = SignedRequest::new("PUT", "s3", &region, "/bucket/key");
+        request.set_payload_stream(payload);
+        request.complement();
+
+        runtime
+            .block_on(
+                client
+                    .dispatch(request, None)
+                    .and_then(HttpResponse::buffer),
+            )
+            .map(|response| response.body_as_str().to_owned())
+    }
+
+    fn chunked_stream() -> ByteStream {
+        let chunks = vec![Bytes::from_static(b"1234"), Bytes::from_static(b"5678")];
+        ByteStream::new(futures::stream::iter_ok(chunks))
+    }
+
+    #[test]
+    fn sized_stream_is_sent_with_content_length() {
+        let received = dispatch_upload(chunked_stream().with_size(8)).unwrap();
+        assert_eq!(
+            received,
+            "content-length=Some(\"8\") transfer-encoding=None received=8"
+        );
+    }
+
+    #[test]
+    fn unsized_stream_is_sent_chunked() {
+        let received = dispatch_upload(chunked_stream()).unwrap();
+        assert_eq!(
+            received,
+            "content-length=None transfer-encoding=Some(\"chunked\") received=8"
+        );
+    }
+
+    #[test]
+    fn stream_shorter_than_its_size_fails() {
+        let err = dispatch_upload(chunked_stream().with_size(12)).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::PayloadSizeMismatch);
+        assert!(err.to_string().contains("ended after 8 bytes"), "{}", err);
+    }
+
+    #[test]
+    fn stream_longer_than_its_size_fails() {
+        let err = dispatch_upload(chunked_stream().with_size(6)).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::PayloadSizeMismatch);
+        assert!(err.to_string().contains("at least 8 bytes"), "{}", err);
+    }
+
+    /// Dispatches a PUT of `payload` with `config` to a hyper server, which answers
+    /// `Expect: 100-continue` as it reads the body, and answers with the `expect` header
+    /// and body size it received, along with how long the request took.
+    fn dispatch_expecting_continue(
+        config: HttpConfig,
+        payload: &'static [u8],
+    ) -> (String, Duration) {
+        use hyper::service::service_fn;
+        use hyper::Server;
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(|| {
+            service_fn(|req: HyperRequest<Body>| {
+                let expect = req
+                    .headers()
+                    .get("expect")
+                    .map(|v| v.to_str().unwrap().to_owned());
+                req.into_body().concat2().map(move |body| {
+                    HyperResponse::new(Body::from(format!(
+                        "expect={:?} received={}",
+                        expect,
+                        body.len()
+                    )))
+                })
+            })
+        });
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: format!("http://{}", server.local_addr()),
+        };
+        runtime.spawn(server.map_err(|_| ()));
+
+        let client = HttpClient::from_connector_with_config(HttpConnector::new(1), config);
+        let mut request = SignedRequest::new("PUT", "s3", &region, "/bucket/key");
+        request.set_payload(Some(payload));
+        request.complement();
+
+        let started = Instant::now();
+        let response = runtime
+            .block_on(
+                client
+                    .dispatch(request, None)
+                    .and_then(HttpResponse::buffer),
+            )
+            .unwrap();
+        (response.body_as_str().to_owned(), started.elapsed())
+    }
+
+    /// Serves a single PUT sent with `Expect: 100-continue` without answering it, and
+    /// reports the head of the request and the number of body bytes it received.
+    ///
+    /// When `reject`, the server answers the head with a final `403` and closes the
+    /// connection, counting the body bytes still arriving. Otherwise it reads the body of
+    /// `body_len` bytes before answering with a `200`.
+    fn expect_continue_server(
+        reject: bool,
+        body_len: usize,
+    ) -> (Region, ::std::sync::mpsc::Receiver<(String, usize)>) {
+        use std::io::{Read, Write};
+        use std::net::{Shutdown, TcpListener};
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = ::std::sync::mpsc::channel();
+        ::std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            socket
+                .set_read_timeout(Some(Duration::from_secs(2)))
+                .unwrap();
+            let mut received = Vec::new();
+            let mut buf = [0; 4096];
+            let head_end = loop {
+                if let Some(end) = received.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break end + 4;
+                }
+                match socket.read(&mut buf) {
+                    Ok(0) | Err(_) => return,
+                    Ok(read) => received.extend_from_slice(&buf[..read]),
+                }
+            };
+            if reject {
+                let _ = socket.write_all(
+                    b"HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                );
+                let _ = socket.shutdown(Shutdown::Write);
+            }
+            // the body, until the client closes the connection or stalls
+            while reject || received.len() - head_end < body_len {
+                match socket.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => received.extend_from_slice(&buf[..read]),
+                }
+            }
+            if !reject {
+                let _ = socket.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n");
+            }
+            let head = String::from_utf8_lossy(&received[..head_end]).to_lowercase();
+            let _ = sender.send((head, received.len() - head_end));
+        });
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint,
+        };
+        (region, receiver)
+    }
+
+    fn expect_continue_config(threshold: u64, timeout: Duration) -> HttpConfig {
+        let mut config = HttpConfig::new();
+        config.expect_continue(Some(threshold));
+        config.expect_continue_timeout(timeout);
+        config
+    }
+
+    #[test]
+    fn expect_continue_body_is_sent_once_the_server_agrees() {
+        let config = expect_continue_config(4, Duration::from_secs(10));
+        let (received, elapsed) = dispatch_expecting_continue(config, b"12345678");
+        assert_eq!(received, "expect=Some(\"100-continue\") received=8");
+        assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+    }
+
+    #[test]
+    fn bodies_under_the_threshold_are_sent_without_expect_continue() {
+        let config = expect_continue_config(16, Duration::from_secs(10));
+        let (received, _) = dispatch_expecting_continue(config, b"12345678");
+        assert_eq!(received, "expect=None received=8");
+
+        let mut config = HttpConfig::new();
+        config.expect_continue(None);
+        let (received, _) = dispatch_expecting_continue(config, b"12345678");
+        assert_eq!(received, "expect=None received=8");
+    }
+
+    #[test]
+    fn expect_continue_body_is_not_sent_once_the_server_rejects_the_request() {
+        let (region, server) = expect_continue_server(true, 8);
+        let config = expect_continue_config(4, Duration::from_secs(10));
+        let client = HttpClient::from_connector_with_config(HttpConnector::new(1), config);
+        let mut request = SignedRequest::new("PUT", "s3", &region, "/bucket/key");
+        request.set_payload(Some(&b"12345678"[..]));
+        request.complement();
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let started = Instant::now();
+        let response = runtime.block_on(client.dispatch(request, None)).unwrap();
+        assert_eq!(response.status, StatusCode::FORBIDDEN);
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        let (head, body_bytes) = server.recv().unwrap();
+        assert!(head.contains("\r\nexpect: 100-continue\r\n"), "{}", head);
+        assert_eq!(body_bytes, 0);
+    }
+
+    #[test]
+    fn expect_continue_body_is_sent_after_the_timeout_without_an_answer() {
+        let (region, server) = expect_continue_server(false, 8);
+        let config = expect_continue_config(4, Duration::from_millis(100));
+        let client = HttpClient::from_connector_with_config(HttpConnector::new(1), config);
+        let mut request = SignedRequest::new("PUT", "s3", &region, "/bucket/key");
+        request.set_payload(Some(&b"12345678"[..]));
+        request.complement();
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let response = runtime.block_on(client.dispatch(request, None)).unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        let (head, body_bytes) = server.recv().unwrap();
+        assert!(head.contains("\r\nexpect: 100-continue\r\n"), "{}", head);
+        assert_eq!(body_bytes, 8);
+    }
+
+    #[test]
+    fn root_certificates_are_trusted() {
+        let pem = fs::read("test_resources/corporate_root_ca.pem").unwrap();
+        let mut bundle = pem.clone();
+        bundle.extend_from_slice(&pem);
+
+        let mut config = HttpConfig::new();
+        config.add_root_certificates_file("test_resources/corporate_root_ca.pem");
+        config.add_root_certificates_pem(&bundle);
+        assert!(HttpClient::new_with_config(config).is_ok());
+    }
+
+    #[test]
+    fn invalid_root_certificates_fail_client_creation() {
+        let create = |config: HttpConfig| HttpClient::new_with_config(config).err().unwrap();
+
+        let mut config = HttpConfig::new();
+        config.add_root_certificates_pem(b"not a certificate");
+        assert_eq!(
+            create(config).to_string(),
+            "Couldn't parse root certificates from PEM bytes: no certificate found"
+        );
+
+        let mut config = HttpConfig::new();
+        config.add_root_certificates_pem(
+            b"-----BEGIN CERTIFICATE-----\nbm90IGEgY2VydGlmaWNhdGU=\n-----END CERTIFICATE-----\n",
+        );
+        let err = create(config).to_string();
+        assert!(
+            err.starts_with("Couldn't parse root certificates from PEM bytes: "),
+            "{}",
+            err
+        );
+
+        let mut config = HttpConfig::new();
+        config.add_root_certificates_file("test_resources/missing_ca.pem");
+        let err = create(config).to_string();
+        assert!(
+            err.starts_with("Couldn't read root certificates from test_resources/missing_ca.pem: "),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn refused_connection_is_an_error_with_its_source() {
+        let region = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            Region::Custom {
+                name: "us-east-1".to_owned(),
+                endpoint: format!("http://{}", listener.local_addr().unwrap()),
+            }
+        };
+
+        let err = dispatch_and_buffer(HttpConfig::new(), &region).unwrap_err();
+        assert_eq!(err.kind(), HttpDispatchErrorKind::Other);
+        assert!(err.source().is_some(), "{:?}", err);
+    }
+
+    #[test]
+    fn non_ascii_response_headers_are_kept() {
+        let region = stalling_server(
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nx-amz-meta-name: café\r\n\r\n",
+            b"ok".to_vec(),
+        );
+
+        let response = dispatch_and_buffer(HttpConfig::new(), &region).unwrap();
+        assert_eq!(
+            response.headers.get("x-amz-meta-name").map(String::as_str),
+            Some("café")
+        );
+    }
+
+    #[test]
+    fn repeated_response_headers_keep_every_value() {
+        let region = stalling_server(
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nx-amz-meta-tag: a\r\n\
+             x-amz-meta-tag: b\r\nx-amz-meta-tags: c\r\n\r\n",
+            b"ok".to_vec(),
+        );
+
+        let response = dispatch_and_buffer(HttpConfig::new(), &region).unwrap();
+        let tags: Vec<&str> = response
+            .headers
+            .get_all("x-amz-meta-tag")
+            .iter()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(tags, vec!["a", "b"]);
+        assert_eq!(
+            response.headers.get("x-amz-meta-tag").map(String::as_str),
+            Some("a")
+        );
+        assert_eq!(
+            response.headers.get("x-amz-meta-tags").map(String::as_str),
+            Some("c")
+        );
+    }
+
+    #[test]
+    fn from_io_error_preserves_dispatch_error_kind() {
+        let dispatch_error = HttpDispatchError {
+            message: "stalled".to_owned(),
+            kind: HttpDispatchErrorKind::BodyReadTimeout,
+            source: None,
+        };
+        let io_error = IoError::from(dispatch_error.clone());
+        assert_eq!(io_error.kind(), io::ErrorKind::TimedOut);
+        assert_eq!(HttpDispatchError::from(io_error), dispatch_error);
+    }
+
     #[test]
     fn from_io_error_preserves_error_message() {
         let io_error = ::std::io::Error::new(::std::io::ErrorKind::Other, "my error message");
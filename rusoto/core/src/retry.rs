@@ -0,0 +1,479 @@
+//! Retrying of failed requests.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use http::{HeaderMap, StatusCode};
+
+use crate::request::{HttpDispatchError, HttpDispatchErrorKind};
+use crate::signature::SignedRequest;
+
+/// Statuses of responses to throttled requests or transient server failures.
+const RETRYABLE_STATUSES: [StatusCode; 5] = [
+    StatusCode::TOO_MANY_REQUESTS,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    StatusCode::BAD_GATEWAY,
+    StatusCode::SERVICE_UNAVAILABLE,
+    StatusCode::GATEWAY_TIMEOUT,
+];
+
+/// Decides whether and when a failed attempt at a request is made again.
+///
+/// An attempt fails when the request couldn't be dispatched, for example because
+/// the connection failed or the attempt timed out, or when the service answered
+/// with a server error or throttled the request. Requests with a streaming body
+/// are never retried as their body can only be sent once. While attempts are left,
+/// the body of a response to a `GET` or `HEAD` request is read before its attempt
+/// ends, so that a body which stalls or breaks off is retried too.
+///
+/// The default policy makes a single attempt at each request. A policy is set on
+/// a `Client` with `Client::with_retry_policy`, and for a single call with an
+/// `OperationConfig`. A response asking to wait with a `Retry-After` header of a
+/// number of seconds is retried after that long, up to the longest wait of the policy.
+///
+/// A policy given a `RetryBudget` only retries while the budget has tokens left, so that
+/// an outage doesn't multiply the requests sent to the failing service.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: usize,
+    base_delay: Duration,
+    max_delay: Duration,
+    budget: Option<RetryBudget>,
+}
+
+impl RetryPolicy {
+    /// A policy making a single attempt at each request.
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// A policy making up to `max_attempts` attempts at each request, waiting
+    /// 100ms before the second attempt and doubling the wait before each of the
+    /// following ones, up to 20 seconds.
+    pub fn new(max_attempts: usize) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(20),
+            budget: None,
+        }
+    }
+
+    /// Sets the time to wait before the second attempt.
+    pub fn with_base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Sets the longest time to wait between two attempts.
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Takes a token from `budget` for each retry, and stops retrying while it has none.
+    ///
+    /// Clones of the budget share its tokens, so giving one to the policies of several
+    /// clients caps their retries together.
+    pub fn with_budget(mut self, budget: RetryBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// The maximum number of attempts made at each request.
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// The budget retries take their tokens from, if any.
+    pub fn budget(&self) -> Option<&RetryBudget> {
+        self.budget.as_ref()
+    }
+
+    /// The time to wait after the given failed attempt, counting from 1.
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let mut delay = self.base_delay;
+        for _ in 1..attempt {
+            match delay.checked_mul(2) {
+                Some(doubled) if doubled < self.max_delay => delay = doubled,
+                _ => return self.max_delay,
+            }
+        }
+        delay.min(self.max_delay)
+    }
+
+    /// The time to wait after the given failed attempt got a response with `headers`,
+    /// and whether it is the time asked for by their `Retry-After` header.
+    pub(crate) fn backoff_after(
+        &self,
+        attempt: usize,
+        headers: &HeaderMap<String>,
+    ) -> (Duration, bool) {
+        match headers
+            .get("retry-after")
+            .and_then(|seconds| seconds.trim().parse::<u64>().ok())
+        {
+            Some(seconds) => (Duration::from_secs(seconds).min(self.max_delay), true),
+            None => (self.backoff(attempt), false),
+        }
+    }
+
+    /// Whether a request is attempted again after the given attempt failed to dispatch.
+    pub(crate) fn retries_dispatch_error(&self, attempt: usize, err: &HttpDispatchError) -> bool {
+        attempt < self.max_attempts
+            && err.kind() != HttpDispatchErrorKind::BodyTooLarge
+            && err.kind() != HttpDispatchErrorKind::PayloadSizeMismatch
+    }
+
+    /// Whether the body of a response to the given attempt at a request made with
+    /// `method` is read within the attempt, for failing to read it to be retried.
+    /// Only the bodies of idempotent requests are, as long as attempts are left.
+    pub(crate) fn reads_body_within_attempt(&self, attempt: usize, method: &str) -> bool {
+        attempt < self.max_attempts && (method == "GET" || method == "HEAD")
+    }
+
+    /// Whether a request is attempted again after the given attempt got a response
+    /// with the given status.
+    pub(crate) fn retries_status(&self, attempt: usize, status: StatusCode) -> bool {
+        attempt < self.max_attempts && RETRYABLE_STATUSES.contains(&status)
+    }
+
+    /// Takes a token for a retry about to be made, failing if the budget has none left.
+    pub(crate) fn spend_retry(&self) -> bool {
+        match self.budget {
+            Some(ref budget) => budget.try_spend(Instant::now()),
+            None => true,
+        }
+    }
+}
+
+/// A bucket of tokens capping the retries of the requests it is shared by.
+///
+/// Each retry takes a token from the bucket, which refills at a steady rate up to its
+/// capacity. Once it is empty, failed requests return their failure instead of being
+/// retried, until tokens are back. This stops a client from answering a broad outage with
+/// a storm of retries, while the occasional failure is still retried.
+///
+/// Clones share their tokens. A budget is given to a `RetryPolicy` with
+/// `RetryPolicy::with_budget`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_core::{RetryBudget, RetryPolicy};
+///
+/// // up to 50 retries in a burst, then one every 2 seconds
+/// let budget = RetryBudget::new(50, 0.5);
+/// let policy = RetryPolicy::new(3).with_budget(budget.clone());
+/// assert_eq!(budget.available(), 50);
+/// ```
+#[derive(Clone)]
+pub struct RetryBudget {
+    capacity: u32,
+    refill_per_second: f64,
+    bucket: Arc<Mutex<TokenBucket>>,
+}
+
+/// The tokens of a `RetryBudget`, as of its last refill.
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RetryBudget {
+    /// A full budget of `capacity` tokens, getting `refill_per_second` tokens back each
+    /// second. A budget which doesn't refill caps the number of retries once and for all.
+    pub fn new(capacity: u32, refill_per_second: f64) -> RetryBudget {
+        RetryBudget {
+            capacity,
+            refill_per_second: refill_per_second.max(0.0),
+            bucket: Arc::new(Mutex::new(TokenBucket {
+                tokens: f64::from(capacity),
+                refilled_at: Instant::now(),
+            })),
+        }
+    }
+
+    /// The most tokens the budget holds.
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// The number of tokens the budget gets back each second.
+    pub fn refill_per_second(&self) -> f64 {
+        self.refill_per_second
+    }
+
+    /// The number of retries the budget allows right now.
+    pub fn available(&self) -> u32 {
+        let mut bucket = self.bucket.lock().unwrap();
+        self.refill(&mut bucket, Instant::now());
+        bucket.tokens as u32
+    }
+
+    /// Takes a token as of `now`, if there is one.
+    fn try_spend(&self, now: Instant) -> bool {
+        let mut bucket = self.bucket.lock().unwrap();
+        self.refill(&mut bucket, now);
+        if bucket.tokens < 1.0 {
+            return false;
+        }
+        bucket.tokens -= 1.0;
+        true
+    }
+
+    fn refill(&self, bucket: &mut TokenBucket, now: Instant) {
+        if now <= bucket.refilled_at {
+            return;
+        }
+        let elapsed = now - bucket.refilled_at;
+        let seconds = elapsed.as_secs() as f64 + f64::from(elapsed.subsec_nanos()) / 1e9;
+        bucket.tokens =
+            (bucket.tokens + seconds * self.refill_per_second).min(f64::from(self.capacity));
+        bucket.refilled_at = now;
+    }
+}
+
+impl fmt::Debug for RetryBudget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryBudget")
+            .field("capacity", &self.capacity)
+            .field("refill_per_second", &self.refill_per_second)
+            .finish()
+    }
+}
+
+/// Budgets are equal when they share their tokens.
+impl PartialEq for RetryBudget {
+    fn eq(&self, other: &RetryBudget) -> bool {
+        Arc::ptr_eq(&self.bucket, &other.bucket)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::none()
+    }
+}
+
+/// A retry of a request, handed to the `RetryObserver` of the client before it waits.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RetryRecord {
+    /// The signing name of the service, such as `s3`.
+    pub service: String,
+    /// The name of the operation, such as `PutObject`, when known.
+    pub operation: Option<String>,
+    /// The number of the attempt which failed, counting from 1.
+    pub attempt: usize,
+    /// The HTTP status of the response to the failed attempt, if there was one.
+    pub status: Option<u16>,
+    /// The AWS request id of the response to the failed attempt, if there was one.
+    pub request_id: Option<String>,
+    /// Why the failed attempt got no response.
+    pub error: Option<String>,
+    /// How long the client waits before the next attempt, in milliseconds.
+    pub delay_ms: u64,
+    /// Whether the wait is the one asked for by the `Retry-After` header of the response.
+    pub retry_after: bool,
+}
+
+impl RetryRecord {
+    /// A record of retrying `request` after `delay`, yet to be completed with the failure.
+    pub(crate) fn new(
+        request: &SignedRequest,
+        attempt: usize,
+        delay: Duration,
+        retry_after: bool,
+    ) -> RetryRecord {
+        RetryRecord {
+            service: request.service.clone(),
+            operation: request.operation.clone(),
+            attempt,
+            status: None,
+            request_id: None,
+            error: None,
+            delay_ms: delay.as_secs() * 1000 + u64::from(delay.subsec_millis()),
+            retry_after,
+        }
+    }
+}
+
+/// A request which got its final response, handed to the `RetryObserver` of the client.
+///
+/// Retries are transparent to callers, so this tells which responses were only got after
+/// some, such as to account for them in SLOs or to flag flaky dependencies.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ResponseRecord {
+    /// The signing name of the service, such as `s3`.
+    pub service: String,
+    /// The name of the operation, such as `PutObject`, when known.
+    pub operation: Option<String>,
+    /// The number of attempts made before the one which got the response, which is 0 when
+    /// the first attempt got it. Redirects followed within an attempt aren't counted.
+    pub retries: usize,
+    /// The HTTP status of the response.
+    pub status: u16,
+    /// The AWS request id of the response, if it had one.
+    pub request_id: Option<String>,
+}
+
+impl ResponseRecord {
+    /// A record of `request`, yet to be completed with its response.
+    pub(crate) fn new(request: &SignedRequest) -> ResponseRecord {
+        ResponseRecord {
+            service: request.service.clone(),
+            operation: request.operation.clone(),
+            retries: 0,
+            status: 0,
+            request_id: None,
+        }
+    }
+
+    /// The number of the attempt which got the response, counting from 1.
+    pub fn attempt(&self) -> usize {
+        self.retries + 1
+    }
+}
+
+/// The bytes a request sent and received, handed to the `RetryObserver` of the client once
+/// the body of its final response has been read, or dropped.
+///
+/// Bodies are counted as they go over the wire, without their headers. Streamed bodies are
+/// counted as they are streamed, so a response body dropped before its end only counts
+/// the bytes read from it.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TransferRecord {
+    /// The signing name of the service, such as `s3`.
+    pub service: String,
+    /// The name of the operation, such as `PutObject`, when known.
+    pub operation: Option<String>,
+    /// The HTTP status of the response.
+    pub status: u16,
+    /// The AWS request id of the response, if it had one.
+    pub request_id: Option<String>,
+    /// The bytes of the request bodies sent, over every attempt and redirect.
+    pub bytes_sent: u64,
+    /// The bytes of the body of the final response.
+    pub bytes_received: u64,
+    /// Whether the body of the response was read to its end.
+    pub complete: bool,
+}
+
+/// Where a client tells of the retries it makes, set with `Client::with_retry_observer`.
+pub trait RetryObserver: Send + Sync {
+    /// Observes a retry about to be made once its delay has passed.
+    fn retry(&self, record: &RetryRecord);
+
+    /// Observes the final response to a request, whether or not it was retried. Does
+    /// nothing by default.
+    fn responded(&self, record: &ResponseRecord) {
+        let _ = record;
+    }
+
+    /// Observes the bytes sent and received by a request, once the body of its final
+    /// response has been read or dropped. Does nothing by default.
+    fn transferred(&self, record: &TransferRecord) {
+        let _ = record;
+    }
+}
+
+impl<F> RetryObserver for F
+where
+    F: Fn(&RetryRecord) + Send + Sync,
+{
+    fn retry(&self, record: &RetryRecord) {
+        self(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_max_delay() {
+        let policy = RetryPolicy::new(10)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(100), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn backoff_follows_retry_after_headers() {
+        let policy = RetryPolicy::new(10)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(5));
+        let mut headers = HeaderMap::default();
+        assert_eq!(
+            policy.backoff_after(2, &headers),
+            (Duration::from_millis(200), false)
+        );
+        headers.insert("retry-after", "3".to_owned());
+        assert_eq!(
+            policy.backoff_after(2, &headers),
+            (Duration::from_secs(3), true)
+        );
+        headers.insert("retry-after", "60".to_owned());
+        assert_eq!(
+            policy.backoff_after(2, &headers),
+            (Duration::from_secs(5), true)
+        );
+        headers.insert("retry-after", "Fri, 31 Dec 1999 23:59:59 GMT".to_owned());
+        assert_eq!(
+            policy.backoff_after(2, &headers),
+            (Duration::from_millis(200), false)
+        );
+    }
+
+    #[test]
+    fn budgets_are_shared_by_their_clones_and_refill_up_to_their_capacity() {
+        let budget = RetryBudget::new(2, 0.5);
+        let clone = budget.clone();
+        let start = budget.bucket.lock().unwrap().refilled_at;
+        assert!(budget.try_spend(start));
+        assert!(clone.try_spend(start));
+        assert!(!budget.try_spend(start));
+
+        // a token every 2 seconds
+        assert!(!budget.try_spend(start + Duration::from_secs(1)));
+        assert!(budget.try_spend(start + Duration::from_secs(2)));
+        assert!(!clone.try_spend(start + Duration::from_secs(2)));
+
+        // up to the capacity, however long the budget is left alone
+        let later = start + Duration::from_secs(3600);
+        assert!(budget.try_spend(later));
+        assert!(budget.try_spend(later));
+        assert!(!budget.try_spend(later));
+
+        assert_eq!(budget, clone);
+        assert_ne!(budget, RetryBudget::new(2, 0.5));
+    }
+
+    #[test]
+    fn policies_without_a_budget_always_spend_retries() {
+        assert!(RetryPolicy::new(3).spend_retry());
+        let policy = RetryPolicy::new(3).with_budget(RetryBudget::new(1, 0.0));
+        assert!(policy.spend_retry());
+        assert!(!policy.spend_retry());
+        assert_eq!(policy.budget().map(RetryBudget::available), Some(0));
+    }
+
+    #[test]
+    fn retries_transient_failures_only() {
+        let policy = RetryPolicy::new(2);
+        assert!(policy.retries_status(1, StatusCode::SERVICE_UNAVAILABLE));
+        assert!(policy.retries_status(1, StatusCode::TOO_MANY_REQUESTS));
+        assert!(!policy.retries_status(1, StatusCode::BAD_REQUEST));
+        assert!(!policy.retries_status(2, StatusCode::SERVICE_UNAVAILABLE));
+        assert!(policy.retries_dispatch_error(1, &HttpDispatchError::new("reset".into())));
+        assert!(
+            !RetryPolicy::none().retries_dispatch_error(1, &HttpDispatchError::new("reset".into()))
+        );
+    }
+}
@@ -34,6 +34,13 @@ impl<'de> Visitor<'de> for BlobVisitor {
             .map(Bytes::from)
             .map_err(|err| SerdeError::custom(err.description()))
     }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        Ok(Bytes::from(v))
+    }
 }
 
 impl SerdeBlob for Bytes {
@@ -48,7 +55,12 @@ impl SerdeBlob for Bytes {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&base64::encode(self.as_ref()))
+        // binary formats such as CBOR have byte strings, text ones get base64
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&base64::encode(self.as_ref()))
+        } else {
+            serializer.serialize_bytes(self.as_ref())
+        }
     }
 }
 
@@ -217,6 +229,37 @@ impl SerdeBlobList for Option<Vec<Bytes>> {
     }
 }
 
+/// The name of the newtype struct timestamps are serialized as by `SerdeTimestamp`, which
+/// CBOR tags as an epoch-based date/time and other formats see through.
+pub const EPOCH_SECONDS_NEWTYPE: &str = "__rusoto_epoch_seconds";
+
+pub trait SerdeTimestamp {
+    fn serialize_timestamp<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+impl SerdeTimestamp for f64 {
+    fn serialize_timestamp<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(EPOCH_SECONDS_NEWTYPE, self)
+    }
+}
+
+impl SerdeTimestamp for Option<f64> {
+    fn serialize_timestamp<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Some(ref timestamp) => SerdeTimestamp::serialize_timestamp(timestamp, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate serde;
@@ -0,0 +1,123 @@
+//! The keys and signatures of AWS signature version 4A.
+//!
+//! Signature version 4A signs requests with an ECDSA P-256 key instead of an HMAC key
+//! chained from the date, region and service, so that a signature can be valid in several
+//! regions. The key is derived from the credentials alone, with the counter mode KDF of
+//! NIST SP 800-108 using HMAC-SHA256.
+
+use hmac::Mac;
+use openssl::bn::{BigNum, BigNumContext};
+use openssl::ec::{EcGroup, EcKey, EcPoint};
+use openssl::ecdsa::EcdsaSig;
+use openssl::nid::Nid;
+use openssl::pkey::Private;
+use sha2::{Digest, Sha256};
+
+use crate::credential::AwsCredentials;
+use crate::signature::hmac;
+
+/// The label of the KDF, which is the name of the algorithm
+const ALGORITHM: &[u8] = b"AWS4-ECDSA-P256-SHA256";
+
+/// Signs `string_to_sign` with the key derived from `creds`, returning the hex encoded DER
+/// signature.
+pub(crate) fn sign_string(string_to_sign: &str, creds: &AwsCredentials) -> String {
+    let key = signing_key(creds.aws_access_key_id(), creds.aws_secret_access_key());
+    let digest = Sha256::digest(string_to_sign.as_bytes());
+    let signature = EcdsaSig::sign(&digest, &key).expect("failed to sign with ECDSA");
+    hex::encode(signature.to_der().expect("failed to encode the signature"))
+}
+
+/// Derives the P-256 key signing the requests made with the given access key.
+///
+/// Candidates are drawn from the KDF with an increasing counter until one is below the
+/// order of the curve minus one, and the key is that candidate plus one.
+pub(crate) fn signing_key(access_key_id: &str, secret_access_key: &str) -> EcKey<Private> {
+    let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).expect("P-256 is supported");
+    let mut ctx = BigNumContext::new().expect("failed to allocate a big number context");
+    let mut n_minus_two = BigNum::new().expect("failed to allocate a big number");
+    group
+        .order(&mut n_minus_two, &mut ctx)
+        .expect("P-256 has an order");
+    n_minus_two.sub_word(2).expect("failed to compute n - 2");
+
+    let input_key = format!("AWS4A{}", secret_access_key);
+    for counter in 1..=254u8 {
+        let mut fixed_input = Vec::with_capacity(ALGORITHM.len() + access_key_id.len() + 10);
+        // the iteration, of which there is one as the key is as long as the HMAC
+        fixed_input.extend_from_slice(&1u32.to_be_bytes());
+        fixed_input.extend_from_slice(ALGORITHM);
+        fixed_input.push(0);
+        fixed_input.extend_from_slice(access_key_id.as_bytes());
+        fixed_input.push(counter);
+        // the length of the key, in bits
+        fixed_input.extend_from_slice(&256u32.to_be_bytes());
+
+        let k0 = hmac(input_key.as_bytes(), &fixed_input).result().code();
+        let mut candidate = BigNum::from_slice(&k0).expect("failed to read the candidate key");
+        if candidate <= n_minus_two {
+            candidate.add_word(1).expect("failed to compute the key");
+            let mut public_key = EcPoint::new(&group).expect("failed to allocate a point");
+            public_key
+                .mul_generator2(&group, &candidate, &mut ctx)
+                .expect("failed to compute the public key");
+            return EcKey::from_private_components(&group, &candidate, &public_key)
+                .expect("the derived key is valid");
+        }
+    }
+    // each candidate is out of range with a probability of about 2^-32
+    panic!("failed to derive a signature version 4A key")
+}
+
+#[cfg(test)]
+mod tests {
+    use openssl::ecdsa::EcdsaSig;
+    use sha2::{Digest, Sha256};
+
+    use super::{sign_string, signing_key};
+    use crate::credential::AwsCredentials;
+
+    const STRING_TO_SIGN: &str = "AWS4-ECDSA-P256-SHA256\n20150830T123600Z\n\
+                                  20150830/service/aws4_request\n\
+                                  cf59db423e841c8b7e3444158185aa261b724a5c27cbe762676f3eed19f4dc02";
+
+    fn credentials() -> AwsCredentials {
+        AwsCredentials::new(
+            "AKIDEXAMPLE",
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn signatures_verify_with_the_derived_public_key() {
+        let signature = sign_string(STRING_TO_SIGN, &credentials());
+
+        // ECDSA signatures aren't deterministic, so they're checked against the public key
+        let key = signing_key("AKIDEXAMPLE", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY");
+        let signature = EcdsaSig::from_der(&hex::decode(signature).unwrap()).unwrap();
+        let digest = Sha256::digest(STRING_TO_SIGN.as_bytes());
+        assert!(signature.verify(&digest, &key).unwrap());
+        let other_digest = Sha256::digest(b"another string");
+        assert!(!signature.verify(&other_digest, &key).unwrap());
+    }
+
+    #[test]
+    fn keys_are_derived_from_the_access_key_and_secret() {
+        let key = signing_key("AKIDEXAMPLE", "secret");
+        key.check_key().unwrap();
+        assert_eq!(
+            key.private_key(),
+            signing_key("AKIDEXAMPLE", "secret").private_key()
+        );
+        assert_ne!(
+            key.private_key(),
+            signing_key("AKIDEXAMPLE2", "secret").private_key()
+        );
+        assert_ne!(
+            key.private_key(),
+            signing_key("AKIDEXAMPLE", "secret2").private_key()
+        );
+    }
+}
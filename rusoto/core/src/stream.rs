@@ -1,14 +1,86 @@
+use std::env;
 use std::fmt;
-use std::io;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
 
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
 use futures::{future, stream, Async, Future, Poll, Stream};
 use tokio::io::AsyncRead;
 
+use crate::checksum::StreamChecksum;
+use crate::progress::{ProgressListener, ProgressStream, DEFAULT_GRANULARITY};
+
+/// The size of the chunks files are read in.
+const FILE_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A payload which can be produced again from its start, so that a request sending it can
+/// be retried after its first attempt consumed it.
+///
+/// A `ByteStream` made from a `ReplayableBody` opens it again for every attempt.
+/// `ByteStream`s made from a buffer are replayable, as is a `FileBody`; other streams can
+/// be made replayable with `ByteStream::spool`.
+pub trait ReplayableBody: Send + Sync {
+    /// Produces the payload from its start.
+    fn open(&self) -> io::Result<ByteStream>;
+
+    /// The number of bytes of the payload, if known.
+    fn size_hint(&self) -> Option<usize>;
+}
+
+impl ReplayableBody for Bytes {
+    fn open(&self) -> io::Result<ByteStream> {
+        Ok(ByteStream::new(stream::once(Ok(self.clone()))))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// The contents of a file, read from the start of the file for every attempt at sending
+/// them.
+///
+/// The file is read with blocking i/o, in chunks of 64 KiB.
+#[derive(Clone, Debug)]
+pub struct FileBody {
+    path: PathBuf,
+    size: usize,
+}
+
+impl FileBody {
+    /// The contents of the file at `path`, whose size is taken now.
+    ///
+    /// Attempts fail with a `PayloadSizeMismatch` error if the size of the file changes.
+    pub fn new<P: AsRef<Path>>(path: P) -> io::Result<FileBody> {
+        let path = path.as_ref().to_owned();
+        let size = fs::metadata(&path)?.len() as usize;
+        Ok(FileBody { path, size })
+    }
+}
+
+impl ReplayableBody for FileBody {
+    fn open(&self) -> io::Result<ByteStream> {
+        Ok(ByteStream::new(ReadStream(File::open(&self.path)?)))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.size)
+    }
+}
+
 /// Stream of bytes.
 pub struct ByteStream {
     size_hint: Option<usize>,
     inner: Box<dyn Stream<Item = Bytes, Error = io::Error> + Send>,
+    /// What the stream is produced from, if it can be produced again.
+    source: Option<Arc<dyn ReplayableBody>>,
+    /// The listeners told of the progress of the stream and of its replays, with how many
+    /// bytes go between two of their reports.
+    progress: Vec<(Arc<dyn ProgressListener>, usize)>,
 }
 
 impl ByteStream {
@@ -20,13 +92,154 @@ impl ByteStream {
         ByteStream {
             size_hint: None,
             inner: Box::new(stream),
+            source: None,
+            progress: Vec::new(),
+        }
+    }
+
+    /// Create a replayable `ByteStream`, which opens `body` once it is first polled.
+    pub fn replayable<B: ReplayableBody + 'static>(body: B) -> ByteStream {
+        ByteStream::from_source(Arc::new(body))
+    }
+
+    fn from_source(source: Arc<dyn ReplayableBody>) -> ByteStream {
+        let opened = source.clone();
+        ByteStream {
+            size_hint: source.size_hint(),
+            inner: Box::new(future::lazy(move || opened.open()).flatten_stream()),
+            source: Some(source),
+            progress: Vec::new(),
         }
     }
 
-    pub(crate) fn size_hint(&self) -> Option<usize> {
+    /// Whether the stream can be produced again, for a request sending it to be retried.
+    ///
+    /// Requests whose payload is a stream which isn't replayable are sent only once.
+    pub fn is_replayable(&self) -> bool {
+        self.source.is_some()
+    }
+
+    /// The stream produced again from its start, if it is replayable.
+    pub(crate) fn replay(&self) -> Option<ByteStream> {
+        self.source.clone().map(|source| {
+            let replay = ByteStream {
+                size_hint: self.size_hint,
+                ..ByteStream::from_source(source)
+            };
+            self.progress
+                .iter()
+                .fold(replay, |replay, &(ref listener, granularity)| {
+                    replay.with_progress_listener(listener.clone(), granularity)
+                })
+        })
+    }
+
+    /// Read the whole stream, so that it can be produced again, keeping it in memory up to
+    /// `memory_limit` bytes and in a temporary file beyond that.
+    ///
+    /// The temporary file is written with blocking i/o, and removed once the returned
+    /// stream and every replay of it are dropped. Streams which are already replayable
+    /// are returned as they are.
+    pub fn spool(
+        self,
+        memory_limit: usize,
+    ) -> impl Future<Item = ByteStream, Error = io::Error> + Send {
+        if self.is_replayable() {
+            return future::Either::A(future::ok(self));
+        }
+        future::Either::B(
+            self.fold(Spool::Memory(BytesMut::new()), move |spool, chunk| {
+                spool.write(&chunk, memory_limit)
+            })
+            .map(|spool| match spool {
+                Spool::Memory(buffer) => ByteStream::from(buffer.freeze()),
+                Spool::File(spooled, _) => ByteStream::replayable(spooled),
+            }),
+        )
+    }
+
+    /// Declare the exact number of bytes the stream produces.
+    ///
+    /// Requests with a sized stream as their payload are sent with a `Content-Length`
+    /// header instead of chunked transfer encoding, which some operations and
+    /// S3-compatible endpoints require. The request fails with a
+    /// `PayloadSizeMismatch` error if the stream produces more or fewer bytes.
+    pub fn with_size(mut self, size: usize) -> ByteStream {
+        self.size_hint = Some(size);
+        self
+    }
+
+    /// The number of bytes the stream produces, if known.
+    pub fn size_hint(&self) -> Option<usize> {
         self.size_hint
     }
 
+    /// Compute the CRC32C checksum of the bytes as they go through the stream.
+    ///
+    /// The returned handle gives the checksum once the stream has ended, such as to compare
+    /// it with the `x-amz-checksum-crc32c` header of an object.
+    pub fn crc32c(self) -> (ByteStream, StreamChecksum) {
+        let checksum = StreamChecksum::new();
+        let stream = ByteStream {
+            size_hint: self.size_hint,
+            inner: Box::new(ChecksumStream {
+                inner: self.inner,
+                checksum: checksum.clone(),
+            }),
+            // a replay would skip the checksum
+            source: None,
+            progress: Vec::new(),
+        };
+        (stream, checksum)
+    }
+
+    /// Add the number of bytes going through the stream to `counter`.
+    pub(crate) fn counted(self, counter: Arc<AtomicU64>) -> ByteStream {
+        ByteStream {
+            inner: Box::new(CountingStream {
+                inner: self.inner,
+                counter,
+            }),
+            ..self
+        }
+    }
+
+    /// Tell `listener` how many bytes went through the stream, every 64 KiB and once it
+    /// ends.
+    ///
+    /// The total of the reports is the size hint of the stream when the listener is
+    /// attached, which is known for the bodies of responses with a `Content-Length`. A
+    /// request retried with a replay of its payload reports it again from its start. See
+    /// the [`progress`](progress/index.html) module.
+    pub fn with_progress<L>(self, listener: L) -> ByteStream
+    where
+        L: ProgressListener + 'static,
+    {
+        self.with_progress_every(DEFAULT_GRANULARITY, listener)
+    }
+
+    /// Tell `listener` how many bytes went through the stream, every time at least
+    /// `granularity` bytes did and once it ends.
+    pub fn with_progress_every<L>(self, granularity: usize, listener: L) -> ByteStream
+    where
+        L: ProgressListener + 'static,
+    {
+        self.with_progress_listener(Arc::new(listener), granularity)
+    }
+
+    fn with_progress_listener(
+        mut self,
+        listener: Arc<dyn ProgressListener>,
+        granularity: usize,
+    ) -> ByteStream {
+        let inner = ProgressStream::new(self.inner, listener.clone(), granularity, self.size_hint);
+        self.progress.push((listener, granularity));
+        ByteStream {
+            inner: Box::new(inner),
+            ..self
+        }
+    }
+
     /// Return an implementation of `AsyncRead` that uses async i/o to consume the stream.
     pub fn into_async_read(self) -> impl AsyncRead + Send {
         ImplAsyncRead::new(self.inner)
@@ -40,10 +253,13 @@ impl ByteStream {
 
 impl From<Vec<u8>> for ByteStream {
     fn from(buf: Vec<u8>) -> ByteStream {
-        ByteStream {
-            size_hint: Some(buf.len()),
-            inner: Box::new(stream::once(Ok(Bytes::from(buf)))),
-        }
+        ByteStream::from(Bytes::from(buf))
+    }
+}
+
+impl<B: ReplayableBody + 'static> From<B> for ByteStream {
+    fn from(body: B) -> ByteStream {
+        ByteStream::replayable(body)
     }
 }
 
@@ -62,6 +278,136 @@ impl Stream for ByteStream {
     }
 }
 
+/// Reads a file in chunks.
+struct ReadStream(File);
+
+impl Stream for ReadStream {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        let mut chunk = vec![0; FILE_CHUNK_SIZE];
+        let read = self.0.read(&mut chunk)?;
+        if read == 0 {
+            return Ok(Async::Ready(None));
+        }
+        chunk.truncate(read);
+        Ok(Async::Ready(Some(Bytes::from(chunk))))
+    }
+}
+
+/// Where `ByteStream::spool` keeps what it has read so far.
+enum Spool {
+    Memory(BytesMut),
+    File(SpooledFile, File),
+}
+
+impl Spool {
+    fn write(self, chunk: &[u8], memory_limit: usize) -> io::Result<Spool> {
+        match self {
+            Spool::Memory(mut buffer) => {
+                if buffer.len() + chunk.len() <= memory_limit {
+                    buffer.extend_from_slice(chunk);
+                    return Ok(Spool::Memory(buffer));
+                }
+                let (mut spooled, mut file) = SpooledFile::create()?;
+                file.write_all(&buffer)?;
+                file.write_all(chunk)?;
+                spooled.body.size = buffer.len() + chunk.len();
+                Ok(Spool::File(spooled, file))
+            }
+            Spool::File(mut spooled, mut file) => {
+                file.write_all(chunk)?;
+                spooled.body.size += chunk.len();
+                Ok(Spool::File(spooled, file))
+            }
+        }
+    }
+}
+
+/// A temporary file holding a spooled stream, removed once dropped.
+struct SpooledFile {
+    body: FileBody,
+}
+
+impl SpooledFile {
+    fn create() -> io::Result<(SpooledFile, File)> {
+        static SPOOLED: AtomicUsize = AtomicUsize::new(0);
+        let path = env::temp_dir().join(format!(
+            "rusoto-spool-{}-{}",
+            process::id(),
+            SPOOLED.fetch_add(1, Ordering::SeqCst)
+        ));
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        let spooled = SpooledFile {
+            body: FileBody { path, size: 0 },
+        };
+        Ok((spooled, file))
+    }
+}
+
+impl ReplayableBody for SpooledFile {
+    fn open(&self) -> io::Result<ByteStream> {
+        self.body.open()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.body.size_hint()
+    }
+}
+
+impl Drop for SpooledFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.body.path);
+    }
+}
+
+struct CountingStream {
+    inner: Box<dyn Stream<Item = Bytes, Error = io::Error> + Send>,
+    counter: Arc<AtomicU64>,
+}
+
+impl Stream for CountingStream {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        let polled = self.inner.poll()?;
+        if let Async::Ready(Some(ref bytes)) = polled {
+            self.counter
+                .fetch_add(bytes.len() as u64, Ordering::Relaxed);
+        }
+        Ok(polled)
+    }
+}
+
+struct ChecksumStream {
+    inner: Box<dyn Stream<Item = Bytes, Error = io::Error> + Send>,
+    checksum: StreamChecksum,
+}
+
+impl Stream for ChecksumStream {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        match self.inner.poll()? {
+            Async::Ready(Some(bytes)) => {
+                self.checksum.update(&bytes);
+                Ok(Async::Ready(Some(bytes)))
+            }
+            Async::Ready(None) => {
+                self.checksum.complete();
+                Ok(Async::Ready(None))
+            }
+            Async::NotReady => Ok(Async::NotReady),
+        }
+    }
+}
+
 struct ImplAsyncRead {
     buffer: io::Cursor<Bytes>,
     stream: stream::Fuse<Box<dyn Stream<Item = Bytes, Error = io::Error> + Send>>,
@@ -163,3 +509,85 @@ fn test_blocking_read() {
     assert_eq!(&buf[..1], b"8");
     assert_eq!(async_read.read(&mut buf).unwrap(), 0);
 }
+
+#[test]
+fn test_crc32c() {
+    let chunks = vec![Bytes::from_static(b"1234"), Bytes::from_static(b"56789")];
+    let (stream, checksum) = ByteStream::new(stream::iter_ok(chunks)).crc32c();
+    let mut stream = stream.wait();
+
+    assert_eq!(stream.next().unwrap().unwrap(), Bytes::from_static(b"1234"));
+    assert!(checksum.crc32c().is_none());
+    assert_eq!(
+        stream.next().unwrap().unwrap(),
+        Bytes::from_static(b"56789")
+    );
+    assert!(stream.next().is_none());
+    assert_eq!(checksum.crc32c().unwrap().finish(), 0xe306_9283);
+}
+
+#[test]
+fn test_replay_buffer() {
+    let stream = ByteStream::from(b"payload".to_vec());
+    let replay = stream.replay().unwrap();
+
+    assert_eq!(
+        stream.concat2().wait().unwrap(),
+        Bytes::from_static(b"payload")
+    );
+    assert_eq!(replay.size_hint(), Some(7));
+    assert_eq!(
+        replay.concat2().wait().unwrap(),
+        Bytes::from_static(b"payload")
+    );
+}
+
+#[test]
+fn test_spool() {
+    let chunks = vec![Bytes::from_static(b"1234"), Bytes::from_static(b"56789")];
+    let stream = ByteStream::new(stream::iter_ok(chunks.clone()));
+    assert!(!stream.is_replayable());
+    assert!(stream.replay().is_none());
+
+    let in_memory = ByteStream::new(stream::iter_ok(chunks.clone()))
+        .spool(16)
+        .wait()
+        .unwrap();
+    assert_eq!(in_memory.size_hint(), Some(9));
+    let replay = in_memory.replay().unwrap();
+    assert_eq!(
+        in_memory.concat2().wait().unwrap(),
+        Bytes::from_static(b"123456789")
+    );
+    assert_eq!(
+        replay.concat2().wait().unwrap(),
+        Bytes::from_static(b"123456789")
+    );
+
+    let in_file = ByteStream::new(stream::iter_ok(chunks))
+        .spool(6)
+        .wait()
+        .unwrap();
+    assert_eq!(in_file.size_hint(), Some(9));
+    let spooled_files = || {
+        fs::read_dir(env::temp_dir())
+            .unwrap()
+            .filter(|entry| {
+                let name = entry.as_ref().unwrap().file_name();
+                name.to_string_lossy()
+                    .starts_with(&format!("rusoto-spool-{}-", process::id()))
+            })
+            .count()
+    };
+    assert_eq!(spooled_files(), 1);
+    let replay = in_file.replay().unwrap();
+    assert_eq!(
+        in_file.concat2().wait().unwrap(),
+        Bytes::from_static(b"123456789")
+    );
+    assert_eq!(
+        replay.concat2().wait().unwrap(),
+        Bytes::from_static(b"123456789")
+    );
+    assert_eq!(spooled_files(), 0);
+}
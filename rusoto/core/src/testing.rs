@@ -0,0 +1,181 @@
+//! Checking the signatures of requests against known-good fixtures, with the
+//! `test-util` feature.
+//!
+//! A signature captured from the AWS CLI or another SDK for the same request, signed
+//! with the same credentials at the same time, is byte for byte the one rusoto computes.
+//! [`assert_authorization`](fn.assert_authorization.html) signs a `SignedRequest` the way
+//! clients do, at the time of a `ManualClock`, and fails with the canonical request and
+//! the string to sign when its `authorization` header isn't the one of the fixture.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rusoto_core::credential::ManualClock;
+//! use rusoto_core::signature::SignedRequest;
+//! use rusoto_core::testing::{assert_authorization, example_credentials};
+//! use rusoto_core::Region;
+//!
+//! let mut request = SignedRequest::new("GET", "service", &Region::UsEast1, "/");
+//! request.set_hostname(Some("example.amazonaws.com".to_owned()));
+//! request.add_param("Param2", "value2");
+//! request.add_param("Param1", "value1");
+//! assert_authorization(
+//!     &mut request,
+//!     &example_credentials(),
+//!     &ManualClock::new("2015-08-30T12:36:00Z".parse().unwrap()),
+//!     "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+//!      SignedHeaders=content-type;host;x-amz-content-sha256;x-amz-date, \
+//!      Signature=85439c4d0c6583c701ef82778600513a639aa09254a2da12d02bb0afc8aaa6b7",
+//! );
+//! ```
+
+use crate::credential::{AwsCredentials, Clock};
+use crate::signature::{clock_time, SignedRequest};
+
+/// The access key ID of the AWS Signature Version 4 test suite.
+pub const EXAMPLE_ACCESS_KEY_ID: &str = "AKIDEXAMPLE";
+
+/// The secret access key of the AWS Signature Version 4 test suite.
+pub const EXAMPLE_SECRET_ACCESS_KEY: &str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+
+/// The credentials of the AWS Signature Version 4 test suite, without a session token.
+pub fn example_credentials() -> AwsCredentials {
+    AwsCredentials::new(EXAMPLE_ACCESS_KEY_ID, EXAMPLE_SECRET_ACCESS_KEY, None, None)
+}
+
+/// What a request was signed from, and the `authorization` header it was signed with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigningTrace {
+    /// The canonical form of the request, whose hash is signed.
+    pub canonical_request: String,
+    /// The string signed with the signing key.
+    pub string_to_sign: String,
+    /// The value of the `authorization` header.
+    pub authorization: String,
+}
+
+/// Signs `request` with `credentials` at the time of `clock`, as clients sign the
+/// requests they send, with a `+` in its query string standing for itself.
+pub fn sign_traced(
+    request: &mut SignedRequest,
+    credentials: &AwsCredentials,
+    clock: &dyn Clock,
+) -> SigningTrace {
+    let signing = request.sign_traced(credentials, true, clock_time(clock), None);
+    let authorization = request
+        .headers()
+        .get("authorization")
+        .and_then(|values| values.last())
+        .map(|value| String::from_utf8_lossy(value).into_owned())
+        .unwrap_or_default();
+    SigningTrace {
+        canonical_request: signing.canonical_request,
+        string_to_sign: signing.string_to_sign,
+        authorization,
+    }
+}
+
+/// Signs `request` like `sign_traced`, and panics unless its `authorization` header is
+/// `expected`, telling what was signed.
+pub fn assert_authorization(
+    request: &mut SignedRequest,
+    credentials: &AwsCredentials,
+    clock: &dyn Clock,
+    expected: &str,
+) {
+    let trace = sign_traced(request, credentials, clock);
+    if trace.authorization != expected {
+        panic!(
+            "the signature of {} {} isn't the one of the fixture\n\
+             expected: {}\n\
+             signed:   {}\n\
+             canonical request:\n{}\n\
+             string to sign:\n{}",
+            request.method(),
+            request.path(),
+            expected,
+            trace.authorization,
+            trace.canonical_request,
+            trace.string_to_sign
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use super::{assert_authorization, example_credentials, sign_traced};
+    use crate::credential::ManualClock;
+    use crate::signature::SignedRequest;
+    use crate::Region;
+
+    fn clock() -> ManualClock {
+        ManualClock::new("2015-08-30T12:36:00Z".parse().unwrap())
+    }
+
+    fn request() -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "service", &Region::UsEast1, "/");
+        request.set_hostname(Some("example.amazonaws.com".to_owned()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+        request.set_payload(Some(&b"Param1=value1"[..]));
+        request
+    }
+
+    #[test]
+    fn signatures_are_traced_from_the_canonical_request() {
+        let trace = sign_traced(&mut request(), &example_credentials(), &clock());
+        assert_eq!(
+            trace.canonical_request,
+            "POST\n/\n\n\
+             content-type:application/x-www-form-urlencoded\n\
+             host:example.amazonaws.com\n\
+             x-amz-content-sha256:\
+             9095672bbd1f56dfc5b65f3e153adc8731a4a654192329106275f4c7b24d0b6e\n\
+             x-amz-date:20150830T123600Z\n\n\
+             content-type;host;x-amz-content-sha256;x-amz-date\n\
+             9095672bbd1f56dfc5b65f3e153adc8731a4a654192329106275f4c7b24d0b6e"
+        );
+        assert!(trace
+            .string_to_sign
+            .starts_with("AWS4-HMAC-SHA256\n20150830T123600Z\n20150830/us-east-1/service/"));
+        assert!(trace.authorization.starts_with(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=content-type;host;x-amz-content-sha256;x-amz-date, Signature="
+        ));
+
+        // signing again gives the same signature
+        let mut request = request();
+        let first = sign_traced(&mut request, &example_credentials(), &clock());
+        assert_eq!(
+            sign_traced(&mut request, &example_credentials(), &clock()),
+            first
+        );
+        assert_eq!(first, trace);
+    }
+
+    #[test]
+    fn mismatched_fixtures_fail_with_what_was_signed() {
+        let expected = sign_traced(&mut request(), &example_credentials(), &clock());
+        assert_authorization(
+            &mut request(),
+            &example_credentials(),
+            &clock(),
+            &expected.authorization,
+        );
+
+        let failure = panic::catch_unwind(|| {
+            assert_authorization(
+                &mut request(),
+                &example_credentials(),
+                &clock(),
+                "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request",
+            )
+        })
+        .unwrap_err();
+        let message = failure.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("the signature of POST / isn't the one of the fixture"));
+        assert!(message.contains(&expected.canonical_request));
+        assert!(message.contains(&expected.string_to_sign));
+    }
+}
@@ -0,0 +1,297 @@
+//! Pre-flight validation of requests against the constraints of the service model.
+//!
+//! Request types generated from a service model implement `Validate` when the model
+//! constrains the length, range or pattern of any of their fields. Validation reports
+//! every violation rather than stopping at the first one, each under the path of the
+//! offending field, for example `key_schema[1].attribute_name`.
+//!
+//! Clients always check length constraints, like the most items in a batch, before
+//! sending a request. Range and pattern constraints are only checked by clients created
+//! with `Client::validate_requests(true)`.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::Mutex;
+
+use regex::Regex;
+
+lazy_static! {
+    static ref PATTERNS: Mutex<HashMap<&'static str, Option<Regex>>> = Mutex::new(HashMap::new());
+}
+
+/// A field breaking a constraint of the service model.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    /// The path of the field, starting at the request.
+    pub field: String,
+    /// The constraint the field breaks.
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Every constraint of the service model broken by a request.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationErrors {
+    errors: Vec<ValidationError>,
+    lengths_only: bool,
+}
+
+impl ValidationErrors {
+    /// Collects violations of length constraints only, ignoring ranges and patterns.
+    pub fn lengths_only() -> Self {
+        ValidationErrors {
+            errors: Vec::new(),
+            lengths_only: true,
+        }
+    }
+
+    /// The violations, in the order the fields were checked.
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    /// Whether no constraint was broken.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Records that `field` breaks a constraint.
+    pub fn add<F: Into<String>, M: Into<String>>(&mut self, field: F, message: M) {
+        self.errors.push(ValidationError {
+            field: field.into(),
+            message: message.into(),
+        });
+    }
+
+    /// Checks that a string, blob, list or map of length `len` is within bounds.
+    pub fn check_length(
+        &mut self,
+        field: &str,
+        len: usize,
+        min: Option<usize>,
+        max: Option<usize>,
+    ) {
+        if let Some(min) = min {
+            if len < min {
+                self.add(
+                    field,
+                    format!("length {} is less than the minimum of {}", len, min),
+                );
+            }
+        }
+        if let Some(max) = max {
+            if len > max {
+                self.add(
+                    field,
+                    format!("length {} is greater than the maximum of {}", len, max),
+                );
+            }
+        }
+    }
+
+    /// Checks that a number is within bounds.
+    pub fn check_range<T>(&mut self, field: &str, value: T, min: Option<T>, max: Option<T>)
+    where
+        T: PartialOrd + fmt::Display,
+    {
+        if self.lengths_only {
+            return;
+        }
+        if let Some(min) = min {
+            if value < min {
+                self.add(
+                    field,
+                    format!("value {} is less than the minimum of {}", value, min),
+                );
+            }
+        }
+        if let Some(max) = max {
+            if value > max {
+                self.add(
+                    field,
+                    format!("value {} is greater than the maximum of {}", value, max),
+                );
+            }
+        }
+    }
+
+    /// Checks that a string matches a pattern of the service model.
+    ///
+    /// As in the service model, the pattern isn't anchored: it must match some part of
+    /// the string. Patterns using syntax the `regex` crate doesn't support, such as
+    /// look-around, are not checked.
+    pub fn check_pattern(&mut self, field: &str, value: &str, pattern: &'static str) {
+        if self.lengths_only {
+            return;
+        }
+        let mut patterns = PATTERNS.lock().unwrap();
+        let regex = patterns
+            .entry(pattern)
+            .or_insert_with(|| Regex::new(pattern).ok());
+        if let Some(ref regex) = *regex {
+            if !regex.is_match(value) {
+                self.add(
+                    field,
+                    format!("value does not match the pattern {}", pattern),
+                );
+            }
+        }
+    }
+
+    /// `Ok` if no constraint was broken, otherwise every violation.
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ValidationErrors {
+    fn description(&self) -> &str {
+        "request breaks constraints of the service model"
+    }
+}
+
+/// Joins the path of a structure and the name of one of its fields.
+pub fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_owned()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+/// A request, or part of a request, constrained by the service model.
+pub trait Validate {
+    /// Checks the constraints of the service model, recording violations under `path`.
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors);
+
+    /// Checks the constraints of the service model, returning every violation.
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        self.validate_at("", &mut errors);
+        errors.into_result()
+    }
+
+    /// Checks the length constraints of the service model only, such as the most items
+    /// in a batch, returning every violation.
+    fn validate_lengths(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::lengths_only();
+        self.validate_at("", &mut errors);
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Inner {
+        name: String,
+    }
+
+    impl Validate for Inner {
+        fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+            errors.check_length(
+                &field_path(path, "name"),
+                self.name.chars().count(),
+                Some(3),
+                Some(5),
+            );
+            errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-z]+$");
+        }
+    }
+
+    struct Outer {
+        limit: Option<i64>,
+        items: Vec<Inner>,
+    }
+
+    impl Validate for Outer {
+        fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+            if let Some(ref limit) = self.limit {
+                errors.check_range(&field_path(path, "limit"), *limit, Some(1), Some(100));
+            }
+            for (i, item) in self.items.iter().enumerate() {
+                item.validate_at(&format!("{}[{}]", field_path(path, "items"), i), errors);
+            }
+        }
+    }
+
+    #[test]
+    fn valid_requests_pass() {
+        let request = Outer {
+            limit: Some(100),
+            items: vec![Inner {
+                name: "abc".to_owned(),
+            }],
+        };
+        assert_eq!(request.validate(), Ok(()));
+    }
+
+    #[test]
+    fn reports_every_violation_with_its_path() {
+        let request = Outer {
+            limit: Some(0),
+            items: vec![
+                Inner {
+                    name: "abc".to_owned(),
+                },
+                Inner {
+                    name: "AB".to_owned(),
+                },
+            ],
+        };
+        let errors = request.validate().unwrap_err();
+        let fields: Vec<&str> = errors.errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["limit", "items[1].name", "items[1].name"]);
+        assert_eq!(
+            errors.to_string(),
+            "limit: value 0 is less than the minimum of 1; \
+             items[1].name: length 2 is less than the minimum of 3; \
+             items[1].name: value does not match the pattern ^[a-z]+$"
+        );
+    }
+
+    #[test]
+    fn lengths_only_ignores_ranges_and_patterns() {
+        let request = Outer {
+            limit: Some(0),
+            items: vec![Inner {
+                name: "ABCDEF".to_owned(),
+            }],
+        };
+        let errors = request.validate_lengths().unwrap_err();
+        assert_eq!(
+            errors.to_string(),
+            "items[0].name: length 6 is greater than the maximum of 5"
+        );
+    }
+
+    #[test]
+    fn unsupported_patterns_are_not_checked() {
+        let mut errors = ValidationErrors::default();
+        errors.check_pattern("name", "anything", "^(?!foo).*$");
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,78 @@
+//! Sources of the current time.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, Utc};
+
+/// A source of the current time, used to check whether credentials have expired and to
+/// date signed requests.
+///
+/// The system clock is used by default. Time-dependent code can be tested without waiting
+/// by giving it a [`ManualClock`](struct.ManualClock.html) instead.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// The current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The clock of the system.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock which only moves when told to, for tests.
+///
+/// Clones share their time, so a clone kept by a test moves the clock given to the code
+/// under test.
+///
+/// ```rust
+/// # extern crate chrono;
+/// # extern crate rusoto_credential;
+/// use chrono::{DateTime, Duration, Utc};
+/// use rusoto_credential::{Clock, ManualClock};
+///
+/// let noon: DateTime<Utc> = "2019-01-01T12:00:00Z".parse().unwrap();
+/// let clock = ManualClock::new(noon);
+/// clock.advance(Duration::minutes(5));
+/// assert_eq!(clock.now(), noon + Duration::minutes(5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ManualClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl ManualClock {
+    /// A clock showing `now`.
+    pub fn new(now: DateTime<Utc>) -> ManualClock {
+        ManualClock {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Sets the time of the clock.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> DateTime<Utc> {
+        C::now(&**self)
+    }
+}
@@ -1,11 +1,13 @@
 //! The Credentials provider to read from a task's IAM Role.
 
 use std::error::Error;
-use std::time::Duration;
+use std::fs;
+use std::time::{Duration, Instant};
 
 use futures::future::{err, FutureResult};
 use futures::{Async, Future, Poll};
-use hyper::{Body, Request};
+use hyper::{Body, Request, Uri};
+use tokio_timer::Delay;
 
 use crate::request::{HttpClient, HttpClientFuture};
 use crate::{
@@ -21,6 +23,23 @@ const AWS_CONTAINER_CREDENTIALS_RELATIVE_URI: &str = "AWS_CONTAINER_CREDENTIALS_
 // environment variables, but they are used by the Java, Go, JavaScript and the Python SDKs.
 const AWS_CONTAINER_CREDENTIALS_FULL_URI: &str = "AWS_CONTAINER_CREDENTIALS_FULL_URI";
 const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOKEN";
+// Used by EKS Pod Identity, which rotates the token in the file.
+const AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE: &str = "AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE";
+
+// Hosts which full URIs may use plain HTTP with: the loopback addresses, and the
+// addresses of the ECS and EKS credentials agents.
+const ALLOWED_HTTP_HOSTS: &[&str] = &[
+    "localhost",
+    "::1",
+    "169.254.170.2",
+    "169.254.170.23",
+    "fd00:ec2::23",
+];
+
+// How often failed requests to the agent are tried again, and how long to wait before
+// the first retry. The delay doubles with each retry.
+const MAX_RETRIES: u32 = 2;
+const RETRY_DELAY: Duration = Duration::from_millis(100);
 
 /// Provides AWS credentials from a task's IAM role.
 ///
@@ -33,8 +52,13 @@ const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOK
 /// URL stored in the environment variable ```AWS_CONTAINER_CREDENTIALS_RELATIVE_URI```, which will
 /// be used to obtain the AWS credentials. If that environment variable is not set, rusoto will use
 /// the URL set in environment variable ```AWS_CONTAINER_CREDENTIALS_FULL_URI``` to obtain AWS
-/// credentials and will (optionally) also set the ```Authorization``` header to the value of
-/// environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN```.
+/// credentials and will (optionally) also set the ```Authorization``` header to the contents of
+/// the file named by environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE```, or else
+/// to the value of environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN```. The full URL
+/// must use HTTPS, unless its host is a loopback address or that of the ECS or EKS credentials
+/// agent.
+///
+/// Failed requests are tried again twice, after a short delay.
 ///
 /// # Example
 ///
@@ -57,6 +81,7 @@ const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOK
 pub struct ContainerProvider {
     client: HttpClient,
     timeout: Duration,
+    relative_uri_host: String,
 }
 
 impl ContainerProvider {
@@ -65,6 +90,7 @@ impl ContainerProvider {
         ContainerProvider {
             client: HttpClient::new(),
             timeout: Duration::from_secs(30),
+            relative_uri_host: AWS_CREDENTIALS_PROVIDER_IP.to_owned(),
         }
     }
 
@@ -84,11 +110,24 @@ impl Default for ContainerProvider {
 /// Future returned from `ContainerProvider`.
 pub struct ContainerProviderFuture {
     inner: ContainerProviderFutureInner,
+    provider: ContainerProvider,
+    retries: u32,
 }
 
 enum ContainerProviderFutureInner {
     Result(FutureResult<String, CredentialsError>),
     Future(HttpClientFuture),
+    Backoff(Delay),
+}
+
+impl ContainerProviderFuture {
+    fn new(provider: ContainerProvider) -> ContainerProviderFuture {
+        ContainerProviderFuture {
+            inner: request_credentials(&provider),
+            provider,
+            retries: 0,
+        }
+    }
 }
 
 impl Future for ContainerProviderFuture {
@@ -96,12 +135,33 @@ impl Future for ContainerProviderFuture {
     type Error = CredentialsError;
 
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
-        let resp = match self.inner {
-            ContainerProviderFutureInner::Result(ref mut result) => try_ready!(result.poll()),
-            ContainerProviderFutureInner::Future(ref mut future) => try_ready!(future.poll()),
-        };
-        let creds = parse_credentials_from_aws_service(&resp)?;
-        Ok(Async::Ready(creds))
+        loop {
+            let next = match self.inner {
+                ContainerProviderFutureInner::Result(ref mut result) => {
+                    let resp = try_ready!(result.poll());
+                    return Ok(Async::Ready(parse_credentials_from_aws_service(&resp)?));
+                }
+                ContainerProviderFutureInner::Future(ref mut future) => match future.poll() {
+                    Ok(Async::Ready(resp)) => {
+                        return Ok(Async::Ready(parse_credentials_from_aws_service(&resp)?));
+                    }
+                    Ok(Async::NotReady) => return Ok(Async::NotReady),
+                    Err(error) => {
+                        if self.retries >= MAX_RETRIES {
+                            return Err(error);
+                        }
+                        let delay = RETRY_DELAY * 2u32.pow(self.retries);
+                        self.retries += 1;
+                        ContainerProviderFutureInner::Backoff(Delay::new(Instant::now() + delay))
+                    }
+                },
+                ContainerProviderFutureInner::Backoff(ref mut delay) => {
+                    try_ready!(delay.poll().map_err(CredentialsError::new));
+                    request_credentials(&self.provider)
+                }
+            };
+            self.inner = next;
+        }
     }
 }
 
@@ -109,31 +169,32 @@ impl ProvideAwsCredentials for ContainerProvider {
     type Future = ContainerProviderFuture;
 
     fn credentials(&self) -> Self::Future {
-        let inner = match credentials_from_container(&self.client, self.timeout) {
-            Ok(future) => ContainerProviderFutureInner::Future(future),
-            Err(e) => ContainerProviderFutureInner::Result(err(e)),
-        };
-        ContainerProviderFuture { inner }
+        ContainerProviderFuture::new(self.clone())
     }
 }
 
-/// Grabs the Credentials from the AWS Container Credentials Provider. (169.254.170.2).
-fn credentials_from_container(
-    client: &HttpClient,
-    timeout: Duration,
-) -> Result<HttpClientFuture, CredentialsError> {
-    Ok(client.request(request_from_env_vars()?, timeout))
+/// Requests the Credentials from the AWS Container Credentials Provider. (169.254.170.2).
+///
+/// The environment is read again for each attempt, as the token file may have been rotated.
+fn request_credentials(provider: &ContainerProvider) -> ContainerProviderFutureInner {
+    match request_from_env_vars(&provider.relative_uri_host) {
+        Ok(request) => {
+            ContainerProviderFutureInner::Future(provider.client.request(request, provider.timeout))
+        }
+        Err(e) => ContainerProviderFutureInner::Result(err(e)),
+    }
 }
 
-fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
+fn request_from_env_vars(relative_uri_host: &str) -> Result<Request<Body>, CredentialsError> {
     let relative_uri = non_empty_env_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI)
-        .map(|path| format!("http://{}{}", AWS_CREDENTIALS_PROVIDER_IP, path));
+        .map(|path| format!("http://{}{}", relative_uri_host, path));
     match relative_uri {
         Some(ref uri) => new_request(uri, AWS_CONTAINER_CREDENTIALS_RELATIVE_URI),
         None => match non_empty_env_var(AWS_CONTAINER_CREDENTIALS_FULL_URI) {
             Some(ref uri) => {
+                check_full_uri(uri)?;
                 let mut request = new_request(uri, AWS_CONTAINER_CREDENTIALS_FULL_URI)?;
-                if let Some(token) = non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN) {
+                if let Some(token) = authorization_token()? {
                     match token.parse() {
                         Ok(parsed_token) => {
                             request.headers_mut().insert("authorization", parsed_token);
@@ -156,6 +217,46 @@ fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
     }
 }
 
+/// Checks that credentials are only sent over plain HTTP to hosts which can't be reached
+/// through the network.
+fn check_full_uri(uri: &str) -> Result<(), CredentialsError> {
+    // parsing errors are reported by `new_request`, along with the environment variable
+    let parsed = match uri.parse::<Uri>() {
+        Ok(parsed) => parsed,
+        Err(_) => return Ok(()),
+    };
+    if parsed.scheme_part().map(|scheme| scheme.as_str()) == Some("https") {
+        return Ok(());
+    }
+    let host = parsed
+        .host()
+        .unwrap_or_default()
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    if host.starts_with("127.") || ALLOWED_HTTP_HOSTS.contains(&host) {
+        return Ok(());
+    }
+    Err(CredentialsError::new(format!(
+        "The URI '{}' set in environment variable '{}' must use HTTPS, or a loopback or container agent host",
+        uri, AWS_CONTAINER_CREDENTIALS_FULL_URI
+    )))
+}
+
+/// The token to send in the `Authorization` header, read from the token file if one is set.
+fn authorization_token() -> Result<Option<String>, CredentialsError> {
+    match non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE) {
+        Some(path) => fs::read_to_string(&path)
+            .map(|token| Some(token.trim().to_owned()))
+            .map_err(|error| {
+                CredentialsError::new(format!(
+                    "Couldn't read the token file '{}' set in environment variable '{}': {}",
+                    path, AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE, error
+                ))
+            }),
+        None => Ok(non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN)),
+    }
+}
+
 fn new_request(uri: &str, env_var_name: &str) -> Result<Request<Body>, CredentialsError> {
     Request::get(uri).body(Body::empty()).map_err(|error| {
         CredentialsError::new(format!(
@@ -172,6 +273,12 @@ mod tests {
     use super::*;
     use crate::test_utils::lock_env;
     use std::env;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    const CREDENTIALS: &str = r#"{"AccessKeyId":"container_access_key","SecretAccessKey":"container_secret_key","Token":"container_token","Expiration":"2038-01-01T00:00:00Z"}"#;
 
     #[test]
     fn request_from_relative_uri() {
@@ -180,7 +287,7 @@ mod tests {
         env::set_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI, path);
         env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, "dummy");
         env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "dummy");
-        let result = request_from_env_vars();
+        let result = request_from_env_vars(AWS_CREDENTIALS_PROVIDER_IP);
         env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
         env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
         env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
@@ -195,7 +302,7 @@ mod tests {
         let _guard = lock_env();
         env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
         env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
-        let result = request_from_env_vars();
+        let result = request_from_env_vars(AWS_CREDENTIALS_PROVIDER_IP);
         assert!(result.is_err());
     }
 
@@ -205,7 +312,7 @@ mod tests {
         env::set_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI, "");
         env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, "");
         env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "");
-        let result = request_from_env_vars();
+        let result = request_from_env_vars(AWS_CREDENTIALS_PROVIDER_IP);
         env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
         env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
         env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
@@ -219,7 +326,7 @@ mod tests {
         env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
         env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
         env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "dummy");
-        let result = request_from_env_vars();
+        let result = request_from_env_vars(AWS_CREDENTIALS_PROVIDER_IP);
         env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
         env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
         assert!(result.is_ok());
@@ -235,7 +342,7 @@ mod tests {
         env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
         env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
         env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
-        let result = request_from_env_vars();
+        let result = request_from_env_vars(AWS_CREDENTIALS_PROVIDER_IP);
         env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
         assert!(result.is_ok());
         let request = result.ok().unwrap();
@@ -250,7 +357,7 @@ mod tests {
         env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
         env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
         env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "");
-        let result = request_from_env_vars();
+        let result = request_from_env_vars(AWS_CREDENTIALS_PROVIDER_IP);
         env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
         env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
         assert!(result.is_ok());
@@ -258,4 +365,180 @@ mod tests {
         assert_eq!(request.uri().to_string(), url);
         assert_eq!(request.headers().contains_key("authorization"), false);
     }
+
+    fn clear_env_vars() {
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE);
+    }
+
+    /// Answers requests with the given statuses in order, with `CREDENTIALS` as the body of
+    /// successful responses. Returns the address of the server, and the heads of the requests
+    /// it received.
+    fn serve(statuses: &'static [u16]) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = requests.clone();
+        thread::spawn(move || {
+            for status in statuses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !String::from_utf8_lossy(&request).contains("\r\n\r\n") {
+                    let read = stream.read(&mut buf).unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..read]);
+                }
+                received
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&request).into_owned());
+                let body = if *status == 200 { CREDENTIALS } else { "" };
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (addr.to_string(), requests)
+    }
+
+    fn credentials_from(provider: &ContainerProvider) -> Result<AwsCredentials, CredentialsError> {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(provider.credentials())
+    }
+
+    #[test]
+    fn credentials_from_relative_uri() {
+        let (addr, requests) = serve(&[200]);
+        let provider = ContainerProvider {
+            relative_uri_host: addr,
+            ..ContainerProvider::new()
+        };
+        let _guard = lock_env();
+        clear_env_vars();
+        env::set_var(
+            AWS_CONTAINER_CREDENTIALS_RELATIVE_URI,
+            "/v2/credentials/task",
+        );
+        let credentials = credentials_from(&provider);
+        clear_env_vars();
+
+        let credentials = credentials.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "container_access_key");
+        assert_eq!(credentials.token(), &Some("container_token".to_owned()));
+        assert_eq!(
+            credentials.expires_at().map(|e| e.to_rfc3339()),
+            Some("2038-01-01T00:00:00+00:00".to_owned())
+        );
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /v2/credentials/task HTTP/1.1\r\n"));
+        assert!(!requests[0].contains("authorization"));
+    }
+
+    #[test]
+    fn credentials_from_full_uri_with_token() {
+        let (addr, requests) = serve(&[200]);
+        let _guard = lock_env();
+        clear_env_vars();
+        env::set_var(
+            AWS_CONTAINER_CREDENTIALS_FULL_URI,
+            format!("http://{}/credentials", addr),
+        );
+        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "Basic dG9rZW4=");
+        let credentials = credentials_from(&ContainerProvider::new());
+        clear_env_vars();
+
+        assert_eq!(
+            credentials.unwrap().aws_access_key_id(),
+            "container_access_key"
+        );
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /credentials HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nauthorization: Basic dG9rZW4=\r\n"));
+    }
+
+    #[test]
+    fn token_file_takes_precedence_over_token() {
+        let path = env::temp_dir().join(format!("rusoto-container-token-{}", std::process::id()));
+        fs::write(&path, "file_token\n").unwrap();
+        let _guard = lock_env();
+        clear_env_vars();
+        env::set_var(
+            AWS_CONTAINER_CREDENTIALS_FULL_URI,
+            "http://169.254.170.23/v1/credentials",
+        );
+        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "env_token");
+        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE, &path);
+        let result = request_from_env_vars(AWS_CREDENTIALS_PROVIDER_IP);
+        clear_env_vars();
+        fs::remove_file(&path).unwrap();
+
+        let request = result.unwrap();
+        assert_eq!(request.headers()["authorization"], "file_token");
+    }
+
+    #[test]
+    fn full_uri_must_use_https_or_a_local_host() {
+        for uri in &[
+            "https://credentials.example.com/",
+            "http://127.0.0.1:8080/credentials",
+            "http://localhost/credentials",
+            "http://[::1]/credentials",
+            "http://169.254.170.2/credentials",
+            "http://[fd00:ec2::23]/credentials",
+        ] {
+            assert_eq!(check_full_uri(uri), Ok(()), "{}", uri);
+        }
+        for uri in &[
+            "http://credentials.example.com/",
+            "http://10.0.0.1/credentials",
+            "http://localhost.example.com/credentials",
+        ] {
+            assert!(check_full_uri(uri).is_err(), "{}", uri);
+        }
+    }
+
+    #[test]
+    fn transient_failures_are_retried() {
+        let (addr, requests) = serve(&[500, 503, 200]);
+        let _guard = lock_env();
+        clear_env_vars();
+        env::set_var(
+            AWS_CONTAINER_CREDENTIALS_FULL_URI,
+            format!("http://{}/credentials", addr),
+        );
+        let credentials = credentials_from(&ContainerProvider::new());
+        clear_env_vars();
+
+        assert!(credentials.is_ok());
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn failures_are_retried_twice() {
+        let (addr, requests) = serve(&[500, 500, 500, 200]);
+        let _guard = lock_env();
+        clear_env_vars();
+        env::set_var(
+            AWS_CONTAINER_CREDENTIALS_FULL_URI,
+            format!("http://{}/credentials", addr),
+        );
+        let credentials = credentials_from(&ContainerProvider::new());
+        clear_env_vars();
+
+        assert_eq!(
+            credentials.unwrap_err().message,
+            "Invalid Response Code: 500 Internal Server Error"
+        );
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
 }
@@ -0,0 +1,276 @@
+//! The Credentials Provider to read from a file of any format, such as a mounted secret.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use futures::future::{result, FutureResult};
+use futures::{Future, Poll};
+
+use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+type Parser = Arc<dyn Fn(&[u8]) -> Result<AwsCredentials, CredentialsError> + Send + Sync>;
+
+/// Provides AWS credentials from a file, re-reading it whenever it changes.
+///
+/// The file is parsed as JSON by default:
+///
+/// ```json
+/// {
+///     "AccessKeyId": "ANTN35UAENTS5UIAEATD",
+///     "SecretAccessKey": "TtnuieannGt2rGuie2t8Tt7urarg5nauedRndrur",
+///     "SessionToken": "DfnGs8Td4rT8r4srxAg6Td4rT8r4srxAg6GtkTir",
+///     "Expiration": "2018-04-21T01:13:02Z"
+/// }
+/// ```
+///
+/// `SessionToken`, which may also be named `Token`, and `Expiration`, in RFC 3339 format,
+/// are optional. Files of other formats are read with `with_parser`.
+///
+/// The parsed credentials are kept until the modification time or the size of the file
+/// changes, so a file which is replaced, as Kubernetes does with mounted secrets, is read
+/// again on the next call to `credentials` while an unchanged one is only looked at.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate rusoto_credential;
+/// #
+/// # fn main() {
+/// use futures::future::Future;
+/// use rusoto_credential::{FileProvider, ProvideAwsCredentials};
+/// use std::fs;
+///
+/// let path = std::env::temp_dir().join("rusoto-file-provider-example.json");
+/// fs::write(
+///     &path,
+///     r#"{"AccessKeyId": "ANTN35UAENTS5UIAEATD", "SecretAccessKey": "TtnuieannGt2rGuie2t8Tt7urarg5nauedRndrur"}"#,
+/// )
+/// .unwrap();
+///
+/// let creds = FileProvider::new(&path).credentials().wait().unwrap();
+///
+/// assert_eq!(creds.aws_access_key_id(), "ANTN35UAENTS5UIAEATD");
+/// assert!(creds.token().is_none());
+/// # fs::remove_file(&path).unwrap();
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FileProvider {
+    path: PathBuf,
+    parser: Parser,
+    cache: Arc<Mutex<Option<CachedFile>>>,
+}
+
+/// The credentials of the file as it was last read.
+struct CachedFile {
+    modified: SystemTime,
+    len: u64,
+    credentials: AwsCredentials,
+}
+
+impl FileProvider {
+    /// Create a FileProvider reading credentials from the JSON file at `path`.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        FileProvider {
+            path: path.as_ref().to_owned(),
+            parser: Arc::new(|contents: &[u8]| {
+                serde_json::from_slice(contents).map_err(CredentialsError::new)
+            }),
+            cache: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Parse the contents of the file with `parser` instead of as JSON.
+    ///
+    /// ```rust
+    /// # use rusoto_credential::{AwsCredentials, FileProvider};
+    /// // a file of two lines, the access key ID and the secret access key
+    /// let provider = FileProvider::new("/var/run/secrets/aws").with_parser(|contents| {
+    ///     let contents = String::from_utf8_lossy(contents);
+    ///     let mut lines = contents.lines();
+    ///     AwsCredentials::builder()
+    ///         .access_key(lines.next().unwrap_or_default())
+    ///         .secret_key(lines.next().unwrap_or_default())
+    ///         .build()
+    /// });
+    /// ```
+    pub fn with_parser<F>(mut self, parser: F) -> Self
+    where
+        F: Fn(&[u8]) -> Result<AwsCredentials, CredentialsError> + Send + Sync + 'static,
+    {
+        self.parser = Arc::new(parser);
+        self
+    }
+
+    /// Get a reference to the path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read(&self) -> Result<AwsCredentials, CredentialsError> {
+        let metadata = fs::metadata(&self.path).map_err(|err| self.error(err))?;
+        let modified = metadata.modified().map_err(|err| self.error(err))?;
+        let mut cache = self.cache.lock().expect("file provider cache poisoned");
+        if let Some(ref cached) = *cache {
+            if cached.modified == modified && cached.len == metadata.len() {
+                return Ok(cached.credentials.clone());
+            }
+        }
+
+        let contents = fs::read(&self.path).map_err(|err| self.error(err))?;
+        let credentials = (self.parser)(&contents).map_err(|err| self.error(err))?;
+        *cache = Some(CachedFile {
+            modified,
+            len: metadata.len(),
+            credentials: credentials.clone(),
+        });
+        Ok(credentials)
+    }
+
+    fn error<E: fmt::Display>(&self, err: E) -> CredentialsError {
+        CredentialsError::new(format!(
+            "Couldn't read credentials from {}: {}",
+            self.path.display(),
+            err
+        ))
+    }
+}
+
+impl fmt::Debug for FileProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FileProvider")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+/// Provides AWS credentials from a file as a Future.
+pub struct FileProviderFuture {
+    inner: FutureResult<AwsCredentials, CredentialsError>,
+}
+
+impl Future for FileProviderFuture {
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+impl ProvideAwsCredentials for FileProvider {
+    type Future = FileProviderFuture;
+
+    fn credentials(&self) -> Self::Future {
+        FileProviderFuture {
+            inner: result(self.read()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::test_utils::{is_secret_hidden_behind_asterisks, SECRET};
+
+    /// A file in the temporary directory, removed when dropped.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, contents: &str) -> TempFile {
+            let path = std::env::temp_dir().join(format!(
+                "rusoto-file-provider-{}-{}",
+                std::process::id(),
+                name
+            ));
+            fs::write(&path, contents).unwrap();
+            TempFile(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn reads_json_credentials() {
+        let file = TempFile::new(
+            "json",
+            &format!(
+                r#"{{"AccessKeyId": "key", "SecretAccessKey": "{}", "Token": "token", "Expiration": "2018-04-21T01:13:02Z"}}"#,
+                SECRET
+            ),
+        );
+
+        let creds = FileProvider::new(&file.0).credentials().wait().unwrap();
+
+        assert_eq!(creds.aws_access_key_id(), "key");
+        assert_eq!(creds.aws_secret_access_key(), SECRET);
+        assert_eq!(creds.token(), &Some("token".to_owned()));
+        assert_eq!(
+            creds.expires_at().unwrap().to_rfc3339(),
+            "2018-04-21T01:13:02+00:00"
+        );
+    }
+
+    #[test]
+    fn rereads_the_file_only_once_it_changed() {
+        let file = TempFile::new("changed", "first-key");
+        let parsed = Arc::new(AtomicUsize::new(0));
+        let counter = parsed.clone();
+        let provider = FileProvider::new(&file.0).with_parser(move |contents| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            AwsCredentials::builder()
+                .access_key(String::from_utf8_lossy(contents))
+                .secret_key(SECRET)
+                .build()
+        });
+
+        let creds = provider.credentials().wait().unwrap();
+        assert_eq!(creds.aws_access_key_id(), "first-key");
+        provider.credentials().wait().unwrap();
+        assert_eq!(parsed.load(Ordering::SeqCst), 1);
+
+        fs::write(&file.0, "second-key-id").unwrap();
+        let creds = provider.credentials().wait().unwrap();
+        assert_eq!(creds.aws_access_key_id(), "second-key-id");
+        assert_eq!(parsed.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn names_the_file_in_errors() {
+        let file = TempFile::new("invalid", "{}");
+        let provider = FileProvider::new(&file.0);
+
+        let err = provider.credentials().wait().unwrap_err();
+        assert!(err.message.contains(&file.0.display().to_string()));
+        assert!(err.message.contains("AccessKeyId"));
+
+        fs::remove_file(&file.0).unwrap();
+        assert!(provider.credentials().wait().is_err());
+    }
+
+    #[test]
+    fn credentials_are_hidden_from_debug() {
+        let file = TempFile::new(
+            "debug",
+            &format!(
+                r#"{{"AccessKeyId": "key", "SecretAccessKey": "{}"}}"#,
+                SECRET
+            ),
+        );
+        let provider = FileProvider::new(&file.0);
+        let creds = provider.credentials().wait().unwrap();
+
+        assert!(is_secret_hidden_behind_asterisks(&creds));
+        assert!(!format!("{:?}", provider).contains(SECRET));
+    }
+}
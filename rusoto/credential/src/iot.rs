@@ -0,0 +1,475 @@
+//! The Credentials provider exchanging the certificate of an AWS IoT device for credentials.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::future::{err, FutureResult};
+use futures::{Async, Future, Poll};
+use hyper::client::connect::Connect;
+use hyper::client::HttpConnector;
+use hyper::{Body, Request};
+use hyper_tls::HttpsConnector;
+use native_tls::{Identity, TlsConnector};
+
+use crate::request::{HttpClient, HttpClientFuture};
+use crate::{
+    AutoRefreshingProvider, AutoRefreshingProviderFuture, AwsCredentials, CredentialsError,
+    ProvideAwsCredentials,
+};
+
+/// The header naming the thing whose certificate authenticates the request.
+const THING_NAME_HEADER: &str = "x-amzn-iot-thingname";
+
+/// Provides AWS credentials from the AWS IoT credentials provider, in exchange for the
+/// X.509 certificate of a device.
+///
+/// As described in the
+/// [AWS IoT developer guide](https://docs.aws.amazon.com/iot/latest/developerguide/authorizing-direct-aws.html),
+/// a device authenticating with its certificate over mutual TLS gets temporary credentials
+/// for the IAM role behind a role alias. The endpoint is specific to the account, of the
+/// form `<prefix>.credentials.iot.<region>.amazonaws.com`, as given by
+/// `aws iot describe-endpoint --endpoint-type iot:CredentialProvider`.
+///
+/// The credentials are cached, and fetched again shortly before they expire.
+///
+/// The provider has a default timeout of 30 seconds. While it should work well for most setups,
+/// you can change the timeout using the `set_timeout` method.
+///
+/// Requires the `iot` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// extern crate rusoto_credential;
+///
+/// use std::fs;
+///
+/// use rusoto_credential::IotCredentialsProvider;
+///
+/// fn main() {
+///   let provider = IotCredentialsProvider::new(
+///       "c2sdcqtxnsmvxz.credentials.iot.us-east-1.amazonaws.com",
+///       "my-role-alias",
+///       "my-thing",
+///       &fs::read("device.pem.crt").unwrap(),
+///       &fs::read("device.pem.key").unwrap(),
+///   )
+///   .unwrap();
+///
+///   // ...
+/// }
+/// ```
+#[derive(Debug)]
+pub struct IotCredentialsProvider<C = HttpsConnector<HttpConnector>>(
+    AutoRefreshingProvider<RoleAliasProvider<C>>,
+)
+where
+    C: Connect + 'static,
+    C::Transport: 'static,
+    C::Future: 'static;
+
+impl IotCredentialsProvider {
+    /// Create a new provider authenticating with the PEM encoded certificate of the device
+    /// and its PKCS #8 private key.
+    pub fn new(
+        endpoint: &str,
+        role_alias: &str,
+        thing_name: &str,
+        certificate: &[u8],
+        private_key: &[u8],
+    ) -> Result<IotCredentialsProvider, CredentialsError> {
+        let tls = Identity::from_pkcs8(certificate, private_key)
+            .and_then(|identity| TlsConnector::builder().identity(identity).build())
+            .map_err(|err| {
+                CredentialsError::new(format!("Invalid device certificate or key: {}", err))
+            })?;
+        let mut http = HttpConnector::new(1);
+        http.enforce_http(false);
+        let mut connector = HttpsConnector::from((http, tls));
+        connector.https_only(true);
+        IotCredentialsProvider::with_connector(endpoint, role_alias, thing_name, connector)
+    }
+}
+
+impl<C> IotCredentialsProvider<C>
+where
+    C: Connect + 'static,
+    C::Transport: 'static,
+    C::Future: 'static,
+{
+    /// Create a new provider making its connections with `connector`, which must present
+    /// the certificate of the device.
+    pub fn with_connector(
+        endpoint: &str,
+        role_alias: &str,
+        thing_name: &str,
+        connector: C,
+    ) -> Result<IotCredentialsProvider<C>, CredentialsError> {
+        let provider = RoleAliasProvider {
+            client: HttpClient::from_connector(connector),
+            uri: format!(
+                "https://{}/role-aliases/{}/credentials",
+                endpoint, role_alias
+            ),
+            thing_name: thing_name.to_owned(),
+            timeout: Duration::from_secs(30),
+        };
+        Ok(IotCredentialsProvider(AutoRefreshingProvider::new(
+            provider,
+        )?))
+    }
+
+    /// Set the timeout on the provider to the specified duration.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.0.get_mut().timeout = timeout;
+    }
+}
+
+impl<C> ProvideAwsCredentials for IotCredentialsProvider<C>
+where
+    C: Connect + 'static,
+    C::Transport: 'static,
+    C::Future: 'static,
+{
+    type Future = IotCredentialsProviderFuture<C>;
+
+    fn credentials(&self) -> Self::Future {
+        IotCredentialsProviderFuture(self.0.credentials())
+    }
+}
+
+/// Future returned from `IotCredentialsProvider`.
+pub struct IotCredentialsProviderFuture<C = HttpsConnector<HttpConnector>>(
+    AutoRefreshingProviderFuture<RoleAliasProvider<C>>,
+)
+where
+    C: Connect + 'static,
+    C::Transport: 'static,
+    C::Future: 'static;
+
+impl<C> Future for IotCredentialsProviderFuture<C>
+where
+    C: Connect + 'static,
+    C::Transport: 'static,
+    C::Future: 'static,
+{
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.0.poll()
+    }
+}
+
+/// Fetches the credentials of a role alias, each time it is asked to.
+#[derive(Debug)]
+struct RoleAliasProvider<C> {
+    client: HttpClient<C>,
+    uri: String,
+    thing_name: String,
+    timeout: Duration,
+}
+
+impl<C> ProvideAwsCredentials for RoleAliasProvider<C>
+where
+    C: Connect + 'static,
+    C::Transport: 'static,
+    C::Future: 'static,
+{
+    type Future = RoleAliasFuture;
+
+    fn credentials(&self) -> Self::Future {
+        let request = Request::get(self.uri.as_str())
+            .header(THING_NAME_HEADER, self.thing_name.as_str())
+            .body(Body::empty());
+        match request {
+            Ok(request) => RoleAliasFuture::Request(self.client.request(request, self.timeout)),
+            Err(error) => RoleAliasFuture::Result(err(CredentialsError::new(format!(
+                "Invalid IoT credentials provider request: {}",
+                error
+            )))),
+        }
+    }
+}
+
+/// Future returned from `RoleAliasProvider`.
+enum RoleAliasFuture {
+    Result(FutureResult<AwsCredentials, CredentialsError>),
+    Request(HttpClientFuture),
+}
+
+impl Future for RoleAliasFuture {
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        match *self {
+            RoleAliasFuture::Result(ref mut result) => result.poll(),
+            RoleAliasFuture::Request(ref mut future) => {
+                let body = try_ready!(future.poll());
+                Ok(Async::Ready(parse_iot_credentials(&body)?))
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct IotCredentialsResponse {
+    credentials: IotCredentials,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IotCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: String,
+    expiration: DateTime<Utc>,
+}
+
+fn parse_iot_credentials(response: &str) -> Result<AwsCredentials, CredentialsError> {
+    let credentials = serde_json::from_str::<IotCredentialsResponse>(response)?.credentials;
+    Ok(AwsCredentials::new(
+        credentials.access_key_id,
+        credentials.secret_access_key,
+        Some(credentials.session_token),
+        Some(credentials.expiration),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use native_tls::Certificate;
+    use openssl::asn1::Asn1Time;
+    use openssl::bn::{BigNum, MsbOption};
+    use openssl::ec::{EcGroup, EcKey};
+    use openssl::hash::MessageDigest;
+    use openssl::nid::Nid;
+    use openssl::pkey::{PKey, Private};
+    use openssl::ssl::{SslAcceptor, SslMethod, SslVerifyMode};
+    use openssl::x509::extension::{BasicConstraints, SubjectAlternativeName};
+    use openssl::x509::{X509NameBuilder, X509};
+
+    const CREDENTIALS: &str = r#"{"credentials":{"accessKeyId":"iot_access_key","secretAccessKey":"iot_secret_key","sessionToken":"iot_token","expiration":"EXPIRATION"}}"#;
+
+    /// A certificate for `name` and its key, signed by `issuer` or else self-signed.
+    fn certificate(name: &str, issuer: Option<&(X509, PKey<Private>)>) -> (X509, PKey<Private>) {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        let key = PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap();
+        let mut subject = X509NameBuilder::new().unwrap();
+        subject.append_entry_by_text("CN", name).unwrap();
+        let subject = subject.build();
+        let mut serial = BigNum::new().unwrap();
+        serial.rand(64, MsbOption::MAYBE_ZERO, false).unwrap();
+
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder
+            .set_serial_number(&serial.to_asn1_integer().unwrap())
+            .unwrap();
+        builder.set_subject_name(&subject).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        match issuer {
+            Some((issuer_cert, issuer_key)) => {
+                builder.set_issuer_name(issuer_cert.subject_name()).unwrap();
+                let san = SubjectAlternativeName::new()
+                    .ip("127.0.0.1")
+                    .build(&builder.x509v3_context(Some(issuer_cert), None))
+                    .unwrap();
+                builder.append_extension(san).unwrap();
+                builder.sign(issuer_key, MessageDigest::sha256()).unwrap();
+            }
+            None => {
+                builder.set_issuer_name(&subject).unwrap();
+                builder
+                    .append_extension(BasicConstraints::new().critical().ca().build().unwrap())
+                    .unwrap();
+                builder.sign(&key, MessageDigest::sha256()).unwrap();
+            }
+        }
+        (builder.build(), key)
+    }
+
+    /// Answers requests over TLS, requiring clients to present a certificate signed by `ca`,
+    /// with credentials expiring at the given times in order. Requests without a thing name
+    /// are answered with a 400. Returns the address of the server, and the heads of the
+    /// requests it received.
+    fn serve(
+        ca: &(X509, PKey<Private>),
+        expirations: &'static [&'static str],
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        let (server_cert, server_key) = certificate("server", Some(ca));
+        let mut acceptor = SslAcceptor::mozilla_intermediate(SslMethod::tls()).unwrap();
+        acceptor.set_certificate(&server_cert).unwrap();
+        acceptor.set_private_key(&server_key).unwrap();
+        acceptor.cert_store_mut().add_cert(ca.0.clone()).unwrap();
+        acceptor.set_verify(SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT);
+        let acceptor = acceptor.build();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = requests.clone();
+        thread::spawn(move || {
+            for expiration in expirations {
+                let (stream, _) = listener.accept().unwrap();
+                let mut stream = match acceptor.accept(stream) {
+                    Ok(stream) => stream,
+                    // clients without a certificate don't get credentials
+                    Err(_) => continue,
+                };
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !String::from_utf8_lossy(&request).contains("\r\n\r\n") {
+                    let read = stream.read(&mut buf).unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..read]);
+                }
+                let request = String::from_utf8_lossy(&request).into_owned();
+                let (status, body) = if request.contains("\r\nx-amzn-iot-thingname: ") {
+                    (200, CREDENTIALS.replace("EXPIRATION", expiration))
+                } else {
+                    (400, String::new())
+                };
+                received.lock().unwrap().push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                stream.shutdown().ok();
+            }
+        });
+        (addr.to_string(), requests)
+    }
+
+    /// A connector presenting `client` and trusting `ca`, as `IotCredentialsProvider::new`
+    /// would with the certificate of a device and the roots of the system.
+    fn connector(
+        ca: &(X509, PKey<Private>),
+        client: Option<&(X509, PKey<Private>)>,
+    ) -> HttpsConnector<HttpConnector> {
+        let mut tls = TlsConnector::builder();
+        tls.add_root_certificate(Certificate::from_der(&ca.0.to_der().unwrap()).unwrap());
+        if let Some((cert, key)) = client {
+            let identity = Identity::from_pkcs8(
+                &cert.to_pem().unwrap(),
+                &key.private_key_to_pem_pkcs8().unwrap(),
+            )
+            .unwrap();
+            tls.identity(identity);
+        }
+        let mut http = HttpConnector::new(1);
+        http.enforce_http(false);
+        HttpsConnector::from((http, tls.build().unwrap()))
+    }
+
+    #[test]
+    fn credentials_are_exchanged_for_the_device_certificate() {
+        let ca = certificate("ca", None);
+        let device = certificate("device", Some(&ca));
+        let (addr, requests) = serve(&ca, &["2038-01-01T00:00:00Z"]);
+        let provider = IotCredentialsProvider::with_connector(
+            &addr,
+            "role",
+            "thing",
+            connector(&ca, Some(&device)),
+        )
+        .unwrap();
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let credentials = runtime.block_on(provider.credentials()).unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "iot_access_key");
+        assert_eq!(credentials.aws_secret_access_key(), "iot_secret_key");
+        assert_eq!(credentials.token(), &Some("iot_token".to_owned()));
+        assert_eq!(
+            credentials.expires_at().map(|e| e.to_rfc3339()),
+            Some("2038-01-01T00:00:00+00:00".to_owned())
+        );
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /role-aliases/role/credentials HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nx-amzn-iot-thingname: thing\r\n"));
+    }
+
+    #[test]
+    fn credentials_are_cached_until_they_expire() {
+        let ca = certificate("ca", None);
+        let device = certificate("device", Some(&ca));
+        let (addr, requests) = serve(&ca, &["2000-01-01T00:00:00Z", "2038-01-01T00:00:00Z"]);
+        let provider = IotCredentialsProvider::with_connector(
+            &addr,
+            "role",
+            "thing",
+            connector(&ca, Some(&device)),
+        )
+        .unwrap();
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let expired = runtime.block_on(provider.credentials()).unwrap();
+        assert_eq!(
+            expired.expires_at().unwrap().to_rfc3339(),
+            "2000-01-01T00:00:00+00:00"
+        );
+        for _ in 0..2 {
+            let credentials = runtime.block_on(provider.credentials()).unwrap();
+            assert_eq!(
+                credentials.expires_at().unwrap().to_rfc3339(),
+                "2038-01-01T00:00:00+00:00"
+            );
+        }
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn devices_without_a_certificate_get_no_credentials() {
+        let ca = certificate("ca", None);
+        let (addr, requests) = serve(&ca, &["2038-01-01T00:00:00Z"]);
+        let provider =
+            IotCredentialsProvider::with_connector(&addr, "role", "thing", connector(&ca, None))
+                .unwrap();
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        assert!(runtime.block_on(provider.credentials()).is_err());
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_certificates_are_reported() {
+        let result = IotCredentialsProvider::new("localhost", "role", "thing", b"cert", b"key");
+        assert!(result
+            .unwrap_err()
+            .message
+            .starts_with("Invalid device certificate or key: "));
+    }
+
+    #[test]
+    fn parse_credentials() {
+        let response = CREDENTIALS.replace("EXPIRATION", "2019-10-07T12:00:00Z");
+        let credentials = parse_iot_credentials(&response).unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "iot_access_key");
+        assert_eq!(
+            credentials.expires_at().map(|e| e.to_rfc3339()),
+            Some("2019-10-07T12:00:00+00:00".to_owned())
+        );
+
+        let missing_token = r#"{"credentials":{"accessKeyId":"a","secretAccessKey":"s","expiration":"2019-10-07T12:00:00Z"}}"#;
+        assert!(parse_iot_credentials(missing_token).is_err());
+    }
+}
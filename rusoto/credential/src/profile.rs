@@ -7,6 +7,7 @@ use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use chrono::{DateTime, FixedOffset, Utc};
 use dirs::home_dir;
 use futures::future::{result, FutureResult};
 use futures::{Future, Poll};
@@ -20,9 +21,21 @@ const AWS_PROFILE: &str = "AWS_PROFILE";
 const AWS_SHARED_CREDENTIALS_FILE: &str = "AWS_SHARED_CREDENTIALS_FILE";
 const DEFAULT: &str = "default";
 const REGION: &str = "region";
+const SSO_SESSION: &str = "sso-session";
 
 /// Provides AWS credentials from a profile in a credentials file, or from a credential process.
 ///
+/// Besides the keys and session token, a profile may give the expiry of temporary credentials
+/// as an RFC 3339 timestamp in `aws_session_expiration`, or in `x_security_token_expires` as
+/// written by tools like saml2aws. Credentials with an expiry are read again from the file once
+/// they expire when the provider is wrapped in an `AutoRefreshingProvider`.
+///
+/// The `credential_process` of the profile in the config file takes precedence over the
+/// credentials file. The command must print the credentials as JSON, and its failures are
+/// reported with what it wrote to stderr. It is run for each call to `credentials`, so it
+/// should also be wrapped in an `AutoRefreshingProvider`, as in the
+/// `DefaultCredentialsProvider`, to run it again only once its credentials expire.
+///
 /// # Warning
 ///
 /// This provider allows the [`credential_process`][credential_process] option, a method of
@@ -89,6 +102,20 @@ impl ProfileProvider {
         })
     }
 
+    /// Reads the AWS SSO settings of the profile from `~/.aws/config` or the file associated
+    /// with the `AWS_CONFIG_FILE` environment variable, or `None` if it doesn't use AWS SSO.
+    ///
+    /// The start URL and region of the SSO portal are read from the `[sso-session]` block
+    /// named by the `sso_session` of the profile, as written by recent versions of the AWS
+    /// CLI, or else from the `sso_start_url` and `sso_region` of the profile itself.
+    pub fn sso_profile(&self) -> Result<Option<SsoProfile>, CredentialsError> {
+        let location = ProfileProvider::default_config_location()?;
+        match parse_config_file(&location) {
+            Some(config) => sso_profile(&config, &self.profile),
+            None => Ok(None),
+        }
+    }
+
     /// Default config file location:
     /// 1: if set and not empty, use the value from environment variable ```AWS_CONFIG_FILE```
     /// 2. otherwise return `~/.aws/config` (Linux/Mac) resp. `%USERPROFILE%\.aws\config` (Windows)
@@ -168,6 +195,60 @@ impl ProfileProvider {
     }
 }
 
+/// The AWS SSO settings of a profile in the config file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SsoProfile {
+    /// The name of the `[sso-session]` block the portal settings were read from, if any.
+    pub session_name: Option<String>,
+    /// The URL of the AWS SSO user portal.
+    pub start_url: String,
+    /// The region of the AWS SSO user portal.
+    pub region: String,
+    /// The account to get the credentials of a role in.
+    pub account_id: String,
+    /// The role to get the credentials of.
+    pub role_name: String,
+}
+
+/// Resolves the AWS SSO settings of `profile`, following its `sso_session` reference.
+fn sso_profile(
+    config: &HashMap<String, HashMap<String, String>>,
+    profile: &str,
+) -> Result<Option<SsoProfile>, CredentialsError> {
+    let props = match config.get(profile) {
+        Some(props) => props,
+        None => return Ok(None),
+    };
+    let session_name = props.get("sso_session");
+    let portal = match session_name {
+        Some(name) => config
+            .get(&format!("{} {}", SSO_SESSION, name))
+            .ok_or_else(|| {
+                CredentialsError::new(format!(
+                    "Profile '{}' refers to the missing sso-session '{}'",
+                    profile, name
+                ))
+            })?,
+        None if props.contains_key("sso_start_url") => props,
+        None => return Ok(None),
+    };
+    let setting = |props: &HashMap<String, String>, key: &str| {
+        props.get(key).cloned().ok_or_else(|| {
+            CredentialsError::new(format!(
+                "Missing {} for the AWS SSO settings of profile '{}'",
+                key, profile
+            ))
+        })
+    };
+    Ok(Some(SsoProfile {
+        session_name: session_name.cloned(),
+        start_url: setting(portal, "sso_start_url")?,
+        region: setting(portal, "sso_region")?,
+        account_id: setting(props, "sso_account_id")?,
+        role_name: setting(props, "sso_role_name")?,
+    }))
+}
+
 /// Provides AWS credentials from a profile in a credentials file as a Future.
 pub struct ProfileProviderFuture {
     inner: ProfileProviderFutureInner,
@@ -191,7 +272,7 @@ impl Future for ProfileProviderFuture {
                     return Err(CredentialsError::new(format!(
                         "Credential process failed with {}: {}",
                         output.status,
-                        String::from_utf8_lossy(&output.stderr)
+                        String::from_utf8_lossy(&output.stderr).trim()
                     )));
                 }
                 Ok(parse_credential_process_output(&output.stdout)?.into())
@@ -334,6 +415,7 @@ fn parse_credentials_file(
     let mut access_key: Option<String> = None;
     let mut secret_key: Option<String> = None;
     let mut token: Option<String> = None;
+    let mut expires_at: Option<DateTime<Utc>> = None;
     let mut profile_name: Option<String> = None;
 
     let file_lines = BufReader::new(&file);
@@ -355,13 +437,14 @@ fn parse_credentials_file(
         if profile_regex.is_match(&unwrapped_line) {
             if let (Some(profile), Some(key), Some(secret)) = (profile_name, access_key, secret_key)
             {
-                let creds = AwsCredentials::new(key, secret, token, None);
+                let creds = AwsCredentials::new(key, secret, token, expires_at);
                 profiles.insert(profile, creds);
             }
 
             access_key = None;
             secret_key = None;
             token = None;
+            expires_at = None;
 
             let caps = profile_regex.captures(&unwrapped_line).unwrap();
             profile_name = Some(caps.get(2).unwrap().as_str().to_string());
@@ -372,26 +455,29 @@ fn parse_credentials_file(
         let lower_case_line = unwrapped_line.to_ascii_lowercase().to_string();
 
         if lower_case_line.contains("aws_access_key_id") && access_key.is_none() {
-            let v: Vec<&str> = unwrapped_line.split('=').collect();
-            if !v.is_empty() {
-                access_key = Some(v[1].trim_matches(' ').to_string());
-            }
+            access_key = property_value(&unwrapped_line);
         } else if lower_case_line.contains("aws_secret_access_key") && secret_key.is_none() {
-            let v: Vec<&str> = unwrapped_line.split('=').collect();
-            if !v.is_empty() {
-                secret_key = Some(v[1].trim_matches(' ').to_string());
-            }
+            secret_key = property_value(&unwrapped_line);
         } else if lower_case_line.contains("aws_session_token") && token.is_none() {
-            let v: Vec<&str> = unwrapped_line.split('=').collect();
-            if !v.is_empty() {
-                token = Some(v[1].trim_matches(' ').to_string());
-            }
+            token = property_value(&unwrapped_line);
         } else if lower_case_line.contains("aws_security_token") {
             if token.is_none() {
-                let v: Vec<&str> = unwrapped_line.split('=').collect();
-                if !v.is_empty() {
-                    token = Some(v[1].trim_matches(' ').to_string());
-                }
+                token = property_value(&unwrapped_line);
+            }
+        } else if lower_case_line.contains("aws_session_expiration")
+            || lower_case_line.contains("x_security_token_expires")
+        {
+            if let Some(value) = property_value(&unwrapped_line) {
+                let expiry = DateTime::<FixedOffset>::parse_from_rfc3339(&value).map_err(|e| {
+                    CredentialsError::new(format!(
+                        "Invalid credentials expiry '{}' in line {} of {:?}: {}",
+                        value,
+                        line_no + 1,
+                        file_path,
+                        e
+                    ))
+                })?;
+                expires_at = Some(expiry.with_timezone(&Utc));
             }
         } else {
             // Ignore unrecognized fields
@@ -400,7 +486,7 @@ fn parse_credentials_file(
     }
 
     if let (Some(profile), Some(key), Some(secret)) = (profile_name, access_key, secret_key) {
-        let creds = AwsCredentials::new(key, secret, token, None);
+        let creds = AwsCredentials::new(key, secret, token, expires_at);
         profiles.insert(profile, creds);
     }
 
@@ -411,6 +497,12 @@ fn parse_credentials_file(
     Ok(profiles)
 }
 
+/// The value of a `key = value` line, which may itself contain `=` like base64 session tokens.
+fn property_value(line: &str) -> Option<String> {
+    line.find('=')
+        .map(|index| line[index + 1..].trim_matches(' ').to_string())
+}
+
 fn parse_command_str(s: &str) -> Result<Command, CredentialsError> {
     let args = shlex::split(s)
         .ok_or_else(|| CredentialsError::new("Unable to parse credential_process value."))?;
@@ -431,7 +523,7 @@ mod tests {
 
     use super::*;
     use crate::test_utils::lock_env;
-    use crate::{CredentialsError, ProvideAwsCredentials};
+    use crate::{AutoRefreshingProvider, CredentialsError, ProvideAwsCredentials};
 
     #[test]
     fn parse_config_file_default_profile() {
@@ -486,6 +578,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sso_profile_reads_portal_from_sso_session() {
+        let config = parse_config_file(Path::new("tests/sample-data/sso_session_config")).unwrap();
+        assert_eq!(
+            super::sso_profile(&config, "dev"),
+            Ok(Some(SsoProfile {
+                session_name: Some("my-sso".to_owned()),
+                start_url: "https://my-sso-portal.awsapps.com/start".to_owned(),
+                region: "us-east-1".to_owned(),
+                account_id: "111122223333".to_owned(),
+                role_name: "SampleRole".to_owned(),
+            }))
+        );
+    }
+
+    #[test]
+    fn sso_profile_reads_inline_portal() {
+        let config = parse_config_file(Path::new("tests/sample-data/sso_session_config")).unwrap();
+        assert_eq!(
+            super::sso_profile(&config, "legacy"),
+            Ok(Some(SsoProfile {
+                session_name: None,
+                start_url: "https://legacy-portal.awsapps.com/start".to_owned(),
+                region: "eu-west-1".to_owned(),
+                account_id: "444455556666".to_owned(),
+                role_name: "LegacyRole".to_owned(),
+            }))
+        );
+    }
+
+    #[test]
+    fn sso_profile_reports_incomplete_settings() {
+        let config = parse_config_file(Path::new("tests/sample-data/sso_session_config")).unwrap();
+        assert_eq!(
+            super::sso_profile(&config, "unknown-session"),
+            Err(CredentialsError::new(
+                "Profile 'unknown-session' refers to the missing sso-session 'no-such-session'"
+            ))
+        );
+        assert_eq!(
+            super::sso_profile(&config, "no-role"),
+            Err(CredentialsError::new(
+                "Missing sso_role_name for the AWS SSO settings of profile 'no-role'"
+            ))
+        );
+        assert_eq!(super::sso_profile(&config, "my-sso"), Ok(None));
+        assert_eq!(super::sso_profile(&config, "not-a-profile"), Ok(None));
+    }
+
+    #[test]
+    fn profile_provider_sso_profile_uses_config_file() {
+        let _guard = lock_env();
+        env::set_var(AWS_CONFIG_FILE, "tests/sample-data/sso_session_config");
+        let provider =
+            ProfileProvider::with_configuration("tests/sample-data/no_credentials", "dev");
+        let sso = provider.sso_profile();
+        env::remove_var(AWS_CONFIG_FILE);
+
+        let sso = sso.unwrap().expect("no AWS SSO settings for profile dev");
+        assert_eq!(sso.start_url, "https://my-sso-portal.awsapps.com/start");
+        assert_eq!(sso.role_name, "SampleRole");
+    }
+
     #[test]
     fn parse_credentials_file_default_profile() {
         let result = super::parse_credentials_file(Path::new(
@@ -542,6 +697,44 @@ mod tests {
         assert_eq!(default_profile.aws_secret_access_key(), "bar");
     }
 
+    #[test]
+    fn parse_temporary_credentials_file() {
+        let profiles = super::parse_credentials_file(Path::new(
+            "tests/sample-data/temporary_profile_credentials",
+        ))
+        .expect("failed to parse temporary_profile_credentials");
+        assert_eq!(profiles.len(), 2);
+
+        let default_profile = profiles
+            .get(DEFAULT)
+            .expect("No default profile in temporary_profile_credentials");
+        assert_eq!(
+            default_profile.token(),
+            &Some("FQoGZXIvYXdzEFoaDK/foo+token==".to_string())
+        );
+        assert_eq!(
+            default_profile.expires_at().map(|e| e.to_rfc3339()),
+            Some("2019-06-01T12:00:00+00:00".to_string())
+        );
+
+        let saml_profile = profiles
+            .get("saml")
+            .expect("No saml profile in temporary_profile_credentials");
+        assert_eq!(saml_profile.token(), &Some("saml_token=".to_string()));
+        assert_eq!(
+            saml_profile.expires_at().map(|e| e.to_rfc3339()),
+            Some("2019-06-01T12:00:00+00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_credentials_file_with_invalid_expiry() {
+        let result = super::parse_credentials_file(Path::new(
+            "tests/sample-data/invalid_expiry_profile_credentials",
+        ));
+        assert!(result.is_err());
+    }
+
     #[test]
     fn profile_provider_happy_path() {
         let _guard = lock_env();
@@ -651,6 +844,48 @@ mod tests {
         env::remove_var(AWS_CONFIG_FILE);
     }
 
+    #[test]
+    fn profile_provider_credential_process_failure() {
+        let _guard = lock_env();
+        env::set_var(
+            AWS_CONFIG_FILE,
+            "tests/sample-data/credential_process_edge_config",
+        );
+        let mut provider = ProfileProvider::new().unwrap();
+        provider.set_profile("failing");
+        let result = provider.credentials().wait();
+        env::remove_var(AWS_CONFIG_FILE);
+
+        let message = result.unwrap_err().message;
+        assert!(message.starts_with("Credential process failed with exit"));
+        assert!(message.ends_with(": the session has expired, run login"));
+    }
+
+    #[test]
+    fn profile_provider_credential_process_is_cached_until_expiry() {
+        let log = env::temp_dir().join(format!("rusoto-credential-process-{}", std::process::id()));
+        let _ = fs::remove_file(&log);
+        let _guard = lock_env();
+        env::set_var(
+            AWS_CONFIG_FILE,
+            "tests/sample-data/credential_process_edge_config",
+        );
+        env::set_var("RUSOTO_CREDENTIAL_PROCESS_LOG", &log);
+        let mut provider = ProfileProvider::new().unwrap();
+        provider.set_profile("counted");
+        let provider = AutoRefreshingProvider::new(provider).unwrap();
+        let first = provider.credentials().wait();
+        let second = provider.credentials().wait();
+        env::remove_var(AWS_CONFIG_FILE);
+        env::remove_var("RUSOTO_CREDENTIAL_PROCESS_LOG");
+        let runs = fs::read_to_string(&log).unwrap();
+        fs::remove_file(&log).unwrap();
+
+        assert_eq!(first.unwrap().aws_access_key_id(), "counted_access_key");
+        assert_eq!(second.unwrap().aws_access_key_id(), "counted_access_key");
+        assert_eq!(runs.lines().count(), 1);
+    }
+
     #[test]
     fn profile_provider_profile_name() {
         let _guard = lock_env();
@@ -5,6 +5,7 @@ use std::time::Duration;
 
 use futures::stream::Concat2;
 use futures::{Async, Future, Poll, Stream};
+use hyper::client::connect::Connect;
 use hyper::client::{HttpConnector, ResponseFuture as HyperResponseFuture};
 use hyper::{Body, Client as HyperClient, Request, Uri};
 use tokio_timer::Timeout;
@@ -84,9 +85,12 @@ impl Future for HttpClientFuture {
 }
 
 /// Http client for use in a credentials provider.
+///
+/// Providers which can't use plain connections, such as those authenticating with a client
+/// certificate, plug in their own connector.
 #[derive(Debug, Clone)]
-pub struct HttpClient {
-    inner: HyperClient<HttpConnector>,
+pub struct HttpClient<C = HttpConnector> {
+    inner: HyperClient<C>,
 }
 
 impl HttpClient {
@@ -96,6 +100,21 @@ impl HttpClient {
             inner: HyperClient::new(),
         }
     }
+}
+
+impl<C> HttpClient<C>
+where
+    C: Connect + 'static,
+    C::Transport: 'static,
+    C::Future: 'static,
+{
+    /// Create an http client making its connections with `connector`.
+    #[cfg(feature = "iot")]
+    pub fn from_connector(connector: C) -> HttpClient<C> {
+        HttpClient {
+            inner: HyperClient::builder().build(connector),
+        }
+    }
 
     pub fn get(&self, uri: Uri, timeout: Duration) -> HttpClientFuture {
         match Request::get(uri).body(Body::empty()) {
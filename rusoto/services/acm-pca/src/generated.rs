@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Contains information about the certificate subject. The certificate can be one issued by your private certificate authority (CA) or it can be your private CA certificate. The <b>Subject</b> field in the certificate identifies the entity that owns or controls the public key in the certificate. The entity can be a user, computer, device, or service. The <b>Subject</b> must contain an X.500 distinguished name (DN). A DN is a sequence of relative distinguished names (RDNs). The RDNs are separated by commas in the certificate. The DN must be unique for each entity, but your private CA can issue more than one certificate with the same DN to the same entity. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -84,9 +85,137 @@ pub struct ASN1Subject {
     pub title: Option<String>,
 }
 
+impl Validate for ASN1Subject {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.common_name {
+            errors.check_length(
+                &field_path(path, "common_name"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.country {
+            errors.check_length(
+                &field_path(path, "country"),
+                value.chars().count(),
+                Some(2),
+                Some(2),
+            );
+            errors.check_pattern(&field_path(path, "country"), &value, "[A-Za-z]{2}");
+        }
+        if let Some(ref value) = self.distinguished_name_qualifier {
+            errors.check_length(
+                &field_path(path, "distinguished_name_qualifier"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "distinguished_name_qualifier"),
+                &value,
+                "[a-zA-Z0-9'()+-.?:/= ]*",
+            );
+        }
+        if let Some(ref value) = self.generation_qualifier {
+            errors.check_length(
+                &field_path(path, "generation_qualifier"),
+                value.chars().count(),
+                None,
+                Some(3),
+            );
+        }
+        if let Some(ref value) = self.given_name {
+            errors.check_length(
+                &field_path(path, "given_name"),
+                value.chars().count(),
+                None,
+                Some(16),
+            );
+        }
+        if let Some(ref value) = self.initials {
+            errors.check_length(
+                &field_path(path, "initials"),
+                value.chars().count(),
+                None,
+                Some(5),
+            );
+        }
+        if let Some(ref value) = self.locality {
+            errors.check_length(
+                &field_path(path, "locality"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.organization {
+            errors.check_length(
+                &field_path(path, "organization"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.organizational_unit {
+            errors.check_length(
+                &field_path(path, "organizational_unit"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.pseudonym {
+            errors.check_length(
+                &field_path(path, "pseudonym"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.serial_number {
+            errors.check_length(
+                &field_path(path, "serial_number"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "serial_number"),
+                &value,
+                "[a-zA-Z0-9'()+-.?:/= ]*",
+            );
+        }
+        if let Some(ref value) = self.state {
+            errors.check_length(
+                &field_path(path, "state"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.surname {
+            errors.check_length(
+                &field_path(path, "surname"),
+                value.chars().count(),
+                None,
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.title {
+            errors.check_length(
+                &field_path(path, "title"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+    }
+}
+
 /// <p>Contains information about your private certificate authority (CA). Your private CA can issue and revoke X.509 digital certificates. Digital certificates verify that the entity named in the certificate <b>Subject</b> field owns or controls the public key contained in the <b>Subject Public Key Info</b> field. Call the <a>CreateCertificateAuthority</a> action to create your private CA. You must then call the <a>GetCertificateAuthorityCertificate</a> action to retrieve a private CA certificate signing request (CSR). Sign the CSR with your ACM Private CA-hosted or on-premises root or subordinate CA certificate. Call the <a>ImportCertificateAuthorityCertificate</a> action to import the signed certificate into AWS Certificate Manager (ACM). </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CertificateAuthority {
     /// <p>Amazon Resource Name (ARN) for your private certificate authority (CA). The format is <code> <i>12345678-1234-1234-1234-123456789012</i> </code>.</p>
     #[serde(rename = "Arn")]
@@ -152,7 +281,15 @@ pub struct CertificateAuthorityConfiguration {
     pub subject: ASN1Subject,
 }
 
+impl Validate for CertificateAuthorityConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.subject
+            .validate_at(&field_path(path, "subject"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCertificateAuthorityAuditReportRequest {
     /// <p>The format in which to create the report. This can be either <b>JSON</b> or <b>CSV</b>.</p>
     #[serde(rename = "AuditReportResponseFormat")]
@@ -165,8 +302,30 @@ pub struct CreateCertificateAuthorityAuditReportRequest {
     pub s3_bucket_name: String,
 }
 
+impl Validate for CreateCertificateAuthorityAuditReportRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "s3_bucket_name"),
+            self.s3_bucket_name.chars().count(),
+            Some(3),
+            Some(63),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCertificateAuthorityAuditReportResponse {
     /// <p>An alphanumeric string that contains a report identifier.</p>
     #[serde(rename = "AuditReportId")]
@@ -179,6 +338,7 @@ pub struct CreateCertificateAuthorityAuditReportResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCertificateAuthorityRequest {
     /// <p>Name and bit size of the private key algorithm, the name of the signing algorithm, and X.500 certificate subject information.</p>
     #[serde(rename = "CertificateAuthorityConfiguration")]
@@ -200,8 +360,40 @@ pub struct CreateCertificateAuthorityRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateCertificateAuthorityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.certificate_authority_configuration.validate_at(
+            &field_path(path, "certificate_authority_configuration"),
+            errors,
+        );
+        if let Some(ref value) = self.idempotency_token {
+            errors.check_length(
+                &field_path(path, "idempotency_token"),
+                value.chars().count(),
+                Some(1),
+                Some(36),
+            );
+            errors.check_pattern(
+                &field_path(path, "idempotency_token"),
+                &value,
+                "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]*",
+            );
+        }
+        if let Some(ref value) = self.revocation_configuration {
+            value.validate_at(&field_path(path, "revocation_configuration"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCertificateAuthorityResponse {
     /// <p>If successful, the Amazon Resource Name (ARN) of the certificate authority (CA). This is of the form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>. </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -210,6 +402,7 @@ pub struct CreateCertificateAuthorityResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePermissionRequest {
     /// <p>The actions that the specified AWS service principal can use. These include <code>IssueCertificate</code>, <code>GetCertificate</code>, and <code>ListPermissions</code>.</p>
     #[serde(rename = "Actions")]
@@ -226,6 +419,44 @@ pub struct CreatePermissionRequest {
     pub source_account: Option<String>,
 }
 
+impl Validate for CreatePermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "actions"),
+            self.actions.len(),
+            Some(1),
+            Some(3),
+        );
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "principal"),
+            self.principal.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "principal"), &self.principal, "^[^*]+$");
+        if let Some(ref value) = self.source_account {
+            errors.check_length(
+                &field_path(path, "source_account"),
+                value.chars().count(),
+                Some(12),
+                Some(12),
+            );
+            errors.check_pattern(&field_path(path, "source_account"), &value, "[0-9]+");
+        }
+    }
+}
+
 /// <p>Contains configuration information for a certificate revocation list (CRL). Your private certificate authority (CA) creates base CRLs. Delta CRLs are not supported. You can enable CRLs for your new or an existing private CA by setting the <b>Enabled</b> parameter to <code>true</code>. Your private CA writes CRLs to an S3 bucket that you specify in the <b>S3BucketName</b> parameter. You can hide the name of your bucket by specifying a value for the <b>CustomCname</b> parameter. Your private CA copies the CNAME or the S3 bucket name to the <b>CRL Distribution Points</b> extension of each certificate it issues. Your S3 bucket policy must give write permission to ACM Private CA. </p> <p>Your private CA uses the value in the <b>ExpirationInDays</b> parameter to calculate the <b>nextUpdate</b> field in the CRL. The CRL is refreshed at 1/2 the age of next update or when a certificate is revoked. When a certificate is revoked, it is recorded in the next CRL that is generated and in the next audit report. Only time valid certificates are listed in the CRL. Expired certificates are not included. </p> <p>CRLs contain the following fields:</p> <ul> <li> <p> <b>Version</b>: The current version number defined in RFC 5280 is V2. The integer value is 0x1. </p> </li> <li> <p> <b>Signature Algorithm</b>: The name of the algorithm used to sign the CRL.</p> </li> <li> <p> <b>Issuer</b>: The X.500 distinguished name of your private CA that issued the CRL.</p> </li> <li> <p> <b>Last Update</b>: The issue date and time of this CRL.</p> </li> <li> <p> <b>Next Update</b>: The day and time by which the next CRL will be issued.</p> </li> <li> <p> <b>Revoked Certificates</b>: List of revoked certificates. Each list item contains the following information.</p> <ul> <li> <p> <b>Serial Number</b>: The serial number, in hexadecimal format, of the revoked certificate.</p> </li> <li> <p> <b>Revocation Date</b>: Date and time the certificate was revoked.</p> </li> <li> <p> <b>CRL Entry Extensions</b>: Optional extensions for the CRL entry.</p> <ul> <li> <p> <b>X509v3 CRL Reason Code</b>: Reason the certificate was revoked.</p> </li> </ul> </li> </ul> </li> <li> <p> <b>CRL Extensions</b>: Optional extensions for the CRL.</p> <ul> <li> <p> <b>X509v3 Authority Key Identifier</b>: Identifies the public key associated with the private key used to sign the certificate.</p> </li> <li> <p> <b>X509v3 CRL Number:</b>: Decimal sequence number for the CRL.</p> </li> </ul> </li> <li> <p> <b>Signature Algorithm</b>: Algorithm used by your private CA to sign the CRL.</p> </li> <li> <p> <b>Signature Value</b>: Signature computed over the CRL.</p> </li> </ul> <p>Certificate revocation lists created by ACM Private CA are DER-encoded. You can use the following OpenSSL command to list a CRL.</p> <p> <code>openssl crl -inform DER -text -in <i>crl_path</i> -noout</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CrlConfiguration {
@@ -246,7 +477,47 @@ pub struct CrlConfiguration {
     pub s3_bucket_name: Option<String>,
 }
 
+impl Validate for CrlConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_cname {
+            errors.check_length(
+                &field_path(path, "custom_cname"),
+                value.chars().count(),
+                None,
+                Some(253),
+            );
+            errors.check_pattern(
+                &field_path(path, "custom_cname"),
+                &value,
+                "^[-a-zA-Z0-9;/?:@&=+$,%_.!~*()']*$",
+            );
+        }
+        if let Some(ref value) = self.expiration_in_days {
+            errors.check_range(
+                &field_path(path, "expiration_in_days"),
+                *value,
+                Some(1),
+                Some(5000),
+            );
+        }
+        if let Some(ref value) = self.s3_bucket_name {
+            errors.check_length(
+                &field_path(path, "s3_bucket_name"),
+                value.chars().count(),
+                Some(3),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "s3_bucket_name"),
+                &value,
+                "^[-a-zA-Z0-9._/]+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCertificateAuthorityRequest {
     /// <p>The Amazon Resource Name (ARN) that was returned when you called <a>CreateCertificateAuthority</a>. This must have the following form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>. </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -257,7 +528,32 @@ pub struct DeleteCertificateAuthorityRequest {
     pub permanent_deletion_time_in_days: Option<i64>,
 }
 
+impl Validate for DeleteCertificateAuthorityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        if let Some(ref value) = self.permanent_deletion_time_in_days {
+            errors.check_range(
+                &field_path(path, "permanent_deletion_time_in_days"),
+                *value,
+                Some(7),
+                Some(30),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePermissionRequest {
     /// <p>The Amazon Resource Number (ARN) of the private CA that issued the permissions. You can find the CA's ARN by calling the <a>ListCertificateAuthorities</a> action. This must have the following form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>. </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -271,7 +567,40 @@ pub struct DeletePermissionRequest {
     pub source_account: Option<String>,
 }
 
+impl Validate for DeletePermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "principal"),
+            self.principal.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "principal"), &self.principal, "^[^*]+$");
+        if let Some(ref value) = self.source_account {
+            errors.check_length(
+                &field_path(path, "source_account"),
+                value.chars().count(),
+                Some(12),
+                Some(12),
+            );
+            errors.check_pattern(&field_path(path, "source_account"), &value, "[0-9]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCertificateAuthorityAuditReportRequest {
     /// <p>The report ID returned by calling the <a>CreateCertificateAuthorityAuditReport</a> action.</p>
     #[serde(rename = "AuditReportId")]
@@ -281,8 +610,35 @@ pub struct DescribeCertificateAuthorityAuditReportRequest {
     pub certificate_authority_arn: String,
 }
 
+impl Validate for DescribeCertificateAuthorityAuditReportRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "audit_report_id"),
+            self.audit_report_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "audit_report_id"),
+            &self.audit_report_id,
+            "[a-z0-9]{8}-[a-z0-9]{4}-[a-z0-9]{4}-[a-z0-9]{4}-[a-z0-9]{12}",
+        );
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCertificateAuthorityAuditReportResponse {
     /// <p>Specifies whether report creation is in progress, has succeeded, or has failed.</p>
     #[serde(rename = "AuditReportStatus")]
@@ -303,14 +659,31 @@ pub struct DescribeCertificateAuthorityAuditReportResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCertificateAuthorityRequest {
     /// <p>The Amazon Resource Name (ARN) that was returned when you called <a>CreateCertificateAuthority</a>. This must be of the form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>. </p>
     #[serde(rename = "CertificateAuthorityArn")]
     pub certificate_authority_arn: String,
 }
 
+impl Validate for DescribeCertificateAuthorityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCertificateAuthorityResponse {
     /// <p>A <a>CertificateAuthority</a> structure that contains information about your private CA.</p>
     #[serde(rename = "CertificateAuthority")]
@@ -319,14 +692,31 @@ pub struct DescribeCertificateAuthorityResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCertificateAuthorityCertificateRequest {
     /// <p>The Amazon Resource Name (ARN) of your private CA. This is of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>. </p>
     #[serde(rename = "CertificateAuthorityArn")]
     pub certificate_authority_arn: String,
 }
 
+impl Validate for GetCertificateAuthorityCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCertificateAuthorityCertificateResponse {
     /// <p>Base64-encoded certificate authority (CA) certificate.</p>
     #[serde(rename = "Certificate")]
@@ -339,14 +729,31 @@ pub struct GetCertificateAuthorityCertificateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCertificateAuthorityCsrRequest {
     /// <p>The Amazon Resource Name (ARN) that was returned when you called the <a>CreateCertificateAuthority</a> action. This must be of the form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
     pub certificate_authority_arn: String,
 }
 
+impl Validate for GetCertificateAuthorityCsrRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCertificateAuthorityCsrResponse {
     /// <p>The base64 PEM-encoded certificate signing request (CSR) for your private CA certificate.</p>
     #[serde(rename = "Csr")]
@@ -355,6 +762,7 @@ pub struct GetCertificateAuthorityCsrResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCertificateRequest {
     /// <p>The ARN of the issued certificate. The ARN contains the certificate serial number and must be in the following form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i>/certificate/<i>286535153982981100925020015808220737245</i> </code> </p>
     #[serde(rename = "CertificateArn")]
@@ -364,8 +772,35 @@ pub struct GetCertificateRequest {
     pub certificate_authority_arn: String,
 }
 
+impl Validate for GetCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCertificateResponse {
     /// <p>The base64 PEM-encoded certificate specified by the <code>CertificateArn</code> parameter.</p>
     #[serde(rename = "Certificate")]
@@ -378,6 +813,7 @@ pub struct GetCertificateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportCertificateAuthorityCertificateRequest {
     /// <p>The PEM-encoded certificate for a private CA. This may be a self-signed certificate in the case of a root CA, or it may be signed by another CA that you control.</p>
     #[serde(rename = "Certificate")]
@@ -401,7 +837,38 @@ pub struct ImportCertificateAuthorityCertificateRequest {
     pub certificate_chain: Option<bytes::Bytes>,
 }
 
+impl Validate for ImportCertificateAuthorityCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate"),
+            self.certificate.len(),
+            Some(1),
+            Some(32768),
+        );
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        if let Some(ref value) = self.certificate_chain {
+            errors.check_length(
+                &field_path(path, "certificate_chain"),
+                value.len(),
+                None,
+                Some(2097152),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IssueCertificateRequest {
     /// <p>The Amazon Resource Name (ARN) that was returned when you called <a>CreateCertificateAuthority</a>. This must be of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -430,8 +897,58 @@ pub struct IssueCertificateRequest {
     pub validity: Validity,
 }
 
+impl Validate for IssueCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "csr"),
+            self.csr.len(),
+            Some(1),
+            Some(32768),
+        );
+        if let Some(ref value) = self.idempotency_token {
+            errors.check_length(
+                &field_path(path, "idempotency_token"),
+                value.chars().count(),
+                Some(1),
+                Some(36),
+            );
+            errors.check_pattern(
+                &field_path(path, "idempotency_token"),
+                &value,
+                "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]*",
+            );
+        }
+        if let Some(ref value) = self.template_arn {
+            errors.check_length(
+                &field_path(path, "template_arn"),
+                value.chars().count(),
+                Some(5),
+                Some(200),
+            );
+            errors.check_pattern(
+                &field_path(path, "template_arn"),
+                &value,
+                "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+            );
+        }
+        self.validity
+            .validate_at(&field_path(path, "validity"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IssueCertificateResponse {
     /// <p>The Amazon Resource Name (ARN) of the issued certificate and the certificate serial number. This is of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i>/certificate/<i>286535153982981100925020015808220737245</i> </code> </p>
     #[serde(rename = "CertificateArn")]
@@ -440,6 +957,7 @@ pub struct IssueCertificateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCertificateAuthoritiesRequest {
     /// <p>Use this parameter when paginating results to specify the maximum number of items to return in the response on each page. If additional items exist beyond the number you specify, the <code>NextToken</code> element is sent in the response. Use this <code>NextToken</code> value in a subsequent request to retrieve additional items.</p>
     #[serde(rename = "MaxResults")]
@@ -451,8 +969,29 @@ pub struct ListCertificateAuthoritiesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListCertificateAuthoritiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(500),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCertificateAuthoritiesResponse {
     /// <p>Summary information about each certificate authority you have created.</p>
     #[serde(rename = "CertificateAuthorities")]
@@ -465,6 +1004,7 @@ pub struct ListCertificateAuthoritiesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPermissionsRequest {
     /// <p>The Amazon Resource Number (ARN) of the private CA to inspect. You can find the ARN by calling the <a>ListCertificateAuthorities</a> action. This must be of the form: <code>arn:aws:acm-pca:region:account:certificate-authority/12345678-1234-1234-1234-123456789012</code> You can get a private CA's ARN by running the <a>ListCertificateAuthorities</a> action.</p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -479,8 +1019,40 @@ pub struct ListPermissionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListPermissionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(500),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPermissionsResponse {
     /// <p>When the list is truncated, this value is present and should be used for the <b>NextToken</b> parameter in a subsequent pagination request. </p>
     #[serde(rename = "NextToken")]
@@ -493,6 +1065,7 @@ pub struct ListPermissionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsRequest {
     /// <p>The Amazon Resource Name (ARN) that was returned when you called the <a>CreateCertificateAuthority</a> action. This must be of the form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -507,8 +1080,40 @@ pub struct ListTagsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(500),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsResponse {
     /// <p>When the list is truncated, this value is present and should be used for the <b>NextToken</b> parameter in a subsequent pagination request. </p>
     #[serde(rename = "NextToken")]
@@ -522,7 +1127,7 @@ pub struct ListTagsResponse {
 
 /// <p>Permissions designate which private CA actions can be performed by an AWS service or entity. In order for ACM to automatically renew private certificates, you must give the ACM service principal all available permissions (<code>IssueCertificate</code>, <code>GetCertificate</code>, and <code>ListPermissions</code>). Permissions can be assigned with the <a>CreatePermission</a> action, removed with the <a>DeletePermission</a> action, and listed with the <a>ListPermissions</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Permission {
     /// <p>The private CA actions that can be performed by the designated AWS service.</p>
     #[serde(rename = "Actions")]
@@ -551,12 +1156,29 @@ pub struct Permission {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreCertificateAuthorityRequest {
     /// <p>The Amazon Resource Name (ARN) that was returned when you called the <a>CreateCertificateAuthority</a> action. This must be of the form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
     pub certificate_authority_arn: String,
 }
 
+impl Validate for RestoreCertificateAuthorityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 /// <p>Certificate revocation information used by the <a>CreateCertificateAuthority</a> and <a>UpdateCertificateAuthority</a> actions. Your private certificate authority (CA) can create and maintain a certificate revocation list (CRL). A CRL contains information about certificates revoked by your CA. For more information, see <a>RevokeCertificate</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RevocationConfiguration {
@@ -566,7 +1188,16 @@ pub struct RevocationConfiguration {
     pub crl_configuration: Option<CrlConfiguration>,
 }
 
+impl Validate for RevocationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.crl_configuration {
+            value.validate_at(&field_path(path, "crl_configuration"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeCertificateRequest {
     /// <p>Amazon Resource Name (ARN) of the private CA that issued the certificate to be revoked. This must be of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -579,6 +1210,28 @@ pub struct RevokeCertificateRequest {
     pub revocation_reason: String,
 }
 
+impl Validate for RevokeCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "certificate_serial"),
+            self.certificate_serial.chars().count(),
+            None,
+            Some(128),
+        );
+    }
+}
+
 /// <p>Tags are labels that you can use to identify and organize your private CAs. Each tag consists of a key and an optional value. You can associate up to 50 tags with a private CA. To add one or more tags to a private CA, call the <a>TagCertificateAuthority</a> action. To remove a tag, call the <a>UntagCertificateAuthority</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -591,7 +1244,37 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagCertificateAuthorityRequest {
     /// <p>The Amazon Resource Name (ARN) that was returned when you called <a>CreateCertificateAuthority</a>. This must be of the form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -601,7 +1284,34 @@ pub struct TagCertificateAuthorityRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagCertificateAuthorityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagCertificateAuthorityRequest {
     /// <p>The Amazon Resource Name (ARN) that was returned when you called <a>CreateCertificateAuthority</a>. This must be of the form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -611,7 +1321,34 @@ pub struct UntagCertificateAuthorityRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for UntagCertificateAuthorityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCertificateAuthorityRequest {
     /// <p>Amazon Resource Name (ARN) of the private CA that issued the certificate to be revoked. This must be of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -626,8 +1363,28 @@ pub struct UpdateCertificateAuthorityRequest {
     pub status: Option<String>,
 }
 
+impl Validate for UpdateCertificateAuthorityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_authority_arn"),
+            self.certificate_authority_arn.chars().count(),
+            Some(5),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_authority_arn"),
+            &self.certificate_authority_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]*:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        if let Some(ref value) = self.revocation_configuration {
+            value.validate_at(&field_path(path, "revocation_configuration"), errors);
+        }
+    }
+}
+
 /// <p>Length of time for which the certificate issued by your private certificate authority (CA), or by the private CA itself, is valid in days, months, or years. You can issue a certificate by calling the <a>IssueCertificate</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Validity {
     /// <p>Specifies whether the <code>Value</code> parameter represents days, months, or years.</p>
     #[serde(rename = "Type")]
@@ -637,6 +1394,12 @@ pub struct Validity {
     pub value: i64,
 }
 
+impl Validate for Validity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(&field_path(path, "value"), self.value, Some(1), None);
+    }
+}
+
 /// Errors returned by CreateCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum CreateCertificateAuthorityError {
@@ -676,7 +1439,12 @@ impl CreateCertificateAuthorityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -751,7 +1519,12 @@ impl CreateCertificateAuthorityAuditReportError {
                         CreateCertificateAuthorityAuditReportError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -816,7 +1589,12 @@ impl CreatePermissionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreatePermissionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -879,7 +1657,12 @@ impl DeleteCertificateAuthorityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -930,7 +1713,12 @@ impl DeletePermissionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeletePermissionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -977,7 +1765,12 @@ impl DescribeCertificateAuthorityError {
                         DescribeCertificateAuthorityError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1029,7 +1822,12 @@ impl DescribeCertificateAuthorityAuditReportError {
                         DescribeCertificateAuthorityAuditReportError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1084,7 +1882,12 @@ impl GetCertificateError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetCertificateError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1139,7 +1942,12 @@ impl GetCertificateAuthorityCertificateError {
                         GetCertificateAuthorityCertificateError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1206,7 +2014,12 @@ impl GetCertificateAuthorityCsrError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1303,7 +2116,12 @@ impl ImportCertificateAuthorityCertificateError {
                         ImportCertificateAuthorityCertificateError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1369,7 +2187,12 @@ impl IssueCertificateError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(IssueCertificateError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1411,7 +2234,12 @@ impl ListCertificateAuthoritiesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1464,7 +2292,12 @@ impl ListPermissionsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListPermissionsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1511,7 +2344,12 @@ impl ListTagsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1564,7 +2402,12 @@ impl RestoreCertificateAuthorityError {
                         RestoreCertificateAuthorityError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1643,7 +2486,12 @@ impl RevokeCertificateError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RevokeCertificateError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1708,7 +2556,12 @@ impl TagCertificateAuthorityError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(TagCertificateAuthorityError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1768,7 +2621,12 @@ impl UntagCertificateAuthorityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1843,7 +2701,12 @@ impl UpdateCertificateAuthorityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1995,71 +2858,14 @@ pub trait AcmPca {
         input: UpdateCertificateAuthorityRequest,
     ) -> RusotoFuture<(), UpdateCertificateAuthorityError>;
 }
-/// A client for the ACM-PCA API.
-#[derive(Clone)]
-pub struct AcmPcaClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl AcmPcaClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> AcmPcaClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> AcmPcaClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> AcmPcaClient {
-        AcmPcaClient { client, region }
-    }
-}
-
-impl AcmPca for AcmPcaClient {
-    /// <p>Creates a root or subordinate private certificate authority (CA). You must specify the CA configuration, the certificate revocation list (CRL) configuration, the CA type, and an optional idempotency token to avoid accidental creation of multiple CAs. The CA configuration specifies the name of the algorithm and key size to be used to create the CA private key, the type of signing algorithm that the CA uses, and X.500 subject information. The CRL configuration specifies the CRL expiration period in days (the validity period of the CRL), the Amazon S3 bucket that will contain the CRL, and a CNAME alias for the S3 bucket that is included in certificates issued by the CA. If successful, this action returns the Amazon Resource Name (ARN) of the CA.</p>
+const _: Option<&dyn AcmPca> = None;
+impl<'a, T: AcmPca + ?Sized> AcmPca for &'a T {
     fn create_certificate_authority(
         &self,
         input: CreateCertificateAuthorityRequest,
     ) -> RusotoFuture<CreateCertificateAuthorityResponse, CreateCertificateAuthorityError> {
-        let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ACMPrivateCA.CreateCertificateAuthority");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCertificateAuthorityResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateCertificateAuthorityError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_certificate_authority(input)
     }
-
-    /// <p>Creates an audit report that lists every time that your CA private key is used. The report is saved in the Amazon S3 bucket that you specify on input. The <a>IssueCertificate</a> and <a>RevokeCertificate</a> actions use the private key.</p>
     fn create_certificate_authority_audit_report(
         &self,
         input: CreateCertificateAuthorityAuditReportRequest,
@@ -2067,22 +2873,479 @@ impl AcmPca for AcmPcaClient {
         CreateCertificateAuthorityAuditReportResponse,
         CreateCertificateAuthorityAuditReportError,
     > {
-        let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "ACMPrivateCA.CreateCertificateAuthorityAuditReport",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
+        (**self).create_certificate_authority_audit_report(input)
+    }
+    fn create_permission(
+        &self,
+        input: CreatePermissionRequest,
+    ) -> RusotoFuture<(), CreatePermissionError> {
+        (**self).create_permission(input)
+    }
+    fn delete_certificate_authority(
+        &self,
+        input: DeleteCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), DeleteCertificateAuthorityError> {
+        (**self).delete_certificate_authority(input)
+    }
+    fn delete_permission(
+        &self,
+        input: DeletePermissionRequest,
+    ) -> RusotoFuture<(), DeletePermissionError> {
+        (**self).delete_permission(input)
+    }
+    fn describe_certificate_authority(
+        &self,
+        input: DescribeCertificateAuthorityRequest,
+    ) -> RusotoFuture<DescribeCertificateAuthorityResponse, DescribeCertificateAuthorityError> {
+        (**self).describe_certificate_authority(input)
+    }
+    fn describe_certificate_authority_audit_report(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> RusotoFuture<
+        DescribeCertificateAuthorityAuditReportResponse,
+        DescribeCertificateAuthorityAuditReportError,
+    > {
+        (**self).describe_certificate_authority_audit_report(input)
+    }
+    fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        (**self).get_certificate(input)
+    }
+    fn get_certificate_authority_certificate(
+        &self,
+        input: GetCertificateAuthorityCertificateRequest,
+    ) -> RusotoFuture<
+        GetCertificateAuthorityCertificateResponse,
+        GetCertificateAuthorityCertificateError,
+    > {
+        (**self).get_certificate_authority_certificate(input)
+    }
+    fn get_certificate_authority_csr(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> RusotoFuture<GetCertificateAuthorityCsrResponse, GetCertificateAuthorityCsrError> {
+        (**self).get_certificate_authority_csr(input)
+    }
+    fn import_certificate_authority_certificate(
+        &self,
+        input: ImportCertificateAuthorityCertificateRequest,
+    ) -> RusotoFuture<(), ImportCertificateAuthorityCertificateError> {
+        (**self).import_certificate_authority_certificate(input)
+    }
+    fn issue_certificate(
+        &self,
+        input: IssueCertificateRequest,
+    ) -> RusotoFuture<IssueCertificateResponse, IssueCertificateError> {
+        (**self).issue_certificate(input)
+    }
+    fn list_certificate_authorities(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<ListCertificateAuthoritiesResponse, ListCertificateAuthoritiesError> {
+        (**self).list_certificate_authorities(input)
+    }
+    fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> RusotoFuture<ListPermissionsResponse, ListPermissionsError> {
+        (**self).list_permissions(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn restore_certificate_authority(
+        &self,
+        input: RestoreCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), RestoreCertificateAuthorityError> {
+        (**self).restore_certificate_authority(input)
+    }
+    fn revoke_certificate(
+        &self,
+        input: RevokeCertificateRequest,
+    ) -> RusotoFuture<(), RevokeCertificateError> {
+        (**self).revoke_certificate(input)
+    }
+    fn tag_certificate_authority(
+        &self,
+        input: TagCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), TagCertificateAuthorityError> {
+        (**self).tag_certificate_authority(input)
+    }
+    fn untag_certificate_authority(
+        &self,
+        input: UntagCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), UntagCertificateAuthorityError> {
+        (**self).untag_certificate_authority(input)
+    }
+    fn update_certificate_authority(
+        &self,
+        input: UpdateCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), UpdateCertificateAuthorityError> {
+        (**self).update_certificate_authority(input)
+    }
+}
+impl<T: AcmPca + ?Sized> AcmPca for Box<T> {
+    fn create_certificate_authority(
+        &self,
+        input: CreateCertificateAuthorityRequest,
+    ) -> RusotoFuture<CreateCertificateAuthorityResponse, CreateCertificateAuthorityError> {
+        (**self).create_certificate_authority(input)
+    }
+    fn create_certificate_authority_audit_report(
+        &self,
+        input: CreateCertificateAuthorityAuditReportRequest,
+    ) -> RusotoFuture<
+        CreateCertificateAuthorityAuditReportResponse,
+        CreateCertificateAuthorityAuditReportError,
+    > {
+        (**self).create_certificate_authority_audit_report(input)
+    }
+    fn create_permission(
+        &self,
+        input: CreatePermissionRequest,
+    ) -> RusotoFuture<(), CreatePermissionError> {
+        (**self).create_permission(input)
+    }
+    fn delete_certificate_authority(
+        &self,
+        input: DeleteCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), DeleteCertificateAuthorityError> {
+        (**self).delete_certificate_authority(input)
+    }
+    fn delete_permission(
+        &self,
+        input: DeletePermissionRequest,
+    ) -> RusotoFuture<(), DeletePermissionError> {
+        (**self).delete_permission(input)
+    }
+    fn describe_certificate_authority(
+        &self,
+        input: DescribeCertificateAuthorityRequest,
+    ) -> RusotoFuture<DescribeCertificateAuthorityResponse, DescribeCertificateAuthorityError> {
+        (**self).describe_certificate_authority(input)
+    }
+    fn describe_certificate_authority_audit_report(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> RusotoFuture<
+        DescribeCertificateAuthorityAuditReportResponse,
+        DescribeCertificateAuthorityAuditReportError,
+    > {
+        (**self).describe_certificate_authority_audit_report(input)
+    }
+    fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        (**self).get_certificate(input)
+    }
+    fn get_certificate_authority_certificate(
+        &self,
+        input: GetCertificateAuthorityCertificateRequest,
+    ) -> RusotoFuture<
+        GetCertificateAuthorityCertificateResponse,
+        GetCertificateAuthorityCertificateError,
+    > {
+        (**self).get_certificate_authority_certificate(input)
+    }
+    fn get_certificate_authority_csr(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> RusotoFuture<GetCertificateAuthorityCsrResponse, GetCertificateAuthorityCsrError> {
+        (**self).get_certificate_authority_csr(input)
+    }
+    fn import_certificate_authority_certificate(
+        &self,
+        input: ImportCertificateAuthorityCertificateRequest,
+    ) -> RusotoFuture<(), ImportCertificateAuthorityCertificateError> {
+        (**self).import_certificate_authority_certificate(input)
+    }
+    fn issue_certificate(
+        &self,
+        input: IssueCertificateRequest,
+    ) -> RusotoFuture<IssueCertificateResponse, IssueCertificateError> {
+        (**self).issue_certificate(input)
+    }
+    fn list_certificate_authorities(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<ListCertificateAuthoritiesResponse, ListCertificateAuthoritiesError> {
+        (**self).list_certificate_authorities(input)
+    }
+    fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> RusotoFuture<ListPermissionsResponse, ListPermissionsError> {
+        (**self).list_permissions(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn restore_certificate_authority(
+        &self,
+        input: RestoreCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), RestoreCertificateAuthorityError> {
+        (**self).restore_certificate_authority(input)
+    }
+    fn revoke_certificate(
+        &self,
+        input: RevokeCertificateRequest,
+    ) -> RusotoFuture<(), RevokeCertificateError> {
+        (**self).revoke_certificate(input)
+    }
+    fn tag_certificate_authority(
+        &self,
+        input: TagCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), TagCertificateAuthorityError> {
+        (**self).tag_certificate_authority(input)
+    }
+    fn untag_certificate_authority(
+        &self,
+        input: UntagCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), UntagCertificateAuthorityError> {
+        (**self).untag_certificate_authority(input)
+    }
+    fn update_certificate_authority(
+        &self,
+        input: UpdateCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), UpdateCertificateAuthorityError> {
+        (**self).update_certificate_authority(input)
+    }
+}
+impl<T: AcmPca + ?Sized> AcmPca for ::std::sync::Arc<T> {
+    fn create_certificate_authority(
+        &self,
+        input: CreateCertificateAuthorityRequest,
+    ) -> RusotoFuture<CreateCertificateAuthorityResponse, CreateCertificateAuthorityError> {
+        (**self).create_certificate_authority(input)
+    }
+    fn create_certificate_authority_audit_report(
+        &self,
+        input: CreateCertificateAuthorityAuditReportRequest,
+    ) -> RusotoFuture<
+        CreateCertificateAuthorityAuditReportResponse,
+        CreateCertificateAuthorityAuditReportError,
+    > {
+        (**self).create_certificate_authority_audit_report(input)
+    }
+    fn create_permission(
+        &self,
+        input: CreatePermissionRequest,
+    ) -> RusotoFuture<(), CreatePermissionError> {
+        (**self).create_permission(input)
+    }
+    fn delete_certificate_authority(
+        &self,
+        input: DeleteCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), DeleteCertificateAuthorityError> {
+        (**self).delete_certificate_authority(input)
+    }
+    fn delete_permission(
+        &self,
+        input: DeletePermissionRequest,
+    ) -> RusotoFuture<(), DeletePermissionError> {
+        (**self).delete_permission(input)
+    }
+    fn describe_certificate_authority(
+        &self,
+        input: DescribeCertificateAuthorityRequest,
+    ) -> RusotoFuture<DescribeCertificateAuthorityResponse, DescribeCertificateAuthorityError> {
+        (**self).describe_certificate_authority(input)
+    }
+    fn describe_certificate_authority_audit_report(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> RusotoFuture<
+        DescribeCertificateAuthorityAuditReportResponse,
+        DescribeCertificateAuthorityAuditReportError,
+    > {
+        (**self).describe_certificate_authority_audit_report(input)
+    }
+    fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        (**self).get_certificate(input)
+    }
+    fn get_certificate_authority_certificate(
+        &self,
+        input: GetCertificateAuthorityCertificateRequest,
+    ) -> RusotoFuture<
+        GetCertificateAuthorityCertificateResponse,
+        GetCertificateAuthorityCertificateError,
+    > {
+        (**self).get_certificate_authority_certificate(input)
+    }
+    fn get_certificate_authority_csr(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> RusotoFuture<GetCertificateAuthorityCsrResponse, GetCertificateAuthorityCsrError> {
+        (**self).get_certificate_authority_csr(input)
+    }
+    fn import_certificate_authority_certificate(
+        &self,
+        input: ImportCertificateAuthorityCertificateRequest,
+    ) -> RusotoFuture<(), ImportCertificateAuthorityCertificateError> {
+        (**self).import_certificate_authority_certificate(input)
+    }
+    fn issue_certificate(
+        &self,
+        input: IssueCertificateRequest,
+    ) -> RusotoFuture<IssueCertificateResponse, IssueCertificateError> {
+        (**self).issue_certificate(input)
+    }
+    fn list_certificate_authorities(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<ListCertificateAuthoritiesResponse, ListCertificateAuthoritiesError> {
+        (**self).list_certificate_authorities(input)
+    }
+    fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> RusotoFuture<ListPermissionsResponse, ListPermissionsError> {
+        (**self).list_permissions(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn restore_certificate_authority(
+        &self,
+        input: RestoreCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), RestoreCertificateAuthorityError> {
+        (**self).restore_certificate_authority(input)
+    }
+    fn revoke_certificate(
+        &self,
+        input: RevokeCertificateRequest,
+    ) -> RusotoFuture<(), RevokeCertificateError> {
+        (**self).revoke_certificate(input)
+    }
+    fn tag_certificate_authority(
+        &self,
+        input: TagCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), TagCertificateAuthorityError> {
+        (**self).tag_certificate_authority(input)
+    }
+    fn untag_certificate_authority(
+        &self,
+        input: UntagCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), UntagCertificateAuthorityError> {
+        (**self).untag_certificate_authority(input)
+    }
+    fn update_certificate_authority(
+        &self,
+        input: UpdateCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), UpdateCertificateAuthorityError> {
+        (**self).update_certificate_authority(input)
+    }
+}
+/// A client for the ACM-PCA API.
+#[derive(Clone)]
+pub struct AcmPcaClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl AcmPcaClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AcmPcaClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AcmPcaClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> AcmPcaClient {
+        AcmPcaClient { client, region }
+    }
+}
+
+impl AcmPca for AcmPcaClient {
+    /// <p>Creates a root or subordinate private certificate authority (CA). You must specify the CA configuration, the certificate revocation list (CRL) configuration, the CA type, and an optional idempotency token to avoid accidental creation of multiple CAs. The CA configuration specifies the name of the algorithm and key size to be used to create the CA private key, the type of signing algorithm that the CA uses, and X.500 subject information. The CRL configuration specifies the CRL expiration period in days (the validity period of the CRL), the Amazon S3 bucket that will contain the CRL, and a CNAME alias for the S3 bucket that is included in certificates issued by the CA. If successful, this action returns the Amazon Resource Name (ARN) of the CA.</p>
+    fn create_certificate_authority(
+        &self,
+        input: CreateCertificateAuthorityRequest,
+    ) -> RusotoFuture<CreateCertificateAuthorityResponse, CreateCertificateAuthorityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("CreateCertificateAuthority");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ACMPrivateCA.CreateCertificateAuthority");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateCertificateAuthorityResponse, _>(
+                    response,
+                    "CreateCertificateAuthority",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateCertificateAuthorityError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates an audit report that lists every time that your CA private key is used. The report is saved in the Amazon S3 bucket that you specify on input. The <a>IssueCertificate</a> and <a>RevokeCertificate</a> actions use the private key.</p>
+    fn create_certificate_authority_audit_report(
+        &self,
+        input: CreateCertificateAuthorityAuditReportRequest,
+    ) -> RusotoFuture<
+        CreateCertificateAuthorityAuditReportResponse,
+        CreateCertificateAuthorityAuditReportError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("CreateCertificateAuthorityAuditReport");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "ACMPrivateCA.CreateCertificateAuthorityAuditReport",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCertificateAuthorityAuditReportResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateCertificateAuthorityAuditReportResponse, _>(response, "CreateCertificateAuthorityAuditReport")
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(CreateCertificateAuthorityAuditReportError::from_response(
@@ -2098,7 +3361,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: CreatePermissionRequest,
     ) -> RusotoFuture<(), CreatePermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("CreatePermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.CreatePermission");
@@ -2124,7 +3396,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: DeleteCertificateAuthorityRequest,
     ) -> RusotoFuture<(), DeleteCertificateAuthorityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DeleteCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.DeleteCertificateAuthority");
@@ -2147,7 +3428,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: DeletePermissionRequest,
     ) -> RusotoFuture<(), DeletePermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DeletePermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.DeletePermission");
@@ -2173,7 +3463,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: DescribeCertificateAuthorityRequest,
     ) -> RusotoFuture<DescribeCertificateAuthorityResponse, DescribeCertificateAuthorityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DescribeCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.DescribeCertificateAuthority");
@@ -2182,10 +3481,10 @@ impl AcmPca for AcmPcaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCertificateAuthorityResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeCertificateAuthorityResponse, _>(
+                    response,
+                    "DescribeCertificateAuthority",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeCertificateAuthorityError::from_response(response))
@@ -2202,7 +3501,16 @@ impl AcmPca for AcmPcaClient {
         DescribeCertificateAuthorityAuditReportResponse,
         DescribeCertificateAuthorityAuditReportError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DescribeCertificateAuthorityAuditReport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2214,10 +3522,10 @@ impl AcmPca for AcmPcaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCertificateAuthorityAuditReportResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<
+                    DescribeCertificateAuthorityAuditReportResponse,
+                    _,
+                >(response, "DescribeCertificateAuthorityAuditReport")
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeCertificateAuthorityAuditReportError::from_response(
@@ -2233,7 +3541,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: GetCertificateRequest,
     ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("GetCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.GetCertificate");
@@ -2242,10 +3559,10 @@ impl AcmPca for AcmPcaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCertificateResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetCertificateResponse, _>(
+                    response,
+                    "GetCertificate",
+                )
             } else {
                 Box::new(
                     response
@@ -2265,7 +3582,16 @@ impl AcmPca for AcmPcaClient {
         GetCertificateAuthorityCertificateResponse,
         GetCertificateAuthorityCertificateError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("GetCertificateAuthorityCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2277,10 +3603,10 @@ impl AcmPca for AcmPcaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCertificateAuthorityCertificateResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetCertificateAuthorityCertificateResponse, _>(
+                    response,
+                    "GetCertificateAuthorityCertificate",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetCertificateAuthorityCertificateError::from_response(
@@ -2296,7 +3622,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: GetCertificateAuthorityCsrRequest,
     ) -> RusotoFuture<GetCertificateAuthorityCsrResponse, GetCertificateAuthorityCsrError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("GetCertificateAuthorityCsr");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.GetCertificateAuthorityCsr");
@@ -2305,10 +3640,10 @@ impl AcmPca for AcmPcaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCertificateAuthorityCsrResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetCertificateAuthorityCsrResponse, _>(
+                    response,
+                    "GetCertificateAuthorityCsr",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetCertificateAuthorityCsrError::from_response(response))
@@ -2322,7 +3657,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: ImportCertificateAuthorityCertificateRequest,
     ) -> RusotoFuture<(), ImportCertificateAuthorityCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ImportCertificateAuthorityCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2350,7 +3694,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: IssueCertificateRequest,
     ) -> RusotoFuture<IssueCertificateResponse, IssueCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("IssueCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.IssueCertificate");
@@ -2359,10 +3712,10 @@ impl AcmPca for AcmPcaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<IssueCertificateResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<IssueCertificateResponse, _>(
+                    response,
+                    "IssueCertificate",
+                )
             } else {
                 Box::new(
                     response
@@ -2379,7 +3732,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: ListCertificateAuthoritiesRequest,
     ) -> RusotoFuture<ListCertificateAuthoritiesResponse, ListCertificateAuthoritiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ListCertificateAuthorities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.ListCertificateAuthorities");
@@ -2388,10 +3750,10 @@ impl AcmPca for AcmPcaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCertificateAuthoritiesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListCertificateAuthoritiesResponse, _>(
+                    response,
+                    "ListCertificateAuthorities",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ListCertificateAuthoritiesError::from_response(response))
@@ -2405,7 +3767,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: ListPermissionsRequest,
     ) -> RusotoFuture<ListPermissionsResponse, ListPermissionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ListPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.ListPermissions");
@@ -2414,10 +3785,10 @@ impl AcmPca for AcmPcaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPermissionsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListPermissionsResponse, _>(
+                    response,
+                    "ListPermissions",
+                )
             } else {
                 Box::new(
                     response
@@ -2431,7 +3802,16 @@ impl AcmPca for AcmPcaClient {
 
     /// <p>Lists the tags, if any, that are associated with your private CA. Tags are labels that you can use to identify and organize your CAs. Each tag consists of a key and an optional value. Call the <a>TagCertificateAuthority</a> action to add one or more tags to your CA. Call the <a>UntagCertificateAuthority</a> action to remove tags. </p>
     fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.ListTags");
@@ -2440,10 +3820,7 @@ impl AcmPca for AcmPcaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsResponse, _>(response, "ListTags")
             } else {
                 Box::new(
                     response
@@ -2460,7 +3837,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: RestoreCertificateAuthorityRequest,
     ) -> RusotoFuture<(), RestoreCertificateAuthorityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("RestoreCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.RestoreCertificateAuthority");
@@ -2483,7 +3869,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: RevokeCertificateRequest,
     ) -> RusotoFuture<(), RevokeCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("RevokeCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.RevokeCertificate");
@@ -2509,7 +3904,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: TagCertificateAuthorityRequest,
     ) -> RusotoFuture<(), TagCertificateAuthorityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("TagCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.TagCertificateAuthority");
@@ -2532,7 +3936,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: UntagCertificateAuthorityRequest,
     ) -> RusotoFuture<(), UntagCertificateAuthorityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("UntagCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.UntagCertificateAuthority");
@@ -2555,7 +3968,16 @@ impl AcmPca for AcmPcaClient {
         &self,
         input: UpdateCertificateAuthorityRequest,
     ) -> RusotoFuture<(), UpdateCertificateAuthorityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("UpdateCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.UpdateCertificateAuthority");
@@ -2573,3 +3995,709 @@ impl AcmPca for AcmPcaClient {
         })
     }
 }
+
+/// A mock of the ACM-PCA API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAcmPca {
+    create_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    CreateCertificateAuthorityRequest,
+                ) -> ::std::result::Result<
+                    CreateCertificateAuthorityResponse,
+                    RusotoError<CreateCertificateAuthorityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_certificate_authority_audit_report: Option<
+        Box<
+            dyn Fn(
+                    CreateCertificateAuthorityAuditReportRequest,
+                ) -> ::std::result::Result<
+                    CreateCertificateAuthorityAuditReportResponse,
+                    RusotoError<CreateCertificateAuthorityAuditReportError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_permission: Option<
+        Box<
+            dyn Fn(
+                    CreatePermissionRequest,
+                ) -> ::std::result::Result<(), RusotoError<CreatePermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    DeleteCertificateAuthorityRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteCertificateAuthorityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_permission: Option<
+        Box<
+            dyn Fn(
+                    DeletePermissionRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeletePermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    DescribeCertificateAuthorityRequest,
+                ) -> ::std::result::Result<
+                    DescribeCertificateAuthorityResponse,
+                    RusotoError<DescribeCertificateAuthorityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_certificate_authority_audit_report: Option<
+        Box<
+            dyn Fn(
+                    DescribeCertificateAuthorityAuditReportRequest,
+                ) -> ::std::result::Result<
+                    DescribeCertificateAuthorityAuditReportResponse,
+                    RusotoError<DescribeCertificateAuthorityAuditReportError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_certificate: Option<
+        Box<
+            dyn Fn(
+                    GetCertificateRequest,
+                ) -> ::std::result::Result<
+                    GetCertificateResponse,
+                    RusotoError<GetCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_certificate_authority_certificate: Option<
+        Box<
+            dyn Fn(
+                    GetCertificateAuthorityCertificateRequest,
+                ) -> ::std::result::Result<
+                    GetCertificateAuthorityCertificateResponse,
+                    RusotoError<GetCertificateAuthorityCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_certificate_authority_csr: Option<
+        Box<
+            dyn Fn(
+                    GetCertificateAuthorityCsrRequest,
+                ) -> ::std::result::Result<
+                    GetCertificateAuthorityCsrResponse,
+                    RusotoError<GetCertificateAuthorityCsrError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    import_certificate_authority_certificate: Option<
+        Box<
+            dyn Fn(
+                    ImportCertificateAuthorityCertificateRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<ImportCertificateAuthorityCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    issue_certificate: Option<
+        Box<
+            dyn Fn(
+                    IssueCertificateRequest,
+                ) -> ::std::result::Result<
+                    IssueCertificateResponse,
+                    RusotoError<IssueCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_certificate_authorities: Option<
+        Box<
+            dyn Fn(
+                    ListCertificateAuthoritiesRequest,
+                ) -> ::std::result::Result<
+                    ListCertificateAuthoritiesResponse,
+                    RusotoError<ListCertificateAuthoritiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_permissions: Option<
+        Box<
+            dyn Fn(
+                    ListPermissionsRequest,
+                ) -> ::std::result::Result<
+                    ListPermissionsResponse,
+                    RusotoError<ListPermissionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags: Option<
+        Box<
+            dyn Fn(
+                    ListTagsRequest,
+                )
+                    -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    restore_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    RestoreCertificateAuthorityRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<RestoreCertificateAuthorityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    revoke_certificate: Option<
+        Box<
+            dyn Fn(
+                    RevokeCertificateRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<RevokeCertificateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    TagCertificateAuthorityRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<TagCertificateAuthorityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    UntagCertificateAuthorityRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UntagCertificateAuthorityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    UpdateCertificateAuthorityRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateCertificateAuthorityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAcmPca {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAcmPca {
+        Default::default()
+    }
+
+    /// Answers `create_certificate_authority` with what `handler` returns for its input.
+    pub fn with_create_certificate_authority<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                CreateCertificateAuthorityRequest,
+            ) -> ::std::result::Result<
+                CreateCertificateAuthorityResponse,
+                RusotoError<CreateCertificateAuthorityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_certificate_authority_audit_report` with what `handler` returns for its input.
+    pub fn with_create_certificate_authority_audit_report<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                CreateCertificateAuthorityAuditReportRequest,
+            ) -> ::std::result::Result<
+                CreateCertificateAuthorityAuditReportResponse,
+                RusotoError<CreateCertificateAuthorityAuditReportError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_certificate_authority_audit_report = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_permission` with what `handler` returns for its input.
+    pub fn with_create_permission<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                CreatePermissionRequest,
+            ) -> ::std::result::Result<(), RusotoError<CreatePermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_certificate_authority` with what `handler` returns for its input.
+    pub fn with_delete_certificate_authority<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                DeleteCertificateAuthorityRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteCertificateAuthorityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_permission` with what `handler` returns for its input.
+    pub fn with_delete_permission<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                DeletePermissionRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeletePermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_certificate_authority` with what `handler` returns for its input.
+    pub fn with_describe_certificate_authority<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                DescribeCertificateAuthorityRequest,
+            ) -> ::std::result::Result<
+                DescribeCertificateAuthorityResponse,
+                RusotoError<DescribeCertificateAuthorityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_certificate_authority_audit_report` with what `handler` returns for its input.
+    pub fn with_describe_certificate_authority_audit_report<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                DescribeCertificateAuthorityAuditReportRequest,
+            ) -> ::std::result::Result<
+                DescribeCertificateAuthorityAuditReportResponse,
+                RusotoError<DescribeCertificateAuthorityAuditReportError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_certificate_authority_audit_report = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_certificate` with what `handler` returns for its input.
+    pub fn with_get_certificate<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                GetCertificateRequest,
+            )
+                -> ::std::result::Result<GetCertificateResponse, RusotoError<GetCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_certificate_authority_certificate` with what `handler` returns for its input.
+    pub fn with_get_certificate_authority_certificate<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                GetCertificateAuthorityCertificateRequest,
+            ) -> ::std::result::Result<
+                GetCertificateAuthorityCertificateResponse,
+                RusotoError<GetCertificateAuthorityCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_certificate_authority_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_certificate_authority_csr` with what `handler` returns for its input.
+    pub fn with_get_certificate_authority_csr<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                GetCertificateAuthorityCsrRequest,
+            ) -> ::std::result::Result<
+                GetCertificateAuthorityCsrResponse,
+                RusotoError<GetCertificateAuthorityCsrError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_certificate_authority_csr = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_certificate_authority_certificate` with what `handler` returns for its input.
+    pub fn with_import_certificate_authority_certificate<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                ImportCertificateAuthorityCertificateRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<ImportCertificateAuthorityCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.import_certificate_authority_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `issue_certificate` with what `handler` returns for its input.
+    pub fn with_issue_certificate<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                IssueCertificateRequest,
+            ) -> ::std::result::Result<
+                IssueCertificateResponse,
+                RusotoError<IssueCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.issue_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_certificate_authorities` with what `handler` returns for its input.
+    pub fn with_list_certificate_authorities<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                ListCertificateAuthoritiesRequest,
+            ) -> ::std::result::Result<
+                ListCertificateAuthoritiesResponse,
+                RusotoError<ListCertificateAuthoritiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_certificate_authorities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_permissions` with what `handler` returns for its input.
+    pub fn with_list_permissions<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                ListPermissionsRequest,
+            )
+                -> ::std::result::Result<ListPermissionsResponse, RusotoError<ListPermissionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags` with what `handler` returns for its input.
+    pub fn with_list_tags<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                ListTagsRequest,
+            ) -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_certificate_authority` with what `handler` returns for its input.
+    pub fn with_restore_certificate_authority<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                RestoreCertificateAuthorityRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<RestoreCertificateAuthorityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `revoke_certificate` with what `handler` returns for its input.
+    pub fn with_revoke_certificate<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                RevokeCertificateRequest,
+            ) -> ::std::result::Result<(), RusotoError<RevokeCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.revoke_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_certificate_authority` with what `handler` returns for its input.
+    pub fn with_tag_certificate_authority<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                TagCertificateAuthorityRequest,
+            ) -> ::std::result::Result<(), RusotoError<TagCertificateAuthorityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_certificate_authority` with what `handler` returns for its input.
+    pub fn with_untag_certificate_authority<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                UntagCertificateAuthorityRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<UntagCertificateAuthorityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_certificate_authority` with what `handler` returns for its input.
+    pub fn with_update_certificate_authority<F>(mut self, handler: F) -> MockAcmPca
+    where
+        F: Fn(
+                UpdateCertificateAuthorityRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<UpdateCertificateAuthorityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_certificate_authority = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl AcmPca for MockAcmPca {
+    fn create_certificate_authority(
+        &self,
+        input: CreateCertificateAuthorityRequest,
+    ) -> RusotoFuture<CreateCertificateAuthorityResponse, CreateCertificateAuthorityError> {
+        match self.create_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for create_certificate_authority"),
+        }
+    }
+    fn create_certificate_authority_audit_report(
+        &self,
+        input: CreateCertificateAuthorityAuditReportRequest,
+    ) -> RusotoFuture<
+        CreateCertificateAuthorityAuditReportResponse,
+        CreateCertificateAuthorityAuditReportError,
+    > {
+        match self.create_certificate_authority_audit_report {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAcmPca has no handler for create_certificate_authority_audit_report")
+            }
+        }
+    }
+    fn create_permission(
+        &self,
+        input: CreatePermissionRequest,
+    ) -> RusotoFuture<(), CreatePermissionError> {
+        match self.create_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for create_permission"),
+        }
+    }
+    fn delete_certificate_authority(
+        &self,
+        input: DeleteCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), DeleteCertificateAuthorityError> {
+        match self.delete_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for delete_certificate_authority"),
+        }
+    }
+    fn delete_permission(
+        &self,
+        input: DeletePermissionRequest,
+    ) -> RusotoFuture<(), DeletePermissionError> {
+        match self.delete_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for delete_permission"),
+        }
+    }
+    fn describe_certificate_authority(
+        &self,
+        input: DescribeCertificateAuthorityRequest,
+    ) -> RusotoFuture<DescribeCertificateAuthorityResponse, DescribeCertificateAuthorityError> {
+        match self.describe_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for describe_certificate_authority"),
+        }
+    }
+    fn describe_certificate_authority_audit_report(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> RusotoFuture<
+        DescribeCertificateAuthorityAuditReportResponse,
+        DescribeCertificateAuthorityAuditReportError,
+    > {
+        match self.describe_certificate_authority_audit_report {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAcmPca has no handler for describe_certificate_authority_audit_report")
+            }
+        }
+    }
+    fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        match self.get_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for get_certificate"),
+        }
+    }
+    fn get_certificate_authority_certificate(
+        &self,
+        input: GetCertificateAuthorityCertificateRequest,
+    ) -> RusotoFuture<
+        GetCertificateAuthorityCertificateResponse,
+        GetCertificateAuthorityCertificateError,
+    > {
+        match self.get_certificate_authority_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for get_certificate_authority_certificate"),
+        }
+    }
+    fn get_certificate_authority_csr(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> RusotoFuture<GetCertificateAuthorityCsrResponse, GetCertificateAuthorityCsrError> {
+        match self.get_certificate_authority_csr {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for get_certificate_authority_csr"),
+        }
+    }
+    fn import_certificate_authority_certificate(
+        &self,
+        input: ImportCertificateAuthorityCertificateRequest,
+    ) -> RusotoFuture<(), ImportCertificateAuthorityCertificateError> {
+        match self.import_certificate_authority_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAcmPca has no handler for import_certificate_authority_certificate")
+            }
+        }
+    }
+    fn issue_certificate(
+        &self,
+        input: IssueCertificateRequest,
+    ) -> RusotoFuture<IssueCertificateResponse, IssueCertificateError> {
+        match self.issue_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for issue_certificate"),
+        }
+    }
+    fn list_certificate_authorities(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<ListCertificateAuthoritiesResponse, ListCertificateAuthoritiesError> {
+        match self.list_certificate_authorities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for list_certificate_authorities"),
+        }
+    }
+    fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> RusotoFuture<ListPermissionsResponse, ListPermissionsError> {
+        match self.list_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for list_permissions"),
+        }
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        match self.list_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for list_tags"),
+        }
+    }
+    fn restore_certificate_authority(
+        &self,
+        input: RestoreCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), RestoreCertificateAuthorityError> {
+        match self.restore_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for restore_certificate_authority"),
+        }
+    }
+    fn revoke_certificate(
+        &self,
+        input: RevokeCertificateRequest,
+    ) -> RusotoFuture<(), RevokeCertificateError> {
+        match self.revoke_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for revoke_certificate"),
+        }
+    }
+    fn tag_certificate_authority(
+        &self,
+        input: TagCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), TagCertificateAuthorityError> {
+        match self.tag_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for tag_certificate_authority"),
+        }
+    }
+    fn untag_certificate_authority(
+        &self,
+        input: UntagCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), UntagCertificateAuthorityError> {
+        match self.untag_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for untag_certificate_authority"),
+        }
+    }
+    fn update_certificate_authority(
+        &self,
+        input: UpdateCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), UpdateCertificateAuthorityError> {
+        match self.update_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcmPca has no handler for update_certificate_authority"),
+        }
+    }
+}
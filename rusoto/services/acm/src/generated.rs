@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToCertificateRequest {
     /// <p>String that contains the ARN of the ACM certificate to which the tag is to be applied. This must be of the form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>. </p>
     #[serde(rename = "CertificateArn")]
@@ -33,9 +35,35 @@ pub struct AddTagsToCertificateRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for AddTagsToCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Contains metadata about an ACM certificate. This structure is returned in the response to a <a>DescribeCertificate</a> request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CertificateDetail {
     /// <p>The Amazon Resource Name (ARN) of the certificate. For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a> in the <i>AWS General Reference</i>.</p>
     #[serde(rename = "CertificateArn")]
@@ -154,7 +182,7 @@ pub struct CertificateOptions {
 
 /// <p>This structure is returned in the response object of <a>ListCertificates</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CertificateSummary {
     /// <p>Amazon Resource Name (ARN) of the certificate. This is of the form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>. </p>
     #[serde(rename = "CertificateArn")]
@@ -167,21 +195,55 @@ pub struct CertificateSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCertificateRequest {
     /// <p>String that contains the ARN of the ACM certificate to be deleted. This must be of the form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "CertificateArn")]
     pub certificate_arn: String,
 }
 
+impl Validate for DeleteCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCertificateRequest {
     /// <p>The Amazon Resource Name (ARN) of the ACM certificate. The ARN must have the following form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "CertificateArn")]
     pub certificate_arn: String,
 }
 
+impl Validate for DescribeCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCertificateResponse {
     /// <p>Metadata about an ACM certificate.</p>
     #[serde(rename = "Certificate")]
@@ -191,7 +253,7 @@ pub struct DescribeCertificateResponse {
 
 /// <p>Contains information about the validation of each domain name in the certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainValidation {
     /// <p>A fully qualified domain name (FQDN) in the certificate. For example, <code>www.example.com</code> or <code>example.com</code>. </p>
     #[serde(rename = "DomainName")]
@@ -220,6 +282,7 @@ pub struct DomainValidation {
 
 /// <p>Contains information about the domain names that you want ACM to use to send you emails that enable you to validate domain ownership.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DomainValidationOption {
     /// <p>A fully qualified domain name (FQDN) in the certificate request.</p>
     #[serde(rename = "DomainName")]
@@ -229,7 +292,27 @@ pub struct DomainValidationOption {
     pub validation_domain: String,
 }
 
+impl Validate for DomainValidationOption {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(&field_path(path, "domain_name"), &self.domain_name, "^(\\*\\.)?(((?!-)[A-Za-z0-9-]{0,62}[A-Za-z0-9])\\.)+((?!-)[A-Za-z0-9-]{1,62}[A-Za-z0-9])$");
+        errors.check_length(
+            &field_path(path, "validation_domain"),
+            self.validation_domain.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(&field_path(path, "validation_domain"), &self.validation_domain, "^(\\*\\.)?(((?!-)[A-Za-z0-9-]{0,62}[A-Za-z0-9])\\.)+((?!-)[A-Za-z0-9-]{1,62}[A-Za-z0-9])$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExportCertificateRequest {
     /// <p>An Amazon Resource Name (ARN) of the issued certificate. This must be of the form:</p> <p> <code>arn:aws:acm:region:account:certificate/12345678-1234-1234-1234-123456789012</code> </p>
     #[serde(rename = "CertificateArn")]
@@ -244,8 +327,30 @@ pub struct ExportCertificateRequest {
     pub passphrase: bytes::Bytes,
 }
 
+impl Validate for ExportCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "passphrase"),
+            self.passphrase.len(),
+            Some(4),
+            Some(128),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportCertificateResponse {
     /// <p>The base64 PEM-encoded certificate.</p>
     #[serde(rename = "Certificate")]
@@ -263,7 +368,7 @@ pub struct ExportCertificateResponse {
 
 /// <p>The Extended Key Usage X.509 v3 extension defines one or more purposes for which the public key can be used. This is in addition to or in place of the basic purposes specified by the Key Usage extension. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExtendedKeyUsage {
     /// <p>The name of an Extended Key Usage value.</p>
     #[serde(rename = "Name")]
@@ -277,6 +382,7 @@ pub struct ExtendedKeyUsage {
 
 /// <p>This structure can be used in the <a>ListCertificates</a> action to filter the output of the certificate list. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filters {
     /// <p>Specify one or more <a>ExtendedKeyUsage</a> extension values.</p>
     #[serde(rename = "extendedKeyUsage")]
@@ -293,14 +399,31 @@ pub struct Filters {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCertificateRequest {
     /// <p>String that contains a certificate ARN in the following format:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "CertificateArn")]
     pub certificate_arn: String,
 }
 
+impl Validate for GetCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCertificateResponse {
     /// <p>String that contains the ACM certificate represented by the ARN specified at input.</p>
     #[serde(rename = "Certificate")]
@@ -313,6 +436,7 @@ pub struct GetCertificateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportCertificateRequest {
     /// <p>The certificate to import.</p>
     #[serde(rename = "Certificate")]
@@ -345,8 +469,46 @@ pub struct ImportCertificateRequest {
     pub private_key: bytes::Bytes,
 }
 
+impl Validate for ImportCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate"),
+            self.certificate.len(),
+            Some(1),
+            Some(32768),
+        );
+        if let Some(ref value) = self.certificate_arn {
+            errors.check_length(
+                &field_path(path, "certificate_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "certificate_arn"),
+                &value,
+                "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+            );
+        }
+        if let Some(ref value) = self.certificate_chain {
+            errors.check_length(
+                &field_path(path, "certificate_chain"),
+                value.len(),
+                Some(1),
+                Some(2097152),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "private_key"),
+            self.private_key.len(),
+            Some(1),
+            Some(5120),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportCertificateResponse {
     /// <p>The <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Name (ARN)</a> of the imported certificate.</p>
     #[serde(rename = "CertificateArn")]
@@ -356,7 +518,7 @@ pub struct ImportCertificateResponse {
 
 /// <p>The Key Usage X.509 v3 extension defines the purpose of the public key contained in the certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KeyUsage {
     /// <p>A string value that contains a Key Usage extension name.</p>
     #[serde(rename = "Name")]
@@ -365,6 +527,7 @@ pub struct KeyUsage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCertificatesRequest {
     /// <p>Filter the certificate list by status value.</p>
     #[serde(rename = "CertificateStatuses")]
@@ -384,8 +547,29 @@ pub struct ListCertificatesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListCertificatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(1000));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(10000),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCertificatesResponse {
     /// <p>A list of ACM certificates.</p>
     #[serde(rename = "CertificateSummaryList")]
@@ -398,14 +582,31 @@ pub struct ListCertificatesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForCertificateRequest {
     /// <p>String that contains the ARN of the ACM certificate for which you want to list the tags. This must have the following form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>. </p>
     #[serde(rename = "CertificateArn")]
     pub certificate_arn: String,
 }
 
+impl Validate for ListTagsForCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForCertificateResponse {
     /// <p>The key-value pairs that define the applied tags.</p>
     #[serde(rename = "Tags")]
@@ -414,6 +615,7 @@ pub struct ListTagsForCertificateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromCertificateRequest {
     /// <p>String that contains the ARN of the ACM Certificate with one or more tags that you want to remove. This must be of the form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>. </p>
     #[serde(rename = "CertificateArn")]
@@ -423,16 +625,59 @@ pub struct RemoveTagsFromCertificateRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for RemoveTagsFromCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RenewCertificateRequest {
     /// <p>String that contains the ARN of the ACM certificate to be renewed. This must be of the form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "CertificateArn")]
     pub certificate_arn: String,
 }
 
+impl Validate for RenewCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 /// <p>Contains information about the status of ACM's <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for the certificate. This structure exists only when the certificate type is <code>AMAZON_ISSUED</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RenewalSummary {
     /// <p>Contains information about the validation of each domain name in the certificate, as it pertains to ACM's <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a>. This is different from the initial validation that occurs as a result of the <a>RequestCertificate</a> request. This field exists only when the certificate type is <code>AMAZON_ISSUED</code>.</p>
     #[serde(rename = "DomainValidationOptions")]
@@ -450,6 +695,7 @@ pub struct RenewalSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RequestCertificateRequest {
     /// <p>The Amazon Resource Name (ARN) of the private certificate authority (CA) that will be used to issue the certificate. If you do not provide an ARN and you are trying to request a private certificate, ACM will attempt to issue a public certificate. For more information about private CAs, see the <a href="https://docs.aws.amazon.com/acm-pca/latest/userguide/PcaWelcome.html">AWS Certificate Manager Private Certificate Authority (PCA)</a> user guide. The ARN must have the following form: </p> <p> <code>arn:aws:acm-pca:region:account:certificate-authority/12345678-1234-1234-1234-123456789012</code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -480,8 +726,67 @@ pub struct RequestCertificateRequest {
     pub validation_method: Option<String>,
 }
 
+impl Validate for RequestCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.certificate_authority_arn {
+            errors.check_length(
+                &field_path(path, "certificate_authority_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "certificate_authority_arn"),
+                &value,
+                "arn:[\\w+=/,.@-]+:acm-pca:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(&field_path(path, "domain_name"), &self.domain_name, "^(\\*\\.)?(((?!-)[A-Za-z0-9-]{0,62}[A-Za-z0-9])\\.)+((?!-)[A-Za-z0-9-]{1,62}[A-Za-z0-9])$");
+        if let Some(ref value) = self.domain_validation_options {
+            errors.check_length(
+                &field_path(path, "domain_validation_options"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "domain_validation_options"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.idempotency_token {
+            errors.check_length(
+                &field_path(path, "idempotency_token"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+            errors.check_pattern(&field_path(path, "idempotency_token"), &value, "\\w+");
+        }
+        if let Some(ref value) = self.subject_alternative_names {
+            errors.check_length(
+                &field_path(path, "subject_alternative_names"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "subject_alternative_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(253));
+                errors.check_pattern(&item_path, &item, "^(\\*\\.)?(((?!-)[A-Za-z0-9-]{0,62}[A-Za-z0-9])\\.)+((?!-)[A-Za-z0-9-]{1,62}[A-Za-z0-9])$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RequestCertificateResponse {
     /// <p>String that contains the ARN of the issued certificate. This must be of the form:</p> <p> <code>arn:aws:acm:us-east-1:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p>
     #[serde(rename = "CertificateArn")]
@@ -490,6 +795,7 @@ pub struct RequestCertificateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResendValidationEmailRequest {
     /// <p>String that contains the ARN of the requested certificate. The certificate ARN is generated and returned by the <a>RequestCertificate</a> action as soon as the request is made. By default, using this parameter causes email to be sent to all top-level domains you specified in the certificate request. The ARN must be of the form: </p> <p> <code>arn:aws:acm:us-east-1:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p>
     #[serde(rename = "CertificateArn")]
@@ -502,9 +808,39 @@ pub struct ResendValidationEmailRequest {
     pub validation_domain: String,
 }
 
+impl Validate for ResendValidationEmailRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(&field_path(path, "domain"), &self.domain, "^(\\*\\.)?(((?!-)[A-Za-z0-9-]{0,62}[A-Za-z0-9])\\.)+((?!-)[A-Za-z0-9-]{1,62}[A-Za-z0-9])$");
+        errors.check_length(
+            &field_path(path, "validation_domain"),
+            self.validation_domain.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(&field_path(path, "validation_domain"), &self.validation_domain, "^(\\*\\.)?(((?!-)[A-Za-z0-9-]{0,62}[A-Za-z0-9])\\.)+((?!-)[A-Za-z0-9-]{1,62}[A-Za-z0-9])$");
+    }
+}
+
 /// <p>Contains a DNS record value that you can use to can use to validate ownership or control of a domain. This is used by the <a>DescribeCertificate</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceRecord {
     /// <p>The name of the DNS record to create in your domain. This is supplied by ACM.</p>
     #[serde(rename = "Name")]
@@ -529,7 +865,37 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "[\\p{L}\\p{Z}\\p{N}_.:\\/=+\\-@]*",
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "[\\p{L}\\p{Z}\\p{N}_.:\\/=+\\-@]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCertificateOptionsRequest {
     /// <p>ARN of the requested certificate to update. This must be of the form:</p> <p> <code>arn:aws:acm:us-east-1:<i>account</i>:certificate/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateArn")]
@@ -539,6 +905,22 @@ pub struct UpdateCertificateOptionsRequest {
     pub options: CertificateOptions,
 }
 
+impl Validate for UpdateCertificateOptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate_arn"),
+            &self.certificate_arn,
+            "arn:[\\w+=/,.@-]+:acm:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=,.@-]+)*",
+        );
+    }
+}
+
 /// Errors returned by AddTagsToCertificate
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToCertificateError {
@@ -570,7 +952,12 @@ impl AddTagsToCertificateError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(AddTagsToCertificateError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -616,7 +1003,12 @@ impl DeleteCertificateError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteCertificateError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -658,7 +1050,12 @@ impl DescribeCertificateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -702,7 +1099,12 @@ impl ExportCertificateError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ExportCertificateError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -747,7 +1149,12 @@ impl GetCertificateError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetCertificateError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -787,7 +1194,12 @@ impl ImportCertificateError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ImportCertificateError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -821,7 +1233,12 @@ impl ListCertificatesError {
                 "InvalidArgsException" => {
                     return RusotoError::Service(ListCertificatesError::InvalidArgs(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -861,7 +1278,12 @@ impl ListTagsForCertificateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -911,7 +1333,12 @@ impl RemoveTagsFromCertificateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -951,7 +1378,12 @@ impl RenewCertificateError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RenewCertificateError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -997,7 +1429,12 @@ impl RequestCertificateError {
                 "LimitExceededException" => {
                     return RusotoError::Service(RequestCertificateError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1051,7 +1488,12 @@ impl ResendValidationEmailError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1108,7 +1550,12 @@ impl UpdateCertificateOptionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1210,6 +1657,247 @@ pub trait Acm {
         input: UpdateCertificateOptionsRequest,
     ) -> RusotoFuture<(), UpdateCertificateOptionsError>;
 }
+const _: Option<&dyn Acm> = None;
+impl<'a, T: Acm + ?Sized> Acm for &'a T {
+    fn add_tags_to_certificate(
+        &self,
+        input: AddTagsToCertificateRequest,
+    ) -> RusotoFuture<(), AddTagsToCertificateError> {
+        (**self).add_tags_to_certificate(input)
+    }
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> RusotoFuture<(), DeleteCertificateError> {
+        (**self).delete_certificate(input)
+    }
+    fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> RusotoFuture<DescribeCertificateResponse, DescribeCertificateError> {
+        (**self).describe_certificate(input)
+    }
+    fn export_certificate(
+        &self,
+        input: ExportCertificateRequest,
+    ) -> RusotoFuture<ExportCertificateResponse, ExportCertificateError> {
+        (**self).export_certificate(input)
+    }
+    fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        (**self).get_certificate(input)
+    }
+    fn import_certificate(
+        &self,
+        input: ImportCertificateRequest,
+    ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        (**self).import_certificate(input)
+    }
+    fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> RusotoFuture<ListCertificatesResponse, ListCertificatesError> {
+        (**self).list_certificates(input)
+    }
+    fn list_tags_for_certificate(
+        &self,
+        input: ListTagsForCertificateRequest,
+    ) -> RusotoFuture<ListTagsForCertificateResponse, ListTagsForCertificateError> {
+        (**self).list_tags_for_certificate(input)
+    }
+    fn remove_tags_from_certificate(
+        &self,
+        input: RemoveTagsFromCertificateRequest,
+    ) -> RusotoFuture<(), RemoveTagsFromCertificateError> {
+        (**self).remove_tags_from_certificate(input)
+    }
+    fn renew_certificate(
+        &self,
+        input: RenewCertificateRequest,
+    ) -> RusotoFuture<(), RenewCertificateError> {
+        (**self).renew_certificate(input)
+    }
+    fn request_certificate(
+        &self,
+        input: RequestCertificateRequest,
+    ) -> RusotoFuture<RequestCertificateResponse, RequestCertificateError> {
+        (**self).request_certificate(input)
+    }
+    fn resend_validation_email(
+        &self,
+        input: ResendValidationEmailRequest,
+    ) -> RusotoFuture<(), ResendValidationEmailError> {
+        (**self).resend_validation_email(input)
+    }
+    fn update_certificate_options(
+        &self,
+        input: UpdateCertificateOptionsRequest,
+    ) -> RusotoFuture<(), UpdateCertificateOptionsError> {
+        (**self).update_certificate_options(input)
+    }
+}
+impl<T: Acm + ?Sized> Acm for Box<T> {
+    fn add_tags_to_certificate(
+        &self,
+        input: AddTagsToCertificateRequest,
+    ) -> RusotoFuture<(), AddTagsToCertificateError> {
+        (**self).add_tags_to_certificate(input)
+    }
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> RusotoFuture<(), DeleteCertificateError> {
+        (**self).delete_certificate(input)
+    }
+    fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> RusotoFuture<DescribeCertificateResponse, DescribeCertificateError> {
+        (**self).describe_certificate(input)
+    }
+    fn export_certificate(
+        &self,
+        input: ExportCertificateRequest,
+    ) -> RusotoFuture<ExportCertificateResponse, ExportCertificateError> {
+        (**self).export_certificate(input)
+    }
+    fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        (**self).get_certificate(input)
+    }
+    fn import_certificate(
+        &self,
+        input: ImportCertificateRequest,
+    ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        (**self).import_certificate(input)
+    }
+    fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> RusotoFuture<ListCertificatesResponse, ListCertificatesError> {
+        (**self).list_certificates(input)
+    }
+    fn list_tags_for_certificate(
+        &self,
+        input: ListTagsForCertificateRequest,
+    ) -> RusotoFuture<ListTagsForCertificateResponse, ListTagsForCertificateError> {
+        (**self).list_tags_for_certificate(input)
+    }
+    fn remove_tags_from_certificate(
+        &self,
+        input: RemoveTagsFromCertificateRequest,
+    ) -> RusotoFuture<(), RemoveTagsFromCertificateError> {
+        (**self).remove_tags_from_certificate(input)
+    }
+    fn renew_certificate(
+        &self,
+        input: RenewCertificateRequest,
+    ) -> RusotoFuture<(), RenewCertificateError> {
+        (**self).renew_certificate(input)
+    }
+    fn request_certificate(
+        &self,
+        input: RequestCertificateRequest,
+    ) -> RusotoFuture<RequestCertificateResponse, RequestCertificateError> {
+        (**self).request_certificate(input)
+    }
+    fn resend_validation_email(
+        &self,
+        input: ResendValidationEmailRequest,
+    ) -> RusotoFuture<(), ResendValidationEmailError> {
+        (**self).resend_validation_email(input)
+    }
+    fn update_certificate_options(
+        &self,
+        input: UpdateCertificateOptionsRequest,
+    ) -> RusotoFuture<(), UpdateCertificateOptionsError> {
+        (**self).update_certificate_options(input)
+    }
+}
+impl<T: Acm + ?Sized> Acm for ::std::sync::Arc<T> {
+    fn add_tags_to_certificate(
+        &self,
+        input: AddTagsToCertificateRequest,
+    ) -> RusotoFuture<(), AddTagsToCertificateError> {
+        (**self).add_tags_to_certificate(input)
+    }
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> RusotoFuture<(), DeleteCertificateError> {
+        (**self).delete_certificate(input)
+    }
+    fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> RusotoFuture<DescribeCertificateResponse, DescribeCertificateError> {
+        (**self).describe_certificate(input)
+    }
+    fn export_certificate(
+        &self,
+        input: ExportCertificateRequest,
+    ) -> RusotoFuture<ExportCertificateResponse, ExportCertificateError> {
+        (**self).export_certificate(input)
+    }
+    fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        (**self).get_certificate(input)
+    }
+    fn import_certificate(
+        &self,
+        input: ImportCertificateRequest,
+    ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        (**self).import_certificate(input)
+    }
+    fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> RusotoFuture<ListCertificatesResponse, ListCertificatesError> {
+        (**self).list_certificates(input)
+    }
+    fn list_tags_for_certificate(
+        &self,
+        input: ListTagsForCertificateRequest,
+    ) -> RusotoFuture<ListTagsForCertificateResponse, ListTagsForCertificateError> {
+        (**self).list_tags_for_certificate(input)
+    }
+    fn remove_tags_from_certificate(
+        &self,
+        input: RemoveTagsFromCertificateRequest,
+    ) -> RusotoFuture<(), RemoveTagsFromCertificateError> {
+        (**self).remove_tags_from_certificate(input)
+    }
+    fn renew_certificate(
+        &self,
+        input: RenewCertificateRequest,
+    ) -> RusotoFuture<(), RenewCertificateError> {
+        (**self).renew_certificate(input)
+    }
+    fn request_certificate(
+        &self,
+        input: RequestCertificateRequest,
+    ) -> RusotoFuture<RequestCertificateResponse, RequestCertificateError> {
+        (**self).request_certificate(input)
+    }
+    fn resend_validation_email(
+        &self,
+        input: ResendValidationEmailRequest,
+    ) -> RusotoFuture<(), ResendValidationEmailError> {
+        (**self).resend_validation_email(input)
+    }
+    fn update_certificate_options(
+        &self,
+        input: UpdateCertificateOptionsRequest,
+    ) -> RusotoFuture<(), UpdateCertificateOptionsError> {
+        (**self).update_certificate_options(input)
+    }
+}
 /// A client for the ACM API.
 #[derive(Clone)]
 pub struct AcmClient {
@@ -1253,7 +1941,16 @@ impl Acm for AcmClient {
         &self,
         input: AddTagsToCertificateRequest,
     ) -> RusotoFuture<(), AddTagsToCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("AddTagsToCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.AddTagsToCertificate");
@@ -1278,7 +1975,16 @@ impl Acm for AcmClient {
         &self,
         input: DeleteCertificateRequest,
     ) -> RusotoFuture<(), DeleteCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("DeleteCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.DeleteCertificate");
@@ -1304,7 +2010,16 @@ impl Acm for AcmClient {
         &self,
         input: DescribeCertificateRequest,
     ) -> RusotoFuture<DescribeCertificateResponse, DescribeCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("DescribeCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.DescribeCertificate");
@@ -1313,10 +2028,10 @@ impl Acm for AcmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCertificateResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeCertificateResponse, _>(
+                    response,
+                    "DescribeCertificate",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1332,7 +2047,16 @@ impl Acm for AcmClient {
         &self,
         input: ExportCertificateRequest,
     ) -> RusotoFuture<ExportCertificateResponse, ExportCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ExportCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ExportCertificate");
@@ -1341,10 +2065,10 @@ impl Acm for AcmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ExportCertificateResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ExportCertificateResponse, _>(
+                    response,
+                    "ExportCertificate",
+                )
             } else {
                 Box::new(
                     response
@@ -1361,7 +2085,16 @@ impl Acm for AcmClient {
         &self,
         input: GetCertificateRequest,
     ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("GetCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.GetCertificate");
@@ -1370,10 +2103,10 @@ impl Acm for AcmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCertificateResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetCertificateResponse, _>(
+                    response,
+                    "GetCertificate",
+                )
             } else {
                 Box::new(
                     response
@@ -1390,7 +2123,16 @@ impl Acm for AcmClient {
         &self,
         input: ImportCertificateRequest,
     ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ImportCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ImportCertificate");
@@ -1399,10 +2141,10 @@ impl Acm for AcmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportCertificateResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ImportCertificateResponse, _>(
+                    response,
+                    "ImportCertificate",
+                )
             } else {
                 Box::new(
                     response
@@ -1419,7 +2161,16 @@ impl Acm for AcmClient {
         &self,
         input: ListCertificatesRequest,
     ) -> RusotoFuture<ListCertificatesResponse, ListCertificatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ListCertificates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ListCertificates");
@@ -1428,10 +2179,10 @@ impl Acm for AcmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCertificatesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListCertificatesResponse, _>(
+                    response,
+                    "ListCertificates",
+                )
             } else {
                 Box::new(
                     response
@@ -1448,7 +2199,16 @@ impl Acm for AcmClient {
         &self,
         input: ListTagsForCertificateRequest,
     ) -> RusotoFuture<ListTagsForCertificateResponse, ListTagsForCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ListTagsForCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ListTagsForCertificate");
@@ -1457,10 +2217,10 @@ impl Acm for AcmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForCertificateResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForCertificateResponse, _>(
+                    response,
+                    "ListTagsForCertificate",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1476,7 +2236,16 @@ impl Acm for AcmClient {
         &self,
         input: RemoveTagsFromCertificateRequest,
     ) -> RusotoFuture<(), RemoveTagsFromCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("RemoveTagsFromCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1502,7 +2271,16 @@ impl Acm for AcmClient {
         &self,
         input: RenewCertificateRequest,
     ) -> RusotoFuture<(), RenewCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("RenewCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.RenewCertificate");
@@ -1528,7 +2306,16 @@ impl Acm for AcmClient {
         &self,
         input: RequestCertificateRequest,
     ) -> RusotoFuture<RequestCertificateResponse, RequestCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("RequestCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.RequestCertificate");
@@ -1537,10 +2324,10 @@ impl Acm for AcmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RequestCertificateResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RequestCertificateResponse, _>(
+                    response,
+                    "RequestCertificate",
+                )
             } else {
                 Box::new(
                     response
@@ -1557,7 +2344,16 @@ impl Acm for AcmClient {
         &self,
         input: ResendValidationEmailRequest,
     ) -> RusotoFuture<(), ResendValidationEmailError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ResendValidationEmail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ResendValidationEmail");
@@ -1582,7 +2378,16 @@ impl Acm for AcmClient {
         &self,
         input: UpdateCertificateOptionsRequest,
     ) -> RusotoFuture<(), UpdateCertificateOptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("UpdateCertificateOptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1603,3 +2408,461 @@ impl Acm for AcmClient {
         })
     }
 }
+
+/// A mock of the ACM API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAcm {
+    add_tags_to_certificate: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToCertificateRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<AddTagsToCertificateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_certificate: Option<
+        Box<
+            dyn Fn(
+                    DeleteCertificateRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteCertificateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_certificate: Option<
+        Box<
+            dyn Fn(
+                    DescribeCertificateRequest,
+                ) -> ::std::result::Result<
+                    DescribeCertificateResponse,
+                    RusotoError<DescribeCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    export_certificate: Option<
+        Box<
+            dyn Fn(
+                    ExportCertificateRequest,
+                ) -> ::std::result::Result<
+                    ExportCertificateResponse,
+                    RusotoError<ExportCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_certificate: Option<
+        Box<
+            dyn Fn(
+                    GetCertificateRequest,
+                ) -> ::std::result::Result<
+                    GetCertificateResponse,
+                    RusotoError<GetCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    import_certificate: Option<
+        Box<
+            dyn Fn(
+                    ImportCertificateRequest,
+                ) -> ::std::result::Result<
+                    ImportCertificateResponse,
+                    RusotoError<ImportCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_certificates: Option<
+        Box<
+            dyn Fn(
+                    ListCertificatesRequest,
+                ) -> ::std::result::Result<
+                    ListCertificatesResponse,
+                    RusotoError<ListCertificatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_certificate: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForCertificateRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForCertificateResponse,
+                    RusotoError<ListTagsForCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_certificate: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromCertificateRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<RemoveTagsFromCertificateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    renew_certificate: Option<
+        Box<
+            dyn Fn(
+                    RenewCertificateRequest,
+                ) -> ::std::result::Result<(), RusotoError<RenewCertificateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    request_certificate: Option<
+        Box<
+            dyn Fn(
+                    RequestCertificateRequest,
+                ) -> ::std::result::Result<
+                    RequestCertificateResponse,
+                    RusotoError<RequestCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    resend_validation_email: Option<
+        Box<
+            dyn Fn(
+                    ResendValidationEmailRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<ResendValidationEmailError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_certificate_options: Option<
+        Box<
+            dyn Fn(
+                    UpdateCertificateOptionsRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateCertificateOptionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAcm {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAcm {
+        Default::default()
+    }
+
+    /// Answers `add_tags_to_certificate` with what `handler` returns for its input.
+    pub fn with_add_tags_to_certificate<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                AddTagsToCertificateRequest,
+            ) -> ::std::result::Result<(), RusotoError<AddTagsToCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_certificate` with what `handler` returns for its input.
+    pub fn with_delete_certificate<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                DeleteCertificateRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_certificate` with what `handler` returns for its input.
+    pub fn with_describe_certificate<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                DescribeCertificateRequest,
+            ) -> ::std::result::Result<
+                DescribeCertificateResponse,
+                RusotoError<DescribeCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `export_certificate` with what `handler` returns for its input.
+    pub fn with_export_certificate<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                ExportCertificateRequest,
+            ) -> ::std::result::Result<
+                ExportCertificateResponse,
+                RusotoError<ExportCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.export_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_certificate` with what `handler` returns for its input.
+    pub fn with_get_certificate<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                GetCertificateRequest,
+            )
+                -> ::std::result::Result<GetCertificateResponse, RusotoError<GetCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_certificate` with what `handler` returns for its input.
+    pub fn with_import_certificate<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                ImportCertificateRequest,
+            ) -> ::std::result::Result<
+                ImportCertificateResponse,
+                RusotoError<ImportCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_certificates` with what `handler` returns for its input.
+    pub fn with_list_certificates<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                ListCertificatesRequest,
+            ) -> ::std::result::Result<
+                ListCertificatesResponse,
+                RusotoError<ListCertificatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_certificates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_certificate` with what `handler` returns for its input.
+    pub fn with_list_tags_for_certificate<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                ListTagsForCertificateRequest,
+            ) -> ::std::result::Result<
+                ListTagsForCertificateResponse,
+                RusotoError<ListTagsForCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_certificate` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_certificate<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                RemoveTagsFromCertificateRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<RemoveTagsFromCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `renew_certificate` with what `handler` returns for its input.
+    pub fn with_renew_certificate<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                RenewCertificateRequest,
+            ) -> ::std::result::Result<(), RusotoError<RenewCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.renew_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `request_certificate` with what `handler` returns for its input.
+    pub fn with_request_certificate<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                RequestCertificateRequest,
+            ) -> ::std::result::Result<
+                RequestCertificateResponse,
+                RusotoError<RequestCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.request_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `resend_validation_email` with what `handler` returns for its input.
+    pub fn with_resend_validation_email<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                ResendValidationEmailRequest,
+            ) -> ::std::result::Result<(), RusotoError<ResendValidationEmailError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resend_validation_email = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_certificate_options` with what `handler` returns for its input.
+    pub fn with_update_certificate_options<F>(mut self, handler: F) -> MockAcm
+    where
+        F: Fn(
+                UpdateCertificateOptionsRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateCertificateOptionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_certificate_options = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Acm for MockAcm {
+    fn add_tags_to_certificate(
+        &self,
+        input: AddTagsToCertificateRequest,
+    ) -> RusotoFuture<(), AddTagsToCertificateError> {
+        match self.add_tags_to_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for add_tags_to_certificate"),
+        }
+    }
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> RusotoFuture<(), DeleteCertificateError> {
+        match self.delete_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for delete_certificate"),
+        }
+    }
+    fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> RusotoFuture<DescribeCertificateResponse, DescribeCertificateError> {
+        match self.describe_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for describe_certificate"),
+        }
+    }
+    fn export_certificate(
+        &self,
+        input: ExportCertificateRequest,
+    ) -> RusotoFuture<ExportCertificateResponse, ExportCertificateError> {
+        match self.export_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for export_certificate"),
+        }
+    }
+    fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        match self.get_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for get_certificate"),
+        }
+    }
+    fn import_certificate(
+        &self,
+        input: ImportCertificateRequest,
+    ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        match self.import_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for import_certificate"),
+        }
+    }
+    fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> RusotoFuture<ListCertificatesResponse, ListCertificatesError> {
+        match self.list_certificates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for list_certificates"),
+        }
+    }
+    fn list_tags_for_certificate(
+        &self,
+        input: ListTagsForCertificateRequest,
+    ) -> RusotoFuture<ListTagsForCertificateResponse, ListTagsForCertificateError> {
+        match self.list_tags_for_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for list_tags_for_certificate"),
+        }
+    }
+    fn remove_tags_from_certificate(
+        &self,
+        input: RemoveTagsFromCertificateRequest,
+    ) -> RusotoFuture<(), RemoveTagsFromCertificateError> {
+        match self.remove_tags_from_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for remove_tags_from_certificate"),
+        }
+    }
+    fn renew_certificate(
+        &self,
+        input: RenewCertificateRequest,
+    ) -> RusotoFuture<(), RenewCertificateError> {
+        match self.renew_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for renew_certificate"),
+        }
+    }
+    fn request_certificate(
+        &self,
+        input: RequestCertificateRequest,
+    ) -> RusotoFuture<RequestCertificateResponse, RequestCertificateError> {
+        match self.request_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for request_certificate"),
+        }
+    }
+    fn resend_validation_email(
+        &self,
+        input: ResendValidationEmailRequest,
+    ) -> RusotoFuture<(), ResendValidationEmailError> {
+        match self.resend_validation_email {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for resend_validation_email"),
+        }
+    }
+    fn update_certificate_options(
+        &self,
+        input: UpdateCertificateOptionsRequest,
+    ) -> RusotoFuture<(), UpdateCertificateOptionsError> {
+        match self.update_certificate_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAcm has no handler for update_certificate_options"),
+        }
+    }
+}
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>An address book with attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddressBook {
     /// <p>The ARN of the address book.</p>
     #[serde(rename = "AddressBookArn")]
@@ -43,7 +44,7 @@ pub struct AddressBook {
 
 /// <p>Information related to an address book.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddressBookData {
     /// <p>The ARN of the address book.</p>
     #[serde(rename = "AddressBookArn")]
@@ -60,17 +61,25 @@ pub struct AddressBookData {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApproveSkillRequest {
     /// <p>The unique identifier of the skill.</p>
     #[serde(rename = "SkillId")]
     pub skill_id: String,
 }
 
+impl Validate for ApproveSkillRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApproveSkillResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateContactWithAddressBookRequest {
     /// <p>The ARN of the address book with which to associate the contact.</p>
     #[serde(rename = "AddressBookArn")]
@@ -80,11 +89,19 @@ pub struct AssociateContactWithAddressBookRequest {
     pub contact_arn: String,
 }
 
+impl Validate for AssociateContactWithAddressBookRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "address_book_arn"), &self.address_book_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        errors.check_pattern(&field_path(path, "contact_arn"), &self.contact_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateContactWithAddressBookResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateDeviceWithNetworkProfileRequest {
     /// <p>The device ARN.</p>
     #[serde(rename = "DeviceArn")]
@@ -94,11 +111,19 @@ pub struct AssociateDeviceWithNetworkProfileRequest {
     pub network_profile_arn: String,
 }
 
+impl Validate for AssociateDeviceWithNetworkProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "device_arn"), &self.device_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        errors.check_pattern(&field_path(path, "network_profile_arn"), &self.network_profile_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateDeviceWithNetworkProfileResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateDeviceWithRoomRequest {
     /// <p>The ARN of the device to associate to a room. Required.</p>
     #[serde(rename = "DeviceArn")]
@@ -110,11 +135,23 @@ pub struct AssociateDeviceWithRoomRequest {
     pub room_arn: Option<String>,
 }
 
+impl Validate for AssociateDeviceWithRoomRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.device_arn {
+            errors.check_pattern(&field_path(path, "device_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateDeviceWithRoomResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateSkillGroupWithRoomRequest {
     /// <p>The ARN of the room with which to associate the skill group. Required.</p>
     #[serde(rename = "RoomArn")]
@@ -126,11 +163,23 @@ pub struct AssociateSkillGroupWithRoomRequest {
     pub skill_group_arn: Option<String>,
 }
 
+impl Validate for AssociateSkillGroupWithRoomRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.skill_group_arn {
+            errors.check_pattern(&field_path(path, "skill_group_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateSkillGroupWithRoomResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateSkillWithSkillGroupRequest {
     /// <p>The ARN of the skill group to associate the skill to. Required.</p>
     #[serde(rename = "SkillGroupArn")]
@@ -141,23 +190,40 @@ pub struct AssociateSkillWithSkillGroupRequest {
     pub skill_id: String,
 }
 
+impl Validate for AssociateSkillWithSkillGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.skill_group_arn {
+            errors.check_pattern(&field_path(path, "skill_group_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateSkillWithSkillGroupResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateSkillWithUsersRequest {
     /// <p>The private skill ID you want to make available to enrolled users.</p>
     #[serde(rename = "SkillId")]
     pub skill_id: String,
 }
 
+impl Validate for AssociateSkillWithUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateSkillWithUsersResponse {}
 
 /// <p>The audio message. There is a 1 MB limit on the audio file input and the only supported format is MP3. To convert your MP3 audio files to an Alexa-friendly, </p> <p>required codec version (MPEG version 2) and bit rate (48 kbps), you might use converter software. One option for this is a command-line tool, FFmpeg. For more information, see <a href="https://www.ffmpeg.org/">FFmpeg</a>. The following command converts the provided &lt;input-file&gt; to an MP3 file that is played in the announcement:</p> <p> <code>ffmpeg -i &lt;input-file&gt; -ac 2 -codec:a libmp3lame -b:a 48k -ar 16000 &lt;output-file.mp3&gt;</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Audio {
     /// <p>The locale of the audio message. Currently, en-US is supported.</p>
     #[serde(rename = "Locale")]
@@ -167,9 +233,21 @@ pub struct Audio {
     pub location: String,
 }
 
+impl Validate for Audio {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "location"),
+            self.location.chars().count(),
+            None,
+            Some(1200),
+        );
+        errors.check_pattern(&field_path(path, "location"), &self.location, "https://([A-Za-z0-9_.-]+)?(s3-[A-Za-z0-9-]+|s3\\.([A-Za-z0-9-])+|s3|s3.dualstack\\.([A-Za-z0-9-])+)+.amazonaws.com/.*");
+    }
+}
+
 /// <p>Usage report with specified parameters.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BusinessReport {
     /// <p>The time of report delivery.</p>
     #[serde(rename = "DeliveryTime")]
@@ -211,9 +289,21 @@ pub struct BusinessReportRecurrence {
     pub start_date: Option<String>,
 }
 
+impl Validate for BusinessReportRecurrence {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.start_date {
+            errors.check_pattern(
+                &field_path(path, "start_date"),
+                &value,
+                "^\\d{4}\\-(0?[1-9]|1[012])\\-(0?[1-9]|[12][0-9]|3[01])$",
+            );
+        }
+    }
+}
+
 /// <p>The S3 location of the output reports.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BusinessReportS3Location {
     /// <p>The S3 bucket name of the output reports.</p>
     #[serde(rename = "BucketName")]
@@ -227,7 +317,7 @@ pub struct BusinessReportS3Location {
 
 /// <p>The schedule of the usage report.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BusinessReportSchedule {
     /// <p>The content range of the reports.</p>
     #[serde(rename = "ContentRange")]
@@ -265,7 +355,7 @@ pub struct BusinessReportSchedule {
 
 /// <p>The skill store category that is shown. Alexa skills are assigned a specific skill category during creation, such as News, Social, and Sports.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Category {
     /// <p>The ID of the skill store category.</p>
     #[serde(rename = "CategoryId")]
@@ -286,9 +376,17 @@ pub struct ConferencePreference {
     pub default_conference_provider_arn: Option<String>,
 }
 
+impl Validate for ConferencePreference {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_conference_provider_arn {
+            errors.check_pattern(&field_path(path, "default_conference_provider_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 /// <p>An entity that provides a conferencing solution. Alexa for Business acts as the voice interface and mediator that connects users to their preferred conference provider. Examples of conference providers include Amazon Chime, Zoom, Cisco, and Polycom. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConferenceProvider {
     /// <p>The ARN of the newly created conference provider.</p>
     #[serde(rename = "Arn")]
@@ -318,7 +416,7 @@ pub struct ConferenceProvider {
 
 /// <p>A contact with attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Contact {
     /// <p>The ARN of the contact.</p>
     #[serde(rename = "ContactArn")]
@@ -352,7 +450,7 @@ pub struct Contact {
 
 /// <p>Information related to a contact.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ContactData {
     /// <p>The ARN of the contact.</p>
     #[serde(rename = "ContactArn")]
@@ -386,6 +484,7 @@ pub struct ContactData {
 
 /// <p>The content definition. This can contain only one text, SSML, or audio list object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Content {
     /// <p>The list of audio messages.</p>
     #[serde(rename = "AudioList")]
@@ -401,7 +500,34 @@ pub struct Content {
     pub text_list: Option<Vec<Text>>,
 }
 
+impl Validate for Content {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_list {
+            errors.check_length(&field_path(path, "audio_list"), value.len(), None, Some(1));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_list"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.ssml_list {
+            errors.check_length(&field_path(path, "ssml_list"), value.len(), None, Some(1));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "ssml_list"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.text_list {
+            errors.check_length(&field_path(path, "text_list"), value.len(), None, Some(1));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "text_list"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAddressBookRequest {
     /// <p>A unique, user-specified identifier for the request that ensures idempotency.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -416,8 +542,42 @@ pub struct CreateAddressBookRequest {
     pub name: String,
 }
 
+impl Validate for CreateAddressBookRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(10),
+                Some(150),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAddressBookResponse {
     /// <p>The ARN of the newly created address book.</p>
     #[serde(rename = "AddressBookArn")]
@@ -426,6 +586,7 @@ pub struct CreateAddressBookResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBusinessReportScheduleRequest {
     /// <p>The client request token.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -455,8 +616,58 @@ pub struct CreateBusinessReportScheduleRequest {
     pub schedule_name: Option<String>,
 }
 
+impl Validate for CreateBusinessReportScheduleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(10),
+                Some(150),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+            );
+        }
+        if let Some(ref value) = self.recurrence {
+            value.validate_at(&field_path(path, "recurrence"), errors);
+        }
+        if let Some(ref value) = self.s3_bucket_name {
+            errors.check_pattern(
+                &field_path(path, "s3_bucket_name"),
+                &value,
+                "[a-z0-9-\\.]{3,63}",
+            );
+        }
+        if let Some(ref value) = self.s3_key_prefix {
+            errors.check_length(
+                &field_path(path, "s3_key_prefix"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "s3_key_prefix"),
+                &value,
+                "[A-Za-z0-9!_\\-\\.\\*'()/]*",
+            );
+        }
+        if let Some(ref value) = self.schedule_name {
+            errors.check_length(
+                &field_path(path, "schedule_name"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "schedule_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBusinessReportScheduleResponse {
     /// <p>The ARN of the business report schedule.</p>
     #[serde(rename = "ScheduleArn")]
@@ -465,6 +676,7 @@ pub struct CreateBusinessReportScheduleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConferenceProviderRequest {
     /// <p>The request token of the client.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -489,8 +701,39 @@ pub struct CreateConferenceProviderRequest {
     pub pstn_dial_in: Option<PSTNDialIn>,
 }
 
+impl Validate for CreateConferenceProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(10),
+                Some(150),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "conference_provider_name"),
+            self.conference_provider_name.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "conference_provider_name"), &self.conference_provider_name, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        if let Some(ref value) = self.ip_dial_in {
+            value.validate_at(&field_path(path, "ip_dial_in"), errors);
+        }
+        if let Some(ref value) = self.pstn_dial_in {
+            value.validate_at(&field_path(path, "pstn_dial_in"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateConferenceProviderResponse {
     /// <p>The ARN of the newly-created conference provider.</p>
     #[serde(rename = "ConferenceProviderArn")]
@@ -499,6 +742,7 @@ pub struct CreateConferenceProviderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateContactRequest {
     /// <p>A unique, user-specified identifier for this request that ensures idempotency.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -529,8 +773,88 @@ pub struct CreateContactRequest {
     pub sip_addresses: Option<Vec<SipAddress>>,
 }
 
+impl Validate for CreateContactRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(10),
+                Some(150),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+            );
+        }
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "display_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        errors.check_length(
+            &field_path(path, "first_name"),
+            self.first_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "first_name"), &self.first_name, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        if let Some(ref value) = self.last_name {
+            errors.check_length(
+                &field_path(path, "last_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "last_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.phone_number {
+            errors.check_length(
+                &field_path(path, "phone_number"),
+                value.chars().count(),
+                None,
+                Some(50),
+            );
+            errors.check_pattern(
+                &field_path(path, "phone_number"),
+                &value,
+                "^[\\+0-9\\#\\,\\(][\\+0-9\\-\\.\\/\\(\\)\\,\\#\\s]+$",
+            );
+        }
+        if let Some(ref value) = self.phone_numbers {
+            errors.check_length(
+                &field_path(path, "phone_numbers"),
+                value.len(),
+                None,
+                Some(3),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "phone_numbers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.sip_addresses {
+            errors.check_length(
+                &field_path(path, "sip_addresses"),
+                value.len(),
+                None,
+                Some(1),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sip_addresses"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateContactResponse {
     /// <p>The ARN of the newly created address book.</p>
     #[serde(rename = "ContactArn")]
@@ -539,6 +863,7 @@ pub struct CreateContactResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGatewayGroupRequest {
     /// <p> A unique, user-specified identifier for the request that ensures idempotency.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -552,8 +877,39 @@ pub struct CreateGatewayGroupRequest {
     pub name: String,
 }
 
+impl Validate for CreateGatewayGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_request_token"),
+            self.client_request_token.chars().count(),
+            Some(10),
+            Some(150),
+        );
+        errors.check_pattern(
+            &field_path(path, "client_request_token"),
+            &self.client_request_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGatewayGroupResponse {
     /// <p>The ARN of the created gateway group.</p>
     #[serde(rename = "GatewayGroupArn")]
@@ -562,6 +918,7 @@ pub struct CreateGatewayGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateNetworkProfileRequest {
     /// <p>The ARN of the Private Certificate Authority (PCA) created in AWS Certificate Manager (ACM). This is used to issue certificates to the devices. </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -600,8 +957,88 @@ pub struct CreateNetworkProfileRequest {
     pub trust_anchors: Option<Vec<String>>,
 }
 
+impl Validate for CreateNetworkProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.certificate_authority_arn {
+            errors.check_pattern(&field_path(path, "certificate_authority_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        errors.check_length(
+            &field_path(path, "client_request_token"),
+            self.client_request_token.chars().count(),
+            Some(10),
+            Some(150),
+        );
+        errors.check_pattern(
+            &field_path(path, "client_request_token"),
+            &self.client_request_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        if let Some(ref value) = self.current_password {
+            errors.check_length(
+                &field_path(path, "current_password"),
+                value.chars().count(),
+                Some(5),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "current_password"),
+                &value,
+                "[\\x00-\\x7F]*",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        errors.check_length(
+            &field_path(path, "network_profile_name"),
+            self.network_profile_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "network_profile_name"), &self.network_profile_name, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        if let Some(ref value) = self.next_password {
+            errors.check_length(
+                &field_path(path, "next_password"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_password"),
+                &value,
+                "(^$)|([\\x00-\\x7F]{5,})",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "ssid"),
+            self.ssid.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(&field_path(path, "ssid"), &self.ssid, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        if let Some(ref value) = self.trust_anchors {
+            errors.check_length(
+                &field_path(path, "trust_anchors"),
+                value.len(),
+                Some(1),
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "trust_anchors"), i);
+                errors.check_pattern(&item_path, &item, "-{5}BEGIN CERTIFICATE-{5}\\u000D?\\u000A([A-Za-z0-9/+]{64}\\u000D?\\u000A)*[A-Za-z0-9/+]{1,64}={0,2}\\u000D?\\u000A-{5}END CERTIFICATE-{5}(\\u000D?\\u000A)?");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateNetworkProfileResponse {
     /// <p>The ARN of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileArn")]
@@ -610,6 +1047,7 @@ pub struct CreateNetworkProfileResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProfileRequest {
     /// <p>The valid address for the room.</p>
     #[serde(rename = "Address")]
@@ -651,8 +1089,53 @@ pub struct CreateProfileRequest {
     pub wake_word: String,
 }
 
+impl Validate for CreateProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "address"),
+            self.address.chars().count(),
+            Some(1),
+            Some(500),
+        );
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(10),
+                Some(150),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+            );
+        }
+        if let Some(ref value) = self.locale {
+            errors.check_length(
+                &field_path(path, "locale"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "profile_name"),
+            self.profile_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "profile_name"), &self.profile_name, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        errors.check_length(
+            &field_path(path, "timezone"),
+            self.timezone.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProfileResponse {
     /// <p>The ARN of the newly created room profile in the response.</p>
     #[serde(rename = "ProfileArn")]
@@ -661,6 +1144,7 @@ pub struct CreateProfileResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRoomRequest {
     /// <p>A unique, user-specified identifier for this request that ensures idempotency. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -687,8 +1171,59 @@ pub struct CreateRoomRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateRoomRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(10),
+                Some(150),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.profile_arn {
+            errors.check_pattern(&field_path(path, "profile_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.provider_calendar_id {
+            errors.check_length(
+                &field_path(path, "provider_calendar_id"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "room_name"),
+            self.room_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "room_name"), &self.room_name, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRoomResponse {
     /// <p>The ARN of the newly created room in the response.</p>
     #[serde(rename = "RoomArn")]
@@ -697,6 +1232,7 @@ pub struct CreateRoomResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSkillGroupRequest {
     /// <p>A unique, user-specified identifier for this request that ensures idempotency. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -711,8 +1247,42 @@ pub struct CreateSkillGroupRequest {
     pub skill_group_name: String,
 }
 
+impl Validate for CreateSkillGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(10),
+                Some(150),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        errors.check_length(
+            &field_path(path, "skill_group_name"),
+            self.skill_group_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "skill_group_name"), &self.skill_group_name, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSkillGroupResponse {
     /// <p>The ARN of the newly created skill group in the response.</p>
     #[serde(rename = "SkillGroupArn")]
@@ -721,6 +1291,7 @@ pub struct CreateSkillGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserRequest {
     /// <p>A unique, user-specified identifier for this request that ensures idempotency. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -747,8 +1318,82 @@ pub struct CreateUserRequest {
     pub user_id: String,
 }
 
+impl Validate for CreateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(10),
+                Some(150),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+            );
+        }
+        if let Some(ref value) = self.email {
+            errors.check_length(
+                &field_path(path, "email"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "email"),
+                &value,
+                "\\w[+-.\\w]*@\\w[\\w\\.\\-]+\\.[0-9a-zA-Z]{2,24}",
+            );
+        }
+        if let Some(ref value) = self.first_name {
+            errors.check_length(
+                &field_path(path, "first_name"),
+                value.chars().count(),
+                None,
+                Some(30),
+            );
+            errors.check_pattern(
+                &field_path(path, "first_name"),
+                &value,
+                "([A-Za-z\\-' 0-9._]|\\p{IsLetter})*",
+            );
+        }
+        if let Some(ref value) = self.last_name {
+            errors.check_length(
+                &field_path(path, "last_name"),
+                value.chars().count(),
+                None,
+                Some(30),
+            );
+            errors.check_pattern(
+                &field_path(path, "last_name"),
+                &value,
+                "([A-Za-z\\-' 0-9._]|\\p{IsLetter})*",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "[a-zA-Z0-9@_+.-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserResponse {
     /// <p>The ARN of the newly created user in the response.</p>
     #[serde(rename = "UserArn")]
@@ -757,61 +1402,97 @@ pub struct CreateUserResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAddressBookRequest {
     /// <p>The ARN of the address book to delete.</p>
     #[serde(rename = "AddressBookArn")]
     pub address_book_arn: String,
 }
 
+impl Validate for DeleteAddressBookRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "address_book_arn"), &self.address_book_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteAddressBookResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBusinessReportScheduleRequest {
     /// <p>The ARN of the business report schedule.</p>
     #[serde(rename = "ScheduleArn")]
     pub schedule_arn: String,
 }
 
+impl Validate for DeleteBusinessReportScheduleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "schedule_arn"), &self.schedule_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBusinessReportScheduleResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConferenceProviderRequest {
     /// <p>The ARN of the conference provider.</p>
     #[serde(rename = "ConferenceProviderArn")]
     pub conference_provider_arn: String,
 }
 
+impl Validate for DeleteConferenceProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "conference_provider_arn"), &self.conference_provider_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteConferenceProviderResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteContactRequest {
     /// <p>The ARN of the contact to delete.</p>
     #[serde(rename = "ContactArn")]
     pub contact_arn: String,
 }
 
+impl Validate for DeleteContactRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "contact_arn"), &self.contact_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteContactResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDeviceRequest {
     /// <p>The ARN of the device for which to request details.</p>
     #[serde(rename = "DeviceArn")]
     pub device_arn: String,
 }
 
+impl Validate for DeleteDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "device_arn"), &self.device_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDeviceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDeviceUsageDataRequest {
     /// <p>The ARN of the device.</p>
     #[serde(rename = "DeviceArn")]
@@ -821,33 +1502,54 @@ pub struct DeleteDeviceUsageDataRequest {
     pub device_usage_type: String,
 }
 
+impl Validate for DeleteDeviceUsageDataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "device_arn"), &self.device_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDeviceUsageDataResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGatewayGroupRequest {
     /// <p>The ARN of the gateway group to delete.</p>
     #[serde(rename = "GatewayGroupArn")]
     pub gateway_group_arn: String,
 }
 
+impl Validate for DeleteGatewayGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "gateway_group_arn"), &self.gateway_group_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteGatewayGroupResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteNetworkProfileRequest {
     /// <p>The ARN of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileArn")]
     pub network_profile_arn: String,
 }
 
+impl Validate for DeleteNetworkProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "network_profile_arn"), &self.network_profile_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteNetworkProfileResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProfileRequest {
     /// <p>The ARN of the room profile to delete. Required.</p>
     #[serde(rename = "ProfileArn")]
@@ -855,11 +1557,20 @@ pub struct DeleteProfileRequest {
     pub profile_arn: Option<String>,
 }
 
+impl Validate for DeleteProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.profile_arn {
+            errors.check_pattern(&field_path(path, "profile_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProfileResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRoomRequest {
     /// <p>The ARN of the room to delete. Required.</p>
     #[serde(rename = "RoomArn")]
@@ -867,11 +1578,20 @@ pub struct DeleteRoomRequest {
     pub room_arn: Option<String>,
 }
 
+impl Validate for DeleteRoomRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRoomResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRoomSkillParameterRequest {
     /// <p>The room skill parameter key for which to remove details.</p>
     #[serde(rename = "ParameterKey")]
@@ -885,11 +1605,27 @@ pub struct DeleteRoomSkillParameterRequest {
     pub skill_id: String,
 }
 
+impl Validate for DeleteRoomSkillParameterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "parameter_key"),
+            self.parameter_key.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRoomSkillParameterResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSkillAuthorizationRequest {
     /// <p>The room that the skill is authorized for.</p>
     #[serde(rename = "RoomArn")]
@@ -900,11 +1636,21 @@ pub struct DeleteSkillAuthorizationRequest {
     pub skill_id: String,
 }
 
+impl Validate for DeleteSkillAuthorizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSkillAuthorizationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSkillGroupRequest {
     /// <p>The ARN of the skill group to delete. Required.</p>
     #[serde(rename = "SkillGroupArn")]
@@ -912,11 +1658,20 @@ pub struct DeleteSkillGroupRequest {
     pub skill_group_arn: Option<String>,
 }
 
+impl Validate for DeleteSkillGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.skill_group_arn {
+            errors.check_pattern(&field_path(path, "skill_group_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSkillGroupResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserRequest {
     /// <p>The ARN of the user's enrollment in the organization. Required.</p>
     #[serde(rename = "EnrollmentId")]
@@ -927,13 +1682,27 @@ pub struct DeleteUserRequest {
     pub user_arn: Option<String>,
 }
 
+impl Validate for DeleteUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "enrollment_id"),
+            self.enrollment_id.chars().count(),
+            None,
+            Some(128),
+        );
+        if let Some(ref value) = self.user_arn {
+            errors.check_pattern(&field_path(path, "user_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUserResponse {}
 
 /// <p>The details about the developer that published the skill.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeveloperInfo {
     /// <p>The name of the developer.</p>
     #[serde(rename = "DeveloperName")]
@@ -955,7 +1724,7 @@ pub struct DeveloperInfo {
 
 /// <p>A device with attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Device {
     /// <p>The ARN of a device.</p>
     #[serde(rename = "DeviceArn")]
@@ -1001,7 +1770,7 @@ pub struct Device {
 
 /// <p>Device attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceData {
     /// <p>The ARN of a device.</p>
     #[serde(rename = "DeviceArn")]
@@ -1055,7 +1824,7 @@ pub struct DeviceData {
 
 /// <p>The list of device events.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceEvent {
     /// <p>The time (in epoch) when the event occurred. </p>
     #[serde(rename = "Timestamp")]
@@ -1073,7 +1842,7 @@ pub struct DeviceEvent {
 
 /// <p>Detailed information about a device's network profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceNetworkProfileInfo {
     /// <p>The ARN of the certificate associated with a device.</p>
     #[serde(rename = "CertificateArn")]
@@ -1091,7 +1860,7 @@ pub struct DeviceNetworkProfileInfo {
 
 /// <p>Details of a device’s status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceStatusDetail {
     /// <p>The device status detail code.</p>
     #[serde(rename = "Code")]
@@ -1105,7 +1874,7 @@ pub struct DeviceStatusDetail {
 
 /// <p>Detailed information about a device's status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceStatusInfo {
     /// <p>The latest available information about the connection status of a device. </p>
     #[serde(rename = "ConnectionStatus")]
@@ -1118,6 +1887,7 @@ pub struct DeviceStatusInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateContactFromAddressBookRequest {
     /// <p>The ARN of the address from which to disassociate the contact.</p>
     #[serde(rename = "AddressBookArn")]
@@ -1127,11 +1897,19 @@ pub struct DisassociateContactFromAddressBookRequest {
     pub contact_arn: String,
 }
 
+impl Validate for DisassociateContactFromAddressBookRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "address_book_arn"), &self.address_book_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        errors.check_pattern(&field_path(path, "contact_arn"), &self.contact_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateContactFromAddressBookResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateDeviceFromRoomRequest {
     /// <p>The ARN of the device to disassociate from a room. Required.</p>
     #[serde(rename = "DeviceArn")]
@@ -1139,11 +1917,20 @@ pub struct DisassociateDeviceFromRoomRequest {
     pub device_arn: Option<String>,
 }
 
+impl Validate for DisassociateDeviceFromRoomRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.device_arn {
+            errors.check_pattern(&field_path(path, "device_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateDeviceFromRoomResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateSkillFromSkillGroupRequest {
     /// <p>The unique identifier of a skill. Required.</p>
     #[serde(rename = "SkillGroupArn")]
@@ -1154,22 +1941,39 @@ pub struct DisassociateSkillFromSkillGroupRequest {
     pub skill_id: String,
 }
 
+impl Validate for DisassociateSkillFromSkillGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.skill_group_arn {
+            errors.check_pattern(&field_path(path, "skill_group_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateSkillFromSkillGroupResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateSkillFromUsersRequest {
     /// <p> The private skill ID you want to make unavailable for enrolled users.</p>
     #[serde(rename = "SkillId")]
     pub skill_id: String,
 }
 
+impl Validate for DisassociateSkillFromUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateSkillFromUsersResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateSkillGroupFromRoomRequest {
     /// <p>The ARN of the room from which the skill group is to be disassociated. Required.</p>
     #[serde(rename = "RoomArn")]
@@ -1181,12 +1985,24 @@ pub struct DisassociateSkillGroupFromRoomRequest {
     pub skill_group_arn: Option<String>,
 }
 
+impl Validate for DisassociateSkillGroupFromRoomRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.skill_group_arn {
+            errors.check_pattern(&field_path(path, "skill_group_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateSkillGroupFromRoomResponse {}
 
 /// <p>A filter name and value pair that is used to return a more specific list of results. Filters can be used to match a set of resources by various criteria.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filter {
     /// <p>The key of a filter.</p>
     #[serde(rename = "Key")]
@@ -1196,20 +2012,48 @@ pub struct Filter {
     pub values: Vec<String>,
 }
 
+impl Validate for Filter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(500),
+        );
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(500));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ForgetSmartHomeAppliancesRequest {
     /// <p>The room that the appliances are associated with.</p>
     #[serde(rename = "RoomArn")]
     pub room_arn: String,
 }
 
+impl Validate for ForgetSmartHomeAppliancesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "room_arn"), &self.room_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ForgetSmartHomeAppliancesResponse {}
 
 /// <p>The details of the gateway. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Gateway {
     /// <p>The ARN of the gateway.</p>
     #[serde(rename = "Arn")]
@@ -1235,7 +2079,7 @@ pub struct Gateway {
 
 /// <p>The details of the gateway group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GatewayGroup {
     /// <p>The ARN of the gateway group.</p>
     #[serde(rename = "Arn")]
@@ -1253,7 +2097,7 @@ pub struct GatewayGroup {
 
 /// <p>The summary of a gateway group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GatewayGroupSummary {
     /// <p>The ARN of the gateway group.</p>
     #[serde(rename = "Arn")]
@@ -1271,7 +2115,7 @@ pub struct GatewayGroupSummary {
 
 /// <p>The summary of a gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GatewaySummary {
     /// <p>The ARN of the gateway.</p>
     #[serde(rename = "Arn")]
@@ -1296,14 +2140,21 @@ pub struct GatewaySummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAddressBookRequest {
     /// <p>The ARN of the address book for which to request details.</p>
     #[serde(rename = "AddressBookArn")]
     pub address_book_arn: String,
 }
 
+impl Validate for GetAddressBookRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "address_book_arn"), &self.address_book_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAddressBookResponse {
     /// <p>The details of the requested address book.</p>
     #[serde(rename = "AddressBook")]
@@ -1312,10 +2163,11 @@ pub struct GetAddressBookResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConferencePreferenceRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConferencePreferenceResponse {
     /// <p>The conference preference.</p>
     #[serde(rename = "Preference")]
@@ -1324,14 +2176,21 @@ pub struct GetConferencePreferenceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConferenceProviderRequest {
     /// <p>The ARN of the newly created conference provider.</p>
     #[serde(rename = "ConferenceProviderArn")]
     pub conference_provider_arn: String,
 }
 
+impl Validate for GetConferenceProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "conference_provider_arn"), &self.conference_provider_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConferenceProviderResponse {
     /// <p>The conference provider.</p>
     #[serde(rename = "ConferenceProvider")]
@@ -1340,14 +2199,21 @@ pub struct GetConferenceProviderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetContactRequest {
     /// <p>The ARN of the contact for which to request details.</p>
     #[serde(rename = "ContactArn")]
     pub contact_arn: String,
 }
 
+impl Validate for GetContactRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "contact_arn"), &self.contact_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetContactResponse {
     /// <p>The details of the requested contact.</p>
     #[serde(rename = "Contact")]
@@ -1356,6 +2222,7 @@ pub struct GetContactResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeviceRequest {
     /// <p>The ARN of the device for which to request details. Required.</p>
     #[serde(rename = "DeviceArn")]
@@ -1363,8 +2230,16 @@ pub struct GetDeviceRequest {
     pub device_arn: Option<String>,
 }
 
+impl Validate for GetDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.device_arn {
+            errors.check_pattern(&field_path(path, "device_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeviceResponse {
     /// <p>The details of the device requested. Required.</p>
     #[serde(rename = "Device")]
@@ -1373,14 +2248,21 @@ pub struct GetDeviceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGatewayGroupRequest {
     /// <p>The ARN of the gateway group to get.</p>
     #[serde(rename = "GatewayGroupArn")]
     pub gateway_group_arn: String,
 }
 
+impl Validate for GetGatewayGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "gateway_group_arn"), &self.gateway_group_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGatewayGroupResponse {
     #[serde(rename = "GatewayGroup")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1388,14 +2270,21 @@ pub struct GetGatewayGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGatewayRequest {
     /// <p>The ARN of the gateway to get.</p>
     #[serde(rename = "GatewayArn")]
     pub gateway_arn: String,
 }
 
+impl Validate for GetGatewayRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "gateway_arn"), &self.gateway_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGatewayResponse {
     /// <p>The details of the gateway.</p>
     #[serde(rename = "Gateway")]
@@ -1404,10 +2293,11 @@ pub struct GetGatewayResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInvitationConfigurationRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInvitationConfigurationResponse {
     /// <p>The email ID of the organization or individual contact that the enrolled user can use. </p>
     #[serde(rename = "ContactEmail")]
@@ -1424,14 +2314,21 @@ pub struct GetInvitationConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetNetworkProfileRequest {
     /// <p>The ARN of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileArn")]
     pub network_profile_arn: String,
 }
 
+impl Validate for GetNetworkProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "network_profile_arn"), &self.network_profile_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetNetworkProfileResponse {
     /// <p>The network profile associated with a device.</p>
     #[serde(rename = "NetworkProfile")]
@@ -1440,6 +2337,7 @@ pub struct GetNetworkProfileResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetProfileRequest {
     /// <p>The ARN of the room profile for which to request details. Required.</p>
     #[serde(rename = "ProfileArn")]
@@ -1447,8 +2345,16 @@ pub struct GetProfileRequest {
     pub profile_arn: Option<String>,
 }
 
+impl Validate for GetProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.profile_arn {
+            errors.check_pattern(&field_path(path, "profile_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetProfileResponse {
     /// <p>The details of the room profile requested. Required.</p>
     #[serde(rename = "Profile")]
@@ -1457,6 +2363,7 @@ pub struct GetProfileResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRoomRequest {
     /// <p>The ARN of the room for which to request details. Required.</p>
     #[serde(rename = "RoomArn")]
@@ -1464,8 +2371,16 @@ pub struct GetRoomRequest {
     pub room_arn: Option<String>,
 }
 
+impl Validate for GetRoomRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRoomResponse {
     /// <p>The details of the room requested.</p>
     #[serde(rename = "Room")]
@@ -1474,6 +2389,7 @@ pub struct GetRoomResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRoomSkillParameterRequest {
     /// <p>The room skill parameter key for which to get details. Required.</p>
     #[serde(rename = "ParameterKey")]
@@ -1487,8 +2403,23 @@ pub struct GetRoomSkillParameterRequest {
     pub skill_id: String,
 }
 
+impl Validate for GetRoomSkillParameterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "parameter_key"),
+            self.parameter_key.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRoomSkillParameterResponse {
     /// <p>The details of the room skill parameter requested. Required.</p>
     #[serde(rename = "RoomSkillParameter")]
@@ -1497,6 +2428,7 @@ pub struct GetRoomSkillParameterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSkillGroupRequest {
     /// <p>The ARN of the skill group for which to get details. Required.</p>
     #[serde(rename = "SkillGroupArn")]
@@ -1504,8 +2436,16 @@ pub struct GetSkillGroupRequest {
     pub skill_group_arn: Option<String>,
 }
 
+impl Validate for GetSkillGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.skill_group_arn {
+            errors.check_pattern(&field_path(path, "skill_group_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSkillGroupResponse {
     /// <p>The details of the skill group requested. Required.</p>
     #[serde(rename = "SkillGroup")]
@@ -1524,7 +2464,19 @@ pub struct IPDialIn {
     pub endpoint: String,
 }
 
+impl Validate for IPDialIn {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "endpoint"),
+            self.endpoint.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBusinessReportSchedulesRequest {
     /// <p>The maximum number of schedules listed in the call.</p>
     #[serde(rename = "MaxResults")]
@@ -1536,8 +2488,24 @@ pub struct ListBusinessReportSchedulesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListBusinessReportSchedulesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBusinessReportSchedulesResponse {
     /// <p>The schedule of the reports.</p>
     #[serde(rename = "BusinessReportSchedules")]
@@ -1550,6 +2518,7 @@ pub struct ListBusinessReportSchedulesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConferenceProvidersRequest {
     /// <p>The maximum number of conference providers to be returned, per paginated calls.</p>
     #[serde(rename = "MaxResults")]
@@ -1561,8 +2530,24 @@ pub struct ListConferenceProvidersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListConferenceProvidersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListConferenceProvidersResponse {
     /// <p>The conference providers.</p>
     #[serde(rename = "ConferenceProviders")]
@@ -1575,6 +2560,7 @@ pub struct ListConferenceProvidersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeviceEventsRequest {
     /// <p>The ARN of a device.</p>
     #[serde(rename = "DeviceArn")]
@@ -1593,8 +2579,25 @@ pub struct ListDeviceEventsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDeviceEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "device_arn"), &self.device_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeviceEventsResponse {
     /// <p>The device events requested for the device ARN.</p>
     #[serde(rename = "DeviceEvents")]
@@ -1607,6 +2610,7 @@ pub struct ListDeviceEventsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGatewayGroupsRequest {
     /// <p>The maximum number of gateway group summaries to return. The default is 50.</p>
     #[serde(rename = "MaxResults")]
@@ -1618,8 +2622,24 @@ pub struct ListGatewayGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListGatewayGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGatewayGroupsResponse {
     /// <p>The gateway groups in the list.</p>
     #[serde(rename = "GatewayGroups")]
@@ -1632,6 +2652,7 @@ pub struct ListGatewayGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGatewaysRequest {
     /// <p>The gateway group ARN for which to list gateways.</p>
     #[serde(rename = "GatewayGroupArn")]
@@ -1647,8 +2668,27 @@ pub struct ListGatewaysRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListGatewaysRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.gateway_group_arn {
+            errors.check_pattern(&field_path(path, "gateway_group_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGatewaysResponse {
     /// <p>The gateways in the list.</p>
     #[serde(rename = "Gateways")]
@@ -1661,6 +2701,7 @@ pub struct ListGatewaysResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSkillsRequest {
     /// <p>Whether the skill is enabled under the user's account, or if it requires linking to be used.</p>
     #[serde(rename = "EnablementType")]
@@ -1684,8 +2725,27 @@ pub struct ListSkillsRequest {
     pub skill_type: Option<String>,
 }
 
+impl Validate for ListSkillsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(10));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+        if let Some(ref value) = self.skill_group_arn {
+            errors.check_pattern(&field_path(path, "skill_group_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSkillsResponse {
     /// <p>The token returned to indicate that there is more data available.</p>
     #[serde(rename = "NextToken")]
@@ -1698,6 +2758,7 @@ pub struct ListSkillsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSkillsStoreCategoriesRequest {
     /// <p>The maximum number of categories returned, per paginated calls.</p>
     #[serde(rename = "MaxResults")]
@@ -1709,8 +2770,24 @@ pub struct ListSkillsStoreCategoriesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListSkillsStoreCategoriesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSkillsStoreCategoriesResponse {
     /// <p>The list of categories.</p>
     #[serde(rename = "CategoryList")]
@@ -1723,6 +2800,7 @@ pub struct ListSkillsStoreCategoriesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSkillsStoreSkillsByCategoryRequest {
     /// <p>The category ID for which the skills are being retrieved from the skill store.</p>
     #[serde(rename = "CategoryId")]
@@ -1737,8 +2815,30 @@ pub struct ListSkillsStoreSkillsByCategoryRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListSkillsStoreSkillsByCategoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "category_id"),
+            self.category_id,
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(10));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSkillsStoreSkillsByCategoryResponse {
     /// <p>The tokens used for pagination.</p>
     #[serde(rename = "NextToken")]
@@ -1751,6 +2851,7 @@ pub struct ListSkillsStoreSkillsByCategoryResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSmartHomeAppliancesRequest {
     /// <p>The maximum number of appliances to be returned, per paginated calls.</p>
     #[serde(rename = "MaxResults")]
@@ -1765,8 +2866,25 @@ pub struct ListSmartHomeAppliancesRequest {
     pub room_arn: String,
 }
 
+impl Validate for ListSmartHomeAppliancesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+        errors.check_pattern(&field_path(path, "room_arn"), &self.room_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSmartHomeAppliancesResponse {
     /// <p>The tokens used for pagination.</p>
     #[serde(rename = "NextToken")]
@@ -1779,6 +2897,7 @@ pub struct ListSmartHomeAppliancesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsRequest {
     /// <p>The ARN of the specified resource for which to list tags.</p>
     #[serde(rename = "Arn")]
@@ -1793,8 +2912,25 @@ pub struct ListTagsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsResponse {
     /// <p>The token returned to indicate that there is more data available.</p>
     #[serde(rename = "NextToken")]
@@ -1816,7 +2952,7 @@ pub struct MeetingSetting {
 
 /// <p>The network profile associated with a device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkProfile {
     /// <p>The ARN of the Private Certificate Authority (PCA) created in AWS Certificate Manager (ACM). This is used to issue certificates to the devices. </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -1862,7 +2998,7 @@ pub struct NetworkProfile {
 
 /// <p>The data associated with a network profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkProfileData {
     /// <p>The ARN of the Private Certificate Authority (PCA) created in AWS Certificate Manager (ACM). This is used to issue certificates to the devices.</p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -1911,6 +3047,33 @@ pub struct PSTNDialIn {
     pub phone_number: String,
 }
 
+impl Validate for PSTNDialIn {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "country_code"),
+            &self.country_code,
+            "\\d{1,3}",
+        );
+        errors.check_length(
+            &field_path(path, "one_click_id_delay"),
+            self.one_click_id_delay.chars().count(),
+            Some(1),
+            Some(2),
+        );
+        errors.check_length(
+            &field_path(path, "one_click_pin_delay"),
+            self.one_click_pin_delay.chars().count(),
+            Some(1),
+            Some(2),
+        );
+        errors.check_pattern(
+            &field_path(path, "phone_number"),
+            &self.phone_number,
+            "\\d{10}",
+        );
+    }
+}
+
 /// <p>The phone number for the contact containing the raw number and phone number type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PhoneNumber {
@@ -1922,9 +3085,25 @@ pub struct PhoneNumber {
     pub type_: String,
 }
 
+impl Validate for PhoneNumber {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "number"),
+            self.number.chars().count(),
+            None,
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "number"),
+            &self.number,
+            "^[\\+0-9\\#\\,\\(][\\+0-9\\-\\.\\/\\(\\)\\,\\#\\s]+$",
+        );
+    }
+}
+
 /// <p>A room profile with attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Profile {
     /// <p>The address of a room profile.</p>
     #[serde(rename = "Address")]
@@ -1982,7 +3161,7 @@ pub struct Profile {
 
 /// <p>The data of a room profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProfileData {
     /// <p>The address of a room profile.</p>
     #[serde(rename = "Address")]
@@ -2023,17 +3202,26 @@ pub struct ProfileData {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutConferencePreferenceRequest {
     /// <p>The conference preference of a specific conference provider.</p>
     #[serde(rename = "ConferencePreference")]
     pub conference_preference: ConferencePreference,
 }
 
+impl Validate for PutConferencePreferenceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.conference_preference
+            .validate_at(&field_path(path, "conference_preference"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutConferencePreferenceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutInvitationConfigurationRequest {
     /// <p>The email ID of the organization or individual contact that the enrolled user can use. </p>
     #[serde(rename = "ContactEmail")]
@@ -2048,11 +3236,49 @@ pub struct PutInvitationConfigurationRequest {
     pub private_skill_ids: Option<Vec<String>>,
 }
 
+impl Validate for PutInvitationConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.contact_email {
+            errors.check_length(
+                &field_path(path, "contact_email"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "contact_email"),
+                &value,
+                "\\w[+-.\\w]*@\\w[\\w\\.\\-]+\\.[0-9a-zA-Z]{2,24}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "organization_name"),
+            self.organization_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "organization_name"), &self.organization_name, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        if let Some(ref value) = self.private_skill_ids {
+            errors.check_length(
+                &field_path(path, "private_skill_ids"),
+                value.len(),
+                None,
+                Some(3),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "private_skill_ids"), i);
+                errors.check_pattern(&item_path, &item, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutInvitationConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRoomSkillParameterRequest {
     /// <p>The ARN of the room associated with the room skill parameter. Required.</p>
     #[serde(rename = "RoomArn")]
@@ -2066,11 +3292,23 @@ pub struct PutRoomSkillParameterRequest {
     pub skill_id: String,
 }
 
+impl Validate for PutRoomSkillParameterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        self.room_skill_parameter
+            .validate_at(&field_path(path, "room_skill_parameter"), errors);
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRoomSkillParameterResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutSkillAuthorizationRequest {
     /// <p>The authorization result specific to OAUTH code grant output. "Code” must be populated in the AuthorizationResult map to establish the authorization.</p>
     #[serde(rename = "AuthorizationResult")]
@@ -2084,11 +3322,25 @@ pub struct PutSkillAuthorizationRequest {
     pub skill_id: String,
 }
 
+impl Validate for PutSkillAuthorizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (key, item) in self.authorization_result.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "authorization_result"), key);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutSkillAuthorizationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterAVSDeviceRequest {
     /// <p>The device type ID for your AVS device generated by Amazon when the OEM creates a new product on Amazon's Developer Console.</p>
     #[serde(rename = "AmazonId")]
@@ -2107,8 +3359,39 @@ pub struct RegisterAVSDeviceRequest {
     pub user_code: String,
 }
 
+impl Validate for RegisterAVSDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "amazon_id"),
+            &self.amazon_id,
+            "[a-zA-Z0-9]{1,18}",
+        );
+        errors.check_pattern(
+            &field_path(path, "client_id"),
+            &self.client_id,
+            "^\\S+{1,256}$",
+        );
+        errors.check_pattern(
+            &field_path(path, "device_serial_number"),
+            &self.device_serial_number,
+            "^[a-zA-Z0-9]{1,50}$",
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_]{1,256}$",
+        );
+        errors.check_length(
+            &field_path(path, "user_code"),
+            self.user_code.chars().count(),
+            Some(1),
+            Some(128),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterAVSDeviceResponse {
     /// <p>The ARN of the device.</p>
     #[serde(rename = "DeviceArn")]
@@ -2117,17 +3400,25 @@ pub struct RegisterAVSDeviceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RejectSkillRequest {
     /// <p>The unique identifier of the skill.</p>
     #[serde(rename = "SkillId")]
     pub skill_id: String,
 }
 
+impl Validate for RejectSkillRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RejectSkillResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResolveRoomRequest {
     /// <p>The ARN of the skill that was requested. Required.</p>
     #[serde(rename = "SkillId")]
@@ -2137,8 +3428,19 @@ pub struct ResolveRoomRequest {
     pub user_id: String,
 }
 
+impl Validate for ResolveRoomRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "skill_id"), &self.skill_id, "(^amzn1\\.ask\\.skill\\.[0-9a-f\\-]{1,200})|(^amzn1\\.echo-sdk-ams\\.app\\.[0-9a-f\\-]{1,200})");
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "amzn1\\.[A-Za-z0-9+-\\/=.]{1,300}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResolveRoomResponse {
     /// <p>The ARN of the room from which the skill request was invoked.</p>
     #[serde(rename = "RoomArn")]
@@ -2155,6 +3457,7 @@ pub struct ResolveRoomResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeInvitationRequest {
     /// <p>The ARN of the enrollment invitation to revoke. Required.</p>
     #[serde(rename = "EnrollmentId")]
@@ -2166,13 +3469,29 @@ pub struct RevokeInvitationRequest {
     pub user_arn: Option<String>,
 }
 
+impl Validate for RevokeInvitationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.enrollment_id {
+            errors.check_length(
+                &field_path(path, "enrollment_id"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.user_arn {
+            errors.check_pattern(&field_path(path, "user_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RevokeInvitationResponse {}
 
 /// <p>A room with attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Room {
     /// <p>The description of a room.</p>
     #[serde(rename = "Description")]
@@ -2198,7 +3517,7 @@ pub struct Room {
 
 /// <p>The data of a room.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RoomData {
     /// <p>The description of a room.</p>
     #[serde(rename = "Description")]
@@ -2237,7 +3556,25 @@ pub struct RoomSkillParameter {
     pub parameter_value: String,
 }
 
+impl Validate for RoomSkillParameter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "parameter_key"),
+            self.parameter_key.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "parameter_value"),
+            self.parameter_value.chars().count(),
+            Some(1),
+            Some(512),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchAddressBooksRequest {
     /// <p>The filters to use to list a specified set of address books. The supported filter key is AddressBookName.</p>
     #[serde(rename = "Filters")]
@@ -2257,8 +3594,43 @@ pub struct SearchAddressBooksRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl Validate for SearchAddressBooksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(25));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+        if let Some(ref value) = self.sort_criteria {
+            errors.check_length(
+                &field_path(path, "sort_criteria"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_criteria"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchAddressBooksResponse {
     /// <p>The address books that meet the specified set of filter criteria, in sort order.</p>
     #[serde(rename = "AddressBooks")]
@@ -2275,6 +3647,7 @@ pub struct SearchAddressBooksResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchContactsRequest {
     /// <p>The filters to use to list a specified set of address books. The supported filter keys are DisplayName, FirstName, LastName, and AddressBookArns.</p>
     #[serde(rename = "Filters")]
@@ -2294,8 +3667,43 @@ pub struct SearchContactsRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl Validate for SearchContactsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(25));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+        if let Some(ref value) = self.sort_criteria {
+            errors.check_length(
+                &field_path(path, "sort_criteria"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_criteria"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchContactsResponse {
     /// <p>The contacts that meet the specified set of filter criteria, in sort order.</p>
     #[serde(rename = "Contacts")]
@@ -2312,6 +3720,7 @@ pub struct SearchContactsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchDevicesRequest {
     /// <p>The filters to use to list a specified set of devices. Supported filter keys are DeviceName, DeviceStatus, DeviceStatusDetailCode, RoomName, DeviceType, DeviceSerialNumber, UnassociatedOnly, ConnectionStatus (ONLINE and OFFLINE), NetworkProfileName, NetworkProfileArn, Feature, and FailureCode.</p>
     #[serde(rename = "Filters")]
@@ -2331,8 +3740,43 @@ pub struct SearchDevicesRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl Validate for SearchDevicesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(25));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+        if let Some(ref value) = self.sort_criteria {
+            errors.check_length(
+                &field_path(path, "sort_criteria"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_criteria"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchDevicesResponse {
     /// <p>The devices that meet the specified set of filter criteria, in sort order.</p>
     #[serde(rename = "Devices")]
@@ -2349,6 +3793,7 @@ pub struct SearchDevicesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchNetworkProfilesRequest {
     /// <p>The filters to use to list a specified set of network profiles. Valid filters are NetworkProfileName, Ssid, and SecurityType.</p>
     #[serde(rename = "Filters")]
@@ -2368,8 +3813,43 @@ pub struct SearchNetworkProfilesRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl Validate for SearchNetworkProfilesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(25));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+        if let Some(ref value) = self.sort_criteria {
+            errors.check_length(
+                &field_path(path, "sort_criteria"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_criteria"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchNetworkProfilesResponse {
     /// <p>The network profiles that meet the specified set of filter criteria, in sort order. It is a list of NetworkProfileData objects. </p>
     #[serde(rename = "NetworkProfiles")]
@@ -2386,6 +3866,7 @@ pub struct SearchNetworkProfilesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchProfilesRequest {
     /// <p>The filters to use to list a specified set of room profiles. Supported filter keys are ProfileName and Address. Required. </p>
     #[serde(rename = "Filters")]
@@ -2405,8 +3886,43 @@ pub struct SearchProfilesRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl Validate for SearchProfilesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(25));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+        if let Some(ref value) = self.sort_criteria {
+            errors.check_length(
+                &field_path(path, "sort_criteria"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_criteria"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchProfilesResponse {
     /// <p>The token returned to indicate that there is more data available.</p>
     #[serde(rename = "NextToken")]
@@ -2423,6 +3939,7 @@ pub struct SearchProfilesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchRoomsRequest {
     /// <p>The filters to use to list a specified set of rooms. The supported filter keys are RoomName and ProfileName.</p>
     #[serde(rename = "Filters")]
@@ -2442,8 +3959,43 @@ pub struct SearchRoomsRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl Validate for SearchRoomsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(25));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+        if let Some(ref value) = self.sort_criteria {
+            errors.check_length(
+                &field_path(path, "sort_criteria"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_criteria"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchRoomsResponse {
     /// <p>The token returned to indicate that there is more data available.</p>
     #[serde(rename = "NextToken")]
@@ -2460,6 +4012,7 @@ pub struct SearchRoomsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchSkillGroupsRequest {
     /// <p>The filters to use to list a specified set of skill groups. The supported filter key is SkillGroupName. </p>
     #[serde(rename = "Filters")]
@@ -2479,8 +4032,43 @@ pub struct SearchSkillGroupsRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl Validate for SearchSkillGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(25));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+        if let Some(ref value) = self.sort_criteria {
+            errors.check_length(
+                &field_path(path, "sort_criteria"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_criteria"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchSkillGroupsResponse {
     /// <p>The token returned to indicate that there is more data available.</p>
     #[serde(rename = "NextToken")]
@@ -2497,6 +4085,7 @@ pub struct SearchSkillGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchUsersRequest {
     /// <p>The filters to use for listing a specific set of users. Required. Supported filter keys are UserId, FirstName, LastName, Email, and EnrollmentStatus.</p>
     #[serde(rename = "Filters")]
@@ -2516,8 +4105,43 @@ pub struct SearchUsersRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl Validate for SearchUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(25));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1100),
+            );
+        }
+        if let Some(ref value) = self.sort_criteria {
+            errors.check_length(
+                &field_path(path, "sort_criteria"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_criteria"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchUsersResponse {
     /// <p>The token returned to indicate that there is more data available.</p>
     #[serde(rename = "NextToken")]
@@ -2534,6 +4158,7 @@ pub struct SearchUsersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendAnnouncementRequest {
     /// <p>The unique, user-specified identifier for the request that ensures idempotency.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -2550,8 +4175,44 @@ pub struct SendAnnouncementRequest {
     pub time_to_live_in_seconds: Option<i64>,
 }
 
+impl Validate for SendAnnouncementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_request_token"),
+            self.client_request_token.chars().count(),
+            Some(10),
+            Some(150),
+        );
+        errors.check_pattern(
+            &field_path(path, "client_request_token"),
+            &self.client_request_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        self.content
+            .validate_at(&field_path(path, "content"), errors);
+        errors.check_length(
+            &field_path(path, "room_filters"),
+            self.room_filters.len(),
+            None,
+            Some(25),
+        );
+        for (i, item) in self.room_filters.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "room_filters"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.time_to_live_in_seconds {
+            errors.check_range(
+                &field_path(path, "time_to_live_in_seconds"),
+                *value,
+                Some(1),
+                Some(3600),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendAnnouncementResponse {
     /// <p>The identifier of the announcement.</p>
     #[serde(rename = "AnnouncementArn")]
@@ -2560,6 +4221,7 @@ pub struct SendAnnouncementResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendInvitationRequest {
     /// <p>The ARN of the user to whom to send an invitation. Required.</p>
     #[serde(rename = "UserArn")]
@@ -2567,8 +4229,16 @@ pub struct SendInvitationRequest {
     pub user_arn: Option<String>,
 }
 
+impl Validate for SendInvitationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.user_arn {
+            errors.check_pattern(&field_path(path, "user_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendInvitationResponse {}
 
 /// <p>The SIP address for the contact containing the URI and SIP address type.</p>
@@ -2582,9 +4252,25 @@ pub struct SipAddress {
     pub uri: String,
 }
 
+impl Validate for SipAddress {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "uri"),
+            self.uri.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "uri"),
+            &self.uri,
+            "^sip[s]?:([^@:]+)\\@([^@]+)$",
+        );
+    }
+}
+
 /// <p>Granular information about the skill.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SkillDetails {
     /// <p>The details about what the skill supports organized as bullet points.</p>
     #[serde(rename = "BulletPoints")]
@@ -2630,7 +4316,7 @@ pub struct SkillDetails {
 
 /// <p>A skill group with attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SkillGroup {
     /// <p>The description of a skill group.</p>
     #[serde(rename = "Description")]
@@ -2648,7 +4334,7 @@ pub struct SkillGroup {
 
 /// <p>The attributes of a skill group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SkillGroupData {
     /// <p>The description of a skill group.</p>
     #[serde(rename = "Description")]
@@ -2666,7 +4352,7 @@ pub struct SkillGroupData {
 
 /// <p>The summary of skills.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SkillSummary {
     /// <p>Whether the skill is enabled under the user's account, or if it requires linking to be used.</p>
     #[serde(rename = "EnablementType")]
@@ -2692,7 +4378,7 @@ pub struct SkillSummary {
 
 /// <p>The detailed information about an Alexa skill.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SkillsStoreSkill {
     /// <p>The URL where the skill icon resides.</p>
     #[serde(rename = "IconUrl")]
@@ -2726,7 +4412,7 @@ pub struct SkillsStoreSkill {
 
 /// <p>A smart home appliance that can connect to a central system. Any domestic device can be a smart appliance. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SmartHomeAppliance {
     /// <p>The description of the smart home appliance.</p>
     #[serde(rename = "Description")]
@@ -2744,6 +4430,7 @@ pub struct SmartHomeAppliance {
 
 /// <p>An object representing a sort criteria. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Sort {
     /// <p>The sort key of a sort object.</p>
     #[serde(rename = "Key")]
@@ -2753,8 +4440,20 @@ pub struct Sort {
     pub value: String,
 }
 
+impl Validate for Sort {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(500),
+        );
+    }
+}
+
 /// <p>The SSML message. For more information, see <a href="https://developer.amazon.com/docs/custom-skills/speech-synthesis-markup-language-ssml-reference.html">SSML Reference</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Ssml {
     /// <p>The locale of the SSML message. Currently, en-US is supported.</p>
     #[serde(rename = "Locale")]
@@ -2764,7 +4463,20 @@ pub struct Ssml {
     pub value: String,
 }
 
+impl Validate for Ssml {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(4096),
+        );
+        errors.check_pattern(&field_path(path, "value"), &self.value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartDeviceSyncRequest {
     /// <p>The ARN of the device to sync. Required.</p>
     #[serde(rename = "DeviceArn")]
@@ -2779,19 +4491,37 @@ pub struct StartDeviceSyncRequest {
     pub room_arn: Option<String>,
 }
 
+impl Validate for StartDeviceSyncRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.device_arn {
+            errors.check_pattern(&field_path(path, "device_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartDeviceSyncResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartSmartHomeApplianceDiscoveryRequest {
     /// <p>The room where smart home appliance discovery was initiated.</p>
     #[serde(rename = "RoomArn")]
     pub room_arn: String,
 }
 
+impl Validate for StartSmartHomeApplianceDiscoveryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "room_arn"), &self.room_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartSmartHomeApplianceDiscoveryResponse {}
 
 /// <p>A key-value pair that can be associated with a resource. </p>
@@ -2805,7 +4535,35 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The ARN of the resource to which to add metadata tags. Required. </p>
     #[serde(rename = "Arn")]
@@ -2815,12 +4573,23 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>The text message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Text {
     /// <p>The locale of the text message. Currently, en-US is supported.</p>
     #[serde(rename = "Locale")]
@@ -2830,7 +4599,20 @@ pub struct Text {
     pub value: String,
 }
 
+impl Validate for Text {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(4096),
+        );
+        errors.check_pattern(&field_path(path, "value"), &self.value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The ARN of the resource from which to remove metadata tags. Required. </p>
     #[serde(rename = "Arn")]
@@ -2840,11 +4622,23 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAddressBookRequest {
     /// <p>The ARN of the room to update.</p>
     #[serde(rename = "AddressBookArn")]
@@ -2859,11 +4653,36 @@ pub struct UpdateAddressBookRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateAddressBookRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "address_book_arn"), &self.address_book_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAddressBookResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateBusinessReportScheduleRequest {
     /// <p>The format of the generated report (individual CSV files or zipped files of individual files).</p>
     #[serde(rename = "Format")]
@@ -2890,11 +4709,50 @@ pub struct UpdateBusinessReportScheduleRequest {
     pub schedule_name: Option<String>,
 }
 
+impl Validate for UpdateBusinessReportScheduleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.recurrence {
+            value.validate_at(&field_path(path, "recurrence"), errors);
+        }
+        if let Some(ref value) = self.s3_bucket_name {
+            errors.check_pattern(
+                &field_path(path, "s3_bucket_name"),
+                &value,
+                "[a-z0-9-\\.]{3,63}",
+            );
+        }
+        if let Some(ref value) = self.s3_key_prefix {
+            errors.check_length(
+                &field_path(path, "s3_key_prefix"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "s3_key_prefix"),
+                &value,
+                "[A-Za-z0-9!_\\-\\.\\*'()/]*",
+            );
+        }
+        errors.check_pattern(&field_path(path, "schedule_arn"), &self.schedule_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        if let Some(ref value) = self.schedule_name {
+            errors.check_length(
+                &field_path(path, "schedule_name"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "schedule_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateBusinessReportScheduleResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConferenceProviderRequest {
     /// <p>The ARN of the conference provider.</p>
     #[serde(rename = "ConferenceProviderArn")]
@@ -2915,11 +4773,24 @@ pub struct UpdateConferenceProviderRequest {
     pub pstn_dial_in: Option<PSTNDialIn>,
 }
 
+impl Validate for UpdateConferenceProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "conference_provider_arn"), &self.conference_provider_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        if let Some(ref value) = self.ip_dial_in {
+            value.validate_at(&field_path(path, "ip_dial_in"), errors);
+        }
+        if let Some(ref value) = self.pstn_dial_in {
+            value.validate_at(&field_path(path, "pstn_dial_in"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateConferenceProviderResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateContactRequest {
     /// <p>The ARN of the contact to update.</p>
     #[serde(rename = "ContactArn")]
@@ -2950,13 +4821,84 @@ pub struct UpdateContactRequest {
     pub sip_addresses: Option<Vec<SipAddress>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateContactResponse {}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateDeviceRequest {
-    /// <p>The ARN of the device to update. Required.</p>
+impl Validate for UpdateContactRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "contact_arn"), &self.contact_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "display_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.first_name {
+            errors.check_length(
+                &field_path(path, "first_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "first_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.last_name {
+            errors.check_length(
+                &field_path(path, "last_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "last_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.phone_number {
+            errors.check_length(
+                &field_path(path, "phone_number"),
+                value.chars().count(),
+                None,
+                Some(50),
+            );
+            errors.check_pattern(
+                &field_path(path, "phone_number"),
+                &value,
+                "^[\\+0-9\\#\\,\\(][\\+0-9\\-\\.\\/\\(\\)\\,\\#\\s]+$",
+            );
+        }
+        if let Some(ref value) = self.phone_numbers {
+            errors.check_length(
+                &field_path(path, "phone_numbers"),
+                value.len(),
+                None,
+                Some(3),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "phone_numbers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.sip_addresses {
+            errors.check_length(
+                &field_path(path, "sip_addresses"),
+                value.len(),
+                None,
+                Some(1),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sip_addresses"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct UpdateContactResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
+pub struct UpdateDeviceRequest {
+    /// <p>The ARN of the device to update. Required.</p>
     #[serde(rename = "DeviceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub device_arn: Option<String>,
@@ -2966,11 +4908,29 @@ pub struct UpdateDeviceRequest {
     pub device_name: Option<String>,
 }
 
+impl Validate for UpdateDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.device_arn {
+            errors.check_pattern(&field_path(path, "device_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.device_name {
+            errors.check_length(
+                &field_path(path, "device_name"),
+                value.chars().count(),
+                Some(2),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "device_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDeviceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGatewayGroupRequest {
     /// <p>The updated description of the gateway group.</p>
     #[serde(rename = "Description")]
@@ -2985,11 +4945,35 @@ pub struct UpdateGatewayGroupRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateGatewayGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        errors.check_pattern(&field_path(path, "gateway_group_arn"), &self.gateway_group_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGatewayGroupResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGatewayRequest {
     /// <p>The updated description of the gateway.</p>
     #[serde(rename = "Description")]
@@ -3008,11 +4992,44 @@ pub struct UpdateGatewayRequest {
     pub software_version: Option<String>,
 }
 
+impl Validate for UpdateGatewayRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        errors.check_pattern(&field_path(path, "gateway_arn"), &self.gateway_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(253),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.software_version {
+            errors.check_length(
+                &field_path(path, "software_version"),
+                value.chars().count(),
+                Some(1),
+                Some(50),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGatewayResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateNetworkProfileRequest {
     /// <p>The ARN of the Private Certificate Authority (PCA) created in AWS Certificate Manager (ACM). This is used to issue certificates to the devices. </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -3043,11 +5060,77 @@ pub struct UpdateNetworkProfileRequest {
     pub trust_anchors: Option<Vec<String>>,
 }
 
+impl Validate for UpdateNetworkProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.certificate_authority_arn {
+            errors.check_pattern(&field_path(path, "certificate_authority_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.current_password {
+            errors.check_length(
+                &field_path(path, "current_password"),
+                value.chars().count(),
+                Some(5),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "current_password"),
+                &value,
+                "[\\x00-\\x7F]*",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        errors.check_pattern(&field_path(path, "network_profile_arn"), &self.network_profile_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        if let Some(ref value) = self.network_profile_name {
+            errors.check_length(
+                &field_path(path, "network_profile_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "network_profile_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.next_password {
+            errors.check_length(
+                &field_path(path, "next_password"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_password"),
+                &value,
+                "(^$)|([\\x00-\\x7F]{5,})",
+            );
+        }
+        if let Some(ref value) = self.trust_anchors {
+            errors.check_length(
+                &field_path(path, "trust_anchors"),
+                value.len(),
+                Some(1),
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "trust_anchors"), i);
+                errors.check_pattern(&item_path, &item, "-{5}BEGIN CERTIFICATE-{5}\\u000D?\\u000A([A-Za-z0-9/+]{64}\\u000D?\\u000A)*[A-Za-z0-9/+]{1,64}={0,2}\\u000D?\\u000A-{5}END CERTIFICATE-{5}(\\u000D?\\u000A)?");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateNetworkProfileResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProfileRequest {
     /// <p>The updated address for the room profile.</p>
     #[serde(rename = "Address")]
@@ -3099,11 +5182,53 @@ pub struct UpdateProfileRequest {
     pub wake_word: Option<String>,
 }
 
+impl Validate for UpdateProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.address {
+            errors.check_length(
+                &field_path(path, "address"),
+                value.chars().count(),
+                Some(1),
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.locale {
+            errors.check_length(
+                &field_path(path, "locale"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.profile_arn {
+            errors.check_pattern(&field_path(path, "profile_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.profile_name {
+            errors.check_length(
+                &field_path(path, "profile_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "profile_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.timezone {
+            errors.check_length(
+                &field_path(path, "timezone"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateProfileResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRoomRequest {
     /// <p>The updated description for the room.</p>
     #[serde(rename = "Description")]
@@ -3127,11 +5252,49 @@ pub struct UpdateRoomRequest {
     pub room_name: Option<String>,
 }
 
+impl Validate for UpdateRoomRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.profile_arn {
+            errors.check_pattern(&field_path(path, "profile_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.provider_calendar_id {
+            errors.check_length(
+                &field_path(path, "provider_calendar_id"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.room_arn {
+            errors.check_pattern(&field_path(path, "room_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.room_name {
+            errors.check_length(
+                &field_path(path, "room_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "room_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRoomResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSkillGroupRequest {
     /// <p>The updated description for the skill group.</p>
     #[serde(rename = "Description")]
@@ -3147,13 +5310,39 @@ pub struct UpdateSkillGroupRequest {
     pub skill_group_name: Option<String>,
 }
 
+impl Validate for UpdateSkillGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+        if let Some(ref value) = self.skill_group_arn {
+            errors.check_pattern(&field_path(path, "skill_group_arn"), &value, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.skill_group_name {
+            errors.check_length(
+                &field_path(path, "skill_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "skill_group_name"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSkillGroupResponse {}
 
 /// <p>Information related to a user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserData {
     /// <p>The email of a user.</p>
     #[serde(rename = "Email")]
@@ -3205,7 +5394,12 @@ impl ApproveSkillError {
                 "NotFoundException" => {
                     return RusotoError::Service(ApproveSkillError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3244,7 +5438,12 @@ impl AssociateContactWithAddressBookError {
                         AssociateContactWithAddressBookError::LimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3295,7 +5494,12 @@ impl AssociateDeviceWithNetworkProfileError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3346,7 +5550,12 @@ impl AssociateDeviceWithRoomError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3385,7 +5594,12 @@ impl AssociateSkillGroupWithRoomError {
                         AssociateSkillGroupWithRoomError::ConcurrentModification(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3436,7 +5650,12 @@ impl AssociateSkillWithSkillGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3478,7 +5697,12 @@ impl AssociateSkillWithUsersError {
                 "NotFoundException" => {
                     return RusotoError::Service(AssociateSkillWithUsersError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3517,7 +5741,12 @@ impl CreateAddressBookError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateAddressBookError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3555,7 +5784,12 @@ impl CreateBusinessReportScheduleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3590,7 +5824,12 @@ impl CreateConferenceProviderError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3628,7 +5867,12 @@ impl CreateContactError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateContactError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3667,7 +5911,12 @@ impl CreateGatewayGroupError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateGatewayGroupError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3727,7 +5976,12 @@ impl CreateNetworkProfileError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateNetworkProfileError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3776,7 +6030,12 @@ impl CreateProfileError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateProfileError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3816,7 +6075,12 @@ impl CreateRoomError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateRoomError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3862,7 +6126,12 @@ impl CreateSkillGroupError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateSkillGroupError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3907,7 +6176,12 @@ impl CreateUserError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(CreateUserError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3949,7 +6223,12 @@ impl DeleteAddressBookError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteAddressBookError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3994,7 +6273,12 @@ impl DeleteBusinessReportScheduleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4028,7 +6312,12 @@ impl DeleteConferenceProviderError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteConferenceProviderError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4068,7 +6357,12 @@ impl DeleteContactError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteContactError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4114,7 +6408,12 @@ impl DeleteDeviceError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteDeviceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4161,7 +6460,12 @@ impl DeleteDeviceUsageDataError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteDeviceUsageDataError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4198,7 +6502,12 @@ impl DeleteGatewayGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4243,7 +6552,12 @@ impl DeleteNetworkProfileError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(DeleteNetworkProfileError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4285,7 +6599,12 @@ impl DeleteProfileError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteProfileError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4324,7 +6643,12 @@ impl DeleteRoomError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteRoomError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4360,7 +6684,12 @@ impl DeleteRoomSkillParameterError {
                         DeleteRoomSkillParameterError::ConcurrentModification(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4400,7 +6729,12 @@ impl DeleteSkillAuthorizationError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteSkillAuthorizationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4441,7 +6775,12 @@ impl DeleteSkillGroupError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteSkillGroupError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4480,7 +6819,12 @@ impl DeleteUserError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteUserError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4510,7 +6854,12 @@ impl DisassociateContactFromAddressBookError {
     ) -> RusotoError<DisassociateContactFromAddressBookError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4552,7 +6901,12 @@ impl DisassociateDeviceFromRoomError {
                         DisassociateDeviceFromRoomError::DeviceNotRegistered(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4597,7 +6951,12 @@ impl DisassociateSkillFromSkillGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4640,7 +6999,12 @@ impl DisassociateSkillFromUsersError {
                 "NotFoundException" => {
                     return RusotoError::Service(DisassociateSkillFromUsersError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4678,7 +7042,12 @@ impl DisassociateSkillGroupFromRoomError {
                         DisassociateSkillGroupFromRoomError::ConcurrentModification(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4711,7 +7080,12 @@ impl ForgetSmartHomeAppliancesError {
                 "NotFoundException" => {
                     return RusotoError::Service(ForgetSmartHomeAppliancesError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4744,7 +7118,12 @@ impl GetAddressBookError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetAddressBookError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4777,7 +7156,12 @@ impl GetConferencePreferenceError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetConferencePreferenceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4810,7 +7194,12 @@ impl GetConferenceProviderError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetConferenceProviderError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4843,7 +7232,12 @@ impl GetContactError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetContactError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4876,7 +7270,12 @@ impl GetDeviceError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetDeviceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4909,7 +7308,12 @@ impl GetGatewayError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetGatewayError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4942,7 +7346,12 @@ impl GetGatewayGroupError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetGatewayGroupError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4977,7 +7386,12 @@ impl GetInvitationConfigurationError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetInvitationConfigurationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5017,7 +7431,12 @@ impl GetNetworkProfileError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetNetworkProfileError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5051,7 +7470,12 @@ impl GetProfileError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetProfileError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5084,7 +7508,12 @@ impl GetRoomError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetRoomError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5117,7 +7546,12 @@ impl GetRoomSkillParameterError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetRoomSkillParameterError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5150,7 +7584,12 @@ impl GetSkillGroupError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetSkillGroupError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5179,7 +7618,12 @@ impl ListBusinessReportSchedulesError {
     ) -> RusotoError<ListBusinessReportSchedulesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5204,7 +7648,12 @@ impl ListConferenceProvidersError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListConferenceProvidersError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5235,7 +7684,12 @@ impl ListDeviceEventsError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListDeviceEventsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5262,7 +7716,12 @@ impl ListGatewayGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListGatewayGroupsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5287,7 +7746,12 @@ impl ListGatewaysError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListGatewaysError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5312,7 +7776,12 @@ impl ListSkillsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSkillsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5337,7 +7806,12 @@ impl ListSkillsStoreCategoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSkillsStoreCategoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5364,7 +7838,12 @@ impl ListSkillsStoreSkillsByCategoryError {
     ) -> RusotoError<ListSkillsStoreSkillsByCategoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5395,7 +7874,12 @@ impl ListSmartHomeAppliancesError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListSmartHomeAppliancesError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5428,7 +7912,12 @@ impl ListTagsError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListTagsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5461,7 +7950,12 @@ impl PutConferencePreferenceError {
                 "NotFoundException" => {
                     return RusotoError::Service(PutConferencePreferenceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5503,7 +7997,12 @@ impl PutInvitationConfigurationError {
                 "NotFoundException" => {
                     return RusotoError::Service(PutInvitationConfigurationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5539,7 +8038,12 @@ impl PutRoomSkillParameterError {
                         PutRoomSkillParameterError::ConcurrentModification(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5579,7 +8083,12 @@ impl PutSkillAuthorizationError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(PutSkillAuthorizationError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5625,7 +8134,12 @@ impl RegisterAVSDeviceError {
                 "LimitExceededException" => {
                     return RusotoError::Service(RegisterAVSDeviceError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5665,7 +8179,12 @@ impl RejectSkillError {
                 "NotFoundException" => {
                     return RusotoError::Service(RejectSkillError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5699,7 +8218,12 @@ impl ResolveRoomError {
                 "NotFoundException" => {
                     return RusotoError::Service(ResolveRoomError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5739,7 +8263,12 @@ impl RevokeInvitationError {
                 "NotFoundException" => {
                     return RusotoError::Service(RevokeInvitationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5767,7 +8296,12 @@ impl SearchAddressBooksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchAddressBooksError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5792,7 +8326,12 @@ impl SearchContactsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchContactsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5817,7 +8356,12 @@ impl SearchDevicesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchDevicesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5842,7 +8386,12 @@ impl SearchNetworkProfilesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchNetworkProfilesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5867,7 +8416,12 @@ impl SearchProfilesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchProfilesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5892,7 +8446,12 @@ impl SearchRoomsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchRoomsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5917,7 +8476,12 @@ impl SearchSkillGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchSkillGroupsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5942,7 +8506,12 @@ impl SearchUsersError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchUsersError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5978,7 +8547,12 @@ impl SendAnnouncementError {
                 "LimitExceededException" => {
                     return RusotoError::Service(SendAnnouncementError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6024,7 +8598,12 @@ impl SendInvitationError {
                 "NotFoundException" => {
                     return RusotoError::Service(SendInvitationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6059,7 +8638,12 @@ impl StartDeviceSyncError {
                 "DeviceNotRegisteredException" => {
                     return RusotoError::Service(StartDeviceSyncError::DeviceNotRegistered(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6096,7 +8680,12 @@ impl StartSmartHomeApplianceDiscoveryError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6129,7 +8718,12 @@ impl TagResourceError {
                 "NotFoundException" => {
                     return RusotoError::Service(TagResourceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6162,7 +8756,12 @@ impl UntagResourceError {
                 "NotFoundException" => {
                     return RusotoError::Service(UntagResourceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6207,7 +8806,12 @@ impl UpdateAddressBookError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateAddressBookError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6253,7 +8857,12 @@ impl UpdateBusinessReportScheduleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6287,7 +8896,12 @@ impl UpdateConferenceProviderError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateConferenceProviderError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6327,7 +8941,12 @@ impl UpdateContactError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateContactError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6371,7 +8990,12 @@ impl UpdateDeviceError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateDeviceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6411,7 +9035,12 @@ impl UpdateGatewayError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateGatewayError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6450,7 +9079,12 @@ impl UpdateGatewayGroupError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateGatewayGroupError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6510,7 +9144,12 @@ impl UpdateNetworkProfileError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateNetworkProfileError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6559,7 +9198,12 @@ impl UpdateProfileError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateProfileError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6599,7 +9243,12 @@ impl UpdateRoomError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateRoomError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6645,7 +9294,12 @@ impl UpdateSkillGroupError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateSkillGroupError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7224,106 +9878,21 @@ pub trait AlexaForBusiness {
         input: UpdateSkillGroupRequest,
     ) -> RusotoFuture<UpdateSkillGroupResponse, UpdateSkillGroupError>;
 }
-/// A client for the Alexa For Business API.
-#[derive(Clone)]
-pub struct AlexaForBusinessClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl AlexaForBusinessClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> AlexaForBusinessClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> AlexaForBusinessClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> AlexaForBusinessClient {
-        AlexaForBusinessClient { client, region }
-    }
-}
-
-impl AlexaForBusiness for AlexaForBusinessClient {
-    /// <p>Associates a skill with the organization under the customer's AWS account. If a skill is private, the user implicitly accepts access to this skill during enablement.</p>
+const _: Option<&dyn AlexaForBusiness> = None;
+impl<'a, T: AlexaForBusiness + ?Sized> AlexaForBusiness for &'a T {
     fn approve_skill(
         &self,
         input: ApproveSkillRequest,
     ) -> RusotoFuture<ApproveSkillResponse, ApproveSkillError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ApproveSkill");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ApproveSkillResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ApproveSkillError::from_response(response))),
-                )
-            }
-        })
+        (**self).approve_skill(input)
     }
-
-    /// <p>Associates a contact with a given address book.</p>
     fn associate_contact_with_address_book(
         &self,
         input: AssociateContactWithAddressBookRequest,
     ) -> RusotoFuture<AssociateContactWithAddressBookResponse, AssociateContactWithAddressBookError>
     {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.AssociateContactWithAddressBook",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateContactWithAddressBookResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateContactWithAddressBookError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).associate_contact_with_address_book(input)
     }
-
-    /// <p>Associates a device with the specified network profile.</p>
     fn associate_device_with_network_profile(
         &self,
         input: AssociateDeviceWithNetworkProfileRequest,
@@ -7331,824 +9900,176 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         AssociateDeviceWithNetworkProfileResponse,
         AssociateDeviceWithNetworkProfileError,
     > {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.AssociateDeviceWithNetworkProfile",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateDeviceWithNetworkProfileResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateDeviceWithNetworkProfileError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).associate_device_with_network_profile(input)
     }
-
-    /// <p>Associates a device with a given room. This applies all the settings from the room profile to the device, and all the skills in any skill groups added to that room. This operation requires the device to be online, or else a manual sync is required. </p>
     fn associate_device_with_room(
         &self,
         input: AssociateDeviceWithRoomRequest,
     ) -> RusotoFuture<AssociateDeviceWithRoomResponse, AssociateDeviceWithRoomError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.AssociateDeviceWithRoom");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateDeviceWithRoomResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateDeviceWithRoomError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_device_with_room(input)
     }
-
-    /// <p>Associates a skill group with a given room. This enables all skills in the associated skill group on all devices in the room.</p>
     fn associate_skill_group_with_room(
         &self,
         input: AssociateSkillGroupWithRoomRequest,
     ) -> RusotoFuture<AssociateSkillGroupWithRoomResponse, AssociateSkillGroupWithRoomError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.AssociateSkillGroupWithRoom",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateSkillGroupWithRoomResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateSkillGroupWithRoomError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_skill_group_with_room(input)
     }
-
-    /// <p>Associates a skill with a skill group.</p>
     fn associate_skill_with_skill_group(
         &self,
         input: AssociateSkillWithSkillGroupRequest,
     ) -> RusotoFuture<AssociateSkillWithSkillGroupResponse, AssociateSkillWithSkillGroupError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.AssociateSkillWithSkillGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateSkillWithSkillGroupResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateSkillWithSkillGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_skill_with_skill_group(input)
     }
-
-    /// <p>Makes a private skill available for enrolled users to enable on their devices.</p>
     fn associate_skill_with_users(
         &self,
         input: AssociateSkillWithUsersRequest,
     ) -> RusotoFuture<AssociateSkillWithUsersResponse, AssociateSkillWithUsersError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.AssociateSkillWithUsers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateSkillWithUsersResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateSkillWithUsersError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_skill_with_users(input)
     }
-
-    /// <p>Creates an address book with the specified details.</p>
     fn create_address_book(
         &self,
         input: CreateAddressBookRequest,
     ) -> RusotoFuture<CreateAddressBookResponse, CreateAddressBookError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.CreateAddressBook");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAddressBookResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAddressBookError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_address_book(input)
     }
-
-    /// <p>Creates a recurring schedule for usage reports to deliver to the specified S3 location with a specified daily or weekly interval.</p>
     fn create_business_report_schedule(
         &self,
         input: CreateBusinessReportScheduleRequest,
     ) -> RusotoFuture<CreateBusinessReportScheduleResponse, CreateBusinessReportScheduleError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.CreateBusinessReportSchedule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBusinessReportScheduleResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateBusinessReportScheduleError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_business_report_schedule(input)
     }
-
-    /// <p>Adds a new conference provider under the user's AWS account.</p>
     fn create_conference_provider(
         &self,
         input: CreateConferenceProviderRequest,
     ) -> RusotoFuture<CreateConferenceProviderResponse, CreateConferenceProviderError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.CreateConferenceProvider");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateConferenceProviderResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateConferenceProviderError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_conference_provider(input)
     }
-
-    /// <p>Creates a contact with the specified details.</p>
     fn create_contact(
         &self,
         input: CreateContactRequest,
     ) -> RusotoFuture<CreateContactResponse, CreateContactError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.CreateContact");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateContactResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateContactError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_contact(input)
     }
-
-    /// <p>Creates a gateway group with the specified details.</p>
     fn create_gateway_group(
         &self,
         input: CreateGatewayGroupRequest,
     ) -> RusotoFuture<CreateGatewayGroupResponse, CreateGatewayGroupError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.CreateGatewayGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGatewayGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGatewayGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_gateway_group(input)
     }
-
-    /// <p>Creates a network profile with the specified details.</p>
     fn create_network_profile(
         &self,
         input: CreateNetworkProfileRequest,
     ) -> RusotoFuture<CreateNetworkProfileResponse, CreateNetworkProfileError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.CreateNetworkProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateNetworkProfileResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateNetworkProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_network_profile(input)
     }
-
-    /// <p>Creates a new room profile with the specified details.</p>
     fn create_profile(
         &self,
         input: CreateProfileRequest,
     ) -> RusotoFuture<CreateProfileResponse, CreateProfileError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.CreateProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateProfileResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateProfileError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_profile(input)
     }
-
-    /// <p>Creates a room with the specified details.</p>
     fn create_room(
         &self,
         input: CreateRoomRequest,
     ) -> RusotoFuture<CreateRoomResponse, CreateRoomError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.CreateRoom");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRoomResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateRoomError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_room(input)
     }
-
-    /// <p>Creates a skill group with a specified name and description.</p>
     fn create_skill_group(
         &self,
         input: CreateSkillGroupRequest,
     ) -> RusotoFuture<CreateSkillGroupResponse, CreateSkillGroupError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.CreateSkillGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSkillGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSkillGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_skill_group(input)
     }
-
-    /// <p>Creates a user.</p>
     fn create_user(
         &self,
         input: CreateUserRequest,
     ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.CreateUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_user(input)
     }
-
-    /// <p>Deletes an address book by the address book ARN.</p>
     fn delete_address_book(
         &self,
         input: DeleteAddressBookRequest,
     ) -> RusotoFuture<DeleteAddressBookResponse, DeleteAddressBookError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteAddressBook");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteAddressBookResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAddressBookError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_address_book(input)
     }
-
-    /// <p>Deletes the recurring report delivery schedule with the specified schedule ARN.</p>
     fn delete_business_report_schedule(
         &self,
         input: DeleteBusinessReportScheduleRequest,
     ) -> RusotoFuture<DeleteBusinessReportScheduleResponse, DeleteBusinessReportScheduleError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.DeleteBusinessReportSchedule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBusinessReportScheduleResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteBusinessReportScheduleError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_business_report_schedule(input)
     }
-
-    /// <p>Deletes a conference provider.</p>
     fn delete_conference_provider(
         &self,
         input: DeleteConferenceProviderRequest,
     ) -> RusotoFuture<DeleteConferenceProviderResponse, DeleteConferenceProviderError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteConferenceProvider");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteConferenceProviderResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteConferenceProviderError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_conference_provider(input)
     }
-
-    /// <p>Deletes a contact by the contact ARN.</p>
     fn delete_contact(
         &self,
         input: DeleteContactRequest,
     ) -> RusotoFuture<DeleteContactResponse, DeleteContactError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteContact");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteContactResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteContactError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_contact(input)
     }
-
-    /// <p>Removes a device from Alexa For Business.</p>
     fn delete_device(
         &self,
         input: DeleteDeviceRequest,
     ) -> RusotoFuture<DeleteDeviceResponse, DeleteDeviceError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteDevice");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDeviceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDeviceError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_device(input)
     }
-
-    /// <p>When this action is called for a specified shared device, it allows authorized users to delete the device's entire previous history of voice input data and associated response data. This action can be called once every 24 hours for a specific shared device.</p>
     fn delete_device_usage_data(
         &self,
         input: DeleteDeviceUsageDataRequest,
     ) -> RusotoFuture<DeleteDeviceUsageDataResponse, DeleteDeviceUsageDataError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteDeviceUsageData");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDeviceUsageDataResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteDeviceUsageDataError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_device_usage_data(input)
     }
-
-    /// <p>Deletes a gateway group.</p>
     fn delete_gateway_group(
         &self,
         input: DeleteGatewayGroupRequest,
     ) -> RusotoFuture<DeleteGatewayGroupResponse, DeleteGatewayGroupError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteGatewayGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteGatewayGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteGatewayGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_gateway_group(input)
     }
-
-    /// <p>Deletes a network profile by the network profile ARN.</p>
     fn delete_network_profile(
         &self,
         input: DeleteNetworkProfileRequest,
     ) -> RusotoFuture<DeleteNetworkProfileResponse, DeleteNetworkProfileError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteNetworkProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteNetworkProfileResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteNetworkProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_network_profile(input)
     }
-
-    /// <p>Deletes a room profile by the profile ARN.</p>
     fn delete_profile(
         &self,
         input: DeleteProfileRequest,
     ) -> RusotoFuture<DeleteProfileResponse, DeleteProfileError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteProfileResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteProfileError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_profile(input)
     }
-
-    /// <p>Deletes a room by the room ARN.</p>
     fn delete_room(
         &self,
         input: DeleteRoomRequest,
     ) -> RusotoFuture<DeleteRoomResponse, DeleteRoomError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoom");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRoomResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteRoomError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_room(input)
     }
-
-    /// <p>Deletes room skill parameter details by room, skill, and parameter key ID.</p>
     fn delete_room_skill_parameter(
         &self,
         input: DeleteRoomSkillParameterRequest,
     ) -> RusotoFuture<DeleteRoomSkillParameterResponse, DeleteRoomSkillParameterError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoomSkillParameter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRoomSkillParameterResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteRoomSkillParameterError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_room_skill_parameter(input)
     }
-
-    /// <p>Unlinks a third-party account from a skill.</p>
     fn delete_skill_authorization(
         &self,
         input: DeleteSkillAuthorizationRequest,
     ) -> RusotoFuture<DeleteSkillAuthorizationResponse, DeleteSkillAuthorizationError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillAuthorization");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSkillAuthorizationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteSkillAuthorizationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_skill_authorization(input)
     }
-
-    /// <p>Deletes a skill group by skill group ARN.</p>
     fn delete_skill_group(
         &self,
         input: DeleteSkillGroupRequest,
     ) -> RusotoFuture<DeleteSkillGroupResponse, DeleteSkillGroupError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSkillGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteSkillGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_skill_group(input)
     }
-
-    /// <p>Deletes a specified user by user ARN and enrollment ARN.</p>
     fn delete_user(
         &self,
         input: DeleteUserRequest,
     ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.DeleteUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_user(input)
     }
-
-    /// <p>Disassociates a contact from a given address book.</p>
     fn disassociate_contact_from_address_book(
         &self,
         input: DisassociateContactFromAddressBookRequest,
@@ -8156,1770 +10077,8216 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         DisassociateContactFromAddressBookResponse,
         DisassociateContactFromAddressBookError,
     > {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.DisassociateContactFromAddressBook",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateContactFromAddressBookResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateContactFromAddressBookError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disassociate_contact_from_address_book(input)
     }
-
-    /// <p>Disassociates a device from its current room. The device continues to be connected to the Wi-Fi network and is still registered to the account. The device settings and skills are removed from the room.</p>
     fn disassociate_device_from_room(
         &self,
         input: DisassociateDeviceFromRoomRequest,
     ) -> RusotoFuture<DisassociateDeviceFromRoomResponse, DisassociateDeviceFromRoomError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.DisassociateDeviceFromRoom",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateDeviceFromRoomResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateDeviceFromRoomError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_device_from_room(input)
     }
-
-    /// <p>Disassociates a skill from a skill group.</p>
     fn disassociate_skill_from_skill_group(
         &self,
         input: DisassociateSkillFromSkillGroupRequest,
     ) -> RusotoFuture<DisassociateSkillFromSkillGroupResponse, DisassociateSkillFromSkillGroupError>
     {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.DisassociateSkillFromSkillGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateSkillFromSkillGroupResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateSkillFromSkillGroupError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disassociate_skill_from_skill_group(input)
     }
-
-    /// <p>Makes a private skill unavailable for enrolled users and prevents them from enabling it on their devices.</p>
     fn disassociate_skill_from_users(
         &self,
         input: DisassociateSkillFromUsersRequest,
     ) -> RusotoFuture<DisassociateSkillFromUsersResponse, DisassociateSkillFromUsersError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.DisassociateSkillFromUsers",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateSkillFromUsersResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateSkillFromUsersError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_skill_from_users(input)
     }
-
-    /// <p>Disassociates a skill group from a specified room. This disables all skills in the skill group on all devices in the room.</p>
     fn disassociate_skill_group_from_room(
         &self,
         input: DisassociateSkillGroupFromRoomRequest,
     ) -> RusotoFuture<DisassociateSkillGroupFromRoomResponse, DisassociateSkillGroupFromRoomError>
     {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.DisassociateSkillGroupFromRoom",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateSkillGroupFromRoomResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateSkillGroupFromRoomError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_skill_group_from_room(input)
     }
-
-    /// <p>Forgets smart home appliances associated to a room.</p>
     fn forget_smart_home_appliances(
         &self,
         input: ForgetSmartHomeAppliancesRequest,
     ) -> RusotoFuture<ForgetSmartHomeAppliancesResponse, ForgetSmartHomeAppliancesError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ForgetSmartHomeAppliances");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ForgetSmartHomeAppliancesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ForgetSmartHomeAppliancesError::from_response(response))
-                }))
-            }
-        })
+        (**self).forget_smart_home_appliances(input)
     }
-
-    /// <p>Gets address the book details by the address book ARN.</p>
     fn get_address_book(
         &self,
         input: GetAddressBookRequest,
     ) -> RusotoFuture<GetAddressBookResponse, GetAddressBookError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetAddressBook");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAddressBookResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAddressBookError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_address_book(input)
     }
-
-    /// <p>Retrieves the existing conference preferences.</p>
     fn get_conference_preference(
         &self,
     ) -> RusotoFuture<GetConferencePreferenceResponse, GetConferencePreferenceError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetConferencePreference");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetConferencePreferenceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetConferencePreferenceError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_conference_preference()
     }
-
-    /// <p>Gets details about a specific conference provider.</p>
     fn get_conference_provider(
         &self,
         input: GetConferenceProviderRequest,
     ) -> RusotoFuture<GetConferenceProviderResponse, GetConferenceProviderError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetConferenceProvider");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetConferenceProviderResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetConferenceProviderError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_conference_provider(input)
     }
-
-    /// <p>Gets the contact details by the contact ARN.</p>
     fn get_contact(
         &self,
         input: GetContactRequest,
     ) -> RusotoFuture<GetContactResponse, GetContactError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetContact");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetContactResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetContactError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_contact(input)
     }
-
-    /// <p>Gets the details of a device by device ARN.</p>
     fn get_device(
         &self,
         input: GetDeviceRequest,
     ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetDevice");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeviceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDeviceError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_device(input)
     }
-
-    /// <p>Retrieves the details of a gateway.</p>
     fn get_gateway(
         &self,
         input: GetGatewayRequest,
     ) -> RusotoFuture<GetGatewayResponse, GetGatewayError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetGateway");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGatewayResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetGatewayError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_gateway(input)
     }
-
-    /// <p>Retrieves the details of a gateway group.</p>
     fn get_gateway_group(
         &self,
         input: GetGatewayGroupRequest,
     ) -> RusotoFuture<GetGatewayGroupResponse, GetGatewayGroupError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetGatewayGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGatewayGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetGatewayGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_gateway_group(input)
     }
-
-    /// <p>Retrieves the configured values for the user enrollment invitation email template.</p>
     fn get_invitation_configuration(
         &self,
     ) -> RusotoFuture<GetInvitationConfigurationResponse, GetInvitationConfigurationError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.GetInvitationConfiguration",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInvitationConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetInvitationConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_invitation_configuration()
     }
-
-    /// <p>Gets the network profile details by the network profile ARN.</p>
     fn get_network_profile(
         &self,
         input: GetNetworkProfileRequest,
     ) -> RusotoFuture<GetNetworkProfileResponse, GetNetworkProfileError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetNetworkProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetNetworkProfileResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetNetworkProfileError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_network_profile(input)
     }
-
-    /// <p>Gets the details of a room profile by profile ARN.</p>
     fn get_profile(
         &self,
         input: GetProfileRequest,
     ) -> RusotoFuture<GetProfileResponse, GetProfileError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetProfileResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetProfileError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_profile(input)
     }
-
-    /// <p>Gets room details by room ARN.</p>
     fn get_room(&self, input: GetRoomRequest) -> RusotoFuture<GetRoomResponse, GetRoomError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetRoom");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetRoomResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRoomError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_room(input)
     }
-
-    /// <p>Gets room skill parameter details by room, skill, and parameter key ARN.</p>
     fn get_room_skill_parameter(
         &self,
         input: GetRoomSkillParameterRequest,
     ) -> RusotoFuture<GetRoomSkillParameterResponse, GetRoomSkillParameterError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetRoomSkillParameter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRoomSkillParameterResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetRoomSkillParameterError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_room_skill_parameter(input)
     }
-
-    /// <p>Gets skill group details by skill group ARN.</p>
     fn get_skill_group(
         &self,
         input: GetSkillGroupRequest,
     ) -> RusotoFuture<GetSkillGroupResponse, GetSkillGroupError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.GetSkillGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSkillGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSkillGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_skill_group(input)
     }
-
-    /// <p>Lists the details of the schedules that a user configured.</p>
     fn list_business_report_schedules(
         &self,
         input: ListBusinessReportSchedulesRequest,
     ) -> RusotoFuture<ListBusinessReportSchedulesResponse, ListBusinessReportSchedulesError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.ListBusinessReportSchedules",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBusinessReportSchedulesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListBusinessReportSchedulesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_business_report_schedules(input)
     }
-
-    /// <p>Lists conference providers under a specific AWS account.</p>
     fn list_conference_providers(
         &self,
         input: ListConferenceProvidersRequest,
     ) -> RusotoFuture<ListConferenceProvidersResponse, ListConferenceProvidersError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ListConferenceProviders");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListConferenceProvidersResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListConferenceProvidersError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_conference_providers(input)
     }
-
-    /// <p>Lists the device event history, including device connection status, for up to 30 days.</p>
     fn list_device_events(
         &self,
         input: ListDeviceEventsRequest,
     ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ListDeviceEvents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeviceEventsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDeviceEventsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_device_events(input)
     }
-
-    /// <p>Retrieves a list of gateway group summaries. Use GetGatewayGroup to retrieve details of a specific gateway group.</p>
     fn list_gateway_groups(
         &self,
         input: ListGatewayGroupsRequest,
     ) -> RusotoFuture<ListGatewayGroupsResponse, ListGatewayGroupsError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ListGatewayGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGatewayGroupsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGatewayGroupsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_gateway_groups(input)
     }
-
-    /// <p>Retrieves a list of gateway summaries. Use GetGateway to retrieve details of a specific gateway. An optional gateway group ARN can be provided to only retrieve gateway summaries of gateways that are associated with that gateway group ARN.</p>
     fn list_gateways(
         &self,
         input: ListGatewaysRequest,
     ) -> RusotoFuture<ListGatewaysResponse, ListGatewaysError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ListGateways");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGatewaysResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGatewaysError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_gateways(input)
     }
-
-    /// <p>Lists all enabled skills in a specific skill group.</p>
     fn list_skills(
         &self,
         input: ListSkillsRequest,
     ) -> RusotoFuture<ListSkillsResponse, ListSkillsError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ListSkills");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSkillsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListSkillsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_skills(input)
     }
-
-    /// <p>Lists all categories in the Alexa skill store.</p>
     fn list_skills_store_categories(
         &self,
         input: ListSkillsStoreCategoriesRequest,
     ) -> RusotoFuture<ListSkillsStoreCategoriesResponse, ListSkillsStoreCategoriesError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ListSkillsStoreCategories");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSkillsStoreCategoriesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSkillsStoreCategoriesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_skills_store_categories(input)
     }
-
-    /// <p>Lists all skills in the Alexa skill store by category.</p>
     fn list_skills_store_skills_by_category(
         &self,
         input: ListSkillsStoreSkillsByCategoryRequest,
     ) -> RusotoFuture<ListSkillsStoreSkillsByCategoryResponse, ListSkillsStoreSkillsByCategoryError>
     {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.ListSkillsStoreSkillsByCategory",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSkillsStoreSkillsByCategoryResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSkillsStoreSkillsByCategoryError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_skills_store_skills_by_category(input)
     }
-
-    /// <p>Lists all of the smart home appliances associated with a room.</p>
     fn list_smart_home_appliances(
         &self,
         input: ListSmartHomeAppliancesRequest,
     ) -> RusotoFuture<ListSmartHomeAppliancesResponse, ListSmartHomeAppliancesError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ListSmartHomeAppliances");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSmartHomeAppliancesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSmartHomeAppliancesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_smart_home_appliances(input)
     }
-
-    /// <p>Lists all tags for the specified resource.</p>
     fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ListTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tags(input)
     }
-
-    /// <p>Sets the conference preferences on a specific conference provider at the account level.</p>
     fn put_conference_preference(
         &self,
         input: PutConferencePreferenceRequest,
     ) -> RusotoFuture<PutConferencePreferenceResponse, PutConferencePreferenceError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.PutConferencePreference");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutConferencePreferenceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutConferencePreferenceError::from_response(response))
-                }))
-            }
-        })
+        (**self).put_conference_preference(input)
     }
-
-    /// <p>Configures the email template for the user enrollment invitation with the specified attributes.</p>
     fn put_invitation_configuration(
         &self,
         input: PutInvitationConfigurationRequest,
     ) -> RusotoFuture<PutInvitationConfigurationResponse, PutInvitationConfigurationError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.PutInvitationConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutInvitationConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutInvitationConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).put_invitation_configuration(input)
     }
-
-    /// <p>Updates room skill parameter details by room, skill, and parameter key ID. Not all skills have a room skill parameter.</p>
     fn put_room_skill_parameter(
         &self,
         input: PutRoomSkillParameterRequest,
     ) -> RusotoFuture<PutRoomSkillParameterResponse, PutRoomSkillParameterError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.PutRoomSkillParameter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutRoomSkillParameterResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutRoomSkillParameterError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).put_room_skill_parameter(input)
     }
-
-    /// <p>Links a user's account to a third-party skill provider. If this API operation is called by an assumed IAM role, the skill being linked must be a private skill. Also, the skill must be owned by the AWS account that assumed the IAM role.</p>
     fn put_skill_authorization(
         &self,
         input: PutSkillAuthorizationRequest,
     ) -> RusotoFuture<PutSkillAuthorizationResponse, PutSkillAuthorizationError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.PutSkillAuthorization");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutSkillAuthorizationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutSkillAuthorizationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).put_skill_authorization(input)
     }
-
-    /// <p>Registers an Alexa-enabled device built by an Original Equipment Manufacturer (OEM) using Alexa Voice Service (AVS).</p>
     fn register_avs_device(
         &self,
         input: RegisterAVSDeviceRequest,
     ) -> RusotoFuture<RegisterAVSDeviceResponse, RegisterAVSDeviceError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.RegisterAVSDevice");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterAVSDeviceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RegisterAVSDeviceError::from_response(response))),
-                )
-            }
-        })
+        (**self).register_avs_device(input)
     }
-
-    /// <p>Disassociates a skill from the organization under a user's AWS account. If the skill is a private skill, it moves to an AcceptStatus of PENDING. Any private or public skill that is rejected can be added later by calling the ApproveSkill API. </p>
     fn reject_skill(
         &self,
         input: RejectSkillRequest,
     ) -> RusotoFuture<RejectSkillResponse, RejectSkillError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.RejectSkill");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RejectSkillResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RejectSkillError::from_response(response))),
-                )
-            }
-        })
+        (**self).reject_skill(input)
     }
-
-    /// <p>Determines the details for the room from which a skill request was invoked. This operation is used by skill developers.</p>
     fn resolve_room(
         &self,
         input: ResolveRoomRequest,
     ) -> RusotoFuture<ResolveRoomResponse, ResolveRoomError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.ResolveRoom");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResolveRoomResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResolveRoomError::from_response(response))),
-                )
-            }
-        })
+        (**self).resolve_room(input)
     }
-
-    /// <p>Revokes an invitation and invalidates the enrollment URL.</p>
     fn revoke_invitation(
         &self,
         input: RevokeInvitationRequest,
     ) -> RusotoFuture<RevokeInvitationResponse, RevokeInvitationError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.RevokeInvitation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RevokeInvitationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RevokeInvitationError::from_response(response))),
-                )
-            }
-        })
+        (**self).revoke_invitation(input)
     }
-
-    /// <p>Searches address books and lists the ones that meet a set of filter and sort criteria.</p>
     fn search_address_books(
         &self,
         input: SearchAddressBooksRequest,
     ) -> RusotoFuture<SearchAddressBooksResponse, SearchAddressBooksError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.SearchAddressBooks");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchAddressBooksResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchAddressBooksError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_address_books(input)
     }
-
-    /// <p>Searches contacts and lists the ones that meet a set of filter and sort criteria.</p>
     fn search_contacts(
         &self,
         input: SearchContactsRequest,
     ) -> RusotoFuture<SearchContactsResponse, SearchContactsError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.SearchContacts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchContactsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchContactsError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_contacts(input)
     }
-
-    /// <p>Searches devices and lists the ones that meet a set of filter criteria.</p>
     fn search_devices(
         &self,
         input: SearchDevicesRequest,
     ) -> RusotoFuture<SearchDevicesResponse, SearchDevicesError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.SearchDevices");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchDevicesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchDevicesError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_devices(input)
     }
-
-    /// <p>Searches network profiles and lists the ones that meet a set of filter and sort criteria.</p>
     fn search_network_profiles(
         &self,
         input: SearchNetworkProfilesRequest,
     ) -> RusotoFuture<SearchNetworkProfilesResponse, SearchNetworkProfilesError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.SearchNetworkProfiles");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchNetworkProfilesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SearchNetworkProfilesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).search_network_profiles(input)
     }
-
-    /// <p>Searches room profiles and lists the ones that meet a set of filter criteria.</p>
     fn search_profiles(
         &self,
         input: SearchProfilesRequest,
     ) -> RusotoFuture<SearchProfilesResponse, SearchProfilesError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.SearchProfiles");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchProfilesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchProfilesError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_profiles(input)
     }
-
-    /// <p>Searches rooms and lists the ones that meet a set of filter and sort criteria.</p>
     fn search_rooms(
         &self,
         input: SearchRoomsRequest,
     ) -> RusotoFuture<SearchRoomsResponse, SearchRoomsError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.SearchRooms");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchRoomsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchRoomsError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_rooms(input)
     }
-
-    /// <p>Searches skill groups and lists the ones that meet a set of filter and sort criteria.</p>
     fn search_skill_groups(
         &self,
         input: SearchSkillGroupsRequest,
     ) -> RusotoFuture<SearchSkillGroupsResponse, SearchSkillGroupsError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.SearchSkillGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchSkillGroupsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchSkillGroupsError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_skill_groups(input)
     }
-
-    /// <p>Searches users and lists the ones that meet a set of filter and sort criteria.</p>
     fn search_users(
         &self,
         input: SearchUsersRequest,
     ) -> RusotoFuture<SearchUsersResponse, SearchUsersError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.SearchUsers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchUsersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchUsersError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_users(input)
     }
-
-    /// <p>Triggers an asynchronous flow to send text, SSML, or audio announcements to rooms that are identified by a search or filter. </p>
     fn send_announcement(
         &self,
         input: SendAnnouncementRequest,
     ) -> RusotoFuture<SendAnnouncementResponse, SendAnnouncementError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.SendAnnouncement");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendAnnouncementResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SendAnnouncementError::from_response(response))),
-                )
-            }
-        })
+        (**self).send_announcement(input)
     }
-
-    /// <p>Sends an enrollment invitation email with a URL to a user. The URL is valid for 30 days or until you call this operation again, whichever comes first. </p>
     fn send_invitation(
         &self,
         input: SendInvitationRequest,
     ) -> RusotoFuture<SendInvitationResponse, SendInvitationError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.SendInvitation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendInvitationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SendInvitationError::from_response(response))),
-                )
-            }
-        })
+        (**self).send_invitation(input)
     }
-
-    /// <p><p>Resets a device and its account to the known default settings. This clears all information and settings set by previous users in the following ways:</p> <ul> <li> <p>Bluetooth - This unpairs all bluetooth devices paired with your echo device.</p> </li> <li> <p>Volume - This resets the echo device&#39;s volume to the default value.</p> </li> <li> <p>Notifications - This clears all notifications from your echo device.</p> </li> <li> <p>Lists - This clears all to-do items from your echo device.</p> </li> <li> <p>Settings - This internally syncs the room&#39;s profile (if the device is assigned to a room), contacts, address books, delegation access for account linking, and communications (if enabled on the room profile).</p> </li> </ul></p>
     fn start_device_sync(
         &self,
         input: StartDeviceSyncRequest,
     ) -> RusotoFuture<StartDeviceSyncResponse, StartDeviceSyncError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.StartDeviceSync");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartDeviceSyncResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartDeviceSyncError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_device_sync(input)
     }
-
-    /// <p>Initiates the discovery of any smart home appliances associated with the room.</p>
     fn start_smart_home_appliance_discovery(
         &self,
         input: StartSmartHomeApplianceDiscoveryRequest,
     ) -> RusotoFuture<StartSmartHomeApplianceDiscoveryResponse, StartSmartHomeApplianceDiscoveryError>
     {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.StartSmartHomeApplianceDiscovery",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartSmartHomeApplianceDiscoveryResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartSmartHomeApplianceDiscoveryError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).start_smart_home_appliance_discovery(input)
     }
-
-    /// <p>Adds metadata tags to a specified resource.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).tag_resource(input)
     }
-
-    /// <p>Removes metadata tags from a specified resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).untag_resource(input)
+    }
+    fn update_address_book(
+        &self,
+        input: UpdateAddressBookRequest,
+    ) -> RusotoFuture<UpdateAddressBookResponse, UpdateAddressBookError> {
+        (**self).update_address_book(input)
+    }
+    fn update_business_report_schedule(
+        &self,
+        input: UpdateBusinessReportScheduleRequest,
+    ) -> RusotoFuture<UpdateBusinessReportScheduleResponse, UpdateBusinessReportScheduleError> {
+        (**self).update_business_report_schedule(input)
+    }
+    fn update_conference_provider(
+        &self,
+        input: UpdateConferenceProviderRequest,
+    ) -> RusotoFuture<UpdateConferenceProviderResponse, UpdateConferenceProviderError> {
+        (**self).update_conference_provider(input)
+    }
+    fn update_contact(
+        &self,
+        input: UpdateContactRequest,
+    ) -> RusotoFuture<UpdateContactResponse, UpdateContactError> {
+        (**self).update_contact(input)
+    }
+    fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> RusotoFuture<UpdateDeviceResponse, UpdateDeviceError> {
+        (**self).update_device(input)
+    }
+    fn update_gateway(
+        &self,
+        input: UpdateGatewayRequest,
+    ) -> RusotoFuture<UpdateGatewayResponse, UpdateGatewayError> {
+        (**self).update_gateway(input)
+    }
+    fn update_gateway_group(
+        &self,
+        input: UpdateGatewayGroupRequest,
+    ) -> RusotoFuture<UpdateGatewayGroupResponse, UpdateGatewayGroupError> {
+        (**self).update_gateway_group(input)
+    }
+    fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> RusotoFuture<UpdateNetworkProfileResponse, UpdateNetworkProfileError> {
+        (**self).update_network_profile(input)
+    }
+    fn update_profile(
+        &self,
+        input: UpdateProfileRequest,
+    ) -> RusotoFuture<UpdateProfileResponse, UpdateProfileError> {
+        (**self).update_profile(input)
+    }
+    fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> RusotoFuture<UpdateRoomResponse, UpdateRoomError> {
+        (**self).update_room(input)
+    }
+    fn update_skill_group(
+        &self,
+        input: UpdateSkillGroupRequest,
+    ) -> RusotoFuture<UpdateSkillGroupResponse, UpdateSkillGroupError> {
+        (**self).update_skill_group(input)
+    }
+}
+impl<T: AlexaForBusiness + ?Sized> AlexaForBusiness for Box<T> {
+    fn approve_skill(
+        &self,
+        input: ApproveSkillRequest,
+    ) -> RusotoFuture<ApproveSkillResponse, ApproveSkillError> {
+        (**self).approve_skill(input)
+    }
+    fn associate_contact_with_address_book(
+        &self,
+        input: AssociateContactWithAddressBookRequest,
+    ) -> RusotoFuture<AssociateContactWithAddressBookResponse, AssociateContactWithAddressBookError>
+    {
+        (**self).associate_contact_with_address_book(input)
+    }
+    fn associate_device_with_network_profile(
+        &self,
+        input: AssociateDeviceWithNetworkProfileRequest,
+    ) -> RusotoFuture<
+        AssociateDeviceWithNetworkProfileResponse,
+        AssociateDeviceWithNetworkProfileError,
+    > {
+        (**self).associate_device_with_network_profile(input)
+    }
+    fn associate_device_with_room(
+        &self,
+        input: AssociateDeviceWithRoomRequest,
+    ) -> RusotoFuture<AssociateDeviceWithRoomResponse, AssociateDeviceWithRoomError> {
+        (**self).associate_device_with_room(input)
+    }
+    fn associate_skill_group_with_room(
+        &self,
+        input: AssociateSkillGroupWithRoomRequest,
+    ) -> RusotoFuture<AssociateSkillGroupWithRoomResponse, AssociateSkillGroupWithRoomError> {
+        (**self).associate_skill_group_with_room(input)
+    }
+    fn associate_skill_with_skill_group(
+        &self,
+        input: AssociateSkillWithSkillGroupRequest,
+    ) -> RusotoFuture<AssociateSkillWithSkillGroupResponse, AssociateSkillWithSkillGroupError> {
+        (**self).associate_skill_with_skill_group(input)
+    }
+    fn associate_skill_with_users(
+        &self,
+        input: AssociateSkillWithUsersRequest,
+    ) -> RusotoFuture<AssociateSkillWithUsersResponse, AssociateSkillWithUsersError> {
+        (**self).associate_skill_with_users(input)
+    }
+    fn create_address_book(
+        &self,
+        input: CreateAddressBookRequest,
+    ) -> RusotoFuture<CreateAddressBookResponse, CreateAddressBookError> {
+        (**self).create_address_book(input)
+    }
+    fn create_business_report_schedule(
+        &self,
+        input: CreateBusinessReportScheduleRequest,
+    ) -> RusotoFuture<CreateBusinessReportScheduleResponse, CreateBusinessReportScheduleError> {
+        (**self).create_business_report_schedule(input)
+    }
+    fn create_conference_provider(
+        &self,
+        input: CreateConferenceProviderRequest,
+    ) -> RusotoFuture<CreateConferenceProviderResponse, CreateConferenceProviderError> {
+        (**self).create_conference_provider(input)
+    }
+    fn create_contact(
+        &self,
+        input: CreateContactRequest,
+    ) -> RusotoFuture<CreateContactResponse, CreateContactError> {
+        (**self).create_contact(input)
+    }
+    fn create_gateway_group(
+        &self,
+        input: CreateGatewayGroupRequest,
+    ) -> RusotoFuture<CreateGatewayGroupResponse, CreateGatewayGroupError> {
+        (**self).create_gateway_group(input)
+    }
+    fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> RusotoFuture<CreateNetworkProfileResponse, CreateNetworkProfileError> {
+        (**self).create_network_profile(input)
+    }
+    fn create_profile(
+        &self,
+        input: CreateProfileRequest,
+    ) -> RusotoFuture<CreateProfileResponse, CreateProfileError> {
+        (**self).create_profile(input)
+    }
+    fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> RusotoFuture<CreateRoomResponse, CreateRoomError> {
+        (**self).create_room(input)
+    }
+    fn create_skill_group(
+        &self,
+        input: CreateSkillGroupRequest,
+    ) -> RusotoFuture<CreateSkillGroupResponse, CreateSkillGroupError> {
+        (**self).create_skill_group(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_address_book(
+        &self,
+        input: DeleteAddressBookRequest,
+    ) -> RusotoFuture<DeleteAddressBookResponse, DeleteAddressBookError> {
+        (**self).delete_address_book(input)
+    }
+    fn delete_business_report_schedule(
+        &self,
+        input: DeleteBusinessReportScheduleRequest,
+    ) -> RusotoFuture<DeleteBusinessReportScheduleResponse, DeleteBusinessReportScheduleError> {
+        (**self).delete_business_report_schedule(input)
+    }
+    fn delete_conference_provider(
+        &self,
+        input: DeleteConferenceProviderRequest,
+    ) -> RusotoFuture<DeleteConferenceProviderResponse, DeleteConferenceProviderError> {
+        (**self).delete_conference_provider(input)
+    }
+    fn delete_contact(
+        &self,
+        input: DeleteContactRequest,
+    ) -> RusotoFuture<DeleteContactResponse, DeleteContactError> {
+        (**self).delete_contact(input)
+    }
+    fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> RusotoFuture<DeleteDeviceResponse, DeleteDeviceError> {
+        (**self).delete_device(input)
+    }
+    fn delete_device_usage_data(
+        &self,
+        input: DeleteDeviceUsageDataRequest,
+    ) -> RusotoFuture<DeleteDeviceUsageDataResponse, DeleteDeviceUsageDataError> {
+        (**self).delete_device_usage_data(input)
+    }
+    fn delete_gateway_group(
+        &self,
+        input: DeleteGatewayGroupRequest,
+    ) -> RusotoFuture<DeleteGatewayGroupResponse, DeleteGatewayGroupError> {
+        (**self).delete_gateway_group(input)
+    }
+    fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> RusotoFuture<DeleteNetworkProfileResponse, DeleteNetworkProfileError> {
+        (**self).delete_network_profile(input)
+    }
+    fn delete_profile(
+        &self,
+        input: DeleteProfileRequest,
+    ) -> RusotoFuture<DeleteProfileResponse, DeleteProfileError> {
+        (**self).delete_profile(input)
+    }
+    fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> RusotoFuture<DeleteRoomResponse, DeleteRoomError> {
+        (**self).delete_room(input)
+    }
+    fn delete_room_skill_parameter(
+        &self,
+        input: DeleteRoomSkillParameterRequest,
+    ) -> RusotoFuture<DeleteRoomSkillParameterResponse, DeleteRoomSkillParameterError> {
+        (**self).delete_room_skill_parameter(input)
+    }
+    fn delete_skill_authorization(
+        &self,
+        input: DeleteSkillAuthorizationRequest,
+    ) -> RusotoFuture<DeleteSkillAuthorizationResponse, DeleteSkillAuthorizationError> {
+        (**self).delete_skill_authorization(input)
+    }
+    fn delete_skill_group(
+        &self,
+        input: DeleteSkillGroupRequest,
+    ) -> RusotoFuture<DeleteSkillGroupResponse, DeleteSkillGroupError> {
+        (**self).delete_skill_group(input)
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn disassociate_contact_from_address_book(
+        &self,
+        input: DisassociateContactFromAddressBookRequest,
+    ) -> RusotoFuture<
+        DisassociateContactFromAddressBookResponse,
+        DisassociateContactFromAddressBookError,
+    > {
+        (**self).disassociate_contact_from_address_book(input)
+    }
+    fn disassociate_device_from_room(
+        &self,
+        input: DisassociateDeviceFromRoomRequest,
+    ) -> RusotoFuture<DisassociateDeviceFromRoomResponse, DisassociateDeviceFromRoomError> {
+        (**self).disassociate_device_from_room(input)
+    }
+    fn disassociate_skill_from_skill_group(
+        &self,
+        input: DisassociateSkillFromSkillGroupRequest,
+    ) -> RusotoFuture<DisassociateSkillFromSkillGroupResponse, DisassociateSkillFromSkillGroupError>
+    {
+        (**self).disassociate_skill_from_skill_group(input)
+    }
+    fn disassociate_skill_from_users(
+        &self,
+        input: DisassociateSkillFromUsersRequest,
+    ) -> RusotoFuture<DisassociateSkillFromUsersResponse, DisassociateSkillFromUsersError> {
+        (**self).disassociate_skill_from_users(input)
+    }
+    fn disassociate_skill_group_from_room(
+        &self,
+        input: DisassociateSkillGroupFromRoomRequest,
+    ) -> RusotoFuture<DisassociateSkillGroupFromRoomResponse, DisassociateSkillGroupFromRoomError>
+    {
+        (**self).disassociate_skill_group_from_room(input)
+    }
+    fn forget_smart_home_appliances(
+        &self,
+        input: ForgetSmartHomeAppliancesRequest,
+    ) -> RusotoFuture<ForgetSmartHomeAppliancesResponse, ForgetSmartHomeAppliancesError> {
+        (**self).forget_smart_home_appliances(input)
+    }
+    fn get_address_book(
+        &self,
+        input: GetAddressBookRequest,
+    ) -> RusotoFuture<GetAddressBookResponse, GetAddressBookError> {
+        (**self).get_address_book(input)
+    }
+    fn get_conference_preference(
+        &self,
+    ) -> RusotoFuture<GetConferencePreferenceResponse, GetConferencePreferenceError> {
+        (**self).get_conference_preference()
+    }
+    fn get_conference_provider(
+        &self,
+        input: GetConferenceProviderRequest,
+    ) -> RusotoFuture<GetConferenceProviderResponse, GetConferenceProviderError> {
+        (**self).get_conference_provider(input)
+    }
+    fn get_contact(
+        &self,
+        input: GetContactRequest,
+    ) -> RusotoFuture<GetContactResponse, GetContactError> {
+        (**self).get_contact(input)
+    }
+    fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
+        (**self).get_device(input)
+    }
+    fn get_gateway(
+        &self,
+        input: GetGatewayRequest,
+    ) -> RusotoFuture<GetGatewayResponse, GetGatewayError> {
+        (**self).get_gateway(input)
+    }
+    fn get_gateway_group(
+        &self,
+        input: GetGatewayGroupRequest,
+    ) -> RusotoFuture<GetGatewayGroupResponse, GetGatewayGroupError> {
+        (**self).get_gateway_group(input)
+    }
+    fn get_invitation_configuration(
+        &self,
+    ) -> RusotoFuture<GetInvitationConfigurationResponse, GetInvitationConfigurationError> {
+        (**self).get_invitation_configuration()
+    }
+    fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> RusotoFuture<GetNetworkProfileResponse, GetNetworkProfileError> {
+        (**self).get_network_profile(input)
+    }
+    fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> RusotoFuture<GetProfileResponse, GetProfileError> {
+        (**self).get_profile(input)
+    }
+    fn get_room(&self, input: GetRoomRequest) -> RusotoFuture<GetRoomResponse, GetRoomError> {
+        (**self).get_room(input)
+    }
+    fn get_room_skill_parameter(
+        &self,
+        input: GetRoomSkillParameterRequest,
+    ) -> RusotoFuture<GetRoomSkillParameterResponse, GetRoomSkillParameterError> {
+        (**self).get_room_skill_parameter(input)
+    }
+    fn get_skill_group(
+        &self,
+        input: GetSkillGroupRequest,
+    ) -> RusotoFuture<GetSkillGroupResponse, GetSkillGroupError> {
+        (**self).get_skill_group(input)
+    }
+    fn list_business_report_schedules(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> RusotoFuture<ListBusinessReportSchedulesResponse, ListBusinessReportSchedulesError> {
+        (**self).list_business_report_schedules(input)
+    }
+    fn list_conference_providers(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> RusotoFuture<ListConferenceProvidersResponse, ListConferenceProvidersError> {
+        (**self).list_conference_providers(input)
+    }
+    fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        (**self).list_device_events(input)
+    }
+    fn list_gateway_groups(
+        &self,
+        input: ListGatewayGroupsRequest,
+    ) -> RusotoFuture<ListGatewayGroupsResponse, ListGatewayGroupsError> {
+        (**self).list_gateway_groups(input)
+    }
+    fn list_gateways(
+        &self,
+        input: ListGatewaysRequest,
+    ) -> RusotoFuture<ListGatewaysResponse, ListGatewaysError> {
+        (**self).list_gateways(input)
+    }
+    fn list_skills(
+        &self,
+        input: ListSkillsRequest,
+    ) -> RusotoFuture<ListSkillsResponse, ListSkillsError> {
+        (**self).list_skills(input)
+    }
+    fn list_skills_store_categories(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> RusotoFuture<ListSkillsStoreCategoriesResponse, ListSkillsStoreCategoriesError> {
+        (**self).list_skills_store_categories(input)
+    }
+    fn list_skills_store_skills_by_category(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> RusotoFuture<ListSkillsStoreSkillsByCategoryResponse, ListSkillsStoreSkillsByCategoryError>
+    {
+        (**self).list_skills_store_skills_by_category(input)
+    }
+    fn list_smart_home_appliances(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> RusotoFuture<ListSmartHomeAppliancesResponse, ListSmartHomeAppliancesError> {
+        (**self).list_smart_home_appliances(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn put_conference_preference(
+        &self,
+        input: PutConferencePreferenceRequest,
+    ) -> RusotoFuture<PutConferencePreferenceResponse, PutConferencePreferenceError> {
+        (**self).put_conference_preference(input)
+    }
+    fn put_invitation_configuration(
+        &self,
+        input: PutInvitationConfigurationRequest,
+    ) -> RusotoFuture<PutInvitationConfigurationResponse, PutInvitationConfigurationError> {
+        (**self).put_invitation_configuration(input)
+    }
+    fn put_room_skill_parameter(
+        &self,
+        input: PutRoomSkillParameterRequest,
+    ) -> RusotoFuture<PutRoomSkillParameterResponse, PutRoomSkillParameterError> {
+        (**self).put_room_skill_parameter(input)
+    }
+    fn put_skill_authorization(
+        &self,
+        input: PutSkillAuthorizationRequest,
+    ) -> RusotoFuture<PutSkillAuthorizationResponse, PutSkillAuthorizationError> {
+        (**self).put_skill_authorization(input)
+    }
+    fn register_avs_device(
+        &self,
+        input: RegisterAVSDeviceRequest,
+    ) -> RusotoFuture<RegisterAVSDeviceResponse, RegisterAVSDeviceError> {
+        (**self).register_avs_device(input)
+    }
+    fn reject_skill(
+        &self,
+        input: RejectSkillRequest,
+    ) -> RusotoFuture<RejectSkillResponse, RejectSkillError> {
+        (**self).reject_skill(input)
+    }
+    fn resolve_room(
+        &self,
+        input: ResolveRoomRequest,
+    ) -> RusotoFuture<ResolveRoomResponse, ResolveRoomError> {
+        (**self).resolve_room(input)
+    }
+    fn revoke_invitation(
+        &self,
+        input: RevokeInvitationRequest,
+    ) -> RusotoFuture<RevokeInvitationResponse, RevokeInvitationError> {
+        (**self).revoke_invitation(input)
+    }
+    fn search_address_books(
+        &self,
+        input: SearchAddressBooksRequest,
+    ) -> RusotoFuture<SearchAddressBooksResponse, SearchAddressBooksError> {
+        (**self).search_address_books(input)
+    }
+    fn search_contacts(
+        &self,
+        input: SearchContactsRequest,
+    ) -> RusotoFuture<SearchContactsResponse, SearchContactsError> {
+        (**self).search_contacts(input)
+    }
+    fn search_devices(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> RusotoFuture<SearchDevicesResponse, SearchDevicesError> {
+        (**self).search_devices(input)
+    }
+    fn search_network_profiles(
+        &self,
+        input: SearchNetworkProfilesRequest,
+    ) -> RusotoFuture<SearchNetworkProfilesResponse, SearchNetworkProfilesError> {
+        (**self).search_network_profiles(input)
+    }
+    fn search_profiles(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> RusotoFuture<SearchProfilesResponse, SearchProfilesError> {
+        (**self).search_profiles(input)
+    }
+    fn search_rooms(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> RusotoFuture<SearchRoomsResponse, SearchRoomsError> {
+        (**self).search_rooms(input)
+    }
+    fn search_skill_groups(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> RusotoFuture<SearchSkillGroupsResponse, SearchSkillGroupsError> {
+        (**self).search_skill_groups(input)
+    }
+    fn search_users(
+        &self,
+        input: SearchUsersRequest,
+    ) -> RusotoFuture<SearchUsersResponse, SearchUsersError> {
+        (**self).search_users(input)
+    }
+    fn send_announcement(
+        &self,
+        input: SendAnnouncementRequest,
+    ) -> RusotoFuture<SendAnnouncementResponse, SendAnnouncementError> {
+        (**self).send_announcement(input)
+    }
+    fn send_invitation(
+        &self,
+        input: SendInvitationRequest,
+    ) -> RusotoFuture<SendInvitationResponse, SendInvitationError> {
+        (**self).send_invitation(input)
+    }
+    fn start_device_sync(
+        &self,
+        input: StartDeviceSyncRequest,
+    ) -> RusotoFuture<StartDeviceSyncResponse, StartDeviceSyncError> {
+        (**self).start_device_sync(input)
+    }
+    fn start_smart_home_appliance_discovery(
+        &self,
+        input: StartSmartHomeApplianceDiscoveryRequest,
+    ) -> RusotoFuture<StartSmartHomeApplianceDiscoveryResponse, StartSmartHomeApplianceDiscoveryError>
+    {
+        (**self).start_smart_home_appliance_discovery(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_address_book(
+        &self,
+        input: UpdateAddressBookRequest,
+    ) -> RusotoFuture<UpdateAddressBookResponse, UpdateAddressBookError> {
+        (**self).update_address_book(input)
+    }
+    fn update_business_report_schedule(
+        &self,
+        input: UpdateBusinessReportScheduleRequest,
+    ) -> RusotoFuture<UpdateBusinessReportScheduleResponse, UpdateBusinessReportScheduleError> {
+        (**self).update_business_report_schedule(input)
+    }
+    fn update_conference_provider(
+        &self,
+        input: UpdateConferenceProviderRequest,
+    ) -> RusotoFuture<UpdateConferenceProviderResponse, UpdateConferenceProviderError> {
+        (**self).update_conference_provider(input)
+    }
+    fn update_contact(
+        &self,
+        input: UpdateContactRequest,
+    ) -> RusotoFuture<UpdateContactResponse, UpdateContactError> {
+        (**self).update_contact(input)
+    }
+    fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> RusotoFuture<UpdateDeviceResponse, UpdateDeviceError> {
+        (**self).update_device(input)
+    }
+    fn update_gateway(
+        &self,
+        input: UpdateGatewayRequest,
+    ) -> RusotoFuture<UpdateGatewayResponse, UpdateGatewayError> {
+        (**self).update_gateway(input)
+    }
+    fn update_gateway_group(
+        &self,
+        input: UpdateGatewayGroupRequest,
+    ) -> RusotoFuture<UpdateGatewayGroupResponse, UpdateGatewayGroupError> {
+        (**self).update_gateway_group(input)
+    }
+    fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> RusotoFuture<UpdateNetworkProfileResponse, UpdateNetworkProfileError> {
+        (**self).update_network_profile(input)
+    }
+    fn update_profile(
+        &self,
+        input: UpdateProfileRequest,
+    ) -> RusotoFuture<UpdateProfileResponse, UpdateProfileError> {
+        (**self).update_profile(input)
+    }
+    fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> RusotoFuture<UpdateRoomResponse, UpdateRoomError> {
+        (**self).update_room(input)
+    }
+    fn update_skill_group(
+        &self,
+        input: UpdateSkillGroupRequest,
+    ) -> RusotoFuture<UpdateSkillGroupResponse, UpdateSkillGroupError> {
+        (**self).update_skill_group(input)
+    }
+}
+impl<T: AlexaForBusiness + ?Sized> AlexaForBusiness for ::std::sync::Arc<T> {
+    fn approve_skill(
+        &self,
+        input: ApproveSkillRequest,
+    ) -> RusotoFuture<ApproveSkillResponse, ApproveSkillError> {
+        (**self).approve_skill(input)
+    }
+    fn associate_contact_with_address_book(
+        &self,
+        input: AssociateContactWithAddressBookRequest,
+    ) -> RusotoFuture<AssociateContactWithAddressBookResponse, AssociateContactWithAddressBookError>
+    {
+        (**self).associate_contact_with_address_book(input)
+    }
+    fn associate_device_with_network_profile(
+        &self,
+        input: AssociateDeviceWithNetworkProfileRequest,
+    ) -> RusotoFuture<
+        AssociateDeviceWithNetworkProfileResponse,
+        AssociateDeviceWithNetworkProfileError,
+    > {
+        (**self).associate_device_with_network_profile(input)
+    }
+    fn associate_device_with_room(
+        &self,
+        input: AssociateDeviceWithRoomRequest,
+    ) -> RusotoFuture<AssociateDeviceWithRoomResponse, AssociateDeviceWithRoomError> {
+        (**self).associate_device_with_room(input)
+    }
+    fn associate_skill_group_with_room(
+        &self,
+        input: AssociateSkillGroupWithRoomRequest,
+    ) -> RusotoFuture<AssociateSkillGroupWithRoomResponse, AssociateSkillGroupWithRoomError> {
+        (**self).associate_skill_group_with_room(input)
+    }
+    fn associate_skill_with_skill_group(
+        &self,
+        input: AssociateSkillWithSkillGroupRequest,
+    ) -> RusotoFuture<AssociateSkillWithSkillGroupResponse, AssociateSkillWithSkillGroupError> {
+        (**self).associate_skill_with_skill_group(input)
+    }
+    fn associate_skill_with_users(
+        &self,
+        input: AssociateSkillWithUsersRequest,
+    ) -> RusotoFuture<AssociateSkillWithUsersResponse, AssociateSkillWithUsersError> {
+        (**self).associate_skill_with_users(input)
+    }
+    fn create_address_book(
+        &self,
+        input: CreateAddressBookRequest,
+    ) -> RusotoFuture<CreateAddressBookResponse, CreateAddressBookError> {
+        (**self).create_address_book(input)
+    }
+    fn create_business_report_schedule(
+        &self,
+        input: CreateBusinessReportScheduleRequest,
+    ) -> RusotoFuture<CreateBusinessReportScheduleResponse, CreateBusinessReportScheduleError> {
+        (**self).create_business_report_schedule(input)
+    }
+    fn create_conference_provider(
+        &self,
+        input: CreateConferenceProviderRequest,
+    ) -> RusotoFuture<CreateConferenceProviderResponse, CreateConferenceProviderError> {
+        (**self).create_conference_provider(input)
+    }
+    fn create_contact(
+        &self,
+        input: CreateContactRequest,
+    ) -> RusotoFuture<CreateContactResponse, CreateContactError> {
+        (**self).create_contact(input)
+    }
+    fn create_gateway_group(
+        &self,
+        input: CreateGatewayGroupRequest,
+    ) -> RusotoFuture<CreateGatewayGroupResponse, CreateGatewayGroupError> {
+        (**self).create_gateway_group(input)
+    }
+    fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> RusotoFuture<CreateNetworkProfileResponse, CreateNetworkProfileError> {
+        (**self).create_network_profile(input)
+    }
+    fn create_profile(
+        &self,
+        input: CreateProfileRequest,
+    ) -> RusotoFuture<CreateProfileResponse, CreateProfileError> {
+        (**self).create_profile(input)
+    }
+    fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> RusotoFuture<CreateRoomResponse, CreateRoomError> {
+        (**self).create_room(input)
+    }
+    fn create_skill_group(
+        &self,
+        input: CreateSkillGroupRequest,
+    ) -> RusotoFuture<CreateSkillGroupResponse, CreateSkillGroupError> {
+        (**self).create_skill_group(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_address_book(
+        &self,
+        input: DeleteAddressBookRequest,
+    ) -> RusotoFuture<DeleteAddressBookResponse, DeleteAddressBookError> {
+        (**self).delete_address_book(input)
+    }
+    fn delete_business_report_schedule(
+        &self,
+        input: DeleteBusinessReportScheduleRequest,
+    ) -> RusotoFuture<DeleteBusinessReportScheduleResponse, DeleteBusinessReportScheduleError> {
+        (**self).delete_business_report_schedule(input)
+    }
+    fn delete_conference_provider(
+        &self,
+        input: DeleteConferenceProviderRequest,
+    ) -> RusotoFuture<DeleteConferenceProviderResponse, DeleteConferenceProviderError> {
+        (**self).delete_conference_provider(input)
+    }
+    fn delete_contact(
+        &self,
+        input: DeleteContactRequest,
+    ) -> RusotoFuture<DeleteContactResponse, DeleteContactError> {
+        (**self).delete_contact(input)
+    }
+    fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> RusotoFuture<DeleteDeviceResponse, DeleteDeviceError> {
+        (**self).delete_device(input)
+    }
+    fn delete_device_usage_data(
+        &self,
+        input: DeleteDeviceUsageDataRequest,
+    ) -> RusotoFuture<DeleteDeviceUsageDataResponse, DeleteDeviceUsageDataError> {
+        (**self).delete_device_usage_data(input)
+    }
+    fn delete_gateway_group(
+        &self,
+        input: DeleteGatewayGroupRequest,
+    ) -> RusotoFuture<DeleteGatewayGroupResponse, DeleteGatewayGroupError> {
+        (**self).delete_gateway_group(input)
+    }
+    fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> RusotoFuture<DeleteNetworkProfileResponse, DeleteNetworkProfileError> {
+        (**self).delete_network_profile(input)
+    }
+    fn delete_profile(
+        &self,
+        input: DeleteProfileRequest,
+    ) -> RusotoFuture<DeleteProfileResponse, DeleteProfileError> {
+        (**self).delete_profile(input)
+    }
+    fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> RusotoFuture<DeleteRoomResponse, DeleteRoomError> {
+        (**self).delete_room(input)
+    }
+    fn delete_room_skill_parameter(
+        &self,
+        input: DeleteRoomSkillParameterRequest,
+    ) -> RusotoFuture<DeleteRoomSkillParameterResponse, DeleteRoomSkillParameterError> {
+        (**self).delete_room_skill_parameter(input)
+    }
+    fn delete_skill_authorization(
+        &self,
+        input: DeleteSkillAuthorizationRequest,
+    ) -> RusotoFuture<DeleteSkillAuthorizationResponse, DeleteSkillAuthorizationError> {
+        (**self).delete_skill_authorization(input)
+    }
+    fn delete_skill_group(
+        &self,
+        input: DeleteSkillGroupRequest,
+    ) -> RusotoFuture<DeleteSkillGroupResponse, DeleteSkillGroupError> {
+        (**self).delete_skill_group(input)
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn disassociate_contact_from_address_book(
+        &self,
+        input: DisassociateContactFromAddressBookRequest,
+    ) -> RusotoFuture<
+        DisassociateContactFromAddressBookResponse,
+        DisassociateContactFromAddressBookError,
+    > {
+        (**self).disassociate_contact_from_address_book(input)
+    }
+    fn disassociate_device_from_room(
+        &self,
+        input: DisassociateDeviceFromRoomRequest,
+    ) -> RusotoFuture<DisassociateDeviceFromRoomResponse, DisassociateDeviceFromRoomError> {
+        (**self).disassociate_device_from_room(input)
+    }
+    fn disassociate_skill_from_skill_group(
+        &self,
+        input: DisassociateSkillFromSkillGroupRequest,
+    ) -> RusotoFuture<DisassociateSkillFromSkillGroupResponse, DisassociateSkillFromSkillGroupError>
+    {
+        (**self).disassociate_skill_from_skill_group(input)
+    }
+    fn disassociate_skill_from_users(
+        &self,
+        input: DisassociateSkillFromUsersRequest,
+    ) -> RusotoFuture<DisassociateSkillFromUsersResponse, DisassociateSkillFromUsersError> {
+        (**self).disassociate_skill_from_users(input)
+    }
+    fn disassociate_skill_group_from_room(
+        &self,
+        input: DisassociateSkillGroupFromRoomRequest,
+    ) -> RusotoFuture<DisassociateSkillGroupFromRoomResponse, DisassociateSkillGroupFromRoomError>
+    {
+        (**self).disassociate_skill_group_from_room(input)
+    }
+    fn forget_smart_home_appliances(
+        &self,
+        input: ForgetSmartHomeAppliancesRequest,
+    ) -> RusotoFuture<ForgetSmartHomeAppliancesResponse, ForgetSmartHomeAppliancesError> {
+        (**self).forget_smart_home_appliances(input)
+    }
+    fn get_address_book(
+        &self,
+        input: GetAddressBookRequest,
+    ) -> RusotoFuture<GetAddressBookResponse, GetAddressBookError> {
+        (**self).get_address_book(input)
+    }
+    fn get_conference_preference(
+        &self,
+    ) -> RusotoFuture<GetConferencePreferenceResponse, GetConferencePreferenceError> {
+        (**self).get_conference_preference()
+    }
+    fn get_conference_provider(
+        &self,
+        input: GetConferenceProviderRequest,
+    ) -> RusotoFuture<GetConferenceProviderResponse, GetConferenceProviderError> {
+        (**self).get_conference_provider(input)
+    }
+    fn get_contact(
+        &self,
+        input: GetContactRequest,
+    ) -> RusotoFuture<GetContactResponse, GetContactError> {
+        (**self).get_contact(input)
+    }
+    fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
+        (**self).get_device(input)
+    }
+    fn get_gateway(
+        &self,
+        input: GetGatewayRequest,
+    ) -> RusotoFuture<GetGatewayResponse, GetGatewayError> {
+        (**self).get_gateway(input)
+    }
+    fn get_gateway_group(
+        &self,
+        input: GetGatewayGroupRequest,
+    ) -> RusotoFuture<GetGatewayGroupResponse, GetGatewayGroupError> {
+        (**self).get_gateway_group(input)
+    }
+    fn get_invitation_configuration(
+        &self,
+    ) -> RusotoFuture<GetInvitationConfigurationResponse, GetInvitationConfigurationError> {
+        (**self).get_invitation_configuration()
+    }
+    fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> RusotoFuture<GetNetworkProfileResponse, GetNetworkProfileError> {
+        (**self).get_network_profile(input)
+    }
+    fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> RusotoFuture<GetProfileResponse, GetProfileError> {
+        (**self).get_profile(input)
+    }
+    fn get_room(&self, input: GetRoomRequest) -> RusotoFuture<GetRoomResponse, GetRoomError> {
+        (**self).get_room(input)
+    }
+    fn get_room_skill_parameter(
+        &self,
+        input: GetRoomSkillParameterRequest,
+    ) -> RusotoFuture<GetRoomSkillParameterResponse, GetRoomSkillParameterError> {
+        (**self).get_room_skill_parameter(input)
+    }
+    fn get_skill_group(
+        &self,
+        input: GetSkillGroupRequest,
+    ) -> RusotoFuture<GetSkillGroupResponse, GetSkillGroupError> {
+        (**self).get_skill_group(input)
+    }
+    fn list_business_report_schedules(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> RusotoFuture<ListBusinessReportSchedulesResponse, ListBusinessReportSchedulesError> {
+        (**self).list_business_report_schedules(input)
+    }
+    fn list_conference_providers(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> RusotoFuture<ListConferenceProvidersResponse, ListConferenceProvidersError> {
+        (**self).list_conference_providers(input)
+    }
+    fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        (**self).list_device_events(input)
+    }
+    fn list_gateway_groups(
+        &self,
+        input: ListGatewayGroupsRequest,
+    ) -> RusotoFuture<ListGatewayGroupsResponse, ListGatewayGroupsError> {
+        (**self).list_gateway_groups(input)
+    }
+    fn list_gateways(
+        &self,
+        input: ListGatewaysRequest,
+    ) -> RusotoFuture<ListGatewaysResponse, ListGatewaysError> {
+        (**self).list_gateways(input)
+    }
+    fn list_skills(
+        &self,
+        input: ListSkillsRequest,
+    ) -> RusotoFuture<ListSkillsResponse, ListSkillsError> {
+        (**self).list_skills(input)
+    }
+    fn list_skills_store_categories(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> RusotoFuture<ListSkillsStoreCategoriesResponse, ListSkillsStoreCategoriesError> {
+        (**self).list_skills_store_categories(input)
+    }
+    fn list_skills_store_skills_by_category(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> RusotoFuture<ListSkillsStoreSkillsByCategoryResponse, ListSkillsStoreSkillsByCategoryError>
+    {
+        (**self).list_skills_store_skills_by_category(input)
+    }
+    fn list_smart_home_appliances(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> RusotoFuture<ListSmartHomeAppliancesResponse, ListSmartHomeAppliancesError> {
+        (**self).list_smart_home_appliances(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn put_conference_preference(
+        &self,
+        input: PutConferencePreferenceRequest,
+    ) -> RusotoFuture<PutConferencePreferenceResponse, PutConferencePreferenceError> {
+        (**self).put_conference_preference(input)
+    }
+    fn put_invitation_configuration(
+        &self,
+        input: PutInvitationConfigurationRequest,
+    ) -> RusotoFuture<PutInvitationConfigurationResponse, PutInvitationConfigurationError> {
+        (**self).put_invitation_configuration(input)
+    }
+    fn put_room_skill_parameter(
+        &self,
+        input: PutRoomSkillParameterRequest,
+    ) -> RusotoFuture<PutRoomSkillParameterResponse, PutRoomSkillParameterError> {
+        (**self).put_room_skill_parameter(input)
+    }
+    fn put_skill_authorization(
+        &self,
+        input: PutSkillAuthorizationRequest,
+    ) -> RusotoFuture<PutSkillAuthorizationResponse, PutSkillAuthorizationError> {
+        (**self).put_skill_authorization(input)
+    }
+    fn register_avs_device(
+        &self,
+        input: RegisterAVSDeviceRequest,
+    ) -> RusotoFuture<RegisterAVSDeviceResponse, RegisterAVSDeviceError> {
+        (**self).register_avs_device(input)
+    }
+    fn reject_skill(
+        &self,
+        input: RejectSkillRequest,
+    ) -> RusotoFuture<RejectSkillResponse, RejectSkillError> {
+        (**self).reject_skill(input)
+    }
+    fn resolve_room(
+        &self,
+        input: ResolveRoomRequest,
+    ) -> RusotoFuture<ResolveRoomResponse, ResolveRoomError> {
+        (**self).resolve_room(input)
+    }
+    fn revoke_invitation(
+        &self,
+        input: RevokeInvitationRequest,
+    ) -> RusotoFuture<RevokeInvitationResponse, RevokeInvitationError> {
+        (**self).revoke_invitation(input)
+    }
+    fn search_address_books(
+        &self,
+        input: SearchAddressBooksRequest,
+    ) -> RusotoFuture<SearchAddressBooksResponse, SearchAddressBooksError> {
+        (**self).search_address_books(input)
+    }
+    fn search_contacts(
+        &self,
+        input: SearchContactsRequest,
+    ) -> RusotoFuture<SearchContactsResponse, SearchContactsError> {
+        (**self).search_contacts(input)
+    }
+    fn search_devices(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> RusotoFuture<SearchDevicesResponse, SearchDevicesError> {
+        (**self).search_devices(input)
+    }
+    fn search_network_profiles(
+        &self,
+        input: SearchNetworkProfilesRequest,
+    ) -> RusotoFuture<SearchNetworkProfilesResponse, SearchNetworkProfilesError> {
+        (**self).search_network_profiles(input)
+    }
+    fn search_profiles(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> RusotoFuture<SearchProfilesResponse, SearchProfilesError> {
+        (**self).search_profiles(input)
+    }
+    fn search_rooms(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> RusotoFuture<SearchRoomsResponse, SearchRoomsError> {
+        (**self).search_rooms(input)
+    }
+    fn search_skill_groups(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> RusotoFuture<SearchSkillGroupsResponse, SearchSkillGroupsError> {
+        (**self).search_skill_groups(input)
+    }
+    fn search_users(
+        &self,
+        input: SearchUsersRequest,
+    ) -> RusotoFuture<SearchUsersResponse, SearchUsersError> {
+        (**self).search_users(input)
+    }
+    fn send_announcement(
+        &self,
+        input: SendAnnouncementRequest,
+    ) -> RusotoFuture<SendAnnouncementResponse, SendAnnouncementError> {
+        (**self).send_announcement(input)
+    }
+    fn send_invitation(
+        &self,
+        input: SendInvitationRequest,
+    ) -> RusotoFuture<SendInvitationResponse, SendInvitationError> {
+        (**self).send_invitation(input)
+    }
+    fn start_device_sync(
+        &self,
+        input: StartDeviceSyncRequest,
+    ) -> RusotoFuture<StartDeviceSyncResponse, StartDeviceSyncError> {
+        (**self).start_device_sync(input)
+    }
+    fn start_smart_home_appliance_discovery(
+        &self,
+        input: StartSmartHomeApplianceDiscoveryRequest,
+    ) -> RusotoFuture<StartSmartHomeApplianceDiscoveryResponse, StartSmartHomeApplianceDiscoveryError>
+    {
+        (**self).start_smart_home_appliance_discovery(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_address_book(
+        &self,
+        input: UpdateAddressBookRequest,
+    ) -> RusotoFuture<UpdateAddressBookResponse, UpdateAddressBookError> {
+        (**self).update_address_book(input)
+    }
+    fn update_business_report_schedule(
+        &self,
+        input: UpdateBusinessReportScheduleRequest,
+    ) -> RusotoFuture<UpdateBusinessReportScheduleResponse, UpdateBusinessReportScheduleError> {
+        (**self).update_business_report_schedule(input)
+    }
+    fn update_conference_provider(
+        &self,
+        input: UpdateConferenceProviderRequest,
+    ) -> RusotoFuture<UpdateConferenceProviderResponse, UpdateConferenceProviderError> {
+        (**self).update_conference_provider(input)
+    }
+    fn update_contact(
+        &self,
+        input: UpdateContactRequest,
+    ) -> RusotoFuture<UpdateContactResponse, UpdateContactError> {
+        (**self).update_contact(input)
+    }
+    fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> RusotoFuture<UpdateDeviceResponse, UpdateDeviceError> {
+        (**self).update_device(input)
+    }
+    fn update_gateway(
+        &self,
+        input: UpdateGatewayRequest,
+    ) -> RusotoFuture<UpdateGatewayResponse, UpdateGatewayError> {
+        (**self).update_gateway(input)
+    }
+    fn update_gateway_group(
+        &self,
+        input: UpdateGatewayGroupRequest,
+    ) -> RusotoFuture<UpdateGatewayGroupResponse, UpdateGatewayGroupError> {
+        (**self).update_gateway_group(input)
+    }
+    fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> RusotoFuture<UpdateNetworkProfileResponse, UpdateNetworkProfileError> {
+        (**self).update_network_profile(input)
+    }
+    fn update_profile(
+        &self,
+        input: UpdateProfileRequest,
+    ) -> RusotoFuture<UpdateProfileResponse, UpdateProfileError> {
+        (**self).update_profile(input)
+    }
+    fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> RusotoFuture<UpdateRoomResponse, UpdateRoomError> {
+        (**self).update_room(input)
+    }
+    fn update_skill_group(
+        &self,
+        input: UpdateSkillGroupRequest,
+    ) -> RusotoFuture<UpdateSkillGroupResponse, UpdateSkillGroupError> {
+        (**self).update_skill_group(input)
+    }
+}
+/// A client for the Alexa For Business API.
+#[derive(Clone)]
+pub struct AlexaForBusinessClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl AlexaForBusinessClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AlexaForBusinessClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AlexaForBusinessClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> AlexaForBusinessClient {
+        AlexaForBusinessClient { client, region }
+    }
+}
+
+impl AlexaForBusiness for AlexaForBusinessClient {
+    /// <p>Associates a skill with the organization under the customer's AWS account. If a skill is private, the user implicitly accepts access to this skill during enablement.</p>
+    fn approve_skill(
+        &self,
+        input: ApproveSkillRequest,
+    ) -> RusotoFuture<ApproveSkillResponse, ApproveSkillError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ApproveSkill");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ApproveSkill");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ApproveSkillResponse, _>(
+                    response,
+                    "ApproveSkill",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ApproveSkillError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates a contact with a given address book.</p>
+    fn associate_contact_with_address_book(
+        &self,
+        input: AssociateContactWithAddressBookRequest,
+    ) -> RusotoFuture<AssociateContactWithAddressBookResponse, AssociateContactWithAddressBookError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateContactWithAddressBook");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.AssociateContactWithAddressBook",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateContactWithAddressBookResponse, _>(
+                    response,
+                    "AssociateContactWithAddressBook",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateContactWithAddressBookError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates a device with the specified network profile.</p>
+    fn associate_device_with_network_profile(
+        &self,
+        input: AssociateDeviceWithNetworkProfileRequest,
+    ) -> RusotoFuture<
+        AssociateDeviceWithNetworkProfileResponse,
+        AssociateDeviceWithNetworkProfileError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateDeviceWithNetworkProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.AssociateDeviceWithNetworkProfile",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateDeviceWithNetworkProfileResponse, _>(
+                    response,
+                    "AssociateDeviceWithNetworkProfile",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateDeviceWithNetworkProfileError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates a device with a given room. This applies all the settings from the room profile to the device, and all the skills in any skill groups added to that room. This operation requires the device to be online, or else a manual sync is required. </p>
+    fn associate_device_with_room(
+        &self,
+        input: AssociateDeviceWithRoomRequest,
+    ) -> RusotoFuture<AssociateDeviceWithRoomResponse, AssociateDeviceWithRoomError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateDeviceWithRoom");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.AssociateDeviceWithRoom");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateDeviceWithRoomResponse, _>(
+                    response,
+                    "AssociateDeviceWithRoom",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateDeviceWithRoomError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates a skill group with a given room. This enables all skills in the associated skill group on all devices in the room.</p>
+    fn associate_skill_group_with_room(
+        &self,
+        input: AssociateSkillGroupWithRoomRequest,
+    ) -> RusotoFuture<AssociateSkillGroupWithRoomResponse, AssociateSkillGroupWithRoomError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateSkillGroupWithRoom");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.AssociateSkillGroupWithRoom",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateSkillGroupWithRoomResponse, _>(
+                    response,
+                    "AssociateSkillGroupWithRoom",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateSkillGroupWithRoomError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates a skill with a skill group.</p>
+    fn associate_skill_with_skill_group(
+        &self,
+        input: AssociateSkillWithSkillGroupRequest,
+    ) -> RusotoFuture<AssociateSkillWithSkillGroupResponse, AssociateSkillWithSkillGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateSkillWithSkillGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.AssociateSkillWithSkillGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateSkillWithSkillGroupResponse, _>(
+                    response,
+                    "AssociateSkillWithSkillGroup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateSkillWithSkillGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Makes a private skill available for enrolled users to enable on their devices.</p>
+    fn associate_skill_with_users(
+        &self,
+        input: AssociateSkillWithUsersRequest,
+    ) -> RusotoFuture<AssociateSkillWithUsersResponse, AssociateSkillWithUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateSkillWithUsers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.AssociateSkillWithUsers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateSkillWithUsersResponse, _>(
+                    response,
+                    "AssociateSkillWithUsers",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateSkillWithUsersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates an address book with the specified details.</p>
+    fn create_address_book(
+        &self,
+        input: CreateAddressBookRequest,
+    ) -> RusotoFuture<CreateAddressBookResponse, CreateAddressBookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateAddressBook");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.CreateAddressBook");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAddressBookResponse, _>(
+                    response,
+                    "CreateAddressBook",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAddressBookError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a recurring schedule for usage reports to deliver to the specified S3 location with a specified daily or weekly interval.</p>
+    fn create_business_report_schedule(
+        &self,
+        input: CreateBusinessReportScheduleRequest,
+    ) -> RusotoFuture<CreateBusinessReportScheduleResponse, CreateBusinessReportScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateBusinessReportSchedule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.CreateBusinessReportSchedule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateBusinessReportScheduleResponse, _>(
+                    response,
+                    "CreateBusinessReportSchedule",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateBusinessReportScheduleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds a new conference provider under the user's AWS account.</p>
+    fn create_conference_provider(
+        &self,
+        input: CreateConferenceProviderRequest,
+    ) -> RusotoFuture<CreateConferenceProviderResponse, CreateConferenceProviderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateConferenceProvider");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.CreateConferenceProvider");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateConferenceProviderResponse, _>(
+                    response,
+                    "CreateConferenceProvider",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateConferenceProviderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a contact with the specified details.</p>
+    fn create_contact(
+        &self,
+        input: CreateContactRequest,
+    ) -> RusotoFuture<CreateContactResponse, CreateContactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateContact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.CreateContact");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateContactResponse, _>(
+                    response,
+                    "CreateContact",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateContactError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a gateway group with the specified details.</p>
+    fn create_gateway_group(
+        &self,
+        input: CreateGatewayGroupRequest,
+    ) -> RusotoFuture<CreateGatewayGroupResponse, CreateGatewayGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateGatewayGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.CreateGatewayGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateGatewayGroupResponse, _>(
+                    response,
+                    "CreateGatewayGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGatewayGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a network profile with the specified details.</p>
+    fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> RusotoFuture<CreateNetworkProfileResponse, CreateNetworkProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateNetworkProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.CreateNetworkProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateNetworkProfileResponse, _>(
+                    response,
+                    "CreateNetworkProfile",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateNetworkProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new room profile with the specified details.</p>
+    fn create_profile(
+        &self,
+        input: CreateProfileRequest,
+    ) -> RusotoFuture<CreateProfileResponse, CreateProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.CreateProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateProfileResponse, _>(
+                    response,
+                    "CreateProfile",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a room with the specified details.</p>
+    fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> RusotoFuture<CreateRoomResponse, CreateRoomError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateRoom");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.CreateRoom");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRoomResponse, _>(response, "CreateRoom")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateRoomError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a skill group with a specified name and description.</p>
+    fn create_skill_group(
+        &self,
+        input: CreateSkillGroupRequest,
+    ) -> RusotoFuture<CreateSkillGroupResponse, CreateSkillGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateSkillGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.CreateSkillGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSkillGroupResponse, _>(
+                    response,
+                    "CreateSkillGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSkillGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a user.</p>
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.CreateUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserResponse, _>(response, "CreateUser")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an address book by the address book ARN.</p>
+    fn delete_address_book(
+        &self,
+        input: DeleteAddressBookRequest,
+    ) -> RusotoFuture<DeleteAddressBookResponse, DeleteAddressBookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteAddressBook");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteAddressBook");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteAddressBookResponse, _>(
+                    response,
+                    "DeleteAddressBook",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAddressBookError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the recurring report delivery schedule with the specified schedule ARN.</p>
+    fn delete_business_report_schedule(
+        &self,
+        input: DeleteBusinessReportScheduleRequest,
+    ) -> RusotoFuture<DeleteBusinessReportScheduleResponse, DeleteBusinessReportScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteBusinessReportSchedule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.DeleteBusinessReportSchedule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteBusinessReportScheduleResponse, _>(
+                    response,
+                    "DeleteBusinessReportSchedule",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteBusinessReportScheduleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a conference provider.</p>
+    fn delete_conference_provider(
+        &self,
+        input: DeleteConferenceProviderRequest,
+    ) -> RusotoFuture<DeleteConferenceProviderResponse, DeleteConferenceProviderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteConferenceProvider");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteConferenceProvider");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteConferenceProviderResponse, _>(
+                    response,
+                    "DeleteConferenceProvider",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteConferenceProviderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a contact by the contact ARN.</p>
+    fn delete_contact(
+        &self,
+        input: DeleteContactRequest,
+    ) -> RusotoFuture<DeleteContactResponse, DeleteContactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteContact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteContact");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteContactResponse, _>(
+                    response,
+                    "DeleteContact",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteContactError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes a device from Alexa For Business.</p>
+    fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> RusotoFuture<DeleteDeviceResponse, DeleteDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteDevice");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteDevice");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDeviceResponse, _>(
+                    response,
+                    "DeleteDevice",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>When this action is called for a specified shared device, it allows authorized users to delete the device's entire previous history of voice input data and associated response data. This action can be called once every 24 hours for a specific shared device.</p>
+    fn delete_device_usage_data(
+        &self,
+        input: DeleteDeviceUsageDataRequest,
+    ) -> RusotoFuture<DeleteDeviceUsageDataResponse, DeleteDeviceUsageDataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteDeviceUsageData");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteDeviceUsageData");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDeviceUsageDataResponse, _>(
+                    response,
+                    "DeleteDeviceUsageData",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteDeviceUsageDataError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a gateway group.</p>
+    fn delete_gateway_group(
+        &self,
+        input: DeleteGatewayGroupRequest,
+    ) -> RusotoFuture<DeleteGatewayGroupResponse, DeleteGatewayGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteGatewayGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteGatewayGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteGatewayGroupResponse, _>(
+                    response,
+                    "DeleteGatewayGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteGatewayGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a network profile by the network profile ARN.</p>
+    fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> RusotoFuture<DeleteNetworkProfileResponse, DeleteNetworkProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteNetworkProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteNetworkProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteNetworkProfileResponse, _>(
+                    response,
+                    "DeleteNetworkProfile",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteNetworkProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a room profile by the profile ARN.</p>
+    fn delete_profile(
+        &self,
+        input: DeleteProfileRequest,
+    ) -> RusotoFuture<DeleteProfileResponse, DeleteProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteProfileResponse, _>(
+                    response,
+                    "DeleteProfile",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a room by the room ARN.</p>
+    fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> RusotoFuture<DeleteRoomResponse, DeleteRoomError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteRoom");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoom");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRoomResponse, _>(response, "DeleteRoom")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteRoomError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes room skill parameter details by room, skill, and parameter key ID.</p>
+    fn delete_room_skill_parameter(
+        &self,
+        input: DeleteRoomSkillParameterRequest,
+    ) -> RusotoFuture<DeleteRoomSkillParameterResponse, DeleteRoomSkillParameterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteRoomSkillParameter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoomSkillParameter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRoomSkillParameterResponse, _>(
+                    response,
+                    "DeleteRoomSkillParameter",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteRoomSkillParameterError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Unlinks a third-party account from a skill.</p>
+    fn delete_skill_authorization(
+        &self,
+        input: DeleteSkillAuthorizationRequest,
+    ) -> RusotoFuture<DeleteSkillAuthorizationResponse, DeleteSkillAuthorizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteSkillAuthorization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillAuthorization");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSkillAuthorizationResponse, _>(
+                    response,
+                    "DeleteSkillAuthorization",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteSkillAuthorizationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a skill group by skill group ARN.</p>
+    fn delete_skill_group(
+        &self,
+        input: DeleteSkillGroupRequest,
+    ) -> RusotoFuture<DeleteSkillGroupResponse, DeleteSkillGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteSkillGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSkillGroupResponse, _>(
+                    response,
+                    "DeleteSkillGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteSkillGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified user by user ARN and enrollment ARN.</p>
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.DeleteUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteUserResponse, _>(response, "DeleteUser")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disassociates a contact from a given address book.</p>
+    fn disassociate_contact_from_address_book(
+        &self,
+        input: DisassociateContactFromAddressBookRequest,
+    ) -> RusotoFuture<
+        DisassociateContactFromAddressBookResponse,
+        DisassociateContactFromAddressBookError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateContactFromAddressBook");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.DisassociateContactFromAddressBook",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateContactFromAddressBookResponse, _>(
+                    response,
+                    "DisassociateContactFromAddressBook",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateContactFromAddressBookError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates a device from its current room. The device continues to be connected to the Wi-Fi network and is still registered to the account. The device settings and skills are removed from the room.</p>
+    fn disassociate_device_from_room(
+        &self,
+        input: DisassociateDeviceFromRoomRequest,
+    ) -> RusotoFuture<DisassociateDeviceFromRoomResponse, DisassociateDeviceFromRoomError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateDeviceFromRoom");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.DisassociateDeviceFromRoom",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateDeviceFromRoomResponse, _>(
+                    response,
+                    "DisassociateDeviceFromRoom",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateDeviceFromRoomError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates a skill from a skill group.</p>
+    fn disassociate_skill_from_skill_group(
+        &self,
+        input: DisassociateSkillFromSkillGroupRequest,
+    ) -> RusotoFuture<DisassociateSkillFromSkillGroupResponse, DisassociateSkillFromSkillGroupError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateSkillFromSkillGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.DisassociateSkillFromSkillGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateSkillFromSkillGroupResponse, _>(
+                    response,
+                    "DisassociateSkillFromSkillGroup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateSkillFromSkillGroupError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Makes a private skill unavailable for enrolled users and prevents them from enabling it on their devices.</p>
+    fn disassociate_skill_from_users(
+        &self,
+        input: DisassociateSkillFromUsersRequest,
+    ) -> RusotoFuture<DisassociateSkillFromUsersResponse, DisassociateSkillFromUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateSkillFromUsers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.DisassociateSkillFromUsers",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateSkillFromUsersResponse, _>(
+                    response,
+                    "DisassociateSkillFromUsers",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateSkillFromUsersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates a skill group from a specified room. This disables all skills in the skill group on all devices in the room.</p>
+    fn disassociate_skill_group_from_room(
+        &self,
+        input: DisassociateSkillGroupFromRoomRequest,
+    ) -> RusotoFuture<DisassociateSkillGroupFromRoomResponse, DisassociateSkillGroupFromRoomError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateSkillGroupFromRoom");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.DisassociateSkillGroupFromRoom",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateSkillGroupFromRoomResponse, _>(
+                    response,
+                    "DisassociateSkillGroupFromRoom",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateSkillGroupFromRoomError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Forgets smart home appliances associated to a room.</p>
+    fn forget_smart_home_appliances(
+        &self,
+        input: ForgetSmartHomeAppliancesRequest,
+    ) -> RusotoFuture<ForgetSmartHomeAppliancesResponse, ForgetSmartHomeAppliancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ForgetSmartHomeAppliances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ForgetSmartHomeAppliances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ForgetSmartHomeAppliancesResponse, _>(
+                    response,
+                    "ForgetSmartHomeAppliances",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ForgetSmartHomeAppliancesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets address the book details by the address book ARN.</p>
+    fn get_address_book(
+        &self,
+        input: GetAddressBookRequest,
+    ) -> RusotoFuture<GetAddressBookResponse, GetAddressBookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetAddressBook");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetAddressBook");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAddressBookResponse, _>(
+                    response,
+                    "GetAddressBook",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAddressBookError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the existing conference preferences.</p>
+    fn get_conference_preference(
+        &self,
+    ) -> RusotoFuture<GetConferencePreferenceResponse, GetConferencePreferenceError> {
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetConferencePreference");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetConferencePreference");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetConferencePreferenceResponse, _>(
+                    response,
+                    "GetConferencePreference",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetConferencePreferenceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets details about a specific conference provider.</p>
+    fn get_conference_provider(
+        &self,
+        input: GetConferenceProviderRequest,
+    ) -> RusotoFuture<GetConferenceProviderResponse, GetConferenceProviderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetConferenceProvider");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetConferenceProvider");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetConferenceProviderResponse, _>(
+                    response,
+                    "GetConferenceProvider",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetConferenceProviderError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the contact details by the contact ARN.</p>
+    fn get_contact(
+        &self,
+        input: GetContactRequest,
+    ) -> RusotoFuture<GetContactResponse, GetContactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetContact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetContact");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetContactResponse, _>(response, "GetContact")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetContactError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the details of a device by device ARN.</p>
+    fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetDevice");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetDevice");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDeviceResponse, _>(response, "GetDevice")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the details of a gateway.</p>
+    fn get_gateway(
+        &self,
+        input: GetGatewayRequest,
+    ) -> RusotoFuture<GetGatewayResponse, GetGatewayError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetGateway");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetGateway");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetGatewayResponse, _>(response, "GetGateway")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetGatewayError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the details of a gateway group.</p>
+    fn get_gateway_group(
+        &self,
+        input: GetGatewayGroupRequest,
+    ) -> RusotoFuture<GetGatewayGroupResponse, GetGatewayGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetGatewayGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetGatewayGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetGatewayGroupResponse, _>(
+                    response,
+                    "GetGatewayGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetGatewayGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the configured values for the user enrollment invitation email template.</p>
+    fn get_invitation_configuration(
+        &self,
+    ) -> RusotoFuture<GetInvitationConfigurationResponse, GetInvitationConfigurationError> {
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetInvitationConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.GetInvitationConfiguration",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInvitationConfigurationResponse, _>(
+                    response,
+                    "GetInvitationConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetInvitationConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the network profile details by the network profile ARN.</p>
+    fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> RusotoFuture<GetNetworkProfileResponse, GetNetworkProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetNetworkProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetNetworkProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetNetworkProfileResponse, _>(
+                    response,
+                    "GetNetworkProfile",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetNetworkProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the details of a room profile by profile ARN.</p>
+    fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> RusotoFuture<GetProfileResponse, GetProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetProfileResponse, _>(response, "GetProfile")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets room details by room ARN.</p>
+    fn get_room(&self, input: GetRoomRequest) -> RusotoFuture<GetRoomResponse, GetRoomError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetRoom");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetRoom");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRoomResponse, _>(response, "GetRoom")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRoomError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets room skill parameter details by room, skill, and parameter key ARN.</p>
+    fn get_room_skill_parameter(
+        &self,
+        input: GetRoomSkillParameterRequest,
+    ) -> RusotoFuture<GetRoomSkillParameterResponse, GetRoomSkillParameterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetRoomSkillParameter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetRoomSkillParameter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRoomSkillParameterResponse, _>(
+                    response,
+                    "GetRoomSkillParameter",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetRoomSkillParameterError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets skill group details by skill group ARN.</p>
+    fn get_skill_group(
+        &self,
+        input: GetSkillGroupRequest,
+    ) -> RusotoFuture<GetSkillGroupResponse, GetSkillGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetSkillGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.GetSkillGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetSkillGroupResponse, _>(
+                    response,
+                    "GetSkillGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSkillGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the details of the schedules that a user configured.</p>
+    fn list_business_report_schedules(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> RusotoFuture<ListBusinessReportSchedulesResponse, ListBusinessReportSchedulesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListBusinessReportSchedules");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.ListBusinessReportSchedules",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListBusinessReportSchedulesResponse, _>(
+                    response,
+                    "ListBusinessReportSchedules",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListBusinessReportSchedulesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists conference providers under a specific AWS account.</p>
+    fn list_conference_providers(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> RusotoFuture<ListConferenceProvidersResponse, ListConferenceProvidersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListConferenceProviders");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ListConferenceProviders");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListConferenceProvidersResponse, _>(
+                    response,
+                    "ListConferenceProviders",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListConferenceProvidersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the device event history, including device connection status, for up to 30 days.</p>
+    fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListDeviceEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ListDeviceEvents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDeviceEventsResponse, _>(
+                    response,
+                    "ListDeviceEvents",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDeviceEventsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of gateway group summaries. Use GetGatewayGroup to retrieve details of a specific gateway group.</p>
+    fn list_gateway_groups(
+        &self,
+        input: ListGatewayGroupsRequest,
+    ) -> RusotoFuture<ListGatewayGroupsResponse, ListGatewayGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListGatewayGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ListGatewayGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGatewayGroupsResponse, _>(
+                    response,
+                    "ListGatewayGroups",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGatewayGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of gateway summaries. Use GetGateway to retrieve details of a specific gateway. An optional gateway group ARN can be provided to only retrieve gateway summaries of gateways that are associated with that gateway group ARN.</p>
+    fn list_gateways(
+        &self,
+        input: ListGatewaysRequest,
+    ) -> RusotoFuture<ListGatewaysResponse, ListGatewaysError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListGateways");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ListGateways");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGatewaysResponse, _>(
+                    response,
+                    "ListGateways",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGatewaysError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all enabled skills in a specific skill group.</p>
+    fn list_skills(
+        &self,
+        input: ListSkillsRequest,
+    ) -> RusotoFuture<ListSkillsResponse, ListSkillsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSkills");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ListSkills");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSkillsResponse, _>(response, "ListSkills")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListSkillsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all categories in the Alexa skill store.</p>
+    fn list_skills_store_categories(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> RusotoFuture<ListSkillsStoreCategoriesResponse, ListSkillsStoreCategoriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSkillsStoreCategories");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ListSkillsStoreCategories");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSkillsStoreCategoriesResponse, _>(
+                    response,
+                    "ListSkillsStoreCategories",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListSkillsStoreCategoriesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all skills in the Alexa skill store by category.</p>
+    fn list_skills_store_skills_by_category(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> RusotoFuture<ListSkillsStoreSkillsByCategoryResponse, ListSkillsStoreSkillsByCategoryError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSkillsStoreSkillsByCategory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.ListSkillsStoreSkillsByCategory",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSkillsStoreSkillsByCategoryResponse, _>(
+                    response,
+                    "ListSkillsStoreSkillsByCategory",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListSkillsStoreSkillsByCategoryError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all of the smart home appliances associated with a room.</p>
+    fn list_smart_home_appliances(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> RusotoFuture<ListSmartHomeAppliancesResponse, ListSmartHomeAppliancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSmartHomeAppliances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ListSmartHomeAppliances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSmartHomeAppliancesResponse, _>(
+                    response,
+                    "ListSmartHomeAppliances",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListSmartHomeAppliancesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all tags for the specified resource.</p>
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ListTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsResponse, _>(response, "ListTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets the conference preferences on a specific conference provider at the account level.</p>
+    fn put_conference_preference(
+        &self,
+        input: PutConferencePreferenceRequest,
+    ) -> RusotoFuture<PutConferencePreferenceResponse, PutConferencePreferenceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutConferencePreference");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.PutConferencePreference");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutConferencePreferenceResponse, _>(
+                    response,
+                    "PutConferencePreference",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutConferencePreferenceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Configures the email template for the user enrollment invitation with the specified attributes.</p>
+    fn put_invitation_configuration(
+        &self,
+        input: PutInvitationConfigurationRequest,
+    ) -> RusotoFuture<PutInvitationConfigurationResponse, PutInvitationConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutInvitationConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.PutInvitationConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutInvitationConfigurationResponse, _>(
+                    response,
+                    "PutInvitationConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutInvitationConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates room skill parameter details by room, skill, and parameter key ID. Not all skills have a room skill parameter.</p>
+    fn put_room_skill_parameter(
+        &self,
+        input: PutRoomSkillParameterRequest,
+    ) -> RusotoFuture<PutRoomSkillParameterResponse, PutRoomSkillParameterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutRoomSkillParameter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.PutRoomSkillParameter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutRoomSkillParameterResponse, _>(
+                    response,
+                    "PutRoomSkillParameter",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutRoomSkillParameterError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Links a user's account to a third-party skill provider. If this API operation is called by an assumed IAM role, the skill being linked must be a private skill. Also, the skill must be owned by the AWS account that assumed the IAM role.</p>
+    fn put_skill_authorization(
+        &self,
+        input: PutSkillAuthorizationRequest,
+    ) -> RusotoFuture<PutSkillAuthorizationResponse, PutSkillAuthorizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutSkillAuthorization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.PutSkillAuthorization");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutSkillAuthorizationResponse, _>(
+                    response,
+                    "PutSkillAuthorization",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutSkillAuthorizationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers an Alexa-enabled device built by an Original Equipment Manufacturer (OEM) using Alexa Voice Service (AVS).</p>
+    fn register_avs_device(
+        &self,
+        input: RegisterAVSDeviceRequest,
+    ) -> RusotoFuture<RegisterAVSDeviceResponse, RegisterAVSDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("RegisterAVSDevice");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.RegisterAVSDevice");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterAVSDeviceResponse, _>(
+                    response,
+                    "RegisterAVSDevice",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RegisterAVSDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disassociates a skill from the organization under a user's AWS account. If the skill is a private skill, it moves to an AcceptStatus of PENDING. Any private or public skill that is rejected can be added later by calling the ApproveSkill API. </p>
+    fn reject_skill(
+        &self,
+        input: RejectSkillRequest,
+    ) -> RusotoFuture<RejectSkillResponse, RejectSkillError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("RejectSkill");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.RejectSkill");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RejectSkillResponse, _>(
+                    response,
+                    "RejectSkill",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RejectSkillError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Determines the details for the room from which a skill request was invoked. This operation is used by skill developers.</p>
+    fn resolve_room(
+        &self,
+        input: ResolveRoomRequest,
+    ) -> RusotoFuture<ResolveRoomResponse, ResolveRoomError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ResolveRoom");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.ResolveRoom");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ResolveRoomResponse, _>(
+                    response,
+                    "ResolveRoom",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResolveRoomError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Revokes an invitation and invalidates the enrollment URL.</p>
+    fn revoke_invitation(
+        &self,
+        input: RevokeInvitationRequest,
+    ) -> RusotoFuture<RevokeInvitationResponse, RevokeInvitationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("RevokeInvitation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.RevokeInvitation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RevokeInvitationResponse, _>(
+                    response,
+                    "RevokeInvitation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RevokeInvitationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Searches address books and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_address_books(
+        &self,
+        input: SearchAddressBooksRequest,
+    ) -> RusotoFuture<SearchAddressBooksResponse, SearchAddressBooksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchAddressBooks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.SearchAddressBooks");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchAddressBooksResponse, _>(
+                    response,
+                    "SearchAddressBooks",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchAddressBooksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Searches contacts and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_contacts(
+        &self,
+        input: SearchContactsRequest,
+    ) -> RusotoFuture<SearchContactsResponse, SearchContactsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchContacts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.SearchContacts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchContactsResponse, _>(
+                    response,
+                    "SearchContacts",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchContactsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Searches devices and lists the ones that meet a set of filter criteria.</p>
+    fn search_devices(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> RusotoFuture<SearchDevicesResponse, SearchDevicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchDevices");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.SearchDevices");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchDevicesResponse, _>(
+                    response,
+                    "SearchDevices",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchDevicesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Searches network profiles and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_network_profiles(
+        &self,
+        input: SearchNetworkProfilesRequest,
+    ) -> RusotoFuture<SearchNetworkProfilesResponse, SearchNetworkProfilesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchNetworkProfiles");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.SearchNetworkProfiles");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchNetworkProfilesResponse, _>(
+                    response,
+                    "SearchNetworkProfiles",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SearchNetworkProfilesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Searches room profiles and lists the ones that meet a set of filter criteria.</p>
+    fn search_profiles(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> RusotoFuture<SearchProfilesResponse, SearchProfilesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchProfiles");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.SearchProfiles");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchProfilesResponse, _>(
+                    response,
+                    "SearchProfiles",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchProfilesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Searches rooms and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_rooms(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> RusotoFuture<SearchRoomsResponse, SearchRoomsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchRooms");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.SearchRooms");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchRoomsResponse, _>(
+                    response,
+                    "SearchRooms",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchRoomsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Searches skill groups and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_skill_groups(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> RusotoFuture<SearchSkillGroupsResponse, SearchSkillGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchSkillGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.SearchSkillGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchSkillGroupsResponse, _>(
+                    response,
+                    "SearchSkillGroups",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchSkillGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Searches users and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_users(
+        &self,
+        input: SearchUsersRequest,
+    ) -> RusotoFuture<SearchUsersResponse, SearchUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchUsers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.SearchUsers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchUsersResponse, _>(
+                    response,
+                    "SearchUsers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchUsersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Triggers an asynchronous flow to send text, SSML, or audio announcements to rooms that are identified by a search or filter. </p>
+    fn send_announcement(
+        &self,
+        input: SendAnnouncementRequest,
+    ) -> RusotoFuture<SendAnnouncementResponse, SendAnnouncementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SendAnnouncement");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.SendAnnouncement");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SendAnnouncementResponse, _>(
+                    response,
+                    "SendAnnouncement",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SendAnnouncementError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sends an enrollment invitation email with a URL to a user. The URL is valid for 30 days or until you call this operation again, whichever comes first. </p>
+    fn send_invitation(
+        &self,
+        input: SendInvitationRequest,
+    ) -> RusotoFuture<SendInvitationResponse, SendInvitationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SendInvitation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.SendInvitation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SendInvitationResponse, _>(
+                    response,
+                    "SendInvitation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SendInvitationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Resets a device and its account to the known default settings. This clears all information and settings set by previous users in the following ways:</p> <ul> <li> <p>Bluetooth - This unpairs all bluetooth devices paired with your echo device.</p> </li> <li> <p>Volume - This resets the echo device&#39;s volume to the default value.</p> </li> <li> <p>Notifications - This clears all notifications from your echo device.</p> </li> <li> <p>Lists - This clears all to-do items from your echo device.</p> </li> <li> <p>Settings - This internally syncs the room&#39;s profile (if the device is assigned to a room), contacts, address books, delegation access for account linking, and communications (if enabled on the room profile).</p> </li> </ul></p>
+    fn start_device_sync(
+        &self,
+        input: StartDeviceSyncRequest,
+    ) -> RusotoFuture<StartDeviceSyncResponse, StartDeviceSyncError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("StartDeviceSync");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.StartDeviceSync");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartDeviceSyncResponse, _>(
+                    response,
+                    "StartDeviceSync",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartDeviceSyncError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Initiates the discovery of any smart home appliances associated with the room.</p>
+    fn start_smart_home_appliance_discovery(
+        &self,
+        input: StartSmartHomeApplianceDiscoveryRequest,
+    ) -> RusotoFuture<StartSmartHomeApplianceDiscoveryResponse, StartSmartHomeApplianceDiscoveryError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("StartSmartHomeApplianceDiscovery");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.StartSmartHomeApplianceDiscovery",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartSmartHomeApplianceDiscoveryResponse, _>(
+                    response,
+                    "StartSmartHomeApplianceDiscovery",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartSmartHomeApplianceDiscoveryError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds metadata tags to a specified resource.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes metadata tags from a specified resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates address book details by the address book ARN.</p>
+    fn update_address_book(
+        &self,
+        input: UpdateAddressBookRequest,
+    ) -> RusotoFuture<UpdateAddressBookResponse, UpdateAddressBookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateAddressBook");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UpdateAddressBook");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateAddressBookResponse, _>(
+                    response,
+                    "UpdateAddressBook",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAddressBookError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the configuration of the report delivery schedule with the specified schedule ARN.</p>
+    fn update_business_report_schedule(
+        &self,
+        input: UpdateBusinessReportScheduleRequest,
+    ) -> RusotoFuture<UpdateBusinessReportScheduleResponse, UpdateBusinessReportScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateBusinessReportSchedule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AlexaForBusiness.UpdateBusinessReportSchedule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateBusinessReportScheduleResponse, _>(
+                    response,
+                    "UpdateBusinessReportSchedule",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateBusinessReportScheduleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates an existing conference provider's settings.</p>
+    fn update_conference_provider(
+        &self,
+        input: UpdateConferenceProviderRequest,
+    ) -> RusotoFuture<UpdateConferenceProviderResponse, UpdateConferenceProviderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateConferenceProvider");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UpdateConferenceProvider");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateConferenceProviderResponse, _>(
+                    response,
+                    "UpdateConferenceProvider",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateConferenceProviderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the contact details by the contact ARN.</p>
+    fn update_contact(
+        &self,
+        input: UpdateContactRequest,
+    ) -> RusotoFuture<UpdateContactResponse, UpdateContactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateContact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UpdateContact");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateContactResponse, _>(
+                    response,
+                    "UpdateContact",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateContactError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the device name by device ARN.</p>
+    fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> RusotoFuture<UpdateDeviceResponse, UpdateDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateDevice");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UpdateDevice");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDeviceResponse, _>(
+                    response,
+                    "UpdateDevice",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the details of a gateway. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
+    fn update_gateway(
+        &self,
+        input: UpdateGatewayRequest,
+    ) -> RusotoFuture<UpdateGatewayResponse, UpdateGatewayError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateGateway");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UpdateGateway");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateGatewayResponse, _>(
+                    response,
+                    "UpdateGateway",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateGatewayError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the details of a gateway group. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
+    fn update_gateway_group(
+        &self,
+        input: UpdateGatewayGroupRequest,
+    ) -> RusotoFuture<UpdateGatewayGroupResponse, UpdateGatewayGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateGatewayGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UpdateGatewayGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateGatewayGroupResponse, _>(
+                    response,
+                    "UpdateGatewayGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateGatewayGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a network profile by the network profile ARN.</p>
+    fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> RusotoFuture<UpdateNetworkProfileResponse, UpdateNetworkProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateNetworkProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UpdateNetworkProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateNetworkProfileResponse, _>(
+                    response,
+                    "UpdateNetworkProfile",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateNetworkProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing room profile by room profile ARN.</p>
+    fn update_profile(
+        &self,
+        input: UpdateProfileRequest,
+    ) -> RusotoFuture<UpdateProfileResponse, UpdateProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UpdateProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateProfileResponse, _>(
+                    response,
+                    "UpdateProfile",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates room details by room ARN.</p>
+    fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> RusotoFuture<UpdateRoomResponse, UpdateRoomError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateRoom");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UpdateRoom");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateRoomResponse, _>(response, "UpdateRoom")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateRoomError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates skill group details by skill group ARN.</p>
+    fn update_skill_group(
+        &self,
+        input: UpdateSkillGroupRequest,
+    ) -> RusotoFuture<UpdateSkillGroupResponse, UpdateSkillGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateSkillGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AlexaForBusiness.UpdateSkillGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateSkillGroupResponse, _>(
+                    response,
+                    "UpdateSkillGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateSkillGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Alexa For Business API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAlexaForBusiness {
+    approve_skill: Option<
+        Box<
+            dyn Fn(
+                    ApproveSkillRequest,
+                )
+                    -> ::std::result::Result<ApproveSkillResponse, RusotoError<ApproveSkillError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    associate_contact_with_address_book: Option<
+        Box<
+            dyn Fn(
+                    AssociateContactWithAddressBookRequest,
+                ) -> ::std::result::Result<
+                    AssociateContactWithAddressBookResponse,
+                    RusotoError<AssociateContactWithAddressBookError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_device_with_network_profile: Option<
+        Box<
+            dyn Fn(
+                    AssociateDeviceWithNetworkProfileRequest,
+                ) -> ::std::result::Result<
+                    AssociateDeviceWithNetworkProfileResponse,
+                    RusotoError<AssociateDeviceWithNetworkProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_device_with_room: Option<
+        Box<
+            dyn Fn(
+                    AssociateDeviceWithRoomRequest,
+                ) -> ::std::result::Result<
+                    AssociateDeviceWithRoomResponse,
+                    RusotoError<AssociateDeviceWithRoomError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_skill_group_with_room: Option<
+        Box<
+            dyn Fn(
+                    AssociateSkillGroupWithRoomRequest,
+                ) -> ::std::result::Result<
+                    AssociateSkillGroupWithRoomResponse,
+                    RusotoError<AssociateSkillGroupWithRoomError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_skill_with_skill_group: Option<
+        Box<
+            dyn Fn(
+                    AssociateSkillWithSkillGroupRequest,
+                ) -> ::std::result::Result<
+                    AssociateSkillWithSkillGroupResponse,
+                    RusotoError<AssociateSkillWithSkillGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_skill_with_users: Option<
+        Box<
+            dyn Fn(
+                    AssociateSkillWithUsersRequest,
+                ) -> ::std::result::Result<
+                    AssociateSkillWithUsersResponse,
+                    RusotoError<AssociateSkillWithUsersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_address_book: Option<
+        Box<
+            dyn Fn(
+                    CreateAddressBookRequest,
+                ) -> ::std::result::Result<
+                    CreateAddressBookResponse,
+                    RusotoError<CreateAddressBookError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_business_report_schedule: Option<
+        Box<
+            dyn Fn(
+                    CreateBusinessReportScheduleRequest,
+                ) -> ::std::result::Result<
+                    CreateBusinessReportScheduleResponse,
+                    RusotoError<CreateBusinessReportScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_conference_provider: Option<
+        Box<
+            dyn Fn(
+                    CreateConferenceProviderRequest,
+                ) -> ::std::result::Result<
+                    CreateConferenceProviderResponse,
+                    RusotoError<CreateConferenceProviderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_contact: Option<
+        Box<
+            dyn Fn(
+                    CreateContactRequest,
+                )
+                    -> ::std::result::Result<CreateContactResponse, RusotoError<CreateContactError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_gateway_group: Option<
+        Box<
+            dyn Fn(
+                    CreateGatewayGroupRequest,
+                ) -> ::std::result::Result<
+                    CreateGatewayGroupResponse,
+                    RusotoError<CreateGatewayGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_network_profile: Option<
+        Box<
+            dyn Fn(
+                    CreateNetworkProfileRequest,
+                ) -> ::std::result::Result<
+                    CreateNetworkProfileResponse,
+                    RusotoError<CreateNetworkProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_profile: Option<
+        Box<
+            dyn Fn(
+                    CreateProfileRequest,
+                )
+                    -> ::std::result::Result<CreateProfileResponse, RusotoError<CreateProfileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_room: Option<
+        Box<
+            dyn Fn(
+                    CreateRoomRequest,
+                )
+                    -> ::std::result::Result<CreateRoomResponse, RusotoError<CreateRoomError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_skill_group: Option<
+        Box<
+            dyn Fn(
+                    CreateSkillGroupRequest,
+                ) -> ::std::result::Result<
+                    CreateSkillGroupResponse,
+                    RusotoError<CreateSkillGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_user: Option<
+        Box<
+            dyn Fn(
+                    CreateUserRequest,
+                )
+                    -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_address_book: Option<
+        Box<
+            dyn Fn(
+                    DeleteAddressBookRequest,
+                ) -> ::std::result::Result<
+                    DeleteAddressBookResponse,
+                    RusotoError<DeleteAddressBookError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_business_report_schedule: Option<
+        Box<
+            dyn Fn(
+                    DeleteBusinessReportScheduleRequest,
+                ) -> ::std::result::Result<
+                    DeleteBusinessReportScheduleResponse,
+                    RusotoError<DeleteBusinessReportScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_conference_provider: Option<
+        Box<
+            dyn Fn(
+                    DeleteConferenceProviderRequest,
+                ) -> ::std::result::Result<
+                    DeleteConferenceProviderResponse,
+                    RusotoError<DeleteConferenceProviderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_contact: Option<
+        Box<
+            dyn Fn(
+                    DeleteContactRequest,
+                )
+                    -> ::std::result::Result<DeleteContactResponse, RusotoError<DeleteContactError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_device: Option<
+        Box<
+            dyn Fn(
+                    DeleteDeviceRequest,
+                )
+                    -> ::std::result::Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_device_usage_data: Option<
+        Box<
+            dyn Fn(
+                    DeleteDeviceUsageDataRequest,
+                ) -> ::std::result::Result<
+                    DeleteDeviceUsageDataResponse,
+                    RusotoError<DeleteDeviceUsageDataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_gateway_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteGatewayGroupRequest,
+                ) -> ::std::result::Result<
+                    DeleteGatewayGroupResponse,
+                    RusotoError<DeleteGatewayGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_network_profile: Option<
+        Box<
+            dyn Fn(
+                    DeleteNetworkProfileRequest,
+                ) -> ::std::result::Result<
+                    DeleteNetworkProfileResponse,
+                    RusotoError<DeleteNetworkProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_profile: Option<
+        Box<
+            dyn Fn(
+                    DeleteProfileRequest,
+                )
+                    -> ::std::result::Result<DeleteProfileResponse, RusotoError<DeleteProfileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_room: Option<
+        Box<
+            dyn Fn(
+                    DeleteRoomRequest,
+                )
+                    -> ::std::result::Result<DeleteRoomResponse, RusotoError<DeleteRoomError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_room_skill_parameter: Option<
+        Box<
+            dyn Fn(
+                    DeleteRoomSkillParameterRequest,
+                ) -> ::std::result::Result<
+                    DeleteRoomSkillParameterResponse,
+                    RusotoError<DeleteRoomSkillParameterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_skill_authorization: Option<
+        Box<
+            dyn Fn(
+                    DeleteSkillAuthorizationRequest,
+                ) -> ::std::result::Result<
+                    DeleteSkillAuthorizationResponse,
+                    RusotoError<DeleteSkillAuthorizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_skill_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteSkillGroupRequest,
+                ) -> ::std::result::Result<
+                    DeleteSkillGroupResponse,
+                    RusotoError<DeleteSkillGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_user: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserRequest,
+                )
+                    -> ::std::result::Result<DeleteUserResponse, RusotoError<DeleteUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disassociate_contact_from_address_book: Option<
+        Box<
+            dyn Fn(
+                    DisassociateContactFromAddressBookRequest,
+                ) -> ::std::result::Result<
+                    DisassociateContactFromAddressBookResponse,
+                    RusotoError<DisassociateContactFromAddressBookError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_device_from_room: Option<
+        Box<
+            dyn Fn(
+                    DisassociateDeviceFromRoomRequest,
+                ) -> ::std::result::Result<
+                    DisassociateDeviceFromRoomResponse,
+                    RusotoError<DisassociateDeviceFromRoomError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_skill_from_skill_group: Option<
+        Box<
+            dyn Fn(
+                    DisassociateSkillFromSkillGroupRequest,
+                ) -> ::std::result::Result<
+                    DisassociateSkillFromSkillGroupResponse,
+                    RusotoError<DisassociateSkillFromSkillGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_skill_from_users: Option<
+        Box<
+            dyn Fn(
+                    DisassociateSkillFromUsersRequest,
+                ) -> ::std::result::Result<
+                    DisassociateSkillFromUsersResponse,
+                    RusotoError<DisassociateSkillFromUsersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_skill_group_from_room: Option<
+        Box<
+            dyn Fn(
+                    DisassociateSkillGroupFromRoomRequest,
+                ) -> ::std::result::Result<
+                    DisassociateSkillGroupFromRoomResponse,
+                    RusotoError<DisassociateSkillGroupFromRoomError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    forget_smart_home_appliances: Option<
+        Box<
+            dyn Fn(
+                    ForgetSmartHomeAppliancesRequest,
+                ) -> ::std::result::Result<
+                    ForgetSmartHomeAppliancesResponse,
+                    RusotoError<ForgetSmartHomeAppliancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_address_book: Option<
+        Box<
+            dyn Fn(
+                    GetAddressBookRequest,
+                ) -> ::std::result::Result<
+                    GetAddressBookResponse,
+                    RusotoError<GetAddressBookError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_conference_preference: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetConferencePreferenceResponse,
+                    RusotoError<GetConferencePreferenceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_conference_provider: Option<
+        Box<
+            dyn Fn(
+                    GetConferenceProviderRequest,
+                ) -> ::std::result::Result<
+                    GetConferenceProviderResponse,
+                    RusotoError<GetConferenceProviderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_contact: Option<
+        Box<
+            dyn Fn(
+                    GetContactRequest,
+                )
+                    -> ::std::result::Result<GetContactResponse, RusotoError<GetContactError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_device: Option<
+        Box<
+            dyn Fn(
+                    GetDeviceRequest,
+                )
+                    -> ::std::result::Result<GetDeviceResponse, RusotoError<GetDeviceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_gateway: Option<
+        Box<
+            dyn Fn(
+                    GetGatewayRequest,
+                )
+                    -> ::std::result::Result<GetGatewayResponse, RusotoError<GetGatewayError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_gateway_group: Option<
+        Box<
+            dyn Fn(
+                    GetGatewayGroupRequest,
+                ) -> ::std::result::Result<
+                    GetGatewayGroupResponse,
+                    RusotoError<GetGatewayGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_invitation_configuration: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetInvitationConfigurationResponse,
+                    RusotoError<GetInvitationConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_network_profile: Option<
+        Box<
+            dyn Fn(
+                    GetNetworkProfileRequest,
+                ) -> ::std::result::Result<
+                    GetNetworkProfileResponse,
+                    RusotoError<GetNetworkProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_profile: Option<
+        Box<
+            dyn Fn(
+                    GetProfileRequest,
+                )
+                    -> ::std::result::Result<GetProfileResponse, RusotoError<GetProfileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_room: Option<
+        Box<
+            dyn Fn(
+                    GetRoomRequest,
+                )
+                    -> ::std::result::Result<GetRoomResponse, RusotoError<GetRoomError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_room_skill_parameter: Option<
+        Box<
+            dyn Fn(
+                    GetRoomSkillParameterRequest,
+                ) -> ::std::result::Result<
+                    GetRoomSkillParameterResponse,
+                    RusotoError<GetRoomSkillParameterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_skill_group: Option<
+        Box<
+            dyn Fn(
+                    GetSkillGroupRequest,
+                )
+                    -> ::std::result::Result<GetSkillGroupResponse, RusotoError<GetSkillGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_business_report_schedules: Option<
+        Box<
+            dyn Fn(
+                    ListBusinessReportSchedulesRequest,
+                ) -> ::std::result::Result<
+                    ListBusinessReportSchedulesResponse,
+                    RusotoError<ListBusinessReportSchedulesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_conference_providers: Option<
+        Box<
+            dyn Fn(
+                    ListConferenceProvidersRequest,
+                ) -> ::std::result::Result<
+                    ListConferenceProvidersResponse,
+                    RusotoError<ListConferenceProvidersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_device_events: Option<
+        Box<
+            dyn Fn(
+                    ListDeviceEventsRequest,
+                ) -> ::std::result::Result<
+                    ListDeviceEventsResponse,
+                    RusotoError<ListDeviceEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_gateway_groups: Option<
+        Box<
+            dyn Fn(
+                    ListGatewayGroupsRequest,
+                ) -> ::std::result::Result<
+                    ListGatewayGroupsResponse,
+                    RusotoError<ListGatewayGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_gateways: Option<
+        Box<
+            dyn Fn(
+                    ListGatewaysRequest,
+                )
+                    -> ::std::result::Result<ListGatewaysResponse, RusotoError<ListGatewaysError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_skills: Option<
+        Box<
+            dyn Fn(
+                    ListSkillsRequest,
+                )
+                    -> ::std::result::Result<ListSkillsResponse, RusotoError<ListSkillsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_skills_store_categories: Option<
+        Box<
+            dyn Fn(
+                    ListSkillsStoreCategoriesRequest,
+                ) -> ::std::result::Result<
+                    ListSkillsStoreCategoriesResponse,
+                    RusotoError<ListSkillsStoreCategoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_skills_store_skills_by_category: Option<
+        Box<
+            dyn Fn(
+                    ListSkillsStoreSkillsByCategoryRequest,
+                ) -> ::std::result::Result<
+                    ListSkillsStoreSkillsByCategoryResponse,
+                    RusotoError<ListSkillsStoreSkillsByCategoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_smart_home_appliances: Option<
+        Box<
+            dyn Fn(
+                    ListSmartHomeAppliancesRequest,
+                ) -> ::std::result::Result<
+                    ListSmartHomeAppliancesResponse,
+                    RusotoError<ListSmartHomeAppliancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags: Option<
+        Box<
+            dyn Fn(
+                    ListTagsRequest,
+                )
+                    -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_conference_preference: Option<
+        Box<
+            dyn Fn(
+                    PutConferencePreferenceRequest,
+                ) -> ::std::result::Result<
+                    PutConferencePreferenceResponse,
+                    RusotoError<PutConferencePreferenceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_invitation_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutInvitationConfigurationRequest,
+                ) -> ::std::result::Result<
+                    PutInvitationConfigurationResponse,
+                    RusotoError<PutInvitationConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_room_skill_parameter: Option<
+        Box<
+            dyn Fn(
+                    PutRoomSkillParameterRequest,
+                ) -> ::std::result::Result<
+                    PutRoomSkillParameterResponse,
+                    RusotoError<PutRoomSkillParameterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_skill_authorization: Option<
+        Box<
+            dyn Fn(
+                    PutSkillAuthorizationRequest,
+                ) -> ::std::result::Result<
+                    PutSkillAuthorizationResponse,
+                    RusotoError<PutSkillAuthorizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_avs_device: Option<
+        Box<
+            dyn Fn(
+                    RegisterAVSDeviceRequest,
+                ) -> ::std::result::Result<
+                    RegisterAVSDeviceResponse,
+                    RusotoError<RegisterAVSDeviceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reject_skill: Option<
+        Box<
+            dyn Fn(
+                    RejectSkillRequest,
+                )
+                    -> ::std::result::Result<RejectSkillResponse, RusotoError<RejectSkillError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    resolve_room: Option<
+        Box<
+            dyn Fn(
+                    ResolveRoomRequest,
+                )
+                    -> ::std::result::Result<ResolveRoomResponse, RusotoError<ResolveRoomError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    revoke_invitation: Option<
+        Box<
+            dyn Fn(
+                    RevokeInvitationRequest,
+                ) -> ::std::result::Result<
+                    RevokeInvitationResponse,
+                    RusotoError<RevokeInvitationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_address_books: Option<
+        Box<
+            dyn Fn(
+                    SearchAddressBooksRequest,
+                ) -> ::std::result::Result<
+                    SearchAddressBooksResponse,
+                    RusotoError<SearchAddressBooksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_contacts: Option<
+        Box<
+            dyn Fn(
+                    SearchContactsRequest,
+                ) -> ::std::result::Result<
+                    SearchContactsResponse,
+                    RusotoError<SearchContactsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_devices: Option<
+        Box<
+            dyn Fn(
+                    SearchDevicesRequest,
+                )
+                    -> ::std::result::Result<SearchDevicesResponse, RusotoError<SearchDevicesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    search_network_profiles: Option<
+        Box<
+            dyn Fn(
+                    SearchNetworkProfilesRequest,
+                ) -> ::std::result::Result<
+                    SearchNetworkProfilesResponse,
+                    RusotoError<SearchNetworkProfilesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_profiles: Option<
+        Box<
+            dyn Fn(
+                    SearchProfilesRequest,
+                ) -> ::std::result::Result<
+                    SearchProfilesResponse,
+                    RusotoError<SearchProfilesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_rooms: Option<
+        Box<
+            dyn Fn(
+                    SearchRoomsRequest,
+                )
+                    -> ::std::result::Result<SearchRoomsResponse, RusotoError<SearchRoomsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    search_skill_groups: Option<
+        Box<
+            dyn Fn(
+                    SearchSkillGroupsRequest,
+                ) -> ::std::result::Result<
+                    SearchSkillGroupsResponse,
+                    RusotoError<SearchSkillGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_users: Option<
+        Box<
+            dyn Fn(
+                    SearchUsersRequest,
+                )
+                    -> ::std::result::Result<SearchUsersResponse, RusotoError<SearchUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    send_announcement: Option<
+        Box<
+            dyn Fn(
+                    SendAnnouncementRequest,
+                ) -> ::std::result::Result<
+                    SendAnnouncementResponse,
+                    RusotoError<SendAnnouncementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    send_invitation: Option<
+        Box<
+            dyn Fn(
+                    SendInvitationRequest,
+                ) -> ::std::result::Result<
+                    SendInvitationResponse,
+                    RusotoError<SendInvitationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_device_sync: Option<
+        Box<
+            dyn Fn(
+                    StartDeviceSyncRequest,
+                ) -> ::std::result::Result<
+                    StartDeviceSyncResponse,
+                    RusotoError<StartDeviceSyncError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_smart_home_appliance_discovery: Option<
+        Box<
+            dyn Fn(
+                    StartSmartHomeApplianceDiscoveryRequest,
+                ) -> ::std::result::Result<
+                    StartSmartHomeApplianceDiscoveryResponse,
+                    RusotoError<StartSmartHomeApplianceDiscoveryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_address_book: Option<
+        Box<
+            dyn Fn(
+                    UpdateAddressBookRequest,
+                ) -> ::std::result::Result<
+                    UpdateAddressBookResponse,
+                    RusotoError<UpdateAddressBookError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_business_report_schedule: Option<
+        Box<
+            dyn Fn(
+                    UpdateBusinessReportScheduleRequest,
+                ) -> ::std::result::Result<
+                    UpdateBusinessReportScheduleResponse,
+                    RusotoError<UpdateBusinessReportScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_conference_provider: Option<
+        Box<
+            dyn Fn(
+                    UpdateConferenceProviderRequest,
+                ) -> ::std::result::Result<
+                    UpdateConferenceProviderResponse,
+                    RusotoError<UpdateConferenceProviderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_contact: Option<
+        Box<
+            dyn Fn(
+                    UpdateContactRequest,
+                )
+                    -> ::std::result::Result<UpdateContactResponse, RusotoError<UpdateContactError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_device: Option<
+        Box<
+            dyn Fn(
+                    UpdateDeviceRequest,
+                )
+                    -> ::std::result::Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_gateway: Option<
+        Box<
+            dyn Fn(
+                    UpdateGatewayRequest,
+                )
+                    -> ::std::result::Result<UpdateGatewayResponse, RusotoError<UpdateGatewayError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_gateway_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateGatewayGroupRequest,
+                ) -> ::std::result::Result<
+                    UpdateGatewayGroupResponse,
+                    RusotoError<UpdateGatewayGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_network_profile: Option<
+        Box<
+            dyn Fn(
+                    UpdateNetworkProfileRequest,
+                ) -> ::std::result::Result<
+                    UpdateNetworkProfileResponse,
+                    RusotoError<UpdateNetworkProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_profile: Option<
+        Box<
+            dyn Fn(
+                    UpdateProfileRequest,
+                )
+                    -> ::std::result::Result<UpdateProfileResponse, RusotoError<UpdateProfileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_room: Option<
+        Box<
+            dyn Fn(
+                    UpdateRoomRequest,
+                )
+                    -> ::std::result::Result<UpdateRoomResponse, RusotoError<UpdateRoomError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_skill_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateSkillGroupRequest,
+                ) -> ::std::result::Result<
+                    UpdateSkillGroupResponse,
+                    RusotoError<UpdateSkillGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAlexaForBusiness {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAlexaForBusiness {
+        Default::default()
+    }
+
+    /// Answers `approve_skill` with what `handler` returns for its input.
+    pub fn with_approve_skill<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ApproveSkillRequest,
+            )
+                -> ::std::result::Result<ApproveSkillResponse, RusotoError<ApproveSkillError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.approve_skill = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_contact_with_address_book` with what `handler` returns for its input.
+    pub fn with_associate_contact_with_address_book<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                AssociateContactWithAddressBookRequest,
+            ) -> ::std::result::Result<
+                AssociateContactWithAddressBookResponse,
+                RusotoError<AssociateContactWithAddressBookError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_contact_with_address_book = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_device_with_network_profile` with what `handler` returns for its input.
+    pub fn with_associate_device_with_network_profile<F>(
+        mut self,
+        handler: F,
+    ) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                AssociateDeviceWithNetworkProfileRequest,
+            ) -> ::std::result::Result<
+                AssociateDeviceWithNetworkProfileResponse,
+                RusotoError<AssociateDeviceWithNetworkProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_device_with_network_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_device_with_room` with what `handler` returns for its input.
+    pub fn with_associate_device_with_room<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                AssociateDeviceWithRoomRequest,
+            ) -> ::std::result::Result<
+                AssociateDeviceWithRoomResponse,
+                RusotoError<AssociateDeviceWithRoomError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_device_with_room = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_skill_group_with_room` with what `handler` returns for its input.
+    pub fn with_associate_skill_group_with_room<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                AssociateSkillGroupWithRoomRequest,
+            ) -> ::std::result::Result<
+                AssociateSkillGroupWithRoomResponse,
+                RusotoError<AssociateSkillGroupWithRoomError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_skill_group_with_room = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_skill_with_skill_group` with what `handler` returns for its input.
+    pub fn with_associate_skill_with_skill_group<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                AssociateSkillWithSkillGroupRequest,
+            ) -> ::std::result::Result<
+                AssociateSkillWithSkillGroupResponse,
+                RusotoError<AssociateSkillWithSkillGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_skill_with_skill_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_skill_with_users` with what `handler` returns for its input.
+    pub fn with_associate_skill_with_users<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                AssociateSkillWithUsersRequest,
+            ) -> ::std::result::Result<
+                AssociateSkillWithUsersResponse,
+                RusotoError<AssociateSkillWithUsersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_skill_with_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_address_book` with what `handler` returns for its input.
+    pub fn with_create_address_book<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                CreateAddressBookRequest,
+            ) -> ::std::result::Result<
+                CreateAddressBookResponse,
+                RusotoError<CreateAddressBookError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_address_book = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_business_report_schedule` with what `handler` returns for its input.
+    pub fn with_create_business_report_schedule<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                CreateBusinessReportScheduleRequest,
+            ) -> ::std::result::Result<
+                CreateBusinessReportScheduleResponse,
+                RusotoError<CreateBusinessReportScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_business_report_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_conference_provider` with what `handler` returns for its input.
+    pub fn with_create_conference_provider<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                CreateConferenceProviderRequest,
+            ) -> ::std::result::Result<
+                CreateConferenceProviderResponse,
+                RusotoError<CreateConferenceProviderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_conference_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_contact` with what `handler` returns for its input.
+    pub fn with_create_contact<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                CreateContactRequest,
+            )
+                -> ::std::result::Result<CreateContactResponse, RusotoError<CreateContactError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_contact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_gateway_group` with what `handler` returns for its input.
+    pub fn with_create_gateway_group<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                CreateGatewayGroupRequest,
+            ) -> ::std::result::Result<
+                CreateGatewayGroupResponse,
+                RusotoError<CreateGatewayGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_gateway_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_network_profile` with what `handler` returns for its input.
+    pub fn with_create_network_profile<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                CreateNetworkProfileRequest,
+            ) -> ::std::result::Result<
+                CreateNetworkProfileResponse,
+                RusotoError<CreateNetworkProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_network_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_profile` with what `handler` returns for its input.
+    pub fn with_create_profile<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                CreateProfileRequest,
+            )
+                -> ::std::result::Result<CreateProfileResponse, RusotoError<CreateProfileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_room` with what `handler` returns for its input.
+    pub fn with_create_room<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                CreateRoomRequest,
+            )
+                -> ::std::result::Result<CreateRoomResponse, RusotoError<CreateRoomError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_room = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_skill_group` with what `handler` returns for its input.
+    pub fn with_create_skill_group<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                CreateSkillGroupRequest,
+            ) -> ::std::result::Result<
+                CreateSkillGroupResponse,
+                RusotoError<CreateSkillGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_skill_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user` with what `handler` returns for its input.
+    pub fn with_create_user<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                CreateUserRequest,
+            )
+                -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_address_book` with what `handler` returns for its input.
+    pub fn with_delete_address_book<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteAddressBookRequest,
+            ) -> ::std::result::Result<
+                DeleteAddressBookResponse,
+                RusotoError<DeleteAddressBookError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_address_book = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_business_report_schedule` with what `handler` returns for its input.
+    pub fn with_delete_business_report_schedule<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteBusinessReportScheduleRequest,
+            ) -> ::std::result::Result<
+                DeleteBusinessReportScheduleResponse,
+                RusotoError<DeleteBusinessReportScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_business_report_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_conference_provider` with what `handler` returns for its input.
+    pub fn with_delete_conference_provider<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteConferenceProviderRequest,
+            ) -> ::std::result::Result<
+                DeleteConferenceProviderResponse,
+                RusotoError<DeleteConferenceProviderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_conference_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_contact` with what `handler` returns for its input.
+    pub fn with_delete_contact<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteContactRequest,
+            )
+                -> ::std::result::Result<DeleteContactResponse, RusotoError<DeleteContactError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_contact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_device` with what `handler` returns for its input.
+    pub fn with_delete_device<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteDeviceRequest,
+            )
+                -> ::std::result::Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_device_usage_data` with what `handler` returns for its input.
+    pub fn with_delete_device_usage_data<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteDeviceUsageDataRequest,
+            ) -> ::std::result::Result<
+                DeleteDeviceUsageDataResponse,
+                RusotoError<DeleteDeviceUsageDataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_device_usage_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_gateway_group` with what `handler` returns for its input.
+    pub fn with_delete_gateway_group<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteGatewayGroupRequest,
+            ) -> ::std::result::Result<
+                DeleteGatewayGroupResponse,
+                RusotoError<DeleteGatewayGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_gateway_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_network_profile` with what `handler` returns for its input.
+    pub fn with_delete_network_profile<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteNetworkProfileRequest,
+            ) -> ::std::result::Result<
+                DeleteNetworkProfileResponse,
+                RusotoError<DeleteNetworkProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_network_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_profile` with what `handler` returns for its input.
+    pub fn with_delete_profile<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteProfileRequest,
+            )
+                -> ::std::result::Result<DeleteProfileResponse, RusotoError<DeleteProfileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_room` with what `handler` returns for its input.
+    pub fn with_delete_room<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteRoomRequest,
+            )
+                -> ::std::result::Result<DeleteRoomResponse, RusotoError<DeleteRoomError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_room = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_room_skill_parameter` with what `handler` returns for its input.
+    pub fn with_delete_room_skill_parameter<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteRoomSkillParameterRequest,
+            ) -> ::std::result::Result<
+                DeleteRoomSkillParameterResponse,
+                RusotoError<DeleteRoomSkillParameterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_room_skill_parameter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_skill_authorization` with what `handler` returns for its input.
+    pub fn with_delete_skill_authorization<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteSkillAuthorizationRequest,
+            ) -> ::std::result::Result<
+                DeleteSkillAuthorizationResponse,
+                RusotoError<DeleteSkillAuthorizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_skill_authorization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_skill_group` with what `handler` returns for its input.
+    pub fn with_delete_skill_group<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteSkillGroupRequest,
+            ) -> ::std::result::Result<
+                DeleteSkillGroupResponse,
+                RusotoError<DeleteSkillGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_skill_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user` with what `handler` returns for its input.
+    pub fn with_delete_user<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DeleteUserRequest,
+            )
+                -> ::std::result::Result<DeleteUserResponse, RusotoError<DeleteUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_contact_from_address_book` with what `handler` returns for its input.
+    pub fn with_disassociate_contact_from_address_book<F>(
+        mut self,
+        handler: F,
+    ) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DisassociateContactFromAddressBookRequest,
+            ) -> ::std::result::Result<
+                DisassociateContactFromAddressBookResponse,
+                RusotoError<DisassociateContactFromAddressBookError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_contact_from_address_book = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_device_from_room` with what `handler` returns for its input.
+    pub fn with_disassociate_device_from_room<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DisassociateDeviceFromRoomRequest,
+            ) -> ::std::result::Result<
+                DisassociateDeviceFromRoomResponse,
+                RusotoError<DisassociateDeviceFromRoomError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_device_from_room = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_skill_from_skill_group` with what `handler` returns for its input.
+    pub fn with_disassociate_skill_from_skill_group<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DisassociateSkillFromSkillGroupRequest,
+            ) -> ::std::result::Result<
+                DisassociateSkillFromSkillGroupResponse,
+                RusotoError<DisassociateSkillFromSkillGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_skill_from_skill_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_skill_from_users` with what `handler` returns for its input.
+    pub fn with_disassociate_skill_from_users<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DisassociateSkillFromUsersRequest,
+            ) -> ::std::result::Result<
+                DisassociateSkillFromUsersResponse,
+                RusotoError<DisassociateSkillFromUsersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_skill_from_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_skill_group_from_room` with what `handler` returns for its input.
+    pub fn with_disassociate_skill_group_from_room<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                DisassociateSkillGroupFromRoomRequest,
+            ) -> ::std::result::Result<
+                DisassociateSkillGroupFromRoomResponse,
+                RusotoError<DisassociateSkillGroupFromRoomError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_skill_group_from_room = Some(Box::new(handler));
+        self
+    }
+    /// Answers `forget_smart_home_appliances` with what `handler` returns for its input.
+    pub fn with_forget_smart_home_appliances<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ForgetSmartHomeAppliancesRequest,
+            ) -> ::std::result::Result<
+                ForgetSmartHomeAppliancesResponse,
+                RusotoError<ForgetSmartHomeAppliancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.forget_smart_home_appliances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_address_book` with what `handler` returns for its input.
+    pub fn with_get_address_book<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                GetAddressBookRequest,
+            )
+                -> ::std::result::Result<GetAddressBookResponse, RusotoError<GetAddressBookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_address_book = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_conference_preference` with what `handler` returns.
+    pub fn with_get_conference_preference<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn() -> ::std::result::Result<
+                GetConferencePreferenceResponse,
+                RusotoError<GetConferencePreferenceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_conference_preference = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_conference_provider` with what `handler` returns for its input.
+    pub fn with_get_conference_provider<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                GetConferenceProviderRequest,
+            ) -> ::std::result::Result<
+                GetConferenceProviderResponse,
+                RusotoError<GetConferenceProviderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_conference_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_contact` with what `handler` returns for its input.
+    pub fn with_get_contact<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                GetContactRequest,
+            )
+                -> ::std::result::Result<GetContactResponse, RusotoError<GetContactError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_contact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_device` with what `handler` returns for its input.
+    pub fn with_get_device<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                GetDeviceRequest,
+            ) -> ::std::result::Result<GetDeviceResponse, RusotoError<GetDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_gateway` with what `handler` returns for its input.
+    pub fn with_get_gateway<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                GetGatewayRequest,
+            )
+                -> ::std::result::Result<GetGatewayResponse, RusotoError<GetGatewayError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_gateway = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_gateway_group` with what `handler` returns for its input.
+    pub fn with_get_gateway_group<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                GetGatewayGroupRequest,
+            )
+                -> ::std::result::Result<GetGatewayGroupResponse, RusotoError<GetGatewayGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_gateway_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_invitation_configuration` with what `handler` returns.
+    pub fn with_get_invitation_configuration<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn() -> ::std::result::Result<
+                GetInvitationConfigurationResponse,
+                RusotoError<GetInvitationConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_invitation_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_network_profile` with what `handler` returns for its input.
+    pub fn with_get_network_profile<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                GetNetworkProfileRequest,
+            ) -> ::std::result::Result<
+                GetNetworkProfileResponse,
+                RusotoError<GetNetworkProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_network_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_profile` with what `handler` returns for its input.
+    pub fn with_get_profile<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                GetProfileRequest,
+            )
+                -> ::std::result::Result<GetProfileResponse, RusotoError<GetProfileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_room` with what `handler` returns for its input.
+    pub fn with_get_room<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(GetRoomRequest) -> ::std::result::Result<GetRoomResponse, RusotoError<GetRoomError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_room = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_room_skill_parameter` with what `handler` returns for its input.
+    pub fn with_get_room_skill_parameter<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                GetRoomSkillParameterRequest,
+            ) -> ::std::result::Result<
+                GetRoomSkillParameterResponse,
+                RusotoError<GetRoomSkillParameterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_room_skill_parameter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_skill_group` with what `handler` returns for its input.
+    pub fn with_get_skill_group<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                GetSkillGroupRequest,
+            )
+                -> ::std::result::Result<GetSkillGroupResponse, RusotoError<GetSkillGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_skill_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_business_report_schedules` with what `handler` returns for its input.
+    pub fn with_list_business_report_schedules<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ListBusinessReportSchedulesRequest,
+            ) -> ::std::result::Result<
+                ListBusinessReportSchedulesResponse,
+                RusotoError<ListBusinessReportSchedulesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_business_report_schedules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_conference_providers` with what `handler` returns for its input.
+    pub fn with_list_conference_providers<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ListConferenceProvidersRequest,
+            ) -> ::std::result::Result<
+                ListConferenceProvidersResponse,
+                RusotoError<ListConferenceProvidersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_conference_providers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_device_events` with what `handler` returns for its input.
+    pub fn with_list_device_events<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ListDeviceEventsRequest,
+            ) -> ::std::result::Result<
+                ListDeviceEventsResponse,
+                RusotoError<ListDeviceEventsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_device_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_gateway_groups` with what `handler` returns for its input.
+    pub fn with_list_gateway_groups<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ListGatewayGroupsRequest,
+            ) -> ::std::result::Result<
+                ListGatewayGroupsResponse,
+                RusotoError<ListGatewayGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_gateway_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_gateways` with what `handler` returns for its input.
+    pub fn with_list_gateways<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ListGatewaysRequest,
+            )
+                -> ::std::result::Result<ListGatewaysResponse, RusotoError<ListGatewaysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_gateways = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_skills` with what `handler` returns for its input.
+    pub fn with_list_skills<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ListSkillsRequest,
+            )
+                -> ::std::result::Result<ListSkillsResponse, RusotoError<ListSkillsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_skills = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_skills_store_categories` with what `handler` returns for its input.
+    pub fn with_list_skills_store_categories<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ListSkillsStoreCategoriesRequest,
+            ) -> ::std::result::Result<
+                ListSkillsStoreCategoriesResponse,
+                RusotoError<ListSkillsStoreCategoriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_skills_store_categories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_skills_store_skills_by_category` with what `handler` returns for its input.
+    pub fn with_list_skills_store_skills_by_category<F>(
+        mut self,
+        handler: F,
+    ) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ListSkillsStoreSkillsByCategoryRequest,
+            ) -> ::std::result::Result<
+                ListSkillsStoreSkillsByCategoryResponse,
+                RusotoError<ListSkillsStoreSkillsByCategoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_skills_store_skills_by_category = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_smart_home_appliances` with what `handler` returns for its input.
+    pub fn with_list_smart_home_appliances<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ListSmartHomeAppliancesRequest,
+            ) -> ::std::result::Result<
+                ListSmartHomeAppliancesResponse,
+                RusotoError<ListSmartHomeAppliancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_smart_home_appliances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags` with what `handler` returns for its input.
+    pub fn with_list_tags<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ListTagsRequest,
+            ) -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_conference_preference` with what `handler` returns for its input.
+    pub fn with_put_conference_preference<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                PutConferencePreferenceRequest,
+            ) -> ::std::result::Result<
+                PutConferencePreferenceResponse,
+                RusotoError<PutConferencePreferenceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_conference_preference = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_invitation_configuration` with what `handler` returns for its input.
+    pub fn with_put_invitation_configuration<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                PutInvitationConfigurationRequest,
+            ) -> ::std::result::Result<
+                PutInvitationConfigurationResponse,
+                RusotoError<PutInvitationConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_invitation_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_room_skill_parameter` with what `handler` returns for its input.
+    pub fn with_put_room_skill_parameter<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                PutRoomSkillParameterRequest,
+            ) -> ::std::result::Result<
+                PutRoomSkillParameterResponse,
+                RusotoError<PutRoomSkillParameterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_room_skill_parameter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_skill_authorization` with what `handler` returns for its input.
+    pub fn with_put_skill_authorization<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                PutSkillAuthorizationRequest,
+            ) -> ::std::result::Result<
+                PutSkillAuthorizationResponse,
+                RusotoError<PutSkillAuthorizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_skill_authorization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_avs_device` with what `handler` returns for its input.
+    pub fn with_register_avs_device<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                RegisterAVSDeviceRequest,
+            ) -> ::std::result::Result<
+                RegisterAVSDeviceResponse,
+                RusotoError<RegisterAVSDeviceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_avs_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reject_skill` with what `handler` returns for its input.
+    pub fn with_reject_skill<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                RejectSkillRequest,
+            )
+                -> ::std::result::Result<RejectSkillResponse, RusotoError<RejectSkillError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reject_skill = Some(Box::new(handler));
+        self
+    }
+    /// Answers `resolve_room` with what `handler` returns for its input.
+    pub fn with_resolve_room<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                ResolveRoomRequest,
+            )
+                -> ::std::result::Result<ResolveRoomResponse, RusotoError<ResolveRoomError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resolve_room = Some(Box::new(handler));
+        self
+    }
+    /// Answers `revoke_invitation` with what `handler` returns for its input.
+    pub fn with_revoke_invitation<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                RevokeInvitationRequest,
+            ) -> ::std::result::Result<
+                RevokeInvitationResponse,
+                RusotoError<RevokeInvitationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.revoke_invitation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_address_books` with what `handler` returns for its input.
+    pub fn with_search_address_books<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                SearchAddressBooksRequest,
+            ) -> ::std::result::Result<
+                SearchAddressBooksResponse,
+                RusotoError<SearchAddressBooksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.search_address_books = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_contacts` with what `handler` returns for its input.
+    pub fn with_search_contacts<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                SearchContactsRequest,
+            )
+                -> ::std::result::Result<SearchContactsResponse, RusotoError<SearchContactsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search_contacts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_devices` with what `handler` returns for its input.
+    pub fn with_search_devices<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                SearchDevicesRequest,
+            )
+                -> ::std::result::Result<SearchDevicesResponse, RusotoError<SearchDevicesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search_devices = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_network_profiles` with what `handler` returns for its input.
+    pub fn with_search_network_profiles<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                SearchNetworkProfilesRequest,
+            ) -> ::std::result::Result<
+                SearchNetworkProfilesResponse,
+                RusotoError<SearchNetworkProfilesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.search_network_profiles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_profiles` with what `handler` returns for its input.
+    pub fn with_search_profiles<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                SearchProfilesRequest,
+            )
+                -> ::std::result::Result<SearchProfilesResponse, RusotoError<SearchProfilesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search_profiles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_rooms` with what `handler` returns for its input.
+    pub fn with_search_rooms<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                SearchRoomsRequest,
+            )
+                -> ::std::result::Result<SearchRoomsResponse, RusotoError<SearchRoomsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search_rooms = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_skill_groups` with what `handler` returns for its input.
+    pub fn with_search_skill_groups<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                SearchSkillGroupsRequest,
+            ) -> ::std::result::Result<
+                SearchSkillGroupsResponse,
+                RusotoError<SearchSkillGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.search_skill_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_users` with what `handler` returns for its input.
+    pub fn with_search_users<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                SearchUsersRequest,
+            )
+                -> ::std::result::Result<SearchUsersResponse, RusotoError<SearchUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_announcement` with what `handler` returns for its input.
+    pub fn with_send_announcement<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                SendAnnouncementRequest,
+            ) -> ::std::result::Result<
+                SendAnnouncementResponse,
+                RusotoError<SendAnnouncementError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.send_announcement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_invitation` with what `handler` returns for its input.
+    pub fn with_send_invitation<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                SendInvitationRequest,
+            )
+                -> ::std::result::Result<SendInvitationResponse, RusotoError<SendInvitationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_invitation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_device_sync` with what `handler` returns for its input.
+    pub fn with_start_device_sync<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                StartDeviceSyncRequest,
+            )
+                -> ::std::result::Result<StartDeviceSyncResponse, RusotoError<StartDeviceSyncError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_device_sync = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_smart_home_appliance_discovery` with what `handler` returns for its input.
+    pub fn with_start_smart_home_appliance_discovery<F>(
+        mut self,
+        handler: F,
+    ) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                StartSmartHomeApplianceDiscoveryRequest,
+            ) -> ::std::result::Result<
+                StartSmartHomeApplianceDiscoveryResponse,
+                RusotoError<StartSmartHomeApplianceDiscoveryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_smart_home_appliance_discovery = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_address_book` with what `handler` returns for its input.
+    pub fn with_update_address_book<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateAddressBookRequest,
+            ) -> ::std::result::Result<
+                UpdateAddressBookResponse,
+                RusotoError<UpdateAddressBookError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_address_book = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_business_report_schedule` with what `handler` returns for its input.
+    pub fn with_update_business_report_schedule<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateBusinessReportScheduleRequest,
+            ) -> ::std::result::Result<
+                UpdateBusinessReportScheduleResponse,
+                RusotoError<UpdateBusinessReportScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_business_report_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_conference_provider` with what `handler` returns for its input.
+    pub fn with_update_conference_provider<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateConferenceProviderRequest,
+            ) -> ::std::result::Result<
+                UpdateConferenceProviderResponse,
+                RusotoError<UpdateConferenceProviderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_conference_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_contact` with what `handler` returns for its input.
+    pub fn with_update_contact<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateContactRequest,
+            )
+                -> ::std::result::Result<UpdateContactResponse, RusotoError<UpdateContactError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_contact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_device` with what `handler` returns for its input.
+    pub fn with_update_device<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateDeviceRequest,
+            )
+                -> ::std::result::Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_gateway` with what `handler` returns for its input.
+    pub fn with_update_gateway<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateGatewayRequest,
+            )
+                -> ::std::result::Result<UpdateGatewayResponse, RusotoError<UpdateGatewayError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_gateway = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_gateway_group` with what `handler` returns for its input.
+    pub fn with_update_gateway_group<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateGatewayGroupRequest,
+            ) -> ::std::result::Result<
+                UpdateGatewayGroupResponse,
+                RusotoError<UpdateGatewayGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_gateway_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_network_profile` with what `handler` returns for its input.
+    pub fn with_update_network_profile<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateNetworkProfileRequest,
+            ) -> ::std::result::Result<
+                UpdateNetworkProfileResponse,
+                RusotoError<UpdateNetworkProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_network_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_profile` with what `handler` returns for its input.
+    pub fn with_update_profile<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateProfileRequest,
+            )
+                -> ::std::result::Result<UpdateProfileResponse, RusotoError<UpdateProfileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_room` with what `handler` returns for its input.
+    pub fn with_update_room<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateRoomRequest,
+            )
+                -> ::std::result::Result<UpdateRoomResponse, RusotoError<UpdateRoomError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_room = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_skill_group` with what `handler` returns for its input.
+    pub fn with_update_skill_group<F>(mut self, handler: F) -> MockAlexaForBusiness
+    where
+        F: Fn(
+                UpdateSkillGroupRequest,
+            ) -> ::std::result::Result<
+                UpdateSkillGroupResponse,
+                RusotoError<UpdateSkillGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_skill_group = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl AlexaForBusiness for MockAlexaForBusiness {
+    fn approve_skill(
+        &self,
+        input: ApproveSkillRequest,
+    ) -> RusotoFuture<ApproveSkillResponse, ApproveSkillError> {
+        match self.approve_skill {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for approve_skill"),
+        }
+    }
+    fn associate_contact_with_address_book(
+        &self,
+        input: AssociateContactWithAddressBookRequest,
+    ) -> RusotoFuture<AssociateContactWithAddressBookResponse, AssociateContactWithAddressBookError>
+    {
+        match self.associate_contact_with_address_book {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockAlexaForBusiness has no handler for associate_contact_with_address_book"
+            ),
+        }
+    }
+    fn associate_device_with_network_profile(
+        &self,
+        input: AssociateDeviceWithNetworkProfileRequest,
+    ) -> RusotoFuture<
+        AssociateDeviceWithNetworkProfileResponse,
+        AssociateDeviceWithNetworkProfileError,
+    > {
+        match self.associate_device_with_network_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockAlexaForBusiness has no handler for associate_device_with_network_profile"
+            ),
+        }
+    }
+    fn associate_device_with_room(
+        &self,
+        input: AssociateDeviceWithRoomRequest,
+    ) -> RusotoFuture<AssociateDeviceWithRoomResponse, AssociateDeviceWithRoomError> {
+        match self.associate_device_with_room {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for associate_device_with_room"),
+        }
+    }
+    fn associate_skill_group_with_room(
+        &self,
+        input: AssociateSkillGroupWithRoomRequest,
+    ) -> RusotoFuture<AssociateSkillGroupWithRoomResponse, AssociateSkillGroupWithRoomError> {
+        match self.associate_skill_group_with_room {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAlexaForBusiness has no handler for associate_skill_group_with_room")
+            }
+        }
+    }
+    fn associate_skill_with_skill_group(
+        &self,
+        input: AssociateSkillWithSkillGroupRequest,
+    ) -> RusotoFuture<AssociateSkillWithSkillGroupResponse, AssociateSkillWithSkillGroupError> {
+        match self.associate_skill_with_skill_group {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAlexaForBusiness has no handler for associate_skill_with_skill_group")
+            }
+        }
+    }
+    fn associate_skill_with_users(
+        &self,
+        input: AssociateSkillWithUsersRequest,
+    ) -> RusotoFuture<AssociateSkillWithUsersResponse, AssociateSkillWithUsersError> {
+        match self.associate_skill_with_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for associate_skill_with_users"),
+        }
+    }
+    fn create_address_book(
+        &self,
+        input: CreateAddressBookRequest,
+    ) -> RusotoFuture<CreateAddressBookResponse, CreateAddressBookError> {
+        match self.create_address_book {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for create_address_book"),
+        }
+    }
+    fn create_business_report_schedule(
+        &self,
+        input: CreateBusinessReportScheduleRequest,
+    ) -> RusotoFuture<CreateBusinessReportScheduleResponse, CreateBusinessReportScheduleError> {
+        match self.create_business_report_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAlexaForBusiness has no handler for create_business_report_schedule")
+            }
+        }
+    }
+    fn create_conference_provider(
+        &self,
+        input: CreateConferenceProviderRequest,
+    ) -> RusotoFuture<CreateConferenceProviderResponse, CreateConferenceProviderError> {
+        match self.create_conference_provider {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for create_conference_provider"),
+        }
+    }
+    fn create_contact(
+        &self,
+        input: CreateContactRequest,
+    ) -> RusotoFuture<CreateContactResponse, CreateContactError> {
+        match self.create_contact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for create_contact"),
+        }
+    }
+    fn create_gateway_group(
+        &self,
+        input: CreateGatewayGroupRequest,
+    ) -> RusotoFuture<CreateGatewayGroupResponse, CreateGatewayGroupError> {
+        match self.create_gateway_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for create_gateway_group"),
+        }
+    }
+    fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> RusotoFuture<CreateNetworkProfileResponse, CreateNetworkProfileError> {
+        match self.create_network_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for create_network_profile"),
+        }
+    }
+    fn create_profile(
+        &self,
+        input: CreateProfileRequest,
+    ) -> RusotoFuture<CreateProfileResponse, CreateProfileError> {
+        match self.create_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for create_profile"),
+        }
+    }
+    fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> RusotoFuture<CreateRoomResponse, CreateRoomError> {
+        match self.create_room {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for create_room"),
+        }
+    }
+    fn create_skill_group(
+        &self,
+        input: CreateSkillGroupRequest,
+    ) -> RusotoFuture<CreateSkillGroupResponse, CreateSkillGroupError> {
+        match self.create_skill_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for create_skill_group"),
+        }
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        match self.create_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for create_user"),
+        }
+    }
+    fn delete_address_book(
+        &self,
+        input: DeleteAddressBookRequest,
+    ) -> RusotoFuture<DeleteAddressBookResponse, DeleteAddressBookError> {
+        match self.delete_address_book {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_address_book"),
+        }
+    }
+    fn delete_business_report_schedule(
+        &self,
+        input: DeleteBusinessReportScheduleRequest,
+    ) -> RusotoFuture<DeleteBusinessReportScheduleResponse, DeleteBusinessReportScheduleError> {
+        match self.delete_business_report_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAlexaForBusiness has no handler for delete_business_report_schedule")
+            }
+        }
+    }
+    fn delete_conference_provider(
+        &self,
+        input: DeleteConferenceProviderRequest,
+    ) -> RusotoFuture<DeleteConferenceProviderResponse, DeleteConferenceProviderError> {
+        match self.delete_conference_provider {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_conference_provider"),
+        }
+    }
+    fn delete_contact(
+        &self,
+        input: DeleteContactRequest,
+    ) -> RusotoFuture<DeleteContactResponse, DeleteContactError> {
+        match self.delete_contact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_contact"),
+        }
+    }
+    fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> RusotoFuture<DeleteDeviceResponse, DeleteDeviceError> {
+        match self.delete_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_device"),
+        }
+    }
+    fn delete_device_usage_data(
+        &self,
+        input: DeleteDeviceUsageDataRequest,
+    ) -> RusotoFuture<DeleteDeviceUsageDataResponse, DeleteDeviceUsageDataError> {
+        match self.delete_device_usage_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_device_usage_data"),
+        }
+    }
+    fn delete_gateway_group(
+        &self,
+        input: DeleteGatewayGroupRequest,
+    ) -> RusotoFuture<DeleteGatewayGroupResponse, DeleteGatewayGroupError> {
+        match self.delete_gateway_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_gateway_group"),
+        }
+    }
+    fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> RusotoFuture<DeleteNetworkProfileResponse, DeleteNetworkProfileError> {
+        match self.delete_network_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_network_profile"),
+        }
+    }
+    fn delete_profile(
+        &self,
+        input: DeleteProfileRequest,
+    ) -> RusotoFuture<DeleteProfileResponse, DeleteProfileError> {
+        match self.delete_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_profile"),
+        }
+    }
+    fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> RusotoFuture<DeleteRoomResponse, DeleteRoomError> {
+        match self.delete_room {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_room"),
+        }
+    }
+    fn delete_room_skill_parameter(
+        &self,
+        input: DeleteRoomSkillParameterRequest,
+    ) -> RusotoFuture<DeleteRoomSkillParameterResponse, DeleteRoomSkillParameterError> {
+        match self.delete_room_skill_parameter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_room_skill_parameter"),
+        }
+    }
+    fn delete_skill_authorization(
+        &self,
+        input: DeleteSkillAuthorizationRequest,
+    ) -> RusotoFuture<DeleteSkillAuthorizationResponse, DeleteSkillAuthorizationError> {
+        match self.delete_skill_authorization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_skill_authorization"),
+        }
+    }
+    fn delete_skill_group(
+        &self,
+        input: DeleteSkillGroupRequest,
+    ) -> RusotoFuture<DeleteSkillGroupResponse, DeleteSkillGroupError> {
+        match self.delete_skill_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_skill_group"),
+        }
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        match self.delete_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for delete_user"),
+        }
+    }
+    fn disassociate_contact_from_address_book(
+        &self,
+        input: DisassociateContactFromAddressBookRequest,
+    ) -> RusotoFuture<
+        DisassociateContactFromAddressBookResponse,
+        DisassociateContactFromAddressBookError,
+    > {
+        match self.disassociate_contact_from_address_book {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockAlexaForBusiness has no handler for disassociate_contact_from_address_book"
+            ),
+        }
+    }
+    fn disassociate_device_from_room(
+        &self,
+        input: DisassociateDeviceFromRoomRequest,
+    ) -> RusotoFuture<DisassociateDeviceFromRoomResponse, DisassociateDeviceFromRoomError> {
+        match self.disassociate_device_from_room {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for disassociate_device_from_room"),
+        }
+    }
+    fn disassociate_skill_from_skill_group(
+        &self,
+        input: DisassociateSkillFromSkillGroupRequest,
+    ) -> RusotoFuture<DisassociateSkillFromSkillGroupResponse, DisassociateSkillFromSkillGroupError>
+    {
+        match self.disassociate_skill_from_skill_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockAlexaForBusiness has no handler for disassociate_skill_from_skill_group"
+            ),
+        }
+    }
+    fn disassociate_skill_from_users(
+        &self,
+        input: DisassociateSkillFromUsersRequest,
+    ) -> RusotoFuture<DisassociateSkillFromUsersResponse, DisassociateSkillFromUsersError> {
+        match self.disassociate_skill_from_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for disassociate_skill_from_users"),
+        }
+    }
+    fn disassociate_skill_group_from_room(
+        &self,
+        input: DisassociateSkillGroupFromRoomRequest,
+    ) -> RusotoFuture<DisassociateSkillGroupFromRoomResponse, DisassociateSkillGroupFromRoomError>
+    {
+        match self.disassociate_skill_group_from_room {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAlexaForBusiness has no handler for disassociate_skill_group_from_room")
+            }
+        }
+    }
+    fn forget_smart_home_appliances(
+        &self,
+        input: ForgetSmartHomeAppliancesRequest,
+    ) -> RusotoFuture<ForgetSmartHomeAppliancesResponse, ForgetSmartHomeAppliancesError> {
+        match self.forget_smart_home_appliances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for forget_smart_home_appliances"),
+        }
+    }
+    fn get_address_book(
+        &self,
+        input: GetAddressBookRequest,
+    ) -> RusotoFuture<GetAddressBookResponse, GetAddressBookError> {
+        match self.get_address_book {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_address_book"),
+        }
+    }
+    fn get_conference_preference(
+        &self,
+    ) -> RusotoFuture<GetConferencePreferenceResponse, GetConferencePreferenceError> {
+        match self.get_conference_preference {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_conference_preference"),
+        }
+    }
+    fn get_conference_provider(
+        &self,
+        input: GetConferenceProviderRequest,
+    ) -> RusotoFuture<GetConferenceProviderResponse, GetConferenceProviderError> {
+        match self.get_conference_provider {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_conference_provider"),
+        }
+    }
+    fn get_contact(
+        &self,
+        input: GetContactRequest,
+    ) -> RusotoFuture<GetContactResponse, GetContactError> {
+        match self.get_contact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_contact"),
+        }
+    }
+    fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
+        match self.get_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_device"),
+        }
+    }
+    fn get_gateway(
+        &self,
+        input: GetGatewayRequest,
+    ) -> RusotoFuture<GetGatewayResponse, GetGatewayError> {
+        match self.get_gateway {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_gateway"),
+        }
+    }
+    fn get_gateway_group(
+        &self,
+        input: GetGatewayGroupRequest,
+    ) -> RusotoFuture<GetGatewayGroupResponse, GetGatewayGroupError> {
+        match self.get_gateway_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_gateway_group"),
+        }
+    }
+    fn get_invitation_configuration(
+        &self,
+    ) -> RusotoFuture<GetInvitationConfigurationResponse, GetInvitationConfigurationError> {
+        match self.get_invitation_configuration {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_invitation_configuration"),
+        }
+    }
+    fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> RusotoFuture<GetNetworkProfileResponse, GetNetworkProfileError> {
+        match self.get_network_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_network_profile"),
+        }
+    }
+    fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> RusotoFuture<GetProfileResponse, GetProfileError> {
+        match self.get_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_profile"),
+        }
+    }
+    fn get_room(&self, input: GetRoomRequest) -> RusotoFuture<GetRoomResponse, GetRoomError> {
+        match self.get_room {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_room"),
+        }
+    }
+    fn get_room_skill_parameter(
+        &self,
+        input: GetRoomSkillParameterRequest,
+    ) -> RusotoFuture<GetRoomSkillParameterResponse, GetRoomSkillParameterError> {
+        match self.get_room_skill_parameter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_room_skill_parameter"),
+        }
+    }
+    fn get_skill_group(
+        &self,
+        input: GetSkillGroupRequest,
+    ) -> RusotoFuture<GetSkillGroupResponse, GetSkillGroupError> {
+        match self.get_skill_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for get_skill_group"),
+        }
+    }
+    fn list_business_report_schedules(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> RusotoFuture<ListBusinessReportSchedulesResponse, ListBusinessReportSchedulesError> {
+        match self.list_business_report_schedules {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAlexaForBusiness has no handler for list_business_report_schedules")
+            }
+        }
+    }
+    fn list_conference_providers(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> RusotoFuture<ListConferenceProvidersResponse, ListConferenceProvidersError> {
+        match self.list_conference_providers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for list_conference_providers"),
+        }
+    }
+    fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        match self.list_device_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for list_device_events"),
+        }
+    }
+    fn list_gateway_groups(
+        &self,
+        input: ListGatewayGroupsRequest,
+    ) -> RusotoFuture<ListGatewayGroupsResponse, ListGatewayGroupsError> {
+        match self.list_gateway_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for list_gateway_groups"),
+        }
+    }
+    fn list_gateways(
+        &self,
+        input: ListGatewaysRequest,
+    ) -> RusotoFuture<ListGatewaysResponse, ListGatewaysError> {
+        match self.list_gateways {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for list_gateways"),
+        }
+    }
+    fn list_skills(
+        &self,
+        input: ListSkillsRequest,
+    ) -> RusotoFuture<ListSkillsResponse, ListSkillsError> {
+        match self.list_skills {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for list_skills"),
+        }
+    }
+    fn list_skills_store_categories(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> RusotoFuture<ListSkillsStoreCategoriesResponse, ListSkillsStoreCategoriesError> {
+        match self.list_skills_store_categories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for list_skills_store_categories"),
+        }
+    }
+    fn list_skills_store_skills_by_category(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> RusotoFuture<ListSkillsStoreSkillsByCategoryResponse, ListSkillsStoreSkillsByCategoryError>
+    {
+        match self.list_skills_store_skills_by_category {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockAlexaForBusiness has no handler for list_skills_store_skills_by_category"
+            ),
+        }
+    }
+    fn list_smart_home_appliances(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> RusotoFuture<ListSmartHomeAppliancesResponse, ListSmartHomeAppliancesError> {
+        match self.list_smart_home_appliances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for list_smart_home_appliances"),
+        }
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        match self.list_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for list_tags"),
+        }
+    }
+    fn put_conference_preference(
+        &self,
+        input: PutConferencePreferenceRequest,
+    ) -> RusotoFuture<PutConferencePreferenceResponse, PutConferencePreferenceError> {
+        match self.put_conference_preference {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for put_conference_preference"),
+        }
+    }
+    fn put_invitation_configuration(
+        &self,
+        input: PutInvitationConfigurationRequest,
+    ) -> RusotoFuture<PutInvitationConfigurationResponse, PutInvitationConfigurationError> {
+        match self.put_invitation_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for put_invitation_configuration"),
+        }
+    }
+    fn put_room_skill_parameter(
+        &self,
+        input: PutRoomSkillParameterRequest,
+    ) -> RusotoFuture<PutRoomSkillParameterResponse, PutRoomSkillParameterError> {
+        match self.put_room_skill_parameter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for put_room_skill_parameter"),
+        }
+    }
+    fn put_skill_authorization(
+        &self,
+        input: PutSkillAuthorizationRequest,
+    ) -> RusotoFuture<PutSkillAuthorizationResponse, PutSkillAuthorizationError> {
+        match self.put_skill_authorization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for put_skill_authorization"),
+        }
+    }
+    fn register_avs_device(
+        &self,
+        input: RegisterAVSDeviceRequest,
+    ) -> RusotoFuture<RegisterAVSDeviceResponse, RegisterAVSDeviceError> {
+        match self.register_avs_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for register_avs_device"),
+        }
+    }
+    fn reject_skill(
+        &self,
+        input: RejectSkillRequest,
+    ) -> RusotoFuture<RejectSkillResponse, RejectSkillError> {
+        match self.reject_skill {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for reject_skill"),
+        }
+    }
+    fn resolve_room(
+        &self,
+        input: ResolveRoomRequest,
+    ) -> RusotoFuture<ResolveRoomResponse, ResolveRoomError> {
+        match self.resolve_room {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for resolve_room"),
+        }
+    }
+    fn revoke_invitation(
+        &self,
+        input: RevokeInvitationRequest,
+    ) -> RusotoFuture<RevokeInvitationResponse, RevokeInvitationError> {
+        match self.revoke_invitation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for revoke_invitation"),
+        }
+    }
+    fn search_address_books(
+        &self,
+        input: SearchAddressBooksRequest,
+    ) -> RusotoFuture<SearchAddressBooksResponse, SearchAddressBooksError> {
+        match self.search_address_books {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for search_address_books"),
+        }
+    }
+    fn search_contacts(
+        &self,
+        input: SearchContactsRequest,
+    ) -> RusotoFuture<SearchContactsResponse, SearchContactsError> {
+        match self.search_contacts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for search_contacts"),
+        }
+    }
+    fn search_devices(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> RusotoFuture<SearchDevicesResponse, SearchDevicesError> {
+        match self.search_devices {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for search_devices"),
+        }
+    }
+    fn search_network_profiles(
+        &self,
+        input: SearchNetworkProfilesRequest,
+    ) -> RusotoFuture<SearchNetworkProfilesResponse, SearchNetworkProfilesError> {
+        match self.search_network_profiles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for search_network_profiles"),
+        }
+    }
+    fn search_profiles(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> RusotoFuture<SearchProfilesResponse, SearchProfilesError> {
+        match self.search_profiles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for search_profiles"),
+        }
+    }
+    fn search_rooms(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> RusotoFuture<SearchRoomsResponse, SearchRoomsError> {
+        match self.search_rooms {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for search_rooms"),
+        }
+    }
+    fn search_skill_groups(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> RusotoFuture<SearchSkillGroupsResponse, SearchSkillGroupsError> {
+        match self.search_skill_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for search_skill_groups"),
+        }
+    }
+    fn search_users(
+        &self,
+        input: SearchUsersRequest,
+    ) -> RusotoFuture<SearchUsersResponse, SearchUsersError> {
+        match self.search_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for search_users"),
+        }
+    }
+    fn send_announcement(
+        &self,
+        input: SendAnnouncementRequest,
+    ) -> RusotoFuture<SendAnnouncementResponse, SendAnnouncementError> {
+        match self.send_announcement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for send_announcement"),
+        }
+    }
+    fn send_invitation(
+        &self,
+        input: SendInvitationRequest,
+    ) -> RusotoFuture<SendInvitationResponse, SendInvitationError> {
+        match self.send_invitation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for send_invitation"),
+        }
+    }
+    fn start_device_sync(
+        &self,
+        input: StartDeviceSyncRequest,
+    ) -> RusotoFuture<StartDeviceSyncResponse, StartDeviceSyncError> {
+        match self.start_device_sync {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for start_device_sync"),
+        }
+    }
+    fn start_smart_home_appliance_discovery(
+        &self,
+        input: StartSmartHomeApplianceDiscoveryRequest,
+    ) -> RusotoFuture<StartSmartHomeApplianceDiscoveryResponse, StartSmartHomeApplianceDiscoveryError>
+    {
+        match self.start_smart_home_appliance_discovery {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockAlexaForBusiness has no handler for start_smart_home_appliance_discovery"
+            ),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Updates address book details by the address book ARN.</p>
     fn update_address_book(
         &self,
         input: UpdateAddressBookRequest,
     ) -> RusotoFuture<UpdateAddressBookResponse, UpdateAddressBookError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UpdateAddressBook");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAddressBookResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAddressBookError::from_response(response))),
-                )
-            }
-        })
+        match self.update_address_book {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for update_address_book"),
+        }
     }
-
-    /// <p>Updates the configuration of the report delivery schedule with the specified schedule ARN.</p>
     fn update_business_report_schedule(
         &self,
         input: UpdateBusinessReportScheduleRequest,
     ) -> RusotoFuture<UpdateBusinessReportScheduleResponse, UpdateBusinessReportScheduleError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AlexaForBusiness.UpdateBusinessReportSchedule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateBusinessReportScheduleResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateBusinessReportScheduleError::from_response(response))
-                }))
+        match self.update_business_report_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAlexaForBusiness has no handler for update_business_report_schedule")
             }
-        })
+        }
     }
-
-    /// <p>Updates an existing conference provider's settings.</p>
     fn update_conference_provider(
         &self,
         input: UpdateConferenceProviderRequest,
     ) -> RusotoFuture<UpdateConferenceProviderResponse, UpdateConferenceProviderError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UpdateConferenceProvider");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateConferenceProviderResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateConferenceProviderError::from_response(response))
-                }))
-            }
-        })
+        match self.update_conference_provider {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for update_conference_provider"),
+        }
     }
-
-    /// <p>Updates the contact details by the contact ARN.</p>
     fn update_contact(
         &self,
         input: UpdateContactRequest,
     ) -> RusotoFuture<UpdateContactResponse, UpdateContactError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UpdateContact");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateContactResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateContactError::from_response(response))),
-                )
-            }
-        })
+        match self.update_contact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for update_contact"),
+        }
     }
-
-    /// <p>Updates the device name by device ARN.</p>
     fn update_device(
         &self,
         input: UpdateDeviceRequest,
     ) -> RusotoFuture<UpdateDeviceResponse, UpdateDeviceError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UpdateDevice");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDeviceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDeviceError::from_response(response))),
-                )
-            }
-        })
+        match self.update_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for update_device"),
+        }
     }
-
-    /// <p>Updates the details of a gateway. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
     fn update_gateway(
         &self,
         input: UpdateGatewayRequest,
     ) -> RusotoFuture<UpdateGatewayResponse, UpdateGatewayError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UpdateGateway");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGatewayResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateGatewayError::from_response(response))),
-                )
-            }
-        })
+        match self.update_gateway {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for update_gateway"),
+        }
     }
-
-    /// <p>Updates the details of a gateway group. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
     fn update_gateway_group(
         &self,
         input: UpdateGatewayGroupRequest,
     ) -> RusotoFuture<UpdateGatewayGroupResponse, UpdateGatewayGroupError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UpdateGatewayGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGatewayGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateGatewayGroupError::from_response(response))),
-                )
-            }
-        })
+        match self.update_gateway_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for update_gateway_group"),
+        }
     }
-
-    /// <p>Updates a network profile by the network profile ARN.</p>
     fn update_network_profile(
         &self,
         input: UpdateNetworkProfileRequest,
     ) -> RusotoFuture<UpdateNetworkProfileResponse, UpdateNetworkProfileError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UpdateNetworkProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateNetworkProfileResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateNetworkProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_network_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for update_network_profile"),
+        }
     }
-
-    /// <p>Updates an existing room profile by room profile ARN.</p>
     fn update_profile(
         &self,
         input: UpdateProfileRequest,
     ) -> RusotoFuture<UpdateProfileResponse, UpdateProfileError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UpdateProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateProfileResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateProfileError::from_response(response))),
-                )
-            }
-        })
+        match self.update_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for update_profile"),
+        }
     }
-
-    /// <p>Updates room details by room ARN.</p>
     fn update_room(
         &self,
         input: UpdateRoomRequest,
     ) -> RusotoFuture<UpdateRoomResponse, UpdateRoomError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UpdateRoom");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRoomResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateRoomError::from_response(response))),
-                )
-            }
-        })
+        match self.update_room {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for update_room"),
+        }
     }
-
-    /// <p>Updates skill group details by skill group ARN.</p>
     fn update_skill_group(
         &self,
         input: UpdateSkillGroupRequest,
     ) -> RusotoFuture<UpdateSkillGroupResponse, UpdateSkillGroupError> {
-        let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AlexaForBusiness.UpdateSkillGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSkillGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateSkillGroupError::from_response(response))),
-                )
-            }
-        })
+        match self.update_skill_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAlexaForBusiness has no handler for update_skill_group"),
+        }
     }
 }
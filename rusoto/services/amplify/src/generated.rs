@@ -23,10 +23,11 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p> Amplify App represents different branches of a repository for building, deploying, and hosting. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct App {
     /// <p> ARN for the Amplify App. </p>
     #[serde(rename = "appArn")]
@@ -135,9 +136,52 @@ pub struct AutoBranchCreationConfig {
     pub stage: Option<String>,
 }
 
+impl Validate for AutoBranchCreationConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.basic_auth_credentials {
+            errors.check_length(
+                &field_path(path, "basic_auth_credentials"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+            errors.check_pattern(
+                &field_path(path, "basic_auth_credentials"),
+                &value,
+                "(?s).*",
+            );
+        }
+        if let Some(ref value) = self.build_spec {
+            errors.check_length(
+                &field_path(path, "build_spec"),
+                value.chars().count(),
+                Some(1),
+                Some(25000),
+            );
+            errors.check_pattern(&field_path(path, "build_spec"), &value, "(?s).+");
+        }
+        if let Some(ref value) = self.environment_variables {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "environment_variables"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(5500));
+                errors.check_pattern(&item_path, &item, "(?s).*");
+            }
+        }
+        if let Some(ref value) = self.framework {
+            errors.check_length(
+                &field_path(path, "framework"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "framework"), &value, "(?s).*");
+        }
+    }
+}
+
 /// <p> Branch for an Amplify App, which maps to a 3rd party repository branch. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Branch {
     /// <p> Id of the active job for a branch, part of an Amplify App. </p>
     #[serde(rename = "activeJobId")]
@@ -211,6 +255,7 @@ pub struct Branch {
 
 /// <p> Request structure used to create Apps in Amplify. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAppRequest {
     /// <p> Personal Access token for 3rd party source control system for an Amplify App, used to create webhook and read-only deploy key. Token is not stored. </p>
     #[serde(rename = "accessToken")]
@@ -281,8 +326,122 @@ pub struct CreateAppRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateAppRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.access_token {
+            errors.check_length(
+                &field_path(path, "access_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "access_token"), &value, "(?s).+");
+        }
+        if let Some(ref value) = self.auto_branch_creation_config {
+            value.validate_at(&field_path(path, "auto_branch_creation_config"), errors);
+        }
+        if let Some(ref value) = self.auto_branch_creation_patterns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "auto_branch_creation_patterns"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+                errors.check_pattern(&item_path, &item, "(?s).+");
+            }
+        }
+        if let Some(ref value) = self.basic_auth_credentials {
+            errors.check_length(
+                &field_path(path, "basic_auth_credentials"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+            errors.check_pattern(
+                &field_path(path, "basic_auth_credentials"),
+                &value,
+                "(?s).*",
+            );
+        }
+        if let Some(ref value) = self.build_spec {
+            errors.check_length(
+                &field_path(path, "build_spec"),
+                value.chars().count(),
+                Some(1),
+                Some(25000),
+            );
+            errors.check_pattern(&field_path(path, "build_spec"), &value, "(?s).+");
+        }
+        if let Some(ref value) = self.custom_rules {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "custom_rules"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.environment_variables {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "environment_variables"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(5500));
+                errors.check_pattern(&item_path, &item, "(?s).*");
+            }
+        }
+        if let Some(ref value) = self.iam_service_role_arn {
+            errors.check_length(
+                &field_path(path, "iam_service_role_arn"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "iam_service_role_arn"), &value, "(?s).*");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "(?s).+");
+        if let Some(ref value) = self.oauth_token {
+            errors.check_length(
+                &field_path(path, "oauth_token"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "oauth_token"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.repository {
+            errors.check_length(
+                &field_path(path, "repository"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "repository"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAppResult {
     #[serde(rename = "app")]
     pub app: App,
@@ -290,6 +449,7 @@ pub struct CreateAppResult {
 
 /// <p> Request structure for a branch create request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBranchRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -347,9 +507,105 @@ pub struct CreateBranchRequest {
     pub ttl: Option<String>,
 }
 
+impl Validate for CreateBranchRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        if let Some(ref value) = self.basic_auth_credentials {
+            errors.check_length(
+                &field_path(path, "basic_auth_credentials"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+            errors.check_pattern(
+                &field_path(path, "basic_auth_credentials"),
+                &value,
+                "(?s).*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        if let Some(ref value) = self.build_spec {
+            errors.check_length(
+                &field_path(path, "build_spec"),
+                value.chars().count(),
+                Some(1),
+                Some(25000),
+            );
+            errors.check_pattern(&field_path(path, "build_spec"), &value, "(?s).+");
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "display_name"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.environment_variables {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "environment_variables"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(5500));
+                errors.check_pattern(&item_path, &item, "(?s).*");
+            }
+        }
+        if let Some(ref value) = self.framework {
+            errors.check_length(
+                &field_path(path, "framework"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "framework"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+        if let Some(ref value) = self.ttl {
+            errors.check_length(
+                &field_path(path, "ttl"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+            errors.check_pattern(&field_path(path, "ttl"), &value, "\\d*");
+        }
+    }
+}
+
 /// <p> Result structure for create branch request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBranchResult {
     /// <p> Branch structure for an Amplify App. </p>
     #[serde(rename = "branch")]
@@ -358,6 +614,7 @@ pub struct CreateBranchResult {
 
 /// <p> Request structure for create a new deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeploymentRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -371,9 +628,39 @@ pub struct CreateDeploymentRequest {
     pub file_map: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateDeploymentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        if let Some(ref value) = self.file_map {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "file_map"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(32));
+                errors.check_pattern(&item_path, &item, "(?s).*");
+            }
+        }
+    }
+}
+
 /// <p> Result structure for create a new deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDeploymentResult {
     /// <p> When the fileMap argument is provided in the request, the fileUploadUrls will contain a map of file names to upload url. </p>
     #[serde(rename = "fileUploadUrls")]
@@ -389,6 +676,7 @@ pub struct CreateDeploymentResult {
 
 /// <p> Request structure for create Domain Association request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDomainAssociationRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -405,9 +693,38 @@ pub struct CreateDomainAssociationRequest {
     pub sub_domain_settings: Vec<SubDomainSetting>,
 }
 
+impl Validate for CreateDomainAssociationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "domain_name"), &self.domain_name, "^(((?!-)[A-Za-z0-9-]{0,62}[A-Za-z0-9])\\.)+((?!-)[A-Za-z0-9-]{1,62}[A-Za-z0-9])(\\.)?$");
+        errors.check_length(
+            &field_path(path, "sub_domain_settings"),
+            self.sub_domain_settings.len(),
+            None,
+            Some(500),
+        );
+        for (i, item) in self.sub_domain_settings.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "sub_domain_settings"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p> Result structure for the create Domain Association request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDomainAssociationResult {
     /// <p> Domain Association structure. </p>
     #[serde(rename = "domainAssociation")]
@@ -416,6 +733,7 @@ pub struct CreateDomainAssociationResult {
 
 /// <p> Request structure for create webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateWebhookRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -429,9 +747,41 @@ pub struct CreateWebhookRequest {
     pub description: Option<String>,
 }
 
+impl Validate for CreateWebhookRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "(?s).*");
+        }
+    }
+}
+
 /// <p> Result structure for the create webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateWebhookResult {
     /// <p> Webhook structure. </p>
     #[serde(rename = "webhook")]
@@ -457,17 +807,67 @@ pub struct CustomRule {
     pub target: String,
 }
 
+impl Validate for CustomRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.condition {
+            errors.check_length(
+                &field_path(path, "condition"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "condition"), &value, "(?s).*");
+        }
+        errors.check_length(
+            &field_path(path, "source"),
+            self.source.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "source"), &self.source, "(?s).+");
+        if let Some(ref value) = self.status {
+            errors.check_length(
+                &field_path(path, "status"),
+                value.chars().count(),
+                Some(3),
+                Some(7),
+            );
+            errors.check_pattern(&field_path(path, "status"), &value, ".{3,7}");
+        }
+        errors.check_length(
+            &field_path(path, "target"),
+            self.target.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "target"), &self.target, "(?s).+");
+    }
+}
+
 /// <p> Request structure for an Amplify App delete request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAppRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
     pub app_id: String,
 }
 
+impl Validate for DeleteAppRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+    }
+}
+
 /// <p> Result structure for an Amplify App delete request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteAppResult {
     #[serde(rename = "app")]
     pub app: App,
@@ -475,6 +875,7 @@ pub struct DeleteAppResult {
 
 /// <p> Request structure for delete branch request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBranchRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -484,9 +885,32 @@ pub struct DeleteBranchRequest {
     pub branch_name: String,
 }
 
+impl Validate for DeleteBranchRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+    }
+}
+
 /// <p> Result structure for delete branch request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBranchResult {
     /// <p> Branch structure for an Amplify App. </p>
     #[serde(rename = "branch")]
@@ -495,6 +919,7 @@ pub struct DeleteBranchResult {
 
 /// <p> Request structure for the delete Domain Association request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDomainAssociationRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -504,8 +929,27 @@ pub struct DeleteDomainAssociationRequest {
     pub domain_name: String,
 }
 
+impl Validate for DeleteDomainAssociationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "domain_name"), &self.domain_name, "^(((?!-)[A-Za-z0-9-]{0,62}[A-Za-z0-9])\\.)+((?!-)[A-Za-z0-9-]{1,62}[A-Za-z0-9])(\\.)?$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDomainAssociationResult {
     #[serde(rename = "domainAssociation")]
     pub domain_association: DomainAssociation,
@@ -513,6 +957,7 @@ pub struct DeleteDomainAssociationResult {
 
 /// <p> Request structure for delete job request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteJobRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -525,9 +970,39 @@ pub struct DeleteJobRequest {
     pub job_id: String,
 }
 
+impl Validate for DeleteJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "job_id"), &self.job_id, "[0-9]+");
+    }
+}
+
 /// <p> Result structure for the delete job request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteJobResult {
     #[serde(rename = "jobSummary")]
     pub job_summary: JobSummary,
@@ -535,15 +1010,28 @@ pub struct DeleteJobResult {
 
 /// <p> Request structure for the delete webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteWebhookRequest {
     /// <p> Unique Id for a webhook. </p>
     #[serde(rename = "webhookId")]
     pub webhook_id: String,
 }
 
+impl Validate for DeleteWebhookRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "webhook_id"),
+            self.webhook_id.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "webhook_id"), &self.webhook_id, "(?s).*");
+    }
+}
+
 /// <p> Result structure for the delete webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteWebhookResult {
     /// <p> Webhook structure. </p>
     #[serde(rename = "webhook")]
@@ -552,7 +1040,7 @@ pub struct DeleteWebhookResult {
 
 /// <p> Structure for Domain Association, which associates a custom domain with an Amplify App. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainAssociation {
     /// <p> DNS Record for certificate verification. </p>
     #[serde(rename = "certificateVerificationDNSRecord")]
@@ -580,14 +1068,27 @@ pub struct DomainAssociation {
 
 /// <p> Request structure for get App request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAppRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
     pub app_id: String,
 }
 
+impl Validate for GetAppRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAppResult {
     #[serde(rename = "app")]
     pub app: App,
@@ -595,6 +1096,7 @@ pub struct GetAppResult {
 
 /// <p> Result structure for get branch request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBranchRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -604,8 +1106,31 @@ pub struct GetBranchRequest {
     pub branch_name: String,
 }
 
+impl Validate for GetBranchRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBranchResult {
     #[serde(rename = "branch")]
     pub branch: Branch,
@@ -613,6 +1138,7 @@ pub struct GetBranchResult {
 
 /// <p> Request structure for the get Domain Association request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDomainAssociationRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -622,9 +1148,28 @@ pub struct GetDomainAssociationRequest {
     pub domain_name: String,
 }
 
+impl Validate for GetDomainAssociationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "domain_name"), &self.domain_name, "^(((?!-)[A-Za-z0-9-]{0,62}[A-Za-z0-9])\\.)+((?!-)[A-Za-z0-9-]{1,62}[A-Za-z0-9])(\\.)?$");
+    }
+}
+
 /// <p> Result structure for the get Domain Association request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDomainAssociationResult {
     /// <p> Domain Association structure. </p>
     #[serde(rename = "domainAssociation")]
@@ -633,6 +1178,7 @@ pub struct GetDomainAssociationResult {
 
 /// <p> Request structure for get job request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -645,8 +1191,38 @@ pub struct GetJobRequest {
     pub job_id: String,
 }
 
+impl Validate for GetJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "job_id"), &self.job_id, "[0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobResult {
     #[serde(rename = "job")]
     pub job: Job,
@@ -654,15 +1230,28 @@ pub struct GetJobResult {
 
 /// <p> Request structure for the get webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetWebhookRequest {
     /// <p> Unique Id for a webhook. </p>
     #[serde(rename = "webhookId")]
     pub webhook_id: String,
 }
 
+impl Validate for GetWebhookRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "webhook_id"),
+            self.webhook_id.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "webhook_id"), &self.webhook_id, "(?s).*");
+    }
+}
+
 /// <p> Result structure for the get webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetWebhookResult {
     /// <p> Webhook structure. </p>
     #[serde(rename = "webhook")]
@@ -671,7 +1260,7 @@ pub struct GetWebhookResult {
 
 /// <p> Structure for an execution job for an Amplify App. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Job {
     /// <p> Execution steps for an execution job, for an Amplify App. </p>
     #[serde(rename = "steps")]
@@ -683,7 +1272,7 @@ pub struct Job {
 
 /// <p> Structure for the summary of a Job. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobSummary {
     /// <p> Commit Id from 3rd party repository provider for the Job. </p>
     #[serde(rename = "commitId")]
@@ -717,6 +1306,7 @@ pub struct JobSummary {
 
 /// <p> Request structure for an Amplify App list request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAppsRequest {
     /// <p> Maximum number of records to list in a single response. </p>
     #[serde(rename = "maxResults")]
@@ -728,9 +1318,26 @@ pub struct ListAppsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAppsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "(?s).*");
+        }
+    }
+}
+
 /// <p> Result structure for an Amplify App list request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAppsResult {
     /// <p> List of Amplify Apps. </p>
     #[serde(rename = "apps")]
@@ -743,6 +1350,7 @@ pub struct ListAppsResult {
 
 /// <p> Request structure for list branches request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBranchesRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -757,9 +1365,33 @@ pub struct ListBranchesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListBranchesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "(?s).*");
+        }
+    }
+}
+
 /// <p> Result structure for list branches request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBranchesResult {
     /// <p> List of branches for an Amplify App. </p>
     #[serde(rename = "branches")]
@@ -772,6 +1404,7 @@ pub struct ListBranchesResult {
 
 /// <p> Request structure for the list Domain Associations request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDomainAssociationsRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -786,9 +1419,33 @@ pub struct ListDomainAssociationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDomainAssociationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "(?s).*");
+        }
+    }
+}
+
 /// <p> Result structure for the list Domain Association request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDomainAssociationsResult {
     /// <p> List of Domain Associations. </p>
     #[serde(rename = "domainAssociations")]
@@ -801,6 +1458,7 @@ pub struct ListDomainAssociationsResult {
 
 /// <p> Request structure for list job request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -818,9 +1476,44 @@ pub struct ListJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "(?s).*");
+        }
+    }
+}
+
 /// <p> Maximum number of records to list in a single response. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJobsResult {
     /// <p> Result structure for list job result request. </p>
     #[serde(rename = "jobSummaries")]
@@ -833,15 +1526,32 @@ pub struct ListJobsResult {
 
 /// <p> Request structure used to list tags for resource. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p> Resource arn used to list tags. </p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:amplify:.*",
+        );
+    }
+}
+
 /// <p> Response for list tags. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p> Tags result for response. </p>
     #[serde(rename = "tags")]
@@ -851,6 +1561,7 @@ pub struct ListTagsForResourceResponse {
 
 /// <p> Request structure for the list webhooks request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWebhooksRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -865,9 +1576,33 @@ pub struct ListWebhooksRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListWebhooksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "(?s).*");
+        }
+    }
+}
+
 /// <p> Result structure for the list webhooks request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWebhooksResult {
     /// <p> Pagination token. If non-null pagination token is returned in a result, then pass its value in another request to fetch more entries. </p>
     #[serde(rename = "nextToken")]
@@ -880,7 +1615,7 @@ pub struct ListWebhooksResult {
 
 /// <p> Structure with Production Branch information. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProductionBranch {
     /// <p> Branch Name for Production Branch. </p>
     #[serde(rename = "branchName")]
@@ -902,6 +1637,7 @@ pub struct ProductionBranch {
 
 /// <p> Request structure for start a deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartDeploymentRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -919,9 +1655,50 @@ pub struct StartDeploymentRequest {
     pub source_url: Option<String>,
 }
 
+impl Validate for StartDeploymentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        if let Some(ref value) = self.job_id {
+            errors.check_length(
+                &field_path(path, "job_id"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "job_id"), &value, "[0-9]+");
+        }
+        if let Some(ref value) = self.source_url {
+            errors.check_length(
+                &field_path(path, "source_url"),
+                value.chars().count(),
+                None,
+                Some(3000),
+            );
+            errors.check_pattern(&field_path(path, "source_url"), &value, "(?s).*");
+        }
+    }
+}
+
 /// <p> Result structure for start a deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartDeploymentResult {
     /// <p> Summary for the Job. </p>
     #[serde(rename = "jobSummary")]
@@ -930,6 +1707,7 @@ pub struct StartDeploymentResult {
 
 /// <p> Request structure for Start job request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartJobRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -962,9 +1740,74 @@ pub struct StartJobRequest {
     pub job_type: String,
 }
 
+impl Validate for StartJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        if let Some(ref value) = self.commit_id {
+            errors.check_length(
+                &field_path(path, "commit_id"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "commit_id"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.commit_message {
+            errors.check_length(
+                &field_path(path, "commit_message"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "commit_message"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.job_id {
+            errors.check_length(
+                &field_path(path, "job_id"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "job_id"), &value, "[0-9]+");
+        }
+        if let Some(ref value) = self.job_reason {
+            errors.check_length(
+                &field_path(path, "job_reason"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "job_reason"), &value, "(?s).*");
+        }
+        errors.check_length(
+            &field_path(path, "job_type"),
+            self.job_type.chars().count(),
+            None,
+            Some(10),
+        );
+    }
+}
+
 /// <p> Result structure for run job request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartJobResult {
     /// <p> Summary for the Job. </p>
     #[serde(rename = "jobSummary")]
@@ -973,7 +1816,7 @@ pub struct StartJobResult {
 
 /// <p> Structure for an execution step for an execution job, for an Amplify App. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Step {
     /// <p> URL to the artifact for the execution step. </p>
     #[serde(rename = "artifactsUrl")]
@@ -1011,6 +1854,7 @@ pub struct Step {
 
 /// <p> Request structure for stop job request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopJobRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -1023,9 +1867,39 @@ pub struct StopJobRequest {
     pub job_id: String,
 }
 
+impl Validate for StopJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "job_id"), &self.job_id, "[0-9]+");
+    }
+}
+
 /// <p> Result structure for the stop job request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopJobResult {
     /// <p> Summary for the Job. </p>
     #[serde(rename = "jobSummary")]
@@ -1034,7 +1908,7 @@ pub struct StopJobResult {
 
 /// <p> Subdomain for the Domain Association. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubDomain {
     /// <p> DNS record for the Subdomain. </p>
     #[serde(rename = "dnsRecord")]
@@ -1058,8 +1932,32 @@ pub struct SubDomainSetting {
     pub prefix: String,
 }
 
+impl Validate for SubDomainSetting {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        errors.check_length(
+            &field_path(path, "prefix"),
+            self.prefix.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "prefix"), &self.prefix, "(?s).*");
+    }
+}
+
 /// <p> Request structure used to tag resource. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p> Resource arn used to tag resource. </p>
     #[serde(rename = "resourceArn")]
@@ -1069,13 +1967,36 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:amplify:.*",
+        );
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), None, Some(50));
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 /// <p> Response for tag resource. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p> Request structure used to untag resource. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p> Resource arn used to untag resource. </p>
     #[serde(rename = "resourceArn")]
@@ -1085,13 +2006,41 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:amplify:.*",
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^(?!aws:)[a-zA-Z+-=._:/]+$");
+        }
+    }
+}
+
 /// <p> Response for untag resource. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 /// <p> Request structure for update App request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAppRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -1150,9 +2099,97 @@ pub struct UpdateAppRequest {
     pub platform: Option<String>,
 }
 
+impl Validate for UpdateAppRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        if let Some(ref value) = self.auto_branch_creation_config {
+            value.validate_at(&field_path(path, "auto_branch_creation_config"), errors);
+        }
+        if let Some(ref value) = self.auto_branch_creation_patterns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "auto_branch_creation_patterns"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+                errors.check_pattern(&item_path, &item, "(?s).+");
+            }
+        }
+        if let Some(ref value) = self.basic_auth_credentials {
+            errors.check_length(
+                &field_path(path, "basic_auth_credentials"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+            errors.check_pattern(
+                &field_path(path, "basic_auth_credentials"),
+                &value,
+                "(?s).*",
+            );
+        }
+        if let Some(ref value) = self.build_spec {
+            errors.check_length(
+                &field_path(path, "build_spec"),
+                value.chars().count(),
+                Some(1),
+                Some(25000),
+            );
+            errors.check_pattern(&field_path(path, "build_spec"), &value, "(?s).+");
+        }
+        if let Some(ref value) = self.custom_rules {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "custom_rules"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.environment_variables {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "environment_variables"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(5500));
+                errors.check_pattern(&item_path, &item, "(?s).*");
+            }
+        }
+        if let Some(ref value) = self.iam_service_role_arn {
+            errors.check_length(
+                &field_path(path, "iam_service_role_arn"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "iam_service_role_arn"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "(?s).+");
+        }
+    }
+}
+
 /// <p> Result structure for an Amplify App update request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAppResult {
     /// <p> App structure for the updated App. </p>
     #[serde(rename = "app")]
@@ -1161,6 +2198,7 @@ pub struct UpdateAppResult {
 
 /// <p> Request structure for update branch request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateBranchRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -1214,9 +2252,97 @@ pub struct UpdateBranchRequest {
     pub ttl: Option<String>,
 }
 
+impl Validate for UpdateBranchRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        if let Some(ref value) = self.basic_auth_credentials {
+            errors.check_length(
+                &field_path(path, "basic_auth_credentials"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+            errors.check_pattern(
+                &field_path(path, "basic_auth_credentials"),
+                &value,
+                "(?s).*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "branch_name"),
+            &self.branch_name,
+            "(?s).+",
+        );
+        if let Some(ref value) = self.build_spec {
+            errors.check_length(
+                &field_path(path, "build_spec"),
+                value.chars().count(),
+                Some(1),
+                Some(25000),
+            );
+            errors.check_pattern(&field_path(path, "build_spec"), &value, "(?s).+");
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "display_name"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.environment_variables {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "environment_variables"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(5500));
+                errors.check_pattern(&item_path, &item, "(?s).*");
+            }
+        }
+        if let Some(ref value) = self.framework {
+            errors.check_length(
+                &field_path(path, "framework"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "framework"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.ttl {
+            errors.check_length(
+                &field_path(path, "ttl"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+            errors.check_pattern(&field_path(path, "ttl"), &value, "\\d*");
+        }
+    }
+}
+
 /// <p> Result structure for update branch request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateBranchResult {
     /// <p> Branch structure for an Amplify App. </p>
     #[serde(rename = "branch")]
@@ -1225,6 +2351,7 @@ pub struct UpdateBranchResult {
 
 /// <p> Request structure for update Domain Association request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDomainAssociationRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -1241,9 +2368,38 @@ pub struct UpdateDomainAssociationRequest {
     pub sub_domain_settings: Vec<SubDomainSetting>,
 }
 
+impl Validate for UpdateDomainAssociationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_id"),
+            self.app_id.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(&field_path(path, "app_id"), &self.app_id, "d[a-z0-9]+");
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "domain_name"), &self.domain_name, "^(((?!-)[A-Za-z0-9-]{0,62}[A-Za-z0-9])\\.)+((?!-)[A-Za-z0-9-]{1,62}[A-Za-z0-9])(\\.)?$");
+        errors.check_length(
+            &field_path(path, "sub_domain_settings"),
+            self.sub_domain_settings.len(),
+            None,
+            Some(500),
+        );
+        for (i, item) in self.sub_domain_settings.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "sub_domain_settings"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p> Result structure for the update Domain Association request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDomainAssociationResult {
     /// <p> Domain Association structure. </p>
     #[serde(rename = "domainAssociation")]
@@ -1252,6 +2408,7 @@ pub struct UpdateDomainAssociationResult {
 
 /// <p> Request structure for update webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateWebhookRequest {
     /// <p> Name for a branch, part of an Amplify App. </p>
     #[serde(rename = "branchName")]
@@ -1266,9 +2423,39 @@ pub struct UpdateWebhookRequest {
     pub webhook_id: String,
 }
 
+impl Validate for UpdateWebhookRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.branch_name {
+            errors.check_length(
+                &field_path(path, "branch_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "branch_name"), &value, "(?s).+");
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "(?s).*");
+        }
+        errors.check_length(
+            &field_path(path, "webhook_id"),
+            self.webhook_id.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "webhook_id"), &self.webhook_id, "(?s).*");
+    }
+}
+
 /// <p> Result structure for the update webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateWebhookResult {
     /// <p> Webhook structure. </p>
     #[serde(rename = "webhook")]
@@ -1277,7 +2464,7 @@ pub struct UpdateWebhookResult {
 
 /// <p> Structure for webhook, which associates a webhook with an Amplify App. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Webhook {
     /// <p> Name for a branch, part of an Amplify App. </p>
     #[serde(rename = "branchName")]
@@ -1336,7 +2523,12 @@ impl CreateAppError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateAppError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1400,7 +2592,12 @@ impl CreateBranchError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateBranchError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1453,7 +2650,12 @@ impl CreateDeploymentError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateDeploymentError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1522,7 +2724,12 @@ impl CreateDomainAssociationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1587,7 +2794,12 @@ impl CreateWebhookError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateWebhookError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1645,7 +2857,12 @@ impl DeleteAppError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteAppError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1704,7 +2921,12 @@ impl DeleteBranchError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteBranchError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1767,7 +2989,12 @@ impl DeleteDomainAssociationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1824,7 +3051,12 @@ impl DeleteJobError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteJobError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1881,7 +3113,12 @@ impl DeleteWebhookError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteWebhookError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1931,7 +3168,12 @@ impl GetAppError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetAppError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1982,7 +3224,12 @@ impl GetBranchError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetBranchError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2035,7 +3282,12 @@ impl GetDomainAssociationError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetDomainAssociationError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2089,7 +3341,12 @@ impl GetJobError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetJobError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2146,7 +3403,12 @@ impl GetWebhookError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetWebhookError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2193,7 +3455,12 @@ impl ListAppsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListAppsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2238,7 +3505,12 @@ impl ListBranchesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListBranchesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2285,7 +3557,12 @@ impl ListDomainAssociationsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListDomainAssociationsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2335,7 +3612,12 @@ impl ListJobsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListJobsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2383,7 +3665,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2433,7 +3720,12 @@ impl ListWebhooksError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListWebhooksError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2489,7 +3781,12 @@ impl StartDeploymentError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(StartDeploymentError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2546,7 +3843,12 @@ impl StartJobError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(StartJobError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2603,7 +3905,12 @@ impl StopJobError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(StopJobError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2650,7 +3957,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2695,7 +4007,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2745,7 +4062,12 @@ impl UpdateAppError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateAppError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2803,7 +4125,12 @@ impl UpdateBranchError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateBranchError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2866,7 +4193,12 @@ impl UpdateDomainAssociationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2925,7 +4257,12 @@ impl UpdateWebhookError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateWebhookError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3097,279 +4434,825 @@ pub trait Amplify {
         input: UpdateWebhookRequest,
     ) -> RusotoFuture<UpdateWebhookResult, UpdateWebhookError>;
 }
-/// A client for the Amplify API.
-#[derive(Clone)]
-pub struct AmplifyClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl AmplifyClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> AmplifyClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> AmplifyClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> AmplifyClient {
-        AmplifyClient { client, region }
-    }
-}
-
-impl Amplify for AmplifyClient {
-    /// <p> Creates a new Amplify App. </p>
+const _: Option<&dyn Amplify> = None;
+impl<'a, T: Amplify + ?Sized> Amplify for &'a T {
     fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
-        let request_uri = "/apps";
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAppResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAppError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_app(input)
     }
-
-    /// <p> Creates a new Branch for an Amplify App. </p>
     fn create_branch(
         &self,
         input: CreateBranchRequest,
     ) -> RusotoFuture<CreateBranchResult, CreateBranchError> {
-        let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBranchResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBranchError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_branch(input)
     }
-
-    /// <p> Create a deployment for manual deploy apps. (Apps are not connected to repository) </p>
     fn create_deployment(
         &self,
         input: CreateDeploymentRequest,
     ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
-        let request_uri = format!(
-            "/apps/{app_id}/branches/{branch_name}/deployments",
-            app_id = input.app_id,
-            branch_name = input.branch_name
-        );
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDeploymentResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_deployment(input)
     }
-
-    /// <p> Create a new DomainAssociation on an App </p>
     fn create_domain_association(
         &self,
         input: CreateDomainAssociationRequest,
     ) -> RusotoFuture<CreateDomainAssociationResult, CreateDomainAssociationError> {
-        let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDomainAssociationResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDomainAssociationError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_domain_association(input)
     }
-
-    /// <p> Create a new webhook on an App. </p>
     fn create_webhook(
         &self,
         input: CreateWebhookRequest,
     ) -> RusotoFuture<CreateWebhookResult, CreateWebhookError> {
-        let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateWebhookResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateWebhookError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_webhook(input)
     }
-
-    /// <p> Delete an existing Amplify App by appId. </p>
     fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<DeleteAppResult, DeleteAppError> {
-        let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
-
-        let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteAppResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAppError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_app(input)
     }
-
-    /// <p> Deletes a branch for an Amplify App. </p>
     fn delete_branch(
         &self,
         input: DeleteBranchRequest,
     ) -> RusotoFuture<DeleteBranchResult, DeleteBranchError> {
-        let request_uri = format!(
-            "/apps/{app_id}/branches/{branch_name}",
-            app_id = input.app_id,
-            branch_name = input.branch_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBranchResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBranchError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_branch(input)
     }
-
-    /// <p> Deletes a DomainAssociation. </p>
     fn delete_domain_association(
         &self,
         input: DeleteDomainAssociationRequest,
     ) -> RusotoFuture<DeleteDomainAssociationResult, DeleteDomainAssociationError> {
-        let request_uri = format!(
-            "/apps/{app_id}/domains/{domain_name}",
-            app_id = input.app_id,
-            domain_name = input.domain_name
-        );
-
+        (**self).delete_domain_association(input)
+    }
+    fn delete_job(&self, input: DeleteJobRequest) -> RusotoFuture<DeleteJobResult, DeleteJobError> {
+        (**self).delete_job(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookRequest,
+    ) -> RusotoFuture<DeleteWebhookResult, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResult, GetAppError> {
+        (**self).get_app(input)
+    }
+    fn get_branch(&self, input: GetBranchRequest) -> RusotoFuture<GetBranchResult, GetBranchError> {
+        (**self).get_branch(input)
+    }
+    fn get_domain_association(
+        &self,
+        input: GetDomainAssociationRequest,
+    ) -> RusotoFuture<GetDomainAssociationResult, GetDomainAssociationError> {
+        (**self).get_domain_association(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_webhook(
+        &self,
+        input: GetWebhookRequest,
+    ) -> RusotoFuture<GetWebhookResult, GetWebhookError> {
+        (**self).get_webhook(input)
+    }
+    fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResult, ListAppsError> {
+        (**self).list_apps(input)
+    }
+    fn list_branches(
+        &self,
+        input: ListBranchesRequest,
+    ) -> RusotoFuture<ListBranchesResult, ListBranchesError> {
+        (**self).list_branches(input)
+    }
+    fn list_domain_associations(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> RusotoFuture<ListDomainAssociationsResult, ListDomainAssociationsError> {
+        (**self).list_domain_associations(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_webhooks(
+        &self,
+        input: ListWebhooksRequest,
+    ) -> RusotoFuture<ListWebhooksResult, ListWebhooksError> {
+        (**self).list_webhooks(input)
+    }
+    fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> RusotoFuture<StartDeploymentResult, StartDeploymentError> {
+        (**self).start_deployment(input)
+    }
+    fn start_job(&self, input: StartJobRequest) -> RusotoFuture<StartJobResult, StartJobError> {
+        (**self).start_job(input)
+    }
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        (**self).stop_job(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<UpdateAppResult, UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_branch(
+        &self,
+        input: UpdateBranchRequest,
+    ) -> RusotoFuture<UpdateBranchResult, UpdateBranchError> {
+        (**self).update_branch(input)
+    }
+    fn update_domain_association(
+        &self,
+        input: UpdateDomainAssociationRequest,
+    ) -> RusotoFuture<UpdateDomainAssociationResult, UpdateDomainAssociationError> {
+        (**self).update_domain_association(input)
+    }
+    fn update_webhook(
+        &self,
+        input: UpdateWebhookRequest,
+    ) -> RusotoFuture<UpdateWebhookResult, UpdateWebhookError> {
+        (**self).update_webhook(input)
+    }
+}
+impl<T: Amplify + ?Sized> Amplify for Box<T> {
+    fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
+        (**self).create_app(input)
+    }
+    fn create_branch(
+        &self,
+        input: CreateBranchRequest,
+    ) -> RusotoFuture<CreateBranchResult, CreateBranchError> {
+        (**self).create_branch(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_domain_association(
+        &self,
+        input: CreateDomainAssociationRequest,
+    ) -> RusotoFuture<CreateDomainAssociationResult, CreateDomainAssociationError> {
+        (**self).create_domain_association(input)
+    }
+    fn create_webhook(
+        &self,
+        input: CreateWebhookRequest,
+    ) -> RusotoFuture<CreateWebhookResult, CreateWebhookError> {
+        (**self).create_webhook(input)
+    }
+    fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<DeleteAppResult, DeleteAppError> {
+        (**self).delete_app(input)
+    }
+    fn delete_branch(
+        &self,
+        input: DeleteBranchRequest,
+    ) -> RusotoFuture<DeleteBranchResult, DeleteBranchError> {
+        (**self).delete_branch(input)
+    }
+    fn delete_domain_association(
+        &self,
+        input: DeleteDomainAssociationRequest,
+    ) -> RusotoFuture<DeleteDomainAssociationResult, DeleteDomainAssociationError> {
+        (**self).delete_domain_association(input)
+    }
+    fn delete_job(&self, input: DeleteJobRequest) -> RusotoFuture<DeleteJobResult, DeleteJobError> {
+        (**self).delete_job(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookRequest,
+    ) -> RusotoFuture<DeleteWebhookResult, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResult, GetAppError> {
+        (**self).get_app(input)
+    }
+    fn get_branch(&self, input: GetBranchRequest) -> RusotoFuture<GetBranchResult, GetBranchError> {
+        (**self).get_branch(input)
+    }
+    fn get_domain_association(
+        &self,
+        input: GetDomainAssociationRequest,
+    ) -> RusotoFuture<GetDomainAssociationResult, GetDomainAssociationError> {
+        (**self).get_domain_association(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_webhook(
+        &self,
+        input: GetWebhookRequest,
+    ) -> RusotoFuture<GetWebhookResult, GetWebhookError> {
+        (**self).get_webhook(input)
+    }
+    fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResult, ListAppsError> {
+        (**self).list_apps(input)
+    }
+    fn list_branches(
+        &self,
+        input: ListBranchesRequest,
+    ) -> RusotoFuture<ListBranchesResult, ListBranchesError> {
+        (**self).list_branches(input)
+    }
+    fn list_domain_associations(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> RusotoFuture<ListDomainAssociationsResult, ListDomainAssociationsError> {
+        (**self).list_domain_associations(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_webhooks(
+        &self,
+        input: ListWebhooksRequest,
+    ) -> RusotoFuture<ListWebhooksResult, ListWebhooksError> {
+        (**self).list_webhooks(input)
+    }
+    fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> RusotoFuture<StartDeploymentResult, StartDeploymentError> {
+        (**self).start_deployment(input)
+    }
+    fn start_job(&self, input: StartJobRequest) -> RusotoFuture<StartJobResult, StartJobError> {
+        (**self).start_job(input)
+    }
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        (**self).stop_job(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<UpdateAppResult, UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_branch(
+        &self,
+        input: UpdateBranchRequest,
+    ) -> RusotoFuture<UpdateBranchResult, UpdateBranchError> {
+        (**self).update_branch(input)
+    }
+    fn update_domain_association(
+        &self,
+        input: UpdateDomainAssociationRequest,
+    ) -> RusotoFuture<UpdateDomainAssociationResult, UpdateDomainAssociationError> {
+        (**self).update_domain_association(input)
+    }
+    fn update_webhook(
+        &self,
+        input: UpdateWebhookRequest,
+    ) -> RusotoFuture<UpdateWebhookResult, UpdateWebhookError> {
+        (**self).update_webhook(input)
+    }
+}
+impl<T: Amplify + ?Sized> Amplify for ::std::sync::Arc<T> {
+    fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
+        (**self).create_app(input)
+    }
+    fn create_branch(
+        &self,
+        input: CreateBranchRequest,
+    ) -> RusotoFuture<CreateBranchResult, CreateBranchError> {
+        (**self).create_branch(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_domain_association(
+        &self,
+        input: CreateDomainAssociationRequest,
+    ) -> RusotoFuture<CreateDomainAssociationResult, CreateDomainAssociationError> {
+        (**self).create_domain_association(input)
+    }
+    fn create_webhook(
+        &self,
+        input: CreateWebhookRequest,
+    ) -> RusotoFuture<CreateWebhookResult, CreateWebhookError> {
+        (**self).create_webhook(input)
+    }
+    fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<DeleteAppResult, DeleteAppError> {
+        (**self).delete_app(input)
+    }
+    fn delete_branch(
+        &self,
+        input: DeleteBranchRequest,
+    ) -> RusotoFuture<DeleteBranchResult, DeleteBranchError> {
+        (**self).delete_branch(input)
+    }
+    fn delete_domain_association(
+        &self,
+        input: DeleteDomainAssociationRequest,
+    ) -> RusotoFuture<DeleteDomainAssociationResult, DeleteDomainAssociationError> {
+        (**self).delete_domain_association(input)
+    }
+    fn delete_job(&self, input: DeleteJobRequest) -> RusotoFuture<DeleteJobResult, DeleteJobError> {
+        (**self).delete_job(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookRequest,
+    ) -> RusotoFuture<DeleteWebhookResult, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResult, GetAppError> {
+        (**self).get_app(input)
+    }
+    fn get_branch(&self, input: GetBranchRequest) -> RusotoFuture<GetBranchResult, GetBranchError> {
+        (**self).get_branch(input)
+    }
+    fn get_domain_association(
+        &self,
+        input: GetDomainAssociationRequest,
+    ) -> RusotoFuture<GetDomainAssociationResult, GetDomainAssociationError> {
+        (**self).get_domain_association(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_webhook(
+        &self,
+        input: GetWebhookRequest,
+    ) -> RusotoFuture<GetWebhookResult, GetWebhookError> {
+        (**self).get_webhook(input)
+    }
+    fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResult, ListAppsError> {
+        (**self).list_apps(input)
+    }
+    fn list_branches(
+        &self,
+        input: ListBranchesRequest,
+    ) -> RusotoFuture<ListBranchesResult, ListBranchesError> {
+        (**self).list_branches(input)
+    }
+    fn list_domain_associations(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> RusotoFuture<ListDomainAssociationsResult, ListDomainAssociationsError> {
+        (**self).list_domain_associations(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_webhooks(
+        &self,
+        input: ListWebhooksRequest,
+    ) -> RusotoFuture<ListWebhooksResult, ListWebhooksError> {
+        (**self).list_webhooks(input)
+    }
+    fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> RusotoFuture<StartDeploymentResult, StartDeploymentError> {
+        (**self).start_deployment(input)
+    }
+    fn start_job(&self, input: StartJobRequest) -> RusotoFuture<StartJobResult, StartJobError> {
+        (**self).start_job(input)
+    }
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        (**self).stop_job(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<UpdateAppResult, UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_branch(
+        &self,
+        input: UpdateBranchRequest,
+    ) -> RusotoFuture<UpdateBranchResult, UpdateBranchError> {
+        (**self).update_branch(input)
+    }
+    fn update_domain_association(
+        &self,
+        input: UpdateDomainAssociationRequest,
+    ) -> RusotoFuture<UpdateDomainAssociationResult, UpdateDomainAssociationError> {
+        (**self).update_domain_association(input)
+    }
+    fn update_webhook(
+        &self,
+        input: UpdateWebhookRequest,
+    ) -> RusotoFuture<UpdateWebhookResult, UpdateWebhookError> {
+        (**self).update_webhook(input)
+    }
+}
+/// A client for the Amplify API.
+#[derive(Clone)]
+pub struct AmplifyClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl AmplifyClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AmplifyClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AmplifyClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> AmplifyClient {
+        AmplifyClient { client, region }
+    }
+}
+
+impl Amplify for AmplifyClient {
+    /// <p> Creates a new Amplify App. </p>
+    fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/apps";
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateApp");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateApp")
+                        .deserialize::<CreateAppResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Creates a new Branch for an Amplify App. </p>
+    fn create_branch(
+        &self,
+        input: CreateBranchRequest,
+    ) -> RusotoFuture<CreateBranchResult, CreateBranchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateBranch");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateBranch")
+                        .deserialize::<CreateBranchResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBranchError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Create a deployment for manual deploy apps. (Apps are not connected to repository) </p>
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/apps/{app_id}/branches/{branch_name}/deployments",
+            app_id = input.app_id,
+            branch_name = input.branch_name
+        );
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("branchName", &input.branch_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDeployment")
+                        .deserialize::<CreateDeploymentResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Create a new DomainAssociation on an App </p>
+    fn create_domain_association(
+        &self,
+        input: CreateDomainAssociationRequest,
+    ) -> RusotoFuture<CreateDomainAssociationResult, CreateDomainAssociationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateDomainAssociation");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDomainAssociation")
+                        .deserialize::<CreateDomainAssociationResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDomainAssociationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> Create a new webhook on an App. </p>
+    fn create_webhook(
+        &self,
+        input: CreateWebhookRequest,
+    ) -> RusotoFuture<CreateWebhookResult, CreateWebhookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateWebhook");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateWebhook")
+                        .deserialize::<CreateWebhookResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateWebhookError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Delete an existing Amplify App by appId. </p>
+    fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<DeleteAppResult, DeleteAppError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
+
+        let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteApp");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteApp")
+                        .deserialize::<DeleteAppResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Deletes a branch for an Amplify App. </p>
+    fn delete_branch(
+        &self,
+        input: DeleteBranchRequest,
+    ) -> RusotoFuture<DeleteBranchResult, DeleteBranchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/apps/{app_id}/branches/{branch_name}",
+            app_id = input.app_id,
+            branch_name = input.branch_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteBranch");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("branchName", &input.branch_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteBranch")
+                        .deserialize::<DeleteBranchResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBranchError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Deletes a DomainAssociation. </p>
+    fn delete_domain_association(
+        &self,
+        input: DeleteDomainAssociationRequest,
+    ) -> RusotoFuture<DeleteDomainAssociationResult, DeleteDomainAssociationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/apps/{app_id}/domains/{domain_name}",
+            app_id = input.app_id,
+            domain_name = input.domain_name
+        );
+
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteDomainAssociation");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteDomainAssociation")
                         .deserialize::<DeleteDomainAssociationResult, _>()?;
 
                     Ok(result)
@@ -3384,6 +5267,14 @@ impl Amplify for AmplifyClient {
 
     /// <p> Delete a job, for an Amplify branch, part of Amplify App. </p>
     fn delete_job(&self, input: DeleteJobRequest) -> RusotoFuture<DeleteJobResult, DeleteJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/jobs/{job_id}",
             app_id = input.app_id,
@@ -3392,12 +5283,18 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteJob");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("branchName", &input.branch_name.to_string());
+        request.add_resource_id("jobId", &input.job_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteJob")
                         .deserialize::<DeleteJobResult, _>()?;
 
                     Ok(result)
@@ -3418,15 +5315,27 @@ impl Amplify for AmplifyClient {
         &self,
         input: DeleteWebhookRequest,
     ) -> RusotoFuture<DeleteWebhookResult, DeleteWebhookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteWebhook");
+        request.add_resource_id("webhookId", &input.webhook_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteWebhook")
                         .deserialize::<DeleteWebhookResult, _>()?;
 
                     Ok(result)
@@ -3444,15 +5353,27 @@ impl Amplify for AmplifyClient {
 
     /// <p> Retrieves an existing Amplify App by appId. </p>
     fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResult, GetAppError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetApp");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetApp")
                         .deserialize::<GetAppResult, _>()?;
 
                     Ok(result)
@@ -3470,6 +5391,14 @@ impl Amplify for AmplifyClient {
 
     /// <p> Retrieves a branch for an Amplify App. </p>
     fn get_branch(&self, input: GetBranchRequest) -> RusotoFuture<GetBranchResult, GetBranchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}",
             app_id = input.app_id,
@@ -3477,12 +5406,17 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetBranch");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("branchName", &input.branch_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBranch")
                         .deserialize::<GetBranchResult, _>()?;
 
                     Ok(result)
@@ -3503,6 +5437,14 @@ impl Amplify for AmplifyClient {
         &self,
         input: GetDomainAssociationRequest,
     ) -> RusotoFuture<GetDomainAssociationResult, GetDomainAssociationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/apps/{app_id}/domains/{domain_name}",
             app_id = input.app_id,
@@ -3510,12 +5452,17 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetDomainAssociation");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDomainAssociation")
                         .deserialize::<GetDomainAssociationResult, _>()?;
 
                     Ok(result)
@@ -3532,6 +5479,14 @@ impl Amplify for AmplifyClient {
 
     /// <p> Get a job for a branch, part of an Amplify App. </p>
     fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/jobs/{job_id}",
             app_id = input.app_id,
@@ -3540,12 +5495,18 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetJob");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("branchName", &input.branch_name.to_string());
+        request.add_resource_id("jobId", &input.job_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetJob")
                         .deserialize::<GetJobResult, _>()?;
 
                     Ok(result)
@@ -3566,15 +5527,27 @@ impl Amplify for AmplifyClient {
         &self,
         input: GetWebhookRequest,
     ) -> RusotoFuture<GetWebhookResult, GetWebhookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetWebhook");
+        request.add_resource_id("webhookId", &input.webhook_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetWebhook")
                         .deserialize::<GetWebhookResult, _>()?;
 
                     Ok(result)
@@ -3592,9 +5565,19 @@ impl Amplify for AmplifyClient {
 
     /// <p> Lists existing Amplify Apps. </p>
     fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResult, ListAppsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListApps");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3610,6 +5593,7 @@ impl Amplify for AmplifyClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListApps")
                         .deserialize::<ListAppsResult, _>()?;
 
                     Ok(result)
@@ -3630,9 +5614,20 @@ impl Amplify for AmplifyClient {
         &self,
         input: ListBranchesRequest,
     ) -> RusotoFuture<ListBranchesResult, ListBranchesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListBranches");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3648,6 +5643,7 @@ impl Amplify for AmplifyClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListBranches")
                         .deserialize::<ListBranchesResult, _>()?;
 
                     Ok(result)
@@ -3668,9 +5664,20 @@ impl Amplify for AmplifyClient {
         &self,
         input: ListDomainAssociationsRequest,
     ) -> RusotoFuture<ListDomainAssociationsResult, ListDomainAssociationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListDomainAssociations");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3686,6 +5693,7 @@ impl Amplify for AmplifyClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDomainAssociations")
                         .deserialize::<ListDomainAssociationsResult, _>()?;
 
                     Ok(result)
@@ -3702,6 +5710,14 @@ impl Amplify for AmplifyClient {
 
     /// <p> List Jobs for a branch, part of an Amplify App. </p>
     fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/jobs",
             app_id = input.app_id,
@@ -3709,6 +5725,10 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListJobs");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("branchName", &input.branch_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3724,6 +5744,7 @@ impl Amplify for AmplifyClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListJobs")
                         .deserialize::<ListJobsResult, _>()?;
 
                     Ok(result)
@@ -3744,15 +5765,27 @@ impl Amplify for AmplifyClient {
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
                         .deserialize::<ListTagsForResourceResponse, _>()?;
 
                     Ok(result)
@@ -3772,9 +5805,20 @@ impl Amplify for AmplifyClient {
         &self,
         input: ListWebhooksRequest,
     ) -> RusotoFuture<ListWebhooksResult, ListWebhooksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListWebhooks");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3790,7 +5834,327 @@ impl Amplify for AmplifyClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListWebhooksResult, _>()?;
+                        .with_operation("ListWebhooks")
+                        .deserialize::<ListWebhooksResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListWebhooksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Start a deployment for manual deploy apps. (Apps are not connected to repository) </p>
+    fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> RusotoFuture<StartDeploymentResult, StartDeploymentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/apps/{app_id}/branches/{branch_name}/deployments/start",
+            app_id = input.app_id,
+            branch_name = input.branch_name
+        );
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("StartDeployment");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("branchName", &input.branch_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartDeployment")
+                        .deserialize::<StartDeploymentResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Starts a new job for a branch, part of an Amplify App. </p>
+    fn start_job(&self, input: StartJobRequest) -> RusotoFuture<StartJobResult, StartJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/apps/{app_id}/branches/{branch_name}/jobs",
+            app_id = input.app_id,
+            branch_name = input.branch_name
+        );
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("StartJob");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("branchName", &input.branch_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartJob")
+                        .deserialize::<StartJobResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Stop a job that is in progress, for an Amplify branch, part of Amplify App. </p>
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/apps/{app_id}/branches/{branch_name}/jobs/{job_id}/stop",
+            app_id = input.app_id,
+            branch_name = input.branch_name,
+            job_id = input.job_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("StopJob");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("branchName", &input.branch_name.to_string());
+        request.add_resource_id("jobId", &input.job_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StopJob")
+                        .deserialize::<StopJobResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Tag resource with tag key and value. </p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
+                        .deserialize::<TagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Untag resource with resourceArn. </p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
+                        .deserialize::<UntagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Updates an existing Amplify App. </p>
+    fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<UpdateAppResult, UpdateAppError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateApp");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateApp")
+                        .deserialize::<UpdateAppResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Updates a branch for an Amplify App. </p>
+    fn update_branch(
+        &self,
+        input: UpdateBranchRequest,
+    ) -> RusotoFuture<UpdateBranchResult, UpdateBranchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/apps/{app_id}/branches/{branch_name}",
+            app_id = input.app_id,
+            branch_name = input.branch_name
+        );
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateBranch");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("branchName", &input.branch_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateBranch")
+                        .deserialize::<UpdateBranchResult, _>()?;
 
                     Ok(result)
                 }))
@@ -3799,24 +6163,36 @@ impl Amplify for AmplifyClient {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(ListWebhooksError::from_response(response))),
+                        .and_then(|response| Err(UpdateBranchError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p> Start a deployment for manual deploy apps. (Apps are not connected to repository) </p>
-    fn start_deployment(
+    /// <p> Create a new DomainAssociation on an App </p>
+    fn update_domain_association(
         &self,
-        input: StartDeploymentRequest,
-    ) -> RusotoFuture<StartDeploymentResult, StartDeploymentError> {
+        input: UpdateDomainAssociationRequest,
+    ) -> RusotoFuture<UpdateDomainAssociationResult, UpdateDomainAssociationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
-            "/apps/{app_id}/branches/{branch_name}/deployments/start",
+            "/apps/{app_id}/domains/{domain_name}",
             app_id = input.app_id,
-            branch_name = input.branch_name
+            domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateDomainAssociation");
+        request.add_resource_id("appId", &input.app_id.to_string());
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3826,7 +6202,49 @@ impl Amplify for AmplifyClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartDeploymentResult, _>()?;
+                        .with_operation("UpdateDomainAssociation")
+                        .deserialize::<UpdateDomainAssociationResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateDomainAssociationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> Update a webhook. </p>
+    fn update_webhook(
+        &self,
+        input: UpdateWebhookRequest,
+    ) -> RusotoFuture<UpdateWebhookResult, UpdateWebhookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
+
+        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateWebhook");
+        request.add_resource_id("webhookId", &input.webhook_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateWebhook")
+                        .deserialize::<UpdateWebhookResult, _>()?;
 
                     Ok(result)
                 }))
@@ -3835,270 +6253,972 @@ impl Amplify for AmplifyClient {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(StartDeploymentError::from_response(response))),
+                        .and_then(|response| Err(UpdateWebhookError::from_response(response))),
                 )
             }
         })
     }
-
-    /// <p> Starts a new job for a branch, part of an Amplify App. </p>
+}
+
+/// A mock of the Amplify API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAmplify {
+    create_app: Option<
+        Box<
+            dyn Fn(
+                    CreateAppRequest,
+                )
+                    -> ::std::result::Result<CreateAppResult, RusotoError<CreateAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_branch: Option<
+        Box<
+            dyn Fn(
+                    CreateBranchRequest,
+                )
+                    -> ::std::result::Result<CreateBranchResult, RusotoError<CreateBranchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_deployment: Option<
+        Box<
+            dyn Fn(
+                    CreateDeploymentRequest,
+                ) -> ::std::result::Result<
+                    CreateDeploymentResult,
+                    RusotoError<CreateDeploymentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_domain_association: Option<
+        Box<
+            dyn Fn(
+                    CreateDomainAssociationRequest,
+                ) -> ::std::result::Result<
+                    CreateDomainAssociationResult,
+                    RusotoError<CreateDomainAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_webhook: Option<
+        Box<
+            dyn Fn(
+                    CreateWebhookRequest,
+                )
+                    -> ::std::result::Result<CreateWebhookResult, RusotoError<CreateWebhookError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_app: Option<
+        Box<
+            dyn Fn(
+                    DeleteAppRequest,
+                )
+                    -> ::std::result::Result<DeleteAppResult, RusotoError<DeleteAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_branch: Option<
+        Box<
+            dyn Fn(
+                    DeleteBranchRequest,
+                )
+                    -> ::std::result::Result<DeleteBranchResult, RusotoError<DeleteBranchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_domain_association: Option<
+        Box<
+            dyn Fn(
+                    DeleteDomainAssociationRequest,
+                ) -> ::std::result::Result<
+                    DeleteDomainAssociationResult,
+                    RusotoError<DeleteDomainAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_job: Option<
+        Box<
+            dyn Fn(
+                    DeleteJobRequest,
+                )
+                    -> ::std::result::Result<DeleteJobResult, RusotoError<DeleteJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_webhook: Option<
+        Box<
+            dyn Fn(
+                    DeleteWebhookRequest,
+                )
+                    -> ::std::result::Result<DeleteWebhookResult, RusotoError<DeleteWebhookError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_app: Option<
+        Box<
+            dyn Fn(GetAppRequest) -> ::std::result::Result<GetAppResult, RusotoError<GetAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_branch: Option<
+        Box<
+            dyn Fn(
+                    GetBranchRequest,
+                )
+                    -> ::std::result::Result<GetBranchResult, RusotoError<GetBranchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_domain_association: Option<
+        Box<
+            dyn Fn(
+                    GetDomainAssociationRequest,
+                ) -> ::std::result::Result<
+                    GetDomainAssociationResult,
+                    RusotoError<GetDomainAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_job: Option<
+        Box<
+            dyn Fn(GetJobRequest) -> ::std::result::Result<GetJobResult, RusotoError<GetJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_webhook: Option<
+        Box<
+            dyn Fn(
+                    GetWebhookRequest,
+                )
+                    -> ::std::result::Result<GetWebhookResult, RusotoError<GetWebhookError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_apps: Option<
+        Box<
+            dyn Fn(
+                    ListAppsRequest,
+                )
+                    -> ::std::result::Result<ListAppsResult, RusotoError<ListAppsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_branches: Option<
+        Box<
+            dyn Fn(
+                    ListBranchesRequest,
+                )
+                    -> ::std::result::Result<ListBranchesResult, RusotoError<ListBranchesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_domain_associations: Option<
+        Box<
+            dyn Fn(
+                    ListDomainAssociationsRequest,
+                ) -> ::std::result::Result<
+                    ListDomainAssociationsResult,
+                    RusotoError<ListDomainAssociationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListJobsRequest,
+                )
+                    -> ::std::result::Result<ListJobsResult, RusotoError<ListJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_webhooks: Option<
+        Box<
+            dyn Fn(
+                    ListWebhooksRequest,
+                )
+                    -> ::std::result::Result<ListWebhooksResult, RusotoError<ListWebhooksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_deployment: Option<
+        Box<
+            dyn Fn(
+                    StartDeploymentRequest,
+                ) -> ::std::result::Result<
+                    StartDeploymentResult,
+                    RusotoError<StartDeploymentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_job: Option<
+        Box<
+            dyn Fn(
+                    StartJobRequest,
+                )
+                    -> ::std::result::Result<StartJobResult, RusotoError<StartJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_job: Option<
+        Box<
+            dyn Fn(
+                    StopJobRequest,
+                )
+                    -> ::std::result::Result<StopJobResult, RusotoError<StopJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_app: Option<
+        Box<
+            dyn Fn(
+                    UpdateAppRequest,
+                )
+                    -> ::std::result::Result<UpdateAppResult, RusotoError<UpdateAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_branch: Option<
+        Box<
+            dyn Fn(
+                    UpdateBranchRequest,
+                )
+                    -> ::std::result::Result<UpdateBranchResult, RusotoError<UpdateBranchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_domain_association: Option<
+        Box<
+            dyn Fn(
+                    UpdateDomainAssociationRequest,
+                ) -> ::std::result::Result<
+                    UpdateDomainAssociationResult,
+                    RusotoError<UpdateDomainAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_webhook: Option<
+        Box<
+            dyn Fn(
+                    UpdateWebhookRequest,
+                )
+                    -> ::std::result::Result<UpdateWebhookResult, RusotoError<UpdateWebhookError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAmplify {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAmplify {
+        Default::default()
+    }
+
+    /// Answers `create_app` with what `handler` returns for its input.
+    pub fn with_create_app<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                CreateAppRequest,
+            ) -> ::std::result::Result<CreateAppResult, RusotoError<CreateAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_branch` with what `handler` returns for its input.
+    pub fn with_create_branch<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                CreateBranchRequest,
+            )
+                -> ::std::result::Result<CreateBranchResult, RusotoError<CreateBranchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_branch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_deployment` with what `handler` returns for its input.
+    pub fn with_create_deployment<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                CreateDeploymentRequest,
+            )
+                -> ::std::result::Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_domain_association` with what `handler` returns for its input.
+    pub fn with_create_domain_association<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                CreateDomainAssociationRequest,
+            ) -> ::std::result::Result<
+                CreateDomainAssociationResult,
+                RusotoError<CreateDomainAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_domain_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_webhook` with what `handler` returns for its input.
+    pub fn with_create_webhook<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                CreateWebhookRequest,
+            )
+                -> ::std::result::Result<CreateWebhookResult, RusotoError<CreateWebhookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_webhook = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_app` with what `handler` returns for its input.
+    pub fn with_delete_app<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                DeleteAppRequest,
+            ) -> ::std::result::Result<DeleteAppResult, RusotoError<DeleteAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_branch` with what `handler` returns for its input.
+    pub fn with_delete_branch<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                DeleteBranchRequest,
+            )
+                -> ::std::result::Result<DeleteBranchResult, RusotoError<DeleteBranchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_branch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_domain_association` with what `handler` returns for its input.
+    pub fn with_delete_domain_association<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                DeleteDomainAssociationRequest,
+            ) -> ::std::result::Result<
+                DeleteDomainAssociationResult,
+                RusotoError<DeleteDomainAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_domain_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_job` with what `handler` returns for its input.
+    pub fn with_delete_job<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                DeleteJobRequest,
+            ) -> ::std::result::Result<DeleteJobResult, RusotoError<DeleteJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_webhook` with what `handler` returns for its input.
+    pub fn with_delete_webhook<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                DeleteWebhookRequest,
+            )
+                -> ::std::result::Result<DeleteWebhookResult, RusotoError<DeleteWebhookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_webhook = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_app` with what `handler` returns for its input.
+    pub fn with_get_app<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(GetAppRequest) -> ::std::result::Result<GetAppResult, RusotoError<GetAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_branch` with what `handler` returns for its input.
+    pub fn with_get_branch<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                GetBranchRequest,
+            ) -> ::std::result::Result<GetBranchResult, RusotoError<GetBranchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_branch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_domain_association` with what `handler` returns for its input.
+    pub fn with_get_domain_association<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                GetDomainAssociationRequest,
+            ) -> ::std::result::Result<
+                GetDomainAssociationResult,
+                RusotoError<GetDomainAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_domain_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job` with what `handler` returns for its input.
+    pub fn with_get_job<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(GetJobRequest) -> ::std::result::Result<GetJobResult, RusotoError<GetJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_webhook` with what `handler` returns for its input.
+    pub fn with_get_webhook<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                GetWebhookRequest,
+            ) -> ::std::result::Result<GetWebhookResult, RusotoError<GetWebhookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_webhook = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_apps` with what `handler` returns for its input.
+    pub fn with_list_apps<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(ListAppsRequest) -> ::std::result::Result<ListAppsResult, RusotoError<ListAppsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_apps = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_branches` with what `handler` returns for its input.
+    pub fn with_list_branches<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                ListBranchesRequest,
+            )
+                -> ::std::result::Result<ListBranchesResult, RusotoError<ListBranchesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_branches = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_domain_associations` with what `handler` returns for its input.
+    pub fn with_list_domain_associations<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                ListDomainAssociationsRequest,
+            ) -> ::std::result::Result<
+                ListDomainAssociationsResult,
+                RusotoError<ListDomainAssociationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_domain_associations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_jobs` with what `handler` returns for its input.
+    pub fn with_list_jobs<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(ListJobsRequest) -> ::std::result::Result<ListJobsResult, RusotoError<ListJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_webhooks` with what `handler` returns for its input.
+    pub fn with_list_webhooks<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                ListWebhooksRequest,
+            )
+                -> ::std::result::Result<ListWebhooksResult, RusotoError<ListWebhooksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_webhooks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_deployment` with what `handler` returns for its input.
+    pub fn with_start_deployment<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                StartDeploymentRequest,
+            )
+                -> ::std::result::Result<StartDeploymentResult, RusotoError<StartDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_job` with what `handler` returns for its input.
+    pub fn with_start_job<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(StartJobRequest) -> ::std::result::Result<StartJobResult, RusotoError<StartJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_job` with what `handler` returns for its input.
+    pub fn with_stop_job<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(StopJobRequest) -> ::std::result::Result<StopJobResult, RusotoError<StopJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_app` with what `handler` returns for its input.
+    pub fn with_update_app<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                UpdateAppRequest,
+            ) -> ::std::result::Result<UpdateAppResult, RusotoError<UpdateAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_branch` with what `handler` returns for its input.
+    pub fn with_update_branch<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                UpdateBranchRequest,
+            )
+                -> ::std::result::Result<UpdateBranchResult, RusotoError<UpdateBranchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_branch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_domain_association` with what `handler` returns for its input.
+    pub fn with_update_domain_association<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                UpdateDomainAssociationRequest,
+            ) -> ::std::result::Result<
+                UpdateDomainAssociationResult,
+                RusotoError<UpdateDomainAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_domain_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_webhook` with what `handler` returns for its input.
+    pub fn with_update_webhook<F>(mut self, handler: F) -> MockAmplify
+    where
+        F: Fn(
+                UpdateWebhookRequest,
+            )
+                -> ::std::result::Result<UpdateWebhookResult, RusotoError<UpdateWebhookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_webhook = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Amplify for MockAmplify {
+    fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
+        match self.create_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for create_app"),
+        }
+    }
+    fn create_branch(
+        &self,
+        input: CreateBranchRequest,
+    ) -> RusotoFuture<CreateBranchResult, CreateBranchError> {
+        match self.create_branch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for create_branch"),
+        }
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
+        match self.create_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for create_deployment"),
+        }
+    }
+    fn create_domain_association(
+        &self,
+        input: CreateDomainAssociationRequest,
+    ) -> RusotoFuture<CreateDomainAssociationResult, CreateDomainAssociationError> {
+        match self.create_domain_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for create_domain_association"),
+        }
+    }
+    fn create_webhook(
+        &self,
+        input: CreateWebhookRequest,
+    ) -> RusotoFuture<CreateWebhookResult, CreateWebhookError> {
+        match self.create_webhook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for create_webhook"),
+        }
+    }
+    fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<DeleteAppResult, DeleteAppError> {
+        match self.delete_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for delete_app"),
+        }
+    }
+    fn delete_branch(
+        &self,
+        input: DeleteBranchRequest,
+    ) -> RusotoFuture<DeleteBranchResult, DeleteBranchError> {
+        match self.delete_branch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for delete_branch"),
+        }
+    }
+    fn delete_domain_association(
+        &self,
+        input: DeleteDomainAssociationRequest,
+    ) -> RusotoFuture<DeleteDomainAssociationResult, DeleteDomainAssociationError> {
+        match self.delete_domain_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for delete_domain_association"),
+        }
+    }
+    fn delete_job(&self, input: DeleteJobRequest) -> RusotoFuture<DeleteJobResult, DeleteJobError> {
+        match self.delete_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for delete_job"),
+        }
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookRequest,
+    ) -> RusotoFuture<DeleteWebhookResult, DeleteWebhookError> {
+        match self.delete_webhook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for delete_webhook"),
+        }
+    }
+    fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResult, GetAppError> {
+        match self.get_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for get_app"),
+        }
+    }
+    fn get_branch(&self, input: GetBranchRequest) -> RusotoFuture<GetBranchResult, GetBranchError> {
+        match self.get_branch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for get_branch"),
+        }
+    }
+    fn get_domain_association(
+        &self,
+        input: GetDomainAssociationRequest,
+    ) -> RusotoFuture<GetDomainAssociationResult, GetDomainAssociationError> {
+        match self.get_domain_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for get_domain_association"),
+        }
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        match self.get_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for get_job"),
+        }
+    }
+    fn get_webhook(
+        &self,
+        input: GetWebhookRequest,
+    ) -> RusotoFuture<GetWebhookResult, GetWebhookError> {
+        match self.get_webhook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for get_webhook"),
+        }
+    }
+    fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResult, ListAppsError> {
+        match self.list_apps {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for list_apps"),
+        }
+    }
+    fn list_branches(
+        &self,
+        input: ListBranchesRequest,
+    ) -> RusotoFuture<ListBranchesResult, ListBranchesError> {
+        match self.list_branches {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for list_branches"),
+        }
+    }
+    fn list_domain_associations(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> RusotoFuture<ListDomainAssociationsResult, ListDomainAssociationsError> {
+        match self.list_domain_associations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for list_domain_associations"),
+        }
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        match self.list_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for list_jobs"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_webhooks(
+        &self,
+        input: ListWebhooksRequest,
+    ) -> RusotoFuture<ListWebhooksResult, ListWebhooksError> {
+        match self.list_webhooks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for list_webhooks"),
+        }
+    }
+    fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> RusotoFuture<StartDeploymentResult, StartDeploymentError> {
+        match self.start_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for start_deployment"),
+        }
+    }
     fn start_job(&self, input: StartJobRequest) -> RusotoFuture<StartJobResult, StartJobError> {
-        let request_uri = format!(
-            "/apps/{app_id}/branches/{branch_name}/jobs",
-            app_id = input.app_id,
-            branch_name = input.branch_name
-        );
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartJobResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartJobError::from_response(response))),
-                )
-            }
-        })
+        match self.start_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for start_job"),
+        }
     }
-
-    /// <p> Stop a job that is in progress, for an Amplify branch, part of Amplify App. </p>
     fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
-        let request_uri = format!(
-            "/apps/{app_id}/branches/{branch_name}/jobs/{job_id}/stop",
-            app_id = input.app_id,
-            branch_name = input.branch_name,
-            job_id = input.job_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopJobResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopJobError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for stop_job"),
+        }
     }
-
-    /// <p> Tag resource with tag key and value. </p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for tag_resource"),
+        }
     }
-
-    /// <p> Untag resource with resourceArn. </p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for untag_resource"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p> Updates an existing Amplify App. </p>
     fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<UpdateAppResult, UpdateAppError> {
-        let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAppResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAppError::from_response(response))),
-                )
-            }
-        })
+        match self.update_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for update_app"),
+        }
     }
-
-    /// <p> Updates a branch for an Amplify App. </p>
     fn update_branch(
         &self,
         input: UpdateBranchRequest,
     ) -> RusotoFuture<UpdateBranchResult, UpdateBranchError> {
-        let request_uri = format!(
-            "/apps/{app_id}/branches/{branch_name}",
-            app_id = input.app_id,
-            branch_name = input.branch_name
-        );
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateBranchResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateBranchError::from_response(response))),
-                )
-            }
-        })
+        match self.update_branch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for update_branch"),
+        }
     }
-
-    /// <p> Create a new DomainAssociation on an App </p>
     fn update_domain_association(
         &self,
         input: UpdateDomainAssociationRequest,
     ) -> RusotoFuture<UpdateDomainAssociationResult, UpdateDomainAssociationError> {
-        let request_uri = format!(
-            "/apps/{app_id}/domains/{domain_name}",
-            app_id = input.app_id,
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDomainAssociationResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateDomainAssociationError::from_response(response))
-                }))
-            }
-        })
+        match self.update_domain_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for update_domain_association"),
+        }
     }
-
-    /// <p> Update a webhook. </p>
     fn update_webhook(
         &self,
         input: UpdateWebhookRequest,
     ) -> RusotoFuture<UpdateWebhookResult, UpdateWebhookError> {
-        let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
-
-        let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateWebhookResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateWebhookError::from_response(response))),
-                )
-            }
-        })
+        match self.update_webhook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAmplify has no handler for update_webhook"),
+        }
     }
 }
@@ -23,10 +23,11 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Access log settings, including the access log format and access log destination ARN.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AccessLogSettings {
     /// <p>The ARN of the CloudWatch Logs log group to receive access logs.</p>
     #[serde(rename = "destinationArn")]
@@ -40,7 +41,7 @@ pub struct AccessLogSettings {
 
 /// <p><p>Represents an AWS account that is associated with API Gateway.</p> <div class="remarks"> <p>To view the account info, call <code>GET</code> on this resource.</p> <h4>Error Codes</h4> <p>The following exception may be thrown when the request fails.</p> <ul> <li>UnauthorizedException</li> <li>NotFoundException</li> <li>TooManyRequestsException</li> </ul> <p>For detailed error code information, including the corresponding HTTP Status Codes, see <a href="https://docs.aws.amazon.com/apigateway/api-reference/handling-errors/#api-error-codes">API Gateway Error Codes</a></p> <h4>Example: Get the information about an account.</h4> <h5>Request</h5> <pre><code>GET /account HTTP/1.1 Content-Type: application/json Host: apigateway.us-east-1.amazonaws.com X-Amz-Date: 20160531T184618Z Authorization: AWS4-HMAC-SHA256 Credential={access<em>key</em>ID}/us-east-1/apigateway/aws4<em>request, SignedHeaders=content-type;host;x-amz-date, Signature={sig4</em>hash} </code></pre> <h5>Response</h5> <p>The successful response returns a <code>200 OK</code> status code and a payload similar to the following:</p> <pre><code>{ &quot;_links&quot;: { &quot;curies&quot;: { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/account-apigateway-{rel}.html&quot;, &quot;name&quot;: &quot;account&quot;, &quot;templated&quot;: true }, &quot;self&quot;: { &quot;href&quot;: &quot;/account&quot; }, &quot;account:update&quot;: { &quot;href&quot;: &quot;/account&quot; } }, &quot;cloudwatchRoleArn&quot;: &quot;arn:aws:iam::123456789012:role/apigAwsProxyRole&quot;, &quot;throttleSettings&quot;: { &quot;rateLimit&quot;: 500, &quot;burstLimit&quot;: 1000 } } </code></pre> <p>In addition to making the REST API call directly, you can use the AWS CLI and an AWS SDK to access this resource.</p> </div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-limits.html">API Gateway Limits</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/welcome.html">Developer Guide</a>, <a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-account.html">AWS CLI</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Account {
     /// <p>The version of the API keys used for the account.</p>
     #[serde(rename = "apiKeyVersion")]
@@ -62,7 +63,7 @@ pub struct Account {
 
 /// <p><p>A resource that can be distributed to callers for executing <a>Method</a> resources that require an API key. API keys can be mapped to any <a>Stage</a> on any <a>RestApi</a>, which indicates that the callers with the API key can make requests to that stage.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-api-keys.html">Use API Keys</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApiKey {
     /// <p>The timestamp when the API Key was created.</p>
     #[serde(rename = "createdDate")]
@@ -108,7 +109,7 @@ pub struct ApiKey {
 
 /// <p>The identifier of an <a>ApiKey</a> used in a <a>UsagePlan</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApiKeyIds {
     /// <p>A list of all the <a>ApiKey</a> identifiers.</p>
     #[serde(rename = "ids")]
@@ -122,7 +123,7 @@ pub struct ApiKeyIds {
 
 /// <p><p>Represents a collection of API keys as represented by an <a>ApiKeys</a> resource.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-api-keys.html">Use API Keys</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApiKeys {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -156,7 +157,7 @@ pub struct ApiStage {
 
 /// <p><p>Represents an authorization layer for methods. If enabled on a method, API Gateway will activate the authorizer when a client calls the method.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-use-lambda-authorizer.html">Use Lambda Function as Authorizer</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-integrate-with-cognito.html">Use Cognito User Pool as Authorizer</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Authorizer {
     /// <p>Optional customer-defined field, used in OpenAPI imports and exports without functional impact.</p>
     #[serde(rename = "authType")]
@@ -202,7 +203,7 @@ pub struct Authorizer {
 
 /// <p><p>Represents a collection of <a>Authorizer</a> resources.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-use-lambda-authorizer.html">Use Lambda Function as Authorizer</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-integrate-with-cognito.html">Use Cognito User Pool as Authorizer</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Authorizers {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -215,7 +216,7 @@ pub struct Authorizers {
 
 /// <p><p>Represents the base path that callers of the API must provide as part of the URL after the domain name.</p> <div class="remarks">A custom domain name plus a <code>BasePathMapping</code> specification identifies a deployed <a>RestApi</a> in a given stage of the owner <a>Account</a>.</div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-custom-domains.html">Use Custom Domain Names</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BasePathMapping {
     /// <p>The base path name that callers of the API must provide as part of the URL after the domain name.</p>
     #[serde(rename = "basePath")]
@@ -233,7 +234,7 @@ pub struct BasePathMapping {
 
 /// <p><p>Represents a collection of <a>BasePathMapping</a> resources.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-custom-domains.html">Use Custom Domain Names</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BasePathMappings {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -267,7 +268,7 @@ pub struct CanarySettings {
 
 /// <p><p>Represents a client certificate used to configure client-side SSL authentication while sending requests to the integration endpoint.</p> <div class="remarks">Client certificates are used to authenticate an API by the backend server. To authenticate an API client (or user), use IAM roles and policies, a custom <a>Authorizer</a> or an Amazon Cognito user pool.</div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/getting-started-client-side-ssl-authentication.html">Use Client-Side Certificate</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClientCertificate {
     /// <p>The identifier of the client certificate.</p>
     #[serde(rename = "clientCertificateId")]
@@ -297,7 +298,7 @@ pub struct ClientCertificate {
 
 /// <p><p>Represents a collection of <a>ClientCertificate</a> resources.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/getting-started-client-side-ssl-authentication.html">Use Client-Side Certificate</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClientCertificates {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -310,6 +311,7 @@ pub struct ClientCertificates {
 
 /// <p>Request to create an <a>ApiKey</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApiKeyRequest {
     /// <p>An AWS Marketplace customer identifier , when integrating with the AWS SaaS Marketplace.</p>
     #[serde(rename = "customerId")]
@@ -347,6 +349,7 @@ pub struct CreateApiKeyRequest {
 
 /// <p>Request to add a new <a>Authorizer</a> to an existing <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAuthorizerRequest {
     /// <p>Optional customer-defined field, used in OpenAPI imports and exports without functional impact.</p>
     #[serde(rename = "authType")]
@@ -389,6 +392,7 @@ pub struct CreateAuthorizerRequest {
 
 /// <p>Requests API Gateway to create a new <a>BasePathMapping</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBasePathMappingRequest {
     /// <p>The base path name that callers of the API must provide as part of the URL after the domain name. This value must be unique for all of the mappings across a single API. Specify '(none)' if you do not want callers to specify a base path name after the domain name.</p>
     #[serde(rename = "basePath")]
@@ -408,6 +412,7 @@ pub struct CreateBasePathMappingRequest {
 
 /// <p>Requests API Gateway to create a <a>Deployment</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeploymentRequest {
     /// <p>Enables a cache cluster for the <a>Stage</a> resource specified in the input.</p>
     #[serde(rename = "cacheClusterEnabled")]
@@ -448,6 +453,7 @@ pub struct CreateDeploymentRequest {
 
 /// <p>Creates a new documentation part of a given API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDocumentationPartRequest {
     /// <p>[Required] The location of the targeted API entity of the to-be-created documentation part.</p>
     #[serde(rename = "location")]
@@ -460,8 +466,16 @@ pub struct CreateDocumentationPartRequest {
     pub rest_api_id: String,
 }
 
+impl Validate for CreateDocumentationPartRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.location
+            .validate_at(&field_path(path, "location"), errors);
+    }
+}
+
 /// <p>Creates a new documentation version of a given API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDocumentationVersionRequest {
     /// <p>A description about the new documentation snapshot.</p>
     #[serde(rename = "description")]
@@ -481,6 +495,7 @@ pub struct CreateDocumentationVersionRequest {
 
 /// <p>A request to create a new domain name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDomainNameRequest {
     /// <p>The reference to an AWS-managed certificate that will be used by edge-optimized endpoint for this domain name. AWS Certificate Manager is the only supported source.</p>
     #[serde(rename = "certificateArn")]
@@ -529,6 +544,7 @@ pub struct CreateDomainNameRequest {
 
 /// <p>Request to add a new <a>Model</a> to an existing <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateModelRequest {
     /// <p>[Required] The content-type for the model.</p>
     #[serde(rename = "contentType")]
@@ -551,6 +567,7 @@ pub struct CreateModelRequest {
 
 /// <p>Creates a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRequestValidatorRequest {
     /// <p>The name of the to-be-created <a>RequestValidator</a>.</p>
     #[serde(rename = "name")]
@@ -571,6 +588,7 @@ pub struct CreateRequestValidatorRequest {
 
 /// <p>Requests API Gateway to create a <a>Resource</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateResourceRequest {
     /// <p>[Required] The parent resource's identifier.</p>
     #[serde(rename = "parentId")]
@@ -585,6 +603,7 @@ pub struct CreateResourceRequest {
 
 /// <p>The POST Request to add a new <a>RestApi</a> resource to your collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRestApiRequest {
     /// <p>The source of the API key for metering requests according to a usage plan. Valid values are: <ul><li><code>HEADER</code> to read the API key from the <code>X-API-Key</code> header of a request. </li><li><code>AUTHORIZER</code> to read the API key from the <code>UsageIdentifierKey</code> from a custom authorizer.</li></ul> </p>
     #[serde(rename = "apiKeySource")]
@@ -629,6 +648,7 @@ pub struct CreateRestApiRequest {
 
 /// <p>Requests API Gateway to create a <a>Stage</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStageRequest {
     /// <p>Whether cache clustering is enabled for the stage.</p>
     #[serde(rename = "cacheClusterEnabled")]
@@ -675,6 +695,7 @@ pub struct CreateStageRequest {
 
 /// <p>The POST request to create a usage plan key for adding an existing API key to a usage plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUsagePlanKeyRequest {
     /// <p>[Required] The identifier of a <a>UsagePlanKey</a> resource for a plan customer.</p>
     #[serde(rename = "keyId")]
@@ -689,6 +710,7 @@ pub struct CreateUsagePlanKeyRequest {
 
 /// <p>The POST request to create a usage plan with the name, description, throttle limits and quota limits, as well as the associated API stages, specified in the payload.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUsagePlanRequest {
     /// <p>The associated API stages of the usage plan.</p>
     #[serde(rename = "apiStages")]
@@ -717,6 +739,7 @@ pub struct CreateUsagePlanRequest {
 
 /// <p>Creates a VPC link, under the caller's account in a selected region, in an asynchronous operation that typically takes 2-4 minutes to complete and become operational. The caller must have permissions to create and update VPC Endpoint services.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVpcLinkRequest {
     /// <p>The description of the VPC link.</p>
     #[serde(rename = "description")]
@@ -736,6 +759,7 @@ pub struct CreateVpcLinkRequest {
 
 /// <p>A request to delete the <a>ApiKey</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApiKeyRequest {
     /// <p>[Required] The identifier of the <a>ApiKey</a> resource to be deleted.</p>
     #[serde(rename = "apiKey")]
@@ -744,6 +768,7 @@ pub struct DeleteApiKeyRequest {
 
 /// <p>Request to delete an existing <a>Authorizer</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAuthorizerRequest {
     /// <p>[Required] The identifier of the <a>Authorizer</a> resource.</p>
     #[serde(rename = "authorizerId")]
@@ -755,6 +780,7 @@ pub struct DeleteAuthorizerRequest {
 
 /// <p>A request to delete the <a>BasePathMapping</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBasePathMappingRequest {
     /// <p>[Required] The base path name of the <a>BasePathMapping</a> resource to delete.</p> <p>To specify an empty base path, set this parameter to <code>'(none)'</code>.</p>
     #[serde(rename = "basePath")]
@@ -766,6 +792,7 @@ pub struct DeleteBasePathMappingRequest {
 
 /// <p>A request to delete the <a>ClientCertificate</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClientCertificateRequest {
     /// <p>[Required] The identifier of the <a>ClientCertificate</a> resource to be deleted.</p>
     #[serde(rename = "clientCertificateId")]
@@ -774,6 +801,7 @@ pub struct DeleteClientCertificateRequest {
 
 /// <p>Requests API Gateway to delete a <a>Deployment</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDeploymentRequest {
     /// <p>[Required] The identifier of the <a>Deployment</a> resource to delete.</p>
     #[serde(rename = "deploymentId")]
@@ -785,6 +813,7 @@ pub struct DeleteDeploymentRequest {
 
 /// <p>Deletes an existing documentation part of an API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDocumentationPartRequest {
     /// <p>[Required] The identifier of the to-be-deleted documentation part.</p>
     #[serde(rename = "documentationPartId")]
@@ -796,6 +825,7 @@ pub struct DeleteDocumentationPartRequest {
 
 /// <p>Deletes an existing documentation version of an API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDocumentationVersionRequest {
     /// <p>[Required] The version identifier of a to-be-deleted documentation snapshot.</p>
     #[serde(rename = "documentationVersion")]
@@ -807,6 +837,7 @@ pub struct DeleteDocumentationVersionRequest {
 
 /// <p>A request to delete the <a>DomainName</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDomainNameRequest {
     /// <p>[Required] The name of the <a>DomainName</a> resource to be deleted.</p>
     #[serde(rename = "domainName")]
@@ -815,6 +846,7 @@ pub struct DeleteDomainNameRequest {
 
 /// <p>Clears any customization of a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a> and resets it with the default settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGatewayResponseRequest {
     /// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
     #[serde(rename = "responseType")]
@@ -826,6 +858,7 @@ pub struct DeleteGatewayResponseRequest {
 
 /// <p>Represents a delete integration request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIntegrationRequest {
     /// <p>[Required] Specifies a delete integration request's HTTP method.</p>
     #[serde(rename = "httpMethod")]
@@ -840,6 +873,7 @@ pub struct DeleteIntegrationRequest {
 
 /// <p>Represents a delete integration response request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIntegrationResponseRequest {
     /// <p>[Required] Specifies a delete integration response request's HTTP method.</p>
     #[serde(rename = "httpMethod")]
@@ -855,8 +889,19 @@ pub struct DeleteIntegrationResponseRequest {
     pub status_code: String,
 }
 
+impl Validate for DeleteIntegrationResponseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "status_code"),
+            &self.status_code,
+            "[1-5]\\d\\d",
+        );
+    }
+}
+
 /// <p>Request to delete an existing <a>Method</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMethodRequest {
     /// <p>[Required] The HTTP verb of the <a>Method</a> resource.</p>
     #[serde(rename = "httpMethod")]
@@ -871,6 +916,7 @@ pub struct DeleteMethodRequest {
 
 /// <p>A request to delete an existing <a>MethodResponse</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMethodResponseRequest {
     /// <p>[Required] The HTTP verb of the <a>Method</a> resource.</p>
     #[serde(rename = "httpMethod")]
@@ -886,8 +932,19 @@ pub struct DeleteMethodResponseRequest {
     pub status_code: String,
 }
 
+impl Validate for DeleteMethodResponseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "status_code"),
+            &self.status_code,
+            "[1-5]\\d\\d",
+        );
+    }
+}
+
 /// <p>Request to delete an existing model in an existing <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteModelRequest {
     /// <p>[Required] The name of the model to delete.</p>
     #[serde(rename = "modelName")]
@@ -899,6 +956,7 @@ pub struct DeleteModelRequest {
 
 /// <p>Deletes a specified <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRequestValidatorRequest {
     /// <p>[Required] The identifier of the <a>RequestValidator</a> to be deleted.</p>
     #[serde(rename = "requestValidatorId")]
@@ -910,6 +968,7 @@ pub struct DeleteRequestValidatorRequest {
 
 /// <p>Request to delete a <a>Resource</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteResourceRequest {
     /// <p>[Required] The identifier of the <a>Resource</a> resource.</p>
     #[serde(rename = "resourceId")]
@@ -921,6 +980,7 @@ pub struct DeleteResourceRequest {
 
 /// <p>Request to delete the specified API from your collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRestApiRequest {
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
@@ -929,6 +989,7 @@ pub struct DeleteRestApiRequest {
 
 /// <p>Requests API Gateway to delete a <a>Stage</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStageRequest {
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
@@ -940,6 +1001,7 @@ pub struct DeleteStageRequest {
 
 /// <p>The DELETE request to delete a usage plan key and remove the underlying API key from the associated usage plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUsagePlanKeyRequest {
     /// <p>[Required] The Id of the <a>UsagePlanKey</a> resource to be deleted.</p>
     #[serde(rename = "keyId")]
@@ -951,6 +1013,7 @@ pub struct DeleteUsagePlanKeyRequest {
 
 /// <p>The DELETE request to delete a usage plan of a given plan Id.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUsagePlanRequest {
     /// <p>[Required] The Id of the to-be-deleted usage plan.</p>
     #[serde(rename = "usagePlanId")]
@@ -959,6 +1022,7 @@ pub struct DeleteUsagePlanRequest {
 
 /// <p>Deletes an existing <a>VpcLink</a> of a specified identifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVpcLinkRequest {
     /// <p>[Required] The identifier of the <a>VpcLink</a>. It is used in an <a>Integration</a> to reference this <a>VpcLink</a>.</p>
     #[serde(rename = "vpcLinkId")]
@@ -967,7 +1031,7 @@ pub struct DeleteVpcLinkRequest {
 
 /// <p><p>An immutable representation of a <a>RestApi</a> resource that can be called by users using <a>Stages</a>. A deployment must be associated with a <a>Stage</a> for it to be callable over the Internet.</p> <div class="remarks">To create a deployment, call <code>POST</code> on the <a>Deployments</a> resource of a <a>RestApi</a>. To view, update, or delete a deployment, call <code>GET</code>, <code>PATCH</code>, or <code>DELETE</code> on the specified deployment resource (<code>/restapis/{restapi<em>id}/deployments/{deployment</em>id}</code>).</div> <div class="seeAlso"><a>RestApi</a>, <a>Deployments</a>, <a>Stage</a>, <a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-deployment.html">AWS CLI</a>, <a href="https://aws.amazon.com/tools/">AWS SDKs</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Deployment {
     /// <p>A summary of the <a>RestApi</a> at the date and time that the deployment resource was created.</p>
     #[serde(rename = "apiSummary")]
@@ -991,6 +1055,7 @@ pub struct Deployment {
 
 /// <p>The input configuration for a canary deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeploymentCanarySettings {
     /// <p>The percentage (0.0-100.0) of traffic routed to the canary deployment.</p>
     #[serde(rename = "percentTraffic")]
@@ -1008,7 +1073,7 @@ pub struct DeploymentCanarySettings {
 
 /// <p><p>Represents a collection resource that contains zero or more references to your existing deployments, and links that guide you on how to interact with your collection. The collection offers a paginated view of the contained deployments.</p> <div class="remarks">To create a new deployment of a <a>RestApi</a>, make a <code>POST</code> request against this resource. To view, update, or delete an existing deployment, make a <code>GET</code>, <code>PATCH</code>, or <code>DELETE</code> request, respectively, on a specified <a>Deployment</a> resource.</div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-deploy-api.html">Deploying an API</a>, <a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-deployment.html">AWS CLI</a>, <a href="https://aws.amazon.com/tools/">AWS SDKs</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Deployments {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -1021,7 +1086,7 @@ pub struct Deployments {
 
 /// <p><p>A documentation part for a targeted API entity.</p> <div class="remarks"> <p>A documentation part consists of a content map (<code>properties</code>) and a target (<code>location</code>). The target specifies an API entity to which the documentation content applies. The supported API entity types are <code>API</code>, <code>AUTHORIZER</code>, <code>MODEL</code>, <code>RESOURCE</code>, <code>METHOD</code>, <code>PATH<em>PARAMETER</code>, <code>QUERY</em>PARAMETER</code>, <code>REQUEST<em>HEADER</code>, <code>REQUEST</em>BODY</code>, <code>RESPONSE</code>, <code>RESPONSE<em>HEADER</code>, and <code>RESPONSE</em>BODY</code>. Valid <code>location</code> fields depend on the API entity type. All valid fields are not required.</p> <p>The content map is a JSON string of API-specific key-value pairs. Although an API can use any shape for the content map, only the OpenAPI-compliant documentation fields will be injected into the associated API entity definition in the exported OpenAPI definition file.</p></div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-documenting-api.html">Documenting an API</a>, <a>DocumentationParts</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentationPart {
     /// <p>The <a>DocumentationPart</a> identifier, generated by API Gateway when the <code>DocumentationPart</code> is created.</p>
     #[serde(rename = "id")]
@@ -1039,7 +1104,7 @@ pub struct DocumentationPart {
 
 /// <p><p>A collection of the imported <a>DocumentationPart</a> identifiers.</p> <div class="remarks">This is used to return the result when documentation parts in an external (e.g., OpenAPI) file are imported into API Gateway</div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-documenting-api.html">Documenting an API</a>, <a href="https://docs.aws.amazon.com/apigateway/api-reference/link-relation/documentationpart-import/">documentationpart:import</a>, <a>DocumentationPart</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentationPartIds {
     /// <p>A list of the returned documentation part identifiers.</p>
     #[serde(rename = "ids")]
@@ -1075,9 +1140,21 @@ pub struct DocumentationPartLocation {
     pub type_: String,
 }
 
+impl Validate for DocumentationPartLocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.status_code {
+            errors.check_pattern(
+                &field_path(path, "status_code"),
+                &value,
+                "^([1-5]\\d\\d|\\*|\\s*)$",
+            );
+        }
+    }
+}
+
 /// <p><p>The collection of documentation parts of an API.</p> <div class="remarks"/> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-documenting-api.html">Documenting an API</a>, <a>DocumentationPart</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentationParts {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -1090,7 +1167,7 @@ pub struct DocumentationParts {
 
 /// <p><p>A snapshot of the documentation of an API.</p> <div class="remarks"><p>Publishing API documentation involves creating a documentation version associated with an API stage and exporting the versioned documentation to an external (e.g., OpenAPI) file.</p></div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-documenting-api.html">Documenting an API</a>, <a>DocumentationPart</a>, <a>DocumentationVersions</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentationVersion {
     /// <p>The date when the API documentation snapshot is created.</p>
     #[serde(rename = "createdDate")]
@@ -1108,7 +1185,7 @@ pub struct DocumentationVersion {
 
 /// <p><p>The collection of documentation snapshots of an API. </p> <div class="remarks"><p>Use the <a>DocumentationVersions</a> to manage documentation snapshots associated with various API stages.</p></div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-documenting-api.html">Documenting an API</a>, <a>DocumentationPart</a>, <a>DocumentationVersion</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentationVersions {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -1121,7 +1198,7 @@ pub struct DocumentationVersions {
 
 /// <p><p>Represents a custom domain name as a user-friendly host name of an API (<a>RestApi</a>).</p> <div class="Remarks"> <p>When you deploy an API, API Gateway creates a default host name for the API. This default API host name is of the <code>{restapi-id}.execute-api.{region}.amazonaws.com</code> format. With the default host name, you can access the API&#39;s root resource with the URL of <code>https://{restapi-id}.execute-api.{region}.amazonaws.com/{stage}/</code>. When you set up a custom domain name of <code>apis.example.com</code> for this API, you can then access the same resource using the URL of the <code>https://apis.examples.com/myApi</code>, where <code>myApi</code> is the base path mapping (<a>BasePathMapping</a>) of your API under the custom domain name. </p> </div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-custom-domains.html">Set a Custom Host Name for an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainName {
     /// <p>The reference to an AWS-managed certificate that will be used by edge-optimized endpoint for this domain name. AWS Certificate Manager is the only supported source.</p>
     #[serde(rename = "certificateArn")]
@@ -1187,7 +1264,7 @@ pub struct DomainName {
 
 /// <p><p>Represents a collection of <a>DomainName</a> resources.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-custom-domains.html">Use Client-Side Certificate</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainNames {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -1220,6 +1297,7 @@ pub struct ExportResponse {
 
 /// <p>Request to flush authorizer cache entries on a specified stage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FlushStageAuthorizersCacheRequest {
     /// <p>The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
@@ -1231,6 +1309,7 @@ pub struct FlushStageAuthorizersCacheRequest {
 
 /// <p>Requests API Gateway to flush a stage's cache.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FlushStageCacheRequest {
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
@@ -1242,7 +1321,7 @@ pub struct FlushStageCacheRequest {
 
 /// <p><p>A gateway response of a given response type and status code, with optional response parameters and mapping templates.</p> <div class="remarks"> For more information about valid gateway response types, see <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/supported-gateway-response-types.html">Gateway Response Types Supported by API Gateway</a> <div class="example"> <h4>Example: Get a Gateway Response of a given response type</h4> <h5>Request</h5> <p>This example shows how to get a gateway response of the <code>MISSING<em>AUTHENTICATION</em>TOKEN</code> type.</p> <pre><code>GET /restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN HTTP/1.1 Host: beta-apigateway.us-east-1.amazonaws.com Content-Type: application/json X-Amz-Date: 20170503T202516Z Authorization: AWS4-HMAC-SHA256 Credential={access-key-id}/20170503/us-east-1/apigateway/aws4<em>request, SignedHeaders=content-type;host;x-amz-date, Signature=1b52460e3159c1a26cff29093855d50ea141c1c5b937528fecaf60f51129697a Cache-Control: no-cache Postman-Token: 3b2a1ce9-c848-2e26-2e2f-9c2caefbed45 </code></pre> <p>The response type is specified as a URL path.</p> <h5>Response</h5> <p>The successful operation returns the <code>200 OK</code> status code and a payload similar to the following:</p> <pre><code>{ &quot;</em>links&quot;: { &quot;curies&quot;: { &quot;href&quot;: &quot;http://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-gatewayresponse-{rel}.html&quot;, &quot;name&quot;: &quot;gatewayresponse&quot;, &quot;templated&quot;: true }, &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN&quot; }, &quot;gatewayresponse:delete&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING</em>AUTHENTICATION<em>TOKEN&quot; } }, &quot;defaultResponse&quot;: false, &quot;responseParameters&quot;: { &quot;gatewayresponse.header.x-request-path&quot;: &quot;method.request.path.petId&quot;, &quot;gatewayresponse.header.Access-Control-Allow-Origin&quot;: &quot;&apos;a.b.c&apos;&quot;, &quot;gatewayresponse.header.x-request-query&quot;: &quot;method.request.querystring.q&quot;, &quot;gatewayresponse.header.x-request-header&quot;: &quot;method.request.header.Accept&quot; }, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{\n &quot;message&quot;: $context.error.messageString,\n &quot;type&quot;: &quot;$context.error.responseType&quot;,\n &quot;stage&quot;: &quot;$context.stage&quot;,\n &quot;resourcePath&quot;: &quot;$context.resourcePath&quot;,\n &quot;stageVariables.a&quot;: &quot;$stageVariables.a&quot;,\n &quot;statusCode&quot;: &quot;&apos;404&apos;&quot;\n}&quot; }, &quot;responseType&quot;: &quot;MISSING</em>AUTHENTICATION_TOKEN&quot;, &quot;statusCode&quot;: &quot;404&quot; }</code></pre> <p></p> </div> </div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/customize-gateway-responses.html">Customize Gateway Responses</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GatewayResponse {
     /// <p>A Boolean flag to indicate whether this <a>GatewayResponse</a> is the default gateway response (<code>true</code>) or not (<code>false</code>). A default gateway response is one generated by API Gateway without any customization by an API developer. </p>
     #[serde(rename = "defaultResponse")]
@@ -1268,7 +1347,7 @@ pub struct GatewayResponse {
 
 /// <p><p>The collection of the <a>GatewayResponse</a> instances of a <a>RestApi</a> as a <code>responseType</code>-to-<a>GatewayResponse</a> object map of key-value pairs. As such, pagination is not supported for querying this collection.</p> <div class="remarks"> For more information about valid gateway response types, see <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/supported-gateway-response-types.html">Gateway Response Types Supported by API Gateway</a> <div class="example"> <h4>Example: Get the collection of gateway responses of an API</h4> <h5>Request</h5> <p>This example request shows how to retrieve the <a>GatewayResponses</a> collection from an API.</p> <pre><code>GET /restapis/o81lxisefl/gatewayresponses HTTP/1.1 Host: beta-apigateway.us-east-1.amazonaws.com Content-Type: application/json X-Amz-Date: 20170503T220604Z Authorization: AWS4-HMAC-SHA256 Credential={access-key-id}/20170503/us-east-1/apigateway/aws4<em>request, SignedHeaders=content-type;host;x-amz-date, Signature=59b42fe54a76a5de8adf2c67baa6d39206f8e9ad49a1d77ccc6a5da3103a398a Cache-Control: no-cache Postman-Token: 5637af27-dc29-fc5c-9dfe-0645d52cb515 </code></pre> <p></p> <h5>Response</h5> <p>The successful operation returns the <code>200 OK</code> status code and a payload similar to the following:</p> <pre><code>{ &quot;</em>links&quot;: { &quot;curies&quot;: { &quot;href&quot;: &quot;http://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-gatewayresponse-{rel}.html&quot;, &quot;name&quot;: &quot;gatewayresponse&quot;, &quot;templated&quot;: true }, &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses&quot; }, &quot;first&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses&quot; }, &quot;gatewayresponse:by-type&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;item&quot;: [ { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION</em>FAILURE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/RESOURCE<em>NOT</em>FOUND&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/REQUEST<em>TOO</em>LARGE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/THROTTLED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNSUPPORTED<em>MEDIA</em>TYPE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>CONFIGURATION</em>ERROR&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT<em>5XX&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT</em>4XX&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>PARAMETERS&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>BODY&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/EXPIRED<em>TOKEN&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/ACCESS</em>DENIED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID<em>API</em>KEY&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNAUTHORIZED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/API<em>CONFIGURATION</em>ERROR&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/QUOTA<em>EXCEEDED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION</em>TIMEOUT&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID<em>SIGNATURE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER</em>FAILURE&quot; } ] }, &quot;<em>embedded&quot;: { &quot;item&quot;: [ { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>FAILURE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>FAILURE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INTEGRATION</em>FAILURE&quot;, &quot;statusCode&quot;: &quot;504&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/RESOURCE</em>NOT<em>FOUND&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/RESOURCE<em>NOT</em>FOUND&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;RESOURCE<em>NOT</em>FOUND&quot;, &quot;statusCode&quot;: &quot;404&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/REQUEST</em>TOO<em>LARGE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/REQUEST<em>TOO</em>LARGE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;REQUEST<em>TOO</em>LARGE&quot;, &quot;statusCode&quot;: &quot;413&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/THROTTLED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/THROTTLED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;THROTTLED&quot;, &quot;statusCode&quot;: &quot;429&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNSUPPORTED</em>MEDIA<em>TYPE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNSUPPORTED<em>MEDIA</em>TYPE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;UNSUPPORTED<em>MEDIA</em>TYPE&quot;, &quot;statusCode&quot;: &quot;415&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER</em>CONFIGURATION<em>ERROR&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>CONFIGURATION</em>ERROR&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;AUTHORIZER<em>CONFIGURATION</em>ERROR&quot;, &quot;statusCode&quot;: &quot;500&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT</em>5XX&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT</em>5XX&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;DEFAULT<em>5XX&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT<em>4XX&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT<em>4XX&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;DEFAULT</em>4XX&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD</em>REQUEST<em>PARAMETERS&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>PARAMETERS&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;BAD<em>REQUEST</em>PARAMETERS&quot;, &quot;statusCode&quot;: &quot;400&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD</em>REQUEST<em>BODY&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>BODY&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;BAD<em>REQUEST</em>BODY&quot;, &quot;statusCode&quot;: &quot;400&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/EXPIRED</em>TOKEN&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/EXPIRED</em>TOKEN&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;EXPIRED<em>TOKEN&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/ACCESS<em>DENIED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/ACCESS<em>DENIED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;ACCESS</em>DENIED&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID</em>API<em>KEY&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID<em>API</em>KEY&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INVALID<em>API</em>KEY&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNAUTHORIZED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNAUTHORIZED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;UNAUTHORIZED&quot;, &quot;statusCode&quot;: &quot;401&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/API</em>CONFIGURATION<em>ERROR&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/API<em>CONFIGURATION</em>ERROR&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;API<em>CONFIGURATION</em>ERROR&quot;, &quot;statusCode&quot;: &quot;500&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/QUOTA</em>EXCEEDED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/QUOTA</em>EXCEEDED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;QUOTA<em>EXCEEDED&quot;, &quot;statusCode&quot;: &quot;429&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>TIMEOUT&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>TIMEOUT&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INTEGRATION</em>TIMEOUT&quot;, &quot;statusCode&quot;: &quot;504&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING</em>AUTHENTICATION<em>TOKEN&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;MISSING<em>AUTHENTICATION</em>TOKEN&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID</em>SIGNATURE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID</em>SIGNATURE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INVALID<em>SIGNATURE&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>FAILURE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>FAILURE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;AUTHORIZER</em>FAILURE&quot;, &quot;statusCode&quot;: &quot;500&quot; } ] } }</code></pre> <p></p> </div> </div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/customize-gateway-responses.html">Customize Gateway Responses</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GatewayResponses {
     /// <p>Returns the entire collection, because of no pagination support.</p>
     #[serde(rename = "items")]
@@ -1281,6 +1360,7 @@ pub struct GatewayResponses {
 
 /// <p>A request to generate a <a>ClientCertificate</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GenerateClientCertificateRequest {
     /// <p>The description of the <a>ClientCertificate</a>.</p>
     #[serde(rename = "description")]
@@ -1294,10 +1374,12 @@ pub struct GenerateClientCertificateRequest {
 
 /// <p>Requests API Gateway to get information about the current <a>Account</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccountRequest {}
 
 /// <p>A request to get information about the current <a>ApiKey</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetApiKeyRequest {
     /// <p>[Required] The identifier of the <a>ApiKey</a> resource.</p>
     #[serde(rename = "apiKey")]
@@ -1310,6 +1392,7 @@ pub struct GetApiKeyRequest {
 
 /// <p>A request to get information about the current <a>ApiKeys</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetApiKeysRequest {
     /// <p>The identifier of a customer in AWS Marketplace or an external system, such as a developer portal.</p>
     #[serde(rename = "customerId")]
@@ -1335,6 +1418,7 @@ pub struct GetApiKeysRequest {
 
 /// <p>Request to describe an existing <a>Authorizer</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAuthorizerRequest {
     /// <p>[Required] The identifier of the <a>Authorizer</a> resource.</p>
     #[serde(rename = "authorizerId")]
@@ -1346,6 +1430,7 @@ pub struct GetAuthorizerRequest {
 
 /// <p>Request to describe an existing <a>Authorizers</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAuthorizersRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1362,6 +1447,7 @@ pub struct GetAuthorizersRequest {
 
 /// <p>Request to describe a <a>BasePathMapping</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBasePathMappingRequest {
     /// <p>[Required] The base path name that callers of the API must provide as part of the URL after the domain name. This value must be unique for all of the mappings across a single API. Specify '(none)' if you do not want callers to specify any base path name after the domain name.</p>
     #[serde(rename = "basePath")]
@@ -1373,6 +1459,7 @@ pub struct GetBasePathMappingRequest {
 
 /// <p>A request to get information about a collection of <a>BasePathMapping</a> resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBasePathMappingsRequest {
     /// <p>[Required] The domain name of a <a>BasePathMapping</a> resource.</p>
     #[serde(rename = "domainName")]
@@ -1389,6 +1476,7 @@ pub struct GetBasePathMappingsRequest {
 
 /// <p>A request to get information about the current <a>ClientCertificate</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetClientCertificateRequest {
     /// <p>[Required] The identifier of the <a>ClientCertificate</a> resource to be described.</p>
     #[serde(rename = "clientCertificateId")]
@@ -1397,6 +1485,7 @@ pub struct GetClientCertificateRequest {
 
 /// <p>A request to get information about a collection of <a>ClientCertificate</a> resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetClientCertificatesRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1410,6 +1499,7 @@ pub struct GetClientCertificatesRequest {
 
 /// <p>Requests API Gateway to get information about a <a>Deployment</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeploymentRequest {
     /// <p>[Required] The identifier of the <a>Deployment</a> resource to get information about.</p>
     #[serde(rename = "deploymentId")]
@@ -1425,6 +1515,7 @@ pub struct GetDeploymentRequest {
 
 /// <p>Requests API Gateway to get information about a <a>Deployments</a> collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeploymentsRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1441,6 +1532,7 @@ pub struct GetDeploymentsRequest {
 
 /// <p>Gets a specified documentation part of a given API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDocumentationPartRequest {
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "documentationPartId")]
@@ -1452,6 +1544,7 @@ pub struct GetDocumentationPartRequest {
 
 /// <p>Gets the documentation parts of an API. The result may be filtered by the type, name, or path of API entities (targets).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDocumentationPartsRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1484,6 +1577,7 @@ pub struct GetDocumentationPartsRequest {
 
 /// <p>Gets a documentation snapshot of an API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDocumentationVersionRequest {
     /// <p>[Required] The version identifier of the to-be-retrieved documentation snapshot.</p>
     #[serde(rename = "documentationVersion")]
@@ -1495,6 +1589,7 @@ pub struct GetDocumentationVersionRequest {
 
 /// <p>Gets the documentation versions of an API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDocumentationVersionsRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1511,6 +1606,7 @@ pub struct GetDocumentationVersionsRequest {
 
 /// <p>Request to get the name of a <a>DomainName</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDomainNameRequest {
     /// <p>[Required] The name of the <a>DomainName</a> resource.</p>
     #[serde(rename = "domainName")]
@@ -1519,6 +1615,7 @@ pub struct GetDomainNameRequest {
 
 /// <p>Request to describe a collection of <a>DomainName</a> resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDomainNamesRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1532,6 +1629,7 @@ pub struct GetDomainNamesRequest {
 
 /// <p>Request a new export of a <a>RestApi</a> for a particular <a>Stage</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetExportRequest {
     /// <p>The content-type of the export, for example <code>application/json</code>. Currently <code>application/json</code> and <code>application/yaml</code> are supported for <code>exportType</code> of<code>oas30</code> and <code>swagger</code>. This should be specified in the <code>Accept</code> header for direct API requests.</p>
     #[serde(rename = "accepts")]
@@ -1554,6 +1652,7 @@ pub struct GetExportRequest {
 
 /// <p>Gets a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGatewayResponseRequest {
     /// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
     #[serde(rename = "responseType")]
@@ -1565,6 +1664,7 @@ pub struct GetGatewayResponseRequest {
 
 /// <p>Gets the <a>GatewayResponses</a> collection on the given <a>RestApi</a>. If an API developer has not added any definitions for gateway responses, the result will be the API Gateway-generated default <a>GatewayResponses</a> collection for the supported response types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGatewayResponsesRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500. The <a>GatewayResponses</a> collection does not support pagination and the limit does not apply here.</p>
     #[serde(rename = "limit")]
@@ -1581,6 +1681,7 @@ pub struct GetGatewayResponsesRequest {
 
 /// <p>Represents a request to get the integration configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIntegrationRequest {
     /// <p>[Required] Specifies a get integration request's HTTP method.</p>
     #[serde(rename = "httpMethod")]
@@ -1595,6 +1696,7 @@ pub struct GetIntegrationRequest {
 
 /// <p>Represents a get integration response request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIntegrationResponseRequest {
     /// <p>[Required] Specifies a get integration response request's HTTP method.</p>
     #[serde(rename = "httpMethod")]
@@ -1610,8 +1712,19 @@ pub struct GetIntegrationResponseRequest {
     pub status_code: String,
 }
 
+impl Validate for GetIntegrationResponseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "status_code"),
+            &self.status_code,
+            "[1-5]\\d\\d",
+        );
+    }
+}
+
 /// <p>Request to describe an existing <a>Method</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMethodRequest {
     /// <p>[Required] Specifies the method request's HTTP method type.</p>
     #[serde(rename = "httpMethod")]
@@ -1626,6 +1739,7 @@ pub struct GetMethodRequest {
 
 /// <p>Request to describe a <a>MethodResponse</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMethodResponseRequest {
     /// <p>[Required] The HTTP verb of the <a>Method</a> resource.</p>
     #[serde(rename = "httpMethod")]
@@ -1641,8 +1755,19 @@ pub struct GetMethodResponseRequest {
     pub status_code: String,
 }
 
+impl Validate for GetMethodResponseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "status_code"),
+            &self.status_code,
+            "[1-5]\\d\\d",
+        );
+    }
+}
+
 /// <p>Request to list information about a model in an existing <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetModelRequest {
     /// <p>A query parameter of a Boolean value to resolve (<code>true</code>) all external model references and returns a flattened model schema or not (<code>false</code>) The default is <code>false</code>.</p>
     #[serde(rename = "flatten")]
@@ -1658,6 +1783,7 @@ pub struct GetModelRequest {
 
 /// <p>Request to generate a sample mapping template used to transform the payload.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetModelTemplateRequest {
     /// <p>[Required] The name of the model for which to generate a template.</p>
     #[serde(rename = "modelName")]
@@ -1669,6 +1795,7 @@ pub struct GetModelTemplateRequest {
 
 /// <p>Request to list existing <a>Models</a> defined for a <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetModelsRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1685,6 +1812,7 @@ pub struct GetModelsRequest {
 
 /// <p>Gets a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRequestValidatorRequest {
     /// <p>[Required] The identifier of the <a>RequestValidator</a> to be retrieved.</p>
     #[serde(rename = "requestValidatorId")]
@@ -1696,6 +1824,7 @@ pub struct GetRequestValidatorRequest {
 
 /// <p>Gets the <a>RequestValidators</a> collection of a given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRequestValidatorsRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1712,6 +1841,7 @@ pub struct GetRequestValidatorsRequest {
 
 /// <p>Request to list information about a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourceRequest {
     /// <p>A query parameter to retrieve the specified resources embedded in the returned <a>Resource</a> representation in the response. This <code>embed</code> parameter value is a list of comma-separated strings. Currently, the request supports only retrieval of the embedded <a>Method</a> resources this way. The query parameter value must be a single-valued list and contain the <code>"methods"</code> string. For example, <code>GET /restapis/{restapi_id}/resources/{resource_id}?embed=methods</code>.</p>
     #[serde(rename = "embed")]
@@ -1727,6 +1857,7 @@ pub struct GetResourceRequest {
 
 /// <p>Request to list information about a collection of resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourcesRequest {
     /// <p>A query parameter used to retrieve the specified resources embedded in the returned <a>Resources</a> resource in the response. This <code>embed</code> parameter value is a list of comma-separated strings. Currently, the request supports only retrieval of the embedded <a>Method</a> resources this way. The query parameter value must be a single-valued list and contain the <code>"methods"</code> string. For example, <code>GET /restapis/{restapi_id}/resources?embed=methods</code>.</p>
     #[serde(rename = "embed")]
@@ -1747,6 +1878,7 @@ pub struct GetResourcesRequest {
 
 /// <p>The GET request to list an existing <a>RestApi</a> defined for your collection. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRestApiRequest {
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
@@ -1755,6 +1887,7 @@ pub struct GetRestApiRequest {
 
 /// <p>The GET request to list existing <a>RestApis</a> defined for your collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRestApisRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1768,6 +1901,7 @@ pub struct GetRestApisRequest {
 
 /// <p>Request a new generated client SDK for a <a>RestApi</a> and <a>Stage</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSdkRequest {
     /// <p>A string-to-string key-value map of query parameters <code>sdkType</code>-dependent properties of the SDK. For <code>sdkType</code> of <code>objectivec</code> or <code>swift</code>, a parameter named <code>classPrefix</code> is required. For <code>sdkType</code> of <code>android</code>, parameters named <code>groupId</code>, <code>artifactId</code>, <code>artifactVersion</code>, and <code>invokerPackage</code> are required. For <code>sdkType</code> of <code>java</code>, parameters named <code>serviceName</code> and <code>javaPackageName</code> are required. </p>
     #[serde(rename = "parameters")]
@@ -1786,6 +1920,7 @@ pub struct GetSdkRequest {
 
 /// <p>Get an <a>SdkType</a> instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSdkTypeRequest {
     /// <p>[Required] The identifier of the queried <a>SdkType</a> instance.</p>
     #[serde(rename = "id")]
@@ -1794,6 +1929,7 @@ pub struct GetSdkTypeRequest {
 
 /// <p>Get the <a>SdkTypes</a> collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSdkTypesRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1807,6 +1943,7 @@ pub struct GetSdkTypesRequest {
 
 /// <p>Requests API Gateway to get information about a <a>Stage</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStageRequest {
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
@@ -1818,6 +1955,7 @@ pub struct GetStageRequest {
 
 /// <p>Requests API Gateway to get information about one or more <a>Stage</a> resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStagesRequest {
     /// <p>The stages' deployment identifiers.</p>
     #[serde(rename = "deploymentId")]
@@ -1830,6 +1968,7 @@ pub struct GetStagesRequest {
 
 /// <p>Gets the <a>Tags</a> collection for a given resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTagsRequest {
     /// <p>(Not currently supported) The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1846,6 +1985,7 @@ pub struct GetTagsRequest {
 
 /// <p>The GET request to get a usage plan key of a given key identifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUsagePlanKeyRequest {
     /// <p>[Required] The key Id of the to-be-retrieved <a>UsagePlanKey</a> resource representing a plan customer.</p>
     #[serde(rename = "keyId")]
@@ -1857,6 +1997,7 @@ pub struct GetUsagePlanKeyRequest {
 
 /// <p>The GET request to get all the usage plan keys representing the API keys added to a specified usage plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUsagePlanKeysRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1877,6 +2018,7 @@ pub struct GetUsagePlanKeysRequest {
 
 /// <p>The GET request to get a usage plan of a given plan identifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUsagePlanRequest {
     /// <p>[Required] The identifier of the <a>UsagePlan</a> resource to be retrieved.</p>
     #[serde(rename = "usagePlanId")]
@@ -1885,6 +2027,7 @@ pub struct GetUsagePlanRequest {
 
 /// <p>The GET request to get all the usage plans of the caller's account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUsagePlansRequest {
     /// <p>The identifier of the API key associated with the usage plans.</p>
     #[serde(rename = "keyId")]
@@ -1902,6 +2045,7 @@ pub struct GetUsagePlansRequest {
 
 /// <p>The GET request to get the usage data of a usage plan in a specified time interval.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUsageRequest {
     /// <p>[Required] The ending date (e.g., 2016-12-31) of the usage data.</p>
     #[serde(rename = "endDate")]
@@ -1928,6 +2072,7 @@ pub struct GetUsageRequest {
 
 /// <p>Gets a specified VPC link under the caller's account in a region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVpcLinkRequest {
     /// <p>[Required] The identifier of the <a>VpcLink</a>. It is used in an <a>Integration</a> to reference this <a>VpcLink</a>.</p>
     #[serde(rename = "vpcLinkId")]
@@ -1936,6 +2081,7 @@ pub struct GetVpcLinkRequest {
 
 /// <p>Gets the <a>VpcLinks</a> collection under the caller's account in a selected region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVpcLinksRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
@@ -1949,6 +2095,7 @@ pub struct GetVpcLinksRequest {
 
 /// <p>The POST request to import API keys from an external source, such as a CSV-formatted file.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportApiKeysRequest {
     /// <p>The payload of the POST request to import API keys. For the payload format, see <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-key-file-format.html">API Key File Format</a>.</p>
     #[serde(rename = "body")]
@@ -1969,6 +2116,7 @@ pub struct ImportApiKeysRequest {
 
 /// <p>Import documentation parts from an external (e.g., OpenAPI) definition file. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportDocumentationPartsRequest {
     /// <p>[Required] Raw byte array representing the to-be-imported documentation parts. To import from an OpenAPI file, this is a JSON object.</p>
     #[serde(rename = "body")]
@@ -1993,6 +2141,7 @@ pub struct ImportDocumentationPartsRequest {
 
 /// <p>A POST request to import an API to API Gateway using an input of an API definition file.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportRestApiRequest {
     /// <p>[Required] The POST request body containing external API definitions. Currently, only OpenAPI definition JSON/YAML files are supported. The maximum size of the API definition file is 2MB.</p>
     #[serde(rename = "body")]
@@ -2014,7 +2163,7 @@ pub struct ImportRestApiRequest {
 
 /// <p><p>Represents an HTTP, HTTP<em>PROXY, AWS, AWS</em>PROXY, or Mock integration.</p> <div class="remarks">In the API Gateway console, the built-in Lambda integration is an AWS integration.</div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-create-api.html">Creating an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Integration {
     /// <p>A list of request parameters whose values API Gateway caches. To be valid values for <code>cacheKeyParameters</code>, these parameters must also be specified for <a>Method</a> <code>requestParameters</code>.</p>
     #[serde(rename = "cacheKeyParameters")]
@@ -2076,7 +2225,7 @@ pub struct Integration {
 
 /// <p><p>Represents an integration response. The status code must map to an existing <a>MethodResponse</a>, and parameters and templates can be used to transform the back-end response.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-create-api.html">Creating an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IntegrationResponse {
     /// <p>Specifies how to handle response payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a response payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the response payload will be passed through from the integration response to the method response without modification.</p>
     #[serde(rename = "contentHandling")]
@@ -2102,7 +2251,7 @@ pub struct IntegrationResponse {
 
 /// <p><p> Represents a client-facing interface by which the client calls the API to access back-end resources. A <b>Method</b> resource is integrated with an <a>Integration</a> resource. Both consist of a request and one or more responses. The method request takes the client input that is passed to the back end through the integration request. A method response returns the output from the back end to the client through an integration response. A method request is embodied in a <b>Method</b> resource, whereas an integration request is embodied in an <a>Integration</a> resource. On the other hand, a method response is represented by a <a>MethodResponse</a> resource, whereas an integration response is represented by an <a>IntegrationResponse</a> resource. </p> <div class="remarks"> <p/> <h4>Example: Retrive the GET method on a specified resource</h4> <h5>Request</h5> <p>The following example request retrieves the information about the GET method on an API resource (<code>3kzxbg5sa2</code>) of an API (<code>fugvjdxtri</code>). </p> <pre><code>GET /restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET HTTP/1.1 Content-Type: application/json Host: apigateway.us-east-1.amazonaws.com X-Amz-Date: 20160603T210259Z Authorization: AWS4-HMAC-SHA256 Credential={access<em>key</em>ID}/20160603/us-east-1/apigateway/aws4<em>request, SignedHeaders=content-type;host;x-amz-date, Signature={sig4</em>hash}</code></pre> <h5>Response</h5> <p>The successful response returns a <code>200 OK</code> status code and a payload similar to the following:</p> <pre><code>{ &quot;<em>links&quot;: { &quot;curies&quot;: [ { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-integration-{rel}.html&quot;, &quot;name&quot;: &quot;integration&quot;, &quot;templated&quot;: true }, { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-integration-response-{rel}.html&quot;, &quot;name&quot;: &quot;integrationresponse&quot;, &quot;templated&quot;: true }, { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-method-{rel}.html&quot;, &quot;name&quot;: &quot;method&quot;, &quot;templated&quot;: true }, { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-method-response-{rel}.html&quot;, &quot;name&quot;: &quot;methodresponse&quot;, &quot;templated&quot;: true } ], &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET&quot;, &quot;name&quot;: &quot;GET&quot;, &quot;title&quot;: &quot;GET&quot; }, &quot;integration:put&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;method:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET&quot; }, &quot;method:integration&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;method:responses&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;method:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET&quot; }, &quot;methodresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/{status</em>code}&quot;, &quot;templated&quot;: true } }, &quot;apiKeyRequired&quot;: true, &quot;authorizationType&quot;: &quot;NONE&quot;, &quot;httpMethod&quot;: &quot;GET&quot;, &quot;<em>embedded&quot;: { &quot;method:integration&quot;: { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;integration:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;integration:responses&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;integration:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;integrationresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/{status<em>code}&quot;, &quot;templated&quot;: true } }, &quot;cacheKeyParameters&quot;: [], &quot;cacheNamespace&quot;: &quot;3kzxbg5sa2&quot;, &quot;credentials&quot;: &quot;arn:aws:iam::123456789012:role/apigAwsProxyRole&quot;, &quot;httpMethod&quot;: &quot;POST&quot;, &quot;passthroughBehavior&quot;: &quot;WHEN</em>NO<em>MATCH&quot;, &quot;requestParameters&quot;: { &quot;integration.request.header.Content-Type&quot;: &quot;&#39;application/x-amz-json-1.1&#39;&quot; }, &quot;requestTemplates&quot;: { &quot;application/json&quot;: &quot;{\n}&quot; }, &quot;type&quot;: &quot;AWS&quot;, &quot;uri&quot;: &quot;arn:aws:apigateway:us-east-1:kinesis:action/ListStreams&quot;, &quot;</em>embedded&quot;: { &quot;integration:responses&quot;: { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;integrationresponse:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot; }, &quot;integrationresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot; } }, &quot;responseParameters&quot;: { &quot;method.response.header.Content-Type&quot;: &quot;&#39;application/xml&#39;&quot; }, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;$util.urlDecode(&quot;%3CkinesisStreams%3E%23foreach(%24stream%20in%20%24input.path(%27%24.StreamNames%27))%3Cstream%3E%3Cname%3E%24stream%3C%2Fname%3E%3C%2Fstream%3E%23end%3C%2FkinesisStreams%3E&quot;)&quot; }, &quot;statusCode&quot;: &quot;200&quot; } } }, &quot;method:responses&quot;: { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;methodresponse:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot; }, &quot;methodresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot; } }, &quot;responseModels&quot;: { &quot;application/json&quot;: &quot;Empty&quot; }, &quot;responseParameters&quot;: { &quot;method.response.header.Content-Type&quot;: false }, &quot;statusCode&quot;: &quot;200&quot; } } }</code></pre> <p>In the example above, the response template for the <code>200 OK</code> response maps the JSON output from the <code>ListStreams</code> action in the back end to an XML output. The mapping template is URL-encoded as <code>%3CkinesisStreams%3E%23foreach(%24stream%20in%20%24input.path(%27%24.StreamNames%27))%3Cstream%3E%3Cname%3E%24stream%3C%2Fname%3E%3C%2Fstream%3E%23end%3C%2FkinesisStreams%3E</code> and the output is decoded using the <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-mapping-template-reference.html#util-templat-reference">$util.urlDecode()</a> helper function.</p> </div> <div class="seeAlso"> <a>MethodResponse</a>, <a>Integration</a>, <a>IntegrationResponse</a>, <a>Resource</a>, <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-method-settings.html">Set up an API&#39;s method</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Method {
     /// <p>A boolean flag specifying whether a valid <a>ApiKey</a> is required to invoke this method.</p>
     #[serde(rename = "apiKeyRequired")]
@@ -2152,7 +2301,7 @@ pub struct Method {
 
 /// <p><p>Represents a method response of a given HTTP status code returned to the client. The method response is passed from the back end through the associated integration response that can be transformed using a mapping template. </p> <div class="remarks"> <p/> <h4>Example: A <b>MethodResponse</b> instance of an API</h4> <h5>Request</h5> <p>The example request retrieves a <b>MethodResponse</b> of the 200 status code.</p> <pre><code>GET /restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200 HTTP/1.1 Content-Type: application/json Host: apigateway.us-east-1.amazonaws.com X-Amz-Date: 20160603T222952Z Authorization: AWS4-HMAC-SHA256 Credential={access<em>key</em>ID}/20160603/us-east-1/apigateway/aws4<em>request, SignedHeaders=content-type;host;x-amz-date, Signature={sig4</em>hash}</code></pre> <h5>Response</h5> <p>The successful response returns <code>200 OK</code> status and a payload as follows:</p> <pre><code>{ &quot;_links&quot;: { &quot;curies&quot;: { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-method-response-{rel}.html&quot;, &quot;name&quot;: &quot;methodresponse&quot;, &quot;templated&quot;: true }, &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;methodresponse:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot; }, &quot;methodresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot; } }, &quot;responseModels&quot;: { &quot;application/json&quot;: &quot;Empty&quot; }, &quot;responseParameters&quot;: { &quot;method.response.header.Content-Type&quot;: false }, &quot;statusCode&quot;: &quot;200&quot; }</code></pre> <p/> </div> <div class="seeAlso"> <a>Method</a>, <a>IntegrationResponse</a>, <a>Integration</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-create-api.html">Creating an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MethodResponse {
     /// <p>Specifies the <a>Model</a> resources used for the response's content-type. Response models are represented as a key/value map, with a content-type as the key and a <a>Model</a> name as the value.</p>
     #[serde(rename = "responseModels")]
@@ -2170,7 +2319,7 @@ pub struct MethodResponse {
 
 /// <p>Specifies the method setting properties.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MethodSetting {
     /// <p>Specifies whether the cached responses are encrypted. The PATCH path for this setting is <code>/{method_setting_key}/caching/dataEncrypted</code>, and the value is a Boolean.</p>
     #[serde(rename = "cacheDataEncrypted")]
@@ -2216,7 +2365,7 @@ pub struct MethodSetting {
 
 /// <p>Represents a summary of a <a>Method</a> resource, given a particular date and time.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MethodSnapshot {
     /// <p>Specifies whether the method requires a valid <a>ApiKey</a>.</p>
     #[serde(rename = "apiKeyRequired")]
@@ -2230,7 +2379,7 @@ pub struct MethodSnapshot {
 
 /// <p><p>Represents the data structure of a method&#39;s request or response payload.</p> <div class="remarks"> <p>A request model defines the data structure of the client-supplied request payload. A response model defines the data structure of the response payload returned by the back end. Although not required, models are useful for mapping payloads between the front end and back end.</p> <p>A model is used for generating an API&#39;s SDK, validating the input request body, and creating a skeletal mapping template.</p> </div> <div class="seeAlso"> <a>Method</a>, <a>MethodResponse</a>, <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/models-mappings.html">Models and Mappings</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Model {
     /// <p>The content-type for the model.</p>
     #[serde(rename = "contentType")]
@@ -2256,7 +2405,7 @@ pub struct Model {
 
 /// <p><p>Represents a collection of <a>Model</a> resources.</p> <div class="seeAlso"> <a>Method</a>, <a>MethodResponse</a>, <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/models-mappings.html">Models and Mappings</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Models {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -2269,6 +2418,7 @@ pub struct Models {
 
 /// <p>A single patch operation to apply to the specified resource. Please refer to http://tools.ietf.org/html/rfc6902#section-4 for an explanation of how each operation is used.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PatchOperation {
     /// <p>The <code>copy</code> update operation's source as identified by a <code>JSON-Pointer</code> value referencing the location within the targeted resource to copy the value from. For example, to promote a canary deployment, you copy the canary deployment ID to the affiliated deployment ID by calling a PATCH request on a <a>Stage</a> resource with <code>"op":"copy"</code>, <code>"from":"/canarySettings/deploymentId"</code> and <code>"path":"/deploymentId"</code>.</p>
     #[serde(rename = "from")]
@@ -2290,6 +2440,7 @@ pub struct PatchOperation {
 
 /// <p>Creates a customization of a <a>GatewayResponse</a> of a specified response type and status code on the given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutGatewayResponseRequest {
     /// <p><p>Response parameters (paths, query strings and headers) of the <a>GatewayResponse</a> as a string-to-string map of key-value pairs.</p></p>
     #[serde(rename = "responseParameters")]
@@ -2311,8 +2462,17 @@ pub struct PutGatewayResponseRequest {
     pub status_code: Option<String>,
 }
 
+impl Validate for PutGatewayResponseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.status_code {
+            errors.check_pattern(&field_path(path, "status_code"), &value, "[1-5]\\d\\d");
+        }
+    }
+}
+
 /// <p>Sets up a method's integration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutIntegrationRequest {
     /// <p>An API-specific tag group of related cached parameters.</p>
     #[serde(rename = "cacheKeyParameters")]
@@ -2378,6 +2538,7 @@ pub struct PutIntegrationRequest {
 
 /// <p>Represents a put integration response request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutIntegrationResponseRequest {
     /// <p>Specifies how to handle response payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a response payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the response payload will be passed through from the integration response to the method response without modification.</p>
     #[serde(rename = "contentHandling")]
@@ -2409,8 +2570,19 @@ pub struct PutIntegrationResponseRequest {
     pub status_code: String,
 }
 
+impl Validate for PutIntegrationResponseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "status_code"),
+            &self.status_code,
+            "[1-5]\\d\\d",
+        );
+    }
+}
+
 /// <p>Request to add a method to an existing <a>Resource</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutMethodRequest {
     /// <p>Specifies whether the method required a valid <a>ApiKey</a>.</p>
     #[serde(rename = "apiKeyRequired")]
@@ -2456,6 +2628,7 @@ pub struct PutMethodRequest {
 
 /// <p>Request to add a <a>MethodResponse</a> to an existing <a>Method</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutMethodResponseRequest {
     /// <p>[Required] The HTTP verb of the <a>Method</a> resource.</p>
     #[serde(rename = "httpMethod")]
@@ -2479,8 +2652,19 @@ pub struct PutMethodResponseRequest {
     pub status_code: String,
 }
 
+impl Validate for PutMethodResponseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "status_code"),
+            &self.status_code,
+            "[1-5]\\d\\d",
+        );
+    }
+}
+
 /// <p>A PUT request to update an existing API, with external API definitions specified as the request body.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRestApiRequest {
     /// <p>[Required] The PUT request body containing external API definitions. Currently, only OpenAPI definition JSON/YAML files are supported. The maximum size of the API definition file is 2MB.</p>
     #[serde(rename = "body")]
@@ -2526,7 +2710,7 @@ pub struct QuotaSettings {
 
 /// <p><p>A set of validation rules for incoming <a>Method</a> requests.</p> <div class="remarks"> <p>In OpenAPI, a <a>RequestValidator</a> of an API is defined by the <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-swagger-extensions.html#api-gateway-swagger-extensions-request-validators.requestValidator.html">x-amazon-apigateway-request-validators.requestValidator</a> object. It the referenced using the <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-swagger-extensions.html#api-gateway-swagger-extensions-request-validator">x-amazon-apigateway-request-validator</a> property.</p> </div> <div class="seeAlso"><a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-method-request-validation.html">Enable Basic Request Validation in API Gateway</a></div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RequestValidator {
     /// <p>The identifier of this <a>RequestValidator</a>.</p>
     #[serde(rename = "id")]
@@ -2548,7 +2732,7 @@ pub struct RequestValidator {
 
 /// <p><p>A collection of <a>RequestValidator</a> resources of a given <a>RestApi</a>.</p> <div class="remarks"> <p>In OpenAPI, the <a>RequestValidators</a> of an API is defined by the <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-swagger-extensions.html#api-gateway-swagger-extensions-request-validators.html">x-amazon-apigateway-request-validators</a> extension.</p> </div> <div class="seeAlso"><a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-method-request-validation.html">Enable Basic Request Validation in API Gateway</a></div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RequestValidators {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -2561,7 +2745,7 @@ pub struct RequestValidators {
 
 /// <p><p>Represents an API resource.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-create-api.html">Create an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Resource {
     /// <p>The resource's identifier.</p>
     #[serde(rename = "id")]
@@ -2587,7 +2771,7 @@ pub struct Resource {
 
 /// <p><p>Represents a collection of <a>Resource</a> resources.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-create-api.html">Create an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Resources {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -2600,7 +2784,7 @@ pub struct Resources {
 
 /// <p><p>Represents a REST API.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-create-api.html">Create an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestApi {
     /// <p>The source of the API key for metering requests according to a usage plan. Valid values are: <ul><li><code>HEADER</code> to read the API key from the <code>X-API-Key</code> header of a request. </li><li><code>AUTHORIZER</code> to read the API key from the <code>UsageIdentifierKey</code> from a custom authorizer.</li></ul> </p>
     #[serde(rename = "apiKeySource")]
@@ -2654,7 +2838,7 @@ pub struct RestApi {
 
 /// <p><p>Contains references to your APIs and links that guide you in how to interact with your collection. A collection offers a paginated view of your APIs.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-create-api.html">Create an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestApis {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -2667,7 +2851,7 @@ pub struct RestApis {
 
 /// <p>A configuration property of an SDK type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SdkConfigurationProperty {
     /// <p>The default value of an <a>SdkType</a> configuration property.</p>
     #[serde(rename = "defaultValue")]
@@ -2704,7 +2888,7 @@ pub struct SdkResponse {
 
 /// <p>A type of SDK that API Gateway can generate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SdkType {
     /// <p>A list of configuration properties of an <a>SdkType</a>.</p>
     #[serde(rename = "configurationProperties")]
@@ -2726,7 +2910,7 @@ pub struct SdkType {
 
 /// <p>The collection of <a>SdkType</a> instances.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SdkTypes {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -2739,7 +2923,7 @@ pub struct SdkTypes {
 
 /// <p><p>Represents a unique identifier for a version of a deployed <a>RestApi</a> that is callable by users.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-deploy-api.html">Deploy an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Stage {
     /// <p>Settings for logging access in this stage.</p>
     #[serde(rename = "accessLogSettings")]
@@ -2813,6 +2997,7 @@ pub struct Stage {
 
 /// <p>A reference to a unique stage identified in the format <code>{restApiId}/{stage}</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StageKey {
     /// <p>The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
@@ -2826,7 +3011,7 @@ pub struct StageKey {
 
 /// <p><p>A list of <a>Stage</a> resources that are associated with the <a>ApiKey</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/stages.html">Deploying API in Stages</a></div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Stages {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "item")]
@@ -2836,6 +3021,7 @@ pub struct Stages {
 
 /// <p>Adds or updates a tag on a given resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>[Required] The ARN of a resource that can be tagged. The resource ARN must be URL-encoded.</p>
     #[serde(rename = "resourceArn")]
@@ -2847,7 +3033,7 @@ pub struct TagResourceRequest {
 
 /// <p>The collection of tags. Each tag element is associated with a given resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Tags {
     /// <p>The collection of tags. Each tag element is associated with a given resource.</p>
     #[serde(rename = "tags")]
@@ -2857,7 +3043,7 @@ pub struct Tags {
 
 /// <p><p>Represents a mapping template used to transform a payload.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/models-mappings.html#models-mappings-mappings">Mapping Templates</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Template {
     /// <p>The Apache <a href="https://velocity.apache.org/engine/devel/vtl-reference-guide.html" target="_blank">Velocity Template Language (VTL)</a> template content used for the template resource.</p>
     #[serde(rename = "value")]
@@ -2867,6 +3053,7 @@ pub struct Template {
 
 /// <p>Make a request to simulate the execution of an <a>Authorizer</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestInvokeAuthorizerRequest {
     /// <p>[Optional] A key-value map of additional context variables.</p>
     #[serde(rename = "additionalContext")]
@@ -2902,7 +3089,7 @@ pub struct TestInvokeAuthorizerRequest {
 
 /// <p>Represents the response of the test invoke request for a custom <a>Authorizer</a></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TestInvokeAuthorizerResponse {
     #[serde(rename = "authorization")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2935,6 +3122,7 @@ pub struct TestInvokeAuthorizerResponse {
 
 /// <p>Make a request to simulate the execution of a <a>Method</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestInvokeMethodRequest {
     /// <p>The simulated request body of an incoming invocation request.</p>
     #[serde(rename = "body")]
@@ -2973,7 +3161,7 @@ pub struct TestInvokeMethodRequest {
 
 /// <p><p>Represents the response of the test invoke request in the HTTP method.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-test-method.html#how-to-test-method-console">Test API using the API Gateway console</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TestInvokeMethodResponse {
     /// <p>The body of the HTTP response.</p>
     #[serde(rename = "body")]
@@ -3016,6 +3204,7 @@ pub struct ThrottleSettings {
 
 /// <p>Removes a tag from a given resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>[Required] The ARN of a resource that can be tagged. The resource ARN must be URL-encoded.</p>
     #[serde(rename = "resourceArn")]
@@ -3027,6 +3216,7 @@ pub struct UntagResourceRequest {
 
 /// <p>Requests API Gateway to change information about the current <a>Account</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAccountRequest {
     /// <p>A list of update operations to be applied to the specified resource and in the order specified in this list.</p>
     #[serde(rename = "patchOperations")]
@@ -3036,6 +3226,7 @@ pub struct UpdateAccountRequest {
 
 /// <p>A request to change information about an <a>ApiKey</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApiKeyRequest {
     /// <p>[Required] The identifier of the <a>ApiKey</a> resource to be updated.</p>
     #[serde(rename = "apiKey")]
@@ -3048,6 +3239,7 @@ pub struct UpdateApiKeyRequest {
 
 /// <p>Request to update an existing <a>Authorizer</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAuthorizerRequest {
     /// <p>[Required] The identifier of the <a>Authorizer</a> resource.</p>
     #[serde(rename = "authorizerId")]
@@ -3063,6 +3255,7 @@ pub struct UpdateAuthorizerRequest {
 
 /// <p>A request to change information about the <a>BasePathMapping</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateBasePathMappingRequest {
     /// <p>[Required] The base path of the <a>BasePathMapping</a> resource to change.</p> <p>To specify an empty base path, set this parameter to <code>'(none)'</code>.</p>
     #[serde(rename = "basePath")]
@@ -3078,6 +3271,7 @@ pub struct UpdateBasePathMappingRequest {
 
 /// <p>A request to change information about an <a>ClientCertificate</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateClientCertificateRequest {
     /// <p>[Required] The identifier of the <a>ClientCertificate</a> resource to be updated.</p>
     #[serde(rename = "clientCertificateId")]
@@ -3090,6 +3284,7 @@ pub struct UpdateClientCertificateRequest {
 
 /// <p>Requests API Gateway to change information about a <a>Deployment</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDeploymentRequest {
     /// <p>The replacement identifier for the <a>Deployment</a> resource to change information about.</p>
     #[serde(rename = "deploymentId")]
@@ -3105,6 +3300,7 @@ pub struct UpdateDeploymentRequest {
 
 /// <p>Updates an existing documentation part of a given API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDocumentationPartRequest {
     /// <p>[Required] The identifier of the to-be-updated documentation part.</p>
     #[serde(rename = "documentationPartId")]
@@ -3120,6 +3316,7 @@ pub struct UpdateDocumentationPartRequest {
 
 /// <p>Updates an existing documentation version of an API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDocumentationVersionRequest {
     /// <p>[Required] The version identifier of the to-be-updated documentation version.</p>
     #[serde(rename = "documentationVersion")]
@@ -3135,6 +3332,7 @@ pub struct UpdateDocumentationVersionRequest {
 
 /// <p>A request to change information about the <a>DomainName</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDomainNameRequest {
     /// <p>[Required] The name of the <a>DomainName</a> resource to be changed.</p>
     #[serde(rename = "domainName")]
@@ -3147,6 +3345,7 @@ pub struct UpdateDomainNameRequest {
 
 /// <p>Updates a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGatewayResponseRequest {
     /// <p>A list of update operations to be applied to the specified resource and in the order specified in this list.</p>
     #[serde(rename = "patchOperations")]
@@ -3162,6 +3361,7 @@ pub struct UpdateGatewayResponseRequest {
 
 /// <p>Represents an update integration request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateIntegrationRequest {
     /// <p>[Required] Represents an update integration request's HTTP method.</p>
     #[serde(rename = "httpMethod")]
@@ -3180,6 +3380,7 @@ pub struct UpdateIntegrationRequest {
 
 /// <p>Represents an update integration response request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateIntegrationResponseRequest {
     /// <p>[Required] Specifies an update integration response request's HTTP method.</p>
     #[serde(rename = "httpMethod")]
@@ -3199,8 +3400,19 @@ pub struct UpdateIntegrationResponseRequest {
     pub status_code: String,
 }
 
+impl Validate for UpdateIntegrationResponseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "status_code"),
+            &self.status_code,
+            "[1-5]\\d\\d",
+        );
+    }
+}
+
 /// <p>Request to update an existing <a>Method</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMethodRequest {
     /// <p>[Required] The HTTP verb of the <a>Method</a> resource.</p>
     #[serde(rename = "httpMethod")]
@@ -3219,6 +3431,7 @@ pub struct UpdateMethodRequest {
 
 /// <p>A request to update an existing <a>MethodResponse</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMethodResponseRequest {
     /// <p>[Required] The HTTP verb of the <a>Method</a> resource.</p>
     #[serde(rename = "httpMethod")]
@@ -3238,8 +3451,19 @@ pub struct UpdateMethodResponseRequest {
     pub status_code: String,
 }
 
+impl Validate for UpdateMethodResponseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "status_code"),
+            &self.status_code,
+            "[1-5]\\d\\d",
+        );
+    }
+}
+
 /// <p>Request to update an existing model in an existing <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateModelRequest {
     /// <p>[Required] The name of the model to update.</p>
     #[serde(rename = "modelName")]
@@ -3255,6 +3479,7 @@ pub struct UpdateModelRequest {
 
 /// <p>Updates a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRequestValidatorRequest {
     /// <p>A list of update operations to be applied to the specified resource and in the order specified in this list.</p>
     #[serde(rename = "patchOperations")]
@@ -3270,6 +3495,7 @@ pub struct UpdateRequestValidatorRequest {
 
 /// <p>Request to change information about a <a>Resource</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateResourceRequest {
     /// <p>A list of update operations to be applied to the specified resource and in the order specified in this list.</p>
     #[serde(rename = "patchOperations")]
@@ -3285,6 +3511,7 @@ pub struct UpdateResourceRequest {
 
 /// <p>Request to update an existing <a>RestApi</a> resource in your collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRestApiRequest {
     /// <p>A list of update operations to be applied to the specified resource and in the order specified in this list.</p>
     #[serde(rename = "patchOperations")]
@@ -3297,6 +3524,7 @@ pub struct UpdateRestApiRequest {
 
 /// <p>Requests API Gateway to change information about a <a>Stage</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStageRequest {
     /// <p>A list of update operations to be applied to the specified resource and in the order specified in this list.</p>
     #[serde(rename = "patchOperations")]
@@ -3312,6 +3540,7 @@ pub struct UpdateStageRequest {
 
 /// <p>The PATCH request to update a usage plan of a given plan Id.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUsagePlanRequest {
     /// <p>A list of update operations to be applied to the specified resource and in the order specified in this list.</p>
     #[serde(rename = "patchOperations")]
@@ -3324,6 +3553,7 @@ pub struct UpdateUsagePlanRequest {
 
 /// <p>The PATCH request to grant a temporary extension to the remaining quota of a usage plan associated with a specified API key.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUsageRequest {
     /// <p>[Required] The identifier of the API key associated with the usage plan in which a temporary extension is granted to the remaining quota.</p>
     #[serde(rename = "keyId")]
@@ -3339,6 +3569,7 @@ pub struct UpdateUsageRequest {
 
 /// <p>Updates an existing <a>VpcLink</a> of a specified identifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateVpcLinkRequest {
     /// <p>A list of update operations to be applied to the specified resource and in the order specified in this list.</p>
     #[serde(rename = "patchOperations")]
@@ -3351,7 +3582,7 @@ pub struct UpdateVpcLinkRequest {
 
 /// <p><p>Represents the usage data of a usage plan.</p> <div class="remarks"/> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-api-usage-plans.html">Create and Use Usage Plans</a>, <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-create-usage-plans-with-console.html#api-gateway-usage-plan-manage-usage">Manage Usage in a Usage Plan</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Usage {
     /// <p>The ending date of the usage data.</p>
     #[serde(rename = "endDate")]
@@ -3376,7 +3607,7 @@ pub struct Usage {
 
 /// <p><p>Represents a usage plan than can specify who can assess associated API stages with specified request limits and quotas.</p> <div class="remarks"> <p>In a usage plan, you associate an API by specifying the API&#39;s Id and a stage name of the specified API. You add plan customers by adding API keys to the plan. </p> </div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-api-usage-plans.html">Create and Use Usage Plans</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UsagePlan {
     /// <p>The associated API stages of a usage plan.</p>
     #[serde(rename = "apiStages")]
@@ -3414,7 +3645,7 @@ pub struct UsagePlan {
 
 /// <p><p>Represents a usage plan key to identify a plan customer.</p> <div class="remarks"> <p>To associate an API stage with a selected API key in a usage plan, you must create a UsagePlanKey resource to represent the selected <a>ApiKey</a>.</p> </div>&quot; <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-api-usage-plans.html">Create and Use Usage Plans</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UsagePlanKey {
     /// <p>The Id of a usage plan key.</p>
     #[serde(rename = "id")]
@@ -3436,7 +3667,7 @@ pub struct UsagePlanKey {
 
 /// <p><p>Represents the collection of usage plan keys added to usage plans for the associated API keys and, possibly, other types of keys.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-api-usage-plans.html">Create and Use Usage Plans</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UsagePlanKeys {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -3449,7 +3680,7 @@ pub struct UsagePlanKeys {
 
 /// <p><p>Represents a collection of usage plans for an AWS account.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-api-usage-plans.html">Create and Use Usage Plans</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UsagePlans {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -3462,7 +3693,7 @@ pub struct UsagePlans {
 
 /// <p><p>A API Gateway VPC link for a <a>RestApi</a> to access resources in an Amazon Virtual Private Cloud (VPC).</p> <div class="remarks"> <p><p>To enable access to a resource in an Amazon Virtual Private Cloud through Amazon API Gateway, you, as an API developer, create a <a>VpcLink</a> resource targeted for one or more network load balancers of the VPC and then integrate an API method with a private integration that uses the <a>VpcLink</a>. The private integration has an integration type of <code>HTTP</code> or <code>HTTP<em>PROXY</code> and has a connection type of <code>VPC</em>LINK</code>. The integration uses the <code>connectionId</code> property to identify the <a>VpcLink</a> used.</p> </p> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VpcLink {
     /// <p>The description of the VPC link.</p>
     #[serde(rename = "description")]
@@ -3496,7 +3727,7 @@ pub struct VpcLink {
 
 /// <p><p>The collection of VPC links under the caller&#39;s account in a region.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/getting-started-with-private-integration.html">Getting Started with Private Integrations</a>, <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/set-up-private-integration.html">Set up Private Integrations</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VpcLinks {
     /// <p>The current page of elements from this collection.</p>
     #[serde(rename = "items")]
@@ -3546,7 +3777,12 @@ impl CreateApiKeyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateApiKeyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3604,7 +3840,12 @@ impl CreateAuthorizerError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateAuthorizerError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3663,7 +3904,12 @@ impl CreateBasePathMappingError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateBasePathMappingError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3730,7 +3976,12 @@ impl CreateDeploymentError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateDeploymentError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3800,7 +4051,12 @@ impl CreateDocumentationPartError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3873,7 +4129,12 @@ impl CreateDocumentationVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3926,7 +4187,12 @@ impl CreateDomainNameError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateDomainNameError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3987,7 +4253,12 @@ impl CreateModelError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateModelError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4049,7 +4320,12 @@ impl CreateRequestValidatorError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateRequestValidatorError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4111,7 +4387,12 @@ impl CreateResourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateResourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4164,7 +4445,12 @@ impl CreateRestApiError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateRestApiError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4225,7 +4511,12 @@ impl CreateStageError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateStageError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4288,7 +4579,12 @@ impl CreateUsagePlanError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateUsagePlanError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4346,7 +4642,12 @@ impl CreateUsagePlanKeyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateUsagePlanKeyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4393,7 +4694,12 @@ impl CreateVpcLinkError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateVpcLinkError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4438,7 +4744,12 @@ impl DeleteApiKeyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteApiKeyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4493,7 +4804,12 @@ impl DeleteAuthorizerError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteAuthorizerError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4552,7 +4868,12 @@ impl DeleteBasePathMappingError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteBasePathMappingError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4608,7 +4929,12 @@ impl DeleteClientCertificateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4659,7 +4985,12 @@ impl DeleteDeploymentError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteDeploymentError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4719,7 +5050,12 @@ impl DeleteDocumentationPartError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4784,7 +5120,12 @@ impl DeleteDocumentationVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4831,7 +5172,12 @@ impl DeleteDomainNameError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteDomainNameError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4888,7 +5234,12 @@ impl DeleteGatewayResponseError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteGatewayResponseError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4940,7 +5291,12 @@ impl DeleteIntegrationError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteIntegrationError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5002,7 +5358,12 @@ impl DeleteIntegrationResponseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5054,7 +5415,12 @@ impl DeleteMethodError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteMethodError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5112,7 +5478,12 @@ impl DeleteMethodResponseError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteMethodResponseError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5169,7 +5540,12 @@ impl DeleteModelError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteModelError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5228,7 +5604,12 @@ impl DeleteRequestValidatorError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteRequestValidatorError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5285,7 +5666,12 @@ impl DeleteResourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteResourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5337,7 +5723,12 @@ impl DeleteRestApiError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteRestApiError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5388,7 +5779,12 @@ impl DeleteStageError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteStageError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5439,7 +5835,12 @@ impl DeleteUsagePlanError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteUsagePlanError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5495,7 +5896,12 @@ impl DeleteUsagePlanKeyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteUsagePlanKeyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5547,7 +5953,12 @@ impl DeleteVpcLinkError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteVpcLinkError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5606,7 +6017,12 @@ impl FlushStageAuthorizersCacheError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5657,7 +6073,12 @@ impl FlushStageCacheError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(FlushStageCacheError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5709,7 +6130,12 @@ impl GenerateClientCertificateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5754,7 +6180,12 @@ impl GetAccountError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetAccountError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5799,7 +6230,12 @@ impl GetApiKeyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetApiKeyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5844,7 +6280,12 @@ impl GetApiKeysError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetApiKeysError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5889,7 +6330,12 @@ impl GetAuthorizerError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetAuthorizerError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5939,7 +6385,12 @@ impl GetAuthorizersError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetAuthorizersError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5985,7 +6436,12 @@ impl GetBasePathMappingError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetBasePathMappingError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6030,7 +6486,12 @@ impl GetBasePathMappingsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetBasePathMappingsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6077,7 +6538,12 @@ impl GetClientCertificateError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetClientCertificateError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6124,7 +6590,12 @@ impl GetClientCertificatesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetClientCertificatesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6174,7 +6645,12 @@ impl GetDeploymentError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetDeploymentError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6225,7 +6701,12 @@ impl GetDeploymentsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetDeploymentsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6273,7 +6754,12 @@ impl GetDocumentationPartError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetDocumentationPartError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6325,7 +6811,12 @@ impl GetDocumentationPartsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetDocumentationPartsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6375,7 +6866,12 @@ impl GetDocumentationVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6429,7 +6925,12 @@ impl GetDocumentationVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6480,7 +6981,12 @@ impl GetDomainNameError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetDomainNameError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6526,7 +7032,12 @@ impl GetDomainNamesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetDomainNamesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6581,7 +7092,12 @@ impl GetExportError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetExportError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6628,7 +7144,12 @@ impl GetGatewayResponseError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetGatewayResponseError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6678,7 +7199,12 @@ impl GetGatewayResponsesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetGatewayResponsesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6724,7 +7250,12 @@ impl GetIntegrationError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetIntegrationError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6771,7 +7302,12 @@ impl GetIntegrationResponseError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetIntegrationResponseError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6816,7 +7352,12 @@ impl GetMethodError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetMethodError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6861,7 +7402,12 @@ impl GetMethodResponseError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetMethodResponseError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6906,7 +7452,12 @@ impl GetModelError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetModelError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6956,7 +7507,12 @@ impl GetModelTemplateError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetModelTemplateError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7007,7 +7563,12 @@ impl GetModelsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetModelsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7053,7 +7614,12 @@ impl GetRequestValidatorError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetRequestValidatorError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7105,7 +7671,12 @@ impl GetRequestValidatorsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetRequestValidatorsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7151,7 +7722,12 @@ impl GetResourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetResourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7201,7 +7777,12 @@ impl GetResourcesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetResourcesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7247,7 +7828,12 @@ impl GetRestApiError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetRestApiError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7292,7 +7878,12 @@ impl GetRestApisError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetRestApisError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7343,7 +7934,12 @@ impl GetSdkError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetSdkError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7390,7 +7986,12 @@ impl GetSdkTypeError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetSdkTypeError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7430,7 +8031,12 @@ impl GetSdkTypesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetSdkTypesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7474,7 +8080,12 @@ impl GetStageError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetStageError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7519,7 +8130,12 @@ impl GetStagesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetStagesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7574,7 +8190,12 @@ impl GetTagsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetTagsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7626,7 +8247,12 @@ impl GetUsageError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetUsageError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7677,7 +8303,12 @@ impl GetUsagePlanError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetUsagePlanError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7728,7 +8359,12 @@ impl GetUsagePlanKeyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetUsagePlanKeyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7779,7 +8415,12 @@ impl GetUsagePlanKeysError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetUsagePlanKeysError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7835,7 +8476,12 @@ impl GetUsagePlansError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetUsagePlansError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7882,7 +8528,12 @@ impl GetVpcLinkError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetVpcLinkError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7927,7 +8578,12 @@ impl GetVpcLinksError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetVpcLinksError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7987,7 +8643,12 @@ impl ImportApiKeysError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ImportApiKeysError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8051,7 +8712,12 @@ impl ImportDocumentationPartsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8108,7 +8774,12 @@ impl ImportRestApiError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ImportRestApiError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8165,7 +8836,12 @@ impl PutGatewayResponseError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(PutGatewayResponseError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8222,7 +8898,12 @@ impl PutIntegrationError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(PutIntegrationError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8288,7 +8969,12 @@ impl PutIntegrationResponseError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(PutIntegrationResponseError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8351,7 +9037,12 @@ impl PutMethodError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(PutMethodError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8414,7 +9105,12 @@ impl PutMethodResponseError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(PutMethodResponseError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8477,7 +9173,12 @@ impl PutRestApiError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(PutRestApiError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8540,7 +9241,12 @@ impl TagResourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(TagResourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8595,7 +9301,12 @@ impl TestInvokeAuthorizerError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(TestInvokeAuthorizerError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8646,7 +9357,12 @@ impl TestInvokeMethodError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(TestInvokeMethodError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8702,7 +9418,12 @@ impl UntagResourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UntagResourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8754,7 +9475,12 @@ impl UpdateAccountError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateAccountError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8810,7 +9536,12 @@ impl UpdateApiKeyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateApiKeyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8862,7 +9593,12 @@ impl UpdateAuthorizerError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateAuthorizerError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8920,7 +9656,12 @@ impl UpdateBasePathMappingError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateBasePathMappingError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8976,7 +9717,12 @@ impl UpdateClientCertificateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9032,7 +9778,12 @@ impl UpdateDeploymentError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateDeploymentError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9100,7 +9851,12 @@ impl UpdateDocumentationPartError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9166,7 +9922,12 @@ impl UpdateDocumentationVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9223,7 +9984,12 @@ impl UpdateDomainNameError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateDomainNameError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9277,7 +10043,12 @@ impl UpdateGatewayResponseError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateGatewayResponseError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9333,7 +10104,12 @@ impl UpdateIntegrationError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateIntegrationError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9396,7 +10172,12 @@ impl UpdateIntegrationResponseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9453,7 +10234,12 @@ impl UpdateMethodError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateMethodError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9517,7 +10303,12 @@ impl UpdateMethodResponseError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateMethodResponseError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9575,7 +10366,12 @@ impl UpdateModelError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateModelError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9629,7 +10425,12 @@ impl UpdateRequestValidatorError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateRequestValidatorError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9685,7 +10486,12 @@ impl UpdateResourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateResourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9742,7 +10548,12 @@ impl UpdateRestApiError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateRestApiError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9799,7 +10610,12 @@ impl UpdateStageError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateStageError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9851,7 +10667,12 @@ impl UpdateUsageError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateUsageError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9907,7 +10728,12 @@ impl UpdateUsagePlanError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateUsagePlanError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9964,7 +10790,12 @@ impl UpdateVpcLinkError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateVpcLinkError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10587,4094 +11418,10243 @@ pub trait ApiGateway {
         input: UpdateVpcLinkRequest,
     ) -> RusotoFuture<VpcLink, UpdateVpcLinkError>;
 }
-/// A client for the Amazon API Gateway API.
-#[derive(Clone)]
-pub struct ApiGatewayClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ApiGatewayClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ApiGatewayClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ApiGatewayClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayClient {
-        ApiGatewayClient { client, region }
-    }
-}
-
-impl ApiGateway for ApiGatewayClient {
-    /// <p><p>Create an <a>ApiKey</a> resource. </p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/create-api-key.html">AWS CLI</a></div></p>
+const _: Option<&dyn ApiGateway> = None;
+impl<'a, T: ApiGateway + ?Sized> ApiGateway for &'a T {
     fn create_api_key(
         &self,
         input: CreateApiKeyRequest,
     ) -> RusotoFuture<ApiKey, CreateApiKeyError> {
-        let request_uri = "/apikeys";
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateApiKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_api_key(input)
     }
-
-    /// <p><p>Adds a new <a>Authorizer</a> resource to an existing <a>RestApi</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/create-authorizer.html">AWS CLI</a></div></p>
     fn create_authorizer(
         &self,
         input: CreateAuthorizerRequest,
     ) -> RusotoFuture<Authorizer, CreateAuthorizerError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/authorizers",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Authorizer, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAuthorizerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_authorizer(input)
     }
-
-    /// <p>Creates a new <a>BasePathMapping</a> resource.</p>
     fn create_base_path_mapping(
         &self,
         input: CreateBasePathMappingRequest,
     ) -> RusotoFuture<BasePathMapping, CreateBasePathMappingError> {
-        let request_uri = format!(
-            "/domainnames/{domain_name}/basepathmappings",
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BasePathMapping, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateBasePathMappingError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_base_path_mapping(input)
     }
-
-    /// <p>Creates a <a>Deployment</a> resource, which makes a specified <a>RestApi</a> callable over the internet.</p>
     fn create_deployment(
         &self,
         input: CreateDeploymentRequest,
     ) -> RusotoFuture<Deployment, CreateDeploymentError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/deployments",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Deployment, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_deployment(input)
     }
-
     fn create_documentation_part(
         &self,
         input: CreateDocumentationPartRequest,
     ) -> RusotoFuture<DocumentationPart, CreateDocumentationPartError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/parts",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DocumentationPart, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDocumentationPartError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_documentation_part(input)
     }
-
     fn create_documentation_version(
         &self,
         input: CreateDocumentationVersionRequest,
     ) -> RusotoFuture<DocumentationVersion, CreateDocumentationVersionError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/versions",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DocumentationVersion, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDocumentationVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_documentation_version(input)
     }
-
-    /// <p>Creates a new domain name.</p>
     fn create_domain_name(
         &self,
         input: CreateDomainNameRequest,
     ) -> RusotoFuture<DomainName, CreateDomainNameError> {
-        let request_uri = "/domainnames";
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DomainName, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDomainNameError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_domain_name(input)
     }
-
-    /// <p>Adds a new <a>Model</a> resource to an existing <a>RestApi</a> resource.</p>
     fn create_model(&self, input: CreateModelRequest) -> RusotoFuture<Model, CreateModelError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/models",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_model(input)
     }
-
-    /// <p>Creates a <a>ReqeustValidator</a> of a given <a>RestApi</a>.</p>
     fn create_request_validator(
         &self,
         input: CreateRequestValidatorRequest,
     ) -> RusotoFuture<RequestValidator, CreateRequestValidatorError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/requestvalidators",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RequestValidator, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateRequestValidatorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_request_validator(input)
     }
-
-    /// <p>Creates a <a>Resource</a> resource.</p>
     fn create_resource(
         &self,
         input: CreateResourceRequest,
     ) -> RusotoFuture<Resource, CreateResourceError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{parent_id}",
-            parent_id = input.parent_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Resource, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_resource(input)
     }
-
-    /// <p>Creates a new <a>RestApi</a> resource.</p>
     fn create_rest_api(
         &self,
         input: CreateRestApiRequest,
     ) -> RusotoFuture<RestApi, CreateRestApiError> {
-        let request_uri = "/restapis";
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateRestApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_rest_api(input)
     }
-
-    /// <p>Creates a new <a>Stage</a> resource that references a pre-existing <a>Deployment</a> for the API. </p>
     fn create_stage(&self, input: CreateStageRequest) -> RusotoFuture<Stage, CreateStageError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/stages",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateStageError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_stage(input)
     }
-
-    /// <p>Creates a usage plan with the throttle and quota limits, as well as the associated API stages, specified in the payload. </p>
     fn create_usage_plan(
         &self,
         input: CreateUsagePlanRequest,
     ) -> RusotoFuture<UsagePlan, CreateUsagePlanError> {
-        let request_uri = "/usageplans";
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UsagePlan, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUsagePlanError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_usage_plan(input)
     }
-
-    /// <p>Creates a usage plan key for adding an existing API key to a usage plan.</p>
     fn create_usage_plan_key(
         &self,
         input: CreateUsagePlanKeyRequest,
     ) -> RusotoFuture<UsagePlanKey, CreateUsagePlanKeyError> {
-        let request_uri = format!(
-            "/usageplans/{usageplan_id}/keys",
-            usageplan_id = input.usage_plan_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UsagePlanKey, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUsagePlanKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_usage_plan_key(input)
     }
-
-    /// <p>Creates a VPC link, under the caller's account in a selected region, in an asynchronous operation that typically takes 2-4 minutes to complete and become operational. The caller must have permissions to create and update VPC Endpoint services.</p>
     fn create_vpc_link(
         &self,
         input: CreateVpcLinkRequest,
     ) -> RusotoFuture<VpcLink, CreateVpcLinkError> {
-        let request_uri = "/vpclinks";
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateVpcLinkError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_vpc_link(input)
     }
-
-    /// <p>Deletes the <a>ApiKey</a> resource.</p>
     fn delete_api_key(&self, input: DeleteApiKeyRequest) -> RusotoFuture<(), DeleteApiKeyError> {
-        let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteApiKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_api_key(input)
     }
-
-    /// <p><p>Deletes an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/delete-authorizer.html">AWS CLI</a></div></p>
     fn delete_authorizer(
         &self,
         input: DeleteAuthorizerRequest,
     ) -> RusotoFuture<(), DeleteAuthorizerError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/authorizers/{authorizer_id}",
-            authorizer_id = input.authorizer_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAuthorizerError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_authorizer(input)
     }
-
-    /// <p>Deletes the <a>BasePathMapping</a> resource.</p>
     fn delete_base_path_mapping(
         &self,
         input: DeleteBasePathMappingRequest,
     ) -> RusotoFuture<(), DeleteBasePathMappingError> {
-        let request_uri = format!(
-            "/domainnames/{domain_name}/basepathmappings/{base_path}",
-            base_path = input.base_path,
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteBasePathMappingError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_base_path_mapping(input)
     }
-
-    /// <p>Deletes the <a>ClientCertificate</a> resource.</p>
     fn delete_client_certificate(
         &self,
         input: DeleteClientCertificateRequest,
     ) -> RusotoFuture<(), DeleteClientCertificateError> {
-        let request_uri = format!(
-            "/clientcertificates/{clientcertificate_id}",
-            clientcertificate_id = input.client_certificate_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteClientCertificateError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_client_certificate(input)
     }
-
-    /// <p>Deletes a <a>Deployment</a> resource. Deleting a deployment will only succeed if there are no <a>Stage</a> resources associated with it.</p>
     fn delete_deployment(
         &self,
         input: DeleteDeploymentRequest,
     ) -> RusotoFuture<(), DeleteDeploymentError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/deployments/{deployment_id}",
-            deployment_id = input.deployment_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_deployment(input)
     }
-
     fn delete_documentation_part(
         &self,
         input: DeleteDocumentationPartRequest,
     ) -> RusotoFuture<(), DeleteDocumentationPartError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/parts/{part_id}",
-            part_id = input.documentation_part_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteDocumentationPartError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_documentation_part(input)
     }
-
     fn delete_documentation_version(
         &self,
         input: DeleteDocumentationVersionRequest,
     ) -> RusotoFuture<(), DeleteDocumentationVersionError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/versions/{doc_version}",
-            doc_version = input.documentation_version,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteDocumentationVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_documentation_version(input)
     }
-
-    /// <p>Deletes the <a>DomainName</a> resource.</p>
     fn delete_domain_name(
         &self,
         input: DeleteDomainNameRequest,
     ) -> RusotoFuture<(), DeleteDomainNameError> {
-        let request_uri = format!(
-            "/domainnames/{domain_name}",
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDomainNameError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_domain_name(input)
     }
-
-    /// <p>Clears any customization of a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a> and resets it with the default settings.</p>
     fn delete_gateway_response(
         &self,
         input: DeleteGatewayResponseRequest,
     ) -> RusotoFuture<(), DeleteGatewayResponseError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/gatewayresponses/{response_type}",
-            response_type = input.response_type,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteGatewayResponseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_gateway_response(input)
     }
-
-    /// <p>Represents a delete integration.</p>
     fn delete_integration(
         &self,
         input: DeleteIntegrationRequest,
     ) -> RusotoFuture<(), DeleteIntegrationError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
-            http_method = input.http_method,
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteIntegrationError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_integration(input)
     }
-
-    /// <p>Represents a delete integration response.</p>
     fn delete_integration_response(
         &self,
         input: DeleteIntegrationResponseRequest,
     ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
-        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteIntegrationResponseError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_integration_response(input)
     }
-
-    /// <p>Deletes an existing <a>Method</a> resource.</p>
     fn delete_method(&self, input: DeleteMethodRequest) -> RusotoFuture<(), DeleteMethodError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
-            http_method = input.http_method,
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteMethodError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_method(input)
     }
-
-    /// <p>Deletes an existing <a>MethodResponse</a> resource.</p>
     fn delete_method_response(
         &self,
         input: DeleteMethodResponseRequest,
     ) -> RusotoFuture<(), DeleteMethodResponseError> {
-        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteMethodResponseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_method_response(input)
     }
-
-    /// <p>Deletes a model.</p>
     fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/models/{model_name}",
-            model_name = input.model_name,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_model(input)
     }
-
-    /// <p>Deletes a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
     fn delete_request_validator(
         &self,
         input: DeleteRequestValidatorRequest,
     ) -> RusotoFuture<(), DeleteRequestValidatorError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/requestvalidators/{requestvalidator_id}",
-            requestvalidator_id = input.request_validator_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteRequestValidatorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_request_validator(input)
     }
-
-    /// <p>Deletes a <a>Resource</a> resource.</p>
     fn delete_resource(
         &self,
         input: DeleteResourceRequest,
     ) -> RusotoFuture<(), DeleteResourceError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}",
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_resource(input)
     }
-
-    /// <p>Deletes the specified API.</p>
     fn delete_rest_api(&self, input: DeleteRestApiRequest) -> RusotoFuture<(), DeleteRestApiError> {
-        let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteRestApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_rest_api(input)
     }
-
-    /// <p>Deletes a <a>Stage</a> resource.</p>
     fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/stages/{stage_name}",
-            restapi_id = input.rest_api_id,
-            stage_name = input.stage_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteStageError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_stage(input)
     }
-
-    /// <p>Deletes a usage plan of a given plan Id.</p>
     fn delete_usage_plan(
         &self,
         input: DeleteUsagePlanRequest,
     ) -> RusotoFuture<(), DeleteUsagePlanError> {
-        let request_uri = format!(
-            "/usageplans/{usageplan_id}",
-            usageplan_id = input.usage_plan_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUsagePlanError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_usage_plan(input)
     }
-
-    /// <p>Deletes a usage plan key and remove the underlying API key from the associated usage plan.</p>
     fn delete_usage_plan_key(
         &self,
         input: DeleteUsagePlanKeyRequest,
     ) -> RusotoFuture<(), DeleteUsagePlanKeyError> {
-        let request_uri = format!(
-            "/usageplans/{usageplan_id}/keys/{key_id}",
-            key_id = input.key_id,
-            usageplan_id = input.usage_plan_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUsagePlanKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_usage_plan_key(input)
     }
-
-    /// <p>Deletes an existing <a>VpcLink</a> of a specified identifier.</p>
     fn delete_vpc_link(&self, input: DeleteVpcLinkRequest) -> RusotoFuture<(), DeleteVpcLinkError> {
-        let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteVpcLinkError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_vpc_link(input)
     }
-
-    /// <p>Flushes all authorizer cache entries on a stage.</p>
     fn flush_stage_authorizers_cache(
         &self,
         input: FlushStageAuthorizersCacheRequest,
     ) -> RusotoFuture<(), FlushStageAuthorizersCacheError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/stages/{stage_name}/cache/authorizers",
-            restapi_id = input.rest_api_id,
-            stage_name = input.stage_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(FlushStageAuthorizersCacheError::from_response(response))
-                }))
-            }
-        })
+        (**self).flush_stage_authorizers_cache(input)
     }
-
-    /// <p>Flushes a stage's cache.</p>
     fn flush_stage_cache(
         &self,
         input: FlushStageCacheRequest,
     ) -> RusotoFuture<(), FlushStageCacheError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/stages/{stage_name}/cache/data",
-            restapi_id = input.rest_api_id,
-            stage_name = input.stage_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(FlushStageCacheError::from_response(response))),
-                )
-            }
-        })
+        (**self).flush_stage_cache(input)
     }
-
-    /// <p>Generates a <a>ClientCertificate</a> resource.</p>
     fn generate_client_certificate(
         &self,
         input: GenerateClientCertificateRequest,
     ) -> RusotoFuture<ClientCertificate, GenerateClientCertificateError> {
-        let request_uri = "/clientcertificates";
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ClientCertificate, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GenerateClientCertificateError::from_response(response))
-                }))
-            }
-        })
+        (**self).generate_client_certificate(input)
     }
-
-    /// <p>Gets information about the current <a>Account</a> resource.</p>
     fn get_account(&self) -> RusotoFuture<Account, GetAccountError> {
-        let request_uri = "/account";
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Account, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAccountError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_account()
     }
-
-    /// <p>Gets information about the current <a>ApiKey</a> resource.</p>
     fn get_api_key(&self, input: GetApiKeyRequest) -> RusotoFuture<ApiKey, GetApiKeyError> {
-        let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.include_value {
-            params.put("includeValue", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetApiKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_api_key(input)
     }
-
-    /// <p>Gets information about the current <a>ApiKeys</a> resource.</p>
     fn get_api_keys(&self, input: GetApiKeysRequest) -> RusotoFuture<ApiKeys, GetApiKeysError> {
-        let request_uri = "/apikeys";
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.customer_id {
-            params.put("customerId", x);
-        }
-        if let Some(ref x) = input.include_values {
-            params.put("includeValues", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.name_query {
-            params.put("name", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<ApiKeys, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetApiKeysError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_api_keys(input)
     }
-
-    /// <p><p>Describe an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-authorizer.html">AWS CLI</a></div></p>
     fn get_authorizer(
         &self,
         input: GetAuthorizerRequest,
     ) -> RusotoFuture<Authorizer, GetAuthorizerError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/authorizers/{authorizer_id}",
-            authorizer_id = input.authorizer_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Authorizer, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAuthorizerError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_authorizer(input)
     }
-
-    /// <p><p>Describe an existing <a>Authorizers</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-authorizers.html">AWS CLI</a></div></p>
     fn get_authorizers(
         &self,
         input: GetAuthorizersRequest,
     ) -> RusotoFuture<Authorizers, GetAuthorizersError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/authorizers",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Authorizers, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAuthorizersError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_authorizers(input)
     }
-
-    /// <p>Describe a <a>BasePathMapping</a> resource.</p>
     fn get_base_path_mapping(
         &self,
         input: GetBasePathMappingRequest,
     ) -> RusotoFuture<BasePathMapping, GetBasePathMappingError> {
-        let request_uri = format!(
-            "/domainnames/{domain_name}/basepathmappings/{base_path}",
-            base_path = input.base_path,
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BasePathMapping, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBasePathMappingError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_base_path_mapping(input)
     }
-
-    /// <p>Represents a collection of <a>BasePathMapping</a> resources.</p>
     fn get_base_path_mappings(
         &self,
         input: GetBasePathMappingsRequest,
     ) -> RusotoFuture<BasePathMappings, GetBasePathMappingsError> {
-        let request_uri = format!(
-            "/domainnames/{domain_name}/basepathmappings",
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BasePathMappings, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetBasePathMappingsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_base_path_mappings(input)
     }
-
-    /// <p>Gets information about the current <a>ClientCertificate</a> resource.</p>
     fn get_client_certificate(
         &self,
         input: GetClientCertificateRequest,
     ) -> RusotoFuture<ClientCertificate, GetClientCertificateError> {
-        let request_uri = format!(
-            "/clientcertificates/{clientcertificate_id}",
-            clientcertificate_id = input.client_certificate_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ClientCertificate, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetClientCertificateError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_client_certificate(input)
     }
-
-    /// <p>Gets a collection of <a>ClientCertificate</a> resources.</p>
     fn get_client_certificates(
         &self,
         input: GetClientCertificatesRequest,
     ) -> RusotoFuture<ClientCertificates, GetClientCertificatesError> {
-        let request_uri = "/clientcertificates";
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ClientCertificates, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetClientCertificatesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_client_certificates(input)
     }
-
-    /// <p>Gets information about a <a>Deployment</a> resource.</p>
     fn get_deployment(
         &self,
         input: GetDeploymentRequest,
     ) -> RusotoFuture<Deployment, GetDeploymentError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/deployments/{deployment_id}",
-            deployment_id = input.deployment_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.embed {
-            for item in x.iter() {
-                params.put("embed", item);
-            }
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Deployment, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_deployment(input)
     }
-
-    /// <p>Gets information about a <a>Deployments</a> collection.</p>
     fn get_deployments(
         &self,
         input: GetDeploymentsRequest,
     ) -> RusotoFuture<Deployments, GetDeploymentsError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/deployments",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Deployments, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDeploymentsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_deployments(input)
     }
-
     fn get_documentation_part(
         &self,
         input: GetDocumentationPartRequest,
     ) -> RusotoFuture<DocumentationPart, GetDocumentationPartError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/parts/{part_id}",
-            part_id = input.documentation_part_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DocumentationPart, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetDocumentationPartError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_documentation_part(input)
     }
-
     fn get_documentation_parts(
         &self,
         input: GetDocumentationPartsRequest,
     ) -> RusotoFuture<DocumentationParts, GetDocumentationPartsError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/parts",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.location_status {
-            params.put("locationStatus", x);
-        }
-        if let Some(ref x) = input.name_query {
-            params.put("name", x);
-        }
-        if let Some(ref x) = input.path {
-            params.put("path", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        if let Some(ref x) = input.type_ {
-            params.put("type", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DocumentationParts, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetDocumentationPartsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_documentation_parts(input)
     }
-
     fn get_documentation_version(
         &self,
         input: GetDocumentationVersionRequest,
     ) -> RusotoFuture<DocumentationVersion, GetDocumentationVersionError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/versions/{doc_version}",
-            doc_version = input.documentation_version,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DocumentationVersion, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetDocumentationVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_documentation_version(input)
     }
-
     fn get_documentation_versions(
         &self,
         input: GetDocumentationVersionsRequest,
     ) -> RusotoFuture<DocumentationVersions, GetDocumentationVersionsError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/versions",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DocumentationVersions, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetDocumentationVersionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_documentation_versions(input)
     }
-
-    /// <p>Represents a domain name that is contained in a simpler, more intuitive URL that can be called.</p>
     fn get_domain_name(
         &self,
         input: GetDomainNameRequest,
     ) -> RusotoFuture<DomainName, GetDomainNameError> {
-        let request_uri = format!(
-            "/domainnames/{domain_name}",
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DomainName, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDomainNameError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_domain_name(input)
     }
-
-    /// <p>Represents a collection of <a>DomainName</a> resources.</p>
     fn get_domain_names(
         &self,
         input: GetDomainNamesRequest,
     ) -> RusotoFuture<DomainNames, GetDomainNamesError> {
-        let request_uri = "/domainnames";
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DomainNames, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDomainNamesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_domain_names(input)
     }
-
-    /// <p>Exports a deployed version of a <a>RestApi</a> in a specified format.</p>
     fn get_export(&self, input: GetExportRequest) -> RusotoFuture<ExportResponse, GetExportError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/stages/{stage_name}/exports/{export_type}",
-            export_type = input.export_type,
-            restapi_id = input.rest_api_id,
-            stage_name = input.stage_name
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref accepts) = input.accepts {
-            request.add_header("Accept", &accepts.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.parameters {
-            for (key, val) in x.iter() {
-                params.put(key, val);
-            }
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = ExportResponse::default();
-                    result.body = Some(response.body);
-
-                    if let Some(content_disposition) = response.headers.get("Content-Disposition") {
-                        let value = content_disposition.to_owned();
-                        result.content_disposition = Some(value)
-                    };
-                    if let Some(content_type) = response.headers.get("Content-Type") {
-                        let value = content_type.to_owned();
-                        result.content_type = Some(value)
-                    };
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetExportError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_export(input)
     }
-
-    /// <p>Gets a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
     fn get_gateway_response(
         &self,
         input: GetGatewayResponseRequest,
     ) -> RusotoFuture<GatewayResponse, GetGatewayResponseError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/gatewayresponses/{response_type}",
-            response_type = input.response_type,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GatewayResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetGatewayResponseError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_gateway_response(input)
     }
-
-    /// <p>Gets the <a>GatewayResponses</a> collection on the given <a>RestApi</a>. If an API developer has not added any definitions for gateway responses, the result will be the API Gateway-generated default <a>GatewayResponses</a> collection for the supported response types.</p>
     fn get_gateway_responses(
         &self,
         input: GetGatewayResponsesRequest,
     ) -> RusotoFuture<GatewayResponses, GetGatewayResponsesError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/gatewayresponses",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GatewayResponses, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetGatewayResponsesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_gateway_responses(input)
     }
-
-    /// <p>Get the integration settings.</p>
     fn get_integration(
         &self,
         input: GetIntegrationRequest,
     ) -> RusotoFuture<Integration, GetIntegrationError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
-            http_method = input.http_method,
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Integration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetIntegrationError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_integration(input)
     }
-
-    /// <p>Represents a get integration response.</p>
     fn get_integration_response(
         &self,
         input: GetIntegrationResponseRequest,
     ) -> RusotoFuture<IntegrationResponse, GetIntegrationResponseError> {
-        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<IntegrationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetIntegrationResponseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_integration_response(input)
     }
-
-    /// <p>Describe an existing <a>Method</a> resource.</p>
     fn get_method(&self, input: GetMethodRequest) -> RusotoFuture<Method, GetMethodError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
-            http_method = input.http_method,
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMethodError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_method(input)
     }
-
-    /// <p>Describes a <a>MethodResponse</a> resource.</p>
     fn get_method_response(
         &self,
         input: GetMethodResponseRequest,
     ) -> RusotoFuture<MethodResponse, GetMethodResponseError> {
-        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MethodResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMethodResponseError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_method_response(input)
     }
-
-    /// <p>Describes an existing model defined for a <a>RestApi</a> resource.</p>
     fn get_model(&self, input: GetModelRequest) -> RusotoFuture<Model, GetModelError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/models/{model_name}",
-            model_name = input.model_name,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.flatten {
-            params.put("flatten", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_model(input)
     }
-
-    /// <p>Generates a sample mapping template that can be used to transform a payload into the structure of a model.</p>
     fn get_model_template(
         &self,
         input: GetModelTemplateRequest,
     ) -> RusotoFuture<Template, GetModelTemplateError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/models/{model_name}/default_template",
-            model_name = input.model_name,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Template, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetModelTemplateError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_model_template(input)
     }
-
-    /// <p>Describes existing <a>Models</a> defined for a <a>RestApi</a> resource.</p>
     fn get_models(&self, input: GetModelsRequest) -> RusotoFuture<Models, GetModelsError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/models",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Models, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetModelsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_models(input)
     }
-
-    /// <p>Gets a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
     fn get_request_validator(
         &self,
         input: GetRequestValidatorRequest,
     ) -> RusotoFuture<RequestValidator, GetRequestValidatorError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/requestvalidators/{requestvalidator_id}",
-            requestvalidator_id = input.request_validator_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RequestValidator, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetRequestValidatorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_request_validator(input)
     }
-
-    /// <p>Gets the <a>RequestValidators</a> collection of a given <a>RestApi</a>.</p>
     fn get_request_validators(
         &self,
         input: GetRequestValidatorsRequest,
     ) -> RusotoFuture<RequestValidators, GetRequestValidatorsError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/requestvalidators",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RequestValidators, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetRequestValidatorsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_request_validators(input)
     }
-
-    /// <p>Lists information about a resource.</p>
     fn get_resource(&self, input: GetResourceRequest) -> RusotoFuture<Resource, GetResourceError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}",
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.embed {
-            for item in x.iter() {
-                params.put("embed", item);
-            }
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Resource, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_resource(input)
     }
-
-    /// <p>Lists information about a collection of <a>Resource</a> resources.</p>
     fn get_resources(
         &self,
         input: GetResourcesRequest,
     ) -> RusotoFuture<Resources, GetResourcesError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.embed {
-            for item in x.iter() {
-                params.put("embed", item);
-            }
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Resources, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetResourcesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_resources(input)
     }
-
-    /// <p>Lists the <a>RestApi</a> resource in the collection.</p>
     fn get_rest_api(&self, input: GetRestApiRequest) -> RusotoFuture<RestApi, GetRestApiError> {
-        let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRestApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_rest_api(input)
     }
-
-    /// <p>Lists the <a>RestApis</a> resources for your collection.</p>
     fn get_rest_apis(&self, input: GetRestApisRequest) -> RusotoFuture<RestApis, GetRestApisError> {
-        let request_uri = "/restapis";
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestApis, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRestApisError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_rest_apis(input)
     }
-
-    /// <p>Generates a client SDK for a <a>RestApi</a> and <a>Stage</a>.</p>
     fn get_sdk(&self, input: GetSdkRequest) -> RusotoFuture<SdkResponse, GetSdkError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/stages/{stage_name}/sdks/{sdk_type}",
-            restapi_id = input.rest_api_id,
-            sdk_type = input.sdk_type,
-            stage_name = input.stage_name
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.parameters {
-            for (key, val) in x.iter() {
-                params.put(key, val);
-            }
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = SdkResponse::default();
-                    result.body = Some(response.body);
-
-                    if let Some(content_disposition) = response.headers.get("Content-Disposition") {
-                        let value = content_disposition.to_owned();
-                        result.content_disposition = Some(value)
-                    };
-                    if let Some(content_type) = response.headers.get("Content-Type") {
-                        let value = content_type.to_owned();
-                        result.content_type = Some(value)
-                    };
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSdkError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_sdk(input)
     }
-
     fn get_sdk_type(&self, input: GetSdkTypeRequest) -> RusotoFuture<SdkType, GetSdkTypeError> {
-        let request_uri = format!("/sdktypes/{sdktype_id}", sdktype_id = input.id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<SdkType, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSdkTypeError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_sdk_type(input)
     }
-
     fn get_sdk_types(&self, input: GetSdkTypesRequest) -> RusotoFuture<SdkTypes, GetSdkTypesError> {
-        let request_uri = "/sdktypes";
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SdkTypes, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSdkTypesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_sdk_types(input)
     }
-
-    /// <p>Gets information about a <a>Stage</a> resource.</p>
     fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<Stage, GetStageError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/stages/{stage_name}",
-            restapi_id = input.rest_api_id,
-            stage_name = input.stage_name
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetStageError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_stage(input)
     }
-
-    /// <p>Gets information about one or more <a>Stage</a> resources.</p>
     fn get_stages(&self, input: GetStagesRequest) -> RusotoFuture<Stages, GetStagesError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/stages",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.deployment_id {
-            params.put("deploymentId", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Stages, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetStagesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_stages(input)
     }
-
-    /// <p>Gets the <a>Tags</a> collection for a given resource.</p>
     fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<Tags, GetTagsError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Tags, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_tags(input)
     }
-
-    /// <p>Gets the usage data of a usage plan in a specified time interval.</p>
     fn get_usage(&self, input: GetUsageRequest) -> RusotoFuture<Usage, GetUsageError> {
-        let request_uri = format!(
-            "/usageplans/{usageplan_id}/usage",
-            usageplan_id = input.usage_plan_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("endDate", &input.end_date);
-        if let Some(ref x) = input.key_id {
-            params.put("keyId", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        params.put("startDate", &input.start_date);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Usage, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUsageError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_usage(input)
     }
-
-    /// <p>Gets a usage plan of a given plan identifier.</p>
     fn get_usage_plan(
         &self,
         input: GetUsagePlanRequest,
     ) -> RusotoFuture<UsagePlan, GetUsagePlanError> {
-        let request_uri = format!(
-            "/usageplans/{usageplan_id}",
-            usageplan_id = input.usage_plan_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UsagePlan, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUsagePlanError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_usage_plan(input)
     }
-
-    /// <p>Gets a usage plan key of a given key identifier.</p>
     fn get_usage_plan_key(
         &self,
         input: GetUsagePlanKeyRequest,
     ) -> RusotoFuture<UsagePlanKey, GetUsagePlanKeyError> {
-        let request_uri = format!(
-            "/usageplans/{usageplan_id}/keys/{key_id}",
-            key_id = input.key_id,
-            usageplan_id = input.usage_plan_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UsagePlanKey, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUsagePlanKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_usage_plan_key(input)
     }
-
-    /// <p>Gets all the usage plan keys representing the API keys added to a specified usage plan.</p>
     fn get_usage_plan_keys(
         &self,
         input: GetUsagePlanKeysRequest,
     ) -> RusotoFuture<UsagePlanKeys, GetUsagePlanKeysError> {
-        let request_uri = format!(
-            "/usageplans/{usageplan_id}/keys",
-            usageplan_id = input.usage_plan_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.name_query {
-            params.put("name", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UsagePlanKeys, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUsagePlanKeysError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_usage_plan_keys(input)
     }
-
-    /// <p>Gets all the usage plans of the caller's account.</p>
     fn get_usage_plans(
         &self,
         input: GetUsagePlansRequest,
     ) -> RusotoFuture<UsagePlans, GetUsagePlansError> {
-        let request_uri = "/usageplans";
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.key_id {
-            params.put("keyId", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UsagePlans, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUsagePlansError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_usage_plans(input)
     }
-
-    /// <p>Gets a specified VPC link under the caller's account in a region.</p>
     fn get_vpc_link(&self, input: GetVpcLinkRequest) -> RusotoFuture<VpcLink, GetVpcLinkError> {
-        let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetVpcLinkError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_vpc_link(input)
     }
-
-    /// <p>Gets the <a>VpcLinks</a> collection under the caller's account in a selected region.</p>
     fn get_vpc_links(&self, input: GetVpcLinksRequest) -> RusotoFuture<VpcLinks, GetVpcLinksError> {
-        let request_uri = "/vpclinks";
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.position {
-            params.put("position", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VpcLinks, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetVpcLinksError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_vpc_links(input)
     }
-
-    /// <p>Import API keys from an external source, such as a CSV-formatted file.</p>
     fn import_api_keys(
         &self,
         input: ImportApiKeysRequest,
     ) -> RusotoFuture<ApiKeyIds, ImportApiKeysError> {
-        let request_uri = "/apikeys";
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(input.body.to_owned());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.fail_on_warnings {
-            params.put("failonwarnings", x);
-        }
-        params.put("format", &input.format);
-        params.put("mode", "import");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ApiKeyIds, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ImportApiKeysError::from_response(response))),
-                )
-            }
-        })
+        (**self).import_api_keys(input)
     }
-
     fn import_documentation_parts(
         &self,
         input: ImportDocumentationPartsRequest,
     ) -> RusotoFuture<DocumentationPartIds, ImportDocumentationPartsError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/parts",
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(input.body.to_owned());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.fail_on_warnings {
-            params.put("failonwarnings", x);
-        }
-        if let Some(ref x) = input.mode {
-            params.put("mode", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DocumentationPartIds, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ImportDocumentationPartsError::from_response(response))
-                }))
-            }
-        })
+        (**self).import_documentation_parts(input)
     }
-
-    /// <p>A feature of the API Gateway control service for creating a new API from an external API definition file.</p>
     fn import_rest_api(
         &self,
         input: ImportRestApiRequest,
     ) -> RusotoFuture<RestApi, ImportRestApiError> {
-        let request_uri = "/restapis";
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(input.body.to_owned());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.fail_on_warnings {
-            params.put("failonwarnings", x);
-        }
-        if let Some(ref x) = input.parameters {
-            for (key, val) in x.iter() {
-                params.put(key, val);
-            }
-        }
-        params.put("mode", "import");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ImportRestApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).import_rest_api(input)
     }
-
-    /// <p>Creates a customization of a <a>GatewayResponse</a> of a specified response type and status code on the given <a>RestApi</a>.</p>
     fn put_gateway_response(
         &self,
         input: PutGatewayResponseRequest,
     ) -> RusotoFuture<GatewayResponse, PutGatewayResponseError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/gatewayresponses/{response_type}",
-            response_type = input.response_type,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GatewayResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutGatewayResponseError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_gateway_response(input)
     }
-
-    /// <p>Sets up a method's integration.</p>
     fn put_integration(
         &self,
         input: PutIntegrationRequest,
     ) -> RusotoFuture<Integration, PutIntegrationError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
-            http_method = input.http_method,
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Integration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutIntegrationError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_integration(input)
     }
-
-    /// <p>Represents a put integration.</p>
     fn put_integration_response(
         &self,
         input: PutIntegrationResponseRequest,
     ) -> RusotoFuture<IntegrationResponse, PutIntegrationResponseError> {
-        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
-
-        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<IntegrationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutIntegrationResponseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).put_integration_response(input)
     }
-
-    /// <p>Add a method to an existing <a>Resource</a> resource.</p>
     fn put_method(&self, input: PutMethodRequest) -> RusotoFuture<Method, PutMethodError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
-            http_method = input.http_method,
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutMethodError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_method(input)
     }
-
-    /// <p>Adds a <a>MethodResponse</a> to an existing <a>Method</a> resource.</p>
     fn put_method_response(
         &self,
         input: PutMethodResponseRequest,
     ) -> RusotoFuture<MethodResponse, PutMethodResponseError> {
-        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
-
-        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MethodResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutMethodResponseError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_method_response(input)
     }
-
-    /// <p>A feature of the API Gateway control service for updating an existing API with an input of external API definitions. The update can take the form of merging the supplied definition into the existing API or overwriting the existing API.</p>
     fn put_rest_api(&self, input: PutRestApiRequest) -> RusotoFuture<RestApi, PutRestApiError> {
-        let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
-
-        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(input.body.to_owned());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.fail_on_warnings {
-            params.put("failonwarnings", x);
-        }
-        if let Some(ref x) = input.mode {
-            params.put("mode", x);
-        }
-        if let Some(ref x) = input.parameters {
-            for (key, val) in x.iter() {
-                params.put(key, val);
-            }
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutRestApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_rest_api(input)
     }
-
-    /// <p>Adds or updates a tag on a given resource.</p>
     fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).tag_resource(input)
     }
-
-    /// <p><p>Simulate the execution of an <a>Authorizer</a> in your <a>RestApi</a> with headers, parameters, and an incoming request body.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-use-lambda-authorizer.html">Use Lambda Function as Authorizer</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-integrate-with-cognito.html">Use Cognito User Pool as Authorizer</a> </div></p>
     fn test_invoke_authorizer(
         &self,
         input: TestInvokeAuthorizerRequest,
     ) -> RusotoFuture<TestInvokeAuthorizerResponse, TestInvokeAuthorizerError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/authorizers/{authorizer_id}",
-            authorizer_id = input.authorizer_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TestInvokeAuthorizerResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(TestInvokeAuthorizerError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).test_invoke_authorizer(input)
     }
-
-    /// <p>Simulate the execution of a <a>Method</a> in your <a>RestApi</a> with headers, parameters, and an incoming request body.</p>
     fn test_invoke_method(
         &self,
         input: TestInvokeMethodRequest,
     ) -> RusotoFuture<TestInvokeMethodResponse, TestInvokeMethodError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
-            http_method = input.http_method,
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TestInvokeMethodResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TestInvokeMethodError::from_response(response))),
-                )
-            }
-        })
+        (**self).test_invoke_method(input)
     }
-
-    /// <p>Removes a tag from a given resource.</p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).untag_resource(input)
     }
-
-    /// <p>Changes information about the current <a>Account</a> resource.</p>
     fn update_account(
         &self,
         input: UpdateAccountRequest,
     ) -> RusotoFuture<Account, UpdateAccountError> {
-        let request_uri = "/account";
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Account, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAccountError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_account(input)
     }
-
-    /// <p>Changes information about an <a>ApiKey</a> resource.</p>
     fn update_api_key(
         &self,
         input: UpdateApiKeyRequest,
     ) -> RusotoFuture<ApiKey, UpdateApiKeyError> {
-        let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateApiKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_api_key(input)
     }
-
-    /// <p><p>Updates an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/update-authorizer.html">AWS CLI</a></div></p>
     fn update_authorizer(
         &self,
         input: UpdateAuthorizerRequest,
     ) -> RusotoFuture<Authorizer, UpdateAuthorizerError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/authorizers/{authorizer_id}",
-            authorizer_id = input.authorizer_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Authorizer, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAuthorizerError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_authorizer(input)
     }
-
-    /// <p>Changes information about the <a>BasePathMapping</a> resource.</p>
     fn update_base_path_mapping(
         &self,
         input: UpdateBasePathMappingRequest,
     ) -> RusotoFuture<BasePathMapping, UpdateBasePathMappingError> {
-        let request_uri = format!(
-            "/domainnames/{domain_name}/basepathmappings/{base_path}",
-            base_path = input.base_path,
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BasePathMapping, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateBasePathMappingError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).update_base_path_mapping(input)
     }
-
-    /// <p>Changes information about an <a>ClientCertificate</a> resource.</p>
     fn update_client_certificate(
         &self,
         input: UpdateClientCertificateRequest,
     ) -> RusotoFuture<ClientCertificate, UpdateClientCertificateError> {
-        let request_uri = format!(
-            "/clientcertificates/{clientcertificate_id}",
-            clientcertificate_id = input.client_certificate_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ClientCertificate, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateClientCertificateError::from_response(response))
-                }))
-            }
-        })
+        (**self).update_client_certificate(input)
     }
-
-    /// <p>Changes information about a <a>Deployment</a> resource.</p>
     fn update_deployment(
         &self,
         input: UpdateDeploymentRequest,
     ) -> RusotoFuture<Deployment, UpdateDeploymentError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/deployments/{deployment_id}",
-            deployment_id = input.deployment_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Deployment, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_deployment(input)
     }
-
     fn update_documentation_part(
         &self,
         input: UpdateDocumentationPartRequest,
     ) -> RusotoFuture<DocumentationPart, UpdateDocumentationPartError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/parts/{part_id}",
-            part_id = input.documentation_part_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DocumentationPart, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateDocumentationPartError::from_response(response))
-                }))
-            }
-        })
+        (**self).update_documentation_part(input)
     }
-
     fn update_documentation_version(
         &self,
         input: UpdateDocumentationVersionRequest,
     ) -> RusotoFuture<DocumentationVersion, UpdateDocumentationVersionError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/documentation/versions/{doc_version}",
-            doc_version = input.documentation_version,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DocumentationVersion, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateDocumentationVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).update_documentation_version(input)
     }
-
-    /// <p>Changes information about the <a>DomainName</a> resource.</p>
     fn update_domain_name(
         &self,
         input: UpdateDomainNameRequest,
     ) -> RusotoFuture<DomainName, UpdateDomainNameError> {
-        let request_uri = format!(
-            "/domainnames/{domain_name}",
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DomainName, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDomainNameError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_domain_name(input)
     }
-
-    /// <p>Updates a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
     fn update_gateway_response(
         &self,
         input: UpdateGatewayResponseRequest,
     ) -> RusotoFuture<GatewayResponse, UpdateGatewayResponseError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/gatewayresponses/{response_type}",
-            response_type = input.response_type,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GatewayResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateGatewayResponseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).update_gateway_response(input)
     }
-
-    /// <p>Represents an update integration.</p>
     fn update_integration(
         &self,
         input: UpdateIntegrationRequest,
     ) -> RusotoFuture<Integration, UpdateIntegrationError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
-            http_method = input.http_method,
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Integration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateIntegrationError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_integration(input)
     }
-
-    /// <p>Represents an update integration response.</p>
     fn update_integration_response(
         &self,
         input: UpdateIntegrationResponseRequest,
     ) -> RusotoFuture<IntegrationResponse, UpdateIntegrationResponseError> {
-        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<IntegrationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateIntegrationResponseError::from_response(response))
-                }))
-            }
-        })
+        (**self).update_integration_response(input)
     }
-
-    /// <p>Updates an existing <a>Method</a> resource.</p>
     fn update_method(&self, input: UpdateMethodRequest) -> RusotoFuture<Method, UpdateMethodError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
-            http_method = input.http_method,
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateMethodError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_method(input)
     }
-
-    /// <p>Updates an existing <a>MethodResponse</a> resource.</p>
     fn update_method_response(
         &self,
         input: UpdateMethodResponseRequest,
     ) -> RusotoFuture<MethodResponse, UpdateMethodResponseError> {
-        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MethodResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateMethodResponseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).update_method_response(input)
+    }
+    fn update_model(&self, input: UpdateModelRequest) -> RusotoFuture<Model, UpdateModelError> {
+        (**self).update_model(input)
+    }
+    fn update_request_validator(
+        &self,
+        input: UpdateRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, UpdateRequestValidatorError> {
+        (**self).update_request_validator(input)
+    }
+    fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> RusotoFuture<Resource, UpdateResourceError> {
+        (**self).update_resource(input)
+    }
+    fn update_rest_api(
+        &self,
+        input: UpdateRestApiRequest,
+    ) -> RusotoFuture<RestApi, UpdateRestApiError> {
+        (**self).update_rest_api(input)
+    }
+    fn update_stage(&self, input: UpdateStageRequest) -> RusotoFuture<Stage, UpdateStageError> {
+        (**self).update_stage(input)
+    }
+    fn update_usage(&self, input: UpdateUsageRequest) -> RusotoFuture<Usage, UpdateUsageError> {
+        (**self).update_usage(input)
+    }
+    fn update_usage_plan(
+        &self,
+        input: UpdateUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, UpdateUsagePlanError> {
+        (**self).update_usage_plan(input)
+    }
+    fn update_vpc_link(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> RusotoFuture<VpcLink, UpdateVpcLinkError> {
+        (**self).update_vpc_link(input)
+    }
+}
+impl<T: ApiGateway + ?Sized> ApiGateway for Box<T> {
+    fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> RusotoFuture<ApiKey, CreateApiKeyError> {
+        (**self).create_api_key(input)
+    }
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, CreateAuthorizerError> {
+        (**self).create_authorizer(input)
+    }
+    fn create_base_path_mapping(
+        &self,
+        input: CreateBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, CreateBasePathMappingError> {
+        (**self).create_base_path_mapping(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<Deployment, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_documentation_part(
+        &self,
+        input: CreateDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, CreateDocumentationPartError> {
+        (**self).create_documentation_part(input)
+    }
+    fn create_documentation_version(
+        &self,
+        input: CreateDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, CreateDocumentationVersionError> {
+        (**self).create_documentation_version(input)
+    }
+    fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> RusotoFuture<DomainName, CreateDomainNameError> {
+        (**self).create_domain_name(input)
+    }
+    fn create_model(&self, input: CreateModelRequest) -> RusotoFuture<Model, CreateModelError> {
+        (**self).create_model(input)
+    }
+    fn create_request_validator(
+        &self,
+        input: CreateRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, CreateRequestValidatorError> {
+        (**self).create_request_validator(input)
+    }
+    fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> RusotoFuture<Resource, CreateResourceError> {
+        (**self).create_resource(input)
+    }
+    fn create_rest_api(
+        &self,
+        input: CreateRestApiRequest,
+    ) -> RusotoFuture<RestApi, CreateRestApiError> {
+        (**self).create_rest_api(input)
+    }
+    fn create_stage(&self, input: CreateStageRequest) -> RusotoFuture<Stage, CreateStageError> {
+        (**self).create_stage(input)
+    }
+    fn create_usage_plan(
+        &self,
+        input: CreateUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, CreateUsagePlanError> {
+        (**self).create_usage_plan(input)
+    }
+    fn create_usage_plan_key(
+        &self,
+        input: CreateUsagePlanKeyRequest,
+    ) -> RusotoFuture<UsagePlanKey, CreateUsagePlanKeyError> {
+        (**self).create_usage_plan_key(input)
+    }
+    fn create_vpc_link(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> RusotoFuture<VpcLink, CreateVpcLinkError> {
+        (**self).create_vpc_link(input)
+    }
+    fn delete_api_key(&self, input: DeleteApiKeyRequest) -> RusotoFuture<(), DeleteApiKeyError> {
+        (**self).delete_api_key(input)
+    }
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<(), DeleteAuthorizerError> {
+        (**self).delete_authorizer(input)
+    }
+    fn delete_base_path_mapping(
+        &self,
+        input: DeleteBasePathMappingRequest,
+    ) -> RusotoFuture<(), DeleteBasePathMappingError> {
+        (**self).delete_base_path_mapping(input)
+    }
+    fn delete_client_certificate(
+        &self,
+        input: DeleteClientCertificateRequest,
+    ) -> RusotoFuture<(), DeleteClientCertificateError> {
+        (**self).delete_client_certificate(input)
+    }
+    fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> RusotoFuture<(), DeleteDeploymentError> {
+        (**self).delete_deployment(input)
+    }
+    fn delete_documentation_part(
+        &self,
+        input: DeleteDocumentationPartRequest,
+    ) -> RusotoFuture<(), DeleteDocumentationPartError> {
+        (**self).delete_documentation_part(input)
+    }
+    fn delete_documentation_version(
+        &self,
+        input: DeleteDocumentationVersionRequest,
+    ) -> RusotoFuture<(), DeleteDocumentationVersionError> {
+        (**self).delete_documentation_version(input)
+    }
+    fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> RusotoFuture<(), DeleteDomainNameError> {
+        (**self).delete_domain_name(input)
+    }
+    fn delete_gateway_response(
+        &self,
+        input: DeleteGatewayResponseRequest,
+    ) -> RusotoFuture<(), DeleteGatewayResponseError> {
+        (**self).delete_gateway_response(input)
+    }
+    fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationError> {
+        (**self).delete_integration(input)
+    }
+    fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
+        (**self).delete_integration_response(input)
+    }
+    fn delete_method(&self, input: DeleteMethodRequest) -> RusotoFuture<(), DeleteMethodError> {
+        (**self).delete_method(input)
+    }
+    fn delete_method_response(
+        &self,
+        input: DeleteMethodResponseRequest,
+    ) -> RusotoFuture<(), DeleteMethodResponseError> {
+        (**self).delete_method_response(input)
+    }
+    fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
+        (**self).delete_model(input)
+    }
+    fn delete_request_validator(
+        &self,
+        input: DeleteRequestValidatorRequest,
+    ) -> RusotoFuture<(), DeleteRequestValidatorError> {
+        (**self).delete_request_validator(input)
+    }
+    fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> RusotoFuture<(), DeleteResourceError> {
+        (**self).delete_resource(input)
+    }
+    fn delete_rest_api(&self, input: DeleteRestApiRequest) -> RusotoFuture<(), DeleteRestApiError> {
+        (**self).delete_rest_api(input)
+    }
+    fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
+        (**self).delete_stage(input)
+    }
+    fn delete_usage_plan(
+        &self,
+        input: DeleteUsagePlanRequest,
+    ) -> RusotoFuture<(), DeleteUsagePlanError> {
+        (**self).delete_usage_plan(input)
+    }
+    fn delete_usage_plan_key(
+        &self,
+        input: DeleteUsagePlanKeyRequest,
+    ) -> RusotoFuture<(), DeleteUsagePlanKeyError> {
+        (**self).delete_usage_plan_key(input)
+    }
+    fn delete_vpc_link(&self, input: DeleteVpcLinkRequest) -> RusotoFuture<(), DeleteVpcLinkError> {
+        (**self).delete_vpc_link(input)
+    }
+    fn flush_stage_authorizers_cache(
+        &self,
+        input: FlushStageAuthorizersCacheRequest,
+    ) -> RusotoFuture<(), FlushStageAuthorizersCacheError> {
+        (**self).flush_stage_authorizers_cache(input)
+    }
+    fn flush_stage_cache(
+        &self,
+        input: FlushStageCacheRequest,
+    ) -> RusotoFuture<(), FlushStageCacheError> {
+        (**self).flush_stage_cache(input)
+    }
+    fn generate_client_certificate(
+        &self,
+        input: GenerateClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, GenerateClientCertificateError> {
+        (**self).generate_client_certificate(input)
+    }
+    fn get_account(&self) -> RusotoFuture<Account, GetAccountError> {
+        (**self).get_account()
+    }
+    fn get_api_key(&self, input: GetApiKeyRequest) -> RusotoFuture<ApiKey, GetApiKeyError> {
+        (**self).get_api_key(input)
+    }
+    fn get_api_keys(&self, input: GetApiKeysRequest) -> RusotoFuture<ApiKeys, GetApiKeysError> {
+        (**self).get_api_keys(input)
+    }
+    fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, GetAuthorizerError> {
+        (**self).get_authorizer(input)
+    }
+    fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> RusotoFuture<Authorizers, GetAuthorizersError> {
+        (**self).get_authorizers(input)
+    }
+    fn get_base_path_mapping(
+        &self,
+        input: GetBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, GetBasePathMappingError> {
+        (**self).get_base_path_mapping(input)
+    }
+    fn get_base_path_mappings(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> RusotoFuture<BasePathMappings, GetBasePathMappingsError> {
+        (**self).get_base_path_mappings(input)
+    }
+    fn get_client_certificate(
+        &self,
+        input: GetClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, GetClientCertificateError> {
+        (**self).get_client_certificate(input)
+    }
+    fn get_client_certificates(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> RusotoFuture<ClientCertificates, GetClientCertificatesError> {
+        (**self).get_client_certificates(input)
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> RusotoFuture<Deployment, GetDeploymentError> {
+        (**self).get_deployment(input)
+    }
+    fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> RusotoFuture<Deployments, GetDeploymentsError> {
+        (**self).get_deployments(input)
+    }
+    fn get_documentation_part(
+        &self,
+        input: GetDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, GetDocumentationPartError> {
+        (**self).get_documentation_part(input)
+    }
+    fn get_documentation_parts(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> RusotoFuture<DocumentationParts, GetDocumentationPartsError> {
+        (**self).get_documentation_parts(input)
+    }
+    fn get_documentation_version(
+        &self,
+        input: GetDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, GetDocumentationVersionError> {
+        (**self).get_documentation_version(input)
+    }
+    fn get_documentation_versions(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> RusotoFuture<DocumentationVersions, GetDocumentationVersionsError> {
+        (**self).get_documentation_versions(input)
+    }
+    fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> RusotoFuture<DomainName, GetDomainNameError> {
+        (**self).get_domain_name(input)
+    }
+    fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> RusotoFuture<DomainNames, GetDomainNamesError> {
+        (**self).get_domain_names(input)
+    }
+    fn get_export(&self, input: GetExportRequest) -> RusotoFuture<ExportResponse, GetExportError> {
+        (**self).get_export(input)
+    }
+    fn get_gateway_response(
+        &self,
+        input: GetGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, GetGatewayResponseError> {
+        (**self).get_gateway_response(input)
+    }
+    fn get_gateway_responses(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> RusotoFuture<GatewayResponses, GetGatewayResponsesError> {
+        (**self).get_gateway_responses(input)
+    }
+    fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> RusotoFuture<Integration, GetIntegrationError> {
+        (**self).get_integration(input)
+    }
+    fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, GetIntegrationResponseError> {
+        (**self).get_integration_response(input)
+    }
+    fn get_method(&self, input: GetMethodRequest) -> RusotoFuture<Method, GetMethodError> {
+        (**self).get_method(input)
+    }
+    fn get_method_response(
+        &self,
+        input: GetMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, GetMethodResponseError> {
+        (**self).get_method_response(input)
+    }
+    fn get_model(&self, input: GetModelRequest) -> RusotoFuture<Model, GetModelError> {
+        (**self).get_model(input)
+    }
+    fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> RusotoFuture<Template, GetModelTemplateError> {
+        (**self).get_model_template(input)
+    }
+    fn get_models(&self, input: GetModelsRequest) -> RusotoFuture<Models, GetModelsError> {
+        (**self).get_models(input)
+    }
+    fn get_request_validator(
+        &self,
+        input: GetRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, GetRequestValidatorError> {
+        (**self).get_request_validator(input)
+    }
+    fn get_request_validators(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> RusotoFuture<RequestValidators, GetRequestValidatorsError> {
+        (**self).get_request_validators(input)
+    }
+    fn get_resource(&self, input: GetResourceRequest) -> RusotoFuture<Resource, GetResourceError> {
+        (**self).get_resource(input)
+    }
+    fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> RusotoFuture<Resources, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn get_rest_api(&self, input: GetRestApiRequest) -> RusotoFuture<RestApi, GetRestApiError> {
+        (**self).get_rest_api(input)
+    }
+    fn get_rest_apis(&self, input: GetRestApisRequest) -> RusotoFuture<RestApis, GetRestApisError> {
+        (**self).get_rest_apis(input)
+    }
+    fn get_sdk(&self, input: GetSdkRequest) -> RusotoFuture<SdkResponse, GetSdkError> {
+        (**self).get_sdk(input)
+    }
+    fn get_sdk_type(&self, input: GetSdkTypeRequest) -> RusotoFuture<SdkType, GetSdkTypeError> {
+        (**self).get_sdk_type(input)
+    }
+    fn get_sdk_types(&self, input: GetSdkTypesRequest) -> RusotoFuture<SdkTypes, GetSdkTypesError> {
+        (**self).get_sdk_types(input)
+    }
+    fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<Stage, GetStageError> {
+        (**self).get_stage(input)
+    }
+    fn get_stages(&self, input: GetStagesRequest) -> RusotoFuture<Stages, GetStagesError> {
+        (**self).get_stages(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<Tags, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn get_usage(&self, input: GetUsageRequest) -> RusotoFuture<Usage, GetUsageError> {
+        (**self).get_usage(input)
+    }
+    fn get_usage_plan(
+        &self,
+        input: GetUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, GetUsagePlanError> {
+        (**self).get_usage_plan(input)
+    }
+    fn get_usage_plan_key(
+        &self,
+        input: GetUsagePlanKeyRequest,
+    ) -> RusotoFuture<UsagePlanKey, GetUsagePlanKeyError> {
+        (**self).get_usage_plan_key(input)
+    }
+    fn get_usage_plan_keys(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> RusotoFuture<UsagePlanKeys, GetUsagePlanKeysError> {
+        (**self).get_usage_plan_keys(input)
+    }
+    fn get_usage_plans(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> RusotoFuture<UsagePlans, GetUsagePlansError> {
+        (**self).get_usage_plans(input)
+    }
+    fn get_vpc_link(&self, input: GetVpcLinkRequest) -> RusotoFuture<VpcLink, GetVpcLinkError> {
+        (**self).get_vpc_link(input)
+    }
+    fn get_vpc_links(&self, input: GetVpcLinksRequest) -> RusotoFuture<VpcLinks, GetVpcLinksError> {
+        (**self).get_vpc_links(input)
+    }
+    fn import_api_keys(
+        &self,
+        input: ImportApiKeysRequest,
+    ) -> RusotoFuture<ApiKeyIds, ImportApiKeysError> {
+        (**self).import_api_keys(input)
+    }
+    fn import_documentation_parts(
+        &self,
+        input: ImportDocumentationPartsRequest,
+    ) -> RusotoFuture<DocumentationPartIds, ImportDocumentationPartsError> {
+        (**self).import_documentation_parts(input)
+    }
+    fn import_rest_api(
+        &self,
+        input: ImportRestApiRequest,
+    ) -> RusotoFuture<RestApi, ImportRestApiError> {
+        (**self).import_rest_api(input)
+    }
+    fn put_gateway_response(
+        &self,
+        input: PutGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, PutGatewayResponseError> {
+        (**self).put_gateway_response(input)
+    }
+    fn put_integration(
+        &self,
+        input: PutIntegrationRequest,
+    ) -> RusotoFuture<Integration, PutIntegrationError> {
+        (**self).put_integration(input)
+    }
+    fn put_integration_response(
+        &self,
+        input: PutIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, PutIntegrationResponseError> {
+        (**self).put_integration_response(input)
+    }
+    fn put_method(&self, input: PutMethodRequest) -> RusotoFuture<Method, PutMethodError> {
+        (**self).put_method(input)
+    }
+    fn put_method_response(
+        &self,
+        input: PutMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, PutMethodResponseError> {
+        (**self).put_method_response(input)
+    }
+    fn put_rest_api(&self, input: PutRestApiRequest) -> RusotoFuture<RestApi, PutRestApiError> {
+        (**self).put_rest_api(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> RusotoFuture<TestInvokeAuthorizerResponse, TestInvokeAuthorizerError> {
+        (**self).test_invoke_authorizer(input)
+    }
+    fn test_invoke_method(
+        &self,
+        input: TestInvokeMethodRequest,
+    ) -> RusotoFuture<TestInvokeMethodResponse, TestInvokeMethodError> {
+        (**self).test_invoke_method(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> RusotoFuture<Account, UpdateAccountError> {
+        (**self).update_account(input)
+    }
+    fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> RusotoFuture<ApiKey, UpdateApiKeyError> {
+        (**self).update_api_key(input)
+    }
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, UpdateAuthorizerError> {
+        (**self).update_authorizer(input)
+    }
+    fn update_base_path_mapping(
+        &self,
+        input: UpdateBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, UpdateBasePathMappingError> {
+        (**self).update_base_path_mapping(input)
+    }
+    fn update_client_certificate(
+        &self,
+        input: UpdateClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, UpdateClientCertificateError> {
+        (**self).update_client_certificate(input)
+    }
+    fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> RusotoFuture<Deployment, UpdateDeploymentError> {
+        (**self).update_deployment(input)
+    }
+    fn update_documentation_part(
+        &self,
+        input: UpdateDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, UpdateDocumentationPartError> {
+        (**self).update_documentation_part(input)
+    }
+    fn update_documentation_version(
+        &self,
+        input: UpdateDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, UpdateDocumentationVersionError> {
+        (**self).update_documentation_version(input)
+    }
+    fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> RusotoFuture<DomainName, UpdateDomainNameError> {
+        (**self).update_domain_name(input)
+    }
+    fn update_gateway_response(
+        &self,
+        input: UpdateGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, UpdateGatewayResponseError> {
+        (**self).update_gateway_response(input)
+    }
+    fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> RusotoFuture<Integration, UpdateIntegrationError> {
+        (**self).update_integration(input)
+    }
+    fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, UpdateIntegrationResponseError> {
+        (**self).update_integration_response(input)
+    }
+    fn update_method(&self, input: UpdateMethodRequest) -> RusotoFuture<Method, UpdateMethodError> {
+        (**self).update_method(input)
+    }
+    fn update_method_response(
+        &self,
+        input: UpdateMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, UpdateMethodResponseError> {
+        (**self).update_method_response(input)
+    }
+    fn update_model(&self, input: UpdateModelRequest) -> RusotoFuture<Model, UpdateModelError> {
+        (**self).update_model(input)
+    }
+    fn update_request_validator(
+        &self,
+        input: UpdateRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, UpdateRequestValidatorError> {
+        (**self).update_request_validator(input)
+    }
+    fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> RusotoFuture<Resource, UpdateResourceError> {
+        (**self).update_resource(input)
+    }
+    fn update_rest_api(
+        &self,
+        input: UpdateRestApiRequest,
+    ) -> RusotoFuture<RestApi, UpdateRestApiError> {
+        (**self).update_rest_api(input)
+    }
+    fn update_stage(&self, input: UpdateStageRequest) -> RusotoFuture<Stage, UpdateStageError> {
+        (**self).update_stage(input)
+    }
+    fn update_usage(&self, input: UpdateUsageRequest) -> RusotoFuture<Usage, UpdateUsageError> {
+        (**self).update_usage(input)
+    }
+    fn update_usage_plan(
+        &self,
+        input: UpdateUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, UpdateUsagePlanError> {
+        (**self).update_usage_plan(input)
+    }
+    fn update_vpc_link(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> RusotoFuture<VpcLink, UpdateVpcLinkError> {
+        (**self).update_vpc_link(input)
+    }
+}
+impl<T: ApiGateway + ?Sized> ApiGateway for ::std::sync::Arc<T> {
+    fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> RusotoFuture<ApiKey, CreateApiKeyError> {
+        (**self).create_api_key(input)
+    }
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, CreateAuthorizerError> {
+        (**self).create_authorizer(input)
+    }
+    fn create_base_path_mapping(
+        &self,
+        input: CreateBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, CreateBasePathMappingError> {
+        (**self).create_base_path_mapping(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<Deployment, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_documentation_part(
+        &self,
+        input: CreateDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, CreateDocumentationPartError> {
+        (**self).create_documentation_part(input)
+    }
+    fn create_documentation_version(
+        &self,
+        input: CreateDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, CreateDocumentationVersionError> {
+        (**self).create_documentation_version(input)
+    }
+    fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> RusotoFuture<DomainName, CreateDomainNameError> {
+        (**self).create_domain_name(input)
+    }
+    fn create_model(&self, input: CreateModelRequest) -> RusotoFuture<Model, CreateModelError> {
+        (**self).create_model(input)
+    }
+    fn create_request_validator(
+        &self,
+        input: CreateRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, CreateRequestValidatorError> {
+        (**self).create_request_validator(input)
+    }
+    fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> RusotoFuture<Resource, CreateResourceError> {
+        (**self).create_resource(input)
+    }
+    fn create_rest_api(
+        &self,
+        input: CreateRestApiRequest,
+    ) -> RusotoFuture<RestApi, CreateRestApiError> {
+        (**self).create_rest_api(input)
+    }
+    fn create_stage(&self, input: CreateStageRequest) -> RusotoFuture<Stage, CreateStageError> {
+        (**self).create_stage(input)
+    }
+    fn create_usage_plan(
+        &self,
+        input: CreateUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, CreateUsagePlanError> {
+        (**self).create_usage_plan(input)
+    }
+    fn create_usage_plan_key(
+        &self,
+        input: CreateUsagePlanKeyRequest,
+    ) -> RusotoFuture<UsagePlanKey, CreateUsagePlanKeyError> {
+        (**self).create_usage_plan_key(input)
+    }
+    fn create_vpc_link(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> RusotoFuture<VpcLink, CreateVpcLinkError> {
+        (**self).create_vpc_link(input)
+    }
+    fn delete_api_key(&self, input: DeleteApiKeyRequest) -> RusotoFuture<(), DeleteApiKeyError> {
+        (**self).delete_api_key(input)
+    }
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<(), DeleteAuthorizerError> {
+        (**self).delete_authorizer(input)
+    }
+    fn delete_base_path_mapping(
+        &self,
+        input: DeleteBasePathMappingRequest,
+    ) -> RusotoFuture<(), DeleteBasePathMappingError> {
+        (**self).delete_base_path_mapping(input)
+    }
+    fn delete_client_certificate(
+        &self,
+        input: DeleteClientCertificateRequest,
+    ) -> RusotoFuture<(), DeleteClientCertificateError> {
+        (**self).delete_client_certificate(input)
+    }
+    fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> RusotoFuture<(), DeleteDeploymentError> {
+        (**self).delete_deployment(input)
+    }
+    fn delete_documentation_part(
+        &self,
+        input: DeleteDocumentationPartRequest,
+    ) -> RusotoFuture<(), DeleteDocumentationPartError> {
+        (**self).delete_documentation_part(input)
+    }
+    fn delete_documentation_version(
+        &self,
+        input: DeleteDocumentationVersionRequest,
+    ) -> RusotoFuture<(), DeleteDocumentationVersionError> {
+        (**self).delete_documentation_version(input)
+    }
+    fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> RusotoFuture<(), DeleteDomainNameError> {
+        (**self).delete_domain_name(input)
+    }
+    fn delete_gateway_response(
+        &self,
+        input: DeleteGatewayResponseRequest,
+    ) -> RusotoFuture<(), DeleteGatewayResponseError> {
+        (**self).delete_gateway_response(input)
+    }
+    fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationError> {
+        (**self).delete_integration(input)
+    }
+    fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
+        (**self).delete_integration_response(input)
+    }
+    fn delete_method(&self, input: DeleteMethodRequest) -> RusotoFuture<(), DeleteMethodError> {
+        (**self).delete_method(input)
+    }
+    fn delete_method_response(
+        &self,
+        input: DeleteMethodResponseRequest,
+    ) -> RusotoFuture<(), DeleteMethodResponseError> {
+        (**self).delete_method_response(input)
+    }
+    fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
+        (**self).delete_model(input)
+    }
+    fn delete_request_validator(
+        &self,
+        input: DeleteRequestValidatorRequest,
+    ) -> RusotoFuture<(), DeleteRequestValidatorError> {
+        (**self).delete_request_validator(input)
+    }
+    fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> RusotoFuture<(), DeleteResourceError> {
+        (**self).delete_resource(input)
+    }
+    fn delete_rest_api(&self, input: DeleteRestApiRequest) -> RusotoFuture<(), DeleteRestApiError> {
+        (**self).delete_rest_api(input)
+    }
+    fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
+        (**self).delete_stage(input)
+    }
+    fn delete_usage_plan(
+        &self,
+        input: DeleteUsagePlanRequest,
+    ) -> RusotoFuture<(), DeleteUsagePlanError> {
+        (**self).delete_usage_plan(input)
+    }
+    fn delete_usage_plan_key(
+        &self,
+        input: DeleteUsagePlanKeyRequest,
+    ) -> RusotoFuture<(), DeleteUsagePlanKeyError> {
+        (**self).delete_usage_plan_key(input)
+    }
+    fn delete_vpc_link(&self, input: DeleteVpcLinkRequest) -> RusotoFuture<(), DeleteVpcLinkError> {
+        (**self).delete_vpc_link(input)
+    }
+    fn flush_stage_authorizers_cache(
+        &self,
+        input: FlushStageAuthorizersCacheRequest,
+    ) -> RusotoFuture<(), FlushStageAuthorizersCacheError> {
+        (**self).flush_stage_authorizers_cache(input)
+    }
+    fn flush_stage_cache(
+        &self,
+        input: FlushStageCacheRequest,
+    ) -> RusotoFuture<(), FlushStageCacheError> {
+        (**self).flush_stage_cache(input)
+    }
+    fn generate_client_certificate(
+        &self,
+        input: GenerateClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, GenerateClientCertificateError> {
+        (**self).generate_client_certificate(input)
+    }
+    fn get_account(&self) -> RusotoFuture<Account, GetAccountError> {
+        (**self).get_account()
+    }
+    fn get_api_key(&self, input: GetApiKeyRequest) -> RusotoFuture<ApiKey, GetApiKeyError> {
+        (**self).get_api_key(input)
+    }
+    fn get_api_keys(&self, input: GetApiKeysRequest) -> RusotoFuture<ApiKeys, GetApiKeysError> {
+        (**self).get_api_keys(input)
+    }
+    fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, GetAuthorizerError> {
+        (**self).get_authorizer(input)
+    }
+    fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> RusotoFuture<Authorizers, GetAuthorizersError> {
+        (**self).get_authorizers(input)
+    }
+    fn get_base_path_mapping(
+        &self,
+        input: GetBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, GetBasePathMappingError> {
+        (**self).get_base_path_mapping(input)
+    }
+    fn get_base_path_mappings(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> RusotoFuture<BasePathMappings, GetBasePathMappingsError> {
+        (**self).get_base_path_mappings(input)
+    }
+    fn get_client_certificate(
+        &self,
+        input: GetClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, GetClientCertificateError> {
+        (**self).get_client_certificate(input)
+    }
+    fn get_client_certificates(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> RusotoFuture<ClientCertificates, GetClientCertificatesError> {
+        (**self).get_client_certificates(input)
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> RusotoFuture<Deployment, GetDeploymentError> {
+        (**self).get_deployment(input)
+    }
+    fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> RusotoFuture<Deployments, GetDeploymentsError> {
+        (**self).get_deployments(input)
+    }
+    fn get_documentation_part(
+        &self,
+        input: GetDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, GetDocumentationPartError> {
+        (**self).get_documentation_part(input)
+    }
+    fn get_documentation_parts(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> RusotoFuture<DocumentationParts, GetDocumentationPartsError> {
+        (**self).get_documentation_parts(input)
+    }
+    fn get_documentation_version(
+        &self,
+        input: GetDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, GetDocumentationVersionError> {
+        (**self).get_documentation_version(input)
+    }
+    fn get_documentation_versions(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> RusotoFuture<DocumentationVersions, GetDocumentationVersionsError> {
+        (**self).get_documentation_versions(input)
+    }
+    fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> RusotoFuture<DomainName, GetDomainNameError> {
+        (**self).get_domain_name(input)
+    }
+    fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> RusotoFuture<DomainNames, GetDomainNamesError> {
+        (**self).get_domain_names(input)
+    }
+    fn get_export(&self, input: GetExportRequest) -> RusotoFuture<ExportResponse, GetExportError> {
+        (**self).get_export(input)
+    }
+    fn get_gateway_response(
+        &self,
+        input: GetGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, GetGatewayResponseError> {
+        (**self).get_gateway_response(input)
+    }
+    fn get_gateway_responses(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> RusotoFuture<GatewayResponses, GetGatewayResponsesError> {
+        (**self).get_gateway_responses(input)
+    }
+    fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> RusotoFuture<Integration, GetIntegrationError> {
+        (**self).get_integration(input)
+    }
+    fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, GetIntegrationResponseError> {
+        (**self).get_integration_response(input)
+    }
+    fn get_method(&self, input: GetMethodRequest) -> RusotoFuture<Method, GetMethodError> {
+        (**self).get_method(input)
+    }
+    fn get_method_response(
+        &self,
+        input: GetMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, GetMethodResponseError> {
+        (**self).get_method_response(input)
+    }
+    fn get_model(&self, input: GetModelRequest) -> RusotoFuture<Model, GetModelError> {
+        (**self).get_model(input)
+    }
+    fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> RusotoFuture<Template, GetModelTemplateError> {
+        (**self).get_model_template(input)
+    }
+    fn get_models(&self, input: GetModelsRequest) -> RusotoFuture<Models, GetModelsError> {
+        (**self).get_models(input)
+    }
+    fn get_request_validator(
+        &self,
+        input: GetRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, GetRequestValidatorError> {
+        (**self).get_request_validator(input)
+    }
+    fn get_request_validators(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> RusotoFuture<RequestValidators, GetRequestValidatorsError> {
+        (**self).get_request_validators(input)
+    }
+    fn get_resource(&self, input: GetResourceRequest) -> RusotoFuture<Resource, GetResourceError> {
+        (**self).get_resource(input)
+    }
+    fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> RusotoFuture<Resources, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn get_rest_api(&self, input: GetRestApiRequest) -> RusotoFuture<RestApi, GetRestApiError> {
+        (**self).get_rest_api(input)
+    }
+    fn get_rest_apis(&self, input: GetRestApisRequest) -> RusotoFuture<RestApis, GetRestApisError> {
+        (**self).get_rest_apis(input)
+    }
+    fn get_sdk(&self, input: GetSdkRequest) -> RusotoFuture<SdkResponse, GetSdkError> {
+        (**self).get_sdk(input)
+    }
+    fn get_sdk_type(&self, input: GetSdkTypeRequest) -> RusotoFuture<SdkType, GetSdkTypeError> {
+        (**self).get_sdk_type(input)
+    }
+    fn get_sdk_types(&self, input: GetSdkTypesRequest) -> RusotoFuture<SdkTypes, GetSdkTypesError> {
+        (**self).get_sdk_types(input)
+    }
+    fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<Stage, GetStageError> {
+        (**self).get_stage(input)
+    }
+    fn get_stages(&self, input: GetStagesRequest) -> RusotoFuture<Stages, GetStagesError> {
+        (**self).get_stages(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<Tags, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn get_usage(&self, input: GetUsageRequest) -> RusotoFuture<Usage, GetUsageError> {
+        (**self).get_usage(input)
+    }
+    fn get_usage_plan(
+        &self,
+        input: GetUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, GetUsagePlanError> {
+        (**self).get_usage_plan(input)
+    }
+    fn get_usage_plan_key(
+        &self,
+        input: GetUsagePlanKeyRequest,
+    ) -> RusotoFuture<UsagePlanKey, GetUsagePlanKeyError> {
+        (**self).get_usage_plan_key(input)
+    }
+    fn get_usage_plan_keys(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> RusotoFuture<UsagePlanKeys, GetUsagePlanKeysError> {
+        (**self).get_usage_plan_keys(input)
+    }
+    fn get_usage_plans(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> RusotoFuture<UsagePlans, GetUsagePlansError> {
+        (**self).get_usage_plans(input)
+    }
+    fn get_vpc_link(&self, input: GetVpcLinkRequest) -> RusotoFuture<VpcLink, GetVpcLinkError> {
+        (**self).get_vpc_link(input)
+    }
+    fn get_vpc_links(&self, input: GetVpcLinksRequest) -> RusotoFuture<VpcLinks, GetVpcLinksError> {
+        (**self).get_vpc_links(input)
+    }
+    fn import_api_keys(
+        &self,
+        input: ImportApiKeysRequest,
+    ) -> RusotoFuture<ApiKeyIds, ImportApiKeysError> {
+        (**self).import_api_keys(input)
+    }
+    fn import_documentation_parts(
+        &self,
+        input: ImportDocumentationPartsRequest,
+    ) -> RusotoFuture<DocumentationPartIds, ImportDocumentationPartsError> {
+        (**self).import_documentation_parts(input)
+    }
+    fn import_rest_api(
+        &self,
+        input: ImportRestApiRequest,
+    ) -> RusotoFuture<RestApi, ImportRestApiError> {
+        (**self).import_rest_api(input)
+    }
+    fn put_gateway_response(
+        &self,
+        input: PutGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, PutGatewayResponseError> {
+        (**self).put_gateway_response(input)
+    }
+    fn put_integration(
+        &self,
+        input: PutIntegrationRequest,
+    ) -> RusotoFuture<Integration, PutIntegrationError> {
+        (**self).put_integration(input)
+    }
+    fn put_integration_response(
+        &self,
+        input: PutIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, PutIntegrationResponseError> {
+        (**self).put_integration_response(input)
+    }
+    fn put_method(&self, input: PutMethodRequest) -> RusotoFuture<Method, PutMethodError> {
+        (**self).put_method(input)
+    }
+    fn put_method_response(
+        &self,
+        input: PutMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, PutMethodResponseError> {
+        (**self).put_method_response(input)
+    }
+    fn put_rest_api(&self, input: PutRestApiRequest) -> RusotoFuture<RestApi, PutRestApiError> {
+        (**self).put_rest_api(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> RusotoFuture<TestInvokeAuthorizerResponse, TestInvokeAuthorizerError> {
+        (**self).test_invoke_authorizer(input)
+    }
+    fn test_invoke_method(
+        &self,
+        input: TestInvokeMethodRequest,
+    ) -> RusotoFuture<TestInvokeMethodResponse, TestInvokeMethodError> {
+        (**self).test_invoke_method(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> RusotoFuture<Account, UpdateAccountError> {
+        (**self).update_account(input)
+    }
+    fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> RusotoFuture<ApiKey, UpdateApiKeyError> {
+        (**self).update_api_key(input)
+    }
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, UpdateAuthorizerError> {
+        (**self).update_authorizer(input)
+    }
+    fn update_base_path_mapping(
+        &self,
+        input: UpdateBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, UpdateBasePathMappingError> {
+        (**self).update_base_path_mapping(input)
+    }
+    fn update_client_certificate(
+        &self,
+        input: UpdateClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, UpdateClientCertificateError> {
+        (**self).update_client_certificate(input)
+    }
+    fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> RusotoFuture<Deployment, UpdateDeploymentError> {
+        (**self).update_deployment(input)
+    }
+    fn update_documentation_part(
+        &self,
+        input: UpdateDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, UpdateDocumentationPartError> {
+        (**self).update_documentation_part(input)
+    }
+    fn update_documentation_version(
+        &self,
+        input: UpdateDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, UpdateDocumentationVersionError> {
+        (**self).update_documentation_version(input)
+    }
+    fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> RusotoFuture<DomainName, UpdateDomainNameError> {
+        (**self).update_domain_name(input)
+    }
+    fn update_gateway_response(
+        &self,
+        input: UpdateGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, UpdateGatewayResponseError> {
+        (**self).update_gateway_response(input)
+    }
+    fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> RusotoFuture<Integration, UpdateIntegrationError> {
+        (**self).update_integration(input)
+    }
+    fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, UpdateIntegrationResponseError> {
+        (**self).update_integration_response(input)
+    }
+    fn update_method(&self, input: UpdateMethodRequest) -> RusotoFuture<Method, UpdateMethodError> {
+        (**self).update_method(input)
+    }
+    fn update_method_response(
+        &self,
+        input: UpdateMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, UpdateMethodResponseError> {
+        (**self).update_method_response(input)
+    }
+    fn update_model(&self, input: UpdateModelRequest) -> RusotoFuture<Model, UpdateModelError> {
+        (**self).update_model(input)
+    }
+    fn update_request_validator(
+        &self,
+        input: UpdateRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, UpdateRequestValidatorError> {
+        (**self).update_request_validator(input)
+    }
+    fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> RusotoFuture<Resource, UpdateResourceError> {
+        (**self).update_resource(input)
+    }
+    fn update_rest_api(
+        &self,
+        input: UpdateRestApiRequest,
+    ) -> RusotoFuture<RestApi, UpdateRestApiError> {
+        (**self).update_rest_api(input)
+    }
+    fn update_stage(&self, input: UpdateStageRequest) -> RusotoFuture<Stage, UpdateStageError> {
+        (**self).update_stage(input)
+    }
+    fn update_usage(&self, input: UpdateUsageRequest) -> RusotoFuture<Usage, UpdateUsageError> {
+        (**self).update_usage(input)
+    }
+    fn update_usage_plan(
+        &self,
+        input: UpdateUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, UpdateUsagePlanError> {
+        (**self).update_usage_plan(input)
+    }
+    fn update_vpc_link(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> RusotoFuture<VpcLink, UpdateVpcLinkError> {
+        (**self).update_vpc_link(input)
+    }
+}
+/// A client for the Amazon API Gateway API.
+#[derive(Clone)]
+pub struct ApiGatewayClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ApiGatewayClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ApiGatewayClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApiGatewayClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayClient {
+        ApiGatewayClient { client, region }
+    }
+}
+
+impl ApiGateway for ApiGatewayClient {
+    /// <p><p>Create an <a>ApiKey</a> resource. </p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/create-api-key.html">AWS CLI</a></div></p>
+    fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> RusotoFuture<ApiKey, CreateApiKeyError> {
+        let request_uri = "/apikeys";
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApiKey");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateApiKey")
+                        .deserialize::<ApiKey, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateApiKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Adds a new <a>Authorizer</a> resource to an existing <a>RestApi</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/create-authorizer.html">AWS CLI</a></div></p>
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, CreateAuthorizerError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/authorizers",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateAuthorizer")
+                        .deserialize::<Authorizer, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAuthorizerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new <a>BasePathMapping</a> resource.</p>
+    fn create_base_path_mapping(
+        &self,
+        input: CreateBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, CreateBasePathMappingError> {
+        let request_uri = format!(
+            "/domainnames/{domain_name}/basepathmappings",
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateBasePathMapping");
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateBasePathMapping")
+                        .deserialize::<BasePathMapping, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateBasePathMappingError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <a>Deployment</a> resource, which makes a specified <a>RestApi</a> callable over the internet.</p>
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<Deployment, CreateDeploymentError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/deployments",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDeployment")
+                        .deserialize::<Deployment, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    fn create_documentation_part(
+        &self,
+        input: CreateDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, CreateDocumentationPartError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/parts",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDocumentationPart");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDocumentationPart")
+                        .deserialize::<DocumentationPart, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDocumentationPartError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    fn create_documentation_version(
+        &self,
+        input: CreateDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, CreateDocumentationVersionError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/versions",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDocumentationVersion");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDocumentationVersion")
+                        .deserialize::<DocumentationVersion, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDocumentationVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a new domain name.</p>
+    fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> RusotoFuture<DomainName, CreateDomainNameError> {
+        let request_uri = "/domainnames";
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDomainName");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDomainName")
+                        .deserialize::<DomainName, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDomainNameError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds a new <a>Model</a> resource to an existing <a>RestApi</a> resource.</p>
+    fn create_model(&self, input: CreateModelRequest) -> RusotoFuture<Model, CreateModelError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/models",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateModel");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateModel")
+                        .deserialize::<Model, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <a>ReqeustValidator</a> of a given <a>RestApi</a>.</p>
+    fn create_request_validator(
+        &self,
+        input: CreateRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, CreateRequestValidatorError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/requestvalidators",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRequestValidator");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateRequestValidator")
+                        .deserialize::<RequestValidator, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateRequestValidatorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <a>Resource</a> resource.</p>
+    fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> RusotoFuture<Resource, CreateResourceError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{parent_id}",
+            parent_id = input.parent_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateResource");
+        request.add_resource_id("parentId", &input.parent_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateResource")
+                        .deserialize::<Resource, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new <a>RestApi</a> resource.</p>
+    fn create_rest_api(
+        &self,
+        input: CreateRestApiRequest,
+    ) -> RusotoFuture<RestApi, CreateRestApiError> {
+        let request_uri = "/restapis";
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRestApi");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateRestApi")
+                        .deserialize::<RestApi, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateRestApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new <a>Stage</a> resource that references a pre-existing <a>Deployment</a> for the API. </p>
+    fn create_stage(&self, input: CreateStageRequest) -> RusotoFuture<Stage, CreateStageError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/stages",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateStage");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateStage")
+                        .deserialize::<Stage, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateStageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a usage plan with the throttle and quota limits, as well as the associated API stages, specified in the payload. </p>
+    fn create_usage_plan(
+        &self,
+        input: CreateUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, CreateUsagePlanError> {
+        let request_uri = "/usageplans";
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateUsagePlan");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateUsagePlan")
+                        .deserialize::<UsagePlan, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUsagePlanError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a usage plan key for adding an existing API key to a usage plan.</p>
+    fn create_usage_plan_key(
+        &self,
+        input: CreateUsagePlanKeyRequest,
+    ) -> RusotoFuture<UsagePlanKey, CreateUsagePlanKeyError> {
+        let request_uri = format!(
+            "/usageplans/{usageplan_id}/keys",
+            usageplan_id = input.usage_plan_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateUsagePlanKey");
+        request.add_resource_id("usagePlanId", &input.usage_plan_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateUsagePlanKey")
+                        .deserialize::<UsagePlanKey, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUsagePlanKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a VPC link, under the caller's account in a selected region, in an asynchronous operation that typically takes 2-4 minutes to complete and become operational. The caller must have permissions to create and update VPC Endpoint services.</p>
+    fn create_vpc_link(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> RusotoFuture<VpcLink, CreateVpcLinkError> {
+        let request_uri = "/vpclinks";
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateVpcLink");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateVpcLink")
+                        .deserialize::<VpcLink, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateVpcLinkError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the <a>ApiKey</a> resource.</p>
+    fn delete_api_key(&self, input: DeleteApiKeyRequest) -> RusotoFuture<(), DeleteApiKeyError> {
+        let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApiKey");
+        request.add_resource_id("apiKey", &input.api_key.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteApiKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/delete-authorizer.html">AWS CLI</a></div></p>
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<(), DeleteAuthorizerError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/authorizers/{authorizer_id}",
+            authorizer_id = input.authorizer_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
+        request.add_resource_id("authorizerId", &input.authorizer_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAuthorizerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the <a>BasePathMapping</a> resource.</p>
+    fn delete_base_path_mapping(
+        &self,
+        input: DeleteBasePathMappingRequest,
+    ) -> RusotoFuture<(), DeleteBasePathMappingError> {
+        let request_uri = format!(
+            "/domainnames/{domain_name}/basepathmappings/{base_path}",
+            base_path = input.base_path,
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteBasePathMapping");
+        request.add_resource_id("basePath", &input.base_path.to_string());
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteBasePathMappingError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the <a>ClientCertificate</a> resource.</p>
+    fn delete_client_certificate(
+        &self,
+        input: DeleteClientCertificateRequest,
+    ) -> RusotoFuture<(), DeleteClientCertificateError> {
+        let request_uri = format!(
+            "/clientcertificates/{clientcertificate_id}",
+            clientcertificate_id = input.client_certificate_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteClientCertificate");
+        request.add_resource_id(
+            "clientCertificateId",
+            &input.client_certificate_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteClientCertificateError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a <a>Deployment</a> resource. Deleting a deployment will only succeed if there are no <a>Stage</a> resources associated with it.</p>
+    fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> RusotoFuture<(), DeleteDeploymentError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/deployments/{deployment_id}",
+            deployment_id = input.deployment_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDeployment");
+        request.add_resource_id("deploymentId", &input.deployment_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    fn delete_documentation_part(
+        &self,
+        input: DeleteDocumentationPartRequest,
+    ) -> RusotoFuture<(), DeleteDocumentationPartError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/parts/{part_id}",
+            part_id = input.documentation_part_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDocumentationPart");
+        request.add_resource_id(
+            "documentationPartId",
+            &input.documentation_part_id.to_string(),
+        );
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteDocumentationPartError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    fn delete_documentation_version(
+        &self,
+        input: DeleteDocumentationVersionRequest,
+    ) -> RusotoFuture<(), DeleteDocumentationVersionError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/versions/{doc_version}",
+            doc_version = input.documentation_version,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDocumentationVersion");
+        request.add_resource_id(
+            "documentationVersion",
+            &input.documentation_version.to_string(),
+        );
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteDocumentationVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the <a>DomainName</a> resource.</p>
+    fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> RusotoFuture<(), DeleteDomainNameError> {
+        let request_uri = format!(
+            "/domainnames/{domain_name}",
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDomainName");
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDomainNameError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Clears any customization of a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a> and resets it with the default settings.</p>
+    fn delete_gateway_response(
+        &self,
+        input: DeleteGatewayResponseRequest,
+    ) -> RusotoFuture<(), DeleteGatewayResponseError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/gatewayresponses/{response_type}",
+            response_type = input.response_type,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteGatewayResponse");
+        request.add_resource_id("responseType", &input.response_type.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteGatewayResponseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents a delete integration.</p>
+    fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
+            http_method = input.http_method,
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegration");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteIntegrationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents a delete integration response.</p>
+    fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationResponse");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("statusCode", &input.status_code.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteIntegrationResponseError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes an existing <a>Method</a> resource.</p>
+    fn delete_method(&self, input: DeleteMethodRequest) -> RusotoFuture<(), DeleteMethodError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
+            http_method = input.http_method,
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteMethod");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteMethodError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing <a>MethodResponse</a> resource.</p>
+    fn delete_method_response(
+        &self,
+        input: DeleteMethodResponseRequest,
+    ) -> RusotoFuture<(), DeleteMethodResponseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteMethodResponse");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("statusCode", &input.status_code.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteMethodResponseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a model.</p>
+    fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/models/{model_name}",
+            model_name = input.model_name,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteModel");
+        request.add_resource_id("modelName", &input.model_name.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
+    fn delete_request_validator(
+        &self,
+        input: DeleteRequestValidatorRequest,
+    ) -> RusotoFuture<(), DeleteRequestValidatorError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/requestvalidators/{requestvalidator_id}",
+            requestvalidator_id = input.request_validator_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRequestValidator");
+        request.add_resource_id(
+            "requestValidatorId",
+            &input.request_validator_id.to_string(),
+        );
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteRequestValidatorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a <a>Resource</a> resource.</p>
+    fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> RusotoFuture<(), DeleteResourceError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}",
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteResource");
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified API.</p>
+    fn delete_rest_api(&self, input: DeleteRestApiRequest) -> RusotoFuture<(), DeleteRestApiError> {
+        let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRestApi");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteRestApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a <a>Stage</a> resource.</p>
+    fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/stages/{stage_name}",
+            restapi_id = input.rest_api_id,
+            stage_name = input.stage_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteStage");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("stageName", &input.stage_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteStageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a usage plan of a given plan Id.</p>
+    fn delete_usage_plan(
+        &self,
+        input: DeleteUsagePlanRequest,
+    ) -> RusotoFuture<(), DeleteUsagePlanError> {
+        let request_uri = format!(
+            "/usageplans/{usageplan_id}",
+            usageplan_id = input.usage_plan_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteUsagePlan");
+        request.add_resource_id("usagePlanId", &input.usage_plan_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUsagePlanError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a usage plan key and remove the underlying API key from the associated usage plan.</p>
+    fn delete_usage_plan_key(
+        &self,
+        input: DeleteUsagePlanKeyRequest,
+    ) -> RusotoFuture<(), DeleteUsagePlanKeyError> {
+        let request_uri = format!(
+            "/usageplans/{usageplan_id}/keys/{key_id}",
+            key_id = input.key_id,
+            usageplan_id = input.usage_plan_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteUsagePlanKey");
+        request.add_resource_id("keyId", &input.key_id.to_string());
+        request.add_resource_id("usagePlanId", &input.usage_plan_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUsagePlanKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing <a>VpcLink</a> of a specified identifier.</p>
+    fn delete_vpc_link(&self, input: DeleteVpcLinkRequest) -> RusotoFuture<(), DeleteVpcLinkError> {
+        let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteVpcLink");
+        request.add_resource_id("vpcLinkId", &input.vpc_link_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteVpcLinkError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Flushes all authorizer cache entries on a stage.</p>
+    fn flush_stage_authorizers_cache(
+        &self,
+        input: FlushStageAuthorizersCacheRequest,
+    ) -> RusotoFuture<(), FlushStageAuthorizersCacheError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/stages/{stage_name}/cache/authorizers",
+            restapi_id = input.rest_api_id,
+            stage_name = input.stage_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("FlushStageAuthorizersCache");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("stageName", &input.stage_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(FlushStageAuthorizersCacheError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Flushes a stage's cache.</p>
+    fn flush_stage_cache(
+        &self,
+        input: FlushStageCacheRequest,
+    ) -> RusotoFuture<(), FlushStageCacheError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/stages/{stage_name}/cache/data",
+            restapi_id = input.rest_api_id,
+            stage_name = input.stage_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("FlushStageCache");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("stageName", &input.stage_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(FlushStageCacheError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Generates a <a>ClientCertificate</a> resource.</p>
+    fn generate_client_certificate(
+        &self,
+        input: GenerateClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, GenerateClientCertificateError> {
+        let request_uri = "/clientcertificates";
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("GenerateClientCertificate");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GenerateClientCertificate")
+                        .deserialize::<ClientCertificate, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GenerateClientCertificateError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about the current <a>Account</a> resource.</p>
+    fn get_account(&self) -> RusotoFuture<Account, GetAccountError> {
+        let request_uri = "/account";
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAccount");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAccount")
+                        .deserialize::<Account, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about the current <a>ApiKey</a> resource.</p>
+    fn get_api_key(&self, input: GetApiKeyRequest) -> RusotoFuture<ApiKey, GetApiKeyError> {
+        let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiKey");
+        request.add_resource_id("apiKey", &input.api_key.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.include_value {
+            params.put("includeValue", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetApiKey")
+                        .deserialize::<ApiKey, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetApiKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about the current <a>ApiKeys</a> resource.</p>
+    fn get_api_keys(&self, input: GetApiKeysRequest) -> RusotoFuture<ApiKeys, GetApiKeysError> {
+        let request_uri = "/apikeys";
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiKeys");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.customer_id {
+            params.put("customerId", x);
+        }
+        if let Some(ref x) = input.include_values {
+            params.put("includeValues", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.name_query {
+            params.put("name", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetApiKeys")
+                        .deserialize::<ApiKeys, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetApiKeysError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Describe an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-authorizer.html">AWS CLI</a></div></p>
+    fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, GetAuthorizerError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/authorizers/{authorizer_id}",
+            authorizer_id = input.authorizer_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizer");
+        request.add_resource_id("authorizerId", &input.authorizer_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAuthorizer")
+                        .deserialize::<Authorizer, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAuthorizerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Describe an existing <a>Authorizers</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-authorizers.html">AWS CLI</a></div></p>
+    fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> RusotoFuture<Authorizers, GetAuthorizersError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/authorizers",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizers");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAuthorizers")
+                        .deserialize::<Authorizers, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAuthorizersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describe a <a>BasePathMapping</a> resource.</p>
+    fn get_base_path_mapping(
+        &self,
+        input: GetBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, GetBasePathMappingError> {
+        let request_uri = format!(
+            "/domainnames/{domain_name}/basepathmappings/{base_path}",
+            base_path = input.base_path,
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetBasePathMapping");
+        request.add_resource_id("basePath", &input.base_path.to_string());
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBasePathMapping")
+                        .deserialize::<BasePathMapping, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBasePathMappingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents a collection of <a>BasePathMapping</a> resources.</p>
+    fn get_base_path_mappings(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> RusotoFuture<BasePathMappings, GetBasePathMappingsError> {
+        let request_uri = format!(
+            "/domainnames/{domain_name}/basepathmappings",
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetBasePathMappings");
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBasePathMappings")
+                        .deserialize::<BasePathMappings, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetBasePathMappingsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about the current <a>ClientCertificate</a> resource.</p>
+    fn get_client_certificate(
+        &self,
+        input: GetClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, GetClientCertificateError> {
+        let request_uri = format!(
+            "/clientcertificates/{clientcertificate_id}",
+            clientcertificate_id = input.client_certificate_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetClientCertificate");
+        request.add_resource_id(
+            "clientCertificateId",
+            &input.client_certificate_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetClientCertificate")
+                        .deserialize::<ClientCertificate, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetClientCertificateError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a collection of <a>ClientCertificate</a> resources.</p>
+    fn get_client_certificates(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> RusotoFuture<ClientCertificates, GetClientCertificatesError> {
+        let request_uri = "/clientcertificates";
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetClientCertificates");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetClientCertificates")
+                        .deserialize::<ClientCertificates, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetClientCertificatesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a <a>Deployment</a> resource.</p>
+    fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> RusotoFuture<Deployment, GetDeploymentError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/deployments/{deployment_id}",
+            deployment_id = input.deployment_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
+        request.add_resource_id("deploymentId", &input.deployment_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.embed {
+            for item in x.iter() {
+                params.put("embed", item);
+            }
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDeployment")
+                        .deserialize::<Deployment, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a <a>Deployments</a> collection.</p>
+    fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> RusotoFuture<Deployments, GetDeploymentsError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/deployments",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployments");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDeployments")
+                        .deserialize::<Deployments, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDeploymentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    fn get_documentation_part(
+        &self,
+        input: GetDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, GetDocumentationPartError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/parts/{part_id}",
+            part_id = input.documentation_part_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationPart");
+        request.add_resource_id(
+            "documentationPartId",
+            &input.documentation_part_id.to_string(),
+        );
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDocumentationPart")
+                        .deserialize::<DocumentationPart, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetDocumentationPartError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    fn get_documentation_parts(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> RusotoFuture<DocumentationParts, GetDocumentationPartsError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/parts",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationParts");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.location_status {
+            params.put("locationStatus", x);
+        }
+        if let Some(ref x) = input.name_query {
+            params.put("name", x);
+        }
+        if let Some(ref x) = input.path {
+            params.put("path", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        if let Some(ref x) = input.type_ {
+            params.put("type", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDocumentationParts")
+                        .deserialize::<DocumentationParts, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetDocumentationPartsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    fn get_documentation_version(
+        &self,
+        input: GetDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, GetDocumentationVersionError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/versions/{doc_version}",
+            doc_version = input.documentation_version,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationVersion");
+        request.add_resource_id(
+            "documentationVersion",
+            &input.documentation_version.to_string(),
+        );
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDocumentationVersion")
+                        .deserialize::<DocumentationVersion, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetDocumentationVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    fn get_documentation_versions(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> RusotoFuture<DocumentationVersions, GetDocumentationVersionsError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/versions",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationVersions");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDocumentationVersions")
+                        .deserialize::<DocumentationVersions, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetDocumentationVersionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Represents a domain name that is contained in a simpler, more intuitive URL that can be called.</p>
+    fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> RusotoFuture<DomainName, GetDomainNameError> {
+        let request_uri = format!(
+            "/domainnames/{domain_name}",
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainName");
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDomainName")
+                        .deserialize::<DomainName, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDomainNameError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents a collection of <a>DomainName</a> resources.</p>
+    fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> RusotoFuture<DomainNames, GetDomainNamesError> {
+        let request_uri = "/domainnames";
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainNames");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDomainNames")
+                        .deserialize::<DomainNames, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDomainNamesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Exports a deployed version of a <a>RestApi</a> in a specified format.</p>
+    fn get_export(&self, input: GetExportRequest) -> RusotoFuture<ExportResponse, GetExportError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/stages/{stage_name}/exports/{export_type}",
+            export_type = input.export_type,
+            restapi_id = input.rest_api_id,
+            stage_name = input.stage_name
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetExport");
+        request.add_resource_id("exportType", &input.export_type.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("stageName", &input.stage_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref accepts) = input.accepts {
+            request.add_header("Accept", &accepts.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.parameters {
+            for (key, val) in x.iter() {
+                params.put(key, val);
+            }
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = ExportResponse::default();
+                    result.body = Some(response.body);
+
+                    if let Some(content_disposition) = response.headers.get("Content-Disposition") {
+                        let value = content_disposition.to_owned();
+                        result.content_disposition = Some(value)
+                    };
+                    if let Some(content_type) = response.headers.get("Content-Type") {
+                        let value = content_type.to_owned();
+                        result.content_type = Some(value)
+                    };
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetExportError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
+    fn get_gateway_response(
+        &self,
+        input: GetGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, GetGatewayResponseError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/gatewayresponses/{response_type}",
+            response_type = input.response_type,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetGatewayResponse");
+        request.add_resource_id("responseType", &input.response_type.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGatewayResponse")
+                        .deserialize::<GatewayResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetGatewayResponseError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the <a>GatewayResponses</a> collection on the given <a>RestApi</a>. If an API developer has not added any definitions for gateway responses, the result will be the API Gateway-generated default <a>GatewayResponses</a> collection for the supported response types.</p>
+    fn get_gateway_responses(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> RusotoFuture<GatewayResponses, GetGatewayResponsesError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/gatewayresponses",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetGatewayResponses");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGatewayResponses")
+                        .deserialize::<GatewayResponses, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetGatewayResponsesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Get the integration settings.</p>
+    fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> RusotoFuture<Integration, GetIntegrationError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
+            http_method = input.http_method,
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegration");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIntegration")
+                        .deserialize::<Integration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetIntegrationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents a get integration response.</p>
+    fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, GetIntegrationResponseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponse");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("statusCode", &input.status_code.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIntegrationResponse")
+                        .deserialize::<IntegrationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetIntegrationResponseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describe an existing <a>Method</a> resource.</p>
+    fn get_method(&self, input: GetMethodRequest) -> RusotoFuture<Method, GetMethodError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
+            http_method = input.http_method,
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetMethod");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetMethod")
+                        .deserialize::<Method, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMethodError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes a <a>MethodResponse</a> resource.</p>
+    fn get_method_response(
+        &self,
+        input: GetMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, GetMethodResponseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetMethodResponse");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("statusCode", &input.status_code.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetMethodResponse")
+                        .deserialize::<MethodResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMethodResponseError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes an existing model defined for a <a>RestApi</a> resource.</p>
+    fn get_model(&self, input: GetModelRequest) -> RusotoFuture<Model, GetModelError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/models/{model_name}",
+            model_name = input.model_name,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModel");
+        request.add_resource_id("modelName", &input.model_name.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.flatten {
+            params.put("flatten", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetModel")
+                        .deserialize::<Model, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Generates a sample mapping template that can be used to transform a payload into the structure of a model.</p>
+    fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> RusotoFuture<Template, GetModelTemplateError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/models/{model_name}/default_template",
+            model_name = input.model_name,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModelTemplate");
+        request.add_resource_id("modelName", &input.model_name.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetModelTemplate")
+                        .deserialize::<Template, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetModelTemplateError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes existing <a>Models</a> defined for a <a>RestApi</a> resource.</p>
+    fn get_models(&self, input: GetModelsRequest) -> RusotoFuture<Models, GetModelsError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/models",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModels");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetModels")
+                        .deserialize::<Models, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetModelsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
+    fn get_request_validator(
+        &self,
+        input: GetRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, GetRequestValidatorError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/requestvalidators/{requestvalidator_id}",
+            requestvalidator_id = input.request_validator_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRequestValidator");
+        request.add_resource_id(
+            "requestValidatorId",
+            &input.request_validator_id.to_string(),
+        );
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetRequestValidator")
+                        .deserialize::<RequestValidator, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetRequestValidatorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the <a>RequestValidators</a> collection of a given <a>RestApi</a>.</p>
+    fn get_request_validators(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> RusotoFuture<RequestValidators, GetRequestValidatorsError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/requestvalidators",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRequestValidators");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetRequestValidators")
+                        .deserialize::<RequestValidators, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetRequestValidatorsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists information about a resource.</p>
+    fn get_resource(&self, input: GetResourceRequest) -> RusotoFuture<Resource, GetResourceError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}",
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetResource");
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.embed {
+            for item in x.iter() {
+                params.put("embed", item);
+            }
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetResource")
+                        .deserialize::<Resource, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists information about a collection of <a>Resource</a> resources.</p>
+    fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> RusotoFuture<Resources, GetResourcesError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetResources");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.embed {
+            for item in x.iter() {
+                params.put("embed", item);
+            }
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetResources")
+                        .deserialize::<Resources, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetResourcesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the <a>RestApi</a> resource in the collection.</p>
+    fn get_rest_api(&self, input: GetRestApiRequest) -> RusotoFuture<RestApi, GetRestApiError> {
+        let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRestApi");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetRestApi")
+                        .deserialize::<RestApi, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRestApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the <a>RestApis</a> resources for your collection.</p>
+    fn get_rest_apis(&self, input: GetRestApisRequest) -> RusotoFuture<RestApis, GetRestApisError> {
+        let request_uri = "/restapis";
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRestApis");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetRestApis")
+                        .deserialize::<RestApis, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRestApisError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Generates a client SDK for a <a>RestApi</a> and <a>Stage</a>.</p>
+    fn get_sdk(&self, input: GetSdkRequest) -> RusotoFuture<SdkResponse, GetSdkError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/stages/{stage_name}/sdks/{sdk_type}",
+            restapi_id = input.rest_api_id,
+            sdk_type = input.sdk_type,
+            stage_name = input.stage_name
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdk");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("sdkType", &input.sdk_type.to_string());
+        request.add_resource_id("stageName", &input.stage_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.parameters {
+            for (key, val) in x.iter() {
+                params.put(key, val);
+            }
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = SdkResponse::default();
+                    result.body = Some(response.body);
+
+                    if let Some(content_disposition) = response.headers.get("Content-Disposition") {
+                        let value = content_disposition.to_owned();
+                        result.content_disposition = Some(value)
+                    };
+                    if let Some(content_type) = response.headers.get("Content-Type") {
+                        let value = content_type.to_owned();
+                        result.content_type = Some(value)
+                    };
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSdkError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    fn get_sdk_type(&self, input: GetSdkTypeRequest) -> RusotoFuture<SdkType, GetSdkTypeError> {
+        let request_uri = format!("/sdktypes/{sdktype_id}", sdktype_id = input.id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdkType");
+        request.add_resource_id("id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSdkType")
+                        .deserialize::<SdkType, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSdkTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    fn get_sdk_types(&self, input: GetSdkTypesRequest) -> RusotoFuture<SdkTypes, GetSdkTypesError> {
+        let request_uri = "/sdktypes";
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdkTypes");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSdkTypes")
+                        .deserialize::<SdkTypes, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSdkTypesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a <a>Stage</a> resource.</p>
+    fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<Stage, GetStageError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/stages/{stage_name}",
+            restapi_id = input.rest_api_id,
+            stage_name = input.stage_name
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStage");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("stageName", &input.stage_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetStage")
+                        .deserialize::<Stage, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetStageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about one or more <a>Stage</a> resources.</p>
+    fn get_stages(&self, input: GetStagesRequest) -> RusotoFuture<Stages, GetStagesError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/stages",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStages");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.deployment_id {
+            params.put("deploymentId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetStages")
+                        .deserialize::<Stages, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetStagesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the <a>Tags</a> collection for a given resource.</p>
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<Tags, GetTagsError> {
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetTags");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetTags")
+                        .deserialize::<Tags, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the usage data of a usage plan in a specified time interval.</p>
+    fn get_usage(&self, input: GetUsageRequest) -> RusotoFuture<Usage, GetUsageError> {
+        let request_uri = format!(
+            "/usageplans/{usageplan_id}/usage",
+            usageplan_id = input.usage_plan_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsage");
+        request.add_resource_id("usagePlanId", &input.usage_plan_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("endDate", &input.end_date);
+        if let Some(ref x) = input.key_id {
+            params.put("keyId", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        params.put("startDate", &input.start_date);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetUsage")
+                        .deserialize::<Usage, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUsageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a usage plan of a given plan identifier.</p>
+    fn get_usage_plan(
+        &self,
+        input: GetUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, GetUsagePlanError> {
+        let request_uri = format!(
+            "/usageplans/{usageplan_id}",
+            usageplan_id = input.usage_plan_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlan");
+        request.add_resource_id("usagePlanId", &input.usage_plan_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetUsagePlan")
+                        .deserialize::<UsagePlan, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUsagePlanError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a usage plan key of a given key identifier.</p>
+    fn get_usage_plan_key(
+        &self,
+        input: GetUsagePlanKeyRequest,
+    ) -> RusotoFuture<UsagePlanKey, GetUsagePlanKeyError> {
+        let request_uri = format!(
+            "/usageplans/{usageplan_id}/keys/{key_id}",
+            key_id = input.key_id,
+            usageplan_id = input.usage_plan_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlanKey");
+        request.add_resource_id("keyId", &input.key_id.to_string());
+        request.add_resource_id("usagePlanId", &input.usage_plan_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetUsagePlanKey")
+                        .deserialize::<UsagePlanKey, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUsagePlanKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets all the usage plan keys representing the API keys added to a specified usage plan.</p>
+    fn get_usage_plan_keys(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> RusotoFuture<UsagePlanKeys, GetUsagePlanKeysError> {
+        let request_uri = format!(
+            "/usageplans/{usageplan_id}/keys",
+            usageplan_id = input.usage_plan_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlanKeys");
+        request.add_resource_id("usagePlanId", &input.usage_plan_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.name_query {
+            params.put("name", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetUsagePlanKeys")
+                        .deserialize::<UsagePlanKeys, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUsagePlanKeysError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets all the usage plans of the caller's account.</p>
+    fn get_usage_plans(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> RusotoFuture<UsagePlans, GetUsagePlansError> {
+        let request_uri = "/usageplans";
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlans");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.key_id {
+            params.put("keyId", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetUsagePlans")
+                        .deserialize::<UsagePlans, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUsagePlansError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a specified VPC link under the caller's account in a region.</p>
+    fn get_vpc_link(&self, input: GetVpcLinkRequest) -> RusotoFuture<VpcLink, GetVpcLinkError> {
+        let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLink");
+        request.add_resource_id("vpcLinkId", &input.vpc_link_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetVpcLink")
+                        .deserialize::<VpcLink, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetVpcLinkError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the <a>VpcLinks</a> collection under the caller's account in a selected region.</p>
+    fn get_vpc_links(&self, input: GetVpcLinksRequest) -> RusotoFuture<VpcLinks, GetVpcLinksError> {
+        let request_uri = "/vpclinks";
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLinks");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.position {
+            params.put("position", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetVpcLinks")
+                        .deserialize::<VpcLinks, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetVpcLinksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Import API keys from an external source, such as a CSV-formatted file.</p>
+    fn import_api_keys(
+        &self,
+        input: ImportApiKeysRequest,
+    ) -> RusotoFuture<ApiKeyIds, ImportApiKeysError> {
+        let request_uri = "/apikeys";
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportApiKeys");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(input.body.to_owned());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.fail_on_warnings {
+            params.put("failonwarnings", x);
+        }
+        params.put("format", &input.format);
+        params.put("mode", "import");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ImportApiKeys")
+                        .deserialize::<ApiKeyIds, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ImportApiKeysError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    fn import_documentation_parts(
+        &self,
+        input: ImportDocumentationPartsRequest,
+    ) -> RusotoFuture<DocumentationPartIds, ImportDocumentationPartsError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/parts",
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportDocumentationParts");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(input.body.to_owned());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.fail_on_warnings {
+            params.put("failonwarnings", x);
+        }
+        if let Some(ref x) = input.mode {
+            params.put("mode", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ImportDocumentationParts")
+                        .deserialize::<DocumentationPartIds, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ImportDocumentationPartsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>A feature of the API Gateway control service for creating a new API from an external API definition file.</p>
+    fn import_rest_api(
+        &self,
+        input: ImportRestApiRequest,
+    ) -> RusotoFuture<RestApi, ImportRestApiError> {
+        let request_uri = "/restapis";
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportRestApi");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(input.body.to_owned());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.fail_on_warnings {
+            params.put("failonwarnings", x);
+        }
+        if let Some(ref x) = input.parameters {
+            for (key, val) in x.iter() {
+                params.put(key, val);
+            }
+        }
+        params.put("mode", "import");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ImportRestApi")
+                        .deserialize::<RestApi, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ImportRestApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a customization of a <a>GatewayResponse</a> of a specified response type and status code on the given <a>RestApi</a>.</p>
+    fn put_gateway_response(
+        &self,
+        input: PutGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, PutGatewayResponseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/restapis/{restapi_id}/gatewayresponses/{response_type}",
+            response_type = input.response_type,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutGatewayResponse");
+        request.add_resource_id("responseType", &input.response_type.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutGatewayResponse")
+                        .deserialize::<GatewayResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutGatewayResponseError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets up a method's integration.</p>
+    fn put_integration(
+        &self,
+        input: PutIntegrationRequest,
+    ) -> RusotoFuture<Integration, PutIntegrationError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
+            http_method = input.http_method,
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutIntegration");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutIntegration")
+                        .deserialize::<Integration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutIntegrationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents a put integration.</p>
+    fn put_integration_response(
+        &self,
+        input: PutIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, PutIntegrationResponseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
+
+        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutIntegrationResponse");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("statusCode", &input.status_code.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutIntegrationResponse")
+                        .deserialize::<IntegrationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutIntegrationResponseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Add a method to an existing <a>Resource</a> resource.</p>
+    fn put_method(&self, input: PutMethodRequest) -> RusotoFuture<Method, PutMethodError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
+            http_method = input.http_method,
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutMethod");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutMethod")
+                        .deserialize::<Method, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutMethodError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds a <a>MethodResponse</a> to an existing <a>Method</a> resource.</p>
+    fn put_method_response(
+        &self,
+        input: PutMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, PutMethodResponseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
+
+        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutMethodResponse");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("statusCode", &input.status_code.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutMethodResponse")
+                        .deserialize::<MethodResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutMethodResponseError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>A feature of the API Gateway control service for updating an existing API with an input of external API definitions. The update can take the form of merging the supplied definition into the existing API or overwriting the existing API.</p>
+    fn put_rest_api(&self, input: PutRestApiRequest) -> RusotoFuture<RestApi, PutRestApiError> {
+        let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
+
+        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutRestApi");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(input.body.to_owned());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.fail_on_warnings {
+            params.put("failonwarnings", x);
+        }
+        if let Some(ref x) = input.mode {
+            params.put("mode", x);
+        }
+        if let Some(ref x) = input.parameters {
+            for (key, val) in x.iter() {
+                params.put(key, val);
+            }
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutRestApi")
+                        .deserialize::<RestApi, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutRestApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds or updates a tag on a given resource.</p>
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Simulate the execution of an <a>Authorizer</a> in your <a>RestApi</a> with headers, parameters, and an incoming request body.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-use-lambda-authorizer.html">Use Lambda Function as Authorizer</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-integrate-with-cognito.html">Use Cognito User Pool as Authorizer</a> </div></p>
+    fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> RusotoFuture<TestInvokeAuthorizerResponse, TestInvokeAuthorizerError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/authorizers/{authorizer_id}",
+            authorizer_id = input.authorizer_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TestInvokeAuthorizer");
+        request.add_resource_id("authorizerId", &input.authorizer_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TestInvokeAuthorizer")
+                        .deserialize::<TestInvokeAuthorizerResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(TestInvokeAuthorizerError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Simulate the execution of a <a>Method</a> in your <a>RestApi</a> with headers, parameters, and an incoming request body.</p>
+    fn test_invoke_method(
+        &self,
+        input: TestInvokeMethodRequest,
+    ) -> RusotoFuture<TestInvokeMethodResponse, TestInvokeMethodError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
+            http_method = input.http_method,
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TestInvokeMethod");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TestInvokeMethod")
+                        .deserialize::<TestInvokeMethodResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TestInvokeMethodError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes a tag from a given resource.</p>
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes information about the current <a>Account</a> resource.</p>
+    fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> RusotoFuture<Account, UpdateAccountError> {
+        let request_uri = "/account";
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAccount");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateAccount")
+                        .deserialize::<Account, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes information about an <a>ApiKey</a> resource.</p>
+    fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> RusotoFuture<ApiKey, UpdateApiKeyError> {
+        let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApiKey");
+        request.add_resource_id("apiKey", &input.api_key.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateApiKey")
+                        .deserialize::<ApiKey, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateApiKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/update-authorizer.html">AWS CLI</a></div></p>
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, UpdateAuthorizerError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/authorizers/{authorizer_id}",
+            authorizer_id = input.authorizer_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
+        request.add_resource_id("authorizerId", &input.authorizer_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateAuthorizer")
+                        .deserialize::<Authorizer, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAuthorizerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes information about the <a>BasePathMapping</a> resource.</p>
+    fn update_base_path_mapping(
+        &self,
+        input: UpdateBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, UpdateBasePathMappingError> {
+        let request_uri = format!(
+            "/domainnames/{domain_name}/basepathmappings/{base_path}",
+            base_path = input.base_path,
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateBasePathMapping");
+        request.add_resource_id("basePath", &input.base_path.to_string());
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateBasePathMapping")
+                        .deserialize::<BasePathMapping, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateBasePathMappingError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes information about an <a>ClientCertificate</a> resource.</p>
+    fn update_client_certificate(
+        &self,
+        input: UpdateClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, UpdateClientCertificateError> {
+        let request_uri = format!(
+            "/clientcertificates/{clientcertificate_id}",
+            clientcertificate_id = input.client_certificate_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateClientCertificate");
+        request.add_resource_id(
+            "clientCertificateId",
+            &input.client_certificate_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateClientCertificate")
+                        .deserialize::<ClientCertificate, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateClientCertificateError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Changes information about a <a>Deployment</a> resource.</p>
+    fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> RusotoFuture<Deployment, UpdateDeploymentError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/deployments/{deployment_id}",
+            deployment_id = input.deployment_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDeployment");
+        request.add_resource_id("deploymentId", &input.deployment_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDeployment")
+                        .deserialize::<Deployment, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    fn update_documentation_part(
+        &self,
+        input: UpdateDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, UpdateDocumentationPartError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/parts/{part_id}",
+            part_id = input.documentation_part_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentationPart");
+        request.add_resource_id(
+            "documentationPartId",
+            &input.documentation_part_id.to_string(),
+        );
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDocumentationPart")
+                        .deserialize::<DocumentationPart, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateDocumentationPartError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    fn update_documentation_version(
+        &self,
+        input: UpdateDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, UpdateDocumentationVersionError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/documentation/versions/{doc_version}",
+            doc_version = input.documentation_version,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentationVersion");
+        request.add_resource_id(
+            "documentationVersion",
+            &input.documentation_version.to_string(),
+        );
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDocumentationVersion")
+                        .deserialize::<DocumentationVersion, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateDocumentationVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Changes information about the <a>DomainName</a> resource.</p>
+    fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> RusotoFuture<DomainName, UpdateDomainNameError> {
+        let request_uri = format!(
+            "/domainnames/{domain_name}",
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDomainName");
+        request.add_resource_id("domainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDomainName")
+                        .deserialize::<DomainName, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDomainNameError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
+    fn update_gateway_response(
+        &self,
+        input: UpdateGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, UpdateGatewayResponseError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/gatewayresponses/{response_type}",
+            response_type = input.response_type,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateGatewayResponse");
+        request.add_resource_id("responseType", &input.response_type.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateGatewayResponse")
+                        .deserialize::<GatewayResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateGatewayResponseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents an update integration.</p>
+    fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> RusotoFuture<Integration, UpdateIntegrationError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
+            http_method = input.http_method,
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegration");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateIntegration")
+                        .deserialize::<Integration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateIntegrationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents an update integration response.</p>
+    fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, UpdateIntegrationResponseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegrationResponse");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("statusCode", &input.status_code.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateIntegrationResponse")
+                        .deserialize::<IntegrationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateIntegrationResponseError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates an existing <a>Method</a> resource.</p>
+    fn update_method(&self, input: UpdateMethodRequest) -> RusotoFuture<Method, UpdateMethodError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
+            http_method = input.http_method,
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateMethod");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateMethod")
+                        .deserialize::<Method, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateMethodError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing <a>MethodResponse</a> resource.</p>
+    fn update_method_response(
+        &self,
+        input: UpdateMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, UpdateMethodResponseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateMethodResponse");
+        request.add_resource_id("httpMethod", &input.http_method.to_string());
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("statusCode", &input.status_code.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateMethodResponse")
+                        .deserialize::<MethodResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateMethodResponseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes information about a model.</p>
+    fn update_model(&self, input: UpdateModelRequest) -> RusotoFuture<Model, UpdateModelError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/models/{model_name}",
+            model_name = input.model_name,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateModel");
+        request.add_resource_id("modelName", &input.model_name.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateModel")
+                        .deserialize::<Model, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
+    fn update_request_validator(
+        &self,
+        input: UpdateRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, UpdateRequestValidatorError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/requestvalidators/{requestvalidator_id}",
+            requestvalidator_id = input.request_validator_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRequestValidator");
+        request.add_resource_id(
+            "requestValidatorId",
+            &input.request_validator_id.to_string(),
+        );
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateRequestValidator")
+                        .deserialize::<RequestValidator, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateRequestValidatorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes information about a <a>Resource</a> resource.</p>
+    fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> RusotoFuture<Resource, UpdateResourceError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/resources/{resource_id}",
+            resource_id = input.resource_id,
+            restapi_id = input.rest_api_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateResource");
+        request.add_resource_id("resourceId", &input.resource_id.to_string());
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateResource")
+                        .deserialize::<Resource, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes information about the specified API.</p>
+    fn update_rest_api(
+        &self,
+        input: UpdateRestApiRequest,
+    ) -> RusotoFuture<RestApi, UpdateRestApiError> {
+        let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRestApi");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateRestApi")
+                        .deserialize::<RestApi, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateRestApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes information about a <a>Stage</a> resource.</p>
+    fn update_stage(&self, input: UpdateStageRequest) -> RusotoFuture<Stage, UpdateStageError> {
+        let request_uri = format!(
+            "/restapis/{restapi_id}/stages/{stage_name}",
+            restapi_id = input.rest_api_id,
+            stage_name = input.stage_name
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateStage");
+        request.add_resource_id("restApiId", &input.rest_api_id.to_string());
+        request.add_resource_id("stageName", &input.stage_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateStage")
+                        .deserialize::<Stage, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateStageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Grants a temporary extension to the remaining quota of a usage plan associated with a specified API key.</p>
+    fn update_usage(&self, input: UpdateUsageRequest) -> RusotoFuture<Usage, UpdateUsageError> {
+        let request_uri = format!(
+            "/usageplans/{usageplan_id}/keys/{key_id}/usage",
+            key_id = input.key_id,
+            usageplan_id = input.usage_plan_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateUsage");
+        request.add_resource_id("keyId", &input.key_id.to_string());
+        request.add_resource_id("usagePlanId", &input.usage_plan_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateUsage")
+                        .deserialize::<Usage, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateUsageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a usage plan of a given plan Id.</p>
+    fn update_usage_plan(
+        &self,
+        input: UpdateUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, UpdateUsagePlanError> {
+        let request_uri = format!(
+            "/usageplans/{usageplan_id}",
+            usageplan_id = input.usage_plan_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateUsagePlan");
+        request.add_resource_id("usagePlanId", &input.usage_plan_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateUsagePlan")
+                        .deserialize::<UsagePlan, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateUsagePlanError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing <a>VpcLink</a> of a specified identifier.</p>
+    fn update_vpc_link(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> RusotoFuture<VpcLink, UpdateVpcLinkError> {
+        let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateVpcLink");
+        request.add_resource_id("vpcLinkId", &input.vpc_link_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateVpcLink")
+                        .deserialize::<VpcLink, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateVpcLinkError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon API Gateway API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockApiGateway {
+    create_api_key: Option<
+        Box<
+            dyn Fn(
+                    CreateApiKeyRequest,
+                ) -> ::std::result::Result<ApiKey, RusotoError<CreateApiKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_authorizer: Option<
+        Box<
+            dyn Fn(
+                    CreateAuthorizerRequest,
+                )
+                    -> ::std::result::Result<Authorizer, RusotoError<CreateAuthorizerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_base_path_mapping: Option<
+        Box<
+            dyn Fn(
+                    CreateBasePathMappingRequest,
+                ) -> ::std::result::Result<
+                    BasePathMapping,
+                    RusotoError<CreateBasePathMappingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_deployment: Option<
+        Box<
+            dyn Fn(
+                    CreateDeploymentRequest,
+                )
+                    -> ::std::result::Result<Deployment, RusotoError<CreateDeploymentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_documentation_part: Option<
+        Box<
+            dyn Fn(
+                    CreateDocumentationPartRequest,
+                ) -> ::std::result::Result<
+                    DocumentationPart,
+                    RusotoError<CreateDocumentationPartError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_documentation_version: Option<
+        Box<
+            dyn Fn(
+                    CreateDocumentationVersionRequest,
+                ) -> ::std::result::Result<
+                    DocumentationVersion,
+                    RusotoError<CreateDocumentationVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_domain_name: Option<
+        Box<
+            dyn Fn(
+                    CreateDomainNameRequest,
+                )
+                    -> ::std::result::Result<DomainName, RusotoError<CreateDomainNameError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_model: Option<
+        Box<
+            dyn Fn(
+                    CreateModelRequest,
+                ) -> ::std::result::Result<Model, RusotoError<CreateModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_request_validator: Option<
+        Box<
+            dyn Fn(
+                    CreateRequestValidatorRequest,
+                ) -> ::std::result::Result<
+                    RequestValidator,
+                    RusotoError<CreateRequestValidatorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_resource: Option<
+        Box<
+            dyn Fn(
+                    CreateResourceRequest,
+                )
+                    -> ::std::result::Result<Resource, RusotoError<CreateResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_rest_api: Option<
+        Box<
+            dyn Fn(
+                    CreateRestApiRequest,
+                )
+                    -> ::std::result::Result<RestApi, RusotoError<CreateRestApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_stage: Option<
+        Box<
+            dyn Fn(
+                    CreateStageRequest,
+                ) -> ::std::result::Result<Stage, RusotoError<CreateStageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_usage_plan: Option<
+        Box<
+            dyn Fn(
+                    CreateUsagePlanRequest,
+                )
+                    -> ::std::result::Result<UsagePlan, RusotoError<CreateUsagePlanError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_usage_plan_key: Option<
+        Box<
+            dyn Fn(
+                    CreateUsagePlanKeyRequest,
+                )
+                    -> ::std::result::Result<UsagePlanKey, RusotoError<CreateUsagePlanKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_vpc_link: Option<
+        Box<
+            dyn Fn(
+                    CreateVpcLinkRequest,
+                )
+                    -> ::std::result::Result<VpcLink, RusotoError<CreateVpcLinkError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_api_key: Option<
+        Box<
+            dyn Fn(DeleteApiKeyRequest) -> ::std::result::Result<(), RusotoError<DeleteApiKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_authorizer: Option<
+        Box<
+            dyn Fn(
+                    DeleteAuthorizerRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteAuthorizerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_base_path_mapping: Option<
+        Box<
+            dyn Fn(
+                    DeleteBasePathMappingRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteBasePathMappingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_client_certificate: Option<
+        Box<
+            dyn Fn(
+                    DeleteClientCertificateRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteClientCertificateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_deployment: Option<
+        Box<
+            dyn Fn(
+                    DeleteDeploymentRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteDeploymentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_documentation_part: Option<
+        Box<
+            dyn Fn(
+                    DeleteDocumentationPartRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteDocumentationPartError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_documentation_version: Option<
+        Box<
+            dyn Fn(
+                    DeleteDocumentationVersionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteDocumentationVersionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_domain_name: Option<
+        Box<
+            dyn Fn(
+                    DeleteDomainNameRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteDomainNameError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_gateway_response: Option<
+        Box<
+            dyn Fn(
+                    DeleteGatewayResponseRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteGatewayResponseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_integration: Option<
+        Box<
+            dyn Fn(
+                    DeleteIntegrationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteIntegrationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_integration_response: Option<
+        Box<
+            dyn Fn(
+                    DeleteIntegrationResponseRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteIntegrationResponseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_method: Option<
+        Box<
+            dyn Fn(DeleteMethodRequest) -> ::std::result::Result<(), RusotoError<DeleteMethodError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_method_response: Option<
+        Box<
+            dyn Fn(
+                    DeleteMethodResponseRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteMethodResponseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_model: Option<
+        Box<
+            dyn Fn(DeleteModelRequest) -> ::std::result::Result<(), RusotoError<DeleteModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_request_validator: Option<
+        Box<
+            dyn Fn(
+                    DeleteRequestValidatorRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteRequestValidatorError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_resource: Option<
+        Box<
+            dyn Fn(
+                    DeleteResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_rest_api: Option<
+        Box<
+            dyn Fn(
+                    DeleteRestApiRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteRestApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_stage: Option<
+        Box<
+            dyn Fn(DeleteStageRequest) -> ::std::result::Result<(), RusotoError<DeleteStageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_usage_plan: Option<
+        Box<
+            dyn Fn(
+                    DeleteUsagePlanRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteUsagePlanError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_usage_plan_key: Option<
+        Box<
+            dyn Fn(
+                    DeleteUsagePlanKeyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteUsagePlanKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_vpc_link: Option<
+        Box<
+            dyn Fn(
+                    DeleteVpcLinkRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteVpcLinkError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    flush_stage_authorizers_cache: Option<
+        Box<
+            dyn Fn(
+                    FlushStageAuthorizersCacheRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<FlushStageAuthorizersCacheError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    flush_stage_cache: Option<
+        Box<
+            dyn Fn(
+                    FlushStageCacheRequest,
+                ) -> ::std::result::Result<(), RusotoError<FlushStageCacheError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    generate_client_certificate: Option<
+        Box<
+            dyn Fn(
+                    GenerateClientCertificateRequest,
+                ) -> ::std::result::Result<
+                    ClientCertificate,
+                    RusotoError<GenerateClientCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_account: Option<
+        Box<dyn Fn() -> ::std::result::Result<Account, RusotoError<GetAccountError>> + Send + Sync>,
+    >,
+    get_api_key: Option<
+        Box<
+            dyn Fn(GetApiKeyRequest) -> ::std::result::Result<ApiKey, RusotoError<GetApiKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_api_keys: Option<
+        Box<
+            dyn Fn(
+                    GetApiKeysRequest,
+                ) -> ::std::result::Result<ApiKeys, RusotoError<GetApiKeysError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_authorizer: Option<
+        Box<
+            dyn Fn(
+                    GetAuthorizerRequest,
+                )
+                    -> ::std::result::Result<Authorizer, RusotoError<GetAuthorizerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_authorizers: Option<
+        Box<
+            dyn Fn(
+                    GetAuthorizersRequest,
+                )
+                    -> ::std::result::Result<Authorizers, RusotoError<GetAuthorizersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_base_path_mapping: Option<
+        Box<
+            dyn Fn(
+                    GetBasePathMappingRequest,
+                )
+                    -> ::std::result::Result<BasePathMapping, RusotoError<GetBasePathMappingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_base_path_mappings: Option<
+        Box<
+            dyn Fn(
+                    GetBasePathMappingsRequest,
+                )
+                    -> ::std::result::Result<BasePathMappings, RusotoError<GetBasePathMappingsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_client_certificate: Option<
+        Box<
+            dyn Fn(
+                    GetClientCertificateRequest,
+                ) -> ::std::result::Result<
+                    ClientCertificate,
+                    RusotoError<GetClientCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_client_certificates: Option<
+        Box<
+            dyn Fn(
+                    GetClientCertificatesRequest,
+                ) -> ::std::result::Result<
+                    ClientCertificates,
+                    RusotoError<GetClientCertificatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_deployment: Option<
+        Box<
+            dyn Fn(
+                    GetDeploymentRequest,
+                )
+                    -> ::std::result::Result<Deployment, RusotoError<GetDeploymentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_deployments: Option<
+        Box<
+            dyn Fn(
+                    GetDeploymentsRequest,
+                )
+                    -> ::std::result::Result<Deployments, RusotoError<GetDeploymentsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_documentation_part: Option<
+        Box<
+            dyn Fn(
+                    GetDocumentationPartRequest,
+                ) -> ::std::result::Result<
+                    DocumentationPart,
+                    RusotoError<GetDocumentationPartError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_documentation_parts: Option<
+        Box<
+            dyn Fn(
+                    GetDocumentationPartsRequest,
+                ) -> ::std::result::Result<
+                    DocumentationParts,
+                    RusotoError<GetDocumentationPartsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_documentation_version: Option<
+        Box<
+            dyn Fn(
+                    GetDocumentationVersionRequest,
+                ) -> ::std::result::Result<
+                    DocumentationVersion,
+                    RusotoError<GetDocumentationVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_documentation_versions: Option<
+        Box<
+            dyn Fn(
+                    GetDocumentationVersionsRequest,
+                ) -> ::std::result::Result<
+                    DocumentationVersions,
+                    RusotoError<GetDocumentationVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_domain_name: Option<
+        Box<
+            dyn Fn(
+                    GetDomainNameRequest,
+                )
+                    -> ::std::result::Result<DomainName, RusotoError<GetDomainNameError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_domain_names: Option<
+        Box<
+            dyn Fn(
+                    GetDomainNamesRequest,
+                )
+                    -> ::std::result::Result<DomainNames, RusotoError<GetDomainNamesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_export: Option<
+        Box<
+            dyn Fn(
+                    GetExportRequest,
+                )
+                    -> ::std::result::Result<ExportResponse, RusotoError<GetExportError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_gateway_response: Option<
+        Box<
+            dyn Fn(
+                    GetGatewayResponseRequest,
+                )
+                    -> ::std::result::Result<GatewayResponse, RusotoError<GetGatewayResponseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_gateway_responses: Option<
+        Box<
+            dyn Fn(
+                    GetGatewayResponsesRequest,
+                )
+                    -> ::std::result::Result<GatewayResponses, RusotoError<GetGatewayResponsesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_integration: Option<
+        Box<
+            dyn Fn(
+                    GetIntegrationRequest,
+                )
+                    -> ::std::result::Result<Integration, RusotoError<GetIntegrationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_integration_response: Option<
+        Box<
+            dyn Fn(
+                    GetIntegrationResponseRequest,
+                ) -> ::std::result::Result<
+                    IntegrationResponse,
+                    RusotoError<GetIntegrationResponseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_method: Option<
+        Box<
+            dyn Fn(GetMethodRequest) -> ::std::result::Result<Method, RusotoError<GetMethodError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_method_response: Option<
+        Box<
+            dyn Fn(
+                    GetMethodResponseRequest,
+                )
+                    -> ::std::result::Result<MethodResponse, RusotoError<GetMethodResponseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_model: Option<
+        Box<
+            dyn Fn(GetModelRequest) -> ::std::result::Result<Model, RusotoError<GetModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_model_template: Option<
+        Box<
+            dyn Fn(
+                    GetModelTemplateRequest,
+                )
+                    -> ::std::result::Result<Template, RusotoError<GetModelTemplateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_models: Option<
+        Box<
+            dyn Fn(GetModelsRequest) -> ::std::result::Result<Models, RusotoError<GetModelsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_request_validator: Option<
+        Box<
+            dyn Fn(
+                    GetRequestValidatorRequest,
+                )
+                    -> ::std::result::Result<RequestValidator, RusotoError<GetRequestValidatorError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_request_validators: Option<
+        Box<
+            dyn Fn(
+                    GetRequestValidatorsRequest,
+                ) -> ::std::result::Result<
+                    RequestValidators,
+                    RusotoError<GetRequestValidatorsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resource: Option<
+        Box<
+            dyn Fn(
+                    GetResourceRequest,
+                )
+                    -> ::std::result::Result<Resource, RusotoError<GetResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_resources: Option<
+        Box<
+            dyn Fn(
+                    GetResourcesRequest,
+                )
+                    -> ::std::result::Result<Resources, RusotoError<GetResourcesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_rest_api: Option<
+        Box<
+            dyn Fn(
+                    GetRestApiRequest,
+                ) -> ::std::result::Result<RestApi, RusotoError<GetRestApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_rest_apis: Option<
+        Box<
+            dyn Fn(
+                    GetRestApisRequest,
+                )
+                    -> ::std::result::Result<RestApis, RusotoError<GetRestApisError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_sdk: Option<
+        Box<
+            dyn Fn(GetSdkRequest) -> ::std::result::Result<SdkResponse, RusotoError<GetSdkError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_sdk_type: Option<
+        Box<
+            dyn Fn(
+                    GetSdkTypeRequest,
+                ) -> ::std::result::Result<SdkType, RusotoError<GetSdkTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_sdk_types: Option<
+        Box<
+            dyn Fn(
+                    GetSdkTypesRequest,
+                )
+                    -> ::std::result::Result<SdkTypes, RusotoError<GetSdkTypesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_stage: Option<
+        Box<
+            dyn Fn(GetStageRequest) -> ::std::result::Result<Stage, RusotoError<GetStageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_stages: Option<
+        Box<
+            dyn Fn(GetStagesRequest) -> ::std::result::Result<Stages, RusotoError<GetStagesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_tags: Option<
+        Box<
+            dyn Fn(GetTagsRequest) -> ::std::result::Result<Tags, RusotoError<GetTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_usage: Option<
+        Box<
+            dyn Fn(GetUsageRequest) -> ::std::result::Result<Usage, RusotoError<GetUsageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_usage_plan: Option<
+        Box<
+            dyn Fn(
+                    GetUsagePlanRequest,
+                )
+                    -> ::std::result::Result<UsagePlan, RusotoError<GetUsagePlanError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_usage_plan_key: Option<
+        Box<
+            dyn Fn(
+                    GetUsagePlanKeyRequest,
+                )
+                    -> ::std::result::Result<UsagePlanKey, RusotoError<GetUsagePlanKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_usage_plan_keys: Option<
+        Box<
+            dyn Fn(
+                    GetUsagePlanKeysRequest,
+                )
+                    -> ::std::result::Result<UsagePlanKeys, RusotoError<GetUsagePlanKeysError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_usage_plans: Option<
+        Box<
+            dyn Fn(
+                    GetUsagePlansRequest,
+                )
+                    -> ::std::result::Result<UsagePlans, RusotoError<GetUsagePlansError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_vpc_link: Option<
+        Box<
+            dyn Fn(
+                    GetVpcLinkRequest,
+                ) -> ::std::result::Result<VpcLink, RusotoError<GetVpcLinkError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_vpc_links: Option<
+        Box<
+            dyn Fn(
+                    GetVpcLinksRequest,
+                )
+                    -> ::std::result::Result<VpcLinks, RusotoError<GetVpcLinksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    import_api_keys: Option<
+        Box<
+            dyn Fn(
+                    ImportApiKeysRequest,
+                )
+                    -> ::std::result::Result<ApiKeyIds, RusotoError<ImportApiKeysError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    import_documentation_parts: Option<
+        Box<
+            dyn Fn(
+                    ImportDocumentationPartsRequest,
+                ) -> ::std::result::Result<
+                    DocumentationPartIds,
+                    RusotoError<ImportDocumentationPartsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    import_rest_api: Option<
+        Box<
+            dyn Fn(
+                    ImportRestApiRequest,
+                )
+                    -> ::std::result::Result<RestApi, RusotoError<ImportRestApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_gateway_response: Option<
+        Box<
+            dyn Fn(
+                    PutGatewayResponseRequest,
+                )
+                    -> ::std::result::Result<GatewayResponse, RusotoError<PutGatewayResponseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_integration: Option<
+        Box<
+            dyn Fn(
+                    PutIntegrationRequest,
+                )
+                    -> ::std::result::Result<Integration, RusotoError<PutIntegrationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_integration_response: Option<
+        Box<
+            dyn Fn(
+                    PutIntegrationResponseRequest,
+                ) -> ::std::result::Result<
+                    IntegrationResponse,
+                    RusotoError<PutIntegrationResponseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_method: Option<
+        Box<
+            dyn Fn(PutMethodRequest) -> ::std::result::Result<Method, RusotoError<PutMethodError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_method_response: Option<
+        Box<
+            dyn Fn(
+                    PutMethodResponseRequest,
+                )
+                    -> ::std::result::Result<MethodResponse, RusotoError<PutMethodResponseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_rest_api: Option<
+        Box<
+            dyn Fn(
+                    PutRestApiRequest,
+                ) -> ::std::result::Result<RestApi, RusotoError<PutRestApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    test_invoke_authorizer: Option<
+        Box<
+            dyn Fn(
+                    TestInvokeAuthorizerRequest,
+                ) -> ::std::result::Result<
+                    TestInvokeAuthorizerResponse,
+                    RusotoError<TestInvokeAuthorizerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    test_invoke_method: Option<
+        Box<
+            dyn Fn(
+                    TestInvokeMethodRequest,
+                ) -> ::std::result::Result<
+                    TestInvokeMethodResponse,
+                    RusotoError<TestInvokeMethodError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_account: Option<
+        Box<
+            dyn Fn(
+                    UpdateAccountRequest,
+                )
+                    -> ::std::result::Result<Account, RusotoError<UpdateAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_api_key: Option<
+        Box<
+            dyn Fn(
+                    UpdateApiKeyRequest,
+                ) -> ::std::result::Result<ApiKey, RusotoError<UpdateApiKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_authorizer: Option<
+        Box<
+            dyn Fn(
+                    UpdateAuthorizerRequest,
+                )
+                    -> ::std::result::Result<Authorizer, RusotoError<UpdateAuthorizerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_base_path_mapping: Option<
+        Box<
+            dyn Fn(
+                    UpdateBasePathMappingRequest,
+                ) -> ::std::result::Result<
+                    BasePathMapping,
+                    RusotoError<UpdateBasePathMappingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_client_certificate: Option<
+        Box<
+            dyn Fn(
+                    UpdateClientCertificateRequest,
+                ) -> ::std::result::Result<
+                    ClientCertificate,
+                    RusotoError<UpdateClientCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_deployment: Option<
+        Box<
+            dyn Fn(
+                    UpdateDeploymentRequest,
+                )
+                    -> ::std::result::Result<Deployment, RusotoError<UpdateDeploymentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_documentation_part: Option<
+        Box<
+            dyn Fn(
+                    UpdateDocumentationPartRequest,
+                ) -> ::std::result::Result<
+                    DocumentationPart,
+                    RusotoError<UpdateDocumentationPartError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_documentation_version: Option<
+        Box<
+            dyn Fn(
+                    UpdateDocumentationVersionRequest,
+                ) -> ::std::result::Result<
+                    DocumentationVersion,
+                    RusotoError<UpdateDocumentationVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_domain_name: Option<
+        Box<
+            dyn Fn(
+                    UpdateDomainNameRequest,
+                )
+                    -> ::std::result::Result<DomainName, RusotoError<UpdateDomainNameError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_gateway_response: Option<
+        Box<
+            dyn Fn(
+                    UpdateGatewayResponseRequest,
+                ) -> ::std::result::Result<
+                    GatewayResponse,
+                    RusotoError<UpdateGatewayResponseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_integration: Option<
+        Box<
+            dyn Fn(
+                    UpdateIntegrationRequest,
+                )
+                    -> ::std::result::Result<Integration, RusotoError<UpdateIntegrationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_integration_response: Option<
+        Box<
+            dyn Fn(
+                    UpdateIntegrationResponseRequest,
+                ) -> ::std::result::Result<
+                    IntegrationResponse,
+                    RusotoError<UpdateIntegrationResponseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_method: Option<
+        Box<
+            dyn Fn(
+                    UpdateMethodRequest,
+                ) -> ::std::result::Result<Method, RusotoError<UpdateMethodError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_method_response: Option<
+        Box<
+            dyn Fn(
+                    UpdateMethodResponseRequest,
+                )
+                    -> ::std::result::Result<MethodResponse, RusotoError<UpdateMethodResponseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_model: Option<
+        Box<
+            dyn Fn(
+                    UpdateModelRequest,
+                ) -> ::std::result::Result<Model, RusotoError<UpdateModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_request_validator: Option<
+        Box<
+            dyn Fn(
+                    UpdateRequestValidatorRequest,
+                ) -> ::std::result::Result<
+                    RequestValidator,
+                    RusotoError<UpdateRequestValidatorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_resource: Option<
+        Box<
+            dyn Fn(
+                    UpdateResourceRequest,
+                )
+                    -> ::std::result::Result<Resource, RusotoError<UpdateResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_rest_api: Option<
+        Box<
+            dyn Fn(
+                    UpdateRestApiRequest,
+                )
+                    -> ::std::result::Result<RestApi, RusotoError<UpdateRestApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_stage: Option<
+        Box<
+            dyn Fn(
+                    UpdateStageRequest,
+                ) -> ::std::result::Result<Stage, RusotoError<UpdateStageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_usage: Option<
+        Box<
+            dyn Fn(
+                    UpdateUsageRequest,
+                ) -> ::std::result::Result<Usage, RusotoError<UpdateUsageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_usage_plan: Option<
+        Box<
+            dyn Fn(
+                    UpdateUsagePlanRequest,
+                )
+                    -> ::std::result::Result<UsagePlan, RusotoError<UpdateUsagePlanError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_vpc_link: Option<
+        Box<
+            dyn Fn(
+                    UpdateVpcLinkRequest,
+                )
+                    -> ::std::result::Result<VpcLink, RusotoError<UpdateVpcLinkError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockApiGateway {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockApiGateway {
+        Default::default()
+    }
+
+    /// Answers `create_api_key` with what `handler` returns for its input.
+    pub fn with_create_api_key<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(CreateApiKeyRequest) -> ::std::result::Result<ApiKey, RusotoError<CreateApiKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_api_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_authorizer` with what `handler` returns for its input.
+    pub fn with_create_authorizer<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateAuthorizerRequest,
+            ) -> ::std::result::Result<Authorizer, RusotoError<CreateAuthorizerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_authorizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_base_path_mapping` with what `handler` returns for its input.
+    pub fn with_create_base_path_mapping<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateBasePathMappingRequest,
+            )
+                -> ::std::result::Result<BasePathMapping, RusotoError<CreateBasePathMappingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_base_path_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_deployment` with what `handler` returns for its input.
+    pub fn with_create_deployment<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateDeploymentRequest,
+            ) -> ::std::result::Result<Deployment, RusotoError<CreateDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_documentation_part` with what `handler` returns for its input.
+    pub fn with_create_documentation_part<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateDocumentationPartRequest,
+            ) -> ::std::result::Result<
+                DocumentationPart,
+                RusotoError<CreateDocumentationPartError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_documentation_part = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_documentation_version` with what `handler` returns for its input.
+    pub fn with_create_documentation_version<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateDocumentationVersionRequest,
+            ) -> ::std::result::Result<
+                DocumentationVersion,
+                RusotoError<CreateDocumentationVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_documentation_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_domain_name` with what `handler` returns for its input.
+    pub fn with_create_domain_name<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateDomainNameRequest,
+            ) -> ::std::result::Result<DomainName, RusotoError<CreateDomainNameError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_domain_name = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_model` with what `handler` returns for its input.
+    pub fn with_create_model<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(CreateModelRequest) -> ::std::result::Result<Model, RusotoError<CreateModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_request_validator` with what `handler` returns for its input.
+    pub fn with_create_request_validator<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateRequestValidatorRequest,
+            )
+                -> ::std::result::Result<RequestValidator, RusotoError<CreateRequestValidatorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_request_validator = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_resource` with what `handler` returns for its input.
+    pub fn with_create_resource<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateResourceRequest,
+            ) -> ::std::result::Result<Resource, RusotoError<CreateResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_rest_api` with what `handler` returns for its input.
+    pub fn with_create_rest_api<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateRestApiRequest,
+            ) -> ::std::result::Result<RestApi, RusotoError<CreateRestApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_rest_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_stage` with what `handler` returns for its input.
+    pub fn with_create_stage<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(CreateStageRequest) -> ::std::result::Result<Stage, RusotoError<CreateStageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_stage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_usage_plan` with what `handler` returns for its input.
+    pub fn with_create_usage_plan<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateUsagePlanRequest,
+            ) -> ::std::result::Result<UsagePlan, RusotoError<CreateUsagePlanError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_usage_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_usage_plan_key` with what `handler` returns for its input.
+    pub fn with_create_usage_plan_key<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateUsagePlanKeyRequest,
+            )
+                -> ::std::result::Result<UsagePlanKey, RusotoError<CreateUsagePlanKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_usage_plan_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_vpc_link` with what `handler` returns for its input.
+    pub fn with_create_vpc_link<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                CreateVpcLinkRequest,
+            ) -> ::std::result::Result<VpcLink, RusotoError<CreateVpcLinkError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_vpc_link = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_api_key` with what `handler` returns for its input.
+    pub fn with_delete_api_key<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(DeleteApiKeyRequest) -> ::std::result::Result<(), RusotoError<DeleteApiKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_api_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_authorizer` with what `handler` returns for its input.
+    pub fn with_delete_authorizer<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteAuthorizerRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteAuthorizerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_authorizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_base_path_mapping` with what `handler` returns for its input.
+    pub fn with_delete_base_path_mapping<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteBasePathMappingRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteBasePathMappingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_base_path_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_client_certificate` with what `handler` returns for its input.
+    pub fn with_delete_client_certificate<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteClientCertificateRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteClientCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_client_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_deployment` with what `handler` returns for its input.
+    pub fn with_delete_deployment<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteDeploymentRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_documentation_part` with what `handler` returns for its input.
+    pub fn with_delete_documentation_part<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteDocumentationPartRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDocumentationPartError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_documentation_part = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_documentation_version` with what `handler` returns for its input.
+    pub fn with_delete_documentation_version<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteDocumentationVersionRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteDocumentationVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_documentation_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_domain_name` with what `handler` returns for its input.
+    pub fn with_delete_domain_name<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteDomainNameRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDomainNameError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_domain_name = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_gateway_response` with what `handler` returns for its input.
+    pub fn with_delete_gateway_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteGatewayResponseRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteGatewayResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_gateway_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_integration` with what `handler` returns for its input.
+    pub fn with_delete_integration<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteIntegrationRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteIntegrationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_integration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_integration_response` with what `handler` returns for its input.
+    pub fn with_delete_integration_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteIntegrationResponseRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteIntegrationResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_integration_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_method` with what `handler` returns for its input.
+    pub fn with_delete_method<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(DeleteMethodRequest) -> ::std::result::Result<(), RusotoError<DeleteMethodError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_method = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_method_response` with what `handler` returns for its input.
+    pub fn with_delete_method_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteMethodResponseRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteMethodResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_method_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_model` with what `handler` returns for its input.
+    pub fn with_delete_model<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(DeleteModelRequest) -> ::std::result::Result<(), RusotoError<DeleteModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_request_validator` with what `handler` returns for its input.
+    pub fn with_delete_request_validator<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteRequestValidatorRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteRequestValidatorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_request_validator = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_resource` with what `handler` returns for its input.
+    pub fn with_delete_resource<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(DeleteResourceRequest) -> ::std::result::Result<(), RusotoError<DeleteResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_rest_api` with what `handler` returns for its input.
+    pub fn with_delete_rest_api<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(DeleteRestApiRequest) -> ::std::result::Result<(), RusotoError<DeleteRestApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_rest_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_stage` with what `handler` returns for its input.
+    pub fn with_delete_stage<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(DeleteStageRequest) -> ::std::result::Result<(), RusotoError<DeleteStageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_stage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_usage_plan` with what `handler` returns for its input.
+    pub fn with_delete_usage_plan<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteUsagePlanRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteUsagePlanError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_usage_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_usage_plan_key` with what `handler` returns for its input.
+    pub fn with_delete_usage_plan_key<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                DeleteUsagePlanKeyRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteUsagePlanKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_usage_plan_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_vpc_link` with what `handler` returns for its input.
+    pub fn with_delete_vpc_link<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(DeleteVpcLinkRequest) -> ::std::result::Result<(), RusotoError<DeleteVpcLinkError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_vpc_link = Some(Box::new(handler));
+        self
+    }
+    /// Answers `flush_stage_authorizers_cache` with what `handler` returns for its input.
+    pub fn with_flush_stage_authorizers_cache<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                FlushStageAuthorizersCacheRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<FlushStageAuthorizersCacheError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.flush_stage_authorizers_cache = Some(Box::new(handler));
+        self
+    }
+    /// Answers `flush_stage_cache` with what `handler` returns for its input.
+    pub fn with_flush_stage_cache<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                FlushStageCacheRequest,
+            ) -> ::std::result::Result<(), RusotoError<FlushStageCacheError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.flush_stage_cache = Some(Box::new(handler));
+        self
+    }
+    /// Answers `generate_client_certificate` with what `handler` returns for its input.
+    pub fn with_generate_client_certificate<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GenerateClientCertificateRequest,
+            ) -> ::std::result::Result<
+                ClientCertificate,
+                RusotoError<GenerateClientCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.generate_client_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_account` with what `handler` returns.
+    pub fn with_get_account<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn() -> ::std::result::Result<Account, RusotoError<GetAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_api_key` with what `handler` returns for its input.
+    pub fn with_get_api_key<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetApiKeyRequest) -> ::std::result::Result<ApiKey, RusotoError<GetApiKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_api_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_api_keys` with what `handler` returns for its input.
+    pub fn with_get_api_keys<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetApiKeysRequest) -> ::std::result::Result<ApiKeys, RusotoError<GetApiKeysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_api_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_authorizer` with what `handler` returns for its input.
+    pub fn with_get_authorizer<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetAuthorizerRequest,
+            ) -> ::std::result::Result<Authorizer, RusotoError<GetAuthorizerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_authorizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_authorizers` with what `handler` returns for its input.
+    pub fn with_get_authorizers<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetAuthorizersRequest,
+            ) -> ::std::result::Result<Authorizers, RusotoError<GetAuthorizersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_authorizers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_base_path_mapping` with what `handler` returns for its input.
+    pub fn with_get_base_path_mapping<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetBasePathMappingRequest,
+            )
+                -> ::std::result::Result<BasePathMapping, RusotoError<GetBasePathMappingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_base_path_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_base_path_mappings` with what `handler` returns for its input.
+    pub fn with_get_base_path_mappings<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetBasePathMappingsRequest,
+            )
+                -> ::std::result::Result<BasePathMappings, RusotoError<GetBasePathMappingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_base_path_mappings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_client_certificate` with what `handler` returns for its input.
+    pub fn with_get_client_certificate<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetClientCertificateRequest,
+            )
+                -> ::std::result::Result<ClientCertificate, RusotoError<GetClientCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_client_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_client_certificates` with what `handler` returns for its input.
+    pub fn with_get_client_certificates<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetClientCertificatesRequest,
+            )
+                -> ::std::result::Result<ClientCertificates, RusotoError<GetClientCertificatesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_client_certificates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployment` with what `handler` returns for its input.
+    pub fn with_get_deployment<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetDeploymentRequest,
+            ) -> ::std::result::Result<Deployment, RusotoError<GetDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployments` with what `handler` returns for its input.
+    pub fn with_get_deployments<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetDeploymentsRequest,
+            ) -> ::std::result::Result<Deployments, RusotoError<GetDeploymentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_documentation_part` with what `handler` returns for its input.
+    pub fn with_get_documentation_part<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetDocumentationPartRequest,
+            )
+                -> ::std::result::Result<DocumentationPart, RusotoError<GetDocumentationPartError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_documentation_part = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_documentation_parts` with what `handler` returns for its input.
+    pub fn with_get_documentation_parts<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetDocumentationPartsRequest,
+            )
+                -> ::std::result::Result<DocumentationParts, RusotoError<GetDocumentationPartsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_documentation_parts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_documentation_version` with what `handler` returns for its input.
+    pub fn with_get_documentation_version<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetDocumentationVersionRequest,
+            ) -> ::std::result::Result<
+                DocumentationVersion,
+                RusotoError<GetDocumentationVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_documentation_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_documentation_versions` with what `handler` returns for its input.
+    pub fn with_get_documentation_versions<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetDocumentationVersionsRequest,
+            ) -> ::std::result::Result<
+                DocumentationVersions,
+                RusotoError<GetDocumentationVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_documentation_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_domain_name` with what `handler` returns for its input.
+    pub fn with_get_domain_name<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetDomainNameRequest,
+            ) -> ::std::result::Result<DomainName, RusotoError<GetDomainNameError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_domain_name = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_domain_names` with what `handler` returns for its input.
+    pub fn with_get_domain_names<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetDomainNamesRequest,
+            ) -> ::std::result::Result<DomainNames, RusotoError<GetDomainNamesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_domain_names = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_export` with what `handler` returns for its input.
+    pub fn with_get_export<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetExportRequest,
+            ) -> ::std::result::Result<ExportResponse, RusotoError<GetExportError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_export = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_gateway_response` with what `handler` returns for its input.
+    pub fn with_get_gateway_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetGatewayResponseRequest,
+            )
+                -> ::std::result::Result<GatewayResponse, RusotoError<GetGatewayResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_gateway_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_gateway_responses` with what `handler` returns for its input.
+    pub fn with_get_gateway_responses<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetGatewayResponsesRequest,
+            )
+                -> ::std::result::Result<GatewayResponses, RusotoError<GetGatewayResponsesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_gateway_responses = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_integration` with what `handler` returns for its input.
+    pub fn with_get_integration<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetIntegrationRequest,
+            ) -> ::std::result::Result<Integration, RusotoError<GetIntegrationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_integration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_integration_response` with what `handler` returns for its input.
+    pub fn with_get_integration_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetIntegrationResponseRequest,
+            ) -> ::std::result::Result<
+                IntegrationResponse,
+                RusotoError<GetIntegrationResponseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_integration_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_method` with what `handler` returns for its input.
+    pub fn with_get_method<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetMethodRequest) -> ::std::result::Result<Method, RusotoError<GetMethodError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_method = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_method_response` with what `handler` returns for its input.
+    pub fn with_get_method_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetMethodResponseRequest,
+            )
+                -> ::std::result::Result<MethodResponse, RusotoError<GetMethodResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_method_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_model` with what `handler` returns for its input.
+    pub fn with_get_model<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetModelRequest) -> ::std::result::Result<Model, RusotoError<GetModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_model_template` with what `handler` returns for its input.
+    pub fn with_get_model_template<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetModelTemplateRequest,
+            ) -> ::std::result::Result<Template, RusotoError<GetModelTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_model_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_models` with what `handler` returns for its input.
+    pub fn with_get_models<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetModelsRequest) -> ::std::result::Result<Models, RusotoError<GetModelsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_models = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_request_validator` with what `handler` returns for its input.
+    pub fn with_get_request_validator<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetRequestValidatorRequest,
+            )
+                -> ::std::result::Result<RequestValidator, RusotoError<GetRequestValidatorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_request_validator = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_request_validators` with what `handler` returns for its input.
+    pub fn with_get_request_validators<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetRequestValidatorsRequest,
+            )
+                -> ::std::result::Result<RequestValidators, RusotoError<GetRequestValidatorsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_request_validators = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource` with what `handler` returns for its input.
+    pub fn with_get_resource<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetResourceRequest) -> ::std::result::Result<Resource, RusotoError<GetResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resources` with what `handler` returns for its input.
+    pub fn with_get_resources<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetResourcesRequest,
+            ) -> ::std::result::Result<Resources, RusotoError<GetResourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_rest_api` with what `handler` returns for its input.
+    pub fn with_get_rest_api<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetRestApiRequest) -> ::std::result::Result<RestApi, RusotoError<GetRestApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_rest_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_rest_apis` with what `handler` returns for its input.
+    pub fn with_get_rest_apis<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetRestApisRequest) -> ::std::result::Result<RestApis, RusotoError<GetRestApisError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_rest_apis = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_sdk` with what `handler` returns for its input.
+    pub fn with_get_sdk<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetSdkRequest) -> ::std::result::Result<SdkResponse, RusotoError<GetSdkError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_sdk = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_sdk_type` with what `handler` returns for its input.
+    pub fn with_get_sdk_type<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetSdkTypeRequest) -> ::std::result::Result<SdkType, RusotoError<GetSdkTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_sdk_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_sdk_types` with what `handler` returns for its input.
+    pub fn with_get_sdk_types<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetSdkTypesRequest) -> ::std::result::Result<SdkTypes, RusotoError<GetSdkTypesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_sdk_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_stage` with what `handler` returns for its input.
+    pub fn with_get_stage<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetStageRequest) -> ::std::result::Result<Stage, RusotoError<GetStageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_stage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_stages` with what `handler` returns for its input.
+    pub fn with_get_stages<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetStagesRequest) -> ::std::result::Result<Stages, RusotoError<GetStagesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_stages = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_tags` with what `handler` returns for its input.
+    pub fn with_get_tags<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetTagsRequest) -> ::std::result::Result<Tags, RusotoError<GetTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_usage` with what `handler` returns for its input.
+    pub fn with_get_usage<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetUsageRequest) -> ::std::result::Result<Usage, RusotoError<GetUsageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_usage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_usage_plan` with what `handler` returns for its input.
+    pub fn with_get_usage_plan<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetUsagePlanRequest,
+            ) -> ::std::result::Result<UsagePlan, RusotoError<GetUsagePlanError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_usage_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_usage_plan_key` with what `handler` returns for its input.
+    pub fn with_get_usage_plan_key<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetUsagePlanKeyRequest,
+            )
+                -> ::std::result::Result<UsagePlanKey, RusotoError<GetUsagePlanKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_usage_plan_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_usage_plan_keys` with what `handler` returns for its input.
+    pub fn with_get_usage_plan_keys<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetUsagePlanKeysRequest,
+            )
+                -> ::std::result::Result<UsagePlanKeys, RusotoError<GetUsagePlanKeysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_usage_plan_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_usage_plans` with what `handler` returns for its input.
+    pub fn with_get_usage_plans<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                GetUsagePlansRequest,
+            ) -> ::std::result::Result<UsagePlans, RusotoError<GetUsagePlansError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_usage_plans = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_vpc_link` with what `handler` returns for its input.
+    pub fn with_get_vpc_link<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetVpcLinkRequest) -> ::std::result::Result<VpcLink, RusotoError<GetVpcLinkError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_vpc_link = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_vpc_links` with what `handler` returns for its input.
+    pub fn with_get_vpc_links<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(GetVpcLinksRequest) -> ::std::result::Result<VpcLinks, RusotoError<GetVpcLinksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_vpc_links = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_api_keys` with what `handler` returns for its input.
+    pub fn with_import_api_keys<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                ImportApiKeysRequest,
+            ) -> ::std::result::Result<ApiKeyIds, RusotoError<ImportApiKeysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.import_api_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_documentation_parts` with what `handler` returns for its input.
+    pub fn with_import_documentation_parts<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                ImportDocumentationPartsRequest,
+            ) -> ::std::result::Result<
+                DocumentationPartIds,
+                RusotoError<ImportDocumentationPartsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_documentation_parts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_rest_api` with what `handler` returns for its input.
+    pub fn with_import_rest_api<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                ImportRestApiRequest,
+            ) -> ::std::result::Result<RestApi, RusotoError<ImportRestApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.import_rest_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_gateway_response` with what `handler` returns for its input.
+    pub fn with_put_gateway_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                PutGatewayResponseRequest,
+            )
+                -> ::std::result::Result<GatewayResponse, RusotoError<PutGatewayResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_gateway_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_integration` with what `handler` returns for its input.
+    pub fn with_put_integration<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                PutIntegrationRequest,
+            ) -> ::std::result::Result<Integration, RusotoError<PutIntegrationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_integration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_integration_response` with what `handler` returns for its input.
+    pub fn with_put_integration_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                PutIntegrationResponseRequest,
+            ) -> ::std::result::Result<
+                IntegrationResponse,
+                RusotoError<PutIntegrationResponseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_integration_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_method` with what `handler` returns for its input.
+    pub fn with_put_method<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(PutMethodRequest) -> ::std::result::Result<Method, RusotoError<PutMethodError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_method = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_method_response` with what `handler` returns for its input.
+    pub fn with_put_method_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                PutMethodResponseRequest,
+            )
+                -> ::std::result::Result<MethodResponse, RusotoError<PutMethodResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_method_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_rest_api` with what `handler` returns for its input.
+    pub fn with_put_rest_api<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(PutRestApiRequest) -> ::std::result::Result<RestApi, RusotoError<PutRestApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_rest_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_invoke_authorizer` with what `handler` returns for its input.
+    pub fn with_test_invoke_authorizer<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                TestInvokeAuthorizerRequest,
+            ) -> ::std::result::Result<
+                TestInvokeAuthorizerResponse,
+                RusotoError<TestInvokeAuthorizerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.test_invoke_authorizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_invoke_method` with what `handler` returns for its input.
+    pub fn with_test_invoke_method<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                TestInvokeMethodRequest,
+            ) -> ::std::result::Result<
+                TestInvokeMethodResponse,
+                RusotoError<TestInvokeMethodError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.test_invoke_method = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_account` with what `handler` returns for its input.
+    pub fn with_update_account<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateAccountRequest,
+            ) -> ::std::result::Result<Account, RusotoError<UpdateAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_api_key` with what `handler` returns for its input.
+    pub fn with_update_api_key<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(UpdateApiKeyRequest) -> ::std::result::Result<ApiKey, RusotoError<UpdateApiKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_api_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_authorizer` with what `handler` returns for its input.
+    pub fn with_update_authorizer<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateAuthorizerRequest,
+            ) -> ::std::result::Result<Authorizer, RusotoError<UpdateAuthorizerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_authorizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_base_path_mapping` with what `handler` returns for its input.
+    pub fn with_update_base_path_mapping<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateBasePathMappingRequest,
+            )
+                -> ::std::result::Result<BasePathMapping, RusotoError<UpdateBasePathMappingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_base_path_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_client_certificate` with what `handler` returns for its input.
+    pub fn with_update_client_certificate<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateClientCertificateRequest,
+            ) -> ::std::result::Result<
+                ClientCertificate,
+                RusotoError<UpdateClientCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_client_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_deployment` with what `handler` returns for its input.
+    pub fn with_update_deployment<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateDeploymentRequest,
+            ) -> ::std::result::Result<Deployment, RusotoError<UpdateDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_documentation_part` with what `handler` returns for its input.
+    pub fn with_update_documentation_part<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateDocumentationPartRequest,
+            ) -> ::std::result::Result<
+                DocumentationPart,
+                RusotoError<UpdateDocumentationPartError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_documentation_part = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_documentation_version` with what `handler` returns for its input.
+    pub fn with_update_documentation_version<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateDocumentationVersionRequest,
+            ) -> ::std::result::Result<
+                DocumentationVersion,
+                RusotoError<UpdateDocumentationVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_documentation_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_domain_name` with what `handler` returns for its input.
+    pub fn with_update_domain_name<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateDomainNameRequest,
+            ) -> ::std::result::Result<DomainName, RusotoError<UpdateDomainNameError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_domain_name = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_gateway_response` with what `handler` returns for its input.
+    pub fn with_update_gateway_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateGatewayResponseRequest,
+            )
+                -> ::std::result::Result<GatewayResponse, RusotoError<UpdateGatewayResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_gateway_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_integration` with what `handler` returns for its input.
+    pub fn with_update_integration<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateIntegrationRequest,
+            )
+                -> ::std::result::Result<Integration, RusotoError<UpdateIntegrationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_integration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_integration_response` with what `handler` returns for its input.
+    pub fn with_update_integration_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateIntegrationResponseRequest,
+            ) -> ::std::result::Result<
+                IntegrationResponse,
+                RusotoError<UpdateIntegrationResponseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_integration_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_method` with what `handler` returns for its input.
+    pub fn with_update_method<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(UpdateMethodRequest) -> ::std::result::Result<Method, RusotoError<UpdateMethodError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_method = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_method_response` with what `handler` returns for its input.
+    pub fn with_update_method_response<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateMethodResponseRequest,
+            )
+                -> ::std::result::Result<MethodResponse, RusotoError<UpdateMethodResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_method_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_model` with what `handler` returns for its input.
+    pub fn with_update_model<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(UpdateModelRequest) -> ::std::result::Result<Model, RusotoError<UpdateModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_request_validator` with what `handler` returns for its input.
+    pub fn with_update_request_validator<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateRequestValidatorRequest,
+            )
+                -> ::std::result::Result<RequestValidator, RusotoError<UpdateRequestValidatorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_request_validator = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_resource` with what `handler` returns for its input.
+    pub fn with_update_resource<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateResourceRequest,
+            ) -> ::std::result::Result<Resource, RusotoError<UpdateResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_rest_api` with what `handler` returns for its input.
+    pub fn with_update_rest_api<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateRestApiRequest,
+            ) -> ::std::result::Result<RestApi, RusotoError<UpdateRestApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_rest_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_stage` with what `handler` returns for its input.
+    pub fn with_update_stage<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(UpdateStageRequest) -> ::std::result::Result<Stage, RusotoError<UpdateStageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_stage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_usage` with what `handler` returns for its input.
+    pub fn with_update_usage<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(UpdateUsageRequest) -> ::std::result::Result<Usage, RusotoError<UpdateUsageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_usage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_usage_plan` with what `handler` returns for its input.
+    pub fn with_update_usage_plan<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateUsagePlanRequest,
+            ) -> ::std::result::Result<UsagePlan, RusotoError<UpdateUsagePlanError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_usage_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_vpc_link` with what `handler` returns for its input.
+    pub fn with_update_vpc_link<F>(mut self, handler: F) -> MockApiGateway
+    where
+        F: Fn(
+                UpdateVpcLinkRequest,
+            ) -> ::std::result::Result<VpcLink, RusotoError<UpdateVpcLinkError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_vpc_link = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ApiGateway for MockApiGateway {
+    fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> RusotoFuture<ApiKey, CreateApiKeyError> {
+        match self.create_api_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_api_key"),
+        }
+    }
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, CreateAuthorizerError> {
+        match self.create_authorizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_authorizer"),
+        }
+    }
+    fn create_base_path_mapping(
+        &self,
+        input: CreateBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, CreateBasePathMappingError> {
+        match self.create_base_path_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_base_path_mapping"),
+        }
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<Deployment, CreateDeploymentError> {
+        match self.create_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_deployment"),
+        }
+    }
+    fn create_documentation_part(
+        &self,
+        input: CreateDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, CreateDocumentationPartError> {
+        match self.create_documentation_part {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_documentation_part"),
+        }
+    }
+    fn create_documentation_version(
+        &self,
+        input: CreateDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, CreateDocumentationVersionError> {
+        match self.create_documentation_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_documentation_version"),
+        }
+    }
+    fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> RusotoFuture<DomainName, CreateDomainNameError> {
+        match self.create_domain_name {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_domain_name"),
+        }
+    }
+    fn create_model(&self, input: CreateModelRequest) -> RusotoFuture<Model, CreateModelError> {
+        match self.create_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_model"),
+        }
+    }
+    fn create_request_validator(
+        &self,
+        input: CreateRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, CreateRequestValidatorError> {
+        match self.create_request_validator {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_request_validator"),
+        }
+    }
+    fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> RusotoFuture<Resource, CreateResourceError> {
+        match self.create_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_resource"),
+        }
+    }
+    fn create_rest_api(
+        &self,
+        input: CreateRestApiRequest,
+    ) -> RusotoFuture<RestApi, CreateRestApiError> {
+        match self.create_rest_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_rest_api"),
+        }
+    }
+    fn create_stage(&self, input: CreateStageRequest) -> RusotoFuture<Stage, CreateStageError> {
+        match self.create_stage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_stage"),
+        }
+    }
+    fn create_usage_plan(
+        &self,
+        input: CreateUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, CreateUsagePlanError> {
+        match self.create_usage_plan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_usage_plan"),
+        }
+    }
+    fn create_usage_plan_key(
+        &self,
+        input: CreateUsagePlanKeyRequest,
+    ) -> RusotoFuture<UsagePlanKey, CreateUsagePlanKeyError> {
+        match self.create_usage_plan_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_usage_plan_key"),
+        }
+    }
+    fn create_vpc_link(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> RusotoFuture<VpcLink, CreateVpcLinkError> {
+        match self.create_vpc_link {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for create_vpc_link"),
+        }
+    }
+    fn delete_api_key(&self, input: DeleteApiKeyRequest) -> RusotoFuture<(), DeleteApiKeyError> {
+        match self.delete_api_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_api_key"),
+        }
+    }
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<(), DeleteAuthorizerError> {
+        match self.delete_authorizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_authorizer"),
+        }
+    }
+    fn delete_base_path_mapping(
+        &self,
+        input: DeleteBasePathMappingRequest,
+    ) -> RusotoFuture<(), DeleteBasePathMappingError> {
+        match self.delete_base_path_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_base_path_mapping"),
+        }
+    }
+    fn delete_client_certificate(
+        &self,
+        input: DeleteClientCertificateRequest,
+    ) -> RusotoFuture<(), DeleteClientCertificateError> {
+        match self.delete_client_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_client_certificate"),
+        }
+    }
+    fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> RusotoFuture<(), DeleteDeploymentError> {
+        match self.delete_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_deployment"),
+        }
+    }
+    fn delete_documentation_part(
+        &self,
+        input: DeleteDocumentationPartRequest,
+    ) -> RusotoFuture<(), DeleteDocumentationPartError> {
+        match self.delete_documentation_part {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_documentation_part"),
+        }
+    }
+    fn delete_documentation_version(
+        &self,
+        input: DeleteDocumentationVersionRequest,
+    ) -> RusotoFuture<(), DeleteDocumentationVersionError> {
+        match self.delete_documentation_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_documentation_version"),
+        }
+    }
+    fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> RusotoFuture<(), DeleteDomainNameError> {
+        match self.delete_domain_name {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_domain_name"),
+        }
+    }
+    fn delete_gateway_response(
+        &self,
+        input: DeleteGatewayResponseRequest,
+    ) -> RusotoFuture<(), DeleteGatewayResponseError> {
+        match self.delete_gateway_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_gateway_response"),
+        }
+    }
+    fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationError> {
+        match self.delete_integration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_integration"),
+        }
+    }
+    fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
+        match self.delete_integration_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_integration_response"),
+        }
+    }
+    fn delete_method(&self, input: DeleteMethodRequest) -> RusotoFuture<(), DeleteMethodError> {
+        match self.delete_method {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_method"),
+        }
+    }
+    fn delete_method_response(
+        &self,
+        input: DeleteMethodResponseRequest,
+    ) -> RusotoFuture<(), DeleteMethodResponseError> {
+        match self.delete_method_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_method_response"),
+        }
+    }
+    fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
+        match self.delete_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_model"),
+        }
+    }
+    fn delete_request_validator(
+        &self,
+        input: DeleteRequestValidatorRequest,
+    ) -> RusotoFuture<(), DeleteRequestValidatorError> {
+        match self.delete_request_validator {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_request_validator"),
+        }
+    }
+    fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> RusotoFuture<(), DeleteResourceError> {
+        match self.delete_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_resource"),
+        }
+    }
+    fn delete_rest_api(&self, input: DeleteRestApiRequest) -> RusotoFuture<(), DeleteRestApiError> {
+        match self.delete_rest_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_rest_api"),
+        }
+    }
+    fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
+        match self.delete_stage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_stage"),
+        }
+    }
+    fn delete_usage_plan(
+        &self,
+        input: DeleteUsagePlanRequest,
+    ) -> RusotoFuture<(), DeleteUsagePlanError> {
+        match self.delete_usage_plan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_usage_plan"),
+        }
+    }
+    fn delete_usage_plan_key(
+        &self,
+        input: DeleteUsagePlanKeyRequest,
+    ) -> RusotoFuture<(), DeleteUsagePlanKeyError> {
+        match self.delete_usage_plan_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_usage_plan_key"),
+        }
+    }
+    fn delete_vpc_link(&self, input: DeleteVpcLinkRequest) -> RusotoFuture<(), DeleteVpcLinkError> {
+        match self.delete_vpc_link {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for delete_vpc_link"),
+        }
+    }
+    fn flush_stage_authorizers_cache(
+        &self,
+        input: FlushStageAuthorizersCacheRequest,
+    ) -> RusotoFuture<(), FlushStageAuthorizersCacheError> {
+        match self.flush_stage_authorizers_cache {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for flush_stage_authorizers_cache"),
+        }
+    }
+    fn flush_stage_cache(
+        &self,
+        input: FlushStageCacheRequest,
+    ) -> RusotoFuture<(), FlushStageCacheError> {
+        match self.flush_stage_cache {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for flush_stage_cache"),
+        }
+    }
+    fn generate_client_certificate(
+        &self,
+        input: GenerateClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, GenerateClientCertificateError> {
+        match self.generate_client_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for generate_client_certificate"),
+        }
+    }
+    fn get_account(&self) -> RusotoFuture<Account, GetAccountError> {
+        match self.get_account {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockApiGateway has no handler for get_account"),
+        }
+    }
+    fn get_api_key(&self, input: GetApiKeyRequest) -> RusotoFuture<ApiKey, GetApiKeyError> {
+        match self.get_api_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_api_key"),
+        }
+    }
+    fn get_api_keys(&self, input: GetApiKeysRequest) -> RusotoFuture<ApiKeys, GetApiKeysError> {
+        match self.get_api_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_api_keys"),
+        }
+    }
+    fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, GetAuthorizerError> {
+        match self.get_authorizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_authorizer"),
+        }
+    }
+    fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> RusotoFuture<Authorizers, GetAuthorizersError> {
+        match self.get_authorizers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_authorizers"),
+        }
+    }
+    fn get_base_path_mapping(
+        &self,
+        input: GetBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, GetBasePathMappingError> {
+        match self.get_base_path_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_base_path_mapping"),
+        }
+    }
+    fn get_base_path_mappings(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> RusotoFuture<BasePathMappings, GetBasePathMappingsError> {
+        match self.get_base_path_mappings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_base_path_mappings"),
+        }
+    }
+    fn get_client_certificate(
+        &self,
+        input: GetClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, GetClientCertificateError> {
+        match self.get_client_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_client_certificate"),
+        }
+    }
+    fn get_client_certificates(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> RusotoFuture<ClientCertificates, GetClientCertificatesError> {
+        match self.get_client_certificates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_client_certificates"),
+        }
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> RusotoFuture<Deployment, GetDeploymentError> {
+        match self.get_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_deployment"),
+        }
+    }
+    fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> RusotoFuture<Deployments, GetDeploymentsError> {
+        match self.get_deployments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_deployments"),
+        }
+    }
+    fn get_documentation_part(
+        &self,
+        input: GetDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, GetDocumentationPartError> {
+        match self.get_documentation_part {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_documentation_part"),
+        }
+    }
+    fn get_documentation_parts(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> RusotoFuture<DocumentationParts, GetDocumentationPartsError> {
+        match self.get_documentation_parts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_documentation_parts"),
+        }
+    }
+    fn get_documentation_version(
+        &self,
+        input: GetDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, GetDocumentationVersionError> {
+        match self.get_documentation_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_documentation_version"),
+        }
+    }
+    fn get_documentation_versions(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> RusotoFuture<DocumentationVersions, GetDocumentationVersionsError> {
+        match self.get_documentation_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_documentation_versions"),
+        }
+    }
+    fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> RusotoFuture<DomainName, GetDomainNameError> {
+        match self.get_domain_name {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_domain_name"),
+        }
+    }
+    fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> RusotoFuture<DomainNames, GetDomainNamesError> {
+        match self.get_domain_names {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_domain_names"),
+        }
+    }
+    fn get_export(&self, input: GetExportRequest) -> RusotoFuture<ExportResponse, GetExportError> {
+        match self.get_export {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_export"),
+        }
+    }
+    fn get_gateway_response(
+        &self,
+        input: GetGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, GetGatewayResponseError> {
+        match self.get_gateway_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_gateway_response"),
+        }
+    }
+    fn get_gateway_responses(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> RusotoFuture<GatewayResponses, GetGatewayResponsesError> {
+        match self.get_gateway_responses {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_gateway_responses"),
+        }
+    }
+    fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> RusotoFuture<Integration, GetIntegrationError> {
+        match self.get_integration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_integration"),
+        }
+    }
+    fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, GetIntegrationResponseError> {
+        match self.get_integration_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_integration_response"),
+        }
+    }
+    fn get_method(&self, input: GetMethodRequest) -> RusotoFuture<Method, GetMethodError> {
+        match self.get_method {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_method"),
+        }
+    }
+    fn get_method_response(
+        &self,
+        input: GetMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, GetMethodResponseError> {
+        match self.get_method_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_method_response"),
+        }
+    }
+    fn get_model(&self, input: GetModelRequest) -> RusotoFuture<Model, GetModelError> {
+        match self.get_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_model"),
+        }
+    }
+    fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> RusotoFuture<Template, GetModelTemplateError> {
+        match self.get_model_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_model_template"),
+        }
+    }
+    fn get_models(&self, input: GetModelsRequest) -> RusotoFuture<Models, GetModelsError> {
+        match self.get_models {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_models"),
+        }
+    }
+    fn get_request_validator(
+        &self,
+        input: GetRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, GetRequestValidatorError> {
+        match self.get_request_validator {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_request_validator"),
+        }
+    }
+    fn get_request_validators(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> RusotoFuture<RequestValidators, GetRequestValidatorsError> {
+        match self.get_request_validators {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_request_validators"),
+        }
+    }
+    fn get_resource(&self, input: GetResourceRequest) -> RusotoFuture<Resource, GetResourceError> {
+        match self.get_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_resource"),
+        }
+    }
+    fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> RusotoFuture<Resources, GetResourcesError> {
+        match self.get_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_resources"),
+        }
+    }
+    fn get_rest_api(&self, input: GetRestApiRequest) -> RusotoFuture<RestApi, GetRestApiError> {
+        match self.get_rest_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_rest_api"),
+        }
+    }
+    fn get_rest_apis(&self, input: GetRestApisRequest) -> RusotoFuture<RestApis, GetRestApisError> {
+        match self.get_rest_apis {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_rest_apis"),
+        }
+    }
+    fn get_sdk(&self, input: GetSdkRequest) -> RusotoFuture<SdkResponse, GetSdkError> {
+        match self.get_sdk {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_sdk"),
+        }
+    }
+    fn get_sdk_type(&self, input: GetSdkTypeRequest) -> RusotoFuture<SdkType, GetSdkTypeError> {
+        match self.get_sdk_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_sdk_type"),
+        }
+    }
+    fn get_sdk_types(&self, input: GetSdkTypesRequest) -> RusotoFuture<SdkTypes, GetSdkTypesError> {
+        match self.get_sdk_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_sdk_types"),
+        }
+    }
+    fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<Stage, GetStageError> {
+        match self.get_stage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_stage"),
+        }
+    }
+    fn get_stages(&self, input: GetStagesRequest) -> RusotoFuture<Stages, GetStagesError> {
+        match self.get_stages {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_stages"),
+        }
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<Tags, GetTagsError> {
+        match self.get_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_tags"),
+        }
+    }
+    fn get_usage(&self, input: GetUsageRequest) -> RusotoFuture<Usage, GetUsageError> {
+        match self.get_usage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_usage"),
+        }
+    }
+    fn get_usage_plan(
+        &self,
+        input: GetUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, GetUsagePlanError> {
+        match self.get_usage_plan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_usage_plan"),
+        }
+    }
+    fn get_usage_plan_key(
+        &self,
+        input: GetUsagePlanKeyRequest,
+    ) -> RusotoFuture<UsagePlanKey, GetUsagePlanKeyError> {
+        match self.get_usage_plan_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_usage_plan_key"),
+        }
+    }
+    fn get_usage_plan_keys(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> RusotoFuture<UsagePlanKeys, GetUsagePlanKeysError> {
+        match self.get_usage_plan_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_usage_plan_keys"),
+        }
+    }
+    fn get_usage_plans(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> RusotoFuture<UsagePlans, GetUsagePlansError> {
+        match self.get_usage_plans {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_usage_plans"),
+        }
+    }
+    fn get_vpc_link(&self, input: GetVpcLinkRequest) -> RusotoFuture<VpcLink, GetVpcLinkError> {
+        match self.get_vpc_link {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_vpc_link"),
+        }
+    }
+    fn get_vpc_links(&self, input: GetVpcLinksRequest) -> RusotoFuture<VpcLinks, GetVpcLinksError> {
+        match self.get_vpc_links {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for get_vpc_links"),
+        }
+    }
+    fn import_api_keys(
+        &self,
+        input: ImportApiKeysRequest,
+    ) -> RusotoFuture<ApiKeyIds, ImportApiKeysError> {
+        match self.import_api_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for import_api_keys"),
+        }
+    }
+    fn import_documentation_parts(
+        &self,
+        input: ImportDocumentationPartsRequest,
+    ) -> RusotoFuture<DocumentationPartIds, ImportDocumentationPartsError> {
+        match self.import_documentation_parts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for import_documentation_parts"),
+        }
+    }
+    fn import_rest_api(
+        &self,
+        input: ImportRestApiRequest,
+    ) -> RusotoFuture<RestApi, ImportRestApiError> {
+        match self.import_rest_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for import_rest_api"),
+        }
+    }
+    fn put_gateway_response(
+        &self,
+        input: PutGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, PutGatewayResponseError> {
+        match self.put_gateway_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for put_gateway_response"),
+        }
+    }
+    fn put_integration(
+        &self,
+        input: PutIntegrationRequest,
+    ) -> RusotoFuture<Integration, PutIntegrationError> {
+        match self.put_integration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for put_integration"),
+        }
+    }
+    fn put_integration_response(
+        &self,
+        input: PutIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, PutIntegrationResponseError> {
+        match self.put_integration_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for put_integration_response"),
+        }
+    }
+    fn put_method(&self, input: PutMethodRequest) -> RusotoFuture<Method, PutMethodError> {
+        match self.put_method {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for put_method"),
+        }
+    }
+    fn put_method_response(
+        &self,
+        input: PutMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, PutMethodResponseError> {
+        match self.put_method_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for put_method_response"),
+        }
+    }
+    fn put_rest_api(&self, input: PutRestApiRequest) -> RusotoFuture<RestApi, PutRestApiError> {
+        match self.put_rest_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for put_rest_api"),
+        }
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for tag_resource"),
+        }
+    }
+    fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> RusotoFuture<TestInvokeAuthorizerResponse, TestInvokeAuthorizerError> {
+        match self.test_invoke_authorizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for test_invoke_authorizer"),
+        }
+    }
+    fn test_invoke_method(
+        &self,
+        input: TestInvokeMethodRequest,
+    ) -> RusotoFuture<TestInvokeMethodResponse, TestInvokeMethodError> {
+        match self.test_invoke_method {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for test_invoke_method"),
+        }
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for untag_resource"),
+        }
+    }
+    fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> RusotoFuture<Account, UpdateAccountError> {
+        match self.update_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_account"),
+        }
+    }
+    fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> RusotoFuture<ApiKey, UpdateApiKeyError> {
+        match self.update_api_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_api_key"),
+        }
+    }
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, UpdateAuthorizerError> {
+        match self.update_authorizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_authorizer"),
+        }
+    }
+    fn update_base_path_mapping(
+        &self,
+        input: UpdateBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, UpdateBasePathMappingError> {
+        match self.update_base_path_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_base_path_mapping"),
+        }
+    }
+    fn update_client_certificate(
+        &self,
+        input: UpdateClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, UpdateClientCertificateError> {
+        match self.update_client_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_client_certificate"),
+        }
+    }
+    fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> RusotoFuture<Deployment, UpdateDeploymentError> {
+        match self.update_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_deployment"),
+        }
+    }
+    fn update_documentation_part(
+        &self,
+        input: UpdateDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, UpdateDocumentationPartError> {
+        match self.update_documentation_part {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_documentation_part"),
+        }
+    }
+    fn update_documentation_version(
+        &self,
+        input: UpdateDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, UpdateDocumentationVersionError> {
+        match self.update_documentation_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_documentation_version"),
+        }
+    }
+    fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> RusotoFuture<DomainName, UpdateDomainNameError> {
+        match self.update_domain_name {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_domain_name"),
+        }
+    }
+    fn update_gateway_response(
+        &self,
+        input: UpdateGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, UpdateGatewayResponseError> {
+        match self.update_gateway_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_gateway_response"),
+        }
+    }
+    fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> RusotoFuture<Integration, UpdateIntegrationError> {
+        match self.update_integration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_integration"),
+        }
+    }
+    fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, UpdateIntegrationResponseError> {
+        match self.update_integration_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_integration_response"),
+        }
+    }
+    fn update_method(&self, input: UpdateMethodRequest) -> RusotoFuture<Method, UpdateMethodError> {
+        match self.update_method {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_method"),
+        }
+    }
+    fn update_method_response(
+        &self,
+        input: UpdateMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, UpdateMethodResponseError> {
+        match self.update_method_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_method_response"),
+        }
     }
-
-    /// <p>Changes information about a model.</p>
     fn update_model(&self, input: UpdateModelRequest) -> RusotoFuture<Model, UpdateModelError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/models/{model_name}",
-            model_name = input.model_name,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateModelError::from_response(response))),
-                )
-            }
-        })
+        match self.update_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_model"),
+        }
     }
-
-    /// <p>Updates a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
     fn update_request_validator(
         &self,
         input: UpdateRequestValidatorRequest,
     ) -> RusotoFuture<RequestValidator, UpdateRequestValidatorError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/requestvalidators/{requestvalidator_id}",
-            requestvalidator_id = input.request_validator_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RequestValidator, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateRequestValidatorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_request_validator {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_request_validator"),
+        }
     }
-
-    /// <p>Changes information about a <a>Resource</a> resource.</p>
     fn update_resource(
         &self,
         input: UpdateResourceRequest,
     ) -> RusotoFuture<Resource, UpdateResourceError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/resources/{resource_id}",
-            resource_id = input.resource_id,
-            restapi_id = input.rest_api_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Resource, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.update_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_resource"),
+        }
     }
-
-    /// <p>Changes information about the specified API.</p>
     fn update_rest_api(
         &self,
         input: UpdateRestApiRequest,
     ) -> RusotoFuture<RestApi, UpdateRestApiError> {
-        let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateRestApiError::from_response(response))),
-                )
-            }
-        })
+        match self.update_rest_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_rest_api"),
+        }
     }
-
-    /// <p>Changes information about a <a>Stage</a> resource.</p>
     fn update_stage(&self, input: UpdateStageRequest) -> RusotoFuture<Stage, UpdateStageError> {
-        let request_uri = format!(
-            "/restapis/{restapi_id}/stages/{stage_name}",
-            restapi_id = input.rest_api_id,
-            stage_name = input.stage_name
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateStageError::from_response(response))),
-                )
-            }
-        })
+        match self.update_stage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_stage"),
+        }
     }
-
-    /// <p>Grants a temporary extension to the remaining quota of a usage plan associated with a specified API key.</p>
     fn update_usage(&self, input: UpdateUsageRequest) -> RusotoFuture<Usage, UpdateUsageError> {
-        let request_uri = format!(
-            "/usageplans/{usageplan_id}/keys/{key_id}/usage",
-            key_id = input.key_id,
-            usageplan_id = input.usage_plan_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<Usage, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateUsageError::from_response(response))),
-                )
-            }
-        })
+        match self.update_usage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_usage"),
+        }
     }
-
-    /// <p>Updates a usage plan of a given plan Id.</p>
     fn update_usage_plan(
         &self,
         input: UpdateUsagePlanRequest,
     ) -> RusotoFuture<UsagePlan, UpdateUsagePlanError> {
-        let request_uri = format!(
-            "/usageplans/{usageplan_id}",
-            usageplan_id = input.usage_plan_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UsagePlan, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateUsagePlanError::from_response(response))),
-                )
-            }
-        })
+        match self.update_usage_plan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_usage_plan"),
+        }
     }
-
-    /// <p>Updates an existing <a>VpcLink</a> of a specified identifier.</p>
     fn update_vpc_link(
         &self,
         input: UpdateVpcLinkRequest,
     ) -> RusotoFuture<VpcLink, UpdateVpcLinkError> {
-        let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result =
-                        proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateVpcLinkError::from_response(response))),
-                )
-            }
-        })
+        match self.update_vpc_link {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGateway has no handler for update_vpc_link"),
+        }
     }
 }
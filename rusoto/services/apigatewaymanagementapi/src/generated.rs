@@ -22,20 +22,23 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConnectionRequest {
     #[serde(rename = "ConnectionId")]
     pub connection_id: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConnectionRequest {
     #[serde(rename = "ConnectionId")]
     pub connection_id: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConnectionResponse {
     /// <p>The time in ISO 8601 format for when the connection was established.</p>
     #[serde(rename = "ConnectedAt")]
@@ -51,7 +54,7 @@ pub struct GetConnectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Identity {
     /// <p>The source IP address of the TCP connection making the request to API Gateway.</p>
     #[serde(rename = "SourceIp")]
@@ -62,6 +65,7 @@ pub struct Identity {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PostToConnectionRequest {
     /// <p>The identifier of the connection that a specific client is using.</p>
     #[serde(rename = "ConnectionId")]
@@ -76,6 +80,17 @@ pub struct PostToConnectionRequest {
     pub data: bytes::Bytes,
 }
 
+impl Validate for PostToConnectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data"),
+            self.data.len(),
+            None,
+            Some(131072),
+        );
+    }
+}
+
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteConnectionError {
@@ -100,7 +115,12 @@ impl DeleteConnectionError {
                 "LimitExceededException" => {
                     return RusotoError::Service(DeleteConnectionError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -143,7 +163,12 @@ impl GetConnectionError {
                 "LimitExceededException" => {
                     return RusotoError::Service(GetConnectionError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -193,7 +218,12 @@ impl PostToConnectionError {
                 "PayloadTooLargeException" => {
                     return RusotoError::Service(PostToConnectionError::PayloadTooLarge(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -235,6 +265,67 @@ pub trait ApiGatewayManagementApi {
         input: PostToConnectionRequest,
     ) -> RusotoFuture<(), PostToConnectionError>;
 }
+const _: Option<&dyn ApiGatewayManagementApi> = None;
+impl<'a, T: ApiGatewayManagementApi + ?Sized> ApiGatewayManagementApi for &'a T {
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<(), DeleteConnectionError> {
+        (**self).delete_connection(input)
+    }
+    fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
+        (**self).get_connection(input)
+    }
+    fn post_to_connection(
+        &self,
+        input: PostToConnectionRequest,
+    ) -> RusotoFuture<(), PostToConnectionError> {
+        (**self).post_to_connection(input)
+    }
+}
+impl<T: ApiGatewayManagementApi + ?Sized> ApiGatewayManagementApi for Box<T> {
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<(), DeleteConnectionError> {
+        (**self).delete_connection(input)
+    }
+    fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
+        (**self).get_connection(input)
+    }
+    fn post_to_connection(
+        &self,
+        input: PostToConnectionRequest,
+    ) -> RusotoFuture<(), PostToConnectionError> {
+        (**self).post_to_connection(input)
+    }
+}
+impl<T: ApiGatewayManagementApi + ?Sized> ApiGatewayManagementApi for ::std::sync::Arc<T> {
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<(), DeleteConnectionError> {
+        (**self).delete_connection(input)
+    }
+    fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
+        (**self).get_connection(input)
+    }
+    fn post_to_connection(
+        &self,
+        input: PostToConnectionRequest,
+    ) -> RusotoFuture<(), PostToConnectionError> {
+        (**self).post_to_connection(input)
+    }
+}
 /// A client for the AmazonApiGatewayManagementApi API.
 #[derive(Clone)]
 pub struct ApiGatewayManagementApiClient {
@@ -287,6 +378,8 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteConnection");
+        request.add_resource_id("ConnectionId", &input.connection_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
@@ -318,12 +411,16 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetConnection");
+        request.add_resource_id("ConnectionId", &input.connection_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetConnection")
                         .deserialize::<GetConnectionResponse, _>()?;
 
                     Ok(result)
@@ -344,12 +441,22 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         &self,
         input: PostToConnectionRequest,
     ) -> RusotoFuture<(), PostToConnectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/@connections/{connection_id}",
             connection_id = input.connection_id
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("PostToConnection");
+        request.add_resource_id("ConnectionId", &input.connection_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.data.to_owned());
@@ -373,3 +480,120 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         })
     }
 }
+
+/// A mock of the AmazonApiGatewayManagementApi API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockApiGatewayManagementApi {
+    delete_connection: Option<
+        Box<
+            dyn Fn(
+                    DeleteConnectionRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteConnectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_connection: Option<
+        Box<
+            dyn Fn(
+                    GetConnectionRequest,
+                )
+                    -> ::std::result::Result<GetConnectionResponse, RusotoError<GetConnectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    post_to_connection: Option<
+        Box<
+            dyn Fn(
+                    PostToConnectionRequest,
+                ) -> ::std::result::Result<(), RusotoError<PostToConnectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockApiGatewayManagementApi {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockApiGatewayManagementApi {
+        Default::default()
+    }
+
+    /// Answers `delete_connection` with what `handler` returns for its input.
+    pub fn with_delete_connection<F>(mut self, handler: F) -> MockApiGatewayManagementApi
+    where
+        F: Fn(
+                DeleteConnectionRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteConnectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_connection` with what `handler` returns for its input.
+    pub fn with_get_connection<F>(mut self, handler: F) -> MockApiGatewayManagementApi
+    where
+        F: Fn(
+                GetConnectionRequest,
+            )
+                -> ::std::result::Result<GetConnectionResponse, RusotoError<GetConnectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `post_to_connection` with what `handler` returns for its input.
+    pub fn with_post_to_connection<F>(mut self, handler: F) -> MockApiGatewayManagementApi
+    where
+        F: Fn(
+                PostToConnectionRequest,
+            ) -> ::std::result::Result<(), RusotoError<PostToConnectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.post_to_connection = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ApiGatewayManagementApi for MockApiGatewayManagementApi {
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<(), DeleteConnectionError> {
+        match self.delete_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayManagementApi has no handler for delete_connection"),
+        }
+    }
+    fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
+        match self.get_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayManagementApi has no handler for get_connection"),
+        }
+    }
+    fn post_to_connection(
+        &self,
+        input: PostToConnectionRequest,
+    ) -> RusotoFuture<(), PostToConnectionError> {
+        match self.post_to_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayManagementApi has no handler for post_to_connection"),
+        }
+    }
+}
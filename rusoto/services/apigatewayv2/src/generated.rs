@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Settings for logging access in a stage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -40,7 +41,7 @@ pub struct AccessLogSettings {
 
 /// <p>Represents an API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Api {
     /// <p>The URI of the API, of the form {api-id}.execute-api.{region}.amazonaws.com. The
     /// stage name is typically appended to this URI to form a complete path to a deployed
@@ -94,7 +95,7 @@ pub struct Api {
 
 /// <p>Represents an API mapping.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApiMapping {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -114,7 +115,7 @@ pub struct ApiMapping {
 
 /// <p>Represents an authorizer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Authorizer {
     /// <p>Specifies the required credentials as an IAM role for API Gateway to invoke the
     /// authorizer. To specify an IAM role for API Gateway to assume, use the role's Amazon
@@ -187,6 +188,7 @@ pub struct Authorizer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApiMappingRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -204,7 +206,7 @@ pub struct CreateApiMappingRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateApiMappingResponse {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -225,6 +227,7 @@ pub struct CreateApiMappingResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApiRequest {
     /// <p>An API key selection expression. See <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-websocket-api-selection-expressions.html#apigateway-websocket-api-apikey-selection-expressions">API Key Selection Expressions</a>.</p>
     #[serde(rename = "ApiKeySelectionExpression")]
@@ -258,7 +261,7 @@ pub struct CreateApiRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateApiResponse {
     /// <p>The URI of the API, of the form {api-id}.execute-api.{region}.amazonaws.com. The
     /// stage name is typically appended to this URI to form a complete path to a deployed
@@ -314,6 +317,7 @@ pub struct CreateApiResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAuthorizerRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -381,8 +385,21 @@ pub struct CreateAuthorizerRequest {
     pub provider_arns: Option<Vec<String>>,
 }
 
+impl Validate for CreateAuthorizerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authorizer_result_ttl_in_seconds {
+            errors.check_range(
+                &field_path(path, "authorizer_result_ttl_in_seconds"),
+                *value,
+                None,
+                Some(3600),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAuthorizerResponse {
     /// <p>Specifies the required credentials as an IAM role for API Gateway to invoke the
     /// authorizer. To specify an IAM role for API Gateway to assume, use the role's Amazon
@@ -456,6 +473,7 @@ pub struct CreateAuthorizerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeploymentRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -472,7 +490,7 @@ pub struct CreateDeploymentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDeploymentResponse {
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
@@ -498,6 +516,7 @@ pub struct CreateDeploymentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDomainNameRequest {
     /// <p>The domain name.</p>
     #[serde(rename = "DomainName")]
@@ -513,7 +532,7 @@ pub struct CreateDomainNameRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDomainNameResponse {
     /// <p>The API mapping selection expression.</p>
     #[serde(rename = "ApiMappingSelectionExpression")]
@@ -534,6 +553,7 @@ pub struct CreateDomainNameResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateIntegrationRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -648,8 +668,21 @@ pub struct CreateIntegrationRequest {
     pub timeout_in_millis: Option<i64>,
 }
 
+impl Validate for CreateIntegrationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.timeout_in_millis {
+            errors.check_range(
+                &field_path(path, "timeout_in_millis"),
+                *value,
+                Some(50),
+                Some(30000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateIntegrationResponse {
     /// <p>The connection ID.</p>
     #[serde(rename = "ConnectionId")]
@@ -771,6 +804,7 @@ pub struct CreateIntegrationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateIntegrationResponseRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -820,7 +854,7 @@ pub struct CreateIntegrationResponseRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateIntegrationResponseResponse {
     /// <p>Specifies how to handle response payload content type conversions. Supported
     /// values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the
@@ -867,6 +901,7 @@ pub struct CreateIntegrationResponseResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateModelRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -889,7 +924,7 @@ pub struct CreateModelRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateModelResponse {
     /// <p>The content-type for the model, for example, "application/json".</p>
     #[serde(rename = "ContentType")]
@@ -915,6 +950,7 @@ pub struct CreateModelResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRouteRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -972,7 +1008,7 @@ pub struct CreateRouteRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRouteResponse {
     /// <p>Specifies whether an API key is required for this route.</p>
     #[serde(rename = "ApiKeyRequired")]
@@ -1037,6 +1073,7 @@ pub struct CreateRouteResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRouteResponseRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1062,7 +1099,7 @@ pub struct CreateRouteResponseRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRouteResponseResponse {
     /// <p>Represents the model selection expression of a route response.</p>
     #[serde(rename = "ModelSelectionExpression")]
@@ -1087,6 +1124,7 @@ pub struct CreateRouteResponseResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStageRequest {
     /// <p>Settings for logging access in this stage.</p>
     #[serde(rename = "AccessLogSettings")]
@@ -1131,7 +1169,7 @@ pub struct CreateStageRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateStageResponse {
     /// <p>Settings for logging access in this stage.</p>
     #[serde(rename = "AccessLogSettings")]
@@ -1183,6 +1221,7 @@ pub struct CreateStageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApiMappingRequest {
     /// <p>The API mapping identifier.</p>
     #[serde(rename = "ApiMappingId")]
@@ -1193,6 +1232,7 @@ pub struct DeleteApiMappingRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApiRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1200,6 +1240,7 @@ pub struct DeleteApiRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAuthorizerRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1210,6 +1251,7 @@ pub struct DeleteAuthorizerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDeploymentRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1220,6 +1262,7 @@ pub struct DeleteDeploymentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDomainNameRequest {
     /// <p>The domain name.</p>
     #[serde(rename = "DomainName")]
@@ -1227,6 +1270,7 @@ pub struct DeleteDomainNameRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIntegrationRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1237,6 +1281,7 @@ pub struct DeleteIntegrationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIntegrationResponseRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1250,6 +1295,7 @@ pub struct DeleteIntegrationResponseRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteModelRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1260,6 +1306,7 @@ pub struct DeleteModelRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRouteRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1270,6 +1317,7 @@ pub struct DeleteRouteRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRouteResponseRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1283,6 +1331,7 @@ pub struct DeleteRouteResponseRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStageRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1296,7 +1345,7 @@ pub struct DeleteStageRequest {
 /// Deployment must be associated with a Stage for it to be
 /// callable over the internet.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Deployment {
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
@@ -1323,7 +1372,7 @@ pub struct Deployment {
 
 /// <p>Represents a domain name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainName {
     /// <p>The API mapping selection expression.</p>
     #[serde(rename = "ApiMappingSelectionExpression")]
@@ -1387,6 +1436,7 @@ pub struct DomainNameConfiguration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetApiMappingRequest {
     /// <p>The API mapping identifier.</p>
     #[serde(rename = "ApiMappingId")]
@@ -1397,7 +1447,7 @@ pub struct GetApiMappingRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetApiMappingResponse {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1418,6 +1468,7 @@ pub struct GetApiMappingResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetApiMappingsRequest {
     /// <p>The domain name.</p>
     #[serde(rename = "DomainName")]
@@ -1434,7 +1485,7 @@ pub struct GetApiMappingsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetApiMappingsResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -1448,6 +1499,7 @@ pub struct GetApiMappingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetApiRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1455,7 +1507,7 @@ pub struct GetApiRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetApiResponse {
     /// <p>The URI of the API, of the form {api-id}.execute-api.{region}.amazonaws.com. The
     /// stage name is typically appended to this URI to form a complete path to a deployed
@@ -1511,6 +1563,7 @@ pub struct GetApiResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetApisRequest {
     /// <p>The maximum number of elements to be returned for this resource.</p>
     #[serde(rename = "MaxResults")]
@@ -1524,7 +1577,7 @@ pub struct GetApisRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetApisResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -1538,6 +1591,7 @@ pub struct GetApisResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAuthorizerRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1548,7 +1602,7 @@ pub struct GetAuthorizerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAuthorizerResponse {
     /// <p>Specifies the required credentials as an IAM role for API Gateway to invoke the
     /// authorizer. To specify an IAM role for API Gateway to assume, use the role's Amazon
@@ -1622,6 +1676,7 @@ pub struct GetAuthorizerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAuthorizersRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1638,7 +1693,7 @@ pub struct GetAuthorizersRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAuthorizersResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -1652,6 +1707,7 @@ pub struct GetAuthorizersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeploymentRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1662,7 +1718,7 @@ pub struct GetDeploymentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeploymentResponse {
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
@@ -1688,6 +1744,7 @@ pub struct GetDeploymentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeploymentsRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1704,7 +1761,7 @@ pub struct GetDeploymentsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeploymentsResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -1718,6 +1775,7 @@ pub struct GetDeploymentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDomainNameRequest {
     /// <p>The domain name.</p>
     #[serde(rename = "DomainName")]
@@ -1725,7 +1783,7 @@ pub struct GetDomainNameRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDomainNameResponse {
     /// <p>The API mapping selection expression.</p>
     #[serde(rename = "ApiMappingSelectionExpression")]
@@ -1746,6 +1804,7 @@ pub struct GetDomainNameResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDomainNamesRequest {
     /// <p>The maximum number of elements to be returned for this resource.</p>
     #[serde(rename = "MaxResults")]
@@ -1759,7 +1818,7 @@ pub struct GetDomainNamesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDomainNamesResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -1773,6 +1832,7 @@ pub struct GetDomainNamesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIntegrationRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1783,7 +1843,7 @@ pub struct GetIntegrationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIntegrationResponse {
     /// <p>The connection ID.</p>
     #[serde(rename = "ConnectionId")]
@@ -1905,6 +1965,7 @@ pub struct GetIntegrationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIntegrationResponseRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1918,7 +1979,7 @@ pub struct GetIntegrationResponseRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIntegrationResponseResponse {
     /// <p>Specifies how to handle response payload content type conversions. Supported
     /// values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the
@@ -1965,6 +2026,7 @@ pub struct GetIntegrationResponseResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIntegrationResponsesRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -1984,7 +2046,7 @@ pub struct GetIntegrationResponsesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIntegrationResponsesResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -1998,6 +2060,7 @@ pub struct GetIntegrationResponsesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIntegrationsRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2014,7 +2077,7 @@ pub struct GetIntegrationsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIntegrationsResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -2028,6 +2091,7 @@ pub struct GetIntegrationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetModelRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2038,7 +2102,7 @@ pub struct GetModelRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetModelResponse {
     /// <p>The content-type for the model, for example, "application/json".</p>
     #[serde(rename = "ContentType")]
@@ -2064,6 +2128,7 @@ pub struct GetModelResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetModelTemplateRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2074,7 +2139,7 @@ pub struct GetModelTemplateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetModelTemplateResponse {
     /// <p>The template value.</p>
     #[serde(rename = "Value")]
@@ -2083,6 +2148,7 @@ pub struct GetModelTemplateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetModelsRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2099,7 +2165,7 @@ pub struct GetModelsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetModelsResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -2113,6 +2179,7 @@ pub struct GetModelsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRouteRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2123,7 +2190,7 @@ pub struct GetRouteRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRouteResponse {
     /// <p>Specifies whether an API key is required for this route.</p>
     #[serde(rename = "ApiKeyRequired")]
@@ -2188,6 +2255,7 @@ pub struct GetRouteResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRouteResponseRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2201,7 +2269,7 @@ pub struct GetRouteResponseRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRouteResponseResponse {
     /// <p>Represents the model selection expression of a route response.</p>
     #[serde(rename = "ModelSelectionExpression")]
@@ -2226,6 +2294,7 @@ pub struct GetRouteResponseResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRouteResponsesRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2245,7 +2314,7 @@ pub struct GetRouteResponsesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRouteResponsesResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -2259,6 +2328,7 @@ pub struct GetRouteResponsesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRoutesRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2275,7 +2345,7 @@ pub struct GetRoutesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRoutesResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -2289,6 +2359,7 @@ pub struct GetRoutesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStageRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2299,7 +2370,7 @@ pub struct GetStageRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetStageResponse {
     /// <p>Settings for logging access in this stage.</p>
     #[serde(rename = "AccessLogSettings")]
@@ -2351,6 +2422,7 @@ pub struct GetStageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStagesRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2367,7 +2439,7 @@ pub struct GetStagesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetStagesResponse {
     /// <p>The elements from this collection.</p>
     #[serde(rename = "Items")]
@@ -2381,13 +2453,14 @@ pub struct GetStagesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTagsRequest {
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTagsResponse {
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2396,7 +2469,7 @@ pub struct GetTagsResponse {
 
 /// <p>Represents an integration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Integration {
     /// <p>The connection ID.</p>
     #[serde(rename = "ConnectionId")]
@@ -2519,7 +2592,7 @@ pub struct Integration {
 
 /// <p>Represents an integration response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IntegrationResponse {
     /// <p>Specifies how to handle response payload content type conversions. Supported
     /// values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the
@@ -2567,7 +2640,7 @@ pub struct IntegrationResponse {
 /// <p>Represents a data model for an API. See <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/models-mappings.html">Create Models and Mapping Templates for Request and Response
 /// Mappings</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Model {
     /// <p>The content-type for the model, for example, "application/json".</p>
     #[serde(rename = "ContentType")]
@@ -2603,7 +2676,7 @@ pub struct ParameterConstraints {
 
 /// <p>Represents a route.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Route {
     /// <p>Specifies whether an API key is required for this route.</p>
     #[serde(rename = "ApiKeyRequired")]
@@ -2668,7 +2741,7 @@ pub struct Route {
 
 /// <p>Represents a route response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RouteResponse {
     /// <p>Represents the model selection expression of a route response.</p>
     #[serde(rename = "ModelSelectionExpression")]
@@ -2722,7 +2795,7 @@ pub struct RouteSettings {
 
 /// <p>Represents an API stage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Stage {
     /// <p>Settings for logging access in this stage.</p>
     #[serde(rename = "AccessLogSettings")]
@@ -2773,6 +2846,7 @@ pub struct Stage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>AWS resource arn </p>
     #[serde(rename = "ResourceArn")]
@@ -2784,10 +2858,11 @@ pub struct TagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>AWS resource arn </p>
     #[serde(rename = "ResourceArn")]
@@ -2798,6 +2873,7 @@ pub struct UntagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApiMappingRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2819,7 +2895,7 @@ pub struct UpdateApiMappingRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateApiMappingResponse {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2840,6 +2916,7 @@ pub struct UpdateApiMappingResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApiRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -2871,7 +2948,7 @@ pub struct UpdateApiRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateApiResponse {
     /// <p>The URI of the API, of the form {api-id}.execute-api.{region}.amazonaws.com. The
     /// stage name is typically appended to this URI to form a complete path to a deployed
@@ -2927,6 +3004,7 @@ pub struct UpdateApiResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAuthorizerRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -3000,8 +3078,21 @@ pub struct UpdateAuthorizerRequest {
     pub provider_arns: Option<Vec<String>>,
 }
 
+impl Validate for UpdateAuthorizerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authorizer_result_ttl_in_seconds {
+            errors.check_range(
+                &field_path(path, "authorizer_result_ttl_in_seconds"),
+                *value,
+                None,
+                Some(3600),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAuthorizerResponse {
     /// <p>Specifies the required credentials as an IAM role for API Gateway to invoke the
     /// authorizer. To specify an IAM role for API Gateway to assume, use the role's Amazon
@@ -3075,6 +3166,7 @@ pub struct UpdateAuthorizerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDeploymentRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -3089,7 +3181,7 @@ pub struct UpdateDeploymentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDeploymentResponse {
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
@@ -3115,6 +3207,7 @@ pub struct UpdateDeploymentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDomainNameRequest {
     /// <p>The domain name.</p>
     #[serde(rename = "DomainName")]
@@ -3126,7 +3219,7 @@ pub struct UpdateDomainNameRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDomainNameResponse {
     /// <p>The API mapping selection expression.</p>
     #[serde(rename = "ApiMappingSelectionExpression")]
@@ -3147,6 +3240,7 @@ pub struct UpdateDomainNameResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateIntegrationRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -3265,8 +3359,21 @@ pub struct UpdateIntegrationRequest {
     pub timeout_in_millis: Option<i64>,
 }
 
+impl Validate for UpdateIntegrationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.timeout_in_millis {
+            errors.check_range(
+                &field_path(path, "timeout_in_millis"),
+                *value,
+                Some(50),
+                Some(30000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateIntegrationResponse {
     /// <p>The connection ID.</p>
     #[serde(rename = "ConnectionId")]
@@ -3388,6 +3495,7 @@ pub struct UpdateIntegrationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateIntegrationResponseRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -3446,7 +3554,7 @@ pub struct UpdateIntegrationResponseRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateIntegrationResponseResponse {
     /// <p>Specifies how to handle response payload content type conversions. Supported
     /// values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the
@@ -3493,6 +3601,7 @@ pub struct UpdateIntegrationResponseResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateModelRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -3520,7 +3629,7 @@ pub struct UpdateModelRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateModelResponse {
     /// <p>The content-type for the model, for example, "application/json".</p>
     #[serde(rename = "ContentType")]
@@ -3546,6 +3655,7 @@ pub struct UpdateModelResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRouteRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -3607,7 +3717,7 @@ pub struct UpdateRouteRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRouteResponse {
     /// <p>Specifies whether an API key is required for this route.</p>
     #[serde(rename = "ApiKeyRequired")]
@@ -3672,6 +3782,7 @@ pub struct UpdateRouteResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRouteResponseRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -3701,7 +3812,7 @@ pub struct UpdateRouteResponseRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRouteResponseResponse {
     /// <p>Represents the model selection expression of a route response.</p>
     #[serde(rename = "ModelSelectionExpression")]
@@ -3726,6 +3837,7 @@ pub struct UpdateRouteResponseResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStageRequest {
     /// <p>Settings for logging access in this stage.</p>
     #[serde(rename = "AccessLogSettings")]
@@ -3766,7 +3878,7 @@ pub struct UpdateStageRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateStageResponse {
     /// <p>Settings for logging access in this stage.</p>
     #[serde(rename = "AccessLogSettings")]
@@ -3850,7 +3962,12 @@ impl CreateApiError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateApiError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3905,7 +4022,12 @@ impl CreateApiMappingError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateApiMappingError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3960,7 +4082,12 @@ impl CreateAuthorizerError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateAuthorizerError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4015,7 +4142,12 @@ impl CreateDeploymentError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateDeploymentError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4070,7 +4202,12 @@ impl CreateDomainNameError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateDomainNameError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4125,7 +4262,12 @@ impl CreateIntegrationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateIntegrationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4184,7 +4326,12 @@ impl CreateIntegrationResponseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4239,7 +4386,12 @@ impl CreateModelError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateModelError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4294,7 +4446,12 @@ impl CreateRouteError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateRouteError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4349,7 +4506,12 @@ impl CreateRouteResponseError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateRouteResponseError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4404,7 +4566,12 @@ impl CreateStageError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateStageError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4446,7 +4613,12 @@ impl DeleteApiError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteApiError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4492,7 +4664,12 @@ impl DeleteApiMappingError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteApiMappingError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4533,7 +4710,12 @@ impl DeleteAuthorizerError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteAuthorizerError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4573,7 +4755,12 @@ impl DeleteDeploymentError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteDeploymentError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4613,7 +4800,12 @@ impl DeleteDomainNameError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteDomainNameError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4653,7 +4845,12 @@ impl DeleteIntegrationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteIntegrationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4695,7 +4892,12 @@ impl DeleteIntegrationResponseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4735,7 +4937,12 @@ impl DeleteModelError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteModelError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4775,7 +4982,12 @@ impl DeleteRouteError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteRouteError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4815,7 +5027,12 @@ impl DeleteRouteResponseError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteRouteResponseError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4855,7 +5072,12 @@ impl DeleteStageError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteStageError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4893,7 +5115,12 @@ impl GetApiError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetApiError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4939,7 +5166,12 @@ impl GetApiMappingError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetApiMappingError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4986,7 +5218,12 @@ impl GetApiMappingsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetApiMappingsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5033,7 +5270,12 @@ impl GetApisError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetApisError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5074,7 +5316,12 @@ impl GetAuthorizerError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetAuthorizerError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5120,7 +5367,12 @@ impl GetAuthorizersError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetAuthorizersError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5161,7 +5413,12 @@ impl GetDeploymentError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetDeploymentError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5207,7 +5464,12 @@ impl GetDeploymentsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetDeploymentsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5248,7 +5510,12 @@ impl GetDomainNameError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetDomainNameError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5294,7 +5561,12 @@ impl GetDomainNamesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetDomainNamesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5335,7 +5607,12 @@ impl GetIntegrationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetIntegrationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5377,7 +5654,12 @@ impl GetIntegrationResponseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5425,7 +5707,12 @@ impl GetIntegrationResponsesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5472,7 +5759,12 @@ impl GetIntegrationsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetIntegrationsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5513,7 +5805,12 @@ impl GetModelError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetModelError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5553,7 +5850,12 @@ impl GetModelTemplateError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetModelTemplateError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5599,7 +5901,12 @@ impl GetModelsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetModelsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5640,7 +5947,12 @@ impl GetRouteError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetRouteError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5680,7 +5992,12 @@ impl GetRouteResponseError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetRouteResponseError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5726,7 +6043,12 @@ impl GetRouteResponsesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetRouteResponsesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5773,7 +6095,12 @@ impl GetRoutesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetRoutesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5814,7 +6141,12 @@ impl GetStageError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetStageError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5860,7 +6192,12 @@ impl GetStagesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetStagesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5914,7 +6251,12 @@ impl GetTagsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5969,7 +6311,12 @@ impl TagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6024,7 +6371,12 @@ impl UntagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6079,7 +6431,12 @@ impl UpdateApiError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateApiError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6134,7 +6491,12 @@ impl UpdateApiMappingError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateApiMappingError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6189,7 +6551,12 @@ impl UpdateAuthorizerError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateAuthorizerError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6244,7 +6611,12 @@ impl UpdateDeploymentError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateDeploymentError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6299,7 +6671,12 @@ impl UpdateDomainNameError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateDomainNameError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6354,7 +6731,12 @@ impl UpdateIntegrationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateIntegrationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6413,7 +6795,12 @@ impl UpdateIntegrationResponseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6468,7 +6855,12 @@ impl UpdateModelError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateModelError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6523,7 +6915,12 @@ impl UpdateRouteError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateRouteError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6578,7 +6975,12 @@ impl UpdateRouteResponseError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateRouteResponseError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6633,7 +7035,12 @@ impl UpdateStageError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateStageError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6978,1999 +7385,5177 @@ pub trait ApiGatewayV2 {
         input: UpdateStageRequest,
     ) -> RusotoFuture<UpdateStageResponse, UpdateStageError>;
 }
-/// A client for the AmazonApiGatewayV2 API.
-#[derive(Clone)]
-pub struct ApiGatewayV2Client {
-    client: Client,
-    region: region::Region,
-}
-
-impl ApiGatewayV2Client {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ApiGatewayV2Client {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ApiGatewayV2Client
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayV2Client {
-        ApiGatewayV2Client { client, region }
-    }
-}
-
-impl ApiGatewayV2 for ApiGatewayV2Client {
-    /// <p>Creates an Api resource.</p>
+const _: Option<&dyn ApiGatewayV2> = None;
+impl<'a, T: ApiGatewayV2 + ?Sized> ApiGatewayV2 for &'a T {
     fn create_api(
         &self,
         input: CreateApiRequest,
     ) -> RusotoFuture<CreateApiResponse, CreateApiError> {
-        let request_uri = "/v2/apis";
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateApiResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_api(input)
     }
-
-    /// <p>Creates an API mapping.</p>
     fn create_api_mapping(
         &self,
         input: CreateApiMappingRequest,
     ) -> RusotoFuture<CreateApiMappingResponse, CreateApiMappingError> {
-        let request_uri = format!(
-            "/v2/domainnames/{domain_name}/apimappings",
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateApiMappingResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateApiMappingError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_api_mapping(input)
     }
-
-    /// <p>Creates an Authorizer for an API.</p>
     fn create_authorizer(
         &self,
         input: CreateAuthorizerRequest,
     ) -> RusotoFuture<CreateAuthorizerResponse, CreateAuthorizerError> {
-        let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAuthorizerResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAuthorizerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_authorizer(input)
     }
-
-    /// <p>Creates a Deployment for an API.</p>
     fn create_deployment(
         &self,
         input: CreateDeploymentRequest,
     ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
-        let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDeploymentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_deployment(input)
     }
-
-    /// <p>Creates a domain name.</p>
     fn create_domain_name(
         &self,
         input: CreateDomainNameRequest,
     ) -> RusotoFuture<CreateDomainNameResponse, CreateDomainNameError> {
-        let request_uri = "/v2/domainnames";
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDomainNameResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDomainNameError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_domain_name(input)
     }
-
-    /// <p>Creates an Integration.</p>
     fn create_integration(
         &self,
         input: CreateIntegrationRequest,
     ) -> RusotoFuture<CreateIntegrationResponse, CreateIntegrationError> {
-        let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateIntegrationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateIntegrationError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_integration(input)
     }
-
-    /// <p>Creates an IntegrationResponses.</p>
     fn create_integration_response(
         &self,
         input: CreateIntegrationResponseRequest,
     ) -> RusotoFuture<CreateIntegrationResponseResponse, CreateIntegrationResponseError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses",
-            api_id = input.api_id,
-            integration_id = input.integration_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateIntegrationResponseResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateIntegrationResponseError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_integration_response(input)
     }
-
-    /// <p>Creates a Model for an API.</p>
     fn create_model(
         &self,
         input: CreateModelRequest,
     ) -> RusotoFuture<CreateModelResponse, CreateModelError> {
-        let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateModelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_model(input)
     }
-
-    /// <p>Creates a Route for an API.</p>
     fn create_route(
         &self,
         input: CreateRouteRequest,
     ) -> RusotoFuture<CreateRouteResponse, CreateRouteError> {
-        let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRouteResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateRouteError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_route(input)
     }
-
-    /// <p>Creates a RouteResponse for a Route.</p>
     fn create_route_response(
         &self,
         input: CreateRouteResponseRequest,
     ) -> RusotoFuture<CreateRouteResponseResponse, CreateRouteResponseError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/routes/{route_id}/routeresponses",
-            api_id = input.api_id,
-            route_id = input.route_id
-        );
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRouteResponseResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateRouteResponseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_route_response(input)
     }
-
-    /// <p>Creates a Stage for an API.</p>
     fn create_stage(
         &self,
         input: CreateStageRequest,
     ) -> RusotoFuture<CreateStageResponse, CreateStageError> {
-        let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateStageResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateStageError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_stage(input)
     }
-
-    /// <p>Deletes an Api resource.</p>
     fn delete_api(&self, input: DeleteApiRequest) -> RusotoFuture<(), DeleteApiError> {
-        let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_api(input)
     }
-
-    /// <p>Deletes an API mapping.</p>
     fn delete_api_mapping(
         &self,
         input: DeleteApiMappingRequest,
     ) -> RusotoFuture<(), DeleteApiMappingError> {
-        let request_uri = format!(
-            "/v2/domainnames/{domain_name}/apimappings/{api_mapping_id}",
-            api_mapping_id = input.api_mapping_id,
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteApiMappingError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_api_mapping(input)
     }
-
-    /// <p>Deletes an Authorizer.</p>
     fn delete_authorizer(
         &self,
         input: DeleteAuthorizerRequest,
     ) -> RusotoFuture<(), DeleteAuthorizerError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/authorizers/{authorizer_id}",
-            api_id = input.api_id,
-            authorizer_id = input.authorizer_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAuthorizerError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_authorizer(input)
     }
-
-    /// <p>Deletes a Deployment.</p>
     fn delete_deployment(
         &self,
         input: DeleteDeploymentRequest,
     ) -> RusotoFuture<(), DeleteDeploymentError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/deployments/{deployment_id}",
-            api_id = input.api_id,
-            deployment_id = input.deployment_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_deployment(input)
     }
-
-    /// <p>Deletes a domain name.</p>
     fn delete_domain_name(
         &self,
         input: DeleteDomainNameRequest,
     ) -> RusotoFuture<(), DeleteDomainNameError> {
-        let request_uri = format!(
-            "/v2/domainnames/{domain_name}",
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDomainNameError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_domain_name(input)
     }
-
-    /// <p>Deletes an Integration.</p>
     fn delete_integration(
         &self,
         input: DeleteIntegrationRequest,
     ) -> RusotoFuture<(), DeleteIntegrationError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/integrations/{integration_id}",
-            api_id = input.api_id,
-            integration_id = input.integration_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteIntegrationError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_integration(input)
     }
-
-    /// <p>Deletes an IntegrationResponses.</p>
     fn delete_integration_response(
         &self,
         input: DeleteIntegrationResponseRequest,
     ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
-        let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteIntegrationResponseError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_integration_response(input)
     }
-
-    /// <p>Deletes a Model.</p>
     fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/models/{model_id}",
-            api_id = input.api_id,
-            model_id = input.model_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_model(input)
     }
-
-    /// <p>Deletes a Route.</p>
     fn delete_route(&self, input: DeleteRouteRequest) -> RusotoFuture<(), DeleteRouteError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/routes/{route_id}",
-            api_id = input.api_id,
-            route_id = input.route_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteRouteError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_route(input)
     }
-
-    /// <p>Deletes a RouteResponse.</p>
     fn delete_route_response(
         &self,
         input: DeleteRouteResponseRequest,
     ) -> RusotoFuture<(), DeleteRouteResponseError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/routes/{route_id}/routeresponses/{route_response_id}",
-            api_id = input.api_id,
-            route_id = input.route_id,
-            route_response_id = input.route_response_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteRouteResponseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_route_response(input)
     }
-
-    /// <p>Deletes a Stage.</p>
     fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/stages/{stage_name}",
-            api_id = input.api_id,
-            stage_name = input.stage_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteStageError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_stage(input)
     }
-
-    /// <p>Gets an Api resource.</p>
     fn get_api(&self, input: GetApiRequest) -> RusotoFuture<GetApiResponse, GetApiError> {
-        let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetApiResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_api(input)
     }
-
-    /// <p>The API mapping.</p>
     fn get_api_mapping(
         &self,
         input: GetApiMappingRequest,
     ) -> RusotoFuture<GetApiMappingResponse, GetApiMappingError> {
-        let request_uri = format!(
-            "/v2/domainnames/{domain_name}/apimappings/{api_mapping_id}",
-            api_mapping_id = input.api_mapping_id,
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetApiMappingResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetApiMappingError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_api_mapping(input)
     }
-
-    /// <p>The API mappings.</p>
     fn get_api_mappings(
         &self,
         input: GetApiMappingsRequest,
     ) -> RusotoFuture<GetApiMappingsResponse, GetApiMappingsError> {
-        let request_uri = format!(
-            "/v2/domainnames/{domain_name}/apimappings",
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetApiMappingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetApiMappingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_api_mappings(input)
     }
-
-    /// <p>Gets a collection of Api resources.</p>
     fn get_apis(&self, input: GetApisRequest) -> RusotoFuture<GetApisResponse, GetApisError> {
-        let request_uri = "/v2/apis";
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetApisResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetApisError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_apis(input)
     }
-
-    /// <p>Gets an Authorizer.</p>
     fn get_authorizer(
         &self,
         input: GetAuthorizerRequest,
     ) -> RusotoFuture<GetAuthorizerResponse, GetAuthorizerError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/authorizers/{authorizer_id}",
-            api_id = input.api_id,
-            authorizer_id = input.authorizer_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAuthorizerResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAuthorizerError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_authorizer(input)
     }
-
-    /// <p>Gets the Authorizers for an API.</p>
     fn get_authorizers(
         &self,
         input: GetAuthorizersRequest,
     ) -> RusotoFuture<GetAuthorizersResponse, GetAuthorizersError> {
-        let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAuthorizersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAuthorizersError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_authorizers(input)
     }
-
-    /// <p>Gets a Deployment.</p>
     fn get_deployment(
         &self,
         input: GetDeploymentRequest,
     ) -> RusotoFuture<GetDeploymentResponse, GetDeploymentError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/deployments/{deployment_id}",
-            api_id = input.api_id,
-            deployment_id = input.deployment_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeploymentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_deployment(input)
     }
-
-    /// <p>Gets the Deployments for an API.</p>
     fn get_deployments(
         &self,
         input: GetDeploymentsRequest,
     ) -> RusotoFuture<GetDeploymentsResponse, GetDeploymentsError> {
-        let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeploymentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDeploymentsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_deployments(input)
     }
-
-    /// <p>Gets a domain name.</p>
     fn get_domain_name(
         &self,
         input: GetDomainNameRequest,
     ) -> RusotoFuture<GetDomainNameResponse, GetDomainNameError> {
-        let request_uri = format!(
-            "/v2/domainnames/{domain_name}",
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDomainNameResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDomainNameError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_domain_name(input)
     }
-
-    /// <p>Gets the domain names for an AWS account.</p>
     fn get_domain_names(
         &self,
         input: GetDomainNamesRequest,
     ) -> RusotoFuture<GetDomainNamesResponse, GetDomainNamesError> {
-        let request_uri = "/v2/domainnames";
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDomainNamesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDomainNamesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_domain_names(input)
     }
-
-    /// <p>Gets an Integration.</p>
     fn get_integration(
         &self,
         input: GetIntegrationRequest,
     ) -> RusotoFuture<GetIntegrationResponse, GetIntegrationError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/integrations/{integration_id}",
-            api_id = input.api_id,
-            integration_id = input.integration_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIntegrationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetIntegrationError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_integration(input)
     }
-
-    /// <p>Gets an IntegrationResponses.</p>
     fn get_integration_response(
         &self,
         input: GetIntegrationResponseRequest,
     ) -> RusotoFuture<GetIntegrationResponseResponse, GetIntegrationResponseError> {
-        let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIntegrationResponseResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetIntegrationResponseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_integration_response(input)
     }
-
-    /// <p>Gets the IntegrationResponses for an Integration.</p>
     fn get_integration_responses(
         &self,
         input: GetIntegrationResponsesRequest,
     ) -> RusotoFuture<GetIntegrationResponsesResponse, GetIntegrationResponsesError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses",
-            api_id = input.api_id,
-            integration_id = input.integration_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIntegrationResponsesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetIntegrationResponsesError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_integration_responses(input)
     }
-
-    /// <p>Gets the Integrations for an API.</p>
     fn get_integrations(
         &self,
         input: GetIntegrationsRequest,
     ) -> RusotoFuture<GetIntegrationsResponse, GetIntegrationsError> {
-        let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIntegrationsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetIntegrationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_integrations(input)
     }
-
-    /// <p>Gets a Model.</p>
     fn get_model(&self, input: GetModelRequest) -> RusotoFuture<GetModelResponse, GetModelError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/models/{model_id}",
-            api_id = input.api_id,
-            model_id = input.model_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetModelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_model(input)
     }
-
-    /// <p>Gets a model template.</p>
     fn get_model_template(
         &self,
         input: GetModelTemplateRequest,
     ) -> RusotoFuture<GetModelTemplateResponse, GetModelTemplateError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/models/{model_id}/template",
-            api_id = input.api_id,
-            model_id = input.model_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetModelTemplateResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetModelTemplateError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_model_template(input)
     }
-
-    /// <p>Gets the Models for an API.</p>
     fn get_models(
         &self,
         input: GetModelsRequest,
     ) -> RusotoFuture<GetModelsResponse, GetModelsError> {
-        let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetModelsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetModelsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_models(input)
     }
-
-    /// <p>Gets a Route.</p>
     fn get_route(&self, input: GetRouteRequest) -> RusotoFuture<GetRouteResponse, GetRouteError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/routes/{route_id}",
-            api_id = input.api_id,
-            route_id = input.route_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRouteResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRouteError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_route(input)
     }
-
-    /// <p>Gets a RouteResponse.</p>
     fn get_route_response(
         &self,
         input: GetRouteResponseRequest,
     ) -> RusotoFuture<GetRouteResponseResponse, GetRouteResponseError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/routes/{route_id}/routeresponses/{route_response_id}",
-            api_id = input.api_id,
-            route_id = input.route_id,
-            route_response_id = input.route_response_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRouteResponseResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRouteResponseError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_route_response(input)
     }
-
-    /// <p>Gets the RouteResponses for a Route.</p>
     fn get_route_responses(
         &self,
         input: GetRouteResponsesRequest,
     ) -> RusotoFuture<GetRouteResponsesResponse, GetRouteResponsesError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/routes/{route_id}/routeresponses",
-            api_id = input.api_id,
-            route_id = input.route_id
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRouteResponsesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRouteResponsesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_route_responses(input)
     }
-
-    /// <p>Gets the Routes for an API.</p>
     fn get_routes(
         &self,
         input: GetRoutesRequest,
     ) -> RusotoFuture<GetRoutesResponse, GetRoutesError> {
-        let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRoutesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRoutesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_routes(input)
     }
-
-    /// <p>Gets a Stage.</p>
     fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<GetStageResponse, GetStageError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/stages/{stage_name}",
-            api_id = input.api_id,
-            stage_name = input.stage_name
-        );
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetStageResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetStageError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_stage(input)
     }
-
-    /// <p>Gets the Stages for an API.</p>
     fn get_stages(
         &self,
         input: GetStagesRequest,
     ) -> RusotoFuture<GetStagesResponse, GetStagesError> {
-        let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetStagesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetStagesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_stages(input)
     }
-
-    /// <p>Gets the Tags for an API.</p>
     fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
-        let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTagsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_tags(input)
     }
-
-    /// <p>Tag an APIGW resource</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).tag_resource(input)
     }
-
-    /// <p>Untag an APIGW resource</p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
-        let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).untag_resource(input)
     }
-
-    /// <p>Updates an Api resource.</p>
     fn update_api(
         &self,
         input: UpdateApiRequest,
     ) -> RusotoFuture<UpdateApiResponse, UpdateApiError> {
-        let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateApiResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_api(input)
     }
-
-    /// <p>The API mapping.</p>
     fn update_api_mapping(
         &self,
         input: UpdateApiMappingRequest,
     ) -> RusotoFuture<UpdateApiMappingResponse, UpdateApiMappingError> {
-        let request_uri = format!(
-            "/v2/domainnames/{domain_name}/apimappings/{api_mapping_id}",
-            api_mapping_id = input.api_mapping_id,
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateApiMappingResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateApiMappingError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_api_mapping(input)
     }
-
-    /// <p>Updates an Authorizer.</p>
     fn update_authorizer(
         &self,
         input: UpdateAuthorizerRequest,
     ) -> RusotoFuture<UpdateAuthorizerResponse, UpdateAuthorizerError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/authorizers/{authorizer_id}",
-            api_id = input.api_id,
-            authorizer_id = input.authorizer_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAuthorizerResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAuthorizerError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_authorizer(input)
+    }
+    fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> RusotoFuture<UpdateDeploymentResponse, UpdateDeploymentError> {
+        (**self).update_deployment(input)
+    }
+    fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> RusotoFuture<UpdateDomainNameResponse, UpdateDomainNameError> {
+        (**self).update_domain_name(input)
+    }
+    fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> RusotoFuture<UpdateIntegrationResponse, UpdateIntegrationError> {
+        (**self).update_integration(input)
+    }
+    fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> RusotoFuture<UpdateIntegrationResponseResponse, UpdateIntegrationResponseError> {
+        (**self).update_integration_response(input)
+    }
+    fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> RusotoFuture<UpdateModelResponse, UpdateModelError> {
+        (**self).update_model(input)
+    }
+    fn update_route(
+        &self,
+        input: UpdateRouteRequest,
+    ) -> RusotoFuture<UpdateRouteResponse, UpdateRouteError> {
+        (**self).update_route(input)
+    }
+    fn update_route_response(
+        &self,
+        input: UpdateRouteResponseRequest,
+    ) -> RusotoFuture<UpdateRouteResponseResponse, UpdateRouteResponseError> {
+        (**self).update_route_response(input)
+    }
+    fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> RusotoFuture<UpdateStageResponse, UpdateStageError> {
+        (**self).update_stage(input)
+    }
+}
+impl<T: ApiGatewayV2 + ?Sized> ApiGatewayV2 for Box<T> {
+    fn create_api(
+        &self,
+        input: CreateApiRequest,
+    ) -> RusotoFuture<CreateApiResponse, CreateApiError> {
+        (**self).create_api(input)
+    }
+    fn create_api_mapping(
+        &self,
+        input: CreateApiMappingRequest,
+    ) -> RusotoFuture<CreateApiMappingResponse, CreateApiMappingError> {
+        (**self).create_api_mapping(input)
+    }
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<CreateAuthorizerResponse, CreateAuthorizerError> {
+        (**self).create_authorizer(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> RusotoFuture<CreateDomainNameResponse, CreateDomainNameError> {
+        (**self).create_domain_name(input)
+    }
+    fn create_integration(
+        &self,
+        input: CreateIntegrationRequest,
+    ) -> RusotoFuture<CreateIntegrationResponse, CreateIntegrationError> {
+        (**self).create_integration(input)
+    }
+    fn create_integration_response(
+        &self,
+        input: CreateIntegrationResponseRequest,
+    ) -> RusotoFuture<CreateIntegrationResponseResponse, CreateIntegrationResponseError> {
+        (**self).create_integration_response(input)
+    }
+    fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> RusotoFuture<CreateModelResponse, CreateModelError> {
+        (**self).create_model(input)
+    }
+    fn create_route(
+        &self,
+        input: CreateRouteRequest,
+    ) -> RusotoFuture<CreateRouteResponse, CreateRouteError> {
+        (**self).create_route(input)
+    }
+    fn create_route_response(
+        &self,
+        input: CreateRouteResponseRequest,
+    ) -> RusotoFuture<CreateRouteResponseResponse, CreateRouteResponseError> {
+        (**self).create_route_response(input)
+    }
+    fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> RusotoFuture<CreateStageResponse, CreateStageError> {
+        (**self).create_stage(input)
+    }
+    fn delete_api(&self, input: DeleteApiRequest) -> RusotoFuture<(), DeleteApiError> {
+        (**self).delete_api(input)
+    }
+    fn delete_api_mapping(
+        &self,
+        input: DeleteApiMappingRequest,
+    ) -> RusotoFuture<(), DeleteApiMappingError> {
+        (**self).delete_api_mapping(input)
+    }
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<(), DeleteAuthorizerError> {
+        (**self).delete_authorizer(input)
+    }
+    fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> RusotoFuture<(), DeleteDeploymentError> {
+        (**self).delete_deployment(input)
+    }
+    fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> RusotoFuture<(), DeleteDomainNameError> {
+        (**self).delete_domain_name(input)
+    }
+    fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationError> {
+        (**self).delete_integration(input)
+    }
+    fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
+        (**self).delete_integration_response(input)
+    }
+    fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
+        (**self).delete_model(input)
+    }
+    fn delete_route(&self, input: DeleteRouteRequest) -> RusotoFuture<(), DeleteRouteError> {
+        (**self).delete_route(input)
+    }
+    fn delete_route_response(
+        &self,
+        input: DeleteRouteResponseRequest,
+    ) -> RusotoFuture<(), DeleteRouteResponseError> {
+        (**self).delete_route_response(input)
+    }
+    fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
+        (**self).delete_stage(input)
+    }
+    fn get_api(&self, input: GetApiRequest) -> RusotoFuture<GetApiResponse, GetApiError> {
+        (**self).get_api(input)
+    }
+    fn get_api_mapping(
+        &self,
+        input: GetApiMappingRequest,
+    ) -> RusotoFuture<GetApiMappingResponse, GetApiMappingError> {
+        (**self).get_api_mapping(input)
+    }
+    fn get_api_mappings(
+        &self,
+        input: GetApiMappingsRequest,
+    ) -> RusotoFuture<GetApiMappingsResponse, GetApiMappingsError> {
+        (**self).get_api_mappings(input)
+    }
+    fn get_apis(&self, input: GetApisRequest) -> RusotoFuture<GetApisResponse, GetApisError> {
+        (**self).get_apis(input)
+    }
+    fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> RusotoFuture<GetAuthorizerResponse, GetAuthorizerError> {
+        (**self).get_authorizer(input)
+    }
+    fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> RusotoFuture<GetAuthorizersResponse, GetAuthorizersError> {
+        (**self).get_authorizers(input)
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> RusotoFuture<GetDeploymentResponse, GetDeploymentError> {
+        (**self).get_deployment(input)
+    }
+    fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> RusotoFuture<GetDeploymentsResponse, GetDeploymentsError> {
+        (**self).get_deployments(input)
+    }
+    fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> RusotoFuture<GetDomainNameResponse, GetDomainNameError> {
+        (**self).get_domain_name(input)
+    }
+    fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> RusotoFuture<GetDomainNamesResponse, GetDomainNamesError> {
+        (**self).get_domain_names(input)
+    }
+    fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> RusotoFuture<GetIntegrationResponse, GetIntegrationError> {
+        (**self).get_integration(input)
+    }
+    fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> RusotoFuture<GetIntegrationResponseResponse, GetIntegrationResponseError> {
+        (**self).get_integration_response(input)
+    }
+    fn get_integration_responses(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> RusotoFuture<GetIntegrationResponsesResponse, GetIntegrationResponsesError> {
+        (**self).get_integration_responses(input)
+    }
+    fn get_integrations(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> RusotoFuture<GetIntegrationsResponse, GetIntegrationsError> {
+        (**self).get_integrations(input)
+    }
+    fn get_model(&self, input: GetModelRequest) -> RusotoFuture<GetModelResponse, GetModelError> {
+        (**self).get_model(input)
+    }
+    fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> RusotoFuture<GetModelTemplateResponse, GetModelTemplateError> {
+        (**self).get_model_template(input)
+    }
+    fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> RusotoFuture<GetModelsResponse, GetModelsError> {
+        (**self).get_models(input)
+    }
+    fn get_route(&self, input: GetRouteRequest) -> RusotoFuture<GetRouteResponse, GetRouteError> {
+        (**self).get_route(input)
+    }
+    fn get_route_response(
+        &self,
+        input: GetRouteResponseRequest,
+    ) -> RusotoFuture<GetRouteResponseResponse, GetRouteResponseError> {
+        (**self).get_route_response(input)
+    }
+    fn get_route_responses(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> RusotoFuture<GetRouteResponsesResponse, GetRouteResponsesError> {
+        (**self).get_route_responses(input)
+    }
+    fn get_routes(
+        &self,
+        input: GetRoutesRequest,
+    ) -> RusotoFuture<GetRoutesResponse, GetRoutesError> {
+        (**self).get_routes(input)
+    }
+    fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<GetStageResponse, GetStageError> {
+        (**self).get_stage(input)
+    }
+    fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> RusotoFuture<GetStagesResponse, GetStagesError> {
+        (**self).get_stages(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_api(
+        &self,
+        input: UpdateApiRequest,
+    ) -> RusotoFuture<UpdateApiResponse, UpdateApiError> {
+        (**self).update_api(input)
+    }
+    fn update_api_mapping(
+        &self,
+        input: UpdateApiMappingRequest,
+    ) -> RusotoFuture<UpdateApiMappingResponse, UpdateApiMappingError> {
+        (**self).update_api_mapping(input)
+    }
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<UpdateAuthorizerResponse, UpdateAuthorizerError> {
+        (**self).update_authorizer(input)
+    }
+    fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> RusotoFuture<UpdateDeploymentResponse, UpdateDeploymentError> {
+        (**self).update_deployment(input)
+    }
+    fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> RusotoFuture<UpdateDomainNameResponse, UpdateDomainNameError> {
+        (**self).update_domain_name(input)
+    }
+    fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> RusotoFuture<UpdateIntegrationResponse, UpdateIntegrationError> {
+        (**self).update_integration(input)
+    }
+    fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> RusotoFuture<UpdateIntegrationResponseResponse, UpdateIntegrationResponseError> {
+        (**self).update_integration_response(input)
+    }
+    fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> RusotoFuture<UpdateModelResponse, UpdateModelError> {
+        (**self).update_model(input)
+    }
+    fn update_route(
+        &self,
+        input: UpdateRouteRequest,
+    ) -> RusotoFuture<UpdateRouteResponse, UpdateRouteError> {
+        (**self).update_route(input)
+    }
+    fn update_route_response(
+        &self,
+        input: UpdateRouteResponseRequest,
+    ) -> RusotoFuture<UpdateRouteResponseResponse, UpdateRouteResponseError> {
+        (**self).update_route_response(input)
+    }
+    fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> RusotoFuture<UpdateStageResponse, UpdateStageError> {
+        (**self).update_stage(input)
+    }
+}
+impl<T: ApiGatewayV2 + ?Sized> ApiGatewayV2 for ::std::sync::Arc<T> {
+    fn create_api(
+        &self,
+        input: CreateApiRequest,
+    ) -> RusotoFuture<CreateApiResponse, CreateApiError> {
+        (**self).create_api(input)
+    }
+    fn create_api_mapping(
+        &self,
+        input: CreateApiMappingRequest,
+    ) -> RusotoFuture<CreateApiMappingResponse, CreateApiMappingError> {
+        (**self).create_api_mapping(input)
+    }
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<CreateAuthorizerResponse, CreateAuthorizerError> {
+        (**self).create_authorizer(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> RusotoFuture<CreateDomainNameResponse, CreateDomainNameError> {
+        (**self).create_domain_name(input)
+    }
+    fn create_integration(
+        &self,
+        input: CreateIntegrationRequest,
+    ) -> RusotoFuture<CreateIntegrationResponse, CreateIntegrationError> {
+        (**self).create_integration(input)
+    }
+    fn create_integration_response(
+        &self,
+        input: CreateIntegrationResponseRequest,
+    ) -> RusotoFuture<CreateIntegrationResponseResponse, CreateIntegrationResponseError> {
+        (**self).create_integration_response(input)
+    }
+    fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> RusotoFuture<CreateModelResponse, CreateModelError> {
+        (**self).create_model(input)
+    }
+    fn create_route(
+        &self,
+        input: CreateRouteRequest,
+    ) -> RusotoFuture<CreateRouteResponse, CreateRouteError> {
+        (**self).create_route(input)
+    }
+    fn create_route_response(
+        &self,
+        input: CreateRouteResponseRequest,
+    ) -> RusotoFuture<CreateRouteResponseResponse, CreateRouteResponseError> {
+        (**self).create_route_response(input)
+    }
+    fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> RusotoFuture<CreateStageResponse, CreateStageError> {
+        (**self).create_stage(input)
+    }
+    fn delete_api(&self, input: DeleteApiRequest) -> RusotoFuture<(), DeleteApiError> {
+        (**self).delete_api(input)
+    }
+    fn delete_api_mapping(
+        &self,
+        input: DeleteApiMappingRequest,
+    ) -> RusotoFuture<(), DeleteApiMappingError> {
+        (**self).delete_api_mapping(input)
+    }
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<(), DeleteAuthorizerError> {
+        (**self).delete_authorizer(input)
+    }
+    fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> RusotoFuture<(), DeleteDeploymentError> {
+        (**self).delete_deployment(input)
+    }
+    fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> RusotoFuture<(), DeleteDomainNameError> {
+        (**self).delete_domain_name(input)
+    }
+    fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationError> {
+        (**self).delete_integration(input)
+    }
+    fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
+        (**self).delete_integration_response(input)
+    }
+    fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
+        (**self).delete_model(input)
+    }
+    fn delete_route(&self, input: DeleteRouteRequest) -> RusotoFuture<(), DeleteRouteError> {
+        (**self).delete_route(input)
+    }
+    fn delete_route_response(
+        &self,
+        input: DeleteRouteResponseRequest,
+    ) -> RusotoFuture<(), DeleteRouteResponseError> {
+        (**self).delete_route_response(input)
+    }
+    fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
+        (**self).delete_stage(input)
+    }
+    fn get_api(&self, input: GetApiRequest) -> RusotoFuture<GetApiResponse, GetApiError> {
+        (**self).get_api(input)
+    }
+    fn get_api_mapping(
+        &self,
+        input: GetApiMappingRequest,
+    ) -> RusotoFuture<GetApiMappingResponse, GetApiMappingError> {
+        (**self).get_api_mapping(input)
+    }
+    fn get_api_mappings(
+        &self,
+        input: GetApiMappingsRequest,
+    ) -> RusotoFuture<GetApiMappingsResponse, GetApiMappingsError> {
+        (**self).get_api_mappings(input)
+    }
+    fn get_apis(&self, input: GetApisRequest) -> RusotoFuture<GetApisResponse, GetApisError> {
+        (**self).get_apis(input)
+    }
+    fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> RusotoFuture<GetAuthorizerResponse, GetAuthorizerError> {
+        (**self).get_authorizer(input)
+    }
+    fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> RusotoFuture<GetAuthorizersResponse, GetAuthorizersError> {
+        (**self).get_authorizers(input)
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> RusotoFuture<GetDeploymentResponse, GetDeploymentError> {
+        (**self).get_deployment(input)
+    }
+    fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> RusotoFuture<GetDeploymentsResponse, GetDeploymentsError> {
+        (**self).get_deployments(input)
+    }
+    fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> RusotoFuture<GetDomainNameResponse, GetDomainNameError> {
+        (**self).get_domain_name(input)
+    }
+    fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> RusotoFuture<GetDomainNamesResponse, GetDomainNamesError> {
+        (**self).get_domain_names(input)
+    }
+    fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> RusotoFuture<GetIntegrationResponse, GetIntegrationError> {
+        (**self).get_integration(input)
+    }
+    fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> RusotoFuture<GetIntegrationResponseResponse, GetIntegrationResponseError> {
+        (**self).get_integration_response(input)
+    }
+    fn get_integration_responses(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> RusotoFuture<GetIntegrationResponsesResponse, GetIntegrationResponsesError> {
+        (**self).get_integration_responses(input)
+    }
+    fn get_integrations(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> RusotoFuture<GetIntegrationsResponse, GetIntegrationsError> {
+        (**self).get_integrations(input)
+    }
+    fn get_model(&self, input: GetModelRequest) -> RusotoFuture<GetModelResponse, GetModelError> {
+        (**self).get_model(input)
+    }
+    fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> RusotoFuture<GetModelTemplateResponse, GetModelTemplateError> {
+        (**self).get_model_template(input)
+    }
+    fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> RusotoFuture<GetModelsResponse, GetModelsError> {
+        (**self).get_models(input)
+    }
+    fn get_route(&self, input: GetRouteRequest) -> RusotoFuture<GetRouteResponse, GetRouteError> {
+        (**self).get_route(input)
+    }
+    fn get_route_response(
+        &self,
+        input: GetRouteResponseRequest,
+    ) -> RusotoFuture<GetRouteResponseResponse, GetRouteResponseError> {
+        (**self).get_route_response(input)
+    }
+    fn get_route_responses(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> RusotoFuture<GetRouteResponsesResponse, GetRouteResponsesError> {
+        (**self).get_route_responses(input)
+    }
+    fn get_routes(
+        &self,
+        input: GetRoutesRequest,
+    ) -> RusotoFuture<GetRoutesResponse, GetRoutesError> {
+        (**self).get_routes(input)
+    }
+    fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<GetStageResponse, GetStageError> {
+        (**self).get_stage(input)
+    }
+    fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> RusotoFuture<GetStagesResponse, GetStagesError> {
+        (**self).get_stages(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_api(
+        &self,
+        input: UpdateApiRequest,
+    ) -> RusotoFuture<UpdateApiResponse, UpdateApiError> {
+        (**self).update_api(input)
+    }
+    fn update_api_mapping(
+        &self,
+        input: UpdateApiMappingRequest,
+    ) -> RusotoFuture<UpdateApiMappingResponse, UpdateApiMappingError> {
+        (**self).update_api_mapping(input)
+    }
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<UpdateAuthorizerResponse, UpdateAuthorizerError> {
+        (**self).update_authorizer(input)
+    }
+    fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> RusotoFuture<UpdateDeploymentResponse, UpdateDeploymentError> {
+        (**self).update_deployment(input)
+    }
+    fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> RusotoFuture<UpdateDomainNameResponse, UpdateDomainNameError> {
+        (**self).update_domain_name(input)
+    }
+    fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> RusotoFuture<UpdateIntegrationResponse, UpdateIntegrationError> {
+        (**self).update_integration(input)
+    }
+    fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> RusotoFuture<UpdateIntegrationResponseResponse, UpdateIntegrationResponseError> {
+        (**self).update_integration_response(input)
+    }
+    fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> RusotoFuture<UpdateModelResponse, UpdateModelError> {
+        (**self).update_model(input)
+    }
+    fn update_route(
+        &self,
+        input: UpdateRouteRequest,
+    ) -> RusotoFuture<UpdateRouteResponse, UpdateRouteError> {
+        (**self).update_route(input)
+    }
+    fn update_route_response(
+        &self,
+        input: UpdateRouteResponseRequest,
+    ) -> RusotoFuture<UpdateRouteResponseResponse, UpdateRouteResponseError> {
+        (**self).update_route_response(input)
+    }
+    fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> RusotoFuture<UpdateStageResponse, UpdateStageError> {
+        (**self).update_stage(input)
+    }
+}
+/// A client for the AmazonApiGatewayV2 API.
+#[derive(Clone)]
+pub struct ApiGatewayV2Client {
+    client: Client,
+    region: region::Region,
+}
+
+impl ApiGatewayV2Client {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ApiGatewayV2Client {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApiGatewayV2Client
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayV2Client {
+        ApiGatewayV2Client { client, region }
+    }
+}
+
+impl ApiGatewayV2 for ApiGatewayV2Client {
+    /// <p>Creates an Api resource.</p>
+    fn create_api(
+        &self,
+        input: CreateApiRequest,
+    ) -> RusotoFuture<CreateApiResponse, CreateApiError> {
+        let request_uri = "/v2/apis";
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApi");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateApi")
+                        .deserialize::<CreateApiResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an API mapping.</p>
+    fn create_api_mapping(
+        &self,
+        input: CreateApiMappingRequest,
+    ) -> RusotoFuture<CreateApiMappingResponse, CreateApiMappingError> {
+        let request_uri = format!(
+            "/v2/domainnames/{domain_name}/apimappings",
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApiMapping");
+        request.add_resource_id("DomainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateApiMapping")
+                        .deserialize::<CreateApiMappingResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateApiMappingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an Authorizer for an API.</p>
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<CreateAuthorizerResponse, CreateAuthorizerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateAuthorizer")
+                        .deserialize::<CreateAuthorizerResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAuthorizerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Deployment for an API.</p>
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
+        let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDeployment")
+                        .deserialize::<CreateDeploymentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a domain name.</p>
+    fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> RusotoFuture<CreateDomainNameResponse, CreateDomainNameError> {
+        let request_uri = "/v2/domainnames";
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDomainName");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDomainName")
+                        .deserialize::<CreateDomainNameResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDomainNameError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an Integration.</p>
+    fn create_integration(
+        &self,
+        input: CreateIntegrationRequest,
+    ) -> RusotoFuture<CreateIntegrationResponse, CreateIntegrationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateIntegration");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateIntegration")
+                        .deserialize::<CreateIntegrationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateIntegrationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an IntegrationResponses.</p>
+    fn create_integration_response(
+        &self,
+        input: CreateIntegrationResponseRequest,
+    ) -> RusotoFuture<CreateIntegrationResponseResponse, CreateIntegrationResponseError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses",
+            api_id = input.api_id,
+            integration_id = input.integration_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateIntegrationResponse");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("IntegrationId", &input.integration_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateIntegrationResponse")
+                        .deserialize::<CreateIntegrationResponseResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateIntegrationResponseError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a Model for an API.</p>
+    fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> RusotoFuture<CreateModelResponse, CreateModelError> {
+        let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateModel");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateModel")
+                        .deserialize::<CreateModelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Route for an API.</p>
+    fn create_route(
+        &self,
+        input: CreateRouteRequest,
+    ) -> RusotoFuture<CreateRouteResponse, CreateRouteError> {
+        let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRoute");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateRoute")
+                        .deserialize::<CreateRouteResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateRouteError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a RouteResponse for a Route.</p>
+    fn create_route_response(
+        &self,
+        input: CreateRouteResponseRequest,
+    ) -> RusotoFuture<CreateRouteResponseResponse, CreateRouteResponseError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/routes/{route_id}/routeresponses",
+            api_id = input.api_id,
+            route_id = input.route_id
+        );
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRouteResponse");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("RouteId", &input.route_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateRouteResponse")
+                        .deserialize::<CreateRouteResponseResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateRouteResponseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Stage for an API.</p>
+    fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> RusotoFuture<CreateStageResponse, CreateStageError> {
+        let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateStage");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateStage")
+                        .deserialize::<CreateStageResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateStageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an Api resource.</p>
+    fn delete_api(&self, input: DeleteApiRequest) -> RusotoFuture<(), DeleteApiError> {
+        let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApi");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an API mapping.</p>
+    fn delete_api_mapping(
+        &self,
+        input: DeleteApiMappingRequest,
+    ) -> RusotoFuture<(), DeleteApiMappingError> {
+        let request_uri = format!(
+            "/v2/domainnames/{domain_name}/apimappings/{api_mapping_id}",
+            api_mapping_id = input.api_mapping_id,
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApiMapping");
+        request.add_resource_id("ApiMappingId", &input.api_mapping_id.to_string());
+        request.add_resource_id("DomainName", &input.domain_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteApiMappingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an Authorizer.</p>
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<(), DeleteAuthorizerError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/authorizers/{authorizer_id}",
+            api_id = input.api_id,
+            authorizer_id = input.authorizer_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("AuthorizerId", &input.authorizer_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAuthorizerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a Deployment.</p>
+    fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> RusotoFuture<(), DeleteDeploymentError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/deployments/{deployment_id}",
+            api_id = input.api_id,
+            deployment_id = input.deployment_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDeployment");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("DeploymentId", &input.deployment_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a domain name.</p>
+    fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> RusotoFuture<(), DeleteDomainNameError> {
+        let request_uri = format!(
+            "/v2/domainnames/{domain_name}",
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDomainName");
+        request.add_resource_id("DomainName", &input.domain_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDomainNameError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an Integration.</p>
+    fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/integrations/{integration_id}",
+            api_id = input.api_id,
+            integration_id = input.integration_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegration");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("IntegrationId", &input.integration_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteIntegrationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an IntegrationResponses.</p>
+    fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
+        let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationResponse");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("IntegrationId", &input.integration_id.to_string());
+        request.add_resource_id(
+            "IntegrationResponseId",
+            &input.integration_response_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteIntegrationResponseError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a Model.</p>
+    fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/models/{model_id}",
+            api_id = input.api_id,
+            model_id = input.model_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteModel");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("ModelId", &input.model_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a Route.</p>
+    fn delete_route(&self, input: DeleteRouteRequest) -> RusotoFuture<(), DeleteRouteError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/routes/{route_id}",
+            api_id = input.api_id,
+            route_id = input.route_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRoute");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("RouteId", &input.route_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteRouteError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a RouteResponse.</p>
+    fn delete_route_response(
+        &self,
+        input: DeleteRouteResponseRequest,
+    ) -> RusotoFuture<(), DeleteRouteResponseError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/routes/{route_id}/routeresponses/{route_response_id}",
+            api_id = input.api_id,
+            route_id = input.route_id,
+            route_response_id = input.route_response_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteResponse");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("RouteId", &input.route_id.to_string());
+        request.add_resource_id("RouteResponseId", &input.route_response_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteRouteResponseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a Stage.</p>
+    fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/stages/{stage_name}",
+            api_id = input.api_id,
+            stage_name = input.stage_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteStage");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("StageName", &input.stage_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteStageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets an Api resource.</p>
+    fn get_api(&self, input: GetApiRequest) -> RusotoFuture<GetApiResponse, GetApiError> {
+        let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApi");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetApi")
+                        .deserialize::<GetApiResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>The API mapping.</p>
+    fn get_api_mapping(
+        &self,
+        input: GetApiMappingRequest,
+    ) -> RusotoFuture<GetApiMappingResponse, GetApiMappingError> {
+        let request_uri = format!(
+            "/v2/domainnames/{domain_name}/apimappings/{api_mapping_id}",
+            api_mapping_id = input.api_mapping_id,
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiMapping");
+        request.add_resource_id("ApiMappingId", &input.api_mapping_id.to_string());
+        request.add_resource_id("DomainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetApiMapping")
+                        .deserialize::<GetApiMappingResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetApiMappingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>The API mappings.</p>
+    fn get_api_mappings(
+        &self,
+        input: GetApiMappingsRequest,
+    ) -> RusotoFuture<GetApiMappingsResponse, GetApiMappingsError> {
+        let request_uri = format!(
+            "/v2/domainnames/{domain_name}/apimappings",
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiMappings");
+        request.add_resource_id("DomainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetApiMappings")
+                        .deserialize::<GetApiMappingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetApiMappingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a collection of Api resources.</p>
+    fn get_apis(&self, input: GetApisRequest) -> RusotoFuture<GetApisResponse, GetApisError> {
+        let request_uri = "/v2/apis";
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApis");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetApis")
+                        .deserialize::<GetApisResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetApisError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets an Authorizer.</p>
+    fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> RusotoFuture<GetAuthorizerResponse, GetAuthorizerError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/authorizers/{authorizer_id}",
+            api_id = input.api_id,
+            authorizer_id = input.authorizer_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizer");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("AuthorizerId", &input.authorizer_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAuthorizer")
+                        .deserialize::<GetAuthorizerResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAuthorizerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the Authorizers for an API.</p>
+    fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> RusotoFuture<GetAuthorizersResponse, GetAuthorizersError> {
+        let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizers");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAuthorizers")
+                        .deserialize::<GetAuthorizersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAuthorizersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a Deployment.</p>
+    fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> RusotoFuture<GetDeploymentResponse, GetDeploymentError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/deployments/{deployment_id}",
+            api_id = input.api_id,
+            deployment_id = input.deployment_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("DeploymentId", &input.deployment_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDeployment")
+                        .deserialize::<GetDeploymentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the Deployments for an API.</p>
+    fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> RusotoFuture<GetDeploymentsResponse, GetDeploymentsError> {
+        let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployments");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDeployments")
+                        .deserialize::<GetDeploymentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDeploymentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a domain name.</p>
+    fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> RusotoFuture<GetDomainNameResponse, GetDomainNameError> {
+        let request_uri = format!(
+            "/v2/domainnames/{domain_name}",
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainName");
+        request.add_resource_id("DomainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDomainName")
+                        .deserialize::<GetDomainNameResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDomainNameError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the domain names for an AWS account.</p>
+    fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> RusotoFuture<GetDomainNamesResponse, GetDomainNamesError> {
+        let request_uri = "/v2/domainnames";
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainNames");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDomainNames")
+                        .deserialize::<GetDomainNamesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDomainNamesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets an Integration.</p>
+    fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> RusotoFuture<GetIntegrationResponse, GetIntegrationError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/integrations/{integration_id}",
+            api_id = input.api_id,
+            integration_id = input.integration_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegration");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("IntegrationId", &input.integration_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIntegration")
+                        .deserialize::<GetIntegrationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetIntegrationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets an IntegrationResponses.</p>
+    fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> RusotoFuture<GetIntegrationResponseResponse, GetIntegrationResponseError> {
+        let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponse");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("IntegrationId", &input.integration_id.to_string());
+        request.add_resource_id(
+            "IntegrationResponseId",
+            &input.integration_response_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIntegrationResponse")
+                        .deserialize::<GetIntegrationResponseResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetIntegrationResponseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the IntegrationResponses for an Integration.</p>
+    fn get_integration_responses(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> RusotoFuture<GetIntegrationResponsesResponse, GetIntegrationResponsesError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses",
+            api_id = input.api_id,
+            integration_id = input.integration_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponses");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("IntegrationId", &input.integration_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIntegrationResponses")
+                        .deserialize::<GetIntegrationResponsesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetIntegrationResponsesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the Integrations for an API.</p>
+    fn get_integrations(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> RusotoFuture<GetIntegrationsResponse, GetIntegrationsError> {
+        let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrations");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIntegrations")
+                        .deserialize::<GetIntegrationsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetIntegrationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a Model.</p>
+    fn get_model(&self, input: GetModelRequest) -> RusotoFuture<GetModelResponse, GetModelError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/models/{model_id}",
+            api_id = input.api_id,
+            model_id = input.model_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModel");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("ModelId", &input.model_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetModel")
+                        .deserialize::<GetModelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a model template.</p>
+    fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> RusotoFuture<GetModelTemplateResponse, GetModelTemplateError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/models/{model_id}/template",
+            api_id = input.api_id,
+            model_id = input.model_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModelTemplate");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("ModelId", &input.model_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetModelTemplate")
+                        .deserialize::<GetModelTemplateResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetModelTemplateError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the Models for an API.</p>
+    fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> RusotoFuture<GetModelsResponse, GetModelsError> {
+        let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModels");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetModels")
+                        .deserialize::<GetModelsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetModelsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a Route.</p>
+    fn get_route(&self, input: GetRouteRequest) -> RusotoFuture<GetRouteResponse, GetRouteError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/routes/{route_id}",
+            api_id = input.api_id,
+            route_id = input.route_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRoute");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("RouteId", &input.route_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetRoute")
+                        .deserialize::<GetRouteResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRouteError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a RouteResponse.</p>
+    fn get_route_response(
+        &self,
+        input: GetRouteResponseRequest,
+    ) -> RusotoFuture<GetRouteResponseResponse, GetRouteResponseError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/routes/{route_id}/routeresponses/{route_response_id}",
+            api_id = input.api_id,
+            route_id = input.route_id,
+            route_response_id = input.route_response_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRouteResponse");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("RouteId", &input.route_id.to_string());
+        request.add_resource_id("RouteResponseId", &input.route_response_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetRouteResponse")
+                        .deserialize::<GetRouteResponseResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRouteResponseError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the RouteResponses for a Route.</p>
+    fn get_route_responses(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> RusotoFuture<GetRouteResponsesResponse, GetRouteResponsesError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/routes/{route_id}/routeresponses",
+            api_id = input.api_id,
+            route_id = input.route_id
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRouteResponses");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("RouteId", &input.route_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetRouteResponses")
+                        .deserialize::<GetRouteResponsesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRouteResponsesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the Routes for an API.</p>
+    fn get_routes(
+        &self,
+        input: GetRoutesRequest,
+    ) -> RusotoFuture<GetRoutesResponse, GetRoutesError> {
+        let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRoutes");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetRoutes")
+                        .deserialize::<GetRoutesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRoutesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a Stage.</p>
+    fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<GetStageResponse, GetStageError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/stages/{stage_name}",
+            api_id = input.api_id,
+            stage_name = input.stage_name
+        );
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStage");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("StageName", &input.stage_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetStage")
+                        .deserialize::<GetStageResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetStageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the Stages for an API.</p>
+    fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> RusotoFuture<GetStagesResponse, GetStagesError> {
+        let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStages");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetStages")
+                        .deserialize::<GetStagesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetStagesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the Tags for an API.</p>
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetTags");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetTags")
+                        .deserialize::<GetTagsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Tag an APIGW resource</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
+                        .deserialize::<TagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Untag an APIGW resource</p>
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an Api resource.</p>
+    fn update_api(
+        &self,
+        input: UpdateApiRequest,
+    ) -> RusotoFuture<UpdateApiResponse, UpdateApiError> {
+        let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApi");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateApi")
+                        .deserialize::<UpdateApiResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>The API mapping.</p>
+    fn update_api_mapping(
+        &self,
+        input: UpdateApiMappingRequest,
+    ) -> RusotoFuture<UpdateApiMappingResponse, UpdateApiMappingError> {
+        let request_uri = format!(
+            "/v2/domainnames/{domain_name}/apimappings/{api_mapping_id}",
+            api_mapping_id = input.api_mapping_id,
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApiMapping");
+        request.add_resource_id("ApiMappingId", &input.api_mapping_id.to_string());
+        request.add_resource_id("DomainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateApiMapping")
+                        .deserialize::<UpdateApiMappingResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateApiMappingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an Authorizer.</p>
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<UpdateAuthorizerResponse, UpdateAuthorizerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v2/apis/{api_id}/authorizers/{authorizer_id}",
+            api_id = input.api_id,
+            authorizer_id = input.authorizer_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("AuthorizerId", &input.authorizer_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateAuthorizer")
+                        .deserialize::<UpdateAuthorizerResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAuthorizerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a Deployment.</p>
+    fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> RusotoFuture<UpdateDeploymentResponse, UpdateDeploymentError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/deployments/{deployment_id}",
+            api_id = input.api_id,
+            deployment_id = input.deployment_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDeployment");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("DeploymentId", &input.deployment_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDeployment")
+                        .deserialize::<UpdateDeploymentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a domain name.</p>
+    fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> RusotoFuture<UpdateDomainNameResponse, UpdateDomainNameError> {
+        let request_uri = format!(
+            "/v2/domainnames/{domain_name}",
+            domain_name = input.domain_name
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDomainName");
+        request.add_resource_id("DomainName", &input.domain_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDomainName")
+                        .deserialize::<UpdateDomainNameResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDomainNameError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an Integration.</p>
+    fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> RusotoFuture<UpdateIntegrationResponse, UpdateIntegrationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v2/apis/{api_id}/integrations/{integration_id}",
+            api_id = input.api_id,
+            integration_id = input.integration_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegration");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("IntegrationId", &input.integration_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateIntegration")
+                        .deserialize::<UpdateIntegrationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateIntegrationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an IntegrationResponses.</p>
+    fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> RusotoFuture<UpdateIntegrationResponseResponse, UpdateIntegrationResponseError> {
+        let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegrationResponse");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("IntegrationId", &input.integration_id.to_string());
+        request.add_resource_id(
+            "IntegrationResponseId",
+            &input.integration_response_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateIntegrationResponse")
+                        .deserialize::<UpdateIntegrationResponseResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateIntegrationResponseError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates a Model.</p>
+    fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> RusotoFuture<UpdateModelResponse, UpdateModelError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/models/{model_id}",
+            api_id = input.api_id,
+            model_id = input.model_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateModel");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("ModelId", &input.model_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateModel")
+                        .deserialize::<UpdateModelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a Route.</p>
+    fn update_route(
+        &self,
+        input: UpdateRouteRequest,
+    ) -> RusotoFuture<UpdateRouteResponse, UpdateRouteError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/routes/{route_id}",
+            api_id = input.api_id,
+            route_id = input.route_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRoute");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("RouteId", &input.route_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateRoute")
+                        .deserialize::<UpdateRouteResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateRouteError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a RouteResponse.</p>
+    fn update_route_response(
+        &self,
+        input: UpdateRouteResponseRequest,
+    ) -> RusotoFuture<UpdateRouteResponseResponse, UpdateRouteResponseError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/routes/{route_id}/routeresponses/{route_response_id}",
+            api_id = input.api_id,
+            route_id = input.route_id,
+            route_response_id = input.route_response_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRouteResponse");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("RouteId", &input.route_id.to_string());
+        request.add_resource_id("RouteResponseId", &input.route_response_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateRouteResponse")
+                        .deserialize::<UpdateRouteResponseResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateRouteResponseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a Stage.</p>
+    fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> RusotoFuture<UpdateStageResponse, UpdateStageError> {
+        let request_uri = format!(
+            "/v2/apis/{api_id}/stages/{stage_name}",
+            api_id = input.api_id,
+            stage_name = input.stage_name
+        );
+
+        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateStage");
+        request.add_resource_id("ApiId", &input.api_id.to_string());
+        request.add_resource_id("StageName", &input.stage_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateStage")
+                        .deserialize::<UpdateStageResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateStageError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the AmazonApiGatewayV2 API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockApiGatewayV2 {
+    create_api: Option<
+        Box<
+            dyn Fn(
+                    CreateApiRequest,
+                )
+                    -> ::std::result::Result<CreateApiResponse, RusotoError<CreateApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_api_mapping: Option<
+        Box<
+            dyn Fn(
+                    CreateApiMappingRequest,
+                ) -> ::std::result::Result<
+                    CreateApiMappingResponse,
+                    RusotoError<CreateApiMappingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_authorizer: Option<
+        Box<
+            dyn Fn(
+                    CreateAuthorizerRequest,
+                ) -> ::std::result::Result<
+                    CreateAuthorizerResponse,
+                    RusotoError<CreateAuthorizerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_deployment: Option<
+        Box<
+            dyn Fn(
+                    CreateDeploymentRequest,
+                ) -> ::std::result::Result<
+                    CreateDeploymentResponse,
+                    RusotoError<CreateDeploymentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_domain_name: Option<
+        Box<
+            dyn Fn(
+                    CreateDomainNameRequest,
+                ) -> ::std::result::Result<
+                    CreateDomainNameResponse,
+                    RusotoError<CreateDomainNameError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_integration: Option<
+        Box<
+            dyn Fn(
+                    CreateIntegrationRequest,
+                ) -> ::std::result::Result<
+                    CreateIntegrationResponse,
+                    RusotoError<CreateIntegrationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_integration_response: Option<
+        Box<
+            dyn Fn(
+                    CreateIntegrationResponseRequest,
+                ) -> ::std::result::Result<
+                    CreateIntegrationResponseResponse,
+                    RusotoError<CreateIntegrationResponseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_model: Option<
+        Box<
+            dyn Fn(
+                    CreateModelRequest,
+                )
+                    -> ::std::result::Result<CreateModelResponse, RusotoError<CreateModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_route: Option<
+        Box<
+            dyn Fn(
+                    CreateRouteRequest,
+                )
+                    -> ::std::result::Result<CreateRouteResponse, RusotoError<CreateRouteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_route_response: Option<
+        Box<
+            dyn Fn(
+                    CreateRouteResponseRequest,
+                ) -> ::std::result::Result<
+                    CreateRouteResponseResponse,
+                    RusotoError<CreateRouteResponseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_stage: Option<
+        Box<
+            dyn Fn(
+                    CreateStageRequest,
+                )
+                    -> ::std::result::Result<CreateStageResponse, RusotoError<CreateStageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_api: Option<
+        Box<
+            dyn Fn(DeleteApiRequest) -> ::std::result::Result<(), RusotoError<DeleteApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_api_mapping: Option<
+        Box<
+            dyn Fn(
+                    DeleteApiMappingRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteApiMappingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_authorizer: Option<
+        Box<
+            dyn Fn(
+                    DeleteAuthorizerRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteAuthorizerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_deployment: Option<
+        Box<
+            dyn Fn(
+                    DeleteDeploymentRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteDeploymentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_domain_name: Option<
+        Box<
+            dyn Fn(
+                    DeleteDomainNameRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteDomainNameError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_integration: Option<
+        Box<
+            dyn Fn(
+                    DeleteIntegrationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteIntegrationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_integration_response: Option<
+        Box<
+            dyn Fn(
+                    DeleteIntegrationResponseRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteIntegrationResponseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_model: Option<
+        Box<
+            dyn Fn(DeleteModelRequest) -> ::std::result::Result<(), RusotoError<DeleteModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_route: Option<
+        Box<
+            dyn Fn(DeleteRouteRequest) -> ::std::result::Result<(), RusotoError<DeleteRouteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_route_response: Option<
+        Box<
+            dyn Fn(
+                    DeleteRouteResponseRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteRouteResponseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_stage: Option<
+        Box<
+            dyn Fn(DeleteStageRequest) -> ::std::result::Result<(), RusotoError<DeleteStageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_api: Option<
+        Box<
+            dyn Fn(GetApiRequest) -> ::std::result::Result<GetApiResponse, RusotoError<GetApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_api_mapping: Option<
+        Box<
+            dyn Fn(
+                    GetApiMappingRequest,
+                )
+                    -> ::std::result::Result<GetApiMappingResponse, RusotoError<GetApiMappingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_api_mappings: Option<
+        Box<
+            dyn Fn(
+                    GetApiMappingsRequest,
+                ) -> ::std::result::Result<
+                    GetApiMappingsResponse,
+                    RusotoError<GetApiMappingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_apis: Option<
+        Box<
+            dyn Fn(
+                    GetApisRequest,
+                )
+                    -> ::std::result::Result<GetApisResponse, RusotoError<GetApisError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_authorizer: Option<
+        Box<
+            dyn Fn(
+                    GetAuthorizerRequest,
+                )
+                    -> ::std::result::Result<GetAuthorizerResponse, RusotoError<GetAuthorizerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_authorizers: Option<
+        Box<
+            dyn Fn(
+                    GetAuthorizersRequest,
+                ) -> ::std::result::Result<
+                    GetAuthorizersResponse,
+                    RusotoError<GetAuthorizersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_deployment: Option<
+        Box<
+            dyn Fn(
+                    GetDeploymentRequest,
+                )
+                    -> ::std::result::Result<GetDeploymentResponse, RusotoError<GetDeploymentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_deployments: Option<
+        Box<
+            dyn Fn(
+                    GetDeploymentsRequest,
+                ) -> ::std::result::Result<
+                    GetDeploymentsResponse,
+                    RusotoError<GetDeploymentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_domain_name: Option<
+        Box<
+            dyn Fn(
+                    GetDomainNameRequest,
+                )
+                    -> ::std::result::Result<GetDomainNameResponse, RusotoError<GetDomainNameError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_domain_names: Option<
+        Box<
+            dyn Fn(
+                    GetDomainNamesRequest,
+                ) -> ::std::result::Result<
+                    GetDomainNamesResponse,
+                    RusotoError<GetDomainNamesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_integration: Option<
+        Box<
+            dyn Fn(
+                    GetIntegrationRequest,
+                ) -> ::std::result::Result<
+                    GetIntegrationResponse,
+                    RusotoError<GetIntegrationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_integration_response: Option<
+        Box<
+            dyn Fn(
+                    GetIntegrationResponseRequest,
+                ) -> ::std::result::Result<
+                    GetIntegrationResponseResponse,
+                    RusotoError<GetIntegrationResponseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_integration_responses: Option<
+        Box<
+            dyn Fn(
+                    GetIntegrationResponsesRequest,
+                ) -> ::std::result::Result<
+                    GetIntegrationResponsesResponse,
+                    RusotoError<GetIntegrationResponsesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_integrations: Option<
+        Box<
+            dyn Fn(
+                    GetIntegrationsRequest,
+                ) -> ::std::result::Result<
+                    GetIntegrationsResponse,
+                    RusotoError<GetIntegrationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_model: Option<
+        Box<
+            dyn Fn(
+                    GetModelRequest,
+                )
+                    -> ::std::result::Result<GetModelResponse, RusotoError<GetModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_model_template: Option<
+        Box<
+            dyn Fn(
+                    GetModelTemplateRequest,
+                ) -> ::std::result::Result<
+                    GetModelTemplateResponse,
+                    RusotoError<GetModelTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_models: Option<
+        Box<
+            dyn Fn(
+                    GetModelsRequest,
+                )
+                    -> ::std::result::Result<GetModelsResponse, RusotoError<GetModelsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_route: Option<
+        Box<
+            dyn Fn(
+                    GetRouteRequest,
+                )
+                    -> ::std::result::Result<GetRouteResponse, RusotoError<GetRouteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_route_response: Option<
+        Box<
+            dyn Fn(
+                    GetRouteResponseRequest,
+                ) -> ::std::result::Result<
+                    GetRouteResponseResponse,
+                    RusotoError<GetRouteResponseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_route_responses: Option<
+        Box<
+            dyn Fn(
+                    GetRouteResponsesRequest,
+                ) -> ::std::result::Result<
+                    GetRouteResponsesResponse,
+                    RusotoError<GetRouteResponsesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_routes: Option<
+        Box<
+            dyn Fn(
+                    GetRoutesRequest,
+                )
+                    -> ::std::result::Result<GetRoutesResponse, RusotoError<GetRoutesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_stage: Option<
+        Box<
+            dyn Fn(
+                    GetStageRequest,
+                )
+                    -> ::std::result::Result<GetStageResponse, RusotoError<GetStageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_stages: Option<
+        Box<
+            dyn Fn(
+                    GetStagesRequest,
+                )
+                    -> ::std::result::Result<GetStagesResponse, RusotoError<GetStagesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_tags: Option<
+        Box<
+            dyn Fn(
+                    GetTagsRequest,
+                )
+                    -> ::std::result::Result<GetTagsResponse, RusotoError<GetTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_api: Option<
+        Box<
+            dyn Fn(
+                    UpdateApiRequest,
+                )
+                    -> ::std::result::Result<UpdateApiResponse, RusotoError<UpdateApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_api_mapping: Option<
+        Box<
+            dyn Fn(
+                    UpdateApiMappingRequest,
+                ) -> ::std::result::Result<
+                    UpdateApiMappingResponse,
+                    RusotoError<UpdateApiMappingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_authorizer: Option<
+        Box<
+            dyn Fn(
+                    UpdateAuthorizerRequest,
+                ) -> ::std::result::Result<
+                    UpdateAuthorizerResponse,
+                    RusotoError<UpdateAuthorizerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_deployment: Option<
+        Box<
+            dyn Fn(
+                    UpdateDeploymentRequest,
+                ) -> ::std::result::Result<
+                    UpdateDeploymentResponse,
+                    RusotoError<UpdateDeploymentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_domain_name: Option<
+        Box<
+            dyn Fn(
+                    UpdateDomainNameRequest,
+                ) -> ::std::result::Result<
+                    UpdateDomainNameResponse,
+                    RusotoError<UpdateDomainNameError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_integration: Option<
+        Box<
+            dyn Fn(
+                    UpdateIntegrationRequest,
+                ) -> ::std::result::Result<
+                    UpdateIntegrationResponse,
+                    RusotoError<UpdateIntegrationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_integration_response: Option<
+        Box<
+            dyn Fn(
+                    UpdateIntegrationResponseRequest,
+                ) -> ::std::result::Result<
+                    UpdateIntegrationResponseResponse,
+                    RusotoError<UpdateIntegrationResponseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_model: Option<
+        Box<
+            dyn Fn(
+                    UpdateModelRequest,
+                )
+                    -> ::std::result::Result<UpdateModelResponse, RusotoError<UpdateModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_route: Option<
+        Box<
+            dyn Fn(
+                    UpdateRouteRequest,
+                )
+                    -> ::std::result::Result<UpdateRouteResponse, RusotoError<UpdateRouteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_route_response: Option<
+        Box<
+            dyn Fn(
+                    UpdateRouteResponseRequest,
+                ) -> ::std::result::Result<
+                    UpdateRouteResponseResponse,
+                    RusotoError<UpdateRouteResponseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_stage: Option<
+        Box<
+            dyn Fn(
+                    UpdateStageRequest,
+                )
+                    -> ::std::result::Result<UpdateStageResponse, RusotoError<UpdateStageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockApiGatewayV2 {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockApiGatewayV2 {
+        Default::default()
+    }
+
+    /// Answers `create_api` with what `handler` returns for its input.
+    pub fn with_create_api<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateApiRequest,
+            ) -> ::std::result::Result<CreateApiResponse, RusotoError<CreateApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_api_mapping` with what `handler` returns for its input.
+    pub fn with_create_api_mapping<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateApiMappingRequest,
+            ) -> ::std::result::Result<
+                CreateApiMappingResponse,
+                RusotoError<CreateApiMappingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_api_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_authorizer` with what `handler` returns for its input.
+    pub fn with_create_authorizer<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateAuthorizerRequest,
+            ) -> ::std::result::Result<
+                CreateAuthorizerResponse,
+                RusotoError<CreateAuthorizerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_authorizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_deployment` with what `handler` returns for its input.
+    pub fn with_create_deployment<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateDeploymentRequest,
+            ) -> ::std::result::Result<
+                CreateDeploymentResponse,
+                RusotoError<CreateDeploymentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_domain_name` with what `handler` returns for its input.
+    pub fn with_create_domain_name<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateDomainNameRequest,
+            ) -> ::std::result::Result<
+                CreateDomainNameResponse,
+                RusotoError<CreateDomainNameError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_domain_name = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_integration` with what `handler` returns for its input.
+    pub fn with_create_integration<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateIntegrationRequest,
+            ) -> ::std::result::Result<
+                CreateIntegrationResponse,
+                RusotoError<CreateIntegrationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_integration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_integration_response` with what `handler` returns for its input.
+    pub fn with_create_integration_response<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateIntegrationResponseRequest,
+            ) -> ::std::result::Result<
+                CreateIntegrationResponseResponse,
+                RusotoError<CreateIntegrationResponseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_integration_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_model` with what `handler` returns for its input.
+    pub fn with_create_model<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateModelRequest,
+            )
+                -> ::std::result::Result<CreateModelResponse, RusotoError<CreateModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_route` with what `handler` returns for its input.
+    pub fn with_create_route<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateRouteRequest,
+            )
+                -> ::std::result::Result<CreateRouteResponse, RusotoError<CreateRouteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_route = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_route_response` with what `handler` returns for its input.
+    pub fn with_create_route_response<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateRouteResponseRequest,
+            ) -> ::std::result::Result<
+                CreateRouteResponseResponse,
+                RusotoError<CreateRouteResponseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_route_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_stage` with what `handler` returns for its input.
+    pub fn with_create_stage<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                CreateStageRequest,
+            )
+                -> ::std::result::Result<CreateStageResponse, RusotoError<CreateStageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_stage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_api` with what `handler` returns for its input.
+    pub fn with_delete_api<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(DeleteApiRequest) -> ::std::result::Result<(), RusotoError<DeleteApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_api_mapping` with what `handler` returns for its input.
+    pub fn with_delete_api_mapping<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                DeleteApiMappingRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteApiMappingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_api_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_authorizer` with what `handler` returns for its input.
+    pub fn with_delete_authorizer<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                DeleteAuthorizerRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteAuthorizerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_authorizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_deployment` with what `handler` returns for its input.
+    pub fn with_delete_deployment<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                DeleteDeploymentRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_domain_name` with what `handler` returns for its input.
+    pub fn with_delete_domain_name<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                DeleteDomainNameRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDomainNameError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_domain_name = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_integration` with what `handler` returns for its input.
+    pub fn with_delete_integration<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                DeleteIntegrationRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteIntegrationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_integration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_integration_response` with what `handler` returns for its input.
+    pub fn with_delete_integration_response<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                DeleteIntegrationResponseRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteIntegrationResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_integration_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_model` with what `handler` returns for its input.
+    pub fn with_delete_model<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(DeleteModelRequest) -> ::std::result::Result<(), RusotoError<DeleteModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_route` with what `handler` returns for its input.
+    pub fn with_delete_route<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(DeleteRouteRequest) -> ::std::result::Result<(), RusotoError<DeleteRouteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_route = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_route_response` with what `handler` returns for its input.
+    pub fn with_delete_route_response<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                DeleteRouteResponseRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteRouteResponseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_route_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_stage` with what `handler` returns for its input.
+    pub fn with_delete_stage<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(DeleteStageRequest) -> ::std::result::Result<(), RusotoError<DeleteStageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_stage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_api` with what `handler` returns for its input.
+    pub fn with_get_api<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(GetApiRequest) -> ::std::result::Result<GetApiResponse, RusotoError<GetApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_api_mapping` with what `handler` returns for its input.
+    pub fn with_get_api_mapping<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetApiMappingRequest,
+            )
+                -> ::std::result::Result<GetApiMappingResponse, RusotoError<GetApiMappingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_api_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_api_mappings` with what `handler` returns for its input.
+    pub fn with_get_api_mappings<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetApiMappingsRequest,
+            )
+                -> ::std::result::Result<GetApiMappingsResponse, RusotoError<GetApiMappingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_api_mappings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_apis` with what `handler` returns for its input.
+    pub fn with_get_apis<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(GetApisRequest) -> ::std::result::Result<GetApisResponse, RusotoError<GetApisError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_apis = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_authorizer` with what `handler` returns for its input.
+    pub fn with_get_authorizer<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetAuthorizerRequest,
+            )
+                -> ::std::result::Result<GetAuthorizerResponse, RusotoError<GetAuthorizerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_authorizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_authorizers` with what `handler` returns for its input.
+    pub fn with_get_authorizers<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetAuthorizersRequest,
+            )
+                -> ::std::result::Result<GetAuthorizersResponse, RusotoError<GetAuthorizersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_authorizers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployment` with what `handler` returns for its input.
+    pub fn with_get_deployment<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetDeploymentRequest,
+            )
+                -> ::std::result::Result<GetDeploymentResponse, RusotoError<GetDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployments` with what `handler` returns for its input.
+    pub fn with_get_deployments<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetDeploymentsRequest,
+            )
+                -> ::std::result::Result<GetDeploymentsResponse, RusotoError<GetDeploymentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_domain_name` with what `handler` returns for its input.
+    pub fn with_get_domain_name<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetDomainNameRequest,
+            )
+                -> ::std::result::Result<GetDomainNameResponse, RusotoError<GetDomainNameError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_domain_name = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_domain_names` with what `handler` returns for its input.
+    pub fn with_get_domain_names<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetDomainNamesRequest,
+            )
+                -> ::std::result::Result<GetDomainNamesResponse, RusotoError<GetDomainNamesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_domain_names = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_integration` with what `handler` returns for its input.
+    pub fn with_get_integration<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetIntegrationRequest,
+            )
+                -> ::std::result::Result<GetIntegrationResponse, RusotoError<GetIntegrationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_integration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_integration_response` with what `handler` returns for its input.
+    pub fn with_get_integration_response<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetIntegrationResponseRequest,
+            ) -> ::std::result::Result<
+                GetIntegrationResponseResponse,
+                RusotoError<GetIntegrationResponseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_integration_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_integration_responses` with what `handler` returns for its input.
+    pub fn with_get_integration_responses<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetIntegrationResponsesRequest,
+            ) -> ::std::result::Result<
+                GetIntegrationResponsesResponse,
+                RusotoError<GetIntegrationResponsesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_integration_responses = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_integrations` with what `handler` returns for its input.
+    pub fn with_get_integrations<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetIntegrationsRequest,
+            )
+                -> ::std::result::Result<GetIntegrationsResponse, RusotoError<GetIntegrationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_integrations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_model` with what `handler` returns for its input.
+    pub fn with_get_model<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetModelRequest,
+            ) -> ::std::result::Result<GetModelResponse, RusotoError<GetModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_model_template` with what `handler` returns for its input.
+    pub fn with_get_model_template<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetModelTemplateRequest,
+            ) -> ::std::result::Result<
+                GetModelTemplateResponse,
+                RusotoError<GetModelTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_model_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_models` with what `handler` returns for its input.
+    pub fn with_get_models<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetModelsRequest,
+            ) -> ::std::result::Result<GetModelsResponse, RusotoError<GetModelsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_models = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_route` with what `handler` returns for its input.
+    pub fn with_get_route<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetRouteRequest,
+            ) -> ::std::result::Result<GetRouteResponse, RusotoError<GetRouteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_route = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_route_response` with what `handler` returns for its input.
+    pub fn with_get_route_response<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetRouteResponseRequest,
+            ) -> ::std::result::Result<
+                GetRouteResponseResponse,
+                RusotoError<GetRouteResponseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_route_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_route_responses` with what `handler` returns for its input.
+    pub fn with_get_route_responses<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetRouteResponsesRequest,
+            ) -> ::std::result::Result<
+                GetRouteResponsesResponse,
+                RusotoError<GetRouteResponsesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_route_responses = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_routes` with what `handler` returns for its input.
+    pub fn with_get_routes<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetRoutesRequest,
+            ) -> ::std::result::Result<GetRoutesResponse, RusotoError<GetRoutesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_routes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_stage` with what `handler` returns for its input.
+    pub fn with_get_stage<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetStageRequest,
+            ) -> ::std::result::Result<GetStageResponse, RusotoError<GetStageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_stage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_stages` with what `handler` returns for its input.
+    pub fn with_get_stages<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                GetStagesRequest,
+            ) -> ::std::result::Result<GetStagesResponse, RusotoError<GetStagesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_stages = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_tags` with what `handler` returns for its input.
+    pub fn with_get_tags<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(GetTagsRequest) -> ::std::result::Result<GetTagsResponse, RusotoError<GetTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_api` with what `handler` returns for its input.
+    pub fn with_update_api<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateApiRequest,
+            ) -> ::std::result::Result<UpdateApiResponse, RusotoError<UpdateApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_api_mapping` with what `handler` returns for its input.
+    pub fn with_update_api_mapping<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateApiMappingRequest,
+            ) -> ::std::result::Result<
+                UpdateApiMappingResponse,
+                RusotoError<UpdateApiMappingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_api_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_authorizer` with what `handler` returns for its input.
+    pub fn with_update_authorizer<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateAuthorizerRequest,
+            ) -> ::std::result::Result<
+                UpdateAuthorizerResponse,
+                RusotoError<UpdateAuthorizerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_authorizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_deployment` with what `handler` returns for its input.
+    pub fn with_update_deployment<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateDeploymentRequest,
+            ) -> ::std::result::Result<
+                UpdateDeploymentResponse,
+                RusotoError<UpdateDeploymentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_domain_name` with what `handler` returns for its input.
+    pub fn with_update_domain_name<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateDomainNameRequest,
+            ) -> ::std::result::Result<
+                UpdateDomainNameResponse,
+                RusotoError<UpdateDomainNameError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_domain_name = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_integration` with what `handler` returns for its input.
+    pub fn with_update_integration<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateIntegrationRequest,
+            ) -> ::std::result::Result<
+                UpdateIntegrationResponse,
+                RusotoError<UpdateIntegrationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_integration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_integration_response` with what `handler` returns for its input.
+    pub fn with_update_integration_response<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateIntegrationResponseRequest,
+            ) -> ::std::result::Result<
+                UpdateIntegrationResponseResponse,
+                RusotoError<UpdateIntegrationResponseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_integration_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_model` with what `handler` returns for its input.
+    pub fn with_update_model<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateModelRequest,
+            )
+                -> ::std::result::Result<UpdateModelResponse, RusotoError<UpdateModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_route` with what `handler` returns for its input.
+    pub fn with_update_route<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateRouteRequest,
+            )
+                -> ::std::result::Result<UpdateRouteResponse, RusotoError<UpdateRouteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_route = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_route_response` with what `handler` returns for its input.
+    pub fn with_update_route_response<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateRouteResponseRequest,
+            ) -> ::std::result::Result<
+                UpdateRouteResponseResponse,
+                RusotoError<UpdateRouteResponseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_route_response = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_stage` with what `handler` returns for its input.
+    pub fn with_update_stage<F>(mut self, handler: F) -> MockApiGatewayV2
+    where
+        F: Fn(
+                UpdateStageRequest,
+            )
+                -> ::std::result::Result<UpdateStageResponse, RusotoError<UpdateStageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_stage = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ApiGatewayV2 for MockApiGatewayV2 {
+    fn create_api(
+        &self,
+        input: CreateApiRequest,
+    ) -> RusotoFuture<CreateApiResponse, CreateApiError> {
+        match self.create_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_api"),
+        }
+    }
+    fn create_api_mapping(
+        &self,
+        input: CreateApiMappingRequest,
+    ) -> RusotoFuture<CreateApiMappingResponse, CreateApiMappingError> {
+        match self.create_api_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_api_mapping"),
+        }
+    }
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<CreateAuthorizerResponse, CreateAuthorizerError> {
+        match self.create_authorizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_authorizer"),
+        }
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
+        match self.create_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_deployment"),
+        }
+    }
+    fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> RusotoFuture<CreateDomainNameResponse, CreateDomainNameError> {
+        match self.create_domain_name {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_domain_name"),
+        }
+    }
+    fn create_integration(
+        &self,
+        input: CreateIntegrationRequest,
+    ) -> RusotoFuture<CreateIntegrationResponse, CreateIntegrationError> {
+        match self.create_integration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_integration"),
+        }
+    }
+    fn create_integration_response(
+        &self,
+        input: CreateIntegrationResponseRequest,
+    ) -> RusotoFuture<CreateIntegrationResponseResponse, CreateIntegrationResponseError> {
+        match self.create_integration_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_integration_response"),
+        }
+    }
+    fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> RusotoFuture<CreateModelResponse, CreateModelError> {
+        match self.create_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_model"),
+        }
+    }
+    fn create_route(
+        &self,
+        input: CreateRouteRequest,
+    ) -> RusotoFuture<CreateRouteResponse, CreateRouteError> {
+        match self.create_route {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_route"),
+        }
+    }
+    fn create_route_response(
+        &self,
+        input: CreateRouteResponseRequest,
+    ) -> RusotoFuture<CreateRouteResponseResponse, CreateRouteResponseError> {
+        match self.create_route_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_route_response"),
+        }
+    }
+    fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> RusotoFuture<CreateStageResponse, CreateStageError> {
+        match self.create_stage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for create_stage"),
+        }
+    }
+    fn delete_api(&self, input: DeleteApiRequest) -> RusotoFuture<(), DeleteApiError> {
+        match self.delete_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_api"),
+        }
+    }
+    fn delete_api_mapping(
+        &self,
+        input: DeleteApiMappingRequest,
+    ) -> RusotoFuture<(), DeleteApiMappingError> {
+        match self.delete_api_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_api_mapping"),
+        }
+    }
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<(), DeleteAuthorizerError> {
+        match self.delete_authorizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_authorizer"),
+        }
+    }
+    fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> RusotoFuture<(), DeleteDeploymentError> {
+        match self.delete_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_deployment"),
+        }
+    }
+    fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> RusotoFuture<(), DeleteDomainNameError> {
+        match self.delete_domain_name {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_domain_name"),
+        }
+    }
+    fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationError> {
+        match self.delete_integration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_integration"),
+        }
+    }
+    fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
+        match self.delete_integration_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_integration_response"),
+        }
+    }
+    fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
+        match self.delete_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_model"),
+        }
+    }
+    fn delete_route(&self, input: DeleteRouteRequest) -> RusotoFuture<(), DeleteRouteError> {
+        match self.delete_route {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_route"),
+        }
+    }
+    fn delete_route_response(
+        &self,
+        input: DeleteRouteResponseRequest,
+    ) -> RusotoFuture<(), DeleteRouteResponseError> {
+        match self.delete_route_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_route_response"),
+        }
+    }
+    fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
+        match self.delete_stage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for delete_stage"),
+        }
+    }
+    fn get_api(&self, input: GetApiRequest) -> RusotoFuture<GetApiResponse, GetApiError> {
+        match self.get_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_api"),
+        }
+    }
+    fn get_api_mapping(
+        &self,
+        input: GetApiMappingRequest,
+    ) -> RusotoFuture<GetApiMappingResponse, GetApiMappingError> {
+        match self.get_api_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_api_mapping"),
+        }
+    }
+    fn get_api_mappings(
+        &self,
+        input: GetApiMappingsRequest,
+    ) -> RusotoFuture<GetApiMappingsResponse, GetApiMappingsError> {
+        match self.get_api_mappings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_api_mappings"),
+        }
+    }
+    fn get_apis(&self, input: GetApisRequest) -> RusotoFuture<GetApisResponse, GetApisError> {
+        match self.get_apis {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_apis"),
+        }
+    }
+    fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> RusotoFuture<GetAuthorizerResponse, GetAuthorizerError> {
+        match self.get_authorizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_authorizer"),
+        }
+    }
+    fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> RusotoFuture<GetAuthorizersResponse, GetAuthorizersError> {
+        match self.get_authorizers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_authorizers"),
+        }
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> RusotoFuture<GetDeploymentResponse, GetDeploymentError> {
+        match self.get_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_deployment"),
+        }
+    }
+    fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> RusotoFuture<GetDeploymentsResponse, GetDeploymentsError> {
+        match self.get_deployments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_deployments"),
+        }
+    }
+    fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> RusotoFuture<GetDomainNameResponse, GetDomainNameError> {
+        match self.get_domain_name {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_domain_name"),
+        }
+    }
+    fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> RusotoFuture<GetDomainNamesResponse, GetDomainNamesError> {
+        match self.get_domain_names {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_domain_names"),
+        }
+    }
+    fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> RusotoFuture<GetIntegrationResponse, GetIntegrationError> {
+        match self.get_integration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_integration"),
+        }
+    }
+    fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> RusotoFuture<GetIntegrationResponseResponse, GetIntegrationResponseError> {
+        match self.get_integration_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_integration_response"),
+        }
+    }
+    fn get_integration_responses(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> RusotoFuture<GetIntegrationResponsesResponse, GetIntegrationResponsesError> {
+        match self.get_integration_responses {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_integration_responses"),
+        }
+    }
+    fn get_integrations(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> RusotoFuture<GetIntegrationsResponse, GetIntegrationsError> {
+        match self.get_integrations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_integrations"),
+        }
+    }
+    fn get_model(&self, input: GetModelRequest) -> RusotoFuture<GetModelResponse, GetModelError> {
+        match self.get_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_model"),
+        }
+    }
+    fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> RusotoFuture<GetModelTemplateResponse, GetModelTemplateError> {
+        match self.get_model_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_model_template"),
+        }
+    }
+    fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> RusotoFuture<GetModelsResponse, GetModelsError> {
+        match self.get_models {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_models"),
+        }
+    }
+    fn get_route(&self, input: GetRouteRequest) -> RusotoFuture<GetRouteResponse, GetRouteError> {
+        match self.get_route {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_route"),
+        }
+    }
+    fn get_route_response(
+        &self,
+        input: GetRouteResponseRequest,
+    ) -> RusotoFuture<GetRouteResponseResponse, GetRouteResponseError> {
+        match self.get_route_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_route_response"),
+        }
+    }
+    fn get_route_responses(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> RusotoFuture<GetRouteResponsesResponse, GetRouteResponsesError> {
+        match self.get_route_responses {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_route_responses"),
+        }
+    }
+    fn get_routes(
+        &self,
+        input: GetRoutesRequest,
+    ) -> RusotoFuture<GetRoutesResponse, GetRoutesError> {
+        match self.get_routes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_routes"),
+        }
+    }
+    fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<GetStageResponse, GetStageError> {
+        match self.get_stage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_stage"),
+        }
+    }
+    fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> RusotoFuture<GetStagesResponse, GetStagesError> {
+        match self.get_stages {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_stages"),
+        }
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        match self.get_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for get_tags"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for untag_resource"),
+        }
+    }
+    fn update_api(
+        &self,
+        input: UpdateApiRequest,
+    ) -> RusotoFuture<UpdateApiResponse, UpdateApiError> {
+        match self.update_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_api"),
+        }
+    }
+    fn update_api_mapping(
+        &self,
+        input: UpdateApiMappingRequest,
+    ) -> RusotoFuture<UpdateApiMappingResponse, UpdateApiMappingError> {
+        match self.update_api_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_api_mapping"),
+        }
+    }
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<UpdateAuthorizerResponse, UpdateAuthorizerError> {
+        match self.update_authorizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_authorizer"),
+        }
     }
-
-    /// <p>Updates a Deployment.</p>
     fn update_deployment(
         &self,
         input: UpdateDeploymentRequest,
     ) -> RusotoFuture<UpdateDeploymentResponse, UpdateDeploymentError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/deployments/{deployment_id}",
-            api_id = input.api_id,
-            deployment_id = input.deployment_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDeploymentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDeploymentError::from_response(response))),
-                )
-            }
-        })
+        match self.update_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_deployment"),
+        }
     }
-
-    /// <p>Updates a domain name.</p>
     fn update_domain_name(
         &self,
         input: UpdateDomainNameRequest,
     ) -> RusotoFuture<UpdateDomainNameResponse, UpdateDomainNameError> {
-        let request_uri = format!(
-            "/v2/domainnames/{domain_name}",
-            domain_name = input.domain_name
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDomainNameResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDomainNameError::from_response(response))),
-                )
-            }
-        })
+        match self.update_domain_name {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_domain_name"),
+        }
     }
-
-    /// <p>Updates an Integration.</p>
     fn update_integration(
         &self,
         input: UpdateIntegrationRequest,
     ) -> RusotoFuture<UpdateIntegrationResponse, UpdateIntegrationError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/integrations/{integration_id}",
-            api_id = input.api_id,
-            integration_id = input.integration_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateIntegrationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateIntegrationError::from_response(response))),
-                )
-            }
-        })
+        match self.update_integration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_integration"),
+        }
     }
-
-    /// <p>Updates an IntegrationResponses.</p>
     fn update_integration_response(
         &self,
         input: UpdateIntegrationResponseRequest,
     ) -> RusotoFuture<UpdateIntegrationResponseResponse, UpdateIntegrationResponseError> {
-        let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateIntegrationResponseResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateIntegrationResponseError::from_response(response))
-                }))
-            }
-        })
+        match self.update_integration_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_integration_response"),
+        }
     }
-
-    /// <p>Updates a Model.</p>
     fn update_model(
         &self,
         input: UpdateModelRequest,
     ) -> RusotoFuture<UpdateModelResponse, UpdateModelError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/models/{model_id}",
-            api_id = input.api_id,
-            model_id = input.model_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateModelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateModelError::from_response(response))),
-                )
-            }
-        })
+        match self.update_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_model"),
+        }
     }
-
-    /// <p>Updates a Route.</p>
     fn update_route(
         &self,
         input: UpdateRouteRequest,
     ) -> RusotoFuture<UpdateRouteResponse, UpdateRouteError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/routes/{route_id}",
-            api_id = input.api_id,
-            route_id = input.route_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRouteResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateRouteError::from_response(response))),
-                )
-            }
-        })
+        match self.update_route {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_route"),
+        }
     }
-
-    /// <p>Updates a RouteResponse.</p>
     fn update_route_response(
         &self,
         input: UpdateRouteResponseRequest,
     ) -> RusotoFuture<UpdateRouteResponseResponse, UpdateRouteResponseError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/routes/{route_id}/routeresponses/{route_response_id}",
-            api_id = input.api_id,
-            route_id = input.route_id,
-            route_response_id = input.route_response_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRouteResponseResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateRouteResponseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_route_response {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_route_response"),
+        }
     }
-
-    /// <p>Updates a Stage.</p>
     fn update_stage(
         &self,
         input: UpdateStageRequest,
     ) -> RusotoFuture<UpdateStageResponse, UpdateStageError> {
-        let request_uri = format!(
-            "/v2/apis/{api_id}/stages/{stage_name}",
-            api_id = input.api_id,
-            stage_name = input.stage_name
-        );
-
-        let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateStageResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateStageError::from_response(response))),
-                )
-            }
-        })
+        match self.update_stage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApiGatewayV2 has no handler for update_stage"),
+        }
     }
 }
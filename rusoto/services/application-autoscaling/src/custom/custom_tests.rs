@@ -5,6 +5,16 @@ use crate::generated::*;
 use self::rusoto_mock::*;
 use rusoto_core::Region;
 
+// lengths are checked before requests are sent, so the resource has to be named
+fn register_scalable_target_request() -> RegisterScalableTargetRequest {
+    RegisterScalableTargetRequest {
+        resource_id: "service/default/sample-webapp".to_owned(),
+        scalable_dimension: "ecs:service:DesiredCount".to_owned(),
+        service_namespace: "ecs".to_owned(),
+        ..Default::default()
+    }
+}
+
 #[test]
 // regression test for #1002
 fn register_scalable_target_happy_path() {
@@ -13,7 +23,9 @@ fn register_scalable_target_happy_path() {
 
     let client =
         ApplicationAutoScalingClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
-    let result = client.register_scalable_target(Default::default()).sync();
+    let result = client
+        .register_scalable_target(register_scalable_target_request())
+        .sync();
 
     result.expect("Couldn't parse register_scalable_target");
 }
@@ -26,7 +38,9 @@ fn register_scalable_target_returs_empty_body() {
 
     let client =
         ApplicationAutoScalingClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
-    let result = client.register_scalable_target(Default::default()).sync();
+    let result = client
+        .register_scalable_target(register_scalable_target_request())
+        .sync();
 
     result.expect("Couldn't parse register_scalable_target");
 }
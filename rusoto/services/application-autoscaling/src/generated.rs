@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Represents a CloudWatch alarm associated with a scaling policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Alarm {
     /// <p>The Amazon Resource Name (ARN) of the alarm.</p>
     #[serde(rename = "AlarmARN")]
@@ -58,6 +59,7 @@ pub struct CustomizedMetricSpecification {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteScalingPolicyRequest {
     /// <p>The name of the scaling policy.</p>
     #[serde(rename = "PolicyName")]
@@ -73,11 +75,39 @@ pub struct DeleteScalingPolicyRequest {
     pub service_namespace: String,
 }
 
+impl Validate for DeleteScalingPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy_name"),
+            self.policy_name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_name"),
+            &self.policy_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteScalingPolicyResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteScheduledActionRequest {
     /// <p><p>The identifier of the resource associated with the scheduled action. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variants - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
@@ -93,11 +123,39 @@ pub struct DeleteScheduledActionRequest {
     pub service_namespace: String,
 }
 
+impl Validate for DeleteScheduledActionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "scheduled_action_name"),
+            self.scheduled_action_name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "scheduled_action_name"),
+            &self.scheduled_action_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteScheduledActionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterScalableTargetRequest {
     /// <p><p>The identifier of the resource associated with the scalable target. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variants - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
@@ -110,11 +168,28 @@ pub struct DeregisterScalableTargetRequest {
     pub service_namespace: String,
 }
 
+impl Validate for DeregisterScalableTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterScalableTargetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScalableTargetsRequest {
     /// <p>The maximum number of scalable targets. This value can be between 1 and 50. The default value is 50.</p> <p>If this parameter is used, the operation returns up to <code>MaxResults</code> results at a time, along with a <code>NextToken</code> value. To get the next set of results, include the <code>NextToken</code> value in a subsequent call. If this parameter is not used, the operation returns up to 50 results and a <code>NextToken</code> value, if applicable.</p>
     #[serde(rename = "MaxResults")]
@@ -137,8 +212,37 @@ pub struct DescribeScalableTargetsRequest {
     pub service_namespace: String,
 }
 
+impl Validate for DescribeScalableTargetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.resource_ids {
+            errors.check_length(
+                &field_path(path, "resource_ids"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1600));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeScalableTargetsResponse {
     /// <p>The token required to get the next set of results. This value is <code>null</code> if there are no more results to return.</p>
     #[serde(rename = "NextToken")]
@@ -151,6 +255,7 @@ pub struct DescribeScalableTargetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScalingActivitiesRequest {
     /// <p>The maximum number of scalable targets. This value can be between 1 and 50. The default value is 50.</p> <p>If this parameter is used, the operation returns up to <code>MaxResults</code> results at a time, along with a <code>NextToken</code> value. To get the next set of results, include the <code>NextToken</code> value in a subsequent call. If this parameter is not used, the operation returns up to 50 results and a <code>NextToken</code> value, if applicable.</p>
     #[serde(rename = "MaxResults")]
@@ -173,8 +278,33 @@ pub struct DescribeScalingActivitiesRequest {
     pub service_namespace: String,
 }
 
+impl Validate for DescribeScalingActivitiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.resource_id {
+            errors.check_length(
+                &field_path(path, "resource_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(
+                &field_path(path, "resource_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeScalingActivitiesResponse {
     /// <p>The token required to get the next set of results. This value is <code>null</code> if there are no more results to return.</p>
     #[serde(rename = "NextToken")]
@@ -187,6 +317,7 @@ pub struct DescribeScalingActivitiesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScalingPoliciesRequest {
     /// <p>The maximum number of scalable targets. This value can be between 1 and 50. The default value is 50.</p> <p>If this parameter is used, the operation returns up to <code>MaxResults</code> results at a time, along with a <code>NextToken</code> value. To get the next set of results, include the <code>NextToken</code> value in a subsequent call. If this parameter is not used, the operation returns up to 50 results and a <code>NextToken</code> value, if applicable.</p>
     #[serde(rename = "MaxResults")]
@@ -213,8 +344,50 @@ pub struct DescribeScalingPoliciesRequest {
     pub service_namespace: String,
 }
 
+impl Validate for DescribeScalingPoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.policy_names {
+            errors.check_length(
+                &field_path(path, "policy_names"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "policy_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1600));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.resource_id {
+            errors.check_length(
+                &field_path(path, "resource_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(
+                &field_path(path, "resource_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeScalingPoliciesResponse {
     /// <p>The token required to get the next set of results. This value is <code>null</code> if there are no more results to return.</p>
     #[serde(rename = "NextToken")]
@@ -227,6 +400,7 @@ pub struct DescribeScalingPoliciesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScheduledActionsRequest {
     /// <p>The maximum number of scheduled action results. This value can be between 1 and 50. The default value is 50.</p> <p>If this parameter is used, the operation returns up to <code>MaxResults</code> results at a time, along with a <code>NextToken</code> value. To get the next set of results, include the <code>NextToken</code> value in a subsequent call. If this parameter is not used, the operation returns up to 50 results and a <code>NextToken</code> value, if applicable.</p>
     #[serde(rename = "MaxResults")]
@@ -253,8 +427,50 @@ pub struct DescribeScheduledActionsRequest {
     pub service_namespace: String,
 }
 
+impl Validate for DescribeScheduledActionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.resource_id {
+            errors.check_length(
+                &field_path(path, "resource_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(
+                &field_path(path, "resource_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.scheduled_action_names {
+            errors.check_length(
+                &field_path(path, "scheduled_action_names"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "scheduled_action_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1600));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeScheduledActionsResponse {
     /// <p>The token required to get the next set of results. This value is <code>null</code> if there are no more results to return.</p>
     #[serde(rename = "NextToken")]
@@ -289,7 +505,21 @@ pub struct PredefinedMetricSpecification {
     pub resource_label: Option<String>,
 }
 
+impl Validate for PredefinedMetricSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_label {
+            errors.check_length(
+                &field_path(path, "resource_label"),
+                value.chars().count(),
+                Some(1),
+                Some(1023),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutScalingPolicyRequest {
     /// <p>The name of the scaling policy.</p>
     #[serde(rename = "PolicyName")]
@@ -318,8 +548,41 @@ pub struct PutScalingPolicyRequest {
         Option<TargetTrackingScalingPolicyConfiguration>,
 }
 
+impl Validate for PutScalingPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy_name"),
+            self.policy_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_name"),
+            &self.policy_name,
+            "\\p{Print}+",
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.target_tracking_scaling_policy_configuration {
+            value.validate_at(
+                &field_path(path, "target_tracking_scaling_policy_configuration"),
+                errors,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutScalingPolicyResponse {
     /// <p>The CloudWatch alarms created for the target tracking scaling policy.</p>
     #[serde(rename = "Alarms")]
@@ -331,6 +594,7 @@ pub struct PutScalingPolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutScheduledActionRequest {
     /// <p>The date and time for the scheduled action to end.</p>
     #[serde(rename = "EndTime")]
@@ -362,11 +626,52 @@ pub struct PutScheduledActionRequest {
     pub start_time: Option<f64>,
 }
 
+impl Validate for PutScheduledActionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.schedule {
+            errors.check_length(
+                &field_path(path, "schedule"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(
+                &field_path(path, "schedule"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "scheduled_action_name"),
+            self.scheduled_action_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "scheduled_action_name"),
+            &self.scheduled_action_name,
+            "(?!((^[ ]+.*)|(.*([\\u0000-\\u001f]|[\\u007f-\\u009f]|[:/|])+.*)|(.*[ ]+$))).+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutScheduledActionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterScalableTargetRequest {
     /// <p>The maximum value to scale to in response to a scale-out event. <code>MaxCapacity</code> is required to register a scalable target.</p>
     #[serde(rename = "MaxCapacity")]
@@ -395,13 +700,42 @@ pub struct RegisterScalableTargetRequest {
     pub suspended_state: Option<SuspendedState>,
 }
 
+impl Validate for RegisterScalableTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterScalableTargetResponse {}
 
 /// <p>Represents a scalable target.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScalableTarget {
     /// <p>The Unix timestamp for when the scalable target was created.</p>
     #[serde(rename = "CreationTime")]
@@ -444,7 +778,7 @@ pub struct ScalableTargetAction {
 
 /// <p>Represents a scaling activity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScalingActivity {
     /// <p>The unique identifier of the scaling activity.</p>
     #[serde(rename = "ActivityId")]
@@ -486,7 +820,7 @@ pub struct ScalingActivity {
 
 /// <p>Represents a scaling policy to use with Application Auto Scaling.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScalingPolicy {
     /// <p>The CloudWatch alarms associated with the scaling policy.</p>
     #[serde(rename = "Alarms")]
@@ -526,7 +860,7 @@ pub struct ScalingPolicy {
 
 /// <p>Represents a scheduled action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScheduledAction {
     /// <p>The date and time that the scheduled action was created.</p>
     #[serde(rename = "CreationTime")]
@@ -650,6 +984,14 @@ pub struct TargetTrackingScalingPolicyConfiguration {
     pub target_value: f64,
 }
 
+impl Validate for TargetTrackingScalingPolicyConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.predefined_metric_specification {
+            value.validate_at(&field_path(path, "predefined_metric_specification"), errors);
+        }
+    }
+}
+
 /// Errors returned by DeleteScalingPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteScalingPolicyError {
@@ -676,7 +1018,12 @@ impl DeleteScalingPolicyError {
                 "ObjectNotFoundException" => {
                     return RusotoError::Service(DeleteScalingPolicyError::ObjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -727,7 +1074,12 @@ impl DeleteScheduledActionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -778,7 +1130,12 @@ impl DeregisterScalableTargetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -829,7 +1186,12 @@ impl DescribeScalableTargetsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -880,7 +1242,12 @@ impl DescribeScalingActivitiesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -938,7 +1305,12 @@ impl DescribeScalingPoliciesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -990,7 +1362,12 @@ impl DescribeScheduledActionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1047,7 +1424,12 @@ impl PutScalingPolicyError {
                 "ObjectNotFoundException" => {
                     return RusotoError::Service(PutScalingPolicyError::ObjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1099,7 +1481,12 @@ impl PutScheduledActionError {
                 "ObjectNotFoundException" => {
                     return RusotoError::Service(PutScheduledActionError::ObjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1151,7 +1538,12 @@ impl RegisterScalableTargetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1234,6 +1626,193 @@ pub trait ApplicationAutoScaling {
         input: RegisterScalableTargetRequest,
     ) -> RusotoFuture<RegisterScalableTargetResponse, RegisterScalableTargetError>;
 }
+const _: Option<&dyn ApplicationAutoScaling> = None;
+impl<'a, T: ApplicationAutoScaling + ?Sized> ApplicationAutoScaling for &'a T {
+    fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyRequest,
+    ) -> RusotoFuture<DeleteScalingPolicyResponse, DeleteScalingPolicyError> {
+        (**self).delete_scaling_policy(input)
+    }
+    fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionRequest,
+    ) -> RusotoFuture<DeleteScheduledActionResponse, DeleteScheduledActionError> {
+        (**self).delete_scheduled_action(input)
+    }
+    fn deregister_scalable_target(
+        &self,
+        input: DeregisterScalableTargetRequest,
+    ) -> RusotoFuture<DeregisterScalableTargetResponse, DeregisterScalableTargetError> {
+        (**self).deregister_scalable_target(input)
+    }
+    fn describe_scalable_targets(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> RusotoFuture<DescribeScalableTargetsResponse, DescribeScalableTargetsError> {
+        (**self).describe_scalable_targets(input)
+    }
+    fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> RusotoFuture<DescribeScalingActivitiesResponse, DescribeScalingActivitiesError> {
+        (**self).describe_scaling_activities(input)
+    }
+    fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> RusotoFuture<DescribeScalingPoliciesResponse, DescribeScalingPoliciesError> {
+        (**self).describe_scaling_policies(input)
+    }
+    fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> RusotoFuture<DescribeScheduledActionsResponse, DescribeScheduledActionsError> {
+        (**self).describe_scheduled_actions(input)
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyRequest,
+    ) -> RusotoFuture<PutScalingPolicyResponse, PutScalingPolicyError> {
+        (**self).put_scaling_policy(input)
+    }
+    fn put_scheduled_action(
+        &self,
+        input: PutScheduledActionRequest,
+    ) -> RusotoFuture<PutScheduledActionResponse, PutScheduledActionError> {
+        (**self).put_scheduled_action(input)
+    }
+    fn register_scalable_target(
+        &self,
+        input: RegisterScalableTargetRequest,
+    ) -> RusotoFuture<RegisterScalableTargetResponse, RegisterScalableTargetError> {
+        (**self).register_scalable_target(input)
+    }
+}
+impl<T: ApplicationAutoScaling + ?Sized> ApplicationAutoScaling for Box<T> {
+    fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyRequest,
+    ) -> RusotoFuture<DeleteScalingPolicyResponse, DeleteScalingPolicyError> {
+        (**self).delete_scaling_policy(input)
+    }
+    fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionRequest,
+    ) -> RusotoFuture<DeleteScheduledActionResponse, DeleteScheduledActionError> {
+        (**self).delete_scheduled_action(input)
+    }
+    fn deregister_scalable_target(
+        &self,
+        input: DeregisterScalableTargetRequest,
+    ) -> RusotoFuture<DeregisterScalableTargetResponse, DeregisterScalableTargetError> {
+        (**self).deregister_scalable_target(input)
+    }
+    fn describe_scalable_targets(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> RusotoFuture<DescribeScalableTargetsResponse, DescribeScalableTargetsError> {
+        (**self).describe_scalable_targets(input)
+    }
+    fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> RusotoFuture<DescribeScalingActivitiesResponse, DescribeScalingActivitiesError> {
+        (**self).describe_scaling_activities(input)
+    }
+    fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> RusotoFuture<DescribeScalingPoliciesResponse, DescribeScalingPoliciesError> {
+        (**self).describe_scaling_policies(input)
+    }
+    fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> RusotoFuture<DescribeScheduledActionsResponse, DescribeScheduledActionsError> {
+        (**self).describe_scheduled_actions(input)
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyRequest,
+    ) -> RusotoFuture<PutScalingPolicyResponse, PutScalingPolicyError> {
+        (**self).put_scaling_policy(input)
+    }
+    fn put_scheduled_action(
+        &self,
+        input: PutScheduledActionRequest,
+    ) -> RusotoFuture<PutScheduledActionResponse, PutScheduledActionError> {
+        (**self).put_scheduled_action(input)
+    }
+    fn register_scalable_target(
+        &self,
+        input: RegisterScalableTargetRequest,
+    ) -> RusotoFuture<RegisterScalableTargetResponse, RegisterScalableTargetError> {
+        (**self).register_scalable_target(input)
+    }
+}
+impl<T: ApplicationAutoScaling + ?Sized> ApplicationAutoScaling for ::std::sync::Arc<T> {
+    fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyRequest,
+    ) -> RusotoFuture<DeleteScalingPolicyResponse, DeleteScalingPolicyError> {
+        (**self).delete_scaling_policy(input)
+    }
+    fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionRequest,
+    ) -> RusotoFuture<DeleteScheduledActionResponse, DeleteScheduledActionError> {
+        (**self).delete_scheduled_action(input)
+    }
+    fn deregister_scalable_target(
+        &self,
+        input: DeregisterScalableTargetRequest,
+    ) -> RusotoFuture<DeregisterScalableTargetResponse, DeregisterScalableTargetError> {
+        (**self).deregister_scalable_target(input)
+    }
+    fn describe_scalable_targets(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> RusotoFuture<DescribeScalableTargetsResponse, DescribeScalableTargetsError> {
+        (**self).describe_scalable_targets(input)
+    }
+    fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> RusotoFuture<DescribeScalingActivitiesResponse, DescribeScalingActivitiesError> {
+        (**self).describe_scaling_activities(input)
+    }
+    fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> RusotoFuture<DescribeScalingPoliciesResponse, DescribeScalingPoliciesError> {
+        (**self).describe_scaling_policies(input)
+    }
+    fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> RusotoFuture<DescribeScheduledActionsResponse, DescribeScheduledActionsError> {
+        (**self).describe_scheduled_actions(input)
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyRequest,
+    ) -> RusotoFuture<PutScalingPolicyResponse, PutScalingPolicyError> {
+        (**self).put_scaling_policy(input)
+    }
+    fn put_scheduled_action(
+        &self,
+        input: PutScheduledActionRequest,
+    ) -> RusotoFuture<PutScheduledActionResponse, PutScheduledActionError> {
+        (**self).put_scheduled_action(input)
+    }
+    fn register_scalable_target(
+        &self,
+        input: RegisterScalableTargetRequest,
+    ) -> RusotoFuture<RegisterScalableTargetResponse, RegisterScalableTargetError> {
+        (**self).register_scalable_target(input)
+    }
+}
 /// A client for the Application Auto Scaling API.
 #[derive(Clone)]
 pub struct ApplicationAutoScalingClient {
@@ -1277,7 +1856,16 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         &self,
         input: DeleteScalingPolicyRequest,
     ) -> RusotoFuture<DeleteScalingPolicyResponse, DeleteScalingPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DeleteScalingPolicy");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1289,10 +1877,10 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteScalingPolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteScalingPolicyResponse, _>(
+                    response,
+                    "DeleteScalingPolicy",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1308,7 +1896,16 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         &self,
         input: DeleteScheduledActionRequest,
     ) -> RusotoFuture<DeleteScheduledActionResponse, DeleteScheduledActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DeleteScheduledAction");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1320,10 +1917,10 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteScheduledActionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteScheduledActionResponse, _>(
+                    response,
+                    "DeleteScheduledAction",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1339,7 +1936,16 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         &self,
         input: DeregisterScalableTargetRequest,
     ) -> RusotoFuture<DeregisterScalableTargetResponse, DeregisterScalableTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DeregisterScalableTarget");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1351,10 +1957,10 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterScalableTargetResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeregisterScalableTargetResponse, _>(
+                    response,
+                    "DeregisterScalableTarget",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DeregisterScalableTargetError::from_response(response))
@@ -1368,7 +1974,16 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         &self,
         input: DescribeScalableTargetsRequest,
     ) -> RusotoFuture<DescribeScalableTargetsResponse, DescribeScalableTargetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalableTargets");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1380,10 +1995,10 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeScalableTargetsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeScalableTargetsResponse, _>(
+                    response,
+                    "DescribeScalableTargets",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeScalableTargetsError::from_response(response))
@@ -1397,7 +2012,16 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         &self,
         input: DescribeScalingActivitiesRequest,
     ) -> RusotoFuture<DescribeScalingActivitiesResponse, DescribeScalingActivitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingActivities");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1409,10 +2033,10 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeScalingActivitiesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeScalingActivitiesResponse, _>(
+                    response,
+                    "DescribeScalingActivities",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeScalingActivitiesError::from_response(response))
@@ -1426,7 +2050,16 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         &self,
         input: DescribeScalingPoliciesRequest,
     ) -> RusotoFuture<DescribeScalingPoliciesResponse, DescribeScalingPoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingPolicies");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1438,10 +2071,10 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeScalingPoliciesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeScalingPoliciesResponse, _>(
+                    response,
+                    "DescribeScalingPolicies",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeScalingPoliciesError::from_response(response))
@@ -1455,7 +2088,16 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         &self,
         input: DescribeScheduledActionsRequest,
     ) -> RusotoFuture<DescribeScheduledActionsResponse, DescribeScheduledActionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScheduledActions");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1467,10 +2109,10 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeScheduledActionsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeScheduledActionsResponse, _>(
+                    response,
+                    "DescribeScheduledActions",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeScheduledActionsError::from_response(response))
@@ -1484,7 +2126,16 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         &self,
         input: PutScalingPolicyRequest,
     ) -> RusotoFuture<PutScalingPolicyResponse, PutScalingPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("PutScalingPolicy");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AnyScaleFrontendService.PutScalingPolicy");
@@ -1493,10 +2144,10 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutScalingPolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<PutScalingPolicyResponse, _>(
+                    response,
+                    "PutScalingPolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -1513,7 +2164,16 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         &self,
         input: PutScheduledActionRequest,
     ) -> RusotoFuture<PutScheduledActionResponse, PutScheduledActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("PutScheduledAction");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AnyScaleFrontendService.PutScheduledAction");
@@ -1522,10 +2182,10 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutScheduledActionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<PutScheduledActionResponse, _>(
+                    response,
+                    "PutScheduledAction",
+                )
             } else {
                 Box::new(
                     response
@@ -1542,7 +2202,16 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         &self,
         input: RegisterScalableTargetRequest,
     ) -> RusotoFuture<RegisterScalableTargetResponse, RegisterScalableTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("RegisterScalableTarget");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1554,10 +2223,10 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterScalableTargetResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RegisterScalableTargetResponse, _>(
+                    response,
+                    "RegisterScalableTarget",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1568,3 +2237,387 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         })
     }
 }
+
+/// A mock of the Application Auto Scaling API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockApplicationAutoScaling {
+    delete_scaling_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteScalingPolicyRequest,
+                ) -> ::std::result::Result<
+                    DeleteScalingPolicyResponse,
+                    RusotoError<DeleteScalingPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_scheduled_action: Option<
+        Box<
+            dyn Fn(
+                    DeleteScheduledActionRequest,
+                ) -> ::std::result::Result<
+                    DeleteScheduledActionResponse,
+                    RusotoError<DeleteScheduledActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deregister_scalable_target: Option<
+        Box<
+            dyn Fn(
+                    DeregisterScalableTargetRequest,
+                ) -> ::std::result::Result<
+                    DeregisterScalableTargetResponse,
+                    RusotoError<DeregisterScalableTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_scalable_targets: Option<
+        Box<
+            dyn Fn(
+                    DescribeScalableTargetsRequest,
+                ) -> ::std::result::Result<
+                    DescribeScalableTargetsResponse,
+                    RusotoError<DescribeScalableTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_scaling_activities: Option<
+        Box<
+            dyn Fn(
+                    DescribeScalingActivitiesRequest,
+                ) -> ::std::result::Result<
+                    DescribeScalingActivitiesResponse,
+                    RusotoError<DescribeScalingActivitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_scaling_policies: Option<
+        Box<
+            dyn Fn(
+                    DescribeScalingPoliciesRequest,
+                ) -> ::std::result::Result<
+                    DescribeScalingPoliciesResponse,
+                    RusotoError<DescribeScalingPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_scheduled_actions: Option<
+        Box<
+            dyn Fn(
+                    DescribeScheduledActionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeScheduledActionsResponse,
+                    RusotoError<DescribeScheduledActionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_scaling_policy: Option<
+        Box<
+            dyn Fn(
+                    PutScalingPolicyRequest,
+                ) -> ::std::result::Result<
+                    PutScalingPolicyResponse,
+                    RusotoError<PutScalingPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_scheduled_action: Option<
+        Box<
+            dyn Fn(
+                    PutScheduledActionRequest,
+                ) -> ::std::result::Result<
+                    PutScheduledActionResponse,
+                    RusotoError<PutScheduledActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_scalable_target: Option<
+        Box<
+            dyn Fn(
+                    RegisterScalableTargetRequest,
+                ) -> ::std::result::Result<
+                    RegisterScalableTargetResponse,
+                    RusotoError<RegisterScalableTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockApplicationAutoScaling {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockApplicationAutoScaling {
+        Default::default()
+    }
+
+    /// Answers `delete_scaling_policy` with what `handler` returns for its input.
+    pub fn with_delete_scaling_policy<F>(mut self, handler: F) -> MockApplicationAutoScaling
+    where
+        F: Fn(
+                DeleteScalingPolicyRequest,
+            ) -> ::std::result::Result<
+                DeleteScalingPolicyResponse,
+                RusotoError<DeleteScalingPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_scaling_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_scheduled_action` with what `handler` returns for its input.
+    pub fn with_delete_scheduled_action<F>(mut self, handler: F) -> MockApplicationAutoScaling
+    where
+        F: Fn(
+                DeleteScheduledActionRequest,
+            ) -> ::std::result::Result<
+                DeleteScheduledActionResponse,
+                RusotoError<DeleteScheduledActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_scheduled_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_scalable_target` with what `handler` returns for its input.
+    pub fn with_deregister_scalable_target<F>(mut self, handler: F) -> MockApplicationAutoScaling
+    where
+        F: Fn(
+                DeregisterScalableTargetRequest,
+            ) -> ::std::result::Result<
+                DeregisterScalableTargetResponse,
+                RusotoError<DeregisterScalableTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_scalable_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scalable_targets` with what `handler` returns for its input.
+    pub fn with_describe_scalable_targets<F>(mut self, handler: F) -> MockApplicationAutoScaling
+    where
+        F: Fn(
+                DescribeScalableTargetsRequest,
+            ) -> ::std::result::Result<
+                DescribeScalableTargetsResponse,
+                RusotoError<DescribeScalableTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scalable_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scaling_activities` with what `handler` returns for its input.
+    pub fn with_describe_scaling_activities<F>(mut self, handler: F) -> MockApplicationAutoScaling
+    where
+        F: Fn(
+                DescribeScalingActivitiesRequest,
+            ) -> ::std::result::Result<
+                DescribeScalingActivitiesResponse,
+                RusotoError<DescribeScalingActivitiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scaling_activities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scaling_policies` with what `handler` returns for its input.
+    pub fn with_describe_scaling_policies<F>(mut self, handler: F) -> MockApplicationAutoScaling
+    where
+        F: Fn(
+                DescribeScalingPoliciesRequest,
+            ) -> ::std::result::Result<
+                DescribeScalingPoliciesResponse,
+                RusotoError<DescribeScalingPoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scaling_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scheduled_actions` with what `handler` returns for its input.
+    pub fn with_describe_scheduled_actions<F>(mut self, handler: F) -> MockApplicationAutoScaling
+    where
+        F: Fn(
+                DescribeScheduledActionsRequest,
+            ) -> ::std::result::Result<
+                DescribeScheduledActionsResponse,
+                RusotoError<DescribeScheduledActionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scheduled_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_scaling_policy` with what `handler` returns for its input.
+    pub fn with_put_scaling_policy<F>(mut self, handler: F) -> MockApplicationAutoScaling
+    where
+        F: Fn(
+                PutScalingPolicyRequest,
+            ) -> ::std::result::Result<
+                PutScalingPolicyResponse,
+                RusotoError<PutScalingPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_scaling_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_scheduled_action` with what `handler` returns for its input.
+    pub fn with_put_scheduled_action<F>(mut self, handler: F) -> MockApplicationAutoScaling
+    where
+        F: Fn(
+                PutScheduledActionRequest,
+            ) -> ::std::result::Result<
+                PutScheduledActionResponse,
+                RusotoError<PutScheduledActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_scheduled_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_scalable_target` with what `handler` returns for its input.
+    pub fn with_register_scalable_target<F>(mut self, handler: F) -> MockApplicationAutoScaling
+    where
+        F: Fn(
+                RegisterScalableTargetRequest,
+            ) -> ::std::result::Result<
+                RegisterScalableTargetResponse,
+                RusotoError<RegisterScalableTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_scalable_target = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ApplicationAutoScaling for MockApplicationAutoScaling {
+    fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyRequest,
+    ) -> RusotoFuture<DeleteScalingPolicyResponse, DeleteScalingPolicyError> {
+        match self.delete_scaling_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApplicationAutoScaling has no handler for delete_scaling_policy"),
+        }
+    }
+    fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionRequest,
+    ) -> RusotoFuture<DeleteScheduledActionResponse, DeleteScheduledActionError> {
+        match self.delete_scheduled_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApplicationAutoScaling has no handler for delete_scheduled_action"),
+        }
+    }
+    fn deregister_scalable_target(
+        &self,
+        input: DeregisterScalableTargetRequest,
+    ) -> RusotoFuture<DeregisterScalableTargetResponse, DeregisterScalableTargetError> {
+        match self.deregister_scalable_target {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockApplicationAutoScaling has no handler for deregister_scalable_target")
+            }
+        }
+    }
+    fn describe_scalable_targets(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> RusotoFuture<DescribeScalableTargetsResponse, DescribeScalableTargetsError> {
+        match self.describe_scalable_targets {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockApplicationAutoScaling has no handler for describe_scalable_targets")
+            }
+        }
+    }
+    fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> RusotoFuture<DescribeScalingActivitiesResponse, DescribeScalingActivitiesError> {
+        match self.describe_scaling_activities {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockApplicationAutoScaling has no handler for describe_scaling_activities")
+            }
+        }
+    }
+    fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> RusotoFuture<DescribeScalingPoliciesResponse, DescribeScalingPoliciesError> {
+        match self.describe_scaling_policies {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockApplicationAutoScaling has no handler for describe_scaling_policies")
+            }
+        }
+    }
+    fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> RusotoFuture<DescribeScheduledActionsResponse, DescribeScheduledActionsError> {
+        match self.describe_scheduled_actions {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockApplicationAutoScaling has no handler for describe_scheduled_actions")
+            }
+        }
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyRequest,
+    ) -> RusotoFuture<PutScalingPolicyResponse, PutScalingPolicyError> {
+        match self.put_scaling_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApplicationAutoScaling has no handler for put_scaling_policy"),
+        }
+    }
+    fn put_scheduled_action(
+        &self,
+        input: PutScheduledActionRequest,
+    ) -> RusotoFuture<PutScheduledActionResponse, PutScheduledActionError> {
+        match self.put_scheduled_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockApplicationAutoScaling has no handler for put_scheduled_action"),
+        }
+    }
+    fn register_scalable_target(
+        &self,
+        input: RegisterScalableTargetRequest,
+    ) -> RusotoFuture<RegisterScalableTargetResponse, RegisterScalableTargetError> {
+        match self.register_scalable_target {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockApplicationAutoScaling has no handler for register_scalable_target")
+            }
+        }
+    }
+}
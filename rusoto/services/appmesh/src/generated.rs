@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>An object representing the access logging information for a virtual node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -33,6 +34,14 @@ pub struct AccessLog {
     pub file: Option<FileAccessLog>,
 }
 
+impl Validate for AccessLog {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.file {
+            value.validate_at(&field_path(path, "file"), errors);
+        }
+    }
+}
+
 /// <p>An object representing the AWS Cloud Map attribute information for your virtual node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsCloudMapInstanceAttribute {
@@ -46,6 +55,29 @@ pub struct AwsCloudMapInstanceAttribute {
     pub value: String,
 }
 
+impl Validate for AwsCloudMapInstanceAttribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "key"), &self.key, "^[a-zA-Z0-9!-~]+$");
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "^([a-zA-Z0-9!-~][  a-zA-Z0-9!-~]*){0,1}[a-zA-Z0-9!-~]{0,1}$",
+        );
+    }
+}
+
 /// <p>An object representing the AWS Cloud Map service discovery information for your virtual
 /// node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -64,6 +96,29 @@ pub struct AwsCloudMapServiceDiscovery {
     pub service_name: String,
 }
 
+impl Validate for AwsCloudMapServiceDiscovery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "namespace_name"),
+            self.namespace_name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "service_name"),
+            self.service_name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>An object representing the backends that a virtual node is expected to send outbound
 /// traffic to. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -75,6 +130,7 @@ pub struct Backend {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMeshInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the
     /// request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
@@ -97,8 +153,26 @@ pub struct CreateMeshInput {
     pub tags: Option<Vec<TagRef>>,
 }
 
+impl Validate for CreateMeshInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateMeshOutput {
     /// <p>The full description of your service mesh following the create call.</p>
     #[serde(rename = "mesh")]
@@ -106,6 +180,7 @@ pub struct CreateMeshOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRouteInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the
     /// request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
@@ -133,8 +208,39 @@ pub struct CreateRouteInput {
     pub virtual_router_name: String,
 }
 
+impl Validate for CreateRouteInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "route_name"),
+            self.route_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        self.spec.validate_at(&field_path(path, "spec"), errors);
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "virtual_router_name"),
+            self.virtual_router_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRouteOutput {
     /// <p>The full description of your mesh following the create call.</p>
     #[serde(rename = "route")]
@@ -142,6 +248,7 @@ pub struct CreateRouteOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVirtualNodeInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the
     /// request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
@@ -166,8 +273,33 @@ pub struct CreateVirtualNodeInput {
     pub virtual_node_name: String,
 }
 
+impl Validate for CreateVirtualNodeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        self.spec.validate_at(&field_path(path, "spec"), errors);
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "virtual_node_name"),
+            self.virtual_node_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateVirtualNodeOutput {
     /// <p>The full description of your virtual node following the create call.</p>
     #[serde(rename = "virtualNode")]
@@ -175,6 +307,7 @@ pub struct CreateVirtualNodeOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVirtualRouterInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the
     /// request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
@@ -199,8 +332,33 @@ pub struct CreateVirtualRouterInput {
     pub virtual_router_name: String,
 }
 
+impl Validate for CreateVirtualRouterInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        self.spec.validate_at(&field_path(path, "spec"), errors);
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "virtual_router_name"),
+            self.virtual_router_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateVirtualRouterOutput {
     /// <p>The full description of your virtual router following the create call.</p>
     #[serde(rename = "virtualRouter")]
@@ -208,6 +366,7 @@ pub struct CreateVirtualRouterOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVirtualServiceInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the
     /// request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
@@ -232,8 +391,27 @@ pub struct CreateVirtualServiceInput {
     pub virtual_service_name: String,
 }
 
+impl Validate for CreateVirtualServiceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        self.spec.validate_at(&field_path(path, "spec"), errors);
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateVirtualServiceOutput {
     /// <p>The full description of your virtual service following the create call.</p>
     #[serde(rename = "virtualService")]
@@ -241,14 +419,26 @@ pub struct CreateVirtualServiceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMeshInput {
     /// <p>The name of the service mesh to delete.</p>
     #[serde(rename = "meshName")]
     pub mesh_name: String,
 }
 
+impl Validate for DeleteMeshInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteMeshOutput {
     /// <p>The service mesh that was deleted.</p>
     #[serde(rename = "mesh")]
@@ -256,6 +446,7 @@ pub struct DeleteMeshOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRouteInput {
     /// <p>The name of the service mesh to delete the route in.</p>
     #[serde(rename = "meshName")]
@@ -268,8 +459,31 @@ pub struct DeleteRouteInput {
     pub virtual_router_name: String,
 }
 
+impl Validate for DeleteRouteInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "route_name"),
+            self.route_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "virtual_router_name"),
+            self.virtual_router_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRouteOutput {
     /// <p>The route that was deleted.</p>
     #[serde(rename = "route")]
@@ -277,6 +491,7 @@ pub struct DeleteRouteOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVirtualNodeInput {
     /// <p>The name of the service mesh to delete the virtual node in.</p>
     #[serde(rename = "meshName")]
@@ -286,8 +501,25 @@ pub struct DeleteVirtualNodeInput {
     pub virtual_node_name: String,
 }
 
+impl Validate for DeleteVirtualNodeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "virtual_node_name"),
+            self.virtual_node_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteVirtualNodeOutput {
     /// <p>The virtual node that was deleted.</p>
     #[serde(rename = "virtualNode")]
@@ -295,6 +527,7 @@ pub struct DeleteVirtualNodeOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVirtualRouterInput {
     /// <p>The name of the service mesh to delete the virtual router in.</p>
     #[serde(rename = "meshName")]
@@ -304,8 +537,25 @@ pub struct DeleteVirtualRouterInput {
     pub virtual_router_name: String,
 }
 
+impl Validate for DeleteVirtualRouterInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "virtual_router_name"),
+            self.virtual_router_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteVirtualRouterOutput {
     /// <p>The virtual router that was deleted.</p>
     #[serde(rename = "virtualRouter")]
@@ -313,6 +563,7 @@ pub struct DeleteVirtualRouterOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVirtualServiceInput {
     /// <p>The name of the service mesh to delete the virtual service in.</p>
     #[serde(rename = "meshName")]
@@ -322,8 +573,19 @@ pub struct DeleteVirtualServiceInput {
     pub virtual_service_name: String,
 }
 
+impl Validate for DeleteVirtualServiceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteVirtualServiceOutput {
     /// <p>The virtual service that was deleted.</p>
     #[serde(rename = "virtualService")]
@@ -331,14 +593,26 @@ pub struct DeleteVirtualServiceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMeshInput {
     /// <p>The name of the service mesh to describe.</p>
     #[serde(rename = "meshName")]
     pub mesh_name: String,
 }
 
+impl Validate for DescribeMeshInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMeshOutput {
     /// <p>The full description of your service mesh.</p>
     #[serde(rename = "mesh")]
@@ -346,6 +620,7 @@ pub struct DescribeMeshOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRouteInput {
     /// <p>The name of the service mesh that the route resides in.</p>
     #[serde(rename = "meshName")]
@@ -358,8 +633,31 @@ pub struct DescribeRouteInput {
     pub virtual_router_name: String,
 }
 
+impl Validate for DescribeRouteInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "route_name"),
+            self.route_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "virtual_router_name"),
+            self.virtual_router_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRouteOutput {
     /// <p>The full description of your route.</p>
     #[serde(rename = "route")]
@@ -367,6 +665,7 @@ pub struct DescribeRouteOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeVirtualNodeInput {
     /// <p>The name of the service mesh that the virtual node resides in.</p>
     #[serde(rename = "meshName")]
@@ -376,8 +675,25 @@ pub struct DescribeVirtualNodeInput {
     pub virtual_node_name: String,
 }
 
+impl Validate for DescribeVirtualNodeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "virtual_node_name"),
+            self.virtual_node_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeVirtualNodeOutput {
     /// <p>The full description of your virtual node.</p>
     #[serde(rename = "virtualNode")]
@@ -385,6 +701,7 @@ pub struct DescribeVirtualNodeOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeVirtualRouterInput {
     /// <p>The name of the service mesh that the virtual router resides in.</p>
     #[serde(rename = "meshName")]
@@ -394,8 +711,25 @@ pub struct DescribeVirtualRouterInput {
     pub virtual_router_name: String,
 }
 
+impl Validate for DescribeVirtualRouterInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "virtual_router_name"),
+            self.virtual_router_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeVirtualRouterOutput {
     /// <p>The full description of your virtual router.</p>
     #[serde(rename = "virtualRouter")]
@@ -403,6 +737,7 @@ pub struct DescribeVirtualRouterOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeVirtualServiceInput {
     /// <p>The name of the service mesh that the virtual service resides in.</p>
     #[serde(rename = "meshName")]
@@ -412,8 +747,19 @@ pub struct DescribeVirtualServiceInput {
     pub virtual_service_name: String,
 }
 
+impl Validate for DescribeVirtualServiceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeVirtualServiceOutput {
     /// <p>The full description of your virtual service.</p>
     #[serde(rename = "virtualService")]
@@ -472,6 +818,17 @@ pub struct FileAccessLog {
     pub path: String,
 }
 
+impl Validate for FileAccessLog {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "path"),
+            self.path.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 /// <p>An object representing the method and value to match the header value sent with a request. Specify one match method.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HeaderMatchMethod {
@@ -497,6 +854,43 @@ pub struct HeaderMatchMethod {
     pub suffix: Option<String>,
 }
 
+impl Validate for HeaderMatchMethod {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exact {
+            errors.check_length(
+                &field_path(path, "exact"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.prefix {
+            errors.check_length(
+                &field_path(path, "prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.regex {
+            errors.check_length(
+                &field_path(path, "regex"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.suffix {
+            errors.check_length(
+                &field_path(path, "suffix"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 /// <p>An object representing the health check policy for a virtual node's listener.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HealthCheckPolicy {
@@ -530,6 +924,38 @@ pub struct HealthCheckPolicy {
     pub unhealthy_threshold: i64,
 }
 
+impl Validate for HealthCheckPolicy {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "healthy_threshold"),
+            self.healthy_threshold,
+            Some(2),
+            Some(10),
+        );
+        errors.check_range(
+            &field_path(path, "interval_millis"),
+            self.interval_millis,
+            Some(5000),
+            Some(300000),
+        );
+        if let Some(ref value) = self.port {
+            errors.check_range(&field_path(path, "port"), *value, Some(1), Some(65535));
+        }
+        errors.check_range(
+            &field_path(path, "timeout_millis"),
+            self.timeout_millis,
+            Some(2000),
+            Some(60000),
+        );
+        errors.check_range(
+            &field_path(path, "unhealthy_threshold"),
+            self.unhealthy_threshold,
+            Some(2),
+            Some(10),
+        );
+    }
+}
+
 /// <p>An object that represents a retry policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HttpRetryPolicy {
@@ -572,6 +998,31 @@ pub struct HttpRetryPolicy {
     pub tcp_retry_events: Option<Vec<String>>,
 }
 
+impl Validate for HttpRetryPolicy {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.http_retry_events {
+            errors.check_length(
+                &field_path(path, "http_retry_events"),
+                value.len(),
+                Some(1),
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "http_retry_events"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(25));
+            }
+        }
+        if let Some(ref value) = self.tcp_retry_events {
+            errors.check_length(
+                &field_path(path, "tcp_retry_events"),
+                value.len(),
+                Some(1),
+                Some(1),
+            );
+        }
+    }
+}
+
 /// <p>An object representing the HTTP routing specification for a route.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HttpRoute {
@@ -587,6 +1038,16 @@ pub struct HttpRoute {
     pub retry_policy: Option<HttpRetryPolicy>,
 }
 
+impl Validate for HttpRoute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.action.validate_at(&field_path(path, "action"), errors);
+        self.match_.validate_at(&field_path(path, "match_"), errors);
+        if let Some(ref value) = self.retry_policy {
+            value.validate_at(&field_path(path, "retry_policy"), errors);
+        }
+    }
+}
+
 /// <p>An object representing the traffic distribution requirements for matched HTTP
 /// requests.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -597,6 +1058,21 @@ pub struct HttpRouteAction {
     pub weighted_targets: Vec<WeightedTarget>,
 }
 
+impl Validate for HttpRouteAction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "weighted_targets"),
+            self.weighted_targets.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.weighted_targets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "weighted_targets"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>An object representing the HTTP header in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HttpRouteHeader {
@@ -613,6 +1089,20 @@ pub struct HttpRouteHeader {
     pub name: String,
 }
 
+impl Validate for HttpRouteHeader {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.match_ {
+            value.validate_at(&field_path(path, "match_"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(50),
+        );
+    }
+}
+
 /// <p>An object representing the requirements for a route to match HTTP requests for a virtual
 /// router.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -639,7 +1129,20 @@ pub struct HttpRouteMatch {
     pub scheme: Option<String>,
 }
 
+impl Validate for HttpRouteMatch {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.headers {
+            errors.check_length(&field_path(path, "headers"), value.len(), Some(1), Some(10));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "headers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMeshesInput {
     /// <p>The maximum number of results returned by <code>ListMeshes</code> in paginated output.
     /// When you use this parameter, <code>ListMeshes</code> returns only <code>limit</code>
@@ -667,8 +1170,16 @@ pub struct ListMeshesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListMeshesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListMeshesOutput {
     /// <p>The list of existing service meshes.</p>
     #[serde(rename = "meshes")]
@@ -683,6 +1194,7 @@ pub struct ListMeshesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRoutesInput {
     /// <p>The maximum number of results returned by <code>ListRoutes</code> in paginated output.
     /// When you use this parameter, <code>ListRoutes</code> returns only <code>limit</code>
@@ -710,8 +1222,28 @@ pub struct ListRoutesInput {
     pub virtual_router_name: String,
 }
 
+impl Validate for ListRoutesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "virtual_router_name"),
+            self.virtual_router_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRoutesOutput {
     /// <p>The <code>nextToken</code> value to include in a future <code>ListRoutes</code> request.
     /// When the results of a <code>ListRoutes</code> request exceed <code>limit</code>, you can
@@ -726,6 +1258,7 @@ pub struct ListRoutesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>The maximum number of tag results returned by <code>ListTagsForResource</code> in
     /// paginated output. When this parameter is used, <code>ListTagsForResource</code> returns
@@ -750,8 +1283,16 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceOutput {
     /// <p>The <code>nextToken</code> value to include in a future <code>ListTagsForResource</code>
     /// request. When the results of a <code>ListTagsForResource</code> request exceed
@@ -766,6 +1307,7 @@ pub struct ListTagsForResourceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVirtualNodesInput {
     /// <p>The maximum number of results returned by <code>ListVirtualNodes</code> in paginated
     /// output. When you use this parameter, <code>ListVirtualNodes</code> returns only
@@ -790,8 +1332,22 @@ pub struct ListVirtualNodesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListVirtualNodesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVirtualNodesOutput {
     /// <p>The <code>nextToken</code> value to include in a future <code>ListVirtualNodes</code>
     /// request. When the results of a <code>ListVirtualNodes</code> request exceed
@@ -806,6 +1362,7 @@ pub struct ListVirtualNodesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVirtualRoutersInput {
     /// <p>The maximum number of results returned by <code>ListVirtualRouters</code> in paginated
     /// output. When you use this parameter, <code>ListVirtualRouters</code> returns only
@@ -830,8 +1387,22 @@ pub struct ListVirtualRoutersInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListVirtualRoutersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVirtualRoutersOutput {
     /// <p>The <code>nextToken</code> value to include in a future <code>ListVirtualRouters</code>
     /// request. When the results of a <code>ListVirtualRouters</code> request exceed
@@ -846,6 +1417,7 @@ pub struct ListVirtualRoutersOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVirtualServicesInput {
     /// <p>The maximum number of results returned by <code>ListVirtualServices</code> in paginated
     /// output. When you use this parameter, <code>ListVirtualServices</code> returns only
@@ -870,8 +1442,22 @@ pub struct ListVirtualServicesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListVirtualServicesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVirtualServicesOutput {
     /// <p>The <code>nextToken</code> value to include in a future <code>ListVirtualServices</code>
     /// request. When the results of a <code>ListVirtualServices</code> request exceed
@@ -897,6 +1483,16 @@ pub struct Listener {
     pub port_mapping: PortMapping,
 }
 
+impl Validate for Listener {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.health_check {
+            value.validate_at(&field_path(path, "health_check"), errors);
+        }
+        self.port_mapping
+            .validate_at(&field_path(path, "port_mapping"), errors);
+    }
+}
+
 /// <p>An object representing the logging information for a virtual node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Logging {
@@ -906,6 +1502,14 @@ pub struct Logging {
     pub access_log: Option<AccessLog>,
 }
 
+impl Validate for Logging {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.access_log {
+            value.validate_at(&field_path(path, "access_log"), errors);
+        }
+    }
+}
+
 /// <p>The range of values to match on. The first character of the range is included in the range, though the last character is not. For example, if the range specified were 1-100, only values 1-99 would be matched.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MatchRange {
@@ -919,7 +1523,7 @@ pub struct MatchRange {
 
 /// <p>An object representing a service mesh returned by a describe operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MeshData {
     /// <p>The name of the service mesh.</p>
     #[serde(rename = "meshName")]
@@ -937,7 +1541,7 @@ pub struct MeshData {
 
 /// <p>An object representing a service mesh returned by a list operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MeshRef {
     /// <p>The full Amazon Resource Name (ARN) of the service mesh.</p>
     #[serde(rename = "arn")]
@@ -958,7 +1562,7 @@ pub struct MeshSpec {
 
 /// <p>An object representing the status of a service mesh.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MeshStatus {
     /// <p>The current mesh status.</p>
     #[serde(rename = "status")]
@@ -977,9 +1581,15 @@ pub struct PortMapping {
     pub protocol: String,
 }
 
+impl Validate for PortMapping {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(&field_path(path, "port"), self.port, Some(1), Some(65535));
+    }
+}
+
 /// <p>An object representing metadata for a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceMetadata {
     /// <p>The full Amazon Resource Name (ARN) for the resource.</p>
     #[serde(rename = "arn")]
@@ -1001,7 +1611,7 @@ pub struct ResourceMetadata {
 
 /// <p>An object representing a route returned by a describe operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RouteData {
     /// <p>The name of the service mesh that the route resides in.</p>
     #[serde(rename = "meshName")]
@@ -1025,7 +1635,7 @@ pub struct RouteData {
 
 /// <p>An object representing a route returned by a list operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RouteRef {
     /// <p>The full Amazon Resource Name (ARN) for the route.</p>
     #[serde(rename = "arn")]
@@ -1058,9 +1668,23 @@ pub struct RouteSpec {
     pub tcp_route: Option<TcpRoute>,
 }
 
+impl Validate for RouteSpec {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.http_route {
+            value.validate_at(&field_path(path, "http_route"), errors);
+        }
+        if let Some(ref value) = self.priority {
+            errors.check_range(&field_path(path, "priority"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.tcp_route {
+            value.validate_at(&field_path(path, "tcp_route"), errors);
+        }
+    }
+}
+
 /// <p>An object representing the current status of a route.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RouteStatus {
     /// <p>The current status for the route.</p>
     #[serde(rename = "status")]
@@ -1080,6 +1704,14 @@ pub struct ServiceDiscovery {
     pub dns: Option<DnsServiceDiscovery>,
 }
 
+impl Validate for ServiceDiscovery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aws_cloud_map {
+            value.validate_at(&field_path(path, "aws_cloud_map"), errors);
+        }
+    }
+}
+
 /// <p>Optional metadata that you apply to a resource to assist with categorization and
 /// organization. Each tag consists of a key and an optional value, both of which you define.
 /// Tag keys can have a maximum character length of 128 characters, and tag values can have
@@ -1097,7 +1729,27 @@ pub struct TagRef {
     pub value: Option<String>,
 }
 
+impl Validate for TagRef {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the resource to add tags to.</p>
     #[serde(rename = "resourceArn")]
@@ -1109,8 +1761,18 @@ pub struct TagResourceInput {
     pub tags: Vec<TagRef>,
 }
 
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), None, Some(50));
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceOutput {}
 
 /// <p>An object representing the TCP routing specification for a route.</p>
@@ -1121,6 +1783,12 @@ pub struct TcpRoute {
     pub action: TcpRouteAction,
 }
 
+impl Validate for TcpRoute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.action.validate_at(&field_path(path, "action"), errors);
+    }
+}
+
 /// <p>An object representing the traffic distribution requirements for matched TCP
 /// requests.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -1131,7 +1799,23 @@ pub struct TcpRouteAction {
     pub weighted_targets: Vec<WeightedTarget>,
 }
 
+impl Validate for TcpRouteAction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "weighted_targets"),
+            self.weighted_targets.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.weighted_targets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "weighted_targets"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the resource to delete tags from.</p>
     #[serde(rename = "resourceArn")]
@@ -1141,11 +1825,27 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMeshInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the
     /// request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
@@ -1161,14 +1861,26 @@ pub struct UpdateMeshInput {
     pub spec: Option<MeshSpec>,
 }
 
+impl Validate for UpdateMeshInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateMeshOutput {
     #[serde(rename = "mesh")]
     pub mesh: MeshData,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRouteInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the
     /// request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
@@ -1189,8 +1901,32 @@ pub struct UpdateRouteInput {
     pub virtual_router_name: String,
 }
 
+impl Validate for UpdateRouteInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "route_name"),
+            self.route_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        self.spec.validate_at(&field_path(path, "spec"), errors);
+        errors.check_length(
+            &field_path(path, "virtual_router_name"),
+            self.virtual_router_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRouteOutput {
     /// <p>A full description of the route that was updated.</p>
     #[serde(rename = "route")]
@@ -1198,6 +1934,7 @@ pub struct UpdateRouteOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateVirtualNodeInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the
     /// request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
@@ -1215,8 +1952,26 @@ pub struct UpdateVirtualNodeInput {
     pub virtual_node_name: String,
 }
 
+impl Validate for UpdateVirtualNodeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        self.spec.validate_at(&field_path(path, "spec"), errors);
+        errors.check_length(
+            &field_path(path, "virtual_node_name"),
+            self.virtual_node_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateVirtualNodeOutput {
     /// <p>A full description of the virtual node that was updated.</p>
     #[serde(rename = "virtualNode")]
@@ -1224,6 +1979,7 @@ pub struct UpdateVirtualNodeOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateVirtualRouterInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the
     /// request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
@@ -1241,8 +1997,26 @@ pub struct UpdateVirtualRouterInput {
     pub virtual_router_name: String,
 }
 
+impl Validate for UpdateVirtualRouterInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        self.spec.validate_at(&field_path(path, "spec"), errors);
+        errors.check_length(
+            &field_path(path, "virtual_router_name"),
+            self.virtual_router_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateVirtualRouterOutput {
     /// <p>A full description of the virtual router that was updated.</p>
     #[serde(rename = "virtualRouter")]
@@ -1250,6 +2024,7 @@ pub struct UpdateVirtualRouterOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateVirtualServiceInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the
     /// request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
@@ -1268,8 +2043,20 @@ pub struct UpdateVirtualServiceInput {
     pub virtual_service_name: String,
 }
 
+impl Validate for UpdateVirtualServiceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mesh_name"),
+            self.mesh_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        self.spec.validate_at(&field_path(path, "spec"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateVirtualServiceOutput {
     /// <p>A full description of the virtual service that was updated.</p>
     #[serde(rename = "virtualService")]
@@ -1278,7 +2065,7 @@ pub struct UpdateVirtualServiceOutput {
 
 /// <p>An object representing a virtual node returned by a describe operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualNodeData {
     /// <p>The name of the service mesh that the virtual node resides in.</p>
     #[serde(rename = "meshName")]
@@ -1299,7 +2086,7 @@ pub struct VirtualNodeData {
 
 /// <p>An object representing a virtual node returned by a list operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualNodeRef {
     /// <p>The full Amazon Resource Name (ARN) for the virtual node.</p>
     #[serde(rename = "arn")]
@@ -1320,6 +2107,17 @@ pub struct VirtualNodeServiceProvider {
     pub virtual_node_name: String,
 }
 
+impl Validate for VirtualNodeServiceProvider {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "virtual_node_name"),
+            self.virtual_node_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 /// <p>An object representing the specification of a virtual node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VirtualNodeSpec {
@@ -1343,9 +2141,26 @@ pub struct VirtualNodeSpec {
     pub service_discovery: Option<ServiceDiscovery>,
 }
 
+impl Validate for VirtualNodeSpec {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.listeners {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "listeners"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.logging {
+            value.validate_at(&field_path(path, "logging"), errors);
+        }
+        if let Some(ref value) = self.service_discovery {
+            value.validate_at(&field_path(path, "service_discovery"), errors);
+        }
+    }
+}
+
 /// <p>An object representing the current status of the virtual node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualNodeStatus {
     /// <p>The current status of the virtual node.</p>
     #[serde(rename = "status")]
@@ -1354,7 +2169,7 @@ pub struct VirtualNodeStatus {
 
 /// <p>An object representing a virtual router returned by a describe operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualRouterData {
     /// <p>The name of the service mesh that the virtual router resides in.</p>
     #[serde(rename = "meshName")]
@@ -1380,9 +2195,16 @@ pub struct VirtualRouterListener {
     pub port_mapping: PortMapping,
 }
 
+impl Validate for VirtualRouterListener {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.port_mapping
+            .validate_at(&field_path(path, "port_mapping"), errors);
+    }
+}
+
 /// <p>An object representing a virtual router returned by a list operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualRouterRef {
     /// <p>The full Amazon Resource Name (ARN) for the virtual router.</p>
     #[serde(rename = "arn")]
@@ -1403,9 +2225,20 @@ pub struct VirtualRouterServiceProvider {
     pub virtual_router_name: String,
 }
 
-/// <p>An object representing the specification of a virtual router.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct VirtualRouterSpec {
+impl Validate for VirtualRouterServiceProvider {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "virtual_router_name"),
+            self.virtual_router_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
+/// <p>An object representing the specification of a virtual router.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VirtualRouterSpec {
     /// <p>The listeners that the virtual router is expected to receive inbound traffic from.
     /// Currently only one listener is supported per virtual router.</p>
     #[serde(rename = "listeners")]
@@ -1413,9 +2246,20 @@ pub struct VirtualRouterSpec {
     pub listeners: Option<Vec<VirtualRouterListener>>,
 }
 
+impl Validate for VirtualRouterSpec {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.listeners {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "listeners"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>An object representing the status of a virtual router. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualRouterStatus {
     /// <p>The current status of the virtual router.</p>
     #[serde(rename = "status")]
@@ -1432,7 +2276,7 @@ pub struct VirtualServiceBackend {
 
 /// <p>An object representing a virtual service returned by a describe operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualServiceData {
     /// <p>The name of the service mesh that the virtual service resides in.</p>
     #[serde(rename = "meshName")]
@@ -1463,9 +2307,20 @@ pub struct VirtualServiceProvider {
     pub virtual_router: Option<VirtualRouterServiceProvider>,
 }
 
+impl Validate for VirtualServiceProvider {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.virtual_node {
+            value.validate_at(&field_path(path, "virtual_node"), errors);
+        }
+        if let Some(ref value) = self.virtual_router {
+            value.validate_at(&field_path(path, "virtual_router"), errors);
+        }
+    }
+}
+
 /// <p>An object representing a virtual service returned by a list operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualServiceRef {
     /// <p>The full Amazon Resource Name (ARN) for the virtual service.</p>
     #[serde(rename = "arn")]
@@ -1488,9 +2343,17 @@ pub struct VirtualServiceSpec {
     pub provider: Option<VirtualServiceProvider>,
 }
 
+impl Validate for VirtualServiceSpec {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.provider {
+            value.validate_at(&field_path(path, "provider"), errors);
+        }
+    }
+}
+
 /// <p>An object representing the status of a virtual service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualServiceStatus {
     /// <p>The current status of the virtual service.</p>
     #[serde(rename = "status")]
@@ -1511,6 +2374,18 @@ pub struct WeightedTarget {
     pub weight: i64,
 }
 
+impl Validate for WeightedTarget {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "virtual_node"),
+            self.virtual_node.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_range(&field_path(path, "weight"), self.weight, None, Some(100));
+    }
+}
+
 /// Errors returned by CreateMesh
 #[derive(Debug, PartialEq)]
 pub enum CreateMeshError {
@@ -1565,7 +2440,12 @@ impl CreateMeshError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateMeshError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1645,7 +2525,12 @@ impl CreateRouteError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateRouteError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1729,7 +2614,12 @@ impl CreateVirtualNodeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateVirtualNodeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1813,7 +2703,12 @@ impl CreateVirtualRouterError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateVirtualRouterError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1899,7 +2794,12 @@ impl CreateVirtualServiceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1973,7 +2873,12 @@ impl DeleteMeshError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteMeshError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2046,7 +2951,12 @@ impl DeleteRouteError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteRouteError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2123,7 +3033,12 @@ impl DeleteVirtualNodeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteVirtualNodeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2200,7 +3115,12 @@ impl DeleteVirtualRouterError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteVirtualRouterError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2273,7 +3193,12 @@ impl DeleteVirtualServiceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2339,7 +3264,12 @@ impl DescribeMeshError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeMeshError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2405,7 +3335,12 @@ impl DescribeRouteError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeRouteError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2475,7 +3410,12 @@ impl DescribeVirtualNodeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeVirtualNodeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2547,7 +3487,12 @@ impl DescribeVirtualRouterError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2619,7 +3564,12 @@ impl DescribeVirtualServiceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2685,7 +3635,12 @@ impl ListMeshesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListMeshesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2751,7 +3706,12 @@ impl ListRoutesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListRoutesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2821,7 +3781,12 @@ impl ListTagsForResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2889,7 +3854,12 @@ impl ListVirtualNodesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListVirtualNodesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2959,7 +3929,12 @@ impl ListVirtualRoutersError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListVirtualRoutersError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3029,7 +4004,12 @@ impl ListVirtualServicesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListVirtualServicesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3102,7 +4082,12 @@ impl TagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3169,7 +4154,12 @@ impl UntagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3241,7 +4231,12 @@ impl UpdateMeshError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateMeshError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3320,7 +4315,12 @@ impl UpdateRouteError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateRouteError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3404,7 +4404,12 @@ impl UpdateVirtualNodeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateVirtualNodeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3488,7 +4493,12 @@ impl UpdateVirtualRouterError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateVirtualRouterError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3574,7 +4584,12 @@ impl UpdateVirtualServiceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3834,1082 +4849,2920 @@ pub trait AppMesh {
         input: UpdateVirtualServiceInput,
     ) -> RusotoFuture<UpdateVirtualServiceOutput, UpdateVirtualServiceError>;
 }
-/// A client for the AWS App Mesh API.
-#[derive(Clone)]
-pub struct AppMeshClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl AppMeshClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> AppMeshClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> AppMeshClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> AppMeshClient {
-        AppMeshClient { client, region }
-    }
-}
-
-impl AppMesh for AppMeshClient {
-    /// <p>Creates a service mesh. A service mesh is a logical boundary for network traffic between
-    /// the services that reside within it.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;After you create your service mesh, you can create virtual services, virtual nodes,
-    /// virtual routers, and routes to distribute traffic between the applications in your
-    /// mesh.&lt;/p&gt;
-    /// </code></pre>
+const _: Option<&dyn AppMesh> = None;
+impl<'a, T: AppMesh + ?Sized> AppMesh for &'a T {
     fn create_mesh(
         &self,
         input: CreateMeshInput,
     ) -> RusotoFuture<CreateMeshOutput, CreateMeshError> {
-        let request_uri = "/v20190125/meshes";
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateMeshOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateMeshError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_mesh(input)
     }
-
-    /// <p>Creates a route that is associated with a virtual router.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;You can use the &lt;code&gt;prefix&lt;/code&gt; parameter in your route specification for path-based
-    /// routing of requests. For example, if your virtual service name is
-    /// &lt;code&gt;my-service.local&lt;/code&gt; and you want the route to match requests to
-    /// &lt;code&gt;my-service.local/metrics&lt;/code&gt;, your prefix should be
-    /// &lt;code&gt;/metrics&lt;/code&gt;.&lt;/p&gt;
-    /// &lt;p&gt;If your route matches a request, you can distribute traffic to one or more target
-    /// virtual nodes with relative weighting.&lt;/p&gt;
-    /// </code></pre>
     fn create_route(
         &self,
         input: CreateRouteInput,
     ) -> RusotoFuture<CreateRouteOutput, CreateRouteError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes",
-            mesh_name = input.mesh_name,
-            virtual_router_name = input.virtual_router_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRouteOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateRouteError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_route(input)
     }
-
-    /// <p>Creates a virtual node within a service mesh.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;A virtual node acts as a logical pointer to a particular task group, such as an Amazon ECS
-    /// service or a Kubernetes deployment. When you create a virtual node, you can specify the
-    /// service discovery information for your task group.&lt;/p&gt;
-    /// &lt;p&gt;Any inbound traffic that your virtual node expects should be specified as a
-    /// &lt;code&gt;listener&lt;/code&gt;. Any outbound traffic that your virtual node expects to reach
-    /// should be specified as a &lt;code&gt;backend&lt;/code&gt;.&lt;/p&gt;
-    /// &lt;p&gt;The response metadata for your new virtual node contains the &lt;code&gt;arn&lt;/code&gt; that is
-    /// associated with the virtual node. Set this value (either the full ARN or the truncated
-    /// resource name: for example, &lt;code&gt;mesh/default/virtualNode/simpleapp&lt;/code&gt;) as the
-    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_NAME&lt;/code&gt; environment variable for your task group&#39;s Envoy
-    /// proxy container in your task definition or pod spec. This is then mapped to the
-    /// &lt;code&gt;node.id&lt;/code&gt; and &lt;code&gt;node.cluster&lt;/code&gt; Envoy parameters.&lt;/p&gt;
-    /// &lt;note&gt;
-    /// &lt;p&gt;If you require your Envoy stats or tracing to use a different name, you can override
-    /// the &lt;code&gt;node.cluster&lt;/code&gt; value that is set by
-    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_NAME&lt;/code&gt; with the
-    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_CLUSTER&lt;/code&gt; environment variable.&lt;/p&gt;
-    /// &lt;/note&gt;
-    /// </code></pre>
     fn create_virtual_node(
         &self,
         input: CreateVirtualNodeInput,
     ) -> RusotoFuture<CreateVirtualNodeOutput, CreateVirtualNodeError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualNodes",
-            mesh_name = input.mesh_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateVirtualNodeOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateVirtualNodeError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_virtual_node(input)
     }
-
-    /// <p>Creates a virtual router within a service mesh.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;Any inbound traffic that your virtual router expects should be specified as a
-    /// &lt;code&gt;listener&lt;/code&gt;. &lt;/p&gt;
-    /// &lt;p&gt;Virtual routers handle traffic for one or more virtual services within your mesh. After
-    /// you create your virtual router, create and associate routes for your virtual router that
-    /// direct incoming requests to different virtual nodes.&lt;/p&gt;
-    /// </code></pre>
     fn create_virtual_router(
         &self,
         input: CreateVirtualRouterInput,
     ) -> RusotoFuture<CreateVirtualRouterOutput, CreateVirtualRouterError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualRouters",
-            mesh_name = input.mesh_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateVirtualRouterOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateVirtualRouterError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_virtual_router(input)
     }
-
-    /// <p>Creates a virtual service within a service mesh.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;A virtual service is an abstraction of a real service that is provided by a virtual node
-    /// directly or indirectly by means of a virtual router. Dependent services call your virtual
-    /// service by its &lt;code&gt;virtualServiceName&lt;/code&gt;, and those requests are routed to the
-    /// virtual node or virtual router that is specified as the provider for the virtual
-    /// service.&lt;/p&gt;
-    /// </code></pre>
     fn create_virtual_service(
         &self,
         input: CreateVirtualServiceInput,
     ) -> RusotoFuture<CreateVirtualServiceOutput, CreateVirtualServiceError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualServices",
-            mesh_name = input.mesh_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateVirtualServiceOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateVirtualServiceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_virtual_service(input)
     }
-
-    /// <p>Deletes an existing service mesh.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;You must delete all resources (virtual services, routes, virtual routers, and virtual
-    /// nodes) in the service mesh before you can delete the mesh itself.&lt;/p&gt;
-    /// </code></pre>
     fn delete_mesh(
         &self,
         input: DeleteMeshInput,
     ) -> RusotoFuture<DeleteMeshOutput, DeleteMeshError> {
-        let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
-
-        let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteMeshOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteMeshError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_mesh(input)
     }
-
-    /// <p>Deletes an existing route.</p>
     fn delete_route(
         &self,
         input: DeleteRouteInput,
     ) -> RusotoFuture<DeleteRouteOutput, DeleteRouteError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes/{route_name}",
-            mesh_name = input.mesh_name,
-            route_name = input.route_name,
-            virtual_router_name = input.virtual_router_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRouteOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteRouteError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_route(input)
     }
-
-    /// <p>Deletes an existing virtual node.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;You must delete any virtual services that list a virtual node as a service provider
-    /// before you can delete the virtual node itself.&lt;/p&gt;
-    /// </code></pre>
     fn delete_virtual_node(
         &self,
         input: DeleteVirtualNodeInput,
     ) -> RusotoFuture<DeleteVirtualNodeOutput, DeleteVirtualNodeError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualNodes/{virtual_node_name}",
-            mesh_name = input.mesh_name,
-            virtual_node_name = input.virtual_node_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteVirtualNodeOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteVirtualNodeError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_virtual_node(input)
     }
-
-    /// <p>Deletes an existing virtual router.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;You must delete any routes associated with the virtual router before you can delete the
-    /// router itself.&lt;/p&gt;
-    /// </code></pre>
     fn delete_virtual_router(
         &self,
         input: DeleteVirtualRouterInput,
     ) -> RusotoFuture<DeleteVirtualRouterOutput, DeleteVirtualRouterError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualRouters/{virtual_router_name}",
-            mesh_name = input.mesh_name,
-            virtual_router_name = input.virtual_router_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteVirtualRouterOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteVirtualRouterError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_virtual_router(input)
     }
-
-    /// <p>Deletes an existing virtual service.</p>
     fn delete_virtual_service(
         &self,
         input: DeleteVirtualServiceInput,
     ) -> RusotoFuture<DeleteVirtualServiceOutput, DeleteVirtualServiceError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualServices/{virtual_service_name}",
-            mesh_name = input.mesh_name,
-            virtual_service_name = input.virtual_service_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteVirtualServiceOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteVirtualServiceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_virtual_service(input)
     }
-
-    /// <p>Describes an existing service mesh.</p>
     fn describe_mesh(
         &self,
         input: DescribeMeshInput,
     ) -> RusotoFuture<DescribeMeshOutput, DescribeMeshError> {
-        let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMeshOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeMeshError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_mesh(input)
     }
-
-    /// <p>Describes an existing route.</p>
     fn describe_route(
         &self,
         input: DescribeRouteInput,
     ) -> RusotoFuture<DescribeRouteOutput, DescribeRouteError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes/{route_name}",
-            mesh_name = input.mesh_name,
-            route_name = input.route_name,
-            virtual_router_name = input.virtual_router_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRouteOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeRouteError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_route(input)
     }
-
-    /// <p>Describes an existing virtual node.</p>
     fn describe_virtual_node(
         &self,
         input: DescribeVirtualNodeInput,
     ) -> RusotoFuture<DescribeVirtualNodeOutput, DescribeVirtualNodeError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualNodes/{virtual_node_name}",
-            mesh_name = input.mesh_name,
-            virtual_node_name = input.virtual_node_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeVirtualNodeOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeVirtualNodeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_virtual_node(input)
     }
+    fn describe_virtual_router(
+        &self,
+        input: DescribeVirtualRouterInput,
+    ) -> RusotoFuture<DescribeVirtualRouterOutput, DescribeVirtualRouterError> {
+        (**self).describe_virtual_router(input)
+    }
+    fn describe_virtual_service(
+        &self,
+        input: DescribeVirtualServiceInput,
+    ) -> RusotoFuture<DescribeVirtualServiceOutput, DescribeVirtualServiceError> {
+        (**self).describe_virtual_service(input)
+    }
+    fn list_meshes(
+        &self,
+        input: ListMeshesInput,
+    ) -> RusotoFuture<ListMeshesOutput, ListMeshesError> {
+        (**self).list_meshes(input)
+    }
+    fn list_routes(
+        &self,
+        input: ListRoutesInput,
+    ) -> RusotoFuture<ListRoutesOutput, ListRoutesError> {
+        (**self).list_routes(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_virtual_nodes(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> RusotoFuture<ListVirtualNodesOutput, ListVirtualNodesError> {
+        (**self).list_virtual_nodes(input)
+    }
+    fn list_virtual_routers(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> RusotoFuture<ListVirtualRoutersOutput, ListVirtualRoutersError> {
+        (**self).list_virtual_routers(input)
+    }
+    fn list_virtual_services(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> RusotoFuture<ListVirtualServicesOutput, ListVirtualServicesError> {
+        (**self).list_virtual_services(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_mesh(
+        &self,
+        input: UpdateMeshInput,
+    ) -> RusotoFuture<UpdateMeshOutput, UpdateMeshError> {
+        (**self).update_mesh(input)
+    }
+    fn update_route(
+        &self,
+        input: UpdateRouteInput,
+    ) -> RusotoFuture<UpdateRouteOutput, UpdateRouteError> {
+        (**self).update_route(input)
+    }
+    fn update_virtual_node(
+        &self,
+        input: UpdateVirtualNodeInput,
+    ) -> RusotoFuture<UpdateVirtualNodeOutput, UpdateVirtualNodeError> {
+        (**self).update_virtual_node(input)
+    }
+    fn update_virtual_router(
+        &self,
+        input: UpdateVirtualRouterInput,
+    ) -> RusotoFuture<UpdateVirtualRouterOutput, UpdateVirtualRouterError> {
+        (**self).update_virtual_router(input)
+    }
+    fn update_virtual_service(
+        &self,
+        input: UpdateVirtualServiceInput,
+    ) -> RusotoFuture<UpdateVirtualServiceOutput, UpdateVirtualServiceError> {
+        (**self).update_virtual_service(input)
+    }
+}
+impl<T: AppMesh + ?Sized> AppMesh for Box<T> {
+    fn create_mesh(
+        &self,
+        input: CreateMeshInput,
+    ) -> RusotoFuture<CreateMeshOutput, CreateMeshError> {
+        (**self).create_mesh(input)
+    }
+    fn create_route(
+        &self,
+        input: CreateRouteInput,
+    ) -> RusotoFuture<CreateRouteOutput, CreateRouteError> {
+        (**self).create_route(input)
+    }
+    fn create_virtual_node(
+        &self,
+        input: CreateVirtualNodeInput,
+    ) -> RusotoFuture<CreateVirtualNodeOutput, CreateVirtualNodeError> {
+        (**self).create_virtual_node(input)
+    }
+    fn create_virtual_router(
+        &self,
+        input: CreateVirtualRouterInput,
+    ) -> RusotoFuture<CreateVirtualRouterOutput, CreateVirtualRouterError> {
+        (**self).create_virtual_router(input)
+    }
+    fn create_virtual_service(
+        &self,
+        input: CreateVirtualServiceInput,
+    ) -> RusotoFuture<CreateVirtualServiceOutput, CreateVirtualServiceError> {
+        (**self).create_virtual_service(input)
+    }
+    fn delete_mesh(
+        &self,
+        input: DeleteMeshInput,
+    ) -> RusotoFuture<DeleteMeshOutput, DeleteMeshError> {
+        (**self).delete_mesh(input)
+    }
+    fn delete_route(
+        &self,
+        input: DeleteRouteInput,
+    ) -> RusotoFuture<DeleteRouteOutput, DeleteRouteError> {
+        (**self).delete_route(input)
+    }
+    fn delete_virtual_node(
+        &self,
+        input: DeleteVirtualNodeInput,
+    ) -> RusotoFuture<DeleteVirtualNodeOutput, DeleteVirtualNodeError> {
+        (**self).delete_virtual_node(input)
+    }
+    fn delete_virtual_router(
+        &self,
+        input: DeleteVirtualRouterInput,
+    ) -> RusotoFuture<DeleteVirtualRouterOutput, DeleteVirtualRouterError> {
+        (**self).delete_virtual_router(input)
+    }
+    fn delete_virtual_service(
+        &self,
+        input: DeleteVirtualServiceInput,
+    ) -> RusotoFuture<DeleteVirtualServiceOutput, DeleteVirtualServiceError> {
+        (**self).delete_virtual_service(input)
+    }
+    fn describe_mesh(
+        &self,
+        input: DescribeMeshInput,
+    ) -> RusotoFuture<DescribeMeshOutput, DescribeMeshError> {
+        (**self).describe_mesh(input)
+    }
+    fn describe_route(
+        &self,
+        input: DescribeRouteInput,
+    ) -> RusotoFuture<DescribeRouteOutput, DescribeRouteError> {
+        (**self).describe_route(input)
+    }
+    fn describe_virtual_node(
+        &self,
+        input: DescribeVirtualNodeInput,
+    ) -> RusotoFuture<DescribeVirtualNodeOutput, DescribeVirtualNodeError> {
+        (**self).describe_virtual_node(input)
+    }
+    fn describe_virtual_router(
+        &self,
+        input: DescribeVirtualRouterInput,
+    ) -> RusotoFuture<DescribeVirtualRouterOutput, DescribeVirtualRouterError> {
+        (**self).describe_virtual_router(input)
+    }
+    fn describe_virtual_service(
+        &self,
+        input: DescribeVirtualServiceInput,
+    ) -> RusotoFuture<DescribeVirtualServiceOutput, DescribeVirtualServiceError> {
+        (**self).describe_virtual_service(input)
+    }
+    fn list_meshes(
+        &self,
+        input: ListMeshesInput,
+    ) -> RusotoFuture<ListMeshesOutput, ListMeshesError> {
+        (**self).list_meshes(input)
+    }
+    fn list_routes(
+        &self,
+        input: ListRoutesInput,
+    ) -> RusotoFuture<ListRoutesOutput, ListRoutesError> {
+        (**self).list_routes(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_virtual_nodes(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> RusotoFuture<ListVirtualNodesOutput, ListVirtualNodesError> {
+        (**self).list_virtual_nodes(input)
+    }
+    fn list_virtual_routers(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> RusotoFuture<ListVirtualRoutersOutput, ListVirtualRoutersError> {
+        (**self).list_virtual_routers(input)
+    }
+    fn list_virtual_services(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> RusotoFuture<ListVirtualServicesOutput, ListVirtualServicesError> {
+        (**self).list_virtual_services(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_mesh(
+        &self,
+        input: UpdateMeshInput,
+    ) -> RusotoFuture<UpdateMeshOutput, UpdateMeshError> {
+        (**self).update_mesh(input)
+    }
+    fn update_route(
+        &self,
+        input: UpdateRouteInput,
+    ) -> RusotoFuture<UpdateRouteOutput, UpdateRouteError> {
+        (**self).update_route(input)
+    }
+    fn update_virtual_node(
+        &self,
+        input: UpdateVirtualNodeInput,
+    ) -> RusotoFuture<UpdateVirtualNodeOutput, UpdateVirtualNodeError> {
+        (**self).update_virtual_node(input)
+    }
+    fn update_virtual_router(
+        &self,
+        input: UpdateVirtualRouterInput,
+    ) -> RusotoFuture<UpdateVirtualRouterOutput, UpdateVirtualRouterError> {
+        (**self).update_virtual_router(input)
+    }
+    fn update_virtual_service(
+        &self,
+        input: UpdateVirtualServiceInput,
+    ) -> RusotoFuture<UpdateVirtualServiceOutput, UpdateVirtualServiceError> {
+        (**self).update_virtual_service(input)
+    }
+}
+impl<T: AppMesh + ?Sized> AppMesh for ::std::sync::Arc<T> {
+    fn create_mesh(
+        &self,
+        input: CreateMeshInput,
+    ) -> RusotoFuture<CreateMeshOutput, CreateMeshError> {
+        (**self).create_mesh(input)
+    }
+    fn create_route(
+        &self,
+        input: CreateRouteInput,
+    ) -> RusotoFuture<CreateRouteOutput, CreateRouteError> {
+        (**self).create_route(input)
+    }
+    fn create_virtual_node(
+        &self,
+        input: CreateVirtualNodeInput,
+    ) -> RusotoFuture<CreateVirtualNodeOutput, CreateVirtualNodeError> {
+        (**self).create_virtual_node(input)
+    }
+    fn create_virtual_router(
+        &self,
+        input: CreateVirtualRouterInput,
+    ) -> RusotoFuture<CreateVirtualRouterOutput, CreateVirtualRouterError> {
+        (**self).create_virtual_router(input)
+    }
+    fn create_virtual_service(
+        &self,
+        input: CreateVirtualServiceInput,
+    ) -> RusotoFuture<CreateVirtualServiceOutput, CreateVirtualServiceError> {
+        (**self).create_virtual_service(input)
+    }
+    fn delete_mesh(
+        &self,
+        input: DeleteMeshInput,
+    ) -> RusotoFuture<DeleteMeshOutput, DeleteMeshError> {
+        (**self).delete_mesh(input)
+    }
+    fn delete_route(
+        &self,
+        input: DeleteRouteInput,
+    ) -> RusotoFuture<DeleteRouteOutput, DeleteRouteError> {
+        (**self).delete_route(input)
+    }
+    fn delete_virtual_node(
+        &self,
+        input: DeleteVirtualNodeInput,
+    ) -> RusotoFuture<DeleteVirtualNodeOutput, DeleteVirtualNodeError> {
+        (**self).delete_virtual_node(input)
+    }
+    fn delete_virtual_router(
+        &self,
+        input: DeleteVirtualRouterInput,
+    ) -> RusotoFuture<DeleteVirtualRouterOutput, DeleteVirtualRouterError> {
+        (**self).delete_virtual_router(input)
+    }
+    fn delete_virtual_service(
+        &self,
+        input: DeleteVirtualServiceInput,
+    ) -> RusotoFuture<DeleteVirtualServiceOutput, DeleteVirtualServiceError> {
+        (**self).delete_virtual_service(input)
+    }
+    fn describe_mesh(
+        &self,
+        input: DescribeMeshInput,
+    ) -> RusotoFuture<DescribeMeshOutput, DescribeMeshError> {
+        (**self).describe_mesh(input)
+    }
+    fn describe_route(
+        &self,
+        input: DescribeRouteInput,
+    ) -> RusotoFuture<DescribeRouteOutput, DescribeRouteError> {
+        (**self).describe_route(input)
+    }
+    fn describe_virtual_node(
+        &self,
+        input: DescribeVirtualNodeInput,
+    ) -> RusotoFuture<DescribeVirtualNodeOutput, DescribeVirtualNodeError> {
+        (**self).describe_virtual_node(input)
+    }
+    fn describe_virtual_router(
+        &self,
+        input: DescribeVirtualRouterInput,
+    ) -> RusotoFuture<DescribeVirtualRouterOutput, DescribeVirtualRouterError> {
+        (**self).describe_virtual_router(input)
+    }
+    fn describe_virtual_service(
+        &self,
+        input: DescribeVirtualServiceInput,
+    ) -> RusotoFuture<DescribeVirtualServiceOutput, DescribeVirtualServiceError> {
+        (**self).describe_virtual_service(input)
+    }
+    fn list_meshes(
+        &self,
+        input: ListMeshesInput,
+    ) -> RusotoFuture<ListMeshesOutput, ListMeshesError> {
+        (**self).list_meshes(input)
+    }
+    fn list_routes(
+        &self,
+        input: ListRoutesInput,
+    ) -> RusotoFuture<ListRoutesOutput, ListRoutesError> {
+        (**self).list_routes(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_virtual_nodes(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> RusotoFuture<ListVirtualNodesOutput, ListVirtualNodesError> {
+        (**self).list_virtual_nodes(input)
+    }
+    fn list_virtual_routers(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> RusotoFuture<ListVirtualRoutersOutput, ListVirtualRoutersError> {
+        (**self).list_virtual_routers(input)
+    }
+    fn list_virtual_services(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> RusotoFuture<ListVirtualServicesOutput, ListVirtualServicesError> {
+        (**self).list_virtual_services(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_mesh(
+        &self,
+        input: UpdateMeshInput,
+    ) -> RusotoFuture<UpdateMeshOutput, UpdateMeshError> {
+        (**self).update_mesh(input)
+    }
+    fn update_route(
+        &self,
+        input: UpdateRouteInput,
+    ) -> RusotoFuture<UpdateRouteOutput, UpdateRouteError> {
+        (**self).update_route(input)
+    }
+    fn update_virtual_node(
+        &self,
+        input: UpdateVirtualNodeInput,
+    ) -> RusotoFuture<UpdateVirtualNodeOutput, UpdateVirtualNodeError> {
+        (**self).update_virtual_node(input)
+    }
+    fn update_virtual_router(
+        &self,
+        input: UpdateVirtualRouterInput,
+    ) -> RusotoFuture<UpdateVirtualRouterOutput, UpdateVirtualRouterError> {
+        (**self).update_virtual_router(input)
+    }
+    fn update_virtual_service(
+        &self,
+        input: UpdateVirtualServiceInput,
+    ) -> RusotoFuture<UpdateVirtualServiceOutput, UpdateVirtualServiceError> {
+        (**self).update_virtual_service(input)
+    }
+}
+/// A client for the AWS App Mesh API.
+#[derive(Clone)]
+pub struct AppMeshClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl AppMeshClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AppMeshClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppMeshClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> AppMeshClient {
+        AppMeshClient { client, region }
+    }
+}
+
+impl AppMesh for AppMeshClient {
+    /// <p>Creates a service mesh. A service mesh is a logical boundary for network traffic between
+    /// the services that reside within it.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;After you create your service mesh, you can create virtual services, virtual nodes,
+    /// virtual routers, and routes to distribute traffic between the applications in your
+    /// mesh.&lt;/p&gt;
+    /// </code></pre>
+    fn create_mesh(
+        &self,
+        input: CreateMeshInput,
+    ) -> RusotoFuture<CreateMeshOutput, CreateMeshError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/v20190125/meshes";
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateMesh");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateMesh")
+                        .deserialize::<CreateMeshOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateMeshError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a route that is associated with a virtual router.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You can use the &lt;code&gt;prefix&lt;/code&gt; parameter in your route specification for path-based
+    /// routing of requests. For example, if your virtual service name is
+    /// &lt;code&gt;my-service.local&lt;/code&gt; and you want the route to match requests to
+    /// &lt;code&gt;my-service.local/metrics&lt;/code&gt;, your prefix should be
+    /// &lt;code&gt;/metrics&lt;/code&gt;.&lt;/p&gt;
+    /// &lt;p&gt;If your route matches a request, you can distribute traffic to one or more target
+    /// virtual nodes with relative weighting.&lt;/p&gt;
+    /// </code></pre>
+    fn create_route(
+        &self,
+        input: CreateRouteInput,
+    ) -> RusotoFuture<CreateRouteOutput, CreateRouteError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes",
+            mesh_name = input.mesh_name,
+            virtual_router_name = input.virtual_router_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateRoute");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("virtualRouterName", &input.virtual_router_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateRoute")
+                        .deserialize::<CreateRouteOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateRouteError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a virtual node within a service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;A virtual node acts as a logical pointer to a particular task group, such as an Amazon ECS
+    /// service or a Kubernetes deployment. When you create a virtual node, you can specify the
+    /// service discovery information for your task group.&lt;/p&gt;
+    /// &lt;p&gt;Any inbound traffic that your virtual node expects should be specified as a
+    /// &lt;code&gt;listener&lt;/code&gt;. Any outbound traffic that your virtual node expects to reach
+    /// should be specified as a &lt;code&gt;backend&lt;/code&gt;.&lt;/p&gt;
+    /// &lt;p&gt;The response metadata for your new virtual node contains the &lt;code&gt;arn&lt;/code&gt; that is
+    /// associated with the virtual node. Set this value (either the full ARN or the truncated
+    /// resource name: for example, &lt;code&gt;mesh/default/virtualNode/simpleapp&lt;/code&gt;) as the
+    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_NAME&lt;/code&gt; environment variable for your task group&#39;s Envoy
+    /// proxy container in your task definition or pod spec. This is then mapped to the
+    /// &lt;code&gt;node.id&lt;/code&gt; and &lt;code&gt;node.cluster&lt;/code&gt; Envoy parameters.&lt;/p&gt;
+    /// &lt;note&gt;
+    /// &lt;p&gt;If you require your Envoy stats or tracing to use a different name, you can override
+    /// the &lt;code&gt;node.cluster&lt;/code&gt; value that is set by
+    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_NAME&lt;/code&gt; with the
+    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_CLUSTER&lt;/code&gt; environment variable.&lt;/p&gt;
+    /// &lt;/note&gt;
+    /// </code></pre>
+    fn create_virtual_node(
+        &self,
+        input: CreateVirtualNodeInput,
+    ) -> RusotoFuture<CreateVirtualNodeOutput, CreateVirtualNodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualNodes",
+            mesh_name = input.mesh_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualNode");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateVirtualNode")
+                        .deserialize::<CreateVirtualNodeOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateVirtualNodeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a virtual router within a service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;Any inbound traffic that your virtual router expects should be specified as a
+    /// &lt;code&gt;listener&lt;/code&gt;. &lt;/p&gt;
+    /// &lt;p&gt;Virtual routers handle traffic for one or more virtual services within your mesh. After
+    /// you create your virtual router, create and associate routes for your virtual router that
+    /// direct incoming requests to different virtual nodes.&lt;/p&gt;
+    /// </code></pre>
+    fn create_virtual_router(
+        &self,
+        input: CreateVirtualRouterInput,
+    ) -> RusotoFuture<CreateVirtualRouterOutput, CreateVirtualRouterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualRouters",
+            mesh_name = input.mesh_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualRouter");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateVirtualRouter")
+                        .deserialize::<CreateVirtualRouterOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateVirtualRouterError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a virtual service within a service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;A virtual service is an abstraction of a real service that is provided by a virtual node
+    /// directly or indirectly by means of a virtual router. Dependent services call your virtual
+    /// service by its &lt;code&gt;virtualServiceName&lt;/code&gt;, and those requests are routed to the
+    /// virtual node or virtual router that is specified as the provider for the virtual
+    /// service.&lt;/p&gt;
+    /// </code></pre>
+    fn create_virtual_service(
+        &self,
+        input: CreateVirtualServiceInput,
+    ) -> RusotoFuture<CreateVirtualServiceOutput, CreateVirtualServiceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualServices",
+            mesh_name = input.mesh_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualService");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateVirtualService")
+                        .deserialize::<CreateVirtualServiceOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateVirtualServiceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You must delete all resources (virtual services, routes, virtual routers, and virtual
+    /// nodes) in the service mesh before you can delete the mesh itself.&lt;/p&gt;
+    /// </code></pre>
+    fn delete_mesh(
+        &self,
+        input: DeleteMeshInput,
+    ) -> RusotoFuture<DeleteMeshOutput, DeleteMeshError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
+
+        let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteMesh");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteMesh")
+                        .deserialize::<DeleteMeshOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteMeshError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing route.</p>
+    fn delete_route(
+        &self,
+        input: DeleteRouteInput,
+    ) -> RusotoFuture<DeleteRouteOutput, DeleteRouteError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes/{route_name}",
+            mesh_name = input.mesh_name,
+            route_name = input.route_name,
+            virtual_router_name = input.virtual_router_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteRoute");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("routeName", &input.route_name.to_string());
+        request.add_resource_id("virtualRouterName", &input.virtual_router_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteRoute")
+                        .deserialize::<DeleteRouteOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteRouteError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing virtual node.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You must delete any virtual services that list a virtual node as a service provider
+    /// before you can delete the virtual node itself.&lt;/p&gt;
+    /// </code></pre>
+    fn delete_virtual_node(
+        &self,
+        input: DeleteVirtualNodeInput,
+    ) -> RusotoFuture<DeleteVirtualNodeOutput, DeleteVirtualNodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualNodes/{virtual_node_name}",
+            mesh_name = input.mesh_name,
+            virtual_node_name = input.virtual_node_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualNode");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("virtualNodeName", &input.virtual_node_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteVirtualNode")
+                        .deserialize::<DeleteVirtualNodeOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteVirtualNodeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing virtual router.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You must delete any routes associated with the virtual router before you can delete the
+    /// router itself.&lt;/p&gt;
+    /// </code></pre>
+    fn delete_virtual_router(
+        &self,
+        input: DeleteVirtualRouterInput,
+    ) -> RusotoFuture<DeleteVirtualRouterOutput, DeleteVirtualRouterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualRouters/{virtual_router_name}",
+            mesh_name = input.mesh_name,
+            virtual_router_name = input.virtual_router_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualRouter");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("virtualRouterName", &input.virtual_router_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteVirtualRouter")
+                        .deserialize::<DeleteVirtualRouterOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteVirtualRouterError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing virtual service.</p>
+    fn delete_virtual_service(
+        &self,
+        input: DeleteVirtualServiceInput,
+    ) -> RusotoFuture<DeleteVirtualServiceOutput, DeleteVirtualServiceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualServices/{virtual_service_name}",
+            mesh_name = input.mesh_name,
+            virtual_service_name = input.virtual_service_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualService");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id(
+            "virtualServiceName",
+            &input.virtual_service_name.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteVirtualService")
+                        .deserialize::<DeleteVirtualServiceOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteVirtualServiceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes an existing service mesh.</p>
+    fn describe_mesh(
+        &self,
+        input: DescribeMeshInput,
+    ) -> RusotoFuture<DescribeMeshOutput, DescribeMeshError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeMesh");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeMesh")
+                        .deserialize::<DescribeMeshOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeMeshError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes an existing route.</p>
+    fn describe_route(
+        &self,
+        input: DescribeRouteInput,
+    ) -> RusotoFuture<DescribeRouteOutput, DescribeRouteError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes/{route_name}",
+            mesh_name = input.mesh_name,
+            route_name = input.route_name,
+            virtual_router_name = input.virtual_router_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeRoute");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("routeName", &input.route_name.to_string());
+        request.add_resource_id("virtualRouterName", &input.virtual_router_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeRoute")
+                        .deserialize::<DescribeRouteOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeRouteError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes an existing virtual node.</p>
+    fn describe_virtual_node(
+        &self,
+        input: DescribeVirtualNodeInput,
+    ) -> RusotoFuture<DescribeVirtualNodeOutput, DescribeVirtualNodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualNodes/{virtual_node_name}",
+            mesh_name = input.mesh_name,
+            virtual_node_name = input.virtual_node_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualNode");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("virtualNodeName", &input.virtual_node_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeVirtualNode")
+                        .deserialize::<DescribeVirtualNodeOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeVirtualNodeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes an existing virtual router.</p>
+    fn describe_virtual_router(
+        &self,
+        input: DescribeVirtualRouterInput,
+    ) -> RusotoFuture<DescribeVirtualRouterOutput, DescribeVirtualRouterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualRouters/{virtual_router_name}",
+            mesh_name = input.mesh_name,
+            virtual_router_name = input.virtual_router_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualRouter");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("virtualRouterName", &input.virtual_router_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeVirtualRouter")
+                        .deserialize::<DescribeVirtualRouterOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeVirtualRouterError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes an existing virtual service.</p>
+    fn describe_virtual_service(
+        &self,
+        input: DescribeVirtualServiceInput,
+    ) -> RusotoFuture<DescribeVirtualServiceOutput, DescribeVirtualServiceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualServices/{virtual_service_name}",
+            mesh_name = input.mesh_name,
+            virtual_service_name = input.virtual_service_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualService");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id(
+            "virtualServiceName",
+            &input.virtual_service_name.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeVirtualService")
+                        .deserialize::<DescribeVirtualServiceOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeVirtualServiceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of existing service meshes.</p>
+    fn list_meshes(
+        &self,
+        input: ListMeshesInput,
+    ) -> RusotoFuture<ListMeshesOutput, ListMeshesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/v20190125/meshes";
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListMeshes");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListMeshes")
+                        .deserialize::<ListMeshesOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListMeshesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of existing routes in a service mesh.</p>
+    fn list_routes(
+        &self,
+        input: ListRoutesInput,
+    ) -> RusotoFuture<ListRoutesOutput, ListRoutesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes",
+            mesh_name = input.mesh_name,
+            virtual_router_name = input.virtual_router_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListRoutes");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("virtualRouterName", &input.virtual_router_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListRoutes")
+                        .deserialize::<ListRoutesOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRoutesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>List the tags for an App Mesh resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/v20190125/tags";
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        params.put("resourceArn", &input.resource_arn);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
+                        .deserialize::<ListTagsForResourceOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of existing virtual nodes.</p>
+    fn list_virtual_nodes(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> RusotoFuture<ListVirtualNodesOutput, ListVirtualNodesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualNodes",
+            mesh_name = input.mesh_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualNodes");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListVirtualNodes")
+                        .deserialize::<ListVirtualNodesOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListVirtualNodesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of existing virtual routers in a service mesh.</p>
+    fn list_virtual_routers(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> RusotoFuture<ListVirtualRoutersOutput, ListVirtualRoutersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualRouters",
+            mesh_name = input.mesh_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualRouters");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListVirtualRouters")
+                        .deserialize::<ListVirtualRoutersOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListVirtualRoutersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of existing virtual services in a service mesh.</p>
+    fn list_virtual_services(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> RusotoFuture<ListVirtualServicesOutput, ListVirtualServicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualServices",
+            mesh_name = input.mesh_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualServices");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListVirtualServices")
+                        .deserialize::<ListVirtualServicesOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListVirtualServicesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>.
+    /// If existing tags on a resource aren't specified in the request parameters, they aren't
+    /// changed. When a resource is deleted, the tags associated with that resource are also
+    /// deleted.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/v20190125/tag";
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("resourceArn", &input.resource_arn);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
+                        .deserialize::<TagResourceOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/v20190125/untag";
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("resourceArn", &input.resource_arn);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
+                        .deserialize::<UntagResourceOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing service mesh.</p>
+    fn update_mesh(
+        &self,
+        input: UpdateMeshInput,
+    ) -> RusotoFuture<UpdateMeshOutput, UpdateMeshError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateMesh");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateMesh")
+                        .deserialize::<UpdateMeshOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateMeshError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing route for a specified service mesh and virtual router.</p>
+    fn update_route(
+        &self,
+        input: UpdateRouteInput,
+    ) -> RusotoFuture<UpdateRouteOutput, UpdateRouteError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes/{route_name}",
+            mesh_name = input.mesh_name,
+            route_name = input.route_name,
+            virtual_router_name = input.virtual_router_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateRoute");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("routeName", &input.route_name.to_string());
+        request.add_resource_id("virtualRouterName", &input.virtual_router_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateRoute")
+                        .deserialize::<UpdateRouteOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateRouteError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing virtual node in a specified service mesh.</p>
+    fn update_virtual_node(
+        &self,
+        input: UpdateVirtualNodeInput,
+    ) -> RusotoFuture<UpdateVirtualNodeOutput, UpdateVirtualNodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualNodes/{virtual_node_name}",
+            mesh_name = input.mesh_name,
+            virtual_node_name = input.virtual_node_name
+        );
 
-    /// <p>Describes an existing virtual router.</p>
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualNode");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("virtualNodeName", &input.virtual_node_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateVirtualNode")
+                        .deserialize::<UpdateVirtualNodeOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateVirtualNodeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing virtual router in a specified service mesh.</p>
+    fn update_virtual_router(
+        &self,
+        input: UpdateVirtualRouterInput,
+    ) -> RusotoFuture<UpdateVirtualRouterOutput, UpdateVirtualRouterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualRouters/{virtual_router_name}",
+            mesh_name = input.mesh_name,
+            virtual_router_name = input.virtual_router_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualRouter");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id("virtualRouterName", &input.virtual_router_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateVirtualRouter")
+                        .deserialize::<UpdateVirtualRouterOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateVirtualRouterError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing virtual service in a specified service mesh.</p>
+    fn update_virtual_service(
+        &self,
+        input: UpdateVirtualServiceInput,
+    ) -> RusotoFuture<UpdateVirtualServiceOutput, UpdateVirtualServiceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v20190125/meshes/{mesh_name}/virtualServices/{virtual_service_name}",
+            mesh_name = input.mesh_name,
+            virtual_service_name = input.virtual_service_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualService");
+        request.add_resource_id("meshName", &input.mesh_name.to_string());
+        request.add_resource_id(
+            "virtualServiceName",
+            &input.virtual_service_name.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateVirtualService")
+                        .deserialize::<UpdateVirtualServiceOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateVirtualServiceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the AWS App Mesh API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAppMesh {
+    create_mesh: Option<
+        Box<
+            dyn Fn(
+                    CreateMeshInput,
+                )
+                    -> ::std::result::Result<CreateMeshOutput, RusotoError<CreateMeshError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_route: Option<
+        Box<
+            dyn Fn(
+                    CreateRouteInput,
+                )
+                    -> ::std::result::Result<CreateRouteOutput, RusotoError<CreateRouteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_virtual_node: Option<
+        Box<
+            dyn Fn(
+                    CreateVirtualNodeInput,
+                ) -> ::std::result::Result<
+                    CreateVirtualNodeOutput,
+                    RusotoError<CreateVirtualNodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_virtual_router: Option<
+        Box<
+            dyn Fn(
+                    CreateVirtualRouterInput,
+                ) -> ::std::result::Result<
+                    CreateVirtualRouterOutput,
+                    RusotoError<CreateVirtualRouterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_virtual_service: Option<
+        Box<
+            dyn Fn(
+                    CreateVirtualServiceInput,
+                ) -> ::std::result::Result<
+                    CreateVirtualServiceOutput,
+                    RusotoError<CreateVirtualServiceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_mesh: Option<
+        Box<
+            dyn Fn(
+                    DeleteMeshInput,
+                )
+                    -> ::std::result::Result<DeleteMeshOutput, RusotoError<DeleteMeshError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_route: Option<
+        Box<
+            dyn Fn(
+                    DeleteRouteInput,
+                )
+                    -> ::std::result::Result<DeleteRouteOutput, RusotoError<DeleteRouteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_virtual_node: Option<
+        Box<
+            dyn Fn(
+                    DeleteVirtualNodeInput,
+                ) -> ::std::result::Result<
+                    DeleteVirtualNodeOutput,
+                    RusotoError<DeleteVirtualNodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_virtual_router: Option<
+        Box<
+            dyn Fn(
+                    DeleteVirtualRouterInput,
+                ) -> ::std::result::Result<
+                    DeleteVirtualRouterOutput,
+                    RusotoError<DeleteVirtualRouterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_virtual_service: Option<
+        Box<
+            dyn Fn(
+                    DeleteVirtualServiceInput,
+                ) -> ::std::result::Result<
+                    DeleteVirtualServiceOutput,
+                    RusotoError<DeleteVirtualServiceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_mesh: Option<
+        Box<
+            dyn Fn(
+                    DescribeMeshInput,
+                )
+                    -> ::std::result::Result<DescribeMeshOutput, RusotoError<DescribeMeshError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_route: Option<
+        Box<
+            dyn Fn(
+                    DescribeRouteInput,
+                )
+                    -> ::std::result::Result<DescribeRouteOutput, RusotoError<DescribeRouteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_virtual_node: Option<
+        Box<
+            dyn Fn(
+                    DescribeVirtualNodeInput,
+                ) -> ::std::result::Result<
+                    DescribeVirtualNodeOutput,
+                    RusotoError<DescribeVirtualNodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_virtual_router: Option<
+        Box<
+            dyn Fn(
+                    DescribeVirtualRouterInput,
+                ) -> ::std::result::Result<
+                    DescribeVirtualRouterOutput,
+                    RusotoError<DescribeVirtualRouterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_virtual_service: Option<
+        Box<
+            dyn Fn(
+                    DescribeVirtualServiceInput,
+                ) -> ::std::result::Result<
+                    DescribeVirtualServiceOutput,
+                    RusotoError<DescribeVirtualServiceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_meshes: Option<
+        Box<
+            dyn Fn(
+                    ListMeshesInput,
+                )
+                    -> ::std::result::Result<ListMeshesOutput, RusotoError<ListMeshesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_routes: Option<
+        Box<
+            dyn Fn(
+                    ListRoutesInput,
+                )
+                    -> ::std::result::Result<ListRoutesOutput, RusotoError<ListRoutesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceOutput,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_virtual_nodes: Option<
+        Box<
+            dyn Fn(
+                    ListVirtualNodesInput,
+                ) -> ::std::result::Result<
+                    ListVirtualNodesOutput,
+                    RusotoError<ListVirtualNodesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_virtual_routers: Option<
+        Box<
+            dyn Fn(
+                    ListVirtualRoutersInput,
+                ) -> ::std::result::Result<
+                    ListVirtualRoutersOutput,
+                    RusotoError<ListVirtualRoutersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_virtual_services: Option<
+        Box<
+            dyn Fn(
+                    ListVirtualServicesInput,
+                ) -> ::std::result::Result<
+                    ListVirtualServicesOutput,
+                    RusotoError<ListVirtualServicesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceInput,
+                )
+                    -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceInput,
+                )
+                    -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_mesh: Option<
+        Box<
+            dyn Fn(
+                    UpdateMeshInput,
+                )
+                    -> ::std::result::Result<UpdateMeshOutput, RusotoError<UpdateMeshError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_route: Option<
+        Box<
+            dyn Fn(
+                    UpdateRouteInput,
+                )
+                    -> ::std::result::Result<UpdateRouteOutput, RusotoError<UpdateRouteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_virtual_node: Option<
+        Box<
+            dyn Fn(
+                    UpdateVirtualNodeInput,
+                ) -> ::std::result::Result<
+                    UpdateVirtualNodeOutput,
+                    RusotoError<UpdateVirtualNodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_virtual_router: Option<
+        Box<
+            dyn Fn(
+                    UpdateVirtualRouterInput,
+                ) -> ::std::result::Result<
+                    UpdateVirtualRouterOutput,
+                    RusotoError<UpdateVirtualRouterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_virtual_service: Option<
+        Box<
+            dyn Fn(
+                    UpdateVirtualServiceInput,
+                ) -> ::std::result::Result<
+                    UpdateVirtualServiceOutput,
+                    RusotoError<UpdateVirtualServiceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAppMesh {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAppMesh {
+        Default::default()
+    }
+
+    /// Answers `create_mesh` with what `handler` returns for its input.
+    pub fn with_create_mesh<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                CreateMeshInput,
+            ) -> ::std::result::Result<CreateMeshOutput, RusotoError<CreateMeshError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_mesh = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_route` with what `handler` returns for its input.
+    pub fn with_create_route<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                CreateRouteInput,
+            )
+                -> ::std::result::Result<CreateRouteOutput, RusotoError<CreateRouteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_route = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_virtual_node` with what `handler` returns for its input.
+    pub fn with_create_virtual_node<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                CreateVirtualNodeInput,
+            ) -> ::std::result::Result<
+                CreateVirtualNodeOutput,
+                RusotoError<CreateVirtualNodeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_virtual_node = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_virtual_router` with what `handler` returns for its input.
+    pub fn with_create_virtual_router<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                CreateVirtualRouterInput,
+            ) -> ::std::result::Result<
+                CreateVirtualRouterOutput,
+                RusotoError<CreateVirtualRouterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_virtual_router = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_virtual_service` with what `handler` returns for its input.
+    pub fn with_create_virtual_service<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                CreateVirtualServiceInput,
+            ) -> ::std::result::Result<
+                CreateVirtualServiceOutput,
+                RusotoError<CreateVirtualServiceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_virtual_service = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_mesh` with what `handler` returns for its input.
+    pub fn with_delete_mesh<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                DeleteMeshInput,
+            ) -> ::std::result::Result<DeleteMeshOutput, RusotoError<DeleteMeshError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_mesh = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_route` with what `handler` returns for its input.
+    pub fn with_delete_route<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                DeleteRouteInput,
+            )
+                -> ::std::result::Result<DeleteRouteOutput, RusotoError<DeleteRouteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_route = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_virtual_node` with what `handler` returns for its input.
+    pub fn with_delete_virtual_node<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                DeleteVirtualNodeInput,
+            ) -> ::std::result::Result<
+                DeleteVirtualNodeOutput,
+                RusotoError<DeleteVirtualNodeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_virtual_node = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_virtual_router` with what `handler` returns for its input.
+    pub fn with_delete_virtual_router<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                DeleteVirtualRouterInput,
+            ) -> ::std::result::Result<
+                DeleteVirtualRouterOutput,
+                RusotoError<DeleteVirtualRouterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_virtual_router = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_virtual_service` with what `handler` returns for its input.
+    pub fn with_delete_virtual_service<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                DeleteVirtualServiceInput,
+            ) -> ::std::result::Result<
+                DeleteVirtualServiceOutput,
+                RusotoError<DeleteVirtualServiceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_virtual_service = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_mesh` with what `handler` returns for its input.
+    pub fn with_describe_mesh<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                DescribeMeshInput,
+            )
+                -> ::std::result::Result<DescribeMeshOutput, RusotoError<DescribeMeshError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_mesh = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_route` with what `handler` returns for its input.
+    pub fn with_describe_route<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                DescribeRouteInput,
+            )
+                -> ::std::result::Result<DescribeRouteOutput, RusotoError<DescribeRouteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_route = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_virtual_node` with what `handler` returns for its input.
+    pub fn with_describe_virtual_node<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                DescribeVirtualNodeInput,
+            ) -> ::std::result::Result<
+                DescribeVirtualNodeOutput,
+                RusotoError<DescribeVirtualNodeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_virtual_node = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_virtual_router` with what `handler` returns for its input.
+    pub fn with_describe_virtual_router<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                DescribeVirtualRouterInput,
+            ) -> ::std::result::Result<
+                DescribeVirtualRouterOutput,
+                RusotoError<DescribeVirtualRouterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_virtual_router = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_virtual_service` with what `handler` returns for its input.
+    pub fn with_describe_virtual_service<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                DescribeVirtualServiceInput,
+            ) -> ::std::result::Result<
+                DescribeVirtualServiceOutput,
+                RusotoError<DescribeVirtualServiceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_virtual_service = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_meshes` with what `handler` returns for its input.
+    pub fn with_list_meshes<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                ListMeshesInput,
+            ) -> ::std::result::Result<ListMeshesOutput, RusotoError<ListMeshesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_meshes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_routes` with what `handler` returns for its input.
+    pub fn with_list_routes<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                ListRoutesInput,
+            ) -> ::std::result::Result<ListRoutesOutput, RusotoError<ListRoutesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_routes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceOutput,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_virtual_nodes` with what `handler` returns for its input.
+    pub fn with_list_virtual_nodes<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                ListVirtualNodesInput,
+            )
+                -> ::std::result::Result<ListVirtualNodesOutput, RusotoError<ListVirtualNodesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_virtual_nodes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_virtual_routers` with what `handler` returns for its input.
+    pub fn with_list_virtual_routers<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                ListVirtualRoutersInput,
+            ) -> ::std::result::Result<
+                ListVirtualRoutersOutput,
+                RusotoError<ListVirtualRoutersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_virtual_routers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_virtual_services` with what `handler` returns for its input.
+    pub fn with_list_virtual_services<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                ListVirtualServicesInput,
+            ) -> ::std::result::Result<
+                ListVirtualServicesOutput,
+                RusotoError<ListVirtualServicesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_virtual_services = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                TagResourceInput,
+            )
+                -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                UntagResourceInput,
+            )
+                -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_mesh` with what `handler` returns for its input.
+    pub fn with_update_mesh<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                UpdateMeshInput,
+            ) -> ::std::result::Result<UpdateMeshOutput, RusotoError<UpdateMeshError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_mesh = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_route` with what `handler` returns for its input.
+    pub fn with_update_route<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                UpdateRouteInput,
+            )
+                -> ::std::result::Result<UpdateRouteOutput, RusotoError<UpdateRouteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_route = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_virtual_node` with what `handler` returns for its input.
+    pub fn with_update_virtual_node<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                UpdateVirtualNodeInput,
+            ) -> ::std::result::Result<
+                UpdateVirtualNodeOutput,
+                RusotoError<UpdateVirtualNodeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_virtual_node = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_virtual_router` with what `handler` returns for its input.
+    pub fn with_update_virtual_router<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                UpdateVirtualRouterInput,
+            ) -> ::std::result::Result<
+                UpdateVirtualRouterOutput,
+                RusotoError<UpdateVirtualRouterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_virtual_router = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_virtual_service` with what `handler` returns for its input.
+    pub fn with_update_virtual_service<F>(mut self, handler: F) -> MockAppMesh
+    where
+        F: Fn(
+                UpdateVirtualServiceInput,
+            ) -> ::std::result::Result<
+                UpdateVirtualServiceOutput,
+                RusotoError<UpdateVirtualServiceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_virtual_service = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl AppMesh for MockAppMesh {
+    fn create_mesh(
+        &self,
+        input: CreateMeshInput,
+    ) -> RusotoFuture<CreateMeshOutput, CreateMeshError> {
+        match self.create_mesh {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for create_mesh"),
+        }
+    }
+    fn create_route(
+        &self,
+        input: CreateRouteInput,
+    ) -> RusotoFuture<CreateRouteOutput, CreateRouteError> {
+        match self.create_route {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for create_route"),
+        }
+    }
+    fn create_virtual_node(
+        &self,
+        input: CreateVirtualNodeInput,
+    ) -> RusotoFuture<CreateVirtualNodeOutput, CreateVirtualNodeError> {
+        match self.create_virtual_node {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for create_virtual_node"),
+        }
+    }
+    fn create_virtual_router(
+        &self,
+        input: CreateVirtualRouterInput,
+    ) -> RusotoFuture<CreateVirtualRouterOutput, CreateVirtualRouterError> {
+        match self.create_virtual_router {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for create_virtual_router"),
+        }
+    }
+    fn create_virtual_service(
+        &self,
+        input: CreateVirtualServiceInput,
+    ) -> RusotoFuture<CreateVirtualServiceOutput, CreateVirtualServiceError> {
+        match self.create_virtual_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for create_virtual_service"),
+        }
+    }
+    fn delete_mesh(
+        &self,
+        input: DeleteMeshInput,
+    ) -> RusotoFuture<DeleteMeshOutput, DeleteMeshError> {
+        match self.delete_mesh {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for delete_mesh"),
+        }
+    }
+    fn delete_route(
+        &self,
+        input: DeleteRouteInput,
+    ) -> RusotoFuture<DeleteRouteOutput, DeleteRouteError> {
+        match self.delete_route {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for delete_route"),
+        }
+    }
+    fn delete_virtual_node(
+        &self,
+        input: DeleteVirtualNodeInput,
+    ) -> RusotoFuture<DeleteVirtualNodeOutput, DeleteVirtualNodeError> {
+        match self.delete_virtual_node {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for delete_virtual_node"),
+        }
+    }
+    fn delete_virtual_router(
+        &self,
+        input: DeleteVirtualRouterInput,
+    ) -> RusotoFuture<DeleteVirtualRouterOutput, DeleteVirtualRouterError> {
+        match self.delete_virtual_router {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for delete_virtual_router"),
+        }
+    }
+    fn delete_virtual_service(
+        &self,
+        input: DeleteVirtualServiceInput,
+    ) -> RusotoFuture<DeleteVirtualServiceOutput, DeleteVirtualServiceError> {
+        match self.delete_virtual_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for delete_virtual_service"),
+        }
+    }
+    fn describe_mesh(
+        &self,
+        input: DescribeMeshInput,
+    ) -> RusotoFuture<DescribeMeshOutput, DescribeMeshError> {
+        match self.describe_mesh {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for describe_mesh"),
+        }
+    }
+    fn describe_route(
+        &self,
+        input: DescribeRouteInput,
+    ) -> RusotoFuture<DescribeRouteOutput, DescribeRouteError> {
+        match self.describe_route {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for describe_route"),
+        }
+    }
+    fn describe_virtual_node(
+        &self,
+        input: DescribeVirtualNodeInput,
+    ) -> RusotoFuture<DescribeVirtualNodeOutput, DescribeVirtualNodeError> {
+        match self.describe_virtual_node {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for describe_virtual_node"),
+        }
+    }
     fn describe_virtual_router(
         &self,
         input: DescribeVirtualRouterInput,
     ) -> RusotoFuture<DescribeVirtualRouterOutput, DescribeVirtualRouterError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualRouters/{virtual_router_name}",
-            mesh_name = input.mesh_name,
-            virtual_router_name = input.virtual_router_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeVirtualRouterOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeVirtualRouterError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.describe_virtual_router {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for describe_virtual_router"),
+        }
     }
-
-    /// <p>Describes an existing virtual service.</p>
     fn describe_virtual_service(
         &self,
         input: DescribeVirtualServiceInput,
     ) -> RusotoFuture<DescribeVirtualServiceOutput, DescribeVirtualServiceError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualServices/{virtual_service_name}",
-            mesh_name = input.mesh_name,
-            virtual_service_name = input.virtual_service_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeVirtualServiceOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeVirtualServiceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.describe_virtual_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for describe_virtual_service"),
+        }
     }
-
-    /// <p>Returns a list of existing service meshes.</p>
     fn list_meshes(
         &self,
         input: ListMeshesInput,
     ) -> RusotoFuture<ListMeshesOutput, ListMeshesError> {
-        let request_uri = "/v20190125/meshes";
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
+        match self.list_meshes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for list_meshes"),
         }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListMeshesOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListMeshesError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Returns a list of existing routes in a service mesh.</p>
     fn list_routes(
         &self,
         input: ListRoutesInput,
     ) -> RusotoFuture<ListRoutesOutput, ListRoutesError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes",
-            mesh_name = input.mesh_name,
-            virtual_router_name = input.virtual_router_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
+        match self.list_routes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for list_routes"),
         }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRoutesOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRoutesError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>List the tags for an App Mesh resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
-        let request_uri = "/v20190125/tags";
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for list_tags_for_resource"),
         }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        params.put("resourceArn", &input.resource_arn);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
     }
-
-    /// <p>Returns a list of existing virtual nodes.</p>
     fn list_virtual_nodes(
         &self,
         input: ListVirtualNodesInput,
     ) -> RusotoFuture<ListVirtualNodesOutput, ListVirtualNodesError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualNodes",
-            mesh_name = input.mesh_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
+        match self.list_virtual_nodes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for list_virtual_nodes"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVirtualNodesOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListVirtualNodesError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Returns a list of existing virtual routers in a service mesh.</p>
     fn list_virtual_routers(
         &self,
         input: ListVirtualRoutersInput,
     ) -> RusotoFuture<ListVirtualRoutersOutput, ListVirtualRoutersError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualRouters",
-            mesh_name = input.mesh_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
+        match self.list_virtual_routers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for list_virtual_routers"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVirtualRoutersOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListVirtualRoutersError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Returns a list of existing virtual services in a service mesh.</p>
     fn list_virtual_services(
         &self,
         input: ListVirtualServicesInput,
     ) -> RusotoFuture<ListVirtualServicesOutput, ListVirtualServicesError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualServices",
-            mesh_name = input.mesh_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
+        match self.list_virtual_services {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for list_virtual_services"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVirtualServicesOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListVirtualServicesError::from_response(response))
-                    }),
-                )
-            }
-        })
     }
-
-    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>.
-    /// If existing tags on a resource aren't specified in the request parameters, they aren't
-    /// changed. When a resource is deleted, the tags associated with that resource are also
-    /// deleted.</p>
     fn tag_resource(
         &self,
         input: TagResourceInput,
     ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
-        let request_uri = "/v20190125/tag";
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("resourceArn", &input.resource_arn);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Deletes specified tags from a resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
-        let request_uri = "/v20190125/untag";
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("resourceArn", &input.resource_arn);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Updates an existing service mesh.</p>
     fn update_mesh(
         &self,
         input: UpdateMeshInput,
     ) -> RusotoFuture<UpdateMeshOutput, UpdateMeshError> {
-        let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateMeshOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateMeshError::from_response(response))),
-                )
-            }
-        })
+        match self.update_mesh {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for update_mesh"),
+        }
     }
-
-    /// <p>Updates an existing route for a specified service mesh and virtual router.</p>
     fn update_route(
         &self,
         input: UpdateRouteInput,
     ) -> RusotoFuture<UpdateRouteOutput, UpdateRouteError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes/{route_name}",
-            mesh_name = input.mesh_name,
-            route_name = input.route_name,
-            virtual_router_name = input.virtual_router_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRouteOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateRouteError::from_response(response))),
-                )
-            }
-        })
+        match self.update_route {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for update_route"),
+        }
     }
-
-    /// <p>Updates an existing virtual node in a specified service mesh.</p>
     fn update_virtual_node(
         &self,
         input: UpdateVirtualNodeInput,
     ) -> RusotoFuture<UpdateVirtualNodeOutput, UpdateVirtualNodeError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualNodes/{virtual_node_name}",
-            mesh_name = input.mesh_name,
-            virtual_node_name = input.virtual_node_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateVirtualNodeOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateVirtualNodeError::from_response(response))),
-                )
-            }
-        })
+        match self.update_virtual_node {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for update_virtual_node"),
+        }
     }
-
-    /// <p>Updates an existing virtual router in a specified service mesh.</p>
     fn update_virtual_router(
         &self,
         input: UpdateVirtualRouterInput,
     ) -> RusotoFuture<UpdateVirtualRouterOutput, UpdateVirtualRouterError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualRouters/{virtual_router_name}",
-            mesh_name = input.mesh_name,
-            virtual_router_name = input.virtual_router_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateVirtualRouterOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateVirtualRouterError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_virtual_router {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for update_virtual_router"),
+        }
     }
-
-    /// <p>Updates an existing virtual service in a specified service mesh.</p>
     fn update_virtual_service(
         &self,
         input: UpdateVirtualServiceInput,
     ) -> RusotoFuture<UpdateVirtualServiceOutput, UpdateVirtualServiceError> {
-        let request_uri = format!(
-            "/v20190125/meshes/{mesh_name}/virtualServices/{virtual_service_name}",
-            mesh_name = input.mesh_name,
-            virtual_service_name = input.virtual_service_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateVirtualServiceOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateVirtualServiceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_virtual_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppMesh has no handler for update_virtual_service"),
+        }
     }
 }
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Describes an interface VPC endpoint (interface endpoint) that lets you create a private connection between the virtual private cloud (VPC) that you specify and AppStream 2.0. When you specify an interface endpoint for a stack, users of the stack can connect to AppStream 2.0 only through that endpoint. When you specify an interface endpoint for an image builder, administrators can connect to the image builder only through that endpoint.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -35,9 +36,22 @@ pub struct AccessEndpoint {
     pub vpce_id: Option<String>,
 }
 
+impl Validate for AccessEndpoint {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.vpce_id {
+            errors.check_length(
+                &field_path(path, "vpce_id"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Describes an application in the application catalog.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Application {
     /// <p>The application name to display.</p>
     #[serde(rename = "DisplayName")]
@@ -71,6 +85,7 @@ pub struct Application {
 
 /// <p>The persistent application settings for users of a stack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationSettings {
     /// <p>Enables or disables persistent application settings for users during their streaming sessions. </p>
     #[serde(rename = "Enabled")]
@@ -81,9 +96,22 @@ pub struct ApplicationSettings {
     pub settings_group: Option<String>,
 }
 
+impl Validate for ApplicationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.settings_group {
+            errors.check_length(
+                &field_path(path, "settings_group"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Describes the persistent application settings for users of a stack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApplicationSettingsResponse {
     /// <p>Specifies whether persistent application settings are enabled for users during their streaming sessions.</p>
     #[serde(rename = "Enabled")]
@@ -100,6 +128,7 @@ pub struct ApplicationSettingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateFleetRequest {
     /// <p>The name of the fleet. </p>
     #[serde(rename = "FleetName")]
@@ -109,19 +138,52 @@ pub struct AssociateFleetRequest {
     pub stack_name: String,
 }
 
+impl Validate for AssociateFleetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_name"),
+            self.fleet_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateFleetResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchAssociateUserStackRequest {
     /// <p>The list of UserStackAssociation objects.</p>
     #[serde(rename = "UserStackAssociations")]
     pub user_stack_associations: Vec<UserStackAssociation>,
 }
 
+impl Validate for BatchAssociateUserStackRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_stack_associations"),
+            self.user_stack_associations.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.user_stack_associations.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "user_stack_associations"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchAssociateUserStackResult {
     /// <p>The list of UserStackAssociationError objects.</p>
     #[serde(rename = "errors")]
@@ -130,14 +192,30 @@ pub struct BatchAssociateUserStackResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDisassociateUserStackRequest {
     /// <p>The list of UserStackAssociation objects.</p>
     #[serde(rename = "UserStackAssociations")]
     pub user_stack_associations: Vec<UserStackAssociation>,
 }
 
+impl Validate for BatchDisassociateUserStackRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_stack_associations"),
+            self.user_stack_associations.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.user_stack_associations.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "user_stack_associations"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDisassociateUserStackResult {
     /// <p>The list of UserStackAssociationError objects.</p>
     #[serde(rename = "errors")]
@@ -147,6 +225,7 @@ pub struct BatchDisassociateUserStackResult {
 
 /// <p>Describes the capacity for a fleet.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ComputeCapacity {
     /// <p>The desired number of streaming instances.</p>
     #[serde(rename = "DesiredInstances")]
@@ -155,7 +234,7 @@ pub struct ComputeCapacity {
 
 /// <p>Describes the capacity status for a fleet.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComputeCapacityStatus {
     /// <p>The number of currently available instances that can be used to stream sessions.</p>
     #[serde(rename = "Available")]
@@ -175,6 +254,7 @@ pub struct ComputeCapacityStatus {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyImageRequest {
     /// <p>The description that the image will have when it is copied to the destination.</p>
     #[serde(rename = "DestinationImageDescription")]
@@ -191,8 +271,37 @@ pub struct CopyImageRequest {
     pub source_image_name: String,
 }
 
+impl Validate for CopyImageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination_image_description {
+            errors.check_length(
+                &field_path(path, "destination_image_description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "destination_image_name"),
+            &self.destination_image_name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+        errors.check_length(
+            &field_path(path, "destination_region"),
+            self.destination_region.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "source_image_name"),
+            &self.source_image_name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CopyImageResponse {
     /// <p>The name of the destination image.</p>
     #[serde(rename = "DestinationImageName")]
@@ -201,6 +310,7 @@ pub struct CopyImageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDirectoryConfigRequest {
     /// <p>The fully qualified name of the directory (for example, corp.example.com).</p>
     #[serde(rename = "DirectoryName")]
@@ -213,8 +323,27 @@ pub struct CreateDirectoryConfigRequest {
     pub service_account_credentials: ServiceAccountCredentials,
 }
 
+impl Validate for CreateDirectoryConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self
+            .organizational_unit_distinguished_names
+            .iter()
+            .enumerate()
+        {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "organizational_unit_distinguished_names"),
+                i
+            );
+            errors.check_length(&item_path, item.chars().count(), None, Some(2000));
+        }
+        self.service_account_credentials
+            .validate_at(&field_path(path, "service_account_credentials"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDirectoryConfigResult {
     /// <p>Information about the directory configuration.</p>
     #[serde(rename = "DirectoryConfig")]
@@ -223,6 +352,7 @@ pub struct CreateDirectoryConfigResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFleetRequest {
     /// <p>The desired capacity for the fleet.</p>
     #[serde(rename = "ComputeCapacity")]
@@ -287,8 +417,67 @@ pub struct CreateFleetRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for CreateFleetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.domain_join_info {
+            value.validate_at(&field_path(path, "domain_join_info"), errors);
+        }
+        if let Some(ref value) = self.iam_role_arn {
+            errors.check_pattern(&field_path(path, "iam_role_arn"), &value, "^arn:aws(?:\\-cn|\\-iso\\-b|\\-iso|\\-us\\-gov)?:[A-Za-z0-9][A-Za-z0-9_/.-]{0,62}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9][A-Za-z0-9:_/+=,@.\\\\-]{0,1023}$");
+        }
+        if let Some(ref value) = self.image_arn {
+            errors.check_pattern(&field_path(path, "image_arn"), &value, "^arn:aws(?:\\-cn|\\-iso\\-b|\\-iso|\\-us\\-gov)?:[A-Za-z0-9][A-Za-z0-9_/.-]{0,62}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9][A-Za-z0-9:_/+=,@.\\\\-]{0,1023}$");
+        }
+        if let Some(ref value) = self.image_name {
+            errors.check_pattern(
+                &field_path(path, "image_name"),
+                &value,
+                "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "instance_type"),
+            self.instance_type.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFleetResult {
     /// <p>Information about the fleet.</p>
     #[serde(rename = "Fleet")]
@@ -297,6 +486,7 @@ pub struct CreateFleetResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateImageBuilderRequest {
     /// <p>The list of interface VPC endpoint (interface endpoint) objects. Administrators can connect to the image builder only through the specified endpoints.</p>
     #[serde(rename = "AccessEndpoints")]
@@ -350,8 +540,88 @@ pub struct CreateImageBuilderRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for CreateImageBuilderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.access_endpoints {
+            errors.check_length(
+                &field_path(path, "access_endpoints"),
+                value.len(),
+                Some(1),
+                Some(4),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "access_endpoints"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.appstream_agent_version {
+            errors.check_length(
+                &field_path(path, "appstream_agent_version"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.domain_join_info {
+            value.validate_at(&field_path(path, "domain_join_info"), errors);
+        }
+        if let Some(ref value) = self.iam_role_arn {
+            errors.check_pattern(&field_path(path, "iam_role_arn"), &value, "^arn:aws(?:\\-cn|\\-iso\\-b|\\-iso|\\-us\\-gov)?:[A-Za-z0-9][A-Za-z0-9_/.-]{0,62}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9][A-Za-z0-9:_/+=,@.\\\\-]{0,1023}$");
+        }
+        if let Some(ref value) = self.image_arn {
+            errors.check_pattern(&field_path(path, "image_arn"), &value, "^arn:aws(?:\\-cn|\\-iso\\-b|\\-iso|\\-us\\-gov)?:[A-Za-z0-9][A-Za-z0-9_/.-]{0,62}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9][A-Za-z0-9:_/+=,@.\\\\-]{0,1023}$");
+        }
+        if let Some(ref value) = self.image_name {
+            errors.check_length(
+                &field_path(path, "image_name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        errors.check_length(
+            &field_path(path, "instance_type"),
+            self.instance_type.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateImageBuilderResult {
     /// <p>Information about the image builder.</p>
     #[serde(rename = "ImageBuilder")]
@@ -360,6 +630,7 @@ pub struct CreateImageBuilderResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateImageBuilderStreamingURLRequest {
     /// <p>The name of the image builder.</p>
     #[serde(rename = "Name")]
@@ -370,8 +641,19 @@ pub struct CreateImageBuilderStreamingURLRequest {
     pub validity: Option<i64>,
 }
 
+impl Validate for CreateImageBuilderStreamingURLRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateImageBuilderStreamingURLResult {
     /// <p>The elapsed time, in seconds after the Unix epoch, when this URL expires.</p>
     #[serde(rename = "Expires")]
@@ -384,6 +666,7 @@ pub struct CreateImageBuilderStreamingURLResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStackRequest {
     /// <p>The list of interface VPC endpoint (interface endpoint) objects. Users of the stack can connect to AppStream 2.0 only through the specified endpoints.</p>
     #[serde(rename = "AccessEndpoints")]
@@ -426,8 +709,87 @@ pub struct CreateStackRequest {
     pub user_settings: Option<Vec<UserSetting>>,
 }
 
+impl Validate for CreateStackRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.access_endpoints {
+            errors.check_length(
+                &field_path(path, "access_endpoints"),
+                value.len(),
+                Some(1),
+                Some(4),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "access_endpoints"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.application_settings {
+            value.validate_at(&field_path(path, "application_settings"), errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.feedback_url {
+            errors.check_length(
+                &field_path(path, "feedback_url"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+        if let Some(ref value) = self.redirect_url {
+            errors.check_length(
+                &field_path(path, "redirect_url"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.storage_connectors {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "storage_connectors"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+        if let Some(ref value) = self.user_settings {
+            errors.check_length(
+                &field_path(path, "user_settings"),
+                value.len(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateStackResult {
     /// <p>Information about the stack.</p>
     #[serde(rename = "Stack")]
@@ -436,6 +798,7 @@ pub struct CreateStackResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStreamingURLRequest {
     /// <p>The name of the application to launch after the session starts. This is the name that you specified as <b>Name</b> in the Image Assistant.</p>
     #[serde(rename = "ApplicationId")]
@@ -460,8 +823,48 @@ pub struct CreateStreamingURLRequest {
     pub validity: Option<i64>,
 }
 
+impl Validate for CreateStreamingURLRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_id {
+            errors.check_length(
+                &field_path(path, "application_id"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        errors.check_length(
+            &field_path(path, "fleet_name"),
+            self.fleet_name.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.session_context {
+            errors.check_length(
+                &field_path(path, "session_context"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(2),
+            Some(32),
+        );
+        errors.check_pattern(&field_path(path, "user_id"), &self.user_id, "[\\w+=,.@-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateStreamingURLResult {
     /// <p>The elapsed time, in seconds after the Unix epoch, when this URL expires.</p>
     #[serde(rename = "Expires")]
@@ -474,10 +877,11 @@ pub struct CreateStreamingURLResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUsageReportSubscriptionRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUsageReportSubscriptionResult {
     /// <p>The Amazon S3 bucket where generated reports are stored.</p> <p>If you enabled on-instance session scripts and Amazon S3 logging for your session script configuration, AppStream 2.0 created an S3 bucket to store the script output. The bucket is unique to your account and Region. When you enable usage reporting in this case, AppStream 2.0 uses the same bucket to store your usage reports. If you haven't already enabled on-instance session scripts, when you enable usage reports, AppStream 2.0 creates a new S3 bucket.</p>
     #[serde(rename = "S3BucketName")]
@@ -490,6 +894,7 @@ pub struct CreateUsageReportSubscriptionResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL. </p>
     #[serde(rename = "AuthenticationType")]
@@ -511,11 +916,54 @@ pub struct CreateUserRequest {
     pub user_name: String,
 }
 
+impl Validate for CreateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.first_name {
+            errors.check_length(
+                &field_path(path, "first_name"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "first_name"),
+                &value,
+                "^[A-Za-z0-9_\\-\\s]+$",
+            );
+        }
+        if let Some(ref value) = self.last_name {
+            errors.check_length(
+                &field_path(path, "last_name"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "last_name"),
+                &value,
+                "^[A-Za-z0-9_\\-\\s]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDirectoryConfigRequest {
     /// <p>The name of the directory configuration.</p>
     #[serde(rename = "DirectoryName")]
@@ -523,29 +971,52 @@ pub struct DeleteDirectoryConfigRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDirectoryConfigResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFleetRequest {
     /// <p>The name of the fleet.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteFleetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFleetResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteImageBuilderRequest {
     /// <p>The name of the image builder.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteImageBuilderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteImageBuilderResult {
     /// <p>Information about the image builder.</p>
     #[serde(rename = "ImageBuilder")]
@@ -554,6 +1025,7 @@ pub struct DeleteImageBuilderResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteImagePermissionsRequest {
     /// <p>The name of the private image.</p>
     #[serde(rename = "Name")]
@@ -563,19 +1035,45 @@ pub struct DeleteImagePermissionsRequest {
     pub shared_account_id: String,
 }
 
+impl Validate for DeleteImagePermissionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+        errors.check_pattern(
+            &field_path(path, "shared_account_id"),
+            &self.shared_account_id,
+            "^\\d+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteImagePermissionsResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteImageRequest {
     /// <p>The name of the image.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteImageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteImageResult {
     /// <p>Information about the image.</p>
     #[serde(rename = "Image")]
@@ -584,24 +1082,38 @@ pub struct DeleteImageResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStackRequest {
     /// <p>The name of the stack.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteStackRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteStackResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUsageReportSubscriptionRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUsageReportSubscriptionResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
@@ -611,11 +1123,28 @@ pub struct DeleteUserRequest {
     pub user_name: String,
 }
 
+impl Validate for DeleteUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUserResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDirectoryConfigsRequest {
     /// <p>The directory names.</p>
     #[serde(rename = "DirectoryNames")]
@@ -631,8 +1160,21 @@ pub struct DescribeDirectoryConfigsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeDirectoryConfigsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDirectoryConfigsResult {
     /// <p>Information about the directory configurations. Note that although the response syntax in this topic includes the account password, this password is not returned in the actual response. </p>
     #[serde(rename = "DirectoryConfigs")]
@@ -645,6 +1187,7 @@ pub struct DescribeDirectoryConfigsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFleetsRequest {
     /// <p>The names of the fleets to describe.</p>
     #[serde(rename = "Names")]
@@ -656,8 +1199,27 @@ pub struct DescribeFleetsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeFleetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), None);
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFleetsResult {
     /// <p>Information about the fleets.</p>
     #[serde(rename = "Fleets")]
@@ -670,6 +1232,7 @@ pub struct DescribeFleetsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeImageBuildersRequest {
     /// <p>The maximum size of each page of results.</p>
     #[serde(rename = "MaxResults")]
@@ -685,8 +1248,27 @@ pub struct DescribeImageBuildersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeImageBuildersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), None);
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeImageBuildersResult {
     /// <p>Information about the image builders.</p>
     #[serde(rename = "ImageBuilders")]
@@ -699,6 +1281,7 @@ pub struct DescribeImageBuildersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeImagePermissionsRequest {
     /// <p>The maximum size of each page of results.</p>
     #[serde(rename = "MaxResults")]
@@ -717,8 +1300,41 @@ pub struct DescribeImagePermissionsRequest {
     pub shared_aws_account_ids: Option<Vec<String>>,
 }
 
+impl Validate for DescribeImagePermissionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(500));
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.shared_aws_account_ids {
+            errors.check_length(
+                &field_path(path, "shared_aws_account_ids"),
+                value.len(),
+                Some(1),
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "shared_aws_account_ids"), i);
+                errors.check_pattern(&item_path, &item, "^\\d+$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeImagePermissionsResult {
     /// <p>The name of the private image.</p>
     #[serde(rename = "Name")]
@@ -735,6 +1351,7 @@ pub struct DescribeImagePermissionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeImagesRequest {
     /// <p>The ARNs of the public, private, and shared images to describe.</p>
     #[serde(rename = "Arns")]
@@ -758,8 +1375,36 @@ pub struct DescribeImagesRequest {
     pub type_: Option<String>,
 }
 
+impl Validate for DescribeImagesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "arns"), i);
+                errors.check_pattern(&item_path, &item, "^arn:aws(?:\\-cn|\\-iso\\-b|\\-iso|\\-us\\-gov)?:[A-Za-z0-9][A-Za-z0-9_/.-]{0,62}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9][A-Za-z0-9:_/+=,@.\\\\-]{0,1023}$");
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        if let Some(ref value) = self.names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), None);
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeImagesResult {
     /// <p>Information about the images.</p>
     #[serde(rename = "Images")]
@@ -772,6 +1417,7 @@ pub struct DescribeImagesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSessionsRequest {
     /// <p>The authentication method. Specify <code>API</code> for a user authenticated using a streaming URL or <code>SAML</code> for a SAML federated user. The default is to authenticate users using a streaming URL.</p>
     #[serde(rename = "AuthenticationType")]
@@ -797,8 +1443,39 @@ pub struct DescribeSessionsRequest {
     pub user_id: Option<String>,
 }
 
+impl Validate for DescribeSessionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_name"),
+            &self.fleet_name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "stack_name"),
+            &self.stack_name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+        if let Some(ref value) = self.user_id {
+            errors.check_length(
+                &field_path(path, "user_id"),
+                value.chars().count(),
+                Some(2),
+                Some(128),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSessionsResult {
     /// <p>The pagination token to use to retrieve the next page of results for this operation. If there are no more pages, this value is null.</p>
     #[serde(rename = "NextToken")]
@@ -811,6 +1488,7 @@ pub struct DescribeSessionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStacksRequest {
     /// <p>The names of the stacks to describe.</p>
     #[serde(rename = "Names")]
@@ -822,8 +1500,27 @@ pub struct DescribeStacksRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeStacksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), None);
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStacksResult {
     /// <p>The pagination token to use to retrieve the next page of results for this operation. If there are no more pages, this value is null.</p>
     #[serde(rename = "NextToken")]
@@ -836,6 +1533,7 @@ pub struct DescribeStacksResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUsageReportSubscriptionsRequest {
     /// <p>The maximum size of each page of results.</p>
     #[serde(rename = "MaxResults")]
@@ -847,8 +1545,21 @@ pub struct DescribeUsageReportSubscriptionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeUsageReportSubscriptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUsageReportSubscriptionsResult {
     /// <p>The pagination token to use to retrieve the next page of results for this operation. If there are no more pages, this value is null.</p>
     #[serde(rename = "NextToken")]
@@ -861,6 +1572,7 @@ pub struct DescribeUsageReportSubscriptionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserStackAssociationsRequest {
     /// <p>The authentication type for the user who is associated with the stack. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
@@ -884,8 +1596,45 @@ pub struct DescribeUserStackAssociationsRequest {
     pub user_name: Option<String>,
 }
 
+impl Validate for DescribeUserStackAssociationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(500));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.stack_name {
+            errors.check_length(
+                &field_path(path, "stack_name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.user_name {
+            errors.check_length(
+                &field_path(path, "user_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "user_name"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserStackAssociationsResult {
     /// <p>The pagination token to use to retrieve the next page of results for this operation. If there are no more pages, this value is null.</p>
     #[serde(rename = "NextToken")]
@@ -898,6 +1647,7 @@ pub struct DescribeUserStackAssociationsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUsersRequest {
     /// <p>The authentication type for the users in the user pool to describe. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
@@ -912,8 +1662,21 @@ pub struct DescribeUsersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUsersResult {
     /// <p>The pagination token to use to retrieve the next page of results for this operation. If there are no more pages, this value is null.</p>
     #[serde(rename = "NextToken")]
@@ -927,7 +1690,7 @@ pub struct DescribeUsersResult {
 
 /// <p>Describes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DirectoryConfig {
     /// <p>The time the directory configuration was created.</p>
     #[serde(rename = "CreatedTime")]
@@ -947,6 +1710,7 @@ pub struct DirectoryConfig {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
@@ -956,11 +1720,28 @@ pub struct DisableUserRequest {
     pub user_name: String,
 }
 
+impl Validate for DisableUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisableUserResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateFleetRequest {
     /// <p>The name of the fleet.</p>
     #[serde(rename = "FleetName")]
@@ -970,8 +1751,25 @@ pub struct DisassociateFleetRequest {
     pub stack_name: String,
 }
 
+impl Validate for DisassociateFleetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_name"),
+            self.fleet_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateFleetResult {}
 
 /// <p>Describes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
@@ -987,7 +1785,21 @@ pub struct DomainJoinInfo {
     pub organizational_unit_distinguished_name: Option<String>,
 }
 
+impl Validate for DomainJoinInfo {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.organizational_unit_distinguished_name {
+            errors.check_length(
+                &field_path(path, "organizational_unit_distinguished_name"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
@@ -997,24 +1809,52 @@ pub struct EnableUserRequest {
     pub user_name: String,
 }
 
+impl Validate for EnableUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableUserResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExpireSessionRequest {
     /// <p>The identifier of the streaming session.</p>
     #[serde(rename = "SessionId")]
     pub session_id: String,
 }
 
+impl Validate for ExpireSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "session_id"),
+            self.session_id.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExpireSessionResult {}
 
 /// <p>Describes a fleet.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Fleet {
     /// <p>The Amazon Resource Name (ARN) for the fleet.</p>
     #[serde(rename = "Arn")]
@@ -1091,7 +1931,7 @@ pub struct Fleet {
 
 /// <p>Describes a fleet error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FleetError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
@@ -1105,7 +1945,7 @@ pub struct FleetError {
 
 /// <p>Describes an image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Image {
     /// <p>The applications associated with the image.</p>
     #[serde(rename = "Applications")]
@@ -1174,7 +2014,7 @@ pub struct Image {
 
 /// <p>Describes a virtual machine that is used to create an image. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImageBuilder {
     /// <p>The list of virtual private cloud (VPC) interface endpoint objects. Administrators can connect to the image builder only through the specified endpoints.</p>
     #[serde(rename = "AccessEndpoints")]
@@ -1250,7 +2090,7 @@ pub struct ImageBuilder {
 
 /// <p>Describes the reason why the last image builder state change occurred.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImageBuilderStateChangeReason {
     /// <p>The state change reason code.</p>
     #[serde(rename = "Code")]
@@ -1277,7 +2117,7 @@ pub struct ImagePermissions {
 
 /// <p>Describes the reason why the last image state change occurred.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImageStateChangeReason {
     /// <p>The state change reason code.</p>
     #[serde(rename = "Code")]
@@ -1291,7 +2131,7 @@ pub struct ImageStateChangeReason {
 
 /// <p>Describes the error that is returned when a usage report can't be generated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LastReportGenerationExecutionError {
     /// <p>The error code for the error that is returned when a usage report can't be generated.</p>
     #[serde(rename = "ErrorCode")]
@@ -1304,6 +2144,7 @@ pub struct LastReportGenerationExecutionError {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAssociatedFleetsRequest {
     /// <p>The pagination token to use to retrieve the next page of results for this operation. If this value is null, it retrieves the first page.</p>
     #[serde(rename = "NextToken")]
@@ -1314,8 +2155,27 @@ pub struct ListAssociatedFleetsRequest {
     pub stack_name: String,
 }
 
+impl Validate for ListAssociatedFleetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAssociatedFleetsResult {
     /// <p>The name of the fleet.</p>
     #[serde(rename = "Names")]
@@ -1328,6 +2188,7 @@ pub struct ListAssociatedFleetsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAssociatedStacksRequest {
     /// <p>The name of the fleet.</p>
     #[serde(rename = "FleetName")]
@@ -1338,8 +2199,27 @@ pub struct ListAssociatedStacksRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAssociatedStacksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_name"),
+            self.fleet_name.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAssociatedStacksResult {
     /// <p>The name of the stack.</p>
     #[serde(rename = "Names")]
@@ -1352,14 +2232,21 @@ pub struct ListAssociatedStacksResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "^arn:aws(?:\\-cn|\\-iso\\-b|\\-iso|\\-us\\-gov)?:[A-Za-z0-9][A-Za-z0-9_/.-]{0,62}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9][A-Za-z0-9:_/+=,@.\\\\-]{0,1023}$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The information about the tags.</p>
     #[serde(rename = "Tags")]
@@ -1369,7 +2256,7 @@ pub struct ListTagsForResourceResponse {
 
 /// <p>Describes the network details of the fleet or image builder instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkAccessConfiguration {
     /// <p>The resource identifier of the elastic network interface that is attached to instances in your VPC. All network interfaces have the eni-xxxxxxxx resource identifier.</p>
     #[serde(rename = "EniId")]
@@ -1383,7 +2270,7 @@ pub struct NetworkAccessConfiguration {
 
 /// <p>Describes a resource error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
@@ -1410,9 +2297,26 @@ pub struct ServiceAccountCredentials {
     pub account_password: String,
 }
 
+impl Validate for ServiceAccountCredentials {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_name"),
+            self.account_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "account_password"),
+            self.account_password.chars().count(),
+            Some(1),
+            Some(127),
+        );
+    }
+}
+
 /// <p>Describes a streaming session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Session {
     /// <p>The authentication method. The user is authenticated using a streaming URL (<code>API</code>) or SAML 2.0 federation (<code>SAML</code>).</p>
     #[serde(rename = "AuthenticationType")]
@@ -1453,7 +2357,7 @@ pub struct Session {
 
 /// <p>Describes the permissions that are available to the specified AWS account for a shared image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SharedImagePermissions {
     /// <p>Describes the permissions for a shared image.</p>
     #[serde(rename = "imagePermissions")]
@@ -1465,7 +2369,7 @@ pub struct SharedImagePermissions {
 
 /// <p>Describes a stack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Stack {
     /// <p>The list of virtual private cloud (VPC) interface endpoint objects. Users of the stack can connect to AppStream 2.0 only through the specified endpoints. </p>
     #[serde(rename = "AccessEndpoints")]
@@ -1518,7 +2422,7 @@ pub struct Stack {
 
 /// <p>Describes a stack error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StackError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
@@ -1531,17 +2435,30 @@ pub struct StackError {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartFleetRequest {
     /// <p>The name of the fleet.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for StartFleetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartFleetResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartImageBuilderRequest {
     /// <p>The version of the AppStream 2.0 agent to use for this image builder. To use the latest version of the AppStream 2.0 agent, specify [LATEST]. </p>
     #[serde(rename = "AppstreamAgentVersion")]
@@ -1552,8 +2469,27 @@ pub struct StartImageBuilderRequest {
     pub name: String,
 }
 
+impl Validate for StartImageBuilderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.appstream_agent_version {
+            errors.check_length(
+                &field_path(path, "appstream_agent_version"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartImageBuilderResult {
     /// <p>Information about the image builder.</p>
     #[serde(rename = "ImageBuilder")]
@@ -1562,25 +2498,49 @@ pub struct StartImageBuilderResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopFleetRequest {
     /// <p>The name of the fleet.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for StopFleetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopFleetResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopImageBuilderRequest {
     /// <p>The name of the image builder.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for StopImageBuilderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopImageBuilderResult {
     /// <p>Information about the image builder.</p>
     #[serde(rename = "ImageBuilder")]
@@ -1604,7 +2564,28 @@ pub struct StorageConnector {
     pub resource_identifier: Option<String>,
 }
 
+impl Validate for StorageConnector {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.domains {
+            errors.check_length(&field_path(path, "domains"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "domains"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+        if let Some(ref value) = self.resource_identifier {
+            errors.check_length(
+                &field_path(path, "resource_identifier"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -1614,11 +2595,29 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "^arn:aws(?:\\-cn|\\-iso\\-b|\\-iso|\\-us\\-gov)?:[A-Za-z0-9][A-Za-z0-9_/.-]{0,62}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9][A-Za-z0-9:_/+=,@.\\\\-]{0,1023}$");
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -1628,11 +2627,33 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "^arn:aws(?:\\-cn|\\-iso\\-b|\\-iso|\\-us\\-gov)?:[A-Za-z0-9][A-Za-z0-9_/.-]{0,62}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9][A-Za-z0-9:_/+=,@.\\\\-]{0,1023}$");
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "^(^(?!aws:).[\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDirectoryConfigRequest {
     /// <p>The name of the Directory Config object.</p>
     #[serde(rename = "DirectoryName")]
@@ -1647,8 +2668,26 @@ pub struct UpdateDirectoryConfigRequest {
     pub service_account_credentials: Option<ServiceAccountCredentials>,
 }
 
+impl Validate for UpdateDirectoryConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.organizational_unit_distinguished_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "organizational_unit_distinguished_names"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(2000));
+            }
+        }
+        if let Some(ref value) = self.service_account_credentials {
+            value.validate_at(&field_path(path, "service_account_credentials"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDirectoryConfigResult {
     /// <p>Information about the Directory Config object.</p>
     #[serde(rename = "DirectoryConfig")]
@@ -1657,6 +2696,7 @@ pub struct UpdateDirectoryConfigResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFleetRequest {
     /// <p>The fleet attributes to delete.</p>
     #[serde(rename = "AttributesToDelete")]
@@ -1720,8 +2760,64 @@ pub struct UpdateFleetRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for UpdateFleetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.domain_join_info {
+            value.validate_at(&field_path(path, "domain_join_info"), errors);
+        }
+        if let Some(ref value) = self.iam_role_arn {
+            errors.check_pattern(&field_path(path, "iam_role_arn"), &value, "^arn:aws(?:\\-cn|\\-iso\\-b|\\-iso|\\-us\\-gov)?:[A-Za-z0-9][A-Za-z0-9_/.-]{0,62}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9][A-Za-z0-9:_/+=,@.\\\\-]{0,1023}$");
+        }
+        if let Some(ref value) = self.image_arn {
+            errors.check_pattern(&field_path(path, "image_arn"), &value, "^arn:aws(?:\\-cn|\\-iso\\-b|\\-iso|\\-us\\-gov)?:[A-Za-z0-9][A-Za-z0-9_/.-]{0,62}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9_/.-]{0,63}:[A-Za-z0-9][A-Za-z0-9:_/+=,@.\\\\-]{0,1023}$");
+        }
+        if let Some(ref value) = self.image_name {
+            errors.check_length(
+                &field_path(path, "image_name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.instance_type {
+            errors.check_length(
+                &field_path(path, "instance_type"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFleetResult {
     /// <p>Information about the fleet.</p>
     #[serde(rename = "Fleet")]
@@ -1730,6 +2826,7 @@ pub struct UpdateFleetResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateImagePermissionsRequest {
     /// <p>The permissions for the image.</p>
     #[serde(rename = "ImagePermissions")]
@@ -1742,11 +2839,27 @@ pub struct UpdateImagePermissionsRequest {
     pub shared_account_id: String,
 }
 
+impl Validate for UpdateImagePermissionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9][a-zA-Z0-9_.-]{0,100}$",
+        );
+        errors.check_pattern(
+            &field_path(path, "shared_account_id"),
+            &self.shared_account_id,
+            "^\\d+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateImagePermissionsResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStackRequest {
     /// <p>The list of interface VPC endpoint (interface endpoint) objects. Users of the stack can connect to AppStream 2.0 only through the specified endpoints.</p>
     #[serde(rename = "AccessEndpoints")]
@@ -1789,8 +2902,80 @@ pub struct UpdateStackRequest {
     pub user_settings: Option<Vec<UserSetting>>,
 }
 
+impl Validate for UpdateStackRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.access_endpoints {
+            errors.check_length(
+                &field_path(path, "access_endpoints"),
+                value.len(),
+                Some(1),
+                Some(4),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "access_endpoints"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.application_settings {
+            value.validate_at(&field_path(path, "application_settings"), errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.feedback_url {
+            errors.check_length(
+                &field_path(path, "feedback_url"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.redirect_url {
+            errors.check_length(
+                &field_path(path, "redirect_url"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.storage_connectors {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "storage_connectors"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.user_settings {
+            errors.check_length(
+                &field_path(path, "user_settings"),
+                value.len(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateStackResult {
     /// <p>Information about the stack.</p>
     #[serde(rename = "Stack")]
@@ -1800,7 +2985,7 @@ pub struct UpdateStackResult {
 
 /// <p>Describes information about the usage report subscription.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UsageReportSubscription {
     /// <p>The time when the last usage report was generated.</p>
     #[serde(rename = "LastGeneratedReportDate")]
@@ -1822,7 +3007,7 @@ pub struct UsageReportSubscription {
 
 /// <p>Describes a user in the user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct User {
     /// <p>The ARN of the user.</p>
     #[serde(rename = "Arn")]
@@ -1886,9 +3071,31 @@ pub struct UserStackAssociation {
     pub user_name: String,
 }
 
+impl Validate for UserStackAssociation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Describes the error that is returned when a user can’t be associated with or disassociated from a stack. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserStackAssociationError {
     /// <p>The error code for the error that is returned when a user can’t be associated with or disassociated from a stack.</p>
     #[serde(rename = "ErrorCode")]
@@ -1917,6 +3124,29 @@ pub struct VpcConfig {
     pub subnet_ids: Option<Vec<String>>,
 }
 
+impl Validate for VpcConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.security_group_ids {
+            errors.check_length(
+                &field_path(path, "security_group_ids"),
+                value.len(),
+                None,
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "security_group_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), None);
+            }
+        }
+        if let Some(ref value) = self.subnet_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "subnet_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), None);
+            }
+        }
+    }
+}
+
 /// Errors returned by AssociateFleet
 #[derive(Debug, PartialEq)]
 pub enum AssociateFleetError {
@@ -1960,7 +3190,12 @@ impl AssociateFleetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(AssociateFleetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2000,7 +3235,12 @@ impl BatchAssociateUserStackError {
                         BatchAssociateUserStackError::OperationNotPermitted(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2029,7 +3269,12 @@ impl BatchDisassociateUserStackError {
     ) -> RusotoError<BatchDisassociateUserStackError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2085,7 +3330,12 @@ impl CopyImageError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CopyImageError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2137,7 +3387,12 @@ impl CreateDirectoryConfigError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2219,7 +3474,12 @@ impl CreateFleetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateFleetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2320,7 +3580,12 @@ impl CreateImageBuilderError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateImageBuilderError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2373,7 +3638,12 @@ impl CreateImageBuilderStreamingURLError {
                         CreateImageBuilderStreamingURLError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2439,7 +3709,12 @@ impl CreateStackError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateStackError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2499,7 +3774,12 @@ impl CreateStreamingURLError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateStreamingURLError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2553,7 +3833,12 @@ impl CreateUsageReportSubscriptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2610,7 +3895,12 @@ impl CreateUserError {
                 "ResourceAlreadyExistsException" => {
                     return RusotoError::Service(CreateUserError::ResourceAlreadyExists(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2654,7 +3944,12 @@ impl DeleteDirectoryConfigError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2698,7 +3993,12 @@ impl DeleteFleetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteFleetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2748,7 +4048,12 @@ impl DeleteImageError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteImageError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2798,7 +4103,12 @@ impl DeleteImageBuilderError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteImageBuilderError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2842,7 +4152,12 @@ impl DeleteImagePermissionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2886,7 +4201,12 @@ impl DeleteStackError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteStackError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2932,7 +4252,12 @@ impl DeleteUsageReportSubscriptionError {
                         DeleteUsageReportSubscriptionError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2966,7 +4291,12 @@ impl DeleteUserError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3001,7 +4331,12 @@ impl DescribeDirectoryConfigsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3034,7 +4369,12 @@ impl DescribeFleetsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeFleetsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3069,7 +4409,12 @@ impl DescribeImageBuildersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3104,7 +4449,12 @@ impl DescribeImagePermissionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3144,7 +4494,12 @@ impl DescribeImagesError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeImagesError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3180,7 +4535,12 @@ impl DescribeSessionsError {
                         DescribeSessionsError::InvalidParameterCombination(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3213,7 +4573,12 @@ impl DescribeStacksError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeStacksError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3257,7 +4622,12 @@ impl DescribeUsageReportSubscriptionsError {
                         DescribeUsageReportSubscriptionsError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3295,7 +4665,12 @@ impl DescribeUserStackAssociationsError {
                         DescribeUserStackAssociationsError::InvalidParameterCombination(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3335,7 +4710,12 @@ impl DescribeUsersError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeUsersError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3369,7 +4749,12 @@ impl DisableUserError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DisableUserError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3414,7 +4799,12 @@ impl DisassociateFleetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DisassociateFleetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3454,7 +4844,12 @@ impl EnableUserError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(EnableUserError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3482,7 +4877,12 @@ impl ExpireSessionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ExpireSessionError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3507,7 +4907,12 @@ impl ListAssociatedFleetsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAssociatedFleetsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3532,7 +4937,12 @@ impl ListAssociatedStacksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAssociatedStacksError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3565,7 +4975,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3628,7 +5043,12 @@ impl StartFleetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StartFleetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3693,7 +5113,12 @@ impl StartImageBuilderError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StartImageBuilderError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3735,7 +5160,12 @@ impl StopFleetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StopFleetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3783,7 +5213,12 @@ impl StopImageBuilderError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StopImageBuilderError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3828,7 +5263,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3863,7 +5303,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3910,7 +5355,12 @@ impl UpdateDirectoryConfigError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3992,7 +5442,12 @@ impl UpdateFleetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateFleetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4050,7 +5505,12 @@ impl UpdateImagePermissionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4127,7 +5587,12 @@ impl UpdateStackError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateStackError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4433,1203 +5898,2348 @@ pub trait AppStream {
         input: UpdateStackRequest,
     ) -> RusotoFuture<UpdateStackResult, UpdateStackError>;
 }
-/// A client for the Amazon AppStream API.
-#[derive(Clone)]
-pub struct AppStreamClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl AppStreamClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> AppStreamClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> AppStreamClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> AppStreamClient {
-        AppStreamClient { client, region }
-    }
-}
-
-impl AppStream for AppStreamClient {
-    /// <p>Associates the specified fleet with the specified stack.</p>
+const _: Option<&dyn AppStream> = None;
+impl<'a, T: AppStream + ?Sized> AppStream for &'a T {
     fn associate_fleet(
         &self,
         input: AssociateFleetRequest,
     ) -> RusotoFuture<AssociateFleetResult, AssociateFleetError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.AssociateFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateFleetResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AssociateFleetError::from_response(response))),
-                )
-            }
-        })
+        (**self).associate_fleet(input)
     }
-
-    /// <p>Associates the specified users with the specified stacks. Users in a user pool cannot be assigned to stacks with fleets that are joined to an Active Directory domain.</p>
     fn batch_associate_user_stack(
         &self,
         input: BatchAssociateUserStackRequest,
     ) -> RusotoFuture<BatchAssociateUserStackResult, BatchAssociateUserStackError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.BatchAssociateUserStack",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchAssociateUserStackResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchAssociateUserStackError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_associate_user_stack(input)
     }
-
-    /// <p>Disassociates the specified users from the specified stacks.</p>
     fn batch_disassociate_user_stack(
         &self,
         input: BatchDisassociateUserStackRequest,
     ) -> RusotoFuture<BatchDisassociateUserStackResult, BatchDisassociateUserStackError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.BatchDisassociateUserStack",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDisassociateUserStackResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchDisassociateUserStackError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_disassociate_user_stack(input)
     }
-
-    /// <p>Copies the image within the same region or to a new region within the same AWS account. Note that any tags you added to the image will not be copied.</p>
     fn copy_image(
         &self,
         input: CopyImageRequest,
     ) -> RusotoFuture<CopyImageResponse, CopyImageError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.CopyImage");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CopyImageResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CopyImageError::from_response(response))),
-                )
-            }
-        })
+        (**self).copy_image(input)
     }
-
-    /// <p>Creates a Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
     fn create_directory_config(
         &self,
         input: CreateDirectoryConfigRequest,
     ) -> RusotoFuture<CreateDirectoryConfigResult, CreateDirectoryConfigError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.CreateDirectoryConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDirectoryConfigResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateDirectoryConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_directory_config(input)
     }
-
-    /// <p>Creates a fleet. A fleet consists of streaming instances that run a specified image.</p>
     fn create_fleet(
         &self,
         input: CreateFleetRequest,
     ) -> RusotoFuture<CreateFleetResult, CreateFleetError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.CreateFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFleetResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateFleetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_fleet(input)
     }
-
-    /// <p>Creates an image builder. An image builder is a virtual machine that is used to create an image.</p> <p>The initial state of the builder is <code>PENDING</code>. When it is ready, the state is <code>RUNNING</code>.</p>
     fn create_image_builder(
         &self,
         input: CreateImageBuilderRequest,
     ) -> RusotoFuture<CreateImageBuilderResult, CreateImageBuilderError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.CreateImageBuilder");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateImageBuilderResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateImageBuilderError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_image_builder(input)
     }
-
-    /// <p>Creates a URL to start an image builder streaming session.</p>
     fn create_image_builder_streaming_url(
         &self,
         input: CreateImageBuilderStreamingURLRequest,
     ) -> RusotoFuture<CreateImageBuilderStreamingURLResult, CreateImageBuilderStreamingURLError>
     {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.CreateImageBuilderStreamingURL",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateImageBuilderStreamingURLResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateImageBuilderStreamingURLError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_image_builder_streaming_url(input)
     }
-
-    /// <p>Creates a stack to start streaming applications to users. A stack consists of an associated fleet, user access policies, and storage configurations. </p>
     fn create_stack(
         &self,
         input: CreateStackRequest,
     ) -> RusotoFuture<CreateStackResult, CreateStackError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStack");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateStackResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateStackError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_stack(input)
     }
-
-    /// <p>Creates a temporary URL to start an AppStream 2.0 streaming session for the specified user. A streaming URL enables application streaming to be tested without user setup. </p>
     fn create_streaming_url(
         &self,
         input: CreateStreamingURLRequest,
     ) -> RusotoFuture<CreateStreamingURLResult, CreateStreamingURLError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStreamingURL");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateStreamingURLResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateStreamingURLError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_streaming_url(input)
     }
-
-    /// <p>Creates a usage report subscription. Usage reports are generated daily.</p>
     fn create_usage_report_subscription(
         &self,
     ) -> RusotoFuture<CreateUsageReportSubscriptionResult, CreateUsageReportSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.CreateUsageReportSubscription",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUsageReportSubscriptionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateUsageReportSubscriptionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_usage_report_subscription()
     }
-
-    /// <p>Creates a new user in the user pool.</p>
     fn create_user(
         &self,
         input: CreateUserRequest,
     ) -> RusotoFuture<CreateUserResult, CreateUserError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.CreateUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_user(input)
     }
-
-    /// <p>Deletes the specified Directory Config object from AppStream 2.0. This object includes the information required to join streaming instances to an Active Directory domain.</p>
     fn delete_directory_config(
         &self,
         input: DeleteDirectoryConfigRequest,
     ) -> RusotoFuture<DeleteDirectoryConfigResult, DeleteDirectoryConfigError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.DeleteDirectoryConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDirectoryConfigResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteDirectoryConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_directory_config(input)
     }
-
-    /// <p>Deletes the specified fleet.</p>
     fn delete_fleet(
         &self,
         input: DeleteFleetRequest,
     ) -> RusotoFuture<DeleteFleetResult, DeleteFleetError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteFleetResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFleetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_fleet(input)
     }
-
-    /// <p>Deletes the specified image. You cannot delete an image when it is in use. After you delete an image, you cannot provision new capacity using the image.</p>
     fn delete_image(
         &self,
         input: DeleteImageRequest,
     ) -> RusotoFuture<DeleteImageResult, DeleteImageError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImage");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteImageResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteImageError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_image(input)
     }
-
-    /// <p>Deletes the specified image builder and releases the capacity.</p>
     fn delete_image_builder(
         &self,
         input: DeleteImageBuilderRequest,
     ) -> RusotoFuture<DeleteImageBuilderResult, DeleteImageBuilderError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImageBuilder");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteImageBuilderResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteImageBuilderError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_image_builder(input)
     }
-
-    /// <p>Deletes permissions for the specified private image. After you delete permissions for an image, AWS accounts to which you previously granted these permissions can no longer use the image.</p>
     fn delete_image_permissions(
         &self,
         input: DeleteImagePermissionsRequest,
     ) -> RusotoFuture<DeleteImagePermissionsResult, DeleteImagePermissionsError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.DeleteImagePermissions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteImagePermissionsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteImagePermissionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_image_permissions(input)
     }
-
-    /// <p>Deletes the specified stack. After the stack is deleted, the application streaming environment provided by the stack is no longer available to users. Also, any reservations made for application streaming sessions for the stack are released.</p>
     fn delete_stack(
         &self,
         input: DeleteStackRequest,
     ) -> RusotoFuture<DeleteStackResult, DeleteStackError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteStack");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteStackResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteStackError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_stack(input)
     }
-
-    /// <p>Disables usage report generation.</p>
     fn delete_usage_report_subscription(
         &self,
     ) -> RusotoFuture<DeleteUsageReportSubscriptionResult, DeleteUsageReportSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.DeleteUsageReportSubscription",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteUsageReportSubscriptionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteUsageReportSubscriptionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_usage_report_subscription()
     }
-
-    /// <p>Deletes a user from the user pool.</p>
     fn delete_user(
         &self,
         input: DeleteUserRequest,
     ) -> RusotoFuture<DeleteUserResult, DeleteUserError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteUserResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_user(input)
     }
-
-    /// <p>Retrieves a list that describes one or more specified Directory Config objects for AppStream 2.0, if the names for these objects are provided. Otherwise, all Directory Config objects in the account are described. These objects include the configuration information required to join fleets and image builders to Microsoft Active Directory domains. </p> <p>Although the response syntax in this topic includes the account password, this password is not returned in the actual response.</p>
     fn describe_directory_configs(
         &self,
         input: DescribeDirectoryConfigsRequest,
     ) -> RusotoFuture<DescribeDirectoryConfigsResult, DescribeDirectoryConfigsError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.DescribeDirectoryConfigs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDirectoryConfigsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDirectoryConfigsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_directory_configs(input)
     }
-
-    /// <p>Retrieves a list that describes one or more specified fleets, if the fleet names are provided. Otherwise, all fleets in the account are described.</p>
     fn describe_fleets(
         &self,
         input: DescribeFleetsRequest,
     ) -> RusotoFuture<DescribeFleetsResult, DescribeFleetsError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeFleets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeFleetsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeFleetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_fleets(input)
     }
-
-    /// <p>Retrieves a list that describes one or more specified image builders, if the image builder names are provided. Otherwise, all image builders in the account are described.</p>
     fn describe_image_builders(
         &self,
         input: DescribeImageBuildersRequest,
     ) -> RusotoFuture<DescribeImageBuildersResult, DescribeImageBuildersError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.DescribeImageBuilders",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeImageBuildersResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeImageBuildersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_image_builders(input)
     }
-
-    /// <p>Retrieves a list that describes the permissions for shared AWS account IDs on a private image that you own. </p>
     fn describe_image_permissions(
         &self,
         input: DescribeImagePermissionsRequest,
     ) -> RusotoFuture<DescribeImagePermissionsResult, DescribeImagePermissionsError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.DescribeImagePermissions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeImagePermissionsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeImagePermissionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_image_permissions(input)
     }
-
-    /// <p>Retrieves a list that describes one or more specified images, if the image names or image ARNs are provided. Otherwise, all images in the account are described.</p>
     fn describe_images(
         &self,
         input: DescribeImagesRequest,
     ) -> RusotoFuture<DescribeImagesResult, DescribeImagesError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeImages");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeImagesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeImagesError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_images(input)
     }
-
-    /// <p>Retrieves a list that describes the streaming sessions for a specified stack and fleet. If a UserId is provided for the stack and fleet, only streaming sessions for that user are described. If an authentication type is not provided, the default is to authenticate users using a streaming URL.</p>
     fn describe_sessions(
         &self,
         input: DescribeSessionsRequest,
     ) -> RusotoFuture<DescribeSessionsResult, DescribeSessionsError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeSessions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSessionsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeSessionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_sessions(input)
     }
-
-    /// <p>Retrieves a list that describes one or more specified stacks, if the stack names are provided. Otherwise, all stacks in the account are described.</p>
     fn describe_stacks(
         &self,
         input: DescribeStacksRequest,
     ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeStacks");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStacksResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeStacksError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_stacks(input)
     }
-
-    /// <p>Retrieves a list that describes one or more usage report subscriptions.</p>
     fn describe_usage_report_subscriptions(
         &self,
         input: DescribeUsageReportSubscriptionsRequest,
     ) -> RusotoFuture<DescribeUsageReportSubscriptionsResult, DescribeUsageReportSubscriptionsError>
     {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.DescribeUsageReportSubscriptions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUsageReportSubscriptionsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeUsageReportSubscriptionsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_usage_report_subscriptions(input)
     }
-
-    /// <p><p>Retrieves a list that describes the UserStackAssociation objects. You must specify either or both of the following:</p> <ul> <li> <p>The stack name</p> </li> <li> <p>The user name (email address of the user associated with the stack) and the authentication type for the user</p> </li> </ul></p>
     fn describe_user_stack_associations(
         &self,
         input: DescribeUserStackAssociationsRequest,
     ) -> RusotoFuture<DescribeUserStackAssociationsResult, DescribeUserStackAssociationsError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.DescribeUserStackAssociations",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUserStackAssociationsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeUserStackAssociationsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_user_stack_associations(input)
     }
-
-    /// <p>Retrieves a list that describes one or more specified users in the user pool.</p>
     fn describe_users(
         &self,
         input: DescribeUsersRequest,
     ) -> RusotoFuture<DescribeUsersResult, DescribeUsersError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeUsers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUsersResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeUsersError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_users(input)
     }
-
-    /// <p>Disables the specified user in the user pool. Users can't sign in to AppStream 2.0 until they are re-enabled. This action does not delete the user. </p>
     fn disable_user(
         &self,
         input: DisableUserRequest,
     ) -> RusotoFuture<DisableUserResult, DisableUserError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DisableUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisableUserResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisableUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).disable_user(input)
     }
-
-    /// <p>Disassociates the specified fleet from the specified stack.</p>
     fn disassociate_fleet(
         &self,
         input: DisassociateFleetRequest,
     ) -> RusotoFuture<DisassociateFleetResult, DisassociateFleetError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.DisassociateFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateFleetResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisassociateFleetError::from_response(response))),
-                )
-            }
-        })
+        (**self).disassociate_fleet(input)
     }
-
-    /// <p>Enables a user in the user pool. After being enabled, users can sign in to AppStream 2.0 and open applications from the stacks to which they are assigned.</p>
     fn enable_user(
         &self,
         input: EnableUserRequest,
     ) -> RusotoFuture<EnableUserResult, EnableUserError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.EnableUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnableUserResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).enable_user(input)
     }
-
-    /// <p>Immediately stops the specified streaming session.</p>
     fn expire_session(
         &self,
         input: ExpireSessionRequest,
     ) -> RusotoFuture<ExpireSessionResult, ExpireSessionError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.ExpireSession");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ExpireSessionResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ExpireSessionError::from_response(response))),
-                )
-            }
-        })
+        (**self).expire_session(input)
     }
-
-    /// <p>Retrieves the name of the fleet that is associated with the specified stack.</p>
     fn list_associated_fleets(
         &self,
         input: ListAssociatedFleetsRequest,
     ) -> RusotoFuture<ListAssociatedFleetsResult, ListAssociatedFleetsError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.ListAssociatedFleets",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAssociatedFleetsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListAssociatedFleetsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_associated_fleets(input)
     }
-
-    /// <p>Retrieves the name of the stack with which the specified fleet is associated.</p>
     fn list_associated_stacks(
         &self,
         input: ListAssociatedStacksRequest,
     ) -> RusotoFuture<ListAssociatedStacksResult, ListAssociatedStacksError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.ListAssociatedStacks",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAssociatedStacksResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListAssociatedStacksError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_associated_stacks(input)
     }
-
-    /// <p>Retrieves a list of all tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.ListTagsForResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Starts the specified fleet.</p>
     fn start_fleet(
         &self,
         input: StartFleetRequest,
     ) -> RusotoFuture<StartFleetResult, StartFleetError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.StartFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartFleetResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartFleetError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_fleet(input)
     }
-
-    /// <p>Starts the specified image builder.</p>
     fn start_image_builder(
         &self,
         input: StartImageBuilderRequest,
     ) -> RusotoFuture<StartImageBuilderResult, StartImageBuilderError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.StartImageBuilder");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartImageBuilderResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartImageBuilderError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_image_builder(input)
     }
-
-    /// <p>Stops the specified fleet.</p>
     fn stop_fleet(&self, input: StopFleetRequest) -> RusotoFuture<StopFleetResult, StopFleetError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.StopFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<StopFleetResult, _>()
-                }))
+        (**self).stop_fleet(input)
+    }
+    fn stop_image_builder(
+        &self,
+        input: StopImageBuilderRequest,
+    ) -> RusotoFuture<StopImageBuilderResult, StopImageBuilderError> {
+        (**self).stop_image_builder(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_directory_config(
+        &self,
+        input: UpdateDirectoryConfigRequest,
+    ) -> RusotoFuture<UpdateDirectoryConfigResult, UpdateDirectoryConfigError> {
+        (**self).update_directory_config(input)
+    }
+    fn update_fleet(
+        &self,
+        input: UpdateFleetRequest,
+    ) -> RusotoFuture<UpdateFleetResult, UpdateFleetError> {
+        (**self).update_fleet(input)
+    }
+    fn update_image_permissions(
+        &self,
+        input: UpdateImagePermissionsRequest,
+    ) -> RusotoFuture<UpdateImagePermissionsResult, UpdateImagePermissionsError> {
+        (**self).update_image_permissions(input)
+    }
+    fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> RusotoFuture<UpdateStackResult, UpdateStackError> {
+        (**self).update_stack(input)
+    }
+}
+impl<T: AppStream + ?Sized> AppStream for Box<T> {
+    fn associate_fleet(
+        &self,
+        input: AssociateFleetRequest,
+    ) -> RusotoFuture<AssociateFleetResult, AssociateFleetError> {
+        (**self).associate_fleet(input)
+    }
+    fn batch_associate_user_stack(
+        &self,
+        input: BatchAssociateUserStackRequest,
+    ) -> RusotoFuture<BatchAssociateUserStackResult, BatchAssociateUserStackError> {
+        (**self).batch_associate_user_stack(input)
+    }
+    fn batch_disassociate_user_stack(
+        &self,
+        input: BatchDisassociateUserStackRequest,
+    ) -> RusotoFuture<BatchDisassociateUserStackResult, BatchDisassociateUserStackError> {
+        (**self).batch_disassociate_user_stack(input)
+    }
+    fn copy_image(
+        &self,
+        input: CopyImageRequest,
+    ) -> RusotoFuture<CopyImageResponse, CopyImageError> {
+        (**self).copy_image(input)
+    }
+    fn create_directory_config(
+        &self,
+        input: CreateDirectoryConfigRequest,
+    ) -> RusotoFuture<CreateDirectoryConfigResult, CreateDirectoryConfigError> {
+        (**self).create_directory_config(input)
+    }
+    fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> RusotoFuture<CreateFleetResult, CreateFleetError> {
+        (**self).create_fleet(input)
+    }
+    fn create_image_builder(
+        &self,
+        input: CreateImageBuilderRequest,
+    ) -> RusotoFuture<CreateImageBuilderResult, CreateImageBuilderError> {
+        (**self).create_image_builder(input)
+    }
+    fn create_image_builder_streaming_url(
+        &self,
+        input: CreateImageBuilderStreamingURLRequest,
+    ) -> RusotoFuture<CreateImageBuilderStreamingURLResult, CreateImageBuilderStreamingURLError>
+    {
+        (**self).create_image_builder_streaming_url(input)
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> RusotoFuture<CreateStackResult, CreateStackError> {
+        (**self).create_stack(input)
+    }
+    fn create_streaming_url(
+        &self,
+        input: CreateStreamingURLRequest,
+    ) -> RusotoFuture<CreateStreamingURLResult, CreateStreamingURLError> {
+        (**self).create_streaming_url(input)
+    }
+    fn create_usage_report_subscription(
+        &self,
+    ) -> RusotoFuture<CreateUsageReportSubscriptionResult, CreateUsageReportSubscriptionError> {
+        (**self).create_usage_report_subscription()
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResult, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_directory_config(
+        &self,
+        input: DeleteDirectoryConfigRequest,
+    ) -> RusotoFuture<DeleteDirectoryConfigResult, DeleteDirectoryConfigError> {
+        (**self).delete_directory_config(input)
+    }
+    fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> RusotoFuture<DeleteFleetResult, DeleteFleetError> {
+        (**self).delete_fleet(input)
+    }
+    fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> RusotoFuture<DeleteImageResult, DeleteImageError> {
+        (**self).delete_image(input)
+    }
+    fn delete_image_builder(
+        &self,
+        input: DeleteImageBuilderRequest,
+    ) -> RusotoFuture<DeleteImageBuilderResult, DeleteImageBuilderError> {
+        (**self).delete_image_builder(input)
+    }
+    fn delete_image_permissions(
+        &self,
+        input: DeleteImagePermissionsRequest,
+    ) -> RusotoFuture<DeleteImagePermissionsResult, DeleteImagePermissionsError> {
+        (**self).delete_image_permissions(input)
+    }
+    fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> RusotoFuture<DeleteStackResult, DeleteStackError> {
+        (**self).delete_stack(input)
+    }
+    fn delete_usage_report_subscription(
+        &self,
+    ) -> RusotoFuture<DeleteUsageReportSubscriptionResult, DeleteUsageReportSubscriptionError> {
+        (**self).delete_usage_report_subscription()
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResult, DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_directory_configs(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> RusotoFuture<DescribeDirectoryConfigsResult, DescribeDirectoryConfigsError> {
+        (**self).describe_directory_configs(input)
+    }
+    fn describe_fleets(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> RusotoFuture<DescribeFleetsResult, DescribeFleetsError> {
+        (**self).describe_fleets(input)
+    }
+    fn describe_image_builders(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> RusotoFuture<DescribeImageBuildersResult, DescribeImageBuildersError> {
+        (**self).describe_image_builders(input)
+    }
+    fn describe_image_permissions(
+        &self,
+        input: DescribeImagePermissionsRequest,
+    ) -> RusotoFuture<DescribeImagePermissionsResult, DescribeImagePermissionsError> {
+        (**self).describe_image_permissions(input)
+    }
+    fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> RusotoFuture<DescribeImagesResult, DescribeImagesError> {
+        (**self).describe_images(input)
+    }
+    fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> RusotoFuture<DescribeSessionsResult, DescribeSessionsError> {
+        (**self).describe_sessions(input)
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
+        (**self).describe_stacks(input)
+    }
+    fn describe_usage_report_subscriptions(
+        &self,
+        input: DescribeUsageReportSubscriptionsRequest,
+    ) -> RusotoFuture<DescribeUsageReportSubscriptionsResult, DescribeUsageReportSubscriptionsError>
+    {
+        (**self).describe_usage_report_subscriptions(input)
+    }
+    fn describe_user_stack_associations(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> RusotoFuture<DescribeUserStackAssociationsResult, DescribeUserStackAssociationsError> {
+        (**self).describe_user_stack_associations(input)
+    }
+    fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> RusotoFuture<DescribeUsersResult, DescribeUsersError> {
+        (**self).describe_users(input)
+    }
+    fn disable_user(
+        &self,
+        input: DisableUserRequest,
+    ) -> RusotoFuture<DisableUserResult, DisableUserError> {
+        (**self).disable_user(input)
+    }
+    fn disassociate_fleet(
+        &self,
+        input: DisassociateFleetRequest,
+    ) -> RusotoFuture<DisassociateFleetResult, DisassociateFleetError> {
+        (**self).disassociate_fleet(input)
+    }
+    fn enable_user(
+        &self,
+        input: EnableUserRequest,
+    ) -> RusotoFuture<EnableUserResult, EnableUserError> {
+        (**self).enable_user(input)
+    }
+    fn expire_session(
+        &self,
+        input: ExpireSessionRequest,
+    ) -> RusotoFuture<ExpireSessionResult, ExpireSessionError> {
+        (**self).expire_session(input)
+    }
+    fn list_associated_fleets(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> RusotoFuture<ListAssociatedFleetsResult, ListAssociatedFleetsError> {
+        (**self).list_associated_fleets(input)
+    }
+    fn list_associated_stacks(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> RusotoFuture<ListAssociatedStacksResult, ListAssociatedStacksError> {
+        (**self).list_associated_stacks(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn start_fleet(
+        &self,
+        input: StartFleetRequest,
+    ) -> RusotoFuture<StartFleetResult, StartFleetError> {
+        (**self).start_fleet(input)
+    }
+    fn start_image_builder(
+        &self,
+        input: StartImageBuilderRequest,
+    ) -> RusotoFuture<StartImageBuilderResult, StartImageBuilderError> {
+        (**self).start_image_builder(input)
+    }
+    fn stop_fleet(&self, input: StopFleetRequest) -> RusotoFuture<StopFleetResult, StopFleetError> {
+        (**self).stop_fleet(input)
+    }
+    fn stop_image_builder(
+        &self,
+        input: StopImageBuilderRequest,
+    ) -> RusotoFuture<StopImageBuilderResult, StopImageBuilderError> {
+        (**self).stop_image_builder(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_directory_config(
+        &self,
+        input: UpdateDirectoryConfigRequest,
+    ) -> RusotoFuture<UpdateDirectoryConfigResult, UpdateDirectoryConfigError> {
+        (**self).update_directory_config(input)
+    }
+    fn update_fleet(
+        &self,
+        input: UpdateFleetRequest,
+    ) -> RusotoFuture<UpdateFleetResult, UpdateFleetError> {
+        (**self).update_fleet(input)
+    }
+    fn update_image_permissions(
+        &self,
+        input: UpdateImagePermissionsRequest,
+    ) -> RusotoFuture<UpdateImagePermissionsResult, UpdateImagePermissionsError> {
+        (**self).update_image_permissions(input)
+    }
+    fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> RusotoFuture<UpdateStackResult, UpdateStackError> {
+        (**self).update_stack(input)
+    }
+}
+impl<T: AppStream + ?Sized> AppStream for ::std::sync::Arc<T> {
+    fn associate_fleet(
+        &self,
+        input: AssociateFleetRequest,
+    ) -> RusotoFuture<AssociateFleetResult, AssociateFleetError> {
+        (**self).associate_fleet(input)
+    }
+    fn batch_associate_user_stack(
+        &self,
+        input: BatchAssociateUserStackRequest,
+    ) -> RusotoFuture<BatchAssociateUserStackResult, BatchAssociateUserStackError> {
+        (**self).batch_associate_user_stack(input)
+    }
+    fn batch_disassociate_user_stack(
+        &self,
+        input: BatchDisassociateUserStackRequest,
+    ) -> RusotoFuture<BatchDisassociateUserStackResult, BatchDisassociateUserStackError> {
+        (**self).batch_disassociate_user_stack(input)
+    }
+    fn copy_image(
+        &self,
+        input: CopyImageRequest,
+    ) -> RusotoFuture<CopyImageResponse, CopyImageError> {
+        (**self).copy_image(input)
+    }
+    fn create_directory_config(
+        &self,
+        input: CreateDirectoryConfigRequest,
+    ) -> RusotoFuture<CreateDirectoryConfigResult, CreateDirectoryConfigError> {
+        (**self).create_directory_config(input)
+    }
+    fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> RusotoFuture<CreateFleetResult, CreateFleetError> {
+        (**self).create_fleet(input)
+    }
+    fn create_image_builder(
+        &self,
+        input: CreateImageBuilderRequest,
+    ) -> RusotoFuture<CreateImageBuilderResult, CreateImageBuilderError> {
+        (**self).create_image_builder(input)
+    }
+    fn create_image_builder_streaming_url(
+        &self,
+        input: CreateImageBuilderStreamingURLRequest,
+    ) -> RusotoFuture<CreateImageBuilderStreamingURLResult, CreateImageBuilderStreamingURLError>
+    {
+        (**self).create_image_builder_streaming_url(input)
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> RusotoFuture<CreateStackResult, CreateStackError> {
+        (**self).create_stack(input)
+    }
+    fn create_streaming_url(
+        &self,
+        input: CreateStreamingURLRequest,
+    ) -> RusotoFuture<CreateStreamingURLResult, CreateStreamingURLError> {
+        (**self).create_streaming_url(input)
+    }
+    fn create_usage_report_subscription(
+        &self,
+    ) -> RusotoFuture<CreateUsageReportSubscriptionResult, CreateUsageReportSubscriptionError> {
+        (**self).create_usage_report_subscription()
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResult, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_directory_config(
+        &self,
+        input: DeleteDirectoryConfigRequest,
+    ) -> RusotoFuture<DeleteDirectoryConfigResult, DeleteDirectoryConfigError> {
+        (**self).delete_directory_config(input)
+    }
+    fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> RusotoFuture<DeleteFleetResult, DeleteFleetError> {
+        (**self).delete_fleet(input)
+    }
+    fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> RusotoFuture<DeleteImageResult, DeleteImageError> {
+        (**self).delete_image(input)
+    }
+    fn delete_image_builder(
+        &self,
+        input: DeleteImageBuilderRequest,
+    ) -> RusotoFuture<DeleteImageBuilderResult, DeleteImageBuilderError> {
+        (**self).delete_image_builder(input)
+    }
+    fn delete_image_permissions(
+        &self,
+        input: DeleteImagePermissionsRequest,
+    ) -> RusotoFuture<DeleteImagePermissionsResult, DeleteImagePermissionsError> {
+        (**self).delete_image_permissions(input)
+    }
+    fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> RusotoFuture<DeleteStackResult, DeleteStackError> {
+        (**self).delete_stack(input)
+    }
+    fn delete_usage_report_subscription(
+        &self,
+    ) -> RusotoFuture<DeleteUsageReportSubscriptionResult, DeleteUsageReportSubscriptionError> {
+        (**self).delete_usage_report_subscription()
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResult, DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_directory_configs(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> RusotoFuture<DescribeDirectoryConfigsResult, DescribeDirectoryConfigsError> {
+        (**self).describe_directory_configs(input)
+    }
+    fn describe_fleets(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> RusotoFuture<DescribeFleetsResult, DescribeFleetsError> {
+        (**self).describe_fleets(input)
+    }
+    fn describe_image_builders(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> RusotoFuture<DescribeImageBuildersResult, DescribeImageBuildersError> {
+        (**self).describe_image_builders(input)
+    }
+    fn describe_image_permissions(
+        &self,
+        input: DescribeImagePermissionsRequest,
+    ) -> RusotoFuture<DescribeImagePermissionsResult, DescribeImagePermissionsError> {
+        (**self).describe_image_permissions(input)
+    }
+    fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> RusotoFuture<DescribeImagesResult, DescribeImagesError> {
+        (**self).describe_images(input)
+    }
+    fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> RusotoFuture<DescribeSessionsResult, DescribeSessionsError> {
+        (**self).describe_sessions(input)
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
+        (**self).describe_stacks(input)
+    }
+    fn describe_usage_report_subscriptions(
+        &self,
+        input: DescribeUsageReportSubscriptionsRequest,
+    ) -> RusotoFuture<DescribeUsageReportSubscriptionsResult, DescribeUsageReportSubscriptionsError>
+    {
+        (**self).describe_usage_report_subscriptions(input)
+    }
+    fn describe_user_stack_associations(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> RusotoFuture<DescribeUserStackAssociationsResult, DescribeUserStackAssociationsError> {
+        (**self).describe_user_stack_associations(input)
+    }
+    fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> RusotoFuture<DescribeUsersResult, DescribeUsersError> {
+        (**self).describe_users(input)
+    }
+    fn disable_user(
+        &self,
+        input: DisableUserRequest,
+    ) -> RusotoFuture<DisableUserResult, DisableUserError> {
+        (**self).disable_user(input)
+    }
+    fn disassociate_fleet(
+        &self,
+        input: DisassociateFleetRequest,
+    ) -> RusotoFuture<DisassociateFleetResult, DisassociateFleetError> {
+        (**self).disassociate_fleet(input)
+    }
+    fn enable_user(
+        &self,
+        input: EnableUserRequest,
+    ) -> RusotoFuture<EnableUserResult, EnableUserError> {
+        (**self).enable_user(input)
+    }
+    fn expire_session(
+        &self,
+        input: ExpireSessionRequest,
+    ) -> RusotoFuture<ExpireSessionResult, ExpireSessionError> {
+        (**self).expire_session(input)
+    }
+    fn list_associated_fleets(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> RusotoFuture<ListAssociatedFleetsResult, ListAssociatedFleetsError> {
+        (**self).list_associated_fleets(input)
+    }
+    fn list_associated_stacks(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> RusotoFuture<ListAssociatedStacksResult, ListAssociatedStacksError> {
+        (**self).list_associated_stacks(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn start_fleet(
+        &self,
+        input: StartFleetRequest,
+    ) -> RusotoFuture<StartFleetResult, StartFleetError> {
+        (**self).start_fleet(input)
+    }
+    fn start_image_builder(
+        &self,
+        input: StartImageBuilderRequest,
+    ) -> RusotoFuture<StartImageBuilderResult, StartImageBuilderError> {
+        (**self).start_image_builder(input)
+    }
+    fn stop_fleet(&self, input: StopFleetRequest) -> RusotoFuture<StopFleetResult, StopFleetError> {
+        (**self).stop_fleet(input)
+    }
+    fn stop_image_builder(
+        &self,
+        input: StopImageBuilderRequest,
+    ) -> RusotoFuture<StopImageBuilderResult, StopImageBuilderError> {
+        (**self).stop_image_builder(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_directory_config(
+        &self,
+        input: UpdateDirectoryConfigRequest,
+    ) -> RusotoFuture<UpdateDirectoryConfigResult, UpdateDirectoryConfigError> {
+        (**self).update_directory_config(input)
+    }
+    fn update_fleet(
+        &self,
+        input: UpdateFleetRequest,
+    ) -> RusotoFuture<UpdateFleetResult, UpdateFleetError> {
+        (**self).update_fleet(input)
+    }
+    fn update_image_permissions(
+        &self,
+        input: UpdateImagePermissionsRequest,
+    ) -> RusotoFuture<UpdateImagePermissionsResult, UpdateImagePermissionsError> {
+        (**self).update_image_permissions(input)
+    }
+    fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> RusotoFuture<UpdateStackResult, UpdateStackError> {
+        (**self).update_stack(input)
+    }
+}
+/// A client for the Amazon AppStream API.
+#[derive(Clone)]
+pub struct AppStreamClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl AppStreamClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AppStreamClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppStreamClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> AppStreamClient {
+        AppStreamClient { client, region }
+    }
+}
+
+impl AppStream for AppStreamClient {
+    /// <p>Associates the specified fleet with the specified stack.</p>
+    fn associate_fleet(
+        &self,
+        input: AssociateFleetRequest,
+    ) -> RusotoFuture<AssociateFleetResult, AssociateFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("AssociateFleet");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.AssociateFleet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateFleetResult, _>(
+                    response,
+                    "AssociateFleet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AssociateFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates the specified users with the specified stacks. Users in a user pool cannot be assigned to stacks with fleets that are joined to an Active Directory domain.</p>
+    fn batch_associate_user_stack(
+        &self,
+        input: BatchAssociateUserStackRequest,
+    ) -> RusotoFuture<BatchAssociateUserStackResult, BatchAssociateUserStackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("BatchAssociateUserStack");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.BatchAssociateUserStack",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchAssociateUserStackResult, _>(
+                    response,
+                    "BatchAssociateUserStack",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchAssociateUserStackError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified users from the specified stacks.</p>
+    fn batch_disassociate_user_stack(
+        &self,
+        input: BatchDisassociateUserStackRequest,
+    ) -> RusotoFuture<BatchDisassociateUserStackResult, BatchDisassociateUserStackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("BatchDisassociateUserStack");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.BatchDisassociateUserStack",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDisassociateUserStackResult, _>(
+                    response,
+                    "BatchDisassociateUserStack",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchDisassociateUserStackError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Copies the image within the same region or to a new region within the same AWS account. Note that any tags you added to the image will not be copied.</p>
+    fn copy_image(
+        &self,
+        input: CopyImageRequest,
+    ) -> RusotoFuture<CopyImageResponse, CopyImageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CopyImage");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.CopyImage");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CopyImageResponse, _>(response, "CopyImage")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CopyImageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
+    fn create_directory_config(
+        &self,
+        input: CreateDirectoryConfigRequest,
+    ) -> RusotoFuture<CreateDirectoryConfigResult, CreateDirectoryConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateDirectoryConfig");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.CreateDirectoryConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDirectoryConfigResult, _>(
+                    response,
+                    "CreateDirectoryConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateDirectoryConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a fleet. A fleet consists of streaming instances that run a specified image.</p>
+    fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> RusotoFuture<CreateFleetResult, CreateFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateFleet");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.CreateFleet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateFleetResult, _>(response, "CreateFleet")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an image builder. An image builder is a virtual machine that is used to create an image.</p> <p>The initial state of the builder is <code>PENDING</code>. When it is ready, the state is <code>RUNNING</code>.</p>
+    fn create_image_builder(
+        &self,
+        input: CreateImageBuilderRequest,
+    ) -> RusotoFuture<CreateImageBuilderResult, CreateImageBuilderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateImageBuilder");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.CreateImageBuilder");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateImageBuilderResult, _>(
+                    response,
+                    "CreateImageBuilder",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateImageBuilderError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a URL to start an image builder streaming session.</p>
+    fn create_image_builder_streaming_url(
+        &self,
+        input: CreateImageBuilderStreamingURLRequest,
+    ) -> RusotoFuture<CreateImageBuilderStreamingURLResult, CreateImageBuilderStreamingURLError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateImageBuilderStreamingURL");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.CreateImageBuilderStreamingURL",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateImageBuilderStreamingURLResult, _>(
+                    response,
+                    "CreateImageBuilderStreamingURL",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateImageBuilderStreamingURLError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a stack to start streaming applications to users. A stack consists of an associated fleet, user access policies, and storage configurations. </p>
+    fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> RusotoFuture<CreateStackResult, CreateStackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateStack");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStack");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateStackResult, _>(response, "CreateStack")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateStackError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a temporary URL to start an AppStream 2.0 streaming session for the specified user. A streaming URL enables application streaming to be tested without user setup. </p>
+    fn create_streaming_url(
+        &self,
+        input: CreateStreamingURLRequest,
+    ) -> RusotoFuture<CreateStreamingURLResult, CreateStreamingURLError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateStreamingURL");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStreamingURL");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateStreamingURLResult, _>(
+                    response,
+                    "CreateStreamingURL",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateStreamingURLError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a usage report subscription. Usage reports are generated daily.</p>
+    fn create_usage_report_subscription(
+        &self,
+    ) -> RusotoFuture<CreateUsageReportSubscriptionResult, CreateUsageReportSubscriptionError> {
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateUsageReportSubscription");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.CreateUsageReportSubscription",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUsageReportSubscriptionResult, _>(
+                    response,
+                    "CreateUsageReportSubscription",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateUsageReportSubscriptionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a new user in the user pool.</p>
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResult, CreateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateUser");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.CreateUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserResult, _>(response, "CreateUser")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified Directory Config object from AppStream 2.0. This object includes the information required to join streaming instances to an Active Directory domain.</p>
+    fn delete_directory_config(
+        &self,
+        input: DeleteDirectoryConfigRequest,
+    ) -> RusotoFuture<DeleteDirectoryConfigResult, DeleteDirectoryConfigError> {
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteDirectoryConfig");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.DeleteDirectoryConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDirectoryConfigResult, _>(
+                    response,
+                    "DeleteDirectoryConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteDirectoryConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified fleet.</p>
+    fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> RusotoFuture<DeleteFleetResult, DeleteFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteFleet");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteFleet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteFleetResult, _>(response, "DeleteFleet")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified image. You cannot delete an image when it is in use. After you delete an image, you cannot provision new capacity using the image.</p>
+    fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> RusotoFuture<DeleteImageResult, DeleteImageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteImage");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImage");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteImageResult, _>(response, "DeleteImage")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteImageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified image builder and releases the capacity.</p>
+    fn delete_image_builder(
+        &self,
+        input: DeleteImageBuilderRequest,
+    ) -> RusotoFuture<DeleteImageBuilderResult, DeleteImageBuilderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteImageBuilder");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImageBuilder");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteImageBuilderResult, _>(
+                    response,
+                    "DeleteImageBuilder",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteImageBuilderError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes permissions for the specified private image. After you delete permissions for an image, AWS accounts to which you previously granted these permissions can no longer use the image.</p>
+    fn delete_image_permissions(
+        &self,
+        input: DeleteImagePermissionsRequest,
+    ) -> RusotoFuture<DeleteImagePermissionsResult, DeleteImagePermissionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteImagePermissions");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.DeleteImagePermissions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteImagePermissionsResult, _>(
+                    response,
+                    "DeleteImagePermissions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteImagePermissionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified stack. After the stack is deleted, the application streaming environment provided by the stack is no longer available to users. Also, any reservations made for application streaming sessions for the stack are released.</p>
+    fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> RusotoFuture<DeleteStackResult, DeleteStackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteStack");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteStack");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteStackResult, _>(response, "DeleteStack")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteStackError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables usage report generation.</p>
+    fn delete_usage_report_subscription(
+        &self,
+    ) -> RusotoFuture<DeleteUsageReportSubscriptionResult, DeleteUsageReportSubscriptionError> {
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteUsageReportSubscription");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.DeleteUsageReportSubscription",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteUsageReportSubscriptionResult, _>(
+                    response,
+                    "DeleteUsageReportSubscription",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteUsageReportSubscriptionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a user from the user pool.</p>
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResult, DeleteUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteUser");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteUserResult, _>(response, "DeleteUser")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes one or more specified Directory Config objects for AppStream 2.0, if the names for these objects are provided. Otherwise, all Directory Config objects in the account are described. These objects include the configuration information required to join fleets and image builders to Microsoft Active Directory domains. </p> <p>Although the response syntax in this topic includes the account password, this password is not returned in the actual response.</p>
+    fn describe_directory_configs(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> RusotoFuture<DescribeDirectoryConfigsResult, DescribeDirectoryConfigsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeDirectoryConfigs");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.DescribeDirectoryConfigs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDirectoryConfigsResult, _>(
+                    response,
+                    "DescribeDirectoryConfigs",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDirectoryConfigsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes one or more specified fleets, if the fleet names are provided. Otherwise, all fleets in the account are described.</p>
+    fn describe_fleets(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> RusotoFuture<DescribeFleetsResult, DescribeFleetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeFleets");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeFleets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeFleetsResult, _>(
+                    response,
+                    "DescribeFleets",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeFleetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes one or more specified image builders, if the image builder names are provided. Otherwise, all image builders in the account are described.</p>
+    fn describe_image_builders(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> RusotoFuture<DescribeImageBuildersResult, DescribeImageBuildersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeImageBuilders");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.DescribeImageBuilders",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeImageBuildersResult, _>(
+                    response,
+                    "DescribeImageBuilders",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeImageBuildersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes the permissions for shared AWS account IDs on a private image that you own. </p>
+    fn describe_image_permissions(
+        &self,
+        input: DescribeImagePermissionsRequest,
+    ) -> RusotoFuture<DescribeImagePermissionsResult, DescribeImagePermissionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeImagePermissions");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.DescribeImagePermissions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeImagePermissionsResult, _>(
+                    response,
+                    "DescribeImagePermissions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeImagePermissionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes one or more specified images, if the image names or image ARNs are provided. Otherwise, all images in the account are described.</p>
+    fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> RusotoFuture<DescribeImagesResult, DescribeImagesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeImages");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeImages");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeImagesResult, _>(
+                    response,
+                    "DescribeImages",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeImagesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes the streaming sessions for a specified stack and fleet. If a UserId is provided for the stack and fleet, only streaming sessions for that user are described. If an authentication type is not provided, the default is to authenticate users using a streaming URL.</p>
+    fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> RusotoFuture<DescribeSessionsResult, DescribeSessionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeSessions");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeSessions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSessionsResult, _>(
+                    response,
+                    "DescribeSessions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeSessionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes one or more specified stacks, if the stack names are provided. Otherwise, all stacks in the account are described.</p>
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeStacks");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeStacks");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeStacksResult, _>(
+                    response,
+                    "DescribeStacks",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeStacksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes one or more usage report subscriptions.</p>
+    fn describe_usage_report_subscriptions(
+        &self,
+        input: DescribeUsageReportSubscriptionsRequest,
+    ) -> RusotoFuture<DescribeUsageReportSubscriptionsResult, DescribeUsageReportSubscriptionsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeUsageReportSubscriptions");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.DescribeUsageReportSubscriptions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeUsageReportSubscriptionsResult, _>(
+                    response,
+                    "DescribeUsageReportSubscriptions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeUsageReportSubscriptionsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves a list that describes the UserStackAssociation objects. You must specify either or both of the following:</p> <ul> <li> <p>The stack name</p> </li> <li> <p>The user name (email address of the user associated with the stack) and the authentication type for the user</p> </li> </ul></p>
+    fn describe_user_stack_associations(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> RusotoFuture<DescribeUserStackAssociationsResult, DescribeUserStackAssociationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeUserStackAssociations");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.DescribeUserStackAssociations",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeUserStackAssociationsResult, _>(
+                    response,
+                    "DescribeUserStackAssociations",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeUserStackAssociationsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes one or more specified users in the user pool.</p>
+    fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> RusotoFuture<DescribeUsersResult, DescribeUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeUsers");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeUsers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeUsersResult, _>(
+                    response,
+                    "DescribeUsers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeUsersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables the specified user in the user pool. Users can't sign in to AppStream 2.0 until they are re-enabled. This action does not delete the user. </p>
+    fn disable_user(
+        &self,
+        input: DisableUserRequest,
+    ) -> RusotoFuture<DisableUserResult, DisableUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DisableUser");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DisableUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisableUserResult, _>(response, "DisableUser")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisableUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified fleet from the specified stack.</p>
+    fn disassociate_fleet(
+        &self,
+        input: DisassociateFleetRequest,
+    ) -> RusotoFuture<DisassociateFleetResult, DisassociateFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DisassociateFleet");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.DisassociateFleet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateFleetResult, _>(
+                    response,
+                    "DisassociateFleet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisassociateFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables a user in the user pool. After being enabled, users can sign in to AppStream 2.0 and open applications from the stacks to which they are assigned.</p>
+    fn enable_user(
+        &self,
+        input: EnableUserRequest,
+    ) -> RusotoFuture<EnableUserResult, EnableUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("EnableUser");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.EnableUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<EnableUserResult, _>(response, "EnableUser")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Immediately stops the specified streaming session.</p>
+    fn expire_session(
+        &self,
+        input: ExpireSessionRequest,
+    ) -> RusotoFuture<ExpireSessionResult, ExpireSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ExpireSession");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.ExpireSession");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ExpireSessionResult, _>(
+                    response,
+                    "ExpireSession",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ExpireSessionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the name of the fleet that is associated with the specified stack.</p>
+    fn list_associated_fleets(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> RusotoFuture<ListAssociatedFleetsResult, ListAssociatedFleetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ListAssociatedFleets");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.ListAssociatedFleets",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAssociatedFleetsResult, _>(
+                    response,
+                    "ListAssociatedFleets",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListAssociatedFleetsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the name of the stack with which the specified fleet is associated.</p>
+    fn list_associated_stacks(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> RusotoFuture<ListAssociatedStacksResult, ListAssociatedStacksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ListAssociatedStacks");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.ListAssociatedStacks",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAssociatedStacksResult, _>(
+                    response,
+                    "ListAssociatedStacks",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListAssociatedStacksError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of all tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.ListTagsForResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts the specified fleet.</p>
+    fn start_fleet(
+        &self,
+        input: StartFleetRequest,
+    ) -> RusotoFuture<StartFleetResult, StartFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StartFleet");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.StartFleet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartFleetResult, _>(response, "StartFleet")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts the specified image builder.</p>
+    fn start_image_builder(
+        &self,
+        input: StartImageBuilderRequest,
+    ) -> RusotoFuture<StartImageBuilderResult, StartImageBuilderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StartImageBuilder");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.StartImageBuilder");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartImageBuilderResult, _>(
+                    response,
+                    "StartImageBuilder",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartImageBuilderError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops the specified fleet.</p>
+    fn stop_fleet(&self, input: StopFleetRequest) -> RusotoFuture<StopFleetResult, StopFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StopFleet");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.StopFleet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopFleetResult, _>(response, "StopFleet")
             } else {
                 Box::new(
                     response
@@ -5640,211 +8250,1872 @@ impl AppStream for AppStreamClient {
             }
         })
     }
-
-    /// <p>Stops the specified image builder.</p>
+
+    /// <p>Stops the specified image builder.</p>
+    fn stop_image_builder(
+        &self,
+        input: StopImageBuilderRequest,
+    ) -> RusotoFuture<StopImageBuilderResult, StopImageBuilderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StopImageBuilder");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.StopImageBuilder");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopImageBuilderResult, _>(
+                    response,
+                    "StopImageBuilder",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopImageBuilderError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds or overwrites one or more tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, this operation updates its value.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>. To disassociate tags from your resources, use <a>UntagResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("TagResource");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disassociates one or more specified tags from the specified AppStream 2.0 resource.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UntagResource");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
+    fn update_directory_config(
+        &self,
+        input: UpdateDirectoryConfigRequest,
+    ) -> RusotoFuture<UpdateDirectoryConfigResult, UpdateDirectoryConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateDirectoryConfig");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.UpdateDirectoryConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDirectoryConfigResult, _>(
+                    response,
+                    "UpdateDirectoryConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateDirectoryConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified fleet.</p> <p>If the fleet is in the <code>STOPPED</code> state, you can update any attribute except the fleet name. If the fleet is in the <code>RUNNING</code> state, you can update the <code>DisplayName</code>, <code>ComputeCapacity</code>, <code>ImageARN</code>, <code>ImageName</code>, <code>IdleDisconnectTimeoutInSeconds</code>, and <code>DisconnectTimeoutInSeconds</code> attributes. If the fleet is in the <code>STARTING</code> or <code>STOPPING</code> state, you can't update it.</p>
+    fn update_fleet(
+        &self,
+        input: UpdateFleetRequest,
+    ) -> RusotoFuture<UpdateFleetResult, UpdateFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateFleet");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateFleet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateFleetResult, _>(response, "UpdateFleet")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds or updates permissions for the specified private image. </p>
+    fn update_image_permissions(
+        &self,
+        input: UpdateImagePermissionsRequest,
+    ) -> RusotoFuture<UpdateImagePermissionsResult, UpdateImagePermissionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateImagePermissions");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "PhotonAdminProxyService.UpdateImagePermissions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateImagePermissionsResult, _>(
+                    response,
+                    "UpdateImagePermissions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateImagePermissionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified fields for the specified stack.</p>
+    fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> RusotoFuture<UpdateStackResult, UpdateStackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateStack");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateStack");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateStackResult, _>(response, "UpdateStack")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateStackError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon AppStream API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAppStream {
+    associate_fleet: Option<
+        Box<
+            dyn Fn(
+                    AssociateFleetRequest,
+                )
+                    -> ::std::result::Result<AssociateFleetResult, RusotoError<AssociateFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    batch_associate_user_stack: Option<
+        Box<
+            dyn Fn(
+                    BatchAssociateUserStackRequest,
+                ) -> ::std::result::Result<
+                    BatchAssociateUserStackResult,
+                    RusotoError<BatchAssociateUserStackError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_disassociate_user_stack: Option<
+        Box<
+            dyn Fn(
+                    BatchDisassociateUserStackRequest,
+                ) -> ::std::result::Result<
+                    BatchDisassociateUserStackResult,
+                    RusotoError<BatchDisassociateUserStackError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    copy_image: Option<
+        Box<
+            dyn Fn(
+                    CopyImageRequest,
+                )
+                    -> ::std::result::Result<CopyImageResponse, RusotoError<CopyImageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_directory_config: Option<
+        Box<
+            dyn Fn(
+                    CreateDirectoryConfigRequest,
+                ) -> ::std::result::Result<
+                    CreateDirectoryConfigResult,
+                    RusotoError<CreateDirectoryConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_fleet: Option<
+        Box<
+            dyn Fn(
+                    CreateFleetRequest,
+                )
+                    -> ::std::result::Result<CreateFleetResult, RusotoError<CreateFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_image_builder: Option<
+        Box<
+            dyn Fn(
+                    CreateImageBuilderRequest,
+                ) -> ::std::result::Result<
+                    CreateImageBuilderResult,
+                    RusotoError<CreateImageBuilderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_image_builder_streaming_url: Option<
+        Box<
+            dyn Fn(
+                    CreateImageBuilderStreamingURLRequest,
+                ) -> ::std::result::Result<
+                    CreateImageBuilderStreamingURLResult,
+                    RusotoError<CreateImageBuilderStreamingURLError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_stack: Option<
+        Box<
+            dyn Fn(
+                    CreateStackRequest,
+                )
+                    -> ::std::result::Result<CreateStackResult, RusotoError<CreateStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_streaming_url: Option<
+        Box<
+            dyn Fn(
+                    CreateStreamingURLRequest,
+                ) -> ::std::result::Result<
+                    CreateStreamingURLResult,
+                    RusotoError<CreateStreamingURLError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_usage_report_subscription: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    CreateUsageReportSubscriptionResult,
+                    RusotoError<CreateUsageReportSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_user: Option<
+        Box<
+            dyn Fn(
+                    CreateUserRequest,
+                )
+                    -> ::std::result::Result<CreateUserResult, RusotoError<CreateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_directory_config: Option<
+        Box<
+            dyn Fn(
+                    DeleteDirectoryConfigRequest,
+                ) -> ::std::result::Result<
+                    DeleteDirectoryConfigResult,
+                    RusotoError<DeleteDirectoryConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_fleet: Option<
+        Box<
+            dyn Fn(
+                    DeleteFleetRequest,
+                )
+                    -> ::std::result::Result<DeleteFleetResult, RusotoError<DeleteFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_image: Option<
+        Box<
+            dyn Fn(
+                    DeleteImageRequest,
+                )
+                    -> ::std::result::Result<DeleteImageResult, RusotoError<DeleteImageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_image_builder: Option<
+        Box<
+            dyn Fn(
+                    DeleteImageBuilderRequest,
+                ) -> ::std::result::Result<
+                    DeleteImageBuilderResult,
+                    RusotoError<DeleteImageBuilderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_image_permissions: Option<
+        Box<
+            dyn Fn(
+                    DeleteImagePermissionsRequest,
+                ) -> ::std::result::Result<
+                    DeleteImagePermissionsResult,
+                    RusotoError<DeleteImagePermissionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_stack: Option<
+        Box<
+            dyn Fn(
+                    DeleteStackRequest,
+                )
+                    -> ::std::result::Result<DeleteStackResult, RusotoError<DeleteStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_usage_report_subscription: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DeleteUsageReportSubscriptionResult,
+                    RusotoError<DeleteUsageReportSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_user: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserRequest,
+                )
+                    -> ::std::result::Result<DeleteUserResult, RusotoError<DeleteUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_directory_configs: Option<
+        Box<
+            dyn Fn(
+                    DescribeDirectoryConfigsRequest,
+                ) -> ::std::result::Result<
+                    DescribeDirectoryConfigsResult,
+                    RusotoError<DescribeDirectoryConfigsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_fleets: Option<
+        Box<
+            dyn Fn(
+                    DescribeFleetsRequest,
+                )
+                    -> ::std::result::Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_image_builders: Option<
+        Box<
+            dyn Fn(
+                    DescribeImageBuildersRequest,
+                ) -> ::std::result::Result<
+                    DescribeImageBuildersResult,
+                    RusotoError<DescribeImageBuildersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_image_permissions: Option<
+        Box<
+            dyn Fn(
+                    DescribeImagePermissionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeImagePermissionsResult,
+                    RusotoError<DescribeImagePermissionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_images: Option<
+        Box<
+            dyn Fn(
+                    DescribeImagesRequest,
+                )
+                    -> ::std::result::Result<DescribeImagesResult, RusotoError<DescribeImagesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_sessions: Option<
+        Box<
+            dyn Fn(
+                    DescribeSessionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeSessionsResult,
+                    RusotoError<DescribeSessionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stacks: Option<
+        Box<
+            dyn Fn(
+                    DescribeStacksRequest,
+                )
+                    -> ::std::result::Result<DescribeStacksResult, RusotoError<DescribeStacksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_usage_report_subscriptions: Option<
+        Box<
+            dyn Fn(
+                    DescribeUsageReportSubscriptionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeUsageReportSubscriptionsResult,
+                    RusotoError<DescribeUsageReportSubscriptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user_stack_associations: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserStackAssociationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeUserStackAssociationsResult,
+                    RusotoError<DescribeUserStackAssociationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_users: Option<
+        Box<
+            dyn Fn(
+                    DescribeUsersRequest,
+                )
+                    -> ::std::result::Result<DescribeUsersResult, RusotoError<DescribeUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_user: Option<
+        Box<
+            dyn Fn(
+                    DisableUserRequest,
+                )
+                    -> ::std::result::Result<DisableUserResult, RusotoError<DisableUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disassociate_fleet: Option<
+        Box<
+            dyn Fn(
+                    DisassociateFleetRequest,
+                ) -> ::std::result::Result<
+                    DisassociateFleetResult,
+                    RusotoError<DisassociateFleetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_user: Option<
+        Box<
+            dyn Fn(
+                    EnableUserRequest,
+                )
+                    -> ::std::result::Result<EnableUserResult, RusotoError<EnableUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    expire_session: Option<
+        Box<
+            dyn Fn(
+                    ExpireSessionRequest,
+                )
+                    -> ::std::result::Result<ExpireSessionResult, RusotoError<ExpireSessionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_associated_fleets: Option<
+        Box<
+            dyn Fn(
+                    ListAssociatedFleetsRequest,
+                ) -> ::std::result::Result<
+                    ListAssociatedFleetsResult,
+                    RusotoError<ListAssociatedFleetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_associated_stacks: Option<
+        Box<
+            dyn Fn(
+                    ListAssociatedStacksRequest,
+                ) -> ::std::result::Result<
+                    ListAssociatedStacksResult,
+                    RusotoError<ListAssociatedStacksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_fleet: Option<
+        Box<
+            dyn Fn(
+                    StartFleetRequest,
+                )
+                    -> ::std::result::Result<StartFleetResult, RusotoError<StartFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_image_builder: Option<
+        Box<
+            dyn Fn(
+                    StartImageBuilderRequest,
+                ) -> ::std::result::Result<
+                    StartImageBuilderResult,
+                    RusotoError<StartImageBuilderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_fleet: Option<
+        Box<
+            dyn Fn(
+                    StopFleetRequest,
+                )
+                    -> ::std::result::Result<StopFleetResult, RusotoError<StopFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_image_builder: Option<
+        Box<
+            dyn Fn(
+                    StopImageBuilderRequest,
+                ) -> ::std::result::Result<
+                    StopImageBuilderResult,
+                    RusotoError<StopImageBuilderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_directory_config: Option<
+        Box<
+            dyn Fn(
+                    UpdateDirectoryConfigRequest,
+                ) -> ::std::result::Result<
+                    UpdateDirectoryConfigResult,
+                    RusotoError<UpdateDirectoryConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_fleet: Option<
+        Box<
+            dyn Fn(
+                    UpdateFleetRequest,
+                )
+                    -> ::std::result::Result<UpdateFleetResult, RusotoError<UpdateFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_image_permissions: Option<
+        Box<
+            dyn Fn(
+                    UpdateImagePermissionsRequest,
+                ) -> ::std::result::Result<
+                    UpdateImagePermissionsResult,
+                    RusotoError<UpdateImagePermissionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_stack: Option<
+        Box<
+            dyn Fn(
+                    UpdateStackRequest,
+                )
+                    -> ::std::result::Result<UpdateStackResult, RusotoError<UpdateStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAppStream {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAppStream {
+        Default::default()
+    }
+
+    /// Answers `associate_fleet` with what `handler` returns for its input.
+    pub fn with_associate_fleet<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                AssociateFleetRequest,
+            )
+                -> ::std::result::Result<AssociateFleetResult, RusotoError<AssociateFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_associate_user_stack` with what `handler` returns for its input.
+    pub fn with_batch_associate_user_stack<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                BatchAssociateUserStackRequest,
+            ) -> ::std::result::Result<
+                BatchAssociateUserStackResult,
+                RusotoError<BatchAssociateUserStackError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_associate_user_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_disassociate_user_stack` with what `handler` returns for its input.
+    pub fn with_batch_disassociate_user_stack<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                BatchDisassociateUserStackRequest,
+            ) -> ::std::result::Result<
+                BatchDisassociateUserStackResult,
+                RusotoError<BatchDisassociateUserStackError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_disassociate_user_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `copy_image` with what `handler` returns for its input.
+    pub fn with_copy_image<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                CopyImageRequest,
+            ) -> ::std::result::Result<CopyImageResponse, RusotoError<CopyImageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.copy_image = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_directory_config` with what `handler` returns for its input.
+    pub fn with_create_directory_config<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                CreateDirectoryConfigRequest,
+            ) -> ::std::result::Result<
+                CreateDirectoryConfigResult,
+                RusotoError<CreateDirectoryConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_directory_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_fleet` with what `handler` returns for its input.
+    pub fn with_create_fleet<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                CreateFleetRequest,
+            )
+                -> ::std::result::Result<CreateFleetResult, RusotoError<CreateFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_image_builder` with what `handler` returns for its input.
+    pub fn with_create_image_builder<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                CreateImageBuilderRequest,
+            ) -> ::std::result::Result<
+                CreateImageBuilderResult,
+                RusotoError<CreateImageBuilderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_image_builder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_image_builder_streaming_url` with what `handler` returns for its input.
+    pub fn with_create_image_builder_streaming_url<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                CreateImageBuilderStreamingURLRequest,
+            ) -> ::std::result::Result<
+                CreateImageBuilderStreamingURLResult,
+                RusotoError<CreateImageBuilderStreamingURLError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_image_builder_streaming_url = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_stack` with what `handler` returns for its input.
+    pub fn with_create_stack<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                CreateStackRequest,
+            )
+                -> ::std::result::Result<CreateStackResult, RusotoError<CreateStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_streaming_url` with what `handler` returns for its input.
+    pub fn with_create_streaming_url<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                CreateStreamingURLRequest,
+            ) -> ::std::result::Result<
+                CreateStreamingURLResult,
+                RusotoError<CreateStreamingURLError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_streaming_url = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_usage_report_subscription` with what `handler` returns.
+    pub fn with_create_usage_report_subscription<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn() -> ::std::result::Result<
+                CreateUsageReportSubscriptionResult,
+                RusotoError<CreateUsageReportSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_usage_report_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user` with what `handler` returns for its input.
+    pub fn with_create_user<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                CreateUserRequest,
+            ) -> ::std::result::Result<CreateUserResult, RusotoError<CreateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_directory_config` with what `handler` returns for its input.
+    pub fn with_delete_directory_config<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DeleteDirectoryConfigRequest,
+            ) -> ::std::result::Result<
+                DeleteDirectoryConfigResult,
+                RusotoError<DeleteDirectoryConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_directory_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_fleet` with what `handler` returns for its input.
+    pub fn with_delete_fleet<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DeleteFleetRequest,
+            )
+                -> ::std::result::Result<DeleteFleetResult, RusotoError<DeleteFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_image` with what `handler` returns for its input.
+    pub fn with_delete_image<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DeleteImageRequest,
+            )
+                -> ::std::result::Result<DeleteImageResult, RusotoError<DeleteImageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_image = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_image_builder` with what `handler` returns for its input.
+    pub fn with_delete_image_builder<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DeleteImageBuilderRequest,
+            ) -> ::std::result::Result<
+                DeleteImageBuilderResult,
+                RusotoError<DeleteImageBuilderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_image_builder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_image_permissions` with what `handler` returns for its input.
+    pub fn with_delete_image_permissions<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DeleteImagePermissionsRequest,
+            ) -> ::std::result::Result<
+                DeleteImagePermissionsResult,
+                RusotoError<DeleteImagePermissionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_image_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_stack` with what `handler` returns for its input.
+    pub fn with_delete_stack<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DeleteStackRequest,
+            )
+                -> ::std::result::Result<DeleteStackResult, RusotoError<DeleteStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_usage_report_subscription` with what `handler` returns.
+    pub fn with_delete_usage_report_subscription<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn() -> ::std::result::Result<
+                DeleteUsageReportSubscriptionResult,
+                RusotoError<DeleteUsageReportSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_usage_report_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user` with what `handler` returns for its input.
+    pub fn with_delete_user<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DeleteUserRequest,
+            ) -> ::std::result::Result<DeleteUserResult, RusotoError<DeleteUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_directory_configs` with what `handler` returns for its input.
+    pub fn with_describe_directory_configs<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DescribeDirectoryConfigsRequest,
+            ) -> ::std::result::Result<
+                DescribeDirectoryConfigsResult,
+                RusotoError<DescribeDirectoryConfigsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_directory_configs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_fleets` with what `handler` returns for its input.
+    pub fn with_describe_fleets<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DescribeFleetsRequest,
+            )
+                -> ::std::result::Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_fleets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_image_builders` with what `handler` returns for its input.
+    pub fn with_describe_image_builders<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DescribeImageBuildersRequest,
+            ) -> ::std::result::Result<
+                DescribeImageBuildersResult,
+                RusotoError<DescribeImageBuildersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_image_builders = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_image_permissions` with what `handler` returns for its input.
+    pub fn with_describe_image_permissions<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DescribeImagePermissionsRequest,
+            ) -> ::std::result::Result<
+                DescribeImagePermissionsResult,
+                RusotoError<DescribeImagePermissionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_image_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_images` with what `handler` returns for its input.
+    pub fn with_describe_images<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DescribeImagesRequest,
+            )
+                -> ::std::result::Result<DescribeImagesResult, RusotoError<DescribeImagesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_images = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_sessions` with what `handler` returns for its input.
+    pub fn with_describe_sessions<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DescribeSessionsRequest,
+            )
+                -> ::std::result::Result<DescribeSessionsResult, RusotoError<DescribeSessionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_sessions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stacks` with what `handler` returns for its input.
+    pub fn with_describe_stacks<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DescribeStacksRequest,
+            )
+                -> ::std::result::Result<DescribeStacksResult, RusotoError<DescribeStacksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stacks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_usage_report_subscriptions` with what `handler` returns for its input.
+    pub fn with_describe_usage_report_subscriptions<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DescribeUsageReportSubscriptionsRequest,
+            ) -> ::std::result::Result<
+                DescribeUsageReportSubscriptionsResult,
+                RusotoError<DescribeUsageReportSubscriptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_usage_report_subscriptions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user_stack_associations` with what `handler` returns for its input.
+    pub fn with_describe_user_stack_associations<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DescribeUserStackAssociationsRequest,
+            ) -> ::std::result::Result<
+                DescribeUserStackAssociationsResult,
+                RusotoError<DescribeUserStackAssociationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user_stack_associations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_users` with what `handler` returns for its input.
+    pub fn with_describe_users<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DescribeUsersRequest,
+            )
+                -> ::std::result::Result<DescribeUsersResult, RusotoError<DescribeUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_user` with what `handler` returns for its input.
+    pub fn with_disable_user<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DisableUserRequest,
+            )
+                -> ::std::result::Result<DisableUserResult, RusotoError<DisableUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_fleet` with what `handler` returns for its input.
+    pub fn with_disassociate_fleet<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                DisassociateFleetRequest,
+            ) -> ::std::result::Result<
+                DisassociateFleetResult,
+                RusotoError<DisassociateFleetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_user` with what `handler` returns for its input.
+    pub fn with_enable_user<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                EnableUserRequest,
+            ) -> ::std::result::Result<EnableUserResult, RusotoError<EnableUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `expire_session` with what `handler` returns for its input.
+    pub fn with_expire_session<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                ExpireSessionRequest,
+            )
+                -> ::std::result::Result<ExpireSessionResult, RusotoError<ExpireSessionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.expire_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_associated_fleets` with what `handler` returns for its input.
+    pub fn with_list_associated_fleets<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                ListAssociatedFleetsRequest,
+            ) -> ::std::result::Result<
+                ListAssociatedFleetsResult,
+                RusotoError<ListAssociatedFleetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_associated_fleets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_associated_stacks` with what `handler` returns for its input.
+    pub fn with_list_associated_stacks<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                ListAssociatedStacksRequest,
+            ) -> ::std::result::Result<
+                ListAssociatedStacksResult,
+                RusotoError<ListAssociatedStacksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_associated_stacks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_fleet` with what `handler` returns for its input.
+    pub fn with_start_fleet<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                StartFleetRequest,
+            ) -> ::std::result::Result<StartFleetResult, RusotoError<StartFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_image_builder` with what `handler` returns for its input.
+    pub fn with_start_image_builder<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                StartImageBuilderRequest,
+            ) -> ::std::result::Result<
+                StartImageBuilderResult,
+                RusotoError<StartImageBuilderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_image_builder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_fleet` with what `handler` returns for its input.
+    pub fn with_stop_fleet<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                StopFleetRequest,
+            ) -> ::std::result::Result<StopFleetResult, RusotoError<StopFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_image_builder` with what `handler` returns for its input.
+    pub fn with_stop_image_builder<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                StopImageBuilderRequest,
+            )
+                -> ::std::result::Result<StopImageBuilderResult, RusotoError<StopImageBuilderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_image_builder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_directory_config` with what `handler` returns for its input.
+    pub fn with_update_directory_config<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                UpdateDirectoryConfigRequest,
+            ) -> ::std::result::Result<
+                UpdateDirectoryConfigResult,
+                RusotoError<UpdateDirectoryConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_directory_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_fleet` with what `handler` returns for its input.
+    pub fn with_update_fleet<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                UpdateFleetRequest,
+            )
+                -> ::std::result::Result<UpdateFleetResult, RusotoError<UpdateFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_image_permissions` with what `handler` returns for its input.
+    pub fn with_update_image_permissions<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                UpdateImagePermissionsRequest,
+            ) -> ::std::result::Result<
+                UpdateImagePermissionsResult,
+                RusotoError<UpdateImagePermissionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_image_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_stack` with what `handler` returns for its input.
+    pub fn with_update_stack<F>(mut self, handler: F) -> MockAppStream
+    where
+        F: Fn(
+                UpdateStackRequest,
+            )
+                -> ::std::result::Result<UpdateStackResult, RusotoError<UpdateStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_stack = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl AppStream for MockAppStream {
+    fn associate_fleet(
+        &self,
+        input: AssociateFleetRequest,
+    ) -> RusotoFuture<AssociateFleetResult, AssociateFleetError> {
+        match self.associate_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for associate_fleet"),
+        }
+    }
+    fn batch_associate_user_stack(
+        &self,
+        input: BatchAssociateUserStackRequest,
+    ) -> RusotoFuture<BatchAssociateUserStackResult, BatchAssociateUserStackError> {
+        match self.batch_associate_user_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for batch_associate_user_stack"),
+        }
+    }
+    fn batch_disassociate_user_stack(
+        &self,
+        input: BatchDisassociateUserStackRequest,
+    ) -> RusotoFuture<BatchDisassociateUserStackResult, BatchDisassociateUserStackError> {
+        match self.batch_disassociate_user_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for batch_disassociate_user_stack"),
+        }
+    }
+    fn copy_image(
+        &self,
+        input: CopyImageRequest,
+    ) -> RusotoFuture<CopyImageResponse, CopyImageError> {
+        match self.copy_image {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for copy_image"),
+        }
+    }
+    fn create_directory_config(
+        &self,
+        input: CreateDirectoryConfigRequest,
+    ) -> RusotoFuture<CreateDirectoryConfigResult, CreateDirectoryConfigError> {
+        match self.create_directory_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for create_directory_config"),
+        }
+    }
+    fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> RusotoFuture<CreateFleetResult, CreateFleetError> {
+        match self.create_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for create_fleet"),
+        }
+    }
+    fn create_image_builder(
+        &self,
+        input: CreateImageBuilderRequest,
+    ) -> RusotoFuture<CreateImageBuilderResult, CreateImageBuilderError> {
+        match self.create_image_builder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for create_image_builder"),
+        }
+    }
+    fn create_image_builder_streaming_url(
+        &self,
+        input: CreateImageBuilderStreamingURLRequest,
+    ) -> RusotoFuture<CreateImageBuilderStreamingURLResult, CreateImageBuilderStreamingURLError>
+    {
+        match self.create_image_builder_streaming_url {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for create_image_builder_streaming_url"),
+        }
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> RusotoFuture<CreateStackResult, CreateStackError> {
+        match self.create_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for create_stack"),
+        }
+    }
+    fn create_streaming_url(
+        &self,
+        input: CreateStreamingURLRequest,
+    ) -> RusotoFuture<CreateStreamingURLResult, CreateStreamingURLError> {
+        match self.create_streaming_url {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for create_streaming_url"),
+        }
+    }
+    fn create_usage_report_subscription(
+        &self,
+    ) -> RusotoFuture<CreateUsageReportSubscriptionResult, CreateUsageReportSubscriptionError> {
+        match self.create_usage_report_subscription {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockAppStream has no handler for create_usage_report_subscription"),
+        }
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResult, CreateUserError> {
+        match self.create_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for create_user"),
+        }
+    }
+    fn delete_directory_config(
+        &self,
+        input: DeleteDirectoryConfigRequest,
+    ) -> RusotoFuture<DeleteDirectoryConfigResult, DeleteDirectoryConfigError> {
+        match self.delete_directory_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for delete_directory_config"),
+        }
+    }
+    fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> RusotoFuture<DeleteFleetResult, DeleteFleetError> {
+        match self.delete_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for delete_fleet"),
+        }
+    }
+    fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> RusotoFuture<DeleteImageResult, DeleteImageError> {
+        match self.delete_image {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for delete_image"),
+        }
+    }
+    fn delete_image_builder(
+        &self,
+        input: DeleteImageBuilderRequest,
+    ) -> RusotoFuture<DeleteImageBuilderResult, DeleteImageBuilderError> {
+        match self.delete_image_builder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for delete_image_builder"),
+        }
+    }
+    fn delete_image_permissions(
+        &self,
+        input: DeleteImagePermissionsRequest,
+    ) -> RusotoFuture<DeleteImagePermissionsResult, DeleteImagePermissionsError> {
+        match self.delete_image_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for delete_image_permissions"),
+        }
+    }
+    fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> RusotoFuture<DeleteStackResult, DeleteStackError> {
+        match self.delete_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for delete_stack"),
+        }
+    }
+    fn delete_usage_report_subscription(
+        &self,
+    ) -> RusotoFuture<DeleteUsageReportSubscriptionResult, DeleteUsageReportSubscriptionError> {
+        match self.delete_usage_report_subscription {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockAppStream has no handler for delete_usage_report_subscription"),
+        }
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResult, DeleteUserError> {
+        match self.delete_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for delete_user"),
+        }
+    }
+    fn describe_directory_configs(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> RusotoFuture<DescribeDirectoryConfigsResult, DescribeDirectoryConfigsError> {
+        match self.describe_directory_configs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for describe_directory_configs"),
+        }
+    }
+    fn describe_fleets(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> RusotoFuture<DescribeFleetsResult, DescribeFleetsError> {
+        match self.describe_fleets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for describe_fleets"),
+        }
+    }
+    fn describe_image_builders(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> RusotoFuture<DescribeImageBuildersResult, DescribeImageBuildersError> {
+        match self.describe_image_builders {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for describe_image_builders"),
+        }
+    }
+    fn describe_image_permissions(
+        &self,
+        input: DescribeImagePermissionsRequest,
+    ) -> RusotoFuture<DescribeImagePermissionsResult, DescribeImagePermissionsError> {
+        match self.describe_image_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for describe_image_permissions"),
+        }
+    }
+    fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> RusotoFuture<DescribeImagesResult, DescribeImagesError> {
+        match self.describe_images {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for describe_images"),
+        }
+    }
+    fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> RusotoFuture<DescribeSessionsResult, DescribeSessionsError> {
+        match self.describe_sessions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for describe_sessions"),
+        }
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
+        match self.describe_stacks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for describe_stacks"),
+        }
+    }
+    fn describe_usage_report_subscriptions(
+        &self,
+        input: DescribeUsageReportSubscriptionsRequest,
+    ) -> RusotoFuture<DescribeUsageReportSubscriptionsResult, DescribeUsageReportSubscriptionsError>
+    {
+        match self.describe_usage_report_subscriptions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for describe_usage_report_subscriptions"),
+        }
+    }
+    fn describe_user_stack_associations(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> RusotoFuture<DescribeUserStackAssociationsResult, DescribeUserStackAssociationsError> {
+        match self.describe_user_stack_associations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for describe_user_stack_associations"),
+        }
+    }
+    fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> RusotoFuture<DescribeUsersResult, DescribeUsersError> {
+        match self.describe_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for describe_users"),
+        }
+    }
+    fn disable_user(
+        &self,
+        input: DisableUserRequest,
+    ) -> RusotoFuture<DisableUserResult, DisableUserError> {
+        match self.disable_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for disable_user"),
+        }
+    }
+    fn disassociate_fleet(
+        &self,
+        input: DisassociateFleetRequest,
+    ) -> RusotoFuture<DisassociateFleetResult, DisassociateFleetError> {
+        match self.disassociate_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for disassociate_fleet"),
+        }
+    }
+    fn enable_user(
+        &self,
+        input: EnableUserRequest,
+    ) -> RusotoFuture<EnableUserResult, EnableUserError> {
+        match self.enable_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for enable_user"),
+        }
+    }
+    fn expire_session(
+        &self,
+        input: ExpireSessionRequest,
+    ) -> RusotoFuture<ExpireSessionResult, ExpireSessionError> {
+        match self.expire_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for expire_session"),
+        }
+    }
+    fn list_associated_fleets(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> RusotoFuture<ListAssociatedFleetsResult, ListAssociatedFleetsError> {
+        match self.list_associated_fleets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for list_associated_fleets"),
+        }
+    }
+    fn list_associated_stacks(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> RusotoFuture<ListAssociatedStacksResult, ListAssociatedStacksError> {
+        match self.list_associated_stacks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for list_associated_stacks"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for list_tags_for_resource"),
+        }
+    }
+    fn start_fleet(
+        &self,
+        input: StartFleetRequest,
+    ) -> RusotoFuture<StartFleetResult, StartFleetError> {
+        match self.start_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for start_fleet"),
+        }
+    }
+    fn start_image_builder(
+        &self,
+        input: StartImageBuilderRequest,
+    ) -> RusotoFuture<StartImageBuilderResult, StartImageBuilderError> {
+        match self.start_image_builder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for start_image_builder"),
+        }
+    }
+    fn stop_fleet(&self, input: StopFleetRequest) -> RusotoFuture<StopFleetResult, StopFleetError> {
+        match self.stop_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for stop_fleet"),
+        }
+    }
     fn stop_image_builder(
         &self,
         input: StopImageBuilderRequest,
     ) -> RusotoFuture<StopImageBuilderResult, StopImageBuilderError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.StopImageBuilder");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopImageBuilderResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopImageBuilderError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_image_builder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for stop_image_builder"),
+        }
     }
-
-    /// <p>Adds or overwrites one or more tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, this operation updates its value.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>. To disassociate tags from your resources, use <a>UntagResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Disassociates one or more specified tags from the specified AppStream 2.0 resource.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Updates the specified Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
     fn update_directory_config(
         &self,
         input: UpdateDirectoryConfigRequest,
     ) -> RusotoFuture<UpdateDirectoryConfigResult, UpdateDirectoryConfigError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.UpdateDirectoryConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDirectoryConfigResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateDirectoryConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_directory_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for update_directory_config"),
+        }
     }
-
-    /// <p>Updates the specified fleet.</p> <p>If the fleet is in the <code>STOPPED</code> state, you can update any attribute except the fleet name. If the fleet is in the <code>RUNNING</code> state, you can update the <code>DisplayName</code>, <code>ComputeCapacity</code>, <code>ImageARN</code>, <code>ImageName</code>, <code>IdleDisconnectTimeoutInSeconds</code>, and <code>DisconnectTimeoutInSeconds</code> attributes. If the fleet is in the <code>STARTING</code> or <code>STOPPING</code> state, you can't update it.</p>
     fn update_fleet(
         &self,
         input: UpdateFleetRequest,
     ) -> RusotoFuture<UpdateFleetResult, UpdateFleetError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFleetResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateFleetError::from_response(response))),
-                )
-            }
-        })
+        match self.update_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for update_fleet"),
+        }
     }
-
-    /// <p>Adds or updates permissions for the specified private image. </p>
     fn update_image_permissions(
         &self,
         input: UpdateImagePermissionsRequest,
     ) -> RusotoFuture<UpdateImagePermissionsResult, UpdateImagePermissionsError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "PhotonAdminProxyService.UpdateImagePermissions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateImagePermissionsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateImagePermissionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_image_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for update_image_permissions"),
+        }
     }
-
-    /// <p>Updates the specified fields for the specified stack.</p>
     fn update_stack(
         &self,
         input: UpdateStackRequest,
     ) -> RusotoFuture<UpdateStackResult, UpdateStackError> {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateStack");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateStackResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateStackError::from_response(response))),
-                )
-            }
-        })
+        match self.update_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppStream has no handler for update_stack"),
+        }
     }
 }
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Describes an additional authentication provider.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -43,7 +44,7 @@ pub struct AdditionalAuthenticationProvider {
 
 /// <p><p>Describes an API key.</p> <p>Customers invoke AWS AppSync GraphQL API operations with API keys as an identity mechanism. There are two key versions:</p> <p> <b>da1</b>: This version was introduced at launch in November 2017. These keys always expire after 7 days. Key expiration is managed by Amazon DynamoDB TTL. The keys ceased to be valid after February 21, 2018 and should not be used after that date.</p> <ul> <li> <p> <code>ListApiKeys</code> returns the expiration time in milliseconds.</p> </li> <li> <p> <code>CreateApiKey</code> returns the expiration time in milliseconds.</p> </li> <li> <p> <code>UpdateApiKey</code> is not available for this key version.</p> </li> <li> <p> <code>DeleteApiKey</code> deletes the item from the table.</p> </li> <li> <p>Expiration is stored in Amazon DynamoDB as milliseconds. This results in a bug where keys are not automatically deleted because DynamoDB expects the TTL to be stored in seconds. As a one-time action, we will delete these keys from the table after February 21, 2018.</p> </li> </ul> <p> <b>da2</b>: This version was introduced in February 2018 when AppSync added support to extend key expiration.</p> <ul> <li> <p> <code>ListApiKeys</code> returns the expiration time in seconds.</p> </li> <li> <p> <code>CreateApiKey</code> returns the expiration time in seconds and accepts a user-provided expiration time in seconds.</p> </li> <li> <p> <code>UpdateApiKey</code> returns the expiration time in seconds and accepts a user-provided expiration time in seconds. Key expiration can only be updated while the key has not expired.</p> </li> <li> <p> <code>DeleteApiKey</code> deletes the item from the table.</p> </li> <li> <p>Expiration is stored in Amazon DynamoDB as seconds.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApiKey {
     /// <p>A description of the purpose of the API key.</p>
     #[serde(rename = "description")]
@@ -100,6 +101,7 @@ pub struct CognitoUserPoolConfig {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApiKeyRequest {
     /// <p>The ID for your GraphQL API.</p>
     #[serde(rename = "apiId")]
@@ -115,7 +117,7 @@ pub struct CreateApiKeyRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateApiKeyResponse {
     /// <p>The API key.</p>
     #[serde(rename = "apiKey")]
@@ -124,6 +126,7 @@ pub struct CreateApiKeyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDataSourceRequest {
     /// <p>The API ID for the GraphQL API for the <code>DataSource</code>.</p>
     #[serde(rename = "apiId")]
@@ -164,8 +167,24 @@ pub struct CreateDataSourceRequest {
     pub type_: String,
 }
 
+impl Validate for CreateDataSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDataSourceResponse {
     /// <p>The <code>DataSource</code> object.</p>
     #[serde(rename = "dataSource")]
@@ -174,6 +193,7 @@ pub struct CreateDataSourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFunctionRequest {
     /// <p>The GraphQL API ID.</p>
     #[serde(rename = "apiId")]
@@ -200,8 +220,59 @@ pub struct CreateFunctionRequest {
     pub response_mapping_template: Option<String>,
 }
 
+impl Validate for CreateFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_source_name"),
+            self.data_source_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_source_name"),
+            &self.data_source_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+        errors.check_length(
+            &field_path(path, "request_mapping_template"),
+            self.request_mapping_template.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "request_mapping_template"),
+            &self.request_mapping_template,
+            "^.*$",
+        );
+        if let Some(ref value) = self.response_mapping_template {
+            errors.check_length(
+                &field_path(path, "response_mapping_template"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(
+                &field_path(path, "response_mapping_template"),
+                &value,
+                "^.*$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFunctionResponse {
     /// <p>The <code>Function</code> object.</p>
     #[serde(rename = "functionConfiguration")]
@@ -210,6 +281,7 @@ pub struct CreateFunctionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGraphqlApiRequest {
     /// <p>A list of additional authentication providers for the <code>GraphqlApi</code> API.</p>
     #[serde(rename = "additionalAuthenticationProviders")]
@@ -239,8 +311,21 @@ pub struct CreateGraphqlApiRequest {
     pub user_pool_config: Option<UserPoolConfig>,
 }
 
+impl Validate for CreateGraphqlApiRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "^[\\s\\w+-=\\.:/@]*$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGraphqlApiResponse {
     /// <p>The <code>GraphqlApi</code>.</p>
     #[serde(rename = "graphqlApi")]
@@ -249,6 +334,7 @@ pub struct CreateGraphqlApiResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateResolverRequest {
     /// <p>The ID for the GraphQL API for which the resolver is being created.</p>
     #[serde(rename = "apiId")]
@@ -280,8 +366,72 @@ pub struct CreateResolverRequest {
     pub type_name: String,
 }
 
+impl Validate for CreateResolverRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.data_source_name {
+            errors.check_length(
+                &field_path(path, "data_source_name"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(
+                &field_path(path, "data_source_name"),
+                &value,
+                "[_A-Za-z][_0-9A-Za-z]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "field_name"),
+            self.field_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "field_name"),
+            &self.field_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+        errors.check_length(
+            &field_path(path, "request_mapping_template"),
+            self.request_mapping_template.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "request_mapping_template"),
+            &self.request_mapping_template,
+            "^.*$",
+        );
+        if let Some(ref value) = self.response_mapping_template {
+            errors.check_length(
+                &field_path(path, "response_mapping_template"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(
+                &field_path(path, "response_mapping_template"),
+                &value,
+                "^.*$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateResolverResponse {
     /// <p>The <code>Resolver</code> object.</p>
     #[serde(rename = "resolver")]
@@ -290,6 +440,7 @@ pub struct CreateResolverResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTypeRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -303,7 +454,7 @@ pub struct CreateTypeRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTypeResponse {
     /// <p>The <code>Type</code> object.</p>
     #[serde(rename = "type")]
@@ -313,7 +464,7 @@ pub struct CreateTypeResponse {
 
 /// <p>Describes a data source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DataSource {
     /// <p>The data source ARN.</p>
     #[serde(rename = "dataSourceArn")]
@@ -358,6 +509,7 @@ pub struct DataSource {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApiKeyRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -368,10 +520,11 @@ pub struct DeleteApiKeyRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteApiKeyResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDataSourceRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -381,11 +534,28 @@ pub struct DeleteDataSourceRequest {
     pub name: String,
 }
 
+impl Validate for DeleteDataSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDataSourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFunctionRequest {
     /// <p>The GraphQL API ID.</p>
     #[serde(rename = "apiId")]
@@ -395,11 +565,28 @@ pub struct DeleteFunctionRequest {
     pub function_id: String,
 }
 
+impl Validate for DeleteFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_id"),
+            self.function_id.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "function_id"),
+            &self.function_id,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFunctionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGraphqlApiRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -407,10 +594,11 @@ pub struct DeleteGraphqlApiRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteGraphqlApiResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteResolverRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -423,11 +611,39 @@ pub struct DeleteResolverRequest {
     pub type_name: String,
 }
 
+impl Validate for DeleteResolverRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "field_name"),
+            self.field_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "field_name"),
+            &self.field_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteResolverResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTypeRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -437,8 +653,24 @@ pub struct DeleteTypeRequest {
     pub type_name: String,
 }
 
+impl Validate for DeleteTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTypeResponse {}
 
 /// <p>Describes an Amazon DynamoDB data source configuration.</p>
@@ -469,7 +701,7 @@ pub struct ElasticsearchDataSourceConfig {
 
 /// <p>A function is a reusable entity. Multiple functions can be used to compose the resolver logic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FunctionConfiguration {
     /// <p>The name of the <code>DataSource</code>.</p>
     #[serde(rename = "dataSourceName")]
@@ -506,6 +738,7 @@ pub struct FunctionConfiguration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDataSourceRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -515,8 +748,24 @@ pub struct GetDataSourceRequest {
     pub name: String,
 }
 
+impl Validate for GetDataSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDataSourceResponse {
     /// <p>The <code>DataSource</code> object.</p>
     #[serde(rename = "dataSource")]
@@ -525,6 +774,7 @@ pub struct GetDataSourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFunctionRequest {
     /// <p>The GraphQL API ID.</p>
     #[serde(rename = "apiId")]
@@ -534,8 +784,24 @@ pub struct GetFunctionRequest {
     pub function_id: String,
 }
 
+impl Validate for GetFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_id"),
+            self.function_id.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "function_id"),
+            &self.function_id,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFunctionResponse {
     /// <p>The <code>Function</code> object.</p>
     #[serde(rename = "functionConfiguration")]
@@ -544,6 +810,7 @@ pub struct GetFunctionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGraphqlApiRequest {
     /// <p>The API ID for the GraphQL API.</p>
     #[serde(rename = "apiId")]
@@ -551,7 +818,7 @@ pub struct GetGraphqlApiRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGraphqlApiResponse {
     /// <p>The <code>GraphqlApi</code> object.</p>
     #[serde(rename = "graphqlApi")]
@@ -560,6 +827,7 @@ pub struct GetGraphqlApiResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIntrospectionSchemaRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -580,6 +848,7 @@ pub struct GetIntrospectionSchemaResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResolverRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -592,8 +861,35 @@ pub struct GetResolverRequest {
     pub type_name: String,
 }
 
+impl Validate for GetResolverRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "field_name"),
+            self.field_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "field_name"),
+            &self.field_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResolverResponse {
     /// <p>The <code>Resolver</code> object.</p>
     #[serde(rename = "resolver")]
@@ -602,6 +898,7 @@ pub struct GetResolverResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSchemaCreationStatusRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -609,7 +906,7 @@ pub struct GetSchemaCreationStatusRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSchemaCreationStatusResponse {
     /// <p>Detailed information about the status of the schema creation operation.</p>
     #[serde(rename = "details")]
@@ -622,6 +919,7 @@ pub struct GetSchemaCreationStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTypeRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -634,8 +932,24 @@ pub struct GetTypeRequest {
     pub type_name: String,
 }
 
+impl Validate for GetTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTypeResponse {
     /// <p>The <code>Type</code> object.</p>
     #[serde(rename = "type")]
@@ -645,7 +959,7 @@ pub struct GetTypeResponse {
 
 /// <p>Describes a GraphQL API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GraphqlApi {
     /// <p>A list of additional authentication providers for the <code>GraphqlApi</code> API.</p>
     #[serde(rename = "additionalAuthenticationProviders")]
@@ -711,6 +1025,7 @@ pub struct LambdaDataSourceConfig {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListApiKeysRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -725,8 +1040,25 @@ pub struct ListApiKeysRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListApiKeysRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\\\S]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListApiKeysResponse {
     /// <p>The <code>ApiKey</code> objects.</p>
     #[serde(rename = "apiKeys")]
@@ -739,6 +1071,7 @@ pub struct ListApiKeysResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDataSourcesRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -753,8 +1086,25 @@ pub struct ListDataSourcesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDataSourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\\\S]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDataSourcesResponse {
     /// <p>The <code>DataSource</code> objects.</p>
     #[serde(rename = "dataSources")]
@@ -767,6 +1117,7 @@ pub struct ListDataSourcesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFunctionsRequest {
     /// <p>The GraphQL API ID.</p>
     #[serde(rename = "apiId")]
@@ -781,8 +1132,25 @@ pub struct ListFunctionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListFunctionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\\\S]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFunctionsResponse {
     /// <p>A list of <code>Function</code> objects.</p>
     #[serde(rename = "functions")]
@@ -795,6 +1163,7 @@ pub struct ListFunctionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGraphqlApisRequest {
     /// <p>The maximum number of results you want the request to return.</p>
     #[serde(rename = "maxResults")]
@@ -806,8 +1175,25 @@ pub struct ListGraphqlApisRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListGraphqlApisRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\\\S]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGraphqlApisResponse {
     /// <p>The <code>GraphqlApi</code> objects.</p>
     #[serde(rename = "graphqlApis")]
@@ -820,6 +1206,7 @@ pub struct ListGraphqlApisResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResolversByFunctionRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -837,8 +1224,25 @@ pub struct ListResolversByFunctionRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListResolversByFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\\\S]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResolversByFunctionResponse {
     /// <p>An identifier that can be used to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -851,6 +1255,7 @@ pub struct ListResolversByFunctionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResolversRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -868,8 +1273,25 @@ pub struct ListResolversRequest {
     pub type_name: String,
 }
 
+impl Validate for ListResolversRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\\\S]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResolversResponse {
     /// <p>An identifier to be passed in the next request to this operation to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -882,14 +1304,31 @@ pub struct ListResolversResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The <code>GraphqlApi</code> ARN.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(70),
+            Some(75),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:appsync:[A-Za-z0-9_/.-]{0,63}:\\d{12}:apis/[0-9A-Za-z_-]{26}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>A <code>TagMap</code> object.</p>
     #[serde(rename = "tags")]
@@ -898,6 +1337,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTypesRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -915,8 +1355,25 @@ pub struct ListTypesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListTypesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\\\S]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTypesResponse {
     /// <p>An identifier to be passed in the next request to this operation to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1012,7 +1469,7 @@ pub struct RelationalDatabaseDataSourceConfig {
 
 /// <p>Describes a resolver.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Resolver {
     /// <p>The resolver data source name.</p>
     #[serde(rename = "dataSourceName")]
@@ -1049,6 +1506,7 @@ pub struct Resolver {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartSchemaCreationRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -1064,7 +1522,7 @@ pub struct StartSchemaCreationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartSchemaCreationResponse {
     /// <p>The current state of the schema (PROCESSING, FAILED, SUCCESS, or NOT_APPLICABLE). When the schema is in the ACTIVE state, you can add data.</p>
     #[serde(rename = "status")]
@@ -1073,6 +1531,7 @@ pub struct StartSchemaCreationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The <code>GraphqlApi</code> ARN.</p>
     #[serde(rename = "resourceArn")]
@@ -1082,13 +1541,40 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(70),
+            Some(75),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:appsync:[A-Za-z0-9_/.-]{0,63}:\\d{12}:apis/[0-9A-Za-z_-]{26}$",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, "^[\\s\\w+-=\\.:/@]*$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>Describes a type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Type {
     /// <p>The type ARN.</p>
     #[serde(rename = "arn")]
@@ -1113,6 +1599,7 @@ pub struct Type {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The <code>GraphqlApi</code> ARN.</p>
     #[serde(rename = "resourceArn")]
@@ -1122,11 +1609,39 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(70),
+            Some(75),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:appsync:[A-Za-z0-9_/.-]{0,63}:\\d{12}:apis/[0-9A-Za-z_-]{26}$",
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^(?!aws:)[ a-zA-Z+-=._:/]+$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApiKeyRequest {
     /// <p>The ID for the GraphQL API.</p>
     #[serde(rename = "apiId")]
@@ -1145,7 +1660,7 @@ pub struct UpdateApiKeyRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateApiKeyResponse {
     /// <p>The API key.</p>
     #[serde(rename = "apiKey")]
@@ -1154,6 +1669,7 @@ pub struct UpdateApiKeyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDataSourceRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -1194,8 +1710,24 @@ pub struct UpdateDataSourceRequest {
     pub type_: String,
 }
 
+impl Validate for UpdateDataSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDataSourceResponse {
     /// <p>The updated <code>DataSource</code> object.</p>
     #[serde(rename = "dataSource")]
@@ -1204,6 +1736,7 @@ pub struct UpdateDataSourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFunctionRequest {
     /// <p>The GraphQL API ID.</p>
     #[serde(rename = "apiId")]
@@ -1233,8 +1766,70 @@ pub struct UpdateFunctionRequest {
     pub response_mapping_template: Option<String>,
 }
 
+impl Validate for UpdateFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_source_name"),
+            self.data_source_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_source_name"),
+            &self.data_source_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+        errors.check_length(
+            &field_path(path, "function_id"),
+            self.function_id.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "function_id"),
+            &self.function_id,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+        errors.check_length(
+            &field_path(path, "request_mapping_template"),
+            self.request_mapping_template.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "request_mapping_template"),
+            &self.request_mapping_template,
+            "^.*$",
+        );
+        if let Some(ref value) = self.response_mapping_template {
+            errors.check_length(
+                &field_path(path, "response_mapping_template"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(
+                &field_path(path, "response_mapping_template"),
+                &value,
+                "^.*$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFunctionResponse {
     /// <p>The <code>Function</code> object.</p>
     #[serde(rename = "functionConfiguration")]
@@ -1243,6 +1838,7 @@ pub struct UpdateFunctionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGraphqlApiRequest {
     /// <p>A list of additional authentication providers for the <code>GraphqlApi</code> API.</p>
     #[serde(rename = "additionalAuthenticationProviders")]
@@ -1273,7 +1869,7 @@ pub struct UpdateGraphqlApiRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGraphqlApiResponse {
     /// <p>The updated <code>GraphqlApi</code> object.</p>
     #[serde(rename = "graphqlApi")]
@@ -1282,6 +1878,7 @@ pub struct UpdateGraphqlApiResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateResolverRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -1313,8 +1910,72 @@ pub struct UpdateResolverRequest {
     pub type_name: String,
 }
 
+impl Validate for UpdateResolverRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.data_source_name {
+            errors.check_length(
+                &field_path(path, "data_source_name"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(
+                &field_path(path, "data_source_name"),
+                &value,
+                "[_A-Za-z][_0-9A-Za-z]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "field_name"),
+            self.field_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "field_name"),
+            &self.field_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+        errors.check_length(
+            &field_path(path, "request_mapping_template"),
+            self.request_mapping_template.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "request_mapping_template"),
+            &self.request_mapping_template,
+            "^.*$",
+        );
+        if let Some(ref value) = self.response_mapping_template {
+            errors.check_length(
+                &field_path(path, "response_mapping_template"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+            errors.check_pattern(
+                &field_path(path, "response_mapping_template"),
+                &value,
+                "^.*$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateResolverResponse {
     /// <p>The updated <code>Resolver</code> object.</p>
     #[serde(rename = "resolver")]
@@ -1323,6 +1984,7 @@ pub struct UpdateResolverResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTypeRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -1339,8 +2001,24 @@ pub struct UpdateTypeRequest {
     pub type_name: String,
 }
 
+impl Validate for UpdateTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(65536),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "[_A-Za-z][_0-9A-Za-z]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTypeResponse {
     /// <p>The updated <code>Type</code> object.</p>
     #[serde(rename = "type")]
@@ -1412,7 +2090,12 @@ impl CreateApiKeyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateApiKeyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1473,7 +2156,12 @@ impl CreateDataSourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateDataSourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1527,7 +2215,12 @@ impl CreateFunctionError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateFunctionError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1590,7 +2283,12 @@ impl CreateGraphqlApiError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateGraphqlApiError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1645,7 +2343,12 @@ impl CreateResolverError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateResolverError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1701,7 +2404,12 @@ impl CreateTypeError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateTypeError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1753,7 +2461,12 @@ impl DeleteApiKeyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteApiKeyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1811,7 +2524,12 @@ impl DeleteDataSourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteDataSourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1865,7 +2583,12 @@ impl DeleteFunctionError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteFunctionError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1928,7 +2651,12 @@ impl DeleteGraphqlApiError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteGraphqlApiError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1983,7 +2711,12 @@ impl DeleteResolverError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteResolverError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2039,7 +2772,12 @@ impl DeleteTypeError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteTypeError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2098,7 +2836,12 @@ impl GetDataSourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetDataSourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2145,7 +2888,12 @@ impl GetFunctionError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetFunctionError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2200,7 +2948,12 @@ impl GetGraphqlApiError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetGraphqlApiError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2256,7 +3009,12 @@ impl GetIntrospectionSchemaError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetIntrospectionSchemaError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2302,7 +3060,12 @@ impl GetResolverError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetResolverError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2356,7 +3119,12 @@ impl GetSchemaCreationStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2412,7 +3180,12 @@ impl GetTypeError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetTypeError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2464,7 +3237,12 @@ impl ListApiKeysError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListApiKeysError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2515,7 +3293,12 @@ impl ListDataSourcesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListDataSourcesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2566,7 +3349,12 @@ impl ListFunctionsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListFunctionsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2612,7 +3400,12 @@ impl ListGraphqlApisError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListGraphqlApisError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2662,7 +3455,12 @@ impl ListResolversError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListResolversError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2717,7 +3515,12 @@ impl ListResolversByFunctionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2778,7 +3581,12 @@ impl ListTagsForResourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListTagsForResourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2836,7 +3644,12 @@ impl ListTypesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListTypesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2895,7 +3708,12 @@ impl StartSchemaCreationError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(StartSchemaCreationError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2957,7 +3775,12 @@ impl TagResourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(TagResourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3020,7 +3843,12 @@ impl UntagResourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UntagResourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3085,7 +3913,12 @@ impl UpdateApiKeyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateApiKeyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3145,7 +3978,12 @@ impl UpdateDataSourceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateDataSourceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3199,7 +4037,12 @@ impl UpdateFunctionError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateFunctionError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3262,7 +4105,12 @@ impl UpdateGraphqlApiError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateGraphqlApiError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3317,7 +4165,12 @@ impl UpdateResolverError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateResolverError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3373,7 +4226,12 @@ impl UpdateTypeError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateTypeError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3611,968 +4469,2018 @@ pub trait AppSync {
         input: UpdateTypeRequest,
     ) -> RusotoFuture<UpdateTypeResponse, UpdateTypeError>;
 }
-/// A client for the AWSAppSync API.
-#[derive(Clone)]
-pub struct AppSyncClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl AppSyncClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> AppSyncClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> AppSyncClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> AppSyncClient {
-        AppSyncClient { client, region }
-    }
-}
-
-impl AppSync for AppSyncClient {
-    /// <p>Creates a unique key that you can distribute to clients who are executing your API.</p>
+const _: Option<&dyn AppSync> = None;
+impl<'a, T: AppSync + ?Sized> AppSync for &'a T {
     fn create_api_key(
         &self,
         input: CreateApiKeyRequest,
     ) -> RusotoFuture<CreateApiKeyResponse, CreateApiKeyError> {
-        let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateApiKeyResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateApiKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_api_key(input)
     }
-
-    /// <p>Creates a <code>DataSource</code> object.</p>
     fn create_data_source(
         &self,
         input: CreateDataSourceRequest,
     ) -> RusotoFuture<CreateDataSourceResponse, CreateDataSourceError> {
-        let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDataSourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDataSourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_data_source(input)
     }
-
-    /// <p>Creates a <code>Function</code> object.</p> <p>A function is a reusable entity. Multiple functions can be used to compose the resolver logic.</p>
     fn create_function(
         &self,
         input: CreateFunctionRequest,
     ) -> RusotoFuture<CreateFunctionResponse, CreateFunctionError> {
-        let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFunctionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateFunctionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_function(input)
     }
-
-    /// <p>Creates a <code>GraphqlApi</code> object.</p>
     fn create_graphql_api(
         &self,
         input: CreateGraphqlApiRequest,
     ) -> RusotoFuture<CreateGraphqlApiResponse, CreateGraphqlApiError> {
-        let request_uri = "/v1/apis";
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGraphqlApiResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGraphqlApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_graphql_api(input)
     }
-
-    /// <p>Creates a <code>Resolver</code> object.</p> <p>A resolver converts incoming requests into a format that a data source can understand and converts the data source's responses into GraphQL.</p>
     fn create_resolver(
         &self,
         input: CreateResolverRequest,
     ) -> RusotoFuture<CreateResolverResponse, CreateResolverError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/types/{type_name}/resolvers",
-            api_id = input.api_id,
-            type_name = input.type_name
-        );
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateResolverResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateResolverError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_resolver(input)
     }
-
-    /// <p>Creates a <code>Type</code> object.</p>
     fn create_type(
         &self,
         input: CreateTypeRequest,
     ) -> RusotoFuture<CreateTypeResponse, CreateTypeError> {
-        let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTypeResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTypeError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_type(input)
     }
-
-    /// <p>Deletes an API key.</p>
     fn delete_api_key(
         &self,
         input: DeleteApiKeyRequest,
     ) -> RusotoFuture<DeleteApiKeyResponse, DeleteApiKeyError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/apikeys/{id}",
-            api_id = input.api_id,
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteApiKeyResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteApiKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_api_key(input)
     }
-
-    /// <p>Deletes a <code>DataSource</code> object.</p>
     fn delete_data_source(
         &self,
         input: DeleteDataSourceRequest,
     ) -> RusotoFuture<DeleteDataSourceResponse, DeleteDataSourceError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/datasources/{name}",
-            api_id = input.api_id,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDataSourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDataSourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_data_source(input)
     }
-
-    /// <p>Deletes a <code>Function</code>.</p>
     fn delete_function(
         &self,
         input: DeleteFunctionRequest,
     ) -> RusotoFuture<DeleteFunctionResponse, DeleteFunctionError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/functions/{function_id}",
-            api_id = input.api_id,
-            function_id = input.function_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteFunctionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFunctionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_function(input)
     }
-
-    /// <p>Deletes a <code>GraphqlApi</code> object.</p>
     fn delete_graphql_api(
         &self,
         input: DeleteGraphqlApiRequest,
     ) -> RusotoFuture<DeleteGraphqlApiResponse, DeleteGraphqlApiError> {
-        let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteGraphqlApiResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteGraphqlApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_graphql_api(input)
     }
-
-    /// <p>Deletes a <code>Resolver</code> object.</p>
     fn delete_resolver(
         &self,
         input: DeleteResolverRequest,
     ) -> RusotoFuture<DeleteResolverResponse, DeleteResolverError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/types/{type_name}/resolvers/{field_name}",
-            api_id = input.api_id,
-            field_name = input.field_name,
-            type_name = input.type_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteResolverResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteResolverError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_resolver(input)
     }
-
-    /// <p>Deletes a <code>Type</code> object.</p>
     fn delete_type(
         &self,
         input: DeleteTypeRequest,
     ) -> RusotoFuture<DeleteTypeResponse, DeleteTypeError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/types/{type_name}",
-            api_id = input.api_id,
-            type_name = input.type_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTypeResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTypeError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_type(input)
     }
-
-    /// <p>Retrieves a <code>DataSource</code> object.</p>
     fn get_data_source(
         &self,
         input: GetDataSourceRequest,
     ) -> RusotoFuture<GetDataSourceResponse, GetDataSourceError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/datasources/{name}",
-            api_id = input.api_id,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDataSourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDataSourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_data_source(input)
     }
-
-    /// <p>Get a <code>Function</code>.</p>
     fn get_function(
         &self,
         input: GetFunctionRequest,
     ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/functions/{function_id}",
-            api_id = input.api_id,
-            function_id = input.function_id
-        );
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFunctionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFunctionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_function(input)
     }
-
-    /// <p>Retrieves a <code>GraphqlApi</code> object.</p>
     fn get_graphql_api(
         &self,
         input: GetGraphqlApiRequest,
     ) -> RusotoFuture<GetGraphqlApiResponse, GetGraphqlApiError> {
-        let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGraphqlApiResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetGraphqlApiError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_graphql_api(input)
     }
-
-    /// <p>Retrieves the introspection schema for a GraphQL API.</p>
     fn get_introspection_schema(
         &self,
         input: GetIntrospectionSchemaRequest,
     ) -> RusotoFuture<GetIntrospectionSchemaResponse, GetIntrospectionSchemaError> {
-        let request_uri = format!("/v1/apis/{api_id}/schema", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("format", &input.format);
-        if let Some(ref x) = input.include_directives {
-            params.put("includeDirectives", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = GetIntrospectionSchemaResponse::default();
-                    result.schema = Some(response.body);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetIntrospectionSchemaError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_introspection_schema(input)
     }
-
-    /// <p>Retrieves a <code>Resolver</code> object.</p>
     fn get_resolver(
         &self,
         input: GetResolverRequest,
     ) -> RusotoFuture<GetResolverResponse, GetResolverError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/types/{type_name}/resolvers/{field_name}",
-            api_id = input.api_id,
-            field_name = input.field_name,
-            type_name = input.type_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetResolverResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetResolverError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_resolver(input)
     }
-
-    /// <p>Retrieves the current status of a schema creation operation.</p>
     fn get_schema_creation_status(
         &self,
         input: GetSchemaCreationStatusRequest,
     ) -> RusotoFuture<GetSchemaCreationStatusResponse, GetSchemaCreationStatusError> {
-        let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSchemaCreationStatusResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetSchemaCreationStatusError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_schema_creation_status(input)
     }
-
-    /// <p>Retrieves a <code>Type</code> object.</p>
     fn get_type(&self, input: GetTypeRequest) -> RusotoFuture<GetTypeResponse, GetTypeError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/types/{type_name}",
-            api_id = input.api_id,
-            type_name = input.type_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("format", &input.format);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTypeResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTypeError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_type(input)
     }
-
-    /// <p><p>Lists the API keys for a given API.</p> <note> <p>API keys are deleted automatically sometime after they expire. However, they may still be included in the response until they have actually been deleted. You can safely call <code>DeleteApiKey</code> to manually delete a key before it&#39;s automatically deleted.</p> </note></p>
     fn list_api_keys(
         &self,
         input: ListApiKeysRequest,
     ) -> RusotoFuture<ListApiKeysResponse, ListApiKeysError> {
-        let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListApiKeysResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListApiKeysError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_api_keys(input)
     }
-
-    /// <p>Lists the data sources for a given API.</p>
     fn list_data_sources(
         &self,
         input: ListDataSourcesRequest,
     ) -> RusotoFuture<ListDataSourcesResponse, ListDataSourcesError> {
-        let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDataSourcesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDataSourcesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_data_sources(input)
     }
-
-    /// <p>List multiple functions.</p>
     fn list_functions(
         &self,
         input: ListFunctionsRequest,
     ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
-        let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFunctionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListFunctionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_functions(input)
     }
-
-    /// <p>Lists your GraphQL APIs.</p>
     fn list_graphql_apis(
         &self,
         input: ListGraphqlApisRequest,
     ) -> RusotoFuture<ListGraphqlApisResponse, ListGraphqlApisError> {
-        let request_uri = "/v1/apis";
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGraphqlApisResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGraphqlApisError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_graphql_apis(input)
     }
-
-    /// <p>Lists the resolvers for a given API and type.</p>
     fn list_resolvers(
         &self,
         input: ListResolversRequest,
     ) -> RusotoFuture<ListResolversResponse, ListResolversError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/types/{type_name}/resolvers",
-            api_id = input.api_id,
-            type_name = input.type_name
-        );
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResolversResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListResolversError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_resolvers(input)
     }
-
-    /// <p>List the resolvers that are associated with a specific function.</p>
     fn list_resolvers_by_function(
         &self,
         input: ListResolversByFunctionRequest,
     ) -> RusotoFuture<ListResolversByFunctionResponse, ListResolversByFunctionError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/functions/{function_id}/resolvers",
-            api_id = input.api_id,
-            function_id = input.function_id
-        );
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResolversByFunctionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListResolversByFunctionError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_resolvers_by_function(input)
     }
-
-    /// <p>Lists the tags for a resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Lists the types for a given API.</p>
     fn list_types(
         &self,
         input: ListTypesRequest,
     ) -> RusotoFuture<ListTypesResponse, ListTypesError> {
-        let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("format", &input.format);
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTypesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTypesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_types(input)
     }
-
-    /// <p>Adds a new schema to your GraphQL API.</p> <p>This operation is asynchronous. Use to determine when it has completed.</p>
     fn start_schema_creation(
         &self,
         input: StartSchemaCreationRequest,
     ) -> RusotoFuture<StartSchemaCreationResponse, StartSchemaCreationError> {
-        let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        (**self).start_schema_creation(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> RusotoFuture<UpdateApiKeyResponse, UpdateApiKeyError> {
+        (**self).update_api_key(input)
+    }
+    fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> RusotoFuture<UpdateDataSourceResponse, UpdateDataSourceError> {
+        (**self).update_data_source(input)
+    }
+    fn update_function(
+        &self,
+        input: UpdateFunctionRequest,
+    ) -> RusotoFuture<UpdateFunctionResponse, UpdateFunctionError> {
+        (**self).update_function(input)
+    }
+    fn update_graphql_api(
+        &self,
+        input: UpdateGraphqlApiRequest,
+    ) -> RusotoFuture<UpdateGraphqlApiResponse, UpdateGraphqlApiError> {
+        (**self).update_graphql_api(input)
+    }
+    fn update_resolver(
+        &self,
+        input: UpdateResolverRequest,
+    ) -> RusotoFuture<UpdateResolverResponse, UpdateResolverError> {
+        (**self).update_resolver(input)
+    }
+    fn update_type(
+        &self,
+        input: UpdateTypeRequest,
+    ) -> RusotoFuture<UpdateTypeResponse, UpdateTypeError> {
+        (**self).update_type(input)
+    }
+}
+impl<T: AppSync + ?Sized> AppSync for Box<T> {
+    fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> RusotoFuture<CreateApiKeyResponse, CreateApiKeyError> {
+        (**self).create_api_key(input)
+    }
+    fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> RusotoFuture<CreateDataSourceResponse, CreateDataSourceError> {
+        (**self).create_data_source(input)
+    }
+    fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> RusotoFuture<CreateFunctionResponse, CreateFunctionError> {
+        (**self).create_function(input)
+    }
+    fn create_graphql_api(
+        &self,
+        input: CreateGraphqlApiRequest,
+    ) -> RusotoFuture<CreateGraphqlApiResponse, CreateGraphqlApiError> {
+        (**self).create_graphql_api(input)
+    }
+    fn create_resolver(
+        &self,
+        input: CreateResolverRequest,
+    ) -> RusotoFuture<CreateResolverResponse, CreateResolverError> {
+        (**self).create_resolver(input)
+    }
+    fn create_type(
+        &self,
+        input: CreateTypeRequest,
+    ) -> RusotoFuture<CreateTypeResponse, CreateTypeError> {
+        (**self).create_type(input)
+    }
+    fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> RusotoFuture<DeleteApiKeyResponse, DeleteApiKeyError> {
+        (**self).delete_api_key(input)
+    }
+    fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> RusotoFuture<DeleteDataSourceResponse, DeleteDataSourceError> {
+        (**self).delete_data_source(input)
+    }
+    fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> RusotoFuture<DeleteFunctionResponse, DeleteFunctionError> {
+        (**self).delete_function(input)
+    }
+    fn delete_graphql_api(
+        &self,
+        input: DeleteGraphqlApiRequest,
+    ) -> RusotoFuture<DeleteGraphqlApiResponse, DeleteGraphqlApiError> {
+        (**self).delete_graphql_api(input)
+    }
+    fn delete_resolver(
+        &self,
+        input: DeleteResolverRequest,
+    ) -> RusotoFuture<DeleteResolverResponse, DeleteResolverError> {
+        (**self).delete_resolver(input)
+    }
+    fn delete_type(
+        &self,
+        input: DeleteTypeRequest,
+    ) -> RusotoFuture<DeleteTypeResponse, DeleteTypeError> {
+        (**self).delete_type(input)
+    }
+    fn get_data_source(
+        &self,
+        input: GetDataSourceRequest,
+    ) -> RusotoFuture<GetDataSourceResponse, GetDataSourceError> {
+        (**self).get_data_source(input)
+    }
+    fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
+        (**self).get_function(input)
+    }
+    fn get_graphql_api(
+        &self,
+        input: GetGraphqlApiRequest,
+    ) -> RusotoFuture<GetGraphqlApiResponse, GetGraphqlApiError> {
+        (**self).get_graphql_api(input)
+    }
+    fn get_introspection_schema(
+        &self,
+        input: GetIntrospectionSchemaRequest,
+    ) -> RusotoFuture<GetIntrospectionSchemaResponse, GetIntrospectionSchemaError> {
+        (**self).get_introspection_schema(input)
+    }
+    fn get_resolver(
+        &self,
+        input: GetResolverRequest,
+    ) -> RusotoFuture<GetResolverResponse, GetResolverError> {
+        (**self).get_resolver(input)
+    }
+    fn get_schema_creation_status(
+        &self,
+        input: GetSchemaCreationStatusRequest,
+    ) -> RusotoFuture<GetSchemaCreationStatusResponse, GetSchemaCreationStatusError> {
+        (**self).get_schema_creation_status(input)
+    }
+    fn get_type(&self, input: GetTypeRequest) -> RusotoFuture<GetTypeResponse, GetTypeError> {
+        (**self).get_type(input)
+    }
+    fn list_api_keys(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> RusotoFuture<ListApiKeysResponse, ListApiKeysError> {
+        (**self).list_api_keys(input)
+    }
+    fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> RusotoFuture<ListDataSourcesResponse, ListDataSourcesError> {
+        (**self).list_data_sources(input)
+    }
+    fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
+        (**self).list_functions(input)
+    }
+    fn list_graphql_apis(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> RusotoFuture<ListGraphqlApisResponse, ListGraphqlApisError> {
+        (**self).list_graphql_apis(input)
+    }
+    fn list_resolvers(
+        &self,
+        input: ListResolversRequest,
+    ) -> RusotoFuture<ListResolversResponse, ListResolversError> {
+        (**self).list_resolvers(input)
+    }
+    fn list_resolvers_by_function(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> RusotoFuture<ListResolversByFunctionResponse, ListResolversByFunctionError> {
+        (**self).list_resolvers_by_function(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_types(
+        &self,
+        input: ListTypesRequest,
+    ) -> RusotoFuture<ListTypesResponse, ListTypesError> {
+        (**self).list_types(input)
+    }
+    fn start_schema_creation(
+        &self,
+        input: StartSchemaCreationRequest,
+    ) -> RusotoFuture<StartSchemaCreationResponse, StartSchemaCreationError> {
+        (**self).start_schema_creation(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> RusotoFuture<UpdateApiKeyResponse, UpdateApiKeyError> {
+        (**self).update_api_key(input)
+    }
+    fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> RusotoFuture<UpdateDataSourceResponse, UpdateDataSourceError> {
+        (**self).update_data_source(input)
+    }
+    fn update_function(
+        &self,
+        input: UpdateFunctionRequest,
+    ) -> RusotoFuture<UpdateFunctionResponse, UpdateFunctionError> {
+        (**self).update_function(input)
+    }
+    fn update_graphql_api(
+        &self,
+        input: UpdateGraphqlApiRequest,
+    ) -> RusotoFuture<UpdateGraphqlApiResponse, UpdateGraphqlApiError> {
+        (**self).update_graphql_api(input)
+    }
+    fn update_resolver(
+        &self,
+        input: UpdateResolverRequest,
+    ) -> RusotoFuture<UpdateResolverResponse, UpdateResolverError> {
+        (**self).update_resolver(input)
+    }
+    fn update_type(
+        &self,
+        input: UpdateTypeRequest,
+    ) -> RusotoFuture<UpdateTypeResponse, UpdateTypeError> {
+        (**self).update_type(input)
+    }
+}
+impl<T: AppSync + ?Sized> AppSync for ::std::sync::Arc<T> {
+    fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> RusotoFuture<CreateApiKeyResponse, CreateApiKeyError> {
+        (**self).create_api_key(input)
+    }
+    fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> RusotoFuture<CreateDataSourceResponse, CreateDataSourceError> {
+        (**self).create_data_source(input)
+    }
+    fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> RusotoFuture<CreateFunctionResponse, CreateFunctionError> {
+        (**self).create_function(input)
+    }
+    fn create_graphql_api(
+        &self,
+        input: CreateGraphqlApiRequest,
+    ) -> RusotoFuture<CreateGraphqlApiResponse, CreateGraphqlApiError> {
+        (**self).create_graphql_api(input)
+    }
+    fn create_resolver(
+        &self,
+        input: CreateResolverRequest,
+    ) -> RusotoFuture<CreateResolverResponse, CreateResolverError> {
+        (**self).create_resolver(input)
+    }
+    fn create_type(
+        &self,
+        input: CreateTypeRequest,
+    ) -> RusotoFuture<CreateTypeResponse, CreateTypeError> {
+        (**self).create_type(input)
+    }
+    fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> RusotoFuture<DeleteApiKeyResponse, DeleteApiKeyError> {
+        (**self).delete_api_key(input)
+    }
+    fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> RusotoFuture<DeleteDataSourceResponse, DeleteDataSourceError> {
+        (**self).delete_data_source(input)
+    }
+    fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> RusotoFuture<DeleteFunctionResponse, DeleteFunctionError> {
+        (**self).delete_function(input)
+    }
+    fn delete_graphql_api(
+        &self,
+        input: DeleteGraphqlApiRequest,
+    ) -> RusotoFuture<DeleteGraphqlApiResponse, DeleteGraphqlApiError> {
+        (**self).delete_graphql_api(input)
+    }
+    fn delete_resolver(
+        &self,
+        input: DeleteResolverRequest,
+    ) -> RusotoFuture<DeleteResolverResponse, DeleteResolverError> {
+        (**self).delete_resolver(input)
+    }
+    fn delete_type(
+        &self,
+        input: DeleteTypeRequest,
+    ) -> RusotoFuture<DeleteTypeResponse, DeleteTypeError> {
+        (**self).delete_type(input)
+    }
+    fn get_data_source(
+        &self,
+        input: GetDataSourceRequest,
+    ) -> RusotoFuture<GetDataSourceResponse, GetDataSourceError> {
+        (**self).get_data_source(input)
+    }
+    fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
+        (**self).get_function(input)
+    }
+    fn get_graphql_api(
+        &self,
+        input: GetGraphqlApiRequest,
+    ) -> RusotoFuture<GetGraphqlApiResponse, GetGraphqlApiError> {
+        (**self).get_graphql_api(input)
+    }
+    fn get_introspection_schema(
+        &self,
+        input: GetIntrospectionSchemaRequest,
+    ) -> RusotoFuture<GetIntrospectionSchemaResponse, GetIntrospectionSchemaError> {
+        (**self).get_introspection_schema(input)
+    }
+    fn get_resolver(
+        &self,
+        input: GetResolverRequest,
+    ) -> RusotoFuture<GetResolverResponse, GetResolverError> {
+        (**self).get_resolver(input)
+    }
+    fn get_schema_creation_status(
+        &self,
+        input: GetSchemaCreationStatusRequest,
+    ) -> RusotoFuture<GetSchemaCreationStatusResponse, GetSchemaCreationStatusError> {
+        (**self).get_schema_creation_status(input)
+    }
+    fn get_type(&self, input: GetTypeRequest) -> RusotoFuture<GetTypeResponse, GetTypeError> {
+        (**self).get_type(input)
+    }
+    fn list_api_keys(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> RusotoFuture<ListApiKeysResponse, ListApiKeysError> {
+        (**self).list_api_keys(input)
+    }
+    fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> RusotoFuture<ListDataSourcesResponse, ListDataSourcesError> {
+        (**self).list_data_sources(input)
+    }
+    fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
+        (**self).list_functions(input)
+    }
+    fn list_graphql_apis(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> RusotoFuture<ListGraphqlApisResponse, ListGraphqlApisError> {
+        (**self).list_graphql_apis(input)
+    }
+    fn list_resolvers(
+        &self,
+        input: ListResolversRequest,
+    ) -> RusotoFuture<ListResolversResponse, ListResolversError> {
+        (**self).list_resolvers(input)
+    }
+    fn list_resolvers_by_function(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> RusotoFuture<ListResolversByFunctionResponse, ListResolversByFunctionError> {
+        (**self).list_resolvers_by_function(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_types(
+        &self,
+        input: ListTypesRequest,
+    ) -> RusotoFuture<ListTypesResponse, ListTypesError> {
+        (**self).list_types(input)
+    }
+    fn start_schema_creation(
+        &self,
+        input: StartSchemaCreationRequest,
+    ) -> RusotoFuture<StartSchemaCreationResponse, StartSchemaCreationError> {
+        (**self).start_schema_creation(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> RusotoFuture<UpdateApiKeyResponse, UpdateApiKeyError> {
+        (**self).update_api_key(input)
+    }
+    fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> RusotoFuture<UpdateDataSourceResponse, UpdateDataSourceError> {
+        (**self).update_data_source(input)
+    }
+    fn update_function(
+        &self,
+        input: UpdateFunctionRequest,
+    ) -> RusotoFuture<UpdateFunctionResponse, UpdateFunctionError> {
+        (**self).update_function(input)
+    }
+    fn update_graphql_api(
+        &self,
+        input: UpdateGraphqlApiRequest,
+    ) -> RusotoFuture<UpdateGraphqlApiResponse, UpdateGraphqlApiError> {
+        (**self).update_graphql_api(input)
+    }
+    fn update_resolver(
+        &self,
+        input: UpdateResolverRequest,
+    ) -> RusotoFuture<UpdateResolverResponse, UpdateResolverError> {
+        (**self).update_resolver(input)
+    }
+    fn update_type(
+        &self,
+        input: UpdateTypeRequest,
+    ) -> RusotoFuture<UpdateTypeResponse, UpdateTypeError> {
+        (**self).update_type(input)
+    }
+}
+/// A client for the AWSAppSync API.
+#[derive(Clone)]
+pub struct AppSyncClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl AppSyncClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AppSyncClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppSyncClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> AppSyncClient {
+        AppSyncClient { client, region }
+    }
+}
+
+impl AppSync for AppSyncClient {
+    /// <p>Creates a unique key that you can distribute to clients who are executing your API.</p>
+    fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> RusotoFuture<CreateApiKeyResponse, CreateApiKeyError> {
+        let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateApiKey");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateApiKey")
+                        .deserialize::<CreateApiKeyResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateApiKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <code>DataSource</code> object.</p>
+    fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> RusotoFuture<CreateDataSourceResponse, CreateDataSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateDataSource");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDataSource")
+                        .deserialize::<CreateDataSourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDataSourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <code>Function</code> object.</p> <p>A function is a reusable entity. Multiple functions can be used to compose the resolver logic.</p>
+    fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> RusotoFuture<CreateFunctionResponse, CreateFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateFunction");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateFunction")
+                        .deserialize::<CreateFunctionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateFunctionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <code>GraphqlApi</code> object.</p>
+    fn create_graphql_api(
+        &self,
+        input: CreateGraphqlApiRequest,
+    ) -> RusotoFuture<CreateGraphqlApiResponse, CreateGraphqlApiError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/v1/apis";
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateGraphqlApi");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateGraphqlApi")
+                        .deserialize::<CreateGraphqlApiResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGraphqlApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <code>Resolver</code> object.</p> <p>A resolver converts incoming requests into a format that a data source can understand and converts the data source's responses into GraphQL.</p>
+    fn create_resolver(
+        &self,
+        input: CreateResolverRequest,
+    ) -> RusotoFuture<CreateResolverResponse, CreateResolverError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/types/{type_name}/resolvers",
+            api_id = input.api_id,
+            type_name = input.type_name
+        );
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateResolver");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("typeName", &input.type_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateResolver")
+                        .deserialize::<CreateResolverResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateResolverError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <code>Type</code> object.</p>
+    fn create_type(
+        &self,
+        input: CreateTypeRequest,
+    ) -> RusotoFuture<CreateTypeResponse, CreateTypeError> {
+        let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateType");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateType")
+                        .deserialize::<CreateTypeResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an API key.</p>
+    fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> RusotoFuture<DeleteApiKeyResponse, DeleteApiKeyError> {
+        let request_uri = format!(
+            "/v1/apis/{api_id}/apikeys/{id}",
+            api_id = input.api_id,
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteApiKey");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("id", &input.id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteApiKey")
+                        .deserialize::<DeleteApiKeyResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteApiKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a <code>DataSource</code> object.</p>
+    fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> RusotoFuture<DeleteDataSourceResponse, DeleteDataSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/datasources/{name}",
+            api_id = input.api_id,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteDataSource");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteDataSource")
+                        .deserialize::<DeleteDataSourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDataSourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a <code>Function</code>.</p>
+    fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> RusotoFuture<DeleteFunctionResponse, DeleteFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/functions/{function_id}",
+            api_id = input.api_id,
+            function_id = input.function_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteFunction");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("functionId", &input.function_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteFunction")
+                        .deserialize::<DeleteFunctionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFunctionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a <code>GraphqlApi</code> object.</p>
+    fn delete_graphql_api(
+        &self,
+        input: DeleteGraphqlApiRequest,
+    ) -> RusotoFuture<DeleteGraphqlApiResponse, DeleteGraphqlApiError> {
+        let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteGraphqlApi");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteGraphqlApi")
+                        .deserialize::<DeleteGraphqlApiResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteGraphqlApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a <code>Resolver</code> object.</p>
+    fn delete_resolver(
+        &self,
+        input: DeleteResolverRequest,
+    ) -> RusotoFuture<DeleteResolverResponse, DeleteResolverError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/types/{type_name}/resolvers/{field_name}",
+            api_id = input.api_id,
+            field_name = input.field_name,
+            type_name = input.type_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteResolver");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("fieldName", &input.field_name.to_string());
+        request.add_resource_id("typeName", &input.type_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteResolver")
+                        .deserialize::<DeleteResolverResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteResolverError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a <code>Type</code> object.</p>
+    fn delete_type(
+        &self,
+        input: DeleteTypeRequest,
+    ) -> RusotoFuture<DeleteTypeResponse, DeleteTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/types/{type_name}",
+            api_id = input.api_id,
+            type_name = input.type_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteType");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("typeName", &input.type_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteType")
+                        .deserialize::<DeleteTypeResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a <code>DataSource</code> object.</p>
+    fn get_data_source(
+        &self,
+        input: GetDataSourceRequest,
+    ) -> RusotoFuture<GetDataSourceResponse, GetDataSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/datasources/{name}",
+            api_id = input.api_id,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetDataSource");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDataSource")
+                        .deserialize::<GetDataSourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDataSourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Get a <code>Function</code>.</p>
+    fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/functions/{function_id}",
+            api_id = input.api_id,
+            function_id = input.function_id
+        );
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetFunction");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("functionId", &input.function_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFunction")
+                        .deserialize::<GetFunctionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFunctionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a <code>GraphqlApi</code> object.</p>
+    fn get_graphql_api(
+        &self,
+        input: GetGraphqlApiRequest,
+    ) -> RusotoFuture<GetGraphqlApiResponse, GetGraphqlApiError> {
+        let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetGraphqlApi");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGraphqlApi")
+                        .deserialize::<GetGraphqlApiResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetGraphqlApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the introspection schema for a GraphQL API.</p>
+    fn get_introspection_schema(
+        &self,
+        input: GetIntrospectionSchemaRequest,
+    ) -> RusotoFuture<GetIntrospectionSchemaResponse, GetIntrospectionSchemaError> {
+        let request_uri = format!("/v1/apis/{api_id}/schema", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetIntrospectionSchema");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("format", &input.format);
+        if let Some(ref x) = input.include_directives {
+            params.put("includeDirectives", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = GetIntrospectionSchemaResponse::default();
+                    result.schema = Some(response.body);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetIntrospectionSchemaError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a <code>Resolver</code> object.</p>
+    fn get_resolver(
+        &self,
+        input: GetResolverRequest,
+    ) -> RusotoFuture<GetResolverResponse, GetResolverError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/types/{type_name}/resolvers/{field_name}",
+            api_id = input.api_id,
+            field_name = input.field_name,
+            type_name = input.type_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetResolver");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("fieldName", &input.field_name.to_string());
+        request.add_resource_id("typeName", &input.type_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetResolver")
+                        .deserialize::<GetResolverResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetResolverError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the current status of a schema creation operation.</p>
+    fn get_schema_creation_status(
+        &self,
+        input: GetSchemaCreationStatusRequest,
+    ) -> RusotoFuture<GetSchemaCreationStatusResponse, GetSchemaCreationStatusError> {
+        let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetSchemaCreationStatus");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSchemaCreationStatus")
+                        .deserialize::<GetSchemaCreationStatusResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetSchemaCreationStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a <code>Type</code> object.</p>
+    fn get_type(&self, input: GetTypeRequest) -> RusotoFuture<GetTypeResponse, GetTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/types/{type_name}",
+            api_id = input.api_id,
+            type_name = input.type_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetType");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("typeName", &input.type_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("format", &input.format);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetType")
+                        .deserialize::<GetTypeResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Lists the API keys for a given API.</p> <note> <p>API keys are deleted automatically sometime after they expire. However, they may still be included in the response until they have actually been deleted. You can safely call <code>DeleteApiKey</code> to manually delete a key before it&#39;s automatically deleted.</p> </note></p>
+    fn list_api_keys(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> RusotoFuture<ListApiKeysResponse, ListApiKeysError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListApiKeys");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListApiKeys")
+                        .deserialize::<ListApiKeysResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListApiKeysError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the data sources for a given API.</p>
+    fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> RusotoFuture<ListDataSourcesResponse, ListDataSourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListDataSources");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDataSources")
+                        .deserialize::<ListDataSourcesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDataSourcesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>List multiple functions.</p>
+    fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListFunctions");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListFunctions")
+                        .deserialize::<ListFunctionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListFunctionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists your GraphQL APIs.</p>
+    fn list_graphql_apis(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> RusotoFuture<ListGraphqlApisResponse, ListGraphqlApisError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/v1/apis";
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListGraphqlApis");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListGraphqlApis")
+                        .deserialize::<ListGraphqlApisResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGraphqlApisError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the resolvers for a given API and type.</p>
+    fn list_resolvers(
+        &self,
+        input: ListResolversRequest,
+    ) -> RusotoFuture<ListResolversResponse, ListResolversError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/types/{type_name}/resolvers",
+            api_id = input.api_id,
+            type_name = input.type_name
+        );
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListResolvers");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("typeName", &input.type_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListResolvers")
+                        .deserialize::<ListResolversResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListResolversError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>List the resolvers that are associated with a specific function.</p>
+    fn list_resolvers_by_function(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> RusotoFuture<ListResolversByFunctionResponse, ListResolversByFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/functions/{function_id}/resolvers",
+            api_id = input.api_id,
+            function_id = input.function_id
+        );
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListResolversByFunction");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("functionId", &input.function_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListResolversByFunction")
+                        .deserialize::<ListResolversByFunctionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListResolversByFunctionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the tags for a resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
+                        .deserialize::<ListTagsForResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the types for a given API.</p>
+    fn list_types(
+        &self,
+        input: ListTypesRequest,
+    ) -> RusotoFuture<ListTypesResponse, ListTypesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListTypes");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("format", &input.format);
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTypes")
+                        .deserialize::<ListTypesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTypesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds a new schema to your GraphQL API.</p> <p>This operation is asynchronous. Use to determine when it has completed.</p>
+    fn start_schema_creation(
+        &self,
+        input: StartSchemaCreationRequest,
+    ) -> RusotoFuture<StartSchemaCreationResponse, StartSchemaCreationError> {
+        let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("StartSchemaCreation");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartSchemaCreation")
+                        .deserialize::<StartSchemaCreationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartSchemaCreationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Tags a resource with user-supplied tags.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
+                        .deserialize::<TagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Untags a resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
+                        .deserialize::<UntagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an API key.</p>
+    fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> RusotoFuture<UpdateApiKeyResponse, UpdateApiKeyError> {
+        let request_uri = format!(
+            "/v1/apis/{api_id}/apikeys/{id}",
+            api_id = input.api_id,
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateApiKey");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
@@ -4581,297 +6489,1486 @@ impl AppSync for AppSyncClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartSchemaCreationResponse, _>()?;
+                        .with_operation("UpdateApiKey")
+                        .deserialize::<UpdateApiKeyResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateApiKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a <code>DataSource</code> object.</p>
+    fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> RusotoFuture<UpdateDataSourceResponse, UpdateDataSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/datasources/{name}",
+            api_id = input.api_id,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateDataSource");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDataSource")
+                        .deserialize::<UpdateDataSourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDataSourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a <code>Function</code> object.</p>
+    fn update_function(
+        &self,
+        input: UpdateFunctionRequest,
+    ) -> RusotoFuture<UpdateFunctionResponse, UpdateFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/functions/{function_id}",
+            api_id = input.api_id,
+            function_id = input.function_id
+        );
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateFunction");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("functionId", &input.function_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateFunction")
+                        .deserialize::<UpdateFunctionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateFunctionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a <code>GraphqlApi</code> object.</p>
+    fn update_graphql_api(
+        &self,
+        input: UpdateGraphqlApiRequest,
+    ) -> RusotoFuture<UpdateGraphqlApiResponse, UpdateGraphqlApiError> {
+        let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateGraphqlApi");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateGraphqlApi")
+                        .deserialize::<UpdateGraphqlApiResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateGraphqlApiError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a <code>Resolver</code> object.</p>
+    fn update_resolver(
+        &self,
+        input: UpdateResolverRequest,
+    ) -> RusotoFuture<UpdateResolverResponse, UpdateResolverError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/types/{type_name}/resolvers/{field_name}",
+            api_id = input.api_id,
+            field_name = input.field_name,
+            type_name = input.type_name
+        );
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateResolver");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("fieldName", &input.field_name.to_string());
+        request.add_resource_id("typeName", &input.type_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateResolver")
+                        .deserialize::<UpdateResolverResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateResolverError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a <code>Type</code> object.</p>
+    fn update_type(
+        &self,
+        input: UpdateTypeRequest,
+    ) -> RusotoFuture<UpdateTypeResponse, UpdateTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/v1/apis/{api_id}/types/{type_name}",
+            api_id = input.api_id,
+            type_name = input.type_name
+        );
+
+        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateType");
+        request.add_resource_id("apiId", &input.api_id.to_string());
+        request.add_resource_id("typeName", &input.type_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateType")
+                        .deserialize::<UpdateTypeResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the AWSAppSync API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAppSync {
+    create_api_key: Option<
+        Box<
+            dyn Fn(
+                    CreateApiKeyRequest,
+                )
+                    -> ::std::result::Result<CreateApiKeyResponse, RusotoError<CreateApiKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_data_source: Option<
+        Box<
+            dyn Fn(
+                    CreateDataSourceRequest,
+                ) -> ::std::result::Result<
+                    CreateDataSourceResponse,
+                    RusotoError<CreateDataSourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_function: Option<
+        Box<
+            dyn Fn(
+                    CreateFunctionRequest,
+                ) -> ::std::result::Result<
+                    CreateFunctionResponse,
+                    RusotoError<CreateFunctionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_graphql_api: Option<
+        Box<
+            dyn Fn(
+                    CreateGraphqlApiRequest,
+                ) -> ::std::result::Result<
+                    CreateGraphqlApiResponse,
+                    RusotoError<CreateGraphqlApiError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_resolver: Option<
+        Box<
+            dyn Fn(
+                    CreateResolverRequest,
+                ) -> ::std::result::Result<
+                    CreateResolverResponse,
+                    RusotoError<CreateResolverError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_type: Option<
+        Box<
+            dyn Fn(
+                    CreateTypeRequest,
+                )
+                    -> ::std::result::Result<CreateTypeResponse, RusotoError<CreateTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_api_key: Option<
+        Box<
+            dyn Fn(
+                    DeleteApiKeyRequest,
+                )
+                    -> ::std::result::Result<DeleteApiKeyResponse, RusotoError<DeleteApiKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_data_source: Option<
+        Box<
+            dyn Fn(
+                    DeleteDataSourceRequest,
+                ) -> ::std::result::Result<
+                    DeleteDataSourceResponse,
+                    RusotoError<DeleteDataSourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_function: Option<
+        Box<
+            dyn Fn(
+                    DeleteFunctionRequest,
+                ) -> ::std::result::Result<
+                    DeleteFunctionResponse,
+                    RusotoError<DeleteFunctionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_graphql_api: Option<
+        Box<
+            dyn Fn(
+                    DeleteGraphqlApiRequest,
+                ) -> ::std::result::Result<
+                    DeleteGraphqlApiResponse,
+                    RusotoError<DeleteGraphqlApiError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_resolver: Option<
+        Box<
+            dyn Fn(
+                    DeleteResolverRequest,
+                ) -> ::std::result::Result<
+                    DeleteResolverResponse,
+                    RusotoError<DeleteResolverError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_type: Option<
+        Box<
+            dyn Fn(
+                    DeleteTypeRequest,
+                )
+                    -> ::std::result::Result<DeleteTypeResponse, RusotoError<DeleteTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_data_source: Option<
+        Box<
+            dyn Fn(
+                    GetDataSourceRequest,
+                )
+                    -> ::std::result::Result<GetDataSourceResponse, RusotoError<GetDataSourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_function: Option<
+        Box<
+            dyn Fn(
+                    GetFunctionRequest,
+                )
+                    -> ::std::result::Result<GetFunctionResponse, RusotoError<GetFunctionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_graphql_api: Option<
+        Box<
+            dyn Fn(
+                    GetGraphqlApiRequest,
+                )
+                    -> ::std::result::Result<GetGraphqlApiResponse, RusotoError<GetGraphqlApiError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_introspection_schema: Option<
+        Box<
+            dyn Fn(
+                    GetIntrospectionSchemaRequest,
+                ) -> ::std::result::Result<
+                    GetIntrospectionSchemaResponse,
+                    RusotoError<GetIntrospectionSchemaError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resolver: Option<
+        Box<
+            dyn Fn(
+                    GetResolverRequest,
+                )
+                    -> ::std::result::Result<GetResolverResponse, RusotoError<GetResolverError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_schema_creation_status: Option<
+        Box<
+            dyn Fn(
+                    GetSchemaCreationStatusRequest,
+                ) -> ::std::result::Result<
+                    GetSchemaCreationStatusResponse,
+                    RusotoError<GetSchemaCreationStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_type: Option<
+        Box<
+            dyn Fn(
+                    GetTypeRequest,
+                )
+                    -> ::std::result::Result<GetTypeResponse, RusotoError<GetTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_api_keys: Option<
+        Box<
+            dyn Fn(
+                    ListApiKeysRequest,
+                )
+                    -> ::std::result::Result<ListApiKeysResponse, RusotoError<ListApiKeysError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_data_sources: Option<
+        Box<
+            dyn Fn(
+                    ListDataSourcesRequest,
+                ) -> ::std::result::Result<
+                    ListDataSourcesResponse,
+                    RusotoError<ListDataSourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_functions: Option<
+        Box<
+            dyn Fn(
+                    ListFunctionsRequest,
+                )
+                    -> ::std::result::Result<ListFunctionsResponse, RusotoError<ListFunctionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_graphql_apis: Option<
+        Box<
+            dyn Fn(
+                    ListGraphqlApisRequest,
+                ) -> ::std::result::Result<
+                    ListGraphqlApisResponse,
+                    RusotoError<ListGraphqlApisError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resolvers: Option<
+        Box<
+            dyn Fn(
+                    ListResolversRequest,
+                )
+                    -> ::std::result::Result<ListResolversResponse, RusotoError<ListResolversError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_resolvers_by_function: Option<
+        Box<
+            dyn Fn(
+                    ListResolversByFunctionRequest,
+                ) -> ::std::result::Result<
+                    ListResolversByFunctionResponse,
+                    RusotoError<ListResolversByFunctionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_types: Option<
+        Box<
+            dyn Fn(
+                    ListTypesRequest,
+                )
+                    -> ::std::result::Result<ListTypesResponse, RusotoError<ListTypesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_schema_creation: Option<
+        Box<
+            dyn Fn(
+                    StartSchemaCreationRequest,
+                ) -> ::std::result::Result<
+                    StartSchemaCreationResponse,
+                    RusotoError<StartSchemaCreationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_api_key: Option<
+        Box<
+            dyn Fn(
+                    UpdateApiKeyRequest,
+                )
+                    -> ::std::result::Result<UpdateApiKeyResponse, RusotoError<UpdateApiKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_data_source: Option<
+        Box<
+            dyn Fn(
+                    UpdateDataSourceRequest,
+                ) -> ::std::result::Result<
+                    UpdateDataSourceResponse,
+                    RusotoError<UpdateDataSourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_function: Option<
+        Box<
+            dyn Fn(
+                    UpdateFunctionRequest,
+                ) -> ::std::result::Result<
+                    UpdateFunctionResponse,
+                    RusotoError<UpdateFunctionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_graphql_api: Option<
+        Box<
+            dyn Fn(
+                    UpdateGraphqlApiRequest,
+                ) -> ::std::result::Result<
+                    UpdateGraphqlApiResponse,
+                    RusotoError<UpdateGraphqlApiError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_resolver: Option<
+        Box<
+            dyn Fn(
+                    UpdateResolverRequest,
+                ) -> ::std::result::Result<
+                    UpdateResolverResponse,
+                    RusotoError<UpdateResolverError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_type: Option<
+        Box<
+            dyn Fn(
+                    UpdateTypeRequest,
+                )
+                    -> ::std::result::Result<UpdateTypeResponse, RusotoError<UpdateTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAppSync {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAppSync {
+        Default::default()
+    }
+
+    /// Answers `create_api_key` with what `handler` returns for its input.
+    pub fn with_create_api_key<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                CreateApiKeyRequest,
+            )
+                -> ::std::result::Result<CreateApiKeyResponse, RusotoError<CreateApiKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_api_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_data_source` with what `handler` returns for its input.
+    pub fn with_create_data_source<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                CreateDataSourceRequest,
+            ) -> ::std::result::Result<
+                CreateDataSourceResponse,
+                RusotoError<CreateDataSourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_data_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_function` with what `handler` returns for its input.
+    pub fn with_create_function<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                CreateFunctionRequest,
+            )
+                -> ::std::result::Result<CreateFunctionResponse, RusotoError<CreateFunctionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_graphql_api` with what `handler` returns for its input.
+    pub fn with_create_graphql_api<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                CreateGraphqlApiRequest,
+            ) -> ::std::result::Result<
+                CreateGraphqlApiResponse,
+                RusotoError<CreateGraphqlApiError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_graphql_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_resolver` with what `handler` returns for its input.
+    pub fn with_create_resolver<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                CreateResolverRequest,
+            )
+                -> ::std::result::Result<CreateResolverResponse, RusotoError<CreateResolverError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_resolver = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_type` with what `handler` returns for its input.
+    pub fn with_create_type<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                CreateTypeRequest,
+            )
+                -> ::std::result::Result<CreateTypeResponse, RusotoError<CreateTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_api_key` with what `handler` returns for its input.
+    pub fn with_delete_api_key<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                DeleteApiKeyRequest,
+            )
+                -> ::std::result::Result<DeleteApiKeyResponse, RusotoError<DeleteApiKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_api_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_data_source` with what `handler` returns for its input.
+    pub fn with_delete_data_source<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                DeleteDataSourceRequest,
+            ) -> ::std::result::Result<
+                DeleteDataSourceResponse,
+                RusotoError<DeleteDataSourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_data_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_function` with what `handler` returns for its input.
+    pub fn with_delete_function<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                DeleteFunctionRequest,
+            )
+                -> ::std::result::Result<DeleteFunctionResponse, RusotoError<DeleteFunctionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_graphql_api` with what `handler` returns for its input.
+    pub fn with_delete_graphql_api<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                DeleteGraphqlApiRequest,
+            ) -> ::std::result::Result<
+                DeleteGraphqlApiResponse,
+                RusotoError<DeleteGraphqlApiError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_graphql_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_resolver` with what `handler` returns for its input.
+    pub fn with_delete_resolver<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                DeleteResolverRequest,
+            )
+                -> ::std::result::Result<DeleteResolverResponse, RusotoError<DeleteResolverError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_resolver = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_type` with what `handler` returns for its input.
+    pub fn with_delete_type<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                DeleteTypeRequest,
+            )
+                -> ::std::result::Result<DeleteTypeResponse, RusotoError<DeleteTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_data_source` with what `handler` returns for its input.
+    pub fn with_get_data_source<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                GetDataSourceRequest,
+            )
+                -> ::std::result::Result<GetDataSourceResponse, RusotoError<GetDataSourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_data_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_function` with what `handler` returns for its input.
+    pub fn with_get_function<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                GetFunctionRequest,
+            )
+                -> ::std::result::Result<GetFunctionResponse, RusotoError<GetFunctionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_graphql_api` with what `handler` returns for its input.
+    pub fn with_get_graphql_api<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                GetGraphqlApiRequest,
+            )
+                -> ::std::result::Result<GetGraphqlApiResponse, RusotoError<GetGraphqlApiError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_graphql_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_introspection_schema` with what `handler` returns for its input.
+    pub fn with_get_introspection_schema<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                GetIntrospectionSchemaRequest,
+            ) -> ::std::result::Result<
+                GetIntrospectionSchemaResponse,
+                RusotoError<GetIntrospectionSchemaError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_introspection_schema = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resolver` with what `handler` returns for its input.
+    pub fn with_get_resolver<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                GetResolverRequest,
+            )
+                -> ::std::result::Result<GetResolverResponse, RusotoError<GetResolverError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resolver = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_schema_creation_status` with what `handler` returns for its input.
+    pub fn with_get_schema_creation_status<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                GetSchemaCreationStatusRequest,
+            ) -> ::std::result::Result<
+                GetSchemaCreationStatusResponse,
+                RusotoError<GetSchemaCreationStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_schema_creation_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_type` with what `handler` returns for its input.
+    pub fn with_get_type<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(GetTypeRequest) -> ::std::result::Result<GetTypeResponse, RusotoError<GetTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_api_keys` with what `handler` returns for its input.
+    pub fn with_list_api_keys<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                ListApiKeysRequest,
+            )
+                -> ::std::result::Result<ListApiKeysResponse, RusotoError<ListApiKeysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_api_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_data_sources` with what `handler` returns for its input.
+    pub fn with_list_data_sources<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                ListDataSourcesRequest,
+            )
+                -> ::std::result::Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_data_sources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_functions` with what `handler` returns for its input.
+    pub fn with_list_functions<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                ListFunctionsRequest,
+            )
+                -> ::std::result::Result<ListFunctionsResponse, RusotoError<ListFunctionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_functions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_graphql_apis` with what `handler` returns for its input.
+    pub fn with_list_graphql_apis<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                ListGraphqlApisRequest,
+            )
+                -> ::std::result::Result<ListGraphqlApisResponse, RusotoError<ListGraphqlApisError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_graphql_apis = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resolvers` with what `handler` returns for its input.
+    pub fn with_list_resolvers<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                ListResolversRequest,
+            )
+                -> ::std::result::Result<ListResolversResponse, RusotoError<ListResolversError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resolvers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resolvers_by_function` with what `handler` returns for its input.
+    pub fn with_list_resolvers_by_function<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                ListResolversByFunctionRequest,
+            ) -> ::std::result::Result<
+                ListResolversByFunctionResponse,
+                RusotoError<ListResolversByFunctionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resolvers_by_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_types` with what `handler` returns for its input.
+    pub fn with_list_types<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                ListTypesRequest,
+            ) -> ::std::result::Result<ListTypesResponse, RusotoError<ListTypesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_schema_creation` with what `handler` returns for its input.
+    pub fn with_start_schema_creation<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                StartSchemaCreationRequest,
+            ) -> ::std::result::Result<
+                StartSchemaCreationResponse,
+                RusotoError<StartSchemaCreationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_schema_creation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_api_key` with what `handler` returns for its input.
+    pub fn with_update_api_key<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                UpdateApiKeyRequest,
+            )
+                -> ::std::result::Result<UpdateApiKeyResponse, RusotoError<UpdateApiKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_api_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_data_source` with what `handler` returns for its input.
+    pub fn with_update_data_source<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                UpdateDataSourceRequest,
+            ) -> ::std::result::Result<
+                UpdateDataSourceResponse,
+                RusotoError<UpdateDataSourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_data_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_function` with what `handler` returns for its input.
+    pub fn with_update_function<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                UpdateFunctionRequest,
+            )
+                -> ::std::result::Result<UpdateFunctionResponse, RusotoError<UpdateFunctionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_graphql_api` with what `handler` returns for its input.
+    pub fn with_update_graphql_api<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                UpdateGraphqlApiRequest,
+            ) -> ::std::result::Result<
+                UpdateGraphqlApiResponse,
+                RusotoError<UpdateGraphqlApiError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_graphql_api = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_resolver` with what `handler` returns for its input.
+    pub fn with_update_resolver<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                UpdateResolverRequest,
+            )
+                -> ::std::result::Result<UpdateResolverResponse, RusotoError<UpdateResolverError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_resolver = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_type` with what `handler` returns for its input.
+    pub fn with_update_type<F>(mut self, handler: F) -> MockAppSync
+    where
+        F: Fn(
+                UpdateTypeRequest,
+            )
+                -> ::std::result::Result<UpdateTypeResponse, RusotoError<UpdateTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_type = Some(Box::new(handler));
+        self
+    }
+}
 
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartSchemaCreationError::from_response(response))
-                    }),
-                )
-            }
-        })
+#[cfg(any(test, feature = "mocks"))]
+impl AppSync for MockAppSync {
+    fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> RusotoFuture<CreateApiKeyResponse, CreateApiKeyError> {
+        match self.create_api_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for create_api_key"),
+        }
+    }
+    fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> RusotoFuture<CreateDataSourceResponse, CreateDataSourceError> {
+        match self.create_data_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for create_data_source"),
+        }
+    }
+    fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> RusotoFuture<CreateFunctionResponse, CreateFunctionError> {
+        match self.create_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for create_function"),
+        }
+    }
+    fn create_graphql_api(
+        &self,
+        input: CreateGraphqlApiRequest,
+    ) -> RusotoFuture<CreateGraphqlApiResponse, CreateGraphqlApiError> {
+        match self.create_graphql_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for create_graphql_api"),
+        }
+    }
+    fn create_resolver(
+        &self,
+        input: CreateResolverRequest,
+    ) -> RusotoFuture<CreateResolverResponse, CreateResolverError> {
+        match self.create_resolver {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for create_resolver"),
+        }
+    }
+    fn create_type(
+        &self,
+        input: CreateTypeRequest,
+    ) -> RusotoFuture<CreateTypeResponse, CreateTypeError> {
+        match self.create_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for create_type"),
+        }
+    }
+    fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> RusotoFuture<DeleteApiKeyResponse, DeleteApiKeyError> {
+        match self.delete_api_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for delete_api_key"),
+        }
+    }
+    fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> RusotoFuture<DeleteDataSourceResponse, DeleteDataSourceError> {
+        match self.delete_data_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for delete_data_source"),
+        }
+    }
+    fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> RusotoFuture<DeleteFunctionResponse, DeleteFunctionError> {
+        match self.delete_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for delete_function"),
+        }
+    }
+    fn delete_graphql_api(
+        &self,
+        input: DeleteGraphqlApiRequest,
+    ) -> RusotoFuture<DeleteGraphqlApiResponse, DeleteGraphqlApiError> {
+        match self.delete_graphql_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for delete_graphql_api"),
+        }
+    }
+    fn delete_resolver(
+        &self,
+        input: DeleteResolverRequest,
+    ) -> RusotoFuture<DeleteResolverResponse, DeleteResolverError> {
+        match self.delete_resolver {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for delete_resolver"),
+        }
+    }
+    fn delete_type(
+        &self,
+        input: DeleteTypeRequest,
+    ) -> RusotoFuture<DeleteTypeResponse, DeleteTypeError> {
+        match self.delete_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for delete_type"),
+        }
+    }
+    fn get_data_source(
+        &self,
+        input: GetDataSourceRequest,
+    ) -> RusotoFuture<GetDataSourceResponse, GetDataSourceError> {
+        match self.get_data_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for get_data_source"),
+        }
+    }
+    fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
+        match self.get_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for get_function"),
+        }
+    }
+    fn get_graphql_api(
+        &self,
+        input: GetGraphqlApiRequest,
+    ) -> RusotoFuture<GetGraphqlApiResponse, GetGraphqlApiError> {
+        match self.get_graphql_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for get_graphql_api"),
+        }
+    }
+    fn get_introspection_schema(
+        &self,
+        input: GetIntrospectionSchemaRequest,
+    ) -> RusotoFuture<GetIntrospectionSchemaResponse, GetIntrospectionSchemaError> {
+        match self.get_introspection_schema {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for get_introspection_schema"),
+        }
+    }
+    fn get_resolver(
+        &self,
+        input: GetResolverRequest,
+    ) -> RusotoFuture<GetResolverResponse, GetResolverError> {
+        match self.get_resolver {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for get_resolver"),
+        }
+    }
+    fn get_schema_creation_status(
+        &self,
+        input: GetSchemaCreationStatusRequest,
+    ) -> RusotoFuture<GetSchemaCreationStatusResponse, GetSchemaCreationStatusError> {
+        match self.get_schema_creation_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for get_schema_creation_status"),
+        }
+    }
+    fn get_type(&self, input: GetTypeRequest) -> RusotoFuture<GetTypeResponse, GetTypeError> {
+        match self.get_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for get_type"),
+        }
+    }
+    fn list_api_keys(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> RusotoFuture<ListApiKeysResponse, ListApiKeysError> {
+        match self.list_api_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for list_api_keys"),
+        }
+    }
+    fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> RusotoFuture<ListDataSourcesResponse, ListDataSourcesError> {
+        match self.list_data_sources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for list_data_sources"),
+        }
+    }
+    fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
+        match self.list_functions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for list_functions"),
+        }
+    }
+    fn list_graphql_apis(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> RusotoFuture<ListGraphqlApisResponse, ListGraphqlApisError> {
+        match self.list_graphql_apis {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for list_graphql_apis"),
+        }
+    }
+    fn list_resolvers(
+        &self,
+        input: ListResolversRequest,
+    ) -> RusotoFuture<ListResolversResponse, ListResolversError> {
+        match self.list_resolvers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for list_resolvers"),
+        }
+    }
+    fn list_resolvers_by_function(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> RusotoFuture<ListResolversByFunctionResponse, ListResolversByFunctionError> {
+        match self.list_resolvers_by_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for list_resolvers_by_function"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_types(
+        &self,
+        input: ListTypesRequest,
+    ) -> RusotoFuture<ListTypesResponse, ListTypesError> {
+        match self.list_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for list_types"),
+        }
+    }
+    fn start_schema_creation(
+        &self,
+        input: StartSchemaCreationRequest,
+    ) -> RusotoFuture<StartSchemaCreationResponse, StartSchemaCreationError> {
+        match self.start_schema_creation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for start_schema_creation"),
+        }
     }
-
-    /// <p>Tags a resource with user-supplied tags.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Untags a resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for untag_resource"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Updates an API key.</p>
     fn update_api_key(
         &self,
         input: UpdateApiKeyRequest,
     ) -> RusotoFuture<UpdateApiKeyResponse, UpdateApiKeyError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/apikeys/{id}",
-            api_id = input.api_id,
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateApiKeyResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateApiKeyError::from_response(response))),
-                )
-            }
-        })
+        match self.update_api_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for update_api_key"),
+        }
     }
-
-    /// <p>Updates a <code>DataSource</code> object.</p>
     fn update_data_source(
         &self,
         input: UpdateDataSourceRequest,
     ) -> RusotoFuture<UpdateDataSourceResponse, UpdateDataSourceError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/datasources/{name}",
-            api_id = input.api_id,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDataSourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDataSourceError::from_response(response))),
-                )
-            }
-        })
+        match self.update_data_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for update_data_source"),
+        }
     }
-
-    /// <p>Updates a <code>Function</code> object.</p>
     fn update_function(
         &self,
         input: UpdateFunctionRequest,
     ) -> RusotoFuture<UpdateFunctionResponse, UpdateFunctionError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/functions/{function_id}",
-            api_id = input.api_id,
-            function_id = input.function_id
-        );
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFunctionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateFunctionError::from_response(response))),
-                )
-            }
-        })
+        match self.update_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for update_function"),
+        }
     }
-
-    /// <p>Updates a <code>GraphqlApi</code> object.</p>
     fn update_graphql_api(
         &self,
         input: UpdateGraphqlApiRequest,
     ) -> RusotoFuture<UpdateGraphqlApiResponse, UpdateGraphqlApiError> {
-        let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGraphqlApiResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateGraphqlApiError::from_response(response))),
-                )
-            }
-        })
+        match self.update_graphql_api {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for update_graphql_api"),
+        }
     }
-
-    /// <p>Updates a <code>Resolver</code> object.</p>
     fn update_resolver(
         &self,
         input: UpdateResolverRequest,
     ) -> RusotoFuture<UpdateResolverResponse, UpdateResolverError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/types/{type_name}/resolvers/{field_name}",
-            api_id = input.api_id,
-            field_name = input.field_name,
-            type_name = input.type_name
-        );
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateResolverResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateResolverError::from_response(response))),
-                )
-            }
-        })
+        match self.update_resolver {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for update_resolver"),
+        }
     }
-
-    /// <p>Updates a <code>Type</code> object.</p>
     fn update_type(
         &self,
         input: UpdateTypeRequest,
     ) -> RusotoFuture<UpdateTypeResponse, UpdateTypeError> {
-        let request_uri = format!(
-            "/v1/apis/{api_id}/types/{type_name}",
-            api_id = input.api_id,
-            type_name = input.type_name
-        );
-
-        let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTypeResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTypeError::from_response(response))),
-                )
-            }
-        })
+        match self.update_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAppSync has no handler for update_type"),
+        }
     }
 }
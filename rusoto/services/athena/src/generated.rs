@@ -22,16 +22,34 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetNamedQueryInput {
     /// <p>An array of query IDs.</p>
     #[serde(rename = "NamedQueryIds")]
     pub named_query_ids: Vec<String>,
 }
 
+impl Validate for BatchGetNamedQueryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "named_query_ids"),
+            self.named_query_ids.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.named_query_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "named_query_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "\\S+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetNamedQueryOutput {
     /// <p>Information about the named query IDs submitted.</p>
     #[serde(rename = "NamedQueries")]
@@ -44,14 +62,31 @@ pub struct BatchGetNamedQueryOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetQueryExecutionInput {
     /// <p>An array of query execution IDs.</p>
     #[serde(rename = "QueryExecutionIds")]
     pub query_execution_ids: Vec<String>,
 }
 
+impl Validate for BatchGetQueryExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "query_execution_ids"),
+            self.query_execution_ids.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.query_execution_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "query_execution_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "\\S+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetQueryExecutionOutput {
     /// <p>Information about a query execution.</p>
     #[serde(rename = "QueryExecutions")]
@@ -65,7 +100,7 @@ pub struct BatchGetQueryExecutionOutput {
 
 /// <p>Information about the columns in a query execution result.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ColumnInfo {
     /// <p>Indicates whether values in the column are case-sensitive.</p>
     #[serde(rename = "CaseSensitive")]
@@ -108,6 +143,7 @@ pub struct ColumnInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateNamedQueryInput {
     /// <p><p>A unique case-sensitive string used to ensure the request to create the query is idempotent (executes only once). If another <code>CreateNamedQuery</code> request is received, the same response is returned and another query is not created. If a parameter has changed, for example, the <code>QueryString</code>, an error is returned.</p> <important> <p>This token is listed as not required because AWS SDKs (for example the AWS SDK for Java) auto-generate the token for users. If you are not using the AWS SDK or the AWS CLI, you must provide this token or the action will fail.</p> </important></p>
     #[serde(rename = "ClientRequestToken")]
@@ -132,8 +168,54 @@ pub struct CreateNamedQueryInput {
     pub work_group: Option<String>,
 }
 
+impl Validate for CreateNamedQueryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(32),
+                Some(128),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database"),
+            self.database.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "query_string"),
+            self.query_string.chars().count(),
+            Some(1),
+            Some(262144),
+        );
+        if let Some(ref value) = self.work_group {
+            errors.check_pattern(
+                &field_path(path, "work_group"),
+                &value,
+                "[a-zA-Z0-9._-]{1,128}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateNamedQueryOutput {
     /// <p>The unique ID of the query.</p>
     #[serde(rename = "NamedQueryId")]
@@ -142,6 +224,7 @@ pub struct CreateNamedQueryOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateWorkGroupInput {
     /// <p>The configuration for the workgroup, which includes the location in Amazon S3 where query results are stored, the encryption configuration, if any, used for encrypting query results, whether the Amazon CloudWatch Metrics are enabled for the workgroup, the limit for the amount of bytes scanned (cutoff) per query, if it is specified, and whether workgroup's settings (specified with EnforceWorkGroupConfiguration) in the WorkGroupConfiguration override client-side settings. See <a>WorkGroupConfiguration$EnforceWorkGroupConfiguration</a>.</p>
     #[serde(rename = "Configuration")]
@@ -160,13 +243,40 @@ pub struct CreateWorkGroupInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateWorkGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.configuration {
+            value.validate_at(&field_path(path, "configuration"), errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[a-zA-Z0-9._-]{1,128}",
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateWorkGroupOutput {}
 
 /// <p>A piece of data (a field in the table).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Datum {
     /// <p>The value of the datum.</p>
     #[serde(rename = "VarCharValue")]
@@ -175,17 +285,35 @@ pub struct Datum {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteNamedQueryInput {
     /// <p>The unique ID of the query to delete.</p>
     #[serde(rename = "NamedQueryId")]
     pub named_query_id: String,
 }
 
+impl Validate for DeleteNamedQueryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "named_query_id"),
+            self.named_query_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "named_query_id"),
+            &self.named_query_id,
+            "\\S+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteNamedQueryOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteWorkGroupInput {
     /// <p>The option to delete the workgroup and its contents even if the workgroup contains any named queries.</p>
     #[serde(rename = "RecursiveDeleteOption")]
@@ -196,8 +324,18 @@ pub struct DeleteWorkGroupInput {
     pub work_group: String,
 }
 
+impl Validate for DeleteWorkGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "work_group"),
+            &self.work_group,
+            "[a-zA-Z0-9._-]{1,128}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteWorkGroupOutput {}
 
 /// <p>If query results are encrypted in Amazon S3, indicates the encryption option used (for example, <code>SSE-KMS</code> or <code>CSE-KMS</code>) and key information.</p>
@@ -213,14 +351,31 @@ pub struct EncryptionConfiguration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetNamedQueryInput {
     /// <p>The unique ID of the query. Use <a>ListNamedQueries</a> to get query IDs.</p>
     #[serde(rename = "NamedQueryId")]
     pub named_query_id: String,
 }
 
+impl Validate for GetNamedQueryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "named_query_id"),
+            self.named_query_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "named_query_id"),
+            &self.named_query_id,
+            "\\S+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetNamedQueryOutput {
     /// <p>Information about the query.</p>
     #[serde(rename = "NamedQuery")]
@@ -229,14 +384,31 @@ pub struct GetNamedQueryOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQueryExecutionInput {
     /// <p>The unique ID of the query execution.</p>
     #[serde(rename = "QueryExecutionId")]
     pub query_execution_id: String,
 }
 
+impl Validate for GetQueryExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "query_execution_id"),
+            self.query_execution_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "query_execution_id"),
+            &self.query_execution_id,
+            "\\S+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetQueryExecutionOutput {
     /// <p>Information about the query execution.</p>
     #[serde(rename = "QueryExecution")]
@@ -245,6 +417,7 @@ pub struct GetQueryExecutionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQueryResultsInput {
     /// <p>The maximum number of results (rows) to return in this request.</p>
     #[serde(rename = "MaxResults")]
@@ -259,8 +432,40 @@ pub struct GetQueryResultsInput {
     pub query_execution_id: String,
 }
 
+impl Validate for GetQueryResultsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "query_execution_id"),
+            self.query_execution_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "query_execution_id"),
+            &self.query_execution_id,
+            "\\S+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetQueryResultsOutput {
     /// <p>A token to be used by the next request if this request is truncated.</p>
     #[serde(rename = "NextToken")]
@@ -277,14 +482,25 @@ pub struct GetQueryResultsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetWorkGroupInput {
     /// <p>The name of the workgroup.</p>
     #[serde(rename = "WorkGroup")]
     pub work_group: String,
 }
 
+impl Validate for GetWorkGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "work_group"),
+            &self.work_group,
+            "[a-zA-Z0-9._-]{1,128}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetWorkGroupOutput {
     /// <p>Information about the workgroup.</p>
     #[serde(rename = "WorkGroup")]
@@ -293,6 +509,7 @@ pub struct GetWorkGroupOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListNamedQueriesInput {
     /// <p>The maximum number of queries to return in this request.</p>
     #[serde(rename = "MaxResults")]
@@ -308,8 +525,31 @@ pub struct ListNamedQueriesInput {
     pub work_group: Option<String>,
 }
 
+impl Validate for ListNamedQueriesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.work_group {
+            errors.check_pattern(
+                &field_path(path, "work_group"),
+                &value,
+                "[a-zA-Z0-9._-]{1,128}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListNamedQueriesOutput {
     /// <p>The list of unique query IDs.</p>
     #[serde(rename = "NamedQueryIds")]
@@ -322,6 +562,7 @@ pub struct ListNamedQueriesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListQueryExecutionsInput {
     /// <p>The maximum number of query executions to return in this request.</p>
     #[serde(rename = "MaxResults")]
@@ -337,8 +578,31 @@ pub struct ListQueryExecutionsInput {
     pub work_group: Option<String>,
 }
 
+impl Validate for ListQueryExecutionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.work_group {
+            errors.check_pattern(
+                &field_path(path, "work_group"),
+                &value,
+                "[a-zA-Z0-9._-]{1,128}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListQueryExecutionsOutput {
     /// <p>A token to be used by the next request if this request is truncated.</p>
     #[serde(rename = "NextToken")]
@@ -351,6 +615,7 @@ pub struct ListQueryExecutionsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>The maximum number of results to be returned per request that lists the tags for the workgroup resource.</p>
     #[serde(rename = "MaxResults")]
@@ -365,8 +630,30 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(75), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1011),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceOutput {
     /// <p>A token to be used by the next request if this request is truncated.</p>
     #[serde(rename = "NextToken")]
@@ -379,6 +666,7 @@ pub struct ListTagsForResourceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWorkGroupsInput {
     /// <p>The maximum number of workgroups to return in this request.</p>
     #[serde(rename = "MaxResults")]
@@ -390,8 +678,24 @@ pub struct ListWorkGroupsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListWorkGroupsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWorkGroupsOutput {
     /// <p>A token to be used by the next request if this request is truncated.</p>
     #[serde(rename = "NextToken")]
@@ -405,7 +709,7 @@ pub struct ListWorkGroupsOutput {
 
 /// <p>A query, where <code>QueryString</code> is the list of SQL query statements that comprise the query.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NamedQuery {
     /// <p>The database to which the query belongs.</p>
     #[serde(rename = "Database")]
@@ -432,7 +736,7 @@ pub struct NamedQuery {
 
 /// <p>Information about a single instance of a query execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QueryExecution {
     /// <p>The SQL query statements which the query execution ran.</p>
     #[serde(rename = "Query")]
@@ -477,9 +781,22 @@ pub struct QueryExecutionContext {
     pub database: Option<String>,
 }
 
+impl Validate for QueryExecutionContext {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.database {
+            errors.check_length(
+                &field_path(path, "database"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 /// <p>The location of a manifest file that tracks file locations generated by the query, the amount of data scanned by the query, and the amount of time that it took the query to run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QueryExecutionStatistics {
     /// <p>The location and file name of a data manifest file. The manifest file is saved to the Athena query results location in Amazon S3. It tracks files that the query wrote to Amazon S3. If the query fails, the manifest file also tracks files that the query intended to write. The manifest is useful for identifying orphaned files resulting from a failed query. For more information, see <a href="https://docs.aws.amazon.com/athena/latest/ug/querying.html">Working with Query Output Files</a> in the <i>Amazon Athena User Guide</i>.</p>
     #[serde(rename = "DataManifestLocation")]
@@ -497,7 +814,7 @@ pub struct QueryExecutionStatistics {
 
 /// <p>The completion date, current state, submission time, and state change reason (if applicable) for the query execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QueryExecutionStatus {
     /// <p>The date and time that the query completed.</p>
     #[serde(rename = "CompletionDateTime")]
@@ -532,6 +849,7 @@ pub struct ResultConfiguration {
 
 /// <p>The information about the updates in the query results, such as output location and encryption configuration for the query results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResultConfigurationUpdates {
     /// <p>The encryption configuration for the query results.</p>
     #[serde(rename = "EncryptionConfiguration")]
@@ -553,7 +871,7 @@ pub struct ResultConfigurationUpdates {
 
 /// <p>The metadata and rows that comprise a query result set. The metadata describes the column structure and data types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResultSet {
     /// <p>The metadata that describes the column structure and data types of a table of query results.</p>
     #[serde(rename = "ResultSetMetadata")]
@@ -567,7 +885,7 @@ pub struct ResultSet {
 
 /// <p>The metadata that describes the column structure and data types of a table of query results. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResultSetMetadata {
     /// <p>Information about the columns returned in a query result metadata.</p>
     #[serde(rename = "ColumnInfo")]
@@ -577,7 +895,7 @@ pub struct ResultSetMetadata {
 
 /// <p>The rows that comprise a query result table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Row {
     /// <p>The data that populates a row in a query result table.</p>
     #[serde(rename = "Data")]
@@ -586,6 +904,7 @@ pub struct Row {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartQueryExecutionInput {
     /// <p><p>A unique case-sensitive string used to ensure the request to create the query is idempotent (executes only once). If another <code>StartQueryExecution</code> request is received, the same response is returned and another query is not created. If a parameter has changed, for example, the <code>QueryString</code>, an error is returned.</p> <important> <p>This token is listed as not required because AWS SDKs (for example the AWS SDK for Java) auto-generate the token for users. If you are not using the AWS SDK or the AWS CLI, you must provide this token or the action will fail.</p> </important></p>
     #[serde(rename = "ClientRequestToken")]
@@ -608,8 +927,37 @@ pub struct StartQueryExecutionInput {
     pub work_group: Option<String>,
 }
 
+impl Validate for StartQueryExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(32),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.query_execution_context {
+            value.validate_at(&field_path(path, "query_execution_context"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "query_string"),
+            self.query_string.chars().count(),
+            Some(1),
+            Some(262144),
+        );
+        if let Some(ref value) = self.work_group {
+            errors.check_pattern(
+                &field_path(path, "work_group"),
+                &value,
+                "[a-zA-Z0-9._-]{1,128}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartQueryExecutionOutput {
     /// <p>The unique ID of the query that ran as a result of this request.</p>
     #[serde(rename = "QueryExecutionId")]
@@ -618,14 +966,31 @@ pub struct StartQueryExecutionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopQueryExecutionInput {
     /// <p>The unique ID of the query execution to stop.</p>
     #[serde(rename = "QueryExecutionId")]
     pub query_execution_id: String,
 }
 
+impl Validate for StopQueryExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "query_execution_id"),
+            self.query_execution_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "query_execution_id"),
+            &self.query_execution_id,
+            "\\S+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopQueryExecutionOutput {}
 
 /// <p>A tag that you can add to a resource. A tag is a label that you assign to an AWS Athena resource (a workgroup). Each tag consists of a key and an optional value, both of which you define. Tags enable you to categorize workgroups in Athena, for example, by purpose, owner, or environment. Use a consistent set of tag keys to make it easier to search and filter workgroups in your account. The maximum tag key length is 128 Unicode characters in UTF-8. The maximum tag value length is 256 Unicode characters in UTF-8. You can use letters and numbers representable in UTF-8, and the following characters: + - = . _ : / @. Tag keys and values are case-sensitive. Tag keys must be unique per resource. </p>
@@ -641,7 +1006,29 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p>Requests that one or more tags are added to the resource (such as a workgroup) for the specified ARN.</p>
     #[serde(rename = "ResourceARN")]
@@ -651,13 +1038,28 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1011),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceOutput {}
 
 /// <p>Information about a named query ID that could not be processed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnprocessedNamedQueryId {
     /// <p>The error code returned when the processing request for the named query failed, if applicable.</p>
     #[serde(rename = "ErrorCode")]
@@ -675,7 +1077,7 @@ pub struct UnprocessedNamedQueryId {
 
 /// <p>Describes a query execution that failed to process.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnprocessedQueryExecutionId {
     /// <p>The error code returned when the query execution failed to process, if applicable.</p>
     #[serde(rename = "ErrorCode")]
@@ -692,6 +1094,7 @@ pub struct UnprocessedQueryExecutionId {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p>Removes one or more tags from the workgroup resource for the specified ARN.</p>
     #[serde(rename = "ResourceARN")]
@@ -701,11 +1104,27 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1011),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateWorkGroupInput {
     /// <p>The workgroup configuration that will be updated for the given workgroup.</p>
     #[serde(rename = "ConfigurationUpdates")]
@@ -724,13 +1143,34 @@ pub struct UpdateWorkGroupInput {
     pub work_group: String,
 }
 
+impl Validate for UpdateWorkGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.configuration_updates {
+            value.validate_at(&field_path(path, "configuration_updates"), errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "work_group"),
+            &self.work_group,
+            "[a-zA-Z0-9._-]{1,128}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateWorkGroupOutput {}
 
 /// <p>A workgroup, which contains a name, description, creation time, state, and other configuration, listed under <a>WorkGroup$Configuration</a>. Each workgroup enables you to isolate queries for you or your group of users from other queries in the same account, to configure the query results location and the encryption configuration (known as workgroup settings), to enable sending query metrics to Amazon CloudWatch, and to establish per-query data usage control limits for all queries in a workgroup. The workgroup settings override is specified in EnforceWorkGroupConfiguration (true/false) in the WorkGroupConfiguration. See <a>WorkGroupConfiguration$EnforceWorkGroupConfiguration</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkGroup {
     /// <p>The configuration of the workgroup, which includes the location in Amazon S3 where query results are stored, the encryption configuration, if any, used for query results; whether the Amazon CloudWatch Metrics are enabled for the workgroup; whether workgroup settings override client-side settings; and the data usage limits for the amount of data scanned per query or per workgroup. The workgroup settings override is specified in EnforceWorkGroupConfiguration (true/false) in the WorkGroupConfiguration. See <a>WorkGroupConfiguration$EnforceWorkGroupConfiguration</a>.</p>
     #[serde(rename = "Configuration")]
@@ -778,8 +1218,22 @@ pub struct WorkGroupConfiguration {
     pub result_configuration: Option<ResultConfiguration>,
 }
 
+impl Validate for WorkGroupConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bytes_scanned_cutoff_per_query {
+            errors.check_range(
+                &field_path(path, "bytes_scanned_cutoff_per_query"),
+                *value,
+                Some(10000000),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>The configuration information that will be updated for this workgroup, which includes the location in Amazon S3 where query results are stored, the encryption option, if any, used for query results, whether the Amazon CloudWatch Metrics are enabled for the workgroup, whether the workgroup settings override the client-side settings, and the data usage limit for the amount of bytes scanned per query, if it is specified.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct WorkGroupConfigurationUpdates {
     /// <p>The upper limit (cutoff) for the amount of bytes a single query in a workgroup is allowed to scan.</p>
     #[serde(rename = "BytesScannedCutoffPerQuery")]
@@ -807,9 +1261,22 @@ pub struct WorkGroupConfigurationUpdates {
     pub result_configuration_updates: Option<ResultConfigurationUpdates>,
 }
 
+impl Validate for WorkGroupConfigurationUpdates {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bytes_scanned_cutoff_per_query {
+            errors.check_range(
+                &field_path(path, "bytes_scanned_cutoff_per_query"),
+                *value,
+                Some(10000000),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>The summary information for the workgroup, which includes its name, state, description, and the date and time it was created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkGroupSummary {
     /// <p>The workgroup creation date and time.</p>
     #[serde(rename = "CreationTime")]
@@ -848,7 +1315,12 @@ impl BatchGetNamedQueryError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(BatchGetNamedQueryError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -891,7 +1363,12 @@ impl BatchGetQueryExecutionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -930,7 +1407,12 @@ impl CreateNamedQueryError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(CreateNamedQueryError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -969,7 +1451,12 @@ impl CreateWorkGroupError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(CreateWorkGroupError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1008,7 +1495,12 @@ impl DeleteNamedQueryError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(DeleteNamedQueryError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1047,7 +1539,12 @@ impl DeleteWorkGroupError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(DeleteWorkGroupError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1086,7 +1583,12 @@ impl GetNamedQueryError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(GetNamedQueryError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1125,7 +1627,12 @@ impl GetQueryExecutionError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(GetQueryExecutionError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1164,7 +1671,12 @@ impl GetQueryResultsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(GetQueryResultsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1203,7 +1715,12 @@ impl GetWorkGroupError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(GetWorkGroupError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1242,7 +1759,12 @@ impl ListNamedQueriesError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListNamedQueriesError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1281,7 +1803,12 @@ impl ListQueryExecutionsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListQueryExecutionsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1327,7 +1854,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1367,7 +1899,12 @@ impl ListWorkGroupsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListWorkGroupsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1411,7 +1948,12 @@ impl StartQueryExecutionError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(StartQueryExecutionError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1451,7 +1993,12 @@ impl StopQueryExecutionError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(StopQueryExecutionError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1495,7 +2042,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1540,7 +2092,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1580,7 +2137,12 @@ impl UpdateWorkGroupError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(UpdateWorkGroupError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1716,185 +2278,579 @@ pub trait Athena {
         input: UpdateWorkGroupInput,
     ) -> RusotoFuture<UpdateWorkGroupOutput, UpdateWorkGroupError>;
 }
-/// A client for the Amazon Athena API.
-#[derive(Clone)]
-pub struct AthenaClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl AthenaClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> AthenaClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> AthenaClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> AthenaClient {
-        AthenaClient { client, region }
-    }
-}
-
-impl Athena for AthenaClient {
-    /// <p>Returns the details of a single named query or a list of up to 50 queries, which you provide as an array of query ID strings. Requires you to have access to the workgroup in which the queries were saved. Use <a>ListNamedQueriesInput</a> to get the list of named query IDs in the specified workgroup. If information could not be retrieved for a submitted query ID, information about the query ID submitted is listed under <a>UnprocessedNamedQueryId</a>. Named queries differ from executed queries. Use <a>BatchGetQueryExecutionInput</a> to get details about each unique query execution, and <a>ListQueryExecutionsInput</a> to get a list of query execution IDs.</p>
+const _: Option<&dyn Athena> = None;
+impl<'a, T: Athena + ?Sized> Athena for &'a T {
     fn batch_get_named_query(
         &self,
         input: BatchGetNamedQueryInput,
     ) -> RusotoFuture<BatchGetNamedQueryOutput, BatchGetNamedQueryError> {
-        let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonAthena.BatchGetNamedQuery");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetNamedQueryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetNamedQueryError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_named_query(input)
     }
-
-    /// <p>Returns the details of a single query execution or a list of up to 50 query executions, which you provide as an array of query execution ID strings. Requires you to have access to the workgroup in which the queries ran. To get a list of query execution IDs, use <a>ListQueryExecutionsInput$WorkGroup</a>. Query executions differ from named (saved) queries. Use <a>BatchGetNamedQueryInput</a> to get details about named queries.</p>
     fn batch_get_query_execution(
         &self,
         input: BatchGetQueryExecutionInput,
     ) -> RusotoFuture<BatchGetQueryExecutionOutput, BatchGetQueryExecutionError> {
-        let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonAthena.BatchGetQueryExecution");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetQueryExecutionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchGetQueryExecutionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_get_query_execution(input)
     }
-
-    /// <p>Creates a named query in the specified workgroup. Requires that you have access to the workgroup.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
     fn create_named_query(
         &self,
         input: CreateNamedQueryInput,
     ) -> RusotoFuture<CreateNamedQueryOutput, CreateNamedQueryError> {
-        let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonAthena.CreateNamedQuery");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateNamedQueryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateNamedQueryError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_named_query(input)
     }
-
-    /// <p>Creates a workgroup with the specified name.</p>
     fn create_work_group(
         &self,
         input: CreateWorkGroupInput,
     ) -> RusotoFuture<CreateWorkGroupOutput, CreateWorkGroupError> {
-        let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonAthena.CreateWorkGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateWorkGroupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateWorkGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_work_group(input)
     }
-
-    /// <p>Deletes the named query if you have access to the workgroup in which the query was saved.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
     fn delete_named_query(
         &self,
         input: DeleteNamedQueryInput,
     ) -> RusotoFuture<DeleteNamedQueryOutput, DeleteNamedQueryError> {
-        let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonAthena.DeleteNamedQuery");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteNamedQueryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteNamedQueryError::from_response(response))),
-                )
-            }
+        (**self).delete_named_query(input)
+    }
+    fn delete_work_group(
+        &self,
+        input: DeleteWorkGroupInput,
+    ) -> RusotoFuture<DeleteWorkGroupOutput, DeleteWorkGroupError> {
+        (**self).delete_work_group(input)
+    }
+    fn get_named_query(
+        &self,
+        input: GetNamedQueryInput,
+    ) -> RusotoFuture<GetNamedQueryOutput, GetNamedQueryError> {
+        (**self).get_named_query(input)
+    }
+    fn get_query_execution(
+        &self,
+        input: GetQueryExecutionInput,
+    ) -> RusotoFuture<GetQueryExecutionOutput, GetQueryExecutionError> {
+        (**self).get_query_execution(input)
+    }
+    fn get_query_results(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> RusotoFuture<GetQueryResultsOutput, GetQueryResultsError> {
+        (**self).get_query_results(input)
+    }
+    fn get_work_group(
+        &self,
+        input: GetWorkGroupInput,
+    ) -> RusotoFuture<GetWorkGroupOutput, GetWorkGroupError> {
+        (**self).get_work_group(input)
+    }
+    fn list_named_queries(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> RusotoFuture<ListNamedQueriesOutput, ListNamedQueriesError> {
+        (**self).list_named_queries(input)
+    }
+    fn list_query_executions(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> RusotoFuture<ListQueryExecutionsOutput, ListQueryExecutionsError> {
+        (**self).list_query_executions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_work_groups(
+        &self,
+        input: ListWorkGroupsInput,
+    ) -> RusotoFuture<ListWorkGroupsOutput, ListWorkGroupsError> {
+        (**self).list_work_groups(input)
+    }
+    fn start_query_execution(
+        &self,
+        input: StartQueryExecutionInput,
+    ) -> RusotoFuture<StartQueryExecutionOutput, StartQueryExecutionError> {
+        (**self).start_query_execution(input)
+    }
+    fn stop_query_execution(
+        &self,
+        input: StopQueryExecutionInput,
+    ) -> RusotoFuture<StopQueryExecutionOutput, StopQueryExecutionError> {
+        (**self).stop_query_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_work_group(
+        &self,
+        input: UpdateWorkGroupInput,
+    ) -> RusotoFuture<UpdateWorkGroupOutput, UpdateWorkGroupError> {
+        (**self).update_work_group(input)
+    }
+}
+impl<T: Athena + ?Sized> Athena for Box<T> {
+    fn batch_get_named_query(
+        &self,
+        input: BatchGetNamedQueryInput,
+    ) -> RusotoFuture<BatchGetNamedQueryOutput, BatchGetNamedQueryError> {
+        (**self).batch_get_named_query(input)
+    }
+    fn batch_get_query_execution(
+        &self,
+        input: BatchGetQueryExecutionInput,
+    ) -> RusotoFuture<BatchGetQueryExecutionOutput, BatchGetQueryExecutionError> {
+        (**self).batch_get_query_execution(input)
+    }
+    fn create_named_query(
+        &self,
+        input: CreateNamedQueryInput,
+    ) -> RusotoFuture<CreateNamedQueryOutput, CreateNamedQueryError> {
+        (**self).create_named_query(input)
+    }
+    fn create_work_group(
+        &self,
+        input: CreateWorkGroupInput,
+    ) -> RusotoFuture<CreateWorkGroupOutput, CreateWorkGroupError> {
+        (**self).create_work_group(input)
+    }
+    fn delete_named_query(
+        &self,
+        input: DeleteNamedQueryInput,
+    ) -> RusotoFuture<DeleteNamedQueryOutput, DeleteNamedQueryError> {
+        (**self).delete_named_query(input)
+    }
+    fn delete_work_group(
+        &self,
+        input: DeleteWorkGroupInput,
+    ) -> RusotoFuture<DeleteWorkGroupOutput, DeleteWorkGroupError> {
+        (**self).delete_work_group(input)
+    }
+    fn get_named_query(
+        &self,
+        input: GetNamedQueryInput,
+    ) -> RusotoFuture<GetNamedQueryOutput, GetNamedQueryError> {
+        (**self).get_named_query(input)
+    }
+    fn get_query_execution(
+        &self,
+        input: GetQueryExecutionInput,
+    ) -> RusotoFuture<GetQueryExecutionOutput, GetQueryExecutionError> {
+        (**self).get_query_execution(input)
+    }
+    fn get_query_results(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> RusotoFuture<GetQueryResultsOutput, GetQueryResultsError> {
+        (**self).get_query_results(input)
+    }
+    fn get_work_group(
+        &self,
+        input: GetWorkGroupInput,
+    ) -> RusotoFuture<GetWorkGroupOutput, GetWorkGroupError> {
+        (**self).get_work_group(input)
+    }
+    fn list_named_queries(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> RusotoFuture<ListNamedQueriesOutput, ListNamedQueriesError> {
+        (**self).list_named_queries(input)
+    }
+    fn list_query_executions(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> RusotoFuture<ListQueryExecutionsOutput, ListQueryExecutionsError> {
+        (**self).list_query_executions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_work_groups(
+        &self,
+        input: ListWorkGroupsInput,
+    ) -> RusotoFuture<ListWorkGroupsOutput, ListWorkGroupsError> {
+        (**self).list_work_groups(input)
+    }
+    fn start_query_execution(
+        &self,
+        input: StartQueryExecutionInput,
+    ) -> RusotoFuture<StartQueryExecutionOutput, StartQueryExecutionError> {
+        (**self).start_query_execution(input)
+    }
+    fn stop_query_execution(
+        &self,
+        input: StopQueryExecutionInput,
+    ) -> RusotoFuture<StopQueryExecutionOutput, StopQueryExecutionError> {
+        (**self).stop_query_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_work_group(
+        &self,
+        input: UpdateWorkGroupInput,
+    ) -> RusotoFuture<UpdateWorkGroupOutput, UpdateWorkGroupError> {
+        (**self).update_work_group(input)
+    }
+}
+impl<T: Athena + ?Sized> Athena for ::std::sync::Arc<T> {
+    fn batch_get_named_query(
+        &self,
+        input: BatchGetNamedQueryInput,
+    ) -> RusotoFuture<BatchGetNamedQueryOutput, BatchGetNamedQueryError> {
+        (**self).batch_get_named_query(input)
+    }
+    fn batch_get_query_execution(
+        &self,
+        input: BatchGetQueryExecutionInput,
+    ) -> RusotoFuture<BatchGetQueryExecutionOutput, BatchGetQueryExecutionError> {
+        (**self).batch_get_query_execution(input)
+    }
+    fn create_named_query(
+        &self,
+        input: CreateNamedQueryInput,
+    ) -> RusotoFuture<CreateNamedQueryOutput, CreateNamedQueryError> {
+        (**self).create_named_query(input)
+    }
+    fn create_work_group(
+        &self,
+        input: CreateWorkGroupInput,
+    ) -> RusotoFuture<CreateWorkGroupOutput, CreateWorkGroupError> {
+        (**self).create_work_group(input)
+    }
+    fn delete_named_query(
+        &self,
+        input: DeleteNamedQueryInput,
+    ) -> RusotoFuture<DeleteNamedQueryOutput, DeleteNamedQueryError> {
+        (**self).delete_named_query(input)
+    }
+    fn delete_work_group(
+        &self,
+        input: DeleteWorkGroupInput,
+    ) -> RusotoFuture<DeleteWorkGroupOutput, DeleteWorkGroupError> {
+        (**self).delete_work_group(input)
+    }
+    fn get_named_query(
+        &self,
+        input: GetNamedQueryInput,
+    ) -> RusotoFuture<GetNamedQueryOutput, GetNamedQueryError> {
+        (**self).get_named_query(input)
+    }
+    fn get_query_execution(
+        &self,
+        input: GetQueryExecutionInput,
+    ) -> RusotoFuture<GetQueryExecutionOutput, GetQueryExecutionError> {
+        (**self).get_query_execution(input)
+    }
+    fn get_query_results(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> RusotoFuture<GetQueryResultsOutput, GetQueryResultsError> {
+        (**self).get_query_results(input)
+    }
+    fn get_work_group(
+        &self,
+        input: GetWorkGroupInput,
+    ) -> RusotoFuture<GetWorkGroupOutput, GetWorkGroupError> {
+        (**self).get_work_group(input)
+    }
+    fn list_named_queries(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> RusotoFuture<ListNamedQueriesOutput, ListNamedQueriesError> {
+        (**self).list_named_queries(input)
+    }
+    fn list_query_executions(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> RusotoFuture<ListQueryExecutionsOutput, ListQueryExecutionsError> {
+        (**self).list_query_executions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_work_groups(
+        &self,
+        input: ListWorkGroupsInput,
+    ) -> RusotoFuture<ListWorkGroupsOutput, ListWorkGroupsError> {
+        (**self).list_work_groups(input)
+    }
+    fn start_query_execution(
+        &self,
+        input: StartQueryExecutionInput,
+    ) -> RusotoFuture<StartQueryExecutionOutput, StartQueryExecutionError> {
+        (**self).start_query_execution(input)
+    }
+    fn stop_query_execution(
+        &self,
+        input: StopQueryExecutionInput,
+    ) -> RusotoFuture<StopQueryExecutionOutput, StopQueryExecutionError> {
+        (**self).stop_query_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_work_group(
+        &self,
+        input: UpdateWorkGroupInput,
+    ) -> RusotoFuture<UpdateWorkGroupOutput, UpdateWorkGroupError> {
+        (**self).update_work_group(input)
+    }
+}
+/// A client for the Amazon Athena API.
+#[derive(Clone)]
+pub struct AthenaClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl AthenaClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AthenaClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AthenaClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> AthenaClient {
+        AthenaClient { client, region }
+    }
+}
+
+impl Athena for AthenaClient {
+    /// <p>Returns the details of a single named query or a list of up to 50 queries, which you provide as an array of query ID strings. Requires you to have access to the workgroup in which the queries were saved. Use <a>ListNamedQueriesInput</a> to get the list of named query IDs in the specified workgroup. If information could not be retrieved for a submitted query ID, information about the query ID submitted is listed under <a>UnprocessedNamedQueryId</a>. Named queries differ from executed queries. Use <a>BatchGetQueryExecutionInput</a> to get details about each unique query execution, and <a>ListQueryExecutionsInput</a> to get a list of query execution IDs.</p>
+    fn batch_get_named_query(
+        &self,
+        input: BatchGetNamedQueryInput,
+    ) -> RusotoFuture<BatchGetNamedQueryOutput, BatchGetNamedQueryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("BatchGetNamedQuery");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonAthena.BatchGetNamedQuery");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetNamedQueryOutput, _>(
+                    response,
+                    "BatchGetNamedQuery",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetNamedQueryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the details of a single query execution or a list of up to 50 query executions, which you provide as an array of query execution ID strings. Requires you to have access to the workgroup in which the queries ran. To get a list of query execution IDs, use <a>ListQueryExecutionsInput$WorkGroup</a>. Query executions differ from named (saved) queries. Use <a>BatchGetNamedQueryInput</a> to get details about named queries.</p>
+    fn batch_get_query_execution(
+        &self,
+        input: BatchGetQueryExecutionInput,
+    ) -> RusotoFuture<BatchGetQueryExecutionOutput, BatchGetQueryExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("BatchGetQueryExecution");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonAthena.BatchGetQueryExecution");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetQueryExecutionOutput, _>(
+                    response,
+                    "BatchGetQueryExecution",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchGetQueryExecutionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a named query in the specified workgroup. Requires that you have access to the workgroup.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    fn create_named_query(
+        &self,
+        input: CreateNamedQueryInput,
+    ) -> RusotoFuture<CreateNamedQueryOutput, CreateNamedQueryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("CreateNamedQuery");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonAthena.CreateNamedQuery");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateNamedQueryOutput, _>(
+                    response,
+                    "CreateNamedQuery",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateNamedQueryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a workgroup with the specified name.</p>
+    fn create_work_group(
+        &self,
+        input: CreateWorkGroupInput,
+    ) -> RusotoFuture<CreateWorkGroupOutput, CreateWorkGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("CreateWorkGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonAthena.CreateWorkGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateWorkGroupOutput, _>(
+                    response,
+                    "CreateWorkGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateWorkGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the named query if you have access to the workgroup in which the query was saved.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    fn delete_named_query(
+        &self,
+        input: DeleteNamedQueryInput,
+    ) -> RusotoFuture<DeleteNamedQueryOutput, DeleteNamedQueryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("DeleteNamedQuery");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonAthena.DeleteNamedQuery");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteNamedQueryOutput, _>(
+                    response,
+                    "DeleteNamedQuery",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteNamedQueryError::from_response(response))),
+                )
+            }
         })
     }
 
@@ -1903,7 +2859,16 @@ impl Athena for AthenaClient {
         &self,
         input: DeleteWorkGroupInput,
     ) -> RusotoFuture<DeleteWorkGroupOutput, DeleteWorkGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("DeleteWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.DeleteWorkGroup");
@@ -1912,10 +2877,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteWorkGroupOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteWorkGroupOutput, _>(
+                    response,
+                    "DeleteWorkGroup",
+                )
             } else {
                 Box::new(
                     response
@@ -1932,7 +2897,16 @@ impl Athena for AthenaClient {
         &self,
         input: GetNamedQueryInput,
     ) -> RusotoFuture<GetNamedQueryOutput, GetNamedQueryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetNamedQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetNamedQuery");
@@ -1941,10 +2915,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetNamedQueryOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetNamedQueryOutput, _>(
+                    response,
+                    "GetNamedQuery",
+                )
             } else {
                 Box::new(
                     response
@@ -1961,7 +2935,16 @@ impl Athena for AthenaClient {
         &self,
         input: GetQueryExecutionInput,
     ) -> RusotoFuture<GetQueryExecutionOutput, GetQueryExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetQueryExecution");
@@ -1970,10 +2953,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetQueryExecutionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetQueryExecutionOutput, _>(
+                    response,
+                    "GetQueryExecution",
+                )
             } else {
                 Box::new(
                     response
@@ -1990,7 +2973,16 @@ impl Athena for AthenaClient {
         &self,
         input: GetQueryResultsInput,
     ) -> RusotoFuture<GetQueryResultsOutput, GetQueryResultsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetQueryResults");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetQueryResults");
@@ -1999,10 +2991,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetQueryResultsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetQueryResultsOutput, _>(
+                    response,
+                    "GetQueryResults",
+                )
             } else {
                 Box::new(
                     response
@@ -2019,7 +3011,16 @@ impl Athena for AthenaClient {
         &self,
         input: GetWorkGroupInput,
     ) -> RusotoFuture<GetWorkGroupOutput, GetWorkGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetWorkGroup");
@@ -2028,10 +3029,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetWorkGroupOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetWorkGroupOutput, _>(
+                    response,
+                    "GetWorkGroup",
+                )
             } else {
                 Box::new(
                     response
@@ -2048,7 +3049,16 @@ impl Athena for AthenaClient {
         &self,
         input: ListNamedQueriesInput,
     ) -> RusotoFuture<ListNamedQueriesOutput, ListNamedQueriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListNamedQueries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListNamedQueries");
@@ -2057,10 +3067,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListNamedQueriesOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListNamedQueriesOutput, _>(
+                    response,
+                    "ListNamedQueries",
+                )
             } else {
                 Box::new(
                     response
@@ -2077,7 +3087,16 @@ impl Athena for AthenaClient {
         &self,
         input: ListQueryExecutionsInput,
     ) -> RusotoFuture<ListQueryExecutionsOutput, ListQueryExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListQueryExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListQueryExecutions");
@@ -2086,10 +3105,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListQueryExecutionsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListQueryExecutionsOutput, _>(
+                    response,
+                    "ListQueryExecutions",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2105,7 +3124,16 @@ impl Athena for AthenaClient {
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListTagsForResource");
@@ -2114,10 +3142,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForResourceOutput, _>(
+                    response,
+                    "ListTagsForResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2133,7 +3161,16 @@ impl Athena for AthenaClient {
         &self,
         input: ListWorkGroupsInput,
     ) -> RusotoFuture<ListWorkGroupsOutput, ListWorkGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListWorkGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListWorkGroups");
@@ -2142,10 +3179,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListWorkGroupsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListWorkGroupsOutput, _>(
+                    response,
+                    "ListWorkGroups",
+                )
             } else {
                 Box::new(
                     response
@@ -2162,7 +3199,16 @@ impl Athena for AthenaClient {
         &self,
         input: StartQueryExecutionInput,
     ) -> RusotoFuture<StartQueryExecutionOutput, StartQueryExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("StartQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.StartQueryExecution");
@@ -2171,10 +3217,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartQueryExecutionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<StartQueryExecutionOutput, _>(
+                    response,
+                    "StartQueryExecution",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2190,7 +3236,16 @@ impl Athena for AthenaClient {
         &self,
         input: StopQueryExecutionInput,
     ) -> RusotoFuture<StopQueryExecutionOutput, StopQueryExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("StopQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.StopQueryExecution");
@@ -2199,10 +3254,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopQueryExecutionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<StopQueryExecutionOutput, _>(
+                    response,
+                    "StopQueryExecution",
+                )
             } else {
                 Box::new(
                     response
@@ -2219,7 +3274,16 @@ impl Athena for AthenaClient {
         &self,
         input: TagResourceInput,
     ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.TagResource");
@@ -2228,10 +3292,7 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourceOutput, _>(response, "TagResource")
             } else {
                 Box::new(
                     response
@@ -2248,7 +3309,16 @@ impl Athena for AthenaClient {
         &self,
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.UntagResource");
@@ -2257,10 +3327,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourceOutput, _>(
+                    response,
+                    "UntagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -2277,7 +3347,16 @@ impl Athena for AthenaClient {
         &self,
         input: UpdateWorkGroupInput,
     ) -> RusotoFuture<UpdateWorkGroupOutput, UpdateWorkGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("UpdateWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.UpdateWorkGroup");
@@ -2286,10 +3365,10 @@ impl Athena for AthenaClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateWorkGroupOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateWorkGroupOutput, _>(
+                    response,
+                    "UpdateWorkGroup",
+                )
             } else {
                 Box::new(
                     response
@@ -2301,3 +3380,673 @@ impl Athena for AthenaClient {
         })
     }
 }
+
+/// A mock of the Amazon Athena API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAthena {
+    batch_get_named_query: Option<
+        Box<
+            dyn Fn(
+                    BatchGetNamedQueryInput,
+                ) -> ::std::result::Result<
+                    BatchGetNamedQueryOutput,
+                    RusotoError<BatchGetNamedQueryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_query_execution: Option<
+        Box<
+            dyn Fn(
+                    BatchGetQueryExecutionInput,
+                ) -> ::std::result::Result<
+                    BatchGetQueryExecutionOutput,
+                    RusotoError<BatchGetQueryExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_named_query: Option<
+        Box<
+            dyn Fn(
+                    CreateNamedQueryInput,
+                ) -> ::std::result::Result<
+                    CreateNamedQueryOutput,
+                    RusotoError<CreateNamedQueryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_work_group: Option<
+        Box<
+            dyn Fn(
+                    CreateWorkGroupInput,
+                ) -> ::std::result::Result<
+                    CreateWorkGroupOutput,
+                    RusotoError<CreateWorkGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_named_query: Option<
+        Box<
+            dyn Fn(
+                    DeleteNamedQueryInput,
+                ) -> ::std::result::Result<
+                    DeleteNamedQueryOutput,
+                    RusotoError<DeleteNamedQueryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_work_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteWorkGroupInput,
+                ) -> ::std::result::Result<
+                    DeleteWorkGroupOutput,
+                    RusotoError<DeleteWorkGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_named_query: Option<
+        Box<
+            dyn Fn(
+                    GetNamedQueryInput,
+                )
+                    -> ::std::result::Result<GetNamedQueryOutput, RusotoError<GetNamedQueryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_query_execution: Option<
+        Box<
+            dyn Fn(
+                    GetQueryExecutionInput,
+                ) -> ::std::result::Result<
+                    GetQueryExecutionOutput,
+                    RusotoError<GetQueryExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_query_results: Option<
+        Box<
+            dyn Fn(
+                    GetQueryResultsInput,
+                ) -> ::std::result::Result<
+                    GetQueryResultsOutput,
+                    RusotoError<GetQueryResultsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_work_group: Option<
+        Box<
+            dyn Fn(
+                    GetWorkGroupInput,
+                )
+                    -> ::std::result::Result<GetWorkGroupOutput, RusotoError<GetWorkGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_named_queries: Option<
+        Box<
+            dyn Fn(
+                    ListNamedQueriesInput,
+                ) -> ::std::result::Result<
+                    ListNamedQueriesOutput,
+                    RusotoError<ListNamedQueriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_query_executions: Option<
+        Box<
+            dyn Fn(
+                    ListQueryExecutionsInput,
+                ) -> ::std::result::Result<
+                    ListQueryExecutionsOutput,
+                    RusotoError<ListQueryExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceOutput,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_work_groups: Option<
+        Box<
+            dyn Fn(
+                    ListWorkGroupsInput,
+                )
+                    -> ::std::result::Result<ListWorkGroupsOutput, RusotoError<ListWorkGroupsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_query_execution: Option<
+        Box<
+            dyn Fn(
+                    StartQueryExecutionInput,
+                ) -> ::std::result::Result<
+                    StartQueryExecutionOutput,
+                    RusotoError<StartQueryExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_query_execution: Option<
+        Box<
+            dyn Fn(
+                    StopQueryExecutionInput,
+                ) -> ::std::result::Result<
+                    StopQueryExecutionOutput,
+                    RusotoError<StopQueryExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceInput,
+                )
+                    -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceInput,
+                )
+                    -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_work_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateWorkGroupInput,
+                ) -> ::std::result::Result<
+                    UpdateWorkGroupOutput,
+                    RusotoError<UpdateWorkGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAthena {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAthena {
+        Default::default()
+    }
+
+    /// Answers `batch_get_named_query` with what `handler` returns for its input.
+    pub fn with_batch_get_named_query<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                BatchGetNamedQueryInput,
+            ) -> ::std::result::Result<
+                BatchGetNamedQueryOutput,
+                RusotoError<BatchGetNamedQueryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_named_query = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_query_execution` with what `handler` returns for its input.
+    pub fn with_batch_get_query_execution<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                BatchGetQueryExecutionInput,
+            ) -> ::std::result::Result<
+                BatchGetQueryExecutionOutput,
+                RusotoError<BatchGetQueryExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_query_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_named_query` with what `handler` returns for its input.
+    pub fn with_create_named_query<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                CreateNamedQueryInput,
+            )
+                -> ::std::result::Result<CreateNamedQueryOutput, RusotoError<CreateNamedQueryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_named_query = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_work_group` with what `handler` returns for its input.
+    pub fn with_create_work_group<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                CreateWorkGroupInput,
+            )
+                -> ::std::result::Result<CreateWorkGroupOutput, RusotoError<CreateWorkGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_work_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_named_query` with what `handler` returns for its input.
+    pub fn with_delete_named_query<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                DeleteNamedQueryInput,
+            )
+                -> ::std::result::Result<DeleteNamedQueryOutput, RusotoError<DeleteNamedQueryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_named_query = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_work_group` with what `handler` returns for its input.
+    pub fn with_delete_work_group<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                DeleteWorkGroupInput,
+            )
+                -> ::std::result::Result<DeleteWorkGroupOutput, RusotoError<DeleteWorkGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_work_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_named_query` with what `handler` returns for its input.
+    pub fn with_get_named_query<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                GetNamedQueryInput,
+            )
+                -> ::std::result::Result<GetNamedQueryOutput, RusotoError<GetNamedQueryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_named_query = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_query_execution` with what `handler` returns for its input.
+    pub fn with_get_query_execution<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                GetQueryExecutionInput,
+            ) -> ::std::result::Result<
+                GetQueryExecutionOutput,
+                RusotoError<GetQueryExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_query_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_query_results` with what `handler` returns for its input.
+    pub fn with_get_query_results<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                GetQueryResultsInput,
+            )
+                -> ::std::result::Result<GetQueryResultsOutput, RusotoError<GetQueryResultsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_query_results = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_work_group` with what `handler` returns for its input.
+    pub fn with_get_work_group<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                GetWorkGroupInput,
+            )
+                -> ::std::result::Result<GetWorkGroupOutput, RusotoError<GetWorkGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_work_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_named_queries` with what `handler` returns for its input.
+    pub fn with_list_named_queries<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                ListNamedQueriesInput,
+            )
+                -> ::std::result::Result<ListNamedQueriesOutput, RusotoError<ListNamedQueriesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_named_queries = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_query_executions` with what `handler` returns for its input.
+    pub fn with_list_query_executions<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                ListQueryExecutionsInput,
+            ) -> ::std::result::Result<
+                ListQueryExecutionsOutput,
+                RusotoError<ListQueryExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_query_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceOutput,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_work_groups` with what `handler` returns for its input.
+    pub fn with_list_work_groups<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                ListWorkGroupsInput,
+            )
+                -> ::std::result::Result<ListWorkGroupsOutput, RusotoError<ListWorkGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_work_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_query_execution` with what `handler` returns for its input.
+    pub fn with_start_query_execution<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                StartQueryExecutionInput,
+            ) -> ::std::result::Result<
+                StartQueryExecutionOutput,
+                RusotoError<StartQueryExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_query_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_query_execution` with what `handler` returns for its input.
+    pub fn with_stop_query_execution<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                StopQueryExecutionInput,
+            ) -> ::std::result::Result<
+                StopQueryExecutionOutput,
+                RusotoError<StopQueryExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_query_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                TagResourceInput,
+            )
+                -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                UntagResourceInput,
+            )
+                -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_work_group` with what `handler` returns for its input.
+    pub fn with_update_work_group<F>(mut self, handler: F) -> MockAthena
+    where
+        F: Fn(
+                UpdateWorkGroupInput,
+            )
+                -> ::std::result::Result<UpdateWorkGroupOutput, RusotoError<UpdateWorkGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_work_group = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Athena for MockAthena {
+    fn batch_get_named_query(
+        &self,
+        input: BatchGetNamedQueryInput,
+    ) -> RusotoFuture<BatchGetNamedQueryOutput, BatchGetNamedQueryError> {
+        match self.batch_get_named_query {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for batch_get_named_query"),
+        }
+    }
+    fn batch_get_query_execution(
+        &self,
+        input: BatchGetQueryExecutionInput,
+    ) -> RusotoFuture<BatchGetQueryExecutionOutput, BatchGetQueryExecutionError> {
+        match self.batch_get_query_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for batch_get_query_execution"),
+        }
+    }
+    fn create_named_query(
+        &self,
+        input: CreateNamedQueryInput,
+    ) -> RusotoFuture<CreateNamedQueryOutput, CreateNamedQueryError> {
+        match self.create_named_query {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for create_named_query"),
+        }
+    }
+    fn create_work_group(
+        &self,
+        input: CreateWorkGroupInput,
+    ) -> RusotoFuture<CreateWorkGroupOutput, CreateWorkGroupError> {
+        match self.create_work_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for create_work_group"),
+        }
+    }
+    fn delete_named_query(
+        &self,
+        input: DeleteNamedQueryInput,
+    ) -> RusotoFuture<DeleteNamedQueryOutput, DeleteNamedQueryError> {
+        match self.delete_named_query {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for delete_named_query"),
+        }
+    }
+    fn delete_work_group(
+        &self,
+        input: DeleteWorkGroupInput,
+    ) -> RusotoFuture<DeleteWorkGroupOutput, DeleteWorkGroupError> {
+        match self.delete_work_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for delete_work_group"),
+        }
+    }
+    fn get_named_query(
+        &self,
+        input: GetNamedQueryInput,
+    ) -> RusotoFuture<GetNamedQueryOutput, GetNamedQueryError> {
+        match self.get_named_query {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for get_named_query"),
+        }
+    }
+    fn get_query_execution(
+        &self,
+        input: GetQueryExecutionInput,
+    ) -> RusotoFuture<GetQueryExecutionOutput, GetQueryExecutionError> {
+        match self.get_query_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for get_query_execution"),
+        }
+    }
+    fn get_query_results(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> RusotoFuture<GetQueryResultsOutput, GetQueryResultsError> {
+        match self.get_query_results {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for get_query_results"),
+        }
+    }
+    fn get_work_group(
+        &self,
+        input: GetWorkGroupInput,
+    ) -> RusotoFuture<GetWorkGroupOutput, GetWorkGroupError> {
+        match self.get_work_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for get_work_group"),
+        }
+    }
+    fn list_named_queries(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> RusotoFuture<ListNamedQueriesOutput, ListNamedQueriesError> {
+        match self.list_named_queries {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for list_named_queries"),
+        }
+    }
+    fn list_query_executions(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> RusotoFuture<ListQueryExecutionsOutput, ListQueryExecutionsError> {
+        match self.list_query_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for list_query_executions"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_work_groups(
+        &self,
+        input: ListWorkGroupsInput,
+    ) -> RusotoFuture<ListWorkGroupsOutput, ListWorkGroupsError> {
+        match self.list_work_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for list_work_groups"),
+        }
+    }
+    fn start_query_execution(
+        &self,
+        input: StartQueryExecutionInput,
+    ) -> RusotoFuture<StartQueryExecutionOutput, StartQueryExecutionError> {
+        match self.start_query_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for start_query_execution"),
+        }
+    }
+    fn stop_query_execution(
+        &self,
+        input: StopQueryExecutionInput,
+    ) -> RusotoFuture<StopQueryExecutionOutput, StopQueryExecutionError> {
+        match self.stop_query_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for stop_query_execution"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for untag_resource"),
+        }
+    }
+    fn update_work_group(
+        &self,
+        input: UpdateWorkGroupInput,
+    ) -> RusotoFuture<UpdateWorkGroupOutput, UpdateWorkGroupError> {
+        match self.update_work_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAthena has no handler for update_work_group"),
+        }
+    }
+}
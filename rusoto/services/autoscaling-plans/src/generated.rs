@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Represents an application source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -36,7 +37,26 @@ pub struct ApplicationSource {
     pub tag_filters: Option<Vec<TagFilter>>,
 }
 
+impl Validate for ApplicationSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cloud_formation_stack_arn {
+            errors.check_pattern(
+                &field_path(path, "cloud_formation_stack_arn"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.tag_filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateScalingPlanRequest {
     /// <p>A CloudFormation stack or set of tags. You can create one scaling plan per application source.</p>
     #[serde(rename = "ApplicationSource")]
@@ -49,8 +69,30 @@ pub struct CreateScalingPlanRequest {
     pub scaling_plan_name: String,
 }
 
+impl Validate for CreateScalingPlanRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.application_source
+            .validate_at(&field_path(path, "application_source"), errors);
+        for (i, item) in self.scaling_instructions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "scaling_instructions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "scaling_plan_name"),
+            self.scaling_plan_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "scaling_plan_name"),
+            &self.scaling_plan_name,
+            "[\\p{Print}&&[^|:/]]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateScalingPlanResponse {
     /// <p>The version number of the scaling plan. This value is always 1.</p> <p>Currently, you cannot specify multiple scaling plan versions.</p>
     #[serde(rename = "ScalingPlanVersion")]
@@ -103,7 +145,7 @@ pub struct CustomizedScalingMetricSpecification {
 
 /// <p>Represents a single value in the forecast data used for predictive scaling.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Datapoint {
     /// <p>The time stamp for the data point in UTC format.</p>
     #[serde(rename = "Timestamp")]
@@ -116,6 +158,7 @@ pub struct Datapoint {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteScalingPlanRequest {
     /// <p>The name of the scaling plan.</p>
     #[serde(rename = "ScalingPlanName")]
@@ -125,11 +168,28 @@ pub struct DeleteScalingPlanRequest {
     pub scaling_plan_version: i64,
 }
 
+impl Validate for DeleteScalingPlanRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "scaling_plan_name"),
+            self.scaling_plan_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "scaling_plan_name"),
+            &self.scaling_plan_name,
+            "[\\p{Print}&&[^|:/]]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteScalingPlanResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScalingPlanResourcesRequest {
     /// <p>The maximum number of scalable resources to return. The value must be between 1 and 50. The default value is 50.</p>
     #[serde(rename = "MaxResults")]
@@ -147,8 +207,24 @@ pub struct DescribeScalingPlanResourcesRequest {
     pub scaling_plan_version: i64,
 }
 
+impl Validate for DescribeScalingPlanResourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "scaling_plan_name"),
+            self.scaling_plan_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "scaling_plan_name"),
+            &self.scaling_plan_name,
+            "[\\p{Print}&&[^|:/]]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeScalingPlanResourcesResponse {
     /// <p>The token required to get the next set of results. This value is <code>null</code> if there are no more results to return.</p>
     #[serde(rename = "NextToken")]
@@ -161,6 +237,7 @@ pub struct DescribeScalingPlanResourcesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScalingPlansRequest {
     /// <p>The sources for the applications (up to 10). If you specify scaling plan names, you cannot specify application sources.</p>
     #[serde(rename = "ApplicationSources")]
@@ -184,8 +261,26 @@ pub struct DescribeScalingPlansRequest {
     pub scaling_plan_version: Option<i64>,
 }
 
+impl Validate for DescribeScalingPlansRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_sources {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "application_sources"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.scaling_plan_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "scaling_plan_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, "[\\p{Print}&&[^|:/]]+");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeScalingPlansResponse {
     /// <p>The token required to get the next set of results. This value is <code>null</code> if there are no more results to return.</p>
     #[serde(rename = "NextToken")]
@@ -198,6 +293,7 @@ pub struct DescribeScalingPlansResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetScalingPlanResourceForecastDataRequest {
     /// <p>The exclusive end time of the time range for the forecast data to get. The maximum time duration between the start and end time is seven days. </p> <p>Although this parameter can accept a date and time that is more than two days in the future, the availability of forecast data has limits. AWS Auto Scaling only issues forecasts for periods of two days in advance.</p>
     #[serde(rename = "EndTime")]
@@ -225,8 +321,29 @@ pub struct GetScalingPlanResourceForecastDataRequest {
     pub start_time: f64,
 }
 
+impl Validate for GetScalingPlanResourceForecastDataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "scaling_plan_name"),
+            self.scaling_plan_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "scaling_plan_name"),
+            &self.scaling_plan_name,
+            "[\\p{Print}&&[^|:/]]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetScalingPlanResourceForecastDataResponse {
     /// <p>The data points to return.</p>
     #[serde(rename = "Datapoints")]
@@ -256,6 +373,19 @@ pub struct PredefinedLoadMetricSpecification {
     pub resource_label: Option<String>,
 }
 
+impl Validate for PredefinedLoadMetricSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_label {
+            errors.check_length(
+                &field_path(path, "resource_label"),
+                value.chars().count(),
+                Some(1),
+                Some(1023),
+            );
+        }
+    }
+}
+
 /// <p>Represents a predefined metric that can be used for dynamic scaling as part of a target tracking scaling policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PredefinedScalingMetricSpecification {
@@ -268,6 +398,19 @@ pub struct PredefinedScalingMetricSpecification {
     pub resource_label: Option<String>,
 }
 
+impl Validate for PredefinedScalingMetricSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_label {
+            errors.check_length(
+                &field_path(path, "resource_label"),
+                value.chars().count(),
+                Some(1),
+                Some(1023),
+            );
+        }
+    }
+}
+
 /// <p>Describes a scaling instruction for a scalable resource.</p> <p>The scaling instruction is used in combination with a scaling plan, which is a set of instructions for configuring dynamic scaling and predictive scaling for the scalable resources in your application. Each scaling instruction applies to one resource.</p> <p>AWS Auto Scaling creates target tracking scaling policies based on the scaling instructions. Target tracking scaling policies adjust the capacity of your scalable resource as required to maintain resource utilization at the target value that you specified. </p> <p>AWS Auto Scaling also configures predictive scaling for your Amazon EC2 Auto Scaling groups using a subset of parameters, including the load metric, the scaling metric, the target value for the scaling metric, the predictive scaling mode (forecast and scale or forecast only), and the desired behavior when the forecast capacity exceeds the maximum capacity of the resource. With predictive scaling, AWS Auto Scaling generates forecasts with traffic predictions for the two days ahead and schedules scaling actions that proactively add and remove resource capacity to match the forecast. </p> <p>We recommend waiting a minimum of 24 hours after creating an Auto Scaling group to configure predictive scaling. At minimum, there must be 24 hours of historical data to generate a forecast.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/plans/userguide/auto-scaling-getting-started.html">Getting Started with AWS Auto Scaling</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScalingInstruction {
@@ -323,9 +466,39 @@ pub struct ScalingInstruction {
     pub target_tracking_configurations: Vec<TargetTrackingConfiguration>,
 }
 
+impl Validate for ScalingInstruction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.predefined_load_metric_specification {
+            value.validate_at(
+                &field_path(path, "predefined_load_metric_specification"),
+                errors,
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.target_tracking_configurations.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "target_tracking_configurations"),
+                i
+            );
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Represents a scaling plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScalingPlan {
     /// <p>The application source.</p>
     #[serde(rename = "ApplicationSource")]
@@ -358,7 +531,7 @@ pub struct ScalingPlan {
 
 /// <p>Represents a scalable resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScalingPlanResource {
     /// <p><p>The ID of the resource. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>Auto Scaling group - The resource type is <code>autoScalingGroup</code> and the unique identifier is the name of the Auto Scaling group. Example: <code>autoScalingGroup/my-asg</code>.</p> </li> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
@@ -390,7 +563,7 @@ pub struct ScalingPlanResource {
 
 /// <p>Represents a scaling policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScalingPolicy {
     /// <p>The name of the scaling policy.</p>
     #[serde(rename = "PolicyName")]
@@ -417,6 +590,35 @@ pub struct TagFilter {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for TagFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// <p>Describes a target tracking configuration to use with AWS Auto Scaling. Used with <a>ScalingInstruction</a> and <a>ScalingPolicy</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TargetTrackingConfiguration {
@@ -449,7 +651,19 @@ pub struct TargetTrackingConfiguration {
     pub target_value: f64,
 }
 
+impl Validate for TargetTrackingConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.predefined_scaling_metric_specification {
+            value.validate_at(
+                &field_path(path, "predefined_scaling_metric_specification"),
+                errors,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateScalingPlanRequest {
     /// <p>A CloudFormation stack or set of tags.</p>
     #[serde(rename = "ApplicationSource")]
@@ -467,8 +681,33 @@ pub struct UpdateScalingPlanRequest {
     pub scaling_plan_version: i64,
 }
 
+impl Validate for UpdateScalingPlanRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_source {
+            value.validate_at(&field_path(path, "application_source"), errors);
+        }
+        if let Some(ref value) = self.scaling_instructions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "scaling_instructions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "scaling_plan_name"),
+            self.scaling_plan_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "scaling_plan_name"),
+            &self.scaling_plan_name,
+            "[\\p{Print}&&[^|:/]]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateScalingPlanResponse {}
 
 /// Errors returned by CreateScalingPlan
@@ -495,7 +734,12 @@ impl CreateScalingPlanError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateScalingPlanError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -540,7 +784,12 @@ impl DeleteScalingPlanError {
                 "ObjectNotFoundException" => {
                     return RusotoError::Service(DeleteScalingPlanError::ObjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -593,7 +842,12 @@ impl DescribeScalingPlanResourcesError {
                         DescribeScalingPlanResourcesError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -644,7 +898,12 @@ impl DescribeScalingPlansError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -683,7 +942,12 @@ impl GetScalingPlanResourceForecastDataError {
                         GetScalingPlanResourceForecastDataError::InternalService(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -726,7 +990,12 @@ impl UpdateScalingPlanError {
                 "ObjectNotFoundException" => {
                     return RusotoError::Service(UpdateScalingPlanError::ObjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -788,6 +1057,130 @@ pub trait AutoscalingPlans {
         input: UpdateScalingPlanRequest,
     ) -> RusotoFuture<UpdateScalingPlanResponse, UpdateScalingPlanError>;
 }
+const _: Option<&dyn AutoscalingPlans> = None;
+impl<'a, T: AutoscalingPlans + ?Sized> AutoscalingPlans for &'a T {
+    fn create_scaling_plan(
+        &self,
+        input: CreateScalingPlanRequest,
+    ) -> RusotoFuture<CreateScalingPlanResponse, CreateScalingPlanError> {
+        (**self).create_scaling_plan(input)
+    }
+    fn delete_scaling_plan(
+        &self,
+        input: DeleteScalingPlanRequest,
+    ) -> RusotoFuture<DeleteScalingPlanResponse, DeleteScalingPlanError> {
+        (**self).delete_scaling_plan(input)
+    }
+    fn describe_scaling_plan_resources(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> RusotoFuture<DescribeScalingPlanResourcesResponse, DescribeScalingPlanResourcesError> {
+        (**self).describe_scaling_plan_resources(input)
+    }
+    fn describe_scaling_plans(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> RusotoFuture<DescribeScalingPlansResponse, DescribeScalingPlansError> {
+        (**self).describe_scaling_plans(input)
+    }
+    fn get_scaling_plan_resource_forecast_data(
+        &self,
+        input: GetScalingPlanResourceForecastDataRequest,
+    ) -> RusotoFuture<
+        GetScalingPlanResourceForecastDataResponse,
+        GetScalingPlanResourceForecastDataError,
+    > {
+        (**self).get_scaling_plan_resource_forecast_data(input)
+    }
+    fn update_scaling_plan(
+        &self,
+        input: UpdateScalingPlanRequest,
+    ) -> RusotoFuture<UpdateScalingPlanResponse, UpdateScalingPlanError> {
+        (**self).update_scaling_plan(input)
+    }
+}
+impl<T: AutoscalingPlans + ?Sized> AutoscalingPlans for Box<T> {
+    fn create_scaling_plan(
+        &self,
+        input: CreateScalingPlanRequest,
+    ) -> RusotoFuture<CreateScalingPlanResponse, CreateScalingPlanError> {
+        (**self).create_scaling_plan(input)
+    }
+    fn delete_scaling_plan(
+        &self,
+        input: DeleteScalingPlanRequest,
+    ) -> RusotoFuture<DeleteScalingPlanResponse, DeleteScalingPlanError> {
+        (**self).delete_scaling_plan(input)
+    }
+    fn describe_scaling_plan_resources(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> RusotoFuture<DescribeScalingPlanResourcesResponse, DescribeScalingPlanResourcesError> {
+        (**self).describe_scaling_plan_resources(input)
+    }
+    fn describe_scaling_plans(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> RusotoFuture<DescribeScalingPlansResponse, DescribeScalingPlansError> {
+        (**self).describe_scaling_plans(input)
+    }
+    fn get_scaling_plan_resource_forecast_data(
+        &self,
+        input: GetScalingPlanResourceForecastDataRequest,
+    ) -> RusotoFuture<
+        GetScalingPlanResourceForecastDataResponse,
+        GetScalingPlanResourceForecastDataError,
+    > {
+        (**self).get_scaling_plan_resource_forecast_data(input)
+    }
+    fn update_scaling_plan(
+        &self,
+        input: UpdateScalingPlanRequest,
+    ) -> RusotoFuture<UpdateScalingPlanResponse, UpdateScalingPlanError> {
+        (**self).update_scaling_plan(input)
+    }
+}
+impl<T: AutoscalingPlans + ?Sized> AutoscalingPlans for ::std::sync::Arc<T> {
+    fn create_scaling_plan(
+        &self,
+        input: CreateScalingPlanRequest,
+    ) -> RusotoFuture<CreateScalingPlanResponse, CreateScalingPlanError> {
+        (**self).create_scaling_plan(input)
+    }
+    fn delete_scaling_plan(
+        &self,
+        input: DeleteScalingPlanRequest,
+    ) -> RusotoFuture<DeleteScalingPlanResponse, DeleteScalingPlanError> {
+        (**self).delete_scaling_plan(input)
+    }
+    fn describe_scaling_plan_resources(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> RusotoFuture<DescribeScalingPlanResourcesResponse, DescribeScalingPlanResourcesError> {
+        (**self).describe_scaling_plan_resources(input)
+    }
+    fn describe_scaling_plans(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> RusotoFuture<DescribeScalingPlansResponse, DescribeScalingPlansError> {
+        (**self).describe_scaling_plans(input)
+    }
+    fn get_scaling_plan_resource_forecast_data(
+        &self,
+        input: GetScalingPlanResourceForecastDataRequest,
+    ) -> RusotoFuture<
+        GetScalingPlanResourceForecastDataResponse,
+        GetScalingPlanResourceForecastDataError,
+    > {
+        (**self).get_scaling_plan_resource_forecast_data(input)
+    }
+    fn update_scaling_plan(
+        &self,
+        input: UpdateScalingPlanRequest,
+    ) -> RusotoFuture<UpdateScalingPlanResponse, UpdateScalingPlanError> {
+        (**self).update_scaling_plan(input)
+    }
+}
 /// A client for the AWS Auto Scaling Plans API.
 #[derive(Clone)]
 pub struct AutoscalingPlansClient {
@@ -831,7 +1224,16 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         &self,
         input: CreateScalingPlanRequest,
     ) -> RusotoFuture<CreateScalingPlanResponse, CreateScalingPlanError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("CreateScalingPlan");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -843,10 +1245,10 @@ impl AutoscalingPlans for AutoscalingPlansClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateScalingPlanResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateScalingPlanResponse, _>(
+                    response,
+                    "CreateScalingPlan",
+                )
             } else {
                 Box::new(
                     response
@@ -863,7 +1265,16 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         &self,
         input: DeleteScalingPlanRequest,
     ) -> RusotoFuture<DeleteScalingPlanResponse, DeleteScalingPlanError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("DeleteScalingPlan");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -875,10 +1286,10 @@ impl AutoscalingPlans for AutoscalingPlansClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteScalingPlanResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteScalingPlanResponse, _>(
+                    response,
+                    "DeleteScalingPlan",
+                )
             } else {
                 Box::new(
                     response
@@ -895,7 +1306,16 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         &self,
         input: DescribeScalingPlanResourcesRequest,
     ) -> RusotoFuture<DescribeScalingPlanResourcesResponse, DescribeScalingPlanResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("DescribeScalingPlanResources");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -907,10 +1327,10 @@ impl AutoscalingPlans for AutoscalingPlansClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeScalingPlanResourcesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeScalingPlanResourcesResponse, _>(
+                    response,
+                    "DescribeScalingPlanResources",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeScalingPlanResourcesError::from_response(response))
@@ -924,7 +1344,16 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         &self,
         input: DescribeScalingPlansRequest,
     ) -> RusotoFuture<DescribeScalingPlansResponse, DescribeScalingPlansError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("DescribeScalingPlans");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -936,10 +1365,10 @@ impl AutoscalingPlans for AutoscalingPlansClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeScalingPlansResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeScalingPlansResponse, _>(
+                    response,
+                    "DescribeScalingPlans",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -958,7 +1387,16 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         GetScalingPlanResourceForecastDataResponse,
         GetScalingPlanResourceForecastDataError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("GetScalingPlanResourceForecastData");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -970,10 +1408,10 @@ impl AutoscalingPlans for AutoscalingPlansClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetScalingPlanResourceForecastDataResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetScalingPlanResourceForecastDataResponse, _>(
+                    response,
+                    "GetScalingPlanResourceForecastData",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetScalingPlanResourceForecastDataError::from_response(
@@ -989,7 +1427,16 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         &self,
         input: UpdateScalingPlanRequest,
     ) -> RusotoFuture<UpdateScalingPlanResponse, UpdateScalingPlanError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("UpdateScalingPlan");
         request.set_endpoint_prefix("autoscaling".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1001,10 +1448,10 @@ impl AutoscalingPlans for AutoscalingPlansClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateScalingPlanResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateScalingPlanResponse, _>(
+                    response,
+                    "UpdateScalingPlan",
+                )
             } else {
                 Box::new(
                     response
@@ -1016,3 +1463,245 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         })
     }
 }
+
+/// A mock of the AWS Auto Scaling Plans API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAutoscalingPlans {
+    create_scaling_plan: Option<
+        Box<
+            dyn Fn(
+                    CreateScalingPlanRequest,
+                ) -> ::std::result::Result<
+                    CreateScalingPlanResponse,
+                    RusotoError<CreateScalingPlanError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_scaling_plan: Option<
+        Box<
+            dyn Fn(
+                    DeleteScalingPlanRequest,
+                ) -> ::std::result::Result<
+                    DeleteScalingPlanResponse,
+                    RusotoError<DeleteScalingPlanError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_scaling_plan_resources: Option<
+        Box<
+            dyn Fn(
+                    DescribeScalingPlanResourcesRequest,
+                ) -> ::std::result::Result<
+                    DescribeScalingPlanResourcesResponse,
+                    RusotoError<DescribeScalingPlanResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_scaling_plans: Option<
+        Box<
+            dyn Fn(
+                    DescribeScalingPlansRequest,
+                ) -> ::std::result::Result<
+                    DescribeScalingPlansResponse,
+                    RusotoError<DescribeScalingPlansError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_scaling_plan_resource_forecast_data: Option<
+        Box<
+            dyn Fn(
+                    GetScalingPlanResourceForecastDataRequest,
+                ) -> ::std::result::Result<
+                    GetScalingPlanResourceForecastDataResponse,
+                    RusotoError<GetScalingPlanResourceForecastDataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_scaling_plan: Option<
+        Box<
+            dyn Fn(
+                    UpdateScalingPlanRequest,
+                ) -> ::std::result::Result<
+                    UpdateScalingPlanResponse,
+                    RusotoError<UpdateScalingPlanError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAutoscalingPlans {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAutoscalingPlans {
+        Default::default()
+    }
+
+    /// Answers `create_scaling_plan` with what `handler` returns for its input.
+    pub fn with_create_scaling_plan<F>(mut self, handler: F) -> MockAutoscalingPlans
+    where
+        F: Fn(
+                CreateScalingPlanRequest,
+            ) -> ::std::result::Result<
+                CreateScalingPlanResponse,
+                RusotoError<CreateScalingPlanError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_scaling_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_scaling_plan` with what `handler` returns for its input.
+    pub fn with_delete_scaling_plan<F>(mut self, handler: F) -> MockAutoscalingPlans
+    where
+        F: Fn(
+                DeleteScalingPlanRequest,
+            ) -> ::std::result::Result<
+                DeleteScalingPlanResponse,
+                RusotoError<DeleteScalingPlanError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_scaling_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scaling_plan_resources` with what `handler` returns for its input.
+    pub fn with_describe_scaling_plan_resources<F>(mut self, handler: F) -> MockAutoscalingPlans
+    where
+        F: Fn(
+                DescribeScalingPlanResourcesRequest,
+            ) -> ::std::result::Result<
+                DescribeScalingPlanResourcesResponse,
+                RusotoError<DescribeScalingPlanResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scaling_plan_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scaling_plans` with what `handler` returns for its input.
+    pub fn with_describe_scaling_plans<F>(mut self, handler: F) -> MockAutoscalingPlans
+    where
+        F: Fn(
+                DescribeScalingPlansRequest,
+            ) -> ::std::result::Result<
+                DescribeScalingPlansResponse,
+                RusotoError<DescribeScalingPlansError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scaling_plans = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_scaling_plan_resource_forecast_data` with what `handler` returns for its input.
+    pub fn with_get_scaling_plan_resource_forecast_data<F>(
+        mut self,
+        handler: F,
+    ) -> MockAutoscalingPlans
+    where
+        F: Fn(
+                GetScalingPlanResourceForecastDataRequest,
+            ) -> ::std::result::Result<
+                GetScalingPlanResourceForecastDataResponse,
+                RusotoError<GetScalingPlanResourceForecastDataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_scaling_plan_resource_forecast_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_scaling_plan` with what `handler` returns for its input.
+    pub fn with_update_scaling_plan<F>(mut self, handler: F) -> MockAutoscalingPlans
+    where
+        F: Fn(
+                UpdateScalingPlanRequest,
+            ) -> ::std::result::Result<
+                UpdateScalingPlanResponse,
+                RusotoError<UpdateScalingPlanError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_scaling_plan = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl AutoscalingPlans for MockAutoscalingPlans {
+    fn create_scaling_plan(
+        &self,
+        input: CreateScalingPlanRequest,
+    ) -> RusotoFuture<CreateScalingPlanResponse, CreateScalingPlanError> {
+        match self.create_scaling_plan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscalingPlans has no handler for create_scaling_plan"),
+        }
+    }
+    fn delete_scaling_plan(
+        &self,
+        input: DeleteScalingPlanRequest,
+    ) -> RusotoFuture<DeleteScalingPlanResponse, DeleteScalingPlanError> {
+        match self.delete_scaling_plan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscalingPlans has no handler for delete_scaling_plan"),
+        }
+    }
+    fn describe_scaling_plan_resources(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> RusotoFuture<DescribeScalingPlanResourcesResponse, DescribeScalingPlanResourcesError> {
+        match self.describe_scaling_plan_resources {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAutoscalingPlans has no handler for describe_scaling_plan_resources")
+            }
+        }
+    }
+    fn describe_scaling_plans(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> RusotoFuture<DescribeScalingPlansResponse, DescribeScalingPlansError> {
+        match self.describe_scaling_plans {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscalingPlans has no handler for describe_scaling_plans"),
+        }
+    }
+    fn get_scaling_plan_resource_forecast_data(
+        &self,
+        input: GetScalingPlanResourceForecastDataRequest,
+    ) -> RusotoFuture<
+        GetScalingPlanResourceForecastDataResponse,
+        GetScalingPlanResourceForecastDataError,
+    > {
+        match self.get_scaling_plan_resource_forecast_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockAutoscalingPlans has no handler for get_scaling_plan_resource_forecast_data"
+            ),
+        }
+    }
+    fn update_scaling_plan(
+        &self,
+        input: UpdateScalingPlanRequest,
+    ) -> RusotoFuture<UpdateScalingPlanResponse, UpdateScalingPlanError> {
+        match self.update_scaling_plan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscalingPlans has no handler for update_scaling_plan"),
+        }
+    }
+}
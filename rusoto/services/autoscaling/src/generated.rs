@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -51,6 +52,8 @@ impl ActivitiesDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ActivitiesType {
     /// <p>The scaling activities. Activities are sorted by start time. Activities still in progress are described first.</p>
     pub activities: Vec<Activity>,
@@ -82,6 +85,8 @@ impl ActivitiesTypeDeserializer {
 }
 /// <p>Describes scaling activity, which is a long-running process that represents a change to your Auto Scaling group, such as changing its size or replacing an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Activity {
     /// <p>The ID of the activity.</p>
     pub activity_id: String,
@@ -169,6 +174,8 @@ impl ActivityIdsSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ActivityType {
     /// <p>A scaling activity.</p>
     pub activity: Option<Activity>,
@@ -194,6 +201,8 @@ impl ActivityTypeDeserializer {
 }
 /// <p>Describes a policy adjustment type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdjustmentType {
     /// <p>The policy adjustment type. The valid values are <code>ChangeInCapacity</code>, <code>ExactCapacity</code>, and <code>PercentChangeInCapacity</code>.</p>
     pub adjustment_type: Option<String>,
@@ -239,6 +248,8 @@ impl AdjustmentTypesDeserializer {
 }
 /// <p>Describes an alarm.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Alarm {
     /// <p>The Amazon Resource Name (ARN) of the alarm.</p>
     pub alarm_arn: Option<String>,
@@ -300,13 +311,15 @@ impl AssociatePublicIpAddressDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachInstancesQuery {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -314,6 +327,33 @@ pub struct AttachInstancesQuery {
     pub instance_ids: Option<Vec<String>>,
 }
 
+impl Validate for AttachInstancesQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.instance_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(19));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// Serialize `AttachInstancesQuery` contents to a `SignedRequest`.
 struct AttachInstancesQuerySerializer;
 impl AttachInstancesQuerySerializer {
@@ -338,6 +378,8 @@ impl AttachInstancesQuerySerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachLoadBalancerTargetGroupsResultType {}
 
 struct AttachLoadBalancerTargetGroupsResultTypeDeserializer;
@@ -357,6 +399,8 @@ impl AttachLoadBalancerTargetGroupsResultTypeDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachLoadBalancerTargetGroupsType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -364,6 +408,31 @@ pub struct AttachLoadBalancerTargetGroupsType {
     pub target_group_ar_ns: Vec<String>,
 }
 
+impl Validate for AttachLoadBalancerTargetGroupsType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.target_group_ar_ns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "target_group_ar_ns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(511));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `AttachLoadBalancerTargetGroupsType` contents to a `SignedRequest`.
 struct AttachLoadBalancerTargetGroupsTypeSerializer;
 impl AttachLoadBalancerTargetGroupsTypeSerializer {
@@ -386,6 +455,8 @@ impl AttachLoadBalancerTargetGroupsTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachLoadBalancersResultType {}
 
 struct AttachLoadBalancersResultTypeDeserializer;
@@ -405,6 +476,8 @@ impl AttachLoadBalancersResultTypeDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachLoadBalancersType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -412,6 +485,31 @@ pub struct AttachLoadBalancersType {
     pub load_balancer_names: Vec<String>,
 }
 
+impl Validate for AttachLoadBalancersType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.load_balancer_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "load_balancer_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `AttachLoadBalancersType` contents to a `SignedRequest`.
 struct AttachLoadBalancersTypeSerializer;
 impl AttachLoadBalancersTypeSerializer {
@@ -435,6 +533,8 @@ impl AttachLoadBalancersTypeSerializer {
 
 /// <p>Describes an Auto Scaling group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutoScalingGroup {
     /// <p>The Amazon Resource Name (ARN) of the Auto Scaling group.</p>
     pub auto_scaling_group_arn: Option<String>,
@@ -645,7 +745,7 @@ impl AutoScalingGroupDesiredCapacityDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -656,7 +756,7 @@ impl AutoScalingGroupMaxSizeDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -667,7 +767,7 @@ impl AutoScalingGroupMinSizeDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -686,6 +786,8 @@ impl AutoScalingGroupNamesSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutoScalingGroupNamesType {
     /// <p>The names of the Auto Scaling groups. Each name can be a maximum of 1600 characters. By default, you can only specify up to 50 names. You can optionally increase this limit using the <code>MaxRecords</code> parameter. </p> <p>If you omit this parameter, all Auto Scaling groups are described.</p>
     pub auto_scaling_group_names: Option<Vec<String>>,
@@ -695,6 +797,29 @@ pub struct AutoScalingGroupNamesType {
     pub next_token: Option<String>,
 }
 
+impl Validate for AutoScalingGroupNamesType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.auto_scaling_group_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "auto_scaling_group_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `AutoScalingGroupNamesType` contents to a `SignedRequest`.
 struct AutoScalingGroupNamesTypeSerializer;
 impl AutoScalingGroupNamesTypeSerializer {
@@ -738,6 +863,8 @@ impl AutoScalingGroupsDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutoScalingGroupsType {
     /// <p>The groups.</p>
     pub auto_scaling_groups: Vec<AutoScalingGroup>,
@@ -772,6 +899,8 @@ impl AutoScalingGroupsTypeDeserializer {
 }
 /// <p>Describes an EC2 instance associated with an Auto Scaling group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutoScalingInstanceDetails {
     /// <p>The name of the Auto Scaling group for the instance.</p>
     pub auto_scaling_group_name: String,
@@ -872,6 +1001,8 @@ impl AutoScalingInstancesDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutoScalingInstancesType {
     /// <p>The instances.</p>
     pub auto_scaling_instances: Option<Vec<AutoScalingInstanceDetails>>,
@@ -973,6 +1104,8 @@ impl AvailabilityZonesSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteScheduledActionAnswer {
     /// <p>The names of the scheduled actions that could not be deleted, including an error message. </p>
     pub failed_scheduled_actions: Option<Vec<FailedScheduledUpdateGroupActionRequest>>,
@@ -1006,6 +1139,8 @@ impl BatchDeleteScheduledActionAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteScheduledActionType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -1013,6 +1148,31 @@ pub struct BatchDeleteScheduledActionType {
     pub scheduled_action_names: Vec<String>,
 }
 
+impl Validate for BatchDeleteScheduledActionType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.scheduled_action_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "scheduled_action_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `BatchDeleteScheduledActionType` contents to a `SignedRequest`.
 struct BatchDeleteScheduledActionTypeSerializer;
 impl BatchDeleteScheduledActionTypeSerializer {
@@ -1035,6 +1195,8 @@ impl BatchDeleteScheduledActionTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchPutScheduledUpdateGroupActionAnswer {
     /// <p>The names of the scheduled actions that could not be created or updated, including an error message.</p>
     pub failed_scheduled_update_group_actions: Option<Vec<FailedScheduledUpdateGroupActionRequest>>,
@@ -1070,6 +1232,8 @@ impl BatchPutScheduledUpdateGroupActionAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchPutScheduledUpdateGroupActionType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -1077,6 +1241,30 @@ pub struct BatchPutScheduledUpdateGroupActionType {
     pub scheduled_update_group_actions: Vec<ScheduledUpdateGroupActionRequest>,
 }
 
+impl Validate for BatchPutScheduledUpdateGroupActionType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.scheduled_update_group_actions.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "scheduled_update_group_actions"),
+                i
+            );
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `BatchPutScheduledUpdateGroupActionType` contents to a `SignedRequest`.
 struct BatchPutScheduledUpdateGroupActionTypeSerializer;
 impl BatchPutScheduledUpdateGroupActionTypeSerializer {
@@ -1103,7 +1291,7 @@ impl BlockDeviceEbsDeleteOnTerminationDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1114,7 +1302,7 @@ impl BlockDeviceEbsEncryptedDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1125,7 +1313,7 @@ impl BlockDeviceEbsIopsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1136,7 +1324,7 @@ impl BlockDeviceEbsVolumeSizeDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1155,6 +1343,8 @@ impl BlockDeviceEbsVolumeTypeDeserializer {
 }
 /// <p>Describes a block device mapping.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BlockDeviceMapping {
     /// <p>The device name exposed to the EC2 instance (for example, <code>/dev/sdh</code> or <code>xvdh</code>). For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/device_naming.html">Device Naming on Linux Instances</a> in the <i>Amazon EC2 User Guide for Linux Instances</i>.</p>
     pub device_name: String,
@@ -1166,6 +1356,38 @@ pub struct BlockDeviceMapping {
     pub virtual_name: Option<String>,
 }
 
+impl Validate for BlockDeviceMapping {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "device_name"),
+            self.device_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_name"),
+            &self.device_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.ebs {
+            value.validate_at(&field_path(path, "ebs"), errors);
+        }
+        if let Some(ref value) = self.virtual_name {
+            errors.check_length(
+                &field_path(path, "virtual_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "virtual_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 struct BlockDeviceMappingDeserializer;
 impl BlockDeviceMappingDeserializer {
     #[allow(unused_variables)]
@@ -1283,6 +1505,8 @@ impl ClassicLinkVPCSecurityGroupsSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompleteLifecycleActionAnswer {}
 
 struct CompleteLifecycleActionAnswerDeserializer;
@@ -1302,6 +1526,8 @@ impl CompleteLifecycleActionAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompleteLifecycleActionType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -1315,6 +1541,54 @@ pub struct CompleteLifecycleActionType {
     pub lifecycle_hook_name: String,
 }
 
+impl Validate for CompleteLifecycleActionType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.instance_id {
+            errors.check_length(
+                &field_path(path, "instance_id"),
+                value.chars().count(),
+                Some(1),
+                Some(19),
+            );
+            errors.check_pattern(
+                &field_path(path, "instance_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.lifecycle_action_token {
+            errors.check_length(
+                &field_path(path, "lifecycle_action_token"),
+                value.chars().count(),
+                Some(36),
+                Some(36),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "lifecycle_hook_name"),
+            self.lifecycle_hook_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "lifecycle_hook_name"),
+            &self.lifecycle_hook_name,
+            "[A-Za-z0-9\\-_\\/]+",
+        );
+    }
+}
+
 /// Serialize `CompleteLifecycleActionType` contents to a `SignedRequest`.
 struct CompleteLifecycleActionTypeSerializer;
 impl CompleteLifecycleActionTypeSerializer {
@@ -1353,13 +1627,15 @@ impl CooldownDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAutoScalingGroupType {
     /// <p>The name of the Auto Scaling group. This name must be unique per Region per account.</p>
     pub auto_scaling_group_name: String,
@@ -1405,6 +1681,166 @@ pub struct CreateAutoScalingGroupType {
     pub vpc_zone_identifier: Option<String>,
 }
 
+impl Validate for CreateAutoScalingGroupType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.availability_zones {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "availability_zones"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.health_check_type {
+            errors.check_length(
+                &field_path(path, "health_check_type"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+            errors.check_pattern(
+                &field_path(path, "health_check_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.instance_id {
+            errors.check_length(
+                &field_path(path, "instance_id"),
+                value.chars().count(),
+                Some(1),
+                Some(19),
+            );
+            errors.check_pattern(
+                &field_path(path, "instance_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.launch_configuration_name {
+            errors.check_length(
+                &field_path(path, "launch_configuration_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "launch_configuration_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.launch_template {
+            value.validate_at(&field_path(path, "launch_template"), errors);
+        }
+        if let Some(ref value) = self.lifecycle_hook_specification_list {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "lifecycle_hook_specification_list"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.load_balancer_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "load_balancer_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.mixed_instances_policy {
+            value.validate_at(&field_path(path, "mixed_instances_policy"), errors);
+        }
+        if let Some(ref value) = self.placement_group {
+            errors.check_length(
+                &field_path(path, "placement_group"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "placement_group"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.service_linked_role_arn {
+            errors.check_length(
+                &field_path(path, "service_linked_role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(
+                &field_path(path, "service_linked_role_arn"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.target_group_ar_ns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "target_group_ar_ns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(511));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.termination_policies {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "termination_policies"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1600));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.vpc_zone_identifier {
+            errors.check_length(
+                &field_path(path, "vpc_zone_identifier"),
+                value.chars().count(),
+                Some(1),
+                Some(2047),
+            );
+            errors.check_pattern(
+                &field_path(path, "vpc_zone_identifier"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `CreateAutoScalingGroupType` contents to a `SignedRequest`.
 struct CreateAutoScalingGroupTypeSerializer;
 impl CreateAutoScalingGroupTypeSerializer {
@@ -1518,6 +1954,8 @@ impl CreateAutoScalingGroupTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLaunchConfigurationType {
     /// <p><p>For Auto Scaling groups that are running in a virtual private cloud (VPC), specifies whether to assign a public IP address to the group&#39;s instances. If you specify <code>true</code>, each instance in the Auto Scaling group receives a unique public IP address. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/asg-in-vpc.html">Launching Auto Scaling Instances in a VPC</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>If you specify this parameter, you must specify at least one subnet for <code>VPCZoneIdentifier</code> when you create your group.</p> <note> <p>If the instance is launched into a default subnet, the default is to assign a public IP address, unless you disabled the option to assign a public IP address on the subnet. If the instance is launched into a nondefault subnet, the default is not to assign a public IP address, unless you enabled the option to assign a public IP address on the subnet.</p> </note></p>
     pub associate_public_ip_address: Option<bool>,
@@ -1557,6 +1995,191 @@ pub struct CreateLaunchConfigurationType {
     pub user_data: Option<String>,
 }
 
+impl Validate for CreateLaunchConfigurationType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.block_device_mappings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "block_device_mappings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.classic_link_vpc_id {
+            errors.check_length(
+                &field_path(path, "classic_link_vpc_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "classic_link_vpc_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.classic_link_vpc_security_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "classic_link_vpc_security_groups"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.iam_instance_profile {
+            errors.check_length(
+                &field_path(path, "iam_instance_profile"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(
+                &field_path(path, "iam_instance_profile"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.image_id {
+            errors.check_length(
+                &field_path(path, "image_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "image_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.instance_id {
+            errors.check_length(
+                &field_path(path, "instance_id"),
+                value.chars().count(),
+                Some(1),
+                Some(19),
+            );
+            errors.check_pattern(
+                &field_path(path, "instance_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.instance_type {
+            errors.check_length(
+                &field_path(path, "instance_type"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "instance_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.kernel_id {
+            errors.check_length(
+                &field_path(path, "kernel_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "kernel_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.key_name {
+            errors.check_length(
+                &field_path(path, "key_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "key_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "launch_configuration_name"),
+            self.launch_configuration_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "launch_configuration_name"),
+            &self.launch_configuration_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.placement_tenancy {
+            errors.check_length(
+                &field_path(path, "placement_tenancy"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "placement_tenancy"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.ramdisk_id {
+            errors.check_length(
+                &field_path(path, "ramdisk_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "ramdisk_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.security_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "security_groups"), i);
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.spot_price {
+            errors.check_length(
+                &field_path(path, "spot_price"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.user_data {
+            errors.check_length(
+                &field_path(path, "user_data"),
+                value.chars().count(),
+                None,
+                Some(21847),
+            );
+            errors.check_pattern(
+                &field_path(path, "user_data"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `CreateLaunchConfigurationType` contents to a `SignedRequest`.
 struct CreateLaunchConfigurationTypeSerializer;
 impl CreateLaunchConfigurationTypeSerializer {
@@ -1644,11 +2267,22 @@ impl CreateLaunchConfigurationTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateOrUpdateTagsType {
     /// <p>One or more tags.</p>
     pub tags: Vec<Tag>,
 }
 
+impl Validate for CreateOrUpdateTagsType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `CreateOrUpdateTagsType` contents to a `SignedRequest`.
 struct CreateOrUpdateTagsTypeSerializer;
 impl CreateOrUpdateTagsTypeSerializer {
@@ -1664,6 +2298,8 @@ impl CreateOrUpdateTagsTypeSerializer {
 
 /// <p>Represents a CloudWatch metric of your choosing for a target tracking scaling policy to use with Amazon EC2 Auto Scaling.</p> <p>To create your customized metric specification:</p> <ul> <li> <p>Add values for each required parameter from CloudWatch. You can use an existing metric, or a new metric that you create. To use your own metric, you must first publish the metric to CloudWatch. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publish Custom Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> </li> <li> <p>Choose a metric that changes proportionally with capacity. The value of the metric should increase or decrease in inverse proportion to the number of capacity units. That is, the value of the metric should decrease when capacity increases. </p> </li> </ul> <p>For more information about CloudWatch, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/cloudwatch_concepts.html">Amazon CloudWatch Concepts</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CustomizedMetricSpecification {
     /// <p>The dimensions of the metric.</p> <p>Conditional: If you published your metric with dimensions, you must specify the same dimensions in your scaling policy.</p>
     pub dimensions: Option<Vec<MetricDimension>>,
@@ -1742,6 +2378,8 @@ impl CustomizedMetricSpecificationSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAutoScalingGroupType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -1749,6 +2387,22 @@ pub struct DeleteAutoScalingGroupType {
     pub force_delete: Option<bool>,
 }
 
+impl Validate for DeleteAutoScalingGroupType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `DeleteAutoScalingGroupType` contents to a `SignedRequest`.
 struct DeleteAutoScalingGroupTypeSerializer;
 impl DeleteAutoScalingGroupTypeSerializer {
@@ -1769,6 +2423,8 @@ impl DeleteAutoScalingGroupTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLifecycleHookAnswer {}
 
 struct DeleteLifecycleHookAnswerDeserializer;
@@ -1788,6 +2444,8 @@ impl DeleteLifecycleHookAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLifecycleHookType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -1795,6 +2453,33 @@ pub struct DeleteLifecycleHookType {
     pub lifecycle_hook_name: String,
 }
 
+impl Validate for DeleteLifecycleHookType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "lifecycle_hook_name"),
+            self.lifecycle_hook_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "lifecycle_hook_name"),
+            &self.lifecycle_hook_name,
+            "[A-Za-z0-9\\-_\\/]+",
+        );
+    }
+}
+
 /// Serialize `DeleteLifecycleHookType` contents to a `SignedRequest`.
 struct DeleteLifecycleHookTypeSerializer;
 impl DeleteLifecycleHookTypeSerializer {
@@ -1816,6 +2501,8 @@ impl DeleteLifecycleHookTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteNotificationConfigurationType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -1823,6 +2510,33 @@ pub struct DeleteNotificationConfigurationType {
     pub topic_arn: String,
 }
 
+impl Validate for DeleteNotificationConfigurationType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "topic_arn"),
+            self.topic_arn.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "topic_arn"),
+            &self.topic_arn,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `DeleteNotificationConfigurationType` contents to a `SignedRequest`.
 struct DeleteNotificationConfigurationTypeSerializer;
 impl DeleteNotificationConfigurationTypeSerializer {
@@ -1841,6 +2555,8 @@ impl DeleteNotificationConfigurationTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePolicyType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: Option<String>,
@@ -1848,6 +2564,35 @@ pub struct DeletePolicyType {
     pub policy_name: String,
 }
 
+impl Validate for DeletePolicyType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.auto_scaling_group_name {
+            errors.check_length(
+                &field_path(path, "auto_scaling_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "auto_scaling_group_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "policy_name"),
+            self.policy_name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_name"),
+            &self.policy_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `DeletePolicyType` contents to a `SignedRequest`.
 struct DeletePolicyTypeSerializer;
 impl DeletePolicyTypeSerializer {
@@ -1868,6 +2613,8 @@ impl DeletePolicyTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteScheduledActionType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -1875,6 +2622,33 @@ pub struct DeleteScheduledActionType {
     pub scheduled_action_name: String,
 }
 
+impl Validate for DeleteScheduledActionType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "scheduled_action_name"),
+            self.scheduled_action_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "scheduled_action_name"),
+            &self.scheduled_action_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `DeleteScheduledActionType` contents to a `SignedRequest`.
 struct DeleteScheduledActionTypeSerializer;
 impl DeleteScheduledActionTypeSerializer {
@@ -1896,11 +2670,22 @@ impl DeleteScheduledActionTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagsType {
     /// <p>One or more tags.</p>
     pub tags: Vec<Tag>,
 }
 
+impl Validate for DeleteTagsType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `DeleteTagsType` contents to a `SignedRequest`.
 struct DeleteTagsTypeSerializer;
 impl DeleteTagsTypeSerializer {
@@ -1915,6 +2700,8 @@ impl DeleteTagsTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountLimitsAnswer {
     /// <p>The maximum number of groups allowed for your AWS account. The default limit is 200 per AWS Region.</p>
     pub max_number_of_auto_scaling_groups: Option<i64>,
@@ -1974,6 +2761,8 @@ impl DescribeAccountLimitsAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAdjustmentTypesAnswer {
     /// <p>The policy adjustment types.</p>
     pub adjustment_types: Option<Vec<AdjustmentType>>,
@@ -2004,6 +2793,8 @@ impl DescribeAdjustmentTypesAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAutoScalingInstancesType {
     /// <p>The IDs of the instances. You can specify up to <code>MaxRecords</code> IDs. If you omit this parameter, all Auto Scaling instances are described. If you specify an ID that does not exist, it is ignored with no error.</p>
     pub instance_ids: Option<Vec<String>>,
@@ -2013,6 +2804,29 @@ pub struct DescribeAutoScalingInstancesType {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAutoScalingInstancesType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.instance_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(19));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeAutoScalingInstancesType` contents to a `SignedRequest`.
 struct DescribeAutoScalingInstancesTypeSerializer;
 impl DescribeAutoScalingInstancesTypeSerializer {
@@ -2039,6 +2853,8 @@ impl DescribeAutoScalingInstancesTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAutoScalingNotificationTypesAnswer {
     /// <p>The notification types.</p>
     pub auto_scaling_notification_types: Option<Vec<String>>,
@@ -2072,6 +2888,8 @@ impl DescribeAutoScalingNotificationTypesAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLifecycleHookTypesAnswer {
     /// <p>The lifecycle hook types.</p>
     pub lifecycle_hook_types: Option<Vec<String>>,
@@ -2105,6 +2923,8 @@ impl DescribeLifecycleHookTypesAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLifecycleHooksAnswer {
     /// <p>The lifecycle hooks for the specified group.</p>
     pub lifecycle_hooks: Option<Vec<LifecycleHook>>,
@@ -2135,6 +2955,8 @@ impl DescribeLifecycleHooksAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLifecycleHooksType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -2142,6 +2964,35 @@ pub struct DescribeLifecycleHooksType {
     pub lifecycle_hook_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeLifecycleHooksType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.lifecycle_hook_names {
+            errors.check_length(
+                &field_path(path, "lifecycle_hook_names"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "lifecycle_hook_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(&item_path, &item, "[A-Za-z0-9\\-_\\/]+");
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeLifecycleHooksType` contents to a `SignedRequest`.
 struct DescribeLifecycleHooksTypeSerializer;
 impl DescribeLifecycleHooksTypeSerializer {
@@ -2166,6 +3017,8 @@ impl DescribeLifecycleHooksTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancerTargetGroupsRequest {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -2175,6 +3028,29 @@ pub struct DescribeLoadBalancerTargetGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeLoadBalancerTargetGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeLoadBalancerTargetGroupsRequest` contents to a `SignedRequest`.
 struct DescribeLoadBalancerTargetGroupsRequestSerializer;
 impl DescribeLoadBalancerTargetGroupsRequestSerializer {
@@ -2198,6 +3074,8 @@ impl DescribeLoadBalancerTargetGroupsRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancerTargetGroupsResponse {
     /// <p>Information about the target groups.</p>
     pub load_balancer_target_groups: Option<Vec<LoadBalancerTargetGroupState>>,
@@ -2237,6 +3115,8 @@ impl DescribeLoadBalancerTargetGroupsResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancersRequest {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -2246,6 +3126,29 @@ pub struct DescribeLoadBalancersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeLoadBalancersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeLoadBalancersRequest` contents to a `SignedRequest`.
 struct DescribeLoadBalancersRequestSerializer;
 impl DescribeLoadBalancersRequestSerializer {
@@ -2269,6 +3172,8 @@ impl DescribeLoadBalancersRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancersResponse {
     /// <p>The load balancers.</p>
     pub load_balancers: Option<Vec<LoadBalancerState>>,
@@ -2305,6 +3210,8 @@ impl DescribeLoadBalancersResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMetricCollectionTypesAnswer {
     /// <p>The granularities for the metrics.</p>
     pub granularities: Option<Vec<MetricGranularityType>>,
@@ -2345,6 +3252,8 @@ impl DescribeMetricCollectionTypesAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeNotificationConfigurationsAnswer {
     /// <p>A string that indicates that the response contains more items than can be returned in a single response. To receive additional items, specify this string for the <code>NextToken</code> value when requesting the next set of items. This value is null when there are no more items to return.</p>
     pub next_token: Option<String>,
@@ -2384,6 +3293,8 @@ impl DescribeNotificationConfigurationsAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeNotificationConfigurationsType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_names: Option<Vec<String>>,
@@ -2393,6 +3304,29 @@ pub struct DescribeNotificationConfigurationsType {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeNotificationConfigurationsType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.auto_scaling_group_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "auto_scaling_group_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeNotificationConfigurationsType` contents to a `SignedRequest`.
 struct DescribeNotificationConfigurationsTypeSerializer;
 impl DescribeNotificationConfigurationsTypeSerializer {
@@ -2419,6 +3353,8 @@ impl DescribeNotificationConfigurationsTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePoliciesType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: Option<String>,
@@ -2432,6 +3368,53 @@ pub struct DescribePoliciesType {
     pub policy_types: Option<Vec<String>>,
 }
 
+impl Validate for DescribePoliciesType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.auto_scaling_group_name {
+            errors.check_length(
+                &field_path(path, "auto_scaling_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "auto_scaling_group_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.policy_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "policy_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1600));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.policy_types {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "policy_types"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// Serialize `DescribePoliciesType` contents to a `SignedRequest`.
 struct DescribePoliciesTypeSerializer;
 impl DescribePoliciesTypeSerializer {
@@ -2471,6 +3454,8 @@ impl DescribePoliciesTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScalingActivitiesType {
     /// <p>The activity IDs of the desired scaling activities. You can specify up to 50 IDs. If you omit this parameter, all activities for the past six weeks are described. If unknown activities are requested, they are ignored with no error. If you specify an Auto Scaling group, the results are limited to that group.</p>
     pub activity_ids: Option<Vec<String>>,
@@ -2482,15 +3467,50 @@ pub struct DescribeScalingActivitiesType {
     pub next_token: Option<String>,
 }
 
-/// Serialize `DescribeScalingActivitiesType` contents to a `SignedRequest`.
-struct DescribeScalingActivitiesTypeSerializer;
-impl DescribeScalingActivitiesTypeSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &DescribeScalingActivitiesType) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
+impl Validate for DescribeScalingActivitiesType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.activity_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "activity_ids"), i);
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
         }
-
+        if let Some(ref value) = self.auto_scaling_group_name {
+            errors.check_length(
+                &field_path(path, "auto_scaling_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "auto_scaling_group_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
+/// Serialize `DescribeScalingActivitiesType` contents to a `SignedRequest`.
+struct DescribeScalingActivitiesTypeSerializer;
+impl DescribeScalingActivitiesTypeSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &DescribeScalingActivitiesType) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
         if let Some(ref field_value) = obj.activity_ids {
             ActivityIdsSerializer::serialize(
                 params,
@@ -2514,6 +3534,8 @@ impl DescribeScalingActivitiesTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScheduledActionsType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: Option<String>,
@@ -2529,6 +3551,42 @@ pub struct DescribeScheduledActionsType {
     pub start_time: Option<String>,
 }
 
+impl Validate for DescribeScheduledActionsType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.auto_scaling_group_name {
+            errors.check_length(
+                &field_path(path, "auto_scaling_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "auto_scaling_group_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.scheduled_action_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "scheduled_action_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeScheduledActionsType` contents to a `SignedRequest`.
 struct DescribeScheduledActionsTypeSerializer;
 impl DescribeScheduledActionsTypeSerializer {
@@ -2567,6 +3625,8 @@ impl DescribeScheduledActionsTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsType {
     /// <p>One or more filters to scope the tags to return. The maximum number of filters per filter type (for example, <code>auto-scaling-group</code>) is 1000.</p>
     pub filters: Option<Vec<Filter>>,
@@ -2576,6 +3636,24 @@ pub struct DescribeTagsType {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeTagsType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeTagsType` contents to a `SignedRequest`.
 struct DescribeTagsTypeSerializer;
 impl DescribeTagsTypeSerializer {
@@ -2598,6 +3676,8 @@ impl DescribeTagsTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTerminationPolicyTypesAnswer {
     /// <p>The termination policies supported by Amazon EC2 Auto Scaling: <code>OldestInstance</code>, <code>OldestLaunchConfiguration</code>, <code>NewestInstance</code>, <code>ClosestToNextInstanceHour</code>, <code>Default</code>, <code>OldestLaunchTemplate</code>, and <code>AllocationStrategy</code>.</p>
     pub termination_policy_types: Option<Vec<String>>,
@@ -2631,6 +3711,8 @@ impl DescribeTerminationPolicyTypesAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachInstancesAnswer {
     /// <p>The activities related to detaching the instances from the Auto Scaling group.</p>
     pub activities: Option<Vec<Activity>>,
@@ -2657,6 +3739,8 @@ impl DetachInstancesAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachInstancesQuery {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -2666,6 +3750,33 @@ pub struct DetachInstancesQuery {
     pub should_decrement_desired_capacity: bool,
 }
 
+impl Validate for DetachInstancesQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.instance_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(19));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// Serialize `DetachInstancesQuery` contents to a `SignedRequest`.
 struct DetachInstancesQuerySerializer;
 impl DetachInstancesQuerySerializer {
@@ -2694,6 +3805,8 @@ impl DetachInstancesQuerySerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachLoadBalancerTargetGroupsResultType {}
 
 struct DetachLoadBalancerTargetGroupsResultTypeDeserializer;
@@ -2713,6 +3826,8 @@ impl DetachLoadBalancerTargetGroupsResultTypeDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachLoadBalancerTargetGroupsType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -2720,6 +3835,31 @@ pub struct DetachLoadBalancerTargetGroupsType {
     pub target_group_ar_ns: Vec<String>,
 }
 
+impl Validate for DetachLoadBalancerTargetGroupsType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.target_group_ar_ns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "target_group_ar_ns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(511));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `DetachLoadBalancerTargetGroupsType` contents to a `SignedRequest`.
 struct DetachLoadBalancerTargetGroupsTypeSerializer;
 impl DetachLoadBalancerTargetGroupsTypeSerializer {
@@ -2742,6 +3882,8 @@ impl DetachLoadBalancerTargetGroupsTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachLoadBalancersResultType {}
 
 struct DetachLoadBalancersResultTypeDeserializer;
@@ -2761,6 +3903,8 @@ impl DetachLoadBalancersResultTypeDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachLoadBalancersType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -2768,6 +3912,31 @@ pub struct DetachLoadBalancersType {
     pub load_balancer_names: Vec<String>,
 }
 
+impl Validate for DetachLoadBalancersType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.load_balancer_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "load_balancer_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `DetachLoadBalancersType` contents to a `SignedRequest`.
 struct DetachLoadBalancersTypeSerializer;
 impl DetachLoadBalancersTypeSerializer {
@@ -2790,6 +3959,8 @@ impl DetachLoadBalancersTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableMetricsCollectionQuery {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -2797,6 +3968,33 @@ pub struct DisableMetricsCollectionQuery {
     pub metrics: Option<Vec<String>>,
 }
 
+impl Validate for DisableMetricsCollectionQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.metrics {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "metrics"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// Serialize `DisableMetricsCollectionQuery` contents to a `SignedRequest`.
 struct DisableMetricsCollectionQuerySerializer;
 impl DisableMetricsCollectionQuerySerializer {
@@ -2821,7 +4019,7 @@ impl DisableScaleInDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2829,6 +4027,8 @@ impl DisableScaleInDeserializer {
 }
 /// <p>Describes an Amazon EBS volume. Used in combination with <a>BlockDeviceMapping</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Ebs {
     /// <p>Indicates whether the volume is deleted on instance termination. For Amazon EC2 Auto Scaling, the default value is <code>true</code>.</p>
     pub delete_on_termination: Option<bool>,
@@ -2844,6 +4044,43 @@ pub struct Ebs {
     pub volume_type: Option<String>,
 }
 
+impl Validate for Ebs {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.iops {
+            errors.check_range(&field_path(path, "iops"), *value, Some(100), Some(20000));
+        }
+        if let Some(ref value) = self.snapshot_id {
+            errors.check_length(
+                &field_path(path, "snapshot_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "snapshot_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.volume_size {
+            errors.check_range(
+                &field_path(path, "volume_size"),
+                *value,
+                Some(1),
+                Some(16384),
+            );
+        }
+        if let Some(ref value) = self.volume_type {
+            errors.check_length(
+                &field_path(path, "volume_type"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 struct EbsDeserializer;
 impl EbsDeserializer {
     #[allow(unused_variables)]
@@ -2929,13 +4166,15 @@ impl EbsOptimizedDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableMetricsCollectionQuery {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -2945,6 +4184,44 @@ pub struct EnableMetricsCollectionQuery {
     pub metrics: Option<Vec<String>>,
 }
 
+impl Validate for EnableMetricsCollectionQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "granularity"),
+            self.granularity.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "granularity"),
+            &self.granularity,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.metrics {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "metrics"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// Serialize `EnableMetricsCollectionQuery` contents to a `SignedRequest`.
 struct EnableMetricsCollectionQuerySerializer;
 impl EnableMetricsCollectionQuerySerializer {
@@ -2967,6 +4244,8 @@ impl EnableMetricsCollectionQuerySerializer {
 
 /// <p>Describes an enabled metric.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnabledMetric {
     /// <p>The granularity of the metric. The only valid value is <code>1Minute</code>.</p>
     pub granularity: Option<String>,
@@ -3018,6 +4297,8 @@ impl EnabledMetricsDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnterStandbyAnswer {
     /// <p>The activities related to moving instances into <code>Standby</code> mode.</p>
     pub activities: Option<Vec<Activity>>,
@@ -3044,6 +4325,8 @@ impl EnterStandbyAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnterStandbyQuery {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -3053,6 +4336,33 @@ pub struct EnterStandbyQuery {
     pub should_decrement_desired_capacity: bool,
 }
 
+impl Validate for EnterStandbyQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.instance_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(19));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// Serialize `EnterStandbyQuery` contents to a `SignedRequest`.
 struct EnterStandbyQuerySerializer;
 impl EnterStandbyQuerySerializer {
@@ -3085,13 +4395,15 @@ impl EstimatedInstanceWarmupDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecutePolicyType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: Option<String>,
@@ -3105,6 +4417,35 @@ pub struct ExecutePolicyType {
     pub policy_name: String,
 }
 
+impl Validate for ExecutePolicyType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.auto_scaling_group_name {
+            errors.check_length(
+                &field_path(path, "auto_scaling_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "auto_scaling_group_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "policy_name"),
+            self.policy_name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_name"),
+            &self.policy_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `ExecutePolicyType` contents to a `SignedRequest`.
 struct ExecutePolicyTypeSerializer;
 impl ExecutePolicyTypeSerializer {
@@ -3134,6 +4475,8 @@ impl ExecutePolicyTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExitStandbyAnswer {
     /// <p>The activities related to moving instances out of <code>Standby</code> mode.</p>
     pub activities: Option<Vec<Activity>>,
@@ -3160,6 +4503,8 @@ impl ExitStandbyAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExitStandbyQuery {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -3167,6 +4512,33 @@ pub struct ExitStandbyQuery {
     pub instance_ids: Option<Vec<String>>,
 }
 
+impl Validate for ExitStandbyQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.instance_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(19));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// Serialize `ExitStandbyQuery` contents to a `SignedRequest`.
 struct ExitStandbyQuerySerializer;
 impl ExitStandbyQuerySerializer {
@@ -3192,6 +4564,8 @@ impl ExitStandbyQuerySerializer {
 
 /// <p>Describes a scheduled action that could not be created, updated, or deleted.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FailedScheduledUpdateGroupActionRequest {
     /// <p>The error code.</p>
     pub error_code: Option<String>,
@@ -3259,6 +4633,8 @@ impl FailedScheduledUpdateGroupActionRequestsDeserializer {
 }
 /// <p>Describes a filter.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filter {
     /// <p>The name of the filter. The valid values are: <code>"auto-scaling-group"</code>, <code>"key"</code>, <code>"value"</code>, and <code>"propagate-at-launch"</code>.</p>
     pub name: Option<String>,
@@ -3266,6 +4642,28 @@ pub struct Filter {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for Filter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// Serialize `Filter` contents to a `SignedRequest`.
 struct FilterSerializer;
 impl FilterSerializer {
@@ -3300,7 +4698,7 @@ impl GlobalTimeoutDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3311,7 +4709,7 @@ impl HealthCheckGracePeriodDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3322,7 +4720,7 @@ impl HeartbeatTimeoutDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3330,6 +4728,8 @@ impl HeartbeatTimeoutDeserializer {
 }
 /// <p>Describes an EC2 instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Instance {
     /// <p>The Availability Zone in which the instance is running.</p>
     pub availability_zone: String,
@@ -3410,6 +4810,8 @@ impl InstanceIdsSerializer {
 
 /// <p>Describes whether detailed monitoring is enabled for the Auto Scaling instances.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceMonitoring {
     /// <p>If <code>true</code>, detailed monitoring is enabled. Otherwise, basic monitoring is enabled.</p>
     pub enabled: Option<bool>,
@@ -3456,7 +4858,7 @@ impl InstanceProtectedDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3481,6 +4883,8 @@ impl InstancesDeserializer {
 }
 /// <p>Describes an instances distribution for an Auto Scaling group with <a>MixedInstancesPolicy</a>.</p> <p>The instances distribution specifies the distribution of On-Demand Instances and Spot Instances, the maximum price to pay for Spot Instances, and how the Auto Scaling group allocates instance types to fulfill On-Demand and Spot capacity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstancesDistribution {
     /// <p>Indicates how to allocate instance types to fulfill On-Demand capacity. </p> <p>The only valid value is <code>prioritized</code>, which is also the default value. This strategy uses the order of instance type overrides for the <a>LaunchTemplate</a> to define the launch priority of each instance type. The first instance type in the array is prioritized higher than the last. If all your On-Demand capacity cannot be fulfilled using your highest priority instance, then the Auto Scaling groups launches the remaining capacity using the second priority instance type, and so on. </p>
     pub on_demand_allocation_strategy: Option<String>,
@@ -3496,6 +4900,33 @@ pub struct InstancesDistribution {
     pub spot_max_price: Option<String>,
 }
 
+impl Validate for InstancesDistribution {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.on_demand_allocation_strategy {
+            errors.check_pattern(
+                &field_path(path, "on_demand_allocation_strategy"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.spot_allocation_strategy {
+            errors.check_pattern(
+                &field_path(path, "spot_allocation_strategy"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.spot_max_price {
+            errors.check_length(
+                &field_path(path, "spot_max_price"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 struct InstancesDistributionDeserializer;
 impl InstancesDistributionDeserializer {
     #[allow(unused_variables)]
@@ -3595,6 +5026,8 @@ impl InstancesDistributionSerializer {
 
 /// <p>Describes a launch configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LaunchConfiguration {
     /// <p>For Auto Scaling groups that are running in a VPC, specifies whether to assign a public IP address to the group's instances. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/asg-in-vpc.html">Launching Auto Scaling Instances in a VPC</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     pub associate_public_ip_address: Option<bool>,
@@ -3754,11 +5187,29 @@ impl LaunchConfigurationDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LaunchConfigurationNameType {
     /// <p>The name of the launch configuration.</p>
     pub launch_configuration_name: String,
 }
 
+impl Validate for LaunchConfigurationNameType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "launch_configuration_name"),
+            self.launch_configuration_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "launch_configuration_name"),
+            &self.launch_configuration_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `LaunchConfigurationNameType` contents to a `SignedRequest`.
 struct LaunchConfigurationNameTypeSerializer;
 impl LaunchConfigurationNameTypeSerializer {
@@ -3787,6 +5238,8 @@ impl LaunchConfigurationNamesSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LaunchConfigurationNamesType {
     /// <p>The launch configuration names. If you omit this parameter, all launch configurations are described.</p>
     pub launch_configuration_names: Option<Vec<String>>,
@@ -3796,6 +5249,30 @@ pub struct LaunchConfigurationNamesType {
     pub next_token: Option<String>,
 }
 
+impl Validate for LaunchConfigurationNamesType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.launch_configuration_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "launch_configuration_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `LaunchConfigurationNamesType` contents to a `SignedRequest`.
 struct LaunchConfigurationNamesTypeSerializer;
 impl LaunchConfigurationNamesTypeSerializer {
@@ -3841,6 +5318,8 @@ impl LaunchConfigurationsDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LaunchConfigurationsType {
     /// <p>The launch configurations.</p>
     pub launch_configurations: Vec<LaunchConfiguration>,
@@ -3881,6 +5360,8 @@ impl LaunchConfigurationsTypeDeserializer {
 }
 /// <p>Describes a launch template and overrides. </p> <p>The overrides are used to override the instance type specified by the launch template with multiple instance types that can be used to launch On-Demand Instances and Spot Instances. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LaunchTemplate {
     /// <p>The launch template to use. You must specify either the launch template ID or launch template name in the request. </p>
     pub launch_template_specification: Option<LaunchTemplateSpecification>,
@@ -3888,6 +5369,20 @@ pub struct LaunchTemplate {
     pub overrides: Option<Vec<LaunchTemplateOverrides>>,
 }
 
+impl Validate for LaunchTemplate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.launch_template_specification {
+            value.validate_at(&field_path(path, "launch_template_specification"), errors);
+        }
+        if let Some(ref value) = self.overrides {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "overrides"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 struct LaunchTemplateDeserializer;
 impl LaunchTemplateDeserializer {
     #[allow(unused_variables)]
@@ -3955,11 +5450,31 @@ impl LaunchTemplateNameDeserializer {
 }
 /// <p>Describes an override for a launch template. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LaunchTemplateOverrides {
     /// <p>The instance type. </p> <p>For information about available instance types, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-types.html#AvailableInstanceTypes">Available Instance Types</a> in the <i>Amazon Elastic Compute Cloud User Guide.</i> </p>
     pub instance_type: Option<String>,
 }
 
+impl Validate for LaunchTemplateOverrides {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.instance_type {
+            errors.check_length(
+                &field_path(path, "instance_type"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "instance_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 struct LaunchTemplateOverridesDeserializer;
 impl LaunchTemplateOverridesDeserializer {
     #[allow(unused_variables)]
@@ -4003,6 +5518,8 @@ impl LaunchTemplateOverridesSerializer {
 
 /// <p>Describes a launch template and the launch template version.</p> <p>The launch template that is specified must be configured for use with an Auto Scaling group. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/create-launch-template.html">Creating a Launch Template for an Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LaunchTemplateSpecification {
     /// <p>The ID of the launch template. You must specify either a template ID or a template name.</p>
     pub launch_template_id: Option<String>,
@@ -4012,6 +5529,50 @@ pub struct LaunchTemplateSpecification {
     pub version: Option<String>,
 }
 
+impl Validate for LaunchTemplateSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.launch_template_id {
+            errors.check_length(
+                &field_path(path, "launch_template_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "launch_template_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.launch_template_name {
+            errors.check_length(
+                &field_path(path, "launch_template_name"),
+                value.chars().count(),
+                Some(3),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "launch_template_name"),
+                &value,
+                "[a-zA-Z0-9\\(\\)\\.\\-/_]+",
+            );
+        }
+        if let Some(ref value) = self.version {
+            errors.check_length(
+                &field_path(path, "version"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "version"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 struct LaunchTemplateSpecificationDeserializer;
 impl LaunchTemplateSpecificationDeserializer {
     #[allow(unused_variables)]
@@ -4084,6 +5645,8 @@ impl LifecycleActionResultDeserializer {
 }
 /// <p>Describes a lifecycle hook, which tells Amazon EC2 Auto Scaling that you want to perform an action whenever it launches instances or terminates instances. Used in response to <a>DescribeLifecycleHooks</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LifecycleHook {
     /// <p>The name of the Auto Scaling group for the lifecycle hook.</p>
     pub auto_scaling_group_name: Option<String>,
@@ -4185,6 +5748,8 @@ impl LifecycleHookNamesSerializer {
 
 /// <p>Describes a lifecycle hook. Used in combination with <a>CreateAutoScalingGroup</a>.</p> <p>A lifecycle hook tells Amazon EC2 Auto Scaling to perform an action on an instance when the instance launches (before it is put into service) or as the instance terminates (before it is fully terminated).</p> <p>This step is a part of the procedure for creating a lifecycle hook for an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p> <b>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</b> </p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state using <a>RecordLifecycleActionHeartbeat</a>.</p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action using <a>CompleteLifecycleAction</a>.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>You can view the lifecycle hooks for an Auto Scaling group using <a>DescribeLifecycleHooks</a>. You can modify an existing lifecycle hook or create new lifecycle hooks using <a>PutLifecycleHook</a>. If you are no longer using a lifecycle hook, you can delete it using <a>DeleteLifecycleHook</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LifecycleHookSpecification {
     /// <p>Defines the action the Auto Scaling group should take when the lifecycle hook timeout elapses or if an unexpected failure occurs. The valid values are <code>CONTINUE</code> and <code>ABANDON</code>. The default value is <code>ABANDON</code>.</p>
     pub default_result: Option<String>,
@@ -4202,6 +5767,61 @@ pub struct LifecycleHookSpecification {
     pub role_arn: Option<String>,
 }
 
+impl Validate for LifecycleHookSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "lifecycle_hook_name"),
+            self.lifecycle_hook_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "lifecycle_hook_name"),
+            &self.lifecycle_hook_name,
+            "[A-Za-z0-9\\-_\\/]+",
+        );
+        if let Some(ref value) = self.notification_metadata {
+            errors.check_length(
+                &field_path(path, "notification_metadata"),
+                value.chars().count(),
+                Some(1),
+                Some(4000),
+            );
+            errors.check_pattern(
+                &field_path(path, "notification_metadata"),
+                &value,
+                "[\\u0009\\u000A\\u000D\\u0020-\\u007e]+",
+            );
+        }
+        if let Some(ref value) = self.notification_target_arn {
+            errors.check_length(
+                &field_path(path, "notification_target_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "notification_target_arn"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `LifecycleHookSpecification` contents to a `SignedRequest`.
 struct LifecycleHookSpecificationSerializer;
 impl LifecycleHookSpecificationSerializer {
@@ -4326,6 +5946,8 @@ impl LoadBalancerNamesSerializer {
 
 /// <p>Describes the state of a Classic Load Balancer.</p> <p>If you specify a load balancer when creating the Auto Scaling group, the state of the load balancer is <code>InService</code>.</p> <p>If you attach a load balancer to an existing Auto Scaling group, the initial state is <code>Adding</code>. The state transitions to <code>Added</code> after all instances in the group are registered with the load balancer. If Elastic Load Balancing health checks are enabled for the load balancer, the state transitions to <code>InService</code> after at least one instance in the group passes the health check. If EC2 health checks are enabled instead, the load balancer remains in the <code>Added</code> state.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoadBalancerState {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: Option<String>,
@@ -4376,6 +5998,8 @@ impl LoadBalancerStatesDeserializer {
 }
 /// <p>Describes the state of a target group.</p> <p>If you attach a target group to an existing Auto Scaling group, the initial state is <code>Adding</code>. The state transitions to <code>Added</code> after all Auto Scaling instances are registered with the target group. If Elastic Load Balancing health checks are enabled, the state transitions to <code>InService</code> after at least one Auto Scaling instance passes the health check. If EC2 health checks are enabled instead, the target group remains in the <code>Added</code> state.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoadBalancerTargetGroupState {
     /// <p>The Amazon Resource Name (ARN) of the target group.</p>
     pub load_balancer_target_group_arn: Option<String>,
@@ -4437,7 +6061,7 @@ impl MaxNumberOfAutoScalingGroupsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4448,7 +6072,7 @@ impl MaxNumberOfLaunchConfigurationsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4456,6 +6080,8 @@ impl MaxNumberOfLaunchConfigurationsDeserializer {
 }
 /// <p>Describes a metric.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricCollectionType {
     /// <p><p>One of the following metrics:</p> <ul> <li> <p> <code>GroupMinSize</code> </p> </li> <li> <p> <code>GroupMaxSize</code> </p> </li> <li> <p> <code>GroupDesiredCapacity</code> </p> </li> <li> <p> <code>GroupInServiceInstances</code> </p> </li> <li> <p> <code>GroupPendingInstances</code> </p> </li> <li> <p> <code>GroupStandbyInstances</code> </p> </li> <li> <p> <code>GroupTerminatingInstances</code> </p> </li> <li> <p> <code>GroupTotalInstances</code> </p> </li> </ul></p>
     pub metric: Option<String>,
@@ -4502,6 +6128,8 @@ impl MetricCollectionTypesDeserializer {
 }
 /// <p>Describes the dimension of a metric.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricDimension {
     /// <p>The name of the dimension.</p>
     pub name: String,
@@ -4598,6 +6226,8 @@ impl MetricDimensionsSerializer {
 
 /// <p>Describes a granularity of a metric.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricGranularityType {
     /// <p>The granularity. The only valid value is <code>1Minute</code>.</p>
     pub granularity: Option<String>,
@@ -4670,7 +6300,7 @@ impl MetricScaleDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4726,7 +6356,7 @@ impl MinAdjustmentMagnitudeDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4737,7 +6367,7 @@ impl MinAdjustmentStepDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4756,6 +6386,8 @@ impl MixedInstanceSpotPriceDeserializer {
 }
 /// <p>Describes a mixed instances policy for an Auto Scaling group. With mixed instances, your Auto Scaling group can provision a combination of On-Demand Instances and Spot Instances across multiple instance types. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/asg-purchase-options.html">Auto Scaling Groups with Multiple Instance Types and Purchase Options</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>You can create a mixed instances policy for a new Auto Scaling group, or you can create it for an existing group by updating the group to specify <code>MixedInstancesPolicy</code> as the top-level parameter instead of a launch configuration or template. For more information, see <a>CreateAutoScalingGroup</a> and <a>UpdateAutoScalingGroup</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MixedInstancesPolicy {
     /// <p>The instances distribution to use.</p> <p>If you leave this parameter unspecified when creating a mixed instances policy, the default values are used.</p>
     pub instances_distribution: Option<InstancesDistribution>,
@@ -4763,6 +6395,17 @@ pub struct MixedInstancesPolicy {
     pub launch_template: Option<LaunchTemplate>,
 }
 
+impl Validate for MixedInstancesPolicy {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.instances_distribution {
+            value.validate_at(&field_path(path, "instances_distribution"), errors);
+        }
+        if let Some(ref value) = self.launch_template {
+            value.validate_at(&field_path(path, "launch_template"), errors);
+        }
+    }
+}
+
 struct MixedInstancesPolicyDeserializer;
 impl MixedInstancesPolicyDeserializer {
     #[allow(unused_variables)]
@@ -4823,7 +6466,7 @@ impl MonitoringEnabledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4834,7 +6477,7 @@ impl NoDeviceDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4842,6 +6485,8 @@ impl NoDeviceDeserializer {
 }
 /// <p>Describes a notification.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotificationConfiguration {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: Option<String>,
@@ -4910,7 +6555,7 @@ impl NumberOfAutoScalingGroupsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4921,7 +6566,7 @@ impl NumberOfLaunchConfigurationsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4932,7 +6577,7 @@ impl OnDemandBaseCapacityDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4943,7 +6588,7 @@ impl OnDemandPercentageAboveBaseCapacityDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4981,6 +6626,8 @@ impl OverridesSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PoliciesType {
     /// <p>A string that indicates that the response contains more items than can be returned in a single response. To receive additional items, specify this string for the <code>NextToken</code> value when requesting the next set of items. This value is null when there are no more items to return.</p>
     pub next_token: Option<String>,
@@ -5013,6 +6660,8 @@ impl PoliciesTypeDeserializer {
 }
 /// <p>Contains the output of PutScalingPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PolicyARNType {
     /// <p>The CloudWatch alarms created for the target tracking scaling policy.</p>
     pub alarms: Option<Vec<Alarm>>,
@@ -5049,7 +6698,7 @@ impl PolicyIncrementDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5080,6 +6729,8 @@ impl PolicyTypesSerializer {
 
 /// <p>Represents a predefined metric for a target tracking scaling policy to use with Amazon EC2 Auto Scaling.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PredefinedMetricSpecification {
     /// <p>The metric type.</p>
     pub predefined_metric_type: String,
@@ -5087,6 +6738,24 @@ pub struct PredefinedMetricSpecification {
     pub resource_label: Option<String>,
 }
 
+impl Validate for PredefinedMetricSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_label {
+            errors.check_length(
+                &field_path(path, "resource_label"),
+                value.chars().count(),
+                Some(1),
+                Some(1023),
+            );
+            errors.check_pattern(
+                &field_path(path, "resource_label"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 struct PredefinedMetricSpecificationDeserializer;
 impl PredefinedMetricSpecificationDeserializer {
     #[allow(unused_variables)]
@@ -5149,6 +6818,8 @@ impl ProcessNamesSerializer {
 
 /// <p>Describes a process type.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html#process-types">Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ProcessType {
     /// <p><p>One of the following processes:</p> <ul> <li> <p> <code>Launch</code> </p> </li> <li> <p> <code>Terminate</code> </p> </li> <li> <p> <code>AddToLoadBalancer</code> </p> </li> <li> <p> <code>AlarmNotification</code> </p> </li> <li> <p> <code>AZRebalance</code> </p> </li> <li> <p> <code>HealthCheck</code> </p> </li> <li> <p> <code>ReplaceUnhealthy</code> </p> </li> <li> <p> <code>ScheduledActions</code> </p> </li> </ul></p>
     pub process_name: String,
@@ -5191,6 +6862,8 @@ impl ProcessesDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ProcessesType {
     /// <p>The names of the process types.</p>
     pub processes: Option<Vec<ProcessType>>,
@@ -5221,7 +6894,7 @@ impl ProgressDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5232,13 +6905,15 @@ impl PropagateAtLaunchDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutLifecycleHookAnswer {}
 
 struct PutLifecycleHookAnswerDeserializer;
@@ -5258,6 +6933,8 @@ impl PutLifecycleHookAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutLifecycleHookType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -5277,6 +6954,72 @@ pub struct PutLifecycleHookType {
     pub role_arn: Option<String>,
 }
 
+impl Validate for PutLifecycleHookType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "lifecycle_hook_name"),
+            self.lifecycle_hook_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "lifecycle_hook_name"),
+            &self.lifecycle_hook_name,
+            "[A-Za-z0-9\\-_\\/]+",
+        );
+        if let Some(ref value) = self.notification_metadata {
+            errors.check_length(
+                &field_path(path, "notification_metadata"),
+                value.chars().count(),
+                Some(1),
+                Some(4000),
+            );
+            errors.check_pattern(
+                &field_path(path, "notification_metadata"),
+                &value,
+                "[\\u0009\\u000A\\u000D\\u0020-\\u007e]+",
+            );
+        }
+        if let Some(ref value) = self.notification_target_arn {
+            errors.check_length(
+                &field_path(path, "notification_target_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "notification_target_arn"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `PutLifecycleHookType` contents to a `SignedRequest`.
 struct PutLifecycleHookTypeSerializer;
 impl PutLifecycleHookTypeSerializer {
@@ -5325,6 +7068,8 @@ impl PutLifecycleHookTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutNotificationConfigurationType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -5334,6 +7079,42 @@ pub struct PutNotificationConfigurationType {
     pub topic_arn: String,
 }
 
+impl Validate for PutNotificationConfigurationType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.notification_types.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "notification_types"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "topic_arn"),
+            self.topic_arn.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "topic_arn"),
+            &self.topic_arn,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `PutNotificationConfigurationType` contents to a `SignedRequest`.
 struct PutNotificationConfigurationTypeSerializer;
 impl PutNotificationConfigurationTypeSerializer {
@@ -5357,6 +7138,8 @@ impl PutNotificationConfigurationTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutScalingPolicyType {
     /// <p>Specifies whether the <code>ScalingAdjustment</code> parameter is an absolute number or a percentage of the current capacity. The valid values are <code>ChangeInCapacity</code>, <code>ExactCapacity</code>, and <code>PercentChangeInCapacity</code>.</p> <p>Valid only if the policy type is <code>StepScaling</code> or <code>SimpleScaling</code>. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-scaling-simple-step.html#as-scaling-adjustment">Scaling Adjustment Types</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     pub adjustment_type: Option<String>,
@@ -5384,6 +7167,75 @@ pub struct PutScalingPolicyType {
     pub target_tracking_configuration: Option<TargetTrackingConfiguration>,
 }
 
+impl Validate for PutScalingPolicyType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.adjustment_type {
+            errors.check_length(
+                &field_path(path, "adjustment_type"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "adjustment_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.metric_aggregation_type {
+            errors.check_length(
+                &field_path(path, "metric_aggregation_type"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+            errors.check_pattern(
+                &field_path(path, "metric_aggregation_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "policy_name"),
+            self.policy_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_name"),
+            &self.policy_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.policy_type {
+            errors.check_length(
+                &field_path(path, "policy_type"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "policy_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.target_tracking_configuration {
+            value.validate_at(&field_path(path, "target_tracking_configuration"), errors);
+        }
+    }
+}
+
 /// Serialize `PutScalingPolicyType` contents to a `SignedRequest`.
 struct PutScalingPolicyTypeSerializer;
 impl PutScalingPolicyTypeSerializer {
@@ -5449,6 +7301,8 @@ impl PutScalingPolicyTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutScheduledUpdateGroupActionType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -5470,6 +7324,46 @@ pub struct PutScheduledUpdateGroupActionType {
     pub time: Option<String>,
 }
 
+impl Validate for PutScheduledUpdateGroupActionType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.recurrence {
+            errors.check_length(
+                &field_path(path, "recurrence"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "recurrence"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "scheduled_action_name"),
+            self.scheduled_action_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "scheduled_action_name"),
+            &self.scheduled_action_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `PutScheduledUpdateGroupActionType` contents to a `SignedRequest`.
 struct PutScheduledUpdateGroupActionTypeSerializer;
 impl PutScheduledUpdateGroupActionTypeSerializer {
@@ -5512,6 +7406,8 @@ impl PutScheduledUpdateGroupActionTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RecordLifecycleActionHeartbeatAnswer {}
 
 struct RecordLifecycleActionHeartbeatAnswerDeserializer;
@@ -5531,6 +7427,8 @@ impl RecordLifecycleActionHeartbeatAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RecordLifecycleActionHeartbeatType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -5542,6 +7440,54 @@ pub struct RecordLifecycleActionHeartbeatType {
     pub lifecycle_hook_name: String,
 }
 
+impl Validate for RecordLifecycleActionHeartbeatType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.instance_id {
+            errors.check_length(
+                &field_path(path, "instance_id"),
+                value.chars().count(),
+                Some(1),
+                Some(19),
+            );
+            errors.check_pattern(
+                &field_path(path, "instance_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.lifecycle_action_token {
+            errors.check_length(
+                &field_path(path, "lifecycle_action_token"),
+                value.chars().count(),
+                Some(36),
+                Some(36),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "lifecycle_hook_name"),
+            self.lifecycle_hook_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "lifecycle_hook_name"),
+            &self.lifecycle_hook_name,
+            "[A-Za-z0-9\\-_\\/]+",
+        );
+    }
+}
+
 /// Serialize `RecordLifecycleActionHeartbeatType` contents to a `SignedRequest`.
 struct RecordLifecycleActionHeartbeatTypeSerializer;
 impl RecordLifecycleActionHeartbeatTypeSerializer {
@@ -5612,6 +7558,8 @@ impl ScalingPoliciesDeserializer {
 }
 /// <p>Describes a scaling policy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScalingPolicy {
     /// <p>The adjustment type, which specifies how <code>ScalingAdjustment</code> is interpreted. The valid values are <code>ChangeInCapacity</code>, <code>ExactCapacity</code>, and <code>PercentChangeInCapacity</code>.</p>
     pub adjustment_type: Option<String>,
@@ -5738,6 +7686,8 @@ impl ScalingPolicyDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScalingProcessQuery {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -5745,9 +7695,36 @@ pub struct ScalingProcessQuery {
     pub scaling_processes: Option<Vec<String>>,
 }
 
-/// Serialize `ScalingProcessQuery` contents to a `SignedRequest`.
-struct ScalingProcessQuerySerializer;
-impl ScalingProcessQuerySerializer {
+impl Validate for ScalingProcessQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.scaling_processes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "scaling_processes"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
+/// Serialize `ScalingProcessQuery` contents to a `SignedRequest`.
+struct ScalingProcessQuerySerializer;
+impl ScalingProcessQuerySerializer {
     fn serialize(params: &mut Params, name: &str, obj: &ScalingProcessQuery) {
         let mut prefix = name.to_string();
         if prefix != "" {
@@ -5780,6 +7757,8 @@ impl ScheduledActionNamesSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScheduledActionsType {
     /// <p>A string that indicates that the response contains more items than can be returned in a single response. To receive additional items, specify this string for the <code>NextToken</code> value when requesting the next set of items. This value is null when there are no more items to return.</p>
     pub next_token: Option<String>,
@@ -5815,6 +7794,8 @@ impl ScheduledActionsTypeDeserializer {
 }
 /// <p>Describes a scheduled scaling action. Used in response to <a>DescribeScheduledActions</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScheduledUpdateGroupAction {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: Option<String>,
@@ -5913,6 +7894,8 @@ impl ScheduledUpdateGroupActionDeserializer {
 }
 /// <p>Describes one or more scheduled scaling action updates for a specified Auto Scaling group. Used in combination with <a>BatchPutScheduledUpdateGroupAction</a>.</p> <p>When updating a scheduled scaling action, all optional parameters are left unchanged if not specified. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScheduledUpdateGroupActionRequest {
     /// <p>The number of EC2 instances that should be running in the group.</p>
     pub desired_capacity: Option<i64>,
@@ -5930,6 +7913,35 @@ pub struct ScheduledUpdateGroupActionRequest {
     pub start_time: Option<String>,
 }
 
+impl Validate for ScheduledUpdateGroupActionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.recurrence {
+            errors.check_length(
+                &field_path(path, "recurrence"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "recurrence"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "scheduled_action_name"),
+            self.scheduled_action_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "scheduled_action_name"),
+            &self.scheduled_action_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `ScheduledUpdateGroupActionRequest` contents to a `SignedRequest`.
 struct ScheduledUpdateGroupActionRequestSerializer;
 impl ScheduledUpdateGroupActionRequestSerializer {
@@ -6024,6 +8036,8 @@ impl SecurityGroupsSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetDesiredCapacityType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -6033,6 +8047,22 @@ pub struct SetDesiredCapacityType {
     pub honor_cooldown: Option<bool>,
 }
 
+impl Validate for SetDesiredCapacityType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `SetDesiredCapacityType` contents to a `SignedRequest`.
 struct SetDesiredCapacityTypeSerializer;
 impl SetDesiredCapacityTypeSerializer {
@@ -6057,6 +8087,8 @@ impl SetDesiredCapacityTypeSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetInstanceHealthQuery {
     /// <p>The health status of the instance. Set to <code>Healthy</code> to have the instance remain in service. Set to <code>Unhealthy</code> to have the instance be out of service. Amazon EC2 Auto Scaling terminates and replaces the unhealthy instance.</p>
     pub health_status: String,
@@ -6066,6 +8098,33 @@ pub struct SetInstanceHealthQuery {
     pub should_respect_grace_period: Option<bool>,
 }
 
+impl Validate for SetInstanceHealthQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "health_status"),
+            self.health_status.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "health_status"),
+            &self.health_status,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `SetInstanceHealthQuery` contents to a `SignedRequest`.
 struct SetInstanceHealthQuerySerializer;
 impl SetInstanceHealthQuerySerializer {
@@ -6087,6 +8146,8 @@ impl SetInstanceHealthQuerySerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetInstanceProtectionAnswer {}
 
 struct SetInstanceProtectionAnswerDeserializer;
@@ -6106,6 +8167,8 @@ impl SetInstanceProtectionAnswerDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetInstanceProtectionQuery {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -6115,6 +8178,31 @@ pub struct SetInstanceProtectionQuery {
     pub protected_from_scale_in: bool,
 }
 
+impl Validate for SetInstanceProtectionQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.instance_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "instance_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(19));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `SetInstanceProtectionQuery` contents to a `SignedRequest`.
 struct SetInstanceProtectionQuerySerializer;
 impl SetInstanceProtectionQuerySerializer {
@@ -6145,7 +8233,7 @@ impl SpotInstancePoolsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6164,6 +8252,8 @@ impl SpotPriceDeserializer {
 }
 /// <p><p>Describes an adjustment based on the difference between the value of the aggregated CloudWatch metric and the breach threshold that you&#39;ve defined for the alarm. Used in combination with <a>PutScalingPolicy</a>.</p> <p>For the following examples, suppose that you have an alarm with a breach threshold of 50:</p> <ul> <li> <p>To trigger the adjustment when the metric is greater than or equal to 50 and less than 60, specify a lower bound of 0 and an upper bound of 10.</p> </li> <li> <p>To trigger the adjustment when the metric is greater than 40 and less than or equal to 50, specify a lower bound of -10 and an upper bound of 0.</p> </li> </ul> <p>There are a few rules for the step adjustments for your step policy:</p> <ul> <li> <p>The ranges of your step adjustments can&#39;t overlap or have a gap.</p> </li> <li> <p>At most, one step adjustment can have a null lower bound. If one step adjustment has a negative lower bound, then there must be a step adjustment with a null lower bound.</p> </li> <li> <p>At most, one step adjustment can have a null upper bound. If one step adjustment has a positive upper bound, then there must be a step adjustment with a null upper bound.</p> </li> <li> <p>The upper and lower bound can&#39;t be null in the same step adjustment.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StepAdjustment {
     /// <p>The lower bound for the difference between the alarm threshold and the CloudWatch metric. If the metric value is above the breach threshold, the lower bound is inclusive (the metric must be greater than or equal to the threshold plus the lower bound). Otherwise, it is exclusive (the metric must be greater than the threshold plus the lower bound). A null value indicates negative infinity.</p>
     pub metric_interval_lower_bound: Option<f64>,
@@ -6264,6 +8354,8 @@ impl StepAdjustmentsSerializer {
 
 /// <p>Describes an automatic scaling process that has been suspended. For more information, see <a>ProcessType</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SuspendedProcess {
     /// <p>The name of the suspended process.</p>
     pub process_name: Option<String>,
@@ -6317,6 +8409,8 @@ impl SuspendedProcessesDeserializer {
 }
 /// <p>Describes a tag for an Auto Scaling group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>The tag key.</p>
     pub key: String,
@@ -6330,6 +8424,49 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.resource_id {
+            errors.check_pattern(
+                &field_path(path, "resource_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.resource_type {
+            errors.check_pattern(
+                &field_path(path, "resource_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `Tag` contents to a `SignedRequest`.
 struct TagSerializer;
 impl TagSerializer {
@@ -6357,6 +8494,8 @@ impl TagSerializer {
 
 /// <p>Describes a tag for an Auto Scaling group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagDescription {
     /// <p>The tag key.</p>
     pub key: Option<String>,
@@ -6457,6 +8596,8 @@ impl TagsSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagsType {
     /// <p>A string that indicates that the response contains more items than can be returned in a single response. To receive additional items, specify this string for the <code>NextToken</code> value when requesting the next set of items. This value is null when there are no more items to return.</p>
     pub next_token: Option<String>,
@@ -6520,6 +8661,8 @@ impl TargetGroupARNsSerializer {
 
 /// <p>Represents a target tracking scaling policy configuration to use with Amazon EC2 Auto Scaling.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TargetTrackingConfiguration {
     /// <p>A customized metric. You must specify either a predefined metric or a customized metric.</p>
     pub customized_metric_specification: Option<CustomizedMetricSpecification>,
@@ -6531,6 +8674,14 @@ pub struct TargetTrackingConfiguration {
     pub target_value: f64,
 }
 
+impl Validate for TargetTrackingConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.predefined_metric_specification {
+            value.validate_at(&field_path(path, "predefined_metric_specification"), errors);
+        }
+    }
+}
+
 struct TargetTrackingConfigurationDeserializer;
 impl TargetTrackingConfigurationDeserializer {
     #[allow(unused_variables)]
@@ -6606,6 +8757,8 @@ impl TargetTrackingConfigurationSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminateInstanceInAutoScalingGroupType {
     /// <p>The ID of the instance.</p>
     pub instance_id: String,
@@ -6613,6 +8766,22 @@ pub struct TerminateInstanceInAutoScalingGroupType {
     pub should_decrement_desired_capacity: bool,
 }
 
+impl Validate for TerminateInstanceInAutoScalingGroupType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// Serialize `TerminateInstanceInAutoScalingGroupType` contents to a `SignedRequest`.
 struct TerminateInstanceInAutoScalingGroupTypeSerializer;
 impl TerminateInstanceInAutoScalingGroupTypeSerializer {
@@ -6673,6 +8842,8 @@ impl TimestampTypeDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAutoScalingGroupType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: String,
@@ -6708,6 +8879,115 @@ pub struct UpdateAutoScalingGroupType {
     pub vpc_zone_identifier: Option<String>,
 }
 
+impl Validate for UpdateAutoScalingGroupType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "auto_scaling_group_name"),
+            self.auto_scaling_group_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "auto_scaling_group_name"),
+            &self.auto_scaling_group_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.availability_zones {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "availability_zones"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.health_check_type {
+            errors.check_length(
+                &field_path(path, "health_check_type"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+            errors.check_pattern(
+                &field_path(path, "health_check_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.launch_configuration_name {
+            errors.check_length(
+                &field_path(path, "launch_configuration_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "launch_configuration_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.launch_template {
+            value.validate_at(&field_path(path, "launch_template"), errors);
+        }
+        if let Some(ref value) = self.mixed_instances_policy {
+            value.validate_at(&field_path(path, "mixed_instances_policy"), errors);
+        }
+        if let Some(ref value) = self.placement_group {
+            errors.check_length(
+                &field_path(path, "placement_group"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "placement_group"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.service_linked_role_arn {
+            errors.check_length(
+                &field_path(path, "service_linked_role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(
+                &field_path(path, "service_linked_role_arn"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.termination_policies {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "termination_policies"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1600));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.vpc_zone_identifier {
+            errors.check_length(
+                &field_path(path, "vpc_zone_identifier"),
+                value.chars().count(),
+                Some(1),
+                Some(2047),
+            );
+            errors.check_pattern(
+                &field_path(path, "vpc_zone_identifier"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// Serialize `UpdateAutoScalingGroupType` contents to a `SignedRequest`.
 struct UpdateAutoScalingGroupTypeSerializer;
 impl UpdateAutoScalingGroupTypeSerializer {
@@ -10256,220 +12536,33 @@ pub trait Autoscaling {
         input: UpdateAutoScalingGroupType,
     ) -> RusotoFuture<(), UpdateAutoScalingGroupError>;
 }
-/// A client for the Auto Scaling API.
-#[derive(Clone)]
-pub struct AutoscalingClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl AutoscalingClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> AutoscalingClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> AutoscalingClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingClient {
-        AutoscalingClient { client, region }
-    }
-}
-
-impl Autoscaling for AutoscalingClient {
-    /// <p>Attaches one or more EC2 instances to the specified Auto Scaling group.</p> <p>When you attach instances, Amazon EC2 Auto Scaling increases the desired capacity of the group by the number of instances being attached. If the number of instances being attached plus the desired capacity of the group exceeds the maximum size of the group, the operation fails.</p> <p>If there is a Classic Load Balancer attached to your Auto Scaling group, the instances are also registered with the load balancer. If there are target groups attached to your Auto Scaling group, the instances are also registered with the target groups.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-instance-asg.html">Attach EC2 Instances to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+const _: Option<&dyn Autoscaling> = None;
+impl<'a, T: Autoscaling + ?Sized> Autoscaling for &'a T {
     fn attach_instances(
         &self,
         input: AttachInstancesQuery,
     ) -> RusotoFuture<(), AttachInstancesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AttachInstances");
-        params.put("Version", "2011-01-01");
-        AttachInstancesQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AttachInstancesError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).attach_instances(input)
     }
-
-    /// <p>Attaches one or more target groups to the specified Auto Scaling group. </p> <p>To describe the target groups for an Auto Scaling group, use <a>DescribeLoadBalancerTargetGroups</a>. To detach the target group from the Auto Scaling group, use <a>DetachLoadBalancerTargetGroups</a>.</p> <p>With Application Load Balancers and Network Load Balancers, instances are registered as targets with a target group. With Classic Load Balancers, instances are registered with the load balancer. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-load-balancer-asg.html">Attaching a Load Balancer to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn attach_load_balancer_target_groups(
         &self,
         input: AttachLoadBalancerTargetGroupsType,
     ) -> RusotoFuture<AttachLoadBalancerTargetGroupsResultType, AttachLoadBalancerTargetGroupsError>
     {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AttachLoadBalancerTargetGroups");
-        params.put("Version", "2011-01-01");
-        AttachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AttachLoadBalancerTargetGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AttachLoadBalancerTargetGroupsResultType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AttachLoadBalancerTargetGroupsResultTypeDeserializer::deserialize(
-                        "AttachLoadBalancerTargetGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).attach_load_balancer_target_groups(input)
     }
-
-    /// <p>Attaches one or more Classic Load Balancers to the specified Auto Scaling group.</p> <p>To attach an Application Load Balancer or a Network Load Balancer instead, see <a>AttachLoadBalancerTargetGroups</a>.</p> <p>To describe the load balancers for an Auto Scaling group, use <a>DescribeLoadBalancers</a>. To detach the load balancer from the Auto Scaling group, use <a>DetachLoadBalancers</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-load-balancer-asg.html">Attaching a Load Balancer to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn attach_load_balancers(
         &self,
         input: AttachLoadBalancersType,
     ) -> RusotoFuture<AttachLoadBalancersResultType, AttachLoadBalancersError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AttachLoadBalancers");
-        params.put("Version", "2011-01-01");
-        AttachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AttachLoadBalancersError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AttachLoadBalancersResultType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AttachLoadBalancersResultTypeDeserializer::deserialize(
-                        "AttachLoadBalancersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).attach_load_balancers(input)
     }
-
-    /// <p>Deletes one or more scheduled actions for the specified Auto Scaling group.</p>
     fn batch_delete_scheduled_action(
         &self,
         input: BatchDeleteScheduledActionType,
     ) -> RusotoFuture<BatchDeleteScheduledActionAnswer, BatchDeleteScheduledActionError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "BatchDeleteScheduledAction");
-        params.put("Version", "2011-01-01");
-        BatchDeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchDeleteScheduledActionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = BatchDeleteScheduledActionAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = BatchDeleteScheduledActionAnswerDeserializer::deserialize(
-                        "BatchDeleteScheduledActionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).batch_delete_scheduled_action(input)
     }
-
-    /// <p>Creates or updates one or more scheduled scaling actions for an Auto Scaling group. If you leave a parameter unspecified when updating a scheduled scaling action, the corresponding value remains unchanged.</p>
     fn batch_put_scheduled_update_group_action(
         &self,
         input: BatchPutScheduledUpdateGroupActionType,
@@ -10477,911 +12570,133 @@ impl Autoscaling for AutoscalingClient {
         BatchPutScheduledUpdateGroupActionAnswer,
         BatchPutScheduledUpdateGroupActionError,
     > {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "BatchPutScheduledUpdateGroupAction");
-        params.put("Version", "2011-01-01");
-        BatchPutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchPutScheduledUpdateGroupActionError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = BatchPutScheduledUpdateGroupActionAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = BatchPutScheduledUpdateGroupActionAnswerDeserializer::deserialize(
-                        "BatchPutScheduledUpdateGroupActionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).batch_put_scheduled_update_group_action(input)
     }
-
-    /// <p>Completes the lifecycle action for the specified token or instance with the specified result.</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state.</p> </li> <li> <p> <b>If you finish before the timeout period ends, complete the lifecycle action.</b> </p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn complete_lifecycle_action(
         &self,
         input: CompleteLifecycleActionType,
     ) -> RusotoFuture<CompleteLifecycleActionAnswer, CompleteLifecycleActionError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CompleteLifecycleAction");
-        params.put("Version", "2011-01-01");
-        CompleteLifecycleActionTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CompleteLifecycleActionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CompleteLifecycleActionAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CompleteLifecycleActionAnswerDeserializer::deserialize(
-                        "CompleteLifecycleActionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).complete_lifecycle_action(input)
     }
-
-    /// <p>Creates an Auto Scaling group with the specified name and attributes.</p> <p>If you exceed your maximum limit of Auto Scaling groups, the call fails. For information about viewing this limit, see <a>DescribeAccountLimits</a>. For information about updating this limit, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn create_auto_scaling_group(
         &self,
         input: CreateAutoScalingGroupType,
     ) -> RusotoFuture<(), CreateAutoScalingGroupError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateAutoScalingGroup");
-        params.put("Version", "2011-01-01");
-        CreateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateAutoScalingGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).create_auto_scaling_group(input)
     }
-
-    /// <p>Creates a launch configuration.</p> <p>If you exceed your maximum limit of launch configurations, the call fails. For information about viewing this limit, see <a>DescribeAccountLimits</a>. For information about updating this limit, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/LaunchConfiguration.html">Launch Configurations</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn create_launch_configuration(
         &self,
         input: CreateLaunchConfigurationType,
     ) -> RusotoFuture<(), CreateLaunchConfigurationError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateLaunchConfiguration");
-        params.put("Version", "2011-01-01");
-        CreateLaunchConfigurationTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateLaunchConfigurationError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).create_launch_configuration(input)
     }
-
-    /// <p>Creates or updates tags for the specified Auto Scaling group.</p> <p>When you specify a tag with a key that already exists, the operation overwrites the previous tag definition, and you do not get an error message.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/autoscaling-tagging.html">Tagging Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn create_or_update_tags(
         &self,
         input: CreateOrUpdateTagsType,
     ) -> RusotoFuture<(), CreateOrUpdateTagsError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateOrUpdateTags");
-        params.put("Version", "2011-01-01");
-        CreateOrUpdateTagsTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateOrUpdateTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).create_or_update_tags(input)
     }
-
-    /// <p>Deletes the specified Auto Scaling group.</p> <p>If the group has instances or scaling activities in progress, you must specify the option to force the deletion in order for it to succeed.</p> <p>If the group has policies, deleting the group deletes the policies, the underlying alarm actions, and any alarm that no longer has an associated action.</p> <p>To remove instances from the Auto Scaling group before deleting it, call <a>DetachInstances</a> with the list of instances and the option to decrement the desired capacity. This ensures that Amazon EC2 Auto Scaling does not launch replacement instances.</p> <p>To terminate all instances before deleting the Auto Scaling group, call <a>UpdateAutoScalingGroup</a> and set the minimum size and desired capacity of the Auto Scaling group to zero.</p>
     fn delete_auto_scaling_group(
         &self,
         input: DeleteAutoScalingGroupType,
     ) -> RusotoFuture<(), DeleteAutoScalingGroupError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteAutoScalingGroup");
-        params.put("Version", "2011-01-01");
-        DeleteAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteAutoScalingGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_auto_scaling_group(input)
     }
-
-    /// <p>Deletes the specified launch configuration.</p> <p>The launch configuration must not be attached to an Auto Scaling group. When this call completes, the launch configuration is no longer available for use.</p>
     fn delete_launch_configuration(
         &self,
         input: LaunchConfigurationNameType,
     ) -> RusotoFuture<(), DeleteLaunchConfigurationError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteLaunchConfiguration");
-        params.put("Version", "2011-01-01");
-        LaunchConfigurationNameTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteLaunchConfigurationError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_launch_configuration(input)
     }
-
-    /// <p>Deletes the specified lifecycle hook.</p> <p>If there are any outstanding lifecycle actions, they are completed first (<code>ABANDON</code> for launching instances, <code>CONTINUE</code> for terminating instances).</p>
     fn delete_lifecycle_hook(
         &self,
         input: DeleteLifecycleHookType,
     ) -> RusotoFuture<DeleteLifecycleHookAnswer, DeleteLifecycleHookError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteLifecycleHook");
-        params.put("Version", "2011-01-01");
-        DeleteLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteLifecycleHookError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteLifecycleHookAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteLifecycleHookAnswerDeserializer::deserialize(
-                        "DeleteLifecycleHookResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_lifecycle_hook(input)
     }
-
-    /// <p>Deletes the specified notification.</p>
     fn delete_notification_configuration(
         &self,
         input: DeleteNotificationConfigurationType,
     ) -> RusotoFuture<(), DeleteNotificationConfigurationError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteNotificationConfiguration");
-        params.put("Version", "2011-01-01");
-        DeleteNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteNotificationConfigurationError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_notification_configuration(input)
     }
-
-    /// <p>Deletes the specified scaling policy.</p> <p>Deleting either a step scaling policy or a simple scaling policy deletes the underlying alarm action, but does not delete the alarm, even if it no longer has an associated action.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/deleting-scaling-policy.html">Deleting a Scaling Policy</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn delete_policy(&self, input: DeletePolicyType) -> RusotoFuture<(), DeletePolicyError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeletePolicy");
-        params.put("Version", "2011-01-01");
-        DeletePolicyTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeletePolicyError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_policy(input)
     }
-
-    /// <p>Deletes the specified scheduled action.</p>
     fn delete_scheduled_action(
         &self,
         input: DeleteScheduledActionType,
     ) -> RusotoFuture<(), DeleteScheduledActionError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteScheduledAction");
-        params.put("Version", "2011-01-01");
-        DeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteScheduledActionError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_scheduled_action(input)
     }
-
-    /// <p>Deletes the specified tags.</p>
     fn delete_tags(&self, input: DeleteTagsType) -> RusotoFuture<(), DeleteTagsError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteTags");
-        params.put("Version", "2011-01-01");
-        DeleteTagsTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_tags(input)
     }
-
-    /// <p>Describes the current Amazon EC2 Auto Scaling resource limits for your AWS account.</p> <p>For information about requesting an increase in these limits, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn describe_account_limits(
         &self,
     ) -> RusotoFuture<DescribeAccountLimitsAnswer, DescribeAccountLimitsError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeAccountLimits");
-        params.put("Version", "2011-01-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAccountLimitsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeAccountLimitsAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeAccountLimitsAnswerDeserializer::deserialize(
-                        "DescribeAccountLimitsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_account_limits()
     }
-
-    /// <p>Describes the policy adjustment types for use with <a>PutScalingPolicy</a>.</p>
     fn describe_adjustment_types(
         &self,
     ) -> RusotoFuture<DescribeAdjustmentTypesAnswer, DescribeAdjustmentTypesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeAdjustmentTypes");
-        params.put("Version", "2011-01-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAdjustmentTypesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeAdjustmentTypesAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeAdjustmentTypesAnswerDeserializer::deserialize(
-                        "DescribeAdjustmentTypesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_adjustment_types()
     }
-
-    /// <p>Describes one or more Auto Scaling groups.</p>
     fn describe_auto_scaling_groups(
         &self,
         input: AutoScalingGroupNamesType,
     ) -> RusotoFuture<AutoScalingGroupsType, DescribeAutoScalingGroupsError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeAutoScalingGroups");
-        params.put("Version", "2011-01-01");
-        AutoScalingGroupNamesTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAutoScalingGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AutoScalingGroupsType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AutoScalingGroupsTypeDeserializer::deserialize(
-                        "DescribeAutoScalingGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_auto_scaling_groups(input)
     }
-
-    /// <p>Describes one or more Auto Scaling instances.</p>
     fn describe_auto_scaling_instances(
         &self,
         input: DescribeAutoScalingInstancesType,
     ) -> RusotoFuture<AutoScalingInstancesType, DescribeAutoScalingInstancesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeAutoScalingInstances");
-        params.put("Version", "2011-01-01");
-        DescribeAutoScalingInstancesTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAutoScalingInstancesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AutoScalingInstancesType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AutoScalingInstancesTypeDeserializer::deserialize(
-                        "DescribeAutoScalingInstancesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_auto_scaling_instances(input)
     }
-
-    /// <p>Describes the notification types that are supported by Amazon EC2 Auto Scaling.</p>
     fn describe_auto_scaling_notification_types(
         &self,
     ) -> RusotoFuture<
         DescribeAutoScalingNotificationTypesAnswer,
         DescribeAutoScalingNotificationTypesError,
     > {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeAutoScalingNotificationTypes");
-        params.put("Version", "2011-01-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAutoScalingNotificationTypesError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeAutoScalingNotificationTypesAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeAutoScalingNotificationTypesAnswerDeserializer::deserialize(
-                        "DescribeAutoScalingNotificationTypesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_auto_scaling_notification_types()
     }
-
-    /// <p>Describes one or more launch configurations.</p>
     fn describe_launch_configurations(
         &self,
         input: LaunchConfigurationNamesType,
     ) -> RusotoFuture<LaunchConfigurationsType, DescribeLaunchConfigurationsError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeLaunchConfigurations");
-        params.put("Version", "2011-01-01");
-        LaunchConfigurationNamesTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLaunchConfigurationsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = LaunchConfigurationsType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = LaunchConfigurationsTypeDeserializer::deserialize(
-                        "DescribeLaunchConfigurationsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_launch_configurations(input)
     }
-
-    /// <p><p>Describes the available types of lifecycle hooks.</p> <p>The following hook types are supported:</p> <ul> <li> <p>autoscaling:EC2<em>INSTANCE</em>LAUNCHING</p> </li> <li> <p>autoscaling:EC2<em>INSTANCE</em>TERMINATING</p> </li> </ul></p>
     fn describe_lifecycle_hook_types(
         &self,
     ) -> RusotoFuture<DescribeLifecycleHookTypesAnswer, DescribeLifecycleHookTypesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeLifecycleHookTypes");
-        params.put("Version", "2011-01-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLifecycleHookTypesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeLifecycleHookTypesAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeLifecycleHookTypesAnswerDeserializer::deserialize(
-                        "DescribeLifecycleHookTypesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_lifecycle_hook_types()
     }
-
-    /// <p>Describes the lifecycle hooks for the specified Auto Scaling group.</p>
     fn describe_lifecycle_hooks(
         &self,
         input: DescribeLifecycleHooksType,
     ) -> RusotoFuture<DescribeLifecycleHooksAnswer, DescribeLifecycleHooksError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeLifecycleHooks");
-        params.put("Version", "2011-01-01");
-        DescribeLifecycleHooksTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLifecycleHooksError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeLifecycleHooksAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeLifecycleHooksAnswerDeserializer::deserialize(
-                        "DescribeLifecycleHooksResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_lifecycle_hooks(input)
     }
-
-    /// <p>Describes the target groups for the specified Auto Scaling group.</p>
     fn describe_load_balancer_target_groups(
         &self,
         input: DescribeLoadBalancerTargetGroupsRequest,
     ) -> RusotoFuture<DescribeLoadBalancerTargetGroupsResponse, DescribeLoadBalancerTargetGroupsError>
     {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeLoadBalancerTargetGroups");
-        params.put("Version", "2011-01-01");
-        DescribeLoadBalancerTargetGroupsRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLoadBalancerTargetGroupsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeLoadBalancerTargetGroupsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeLoadBalancerTargetGroupsResponseDeserializer::deserialize(
-                        "DescribeLoadBalancerTargetGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_load_balancer_target_groups(input)
     }
-
-    /// <p>Describes the load balancers for the specified Auto Scaling group.</p> <p>This operation describes only Classic Load Balancers. If you have Application Load Balancers or Network Load Balancers, use <a>DescribeLoadBalancerTargetGroups</a> instead.</p>
     fn describe_load_balancers(
         &self,
         input: DescribeLoadBalancersRequest,
     ) -> RusotoFuture<DescribeLoadBalancersResponse, DescribeLoadBalancersError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeLoadBalancers");
-        params.put("Version", "2011-01-01");
-        DescribeLoadBalancersRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLoadBalancersError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeLoadBalancersResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeLoadBalancersResponseDeserializer::deserialize(
-                        "DescribeLoadBalancersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_load_balancers(input)
     }
-
-    /// <p>Describes the available CloudWatch metrics for Amazon EC2 Auto Scaling.</p> <p>The <code>GroupStandbyInstances</code> metric is not returned by default. You must explicitly request this metric when calling <a>EnableMetricsCollection</a>.</p>
     fn describe_metric_collection_types(
         &self,
     ) -> RusotoFuture<DescribeMetricCollectionTypesAnswer, DescribeMetricCollectionTypesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeMetricCollectionTypes");
-        params.put("Version", "2011-01-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMetricCollectionTypesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeMetricCollectionTypesAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeMetricCollectionTypesAnswerDeserializer::deserialize(
-                        "DescribeMetricCollectionTypesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_metric_collection_types()
     }
-
-    /// <p>Describes the notification actions associated with the specified Auto Scaling group.</p>
     fn describe_notification_configurations(
         &self,
         input: DescribeNotificationConfigurationsType,
@@ -11389,1099 +12704,5374 @@ impl Autoscaling for AutoscalingClient {
         DescribeNotificationConfigurationsAnswer,
         DescribeNotificationConfigurationsError,
     > {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeNotificationConfigurations");
-        params.put("Version", "2011-01-01");
-        DescribeNotificationConfigurationsTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeNotificationConfigurationsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeNotificationConfigurationsAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeNotificationConfigurationsAnswerDeserializer::deserialize(
-                        "DescribeNotificationConfigurationsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_notification_configurations(input)
     }
-
-    /// <p>Describes the policies for the specified Auto Scaling group.</p>
     fn describe_policies(
         &self,
         input: DescribePoliciesType,
     ) -> RusotoFuture<PoliciesType, DescribePoliciesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribePolicies");
-        params.put("Version", "2011-01-01");
-        DescribePoliciesTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribePoliciesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = PoliciesType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = PoliciesTypeDeserializer::deserialize(
-                        "DescribePoliciesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_policies(input)
     }
-
-    /// <p>Describes one or more scaling activities for the specified Auto Scaling group.</p>
     fn describe_scaling_activities(
         &self,
         input: DescribeScalingActivitiesType,
     ) -> RusotoFuture<ActivitiesType, DescribeScalingActivitiesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeScalingActivities");
-        params.put("Version", "2011-01-01");
-        DescribeScalingActivitiesTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeScalingActivitiesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ActivitiesType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ActivitiesTypeDeserializer::deserialize(
-                        "DescribeScalingActivitiesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_scaling_activities(input)
     }
-
-    /// <p>Describes the scaling process types for use with <a>ResumeProcesses</a> and <a>SuspendProcesses</a>.</p>
     fn describe_scaling_process_types(
         &self,
     ) -> RusotoFuture<ProcessesType, DescribeScalingProcessTypesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeScalingProcessTypes");
-        params.put("Version", "2011-01-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeScalingProcessTypesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ProcessesType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ProcessesTypeDeserializer::deserialize(
-                        "DescribeScalingProcessTypesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_scaling_process_types()
     }
-
-    /// <p>Describes the actions scheduled for your Auto Scaling group that haven't run or that have not reached their end time. To describe the actions that have already run, use <a>DescribeScalingActivities</a>. </p>
     fn describe_scheduled_actions(
         &self,
         input: DescribeScheduledActionsType,
     ) -> RusotoFuture<ScheduledActionsType, DescribeScheduledActionsError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeScheduledActions");
-        params.put("Version", "2011-01-01");
-        DescribeScheduledActionsTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeScheduledActionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ScheduledActionsType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ScheduledActionsTypeDeserializer::deserialize(
-                        "DescribeScheduledActionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_scheduled_actions(input)
     }
-
-    /// <p>Describes the specified tags.</p> <p>You can use filters to limit the results. For example, you can query for the tags for a specific Auto Scaling group. You can specify multiple values for a filter. A tag must match at least one of the specified values for it to be included in the results.</p> <p>You can also specify multiple filters. The result includes information for a particular tag only if it matches all the filters. If there's no match, no special message is returned.</p>
     fn describe_tags(&self, input: DescribeTagsType) -> RusotoFuture<TagsType, DescribeTagsError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeTags");
-        params.put("Version", "2011-01-01");
-        DescribeTagsTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TagsType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TagsTypeDeserializer::deserialize("DescribeTagsResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_tags(input)
     }
-
-    /// <p>Describes the termination policies supported by Amazon EC2 Auto Scaling.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html">Controlling Which Auto Scaling Instances Terminate During Scale In</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn describe_termination_policy_types(
         &self,
     ) -> RusotoFuture<DescribeTerminationPolicyTypesAnswer, DescribeTerminationPolicyTypesError>
     {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeTerminationPolicyTypes");
-        params.put("Version", "2011-01-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeTerminationPolicyTypesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeTerminationPolicyTypesAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeTerminationPolicyTypesAnswerDeserializer::deserialize(
-                        "DescribeTerminationPolicyTypesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_termination_policy_types()
     }
-
-    /// <p>Removes one or more instances from the specified Auto Scaling group.</p> <p>After the instances are detached, you can manage them independent of the Auto Scaling group.</p> <p>If you do not specify the option to decrement the desired capacity, Amazon EC2 Auto Scaling launches instances to replace the ones that are detached.</p> <p>If there is a Classic Load Balancer attached to the Auto Scaling group, the instances are deregistered from the load balancer. If there are target groups attached to the Auto Scaling group, the instances are deregistered from the target groups.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/detach-instance-asg.html">Detach EC2 Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn detach_instances(
         &self,
         input: DetachInstancesQuery,
     ) -> RusotoFuture<DetachInstancesAnswer, DetachInstancesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DetachInstances");
-        params.put("Version", "2011-01-01");
-        DetachInstancesQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetachInstancesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DetachInstancesAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DetachInstancesAnswerDeserializer::deserialize(
-                        "DetachInstancesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).detach_instances(input)
     }
-
-    /// <p>Detaches one or more target groups from the specified Auto Scaling group.</p>
     fn detach_load_balancer_target_groups(
         &self,
         input: DetachLoadBalancerTargetGroupsType,
     ) -> RusotoFuture<DetachLoadBalancerTargetGroupsResultType, DetachLoadBalancerTargetGroupsError>
     {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DetachLoadBalancerTargetGroups");
-        params.put("Version", "2011-01-01");
-        DetachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DetachLoadBalancerTargetGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DetachLoadBalancerTargetGroupsResultType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DetachLoadBalancerTargetGroupsResultTypeDeserializer::deserialize(
-                        "DetachLoadBalancerTargetGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).detach_load_balancer_target_groups(input)
     }
-
-    /// <p>Detaches one or more Classic Load Balancers from the specified Auto Scaling group.</p> <p>This operation detaches only Classic Load Balancers. If you have Application Load Balancers or Network Load Balancers, use <a>DetachLoadBalancerTargetGroups</a> instead.</p> <p>When you detach a load balancer, it enters the <code>Removing</code> state while deregistering the instances in the group. When all instances are deregistered, then you can no longer describe the load balancer using <a>DescribeLoadBalancers</a>. The instances remain running.</p>
     fn detach_load_balancers(
         &self,
         input: DetachLoadBalancersType,
     ) -> RusotoFuture<DetachLoadBalancersResultType, DetachLoadBalancersError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DetachLoadBalancers");
-        params.put("Version", "2011-01-01");
-        DetachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DetachLoadBalancersError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DetachLoadBalancersResultType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DetachLoadBalancersResultTypeDeserializer::deserialize(
-                        "DetachLoadBalancersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).detach_load_balancers(input)
     }
-
-    /// <p>Disables group metrics for the specified Auto Scaling group.</p>
     fn disable_metrics_collection(
         &self,
         input: DisableMetricsCollectionQuery,
     ) -> RusotoFuture<(), DisableMetricsCollectionError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DisableMetricsCollection");
-        params.put("Version", "2011-01-01");
-        DisableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisableMetricsCollectionError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).disable_metrics_collection(input)
     }
-
-    /// <p>Enables group metrics for the specified Auto Scaling group. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-monitoring.html">Monitoring Your Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn enable_metrics_collection(
         &self,
         input: EnableMetricsCollectionQuery,
     ) -> RusotoFuture<(), EnableMetricsCollectionError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "EnableMetricsCollection");
-        params.put("Version", "2011-01-01");
-        EnableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(EnableMetricsCollectionError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).enable_metrics_collection(input)
     }
-
-    /// <p>Moves the specified instances into the standby state.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-enter-exit-standby.html">Temporarily Removing Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn enter_standby(
         &self,
         input: EnterStandbyQuery,
     ) -> RusotoFuture<EnterStandbyAnswer, EnterStandbyError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "EnterStandby");
-        params.put("Version", "2011-01-01");
-        EnterStandbyQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnterStandbyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EnterStandbyAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EnterStandbyAnswerDeserializer::deserialize(
-                        "EnterStandbyResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).enter_standby(input)
     }
-
-    /// <p>Executes the specified policy.</p>
     fn execute_policy(&self, input: ExecutePolicyType) -> RusotoFuture<(), ExecutePolicyError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ExecutePolicy");
-        params.put("Version", "2011-01-01");
-        ExecutePolicyTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ExecutePolicyError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).execute_policy(input)
     }
-
-    /// <p>Moves the specified instances out of the standby state.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-enter-exit-standby.html">Temporarily Removing Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn exit_standby(
         &self,
         input: ExitStandbyQuery,
     ) -> RusotoFuture<ExitStandbyAnswer, ExitStandbyError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ExitStandby");
-        params.put("Version", "2011-01-01");
-        ExitStandbyQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ExitStandbyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ExitStandbyAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ExitStandbyAnswerDeserializer::deserialize(
-                        "ExitStandbyResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).exit_standby(input)
     }
-
-    /// <p>Creates or updates a lifecycle hook for the specified Auto Scaling group.</p> <p>A lifecycle hook tells Amazon EC2 Auto Scaling to perform an action on an instance when the instance launches (before it is put into service) or as the instance terminates (before it is fully terminated).</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p> <b>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</b> </p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state using <a>RecordLifecycleActionHeartbeat</a>.</p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action using <a>CompleteLifecycleAction</a>.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>If you exceed your maximum limit of lifecycle hooks, which by default is 50 per Auto Scaling group, the call fails.</p> <p>You can view the lifecycle hooks for an Auto Scaling group using <a>DescribeLifecycleHooks</a>. If you are no longer using a lifecycle hook, you can delete it using <a>DeleteLifecycleHook</a>.</p>
     fn put_lifecycle_hook(
         &self,
         input: PutLifecycleHookType,
     ) -> RusotoFuture<PutLifecycleHookAnswer, PutLifecycleHookError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "PutLifecycleHook");
-        params.put("Version", "2011-01-01");
-        PutLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutLifecycleHookError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = PutLifecycleHookAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = PutLifecycleHookAnswerDeserializer::deserialize(
-                        "PutLifecycleHookResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).put_lifecycle_hook(input)
     }
-
-    /// <p>Configures an Auto Scaling group to send notifications when specified events take place. Subscribers to the specified topic can have messages delivered to an endpoint such as a web server or an email address.</p> <p>This configuration overwrites any existing configuration.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/ASGettingNotifications.html">Getting Amazon SNS Notifications When Your Auto Scaling Group Scales</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn put_notification_configuration(
         &self,
         input: PutNotificationConfigurationType,
     ) -> RusotoFuture<(), PutNotificationConfigurationError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "PutNotificationConfiguration");
-        params.put("Version", "2011-01-01");
-        PutNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutNotificationConfigurationError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).put_notification_configuration(input)
     }
-
-    /// <p>Creates or updates a scaling policy for an Auto Scaling group. To update an existing scaling policy, use the existing policy name and set the parameters to change. Any existing parameter not changed in an update to an existing policy is not changed in this update request.</p> <p>For more information about using scaling policies to scale your Auto Scaling group automatically, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-scale-based-on-demand.html">Dynamic Scaling</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn put_scaling_policy(
         &self,
         input: PutScalingPolicyType,
     ) -> RusotoFuture<PolicyARNType, PutScalingPolicyError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "PutScalingPolicy");
-        params.put("Version", "2011-01-01");
-        PutScalingPolicyTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutScalingPolicyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = PolicyARNType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = PolicyARNTypeDeserializer::deserialize(
-                        "PutScalingPolicyResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).put_scaling_policy(input)
     }
-
-    /// <p>Creates or updates a scheduled scaling action for an Auto Scaling group. If you leave a parameter unspecified when updating a scheduled scaling action, the corresponding value remains unchanged.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/schedule_time.html">Scheduled Scaling</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn put_scheduled_update_group_action(
         &self,
         input: PutScheduledUpdateGroupActionType,
     ) -> RusotoFuture<(), PutScheduledUpdateGroupActionError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "PutScheduledUpdateGroupAction");
-        params.put("Version", "2011-01-01");
-        PutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
+        (**self).put_scheduled_update_group_action(input)
+    }
+    fn record_lifecycle_action_heartbeat(
+        &self,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> RusotoFuture<RecordLifecycleActionHeartbeatAnswer, RecordLifecycleActionHeartbeatError>
+    {
+        (**self).record_lifecycle_action_heartbeat(input)
+    }
+    fn resume_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> RusotoFuture<(), ResumeProcessesError> {
+        (**self).resume_processes(input)
+    }
+    fn set_desired_capacity(
+        &self,
+        input: SetDesiredCapacityType,
+    ) -> RusotoFuture<(), SetDesiredCapacityError> {
+        (**self).set_desired_capacity(input)
+    }
+    fn set_instance_health(
+        &self,
+        input: SetInstanceHealthQuery,
+    ) -> RusotoFuture<(), SetInstanceHealthError> {
+        (**self).set_instance_health(input)
+    }
+    fn set_instance_protection(
+        &self,
+        input: SetInstanceProtectionQuery,
+    ) -> RusotoFuture<SetInstanceProtectionAnswer, SetInstanceProtectionError> {
+        (**self).set_instance_protection(input)
+    }
+    fn suspend_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> RusotoFuture<(), SuspendProcessesError> {
+        (**self).suspend_processes(input)
+    }
+    fn terminate_instance_in_auto_scaling_group(
+        &self,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> RusotoFuture<ActivityType, TerminateInstanceInAutoScalingGroupError> {
+        (**self).terminate_instance_in_auto_scaling_group(input)
+    }
+    fn update_auto_scaling_group(
+        &self,
+        input: UpdateAutoScalingGroupType,
+    ) -> RusotoFuture<(), UpdateAutoScalingGroupError> {
+        (**self).update_auto_scaling_group(input)
+    }
+}
+impl<T: Autoscaling + ?Sized> Autoscaling for Box<T> {
+    fn attach_instances(
+        &self,
+        input: AttachInstancesQuery,
+    ) -> RusotoFuture<(), AttachInstancesError> {
+        (**self).attach_instances(input)
+    }
+    fn attach_load_balancer_target_groups(
+        &self,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> RusotoFuture<AttachLoadBalancerTargetGroupsResultType, AttachLoadBalancerTargetGroupsError>
+    {
+        (**self).attach_load_balancer_target_groups(input)
+    }
+    fn attach_load_balancers(
+        &self,
+        input: AttachLoadBalancersType,
+    ) -> RusotoFuture<AttachLoadBalancersResultType, AttachLoadBalancersError> {
+        (**self).attach_load_balancers(input)
+    }
+    fn batch_delete_scheduled_action(
+        &self,
+        input: BatchDeleteScheduledActionType,
+    ) -> RusotoFuture<BatchDeleteScheduledActionAnswer, BatchDeleteScheduledActionError> {
+        (**self).batch_delete_scheduled_action(input)
+    }
+    fn batch_put_scheduled_update_group_action(
+        &self,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> RusotoFuture<
+        BatchPutScheduledUpdateGroupActionAnswer,
+        BatchPutScheduledUpdateGroupActionError,
+    > {
+        (**self).batch_put_scheduled_update_group_action(input)
+    }
+    fn complete_lifecycle_action(
+        &self,
+        input: CompleteLifecycleActionType,
+    ) -> RusotoFuture<CompleteLifecycleActionAnswer, CompleteLifecycleActionError> {
+        (**self).complete_lifecycle_action(input)
+    }
+    fn create_auto_scaling_group(
+        &self,
+        input: CreateAutoScalingGroupType,
+    ) -> RusotoFuture<(), CreateAutoScalingGroupError> {
+        (**self).create_auto_scaling_group(input)
+    }
+    fn create_launch_configuration(
+        &self,
+        input: CreateLaunchConfigurationType,
+    ) -> RusotoFuture<(), CreateLaunchConfigurationError> {
+        (**self).create_launch_configuration(input)
+    }
+    fn create_or_update_tags(
+        &self,
+        input: CreateOrUpdateTagsType,
+    ) -> RusotoFuture<(), CreateOrUpdateTagsError> {
+        (**self).create_or_update_tags(input)
+    }
+    fn delete_auto_scaling_group(
+        &self,
+        input: DeleteAutoScalingGroupType,
+    ) -> RusotoFuture<(), DeleteAutoScalingGroupError> {
+        (**self).delete_auto_scaling_group(input)
+    }
+    fn delete_launch_configuration(
+        &self,
+        input: LaunchConfigurationNameType,
+    ) -> RusotoFuture<(), DeleteLaunchConfigurationError> {
+        (**self).delete_launch_configuration(input)
+    }
+    fn delete_lifecycle_hook(
+        &self,
+        input: DeleteLifecycleHookType,
+    ) -> RusotoFuture<DeleteLifecycleHookAnswer, DeleteLifecycleHookError> {
+        (**self).delete_lifecycle_hook(input)
+    }
+    fn delete_notification_configuration(
+        &self,
+        input: DeleteNotificationConfigurationType,
+    ) -> RusotoFuture<(), DeleteNotificationConfigurationError> {
+        (**self).delete_notification_configuration(input)
+    }
+    fn delete_policy(&self, input: DeletePolicyType) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionType,
+    ) -> RusotoFuture<(), DeleteScheduledActionError> {
+        (**self).delete_scheduled_action(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsType) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_account_limits(
+        &self,
+    ) -> RusotoFuture<DescribeAccountLimitsAnswer, DescribeAccountLimitsError> {
+        (**self).describe_account_limits()
+    }
+    fn describe_adjustment_types(
+        &self,
+    ) -> RusotoFuture<DescribeAdjustmentTypesAnswer, DescribeAdjustmentTypesError> {
+        (**self).describe_adjustment_types()
+    }
+    fn describe_auto_scaling_groups(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> RusotoFuture<AutoScalingGroupsType, DescribeAutoScalingGroupsError> {
+        (**self).describe_auto_scaling_groups(input)
+    }
+    fn describe_auto_scaling_instances(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> RusotoFuture<AutoScalingInstancesType, DescribeAutoScalingInstancesError> {
+        (**self).describe_auto_scaling_instances(input)
+    }
+    fn describe_auto_scaling_notification_types(
+        &self,
+    ) -> RusotoFuture<
+        DescribeAutoScalingNotificationTypesAnswer,
+        DescribeAutoScalingNotificationTypesError,
+    > {
+        (**self).describe_auto_scaling_notification_types()
+    }
+    fn describe_launch_configurations(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> RusotoFuture<LaunchConfigurationsType, DescribeLaunchConfigurationsError> {
+        (**self).describe_launch_configurations(input)
+    }
+    fn describe_lifecycle_hook_types(
+        &self,
+    ) -> RusotoFuture<DescribeLifecycleHookTypesAnswer, DescribeLifecycleHookTypesError> {
+        (**self).describe_lifecycle_hook_types()
+    }
+    fn describe_lifecycle_hooks(
+        &self,
+        input: DescribeLifecycleHooksType,
+    ) -> RusotoFuture<DescribeLifecycleHooksAnswer, DescribeLifecycleHooksError> {
+        (**self).describe_lifecycle_hooks(input)
+    }
+    fn describe_load_balancer_target_groups(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> RusotoFuture<DescribeLoadBalancerTargetGroupsResponse, DescribeLoadBalancerTargetGroupsError>
+    {
+        (**self).describe_load_balancer_target_groups(input)
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> RusotoFuture<DescribeLoadBalancersResponse, DescribeLoadBalancersError> {
+        (**self).describe_load_balancers(input)
+    }
+    fn describe_metric_collection_types(
+        &self,
+    ) -> RusotoFuture<DescribeMetricCollectionTypesAnswer, DescribeMetricCollectionTypesError> {
+        (**self).describe_metric_collection_types()
+    }
+    fn describe_notification_configurations(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> RusotoFuture<
+        DescribeNotificationConfigurationsAnswer,
+        DescribeNotificationConfigurationsError,
+    > {
+        (**self).describe_notification_configurations(input)
+    }
+    fn describe_policies(
+        &self,
+        input: DescribePoliciesType,
+    ) -> RusotoFuture<PoliciesType, DescribePoliciesError> {
+        (**self).describe_policies(input)
+    }
+    fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> RusotoFuture<ActivitiesType, DescribeScalingActivitiesError> {
+        (**self).describe_scaling_activities(input)
+    }
+    fn describe_scaling_process_types(
+        &self,
+    ) -> RusotoFuture<ProcessesType, DescribeScalingProcessTypesError> {
+        (**self).describe_scaling_process_types()
+    }
+    fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> RusotoFuture<ScheduledActionsType, DescribeScheduledActionsError> {
+        (**self).describe_scheduled_actions(input)
+    }
+    fn describe_tags(&self, input: DescribeTagsType) -> RusotoFuture<TagsType, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_termination_policy_types(
+        &self,
+    ) -> RusotoFuture<DescribeTerminationPolicyTypesAnswer, DescribeTerminationPolicyTypesError>
+    {
+        (**self).describe_termination_policy_types()
+    }
+    fn detach_instances(
+        &self,
+        input: DetachInstancesQuery,
+    ) -> RusotoFuture<DetachInstancesAnswer, DetachInstancesError> {
+        (**self).detach_instances(input)
+    }
+    fn detach_load_balancer_target_groups(
+        &self,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> RusotoFuture<DetachLoadBalancerTargetGroupsResultType, DetachLoadBalancerTargetGroupsError>
+    {
+        (**self).detach_load_balancer_target_groups(input)
+    }
+    fn detach_load_balancers(
+        &self,
+        input: DetachLoadBalancersType,
+    ) -> RusotoFuture<DetachLoadBalancersResultType, DetachLoadBalancersError> {
+        (**self).detach_load_balancers(input)
+    }
+    fn disable_metrics_collection(
+        &self,
+        input: DisableMetricsCollectionQuery,
+    ) -> RusotoFuture<(), DisableMetricsCollectionError> {
+        (**self).disable_metrics_collection(input)
+    }
+    fn enable_metrics_collection(
+        &self,
+        input: EnableMetricsCollectionQuery,
+    ) -> RusotoFuture<(), EnableMetricsCollectionError> {
+        (**self).enable_metrics_collection(input)
+    }
+    fn enter_standby(
+        &self,
+        input: EnterStandbyQuery,
+    ) -> RusotoFuture<EnterStandbyAnswer, EnterStandbyError> {
+        (**self).enter_standby(input)
+    }
+    fn execute_policy(&self, input: ExecutePolicyType) -> RusotoFuture<(), ExecutePolicyError> {
+        (**self).execute_policy(input)
+    }
+    fn exit_standby(
+        &self,
+        input: ExitStandbyQuery,
+    ) -> RusotoFuture<ExitStandbyAnswer, ExitStandbyError> {
+        (**self).exit_standby(input)
+    }
+    fn put_lifecycle_hook(
+        &self,
+        input: PutLifecycleHookType,
+    ) -> RusotoFuture<PutLifecycleHookAnswer, PutLifecycleHookError> {
+        (**self).put_lifecycle_hook(input)
+    }
+    fn put_notification_configuration(
+        &self,
+        input: PutNotificationConfigurationType,
+    ) -> RusotoFuture<(), PutNotificationConfigurationError> {
+        (**self).put_notification_configuration(input)
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyType,
+    ) -> RusotoFuture<PolicyARNType, PutScalingPolicyError> {
+        (**self).put_scaling_policy(input)
+    }
+    fn put_scheduled_update_group_action(
+        &self,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> RusotoFuture<(), PutScheduledUpdateGroupActionError> {
+        (**self).put_scheduled_update_group_action(input)
+    }
+    fn record_lifecycle_action_heartbeat(
+        &self,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> RusotoFuture<RecordLifecycleActionHeartbeatAnswer, RecordLifecycleActionHeartbeatError>
+    {
+        (**self).record_lifecycle_action_heartbeat(input)
+    }
+    fn resume_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> RusotoFuture<(), ResumeProcessesError> {
+        (**self).resume_processes(input)
+    }
+    fn set_desired_capacity(
+        &self,
+        input: SetDesiredCapacityType,
+    ) -> RusotoFuture<(), SetDesiredCapacityError> {
+        (**self).set_desired_capacity(input)
+    }
+    fn set_instance_health(
+        &self,
+        input: SetInstanceHealthQuery,
+    ) -> RusotoFuture<(), SetInstanceHealthError> {
+        (**self).set_instance_health(input)
+    }
+    fn set_instance_protection(
+        &self,
+        input: SetInstanceProtectionQuery,
+    ) -> RusotoFuture<SetInstanceProtectionAnswer, SetInstanceProtectionError> {
+        (**self).set_instance_protection(input)
+    }
+    fn suspend_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> RusotoFuture<(), SuspendProcessesError> {
+        (**self).suspend_processes(input)
+    }
+    fn terminate_instance_in_auto_scaling_group(
+        &self,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> RusotoFuture<ActivityType, TerminateInstanceInAutoScalingGroupError> {
+        (**self).terminate_instance_in_auto_scaling_group(input)
+    }
+    fn update_auto_scaling_group(
+        &self,
+        input: UpdateAutoScalingGroupType,
+    ) -> RusotoFuture<(), UpdateAutoScalingGroupError> {
+        (**self).update_auto_scaling_group(input)
+    }
+}
+impl<T: Autoscaling + ?Sized> Autoscaling for ::std::sync::Arc<T> {
+    fn attach_instances(
+        &self,
+        input: AttachInstancesQuery,
+    ) -> RusotoFuture<(), AttachInstancesError> {
+        (**self).attach_instances(input)
+    }
+    fn attach_load_balancer_target_groups(
+        &self,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> RusotoFuture<AttachLoadBalancerTargetGroupsResultType, AttachLoadBalancerTargetGroupsError>
+    {
+        (**self).attach_load_balancer_target_groups(input)
+    }
+    fn attach_load_balancers(
+        &self,
+        input: AttachLoadBalancersType,
+    ) -> RusotoFuture<AttachLoadBalancersResultType, AttachLoadBalancersError> {
+        (**self).attach_load_balancers(input)
+    }
+    fn batch_delete_scheduled_action(
+        &self,
+        input: BatchDeleteScheduledActionType,
+    ) -> RusotoFuture<BatchDeleteScheduledActionAnswer, BatchDeleteScheduledActionError> {
+        (**self).batch_delete_scheduled_action(input)
+    }
+    fn batch_put_scheduled_update_group_action(
+        &self,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> RusotoFuture<
+        BatchPutScheduledUpdateGroupActionAnswer,
+        BatchPutScheduledUpdateGroupActionError,
+    > {
+        (**self).batch_put_scheduled_update_group_action(input)
+    }
+    fn complete_lifecycle_action(
+        &self,
+        input: CompleteLifecycleActionType,
+    ) -> RusotoFuture<CompleteLifecycleActionAnswer, CompleteLifecycleActionError> {
+        (**self).complete_lifecycle_action(input)
+    }
+    fn create_auto_scaling_group(
+        &self,
+        input: CreateAutoScalingGroupType,
+    ) -> RusotoFuture<(), CreateAutoScalingGroupError> {
+        (**self).create_auto_scaling_group(input)
+    }
+    fn create_launch_configuration(
+        &self,
+        input: CreateLaunchConfigurationType,
+    ) -> RusotoFuture<(), CreateLaunchConfigurationError> {
+        (**self).create_launch_configuration(input)
+    }
+    fn create_or_update_tags(
+        &self,
+        input: CreateOrUpdateTagsType,
+    ) -> RusotoFuture<(), CreateOrUpdateTagsError> {
+        (**self).create_or_update_tags(input)
+    }
+    fn delete_auto_scaling_group(
+        &self,
+        input: DeleteAutoScalingGroupType,
+    ) -> RusotoFuture<(), DeleteAutoScalingGroupError> {
+        (**self).delete_auto_scaling_group(input)
+    }
+    fn delete_launch_configuration(
+        &self,
+        input: LaunchConfigurationNameType,
+    ) -> RusotoFuture<(), DeleteLaunchConfigurationError> {
+        (**self).delete_launch_configuration(input)
+    }
+    fn delete_lifecycle_hook(
+        &self,
+        input: DeleteLifecycleHookType,
+    ) -> RusotoFuture<DeleteLifecycleHookAnswer, DeleteLifecycleHookError> {
+        (**self).delete_lifecycle_hook(input)
+    }
+    fn delete_notification_configuration(
+        &self,
+        input: DeleteNotificationConfigurationType,
+    ) -> RusotoFuture<(), DeleteNotificationConfigurationError> {
+        (**self).delete_notification_configuration(input)
+    }
+    fn delete_policy(&self, input: DeletePolicyType) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionType,
+    ) -> RusotoFuture<(), DeleteScheduledActionError> {
+        (**self).delete_scheduled_action(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsType) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_account_limits(
+        &self,
+    ) -> RusotoFuture<DescribeAccountLimitsAnswer, DescribeAccountLimitsError> {
+        (**self).describe_account_limits()
+    }
+    fn describe_adjustment_types(
+        &self,
+    ) -> RusotoFuture<DescribeAdjustmentTypesAnswer, DescribeAdjustmentTypesError> {
+        (**self).describe_adjustment_types()
+    }
+    fn describe_auto_scaling_groups(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> RusotoFuture<AutoScalingGroupsType, DescribeAutoScalingGroupsError> {
+        (**self).describe_auto_scaling_groups(input)
+    }
+    fn describe_auto_scaling_instances(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> RusotoFuture<AutoScalingInstancesType, DescribeAutoScalingInstancesError> {
+        (**self).describe_auto_scaling_instances(input)
+    }
+    fn describe_auto_scaling_notification_types(
+        &self,
+    ) -> RusotoFuture<
+        DescribeAutoScalingNotificationTypesAnswer,
+        DescribeAutoScalingNotificationTypesError,
+    > {
+        (**self).describe_auto_scaling_notification_types()
+    }
+    fn describe_launch_configurations(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> RusotoFuture<LaunchConfigurationsType, DescribeLaunchConfigurationsError> {
+        (**self).describe_launch_configurations(input)
+    }
+    fn describe_lifecycle_hook_types(
+        &self,
+    ) -> RusotoFuture<DescribeLifecycleHookTypesAnswer, DescribeLifecycleHookTypesError> {
+        (**self).describe_lifecycle_hook_types()
+    }
+    fn describe_lifecycle_hooks(
+        &self,
+        input: DescribeLifecycleHooksType,
+    ) -> RusotoFuture<DescribeLifecycleHooksAnswer, DescribeLifecycleHooksError> {
+        (**self).describe_lifecycle_hooks(input)
+    }
+    fn describe_load_balancer_target_groups(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> RusotoFuture<DescribeLoadBalancerTargetGroupsResponse, DescribeLoadBalancerTargetGroupsError>
+    {
+        (**self).describe_load_balancer_target_groups(input)
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> RusotoFuture<DescribeLoadBalancersResponse, DescribeLoadBalancersError> {
+        (**self).describe_load_balancers(input)
+    }
+    fn describe_metric_collection_types(
+        &self,
+    ) -> RusotoFuture<DescribeMetricCollectionTypesAnswer, DescribeMetricCollectionTypesError> {
+        (**self).describe_metric_collection_types()
+    }
+    fn describe_notification_configurations(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> RusotoFuture<
+        DescribeNotificationConfigurationsAnswer,
+        DescribeNotificationConfigurationsError,
+    > {
+        (**self).describe_notification_configurations(input)
+    }
+    fn describe_policies(
+        &self,
+        input: DescribePoliciesType,
+    ) -> RusotoFuture<PoliciesType, DescribePoliciesError> {
+        (**self).describe_policies(input)
+    }
+    fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> RusotoFuture<ActivitiesType, DescribeScalingActivitiesError> {
+        (**self).describe_scaling_activities(input)
+    }
+    fn describe_scaling_process_types(
+        &self,
+    ) -> RusotoFuture<ProcessesType, DescribeScalingProcessTypesError> {
+        (**self).describe_scaling_process_types()
+    }
+    fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> RusotoFuture<ScheduledActionsType, DescribeScheduledActionsError> {
+        (**self).describe_scheduled_actions(input)
+    }
+    fn describe_tags(&self, input: DescribeTagsType) -> RusotoFuture<TagsType, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_termination_policy_types(
+        &self,
+    ) -> RusotoFuture<DescribeTerminationPolicyTypesAnswer, DescribeTerminationPolicyTypesError>
+    {
+        (**self).describe_termination_policy_types()
+    }
+    fn detach_instances(
+        &self,
+        input: DetachInstancesQuery,
+    ) -> RusotoFuture<DetachInstancesAnswer, DetachInstancesError> {
+        (**self).detach_instances(input)
+    }
+    fn detach_load_balancer_target_groups(
+        &self,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> RusotoFuture<DetachLoadBalancerTargetGroupsResultType, DetachLoadBalancerTargetGroupsError>
+    {
+        (**self).detach_load_balancer_target_groups(input)
+    }
+    fn detach_load_balancers(
+        &self,
+        input: DetachLoadBalancersType,
+    ) -> RusotoFuture<DetachLoadBalancersResultType, DetachLoadBalancersError> {
+        (**self).detach_load_balancers(input)
+    }
+    fn disable_metrics_collection(
+        &self,
+        input: DisableMetricsCollectionQuery,
+    ) -> RusotoFuture<(), DisableMetricsCollectionError> {
+        (**self).disable_metrics_collection(input)
+    }
+    fn enable_metrics_collection(
+        &self,
+        input: EnableMetricsCollectionQuery,
+    ) -> RusotoFuture<(), EnableMetricsCollectionError> {
+        (**self).enable_metrics_collection(input)
+    }
+    fn enter_standby(
+        &self,
+        input: EnterStandbyQuery,
+    ) -> RusotoFuture<EnterStandbyAnswer, EnterStandbyError> {
+        (**self).enter_standby(input)
+    }
+    fn execute_policy(&self, input: ExecutePolicyType) -> RusotoFuture<(), ExecutePolicyError> {
+        (**self).execute_policy(input)
+    }
+    fn exit_standby(
+        &self,
+        input: ExitStandbyQuery,
+    ) -> RusotoFuture<ExitStandbyAnswer, ExitStandbyError> {
+        (**self).exit_standby(input)
+    }
+    fn put_lifecycle_hook(
+        &self,
+        input: PutLifecycleHookType,
+    ) -> RusotoFuture<PutLifecycleHookAnswer, PutLifecycleHookError> {
+        (**self).put_lifecycle_hook(input)
+    }
+    fn put_notification_configuration(
+        &self,
+        input: PutNotificationConfigurationType,
+    ) -> RusotoFuture<(), PutNotificationConfigurationError> {
+        (**self).put_notification_configuration(input)
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyType,
+    ) -> RusotoFuture<PolicyARNType, PutScalingPolicyError> {
+        (**self).put_scaling_policy(input)
+    }
+    fn put_scheduled_update_group_action(
+        &self,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> RusotoFuture<(), PutScheduledUpdateGroupActionError> {
+        (**self).put_scheduled_update_group_action(input)
+    }
+    fn record_lifecycle_action_heartbeat(
+        &self,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> RusotoFuture<RecordLifecycleActionHeartbeatAnswer, RecordLifecycleActionHeartbeatError>
+    {
+        (**self).record_lifecycle_action_heartbeat(input)
+    }
+    fn resume_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> RusotoFuture<(), ResumeProcessesError> {
+        (**self).resume_processes(input)
+    }
+    fn set_desired_capacity(
+        &self,
+        input: SetDesiredCapacityType,
+    ) -> RusotoFuture<(), SetDesiredCapacityError> {
+        (**self).set_desired_capacity(input)
+    }
+    fn set_instance_health(
+        &self,
+        input: SetInstanceHealthQuery,
+    ) -> RusotoFuture<(), SetInstanceHealthError> {
+        (**self).set_instance_health(input)
+    }
+    fn set_instance_protection(
+        &self,
+        input: SetInstanceProtectionQuery,
+    ) -> RusotoFuture<SetInstanceProtectionAnswer, SetInstanceProtectionError> {
+        (**self).set_instance_protection(input)
+    }
+    fn suspend_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> RusotoFuture<(), SuspendProcessesError> {
+        (**self).suspend_processes(input)
+    }
+    fn terminate_instance_in_auto_scaling_group(
+        &self,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> RusotoFuture<ActivityType, TerminateInstanceInAutoScalingGroupError> {
+        (**self).terminate_instance_in_auto_scaling_group(input)
+    }
+    fn update_auto_scaling_group(
+        &self,
+        input: UpdateAutoScalingGroupType,
+    ) -> RusotoFuture<(), UpdateAutoScalingGroupError> {
+        (**self).update_auto_scaling_group(input)
+    }
+}
+/// A client for the Auto Scaling API.
+#[derive(Clone)]
+pub struct AutoscalingClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl AutoscalingClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AutoscalingClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AutoscalingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingClient {
+        AutoscalingClient { client, region }
+    }
+}
+
+impl Autoscaling for AutoscalingClient {
+    /// <p>Attaches one or more EC2 instances to the specified Auto Scaling group.</p> <p>When you attach instances, Amazon EC2 Auto Scaling increases the desired capacity of the group by the number of instances being attached. If the number of instances being attached plus the desired capacity of the group exceeds the maximum size of the group, the operation fails.</p> <p>If there is a Classic Load Balancer attached to your Auto Scaling group, the instances are also registered with the load balancer. If there are target groups attached to your Auto Scaling group, the instances are also registered with the target groups.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-instance-asg.html">Attach EC2 Instances to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn attach_instances(
+        &self,
+        input: AttachInstancesQuery,
+    ) -> RusotoFuture<(), AttachInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachInstances");
+        let mut params = Params::new();
+
+        params.put("Action", "AttachInstances");
+        params.put("Version", "2011-01-01");
+        AttachInstancesQuerySerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AttachInstancesError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Attaches one or more target groups to the specified Auto Scaling group. </p> <p>To describe the target groups for an Auto Scaling group, use <a>DescribeLoadBalancerTargetGroups</a>. To detach the target group from the Auto Scaling group, use <a>DetachLoadBalancerTargetGroups</a>.</p> <p>With Application Load Balancers and Network Load Balancers, instances are registered as targets with a target group. With Classic Load Balancers, instances are registered with the load balancer. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-load-balancer-asg.html">Attaching a Load Balancer to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn attach_load_balancer_target_groups(
+        &self,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> RusotoFuture<AttachLoadBalancerTargetGroupsResultType, AttachLoadBalancerTargetGroupsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachLoadBalancerTargetGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "AttachLoadBalancerTargetGroups");
+        params.put("Version", "2011-01-01");
+        AttachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AttachLoadBalancerTargetGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AttachLoadBalancerTargetGroupsResultType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AttachLoadBalancerTargetGroupsResultTypeDeserializer::deserialize(
+                        "AttachLoadBalancerTargetGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("AttachLoadBalancerTargetGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Attaches one or more Classic Load Balancers to the specified Auto Scaling group.</p> <p>To attach an Application Load Balancer or a Network Load Balancer instead, see <a>AttachLoadBalancerTargetGroups</a>.</p> <p>To describe the load balancers for an Auto Scaling group, use <a>DescribeLoadBalancers</a>. To detach the load balancer from the Auto Scaling group, use <a>DetachLoadBalancers</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-load-balancer-asg.html">Attaching a Load Balancer to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn attach_load_balancers(
+        &self,
+        input: AttachLoadBalancersType,
+    ) -> RusotoFuture<AttachLoadBalancersResultType, AttachLoadBalancersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachLoadBalancers");
+        let mut params = Params::new();
+
+        params.put("Action", "AttachLoadBalancers");
+        params.put("Version", "2011-01-01");
+        AttachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AttachLoadBalancersError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AttachLoadBalancersResultType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AttachLoadBalancersResultTypeDeserializer::deserialize(
+                        "AttachLoadBalancersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("AttachLoadBalancers", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes one or more scheduled actions for the specified Auto Scaling group.</p>
+    fn batch_delete_scheduled_action(
+        &self,
+        input: BatchDeleteScheduledActionType,
+    ) -> RusotoFuture<BatchDeleteScheduledActionAnswer, BatchDeleteScheduledActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("BatchDeleteScheduledAction");
+        let mut params = Params::new();
+
+        params.put("Action", "BatchDeleteScheduledAction");
+        params.put("Version", "2011-01-01");
+        BatchDeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchDeleteScheduledActionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = BatchDeleteScheduledActionAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = BatchDeleteScheduledActionAnswerDeserializer::deserialize(
+                        "BatchDeleteScheduledActionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("BatchDeleteScheduledAction", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates or updates one or more scheduled scaling actions for an Auto Scaling group. If you leave a parameter unspecified when updating a scheduled scaling action, the corresponding value remains unchanged.</p>
+    fn batch_put_scheduled_update_group_action(
+        &self,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> RusotoFuture<
+        BatchPutScheduledUpdateGroupActionAnswer,
+        BatchPutScheduledUpdateGroupActionError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("BatchPutScheduledUpdateGroupAction");
+        let mut params = Params::new();
+
+        params.put("Action", "BatchPutScheduledUpdateGroupAction");
+        params.put("Version", "2011-01-01");
+        BatchPutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchPutScheduledUpdateGroupActionError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = BatchPutScheduledUpdateGroupActionAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = BatchPutScheduledUpdateGroupActionAnswerDeserializer::deserialize(
+                        "BatchPutScheduledUpdateGroupActionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("BatchPutScheduledUpdateGroupAction", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Completes the lifecycle action for the specified token or instance with the specified result.</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state.</p> </li> <li> <p> <b>If you finish before the timeout period ends, complete the lifecycle action.</b> </p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn complete_lifecycle_action(
+        &self,
+        input: CompleteLifecycleActionType,
+    ) -> RusotoFuture<CompleteLifecycleActionAnswer, CompleteLifecycleActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CompleteLifecycleAction");
+        let mut params = Params::new();
+
+        params.put("Action", "CompleteLifecycleAction");
+        params.put("Version", "2011-01-01");
+        CompleteLifecycleActionTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CompleteLifecycleActionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CompleteLifecycleActionAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CompleteLifecycleActionAnswerDeserializer::deserialize(
+                        "CompleteLifecycleActionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CompleteLifecycleAction", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates an Auto Scaling group with the specified name and attributes.</p> <p>If you exceed your maximum limit of Auto Scaling groups, the call fails. For information about viewing this limit, see <a>DescribeAccountLimits</a>. For information about updating this limit, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn create_auto_scaling_group(
+        &self,
+        input: CreateAutoScalingGroupType,
+    ) -> RusotoFuture<(), CreateAutoScalingGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateAutoScalingGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateAutoScalingGroup");
+        params.put("Version", "2011-01-01");
+        CreateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateAutoScalingGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Creates a launch configuration.</p> <p>If you exceed your maximum limit of launch configurations, the call fails. For information about viewing this limit, see <a>DescribeAccountLimits</a>. For information about updating this limit, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/LaunchConfiguration.html">Launch Configurations</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn create_launch_configuration(
+        &self,
+        input: CreateLaunchConfigurationType,
+    ) -> RusotoFuture<(), CreateLaunchConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateLaunchConfiguration");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateLaunchConfiguration");
+        params.put("Version", "2011-01-01");
+        CreateLaunchConfigurationTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateLaunchConfigurationError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Creates or updates tags for the specified Auto Scaling group.</p> <p>When you specify a tag with a key that already exists, the operation overwrites the previous tag definition, and you do not get an error message.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/autoscaling-tagging.html">Tagging Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn create_or_update_tags(
+        &self,
+        input: CreateOrUpdateTagsType,
+    ) -> RusotoFuture<(), CreateOrUpdateTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateOrUpdateTags");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateOrUpdateTags");
+        params.put("Version", "2011-01-01");
+        CreateOrUpdateTagsTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateOrUpdateTagsError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified Auto Scaling group.</p> <p>If the group has instances or scaling activities in progress, you must specify the option to force the deletion in order for it to succeed.</p> <p>If the group has policies, deleting the group deletes the policies, the underlying alarm actions, and any alarm that no longer has an associated action.</p> <p>To remove instances from the Auto Scaling group before deleting it, call <a>DetachInstances</a> with the list of instances and the option to decrement the desired capacity. This ensures that Amazon EC2 Auto Scaling does not launch replacement instances.</p> <p>To terminate all instances before deleting the Auto Scaling group, call <a>UpdateAutoScalingGroup</a> and set the minimum size and desired capacity of the Auto Scaling group to zero.</p>
+    fn delete_auto_scaling_group(
+        &self,
+        input: DeleteAutoScalingGroupType,
+    ) -> RusotoFuture<(), DeleteAutoScalingGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteAutoScalingGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteAutoScalingGroup");
+        params.put("Version", "2011-01-01");
+        DeleteAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteAutoScalingGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified launch configuration.</p> <p>The launch configuration must not be attached to an Auto Scaling group. When this call completes, the launch configuration is no longer available for use.</p>
+    fn delete_launch_configuration(
+        &self,
+        input: LaunchConfigurationNameType,
+    ) -> RusotoFuture<(), DeleteLaunchConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteLaunchConfiguration");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteLaunchConfiguration");
+        params.put("Version", "2011-01-01");
+        LaunchConfigurationNameTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteLaunchConfigurationError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified lifecycle hook.</p> <p>If there are any outstanding lifecycle actions, they are completed first (<code>ABANDON</code> for launching instances, <code>CONTINUE</code> for terminating instances).</p>
+    fn delete_lifecycle_hook(
+        &self,
+        input: DeleteLifecycleHookType,
+    ) -> RusotoFuture<DeleteLifecycleHookAnswer, DeleteLifecycleHookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteLifecycleHook");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteLifecycleHook");
+        params.put("Version", "2011-01-01");
+        DeleteLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteLifecycleHookError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteLifecycleHookAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteLifecycleHookAnswerDeserializer::deserialize(
+                        "DeleteLifecycleHookResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteLifecycleHook", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified notification.</p>
+    fn delete_notification_configuration(
+        &self,
+        input: DeleteNotificationConfigurationType,
+    ) -> RusotoFuture<(), DeleteNotificationConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteNotificationConfiguration");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteNotificationConfiguration");
+        params.put("Version", "2011-01-01");
+        DeleteNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteNotificationConfigurationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified scaling policy.</p> <p>Deleting either a step scaling policy or a simple scaling policy deletes the underlying alarm action, but does not delete the alarm, even if it no longer has an associated action.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/deleting-scaling-policy.html">Deleting a Scaling Policy</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn delete_policy(&self, input: DeletePolicyType) -> RusotoFuture<(), DeletePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeletePolicy");
+        let mut params = Params::new();
+
+        params.put("Action", "DeletePolicy");
+        params.put("Version", "2011-01-01");
+        DeletePolicyTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeletePolicyError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified scheduled action.</p>
+    fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionType,
+    ) -> RusotoFuture<(), DeleteScheduledActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteScheduledAction");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteScheduledAction");
+        params.put("Version", "2011-01-01");
+        DeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteScheduledActionError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified tags.</p>
+    fn delete_tags(&self, input: DeleteTagsType) -> RusotoFuture<(), DeleteTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteTags");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteTags");
+        params.put("Version", "2011-01-01");
+        DeleteTagsTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Describes the current Amazon EC2 Auto Scaling resource limits for your AWS account.</p> <p>For information about requesting an increase in these limits, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn describe_account_limits(
+        &self,
+    ) -> RusotoFuture<DescribeAccountLimitsAnswer, DescribeAccountLimitsError> {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeAccountLimits");
+        params.put("Version", "2011-01-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAccountLimitsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeAccountLimitsAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeAccountLimitsAnswerDeserializer::deserialize(
+                        "DescribeAccountLimitsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAccountLimits", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the policy adjustment types for use with <a>PutScalingPolicy</a>.</p>
+    fn describe_adjustment_types(
+        &self,
+    ) -> RusotoFuture<DescribeAdjustmentTypesAnswer, DescribeAdjustmentTypesError> {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAdjustmentTypes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeAdjustmentTypes");
+        params.put("Version", "2011-01-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAdjustmentTypesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeAdjustmentTypesAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeAdjustmentTypesAnswerDeserializer::deserialize(
+                        "DescribeAdjustmentTypesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAdjustmentTypes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes one or more Auto Scaling groups.</p>
+    fn describe_auto_scaling_groups(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> RusotoFuture<AutoScalingGroupsType, DescribeAutoScalingGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeAutoScalingGroups");
+        params.put("Version", "2011-01-01");
+        AutoScalingGroupNamesTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAutoScalingGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AutoScalingGroupsType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AutoScalingGroupsTypeDeserializer::deserialize(
+                        "DescribeAutoScalingGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAutoScalingGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes one or more Auto Scaling instances.</p>
+    fn describe_auto_scaling_instances(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> RusotoFuture<AutoScalingInstancesType, DescribeAutoScalingInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingInstances");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeAutoScalingInstances");
+        params.put("Version", "2011-01-01");
+        DescribeAutoScalingInstancesTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAutoScalingInstancesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AutoScalingInstancesType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AutoScalingInstancesTypeDeserializer::deserialize(
+                        "DescribeAutoScalingInstancesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAutoScalingInstances", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the notification types that are supported by Amazon EC2 Auto Scaling.</p>
+    fn describe_auto_scaling_notification_types(
+        &self,
+    ) -> RusotoFuture<
+        DescribeAutoScalingNotificationTypesAnswer,
+        DescribeAutoScalingNotificationTypesError,
+    > {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingNotificationTypes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeAutoScalingNotificationTypes");
+        params.put("Version", "2011-01-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAutoScalingNotificationTypesError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeAutoScalingNotificationTypesAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeAutoScalingNotificationTypesAnswerDeserializer::deserialize(
+                        "DescribeAutoScalingNotificationTypesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "DescribeAutoScalingNotificationTypes",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes one or more launch configurations.</p>
+    fn describe_launch_configurations(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> RusotoFuture<LaunchConfigurationsType, DescribeLaunchConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLaunchConfigurations");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeLaunchConfigurations");
+        params.put("Version", "2011-01-01");
+        LaunchConfigurationNamesTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeLaunchConfigurationsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = LaunchConfigurationsType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = LaunchConfigurationsTypeDeserializer::deserialize(
+                        "DescribeLaunchConfigurationsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLaunchConfigurations", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Describes the available types of lifecycle hooks.</p> <p>The following hook types are supported:</p> <ul> <li> <p>autoscaling:EC2<em>INSTANCE</em>LAUNCHING</p> </li> <li> <p>autoscaling:EC2<em>INSTANCE</em>TERMINATING</p> </li> </ul></p>
+    fn describe_lifecycle_hook_types(
+        &self,
+    ) -> RusotoFuture<DescribeLifecycleHookTypesAnswer, DescribeLifecycleHookTypesError> {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLifecycleHookTypes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeLifecycleHookTypes");
+        params.put("Version", "2011-01-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeLifecycleHookTypesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeLifecycleHookTypesAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeLifecycleHookTypesAnswerDeserializer::deserialize(
+                        "DescribeLifecycleHookTypesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLifecycleHookTypes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the lifecycle hooks for the specified Auto Scaling group.</p>
+    fn describe_lifecycle_hooks(
+        &self,
+        input: DescribeLifecycleHooksType,
+    ) -> RusotoFuture<DescribeLifecycleHooksAnswer, DescribeLifecycleHooksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLifecycleHooks");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeLifecycleHooks");
+        params.put("Version", "2011-01-01");
+        DescribeLifecycleHooksTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeLifecycleHooksError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeLifecycleHooksAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeLifecycleHooksAnswerDeserializer::deserialize(
+                        "DescribeLifecycleHooksResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLifecycleHooks", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the target groups for the specified Auto Scaling group.</p>
+    fn describe_load_balancer_target_groups(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> RusotoFuture<DescribeLoadBalancerTargetGroupsResponse, DescribeLoadBalancerTargetGroupsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerTargetGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeLoadBalancerTargetGroups");
+        params.put("Version", "2011-01-01");
+        DescribeLoadBalancerTargetGroupsRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeLoadBalancerTargetGroupsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeLoadBalancerTargetGroupsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeLoadBalancerTargetGroupsResponseDeserializer::deserialize(
+                        "DescribeLoadBalancerTargetGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLoadBalancerTargetGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the load balancers for the specified Auto Scaling group.</p> <p>This operation describes only Classic Load Balancers. If you have Application Load Balancers or Network Load Balancers, use <a>DescribeLoadBalancerTargetGroups</a> instead.</p>
+    fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> RusotoFuture<DescribeLoadBalancersResponse, DescribeLoadBalancersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeLoadBalancers");
+        params.put("Version", "2011-01-01");
+        DescribeLoadBalancersRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeLoadBalancersError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeLoadBalancersResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeLoadBalancersResponseDeserializer::deserialize(
+                        "DescribeLoadBalancersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLoadBalancers", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the available CloudWatch metrics for Amazon EC2 Auto Scaling.</p> <p>The <code>GroupStandbyInstances</code> metric is not returned by default. You must explicitly request this metric when calling <a>EnableMetricsCollection</a>.</p>
+    fn describe_metric_collection_types(
+        &self,
+    ) -> RusotoFuture<DescribeMetricCollectionTypesAnswer, DescribeMetricCollectionTypesError> {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeMetricCollectionTypes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeMetricCollectionTypes");
+        params.put("Version", "2011-01-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMetricCollectionTypesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeMetricCollectionTypesAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeMetricCollectionTypesAnswerDeserializer::deserialize(
+                        "DescribeMetricCollectionTypesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeMetricCollectionTypes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the notification actions associated with the specified Auto Scaling group.</p>
+    fn describe_notification_configurations(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> RusotoFuture<
+        DescribeNotificationConfigurationsAnswer,
+        DescribeNotificationConfigurationsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeNotificationConfigurations");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeNotificationConfigurations");
+        params.put("Version", "2011-01-01");
+        DescribeNotificationConfigurationsTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeNotificationConfigurationsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeNotificationConfigurationsAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeNotificationConfigurationsAnswerDeserializer::deserialize(
+                        "DescribeNotificationConfigurationsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeNotificationConfigurations", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the policies for the specified Auto Scaling group.</p>
+    fn describe_policies(
+        &self,
+        input: DescribePoliciesType,
+    ) -> RusotoFuture<PoliciesType, DescribePoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribePolicies");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribePolicies");
+        params.put("Version", "2011-01-01");
+        DescribePoliciesTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribePoliciesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = PoliciesType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        PoliciesTypeDeserializer::deserialize("DescribePoliciesResult", &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("DescribePolicies", err, &response.body)
+                            })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes one or more scaling activities for the specified Auto Scaling group.</p>
+    fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> RusotoFuture<ActivitiesType, DescribeScalingActivitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingActivities");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeScalingActivities");
+        params.put("Version", "2011-01-01");
+        DescribeScalingActivitiesTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeScalingActivitiesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ActivitiesType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ActivitiesTypeDeserializer::deserialize(
+                        "DescribeScalingActivitiesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeScalingActivities", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the scaling process types for use with <a>ResumeProcesses</a> and <a>SuspendProcesses</a>.</p>
+    fn describe_scaling_process_types(
+        &self,
+    ) -> RusotoFuture<ProcessesType, DescribeScalingProcessTypesError> {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingProcessTypes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeScalingProcessTypes");
+        params.put("Version", "2011-01-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeScalingProcessTypesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ProcessesType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ProcessesTypeDeserializer::deserialize(
+                        "DescribeScalingProcessTypesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeScalingProcessTypes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the actions scheduled for your Auto Scaling group that haven't run or that have not reached their end time. To describe the actions that have already run, use <a>DescribeScalingActivities</a>. </p>
+    fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> RusotoFuture<ScheduledActionsType, DescribeScheduledActionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScheduledActions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeScheduledActions");
+        params.put("Version", "2011-01-01");
+        DescribeScheduledActionsTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeScheduledActionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ScheduledActionsType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ScheduledActionsTypeDeserializer::deserialize(
+                        "DescribeScheduledActionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeScheduledActions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the specified tags.</p> <p>You can use filters to limit the results. For example, you can query for the tags for a specific Auto Scaling group. You can specify multiple values for a filter. A tag must match at least one of the specified values for it to be included in the results.</p> <p>You can also specify multiple filters. The result includes information for a particular tag only if it matches all the filters. If there's no match, no special message is returned.</p>
+    fn describe_tags(&self, input: DescribeTagsType) -> RusotoFuture<TagsType, DescribeTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeTags");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeTags");
+        params.put("Version", "2011-01-01");
+        DescribeTagsTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TagsType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TagsTypeDeserializer::deserialize("DescribeTagsResult", &mut stack)
+                        .map_err(|err| stack.parse_error("DescribeTags", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the termination policies supported by Amazon EC2 Auto Scaling.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html">Controlling Which Auto Scaling Instances Terminate During Scale In</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn describe_termination_policy_types(
+        &self,
+    ) -> RusotoFuture<DescribeTerminationPolicyTypesAnswer, DescribeTerminationPolicyTypesError>
+    {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeTerminationPolicyTypes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeTerminationPolicyTypes");
+        params.put("Version", "2011-01-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeTerminationPolicyTypesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeTerminationPolicyTypesAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeTerminationPolicyTypesAnswerDeserializer::deserialize(
+                        "DescribeTerminationPolicyTypesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeTerminationPolicyTypes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Removes one or more instances from the specified Auto Scaling group.</p> <p>After the instances are detached, you can manage them independent of the Auto Scaling group.</p> <p>If you do not specify the option to decrement the desired capacity, Amazon EC2 Auto Scaling launches instances to replace the ones that are detached.</p> <p>If there is a Classic Load Balancer attached to the Auto Scaling group, the instances are deregistered from the load balancer. If there are target groups attached to the Auto Scaling group, the instances are deregistered from the target groups.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/detach-instance-asg.html">Detach EC2 Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn detach_instances(
+        &self,
+        input: DetachInstancesQuery,
+    ) -> RusotoFuture<DetachInstancesAnswer, DetachInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachInstances");
+        let mut params = Params::new();
+
+        params.put("Action", "DetachInstances");
+        params.put("Version", "2011-01-01");
+        DetachInstancesQuerySerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetachInstancesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DetachInstancesAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DetachInstancesAnswerDeserializer::deserialize(
+                        "DetachInstancesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DetachInstances", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Detaches one or more target groups from the specified Auto Scaling group.</p>
+    fn detach_load_balancer_target_groups(
+        &self,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> RusotoFuture<DetachLoadBalancerTargetGroupsResultType, DetachLoadBalancerTargetGroupsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachLoadBalancerTargetGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DetachLoadBalancerTargetGroups");
+        params.put("Version", "2011-01-01");
+        DetachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DetachLoadBalancerTargetGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DetachLoadBalancerTargetGroupsResultType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DetachLoadBalancerTargetGroupsResultTypeDeserializer::deserialize(
+                        "DetachLoadBalancerTargetGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DetachLoadBalancerTargetGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Detaches one or more Classic Load Balancers from the specified Auto Scaling group.</p> <p>This operation detaches only Classic Load Balancers. If you have Application Load Balancers or Network Load Balancers, use <a>DetachLoadBalancerTargetGroups</a> instead.</p> <p>When you detach a load balancer, it enters the <code>Removing</code> state while deregistering the instances in the group. When all instances are deregistered, then you can no longer describe the load balancer using <a>DescribeLoadBalancers</a>. The instances remain running.</p>
+    fn detach_load_balancers(
+        &self,
+        input: DetachLoadBalancersType,
+    ) -> RusotoFuture<DetachLoadBalancersResultType, DetachLoadBalancersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachLoadBalancers");
+        let mut params = Params::new();
+
+        params.put("Action", "DetachLoadBalancers");
+        params.put("Version", "2011-01-01");
+        DetachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DetachLoadBalancersError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DetachLoadBalancersResultType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DetachLoadBalancersResultTypeDeserializer::deserialize(
+                        "DetachLoadBalancersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DetachLoadBalancers", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Disables group metrics for the specified Auto Scaling group.</p>
+    fn disable_metrics_collection(
+        &self,
+        input: DisableMetricsCollectionQuery,
+    ) -> RusotoFuture<(), DisableMetricsCollectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DisableMetricsCollection");
+        let mut params = Params::new();
+
+        params.put("Action", "DisableMetricsCollection");
+        params.put("Version", "2011-01-01");
+        DisableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisableMetricsCollectionError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Enables group metrics for the specified Auto Scaling group. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-monitoring.html">Monitoring Your Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn enable_metrics_collection(
+        &self,
+        input: EnableMetricsCollectionQuery,
+    ) -> RusotoFuture<(), EnableMetricsCollectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("EnableMetricsCollection");
+        let mut params = Params::new();
+
+        params.put("Action", "EnableMetricsCollection");
+        params.put("Version", "2011-01-01");
+        EnableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(EnableMetricsCollectionError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Moves the specified instances into the standby state.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-enter-exit-standby.html">Temporarily Removing Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn enter_standby(
+        &self,
+        input: EnterStandbyQuery,
+    ) -> RusotoFuture<EnterStandbyAnswer, EnterStandbyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("EnterStandby");
+        let mut params = Params::new();
+
+        params.put("Action", "EnterStandby");
+        params.put("Version", "2011-01-01");
+        EnterStandbyQuerySerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnterStandbyError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EnterStandbyAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EnterStandbyAnswerDeserializer::deserialize(
+                        "EnterStandbyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("EnterStandby", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Executes the specified policy.</p>
+    fn execute_policy(&self, input: ExecutePolicyType) -> RusotoFuture<(), ExecutePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ExecutePolicy");
+        let mut params = Params::new();
+
+        params.put("Action", "ExecutePolicy");
+        params.put("Version", "2011-01-01");
+        ExecutePolicyTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ExecutePolicyError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Moves the specified instances out of the standby state.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-enter-exit-standby.html">Temporarily Removing Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn exit_standby(
+        &self,
+        input: ExitStandbyQuery,
+    ) -> RusotoFuture<ExitStandbyAnswer, ExitStandbyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ExitStandby");
+        let mut params = Params::new();
+
+        params.put("Action", "ExitStandby");
+        params.put("Version", "2011-01-01");
+        ExitStandbyQuerySerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ExitStandbyError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ExitStandbyAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        ExitStandbyAnswerDeserializer::deserialize("ExitStandbyResult", &mut stack)
+                            .map_err(|err| stack.parse_error("ExitStandby", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates or updates a lifecycle hook for the specified Auto Scaling group.</p> <p>A lifecycle hook tells Amazon EC2 Auto Scaling to perform an action on an instance when the instance launches (before it is put into service) or as the instance terminates (before it is fully terminated).</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p> <b>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</b> </p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state using <a>RecordLifecycleActionHeartbeat</a>.</p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action using <a>CompleteLifecycleAction</a>.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>If you exceed your maximum limit of lifecycle hooks, which by default is 50 per Auto Scaling group, the call fails.</p> <p>You can view the lifecycle hooks for an Auto Scaling group using <a>DescribeLifecycleHooks</a>. If you are no longer using a lifecycle hook, you can delete it using <a>DeleteLifecycleHook</a>.</p>
+    fn put_lifecycle_hook(
+        &self,
+        input: PutLifecycleHookType,
+    ) -> RusotoFuture<PutLifecycleHookAnswer, PutLifecycleHookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutLifecycleHook");
+        let mut params = Params::new();
+
+        params.put("Action", "PutLifecycleHook");
+        params.put("Version", "2011-01-01");
+        PutLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutLifecycleHookError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = PutLifecycleHookAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = PutLifecycleHookAnswerDeserializer::deserialize(
+                        "PutLifecycleHookResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("PutLifecycleHook", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Configures an Auto Scaling group to send notifications when specified events take place. Subscribers to the specified topic can have messages delivered to an endpoint such as a web server or an email address.</p> <p>This configuration overwrites any existing configuration.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/ASGettingNotifications.html">Getting Amazon SNS Notifications When Your Auto Scaling Group Scales</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn put_notification_configuration(
+        &self,
+        input: PutNotificationConfigurationType,
+    ) -> RusotoFuture<(), PutNotificationConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutNotificationConfiguration");
+        let mut params = Params::new();
+
+        params.put("Action", "PutNotificationConfiguration");
+        params.put("Version", "2011-01-01");
+        PutNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutNotificationConfigurationError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Creates or updates a scaling policy for an Auto Scaling group. To update an existing scaling policy, use the existing policy name and set the parameters to change. Any existing parameter not changed in an update to an existing policy is not changed in this update request.</p> <p>For more information about using scaling policies to scale your Auto Scaling group automatically, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-scale-based-on-demand.html">Dynamic Scaling</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyType,
+    ) -> RusotoFuture<PolicyARNType, PutScalingPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutScalingPolicy");
+        let mut params = Params::new();
+
+        params.put("Action", "PutScalingPolicy");
+        params.put("Version", "2011-01-01");
+        PutScalingPolicyTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutScalingPolicyError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = PolicyARNType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = PolicyARNTypeDeserializer::deserialize(
+                        "PutScalingPolicyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("PutScalingPolicy", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates or updates a scheduled scaling action for an Auto Scaling group. If you leave a parameter unspecified when updating a scheduled scaling action, the corresponding value remains unchanged.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/schedule_time.html">Scheduled Scaling</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn put_scheduled_update_group_action(
+        &self,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> RusotoFuture<(), PutScheduledUpdateGroupActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutScheduledUpdateGroupAction");
+        let mut params = Params::new();
+
+        params.put("Action", "PutScheduledUpdateGroupAction");
+        params.put("Version", "2011-01-01");
+        PutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutScheduledUpdateGroupActionError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Records a heartbeat for the lifecycle action associated with the specified token or instance. This extends the timeout by the length of time defined using <a>PutLifecycleHook</a>.</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</p> </li> <li> <p> <b>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state.</b> </p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/AutoScalingGroupLifecycle.html">Auto Scaling Lifecycle</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn record_lifecycle_action_heartbeat(
+        &self,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> RusotoFuture<RecordLifecycleActionHeartbeatAnswer, RecordLifecycleActionHeartbeatError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("RecordLifecycleActionHeartbeat");
+        let mut params = Params::new();
+
+        params.put("Action", "RecordLifecycleActionHeartbeat");
+        params.put("Version", "2011-01-01");
+        RecordLifecycleActionHeartbeatTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RecordLifecycleActionHeartbeatError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RecordLifecycleActionHeartbeatAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RecordLifecycleActionHeartbeatAnswerDeserializer::deserialize(
+                        "RecordLifecycleActionHeartbeatResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RecordLifecycleActionHeartbeat", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Resumes the specified suspended automatic scaling processes, or all suspended process, for the specified Auto Scaling group.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html">Suspending and Resuming Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn resume_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> RusotoFuture<(), ResumeProcessesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ResumeProcesses");
+        let mut params = Params::new();
+
+        params.put("Action", "ResumeProcesses");
+        params.put("Version", "2011-01-01");
+        ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResumeProcessesError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Sets the size of the specified Auto Scaling group.</p> <p>For more information about desired capacity, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/what-is-amazon-ec2-auto-scaling.html">What Is Amazon EC2 Auto Scaling?</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn set_desired_capacity(
+        &self,
+        input: SetDesiredCapacityType,
+    ) -> RusotoFuture<(), SetDesiredCapacityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetDesiredCapacity");
+        let mut params = Params::new();
+
+        params.put("Action", "SetDesiredCapacity");
+        params.put("Version", "2011-01-01");
+        SetDesiredCapacityTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetDesiredCapacityError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Sets the health status of the specified instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/healthcheck.html">Health Checks for Auto Scaling Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn set_instance_health(
+        &self,
+        input: SetInstanceHealthQuery,
+    ) -> RusotoFuture<(), SetInstanceHealthError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetInstanceHealth");
+        let mut params = Params::new();
+
+        params.put("Action", "SetInstanceHealth");
+        params.put("Version", "2011-01-01");
+        SetInstanceHealthQuerySerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetInstanceHealthError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Updates the instance protection settings of the specified instances.</p> <p>For more information about preventing instances that are part of an Auto Scaling group from terminating on scale in, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html#instance-protection">Instance Protection</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn set_instance_protection(
+        &self,
+        input: SetInstanceProtectionQuery,
+    ) -> RusotoFuture<SetInstanceProtectionAnswer, SetInstanceProtectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetInstanceProtection");
+        let mut params = Params::new();
+
+        params.put("Action", "SetInstanceProtection");
+        params.put("Version", "2011-01-01");
+        SetInstanceProtectionQuerySerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetInstanceProtectionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetInstanceProtectionAnswer::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetInstanceProtectionAnswerDeserializer::deserialize(
+                        "SetInstanceProtectionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("SetInstanceProtection", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Suspends the specified automatic scaling processes, or all processes, for the specified Auto Scaling group.</p> <p>If you suspend either the <code>Launch</code> or <code>Terminate</code> process types, it can prevent other process types from functioning properly.</p> <p>To resume processes that have been suspended, use <a>ResumeProcesses</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html">Suspending and Resuming Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    fn suspend_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> RusotoFuture<(), SuspendProcessesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SuspendProcesses");
+        let mut params = Params::new();
+
+        params.put("Action", "SuspendProcesses");
+        params.put("Version", "2011-01-01");
+        ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SuspendProcessesError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Terminates the specified instance and optionally adjusts the desired group size.</p> <p>This call simply makes a termination request. The instance is not terminated immediately.</p>
+    fn terminate_instance_in_auto_scaling_group(
+        &self,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> RusotoFuture<ActivityType, TerminateInstanceInAutoScalingGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("TerminateInstanceInAutoScalingGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "TerminateInstanceInAutoScalingGroup");
+        params.put("Version", "2011-01-01");
+        TerminateInstanceInAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutScheduledUpdateGroupActionError::from_response(response))
+                    Err(TerminateInstanceInAutoScalingGroupError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ActivityType::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ActivityTypeDeserializer::deserialize(
+                        "TerminateInstanceInAutoScalingGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "TerminateInstanceInAutoScalingGroup",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates the configuration for the specified Auto Scaling group.</p> <p>To update an Auto Scaling group, specify the name of the group and the parameter that you want to change. Any parameters that you don't specify are not changed by this update request. The new settings take effect on any scaling activities after this call returns. Scaling activities that are currently in progress aren't affected.</p> <p>If you associate a new launch configuration or template with an Auto Scaling group, all new instances will get the updated configuration. Existing instances continue to run with the configuration that they were originally launched with. When you update a group to specify a mixed instances policy instead of a launch configuration or template, existing instances may be replaced to match the new purchasing options that you specified in the policy. For example, if the group currently has 100% On-Demand capacity and the policy specifies 50% Spot capacity, this means that half of your instances will be gradually terminated and relaunched as Spot Instances. When replacing instances, Amazon EC2 Auto Scaling launches new instances before terminating the old ones, so that updating your group does not compromise the performance or availability of your application.</p> <p>Note the following about changing <code>DesiredCapacity</code>, <code>MaxSize</code>, or <code>MinSize</code>:</p> <ul> <li> <p>If a scale-in event occurs as a result of a new <code>DesiredCapacity</code> value that is lower than the current size of the group, the Auto Scaling group uses its termination policy to determine which instances to terminate.</p> </li> <li> <p>If you specify a new value for <code>MinSize</code> without specifying a value for <code>DesiredCapacity</code>, and the new <code>MinSize</code> is larger than the current size of the group, this sets the group's <code>DesiredCapacity</code> to the new <code>MinSize</code> value.</p> </li> <li> <p>If you specify a new value for <code>MaxSize</code> without specifying a value for <code>DesiredCapacity</code>, and the new <code>MaxSize</code> is smaller than the current size of the group, this sets the group's <code>DesiredCapacity</code> to the new <code>MaxSize</code> value.</p> </li> </ul> <p>To see which parameters have been set, use <a>DescribeAutoScalingGroups</a>. You can also view the scaling policies for an Auto Scaling group using <a>DescribePolicies</a>. If the group has scaling policies, you can update them using <a>PutScalingPolicy</a>.</p>
+    fn update_auto_scaling_group(
+        &self,
+        input: UpdateAutoScalingGroupType,
+    ) -> RusotoFuture<(), UpdateAutoScalingGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("UpdateAutoScalingGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateAutoScalingGroup");
+        params.put("Version", "2011-01-01");
+        UpdateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateAutoScalingGroupError::from_response(response))
                 }));
             }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+}
+
+/// A mock of the Auto Scaling API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAutoscaling {
+    attach_instances: Option<
+        Box<
+            dyn Fn(
+                    AttachInstancesQuery,
+                ) -> ::std::result::Result<(), RusotoError<AttachInstancesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    attach_load_balancer_target_groups: Option<
+        Box<
+            dyn Fn(
+                    AttachLoadBalancerTargetGroupsType,
+                ) -> ::std::result::Result<
+                    AttachLoadBalancerTargetGroupsResultType,
+                    RusotoError<AttachLoadBalancerTargetGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    attach_load_balancers: Option<
+        Box<
+            dyn Fn(
+                    AttachLoadBalancersType,
+                ) -> ::std::result::Result<
+                    AttachLoadBalancersResultType,
+                    RusotoError<AttachLoadBalancersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_delete_scheduled_action: Option<
+        Box<
+            dyn Fn(
+                    BatchDeleteScheduledActionType,
+                ) -> ::std::result::Result<
+                    BatchDeleteScheduledActionAnswer,
+                    RusotoError<BatchDeleteScheduledActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_put_scheduled_update_group_action: Option<
+        Box<
+            dyn Fn(
+                    BatchPutScheduledUpdateGroupActionType,
+                ) -> ::std::result::Result<
+                    BatchPutScheduledUpdateGroupActionAnswer,
+                    RusotoError<BatchPutScheduledUpdateGroupActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    complete_lifecycle_action: Option<
+        Box<
+            dyn Fn(
+                    CompleteLifecycleActionType,
+                ) -> ::std::result::Result<
+                    CompleteLifecycleActionAnswer,
+                    RusotoError<CompleteLifecycleActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_auto_scaling_group: Option<
+        Box<
+            dyn Fn(
+                    CreateAutoScalingGroupType,
+                )
+                    -> ::std::result::Result<(), RusotoError<CreateAutoScalingGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_launch_configuration: Option<
+        Box<
+            dyn Fn(
+                    CreateLaunchConfigurationType,
+                )
+                    -> ::std::result::Result<(), RusotoError<CreateLaunchConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_or_update_tags: Option<
+        Box<
+            dyn Fn(
+                    CreateOrUpdateTagsType,
+                )
+                    -> ::std::result::Result<(), RusotoError<CreateOrUpdateTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_auto_scaling_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteAutoScalingGroupType,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteAutoScalingGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_launch_configuration: Option<
+        Box<
+            dyn Fn(
+                    LaunchConfigurationNameType,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteLaunchConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_lifecycle_hook: Option<
+        Box<
+            dyn Fn(
+                    DeleteLifecycleHookType,
+                ) -> ::std::result::Result<
+                    DeleteLifecycleHookAnswer,
+                    RusotoError<DeleteLifecycleHookError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_notification_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteNotificationConfigurationType,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteNotificationConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_policy: Option<
+        Box<
+            dyn Fn(DeletePolicyType) -> ::std::result::Result<(), RusotoError<DeletePolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_scheduled_action: Option<
+        Box<
+            dyn Fn(
+                    DeleteScheduledActionType,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteScheduledActionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_tags: Option<
+        Box<
+            dyn Fn(DeleteTagsType) -> ::std::result::Result<(), RusotoError<DeleteTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_account_limits: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeAccountLimitsAnswer,
+                    RusotoError<DescribeAccountLimitsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_adjustment_types: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeAdjustmentTypesAnswer,
+                    RusotoError<DescribeAdjustmentTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_auto_scaling_groups: Option<
+        Box<
+            dyn Fn(
+                    AutoScalingGroupNamesType,
+                ) -> ::std::result::Result<
+                    AutoScalingGroupsType,
+                    RusotoError<DescribeAutoScalingGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_auto_scaling_instances: Option<
+        Box<
+            dyn Fn(
+                    DescribeAutoScalingInstancesType,
+                ) -> ::std::result::Result<
+                    AutoScalingInstancesType,
+                    RusotoError<DescribeAutoScalingInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_auto_scaling_notification_types: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeAutoScalingNotificationTypesAnswer,
+                    RusotoError<DescribeAutoScalingNotificationTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_launch_configurations: Option<
+        Box<
+            dyn Fn(
+                    LaunchConfigurationNamesType,
+                ) -> ::std::result::Result<
+                    LaunchConfigurationsType,
+                    RusotoError<DescribeLaunchConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_lifecycle_hook_types: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeLifecycleHookTypesAnswer,
+                    RusotoError<DescribeLifecycleHookTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_lifecycle_hooks: Option<
+        Box<
+            dyn Fn(
+                    DescribeLifecycleHooksType,
+                ) -> ::std::result::Result<
+                    DescribeLifecycleHooksAnswer,
+                    RusotoError<DescribeLifecycleHooksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_load_balancer_target_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeLoadBalancerTargetGroupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeLoadBalancerTargetGroupsResponse,
+                    RusotoError<DescribeLoadBalancerTargetGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_load_balancers: Option<
+        Box<
+            dyn Fn(
+                    DescribeLoadBalancersRequest,
+                ) -> ::std::result::Result<
+                    DescribeLoadBalancersResponse,
+                    RusotoError<DescribeLoadBalancersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_metric_collection_types: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeMetricCollectionTypesAnswer,
+                    RusotoError<DescribeMetricCollectionTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_notification_configurations: Option<
+        Box<
+            dyn Fn(
+                    DescribeNotificationConfigurationsType,
+                ) -> ::std::result::Result<
+                    DescribeNotificationConfigurationsAnswer,
+                    RusotoError<DescribeNotificationConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_policies: Option<
+        Box<
+            dyn Fn(
+                    DescribePoliciesType,
+                )
+                    -> ::std::result::Result<PoliciesType, RusotoError<DescribePoliciesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_scaling_activities: Option<
+        Box<
+            dyn Fn(
+                    DescribeScalingActivitiesType,
+                ) -> ::std::result::Result<
+                    ActivitiesType,
+                    RusotoError<DescribeScalingActivitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_scaling_process_types: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    ProcessesType,
+                    RusotoError<DescribeScalingProcessTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_scheduled_actions: Option<
+        Box<
+            dyn Fn(
+                    DescribeScheduledActionsType,
+                ) -> ::std::result::Result<
+                    ScheduledActionsType,
+                    RusotoError<DescribeScheduledActionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tags: Option<
+        Box<
+            dyn Fn(
+                    DescribeTagsType,
+                )
+                    -> ::std::result::Result<TagsType, RusotoError<DescribeTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_termination_policy_types: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeTerminationPolicyTypesAnswer,
+                    RusotoError<DescribeTerminationPolicyTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detach_instances: Option<
+        Box<
+            dyn Fn(
+                    DetachInstancesQuery,
+                ) -> ::std::result::Result<
+                    DetachInstancesAnswer,
+                    RusotoError<DetachInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detach_load_balancer_target_groups: Option<
+        Box<
+            dyn Fn(
+                    DetachLoadBalancerTargetGroupsType,
+                ) -> ::std::result::Result<
+                    DetachLoadBalancerTargetGroupsResultType,
+                    RusotoError<DetachLoadBalancerTargetGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detach_load_balancers: Option<
+        Box<
+            dyn Fn(
+                    DetachLoadBalancersType,
+                ) -> ::std::result::Result<
+                    DetachLoadBalancersResultType,
+                    RusotoError<DetachLoadBalancersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_metrics_collection: Option<
+        Box<
+            dyn Fn(
+                    DisableMetricsCollectionQuery,
+                )
+                    -> ::std::result::Result<(), RusotoError<DisableMetricsCollectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    enable_metrics_collection: Option<
+        Box<
+            dyn Fn(
+                    EnableMetricsCollectionQuery,
+                )
+                    -> ::std::result::Result<(), RusotoError<EnableMetricsCollectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    enter_standby: Option<
+        Box<
+            dyn Fn(
+                    EnterStandbyQuery,
+                )
+                    -> ::std::result::Result<EnterStandbyAnswer, RusotoError<EnterStandbyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    execute_policy: Option<
+        Box<
+            dyn Fn(ExecutePolicyType) -> ::std::result::Result<(), RusotoError<ExecutePolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    exit_standby: Option<
+        Box<
+            dyn Fn(
+                    ExitStandbyQuery,
+                )
+                    -> ::std::result::Result<ExitStandbyAnswer, RusotoError<ExitStandbyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_lifecycle_hook: Option<
+        Box<
+            dyn Fn(
+                    PutLifecycleHookType,
+                ) -> ::std::result::Result<
+                    PutLifecycleHookAnswer,
+                    RusotoError<PutLifecycleHookError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_notification_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutNotificationConfigurationType,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutNotificationConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_scaling_policy: Option<
+        Box<
+            dyn Fn(
+                    PutScalingPolicyType,
+                )
+                    -> ::std::result::Result<PolicyARNType, RusotoError<PutScalingPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_scheduled_update_group_action: Option<
+        Box<
+            dyn Fn(
+                    PutScheduledUpdateGroupActionType,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutScheduledUpdateGroupActionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    record_lifecycle_action_heartbeat: Option<
+        Box<
+            dyn Fn(
+                    RecordLifecycleActionHeartbeatType,
+                ) -> ::std::result::Result<
+                    RecordLifecycleActionHeartbeatAnswer,
+                    RusotoError<RecordLifecycleActionHeartbeatError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    resume_processes: Option<
+        Box<
+            dyn Fn(
+                    ScalingProcessQuery,
+                ) -> ::std::result::Result<(), RusotoError<ResumeProcessesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_desired_capacity: Option<
+        Box<
+            dyn Fn(
+                    SetDesiredCapacityType,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetDesiredCapacityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_instance_health: Option<
+        Box<
+            dyn Fn(
+                    SetInstanceHealthQuery,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetInstanceHealthError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_instance_protection: Option<
+        Box<
+            dyn Fn(
+                    SetInstanceProtectionQuery,
+                ) -> ::std::result::Result<
+                    SetInstanceProtectionAnswer,
+                    RusotoError<SetInstanceProtectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    suspend_processes: Option<
+        Box<
+            dyn Fn(
+                    ScalingProcessQuery,
+                ) -> ::std::result::Result<(), RusotoError<SuspendProcessesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    terminate_instance_in_auto_scaling_group: Option<
+        Box<
+            dyn Fn(
+                    TerminateInstanceInAutoScalingGroupType,
+                ) -> ::std::result::Result<
+                    ActivityType,
+                    RusotoError<TerminateInstanceInAutoScalingGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_auto_scaling_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateAutoScalingGroupType,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateAutoScalingGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAutoscaling {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAutoscaling {
+        Default::default()
+    }
+
+    /// Answers `attach_instances` with what `handler` returns for its input.
+    pub fn with_attach_instances<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(AttachInstancesQuery) -> ::std::result::Result<(), RusotoError<AttachInstancesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_load_balancer_target_groups` with what `handler` returns for its input.
+    pub fn with_attach_load_balancer_target_groups<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                AttachLoadBalancerTargetGroupsType,
+            ) -> ::std::result::Result<
+                AttachLoadBalancerTargetGroupsResultType,
+                RusotoError<AttachLoadBalancerTargetGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_load_balancer_target_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_load_balancers` with what `handler` returns for its input.
+    pub fn with_attach_load_balancers<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                AttachLoadBalancersType,
+            ) -> ::std::result::Result<
+                AttachLoadBalancersResultType,
+                RusotoError<AttachLoadBalancersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_load_balancers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_delete_scheduled_action` with what `handler` returns for its input.
+    pub fn with_batch_delete_scheduled_action<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                BatchDeleteScheduledActionType,
+            ) -> ::std::result::Result<
+                BatchDeleteScheduledActionAnswer,
+                RusotoError<BatchDeleteScheduledActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_scheduled_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_put_scheduled_update_group_action` with what `handler` returns for its input.
+    pub fn with_batch_put_scheduled_update_group_action<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                BatchPutScheduledUpdateGroupActionType,
+            ) -> ::std::result::Result<
+                BatchPutScheduledUpdateGroupActionAnswer,
+                RusotoError<BatchPutScheduledUpdateGroupActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_put_scheduled_update_group_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `complete_lifecycle_action` with what `handler` returns for its input.
+    pub fn with_complete_lifecycle_action<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                CompleteLifecycleActionType,
+            ) -> ::std::result::Result<
+                CompleteLifecycleActionAnswer,
+                RusotoError<CompleteLifecycleActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.complete_lifecycle_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_auto_scaling_group` with what `handler` returns for its input.
+    pub fn with_create_auto_scaling_group<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                CreateAutoScalingGroupType,
+            ) -> ::std::result::Result<(), RusotoError<CreateAutoScalingGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_auto_scaling_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_launch_configuration` with what `handler` returns for its input.
+    pub fn with_create_launch_configuration<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                CreateLaunchConfigurationType,
+            )
+                -> ::std::result::Result<(), RusotoError<CreateLaunchConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_launch_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_or_update_tags` with what `handler` returns for its input.
+    pub fn with_create_or_update_tags<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                CreateOrUpdateTagsType,
+            ) -> ::std::result::Result<(), RusotoError<CreateOrUpdateTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_or_update_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_auto_scaling_group` with what `handler` returns for its input.
+    pub fn with_delete_auto_scaling_group<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DeleteAutoScalingGroupType,
+            ) -> ::std::result::Result<(), RusotoError<DeleteAutoScalingGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_auto_scaling_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_launch_configuration` with what `handler` returns for its input.
+    pub fn with_delete_launch_configuration<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                LaunchConfigurationNameType,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteLaunchConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_launch_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_lifecycle_hook` with what `handler` returns for its input.
+    pub fn with_delete_lifecycle_hook<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DeleteLifecycleHookType,
+            ) -> ::std::result::Result<
+                DeleteLifecycleHookAnswer,
+                RusotoError<DeleteLifecycleHookError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_lifecycle_hook = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_notification_configuration` with what `handler` returns for its input.
+    pub fn with_delete_notification_configuration<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DeleteNotificationConfigurationType,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteNotificationConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_notification_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_policy` with what `handler` returns for its input.
+    pub fn with_delete_policy<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(DeletePolicyType) -> ::std::result::Result<(), RusotoError<DeletePolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_scheduled_action` with what `handler` returns for its input.
+    pub fn with_delete_scheduled_action<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DeleteScheduledActionType,
+            ) -> ::std::result::Result<(), RusotoError<DeleteScheduledActionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_scheduled_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tags` with what `handler` returns for its input.
+    pub fn with_delete_tags<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(DeleteTagsType) -> ::std::result::Result<(), RusotoError<DeleteTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account_limits` with what `handler` returns.
+    pub fn with_describe_account_limits<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeAccountLimitsAnswer,
+                RusotoError<DescribeAccountLimitsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account_limits = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_adjustment_types` with what `handler` returns.
+    pub fn with_describe_adjustment_types<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeAdjustmentTypesAnswer,
+                RusotoError<DescribeAdjustmentTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_adjustment_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_auto_scaling_groups` with what `handler` returns for its input.
+    pub fn with_describe_auto_scaling_groups<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                AutoScalingGroupNamesType,
+            ) -> ::std::result::Result<
+                AutoScalingGroupsType,
+                RusotoError<DescribeAutoScalingGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_auto_scaling_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_auto_scaling_instances` with what `handler` returns for its input.
+    pub fn with_describe_auto_scaling_instances<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DescribeAutoScalingInstancesType,
+            ) -> ::std::result::Result<
+                AutoScalingInstancesType,
+                RusotoError<DescribeAutoScalingInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_auto_scaling_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_auto_scaling_notification_types` with what `handler` returns.
+    pub fn with_describe_auto_scaling_notification_types<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeAutoScalingNotificationTypesAnswer,
+                RusotoError<DescribeAutoScalingNotificationTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_auto_scaling_notification_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_launch_configurations` with what `handler` returns for its input.
+    pub fn with_describe_launch_configurations<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                LaunchConfigurationNamesType,
+            ) -> ::std::result::Result<
+                LaunchConfigurationsType,
+                RusotoError<DescribeLaunchConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_launch_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_lifecycle_hook_types` with what `handler` returns.
+    pub fn with_describe_lifecycle_hook_types<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeLifecycleHookTypesAnswer,
+                RusotoError<DescribeLifecycleHookTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_lifecycle_hook_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_lifecycle_hooks` with what `handler` returns for its input.
+    pub fn with_describe_lifecycle_hooks<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DescribeLifecycleHooksType,
+            ) -> ::std::result::Result<
+                DescribeLifecycleHooksAnswer,
+                RusotoError<DescribeLifecycleHooksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_lifecycle_hooks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_load_balancer_target_groups` with what `handler` returns for its input.
+    pub fn with_describe_load_balancer_target_groups<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DescribeLoadBalancerTargetGroupsRequest,
+            ) -> ::std::result::Result<
+                DescribeLoadBalancerTargetGroupsResponse,
+                RusotoError<DescribeLoadBalancerTargetGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_load_balancer_target_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_load_balancers` with what `handler` returns for its input.
+    pub fn with_describe_load_balancers<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DescribeLoadBalancersRequest,
+            ) -> ::std::result::Result<
+                DescribeLoadBalancersResponse,
+                RusotoError<DescribeLoadBalancersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_load_balancers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_metric_collection_types` with what `handler` returns.
+    pub fn with_describe_metric_collection_types<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeMetricCollectionTypesAnswer,
+                RusotoError<DescribeMetricCollectionTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_metric_collection_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_notification_configurations` with what `handler` returns for its input.
+    pub fn with_describe_notification_configurations<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DescribeNotificationConfigurationsType,
+            ) -> ::std::result::Result<
+                DescribeNotificationConfigurationsAnswer,
+                RusotoError<DescribeNotificationConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_notification_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_policies` with what `handler` returns for its input.
+    pub fn with_describe_policies<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DescribePoliciesType,
+            )
+                -> ::std::result::Result<PoliciesType, RusotoError<DescribePoliciesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scaling_activities` with what `handler` returns for its input.
+    pub fn with_describe_scaling_activities<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DescribeScalingActivitiesType,
+            )
+                -> ::std::result::Result<ActivitiesType, RusotoError<DescribeScalingActivitiesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scaling_activities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scaling_process_types` with what `handler` returns.
+    pub fn with_describe_scaling_process_types<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn() -> ::std::result::Result<
+                ProcessesType,
+                RusotoError<DescribeScalingProcessTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scaling_process_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scheduled_actions` with what `handler` returns for its input.
+    pub fn with_describe_scheduled_actions<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DescribeScheduledActionsType,
+            ) -> ::std::result::Result<
+                ScheduledActionsType,
+                RusotoError<DescribeScheduledActionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scheduled_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tags` with what `handler` returns for its input.
+    pub fn with_describe_tags<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(DescribeTagsType) -> ::std::result::Result<TagsType, RusotoError<DescribeTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_termination_policy_types` with what `handler` returns.
+    pub fn with_describe_termination_policy_types<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeTerminationPolicyTypesAnswer,
+                RusotoError<DescribeTerminationPolicyTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_termination_policy_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_instances` with what `handler` returns for its input.
+    pub fn with_detach_instances<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DetachInstancesQuery,
+            )
+                -> ::std::result::Result<DetachInstancesAnswer, RusotoError<DetachInstancesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_load_balancer_target_groups` with what `handler` returns for its input.
+    pub fn with_detach_load_balancer_target_groups<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DetachLoadBalancerTargetGroupsType,
+            ) -> ::std::result::Result<
+                DetachLoadBalancerTargetGroupsResultType,
+                RusotoError<DetachLoadBalancerTargetGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_load_balancer_target_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_load_balancers` with what `handler` returns for its input.
+    pub fn with_detach_load_balancers<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DetachLoadBalancersType,
+            ) -> ::std::result::Result<
+                DetachLoadBalancersResultType,
+                RusotoError<DetachLoadBalancersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_load_balancers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_metrics_collection` with what `handler` returns for its input.
+    pub fn with_disable_metrics_collection<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                DisableMetricsCollectionQuery,
+            ) -> ::std::result::Result<(), RusotoError<DisableMetricsCollectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_metrics_collection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_metrics_collection` with what `handler` returns for its input.
+    pub fn with_enable_metrics_collection<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                EnableMetricsCollectionQuery,
+            ) -> ::std::result::Result<(), RusotoError<EnableMetricsCollectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_metrics_collection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enter_standby` with what `handler` returns for its input.
+    pub fn with_enter_standby<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                EnterStandbyQuery,
+            )
+                -> ::std::result::Result<EnterStandbyAnswer, RusotoError<EnterStandbyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enter_standby = Some(Box::new(handler));
+        self
+    }
+    /// Answers `execute_policy` with what `handler` returns for its input.
+    pub fn with_execute_policy<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(ExecutePolicyType) -> ::std::result::Result<(), RusotoError<ExecutePolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.execute_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `exit_standby` with what `handler` returns for its input.
+    pub fn with_exit_standby<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                ExitStandbyQuery,
+            )
+                -> ::std::result::Result<ExitStandbyAnswer, RusotoError<ExitStandbyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.exit_standby = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_lifecycle_hook` with what `handler` returns for its input.
+    pub fn with_put_lifecycle_hook<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                PutLifecycleHookType,
+            )
+                -> ::std::result::Result<PutLifecycleHookAnswer, RusotoError<PutLifecycleHookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_lifecycle_hook = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_notification_configuration` with what `handler` returns for its input.
+    pub fn with_put_notification_configuration<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                PutNotificationConfigurationType,
+            )
+                -> ::std::result::Result<(), RusotoError<PutNotificationConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_notification_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_scaling_policy` with what `handler` returns for its input.
+    pub fn with_put_scaling_policy<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                PutScalingPolicyType,
+            )
+                -> ::std::result::Result<PolicyARNType, RusotoError<PutScalingPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_scaling_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_scheduled_update_group_action` with what `handler` returns for its input.
+    pub fn with_put_scheduled_update_group_action<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                PutScheduledUpdateGroupActionType,
+            )
+                -> ::std::result::Result<(), RusotoError<PutScheduledUpdateGroupActionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_scheduled_update_group_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `record_lifecycle_action_heartbeat` with what `handler` returns for its input.
+    pub fn with_record_lifecycle_action_heartbeat<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                RecordLifecycleActionHeartbeatType,
+            ) -> ::std::result::Result<
+                RecordLifecycleActionHeartbeatAnswer,
+                RusotoError<RecordLifecycleActionHeartbeatError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.record_lifecycle_action_heartbeat = Some(Box::new(handler));
+        self
+    }
+    /// Answers `resume_processes` with what `handler` returns for its input.
+    pub fn with_resume_processes<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(ScalingProcessQuery) -> ::std::result::Result<(), RusotoError<ResumeProcessesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resume_processes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_desired_capacity` with what `handler` returns for its input.
+    pub fn with_set_desired_capacity<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                SetDesiredCapacityType,
+            ) -> ::std::result::Result<(), RusotoError<SetDesiredCapacityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_desired_capacity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_instance_health` with what `handler` returns for its input.
+    pub fn with_set_instance_health<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                SetInstanceHealthQuery,
+            ) -> ::std::result::Result<(), RusotoError<SetInstanceHealthError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_instance_health = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_instance_protection` with what `handler` returns for its input.
+    pub fn with_set_instance_protection<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                SetInstanceProtectionQuery,
+            ) -> ::std::result::Result<
+                SetInstanceProtectionAnswer,
+                RusotoError<SetInstanceProtectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_instance_protection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `suspend_processes` with what `handler` returns for its input.
+    pub fn with_suspend_processes<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(ScalingProcessQuery) -> ::std::result::Result<(), RusotoError<SuspendProcessesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.suspend_processes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `terminate_instance_in_auto_scaling_group` with what `handler` returns for its input.
+    pub fn with_terminate_instance_in_auto_scaling_group<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                TerminateInstanceInAutoScalingGroupType,
+            ) -> ::std::result::Result<
+                ActivityType,
+                RusotoError<TerminateInstanceInAutoScalingGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.terminate_instance_in_auto_scaling_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_auto_scaling_group` with what `handler` returns for its input.
+    pub fn with_update_auto_scaling_group<F>(mut self, handler: F) -> MockAutoscaling
+    where
+        F: Fn(
+                UpdateAutoScalingGroupType,
+            ) -> ::std::result::Result<(), RusotoError<UpdateAutoScalingGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_auto_scaling_group = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Autoscaling for MockAutoscaling {
+    fn attach_instances(
+        &self,
+        input: AttachInstancesQuery,
+    ) -> RusotoFuture<(), AttachInstancesError> {
+        match self.attach_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for attach_instances"),
+        }
+    }
+    fn attach_load_balancer_target_groups(
+        &self,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> RusotoFuture<AttachLoadBalancerTargetGroupsResultType, AttachLoadBalancerTargetGroupsError>
+    {
+        match self.attach_load_balancer_target_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for attach_load_balancer_target_groups"),
+        }
+    }
+    fn attach_load_balancers(
+        &self,
+        input: AttachLoadBalancersType,
+    ) -> RusotoFuture<AttachLoadBalancersResultType, AttachLoadBalancersError> {
+        match self.attach_load_balancers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for attach_load_balancers"),
+        }
+    }
+    fn batch_delete_scheduled_action(
+        &self,
+        input: BatchDeleteScheduledActionType,
+    ) -> RusotoFuture<BatchDeleteScheduledActionAnswer, BatchDeleteScheduledActionError> {
+        match self.batch_delete_scheduled_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for batch_delete_scheduled_action"),
+        }
+    }
+    fn batch_put_scheduled_update_group_action(
+        &self,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> RusotoFuture<
+        BatchPutScheduledUpdateGroupActionAnswer,
+        BatchPutScheduledUpdateGroupActionError,
+    > {
+        match self.batch_put_scheduled_update_group_action {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAutoscaling has no handler for batch_put_scheduled_update_group_action")
+            }
+        }
+    }
+    fn complete_lifecycle_action(
+        &self,
+        input: CompleteLifecycleActionType,
+    ) -> RusotoFuture<CompleteLifecycleActionAnswer, CompleteLifecycleActionError> {
+        match self.complete_lifecycle_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for complete_lifecycle_action"),
+        }
+    }
+    fn create_auto_scaling_group(
+        &self,
+        input: CreateAutoScalingGroupType,
+    ) -> RusotoFuture<(), CreateAutoScalingGroupError> {
+        match self.create_auto_scaling_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for create_auto_scaling_group"),
+        }
+    }
+    fn create_launch_configuration(
+        &self,
+        input: CreateLaunchConfigurationType,
+    ) -> RusotoFuture<(), CreateLaunchConfigurationError> {
+        match self.create_launch_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for create_launch_configuration"),
+        }
+    }
+    fn create_or_update_tags(
+        &self,
+        input: CreateOrUpdateTagsType,
+    ) -> RusotoFuture<(), CreateOrUpdateTagsError> {
+        match self.create_or_update_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for create_or_update_tags"),
+        }
+    }
+    fn delete_auto_scaling_group(
+        &self,
+        input: DeleteAutoScalingGroupType,
+    ) -> RusotoFuture<(), DeleteAutoScalingGroupError> {
+        match self.delete_auto_scaling_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for delete_auto_scaling_group"),
+        }
+    }
+    fn delete_launch_configuration(
+        &self,
+        input: LaunchConfigurationNameType,
+    ) -> RusotoFuture<(), DeleteLaunchConfigurationError> {
+        match self.delete_launch_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for delete_launch_configuration"),
+        }
+    }
+    fn delete_lifecycle_hook(
+        &self,
+        input: DeleteLifecycleHookType,
+    ) -> RusotoFuture<DeleteLifecycleHookAnswer, DeleteLifecycleHookError> {
+        match self.delete_lifecycle_hook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for delete_lifecycle_hook"),
+        }
+    }
+    fn delete_notification_configuration(
+        &self,
+        input: DeleteNotificationConfigurationType,
+    ) -> RusotoFuture<(), DeleteNotificationConfigurationError> {
+        match self.delete_notification_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for delete_notification_configuration"),
+        }
+    }
+    fn delete_policy(&self, input: DeletePolicyType) -> RusotoFuture<(), DeletePolicyError> {
+        match self.delete_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for delete_policy"),
+        }
+    }
+    fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionType,
+    ) -> RusotoFuture<(), DeleteScheduledActionError> {
+        match self.delete_scheduled_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for delete_scheduled_action"),
+        }
+    }
+    fn delete_tags(&self, input: DeleteTagsType) -> RusotoFuture<(), DeleteTagsError> {
+        match self.delete_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for delete_tags"),
+        }
+    }
+    fn describe_account_limits(
+        &self,
+    ) -> RusotoFuture<DescribeAccountLimitsAnswer, DescribeAccountLimitsError> {
+        match self.describe_account_limits {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockAutoscaling has no handler for describe_account_limits"),
+        }
+    }
+    fn describe_adjustment_types(
+        &self,
+    ) -> RusotoFuture<DescribeAdjustmentTypesAnswer, DescribeAdjustmentTypesError> {
+        match self.describe_adjustment_types {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockAutoscaling has no handler for describe_adjustment_types"),
+        }
+    }
+    fn describe_auto_scaling_groups(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> RusotoFuture<AutoScalingGroupsType, DescribeAutoScalingGroupsError> {
+        match self.describe_auto_scaling_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for describe_auto_scaling_groups"),
+        }
+    }
+    fn describe_auto_scaling_instances(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> RusotoFuture<AutoScalingInstancesType, DescribeAutoScalingInstancesError> {
+        match self.describe_auto_scaling_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for describe_auto_scaling_instances"),
+        }
+    }
+    fn describe_auto_scaling_notification_types(
+        &self,
+    ) -> RusotoFuture<
+        DescribeAutoScalingNotificationTypesAnswer,
+        DescribeAutoScalingNotificationTypesError,
+    > {
+        match self.describe_auto_scaling_notification_types {
+            Some(ref handler) => handler().into(),
+            None => panic!(
+                "MockAutoscaling has no handler for describe_auto_scaling_notification_types"
+            ),
+        }
+    }
+    fn describe_launch_configurations(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> RusotoFuture<LaunchConfigurationsType, DescribeLaunchConfigurationsError> {
+        match self.describe_launch_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for describe_launch_configurations"),
+        }
+    }
+    fn describe_lifecycle_hook_types(
+        &self,
+    ) -> RusotoFuture<DescribeLifecycleHookTypesAnswer, DescribeLifecycleHookTypesError> {
+        match self.describe_lifecycle_hook_types {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockAutoscaling has no handler for describe_lifecycle_hook_types"),
+        }
+    }
+    fn describe_lifecycle_hooks(
+        &self,
+        input: DescribeLifecycleHooksType,
+    ) -> RusotoFuture<DescribeLifecycleHooksAnswer, DescribeLifecycleHooksError> {
+        match self.describe_lifecycle_hooks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for describe_lifecycle_hooks"),
+        }
+    }
+    fn describe_load_balancer_target_groups(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> RusotoFuture<DescribeLoadBalancerTargetGroupsResponse, DescribeLoadBalancerTargetGroupsError>
+    {
+        match self.describe_load_balancer_target_groups {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAutoscaling has no handler for describe_load_balancer_target_groups")
+            }
+        }
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> RusotoFuture<DescribeLoadBalancersResponse, DescribeLoadBalancersError> {
+        match self.describe_load_balancers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for describe_load_balancers"),
+        }
+    }
+    fn describe_metric_collection_types(
+        &self,
+    ) -> RusotoFuture<DescribeMetricCollectionTypesAnswer, DescribeMetricCollectionTypesError> {
+        match self.describe_metric_collection_types {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockAutoscaling has no handler for describe_metric_collection_types"),
+        }
+    }
+    fn describe_notification_configurations(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> RusotoFuture<
+        DescribeNotificationConfigurationsAnswer,
+        DescribeNotificationConfigurationsError,
+    > {
+        match self.describe_notification_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAutoscaling has no handler for describe_notification_configurations")
+            }
+        }
+    }
+    fn describe_policies(
+        &self,
+        input: DescribePoliciesType,
+    ) -> RusotoFuture<PoliciesType, DescribePoliciesError> {
+        match self.describe_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for describe_policies"),
+        }
+    }
+    fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> RusotoFuture<ActivitiesType, DescribeScalingActivitiesError> {
+        match self.describe_scaling_activities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for describe_scaling_activities"),
+        }
+    }
+    fn describe_scaling_process_types(
+        &self,
+    ) -> RusotoFuture<ProcessesType, DescribeScalingProcessTypesError> {
+        match self.describe_scaling_process_types {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockAutoscaling has no handler for describe_scaling_process_types"),
+        }
+    }
+    fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> RusotoFuture<ScheduledActionsType, DescribeScheduledActionsError> {
+        match self.describe_scheduled_actions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for describe_scheduled_actions"),
+        }
+    }
+    fn describe_tags(&self, input: DescribeTagsType) -> RusotoFuture<TagsType, DescribeTagsError> {
+        match self.describe_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for describe_tags"),
+        }
+    }
+    fn describe_termination_policy_types(
+        &self,
+    ) -> RusotoFuture<DescribeTerminationPolicyTypesAnswer, DescribeTerminationPolicyTypesError>
+    {
+        match self.describe_termination_policy_types {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockAutoscaling has no handler for describe_termination_policy_types"),
+        }
+    }
+    fn detach_instances(
+        &self,
+        input: DetachInstancesQuery,
+    ) -> RusotoFuture<DetachInstancesAnswer, DetachInstancesError> {
+        match self.detach_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for detach_instances"),
+        }
+    }
+    fn detach_load_balancer_target_groups(
+        &self,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> RusotoFuture<DetachLoadBalancerTargetGroupsResultType, DetachLoadBalancerTargetGroupsError>
+    {
+        match self.detach_load_balancer_target_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for detach_load_balancer_target_groups"),
+        }
+    }
+    fn detach_load_balancers(
+        &self,
+        input: DetachLoadBalancersType,
+    ) -> RusotoFuture<DetachLoadBalancersResultType, DetachLoadBalancersError> {
+        match self.detach_load_balancers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for detach_load_balancers"),
+        }
+    }
+    fn disable_metrics_collection(
+        &self,
+        input: DisableMetricsCollectionQuery,
+    ) -> RusotoFuture<(), DisableMetricsCollectionError> {
+        match self.disable_metrics_collection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for disable_metrics_collection"),
+        }
+    }
+    fn enable_metrics_collection(
+        &self,
+        input: EnableMetricsCollectionQuery,
+    ) -> RusotoFuture<(), EnableMetricsCollectionError> {
+        match self.enable_metrics_collection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for enable_metrics_collection"),
+        }
+    }
+    fn enter_standby(
+        &self,
+        input: EnterStandbyQuery,
+    ) -> RusotoFuture<EnterStandbyAnswer, EnterStandbyError> {
+        match self.enter_standby {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for enter_standby"),
+        }
+    }
+    fn execute_policy(&self, input: ExecutePolicyType) -> RusotoFuture<(), ExecutePolicyError> {
+        match self.execute_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for execute_policy"),
+        }
+    }
+    fn exit_standby(
+        &self,
+        input: ExitStandbyQuery,
+    ) -> RusotoFuture<ExitStandbyAnswer, ExitStandbyError> {
+        match self.exit_standby {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for exit_standby"),
+        }
+    }
+    fn put_lifecycle_hook(
+        &self,
+        input: PutLifecycleHookType,
+    ) -> RusotoFuture<PutLifecycleHookAnswer, PutLifecycleHookError> {
+        match self.put_lifecycle_hook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for put_lifecycle_hook"),
+        }
+    }
+    fn put_notification_configuration(
+        &self,
+        input: PutNotificationConfigurationType,
+    ) -> RusotoFuture<(), PutNotificationConfigurationError> {
+        match self.put_notification_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for put_notification_configuration"),
+        }
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyType,
+    ) -> RusotoFuture<PolicyARNType, PutScalingPolicyError> {
+        match self.put_scaling_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for put_scaling_policy"),
+        }
+    }
+    fn put_scheduled_update_group_action(
+        &self,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> RusotoFuture<(), PutScheduledUpdateGroupActionError> {
+        match self.put_scheduled_update_group_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for put_scheduled_update_group_action"),
+        }
     }
-
-    /// <p>Records a heartbeat for the lifecycle action associated with the specified token or instance. This extends the timeout by the length of time defined using <a>PutLifecycleHook</a>.</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</p> </li> <li> <p> <b>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state.</b> </p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/AutoScalingGroupLifecycle.html">Auto Scaling Lifecycle</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn record_lifecycle_action_heartbeat(
         &self,
         input: RecordLifecycleActionHeartbeatType,
     ) -> RusotoFuture<RecordLifecycleActionHeartbeatAnswer, RecordLifecycleActionHeartbeatError>
     {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RecordLifecycleActionHeartbeat");
-        params.put("Version", "2011-01-01");
-        RecordLifecycleActionHeartbeatTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RecordLifecycleActionHeartbeatError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RecordLifecycleActionHeartbeatAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RecordLifecycleActionHeartbeatAnswerDeserializer::deserialize(
-                        "RecordLifecycleActionHeartbeatResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.record_lifecycle_action_heartbeat {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for record_lifecycle_action_heartbeat"),
+        }
     }
-
-    /// <p>Resumes the specified suspended automatic scaling processes, or all suspended process, for the specified Auto Scaling group.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html">Suspending and Resuming Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn resume_processes(
         &self,
         input: ScalingProcessQuery,
     ) -> RusotoFuture<(), ResumeProcessesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ResumeProcesses");
-        params.put("Version", "2011-01-01");
-        ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResumeProcessesError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.resume_processes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for resume_processes"),
+        }
     }
-
-    /// <p>Sets the size of the specified Auto Scaling group.</p> <p>For more information about desired capacity, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/what-is-amazon-ec2-auto-scaling.html">What Is Amazon EC2 Auto Scaling?</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn set_desired_capacity(
         &self,
         input: SetDesiredCapacityType,
     ) -> RusotoFuture<(), SetDesiredCapacityError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetDesiredCapacity");
-        params.put("Version", "2011-01-01");
-        SetDesiredCapacityTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetDesiredCapacityError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.set_desired_capacity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for set_desired_capacity"),
+        }
     }
-
-    /// <p>Sets the health status of the specified instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/healthcheck.html">Health Checks for Auto Scaling Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn set_instance_health(
         &self,
         input: SetInstanceHealthQuery,
     ) -> RusotoFuture<(), SetInstanceHealthError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetInstanceHealth");
-        params.put("Version", "2011-01-01");
-        SetInstanceHealthQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetInstanceHealthError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.set_instance_health {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for set_instance_health"),
+        }
     }
-
-    /// <p>Updates the instance protection settings of the specified instances.</p> <p>For more information about preventing instances that are part of an Auto Scaling group from terminating on scale in, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html#instance-protection">Instance Protection</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn set_instance_protection(
         &self,
         input: SetInstanceProtectionQuery,
     ) -> RusotoFuture<SetInstanceProtectionAnswer, SetInstanceProtectionError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetInstanceProtection");
-        params.put("Version", "2011-01-01");
-        SetInstanceProtectionQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetInstanceProtectionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetInstanceProtectionAnswer::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetInstanceProtectionAnswerDeserializer::deserialize(
-                        "SetInstanceProtectionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.set_instance_protection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for set_instance_protection"),
+        }
     }
-
-    /// <p>Suspends the specified automatic scaling processes, or all processes, for the specified Auto Scaling group.</p> <p>If you suspend either the <code>Launch</code> or <code>Terminate</code> process types, it can prevent other process types from functioning properly.</p> <p>To resume processes that have been suspended, use <a>ResumeProcesses</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html">Suspending and Resuming Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     fn suspend_processes(
         &self,
         input: ScalingProcessQuery,
     ) -> RusotoFuture<(), SuspendProcessesError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SuspendProcesses");
-        params.put("Version", "2011-01-01");
-        ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SuspendProcessesError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.suspend_processes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for suspend_processes"),
+        }
     }
-
-    /// <p>Terminates the specified instance and optionally adjusts the desired group size.</p> <p>This call simply makes a termination request. The instance is not terminated immediately.</p>
     fn terminate_instance_in_auto_scaling_group(
         &self,
         input: TerminateInstanceInAutoScalingGroupType,
     ) -> RusotoFuture<ActivityType, TerminateInstanceInAutoScalingGroupError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "TerminateInstanceInAutoScalingGroup");
-        params.put("Version", "2011-01-01");
-        TerminateInstanceInAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(TerminateInstanceInAutoScalingGroupError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ActivityType::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ActivityTypeDeserializer::deserialize(
-                        "TerminateInstanceInAutoScalingGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.terminate_instance_in_auto_scaling_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockAutoscaling has no handler for terminate_instance_in_auto_scaling_group"
+            ),
+        }
     }
-
-    /// <p>Updates the configuration for the specified Auto Scaling group.</p> <p>To update an Auto Scaling group, specify the name of the group and the parameter that you want to change. Any parameters that you don't specify are not changed by this update request. The new settings take effect on any scaling activities after this call returns. Scaling activities that are currently in progress aren't affected.</p> <p>If you associate a new launch configuration or template with an Auto Scaling group, all new instances will get the updated configuration. Existing instances continue to run with the configuration that they were originally launched with. When you update a group to specify a mixed instances policy instead of a launch configuration or template, existing instances may be replaced to match the new purchasing options that you specified in the policy. For example, if the group currently has 100% On-Demand capacity and the policy specifies 50% Spot capacity, this means that half of your instances will be gradually terminated and relaunched as Spot Instances. When replacing instances, Amazon EC2 Auto Scaling launches new instances before terminating the old ones, so that updating your group does not compromise the performance or availability of your application.</p> <p>Note the following about changing <code>DesiredCapacity</code>, <code>MaxSize</code>, or <code>MinSize</code>:</p> <ul> <li> <p>If a scale-in event occurs as a result of a new <code>DesiredCapacity</code> value that is lower than the current size of the group, the Auto Scaling group uses its termination policy to determine which instances to terminate.</p> </li> <li> <p>If you specify a new value for <code>MinSize</code> without specifying a value for <code>DesiredCapacity</code>, and the new <code>MinSize</code> is larger than the current size of the group, this sets the group's <code>DesiredCapacity</code> to the new <code>MinSize</code> value.</p> </li> <li> <p>If you specify a new value for <code>MaxSize</code> without specifying a value for <code>DesiredCapacity</code>, and the new <code>MaxSize</code> is smaller than the current size of the group, this sets the group's <code>DesiredCapacity</code> to the new <code>MaxSize</code> value.</p> </li> </ul> <p>To see which parameters have been set, use <a>DescribeAutoScalingGroups</a>. You can also view the scaling policies for an Auto Scaling group using <a>DescribePolicies</a>. If the group has scaling policies, you can update them using <a>PutScalingPolicy</a>.</p>
     fn update_auto_scaling_group(
         &self,
         input: UpdateAutoScalingGroupType,
     ) -> RusotoFuture<(), UpdateAutoScalingGroupError> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateAutoScalingGroup");
-        params.put("Version", "2011-01-01");
-        UpdateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateAutoScalingGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.update_auto_scaling_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAutoscaling has no handler for update_auto_scaling_group"),
+        }
     }
 }
 
@@ -12503,7 +18093,10 @@ mod protocol_tests {
         let mock = MockRequestDispatcher::with_status(400).with_body(&mock_response);
         let client =
             AutoscalingClient::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = DeletePolicyType::default();
+        let request = DeletePolicyType {
+            policy_name: "x".to_owned(),
+            ..Default::default()
+        };
         let result = client.delete_policy(request).sync();
         assert!(!result.is_ok(), "parse error: {:?}", result);
     }
@@ -25,6 +25,7 @@ use rusoto_core::signature::SignedRequest;
 use serde_json;
 /// <p>An object representing an AWS Batch array job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ArrayProperties {
     /// <p>The size of the array job.</p>
     #[serde(rename = "size")]
@@ -34,7 +35,7 @@ pub struct ArrayProperties {
 
 /// <p>An object representing the array properties of a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ArrayPropertiesDetail {
     /// <p>The job index within the array that is associated with this job. This parameter is returned for array job children.</p>
     #[serde(rename = "index")]
@@ -52,7 +53,7 @@ pub struct ArrayPropertiesDetail {
 
 /// <p>An object representing the array properties of a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ArrayPropertiesSummary {
     /// <p>The job index within the array that is associated with this job. This parameter is returned for children of array jobs.</p>
     #[serde(rename = "index")]
@@ -66,7 +67,7 @@ pub struct ArrayPropertiesSummary {
 
 /// <p>An object representing the details of a container that is part of a job attempt.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttemptContainerDetail {
     /// <p>The Amazon Resource Name (ARN) of the Amazon ECS container instance that hosts the job attempt.</p>
     #[serde(rename = "containerInstanceArn")]
@@ -96,7 +97,7 @@ pub struct AttemptContainerDetail {
 
 /// <p>An object representing a job attempt.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttemptDetail {
     /// <p>Details about the container in this job attempt.</p>
     #[serde(rename = "container")]
@@ -117,6 +118,7 @@ pub struct AttemptDetail {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelJobRequest {
     /// <p>The AWS Batch job ID of the job to cancel.</p>
     #[serde(rename = "jobId")]
@@ -127,12 +129,12 @@ pub struct CancelJobRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelJobResponse {}
 
 /// <p>An object representing an AWS Batch compute environment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComputeEnvironmentDetail {
     /// <p>The Amazon Resource Name (ARN) of the compute environment. </p>
     #[serde(rename = "computeEnvironmentArn")]
@@ -241,6 +243,7 @@ pub struct ComputeResource {
 
 /// <p>An object representing the attributes of a compute environment that can be updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ComputeResourceUpdate {
     /// <p>The desired number of EC2 vCPUS in the compute environment.</p>
     #[serde(rename = "desiredvCpus")]
@@ -258,7 +261,7 @@ pub struct ComputeResourceUpdate {
 
 /// <p>An object representing the details of a container that is part of a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ContainerDetail {
     /// <p>The command that is passed to the container. </p>
     #[serde(rename = "command")]
@@ -348,6 +351,7 @@ pub struct ContainerDetail {
 
 /// <p>The overrides that should be sent to a container.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContainerOverrides {
     /// <p>The command to send to the container that overrides the default command from the Docker image or the job definition.</p>
     #[serde(rename = "command")]
@@ -442,7 +446,7 @@ pub struct ContainerProperties {
 
 /// <p>An object representing summary details of a container within a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ContainerSummary {
     /// <p>The exit code to return upon completion.</p>
     #[serde(rename = "exitCode")]
@@ -455,6 +459,7 @@ pub struct ContainerSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateComputeEnvironmentRequest {
     /// <p>The name for your compute environment. Up to 128 letters (uppercase and lowercase), numbers, hyphens, and underscores are allowed.</p>
     #[serde(rename = "computeEnvironmentName")]
@@ -476,7 +481,7 @@ pub struct CreateComputeEnvironmentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateComputeEnvironmentResponse {
     /// <p>The Amazon Resource Name (ARN) of the compute environment. </p>
     #[serde(rename = "computeEnvironmentArn")]
@@ -489,6 +494,7 @@ pub struct CreateComputeEnvironmentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJobQueueRequest {
     /// <p>The set of compute environments mapped to a job queue and their order relative to each other. The job scheduler uses this parameter to determine which compute environment should execute a given job. Compute environments must be in the <code>VALID</code> state before you can associate them with a job queue. You can associate up to three compute environments with a job queue.</p>
     #[serde(rename = "computeEnvironmentOrder")]
@@ -506,7 +512,7 @@ pub struct CreateJobQueueRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateJobQueueResponse {
     /// <p>The Amazon Resource Name (ARN) of the job queue.</p>
     #[serde(rename = "jobQueueArn")]
@@ -517,6 +523,7 @@ pub struct CreateJobQueueResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteComputeEnvironmentRequest {
     /// <p>The name or Amazon Resource Name (ARN) of the compute environment to delete. </p>
     #[serde(rename = "computeEnvironment")]
@@ -524,10 +531,11 @@ pub struct DeleteComputeEnvironmentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteComputeEnvironmentResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteJobQueueRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the queue to delete. </p>
     #[serde(rename = "jobQueue")]
@@ -535,10 +543,11 @@ pub struct DeleteJobQueueRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteJobQueueResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterJobDefinitionRequest {
     /// <p>The name and revision (<code>name:revision</code>) or full Amazon Resource Name (ARN) of the job definition to deregister. </p>
     #[serde(rename = "jobDefinition")]
@@ -546,10 +555,11 @@ pub struct DeregisterJobDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterJobDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeComputeEnvironmentsRequest {
     /// <p>A list of up to 100 compute environment names or full Amazon Resource Name (ARN) entries. </p>
     #[serde(rename = "computeEnvironments")]
@@ -566,7 +576,7 @@ pub struct DescribeComputeEnvironmentsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeComputeEnvironmentsResponse {
     /// <p>The list of compute environments.</p>
     #[serde(rename = "computeEnvironments")]
@@ -579,6 +589,7 @@ pub struct DescribeComputeEnvironmentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeJobDefinitionsRequest {
     /// <p>The name of the job definition to describe.</p>
     #[serde(rename = "jobDefinitionName")]
@@ -603,7 +614,7 @@ pub struct DescribeJobDefinitionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeJobDefinitionsResponse {
     /// <p>The list of job definitions. </p>
     #[serde(rename = "jobDefinitions")]
@@ -616,6 +627,7 @@ pub struct DescribeJobDefinitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeJobQueuesRequest {
     /// <p>A list of up to 100 queue names or full queue Amazon Resource Name (ARN) entries.</p>
     #[serde(rename = "jobQueues")]
@@ -632,7 +644,7 @@ pub struct DescribeJobQueuesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeJobQueuesResponse {
     /// <p>The list of job queues. </p>
     #[serde(rename = "jobQueues")]
@@ -645,6 +657,7 @@ pub struct DescribeJobQueuesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeJobsRequest {
     /// <p>A list of up to 100 job IDs.</p>
     #[serde(rename = "jobs")]
@@ -652,7 +665,7 @@ pub struct DescribeJobsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeJobsResponse {
     /// <p>The list of jobs. </p>
     #[serde(rename = "jobs")]
@@ -687,7 +700,7 @@ pub struct Host {
 
 /// <p>An object representing an AWS Batch job definition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobDefinition {
     /// <p>An object with various properties specific to container-based jobs. </p>
     #[serde(rename = "containerProperties")]
@@ -742,7 +755,7 @@ pub struct JobDependency {
 
 /// <p>An object representing an AWS Batch job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobDetail {
     /// <p>The array properties of the job, if it is an array job.</p>
     #[serde(rename = "arrayProperties")]
@@ -814,7 +827,7 @@ pub struct JobDetail {
 
 /// <p>An object representing the details of an AWS Batch job queue.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobQueueDetail {
     /// <p>The compute environments that are attached to the job queue and the order in which job placement is preferred. Compute environments are selected for job placement in ascending order.</p>
     #[serde(rename = "computeEnvironmentOrder")]
@@ -843,7 +856,7 @@ pub struct JobQueueDetail {
 
 /// <p>An object representing summary details of a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobSummary {
     /// <p>The array properties of the job, if it is an array job.</p>
     #[serde(rename = "arrayProperties")]
@@ -934,6 +947,7 @@ pub struct LinuxParameters {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsRequest {
     /// <p>The job ID for an array job. Specifying an array job ID with this parameter lists all child jobs from within the specified array.</p>
     #[serde(rename = "arrayJobId")]
@@ -962,7 +976,7 @@ pub struct ListJobsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJobsResponse {
     /// <p>A list of job summaries that match the request.</p>
     #[serde(rename = "jobSummaryList")]
@@ -992,7 +1006,7 @@ pub struct MountPoint {
 
 /// <p>An object representing the elastic network interface for a multi-node parallel job node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkInterface {
     /// <p>The attachment ID for the network interface.</p>
     #[serde(rename = "attachmentId")]
@@ -1010,7 +1024,7 @@ pub struct NetworkInterface {
 
 /// <p>An object representing the details of a multi-node parallel job node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NodeDetails {
     /// <p>Specifies whether the current node is the main node for a multi-node parallel job.</p>
     #[serde(rename = "isMainNode")]
@@ -1024,6 +1038,7 @@ pub struct NodeDetails {
 
 /// <p>Object representing any node overrides to a job definition that is used in a <a>SubmitJob</a> API operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NodeOverrides {
     /// <p>The node property overrides for the job.</p>
     #[serde(rename = "nodePropertyOverrides")]
@@ -1051,7 +1066,7 @@ pub struct NodeProperties {
 
 /// <p>An object representing the properties of a node that is associated with a multi-node parallel job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NodePropertiesSummary {
     /// <p>Specifies whether the current node is the main node for a multi-node parallel job.</p>
     #[serde(rename = "isMainNode")]
@@ -1069,6 +1084,7 @@ pub struct NodePropertiesSummary {
 
 /// <p>Object representing any node overrides to a job definition that is used in a <a>SubmitJob</a> API operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NodePropertyOverride {
     /// <p>The overrides that should be sent to a node range.</p>
     #[serde(rename = "containerOverrides")]
@@ -1092,6 +1108,7 @@ pub struct NodeRangeProperty {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterJobDefinitionRequest {
     /// <p>An object with various properties specific to single-node container-based jobs. If the job definition's <code>type</code> parameter is <code>container</code>, then you must specify either <code>containerProperties</code> or <code>nodeProperties</code>.</p>
     #[serde(rename = "containerProperties")]
@@ -1122,7 +1139,7 @@ pub struct RegisterJobDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterJobDefinitionResponse {
     /// <p>The Amazon Resource Name (ARN) of the job definition. </p>
     #[serde(rename = "jobDefinitionArn")]
@@ -1156,6 +1173,7 @@ pub struct RetryStrategy {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SubmitJobRequest {
     /// <p>The array properties for the submitted job, such as the size of the array. The array size can be between 2 and 10,000. If you specify array properties for a job, it becomes an array job. For more information, see <a href="https://docs.aws.amazon.com/batch/latest/userguide/array_jobs.html">Array Jobs</a> in the <i>AWS Batch User Guide</i>.</p>
     #[serde(rename = "arrayProperties")]
@@ -1197,7 +1215,7 @@ pub struct SubmitJobRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubmitJobResponse {
     /// <p>The unique identifier for the job.</p>
     #[serde(rename = "jobId")]
@@ -1208,6 +1226,7 @@ pub struct SubmitJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminateJobRequest {
     /// <p>The AWS Batch job ID of the job to terminate.</p>
     #[serde(rename = "jobId")]
@@ -1218,7 +1237,7 @@ pub struct TerminateJobRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TerminateJobResponse {}
 
 /// <p>The <code>ulimit</code> settings to pass to the container.</p>
@@ -1236,6 +1255,7 @@ pub struct Ulimit {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateComputeEnvironmentRequest {
     /// <p>The name or full Amazon Resource Name (ARN) of the compute environment to update.</p>
     #[serde(rename = "computeEnvironment")]
@@ -1255,7 +1275,7 @@ pub struct UpdateComputeEnvironmentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateComputeEnvironmentResponse {
     /// <p>The Amazon Resource Name (ARN) of the compute environment. </p>
     #[serde(rename = "computeEnvironmentArn")]
@@ -1268,6 +1288,7 @@ pub struct UpdateComputeEnvironmentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateJobQueueRequest {
     /// <p>Details the set of compute environments mapped to a job queue and their order relative to each other. This is one of the parameters used by the job scheduler to determine which compute environment should execute a given job. </p>
     #[serde(rename = "computeEnvironmentOrder")]
@@ -1287,7 +1308,7 @@ pub struct UpdateJobQueueRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateJobQueueResponse {
     /// <p>The Amazon Resource Name (ARN) of the job queue.</p>
     #[serde(rename = "jobQueueArn")]
@@ -1327,7 +1348,12 @@ impl CancelJobError {
             match err.typ.as_str() {
                 "ClientException" => return RusotoError::Service(CancelJobError::Client(err.msg)),
                 "ServerException" => return RusotoError::Service(CancelJobError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1366,7 +1392,12 @@ impl CreateComputeEnvironmentError {
                 "ServerException" => {
                     return RusotoError::Service(CreateComputeEnvironmentError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1405,7 +1436,12 @@ impl CreateJobQueueError {
                 "ServerException" => {
                     return RusotoError::Service(CreateJobQueueError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1444,7 +1480,12 @@ impl DeleteComputeEnvironmentError {
                 "ServerException" => {
                     return RusotoError::Service(DeleteComputeEnvironmentError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1483,7 +1524,12 @@ impl DeleteJobQueueError {
                 "ServerException" => {
                     return RusotoError::Service(DeleteJobQueueError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1522,7 +1568,12 @@ impl DeregisterJobDefinitionError {
                 "ServerException" => {
                     return RusotoError::Service(DeregisterJobDefinitionError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1563,7 +1614,12 @@ impl DescribeComputeEnvironmentsError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeComputeEnvironmentsError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1602,7 +1658,12 @@ impl DescribeJobDefinitionsError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeJobDefinitionsError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1641,7 +1702,12 @@ impl DescribeJobQueuesError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeJobQueuesError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1680,7 +1746,12 @@ impl DescribeJobsError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeJobsError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1715,7 +1786,12 @@ impl ListJobsError {
             match err.typ.as_str() {
                 "ClientException" => return RusotoError::Service(ListJobsError::Client(err.msg)),
                 "ServerException" => return RusotoError::Service(ListJobsError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1754,7 +1830,12 @@ impl RegisterJobDefinitionError {
                 "ServerException" => {
                     return RusotoError::Service(RegisterJobDefinitionError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1789,7 +1870,12 @@ impl SubmitJobError {
             match err.typ.as_str() {
                 "ClientException" => return RusotoError::Service(SubmitJobError::Client(err.msg)),
                 "ServerException" => return RusotoError::Service(SubmitJobError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1828,7 +1914,12 @@ impl TerminateJobError {
                 "ServerException" => {
                     return RusotoError::Service(TerminateJobError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1867,7 +1958,12 @@ impl UpdateComputeEnvironmentError {
                 "ServerException" => {
                     return RusotoError::Service(UpdateComputeEnvironmentError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1906,7 +2002,12 @@ impl UpdateJobQueueError {
                 "ServerException" => {
                     return RusotoError::Service(UpdateJobQueueError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2021,6 +2122,292 @@ pub trait Batch {
         input: UpdateJobQueueRequest,
     ) -> RusotoFuture<UpdateJobQueueResponse, UpdateJobQueueError>;
 }
+const _: Option<&dyn Batch> = None;
+impl<'a, T: Batch + ?Sized> Batch for &'a T {
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_compute_environment(
+        &self,
+        input: CreateComputeEnvironmentRequest,
+    ) -> RusotoFuture<CreateComputeEnvironmentResponse, CreateComputeEnvironmentError> {
+        (**self).create_compute_environment(input)
+    }
+    fn create_job_queue(
+        &self,
+        input: CreateJobQueueRequest,
+    ) -> RusotoFuture<CreateJobQueueResponse, CreateJobQueueError> {
+        (**self).create_job_queue(input)
+    }
+    fn delete_compute_environment(
+        &self,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> RusotoFuture<DeleteComputeEnvironmentResponse, DeleteComputeEnvironmentError> {
+        (**self).delete_compute_environment(input)
+    }
+    fn delete_job_queue(
+        &self,
+        input: DeleteJobQueueRequest,
+    ) -> RusotoFuture<DeleteJobQueueResponse, DeleteJobQueueError> {
+        (**self).delete_job_queue(input)
+    }
+    fn deregister_job_definition(
+        &self,
+        input: DeregisterJobDefinitionRequest,
+    ) -> RusotoFuture<DeregisterJobDefinitionResponse, DeregisterJobDefinitionError> {
+        (**self).deregister_job_definition(input)
+    }
+    fn describe_compute_environments(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> RusotoFuture<DescribeComputeEnvironmentsResponse, DescribeComputeEnvironmentsError> {
+        (**self).describe_compute_environments(input)
+    }
+    fn describe_job_definitions(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> RusotoFuture<DescribeJobDefinitionsResponse, DescribeJobDefinitionsError> {
+        (**self).describe_job_definitions(input)
+    }
+    fn describe_job_queues(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> RusotoFuture<DescribeJobQueuesResponse, DescribeJobQueuesError> {
+        (**self).describe_job_queues(input)
+    }
+    fn describe_jobs(
+        &self,
+        input: DescribeJobsRequest,
+    ) -> RusotoFuture<DescribeJobsResponse, DescribeJobsError> {
+        (**self).describe_jobs(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn register_job_definition(
+        &self,
+        input: RegisterJobDefinitionRequest,
+    ) -> RusotoFuture<RegisterJobDefinitionResponse, RegisterJobDefinitionError> {
+        (**self).register_job_definition(input)
+    }
+    fn submit_job(
+        &self,
+        input: SubmitJobRequest,
+    ) -> RusotoFuture<SubmitJobResponse, SubmitJobError> {
+        (**self).submit_job(input)
+    }
+    fn terminate_job(
+        &self,
+        input: TerminateJobRequest,
+    ) -> RusotoFuture<TerminateJobResponse, TerminateJobError> {
+        (**self).terminate_job(input)
+    }
+    fn update_compute_environment(
+        &self,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> RusotoFuture<UpdateComputeEnvironmentResponse, UpdateComputeEnvironmentError> {
+        (**self).update_compute_environment(input)
+    }
+    fn update_job_queue(
+        &self,
+        input: UpdateJobQueueRequest,
+    ) -> RusotoFuture<UpdateJobQueueResponse, UpdateJobQueueError> {
+        (**self).update_job_queue(input)
+    }
+}
+impl<T: Batch + ?Sized> Batch for Box<T> {
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_compute_environment(
+        &self,
+        input: CreateComputeEnvironmentRequest,
+    ) -> RusotoFuture<CreateComputeEnvironmentResponse, CreateComputeEnvironmentError> {
+        (**self).create_compute_environment(input)
+    }
+    fn create_job_queue(
+        &self,
+        input: CreateJobQueueRequest,
+    ) -> RusotoFuture<CreateJobQueueResponse, CreateJobQueueError> {
+        (**self).create_job_queue(input)
+    }
+    fn delete_compute_environment(
+        &self,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> RusotoFuture<DeleteComputeEnvironmentResponse, DeleteComputeEnvironmentError> {
+        (**self).delete_compute_environment(input)
+    }
+    fn delete_job_queue(
+        &self,
+        input: DeleteJobQueueRequest,
+    ) -> RusotoFuture<DeleteJobQueueResponse, DeleteJobQueueError> {
+        (**self).delete_job_queue(input)
+    }
+    fn deregister_job_definition(
+        &self,
+        input: DeregisterJobDefinitionRequest,
+    ) -> RusotoFuture<DeregisterJobDefinitionResponse, DeregisterJobDefinitionError> {
+        (**self).deregister_job_definition(input)
+    }
+    fn describe_compute_environments(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> RusotoFuture<DescribeComputeEnvironmentsResponse, DescribeComputeEnvironmentsError> {
+        (**self).describe_compute_environments(input)
+    }
+    fn describe_job_definitions(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> RusotoFuture<DescribeJobDefinitionsResponse, DescribeJobDefinitionsError> {
+        (**self).describe_job_definitions(input)
+    }
+    fn describe_job_queues(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> RusotoFuture<DescribeJobQueuesResponse, DescribeJobQueuesError> {
+        (**self).describe_job_queues(input)
+    }
+    fn describe_jobs(
+        &self,
+        input: DescribeJobsRequest,
+    ) -> RusotoFuture<DescribeJobsResponse, DescribeJobsError> {
+        (**self).describe_jobs(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn register_job_definition(
+        &self,
+        input: RegisterJobDefinitionRequest,
+    ) -> RusotoFuture<RegisterJobDefinitionResponse, RegisterJobDefinitionError> {
+        (**self).register_job_definition(input)
+    }
+    fn submit_job(
+        &self,
+        input: SubmitJobRequest,
+    ) -> RusotoFuture<SubmitJobResponse, SubmitJobError> {
+        (**self).submit_job(input)
+    }
+    fn terminate_job(
+        &self,
+        input: TerminateJobRequest,
+    ) -> RusotoFuture<TerminateJobResponse, TerminateJobError> {
+        (**self).terminate_job(input)
+    }
+    fn update_compute_environment(
+        &self,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> RusotoFuture<UpdateComputeEnvironmentResponse, UpdateComputeEnvironmentError> {
+        (**self).update_compute_environment(input)
+    }
+    fn update_job_queue(
+        &self,
+        input: UpdateJobQueueRequest,
+    ) -> RusotoFuture<UpdateJobQueueResponse, UpdateJobQueueError> {
+        (**self).update_job_queue(input)
+    }
+}
+impl<T: Batch + ?Sized> Batch for ::std::sync::Arc<T> {
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_compute_environment(
+        &self,
+        input: CreateComputeEnvironmentRequest,
+    ) -> RusotoFuture<CreateComputeEnvironmentResponse, CreateComputeEnvironmentError> {
+        (**self).create_compute_environment(input)
+    }
+    fn create_job_queue(
+        &self,
+        input: CreateJobQueueRequest,
+    ) -> RusotoFuture<CreateJobQueueResponse, CreateJobQueueError> {
+        (**self).create_job_queue(input)
+    }
+    fn delete_compute_environment(
+        &self,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> RusotoFuture<DeleteComputeEnvironmentResponse, DeleteComputeEnvironmentError> {
+        (**self).delete_compute_environment(input)
+    }
+    fn delete_job_queue(
+        &self,
+        input: DeleteJobQueueRequest,
+    ) -> RusotoFuture<DeleteJobQueueResponse, DeleteJobQueueError> {
+        (**self).delete_job_queue(input)
+    }
+    fn deregister_job_definition(
+        &self,
+        input: DeregisterJobDefinitionRequest,
+    ) -> RusotoFuture<DeregisterJobDefinitionResponse, DeregisterJobDefinitionError> {
+        (**self).deregister_job_definition(input)
+    }
+    fn describe_compute_environments(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> RusotoFuture<DescribeComputeEnvironmentsResponse, DescribeComputeEnvironmentsError> {
+        (**self).describe_compute_environments(input)
+    }
+    fn describe_job_definitions(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> RusotoFuture<DescribeJobDefinitionsResponse, DescribeJobDefinitionsError> {
+        (**self).describe_job_definitions(input)
+    }
+    fn describe_job_queues(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> RusotoFuture<DescribeJobQueuesResponse, DescribeJobQueuesError> {
+        (**self).describe_job_queues(input)
+    }
+    fn describe_jobs(
+        &self,
+        input: DescribeJobsRequest,
+    ) -> RusotoFuture<DescribeJobsResponse, DescribeJobsError> {
+        (**self).describe_jobs(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn register_job_definition(
+        &self,
+        input: RegisterJobDefinitionRequest,
+    ) -> RusotoFuture<RegisterJobDefinitionResponse, RegisterJobDefinitionError> {
+        (**self).register_job_definition(input)
+    }
+    fn submit_job(
+        &self,
+        input: SubmitJobRequest,
+    ) -> RusotoFuture<SubmitJobResponse, SubmitJobError> {
+        (**self).submit_job(input)
+    }
+    fn terminate_job(
+        &self,
+        input: TerminateJobRequest,
+    ) -> RusotoFuture<TerminateJobResponse, TerminateJobError> {
+        (**self).terminate_job(input)
+    }
+    fn update_compute_environment(
+        &self,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> RusotoFuture<UpdateComputeEnvironmentResponse, UpdateComputeEnvironmentError> {
+        (**self).update_compute_environment(input)
+    }
+    fn update_job_queue(
+        &self,
+        input: UpdateJobQueueRequest,
+    ) -> RusotoFuture<UpdateJobQueueResponse, UpdateJobQueueError> {
+        (**self).update_job_queue(input)
+    }
+}
 /// A client for the AWS Batch API.
 #[derive(Clone)]
 pub struct BatchClient {
@@ -2067,6 +2454,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/canceljob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2076,6 +2464,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CancelJob")
                         .deserialize::<CancelJobResponse, _>()?;
 
                     Ok(result)
@@ -2099,6 +2488,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/createcomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CreateComputeEnvironment");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2108,6 +2499,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateComputeEnvironment")
                         .deserialize::<CreateComputeEnvironmentResponse, _>()?;
 
                     Ok(result)
@@ -2128,6 +2520,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/createjobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CreateJobQueue");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2137,6 +2531,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateJobQueue")
                         .deserialize::<CreateJobQueueResponse, _>()?;
 
                     Ok(result)
@@ -2160,6 +2555,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deletecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeleteComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2169,6 +2565,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteComputeEnvironment")
                         .deserialize::<DeleteComputeEnvironmentResponse, _>()?;
 
                     Ok(result)
@@ -2189,6 +2586,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deletejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeleteJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2198,6 +2596,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteJobQueue")
                         .deserialize::<DeleteJobQueueResponse, _>()?;
 
                     Ok(result)
@@ -2221,6 +2620,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deregisterjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeregisterJobDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2230,6 +2630,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeregisterJobDefinition")
                         .deserialize::<DeregisterJobDefinitionResponse, _>()?;
 
                     Ok(result)
@@ -2250,6 +2651,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describecomputeenvironments";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeComputeEnvironments");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2259,6 +2662,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeComputeEnvironments")
                         .deserialize::<DescribeComputeEnvironmentsResponse, _>()?;
 
                     Ok(result)
@@ -2279,6 +2683,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobdefinitions";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobDefinitions");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2288,6 +2694,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeJobDefinitions")
                         .deserialize::<DescribeJobDefinitionsResponse, _>()?;
 
                     Ok(result)
@@ -2310,6 +2717,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobqueues";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobQueues");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2319,6 +2728,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeJobQueues")
                         .deserialize::<DescribeJobQueuesResponse, _>()?;
 
                     Ok(result)
@@ -2342,6 +2752,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobs");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2351,6 +2763,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeJobs")
                         .deserialize::<DescribeJobsResponse, _>()?;
 
                     Ok(result)
@@ -2371,6 +2784,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/listjobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("ListJobs");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2380,6 +2795,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListJobs")
                         .deserialize::<ListJobsResponse, _>()?;
 
                     Ok(result)
@@ -2403,6 +2819,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/registerjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("RegisterJobDefinition");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2412,6 +2830,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RegisterJobDefinition")
                         .deserialize::<RegisterJobDefinitionResponse, _>()?;
 
                     Ok(result)
@@ -2434,6 +2853,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/submitjob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("SubmitJob");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2443,6 +2864,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("SubmitJob")
                         .deserialize::<SubmitJobResponse, _>()?;
 
                     Ok(result)
@@ -2466,6 +2888,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/terminatejob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("TerminateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2475,6 +2898,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TerminateJob")
                         .deserialize::<TerminateJobResponse, _>()?;
 
                     Ok(result)
@@ -2498,6 +2922,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/updatecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("UpdateComputeEnvironment");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2507,6 +2933,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateComputeEnvironment")
                         .deserialize::<UpdateComputeEnvironmentResponse, _>()?;
 
                     Ok(result)
@@ -2527,6 +2954,8 @@ impl Batch for BatchClient {
         let request_uri = "/v1/updatejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("UpdateJobQueue");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2536,6 +2965,7 @@ impl Batch for BatchClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateJobQueue")
                         .deserialize::<UpdateJobQueueResponse, _>()?;
 
                     Ok(result)
@@ -2551,3 +2981,566 @@ impl Batch for BatchClient {
         })
     }
 }
+
+/// A mock of the AWS Batch API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockBatch {
+    cancel_job: Option<
+        Box<
+            dyn Fn(
+                    CancelJobRequest,
+                )
+                    -> ::std::result::Result<CancelJobResponse, RusotoError<CancelJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_compute_environment: Option<
+        Box<
+            dyn Fn(
+                    CreateComputeEnvironmentRequest,
+                ) -> ::std::result::Result<
+                    CreateComputeEnvironmentResponse,
+                    RusotoError<CreateComputeEnvironmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_job_queue: Option<
+        Box<
+            dyn Fn(
+                    CreateJobQueueRequest,
+                ) -> ::std::result::Result<
+                    CreateJobQueueResponse,
+                    RusotoError<CreateJobQueueError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_compute_environment: Option<
+        Box<
+            dyn Fn(
+                    DeleteComputeEnvironmentRequest,
+                ) -> ::std::result::Result<
+                    DeleteComputeEnvironmentResponse,
+                    RusotoError<DeleteComputeEnvironmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_job_queue: Option<
+        Box<
+            dyn Fn(
+                    DeleteJobQueueRequest,
+                ) -> ::std::result::Result<
+                    DeleteJobQueueResponse,
+                    RusotoError<DeleteJobQueueError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deregister_job_definition: Option<
+        Box<
+            dyn Fn(
+                    DeregisterJobDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DeregisterJobDefinitionResponse,
+                    RusotoError<DeregisterJobDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_compute_environments: Option<
+        Box<
+            dyn Fn(
+                    DescribeComputeEnvironmentsRequest,
+                ) -> ::std::result::Result<
+                    DescribeComputeEnvironmentsResponse,
+                    RusotoError<DescribeComputeEnvironmentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_job_definitions: Option<
+        Box<
+            dyn Fn(
+                    DescribeJobDefinitionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeJobDefinitionsResponse,
+                    RusotoError<DescribeJobDefinitionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_job_queues: Option<
+        Box<
+            dyn Fn(
+                    DescribeJobQueuesRequest,
+                ) -> ::std::result::Result<
+                    DescribeJobQueuesResponse,
+                    RusotoError<DescribeJobQueuesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_jobs: Option<
+        Box<
+            dyn Fn(
+                    DescribeJobsRequest,
+                )
+                    -> ::std::result::Result<DescribeJobsResponse, RusotoError<DescribeJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListJobsRequest,
+                )
+                    -> ::std::result::Result<ListJobsResponse, RusotoError<ListJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_job_definition: Option<
+        Box<
+            dyn Fn(
+                    RegisterJobDefinitionRequest,
+                ) -> ::std::result::Result<
+                    RegisterJobDefinitionResponse,
+                    RusotoError<RegisterJobDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    submit_job: Option<
+        Box<
+            dyn Fn(
+                    SubmitJobRequest,
+                )
+                    -> ::std::result::Result<SubmitJobResponse, RusotoError<SubmitJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    terminate_job: Option<
+        Box<
+            dyn Fn(
+                    TerminateJobRequest,
+                )
+                    -> ::std::result::Result<TerminateJobResponse, RusotoError<TerminateJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_compute_environment: Option<
+        Box<
+            dyn Fn(
+                    UpdateComputeEnvironmentRequest,
+                ) -> ::std::result::Result<
+                    UpdateComputeEnvironmentResponse,
+                    RusotoError<UpdateComputeEnvironmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_job_queue: Option<
+        Box<
+            dyn Fn(
+                    UpdateJobQueueRequest,
+                ) -> ::std::result::Result<
+                    UpdateJobQueueResponse,
+                    RusotoError<UpdateJobQueueError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockBatch {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockBatch {
+        Default::default()
+    }
+
+    /// Answers `cancel_job` with what `handler` returns for its input.
+    pub fn with_cancel_job<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                CancelJobRequest,
+            ) -> ::std::result::Result<CancelJobResponse, RusotoError<CancelJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_compute_environment` with what `handler` returns for its input.
+    pub fn with_create_compute_environment<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                CreateComputeEnvironmentRequest,
+            ) -> ::std::result::Result<
+                CreateComputeEnvironmentResponse,
+                RusotoError<CreateComputeEnvironmentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_compute_environment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_job_queue` with what `handler` returns for its input.
+    pub fn with_create_job_queue<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                CreateJobQueueRequest,
+            )
+                -> ::std::result::Result<CreateJobQueueResponse, RusotoError<CreateJobQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_job_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_compute_environment` with what `handler` returns for its input.
+    pub fn with_delete_compute_environment<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                DeleteComputeEnvironmentRequest,
+            ) -> ::std::result::Result<
+                DeleteComputeEnvironmentResponse,
+                RusotoError<DeleteComputeEnvironmentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_compute_environment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_job_queue` with what `handler` returns for its input.
+    pub fn with_delete_job_queue<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                DeleteJobQueueRequest,
+            )
+                -> ::std::result::Result<DeleteJobQueueResponse, RusotoError<DeleteJobQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_job_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_job_definition` with what `handler` returns for its input.
+    pub fn with_deregister_job_definition<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                DeregisterJobDefinitionRequest,
+            ) -> ::std::result::Result<
+                DeregisterJobDefinitionResponse,
+                RusotoError<DeregisterJobDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_job_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_compute_environments` with what `handler` returns for its input.
+    pub fn with_describe_compute_environments<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                DescribeComputeEnvironmentsRequest,
+            ) -> ::std::result::Result<
+                DescribeComputeEnvironmentsResponse,
+                RusotoError<DescribeComputeEnvironmentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_compute_environments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_job_definitions` with what `handler` returns for its input.
+    pub fn with_describe_job_definitions<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                DescribeJobDefinitionsRequest,
+            ) -> ::std::result::Result<
+                DescribeJobDefinitionsResponse,
+                RusotoError<DescribeJobDefinitionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_job_definitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_job_queues` with what `handler` returns for its input.
+    pub fn with_describe_job_queues<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                DescribeJobQueuesRequest,
+            ) -> ::std::result::Result<
+                DescribeJobQueuesResponse,
+                RusotoError<DescribeJobQueuesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_job_queues = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_jobs` with what `handler` returns for its input.
+    pub fn with_describe_jobs<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                DescribeJobsRequest,
+            )
+                -> ::std::result::Result<DescribeJobsResponse, RusotoError<DescribeJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_jobs` with what `handler` returns for its input.
+    pub fn with_list_jobs<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                ListJobsRequest,
+            ) -> ::std::result::Result<ListJobsResponse, RusotoError<ListJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_job_definition` with what `handler` returns for its input.
+    pub fn with_register_job_definition<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                RegisterJobDefinitionRequest,
+            ) -> ::std::result::Result<
+                RegisterJobDefinitionResponse,
+                RusotoError<RegisterJobDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_job_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `submit_job` with what `handler` returns for its input.
+    pub fn with_submit_job<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                SubmitJobRequest,
+            ) -> ::std::result::Result<SubmitJobResponse, RusotoError<SubmitJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.submit_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `terminate_job` with what `handler` returns for its input.
+    pub fn with_terminate_job<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                TerminateJobRequest,
+            )
+                -> ::std::result::Result<TerminateJobResponse, RusotoError<TerminateJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.terminate_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_compute_environment` with what `handler` returns for its input.
+    pub fn with_update_compute_environment<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                UpdateComputeEnvironmentRequest,
+            ) -> ::std::result::Result<
+                UpdateComputeEnvironmentResponse,
+                RusotoError<UpdateComputeEnvironmentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_compute_environment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_job_queue` with what `handler` returns for its input.
+    pub fn with_update_job_queue<F>(mut self, handler: F) -> MockBatch
+    where
+        F: Fn(
+                UpdateJobQueueRequest,
+            )
+                -> ::std::result::Result<UpdateJobQueueResponse, RusotoError<UpdateJobQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_job_queue = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Batch for MockBatch {
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        match self.cancel_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for cancel_job"),
+        }
+    }
+    fn create_compute_environment(
+        &self,
+        input: CreateComputeEnvironmentRequest,
+    ) -> RusotoFuture<CreateComputeEnvironmentResponse, CreateComputeEnvironmentError> {
+        match self.create_compute_environment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for create_compute_environment"),
+        }
+    }
+    fn create_job_queue(
+        &self,
+        input: CreateJobQueueRequest,
+    ) -> RusotoFuture<CreateJobQueueResponse, CreateJobQueueError> {
+        match self.create_job_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for create_job_queue"),
+        }
+    }
+    fn delete_compute_environment(
+        &self,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> RusotoFuture<DeleteComputeEnvironmentResponse, DeleteComputeEnvironmentError> {
+        match self.delete_compute_environment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for delete_compute_environment"),
+        }
+    }
+    fn delete_job_queue(
+        &self,
+        input: DeleteJobQueueRequest,
+    ) -> RusotoFuture<DeleteJobQueueResponse, DeleteJobQueueError> {
+        match self.delete_job_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for delete_job_queue"),
+        }
+    }
+    fn deregister_job_definition(
+        &self,
+        input: DeregisterJobDefinitionRequest,
+    ) -> RusotoFuture<DeregisterJobDefinitionResponse, DeregisterJobDefinitionError> {
+        match self.deregister_job_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for deregister_job_definition"),
+        }
+    }
+    fn describe_compute_environments(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> RusotoFuture<DescribeComputeEnvironmentsResponse, DescribeComputeEnvironmentsError> {
+        match self.describe_compute_environments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for describe_compute_environments"),
+        }
+    }
+    fn describe_job_definitions(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> RusotoFuture<DescribeJobDefinitionsResponse, DescribeJobDefinitionsError> {
+        match self.describe_job_definitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for describe_job_definitions"),
+        }
+    }
+    fn describe_job_queues(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> RusotoFuture<DescribeJobQueuesResponse, DescribeJobQueuesError> {
+        match self.describe_job_queues {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for describe_job_queues"),
+        }
+    }
+    fn describe_jobs(
+        &self,
+        input: DescribeJobsRequest,
+    ) -> RusotoFuture<DescribeJobsResponse, DescribeJobsError> {
+        match self.describe_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for describe_jobs"),
+        }
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        match self.list_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for list_jobs"),
+        }
+    }
+    fn register_job_definition(
+        &self,
+        input: RegisterJobDefinitionRequest,
+    ) -> RusotoFuture<RegisterJobDefinitionResponse, RegisterJobDefinitionError> {
+        match self.register_job_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for register_job_definition"),
+        }
+    }
+    fn submit_job(
+        &self,
+        input: SubmitJobRequest,
+    ) -> RusotoFuture<SubmitJobResponse, SubmitJobError> {
+        match self.submit_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for submit_job"),
+        }
+    }
+    fn terminate_job(
+        &self,
+        input: TerminateJobRequest,
+    ) -> RusotoFuture<TerminateJobResponse, TerminateJobError> {
+        match self.terminate_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for terminate_job"),
+        }
+    }
+    fn update_compute_environment(
+        &self,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> RusotoFuture<UpdateComputeEnvironmentResponse, UpdateComputeEnvironmentError> {
+        match self.update_compute_environment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for update_compute_environment"),
+        }
+    }
+    fn update_job_queue(
+        &self,
+        input: UpdateJobQueueRequest,
+    ) -> RusotoFuture<UpdateJobQueueResponse, UpdateJobQueueError> {
+        match self.update_job_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBatch has no handler for update_job_queue"),
+        }
+    }
+}
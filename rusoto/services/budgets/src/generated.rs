@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Represents the output of the <code>CreateBudget</code> operation. The content consists of the detailed metadata and data file information, and the current status of the <code>budget</code> object.</p> <p>This is the ARN pattern for a budget: </p> <p> <code>arn:aws:budgetservice::AccountId:budget/budgetName</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -65,9 +66,47 @@ pub struct Budget {
     pub time_unit: String,
 }
 
+impl Validate for Budget {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.budget_limit {
+            value.validate_at(&field_path(path, "budget_limit"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+        if let Some(ref value) = self.calculated_spend {
+            value.validate_at(&field_path(path, "calculated_spend"), errors);
+        }
+        if let Some(ref value) = self.cost_filters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "cost_filters"), key);
+                for (i2, item2) in item.iter().enumerate() {
+                    let item_path2 = format!("{}[{}]", item_path, i2);
+                    errors.check_length(&item_path2, item2.chars().count(), None, Some(2147483647));
+                    errors.check_pattern(&item_path2, &item2, "[\\S\\s]*");
+                }
+            }
+        }
+        if let Some(ref value) = self.planned_budget_limits {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "planned_budget_limits"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>A history of the state of a budget at the end of the budget's specified time period.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BudgetPerformanceHistory {
     #[serde(rename = "BudgetName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -94,7 +133,7 @@ pub struct BudgetPerformanceHistory {
 
 /// <p>The amount of cost or usage that you created the budget for, compared to your actual costs or usage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BudgetedAndActualAmounts {
     /// <p>Your actual costs or usage for a budget period.</p>
     #[serde(rename = "ActualAmount")]
@@ -122,6 +161,16 @@ pub struct CalculatedSpend {
     pub forecasted_spend: Option<Spend>,
 }
 
+impl Validate for CalculatedSpend {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.actual_spend
+            .validate_at(&field_path(path, "actual_spend"), errors);
+        if let Some(ref value) = self.forecasted_spend {
+            value.validate_at(&field_path(path, "forecasted_spend"), errors);
+        }
+    }
+}
+
 /// <p>The types of cost that are included in a <code>COST</code> budget, such as tax and subscriptions.</p> <p> <code>USAGE</code>, <code>RI_UTILIZATION</code>, and <code>RI_COVERAGE</code> budgets do not have <code>CostTypes</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CostTypes {
@@ -173,6 +222,7 @@ pub struct CostTypes {
 
 /// <p> Request of CreateBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBudgetRequest {
     /// <p>The <code>accountId</code> that is associated with the budget.</p>
     #[serde(rename = "AccountId")]
@@ -186,13 +236,43 @@ pub struct CreateBudgetRequest {
     pub notifications_with_subscribers: Option<Vec<NotificationWithSubscribers>>,
 }
 
+impl Validate for CreateBudgetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        self.budget.validate_at(&field_path(path, "budget"), errors);
+        if let Some(ref value) = self.notifications_with_subscribers {
+            errors.check_length(
+                &field_path(path, "notifications_with_subscribers"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "notifications_with_subscribers"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p> Response of CreateBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBudgetResponse {}
 
 /// <p> Request of CreateNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateNotificationRequest {
     /// <p>The <code>accountId</code> that is associated with the budget that you want to create a notification for.</p>
     #[serde(rename = "AccountId")]
@@ -208,13 +288,49 @@ pub struct CreateNotificationRequest {
     pub subscribers: Vec<Subscriber>,
 }
 
+impl Validate for CreateNotificationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+        self.notification
+            .validate_at(&field_path(path, "notification"), errors);
+        errors.check_length(
+            &field_path(path, "subscribers"),
+            self.subscribers.len(),
+            Some(1),
+            Some(11),
+        );
+        for (i, item) in self.subscribers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subscribers"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p> Response of CreateNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateNotificationResponse {}
 
 /// <p> Request of CreateSubscriber </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSubscriberRequest {
     /// <p>The <code>accountId</code> that is associated with the budget that you want to create a subscriber for.</p>
     #[serde(rename = "AccountId")]
@@ -230,13 +346,41 @@ pub struct CreateSubscriberRequest {
     pub subscriber: Subscriber,
 }
 
+impl Validate for CreateSubscriberRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+        self.notification
+            .validate_at(&field_path(path, "notification"), errors);
+        self.subscriber
+            .validate_at(&field_path(path, "subscriber"), errors);
+    }
+}
+
 /// <p> Response of CreateSubscriber </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSubscriberResponse {}
 
 /// <p> Request of DeleteBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBudgetRequest {
     /// <p>The <code>accountId</code> that is associated with the budget that you want to delete.</p>
     #[serde(rename = "AccountId")]
@@ -246,13 +390,37 @@ pub struct DeleteBudgetRequest {
     pub budget_name: String,
 }
 
+impl Validate for DeleteBudgetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+    }
+}
+
 /// <p> Response of DeleteBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBudgetResponse {}
 
 /// <p> Request of DeleteNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteNotificationRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose notification you want to delete.</p>
     #[serde(rename = "AccountId")]
@@ -265,13 +433,39 @@ pub struct DeleteNotificationRequest {
     pub notification: Notification,
 }
 
+impl Validate for DeleteNotificationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+        self.notification
+            .validate_at(&field_path(path, "notification"), errors);
+    }
+}
+
 /// <p> Response of DeleteNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteNotificationResponse {}
 
 /// <p> Request of DeleteSubscriber </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSubscriberRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose subscriber you want to delete.</p>
     #[serde(rename = "AccountId")]
@@ -287,12 +481,40 @@ pub struct DeleteSubscriberRequest {
     pub subscriber: Subscriber,
 }
 
+impl Validate for DeleteSubscriberRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+        self.notification
+            .validate_at(&field_path(path, "notification"), errors);
+        self.subscriber
+            .validate_at(&field_path(path, "subscriber"), errors);
+    }
+}
+
 /// <p> Response of DeleteSubscriber </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSubscriberResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBudgetPerformanceHistoryRequest {
     #[serde(rename = "AccountId")]
     pub account_id: String,
@@ -310,8 +532,43 @@ pub struct DescribeBudgetPerformanceHistoryRequest {
     pub time_period: Option<TimePeriod>,
 }
 
+impl Validate for DescribeBudgetPerformanceHistoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBudgetPerformanceHistoryResponse {
     /// <p>The history of how often the budget has gone into an <code>ALARM</code> state.</p> <p>For <code>DAILY</code> budgets, the history saves the state of the budget for the last 60 days. For <code>MONTHLY</code> budgets, the history saves the state of the budget for the current month plus the last 12 months. For <code>QUARTERLY</code> budgets, the history saves the state of the budget for the last four quarters.</p>
     #[serde(rename = "BudgetPerformanceHistory")]
@@ -324,6 +581,7 @@ pub struct DescribeBudgetPerformanceHistoryResponse {
 
 /// <p> Request of DescribeBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBudgetRequest {
     /// <p>The <code>accountId</code> that is associated with the budget that you want a description of.</p>
     #[serde(rename = "AccountId")]
@@ -333,9 +591,32 @@ pub struct DescribeBudgetRequest {
     pub budget_name: String,
 }
 
+impl Validate for DescribeBudgetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+    }
+}
+
 /// <p> Response of DescribeBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBudgetResponse {
     /// <p>The description of the budget.</p>
     #[serde(rename = "Budget")]
@@ -345,6 +626,7 @@ pub struct DescribeBudgetResponse {
 
 /// <p> Request of DescribeBudgets </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBudgetsRequest {
     /// <p>The <code>accountId</code> that is associated with the budgets that you want descriptions of.</p>
     #[serde(rename = "AccountId")]
@@ -359,9 +641,38 @@ pub struct DescribeBudgetsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeBudgetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 /// <p> Response of DescribeBudgets </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBudgetsResponse {
     /// <p>A list of budgets.</p>
     #[serde(rename = "Budgets")]
@@ -375,6 +686,7 @@ pub struct DescribeBudgetsResponse {
 
 /// <p> Request of DescribeNotificationsForBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeNotificationsForBudgetRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose notifications you want descriptions of.</p>
     #[serde(rename = "AccountId")]
@@ -392,9 +704,44 @@ pub struct DescribeNotificationsForBudgetRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeNotificationsForBudgetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 /// <p> Response of GetNotificationsForBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeNotificationsForBudgetResponse {
     /// <p>The pagination token in the service response that indicates the next set of results that you can retrieve.</p>
     #[serde(rename = "NextToken")]
@@ -408,6 +755,7 @@ pub struct DescribeNotificationsForBudgetResponse {
 
 /// <p> Request of DescribeSubscribersForNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSubscribersForNotificationRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose subscribers you want descriptions of.</p>
     #[serde(rename = "AccountId")]
@@ -428,9 +776,46 @@ pub struct DescribeSubscribersForNotificationRequest {
     pub notification: Notification,
 }
 
+impl Validate for DescribeSubscribersForNotificationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+        self.notification
+            .validate_at(&field_path(path, "notification"), errors);
+    }
+}
+
 /// <p> Response of DescribeSubscribersForNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSubscribersForNotificationResponse {
     /// <p>The pagination token in the service response that indicates the next set of results that you can retrieve.</p>
     #[serde(rename = "NextToken")]
@@ -464,8 +849,20 @@ pub struct Notification {
     pub threshold_type: Option<String>,
 }
 
+impl Validate for Notification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "threshold"),
+            self.threshold,
+            None,
+            Some(15000000000000.0),
+        );
+    }
+}
+
 /// <p>A notification with subscribers. A notification can have one SNS subscriber and up to 10 email subscribers, for a total of 11 subscribers.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotificationWithSubscribers {
     /// <p>The notification that is associated with a budget.</p>
     #[serde(rename = "Notification")]
@@ -475,6 +872,23 @@ pub struct NotificationWithSubscribers {
     pub subscribers: Vec<Subscriber>,
 }
 
+impl Validate for NotificationWithSubscribers {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.notification
+            .validate_at(&field_path(path, "notification"), errors);
+        errors.check_length(
+            &field_path(path, "subscribers"),
+            self.subscribers.len(),
+            Some(1),
+            Some(11),
+        );
+        for (i, item) in self.subscribers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subscribers"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p><p>The amount of cost or usage that is measured for a budget.</p> <p>For example, a <code>Spend</code> for <code>3 GB</code> of S3 usage would have the following parameters:</p> <ul> <li> <p>An <code>Amount</code> of <code>3</code> </p> </li> <li> <p>A <code>unit</code> of <code>GB</code> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Spend {
@@ -486,6 +900,29 @@ pub struct Spend {
     pub unit: String,
 }
 
+impl Validate for Spend {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "amount"),
+            self.amount.chars().count(),
+            Some(1),
+            Some(2147483647),
+        );
+        errors.check_pattern(
+            &field_path(path, "amount"),
+            &self.amount,
+            "([0-9]*\\.)?[0-9]+",
+        );
+        errors.check_length(
+            &field_path(path, "unit"),
+            self.unit.chars().count(),
+            Some(1),
+            Some(2147483647),
+        );
+        errors.check_pattern(&field_path(path, "unit"), &self.unit, ".*");
+    }
+}
+
 /// <p><p>The subscriber to a budget notification. The subscriber consists of a subscription type and either an Amazon SNS topic or an email address.</p> <p>For example, an email subscriber would have the following parameters:</p> <ul> <li> <p>A <code>subscriptionType</code> of <code>EMAIL</code> </p> </li> <li> <p>An <code>address</code> of <code>example@example.com</code> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Subscriber {
@@ -497,6 +934,22 @@ pub struct Subscriber {
     pub subscription_type: String,
 }
 
+impl Validate for Subscriber {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "address"),
+            self.address.chars().count(),
+            Some(1),
+            Some(2147483647),
+        );
+        errors.check_pattern(
+            &field_path(path, "address"),
+            &self.address,
+            "(.*[\\n\\r\\t\\f\\ ]?)*",
+        );
+    }
+}
+
 /// <p>The period of time that is covered by a budget. The period has a start date and an end date. The start date must come before the end date. There are no restrictions on the end date. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimePeriod {
@@ -512,6 +965,7 @@ pub struct TimePeriod {
 
 /// <p> Request of UpdateBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateBudgetRequest {
     /// <p>The <code>accountId</code> that is associated with the budget that you want to update.</p>
     #[serde(rename = "AccountId")]
@@ -521,13 +975,28 @@ pub struct UpdateBudgetRequest {
     pub new_budget: Budget,
 }
 
+impl Validate for UpdateBudgetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        self.new_budget
+            .validate_at(&field_path(path, "new_budget"), errors);
+    }
+}
+
 /// <p> Response of UpdateBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateBudgetResponse {}
 
 /// <p> Request of UpdateNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateNotificationRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose notification you want to update.</p>
     #[serde(rename = "AccountId")]
@@ -543,13 +1012,41 @@ pub struct UpdateNotificationRequest {
     pub old_notification: Notification,
 }
 
+impl Validate for UpdateNotificationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+        self.new_notification
+            .validate_at(&field_path(path, "new_notification"), errors);
+        self.old_notification
+            .validate_at(&field_path(path, "old_notification"), errors);
+    }
+}
+
 /// <p> Response of UpdateNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateNotificationResponse {}
 
 /// <p> Request of UpdateSubscriber </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSubscriberRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose subscriber you want to update.</p>
     #[serde(rename = "AccountId")]
@@ -568,9 +1065,38 @@ pub struct UpdateSubscriberRequest {
     pub old_subscriber: Subscriber,
 }
 
+impl Validate for UpdateSubscriberRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "budget_name"),
+            &self.budget_name,
+            "^(?![^:\\\\]*/action/)[^:\\\\]+$",
+        );
+        self.new_subscriber
+            .validate_at(&field_path(path, "new_subscriber"), errors);
+        self.notification
+            .validate_at(&field_path(path, "notification"), errors);
+        self.old_subscriber
+            .validate_at(&field_path(path, "old_subscriber"), errors);
+    }
+}
+
 /// <p> Response of UpdateSubscriber </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSubscriberResponse {}
 
 /// Errors returned by CreateBudget
@@ -602,7 +1128,12 @@ impl CreateBudgetError {
                 "InvalidParameterException" => {
                     return RusotoError::Service(CreateBudgetError::InvalidParameter(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -660,7 +1191,12 @@ impl CreateNotificationError {
                 "NotFoundException" => {
                     return RusotoError::Service(CreateNotificationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -719,7 +1255,12 @@ impl CreateSubscriberError {
                 "NotFoundException" => {
                     return RusotoError::Service(CreateSubscriberError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -766,7 +1307,12 @@ impl DeleteBudgetError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteBudgetError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -811,7 +1357,12 @@ impl DeleteNotificationError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteNotificationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -856,7 +1407,12 @@ impl DeleteSubscriberError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteSubscriberError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -901,7 +1457,12 @@ impl DescribeBudgetError {
                 "NotFoundException" => {
                     return RusotoError::Service(DescribeBudgetError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -968,7 +1529,12 @@ impl DescribeBudgetPerformanceHistoryError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1025,7 +1591,12 @@ impl DescribeBudgetsError {
                 "NotFoundException" => {
                     return RusotoError::Service(DescribeBudgetsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1094,7 +1665,12 @@ impl DescribeNotificationsForBudgetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1163,7 +1739,12 @@ impl DescribeSubscribersForNotificationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1210,7 +1791,12 @@ impl UpdateBudgetError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateBudgetError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1260,7 +1846,12 @@ impl UpdateNotificationError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateNotificationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1311,7 +1902,12 @@ impl UpdateSubscriberError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateSubscriberError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1422,149 +2018,459 @@ pub trait Budgets {
         input: UpdateSubscriberRequest,
     ) -> RusotoFuture<UpdateSubscriberResponse, UpdateSubscriberError>;
 }
-/// A client for the AWSBudgets API.
-#[derive(Clone)]
-pub struct BudgetsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl BudgetsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> BudgetsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> BudgetsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> BudgetsClient {
-        BudgetsClient { client, region }
-    }
-}
-
-impl Budgets for BudgetsClient {
-    /// <p><p>Creates a budget and, if included, notifications and subscribers. </p> <important> <p>Only one of <code>BudgetLimit</code> or <code>PlannedBudgetLimits</code> can be present in the syntax at one time. Use the syntax that matches your case. The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_CreateBudget.html#API_CreateBudget_Examples">Examples</a> section. </p> </important></p>
+const _: Option<&dyn Budgets> = None;
+impl<'a, T: Budgets + ?Sized> Budgets for &'a T {
     fn create_budget(
         &self,
         input: CreateBudgetRequest,
     ) -> RusotoFuture<CreateBudgetResponse, CreateBudgetError> {
-        let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateBudget");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBudgetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBudgetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_budget(input)
     }
-
-    /// <p>Creates a notification. You must create the budget before you create the associated notification.</p>
     fn create_notification(
         &self,
         input: CreateNotificationRequest,
     ) -> RusotoFuture<CreateNotificationResponse, CreateNotificationError> {
-        let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateNotification");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateNotificationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateNotificationError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_notification(input)
     }
-
-    /// <p>Creates a subscriber. You must create the associated budget and notification before you create the subscriber.</p>
     fn create_subscriber(
         &self,
         input: CreateSubscriberRequest,
     ) -> RusotoFuture<CreateSubscriberResponse, CreateSubscriberError> {
-        let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateSubscriber");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSubscriberResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSubscriberError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_subscriber(input)
     }
-
-    /// <p><p>Deletes a budget. You can delete your budget at any time.</p> <important> <p>Deleting a budget also deletes the notifications and subscribers that are associated with that budget.</p> </important></p>
     fn delete_budget(
         &self,
         input: DeleteBudgetRequest,
     ) -> RusotoFuture<DeleteBudgetResponse, DeleteBudgetError> {
-        let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteBudget");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBudgetResponse, _>()
-                }))
+        (**self).delete_budget(input)
+    }
+    fn delete_notification(
+        &self,
+        input: DeleteNotificationRequest,
+    ) -> RusotoFuture<DeleteNotificationResponse, DeleteNotificationError> {
+        (**self).delete_notification(input)
+    }
+    fn delete_subscriber(
+        &self,
+        input: DeleteSubscriberRequest,
+    ) -> RusotoFuture<DeleteSubscriberResponse, DeleteSubscriberError> {
+        (**self).delete_subscriber(input)
+    }
+    fn describe_budget(
+        &self,
+        input: DescribeBudgetRequest,
+    ) -> RusotoFuture<DescribeBudgetResponse, DescribeBudgetError> {
+        (**self).describe_budget(input)
+    }
+    fn describe_budget_performance_history(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> RusotoFuture<DescribeBudgetPerformanceHistoryResponse, DescribeBudgetPerformanceHistoryError>
+    {
+        (**self).describe_budget_performance_history(input)
+    }
+    fn describe_budgets(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> RusotoFuture<DescribeBudgetsResponse, DescribeBudgetsError> {
+        (**self).describe_budgets(input)
+    }
+    fn describe_notifications_for_budget(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> RusotoFuture<DescribeNotificationsForBudgetResponse, DescribeNotificationsForBudgetError>
+    {
+        (**self).describe_notifications_for_budget(input)
+    }
+    fn describe_subscribers_for_notification(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> RusotoFuture<
+        DescribeSubscribersForNotificationResponse,
+        DescribeSubscribersForNotificationError,
+    > {
+        (**self).describe_subscribers_for_notification(input)
+    }
+    fn update_budget(
+        &self,
+        input: UpdateBudgetRequest,
+    ) -> RusotoFuture<UpdateBudgetResponse, UpdateBudgetError> {
+        (**self).update_budget(input)
+    }
+    fn update_notification(
+        &self,
+        input: UpdateNotificationRequest,
+    ) -> RusotoFuture<UpdateNotificationResponse, UpdateNotificationError> {
+        (**self).update_notification(input)
+    }
+    fn update_subscriber(
+        &self,
+        input: UpdateSubscriberRequest,
+    ) -> RusotoFuture<UpdateSubscriberResponse, UpdateSubscriberError> {
+        (**self).update_subscriber(input)
+    }
+}
+impl<T: Budgets + ?Sized> Budgets for Box<T> {
+    fn create_budget(
+        &self,
+        input: CreateBudgetRequest,
+    ) -> RusotoFuture<CreateBudgetResponse, CreateBudgetError> {
+        (**self).create_budget(input)
+    }
+    fn create_notification(
+        &self,
+        input: CreateNotificationRequest,
+    ) -> RusotoFuture<CreateNotificationResponse, CreateNotificationError> {
+        (**self).create_notification(input)
+    }
+    fn create_subscriber(
+        &self,
+        input: CreateSubscriberRequest,
+    ) -> RusotoFuture<CreateSubscriberResponse, CreateSubscriberError> {
+        (**self).create_subscriber(input)
+    }
+    fn delete_budget(
+        &self,
+        input: DeleteBudgetRequest,
+    ) -> RusotoFuture<DeleteBudgetResponse, DeleteBudgetError> {
+        (**self).delete_budget(input)
+    }
+    fn delete_notification(
+        &self,
+        input: DeleteNotificationRequest,
+    ) -> RusotoFuture<DeleteNotificationResponse, DeleteNotificationError> {
+        (**self).delete_notification(input)
+    }
+    fn delete_subscriber(
+        &self,
+        input: DeleteSubscriberRequest,
+    ) -> RusotoFuture<DeleteSubscriberResponse, DeleteSubscriberError> {
+        (**self).delete_subscriber(input)
+    }
+    fn describe_budget(
+        &self,
+        input: DescribeBudgetRequest,
+    ) -> RusotoFuture<DescribeBudgetResponse, DescribeBudgetError> {
+        (**self).describe_budget(input)
+    }
+    fn describe_budget_performance_history(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> RusotoFuture<DescribeBudgetPerformanceHistoryResponse, DescribeBudgetPerformanceHistoryError>
+    {
+        (**self).describe_budget_performance_history(input)
+    }
+    fn describe_budgets(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> RusotoFuture<DescribeBudgetsResponse, DescribeBudgetsError> {
+        (**self).describe_budgets(input)
+    }
+    fn describe_notifications_for_budget(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> RusotoFuture<DescribeNotificationsForBudgetResponse, DescribeNotificationsForBudgetError>
+    {
+        (**self).describe_notifications_for_budget(input)
+    }
+    fn describe_subscribers_for_notification(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> RusotoFuture<
+        DescribeSubscribersForNotificationResponse,
+        DescribeSubscribersForNotificationError,
+    > {
+        (**self).describe_subscribers_for_notification(input)
+    }
+    fn update_budget(
+        &self,
+        input: UpdateBudgetRequest,
+    ) -> RusotoFuture<UpdateBudgetResponse, UpdateBudgetError> {
+        (**self).update_budget(input)
+    }
+    fn update_notification(
+        &self,
+        input: UpdateNotificationRequest,
+    ) -> RusotoFuture<UpdateNotificationResponse, UpdateNotificationError> {
+        (**self).update_notification(input)
+    }
+    fn update_subscriber(
+        &self,
+        input: UpdateSubscriberRequest,
+    ) -> RusotoFuture<UpdateSubscriberResponse, UpdateSubscriberError> {
+        (**self).update_subscriber(input)
+    }
+}
+impl<T: Budgets + ?Sized> Budgets for ::std::sync::Arc<T> {
+    fn create_budget(
+        &self,
+        input: CreateBudgetRequest,
+    ) -> RusotoFuture<CreateBudgetResponse, CreateBudgetError> {
+        (**self).create_budget(input)
+    }
+    fn create_notification(
+        &self,
+        input: CreateNotificationRequest,
+    ) -> RusotoFuture<CreateNotificationResponse, CreateNotificationError> {
+        (**self).create_notification(input)
+    }
+    fn create_subscriber(
+        &self,
+        input: CreateSubscriberRequest,
+    ) -> RusotoFuture<CreateSubscriberResponse, CreateSubscriberError> {
+        (**self).create_subscriber(input)
+    }
+    fn delete_budget(
+        &self,
+        input: DeleteBudgetRequest,
+    ) -> RusotoFuture<DeleteBudgetResponse, DeleteBudgetError> {
+        (**self).delete_budget(input)
+    }
+    fn delete_notification(
+        &self,
+        input: DeleteNotificationRequest,
+    ) -> RusotoFuture<DeleteNotificationResponse, DeleteNotificationError> {
+        (**self).delete_notification(input)
+    }
+    fn delete_subscriber(
+        &self,
+        input: DeleteSubscriberRequest,
+    ) -> RusotoFuture<DeleteSubscriberResponse, DeleteSubscriberError> {
+        (**self).delete_subscriber(input)
+    }
+    fn describe_budget(
+        &self,
+        input: DescribeBudgetRequest,
+    ) -> RusotoFuture<DescribeBudgetResponse, DescribeBudgetError> {
+        (**self).describe_budget(input)
+    }
+    fn describe_budget_performance_history(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> RusotoFuture<DescribeBudgetPerformanceHistoryResponse, DescribeBudgetPerformanceHistoryError>
+    {
+        (**self).describe_budget_performance_history(input)
+    }
+    fn describe_budgets(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> RusotoFuture<DescribeBudgetsResponse, DescribeBudgetsError> {
+        (**self).describe_budgets(input)
+    }
+    fn describe_notifications_for_budget(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> RusotoFuture<DescribeNotificationsForBudgetResponse, DescribeNotificationsForBudgetError>
+    {
+        (**self).describe_notifications_for_budget(input)
+    }
+    fn describe_subscribers_for_notification(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> RusotoFuture<
+        DescribeSubscribersForNotificationResponse,
+        DescribeSubscribersForNotificationError,
+    > {
+        (**self).describe_subscribers_for_notification(input)
+    }
+    fn update_budget(
+        &self,
+        input: UpdateBudgetRequest,
+    ) -> RusotoFuture<UpdateBudgetResponse, UpdateBudgetError> {
+        (**self).update_budget(input)
+    }
+    fn update_notification(
+        &self,
+        input: UpdateNotificationRequest,
+    ) -> RusotoFuture<UpdateNotificationResponse, UpdateNotificationError> {
+        (**self).update_notification(input)
+    }
+    fn update_subscriber(
+        &self,
+        input: UpdateSubscriberRequest,
+    ) -> RusotoFuture<UpdateSubscriberResponse, UpdateSubscriberError> {
+        (**self).update_subscriber(input)
+    }
+}
+/// A client for the AWSBudgets API.
+#[derive(Clone)]
+pub struct BudgetsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl BudgetsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> BudgetsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> BudgetsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> BudgetsClient {
+        BudgetsClient { client, region }
+    }
+}
+
+impl Budgets for BudgetsClient {
+    /// <p><p>Creates a budget and, if included, notifications and subscribers. </p> <important> <p>Only one of <code>BudgetLimit</code> or <code>PlannedBudgetLimits</code> can be present in the syntax at one time. Use the syntax that matches your case. The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_CreateBudget.html#API_CreateBudget_Examples">Examples</a> section. </p> </important></p>
+    fn create_budget(
+        &self,
+        input: CreateBudgetRequest,
+    ) -> RusotoFuture<CreateBudgetResponse, CreateBudgetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("CreateBudget");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateBudget");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateBudgetResponse, _>(
+                    response,
+                    "CreateBudget",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBudgetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a notification. You must create the budget before you create the associated notification.</p>
+    fn create_notification(
+        &self,
+        input: CreateNotificationRequest,
+    ) -> RusotoFuture<CreateNotificationResponse, CreateNotificationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("CreateNotification");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateNotification");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateNotificationResponse, _>(
+                    response,
+                    "CreateNotification",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateNotificationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a subscriber. You must create the associated budget and notification before you create the subscriber.</p>
+    fn create_subscriber(
+        &self,
+        input: CreateSubscriberRequest,
+    ) -> RusotoFuture<CreateSubscriberResponse, CreateSubscriberError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("CreateSubscriber");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateSubscriber");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSubscriberResponse, _>(
+                    response,
+                    "CreateSubscriber",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSubscriberError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a budget. You can delete your budget at any time.</p> <important> <p>Deleting a budget also deletes the notifications and subscribers that are associated with that budget.</p> </important></p>
+    fn delete_budget(
+        &self,
+        input: DeleteBudgetRequest,
+    ) -> RusotoFuture<DeleteBudgetResponse, DeleteBudgetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DeleteBudget");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteBudget");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteBudgetResponse, _>(
+                    response,
+                    "DeleteBudget",
+                )
             } else {
                 Box::new(
                     response
@@ -1581,7 +2487,16 @@ impl Budgets for BudgetsClient {
         &self,
         input: DeleteNotificationRequest,
     ) -> RusotoFuture<DeleteNotificationResponse, DeleteNotificationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DeleteNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteNotification");
@@ -1590,10 +2505,10 @@ impl Budgets for BudgetsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteNotificationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteNotificationResponse, _>(
+                    response,
+                    "DeleteNotification",
+                )
             } else {
                 Box::new(
                     response
@@ -1610,7 +2525,16 @@ impl Budgets for BudgetsClient {
         &self,
         input: DeleteSubscriberRequest,
     ) -> RusotoFuture<DeleteSubscriberResponse, DeleteSubscriberError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DeleteSubscriber");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteSubscriber");
@@ -1619,10 +2543,10 @@ impl Budgets for BudgetsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSubscriberResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteSubscriberResponse, _>(
+                    response,
+                    "DeleteSubscriber",
+                )
             } else {
                 Box::new(
                     response
@@ -1639,7 +2563,16 @@ impl Budgets for BudgetsClient {
         &self,
         input: DescribeBudgetRequest,
     ) -> RusotoFuture<DescribeBudgetResponse, DescribeBudgetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DescribeBudget");
@@ -1648,10 +2581,10 @@ impl Budgets for BudgetsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBudgetResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeBudgetResponse, _>(
+                    response,
+                    "DescribeBudget",
+                )
             } else {
                 Box::new(
                     response
@@ -1669,7 +2602,16 @@ impl Budgets for BudgetsClient {
         input: DescribeBudgetPerformanceHistoryRequest,
     ) -> RusotoFuture<DescribeBudgetPerformanceHistoryResponse, DescribeBudgetPerformanceHistoryError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeBudgetPerformanceHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1681,10 +2623,10 @@ impl Budgets for BudgetsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBudgetPerformanceHistoryResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeBudgetPerformanceHistoryResponse, _>(
+                    response,
+                    "DescribeBudgetPerformanceHistory",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeBudgetPerformanceHistoryError::from_response(
@@ -1700,7 +2642,16 @@ impl Budgets for BudgetsClient {
         &self,
         input: DescribeBudgetsRequest,
     ) -> RusotoFuture<DescribeBudgetsResponse, DescribeBudgetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeBudgets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DescribeBudgets");
@@ -1709,10 +2660,10 @@ impl Budgets for BudgetsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBudgetsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeBudgetsResponse, _>(
+                    response,
+                    "DescribeBudgets",
+                )
             } else {
                 Box::new(
                     response
@@ -1730,7 +2681,16 @@ impl Budgets for BudgetsClient {
         input: DescribeNotificationsForBudgetRequest,
     ) -> RusotoFuture<DescribeNotificationsForBudgetResponse, DescribeNotificationsForBudgetError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeNotificationsForBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1742,10 +2702,10 @@ impl Budgets for BudgetsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeNotificationsForBudgetResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeNotificationsForBudgetResponse, _>(
+                    response,
+                    "DescribeNotificationsForBudget",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeNotificationsForBudgetError::from_response(response))
@@ -1762,7 +2722,16 @@ impl Budgets for BudgetsClient {
         DescribeSubscribersForNotificationResponse,
         DescribeSubscribersForNotificationError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeSubscribersForNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1774,10 +2743,10 @@ impl Budgets for BudgetsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSubscribersForNotificationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeSubscribersForNotificationResponse, _>(
+                    response,
+                    "DescribeSubscribersForNotification",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeSubscribersForNotificationError::from_response(
@@ -1793,7 +2762,16 @@ impl Budgets for BudgetsClient {
         &self,
         input: UpdateBudgetRequest,
     ) -> RusotoFuture<UpdateBudgetResponse, UpdateBudgetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("UpdateBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateBudget");
@@ -1802,10 +2780,10 @@ impl Budgets for BudgetsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateBudgetResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateBudgetResponse, _>(
+                    response,
+                    "UpdateBudget",
+                )
             } else {
                 Box::new(
                     response
@@ -1822,7 +2800,16 @@ impl Budgets for BudgetsClient {
         &self,
         input: UpdateNotificationRequest,
     ) -> RusotoFuture<UpdateNotificationResponse, UpdateNotificationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("UpdateNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateNotification");
@@ -1831,10 +2818,10 @@ impl Budgets for BudgetsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateNotificationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateNotificationResponse, _>(
+                    response,
+                    "UpdateNotification",
+                )
             } else {
                 Box::new(
                     response
@@ -1851,7 +2838,16 @@ impl Budgets for BudgetsClient {
         &self,
         input: UpdateSubscriberRequest,
     ) -> RusotoFuture<UpdateSubscriberResponse, UpdateSubscriberError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("UpdateSubscriber");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateSubscriber");
@@ -1860,10 +2856,10 @@ impl Budgets for BudgetsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSubscriberResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateSubscriberResponse, _>(
+                    response,
+                    "UpdateSubscriber",
+                )
             } else {
                 Box::new(
                     response
@@ -1875,3 +2871,512 @@ impl Budgets for BudgetsClient {
         })
     }
 }
+
+/// A mock of the AWSBudgets API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockBudgets {
+    create_budget: Option<
+        Box<
+            dyn Fn(
+                    CreateBudgetRequest,
+                )
+                    -> ::std::result::Result<CreateBudgetResponse, RusotoError<CreateBudgetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_notification: Option<
+        Box<
+            dyn Fn(
+                    CreateNotificationRequest,
+                ) -> ::std::result::Result<
+                    CreateNotificationResponse,
+                    RusotoError<CreateNotificationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_subscriber: Option<
+        Box<
+            dyn Fn(
+                    CreateSubscriberRequest,
+                ) -> ::std::result::Result<
+                    CreateSubscriberResponse,
+                    RusotoError<CreateSubscriberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_budget: Option<
+        Box<
+            dyn Fn(
+                    DeleteBudgetRequest,
+                )
+                    -> ::std::result::Result<DeleteBudgetResponse, RusotoError<DeleteBudgetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_notification: Option<
+        Box<
+            dyn Fn(
+                    DeleteNotificationRequest,
+                ) -> ::std::result::Result<
+                    DeleteNotificationResponse,
+                    RusotoError<DeleteNotificationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_subscriber: Option<
+        Box<
+            dyn Fn(
+                    DeleteSubscriberRequest,
+                ) -> ::std::result::Result<
+                    DeleteSubscriberResponse,
+                    RusotoError<DeleteSubscriberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_budget: Option<
+        Box<
+            dyn Fn(
+                    DescribeBudgetRequest,
+                ) -> ::std::result::Result<
+                    DescribeBudgetResponse,
+                    RusotoError<DescribeBudgetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_budget_performance_history: Option<
+        Box<
+            dyn Fn(
+                    DescribeBudgetPerformanceHistoryRequest,
+                ) -> ::std::result::Result<
+                    DescribeBudgetPerformanceHistoryResponse,
+                    RusotoError<DescribeBudgetPerformanceHistoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_budgets: Option<
+        Box<
+            dyn Fn(
+                    DescribeBudgetsRequest,
+                ) -> ::std::result::Result<
+                    DescribeBudgetsResponse,
+                    RusotoError<DescribeBudgetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_notifications_for_budget: Option<
+        Box<
+            dyn Fn(
+                    DescribeNotificationsForBudgetRequest,
+                ) -> ::std::result::Result<
+                    DescribeNotificationsForBudgetResponse,
+                    RusotoError<DescribeNotificationsForBudgetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_subscribers_for_notification: Option<
+        Box<
+            dyn Fn(
+                    DescribeSubscribersForNotificationRequest,
+                ) -> ::std::result::Result<
+                    DescribeSubscribersForNotificationResponse,
+                    RusotoError<DescribeSubscribersForNotificationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_budget: Option<
+        Box<
+            dyn Fn(
+                    UpdateBudgetRequest,
+                )
+                    -> ::std::result::Result<UpdateBudgetResponse, RusotoError<UpdateBudgetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_notification: Option<
+        Box<
+            dyn Fn(
+                    UpdateNotificationRequest,
+                ) -> ::std::result::Result<
+                    UpdateNotificationResponse,
+                    RusotoError<UpdateNotificationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_subscriber: Option<
+        Box<
+            dyn Fn(
+                    UpdateSubscriberRequest,
+                ) -> ::std::result::Result<
+                    UpdateSubscriberResponse,
+                    RusotoError<UpdateSubscriberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockBudgets {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockBudgets {
+        Default::default()
+    }
+
+    /// Answers `create_budget` with what `handler` returns for its input.
+    pub fn with_create_budget<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                CreateBudgetRequest,
+            )
+                -> ::std::result::Result<CreateBudgetResponse, RusotoError<CreateBudgetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_budget = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_notification` with what `handler` returns for its input.
+    pub fn with_create_notification<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                CreateNotificationRequest,
+            ) -> ::std::result::Result<
+                CreateNotificationResponse,
+                RusotoError<CreateNotificationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_notification = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_subscriber` with what `handler` returns for its input.
+    pub fn with_create_subscriber<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                CreateSubscriberRequest,
+            ) -> ::std::result::Result<
+                CreateSubscriberResponse,
+                RusotoError<CreateSubscriberError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_subscriber = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_budget` with what `handler` returns for its input.
+    pub fn with_delete_budget<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                DeleteBudgetRequest,
+            )
+                -> ::std::result::Result<DeleteBudgetResponse, RusotoError<DeleteBudgetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_budget = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_notification` with what `handler` returns for its input.
+    pub fn with_delete_notification<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                DeleteNotificationRequest,
+            ) -> ::std::result::Result<
+                DeleteNotificationResponse,
+                RusotoError<DeleteNotificationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_notification = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_subscriber` with what `handler` returns for its input.
+    pub fn with_delete_subscriber<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                DeleteSubscriberRequest,
+            ) -> ::std::result::Result<
+                DeleteSubscriberResponse,
+                RusotoError<DeleteSubscriberError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_subscriber = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_budget` with what `handler` returns for its input.
+    pub fn with_describe_budget<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                DescribeBudgetRequest,
+            )
+                -> ::std::result::Result<DescribeBudgetResponse, RusotoError<DescribeBudgetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_budget = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_budget_performance_history` with what `handler` returns for its input.
+    pub fn with_describe_budget_performance_history<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                DescribeBudgetPerformanceHistoryRequest,
+            ) -> ::std::result::Result<
+                DescribeBudgetPerformanceHistoryResponse,
+                RusotoError<DescribeBudgetPerformanceHistoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_budget_performance_history = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_budgets` with what `handler` returns for its input.
+    pub fn with_describe_budgets<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                DescribeBudgetsRequest,
+            )
+                -> ::std::result::Result<DescribeBudgetsResponse, RusotoError<DescribeBudgetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_budgets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_notifications_for_budget` with what `handler` returns for its input.
+    pub fn with_describe_notifications_for_budget<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                DescribeNotificationsForBudgetRequest,
+            ) -> ::std::result::Result<
+                DescribeNotificationsForBudgetResponse,
+                RusotoError<DescribeNotificationsForBudgetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_notifications_for_budget = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_subscribers_for_notification` with what `handler` returns for its input.
+    pub fn with_describe_subscribers_for_notification<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                DescribeSubscribersForNotificationRequest,
+            ) -> ::std::result::Result<
+                DescribeSubscribersForNotificationResponse,
+                RusotoError<DescribeSubscribersForNotificationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_subscribers_for_notification = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_budget` with what `handler` returns for its input.
+    pub fn with_update_budget<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                UpdateBudgetRequest,
+            )
+                -> ::std::result::Result<UpdateBudgetResponse, RusotoError<UpdateBudgetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_budget = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_notification` with what `handler` returns for its input.
+    pub fn with_update_notification<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                UpdateNotificationRequest,
+            ) -> ::std::result::Result<
+                UpdateNotificationResponse,
+                RusotoError<UpdateNotificationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_notification = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_subscriber` with what `handler` returns for its input.
+    pub fn with_update_subscriber<F>(mut self, handler: F) -> MockBudgets
+    where
+        F: Fn(
+                UpdateSubscriberRequest,
+            ) -> ::std::result::Result<
+                UpdateSubscriberResponse,
+                RusotoError<UpdateSubscriberError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_subscriber = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Budgets for MockBudgets {
+    fn create_budget(
+        &self,
+        input: CreateBudgetRequest,
+    ) -> RusotoFuture<CreateBudgetResponse, CreateBudgetError> {
+        match self.create_budget {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for create_budget"),
+        }
+    }
+    fn create_notification(
+        &self,
+        input: CreateNotificationRequest,
+    ) -> RusotoFuture<CreateNotificationResponse, CreateNotificationError> {
+        match self.create_notification {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for create_notification"),
+        }
+    }
+    fn create_subscriber(
+        &self,
+        input: CreateSubscriberRequest,
+    ) -> RusotoFuture<CreateSubscriberResponse, CreateSubscriberError> {
+        match self.create_subscriber {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for create_subscriber"),
+        }
+    }
+    fn delete_budget(
+        &self,
+        input: DeleteBudgetRequest,
+    ) -> RusotoFuture<DeleteBudgetResponse, DeleteBudgetError> {
+        match self.delete_budget {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for delete_budget"),
+        }
+    }
+    fn delete_notification(
+        &self,
+        input: DeleteNotificationRequest,
+    ) -> RusotoFuture<DeleteNotificationResponse, DeleteNotificationError> {
+        match self.delete_notification {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for delete_notification"),
+        }
+    }
+    fn delete_subscriber(
+        &self,
+        input: DeleteSubscriberRequest,
+    ) -> RusotoFuture<DeleteSubscriberResponse, DeleteSubscriberError> {
+        match self.delete_subscriber {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for delete_subscriber"),
+        }
+    }
+    fn describe_budget(
+        &self,
+        input: DescribeBudgetRequest,
+    ) -> RusotoFuture<DescribeBudgetResponse, DescribeBudgetError> {
+        match self.describe_budget {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for describe_budget"),
+        }
+    }
+    fn describe_budget_performance_history(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> RusotoFuture<DescribeBudgetPerformanceHistoryResponse, DescribeBudgetPerformanceHistoryError>
+    {
+        match self.describe_budget_performance_history {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for describe_budget_performance_history"),
+        }
+    }
+    fn describe_budgets(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> RusotoFuture<DescribeBudgetsResponse, DescribeBudgetsError> {
+        match self.describe_budgets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for describe_budgets"),
+        }
+    }
+    fn describe_notifications_for_budget(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> RusotoFuture<DescribeNotificationsForBudgetResponse, DescribeNotificationsForBudgetError>
+    {
+        match self.describe_notifications_for_budget {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for describe_notifications_for_budget"),
+        }
+    }
+    fn describe_subscribers_for_notification(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> RusotoFuture<
+        DescribeSubscribersForNotificationResponse,
+        DescribeSubscribersForNotificationError,
+    > {
+        match self.describe_subscribers_for_notification {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for describe_subscribers_for_notification"),
+        }
+    }
+    fn update_budget(
+        &self,
+        input: UpdateBudgetRequest,
+    ) -> RusotoFuture<UpdateBudgetResponse, UpdateBudgetError> {
+        match self.update_budget {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for update_budget"),
+        }
+    }
+    fn update_notification(
+        &self,
+        input: UpdateNotificationRequest,
+    ) -> RusotoFuture<UpdateNotificationResponse, UpdateNotificationError> {
+        match self.update_notification {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for update_notification"),
+        }
+    }
+    fn update_subscriber(
+        &self,
+        input: UpdateSubscriberRequest,
+    ) -> RusotoFuture<UpdateSubscriberResponse, UpdateSubscriberError> {
+        match self.update_subscriber {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockBudgets has no handler for update_subscriber"),
+        }
+    }
+}
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The amount of instance usage that a reservation covered.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Coverage {
     /// <p>The amount of cost that the reservation covered.</p>
     #[serde(rename = "CoverageCost")]
@@ -43,7 +44,7 @@ pub struct Coverage {
 
 /// <p>Reservation coverage for a specified period, in hours.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CoverageByTime {
     /// <p>The groups of instances that the reservation covered.</p>
     #[serde(rename = "Groups")]
@@ -61,7 +62,7 @@ pub struct CoverageByTime {
 
 /// <p>How much it cost to run an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CoverageCost {
     /// <p>How much an On-Demand instance cost.</p>
     #[serde(rename = "OnDemandCost")]
@@ -71,7 +72,7 @@ pub struct CoverageCost {
 
 /// <p>How long a running instance either used a reservation or was On-Demand.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CoverageHours {
     /// <p>The percentage of instance hours that a reservation covered.</p>
     #[serde(rename = "CoverageHoursPercentage")]
@@ -93,7 +94,7 @@ pub struct CoverageHours {
 
 /// <p>The amount of instance usage, in normalized units. Normalized units enable you to see your EC2 usage for multiple sizes of instances in a uniform way. For example, suppose you run an xlarge instance and a 2xlarge instance. If you run both instances for the same amount of time, the 2xlarge instance uses twice as much of your reservation as the xlarge instance, even though both instances show only one instance-hour. Using normalized units instead of instance-hours, the xlarge instance used 8 normalized units, and the 2xlarge instance used 16 normalized units.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ri-modifying.html">Modifying Reserved Instances</a> in the <i>Amazon Elastic Compute Cloud User Guide for Linux Instances</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CoverageNormalizedUnits {
     /// <p>The percentage of your used instance normalized units that a reservation covers.</p>
     #[serde(rename = "CoverageNormalizedUnitsPercentage")]
@@ -115,7 +116,7 @@ pub struct CoverageNormalizedUnits {
 
 /// <p>Context about the current instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CurrentInstance {
     /// <p> The currency code that Amazon Web Services used to calculate the costs for this instance.</p>
     #[serde(rename = "CurrencyCode")]
@@ -166,8 +167,36 @@ pub struct DateInterval {
     pub start: String,
 }
 
+impl Validate for DateInterval {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "end"),
+            self.end.chars().count(),
+            None,
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "end"),
+            &self.end,
+            "(\\d{4}-\\d{2}-\\d{2})(T\\d{2}:\\d{2}:\\d{2}Z)?",
+        );
+        errors.check_length(
+            &field_path(path, "start"),
+            self.start.chars().count(),
+            None,
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "start"),
+            &self.start,
+            "(\\d{4}-\\d{2}-\\d{2})(T\\d{2}:\\d{2}:\\d{2}Z)?",
+        );
+    }
+}
+
 /// <p>The metadata that you can use to filter and group your results. You can use <code>GetDimensionValues</code> to find specific values.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DimensionValues {
     /// <p>The names of the metadata types that you can use to filter and group your results. For example, <code>AZ</code> returns a list of Availability Zones.</p>
     #[serde(rename = "Key")]
@@ -179,9 +208,21 @@ pub struct DimensionValues {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for DimensionValues {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+                errors.check_pattern(&item_path, &item, "[\\S\\s]*");
+            }
+        }
+    }
+}
+
 /// <p>The metadata of a specific type that you can use to filter and group your results. You can use <code>GetDimensionValues</code> to find specific values.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DimensionValuesWithAttributes {
     /// <p>The attribute that applies to a specific <code>Dimension</code>.</p>
     #[serde(rename = "Attributes")]
@@ -195,7 +236,7 @@ pub struct DimensionValuesWithAttributes {
 
 /// <p>Details about the Amazon EC2 instances that AWS recommends that you purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EC2InstanceDetails {
     /// <p>The Availability Zone of the recommended reservation.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -233,7 +274,7 @@ pub struct EC2InstanceDetails {
 
 /// <p> Details on the Amazon EC2 Resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EC2ResourceDetails {
     /// <p> Hourly public On Demand rate for the instance type.</p>
     #[serde(rename = "HourlyOnDemandRate")]
@@ -275,7 +316,7 @@ pub struct EC2ResourceDetails {
 
 /// <p> Utilization metrics of the instance. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EC2ResourceUtilization {
     /// <p> Maximum observed or expected CPU utilization of the instance.</p>
     #[serde(rename = "MaxCpuUtilizationPercentage")]
@@ -302,7 +343,7 @@ pub struct EC2Specification {
 
 /// <p>Details about the Amazon ES instances that AWS recommends that you purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ESInstanceDetails {
     /// <p>Whether the recommendation is for a current-generation instance.</p>
     #[serde(rename = "CurrentGeneration")]
@@ -328,7 +369,7 @@ pub struct ESInstanceDetails {
 
 /// <p>Details about the Amazon ElastiCache instances that AWS recommends that you purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ElastiCacheInstanceDetails {
     /// <p>Whether the recommendation is for a current generation instance.</p>
     #[serde(rename = "CurrentGeneration")]
@@ -358,6 +399,7 @@ pub struct ElastiCacheInstanceDetails {
 
 /// <p><p>Use <code>Expression</code> to filter by cost or by usage. There are two patterns: </p> <ul> <li> <p>Simple dimension values - You can set the dimension name and values for the filters that you plan to use. For example, you can filter for <code>REGION==us-east-1 OR REGION==us-west-1</code>. The <code>Expression</code> for that looks like this:</p> <p> <code>{ &quot;Dimensions&quot;: { &quot;Key&quot;: &quot;REGION&quot;, &quot;Values&quot;: [ &quot;us-east-1&quot;, “us-west-1” ] } }</code> </p> <p>The list of dimension values are OR&#39;d together to retrieve cost or usage data. You can create <code>Expression</code> and <code>DimensionValues</code> objects using either <code>with<em></code> methods or <code>set</em></code> methods in multiple lines. </p> </li> <li> <p>Compound dimension values with logical operations - You can use multiple <code>Expression</code> types and the logical operators <code>AND/OR/NOT</code> to create a list of one or more <code>Expression</code> objects. This allows you to filter on more advanced options. For example, you can filter on <code>((REGION == us-east-1 OR REGION == us-west-1) OR (TAG.Type == Type1)) AND (USAGE<em>TYPE != DataTransfer)</code>. The <code>Expression</code> for that looks like this:</p> <p> <code>{ &quot;And&quot;: [ {&quot;Or&quot;: [ {&quot;Dimensions&quot;: { &quot;Key&quot;: &quot;REGION&quot;, &quot;Values&quot;: [ &quot;us-east-1&quot;, &quot;us-west-1&quot; ] }}, {&quot;Tags&quot;: { &quot;Key&quot;: &quot;TagName&quot;, &quot;Values&quot;: [&quot;Value1&quot;] } } ]}, {&quot;Not&quot;: {&quot;Dimensions&quot;: { &quot;Key&quot;: &quot;USAGE</em>TYPE&quot;, &quot;Values&quot;: [&quot;DataTransfer&quot;] }}} ] } </code> </p> <note> <p>Because each <code>Expression</code> can have only one operator, the service returns an error if more than one is specified. The following example shows an <code>Expression</code> object that creates an error.</p> </note> <p> <code> { &quot;And&quot;: [ ... ], &quot;DimensionValues&quot;: { &quot;Dimension&quot;: &quot;USAGE<em>TYPE&quot;, &quot;Values&quot;: [ &quot;DataTransfer&quot; ] } } </code> </p> </li> </ul> <note> <p>For <code>GetRightsizingRecommendation</code> action, a combination of OR and NOT is not supported. OR is not supported between different dimensions, or dimensions and tags. NOT operators aren&#39;t supported. Dimentions are also limited to <code>LINKED</em>ACCOUNT</code>, <code>REGION</code>, or <code>RIGHTSIZING_TYPE</code>.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Expression {
     /// <p>Return results that match both <code>Dimension</code> objects.</p>
     #[serde(rename = "And")]
@@ -381,9 +423,35 @@ pub struct Expression {
     pub tags: Option<TagValues>,
 }
 
+impl Validate for Expression {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.and {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "and"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.dimensions {
+            value.validate_at(&field_path(path, "dimensions"), errors);
+        }
+        if let Some(ref value) = *self.not {
+            value.validate_at(&field_path(path, "not"), errors);
+        }
+        if let Some(ref value) = self.or {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "or"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.tags {
+            value.validate_at(&field_path(path, "tags"), errors);
+        }
+    }
+}
+
 /// <p>The forecast created for your query.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ForecastResult {
     /// <p>The mean value of the forecast.</p>
     #[serde(rename = "MeanValue")]
@@ -404,6 +472,7 @@ pub struct ForecastResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCostAndUsageRequest {
     /// <p>Filters AWS costs by different dimensions. For example, you can specify <code>SERVICE</code> and <code>LINKED_ACCOUNT</code> and get the costs that are associated with that account's usage of that service. You can nest <code>Expression</code> objects to define any combination of dimension filters. For more information, see <a href="http://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_Expression.html">Expression</a>. </p>
     #[serde(rename = "Filter")]
@@ -430,8 +499,40 @@ pub struct GetCostAndUsageRequest {
     pub time_period: DateInterval,
 }
 
+impl Validate for GetCostAndUsageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.group_by {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "group_by"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.metrics {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "metrics"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+                errors.check_pattern(&item_path, &item, "[\\S\\s]*");
+            }
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_page_token"), &value, "[\\S\\s]*");
+        }
+        self.time_period
+            .validate_at(&field_path(path, "time_period"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCostAndUsageResponse {
     /// <p>The groups that are specified by the <code>Filter</code> or <code>GroupBy</code> parameters in the request.</p>
     #[serde(rename = "GroupDefinitions")]
@@ -448,6 +549,7 @@ pub struct GetCostAndUsageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCostForecastRequest {
     /// <p>The filters that you want to use to filter your forecast. Cost Explorer API supports all of the Cost Explorer filters.</p>
     #[serde(rename = "Filter")]
@@ -468,8 +570,26 @@ pub struct GetCostForecastRequest {
     pub time_period: DateInterval,
 }
 
+impl Validate for GetCostForecastRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.prediction_interval_level {
+            errors.check_range(
+                &field_path(path, "prediction_interval_level"),
+                *value,
+                Some(51),
+                Some(99),
+            );
+        }
+        self.time_period
+            .validate_at(&field_path(path, "time_period"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCostForecastResponse {
     /// <p>The forecasts for your query, in order. For <code>DAILY</code> forecasts, this is a list of days. For <code>MONTHLY</code> forecasts, this is a list of months.</p>
     #[serde(rename = "ForecastResultsByTime")]
@@ -482,6 +602,7 @@ pub struct GetCostForecastResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDimensionValuesRequest {
     /// <p><p>The context for the call to <code>GetDimensionValues</code>. This can be <code>RESERVATIONS</code> or <code>COST<em>AND</em>USAGE</code>. The default value is <code>COST<em>AND</em>USAGE</code>. If the context is set to <code>RESERVATIONS</code>, the resulting dimension values can be used in the <code>GetReservationUtilization</code> operation. If the context is set to <code>COST<em>AND</em>USAGE</code>, the resulting dimension values can be used in the <code>GetCostAndUsage</code> operation.</p> <p>If you set the context to <code>COST<em>AND</em>USAGE</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>AZ - The Availability Zone. An example is <code>us-east-1a</code>.</p> </li> <li> <p>DATABASE<em>ENGINE - The Amazon Relational Database Service database. Examples are Aurora or MySQL.</p> </li> <li> <p>INSTANCE</em>TYPE - The type of Amazon EC2 instance. An example is <code>m4.xlarge</code>.</p> </li> <li> <p>LEGAL<em>ENTITY</em>NAME - The name of the organization that sells you AWS services, such as Amazon Web Services.</p> </li> <li> <p>LINKED<em>ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>OPERATING</em>SYSTEM - The operating system. Examples are Windows or Linux.</p> </li> <li> <p>OPERATION - The action performed. Examples include <code>RunInstance</code> and <code>CreateBucket</code>.</p> </li> <li> <p>PLATFORM - The Amazon EC2 operating system. Examples are Windows or Linux.</p> </li> <li> <p>PURCHASE<em>TYPE - The reservation type of the purchase to which this usage is related. Examples include On-Demand Instances and Standard Reserved Instances.</p> </li> <li> <p>SERVICE - The AWS service such as Amazon DynamoDB.</p> </li> <li> <p>USAGE</em>TYPE - The type of usage. An example is DataTransfer-In-Bytes. The response for the <code>GetDimensionValues</code> operation includes a unit attribute. Examples include GB and Hrs.</p> </li> <li> <p>USAGE<em>TYPE</em>GROUP - The grouping of common usage types. An example is Amazon EC2: CloudWatch – Alarms. The response for this operation includes a unit attribute.</p> </li> <li> <p>RECORD<em>TYPE - The different types of charges such as RI fees, usage costs, tax refunds, and credits.</p> </li> </ul> <p>If you set the context to <code>RESERVATIONS</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>AZ - The Availability Zone. An example is <code>us-east-1a</code>.</p> </li> <li> <p>CACHE</em>ENGINE - The Amazon ElastiCache operating system. Examples are Windows or Linux.</p> </li> <li> <p>DEPLOYMENT<em>OPTION - The scope of Amazon Relational Database Service deployments. Valid values are <code>SingleAZ</code> and <code>MultiAZ</code>.</p> </li> <li> <p>INSTANCE</em>TYPE - The type of Amazon EC2 instance. An example is <code>m4.xlarge</code>.</p> </li> <li> <p>LINKED_ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>PLATFORM - The Amazon EC2 operating system. Examples are Windows or Linux.</p> </li> <li> <p>REGION - The AWS Region.</p> </li> <li> <p>SCOPE (Utilization only) - The scope of a Reserved Instance (RI). Values are regional or a single Availability Zone.</p> </li> <li> <p>TAG (Coverage only) - The tags that are associated with a Reserved Instance (RI).</p> </li> <li> <p>TENANCY - The tenancy of a resource. Examples are shared or dedicated.</p> </li> </ul></p>
     #[serde(rename = "Context")]
@@ -503,8 +624,33 @@ pub struct GetDimensionValuesRequest {
     pub time_period: DateInterval,
 }
 
+impl Validate for GetDimensionValuesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_page_token"), &value, "[\\S\\s]*");
+        }
+        if let Some(ref value) = self.search_string {
+            errors.check_length(
+                &field_path(path, "search_string"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "search_string"), &value, "[\\S\\s]*");
+        }
+        self.time_period
+            .validate_at(&field_path(path, "time_period"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDimensionValuesResponse {
     /// <p><p>The filters that you used to filter your request. Some dimensions are available only for a specific context.</p> <p>If you set the context to <code>COST<em>AND</em>USAGE</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>AZ - The Availability Zone. An example is <code>us-east-1a</code>.</p> </li> <li> <p>DATABASE<em>ENGINE - The Amazon Relational Database Service database. Examples are Aurora or MySQL.</p> </li> <li> <p>INSTANCE</em>TYPE - The type of Amazon EC2 instance. An example is <code>m4.xlarge</code>.</p> </li> <li> <p>LEGAL<em>ENTITY</em>NAME - The name of the organization that sells you AWS services, such as Amazon Web Services.</p> </li> <li> <p>LINKED<em>ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>OPERATING</em>SYSTEM - The operating system. Examples are Windows or Linux.</p> </li> <li> <p>OPERATION - The action performed. Examples include <code>RunInstance</code> and <code>CreateBucket</code>.</p> </li> <li> <p>PLATFORM - The Amazon EC2 operating system. Examples are Windows or Linux.</p> </li> <li> <p>PURCHASE<em>TYPE - The reservation type of the purchase to which this usage is related. Examples include On-Demand Instances and Standard Reserved Instances.</p> </li> <li> <p>SERVICE - The AWS service such as Amazon DynamoDB.</p> </li> <li> <p>USAGE</em>TYPE - The type of usage. An example is DataTransfer-In-Bytes. The response for the <code>GetDimensionValues</code> operation includes a unit attribute. Examples include GB and Hrs.</p> </li> <li> <p>USAGE<em>TYPE</em>GROUP - The grouping of common usage types. An example is Amazon EC2: CloudWatch – Alarms. The response for this operation includes a unit attribute.</p> </li> <li> <p>RECORD<em>TYPE - The different types of charges such as RI fees, usage costs, tax refunds, and credits.</p> </li> </ul> <p>If you set the context to <code>RESERVATIONS</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>AZ - The Availability Zone. An example is <code>us-east-1a</code>.</p> </li> <li> <p>CACHE</em>ENGINE - The Amazon ElastiCache operating system. Examples are Windows or Linux.</p> </li> <li> <p>DEPLOYMENT<em>OPTION - The scope of Amazon Relational Database Service deployments. Valid values are <code>SingleAZ</code> and <code>MultiAZ</code>.</p> </li> <li> <p>INSTANCE</em>TYPE - The type of Amazon EC2 instance. An example is <code>m4.xlarge</code>.</p> </li> <li> <p>LINKED_ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>PLATFORM - The Amazon EC2 operating system. Examples are Windows or Linux.</p> </li> <li> <p>REGION - The AWS Region.</p> </li> <li> <p>SCOPE (Utilization only) - The scope of a Reserved Instance (RI). Values are regional or a single Availability Zone.</p> </li> <li> <p>TAG (Coverage only) - The tags that are associated with a Reserved Instance (RI).</p> </li> <li> <p>TENANCY - The tenancy of a resource. Examples are shared or dedicated.</p> </li> </ul></p>
     #[serde(rename = "DimensionValues")]
@@ -523,6 +669,7 @@ pub struct GetDimensionValuesResponse {
 
 /// <p>You can use the following request parameters to query for how much of your instance usage a reservation covered.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReservationCoverageRequest {
     /// <p>Filters utilization data by dimensions. You can filter by the following dimensions:</p> <ul> <li> <p>AZ</p> </li> <li> <p>CACHE_ENGINE</p> </li> <li> <p>DATABASE_ENGINE</p> </li> <li> <p>DEPLOYMENT_OPTION</p> </li> <li> <p>INSTANCE_TYPE</p> </li> <li> <p>LINKED_ACCOUNT</p> </li> <li> <p>OPERATING_SYSTEM</p> </li> <li> <p>PLATFORM</p> </li> <li> <p>REGION</p> </li> <li> <p>SERVICE</p> </li> <li> <p>TAG</p> </li> <li> <p>TENANCY</p> </li> </ul> <p> <code>GetReservationCoverage</code> uses the same <a href="http://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_Expression.html">Expression</a> object as the other operations, but only <code>AND</code> is supported among each dimension. You can nest only one level deep. If there are multiple values for a dimension, they are OR'd together.</p> <p>If you don't provide a <code>SERVICE</code> filter, Cost Explorer defaults to EC2.</p>
     #[serde(rename = "Filter")]
@@ -549,8 +696,40 @@ pub struct GetReservationCoverageRequest {
     pub time_period: DateInterval,
 }
 
+impl Validate for GetReservationCoverageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.group_by {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "group_by"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.metrics {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "metrics"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+                errors.check_pattern(&item_path, &item, "[\\S\\s]*");
+            }
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_page_token"), &value, "[\\S\\s]*");
+        }
+        self.time_period
+            .validate_at(&field_path(path, "time_period"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetReservationCoverageResponse {
     /// <p>The amount of time that your reservations covered.</p>
     #[serde(rename = "CoveragesByTime")]
@@ -566,6 +745,7 @@ pub struct GetReservationCoverageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReservationPurchaseRecommendationRequest {
     /// <p>The account ID that is associated with the recommendation. </p>
     #[serde(rename = "AccountId")]
@@ -604,8 +784,38 @@ pub struct GetReservationPurchaseRecommendationRequest {
     pub term_in_years: Option<String>,
 }
 
+impl Validate for GetReservationPurchaseRecommendationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_id {
+            errors.check_length(
+                &field_path(path, "account_id"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "account_id"), &value, "[\\S\\s]*");
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_page_token"), &value, "[\\S\\s]*");
+        }
+        errors.check_length(
+            &field_path(path, "service"),
+            self.service.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(&field_path(path, "service"), &self.service, "[\\S\\s]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetReservationPurchaseRecommendationResponse {
     /// <p>Information about this specific recommendation call, such as the time stamp for when Cost Explorer generated this recommendation.</p>
     #[serde(rename = "Metadata")]
@@ -622,6 +832,7 @@ pub struct GetReservationPurchaseRecommendationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReservationUtilizationRequest {
     /// <p>Filters utilization data by dimensions. You can filter by the following dimensions:</p> <ul> <li> <p>AZ</p> </li> <li> <p>CACHE_ENGINE</p> </li> <li> <p>DATABASE_ENGINE</p> </li> <li> <p>DEPLOYMENT_OPTION</p> </li> <li> <p>INSTANCE_TYPE</p> </li> <li> <p>LINKED_ACCOUNT</p> </li> <li> <p>OPERATING_SYSTEM</p> </li> <li> <p>PLATFORM</p> </li> <li> <p>REGION</p> </li> <li> <p>SERVICE</p> </li> <li> <p>SCOPE</p> </li> <li> <p>TENANCY</p> </li> </ul> <p> <code>GetReservationUtilization</code> uses the same <a href="http://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_Expression.html">Expression</a> object as the other operations, but only <code>AND</code> is supported among each dimension, and nesting is supported up to only one level deep. If there are multiple values for a dimension, they are OR'd together.</p>
     #[serde(rename = "Filter")]
@@ -644,8 +855,33 @@ pub struct GetReservationUtilizationRequest {
     pub time_period: DateInterval,
 }
 
+impl Validate for GetReservationUtilizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.group_by {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "group_by"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_page_token"), &value, "[\\S\\s]*");
+        }
+        self.time_period
+            .validate_at(&field_path(path, "time_period"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetReservationUtilizationResponse {
     /// <p>The token for the next set of retrievable results. AWS provides the token when the response from a previous call has more results than the maximum page size.</p>
     #[serde(rename = "NextPageToken")]
@@ -661,6 +897,7 @@ pub struct GetReservationUtilizationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRightsizingRecommendationRequest {
     #[serde(rename = "Filter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -678,8 +915,32 @@ pub struct GetRightsizingRecommendationRequest {
     pub service: String,
 }
 
+impl Validate for GetRightsizingRecommendationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_page_token"), &value, "[\\S\\s]*");
+        }
+        errors.check_length(
+            &field_path(path, "service"),
+            self.service.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(&field_path(path, "service"), &self.service, "[\\S\\s]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRightsizingRecommendationResponse {
     /// <p>Information regarding this specific recommendation set.</p>
     #[serde(rename = "Metadata")]
@@ -700,6 +961,7 @@ pub struct GetRightsizingRecommendationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTagsRequest {
     /// <p>The token to retrieve the next set of results. AWS provides the token when the response from a previous call has more results than the maximum page size.</p>
     #[serde(rename = "NextPageToken")]
@@ -718,8 +980,42 @@ pub struct GetTagsRequest {
     pub time_period: DateInterval,
 }
 
+impl Validate for GetTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_page_token"), &value, "[\\S\\s]*");
+        }
+        if let Some(ref value) = self.search_string {
+            errors.check_length(
+                &field_path(path, "search_string"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "search_string"), &value, "[\\S\\s]*");
+        }
+        if let Some(ref value) = self.tag_key {
+            errors.check_length(
+                &field_path(path, "tag_key"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "tag_key"), &value, "[\\S\\s]*");
+        }
+        self.time_period
+            .validate_at(&field_path(path, "time_period"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTagsResponse {
     /// <p>The token for the next set of retrievable results. AWS provides the token when the response from a previous call has more results than the maximum page size.</p>
     #[serde(rename = "NextPageToken")]
@@ -737,6 +1033,7 @@ pub struct GetTagsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUsageForecastRequest {
     /// <p>The filters that you want to use to filter your forecast. Cost Explorer API supports all of the Cost Explorer filters.</p>
     #[serde(rename = "Filter")]
@@ -757,8 +1054,26 @@ pub struct GetUsageForecastRequest {
     pub time_period: DateInterval,
 }
 
+impl Validate for GetUsageForecastRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.prediction_interval_level {
+            errors.check_range(
+                &field_path(path, "prediction_interval_level"),
+                *value,
+                Some(51),
+                Some(99),
+            );
+        }
+        self.time_period
+            .validate_at(&field_path(path, "time_period"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUsageForecastResponse {
     /// <p>The forecasts for your query, in order. For <code>DAILY</code> forecasts, this is a list of days. For <code>MONTHLY</code> forecasts, this is a list of months.</p>
     #[serde(rename = "ForecastResultsByTime")]
@@ -772,7 +1087,7 @@ pub struct GetUsageForecastResponse {
 
 /// <p>One level of grouped data in the results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Group {
     /// <p>The keys that are included in this group.</p>
     #[serde(rename = "Keys")]
@@ -797,9 +1112,23 @@ pub struct GroupDefinition {
     pub type_: Option<String>,
 }
 
+impl Validate for GroupDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "key"), &value, "[\\S\\s]*");
+        }
+    }
+}
+
 /// <p>Details about the instances that AWS recommends that you purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceDetails {
     /// <p>The Amazon EC2 instances that AWS recommends that you purchase.</p>
     #[serde(rename = "EC2InstanceDetails")]
@@ -825,7 +1154,7 @@ pub struct InstanceDetails {
 
 /// <p>The aggregated value for a metric.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MetricValue {
     /// <p>The actual number that represents the metric.</p>
     #[serde(rename = "Amount")]
@@ -839,7 +1168,7 @@ pub struct MetricValue {
 
 /// <p> Details on the modification recommendation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyRecommendationDetail {
     /// <p> Identifies whether this instance type is the Amazon Web Services default recommendation.</p>
     #[serde(rename = "TargetInstances")]
@@ -849,7 +1178,7 @@ pub struct ModifyRecommendationDetail {
 
 /// <p>Details about the Amazon RDS instances that AWS recommends that you purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RDSInstanceDetails {
     /// <p>Whether the recommendation is for a current-generation instance. </p>
     #[serde(rename = "CurrentGeneration")]
@@ -891,7 +1220,7 @@ pub struct RDSInstanceDetails {
 
 /// <p>Details about the Amazon Redshift instances that AWS recommends that you purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RedshiftInstanceDetails {
     /// <p>Whether the recommendation is for a current-generation instance.</p>
     #[serde(rename = "CurrentGeneration")]
@@ -917,7 +1246,7 @@ pub struct RedshiftInstanceDetails {
 
 /// <p>The aggregated numbers for your reservation usage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReservationAggregates {
     /// <p>The monthly cost of your reservation, amortized over the reservation period.</p>
     #[serde(rename = "AmortizedRecurringFee")]
@@ -979,7 +1308,7 @@ pub struct ReservationAggregates {
 
 /// <p>A group of reservations that share a set of attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReservationCoverageGroup {
     /// <p>The attributes for this group of reservations.</p>
     #[serde(rename = "Attributes")]
@@ -993,7 +1322,7 @@ pub struct ReservationCoverageGroup {
 
 /// <p>A specific reservation that AWS recommends for purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReservationPurchaseRecommendation {
     /// <p>The account scope that AWS recommends that you purchase this instance for. For example, you can purchase this reservation for an entire organization in AWS Organizations.</p>
     #[serde(rename = "AccountScope")]
@@ -1027,7 +1356,7 @@ pub struct ReservationPurchaseRecommendation {
 
 /// <p>Details about your recommended reservation purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReservationPurchaseRecommendationDetail {
     /// <p>The account that this RI recommendation is for.</p>
     #[serde(rename = "AccountId")]
@@ -1109,7 +1438,7 @@ pub struct ReservationPurchaseRecommendationDetail {
 
 /// <p>Information about this specific recommendation, such as the time stamp for when AWS made a specific recommendation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReservationPurchaseRecommendationMetadata {
     /// <p>The time stamp for when AWS made this recommendation.</p>
     #[serde(rename = "GenerationTimestamp")]
@@ -1123,7 +1452,7 @@ pub struct ReservationPurchaseRecommendationMetadata {
 
 /// <p>A summary about this recommendation, such as the currency code, the amount that AWS estimates that you could save, and the total amount of reservation to purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReservationPurchaseRecommendationSummary {
     /// <p>The currency code used for this recommendation.</p>
     #[serde(rename = "CurrencyCode")]
@@ -1141,7 +1470,7 @@ pub struct ReservationPurchaseRecommendationSummary {
 
 /// <p>A group of reservations that share a set of attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReservationUtilizationGroup {
     /// <p>The attributes for this group of reservations.</p>
     #[serde(rename = "Attributes")]
@@ -1163,7 +1492,7 @@ pub struct ReservationUtilizationGroup {
 
 /// <p>Details on the resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceDetails {
     /// <p>Details on the Amazon EC2 resource.</p>
     #[serde(rename = "EC2ResourceDetails")]
@@ -1173,7 +1502,7 @@ pub struct ResourceDetails {
 
 /// <p>Resource utilization of current resource. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceUtilization {
     /// <p>Utilization of current Amazon EC2 Instance </p>
     #[serde(rename = "EC2ResourceUtilization")]
@@ -1183,7 +1512,7 @@ pub struct ResourceUtilization {
 
 /// <p>The result that is associated with a time period.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResultByTime {
     /// <p>Whether the result is estimated.</p>
     #[serde(rename = "Estimated")]
@@ -1205,7 +1534,7 @@ pub struct ResultByTime {
 
 /// <p>Recommendations to rightsize resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RightsizingRecommendation {
     /// <p>The account that this recommendation is for.</p>
     #[serde(rename = "AccountId")]
@@ -1231,7 +1560,7 @@ pub struct RightsizingRecommendation {
 
 /// <p>Metadata for this recommendation set.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RightsizingRecommendationMetadata {
     /// <p> The time stamp for when Amazon Web Services made this recommendation.</p>
     #[serde(rename = "GenerationTimestamp")]
@@ -1249,7 +1578,7 @@ pub struct RightsizingRecommendationMetadata {
 
 /// <p> Summary of rightsizing recommendations </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RightsizingRecommendationSummary {
     /// <p> Estimated total savings resulting from modifications, on a monthly basis.</p>
     #[serde(rename = "EstimatedTotalMonthlySavingsAmount")]
@@ -1291,9 +1620,30 @@ pub struct TagValues {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for TagValues {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "key"), &value, "[\\S\\s]*");
+        }
+        if let Some(ref value) = self.values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+                errors.check_pattern(&item_path, &item, "[\\S\\s]*");
+            }
+        }
+    }
+}
+
 /// <p> Details on recommended instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TargetInstance {
     /// <p> The currency code that Amazon Web Services used to calculate the costs for this instance.</p>
     #[serde(rename = "CurrencyCode")]
@@ -1323,7 +1673,7 @@ pub struct TargetInstance {
 
 /// <p> Details on termination recommendation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TerminateRecommendationDetail {
     /// <p> The currency code that Amazon Web Services used to calculate the costs for this instance.</p>
     #[serde(rename = "CurrencyCode")]
@@ -1337,7 +1687,7 @@ pub struct TerminateRecommendationDetail {
 
 /// <p>The amount of utilization, in hours.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UtilizationByTime {
     /// <p>The groups that this utilization result uses.</p>
     #[serde(rename = "Groups")]
@@ -1387,7 +1737,12 @@ impl GetCostAndUsageError {
                 "RequestChangedException" => {
                     return RusotoError::Service(GetCostAndUsageError::RequestChanged(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1429,7 +1784,12 @@ impl GetCostForecastError {
                 "LimitExceededException" => {
                     return RusotoError::Service(GetCostForecastError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1483,7 +1843,12 @@ impl GetDimensionValuesError {
                 "RequestChangedException" => {
                     return RusotoError::Service(GetDimensionValuesError::RequestChanged(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1536,7 +1901,12 @@ impl GetReservationCoverageError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1589,7 +1959,12 @@ impl GetReservationPurchaseRecommendationError {
                         GetReservationPurchaseRecommendationError::LimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1640,7 +2015,12 @@ impl GetReservationUtilizationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1686,7 +2066,12 @@ impl GetRightsizingRecommendationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1740,7 +2125,12 @@ impl GetTagsError {
                 "RequestChangedException" => {
                     return RusotoError::Service(GetTagsError::RequestChanged(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1789,7 +2179,12 @@ impl GetUsageForecastError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1866,6 +2261,175 @@ pub trait CostExplorer {
         input: GetUsageForecastRequest,
     ) -> RusotoFuture<GetUsageForecastResponse, GetUsageForecastError>;
 }
+const _: Option<&dyn CostExplorer> = None;
+impl<'a, T: CostExplorer + ?Sized> CostExplorer for &'a T {
+    fn get_cost_and_usage(
+        &self,
+        input: GetCostAndUsageRequest,
+    ) -> RusotoFuture<GetCostAndUsageResponse, GetCostAndUsageError> {
+        (**self).get_cost_and_usage(input)
+    }
+    fn get_cost_forecast(
+        &self,
+        input: GetCostForecastRequest,
+    ) -> RusotoFuture<GetCostForecastResponse, GetCostForecastError> {
+        (**self).get_cost_forecast(input)
+    }
+    fn get_dimension_values(
+        &self,
+        input: GetDimensionValuesRequest,
+    ) -> RusotoFuture<GetDimensionValuesResponse, GetDimensionValuesError> {
+        (**self).get_dimension_values(input)
+    }
+    fn get_reservation_coverage(
+        &self,
+        input: GetReservationCoverageRequest,
+    ) -> RusotoFuture<GetReservationCoverageResponse, GetReservationCoverageError> {
+        (**self).get_reservation_coverage(input)
+    }
+    fn get_reservation_purchase_recommendation(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> RusotoFuture<
+        GetReservationPurchaseRecommendationResponse,
+        GetReservationPurchaseRecommendationError,
+    > {
+        (**self).get_reservation_purchase_recommendation(input)
+    }
+    fn get_reservation_utilization(
+        &self,
+        input: GetReservationUtilizationRequest,
+    ) -> RusotoFuture<GetReservationUtilizationResponse, GetReservationUtilizationError> {
+        (**self).get_reservation_utilization(input)
+    }
+    fn get_rightsizing_recommendation(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> RusotoFuture<GetRightsizingRecommendationResponse, GetRightsizingRecommendationError> {
+        (**self).get_rightsizing_recommendation(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn get_usage_forecast(
+        &self,
+        input: GetUsageForecastRequest,
+    ) -> RusotoFuture<GetUsageForecastResponse, GetUsageForecastError> {
+        (**self).get_usage_forecast(input)
+    }
+}
+impl<T: CostExplorer + ?Sized> CostExplorer for Box<T> {
+    fn get_cost_and_usage(
+        &self,
+        input: GetCostAndUsageRequest,
+    ) -> RusotoFuture<GetCostAndUsageResponse, GetCostAndUsageError> {
+        (**self).get_cost_and_usage(input)
+    }
+    fn get_cost_forecast(
+        &self,
+        input: GetCostForecastRequest,
+    ) -> RusotoFuture<GetCostForecastResponse, GetCostForecastError> {
+        (**self).get_cost_forecast(input)
+    }
+    fn get_dimension_values(
+        &self,
+        input: GetDimensionValuesRequest,
+    ) -> RusotoFuture<GetDimensionValuesResponse, GetDimensionValuesError> {
+        (**self).get_dimension_values(input)
+    }
+    fn get_reservation_coverage(
+        &self,
+        input: GetReservationCoverageRequest,
+    ) -> RusotoFuture<GetReservationCoverageResponse, GetReservationCoverageError> {
+        (**self).get_reservation_coverage(input)
+    }
+    fn get_reservation_purchase_recommendation(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> RusotoFuture<
+        GetReservationPurchaseRecommendationResponse,
+        GetReservationPurchaseRecommendationError,
+    > {
+        (**self).get_reservation_purchase_recommendation(input)
+    }
+    fn get_reservation_utilization(
+        &self,
+        input: GetReservationUtilizationRequest,
+    ) -> RusotoFuture<GetReservationUtilizationResponse, GetReservationUtilizationError> {
+        (**self).get_reservation_utilization(input)
+    }
+    fn get_rightsizing_recommendation(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> RusotoFuture<GetRightsizingRecommendationResponse, GetRightsizingRecommendationError> {
+        (**self).get_rightsizing_recommendation(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn get_usage_forecast(
+        &self,
+        input: GetUsageForecastRequest,
+    ) -> RusotoFuture<GetUsageForecastResponse, GetUsageForecastError> {
+        (**self).get_usage_forecast(input)
+    }
+}
+impl<T: CostExplorer + ?Sized> CostExplorer for ::std::sync::Arc<T> {
+    fn get_cost_and_usage(
+        &self,
+        input: GetCostAndUsageRequest,
+    ) -> RusotoFuture<GetCostAndUsageResponse, GetCostAndUsageError> {
+        (**self).get_cost_and_usage(input)
+    }
+    fn get_cost_forecast(
+        &self,
+        input: GetCostForecastRequest,
+    ) -> RusotoFuture<GetCostForecastResponse, GetCostForecastError> {
+        (**self).get_cost_forecast(input)
+    }
+    fn get_dimension_values(
+        &self,
+        input: GetDimensionValuesRequest,
+    ) -> RusotoFuture<GetDimensionValuesResponse, GetDimensionValuesError> {
+        (**self).get_dimension_values(input)
+    }
+    fn get_reservation_coverage(
+        &self,
+        input: GetReservationCoverageRequest,
+    ) -> RusotoFuture<GetReservationCoverageResponse, GetReservationCoverageError> {
+        (**self).get_reservation_coverage(input)
+    }
+    fn get_reservation_purchase_recommendation(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> RusotoFuture<
+        GetReservationPurchaseRecommendationResponse,
+        GetReservationPurchaseRecommendationError,
+    > {
+        (**self).get_reservation_purchase_recommendation(input)
+    }
+    fn get_reservation_utilization(
+        &self,
+        input: GetReservationUtilizationRequest,
+    ) -> RusotoFuture<GetReservationUtilizationResponse, GetReservationUtilizationError> {
+        (**self).get_reservation_utilization(input)
+    }
+    fn get_rightsizing_recommendation(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> RusotoFuture<GetRightsizingRecommendationResponse, GetRightsizingRecommendationError> {
+        (**self).get_rightsizing_recommendation(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn get_usage_forecast(
+        &self,
+        input: GetUsageForecastRequest,
+    ) -> RusotoFuture<GetUsageForecastResponse, GetUsageForecastError> {
+        (**self).get_usage_forecast(input)
+    }
+}
 /// A client for the AWS Cost Explorer API.
 #[derive(Clone)]
 pub struct CostExplorerClient {
@@ -1909,7 +2473,16 @@ impl CostExplorer for CostExplorerClient {
         &self,
         input: GetCostAndUsageRequest,
     ) -> RusotoFuture<GetCostAndUsageResponse, GetCostAndUsageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetCostAndUsage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetCostAndUsage");
@@ -1918,10 +2491,10 @@ impl CostExplorer for CostExplorerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCostAndUsageResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetCostAndUsageResponse, _>(
+                    response,
+                    "GetCostAndUsage",
+                )
             } else {
                 Box::new(
                     response
@@ -1938,7 +2511,16 @@ impl CostExplorer for CostExplorerClient {
         &self,
         input: GetCostForecastRequest,
     ) -> RusotoFuture<GetCostForecastResponse, GetCostForecastError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetCostForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetCostForecast");
@@ -1947,10 +2529,10 @@ impl CostExplorer for CostExplorerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCostForecastResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetCostForecastResponse, _>(
+                    response,
+                    "GetCostForecast",
+                )
             } else {
                 Box::new(
                     response
@@ -1967,7 +2549,16 @@ impl CostExplorer for CostExplorerClient {
         &self,
         input: GetDimensionValuesRequest,
     ) -> RusotoFuture<GetDimensionValuesResponse, GetDimensionValuesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetDimensionValues");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetDimensionValues");
@@ -1976,10 +2567,10 @@ impl CostExplorer for CostExplorerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDimensionValuesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetDimensionValuesResponse, _>(
+                    response,
+                    "GetDimensionValues",
+                )
             } else {
                 Box::new(
                     response
@@ -1996,7 +2587,16 @@ impl CostExplorer for CostExplorerClient {
         &self,
         input: GetReservationCoverageRequest,
     ) -> RusotoFuture<GetReservationCoverageResponse, GetReservationCoverageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetReservationCoverage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2008,10 +2608,10 @@ impl CostExplorer for CostExplorerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetReservationCoverageResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetReservationCoverageResponse, _>(
+                    response,
+                    "GetReservationCoverage",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2030,7 +2630,16 @@ impl CostExplorer for CostExplorerClient {
         GetReservationPurchaseRecommendationResponse,
         GetReservationPurchaseRecommendationError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetReservationPurchaseRecommendation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2042,10 +2651,7 @@ impl CostExplorer for CostExplorerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetReservationPurchaseRecommendationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetReservationPurchaseRecommendationResponse, _>(response, "GetReservationPurchaseRecommendation")
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetReservationPurchaseRecommendationError::from_response(
@@ -2061,7 +2667,16 @@ impl CostExplorer for CostExplorerClient {
         &self,
         input: GetReservationUtilizationRequest,
     ) -> RusotoFuture<GetReservationUtilizationResponse, GetReservationUtilizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetReservationUtilization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2073,10 +2688,10 @@ impl CostExplorer for CostExplorerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetReservationUtilizationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetReservationUtilizationResponse, _>(
+                    response,
+                    "GetReservationUtilization",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetReservationUtilizationError::from_response(response))
@@ -2090,7 +2705,16 @@ impl CostExplorer for CostExplorerClient {
         &self,
         input: GetRightsizingRecommendationRequest,
     ) -> RusotoFuture<GetRightsizingRecommendationResponse, GetRightsizingRecommendationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetRightsizingRecommendation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2102,10 +2726,10 @@ impl CostExplorer for CostExplorerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRightsizingRecommendationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetRightsizingRecommendationResponse, _>(
+                    response,
+                    "GetRightsizingRecommendation",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetRightsizingRecommendationError::from_response(response))
@@ -2116,7 +2740,16 @@ impl CostExplorer for CostExplorerClient {
 
     /// <p>Queries for available tag keys and tag values for a specified period. You can search the tag values for an arbitrary string. </p>
     fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetTags");
@@ -2125,9 +2758,7 @@ impl CostExplorer for CostExplorerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetTagsResponse, _>(response, "GetTags")
             } else {
                 Box::new(
                     response
@@ -2144,7 +2775,16 @@ impl CostExplorer for CostExplorerClient {
         &self,
         input: GetUsageForecastRequest,
     ) -> RusotoFuture<GetUsageForecastResponse, GetUsageForecastError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetUsageForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetUsageForecast");
@@ -2153,10 +2793,10 @@ impl CostExplorer for CostExplorerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetUsageForecastResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetUsageForecastResponse, _>(
+                    response,
+                    "GetUsageForecast",
+                )
             } else {
                 Box::new(
                     response
@@ -2168,3 +2808,335 @@ impl CostExplorer for CostExplorerClient {
         })
     }
 }
+
+/// A mock of the AWS Cost Explorer API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCostExplorer {
+    get_cost_and_usage: Option<
+        Box<
+            dyn Fn(
+                    GetCostAndUsageRequest,
+                ) -> ::std::result::Result<
+                    GetCostAndUsageResponse,
+                    RusotoError<GetCostAndUsageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_cost_forecast: Option<
+        Box<
+            dyn Fn(
+                    GetCostForecastRequest,
+                ) -> ::std::result::Result<
+                    GetCostForecastResponse,
+                    RusotoError<GetCostForecastError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_dimension_values: Option<
+        Box<
+            dyn Fn(
+                    GetDimensionValuesRequest,
+                ) -> ::std::result::Result<
+                    GetDimensionValuesResponse,
+                    RusotoError<GetDimensionValuesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_reservation_coverage: Option<
+        Box<
+            dyn Fn(
+                    GetReservationCoverageRequest,
+                ) -> ::std::result::Result<
+                    GetReservationCoverageResponse,
+                    RusotoError<GetReservationCoverageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_reservation_purchase_recommendation: Option<
+        Box<
+            dyn Fn(
+                    GetReservationPurchaseRecommendationRequest,
+                ) -> ::std::result::Result<
+                    GetReservationPurchaseRecommendationResponse,
+                    RusotoError<GetReservationPurchaseRecommendationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_reservation_utilization: Option<
+        Box<
+            dyn Fn(
+                    GetReservationUtilizationRequest,
+                ) -> ::std::result::Result<
+                    GetReservationUtilizationResponse,
+                    RusotoError<GetReservationUtilizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_rightsizing_recommendation: Option<
+        Box<
+            dyn Fn(
+                    GetRightsizingRecommendationRequest,
+                ) -> ::std::result::Result<
+                    GetRightsizingRecommendationResponse,
+                    RusotoError<GetRightsizingRecommendationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_tags: Option<
+        Box<
+            dyn Fn(
+                    GetTagsRequest,
+                )
+                    -> ::std::result::Result<GetTagsResponse, RusotoError<GetTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_usage_forecast: Option<
+        Box<
+            dyn Fn(
+                    GetUsageForecastRequest,
+                ) -> ::std::result::Result<
+                    GetUsageForecastResponse,
+                    RusotoError<GetUsageForecastError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCostExplorer {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCostExplorer {
+        Default::default()
+    }
+
+    /// Answers `get_cost_and_usage` with what `handler` returns for its input.
+    pub fn with_get_cost_and_usage<F>(mut self, handler: F) -> MockCostExplorer
+    where
+        F: Fn(
+                GetCostAndUsageRequest,
+            )
+                -> ::std::result::Result<GetCostAndUsageResponse, RusotoError<GetCostAndUsageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_cost_and_usage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_cost_forecast` with what `handler` returns for its input.
+    pub fn with_get_cost_forecast<F>(mut self, handler: F) -> MockCostExplorer
+    where
+        F: Fn(
+                GetCostForecastRequest,
+            )
+                -> ::std::result::Result<GetCostForecastResponse, RusotoError<GetCostForecastError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_cost_forecast = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_dimension_values` with what `handler` returns for its input.
+    pub fn with_get_dimension_values<F>(mut self, handler: F) -> MockCostExplorer
+    where
+        F: Fn(
+                GetDimensionValuesRequest,
+            ) -> ::std::result::Result<
+                GetDimensionValuesResponse,
+                RusotoError<GetDimensionValuesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_dimension_values = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_reservation_coverage` with what `handler` returns for its input.
+    pub fn with_get_reservation_coverage<F>(mut self, handler: F) -> MockCostExplorer
+    where
+        F: Fn(
+                GetReservationCoverageRequest,
+            ) -> ::std::result::Result<
+                GetReservationCoverageResponse,
+                RusotoError<GetReservationCoverageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_reservation_coverage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_reservation_purchase_recommendation` with what `handler` returns for its input.
+    pub fn with_get_reservation_purchase_recommendation<F>(mut self, handler: F) -> MockCostExplorer
+    where
+        F: Fn(
+                GetReservationPurchaseRecommendationRequest,
+            ) -> ::std::result::Result<
+                GetReservationPurchaseRecommendationResponse,
+                RusotoError<GetReservationPurchaseRecommendationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_reservation_purchase_recommendation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_reservation_utilization` with what `handler` returns for its input.
+    pub fn with_get_reservation_utilization<F>(mut self, handler: F) -> MockCostExplorer
+    where
+        F: Fn(
+                GetReservationUtilizationRequest,
+            ) -> ::std::result::Result<
+                GetReservationUtilizationResponse,
+                RusotoError<GetReservationUtilizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_reservation_utilization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_rightsizing_recommendation` with what `handler` returns for its input.
+    pub fn with_get_rightsizing_recommendation<F>(mut self, handler: F) -> MockCostExplorer
+    where
+        F: Fn(
+                GetRightsizingRecommendationRequest,
+            ) -> ::std::result::Result<
+                GetRightsizingRecommendationResponse,
+                RusotoError<GetRightsizingRecommendationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_rightsizing_recommendation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_tags` with what `handler` returns for its input.
+    pub fn with_get_tags<F>(mut self, handler: F) -> MockCostExplorer
+    where
+        F: Fn(GetTagsRequest) -> ::std::result::Result<GetTagsResponse, RusotoError<GetTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_usage_forecast` with what `handler` returns for its input.
+    pub fn with_get_usage_forecast<F>(mut self, handler: F) -> MockCostExplorer
+    where
+        F: Fn(
+                GetUsageForecastRequest,
+            ) -> ::std::result::Result<
+                GetUsageForecastResponse,
+                RusotoError<GetUsageForecastError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_usage_forecast = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CostExplorer for MockCostExplorer {
+    fn get_cost_and_usage(
+        &self,
+        input: GetCostAndUsageRequest,
+    ) -> RusotoFuture<GetCostAndUsageResponse, GetCostAndUsageError> {
+        match self.get_cost_and_usage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostExplorer has no handler for get_cost_and_usage"),
+        }
+    }
+    fn get_cost_forecast(
+        &self,
+        input: GetCostForecastRequest,
+    ) -> RusotoFuture<GetCostForecastResponse, GetCostForecastError> {
+        match self.get_cost_forecast {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostExplorer has no handler for get_cost_forecast"),
+        }
+    }
+    fn get_dimension_values(
+        &self,
+        input: GetDimensionValuesRequest,
+    ) -> RusotoFuture<GetDimensionValuesResponse, GetDimensionValuesError> {
+        match self.get_dimension_values {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostExplorer has no handler for get_dimension_values"),
+        }
+    }
+    fn get_reservation_coverage(
+        &self,
+        input: GetReservationCoverageRequest,
+    ) -> RusotoFuture<GetReservationCoverageResponse, GetReservationCoverageError> {
+        match self.get_reservation_coverage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostExplorer has no handler for get_reservation_coverage"),
+        }
+    }
+    fn get_reservation_purchase_recommendation(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> RusotoFuture<
+        GetReservationPurchaseRecommendationResponse,
+        GetReservationPurchaseRecommendationError,
+    > {
+        match self.get_reservation_purchase_recommendation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCostExplorer has no handler for get_reservation_purchase_recommendation"
+            ),
+        }
+    }
+    fn get_reservation_utilization(
+        &self,
+        input: GetReservationUtilizationRequest,
+    ) -> RusotoFuture<GetReservationUtilizationResponse, GetReservationUtilizationError> {
+        match self.get_reservation_utilization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostExplorer has no handler for get_reservation_utilization"),
+        }
+    }
+    fn get_rightsizing_recommendation(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> RusotoFuture<GetRightsizingRecommendationResponse, GetRightsizingRecommendationError> {
+        match self.get_rightsizing_recommendation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostExplorer has no handler for get_rightsizing_recommendation"),
+        }
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        match self.get_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostExplorer has no handler for get_tags"),
+        }
+    }
+    fn get_usage_forecast(
+        &self,
+        input: GetUsageForecastRequest,
+    ) -> RusotoFuture<GetUsageForecastResponse, GetUsageForecastError> {
+        match self.get_usage_forecast {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostExplorer has no handler for get_usage_forecast"),
+        }
+    }
+}
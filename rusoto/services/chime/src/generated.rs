@@ -23,10 +23,11 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The Amazon Chime account details. An AWS account can have multiple Amazon Chime accounts.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Account {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -69,6 +70,7 @@ pub struct AccountSettings {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociatePhoneNumberWithUserRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -81,11 +83,22 @@ pub struct AssociatePhoneNumberWithUserRequest {
     pub user_id: String,
 }
 
+impl Validate for AssociatePhoneNumberWithUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "e164_phone_number"),
+            &self.e164_phone_number,
+            "^\\+?[1-9]\\d{1,14}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociatePhoneNumberWithUserResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociatePhoneNumbersWithVoiceConnectorRequest {
     /// <p>List of phone numbers, in E.164 format.</p>
     #[serde(rename = "E164PhoneNumbers")]
@@ -96,8 +109,24 @@ pub struct AssociatePhoneNumbersWithVoiceConnectorRequest {
     pub voice_connector_id: String,
 }
 
+impl Validate for AssociatePhoneNumbersWithVoiceConnectorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.e164_phone_numbers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "e164_phone_numbers"), i);
+                errors.check_pattern(&item_path, &item, "^\\+?[1-9]\\d{1,14}$");
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociatePhoneNumbersWithVoiceConnectorResponse {
     /// <p>If the action fails for one or more of the phone numbers in the request, a list of the phone numbers is returned, along with error codes and error messages.</p>
     #[serde(rename = "PhoneNumberErrors")]
@@ -106,14 +135,26 @@ pub struct AssociatePhoneNumbersWithVoiceConnectorResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeletePhoneNumberRequest {
     /// <p>List of phone number IDs.</p>
     #[serde(rename = "PhoneNumberIds")]
     pub phone_number_ids: Vec<String>,
 }
 
+impl Validate for BatchDeletePhoneNumberRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "phone_number_ids"),
+            self.phone_number_ids.len(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDeletePhoneNumberResponse {
     /// <p>If the action fails for one or more of the phone numbers in the request, a list of the phone numbers is returned, along with error codes and error messages.</p>
     #[serde(rename = "PhoneNumberErrors")]
@@ -122,6 +163,7 @@ pub struct BatchDeletePhoneNumberResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchSuspendUserRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -131,8 +173,24 @@ pub struct BatchSuspendUserRequest {
     pub user_id_list: Vec<String>,
 }
 
+impl Validate for BatchSuspendUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_length(
+            &field_path(path, "user_id_list"),
+            self.user_id_list.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.user_id_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "user_id_list"), i);
+            errors.check_pattern(&item_path, &item, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchSuspendUserResponse {
     /// <p>If the <a>BatchSuspendUser</a> action fails for one or more of the user IDs in the request, a list of the user IDs is returned, along with error codes and error messages.</p>
     #[serde(rename = "UserErrors")]
@@ -141,6 +199,7 @@ pub struct BatchSuspendUserResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchUnsuspendUserRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -150,8 +209,24 @@ pub struct BatchUnsuspendUserRequest {
     pub user_id_list: Vec<String>,
 }
 
+impl Validate for BatchUnsuspendUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_length(
+            &field_path(path, "user_id_list"),
+            self.user_id_list.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.user_id_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "user_id_list"), i);
+            errors.check_pattern(&item_path, &item, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchUnsuspendUserResponse {
     /// <p>If the <a>BatchUnsuspendUser</a> action fails for one or more of the user IDs in the request, a list of the user IDs is returned, along with error codes and error messages.</p>
     #[serde(rename = "UserErrors")]
@@ -160,14 +235,28 @@ pub struct BatchUnsuspendUserResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchUpdatePhoneNumberRequest {
     /// <p>The request containing the phone number IDs and product types to update.</p>
     #[serde(rename = "UpdatePhoneNumberRequestItems")]
     pub update_phone_number_request_items: Vec<UpdatePhoneNumberRequestItem>,
 }
 
+impl Validate for BatchUpdatePhoneNumberRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.update_phone_number_request_items.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "update_phone_number_request_items"),
+                i
+            );
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchUpdatePhoneNumberResponse {
     /// <p>If the action fails for one or more of the phone numbers in the request, a list of the phone numbers is returned, along with error codes and error messages.</p>
     #[serde(rename = "PhoneNumberErrors")]
@@ -176,6 +265,7 @@ pub struct BatchUpdatePhoneNumberResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchUpdateUserRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -185,8 +275,24 @@ pub struct BatchUpdateUserRequest {
     pub update_user_request_items: Vec<UpdateUserRequestItem>,
 }
 
+impl Validate for BatchUpdateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_length(
+            &field_path(path, "update_user_request_items"),
+            self.update_user_request_items.len(),
+            None,
+            Some(20),
+        );
+        for (i, item) in self.update_user_request_items.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "update_user_request_items"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchUpdateUserResponse {
     /// <p>If the <a>BatchUpdateUser</a> action fails for one or more of the user IDs in the request, a list of the user IDs is returned, along with error codes and error messages.</p>
     #[serde(rename = "UserErrors")]
@@ -196,7 +302,7 @@ pub struct BatchUpdateUserResponse {
 
 /// <p>A resource that allows Enterprise account administrators to configure an interface to receive events from Amazon Chime.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Bot {
     /// <p>The bot email address.</p>
     #[serde(rename = "BotEmail")]
@@ -246,14 +352,27 @@ pub struct BusinessCallingSettings {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAccountRequest {
     /// <p>The name of the Amazon Chime account.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for CreateAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAccountResponse {
     /// <p>The Amazon Chime account details.</p>
     #[serde(rename = "Account")]
@@ -262,6 +381,7 @@ pub struct CreateAccountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBotRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -275,8 +395,17 @@ pub struct CreateBotRequest {
     pub domain: Option<String>,
 }
 
+impl Validate for CreateBotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        if let Some(ref value) = self.domain {
+            errors.check_pattern(&field_path(path, "domain"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBotResponse {
     /// <p>The bot details.</p>
     #[serde(rename = "Bot")]
@@ -285,6 +414,7 @@ pub struct CreateBotResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePhoneNumberOrderRequest {
     /// <p>List of phone numbers, in E.164 format.</p>
     #[serde(rename = "E164PhoneNumbers")]
@@ -294,8 +424,17 @@ pub struct CreatePhoneNumberOrderRequest {
     pub product_type: String,
 }
 
+impl Validate for CreatePhoneNumberOrderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.e164_phone_numbers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "e164_phone_numbers"), i);
+            errors.check_pattern(&item_path, &item, "^\\+?[1-9]\\d{1,14}$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePhoneNumberOrderResponse {
     /// <p>The phone number order details.</p>
     #[serde(rename = "PhoneNumberOrder")]
@@ -304,6 +443,7 @@ pub struct CreatePhoneNumberOrderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVoiceConnectorRequest {
     /// <p>The name of the Amazon Chime Voice Connector.</p>
     #[serde(rename = "Name")]
@@ -313,8 +453,19 @@ pub struct CreateVoiceConnectorRequest {
     pub require_encryption: bool,
 }
 
+impl Validate for CreateVoiceConnectorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateVoiceConnectorResponse {
     /// <p>The Amazon Chime Voice Connector details.</p>
     #[serde(rename = "VoiceConnector")]
@@ -324,6 +475,7 @@ pub struct CreateVoiceConnectorResponse {
 
 /// <p>The SIP credentials used to authenticate requests to your Amazon Chime Voice Connector.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Credential {
     /// <p>The RFC2617 compliant password associated with the SIP credentials, in US-ASCII format.</p>
     #[serde(rename = "Password")]
@@ -336,17 +488,25 @@ pub struct Credential {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAccountRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
     pub account_id: String,
 }
 
+impl Validate for DeleteAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteAccountResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEventsConfigurationRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -356,7 +516,15 @@ pub struct DeleteEventsConfigurationRequest {
     pub bot_id: String,
 }
 
+impl Validate for DeleteEventsConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "bot_id"), &self.bot_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePhoneNumberRequest {
     /// <p>The phone number ID.</p>
     #[serde(rename = "PhoneNumberId")]
@@ -364,20 +532,43 @@ pub struct DeletePhoneNumberRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVoiceConnectorOriginationRequest {
     /// <p>The Amazon Chime Voice Connector ID.</p>
     #[serde(rename = "VoiceConnectorId")]
     pub voice_connector_id: String,
 }
 
+impl Validate for DeleteVoiceConnectorOriginationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVoiceConnectorRequest {
     /// <p>The Amazon Chime Voice Connector ID.</p>
     #[serde(rename = "VoiceConnectorId")]
     pub voice_connector_id: String,
 }
 
+impl Validate for DeleteVoiceConnectorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVoiceConnectorTerminationCredentialsRequest {
     /// <p>The RFC2617 compliant username associated with the SIP credentials, in US-ASCII format.</p>
     #[serde(rename = "Usernames")]
@@ -388,14 +579,36 @@ pub struct DeleteVoiceConnectorTerminationCredentialsRequest {
     pub voice_connector_id: String,
 }
 
+impl Validate for DeleteVoiceConnectorTerminationCredentialsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVoiceConnectorTerminationRequest {
     /// <p>The Amazon Chime Voice Connector ID.</p>
     #[serde(rename = "VoiceConnectorId")]
     pub voice_connector_id: String,
 }
 
+impl Validate for DeleteVoiceConnectorTerminationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociatePhoneNumberFromUserRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -406,10 +619,11 @@ pub struct DisassociatePhoneNumberFromUserRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociatePhoneNumberFromUserResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociatePhoneNumbersFromVoiceConnectorRequest {
     /// <p>List of phone numbers, in E.164 format.</p>
     #[serde(rename = "E164PhoneNumbers")]
@@ -420,8 +634,24 @@ pub struct DisassociatePhoneNumbersFromVoiceConnectorRequest {
     pub voice_connector_id: String,
 }
 
+impl Validate for DisassociatePhoneNumbersFromVoiceConnectorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.e164_phone_numbers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "e164_phone_numbers"), i);
+                errors.check_pattern(&item_path, &item, "^\\+?[1-9]\\d{1,14}$");
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociatePhoneNumbersFromVoiceConnectorResponse {
     /// <p>If the action fails for one or more of the phone numbers in the request, a list of the phone numbers is returned, along with error codes and error messages.</p>
     #[serde(rename = "PhoneNumberErrors")]
@@ -431,7 +661,7 @@ pub struct DisassociatePhoneNumbersFromVoiceConnectorResponse {
 
 /// <p>The configuration that allows a bot to receive outgoing events. Can be either an HTTPS endpoint or a Lambda function ARN.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventsConfiguration {
     /// <p>The bot ID.</p>
     #[serde(rename = "BotId")]
@@ -448,14 +678,21 @@ pub struct EventsConfiguration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccountRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
     pub account_id: String,
 }
 
+impl Validate for GetAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAccountResponse {
     /// <p>The Amazon Chime account details.</p>
     #[serde(rename = "Account")]
@@ -464,14 +701,21 @@ pub struct GetAccountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccountSettingsRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
     pub account_id: String,
 }
 
+impl Validate for GetAccountSettingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAccountSettingsResponse {
     /// <p>The Amazon Chime account settings.</p>
     #[serde(rename = "AccountSettings")]
@@ -480,6 +724,7 @@ pub struct GetAccountSettingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBotRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -489,8 +734,15 @@ pub struct GetBotRequest {
     pub bot_id: String,
 }
 
+impl Validate for GetBotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "bot_id"), &self.bot_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBotResponse {
     /// <p>The chat bot details.</p>
     #[serde(rename = "Bot")]
@@ -499,6 +751,7 @@ pub struct GetBotResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetEventsConfigurationRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -508,8 +761,15 @@ pub struct GetEventsConfigurationRequest {
     pub bot_id: String,
 }
 
+impl Validate for GetEventsConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "bot_id"), &self.bot_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetEventsConfigurationResponse {
     /// <p>The events configuration details.</p>
     #[serde(rename = "EventsConfiguration")]
@@ -518,7 +778,7 @@ pub struct GetEventsConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGlobalSettingsResponse {
     /// <p>The Amazon Chime Business Calling settings.</p>
     #[serde(rename = "BusinessCalling")]
@@ -531,14 +791,25 @@ pub struct GetGlobalSettingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPhoneNumberOrderRequest {
     /// <p>The ID for the phone number order.</p>
     #[serde(rename = "PhoneNumberOrderId")]
     pub phone_number_order_id: String,
 }
 
+impl Validate for GetPhoneNumberOrderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "phone_number_order_id"),
+            &self.phone_number_order_id,
+            "[a-fA-F0-9]{8}(?:-[a-fA-F0-9]{4}){3}-[a-fA-F0-9]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPhoneNumberOrderResponse {
     /// <p>The phone number order details.</p>
     #[serde(rename = "PhoneNumberOrder")]
@@ -547,6 +818,7 @@ pub struct GetPhoneNumberOrderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPhoneNumberRequest {
     /// <p>The phone number ID.</p>
     #[serde(rename = "PhoneNumberId")]
@@ -554,7 +826,7 @@ pub struct GetPhoneNumberRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPhoneNumberResponse {
     /// <p>The phone number details.</p>
     #[serde(rename = "PhoneNumber")]
@@ -563,6 +835,7 @@ pub struct GetPhoneNumberResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUserRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -572,8 +845,15 @@ pub struct GetUserRequest {
     pub user_id: String,
 }
 
+impl Validate for GetUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "user_id"), &self.user_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUserResponse {
     /// <p>The user details.</p>
     #[serde(rename = "User")]
@@ -582,6 +862,7 @@ pub struct GetUserResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUserSettingsRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -592,7 +873,7 @@ pub struct GetUserSettingsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUserSettingsResponse {
     /// <p>The user settings.</p>
     #[serde(rename = "UserSettings")]
@@ -601,14 +882,25 @@ pub struct GetUserSettingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVoiceConnectorOriginationRequest {
     /// <p>The Amazon Chime Voice Connector ID.</p>
     #[serde(rename = "VoiceConnectorId")]
     pub voice_connector_id: String,
 }
 
+impl Validate for GetVoiceConnectorOriginationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetVoiceConnectorOriginationResponse {
     /// <p>The origination setting details.</p>
     #[serde(rename = "Origination")]
@@ -617,14 +909,25 @@ pub struct GetVoiceConnectorOriginationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVoiceConnectorRequest {
     /// <p>The Amazon Chime Voice Connector ID.</p>
     #[serde(rename = "VoiceConnectorId")]
     pub voice_connector_id: String,
 }
 
+impl Validate for GetVoiceConnectorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetVoiceConnectorResponse {
     /// <p>The Amazon Chime Voice Connector details.</p>
     #[serde(rename = "VoiceConnector")]
@@ -633,14 +936,25 @@ pub struct GetVoiceConnectorResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVoiceConnectorTerminationHealthRequest {
     /// <p>The Amazon Chime Voice Connector ID.</p>
     #[serde(rename = "VoiceConnectorId")]
     pub voice_connector_id: String,
 }
 
+impl Validate for GetVoiceConnectorTerminationHealthRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetVoiceConnectorTerminationHealthResponse {
     /// <p>The termination health details.</p>
     #[serde(rename = "TerminationHealth")]
@@ -649,14 +963,25 @@ pub struct GetVoiceConnectorTerminationHealthResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVoiceConnectorTerminationRequest {
     /// <p>The Amazon Chime Voice Connector ID.</p>
     #[serde(rename = "VoiceConnectorId")]
     pub voice_connector_id: String,
 }
 
+impl Validate for GetVoiceConnectorTerminationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetVoiceConnectorTerminationResponse {
     /// <p>The termination setting details.</p>
     #[serde(rename = "Termination")]
@@ -666,7 +991,7 @@ pub struct GetVoiceConnectorTerminationResponse {
 
 /// <p>Invitation object returned after emailing users to invite them to join the Amazon Chime <code>Team</code> account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Invite {
     /// <p>The email address to which the invite is sent.</p>
     #[serde(rename = "EmailAddress")]
@@ -687,6 +1012,7 @@ pub struct Invite {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InviteUsersRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -696,8 +1022,24 @@ pub struct InviteUsersRequest {
     pub user_email_list: Vec<String>,
 }
 
+impl Validate for InviteUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_length(
+            &field_path(path, "user_email_list"),
+            self.user_email_list.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.user_email_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "user_email_list"), i);
+            errors.check_pattern(&item_path, &item, ".+@.+\\..+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InviteUsersResponse {
     /// <p>The invite details.</p>
     #[serde(rename = "Invites")]
@@ -706,6 +1048,7 @@ pub struct InviteUsersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAccountsRequest {
     /// <p>The maximum number of results to return in a single call. Defaults to 100.</p>
     #[serde(rename = "MaxResults")]
@@ -725,8 +1068,28 @@ pub struct ListAccountsRequest {
     pub user_email: Option<String>,
 }
 
+impl Validate for ListAccountsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(200));
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.user_email {
+            errors.check_pattern(&field_path(path, "user_email"), &value, ".+@.+\\..+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAccountsResponse {
     /// <p>List of Amazon Chime accounts and account details.</p>
     #[serde(rename = "Accounts")]
@@ -739,6 +1102,7 @@ pub struct ListAccountsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBotsRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -753,8 +1117,17 @@ pub struct ListBotsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListBotsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(99));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBotsResponse {
     /// <p>List of bots and bot details.</p>
     #[serde(rename = "Bots")]
@@ -767,6 +1140,7 @@ pub struct ListBotsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPhoneNumberOrdersRequest {
     /// <p>The maximum number of results to return in a single call.</p>
     #[serde(rename = "MaxResults")]
@@ -778,8 +1152,16 @@ pub struct ListPhoneNumberOrdersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListPhoneNumberOrdersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(99));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPhoneNumberOrdersResponse {
     /// <p>The token to use to retrieve the next page of results.</p>
     #[serde(rename = "NextToken")]
@@ -792,6 +1174,7 @@ pub struct ListPhoneNumberOrdersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPhoneNumbersRequest {
     /// <p>The filter to use to limit the number of results.</p>
     #[serde(rename = "FilterName")]
@@ -819,8 +1202,16 @@ pub struct ListPhoneNumbersRequest {
     pub status: Option<String>,
 }
 
+impl Validate for ListPhoneNumbersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(99));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPhoneNumbersResponse {
     /// <p>The token to use to retrieve the next page of results.</p>
     #[serde(rename = "NextToken")]
@@ -833,6 +1224,7 @@ pub struct ListPhoneNumbersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUsersRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -851,8 +1243,20 @@ pub struct ListUsersRequest {
     pub user_email: Option<String>,
 }
 
+impl Validate for ListUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(200));
+        }
+        if let Some(ref value) = self.user_email {
+            errors.check_pattern(&field_path(path, "user_email"), &value, ".+@.+\\..+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUsersResponse {
     /// <p>The token to use to retrieve the next page of results.</p>
     #[serde(rename = "NextToken")]
@@ -865,14 +1269,25 @@ pub struct ListUsersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVoiceConnectorTerminationCredentialsRequest {
     /// <p>The Amazon Chime Voice Connector ID.</p>
     #[serde(rename = "VoiceConnectorId")]
     pub voice_connector_id: String,
 }
 
+impl Validate for ListVoiceConnectorTerminationCredentialsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVoiceConnectorTerminationCredentialsResponse {
     /// <p>A list of user names.</p>
     #[serde(rename = "Usernames")]
@@ -881,6 +1296,7 @@ pub struct ListVoiceConnectorTerminationCredentialsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVoiceConnectorsRequest {
     /// <p>The maximum number of results to return in a single call.</p>
     #[serde(rename = "MaxResults")]
@@ -892,8 +1308,16 @@ pub struct ListVoiceConnectorsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListVoiceConnectorsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(99));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVoiceConnectorsResponse {
     /// <p>The token to use to retrieve the next page of results.</p>
     #[serde(rename = "NextToken")]
@@ -906,6 +1330,7 @@ pub struct ListVoiceConnectorsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LogoutUserRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -915,13 +1340,20 @@ pub struct LogoutUserRequest {
     pub user_id: String,
 }
 
+impl Validate for LogoutUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "user_id"), &self.user_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LogoutUserResponse {}
 
 /// <p>A phone number for which an order has been placed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OrderedPhoneNumber {
     /// <p>The phone number, in E.164 format.</p>
     #[serde(rename = "E164PhoneNumber")]
@@ -946,6 +1378,17 @@ pub struct Origination {
     pub routes: Option<Vec<OriginationRoute>>,
 }
 
+impl Validate for Origination {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.routes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "routes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Origination routes define call distribution properties for your SIP hosts to receive inbound calls using your Amazon Chime Voice Connector. Limit: 10 origination routes per Amazon Chime Voice Connector.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OriginationRoute {
@@ -971,9 +1414,23 @@ pub struct OriginationRoute {
     pub weight: Option<i64>,
 }
 
+impl Validate for OriginationRoute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.port {
+            errors.check_range(&field_path(path, "port"), *value, None, Some(65535));
+        }
+        if let Some(ref value) = self.priority {
+            errors.check_range(&field_path(path, "priority"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.weight {
+            errors.check_range(&field_path(path, "weight"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 /// <p>A phone number used for Amazon Chime Business Calling or an Amazon Chime Voice Connector.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PhoneNumber {
     /// <p>The phone number associations.</p>
     #[serde(rename = "Associations")]
@@ -1019,7 +1476,7 @@ pub struct PhoneNumber {
 
 /// <p>The phone number associations, such as Amazon Chime account ID, Amazon Chime user ID, or Amazon Chime Voice Connector ID.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PhoneNumberAssociation {
     /// <p>The timestamp of the phone number association, in ISO 8601 format.</p>
     #[serde(rename = "AssociatedTimestamp")]
@@ -1037,7 +1494,7 @@ pub struct PhoneNumberAssociation {
 
 /// <p>The phone number capabilities, such as enabled inbound and outbound calling and text messaging.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PhoneNumberCapabilities {
     /// <p>Allows or denies inbound calling for the specified phone number.</p>
     #[serde(rename = "InboundCall")]
@@ -1067,7 +1524,7 @@ pub struct PhoneNumberCapabilities {
 
 /// <p>If the phone number action fails for one or more of the phone numbers in the request, a list of the phone numbers is returned, along with error codes and error messages.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PhoneNumberError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
@@ -1085,7 +1542,7 @@ pub struct PhoneNumberError {
 
 /// <p>The details of a phone number order created for Amazon Chime.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PhoneNumberOrder {
     /// <p>The phone number order creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
@@ -1114,6 +1571,7 @@ pub struct PhoneNumberOrder {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutEventsConfigurationRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1131,8 +1589,15 @@ pub struct PutEventsConfigurationRequest {
     pub outbound_events_https_endpoint: Option<String>,
 }
 
+impl Validate for PutEventsConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "bot_id"), &self.bot_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutEventsConfigurationResponse {
     #[serde(rename = "EventsConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1140,6 +1605,7 @@ pub struct PutEventsConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutVoiceConnectorOriginationRequest {
     /// <p>The origination setting details to add.</p>
     #[serde(rename = "Origination")]
@@ -1149,8 +1615,20 @@ pub struct PutVoiceConnectorOriginationRequest {
     pub voice_connector_id: String,
 }
 
+impl Validate for PutVoiceConnectorOriginationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.origination
+            .validate_at(&field_path(path, "origination"), errors);
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutVoiceConnectorOriginationResponse {
     /// <p>The updated origination setting details.</p>
     #[serde(rename = "Origination")]
@@ -1159,6 +1637,7 @@ pub struct PutVoiceConnectorOriginationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutVoiceConnectorTerminationCredentialsRequest {
     /// <p>The termination SIP credentials.</p>
     #[serde(rename = "Credentials")]
@@ -1169,7 +1648,18 @@ pub struct PutVoiceConnectorTerminationCredentialsRequest {
     pub voice_connector_id: String,
 }
 
+impl Validate for PutVoiceConnectorTerminationCredentialsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutVoiceConnectorTerminationRequest {
     /// <p>The termination setting details to add.</p>
     #[serde(rename = "Termination")]
@@ -1179,8 +1669,20 @@ pub struct PutVoiceConnectorTerminationRequest {
     pub voice_connector_id: String,
 }
 
+impl Validate for PutVoiceConnectorTerminationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.termination
+            .validate_at(&field_path(path, "termination"), errors);
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutVoiceConnectorTerminationResponse {
     /// <p>The updated termination setting details.</p>
     #[serde(rename = "Termination")]
@@ -1189,6 +1691,7 @@ pub struct PutVoiceConnectorTerminationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegenerateSecurityTokenRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1198,8 +1701,15 @@ pub struct RegenerateSecurityTokenRequest {
     pub bot_id: String,
 }
 
+impl Validate for RegenerateSecurityTokenRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "bot_id"), &self.bot_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegenerateSecurityTokenResponse {
     #[serde(rename = "Bot")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1207,6 +1717,7 @@ pub struct RegenerateSecurityTokenResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResetPersonalPINRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1216,8 +1727,15 @@ pub struct ResetPersonalPINRequest {
     pub user_id: String,
 }
 
+impl Validate for ResetPersonalPINRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "user_id"), &self.user_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResetPersonalPINResponse {
     /// <p>The user details and new personal meeting PIN.</p>
     #[serde(rename = "User")]
@@ -1226,14 +1744,25 @@ pub struct ResetPersonalPINResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestorePhoneNumberRequest {
     /// <p>The phone number.</p>
     #[serde(rename = "PhoneNumberId")]
     pub phone_number_id: String,
 }
 
+impl Validate for RestorePhoneNumberRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "phone_number_id"),
+            &self.phone_number_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestorePhoneNumberResponse {
     /// <p>The phone number details.</p>
     #[serde(rename = "PhoneNumber")]
@@ -1242,6 +1771,7 @@ pub struct RestorePhoneNumberResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchAvailablePhoneNumbersRequest {
     /// <p>The area code used to filter results.</p>
     #[serde(rename = "AreaCode")]
@@ -1273,8 +1803,32 @@ pub struct SearchAvailablePhoneNumbersRequest {
     pub toll_free_prefix: Option<String>,
 }
 
+impl Validate for SearchAvailablePhoneNumbersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.country {
+            errors.check_pattern(&field_path(path, "country"), &value, "[A-Z]{2}");
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+        if let Some(ref value) = self.toll_free_prefix {
+            errors.check_length(
+                &field_path(path, "toll_free_prefix"),
+                value.chars().count(),
+                Some(3),
+                Some(3),
+            );
+            errors.check_pattern(
+                &field_path(path, "toll_free_prefix"),
+                &value,
+                "^8(00|33|44|55|66|77|88)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchAvailablePhoneNumbersResponse {
     /// <p>List of phone numbers, in E.164 format.</p>
     #[serde(rename = "E164PhoneNumbers")]
@@ -1321,9 +1875,24 @@ pub struct Termination {
     pub disabled: Option<bool>,
 }
 
+impl Validate for Termination {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cps_limit {
+            errors.check_range(&field_path(path, "cps_limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.default_phone_number {
+            errors.check_pattern(
+                &field_path(path, "default_phone_number"),
+                &value,
+                "^\\+?[1-9]\\d{1,14}$",
+            );
+        }
+    }
+}
+
 /// <p>The termination health details, including the source IP address and timestamp of the last successful SIP <code>OPTIONS</code> message from your SIP infrastructure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TerminationHealth {
     /// <p>The source IP address.</p>
     #[serde(rename = "Source")]
@@ -1336,6 +1905,7 @@ pub struct TerminationHealth {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAccountRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1346,8 +1916,23 @@ pub struct UpdateAccountRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAccountResponse {
     /// <p>The updated Amazon Chime account details.</p>
     #[serde(rename = "Account")]
@@ -1356,6 +1941,7 @@ pub struct UpdateAccountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAccountSettingsRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1365,11 +1951,18 @@ pub struct UpdateAccountSettingsRequest {
     pub account_settings: AccountSettings,
 }
 
+impl Validate for UpdateAccountSettingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAccountSettingsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateBotRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1383,8 +1976,15 @@ pub struct UpdateBotRequest {
     pub disabled: Option<bool>,
 }
 
+impl Validate for UpdateBotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "bot_id"), &self.bot_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateBotResponse {
     /// <p>The updated bot details.</p>
     #[serde(rename = "Bot")]
@@ -1393,6 +1993,7 @@ pub struct UpdateBotResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGlobalSettingsRequest {
     /// <p>The Amazon Chime Business Calling settings.</p>
     #[serde(rename = "BusinessCalling")]
@@ -1403,6 +2004,7 @@ pub struct UpdateGlobalSettingsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePhoneNumberRequest {
     /// <p>The phone number ID.</p>
     #[serde(rename = "PhoneNumberId")]
@@ -1415,6 +2017,7 @@ pub struct UpdatePhoneNumberRequest {
 
 /// <p>The phone number ID and product type fields to update, used with the <a>BatchUpdatePhoneNumber</a> and <a>UpdatePhoneNumber</a> actions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePhoneNumberRequestItem {
     /// <p>The phone number ID to update.</p>
     #[serde(rename = "PhoneNumberId")]
@@ -1425,8 +2028,18 @@ pub struct UpdatePhoneNumberRequestItem {
     pub product_type: Option<String>,
 }
 
+impl Validate for UpdatePhoneNumberRequestItem {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "phone_number_id"),
+            &self.phone_number_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePhoneNumberResponse {
     /// <p>The updated phone number details.</p>
     #[serde(rename = "PhoneNumber")]
@@ -1435,6 +2048,7 @@ pub struct UpdatePhoneNumberResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1448,8 +2062,16 @@ pub struct UpdateUserRequest {
     pub user_id: String,
 }
 
+impl Validate for UpdateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "user_id"), &self.user_id, ".*\\S.*");
+    }
+}
+
 /// <p>The user ID and user fields to update, used with the <a>BatchUpdateUser</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserRequestItem {
     /// <p>The user license type.</p>
     #[serde(rename = "LicenseType")]
@@ -1460,8 +2082,14 @@ pub struct UpdateUserRequestItem {
     pub user_id: String,
 }
 
+impl Validate for UpdateUserRequestItem {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "user_id"), &self.user_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUserResponse {
     /// <p>The updated user details.</p>
     #[serde(rename = "User")]
@@ -1470,6 +2098,7 @@ pub struct UpdateUserResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserSettingsRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1483,6 +2112,7 @@ pub struct UpdateUserSettingsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateVoiceConnectorRequest {
     /// <p>The name of the Amazon Chime Voice Connector.</p>
     #[serde(rename = "Name")]
@@ -1495,8 +2125,24 @@ pub struct UpdateVoiceConnectorRequest {
     pub voice_connector_id: String,
 }
 
+impl Validate for UpdateVoiceConnectorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "voice_connector_id"),
+            &self.voice_connector_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateVoiceConnectorResponse {
     /// <p>The Amazon Chime Voice Connector details.</p>
     #[serde(rename = "VoiceConnector")]
@@ -1506,7 +2152,7 @@ pub struct UpdateVoiceConnectorResponse {
 
 /// <p>The user on the Amazon Chime account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct User {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1555,7 +2201,7 @@ pub struct User {
 
 /// <p>The list of errors returned when errors are encountered during the <a>BatchSuspendUser</a>, <a>BatchUnsuspendUser</a>, or <a>BatchUpdateUser</a> actions. This includes user IDs, error codes, and error messages.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
@@ -1581,7 +2227,7 @@ pub struct UserSettings {
 
 /// <p>The Amazon Chime Voice Connector configuration, including outbound host name and encryption settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VoiceConnector {
     /// <p>The Amazon Chime Voice Connector creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
@@ -1678,7 +2324,12 @@ impl AssociatePhoneNumberWithUserError {
                         AssociatePhoneNumberWithUserError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1763,7 +2414,12 @@ impl AssociatePhoneNumbersWithVoiceConnectorError {
                         AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1840,7 +2496,12 @@ impl BatchDeletePhoneNumberError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1909,7 +2570,12 @@ impl BatchSuspendUserError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(BatchSuspendUserError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1982,7 +2648,12 @@ impl BatchUnsuspendUserError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2059,7 +2730,12 @@ impl BatchUpdatePhoneNumberError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2128,7 +2804,12 @@ impl BatchUpdateUserError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(BatchUpdateUserError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2197,7 +2878,12 @@ impl CreateAccountError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(CreateAccountError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2266,7 +2952,12 @@ impl CreateBotError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(CreateBotError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2338,7 +3029,12 @@ impl CreatePhoneNumberOrderError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2407,7 +3103,12 @@ impl CreateVoiceConnectorError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2480,7 +3181,12 @@ impl DeleteAccountError {
                 "UnprocessableEntityException" => {
                     return RusotoError::Service(DeleteAccountError::UnprocessableEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2555,7 +3261,12 @@ impl DeleteEventsConfigurationError {
                         DeleteEventsConfigurationError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2627,7 +3338,12 @@ impl DeletePhoneNumberError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2702,7 +3418,12 @@ impl DeleteVoiceConnectorError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2787,7 +3508,12 @@ impl DeleteVoiceConnectorOriginationError {
                         DeleteVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2872,7 +3598,12 @@ impl DeleteVoiceConnectorTerminationError {
                         DeleteVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2961,7 +3692,12 @@ impl DeleteVoiceConnectorTerminationCredentialsError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3046,7 +3782,12 @@ impl DisassociatePhoneNumberFromUserError {
                         DisassociatePhoneNumberFromUserError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3135,7 +3876,12 @@ impl DisassociatePhoneNumbersFromVoiceConnectorError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3204,7 +3950,12 @@ impl GetAccountError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(GetAccountError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3277,7 +4028,12 @@ impl GetAccountSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3339,7 +4095,12 @@ impl GetBotError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(GetBotError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3415,7 +4176,12 @@ impl GetEventsConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3483,7 +4249,12 @@ impl GetGlobalSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3551,7 +4322,12 @@ impl GetPhoneNumberError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(GetPhoneNumberError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3624,7 +4400,12 @@ impl GetPhoneNumberOrderError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3693,7 +4474,12 @@ impl GetUserError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(GetUserError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3762,7 +4548,12 @@ impl GetUserSettingsError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(GetUserSettingsError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3835,7 +4626,12 @@ impl GetVoiceConnectorError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3920,7 +4716,12 @@ impl GetVoiceConnectorOriginationError {
                         GetVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4005,7 +4806,12 @@ impl GetVoiceConnectorTerminationError {
                         GetVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4090,7 +4896,12 @@ impl GetVoiceConnectorTerminationHealthError {
                         GetVoiceConnectorTerminationHealthError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4159,7 +4970,12 @@ impl InviteUsersError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(InviteUsersError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4228,7 +5044,12 @@ impl ListAccountsError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(ListAccountsError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4292,7 +5113,12 @@ impl ListBotsError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(ListBotsError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4363,7 +5189,12 @@ impl ListPhoneNumberOrdersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4426,7 +5257,12 @@ impl ListPhoneNumbersError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(ListPhoneNumbersError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4494,7 +5330,12 @@ impl ListUsersError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(ListUsersError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4579,7 +5420,12 @@ impl ListVoiceConnectorTerminationCredentialsError {
                         ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4647,7 +5493,12 @@ impl ListVoiceConnectorsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4715,7 +5566,12 @@ impl LogoutUserError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(LogoutUserError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4792,7 +5648,12 @@ impl PutEventsConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4877,7 +5738,12 @@ impl PutVoiceConnectorOriginationError {
                         PutVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4962,7 +5828,12 @@ impl PutVoiceConnectorTerminationError {
                         PutVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5047,7 +5918,12 @@ impl PutVoiceConnectorTerminationCredentialsError {
                         PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5117,7 +5993,12 @@ impl RegenerateSecurityTokenError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5185,7 +6066,12 @@ impl ResetPersonalPINError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(ResetPersonalPINError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5258,7 +6144,12 @@ impl RestorePhoneNumberError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5336,7 +6227,12 @@ impl SearchAvailablePhoneNumbersError {
                         SearchAvailablePhoneNumbersError::UnauthorizedClient(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5404,7 +6300,12 @@ impl UpdateAccountError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(UpdateAccountError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5486,7 +6387,12 @@ impl UpdateAccountSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5551,7 +6457,12 @@ impl UpdateBotError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(UpdateBotError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5620,7 +6531,12 @@ impl UpdateGlobalSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5692,7 +6608,12 @@ impl UpdatePhoneNumberError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5761,7 +6682,12 @@ impl UpdateUserError {
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(UpdateUserError::UnauthorizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5834,7 +6760,12 @@ impl UpdateUserSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5909,7 +6840,12 @@ impl UpdateVoiceConnectorError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6286,82 +7222,14 @@ pub trait Chime {
         input: UpdateVoiceConnectorRequest,
     ) -> RusotoFuture<UpdateVoiceConnectorResponse, UpdateVoiceConnectorError>;
 }
-/// A client for the Amazon Chime API.
-#[derive(Clone)]
-pub struct ChimeClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ChimeClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ChimeClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ChimeClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ChimeClient {
-        ChimeClient { client, region }
-    }
-}
-
-impl Chime for ChimeClient {
-    /// <p>Associates a phone number with the specified Amazon Chime user.</p>
+const _: Option<&dyn Chime> = None;
+impl<'a, T: Chime + ?Sized> Chime for &'a T {
     fn associate_phone_number_with_user(
         &self,
         input: AssociatePhoneNumberWithUserRequest,
     ) -> RusotoFuture<AssociatePhoneNumberWithUserResponse, AssociatePhoneNumberWithUserError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users/{user_id}",
-            account_id = input.account_id,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "associate-phone-number");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociatePhoneNumberWithUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociatePhoneNumberWithUserError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_phone_number_with_user(input)
     }
-
-    /// <p>Associates a phone number with the specified Amazon Chime Voice Connector.</p>
     fn associate_phone_numbers_with_voice_connector(
         &self,
         input: AssociatePhoneNumbersWithVoiceConnectorRequest,
@@ -6369,601 +7237,111 @@ impl Chime for ChimeClient {
         AssociatePhoneNumbersWithVoiceConnectorResponse,
         AssociatePhoneNumbersWithVoiceConnectorError,
     > {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "associate-phone-numbers");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociatePhoneNumbersWithVoiceConnectorResponse, _>(
-                    )?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociatePhoneNumbersWithVoiceConnectorError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).associate_phone_numbers_with_voice_connector(input)
     }
-
-    /// <p>Moves phone numbers into the <b>Deletion queue</b>. Phone numbers must be disassociated from any users or Amazon Chime Voice Connectors before they can be deleted.</p> <p>Phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
     fn batch_delete_phone_number(
         &self,
         input: BatchDeletePhoneNumberRequest,
     ) -> RusotoFuture<BatchDeletePhoneNumberResponse, BatchDeletePhoneNumberError> {
-        let request_uri = "/phone-numbers";
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "batch-delete");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDeletePhoneNumberResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchDeletePhoneNumberError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_delete_phone_number(input)
     }
-
-    /// <p>Suspends up to 50 users from a <code>Team</code> or <code>EnterpriseLWA</code> Amazon Chime account. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Users suspended from a <code>Team</code> account are dissasociated from the account, but they can continue to use Amazon Chime as free users. To remove the suspension from suspended <code>Team</code> account users, invite them to the <code>Team</code> account again. You can use the <a>InviteUsers</a> action to do so.</p> <p>Users suspended from an <code>EnterpriseLWA</code> account are immediately signed out of Amazon Chime and can no longer sign in. To remove the suspension from suspended <code>EnterpriseLWA</code> account users, use the <a>BatchUnsuspendUser</a> action. </p> <p>To sign out users without suspending them, use the <a>LogoutUser</a> action.</p>
     fn batch_suspend_user(
         &self,
         input: BatchSuspendUserRequest,
     ) -> RusotoFuture<BatchSuspendUserResponse, BatchSuspendUserError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "suspend");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchSuspendUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchSuspendUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_suspend_user(input)
     }
-
-    /// <p>Removes the suspension from up to 50 previously suspended users for the specified Amazon Chime <code>EnterpriseLWA</code> account. Only users on <code>EnterpriseLWA</code> accounts can be unsuspended using this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Previously suspended users who are unsuspended using this action are returned to <code>Registered</code> status. Users who are not previously suspended are ignored.</p>
     fn batch_unsuspend_user(
         &self,
         input: BatchUnsuspendUserRequest,
     ) -> RusotoFuture<BatchUnsuspendUserResponse, BatchUnsuspendUserError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "unsuspend");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchUnsuspendUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchUnsuspendUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_unsuspend_user(input)
     }
-
-    /// <p>Updates phone number product types. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
     fn batch_update_phone_number(
         &self,
         input: BatchUpdatePhoneNumberRequest,
     ) -> RusotoFuture<BatchUpdatePhoneNumberResponse, BatchUpdatePhoneNumberError> {
-        let request_uri = "/phone-numbers";
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "batch-update");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchUpdatePhoneNumberResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchUpdatePhoneNumberError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_update_phone_number(input)
     }
-
-    /// <p>Updates user details within the <a>UpdateUserRequestItem</a> object for up to 20 users for the specified Amazon Chime account. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
     fn batch_update_user(
         &self,
         input: BatchUpdateUserRequest,
     ) -> RusotoFuture<BatchUpdateUserResponse, BatchUpdateUserError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchUpdateUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchUpdateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_update_user(input)
     }
-
-    /// <p>Creates an Amazon Chime account under the administrator's AWS account. Only <code>Team</code> account types are currently supported for this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p>
     fn create_account(
         &self,
         input: CreateAccountRequest,
     ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
-        let request_uri = "/accounts";
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAccountError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_account(input)
     }
-
-    /// <p>Creates a bot for an Amazon Chime Enterprise account.</p>
     fn create_bot(
         &self,
         input: CreateBotRequest,
     ) -> RusotoFuture<CreateBotResponse, CreateBotError> {
-        let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBotResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBotError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_bot(input)
     }
-
-    /// <p>Creates an order for phone numbers to be provisioned. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
     fn create_phone_number_order(
         &self,
         input: CreatePhoneNumberOrderRequest,
     ) -> RusotoFuture<CreatePhoneNumberOrderResponse, CreatePhoneNumberOrderError> {
-        let request_uri = "/phone-number-orders";
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePhoneNumberOrderResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreatePhoneNumberOrderError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_phone_number_order(input)
     }
-
-    /// <p>Creates an Amazon Chime Voice Connector under the administrator's AWS account. Enabling <a>CreateVoiceConnectorRequest$RequireEncryption</a> configures your Amazon Chime Voice Connector to use TLS transport for SIP signaling and Secure RTP (SRTP) for media. Inbound calls use TLS transport, and unencrypted outbound calls are blocked.</p>
     fn create_voice_connector(
         &self,
         input: CreateVoiceConnectorRequest,
     ) -> RusotoFuture<CreateVoiceConnectorResponse, CreateVoiceConnectorError> {
-        let request_uri = "/voice-connectors";
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateVoiceConnectorResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateVoiceConnectorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_voice_connector(input)
     }
-
-    /// <p>Deletes the specified Amazon Chime account. You must suspend all users before deleting a <code>Team</code> account. You can use the <a>BatchSuspendUser</a> action to do so.</p> <p>For <code>EnterpriseLWA</code> and <code>EnterpriseAD</code> accounts, you must release the claimed domains for your Amazon Chime account before deletion. As soon as you release the domain, all users under that account are suspended.</p> <p>Deleted accounts appear in your <code>Disabled</code> accounts list for 90 days. To restore a deleted account from your <code>Disabled</code> accounts list, you must contact AWS Support.</p> <p>After 90 days, deleted accounts are permanently removed from your <code>Disabled</code> accounts list.</p>
     fn delete_account(
         &self,
         input: DeleteAccountRequest,
     ) -> RusotoFuture<DeleteAccountResponse, DeleteAccountError> {
-        let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
-
-        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAccountError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_account(input)
     }
-
-    /// <p>Deletes the events configuration that allows a bot to receive outgoing events.</p>
     fn delete_events_configuration(
         &self,
         input: DeleteEventsConfigurationRequest,
     ) -> RusotoFuture<(), DeleteEventsConfigurationError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/bots/{bot_id}/events-configuration",
-            account_id = input.account_id,
-            bot_id = input.bot_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteEventsConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_events_configuration(input)
     }
-
-    /// <p>Moves the specified phone number into the <b>Deletion queue</b>. A phone number must be disassociated from any users or Amazon Chime Voice Connectors before it can be deleted.</p> <p>Deleted phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
     fn delete_phone_number(
         &self,
         input: DeletePhoneNumberRequest,
     ) -> RusotoFuture<(), DeletePhoneNumberError> {
-        let request_uri = format!(
-            "/phone-numbers/{phone_number_id}",
-            phone_number_id = input.phone_number_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeletePhoneNumberError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_phone_number(input)
     }
-
-    /// <p>Deletes the specified Amazon Chime Voice Connector. Any phone numbers assigned to the Amazon Chime Voice Connector must be unassigned from it before it can be deleted.</p>
     fn delete_voice_connector(
         &self,
         input: DeleteVoiceConnectorRequest,
     ) -> RusotoFuture<(), DeleteVoiceConnectorError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteVoiceConnectorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_voice_connector(input)
     }
-
-    /// <p>Deletes the origination settings for the specified Amazon Chime Voice Connector.</p>
     fn delete_voice_connector_origination(
         &self,
         input: DeleteVoiceConnectorOriginationRequest,
     ) -> RusotoFuture<(), DeleteVoiceConnectorOriginationError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/origination",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteVoiceConnectorOriginationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).delete_voice_connector_origination(input)
     }
-
-    /// <p>Deletes the termination settings for the specified Amazon Chime Voice Connector.</p>
     fn delete_voice_connector_termination(
         &self,
         input: DeleteVoiceConnectorTerminationRequest,
     ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/termination",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteVoiceConnectorTerminationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).delete_voice_connector_termination(input)
     }
-
-    /// <p>Deletes the specified SIP credentials used by your equipment to authenticate during call termination.</p>
     fn delete_voice_connector_termination_credentials(
         &self,
         input: DeleteVoiceConnectorTerminationCredentialsRequest,
     ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationCredentialsError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/termination/credentials",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "delete");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteVoiceConnectorTerminationCredentialsError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_voice_connector_termination_credentials(input)
     }
-
-    /// <p>Disassociates the primary provisioned phone number from the specified Amazon Chime user.</p>
     fn disassociate_phone_number_from_user(
         &self,
         input: DisassociatePhoneNumberFromUserRequest,
     ) -> RusotoFuture<DisassociatePhoneNumberFromUserResponse, DisassociatePhoneNumberFromUserError>
     {
-        let request_uri = format!(
-            "/accounts/{account_id}/users/{user_id}",
-            account_id = input.account_id,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("operation", "disassociate-phone-number");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociatePhoneNumberFromUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociatePhoneNumberFromUserError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disassociate_phone_number_from_user(input)
     }
-
-    /// <p>Disassociates the specified phone number from the specified Amazon Chime Voice Connector.</p>
     fn disassociate_phone_numbers_from_voice_connector(
         &self,
         input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
@@ -6971,406 +7349,73 @@ impl Chime for ChimeClient {
         DisassociatePhoneNumbersFromVoiceConnectorResponse,
         DisassociatePhoneNumbersFromVoiceConnectorError,
     > {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "disassociate-phone-numbers");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociatePhoneNumbersFromVoiceConnectorResponse, _>(
-                    )?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociatePhoneNumbersFromVoiceConnectorError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_phone_numbers_from_voice_connector(input)
     }
-
-    /// <p>Retrieves details for the specified Amazon Chime account, such as account type and supported licenses.</p>
     fn get_account(
         &self,
         input: GetAccountRequest,
     ) -> RusotoFuture<GetAccountResponse, GetAccountError> {
-        let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAccountError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_account(input)
     }
-
-    /// <p>Retrieves account settings for the specified Amazon Chime account ID, such as remote control and dial out settings. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
     fn get_account_settings(
         &self,
         input: GetAccountSettingsRequest,
     ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/settings",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAccountSettingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAccountSettingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_account_settings(input)
     }
-
-    /// <p>Retrieves details for the specified bot, such as bot email address, bot type, status, and display name.</p>
     fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/bots/{bot_id}",
-            account_id = input.account_id,
-            bot_id = input.bot_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBotResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBotError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_bot(input)
     }
-
-    /// <p>Gets details for an events configuration that allows a bot to receive outgoing events, such as an HTTPS endpoint or Lambda function ARN. </p>
     fn get_events_configuration(
         &self,
         input: GetEventsConfigurationRequest,
     ) -> RusotoFuture<GetEventsConfigurationResponse, GetEventsConfigurationError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/bots/{bot_id}/events-configuration",
-            account_id = input.account_id,
-            bot_id = input.bot_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetEventsConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetEventsConfigurationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_events_configuration(input)
     }
-
-    /// <p>Retrieves global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
     fn get_global_settings(
         &self,
     ) -> RusotoFuture<GetGlobalSettingsResponse, GetGlobalSettingsError> {
-        let request_uri = "/settings";
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGlobalSettingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetGlobalSettingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_global_settings()
     }
-
-    /// <p>Retrieves details for the specified phone number ID, such as associations, capabilities, and product type.</p>
     fn get_phone_number(
         &self,
         input: GetPhoneNumberRequest,
     ) -> RusotoFuture<GetPhoneNumberResponse, GetPhoneNumberError> {
-        let request_uri = format!(
-            "/phone-numbers/{phone_number_id}",
-            phone_number_id = input.phone_number_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPhoneNumberResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPhoneNumberError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_phone_number(input)
     }
-
-    /// <p>Retrieves details for the specified phone number order, such as order creation timestamp, phone numbers in E.164 format, product type, and order status.</p>
     fn get_phone_number_order(
         &self,
         input: GetPhoneNumberOrderRequest,
     ) -> RusotoFuture<GetPhoneNumberOrderResponse, GetPhoneNumberOrderError> {
-        let request_uri = format!(
-            "/phone-number-orders/{phone_number_order_id}",
-            phone_number_order_id = input.phone_number_order_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPhoneNumberOrderResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetPhoneNumberOrderError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_phone_number_order(input)
     }
-
-    /// <p>Retrieves details for the specified user ID, such as primary email address, license type, and personal meeting PIN.</p> <p>To retrieve user details with an email address instead of a user ID, use the <a>ListUsers</a> action, and then filter by email address.</p>
     fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users/{user_id}",
-            account_id = input.account_id,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_user(input)
     }
-
-    /// <p>Retrieves settings for the specified user ID, such as any associated phone number settings.</p>
     fn get_user_settings(
         &self,
         input: GetUserSettingsRequest,
     ) -> RusotoFuture<GetUserSettingsResponse, GetUserSettingsError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users/{user_id}/settings",
-            account_id = input.account_id,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetUserSettingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUserSettingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_user_settings(input)
     }
-
-    /// <p>Retrieves details for the specified Amazon Chime Voice Connector, such as timestamps, name, outbound host, and encryption requirements.</p>
     fn get_voice_connector(
         &self,
         input: GetVoiceConnectorRequest,
     ) -> RusotoFuture<GetVoiceConnectorResponse, GetVoiceConnectorError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetVoiceConnectorResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetVoiceConnectorError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_voice_connector(input)
     }
-
-    /// <p>Retrieves origination setting details for the specified Amazon Chime Voice Connector.</p>
     fn get_voice_connector_origination(
         &self,
         input: GetVoiceConnectorOriginationRequest,
     ) -> RusotoFuture<GetVoiceConnectorOriginationResponse, GetVoiceConnectorOriginationError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/origination",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetVoiceConnectorOriginationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetVoiceConnectorOriginationError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_voice_connector_origination(input)
     }
-
-    /// <p>Retrieves termination setting details for the specified Amazon Chime Voice Connector.</p>
     fn get_voice_connector_termination(
         &self,
         input: GetVoiceConnectorTerminationRequest,
     ) -> RusotoFuture<GetVoiceConnectorTerminationResponse, GetVoiceConnectorTerminationError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/termination",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetVoiceConnectorTerminationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetVoiceConnectorTerminationError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_voice_connector_termination(input)
     }
-
-    /// <p>Retrieves information about the last time a SIP <code>OPTIONS</code> ping was received from your SIP infrastructure for the specified Amazon Chime Voice Connector.</p>
     fn get_voice_connector_termination_health(
         &self,
         input: GetVoiceConnectorTerminationHealthRequest,
@@ -7378,282 +7423,41 @@ impl Chime for ChimeClient {
         GetVoiceConnectorTerminationHealthResponse,
         GetVoiceConnectorTerminationHealthError,
     > {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/termination/health",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetVoiceConnectorTerminationHealthResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetVoiceConnectorTerminationHealthError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_voice_connector_termination_health(input)
     }
-
-    /// <p>Sends email invites to as many as 50 users, inviting them to the specified Amazon Chime <code>Team</code> account. Only <code>Team</code> account types are currently supported for this action. </p>
     fn invite_users(
         &self,
         input: InviteUsersRequest,
     ) -> RusotoFuture<InviteUsersResponse, InviteUsersError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "add");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InviteUsersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(InviteUsersError::from_response(response))),
-                )
-            }
-        })
+        (**self).invite_users(input)
     }
-
-    /// <p>Lists the Amazon Chime accounts under the administrator's AWS account. You can filter accounts by account name prefix. To find out which Amazon Chime account a user belongs to, you can filter by the user's email address, which returns one account result.</p>
     fn list_accounts(
         &self,
         input: ListAccountsRequest,
     ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
-        let request_uri = "/accounts";
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("max-results", x);
-        }
-        if let Some(ref x) = input.name {
-            params.put("name", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("next-token", x);
-        }
-        if let Some(ref x) = input.user_email {
-            params.put("user-email", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAccountsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAccountsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_accounts(input)
     }
-
-    /// <p>Lists the bots associated with the administrator's Amazon Chime Enterprise account ID.</p>
     fn list_bots(&self, input: ListBotsRequest) -> RusotoFuture<ListBotsResponse, ListBotsError> {
-        let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("max-results", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("next-token", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBotsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListBotsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_bots(input)
     }
-
-    /// <p>Lists the phone number orders for the administrator's Amazon Chime account.</p>
     fn list_phone_number_orders(
         &self,
         input: ListPhoneNumberOrdersRequest,
     ) -> RusotoFuture<ListPhoneNumberOrdersResponse, ListPhoneNumberOrdersError> {
-        let request_uri = "/phone-number-orders";
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("max-results", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("next-token", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPhoneNumberOrdersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListPhoneNumberOrdersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_phone_number_orders(input)
     }
-
-    /// <p>Lists the phone numbers for the specified Amazon Chime account, Amazon Chime user, or Amazon Chime Voice Connector.</p>
     fn list_phone_numbers(
         &self,
         input: ListPhoneNumbersRequest,
     ) -> RusotoFuture<ListPhoneNumbersResponse, ListPhoneNumbersError> {
-        let request_uri = "/phone-numbers";
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.filter_name {
-            params.put("filter-name", x);
-        }
-        if let Some(ref x) = input.filter_value {
-            params.put("filter-value", x);
-        }
-        if let Some(ref x) = input.max_results {
-            params.put("max-results", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("next-token", x);
-        }
-        if let Some(ref x) = input.product_type {
-            params.put("product-type", x);
-        }
-        if let Some(ref x) = input.status {
-            params.put("status", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPhoneNumbersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListPhoneNumbersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_phone_numbers(input)
     }
-
-    /// <p>Lists the users that belong to the specified Amazon Chime account. You can specify an email address to list only the user that the email address belongs to.</p>
     fn list_users(
         &self,
         input: ListUsersRequest,
     ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("max-results", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("next-token", x);
-        }
-        if let Some(ref x) = input.user_email {
-            params.put("user-email", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUsersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListUsersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_users(input)
     }
-
-    /// <p>Lists the SIP credentials for the specified Amazon Chime Voice Connector.</p>
     fn list_voice_connector_termination_credentials(
         &self,
         input: ListVoiceConnectorTerminationCredentialsRequest,
@@ -7661,668 +7465,5473 @@ impl Chime for ChimeClient {
         ListVoiceConnectorTerminationCredentialsResponse,
         ListVoiceConnectorTerminationCredentialsError,
     > {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/termination/credentials",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVoiceConnectorTerminationCredentialsResponse, _>(
-                    )?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListVoiceConnectorTerminationCredentialsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_voice_connector_termination_credentials(input)
     }
-
-    /// <p>Lists the Amazon Chime Voice Connectors for the administrator's AWS account.</p>
     fn list_voice_connectors(
         &self,
         input: ListVoiceConnectorsRequest,
     ) -> RusotoFuture<ListVoiceConnectorsResponse, ListVoiceConnectorsError> {
-        let request_uri = "/voice-connectors";
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("max-results", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("next-token", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVoiceConnectorsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListVoiceConnectorsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_voice_connectors(input)
     }
-
-    /// <p>Logs out the specified user from all of the devices they are currently logged into.</p>
     fn logout_user(
         &self,
         input: LogoutUserRequest,
     ) -> RusotoFuture<LogoutUserResponse, LogoutUserError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users/{user_id}",
-            account_id = input.account_id,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("operation", "logout");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<LogoutUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(LogoutUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).logout_user(input)
     }
-
-    /// <p>Creates an events configuration that allows a bot to receive outgoing events sent by Amazon Chime. Choose either an HTTPS endpoint or a Lambda function ARN. For more information, see <a>Bot</a>.</p>
     fn put_events_configuration(
         &self,
         input: PutEventsConfigurationRequest,
     ) -> RusotoFuture<PutEventsConfigurationResponse, PutEventsConfigurationError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/bots/{bot_id}/events-configuration",
-            account_id = input.account_id,
-            bot_id = input.bot_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutEventsConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutEventsConfigurationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).put_events_configuration(input)
     }
-
-    /// <p>Adds origination settings for the specified Amazon Chime Voice Connector.</p>
     fn put_voice_connector_origination(
         &self,
         input: PutVoiceConnectorOriginationRequest,
     ) -> RusotoFuture<PutVoiceConnectorOriginationResponse, PutVoiceConnectorOriginationError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/origination",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutVoiceConnectorOriginationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutVoiceConnectorOriginationError::from_response(response))
-                }))
-            }
-        })
+        (**self).put_voice_connector_origination(input)
     }
-
-    /// <p>Adds termination settings for the specified Amazon Chime Voice Connector.</p>
     fn put_voice_connector_termination(
         &self,
         input: PutVoiceConnectorTerminationRequest,
     ) -> RusotoFuture<PutVoiceConnectorTerminationResponse, PutVoiceConnectorTerminationError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/termination",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutVoiceConnectorTerminationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutVoiceConnectorTerminationError::from_response(response))
-                }))
-            }
-        })
+        (**self).put_voice_connector_termination(input)
     }
-
-    /// <p>Adds termination SIP credentials for the specified Amazon Chime Voice Connector.</p>
     fn put_voice_connector_termination_credentials(
         &self,
         input: PutVoiceConnectorTerminationCredentialsRequest,
     ) -> RusotoFuture<(), PutVoiceConnectorTerminationCredentialsError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/termination/credentials",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "put");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutVoiceConnectorTerminationCredentialsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).put_voice_connector_termination_credentials(input)
     }
-
-    /// <p>Regenerates the security token for a bot.</p>
     fn regenerate_security_token(
         &self,
         input: RegenerateSecurityTokenRequest,
     ) -> RusotoFuture<RegenerateSecurityTokenResponse, RegenerateSecurityTokenError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/bots/{bot_id}",
-            account_id = input.account_id,
-            bot_id = input.bot_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("operation", "regenerate-security-token");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegenerateSecurityTokenResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegenerateSecurityTokenError::from_response(response))
-                }))
-            }
-        })
+        (**self).regenerate_security_token(input)
     }
-
-    /// <p>Resets the personal meeting PIN for the specified user on an Amazon Chime account. Returns the <a>User</a> object with the updated personal meeting PIN.</p>
     fn reset_personal_pin(
         &self,
         input: ResetPersonalPINRequest,
     ) -> RusotoFuture<ResetPersonalPINResponse, ResetPersonalPINError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users/{user_id}",
-            account_id = input.account_id,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("operation", "reset-personal-pin");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResetPersonalPINResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResetPersonalPINError::from_response(response))),
-                )
-            }
-        })
+        (**self).reset_personal_pin(input)
     }
-
-    /// <p>Moves a phone number from the <b>Deletion queue</b> back into the phone number <b>Inventory</b>.</p>
     fn restore_phone_number(
         &self,
         input: RestorePhoneNumberRequest,
     ) -> RusotoFuture<RestorePhoneNumberResponse, RestorePhoneNumberError> {
-        let request_uri = format!(
-            "/phone-numbers/{phone_number_id}",
-            phone_number_id = input.phone_number_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("operation", "restore");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestorePhoneNumberResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RestorePhoneNumberError::from_response(response))),
-                )
-            }
-        })
+        (**self).restore_phone_number(input)
     }
-
-    /// <p>Searches phone numbers that can be ordered.</p>
     fn search_available_phone_numbers(
         &self,
         input: SearchAvailablePhoneNumbersRequest,
     ) -> RusotoFuture<SearchAvailablePhoneNumbersResponse, SearchAvailablePhoneNumbersError> {
-        let request_uri = "/search";
-
-        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.area_code {
-            params.put("area-code", x);
-        }
-        if let Some(ref x) = input.city {
-            params.put("city", x);
-        }
-        if let Some(ref x) = input.country {
-            params.put("country", x);
-        }
-        if let Some(ref x) = input.max_results {
-            params.put("max-results", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("next-token", x);
-        }
-        if let Some(ref x) = input.state {
-            params.put("state", x);
-        }
-        if let Some(ref x) = input.toll_free_prefix {
-            params.put("toll-free-prefix", x);
-        }
-        params.put("type", "phone-numbers");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchAvailablePhoneNumbersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SearchAvailablePhoneNumbersError::from_response(response))
-                }))
-            }
-        })
+        (**self).search_available_phone_numbers(input)
+    }
+    fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> RusotoFuture<UpdateAccountResponse, UpdateAccountError> {
+        (**self).update_account(input)
+    }
+    fn update_account_settings(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> RusotoFuture<UpdateAccountSettingsResponse, UpdateAccountSettingsError> {
+        (**self).update_account_settings(input)
+    }
+    fn update_bot(
+        &self,
+        input: UpdateBotRequest,
+    ) -> RusotoFuture<UpdateBotResponse, UpdateBotError> {
+        (**self).update_bot(input)
+    }
+    fn update_global_settings(
+        &self,
+        input: UpdateGlobalSettingsRequest,
+    ) -> RusotoFuture<(), UpdateGlobalSettingsError> {
+        (**self).update_global_settings(input)
+    }
+    fn update_phone_number(
+        &self,
+        input: UpdatePhoneNumberRequest,
+    ) -> RusotoFuture<UpdatePhoneNumberResponse, UpdatePhoneNumberError> {
+        (**self).update_phone_number(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+    fn update_user_settings(
+        &self,
+        input: UpdateUserSettingsRequest,
+    ) -> RusotoFuture<(), UpdateUserSettingsError> {
+        (**self).update_user_settings(input)
+    }
+    fn update_voice_connector(
+        &self,
+        input: UpdateVoiceConnectorRequest,
+    ) -> RusotoFuture<UpdateVoiceConnectorResponse, UpdateVoiceConnectorError> {
+        (**self).update_voice_connector(input)
+    }
+}
+impl<T: Chime + ?Sized> Chime for Box<T> {
+    fn associate_phone_number_with_user(
+        &self,
+        input: AssociatePhoneNumberWithUserRequest,
+    ) -> RusotoFuture<AssociatePhoneNumberWithUserResponse, AssociatePhoneNumberWithUserError> {
+        (**self).associate_phone_number_with_user(input)
+    }
+    fn associate_phone_numbers_with_voice_connector(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorRequest,
+    ) -> RusotoFuture<
+        AssociatePhoneNumbersWithVoiceConnectorResponse,
+        AssociatePhoneNumbersWithVoiceConnectorError,
+    > {
+        (**self).associate_phone_numbers_with_voice_connector(input)
+    }
+    fn batch_delete_phone_number(
+        &self,
+        input: BatchDeletePhoneNumberRequest,
+    ) -> RusotoFuture<BatchDeletePhoneNumberResponse, BatchDeletePhoneNumberError> {
+        (**self).batch_delete_phone_number(input)
+    }
+    fn batch_suspend_user(
+        &self,
+        input: BatchSuspendUserRequest,
+    ) -> RusotoFuture<BatchSuspendUserResponse, BatchSuspendUserError> {
+        (**self).batch_suspend_user(input)
+    }
+    fn batch_unsuspend_user(
+        &self,
+        input: BatchUnsuspendUserRequest,
+    ) -> RusotoFuture<BatchUnsuspendUserResponse, BatchUnsuspendUserError> {
+        (**self).batch_unsuspend_user(input)
+    }
+    fn batch_update_phone_number(
+        &self,
+        input: BatchUpdatePhoneNumberRequest,
+    ) -> RusotoFuture<BatchUpdatePhoneNumberResponse, BatchUpdatePhoneNumberError> {
+        (**self).batch_update_phone_number(input)
+    }
+    fn batch_update_user(
+        &self,
+        input: BatchUpdateUserRequest,
+    ) -> RusotoFuture<BatchUpdateUserResponse, BatchUpdateUserError> {
+        (**self).batch_update_user(input)
+    }
+    fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
+        (**self).create_account(input)
+    }
+    fn create_bot(
+        &self,
+        input: CreateBotRequest,
+    ) -> RusotoFuture<CreateBotResponse, CreateBotError> {
+        (**self).create_bot(input)
+    }
+    fn create_phone_number_order(
+        &self,
+        input: CreatePhoneNumberOrderRequest,
+    ) -> RusotoFuture<CreatePhoneNumberOrderResponse, CreatePhoneNumberOrderError> {
+        (**self).create_phone_number_order(input)
+    }
+    fn create_voice_connector(
+        &self,
+        input: CreateVoiceConnectorRequest,
+    ) -> RusotoFuture<CreateVoiceConnectorResponse, CreateVoiceConnectorError> {
+        (**self).create_voice_connector(input)
+    }
+    fn delete_account(
+        &self,
+        input: DeleteAccountRequest,
+    ) -> RusotoFuture<DeleteAccountResponse, DeleteAccountError> {
+        (**self).delete_account(input)
+    }
+    fn delete_events_configuration(
+        &self,
+        input: DeleteEventsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteEventsConfigurationError> {
+        (**self).delete_events_configuration(input)
+    }
+    fn delete_phone_number(
+        &self,
+        input: DeletePhoneNumberRequest,
+    ) -> RusotoFuture<(), DeletePhoneNumberError> {
+        (**self).delete_phone_number(input)
+    }
+    fn delete_voice_connector(
+        &self,
+        input: DeleteVoiceConnectorRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorError> {
+        (**self).delete_voice_connector(input)
+    }
+    fn delete_voice_connector_origination(
+        &self,
+        input: DeleteVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorOriginationError> {
+        (**self).delete_voice_connector_origination(input)
+    }
+    fn delete_voice_connector_termination(
+        &self,
+        input: DeleteVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationError> {
+        (**self).delete_voice_connector_termination(input)
+    }
+    fn delete_voice_connector_termination_credentials(
+        &self,
+        input: DeleteVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationCredentialsError> {
+        (**self).delete_voice_connector_termination_credentials(input)
+    }
+    fn disassociate_phone_number_from_user(
+        &self,
+        input: DisassociatePhoneNumberFromUserRequest,
+    ) -> RusotoFuture<DisassociatePhoneNumberFromUserResponse, DisassociatePhoneNumberFromUserError>
+    {
+        (**self).disassociate_phone_number_from_user(input)
+    }
+    fn disassociate_phone_numbers_from_voice_connector(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
+    ) -> RusotoFuture<
+        DisassociatePhoneNumbersFromVoiceConnectorResponse,
+        DisassociatePhoneNumbersFromVoiceConnectorError,
+    > {
+        (**self).disassociate_phone_numbers_from_voice_connector(input)
+    }
+    fn get_account(
+        &self,
+        input: GetAccountRequest,
+    ) -> RusotoFuture<GetAccountResponse, GetAccountError> {
+        (**self).get_account(input)
+    }
+    fn get_account_settings(
+        &self,
+        input: GetAccountSettingsRequest,
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
+        (**self).get_account_settings(input)
+    }
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
+        (**self).get_bot(input)
+    }
+    fn get_events_configuration(
+        &self,
+        input: GetEventsConfigurationRequest,
+    ) -> RusotoFuture<GetEventsConfigurationResponse, GetEventsConfigurationError> {
+        (**self).get_events_configuration(input)
+    }
+    fn get_global_settings(
+        &self,
+    ) -> RusotoFuture<GetGlobalSettingsResponse, GetGlobalSettingsError> {
+        (**self).get_global_settings()
+    }
+    fn get_phone_number(
+        &self,
+        input: GetPhoneNumberRequest,
+    ) -> RusotoFuture<GetPhoneNumberResponse, GetPhoneNumberError> {
+        (**self).get_phone_number(input)
+    }
+    fn get_phone_number_order(
+        &self,
+        input: GetPhoneNumberOrderRequest,
+    ) -> RusotoFuture<GetPhoneNumberOrderResponse, GetPhoneNumberOrderError> {
+        (**self).get_phone_number_order(input)
+    }
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        (**self).get_user(input)
+    }
+    fn get_user_settings(
+        &self,
+        input: GetUserSettingsRequest,
+    ) -> RusotoFuture<GetUserSettingsResponse, GetUserSettingsError> {
+        (**self).get_user_settings(input)
+    }
+    fn get_voice_connector(
+        &self,
+        input: GetVoiceConnectorRequest,
+    ) -> RusotoFuture<GetVoiceConnectorResponse, GetVoiceConnectorError> {
+        (**self).get_voice_connector(input)
+    }
+    fn get_voice_connector_origination(
+        &self,
+        input: GetVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<GetVoiceConnectorOriginationResponse, GetVoiceConnectorOriginationError> {
+        (**self).get_voice_connector_origination(input)
+    }
+    fn get_voice_connector_termination(
+        &self,
+        input: GetVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<GetVoiceConnectorTerminationResponse, GetVoiceConnectorTerminationError> {
+        (**self).get_voice_connector_termination(input)
+    }
+    fn get_voice_connector_termination_health(
+        &self,
+        input: GetVoiceConnectorTerminationHealthRequest,
+    ) -> RusotoFuture<
+        GetVoiceConnectorTerminationHealthResponse,
+        GetVoiceConnectorTerminationHealthError,
+    > {
+        (**self).get_voice_connector_termination_health(input)
+    }
+    fn invite_users(
+        &self,
+        input: InviteUsersRequest,
+    ) -> RusotoFuture<InviteUsersResponse, InviteUsersError> {
+        (**self).invite_users(input)
+    }
+    fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
+        (**self).list_accounts(input)
+    }
+    fn list_bots(&self, input: ListBotsRequest) -> RusotoFuture<ListBotsResponse, ListBotsError> {
+        (**self).list_bots(input)
+    }
+    fn list_phone_number_orders(
+        &self,
+        input: ListPhoneNumberOrdersRequest,
+    ) -> RusotoFuture<ListPhoneNumberOrdersResponse, ListPhoneNumberOrdersError> {
+        (**self).list_phone_number_orders(input)
+    }
+    fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> RusotoFuture<ListPhoneNumbersResponse, ListPhoneNumbersError> {
+        (**self).list_phone_numbers(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn list_voice_connector_termination_credentials(
+        &self,
+        input: ListVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<
+        ListVoiceConnectorTerminationCredentialsResponse,
+        ListVoiceConnectorTerminationCredentialsError,
+    > {
+        (**self).list_voice_connector_termination_credentials(input)
+    }
+    fn list_voice_connectors(
+        &self,
+        input: ListVoiceConnectorsRequest,
+    ) -> RusotoFuture<ListVoiceConnectorsResponse, ListVoiceConnectorsError> {
+        (**self).list_voice_connectors(input)
+    }
+    fn logout_user(
+        &self,
+        input: LogoutUserRequest,
+    ) -> RusotoFuture<LogoutUserResponse, LogoutUserError> {
+        (**self).logout_user(input)
+    }
+    fn put_events_configuration(
+        &self,
+        input: PutEventsConfigurationRequest,
+    ) -> RusotoFuture<PutEventsConfigurationResponse, PutEventsConfigurationError> {
+        (**self).put_events_configuration(input)
+    }
+    fn put_voice_connector_origination(
+        &self,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<PutVoiceConnectorOriginationResponse, PutVoiceConnectorOriginationError> {
+        (**self).put_voice_connector_origination(input)
+    }
+    fn put_voice_connector_termination(
+        &self,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<PutVoiceConnectorTerminationResponse, PutVoiceConnectorTerminationError> {
+        (**self).put_voice_connector_termination(input)
+    }
+    fn put_voice_connector_termination_credentials(
+        &self,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<(), PutVoiceConnectorTerminationCredentialsError> {
+        (**self).put_voice_connector_termination_credentials(input)
+    }
+    fn regenerate_security_token(
+        &self,
+        input: RegenerateSecurityTokenRequest,
+    ) -> RusotoFuture<RegenerateSecurityTokenResponse, RegenerateSecurityTokenError> {
+        (**self).regenerate_security_token(input)
+    }
+    fn reset_personal_pin(
+        &self,
+        input: ResetPersonalPINRequest,
+    ) -> RusotoFuture<ResetPersonalPINResponse, ResetPersonalPINError> {
+        (**self).reset_personal_pin(input)
+    }
+    fn restore_phone_number(
+        &self,
+        input: RestorePhoneNumberRequest,
+    ) -> RusotoFuture<RestorePhoneNumberResponse, RestorePhoneNumberError> {
+        (**self).restore_phone_number(input)
+    }
+    fn search_available_phone_numbers(
+        &self,
+        input: SearchAvailablePhoneNumbersRequest,
+    ) -> RusotoFuture<SearchAvailablePhoneNumbersResponse, SearchAvailablePhoneNumbersError> {
+        (**self).search_available_phone_numbers(input)
+    }
+    fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> RusotoFuture<UpdateAccountResponse, UpdateAccountError> {
+        (**self).update_account(input)
+    }
+    fn update_account_settings(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> RusotoFuture<UpdateAccountSettingsResponse, UpdateAccountSettingsError> {
+        (**self).update_account_settings(input)
+    }
+    fn update_bot(
+        &self,
+        input: UpdateBotRequest,
+    ) -> RusotoFuture<UpdateBotResponse, UpdateBotError> {
+        (**self).update_bot(input)
+    }
+    fn update_global_settings(
+        &self,
+        input: UpdateGlobalSettingsRequest,
+    ) -> RusotoFuture<(), UpdateGlobalSettingsError> {
+        (**self).update_global_settings(input)
+    }
+    fn update_phone_number(
+        &self,
+        input: UpdatePhoneNumberRequest,
+    ) -> RusotoFuture<UpdatePhoneNumberResponse, UpdatePhoneNumberError> {
+        (**self).update_phone_number(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+    fn update_user_settings(
+        &self,
+        input: UpdateUserSettingsRequest,
+    ) -> RusotoFuture<(), UpdateUserSettingsError> {
+        (**self).update_user_settings(input)
+    }
+    fn update_voice_connector(
+        &self,
+        input: UpdateVoiceConnectorRequest,
+    ) -> RusotoFuture<UpdateVoiceConnectorResponse, UpdateVoiceConnectorError> {
+        (**self).update_voice_connector(input)
+    }
+}
+impl<T: Chime + ?Sized> Chime for ::std::sync::Arc<T> {
+    fn associate_phone_number_with_user(
+        &self,
+        input: AssociatePhoneNumberWithUserRequest,
+    ) -> RusotoFuture<AssociatePhoneNumberWithUserResponse, AssociatePhoneNumberWithUserError> {
+        (**self).associate_phone_number_with_user(input)
+    }
+    fn associate_phone_numbers_with_voice_connector(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorRequest,
+    ) -> RusotoFuture<
+        AssociatePhoneNumbersWithVoiceConnectorResponse,
+        AssociatePhoneNumbersWithVoiceConnectorError,
+    > {
+        (**self).associate_phone_numbers_with_voice_connector(input)
+    }
+    fn batch_delete_phone_number(
+        &self,
+        input: BatchDeletePhoneNumberRequest,
+    ) -> RusotoFuture<BatchDeletePhoneNumberResponse, BatchDeletePhoneNumberError> {
+        (**self).batch_delete_phone_number(input)
+    }
+    fn batch_suspend_user(
+        &self,
+        input: BatchSuspendUserRequest,
+    ) -> RusotoFuture<BatchSuspendUserResponse, BatchSuspendUserError> {
+        (**self).batch_suspend_user(input)
+    }
+    fn batch_unsuspend_user(
+        &self,
+        input: BatchUnsuspendUserRequest,
+    ) -> RusotoFuture<BatchUnsuspendUserResponse, BatchUnsuspendUserError> {
+        (**self).batch_unsuspend_user(input)
+    }
+    fn batch_update_phone_number(
+        &self,
+        input: BatchUpdatePhoneNumberRequest,
+    ) -> RusotoFuture<BatchUpdatePhoneNumberResponse, BatchUpdatePhoneNumberError> {
+        (**self).batch_update_phone_number(input)
+    }
+    fn batch_update_user(
+        &self,
+        input: BatchUpdateUserRequest,
+    ) -> RusotoFuture<BatchUpdateUserResponse, BatchUpdateUserError> {
+        (**self).batch_update_user(input)
+    }
+    fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
+        (**self).create_account(input)
+    }
+    fn create_bot(
+        &self,
+        input: CreateBotRequest,
+    ) -> RusotoFuture<CreateBotResponse, CreateBotError> {
+        (**self).create_bot(input)
+    }
+    fn create_phone_number_order(
+        &self,
+        input: CreatePhoneNumberOrderRequest,
+    ) -> RusotoFuture<CreatePhoneNumberOrderResponse, CreatePhoneNumberOrderError> {
+        (**self).create_phone_number_order(input)
+    }
+    fn create_voice_connector(
+        &self,
+        input: CreateVoiceConnectorRequest,
+    ) -> RusotoFuture<CreateVoiceConnectorResponse, CreateVoiceConnectorError> {
+        (**self).create_voice_connector(input)
+    }
+    fn delete_account(
+        &self,
+        input: DeleteAccountRequest,
+    ) -> RusotoFuture<DeleteAccountResponse, DeleteAccountError> {
+        (**self).delete_account(input)
+    }
+    fn delete_events_configuration(
+        &self,
+        input: DeleteEventsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteEventsConfigurationError> {
+        (**self).delete_events_configuration(input)
+    }
+    fn delete_phone_number(
+        &self,
+        input: DeletePhoneNumberRequest,
+    ) -> RusotoFuture<(), DeletePhoneNumberError> {
+        (**self).delete_phone_number(input)
+    }
+    fn delete_voice_connector(
+        &self,
+        input: DeleteVoiceConnectorRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorError> {
+        (**self).delete_voice_connector(input)
+    }
+    fn delete_voice_connector_origination(
+        &self,
+        input: DeleteVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorOriginationError> {
+        (**self).delete_voice_connector_origination(input)
+    }
+    fn delete_voice_connector_termination(
+        &self,
+        input: DeleteVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationError> {
+        (**self).delete_voice_connector_termination(input)
+    }
+    fn delete_voice_connector_termination_credentials(
+        &self,
+        input: DeleteVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationCredentialsError> {
+        (**self).delete_voice_connector_termination_credentials(input)
+    }
+    fn disassociate_phone_number_from_user(
+        &self,
+        input: DisassociatePhoneNumberFromUserRequest,
+    ) -> RusotoFuture<DisassociatePhoneNumberFromUserResponse, DisassociatePhoneNumberFromUserError>
+    {
+        (**self).disassociate_phone_number_from_user(input)
+    }
+    fn disassociate_phone_numbers_from_voice_connector(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
+    ) -> RusotoFuture<
+        DisassociatePhoneNumbersFromVoiceConnectorResponse,
+        DisassociatePhoneNumbersFromVoiceConnectorError,
+    > {
+        (**self).disassociate_phone_numbers_from_voice_connector(input)
+    }
+    fn get_account(
+        &self,
+        input: GetAccountRequest,
+    ) -> RusotoFuture<GetAccountResponse, GetAccountError> {
+        (**self).get_account(input)
+    }
+    fn get_account_settings(
+        &self,
+        input: GetAccountSettingsRequest,
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
+        (**self).get_account_settings(input)
+    }
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
+        (**self).get_bot(input)
+    }
+    fn get_events_configuration(
+        &self,
+        input: GetEventsConfigurationRequest,
+    ) -> RusotoFuture<GetEventsConfigurationResponse, GetEventsConfigurationError> {
+        (**self).get_events_configuration(input)
+    }
+    fn get_global_settings(
+        &self,
+    ) -> RusotoFuture<GetGlobalSettingsResponse, GetGlobalSettingsError> {
+        (**self).get_global_settings()
+    }
+    fn get_phone_number(
+        &self,
+        input: GetPhoneNumberRequest,
+    ) -> RusotoFuture<GetPhoneNumberResponse, GetPhoneNumberError> {
+        (**self).get_phone_number(input)
+    }
+    fn get_phone_number_order(
+        &self,
+        input: GetPhoneNumberOrderRequest,
+    ) -> RusotoFuture<GetPhoneNumberOrderResponse, GetPhoneNumberOrderError> {
+        (**self).get_phone_number_order(input)
+    }
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        (**self).get_user(input)
+    }
+    fn get_user_settings(
+        &self,
+        input: GetUserSettingsRequest,
+    ) -> RusotoFuture<GetUserSettingsResponse, GetUserSettingsError> {
+        (**self).get_user_settings(input)
+    }
+    fn get_voice_connector(
+        &self,
+        input: GetVoiceConnectorRequest,
+    ) -> RusotoFuture<GetVoiceConnectorResponse, GetVoiceConnectorError> {
+        (**self).get_voice_connector(input)
+    }
+    fn get_voice_connector_origination(
+        &self,
+        input: GetVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<GetVoiceConnectorOriginationResponse, GetVoiceConnectorOriginationError> {
+        (**self).get_voice_connector_origination(input)
+    }
+    fn get_voice_connector_termination(
+        &self,
+        input: GetVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<GetVoiceConnectorTerminationResponse, GetVoiceConnectorTerminationError> {
+        (**self).get_voice_connector_termination(input)
+    }
+    fn get_voice_connector_termination_health(
+        &self,
+        input: GetVoiceConnectorTerminationHealthRequest,
+    ) -> RusotoFuture<
+        GetVoiceConnectorTerminationHealthResponse,
+        GetVoiceConnectorTerminationHealthError,
+    > {
+        (**self).get_voice_connector_termination_health(input)
+    }
+    fn invite_users(
+        &self,
+        input: InviteUsersRequest,
+    ) -> RusotoFuture<InviteUsersResponse, InviteUsersError> {
+        (**self).invite_users(input)
+    }
+    fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
+        (**self).list_accounts(input)
+    }
+    fn list_bots(&self, input: ListBotsRequest) -> RusotoFuture<ListBotsResponse, ListBotsError> {
+        (**self).list_bots(input)
+    }
+    fn list_phone_number_orders(
+        &self,
+        input: ListPhoneNumberOrdersRequest,
+    ) -> RusotoFuture<ListPhoneNumberOrdersResponse, ListPhoneNumberOrdersError> {
+        (**self).list_phone_number_orders(input)
+    }
+    fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> RusotoFuture<ListPhoneNumbersResponse, ListPhoneNumbersError> {
+        (**self).list_phone_numbers(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn list_voice_connector_termination_credentials(
+        &self,
+        input: ListVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<
+        ListVoiceConnectorTerminationCredentialsResponse,
+        ListVoiceConnectorTerminationCredentialsError,
+    > {
+        (**self).list_voice_connector_termination_credentials(input)
+    }
+    fn list_voice_connectors(
+        &self,
+        input: ListVoiceConnectorsRequest,
+    ) -> RusotoFuture<ListVoiceConnectorsResponse, ListVoiceConnectorsError> {
+        (**self).list_voice_connectors(input)
+    }
+    fn logout_user(
+        &self,
+        input: LogoutUserRequest,
+    ) -> RusotoFuture<LogoutUserResponse, LogoutUserError> {
+        (**self).logout_user(input)
+    }
+    fn put_events_configuration(
+        &self,
+        input: PutEventsConfigurationRequest,
+    ) -> RusotoFuture<PutEventsConfigurationResponse, PutEventsConfigurationError> {
+        (**self).put_events_configuration(input)
+    }
+    fn put_voice_connector_origination(
+        &self,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<PutVoiceConnectorOriginationResponse, PutVoiceConnectorOriginationError> {
+        (**self).put_voice_connector_origination(input)
+    }
+    fn put_voice_connector_termination(
+        &self,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<PutVoiceConnectorTerminationResponse, PutVoiceConnectorTerminationError> {
+        (**self).put_voice_connector_termination(input)
+    }
+    fn put_voice_connector_termination_credentials(
+        &self,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<(), PutVoiceConnectorTerminationCredentialsError> {
+        (**self).put_voice_connector_termination_credentials(input)
+    }
+    fn regenerate_security_token(
+        &self,
+        input: RegenerateSecurityTokenRequest,
+    ) -> RusotoFuture<RegenerateSecurityTokenResponse, RegenerateSecurityTokenError> {
+        (**self).regenerate_security_token(input)
+    }
+    fn reset_personal_pin(
+        &self,
+        input: ResetPersonalPINRequest,
+    ) -> RusotoFuture<ResetPersonalPINResponse, ResetPersonalPINError> {
+        (**self).reset_personal_pin(input)
+    }
+    fn restore_phone_number(
+        &self,
+        input: RestorePhoneNumberRequest,
+    ) -> RusotoFuture<RestorePhoneNumberResponse, RestorePhoneNumberError> {
+        (**self).restore_phone_number(input)
+    }
+    fn search_available_phone_numbers(
+        &self,
+        input: SearchAvailablePhoneNumbersRequest,
+    ) -> RusotoFuture<SearchAvailablePhoneNumbersResponse, SearchAvailablePhoneNumbersError> {
+        (**self).search_available_phone_numbers(input)
+    }
+    fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> RusotoFuture<UpdateAccountResponse, UpdateAccountError> {
+        (**self).update_account(input)
+    }
+    fn update_account_settings(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> RusotoFuture<UpdateAccountSettingsResponse, UpdateAccountSettingsError> {
+        (**self).update_account_settings(input)
+    }
+    fn update_bot(
+        &self,
+        input: UpdateBotRequest,
+    ) -> RusotoFuture<UpdateBotResponse, UpdateBotError> {
+        (**self).update_bot(input)
+    }
+    fn update_global_settings(
+        &self,
+        input: UpdateGlobalSettingsRequest,
+    ) -> RusotoFuture<(), UpdateGlobalSettingsError> {
+        (**self).update_global_settings(input)
+    }
+    fn update_phone_number(
+        &self,
+        input: UpdatePhoneNumberRequest,
+    ) -> RusotoFuture<UpdatePhoneNumberResponse, UpdatePhoneNumberError> {
+        (**self).update_phone_number(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+    fn update_user_settings(
+        &self,
+        input: UpdateUserSettingsRequest,
+    ) -> RusotoFuture<(), UpdateUserSettingsError> {
+        (**self).update_user_settings(input)
+    }
+    fn update_voice_connector(
+        &self,
+        input: UpdateVoiceConnectorRequest,
+    ) -> RusotoFuture<UpdateVoiceConnectorResponse, UpdateVoiceConnectorError> {
+        (**self).update_voice_connector(input)
+    }
+}
+/// A client for the Amazon Chime API.
+#[derive(Clone)]
+pub struct ChimeClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ChimeClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ChimeClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ChimeClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ChimeClient {
+        ChimeClient { client, region }
+    }
+}
+
+impl Chime for ChimeClient {
+    /// <p>Associates a phone number with the specified Amazon Chime user.</p>
+    fn associate_phone_number_with_user(
+        &self,
+        input: AssociatePhoneNumberWithUserRequest,
+    ) -> RusotoFuture<AssociatePhoneNumberWithUserResponse, AssociatePhoneNumberWithUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/users/{user_id}",
+            account_id = input.account_id,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumberWithUser");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "associate-phone-number");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AssociatePhoneNumberWithUser")
+                        .deserialize::<AssociatePhoneNumberWithUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociatePhoneNumberWithUserError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates a phone number with the specified Amazon Chime Voice Connector.</p>
+    fn associate_phone_numbers_with_voice_connector(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorRequest,
+    ) -> RusotoFuture<
+        AssociatePhoneNumbersWithVoiceConnectorResponse,
+        AssociatePhoneNumbersWithVoiceConnectorError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumbersWithVoiceConnector");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "associate-phone-numbers");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AssociatePhoneNumbersWithVoiceConnector")
+                        .deserialize::<AssociatePhoneNumbersWithVoiceConnectorResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociatePhoneNumbersWithVoiceConnectorError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Moves phone numbers into the <b>Deletion queue</b>. Phone numbers must be disassociated from any users or Amazon Chime Voice Connectors before they can be deleted.</p> <p>Phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
+    fn batch_delete_phone_number(
+        &self,
+        input: BatchDeletePhoneNumberRequest,
+    ) -> RusotoFuture<BatchDeletePhoneNumberResponse, BatchDeletePhoneNumberError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/phone-numbers";
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchDeletePhoneNumber");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "batch-delete");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchDeletePhoneNumber")
+                        .deserialize::<BatchDeletePhoneNumberResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchDeletePhoneNumberError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Suspends up to 50 users from a <code>Team</code> or <code>EnterpriseLWA</code> Amazon Chime account. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Users suspended from a <code>Team</code> account are dissasociated from the account, but they can continue to use Amazon Chime as free users. To remove the suspension from suspended <code>Team</code> account users, invite them to the <code>Team</code> account again. You can use the <a>InviteUsers</a> action to do so.</p> <p>Users suspended from an <code>EnterpriseLWA</code> account are immediately signed out of Amazon Chime and can no longer sign in. To remove the suspension from suspended <code>EnterpriseLWA</code> account users, use the <a>BatchUnsuspendUser</a> action. </p> <p>To sign out users without suspending them, use the <a>LogoutUser</a> action.</p>
+    fn batch_suspend_user(
+        &self,
+        input: BatchSuspendUserRequest,
+    ) -> RusotoFuture<BatchSuspendUserResponse, BatchSuspendUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/users",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchSuspendUser");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "suspend");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchSuspendUser")
+                        .deserialize::<BatchSuspendUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchSuspendUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the suspension from up to 50 previously suspended users for the specified Amazon Chime <code>EnterpriseLWA</code> account. Only users on <code>EnterpriseLWA</code> accounts can be unsuspended using this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Previously suspended users who are unsuspended using this action are returned to <code>Registered</code> status. Users who are not previously suspended are ignored.</p>
+    fn batch_unsuspend_user(
+        &self,
+        input: BatchUnsuspendUserRequest,
+    ) -> RusotoFuture<BatchUnsuspendUserResponse, BatchUnsuspendUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/users",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUnsuspendUser");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "unsuspend");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchUnsuspendUser")
+                        .deserialize::<BatchUnsuspendUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchUnsuspendUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates phone number product types. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
+    fn batch_update_phone_number(
+        &self,
+        input: BatchUpdatePhoneNumberRequest,
+    ) -> RusotoFuture<BatchUpdatePhoneNumberResponse, BatchUpdatePhoneNumberError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/phone-numbers";
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUpdatePhoneNumber");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "batch-update");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchUpdatePhoneNumber")
+                        .deserialize::<BatchUpdatePhoneNumberResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchUpdatePhoneNumberError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates user details within the <a>UpdateUserRequestItem</a> object for up to 20 users for the specified Amazon Chime account. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
+    fn batch_update_user(
+        &self,
+        input: BatchUpdateUserRequest,
+    ) -> RusotoFuture<BatchUpdateUserResponse, BatchUpdateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/users",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUpdateUser");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchUpdateUser")
+                        .deserialize::<BatchUpdateUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchUpdateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an Amazon Chime account under the administrator's AWS account. Only <code>Team</code> account types are currently supported for this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/accounts";
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAccount");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateAccount")
+                        .deserialize::<CreateAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a bot for an Amazon Chime Enterprise account.</p>
+    fn create_bot(
+        &self,
+        input: CreateBotRequest,
+    ) -> RusotoFuture<CreateBotResponse, CreateBotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateBot");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateBot")
+                        .deserialize::<CreateBotResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an order for phone numbers to be provisioned. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
+    fn create_phone_number_order(
+        &self,
+        input: CreatePhoneNumberOrderRequest,
+    ) -> RusotoFuture<CreatePhoneNumberOrderResponse, CreatePhoneNumberOrderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/phone-number-orders";
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreatePhoneNumberOrder");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreatePhoneNumberOrder")
+                        .deserialize::<CreatePhoneNumberOrderResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreatePhoneNumberOrderError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an Amazon Chime Voice Connector under the administrator's AWS account. Enabling <a>CreateVoiceConnectorRequest$RequireEncryption</a> configures your Amazon Chime Voice Connector to use TLS transport for SIP signaling and Secure RTP (SRTP) for media. Inbound calls use TLS transport, and unencrypted outbound calls are blocked.</p>
+    fn create_voice_connector(
+        &self,
+        input: CreateVoiceConnectorRequest,
+    ) -> RusotoFuture<CreateVoiceConnectorResponse, CreateVoiceConnectorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/voice-connectors";
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateVoiceConnector");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateVoiceConnector")
+                        .deserialize::<CreateVoiceConnectorResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateVoiceConnectorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified Amazon Chime account. You must suspend all users before deleting a <code>Team</code> account. You can use the <a>BatchSuspendUser</a> action to do so.</p> <p>For <code>EnterpriseLWA</code> and <code>EnterpriseAD</code> accounts, you must release the claimed domains for your Amazon Chime account before deletion. As soon as you release the domain, all users under that account are suspended.</p> <p>Deleted accounts appear in your <code>Disabled</code> accounts list for 90 days. To restore a deleted account from your <code>Disabled</code> accounts list, you must contact AWS Support.</p> <p>After 90 days, deleted accounts are permanently removed from your <code>Disabled</code> accounts list.</p>
+    fn delete_account(
+        &self,
+        input: DeleteAccountRequest,
+    ) -> RusotoFuture<DeleteAccountResponse, DeleteAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
+
+        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAccount");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteAccount")
+                        .deserialize::<DeleteAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the events configuration that allows a bot to receive outgoing events.</p>
+    fn delete_events_configuration(
+        &self,
+        input: DeleteEventsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteEventsConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/bots/{bot_id}/events-configuration",
+            account_id = input.account_id,
+            bot_id = input.bot_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteEventsConfiguration");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("BotId", &input.bot_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteEventsConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Moves the specified phone number into the <b>Deletion queue</b>. A phone number must be disassociated from any users or Amazon Chime Voice Connectors before it can be deleted.</p> <p>Deleted phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
+    fn delete_phone_number(
+        &self,
+        input: DeletePhoneNumberRequest,
+    ) -> RusotoFuture<(), DeletePhoneNumberError> {
+        let request_uri = format!(
+            "/phone-numbers/{phone_number_id}",
+            phone_number_id = input.phone_number_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeletePhoneNumber");
+        request.add_resource_id("PhoneNumberId", &input.phone_number_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeletePhoneNumberError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified Amazon Chime Voice Connector. Any phone numbers assigned to the Amazon Chime Voice Connector must be unassigned from it before it can be deleted.</p>
+    fn delete_voice_connector(
+        &self,
+        input: DeleteVoiceConnectorRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnector");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteVoiceConnectorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the origination settings for the specified Amazon Chime Voice Connector.</p>
+    fn delete_voice_connector_origination(
+        &self,
+        input: DeleteVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorOriginationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}/origination",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorOrigination");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteVoiceConnectorOriginationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the termination settings for the specified Amazon Chime Voice Connector.</p>
+    fn delete_voice_connector_termination(
+        &self,
+        input: DeleteVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}/termination",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorTermination");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteVoiceConnectorTerminationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified SIP credentials used by your equipment to authenticate during call termination.</p>
+    fn delete_voice_connector_termination_credentials(
+        &self,
+        input: DeleteVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationCredentialsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}/termination/credentials",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorTerminationCredentials");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "delete");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteVoiceConnectorTerminationCredentialsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the primary provisioned phone number from the specified Amazon Chime user.</p>
+    fn disassociate_phone_number_from_user(
+        &self,
+        input: DisassociatePhoneNumberFromUserRequest,
+    ) -> RusotoFuture<DisassociatePhoneNumberFromUserResponse, DisassociatePhoneNumberFromUserError>
+    {
+        let request_uri = format!(
+            "/accounts/{account_id}/users/{user_id}",
+            account_id = input.account_id,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumberFromUser");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("operation", "disassociate-phone-number");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociatePhoneNumberFromUser")
+                        .deserialize::<DisassociatePhoneNumberFromUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociatePhoneNumberFromUserError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified phone number from the specified Amazon Chime Voice Connector.</p>
+    fn disassociate_phone_numbers_from_voice_connector(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
+    ) -> RusotoFuture<
+        DisassociatePhoneNumbersFromVoiceConnectorResponse,
+        DisassociatePhoneNumbersFromVoiceConnectorError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumbersFromVoiceConnector");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "disassociate-phone-numbers");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociatePhoneNumbersFromVoiceConnector")
+                        .deserialize::<DisassociatePhoneNumbersFromVoiceConnectorResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociatePhoneNumbersFromVoiceConnectorError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves details for the specified Amazon Chime account, such as account type and supported licenses.</p>
+    fn get_account(
+        &self,
+        input: GetAccountRequest,
+    ) -> RusotoFuture<GetAccountResponse, GetAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAccount");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAccount")
+                        .deserialize::<GetAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves account settings for the specified Amazon Chime account ID, such as remote control and dial out settings. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    fn get_account_settings(
+        &self,
+        input: GetAccountSettingsRequest,
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/settings",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAccountSettings");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAccountSettings")
+                        .deserialize::<GetAccountSettingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAccountSettingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves details for the specified bot, such as bot email address, bot type, status, and display name.</p>
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/bots/{bot_id}",
+            account_id = input.account_id,
+            bot_id = input.bot_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetBot");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("BotId", &input.bot_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBot")
+                        .deserialize::<GetBotResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets details for an events configuration that allows a bot to receive outgoing events, such as an HTTPS endpoint or Lambda function ARN. </p>
+    fn get_events_configuration(
+        &self,
+        input: GetEventsConfigurationRequest,
+    ) -> RusotoFuture<GetEventsConfigurationResponse, GetEventsConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/bots/{bot_id}/events-configuration",
+            account_id = input.account_id,
+            bot_id = input.bot_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetEventsConfiguration");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("BotId", &input.bot_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetEventsConfiguration")
+                        .deserialize::<GetEventsConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetEventsConfigurationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
+    fn get_global_settings(
+        &self,
+    ) -> RusotoFuture<GetGlobalSettingsResponse, GetGlobalSettingsError> {
+        let request_uri = "/settings";
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetGlobalSettings");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGlobalSettings")
+                        .deserialize::<GetGlobalSettingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetGlobalSettingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves details for the specified phone number ID, such as associations, capabilities, and product type.</p>
+    fn get_phone_number(
+        &self,
+        input: GetPhoneNumberRequest,
+    ) -> RusotoFuture<GetPhoneNumberResponse, GetPhoneNumberError> {
+        let request_uri = format!(
+            "/phone-numbers/{phone_number_id}",
+            phone_number_id = input.phone_number_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumber");
+        request.add_resource_id("PhoneNumberId", &input.phone_number_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetPhoneNumber")
+                        .deserialize::<GetPhoneNumberResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPhoneNumberError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves details for the specified phone number order, such as order creation timestamp, phone numbers in E.164 format, product type, and order status.</p>
+    fn get_phone_number_order(
+        &self,
+        input: GetPhoneNumberOrderRequest,
+    ) -> RusotoFuture<GetPhoneNumberOrderResponse, GetPhoneNumberOrderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/phone-number-orders/{phone_number_order_id}",
+            phone_number_order_id = input.phone_number_order_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumberOrder");
+        request.add_resource_id(
+            "PhoneNumberOrderId",
+            &input.phone_number_order_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetPhoneNumberOrder")
+                        .deserialize::<GetPhoneNumberOrderResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetPhoneNumberOrderError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves details for the specified user ID, such as primary email address, license type, and personal meeting PIN.</p> <p>To retrieve user details with an email address instead of a user ID, use the <a>ListUsers</a> action, and then filter by email address.</p>
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/users/{user_id}",
+            account_id = input.account_id,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetUser");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetUser")
+                        .deserialize::<GetUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves settings for the specified user ID, such as any associated phone number settings.</p>
+    fn get_user_settings(
+        &self,
+        input: GetUserSettingsRequest,
+    ) -> RusotoFuture<GetUserSettingsResponse, GetUserSettingsError> {
+        let request_uri = format!(
+            "/accounts/{account_id}/users/{user_id}/settings",
+            account_id = input.account_id,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetUserSettings");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetUserSettings")
+                        .deserialize::<GetUserSettingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUserSettingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves details for the specified Amazon Chime Voice Connector, such as timestamps, name, outbound host, and encryption requirements.</p>
+    fn get_voice_connector(
+        &self,
+        input: GetVoiceConnectorRequest,
+    ) -> RusotoFuture<GetVoiceConnectorResponse, GetVoiceConnectorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnector");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetVoiceConnector")
+                        .deserialize::<GetVoiceConnectorResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetVoiceConnectorError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves origination setting details for the specified Amazon Chime Voice Connector.</p>
+    fn get_voice_connector_origination(
+        &self,
+        input: GetVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<GetVoiceConnectorOriginationResponse, GetVoiceConnectorOriginationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}/origination",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorOrigination");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetVoiceConnectorOrigination")
+                        .deserialize::<GetVoiceConnectorOriginationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetVoiceConnectorOriginationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves termination setting details for the specified Amazon Chime Voice Connector.</p>
+    fn get_voice_connector_termination(
+        &self,
+        input: GetVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<GetVoiceConnectorTerminationResponse, GetVoiceConnectorTerminationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}/termination",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorTermination");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetVoiceConnectorTermination")
+                        .deserialize::<GetVoiceConnectorTerminationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetVoiceConnectorTerminationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about the last time a SIP <code>OPTIONS</code> ping was received from your SIP infrastructure for the specified Amazon Chime Voice Connector.</p>
+    fn get_voice_connector_termination_health(
+        &self,
+        input: GetVoiceConnectorTerminationHealthRequest,
+    ) -> RusotoFuture<
+        GetVoiceConnectorTerminationHealthResponse,
+        GetVoiceConnectorTerminationHealthError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}/termination/health",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorTerminationHealth");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetVoiceConnectorTerminationHealth")
+                        .deserialize::<GetVoiceConnectorTerminationHealthResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetVoiceConnectorTerminationHealthError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Sends email invites to as many as 50 users, inviting them to the specified Amazon Chime <code>Team</code> account. Only <code>Team</code> account types are currently supported for this action. </p>
+    fn invite_users(
+        &self,
+        input: InviteUsersRequest,
+    ) -> RusotoFuture<InviteUsersResponse, InviteUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/users",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("InviteUsers");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "add");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("InviteUsers")
+                        .deserialize::<InviteUsersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(InviteUsersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the Amazon Chime accounts under the administrator's AWS account. You can filter accounts by account name prefix. To find out which Amazon Chime account a user belongs to, you can filter by the user's email address, which returns one account result.</p>
+    fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/accounts";
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAccounts");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("max-results", x);
+        }
+        if let Some(ref x) = input.name {
+            params.put("name", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("next-token", x);
+        }
+        if let Some(ref x) = input.user_email {
+            params.put("user-email", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListAccounts")
+                        .deserialize::<ListAccountsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAccountsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the bots associated with the administrator's Amazon Chime Enterprise account ID.</p>
+    fn list_bots(&self, input: ListBotsRequest) -> RusotoFuture<ListBotsResponse, ListBotsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListBots");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("max-results", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("next-token", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListBots")
+                        .deserialize::<ListBotsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListBotsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the phone number orders for the administrator's Amazon Chime account.</p>
+    fn list_phone_number_orders(
+        &self,
+        input: ListPhoneNumberOrdersRequest,
+    ) -> RusotoFuture<ListPhoneNumberOrdersResponse, ListPhoneNumberOrdersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/phone-number-orders";
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumberOrders");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("max-results", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("next-token", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListPhoneNumberOrders")
+                        .deserialize::<ListPhoneNumberOrdersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListPhoneNumberOrdersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the phone numbers for the specified Amazon Chime account, Amazon Chime user, or Amazon Chime Voice Connector.</p>
+    fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> RusotoFuture<ListPhoneNumbersResponse, ListPhoneNumbersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/phone-numbers";
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumbers");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.filter_name {
+            params.put("filter-name", x);
+        }
+        if let Some(ref x) = input.filter_value {
+            params.put("filter-value", x);
+        }
+        if let Some(ref x) = input.max_results {
+            params.put("max-results", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("next-token", x);
+        }
+        if let Some(ref x) = input.product_type {
+            params.put("product-type", x);
+        }
+        if let Some(ref x) = input.status {
+            params.put("status", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListPhoneNumbers")
+                        .deserialize::<ListPhoneNumbersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListPhoneNumbersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the users that belong to the specified Amazon Chime account. You can specify an email address to list only the user that the email address belongs to.</p>
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/users",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListUsers");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("max-results", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("next-token", x);
+        }
+        if let Some(ref x) = input.user_email {
+            params.put("user-email", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListUsers")
+                        .deserialize::<ListUsersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListUsersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the SIP credentials for the specified Amazon Chime Voice Connector.</p>
+    fn list_voice_connector_termination_credentials(
+        &self,
+        input: ListVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<
+        ListVoiceConnectorTerminationCredentialsResponse,
+        ListVoiceConnectorTerminationCredentialsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}/termination/credentials",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectorTerminationCredentials");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListVoiceConnectorTerminationCredentials")
+                        .deserialize::<ListVoiceConnectorTerminationCredentialsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListVoiceConnectorTerminationCredentialsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the Amazon Chime Voice Connectors for the administrator's AWS account.</p>
+    fn list_voice_connectors(
+        &self,
+        input: ListVoiceConnectorsRequest,
+    ) -> RusotoFuture<ListVoiceConnectorsResponse, ListVoiceConnectorsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/voice-connectors";
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectors");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("max-results", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("next-token", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListVoiceConnectors")
+                        .deserialize::<ListVoiceConnectorsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListVoiceConnectorsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Logs out the specified user from all of the devices they are currently logged into.</p>
+    fn logout_user(
+        &self,
+        input: LogoutUserRequest,
+    ) -> RusotoFuture<LogoutUserResponse, LogoutUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/users/{user_id}",
+            account_id = input.account_id,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("LogoutUser");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("operation", "logout");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("LogoutUser")
+                        .deserialize::<LogoutUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(LogoutUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an events configuration that allows a bot to receive outgoing events sent by Amazon Chime. Choose either an HTTPS endpoint or a Lambda function ARN. For more information, see <a>Bot</a>.</p>
+    fn put_events_configuration(
+        &self,
+        input: PutEventsConfigurationRequest,
+    ) -> RusotoFuture<PutEventsConfigurationResponse, PutEventsConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/bots/{bot_id}/events-configuration",
+            account_id = input.account_id,
+            bot_id = input.bot_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutEventsConfiguration");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("BotId", &input.bot_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutEventsConfiguration")
+                        .deserialize::<PutEventsConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutEventsConfigurationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds origination settings for the specified Amazon Chime Voice Connector.</p>
+    fn put_voice_connector_origination(
+        &self,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<PutVoiceConnectorOriginationResponse, PutVoiceConnectorOriginationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}/origination",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorOrigination");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutVoiceConnectorOrigination")
+                        .deserialize::<PutVoiceConnectorOriginationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutVoiceConnectorOriginationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds termination settings for the specified Amazon Chime Voice Connector.</p>
+    fn put_voice_connector_termination(
+        &self,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<PutVoiceConnectorTerminationResponse, PutVoiceConnectorTerminationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}/termination",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorTermination");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutVoiceConnectorTermination")
+                        .deserialize::<PutVoiceConnectorTerminationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutVoiceConnectorTerminationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds termination SIP credentials for the specified Amazon Chime Voice Connector.</p>
+    fn put_voice_connector_termination_credentials(
+        &self,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<(), PutVoiceConnectorTerminationCredentialsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}/termination/credentials",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorTerminationCredentials");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "put");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutVoiceConnectorTerminationCredentialsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Regenerates the security token for a bot.</p>
+    fn regenerate_security_token(
+        &self,
+        input: RegenerateSecurityTokenRequest,
+    ) -> RusotoFuture<RegenerateSecurityTokenResponse, RegenerateSecurityTokenError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/bots/{bot_id}",
+            account_id = input.account_id,
+            bot_id = input.bot_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RegenerateSecurityToken");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("BotId", &input.bot_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("operation", "regenerate-security-token");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RegenerateSecurityToken")
+                        .deserialize::<RegenerateSecurityTokenResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RegenerateSecurityTokenError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Resets the personal meeting PIN for the specified user on an Amazon Chime account. Returns the <a>User</a> object with the updated personal meeting PIN.</p>
+    fn reset_personal_pin(
+        &self,
+        input: ResetPersonalPINRequest,
+    ) -> RusotoFuture<ResetPersonalPINResponse, ResetPersonalPINError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/users/{user_id}",
+            account_id = input.account_id,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("ResetPersonalPIN");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("operation", "reset-personal-pin");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ResetPersonalPIN")
+                        .deserialize::<ResetPersonalPINResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResetPersonalPINError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Moves a phone number from the <b>Deletion queue</b> back into the phone number <b>Inventory</b>.</p>
+    fn restore_phone_number(
+        &self,
+        input: RestorePhoneNumberRequest,
+    ) -> RusotoFuture<RestorePhoneNumberResponse, RestorePhoneNumberError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/phone-numbers/{phone_number_id}",
+            phone_number_id = input.phone_number_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RestorePhoneNumber");
+        request.add_resource_id("PhoneNumberId", &input.phone_number_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("operation", "restore");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RestorePhoneNumber")
+                        .deserialize::<RestorePhoneNumberResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RestorePhoneNumberError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Searches phone numbers that can be ordered.</p>
+    fn search_available_phone_numbers(
+        &self,
+        input: SearchAvailablePhoneNumbersRequest,
+    ) -> RusotoFuture<SearchAvailablePhoneNumbersResponse, SearchAvailablePhoneNumbersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/search";
+
+        let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("SearchAvailablePhoneNumbers");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.area_code {
+            params.put("area-code", x);
+        }
+        if let Some(ref x) = input.city {
+            params.put("city", x);
+        }
+        if let Some(ref x) = input.country {
+            params.put("country", x);
+        }
+        if let Some(ref x) = input.max_results {
+            params.put("max-results", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("next-token", x);
+        }
+        if let Some(ref x) = input.state {
+            params.put("state", x);
+        }
+        if let Some(ref x) = input.toll_free_prefix {
+            params.put("toll-free-prefix", x);
+        }
+        params.put("type", "phone-numbers");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("SearchAvailablePhoneNumbers")
+                        .deserialize::<SearchAvailablePhoneNumbersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SearchAvailablePhoneNumbersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates account details for the specified Amazon Chime account. Currently, only account name updates are supported for this action.</p>
+    fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> RusotoFuture<UpdateAccountResponse, UpdateAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAccount");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateAccount")
+                        .deserialize::<UpdateAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the settings for the specified Amazon Chime account. You can update settings for remote control of shared screens, or for the dial-out option. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    fn update_account_settings(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> RusotoFuture<UpdateAccountSettingsResponse, UpdateAccountSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/settings",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAccountSettings");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateAccountSettings")
+                        .deserialize::<UpdateAccountSettingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateAccountSettingsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the status of the specified bot, such as starting or stopping the bot from running in your Amazon Chime Enterprise account.</p>
+    fn update_bot(
+        &self,
+        input: UpdateBotRequest,
+    ) -> RusotoFuture<UpdateBotResponse, UpdateBotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/bots/{bot_id}",
+            account_id = input.account_id,
+            bot_id = input.bot_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateBot");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("BotId", &input.bot_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateBot")
+                        .deserialize::<UpdateBotResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateBotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
+    fn update_global_settings(
+        &self,
+        input: UpdateGlobalSettingsRequest,
+    ) -> RusotoFuture<(), UpdateGlobalSettingsError> {
+        let request_uri = "/settings";
+
+        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateGlobalSettings");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateGlobalSettingsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates phone number details, such as product type, for the specified phone number ID. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
+    fn update_phone_number(
+        &self,
+        input: UpdatePhoneNumberRequest,
+    ) -> RusotoFuture<UpdatePhoneNumberResponse, UpdatePhoneNumberError> {
+        let request_uri = format!(
+            "/phone-numbers/{phone_number_id}",
+            phone_number_id = input.phone_number_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdatePhoneNumber");
+        request.add_resource_id("PhoneNumberId", &input.phone_number_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdatePhoneNumber")
+                        .deserialize::<UpdatePhoneNumberResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdatePhoneNumberError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates user details for a specified user ID. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/accounts/{account_id}/users/{user_id}",
+            account_id = input.account_id,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateUser")
+                        .deserialize::<UpdateUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the settings for the specified user, such as phone number settings.</p>
+    fn update_user_settings(
+        &self,
+        input: UpdateUserSettingsRequest,
+    ) -> RusotoFuture<(), UpdateUserSettingsError> {
+        let request_uri = format!(
+            "/accounts/{account_id}/users/{user_id}/settings",
+            account_id = input.account_id,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateUserSettings");
+        request.add_resource_id("AccountId", &input.account_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateUserSettingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates details for the specified Amazon Chime Voice Connector.</p>
+    fn update_voice_connector(
+        &self,
+        input: UpdateVoiceConnectorRequest,
+    ) -> RusotoFuture<UpdateVoiceConnectorResponse, UpdateVoiceConnectorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/voice-connectors/{voice_connector_id}",
+            voice_connector_id = input.voice_connector_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateVoiceConnector");
+        request.add_resource_id("VoiceConnectorId", &input.voice_connector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateVoiceConnector")
+                        .deserialize::<UpdateVoiceConnectorResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateVoiceConnectorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon Chime API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockChime {
+    associate_phone_number_with_user: Option<
+        Box<
+            dyn Fn(
+                    AssociatePhoneNumberWithUserRequest,
+                ) -> ::std::result::Result<
+                    AssociatePhoneNumberWithUserResponse,
+                    RusotoError<AssociatePhoneNumberWithUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_phone_numbers_with_voice_connector: Option<
+        Box<
+            dyn Fn(
+                    AssociatePhoneNumbersWithVoiceConnectorRequest,
+                ) -> ::std::result::Result<
+                    AssociatePhoneNumbersWithVoiceConnectorResponse,
+                    RusotoError<AssociatePhoneNumbersWithVoiceConnectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_delete_phone_number: Option<
+        Box<
+            dyn Fn(
+                    BatchDeletePhoneNumberRequest,
+                ) -> ::std::result::Result<
+                    BatchDeletePhoneNumberResponse,
+                    RusotoError<BatchDeletePhoneNumberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_suspend_user: Option<
+        Box<
+            dyn Fn(
+                    BatchSuspendUserRequest,
+                ) -> ::std::result::Result<
+                    BatchSuspendUserResponse,
+                    RusotoError<BatchSuspendUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_unsuspend_user: Option<
+        Box<
+            dyn Fn(
+                    BatchUnsuspendUserRequest,
+                ) -> ::std::result::Result<
+                    BatchUnsuspendUserResponse,
+                    RusotoError<BatchUnsuspendUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_update_phone_number: Option<
+        Box<
+            dyn Fn(
+                    BatchUpdatePhoneNumberRequest,
+                ) -> ::std::result::Result<
+                    BatchUpdatePhoneNumberResponse,
+                    RusotoError<BatchUpdatePhoneNumberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_update_user: Option<
+        Box<
+            dyn Fn(
+                    BatchUpdateUserRequest,
+                ) -> ::std::result::Result<
+                    BatchUpdateUserResponse,
+                    RusotoError<BatchUpdateUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_account: Option<
+        Box<
+            dyn Fn(
+                    CreateAccountRequest,
+                )
+                    -> ::std::result::Result<CreateAccountResponse, RusotoError<CreateAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_bot: Option<
+        Box<
+            dyn Fn(
+                    CreateBotRequest,
+                )
+                    -> ::std::result::Result<CreateBotResponse, RusotoError<CreateBotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_phone_number_order: Option<
+        Box<
+            dyn Fn(
+                    CreatePhoneNumberOrderRequest,
+                ) -> ::std::result::Result<
+                    CreatePhoneNumberOrderResponse,
+                    RusotoError<CreatePhoneNumberOrderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_voice_connector: Option<
+        Box<
+            dyn Fn(
+                    CreateVoiceConnectorRequest,
+                ) -> ::std::result::Result<
+                    CreateVoiceConnectorResponse,
+                    RusotoError<CreateVoiceConnectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_account: Option<
+        Box<
+            dyn Fn(
+                    DeleteAccountRequest,
+                )
+                    -> ::std::result::Result<DeleteAccountResponse, RusotoError<DeleteAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_events_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteEventsConfigurationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteEventsConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_phone_number: Option<
+        Box<
+            dyn Fn(
+                    DeletePhoneNumberRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeletePhoneNumberError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_voice_connector: Option<
+        Box<
+            dyn Fn(
+                    DeleteVoiceConnectorRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteVoiceConnectorError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_voice_connector_origination: Option<
+        Box<
+            dyn Fn(
+                    DeleteVoiceConnectorOriginationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteVoiceConnectorOriginationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_voice_connector_termination: Option<
+        Box<
+            dyn Fn(
+                    DeleteVoiceConnectorTerminationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteVoiceConnectorTerminationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_voice_connector_termination_credentials: Option<
+        Box<
+            dyn Fn(
+                    DeleteVoiceConnectorTerminationCredentialsRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<DeleteVoiceConnectorTerminationCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_phone_number_from_user: Option<
+        Box<
+            dyn Fn(
+                    DisassociatePhoneNumberFromUserRequest,
+                ) -> ::std::result::Result<
+                    DisassociatePhoneNumberFromUserResponse,
+                    RusotoError<DisassociatePhoneNumberFromUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_phone_numbers_from_voice_connector: Option<
+        Box<
+            dyn Fn(
+                    DisassociatePhoneNumbersFromVoiceConnectorRequest,
+                ) -> ::std::result::Result<
+                    DisassociatePhoneNumbersFromVoiceConnectorResponse,
+                    RusotoError<DisassociatePhoneNumbersFromVoiceConnectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_account: Option<
+        Box<
+            dyn Fn(
+                    GetAccountRequest,
+                )
+                    -> ::std::result::Result<GetAccountResponse, RusotoError<GetAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_account_settings: Option<
+        Box<
+            dyn Fn(
+                    GetAccountSettingsRequest,
+                ) -> ::std::result::Result<
+                    GetAccountSettingsResponse,
+                    RusotoError<GetAccountSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bot: Option<
+        Box<
+            dyn Fn(GetBotRequest) -> ::std::result::Result<GetBotResponse, RusotoError<GetBotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_events_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetEventsConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetEventsConfigurationResponse,
+                    RusotoError<GetEventsConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_global_settings: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetGlobalSettingsResponse,
+                    RusotoError<GetGlobalSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_phone_number: Option<
+        Box<
+            dyn Fn(
+                    GetPhoneNumberRequest,
+                ) -> ::std::result::Result<
+                    GetPhoneNumberResponse,
+                    RusotoError<GetPhoneNumberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_phone_number_order: Option<
+        Box<
+            dyn Fn(
+                    GetPhoneNumberOrderRequest,
+                ) -> ::std::result::Result<
+                    GetPhoneNumberOrderResponse,
+                    RusotoError<GetPhoneNumberOrderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_user: Option<
+        Box<
+            dyn Fn(
+                    GetUserRequest,
+                )
+                    -> ::std::result::Result<GetUserResponse, RusotoError<GetUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_user_settings: Option<
+        Box<
+            dyn Fn(
+                    GetUserSettingsRequest,
+                ) -> ::std::result::Result<
+                    GetUserSettingsResponse,
+                    RusotoError<GetUserSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_voice_connector: Option<
+        Box<
+            dyn Fn(
+                    GetVoiceConnectorRequest,
+                ) -> ::std::result::Result<
+                    GetVoiceConnectorResponse,
+                    RusotoError<GetVoiceConnectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_voice_connector_origination: Option<
+        Box<
+            dyn Fn(
+                    GetVoiceConnectorOriginationRequest,
+                ) -> ::std::result::Result<
+                    GetVoiceConnectorOriginationResponse,
+                    RusotoError<GetVoiceConnectorOriginationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_voice_connector_termination: Option<
+        Box<
+            dyn Fn(
+                    GetVoiceConnectorTerminationRequest,
+                ) -> ::std::result::Result<
+                    GetVoiceConnectorTerminationResponse,
+                    RusotoError<GetVoiceConnectorTerminationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_voice_connector_termination_health: Option<
+        Box<
+            dyn Fn(
+                    GetVoiceConnectorTerminationHealthRequest,
+                ) -> ::std::result::Result<
+                    GetVoiceConnectorTerminationHealthResponse,
+                    RusotoError<GetVoiceConnectorTerminationHealthError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    invite_users: Option<
+        Box<
+            dyn Fn(
+                    InviteUsersRequest,
+                )
+                    -> ::std::result::Result<InviteUsersResponse, RusotoError<InviteUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_accounts: Option<
+        Box<
+            dyn Fn(
+                    ListAccountsRequest,
+                )
+                    -> ::std::result::Result<ListAccountsResponse, RusotoError<ListAccountsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_bots: Option<
+        Box<
+            dyn Fn(
+                    ListBotsRequest,
+                )
+                    -> ::std::result::Result<ListBotsResponse, RusotoError<ListBotsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_phone_number_orders: Option<
+        Box<
+            dyn Fn(
+                    ListPhoneNumberOrdersRequest,
+                ) -> ::std::result::Result<
+                    ListPhoneNumberOrdersResponse,
+                    RusotoError<ListPhoneNumberOrdersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_phone_numbers: Option<
+        Box<
+            dyn Fn(
+                    ListPhoneNumbersRequest,
+                ) -> ::std::result::Result<
+                    ListPhoneNumbersResponse,
+                    RusotoError<ListPhoneNumbersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_users: Option<
+        Box<
+            dyn Fn(
+                    ListUsersRequest,
+                )
+                    -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_voice_connector_termination_credentials: Option<
+        Box<
+            dyn Fn(
+                    ListVoiceConnectorTerminationCredentialsRequest,
+                ) -> ::std::result::Result<
+                    ListVoiceConnectorTerminationCredentialsResponse,
+                    RusotoError<ListVoiceConnectorTerminationCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_voice_connectors: Option<
+        Box<
+            dyn Fn(
+                    ListVoiceConnectorsRequest,
+                ) -> ::std::result::Result<
+                    ListVoiceConnectorsResponse,
+                    RusotoError<ListVoiceConnectorsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    logout_user: Option<
+        Box<
+            dyn Fn(
+                    LogoutUserRequest,
+                )
+                    -> ::std::result::Result<LogoutUserResponse, RusotoError<LogoutUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_events_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutEventsConfigurationRequest,
+                ) -> ::std::result::Result<
+                    PutEventsConfigurationResponse,
+                    RusotoError<PutEventsConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_voice_connector_origination: Option<
+        Box<
+            dyn Fn(
+                    PutVoiceConnectorOriginationRequest,
+                ) -> ::std::result::Result<
+                    PutVoiceConnectorOriginationResponse,
+                    RusotoError<PutVoiceConnectorOriginationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_voice_connector_termination: Option<
+        Box<
+            dyn Fn(
+                    PutVoiceConnectorTerminationRequest,
+                ) -> ::std::result::Result<
+                    PutVoiceConnectorTerminationResponse,
+                    RusotoError<PutVoiceConnectorTerminationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_voice_connector_termination_credentials: Option<
+        Box<
+            dyn Fn(
+                    PutVoiceConnectorTerminationCredentialsRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<PutVoiceConnectorTerminationCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    regenerate_security_token: Option<
+        Box<
+            dyn Fn(
+                    RegenerateSecurityTokenRequest,
+                ) -> ::std::result::Result<
+                    RegenerateSecurityTokenResponse,
+                    RusotoError<RegenerateSecurityTokenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reset_personal_pin: Option<
+        Box<
+            dyn Fn(
+                    ResetPersonalPINRequest,
+                ) -> ::std::result::Result<
+                    ResetPersonalPINResponse,
+                    RusotoError<ResetPersonalPINError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    restore_phone_number: Option<
+        Box<
+            dyn Fn(
+                    RestorePhoneNumberRequest,
+                ) -> ::std::result::Result<
+                    RestorePhoneNumberResponse,
+                    RusotoError<RestorePhoneNumberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_available_phone_numbers: Option<
+        Box<
+            dyn Fn(
+                    SearchAvailablePhoneNumbersRequest,
+                ) -> ::std::result::Result<
+                    SearchAvailablePhoneNumbersResponse,
+                    RusotoError<SearchAvailablePhoneNumbersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_account: Option<
+        Box<
+            dyn Fn(
+                    UpdateAccountRequest,
+                )
+                    -> ::std::result::Result<UpdateAccountResponse, RusotoError<UpdateAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_account_settings: Option<
+        Box<
+            dyn Fn(
+                    UpdateAccountSettingsRequest,
+                ) -> ::std::result::Result<
+                    UpdateAccountSettingsResponse,
+                    RusotoError<UpdateAccountSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_bot: Option<
+        Box<
+            dyn Fn(
+                    UpdateBotRequest,
+                )
+                    -> ::std::result::Result<UpdateBotResponse, RusotoError<UpdateBotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_global_settings: Option<
+        Box<
+            dyn Fn(
+                    UpdateGlobalSettingsRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateGlobalSettingsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_phone_number: Option<
+        Box<
+            dyn Fn(
+                    UpdatePhoneNumberRequest,
+                ) -> ::std::result::Result<
+                    UpdatePhoneNumberResponse,
+                    RusotoError<UpdatePhoneNumberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_user: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserRequest,
+                )
+                    -> ::std::result::Result<UpdateUserResponse, RusotoError<UpdateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_user_settings: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserSettingsRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateUserSettingsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_voice_connector: Option<
+        Box<
+            dyn Fn(
+                    UpdateVoiceConnectorRequest,
+                ) -> ::std::result::Result<
+                    UpdateVoiceConnectorResponse,
+                    RusotoError<UpdateVoiceConnectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockChime {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockChime {
+        Default::default()
+    }
+
+    /// Answers `associate_phone_number_with_user` with what `handler` returns for its input.
+    pub fn with_associate_phone_number_with_user<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                AssociatePhoneNumberWithUserRequest,
+            ) -> ::std::result::Result<
+                AssociatePhoneNumberWithUserResponse,
+                RusotoError<AssociatePhoneNumberWithUserError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_phone_number_with_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_phone_numbers_with_voice_connector` with what `handler` returns for its input.
+    pub fn with_associate_phone_numbers_with_voice_connector<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                AssociatePhoneNumbersWithVoiceConnectorRequest,
+            ) -> ::std::result::Result<
+                AssociatePhoneNumbersWithVoiceConnectorResponse,
+                RusotoError<AssociatePhoneNumbersWithVoiceConnectorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_phone_numbers_with_voice_connector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_delete_phone_number` with what `handler` returns for its input.
+    pub fn with_batch_delete_phone_number<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                BatchDeletePhoneNumberRequest,
+            ) -> ::std::result::Result<
+                BatchDeletePhoneNumberResponse,
+                RusotoError<BatchDeletePhoneNumberError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_phone_number = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_suspend_user` with what `handler` returns for its input.
+    pub fn with_batch_suspend_user<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                BatchSuspendUserRequest,
+            ) -> ::std::result::Result<
+                BatchSuspendUserResponse,
+                RusotoError<BatchSuspendUserError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_suspend_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_unsuspend_user` with what `handler` returns for its input.
+    pub fn with_batch_unsuspend_user<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                BatchUnsuspendUserRequest,
+            ) -> ::std::result::Result<
+                BatchUnsuspendUserResponse,
+                RusotoError<BatchUnsuspendUserError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_unsuspend_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_update_phone_number` with what `handler` returns for its input.
+    pub fn with_batch_update_phone_number<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                BatchUpdatePhoneNumberRequest,
+            ) -> ::std::result::Result<
+                BatchUpdatePhoneNumberResponse,
+                RusotoError<BatchUpdatePhoneNumberError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_update_phone_number = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_update_user` with what `handler` returns for its input.
+    pub fn with_batch_update_user<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                BatchUpdateUserRequest,
+            )
+                -> ::std::result::Result<BatchUpdateUserResponse, RusotoError<BatchUpdateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_update_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_account` with what `handler` returns for its input.
+    pub fn with_create_account<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                CreateAccountRequest,
+            )
+                -> ::std::result::Result<CreateAccountResponse, RusotoError<CreateAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_bot` with what `handler` returns for its input.
+    pub fn with_create_bot<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                CreateBotRequest,
+            ) -> ::std::result::Result<CreateBotResponse, RusotoError<CreateBotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_bot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_phone_number_order` with what `handler` returns for its input.
+    pub fn with_create_phone_number_order<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                CreatePhoneNumberOrderRequest,
+            ) -> ::std::result::Result<
+                CreatePhoneNumberOrderResponse,
+                RusotoError<CreatePhoneNumberOrderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_phone_number_order = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_voice_connector` with what `handler` returns for its input.
+    pub fn with_create_voice_connector<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                CreateVoiceConnectorRequest,
+            ) -> ::std::result::Result<
+                CreateVoiceConnectorResponse,
+                RusotoError<CreateVoiceConnectorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_voice_connector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_account` with what `handler` returns for its input.
+    pub fn with_delete_account<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                DeleteAccountRequest,
+            )
+                -> ::std::result::Result<DeleteAccountResponse, RusotoError<DeleteAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_events_configuration` with what `handler` returns for its input.
+    pub fn with_delete_events_configuration<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                DeleteEventsConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteEventsConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_events_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_phone_number` with what `handler` returns for its input.
+    pub fn with_delete_phone_number<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                DeletePhoneNumberRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeletePhoneNumberError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_phone_number = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_voice_connector` with what `handler` returns for its input.
+    pub fn with_delete_voice_connector<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                DeleteVoiceConnectorRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteVoiceConnectorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_voice_connector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_voice_connector_origination` with what `handler` returns for its input.
+    pub fn with_delete_voice_connector_origination<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                DeleteVoiceConnectorOriginationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteVoiceConnectorOriginationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_voice_connector_origination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_voice_connector_termination` with what `handler` returns for its input.
+    pub fn with_delete_voice_connector_termination<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                DeleteVoiceConnectorTerminationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteVoiceConnectorTerminationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_voice_connector_termination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_voice_connector_termination_credentials` with what `handler` returns for its input.
+    pub fn with_delete_voice_connector_termination_credentials<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                DeleteVoiceConnectorTerminationCredentialsRequest,
+            ) -> ::std::result::Result<
+                (),
+                RusotoError<DeleteVoiceConnectorTerminationCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_voice_connector_termination_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_phone_number_from_user` with what `handler` returns for its input.
+    pub fn with_disassociate_phone_number_from_user<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                DisassociatePhoneNumberFromUserRequest,
+            ) -> ::std::result::Result<
+                DisassociatePhoneNumberFromUserResponse,
+                RusotoError<DisassociatePhoneNumberFromUserError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_phone_number_from_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_phone_numbers_from_voice_connector` with what `handler` returns for its input.
+    pub fn with_disassociate_phone_numbers_from_voice_connector<F>(
+        mut self,
+        handler: F,
+    ) -> MockChime
+    where
+        F: Fn(
+                DisassociatePhoneNumbersFromVoiceConnectorRequest,
+            ) -> ::std::result::Result<
+                DisassociatePhoneNumbersFromVoiceConnectorResponse,
+                RusotoError<DisassociatePhoneNumbersFromVoiceConnectorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_phone_numbers_from_voice_connector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_account` with what `handler` returns for its input.
+    pub fn with_get_account<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                GetAccountRequest,
+            )
+                -> ::std::result::Result<GetAccountResponse, RusotoError<GetAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_account_settings` with what `handler` returns for its input.
+    pub fn with_get_account_settings<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                GetAccountSettingsRequest,
+            ) -> ::std::result::Result<
+                GetAccountSettingsResponse,
+                RusotoError<GetAccountSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_account_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bot` with what `handler` returns for its input.
+    pub fn with_get_bot<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(GetBotRequest) -> ::std::result::Result<GetBotResponse, RusotoError<GetBotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_events_configuration` with what `handler` returns for its input.
+    pub fn with_get_events_configuration<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                GetEventsConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetEventsConfigurationResponse,
+                RusotoError<GetEventsConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_events_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_global_settings` with what `handler` returns.
+    pub fn with_get_global_settings<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn() -> ::std::result::Result<
+                GetGlobalSettingsResponse,
+                RusotoError<GetGlobalSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_global_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_phone_number` with what `handler` returns for its input.
+    pub fn with_get_phone_number<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                GetPhoneNumberRequest,
+            )
+                -> ::std::result::Result<GetPhoneNumberResponse, RusotoError<GetPhoneNumberError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_phone_number = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_phone_number_order` with what `handler` returns for its input.
+    pub fn with_get_phone_number_order<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                GetPhoneNumberOrderRequest,
+            ) -> ::std::result::Result<
+                GetPhoneNumberOrderResponse,
+                RusotoError<GetPhoneNumberOrderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_phone_number_order = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_user` with what `handler` returns for its input.
+    pub fn with_get_user<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(GetUserRequest) -> ::std::result::Result<GetUserResponse, RusotoError<GetUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_user_settings` with what `handler` returns for its input.
+    pub fn with_get_user_settings<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                GetUserSettingsRequest,
+            )
+                -> ::std::result::Result<GetUserSettingsResponse, RusotoError<GetUserSettingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_user_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_voice_connector` with what `handler` returns for its input.
+    pub fn with_get_voice_connector<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                GetVoiceConnectorRequest,
+            ) -> ::std::result::Result<
+                GetVoiceConnectorResponse,
+                RusotoError<GetVoiceConnectorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_voice_connector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_voice_connector_origination` with what `handler` returns for its input.
+    pub fn with_get_voice_connector_origination<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                GetVoiceConnectorOriginationRequest,
+            ) -> ::std::result::Result<
+                GetVoiceConnectorOriginationResponse,
+                RusotoError<GetVoiceConnectorOriginationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_voice_connector_origination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_voice_connector_termination` with what `handler` returns for its input.
+    pub fn with_get_voice_connector_termination<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                GetVoiceConnectorTerminationRequest,
+            ) -> ::std::result::Result<
+                GetVoiceConnectorTerminationResponse,
+                RusotoError<GetVoiceConnectorTerminationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_voice_connector_termination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_voice_connector_termination_health` with what `handler` returns for its input.
+    pub fn with_get_voice_connector_termination_health<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                GetVoiceConnectorTerminationHealthRequest,
+            ) -> ::std::result::Result<
+                GetVoiceConnectorTerminationHealthResponse,
+                RusotoError<GetVoiceConnectorTerminationHealthError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_voice_connector_termination_health = Some(Box::new(handler));
+        self
+    }
+    /// Answers `invite_users` with what `handler` returns for its input.
+    pub fn with_invite_users<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                InviteUsersRequest,
+            )
+                -> ::std::result::Result<InviteUsersResponse, RusotoError<InviteUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.invite_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_accounts` with what `handler` returns for its input.
+    pub fn with_list_accounts<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                ListAccountsRequest,
+            )
+                -> ::std::result::Result<ListAccountsResponse, RusotoError<ListAccountsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_accounts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_bots` with what `handler` returns for its input.
+    pub fn with_list_bots<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                ListBotsRequest,
+            ) -> ::std::result::Result<ListBotsResponse, RusotoError<ListBotsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_bots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_phone_number_orders` with what `handler` returns for its input.
+    pub fn with_list_phone_number_orders<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                ListPhoneNumberOrdersRequest,
+            ) -> ::std::result::Result<
+                ListPhoneNumberOrdersResponse,
+                RusotoError<ListPhoneNumberOrdersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_phone_number_orders = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_phone_numbers` with what `handler` returns for its input.
+    pub fn with_list_phone_numbers<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                ListPhoneNumbersRequest,
+            ) -> ::std::result::Result<
+                ListPhoneNumbersResponse,
+                RusotoError<ListPhoneNumbersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_phone_numbers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_users` with what `handler` returns for its input.
+    pub fn with_list_users<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                ListUsersRequest,
+            ) -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_voice_connector_termination_credentials` with what `handler` returns for its input.
+    pub fn with_list_voice_connector_termination_credentials<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                ListVoiceConnectorTerminationCredentialsRequest,
+            ) -> ::std::result::Result<
+                ListVoiceConnectorTerminationCredentialsResponse,
+                RusotoError<ListVoiceConnectorTerminationCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_voice_connector_termination_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_voice_connectors` with what `handler` returns for its input.
+    pub fn with_list_voice_connectors<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                ListVoiceConnectorsRequest,
+            ) -> ::std::result::Result<
+                ListVoiceConnectorsResponse,
+                RusotoError<ListVoiceConnectorsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_voice_connectors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `logout_user` with what `handler` returns for its input.
+    pub fn with_logout_user<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                LogoutUserRequest,
+            )
+                -> ::std::result::Result<LogoutUserResponse, RusotoError<LogoutUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.logout_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_events_configuration` with what `handler` returns for its input.
+    pub fn with_put_events_configuration<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                PutEventsConfigurationRequest,
+            ) -> ::std::result::Result<
+                PutEventsConfigurationResponse,
+                RusotoError<PutEventsConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_events_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_voice_connector_origination` with what `handler` returns for its input.
+    pub fn with_put_voice_connector_origination<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                PutVoiceConnectorOriginationRequest,
+            ) -> ::std::result::Result<
+                PutVoiceConnectorOriginationResponse,
+                RusotoError<PutVoiceConnectorOriginationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_voice_connector_origination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_voice_connector_termination` with what `handler` returns for its input.
+    pub fn with_put_voice_connector_termination<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                PutVoiceConnectorTerminationRequest,
+            ) -> ::std::result::Result<
+                PutVoiceConnectorTerminationResponse,
+                RusotoError<PutVoiceConnectorTerminationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_voice_connector_termination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_voice_connector_termination_credentials` with what `handler` returns for its input.
+    pub fn with_put_voice_connector_termination_credentials<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                PutVoiceConnectorTerminationCredentialsRequest,
+            ) -> ::std::result::Result<
+                (),
+                RusotoError<PutVoiceConnectorTerminationCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_voice_connector_termination_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `regenerate_security_token` with what `handler` returns for its input.
+    pub fn with_regenerate_security_token<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                RegenerateSecurityTokenRequest,
+            ) -> ::std::result::Result<
+                RegenerateSecurityTokenResponse,
+                RusotoError<RegenerateSecurityTokenError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.regenerate_security_token = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reset_personal_pin` with what `handler` returns for its input.
+    pub fn with_reset_personal_pin<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                ResetPersonalPINRequest,
+            ) -> ::std::result::Result<
+                ResetPersonalPINResponse,
+                RusotoError<ResetPersonalPINError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reset_personal_pin = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_phone_number` with what `handler` returns for its input.
+    pub fn with_restore_phone_number<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                RestorePhoneNumberRequest,
+            ) -> ::std::result::Result<
+                RestorePhoneNumberResponse,
+                RusotoError<RestorePhoneNumberError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_phone_number = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_available_phone_numbers` with what `handler` returns for its input.
+    pub fn with_search_available_phone_numbers<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                SearchAvailablePhoneNumbersRequest,
+            ) -> ::std::result::Result<
+                SearchAvailablePhoneNumbersResponse,
+                RusotoError<SearchAvailablePhoneNumbersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.search_available_phone_numbers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_account` with what `handler` returns for its input.
+    pub fn with_update_account<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                UpdateAccountRequest,
+            )
+                -> ::std::result::Result<UpdateAccountResponse, RusotoError<UpdateAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_account_settings` with what `handler` returns for its input.
+    pub fn with_update_account_settings<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                UpdateAccountSettingsRequest,
+            ) -> ::std::result::Result<
+                UpdateAccountSettingsResponse,
+                RusotoError<UpdateAccountSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_account_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_bot` with what `handler` returns for its input.
+    pub fn with_update_bot<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                UpdateBotRequest,
+            ) -> ::std::result::Result<UpdateBotResponse, RusotoError<UpdateBotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_bot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_global_settings` with what `handler` returns for its input.
+    pub fn with_update_global_settings<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                UpdateGlobalSettingsRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateGlobalSettingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_global_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_phone_number` with what `handler` returns for its input.
+    pub fn with_update_phone_number<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                UpdatePhoneNumberRequest,
+            ) -> ::std::result::Result<
+                UpdatePhoneNumberResponse,
+                RusotoError<UpdatePhoneNumberError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_phone_number = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user` with what `handler` returns for its input.
+    pub fn with_update_user<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                UpdateUserRequest,
+            )
+                -> ::std::result::Result<UpdateUserResponse, RusotoError<UpdateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_settings` with what `handler` returns for its input.
+    pub fn with_update_user_settings<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                UpdateUserSettingsRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateUserSettingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_voice_connector` with what `handler` returns for its input.
+    pub fn with_update_voice_connector<F>(mut self, handler: F) -> MockChime
+    where
+        F: Fn(
+                UpdateVoiceConnectorRequest,
+            ) -> ::std::result::Result<
+                UpdateVoiceConnectorResponse,
+                RusotoError<UpdateVoiceConnectorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_voice_connector = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Chime for MockChime {
+    fn associate_phone_number_with_user(
+        &self,
+        input: AssociatePhoneNumberWithUserRequest,
+    ) -> RusotoFuture<AssociatePhoneNumberWithUserResponse, AssociatePhoneNumberWithUserError> {
+        match self.associate_phone_number_with_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for associate_phone_number_with_user"),
+        }
+    }
+    fn associate_phone_numbers_with_voice_connector(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorRequest,
+    ) -> RusotoFuture<
+        AssociatePhoneNumbersWithVoiceConnectorResponse,
+        AssociatePhoneNumbersWithVoiceConnectorError,
+    > {
+        match self.associate_phone_numbers_with_voice_connector {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockChime has no handler for associate_phone_numbers_with_voice_connector")
+            }
+        }
+    }
+    fn batch_delete_phone_number(
+        &self,
+        input: BatchDeletePhoneNumberRequest,
+    ) -> RusotoFuture<BatchDeletePhoneNumberResponse, BatchDeletePhoneNumberError> {
+        match self.batch_delete_phone_number {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for batch_delete_phone_number"),
+        }
+    }
+    fn batch_suspend_user(
+        &self,
+        input: BatchSuspendUserRequest,
+    ) -> RusotoFuture<BatchSuspendUserResponse, BatchSuspendUserError> {
+        match self.batch_suspend_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for batch_suspend_user"),
+        }
+    }
+    fn batch_unsuspend_user(
+        &self,
+        input: BatchUnsuspendUserRequest,
+    ) -> RusotoFuture<BatchUnsuspendUserResponse, BatchUnsuspendUserError> {
+        match self.batch_unsuspend_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for batch_unsuspend_user"),
+        }
+    }
+    fn batch_update_phone_number(
+        &self,
+        input: BatchUpdatePhoneNumberRequest,
+    ) -> RusotoFuture<BatchUpdatePhoneNumberResponse, BatchUpdatePhoneNumberError> {
+        match self.batch_update_phone_number {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for batch_update_phone_number"),
+        }
+    }
+    fn batch_update_user(
+        &self,
+        input: BatchUpdateUserRequest,
+    ) -> RusotoFuture<BatchUpdateUserResponse, BatchUpdateUserError> {
+        match self.batch_update_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for batch_update_user"),
+        }
+    }
+    fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
+        match self.create_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for create_account"),
+        }
+    }
+    fn create_bot(
+        &self,
+        input: CreateBotRequest,
+    ) -> RusotoFuture<CreateBotResponse, CreateBotError> {
+        match self.create_bot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for create_bot"),
+        }
+    }
+    fn create_phone_number_order(
+        &self,
+        input: CreatePhoneNumberOrderRequest,
+    ) -> RusotoFuture<CreatePhoneNumberOrderResponse, CreatePhoneNumberOrderError> {
+        match self.create_phone_number_order {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for create_phone_number_order"),
+        }
+    }
+    fn create_voice_connector(
+        &self,
+        input: CreateVoiceConnectorRequest,
+    ) -> RusotoFuture<CreateVoiceConnectorResponse, CreateVoiceConnectorError> {
+        match self.create_voice_connector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for create_voice_connector"),
+        }
+    }
+    fn delete_account(
+        &self,
+        input: DeleteAccountRequest,
+    ) -> RusotoFuture<DeleteAccountResponse, DeleteAccountError> {
+        match self.delete_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for delete_account"),
+        }
+    }
+    fn delete_events_configuration(
+        &self,
+        input: DeleteEventsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteEventsConfigurationError> {
+        match self.delete_events_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for delete_events_configuration"),
+        }
+    }
+    fn delete_phone_number(
+        &self,
+        input: DeletePhoneNumberRequest,
+    ) -> RusotoFuture<(), DeletePhoneNumberError> {
+        match self.delete_phone_number {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for delete_phone_number"),
+        }
+    }
+    fn delete_voice_connector(
+        &self,
+        input: DeleteVoiceConnectorRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorError> {
+        match self.delete_voice_connector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for delete_voice_connector"),
+        }
+    }
+    fn delete_voice_connector_origination(
+        &self,
+        input: DeleteVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorOriginationError> {
+        match self.delete_voice_connector_origination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for delete_voice_connector_origination"),
+        }
+    }
+    fn delete_voice_connector_termination(
+        &self,
+        input: DeleteVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationError> {
+        match self.delete_voice_connector_termination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for delete_voice_connector_termination"),
+        }
+    }
+    fn delete_voice_connector_termination_credentials(
+        &self,
+        input: DeleteVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationCredentialsError> {
+        match self.delete_voice_connector_termination_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockChime has no handler for delete_voice_connector_termination_credentials"
+            ),
+        }
+    }
+    fn disassociate_phone_number_from_user(
+        &self,
+        input: DisassociatePhoneNumberFromUserRequest,
+    ) -> RusotoFuture<DisassociatePhoneNumberFromUserResponse, DisassociatePhoneNumberFromUserError>
+    {
+        match self.disassociate_phone_number_from_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for disassociate_phone_number_from_user"),
+        }
+    }
+    fn disassociate_phone_numbers_from_voice_connector(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
+    ) -> RusotoFuture<
+        DisassociatePhoneNumbersFromVoiceConnectorResponse,
+        DisassociatePhoneNumbersFromVoiceConnectorError,
+    > {
+        match self.disassociate_phone_numbers_from_voice_connector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockChime has no handler for disassociate_phone_numbers_from_voice_connector"
+            ),
+        }
+    }
+    fn get_account(
+        &self,
+        input: GetAccountRequest,
+    ) -> RusotoFuture<GetAccountResponse, GetAccountError> {
+        match self.get_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_account"),
+        }
+    }
+    fn get_account_settings(
+        &self,
+        input: GetAccountSettingsRequest,
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
+        match self.get_account_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_account_settings"),
+        }
+    }
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
+        match self.get_bot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_bot"),
+        }
+    }
+    fn get_events_configuration(
+        &self,
+        input: GetEventsConfigurationRequest,
+    ) -> RusotoFuture<GetEventsConfigurationResponse, GetEventsConfigurationError> {
+        match self.get_events_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_events_configuration"),
+        }
+    }
+    fn get_global_settings(
+        &self,
+    ) -> RusotoFuture<GetGlobalSettingsResponse, GetGlobalSettingsError> {
+        match self.get_global_settings {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockChime has no handler for get_global_settings"),
+        }
+    }
+    fn get_phone_number(
+        &self,
+        input: GetPhoneNumberRequest,
+    ) -> RusotoFuture<GetPhoneNumberResponse, GetPhoneNumberError> {
+        match self.get_phone_number {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_phone_number"),
+        }
+    }
+    fn get_phone_number_order(
+        &self,
+        input: GetPhoneNumberOrderRequest,
+    ) -> RusotoFuture<GetPhoneNumberOrderResponse, GetPhoneNumberOrderError> {
+        match self.get_phone_number_order {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_phone_number_order"),
+        }
+    }
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        match self.get_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_user"),
+        }
+    }
+    fn get_user_settings(
+        &self,
+        input: GetUserSettingsRequest,
+    ) -> RusotoFuture<GetUserSettingsResponse, GetUserSettingsError> {
+        match self.get_user_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_user_settings"),
+        }
+    }
+    fn get_voice_connector(
+        &self,
+        input: GetVoiceConnectorRequest,
+    ) -> RusotoFuture<GetVoiceConnectorResponse, GetVoiceConnectorError> {
+        match self.get_voice_connector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_voice_connector"),
+        }
+    }
+    fn get_voice_connector_origination(
+        &self,
+        input: GetVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<GetVoiceConnectorOriginationResponse, GetVoiceConnectorOriginationError> {
+        match self.get_voice_connector_origination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_voice_connector_origination"),
+        }
+    }
+    fn get_voice_connector_termination(
+        &self,
+        input: GetVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<GetVoiceConnectorTerminationResponse, GetVoiceConnectorTerminationError> {
+        match self.get_voice_connector_termination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_voice_connector_termination"),
+        }
+    }
+    fn get_voice_connector_termination_health(
+        &self,
+        input: GetVoiceConnectorTerminationHealthRequest,
+    ) -> RusotoFuture<
+        GetVoiceConnectorTerminationHealthResponse,
+        GetVoiceConnectorTerminationHealthError,
+    > {
+        match self.get_voice_connector_termination_health {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for get_voice_connector_termination_health"),
+        }
+    }
+    fn invite_users(
+        &self,
+        input: InviteUsersRequest,
+    ) -> RusotoFuture<InviteUsersResponse, InviteUsersError> {
+        match self.invite_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for invite_users"),
+        }
+    }
+    fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
+        match self.list_accounts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for list_accounts"),
+        }
+    }
+    fn list_bots(&self, input: ListBotsRequest) -> RusotoFuture<ListBotsResponse, ListBotsError> {
+        match self.list_bots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for list_bots"),
+        }
+    }
+    fn list_phone_number_orders(
+        &self,
+        input: ListPhoneNumberOrdersRequest,
+    ) -> RusotoFuture<ListPhoneNumberOrdersResponse, ListPhoneNumberOrdersError> {
+        match self.list_phone_number_orders {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for list_phone_number_orders"),
+        }
+    }
+    fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> RusotoFuture<ListPhoneNumbersResponse, ListPhoneNumbersError> {
+        match self.list_phone_numbers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for list_phone_numbers"),
+        }
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        match self.list_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for list_users"),
+        }
+    }
+    fn list_voice_connector_termination_credentials(
+        &self,
+        input: ListVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<
+        ListVoiceConnectorTerminationCredentialsResponse,
+        ListVoiceConnectorTerminationCredentialsError,
+    > {
+        match self.list_voice_connector_termination_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockChime has no handler for list_voice_connector_termination_credentials")
+            }
+        }
+    }
+    fn list_voice_connectors(
+        &self,
+        input: ListVoiceConnectorsRequest,
+    ) -> RusotoFuture<ListVoiceConnectorsResponse, ListVoiceConnectorsError> {
+        match self.list_voice_connectors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for list_voice_connectors"),
+        }
+    }
+    fn logout_user(
+        &self,
+        input: LogoutUserRequest,
+    ) -> RusotoFuture<LogoutUserResponse, LogoutUserError> {
+        match self.logout_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for logout_user"),
+        }
+    }
+    fn put_events_configuration(
+        &self,
+        input: PutEventsConfigurationRequest,
+    ) -> RusotoFuture<PutEventsConfigurationResponse, PutEventsConfigurationError> {
+        match self.put_events_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for put_events_configuration"),
+        }
+    }
+    fn put_voice_connector_origination(
+        &self,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<PutVoiceConnectorOriginationResponse, PutVoiceConnectorOriginationError> {
+        match self.put_voice_connector_origination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for put_voice_connector_origination"),
+        }
+    }
+    fn put_voice_connector_termination(
+        &self,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<PutVoiceConnectorTerminationResponse, PutVoiceConnectorTerminationError> {
+        match self.put_voice_connector_termination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for put_voice_connector_termination"),
+        }
+    }
+    fn put_voice_connector_termination_credentials(
+        &self,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<(), PutVoiceConnectorTerminationCredentialsError> {
+        match self.put_voice_connector_termination_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockChime has no handler for put_voice_connector_termination_credentials")
+            }
+        }
+    }
+    fn regenerate_security_token(
+        &self,
+        input: RegenerateSecurityTokenRequest,
+    ) -> RusotoFuture<RegenerateSecurityTokenResponse, RegenerateSecurityTokenError> {
+        match self.regenerate_security_token {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for regenerate_security_token"),
+        }
+    }
+    fn reset_personal_pin(
+        &self,
+        input: ResetPersonalPINRequest,
+    ) -> RusotoFuture<ResetPersonalPINResponse, ResetPersonalPINError> {
+        match self.reset_personal_pin {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for reset_personal_pin"),
+        }
+    }
+    fn restore_phone_number(
+        &self,
+        input: RestorePhoneNumberRequest,
+    ) -> RusotoFuture<RestorePhoneNumberResponse, RestorePhoneNumberError> {
+        match self.restore_phone_number {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for restore_phone_number"),
+        }
+    }
+    fn search_available_phone_numbers(
+        &self,
+        input: SearchAvailablePhoneNumbersRequest,
+    ) -> RusotoFuture<SearchAvailablePhoneNumbersResponse, SearchAvailablePhoneNumbersError> {
+        match self.search_available_phone_numbers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for search_available_phone_numbers"),
+        }
     }
-
-    /// <p>Updates account details for the specified Amazon Chime account. Currently, only account name updates are supported for this action.</p>
     fn update_account(
         &self,
         input: UpdateAccountRequest,
     ) -> RusotoFuture<UpdateAccountResponse, UpdateAccountError> {
-        let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAccountError::from_response(response))),
-                )
-            }
-        })
+        match self.update_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for update_account"),
+        }
     }
-
-    /// <p>Updates the settings for the specified Amazon Chime account. You can update settings for remote control of shared screens, or for the dial-out option. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
     fn update_account_settings(
         &self,
         input: UpdateAccountSettingsRequest,
     ) -> RusotoFuture<UpdateAccountSettingsResponse, UpdateAccountSettingsError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/settings",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAccountSettingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateAccountSettingsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_account_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for update_account_settings"),
+        }
     }
-
-    /// <p>Updates the status of the specified bot, such as starting or stopping the bot from running in your Amazon Chime Enterprise account.</p>
     fn update_bot(
         &self,
         input: UpdateBotRequest,
     ) -> RusotoFuture<UpdateBotResponse, UpdateBotError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/bots/{bot_id}",
-            account_id = input.account_id,
-            bot_id = input.bot_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateBotResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateBotError::from_response(response))),
-                )
-            }
-        })
+        match self.update_bot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for update_bot"),
+        }
     }
-
-    /// <p>Updates global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
     fn update_global_settings(
         &self,
         input: UpdateGlobalSettingsRequest,
     ) -> RusotoFuture<(), UpdateGlobalSettingsError> {
-        let request_uri = "/settings";
-
-        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateGlobalSettingsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_global_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for update_global_settings"),
+        }
     }
-
-    /// <p>Updates phone number details, such as product type, for the specified phone number ID. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
     fn update_phone_number(
         &self,
         input: UpdatePhoneNumberRequest,
     ) -> RusotoFuture<UpdatePhoneNumberResponse, UpdatePhoneNumberError> {
-        let request_uri = format!(
-            "/phone-numbers/{phone_number_id}",
-            phone_number_id = input.phone_number_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdatePhoneNumberResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdatePhoneNumberError::from_response(response))),
-                )
-            }
-        })
+        match self.update_phone_number {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for update_phone_number"),
+        }
     }
-
-    /// <p>Updates user details for a specified user ID. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
     fn update_user(
         &self,
         input: UpdateUserRequest,
     ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users/{user_id}",
-            account_id = input.account_id,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateUserError::from_response(response))),
-                )
-            }
-        })
+        match self.update_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for update_user"),
+        }
     }
-
-    /// <p>Updates the settings for the specified user, such as phone number settings.</p>
     fn update_user_settings(
         &self,
         input: UpdateUserSettingsRequest,
     ) -> RusotoFuture<(), UpdateUserSettingsError> {
-        let request_uri = format!(
-            "/accounts/{account_id}/users/{user_id}/settings",
-            account_id = input.account_id,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateUserSettingsError::from_response(response))),
-                )
-            }
-        })
+        match self.update_user_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for update_user_settings"),
+        }
     }
-
-    /// <p>Updates details for the specified Amazon Chime Voice Connector.</p>
     fn update_voice_connector(
         &self,
         input: UpdateVoiceConnectorRequest,
     ) -> RusotoFuture<UpdateVoiceConnectorResponse, UpdateVoiceConnectorError> {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateVoiceConnectorResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateVoiceConnectorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_voice_connector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockChime has no handler for update_voice_connector"),
+        }
     }
 }
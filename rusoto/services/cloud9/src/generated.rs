@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEnvironmentEC2Request {
     /// <p>The number of minutes until the running instance is shut down after the environment has last been used.</p>
     #[serde(rename = "automaticStopTimeMinutes")]
@@ -53,8 +55,69 @@ pub struct CreateEnvironmentEC2Request {
     pub subnet_id: Option<String>,
 }
 
+impl Validate for CreateEnvironmentEC2Request {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.automatic_stop_time_minutes {
+            errors.check_range(
+                &field_path(path, "automatic_stop_time_minutes"),
+                *value,
+                None,
+                Some(20160),
+            );
+        }
+        if let Some(ref value) = self.client_request_token {
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[\\x20-\\x7E]{10,128}",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "instance_type"),
+            self.instance_type.chars().count(),
+            Some(5),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_type"),
+            &self.instance_type,
+            "^[a-z]+[1-9][.][a-z0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(60),
+        );
+        if let Some(ref value) = self.owner_arn {
+            errors.check_pattern(&field_path(path, "owner_arn"), &value, "^arn:(aws|aws-cn|aws-us-gov|aws-iso|aws-iso-b):(iam|sts)::\\d+:(root|(user\\/[\\w+=/:,.@-]{1,64}|federated-user\\/[\\w+=/:,.@-]{2,32}|assumed-role\\/[\\w+=:,.@-]{1,64}\\/[\\w+=,.@-]{1,64}))$");
+        }
+        if let Some(ref value) = self.subnet_id {
+            errors.check_length(
+                &field_path(path, "subnet_id"),
+                value.chars().count(),
+                Some(15),
+                Some(24),
+            );
+            errors.check_pattern(
+                &field_path(path, "subnet_id"),
+                &value,
+                "^(subnet-[0-9a-f]{8}|subnet-[0-9a-f]{17})$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateEnvironmentEC2Result {
     /// <p>The ID of the environment that was created.</p>
     #[serde(rename = "environmentId")]
@@ -63,6 +126,7 @@ pub struct CreateEnvironmentEC2Result {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEnvironmentMembershipRequest {
     /// <p>The ID of the environment that contains the environment member you want to add.</p>
     #[serde(rename = "environmentId")]
@@ -75,8 +139,19 @@ pub struct CreateEnvironmentMembershipRequest {
     pub user_arn: String,
 }
 
+impl Validate for CreateEnvironmentMembershipRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "environment_id"),
+            &self.environment_id,
+            "^[a-zA-Z0-9]{8,32}$",
+        );
+        errors.check_pattern(&field_path(path, "user_arn"), &self.user_arn, "^arn:(aws|aws-cn|aws-us-gov|aws-iso|aws-iso-b):(iam|sts)::\\d+:(root|(user\\/[\\w+=/:,.@-]{1,64}|federated-user\\/[\\w+=/:,.@-]{2,32}|assumed-role\\/[\\w+=:,.@-]{1,64}\\/[\\w+=,.@-]{1,64}))$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateEnvironmentMembershipResult {
     /// <p>Information about the environment member that was added.</p>
     #[serde(rename = "membership")]
@@ -85,6 +160,7 @@ pub struct CreateEnvironmentMembershipResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEnvironmentMembershipRequest {
     /// <p>The ID of the environment to delete the environment member from.</p>
     #[serde(rename = "environmentId")]
@@ -94,22 +170,45 @@ pub struct DeleteEnvironmentMembershipRequest {
     pub user_arn: String,
 }
 
+impl Validate for DeleteEnvironmentMembershipRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "environment_id"),
+            &self.environment_id,
+            "^[a-zA-Z0-9]{8,32}$",
+        );
+        errors.check_pattern(&field_path(path, "user_arn"), &self.user_arn, "^arn:(aws|aws-cn|aws-us-gov|aws-iso|aws-iso-b):(iam|sts)::\\d+:(root|(user\\/[\\w+=/:,.@-]{1,64}|federated-user\\/[\\w+=/:,.@-]{2,32}|assumed-role\\/[\\w+=:,.@-]{1,64}\\/[\\w+=,.@-]{1,64}))$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteEnvironmentMembershipResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEnvironmentRequest {
     /// <p>The ID of the environment to delete.</p>
     #[serde(rename = "environmentId")]
     pub environment_id: String,
 }
 
+impl Validate for DeleteEnvironmentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "environment_id"),
+            &self.environment_id,
+            "^[a-zA-Z0-9]{8,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteEnvironmentResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentMembershipsRequest {
     /// <p>The ID of the environment to get environment member information about.</p>
     #[serde(rename = "environmentId")]
@@ -133,8 +232,26 @@ pub struct DescribeEnvironmentMembershipsRequest {
     pub user_arn: Option<String>,
 }
 
+impl Validate for DescribeEnvironmentMembershipsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_id {
+            errors.check_pattern(
+                &field_path(path, "environment_id"),
+                &value,
+                "^[a-zA-Z0-9]{8,32}$",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        if let Some(ref value) = self.user_arn {
+            errors.check_pattern(&field_path(path, "user_arn"), &value, "^arn:(aws|aws-cn|aws-us-gov|aws-iso|aws-iso-b):(iam|sts)::\\d+:(root|(user\\/[\\w+=/:,.@-]{1,64}|federated-user\\/[\\w+=/:,.@-]{2,32}|assumed-role\\/[\\w+=:,.@-]{1,64}\\/[\\w+=,.@-]{1,64}))$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEnvironmentMembershipsResult {
     /// <p>Information about the environment members for the environment.</p>
     #[serde(rename = "memberships")]
@@ -147,14 +264,25 @@ pub struct DescribeEnvironmentMembershipsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentStatusRequest {
     /// <p>The ID of the environment to get status information about.</p>
     #[serde(rename = "environmentId")]
     pub environment_id: String,
 }
 
+impl Validate for DescribeEnvironmentStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "environment_id"),
+            &self.environment_id,
+            "^[a-zA-Z0-9]{8,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEnvironmentStatusResult {
     /// <p>Any informational message about the status of the environment.</p>
     #[serde(rename = "message")]
@@ -167,14 +295,30 @@ pub struct DescribeEnvironmentStatusResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentsRequest {
     /// <p>The IDs of individual environments to get information about.</p>
     #[serde(rename = "environmentIds")]
     pub environment_ids: Vec<String>,
 }
 
+impl Validate for DescribeEnvironmentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "environment_ids"),
+            self.environment_ids.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.environment_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "environment_ids"), i);
+            errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9]{8,32}$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEnvironmentsResult {
     /// <p>Information about the environments that are returned.</p>
     #[serde(rename = "environments")]
@@ -184,7 +328,7 @@ pub struct DescribeEnvironmentsResult {
 
 /// <p>Information about an AWS Cloud9 development environment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Environment {
     /// <p>The Amazon Resource Name (ARN) of the environment.</p>
     #[serde(rename = "arn")]
@@ -218,7 +362,7 @@ pub struct Environment {
 
 /// <p>Information about the current creation or deletion lifecycle state of an AWS Cloud9 development environment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnvironmentLifecycle {
     /// <p>If the environment failed to delete, the Amazon Resource Name (ARN) of the related AWS resource.</p>
     #[serde(rename = "failureResource")]
@@ -236,7 +380,7 @@ pub struct EnvironmentLifecycle {
 
 /// <p>Information about an environment member for an AWS Cloud9 development environment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnvironmentMember {
     /// <p>The ID of the environment for the environment member.</p>
     #[serde(rename = "environmentId")]
@@ -261,6 +405,7 @@ pub struct EnvironmentMember {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEnvironmentsRequest {
     /// <p>The maximum number of environments to get identifiers for.</p>
     #[serde(rename = "maxResults")]
@@ -272,8 +417,16 @@ pub struct ListEnvironmentsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListEnvironmentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListEnvironmentsResult {
     /// <p>The list of environment identifiers.</p>
     #[serde(rename = "environmentIds")]
@@ -286,6 +439,7 @@ pub struct ListEnvironmentsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateEnvironmentMembershipRequest {
     /// <p>The ID of the environment for the environment member whose settings you want to change.</p>
     #[serde(rename = "environmentId")]
@@ -298,8 +452,19 @@ pub struct UpdateEnvironmentMembershipRequest {
     pub user_arn: String,
 }
 
+impl Validate for UpdateEnvironmentMembershipRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "environment_id"),
+            &self.environment_id,
+            "^[a-zA-Z0-9]{8,32}$",
+        );
+        errors.check_pattern(&field_path(path, "user_arn"), &self.user_arn, "^arn:(aws|aws-cn|aws-us-gov|aws-iso|aws-iso-b):(iam|sts)::\\d+:(root|(user\\/[\\w+=/:,.@-]{1,64}|federated-user\\/[\\w+=/:,.@-]{2,32}|assumed-role\\/[\\w+=:,.@-]{1,64}\\/[\\w+=,.@-]{1,64}))$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateEnvironmentMembershipResult {
     /// <p>Information about the environment member whose settings were changed.</p>
     #[serde(rename = "membership")]
@@ -308,6 +473,7 @@ pub struct UpdateEnvironmentMembershipResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateEnvironmentRequest {
     /// <p>Any new or replacement description for the environment.</p>
     #[serde(rename = "description")]
@@ -322,8 +488,34 @@ pub struct UpdateEnvironmentRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateEnvironmentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "environment_id"),
+            &self.environment_id,
+            "^[a-zA-Z0-9]{8,32}$",
+        );
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(60),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateEnvironmentResult {}
 
 /// Errors returned by CreateEnvironmentEC2
@@ -374,7 +566,12 @@ impl CreateEnvironmentEC2Error {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -459,7 +656,12 @@ impl CreateEnvironmentMembershipError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -530,7 +732,12 @@ impl DeleteEnvironmentError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteEnvironmentError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -615,7 +822,12 @@ impl DeleteEnvironmentMembershipError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -700,7 +912,12 @@ impl DescribeEnvironmentMembershipsError {
                         DescribeEnvironmentMembershipsError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -777,7 +994,12 @@ impl DescribeEnvironmentStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -850,7 +1072,12 @@ impl DescribeEnvironmentsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -921,7 +1148,12 @@ impl ListEnvironmentsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListEnvironmentsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -992,7 +1224,12 @@ impl UpdateEnvironmentError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateEnvironmentError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1077,7 +1314,12 @@ impl UpdateEnvironmentMembershipError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1164,6 +1406,196 @@ pub trait Cloud9 {
         input: UpdateEnvironmentMembershipRequest,
     ) -> RusotoFuture<UpdateEnvironmentMembershipResult, UpdateEnvironmentMembershipError>;
 }
+const _: Option<&dyn Cloud9> = None;
+impl<'a, T: Cloud9 + ?Sized> Cloud9 for &'a T {
+    fn create_environment_ec2(
+        &self,
+        input: CreateEnvironmentEC2Request,
+    ) -> RusotoFuture<CreateEnvironmentEC2Result, CreateEnvironmentEC2Error> {
+        (**self).create_environment_ec2(input)
+    }
+    fn create_environment_membership(
+        &self,
+        input: CreateEnvironmentMembershipRequest,
+    ) -> RusotoFuture<CreateEnvironmentMembershipResult, CreateEnvironmentMembershipError> {
+        (**self).create_environment_membership(input)
+    }
+    fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> RusotoFuture<DeleteEnvironmentResult, DeleteEnvironmentError> {
+        (**self).delete_environment(input)
+    }
+    fn delete_environment_membership(
+        &self,
+        input: DeleteEnvironmentMembershipRequest,
+    ) -> RusotoFuture<DeleteEnvironmentMembershipResult, DeleteEnvironmentMembershipError> {
+        (**self).delete_environment_membership(input)
+    }
+    fn describe_environment_memberships(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentMembershipsResult, DescribeEnvironmentMembershipsError>
+    {
+        (**self).describe_environment_memberships(input)
+    }
+    fn describe_environment_status(
+        &self,
+        input: DescribeEnvironmentStatusRequest,
+    ) -> RusotoFuture<DescribeEnvironmentStatusResult, DescribeEnvironmentStatusError> {
+        (**self).describe_environment_status(input)
+    }
+    fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentsResult, DescribeEnvironmentsError> {
+        (**self).describe_environments(input)
+    }
+    fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> RusotoFuture<ListEnvironmentsResult, ListEnvironmentsError> {
+        (**self).list_environments(input)
+    }
+    fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> RusotoFuture<UpdateEnvironmentResult, UpdateEnvironmentError> {
+        (**self).update_environment(input)
+    }
+    fn update_environment_membership(
+        &self,
+        input: UpdateEnvironmentMembershipRequest,
+    ) -> RusotoFuture<UpdateEnvironmentMembershipResult, UpdateEnvironmentMembershipError> {
+        (**self).update_environment_membership(input)
+    }
+}
+impl<T: Cloud9 + ?Sized> Cloud9 for Box<T> {
+    fn create_environment_ec2(
+        &self,
+        input: CreateEnvironmentEC2Request,
+    ) -> RusotoFuture<CreateEnvironmentEC2Result, CreateEnvironmentEC2Error> {
+        (**self).create_environment_ec2(input)
+    }
+    fn create_environment_membership(
+        &self,
+        input: CreateEnvironmentMembershipRequest,
+    ) -> RusotoFuture<CreateEnvironmentMembershipResult, CreateEnvironmentMembershipError> {
+        (**self).create_environment_membership(input)
+    }
+    fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> RusotoFuture<DeleteEnvironmentResult, DeleteEnvironmentError> {
+        (**self).delete_environment(input)
+    }
+    fn delete_environment_membership(
+        &self,
+        input: DeleteEnvironmentMembershipRequest,
+    ) -> RusotoFuture<DeleteEnvironmentMembershipResult, DeleteEnvironmentMembershipError> {
+        (**self).delete_environment_membership(input)
+    }
+    fn describe_environment_memberships(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentMembershipsResult, DescribeEnvironmentMembershipsError>
+    {
+        (**self).describe_environment_memberships(input)
+    }
+    fn describe_environment_status(
+        &self,
+        input: DescribeEnvironmentStatusRequest,
+    ) -> RusotoFuture<DescribeEnvironmentStatusResult, DescribeEnvironmentStatusError> {
+        (**self).describe_environment_status(input)
+    }
+    fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentsResult, DescribeEnvironmentsError> {
+        (**self).describe_environments(input)
+    }
+    fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> RusotoFuture<ListEnvironmentsResult, ListEnvironmentsError> {
+        (**self).list_environments(input)
+    }
+    fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> RusotoFuture<UpdateEnvironmentResult, UpdateEnvironmentError> {
+        (**self).update_environment(input)
+    }
+    fn update_environment_membership(
+        &self,
+        input: UpdateEnvironmentMembershipRequest,
+    ) -> RusotoFuture<UpdateEnvironmentMembershipResult, UpdateEnvironmentMembershipError> {
+        (**self).update_environment_membership(input)
+    }
+}
+impl<T: Cloud9 + ?Sized> Cloud9 for ::std::sync::Arc<T> {
+    fn create_environment_ec2(
+        &self,
+        input: CreateEnvironmentEC2Request,
+    ) -> RusotoFuture<CreateEnvironmentEC2Result, CreateEnvironmentEC2Error> {
+        (**self).create_environment_ec2(input)
+    }
+    fn create_environment_membership(
+        &self,
+        input: CreateEnvironmentMembershipRequest,
+    ) -> RusotoFuture<CreateEnvironmentMembershipResult, CreateEnvironmentMembershipError> {
+        (**self).create_environment_membership(input)
+    }
+    fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> RusotoFuture<DeleteEnvironmentResult, DeleteEnvironmentError> {
+        (**self).delete_environment(input)
+    }
+    fn delete_environment_membership(
+        &self,
+        input: DeleteEnvironmentMembershipRequest,
+    ) -> RusotoFuture<DeleteEnvironmentMembershipResult, DeleteEnvironmentMembershipError> {
+        (**self).delete_environment_membership(input)
+    }
+    fn describe_environment_memberships(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentMembershipsResult, DescribeEnvironmentMembershipsError>
+    {
+        (**self).describe_environment_memberships(input)
+    }
+    fn describe_environment_status(
+        &self,
+        input: DescribeEnvironmentStatusRequest,
+    ) -> RusotoFuture<DescribeEnvironmentStatusResult, DescribeEnvironmentStatusError> {
+        (**self).describe_environment_status(input)
+    }
+    fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentsResult, DescribeEnvironmentsError> {
+        (**self).describe_environments(input)
+    }
+    fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> RusotoFuture<ListEnvironmentsResult, ListEnvironmentsError> {
+        (**self).list_environments(input)
+    }
+    fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> RusotoFuture<UpdateEnvironmentResult, UpdateEnvironmentError> {
+        (**self).update_environment(input)
+    }
+    fn update_environment_membership(
+        &self,
+        input: UpdateEnvironmentMembershipRequest,
+    ) -> RusotoFuture<UpdateEnvironmentMembershipResult, UpdateEnvironmentMembershipError> {
+        (**self).update_environment_membership(input)
+    }
+}
 /// A client for the AWS Cloud9 API.
 #[derive(Clone)]
 pub struct Cloud9Client {
@@ -1207,7 +1639,16 @@ impl Cloud9 for Cloud9Client {
         &self,
         input: CreateEnvironmentEC2Request,
     ) -> RusotoFuture<CreateEnvironmentEC2Result, CreateEnvironmentEC2Error> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("CreateEnvironmentEC2");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1219,10 +1660,10 @@ impl Cloud9 for Cloud9Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateEnvironmentEC2Result, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateEnvironmentEC2Result, _>(
+                    response,
+                    "CreateEnvironmentEC2",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1238,7 +1679,16 @@ impl Cloud9 for Cloud9Client {
         &self,
         input: CreateEnvironmentMembershipRequest,
     ) -> RusotoFuture<CreateEnvironmentMembershipResult, CreateEnvironmentMembershipError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("CreateEnvironmentMembership");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1250,10 +1700,10 @@ impl Cloud9 for Cloud9Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateEnvironmentMembershipResult, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateEnvironmentMembershipResult, _>(
+                    response,
+                    "CreateEnvironmentMembership",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(CreateEnvironmentMembershipError::from_response(response))
@@ -1267,7 +1717,16 @@ impl Cloud9 for Cloud9Client {
         &self,
         input: DeleteEnvironmentRequest,
     ) -> RusotoFuture<DeleteEnvironmentResult, DeleteEnvironmentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DeleteEnvironment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1279,10 +1738,10 @@ impl Cloud9 for Cloud9Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteEnvironmentResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteEnvironmentResult, _>(
+                    response,
+                    "DeleteEnvironment",
+                )
             } else {
                 Box::new(
                     response
@@ -1299,7 +1758,16 @@ impl Cloud9 for Cloud9Client {
         &self,
         input: DeleteEnvironmentMembershipRequest,
     ) -> RusotoFuture<DeleteEnvironmentMembershipResult, DeleteEnvironmentMembershipError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DeleteEnvironmentMembership");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1311,10 +1779,10 @@ impl Cloud9 for Cloud9Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteEnvironmentMembershipResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteEnvironmentMembershipResult, _>(
+                    response,
+                    "DeleteEnvironmentMembership",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DeleteEnvironmentMembershipError::from_response(response))
@@ -1329,7 +1797,16 @@ impl Cloud9 for Cloud9Client {
         input: DescribeEnvironmentMembershipsRequest,
     ) -> RusotoFuture<DescribeEnvironmentMembershipsResult, DescribeEnvironmentMembershipsError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DescribeEnvironmentMemberships");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1341,10 +1818,10 @@ impl Cloud9 for Cloud9Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEnvironmentMembershipsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeEnvironmentMembershipsResult, _>(
+                    response,
+                    "DescribeEnvironmentMemberships",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeEnvironmentMembershipsError::from_response(response))
@@ -1358,7 +1835,16 @@ impl Cloud9 for Cloud9Client {
         &self,
         input: DescribeEnvironmentStatusRequest,
     ) -> RusotoFuture<DescribeEnvironmentStatusResult, DescribeEnvironmentStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DescribeEnvironmentStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1370,10 +1856,10 @@ impl Cloud9 for Cloud9Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEnvironmentStatusResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeEnvironmentStatusResult, _>(
+                    response,
+                    "DescribeEnvironmentStatus",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeEnvironmentStatusError::from_response(response))
@@ -1387,7 +1873,16 @@ impl Cloud9 for Cloud9Client {
         &self,
         input: DescribeEnvironmentsRequest,
     ) -> RusotoFuture<DescribeEnvironmentsResult, DescribeEnvironmentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DescribeEnvironments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1399,10 +1894,10 @@ impl Cloud9 for Cloud9Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEnvironmentsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeEnvironmentsResult, _>(
+                    response,
+                    "DescribeEnvironments",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1418,7 +1913,16 @@ impl Cloud9 for Cloud9Client {
         &self,
         input: ListEnvironmentsRequest,
     ) -> RusotoFuture<ListEnvironmentsResult, ListEnvironmentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("ListEnvironments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1430,10 +1934,10 @@ impl Cloud9 for Cloud9Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListEnvironmentsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListEnvironmentsResult, _>(
+                    response,
+                    "ListEnvironments",
+                )
             } else {
                 Box::new(
                     response
@@ -1450,7 +1954,16 @@ impl Cloud9 for Cloud9Client {
         &self,
         input: UpdateEnvironmentRequest,
     ) -> RusotoFuture<UpdateEnvironmentResult, UpdateEnvironmentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("UpdateEnvironment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1462,10 +1975,10 @@ impl Cloud9 for Cloud9Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateEnvironmentResult, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateEnvironmentResult, _>(
+                    response,
+                    "UpdateEnvironment",
+                )
             } else {
                 Box::new(
                     response
@@ -1482,7 +1995,16 @@ impl Cloud9 for Cloud9Client {
         &self,
         input: UpdateEnvironmentMembershipRequest,
     ) -> RusotoFuture<UpdateEnvironmentMembershipResult, UpdateEnvironmentMembershipError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("UpdateEnvironmentMembership");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1494,10 +2016,10 @@ impl Cloud9 for Cloud9Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateEnvironmentMembershipResult, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateEnvironmentMembershipResult, _>(
+                    response,
+                    "UpdateEnvironmentMembership",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(UpdateEnvironmentMembershipError::from_response(response))
@@ -1506,3 +2028,375 @@ impl Cloud9 for Cloud9Client {
         })
     }
 }
+
+/// A mock of the AWS Cloud9 API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloud9 {
+    create_environment_ec2: Option<
+        Box<
+            dyn Fn(
+                    CreateEnvironmentEC2Request,
+                ) -> ::std::result::Result<
+                    CreateEnvironmentEC2Result,
+                    RusotoError<CreateEnvironmentEC2Error>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_environment_membership: Option<
+        Box<
+            dyn Fn(
+                    CreateEnvironmentMembershipRequest,
+                ) -> ::std::result::Result<
+                    CreateEnvironmentMembershipResult,
+                    RusotoError<CreateEnvironmentMembershipError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_environment: Option<
+        Box<
+            dyn Fn(
+                    DeleteEnvironmentRequest,
+                ) -> ::std::result::Result<
+                    DeleteEnvironmentResult,
+                    RusotoError<DeleteEnvironmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_environment_membership: Option<
+        Box<
+            dyn Fn(
+                    DeleteEnvironmentMembershipRequest,
+                ) -> ::std::result::Result<
+                    DeleteEnvironmentMembershipResult,
+                    RusotoError<DeleteEnvironmentMembershipError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_environment_memberships: Option<
+        Box<
+            dyn Fn(
+                    DescribeEnvironmentMembershipsRequest,
+                ) -> ::std::result::Result<
+                    DescribeEnvironmentMembershipsResult,
+                    RusotoError<DescribeEnvironmentMembershipsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_environment_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeEnvironmentStatusRequest,
+                ) -> ::std::result::Result<
+                    DescribeEnvironmentStatusResult,
+                    RusotoError<DescribeEnvironmentStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_environments: Option<
+        Box<
+            dyn Fn(
+                    DescribeEnvironmentsRequest,
+                ) -> ::std::result::Result<
+                    DescribeEnvironmentsResult,
+                    RusotoError<DescribeEnvironmentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_environments: Option<
+        Box<
+            dyn Fn(
+                    ListEnvironmentsRequest,
+                ) -> ::std::result::Result<
+                    ListEnvironmentsResult,
+                    RusotoError<ListEnvironmentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_environment: Option<
+        Box<
+            dyn Fn(
+                    UpdateEnvironmentRequest,
+                ) -> ::std::result::Result<
+                    UpdateEnvironmentResult,
+                    RusotoError<UpdateEnvironmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_environment_membership: Option<
+        Box<
+            dyn Fn(
+                    UpdateEnvironmentMembershipRequest,
+                ) -> ::std::result::Result<
+                    UpdateEnvironmentMembershipResult,
+                    RusotoError<UpdateEnvironmentMembershipError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloud9 {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloud9 {
+        Default::default()
+    }
+
+    /// Answers `create_environment_ec2` with what `handler` returns for its input.
+    pub fn with_create_environment_ec2<F>(mut self, handler: F) -> MockCloud9
+    where
+        F: Fn(
+                CreateEnvironmentEC2Request,
+            ) -> ::std::result::Result<
+                CreateEnvironmentEC2Result,
+                RusotoError<CreateEnvironmentEC2Error>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_environment_ec2 = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_environment_membership` with what `handler` returns for its input.
+    pub fn with_create_environment_membership<F>(mut self, handler: F) -> MockCloud9
+    where
+        F: Fn(
+                CreateEnvironmentMembershipRequest,
+            ) -> ::std::result::Result<
+                CreateEnvironmentMembershipResult,
+                RusotoError<CreateEnvironmentMembershipError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_environment_membership = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_environment` with what `handler` returns for its input.
+    pub fn with_delete_environment<F>(mut self, handler: F) -> MockCloud9
+    where
+        F: Fn(
+                DeleteEnvironmentRequest,
+            ) -> ::std::result::Result<
+                DeleteEnvironmentResult,
+                RusotoError<DeleteEnvironmentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_environment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_environment_membership` with what `handler` returns for its input.
+    pub fn with_delete_environment_membership<F>(mut self, handler: F) -> MockCloud9
+    where
+        F: Fn(
+                DeleteEnvironmentMembershipRequest,
+            ) -> ::std::result::Result<
+                DeleteEnvironmentMembershipResult,
+                RusotoError<DeleteEnvironmentMembershipError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_environment_membership = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_environment_memberships` with what `handler` returns for its input.
+    pub fn with_describe_environment_memberships<F>(mut self, handler: F) -> MockCloud9
+    where
+        F: Fn(
+                DescribeEnvironmentMembershipsRequest,
+            ) -> ::std::result::Result<
+                DescribeEnvironmentMembershipsResult,
+                RusotoError<DescribeEnvironmentMembershipsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_environment_memberships = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_environment_status` with what `handler` returns for its input.
+    pub fn with_describe_environment_status<F>(mut self, handler: F) -> MockCloud9
+    where
+        F: Fn(
+                DescribeEnvironmentStatusRequest,
+            ) -> ::std::result::Result<
+                DescribeEnvironmentStatusResult,
+                RusotoError<DescribeEnvironmentStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_environment_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_environments` with what `handler` returns for its input.
+    pub fn with_describe_environments<F>(mut self, handler: F) -> MockCloud9
+    where
+        F: Fn(
+                DescribeEnvironmentsRequest,
+            ) -> ::std::result::Result<
+                DescribeEnvironmentsResult,
+                RusotoError<DescribeEnvironmentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_environments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_environments` with what `handler` returns for its input.
+    pub fn with_list_environments<F>(mut self, handler: F) -> MockCloud9
+    where
+        F: Fn(
+                ListEnvironmentsRequest,
+            )
+                -> ::std::result::Result<ListEnvironmentsResult, RusotoError<ListEnvironmentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_environments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_environment` with what `handler` returns for its input.
+    pub fn with_update_environment<F>(mut self, handler: F) -> MockCloud9
+    where
+        F: Fn(
+                UpdateEnvironmentRequest,
+            ) -> ::std::result::Result<
+                UpdateEnvironmentResult,
+                RusotoError<UpdateEnvironmentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_environment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_environment_membership` with what `handler` returns for its input.
+    pub fn with_update_environment_membership<F>(mut self, handler: F) -> MockCloud9
+    where
+        F: Fn(
+                UpdateEnvironmentMembershipRequest,
+            ) -> ::std::result::Result<
+                UpdateEnvironmentMembershipResult,
+                RusotoError<UpdateEnvironmentMembershipError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_environment_membership = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Cloud9 for MockCloud9 {
+    fn create_environment_ec2(
+        &self,
+        input: CreateEnvironmentEC2Request,
+    ) -> RusotoFuture<CreateEnvironmentEC2Result, CreateEnvironmentEC2Error> {
+        match self.create_environment_ec2 {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloud9 has no handler for create_environment_ec2"),
+        }
+    }
+    fn create_environment_membership(
+        &self,
+        input: CreateEnvironmentMembershipRequest,
+    ) -> RusotoFuture<CreateEnvironmentMembershipResult, CreateEnvironmentMembershipError> {
+        match self.create_environment_membership {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloud9 has no handler for create_environment_membership"),
+        }
+    }
+    fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> RusotoFuture<DeleteEnvironmentResult, DeleteEnvironmentError> {
+        match self.delete_environment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloud9 has no handler for delete_environment"),
+        }
+    }
+    fn delete_environment_membership(
+        &self,
+        input: DeleteEnvironmentMembershipRequest,
+    ) -> RusotoFuture<DeleteEnvironmentMembershipResult, DeleteEnvironmentMembershipError> {
+        match self.delete_environment_membership {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloud9 has no handler for delete_environment_membership"),
+        }
+    }
+    fn describe_environment_memberships(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentMembershipsResult, DescribeEnvironmentMembershipsError>
+    {
+        match self.describe_environment_memberships {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloud9 has no handler for describe_environment_memberships"),
+        }
+    }
+    fn describe_environment_status(
+        &self,
+        input: DescribeEnvironmentStatusRequest,
+    ) -> RusotoFuture<DescribeEnvironmentStatusResult, DescribeEnvironmentStatusError> {
+        match self.describe_environment_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloud9 has no handler for describe_environment_status"),
+        }
+    }
+    fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentsResult, DescribeEnvironmentsError> {
+        match self.describe_environments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloud9 has no handler for describe_environments"),
+        }
+    }
+    fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> RusotoFuture<ListEnvironmentsResult, ListEnvironmentsError> {
+        match self.list_environments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloud9 has no handler for list_environments"),
+        }
+    }
+    fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> RusotoFuture<UpdateEnvironmentResult, UpdateEnvironmentError> {
+        match self.update_environment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloud9 has no handler for update_environment"),
+        }
+    }
+    fn update_environment_membership(
+        &self,
+        input: UpdateEnvironmentMembershipRequest,
+    ) -> RusotoFuture<UpdateEnvironmentMembershipResult, UpdateEnvironmentMembershipError> {
+        match self.update_environment_membership {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloud9 has no handler for update_environment_membership"),
+        }
+    }
+}
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddFacetToObjectRequest {
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where the object resides. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
@@ -40,11 +42,25 @@ pub struct AddFacetToObjectRequest {
     pub schema_facet: SchemaFacet,
 }
 
+impl Validate for AddFacetToObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.object_attribute_list {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "object_attribute_list"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.schema_facet
+            .validate_at(&field_path(path, "schema_facet"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddFacetToObjectResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplySchemaRequest {
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> into which the schema is copied. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
@@ -55,7 +71,7 @@ pub struct ApplySchemaRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApplySchemaResponse {
     /// <p>The applied schema ARN that is associated with the copied schema in the <a>Directory</a>. You can use this ARN to describe the schema information applied on this directory. For more information, see <a>arns</a>.</p>
     #[serde(rename = "AppliedSchemaArn")]
@@ -68,6 +84,7 @@ pub struct ApplySchemaResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachObjectRequest {
     /// <p>The child object reference to be attached to the object.</p>
     #[serde(rename = "ChildReference")]
@@ -83,8 +100,24 @@ pub struct AttachObjectRequest {
     pub parent_reference: ObjectReference,
 }
 
+impl Validate for AttachObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "link_name"),
+            self.link_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "link_name"),
+            &self.link_name,
+            "[^\\/\\[\\]\\(\\):\\{\\}#@!?\\s\\\\;]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachObjectResponse {
     /// <p>The attached <code>ObjectIdentifier</code>, which is the child <code>ObjectIdentifier</code>.</p>
     #[serde(rename = "AttachedObjectIdentifier")]
@@ -93,6 +126,7 @@ pub struct AttachObjectResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachPolicyRequest {
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where both objects reside. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
@@ -106,10 +140,11 @@ pub struct AttachPolicyRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachPolicyResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachToIndexRequest {
     /// <p>The Amazon Resource Name (ARN) of the directory where the object and index exist.</p>
     #[serde(rename = "DirectoryArn")]
@@ -123,7 +158,7 @@ pub struct AttachToIndexRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachToIndexResponse {
     /// <p>The <code>ObjectIdentifier</code> of the object that was attached to the index.</p>
     #[serde(rename = "AttachedObjectIdentifier")]
@@ -132,6 +167,7 @@ pub struct AttachToIndexResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachTypedLinkRequest {
     /// <p>A set of attributes that are associated with the typed link.</p>
     #[serde(rename = "Attributes")]
@@ -150,8 +186,19 @@ pub struct AttachTypedLinkRequest {
     pub typed_link_facet: TypedLinkSchemaAndFacetName,
 }
 
+impl Validate for AttachTypedLinkRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.typed_link_facet
+            .validate_at(&field_path(path, "typed_link_facet"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachTypedLinkResponse {
     /// <p>Returns a typed link specifier as output.</p>
     #[serde(rename = "TypedLinkSpecifier")]
@@ -173,6 +220,29 @@ pub struct AttributeKey {
     pub schema_arn: String,
 }
 
+impl Validate for AttributeKey {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "facet_name"),
+            self.facet_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "facet_name"),
+            &self.facet_name,
+            "^[a-zA-Z0-9._-]*$",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(230),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._:-]*$");
+    }
+}
+
 /// <p>The combination of an attribute key and an attribute value.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributeKeyAndValue {
@@ -184,6 +254,12 @@ pub struct AttributeKeyAndValue {
     pub value: TypedAttributeValue,
 }
 
+impl Validate for AttributeKeyAndValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.key.validate_at(&field_path(path, "key"), errors);
+    }
+}
+
 /// <p>Identifies the attribute name and value for a typed link.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributeNameAndValue {
@@ -195,8 +271,25 @@ pub struct AttributeNameAndValue {
     pub value: TypedAttributeValue,
 }
 
+impl Validate for AttributeNameAndValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute_name"),
+            self.attribute_name.chars().count(),
+            Some(1),
+            Some(230),
+        );
+        errors.check_pattern(
+            &field_path(path, "attribute_name"),
+            &self.attribute_name,
+            "^[a-zA-Z0-9._:-]*$",
+        );
+    }
+}
+
 /// <p>Represents the output of a batch add facet to object operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchAddFacetToObject {
     /// <p>The attributes to set on the object.</p>
     #[serde(rename = "ObjectAttributeList")]
@@ -209,13 +302,25 @@ pub struct BatchAddFacetToObject {
     pub schema_facet: SchemaFacet,
 }
 
+impl Validate for BatchAddFacetToObject {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.object_attribute_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "object_attribute_list"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.schema_facet
+            .validate_at(&field_path(path, "schema_facet"), errors);
+    }
+}
+
 /// <p>The result of a batch add facet to object operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchAddFacetToObjectResponse {}
 
 /// <p>Represents the output of an <a>AttachObject</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchAttachObject {
     /// <p>The child object reference that is to be attached to the object.</p>
     #[serde(rename = "ChildReference")]
@@ -228,9 +333,25 @@ pub struct BatchAttachObject {
     pub parent_reference: ObjectReference,
 }
 
+impl Validate for BatchAttachObject {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "link_name"),
+            self.link_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "link_name"),
+            &self.link_name,
+            "[^\\/\\[\\]\\(\\):\\{\\}#@!?\\s\\\\;]+",
+        );
+    }
+}
+
 /// <p>Represents the output batch <a>AttachObject</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchAttachObjectResponse {
     /// <p>The <code>ObjectIdentifier</code> of the object that has been attached.</p>
     #[serde(rename = "attachedObjectIdentifier")]
@@ -240,6 +361,7 @@ pub struct BatchAttachObjectResponse {
 
 /// <p>Attaches a policy object to a regular object inside a <a>BatchRead</a> operation. For more information, see <a>AttachPolicy</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchAttachPolicy {
     /// <p>The reference that identifies the object to which the policy will be attached.</p>
     #[serde(rename = "ObjectReference")]
@@ -251,11 +373,12 @@ pub struct BatchAttachPolicy {
 
 /// <p>Represents the output of an <a>AttachPolicy</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchAttachPolicyResponse {}
 
 /// <p>Attaches the specified object to the specified index inside a <a>BatchRead</a> operation. For more information, see <a>AttachToIndex</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchAttachToIndex {
     /// <p>A reference to the index that you are attaching the object to.</p>
     #[serde(rename = "IndexReference")]
@@ -267,7 +390,7 @@ pub struct BatchAttachToIndex {
 
 /// <p>Represents the output of a <a>AttachToIndex</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchAttachToIndexResponse {
     /// <p>The <code>ObjectIdentifier</code> of the object that was attached to the index.</p>
     #[serde(rename = "AttachedObjectIdentifier")]
@@ -277,6 +400,7 @@ pub struct BatchAttachToIndexResponse {
 
 /// <p>Attaches a typed link to a specified source and target object inside a <a>BatchRead</a> operation. For more information, see <a>AttachTypedLink</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchAttachTypedLink {
     /// <p>A set of attributes that are associated with the typed link.</p>
     #[serde(rename = "Attributes")]
@@ -292,9 +416,20 @@ pub struct BatchAttachTypedLink {
     pub typed_link_facet: TypedLinkSchemaAndFacetName,
 }
 
+impl Validate for BatchAttachTypedLink {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.typed_link_facet
+            .validate_at(&field_path(path, "typed_link_facet"), errors);
+    }
+}
+
 /// <p>Represents the output of a <a>AttachTypedLink</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchAttachTypedLinkResponse {
     /// <p>Returns a typed link specifier as output.</p>
     #[serde(rename = "TypedLinkSpecifier")]
@@ -304,6 +439,7 @@ pub struct BatchAttachTypedLinkResponse {
 
 /// <p>Creates an index object inside of a <a>BatchRead</a> operation. For more information, see <a>CreateIndex</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchCreateIndex {
     /// <p>The batch reference name. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/transaction_support.html">Transaction Support</a> for more information.</p>
     #[serde(rename = "BatchReferenceName")]
@@ -325,9 +461,35 @@ pub struct BatchCreateIndex {
     pub parent_reference: Option<ObjectReference>,
 }
 
+impl Validate for BatchCreateIndex {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.link_name {
+            errors.check_length(
+                &field_path(path, "link_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "link_name"),
+                &value,
+                "[^\\/\\[\\]\\(\\):\\{\\}#@!?\\s\\\\;]+",
+            );
+        }
+        for (i, item) in self.ordered_indexed_attribute_list.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "ordered_indexed_attribute_list"),
+                i
+            );
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>CreateIndex</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchCreateIndexResponse {
     /// <p>The <code>ObjectIdentifier</code> of the index created by this operation.</p>
     #[serde(rename = "ObjectIdentifier")]
@@ -337,6 +499,7 @@ pub struct BatchCreateIndexResponse {
 
 /// <p>Represents the output of a <a>CreateObject</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchCreateObject {
     /// <p>The batch reference name. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/transaction_support.html">Transaction Support</a> for more information.</p>
     #[serde(rename = "BatchReferenceName")]
@@ -358,9 +521,35 @@ pub struct BatchCreateObject {
     pub schema_facet: Vec<SchemaFacet>,
 }
 
+impl Validate for BatchCreateObject {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.link_name {
+            errors.check_length(
+                &field_path(path, "link_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "link_name"),
+                &value,
+                "[^\\/\\[\\]\\(\\):\\{\\}#@!?\\s\\\\;]+",
+            );
+        }
+        for (i, item) in self.object_attribute_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "object_attribute_list"), i);
+            item.validate_at(&item_path, errors);
+        }
+        for (i, item) in self.schema_facet.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "schema_facet"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>CreateObject</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchCreateObjectResponse {
     /// <p>The ID that is associated with the object.</p>
     #[serde(rename = "ObjectIdentifier")]
@@ -370,6 +559,7 @@ pub struct BatchCreateObjectResponse {
 
 /// <p>Represents the output of a <a>DeleteObject</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteObject {
     /// <p>The reference that identifies the object.</p>
     #[serde(rename = "ObjectReference")]
@@ -378,11 +568,12 @@ pub struct BatchDeleteObject {
 
 /// <p>Represents the output of a <a>DeleteObject</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDeleteObjectResponse {}
 
 /// <p>Detaches the specified object from the specified index inside a <a>BatchRead</a> operation. For more information, see <a>DetachFromIndex</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDetachFromIndex {
     /// <p>A reference to the index object.</p>
     #[serde(rename = "IndexReference")]
@@ -394,7 +585,7 @@ pub struct BatchDetachFromIndex {
 
 /// <p>Represents the output of a <a>DetachFromIndex</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetachFromIndexResponse {
     /// <p>The <code>ObjectIdentifier</code> of the object that was detached from the index.</p>
     #[serde(rename = "DetachedObjectIdentifier")]
@@ -404,6 +595,7 @@ pub struct BatchDetachFromIndexResponse {
 
 /// <p>Represents the output of a <a>DetachObject</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDetachObject {
     /// <p>The batch reference name. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/transaction_support.html">Transaction Support</a> for more information.</p>
     #[serde(rename = "BatchReferenceName")]
@@ -417,9 +609,25 @@ pub struct BatchDetachObject {
     pub parent_reference: ObjectReference,
 }
 
+impl Validate for BatchDetachObject {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "link_name"),
+            self.link_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "link_name"),
+            &self.link_name,
+            "[^\\/\\[\\]\\(\\):\\{\\}#@!?\\s\\\\;]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <a>DetachObject</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetachObjectResponse {
     /// <p>The <code>ObjectIdentifier</code> of the detached object.</p>
     #[serde(rename = "detachedObjectIdentifier")]
@@ -429,6 +637,7 @@ pub struct BatchDetachObjectResponse {
 
 /// <p>Detaches the specified policy from the specified directory inside a <a>BatchWrite</a> operation. For more information, see <a>DetachPolicy</a> and <a>BatchWriteRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDetachPolicy {
     /// <p>Reference that identifies the object whose policy object will be detached.</p>
     #[serde(rename = "ObjectReference")]
@@ -440,24 +649,33 @@ pub struct BatchDetachPolicy {
 
 /// <p>Represents the output of a <a>DetachPolicy</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetachPolicyResponse {}
 
 /// <p>Detaches a typed link from a specified source and target object inside a <a>BatchRead</a> operation. For more information, see <a>DetachTypedLink</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDetachTypedLink {
     /// <p>Used to accept a typed link specifier as input.</p>
     #[serde(rename = "TypedLinkSpecifier")]
     pub typed_link_specifier: TypedLinkSpecifier,
 }
 
+impl Validate for BatchDetachTypedLink {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.typed_link_specifier
+            .validate_at(&field_path(path, "typed_link_specifier"), errors);
+    }
+}
+
 /// <p>Represents the output of a <a>DetachTypedLink</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetachTypedLinkResponse {}
 
 /// <p>Retrieves attributes that are associated with a typed link inside a <a>BatchRead</a> operation. For more information, see <a>GetLinkAttributes</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetLinkAttributes {
     /// <p>A list of attribute names whose values will be retrieved.</p>
     #[serde(rename = "AttributeNames")]
@@ -467,9 +685,21 @@ pub struct BatchGetLinkAttributes {
     pub typed_link_specifier: TypedLinkSpecifier,
 }
 
+impl Validate for BatchGetLinkAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attribute_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(230));
+            errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9._:-]*$");
+        }
+        self.typed_link_specifier
+            .validate_at(&field_path(path, "typed_link_specifier"), errors);
+    }
+}
+
 /// <p>Represents the output of a <a>GetLinkAttributes</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetLinkAttributesResponse {
     /// <p>The attributes that are associated with the typed link.</p>
     #[serde(rename = "Attributes")]
@@ -479,6 +709,7 @@ pub struct BatchGetLinkAttributesResponse {
 
 /// <p>Retrieves attributes within a facet that are associated with an object inside an <a>BatchRead</a> operation. For more information, see <a>GetObjectAttributes</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetObjectAttributes {
     /// <p>List of attribute names whose values will be retrieved.</p>
     #[serde(rename = "AttributeNames")]
@@ -491,9 +722,21 @@ pub struct BatchGetObjectAttributes {
     pub schema_facet: SchemaFacet,
 }
 
+impl Validate for BatchGetObjectAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attribute_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(230));
+            errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9._:-]*$");
+        }
+        self.schema_facet
+            .validate_at(&field_path(path, "schema_facet"), errors);
+    }
+}
+
 /// <p>Represents the output of a <a>GetObjectAttributes</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetObjectAttributesResponse {
     /// <p>The attribute values that are associated with an object.</p>
     #[serde(rename = "Attributes")]
@@ -503,6 +746,7 @@ pub struct BatchGetObjectAttributesResponse {
 
 /// <p>Retrieves metadata about an object inside a <a>BatchRead</a> operation. For more information, see <a>GetObjectInformation</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetObjectInformation {
     /// <p>A reference to the object.</p>
     #[serde(rename = "ObjectReference")]
@@ -511,7 +755,7 @@ pub struct BatchGetObjectInformation {
 
 /// <p>Represents the output of a <a>GetObjectInformation</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetObjectInformationResponse {
     /// <p>The <code>ObjectIdentifier</code> of the specified object.</p>
     #[serde(rename = "ObjectIdentifier")]
@@ -525,6 +769,7 @@ pub struct BatchGetObjectInformationResponse {
 
 /// <p>Lists indices attached to an object inside a <a>BatchRead</a> operation. For more information, see <a>ListAttachedIndices</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchListAttachedIndices {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -539,9 +784,17 @@ pub struct BatchListAttachedIndices {
     pub target_reference: ObjectReference,
 }
 
+impl Validate for BatchListAttachedIndices {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>ListAttachedIndices</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchListAttachedIndicesResponse {
     /// <p>The indices attached to the specified object.</p>
     #[serde(rename = "IndexAttachments")]
@@ -555,6 +808,7 @@ pub struct BatchListAttachedIndicesResponse {
 
 /// <p>Returns a paginated list of all the incoming <a>TypedLinkSpecifier</a> information for an object inside a <a>BatchRead</a> operation. For more information, see <a>ListIncomingTypedLinks</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchListIncomingTypedLinks {
     /// <p>Provides range filters for multiple attributes. When providing ranges to typed link selection, any inexact ranges must be specified at the end. Any attributes that do not have a range specified are presumed to match the entire range.</p>
     #[serde(rename = "FilterAttributeRanges")]
@@ -577,9 +831,26 @@ pub struct BatchListIncomingTypedLinks {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for BatchListIncomingTypedLinks {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter_attribute_ranges {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filter_attribute_ranges"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.filter_typed_link {
+            value.validate_at(&field_path(path, "filter_typed_link"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>ListIncomingTypedLinks</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchListIncomingTypedLinksResponse {
     /// <p>Returns one or more typed link specifiers as output.</p>
     #[serde(rename = "LinkSpecifiers")]
@@ -593,6 +864,7 @@ pub struct BatchListIncomingTypedLinksResponse {
 
 /// <p>Lists objects attached to the specified index inside a <a>BatchRead</a> operation. For more information, see <a>ListIndex</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchListIndex {
     /// <p>The reference to the index to list.</p>
     #[serde(rename = "IndexReference")]
@@ -611,9 +883,23 @@ pub struct BatchListIndex {
     pub ranges_on_indexed_values: Option<Vec<ObjectAttributeRange>>,
 }
 
+impl Validate for BatchListIndex {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.ranges_on_indexed_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "ranges_on_indexed_values"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>ListIndex</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchListIndexResponse {
     /// <p>The objects and indexed values attached to the index.</p>
     #[serde(rename = "IndexAttachments")]
@@ -627,6 +913,7 @@ pub struct BatchListIndexResponse {
 
 /// <p>Represents the output of a <a>ListObjectAttributes</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchListObjectAttributes {
     /// <p>Used to filter the list of object attributes that are associated with a certain facet.</p>
     #[serde(rename = "FacetFilter")]
@@ -645,9 +932,20 @@ pub struct BatchListObjectAttributes {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for BatchListObjectAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.facet_filter {
+            value.validate_at(&field_path(path, "facet_filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>ListObjectAttributes</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchListObjectAttributesResponse {
     /// <p>The attributes map that is associated with the object. <code>AttributeArn</code> is the key; attribute value is the value.</p>
     #[serde(rename = "Attributes")]
@@ -661,6 +959,7 @@ pub struct BatchListObjectAttributesResponse {
 
 /// <p>Represents the output of a <a>ListObjectChildren</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchListObjectChildren {
     /// <p>Maximum number of items to be retrieved in a single call. This is an approximate number.</p>
     #[serde(rename = "MaxResults")]
@@ -675,9 +974,17 @@ pub struct BatchListObjectChildren {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for BatchListObjectChildren {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>ListObjectChildren</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchListObjectChildrenResponse {
     /// <p>The children structure, which is a map with the key as the <code>LinkName</code> and <code>ObjectIdentifier</code> as the value.</p>
     #[serde(rename = "Children")]
@@ -691,6 +998,7 @@ pub struct BatchListObjectChildrenResponse {
 
 /// <p>Retrieves all available parent paths for any object type such as node, leaf node, policy node, and index node objects inside a <a>BatchRead</a> operation. For more information, see <a>ListObjectParentPaths</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchListObjectParentPaths {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -705,9 +1013,17 @@ pub struct BatchListObjectParentPaths {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for BatchListObjectParentPaths {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>ListObjectParentPaths</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchListObjectParentPathsResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -720,6 +1036,7 @@ pub struct BatchListObjectParentPathsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchListObjectParents {
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -731,8 +1048,16 @@ pub struct BatchListObjectParents {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for BatchListObjectParents {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchListObjectParentsResponse {
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -744,6 +1069,7 @@ pub struct BatchListObjectParentsResponse {
 
 /// <p>Returns policies attached to an object in pagination fashion inside a <a>BatchRead</a> operation. For more information, see <a>ListObjectPolicies</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchListObjectPolicies {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -758,9 +1084,17 @@ pub struct BatchListObjectPolicies {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for BatchListObjectPolicies {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>ListObjectPolicies</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchListObjectPoliciesResponse {
     /// <p>A list of policy <code>ObjectIdentifiers</code>, that are attached to the object.</p>
     #[serde(rename = "AttachedPolicyIds")]
@@ -774,6 +1108,7 @@ pub struct BatchListObjectPoliciesResponse {
 
 /// <p>Returns a paginated list of all the outgoing <a>TypedLinkSpecifier</a> information for an object inside a <a>BatchRead</a> operation. For more information, see <a>ListOutgoingTypedLinks</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchListOutgoingTypedLinks {
     /// <p>Provides range filters for multiple attributes. When providing ranges to typed link selection, any inexact ranges must be specified at the end. Any attributes that do not have a range specified are presumed to match the entire range.</p>
     #[serde(rename = "FilterAttributeRanges")]
@@ -796,9 +1131,26 @@ pub struct BatchListOutgoingTypedLinks {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for BatchListOutgoingTypedLinks {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter_attribute_ranges {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filter_attribute_ranges"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.filter_typed_link {
+            value.validate_at(&field_path(path, "filter_typed_link"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>ListOutgoingTypedLinks</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchListOutgoingTypedLinksResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -812,6 +1164,7 @@ pub struct BatchListOutgoingTypedLinksResponse {
 
 /// <p>Returns all of the <code>ObjectIdentifiers</code> to which a given policy is attached inside a <a>BatchRead</a> operation. For more information, see <a>ListPolicyAttachments</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchListPolicyAttachments {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -826,9 +1179,17 @@ pub struct BatchListPolicyAttachments {
     pub policy_reference: ObjectReference,
 }
 
+impl Validate for BatchListPolicyAttachments {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>ListPolicyAttachments</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchListPolicyAttachmentsResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -842,6 +1203,7 @@ pub struct BatchListPolicyAttachmentsResponse {
 
 /// <p>Lists all policies from the root of the Directory to the object specified inside a <a>BatchRead</a> operation. For more information, see <a>LookupPolicy</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchLookupPolicy {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -856,9 +1218,17 @@ pub struct BatchLookupPolicy {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for BatchLookupPolicy {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <a>LookupPolicy</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchLookupPolicyResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -872,7 +1242,7 @@ pub struct BatchLookupPolicyResponse {
 
 /// <p>The batch read exception structure, which contains the exception type and message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchReadException {
     /// <p>An exception message that is associated with the failure.</p>
     #[serde(rename = "Message")]
@@ -886,6 +1256,7 @@ pub struct BatchReadException {
 
 /// <p>Represents the output of a <code>BatchRead</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchReadOperation {
     /// <p>Retrieves attributes that are associated with a typed link.</p>
     #[serde(rename = "GetLinkAttributes")]
@@ -944,9 +1315,53 @@ pub struct BatchReadOperation {
     pub lookup_policy: Option<BatchLookupPolicy>,
 }
 
+impl Validate for BatchReadOperation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.get_link_attributes {
+            value.validate_at(&field_path(path, "get_link_attributes"), errors);
+        }
+        if let Some(ref value) = self.get_object_attributes {
+            value.validate_at(&field_path(path, "get_object_attributes"), errors);
+        }
+        if let Some(ref value) = self.list_attached_indices {
+            value.validate_at(&field_path(path, "list_attached_indices"), errors);
+        }
+        if let Some(ref value) = self.list_incoming_typed_links {
+            value.validate_at(&field_path(path, "list_incoming_typed_links"), errors);
+        }
+        if let Some(ref value) = self.list_index {
+            value.validate_at(&field_path(path, "list_index"), errors);
+        }
+        if let Some(ref value) = self.list_object_attributes {
+            value.validate_at(&field_path(path, "list_object_attributes"), errors);
+        }
+        if let Some(ref value) = self.list_object_children {
+            value.validate_at(&field_path(path, "list_object_children"), errors);
+        }
+        if let Some(ref value) = self.list_object_parent_paths {
+            value.validate_at(&field_path(path, "list_object_parent_paths"), errors);
+        }
+        if let Some(ref value) = self.list_object_parents {
+            value.validate_at(&field_path(path, "list_object_parents"), errors);
+        }
+        if let Some(ref value) = self.list_object_policies {
+            value.validate_at(&field_path(path, "list_object_policies"), errors);
+        }
+        if let Some(ref value) = self.list_outgoing_typed_links {
+            value.validate_at(&field_path(path, "list_outgoing_typed_links"), errors);
+        }
+        if let Some(ref value) = self.list_policy_attachments {
+            value.validate_at(&field_path(path, "list_policy_attachments"), errors);
+        }
+        if let Some(ref value) = self.lookup_policy {
+            value.validate_at(&field_path(path, "lookup_policy"), errors);
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>BatchRead</code> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchReadOperationResponse {
     /// <p>Identifies which operation in a batch has failed.</p>
     #[serde(rename = "ExceptionResponse")]
@@ -959,6 +1374,7 @@ pub struct BatchReadOperationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchReadRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -972,8 +1388,17 @@ pub struct BatchReadRequest {
     pub operations: Vec<BatchReadOperation>,
 }
 
+impl Validate for BatchReadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.operations.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "operations"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchReadResponse {
     /// <p>A list of all the responses for each batch read.</p>
     #[serde(rename = "Responses")]
@@ -983,7 +1408,7 @@ pub struct BatchReadResponse {
 
 /// <p>Represents the output of a <code>BatchRead</code> success response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchReadSuccessfulResponse {
     /// <p>The list of attributes to retrieve from the typed link.</p>
     #[serde(rename = "GetLinkAttributes")]
@@ -1044,6 +1469,7 @@ pub struct BatchReadSuccessfulResponse {
 
 /// <p>A batch operation to remove a facet from an object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchRemoveFacetFromObject {
     /// <p>A reference to the object whose facet will be removed.</p>
     #[serde(rename = "ObjectReference")]
@@ -1053,13 +1479,21 @@ pub struct BatchRemoveFacetFromObject {
     pub schema_facet: SchemaFacet,
 }
 
+impl Validate for BatchRemoveFacetFromObject {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.schema_facet
+            .validate_at(&field_path(path, "schema_facet"), errors);
+    }
+}
+
 /// <p>An empty result that represents success.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchRemoveFacetFromObjectResponse {}
 
 /// <p>Updates a given typed link’s attributes inside a <a>BatchRead</a> operation. Attributes to be updated must not contribute to the typed link’s identity, as defined by its <code>IdentityAttributeOrder</code>. For more information, see <a>UpdateLinkAttributes</a> and <a>BatchReadRequest$Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchUpdateLinkAttributes {
     /// <p>The attributes update structure.</p>
     #[serde(rename = "AttributeUpdates")]
@@ -1069,13 +1503,25 @@ pub struct BatchUpdateLinkAttributes {
     pub typed_link_specifier: TypedLinkSpecifier,
 }
 
+impl Validate for BatchUpdateLinkAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attribute_updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.typed_link_specifier
+            .validate_at(&field_path(path, "typed_link_specifier"), errors);
+    }
+}
+
 /// <p>Represents the output of a <a>UpdateLinkAttributes</a> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchUpdateLinkAttributesResponse {}
 
 /// <p>Represents the output of a <code>BatchUpdate</code> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchUpdateObjectAttributes {
     /// <p>Attributes update structure.</p>
     #[serde(rename = "AttributeUpdates")]
@@ -1085,9 +1531,18 @@ pub struct BatchUpdateObjectAttributes {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for BatchUpdateObjectAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attribute_updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>BatchUpdate</code> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchUpdateObjectAttributesResponse {
     /// <p>ID that is associated with the object.</p>
     #[serde(rename = "ObjectIdentifier")]
@@ -1097,6 +1552,7 @@ pub struct BatchUpdateObjectAttributesResponse {
 
 /// <p>Represents the output of a <code>BatchWrite</code> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchWriteOperation {
     /// <p>A batch operation that adds a facet to an object.</p>
     #[serde(rename = "AddFacetToObject")]
@@ -1160,9 +1616,44 @@ pub struct BatchWriteOperation {
     pub update_object_attributes: Option<BatchUpdateObjectAttributes>,
 }
 
+impl Validate for BatchWriteOperation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.add_facet_to_object {
+            value.validate_at(&field_path(path, "add_facet_to_object"), errors);
+        }
+        if let Some(ref value) = self.attach_object {
+            value.validate_at(&field_path(path, "attach_object"), errors);
+        }
+        if let Some(ref value) = self.attach_typed_link {
+            value.validate_at(&field_path(path, "attach_typed_link"), errors);
+        }
+        if let Some(ref value) = self.create_index {
+            value.validate_at(&field_path(path, "create_index"), errors);
+        }
+        if let Some(ref value) = self.create_object {
+            value.validate_at(&field_path(path, "create_object"), errors);
+        }
+        if let Some(ref value) = self.detach_object {
+            value.validate_at(&field_path(path, "detach_object"), errors);
+        }
+        if let Some(ref value) = self.detach_typed_link {
+            value.validate_at(&field_path(path, "detach_typed_link"), errors);
+        }
+        if let Some(ref value) = self.remove_facet_from_object {
+            value.validate_at(&field_path(path, "remove_facet_from_object"), errors);
+        }
+        if let Some(ref value) = self.update_link_attributes {
+            value.validate_at(&field_path(path, "update_link_attributes"), errors);
+        }
+        if let Some(ref value) = self.update_object_attributes {
+            value.validate_at(&field_path(path, "update_object_attributes"), errors);
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>BatchWrite</code> response operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchWriteOperationResponse {
     /// <p>The result of an add facet to object batch operation.</p>
     #[serde(rename = "AddFacetToObject")]
@@ -1227,6 +1718,7 @@ pub struct BatchWriteOperationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchWriteRequest {
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a>. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1236,8 +1728,17 @@ pub struct BatchWriteRequest {
     pub operations: Vec<BatchWriteOperation>,
 }
 
+impl Validate for BatchWriteRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.operations.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "operations"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchWriteResponse {
     /// <p>A list of all the responses for each batch write.</p>
     #[serde(rename = "Responses")]
@@ -1246,6 +1747,7 @@ pub struct BatchWriteResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDirectoryRequest {
     /// <p>The name of the <a>Directory</a>. Should be unique per account, per region.</p>
     #[serde(rename = "Name")]
@@ -1255,8 +1757,20 @@ pub struct CreateDirectoryRequest {
     pub schema_arn: String,
 }
 
+impl Validate for CreateDirectoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDirectoryResponse {
     /// <p>The ARN of the published schema in the <a>Directory</a>. Once a published schema is copied into the directory, it has its own ARN, which is referred to applied schema ARN. For more information, see <a>arns</a>.</p>
     #[serde(rename = "AppliedSchemaArn")]
@@ -1273,6 +1787,7 @@ pub struct CreateDirectoryResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFacetRequest {
     /// <p>The attributes that are associated with the <a>Facet</a>.</p>
     #[serde(rename = "Attributes")]
@@ -1294,11 +1809,30 @@ pub struct CreateFacetRequest {
     pub schema_arn: String,
 }
 
+impl Validate for CreateFacetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFacetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateIndexRequest {
     /// <p>The ARN of the directory where the index should be created.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1319,8 +1853,34 @@ pub struct CreateIndexRequest {
     pub parent_reference: Option<ObjectReference>,
 }
 
+impl Validate for CreateIndexRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.link_name {
+            errors.check_length(
+                &field_path(path, "link_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "link_name"),
+                &value,
+                "[^\\/\\[\\]\\(\\):\\{\\}#@!?\\s\\\\;]+",
+            );
+        }
+        for (i, item) in self.ordered_indexed_attribute_list.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "ordered_indexed_attribute_list"),
+                i
+            );
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateIndexResponse {
     /// <p>The <code>ObjectIdentifier</code> of the index created by this operation.</p>
     #[serde(rename = "ObjectIdentifier")]
@@ -1329,6 +1889,7 @@ pub struct CreateIndexResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateObjectRequest {
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> in which the object will be created. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1350,8 +1911,36 @@ pub struct CreateObjectRequest {
     pub schema_facets: Vec<SchemaFacet>,
 }
 
+impl Validate for CreateObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.link_name {
+            errors.check_length(
+                &field_path(path, "link_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "link_name"),
+                &value,
+                "[^\\/\\[\\]\\(\\):\\{\\}#@!?\\s\\\\;]+",
+            );
+        }
+        if let Some(ref value) = self.object_attribute_list {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "object_attribute_list"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        for (i, item) in self.schema_facets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "schema_facets"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateObjectResponse {
     /// <p>The identifier that is associated with the object.</p>
     #[serde(rename = "ObjectIdentifier")]
@@ -1360,14 +1949,27 @@ pub struct CreateObjectResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSchemaRequest {
     /// <p>The name that is associated with the schema. This is unique to each account and in each region.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for CreateSchemaRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSchemaResponse {
     /// <p>The Amazon Resource Name (ARN) that is associated with the schema. For more information, see <a>arns</a>.</p>
     #[serde(rename = "SchemaArn")]
@@ -1376,6 +1978,7 @@ pub struct CreateSchemaResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTypedLinkFacetRequest {
     /// <p> <a>Facet</a> structure that is associated with the typed link facet.</p>
     #[serde(rename = "Facet")]
@@ -1385,11 +1988,18 @@ pub struct CreateTypedLinkFacetRequest {
     pub schema_arn: String,
 }
 
+impl Validate for CreateTypedLinkFacetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.facet.validate_at(&field_path(path, "facet"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTypedLinkFacetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDirectoryRequest {
     /// <p>The ARN of the directory to delete.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1397,7 +2007,7 @@ pub struct DeleteDirectoryRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDirectoryResponse {
     /// <p>The ARN of the deleted directory.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1405,6 +2015,7 @@ pub struct DeleteDirectoryResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFacetRequest {
     /// <p>The name of the facet to delete.</p>
     #[serde(rename = "Name")]
@@ -1414,11 +2025,24 @@ pub struct DeleteFacetRequest {
     pub schema_arn: String,
 }
 
+impl Validate for DeleteFacetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFacetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteObjectRequest {
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where the object resides. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1429,10 +2053,11 @@ pub struct DeleteObjectRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteObjectResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSchemaRequest {
     /// <p>The Amazon Resource Name (ARN) of the development schema. For more information, see <a>arns</a>.</p>
     #[serde(rename = "SchemaArn")]
@@ -1440,7 +2065,7 @@ pub struct DeleteSchemaRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSchemaResponse {
     /// <p>The input ARN that is returned as part of the response. For more information, see <a>arns</a>.</p>
     #[serde(rename = "SchemaArn")]
@@ -1449,6 +2074,7 @@ pub struct DeleteSchemaResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTypedLinkFacetRequest {
     /// <p>The unique name of the typed link facet.</p>
     #[serde(rename = "Name")]
@@ -1458,11 +2084,18 @@ pub struct DeleteTypedLinkFacetRequest {
     pub schema_arn: String,
 }
 
+impl Validate for DeleteTypedLinkFacetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTypedLinkFacetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachFromIndexRequest {
     /// <p>The Amazon Resource Name (ARN) of the directory the index and object exist in.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1476,7 +2109,7 @@ pub struct DetachFromIndexRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetachFromIndexResponse {
     /// <p>The <code>ObjectIdentifier</code> of the object that was detached from the index.</p>
     #[serde(rename = "DetachedObjectIdentifier")]
@@ -1485,6 +2118,7 @@ pub struct DetachFromIndexResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachObjectRequest {
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where objects reside. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1497,8 +2131,24 @@ pub struct DetachObjectRequest {
     pub parent_reference: ObjectReference,
 }
 
+impl Validate for DetachObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "link_name"),
+            self.link_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "link_name"),
+            &self.link_name,
+            "[^\\/\\[\\]\\(\\):\\{\\}#@!?\\s\\\\;]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetachObjectResponse {
     /// <p>The <code>ObjectIdentifier</code> that was detached from the object.</p>
     #[serde(rename = "DetachedObjectIdentifier")]
@@ -1507,6 +2157,7 @@ pub struct DetachObjectResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachPolicyRequest {
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where both objects reside. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1520,10 +2171,11 @@ pub struct DetachPolicyRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetachPolicyResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachTypedLinkRequest {
     /// <p>The Amazon Resource Name (ARN) of the directory where you want to detach the typed link.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1533,9 +2185,16 @@ pub struct DetachTypedLinkRequest {
     pub typed_link_specifier: TypedLinkSpecifier,
 }
 
+impl Validate for DetachTypedLinkRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.typed_link_specifier
+            .validate_at(&field_path(path, "typed_link_specifier"), errors);
+    }
+}
+
 /// <p>Directory structure that includes the directory name and directory ARN.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Directory {
     /// <p>The date and time when the directory was created.</p>
     #[serde(rename = "CreationDateTime")]
@@ -1556,6 +2215,7 @@ pub struct Directory {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableDirectoryRequest {
     /// <p>The ARN of the directory to disable.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1563,7 +2223,7 @@ pub struct DisableDirectoryRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisableDirectoryResponse {
     /// <p>The ARN of the directory that has been disabled.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1571,6 +2231,7 @@ pub struct DisableDirectoryResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableDirectoryRequest {
     /// <p>The ARN of the directory to enable.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1578,7 +2239,7 @@ pub struct EnableDirectoryRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableDirectoryResponse {
     /// <p>The ARN of the enabled directory.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1587,7 +2248,7 @@ pub struct EnableDirectoryResponse {
 
 /// <p>A structure that contains <code>Name</code>, <code>ARN</code>, <code>Attributes</code>, <code> <a>Rule</a>s</code>, and <code>ObjectTypes</code>. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_whatarefacets.html">Facets</a> for more information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Facet {
     /// <p>There are two different styles that you can define on any given facet, <code>Static</code> and <code>Dynamic</code>. For static facets, all attributes must be defined in the schema. For dynamic facets, attributes can be defined during data plane operations.</p>
     #[serde(rename = "FacetStyle")]
@@ -1623,6 +2284,21 @@ pub struct FacetAttribute {
     pub required_behavior: Option<String>,
 }
 
+impl Validate for FacetAttribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_reference {
+            value.validate_at(&field_path(path, "attribute_reference"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(230),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._:-]*$");
+    }
+}
+
 /// <p>A facet attribute definition. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_attributereferences.html">Attribute References</a> for more information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FacetAttributeDefinition {
@@ -1654,8 +2330,36 @@ pub struct FacetAttributeReference {
     pub target_facet_name: String,
 }
 
+impl Validate for FacetAttributeReference {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "target_attribute_name"),
+            self.target_attribute_name.chars().count(),
+            Some(1),
+            Some(230),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_attribute_name"),
+            &self.target_attribute_name,
+            "^[a-zA-Z0-9._:-]*$",
+        );
+        errors.check_length(
+            &field_path(path, "target_facet_name"),
+            self.target_facet_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_facet_name"),
+            &self.target_facet_name,
+            "^[a-zA-Z0-9._-]*$",
+        );
+    }
+}
+
 /// <p>A structure that contains information used to update an attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FacetAttributeUpdate {
     /// <p>The action to perform when updating the attribute.</p>
     #[serde(rename = "Action")]
@@ -1667,7 +2371,16 @@ pub struct FacetAttributeUpdate {
     pub attribute: Option<FacetAttribute>,
 }
 
+impl Validate for FacetAttributeUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute {
+            value.validate_at(&field_path(path, "attribute"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAppliedSchemaVersionRequest {
     /// <p>The ARN of the applied schema.</p>
     #[serde(rename = "SchemaArn")]
@@ -1675,7 +2388,7 @@ pub struct GetAppliedSchemaVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAppliedSchemaVersionResponse {
     /// <p>Current applied schema ARN, including the minor version in use if one was provided.</p>
     #[serde(rename = "AppliedSchemaArn")]
@@ -1684,6 +2397,7 @@ pub struct GetAppliedSchemaVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDirectoryRequest {
     /// <p>The ARN of the directory.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1691,7 +2405,7 @@ pub struct GetDirectoryRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDirectoryResponse {
     /// <p>Metadata about the directory.</p>
     #[serde(rename = "Directory")]
@@ -1699,6 +2413,7 @@ pub struct GetDirectoryResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFacetRequest {
     /// <p>The name of the facet to retrieve.</p>
     #[serde(rename = "Name")]
@@ -1708,8 +2423,20 @@ pub struct GetFacetRequest {
     pub schema_arn: String,
 }
 
+impl Validate for GetFacetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFacetResponse {
     /// <p>The <a>Facet</a> structure that is associated with the facet.</p>
     #[serde(rename = "Facet")]
@@ -1718,6 +2445,7 @@ pub struct GetFacetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLinkAttributesRequest {
     /// <p>A list of attribute names whose values will be retrieved.</p>
     #[serde(rename = "AttributeNames")]
@@ -1734,8 +2462,20 @@ pub struct GetLinkAttributesRequest {
     pub typed_link_specifier: TypedLinkSpecifier,
 }
 
+impl Validate for GetLinkAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attribute_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(230));
+            errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9._:-]*$");
+        }
+        self.typed_link_specifier
+            .validate_at(&field_path(path, "typed_link_specifier"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLinkAttributesResponse {
     /// <p>The attributes that are associated with the typed link.</p>
     #[serde(rename = "Attributes")]
@@ -1744,6 +2484,7 @@ pub struct GetLinkAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectAttributesRequest {
     /// <p>List of attribute names whose values will be retrieved.</p>
     #[serde(rename = "AttributeNames")]
@@ -1763,8 +2504,20 @@ pub struct GetObjectAttributesRequest {
     pub schema_facet: SchemaFacet,
 }
 
+impl Validate for GetObjectAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attribute_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(230));
+            errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9._:-]*$");
+        }
+        self.schema_facet
+            .validate_at(&field_path(path, "schema_facet"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetObjectAttributesResponse {
     /// <p>The attributes that are associated with the object.</p>
     #[serde(rename = "Attributes")]
@@ -1773,6 +2526,7 @@ pub struct GetObjectAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectInformationRequest {
     /// <p>The consistency level at which to retrieve the object information.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -1787,7 +2541,7 @@ pub struct GetObjectInformationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetObjectInformationResponse {
     /// <p>The <code>ObjectIdentifier</code> of the specified object.</p>
     #[serde(rename = "ObjectIdentifier")]
@@ -1800,6 +2554,7 @@ pub struct GetObjectInformationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSchemaAsJsonRequest {
     /// <p>The ARN of the schema to retrieve.</p>
     #[serde(rename = "SchemaArn")]
@@ -1807,7 +2562,7 @@ pub struct GetSchemaAsJsonRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSchemaAsJsonResponse {
     /// <p>The JSON representation of the schema document.</p>
     #[serde(rename = "Document")]
@@ -1820,6 +2575,7 @@ pub struct GetSchemaAsJsonResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTypedLinkFacetInformationRequest {
     /// <p>The unique name of the typed link facet.</p>
     #[serde(rename = "Name")]
@@ -1829,8 +2585,14 @@ pub struct GetTypedLinkFacetInformationRequest {
     pub schema_arn: String,
 }
 
+impl Validate for GetTypedLinkFacetInformationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTypedLinkFacetInformationResponse {
     /// <p>The order of identity attributes for the facet, from most significant to least significant. The ability to filter typed links considers the order that the attributes are defined on the typed link facet. When providing ranges to typed link selection, any inexact ranges must be specified at the end. Any attributes that do not have a range specified are presumed to match the entire range. Filters are interpreted in the order of the attributes on the typed link facet, not the order in which they are supplied to any API calls. For more information about identity attributes, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     #[serde(rename = "IdentityAttributeOrder")]
@@ -1840,7 +2602,7 @@ pub struct GetTypedLinkFacetInformationResponse {
 
 /// <p>Represents an index and an attached object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IndexAttachment {
     /// <p>The indexed attribute values.</p>
     #[serde(rename = "IndexedAttributes")]
@@ -1854,6 +2616,7 @@ pub struct IndexAttachment {
 
 /// <p>The action to take on a typed link attribute value. Updates are only supported for attributes which don’t contribute to link identity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LinkAttributeAction {
     /// <p>A type that can be either <code>UPDATE_OR_CREATE</code> or <code>DELETE</code>.</p>
     #[serde(rename = "AttributeActionType")]
@@ -1867,6 +2630,7 @@ pub struct LinkAttributeAction {
 
 /// <p>Structure that contains attribute update information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LinkAttributeUpdate {
     /// <p>The action to perform as part of the attribute update.</p>
     #[serde(rename = "AttributeAction")]
@@ -1878,7 +2642,16 @@ pub struct LinkAttributeUpdate {
     pub attribute_key: Option<AttributeKey>,
 }
 
+impl Validate for LinkAttributeUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_key {
+            value.validate_at(&field_path(path, "attribute_key"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAppliedSchemaArnsRequest {
     /// <p>The ARN of the directory you are listing.</p>
     #[serde(rename = "DirectoryArn")]
@@ -1897,8 +2670,16 @@ pub struct ListAppliedSchemaArnsRequest {
     pub schema_arn: Option<String>,
 }
 
+impl Validate for ListAppliedSchemaArnsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAppliedSchemaArnsResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -1911,6 +2692,7 @@ pub struct ListAppliedSchemaArnsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAttachedIndicesRequest {
     /// <p>The consistency level to use for this operation.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -1932,8 +2714,16 @@ pub struct ListAttachedIndicesRequest {
     pub target_reference: ObjectReference,
 }
 
+impl Validate for ListAttachedIndicesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAttachedIndicesResponse {
     /// <p>The indices attached to the specified object.</p>
     #[serde(rename = "IndexAttachments")]
@@ -1946,6 +2736,7 @@ pub struct ListAttachedIndicesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDevelopmentSchemaArnsRequest {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -1957,8 +2748,16 @@ pub struct ListDevelopmentSchemaArnsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDevelopmentSchemaArnsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDevelopmentSchemaArnsResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -1971,6 +2770,7 @@ pub struct ListDevelopmentSchemaArnsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDirectoriesRequest {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -1986,8 +2786,16 @@ pub struct ListDirectoriesRequest {
     pub state: Option<String>,
 }
 
+impl Validate for ListDirectoriesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDirectoriesResponse {
     /// <p>Lists all directories that are associated with your account in pagination fashion.</p>
     #[serde(rename = "Directories")]
@@ -1999,6 +2807,7 @@ pub struct ListDirectoriesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFacetAttributesRequest {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -2016,8 +2825,23 @@ pub struct ListFacetAttributesRequest {
     pub schema_arn: String,
 }
 
+impl Validate for ListFacetAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFacetAttributesResponse {
     /// <p>The attributes attached to the facet.</p>
     #[serde(rename = "Attributes")]
@@ -2030,6 +2854,7 @@ pub struct ListFacetAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFacetNamesRequest {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -2044,8 +2869,16 @@ pub struct ListFacetNamesRequest {
     pub schema_arn: String,
 }
 
+impl Validate for ListFacetNamesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFacetNamesResponse {
     /// <p>The names of facets that exist within the schema.</p>
     #[serde(rename = "FacetNames")]
@@ -2058,6 +2891,7 @@ pub struct ListFacetNamesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIncomingTypedLinksRequest {
     /// <p>The consistency level to execute the request at.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -2087,8 +2921,25 @@ pub struct ListIncomingTypedLinksRequest {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for ListIncomingTypedLinksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter_attribute_ranges {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filter_attribute_ranges"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.filter_typed_link {
+            value.validate_at(&field_path(path, "filter_typed_link"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListIncomingTypedLinksResponse {
     /// <p>Returns one or more typed link specifiers as output.</p>
     #[serde(rename = "LinkSpecifiers")]
@@ -2101,6 +2952,7 @@ pub struct ListIncomingTypedLinksResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIndexRequest {
     /// <p>The consistency level to execute the request at.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -2126,8 +2978,22 @@ pub struct ListIndexRequest {
     pub ranges_on_indexed_values: Option<Vec<ObjectAttributeRange>>,
 }
 
+impl Validate for ListIndexRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.ranges_on_indexed_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "ranges_on_indexed_values"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListIndexResponse {
     /// <p>The objects and indexed values attached to the index.</p>
     #[serde(rename = "IndexAttachments")]
@@ -2140,6 +3006,7 @@ pub struct ListIndexResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListManagedSchemaArnsRequest {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -2155,8 +3022,16 @@ pub struct ListManagedSchemaArnsRequest {
     pub schema_arn: Option<String>,
 }
 
+impl Validate for ListManagedSchemaArnsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListManagedSchemaArnsResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -2169,6 +3044,7 @@ pub struct ListManagedSchemaArnsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectAttributesRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -2194,8 +3070,19 @@ pub struct ListObjectAttributesRequest {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for ListObjectAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.facet_filter {
+            value.validate_at(&field_path(path, "facet_filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListObjectAttributesResponse {
     /// <p>Attributes map that is associated with the object. <code>AttributeArn</code> is the key, and attribute value is the value.</p>
     #[serde(rename = "Attributes")]
@@ -2208,6 +3095,7 @@ pub struct ListObjectAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectChildrenRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -2229,8 +3117,16 @@ pub struct ListObjectChildrenRequest {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for ListObjectChildrenRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListObjectChildrenResponse {
     /// <p>Children structure, which is a map with key as the <code>LinkName</code> and <code>ObjectIdentifier</code> as the value.</p>
     #[serde(rename = "Children")]
@@ -2243,6 +3139,7 @@ pub struct ListObjectChildrenResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectParentPathsRequest {
     /// <p>The ARN of the directory to which the parent path applies.</p>
     #[serde(rename = "DirectoryArn")]
@@ -2260,8 +3157,16 @@ pub struct ListObjectParentPathsRequest {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for ListObjectParentPathsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListObjectParentPathsResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -2274,6 +3179,7 @@ pub struct ListObjectParentPathsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectParentsRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -2299,8 +3205,16 @@ pub struct ListObjectParentsRequest {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for ListObjectParentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListObjectParentsResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -2317,6 +3231,7 @@ pub struct ListObjectParentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectPoliciesRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -2338,8 +3253,16 @@ pub struct ListObjectPoliciesRequest {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for ListObjectPoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListObjectPoliciesResponse {
     /// <p>A list of policy <code>ObjectIdentifiers</code>, that are attached to the object.</p>
     #[serde(rename = "AttachedPolicyIds")]
@@ -2352,6 +3275,7 @@ pub struct ListObjectPoliciesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOutgoingTypedLinksRequest {
     /// <p>The consistency level to execute the request at.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -2381,8 +3305,25 @@ pub struct ListOutgoingTypedLinksRequest {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for ListOutgoingTypedLinksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter_attribute_ranges {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filter_attribute_ranges"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.filter_typed_link {
+            value.validate_at(&field_path(path, "filter_typed_link"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOutgoingTypedLinksResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -2395,6 +3336,7 @@ pub struct ListOutgoingTypedLinksResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPolicyAttachmentsRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
@@ -2416,8 +3358,16 @@ pub struct ListPolicyAttachmentsRequest {
     pub policy_reference: ObjectReference,
 }
 
+impl Validate for ListPolicyAttachmentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPolicyAttachmentsResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -2430,6 +3380,7 @@ pub struct ListPolicyAttachmentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPublishedSchemaArnsRequest {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -2445,8 +3396,16 @@ pub struct ListPublishedSchemaArnsRequest {
     pub schema_arn: Option<String>,
 }
 
+impl Validate for ListPublishedSchemaArnsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPublishedSchemaArnsResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -2459,6 +3418,7 @@ pub struct ListPublishedSchemaArnsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The <code>MaxResults</code> parameter sets the maximum number of results returned in a single page. This is for future use and is not supported currently.</p>
     #[serde(rename = "MaxResults")]
@@ -2473,8 +3433,16 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(50), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The token to use to retrieve the next page of results. This value is null when there are no more results to return.</p>
     #[serde(rename = "NextToken")]
@@ -2487,6 +3455,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTypedLinkFacetAttributesRequest {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -2504,8 +3473,17 @@ pub struct ListTypedLinkFacetAttributesRequest {
     pub schema_arn: String,
 }
 
+impl Validate for ListTypedLinkFacetAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTypedLinkFacetAttributesResponse {
     /// <p>An ordered set of attributes associate with the typed link.</p>
     #[serde(rename = "Attributes")]
@@ -2518,6 +3496,7 @@ pub struct ListTypedLinkFacetAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTypedLinkFacetNamesRequest {
     /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
@@ -2532,8 +3511,16 @@ pub struct ListTypedLinkFacetNamesRequest {
     pub schema_arn: String,
 }
 
+impl Validate for ListTypedLinkFacetNamesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTypedLinkFacetNamesResponse {
     /// <p>The names of typed link facets that exist within the schema.</p>
     #[serde(rename = "FacetNames")]
@@ -2546,6 +3533,7 @@ pub struct ListTypedLinkFacetNamesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LookupPolicyRequest {
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a>. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
@@ -2563,8 +3551,16 @@ pub struct LookupPolicyRequest {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for LookupPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LookupPolicyResponse {
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -2578,6 +3574,7 @@ pub struct LookupPolicyResponse {
 
 /// <p>The action to take on the object attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ObjectAttributeAction {
     /// <p>A type that can be either <code>Update</code> or <code>Delete</code>.</p>
     #[serde(rename = "ObjectAttributeActionType")]
@@ -2591,6 +3588,7 @@ pub struct ObjectAttributeAction {
 
 /// <p>A range of attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ObjectAttributeRange {
     /// <p>The key of the attribute that the attribute range covers.</p>
     #[serde(rename = "AttributeKey")]
@@ -2602,8 +3600,17 @@ pub struct ObjectAttributeRange {
     pub range: Option<TypedAttributeValueRange>,
 }
 
+impl Validate for ObjectAttributeRange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_key {
+            value.validate_at(&field_path(path, "attribute_key"), errors);
+        }
+    }
+}
+
 /// <p>Structure that contains attribute update information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ObjectAttributeUpdate {
     /// <p>The action to perform as part of the attribute update.</p>
     #[serde(rename = "ObjectAttributeAction")]
@@ -2615,9 +3622,17 @@ pub struct ObjectAttributeUpdate {
     pub object_attribute_key: Option<AttributeKey>,
 }
 
+impl Validate for ObjectAttributeUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.object_attribute_key {
+            value.validate_at(&field_path(path, "object_attribute_key"), errors);
+        }
+    }
+}
+
 /// <p>A pair of ObjectIdentifier and LinkName.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ObjectIdentifierAndLinkNameTuple {
     /// <p>The name of the link between the parent and the child object.</p>
     #[serde(rename = "LinkName")]
@@ -2640,7 +3655,7 @@ pub struct ObjectReference {
 
 /// <p>Returns the path to the <code>ObjectIdentifiers</code> that is associated with the directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PathToObjectIdentifiers {
     /// <p>Lists <code>ObjectIdentifiers</code> starting from directory root to the object in the request.</p>
     #[serde(rename = "ObjectIdentifiers")]
@@ -2654,7 +3669,7 @@ pub struct PathToObjectIdentifiers {
 
 /// <p>Contains the <code>PolicyType</code>, <code>PolicyId</code>, and the <code>ObjectIdentifier</code> to which it is attached. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directory.html#key_concepts_policies">Policies</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PolicyAttachment {
     /// <p>The <code>ObjectIdentifier</code> that is associated with <code>PolicyAttachment</code>.</p>
     #[serde(rename = "ObjectIdentifier")]
@@ -2672,7 +3687,7 @@ pub struct PolicyAttachment {
 
 /// <p>Used when a regular object exists in a <a>Directory</a> and you want to find all of the policies that are associated with that object and the parent to that object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PolicyToPath {
     /// <p>The path that is referenced from the root.</p>
     #[serde(rename = "Path")]
@@ -2685,6 +3700,7 @@ pub struct PolicyToPath {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublishSchemaRequest {
     /// <p>The Amazon Resource Name (ARN) that is associated with the development schema. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DevelopmentSchemaArn")]
@@ -2702,8 +3718,46 @@ pub struct PublishSchemaRequest {
     pub version: String,
 }
 
+impl Validate for PublishSchemaRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.minor_version {
+            errors.check_length(
+                &field_path(path, "minor_version"),
+                value.chars().count(),
+                Some(1),
+                Some(10),
+            );
+            errors.check_pattern(
+                &field_path(path, "minor_version"),
+                &value,
+                "^[a-zA-Z0-9._-]*$",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "^[a-zA-Z0-9._-]*$");
+        }
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(10),
+        );
+        errors.check_pattern(
+            &field_path(path, "version"),
+            &self.version,
+            "^[a-zA-Z0-9._-]*$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PublishSchemaResponse {
     /// <p>The ARN that is associated with the published schema. For more information, see <a>arns</a>.</p>
     #[serde(rename = "PublishedSchemaArn")]
@@ -2712,6 +3766,7 @@ pub struct PublishSchemaResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutSchemaFromJsonRequest {
     /// <p>The replacement JSON schema.</p>
     #[serde(rename = "Document")]
@@ -2722,7 +3777,7 @@ pub struct PutSchemaFromJsonRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutSchemaFromJsonResponse {
     /// <p>The ARN of the schema to update.</p>
     #[serde(rename = "Arn")]
@@ -2731,6 +3786,7 @@ pub struct PutSchemaFromJsonResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveFacetFromObjectRequest {
     /// <p>The ARN of the directory in which the object resides.</p>
     #[serde(rename = "DirectoryArn")]
@@ -2743,8 +3799,15 @@ pub struct RemoveFacetFromObjectRequest {
     pub schema_facet: SchemaFacet,
 }
 
+impl Validate for RemoveFacetFromObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.schema_facet
+            .validate_at(&field_path(path, "schema_facet"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveFacetFromObjectResponse {}
 
 /// <p>Contains an Amazon Resource Name (ARN) and parameters that are associated with the rule.</p>
@@ -2773,6 +3836,20 @@ pub struct SchemaFacet {
     pub schema_arn: Option<String>,
 }
 
+impl Validate for SchemaFacet {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.facet_name {
+            errors.check_length(
+                &field_path(path, "facet_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "facet_name"), &value, "^[a-zA-Z0-9._-]*$");
+        }
+    }
+}
+
 /// <p>The tag structure that contains a tag key and value.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -2787,6 +3864,7 @@ pub struct Tag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource. Tagging is only supported for directories.</p>
     #[serde(rename = "ResourceArn")]
@@ -2797,7 +3875,7 @@ pub struct TagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>Represents the data for a typed attribute. You can set one, and only one, of the elements. Each attribute in an item is a name-value pair. Attributes have a single value.</p>
@@ -2832,6 +3910,7 @@ pub struct TypedAttributeValue {
 
 /// <p>A range of attribute values. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_range_filters.html">Range Filters</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TypedAttributeValueRange {
     /// <p>The inclusive or exclusive range end.</p>
     #[serde(rename = "EndMode")]
@@ -2875,8 +3954,21 @@ pub struct TypedLinkAttributeDefinition {
     pub type_: String,
 }
 
+impl Validate for TypedLinkAttributeDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(230),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._:-]*$");
+    }
+}
+
 /// <p>Identifies the range of attributes that are used by a specified filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TypedLinkAttributeRange {
     /// <p>The unique name of the typed link attribute.</p>
     #[serde(rename = "AttributeName")]
@@ -2887,8 +3979,27 @@ pub struct TypedLinkAttributeRange {
     pub range: TypedAttributeValueRange,
 }
 
+impl Validate for TypedLinkAttributeRange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_name {
+            errors.check_length(
+                &field_path(path, "attribute_name"),
+                value.chars().count(),
+                Some(1),
+                Some(230),
+            );
+            errors.check_pattern(
+                &field_path(path, "attribute_name"),
+                &value,
+                "^[a-zA-Z0-9._:-]*$",
+            );
+        }
+    }
+}
+
 /// <p>Defines the typed links structure and its attributes. To create a typed link facet, use the <a>CreateTypedLinkFacet</a> API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TypedLinkFacet {
     /// <p>A set of key-value pairs associated with the typed link. Typed link attributes are used when you have data values that are related to the link itself, and not to one of the two objects being linked. Identity attributes also serve to distinguish the link from others of the same type between the same objects.</p>
     #[serde(rename = "Attributes")]
@@ -2901,8 +4012,24 @@ pub struct TypedLinkFacet {
     pub name: String,
 }
 
+impl Validate for TypedLinkFacet {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+        for (i, item) in self.identity_attribute_order.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "identity_attribute_order"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(230));
+            errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9._:-]*$");
+        }
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 /// <p>A typed link facet attribute update.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TypedLinkFacetAttributeUpdate {
     /// <p>The action to perform when updating the attribute.</p>
     #[serde(rename = "Action")]
@@ -2912,6 +4039,13 @@ pub struct TypedLinkFacetAttributeUpdate {
     pub attribute: TypedLinkAttributeDefinition,
 }
 
+impl Validate for TypedLinkFacetAttributeUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.attribute
+            .validate_at(&field_path(path, "attribute"), errors);
+    }
+}
+
 /// <p>Identifies the schema Amazon Resource Name (ARN) and facet name for the typed link.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TypedLinkSchemaAndFacetName {
@@ -2923,6 +4057,16 @@ pub struct TypedLinkSchemaAndFacetName {
     pub typed_link_name: String,
 }
 
+impl Validate for TypedLinkSchemaAndFacetName {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "typed_link_name"),
+            &self.typed_link_name,
+            "^[a-zA-Z0-9._-]*$",
+        );
+    }
+}
+
 /// <p>Contains all the information that is used to uniquely identify a typed link. The parameters discussed in this topic are used to uniquely specify the typed link being operated on. The <a>AttachTypedLink</a> API returns a typed link specifier while the <a>DetachTypedLink</a> API accepts one as input. Similarly, the <a>ListIncomingTypedLinks</a> and <a>ListOutgoingTypedLinks</a> API operations provide typed link specifiers as output. You can also construct a typed link specifier from scratch.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TypedLinkSpecifier {
@@ -2940,7 +4084,19 @@ pub struct TypedLinkSpecifier {
     pub typed_link_facet: TypedLinkSchemaAndFacetName,
 }
 
+impl Validate for TypedLinkSpecifier {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.identity_attribute_values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "identity_attribute_values"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.typed_link_facet
+            .validate_at(&field_path(path, "typed_link_facet"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource. Tagging is only supported for directories.</p>
     #[serde(rename = "ResourceArn")]
@@ -2951,10 +4107,11 @@ pub struct UntagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFacetRequest {
     /// <p>List of attributes that need to be updated in a given schema <a>Facet</a>. Each attribute is followed by <code>AttributeAction</code>, which specifies the type of update operation to perform. </p>
     #[serde(rename = "AttributeUpdates")]
@@ -2972,11 +4129,30 @@ pub struct UpdateFacetRequest {
     pub schema_arn: String,
 }
 
+impl Validate for UpdateFacetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_updates {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attribute_updates"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFacetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateLinkAttributesRequest {
     /// <p>The attributes update structure.</p>
     #[serde(rename = "AttributeUpdates")]
@@ -2989,11 +4165,23 @@ pub struct UpdateLinkAttributesRequest {
     pub typed_link_specifier: TypedLinkSpecifier,
 }
 
+impl Validate for UpdateLinkAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attribute_updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.typed_link_specifier
+            .validate_at(&field_path(path, "typed_link_specifier"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateLinkAttributesResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateObjectAttributesRequest {
     /// <p>The attributes update structure.</p>
     #[serde(rename = "AttributeUpdates")]
@@ -3006,8 +4194,17 @@ pub struct UpdateObjectAttributesRequest {
     pub object_reference: ObjectReference,
 }
 
+impl Validate for UpdateObjectAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attribute_updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateObjectAttributesResponse {
     /// <p>The <code>ObjectIdentifier</code> of the updated object.</p>
     #[serde(rename = "ObjectIdentifier")]
@@ -3016,6 +4213,7 @@ pub struct UpdateObjectAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSchemaRequest {
     /// <p>The name of the schema.</p>
     #[serde(rename = "Name")]
@@ -3025,8 +4223,20 @@ pub struct UpdateSchemaRequest {
     pub schema_arn: String,
 }
 
+impl Validate for UpdateSchemaRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSchemaResponse {
     /// <p>The ARN that is associated with the updated schema. For more information, see <a>arns</a>.</p>
     #[serde(rename = "SchemaArn")]
@@ -3035,6 +4245,7 @@ pub struct UpdateSchemaResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTypedLinkFacetRequest {
     /// <p>Attributes update structure.</p>
     #[serde(rename = "AttributeUpdates")]
@@ -3050,11 +4261,27 @@ pub struct UpdateTypedLinkFacetRequest {
     pub schema_arn: String,
 }
 
+impl Validate for UpdateTypedLinkFacetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attribute_updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+        for (i, item) in self.identity_attribute_order.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "identity_attribute_order"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(230));
+            errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9._:-]*$");
+        }
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9._-]*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTypedLinkFacetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpgradeAppliedSchemaRequest {
     /// <p>The ARN for the directory to which the upgraded schema will be applied.</p>
     #[serde(rename = "DirectoryArn")]
@@ -3069,7 +4296,7 @@ pub struct UpgradeAppliedSchemaRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpgradeAppliedSchemaResponse {
     /// <p>The ARN of the directory that is returned as part of the response.</p>
     #[serde(rename = "DirectoryArn")]
@@ -3082,6 +4309,7 @@ pub struct UpgradeAppliedSchemaResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpgradePublishedSchemaRequest {
     /// <p>The ARN of the development schema with the changes used for the upgrade.</p>
     #[serde(rename = "DevelopmentSchemaArn")]
@@ -3098,8 +4326,24 @@ pub struct UpgradePublishedSchemaRequest {
     pub published_schema_arn: String,
 }
 
+impl Validate for UpgradePublishedSchemaRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "minor_version"),
+            self.minor_version.chars().count(),
+            Some(1),
+            Some(10),
+        );
+        errors.check_pattern(
+            &field_path(path, "minor_version"),
+            &self.minor_version,
+            "^[a-zA-Z0-9._-]*$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpgradePublishedSchemaResponse {
     /// <p>The ARN of the upgraded schema that is returned as part of the response.</p>
     #[serde(rename = "UpgradedSchemaArn")]
@@ -3158,7 +4402,12 @@ impl AddFacetToObjectError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(AddFacetToObjectError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3233,7 +4482,12 @@ impl ApplySchemaError {
                 "SchemaAlreadyExistsException" => {
                     return RusotoError::Service(ApplySchemaError::SchemaAlreadyExists(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3318,7 +4572,12 @@ impl AttachObjectError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(AttachObjectError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3395,7 +4654,12 @@ impl AttachPolicyError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(AttachPolicyError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3487,7 +4751,12 @@ impl AttachToIndexError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(AttachToIndexError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3570,7 +4839,12 @@ impl AttachTypedLinkError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(AttachTypedLinkError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3636,7 +4910,12 @@ impl BatchReadError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(BatchReadError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3704,7 +4983,12 @@ impl BatchWriteError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(BatchWriteError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3775,7 +5059,12 @@ impl CreateDirectoryError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(CreateDirectoryError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3854,7 +5143,12 @@ impl CreateFacetError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(CreateFacetError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3940,7 +5234,12 @@ impl CreateIndexError {
                 "UnsupportedIndexTypeException" => {
                     return RusotoError::Service(CreateIndexError::UnsupportedIndexType(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4027,7 +5326,12 @@ impl CreateObjectError {
                 "UnsupportedIndexTypeException" => {
                     return RusotoError::Service(CreateObjectError::UnsupportedIndexType(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4094,7 +5398,12 @@ impl CreateSchemaError {
                 "SchemaAlreadyExistsException" => {
                     return RusotoError::Service(CreateSchemaError::SchemaAlreadyExists(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4182,7 +5491,12 @@ impl CreateTypedLinkFacetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4260,7 +5574,12 @@ impl DeleteDirectoryError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(DeleteDirectoryError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4335,7 +5654,12 @@ impl DeleteFacetError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(DeleteFacetError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4410,7 +5734,12 @@ impl DeleteObjectError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(DeleteObjectError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4480,7 +5809,12 @@ impl DeleteSchemaError {
                 "StillContainsLinksException" => {
                     return RusotoError::Service(DeleteSchemaError::StillContainsLinks(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4555,7 +5889,12 @@ impl DeleteTypedLinkFacetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4636,7 +5975,12 @@ impl DetachFromIndexError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(DetachFromIndexError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4712,7 +6056,12 @@ impl DetachObjectError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(DetachObjectError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4787,7 +6136,12 @@ impl DetachPolicyError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(DetachPolicyError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4862,7 +6216,12 @@ impl DetachTypedLinkError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(DetachTypedLinkError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4932,7 +6291,12 @@ impl DisableDirectoryError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(DisableDirectoryError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5001,7 +6365,12 @@ impl EnableDirectoryError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(EnableDirectoryError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5075,7 +6444,12 @@ impl GetAppliedSchemaVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5133,7 +6507,12 @@ impl GetDirectoryError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(GetDirectoryError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5200,7 +6579,12 @@ impl GetFacetError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(GetFacetError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5276,7 +6660,12 @@ impl GetLinkAttributesError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(GetLinkAttributesError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5357,7 +6746,12 @@ impl GetObjectAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5435,7 +6829,12 @@ impl GetObjectInformationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5499,7 +6898,12 @@ impl GetSchemaAsJsonError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(GetSchemaAsJsonError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5590,7 +6994,12 @@ impl GetTypedLinkFacetInformationError {
                         GetTypedLinkFacetInformationError::RetryableConflict(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5668,7 +7077,12 @@ impl ListAppliedSchemaArnsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5743,7 +7157,12 @@ impl ListAttachedIndicesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5826,7 +7245,12 @@ impl ListDevelopmentSchemaArnsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5890,7 +7314,12 @@ impl ListDirectoriesError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(ListDirectoriesError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5969,7 +7398,12 @@ impl ListFacetAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6039,7 +7473,12 @@ impl ListFacetNamesError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(ListFacetNamesError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6132,7 +7571,12 @@ impl ListIncomingTypedLinksError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6218,7 +7662,12 @@ impl ListIndexError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(ListIndexError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6286,7 +7735,12 @@ impl ListManagedSchemaArnsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6375,7 +7829,12 @@ impl ListObjectAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6460,7 +7919,12 @@ impl ListObjectChildrenError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6546,7 +8010,12 @@ impl ListObjectParentPathsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6630,7 +8099,12 @@ impl ListObjectParentsError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(ListObjectParentsError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6710,7 +8184,12 @@ impl ListObjectPoliciesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6804,7 +8283,12 @@ impl ListOutgoingTypedLinksError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6895,7 +8379,12 @@ impl ListPolicyAttachmentsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6978,7 +8467,12 @@ impl ListPublishedSchemaArnsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7053,7 +8547,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7145,7 +8644,12 @@ impl ListTypedLinkFacetAttributesError {
                         ListTypedLinkFacetAttributesError::RetryableConflict(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7227,7 +8731,12 @@ impl ListTypedLinkFacetNamesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7301,7 +8810,12 @@ impl LookupPolicyError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(LookupPolicyError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7373,7 +8887,12 @@ impl PublishSchemaError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7442,7 +8961,12 @@ impl PutSchemaFromJsonError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(PutSchemaFromJsonError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7526,7 +9050,12 @@ impl RemoveFacetFromObjectError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7596,7 +9125,12 @@ impl TagResourceError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(TagResourceError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7665,7 +9199,12 @@ impl UntagResourceError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(UntagResourceError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7749,7 +9288,12 @@ impl UpdateFacetError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(UpdateFacetError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7836,7 +9380,12 @@ impl UpdateLinkAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7930,7 +9479,12 @@ impl UpdateObjectAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7996,7 +9550,12 @@ impl UpdateSchemaError {
                 "RetryableConflictException" => {
                     return RusotoError::Service(UpdateSchemaError::RetryableConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8089,7 +9648,12 @@ impl UpdateTypedLinkFacetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8178,7 +9742,12 @@ impl UpgradeAppliedSchemaError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8265,7 +9834,12 @@ impl UpgradePublishedSchemaError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8686,2193 +10260,6215 @@ pub trait CloudDirectory {
         input: UpgradePublishedSchemaRequest,
     ) -> RusotoFuture<UpgradePublishedSchemaResponse, UpgradePublishedSchemaError>;
 }
-/// A client for the Amazon CloudDirectory API.
-#[derive(Clone)]
-pub struct CloudDirectoryClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CloudDirectoryClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CloudDirectoryClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CloudDirectoryClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CloudDirectoryClient {
-        CloudDirectoryClient { client, region }
-    }
-}
-
-impl CloudDirectory for CloudDirectoryClient {
-    /// <p>Adds a new <a>Facet</a> to an object. An object can have more than one facet applied on it.</p>
+const _: Option<&dyn CloudDirectory> = None;
+impl<'a, T: CloudDirectory + ?Sized> CloudDirectory for &'a T {
     fn add_facet_to_object(
         &self,
         input: AddFacetToObjectRequest,
     ) -> RusotoFuture<AddFacetToObjectResponse, AddFacetToObjectError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/facets";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddFacetToObjectResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddFacetToObjectError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_facet_to_object(input)
     }
-
-    /// <p>Copies the input published schema, at the specified version, into the <a>Directory</a> with the same name and version as that of the published schema.</p>
     fn apply_schema(
         &self,
         input: ApplySchemaRequest,
     ) -> RusotoFuture<ApplySchemaResponse, ApplySchemaError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/apply";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ApplySchemaResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ApplySchemaError::from_response(response))),
-                )
-            }
-        })
+        (**self).apply_schema(input)
     }
-
-    /// <p><p>Attaches an existing object to another object. An object can be accessed in two ways:</p> <ol> <li> <p>Using the path</p> </li> <li> <p>Using <code>ObjectIdentifier</code> </p> </li> </ol></p>
     fn attach_object(
         &self,
         input: AttachObjectRequest,
     ) -> RusotoFuture<AttachObjectResponse, AttachObjectError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/attach";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AttachObjectResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AttachObjectError::from_response(response))),
-                )
-            }
-        })
+        (**self).attach_object(input)
     }
-
-    /// <p>Attaches a policy object to a regular object. An object can have a limited number of attached policies.</p>
     fn attach_policy(
         &self,
         input: AttachPolicyRequest,
     ) -> RusotoFuture<AttachPolicyResponse, AttachPolicyError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/policy/attach";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AttachPolicyResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AttachPolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).attach_policy(input)
     }
-
-    /// <p>Attaches the specified object to the specified index.</p>
     fn attach_to_index(
         &self,
         input: AttachToIndexRequest,
     ) -> RusotoFuture<AttachToIndexResponse, AttachToIndexError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/index/attach";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AttachToIndexResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AttachToIndexError::from_response(response))),
-                )
-            }
-        })
+        (**self).attach_to_index(input)
     }
-
-    /// <p>Attaches a typed link to a specified source and target object. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     fn attach_typed_link(
         &self,
         input: AttachTypedLinkRequest,
     ) -> RusotoFuture<AttachTypedLinkResponse, AttachTypedLinkError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attach";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AttachTypedLinkResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AttachTypedLinkError::from_response(response))),
-                )
-            }
-        })
+        (**self).attach_typed_link(input)
     }
-
-    /// <p>Performs all the read operations in a batch. </p>
     fn batch_read(
         &self,
         input: BatchReadRequest,
     ) -> RusotoFuture<BatchReadResponse, BatchReadError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/batchread";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref consistency_level) = input.consistency_level {
-            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
-        }
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchReadResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchReadError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_read(input)
     }
-
-    /// <p>Performs all the write operations in a batch. Either all the operations succeed or none.</p>
     fn batch_write(
         &self,
         input: BatchWriteRequest,
     ) -> RusotoFuture<BatchWriteResponse, BatchWriteError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/batchwrite";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchWriteResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchWriteError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_write(input)
     }
-
-    /// <p>Creates a <a>Directory</a> by copying the published schema into the directory. A directory cannot be created without a schema.</p> <p>You can also quickly create a directory using a managed schema, called the <code>QuickStartSchema</code>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_managed.html">Managed Schema</a> in the <i>Amazon Cloud Directory Developer Guide</i>.</p>
     fn create_directory(
         &self,
         input: CreateDirectoryRequest,
     ) -> RusotoFuture<CreateDirectoryResponse, CreateDirectoryError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/directory/create";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDirectoryResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDirectoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_directory(input)
     }
-
-    /// <p>Creates a new <a>Facet</a> in a schema. Facet creation is allowed only in development or applied schemas.</p>
     fn create_facet(
         &self,
         input: CreateFacetRequest,
     ) -> RusotoFuture<CreateFacetResponse, CreateFacetError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/facet/create";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFacetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateFacetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_facet(input)
     }
-
-    /// <p>Creates an index object. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/indexing_search.html">Indexing and search</a> for more information.</p>
     fn create_index(
         &self,
         input: CreateIndexRequest,
     ) -> RusotoFuture<CreateIndexResponse, CreateIndexError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/index";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateIndexResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateIndexError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_index(input)
     }
-
-    /// <p>Creates an object in a <a>Directory</a>. Additionally attaches the object to a parent, if a parent reference and <code>LinkName</code> is specified. An object is simply a collection of <a>Facet</a> attributes. You can also use this API call to create a policy object, if the facet from which you create the object is a policy facet. </p>
     fn create_object(
         &self,
         input: CreateObjectRequest,
     ) -> RusotoFuture<CreateObjectResponse, CreateObjectError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateObjectResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateObjectError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_object(input)
     }
-
-    /// <p><p>Creates a new schema in a development state. A schema can exist in three phases:</p> <ul> <li> <p> <i>Development:</i> This is a mutable phase of the schema. All new schemas are in the development phase. Once the schema is finalized, it can be published.</p> </li> <li> <p> <i>Published:</i> Published schemas are immutable and have a version associated with them.</p> </li> <li> <p> <i>Applied:</i> Applied schemas are mutable in a way that allows you to add new schema facets. You can also add new, nonrequired attributes to existing schema facets. You can apply only published schemas to directories. </p> </li> </ul></p>
     fn create_schema(
         &self,
         input: CreateSchemaRequest,
     ) -> RusotoFuture<CreateSchemaResponse, CreateSchemaError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/create";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSchemaResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSchemaError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_schema(input)
     }
-
-    /// <p>Creates a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     fn create_typed_link_facet(
         &self,
         input: CreateTypedLinkFacetRequest,
     ) -> RusotoFuture<CreateTypedLinkFacetResponse, CreateTypedLinkFacetError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/create";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTypedLinkFacetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateTypedLinkFacetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_typed_link_facet(input)
     }
-
-    /// <p>Deletes a directory. Only disabled directories can be deleted. A deleted directory cannot be undone. Exercise extreme caution when deleting directories.</p>
     fn delete_directory(
         &self,
         input: DeleteDirectoryRequest,
     ) -> RusotoFuture<DeleteDirectoryResponse, DeleteDirectoryError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/directory";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDirectoryResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDirectoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_directory(input)
     }
-
-    /// <p>Deletes a given <a>Facet</a>. All attributes and <a>Rule</a>s that are associated with the facet will be deleted. Only development schema facets are allowed deletion.</p>
     fn delete_facet(
         &self,
         input: DeleteFacetRequest,
     ) -> RusotoFuture<DeleteFacetResponse, DeleteFacetError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/facet/delete";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteFacetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFacetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_facet(input)
     }
-
-    /// <p>Deletes an object and its associated attributes. Only objects with no children and no parents can be deleted. The maximum number of attributes that can be deleted during an object deletion is 30. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/limits.html">Amazon Cloud Directory Limits</a>.</p>
     fn delete_object(
         &self,
         input: DeleteObjectRequest,
     ) -> RusotoFuture<DeleteObjectResponse, DeleteObjectError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/delete";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteObjectResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteObjectError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_object(input)
     }
-
-    /// <p>Deletes a given schema. Schemas in a development and published state can only be deleted. </p>
     fn delete_schema(
         &self,
         input: DeleteSchemaRequest,
     ) -> RusotoFuture<DeleteSchemaResponse, DeleteSchemaError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSchemaResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteSchemaError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_schema(input)
     }
-
-    /// <p>Deletes a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     fn delete_typed_link_facet(
         &self,
         input: DeleteTypedLinkFacetRequest,
     ) -> RusotoFuture<DeleteTypedLinkFacetResponse, DeleteTypedLinkFacetError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/delete";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTypedLinkFacetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteTypedLinkFacetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_typed_link_facet(input)
     }
-
-    /// <p>Detaches the specified object from the specified index.</p>
     fn detach_from_index(
         &self,
         input: DetachFromIndexRequest,
     ) -> RusotoFuture<DetachFromIndexResponse, DetachFromIndexError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/index/detach";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetachFromIndexResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetachFromIndexError::from_response(response))),
-                )
-            }
-        })
+        (**self).detach_from_index(input)
     }
-
-    /// <p>Detaches a given object from the parent object. The object that is to be detached from the parent is specified by the link name.</p>
     fn detach_object(
         &self,
         input: DetachObjectRequest,
     ) -> RusotoFuture<DetachObjectResponse, DetachObjectError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/detach";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetachObjectResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetachObjectError::from_response(response))),
-                )
-            }
-        })
+        (**self).detach_object(input)
     }
-
-    /// <p>Detaches a policy from an object.</p>
     fn detach_policy(
         &self,
         input: DetachPolicyRequest,
     ) -> RusotoFuture<DetachPolicyResponse, DetachPolicyError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/policy/detach";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetachPolicyResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetachPolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).detach_policy(input)
     }
-
-    /// <p>Detaches a typed link from a specified source and target object. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     fn detach_typed_link(
         &self,
         input: DetachTypedLinkRequest,
     ) -> RusotoFuture<(), DetachTypedLinkError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/detach";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetachTypedLinkError::from_response(response))),
-                )
-            }
-        })
+        (**self).detach_typed_link(input)
     }
-
-    /// <p>Disables the specified directory. Disabled directories cannot be read or written to. Only enabled directories can be disabled. Disabled directories may be reenabled.</p>
     fn disable_directory(
         &self,
         input: DisableDirectoryRequest,
     ) -> RusotoFuture<DisableDirectoryResponse, DisableDirectoryError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/directory/disable";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisableDirectoryResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisableDirectoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).disable_directory(input)
     }
-
-    /// <p>Enables the specified directory. Only disabled directories can be enabled. Once enabled, the directory can then be read and written to.</p>
     fn enable_directory(
         &self,
         input: EnableDirectoryRequest,
     ) -> RusotoFuture<EnableDirectoryResponse, EnableDirectoryError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/directory/enable";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnableDirectoryResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableDirectoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).enable_directory(input)
     }
-
-    /// <p>Returns current applied schema version ARN, including the minor version in use.</p>
     fn get_applied_schema_version(
         &self,
         input: GetAppliedSchemaVersionRequest,
     ) -> RusotoFuture<GetAppliedSchemaVersionResponse, GetAppliedSchemaVersionError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/getappliedschema";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAppliedSchemaVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetAppliedSchemaVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_applied_schema_version(input)
     }
-
-    /// <p>Retrieves metadata about a directory.</p>
     fn get_directory(
         &self,
         input: GetDirectoryRequest,
     ) -> RusotoFuture<GetDirectoryResponse, GetDirectoryError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/directory/get";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDirectoryResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDirectoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_directory(input)
     }
-
-    /// <p>Gets details of the <a>Facet</a>, such as facet name, attributes, <a>Rule</a>s, or <code>ObjectType</code>. You can call this on all kinds of schema facets -- published, development, or applied.</p>
     fn get_facet(&self, input: GetFacetRequest) -> RusotoFuture<GetFacetResponse, GetFacetError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/facet";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFacetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFacetError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_facet(input)
     }
-
-    /// <p>Retrieves attributes that are associated with a typed link.</p>
     fn get_link_attributes(
         &self,
         input: GetLinkAttributesRequest,
     ) -> RusotoFuture<GetLinkAttributesResponse, GetLinkAttributesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/get";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLinkAttributesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetLinkAttributesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_link_attributes(input)
     }
-
-    /// <p>Retrieves attributes within a facet that are associated with an object.</p>
     fn get_object_attributes(
         &self,
         input: GetObjectAttributesRequest,
     ) -> RusotoFuture<GetObjectAttributesResponse, GetObjectAttributesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes/get";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref consistency_level) = input.consistency_level {
-            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
-        }
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetObjectAttributesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetObjectAttributesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_object_attributes(input)
     }
-
-    /// <p>Retrieves metadata about an object.</p>
     fn get_object_information(
         &self,
         input: GetObjectInformationRequest,
     ) -> RusotoFuture<GetObjectInformationResponse, GetObjectInformationError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/information";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref consistency_level) = input.consistency_level {
-            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
-        }
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetObjectInformationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetObjectInformationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_object_information(input)
     }
-
-    /// <p>Retrieves a JSON representation of the schema. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_jsonformat.html#schemas_json">JSON Schema Format</a> for more information.</p>
     fn get_schema_as_json(
         &self,
         input: GetSchemaAsJsonRequest,
     ) -> RusotoFuture<GetSchemaAsJsonResponse, GetSchemaAsJsonError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSchemaAsJsonResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSchemaAsJsonError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_schema_as_json(input)
     }
-
-    /// <p>Returns the identity attribute order for a specific <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     fn get_typed_link_facet_information(
         &self,
         input: GetTypedLinkFacetInformationRequest,
     ) -> RusotoFuture<GetTypedLinkFacetInformationResponse, GetTypedLinkFacetInformationError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/get";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTypedLinkFacetInformationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetTypedLinkFacetInformationError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_typed_link_facet_information(input)
     }
-
-    /// <p>Lists schema major versions applied to a directory. If <code>SchemaArn</code> is provided, lists the minor version.</p>
     fn list_applied_schema_arns(
         &self,
         input: ListAppliedSchemaArnsRequest,
     ) -> RusotoFuture<ListAppliedSchemaArnsResponse, ListAppliedSchemaArnsError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/applied";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAppliedSchemaArnsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListAppliedSchemaArnsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_applied_schema_arns(input)
     }
-
-    /// <p>Lists indices attached to the specified object.</p>
     fn list_attached_indices(
         &self,
         input: ListAttachedIndicesRequest,
     ) -> RusotoFuture<ListAttachedIndicesResponse, ListAttachedIndicesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/indices";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref consistency_level) = input.consistency_level {
-            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
-        }
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAttachedIndicesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListAttachedIndicesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_attached_indices(input)
     }
-
-    /// <p>Retrieves each Amazon Resource Name (ARN) of schemas in the development state.</p>
     fn list_development_schema_arns(
         &self,
         input: ListDevelopmentSchemaArnsRequest,
     ) -> RusotoFuture<ListDevelopmentSchemaArnsResponse, ListDevelopmentSchemaArnsError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/development";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDevelopmentSchemaArnsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListDevelopmentSchemaArnsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_development_schema_arns(input)
     }
-
-    /// <p>Lists directories created within an account.</p>
     fn list_directories(
         &self,
         input: ListDirectoriesRequest,
     ) -> RusotoFuture<ListDirectoriesResponse, ListDirectoriesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/directory/list";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDirectoriesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDirectoriesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_directories(input)
     }
-
-    /// <p>Retrieves attributes attached to the facet.</p>
     fn list_facet_attributes(
         &self,
         input: ListFacetAttributesRequest,
     ) -> RusotoFuture<ListFacetAttributesResponse, ListFacetAttributesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/facet/attributes";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFacetAttributesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListFacetAttributesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_facet_attributes(input)
     }
-
-    /// <p>Retrieves the names of facets that exist in a schema.</p>
     fn list_facet_names(
         &self,
         input: ListFacetNamesRequest,
     ) -> RusotoFuture<ListFacetNamesResponse, ListFacetNamesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/facet/list";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFacetNamesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListFacetNamesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_facet_names(input)
     }
-
-    /// <p>Returns a paginated list of all the incoming <a>TypedLinkSpecifier</a> information for an object. It also supports filtering by typed link facet and identity attributes. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     fn list_incoming_typed_links(
         &self,
         input: ListIncomingTypedLinksRequest,
     ) -> RusotoFuture<ListIncomingTypedLinksResponse, ListIncomingTypedLinksError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/incoming";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListIncomingTypedLinksResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListIncomingTypedLinksError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_incoming_typed_links(input)
+    }
+    fn list_index(
+        &self,
+        input: ListIndexRequest,
+    ) -> RusotoFuture<ListIndexResponse, ListIndexError> {
+        (**self).list_index(input)
+    }
+    fn list_managed_schema_arns(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> RusotoFuture<ListManagedSchemaArnsResponse, ListManagedSchemaArnsError> {
+        (**self).list_managed_schema_arns(input)
+    }
+    fn list_object_attributes(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> RusotoFuture<ListObjectAttributesResponse, ListObjectAttributesError> {
+        (**self).list_object_attributes(input)
+    }
+    fn list_object_children(
+        &self,
+        input: ListObjectChildrenRequest,
+    ) -> RusotoFuture<ListObjectChildrenResponse, ListObjectChildrenError> {
+        (**self).list_object_children(input)
+    }
+    fn list_object_parent_paths(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> RusotoFuture<ListObjectParentPathsResponse, ListObjectParentPathsError> {
+        (**self).list_object_parent_paths(input)
+    }
+    fn list_object_parents(
+        &self,
+        input: ListObjectParentsRequest,
+    ) -> RusotoFuture<ListObjectParentsResponse, ListObjectParentsError> {
+        (**self).list_object_parents(input)
+    }
+    fn list_object_policies(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> RusotoFuture<ListObjectPoliciesResponse, ListObjectPoliciesError> {
+        (**self).list_object_policies(input)
+    }
+    fn list_outgoing_typed_links(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> RusotoFuture<ListOutgoingTypedLinksResponse, ListOutgoingTypedLinksError> {
+        (**self).list_outgoing_typed_links(input)
+    }
+    fn list_policy_attachments(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> RusotoFuture<ListPolicyAttachmentsResponse, ListPolicyAttachmentsError> {
+        (**self).list_policy_attachments(input)
+    }
+    fn list_published_schema_arns(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> RusotoFuture<ListPublishedSchemaArnsResponse, ListPublishedSchemaArnsError> {
+        (**self).list_published_schema_arns(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_typed_link_facet_attributes(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> RusotoFuture<ListTypedLinkFacetAttributesResponse, ListTypedLinkFacetAttributesError> {
+        (**self).list_typed_link_facet_attributes(input)
+    }
+    fn list_typed_link_facet_names(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> RusotoFuture<ListTypedLinkFacetNamesResponse, ListTypedLinkFacetNamesError> {
+        (**self).list_typed_link_facet_names(input)
+    }
+    fn lookup_policy(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> RusotoFuture<LookupPolicyResponse, LookupPolicyError> {
+        (**self).lookup_policy(input)
+    }
+    fn publish_schema(
+        &self,
+        input: PublishSchemaRequest,
+    ) -> RusotoFuture<PublishSchemaResponse, PublishSchemaError> {
+        (**self).publish_schema(input)
+    }
+    fn put_schema_from_json(
+        &self,
+        input: PutSchemaFromJsonRequest,
+    ) -> RusotoFuture<PutSchemaFromJsonResponse, PutSchemaFromJsonError> {
+        (**self).put_schema_from_json(input)
+    }
+    fn remove_facet_from_object(
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> RusotoFuture<RemoveFacetFromObjectResponse, RemoveFacetFromObjectError> {
+        (**self).remove_facet_from_object(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_facet(
+        &self,
+        input: UpdateFacetRequest,
+    ) -> RusotoFuture<UpdateFacetResponse, UpdateFacetError> {
+        (**self).update_facet(input)
+    }
+    fn update_link_attributes(
+        &self,
+        input: UpdateLinkAttributesRequest,
+    ) -> RusotoFuture<UpdateLinkAttributesResponse, UpdateLinkAttributesError> {
+        (**self).update_link_attributes(input)
+    }
+    fn update_object_attributes(
+        &self,
+        input: UpdateObjectAttributesRequest,
+    ) -> RusotoFuture<UpdateObjectAttributesResponse, UpdateObjectAttributesError> {
+        (**self).update_object_attributes(input)
+    }
+    fn update_schema(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> RusotoFuture<UpdateSchemaResponse, UpdateSchemaError> {
+        (**self).update_schema(input)
+    }
+    fn update_typed_link_facet(
+        &self,
+        input: UpdateTypedLinkFacetRequest,
+    ) -> RusotoFuture<UpdateTypedLinkFacetResponse, UpdateTypedLinkFacetError> {
+        (**self).update_typed_link_facet(input)
+    }
+    fn upgrade_applied_schema(
+        &self,
+        input: UpgradeAppliedSchemaRequest,
+    ) -> RusotoFuture<UpgradeAppliedSchemaResponse, UpgradeAppliedSchemaError> {
+        (**self).upgrade_applied_schema(input)
+    }
+    fn upgrade_published_schema(
+        &self,
+        input: UpgradePublishedSchemaRequest,
+    ) -> RusotoFuture<UpgradePublishedSchemaResponse, UpgradePublishedSchemaError> {
+        (**self).upgrade_published_schema(input)
+    }
+}
+impl<T: CloudDirectory + ?Sized> CloudDirectory for Box<T> {
+    fn add_facet_to_object(
+        &self,
+        input: AddFacetToObjectRequest,
+    ) -> RusotoFuture<AddFacetToObjectResponse, AddFacetToObjectError> {
+        (**self).add_facet_to_object(input)
+    }
+    fn apply_schema(
+        &self,
+        input: ApplySchemaRequest,
+    ) -> RusotoFuture<ApplySchemaResponse, ApplySchemaError> {
+        (**self).apply_schema(input)
+    }
+    fn attach_object(
+        &self,
+        input: AttachObjectRequest,
+    ) -> RusotoFuture<AttachObjectResponse, AttachObjectError> {
+        (**self).attach_object(input)
+    }
+    fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> RusotoFuture<AttachPolicyResponse, AttachPolicyError> {
+        (**self).attach_policy(input)
+    }
+    fn attach_to_index(
+        &self,
+        input: AttachToIndexRequest,
+    ) -> RusotoFuture<AttachToIndexResponse, AttachToIndexError> {
+        (**self).attach_to_index(input)
+    }
+    fn attach_typed_link(
+        &self,
+        input: AttachTypedLinkRequest,
+    ) -> RusotoFuture<AttachTypedLinkResponse, AttachTypedLinkError> {
+        (**self).attach_typed_link(input)
+    }
+    fn batch_read(
+        &self,
+        input: BatchReadRequest,
+    ) -> RusotoFuture<BatchReadResponse, BatchReadError> {
+        (**self).batch_read(input)
+    }
+    fn batch_write(
+        &self,
+        input: BatchWriteRequest,
+    ) -> RusotoFuture<BatchWriteResponse, BatchWriteError> {
+        (**self).batch_write(input)
+    }
+    fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> RusotoFuture<CreateDirectoryResponse, CreateDirectoryError> {
+        (**self).create_directory(input)
+    }
+    fn create_facet(
+        &self,
+        input: CreateFacetRequest,
+    ) -> RusotoFuture<CreateFacetResponse, CreateFacetError> {
+        (**self).create_facet(input)
+    }
+    fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> RusotoFuture<CreateIndexResponse, CreateIndexError> {
+        (**self).create_index(input)
+    }
+    fn create_object(
+        &self,
+        input: CreateObjectRequest,
+    ) -> RusotoFuture<CreateObjectResponse, CreateObjectError> {
+        (**self).create_object(input)
+    }
+    fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> RusotoFuture<CreateSchemaResponse, CreateSchemaError> {
+        (**self).create_schema(input)
+    }
+    fn create_typed_link_facet(
+        &self,
+        input: CreateTypedLinkFacetRequest,
+    ) -> RusotoFuture<CreateTypedLinkFacetResponse, CreateTypedLinkFacetError> {
+        (**self).create_typed_link_facet(input)
+    }
+    fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> RusotoFuture<DeleteDirectoryResponse, DeleteDirectoryError> {
+        (**self).delete_directory(input)
+    }
+    fn delete_facet(
+        &self,
+        input: DeleteFacetRequest,
+    ) -> RusotoFuture<DeleteFacetResponse, DeleteFacetError> {
+        (**self).delete_facet(input)
+    }
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectResponse, DeleteObjectError> {
+        (**self).delete_object(input)
+    }
+    fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> RusotoFuture<DeleteSchemaResponse, DeleteSchemaError> {
+        (**self).delete_schema(input)
+    }
+    fn delete_typed_link_facet(
+        &self,
+        input: DeleteTypedLinkFacetRequest,
+    ) -> RusotoFuture<DeleteTypedLinkFacetResponse, DeleteTypedLinkFacetError> {
+        (**self).delete_typed_link_facet(input)
+    }
+    fn detach_from_index(
+        &self,
+        input: DetachFromIndexRequest,
+    ) -> RusotoFuture<DetachFromIndexResponse, DetachFromIndexError> {
+        (**self).detach_from_index(input)
+    }
+    fn detach_object(
+        &self,
+        input: DetachObjectRequest,
+    ) -> RusotoFuture<DetachObjectResponse, DetachObjectError> {
+        (**self).detach_object(input)
+    }
+    fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> RusotoFuture<DetachPolicyResponse, DetachPolicyError> {
+        (**self).detach_policy(input)
+    }
+    fn detach_typed_link(
+        &self,
+        input: DetachTypedLinkRequest,
+    ) -> RusotoFuture<(), DetachTypedLinkError> {
+        (**self).detach_typed_link(input)
+    }
+    fn disable_directory(
+        &self,
+        input: DisableDirectoryRequest,
+    ) -> RusotoFuture<DisableDirectoryResponse, DisableDirectoryError> {
+        (**self).disable_directory(input)
+    }
+    fn enable_directory(
+        &self,
+        input: EnableDirectoryRequest,
+    ) -> RusotoFuture<EnableDirectoryResponse, EnableDirectoryError> {
+        (**self).enable_directory(input)
+    }
+    fn get_applied_schema_version(
+        &self,
+        input: GetAppliedSchemaVersionRequest,
+    ) -> RusotoFuture<GetAppliedSchemaVersionResponse, GetAppliedSchemaVersionError> {
+        (**self).get_applied_schema_version(input)
+    }
+    fn get_directory(
+        &self,
+        input: GetDirectoryRequest,
+    ) -> RusotoFuture<GetDirectoryResponse, GetDirectoryError> {
+        (**self).get_directory(input)
+    }
+    fn get_facet(&self, input: GetFacetRequest) -> RusotoFuture<GetFacetResponse, GetFacetError> {
+        (**self).get_facet(input)
+    }
+    fn get_link_attributes(
+        &self,
+        input: GetLinkAttributesRequest,
+    ) -> RusotoFuture<GetLinkAttributesResponse, GetLinkAttributesError> {
+        (**self).get_link_attributes(input)
+    }
+    fn get_object_attributes(
+        &self,
+        input: GetObjectAttributesRequest,
+    ) -> RusotoFuture<GetObjectAttributesResponse, GetObjectAttributesError> {
+        (**self).get_object_attributes(input)
+    }
+    fn get_object_information(
+        &self,
+        input: GetObjectInformationRequest,
+    ) -> RusotoFuture<GetObjectInformationResponse, GetObjectInformationError> {
+        (**self).get_object_information(input)
+    }
+    fn get_schema_as_json(
+        &self,
+        input: GetSchemaAsJsonRequest,
+    ) -> RusotoFuture<GetSchemaAsJsonResponse, GetSchemaAsJsonError> {
+        (**self).get_schema_as_json(input)
+    }
+    fn get_typed_link_facet_information(
+        &self,
+        input: GetTypedLinkFacetInformationRequest,
+    ) -> RusotoFuture<GetTypedLinkFacetInformationResponse, GetTypedLinkFacetInformationError> {
+        (**self).get_typed_link_facet_information(input)
+    }
+    fn list_applied_schema_arns(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> RusotoFuture<ListAppliedSchemaArnsResponse, ListAppliedSchemaArnsError> {
+        (**self).list_applied_schema_arns(input)
+    }
+    fn list_attached_indices(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> RusotoFuture<ListAttachedIndicesResponse, ListAttachedIndicesError> {
+        (**self).list_attached_indices(input)
+    }
+    fn list_development_schema_arns(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> RusotoFuture<ListDevelopmentSchemaArnsResponse, ListDevelopmentSchemaArnsError> {
+        (**self).list_development_schema_arns(input)
+    }
+    fn list_directories(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> RusotoFuture<ListDirectoriesResponse, ListDirectoriesError> {
+        (**self).list_directories(input)
+    }
+    fn list_facet_attributes(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> RusotoFuture<ListFacetAttributesResponse, ListFacetAttributesError> {
+        (**self).list_facet_attributes(input)
+    }
+    fn list_facet_names(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> RusotoFuture<ListFacetNamesResponse, ListFacetNamesError> {
+        (**self).list_facet_names(input)
+    }
+    fn list_incoming_typed_links(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> RusotoFuture<ListIncomingTypedLinksResponse, ListIncomingTypedLinksError> {
+        (**self).list_incoming_typed_links(input)
+    }
+    fn list_index(
+        &self,
+        input: ListIndexRequest,
+    ) -> RusotoFuture<ListIndexResponse, ListIndexError> {
+        (**self).list_index(input)
+    }
+    fn list_managed_schema_arns(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> RusotoFuture<ListManagedSchemaArnsResponse, ListManagedSchemaArnsError> {
+        (**self).list_managed_schema_arns(input)
+    }
+    fn list_object_attributes(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> RusotoFuture<ListObjectAttributesResponse, ListObjectAttributesError> {
+        (**self).list_object_attributes(input)
+    }
+    fn list_object_children(
+        &self,
+        input: ListObjectChildrenRequest,
+    ) -> RusotoFuture<ListObjectChildrenResponse, ListObjectChildrenError> {
+        (**self).list_object_children(input)
+    }
+    fn list_object_parent_paths(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> RusotoFuture<ListObjectParentPathsResponse, ListObjectParentPathsError> {
+        (**self).list_object_parent_paths(input)
+    }
+    fn list_object_parents(
+        &self,
+        input: ListObjectParentsRequest,
+    ) -> RusotoFuture<ListObjectParentsResponse, ListObjectParentsError> {
+        (**self).list_object_parents(input)
+    }
+    fn list_object_policies(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> RusotoFuture<ListObjectPoliciesResponse, ListObjectPoliciesError> {
+        (**self).list_object_policies(input)
+    }
+    fn list_outgoing_typed_links(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> RusotoFuture<ListOutgoingTypedLinksResponse, ListOutgoingTypedLinksError> {
+        (**self).list_outgoing_typed_links(input)
+    }
+    fn list_policy_attachments(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> RusotoFuture<ListPolicyAttachmentsResponse, ListPolicyAttachmentsError> {
+        (**self).list_policy_attachments(input)
+    }
+    fn list_published_schema_arns(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> RusotoFuture<ListPublishedSchemaArnsResponse, ListPublishedSchemaArnsError> {
+        (**self).list_published_schema_arns(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_typed_link_facet_attributes(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> RusotoFuture<ListTypedLinkFacetAttributesResponse, ListTypedLinkFacetAttributesError> {
+        (**self).list_typed_link_facet_attributes(input)
+    }
+    fn list_typed_link_facet_names(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> RusotoFuture<ListTypedLinkFacetNamesResponse, ListTypedLinkFacetNamesError> {
+        (**self).list_typed_link_facet_names(input)
+    }
+    fn lookup_policy(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> RusotoFuture<LookupPolicyResponse, LookupPolicyError> {
+        (**self).lookup_policy(input)
+    }
+    fn publish_schema(
+        &self,
+        input: PublishSchemaRequest,
+    ) -> RusotoFuture<PublishSchemaResponse, PublishSchemaError> {
+        (**self).publish_schema(input)
+    }
+    fn put_schema_from_json(
+        &self,
+        input: PutSchemaFromJsonRequest,
+    ) -> RusotoFuture<PutSchemaFromJsonResponse, PutSchemaFromJsonError> {
+        (**self).put_schema_from_json(input)
+    }
+    fn remove_facet_from_object(
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> RusotoFuture<RemoveFacetFromObjectResponse, RemoveFacetFromObjectError> {
+        (**self).remove_facet_from_object(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_facet(
+        &self,
+        input: UpdateFacetRequest,
+    ) -> RusotoFuture<UpdateFacetResponse, UpdateFacetError> {
+        (**self).update_facet(input)
+    }
+    fn update_link_attributes(
+        &self,
+        input: UpdateLinkAttributesRequest,
+    ) -> RusotoFuture<UpdateLinkAttributesResponse, UpdateLinkAttributesError> {
+        (**self).update_link_attributes(input)
+    }
+    fn update_object_attributes(
+        &self,
+        input: UpdateObjectAttributesRequest,
+    ) -> RusotoFuture<UpdateObjectAttributesResponse, UpdateObjectAttributesError> {
+        (**self).update_object_attributes(input)
+    }
+    fn update_schema(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> RusotoFuture<UpdateSchemaResponse, UpdateSchemaError> {
+        (**self).update_schema(input)
+    }
+    fn update_typed_link_facet(
+        &self,
+        input: UpdateTypedLinkFacetRequest,
+    ) -> RusotoFuture<UpdateTypedLinkFacetResponse, UpdateTypedLinkFacetError> {
+        (**self).update_typed_link_facet(input)
+    }
+    fn upgrade_applied_schema(
+        &self,
+        input: UpgradeAppliedSchemaRequest,
+    ) -> RusotoFuture<UpgradeAppliedSchemaResponse, UpgradeAppliedSchemaError> {
+        (**self).upgrade_applied_schema(input)
+    }
+    fn upgrade_published_schema(
+        &self,
+        input: UpgradePublishedSchemaRequest,
+    ) -> RusotoFuture<UpgradePublishedSchemaResponse, UpgradePublishedSchemaError> {
+        (**self).upgrade_published_schema(input)
+    }
+}
+impl<T: CloudDirectory + ?Sized> CloudDirectory for ::std::sync::Arc<T> {
+    fn add_facet_to_object(
+        &self,
+        input: AddFacetToObjectRequest,
+    ) -> RusotoFuture<AddFacetToObjectResponse, AddFacetToObjectError> {
+        (**self).add_facet_to_object(input)
+    }
+    fn apply_schema(
+        &self,
+        input: ApplySchemaRequest,
+    ) -> RusotoFuture<ApplySchemaResponse, ApplySchemaError> {
+        (**self).apply_schema(input)
+    }
+    fn attach_object(
+        &self,
+        input: AttachObjectRequest,
+    ) -> RusotoFuture<AttachObjectResponse, AttachObjectError> {
+        (**self).attach_object(input)
+    }
+    fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> RusotoFuture<AttachPolicyResponse, AttachPolicyError> {
+        (**self).attach_policy(input)
+    }
+    fn attach_to_index(
+        &self,
+        input: AttachToIndexRequest,
+    ) -> RusotoFuture<AttachToIndexResponse, AttachToIndexError> {
+        (**self).attach_to_index(input)
+    }
+    fn attach_typed_link(
+        &self,
+        input: AttachTypedLinkRequest,
+    ) -> RusotoFuture<AttachTypedLinkResponse, AttachTypedLinkError> {
+        (**self).attach_typed_link(input)
+    }
+    fn batch_read(
+        &self,
+        input: BatchReadRequest,
+    ) -> RusotoFuture<BatchReadResponse, BatchReadError> {
+        (**self).batch_read(input)
+    }
+    fn batch_write(
+        &self,
+        input: BatchWriteRequest,
+    ) -> RusotoFuture<BatchWriteResponse, BatchWriteError> {
+        (**self).batch_write(input)
+    }
+    fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> RusotoFuture<CreateDirectoryResponse, CreateDirectoryError> {
+        (**self).create_directory(input)
+    }
+    fn create_facet(
+        &self,
+        input: CreateFacetRequest,
+    ) -> RusotoFuture<CreateFacetResponse, CreateFacetError> {
+        (**self).create_facet(input)
+    }
+    fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> RusotoFuture<CreateIndexResponse, CreateIndexError> {
+        (**self).create_index(input)
+    }
+    fn create_object(
+        &self,
+        input: CreateObjectRequest,
+    ) -> RusotoFuture<CreateObjectResponse, CreateObjectError> {
+        (**self).create_object(input)
+    }
+    fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> RusotoFuture<CreateSchemaResponse, CreateSchemaError> {
+        (**self).create_schema(input)
+    }
+    fn create_typed_link_facet(
+        &self,
+        input: CreateTypedLinkFacetRequest,
+    ) -> RusotoFuture<CreateTypedLinkFacetResponse, CreateTypedLinkFacetError> {
+        (**self).create_typed_link_facet(input)
+    }
+    fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> RusotoFuture<DeleteDirectoryResponse, DeleteDirectoryError> {
+        (**self).delete_directory(input)
+    }
+    fn delete_facet(
+        &self,
+        input: DeleteFacetRequest,
+    ) -> RusotoFuture<DeleteFacetResponse, DeleteFacetError> {
+        (**self).delete_facet(input)
+    }
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectResponse, DeleteObjectError> {
+        (**self).delete_object(input)
+    }
+    fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> RusotoFuture<DeleteSchemaResponse, DeleteSchemaError> {
+        (**self).delete_schema(input)
+    }
+    fn delete_typed_link_facet(
+        &self,
+        input: DeleteTypedLinkFacetRequest,
+    ) -> RusotoFuture<DeleteTypedLinkFacetResponse, DeleteTypedLinkFacetError> {
+        (**self).delete_typed_link_facet(input)
+    }
+    fn detach_from_index(
+        &self,
+        input: DetachFromIndexRequest,
+    ) -> RusotoFuture<DetachFromIndexResponse, DetachFromIndexError> {
+        (**self).detach_from_index(input)
+    }
+    fn detach_object(
+        &self,
+        input: DetachObjectRequest,
+    ) -> RusotoFuture<DetachObjectResponse, DetachObjectError> {
+        (**self).detach_object(input)
+    }
+    fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> RusotoFuture<DetachPolicyResponse, DetachPolicyError> {
+        (**self).detach_policy(input)
+    }
+    fn detach_typed_link(
+        &self,
+        input: DetachTypedLinkRequest,
+    ) -> RusotoFuture<(), DetachTypedLinkError> {
+        (**self).detach_typed_link(input)
+    }
+    fn disable_directory(
+        &self,
+        input: DisableDirectoryRequest,
+    ) -> RusotoFuture<DisableDirectoryResponse, DisableDirectoryError> {
+        (**self).disable_directory(input)
+    }
+    fn enable_directory(
+        &self,
+        input: EnableDirectoryRequest,
+    ) -> RusotoFuture<EnableDirectoryResponse, EnableDirectoryError> {
+        (**self).enable_directory(input)
+    }
+    fn get_applied_schema_version(
+        &self,
+        input: GetAppliedSchemaVersionRequest,
+    ) -> RusotoFuture<GetAppliedSchemaVersionResponse, GetAppliedSchemaVersionError> {
+        (**self).get_applied_schema_version(input)
+    }
+    fn get_directory(
+        &self,
+        input: GetDirectoryRequest,
+    ) -> RusotoFuture<GetDirectoryResponse, GetDirectoryError> {
+        (**self).get_directory(input)
+    }
+    fn get_facet(&self, input: GetFacetRequest) -> RusotoFuture<GetFacetResponse, GetFacetError> {
+        (**self).get_facet(input)
+    }
+    fn get_link_attributes(
+        &self,
+        input: GetLinkAttributesRequest,
+    ) -> RusotoFuture<GetLinkAttributesResponse, GetLinkAttributesError> {
+        (**self).get_link_attributes(input)
+    }
+    fn get_object_attributes(
+        &self,
+        input: GetObjectAttributesRequest,
+    ) -> RusotoFuture<GetObjectAttributesResponse, GetObjectAttributesError> {
+        (**self).get_object_attributes(input)
+    }
+    fn get_object_information(
+        &self,
+        input: GetObjectInformationRequest,
+    ) -> RusotoFuture<GetObjectInformationResponse, GetObjectInformationError> {
+        (**self).get_object_information(input)
+    }
+    fn get_schema_as_json(
+        &self,
+        input: GetSchemaAsJsonRequest,
+    ) -> RusotoFuture<GetSchemaAsJsonResponse, GetSchemaAsJsonError> {
+        (**self).get_schema_as_json(input)
+    }
+    fn get_typed_link_facet_information(
+        &self,
+        input: GetTypedLinkFacetInformationRequest,
+    ) -> RusotoFuture<GetTypedLinkFacetInformationResponse, GetTypedLinkFacetInformationError> {
+        (**self).get_typed_link_facet_information(input)
+    }
+    fn list_applied_schema_arns(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> RusotoFuture<ListAppliedSchemaArnsResponse, ListAppliedSchemaArnsError> {
+        (**self).list_applied_schema_arns(input)
+    }
+    fn list_attached_indices(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> RusotoFuture<ListAttachedIndicesResponse, ListAttachedIndicesError> {
+        (**self).list_attached_indices(input)
+    }
+    fn list_development_schema_arns(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> RusotoFuture<ListDevelopmentSchemaArnsResponse, ListDevelopmentSchemaArnsError> {
+        (**self).list_development_schema_arns(input)
+    }
+    fn list_directories(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> RusotoFuture<ListDirectoriesResponse, ListDirectoriesError> {
+        (**self).list_directories(input)
+    }
+    fn list_facet_attributes(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> RusotoFuture<ListFacetAttributesResponse, ListFacetAttributesError> {
+        (**self).list_facet_attributes(input)
+    }
+    fn list_facet_names(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> RusotoFuture<ListFacetNamesResponse, ListFacetNamesError> {
+        (**self).list_facet_names(input)
+    }
+    fn list_incoming_typed_links(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> RusotoFuture<ListIncomingTypedLinksResponse, ListIncomingTypedLinksError> {
+        (**self).list_incoming_typed_links(input)
+    }
+    fn list_index(
+        &self,
+        input: ListIndexRequest,
+    ) -> RusotoFuture<ListIndexResponse, ListIndexError> {
+        (**self).list_index(input)
+    }
+    fn list_managed_schema_arns(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> RusotoFuture<ListManagedSchemaArnsResponse, ListManagedSchemaArnsError> {
+        (**self).list_managed_schema_arns(input)
+    }
+    fn list_object_attributes(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> RusotoFuture<ListObjectAttributesResponse, ListObjectAttributesError> {
+        (**self).list_object_attributes(input)
+    }
+    fn list_object_children(
+        &self,
+        input: ListObjectChildrenRequest,
+    ) -> RusotoFuture<ListObjectChildrenResponse, ListObjectChildrenError> {
+        (**self).list_object_children(input)
+    }
+    fn list_object_parent_paths(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> RusotoFuture<ListObjectParentPathsResponse, ListObjectParentPathsError> {
+        (**self).list_object_parent_paths(input)
+    }
+    fn list_object_parents(
+        &self,
+        input: ListObjectParentsRequest,
+    ) -> RusotoFuture<ListObjectParentsResponse, ListObjectParentsError> {
+        (**self).list_object_parents(input)
+    }
+    fn list_object_policies(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> RusotoFuture<ListObjectPoliciesResponse, ListObjectPoliciesError> {
+        (**self).list_object_policies(input)
+    }
+    fn list_outgoing_typed_links(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> RusotoFuture<ListOutgoingTypedLinksResponse, ListOutgoingTypedLinksError> {
+        (**self).list_outgoing_typed_links(input)
+    }
+    fn list_policy_attachments(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> RusotoFuture<ListPolicyAttachmentsResponse, ListPolicyAttachmentsError> {
+        (**self).list_policy_attachments(input)
+    }
+    fn list_published_schema_arns(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> RusotoFuture<ListPublishedSchemaArnsResponse, ListPublishedSchemaArnsError> {
+        (**self).list_published_schema_arns(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_typed_link_facet_attributes(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> RusotoFuture<ListTypedLinkFacetAttributesResponse, ListTypedLinkFacetAttributesError> {
+        (**self).list_typed_link_facet_attributes(input)
+    }
+    fn list_typed_link_facet_names(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> RusotoFuture<ListTypedLinkFacetNamesResponse, ListTypedLinkFacetNamesError> {
+        (**self).list_typed_link_facet_names(input)
+    }
+    fn lookup_policy(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> RusotoFuture<LookupPolicyResponse, LookupPolicyError> {
+        (**self).lookup_policy(input)
+    }
+    fn publish_schema(
+        &self,
+        input: PublishSchemaRequest,
+    ) -> RusotoFuture<PublishSchemaResponse, PublishSchemaError> {
+        (**self).publish_schema(input)
+    }
+    fn put_schema_from_json(
+        &self,
+        input: PutSchemaFromJsonRequest,
+    ) -> RusotoFuture<PutSchemaFromJsonResponse, PutSchemaFromJsonError> {
+        (**self).put_schema_from_json(input)
+    }
+    fn remove_facet_from_object(
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> RusotoFuture<RemoveFacetFromObjectResponse, RemoveFacetFromObjectError> {
+        (**self).remove_facet_from_object(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_facet(
+        &self,
+        input: UpdateFacetRequest,
+    ) -> RusotoFuture<UpdateFacetResponse, UpdateFacetError> {
+        (**self).update_facet(input)
+    }
+    fn update_link_attributes(
+        &self,
+        input: UpdateLinkAttributesRequest,
+    ) -> RusotoFuture<UpdateLinkAttributesResponse, UpdateLinkAttributesError> {
+        (**self).update_link_attributes(input)
+    }
+    fn update_object_attributes(
+        &self,
+        input: UpdateObjectAttributesRequest,
+    ) -> RusotoFuture<UpdateObjectAttributesResponse, UpdateObjectAttributesError> {
+        (**self).update_object_attributes(input)
+    }
+    fn update_schema(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> RusotoFuture<UpdateSchemaResponse, UpdateSchemaError> {
+        (**self).update_schema(input)
+    }
+    fn update_typed_link_facet(
+        &self,
+        input: UpdateTypedLinkFacetRequest,
+    ) -> RusotoFuture<UpdateTypedLinkFacetResponse, UpdateTypedLinkFacetError> {
+        (**self).update_typed_link_facet(input)
+    }
+    fn upgrade_applied_schema(
+        &self,
+        input: UpgradeAppliedSchemaRequest,
+    ) -> RusotoFuture<UpgradeAppliedSchemaResponse, UpgradeAppliedSchemaError> {
+        (**self).upgrade_applied_schema(input)
+    }
+    fn upgrade_published_schema(
+        &self,
+        input: UpgradePublishedSchemaRequest,
+    ) -> RusotoFuture<UpgradePublishedSchemaResponse, UpgradePublishedSchemaError> {
+        (**self).upgrade_published_schema(input)
+    }
+}
+/// A client for the Amazon CloudDirectory API.
+#[derive(Clone)]
+pub struct CloudDirectoryClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CloudDirectoryClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudDirectoryClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudDirectoryClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudDirectoryClient {
+        CloudDirectoryClient { client, region }
+    }
+}
+
+impl CloudDirectory for CloudDirectoryClient {
+    /// <p>Adds a new <a>Facet</a> to an object. An object can have more than one facet applied on it.</p>
+    fn add_facet_to_object(
+        &self,
+        input: AddFacetToObjectRequest,
+    ) -> RusotoFuture<AddFacetToObjectResponse, AddFacetToObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/facets";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AddFacetToObject");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AddFacetToObject")
+                        .deserialize::<AddFacetToObjectResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddFacetToObjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Copies the input published schema, at the specified version, into the <a>Directory</a> with the same name and version as that of the published schema.</p>
+    fn apply_schema(
+        &self,
+        input: ApplySchemaRequest,
+    ) -> RusotoFuture<ApplySchemaResponse, ApplySchemaError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/apply";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ApplySchema");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ApplySchema")
+                        .deserialize::<ApplySchemaResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ApplySchemaError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Attaches an existing object to another object. An object can be accessed in two ways:</p> <ol> <li> <p>Using the path</p> </li> <li> <p>Using <code>ObjectIdentifier</code> </p> </li> </ol></p>
+    fn attach_object(
+        &self,
+        input: AttachObjectRequest,
+    ) -> RusotoFuture<AttachObjectResponse, AttachObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/attach";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachObject");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AttachObject")
+                        .deserialize::<AttachObjectResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AttachObjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Attaches a policy object to a regular object. An object can have a limited number of attached policies.</p>
+    fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> RusotoFuture<AttachPolicyResponse, AttachPolicyError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/policy/attach";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachPolicy");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AttachPolicy")
+                        .deserialize::<AttachPolicyResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AttachPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Attaches the specified object to the specified index.</p>
+    fn attach_to_index(
+        &self,
+        input: AttachToIndexRequest,
+    ) -> RusotoFuture<AttachToIndexResponse, AttachToIndexError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/index/attach";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachToIndex");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AttachToIndex")
+                        .deserialize::<AttachToIndexResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AttachToIndexError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Attaches a typed link to a specified source and target object. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    fn attach_typed_link(
+        &self,
+        input: AttachTypedLinkRequest,
+    ) -> RusotoFuture<AttachTypedLinkResponse, AttachTypedLinkError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attach";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachTypedLink");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AttachTypedLink")
+                        .deserialize::<AttachTypedLinkResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AttachTypedLinkError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Performs all the read operations in a batch. </p>
+    fn batch_read(
+        &self,
+        input: BatchReadRequest,
+    ) -> RusotoFuture<BatchReadResponse, BatchReadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/batchread";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("BatchRead");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref consistency_level) = input.consistency_level {
+            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        }
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchRead")
+                        .deserialize::<BatchReadResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchReadError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Performs all the write operations in a batch. Either all the operations succeed or none.</p>
+    fn batch_write(
+        &self,
+        input: BatchWriteRequest,
+    ) -> RusotoFuture<BatchWriteResponse, BatchWriteError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/batchwrite";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("BatchWrite");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchWrite")
+                        .deserialize::<BatchWriteResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchWriteError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <a>Directory</a> by copying the published schema into the directory. A directory cannot be created without a schema.</p> <p>You can also quickly create a directory using a managed schema, called the <code>QuickStartSchema</code>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_managed.html">Managed Schema</a> in the <i>Amazon Cloud Directory Developer Guide</i>.</p>
+    fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> RusotoFuture<CreateDirectoryResponse, CreateDirectoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/directory/create";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateDirectory");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDirectory")
+                        .deserialize::<CreateDirectoryResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDirectoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new <a>Facet</a> in a schema. Facet creation is allowed only in development or applied schemas.</p>
+    fn create_facet(
+        &self,
+        input: CreateFacetRequest,
+    ) -> RusotoFuture<CreateFacetResponse, CreateFacetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/facet/create";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateFacet");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateFacet")
+                        .deserialize::<CreateFacetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateFacetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an index object. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/indexing_search.html">Indexing and search</a> for more information.</p>
+    fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> RusotoFuture<CreateIndexResponse, CreateIndexError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/index";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateIndex");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateIndex")
+                        .deserialize::<CreateIndexResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateIndexError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an object in a <a>Directory</a>. Additionally attaches the object to a parent, if a parent reference and <code>LinkName</code> is specified. An object is simply a collection of <a>Facet</a> attributes. You can also use this API call to create a policy object, if the facet from which you create the object is a policy facet. </p>
+    fn create_object(
+        &self,
+        input: CreateObjectRequest,
+    ) -> RusotoFuture<CreateObjectResponse, CreateObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateObject");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateObject")
+                        .deserialize::<CreateObjectResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateObjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a new schema in a development state. A schema can exist in three phases:</p> <ul> <li> <p> <i>Development:</i> This is a mutable phase of the schema. All new schemas are in the development phase. Once the schema is finalized, it can be published.</p> </li> <li> <p> <i>Published:</i> Published schemas are immutable and have a version associated with them.</p> </li> <li> <p> <i>Applied:</i> Applied schemas are mutable in a way that allows you to add new schema facets. You can also add new, nonrequired attributes to existing schema facets. You can apply only published schemas to directories. </p> </li> </ul></p>
+    fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> RusotoFuture<CreateSchemaResponse, CreateSchemaError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/create";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateSchema");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateSchema")
+                        .deserialize::<CreateSchemaResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSchemaError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    fn create_typed_link_facet(
+        &self,
+        input: CreateTypedLinkFacetRequest,
+    ) -> RusotoFuture<CreateTypedLinkFacetResponse, CreateTypedLinkFacetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/create";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateTypedLinkFacet");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateTypedLinkFacet")
+                        .deserialize::<CreateTypedLinkFacetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateTypedLinkFacetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a directory. Only disabled directories can be deleted. A deleted directory cannot be undone. Exercise extreme caution when deleting directories.</p>
+    fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> RusotoFuture<DeleteDirectoryResponse, DeleteDirectoryError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/directory";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteDirectory");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteDirectory")
+                        .deserialize::<DeleteDirectoryResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDirectoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a given <a>Facet</a>. All attributes and <a>Rule</a>s that are associated with the facet will be deleted. Only development schema facets are allowed deletion.</p>
+    fn delete_facet(
+        &self,
+        input: DeleteFacetRequest,
+    ) -> RusotoFuture<DeleteFacetResponse, DeleteFacetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/facet/delete";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteFacet");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteFacet")
+                        .deserialize::<DeleteFacetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFacetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an object and its associated attributes. Only objects with no children and no parents can be deleted. The maximum number of attributes that can be deleted during an object deletion is 30. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/limits.html">Amazon Cloud Directory Limits</a>.</p>
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectResponse, DeleteObjectError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/delete";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteObject");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteObject")
+                        .deserialize::<DeleteObjectResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteObjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a given schema. Schemas in a development and published state can only be deleted. </p>
+    fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> RusotoFuture<DeleteSchemaResponse, DeleteSchemaError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteSchema");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteSchema")
+                        .deserialize::<DeleteSchemaResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteSchemaError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    fn delete_typed_link_facet(
+        &self,
+        input: DeleteTypedLinkFacetRequest,
+    ) -> RusotoFuture<DeleteTypedLinkFacetResponse, DeleteTypedLinkFacetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/delete";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteTypedLinkFacet");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteTypedLinkFacet")
+                        .deserialize::<DeleteTypedLinkFacetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteTypedLinkFacetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Detaches the specified object from the specified index.</p>
+    fn detach_from_index(
+        &self,
+        input: DetachFromIndexRequest,
+    ) -> RusotoFuture<DetachFromIndexResponse, DetachFromIndexError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/index/detach";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachFromIndex");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DetachFromIndex")
+                        .deserialize::<DetachFromIndexResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetachFromIndexError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Detaches a given object from the parent object. The object that is to be detached from the parent is specified by the link name.</p>
+    fn detach_object(
+        &self,
+        input: DetachObjectRequest,
+    ) -> RusotoFuture<DetachObjectResponse, DetachObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/detach";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachObject");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DetachObject")
+                        .deserialize::<DetachObjectResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetachObjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Detaches a policy from an object.</p>
+    fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> RusotoFuture<DetachPolicyResponse, DetachPolicyError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/policy/detach";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachPolicy");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DetachPolicy")
+                        .deserialize::<DetachPolicyResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetachPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Detaches a typed link from a specified source and target object. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    fn detach_typed_link(
+        &self,
+        input: DetachTypedLinkRequest,
+    ) -> RusotoFuture<(), DetachTypedLinkError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/detach";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachTypedLink");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetachTypedLinkError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables the specified directory. Disabled directories cannot be read or written to. Only enabled directories can be disabled. Disabled directories may be reenabled.</p>
+    fn disable_directory(
+        &self,
+        input: DisableDirectoryRequest,
+    ) -> RusotoFuture<DisableDirectoryResponse, DisableDirectoryError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/directory/disable";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DisableDirectory");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisableDirectory")
+                        .deserialize::<DisableDirectoryResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisableDirectoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables the specified directory. Only disabled directories can be enabled. Once enabled, the directory can then be read and written to.</p>
+    fn enable_directory(
+        &self,
+        input: EnableDirectoryRequest,
+    ) -> RusotoFuture<EnableDirectoryResponse, EnableDirectoryError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/directory/enable";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("EnableDirectory");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("EnableDirectory")
+                        .deserialize::<EnableDirectoryResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableDirectoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns current applied schema version ARN, including the minor version in use.</p>
+    fn get_applied_schema_version(
+        &self,
+        input: GetAppliedSchemaVersionRequest,
+    ) -> RusotoFuture<GetAppliedSchemaVersionResponse, GetAppliedSchemaVersionError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/getappliedschema";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetAppliedSchemaVersion");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAppliedSchemaVersion")
+                        .deserialize::<GetAppliedSchemaVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetAppliedSchemaVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves metadata about a directory.</p>
+    fn get_directory(
+        &self,
+        input: GetDirectoryRequest,
+    ) -> RusotoFuture<GetDirectoryResponse, GetDirectoryError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/directory/get";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetDirectory");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDirectory")
+                        .deserialize::<GetDirectoryResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDirectoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets details of the <a>Facet</a>, such as facet name, attributes, <a>Rule</a>s, or <code>ObjectType</code>. You can call this on all kinds of schema facets -- published, development, or applied.</p>
+    fn get_facet(&self, input: GetFacetRequest) -> RusotoFuture<GetFacetResponse, GetFacetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/facet";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetFacet");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFacet")
+                        .deserialize::<GetFacetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFacetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves attributes that are associated with a typed link.</p>
+    fn get_link_attributes(
+        &self,
+        input: GetLinkAttributesRequest,
+    ) -> RusotoFuture<GetLinkAttributesResponse, GetLinkAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/get";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetLinkAttributes");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetLinkAttributes")
+                        .deserialize::<GetLinkAttributesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetLinkAttributesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves attributes within a facet that are associated with an object.</p>
+    fn get_object_attributes(
+        &self,
+        input: GetObjectAttributesRequest,
+    ) -> RusotoFuture<GetObjectAttributesResponse, GetObjectAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes/get";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetObjectAttributes");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref consistency_level) = input.consistency_level {
+            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        }
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetObjectAttributes")
+                        .deserialize::<GetObjectAttributesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetObjectAttributesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves metadata about an object.</p>
+    fn get_object_information(
+        &self,
+        input: GetObjectInformationRequest,
+    ) -> RusotoFuture<GetObjectInformationResponse, GetObjectInformationError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/information";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetObjectInformation");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref consistency_level) = input.consistency_level {
+            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        }
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetObjectInformation")
+                        .deserialize::<GetObjectInformationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetObjectInformationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a JSON representation of the schema. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_jsonformat.html#schemas_json">JSON Schema Format</a> for more information.</p>
+    fn get_schema_as_json(
+        &self,
+        input: GetSchemaAsJsonRequest,
+    ) -> RusotoFuture<GetSchemaAsJsonResponse, GetSchemaAsJsonError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetSchemaAsJson");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSchemaAsJson")
+                        .deserialize::<GetSchemaAsJsonResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSchemaAsJsonError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the identity attribute order for a specific <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    fn get_typed_link_facet_information(
+        &self,
+        input: GetTypedLinkFacetInformationRequest,
+    ) -> RusotoFuture<GetTypedLinkFacetInformationResponse, GetTypedLinkFacetInformationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/get";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetTypedLinkFacetInformation");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetTypedLinkFacetInformation")
+                        .deserialize::<GetTypedLinkFacetInformationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetTypedLinkFacetInformationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists schema major versions applied to a directory. If <code>SchemaArn</code> is provided, lists the minor version.</p>
+    fn list_applied_schema_arns(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> RusotoFuture<ListAppliedSchemaArnsResponse, ListAppliedSchemaArnsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/applied";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListAppliedSchemaArns");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListAppliedSchemaArns")
+                        .deserialize::<ListAppliedSchemaArnsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListAppliedSchemaArnsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists indices attached to the specified object.</p>
+    fn list_attached_indices(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> RusotoFuture<ListAttachedIndicesResponse, ListAttachedIndicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/indices";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListAttachedIndices");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref consistency_level) = input.consistency_level {
+            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        }
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListAttachedIndices")
+                        .deserialize::<ListAttachedIndicesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListAttachedIndicesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves each Amazon Resource Name (ARN) of schemas in the development state.</p>
+    fn list_development_schema_arns(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> RusotoFuture<ListDevelopmentSchemaArnsResponse, ListDevelopmentSchemaArnsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/development";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListDevelopmentSchemaArns");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDevelopmentSchemaArns")
+                        .deserialize::<ListDevelopmentSchemaArnsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListDevelopmentSchemaArnsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists directories created within an account.</p>
+    fn list_directories(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> RusotoFuture<ListDirectoriesResponse, ListDirectoriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/directory/list";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListDirectories");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDirectories")
+                        .deserialize::<ListDirectoriesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDirectoriesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves attributes attached to the facet.</p>
+    fn list_facet_attributes(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> RusotoFuture<ListFacetAttributesResponse, ListFacetAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/facet/attributes";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListFacetAttributes");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListFacetAttributes")
+                        .deserialize::<ListFacetAttributesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListFacetAttributesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the names of facets that exist in a schema.</p>
+    fn list_facet_names(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> RusotoFuture<ListFacetNamesResponse, ListFacetNamesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/facet/list";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListFacetNames");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListFacetNames")
+                        .deserialize::<ListFacetNamesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListFacetNamesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a paginated list of all the incoming <a>TypedLinkSpecifier</a> information for an object. It also supports filtering by typed link facet and identity attributes. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    fn list_incoming_typed_links(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> RusotoFuture<ListIncomingTypedLinksResponse, ListIncomingTypedLinksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/incoming";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListIncomingTypedLinks");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListIncomingTypedLinks")
+                        .deserialize::<ListIncomingTypedLinksResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListIncomingTypedLinksError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists objects attached to the specified index.</p>
+    fn list_index(
+        &self,
+        input: ListIndexRequest,
+    ) -> RusotoFuture<ListIndexResponse, ListIndexError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/index/targets";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListIndex");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref consistency_level) = input.consistency_level {
+            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        }
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListIndex")
+                        .deserialize::<ListIndexResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListIndexError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the major version families of each managed schema. If a major version ARN is provided as SchemaArn, the minor version revisions in that family are listed instead.</p>
+    fn list_managed_schema_arns(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> RusotoFuture<ListManagedSchemaArnsResponse, ListManagedSchemaArnsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/managed";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListManagedSchemaArns");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListManagedSchemaArns")
+                        .deserialize::<ListManagedSchemaArnsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListManagedSchemaArnsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all attributes that are associated with an object. </p>
+    fn list_object_attributes(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> RusotoFuture<ListObjectAttributesResponse, ListObjectAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectAttributes");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref consistency_level) = input.consistency_level {
+            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        }
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListObjectAttributes")
+                        .deserialize::<ListObjectAttributesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListObjectAttributesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a paginated list of child objects that are associated with a given object.</p>
+    fn list_object_children(
+        &self,
+        input: ListObjectChildrenRequest,
+    ) -> RusotoFuture<ListObjectChildrenResponse, ListObjectChildrenError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/children";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectChildren");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref consistency_level) = input.consistency_level {
+            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        }
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListObjectChildren")
+                        .deserialize::<ListObjectChildrenResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListObjectChildrenError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves all available parent paths for any object type such as node, leaf node, policy node, and index node objects. For more information about objects, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directorystructure.html">Directory Structure</a>.</p> <p>Use this API to evaluate all parents for an object. The call returns all objects from the root of the directory up to the requested object. The API returns the number of paths based on user-defined <code>MaxResults</code>, in case there are multiple paths to the parent. The order of the paths and nodes returned is consistent among multiple API calls unless the objects are deleted or moved. Paths not leading to the directory root are ignored from the target object.</p>
+    fn list_object_parent_paths(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> RusotoFuture<ListObjectParentPathsResponse, ListObjectParentPathsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/parentpaths";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectParentPaths");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListObjectParentPaths")
+                        .deserialize::<ListObjectParentPathsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListObjectParentPathsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists parent objects that are associated with a given object in pagination fashion.</p>
+    fn list_object_parents(
+        &self,
+        input: ListObjectParentsRequest,
+    ) -> RusotoFuture<ListObjectParentsResponse, ListObjectParentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/parent";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectParents");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref consistency_level) = input.consistency_level {
+            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        }
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListObjectParents")
+                        .deserialize::<ListObjectParentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListObjectParentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns policies attached to an object in pagination fashion.</p>
+    fn list_object_policies(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> RusotoFuture<ListObjectPoliciesResponse, ListObjectPoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/policy";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectPolicies");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref consistency_level) = input.consistency_level {
+            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        }
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListObjectPolicies")
+                        .deserialize::<ListObjectPoliciesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListObjectPoliciesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a paginated list of all the outgoing <a>TypedLinkSpecifier</a> information for an object. It also supports filtering by typed link facet and identity attributes. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    fn list_outgoing_typed_links(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> RusotoFuture<ListOutgoingTypedLinksResponse, ListOutgoingTypedLinksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/outgoing";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListOutgoingTypedLinks");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListOutgoingTypedLinks")
+                        .deserialize::<ListOutgoingTypedLinksResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListOutgoingTypedLinksError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns all of the <code>ObjectIdentifiers</code> to which a given policy is attached.</p>
+    fn list_policy_attachments(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> RusotoFuture<ListPolicyAttachmentsResponse, ListPolicyAttachmentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/policy/attachment";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListPolicyAttachments");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref consistency_level) = input.consistency_level {
+            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        }
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListPolicyAttachments")
+                        .deserialize::<ListPolicyAttachmentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListPolicyAttachmentsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the major version families of each published schema. If a major version ARN is provided as <code>SchemaArn</code>, the minor version revisions in that family are listed instead.</p>
+    fn list_published_schema_arns(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> RusotoFuture<ListPublishedSchemaArnsResponse, ListPublishedSchemaArnsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/published";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListPublishedSchemaArns");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListPublishedSchemaArns")
+                        .deserialize::<ListPublishedSchemaArnsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListPublishedSchemaArnsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns tags for a resource. Tagging is currently supported only for directories with a limit of 50 tags per directory. All 50 tags are returned for a given directory with this API call.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/tags";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
+                        .deserialize::<ListTagsForResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a paginated list of all attribute definitions for a particular <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    fn list_typed_link_facet_attributes(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> RusotoFuture<ListTypedLinkFacetAttributesResponse, ListTypedLinkFacetAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/attributes";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTypedLinkFacetAttributes");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTypedLinkFacetAttributes")
+                        .deserialize::<ListTypedLinkFacetAttributesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListTypedLinkFacetAttributesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a paginated list of <code>TypedLink</code> facet names for a particular schema. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    fn list_typed_link_facet_names(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> RusotoFuture<ListTypedLinkFacetNamesResponse, ListTypedLinkFacetNamesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/list";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTypedLinkFacetNames");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTypedLinkFacetNames")
+                        .deserialize::<ListTypedLinkFacetNamesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListTypedLinkFacetNamesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all policies from the root of the <a>Directory</a> to the object specified. If there are no policies present, an empty list is returned. If policies are present, and if some objects don't have the policies attached, it returns the <code>ObjectIdentifier</code> for such objects. If policies are present, it returns <code>ObjectIdentifier</code>, <code>policyId</code>, and <code>policyType</code>. Paths that don't lead to the root from the target object are ignored. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directory.html#key_concepts_policies">Policies</a>.</p>
+    fn lookup_policy(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> RusotoFuture<LookupPolicyResponse, LookupPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/policy/lookup";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("LookupPolicy");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("LookupPolicy")
+                        .deserialize::<LookupPolicyResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(LookupPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Publishes a development schema with a major version and a recommended minor version.</p>
+    fn publish_schema(
+        &self,
+        input: PublishSchemaRequest,
+    ) -> RusotoFuture<PublishSchemaResponse, PublishSchemaError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/publish";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("PublishSchema");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.development_schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PublishSchema")
+                        .deserialize::<PublishSchemaResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PublishSchemaError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Allows a schema to be updated using JSON upload. Only available for development schemas. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_jsonformat.html#schemas_json">JSON Schema Format</a> for more information.</p>
+    fn put_schema_from_json(
+        &self,
+        input: PutSchemaFromJsonRequest,
+    ) -> RusotoFuture<PutSchemaFromJsonResponse, PutSchemaFromJsonError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("PutSchemaFromJson");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutSchemaFromJson")
+                        .deserialize::<PutSchemaFromJsonResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutSchemaFromJsonError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the specified facet from the specified object.</p>
+    fn remove_facet_from_object(
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> RusotoFuture<RemoveFacetFromObjectResponse, RemoveFacetFromObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/facets/delete";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("RemoveFacetFromObject");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RemoveFacetFromObject")
+                        .deserialize::<RemoveFacetFromObjectResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RemoveFacetFromObjectError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>An API operation for adding tags to a resource.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/tags/add";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
+                        .deserialize::<TagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>An API operation for removing tags from a resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/tags/remove";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
+                        .deserialize::<UntagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Does the following:</p> <ol> <li> <p>Adds new <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> <li> <p>Updates existing <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> <li> <p>Deletes existing <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> </ol></p>
+    fn update_facet(
+        &self,
+        input: UpdateFacetRequest,
+    ) -> RusotoFuture<UpdateFacetResponse, UpdateFacetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/facet";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateFacet");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateFacet")
+                        .deserialize::<UpdateFacetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateFacetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a given typed link’s attributes. Attributes to be updated must not contribute to the typed link’s identity, as defined by its <code>IdentityAttributeOrder</code>.</p>
+    fn update_link_attributes(
+        &self,
+        input: UpdateLinkAttributesRequest,
+    ) -> RusotoFuture<UpdateLinkAttributesResponse, UpdateLinkAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/update";
+
+        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateLinkAttributes");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateLinkAttributes")
+                        .deserialize::<UpdateLinkAttributesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateLinkAttributesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a given object's attributes.</p>
+    fn update_object_attributes(
+        &self,
+        input: UpdateObjectAttributesRequest,
+    ) -> RusotoFuture<UpdateObjectAttributesResponse, UpdateObjectAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/object/update";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateObjectAttributes");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.directory_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateObjectAttributes")
+                        .deserialize::<UpdateObjectAttributesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateObjectAttributesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the schema name with a new name. Only development schema names can be updated.</p>
+    fn update_schema(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> RusotoFuture<UpdateSchemaResponse, UpdateSchemaError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/update";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateSchema");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateSchema")
+                        .deserialize::<UpdateSchemaResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateSchemaError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    fn update_typed_link_facet(
+        &self,
+        input: UpdateTypedLinkFacetRequest,
+    ) -> RusotoFuture<UpdateTypedLinkFacetResponse, UpdateTypedLinkFacetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateTypedLinkFacet");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("x-amz-data-partition", &input.schema_arn);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateTypedLinkFacet")
+                        .deserialize::<UpdateTypedLinkFacetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateTypedLinkFacetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Upgrades a single directory in-place using the <code>PublishedSchemaArn</code> with schema updates found in <code>MinorVersion</code>. Backwards-compatible minor version upgrades are instantaneously available for readers on all objects in the directory. Note: This is a synchronous API call and upgrades only one schema on a given directory per call. To upgrade multiple directories from one schema, you would need to call this API on each directory.</p>
+    fn upgrade_applied_schema(
+        &self,
+        input: UpgradeAppliedSchemaRequest,
+    ) -> RusotoFuture<UpgradeAppliedSchemaResponse, UpgradeAppliedSchemaError> {
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradeapplied";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpgradeAppliedSchema");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpgradeAppliedSchema")
+                        .deserialize::<UpgradeAppliedSchemaResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpgradeAppliedSchemaError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Upgrades a published schema under a new minor version revision using the current contents of <code>DevelopmentSchemaArn</code>.</p>
+    fn upgrade_published_schema(
+        &self,
+        input: UpgradePublishedSchemaRequest,
+    ) -> RusotoFuture<UpgradePublishedSchemaResponse, UpgradePublishedSchemaError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradepublished";
+
+        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpgradePublishedSchema");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpgradePublishedSchema")
+                        .deserialize::<UpgradePublishedSchemaResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpgradePublishedSchemaError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon CloudDirectory API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloudDirectory {
+    add_facet_to_object: Option<
+        Box<
+            dyn Fn(
+                    AddFacetToObjectRequest,
+                ) -> ::std::result::Result<
+                    AddFacetToObjectResponse,
+                    RusotoError<AddFacetToObjectError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    apply_schema: Option<
+        Box<
+            dyn Fn(
+                    ApplySchemaRequest,
+                )
+                    -> ::std::result::Result<ApplySchemaResponse, RusotoError<ApplySchemaError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    attach_object: Option<
+        Box<
+            dyn Fn(
+                    AttachObjectRequest,
+                )
+                    -> ::std::result::Result<AttachObjectResponse, RusotoError<AttachObjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    attach_policy: Option<
+        Box<
+            dyn Fn(
+                    AttachPolicyRequest,
+                )
+                    -> ::std::result::Result<AttachPolicyResponse, RusotoError<AttachPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    attach_to_index: Option<
+        Box<
+            dyn Fn(
+                    AttachToIndexRequest,
+                )
+                    -> ::std::result::Result<AttachToIndexResponse, RusotoError<AttachToIndexError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    attach_typed_link: Option<
+        Box<
+            dyn Fn(
+                    AttachTypedLinkRequest,
+                ) -> ::std::result::Result<
+                    AttachTypedLinkResponse,
+                    RusotoError<AttachTypedLinkError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_read: Option<
+        Box<
+            dyn Fn(
+                    BatchReadRequest,
+                )
+                    -> ::std::result::Result<BatchReadResponse, RusotoError<BatchReadError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    batch_write: Option<
+        Box<
+            dyn Fn(
+                    BatchWriteRequest,
+                )
+                    -> ::std::result::Result<BatchWriteResponse, RusotoError<BatchWriteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_directory: Option<
+        Box<
+            dyn Fn(
+                    CreateDirectoryRequest,
+                ) -> ::std::result::Result<
+                    CreateDirectoryResponse,
+                    RusotoError<CreateDirectoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_facet: Option<
+        Box<
+            dyn Fn(
+                    CreateFacetRequest,
+                )
+                    -> ::std::result::Result<CreateFacetResponse, RusotoError<CreateFacetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_index: Option<
+        Box<
+            dyn Fn(
+                    CreateIndexRequest,
+                )
+                    -> ::std::result::Result<CreateIndexResponse, RusotoError<CreateIndexError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_object: Option<
+        Box<
+            dyn Fn(
+                    CreateObjectRequest,
+                )
+                    -> ::std::result::Result<CreateObjectResponse, RusotoError<CreateObjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_schema: Option<
+        Box<
+            dyn Fn(
+                    CreateSchemaRequest,
+                )
+                    -> ::std::result::Result<CreateSchemaResponse, RusotoError<CreateSchemaError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_typed_link_facet: Option<
+        Box<
+            dyn Fn(
+                    CreateTypedLinkFacetRequest,
+                ) -> ::std::result::Result<
+                    CreateTypedLinkFacetResponse,
+                    RusotoError<CreateTypedLinkFacetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_directory: Option<
+        Box<
+            dyn Fn(
+                    DeleteDirectoryRequest,
+                ) -> ::std::result::Result<
+                    DeleteDirectoryResponse,
+                    RusotoError<DeleteDirectoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_facet: Option<
+        Box<
+            dyn Fn(
+                    DeleteFacetRequest,
+                )
+                    -> ::std::result::Result<DeleteFacetResponse, RusotoError<DeleteFacetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_object: Option<
+        Box<
+            dyn Fn(
+                    DeleteObjectRequest,
+                )
+                    -> ::std::result::Result<DeleteObjectResponse, RusotoError<DeleteObjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_schema: Option<
+        Box<
+            dyn Fn(
+                    DeleteSchemaRequest,
+                )
+                    -> ::std::result::Result<DeleteSchemaResponse, RusotoError<DeleteSchemaError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_typed_link_facet: Option<
+        Box<
+            dyn Fn(
+                    DeleteTypedLinkFacetRequest,
+                ) -> ::std::result::Result<
+                    DeleteTypedLinkFacetResponse,
+                    RusotoError<DeleteTypedLinkFacetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detach_from_index: Option<
+        Box<
+            dyn Fn(
+                    DetachFromIndexRequest,
+                ) -> ::std::result::Result<
+                    DetachFromIndexResponse,
+                    RusotoError<DetachFromIndexError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detach_object: Option<
+        Box<
+            dyn Fn(
+                    DetachObjectRequest,
+                )
+                    -> ::std::result::Result<DetachObjectResponse, RusotoError<DetachObjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    detach_policy: Option<
+        Box<
+            dyn Fn(
+                    DetachPolicyRequest,
+                )
+                    -> ::std::result::Result<DetachPolicyResponse, RusotoError<DetachPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    detach_typed_link: Option<
+        Box<
+            dyn Fn(
+                    DetachTypedLinkRequest,
+                ) -> ::std::result::Result<(), RusotoError<DetachTypedLinkError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_directory: Option<
+        Box<
+            dyn Fn(
+                    DisableDirectoryRequest,
+                ) -> ::std::result::Result<
+                    DisableDirectoryResponse,
+                    RusotoError<DisableDirectoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_directory: Option<
+        Box<
+            dyn Fn(
+                    EnableDirectoryRequest,
+                ) -> ::std::result::Result<
+                    EnableDirectoryResponse,
+                    RusotoError<EnableDirectoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_applied_schema_version: Option<
+        Box<
+            dyn Fn(
+                    GetAppliedSchemaVersionRequest,
+                ) -> ::std::result::Result<
+                    GetAppliedSchemaVersionResponse,
+                    RusotoError<GetAppliedSchemaVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_directory: Option<
+        Box<
+            dyn Fn(
+                    GetDirectoryRequest,
+                )
+                    -> ::std::result::Result<GetDirectoryResponse, RusotoError<GetDirectoryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_facet: Option<
+        Box<
+            dyn Fn(
+                    GetFacetRequest,
+                )
+                    -> ::std::result::Result<GetFacetResponse, RusotoError<GetFacetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_link_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetLinkAttributesRequest,
+                ) -> ::std::result::Result<
+                    GetLinkAttributesResponse,
+                    RusotoError<GetLinkAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_object_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetObjectAttributesRequest,
+                ) -> ::std::result::Result<
+                    GetObjectAttributesResponse,
+                    RusotoError<GetObjectAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_object_information: Option<
+        Box<
+            dyn Fn(
+                    GetObjectInformationRequest,
+                ) -> ::std::result::Result<
+                    GetObjectInformationResponse,
+                    RusotoError<GetObjectInformationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_schema_as_json: Option<
+        Box<
+            dyn Fn(
+                    GetSchemaAsJsonRequest,
+                ) -> ::std::result::Result<
+                    GetSchemaAsJsonResponse,
+                    RusotoError<GetSchemaAsJsonError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_typed_link_facet_information: Option<
+        Box<
+            dyn Fn(
+                    GetTypedLinkFacetInformationRequest,
+                ) -> ::std::result::Result<
+                    GetTypedLinkFacetInformationResponse,
+                    RusotoError<GetTypedLinkFacetInformationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_applied_schema_arns: Option<
+        Box<
+            dyn Fn(
+                    ListAppliedSchemaArnsRequest,
+                ) -> ::std::result::Result<
+                    ListAppliedSchemaArnsResponse,
+                    RusotoError<ListAppliedSchemaArnsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_attached_indices: Option<
+        Box<
+            dyn Fn(
+                    ListAttachedIndicesRequest,
+                ) -> ::std::result::Result<
+                    ListAttachedIndicesResponse,
+                    RusotoError<ListAttachedIndicesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_development_schema_arns: Option<
+        Box<
+            dyn Fn(
+                    ListDevelopmentSchemaArnsRequest,
+                ) -> ::std::result::Result<
+                    ListDevelopmentSchemaArnsResponse,
+                    RusotoError<ListDevelopmentSchemaArnsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_directories: Option<
+        Box<
+            dyn Fn(
+                    ListDirectoriesRequest,
+                ) -> ::std::result::Result<
+                    ListDirectoriesResponse,
+                    RusotoError<ListDirectoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_facet_attributes: Option<
+        Box<
+            dyn Fn(
+                    ListFacetAttributesRequest,
+                ) -> ::std::result::Result<
+                    ListFacetAttributesResponse,
+                    RusotoError<ListFacetAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_facet_names: Option<
+        Box<
+            dyn Fn(
+                    ListFacetNamesRequest,
+                ) -> ::std::result::Result<
+                    ListFacetNamesResponse,
+                    RusotoError<ListFacetNamesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_incoming_typed_links: Option<
+        Box<
+            dyn Fn(
+                    ListIncomingTypedLinksRequest,
+                ) -> ::std::result::Result<
+                    ListIncomingTypedLinksResponse,
+                    RusotoError<ListIncomingTypedLinksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_index: Option<
+        Box<
+            dyn Fn(
+                    ListIndexRequest,
+                )
+                    -> ::std::result::Result<ListIndexResponse, RusotoError<ListIndexError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_managed_schema_arns: Option<
+        Box<
+            dyn Fn(
+                    ListManagedSchemaArnsRequest,
+                ) -> ::std::result::Result<
+                    ListManagedSchemaArnsResponse,
+                    RusotoError<ListManagedSchemaArnsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_object_attributes: Option<
+        Box<
+            dyn Fn(
+                    ListObjectAttributesRequest,
+                ) -> ::std::result::Result<
+                    ListObjectAttributesResponse,
+                    RusotoError<ListObjectAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_object_children: Option<
+        Box<
+            dyn Fn(
+                    ListObjectChildrenRequest,
+                ) -> ::std::result::Result<
+                    ListObjectChildrenResponse,
+                    RusotoError<ListObjectChildrenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_object_parent_paths: Option<
+        Box<
+            dyn Fn(
+                    ListObjectParentPathsRequest,
+                ) -> ::std::result::Result<
+                    ListObjectParentPathsResponse,
+                    RusotoError<ListObjectParentPathsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_object_parents: Option<
+        Box<
+            dyn Fn(
+                    ListObjectParentsRequest,
+                ) -> ::std::result::Result<
+                    ListObjectParentsResponse,
+                    RusotoError<ListObjectParentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_object_policies: Option<
+        Box<
+            dyn Fn(
+                    ListObjectPoliciesRequest,
+                ) -> ::std::result::Result<
+                    ListObjectPoliciesResponse,
+                    RusotoError<ListObjectPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_outgoing_typed_links: Option<
+        Box<
+            dyn Fn(
+                    ListOutgoingTypedLinksRequest,
+                ) -> ::std::result::Result<
+                    ListOutgoingTypedLinksResponse,
+                    RusotoError<ListOutgoingTypedLinksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_policy_attachments: Option<
+        Box<
+            dyn Fn(
+                    ListPolicyAttachmentsRequest,
+                ) -> ::std::result::Result<
+                    ListPolicyAttachmentsResponse,
+                    RusotoError<ListPolicyAttachmentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_published_schema_arns: Option<
+        Box<
+            dyn Fn(
+                    ListPublishedSchemaArnsRequest,
+                ) -> ::std::result::Result<
+                    ListPublishedSchemaArnsResponse,
+                    RusotoError<ListPublishedSchemaArnsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_typed_link_facet_attributes: Option<
+        Box<
+            dyn Fn(
+                    ListTypedLinkFacetAttributesRequest,
+                ) -> ::std::result::Result<
+                    ListTypedLinkFacetAttributesResponse,
+                    RusotoError<ListTypedLinkFacetAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_typed_link_facet_names: Option<
+        Box<
+            dyn Fn(
+                    ListTypedLinkFacetNamesRequest,
+                ) -> ::std::result::Result<
+                    ListTypedLinkFacetNamesResponse,
+                    RusotoError<ListTypedLinkFacetNamesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    lookup_policy: Option<
+        Box<
+            dyn Fn(
+                    LookupPolicyRequest,
+                )
+                    -> ::std::result::Result<LookupPolicyResponse, RusotoError<LookupPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    publish_schema: Option<
+        Box<
+            dyn Fn(
+                    PublishSchemaRequest,
+                )
+                    -> ::std::result::Result<PublishSchemaResponse, RusotoError<PublishSchemaError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_schema_from_json: Option<
+        Box<
+            dyn Fn(
+                    PutSchemaFromJsonRequest,
+                ) -> ::std::result::Result<
+                    PutSchemaFromJsonResponse,
+                    RusotoError<PutSchemaFromJsonError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_facet_from_object: Option<
+        Box<
+            dyn Fn(
+                    RemoveFacetFromObjectRequest,
+                ) -> ::std::result::Result<
+                    RemoveFacetFromObjectResponse,
+                    RusotoError<RemoveFacetFromObjectError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_facet: Option<
+        Box<
+            dyn Fn(
+                    UpdateFacetRequest,
+                )
+                    -> ::std::result::Result<UpdateFacetResponse, RusotoError<UpdateFacetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_link_attributes: Option<
+        Box<
+            dyn Fn(
+                    UpdateLinkAttributesRequest,
+                ) -> ::std::result::Result<
+                    UpdateLinkAttributesResponse,
+                    RusotoError<UpdateLinkAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_object_attributes: Option<
+        Box<
+            dyn Fn(
+                    UpdateObjectAttributesRequest,
+                ) -> ::std::result::Result<
+                    UpdateObjectAttributesResponse,
+                    RusotoError<UpdateObjectAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_schema: Option<
+        Box<
+            dyn Fn(
+                    UpdateSchemaRequest,
+                )
+                    -> ::std::result::Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_typed_link_facet: Option<
+        Box<
+            dyn Fn(
+                    UpdateTypedLinkFacetRequest,
+                ) -> ::std::result::Result<
+                    UpdateTypedLinkFacetResponse,
+                    RusotoError<UpdateTypedLinkFacetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    upgrade_applied_schema: Option<
+        Box<
+            dyn Fn(
+                    UpgradeAppliedSchemaRequest,
+                ) -> ::std::result::Result<
+                    UpgradeAppliedSchemaResponse,
+                    RusotoError<UpgradeAppliedSchemaError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    upgrade_published_schema: Option<
+        Box<
+            dyn Fn(
+                    UpgradePublishedSchemaRequest,
+                ) -> ::std::result::Result<
+                    UpgradePublishedSchemaResponse,
+                    RusotoError<UpgradePublishedSchemaError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloudDirectory {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloudDirectory {
+        Default::default()
+    }
+
+    /// Answers `add_facet_to_object` with what `handler` returns for its input.
+    pub fn with_add_facet_to_object<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                AddFacetToObjectRequest,
+            ) -> ::std::result::Result<
+                AddFacetToObjectResponse,
+                RusotoError<AddFacetToObjectError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_facet_to_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `apply_schema` with what `handler` returns for its input.
+    pub fn with_apply_schema<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ApplySchemaRequest,
+            )
+                -> ::std::result::Result<ApplySchemaResponse, RusotoError<ApplySchemaError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.apply_schema = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_object` with what `handler` returns for its input.
+    pub fn with_attach_object<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                AttachObjectRequest,
+            )
+                -> ::std::result::Result<AttachObjectResponse, RusotoError<AttachObjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_policy` with what `handler` returns for its input.
+    pub fn with_attach_policy<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                AttachPolicyRequest,
+            )
+                -> ::std::result::Result<AttachPolicyResponse, RusotoError<AttachPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_to_index` with what `handler` returns for its input.
+    pub fn with_attach_to_index<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                AttachToIndexRequest,
+            )
+                -> ::std::result::Result<AttachToIndexResponse, RusotoError<AttachToIndexError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_to_index = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_typed_link` with what `handler` returns for its input.
+    pub fn with_attach_typed_link<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                AttachTypedLinkRequest,
+            )
+                -> ::std::result::Result<AttachTypedLinkResponse, RusotoError<AttachTypedLinkError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_typed_link = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_read` with what `handler` returns for its input.
+    pub fn with_batch_read<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                BatchReadRequest,
+            ) -> ::std::result::Result<BatchReadResponse, RusotoError<BatchReadError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_read = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_write` with what `handler` returns for its input.
+    pub fn with_batch_write<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                BatchWriteRequest,
+            )
+                -> ::std::result::Result<BatchWriteResponse, RusotoError<BatchWriteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_write = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_directory` with what `handler` returns for its input.
+    pub fn with_create_directory<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                CreateDirectoryRequest,
+            )
+                -> ::std::result::Result<CreateDirectoryResponse, RusotoError<CreateDirectoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_facet` with what `handler` returns for its input.
+    pub fn with_create_facet<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                CreateFacetRequest,
+            )
+                -> ::std::result::Result<CreateFacetResponse, RusotoError<CreateFacetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_facet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_index` with what `handler` returns for its input.
+    pub fn with_create_index<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                CreateIndexRequest,
+            )
+                -> ::std::result::Result<CreateIndexResponse, RusotoError<CreateIndexError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_index = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_object` with what `handler` returns for its input.
+    pub fn with_create_object<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                CreateObjectRequest,
+            )
+                -> ::std::result::Result<CreateObjectResponse, RusotoError<CreateObjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_schema` with what `handler` returns for its input.
+    pub fn with_create_schema<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                CreateSchemaRequest,
+            )
+                -> ::std::result::Result<CreateSchemaResponse, RusotoError<CreateSchemaError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_schema = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_typed_link_facet` with what `handler` returns for its input.
+    pub fn with_create_typed_link_facet<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                CreateTypedLinkFacetRequest,
+            ) -> ::std::result::Result<
+                CreateTypedLinkFacetResponse,
+                RusotoError<CreateTypedLinkFacetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_typed_link_facet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_directory` with what `handler` returns for its input.
+    pub fn with_delete_directory<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                DeleteDirectoryRequest,
+            )
+                -> ::std::result::Result<DeleteDirectoryResponse, RusotoError<DeleteDirectoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_facet` with what `handler` returns for its input.
+    pub fn with_delete_facet<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                DeleteFacetRequest,
+            )
+                -> ::std::result::Result<DeleteFacetResponse, RusotoError<DeleteFacetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_facet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_object` with what `handler` returns for its input.
+    pub fn with_delete_object<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                DeleteObjectRequest,
+            )
+                -> ::std::result::Result<DeleteObjectResponse, RusotoError<DeleteObjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_schema` with what `handler` returns for its input.
+    pub fn with_delete_schema<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                DeleteSchemaRequest,
+            )
+                -> ::std::result::Result<DeleteSchemaResponse, RusotoError<DeleteSchemaError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_schema = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_typed_link_facet` with what `handler` returns for its input.
+    pub fn with_delete_typed_link_facet<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                DeleteTypedLinkFacetRequest,
+            ) -> ::std::result::Result<
+                DeleteTypedLinkFacetResponse,
+                RusotoError<DeleteTypedLinkFacetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_typed_link_facet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_from_index` with what `handler` returns for its input.
+    pub fn with_detach_from_index<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                DetachFromIndexRequest,
+            )
+                -> ::std::result::Result<DetachFromIndexResponse, RusotoError<DetachFromIndexError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_from_index = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_object` with what `handler` returns for its input.
+    pub fn with_detach_object<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                DetachObjectRequest,
+            )
+                -> ::std::result::Result<DetachObjectResponse, RusotoError<DetachObjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_policy` with what `handler` returns for its input.
+    pub fn with_detach_policy<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                DetachPolicyRequest,
+            )
+                -> ::std::result::Result<DetachPolicyResponse, RusotoError<DetachPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_typed_link` with what `handler` returns for its input.
+    pub fn with_detach_typed_link<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                DetachTypedLinkRequest,
+            ) -> ::std::result::Result<(), RusotoError<DetachTypedLinkError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_typed_link = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_directory` with what `handler` returns for its input.
+    pub fn with_disable_directory<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                DisableDirectoryRequest,
+            ) -> ::std::result::Result<
+                DisableDirectoryResponse,
+                RusotoError<DisableDirectoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_directory` with what `handler` returns for its input.
+    pub fn with_enable_directory<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                EnableDirectoryRequest,
+            )
+                -> ::std::result::Result<EnableDirectoryResponse, RusotoError<EnableDirectoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_applied_schema_version` with what `handler` returns for its input.
+    pub fn with_get_applied_schema_version<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                GetAppliedSchemaVersionRequest,
+            ) -> ::std::result::Result<
+                GetAppliedSchemaVersionResponse,
+                RusotoError<GetAppliedSchemaVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_applied_schema_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_directory` with what `handler` returns for its input.
+    pub fn with_get_directory<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                GetDirectoryRequest,
+            )
+                -> ::std::result::Result<GetDirectoryResponse, RusotoError<GetDirectoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_facet` with what `handler` returns for its input.
+    pub fn with_get_facet<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                GetFacetRequest,
+            ) -> ::std::result::Result<GetFacetResponse, RusotoError<GetFacetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_facet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_link_attributes` with what `handler` returns for its input.
+    pub fn with_get_link_attributes<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                GetLinkAttributesRequest,
+            ) -> ::std::result::Result<
+                GetLinkAttributesResponse,
+                RusotoError<GetLinkAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_link_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_object_attributes` with what `handler` returns for its input.
+    pub fn with_get_object_attributes<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                GetObjectAttributesRequest,
+            ) -> ::std::result::Result<
+                GetObjectAttributesResponse,
+                RusotoError<GetObjectAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_object_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_object_information` with what `handler` returns for its input.
+    pub fn with_get_object_information<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                GetObjectInformationRequest,
+            ) -> ::std::result::Result<
+                GetObjectInformationResponse,
+                RusotoError<GetObjectInformationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_object_information = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_schema_as_json` with what `handler` returns for its input.
+    pub fn with_get_schema_as_json<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                GetSchemaAsJsonRequest,
+            )
+                -> ::std::result::Result<GetSchemaAsJsonResponse, RusotoError<GetSchemaAsJsonError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_schema_as_json = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_typed_link_facet_information` with what `handler` returns for its input.
+    pub fn with_get_typed_link_facet_information<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                GetTypedLinkFacetInformationRequest,
+            ) -> ::std::result::Result<
+                GetTypedLinkFacetInformationResponse,
+                RusotoError<GetTypedLinkFacetInformationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_typed_link_facet_information = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_applied_schema_arns` with what `handler` returns for its input.
+    pub fn with_list_applied_schema_arns<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListAppliedSchemaArnsRequest,
+            ) -> ::std::result::Result<
+                ListAppliedSchemaArnsResponse,
+                RusotoError<ListAppliedSchemaArnsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_applied_schema_arns = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_attached_indices` with what `handler` returns for its input.
+    pub fn with_list_attached_indices<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListAttachedIndicesRequest,
+            ) -> ::std::result::Result<
+                ListAttachedIndicesResponse,
+                RusotoError<ListAttachedIndicesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_attached_indices = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_development_schema_arns` with what `handler` returns for its input.
+    pub fn with_list_development_schema_arns<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListDevelopmentSchemaArnsRequest,
+            ) -> ::std::result::Result<
+                ListDevelopmentSchemaArnsResponse,
+                RusotoError<ListDevelopmentSchemaArnsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_development_schema_arns = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_directories` with what `handler` returns for its input.
+    pub fn with_list_directories<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListDirectoriesRequest,
+            )
+                -> ::std::result::Result<ListDirectoriesResponse, RusotoError<ListDirectoriesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_directories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_facet_attributes` with what `handler` returns for its input.
+    pub fn with_list_facet_attributes<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListFacetAttributesRequest,
+            ) -> ::std::result::Result<
+                ListFacetAttributesResponse,
+                RusotoError<ListFacetAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_facet_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_facet_names` with what `handler` returns for its input.
+    pub fn with_list_facet_names<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListFacetNamesRequest,
+            )
+                -> ::std::result::Result<ListFacetNamesResponse, RusotoError<ListFacetNamesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_facet_names = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_incoming_typed_links` with what `handler` returns for its input.
+    pub fn with_list_incoming_typed_links<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListIncomingTypedLinksRequest,
+            ) -> ::std::result::Result<
+                ListIncomingTypedLinksResponse,
+                RusotoError<ListIncomingTypedLinksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_incoming_typed_links = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_index` with what `handler` returns for its input.
+    pub fn with_list_index<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListIndexRequest,
+            ) -> ::std::result::Result<ListIndexResponse, RusotoError<ListIndexError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_index = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_managed_schema_arns` with what `handler` returns for its input.
+    pub fn with_list_managed_schema_arns<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListManagedSchemaArnsRequest,
+            ) -> ::std::result::Result<
+                ListManagedSchemaArnsResponse,
+                RusotoError<ListManagedSchemaArnsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_managed_schema_arns = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_object_attributes` with what `handler` returns for its input.
+    pub fn with_list_object_attributes<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListObjectAttributesRequest,
+            ) -> ::std::result::Result<
+                ListObjectAttributesResponse,
+                RusotoError<ListObjectAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_object_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_object_children` with what `handler` returns for its input.
+    pub fn with_list_object_children<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListObjectChildrenRequest,
+            ) -> ::std::result::Result<
+                ListObjectChildrenResponse,
+                RusotoError<ListObjectChildrenError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_object_children = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_object_parent_paths` with what `handler` returns for its input.
+    pub fn with_list_object_parent_paths<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListObjectParentPathsRequest,
+            ) -> ::std::result::Result<
+                ListObjectParentPathsResponse,
+                RusotoError<ListObjectParentPathsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_object_parent_paths = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_object_parents` with what `handler` returns for its input.
+    pub fn with_list_object_parents<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListObjectParentsRequest,
+            ) -> ::std::result::Result<
+                ListObjectParentsResponse,
+                RusotoError<ListObjectParentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_object_parents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_object_policies` with what `handler` returns for its input.
+    pub fn with_list_object_policies<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListObjectPoliciesRequest,
+            ) -> ::std::result::Result<
+                ListObjectPoliciesResponse,
+                RusotoError<ListObjectPoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_object_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_outgoing_typed_links` with what `handler` returns for its input.
+    pub fn with_list_outgoing_typed_links<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListOutgoingTypedLinksRequest,
+            ) -> ::std::result::Result<
+                ListOutgoingTypedLinksResponse,
+                RusotoError<ListOutgoingTypedLinksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_outgoing_typed_links = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_policy_attachments` with what `handler` returns for its input.
+    pub fn with_list_policy_attachments<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListPolicyAttachmentsRequest,
+            ) -> ::std::result::Result<
+                ListPolicyAttachmentsResponse,
+                RusotoError<ListPolicyAttachmentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_policy_attachments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_published_schema_arns` with what `handler` returns for its input.
+    pub fn with_list_published_schema_arns<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListPublishedSchemaArnsRequest,
+            ) -> ::std::result::Result<
+                ListPublishedSchemaArnsResponse,
+                RusotoError<ListPublishedSchemaArnsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_published_schema_arns = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_typed_link_facet_attributes` with what `handler` returns for its input.
+    pub fn with_list_typed_link_facet_attributes<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListTypedLinkFacetAttributesRequest,
+            ) -> ::std::result::Result<
+                ListTypedLinkFacetAttributesResponse,
+                RusotoError<ListTypedLinkFacetAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_typed_link_facet_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_typed_link_facet_names` with what `handler` returns for its input.
+    pub fn with_list_typed_link_facet_names<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                ListTypedLinkFacetNamesRequest,
+            ) -> ::std::result::Result<
+                ListTypedLinkFacetNamesResponse,
+                RusotoError<ListTypedLinkFacetNamesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_typed_link_facet_names = Some(Box::new(handler));
+        self
+    }
+    /// Answers `lookup_policy` with what `handler` returns for its input.
+    pub fn with_lookup_policy<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                LookupPolicyRequest,
+            )
+                -> ::std::result::Result<LookupPolicyResponse, RusotoError<LookupPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.lookup_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `publish_schema` with what `handler` returns for its input.
+    pub fn with_publish_schema<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                PublishSchemaRequest,
+            )
+                -> ::std::result::Result<PublishSchemaResponse, RusotoError<PublishSchemaError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.publish_schema = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_schema_from_json` with what `handler` returns for its input.
+    pub fn with_put_schema_from_json<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                PutSchemaFromJsonRequest,
+            ) -> ::std::result::Result<
+                PutSchemaFromJsonResponse,
+                RusotoError<PutSchemaFromJsonError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_schema_from_json = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_facet_from_object` with what `handler` returns for its input.
+    pub fn with_remove_facet_from_object<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                RemoveFacetFromObjectRequest,
+            ) -> ::std::result::Result<
+                RemoveFacetFromObjectResponse,
+                RusotoError<RemoveFacetFromObjectError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_facet_from_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_facet` with what `handler` returns for its input.
+    pub fn with_update_facet<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                UpdateFacetRequest,
+            )
+                -> ::std::result::Result<UpdateFacetResponse, RusotoError<UpdateFacetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_facet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_link_attributes` with what `handler` returns for its input.
+    pub fn with_update_link_attributes<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                UpdateLinkAttributesRequest,
+            ) -> ::std::result::Result<
+                UpdateLinkAttributesResponse,
+                RusotoError<UpdateLinkAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_link_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_object_attributes` with what `handler` returns for its input.
+    pub fn with_update_object_attributes<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                UpdateObjectAttributesRequest,
+            ) -> ::std::result::Result<
+                UpdateObjectAttributesResponse,
+                RusotoError<UpdateObjectAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_object_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_schema` with what `handler` returns for its input.
+    pub fn with_update_schema<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                UpdateSchemaRequest,
+            )
+                -> ::std::result::Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_schema = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_typed_link_facet` with what `handler` returns for its input.
+    pub fn with_update_typed_link_facet<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                UpdateTypedLinkFacetRequest,
+            ) -> ::std::result::Result<
+                UpdateTypedLinkFacetResponse,
+                RusotoError<UpdateTypedLinkFacetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_typed_link_facet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `upgrade_applied_schema` with what `handler` returns for its input.
+    pub fn with_upgrade_applied_schema<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                UpgradeAppliedSchemaRequest,
+            ) -> ::std::result::Result<
+                UpgradeAppliedSchemaResponse,
+                RusotoError<UpgradeAppliedSchemaError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.upgrade_applied_schema = Some(Box::new(handler));
+        self
+    }
+    /// Answers `upgrade_published_schema` with what `handler` returns for its input.
+    pub fn with_upgrade_published_schema<F>(mut self, handler: F) -> MockCloudDirectory
+    where
+        F: Fn(
+                UpgradePublishedSchemaRequest,
+            ) -> ::std::result::Result<
+                UpgradePublishedSchemaResponse,
+                RusotoError<UpgradePublishedSchemaError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.upgrade_published_schema = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CloudDirectory for MockCloudDirectory {
+    fn add_facet_to_object(
+        &self,
+        input: AddFacetToObjectRequest,
+    ) -> RusotoFuture<AddFacetToObjectResponse, AddFacetToObjectError> {
+        match self.add_facet_to_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for add_facet_to_object"),
+        }
+    }
+    fn apply_schema(
+        &self,
+        input: ApplySchemaRequest,
+    ) -> RusotoFuture<ApplySchemaResponse, ApplySchemaError> {
+        match self.apply_schema {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for apply_schema"),
+        }
+    }
+    fn attach_object(
+        &self,
+        input: AttachObjectRequest,
+    ) -> RusotoFuture<AttachObjectResponse, AttachObjectError> {
+        match self.attach_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for attach_object"),
+        }
+    }
+    fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> RusotoFuture<AttachPolicyResponse, AttachPolicyError> {
+        match self.attach_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for attach_policy"),
+        }
+    }
+    fn attach_to_index(
+        &self,
+        input: AttachToIndexRequest,
+    ) -> RusotoFuture<AttachToIndexResponse, AttachToIndexError> {
+        match self.attach_to_index {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for attach_to_index"),
+        }
+    }
+    fn attach_typed_link(
+        &self,
+        input: AttachTypedLinkRequest,
+    ) -> RusotoFuture<AttachTypedLinkResponse, AttachTypedLinkError> {
+        match self.attach_typed_link {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for attach_typed_link"),
+        }
+    }
+    fn batch_read(
+        &self,
+        input: BatchReadRequest,
+    ) -> RusotoFuture<BatchReadResponse, BatchReadError> {
+        match self.batch_read {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for batch_read"),
+        }
+    }
+    fn batch_write(
+        &self,
+        input: BatchWriteRequest,
+    ) -> RusotoFuture<BatchWriteResponse, BatchWriteError> {
+        match self.batch_write {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for batch_write"),
+        }
+    }
+    fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> RusotoFuture<CreateDirectoryResponse, CreateDirectoryError> {
+        match self.create_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for create_directory"),
+        }
+    }
+    fn create_facet(
+        &self,
+        input: CreateFacetRequest,
+    ) -> RusotoFuture<CreateFacetResponse, CreateFacetError> {
+        match self.create_facet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for create_facet"),
+        }
+    }
+    fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> RusotoFuture<CreateIndexResponse, CreateIndexError> {
+        match self.create_index {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for create_index"),
+        }
+    }
+    fn create_object(
+        &self,
+        input: CreateObjectRequest,
+    ) -> RusotoFuture<CreateObjectResponse, CreateObjectError> {
+        match self.create_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for create_object"),
+        }
+    }
+    fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> RusotoFuture<CreateSchemaResponse, CreateSchemaError> {
+        match self.create_schema {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for create_schema"),
+        }
+    }
+    fn create_typed_link_facet(
+        &self,
+        input: CreateTypedLinkFacetRequest,
+    ) -> RusotoFuture<CreateTypedLinkFacetResponse, CreateTypedLinkFacetError> {
+        match self.create_typed_link_facet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for create_typed_link_facet"),
+        }
+    }
+    fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> RusotoFuture<DeleteDirectoryResponse, DeleteDirectoryError> {
+        match self.delete_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for delete_directory"),
+        }
+    }
+    fn delete_facet(
+        &self,
+        input: DeleteFacetRequest,
+    ) -> RusotoFuture<DeleteFacetResponse, DeleteFacetError> {
+        match self.delete_facet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for delete_facet"),
+        }
+    }
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectResponse, DeleteObjectError> {
+        match self.delete_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for delete_object"),
+        }
+    }
+    fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> RusotoFuture<DeleteSchemaResponse, DeleteSchemaError> {
+        match self.delete_schema {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for delete_schema"),
+        }
+    }
+    fn delete_typed_link_facet(
+        &self,
+        input: DeleteTypedLinkFacetRequest,
+    ) -> RusotoFuture<DeleteTypedLinkFacetResponse, DeleteTypedLinkFacetError> {
+        match self.delete_typed_link_facet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for delete_typed_link_facet"),
+        }
+    }
+    fn detach_from_index(
+        &self,
+        input: DetachFromIndexRequest,
+    ) -> RusotoFuture<DetachFromIndexResponse, DetachFromIndexError> {
+        match self.detach_from_index {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for detach_from_index"),
+        }
+    }
+    fn detach_object(
+        &self,
+        input: DetachObjectRequest,
+    ) -> RusotoFuture<DetachObjectResponse, DetachObjectError> {
+        match self.detach_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for detach_object"),
+        }
+    }
+    fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> RusotoFuture<DetachPolicyResponse, DetachPolicyError> {
+        match self.detach_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for detach_policy"),
+        }
+    }
+    fn detach_typed_link(
+        &self,
+        input: DetachTypedLinkRequest,
+    ) -> RusotoFuture<(), DetachTypedLinkError> {
+        match self.detach_typed_link {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for detach_typed_link"),
+        }
+    }
+    fn disable_directory(
+        &self,
+        input: DisableDirectoryRequest,
+    ) -> RusotoFuture<DisableDirectoryResponse, DisableDirectoryError> {
+        match self.disable_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for disable_directory"),
+        }
+    }
+    fn enable_directory(
+        &self,
+        input: EnableDirectoryRequest,
+    ) -> RusotoFuture<EnableDirectoryResponse, EnableDirectoryError> {
+        match self.enable_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for enable_directory"),
+        }
+    }
+    fn get_applied_schema_version(
+        &self,
+        input: GetAppliedSchemaVersionRequest,
+    ) -> RusotoFuture<GetAppliedSchemaVersionResponse, GetAppliedSchemaVersionError> {
+        match self.get_applied_schema_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for get_applied_schema_version"),
+        }
+    }
+    fn get_directory(
+        &self,
+        input: GetDirectoryRequest,
+    ) -> RusotoFuture<GetDirectoryResponse, GetDirectoryError> {
+        match self.get_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for get_directory"),
+        }
+    }
+    fn get_facet(&self, input: GetFacetRequest) -> RusotoFuture<GetFacetResponse, GetFacetError> {
+        match self.get_facet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for get_facet"),
+        }
+    }
+    fn get_link_attributes(
+        &self,
+        input: GetLinkAttributesRequest,
+    ) -> RusotoFuture<GetLinkAttributesResponse, GetLinkAttributesError> {
+        match self.get_link_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for get_link_attributes"),
+        }
+    }
+    fn get_object_attributes(
+        &self,
+        input: GetObjectAttributesRequest,
+    ) -> RusotoFuture<GetObjectAttributesResponse, GetObjectAttributesError> {
+        match self.get_object_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for get_object_attributes"),
+        }
+    }
+    fn get_object_information(
+        &self,
+        input: GetObjectInformationRequest,
+    ) -> RusotoFuture<GetObjectInformationResponse, GetObjectInformationError> {
+        match self.get_object_information {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for get_object_information"),
+        }
+    }
+    fn get_schema_as_json(
+        &self,
+        input: GetSchemaAsJsonRequest,
+    ) -> RusotoFuture<GetSchemaAsJsonResponse, GetSchemaAsJsonError> {
+        match self.get_schema_as_json {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for get_schema_as_json"),
+        }
+    }
+    fn get_typed_link_facet_information(
+        &self,
+        input: GetTypedLinkFacetInformationRequest,
+    ) -> RusotoFuture<GetTypedLinkFacetInformationResponse, GetTypedLinkFacetInformationError> {
+        match self.get_typed_link_facet_information {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudDirectory has no handler for get_typed_link_facet_information")
+            }
+        }
+    }
+    fn list_applied_schema_arns(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> RusotoFuture<ListAppliedSchemaArnsResponse, ListAppliedSchemaArnsError> {
+        match self.list_applied_schema_arns {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_applied_schema_arns"),
+        }
+    }
+    fn list_attached_indices(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> RusotoFuture<ListAttachedIndicesResponse, ListAttachedIndicesError> {
+        match self.list_attached_indices {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_attached_indices"),
+        }
+    }
+    fn list_development_schema_arns(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> RusotoFuture<ListDevelopmentSchemaArnsResponse, ListDevelopmentSchemaArnsError> {
+        match self.list_development_schema_arns {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_development_schema_arns"),
+        }
+    }
+    fn list_directories(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> RusotoFuture<ListDirectoriesResponse, ListDirectoriesError> {
+        match self.list_directories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_directories"),
+        }
+    }
+    fn list_facet_attributes(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> RusotoFuture<ListFacetAttributesResponse, ListFacetAttributesError> {
+        match self.list_facet_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_facet_attributes"),
+        }
+    }
+    fn list_facet_names(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> RusotoFuture<ListFacetNamesResponse, ListFacetNamesError> {
+        match self.list_facet_names {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_facet_names"),
+        }
+    }
+    fn list_incoming_typed_links(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> RusotoFuture<ListIncomingTypedLinksResponse, ListIncomingTypedLinksError> {
+        match self.list_incoming_typed_links {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_incoming_typed_links"),
+        }
     }
-
-    /// <p>Lists objects attached to the specified index.</p>
     fn list_index(
         &self,
         input: ListIndexRequest,
     ) -> RusotoFuture<ListIndexResponse, ListIndexError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/index/targets";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref consistency_level) = input.consistency_level {
-            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        match self.list_index {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_index"),
         }
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListIndexResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListIndexError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Lists the major version families of each managed schema. If a major version ARN is provided as SchemaArn, the minor version revisions in that family are listed instead.</p>
     fn list_managed_schema_arns(
         &self,
         input: ListManagedSchemaArnsRequest,
     ) -> RusotoFuture<ListManagedSchemaArnsResponse, ListManagedSchemaArnsError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/managed";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListManagedSchemaArnsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListManagedSchemaArnsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_managed_schema_arns {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_managed_schema_arns"),
+        }
     }
-
-    /// <p>Lists all attributes that are associated with an object. </p>
     fn list_object_attributes(
         &self,
         input: ListObjectAttributesRequest,
     ) -> RusotoFuture<ListObjectAttributesResponse, ListObjectAttributesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref consistency_level) = input.consistency_level {
-            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        match self.list_object_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_object_attributes"),
         }
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListObjectAttributesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListObjectAttributesError::from_response(response))
-                    }),
-                )
-            }
-        })
     }
-
-    /// <p>Returns a paginated list of child objects that are associated with a given object.</p>
     fn list_object_children(
         &self,
         input: ListObjectChildrenRequest,
     ) -> RusotoFuture<ListObjectChildrenResponse, ListObjectChildrenError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/children";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref consistency_level) = input.consistency_level {
-            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        match self.list_object_children {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_object_children"),
         }
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListObjectChildrenResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListObjectChildrenError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Retrieves all available parent paths for any object type such as node, leaf node, policy node, and index node objects. For more information about objects, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directorystructure.html">Directory Structure</a>.</p> <p>Use this API to evaluate all parents for an object. The call returns all objects from the root of the directory up to the requested object. The API returns the number of paths based on user-defined <code>MaxResults</code>, in case there are multiple paths to the parent. The order of the paths and nodes returned is consistent among multiple API calls unless the objects are deleted or moved. Paths not leading to the directory root are ignored from the target object.</p>
     fn list_object_parent_paths(
         &self,
         input: ListObjectParentPathsRequest,
     ) -> RusotoFuture<ListObjectParentPathsResponse, ListObjectParentPathsError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/parentpaths";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListObjectParentPathsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListObjectParentPathsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_object_parent_paths {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_object_parent_paths"),
+        }
     }
-
-    /// <p>Lists parent objects that are associated with a given object in pagination fashion.</p>
     fn list_object_parents(
         &self,
         input: ListObjectParentsRequest,
     ) -> RusotoFuture<ListObjectParentsResponse, ListObjectParentsError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/parent";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref consistency_level) = input.consistency_level {
-            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        match self.list_object_parents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_object_parents"),
         }
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListObjectParentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListObjectParentsError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Returns policies attached to an object in pagination fashion.</p>
     fn list_object_policies(
         &self,
         input: ListObjectPoliciesRequest,
     ) -> RusotoFuture<ListObjectPoliciesResponse, ListObjectPoliciesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/policy";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref consistency_level) = input.consistency_level {
-            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        match self.list_object_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_object_policies"),
+        }
+    }
+    fn list_outgoing_typed_links(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> RusotoFuture<ListOutgoingTypedLinksResponse, ListOutgoingTypedLinksError> {
+        match self.list_outgoing_typed_links {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_outgoing_typed_links"),
         }
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListObjectPoliciesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListObjectPoliciesError::from_response(response))),
-                )
-            }
-        })
-    }
-
-    /// <p>Returns a paginated list of all the outgoing <a>TypedLinkSpecifier</a> information for an object. It also supports filtering by typed link facet and identity attributes. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    fn list_outgoing_typed_links(
-        &self,
-        input: ListOutgoingTypedLinksRequest,
-    ) -> RusotoFuture<ListOutgoingTypedLinksResponse, ListOutgoingTypedLinksError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/outgoing";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOutgoingTypedLinksResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListOutgoingTypedLinksError::from_response(response))
-                    }),
-                )
-            }
-        })
     }
-
-    /// <p>Returns all of the <code>ObjectIdentifiers</code> to which a given policy is attached.</p>
     fn list_policy_attachments(
         &self,
         input: ListPolicyAttachmentsRequest,
     ) -> RusotoFuture<ListPolicyAttachmentsResponse, ListPolicyAttachmentsError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/policy/attachment";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref consistency_level) = input.consistency_level {
-            request.add_header("x-amz-consistency-level", &consistency_level.to_string());
+        match self.list_policy_attachments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_policy_attachments"),
         }
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPolicyAttachmentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListPolicyAttachmentsError::from_response(response))
-                    }),
-                )
-            }
-        })
     }
-
-    /// <p>Lists the major version families of each published schema. If a major version ARN is provided as <code>SchemaArn</code>, the minor version revisions in that family are listed instead.</p>
     fn list_published_schema_arns(
         &self,
         input: ListPublishedSchemaArnsRequest,
     ) -> RusotoFuture<ListPublishedSchemaArnsResponse, ListPublishedSchemaArnsError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/published";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPublishedSchemaArnsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListPublishedSchemaArnsError::from_response(response))
-                }))
-            }
-        })
+        match self.list_published_schema_arns {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_published_schema_arns"),
+        }
     }
-
-    /// <p>Returns tags for a resource. Tagging is currently supported only for directories with a limit of 50 tags per directory. All 50 tags are returned for a given directory with this API call.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/tags";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_tags_for_resource"),
+        }
     }
-
-    /// <p>Returns a paginated list of all attribute definitions for a particular <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     fn list_typed_link_facet_attributes(
         &self,
         input: ListTypedLinkFacetAttributesRequest,
     ) -> RusotoFuture<ListTypedLinkFacetAttributesResponse, ListTypedLinkFacetAttributesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/attributes";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTypedLinkFacetAttributesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListTypedLinkFacetAttributesError::from_response(response))
-                }))
+        match self.list_typed_link_facet_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudDirectory has no handler for list_typed_link_facet_attributes")
             }
-        })
+        }
     }
-
-    /// <p>Returns a paginated list of <code>TypedLink</code> facet names for a particular schema. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     fn list_typed_link_facet_names(
         &self,
         input: ListTypedLinkFacetNamesRequest,
     ) -> RusotoFuture<ListTypedLinkFacetNamesResponse, ListTypedLinkFacetNamesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/list";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTypedLinkFacetNamesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListTypedLinkFacetNamesError::from_response(response))
-                }))
-            }
-        })
+        match self.list_typed_link_facet_names {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for list_typed_link_facet_names"),
+        }
     }
-
-    /// <p>Lists all policies from the root of the <a>Directory</a> to the object specified. If there are no policies present, an empty list is returned. If policies are present, and if some objects don't have the policies attached, it returns the <code>ObjectIdentifier</code> for such objects. If policies are present, it returns <code>ObjectIdentifier</code>, <code>policyId</code>, and <code>policyType</code>. Paths that don't lead to the root from the target object are ignored. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directory.html#key_concepts_policies">Policies</a>.</p>
     fn lookup_policy(
         &self,
         input: LookupPolicyRequest,
     ) -> RusotoFuture<LookupPolicyResponse, LookupPolicyError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/policy/lookup";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<LookupPolicyResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(LookupPolicyError::from_response(response))),
-                )
-            }
-        })
+        match self.lookup_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for lookup_policy"),
+        }
     }
-
-    /// <p>Publishes a development schema with a major version and a recommended minor version.</p>
     fn publish_schema(
         &self,
         input: PublishSchemaRequest,
     ) -> RusotoFuture<PublishSchemaResponse, PublishSchemaError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/publish";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.development_schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PublishSchemaResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PublishSchemaError::from_response(response))),
-                )
-            }
-        })
+        match self.publish_schema {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for publish_schema"),
+        }
     }
-
-    /// <p>Allows a schema to be updated using JSON upload. Only available for development schemas. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_jsonformat.html#schemas_json">JSON Schema Format</a> for more information.</p>
     fn put_schema_from_json(
         &self,
         input: PutSchemaFromJsonRequest,
     ) -> RusotoFuture<PutSchemaFromJsonResponse, PutSchemaFromJsonError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutSchemaFromJsonResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutSchemaFromJsonError::from_response(response))),
-                )
-            }
-        })
+        match self.put_schema_from_json {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for put_schema_from_json"),
+        }
     }
-
-    /// <p>Removes the specified facet from the specified object.</p>
     fn remove_facet_from_object(
-        &self,
-        input: RemoveFacetFromObjectRequest,
-    ) -> RusotoFuture<RemoveFacetFromObjectResponse, RemoveFacetFromObjectError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/facets/delete";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveFacetFromObjectResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RemoveFacetFromObjectError::from_response(response))
-                    }),
-                )
-            }
-        })
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> RusotoFuture<RemoveFacetFromObjectResponse, RemoveFacetFromObjectError> {
+        match self.remove_facet_from_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for remove_facet_from_object"),
+        }
     }
-
-    /// <p>An API operation for adding tags to a resource.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/tags/add";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for tag_resource"),
+        }
     }
-
-    /// <p>An API operation for removing tags from a resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/tags/remove";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for untag_resource"),
+        }
     }
-
-    /// <p><p>Does the following:</p> <ol> <li> <p>Adds new <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> <li> <p>Updates existing <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> <li> <p>Deletes existing <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> </ol></p>
     fn update_facet(
         &self,
         input: UpdateFacetRequest,
     ) -> RusotoFuture<UpdateFacetResponse, UpdateFacetError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/facet";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFacetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateFacetError::from_response(response))),
-                )
-            }
-        })
+        match self.update_facet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for update_facet"),
+        }
     }
-
-    /// <p>Updates a given typed link’s attributes. Attributes to be updated must not contribute to the typed link’s identity, as defined by its <code>IdentityAttributeOrder</code>.</p>
     fn update_link_attributes(
         &self,
         input: UpdateLinkAttributesRequest,
     ) -> RusotoFuture<UpdateLinkAttributesResponse, UpdateLinkAttributesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/update";
-
-        let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateLinkAttributesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateLinkAttributesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_link_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for update_link_attributes"),
+        }
     }
-
-    /// <p>Updates a given object's attributes.</p>
     fn update_object_attributes(
         &self,
         input: UpdateObjectAttributesRequest,
     ) -> RusotoFuture<UpdateObjectAttributesResponse, UpdateObjectAttributesError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/object/update";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.directory_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateObjectAttributesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateObjectAttributesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_object_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for update_object_attributes"),
+        }
     }
-
-    /// <p>Updates the schema name with a new name. Only development schema names can be updated.</p>
     fn update_schema(
         &self,
         input: UpdateSchemaRequest,
     ) -> RusotoFuture<UpdateSchemaResponse, UpdateSchemaError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/update";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSchemaResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateSchemaError::from_response(response))),
-                )
-            }
-        })
+        match self.update_schema {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for update_schema"),
+        }
     }
-
-    /// <p>Updates a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     fn update_typed_link_facet(
         &self,
         input: UpdateTypedLinkFacetRequest,
     ) -> RusotoFuture<UpdateTypedLinkFacetResponse, UpdateTypedLinkFacetError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("x-amz-data-partition", &input.schema_arn);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTypedLinkFacetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateTypedLinkFacetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_typed_link_facet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for update_typed_link_facet"),
+        }
     }
-
-    /// <p>Upgrades a single directory in-place using the <code>PublishedSchemaArn</code> with schema updates found in <code>MinorVersion</code>. Backwards-compatible minor version upgrades are instantaneously available for readers on all objects in the directory. Note: This is a synchronous API call and upgrades only one schema on a given directory per call. To upgrade multiple directories from one schema, you would need to call this API on each directory.</p>
     fn upgrade_applied_schema(
         &self,
         input: UpgradeAppliedSchemaRequest,
     ) -> RusotoFuture<UpgradeAppliedSchemaResponse, UpgradeAppliedSchemaError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradeapplied";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpgradeAppliedSchemaResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpgradeAppliedSchemaError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.upgrade_applied_schema {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for upgrade_applied_schema"),
+        }
     }
-
-    /// <p>Upgrades a published schema under a new minor version revision using the current contents of <code>DevelopmentSchemaArn</code>.</p>
     fn upgrade_published_schema(
         &self,
         input: UpgradePublishedSchemaRequest,
     ) -> RusotoFuture<UpgradePublishedSchemaResponse, UpgradePublishedSchemaError> {
-        let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradepublished";
-
-        let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpgradePublishedSchemaResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpgradePublishedSchemaError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.upgrade_published_schema {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudDirectory has no handler for upgrade_published_schema"),
+        }
     }
 }
@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -46,6 +47,8 @@ impl AccountDeserializer {
 }
 /// <p>Structure that contains the results of the account gate function which AWS CloudFormation invokes, if present, before proceeding with a stack set operation in an account and region.</p> <p>For each account and region, AWS CloudFormation lets you specify a Lamdba function that encapsulates any requirements that must be met before CloudFormation can proceed with a stack set operation in that account and region. CloudFormation invokes the function each time a stack set operation is requested for that account and region; if the function returns <code>FAILED</code>, CloudFormation cancels the operation in that account and region, and sets the stack set operation result status for that account and region to <code>FAILED</code>. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-account-gating.html">Configuring a target account gate</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccountGateResult {
     /// <p><p>The status of the account gate function.</p> <ul> <li> <p> <code>SUCCEEDED</code>: The account gate function has determined that the account and region passes any requirements for a stack set operation to occur. AWS CloudFormation proceeds with the stack operation in that account and region. </p> </li> <li> <p> <code>FAILED</code>: The account gate function has determined that the account and region does not meet the requirements for a stack set operation to occur. AWS CloudFormation cancels the stack set operation in that account and region, and sets the stack set operation result status for that account and region to <code>FAILED</code>. </p> </li> <li> <p> <code>SKIPPED</code>: AWS CloudFormation has skipped calling the account gate function for this account and region, for one of the following reasons:</p> <ul> <li> <p>An account gate function has not been specified for the account and region. AWS CloudFormation proceeds with the stack set operation in this account and region.</p> </li> <li> <p>The <code>AWSCloudFormationStackSetExecutionRole</code> of the stack set adminstration account lacks permissions to invoke the function. AWS CloudFormation proceeds with the stack set operation in this account and region.</p> </li> <li> <p>Either no action is necessary, or no action is possible, on the stack. AWS CloudFormation skips the stack set operation in this account and region.</p> </li> </ul> </li> </ul></p>
     pub status: Option<String>,
@@ -101,6 +104,8 @@ impl AccountGateStatusReasonDeserializer {
 }
 /// <p>The AccountLimit data type. For more information about account limits, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">AWS CloudFormation Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccountLimit {
     /// <p>The name of the account limit.</p>
     pub name: Option<String>,
@@ -202,7 +207,7 @@ impl BoxedIntegerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -210,6 +215,8 @@ impl BoxedIntegerDeserializer {
 }
 /// <p>The input for the <a>CancelUpdateStack</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelUpdateStackInput {
     /// <p>A unique identifier for this <code>CancelUpdateStack</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to cancel an update on a stack with the same name. You might retry <code>CancelUpdateStack</code> requests to ensure that AWS CloudFormation successfully received them.</p>
     pub client_request_token: Option<String>,
@@ -217,6 +224,24 @@ pub struct CancelUpdateStackInput {
     pub stack_name: String,
 }
 
+impl Validate for CancelUpdateStackInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+    }
+}
+
 /// Serialize `CancelUpdateStackInput` contents to a `SignedRequest`.
 struct CancelUpdateStackInputSerializer;
 impl CancelUpdateStackInputSerializer {
@@ -297,6 +322,8 @@ impl CausingEntityDeserializer {
 }
 /// <p>The <code>Change</code> structure describes the changes AWS CloudFormation will perform if you execute the change set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Change {
     /// <p>A <code>ResourceChange</code> structure that describes the resource and action that AWS CloudFormation will perform.</p>
     pub resource_change: Option<ResourceChange>,
@@ -399,6 +426,8 @@ impl ChangeSetSummariesDeserializer {
 }
 /// <p>The <code>ChangeSetSummary</code> structure describes a change set, its status, and the stack with which it's associated.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeSetSummary {
     /// <p>The ID of the change set.</p>
     pub change_set_id: Option<String>,
@@ -528,6 +557,8 @@ impl ClientRequestTokenDeserializer {
 }
 /// <p>The input for the <a>ContinueUpdateRollback</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContinueUpdateRollbackInput {
     /// <p>A unique identifier for this <code>ContinueUpdateRollback</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to continue the rollback to a stack with the same name. You might retry <code>ContinueUpdateRollback</code> requests to ensure that AWS CloudFormation successfully received them.</p>
     pub client_request_token: Option<String>,
@@ -539,6 +570,53 @@ pub struct ContinueUpdateRollbackInput {
     pub stack_name: String,
 }
 
+impl Validate for ContinueUpdateRollbackInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.resources_to_skip {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resources_to_skip"), i);
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[a-zA-Z0-9]+|[a-zA-Z][-a-zA-Z0-9]*\\.[a-zA-Z0-9]+",
+                );
+            }
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "stack_name"),
+            &self.stack_name,
+            "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+        );
+    }
+}
+
 /// Serialize `ContinueUpdateRollbackInput` contents to a `SignedRequest`.
 struct ContinueUpdateRollbackInputSerializer;
 impl ContinueUpdateRollbackInputSerializer {
@@ -567,6 +645,8 @@ impl ContinueUpdateRollbackInputSerializer {
 
 /// <p>The output for a <a>ContinueUpdateRollback</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContinueUpdateRollbackOutput {}
 
 struct ContinueUpdateRollbackOutputDeserializer;
@@ -587,6 +667,8 @@ impl ContinueUpdateRollbackOutputDeserializer {
 }
 /// <p>The input for the <a>CreateChangeSet</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateChangeSetInput {
     /// <p><p>In some cases, you must explicity acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to create the stack.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some template contain macros. Macros perform custom processing on templates; this can include simple actions like find-and-replace operations, all the way to extensive transformations of entire templates. Because of this, users typically create a change set from the processed template, so that they can review the changes resulting from the macros before actually creating the stack. If your stack template contains one or more macros, and you choose to create a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.</p> <note> <p>This capacity does not apply to creating change sets, and specifying it when creating change sets has no effect.</p> <p>Also, change sets do not currently support nested stacks. If you want to create a stack from a stack template that contains macros <i>and</i> nested stacks, you must create or update the stack directly from the template using the <a>CreateStack</a> or <a>UpdateStack</a> action, and specifying this capability.</p> </note> <p>For more information on macros, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> </li> </ul></p>
     pub capabilities: Option<Vec<String>>,
@@ -620,6 +702,97 @@ pub struct CreateChangeSetInput {
     pub use_previous_template: Option<bool>,
 }
 
+impl Validate for CreateChangeSetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_set_name"),
+            self.change_set_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_set_name"),
+            &self.change_set_name,
+            "[a-zA-Z][-a-zA-Z0-9]*",
+        );
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.notification_ar_ns {
+            errors.check_length(
+                &field_path(path, "notification_ar_ns"),
+                value.len(),
+                None,
+                Some(5),
+            );
+        }
+        if let Some(ref value) = self.resource_types {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_types"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.rollback_configuration {
+            value.validate_at(&field_path(path, "rollback_configuration"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "stack_name"),
+            &self.stack_name,
+            "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.template_body {
+            errors.check_length(
+                &field_path(path, "template_body"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.template_url {
+            errors.check_length(
+                &field_path(path, "template_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `CreateChangeSetInput` contents to a `SignedRequest`.
 struct CreateChangeSetInputSerializer;
 impl CreateChangeSetInputSerializer {
@@ -701,6 +874,8 @@ impl CreateChangeSetInputSerializer {
 
 /// <p>The output for the <a>CreateChangeSet</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateChangeSetOutput {
     /// <p>The Amazon Resource Name (ARN) of the change set.</p>
     pub id: Option<String>,
@@ -731,6 +906,8 @@ impl CreateChangeSetOutputDeserializer {
 }
 /// <p>The input for <a>CreateStack</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStackInput {
     /// <p><p>In some cases, you must explicity acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to create the stack.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some template contain macros. Macros perform custom processing on templates; this can include simple actions like find-and-replace operations, all the way to extensive transformations of entire templates. Because of this, users typically create a change set from the processed template, so that they can review the changes resulting from the macros before actually creating the stack. If your stack template contains one or more macros, and you choose to create a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.</p> <p>Change sets do not currently support nested stacks. If you want to create a stack from a stack template that contains macros <i>and</i> nested stacks, you must create the stack directly from the template using this capability.</p> <important> <p>You should only create stacks directly from a stack template that contains macros if you know what processing the macro performs.</p> <p>Each macro relies on an underlying Lambda service function for processing stack templates. Be aware that the Lambda function owner can update the function operation without AWS CloudFormation being notified.</p> </important> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> </li> </ul></p>
     pub capabilities: Option<Vec<String>>,
@@ -768,6 +945,96 @@ pub struct CreateStackInput {
     pub timeout_in_minutes: Option<i64>,
 }
 
+impl Validate for CreateStackInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.notification_ar_ns {
+            errors.check_length(
+                &field_path(path, "notification_ar_ns"),
+                value.len(),
+                None,
+                Some(5),
+            );
+        }
+        if let Some(ref value) = self.resource_types {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_types"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.rollback_configuration {
+            value.validate_at(&field_path(path, "rollback_configuration"), errors);
+        }
+        if let Some(ref value) = self.stack_policy_body {
+            errors.check_length(
+                &field_path(path, "stack_policy_body"),
+                value.chars().count(),
+                Some(1),
+                Some(16384),
+            );
+        }
+        if let Some(ref value) = self.stack_policy_url {
+            errors.check_length(
+                &field_path(path, "stack_policy_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1350),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.template_body {
+            errors.check_length(
+                &field_path(path, "template_body"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.template_url {
+            errors.check_length(
+                &field_path(path, "template_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.timeout_in_minutes {
+            errors.check_range(
+                &field_path(path, "timeout_in_minutes"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// Serialize `CreateStackInput` contents to a `SignedRequest`.
 struct CreateStackInputSerializer;
 impl CreateStackInputSerializer {
@@ -853,6 +1120,8 @@ impl CreateStackInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStackInstancesInput {
     /// <p>The names of one or more AWS accounts that you want to create stack instances in the specified region(s) for.</p>
     pub accounts: Vec<String>,
@@ -868,6 +1137,35 @@ pub struct CreateStackInstancesInput {
     pub stack_set_name: String,
 }
 
+impl Validate for CreateStackInstancesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.accounts.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "accounts"), i);
+            errors.check_pattern(&item_path, &item, "^[0-9]{12}$");
+        }
+        if let Some(ref value) = self.operation_id {
+            errors.check_length(
+                &field_path(path, "operation_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "operation_id"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.operation_preferences {
+            value.validate_at(&field_path(path, "operation_preferences"), errors);
+        }
+        for (i, item) in self.regions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "regions"), i);
+            errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9-]{1,128}$");
+        }
+    }
+}
+
 /// Serialize `CreateStackInstancesInput` contents to a `SignedRequest`.
 struct CreateStackInstancesInputSerializer;
 impl CreateStackInstancesInputSerializer {
@@ -908,6 +1206,8 @@ impl CreateStackInstancesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStackInstancesOutput {
     /// <p>The unique identifier for this stack set operation.</p>
     pub operation_id: Option<String>,
@@ -940,6 +1240,8 @@ impl CreateStackInstancesOutputDeserializer {
 }
 /// <p>The output for a <a>CreateStack</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStackOutput {
     /// <p>Unique identifier of the stack.</p>
     pub stack_id: Option<String>,
@@ -964,6 +1266,8 @@ impl CreateStackOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStackSetInput {
     /// <p>The Amazon Resource Number (ARN) of the IAM role to use to create this stack set. </p> <p>Specify an IAM role only if you are using customized administrator roles to control which users or groups can manage specific stack sets within the same administrator account. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-prereqs.html">Prerequisites: Granting Permissions for Stack Set Operations</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub administration_role_arn: Option<String>,
@@ -987,6 +1291,76 @@ pub struct CreateStackSetInput {
     pub template_url: Option<String>,
 }
 
+impl Validate for CreateStackSetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.administration_role_arn {
+            errors.check_length(
+                &field_path(path, "administration_role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.execution_role_name {
+            errors.check_length(
+                &field_path(path, "execution_role_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "execution_role_name"),
+                &value,
+                "[a-zA-Z_0-9+=,.@-]+",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.template_body {
+            errors.check_length(
+                &field_path(path, "template_body"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.template_url {
+            errors.check_length(
+                &field_path(path, "template_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `CreateStackSetInput` contents to a `SignedRequest`.
 struct CreateStackSetInputSerializer;
 impl CreateStackSetInputSerializer {
@@ -1042,6 +1416,8 @@ impl CreateStackSetInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStackSetOutput {
     /// <p>The ID of the stack set that you're creating.</p>
     pub stack_set_id: Option<String>,
@@ -1079,6 +1455,8 @@ impl CreationTimeDeserializer {
 }
 /// <p>The input for the <a>DeleteChangeSet</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteChangeSetInput {
     /// <p>The name or Amazon Resource Name (ARN) of the change set that you want to delete.</p>
     pub change_set_name: String,
@@ -1086,6 +1464,35 @@ pub struct DeleteChangeSetInput {
     pub stack_name: Option<String>,
 }
 
+impl Validate for DeleteChangeSetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_set_name"),
+            self.change_set_name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_set_name"),
+            &self.change_set_name,
+            "[a-zA-Z][-a-zA-Z0-9]*|arn:[-a-zA-Z0-9:/]*",
+        );
+        if let Some(ref value) = self.stack_name {
+            errors.check_length(
+                &field_path(path, "stack_name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(
+                &field_path(path, "stack_name"),
+                &value,
+                "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+            );
+        }
+    }
+}
+
 /// Serialize `DeleteChangeSetInput` contents to a `SignedRequest`.
 struct DeleteChangeSetInputSerializer;
 impl DeleteChangeSetInputSerializer {
@@ -1107,6 +1514,8 @@ impl DeleteChangeSetInputSerializer {
 
 /// <p>The output for the <a>DeleteChangeSet</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteChangeSetOutput {}
 
 struct DeleteChangeSetOutputDeserializer;
@@ -1127,6 +1536,8 @@ impl DeleteChangeSetOutputDeserializer {
 }
 /// <p>The input for <a>DeleteStack</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStackInput {
     /// <p>A unique identifier for this <code>DeleteStack</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to delete a stack with the same name. You might retry <code>DeleteStack</code> requests to ensure that AWS CloudFormation successfully received them.</p> <p>All events triggered by a given stack operation are assigned the same client request token, which you can use to track operations. For example, if you execute a <code>CreateStack</code> operation with the token <code>token1</code>, then all the <code>StackEvents</code> generated by that operation will have <code>ClientRequestToken</code> set as <code>token1</code>.</p> <p>In the console, stack operations display the client request token on the Events tab. Stack operations that are initiated from the console use the token format <i>Console-StackOperation-ID</i>, which helps you easily identify the stack operation . For example, if you create a stack using the console, each stack event would be assigned the same token in the following format: <code>Console-CreateStack-7f59c3cf-00d2-40c7-b2ff-e75db0987002</code>. </p>
     pub client_request_token: Option<String>,
@@ -1138,6 +1549,32 @@ pub struct DeleteStackInput {
     pub stack_name: String,
 }
 
+impl Validate for DeleteStackInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+    }
+}
+
 /// Serialize `DeleteStackInput` contents to a `SignedRequest`.
 struct DeleteStackInputSerializer;
 impl DeleteStackInputSerializer {
@@ -1165,6 +1602,8 @@ impl DeleteStackInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStackInstancesInput {
     /// <p>The names of the AWS accounts that you want to delete stack instances for.</p>
     pub accounts: Vec<String>,
@@ -1180,6 +1619,35 @@ pub struct DeleteStackInstancesInput {
     pub stack_set_name: String,
 }
 
+impl Validate for DeleteStackInstancesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.accounts.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "accounts"), i);
+            errors.check_pattern(&item_path, &item, "^[0-9]{12}$");
+        }
+        if let Some(ref value) = self.operation_id {
+            errors.check_length(
+                &field_path(path, "operation_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "operation_id"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.operation_preferences {
+            value.validate_at(&field_path(path, "operation_preferences"), errors);
+        }
+        for (i, item) in self.regions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "regions"), i);
+            errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9-]{1,128}$");
+        }
+    }
+}
+
 /// Serialize `DeleteStackInstancesInput` contents to a `SignedRequest`.
 struct DeleteStackInstancesInputSerializer;
 impl DeleteStackInstancesInputSerializer {
@@ -1214,6 +1682,8 @@ impl DeleteStackInstancesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStackInstancesOutput {
     /// <p>The unique identifier for this stack set operation.</p>
     pub operation_id: Option<String>,
@@ -1245,6 +1715,8 @@ impl DeleteStackInstancesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStackSetInput {
     /// <p>The name or unique ID of the stack set that you're deleting. You can obtain this value by running <a>ListStackSets</a>.</p>
     pub stack_set_name: String,
@@ -1267,6 +1739,8 @@ impl DeleteStackSetInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStackSetOutput {}
 
 struct DeleteStackSetOutputDeserializer;
@@ -1298,11 +1772,26 @@ impl DeletionTimeDeserializer {
 }
 /// <p>The input for the <a>DescribeAccountLimits</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountLimitsInput {
     /// <p>A string that identifies the next page of limits that you want to retrieve.</p>
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAccountLimitsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeAccountLimitsInput` contents to a `SignedRequest`.
 struct DescribeAccountLimitsInputSerializer;
 impl DescribeAccountLimitsInputSerializer {
@@ -1320,6 +1809,8 @@ impl DescribeAccountLimitsInputSerializer {
 
 /// <p>The output for the <a>DescribeAccountLimits</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountLimitsOutput {
     /// <p>An account limit structure that contain a list of AWS CloudFormation account limits and their values.</p>
     pub account_limits: Option<Vec<AccountLimit>>,
@@ -1357,6 +1848,8 @@ impl DescribeAccountLimitsOutputDeserializer {
 }
 /// <p>The input for the <a>DescribeChangeSet</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeChangeSetInput {
     /// <p>The name or Amazon Resource Name (ARN) of the change set that you want to describe.</p>
     pub change_set_name: String,
@@ -1366,6 +1859,43 @@ pub struct DescribeChangeSetInput {
     pub stack_name: Option<String>,
 }
 
+impl Validate for DescribeChangeSetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_set_name"),
+            self.change_set_name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_set_name"),
+            &self.change_set_name,
+            "[a-zA-Z][-a-zA-Z0-9]*|arn:[-a-zA-Z0-9:/]*",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.stack_name {
+            errors.check_length(
+                &field_path(path, "stack_name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(
+                &field_path(path, "stack_name"),
+                &value,
+                "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeChangeSetInput` contents to a `SignedRequest`.
 struct DescribeChangeSetInputSerializer;
 impl DescribeChangeSetInputSerializer {
@@ -1390,6 +1920,8 @@ impl DescribeChangeSetInputSerializer {
 
 /// <p>The output for the <a>DescribeChangeSet</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeChangeSetOutput {
     /// <p>If you execute the change set, the list of capabilities that were explicitly acknowledged when the change set was created.</p>
     pub capabilities: Option<Vec<String>>,
@@ -1524,11 +2056,24 @@ impl DescribeChangeSetOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackDriftDetectionStatusInput {
     /// <p>The ID of the drift detection results of this operation. </p> <p>AWS CloudFormation generates new results, with a new drift detection ID, each time this operation is run. However, the number of drift results AWS CloudFormation retains for any given stack, and for how long, may vary. </p>
     pub stack_drift_detection_id: String,
 }
 
+impl Validate for DescribeStackDriftDetectionStatusInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stack_drift_detection_id"),
+            self.stack_drift_detection_id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+    }
+}
+
 /// Serialize `DescribeStackDriftDetectionStatusInput` contents to a `SignedRequest`.
 struct DescribeStackDriftDetectionStatusInputSerializer;
 impl DescribeStackDriftDetectionStatusInputSerializer {
@@ -1546,6 +2091,8 @@ impl DescribeStackDriftDetectionStatusInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackDriftDetectionStatusOutput {
     /// <p><p>The status of the stack drift detection operation.</p> <ul> <li> <p> <code>DETECTION<em>COMPLETE</code>: The stack drift detection operation has successfully completed for all resources in the stack that support drift detection. (Resources that do not currently support stack detection remain unchecked.)</p> <p>If you specified logical resource IDs for AWS CloudFormation to use as a filter for the stack drift detection operation, only the resources with those logical IDs are checked for drift.</p> </li> <li> <p> <code>DETECTION</em>FAILED</code>: The stack drift detection operation has failed for at least one resource in the stack. Results will be available for resources on which AWS CloudFormation successfully completed drift detection.</p> </li> <li> <p> <code>DETECTION<em>IN</em>PROGRESS</code>: The stack drift detection operation is currently in progress.</p> </li> </ul></p>
     pub detection_status: String,
@@ -1623,6 +2170,8 @@ impl DescribeStackDriftDetectionStatusOutputDeserializer {
 }
 /// <p>The input for <a>DescribeStackEvents</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackEventsInput {
     /// <p>A string that identifies the next page of events that you want to retrieve.</p>
     pub next_token: Option<String>,
@@ -1630,6 +2179,19 @@ pub struct DescribeStackEventsInput {
     pub stack_name: Option<String>,
 }
 
+impl Validate for DescribeStackEventsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeStackEventsInput` contents to a `SignedRequest`.
 struct DescribeStackEventsInputSerializer;
 impl DescribeStackEventsInputSerializer {
@@ -1650,6 +2212,8 @@ impl DescribeStackEventsInputSerializer {
 
 /// <p>The output for a <a>DescribeStackEvents</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackEventsOutput {
     /// <p>If the output exceeds 1 MB in size, a string that identifies the next page of events. If no additional page exists, this value is null.</p>
     pub next_token: Option<String>,
@@ -1686,6 +2250,8 @@ impl DescribeStackEventsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackInstanceInput {
     /// <p>The ID of an AWS account that's associated with this stack instance.</p>
     pub stack_instance_account: String,
@@ -1695,6 +2261,21 @@ pub struct DescribeStackInstanceInput {
     pub stack_set_name: String,
 }
 
+impl Validate for DescribeStackInstanceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "stack_instance_account"),
+            &self.stack_instance_account,
+            "^[0-9]{12}$",
+        );
+        errors.check_pattern(
+            &field_path(path, "stack_instance_region"),
+            &self.stack_instance_region,
+            "^[a-zA-Z0-9-]{1,128}$",
+        );
+    }
+}
+
 /// Serialize `DescribeStackInstanceInput` contents to a `SignedRequest`.
 struct DescribeStackInstanceInputSerializer;
 impl DescribeStackInstanceInputSerializer {
@@ -1720,6 +2301,8 @@ impl DescribeStackInstanceInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackInstanceOutput {
     /// <p>The stack instance that matches the specified request parameters.</p>
     pub stack_instance: Option<StackInstance>,
@@ -1751,6 +2334,8 @@ impl DescribeStackInstanceOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackResourceDriftsInput {
     /// <p>The maximum number of results to be returned with a single call. If the number of available results exceeds this maximum, the response includes a <code>NextToken</code> value that you can assign to the <code>NextToken</code> request parameter to get the next set of results.</p>
     pub max_results: Option<i64>,
@@ -1762,6 +2347,41 @@ pub struct DescribeStackResourceDriftsInput {
     pub stack_resource_drift_status_filters: Option<Vec<String>>,
 }
 
+impl Validate for DescribeStackResourceDriftsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "stack_name"),
+            &self.stack_name,
+            "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+        );
+        if let Some(ref value) = self.stack_resource_drift_status_filters {
+            errors.check_length(
+                &field_path(path, "stack_resource_drift_status_filters"),
+                value.len(),
+                Some(1),
+                Some(4),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeStackResourceDriftsInput` contents to a `SignedRequest`.
 struct DescribeStackResourceDriftsInputSerializer;
 impl DescribeStackResourceDriftsInputSerializer {
@@ -1789,6 +2409,8 @@ impl DescribeStackResourceDriftsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackResourceDriftsOutput {
     /// <p>If the request doesn't return all of the remaining results, <code>NextToken</code> is set to a token. To retrieve the next set of results, call <code>DescribeStackResourceDrifts</code> again and assign that token to the request object's <code>NextToken</code> parameter. If the request returns all results, <code>NextToken</code> is set to <code>null</code>.</p>
     pub next_token: Option<String>,
@@ -1829,6 +2451,8 @@ impl DescribeStackResourceDriftsOutputDeserializer {
 }
 /// <p>The input for <a>DescribeStackResource</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackResourceInput {
     /// <p>The logical name of the resource as specified in the template.</p> <p>Default: There is no default value.</p>
     pub logical_resource_id: String,
@@ -1855,6 +2479,8 @@ impl DescribeStackResourceInputSerializer {
 
 /// <p>The output for a <a>DescribeStackResource</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackResourceOutput {
     /// <p>A <code>StackResourceDetail</code> structure containing the description of the specified resource in the specified stack.</p>
     pub stack_resource_detail: Option<StackResourceDetail>,
@@ -1888,6 +2514,8 @@ impl DescribeStackResourceOutputDeserializer {
 }
 /// <p>The input for <a>DescribeStackResources</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackResourcesInput {
     /// <p>The logical name of the resource as specified in the template.</p> <p>Default: There is no default value.</p>
     pub logical_resource_id: Option<String>,
@@ -1920,6 +2548,8 @@ impl DescribeStackResourcesInputSerializer {
 
 /// <p>The output for a <a>DescribeStackResources</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackResourcesOutput {
     /// <p>A list of <code>StackResource</code> structures.</p>
     pub stack_resources: Option<Vec<StackResource>>,
@@ -1950,6 +2580,8 @@ impl DescribeStackResourcesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackSetInput {
     /// <p>The name or unique ID of the stack set whose description you want.</p>
     pub stack_set_name: String,
@@ -1972,6 +2604,8 @@ impl DescribeStackSetInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackSetOperationInput {
     /// <p>The unique ID of the stack set operation. </p>
     pub operation_id: String,
@@ -1979,6 +2613,22 @@ pub struct DescribeStackSetOperationInput {
     pub stack_set_name: String,
 }
 
+impl Validate for DescribeStackSetOperationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "operation_id"),
+            self.operation_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "operation_id"),
+            &self.operation_id,
+            "[a-zA-Z0-9][-a-zA-Z0-9]*",
+        );
+    }
+}
+
 /// Serialize `DescribeStackSetOperationInput` contents to a `SignedRequest`.
 struct DescribeStackSetOperationInputSerializer;
 impl DescribeStackSetOperationInputSerializer {
@@ -1997,6 +2647,8 @@ impl DescribeStackSetOperationInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackSetOperationOutput {
     /// <p>The specified stack set operation.</p>
     pub stack_set_operation: Option<StackSetOperation>,
@@ -2028,6 +2680,8 @@ impl DescribeStackSetOperationOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackSetOutput {
     /// <p>The specified stack set.</p>
     pub stack_set: Option<StackSet>,
@@ -2053,6 +2707,8 @@ impl DescribeStackSetOutputDeserializer {
 }
 /// <p>The input for <a>DescribeStacks</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStacksInput {
     /// <p>A string that identifies the next page of stacks that you want to retrieve.</p>
     pub next_token: Option<String>,
@@ -2060,6 +2716,19 @@ pub struct DescribeStacksInput {
     pub stack_name: Option<String>,
 }
 
+impl Validate for DescribeStacksInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeStacksInput` contents to a `SignedRequest`.
 struct DescribeStacksInputSerializer;
 impl DescribeStacksInputSerializer {
@@ -2080,6 +2749,8 @@ impl DescribeStacksInputSerializer {
 
 /// <p>The output for a <a>DescribeStacks</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStacksOutput {
     /// <p>If the output exceeds 1 MB in size, a string that identifies the next page of stacks. If no additional page exists, this value is null.</p>
     pub next_token: Option<String>,
@@ -2122,6 +2793,8 @@ impl DescriptionDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectStackDriftInput {
     /// <p>The logical names of any resources you want to use as filters.</p>
     pub logical_resource_ids: Option<Vec<String>>,
@@ -2129,6 +2802,30 @@ pub struct DetectStackDriftInput {
     pub stack_name: String,
 }
 
+impl Validate for DetectStackDriftInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.logical_resource_ids {
+            errors.check_length(
+                &field_path(path, "logical_resource_ids"),
+                value.len(),
+                Some(1),
+                Some(200),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "stack_name"),
+            &self.stack_name,
+            "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+        );
+    }
+}
+
 /// Serialize `DetectStackDriftInput` contents to a `SignedRequest`.
 struct DetectStackDriftInputSerializer;
 impl DetectStackDriftInputSerializer {
@@ -2150,6 +2847,8 @@ impl DetectStackDriftInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectStackDriftOutput {
     /// <p>The ID of the drift detection results of this operation. </p> <p>AWS CloudFormation generates new results, with a new drift detection ID, each time this operation is run. However, the number of drift results AWS CloudFormation retains for any given stack, and for how long, may vary. </p>
     pub stack_drift_detection_id: String,
@@ -2177,6 +2876,8 @@ impl DetectStackDriftOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectStackResourceDriftInput {
     /// <p>The logical name of the resource for which to return drift information.</p>
     pub logical_resource_id: String,
@@ -2184,6 +2885,22 @@ pub struct DetectStackResourceDriftInput {
     pub stack_name: String,
 }
 
+impl Validate for DetectStackResourceDriftInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "stack_name"),
+            &self.stack_name,
+            "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+        );
+    }
+}
+
 /// Serialize `DetectStackResourceDriftInput` contents to a `SignedRequest`.
 struct DetectStackResourceDriftInputSerializer;
 impl DetectStackResourceDriftInputSerializer {
@@ -2202,6 +2919,8 @@ impl DetectStackResourceDriftInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectStackResourceDriftOutput {
     /// <p>Information about whether the resource's actual configuration has drifted from its expected template configuration, including actual and expected property values and any differences detected.</p>
     pub stack_resource_drift: StackResourceDrift,
@@ -2248,7 +2967,7 @@ impl DisableRollbackDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2259,7 +2978,7 @@ impl EnableTerminationProtectionDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2267,6 +2986,8 @@ impl EnableTerminationProtectionDeserializer {
 }
 /// <p>The input for an <a>EstimateTemplateCost</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EstimateTemplateCostInput {
     /// <p>A list of <code>Parameter</code> structures that specify input parameters.</p>
     pub parameters: Option<Vec<Parameter>>,
@@ -2276,6 +2997,27 @@ pub struct EstimateTemplateCostInput {
     pub template_url: Option<String>,
 }
 
+impl Validate for EstimateTemplateCostInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.template_body {
+            errors.check_length(
+                &field_path(path, "template_body"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.template_url {
+            errors.check_length(
+                &field_path(path, "template_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `EstimateTemplateCostInput` contents to a `SignedRequest`.
 struct EstimateTemplateCostInputSerializer;
 impl EstimateTemplateCostInputSerializer {
@@ -2303,6 +3045,8 @@ impl EstimateTemplateCostInputSerializer {
 
 /// <p>The output for a <a>EstimateTemplateCost</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EstimateTemplateCostOutput {
     /// <p>An AWS Simple Monthly Calculator URL with a query string that describes the resources required to run the template.</p>
     pub url: Option<String>,
@@ -2354,6 +3098,8 @@ impl EventIdDeserializer {
 }
 /// <p>The input for the <a>ExecuteChangeSet</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecuteChangeSetInput {
     /// <p>The name or ARN of the change set that you want use to update the specified stack.</p>
     pub change_set_name: String,
@@ -2363,6 +3109,48 @@ pub struct ExecuteChangeSetInput {
     pub stack_name: Option<String>,
 }
 
+impl Validate for ExecuteChangeSetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_set_name"),
+            self.change_set_name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_set_name"),
+            &self.change_set_name,
+            "[a-zA-Z][-a-zA-Z0-9]*|arn:[-a-zA-Z0-9:/]*",
+        );
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.stack_name {
+            errors.check_length(
+                &field_path(path, "stack_name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(
+                &field_path(path, "stack_name"),
+                &value,
+                "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+            );
+        }
+    }
+}
+
 /// Serialize `ExecuteChangeSetInput` contents to a `SignedRequest`.
 struct ExecuteChangeSetInputSerializer;
 impl ExecuteChangeSetInputSerializer {
@@ -2387,6 +3175,8 @@ impl ExecuteChangeSetInputSerializer {
 
 /// <p>The output for the <a>ExecuteChangeSet</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecuteChangeSetOutput {}
 
 struct ExecuteChangeSetOutputDeserializer;
@@ -2429,6 +3219,8 @@ impl ExecutionStatusDeserializer {
 }
 /// <p>The <code>Export</code> structure describes the exported output values for a stack.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Export {
     /// <p>The stack that contains the exported output name and value.</p>
     pub exporting_stack_id: Option<String>,
@@ -2504,7 +3296,7 @@ impl FailureToleranceCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2515,7 +3307,7 @@ impl FailureTolerancePercentageDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2523,6 +3315,8 @@ impl FailureTolerancePercentageDeserializer {
 }
 /// <p>The input for the <a>GetStackPolicy</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStackPolicyInput {
     /// <p>The name or unique stack ID that is associated with the stack whose policy you want to get.</p>
     pub stack_name: String,
@@ -2543,6 +3337,8 @@ impl GetStackPolicyInputSerializer {
 
 /// <p>The output for the <a>GetStackPolicy</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStackPolicyOutput {
     /// <p>Structure containing the stack policy body. (For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/protect-stack-resources.html"> Prevent Updates to Stack Resources</a> in the AWS CloudFormation User Guide.)</p>
     pub stack_policy_body: Option<String>,
@@ -2571,6 +3367,8 @@ impl GetStackPolicyOutputDeserializer {
 }
 /// <p>The input for a <a>GetTemplate</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTemplateInput {
     /// <p>The name or Amazon Resource Name (ARN) of a change set for which AWS CloudFormation returns the associated template. If you specify a name, you must also specify the <code>StackName</code>.</p>
     pub change_set_name: Option<String>,
@@ -2580,6 +3378,24 @@ pub struct GetTemplateInput {
     pub template_stage: Option<String>,
 }
 
+impl Validate for GetTemplateInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.change_set_name {
+            errors.check_length(
+                &field_path(path, "change_set_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(
+                &field_path(path, "change_set_name"),
+                &value,
+                "[a-zA-Z][-a-zA-Z0-9]*|arn:[-a-zA-Z0-9:/]*",
+            );
+        }
+    }
+}
+
 /// Serialize `GetTemplateInput` contents to a `SignedRequest`.
 struct GetTemplateInputSerializer;
 impl GetTemplateInputSerializer {
@@ -2603,6 +3419,8 @@ impl GetTemplateInputSerializer {
 
 /// <p>The output for <a>GetTemplate</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTemplateOutput {
     /// <p>The stage of the template that you can retrieve. For stacks, the <code>Original</code> and <code>Processed</code> templates are always available. For change sets, the <code>Original</code> template is always available. After AWS CloudFormation finishes creating the change set, the <code>Processed</code> template becomes available.</p>
     pub stages_available: Option<Vec<String>>,
@@ -2638,6 +3456,8 @@ impl GetTemplateOutputDeserializer {
 }
 /// <p>The input for the <a>GetTemplateSummary</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTemplateSummaryInput {
     /// <p>The name or the stack ID that is associated with the stack, which are not always interchangeable. For running stacks, you can specify either the stack's name or its unique stack ID. For deleted stack, you must specify the unique stack ID.</p> <p>Conditional: You must specify only one of the following parameters: <code>StackName</code>, <code>StackSetName</code>, <code>TemplateBody</code>, or <code>TemplateURL</code>.</p>
     pub stack_name: Option<String>,
@@ -2649,6 +3469,43 @@ pub struct GetTemplateSummaryInput {
     pub template_url: Option<String>,
 }
 
+impl Validate for GetTemplateSummaryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.stack_name {
+            errors.check_length(
+                &field_path(path, "stack_name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(
+                &field_path(path, "stack_name"),
+                &value,
+                "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+            );
+        }
+        if let Some(ref value) = self.stack_set_name {
+            errors.check_pattern(&field_path(path, "stack_set_name"), &value, "[a-zA-Z][-a-zA-Z0-9]*(?::[a-zA-Z0-9]{8}-[a-zA-Z0-9]{4}-[a-zA-Z0-9]{4}-[a-zA-Z0-9]{4}-[a-zA-Z0-9]{12})?");
+        }
+        if let Some(ref value) = self.template_body {
+            errors.check_length(
+                &field_path(path, "template_body"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.template_url {
+            errors.check_length(
+                &field_path(path, "template_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `GetTemplateSummaryInput` contents to a `SignedRequest`.
 struct GetTemplateSummaryInputSerializer;
 impl GetTemplateSummaryInputSerializer {
@@ -2675,6 +3532,8 @@ impl GetTemplateSummaryInputSerializer {
 
 /// <p>The output for the <a>GetTemplateSummary</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTemplateSummaryOutput {
     /// <p>The capabilities found within the template. If your template contains IAM resources, you must specify the CAPABILITY_IAM or CAPABILITY_NAMED_IAM value for this parameter when you use the <a>CreateStack</a> or <a>UpdateStack</a> actions with your template; otherwise, those actions return an InsufficientCapabilities error.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p>
     pub capabilities: Option<Vec<String>>,
@@ -2805,7 +3664,7 @@ impl LimitValueDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2813,6 +3672,8 @@ impl LimitValueDeserializer {
 }
 /// <p>The input for the <a>ListChangeSets</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListChangeSetsInput {
     /// <p>A string (provided by the <a>ListChangeSets</a> response output) that identifies the next page of change sets that you want to retrieve.</p>
     pub next_token: Option<String>,
@@ -2820,6 +3681,30 @@ pub struct ListChangeSetsInput {
     pub stack_name: String,
 }
 
+impl Validate for ListChangeSetsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "stack_name"),
+            &self.stack_name,
+            "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+        );
+    }
+}
+
 /// Serialize `ListChangeSetsInput` contents to a `SignedRequest`.
 struct ListChangeSetsInputSerializer;
 impl ListChangeSetsInputSerializer {
@@ -2838,6 +3723,8 @@ impl ListChangeSetsInputSerializer {
 
 /// <p>The output for the <a>ListChangeSets</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListChangeSetsOutput {
     /// <p>If the output exceeds 1 MB, a string that identifies the next page of change sets. If there is no additional page, this value is null.</p>
     pub next_token: Option<String>,
@@ -2869,11 +3756,26 @@ impl ListChangeSetsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListExportsInput {
     /// <p>A string (provided by the <a>ListExports</a> response output) that identifies the next page of exported output values that you asked to retrieve.</p>
     pub next_token: Option<String>,
 }
 
+impl Validate for ListExportsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `ListExportsInput` contents to a `SignedRequest`.
 struct ListExportsInputSerializer;
 impl ListExportsInputSerializer {
@@ -2890,6 +3792,8 @@ impl ListExportsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListExportsOutput {
     /// <p>The output for the <a>ListExports</a> action.</p>
     pub exports: Option<Vec<Export>>,
@@ -2921,6 +3825,8 @@ impl ListExportsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListImportsInput {
     /// <p>The name of the exported output value. AWS CloudFormation returns the stack names that are importing this value. </p>
     pub export_name: String,
@@ -2928,6 +3834,19 @@ pub struct ListImportsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListImportsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `ListImportsInput` contents to a `SignedRequest`.
 struct ListImportsInputSerializer;
 impl ListImportsInputSerializer {
@@ -2945,6 +3864,8 @@ impl ListImportsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListImportsOutput {
     /// <p>A list of stack names that are importing the specified exported output value. </p>
     pub imports: Option<Vec<String>>,
@@ -2976,6 +3897,8 @@ impl ListImportsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackInstancesInput {
     /// <p>The maximum number of results to be returned with a single call. If the number of available results exceeds this maximum, the response includes a <code>NextToken</code> value that you can assign to the <code>NextToken</code> request parameter to get the next set of results.</p>
     pub max_results: Option<i64>,
@@ -2989,6 +3912,36 @@ pub struct ListStackInstancesInput {
     pub stack_set_name: String,
 }
 
+impl Validate for ListStackInstancesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.stack_instance_account {
+            errors.check_pattern(
+                &field_path(path, "stack_instance_account"),
+                &value,
+                "^[0-9]{12}$",
+            );
+        }
+        if let Some(ref value) = self.stack_instance_region {
+            errors.check_pattern(
+                &field_path(path, "stack_instance_region"),
+                &value,
+                "^[a-zA-Z0-9-]{1,128}$",
+            );
+        }
+    }
+}
+
 /// Serialize `ListStackInstancesInput` contents to a `SignedRequest`.
 struct ListStackInstancesInputSerializer;
 impl ListStackInstancesInputSerializer {
@@ -3024,6 +3977,8 @@ impl ListStackInstancesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackInstancesOutput {
     /// <p>If the request doesn't return all of the remaining results, <code>NextToken</code> is set to a token. To retrieve the next set of results, call <code>ListStackInstances</code> again and assign that token to the request object's <code>NextToken</code> parameter. If the request returns all results, <code>NextToken</code> is set to <code>null</code>.</p>
     pub next_token: Option<String>,
@@ -3061,6 +4016,8 @@ impl ListStackInstancesOutputDeserializer {
 }
 /// <p>The input for the <a>ListStackResource</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackResourcesInput {
     /// <p>A string that identifies the next page of stack resources that you want to retrieve.</p>
     pub next_token: Option<String>,
@@ -3068,6 +4025,19 @@ pub struct ListStackResourcesInput {
     pub stack_name: String,
 }
 
+impl Validate for ListStackResourcesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `ListStackResourcesInput` contents to a `SignedRequest`.
 struct ListStackResourcesInputSerializer;
 impl ListStackResourcesInputSerializer {
@@ -3086,6 +4056,8 @@ impl ListStackResourcesInputSerializer {
 
 /// <p>The output for a <a>ListStackResources</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackResourcesOutput {
     /// <p>If the output exceeds 1 MB, a string that identifies the next page of stack resources. If no additional page exists, this value is null.</p>
     pub next_token: Option<String>,
@@ -3125,6 +4097,8 @@ impl ListStackResourcesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackSetOperationResultsInput {
     /// <p>The maximum number of results to be returned with a single call. If the number of available results exceeds this maximum, the response includes a <code>NextToken</code> value that you can assign to the <code>NextToken</code> request parameter to get the next set of results.</p>
     pub max_results: Option<i64>,
@@ -3136,13 +4110,40 @@ pub struct ListStackSetOperationResultsInput {
     pub stack_set_name: String,
 }
 
-/// Serialize `ListStackSetOperationResultsInput` contents to a `SignedRequest`.
-struct ListStackSetOperationResultsInputSerializer;
-impl ListStackSetOperationResultsInputSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &ListStackSetOperationResultsInput) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
+impl Validate for ListStackSetOperationResultsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "operation_id"),
+            self.operation_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "operation_id"),
+            &self.operation_id,
+            "[a-zA-Z0-9][-a-zA-Z0-9]*",
+        );
+    }
+}
+
+/// Serialize `ListStackSetOperationResultsInput` contents to a `SignedRequest`.
+struct ListStackSetOperationResultsInputSerializer;
+impl ListStackSetOperationResultsInputSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &ListStackSetOperationResultsInput) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
         }
 
         if let Some(ref field_value) = obj.max_results {
@@ -3160,6 +4161,8 @@ impl ListStackSetOperationResultsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackSetOperationResultsOutput {
     /// <p>If the request doesn't return all results, <code>NextToken</code> is set to a token. To retrieve the next set of results, call <code>ListOperationResults</code> again and assign that token to the request object's <code>NextToken</code> parameter. If there are no remaining results, <code>NextToken</code> is set to <code>null</code>.</p>
     pub next_token: Option<String>,
@@ -3199,6 +4202,8 @@ impl ListStackSetOperationResultsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackSetOperationsInput {
     /// <p>The maximum number of results to be returned with a single call. If the number of available results exceeds this maximum, the response includes a <code>NextToken</code> value that you can assign to the <code>NextToken</code> request parameter to get the next set of results.</p>
     pub max_results: Option<i64>,
@@ -3208,6 +4213,22 @@ pub struct ListStackSetOperationsInput {
     pub stack_set_name: String,
 }
 
+impl Validate for ListStackSetOperationsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `ListStackSetOperationsInput` contents to a `SignedRequest`.
 struct ListStackSetOperationsInputSerializer;
 impl ListStackSetOperationsInputSerializer {
@@ -3231,6 +4252,8 @@ impl ListStackSetOperationsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackSetOperationsOutput {
     /// <p>If the request doesn't return all results, <code>NextToken</code> is set to a token. To retrieve the next set of results, call <code>ListOperationResults</code> again and assign that token to the request object's <code>NextToken</code> parameter. If there are no remaining results, <code>NextToken</code> is set to <code>null</code>.</p>
     pub next_token: Option<String>,
@@ -3270,6 +4293,8 @@ impl ListStackSetOperationsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackSetsInput {
     /// <p>The maximum number of results to be returned with a single call. If the number of available results exceeds this maximum, the response includes a <code>NextToken</code> value that you can assign to the <code>NextToken</code> request parameter to get the next set of results.</p>
     pub max_results: Option<i64>,
@@ -3279,6 +4304,22 @@ pub struct ListStackSetsInput {
     pub status: Option<String>,
 }
 
+impl Validate for ListStackSetsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `ListStackSetsInput` contents to a `SignedRequest`.
 struct ListStackSetsInputSerializer;
 impl ListStackSetsInputSerializer {
@@ -3301,6 +4342,8 @@ impl ListStackSetsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackSetsOutput {
     /// <p>If the request doesn't return all of the remaining results, <code>NextToken</code> is set to a token. To retrieve the next set of results, call <code>ListStackInstances</code> again and assign that token to the request object's <code>NextToken</code> parameter. If the request returns all results, <code>NextToken</code> is set to <code>null</code>.</p>
     pub next_token: Option<String>,
@@ -3333,6 +4376,8 @@ impl ListStackSetsOutputDeserializer {
 }
 /// <p>The input for <a>ListStacks</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStacksInput {
     /// <p>A string that identifies the next page of stacks that you want to retrieve.</p>
     pub next_token: Option<String>,
@@ -3340,6 +4385,19 @@ pub struct ListStacksInput {
     pub stack_status_filter: Option<Vec<String>>,
 }
 
+impl Validate for ListStacksInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `ListStacksInput` contents to a `SignedRequest`.
 struct ListStacksInputSerializer;
 impl ListStacksInputSerializer {
@@ -3364,6 +4422,8 @@ impl ListStacksInputSerializer {
 
 /// <p>The output for <a>ListStacks</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStacksOutput {
     /// <p>If the output exceeds 1 MB in size, a string that identifies the next page of stacks. If no additional page exists, this value is null.</p>
     pub next_token: Option<String>,
@@ -3422,7 +4482,7 @@ impl MaxConcurrentCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3433,7 +4493,7 @@ impl MaxConcurrentPercentageDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3455,7 +4515,7 @@ impl MonitoringTimeInMinutesDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3477,7 +4537,7 @@ impl NoEchoDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3525,6 +4585,8 @@ impl NotificationARNsSerializer {
 
 /// <p>The Output data type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Output {
     /// <p>User defined description associated with the output.</p>
     pub description: Option<String>,
@@ -3604,6 +4666,8 @@ impl OutputsDeserializer {
 }
 /// <p>The Parameter data type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Parameter {
     /// <p>The key associated with the parameter. If you don't specify a key and value for a particular parameter, AWS CloudFormation uses the default value that is specified in your template.</p>
     pub parameter_key: Option<String>,
@@ -3681,6 +4745,8 @@ impl ParameterSerializer {
 
 /// <p>A set of criteria that AWS CloudFormation uses to validate parameter values. Although other constraints might be defined in the stack template, AWS CloudFormation returns only the <code>AllowedValues</code> property.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ParameterConstraints {
     /// <p>A list of values that are permitted for a parameter.</p>
     pub allowed_values: Option<Vec<String>>,
@@ -3708,6 +4774,8 @@ impl ParameterConstraintsDeserializer {
 }
 /// <p>The ParameterDeclaration data type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ParameterDeclaration {
     /// <p>The default value of the parameter.</p>
     pub default_value: Option<String>,
@@ -3885,6 +4953,8 @@ impl PhysicalResourceIdContextDeserializer {
 }
 /// <p>Context information that enables AWS CloudFormation to uniquely identify a resource. AWS CloudFormation uses context key-value pairs in cases where a resource's logical and physical IDs are not enough to uniquely identify that resource. Each context key-value pair specifies a resource that contains the targeted resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PhysicalResourceIdContextKeyValuePair {
     /// <p>The resource context key.</p>
     pub key: String,
@@ -3930,6 +5000,8 @@ impl PropertiesDeserializer {
 }
 /// <p>Information about a resource property whose actual value differs from its expected value, as defined in the stack template and any values specified as template parameters. These will be present only for resources whose <code>StackResourceDriftStatus</code> is <code>MODIFIED</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PropertyDifference {
     /// <p>The actual property value of the resource property.</p>
     pub actual_value: String,
@@ -4110,6 +5182,8 @@ impl ResourceAttributeDeserializer {
 }
 /// <p>The <code>ResourceChange</code> structure describes the resource and the action that AWS CloudFormation will perform on it if you execute this change set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceChange {
     /// <p>The action that AWS CloudFormation takes on the resource, such as <code>Add</code> (adds a new resource), <code>Modify</code> (changes a resource), or <code>Remove</code> (deletes a resource).</p>
     pub action: Option<String>,
@@ -4179,6 +5253,8 @@ impl ResourceChangeDeserializer {
 }
 /// <p>For a resource with <code>Modify</code> as the action, the <code>ResourceChange</code> structure describes the changes AWS CloudFormation will make to that resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceChangeDetail {
     /// <p>The identity of the entity that triggered this change. This entity is a member of the group that is specified by the <code>ChangeSource</code> field. For example, if you modified the value of the <code>KeyPairName</code> parameter, the <code>CausingEntity</code> is the name of the parameter (<code>KeyPairName</code>).</p> <p>If the <code>ChangeSource</code> value is <code>DirectModification</code>, no value is given for <code>CausingEntity</code>.</p>
     pub causing_entity: Option<String>,
@@ -4282,6 +5358,8 @@ impl ResourceStatusReasonDeserializer {
 }
 /// <p>The field that AWS CloudFormation will change, such as the name of a resource's property, and whether the resource will be recreated.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceTargetDefinition {
     /// <p>Indicates which resource attribute is triggering this update, such as a change in the resource attribute's <code>Metadata</code>, <code>Properties</code>, or <code>Tags</code>.</p>
     pub attribute: Option<String>,
@@ -4393,7 +5471,7 @@ impl RetainStacksNullableDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4412,6 +5490,8 @@ impl RoleARNDeserializer {
 }
 /// <p>Structure containing the rollback triggers for AWS CloudFormation to monitor during stack creation and updating operations, and for the specified monitoring period afterwards.</p> <p>Rollback triggers enable you to have AWS CloudFormation monitor the state of your application during stack creation and updating, and to roll back that operation if the application breaches the threshold of any of the alarms you've specified. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-rollback-triggers.html">Monitor and Roll Back Stack Operations</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RollbackConfiguration {
     /// <p>The amount of time, in minutes, during which CloudFormation should monitor all the rollback triggers after the stack creation or update operation deploys all necessary resources.</p> <p>The default is 0 minutes.</p> <p>If you specify a monitoring period but do not specify any rollback triggers, CloudFormation still waits the specified period of time before cleaning up old resources after update operations. You can use this monitoring period to perform any manual stack validation desired, and manually cancel the stack creation or update (using <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_CancelUpdateStack.html">CancelUpdateStack</a>, for example) as necessary.</p> <p>If you specify 0 for this parameter, CloudFormation still monitors the specified rollback triggers during stack creation and update operations. Then, for update operations, it begins disposing of old resources immediately once the operation completes.</p>
     pub monitoring_time_in_minutes: Option<i64>,
@@ -4419,6 +5499,27 @@ pub struct RollbackConfiguration {
     pub rollback_triggers: Option<Vec<RollbackTrigger>>,
 }
 
+impl Validate for RollbackConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.monitoring_time_in_minutes {
+            errors.check_range(
+                &field_path(path, "monitoring_time_in_minutes"),
+                *value,
+                None,
+                Some(180),
+            );
+        }
+        if let Some(ref value) = self.rollback_triggers {
+            errors.check_length(
+                &field_path(path, "rollback_triggers"),
+                value.len(),
+                None,
+                Some(5),
+            );
+        }
+    }
+}
+
 struct RollbackConfigurationDeserializer;
 impl RollbackConfigurationDeserializer {
     #[allow(unused_variables)]
@@ -4474,6 +5575,8 @@ impl RollbackConfigurationSerializer {
 
 /// <p>A rollback trigger AWS CloudFormation monitors during creation and updating of stacks. If any of the alarms you specify goes to ALARM state during the stack operation or within the specified monitoring period afterwards, CloudFormation rolls back the entire stack operation. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RollbackTrigger {
     /// <p>The Amazon Resource Name (ARN) of the rollback trigger.</p> <p>If a specified trigger is missing, the entire stack operation fails and is rolled back. </p>
     pub arn: String,
@@ -4565,6 +5668,8 @@ impl ScopeDeserializer {
 }
 /// <p>The input for the <a>SetStackPolicy</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetStackPolicyInput {
     /// <p>The name or unique stack ID that you want to associate a policy with.</p>
     pub stack_name: String,
@@ -4574,6 +5679,27 @@ pub struct SetStackPolicyInput {
     pub stack_policy_url: Option<String>,
 }
 
+impl Validate for SetStackPolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.stack_policy_body {
+            errors.check_length(
+                &field_path(path, "stack_policy_body"),
+                value.chars().count(),
+                Some(1),
+                Some(16384),
+            );
+        }
+        if let Some(ref value) = self.stack_policy_url {
+            errors.check_length(
+                &field_path(path, "stack_policy_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1350),
+            );
+        }
+    }
+}
+
 /// Serialize `SetStackPolicyInput` contents to a `SignedRequest`.
 struct SetStackPolicyInputSerializer;
 impl SetStackPolicyInputSerializer {
@@ -4595,6 +5721,8 @@ impl SetStackPolicyInputSerializer {
 
 /// <p>The input for the <a>SignalResource</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SignalResourceInput {
     /// <p>The logical ID of the resource that you want to signal. The logical ID is the name of the resource that given in the template.</p>
     pub logical_resource_id: String,
@@ -4606,6 +5734,28 @@ pub struct SignalResourceInput {
     pub unique_id: String,
 }
 
+impl Validate for SignalResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "stack_name"),
+            &self.stack_name,
+            "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+        );
+        errors.check_length(
+            &field_path(path, "unique_id"),
+            self.unique_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// Serialize `SignalResourceInput` contents to a `SignedRequest`.
 struct SignalResourceInputSerializer;
 impl SignalResourceInputSerializer {
@@ -4627,6 +5777,8 @@ impl SignalResourceInputSerializer {
 
 /// <p>The Stack data type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Stack {
     /// <p>The capabilities allowed in the stack.</p>
     pub capabilities: Option<Vec<String>>,
@@ -4826,6 +5978,8 @@ impl StackDriftDetectionStatusReasonDeserializer {
 }
 /// <p>Contains information about whether the stack's actual configuration differs, or has <i>drifted</i>, from its expected configuration, as defined in the stack template and any values specified as template parameters. A stack is considered to have drifted if one or more of its resources have drifted.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackDriftInformation {
     /// <p>Most recent time when a drift detection operation was initiated on the stack, or any of its individual resources that support drift detection.</p>
     pub last_check_timestamp: Option<String>,
@@ -4860,6 +6014,8 @@ impl StackDriftInformationDeserializer {
 }
 /// <p>Contains information about whether the stack's actual configuration differs, or has <i>drifted</i>, from its expected configuration, as defined in the stack template and any values specified as template parameters. A stack is considered to have drifted if one or more of its resources have drifted.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackDriftInformationSummary {
     /// <p>Most recent time when a drift detection operation was initiated on the stack, or any of its individual resources that support drift detection.</p>
     pub last_check_timestamp: Option<String>,
@@ -4909,6 +6065,8 @@ impl StackDriftStatusDeserializer {
 }
 /// <p>The StackEvent data type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackEvent {
     /// <p>The token passed to the operation that generated this event.</p> <p>All events triggered by a given stack operation are assigned the same client request token, which you can use to track operations. For example, if you execute a <code>CreateStack</code> operation with the token <code>token1</code>, then all the <code>StackEvents</code> generated by that operation will have <code>ClientRequestToken</code> set as <code>token1</code>.</p> <p>In the console, stack operations display the client request token on the Events tab. Stack operations that are initiated from the console use the token format <i>Console-StackOperation-ID</i>, which helps you easily identify the stack operation . For example, if you create a stack using the console, each stack event would be assigned the same token in the following format: <code>Console-CreateStack-7f59c3cf-00d2-40c7-b2ff-e75db0987002</code>. </p>
     pub client_request_token: Option<String>,
@@ -5034,6 +6192,8 @@ impl StackIdDeserializer {
 }
 /// <p>An AWS CloudFormation stack, in a specific account and region, that's part of a stack set operation. A stack instance is a reference to an attempted or actual stack in a given account within a given region. A stack instance can exist without a stack—for example, if the stack couldn't be created for some reason. A stack instance is associated with only one stack set. Each stack instance contains the ID of its associated stack set, as well as the ID of the actual stack and the stack status.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackInstance {
     /// <p>The name of the AWS account that the stack instance is associated with.</p>
     pub account: Option<String>,
@@ -5125,6 +6285,8 @@ impl StackInstanceSummariesDeserializer {
 }
 /// <p>The structure that contains summary information about a stack instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackInstanceSummary {
     /// <p>The name of the AWS account that the stack instance is associated with.</p>
     pub account: Option<String>,
@@ -5201,6 +6363,8 @@ impl StackPolicyBodyDeserializer {
 }
 /// <p>The StackResource data type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackResource {
     /// <p>User defined description associated with the resource.</p>
     pub description: Option<String>,
@@ -5286,6 +6450,8 @@ impl StackResourceDeserializer {
 }
 /// <p>Contains detailed information about the specified stack resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackResourceDetail {
     /// <p>User defined description associated with the resource.</p>
     pub description: Option<String>,
@@ -5377,6 +6543,8 @@ impl StackResourceDetailDeserializer {
 }
 /// <p>Contains the drift information for a resource that has been checked for drift. This includes actual and expected property values for resources in which AWS CloudFormation has detected drift. Only resource properties explicitly defined in the stack template are checked for drift. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Resources that do not currently support drift detection cannot be checked. For a list of resources that support drift detection, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p>Use <a>DetectStackResourceDrift</a> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all resources in a given stack that support drift detection.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackResourceDrift {
     /// <p>A JSON structure containing the actual property values of the stack resource.</p> <p>For resources whose <code>StackResourceDriftStatus</code> is <code>DELETED</code>, this structure will not be present. </p>
     pub actual_properties: Option<String>,
@@ -5469,6 +6637,8 @@ impl StackResourceDriftDeserializer {
 }
 /// <p>Contains information about whether the resource's actual configuration differs, or has <i>drifted</i>, from its expected configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackResourceDriftInformation {
     /// <p>When AWS CloudFormation last checked if the resource had drifted from its expected configuration.</p>
     pub last_check_timestamp: Option<String>,
@@ -5510,6 +6680,8 @@ impl StackResourceDriftInformationDeserializer {
 }
 /// <p>Summarizes information about whether the resource's actual configuration differs, or has <i>drifted</i>, from its expected configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackResourceDriftInformationSummary {
     /// <p>When AWS CloudFormation last checked if the resource had drifted from its expected configuration.</p>
     pub last_check_timestamp: Option<String>,
@@ -5612,6 +6784,8 @@ impl StackResourceSummariesDeserializer {
 }
 /// <p>Contains high-level information about the specified stack resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackResourceSummary {
     /// <p>Information about whether the resource's actual configuration differs, or has <i>drifted</i>, from its expected configuration, as defined in the stack template and any values specified as template parameters. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p>
     pub drift_information: Option<StackResourceDriftInformationSummary>,
@@ -5700,6 +6874,8 @@ impl StackResourcesDeserializer {
 }
 /// <p>A structure that contains information about a stack set. A stack set enables you to provision stacks into AWS accounts and across regions by using a single CloudFormation template. In the stack set, you specify the template to use, as well as any parameters and capabilities that the template requires. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackSet {
     /// <p>The Amazon Resource Number (ARN) of the IAM role used to create or update the stack set.</p> <p>Use customized administrator roles to control which users or groups can manage specific stack sets within the same administrator account. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-prereqs.html">Prerequisites: Granting Permissions for Stack Set Operations</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub administration_role_arn: Option<String>,
@@ -5829,6 +7005,8 @@ impl StackSetNameDeserializer {
 }
 /// <p>The structure that contains information about a stack set operation. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackSetOperation {
     /// <p>The type of stack set operation: <code>CREATE</code>, <code>UPDATE</code>, or <code>DELETE</code>. Create and delete operations affect only the specified stack set instances that are associated with the specified stack set. Update operations affect both the stack set itself, as well as <i>all</i> associated stack set instances.</p>
     pub action: Option<String>,
@@ -5935,6 +7113,8 @@ impl StackSetOperationActionDeserializer {
 }
 /// <p>The user-specified preferences for how AWS CloudFormation performs a stack set operation. </p> <p>For more information on maximum concurrent accounts and failure tolerance, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-concepts.html#stackset-ops-options">Stack set operation options</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackSetOperationPreferences {
     /// <p>The number of accounts, per region, for which this operation can fail before AWS CloudFormation stops the operation in that region. If the operation is stopped in a region, AWS CloudFormation doesn't attempt the operation in any subsequent regions.</p> <p>Conditional: You must specify either <code>FailureToleranceCount</code> or <code>FailureTolerancePercentage</code> (but not both).</p>
     pub failure_tolerance_count: Option<i64>,
@@ -5948,6 +7128,41 @@ pub struct StackSetOperationPreferences {
     pub region_order: Option<Vec<String>>,
 }
 
+impl Validate for StackSetOperationPreferences {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.failure_tolerance_percentage {
+            errors.check_range(
+                &field_path(path, "failure_tolerance_percentage"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.max_concurrent_count {
+            errors.check_range(
+                &field_path(path, "max_concurrent_count"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.max_concurrent_percentage {
+            errors.check_range(
+                &field_path(path, "max_concurrent_percentage"),
+                *value,
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.region_order {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "region_order"), i);
+                errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9-]{1,128}$");
+            }
+        }
+    }
+}
+
 struct StackSetOperationPreferencesDeserializer;
 impl StackSetOperationPreferencesDeserializer {
     #[allow(unused_variables)]
@@ -6073,6 +7288,8 @@ impl StackSetOperationResultSummariesDeserializer {
 }
 /// <p>The structure that contains information about a specified operation's results for a given account in a given region.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackSetOperationResultSummary {
     /// <p>The name of the AWS account for this operation result.</p>
     pub account: Option<String>,
@@ -6158,6 +7375,8 @@ impl StackSetOperationSummariesDeserializer {
 }
 /// <p>The structures that contain summary information about the specified operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackSetOperationSummary {
     /// <p>The type of operation: <code>CREATE</code>, <code>UPDATE</code>, or <code>DELETE</code>. Create and delete operations affect only the specified stack instances that are associated with the specified stack set. Update operations affect both the stack set itself as well as <i>all</i> associated stack set instances.</p>
     pub action: Option<String>,
@@ -6246,6 +7465,8 @@ impl StackSetSummariesDeserializer {
 }
 /// <p>The structures that contain summary information about the specified stack set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackSetSummary {
     /// <p>A description of the stack set that you specify when the stack set is created or updated.</p>
     pub description: Option<String>,
@@ -6342,6 +7563,8 @@ impl StackSummariesDeserializer {
 }
 /// <p>The StackSummary Data Type</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StackSummary {
     /// <p>The time the stack was created.</p>
     pub creation_time: String,
@@ -6467,6 +7690,8 @@ impl StageListDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopStackSetOperationInput {
     /// <p>The ID of the stack operation. </p>
     pub operation_id: String,
@@ -6474,6 +7699,22 @@ pub struct StopStackSetOperationInput {
     pub stack_set_name: String,
 }
 
+impl Validate for StopStackSetOperationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "operation_id"),
+            self.operation_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "operation_id"),
+            &self.operation_id,
+            "[a-zA-Z0-9][-a-zA-Z0-9]*",
+        );
+    }
+}
+
 /// Serialize `StopStackSetOperationInput` contents to a `SignedRequest`.
 struct StopStackSetOperationInputSerializer;
 impl StopStackSetOperationInputSerializer {
@@ -6492,6 +7733,8 @@ impl StopStackSetOperationInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopStackSetOperationOutput {}
 
 struct StopStackSetOperationOutputDeserializer;
@@ -6512,6 +7755,8 @@ impl StopStackSetOperationOutputDeserializer {
 }
 /// <p>The Tag type enables you to specify a key-value pair that can be used to store information about an AWS CloudFormation stack.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p> <i>Required</i>. A string used to identify this tag. You can specify a maximum of 128 characters for a tag key. Tags owned by Amazon Web Services (AWS) have the reserved prefix: <code>aws:</code>.</p>
     pub key: String,
@@ -6519,6 +7764,23 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
@@ -6627,6 +7889,8 @@ impl TemplateDescriptionDeserializer {
 }
 /// <p>The TemplateParameter data type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TemplateParameter {
     /// <p>The default value associated with the parameter.</p>
     pub default_value: Option<String>,
@@ -6705,7 +7969,7 @@ impl TimeoutMinutesDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6763,6 +8027,8 @@ impl TypeDeserializer {
 }
 /// <p>The input for an <a>UpdateStack</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStackInput {
     /// <p><p>In some cases, you must explicity acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to update the stack.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some template contain macros. Macros perform custom processing on templates; this can include simple actions like find-and-replace operations, all the way to extensive transformations of entire templates. Because of this, users typically create a change set from the processed template, so that they can review the changes resulting from the macros before actually updating the stack. If your stack template contains one or more macros, and you choose to update a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.</p> <p>Change sets do not currently support nested stacks. If you want to update a stack from a stack template that contains macros <i>and</i> nested stacks, you must update the stack directly from the template using this capability.</p> <important> <p>You should only update stacks directly from a stack template that contains macros if you know what processing the macro performs.</p> <p>Each macro relies on an underlying Lambda service function for processing stack templates. Be aware that the Lambda function owner can update the function operation without AWS CloudFormation being notified.</p> </important> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> </li> </ul></p>
     pub capabilities: Option<Vec<String>>,
@@ -6798,6 +8064,104 @@ pub struct UpdateStackInput {
     pub use_previous_template: Option<bool>,
 }
 
+impl Validate for UpdateStackInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.notification_ar_ns {
+            errors.check_length(
+                &field_path(path, "notification_ar_ns"),
+                value.len(),
+                None,
+                Some(5),
+            );
+        }
+        if let Some(ref value) = self.resource_types {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_types"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.rollback_configuration {
+            value.validate_at(&field_path(path, "rollback_configuration"), errors);
+        }
+        if let Some(ref value) = self.stack_policy_body {
+            errors.check_length(
+                &field_path(path, "stack_policy_body"),
+                value.chars().count(),
+                Some(1),
+                Some(16384),
+            );
+        }
+        if let Some(ref value) = self.stack_policy_during_update_body {
+            errors.check_length(
+                &field_path(path, "stack_policy_during_update_body"),
+                value.chars().count(),
+                Some(1),
+                Some(16384),
+            );
+        }
+        if let Some(ref value) = self.stack_policy_during_update_url {
+            errors.check_length(
+                &field_path(path, "stack_policy_during_update_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1350),
+            );
+        }
+        if let Some(ref value) = self.stack_policy_url {
+            errors.check_length(
+                &field_path(path, "stack_policy_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1350),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.template_body {
+            errors.check_length(
+                &field_path(path, "template_body"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.template_url {
+            errors.check_length(
+                &field_path(path, "template_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `UpdateStackInput` contents to a `SignedRequest`.
 struct UpdateStackInputSerializer;
 impl UpdateStackInputSerializer {
@@ -6886,6 +8250,8 @@ impl UpdateStackInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStackInstancesInput {
     /// <p>The names of one or more AWS accounts for which you want to update parameter values for stack instances. The overridden parameter values will be applied to all stack instances in the specified accounts and regions.</p>
     pub accounts: Vec<String>,
@@ -6901,6 +8267,36 @@ pub struct UpdateStackInstancesInput {
     pub stack_set_name: String,
 }
 
+impl Validate for UpdateStackInstancesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.accounts.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "accounts"), i);
+            errors.check_pattern(&item_path, &item, "^[0-9]{12}$");
+        }
+        if let Some(ref value) = self.operation_id {
+            errors.check_length(
+                &field_path(path, "operation_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "operation_id"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.operation_preferences {
+            value.validate_at(&field_path(path, "operation_preferences"), errors);
+        }
+        for (i, item) in self.regions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "regions"), i);
+            errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9-]{1,128}$");
+        }
+        errors.check_pattern(&field_path(path, "stack_set_name"), &self.stack_set_name, "[a-zA-Z][-a-zA-Z0-9]*(?::[a-zA-Z0-9]{8}-[a-zA-Z0-9]{4}-[a-zA-Z0-9]{4}-[a-zA-Z0-9]{4}-[a-zA-Z0-9]{12})?");
+    }
+}
+
 /// Serialize `UpdateStackInstancesInput` contents to a `SignedRequest`.
 struct UpdateStackInstancesInputSerializer;
 impl UpdateStackInstancesInputSerializer {
@@ -6941,6 +8337,8 @@ impl UpdateStackInstancesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStackInstancesOutput {
     /// <p>The unique identifier for this stack set operation. </p>
     pub operation_id: Option<String>,
@@ -6973,6 +8371,8 @@ impl UpdateStackInstancesOutputDeserializer {
 }
 /// <p>The output for an <a>UpdateStack</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStackOutput {
     /// <p>Unique identifier of the stack.</p>
     pub stack_id: Option<String>,
@@ -6997,6 +8397,8 @@ impl UpdateStackOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStackSetInput {
     /// <p>The accounts in which to update associated stack instances. If you specify accounts, you must also specify the regions in which to update stack set instances.</p> <p>To update <i>all</i> the stack instances associated with this stack set, do not specify the <code>Accounts</code> or <code>Regions</code> properties.</p> <p>If the stack set update includes changes to the template (that is, if the <code>TemplateBody</code> or <code>TemplateURL</code> properties are specified), or the <code>Parameters</code> property, AWS CloudFormation marks all stack instances with a status of <code>OUTDATED</code> prior to updating the stack instances in the specified accounts and regions. If the stack set update does not include changes to the template or parameters, AWS CloudFormation updates the stack instances in the specified accounts and regions, while leaving all other stack instances with their existing stack instance status. </p>
     pub accounts: Option<Vec<String>>,
@@ -7028,6 +8430,91 @@ pub struct UpdateStackSetInput {
     pub use_previous_template: Option<bool>,
 }
 
+impl Validate for UpdateStackSetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accounts {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "accounts"), i);
+                errors.check_pattern(&item_path, &item, "^[0-9]{12}$");
+            }
+        }
+        if let Some(ref value) = self.administration_role_arn {
+            errors.check_length(
+                &field_path(path, "administration_role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.execution_role_name {
+            errors.check_length(
+                &field_path(path, "execution_role_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "execution_role_name"),
+                &value,
+                "[a-zA-Z_0-9+=,.@-]+",
+            );
+        }
+        if let Some(ref value) = self.operation_id {
+            errors.check_length(
+                &field_path(path, "operation_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "operation_id"),
+                &value,
+                "[a-zA-Z0-9][-a-zA-Z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.operation_preferences {
+            value.validate_at(&field_path(path, "operation_preferences"), errors);
+        }
+        if let Some(ref value) = self.regions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "regions"), i);
+                errors.check_pattern(&item_path, &item, "^[a-zA-Z0-9-]{1,128}$");
+            }
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.template_body {
+            errors.check_length(
+                &field_path(path, "template_body"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.template_url {
+            errors.check_length(
+                &field_path(path, "template_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `UpdateStackSetInput` contents to a `SignedRequest`.
 struct UpdateStackSetInputSerializer;
 impl UpdateStackSetInputSerializer {
@@ -7110,6 +8597,8 @@ impl UpdateStackSetInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStackSetOutput {
     /// <p>The unique ID for this stack set operation.</p>
     pub operation_id: Option<String>,
@@ -7137,6 +8626,8 @@ impl UpdateStackSetOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTerminationProtectionInput {
     /// <p>Whether to enable termination protection on the specified stack.</p>
     pub enable_termination_protection: bool,
@@ -7144,6 +8635,22 @@ pub struct UpdateTerminationProtectionInput {
     pub stack_name: String,
 }
 
+impl Validate for UpdateTerminationProtectionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stack_name"),
+            self.stack_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "stack_name"),
+            &self.stack_name,
+            "([a-zA-Z][-a-zA-Z0-9]*)|(arn:\\b(aws|aws-us-gov|aws-cn)\\b:[-a-zA-Z0-9:/._+]*)",
+        );
+    }
+}
+
 /// Serialize `UpdateTerminationProtectionInput` contents to a `SignedRequest`.
 struct UpdateTerminationProtectionInputSerializer;
 impl UpdateTerminationProtectionInputSerializer {
@@ -7162,6 +8669,8 @@ impl UpdateTerminationProtectionInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTerminationProtectionOutput {
     /// <p>The unique ID of the stack.</p>
     pub stack_id: Option<String>,
@@ -7205,7 +8714,7 @@ impl UsePreviousValueDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7213,6 +8722,8 @@ impl UsePreviousValueDeserializer {
 }
 /// <p>The input for <a>ValidateTemplate</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ValidateTemplateInput {
     /// <p>Structure containing the template body with a minimum length of 1 byte and a maximum length of 51,200 bytes. For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-anatomy.html">Template Anatomy</a> in the AWS CloudFormation User Guide.</p> <p>Conditional: You must pass <code>TemplateURL</code> or <code>TemplateBody</code>. If both are passed, only <code>TemplateBody</code> is used.</p>
     pub template_body: Option<String>,
@@ -7220,6 +8731,27 @@ pub struct ValidateTemplateInput {
     pub template_url: Option<String>,
 }
 
+impl Validate for ValidateTemplateInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.template_body {
+            errors.check_length(
+                &field_path(path, "template_body"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.template_url {
+            errors.check_length(
+                &field_path(path, "template_url"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `ValidateTemplateInput` contents to a `SignedRequest`.
 struct ValidateTemplateInputSerializer;
 impl ValidateTemplateInputSerializer {
@@ -7240,6 +8772,8 @@ impl ValidateTemplateInputSerializer {
 
 /// <p>The output for <a>ValidateTemplate</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ValidateTemplateOutput {
     /// <p>The capabilities found within the template. If your template contains IAM resources, you must specify the CAPABILITY_IAM or CAPABILITY_NAMED_IAM value for this parameter when you use the <a>CreateStack</a> or <a>UpdateStack</a> actions with your template; otherwise, those actions return an InsufficientCapabilities error.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p>
     pub capabilities: Option<Vec<String>>,
@@ -9790,2138 +11324,3462 @@ pub trait CloudFormation {
         input: ValidateTemplateInput,
     ) -> RusotoFuture<ValidateTemplateOutput, ValidateTemplateError>;
 }
-/// A client for the AWS CloudFormation API.
-#[derive(Clone)]
-pub struct CloudFormationClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CloudFormationClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CloudFormationClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CloudFormationClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CloudFormationClient {
-        CloudFormationClient { client, region }
-    }
-}
-
-impl CloudFormation for CloudFormationClient {
-    /// <p><p>Cancels an update on the specified stack. If the call completes successfully, the stack rolls back the update and reverts to the previous stack configuration.</p> <note> <p>You can cancel only stacks that are in the UPDATE<em>IN</em>PROGRESS state.</p> </note></p>
+const _: Option<&dyn CloudFormation> = None;
+impl<'a, T: CloudFormation + ?Sized> CloudFormation for &'a T {
     fn cancel_update_stack(
         &self,
         input: CancelUpdateStackInput,
     ) -> RusotoFuture<(), CancelUpdateStackError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CancelUpdateStack");
-        params.put("Version", "2010-05-15");
-        CancelUpdateStackInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelUpdateStackError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).cancel_update_stack(input)
     }
-
-    /// <p>For a specified stack that is in the <code>UPDATE_ROLLBACK_FAILED</code> state, continues rolling it back to the <code>UPDATE_ROLLBACK_COMPLETE</code> state. Depending on the cause of the failure, you can manually <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/troubleshooting.html#troubleshooting-errors-update-rollback-failed"> fix the error</a> and continue the rollback. By continuing the rollback, you can return your stack to a working state (the <code>UPDATE_ROLLBACK_COMPLETE</code> state), and then try to update the stack again.</p> <p>A stack goes into the <code>UPDATE_ROLLBACK_FAILED</code> state when AWS CloudFormation cannot roll back all changes after a failed stack update. For example, you might have a stack that is rolling back to an old database instance that was deleted outside of AWS CloudFormation. Because AWS CloudFormation doesn't know the database was deleted, it assumes that the database instance still exists and attempts to roll back to it, causing the update rollback to fail.</p>
     fn continue_update_rollback(
         &self,
         input: ContinueUpdateRollbackInput,
     ) -> RusotoFuture<ContinueUpdateRollbackOutput, ContinueUpdateRollbackError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ContinueUpdateRollback");
-        params.put("Version", "2010-05-15");
-        ContinueUpdateRollbackInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ContinueUpdateRollbackError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ContinueUpdateRollbackOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ContinueUpdateRollbackOutputDeserializer::deserialize(
-                        "ContinueUpdateRollbackResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).continue_update_rollback(input)
     }
-
-    /// <p>Creates a list of changes that will be applied to a stack so that you can review the changes before executing them. You can create a change set for a stack that doesn't exist or an existing stack. If you create a change set for a stack that doesn't exist, the change set shows all of the resources that AWS CloudFormation will create. If you create a change set for an existing stack, AWS CloudFormation compares the stack's information with the information that you submit in the change set and lists the differences. Use change sets to understand which resources AWS CloudFormation will create or change, and how it will change resources in an existing stack, before you create or update a stack.</p> <p>To create a change set for a stack that doesn't exist, for the <code>ChangeSetType</code> parameter, specify <code>CREATE</code>. To create a change set for an existing stack, specify <code>UPDATE</code> for the <code>ChangeSetType</code> parameter. After the <code>CreateChangeSet</code> call successfully completes, AWS CloudFormation starts creating the change set. To check the status of the change set or to review it, use the <a>DescribeChangeSet</a> action.</p> <p>When you are satisfied with the changes the change set will make, execute the change set by using the <a>ExecuteChangeSet</a> action. AWS CloudFormation doesn't make changes until you execute the change set.</p>
     fn create_change_set(
         &self,
         input: CreateChangeSetInput,
     ) -> RusotoFuture<CreateChangeSetOutput, CreateChangeSetError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateChangeSet");
-        params.put("Version", "2010-05-15");
-        CreateChangeSetInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateChangeSetError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateChangeSetOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateChangeSetOutputDeserializer::deserialize(
-                        "CreateChangeSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_change_set(input)
     }
-
-    /// <p>Creates a stack as specified in the template. After the call completes successfully, the stack creation starts. You can check the status of the stack via the <a>DescribeStacks</a> API.</p>
     fn create_stack(
         &self,
         input: CreateStackInput,
     ) -> RusotoFuture<CreateStackOutput, CreateStackError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateStack");
-        params.put("Version", "2010-05-15");
-        CreateStackInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateStackError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateStackOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateStackOutputDeserializer::deserialize(
-                        "CreateStackResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_stack(input)
     }
-
-    /// <p>Creates stack instances for the specified accounts, within the specified regions. A stack instance refers to a stack in a specific account and region. <code>Accounts</code> and <code>Regions</code> are required parameters—you must specify at least one account and one region. </p>
     fn create_stack_instances(
         &self,
         input: CreateStackInstancesInput,
     ) -> RusotoFuture<CreateStackInstancesOutput, CreateStackInstancesError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateStackInstances");
-        params.put("Version", "2010-05-15");
-        CreateStackInstancesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateStackInstancesError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateStackInstancesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateStackInstancesOutputDeserializer::deserialize(
-                        "CreateStackInstancesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_stack_instances(input)
     }
-
-    /// <p>Creates a stack set.</p>
     fn create_stack_set(
         &self,
         input: CreateStackSetInput,
     ) -> RusotoFuture<CreateStackSetOutput, CreateStackSetError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateStackSet");
-        params.put("Version", "2010-05-15");
-        CreateStackSetInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateStackSetError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateStackSetOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateStackSetOutputDeserializer::deserialize(
-                        "CreateStackSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_stack_set(input)
     }
-
-    /// <p>Deletes the specified change set. Deleting change sets ensures that no one executes the wrong change set.</p> <p>If the call successfully completes, AWS CloudFormation successfully deleted the change set.</p>
     fn delete_change_set(
         &self,
         input: DeleteChangeSetInput,
     ) -> RusotoFuture<DeleteChangeSetOutput, DeleteChangeSetError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteChangeSet");
-        params.put("Version", "2010-05-15");
-        DeleteChangeSetInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteChangeSetError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteChangeSetOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteChangeSetOutputDeserializer::deserialize(
-                        "DeleteChangeSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_change_set(input)
     }
-
-    /// <p>Deletes a specified stack. Once the call completes successfully, stack deletion starts. Deleted stacks do not show up in the <a>DescribeStacks</a> API if the deletion has been completed successfully.</p>
     fn delete_stack(&self, input: DeleteStackInput) -> RusotoFuture<(), DeleteStackError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteStack");
-        params.put("Version", "2010-05-15");
-        DeleteStackInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteStackError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_stack(input)
     }
-
-    /// <p>Deletes stack instances for the specified accounts, in the specified regions. </p>
     fn delete_stack_instances(
         &self,
         input: DeleteStackInstancesInput,
     ) -> RusotoFuture<DeleteStackInstancesOutput, DeleteStackInstancesError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteStackInstances");
-        params.put("Version", "2010-05-15");
-        DeleteStackInstancesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteStackInstancesError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteStackInstancesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteStackInstancesOutputDeserializer::deserialize(
-                        "DeleteStackInstancesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_stack_instances(input)
     }
-
-    /// <p>Deletes a stack set. Before you can delete a stack set, all of its member stack instances must be deleted. For more information about how to do this, see <a>DeleteStackInstances</a>. </p>
     fn delete_stack_set(
         &self,
         input: DeleteStackSetInput,
     ) -> RusotoFuture<DeleteStackSetOutput, DeleteStackSetError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteStackSet");
-        params.put("Version", "2010-05-15");
-        DeleteStackSetInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteStackSetError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteStackSetOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteStackSetOutputDeserializer::deserialize(
-                        "DeleteStackSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_stack_set(input)
     }
-
-    /// <p>Retrieves your account's AWS CloudFormation limits, such as the maximum number of stacks that you can create in your account. For more information about account limits, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">AWS CloudFormation Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     fn describe_account_limits(
         &self,
         input: DescribeAccountLimitsInput,
     ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeAccountLimits");
-        params.put("Version", "2010-05-15");
-        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAccountLimitsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeAccountLimitsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeAccountLimitsOutputDeserializer::deserialize(
-                        "DescribeAccountLimitsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_account_limits(input)
     }
-
-    /// <p>Returns the inputs for the change set and a list of changes that AWS CloudFormation will make if you execute the change set. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks-changesets.html">Updating Stacks Using Change Sets</a> in the AWS CloudFormation User Guide.</p>
     fn describe_change_set(
         &self,
         input: DescribeChangeSetInput,
     ) -> RusotoFuture<DescribeChangeSetOutput, DescribeChangeSetError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeChangeSet");
-        params.put("Version", "2010-05-15");
-        DescribeChangeSetInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeChangeSetError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeChangeSetOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeChangeSetOutputDeserializer::deserialize(
-                        "DescribeChangeSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_change_set(input)
     }
-
-    /// <p>Returns information about a stack drift detection operation. A stack drift detection operation detects whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. A stack is considered to have drifted if one or more of its resources have drifted. For more information on stack and resource drift, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <a>DetectStackDrift</a> to initiate a stack drift detection operation. <code>DetectStackDrift</code> returns a <code>StackDriftDetectionId</code> you can use to monitor the progress of the operation using <code>DescribeStackDriftDetectionStatus</code>. Once the drift detection operation has completed, use <a>DescribeStackResourceDrifts</a> to return drift information about the stack and its resources.</p>
     fn describe_stack_drift_detection_status(
         &self,
         input: DescribeStackDriftDetectionStatusInput,
     ) -> RusotoFuture<DescribeStackDriftDetectionStatusOutput, DescribeStackDriftDetectionStatusError>
     {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeStackDriftDetectionStatus");
-        params.put("Version", "2010-05-15");
-        DescribeStackDriftDetectionStatusInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeStackDriftDetectionStatusError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeStackDriftDetectionStatusOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeStackDriftDetectionStatusOutputDeserializer::deserialize(
-                        "DescribeStackDriftDetectionStatusResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_stack_drift_detection_status(input)
     }
-
-    /// <p><p>Returns all stack related events for a specified stack in reverse chronological order. For more information about a stack&#39;s event history, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/concept-stack.html">Stacks</a> in the AWS CloudFormation User Guide.</p> <note> <p>You can list events for stacks that have failed to create or have been deleted by specifying the unique stack identifier (stack ID).</p> </note></p>
     fn describe_stack_events(
         &self,
         input: DescribeStackEventsInput,
     ) -> RusotoFuture<DescribeStackEventsOutput, DescribeStackEventsError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeStackEvents");
-        params.put("Version", "2010-05-15");
-        DescribeStackEventsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeStackEventsError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeStackEventsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeStackEventsOutputDeserializer::deserialize(
-                        "DescribeStackEventsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_stack_events(input)
     }
-
-    /// <p>Returns the stack instance that's associated with the specified stack set, AWS account, and region.</p> <p>For a list of stack instances that are associated with a specific stack set, use <a>ListStackInstances</a>.</p>
     fn describe_stack_instance(
         &self,
         input: DescribeStackInstanceInput,
     ) -> RusotoFuture<DescribeStackInstanceOutput, DescribeStackInstanceError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeStackInstance");
-        params.put("Version", "2010-05-15");
-        DescribeStackInstanceInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeStackInstanceError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeStackInstanceOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeStackInstanceOutputDeserializer::deserialize(
-                        "DescribeStackInstanceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_stack_instance(input)
     }
-
-    /// <p>Returns a description of the specified resource in the specified stack.</p> <p>For deleted stacks, DescribeStackResource returns resource information for up to 90 days after the stack has been deleted.</p>
     fn describe_stack_resource(
         &self,
         input: DescribeStackResourceInput,
     ) -> RusotoFuture<DescribeStackResourceOutput, DescribeStackResourceError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeStackResource");
-        params.put("Version", "2010-05-15");
-        DescribeStackResourceInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeStackResourceError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeStackResourceOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeStackResourceOutputDeserializer::deserialize(
-                        "DescribeStackResourceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_stack_resource(input)
     }
-
-    /// <p>Returns drift information for the resources that have been checked for drift in the specified stack. This includes actual and expected configuration values for resources where AWS CloudFormation detects configuration drift.</p> <p>For a given stack, there will be one <code>StackResourceDrift</code> for each stack resource that has been checked for drift. Resources that have not yet been checked for drift are not included. Resources that do not currently support drift detection are not checked, and so not included. For a list of resources that support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p>Use <a>DetectStackResourceDrift</a> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all supported resources for a given stack.</p>
     fn describe_stack_resource_drifts(
         &self,
         input: DescribeStackResourceDriftsInput,
     ) -> RusotoFuture<DescribeStackResourceDriftsOutput, DescribeStackResourceDriftsError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeStackResourceDrifts");
-        params.put("Version", "2010-05-15");
-        DescribeStackResourceDriftsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeStackResourceDriftsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeStackResourceDriftsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeStackResourceDriftsOutputDeserializer::deserialize(
-                        "DescribeStackResourceDriftsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_stack_resource_drifts(input)
     }
-
-    /// <p><p>Returns AWS resource descriptions for running and deleted stacks. If <code>StackName</code> is specified, all the associated resources that are part of the stack are returned. If <code>PhysicalResourceId</code> is specified, the associated resources of the stack that the resource belongs to are returned.</p> <note> <p>Only the first 100 resources will be returned. If your stack has more resources than this, you should use <code>ListStackResources</code> instead.</p> </note> <p>For deleted stacks, <code>DescribeStackResources</code> returns resource information for up to 90 days after the stack has been deleted.</p> <p>You must specify either <code>StackName</code> or <code>PhysicalResourceId</code>, but not both. In addition, you can specify <code>LogicalResourceId</code> to filter the returned result. For more information about resources, the <code>LogicalResourceId</code> and <code>PhysicalResourceId</code>, go to the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/">AWS CloudFormation User Guide</a>.</p> <note> <p>A <code>ValidationError</code> is returned if you specify both <code>StackName</code> and <code>PhysicalResourceId</code> in the same request.</p> </note></p>
     fn describe_stack_resources(
         &self,
         input: DescribeStackResourcesInput,
     ) -> RusotoFuture<DescribeStackResourcesOutput, DescribeStackResourcesError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeStackResources");
-        params.put("Version", "2010-05-15");
-        DescribeStackResourcesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeStackResourcesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeStackResourcesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeStackResourcesOutputDeserializer::deserialize(
-                        "DescribeStackResourcesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_stack_resources(input)
     }
-
-    /// <p>Returns the description of the specified stack set. </p>
     fn describe_stack_set(
         &self,
         input: DescribeStackSetInput,
     ) -> RusotoFuture<DescribeStackSetOutput, DescribeStackSetError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeStackSet");
-        params.put("Version", "2010-05-15");
-        DescribeStackSetInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeStackSetError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeStackSetOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeStackSetOutputDeserializer::deserialize(
-                        "DescribeStackSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_stack_set(input)
     }
-
-    /// <p>Returns the description of the specified stack set operation. </p>
     fn describe_stack_set_operation(
         &self,
         input: DescribeStackSetOperationInput,
     ) -> RusotoFuture<DescribeStackSetOperationOutput, DescribeStackSetOperationError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeStackSetOperation");
-        params.put("Version", "2010-05-15");
-        DescribeStackSetOperationInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeStackSetOperationError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeStackSetOperationOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeStackSetOperationOutputDeserializer::deserialize(
-                        "DescribeStackSetOperationResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_stack_set_operation(input)
     }
-
-    /// <p><p>Returns the description for the specified stack; if no stack name was specified, then it returns the description for all the stacks created.</p> <note> <p>If the stack does not exist, an <code>AmazonCloudFormationException</code> is returned.</p> </note></p>
     fn describe_stacks(
         &self,
         input: DescribeStacksInput,
     ) -> RusotoFuture<DescribeStacksOutput, DescribeStacksError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeStacks");
-        params.put("Version", "2010-05-15");
-        DescribeStacksInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeStacksError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeStacksOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeStacksOutputDeserializer::deserialize(
-                        "DescribeStacksResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_stacks(input)
     }
-
-    /// <p>Detects whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. For each resource in the stack that supports drift detection, AWS CloudFormation compares the actual configuration of the resource with its expected template configuration. Only resource properties explicitly defined in the stack template are checked for drift. A stack is considered to have drifted if one or more of its resources differ from their expected template configurations. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <code>DetectStackDrift</code> to detect drift on all supported resources for a given stack, or <a>DetectStackResourceDrift</a> to detect drift on individual resources.</p> <p>For a list of stack resources that currently support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p> <code>DetectStackDrift</code> can take up to several minutes, depending on the number of resources contained within the stack. Use <a>DescribeStackDriftDetectionStatus</a> to monitor the progress of a detect stack drift operation. Once the drift detection operation has completed, use <a>DescribeStackResourceDrifts</a> to return drift information about the stack and its resources.</p> <p>When detecting drift on a stack, AWS CloudFormation does not detect drift on any nested stacks belonging to that stack. Perform <code>DetectStackDrift</code> directly on the nested stack itself.</p>
     fn detect_stack_drift(
         &self,
         input: DetectStackDriftInput,
     ) -> RusotoFuture<DetectStackDriftOutput, DetectStackDriftError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DetectStackDrift");
-        params.put("Version", "2010-05-15");
-        DetectStackDriftInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetectStackDriftError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DetectStackDriftOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DetectStackDriftOutputDeserializer::deserialize(
-                        "DetectStackDriftResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).detect_stack_drift(input)
     }
-
-    /// <p>Returns information about whether a resource's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. This information includes actual and expected property values for resources in which AWS CloudFormation detects drift. Only resource properties explicitly defined in the stack template are checked for drift. For more information about stack and resource drift, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <code>DetectStackResourceDrift</code> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all resources in a given stack that support drift detection.</p> <p>Resources that do not currently support drift detection cannot be checked. For a list of resources that support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p>
     fn detect_stack_resource_drift(
         &self,
         input: DetectStackResourceDriftInput,
     ) -> RusotoFuture<DetectStackResourceDriftOutput, DetectStackResourceDriftError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DetectStackResourceDrift");
-        params.put("Version", "2010-05-15");
-        DetectStackResourceDriftInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DetectStackResourceDriftError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DetectStackResourceDriftOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DetectStackResourceDriftOutputDeserializer::deserialize(
-                        "DetectStackResourceDriftResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).detect_stack_resource_drift(input)
     }
-
-    /// <p>Returns the estimated monthly cost of a template. The return value is an AWS Simple Monthly Calculator URL with a query string that describes the resources required to run the template.</p>
     fn estimate_template_cost(
         &self,
         input: EstimateTemplateCostInput,
     ) -> RusotoFuture<EstimateTemplateCostOutput, EstimateTemplateCostError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "EstimateTemplateCost");
-        params.put("Version", "2010-05-15");
-        EstimateTemplateCostInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(EstimateTemplateCostError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EstimateTemplateCostOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EstimateTemplateCostOutputDeserializer::deserialize(
-                        "EstimateTemplateCostResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).estimate_template_cost(input)
     }
-
-    /// <p>Updates a stack using the input information that was provided when the specified change set was created. After the call successfully completes, AWS CloudFormation starts updating the stack. Use the <a>DescribeStacks</a> action to view the status of the update.</p> <p>When you execute a change set, AWS CloudFormation deletes all other change sets associated with the stack because they aren't valid for the updated stack.</p> <p>If a stack policy is associated with the stack, AWS CloudFormation enforces the policy during the update. You can't specify a temporary stack policy that overrides the current policy.</p>
     fn execute_change_set(
         &self,
         input: ExecuteChangeSetInput,
     ) -> RusotoFuture<ExecuteChangeSetOutput, ExecuteChangeSetError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ExecuteChangeSet");
-        params.put("Version", "2010-05-15");
-        ExecuteChangeSetInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ExecuteChangeSetError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ExecuteChangeSetOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ExecuteChangeSetOutputDeserializer::deserialize(
-                        "ExecuteChangeSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).execute_change_set(input)
     }
-
-    /// <p>Returns the stack policy for a specified stack. If a stack doesn't have a policy, a null value is returned.</p>
     fn get_stack_policy(
         &self,
         input: GetStackPolicyInput,
     ) -> RusotoFuture<GetStackPolicyOutput, GetStackPolicyError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetStackPolicy");
-        params.put("Version", "2010-05-15");
-        GetStackPolicyInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetStackPolicyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetStackPolicyOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetStackPolicyOutputDeserializer::deserialize(
-                        "GetStackPolicyResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_stack_policy(input)
     }
-
-    /// <p><p>Returns the template body for a specified stack. You can get the template for running or deleted stacks.</p> <p>For deleted stacks, GetTemplate returns the template for up to 90 days after the stack has been deleted.</p> <note> <p> If the template does not exist, a <code>ValidationError</code> is returned. </p> </note></p>
     fn get_template(
         &self,
         input: GetTemplateInput,
     ) -> RusotoFuture<GetTemplateOutput, GetTemplateError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetTemplate");
-        params.put("Version", "2010-05-15");
-        GetTemplateInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTemplateError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetTemplateOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetTemplateOutputDeserializer::deserialize(
-                        "GetTemplateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_template(input)
     }
-
-    /// <p>Returns information about a new or existing template. The <code>GetTemplateSummary</code> action is useful for viewing parameter information, such as default parameter values and parameter types, before you create or update a stack or stack set.</p> <p>You can use the <code>GetTemplateSummary</code> action when you submit a template, or you can get template information for a stack set, or a running or deleted stack.</p> <p>For deleted stacks, <code>GetTemplateSummary</code> returns the template information for up to 90 days after the stack has been deleted. If the template does not exist, a <code>ValidationError</code> is returned.</p>
     fn get_template_summary(
         &self,
         input: GetTemplateSummaryInput,
     ) -> RusotoFuture<GetTemplateSummaryOutput, GetTemplateSummaryError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetTemplateSummary");
-        params.put("Version", "2010-05-15");
-        GetTemplateSummaryInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTemplateSummaryError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetTemplateSummaryOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetTemplateSummaryOutputDeserializer::deserialize(
-                        "GetTemplateSummaryResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_template_summary(input)
     }
-
-    /// <p>Returns the ID and status of each active change set for a stack. For example, AWS CloudFormation lists change sets that are in the <code>CREATE_IN_PROGRESS</code> or <code>CREATE_PENDING</code> state.</p>
     fn list_change_sets(
         &self,
         input: ListChangeSetsInput,
     ) -> RusotoFuture<ListChangeSetsOutput, ListChangeSetsError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListChangeSets");
-        params.put("Version", "2010-05-15");
-        ListChangeSetsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListChangeSetsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListChangeSetsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListChangeSetsOutputDeserializer::deserialize(
-                        "ListChangeSetsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_change_sets(input)
     }
-
-    /// <p>Lists all exported output values in the account and region in which you call this action. Use this action to see the exported output values that you can import into other stacks. To import values, use the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/intrinsic-function-reference-importvalue.html"> <code>Fn::ImportValue</code> </a> function. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-exports.html"> AWS CloudFormation Export Stack Output Values</a>.</p>
     fn list_exports(
         &self,
         input: ListExportsInput,
     ) -> RusotoFuture<ListExportsOutput, ListExportsError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListExports");
-        params.put("Version", "2010-05-15");
-        ListExportsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListExportsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListExportsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListExportsOutputDeserializer::deserialize(
-                        "ListExportsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_exports(input)
     }
-
-    /// <p>Lists all stacks that are importing an exported output value. To modify or remove an exported output value, first use this action to see which stacks are using it. To see the exported output values in your account, see <a>ListExports</a>. </p> <p>For more information about importing an exported output value, see the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/intrinsic-function-reference-importvalue.html"> <code>Fn::ImportValue</code> </a> function. </p>
     fn list_imports(
         &self,
         input: ListImportsInput,
     ) -> RusotoFuture<ListImportsOutput, ListImportsError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListImports");
-        params.put("Version", "2010-05-15");
-        ListImportsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListImportsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListImportsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListImportsOutputDeserializer::deserialize(
-                        "ListImportsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_imports(input)
     }
-
-    /// <p>Returns summary information about stack instances that are associated with the specified stack set. You can filter for stack instances that are associated with a specific AWS account name or region.</p>
     fn list_stack_instances(
         &self,
         input: ListStackInstancesInput,
     ) -> RusotoFuture<ListStackInstancesOutput, ListStackInstancesError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListStackInstances");
-        params.put("Version", "2010-05-15");
-        ListStackInstancesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListStackInstancesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListStackInstancesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListStackInstancesOutputDeserializer::deserialize(
-                        "ListStackInstancesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_stack_instances(input)
     }
-
-    /// <p>Returns descriptions of all resources of the specified stack.</p> <p>For deleted stacks, ListStackResources returns resource information for up to 90 days after the stack has been deleted.</p>
     fn list_stack_resources(
         &self,
         input: ListStackResourcesInput,
     ) -> RusotoFuture<ListStackResourcesOutput, ListStackResourcesError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListStackResources");
-        params.put("Version", "2010-05-15");
-        ListStackResourcesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListStackResourcesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListStackResourcesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListStackResourcesOutputDeserializer::deserialize(
-                        "ListStackResourcesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_stack_resources(input)
     }
-
-    /// <p>Returns summary information about the results of a stack set operation. </p>
     fn list_stack_set_operation_results(
         &self,
         input: ListStackSetOperationResultsInput,
     ) -> RusotoFuture<ListStackSetOperationResultsOutput, ListStackSetOperationResultsError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListStackSetOperationResults");
-        params.put("Version", "2010-05-15");
-        ListStackSetOperationResultsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListStackSetOperationResultsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListStackSetOperationResultsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListStackSetOperationResultsOutputDeserializer::deserialize(
-                        "ListStackSetOperationResultsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_stack_set_operation_results(input)
     }
-
-    /// <p>Returns summary information about operations performed on a stack set. </p>
     fn list_stack_set_operations(
         &self,
         input: ListStackSetOperationsInput,
     ) -> RusotoFuture<ListStackSetOperationsOutput, ListStackSetOperationsError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListStackSetOperations");
-        params.put("Version", "2010-05-15");
-        ListStackSetOperationsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListStackSetOperationsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListStackSetOperationsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListStackSetOperationsOutputDeserializer::deserialize(
-                        "ListStackSetOperationsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_stack_set_operations(input)
     }
-
-    /// <p>Returns summary information about stack sets that are associated with the user.</p>
     fn list_stack_sets(
         &self,
         input: ListStackSetsInput,
     ) -> RusotoFuture<ListStackSetsOutput, ListStackSetsError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListStackSets");
-        params.put("Version", "2010-05-15");
-        ListStackSetsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListStackSetsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListStackSetsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListStackSetsOutputDeserializer::deserialize(
-                        "ListStackSetsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_stack_sets(input)
     }
-
-    /// <p>Returns the summary information for stacks whose status matches the specified StackStatusFilter. Summary information for stacks that have been deleted is kept for 90 days after the stack is deleted. If no StackStatusFilter is specified, summary information for all stacks is returned (including existing stacks and stacks that have been deleted).</p>
     fn list_stacks(
         &self,
         input: ListStacksInput,
     ) -> RusotoFuture<ListStacksOutput, ListStacksError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListStacks");
-        params.put("Version", "2010-05-15");
-        ListStacksInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListStacksError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListStacksOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        ListStacksOutputDeserializer::deserialize("ListStacksResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_stacks(input)
     }
-
-    /// <p>Sets a stack policy for a specified stack.</p>
     fn set_stack_policy(
         &self,
         input: SetStackPolicyInput,
     ) -> RusotoFuture<(), SetStackPolicyError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetStackPolicy");
-        params.put("Version", "2010-05-15");
-        SetStackPolicyInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetStackPolicyError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).set_stack_policy(input)
     }
-
-    /// <p>Sends a signal to the specified resource with a success or failure status. You can use the SignalResource API in conjunction with a creation policy or update policy. AWS CloudFormation doesn't proceed with a stack creation or update until resources receive the required number of signals or the timeout period is exceeded. The SignalResource API is useful in cases where you want to send signals from anywhere other than an Amazon EC2 instance.</p>
     fn signal_resource(&self, input: SignalResourceInput) -> RusotoFuture<(), SignalResourceError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SignalResource");
-        params.put("Version", "2010-05-15");
-        SignalResourceInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SignalResourceError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).signal_resource(input)
     }
-
-    /// <p>Stops an in-progress operation on a stack set and its associated stack instances. </p>
     fn stop_stack_set_operation(
         &self,
         input: StopStackSetOperationInput,
     ) -> RusotoFuture<StopStackSetOperationOutput, StopStackSetOperationError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "StopStackSetOperation");
-        params.put("Version", "2010-05-15");
-        StopStackSetOperationInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopStackSetOperationError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = StopStackSetOperationOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = StopStackSetOperationOutputDeserializer::deserialize(
-                        "StopStackSetOperationResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).stop_stack_set_operation(input)
     }
-
-    /// <p>Updates a stack as specified in the template. After the call completes successfully, the stack update starts. You can check the status of the stack via the <a>DescribeStacks</a> action.</p> <p>To get a copy of the template for an existing stack, you can use the <a>GetTemplate</a> action.</p> <p>For more information about creating an update template, updating a stack, and monitoring the progress of the update, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks.html">Updating a Stack</a>.</p>
     fn update_stack(
         &self,
         input: UpdateStackInput,
     ) -> RusotoFuture<UpdateStackOutput, UpdateStackError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateStack");
-        params.put("Version", "2010-05-15");
-        UpdateStackInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateStackError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateStackOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = UpdateStackOutputDeserializer::deserialize(
-                        "UpdateStackResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).update_stack(input)
     }
-
-    /// <p>Updates the parameter values for stack instances for the specified accounts, within the specified regions. A stack instance refers to a stack in a specific account and region. </p> <p>You can only update stack instances in regions and accounts where they already exist; to create additional stack instances, use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_CreateStackInstances.html">CreateStackInstances</a>. </p> <p>During stack set updates, any parameters overridden for a stack instance are not updated, but retain their overridden value.</p> <p>You can only update the parameter <i>values</i> that are specified in the stack set; to add or delete a parameter itself, use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_UpdateStackSet.html">UpdateStackSet</a> to update the stack set template. If you add a parameter to a template, before you can override the parameter value specified in the stack set you must first use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_UpdateStackSet.html">UpdateStackSet</a> to update all stack instances with the updated template and parameter value specified in the stack set. Once a stack instance has been updated with the new parameter, you can then override the parameter value using <code>UpdateStackInstances</code>.</p>
     fn update_stack_instances(
         &self,
         input: UpdateStackInstancesInput,
     ) -> RusotoFuture<UpdateStackInstancesOutput, UpdateStackInstancesError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateStackInstances");
-        params.put("Version", "2010-05-15");
-        UpdateStackInstancesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateStackInstancesError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateStackInstancesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = UpdateStackInstancesOutputDeserializer::deserialize(
-                        "UpdateStackInstancesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).update_stack_instances(input)
     }
-
-    /// <p>Updates the stack set, and associated stack instances in the specified accounts and regions.</p> <p>Even if the stack set operation created by updating the stack set fails (completely or partially, below or above a specified failure tolerance), the stack set is updated with your changes. Subsequent <a>CreateStackInstances</a> calls on the specified stack set use the updated stack set.</p>
     fn update_stack_set(
         &self,
         input: UpdateStackSetInput,
     ) -> RusotoFuture<UpdateStackSetOutput, UpdateStackSetError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateStackSet");
-        params.put("Version", "2010-05-15");
-        UpdateStackSetInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateStackSetError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateStackSetOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = UpdateStackSetOutputDeserializer::deserialize(
-                        "UpdateStackSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).update_stack_set(input)
     }
-
-    /// <p>Updates termination protection for the specified stack. If a user attempts to delete a stack with termination protection enabled, the operation fails and the stack remains unchanged. For more information, see <a href="AWSCloudFormation/latest/UserGuide/using-cfn-protect-stacks.html">Protecting a Stack From Being Deleted</a> in the <i>AWS CloudFormation User Guide</i>.</p> <p> For <a href="AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">nested stacks</a>, termination protection is set on the root stack and cannot be changed directly on the nested stack.</p>
     fn update_termination_protection(
         &self,
         input: UpdateTerminationProtectionInput,
     ) -> RusotoFuture<UpdateTerminationProtectionOutput, UpdateTerminationProtectionError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateTerminationProtection");
-        params.put("Version", "2010-05-15");
-        UpdateTerminationProtectionInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateTerminationProtectionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateTerminationProtectionOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
+        (**self).update_termination_protection(input)
+    }
+    fn validate_template(
+        &self,
+        input: ValidateTemplateInput,
+    ) -> RusotoFuture<ValidateTemplateOutput, ValidateTemplateError> {
+        (**self).validate_template(input)
+    }
+}
+impl<T: CloudFormation + ?Sized> CloudFormation for Box<T> {
+    fn cancel_update_stack(
+        &self,
+        input: CancelUpdateStackInput,
+    ) -> RusotoFuture<(), CancelUpdateStackError> {
+        (**self).cancel_update_stack(input)
+    }
+    fn continue_update_rollback(
+        &self,
+        input: ContinueUpdateRollbackInput,
+    ) -> RusotoFuture<ContinueUpdateRollbackOutput, ContinueUpdateRollbackError> {
+        (**self).continue_update_rollback(input)
+    }
+    fn create_change_set(
+        &self,
+        input: CreateChangeSetInput,
+    ) -> RusotoFuture<CreateChangeSetOutput, CreateChangeSetError> {
+        (**self).create_change_set(input)
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackInput,
+    ) -> RusotoFuture<CreateStackOutput, CreateStackError> {
+        (**self).create_stack(input)
+    }
+    fn create_stack_instances(
+        &self,
+        input: CreateStackInstancesInput,
+    ) -> RusotoFuture<CreateStackInstancesOutput, CreateStackInstancesError> {
+        (**self).create_stack_instances(input)
+    }
+    fn create_stack_set(
+        &self,
+        input: CreateStackSetInput,
+    ) -> RusotoFuture<CreateStackSetOutput, CreateStackSetError> {
+        (**self).create_stack_set(input)
+    }
+    fn delete_change_set(
+        &self,
+        input: DeleteChangeSetInput,
+    ) -> RusotoFuture<DeleteChangeSetOutput, DeleteChangeSetError> {
+        (**self).delete_change_set(input)
+    }
+    fn delete_stack(&self, input: DeleteStackInput) -> RusotoFuture<(), DeleteStackError> {
+        (**self).delete_stack(input)
+    }
+    fn delete_stack_instances(
+        &self,
+        input: DeleteStackInstancesInput,
+    ) -> RusotoFuture<DeleteStackInstancesOutput, DeleteStackInstancesError> {
+        (**self).delete_stack_instances(input)
+    }
+    fn delete_stack_set(
+        &self,
+        input: DeleteStackSetInput,
+    ) -> RusotoFuture<DeleteStackSetOutput, DeleteStackSetError> {
+        (**self).delete_stack_set(input)
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        (**self).describe_account_limits(input)
+    }
+    fn describe_change_set(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> RusotoFuture<DescribeChangeSetOutput, DescribeChangeSetError> {
+        (**self).describe_change_set(input)
+    }
+    fn describe_stack_drift_detection_status(
+        &self,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> RusotoFuture<DescribeStackDriftDetectionStatusOutput, DescribeStackDriftDetectionStatusError>
+    {
+        (**self).describe_stack_drift_detection_status(input)
+    }
+    fn describe_stack_events(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> RusotoFuture<DescribeStackEventsOutput, DescribeStackEventsError> {
+        (**self).describe_stack_events(input)
+    }
+    fn describe_stack_instance(
+        &self,
+        input: DescribeStackInstanceInput,
+    ) -> RusotoFuture<DescribeStackInstanceOutput, DescribeStackInstanceError> {
+        (**self).describe_stack_instance(input)
+    }
+    fn describe_stack_resource(
+        &self,
+        input: DescribeStackResourceInput,
+    ) -> RusotoFuture<DescribeStackResourceOutput, DescribeStackResourceError> {
+        (**self).describe_stack_resource(input)
+    }
+    fn describe_stack_resource_drifts(
+        &self,
+        input: DescribeStackResourceDriftsInput,
+    ) -> RusotoFuture<DescribeStackResourceDriftsOutput, DescribeStackResourceDriftsError> {
+        (**self).describe_stack_resource_drifts(input)
+    }
+    fn describe_stack_resources(
+        &self,
+        input: DescribeStackResourcesInput,
+    ) -> RusotoFuture<DescribeStackResourcesOutput, DescribeStackResourcesError> {
+        (**self).describe_stack_resources(input)
+    }
+    fn describe_stack_set(
+        &self,
+        input: DescribeStackSetInput,
+    ) -> RusotoFuture<DescribeStackSetOutput, DescribeStackSetError> {
+        (**self).describe_stack_set(input)
+    }
+    fn describe_stack_set_operation(
+        &self,
+        input: DescribeStackSetOperationInput,
+    ) -> RusotoFuture<DescribeStackSetOperationOutput, DescribeStackSetOperationError> {
+        (**self).describe_stack_set_operation(input)
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksInput,
+    ) -> RusotoFuture<DescribeStacksOutput, DescribeStacksError> {
+        (**self).describe_stacks(input)
+    }
+    fn detect_stack_drift(
+        &self,
+        input: DetectStackDriftInput,
+    ) -> RusotoFuture<DetectStackDriftOutput, DetectStackDriftError> {
+        (**self).detect_stack_drift(input)
+    }
+    fn detect_stack_resource_drift(
+        &self,
+        input: DetectStackResourceDriftInput,
+    ) -> RusotoFuture<DetectStackResourceDriftOutput, DetectStackResourceDriftError> {
+        (**self).detect_stack_resource_drift(input)
+    }
+    fn estimate_template_cost(
+        &self,
+        input: EstimateTemplateCostInput,
+    ) -> RusotoFuture<EstimateTemplateCostOutput, EstimateTemplateCostError> {
+        (**self).estimate_template_cost(input)
+    }
+    fn execute_change_set(
+        &self,
+        input: ExecuteChangeSetInput,
+    ) -> RusotoFuture<ExecuteChangeSetOutput, ExecuteChangeSetError> {
+        (**self).execute_change_set(input)
+    }
+    fn get_stack_policy(
+        &self,
+        input: GetStackPolicyInput,
+    ) -> RusotoFuture<GetStackPolicyOutput, GetStackPolicyError> {
+        (**self).get_stack_policy(input)
+    }
+    fn get_template(
+        &self,
+        input: GetTemplateInput,
+    ) -> RusotoFuture<GetTemplateOutput, GetTemplateError> {
+        (**self).get_template(input)
+    }
+    fn get_template_summary(
+        &self,
+        input: GetTemplateSummaryInput,
+    ) -> RusotoFuture<GetTemplateSummaryOutput, GetTemplateSummaryError> {
+        (**self).get_template_summary(input)
+    }
+    fn list_change_sets(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> RusotoFuture<ListChangeSetsOutput, ListChangeSetsError> {
+        (**self).list_change_sets(input)
+    }
+    fn list_exports(
+        &self,
+        input: ListExportsInput,
+    ) -> RusotoFuture<ListExportsOutput, ListExportsError> {
+        (**self).list_exports(input)
+    }
+    fn list_imports(
+        &self,
+        input: ListImportsInput,
+    ) -> RusotoFuture<ListImportsOutput, ListImportsError> {
+        (**self).list_imports(input)
+    }
+    fn list_stack_instances(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> RusotoFuture<ListStackInstancesOutput, ListStackInstancesError> {
+        (**self).list_stack_instances(input)
+    }
+    fn list_stack_resources(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> RusotoFuture<ListStackResourcesOutput, ListStackResourcesError> {
+        (**self).list_stack_resources(input)
+    }
+    fn list_stack_set_operation_results(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> RusotoFuture<ListStackSetOperationResultsOutput, ListStackSetOperationResultsError> {
+        (**self).list_stack_set_operation_results(input)
+    }
+    fn list_stack_set_operations(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> RusotoFuture<ListStackSetOperationsOutput, ListStackSetOperationsError> {
+        (**self).list_stack_set_operations(input)
+    }
+    fn list_stack_sets(
+        &self,
+        input: ListStackSetsInput,
+    ) -> RusotoFuture<ListStackSetsOutput, ListStackSetsError> {
+        (**self).list_stack_sets(input)
+    }
+    fn list_stacks(
+        &self,
+        input: ListStacksInput,
+    ) -> RusotoFuture<ListStacksOutput, ListStacksError> {
+        (**self).list_stacks(input)
+    }
+    fn set_stack_policy(
+        &self,
+        input: SetStackPolicyInput,
+    ) -> RusotoFuture<(), SetStackPolicyError> {
+        (**self).set_stack_policy(input)
+    }
+    fn signal_resource(&self, input: SignalResourceInput) -> RusotoFuture<(), SignalResourceError> {
+        (**self).signal_resource(input)
+    }
+    fn stop_stack_set_operation(
+        &self,
+        input: StopStackSetOperationInput,
+    ) -> RusotoFuture<StopStackSetOperationOutput, StopStackSetOperationError> {
+        (**self).stop_stack_set_operation(input)
+    }
+    fn update_stack(
+        &self,
+        input: UpdateStackInput,
+    ) -> RusotoFuture<UpdateStackOutput, UpdateStackError> {
+        (**self).update_stack(input)
+    }
+    fn update_stack_instances(
+        &self,
+        input: UpdateStackInstancesInput,
+    ) -> RusotoFuture<UpdateStackInstancesOutput, UpdateStackInstancesError> {
+        (**self).update_stack_instances(input)
+    }
+    fn update_stack_set(
+        &self,
+        input: UpdateStackSetInput,
+    ) -> RusotoFuture<UpdateStackSetOutput, UpdateStackSetError> {
+        (**self).update_stack_set(input)
+    }
+    fn update_termination_protection(
+        &self,
+        input: UpdateTerminationProtectionInput,
+    ) -> RusotoFuture<UpdateTerminationProtectionOutput, UpdateTerminationProtectionError> {
+        (**self).update_termination_protection(input)
+    }
+    fn validate_template(
+        &self,
+        input: ValidateTemplateInput,
+    ) -> RusotoFuture<ValidateTemplateOutput, ValidateTemplateError> {
+        (**self).validate_template(input)
+    }
+}
+impl<T: CloudFormation + ?Sized> CloudFormation for ::std::sync::Arc<T> {
+    fn cancel_update_stack(
+        &self,
+        input: CancelUpdateStackInput,
+    ) -> RusotoFuture<(), CancelUpdateStackError> {
+        (**self).cancel_update_stack(input)
+    }
+    fn continue_update_rollback(
+        &self,
+        input: ContinueUpdateRollbackInput,
+    ) -> RusotoFuture<ContinueUpdateRollbackOutput, ContinueUpdateRollbackError> {
+        (**self).continue_update_rollback(input)
+    }
+    fn create_change_set(
+        &self,
+        input: CreateChangeSetInput,
+    ) -> RusotoFuture<CreateChangeSetOutput, CreateChangeSetError> {
+        (**self).create_change_set(input)
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackInput,
+    ) -> RusotoFuture<CreateStackOutput, CreateStackError> {
+        (**self).create_stack(input)
+    }
+    fn create_stack_instances(
+        &self,
+        input: CreateStackInstancesInput,
+    ) -> RusotoFuture<CreateStackInstancesOutput, CreateStackInstancesError> {
+        (**self).create_stack_instances(input)
+    }
+    fn create_stack_set(
+        &self,
+        input: CreateStackSetInput,
+    ) -> RusotoFuture<CreateStackSetOutput, CreateStackSetError> {
+        (**self).create_stack_set(input)
+    }
+    fn delete_change_set(
+        &self,
+        input: DeleteChangeSetInput,
+    ) -> RusotoFuture<DeleteChangeSetOutput, DeleteChangeSetError> {
+        (**self).delete_change_set(input)
+    }
+    fn delete_stack(&self, input: DeleteStackInput) -> RusotoFuture<(), DeleteStackError> {
+        (**self).delete_stack(input)
+    }
+    fn delete_stack_instances(
+        &self,
+        input: DeleteStackInstancesInput,
+    ) -> RusotoFuture<DeleteStackInstancesOutput, DeleteStackInstancesError> {
+        (**self).delete_stack_instances(input)
+    }
+    fn delete_stack_set(
+        &self,
+        input: DeleteStackSetInput,
+    ) -> RusotoFuture<DeleteStackSetOutput, DeleteStackSetError> {
+        (**self).delete_stack_set(input)
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        (**self).describe_account_limits(input)
+    }
+    fn describe_change_set(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> RusotoFuture<DescribeChangeSetOutput, DescribeChangeSetError> {
+        (**self).describe_change_set(input)
+    }
+    fn describe_stack_drift_detection_status(
+        &self,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> RusotoFuture<DescribeStackDriftDetectionStatusOutput, DescribeStackDriftDetectionStatusError>
+    {
+        (**self).describe_stack_drift_detection_status(input)
+    }
+    fn describe_stack_events(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> RusotoFuture<DescribeStackEventsOutput, DescribeStackEventsError> {
+        (**self).describe_stack_events(input)
+    }
+    fn describe_stack_instance(
+        &self,
+        input: DescribeStackInstanceInput,
+    ) -> RusotoFuture<DescribeStackInstanceOutput, DescribeStackInstanceError> {
+        (**self).describe_stack_instance(input)
+    }
+    fn describe_stack_resource(
+        &self,
+        input: DescribeStackResourceInput,
+    ) -> RusotoFuture<DescribeStackResourceOutput, DescribeStackResourceError> {
+        (**self).describe_stack_resource(input)
+    }
+    fn describe_stack_resource_drifts(
+        &self,
+        input: DescribeStackResourceDriftsInput,
+    ) -> RusotoFuture<DescribeStackResourceDriftsOutput, DescribeStackResourceDriftsError> {
+        (**self).describe_stack_resource_drifts(input)
+    }
+    fn describe_stack_resources(
+        &self,
+        input: DescribeStackResourcesInput,
+    ) -> RusotoFuture<DescribeStackResourcesOutput, DescribeStackResourcesError> {
+        (**self).describe_stack_resources(input)
+    }
+    fn describe_stack_set(
+        &self,
+        input: DescribeStackSetInput,
+    ) -> RusotoFuture<DescribeStackSetOutput, DescribeStackSetError> {
+        (**self).describe_stack_set(input)
+    }
+    fn describe_stack_set_operation(
+        &self,
+        input: DescribeStackSetOperationInput,
+    ) -> RusotoFuture<DescribeStackSetOperationOutput, DescribeStackSetOperationError> {
+        (**self).describe_stack_set_operation(input)
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksInput,
+    ) -> RusotoFuture<DescribeStacksOutput, DescribeStacksError> {
+        (**self).describe_stacks(input)
+    }
+    fn detect_stack_drift(
+        &self,
+        input: DetectStackDriftInput,
+    ) -> RusotoFuture<DetectStackDriftOutput, DetectStackDriftError> {
+        (**self).detect_stack_drift(input)
+    }
+    fn detect_stack_resource_drift(
+        &self,
+        input: DetectStackResourceDriftInput,
+    ) -> RusotoFuture<DetectStackResourceDriftOutput, DetectStackResourceDriftError> {
+        (**self).detect_stack_resource_drift(input)
+    }
+    fn estimate_template_cost(
+        &self,
+        input: EstimateTemplateCostInput,
+    ) -> RusotoFuture<EstimateTemplateCostOutput, EstimateTemplateCostError> {
+        (**self).estimate_template_cost(input)
+    }
+    fn execute_change_set(
+        &self,
+        input: ExecuteChangeSetInput,
+    ) -> RusotoFuture<ExecuteChangeSetOutput, ExecuteChangeSetError> {
+        (**self).execute_change_set(input)
+    }
+    fn get_stack_policy(
+        &self,
+        input: GetStackPolicyInput,
+    ) -> RusotoFuture<GetStackPolicyOutput, GetStackPolicyError> {
+        (**self).get_stack_policy(input)
+    }
+    fn get_template(
+        &self,
+        input: GetTemplateInput,
+    ) -> RusotoFuture<GetTemplateOutput, GetTemplateError> {
+        (**self).get_template(input)
+    }
+    fn get_template_summary(
+        &self,
+        input: GetTemplateSummaryInput,
+    ) -> RusotoFuture<GetTemplateSummaryOutput, GetTemplateSummaryError> {
+        (**self).get_template_summary(input)
+    }
+    fn list_change_sets(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> RusotoFuture<ListChangeSetsOutput, ListChangeSetsError> {
+        (**self).list_change_sets(input)
+    }
+    fn list_exports(
+        &self,
+        input: ListExportsInput,
+    ) -> RusotoFuture<ListExportsOutput, ListExportsError> {
+        (**self).list_exports(input)
+    }
+    fn list_imports(
+        &self,
+        input: ListImportsInput,
+    ) -> RusotoFuture<ListImportsOutput, ListImportsError> {
+        (**self).list_imports(input)
+    }
+    fn list_stack_instances(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> RusotoFuture<ListStackInstancesOutput, ListStackInstancesError> {
+        (**self).list_stack_instances(input)
+    }
+    fn list_stack_resources(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> RusotoFuture<ListStackResourcesOutput, ListStackResourcesError> {
+        (**self).list_stack_resources(input)
+    }
+    fn list_stack_set_operation_results(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> RusotoFuture<ListStackSetOperationResultsOutput, ListStackSetOperationResultsError> {
+        (**self).list_stack_set_operation_results(input)
+    }
+    fn list_stack_set_operations(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> RusotoFuture<ListStackSetOperationsOutput, ListStackSetOperationsError> {
+        (**self).list_stack_set_operations(input)
+    }
+    fn list_stack_sets(
+        &self,
+        input: ListStackSetsInput,
+    ) -> RusotoFuture<ListStackSetsOutput, ListStackSetsError> {
+        (**self).list_stack_sets(input)
+    }
+    fn list_stacks(
+        &self,
+        input: ListStacksInput,
+    ) -> RusotoFuture<ListStacksOutput, ListStacksError> {
+        (**self).list_stacks(input)
+    }
+    fn set_stack_policy(
+        &self,
+        input: SetStackPolicyInput,
+    ) -> RusotoFuture<(), SetStackPolicyError> {
+        (**self).set_stack_policy(input)
+    }
+    fn signal_resource(&self, input: SignalResourceInput) -> RusotoFuture<(), SignalResourceError> {
+        (**self).signal_resource(input)
+    }
+    fn stop_stack_set_operation(
+        &self,
+        input: StopStackSetOperationInput,
+    ) -> RusotoFuture<StopStackSetOperationOutput, StopStackSetOperationError> {
+        (**self).stop_stack_set_operation(input)
+    }
+    fn update_stack(
+        &self,
+        input: UpdateStackInput,
+    ) -> RusotoFuture<UpdateStackOutput, UpdateStackError> {
+        (**self).update_stack(input)
+    }
+    fn update_stack_instances(
+        &self,
+        input: UpdateStackInstancesInput,
+    ) -> RusotoFuture<UpdateStackInstancesOutput, UpdateStackInstancesError> {
+        (**self).update_stack_instances(input)
+    }
+    fn update_stack_set(
+        &self,
+        input: UpdateStackSetInput,
+    ) -> RusotoFuture<UpdateStackSetOutput, UpdateStackSetError> {
+        (**self).update_stack_set(input)
+    }
+    fn update_termination_protection(
+        &self,
+        input: UpdateTerminationProtectionInput,
+    ) -> RusotoFuture<UpdateTerminationProtectionOutput, UpdateTerminationProtectionError> {
+        (**self).update_termination_protection(input)
+    }
+    fn validate_template(
+        &self,
+        input: ValidateTemplateInput,
+    ) -> RusotoFuture<ValidateTemplateOutput, ValidateTemplateError> {
+        (**self).validate_template(input)
+    }
+}
+/// A client for the AWS CloudFormation API.
+#[derive(Clone)]
+pub struct CloudFormationClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CloudFormationClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudFormationClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudFormationClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudFormationClient {
+        CloudFormationClient { client, region }
+    }
+}
+
+impl CloudFormation for CloudFormationClient {
+    /// <p><p>Cancels an update on the specified stack. If the call completes successfully, the stack rolls back the update and reverts to the previous stack configuration.</p> <note> <p>You can cancel only stacks that are in the UPDATE<em>IN</em>PROGRESS state.</p> </note></p>
+    fn cancel_update_stack(
+        &self,
+        input: CancelUpdateStackInput,
+    ) -> RusotoFuture<(), CancelUpdateStackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CancelUpdateStack");
+        let mut params = Params::new();
+
+        params.put("Action", "CancelUpdateStack");
+        params.put("Version", "2010-05-15");
+        CancelUpdateStackInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelUpdateStackError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>For a specified stack that is in the <code>UPDATE_ROLLBACK_FAILED</code> state, continues rolling it back to the <code>UPDATE_ROLLBACK_COMPLETE</code> state. Depending on the cause of the failure, you can manually <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/troubleshooting.html#troubleshooting-errors-update-rollback-failed"> fix the error</a> and continue the rollback. By continuing the rollback, you can return your stack to a working state (the <code>UPDATE_ROLLBACK_COMPLETE</code> state), and then try to update the stack again.</p> <p>A stack goes into the <code>UPDATE_ROLLBACK_FAILED</code> state when AWS CloudFormation cannot roll back all changes after a failed stack update. For example, you might have a stack that is rolling back to an old database instance that was deleted outside of AWS CloudFormation. Because AWS CloudFormation doesn't know the database was deleted, it assumes that the database instance still exists and attempts to roll back to it, causing the update rollback to fail.</p>
+    fn continue_update_rollback(
+        &self,
+        input: ContinueUpdateRollbackInput,
+    ) -> RusotoFuture<ContinueUpdateRollbackOutput, ContinueUpdateRollbackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ContinueUpdateRollback");
+        let mut params = Params::new();
+
+        params.put("Action", "ContinueUpdateRollback");
+        params.put("Version", "2010-05-15");
+        ContinueUpdateRollbackInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ContinueUpdateRollbackError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ContinueUpdateRollbackOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ContinueUpdateRollbackOutputDeserializer::deserialize(
+                        "ContinueUpdateRollbackResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ContinueUpdateRollback", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a list of changes that will be applied to a stack so that you can review the changes before executing them. You can create a change set for a stack that doesn't exist or an existing stack. If you create a change set for a stack that doesn't exist, the change set shows all of the resources that AWS CloudFormation will create. If you create a change set for an existing stack, AWS CloudFormation compares the stack's information with the information that you submit in the change set and lists the differences. Use change sets to understand which resources AWS CloudFormation will create or change, and how it will change resources in an existing stack, before you create or update a stack.</p> <p>To create a change set for a stack that doesn't exist, for the <code>ChangeSetType</code> parameter, specify <code>CREATE</code>. To create a change set for an existing stack, specify <code>UPDATE</code> for the <code>ChangeSetType</code> parameter. After the <code>CreateChangeSet</code> call successfully completes, AWS CloudFormation starts creating the change set. To check the status of the change set or to review it, use the <a>DescribeChangeSet</a> action.</p> <p>When you are satisfied with the changes the change set will make, execute the change set by using the <a>ExecuteChangeSet</a> action. AWS CloudFormation doesn't make changes until you execute the change set.</p>
+    fn create_change_set(
+        &self,
+        input: CreateChangeSetInput,
+    ) -> RusotoFuture<CreateChangeSetOutput, CreateChangeSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateChangeSet");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateChangeSet");
+        params.put("Version", "2010-05-15");
+        CreateChangeSetInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateChangeSetError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateChangeSetOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateChangeSetOutputDeserializer::deserialize(
+                        "CreateChangeSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateChangeSet", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a stack as specified in the template. After the call completes successfully, the stack creation starts. You can check the status of the stack via the <a>DescribeStacks</a> API.</p>
+    fn create_stack(
+        &self,
+        input: CreateStackInput,
+    ) -> RusotoFuture<CreateStackOutput, CreateStackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStack");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateStack");
+        params.put("Version", "2010-05-15");
+        CreateStackInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateStackError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateStackOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        CreateStackOutputDeserializer::deserialize("CreateStackResult", &mut stack)
+                            .map_err(|err| stack.parse_error("CreateStack", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates stack instances for the specified accounts, within the specified regions. A stack instance refers to a stack in a specific account and region. <code>Accounts</code> and <code>Regions</code> are required parameters—you must specify at least one account and one region. </p>
+    fn create_stack_instances(
+        &self,
+        input: CreateStackInstancesInput,
+    ) -> RusotoFuture<CreateStackInstancesOutput, CreateStackInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStackInstances");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateStackInstances");
+        params.put("Version", "2010-05-15");
+        CreateStackInstancesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateStackInstancesError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateStackInstancesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateStackInstancesOutputDeserializer::deserialize(
+                        "CreateStackInstancesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateStackInstances", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a stack set.</p>
+    fn create_stack_set(
+        &self,
+        input: CreateStackSetInput,
+    ) -> RusotoFuture<CreateStackSetOutput, CreateStackSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStackSet");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateStackSet");
+        params.put("Version", "2010-05-15");
+        CreateStackSetInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateStackSetError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateStackSetOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateStackSetOutputDeserializer::deserialize(
+                        "CreateStackSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateStackSet", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified change set. Deleting change sets ensures that no one executes the wrong change set.</p> <p>If the call successfully completes, AWS CloudFormation successfully deleted the change set.</p>
+    fn delete_change_set(
+        &self,
+        input: DeleteChangeSetInput,
+    ) -> RusotoFuture<DeleteChangeSetOutput, DeleteChangeSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteChangeSet");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteChangeSet");
+        params.put("Version", "2010-05-15");
+        DeleteChangeSetInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteChangeSetError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteChangeSetOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteChangeSetOutputDeserializer::deserialize(
+                        "DeleteChangeSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteChangeSet", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a specified stack. Once the call completes successfully, stack deletion starts. Deleted stacks do not show up in the <a>DescribeStacks</a> API if the deletion has been completed successfully.</p>
+    fn delete_stack(&self, input: DeleteStackInput) -> RusotoFuture<(), DeleteStackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStack");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteStack");
+        params.put("Version", "2010-05-15");
+        DeleteStackInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteStackError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes stack instances for the specified accounts, in the specified regions. </p>
+    fn delete_stack_instances(
+        &self,
+        input: DeleteStackInstancesInput,
+    ) -> RusotoFuture<DeleteStackInstancesOutput, DeleteStackInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStackInstances");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteStackInstances");
+        params.put("Version", "2010-05-15");
+        DeleteStackInstancesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteStackInstancesError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteStackInstancesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteStackInstancesOutputDeserializer::deserialize(
+                        "DeleteStackInstancesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteStackInstances", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a stack set. Before you can delete a stack set, all of its member stack instances must be deleted. For more information about how to do this, see <a>DeleteStackInstances</a>. </p>
+    fn delete_stack_set(
+        &self,
+        input: DeleteStackSetInput,
+    ) -> RusotoFuture<DeleteStackSetOutput, DeleteStackSetError> {
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStackSet");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteStackSet");
+        params.put("Version", "2010-05-15");
+        DeleteStackSetInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteStackSetError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteStackSetOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteStackSetOutputDeserializer::deserialize(
+                        "DeleteStackSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteStackSet", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieves your account's AWS CloudFormation limits, such as the maximum number of stacks that you can create in your account. For more information about account limits, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">AWS CloudFormation Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeAccountLimits");
+        params.put("Version", "2010-05-15");
+        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAccountLimitsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeAccountLimitsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeAccountLimitsOutputDeserializer::deserialize(
+                        "DescribeAccountLimitsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAccountLimits", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the inputs for the change set and a list of changes that AWS CloudFormation will make if you execute the change set. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks-changesets.html">Updating Stacks Using Change Sets</a> in the AWS CloudFormation User Guide.</p>
+    fn describe_change_set(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> RusotoFuture<DescribeChangeSetOutput, DescribeChangeSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeChangeSet");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeChangeSet");
+        params.put("Version", "2010-05-15");
+        DescribeChangeSetInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeChangeSetError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeChangeSetOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeChangeSetOutputDeserializer::deserialize(
+                        "DescribeChangeSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeChangeSet", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about a stack drift detection operation. A stack drift detection operation detects whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. A stack is considered to have drifted if one or more of its resources have drifted. For more information on stack and resource drift, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <a>DetectStackDrift</a> to initiate a stack drift detection operation. <code>DetectStackDrift</code> returns a <code>StackDriftDetectionId</code> you can use to monitor the progress of the operation using <code>DescribeStackDriftDetectionStatus</code>. Once the drift detection operation has completed, use <a>DescribeStackResourceDrifts</a> to return drift information about the stack and its resources.</p>
+    fn describe_stack_drift_detection_status(
+        &self,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> RusotoFuture<DescribeStackDriftDetectionStatusOutput, DescribeStackDriftDetectionStatusError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackDriftDetectionStatus");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeStackDriftDetectionStatus");
+        params.put("Version", "2010-05-15");
+        DescribeStackDriftDetectionStatusInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeStackDriftDetectionStatusError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeStackDriftDetectionStatusOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeStackDriftDetectionStatusOutputDeserializer::deserialize(
+                        "DescribeStackDriftDetectionStatusResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeStackDriftDetectionStatus", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Returns all stack related events for a specified stack in reverse chronological order. For more information about a stack&#39;s event history, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/concept-stack.html">Stacks</a> in the AWS CloudFormation User Guide.</p> <note> <p>You can list events for stacks that have failed to create or have been deleted by specifying the unique stack identifier (stack ID).</p> </note></p>
+    fn describe_stack_events(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> RusotoFuture<DescribeStackEventsOutput, DescribeStackEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackEvents");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeStackEvents");
+        params.put("Version", "2010-05-15");
+        DescribeStackEventsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeStackEventsError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeStackEventsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeStackEventsOutputDeserializer::deserialize(
+                        "DescribeStackEventsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeStackEvents", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the stack instance that's associated with the specified stack set, AWS account, and region.</p> <p>For a list of stack instances that are associated with a specific stack set, use <a>ListStackInstances</a>.</p>
+    fn describe_stack_instance(
+        &self,
+        input: DescribeStackInstanceInput,
+    ) -> RusotoFuture<DescribeStackInstanceOutput, DescribeStackInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackInstance");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeStackInstance");
+        params.put("Version", "2010-05-15");
+        DescribeStackInstanceInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeStackInstanceError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeStackInstanceOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeStackInstanceOutputDeserializer::deserialize(
+                        "DescribeStackInstanceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeStackInstance", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a description of the specified resource in the specified stack.</p> <p>For deleted stacks, DescribeStackResource returns resource information for up to 90 days after the stack has been deleted.</p>
+    fn describe_stack_resource(
+        &self,
+        input: DescribeStackResourceInput,
+    ) -> RusotoFuture<DescribeStackResourceOutput, DescribeStackResourceError> {
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResource");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeStackResource");
+        params.put("Version", "2010-05-15");
+        DescribeStackResourceInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeStackResourceError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeStackResourceOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeStackResourceOutputDeserializer::deserialize(
+                        "DescribeStackResourceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeStackResource", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns drift information for the resources that have been checked for drift in the specified stack. This includes actual and expected configuration values for resources where AWS CloudFormation detects configuration drift.</p> <p>For a given stack, there will be one <code>StackResourceDrift</code> for each stack resource that has been checked for drift. Resources that have not yet been checked for drift are not included. Resources that do not currently support drift detection are not checked, and so not included. For a list of resources that support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p>Use <a>DetectStackResourceDrift</a> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all supported resources for a given stack.</p>
+    fn describe_stack_resource_drifts(
+        &self,
+        input: DescribeStackResourceDriftsInput,
+    ) -> RusotoFuture<DescribeStackResourceDriftsOutput, DescribeStackResourceDriftsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResourceDrifts");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeStackResourceDrifts");
+        params.put("Version", "2010-05-15");
+        DescribeStackResourceDriftsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeStackResourceDriftsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeStackResourceDriftsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeStackResourceDriftsOutputDeserializer::deserialize(
+                        "DescribeStackResourceDriftsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeStackResourceDrifts", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Returns AWS resource descriptions for running and deleted stacks. If <code>StackName</code> is specified, all the associated resources that are part of the stack are returned. If <code>PhysicalResourceId</code> is specified, the associated resources of the stack that the resource belongs to are returned.</p> <note> <p>Only the first 100 resources will be returned. If your stack has more resources than this, you should use <code>ListStackResources</code> instead.</p> </note> <p>For deleted stacks, <code>DescribeStackResources</code> returns resource information for up to 90 days after the stack has been deleted.</p> <p>You must specify either <code>StackName</code> or <code>PhysicalResourceId</code>, but not both. In addition, you can specify <code>LogicalResourceId</code> to filter the returned result. For more information about resources, the <code>LogicalResourceId</code> and <code>PhysicalResourceId</code>, go to the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/">AWS CloudFormation User Guide</a>.</p> <note> <p>A <code>ValidationError</code> is returned if you specify both <code>StackName</code> and <code>PhysicalResourceId</code> in the same request.</p> </note></p>
+    fn describe_stack_resources(
+        &self,
+        input: DescribeStackResourcesInput,
+    ) -> RusotoFuture<DescribeStackResourcesOutput, DescribeStackResourcesError> {
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResources");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeStackResources");
+        params.put("Version", "2010-05-15");
+        DescribeStackResourcesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeStackResourcesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeStackResourcesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeStackResourcesOutputDeserializer::deserialize(
+                        "DescribeStackResourcesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeStackResources", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the description of the specified stack set. </p>
+    fn describe_stack_set(
+        &self,
+        input: DescribeStackSetInput,
+    ) -> RusotoFuture<DescribeStackSetOutput, DescribeStackSetError> {
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackSet");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeStackSet");
+        params.put("Version", "2010-05-15");
+        DescribeStackSetInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeStackSetError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeStackSetOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeStackSetOutputDeserializer::deserialize(
+                        "DescribeStackSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeStackSet", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the description of the specified stack set operation. </p>
+    fn describe_stack_set_operation(
+        &self,
+        input: DescribeStackSetOperationInput,
+    ) -> RusotoFuture<DescribeStackSetOperationOutput, DescribeStackSetOperationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackSetOperation");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeStackSetOperation");
+        params.put("Version", "2010-05-15");
+        DescribeStackSetOperationInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeStackSetOperationError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeStackSetOperationOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeStackSetOperationOutputDeserializer::deserialize(
+                        "DescribeStackSetOperationResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeStackSetOperation", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Returns the description for the specified stack; if no stack name was specified, then it returns the description for all the stacks created.</p> <note> <p>If the stack does not exist, an <code>AmazonCloudFormationException</code> is returned.</p> </note></p>
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksInput,
+    ) -> RusotoFuture<DescribeStacksOutput, DescribeStacksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStacks");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeStacks");
+        params.put("Version", "2010-05-15");
+        DescribeStacksInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeStacksError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeStacksOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeStacksOutputDeserializer::deserialize(
+                        "DescribeStacksResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeStacks", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Detects whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. For each resource in the stack that supports drift detection, AWS CloudFormation compares the actual configuration of the resource with its expected template configuration. Only resource properties explicitly defined in the stack template are checked for drift. A stack is considered to have drifted if one or more of its resources differ from their expected template configurations. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <code>DetectStackDrift</code> to detect drift on all supported resources for a given stack, or <a>DetectStackResourceDrift</a> to detect drift on individual resources.</p> <p>For a list of stack resources that currently support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p> <code>DetectStackDrift</code> can take up to several minutes, depending on the number of resources contained within the stack. Use <a>DescribeStackDriftDetectionStatus</a> to monitor the progress of a detect stack drift operation. Once the drift detection operation has completed, use <a>DescribeStackResourceDrifts</a> to return drift information about the stack and its resources.</p> <p>When detecting drift on a stack, AWS CloudFormation does not detect drift on any nested stacks belonging to that stack. Perform <code>DetectStackDrift</code> directly on the nested stack itself.</p>
+    fn detect_stack_drift(
+        &self,
+        input: DetectStackDriftInput,
+    ) -> RusotoFuture<DetectStackDriftOutput, DetectStackDriftError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackDrift");
+        let mut params = Params::new();
+
+        params.put("Action", "DetectStackDrift");
+        params.put("Version", "2010-05-15");
+        DetectStackDriftInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetectStackDriftError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DetectStackDriftOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DetectStackDriftOutputDeserializer::deserialize(
+                        "DetectStackDriftResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DetectStackDrift", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about whether a resource's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. This information includes actual and expected property values for resources in which AWS CloudFormation detects drift. Only resource properties explicitly defined in the stack template are checked for drift. For more information about stack and resource drift, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <code>DetectStackResourceDrift</code> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all resources in a given stack that support drift detection.</p> <p>Resources that do not currently support drift detection cannot be checked. For a list of resources that support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p>
+    fn detect_stack_resource_drift(
+        &self,
+        input: DetectStackResourceDriftInput,
+    ) -> RusotoFuture<DetectStackResourceDriftOutput, DetectStackResourceDriftError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackResourceDrift");
+        let mut params = Params::new();
+
+        params.put("Action", "DetectStackResourceDrift");
+        params.put("Version", "2010-05-15");
+        DetectStackResourceDriftInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DetectStackResourceDriftError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DetectStackResourceDriftOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DetectStackResourceDriftOutputDeserializer::deserialize(
+                        "DetectStackResourceDriftResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DetectStackResourceDrift", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the estimated monthly cost of a template. The return value is an AWS Simple Monthly Calculator URL with a query string that describes the resources required to run the template.</p>
+    fn estimate_template_cost(
+        &self,
+        input: EstimateTemplateCostInput,
+    ) -> RusotoFuture<EstimateTemplateCostOutput, EstimateTemplateCostError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("EstimateTemplateCost");
+        let mut params = Params::new();
+
+        params.put("Action", "EstimateTemplateCost");
+        params.put("Version", "2010-05-15");
+        EstimateTemplateCostInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(EstimateTemplateCostError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EstimateTemplateCostOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EstimateTemplateCostOutputDeserializer::deserialize(
+                        "EstimateTemplateCostResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("EstimateTemplateCost", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates a stack using the input information that was provided when the specified change set was created. After the call successfully completes, AWS CloudFormation starts updating the stack. Use the <a>DescribeStacks</a> action to view the status of the update.</p> <p>When you execute a change set, AWS CloudFormation deletes all other change sets associated with the stack because they aren't valid for the updated stack.</p> <p>If a stack policy is associated with the stack, AWS CloudFormation enforces the policy during the update. You can't specify a temporary stack policy that overrides the current policy.</p>
+    fn execute_change_set(
+        &self,
+        input: ExecuteChangeSetInput,
+    ) -> RusotoFuture<ExecuteChangeSetOutput, ExecuteChangeSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ExecuteChangeSet");
+        let mut params = Params::new();
+
+        params.put("Action", "ExecuteChangeSet");
+        params.put("Version", "2010-05-15");
+        ExecuteChangeSetInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ExecuteChangeSetError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ExecuteChangeSetOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ExecuteChangeSetOutputDeserializer::deserialize(
+                        "ExecuteChangeSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ExecuteChangeSet", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the stack policy for a specified stack. If a stack doesn't have a policy, a null value is returned.</p>
+    fn get_stack_policy(
+        &self,
+        input: GetStackPolicyInput,
+    ) -> RusotoFuture<GetStackPolicyOutput, GetStackPolicyError> {
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetStackPolicy");
+        let mut params = Params::new();
+
+        params.put("Action", "GetStackPolicy");
+        params.put("Version", "2010-05-15");
+        GetStackPolicyInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetStackPolicyError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetStackPolicyOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetStackPolicyOutputDeserializer::deserialize(
+                        "GetStackPolicyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetStackPolicy", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Returns the template body for a specified stack. You can get the template for running or deleted stacks.</p> <p>For deleted stacks, GetTemplate returns the template for up to 90 days after the stack has been deleted.</p> <note> <p> If the template does not exist, a <code>ValidationError</code> is returned. </p> </note></p>
+    fn get_template(
+        &self,
+        input: GetTemplateInput,
+    ) -> RusotoFuture<GetTemplateOutput, GetTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "GetTemplate");
+        params.put("Version", "2010-05-15");
+        GetTemplateInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTemplateError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetTemplateOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        GetTemplateOutputDeserializer::deserialize("GetTemplateResult", &mut stack)
+                            .map_err(|err| stack.parse_error("GetTemplate", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about a new or existing template. The <code>GetTemplateSummary</code> action is useful for viewing parameter information, such as default parameter values and parameter types, before you create or update a stack or stack set.</p> <p>You can use the <code>GetTemplateSummary</code> action when you submit a template, or you can get template information for a stack set, or a running or deleted stack.</p> <p>For deleted stacks, <code>GetTemplateSummary</code> returns the template information for up to 90 days after the stack has been deleted. If the template does not exist, a <code>ValidationError</code> is returned.</p>
+    fn get_template_summary(
+        &self,
+        input: GetTemplateSummaryInput,
+    ) -> RusotoFuture<GetTemplateSummaryOutput, GetTemplateSummaryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetTemplateSummary");
+        let mut params = Params::new();
+
+        params.put("Action", "GetTemplateSummary");
+        params.put("Version", "2010-05-15");
+        GetTemplateSummaryInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTemplateSummaryError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetTemplateSummaryOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetTemplateSummaryOutputDeserializer::deserialize(
+                        "GetTemplateSummaryResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetTemplateSummary", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the ID and status of each active change set for a stack. For example, AWS CloudFormation lists change sets that are in the <code>CREATE_IN_PROGRESS</code> or <code>CREATE_PENDING</code> state.</p>
+    fn list_change_sets(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> RusotoFuture<ListChangeSetsOutput, ListChangeSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListChangeSets");
+        let mut params = Params::new();
+
+        params.put("Action", "ListChangeSets");
+        params.put("Version", "2010-05-15");
+        ListChangeSetsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListChangeSetsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListChangeSetsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListChangeSetsOutputDeserializer::deserialize(
+                        "ListChangeSetsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListChangeSets", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists all exported output values in the account and region in which you call this action. Use this action to see the exported output values that you can import into other stacks. To import values, use the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/intrinsic-function-reference-importvalue.html"> <code>Fn::ImportValue</code> </a> function. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-exports.html"> AWS CloudFormation Export Stack Output Values</a>.</p>
+    fn list_exports(
+        &self,
+        input: ListExportsInput,
+    ) -> RusotoFuture<ListExportsOutput, ListExportsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListExports");
+        let mut params = Params::new();
+
+        params.put("Action", "ListExports");
+        params.put("Version", "2010-05-15");
+        ListExportsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListExportsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListExportsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        ListExportsOutputDeserializer::deserialize("ListExportsResult", &mut stack)
+                            .map_err(|err| stack.parse_error("ListExports", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists all stacks that are importing an exported output value. To modify or remove an exported output value, first use this action to see which stacks are using it. To see the exported output values in your account, see <a>ListExports</a>. </p> <p>For more information about importing an exported output value, see the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/intrinsic-function-reference-importvalue.html"> <code>Fn::ImportValue</code> </a> function. </p>
+    fn list_imports(
+        &self,
+        input: ListImportsInput,
+    ) -> RusotoFuture<ListImportsOutput, ListImportsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListImports");
+        let mut params = Params::new();
+
+        params.put("Action", "ListImports");
+        params.put("Version", "2010-05-15");
+        ListImportsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListImportsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListImportsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        ListImportsOutputDeserializer::deserialize("ListImportsResult", &mut stack)
+                            .map_err(|err| stack.parse_error("ListImports", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns summary information about stack instances that are associated with the specified stack set. You can filter for stack instances that are associated with a specific AWS account name or region.</p>
+    fn list_stack_instances(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> RusotoFuture<ListStackInstancesOutput, ListStackInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackInstances");
+        let mut params = Params::new();
+
+        params.put("Action", "ListStackInstances");
+        params.put("Version", "2010-05-15");
+        ListStackInstancesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListStackInstancesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListStackInstancesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListStackInstancesOutputDeserializer::deserialize(
+                        "ListStackInstancesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListStackInstances", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns descriptions of all resources of the specified stack.</p> <p>For deleted stacks, ListStackResources returns resource information for up to 90 days after the stack has been deleted.</p>
+    fn list_stack_resources(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> RusotoFuture<ListStackResourcesOutput, ListStackResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackResources");
+        let mut params = Params::new();
+
+        params.put("Action", "ListStackResources");
+        params.put("Version", "2010-05-15");
+        ListStackResourcesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListStackResourcesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListStackResourcesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListStackResourcesOutputDeserializer::deserialize(
+                        "ListStackResourcesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListStackResources", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns summary information about the results of a stack set operation. </p>
+    fn list_stack_set_operation_results(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> RusotoFuture<ListStackSetOperationResultsOutput, ListStackSetOperationResultsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSetOperationResults");
+        let mut params = Params::new();
+
+        params.put("Action", "ListStackSetOperationResults");
+        params.put("Version", "2010-05-15");
+        ListStackSetOperationResultsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListStackSetOperationResultsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListStackSetOperationResultsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListStackSetOperationResultsOutputDeserializer::deserialize(
+                        "ListStackSetOperationResultsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListStackSetOperationResults", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns summary information about operations performed on a stack set. </p>
+    fn list_stack_set_operations(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> RusotoFuture<ListStackSetOperationsOutput, ListStackSetOperationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSetOperations");
+        let mut params = Params::new();
+
+        params.put("Action", "ListStackSetOperations");
+        params.put("Version", "2010-05-15");
+        ListStackSetOperationsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListStackSetOperationsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListStackSetOperationsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListStackSetOperationsOutputDeserializer::deserialize(
+                        "ListStackSetOperationsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListStackSetOperations", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns summary information about stack sets that are associated with the user.</p>
+    fn list_stack_sets(
+        &self,
+        input: ListStackSetsInput,
+    ) -> RusotoFuture<ListStackSetsOutput, ListStackSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSets");
+        let mut params = Params::new();
+
+        params.put("Action", "ListStackSets");
+        params.put("Version", "2010-05-15");
+        ListStackSetsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListStackSetsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListStackSetsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListStackSetsOutputDeserializer::deserialize(
+                        "ListStackSetsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListStackSets", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the summary information for stacks whose status matches the specified StackStatusFilter. Summary information for stacks that have been deleted is kept for 90 days after the stack is deleted. If no StackStatusFilter is specified, summary information for all stacks is returned (including existing stacks and stacks that have been deleted).</p>
+    fn list_stacks(
+        &self,
+        input: ListStacksInput,
+    ) -> RusotoFuture<ListStacksOutput, ListStacksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStacks");
+        let mut params = Params::new();
+
+        params.put("Action", "ListStacks");
+        params.put("Version", "2010-05-15");
+        ListStacksInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListStacksError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListStacksOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        ListStacksOutputDeserializer::deserialize("ListStacksResult", &mut stack)
+                            .map_err(|err| stack.parse_error("ListStacks", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Sets a stack policy for a specified stack.</p>
+    fn set_stack_policy(
+        &self,
+        input: SetStackPolicyInput,
+    ) -> RusotoFuture<(), SetStackPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SetStackPolicy");
+        let mut params = Params::new();
+
+        params.put("Action", "SetStackPolicy");
+        params.put("Version", "2010-05-15");
+        SetStackPolicyInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetStackPolicyError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Sends a signal to the specified resource with a success or failure status. You can use the SignalResource API in conjunction with a creation policy or update policy. AWS CloudFormation doesn't proceed with a stack creation or update until resources receive the required number of signals or the timeout period is exceeded. The SignalResource API is useful in cases where you want to send signals from anywhere other than an Amazon EC2 instance.</p>
+    fn signal_resource(&self, input: SignalResourceInput) -> RusotoFuture<(), SignalResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SignalResource");
+        let mut params = Params::new();
+
+        params.put("Action", "SignalResource");
+        params.put("Version", "2010-05-15");
+        SignalResourceInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SignalResourceError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Stops an in-progress operation on a stack set and its associated stack instances. </p>
+    fn stop_stack_set_operation(
+        &self,
+        input: StopStackSetOperationInput,
+    ) -> RusotoFuture<StopStackSetOperationOutput, StopStackSetOperationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("StopStackSetOperation");
+        let mut params = Params::new();
+
+        params.put("Action", "StopStackSetOperation");
+        params.put("Version", "2010-05-15");
+        StopStackSetOperationInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopStackSetOperationError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = StopStackSetOperationOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = StopStackSetOperationOutputDeserializer::deserialize(
+                        "StopStackSetOperationResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("StopStackSetOperation", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates a stack as specified in the template. After the call completes successfully, the stack update starts. You can check the status of the stack via the <a>DescribeStacks</a> action.</p> <p>To get a copy of the template for an existing stack, you can use the <a>GetTemplate</a> action.</p> <p>For more information about creating an update template, updating a stack, and monitoring the progress of the update, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks.html">Updating a Stack</a>.</p>
+    fn update_stack(
+        &self,
+        input: UpdateStackInput,
+    ) -> RusotoFuture<UpdateStackOutput, UpdateStackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStack");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateStack");
+        params.put("Version", "2010-05-15");
+        UpdateStackInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateStackError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateStackOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        UpdateStackOutputDeserializer::deserialize("UpdateStackResult", &mut stack)
+                            .map_err(|err| stack.parse_error("UpdateStack", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates the parameter values for stack instances for the specified accounts, within the specified regions. A stack instance refers to a stack in a specific account and region. </p> <p>You can only update stack instances in regions and accounts where they already exist; to create additional stack instances, use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_CreateStackInstances.html">CreateStackInstances</a>. </p> <p>During stack set updates, any parameters overridden for a stack instance are not updated, but retain their overridden value.</p> <p>You can only update the parameter <i>values</i> that are specified in the stack set; to add or delete a parameter itself, use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_UpdateStackSet.html">UpdateStackSet</a> to update the stack set template. If you add a parameter to a template, before you can override the parameter value specified in the stack set you must first use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_UpdateStackSet.html">UpdateStackSet</a> to update all stack instances with the updated template and parameter value specified in the stack set. Once a stack instance has been updated with the new parameter, you can then override the parameter value using <code>UpdateStackInstances</code>.</p>
+    fn update_stack_instances(
+        &self,
+        input: UpdateStackInstancesInput,
+    ) -> RusotoFuture<UpdateStackInstancesOutput, UpdateStackInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStackInstances");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateStackInstances");
+        params.put("Version", "2010-05-15");
+        UpdateStackInstancesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateStackInstancesError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateStackInstancesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = UpdateStackInstancesOutputDeserializer::deserialize(
+                        "UpdateStackInstancesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateStackInstances", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates the stack set, and associated stack instances in the specified accounts and regions.</p> <p>Even if the stack set operation created by updating the stack set fails (completely or partially, below or above a specified failure tolerance), the stack set is updated with your changes. Subsequent <a>CreateStackInstances</a> calls on the specified stack set use the updated stack set.</p>
+    fn update_stack_set(
+        &self,
+        input: UpdateStackSetInput,
+    ) -> RusotoFuture<UpdateStackSetOutput, UpdateStackSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStackSet");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateStackSet");
+        params.put("Version", "2010-05-15");
+        UpdateStackSetInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateStackSetError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateStackSetOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = UpdateStackSetOutputDeserializer::deserialize(
+                        "UpdateStackSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("UpdateStackSet", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates termination protection for the specified stack. If a user attempts to delete a stack with termination protection enabled, the operation fails and the stack remains unchanged. For more information, see <a href="AWSCloudFormation/latest/UserGuide/using-cfn-protect-stacks.html">Protecting a Stack From Being Deleted</a> in the <i>AWS CloudFormation User Guide</i>.</p> <p> For <a href="AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">nested stacks</a>, termination protection is set on the root stack and cannot be changed directly on the nested stack.</p>
+    fn update_termination_protection(
+        &self,
+        input: UpdateTerminationProtectionInput,
+    ) -> RusotoFuture<UpdateTerminationProtectionOutput, UpdateTerminationProtectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateTerminationProtection");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateTerminationProtection");
+        params.put("Version", "2010-05-15");
+        UpdateTerminationProtectionInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateTerminationProtectionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateTerminationProtectionOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
                     result = UpdateTerminationProtectionOutputDeserializer::deserialize(
                         "UpdateTerminationProtectionResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateTerminationProtection", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Validates a specified template. AWS CloudFormation first checks if the template is valid JSON. If it isn't, AWS CloudFormation checks if the template is valid YAML. If both these checks fail, AWS CloudFormation returns a template validation error.</p>
+    fn validate_template(
+        &self,
+        input: ValidateTemplateInput,
+    ) -> RusotoFuture<ValidateTemplateOutput, ValidateTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ValidateTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "ValidateTemplate");
+        params.put("Version", "2010-05-15");
+        ValidateTemplateInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ValidateTemplateError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ValidateTemplateOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ValidateTemplateOutputDeserializer::deserialize(
+                        "ValidateTemplateResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ValidateTemplate", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -11930,56 +14788,1545 @@ impl CloudFormation for CloudFormationClient {
             }))
         })
     }
-
-    /// <p>Validates a specified template. AWS CloudFormation first checks if the template is valid JSON. If it isn't, AWS CloudFormation checks if the template is valid YAML. If both these checks fail, AWS CloudFormation returns a template validation error.</p>
+}
+
+/// A mock of the AWS CloudFormation API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloudFormation {
+    cancel_update_stack: Option<
+        Box<
+            dyn Fn(
+                    CancelUpdateStackInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<CancelUpdateStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    continue_update_rollback: Option<
+        Box<
+            dyn Fn(
+                    ContinueUpdateRollbackInput,
+                ) -> ::std::result::Result<
+                    ContinueUpdateRollbackOutput,
+                    RusotoError<ContinueUpdateRollbackError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_change_set: Option<
+        Box<
+            dyn Fn(
+                    CreateChangeSetInput,
+                ) -> ::std::result::Result<
+                    CreateChangeSetOutput,
+                    RusotoError<CreateChangeSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_stack: Option<
+        Box<
+            dyn Fn(
+                    CreateStackInput,
+                )
+                    -> ::std::result::Result<CreateStackOutput, RusotoError<CreateStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_stack_instances: Option<
+        Box<
+            dyn Fn(
+                    CreateStackInstancesInput,
+                ) -> ::std::result::Result<
+                    CreateStackInstancesOutput,
+                    RusotoError<CreateStackInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_stack_set: Option<
+        Box<
+            dyn Fn(
+                    CreateStackSetInput,
+                )
+                    -> ::std::result::Result<CreateStackSetOutput, RusotoError<CreateStackSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_change_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteChangeSetInput,
+                ) -> ::std::result::Result<
+                    DeleteChangeSetOutput,
+                    RusotoError<DeleteChangeSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_stack: Option<
+        Box<
+            dyn Fn(DeleteStackInput) -> ::std::result::Result<(), RusotoError<DeleteStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_stack_instances: Option<
+        Box<
+            dyn Fn(
+                    DeleteStackInstancesInput,
+                ) -> ::std::result::Result<
+                    DeleteStackInstancesOutput,
+                    RusotoError<DeleteStackInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_stack_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteStackSetInput,
+                )
+                    -> ::std::result::Result<DeleteStackSetOutput, RusotoError<DeleteStackSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_account_limits: Option<
+        Box<
+            dyn Fn(
+                    DescribeAccountLimitsInput,
+                ) -> ::std::result::Result<
+                    DescribeAccountLimitsOutput,
+                    RusotoError<DescribeAccountLimitsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_change_set: Option<
+        Box<
+            dyn Fn(
+                    DescribeChangeSetInput,
+                ) -> ::std::result::Result<
+                    DescribeChangeSetOutput,
+                    RusotoError<DescribeChangeSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stack_drift_detection_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeStackDriftDetectionStatusInput,
+                ) -> ::std::result::Result<
+                    DescribeStackDriftDetectionStatusOutput,
+                    RusotoError<DescribeStackDriftDetectionStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stack_events: Option<
+        Box<
+            dyn Fn(
+                    DescribeStackEventsInput,
+                ) -> ::std::result::Result<
+                    DescribeStackEventsOutput,
+                    RusotoError<DescribeStackEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stack_instance: Option<
+        Box<
+            dyn Fn(
+                    DescribeStackInstanceInput,
+                ) -> ::std::result::Result<
+                    DescribeStackInstanceOutput,
+                    RusotoError<DescribeStackInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stack_resource: Option<
+        Box<
+            dyn Fn(
+                    DescribeStackResourceInput,
+                ) -> ::std::result::Result<
+                    DescribeStackResourceOutput,
+                    RusotoError<DescribeStackResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stack_resource_drifts: Option<
+        Box<
+            dyn Fn(
+                    DescribeStackResourceDriftsInput,
+                ) -> ::std::result::Result<
+                    DescribeStackResourceDriftsOutput,
+                    RusotoError<DescribeStackResourceDriftsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stack_resources: Option<
+        Box<
+            dyn Fn(
+                    DescribeStackResourcesInput,
+                ) -> ::std::result::Result<
+                    DescribeStackResourcesOutput,
+                    RusotoError<DescribeStackResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stack_set: Option<
+        Box<
+            dyn Fn(
+                    DescribeStackSetInput,
+                ) -> ::std::result::Result<
+                    DescribeStackSetOutput,
+                    RusotoError<DescribeStackSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stack_set_operation: Option<
+        Box<
+            dyn Fn(
+                    DescribeStackSetOperationInput,
+                ) -> ::std::result::Result<
+                    DescribeStackSetOperationOutput,
+                    RusotoError<DescribeStackSetOperationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stacks: Option<
+        Box<
+            dyn Fn(
+                    DescribeStacksInput,
+                )
+                    -> ::std::result::Result<DescribeStacksOutput, RusotoError<DescribeStacksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    detect_stack_drift: Option<
+        Box<
+            dyn Fn(
+                    DetectStackDriftInput,
+                ) -> ::std::result::Result<
+                    DetectStackDriftOutput,
+                    RusotoError<DetectStackDriftError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detect_stack_resource_drift: Option<
+        Box<
+            dyn Fn(
+                    DetectStackResourceDriftInput,
+                ) -> ::std::result::Result<
+                    DetectStackResourceDriftOutput,
+                    RusotoError<DetectStackResourceDriftError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    estimate_template_cost: Option<
+        Box<
+            dyn Fn(
+                    EstimateTemplateCostInput,
+                ) -> ::std::result::Result<
+                    EstimateTemplateCostOutput,
+                    RusotoError<EstimateTemplateCostError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    execute_change_set: Option<
+        Box<
+            dyn Fn(
+                    ExecuteChangeSetInput,
+                ) -> ::std::result::Result<
+                    ExecuteChangeSetOutput,
+                    RusotoError<ExecuteChangeSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_stack_policy: Option<
+        Box<
+            dyn Fn(
+                    GetStackPolicyInput,
+                )
+                    -> ::std::result::Result<GetStackPolicyOutput, RusotoError<GetStackPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_template: Option<
+        Box<
+            dyn Fn(
+                    GetTemplateInput,
+                )
+                    -> ::std::result::Result<GetTemplateOutput, RusotoError<GetTemplateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_template_summary: Option<
+        Box<
+            dyn Fn(
+                    GetTemplateSummaryInput,
+                ) -> ::std::result::Result<
+                    GetTemplateSummaryOutput,
+                    RusotoError<GetTemplateSummaryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_change_sets: Option<
+        Box<
+            dyn Fn(
+                    ListChangeSetsInput,
+                )
+                    -> ::std::result::Result<ListChangeSetsOutput, RusotoError<ListChangeSetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_exports: Option<
+        Box<
+            dyn Fn(
+                    ListExportsInput,
+                )
+                    -> ::std::result::Result<ListExportsOutput, RusotoError<ListExportsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_imports: Option<
+        Box<
+            dyn Fn(
+                    ListImportsInput,
+                )
+                    -> ::std::result::Result<ListImportsOutput, RusotoError<ListImportsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_stack_instances: Option<
+        Box<
+            dyn Fn(
+                    ListStackInstancesInput,
+                ) -> ::std::result::Result<
+                    ListStackInstancesOutput,
+                    RusotoError<ListStackInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_stack_resources: Option<
+        Box<
+            dyn Fn(
+                    ListStackResourcesInput,
+                ) -> ::std::result::Result<
+                    ListStackResourcesOutput,
+                    RusotoError<ListStackResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_stack_set_operation_results: Option<
+        Box<
+            dyn Fn(
+                    ListStackSetOperationResultsInput,
+                ) -> ::std::result::Result<
+                    ListStackSetOperationResultsOutput,
+                    RusotoError<ListStackSetOperationResultsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_stack_set_operations: Option<
+        Box<
+            dyn Fn(
+                    ListStackSetOperationsInput,
+                ) -> ::std::result::Result<
+                    ListStackSetOperationsOutput,
+                    RusotoError<ListStackSetOperationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_stack_sets: Option<
+        Box<
+            dyn Fn(
+                    ListStackSetsInput,
+                )
+                    -> ::std::result::Result<ListStackSetsOutput, RusotoError<ListStackSetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_stacks: Option<
+        Box<
+            dyn Fn(
+                    ListStacksInput,
+                )
+                    -> ::std::result::Result<ListStacksOutput, RusotoError<ListStacksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_stack_policy: Option<
+        Box<
+            dyn Fn(
+                    SetStackPolicyInput,
+                ) -> ::std::result::Result<(), RusotoError<SetStackPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    signal_resource: Option<
+        Box<
+            dyn Fn(
+                    SignalResourceInput,
+                ) -> ::std::result::Result<(), RusotoError<SignalResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_stack_set_operation: Option<
+        Box<
+            dyn Fn(
+                    StopStackSetOperationInput,
+                ) -> ::std::result::Result<
+                    StopStackSetOperationOutput,
+                    RusotoError<StopStackSetOperationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_stack: Option<
+        Box<
+            dyn Fn(
+                    UpdateStackInput,
+                )
+                    -> ::std::result::Result<UpdateStackOutput, RusotoError<UpdateStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_stack_instances: Option<
+        Box<
+            dyn Fn(
+                    UpdateStackInstancesInput,
+                ) -> ::std::result::Result<
+                    UpdateStackInstancesOutput,
+                    RusotoError<UpdateStackInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_stack_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateStackSetInput,
+                )
+                    -> ::std::result::Result<UpdateStackSetOutput, RusotoError<UpdateStackSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_termination_protection: Option<
+        Box<
+            dyn Fn(
+                    UpdateTerminationProtectionInput,
+                ) -> ::std::result::Result<
+                    UpdateTerminationProtectionOutput,
+                    RusotoError<UpdateTerminationProtectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    validate_template: Option<
+        Box<
+            dyn Fn(
+                    ValidateTemplateInput,
+                ) -> ::std::result::Result<
+                    ValidateTemplateOutput,
+                    RusotoError<ValidateTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloudFormation {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloudFormation {
+        Default::default()
+    }
+
+    /// Answers `cancel_update_stack` with what `handler` returns for its input.
+    pub fn with_cancel_update_stack<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                CancelUpdateStackInput,
+            ) -> ::std::result::Result<(), RusotoError<CancelUpdateStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_update_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `continue_update_rollback` with what `handler` returns for its input.
+    pub fn with_continue_update_rollback<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ContinueUpdateRollbackInput,
+            ) -> ::std::result::Result<
+                ContinueUpdateRollbackOutput,
+                RusotoError<ContinueUpdateRollbackError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.continue_update_rollback = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_change_set` with what `handler` returns for its input.
+    pub fn with_create_change_set<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                CreateChangeSetInput,
+            )
+                -> ::std::result::Result<CreateChangeSetOutput, RusotoError<CreateChangeSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_change_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_stack` with what `handler` returns for its input.
+    pub fn with_create_stack<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                CreateStackInput,
+            )
+                -> ::std::result::Result<CreateStackOutput, RusotoError<CreateStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_stack_instances` with what `handler` returns for its input.
+    pub fn with_create_stack_instances<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                CreateStackInstancesInput,
+            ) -> ::std::result::Result<
+                CreateStackInstancesOutput,
+                RusotoError<CreateStackInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_stack_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_stack_set` with what `handler` returns for its input.
+    pub fn with_create_stack_set<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                CreateStackSetInput,
+            )
+                -> ::std::result::Result<CreateStackSetOutput, RusotoError<CreateStackSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_stack_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_change_set` with what `handler` returns for its input.
+    pub fn with_delete_change_set<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DeleteChangeSetInput,
+            )
+                -> ::std::result::Result<DeleteChangeSetOutput, RusotoError<DeleteChangeSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_change_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_stack` with what `handler` returns for its input.
+    pub fn with_delete_stack<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(DeleteStackInput) -> ::std::result::Result<(), RusotoError<DeleteStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_stack_instances` with what `handler` returns for its input.
+    pub fn with_delete_stack_instances<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DeleteStackInstancesInput,
+            ) -> ::std::result::Result<
+                DeleteStackInstancesOutput,
+                RusotoError<DeleteStackInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_stack_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_stack_set` with what `handler` returns for its input.
+    pub fn with_delete_stack_set<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DeleteStackSetInput,
+            )
+                -> ::std::result::Result<DeleteStackSetOutput, RusotoError<DeleteStackSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_stack_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account_limits` with what `handler` returns for its input.
+    pub fn with_describe_account_limits<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeAccountLimitsInput,
+            ) -> ::std::result::Result<
+                DescribeAccountLimitsOutput,
+                RusotoError<DescribeAccountLimitsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account_limits = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_change_set` with what `handler` returns for its input.
+    pub fn with_describe_change_set<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeChangeSetInput,
+            ) -> ::std::result::Result<
+                DescribeChangeSetOutput,
+                RusotoError<DescribeChangeSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_change_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stack_drift_detection_status` with what `handler` returns for its input.
+    pub fn with_describe_stack_drift_detection_status<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeStackDriftDetectionStatusInput,
+            ) -> ::std::result::Result<
+                DescribeStackDriftDetectionStatusOutput,
+                RusotoError<DescribeStackDriftDetectionStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stack_drift_detection_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stack_events` with what `handler` returns for its input.
+    pub fn with_describe_stack_events<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeStackEventsInput,
+            ) -> ::std::result::Result<
+                DescribeStackEventsOutput,
+                RusotoError<DescribeStackEventsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stack_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stack_instance` with what `handler` returns for its input.
+    pub fn with_describe_stack_instance<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeStackInstanceInput,
+            ) -> ::std::result::Result<
+                DescribeStackInstanceOutput,
+                RusotoError<DescribeStackInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stack_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stack_resource` with what `handler` returns for its input.
+    pub fn with_describe_stack_resource<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeStackResourceInput,
+            ) -> ::std::result::Result<
+                DescribeStackResourceOutput,
+                RusotoError<DescribeStackResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stack_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stack_resource_drifts` with what `handler` returns for its input.
+    pub fn with_describe_stack_resource_drifts<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeStackResourceDriftsInput,
+            ) -> ::std::result::Result<
+                DescribeStackResourceDriftsOutput,
+                RusotoError<DescribeStackResourceDriftsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stack_resource_drifts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stack_resources` with what `handler` returns for its input.
+    pub fn with_describe_stack_resources<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeStackResourcesInput,
+            ) -> ::std::result::Result<
+                DescribeStackResourcesOutput,
+                RusotoError<DescribeStackResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stack_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stack_set` with what `handler` returns for its input.
+    pub fn with_describe_stack_set<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeStackSetInput,
+            )
+                -> ::std::result::Result<DescribeStackSetOutput, RusotoError<DescribeStackSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stack_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stack_set_operation` with what `handler` returns for its input.
+    pub fn with_describe_stack_set_operation<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeStackSetOperationInput,
+            ) -> ::std::result::Result<
+                DescribeStackSetOperationOutput,
+                RusotoError<DescribeStackSetOperationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stack_set_operation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stacks` with what `handler` returns for its input.
+    pub fn with_describe_stacks<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DescribeStacksInput,
+            )
+                -> ::std::result::Result<DescribeStacksOutput, RusotoError<DescribeStacksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stacks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_stack_drift` with what `handler` returns for its input.
+    pub fn with_detect_stack_drift<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DetectStackDriftInput,
+            )
+                -> ::std::result::Result<DetectStackDriftOutput, RusotoError<DetectStackDriftError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_stack_drift = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_stack_resource_drift` with what `handler` returns for its input.
+    pub fn with_detect_stack_resource_drift<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                DetectStackResourceDriftInput,
+            ) -> ::std::result::Result<
+                DetectStackResourceDriftOutput,
+                RusotoError<DetectStackResourceDriftError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_stack_resource_drift = Some(Box::new(handler));
+        self
+    }
+    /// Answers `estimate_template_cost` with what `handler` returns for its input.
+    pub fn with_estimate_template_cost<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                EstimateTemplateCostInput,
+            ) -> ::std::result::Result<
+                EstimateTemplateCostOutput,
+                RusotoError<EstimateTemplateCostError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.estimate_template_cost = Some(Box::new(handler));
+        self
+    }
+    /// Answers `execute_change_set` with what `handler` returns for its input.
+    pub fn with_execute_change_set<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ExecuteChangeSetInput,
+            )
+                -> ::std::result::Result<ExecuteChangeSetOutput, RusotoError<ExecuteChangeSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.execute_change_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_stack_policy` with what `handler` returns for its input.
+    pub fn with_get_stack_policy<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                GetStackPolicyInput,
+            )
+                -> ::std::result::Result<GetStackPolicyOutput, RusotoError<GetStackPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_stack_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_template` with what `handler` returns for its input.
+    pub fn with_get_template<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                GetTemplateInput,
+            )
+                -> ::std::result::Result<GetTemplateOutput, RusotoError<GetTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_template_summary` with what `handler` returns for its input.
+    pub fn with_get_template_summary<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                GetTemplateSummaryInput,
+            ) -> ::std::result::Result<
+                GetTemplateSummaryOutput,
+                RusotoError<GetTemplateSummaryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_template_summary = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_change_sets` with what `handler` returns for its input.
+    pub fn with_list_change_sets<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ListChangeSetsInput,
+            )
+                -> ::std::result::Result<ListChangeSetsOutput, RusotoError<ListChangeSetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_change_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_exports` with what `handler` returns for its input.
+    pub fn with_list_exports<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ListExportsInput,
+            )
+                -> ::std::result::Result<ListExportsOutput, RusotoError<ListExportsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_exports = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_imports` with what `handler` returns for its input.
+    pub fn with_list_imports<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ListImportsInput,
+            )
+                -> ::std::result::Result<ListImportsOutput, RusotoError<ListImportsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_imports = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_stack_instances` with what `handler` returns for its input.
+    pub fn with_list_stack_instances<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ListStackInstancesInput,
+            ) -> ::std::result::Result<
+                ListStackInstancesOutput,
+                RusotoError<ListStackInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_stack_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_stack_resources` with what `handler` returns for its input.
+    pub fn with_list_stack_resources<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ListStackResourcesInput,
+            ) -> ::std::result::Result<
+                ListStackResourcesOutput,
+                RusotoError<ListStackResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_stack_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_stack_set_operation_results` with what `handler` returns for its input.
+    pub fn with_list_stack_set_operation_results<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ListStackSetOperationResultsInput,
+            ) -> ::std::result::Result<
+                ListStackSetOperationResultsOutput,
+                RusotoError<ListStackSetOperationResultsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_stack_set_operation_results = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_stack_set_operations` with what `handler` returns for its input.
+    pub fn with_list_stack_set_operations<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ListStackSetOperationsInput,
+            ) -> ::std::result::Result<
+                ListStackSetOperationsOutput,
+                RusotoError<ListStackSetOperationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_stack_set_operations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_stack_sets` with what `handler` returns for its input.
+    pub fn with_list_stack_sets<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ListStackSetsInput,
+            )
+                -> ::std::result::Result<ListStackSetsOutput, RusotoError<ListStackSetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_stack_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_stacks` with what `handler` returns for its input.
+    pub fn with_list_stacks<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ListStacksInput,
+            ) -> ::std::result::Result<ListStacksOutput, RusotoError<ListStacksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_stacks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_stack_policy` with what `handler` returns for its input.
+    pub fn with_set_stack_policy<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(SetStackPolicyInput) -> ::std::result::Result<(), RusotoError<SetStackPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_stack_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `signal_resource` with what `handler` returns for its input.
+    pub fn with_signal_resource<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(SignalResourceInput) -> ::std::result::Result<(), RusotoError<SignalResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.signal_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_stack_set_operation` with what `handler` returns for its input.
+    pub fn with_stop_stack_set_operation<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                StopStackSetOperationInput,
+            ) -> ::std::result::Result<
+                StopStackSetOperationOutput,
+                RusotoError<StopStackSetOperationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_stack_set_operation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_stack` with what `handler` returns for its input.
+    pub fn with_update_stack<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                UpdateStackInput,
+            )
+                -> ::std::result::Result<UpdateStackOutput, RusotoError<UpdateStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_stack_instances` with what `handler` returns for its input.
+    pub fn with_update_stack_instances<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                UpdateStackInstancesInput,
+            ) -> ::std::result::Result<
+                UpdateStackInstancesOutput,
+                RusotoError<UpdateStackInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_stack_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_stack_set` with what `handler` returns for its input.
+    pub fn with_update_stack_set<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                UpdateStackSetInput,
+            )
+                -> ::std::result::Result<UpdateStackSetOutput, RusotoError<UpdateStackSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_stack_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_termination_protection` with what `handler` returns for its input.
+    pub fn with_update_termination_protection<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                UpdateTerminationProtectionInput,
+            ) -> ::std::result::Result<
+                UpdateTerminationProtectionOutput,
+                RusotoError<UpdateTerminationProtectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_termination_protection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `validate_template` with what `handler` returns for its input.
+    pub fn with_validate_template<F>(mut self, handler: F) -> MockCloudFormation
+    where
+        F: Fn(
+                ValidateTemplateInput,
+            )
+                -> ::std::result::Result<ValidateTemplateOutput, RusotoError<ValidateTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.validate_template = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CloudFormation for MockCloudFormation {
+    fn cancel_update_stack(
+        &self,
+        input: CancelUpdateStackInput,
+    ) -> RusotoFuture<(), CancelUpdateStackError> {
+        match self.cancel_update_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for cancel_update_stack"),
+        }
+    }
+    fn continue_update_rollback(
+        &self,
+        input: ContinueUpdateRollbackInput,
+    ) -> RusotoFuture<ContinueUpdateRollbackOutput, ContinueUpdateRollbackError> {
+        match self.continue_update_rollback {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for continue_update_rollback"),
+        }
+    }
+    fn create_change_set(
+        &self,
+        input: CreateChangeSetInput,
+    ) -> RusotoFuture<CreateChangeSetOutput, CreateChangeSetError> {
+        match self.create_change_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for create_change_set"),
+        }
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackInput,
+    ) -> RusotoFuture<CreateStackOutput, CreateStackError> {
+        match self.create_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for create_stack"),
+        }
+    }
+    fn create_stack_instances(
+        &self,
+        input: CreateStackInstancesInput,
+    ) -> RusotoFuture<CreateStackInstancesOutput, CreateStackInstancesError> {
+        match self.create_stack_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for create_stack_instances"),
+        }
+    }
+    fn create_stack_set(
+        &self,
+        input: CreateStackSetInput,
+    ) -> RusotoFuture<CreateStackSetOutput, CreateStackSetError> {
+        match self.create_stack_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for create_stack_set"),
+        }
+    }
+    fn delete_change_set(
+        &self,
+        input: DeleteChangeSetInput,
+    ) -> RusotoFuture<DeleteChangeSetOutput, DeleteChangeSetError> {
+        match self.delete_change_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for delete_change_set"),
+        }
+    }
+    fn delete_stack(&self, input: DeleteStackInput) -> RusotoFuture<(), DeleteStackError> {
+        match self.delete_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for delete_stack"),
+        }
+    }
+    fn delete_stack_instances(
+        &self,
+        input: DeleteStackInstancesInput,
+    ) -> RusotoFuture<DeleteStackInstancesOutput, DeleteStackInstancesError> {
+        match self.delete_stack_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for delete_stack_instances"),
+        }
+    }
+    fn delete_stack_set(
+        &self,
+        input: DeleteStackSetInput,
+    ) -> RusotoFuture<DeleteStackSetOutput, DeleteStackSetError> {
+        match self.delete_stack_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for delete_stack_set"),
+        }
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        match self.describe_account_limits {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for describe_account_limits"),
+        }
+    }
+    fn describe_change_set(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> RusotoFuture<DescribeChangeSetOutput, DescribeChangeSetError> {
+        match self.describe_change_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for describe_change_set"),
+        }
+    }
+    fn describe_stack_drift_detection_status(
+        &self,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> RusotoFuture<DescribeStackDriftDetectionStatusOutput, DescribeStackDriftDetectionStatusError>
+    {
+        match self.describe_stack_drift_detection_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCloudFormation has no handler for describe_stack_drift_detection_status"
+            ),
+        }
+    }
+    fn describe_stack_events(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> RusotoFuture<DescribeStackEventsOutput, DescribeStackEventsError> {
+        match self.describe_stack_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for describe_stack_events"),
+        }
+    }
+    fn describe_stack_instance(
+        &self,
+        input: DescribeStackInstanceInput,
+    ) -> RusotoFuture<DescribeStackInstanceOutput, DescribeStackInstanceError> {
+        match self.describe_stack_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for describe_stack_instance"),
+        }
+    }
+    fn describe_stack_resource(
+        &self,
+        input: DescribeStackResourceInput,
+    ) -> RusotoFuture<DescribeStackResourceOutput, DescribeStackResourceError> {
+        match self.describe_stack_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for describe_stack_resource"),
+        }
+    }
+    fn describe_stack_resource_drifts(
+        &self,
+        input: DescribeStackResourceDriftsInput,
+    ) -> RusotoFuture<DescribeStackResourceDriftsOutput, DescribeStackResourceDriftsError> {
+        match self.describe_stack_resource_drifts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for describe_stack_resource_drifts"),
+        }
+    }
+    fn describe_stack_resources(
+        &self,
+        input: DescribeStackResourcesInput,
+    ) -> RusotoFuture<DescribeStackResourcesOutput, DescribeStackResourcesError> {
+        match self.describe_stack_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for describe_stack_resources"),
+        }
+    }
+    fn describe_stack_set(
+        &self,
+        input: DescribeStackSetInput,
+    ) -> RusotoFuture<DescribeStackSetOutput, DescribeStackSetError> {
+        match self.describe_stack_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for describe_stack_set"),
+        }
+    }
+    fn describe_stack_set_operation(
+        &self,
+        input: DescribeStackSetOperationInput,
+    ) -> RusotoFuture<DescribeStackSetOperationOutput, DescribeStackSetOperationError> {
+        match self.describe_stack_set_operation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for describe_stack_set_operation"),
+        }
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksInput,
+    ) -> RusotoFuture<DescribeStacksOutput, DescribeStacksError> {
+        match self.describe_stacks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for describe_stacks"),
+        }
+    }
+    fn detect_stack_drift(
+        &self,
+        input: DetectStackDriftInput,
+    ) -> RusotoFuture<DetectStackDriftOutput, DetectStackDriftError> {
+        match self.detect_stack_drift {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for detect_stack_drift"),
+        }
+    }
+    fn detect_stack_resource_drift(
+        &self,
+        input: DetectStackResourceDriftInput,
+    ) -> RusotoFuture<DetectStackResourceDriftOutput, DetectStackResourceDriftError> {
+        match self.detect_stack_resource_drift {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for detect_stack_resource_drift"),
+        }
+    }
+    fn estimate_template_cost(
+        &self,
+        input: EstimateTemplateCostInput,
+    ) -> RusotoFuture<EstimateTemplateCostOutput, EstimateTemplateCostError> {
+        match self.estimate_template_cost {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for estimate_template_cost"),
+        }
+    }
+    fn execute_change_set(
+        &self,
+        input: ExecuteChangeSetInput,
+    ) -> RusotoFuture<ExecuteChangeSetOutput, ExecuteChangeSetError> {
+        match self.execute_change_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for execute_change_set"),
+        }
+    }
+    fn get_stack_policy(
+        &self,
+        input: GetStackPolicyInput,
+    ) -> RusotoFuture<GetStackPolicyOutput, GetStackPolicyError> {
+        match self.get_stack_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for get_stack_policy"),
+        }
+    }
+    fn get_template(
+        &self,
+        input: GetTemplateInput,
+    ) -> RusotoFuture<GetTemplateOutput, GetTemplateError> {
+        match self.get_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for get_template"),
+        }
+    }
+    fn get_template_summary(
+        &self,
+        input: GetTemplateSummaryInput,
+    ) -> RusotoFuture<GetTemplateSummaryOutput, GetTemplateSummaryError> {
+        match self.get_template_summary {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for get_template_summary"),
+        }
+    }
+    fn list_change_sets(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> RusotoFuture<ListChangeSetsOutput, ListChangeSetsError> {
+        match self.list_change_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for list_change_sets"),
+        }
+    }
+    fn list_exports(
+        &self,
+        input: ListExportsInput,
+    ) -> RusotoFuture<ListExportsOutput, ListExportsError> {
+        match self.list_exports {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for list_exports"),
+        }
+    }
+    fn list_imports(
+        &self,
+        input: ListImportsInput,
+    ) -> RusotoFuture<ListImportsOutput, ListImportsError> {
+        match self.list_imports {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for list_imports"),
+        }
+    }
+    fn list_stack_instances(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> RusotoFuture<ListStackInstancesOutput, ListStackInstancesError> {
+        match self.list_stack_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for list_stack_instances"),
+        }
+    }
+    fn list_stack_resources(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> RusotoFuture<ListStackResourcesOutput, ListStackResourcesError> {
+        match self.list_stack_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for list_stack_resources"),
+        }
+    }
+    fn list_stack_set_operation_results(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> RusotoFuture<ListStackSetOperationResultsOutput, ListStackSetOperationResultsError> {
+        match self.list_stack_set_operation_results {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudFormation has no handler for list_stack_set_operation_results")
+            }
+        }
+    }
+    fn list_stack_set_operations(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> RusotoFuture<ListStackSetOperationsOutput, ListStackSetOperationsError> {
+        match self.list_stack_set_operations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for list_stack_set_operations"),
+        }
+    }
+    fn list_stack_sets(
+        &self,
+        input: ListStackSetsInput,
+    ) -> RusotoFuture<ListStackSetsOutput, ListStackSetsError> {
+        match self.list_stack_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for list_stack_sets"),
+        }
+    }
+    fn list_stacks(
+        &self,
+        input: ListStacksInput,
+    ) -> RusotoFuture<ListStacksOutput, ListStacksError> {
+        match self.list_stacks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for list_stacks"),
+        }
+    }
+    fn set_stack_policy(
+        &self,
+        input: SetStackPolicyInput,
+    ) -> RusotoFuture<(), SetStackPolicyError> {
+        match self.set_stack_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for set_stack_policy"),
+        }
+    }
+    fn signal_resource(&self, input: SignalResourceInput) -> RusotoFuture<(), SignalResourceError> {
+        match self.signal_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for signal_resource"),
+        }
+    }
+    fn stop_stack_set_operation(
+        &self,
+        input: StopStackSetOperationInput,
+    ) -> RusotoFuture<StopStackSetOperationOutput, StopStackSetOperationError> {
+        match self.stop_stack_set_operation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for stop_stack_set_operation"),
+        }
+    }
+    fn update_stack(
+        &self,
+        input: UpdateStackInput,
+    ) -> RusotoFuture<UpdateStackOutput, UpdateStackError> {
+        match self.update_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for update_stack"),
+        }
+    }
+    fn update_stack_instances(
+        &self,
+        input: UpdateStackInstancesInput,
+    ) -> RusotoFuture<UpdateStackInstancesOutput, UpdateStackInstancesError> {
+        match self.update_stack_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for update_stack_instances"),
+        }
+    }
+    fn update_stack_set(
+        &self,
+        input: UpdateStackSetInput,
+    ) -> RusotoFuture<UpdateStackSetOutput, UpdateStackSetError> {
+        match self.update_stack_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for update_stack_set"),
+        }
+    }
+    fn update_termination_protection(
+        &self,
+        input: UpdateTerminationProtectionInput,
+    ) -> RusotoFuture<UpdateTerminationProtectionOutput, UpdateTerminationProtectionError> {
+        match self.update_termination_protection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for update_termination_protection"),
+        }
+    }
     fn validate_template(
         &self,
         input: ValidateTemplateInput,
     ) -> RusotoFuture<ValidateTemplateOutput, ValidateTemplateError> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ValidateTemplate");
-        params.put("Version", "2010-05-15");
-        ValidateTemplateInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ValidateTemplateError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ValidateTemplateOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ValidateTemplateOutputDeserializer::deserialize(
-                        "ValidateTemplateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.validate_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFormation has no handler for validate_template"),
+        }
     }
 }
 
@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use std::io::Write;
 use std::str::FromStr;
 use xml;
@@ -37,6 +38,8 @@ use xml::EventWriter;
 
 /// <p>A complex type that lists the AWS accounts, if any, that you included in the <code>TrustedSigners</code> complex type for this distribution. These are the accounts that you want to allow to create signed URLs for private content.</p> <p>The <code>Signer</code> complex type lists the AWS account number of the trusted signer or <code>self</code> if the signer is the AWS account that created the distribution. The <code>Signer</code> element also includes the IDs of any active CloudFront key pairs that are associated with the trusted signer's AWS account. If no <code>KeyPairId</code> element appears for a <code>Signer</code>, that signer can't create signed URLs. </p> <p>For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ActiveTrustedSigners {
     /// <p>Enabled is <code>true</code> if any of the AWS accounts listed in the <code>TrustedSigners</code> complex type for this RTMP distribution have active CloudFront key pairs. If not, <code>Enabled</code> is <code>false</code>.</p> <p>For more information, see <a>ActiveTrustedSigners</a>.</p>
     pub enabled: bool,
@@ -112,6 +115,8 @@ impl AliasListSerializer {
 
 /// <p>A complex type that contains information about CNAMEs (alternate domain names), if any, for this distribution. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Aliases {
     /// <p>A complex type that contains the CNAME aliases, if any, that you want to associate with this distribution.</p>
     pub items: Option<Vec<String>>,
@@ -170,6 +175,8 @@ impl AliasesSerializer {
 
 /// <p>A complex type that controls which HTTP methods CloudFront processes and forwards to your Amazon S3 bucket or your custom origin. There are three choices:</p> <ul> <li> <p>CloudFront forwards only <code>GET</code> and <code>HEAD</code> requests.</p> </li> <li> <p>CloudFront forwards only <code>GET</code>, <code>HEAD</code>, and <code>OPTIONS</code> requests.</p> </li> <li> <p>CloudFront forwards <code>GET, HEAD, OPTIONS, PUT, PATCH, POST</code>, and <code>DELETE</code> requests.</p> </li> </ul> <p>If you pick the third choice, you may need to restrict access to your Amazon S3 bucket or to your custom origin so users can't perform operations that you don't want them to. For example, you might not want users to have permissions to delete objects from your origin.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AllowedMethods {
     pub cached_methods: Option<CachedMethods>,
     /// <p>A complex type that contains the HTTP methods that you want CloudFront to process and forward to your origin.</p>
@@ -276,7 +283,7 @@ impl BooleanDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -305,6 +312,8 @@ impl BooleanSerializer {
 
 /// <p>A complex type that describes how CloudFront processes requests.</p> <p>You must create at least as many cache behaviors (including the default cache behavior) as you have origins if you want CloudFront to distribute objects from all of the origins. Each cache behavior specifies the one origin from which you want CloudFront to get objects. If you have two origins and only the default cache behavior, the default cache behavior will cause CloudFront to get objects from one of the origins, but the other origin is never used.</p> <p>For the current limit on the number of cache behaviors that you can add to a distribution, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_cloudfront">Amazon CloudFront Limits</a> in the <i>AWS General Reference</i>.</p> <p>If you don't want to specify any cache behaviors, include only an empty <code>CacheBehaviors</code> element. Don't include an empty <code>CacheBehavior</code> element, or CloudFront returns a <code>MalformedXML</code> error.</p> <p>To delete all cache behaviors in an existing distribution, update the distribution configuration and include only an empty <code>CacheBehaviors</code> element.</p> <p>To add, change, or remove one or more cache behaviors, update the distribution configuration and specify all of the cache behaviors that you want to include in the updated distribution.</p> <p>For more information about cache behaviors, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-web-values-specify.html#DownloadDistValuesCacheBehavior">Cache Behaviors</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheBehavior {
     pub allowed_methods: Option<AllowedMethods>,
     /// <p>Whether you want CloudFront to automatically compress certain files for this cache behavior. If so, specify true; if not, specify false. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/ServingCompressedFiles.html">Serving Compressed Files</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
@@ -546,6 +555,8 @@ impl CacheBehaviorListSerializer {
 
 /// <p>A complex type that contains zero or more <code>CacheBehavior</code> elements. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheBehaviors {
     /// <p>Optional: A complex type that contains cache behaviors for this distribution. If <code>Quantity</code> is <code>0</code>, you can omit <code>Items</code>.</p>
     pub items: Option<Vec<CacheBehavior>>,
@@ -604,6 +615,8 @@ impl CacheBehaviorsSerializer {
 
 /// <p>A complex type that controls whether CloudFront caches the response to requests using the specified HTTP methods. There are two choices:</p> <ul> <li> <p>CloudFront caches responses to <code>GET</code> and <code>HEAD</code> requests.</p> </li> <li> <p>CloudFront caches responses to <code>GET</code>, <code>HEAD</code>, and <code>OPTIONS</code> requests.</p> </li> </ul> <p>If you pick the second choice for your Amazon S3 Origin, you may need to forward Access-Control-Request-Method, Access-Control-Request-Headers, and Origin headers for the responses to be cached correctly. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CachedMethods {
     /// <p>A complex type that contains the HTTP methods that you want CloudFront to cache responses to.</p>
     pub items: Vec<String>,
@@ -659,6 +672,8 @@ impl CachedMethodsSerializer {
 
 /// <p>CloudFront origin access identity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudFrontOriginAccessIdentity {
     /// <p>The current configuration information for the identity. </p>
     pub cloud_front_origin_access_identity_config: Option<CloudFrontOriginAccessIdentityConfig>,
@@ -704,6 +719,8 @@ impl CloudFrontOriginAccessIdentityDeserializer {
 }
 /// <p>Origin access identity configuration. Send a <code>GET</code> request to the <code>/<i>CloudFront API version</i>/CloudFront/identity ID/config</code> resource. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudFrontOriginAccessIdentityConfig {
     /// <p>A unique value (for example, a date-time stamp) that ensures that the request can't be replayed.</p> <p>If the value of <code>CallerReference</code> is new (regardless of the content of the <code>CloudFrontOriginAccessIdentityConfig</code> object), a new origin access identity is created.</p> <p>If the <code>CallerReference</code> is a value already sent in a previous identity request, and the content of the <code>CloudFrontOriginAccessIdentityConfig</code> is identical to the original request (ignoring white space), the response includes the same information returned to the original request. </p> <p>If the <code>CallerReference</code> is a value you already sent in a previous request to create an identity, but the content of the <code>CloudFrontOriginAccessIdentityConfig</code> is different from the original request, CloudFront returns a <code>CloudFrontOriginAccessIdentityAlreadyExists</code> error. </p>
     pub caller_reference: String,
@@ -768,6 +785,8 @@ impl CloudFrontOriginAccessIdentityConfigSerializer {
 
 /// <p>Lists the origin access identities for CloudFront.Send a <code>GET</code> request to the <code>/<i>CloudFront API version</i>/origin-access-identity/cloudfront</code> resource. The response includes a <code>CloudFrontOriginAccessIdentityList</code> element with zero or more <code>CloudFrontOriginAccessIdentitySummary</code> child elements. By default, your entire list of origin access identities is returned in one single page. If the list is long, you can paginate it using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudFrontOriginAccessIdentityList {
     /// <p>A flag that indicates whether more origin access identities remain to be listed. If your results were truncated, you can make a follow-up pagination request using the <code>Marker</code> request parameter to retrieve more items in the list.</p>
     pub is_truncated: bool,
@@ -827,6 +846,8 @@ impl CloudFrontOriginAccessIdentityListDeserializer {
 }
 /// <p>Summary of the information about a CloudFront origin access identity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudFrontOriginAccessIdentitySummary {
     /// <p>The comment for this origin access identity, as originally specified when created.</p>
     pub comment: String,
@@ -889,6 +910,8 @@ impl CloudFrontOriginAccessIdentitySummaryListDeserializer {
 }
 /// <p>A field-level encryption content type profile. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContentTypeProfile {
     /// <p>The content type for a field-level encryption content type-profile mapping. </p>
     pub content_type: String,
@@ -961,6 +984,8 @@ impl ContentTypeProfileSerializer {
 
 /// <p>The configuration for a field-level encryption content type-profile mapping. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContentTypeProfileConfig {
     /// <p>The configuration for a field-level encryption content type-profile. </p>
     pub content_type_profiles: Option<ContentTypeProfiles>,
@@ -1072,6 +1097,8 @@ impl ContentTypeProfileListSerializer {
 
 /// <p>Field-level encryption content type-profile. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContentTypeProfiles {
     /// <p>Items in a field-level encryption content type-profile mapping. </p>
     pub items: Option<Vec<ContentTypeProfile>>,
@@ -1168,6 +1195,8 @@ impl CookieNameListSerializer {
 
 /// <p>A complex type that specifies whether you want CloudFront to forward cookies to the origin and, if so, which ones. For more information about forwarding cookies to the origin, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Cookies.html">How CloudFront Forwards, Caches, and Logs Cookies</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CookieNames {
     /// <p>A complex type that contains one <code>Name</code> element for each cookie that you want CloudFront to forward to the origin for this cache behavior.</p>
     pub items: Option<Vec<String>>,
@@ -1226,6 +1255,8 @@ impl CookieNamesSerializer {
 
 /// <p>A complex type that specifies whether you want CloudFront to forward cookies to the origin and, if so, which ones. For more information about forwarding cookies to the origin, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Cookies.html">How CloudFront Forwards, Caches, and Logs Cookies</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CookiePreference {
     /// <p>Specifies which cookies to forward to the origin for this cache behavior: all, none, or the list of cookies specified in the <code>WhitelistedNames</code> complex type.</p> <p>Amazon S3 doesn't process cookies. When the cache behavior is forwarding requests to an Amazon S3 origin, specify none for the <code>Forward</code> element. </p>
     pub forward: String,
@@ -1285,6 +1316,8 @@ impl CookiePreferenceSerializer {
 
 /// <p>The request to create a new origin access identity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCloudFrontOriginAccessIdentityRequest {
     /// <p>The current configuration information for the identity.</p>
     pub cloud_front_origin_access_identity_config: CloudFrontOriginAccessIdentityConfig,
@@ -1292,6 +1325,8 @@ pub struct CreateCloudFrontOriginAccessIdentityRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCloudFrontOriginAccessIdentityResult {
     /// <p>The origin access identity's information.</p>
     pub cloud_front_origin_access_identity: Option<CloudFrontOriginAccessIdentity>,
@@ -1321,13 +1356,24 @@ impl CreateCloudFrontOriginAccessIdentityResultDeserializer {
 }
 /// <p>The request to create a new distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDistributionRequest {
     /// <p>The distribution's configuration information.</p>
     pub distribution_config: DistributionConfig,
 }
 
+impl Validate for CreateDistributionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.distribution_config
+            .validate_at(&field_path(path, "distribution_config"), errors);
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDistributionResult {
     /// <p>The distribution's information.</p>
     pub distribution: Option<Distribution>,
@@ -1355,13 +1401,24 @@ impl CreateDistributionResultDeserializer {
 }
 /// <p>The request to create a new distribution with tags. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDistributionWithTagsRequest {
     /// <p>The distribution's configuration information. </p>
     pub distribution_config_with_tags: DistributionConfigWithTags,
 }
 
+impl Validate for CreateDistributionWithTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.distribution_config_with_tags
+            .validate_at(&field_path(path, "distribution_config_with_tags"), errors);
+    }
+}
+
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDistributionWithTagsResult {
     /// <p>The distribution's information. </p>
     pub distribution: Option<Distribution>,
@@ -1388,12 +1445,16 @@ impl CreateDistributionWithTagsResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFieldLevelEncryptionConfigRequest {
     /// <p>The request to create a new field-level encryption configuration.</p>
     pub field_level_encryption_config: FieldLevelEncryptionConfig,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFieldLevelEncryptionConfigResult {
     /// <p>The current version of the field level encryption configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -1420,12 +1481,16 @@ impl CreateFieldLevelEncryptionConfigResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFieldLevelEncryptionProfileRequest {
     /// <p>The request to create a field-level encryption profile.</p>
     pub field_level_encryption_profile_config: FieldLevelEncryptionProfileConfig,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFieldLevelEncryptionProfileResult {
     /// <p>The current version of the field level encryption profile. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -1455,6 +1520,8 @@ impl CreateFieldLevelEncryptionProfileResultDeserializer {
 }
 /// <p>The request to create an invalidation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInvalidationRequest {
     /// <p>The distribution's id.</p>
     pub distribution_id: String,
@@ -1464,6 +1531,8 @@ pub struct CreateInvalidationRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInvalidationResult {
     /// <p>The invalidation's information.</p>
     pub invalidation: Option<Invalidation>,
@@ -1488,12 +1557,16 @@ impl CreateInvalidationResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePublicKeyRequest {
     /// <p>The request to add a public key to CloudFront.</p>
     pub public_key_config: PublicKeyConfig,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePublicKeyResult {
     /// <p>The current version of the public key. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -1518,6 +1591,8 @@ impl CreatePublicKeyResultDeserializer {
 }
 /// <p>The request to create a new streaming distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStreamingDistributionRequest {
     /// <p>The streaming distribution's configuration information.</p>
     pub streaming_distribution_config: StreamingDistributionConfig,
@@ -1525,6 +1600,8 @@ pub struct CreateStreamingDistributionRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStreamingDistributionResult {
     /// <p>The current version of the streaming distribution created.</p>
     pub e_tag: Option<String>,
@@ -1552,13 +1629,26 @@ impl CreateStreamingDistributionResultDeserializer {
 }
 /// <p>The request to create a new streaming distribution with tags.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStreamingDistributionWithTagsRequest {
     /// <p> The streaming distribution's configuration information. </p>
     pub streaming_distribution_config_with_tags: StreamingDistributionConfigWithTags,
 }
 
+impl Validate for CreateStreamingDistributionWithTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.streaming_distribution_config_with_tags.validate_at(
+            &field_path(path, "streaming_distribution_config_with_tags"),
+            errors,
+        );
+    }
+}
+
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStreamingDistributionWithTagsResult {
     /// <p>The current version of the distribution created.</p>
     pub e_tag: Option<String>,
@@ -1586,6 +1676,8 @@ impl CreateStreamingDistributionWithTagsResultDeserializer {
 }
 /// <p>A complex type that controls:</p> <ul> <li> <p>Whether CloudFront replaces HTTP status codes in the 4xx and 5xx range with custom error messages before returning the response to the viewer. </p> </li> <li> <p>How long CloudFront caches HTTP status codes in the 4xx and 5xx range.</p> </li> </ul> <p>For more information about custom error pages, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/custom-error-pages.html">Customizing Error Responses</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CustomErrorResponse {
     /// <p>The minimum amount of time, in seconds, that you want CloudFront to cache the HTTP status code specified in <code>ErrorCode</code>. When this time period has elapsed, CloudFront queries your origin to see whether the problem that caused the error has been resolved and the requested object is now available.</p> <p>If you don't want to specify a value, include an empty element, <code>&lt;ErrorCachingMinTTL&gt;</code>, in the XML document.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/custom-error-pages.html">Customizing Error Responses</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
     pub error_caching_min_ttl: Option<i64>,
@@ -1717,6 +1809,8 @@ impl CustomErrorResponseListSerializer {
 
 /// <p>A complex type that controls:</p> <ul> <li> <p>Whether CloudFront replaces HTTP status codes in the 4xx and 5xx range with custom error messages before returning the response to the viewer.</p> </li> <li> <p>How long CloudFront caches HTTP status codes in the 4xx and 5xx range.</p> </li> </ul> <p>For more information about custom error pages, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/custom-error-pages.html">Customizing Error Responses</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CustomErrorResponses {
     /// <p>A complex type that contains a <code>CustomErrorResponse</code> element for each HTTP status code for which you want to specify a custom error page and/or a caching duration. </p>
     pub items: Option<Vec<CustomErrorResponse>>,
@@ -1775,6 +1869,8 @@ impl CustomErrorResponsesSerializer {
 
 /// <p>A complex type that contains the list of Custom Headers for each origin. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CustomHeaders {
     /// <p> <b>Optional</b>: A list that contains one <code>OriginCustomHeader</code> element for each custom header that you want CloudFront to forward to the origin. If Quantity is <code>0</code>, omit <code>Items</code>.</p>
     pub items: Option<Vec<OriginCustomHeader>>,
@@ -1833,6 +1929,8 @@ impl CustomHeadersSerializer {
 
 /// <p>A customer origin or an Amazon S3 bucket configured as a website endpoint.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CustomOriginConfig {
     /// <p>The HTTP port the custom origin listens on.</p>
     pub http_port: i64,
@@ -1951,6 +2049,8 @@ impl CustomOriginConfigSerializer {
 
 /// <p>A complex type that describes the default cache behavior if you don't specify a <code>CacheBehavior</code> element or if files don't match any of the values of <code>PathPattern</code> in <code>CacheBehavior</code> elements. You must create exactly one default cache behavior.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefaultCacheBehavior {
     pub allowed_methods: Option<AllowedMethods>,
     /// <p>Whether you want CloudFront to automatically compress certain files for this cache behavior. If so, specify <code>true</code>; if not, specify <code>false</code>. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/ServingCompressedFiles.html">Serving Compressed Files</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
@@ -2139,6 +2239,8 @@ impl DefaultCacheBehaviorSerializer {
 
 /// <p>Deletes a origin access identity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCloudFrontOriginAccessIdentityRequest {
     /// <p>The origin access identity's ID.</p>
     pub id: String,
@@ -2148,6 +2250,8 @@ pub struct DeleteCloudFrontOriginAccessIdentityRequest {
 
 /// <p>This action deletes a web distribution. To delete a web distribution using the CloudFront API, perform the following steps.</p> <p> <b>To delete a web distribution using the CloudFront API:</b> </p> <ol> <li> <p>Disable the web distribution </p> </li> <li> <p>Submit a <code>GET Distribution Config</code> request to get the current configuration and the <code>Etag</code> header for the distribution.</p> </li> <li> <p>Update the XML document that was returned in the response to your <code>GET Distribution Config</code> request to change the value of <code>Enabled</code> to <code>false</code>.</p> </li> <li> <p>Submit a <code>PUT Distribution Config</code> request to update the configuration for your distribution. In the request body, include the XML document that you updated in Step 3. Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to the <code>PUT Distribution Config</code> request to confirm that the distribution was successfully disabled.</p> </li> <li> <p>Submit a <code>GET Distribution</code> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> <li> <p>Submit a <code>DELETE Distribution</code> request. Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Distribution Config</code> request in Step 6.</p> </li> <li> <p>Review the response to your <code>DELETE Distribution</code> request to confirm that the distribution was successfully deleted.</p> </li> </ol> <p>For information about deleting a distribution using the CloudFront console, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/HowToDeleteDistribution.html">Deleting a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDistributionRequest {
     /// <p>The distribution ID. </p>
     pub id: String,
@@ -2156,6 +2260,8 @@ pub struct DeleteDistributionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFieldLevelEncryptionConfigRequest {
     /// <p>The ID of the configuration you want to delete from CloudFront.</p>
     pub id: String,
@@ -2164,6 +2270,8 @@ pub struct DeleteFieldLevelEncryptionConfigRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFieldLevelEncryptionProfileRequest {
     /// <p>Request the ID of the profile you want to delete from CloudFront.</p>
     pub id: String,
@@ -2172,6 +2280,8 @@ pub struct DeleteFieldLevelEncryptionProfileRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePublicKeyRequest {
     /// <p>The ID of the public key you want to remove from CloudFront.</p>
     pub id: String,
@@ -2181,6 +2291,8 @@ pub struct DeletePublicKeyRequest {
 
 /// <p>The request to delete a streaming distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStreamingDistributionRequest {
     /// <p>The distribution ID. </p>
     pub id: String,
@@ -2190,6 +2302,8 @@ pub struct DeleteStreamingDistributionRequest {
 
 /// <p>The distribution's information.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Distribution {
     /// <p>The ARN (Amazon Resource Name) for the distribution. For example: <code>arn:aws:cloudfront::123456789012:distribution/EDFDVBD632BHDS5</code>, where <code>123456789012</code> is your AWS account ID.</p>
     pub arn: String,
@@ -2256,6 +2370,8 @@ impl DistributionDeserializer {
 }
 /// <p>A distribution configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DistributionConfig {
     /// <p>A complex type that contains information about CNAMEs (alternate domain names), if any, for this distribution.</p>
     pub aliases: Option<Aliases>,
@@ -2293,6 +2409,16 @@ pub struct DistributionConfig {
     pub web_acl_id: Option<String>,
 }
 
+impl Validate for DistributionConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.origin_groups {
+            value.validate_at(&field_path(path, "origin_groups"), errors);
+        }
+        self.origins
+            .validate_at(&field_path(path, "origins"), errors);
+    }
+}
+
 struct DistributionConfigDeserializer;
 impl DistributionConfigDeserializer {
     #[allow(unused_variables)]
@@ -2487,6 +2613,8 @@ impl DistributionConfigSerializer {
 
 /// <p>A distribution Configuration and a list of tags to be associated with the distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DistributionConfigWithTags {
     /// <p>A distribution configuration.</p>
     pub distribution_config: DistributionConfig,
@@ -2494,6 +2622,14 @@ pub struct DistributionConfigWithTags {
     pub tags: Tags,
 }
 
+impl Validate for DistributionConfigWithTags {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.distribution_config
+            .validate_at(&field_path(path, "distribution_config"), errors);
+        self.tags.validate_at(&field_path(path, "tags"), errors);
+    }
+}
+
 pub struct DistributionConfigWithTagsSerializer;
 impl DistributionConfigWithTagsSerializer {
     #[allow(unused_variables, warnings)]
@@ -2518,6 +2654,8 @@ impl DistributionConfigWithTagsSerializer {
 
 /// <p>A distribution list.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DistributionList {
     /// <p>A flag that indicates whether more distributions remain to be listed. If your results were truncated, you can make a follow-up pagination request using the <code>Marker</code> request parameter to retrieve more distributions in the list.</p>
     pub is_truncated: bool,
@@ -2570,6 +2708,8 @@ impl DistributionListDeserializer {
 }
 /// <p>A summary of the information about a CloudFront distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DistributionSummary {
     /// <p>The ARN (Amazon Resource Name) for the distribution. For example: <code>arn:aws:cloudfront::123456789012:distribution/EDFDVBD632BHDS5</code>, where <code>123456789012</code> is your AWS account ID.</p>
     pub arn: String,
@@ -2718,6 +2858,8 @@ impl DistributionSummaryListDeserializer {
 }
 /// <p>Complex data type for field-level encryption profiles that includes all of the encryption entities. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EncryptionEntities {
     /// <p>An array of field patterns in a field-level encryption content type-profile mapping. </p>
     pub items: Option<Vec<EncryptionEntity>>,
@@ -2776,6 +2918,8 @@ impl EncryptionEntitiesSerializer {
 
 /// <p>Complex data type for field-level encryption profiles that includes the encryption key and field pattern specifications. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EncryptionEntity {
     /// <p>Field patterns in a field-level encryption content type profile specify the fields that you want to be encrypted. You can provide the full field name, or any beginning characters followed by a wildcard (*). You can't overlap field patterns. For example, you can't have both ABC* and AB*. Note that field patterns are case-sensitive. </p>
     pub field_patterns: FieldPatterns,
@@ -2915,6 +3059,8 @@ impl EventTypeSerializer {
 
 /// <p>A complex data type that includes the profile configurations and other options specified for field-level encryption. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FieldLevelEncryption {
     /// <p>A complex data type that includes the profile configurations specified for field-level encryption. </p>
     pub field_level_encryption_config: FieldLevelEncryptionConfig,
@@ -2955,6 +3101,8 @@ impl FieldLevelEncryptionDeserializer {
 }
 /// <p>A complex data type that includes the profile configurations specified for field-level encryption. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FieldLevelEncryptionConfig {
     /// <p>A unique number that ensures the request can't be replayed.</p>
     pub caller_reference: String,
@@ -3053,6 +3201,8 @@ impl FieldLevelEncryptionConfigSerializer {
 
 /// <p>List of field-level encrpytion configurations.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FieldLevelEncryptionList {
     /// <p>An array of field-level encryption items.</p>
     pub items: Option<Vec<FieldLevelEncryptionSummary>>,
@@ -3102,6 +3252,8 @@ impl FieldLevelEncryptionListDeserializer {
 }
 /// <p>A complex data type for field-level encryption profiles.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FieldLevelEncryptionProfile {
     /// <p>A complex data type that includes the profile name and the encryption entities for the field-level encryption profile.</p>
     pub field_level_encryption_profile_config: FieldLevelEncryptionProfileConfig,
@@ -3146,6 +3298,8 @@ impl FieldLevelEncryptionProfileDeserializer {
 }
 /// <p>A complex data type of profiles for the field-level encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FieldLevelEncryptionProfileConfig {
     /// <p>A unique number that ensures that the request can't be replayed.</p>
     pub caller_reference: String,
@@ -3236,6 +3390,8 @@ impl FieldLevelEncryptionProfileConfigSerializer {
 
 /// <p>List of field-level encryption profiles.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FieldLevelEncryptionProfileList {
     /// <p>The field-level encryption profile items.</p>
     pub items: Option<Vec<FieldLevelEncryptionProfileSummary>>,
@@ -3285,6 +3441,8 @@ impl FieldLevelEncryptionProfileListDeserializer {
 }
 /// <p>The field-level encryption profile summary.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FieldLevelEncryptionProfileSummary {
     /// <p>An optional comment for the field-level encryption profile summary.</p>
     pub comment: Option<String>,
@@ -3358,6 +3516,8 @@ impl FieldLevelEncryptionProfileSummaryListDeserializer {
 }
 /// <p>A summary of a field-level encryption item.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FieldLevelEncryptionSummary {
     /// <p>An optional comment about the field-level encryption item.</p>
     pub comment: Option<String>,
@@ -3474,6 +3634,8 @@ impl FieldPatternListSerializer {
 
 /// <p>A complex data type that includes the field patterns to match for field-level encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FieldPatterns {
     /// <p>An array of the field-level encryption field patterns.</p>
     pub items: Option<Vec<String>>,
@@ -3564,6 +3726,8 @@ impl FormatSerializer {
 
 /// <p>A complex type that specifies how CloudFront handles query strings and cookies.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ForwardedValues {
     /// <p>A complex type that specifies whether you want CloudFront to forward cookies to the origin and, if so, which ones. For more information about forwarding cookies to the origin, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Cookies.html">How CloudFront Forwards, Caches, and Logs Cookies</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
     pub cookies: CookiePreference,
@@ -3638,6 +3802,8 @@ impl ForwardedValuesSerializer {
 
 /// <p>A complex type that controls the countries in which your content is distributed. CloudFront determines the location of your users using <code>MaxMind</code> GeoIP databases. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GeoRestriction {
     /// <p> A complex type that contains a <code>Location</code> element for each country in which you want CloudFront either to distribute your content (<code>whitelist</code>) or not distribute your content (<code>blacklist</code>).</p> <p>The <code>Location</code> element is a two-letter, uppercase country code for a country that you want to include in your <code>blacklist</code> or <code>whitelist</code>. Include one <code>Location</code> element for each country.</p> <p>CloudFront and <code>MaxMind</code> both use <code>ISO 3166</code> country codes. For the current list of countries and the corresponding codes, see <code>ISO 3166-1-alpha-2</code> code on the <i>International Organization for Standardization</i> website. You can also refer to the country list on the CloudFront console, which includes both country names and codes.</p>
     pub items: Option<Vec<String>>,
@@ -3740,6 +3906,8 @@ impl GeoRestrictionTypeSerializer {
 
 /// <p>The origin access identity's configuration information. For more information, see <a>CloudFrontOriginAccessIdentityConfigComplexType</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCloudFrontOriginAccessIdentityConfigRequest {
     /// <p>The identity's ID. </p>
     pub id: String,
@@ -3747,6 +3915,8 @@ pub struct GetCloudFrontOriginAccessIdentityConfigRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCloudFrontOriginAccessIdentityConfigResult {
     /// <p>The origin access identity's configuration information. </p>
     pub cloud_front_origin_access_identity_config: Option<CloudFrontOriginAccessIdentityConfig>,
@@ -3774,6 +3944,8 @@ impl GetCloudFrontOriginAccessIdentityConfigResultDeserializer {
 }
 /// <p>The request to get an origin access identity's information.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCloudFrontOriginAccessIdentityRequest {
     /// <p>The identity's ID.</p>
     pub id: String,
@@ -3781,6 +3953,8 @@ pub struct GetCloudFrontOriginAccessIdentityRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCloudFrontOriginAccessIdentityResult {
     /// <p>The origin access identity's information.</p>
     pub cloud_front_origin_access_identity: Option<CloudFrontOriginAccessIdentity>,
@@ -3808,6 +3982,8 @@ impl GetCloudFrontOriginAccessIdentityResultDeserializer {
 }
 /// <p>The request to get a distribution configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDistributionConfigRequest {
     /// <p>The distribution's ID.</p>
     pub id: String,
@@ -3815,6 +3991,8 @@ pub struct GetDistributionConfigRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDistributionConfigResult {
     /// <p>The distribution's configuration information.</p>
     pub distribution_config: Option<DistributionConfig>,
@@ -3840,6 +4018,8 @@ impl GetDistributionConfigResultDeserializer {
 }
 /// <p>The request to get a distribution's information.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDistributionRequest {
     /// <p>The distribution's ID.</p>
     pub id: String,
@@ -3847,6 +4027,8 @@ pub struct GetDistributionRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDistributionResult {
     /// <p>The distribution's information.</p>
     pub distribution: Option<Distribution>,
@@ -3871,12 +4053,16 @@ impl GetDistributionResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFieldLevelEncryptionConfigRequest {
     /// <p>Request the ID for the field-level encryption configuration information.</p>
     pub id: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFieldLevelEncryptionConfigResult {
     /// <p>The current version of the field level encryption configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -3903,12 +4089,16 @@ impl GetFieldLevelEncryptionConfigResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFieldLevelEncryptionProfileConfigRequest {
     /// <p>Get the ID for the field-level encryption profile configuration information.</p>
     pub id: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFieldLevelEncryptionProfileConfigResult {
     /// <p>The current version of the field-level encryption profile configuration result. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -3935,12 +4125,16 @@ impl GetFieldLevelEncryptionProfileConfigResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFieldLevelEncryptionProfileRequest {
     /// <p>Get the ID for the field-level encryption profile information.</p>
     pub id: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFieldLevelEncryptionProfileResult {
     /// <p>The current version of the field level encryption profile. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -3967,12 +4161,16 @@ impl GetFieldLevelEncryptionProfileResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFieldLevelEncryptionRequest {
     /// <p>Request the ID for the field-level encryption configuration information.</p>
     pub id: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFieldLevelEncryptionResult {
     /// <p>The current version of the field level encryption configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -3998,6 +4196,8 @@ impl GetFieldLevelEncryptionResultDeserializer {
 }
 /// <p>The request to get an invalidation's information. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInvalidationRequest {
     /// <p>The distribution's ID.</p>
     pub distribution_id: String,
@@ -4007,6 +4207,8 @@ pub struct GetInvalidationRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInvalidationResult {
     /// <p>The invalidation's information. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/InvalidationDatatype.html">Invalidation Complex Type</a>. </p>
     pub invalidation: Option<Invalidation>,
@@ -4029,12 +4231,16 @@ impl GetInvalidationResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPublicKeyConfigRequest {
     /// <p>Request the ID for the public key configuration.</p>
     pub id: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPublicKeyConfigResult {
     /// <p>The current version of the public key configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -4059,12 +4265,16 @@ impl GetPublicKeyConfigResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPublicKeyRequest {
     /// <p>Request the ID for the public key.</p>
     pub id: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPublicKeyResult {
     /// <p>The current version of the public key. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -4087,6 +4297,8 @@ impl GetPublicKeyResultDeserializer {
 }
 /// <p>To request to get a streaming distribution configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStreamingDistributionConfigRequest {
     /// <p>The streaming distribution's ID.</p>
     pub id: String,
@@ -4094,6 +4306,8 @@ pub struct GetStreamingDistributionConfigRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStreamingDistributionConfigResult {
     /// <p>The current version of the configuration. For example: <code>E2QWRUHAPOMQZL</code>. </p>
     pub e_tag: Option<String>,
@@ -4121,6 +4335,8 @@ impl GetStreamingDistributionConfigResultDeserializer {
 }
 /// <p>The request to get a streaming distribution's information.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStreamingDistributionRequest {
     /// <p>The streaming distribution's ID.</p>
     pub id: String,
@@ -4128,6 +4344,8 @@ pub struct GetStreamingDistributionRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStreamingDistributionResult {
     /// <p>The current version of the streaming distribution's information. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -4191,6 +4409,8 @@ impl HeaderListSerializer {
 
 /// <p>A complex type that specifies the request headers, if any, that you want CloudFront to base caching on for this cache behavior. </p> <p>For the headers that you specify, CloudFront caches separate versions of a specified object based on the header values in viewer requests. For example, suppose viewer requests for <code>logo.jpg</code> contain a custom <code>product</code> header that has a value of either <code>acme</code> or <code>apex</code>, and you configure CloudFront to cache your content based on values in the <code>product</code> header. CloudFront forwards the <code>product</code> header to the origin and caches the response from the origin once for each header value. For more information about caching based on header values, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/header-caching.html">How CloudFront Forwards and Caches Headers</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Headers {
     /// <p>A list that contains one <code>Name</code> element for each header that you want CloudFront to use for caching in this cache behavior. If <code>Quantity</code> is <code>0</code>, omit <code>Items</code>.</p>
     pub items: Option<Vec<String>>,
@@ -4284,7 +4504,7 @@ impl IntegerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4313,6 +4533,8 @@ impl IntegerSerializer {
 
 /// <p>An invalidation. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Invalidation {
     /// <p>The date and time the invalidation request was first made. </p>
     pub create_time: String,
@@ -4354,6 +4576,8 @@ impl InvalidationDeserializer {
 }
 /// <p>An invalidation batch.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InvalidationBatch {
     /// <p>A value that you specify to uniquely identify an invalidation request. CloudFront uses the value to prevent you from accidentally resubmitting an identical request. Whenever you create a new invalidation request, you must specify a new value for <code>CallerReference</code> and change other values in the request as applicable. One way to ensure that the value of <code>CallerReference</code> is unique is to use a <code>timestamp</code>, for example, <code>20120301090000</code>.</p> <p>If you make a second invalidation request with the same value for <code>CallerReference</code>, and if the rest of the request is the same, CloudFront doesn't create a new invalidation request. Instead, CloudFront returns information about the invalidation request that you previously created with the same <code>CallerReference</code>.</p> <p>If <code>CallerReference</code> is a value you already sent in a previous invalidation batch request but the content of any <code>Path</code> is different from the original request, CloudFront returns an <code>InvalidationBatchAlreadyExists</code> error.</p>
     pub caller_reference: String,
@@ -4409,6 +4633,8 @@ impl InvalidationBatchSerializer {
 
 /// <p>The <code>InvalidationList</code> complex type describes the list of invalidation objects. For more information about invalidation, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Invalidation.html">Invalidating Objects (Web Distributions Only)</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InvalidationList {
     /// <p>A flag that indicates whether more invalidation batch requests remain to be listed. If your results were truncated, you can make a follow-up pagination request using the <code>Marker</code> request parameter to retrieve more invalidation batches in the list.</p>
     pub is_truncated: bool,
@@ -4461,6 +4687,8 @@ impl InvalidationListDeserializer {
 }
 /// <p>A summary of an invalidation request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InvalidationSummary {
     /// <p>The time that an invalidation request was created.</p>
     pub create_time: String,
@@ -4565,6 +4793,8 @@ impl KeyPairIdListDeserializer {
 }
 /// <p>A complex type that lists the active CloudFront key pairs, if any, that are associated with <code>AwsAccountNumber</code>. </p> <p>For more information, see <a>ActiveTrustedSigners</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KeyPairIds {
     /// <p>A complex type that lists the active CloudFront key pairs, if any, that are associated with <code>AwsAccountNumber</code>.</p> <p>For more information, see <a>ActiveTrustedSigners</a>.</p>
     pub items: Option<Vec<String>>,
@@ -4629,6 +4859,8 @@ impl LambdaFunctionARNSerializer {
 
 /// <p>A complex type that contains a Lambda function association.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LambdaFunctionAssociation {
     /// <p><p>Specifies the event type that triggers a Lambda function invocation. You can specify the following values:</p> <ul> <li> <p> <code>viewer-request</code>: The function executes when CloudFront receives a request from a viewer and before it checks to see whether the requested object is in the edge cache. </p> </li> <li> <p> <code>origin-request</code>: The function executes only when CloudFront forwards a request to your origin. When the requested object is in the edge cache, the function doesn&#39;t execute.</p> </li> <li> <p> <code>origin-response</code>: The function executes after CloudFront receives a response from the origin and before it caches the object in the response. When the requested object is in the edge cache, the function doesn&#39;t execute.</p> </li> <li> <p> <code>viewer-response</code>: The function executes before CloudFront returns the requested object to the viewer. The function executes regardless of whether the object was already in the edge cache.</p> <p>If the origin returns an HTTP status code other than HTTP 200 (OK), the function doesn&#39;t execute.</p> </li> </ul></p>
     pub event_type: String,
@@ -4752,6 +4984,8 @@ impl LambdaFunctionAssociationListSerializer {
 
 /// <p>A complex type that specifies a list of Lambda functions associations for a cache behavior.</p> <p>If you want to invoke one or more Lambda functions triggered by requests that match the <code>PathPattern</code> of the cache behavior, specify the applicable values for <code>Quantity</code> and <code>Items</code>. Note that there can be up to 4 <code>LambdaFunctionAssociation</code> items in this list (one for each possible value of <code>EventType</code>) and each <code>EventType</code> can be associated with the Lambda function only once.</p> <p>If you don't want to invoke any Lambda functions for the requests that match <code>PathPattern</code>, specify <code>0</code> for <code>Quantity</code> and omit <code>Items</code>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LambdaFunctionAssociations {
     /// <p> <b>Optional</b>: A complex type that contains <code>LambdaFunctionAssociation</code> items for this cache behavior. If <code>Quantity</code> is <code>0</code>, you can omit <code>Items</code>.</p>
     pub items: Option<Vec<LambdaFunctionAssociation>>,
@@ -4814,6 +5048,8 @@ impl LambdaFunctionAssociationsSerializer {
 
 /// <p>The request to list origin access identities. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCloudFrontOriginAccessIdentitiesRequest {
     /// <p>Use this when paginating results to indicate where to begin in your list of origin access identities. The results include identities in the list that occur after the marker. To get the next page of results, set the <code>Marker</code> to the value of the <code>NextMarker</code> from the current page's response (which is also the ID of the last identity on that page).</p>
     pub marker: Option<String>,
@@ -4823,6 +5059,8 @@ pub struct ListCloudFrontOriginAccessIdentitiesRequest {
 
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCloudFrontOriginAccessIdentitiesResult {
     /// <p>The <code>CloudFrontOriginAccessIdentityList</code> type. </p>
     pub cloud_front_origin_access_identity_list: Option<CloudFrontOriginAccessIdentityList>,
@@ -4848,6 +5086,8 @@ impl ListCloudFrontOriginAccessIdentitiesResultDeserializer {
 }
 /// <p>The request to list distributions that are associated with a specified AWS WAF web ACL. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDistributionsByWebACLIdRequest {
     /// <p>Use <code>Marker</code> and <code>MaxItems</code> to control pagination of results. If you have more than <code>MaxItems</code> distributions that satisfy the request, the response includes a <code>NextMarker</code> element. To get the next page of results, submit another request. For the value of <code>Marker</code>, specify the value of <code>NextMarker</code> from the last response. (For the first request, omit <code>Marker</code>.) </p>
     pub marker: Option<String>,
@@ -4859,6 +5099,8 @@ pub struct ListDistributionsByWebACLIdRequest {
 
 /// <p>The response to a request to list the distributions that are associated with a specified AWS WAF web ACL. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDistributionsByWebACLIdResult {
     /// <p>The <code>DistributionList</code> type. </p>
     pub distribution_list: Option<DistributionList>,
@@ -4882,6 +5124,8 @@ impl ListDistributionsByWebACLIdResultDeserializer {
 }
 /// <p>The request to list your distributions. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDistributionsRequest {
     /// <p>Use this when paginating results to indicate where to begin in your list of distributions. The results include distributions in the list that occur after the marker. To get the next page of results, set the <code>Marker</code> to the value of the <code>NextMarker</code> from the current page's response (which is also the ID of the last distribution on that page).</p>
     pub marker: Option<String>,
@@ -4891,6 +5135,8 @@ pub struct ListDistributionsRequest {
 
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDistributionsResult {
     /// <p>The <code>DistributionList</code> type. </p>
     pub distribution_list: Option<DistributionList>,
@@ -4913,6 +5159,8 @@ impl ListDistributionsResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFieldLevelEncryptionConfigsRequest {
     /// <p>Use this when paginating results to indicate where to begin in your list of configurations. The results include configurations in the list that occur after the marker. To get the next page of results, set the <code>Marker</code> to the value of the <code>NextMarker</code> from the current page's response (which is also the ID of the last configuration on that page). </p>
     pub marker: Option<String>,
@@ -4921,6 +5169,8 @@ pub struct ListFieldLevelEncryptionConfigsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFieldLevelEncryptionConfigsResult {
     /// <p>Returns a list of all field-level encryption configurations that have been created in CloudFront for this account.</p>
     pub field_level_encryption_list: Option<FieldLevelEncryptionList>,
@@ -4943,6 +5193,8 @@ impl ListFieldLevelEncryptionConfigsResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFieldLevelEncryptionProfilesRequest {
     /// <p>Use this when paginating results to indicate where to begin in your list of profiles. The results include profiles in the list that occur after the marker. To get the next page of results, set the <code>Marker</code> to the value of the <code>NextMarker</code> from the current page's response (which is also the ID of the last profile on that page). </p>
     pub marker: Option<String>,
@@ -4951,6 +5203,8 @@ pub struct ListFieldLevelEncryptionProfilesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFieldLevelEncryptionProfilesResult {
     /// <p>Returns a list of the field-level encryption profiles that have been created in CloudFront for this account.</p>
     pub field_level_encryption_profile_list: Option<FieldLevelEncryptionProfileList>,
@@ -4976,6 +5230,8 @@ impl ListFieldLevelEncryptionProfilesResultDeserializer {
 }
 /// <p>The request to list invalidations. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInvalidationsRequest {
     /// <p>The distribution's ID.</p>
     pub distribution_id: String,
@@ -4987,6 +5243,8 @@ pub struct ListInvalidationsRequest {
 
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInvalidationsResult {
     /// <p>Information about invalidation batches. </p>
     pub invalidation_list: Option<InvalidationList>,
@@ -5009,6 +5267,8 @@ impl ListInvalidationsResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPublicKeysRequest {
     /// <p>Use this when paginating results to indicate where to begin in your list of public keys. The results include public keys in the list that occur after the marker. To get the next page of results, set the <code>Marker</code> to the value of the <code>NextMarker</code> from the current page's response (which is also the ID of the last public key on that page). </p>
     pub marker: Option<String>,
@@ -5017,6 +5277,8 @@ pub struct ListPublicKeysRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPublicKeysResult {
     /// <p>Returns a list of all public keys that have been added to CloudFront for this account.</p>
     pub public_key_list: Option<PublicKeyList>,
@@ -5040,6 +5302,8 @@ impl ListPublicKeysResultDeserializer {
 }
 /// <p>The request to list your streaming distributions. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStreamingDistributionsRequest {
     /// <p>The value that you provided for the <code>Marker</code> request parameter.</p>
     pub marker: Option<String>,
@@ -5049,6 +5313,8 @@ pub struct ListStreamingDistributionsRequest {
 
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStreamingDistributionsResult {
     /// <p>The <code>StreamingDistributionList</code> type. </p>
     pub streaming_distribution_list: Option<StreamingDistributionList>,
@@ -5072,13 +5338,27 @@ impl ListStreamingDistributionsResultDeserializer {
 }
 /// <p> The request to list tags for a CloudFront resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p> An ARN of a CloudFront resource.</p>
     pub resource: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource"),
+            &self.resource,
+            "arn:aws:cloudfront::[0-9]+:.*",
+        );
+    }
+}
+
 /// <p> The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceResult {
     /// <p> A complex type that contains zero or more <code>Tag</code> elements.</p>
     pub tags: Tags,
@@ -5137,6 +5417,8 @@ impl LocationListSerializer {
 
 /// <p>A complex type that controls whether access logs are written for the distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoggingConfig {
     /// <p>The Amazon S3 bucket to store the access logs in, for example, <code>myawslogbucket.s3.amazonaws.com</code>.</p>
     pub bucket: String,
@@ -5222,7 +5504,7 @@ impl LongDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5353,6 +5635,8 @@ impl MinimumProtocolVersionSerializer {
 
 /// <p>A complex type that describes the Amazon S3 bucket, HTTP server (for example, a web server), Amazon MediaStore, or other server from which CloudFront gets your files. This can also be an origin group, if you've created an origin group. You must specify at least one origin or origin group.</p> <p>For the current limit on the number of origins or origin groups that you can specify for a distribution, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_cloudfront">Amazon CloudFront Limits</a> in the <i>AWS General Reference</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Origin {
     /// <p>A complex type that contains names and values for the custom headers that you want.</p>
     pub custom_headers: Option<CustomHeaders>,
@@ -5455,6 +5739,8 @@ impl OriginSerializer {
 
 /// <p>A complex type that contains <code>HeaderName</code> and <code>HeaderValue</code> elements, if any, for this distribution. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OriginCustomHeader {
     /// <p>The name of a header that you want CloudFront to forward to your origin. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/forward-custom-headers.html">Forwarding Custom Headers to Your Origin (Web Distributions Only)</a> in the <i>Amazon Amazon CloudFront Developer Guide</i>.</p>
     pub header_name: String,
@@ -5555,6 +5841,8 @@ impl OriginCustomHeadersListSerializer {
 
 /// <p>An origin group includes two origins (a primary origin and a second origin to failover to) and a failover criteria that you specify. You create an origin group to support origin failover in CloudFront. When you create or update a distribution, you can specifiy the origin group instead of a single origin, and CloudFront will failover from the primary origin to the second origin under the failover conditions that you've chosen.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OriginGroup {
     /// <p>A complex type that contains information about the failover criteria for an origin group.</p>
     pub failover_criteria: OriginGroupFailoverCriteria,
@@ -5564,6 +5852,15 @@ pub struct OriginGroup {
     pub members: OriginGroupMembers,
 }
 
+impl Validate for OriginGroup {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.failover_criteria
+            .validate_at(&field_path(path, "failover_criteria"), errors);
+        self.members
+            .validate_at(&field_path(path, "members"), errors);
+    }
+}
+
 struct OriginGroupDeserializer;
 impl OriginGroupDeserializer {
     #[allow(unused_variables)]
@@ -5622,11 +5919,20 @@ impl OriginGroupSerializer {
 
 /// <p>A complex data type that includes information about the failover criteria for an origin group, including the status codes for which CloudFront will failover from the primary origin to the second origin.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OriginGroupFailoverCriteria {
     /// <p>The status codes that, when returned from the primary origin, will trigger CloudFront to failover to the second origin.</p>
     pub status_codes: StatusCodes,
 }
 
+impl Validate for OriginGroupFailoverCriteria {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.status_codes
+            .validate_at(&field_path(path, "status_codes"), errors);
+    }
+}
+
 struct OriginGroupFailoverCriteriaDeserializer;
 impl OriginGroupFailoverCriteriaDeserializer {
     #[allow(unused_variables)]
@@ -5708,6 +6014,8 @@ impl OriginGroupListSerializer {
 
 /// <p>An origin in an origin group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OriginGroupMember {
     /// <p>The ID for an origin in an origin group.</p>
     pub origin_id: String,
@@ -5797,6 +6105,8 @@ impl OriginGroupMemberListSerializer {
 
 /// <p>A complex data type for the origins included in an origin group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OriginGroupMembers {
     /// <p>Items (origins) in an origin group.</p>
     pub items: Vec<OriginGroupMember>,
@@ -5804,6 +6114,17 @@ pub struct OriginGroupMembers {
     pub quantity: i64,
 }
 
+impl Validate for OriginGroupMembers {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "items"),
+            self.items.len(),
+            Some(2),
+            Some(2),
+        );
+    }
+}
+
 struct OriginGroupMembersDeserializer;
 impl OriginGroupMembersDeserializer {
     #[allow(unused_variables)]
@@ -5854,6 +6175,8 @@ impl OriginGroupMembersSerializer {
 
 /// <p>A complex data type for the origin groups specified for a distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OriginGroups {
     /// <p>The items (origin groups) in a distribution.</p>
     pub items: Option<Vec<OriginGroup>>,
@@ -5861,6 +6184,17 @@ pub struct OriginGroups {
     pub quantity: i64,
 }
 
+impl Validate for OriginGroups {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.items {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "items"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 struct OriginGroupsDeserializer;
 impl OriginGroupsDeserializer {
     #[allow(unused_variables)]
@@ -5982,6 +6316,8 @@ impl OriginProtocolPolicySerializer {
 
 /// <p>A complex type that contains information about the SSL/TLS protocols that CloudFront can use when establishing an HTTPS connection with your origin. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OriginSslProtocols {
     /// <p>A list that contains allowed SSL/TLS protocols for this distribution.</p>
     pub items: Vec<String>,
@@ -6037,6 +6373,8 @@ impl OriginSslProtocolsSerializer {
 
 /// <p>A complex type that contains information about origins and origin groups for this distribution. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Origins {
     /// <p>A complex type that contains origins or origin groups for this distribution.</p>
     pub items: Vec<Origin>,
@@ -6044,6 +6382,12 @@ pub struct Origins {
     pub quantity: i64,
 }
 
+impl Validate for Origins {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "items"), self.items.len(), Some(1), None);
+    }
+}
+
 struct OriginsDeserializer;
 impl OriginsDeserializer {
     #[allow(unused_variables)]
@@ -6130,6 +6474,8 @@ impl PathListSerializer {
 
 /// <p>A complex type that contains information about the objects that you want to invalidate. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Invalidation.html#invalidation-specifying-objects">Specifying the Objects to Invalidate</a> in the <i>Amazon CloudFront Developer Guide</i>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Paths {
     /// <p>A complex type that contains a list of the paths that you want to invalidate.</p>
     pub items: Option<Vec<String>>,
@@ -6217,6 +6563,8 @@ impl PriceClassSerializer {
 
 /// <p>A complex data type of public keys you add to CloudFront to use with features like field-level encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublicKey {
     /// <p>A time you added a public key to CloudFront.</p>
     pub created_time: String,
@@ -6253,6 +6601,8 @@ impl PublicKeyDeserializer {
 }
 /// <p>Information about a public key you add to CloudFront to use with features like field-level encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublicKeyConfig {
     /// <p>A unique number that ensures that the request can't be replayed.</p>
     pub caller_reference: String,
@@ -6337,6 +6687,8 @@ impl PublicKeyConfigSerializer {
 
 /// <p>A list of public keys you've added to CloudFront to use with features like field-level encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublicKeyList {
     /// <p>An array of information about a public key you add to CloudFront to use with features like field-level encryption.</p>
     pub items: Option<Vec<PublicKeySummary>>,
@@ -6379,6 +6731,8 @@ impl PublicKeyListDeserializer {
 }
 /// <p>A complex data type for public key information. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublicKeySummary {
     /// <p> Comment for public key information summary. </p>
     pub comment: Option<String>,
@@ -6444,6 +6798,8 @@ impl PublicKeySummaryListDeserializer {
 }
 /// <p>Query argument-profile mapping for field-level encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueryArgProfile {
     /// <p>ID of profile to use for field-level encryption query argument-profile mapping</p>
     pub profile_id: String,
@@ -6503,6 +6859,8 @@ impl QueryArgProfileSerializer {
 
 /// <p>Configuration for query argument-profile mapping for field-level encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueryArgProfileConfig {
     /// <p>Flag to set if you want a request to be forwarded to the origin even if the profile specified by the field-level encryption query argument, fle-profile, is unknown.</p>
     pub forward_when_query_arg_profile_is_unknown: bool,
@@ -6609,6 +6967,8 @@ impl QueryArgProfileListSerializer {
 
 /// <p>Query argument-profile mapping for field-level encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueryArgProfiles {
     /// <p>Number of items for query argument-profile mapping for field-level encryption.</p>
     pub items: Option<Vec<QueryArgProfile>>,
@@ -6666,6 +7026,8 @@ impl QueryArgProfilesSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueryStringCacheKeys {
     /// <p>(Optional) A list that contains the query string parameters that you want CloudFront to use as a basis for caching for this cache behavior. If <code>Quantity</code> is 0, you can omit <code>Items</code>. </p>
     pub items: Option<Vec<String>>,
@@ -6782,6 +7144,8 @@ impl ResourceARNSerializer {
 
 /// <p>A complex type that identifies ways in which you want to restrict distribution of your content.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Restrictions {
     pub geo_restriction: GeoRestriction,
 }
@@ -6825,6 +7189,8 @@ impl RestrictionsSerializer {
 
 /// <p>A complex type that contains information about the Amazon S3 bucket from which you want CloudFront to get your media files for distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3Origin {
     /// <p>The DNS name of the Amazon S3 origin. </p>
     pub domain_name: String,
@@ -6885,6 +7251,8 @@ impl S3OriginSerializer {
 
 /// <p>A complex type that contains information about the Amazon S3 origin. If the origin is a custom origin, use the <code>CustomOriginConfig</code> element instead.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3OriginConfig {
     /// <p>The CloudFront origin access identity to associate with the origin. Use an origin access identity to configure the origin so that viewers can <i>only</i> access objects in an Amazon S3 bucket through CloudFront. The format of the value is:</p> <p>origin-access-identity/cloudfront/<i>ID-of-origin-access-identity</i> </p> <p>where <code> <i>ID-of-origin-access-identity</i> </code> is the value that CloudFront returned in the <code>ID</code> element when you created the origin access identity.</p> <p>If you want viewers to be able to access objects using either the CloudFront URL or the Amazon S3 URL, specify an empty <code>OriginAccessIdentity</code> element.</p> <p>To delete the origin access identity from an existing distribution, update the distribution configuration and include an empty <code>OriginAccessIdentity</code> element.</p> <p>To replace the origin access identity, update the distribution configuration and specify the new origin access identity.</p> <p>For more information about the origin access identity, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
     pub origin_access_identity: String,
@@ -6966,6 +7334,8 @@ impl SSLSupportMethodSerializer {
 
 /// <p>A complex type that lists the AWS accounts that were included in the <code>TrustedSigners</code> complex type, as well as their active CloudFront key pair IDs, if any. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Signer {
     /// <p><p>An AWS account that is included in the <code>TrustedSigners</code> complex type for this RTMP distribution. Valid values include:</p> <ul> <li> <p> <code>self</code>, which is the AWS account used to create the distribution.</p> </li> <li> <p>An AWS account number.</p> </li> </ul></p>
     pub aws_account_number: Option<String>,
@@ -7120,6 +7490,8 @@ impl StatusCodeListSerializer {
 
 /// <p>A complex data type for the status codes that you specify that, when returned by a primary origin, trigger CloudFront to failover to a second origin.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StatusCodes {
     /// <p>The items (status codes) for an origin group.</p>
     pub items: Vec<i64>,
@@ -7127,6 +7499,12 @@ pub struct StatusCodes {
     pub quantity: i64,
 }
 
+impl Validate for StatusCodes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "items"), self.items.len(), Some(1), None);
+    }
+}
+
 struct StatusCodesDeserializer;
 impl StatusCodesDeserializer {
     #[allow(unused_variables)]
@@ -7175,6 +7553,8 @@ impl StatusCodesSerializer {
 
 /// <p>A streaming distribution. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StreamingDistribution {
     /// <p>The ARN (Amazon Resource Name) for the distribution. For example: <code>arn:aws:cloudfront::123456789012:distribution/EDFDVBD632BHDS5</code>, where <code>123456789012</code> is your AWS account ID.</p>
     pub arn: String,
@@ -7240,6 +7620,8 @@ impl StreamingDistributionDeserializer {
 }
 /// <p>The RTMP distribution's configuration information.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StreamingDistributionConfig {
     /// <p>A complex type that contains information about CNAMEs (alternate domain names), if any, for this streaming distribution. </p>
     pub aliases: Option<Aliases>,
@@ -7360,6 +7742,8 @@ impl StreamingDistributionConfigSerializer {
 
 /// <p>A streaming distribution Configuration and a list of tags to be associated with the streaming distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StreamingDistributionConfigWithTags {
     /// <p>A streaming distribution Configuration.</p>
     pub streaming_distribution_config: StreamingDistributionConfig,
@@ -7367,6 +7751,12 @@ pub struct StreamingDistributionConfigWithTags {
     pub tags: Tags,
 }
 
+impl Validate for StreamingDistributionConfigWithTags {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.tags.validate_at(&field_path(path, "tags"), errors);
+    }
+}
+
 pub struct StreamingDistributionConfigWithTagsSerializer;
 impl StreamingDistributionConfigWithTagsSerializer {
     #[allow(unused_variables, warnings)]
@@ -7391,6 +7781,8 @@ impl StreamingDistributionConfigWithTagsSerializer {
 
 /// <p>A streaming distribution list. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StreamingDistributionList {
     /// <p>A flag that indicates whether more streaming distributions remain to be listed. If your results were truncated, you can make a follow-up pagination request using the <code>Marker</code> request parameter to retrieve more distributions in the list. </p>
     pub is_truncated: bool,
@@ -7450,6 +7842,8 @@ impl StreamingDistributionListDeserializer {
 }
 /// <p> A summary of the information for an Amazon CloudFront streaming distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StreamingDistributionSummary {
     /// <p> The ARN (Amazon Resource Name) for the streaming distribution. For example: <code>arn:aws:cloudfront::123456789012:streaming-distribution/EDFDVBD632BHDS5</code>, where <code>123456789012</code> is your AWS account ID.</p>
     pub arn: String,
@@ -7551,6 +7945,8 @@ impl StreamingDistributionSummaryListDeserializer {
 }
 /// <p>A complex type that controls whether access logs are written for this streaming distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StreamingLoggingConfig {
     /// <p>The Amazon S3 bucket to store the access logs in, for example, <code>myawslogbucket.s3.amazonaws.com</code>.</p>
     pub bucket: String,
@@ -7653,6 +8049,8 @@ impl StringSerializer {
 
 /// <p> A complex type that contains <code>Tag</code> key and <code>Tag</code> value.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p> A string that contains <code>Tag</code> key.</p> <p>The string length should be between 1 and 128 characters. Valid characters include <code>a-z</code>, <code>A-Z</code>, <code>0-9</code>, space, and the special characters <code>_ - . : / = + @</code>.</p>
     pub key: String,
@@ -7660,6 +8058,35 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
@@ -7763,11 +8190,25 @@ impl TagKeyListSerializer {
 
 /// <p> A complex type that contains zero or more <code>Tag</code> elements.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagKeys {
     /// <p> A complex type that contains <code>Tag</code> key elements.</p>
     pub items: Option<Vec<String>>,
 }
 
+impl Validate for TagKeys {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.items {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "items"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+    }
+}
+
 pub struct TagKeysSerializer;
 impl TagKeysSerializer {
     #[allow(unused_variables, warnings)]
@@ -7827,6 +8268,8 @@ impl TagListSerializer {
 
 /// <p> The request to add tags to a CloudFront resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p> An ARN of a CloudFront resource.</p>
     pub resource: String,
@@ -7834,6 +8277,17 @@ pub struct TagResourceRequest {
     pub tags: Tags,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource"),
+            &self.resource,
+            "arn:aws:cloudfront::[0-9]+:.*",
+        );
+        self.tags.validate_at(&field_path(path, "tags"), errors);
+    }
+}
+
 struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
@@ -7868,11 +8322,24 @@ impl TagValueSerializer {
 
 /// <p> A complex type that contains zero or more <code>Tag</code> elements.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tags {
     /// <p> A complex type that contains <code>Tag</code> elements.</p>
     pub items: Option<Vec<Tag>>,
 }
 
+impl Validate for Tags {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.items {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "items"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 struct TagsDeserializer;
 impl TagsDeserializer {
     #[allow(unused_variables)]
@@ -7923,6 +8390,8 @@ impl TimestampDeserializer {
 }
 /// <p>A complex type that specifies the AWS accounts, if any, that you want to allow to create signed URLs for private content.</p> <p>If you want to require signed URLs in requests for objects in the target origin that match the <code>PathPattern</code> for this cache behavior, specify <code>true</code> for <code>Enabled</code>, and specify the applicable values for <code>Quantity</code> and <code>Items</code>. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon Amazon CloudFront Developer Guide</i>.</p> <p>If you don't want to require signed URLs in requests for objects that match <code>PathPattern</code>, specify <code>false</code> for <code>Enabled</code> and <code>0</code> for <code>Quantity</code>. Omit <code>Items</code>.</p> <p>To add, change, or remove one or more trusted signers, change <code>Enabled</code> to <code>true</code> (if it's currently <code>false</code>), change <code>Quantity</code> as applicable, and specify all of the trusted signers that you want to include in the updated distribution.</p> <p>For more information about updating the distribution configuration, see <a>DistributionConfig</a> .</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TrustedSigners {
     /// <p>Specifies whether you want to require viewers to use signed URLs to access the files specified by <code>PathPattern</code> and <code>TargetOriginId</code>.</p>
     pub enabled: bool,
@@ -7992,6 +8461,8 @@ impl TrustedSignersSerializer {
 
 /// <p> The request to remove tags from a CloudFront resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p> An ARN of a CloudFront resource.</p>
     pub resource: String,
@@ -7999,8 +8470,22 @@ pub struct UntagResourceRequest {
     pub tag_keys: TagKeys,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource"),
+            &self.resource,
+            "arn:aws:cloudfront::[0-9]+:.*",
+        );
+        self.tag_keys
+            .validate_at(&field_path(path, "tag_keys"), errors);
+    }
+}
+
 /// <p>The request to update an origin access identity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCloudFrontOriginAccessIdentityRequest {
     /// <p>The identity's configuration information.</p>
     pub cloud_front_origin_access_identity_config: CloudFrontOriginAccessIdentityConfig,
@@ -8012,6 +8497,8 @@ pub struct UpdateCloudFrontOriginAccessIdentityRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCloudFrontOriginAccessIdentityResult {
     /// <p>The origin access identity's information.</p>
     pub cloud_front_origin_access_identity: Option<CloudFrontOriginAccessIdentity>,
@@ -8039,6 +8526,8 @@ impl UpdateCloudFrontOriginAccessIdentityResultDeserializer {
 }
 /// <p>The request to update a distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDistributionRequest {
     /// <p>The distribution's configuration information.</p>
     pub distribution_config: DistributionConfig,
@@ -8048,8 +8537,17 @@ pub struct UpdateDistributionRequest {
     pub if_match: Option<String>,
 }
 
+impl Validate for UpdateDistributionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.distribution_config
+            .validate_at(&field_path(path, "distribution_config"), errors);
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDistributionResult {
     /// <p>The distribution's information.</p>
     pub distribution: Option<Distribution>,
@@ -8074,6 +8572,8 @@ impl UpdateDistributionResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFieldLevelEncryptionConfigRequest {
     /// <p>Request to update a field-level encryption configuration. </p>
     pub field_level_encryption_config: FieldLevelEncryptionConfig,
@@ -8084,6 +8584,8 @@ pub struct UpdateFieldLevelEncryptionConfigRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFieldLevelEncryptionConfigResult {
     /// <p>The value of the <code>ETag</code> header that you received when updating the configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -8108,6 +8610,8 @@ impl UpdateFieldLevelEncryptionConfigResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFieldLevelEncryptionProfileRequest {
     /// <p>Request to update a field-level encryption profile. </p>
     pub field_level_encryption_profile_config: FieldLevelEncryptionProfileConfig,
@@ -8118,6 +8622,8 @@ pub struct UpdateFieldLevelEncryptionProfileRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFieldLevelEncryptionProfileResult {
     /// <p>The result of the field-level encryption profile request. </p>
     pub e_tag: Option<String>,
@@ -8144,6 +8650,8 @@ impl UpdateFieldLevelEncryptionProfileResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePublicKeyRequest {
     /// <p>ID of the public key to be updated.</p>
     pub id: String,
@@ -8154,6 +8662,8 @@ pub struct UpdatePublicKeyRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePublicKeyResult {
     /// <p>The current version of the update public key result. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -8176,6 +8686,8 @@ impl UpdatePublicKeyResultDeserializer {
 }
 /// <p>The request to update a streaming distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStreamingDistributionRequest {
     /// <p>The streaming distribution's id.</p>
     pub id: String,
@@ -8187,6 +8699,8 @@ pub struct UpdateStreamingDistributionRequest {
 
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStreamingDistributionResult {
     /// <p>The current version of the configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
     pub e_tag: Option<String>,
@@ -8212,6 +8726,8 @@ impl UpdateStreamingDistributionResultDeserializer {
 }
 /// <p>A complex type that specifies the following:</p> <ul> <li> <p>Whether you want viewers to use HTTP or HTTPS to request your objects.</p> </li> <li> <p>If you want viewers to use HTTPS, whether you're using an alternate domain name such as <code>example.com</code> or the CloudFront domain name for your distribution, such as <code>d111111abcdef8.cloudfront.net</code>.</p> </li> <li> <p>If you're using an alternate domain name, whether AWS Certificate Manager (ACM) provided the certificate, or you purchased a certificate from a third-party certificate authority and imported it into ACM or uploaded it to the IAM certificate store.</p> </li> </ul> <p>You must specify only one of the following values: </p> <ul> <li> <p> <a>ViewerCertificate$ACMCertificateArn</a> </p> </li> <li> <p> <a>ViewerCertificate$IAMCertificateId</a> </p> </li> <li> <p> <a>ViewerCertificate$CloudFrontDefaultCertificate</a> </p> </li> </ul> <p>Don't specify <code>false</code> for <code>CloudFrontDefaultCertificate</code>.</p> <p> <b>If you want viewers to use HTTP instead of HTTPS to request your objects</b>: Specify the following value:</p> <p> <code>&lt;CloudFrontDefaultCertificate&gt;true&lt;CloudFrontDefaultCertificate&gt;</code> </p> <p>In addition, specify <code>allow-all</code> for <code>ViewerProtocolPolicy</code> for all of your cache behaviors.</p> <p> <b>If you want viewers to use HTTPS to request your objects</b>: Choose the type of certificate that you want to use based on whether you're using an alternate domain name for your objects or the CloudFront domain name:</p> <ul> <li> <p> <b>If you're using an alternate domain name, such as example.com</b>: Specify one of the following values, depending on whether ACM provided your certificate or you purchased your certificate from third-party certificate authority:</p> <ul> <li> <p> <code>&lt;ACMCertificateArn&gt;<i>ARN for ACM SSL/TLS certificate</i>&lt;ACMCertificateArn&gt;</code> where <code> <i>ARN for ACM SSL/TLS certificate</i> </code> is the ARN for the ACM SSL/TLS certificate that you want to use for this distribution.</p> </li> <li> <p> <code>&lt;IAMCertificateId&gt;<i>IAM certificate ID</i>&lt;IAMCertificateId&gt;</code> where <code> <i>IAM certificate ID</i> </code> is the ID that IAM returned when you added the certificate to the IAM certificate store.</p> </li> </ul> <p>If you specify <code>ACMCertificateArn</code> or <code>IAMCertificateId</code>, you must also specify a value for <code>SSLSupportMethod</code>.</p> <p>If you choose to use an ACM certificate or a certificate in the IAM certificate store, we recommend that you use only an alternate domain name in your object URLs (<code>https://example.com/logo.jpg</code>). If you use the domain name that is associated with your CloudFront distribution (such as <code>https://d111111abcdef8.cloudfront.net/logo.jpg</code>) and the viewer supports <code>SNI</code>, then CloudFront behaves normally. However, if the browser does not support SNI, the user's experience depends on the value that you choose for <code>SSLSupportMethod</code>:</p> <ul> <li> <p> <code>vip</code>: The viewer displays a warning because there is a mismatch between the CloudFront domain name and the domain name in your SSL/TLS certificate.</p> </li> <li> <p> <code>sni-only</code>: CloudFront drops the connection with the browser without returning the object.</p> </li> </ul> </li> <li> <p> <b>If you're using the CloudFront domain name for your distribution, such as <code>d111111abcdef8.cloudfront.net</code> </b>: Specify the following value:</p> <p> <code>&lt;CloudFrontDefaultCertificate&gt;true&lt;CloudFrontDefaultCertificate&gt; </code> </p> </li> </ul> <p>If you want viewers to use HTTPS, you must also specify one of the following values in your cache behaviors:</p> <ul> <li> <p> <code> &lt;ViewerProtocolPolicy&gt;https-only&lt;ViewerProtocolPolicy&gt;</code> </p> </li> <li> <p> <code>&lt;ViewerProtocolPolicy&gt;redirect-to-https&lt;ViewerProtocolPolicy&gt;</code> </p> </li> </ul> <p>You can also optionally require that CloudFront use HTTPS to communicate with your origin by specifying one of the following values for the applicable origins:</p> <ul> <li> <p> <code>&lt;OriginProtocolPolicy&gt;https-only&lt;OriginProtocolPolicy&gt; </code> </p> </li> <li> <p> <code>&lt;OriginProtocolPolicy&gt;match-viewer&lt;OriginProtocolPolicy&gt; </code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/SecureConnections.html#CNAMEsAndHTTPS">Using Alternate Domain Names and HTTPS</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ViewerCertificate {
     /// <p>For information about how and when to use <code>ACMCertificateArn</code>, see <a>ViewerCertificate</a>.</p>
     pub acm_certificate_arn: Option<String>,
@@ -12080,46 +12596,8 @@ pub trait CloudFront {
         input: UpdateStreamingDistributionRequest,
     ) -> RusotoFuture<UpdateStreamingDistributionResult, UpdateStreamingDistributionError>;
 }
-/// A client for the CloudFront API.
-#[derive(Clone)]
-pub struct CloudFrontClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CloudFrontClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CloudFrontClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CloudFrontClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CloudFrontClient {
-        CloudFrontClient { client, region }
-    }
-}
-
-impl CloudFront for CloudFrontClient {
-    /// <p>Creates a new origin access identity. If you're using Amazon S3 for your origin, you can use an origin access identity to require users to access your content using a CloudFront URL instead of the Amazon S3 URL. For more information about how to use origin access identities, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
-    #[allow(unused_variables, warnings)]
+const _: Option<&dyn CloudFront> = None;
+impl<'a, T: CloudFront + ?Sized> CloudFront for &'a T {
     fn create_cloud_front_origin_access_identity(
         &self,
         input: CreateCloudFrontOriginAccessIdentityRequest,
@@ -12127,469 +12605,52 @@ impl CloudFront for CloudFrontClient {
         CreateCloudFrontOriginAccessIdentityResult,
         CreateCloudFrontOriginAccessIdentityError,
     > {
-        let request_uri = "/2018-11-05/origin-access-identity/cloudfront";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        CloudFrontOriginAccessIdentityConfigSerializer::serialize(
-            &mut writer,
-            "CloudFrontOriginAccessIdentityConfig",
-            &input.cloud_front_origin_access_identity_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateCloudFrontOriginAccessIdentityError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateCloudFrontOriginAccessIdentityResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateCloudFrontOriginAccessIdentityResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(location) = response.headers.get("Location") {
-                    let value = location.to_owned();
-                    result.location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_cloud_front_origin_access_identity(input)
     }
-
-    /// <p>Creates a new web distribution. You create a CloudFront distribution to tell CloudFront where you want content to be delivered from, and the details about how to track and manage content delivery. Send a <code>POST</code> request to the <code>/<i>CloudFront API version</i>/distribution</code>/<code>distribution ID</code> resource.</p> <important> <p>When you update a distribution, there are more required fields than when you create a distribution. When you update your distribution by using <a>UpdateDistribution</a>, follow the steps included in the documentation to get the current configuration and then make your updates. This helps to make sure that you include all of the required fields. To view a summary, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-overview-required-fields.html">Required Fields for Create Distribution and Update Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </important> <p>If you are using Adobe Flash Media Server's RTMP protocol, you set up a different kind of CloudFront distribution. For more information, see <a>CreateStreamingDistribution</a>.</p>
-    #[allow(unused_variables, warnings)]
     fn create_distribution(
         &self,
         input: CreateDistributionRequest,
     ) -> RusotoFuture<CreateDistributionResult, CreateDistributionError> {
-        let request_uri = "/2018-11-05/distribution";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        DistributionConfigSerializer::serialize(
-            &mut writer,
-            "DistributionConfig",
-            &input.distribution_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDistributionError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateDistributionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateDistributionResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(location) = response.headers.get("Location") {
-                    let value = location.to_owned();
-                    result.location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_distribution(input)
     }
-
-    /// <p>Create a new distribution with tags.</p>
-    #[allow(unused_variables, warnings)]
     fn create_distribution_with_tags(
         &self,
         input: CreateDistributionWithTagsRequest,
     ) -> RusotoFuture<CreateDistributionWithTagsResult, CreateDistributionWithTagsError> {
-        let request_uri = "/2018-11-05/distribution";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put_key("WithTags");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        DistributionConfigWithTagsSerializer::serialize(
-            &mut writer,
-            "DistributionConfigWithTags",
-            &input.distribution_config_with_tags,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDistributionWithTagsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateDistributionWithTagsResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateDistributionWithTagsResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(location) = response.headers.get("Location") {
-                    let value = location.to_owned();
-                    result.location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_distribution_with_tags(input)
     }
-
-    /// <p>Create a new field-level encryption configuration.</p>
-    #[allow(unused_variables, warnings)]
     fn create_field_level_encryption_config(
         &self,
         input: CreateFieldLevelEncryptionConfigRequest,
     ) -> RusotoFuture<CreateFieldLevelEncryptionConfigResult, CreateFieldLevelEncryptionConfigError>
     {
-        let request_uri = "/2018-11-05/field-level-encryption";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        FieldLevelEncryptionConfigSerializer::serialize(
-            &mut writer,
-            "FieldLevelEncryptionConfig",
-            &input.field_level_encryption_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateFieldLevelEncryptionConfigError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateFieldLevelEncryptionConfigResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateFieldLevelEncryptionConfigResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(location) = response.headers.get("Location") {
-                    let value = location.to_owned();
-                    result.location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_field_level_encryption_config(input)
     }
-
-    /// <p>Create a field-level encryption profile.</p>
-    #[allow(unused_variables, warnings)]
     fn create_field_level_encryption_profile(
         &self,
         input: CreateFieldLevelEncryptionProfileRequest,
     ) -> RusotoFuture<CreateFieldLevelEncryptionProfileResult, CreateFieldLevelEncryptionProfileError>
     {
-        let request_uri = "/2018-11-05/field-level-encryption-profile";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        FieldLevelEncryptionProfileConfigSerializer::serialize(
-            &mut writer,
-            "FieldLevelEncryptionProfileConfig",
-            &input.field_level_encryption_profile_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateFieldLevelEncryptionProfileError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateFieldLevelEncryptionProfileResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateFieldLevelEncryptionProfileResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(location) = response.headers.get("Location") {
-                    let value = location.to_owned();
-                    result.location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_field_level_encryption_profile(input)
     }
-
-    /// <p>Create a new invalidation. </p>
-    #[allow(unused_variables, warnings)]
     fn create_invalidation(
         &self,
         input: CreateInvalidationRequest,
     ) -> RusotoFuture<CreateInvalidationResult, CreateInvalidationError> {
-        let request_uri = format!(
-            "/2018-11-05/distribution/{distribution_id}/invalidation",
-            distribution_id = input.distribution_id
-        );
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        InvalidationBatchSerializer::serialize(
-            &mut writer,
-            "InvalidationBatch",
-            &input.invalidation_batch,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateInvalidationError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateInvalidationResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateInvalidationResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(location) = response.headers.get("Location") {
-                    let value = location.to_owned();
-                    result.location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_invalidation(input)
     }
-
-    /// <p>Add a new public key to CloudFront to use, for example, for field-level encryption. You can add a maximum of 10 public keys with one AWS account.</p>
-    #[allow(unused_variables, warnings)]
     fn create_public_key(
         &self,
         input: CreatePublicKeyRequest,
     ) -> RusotoFuture<CreatePublicKeyResult, CreatePublicKeyError> {
-        let request_uri = "/2018-11-05/public-key";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        PublicKeyConfigSerializer::serialize(
-            &mut writer,
-            "PublicKeyConfig",
-            &input.public_key_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreatePublicKeyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreatePublicKeyResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreatePublicKeyResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(location) = response.headers.get("Location") {
-                    let value = location.to_owned();
-                    result.location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_public_key(input)
     }
-
-    /// <p><p>Creates a new RMTP distribution. An RTMP distribution is similar to a web distribution, but an RTMP distribution streams media files using the Adobe Real-Time Messaging Protocol (RTMP) instead of serving files using HTTP. </p> <p>To create a new web distribution, submit a <code>POST</code> request to the <i>CloudFront API version</i>/distribution resource. The request body must include a document with a <i>StreamingDistributionConfig</i> element. The response echoes the <code>StreamingDistributionConfig</code> element and returns other information about the RTMP distribution.</p> <p>To get the status of your request, use the <i>GET StreamingDistribution</i> API action. When the value of <code>Enabled</code> is <code>true</code> and the value of <code>Status</code> is <code>Deployed</code>, your distribution is ready. A distribution usually deploys in less than 15 minutes.</p> <p>For more information about web distributions, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-rtmp.html">Working with RTMP Distributions</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <important> <p>Beginning with the 2012-05-05 version of the CloudFront API, we made substantial changes to the format of the XML document that you include in the request body when you create or update a web distribution or an RTMP distribution, and when you invalidate objects. With previous versions of the API, we discovered that it was too easy to accidentally delete one or more values for an element that accepts multiple values, for example, CNAMEs and trusted signers. Our changes for the 2012-05-05 release are intended to prevent these accidental deletions and to notify you when there&#39;s a mismatch between the number of values you say you&#39;re specifying in the <code>Quantity</code> element and the number of values specified.</p> </important></p>
-    #[allow(unused_variables, warnings)]
     fn create_streaming_distribution(
         &self,
         input: CreateStreamingDistributionRequest,
     ) -> RusotoFuture<CreateStreamingDistributionResult, CreateStreamingDistributionError> {
-        let request_uri = "/2018-11-05/streaming-distribution";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        StreamingDistributionConfigSerializer::serialize(
-            &mut writer,
-            "StreamingDistributionConfig",
-            &input.streaming_distribution_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateStreamingDistributionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateStreamingDistributionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateStreamingDistributionResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(location) = response.headers.get("Location") {
-                    let value = location.to_owned();
-                    result.location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_streaming_distribution(input)
     }
-
-    /// <p>Create a new streaming distribution with tags.</p>
-    #[allow(unused_variables, warnings)]
     fn create_streaming_distribution_with_tags(
         &self,
         input: CreateStreamingDistributionWithTagsRequest,
@@ -12597,281 +12658,51 @@ impl CloudFront for CloudFrontClient {
         CreateStreamingDistributionWithTagsResult,
         CreateStreamingDistributionWithTagsError,
     > {
-        let request_uri = "/2018-11-05/streaming-distribution";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put_key("WithTags");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        StreamingDistributionConfigWithTagsSerializer::serialize(
-            &mut writer,
-            "StreamingDistributionConfigWithTags",
-            &input.streaming_distribution_config_with_tags,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateStreamingDistributionWithTagsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateStreamingDistributionWithTagsResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateStreamingDistributionWithTagsResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(location) = response.headers.get("Location") {
-                    let value = location.to_owned();
-                    result.location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_streaming_distribution_with_tags(input)
     }
-
-    /// <p>Delete an origin access identity. </p>
-    #[allow(unused_variables, warnings)]
     fn delete_cloud_front_origin_access_identity(
         &self,
         input: DeleteCloudFrontOriginAccessIdentityRequest,
     ) -> RusotoFuture<(), DeleteCloudFrontOriginAccessIdentityError> {
-        let request_uri = format!(
-            "/2018-11-05/origin-access-identity/cloudfront/{id}",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteCloudFrontOriginAccessIdentityError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_cloud_front_origin_access_identity(input)
     }
-
-    /// <p>Delete a distribution. </p>
-    #[allow(unused_variables, warnings)]
     fn delete_distribution(
         &self,
         input: DeleteDistributionRequest,
     ) -> RusotoFuture<(), DeleteDistributionError> {
-        let request_uri = format!("/2018-11-05/distribution/{id}", id = input.id);
-
-        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDistributionError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_distribution(input)
     }
-
-    /// <p>Remove a field-level encryption configuration.</p>
-    #[allow(unused_variables, warnings)]
     fn delete_field_level_encryption_config(
         &self,
         input: DeleteFieldLevelEncryptionConfigRequest,
     ) -> RusotoFuture<(), DeleteFieldLevelEncryptionConfigError> {
-        let request_uri = format!("/2018-11-05/field-level-encryption/{id}", id = input.id);
-
-        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteFieldLevelEncryptionConfigError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_field_level_encryption_config(input)
     }
-
-    /// <p>Remove a field-level encryption profile.</p>
-    #[allow(unused_variables, warnings)]
     fn delete_field_level_encryption_profile(
         &self,
         input: DeleteFieldLevelEncryptionProfileRequest,
     ) -> RusotoFuture<(), DeleteFieldLevelEncryptionProfileError> {
-        let request_uri = format!(
-            "/2018-11-05/field-level-encryption-profile/{id}",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteFieldLevelEncryptionProfileError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_field_level_encryption_profile(input)
     }
-
-    /// <p>Remove a public key you previously added to CloudFront.</p>
-    #[allow(unused_variables, warnings)]
     fn delete_public_key(
         &self,
         input: DeletePublicKeyRequest,
     ) -> RusotoFuture<(), DeletePublicKeyError> {
-        let request_uri = format!("/2018-11-05/public-key/{id}", id = input.id);
-
-        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeletePublicKeyError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_public_key(input)
     }
-
-    /// <p>Delete a streaming distribution. To delete an RTMP distribution using the CloudFront API, perform the following steps.</p> <p> <b>To delete an RTMP distribution using the CloudFront API</b>:</p> <ol> <li> <p>Disable the RTMP distribution.</p> </li> <li> <p>Submit a <code>GET Streaming Distribution Config</code> request to get the current configuration and the <code>Etag</code> header for the distribution. </p> </li> <li> <p>Update the XML document that was returned in the response to your <code>GET Streaming Distribution Config</code> request to change the value of <code>Enabled</code> to <code>false</code>.</p> </li> <li> <p>Submit a <code>PUT Streaming Distribution Config</code> request to update the configuration for your distribution. In the request body, include the XML document that you updated in Step 3. Then set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Streaming Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to the <code>PUT Streaming Distribution Config</code> request to confirm that the distribution was successfully disabled.</p> </li> <li> <p>Submit a <code>GET Streaming Distribution Config</code> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> <li> <p>Submit a <code>DELETE Streaming Distribution</code> request. Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Streaming Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to your <code>DELETE Streaming Distribution</code> request to confirm that the distribution was successfully deleted.</p> </li> </ol> <p>For information about deleting a distribution using the CloudFront console, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/HowToDeleteDistribution.html">Deleting a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
-    #[allow(unused_variables, warnings)]
     fn delete_streaming_distribution(
         &self,
         input: DeleteStreamingDistributionRequest,
     ) -> RusotoFuture<(), DeleteStreamingDistributionError> {
-        let request_uri = format!("/2018-11-05/streaming-distribution/{id}", id = input.id);
-
-        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteStreamingDistributionError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_streaming_distribution(input)
     }
-
-    /// <p>Get the information about an origin access identity. </p>
-    #[allow(unused_variables, warnings)]
     fn get_cloud_front_origin_access_identity(
         &self,
         input: GetCloudFrontOriginAccessIdentityRequest,
     ) -> RusotoFuture<GetCloudFrontOriginAccessIdentityResult, GetCloudFrontOriginAccessIdentityError>
     {
-        let request_uri = format!(
-            "/2018-11-05/origin-access-identity/cloudfront/{id}",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetCloudFrontOriginAccessIdentityError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetCloudFrontOriginAccessIdentityResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetCloudFrontOriginAccessIdentityResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_cloud_front_origin_access_identity(input)
     }
-
-    /// <p>Get the configuration information about an origin access identity. </p>
-    #[allow(unused_variables, warnings)]
     fn get_cloud_front_origin_access_identity_config(
         &self,
         input: GetCloudFrontOriginAccessIdentityConfigRequest,
@@ -12879,282 +12710,39 @@ impl CloudFront for CloudFrontClient {
         GetCloudFrontOriginAccessIdentityConfigResult,
         GetCloudFrontOriginAccessIdentityConfigError,
     > {
-        let request_uri = format!(
-            "/2018-11-05/origin-access-identity/cloudfront/{id}/config",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetCloudFrontOriginAccessIdentityConfigError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetCloudFrontOriginAccessIdentityConfigResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        GetCloudFrontOriginAccessIdentityConfigResultDeserializer::deserialize(
-                            &actual_tag_name,
-                            &mut stack,
-                        )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_cloud_front_origin_access_identity_config(input)
     }
-
-    /// <p>Get the information about a distribution. </p>
-    #[allow(unused_variables, warnings)]
     fn get_distribution(
         &self,
         input: GetDistributionRequest,
     ) -> RusotoFuture<GetDistributionResult, GetDistributionError> {
-        let request_uri = format!("/2018-11-05/distribution/{id}", id = input.id);
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDistributionError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetDistributionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetDistributionResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_distribution(input)
     }
-
-    /// <p>Get the configuration information about a distribution. </p>
-    #[allow(unused_variables, warnings)]
     fn get_distribution_config(
         &self,
         input: GetDistributionConfigRequest,
     ) -> RusotoFuture<GetDistributionConfigResult, GetDistributionConfigError> {
-        let request_uri = format!("/2018-11-05/distribution/{id}/config", id = input.id);
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetDistributionConfigError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetDistributionConfigResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetDistributionConfigResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_distribution_config(input)
     }
-
-    /// <p>Get the field-level encryption configuration information.</p>
-    #[allow(unused_variables, warnings)]
     fn get_field_level_encryption(
         &self,
         input: GetFieldLevelEncryptionRequest,
     ) -> RusotoFuture<GetFieldLevelEncryptionResult, GetFieldLevelEncryptionError> {
-        let request_uri = format!("/2018-11-05/field-level-encryption/{id}", id = input.id);
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetFieldLevelEncryptionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetFieldLevelEncryptionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetFieldLevelEncryptionResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_field_level_encryption(input)
     }
-
-    /// <p>Get the field-level encryption configuration information.</p>
-    #[allow(unused_variables, warnings)]
     fn get_field_level_encryption_config(
         &self,
         input: GetFieldLevelEncryptionConfigRequest,
     ) -> RusotoFuture<GetFieldLevelEncryptionConfigResult, GetFieldLevelEncryptionConfigError> {
-        let request_uri = format!(
-            "/2018-11-05/field-level-encryption/{id}/config",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetFieldLevelEncryptionConfigError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetFieldLevelEncryptionConfigResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetFieldLevelEncryptionConfigResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_field_level_encryption_config(input)
     }
-
-    /// <p>Get the field-level encryption profile information.</p>
-    #[allow(unused_variables, warnings)]
     fn get_field_level_encryption_profile(
         &self,
         input: GetFieldLevelEncryptionProfileRequest,
     ) -> RusotoFuture<GetFieldLevelEncryptionProfileResult, GetFieldLevelEncryptionProfileError>
     {
-        let request_uri = format!(
-            "/2018-11-05/field-level-encryption-profile/{id}",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetFieldLevelEncryptionProfileError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetFieldLevelEncryptionProfileResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetFieldLevelEncryptionProfileResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_field_level_encryption_profile(input)
     }
-
-    /// <p>Get the field-level encryption profile configuration information.</p>
-    #[allow(unused_variables, warnings)]
     fn get_field_level_encryption_profile_config(
         &self,
         input: GetFieldLevelEncryptionProfileConfigRequest,
@@ -13162,283 +12750,39 @@ impl CloudFront for CloudFrontClient {
         GetFieldLevelEncryptionProfileConfigResult,
         GetFieldLevelEncryptionProfileConfigError,
     > {
-        let request_uri = format!(
-            "/2018-11-05/field-level-encryption-profile/{id}/config",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetFieldLevelEncryptionProfileConfigError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetFieldLevelEncryptionProfileConfigResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetFieldLevelEncryptionProfileConfigResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_field_level_encryption_profile_config(input)
     }
-
-    /// <p>Get the information about an invalidation. </p>
-    #[allow(unused_variables, warnings)]
     fn get_invalidation(
         &self,
         input: GetInvalidationRequest,
     ) -> RusotoFuture<GetInvalidationResult, GetInvalidationError> {
-        let request_uri = format!(
-            "/2018-11-05/distribution/{distribution_id}/invalidation/{id}",
-            distribution_id = input.distribution_id,
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetInvalidationError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetInvalidationResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetInvalidationResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_invalidation(input)
     }
-
-    /// <p>Get the public key information.</p>
-    #[allow(unused_variables, warnings)]
     fn get_public_key(
         &self,
         input: GetPublicKeyRequest,
     ) -> RusotoFuture<GetPublicKeyResult, GetPublicKeyError> {
-        let request_uri = format!("/2018-11-05/public-key/{id}", id = input.id);
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPublicKeyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetPublicKeyResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        GetPublicKeyResultDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_public_key(input)
     }
-
-    /// <p>Return public key configuration informaation</p>
-    #[allow(unused_variables, warnings)]
     fn get_public_key_config(
         &self,
         input: GetPublicKeyConfigRequest,
     ) -> RusotoFuture<GetPublicKeyConfigResult, GetPublicKeyConfigError> {
-        let request_uri = format!("/2018-11-05/public-key/{id}/config", id = input.id);
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPublicKeyConfigError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetPublicKeyConfigResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetPublicKeyConfigResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_public_key_config(input)
     }
-
-    /// <p>Gets information about a specified RTMP distribution, including the distribution configuration.</p>
-    #[allow(unused_variables, warnings)]
     fn get_streaming_distribution(
         &self,
         input: GetStreamingDistributionRequest,
     ) -> RusotoFuture<GetStreamingDistributionResult, GetStreamingDistributionError> {
-        let request_uri = format!("/2018-11-05/streaming-distribution/{id}", id = input.id);
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetStreamingDistributionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetStreamingDistributionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetStreamingDistributionResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_streaming_distribution(input)
     }
-
-    /// <p>Get the configuration information about a streaming distribution. </p>
-    #[allow(unused_variables, warnings)]
     fn get_streaming_distribution_config(
         &self,
         input: GetStreamingDistributionConfigRequest,
     ) -> RusotoFuture<GetStreamingDistributionConfigResult, GetStreamingDistributionConfigError>
     {
-        let request_uri = format!(
-            "/2018-11-05/streaming-distribution/{id}/config",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetStreamingDistributionConfigError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetStreamingDistributionConfigResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetStreamingDistributionConfigResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_streaming_distribution_config(input)
     }
-
-    /// <p>Lists origin access identities.</p>
-    #[allow(unused_variables, warnings)]
     fn list_cloud_front_origin_access_identities(
         &self,
         input: ListCloudFrontOriginAccessIdentitiesRequest,
@@ -13446,530 +12790,64 @@ impl CloudFront for CloudFrontClient {
         ListCloudFrontOriginAccessIdentitiesResult,
         ListCloudFrontOriginAccessIdentitiesError,
     > {
-        let request_uri = "/2018-11-05/origin-access-identity/cloudfront";
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListCloudFrontOriginAccessIdentitiesError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListCloudFrontOriginAccessIdentitiesResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListCloudFrontOriginAccessIdentitiesResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_cloud_front_origin_access_identities(input)
     }
-
-    /// <p>List distributions. </p>
-    #[allow(unused_variables, warnings)]
     fn list_distributions(
         &self,
         input: ListDistributionsRequest,
     ) -> RusotoFuture<ListDistributionsResult, ListDistributionsError> {
-        let request_uri = "/2018-11-05/distribution";
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDistributionsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListDistributionsResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListDistributionsResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_distributions(input)
     }
-
-    /// <p>List the distributions that are associated with a specified AWS WAF web ACL. </p>
-    #[allow(unused_variables, warnings)]
     fn list_distributions_by_web_acl_id(
         &self,
         input: ListDistributionsByWebACLIdRequest,
     ) -> RusotoFuture<ListDistributionsByWebACLIdResult, ListDistributionsByWebACLIdError> {
-        let request_uri = format!(
-            "/2018-11-05/distributionsByWebACLId/{web_acl_id}",
-            web_acl_id = input.web_acl_id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListDistributionsByWebACLIdError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListDistributionsByWebACLIdResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListDistributionsByWebACLIdResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_distributions_by_web_acl_id(input)
     }
-
-    /// <p>List all field-level encryption configurations that have been created in CloudFront for this account.</p>
-    #[allow(unused_variables, warnings)]
     fn list_field_level_encryption_configs(
         &self,
         input: ListFieldLevelEncryptionConfigsRequest,
     ) -> RusotoFuture<ListFieldLevelEncryptionConfigsResult, ListFieldLevelEncryptionConfigsError>
     {
-        let request_uri = "/2018-11-05/field-level-encryption";
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListFieldLevelEncryptionConfigsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListFieldLevelEncryptionConfigsResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListFieldLevelEncryptionConfigsResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_field_level_encryption_configs(input)
     }
-
-    /// <p>Request a list of field-level encryption profiles that have been created in CloudFront for this account.</p>
-    #[allow(unused_variables, warnings)]
     fn list_field_level_encryption_profiles(
         &self,
         input: ListFieldLevelEncryptionProfilesRequest,
     ) -> RusotoFuture<ListFieldLevelEncryptionProfilesResult, ListFieldLevelEncryptionProfilesError>
     {
-        let request_uri = "/2018-11-05/field-level-encryption-profile";
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListFieldLevelEncryptionProfilesError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListFieldLevelEncryptionProfilesResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListFieldLevelEncryptionProfilesResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_field_level_encryption_profiles(input)
     }
-
-    /// <p>Lists invalidation batches. </p>
-    #[allow(unused_variables, warnings)]
     fn list_invalidations(
         &self,
         input: ListInvalidationsRequest,
     ) -> RusotoFuture<ListInvalidationsResult, ListInvalidationsError> {
-        let request_uri = format!(
-            "/2018-11-05/distribution/{distribution_id}/invalidation",
-            distribution_id = input.distribution_id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListInvalidationsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListInvalidationsResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListInvalidationsResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_invalidations(input)
     }
-
-    /// <p>List all public keys that have been added to CloudFront for this account.</p>
-    #[allow(unused_variables, warnings)]
     fn list_public_keys(
         &self,
         input: ListPublicKeysRequest,
     ) -> RusotoFuture<ListPublicKeysResult, ListPublicKeysError> {
-        let request_uri = "/2018-11-05/public-key";
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListPublicKeysError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListPublicKeysResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListPublicKeysResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_public_keys(input)
     }
-
-    /// <p>List streaming distributions. </p>
-    #[allow(unused_variables, warnings)]
     fn list_streaming_distributions(
         &self,
         input: ListStreamingDistributionsRequest,
     ) -> RusotoFuture<ListStreamingDistributionsResult, ListStreamingDistributionsError> {
-        let request_uri = "/2018-11-05/streaming-distribution";
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListStreamingDistributionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListStreamingDistributionsResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListStreamingDistributionsResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_streaming_distributions(input)
     }
-
-    /// <p>List tags for a CloudFront resource.</p>
-    #[allow(unused_variables, warnings)]
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
-        let request_uri = "/2018-11-05/tagging";
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put("Resource", &input.resource);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListTagsForResourceResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListTagsForResourceResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Add tags to a CloudFront resource.</p>
-    #[allow(unused_variables, warnings)]
     fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
-        let request_uri = "/2018-11-05/tagging";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put("Resource", &input.resource);
-        params.put("Operation", "Tag");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        TagsSerializer::serialize(&mut writer, "Tags", &input.tags);
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).tag_resource(input)
     }
-
-    /// <p>Remove tags from a CloudFront resource.</p>
-    #[allow(unused_variables, warnings)]
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
-        let request_uri = "/2018-11-05/tagging";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put("Resource", &input.resource);
-        params.put("Operation", "Untag");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        TagKeysSerializer::serialize(&mut writer, "TagKeys", &input.tag_keys);
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).untag_resource(input)
     }
-
-    /// <p>Update an origin access identity. </p>
-    #[allow(unused_variables, warnings)]
     fn update_cloud_front_origin_access_identity(
         &self,
         input: UpdateCloudFrontOriginAccessIdentityRequest,
@@ -13977,360 +12855,4806 @@ impl CloudFront for CloudFrontClient {
         UpdateCloudFrontOriginAccessIdentityResult,
         UpdateCloudFrontOriginAccessIdentityError,
     > {
-        let request_uri = format!(
-            "/2018-11-05/origin-access-identity/cloudfront/{id}/config",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        let mut writer = EventWriter::new(Vec::new());
-        CloudFrontOriginAccessIdentityConfigSerializer::serialize(
-            &mut writer,
-            "CloudFrontOriginAccessIdentityConfig",
-            &input.cloud_front_origin_access_identity_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateCloudFrontOriginAccessIdentityError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UpdateCloudFrontOriginAccessIdentityResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UpdateCloudFrontOriginAccessIdentityResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).update_cloud_front_origin_access_identity(input)
     }
-
-    /// <p><p>Updates the configuration for a web distribution. </p> <important> <p>When you update a distribution, there are more required fields than when you create a distribution. When you update your distribution by using this API action, follow the steps here to get the current configuration and then make your updates, to make sure that you include all of the required fields. To view a summary, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-overview-required-fields.html">Required Fields for Create Distribution and Update Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </important> <p>The update process includes getting the current distribution configuration, updating the XML document that is returned to make your changes, and then submitting an <code>UpdateDistribution</code> request to make the updates.</p> <p>For information about updating a distribution using the CloudFront console instead, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-web-creating-console.html">Creating a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <p> <b>To update a web distribution using the CloudFront API</b> </p> <ol> <li> <p>Submit a <a>GetDistributionConfig</a> request to get the current configuration and an <code>Etag</code> header for the distribution.</p> <note> <p>If you update the distribution again, you must get a new <code>Etag</code> header.</p> </note> </li> <li> <p>Update the XML document that was returned in the response to your <code>GetDistributionConfig</code> request to include your changes. </p> <important> <p>When you edit the XML file, be aware of the following:</p> <ul> <li> <p>You must strip out the ETag parameter that is returned.</p> </li> <li> <p>Additional fields are required when you update a distribution. There may be fields included in the XML file for features that you haven&#39;t configured for your distribution. This is expected and required to successfully update the distribution.</p> </li> <li> <p>You can&#39;t change the value of <code>CallerReference</code>. If you try to change this value, CloudFront returns an <code>IllegalUpdate</code> error. </p> </li> <li> <p>The new configuration replaces the existing configuration; the values that you specify in an <code>UpdateDistribution</code> request are not merged into your existing configuration. When you add, delete, or replace values in an element that allows multiple values (for example, <code>CNAME</code>), you must specify all of the values that you want to appear in the updated distribution. In addition, you must update the corresponding <code>Quantity</code> element.</p> </li> </ul> </important> </li> <li> <p>Submit an <code>UpdateDistribution</code> request to update the configuration for your distribution:</p> <ul> <li> <p>In the request body, include the XML document that you updated in Step 2. The request body must include an XML document with a <code>DistributionConfig</code> element.</p> </li> <li> <p>Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GetDistributionConfig</code> request in Step 1.</p> </li> </ul> </li> <li> <p>Review the response to the <code>UpdateDistribution</code> request to confirm that the configuration was successfully updated.</p> </li> <li> <p>Optional: Submit a <a>GetDistribution</a> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> </ol></p>
-    #[allow(unused_variables, warnings)]
     fn update_distribution(
         &self,
         input: UpdateDistributionRequest,
     ) -> RusotoFuture<UpdateDistributionResult, UpdateDistributionError> {
-        let request_uri = format!("/2018-11-05/distribution/{id}/config", id = input.id);
-
-        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        let mut writer = EventWriter::new(Vec::new());
-        DistributionConfigSerializer::serialize(
-            &mut writer,
-            "DistributionConfig",
-            &input.distribution_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDistributionError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UpdateDistributionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UpdateDistributionResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).update_distribution(input)
     }
-
-    /// <p>Update a field-level encryption configuration. </p>
-    #[allow(unused_variables, warnings)]
     fn update_field_level_encryption_config(
         &self,
         input: UpdateFieldLevelEncryptionConfigRequest,
     ) -> RusotoFuture<UpdateFieldLevelEncryptionConfigResult, UpdateFieldLevelEncryptionConfigError>
     {
-        let request_uri = format!(
-            "/2018-11-05/field-level-encryption/{id}/config",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        let mut writer = EventWriter::new(Vec::new());
-        FieldLevelEncryptionConfigSerializer::serialize(
-            &mut writer,
-            "FieldLevelEncryptionConfig",
-            &input.field_level_encryption_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateFieldLevelEncryptionConfigError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UpdateFieldLevelEncryptionConfigResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UpdateFieldLevelEncryptionConfigResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).update_field_level_encryption_config(input)
     }
-
-    /// <p>Update a field-level encryption profile. </p>
-    #[allow(unused_variables, warnings)]
     fn update_field_level_encryption_profile(
         &self,
         input: UpdateFieldLevelEncryptionProfileRequest,
     ) -> RusotoFuture<UpdateFieldLevelEncryptionProfileResult, UpdateFieldLevelEncryptionProfileError>
     {
-        let request_uri = format!(
-            "/2018-11-05/field-level-encryption-profile/{id}/config",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        let mut writer = EventWriter::new(Vec::new());
-        FieldLevelEncryptionProfileConfigSerializer::serialize(
-            &mut writer,
-            "FieldLevelEncryptionProfileConfig",
-            &input.field_level_encryption_profile_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateFieldLevelEncryptionProfileError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UpdateFieldLevelEncryptionProfileResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UpdateFieldLevelEncryptionProfileResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).update_field_level_encryption_profile(input)
     }
-
-    /// <p>Update public key information. Note that the only value you can change is the comment.</p>
-    #[allow(unused_variables, warnings)]
     fn update_public_key(
         &self,
         input: UpdatePublicKeyRequest,
     ) -> RusotoFuture<UpdatePublicKeyResult, UpdatePublicKeyError> {
-        let request_uri = format!("/2018-11-05/public-key/{id}/config", id = input.id);
-
-        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        let mut writer = EventWriter::new(Vec::new());
-        PublicKeyConfigSerializer::serialize(
-            &mut writer,
-            "PublicKeyConfig",
-            &input.public_key_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdatePublicKeyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UpdatePublicKeyResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UpdatePublicKeyResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).update_public_key(input)
     }
-
-    /// <p>Update a streaming distribution. </p>
-    #[allow(unused_variables, warnings)]
     fn update_streaming_distribution(
         &self,
         input: UpdateStreamingDistributionRequest,
     ) -> RusotoFuture<UpdateStreamingDistributionResult, UpdateStreamingDistributionError> {
-        let request_uri = format!(
-            "/2018-11-05/streaming-distribution/{id}/config",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        let mut writer = EventWriter::new(Vec::new());
-        StreamingDistributionConfigSerializer::serialize(
-            &mut writer,
-            "StreamingDistributionConfig",
-            &input.streaming_distribution_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateStreamingDistributionError::from_response(response))
+        (**self).update_streaming_distribution(input)
+    }
+}
+impl<T: CloudFront + ?Sized> CloudFront for Box<T> {
+    fn create_cloud_front_origin_access_identity(
+        &self,
+        input: CreateCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<
+        CreateCloudFrontOriginAccessIdentityResult,
+        CreateCloudFrontOriginAccessIdentityError,
+    > {
+        (**self).create_cloud_front_origin_access_identity(input)
+    }
+    fn create_distribution(
+        &self,
+        input: CreateDistributionRequest,
+    ) -> RusotoFuture<CreateDistributionResult, CreateDistributionError> {
+        (**self).create_distribution(input)
+    }
+    fn create_distribution_with_tags(
+        &self,
+        input: CreateDistributionWithTagsRequest,
+    ) -> RusotoFuture<CreateDistributionWithTagsResult, CreateDistributionWithTagsError> {
+        (**self).create_distribution_with_tags(input)
+    }
+    fn create_field_level_encryption_config(
+        &self,
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<CreateFieldLevelEncryptionConfigResult, CreateFieldLevelEncryptionConfigError>
+    {
+        (**self).create_field_level_encryption_config(input)
+    }
+    fn create_field_level_encryption_profile(
+        &self,
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<CreateFieldLevelEncryptionProfileResult, CreateFieldLevelEncryptionProfileError>
+    {
+        (**self).create_field_level_encryption_profile(input)
+    }
+    fn create_invalidation(
+        &self,
+        input: CreateInvalidationRequest,
+    ) -> RusotoFuture<CreateInvalidationResult, CreateInvalidationError> {
+        (**self).create_invalidation(input)
+    }
+    fn create_public_key(
+        &self,
+        input: CreatePublicKeyRequest,
+    ) -> RusotoFuture<CreatePublicKeyResult, CreatePublicKeyError> {
+        (**self).create_public_key(input)
+    }
+    fn create_streaming_distribution(
+        &self,
+        input: CreateStreamingDistributionRequest,
+    ) -> RusotoFuture<CreateStreamingDistributionResult, CreateStreamingDistributionError> {
+        (**self).create_streaming_distribution(input)
+    }
+    fn create_streaming_distribution_with_tags(
+        &self,
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> RusotoFuture<
+        CreateStreamingDistributionWithTagsResult,
+        CreateStreamingDistributionWithTagsError,
+    > {
+        (**self).create_streaming_distribution_with_tags(input)
+    }
+    fn delete_cloud_front_origin_access_identity(
+        &self,
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<(), DeleteCloudFrontOriginAccessIdentityError> {
+        (**self).delete_cloud_front_origin_access_identity(input)
+    }
+    fn delete_distribution(
+        &self,
+        input: DeleteDistributionRequest,
+    ) -> RusotoFuture<(), DeleteDistributionError> {
+        (**self).delete_distribution(input)
+    }
+    fn delete_field_level_encryption_config(
+        &self,
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<(), DeleteFieldLevelEncryptionConfigError> {
+        (**self).delete_field_level_encryption_config(input)
+    }
+    fn delete_field_level_encryption_profile(
+        &self,
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<(), DeleteFieldLevelEncryptionProfileError> {
+        (**self).delete_field_level_encryption_profile(input)
+    }
+    fn delete_public_key(
+        &self,
+        input: DeletePublicKeyRequest,
+    ) -> RusotoFuture<(), DeletePublicKeyError> {
+        (**self).delete_public_key(input)
+    }
+    fn delete_streaming_distribution(
+        &self,
+        input: DeleteStreamingDistributionRequest,
+    ) -> RusotoFuture<(), DeleteStreamingDistributionError> {
+        (**self).delete_streaming_distribution(input)
+    }
+    fn get_cloud_front_origin_access_identity(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<GetCloudFrontOriginAccessIdentityResult, GetCloudFrontOriginAccessIdentityError>
+    {
+        (**self).get_cloud_front_origin_access_identity(input)
+    }
+    fn get_cloud_front_origin_access_identity_config(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> RusotoFuture<
+        GetCloudFrontOriginAccessIdentityConfigResult,
+        GetCloudFrontOriginAccessIdentityConfigError,
+    > {
+        (**self).get_cloud_front_origin_access_identity_config(input)
+    }
+    fn get_distribution(
+        &self,
+        input: GetDistributionRequest,
+    ) -> RusotoFuture<GetDistributionResult, GetDistributionError> {
+        (**self).get_distribution(input)
+    }
+    fn get_distribution_config(
+        &self,
+        input: GetDistributionConfigRequest,
+    ) -> RusotoFuture<GetDistributionConfigResult, GetDistributionConfigError> {
+        (**self).get_distribution_config(input)
+    }
+    fn get_field_level_encryption(
+        &self,
+        input: GetFieldLevelEncryptionRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionResult, GetFieldLevelEncryptionError> {
+        (**self).get_field_level_encryption(input)
+    }
+    fn get_field_level_encryption_config(
+        &self,
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionConfigResult, GetFieldLevelEncryptionConfigError> {
+        (**self).get_field_level_encryption_config(input)
+    }
+    fn get_field_level_encryption_profile(
+        &self,
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionProfileResult, GetFieldLevelEncryptionProfileError>
+    {
+        (**self).get_field_level_encryption_profile(input)
+    }
+    fn get_field_level_encryption_profile_config(
+        &self,
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> RusotoFuture<
+        GetFieldLevelEncryptionProfileConfigResult,
+        GetFieldLevelEncryptionProfileConfigError,
+    > {
+        (**self).get_field_level_encryption_profile_config(input)
+    }
+    fn get_invalidation(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> RusotoFuture<GetInvalidationResult, GetInvalidationError> {
+        (**self).get_invalidation(input)
+    }
+    fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> RusotoFuture<GetPublicKeyResult, GetPublicKeyError> {
+        (**self).get_public_key(input)
+    }
+    fn get_public_key_config(
+        &self,
+        input: GetPublicKeyConfigRequest,
+    ) -> RusotoFuture<GetPublicKeyConfigResult, GetPublicKeyConfigError> {
+        (**self).get_public_key_config(input)
+    }
+    fn get_streaming_distribution(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> RusotoFuture<GetStreamingDistributionResult, GetStreamingDistributionError> {
+        (**self).get_streaming_distribution(input)
+    }
+    fn get_streaming_distribution_config(
+        &self,
+        input: GetStreamingDistributionConfigRequest,
+    ) -> RusotoFuture<GetStreamingDistributionConfigResult, GetStreamingDistributionConfigError>
+    {
+        (**self).get_streaming_distribution_config(input)
+    }
+    fn list_cloud_front_origin_access_identities(
+        &self,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> RusotoFuture<
+        ListCloudFrontOriginAccessIdentitiesResult,
+        ListCloudFrontOriginAccessIdentitiesError,
+    > {
+        (**self).list_cloud_front_origin_access_identities(input)
+    }
+    fn list_distributions(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> RusotoFuture<ListDistributionsResult, ListDistributionsError> {
+        (**self).list_distributions(input)
+    }
+    fn list_distributions_by_web_acl_id(
+        &self,
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> RusotoFuture<ListDistributionsByWebACLIdResult, ListDistributionsByWebACLIdError> {
+        (**self).list_distributions_by_web_acl_id(input)
+    }
+    fn list_field_level_encryption_configs(
+        &self,
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> RusotoFuture<ListFieldLevelEncryptionConfigsResult, ListFieldLevelEncryptionConfigsError>
+    {
+        (**self).list_field_level_encryption_configs(input)
+    }
+    fn list_field_level_encryption_profiles(
+        &self,
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> RusotoFuture<ListFieldLevelEncryptionProfilesResult, ListFieldLevelEncryptionProfilesError>
+    {
+        (**self).list_field_level_encryption_profiles(input)
+    }
+    fn list_invalidations(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> RusotoFuture<ListInvalidationsResult, ListInvalidationsError> {
+        (**self).list_invalidations(input)
+    }
+    fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> RusotoFuture<ListPublicKeysResult, ListPublicKeysError> {
+        (**self).list_public_keys(input)
+    }
+    fn list_streaming_distributions(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> RusotoFuture<ListStreamingDistributionsResult, ListStreamingDistributionsError> {
+        (**self).list_streaming_distributions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cloud_front_origin_access_identity(
+        &self,
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<
+        UpdateCloudFrontOriginAccessIdentityResult,
+        UpdateCloudFrontOriginAccessIdentityError,
+    > {
+        (**self).update_cloud_front_origin_access_identity(input)
+    }
+    fn update_distribution(
+        &self,
+        input: UpdateDistributionRequest,
+    ) -> RusotoFuture<UpdateDistributionResult, UpdateDistributionError> {
+        (**self).update_distribution(input)
+    }
+    fn update_field_level_encryption_config(
+        &self,
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<UpdateFieldLevelEncryptionConfigResult, UpdateFieldLevelEncryptionConfigError>
+    {
+        (**self).update_field_level_encryption_config(input)
+    }
+    fn update_field_level_encryption_profile(
+        &self,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<UpdateFieldLevelEncryptionProfileResult, UpdateFieldLevelEncryptionProfileError>
+    {
+        (**self).update_field_level_encryption_profile(input)
+    }
+    fn update_public_key(
+        &self,
+        input: UpdatePublicKeyRequest,
+    ) -> RusotoFuture<UpdatePublicKeyResult, UpdatePublicKeyError> {
+        (**self).update_public_key(input)
+    }
+    fn update_streaming_distribution(
+        &self,
+        input: UpdateStreamingDistributionRequest,
+    ) -> RusotoFuture<UpdateStreamingDistributionResult, UpdateStreamingDistributionError> {
+        (**self).update_streaming_distribution(input)
+    }
+}
+impl<T: CloudFront + ?Sized> CloudFront for ::std::sync::Arc<T> {
+    fn create_cloud_front_origin_access_identity(
+        &self,
+        input: CreateCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<
+        CreateCloudFrontOriginAccessIdentityResult,
+        CreateCloudFrontOriginAccessIdentityError,
+    > {
+        (**self).create_cloud_front_origin_access_identity(input)
+    }
+    fn create_distribution(
+        &self,
+        input: CreateDistributionRequest,
+    ) -> RusotoFuture<CreateDistributionResult, CreateDistributionError> {
+        (**self).create_distribution(input)
+    }
+    fn create_distribution_with_tags(
+        &self,
+        input: CreateDistributionWithTagsRequest,
+    ) -> RusotoFuture<CreateDistributionWithTagsResult, CreateDistributionWithTagsError> {
+        (**self).create_distribution_with_tags(input)
+    }
+    fn create_field_level_encryption_config(
+        &self,
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<CreateFieldLevelEncryptionConfigResult, CreateFieldLevelEncryptionConfigError>
+    {
+        (**self).create_field_level_encryption_config(input)
+    }
+    fn create_field_level_encryption_profile(
+        &self,
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<CreateFieldLevelEncryptionProfileResult, CreateFieldLevelEncryptionProfileError>
+    {
+        (**self).create_field_level_encryption_profile(input)
+    }
+    fn create_invalidation(
+        &self,
+        input: CreateInvalidationRequest,
+    ) -> RusotoFuture<CreateInvalidationResult, CreateInvalidationError> {
+        (**self).create_invalidation(input)
+    }
+    fn create_public_key(
+        &self,
+        input: CreatePublicKeyRequest,
+    ) -> RusotoFuture<CreatePublicKeyResult, CreatePublicKeyError> {
+        (**self).create_public_key(input)
+    }
+    fn create_streaming_distribution(
+        &self,
+        input: CreateStreamingDistributionRequest,
+    ) -> RusotoFuture<CreateStreamingDistributionResult, CreateStreamingDistributionError> {
+        (**self).create_streaming_distribution(input)
+    }
+    fn create_streaming_distribution_with_tags(
+        &self,
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> RusotoFuture<
+        CreateStreamingDistributionWithTagsResult,
+        CreateStreamingDistributionWithTagsError,
+    > {
+        (**self).create_streaming_distribution_with_tags(input)
+    }
+    fn delete_cloud_front_origin_access_identity(
+        &self,
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<(), DeleteCloudFrontOriginAccessIdentityError> {
+        (**self).delete_cloud_front_origin_access_identity(input)
+    }
+    fn delete_distribution(
+        &self,
+        input: DeleteDistributionRequest,
+    ) -> RusotoFuture<(), DeleteDistributionError> {
+        (**self).delete_distribution(input)
+    }
+    fn delete_field_level_encryption_config(
+        &self,
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<(), DeleteFieldLevelEncryptionConfigError> {
+        (**self).delete_field_level_encryption_config(input)
+    }
+    fn delete_field_level_encryption_profile(
+        &self,
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<(), DeleteFieldLevelEncryptionProfileError> {
+        (**self).delete_field_level_encryption_profile(input)
+    }
+    fn delete_public_key(
+        &self,
+        input: DeletePublicKeyRequest,
+    ) -> RusotoFuture<(), DeletePublicKeyError> {
+        (**self).delete_public_key(input)
+    }
+    fn delete_streaming_distribution(
+        &self,
+        input: DeleteStreamingDistributionRequest,
+    ) -> RusotoFuture<(), DeleteStreamingDistributionError> {
+        (**self).delete_streaming_distribution(input)
+    }
+    fn get_cloud_front_origin_access_identity(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<GetCloudFrontOriginAccessIdentityResult, GetCloudFrontOriginAccessIdentityError>
+    {
+        (**self).get_cloud_front_origin_access_identity(input)
+    }
+    fn get_cloud_front_origin_access_identity_config(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> RusotoFuture<
+        GetCloudFrontOriginAccessIdentityConfigResult,
+        GetCloudFrontOriginAccessIdentityConfigError,
+    > {
+        (**self).get_cloud_front_origin_access_identity_config(input)
+    }
+    fn get_distribution(
+        &self,
+        input: GetDistributionRequest,
+    ) -> RusotoFuture<GetDistributionResult, GetDistributionError> {
+        (**self).get_distribution(input)
+    }
+    fn get_distribution_config(
+        &self,
+        input: GetDistributionConfigRequest,
+    ) -> RusotoFuture<GetDistributionConfigResult, GetDistributionConfigError> {
+        (**self).get_distribution_config(input)
+    }
+    fn get_field_level_encryption(
+        &self,
+        input: GetFieldLevelEncryptionRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionResult, GetFieldLevelEncryptionError> {
+        (**self).get_field_level_encryption(input)
+    }
+    fn get_field_level_encryption_config(
+        &self,
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionConfigResult, GetFieldLevelEncryptionConfigError> {
+        (**self).get_field_level_encryption_config(input)
+    }
+    fn get_field_level_encryption_profile(
+        &self,
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionProfileResult, GetFieldLevelEncryptionProfileError>
+    {
+        (**self).get_field_level_encryption_profile(input)
+    }
+    fn get_field_level_encryption_profile_config(
+        &self,
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> RusotoFuture<
+        GetFieldLevelEncryptionProfileConfigResult,
+        GetFieldLevelEncryptionProfileConfigError,
+    > {
+        (**self).get_field_level_encryption_profile_config(input)
+    }
+    fn get_invalidation(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> RusotoFuture<GetInvalidationResult, GetInvalidationError> {
+        (**self).get_invalidation(input)
+    }
+    fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> RusotoFuture<GetPublicKeyResult, GetPublicKeyError> {
+        (**self).get_public_key(input)
+    }
+    fn get_public_key_config(
+        &self,
+        input: GetPublicKeyConfigRequest,
+    ) -> RusotoFuture<GetPublicKeyConfigResult, GetPublicKeyConfigError> {
+        (**self).get_public_key_config(input)
+    }
+    fn get_streaming_distribution(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> RusotoFuture<GetStreamingDistributionResult, GetStreamingDistributionError> {
+        (**self).get_streaming_distribution(input)
+    }
+    fn get_streaming_distribution_config(
+        &self,
+        input: GetStreamingDistributionConfigRequest,
+    ) -> RusotoFuture<GetStreamingDistributionConfigResult, GetStreamingDistributionConfigError>
+    {
+        (**self).get_streaming_distribution_config(input)
+    }
+    fn list_cloud_front_origin_access_identities(
+        &self,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> RusotoFuture<
+        ListCloudFrontOriginAccessIdentitiesResult,
+        ListCloudFrontOriginAccessIdentitiesError,
+    > {
+        (**self).list_cloud_front_origin_access_identities(input)
+    }
+    fn list_distributions(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> RusotoFuture<ListDistributionsResult, ListDistributionsError> {
+        (**self).list_distributions(input)
+    }
+    fn list_distributions_by_web_acl_id(
+        &self,
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> RusotoFuture<ListDistributionsByWebACLIdResult, ListDistributionsByWebACLIdError> {
+        (**self).list_distributions_by_web_acl_id(input)
+    }
+    fn list_field_level_encryption_configs(
+        &self,
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> RusotoFuture<ListFieldLevelEncryptionConfigsResult, ListFieldLevelEncryptionConfigsError>
+    {
+        (**self).list_field_level_encryption_configs(input)
+    }
+    fn list_field_level_encryption_profiles(
+        &self,
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> RusotoFuture<ListFieldLevelEncryptionProfilesResult, ListFieldLevelEncryptionProfilesError>
+    {
+        (**self).list_field_level_encryption_profiles(input)
+    }
+    fn list_invalidations(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> RusotoFuture<ListInvalidationsResult, ListInvalidationsError> {
+        (**self).list_invalidations(input)
+    }
+    fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> RusotoFuture<ListPublicKeysResult, ListPublicKeysError> {
+        (**self).list_public_keys(input)
+    }
+    fn list_streaming_distributions(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> RusotoFuture<ListStreamingDistributionsResult, ListStreamingDistributionsError> {
+        (**self).list_streaming_distributions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cloud_front_origin_access_identity(
+        &self,
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<
+        UpdateCloudFrontOriginAccessIdentityResult,
+        UpdateCloudFrontOriginAccessIdentityError,
+    > {
+        (**self).update_cloud_front_origin_access_identity(input)
+    }
+    fn update_distribution(
+        &self,
+        input: UpdateDistributionRequest,
+    ) -> RusotoFuture<UpdateDistributionResult, UpdateDistributionError> {
+        (**self).update_distribution(input)
+    }
+    fn update_field_level_encryption_config(
+        &self,
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<UpdateFieldLevelEncryptionConfigResult, UpdateFieldLevelEncryptionConfigError>
+    {
+        (**self).update_field_level_encryption_config(input)
+    }
+    fn update_field_level_encryption_profile(
+        &self,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<UpdateFieldLevelEncryptionProfileResult, UpdateFieldLevelEncryptionProfileError>
+    {
+        (**self).update_field_level_encryption_profile(input)
+    }
+    fn update_public_key(
+        &self,
+        input: UpdatePublicKeyRequest,
+    ) -> RusotoFuture<UpdatePublicKeyResult, UpdatePublicKeyError> {
+        (**self).update_public_key(input)
+    }
+    fn update_streaming_distribution(
+        &self,
+        input: UpdateStreamingDistributionRequest,
+    ) -> RusotoFuture<UpdateStreamingDistributionResult, UpdateStreamingDistributionError> {
+        (**self).update_streaming_distribution(input)
+    }
+}
+/// A client for the CloudFront API.
+#[derive(Clone)]
+pub struct CloudFrontClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CloudFrontClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudFrontClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudFrontClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudFrontClient {
+        CloudFrontClient { client, region }
+    }
+}
+
+impl CloudFront for CloudFrontClient {
+    /// <p>Creates a new origin access identity. If you're using Amazon S3 for your origin, you can use an origin access identity to require users to access your content using a CloudFront URL instead of the Amazon S3 URL. For more information about how to use origin access identities, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_cloud_front_origin_access_identity(
+        &self,
+        input: CreateCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<
+        CreateCloudFrontOriginAccessIdentityResult,
+        CreateCloudFrontOriginAccessIdentityError,
+    > {
+        let request_uri = "/2018-11-05/origin-access-identity/cloudfront";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateCloudFrontOriginAccessIdentity");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        CloudFrontOriginAccessIdentityConfigSerializer::serialize(
+            &mut writer,
+            "CloudFrontOriginAccessIdentityConfig",
+            &input.cloud_front_origin_access_identity_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateCloudFrontOriginAccessIdentityError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateCloudFrontOriginAccessIdentityResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateCloudFrontOriginAccessIdentityResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "CreateCloudFrontOriginAccessIdentity",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(location) = response.headers.get("Location") {
+                    let value = location.to_owned();
+                    result.location = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new web distribution. You create a CloudFront distribution to tell CloudFront where you want content to be delivered from, and the details about how to track and manage content delivery. Send a <code>POST</code> request to the <code>/<i>CloudFront API version</i>/distribution</code>/<code>distribution ID</code> resource.</p> <important> <p>When you update a distribution, there are more required fields than when you create a distribution. When you update your distribution by using <a>UpdateDistribution</a>, follow the steps included in the documentation to get the current configuration and then make your updates. This helps to make sure that you include all of the required fields. To view a summary, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-overview-required-fields.html">Required Fields for Create Distribution and Update Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </important> <p>If you are using Adobe Flash Media Server's RTMP protocol, you set up a different kind of CloudFront distribution. For more information, see <a>CreateStreamingDistribution</a>.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_distribution(
+        &self,
+        input: CreateDistributionRequest,
+    ) -> RusotoFuture<CreateDistributionResult, CreateDistributionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2018-11-05/distribution";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateDistribution");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        DistributionConfigSerializer::serialize(
+            &mut writer,
+            "DistributionConfig",
+            &input.distribution_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDistributionError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateDistributionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateDistributionResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateDistribution", err, &response.body))?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(location) = response.headers.get("Location") {
+                    let value = location.to_owned();
+                    result.location = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Create a new distribution with tags.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_distribution_with_tags(
+        &self,
+        input: CreateDistributionWithTagsRequest,
+    ) -> RusotoFuture<CreateDistributionWithTagsResult, CreateDistributionWithTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2018-11-05/distribution";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateDistributionWithTags");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        params.put_key("WithTags");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        DistributionConfigWithTagsSerializer::serialize(
+            &mut writer,
+            "DistributionConfigWithTags",
+            &input.distribution_config_with_tags,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDistributionWithTagsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateDistributionWithTagsResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateDistributionWithTagsResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateDistributionWithTags", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(location) = response.headers.get("Location") {
+                    let value = location.to_owned();
+                    result.location = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Create a new field-level encryption configuration.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_field_level_encryption_config(
+        &self,
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<CreateFieldLevelEncryptionConfigResult, CreateFieldLevelEncryptionConfigError>
+    {
+        let request_uri = "/2018-11-05/field-level-encryption";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateFieldLevelEncryptionConfig");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        FieldLevelEncryptionConfigSerializer::serialize(
+            &mut writer,
+            "FieldLevelEncryptionConfig",
+            &input.field_level_encryption_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateFieldLevelEncryptionConfigError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateFieldLevelEncryptionConfigResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateFieldLevelEncryptionConfigResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateFieldLevelEncryptionConfig", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(location) = response.headers.get("Location") {
+                    let value = location.to_owned();
+                    result.location = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Create a field-level encryption profile.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_field_level_encryption_profile(
+        &self,
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<CreateFieldLevelEncryptionProfileResult, CreateFieldLevelEncryptionProfileError>
+    {
+        let request_uri = "/2018-11-05/field-level-encryption-profile";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateFieldLevelEncryptionProfile");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        FieldLevelEncryptionProfileConfigSerializer::serialize(
+            &mut writer,
+            "FieldLevelEncryptionProfileConfig",
+            &input.field_level_encryption_profile_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateFieldLevelEncryptionProfileError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateFieldLevelEncryptionProfileResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateFieldLevelEncryptionProfileResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateFieldLevelEncryptionProfile", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(location) = response.headers.get("Location") {
+                    let value = location.to_owned();
+                    result.location = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Create a new invalidation. </p>
+    #[allow(unused_variables, warnings)]
+    fn create_invalidation(
+        &self,
+        input: CreateInvalidationRequest,
+    ) -> RusotoFuture<CreateInvalidationResult, CreateInvalidationError> {
+        let request_uri = format!(
+            "/2018-11-05/distribution/{distribution_id}/invalidation",
+            distribution_id = input.distribution_id
+        );
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateInvalidation");
+        request.add_resource_id("DistributionId", &input.distribution_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        InvalidationBatchSerializer::serialize(
+            &mut writer,
+            "InvalidationBatch",
+            &input.invalidation_batch,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateInvalidationError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateInvalidationResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateInvalidationResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateInvalidation", err, &response.body))?;
+                }
+                if let Some(location) = response.headers.get("Location") {
+                    let value = location.to_owned();
+                    result.location = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Add a new public key to CloudFront to use, for example, for field-level encryption. You can add a maximum of 10 public keys with one AWS account.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_public_key(
+        &self,
+        input: CreatePublicKeyRequest,
+    ) -> RusotoFuture<CreatePublicKeyResult, CreatePublicKeyError> {
+        let request_uri = "/2018-11-05/public-key";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreatePublicKey");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        PublicKeyConfigSerializer::serialize(
+            &mut writer,
+            "PublicKeyConfig",
+            &input.public_key_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreatePublicKeyError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreatePublicKeyResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreatePublicKeyResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreatePublicKey", err, &response.body))?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(location) = response.headers.get("Location") {
+                    let value = location.to_owned();
+                    result.location = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Creates a new RMTP distribution. An RTMP distribution is similar to a web distribution, but an RTMP distribution streams media files using the Adobe Real-Time Messaging Protocol (RTMP) instead of serving files using HTTP. </p> <p>To create a new web distribution, submit a <code>POST</code> request to the <i>CloudFront API version</i>/distribution resource. The request body must include a document with a <i>StreamingDistributionConfig</i> element. The response echoes the <code>StreamingDistributionConfig</code> element and returns other information about the RTMP distribution.</p> <p>To get the status of your request, use the <i>GET StreamingDistribution</i> API action. When the value of <code>Enabled</code> is <code>true</code> and the value of <code>Status</code> is <code>Deployed</code>, your distribution is ready. A distribution usually deploys in less than 15 minutes.</p> <p>For more information about web distributions, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-rtmp.html">Working with RTMP Distributions</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <important> <p>Beginning with the 2012-05-05 version of the CloudFront API, we made substantial changes to the format of the XML document that you include in the request body when you create or update a web distribution or an RTMP distribution, and when you invalidate objects. With previous versions of the API, we discovered that it was too easy to accidentally delete one or more values for an element that accepts multiple values, for example, CNAMEs and trusted signers. Our changes for the 2012-05-05 release are intended to prevent these accidental deletions and to notify you when there&#39;s a mismatch between the number of values you say you&#39;re specifying in the <code>Quantity</code> element and the number of values specified.</p> </important></p>
+    #[allow(unused_variables, warnings)]
+    fn create_streaming_distribution(
+        &self,
+        input: CreateStreamingDistributionRequest,
+    ) -> RusotoFuture<CreateStreamingDistributionResult, CreateStreamingDistributionError> {
+        let request_uri = "/2018-11-05/streaming-distribution";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateStreamingDistribution");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        StreamingDistributionConfigSerializer::serialize(
+            &mut writer,
+            "StreamingDistributionConfig",
+            &input.streaming_distribution_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateStreamingDistributionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateStreamingDistributionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateStreamingDistributionResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateStreamingDistribution", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(location) = response.headers.get("Location") {
+                    let value = location.to_owned();
+                    result.location = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Create a new streaming distribution with tags.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_streaming_distribution_with_tags(
+        &self,
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> RusotoFuture<
+        CreateStreamingDistributionWithTagsResult,
+        CreateStreamingDistributionWithTagsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2018-11-05/streaming-distribution";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateStreamingDistributionWithTags");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        params.put_key("WithTags");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        StreamingDistributionConfigWithTagsSerializer::serialize(
+            &mut writer,
+            "StreamingDistributionConfigWithTags",
+            &input.streaming_distribution_config_with_tags,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateStreamingDistributionWithTagsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateStreamingDistributionWithTagsResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateStreamingDistributionWithTagsResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "CreateStreamingDistributionWithTags",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(location) = response.headers.get("Location") {
+                    let value = location.to_owned();
+                    result.location = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Delete an origin access identity. </p>
+    #[allow(unused_variables, warnings)]
+    fn delete_cloud_front_origin_access_identity(
+        &self,
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<(), DeleteCloudFrontOriginAccessIdentityError> {
+        let request_uri = format!(
+            "/2018-11-05/origin-access-identity/cloudfront/{id}",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteCloudFrontOriginAccessIdentity");
+        request.add_resource_id("Id", &input.id.to_string());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteCloudFrontOriginAccessIdentityError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Delete a distribution. </p>
+    #[allow(unused_variables, warnings)]
+    fn delete_distribution(
+        &self,
+        input: DeleteDistributionRequest,
+    ) -> RusotoFuture<(), DeleteDistributionError> {
+        let request_uri = format!("/2018-11-05/distribution/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteDistribution");
+        request.add_resource_id("Id", &input.id.to_string());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDistributionError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Remove a field-level encryption configuration.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_field_level_encryption_config(
+        &self,
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<(), DeleteFieldLevelEncryptionConfigError> {
+        let request_uri = format!("/2018-11-05/field-level-encryption/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteFieldLevelEncryptionConfig");
+        request.add_resource_id("Id", &input.id.to_string());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteFieldLevelEncryptionConfigError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Remove a field-level encryption profile.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_field_level_encryption_profile(
+        &self,
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<(), DeleteFieldLevelEncryptionProfileError> {
+        let request_uri = format!(
+            "/2018-11-05/field-level-encryption-profile/{id}",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteFieldLevelEncryptionProfile");
+        request.add_resource_id("Id", &input.id.to_string());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteFieldLevelEncryptionProfileError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Remove a public key you previously added to CloudFront.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_public_key(
+        &self,
+        input: DeletePublicKeyRequest,
+    ) -> RusotoFuture<(), DeletePublicKeyError> {
+        let request_uri = format!("/2018-11-05/public-key/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeletePublicKey");
+        request.add_resource_id("Id", &input.id.to_string());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeletePublicKeyError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Delete a streaming distribution. To delete an RTMP distribution using the CloudFront API, perform the following steps.</p> <p> <b>To delete an RTMP distribution using the CloudFront API</b>:</p> <ol> <li> <p>Disable the RTMP distribution.</p> </li> <li> <p>Submit a <code>GET Streaming Distribution Config</code> request to get the current configuration and the <code>Etag</code> header for the distribution. </p> </li> <li> <p>Update the XML document that was returned in the response to your <code>GET Streaming Distribution Config</code> request to change the value of <code>Enabled</code> to <code>false</code>.</p> </li> <li> <p>Submit a <code>PUT Streaming Distribution Config</code> request to update the configuration for your distribution. In the request body, include the XML document that you updated in Step 3. Then set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Streaming Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to the <code>PUT Streaming Distribution Config</code> request to confirm that the distribution was successfully disabled.</p> </li> <li> <p>Submit a <code>GET Streaming Distribution Config</code> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> <li> <p>Submit a <code>DELETE Streaming Distribution</code> request. Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Streaming Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to your <code>DELETE Streaming Distribution</code> request to confirm that the distribution was successfully deleted.</p> </li> </ol> <p>For information about deleting a distribution using the CloudFront console, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/HowToDeleteDistribution.html">Deleting a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_streaming_distribution(
+        &self,
+        input: DeleteStreamingDistributionRequest,
+    ) -> RusotoFuture<(), DeleteStreamingDistributionError> {
+        let request_uri = format!("/2018-11-05/streaming-distribution/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteStreamingDistribution");
+        request.add_resource_id("Id", &input.id.to_string());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteStreamingDistributionError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Get the information about an origin access identity. </p>
+    #[allow(unused_variables, warnings)]
+    fn get_cloud_front_origin_access_identity(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<GetCloudFrontOriginAccessIdentityResult, GetCloudFrontOriginAccessIdentityError>
+    {
+        let request_uri = format!(
+            "/2018-11-05/origin-access-identity/cloudfront/{id}",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCloudFrontOriginAccessIdentity");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetCloudFrontOriginAccessIdentityError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetCloudFrontOriginAccessIdentityResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetCloudFrontOriginAccessIdentityResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetCloudFrontOriginAccessIdentity", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Get the configuration information about an origin access identity. </p>
+    #[allow(unused_variables, warnings)]
+    fn get_cloud_front_origin_access_identity_config(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> RusotoFuture<
+        GetCloudFrontOriginAccessIdentityConfigResult,
+        GetCloudFrontOriginAccessIdentityConfigError,
+    > {
+        let request_uri = format!(
+            "/2018-11-05/origin-access-identity/cloudfront/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCloudFrontOriginAccessIdentityConfig");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetCloudFrontOriginAccessIdentityConfigError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetCloudFrontOriginAccessIdentityConfigResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        GetCloudFrontOriginAccessIdentityConfigResultDeserializer::deserialize(
+                            &actual_tag_name,
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "GetCloudFrontOriginAccessIdentityConfig",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Get the information about a distribution. </p>
+    #[allow(unused_variables, warnings)]
+    fn get_distribution(
+        &self,
+        input: GetDistributionRequest,
+    ) -> RusotoFuture<GetDistributionResult, GetDistributionError> {
+        let request_uri = format!("/2018-11-05/distribution/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetDistribution");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDistributionError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetDistributionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetDistributionResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetDistribution", err, &response.body))?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Get the configuration information about a distribution. </p>
+    #[allow(unused_variables, warnings)]
+    fn get_distribution_config(
+        &self,
+        input: GetDistributionConfigRequest,
+    ) -> RusotoFuture<GetDistributionConfigResult, GetDistributionConfigError> {
+        let request_uri = format!("/2018-11-05/distribution/{id}/config", id = input.id);
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetDistributionConfig");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetDistributionConfigError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetDistributionConfigResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetDistributionConfigResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetDistributionConfig", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Get the field-level encryption configuration information.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_field_level_encryption(
+        &self,
+        input: GetFieldLevelEncryptionRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionResult, GetFieldLevelEncryptionError> {
+        let request_uri = format!("/2018-11-05/field-level-encryption/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryption");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetFieldLevelEncryptionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetFieldLevelEncryptionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetFieldLevelEncryptionResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetFieldLevelEncryption", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Get the field-level encryption configuration information.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_field_level_encryption_config(
+        &self,
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionConfigResult, GetFieldLevelEncryptionConfigError> {
+        let request_uri = format!(
+            "/2018-11-05/field-level-encryption/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionConfig");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetFieldLevelEncryptionConfigError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetFieldLevelEncryptionConfigResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetFieldLevelEncryptionConfigResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetFieldLevelEncryptionConfig", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Get the field-level encryption profile information.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_field_level_encryption_profile(
+        &self,
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionProfileResult, GetFieldLevelEncryptionProfileError>
+    {
+        let request_uri = format!(
+            "/2018-11-05/field-level-encryption-profile/{id}",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionProfile");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetFieldLevelEncryptionProfileError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetFieldLevelEncryptionProfileResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetFieldLevelEncryptionProfileResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetFieldLevelEncryptionProfile", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Get the field-level encryption profile configuration information.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_field_level_encryption_profile_config(
+        &self,
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> RusotoFuture<
+        GetFieldLevelEncryptionProfileConfigResult,
+        GetFieldLevelEncryptionProfileConfigError,
+    > {
+        let request_uri = format!(
+            "/2018-11-05/field-level-encryption-profile/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionProfileConfig");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetFieldLevelEncryptionProfileConfigError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetFieldLevelEncryptionProfileConfigResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetFieldLevelEncryptionProfileConfigResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "GetFieldLevelEncryptionProfileConfig",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Get the information about an invalidation. </p>
+    #[allow(unused_variables, warnings)]
+    fn get_invalidation(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> RusotoFuture<GetInvalidationResult, GetInvalidationError> {
+        let request_uri = format!(
+            "/2018-11-05/distribution/{distribution_id}/invalidation/{id}",
+            distribution_id = input.distribution_id,
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetInvalidation");
+        request.add_resource_id("DistributionId", &input.distribution_id.to_string());
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetInvalidationError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetInvalidationResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetInvalidationResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetInvalidation", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Get the public key information.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> RusotoFuture<GetPublicKeyResult, GetPublicKeyError> {
+        let request_uri = format!("/2018-11-05/public-key/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetPublicKey");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPublicKeyError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetPublicKeyResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        GetPublicKeyResultDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("GetPublicKey", err, &response.body))?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Return public key configuration informaation</p>
+    #[allow(unused_variables, warnings)]
+    fn get_public_key_config(
+        &self,
+        input: GetPublicKeyConfigRequest,
+    ) -> RusotoFuture<GetPublicKeyConfigResult, GetPublicKeyConfigError> {
+        let request_uri = format!("/2018-11-05/public-key/{id}/config", id = input.id);
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetPublicKeyConfig");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPublicKeyConfigError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetPublicKeyConfigResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetPublicKeyConfigResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetPublicKeyConfig", err, &response.body))?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about a specified RTMP distribution, including the distribution configuration.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_streaming_distribution(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> RusotoFuture<GetStreamingDistributionResult, GetStreamingDistributionError> {
+        let request_uri = format!("/2018-11-05/streaming-distribution/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetStreamingDistribution");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetStreamingDistributionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetStreamingDistributionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetStreamingDistributionResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetStreamingDistribution", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Get the configuration information about a streaming distribution. </p>
+    #[allow(unused_variables, warnings)]
+    fn get_streaming_distribution_config(
+        &self,
+        input: GetStreamingDistributionConfigRequest,
+    ) -> RusotoFuture<GetStreamingDistributionConfigResult, GetStreamingDistributionConfigError>
+    {
+        let request_uri = format!(
+            "/2018-11-05/streaming-distribution/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetStreamingDistributionConfig");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetStreamingDistributionConfigError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetStreamingDistributionConfigResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetStreamingDistributionConfigResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetStreamingDistributionConfig", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists origin access identities.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_cloud_front_origin_access_identities(
+        &self,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> RusotoFuture<
+        ListCloudFrontOriginAccessIdentitiesResult,
+        ListCloudFrontOriginAccessIdentitiesError,
+    > {
+        let request_uri = "/2018-11-05/origin-access-identity/cloudfront";
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListCloudFrontOriginAccessIdentities");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListCloudFrontOriginAccessIdentitiesError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListCloudFrontOriginAccessIdentitiesResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListCloudFrontOriginAccessIdentitiesResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "ListCloudFrontOriginAccessIdentities",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>List distributions. </p>
+    #[allow(unused_variables, warnings)]
+    fn list_distributions(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> RusotoFuture<ListDistributionsResult, ListDistributionsError> {
+        let request_uri = "/2018-11-05/distribution";
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributions");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDistributionsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListDistributionsResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListDistributionsResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListDistributions", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>List the distributions that are associated with a specified AWS WAF web ACL. </p>
+    #[allow(unused_variables, warnings)]
+    fn list_distributions_by_web_acl_id(
+        &self,
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> RusotoFuture<ListDistributionsByWebACLIdResult, ListDistributionsByWebACLIdError> {
+        let request_uri = format!(
+            "/2018-11-05/distributionsByWebACLId/{web_acl_id}",
+            web_acl_id = input.web_acl_id
+        );
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributionsByWebACLId");
+        request.add_resource_id("WebACLId", &input.web_acl_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListDistributionsByWebACLIdError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListDistributionsByWebACLIdResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListDistributionsByWebACLIdResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListDistributionsByWebACLId", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>List all field-level encryption configurations that have been created in CloudFront for this account.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_field_level_encryption_configs(
+        &self,
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> RusotoFuture<ListFieldLevelEncryptionConfigsResult, ListFieldLevelEncryptionConfigsError>
+    {
+        let request_uri = "/2018-11-05/field-level-encryption";
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListFieldLevelEncryptionConfigs");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListFieldLevelEncryptionConfigsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListFieldLevelEncryptionConfigsResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListFieldLevelEncryptionConfigsResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListFieldLevelEncryptionConfigs", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Request a list of field-level encryption profiles that have been created in CloudFront for this account.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_field_level_encryption_profiles(
+        &self,
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> RusotoFuture<ListFieldLevelEncryptionProfilesResult, ListFieldLevelEncryptionProfilesError>
+    {
+        let request_uri = "/2018-11-05/field-level-encryption-profile";
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListFieldLevelEncryptionProfiles");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListFieldLevelEncryptionProfilesError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListFieldLevelEncryptionProfilesResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListFieldLevelEncryptionProfilesResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListFieldLevelEncryptionProfiles", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists invalidation batches. </p>
+    #[allow(unused_variables, warnings)]
+    fn list_invalidations(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> RusotoFuture<ListInvalidationsResult, ListInvalidationsError> {
+        let request_uri = format!(
+            "/2018-11-05/distribution/{distribution_id}/invalidation",
+            distribution_id = input.distribution_id
+        );
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListInvalidations");
+        request.add_resource_id("DistributionId", &input.distribution_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListInvalidationsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListInvalidationsResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListInvalidationsResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListInvalidations", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>List all public keys that have been added to CloudFront for this account.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> RusotoFuture<ListPublicKeysResult, ListPublicKeysError> {
+        let request_uri = "/2018-11-05/public-key";
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListPublicKeys");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListPublicKeysError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListPublicKeysResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        ListPublicKeysResultDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("ListPublicKeys", err, &response.body)
+                            })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>List streaming distributions. </p>
+    #[allow(unused_variables, warnings)]
+    fn list_streaming_distributions(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> RusotoFuture<ListStreamingDistributionsResult, ListStreamingDistributionsError> {
+        let request_uri = "/2018-11-05/streaming-distribution";
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListStreamingDistributions");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListStreamingDistributionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListStreamingDistributionsResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListStreamingDistributionsResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListStreamingDistributions", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>List tags for a CloudFront resource.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2018-11-05/tagging";
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        params.put("Resource", &input.resource);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListTagsForResourceResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListTagsForResourceResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListTagsForResource", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Add tags to a CloudFront resource.</p>
+    #[allow(unused_variables, warnings)]
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2018-11-05/tagging";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("TagResource");
+
+        let mut params = Params::new();
+        params.put("Resource", &input.resource);
+        params.put("Operation", "Tag");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        TagsSerializer::serialize(&mut writer, "Tags", &input.tags);
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Remove tags from a CloudFront resource.</p>
+    #[allow(unused_variables, warnings)]
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2018-11-05/tagging";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+
+        let mut params = Params::new();
+        params.put("Resource", &input.resource);
+        params.put("Operation", "Untag");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        TagKeysSerializer::serialize(&mut writer, "TagKeys", &input.tag_keys);
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Update an origin access identity. </p>
+    #[allow(unused_variables, warnings)]
+    fn update_cloud_front_origin_access_identity(
+        &self,
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<
+        UpdateCloudFrontOriginAccessIdentityResult,
+        UpdateCloudFrontOriginAccessIdentityError,
+    > {
+        let request_uri = format!(
+            "/2018-11-05/origin-access-identity/cloudfront/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateCloudFrontOriginAccessIdentity");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        let mut writer = EventWriter::new(Vec::new());
+        CloudFrontOriginAccessIdentityConfigSerializer::serialize(
+            &mut writer,
+            "CloudFrontOriginAccessIdentityConfig",
+            &input.cloud_front_origin_access_identity_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateCloudFrontOriginAccessIdentityError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UpdateCloudFrontOriginAccessIdentityResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = UpdateCloudFrontOriginAccessIdentityResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "UpdateCloudFrontOriginAccessIdentity",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Updates the configuration for a web distribution. </p> <important> <p>When you update a distribution, there are more required fields than when you create a distribution. When you update your distribution by using this API action, follow the steps here to get the current configuration and then make your updates, to make sure that you include all of the required fields. To view a summary, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-overview-required-fields.html">Required Fields for Create Distribution and Update Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </important> <p>The update process includes getting the current distribution configuration, updating the XML document that is returned to make your changes, and then submitting an <code>UpdateDistribution</code> request to make the updates.</p> <p>For information about updating a distribution using the CloudFront console instead, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-web-creating-console.html">Creating a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <p> <b>To update a web distribution using the CloudFront API</b> </p> <ol> <li> <p>Submit a <a>GetDistributionConfig</a> request to get the current configuration and an <code>Etag</code> header for the distribution.</p> <note> <p>If you update the distribution again, you must get a new <code>Etag</code> header.</p> </note> </li> <li> <p>Update the XML document that was returned in the response to your <code>GetDistributionConfig</code> request to include your changes. </p> <important> <p>When you edit the XML file, be aware of the following:</p> <ul> <li> <p>You must strip out the ETag parameter that is returned.</p> </li> <li> <p>Additional fields are required when you update a distribution. There may be fields included in the XML file for features that you haven&#39;t configured for your distribution. This is expected and required to successfully update the distribution.</p> </li> <li> <p>You can&#39;t change the value of <code>CallerReference</code>. If you try to change this value, CloudFront returns an <code>IllegalUpdate</code> error. </p> </li> <li> <p>The new configuration replaces the existing configuration; the values that you specify in an <code>UpdateDistribution</code> request are not merged into your existing configuration. When you add, delete, or replace values in an element that allows multiple values (for example, <code>CNAME</code>), you must specify all of the values that you want to appear in the updated distribution. In addition, you must update the corresponding <code>Quantity</code> element.</p> </li> </ul> </important> </li> <li> <p>Submit an <code>UpdateDistribution</code> request to update the configuration for your distribution:</p> <ul> <li> <p>In the request body, include the XML document that you updated in Step 2. The request body must include an XML document with a <code>DistributionConfig</code> element.</p> </li> <li> <p>Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GetDistributionConfig</code> request in Step 1.</p> </li> </ul> </li> <li> <p>Review the response to the <code>UpdateDistribution</code> request to confirm that the configuration was successfully updated.</p> </li> <li> <p>Optional: Submit a <a>GetDistribution</a> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> </ol></p>
+    #[allow(unused_variables, warnings)]
+    fn update_distribution(
+        &self,
+        input: UpdateDistributionRequest,
+    ) -> RusotoFuture<UpdateDistributionResult, UpdateDistributionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2018-11-05/distribution/{id}/config", id = input.id);
+
+        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateDistribution");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        let mut writer = EventWriter::new(Vec::new());
+        DistributionConfigSerializer::serialize(
+            &mut writer,
+            "DistributionConfig",
+            &input.distribution_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDistributionError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UpdateDistributionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = UpdateDistributionResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("UpdateDistribution", err, &response.body))?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Update a field-level encryption configuration. </p>
+    #[allow(unused_variables, warnings)]
+    fn update_field_level_encryption_config(
+        &self,
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<UpdateFieldLevelEncryptionConfigResult, UpdateFieldLevelEncryptionConfigError>
+    {
+        let request_uri = format!(
+            "/2018-11-05/field-level-encryption/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateFieldLevelEncryptionConfig");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        let mut writer = EventWriter::new(Vec::new());
+        FieldLevelEncryptionConfigSerializer::serialize(
+            &mut writer,
+            "FieldLevelEncryptionConfig",
+            &input.field_level_encryption_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateFieldLevelEncryptionConfigError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UpdateFieldLevelEncryptionConfigResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = UpdateFieldLevelEncryptionConfigResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateFieldLevelEncryptionConfig", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Update a field-level encryption profile. </p>
+    #[allow(unused_variables, warnings)]
+    fn update_field_level_encryption_profile(
+        &self,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<UpdateFieldLevelEncryptionProfileResult, UpdateFieldLevelEncryptionProfileError>
+    {
+        let request_uri = format!(
+            "/2018-11-05/field-level-encryption-profile/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateFieldLevelEncryptionProfile");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        let mut writer = EventWriter::new(Vec::new());
+        FieldLevelEncryptionProfileConfigSerializer::serialize(
+            &mut writer,
+            "FieldLevelEncryptionProfileConfig",
+            &input.field_level_encryption_profile_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateFieldLevelEncryptionProfileError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UpdateFieldLevelEncryptionProfileResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = UpdateFieldLevelEncryptionProfileResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateFieldLevelEncryptionProfile", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Update public key information. Note that the only value you can change is the comment.</p>
+    #[allow(unused_variables, warnings)]
+    fn update_public_key(
+        &self,
+        input: UpdatePublicKeyRequest,
+    ) -> RusotoFuture<UpdatePublicKeyResult, UpdatePublicKeyError> {
+        let request_uri = format!("/2018-11-05/public-key/{id}/config", id = input.id);
+
+        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdatePublicKey");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        let mut writer = EventWriter::new(Vec::new());
+        PublicKeyConfigSerializer::serialize(
+            &mut writer,
+            "PublicKeyConfig",
+            &input.public_key_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdatePublicKeyError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UpdatePublicKeyResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = UpdatePublicKeyResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("UpdatePublicKey", err, &response.body))?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Update a streaming distribution. </p>
+    #[allow(unused_variables, warnings)]
+    fn update_streaming_distribution(
+        &self,
+        input: UpdateStreamingDistributionRequest,
+    ) -> RusotoFuture<UpdateStreamingDistributionResult, UpdateStreamingDistributionError> {
+        let request_uri = format!(
+            "/2018-11-05/streaming-distribution/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateStreamingDistribution");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        let mut writer = EventWriter::new(Vec::new());
+        StreamingDistributionConfigSerializer::serialize(
+            &mut writer,
+            "StreamingDistributionConfig",
+            &input.streaming_distribution_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateStreamingDistributionError::from_response(response))
                 }));
             }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UpdateStreamingDistributionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UpdateStreamingDistributionResultDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UpdateStreamingDistributionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = UpdateStreamingDistributionResultDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateStreamingDistribution", err, &response.body)
+                    })?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+}
+
+/// A mock of the CloudFront API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloudFront {
+    create_cloud_front_origin_access_identity: Option<
+        Box<
+            dyn Fn(
+                    CreateCloudFrontOriginAccessIdentityRequest,
+                ) -> ::std::result::Result<
+                    CreateCloudFrontOriginAccessIdentityResult,
+                    RusotoError<CreateCloudFrontOriginAccessIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_distribution: Option<
+        Box<
+            dyn Fn(
+                    CreateDistributionRequest,
+                ) -> ::std::result::Result<
+                    CreateDistributionResult,
+                    RusotoError<CreateDistributionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_distribution_with_tags: Option<
+        Box<
+            dyn Fn(
+                    CreateDistributionWithTagsRequest,
+                ) -> ::std::result::Result<
+                    CreateDistributionWithTagsResult,
+                    RusotoError<CreateDistributionWithTagsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_field_level_encryption_config: Option<
+        Box<
+            dyn Fn(
+                    CreateFieldLevelEncryptionConfigRequest,
+                ) -> ::std::result::Result<
+                    CreateFieldLevelEncryptionConfigResult,
+                    RusotoError<CreateFieldLevelEncryptionConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_field_level_encryption_profile: Option<
+        Box<
+            dyn Fn(
+                    CreateFieldLevelEncryptionProfileRequest,
+                ) -> ::std::result::Result<
+                    CreateFieldLevelEncryptionProfileResult,
+                    RusotoError<CreateFieldLevelEncryptionProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_invalidation: Option<
+        Box<
+            dyn Fn(
+                    CreateInvalidationRequest,
+                ) -> ::std::result::Result<
+                    CreateInvalidationResult,
+                    RusotoError<CreateInvalidationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_public_key: Option<
+        Box<
+            dyn Fn(
+                    CreatePublicKeyRequest,
+                ) -> ::std::result::Result<
+                    CreatePublicKeyResult,
+                    RusotoError<CreatePublicKeyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_streaming_distribution: Option<
+        Box<
+            dyn Fn(
+                    CreateStreamingDistributionRequest,
+                ) -> ::std::result::Result<
+                    CreateStreamingDistributionResult,
+                    RusotoError<CreateStreamingDistributionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_streaming_distribution_with_tags: Option<
+        Box<
+            dyn Fn(
+                    CreateStreamingDistributionWithTagsRequest,
+                ) -> ::std::result::Result<
+                    CreateStreamingDistributionWithTagsResult,
+                    RusotoError<CreateStreamingDistributionWithTagsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_cloud_front_origin_access_identity: Option<
+        Box<
+            dyn Fn(
+                    DeleteCloudFrontOriginAccessIdentityRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<DeleteCloudFrontOriginAccessIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_distribution: Option<
+        Box<
+            dyn Fn(
+                    DeleteDistributionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteDistributionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_field_level_encryption_config: Option<
+        Box<
+            dyn Fn(
+                    DeleteFieldLevelEncryptionConfigRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteFieldLevelEncryptionConfigError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_field_level_encryption_profile: Option<
+        Box<
+            dyn Fn(
+                    DeleteFieldLevelEncryptionProfileRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteFieldLevelEncryptionProfileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_public_key: Option<
+        Box<
+            dyn Fn(
+                    DeletePublicKeyRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeletePublicKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_streaming_distribution: Option<
+        Box<
+            dyn Fn(
+                    DeleteStreamingDistributionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteStreamingDistributionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_cloud_front_origin_access_identity: Option<
+        Box<
+            dyn Fn(
+                    GetCloudFrontOriginAccessIdentityRequest,
+                ) -> ::std::result::Result<
+                    GetCloudFrontOriginAccessIdentityResult,
+                    RusotoError<GetCloudFrontOriginAccessIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_cloud_front_origin_access_identity_config: Option<
+        Box<
+            dyn Fn(
+                    GetCloudFrontOriginAccessIdentityConfigRequest,
+                ) -> ::std::result::Result<
+                    GetCloudFrontOriginAccessIdentityConfigResult,
+                    RusotoError<GetCloudFrontOriginAccessIdentityConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_distribution: Option<
+        Box<
+            dyn Fn(
+                    GetDistributionRequest,
+                ) -> ::std::result::Result<
+                    GetDistributionResult,
+                    RusotoError<GetDistributionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_distribution_config: Option<
+        Box<
+            dyn Fn(
+                    GetDistributionConfigRequest,
+                ) -> ::std::result::Result<
+                    GetDistributionConfigResult,
+                    RusotoError<GetDistributionConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_field_level_encryption: Option<
+        Box<
+            dyn Fn(
+                    GetFieldLevelEncryptionRequest,
+                ) -> ::std::result::Result<
+                    GetFieldLevelEncryptionResult,
+                    RusotoError<GetFieldLevelEncryptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_field_level_encryption_config: Option<
+        Box<
+            dyn Fn(
+                    GetFieldLevelEncryptionConfigRequest,
+                ) -> ::std::result::Result<
+                    GetFieldLevelEncryptionConfigResult,
+                    RusotoError<GetFieldLevelEncryptionConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_field_level_encryption_profile: Option<
+        Box<
+            dyn Fn(
+                    GetFieldLevelEncryptionProfileRequest,
+                ) -> ::std::result::Result<
+                    GetFieldLevelEncryptionProfileResult,
+                    RusotoError<GetFieldLevelEncryptionProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_field_level_encryption_profile_config: Option<
+        Box<
+            dyn Fn(
+                    GetFieldLevelEncryptionProfileConfigRequest,
+                ) -> ::std::result::Result<
+                    GetFieldLevelEncryptionProfileConfigResult,
+                    RusotoError<GetFieldLevelEncryptionProfileConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_invalidation: Option<
+        Box<
+            dyn Fn(
+                    GetInvalidationRequest,
+                ) -> ::std::result::Result<
+                    GetInvalidationResult,
+                    RusotoError<GetInvalidationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_public_key: Option<
+        Box<
+            dyn Fn(
+                    GetPublicKeyRequest,
+                )
+                    -> ::std::result::Result<GetPublicKeyResult, RusotoError<GetPublicKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_public_key_config: Option<
+        Box<
+            dyn Fn(
+                    GetPublicKeyConfigRequest,
+                ) -> ::std::result::Result<
+                    GetPublicKeyConfigResult,
+                    RusotoError<GetPublicKeyConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_streaming_distribution: Option<
+        Box<
+            dyn Fn(
+                    GetStreamingDistributionRequest,
+                ) -> ::std::result::Result<
+                    GetStreamingDistributionResult,
+                    RusotoError<GetStreamingDistributionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_streaming_distribution_config: Option<
+        Box<
+            dyn Fn(
+                    GetStreamingDistributionConfigRequest,
+                ) -> ::std::result::Result<
+                    GetStreamingDistributionConfigResult,
+                    RusotoError<GetStreamingDistributionConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_cloud_front_origin_access_identities: Option<
+        Box<
+            dyn Fn(
+                    ListCloudFrontOriginAccessIdentitiesRequest,
+                ) -> ::std::result::Result<
+                    ListCloudFrontOriginAccessIdentitiesResult,
+                    RusotoError<ListCloudFrontOriginAccessIdentitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_distributions: Option<
+        Box<
+            dyn Fn(
+                    ListDistributionsRequest,
+                ) -> ::std::result::Result<
+                    ListDistributionsResult,
+                    RusotoError<ListDistributionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_distributions_by_web_acl_id: Option<
+        Box<
+            dyn Fn(
+                    ListDistributionsByWebACLIdRequest,
+                ) -> ::std::result::Result<
+                    ListDistributionsByWebACLIdResult,
+                    RusotoError<ListDistributionsByWebACLIdError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_field_level_encryption_configs: Option<
+        Box<
+            dyn Fn(
+                    ListFieldLevelEncryptionConfigsRequest,
+                ) -> ::std::result::Result<
+                    ListFieldLevelEncryptionConfigsResult,
+                    RusotoError<ListFieldLevelEncryptionConfigsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_field_level_encryption_profiles: Option<
+        Box<
+            dyn Fn(
+                    ListFieldLevelEncryptionProfilesRequest,
+                ) -> ::std::result::Result<
+                    ListFieldLevelEncryptionProfilesResult,
+                    RusotoError<ListFieldLevelEncryptionProfilesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_invalidations: Option<
+        Box<
+            dyn Fn(
+                    ListInvalidationsRequest,
+                ) -> ::std::result::Result<
+                    ListInvalidationsResult,
+                    RusotoError<ListInvalidationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_public_keys: Option<
+        Box<
+            dyn Fn(
+                    ListPublicKeysRequest,
+                )
+                    -> ::std::result::Result<ListPublicKeysResult, RusotoError<ListPublicKeysError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_streaming_distributions: Option<
+        Box<
+            dyn Fn(
+                    ListStreamingDistributionsRequest,
+                ) -> ::std::result::Result<
+                    ListStreamingDistributionsResult,
+                    RusotoError<ListStreamingDistributionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResult,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_cloud_front_origin_access_identity: Option<
+        Box<
+            dyn Fn(
+                    UpdateCloudFrontOriginAccessIdentityRequest,
+                ) -> ::std::result::Result<
+                    UpdateCloudFrontOriginAccessIdentityResult,
+                    RusotoError<UpdateCloudFrontOriginAccessIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_distribution: Option<
+        Box<
+            dyn Fn(
+                    UpdateDistributionRequest,
+                ) -> ::std::result::Result<
+                    UpdateDistributionResult,
+                    RusotoError<UpdateDistributionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_field_level_encryption_config: Option<
+        Box<
+            dyn Fn(
+                    UpdateFieldLevelEncryptionConfigRequest,
+                ) -> ::std::result::Result<
+                    UpdateFieldLevelEncryptionConfigResult,
+                    RusotoError<UpdateFieldLevelEncryptionConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_field_level_encryption_profile: Option<
+        Box<
+            dyn Fn(
+                    UpdateFieldLevelEncryptionProfileRequest,
+                ) -> ::std::result::Result<
+                    UpdateFieldLevelEncryptionProfileResult,
+                    RusotoError<UpdateFieldLevelEncryptionProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_public_key: Option<
+        Box<
+            dyn Fn(
+                    UpdatePublicKeyRequest,
+                ) -> ::std::result::Result<
+                    UpdatePublicKeyResult,
+                    RusotoError<UpdatePublicKeyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_streaming_distribution: Option<
+        Box<
+            dyn Fn(
+                    UpdateStreamingDistributionRequest,
+                ) -> ::std::result::Result<
+                    UpdateStreamingDistributionResult,
+                    RusotoError<UpdateStreamingDistributionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloudFront {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloudFront {
+        Default::default()
+    }
+
+    /// Answers `create_cloud_front_origin_access_identity` with what `handler` returns for its input.
+    pub fn with_create_cloud_front_origin_access_identity<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                CreateCloudFrontOriginAccessIdentityRequest,
+            ) -> ::std::result::Result<
+                CreateCloudFrontOriginAccessIdentityResult,
+                RusotoError<CreateCloudFrontOriginAccessIdentityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cloud_front_origin_access_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_distribution` with what `handler` returns for its input.
+    pub fn with_create_distribution<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                CreateDistributionRequest,
+            ) -> ::std::result::Result<
+                CreateDistributionResult,
+                RusotoError<CreateDistributionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_distribution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_distribution_with_tags` with what `handler` returns for its input.
+    pub fn with_create_distribution_with_tags<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                CreateDistributionWithTagsRequest,
+            ) -> ::std::result::Result<
+                CreateDistributionWithTagsResult,
+                RusotoError<CreateDistributionWithTagsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_distribution_with_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_field_level_encryption_config` with what `handler` returns for its input.
+    pub fn with_create_field_level_encryption_config<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                CreateFieldLevelEncryptionConfigRequest,
+            ) -> ::std::result::Result<
+                CreateFieldLevelEncryptionConfigResult,
+                RusotoError<CreateFieldLevelEncryptionConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_field_level_encryption_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_field_level_encryption_profile` with what `handler` returns for its input.
+    pub fn with_create_field_level_encryption_profile<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                CreateFieldLevelEncryptionProfileRequest,
+            ) -> ::std::result::Result<
+                CreateFieldLevelEncryptionProfileResult,
+                RusotoError<CreateFieldLevelEncryptionProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_field_level_encryption_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_invalidation` with what `handler` returns for its input.
+    pub fn with_create_invalidation<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                CreateInvalidationRequest,
+            ) -> ::std::result::Result<
+                CreateInvalidationResult,
+                RusotoError<CreateInvalidationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_invalidation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_public_key` with what `handler` returns for its input.
+    pub fn with_create_public_key<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                CreatePublicKeyRequest,
+            )
+                -> ::std::result::Result<CreatePublicKeyResult, RusotoError<CreatePublicKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_public_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_streaming_distribution` with what `handler` returns for its input.
+    pub fn with_create_streaming_distribution<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                CreateStreamingDistributionRequest,
+            ) -> ::std::result::Result<
+                CreateStreamingDistributionResult,
+                RusotoError<CreateStreamingDistributionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_streaming_distribution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_streaming_distribution_with_tags` with what `handler` returns for its input.
+    pub fn with_create_streaming_distribution_with_tags<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                CreateStreamingDistributionWithTagsRequest,
+            ) -> ::std::result::Result<
+                CreateStreamingDistributionWithTagsResult,
+                RusotoError<CreateStreamingDistributionWithTagsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_streaming_distribution_with_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cloud_front_origin_access_identity` with what `handler` returns for its input.
+    pub fn with_delete_cloud_front_origin_access_identity<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                DeleteCloudFrontOriginAccessIdentityRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteCloudFrontOriginAccessIdentityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cloud_front_origin_access_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_distribution` with what `handler` returns for its input.
+    pub fn with_delete_distribution<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                DeleteDistributionRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDistributionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_distribution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_field_level_encryption_config` with what `handler` returns for its input.
+    pub fn with_delete_field_level_encryption_config<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                DeleteFieldLevelEncryptionConfigRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteFieldLevelEncryptionConfigError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_field_level_encryption_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_field_level_encryption_profile` with what `handler` returns for its input.
+    pub fn with_delete_field_level_encryption_profile<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                DeleteFieldLevelEncryptionProfileRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteFieldLevelEncryptionProfileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_field_level_encryption_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_public_key` with what `handler` returns for its input.
+    pub fn with_delete_public_key<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                DeletePublicKeyRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeletePublicKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_public_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_streaming_distribution` with what `handler` returns for its input.
+    pub fn with_delete_streaming_distribution<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                DeleteStreamingDistributionRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteStreamingDistributionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_streaming_distribution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_cloud_front_origin_access_identity` with what `handler` returns for its input.
+    pub fn with_get_cloud_front_origin_access_identity<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetCloudFrontOriginAccessIdentityRequest,
+            ) -> ::std::result::Result<
+                GetCloudFrontOriginAccessIdentityResult,
+                RusotoError<GetCloudFrontOriginAccessIdentityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_cloud_front_origin_access_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_cloud_front_origin_access_identity_config` with what `handler` returns for its input.
+    pub fn with_get_cloud_front_origin_access_identity_config<F>(
+        mut self,
+        handler: F,
+    ) -> MockCloudFront
+    where
+        F: Fn(
+                GetCloudFrontOriginAccessIdentityConfigRequest,
+            ) -> ::std::result::Result<
+                GetCloudFrontOriginAccessIdentityConfigResult,
+                RusotoError<GetCloudFrontOriginAccessIdentityConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_cloud_front_origin_access_identity_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_distribution` with what `handler` returns for its input.
+    pub fn with_get_distribution<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetDistributionRequest,
+            )
+                -> ::std::result::Result<GetDistributionResult, RusotoError<GetDistributionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_distribution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_distribution_config` with what `handler` returns for its input.
+    pub fn with_get_distribution_config<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetDistributionConfigRequest,
+            ) -> ::std::result::Result<
+                GetDistributionConfigResult,
+                RusotoError<GetDistributionConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_distribution_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_field_level_encryption` with what `handler` returns for its input.
+    pub fn with_get_field_level_encryption<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetFieldLevelEncryptionRequest,
+            ) -> ::std::result::Result<
+                GetFieldLevelEncryptionResult,
+                RusotoError<GetFieldLevelEncryptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_field_level_encryption = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_field_level_encryption_config` with what `handler` returns for its input.
+    pub fn with_get_field_level_encryption_config<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetFieldLevelEncryptionConfigRequest,
+            ) -> ::std::result::Result<
+                GetFieldLevelEncryptionConfigResult,
+                RusotoError<GetFieldLevelEncryptionConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_field_level_encryption_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_field_level_encryption_profile` with what `handler` returns for its input.
+    pub fn with_get_field_level_encryption_profile<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetFieldLevelEncryptionProfileRequest,
+            ) -> ::std::result::Result<
+                GetFieldLevelEncryptionProfileResult,
+                RusotoError<GetFieldLevelEncryptionProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_field_level_encryption_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_field_level_encryption_profile_config` with what `handler` returns for its input.
+    pub fn with_get_field_level_encryption_profile_config<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetFieldLevelEncryptionProfileConfigRequest,
+            ) -> ::std::result::Result<
+                GetFieldLevelEncryptionProfileConfigResult,
+                RusotoError<GetFieldLevelEncryptionProfileConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_field_level_encryption_profile_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_invalidation` with what `handler` returns for its input.
+    pub fn with_get_invalidation<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetInvalidationRequest,
+            )
+                -> ::std::result::Result<GetInvalidationResult, RusotoError<GetInvalidationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_invalidation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_public_key` with what `handler` returns for its input.
+    pub fn with_get_public_key<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetPublicKeyRequest,
+            )
+                -> ::std::result::Result<GetPublicKeyResult, RusotoError<GetPublicKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_public_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_public_key_config` with what `handler` returns for its input.
+    pub fn with_get_public_key_config<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetPublicKeyConfigRequest,
+            ) -> ::std::result::Result<
+                GetPublicKeyConfigResult,
+                RusotoError<GetPublicKeyConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_public_key_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_streaming_distribution` with what `handler` returns for its input.
+    pub fn with_get_streaming_distribution<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetStreamingDistributionRequest,
+            ) -> ::std::result::Result<
+                GetStreamingDistributionResult,
+                RusotoError<GetStreamingDistributionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_streaming_distribution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_streaming_distribution_config` with what `handler` returns for its input.
+    pub fn with_get_streaming_distribution_config<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                GetStreamingDistributionConfigRequest,
+            ) -> ::std::result::Result<
+                GetStreamingDistributionConfigResult,
+                RusotoError<GetStreamingDistributionConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_streaming_distribution_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_cloud_front_origin_access_identities` with what `handler` returns for its input.
+    pub fn with_list_cloud_front_origin_access_identities<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                ListCloudFrontOriginAccessIdentitiesRequest,
+            ) -> ::std::result::Result<
+                ListCloudFrontOriginAccessIdentitiesResult,
+                RusotoError<ListCloudFrontOriginAccessIdentitiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_cloud_front_origin_access_identities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_distributions` with what `handler` returns for its input.
+    pub fn with_list_distributions<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                ListDistributionsRequest,
+            ) -> ::std::result::Result<
+                ListDistributionsResult,
+                RusotoError<ListDistributionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_distributions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_distributions_by_web_acl_id` with what `handler` returns for its input.
+    pub fn with_list_distributions_by_web_acl_id<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                ListDistributionsByWebACLIdRequest,
+            ) -> ::std::result::Result<
+                ListDistributionsByWebACLIdResult,
+                RusotoError<ListDistributionsByWebACLIdError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_distributions_by_web_acl_id = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_field_level_encryption_configs` with what `handler` returns for its input.
+    pub fn with_list_field_level_encryption_configs<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                ListFieldLevelEncryptionConfigsRequest,
+            ) -> ::std::result::Result<
+                ListFieldLevelEncryptionConfigsResult,
+                RusotoError<ListFieldLevelEncryptionConfigsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_field_level_encryption_configs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_field_level_encryption_profiles` with what `handler` returns for its input.
+    pub fn with_list_field_level_encryption_profiles<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                ListFieldLevelEncryptionProfilesRequest,
+            ) -> ::std::result::Result<
+                ListFieldLevelEncryptionProfilesResult,
+                RusotoError<ListFieldLevelEncryptionProfilesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_field_level_encryption_profiles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_invalidations` with what `handler` returns for its input.
+    pub fn with_list_invalidations<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                ListInvalidationsRequest,
+            ) -> ::std::result::Result<
+                ListInvalidationsResult,
+                RusotoError<ListInvalidationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_invalidations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_public_keys` with what `handler` returns for its input.
+    pub fn with_list_public_keys<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                ListPublicKeysRequest,
+            )
+                -> ::std::result::Result<ListPublicKeysResult, RusotoError<ListPublicKeysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_public_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_streaming_distributions` with what `handler` returns for its input.
+    pub fn with_list_streaming_distributions<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                ListStreamingDistributionsRequest,
+            ) -> ::std::result::Result<
+                ListStreamingDistributionsResult,
+                RusotoError<ListStreamingDistributionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_streaming_distributions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResult,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_cloud_front_origin_access_identity` with what `handler` returns for its input.
+    pub fn with_update_cloud_front_origin_access_identity<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                UpdateCloudFrontOriginAccessIdentityRequest,
+            ) -> ::std::result::Result<
+                UpdateCloudFrontOriginAccessIdentityResult,
+                RusotoError<UpdateCloudFrontOriginAccessIdentityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_cloud_front_origin_access_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_distribution` with what `handler` returns for its input.
+    pub fn with_update_distribution<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                UpdateDistributionRequest,
+            ) -> ::std::result::Result<
+                UpdateDistributionResult,
+                RusotoError<UpdateDistributionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_distribution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_field_level_encryption_config` with what `handler` returns for its input.
+    pub fn with_update_field_level_encryption_config<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                UpdateFieldLevelEncryptionConfigRequest,
+            ) -> ::std::result::Result<
+                UpdateFieldLevelEncryptionConfigResult,
+                RusotoError<UpdateFieldLevelEncryptionConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_field_level_encryption_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_field_level_encryption_profile` with what `handler` returns for its input.
+    pub fn with_update_field_level_encryption_profile<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                UpdateFieldLevelEncryptionProfileRequest,
+            ) -> ::std::result::Result<
+                UpdateFieldLevelEncryptionProfileResult,
+                RusotoError<UpdateFieldLevelEncryptionProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_field_level_encryption_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_public_key` with what `handler` returns for its input.
+    pub fn with_update_public_key<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                UpdatePublicKeyRequest,
+            )
+                -> ::std::result::Result<UpdatePublicKeyResult, RusotoError<UpdatePublicKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_public_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_streaming_distribution` with what `handler` returns for its input.
+    pub fn with_update_streaming_distribution<F>(mut self, handler: F) -> MockCloudFront
+    where
+        F: Fn(
+                UpdateStreamingDistributionRequest,
+            ) -> ::std::result::Result<
+                UpdateStreamingDistributionResult,
+                RusotoError<UpdateStreamingDistributionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_streaming_distribution = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CloudFront for MockCloudFront {
+    fn create_cloud_front_origin_access_identity(
+        &self,
+        input: CreateCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<
+        CreateCloudFrontOriginAccessIdentityResult,
+        CreateCloudFrontOriginAccessIdentityError,
+    > {
+        match self.create_cloud_front_origin_access_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCloudFront has no handler for create_cloud_front_origin_access_identity"
+            ),
+        }
+    }
+    fn create_distribution(
+        &self,
+        input: CreateDistributionRequest,
+    ) -> RusotoFuture<CreateDistributionResult, CreateDistributionError> {
+        match self.create_distribution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for create_distribution"),
+        }
+    }
+    fn create_distribution_with_tags(
+        &self,
+        input: CreateDistributionWithTagsRequest,
+    ) -> RusotoFuture<CreateDistributionWithTagsResult, CreateDistributionWithTagsError> {
+        match self.create_distribution_with_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for create_distribution_with_tags"),
+        }
+    }
+    fn create_field_level_encryption_config(
+        &self,
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<CreateFieldLevelEncryptionConfigResult, CreateFieldLevelEncryptionConfigError>
+    {
+        match self.create_field_level_encryption_config {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudFront has no handler for create_field_level_encryption_config")
+            }
+        }
+    }
+    fn create_field_level_encryption_profile(
+        &self,
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<CreateFieldLevelEncryptionProfileResult, CreateFieldLevelEncryptionProfileError>
+    {
+        match self.create_field_level_encryption_profile {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudFront has no handler for create_field_level_encryption_profile")
+            }
+        }
+    }
+    fn create_invalidation(
+        &self,
+        input: CreateInvalidationRequest,
+    ) -> RusotoFuture<CreateInvalidationResult, CreateInvalidationError> {
+        match self.create_invalidation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for create_invalidation"),
+        }
+    }
+    fn create_public_key(
+        &self,
+        input: CreatePublicKeyRequest,
+    ) -> RusotoFuture<CreatePublicKeyResult, CreatePublicKeyError> {
+        match self.create_public_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for create_public_key"),
+        }
+    }
+    fn create_streaming_distribution(
+        &self,
+        input: CreateStreamingDistributionRequest,
+    ) -> RusotoFuture<CreateStreamingDistributionResult, CreateStreamingDistributionError> {
+        match self.create_streaming_distribution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for create_streaming_distribution"),
+        }
+    }
+    fn create_streaming_distribution_with_tags(
+        &self,
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> RusotoFuture<
+        CreateStreamingDistributionWithTagsResult,
+        CreateStreamingDistributionWithTagsError,
+    > {
+        match self.create_streaming_distribution_with_tags {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudFront has no handler for create_streaming_distribution_with_tags")
+            }
+        }
+    }
+    fn delete_cloud_front_origin_access_identity(
+        &self,
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<(), DeleteCloudFrontOriginAccessIdentityError> {
+        match self.delete_cloud_front_origin_access_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCloudFront has no handler for delete_cloud_front_origin_access_identity"
+            ),
+        }
+    }
+    fn delete_distribution(
+        &self,
+        input: DeleteDistributionRequest,
+    ) -> RusotoFuture<(), DeleteDistributionError> {
+        match self.delete_distribution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for delete_distribution"),
+        }
+    }
+    fn delete_field_level_encryption_config(
+        &self,
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<(), DeleteFieldLevelEncryptionConfigError> {
+        match self.delete_field_level_encryption_config {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudFront has no handler for delete_field_level_encryption_config")
+            }
+        }
+    }
+    fn delete_field_level_encryption_profile(
+        &self,
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<(), DeleteFieldLevelEncryptionProfileError> {
+        match self.delete_field_level_encryption_profile {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudFront has no handler for delete_field_level_encryption_profile")
+            }
+        }
+    }
+    fn delete_public_key(
+        &self,
+        input: DeletePublicKeyRequest,
+    ) -> RusotoFuture<(), DeletePublicKeyError> {
+        match self.delete_public_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for delete_public_key"),
+        }
+    }
+    fn delete_streaming_distribution(
+        &self,
+        input: DeleteStreamingDistributionRequest,
+    ) -> RusotoFuture<(), DeleteStreamingDistributionError> {
+        match self.delete_streaming_distribution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for delete_streaming_distribution"),
+        }
+    }
+    fn get_cloud_front_origin_access_identity(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<GetCloudFrontOriginAccessIdentityResult, GetCloudFrontOriginAccessIdentityError>
+    {
+        match self.get_cloud_front_origin_access_identity {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudFront has no handler for get_cloud_front_origin_access_identity")
+            }
+        }
+    }
+    fn get_cloud_front_origin_access_identity_config(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> RusotoFuture<
+        GetCloudFrontOriginAccessIdentityConfigResult,
+        GetCloudFrontOriginAccessIdentityConfigError,
+    > {
+        match self.get_cloud_front_origin_access_identity_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCloudFront has no handler for get_cloud_front_origin_access_identity_config"
+            ),
+        }
+    }
+    fn get_distribution(
+        &self,
+        input: GetDistributionRequest,
+    ) -> RusotoFuture<GetDistributionResult, GetDistributionError> {
+        match self.get_distribution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for get_distribution"),
+        }
+    }
+    fn get_distribution_config(
+        &self,
+        input: GetDistributionConfigRequest,
+    ) -> RusotoFuture<GetDistributionConfigResult, GetDistributionConfigError> {
+        match self.get_distribution_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for get_distribution_config"),
+        }
+    }
+    fn get_field_level_encryption(
+        &self,
+        input: GetFieldLevelEncryptionRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionResult, GetFieldLevelEncryptionError> {
+        match self.get_field_level_encryption {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for get_field_level_encryption"),
+        }
+    }
+    fn get_field_level_encryption_config(
+        &self,
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionConfigResult, GetFieldLevelEncryptionConfigError> {
+        match self.get_field_level_encryption_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for get_field_level_encryption_config"),
+        }
+    }
+    fn get_field_level_encryption_profile(
+        &self,
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionProfileResult, GetFieldLevelEncryptionProfileError>
+    {
+        match self.get_field_level_encryption_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for get_field_level_encryption_profile"),
+        }
+    }
+    fn get_field_level_encryption_profile_config(
+        &self,
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> RusotoFuture<
+        GetFieldLevelEncryptionProfileConfigResult,
+        GetFieldLevelEncryptionProfileConfigError,
+    > {
+        match self.get_field_level_encryption_profile_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCloudFront has no handler for get_field_level_encryption_profile_config"
+            ),
+        }
+    }
+    fn get_invalidation(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> RusotoFuture<GetInvalidationResult, GetInvalidationError> {
+        match self.get_invalidation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for get_invalidation"),
+        }
+    }
+    fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> RusotoFuture<GetPublicKeyResult, GetPublicKeyError> {
+        match self.get_public_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for get_public_key"),
+        }
+    }
+    fn get_public_key_config(
+        &self,
+        input: GetPublicKeyConfigRequest,
+    ) -> RusotoFuture<GetPublicKeyConfigResult, GetPublicKeyConfigError> {
+        match self.get_public_key_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for get_public_key_config"),
+        }
+    }
+    fn get_streaming_distribution(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> RusotoFuture<GetStreamingDistributionResult, GetStreamingDistributionError> {
+        match self.get_streaming_distribution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for get_streaming_distribution"),
+        }
+    }
+    fn get_streaming_distribution_config(
+        &self,
+        input: GetStreamingDistributionConfigRequest,
+    ) -> RusotoFuture<GetStreamingDistributionConfigResult, GetStreamingDistributionConfigError>
+    {
+        match self.get_streaming_distribution_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for get_streaming_distribution_config"),
+        }
+    }
+    fn list_cloud_front_origin_access_identities(
+        &self,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> RusotoFuture<
+        ListCloudFrontOriginAccessIdentitiesResult,
+        ListCloudFrontOriginAccessIdentitiesError,
+    > {
+        match self.list_cloud_front_origin_access_identities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCloudFront has no handler for list_cloud_front_origin_access_identities"
+            ),
+        }
+    }
+    fn list_distributions(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> RusotoFuture<ListDistributionsResult, ListDistributionsError> {
+        match self.list_distributions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for list_distributions"),
+        }
+    }
+    fn list_distributions_by_web_acl_id(
+        &self,
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> RusotoFuture<ListDistributionsByWebACLIdResult, ListDistributionsByWebACLIdError> {
+        match self.list_distributions_by_web_acl_id {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for list_distributions_by_web_acl_id"),
+        }
+    }
+    fn list_field_level_encryption_configs(
+        &self,
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> RusotoFuture<ListFieldLevelEncryptionConfigsResult, ListFieldLevelEncryptionConfigsError>
+    {
+        match self.list_field_level_encryption_configs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for list_field_level_encryption_configs"),
+        }
+    }
+    fn list_field_level_encryption_profiles(
+        &self,
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> RusotoFuture<ListFieldLevelEncryptionProfilesResult, ListFieldLevelEncryptionProfilesError>
+    {
+        match self.list_field_level_encryption_profiles {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudFront has no handler for list_field_level_encryption_profiles")
+            }
+        }
+    }
+    fn list_invalidations(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> RusotoFuture<ListInvalidationsResult, ListInvalidationsError> {
+        match self.list_invalidations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for list_invalidations"),
+        }
+    }
+    fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> RusotoFuture<ListPublicKeysResult, ListPublicKeysError> {
+        match self.list_public_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for list_public_keys"),
+        }
+    }
+    fn list_streaming_distributions(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> RusotoFuture<ListStreamingDistributionsResult, ListStreamingDistributionsError> {
+        match self.list_streaming_distributions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for list_streaming_distributions"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for list_tags_for_resource"),
+        }
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for untag_resource"),
+        }
+    }
+    fn update_cloud_front_origin_access_identity(
+        &self,
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<
+        UpdateCloudFrontOriginAccessIdentityResult,
+        UpdateCloudFrontOriginAccessIdentityError,
+    > {
+        match self.update_cloud_front_origin_access_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCloudFront has no handler for update_cloud_front_origin_access_identity"
+            ),
+        }
+    }
+    fn update_distribution(
+        &self,
+        input: UpdateDistributionRequest,
+    ) -> RusotoFuture<UpdateDistributionResult, UpdateDistributionError> {
+        match self.update_distribution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for update_distribution"),
+        }
+    }
+    fn update_field_level_encryption_config(
+        &self,
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<UpdateFieldLevelEncryptionConfigResult, UpdateFieldLevelEncryptionConfigError>
+    {
+        match self.update_field_level_encryption_config {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudFront has no handler for update_field_level_encryption_config")
+            }
+        }
+    }
+    fn update_field_level_encryption_profile(
+        &self,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<UpdateFieldLevelEncryptionProfileResult, UpdateFieldLevelEncryptionProfileError>
+    {
+        match self.update_field_level_encryption_profile {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCloudFront has no handler for update_field_level_encryption_profile")
+            }
+        }
+    }
+    fn update_public_key(
+        &self,
+        input: UpdatePublicKeyRequest,
+    ) -> RusotoFuture<UpdatePublicKeyResult, UpdatePublicKeyError> {
+        match self.update_public_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for update_public_key"),
+        }
+    }
+    fn update_streaming_distribution(
+        &self,
+        input: UpdateStreamingDistributionRequest,
+    ) -> RusotoFuture<UpdateStreamingDistributionResult, UpdateStreamingDistributionError> {
+        match self.update_streaming_distribution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudFront has no handler for update_streaming_distribution"),
+        }
     }
 }
 
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the AWS CloudHSM resource to tag.</p>
     #[serde(rename = "ResourceArn")]
@@ -33,8 +35,22 @@ pub struct AddTagsToResourceRequest {
     pub tag_list: Vec<Tag>,
 }
 
+impl Validate for AddTagsToResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "[\\w :+=./\\\\-]*",
+        );
+        for (i, item) in self.tag_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_list"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsToResourceResponse {
     /// <p>The status of the operation.</p>
     #[serde(rename = "Status")]
@@ -43,15 +59,26 @@ pub struct AddTagsToResourceResponse {
 
 /// <p>Contains the inputs for the <a>CreateHapgRequest</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHapgRequest {
     /// <p>The label of the new high-availability partition group.</p>
     #[serde(rename = "Label")]
     pub label: String,
 }
 
+impl Validate for CreateHapgRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "label"),
+            &self.label,
+            "[a-zA-Z0-9_.-]{1,64}",
+        );
+    }
+}
+
 /// <p>Contains the output of the <a>CreateHAPartitionGroup</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateHapgResponse {
     /// <p>The ARN of the high-availability partition group.</p>
     #[serde(rename = "HapgArn")]
@@ -61,6 +88,7 @@ pub struct CreateHapgResponse {
 
 /// <p>Contains the inputs for the <code>CreateHsm</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHsmRequest {
     /// <p>A user-defined token to ensure idempotence. Subsequent calls to this operation with the same token will be ignored.</p>
     #[serde(rename = "ClientToken")]
@@ -91,9 +119,53 @@ pub struct CreateHsmRequest {
     pub syslog_ip: Option<String>,
 }
 
+impl Validate for CreateHsmRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_token {
+            errors.check_pattern(
+                &field_path(path, "client_token"),
+                &value,
+                "[a-zA-Z0-9]{1,64}",
+            );
+        }
+        if let Some(ref value) = self.eni_ip {
+            errors.check_pattern(
+                &field_path(path, "eni_ip"),
+                &value,
+                "\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}",
+            );
+        }
+        if let Some(ref value) = self.external_id {
+            errors.check_pattern(&field_path(path, "external_id"), &value, "[\\w :+=./-]*");
+        }
+        errors.check_pattern(
+            &field_path(path, "iam_role_arn"),
+            &self.iam_role_arn,
+            "arn:aws(-iso)?:iam::[0-9]{12}:role/[a-zA-Z0-9_\\+=,\\.\\-@]{1,64}",
+        );
+        errors.check_pattern(
+            &field_path(path, "ssh_key"),
+            &self.ssh_key,
+            "[a-zA-Z0-9+/= ._:\\\\@-]*",
+        );
+        errors.check_pattern(
+            &field_path(path, "subnet_id"),
+            &self.subnet_id,
+            "subnet-[0-9a-f]{8}",
+        );
+        if let Some(ref value) = self.syslog_ip {
+            errors.check_pattern(
+                &field_path(path, "syslog_ip"),
+                &value,
+                "\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}",
+            );
+        }
+    }
+}
+
 /// <p>Contains the output of the <code>CreateHsm</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateHsmResponse {
     /// <p>The ARN of the HSM.</p>
     #[serde(rename = "HsmArn")]
@@ -103,6 +175,7 @@ pub struct CreateHsmResponse {
 
 /// <p>Contains the inputs for the <a>CreateLunaClient</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLunaClientRequest {
     /// <p>The contents of a Base64-Encoded X.509 v3 certificate to be installed on the HSMs used by this client.</p>
     #[serde(rename = "Certificate")]
@@ -113,9 +186,28 @@ pub struct CreateLunaClientRequest {
     pub label: Option<String>,
 }
 
+impl Validate for CreateLunaClientRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate"),
+            self.certificate.chars().count(),
+            Some(600),
+            Some(2400),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate"),
+            &self.certificate,
+            "[\\w :+=./\\n-]*",
+        );
+        if let Some(ref value) = self.label {
+            errors.check_pattern(&field_path(path, "label"), &value, "[a-zA-Z0-9_.-]{2,64}");
+        }
+    }
+}
+
 /// <p>Contains the output of the <a>CreateLunaClient</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLunaClientResponse {
     /// <p>The ARN of the client.</p>
     #[serde(rename = "ClientArn")]
@@ -125,15 +217,26 @@ pub struct CreateLunaClientResponse {
 
 /// <p>Contains the inputs for the <a>DeleteHapg</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteHapgRequest {
     /// <p>The ARN of the high-availability partition group to delete.</p>
     #[serde(rename = "HapgArn")]
     pub hapg_arn: String,
 }
 
+impl Validate for DeleteHapgRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "hapg_arn"),
+            &self.hapg_arn,
+            "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:hapg-[0-9a-f]{8}",
+        );
+    }
+}
+
 /// <p>Contains the output of the <a>DeleteHapg</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteHapgResponse {
     /// <p>The status of the action.</p>
     #[serde(rename = "Status")]
@@ -142,15 +245,26 @@ pub struct DeleteHapgResponse {
 
 /// <p>Contains the inputs for the <a>DeleteHsm</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteHsmRequest {
     /// <p>The ARN of the HSM to delete.</p>
     #[serde(rename = "HsmArn")]
     pub hsm_arn: String,
 }
 
+impl Validate for DeleteHsmRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "hsm_arn"),
+            &self.hsm_arn,
+            "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:hsm-[0-9a-f]{8}",
+        );
+    }
+}
+
 /// <p>Contains the output of the <a>DeleteHsm</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteHsmResponse {
     /// <p>The status of the operation.</p>
     #[serde(rename = "Status")]
@@ -158,14 +272,25 @@ pub struct DeleteHsmResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLunaClientRequest {
     /// <p>The ARN of the client to delete.</p>
     #[serde(rename = "ClientArn")]
     pub client_arn: String,
 }
 
+impl Validate for DeleteLunaClientRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "client_arn"),
+            &self.client_arn,
+            "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:client-[0-9a-f]{8}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLunaClientResponse {
     /// <p>The status of the action.</p>
     #[serde(rename = "Status")]
@@ -174,15 +299,26 @@ pub struct DeleteLunaClientResponse {
 
 /// <p>Contains the inputs for the <a>DescribeHapg</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeHapgRequest {
     /// <p>The ARN of the high-availability partition group to describe.</p>
     #[serde(rename = "HapgArn")]
     pub hapg_arn: String,
 }
 
+impl Validate for DescribeHapgRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "hapg_arn"),
+            &self.hapg_arn,
+            "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:hapg-[0-9a-f]{8}",
+        );
+    }
+}
+
 /// <p>Contains the output of the <a>DescribeHapg</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeHapgResponse {
     /// <p>The ARN of the high-availability partition group.</p>
     #[serde(rename = "HapgArn")]
@@ -224,6 +360,7 @@ pub struct DescribeHapgResponse {
 
 /// <p>Contains the inputs for the <a>DescribeHsm</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeHsmRequest {
     /// <p>The ARN of the HSM. Either the <code>HsmArn</code> or the <code>SerialNumber</code> parameter must be specified.</p>
     #[serde(rename = "HsmArn")]
@@ -235,9 +372,24 @@ pub struct DescribeHsmRequest {
     pub hsm_serial_number: Option<String>,
 }
 
+impl Validate for DescribeHsmRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hsm_arn {
+            errors.check_pattern(
+                &field_path(path, "hsm_arn"),
+                &value,
+                "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:hsm-[0-9a-f]{8}",
+            );
+        }
+        if let Some(ref value) = self.hsm_serial_number {
+            errors.check_pattern(&field_path(path, "hsm_serial_number"), &value, "\\d{1,16}");
+        }
+    }
+}
+
 /// <p>Contains the output of the <a>DescribeHsm</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeHsmResponse {
     /// <p>The Availability Zone that the HSM is in.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -325,6 +477,7 @@ pub struct DescribeHsmResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLunaClientRequest {
     /// <p>The certificate fingerprint.</p>
     #[serde(rename = "CertificateFingerprint")]
@@ -336,8 +489,27 @@ pub struct DescribeLunaClientRequest {
     pub client_arn: Option<String>,
 }
 
+impl Validate for DescribeLunaClientRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.certificate_fingerprint {
+            errors.check_pattern(
+                &field_path(path, "certificate_fingerprint"),
+                &value,
+                "([0-9a-fA-F][0-9a-fA-F]:){15}[0-9a-fA-F][0-9a-fA-F]",
+            );
+        }
+        if let Some(ref value) = self.client_arn {
+            errors.check_pattern(
+                &field_path(path, "client_arn"),
+                &value,
+                "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:client-[0-9a-f]{8}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeLunaClientResponse {
     /// <p>The certificate installed on the HSMs used by this client.</p>
     #[serde(rename = "Certificate")]
@@ -362,6 +534,7 @@ pub struct DescribeLunaClientResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConfigRequest {
     /// <p>The ARN of the client.</p>
     #[serde(rename = "ClientArn")]
@@ -374,8 +547,26 @@ pub struct GetConfigRequest {
     pub hapg_list: Vec<String>,
 }
 
+impl Validate for GetConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "client_arn"),
+            &self.client_arn,
+            "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:client-[0-9a-f]{8}",
+        );
+        for (i, item) in self.hapg_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "hapg_list"), i);
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:hapg-[0-9a-f]{8}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConfigResponse {
     /// <p>The certificate file containing the server.pem files of the HSMs.</p>
     #[serde(rename = "ConfigCred")]
@@ -393,10 +584,11 @@ pub struct GetConfigResponse {
 
 /// <p>Contains the inputs for the <a>ListAvailableZones</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAvailableZonesRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAvailableZonesResponse {
     /// <p>The list of Availability Zones that have available AWS CloudHSM capacity.</p>
     #[serde(rename = "AZList")]
@@ -405,6 +597,7 @@ pub struct ListAvailableZonesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHapgsRequest {
     /// <p>The <code>NextToken</code> value from a previous call to <code>ListHapgs</code>. Pass null if this is the first call.</p>
     #[serde(rename = "NextToken")]
@@ -412,8 +605,16 @@ pub struct ListHapgsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListHapgsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[a-zA-Z0-9+/]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListHapgsResponse {
     /// <p>The list of high-availability partition groups.</p>
     #[serde(rename = "HapgList")]
@@ -425,6 +626,7 @@ pub struct ListHapgsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHsmsRequest {
     /// <p>The <code>NextToken</code> value from a previous call to <code>ListHsms</code>. Pass null if this is the first call.</p>
     #[serde(rename = "NextToken")]
@@ -432,9 +634,17 @@ pub struct ListHsmsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListHsmsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[a-zA-Z0-9+/]*");
+        }
+    }
+}
+
 /// <p>Contains the output of the <code>ListHsms</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListHsmsResponse {
     /// <p>The list of ARNs that identify the HSMs.</p>
     #[serde(rename = "HsmList")]
@@ -447,6 +657,7 @@ pub struct ListHsmsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLunaClientsRequest {
     /// <p>The <code>NextToken</code> value from a previous call to <code>ListLunaClients</code>. Pass null if this is the first call.</p>
     #[serde(rename = "NextToken")]
@@ -454,8 +665,16 @@ pub struct ListLunaClientsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListLunaClientsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[a-zA-Z0-9+/]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLunaClientsResponse {
     /// <p>The list of clients.</p>
     #[serde(rename = "ClientList")]
@@ -467,14 +686,25 @@ pub struct ListLunaClientsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the AWS CloudHSM resource.</p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "[\\w :+=./\\\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>One or more tags.</p>
     #[serde(rename = "TagList")]
@@ -482,6 +712,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyHapgRequest {
     /// <p>The ARN of the high-availability partition group to modify.</p>
     #[serde(rename = "HapgArn")]
@@ -496,8 +727,27 @@ pub struct ModifyHapgRequest {
     pub partition_serial_list: Option<Vec<String>>,
 }
 
+impl Validate for ModifyHapgRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "hapg_arn"),
+            &self.hapg_arn,
+            "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:hapg-[0-9a-f]{8}",
+        );
+        if let Some(ref value) = self.label {
+            errors.check_pattern(&field_path(path, "label"), &value, "[a-zA-Z0-9_.-]{1,64}");
+        }
+        if let Some(ref value) = self.partition_serial_list {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "partition_serial_list"), i);
+                errors.check_pattern(&item_path, &item, "\\d{6,12}");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyHapgResponse {
     /// <p>The ARN of the high-availability partition group.</p>
     #[serde(rename = "HapgArn")]
@@ -507,6 +757,7 @@ pub struct ModifyHapgResponse {
 
 /// <p>Contains the inputs for the <a>ModifyHsm</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyHsmRequest {
     /// <p>The new IP address for the elastic network interface (ENI) attached to the HSM.</p> <p>If the HSM is moved to a different subnet, and an IP address is not specified, an IP address will be randomly chosen from the CIDR range of the new subnet.</p>
     #[serde(rename = "EniIp")]
@@ -533,9 +784,46 @@ pub struct ModifyHsmRequest {
     pub syslog_ip: Option<String>,
 }
 
+impl Validate for ModifyHsmRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.eni_ip {
+            errors.check_pattern(
+                &field_path(path, "eni_ip"),
+                &value,
+                "\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}",
+            );
+        }
+        if let Some(ref value) = self.external_id {
+            errors.check_pattern(&field_path(path, "external_id"), &value, "[\\w :+=./-]*");
+        }
+        errors.check_pattern(
+            &field_path(path, "hsm_arn"),
+            &self.hsm_arn,
+            "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:hsm-[0-9a-f]{8}",
+        );
+        if let Some(ref value) = self.iam_role_arn {
+            errors.check_pattern(
+                &field_path(path, "iam_role_arn"),
+                &value,
+                "arn:aws(-iso)?:iam::[0-9]{12}:role/[a-zA-Z0-9_\\+=,\\.\\-@]{1,64}",
+            );
+        }
+        if let Some(ref value) = self.subnet_id {
+            errors.check_pattern(&field_path(path, "subnet_id"), &value, "subnet-[0-9a-f]{8}");
+        }
+        if let Some(ref value) = self.syslog_ip {
+            errors.check_pattern(
+                &field_path(path, "syslog_ip"),
+                &value,
+                "\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}",
+            );
+        }
+    }
+}
+
 /// <p>Contains the output of the <a>ModifyHsm</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyHsmResponse {
     /// <p>The ARN of the HSM.</p>
     #[serde(rename = "HsmArn")]
@@ -544,6 +832,7 @@ pub struct ModifyHsmResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyLunaClientRequest {
     /// <p>The new certificate for the client.</p>
     #[serde(rename = "Certificate")]
@@ -553,8 +842,29 @@ pub struct ModifyLunaClientRequest {
     pub client_arn: String,
 }
 
+impl Validate for ModifyLunaClientRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate"),
+            self.certificate.chars().count(),
+            Some(600),
+            Some(2400),
+        );
+        errors.check_pattern(
+            &field_path(path, "certificate"),
+            &self.certificate,
+            "[\\w :+=./\\n-]*",
+        );
+        errors.check_pattern(
+            &field_path(path, "client_arn"),
+            &self.client_arn,
+            "arn:aws(-iso)?:cloudhsm:[a-zA-Z0-9\\-]*:[0-9]{12}:client-[0-9a-f]{8}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyLunaClientResponse {
     /// <p>The ARN of the client.</p>
     #[serde(rename = "ClientArn")]
@@ -563,6 +873,7 @@ pub struct ModifyLunaClientResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the AWS CloudHSM resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -572,8 +883,22 @@ pub struct RemoveTagsFromResourceRequest {
     pub tag_key_list: Vec<String>,
 }
 
+impl Validate for RemoveTagsFromResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "[\\w :+=./\\\\-]*",
+        );
+        for (i, item) in self.tag_key_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_key_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsFromResourceResponse {
     /// <p>The status of the operation.</p>
     #[serde(rename = "Status")]
@@ -591,6 +916,23 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToResourceError {
@@ -615,7 +957,12 @@ impl AddTagsToResourceError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(AddTagsToResourceError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -660,7 +1007,12 @@ impl CreateHapgError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(CreateHapgError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -705,7 +1057,12 @@ impl CreateHsmError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(CreateHsmError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -750,7 +1107,12 @@ impl CreateLunaClientError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(CreateLunaClientError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -795,7 +1157,12 @@ impl DeleteHapgError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(DeleteHapgError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -840,7 +1207,12 @@ impl DeleteHsmError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(DeleteHsmError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -885,7 +1257,12 @@ impl DeleteLunaClientError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(DeleteLunaClientError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -930,7 +1307,12 @@ impl DescribeHapgError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(DescribeHapgError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -975,7 +1357,12 @@ impl DescribeHsmError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(DescribeHsmError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1020,7 +1407,12 @@ impl DescribeLunaClientError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(DescribeLunaClientError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1065,7 +1457,12 @@ impl GetConfigError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(GetConfigError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1110,7 +1507,12 @@ impl ListAvailableZonesError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListAvailableZonesError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1155,7 +1557,12 @@ impl ListHapgsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListHapgsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1200,7 +1607,12 @@ impl ListHsmsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListHsmsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1245,7 +1657,12 @@ impl ListLunaClientsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListLunaClientsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1292,7 +1709,12 @@ impl ListTagsForResourceError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1337,7 +1759,12 @@ impl ModifyHapgError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ModifyHapgError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1382,7 +1809,12 @@ impl ModifyHsmError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ModifyHsmError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1417,7 +1849,12 @@ impl ModifyLunaClientError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(ModifyLunaClientError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1466,7 +1903,12 @@ impl RemoveTagsFromResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1605,182 +2047,573 @@ pub trait CloudHsm {
         input: RemoveTagsFromResourceRequest,
     ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError>;
 }
-/// A client for the CloudHSM API.
-#[derive(Clone)]
-pub struct CloudHsmClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CloudHsmClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CloudHsmClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CloudHsmClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmClient {
-        CloudHsmClient { client, region }
-    }
-}
-
-impl CloudHsm for CloudHsmClient {
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Adds or overwrites one or more tags for the specified AWS CloudHSM resource.</p> <p>Each tag consists of a key and a value. Tag keys must be unique to each resource.</p>
+const _: Option<&dyn CloudHsm> = None;
+impl<'a, T: CloudHsm + ?Sized> CloudHsm for &'a T {
     fn add_tags_to_resource(
         &self,
         input: AddTagsToResourceRequest,
     ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
-        let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CloudHsmFrontendService.AddTagsToResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddTagsToResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_tags_to_resource(input)
     }
-
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates a high-availability partition group. A high-availability partition group is a group of partitions that spans multiple physical HSMs.</p>
     fn create_hapg(
         &self,
         input: CreateHapgRequest,
     ) -> RusotoFuture<CreateHapgResponse, CreateHapgError> {
-        let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHapg");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateHapgResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateHapgError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_hapg(input)
     }
-
-    /// <p><p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates an uninitialized HSM instance.</p> <p>There is an upfront fee charged for each HSM instance that you create with the <code>CreateHsm</code> operation. If you accidentally provision an HSM and want to request a refund, delete the instance using the <a>DeleteHsm</a> operation, go to the <a href="https://console.aws.amazon.com/support/home">AWS Support Center</a>, create a new case, and select <b>Account and Billing Support</b>.</p> <important> <p>It can take up to 20 minutes to create and provision an HSM. You can monitor the status of the HSM with the <a>DescribeHsm</a> operation. The HSM is ready to be initialized when the status changes to <code>RUNNING</code>.</p> </important></p>
     fn create_hsm(
         &self,
         input: CreateHsmRequest,
     ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
-        let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHsm");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateHsmResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateHsmError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_hsm(input)
     }
-
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates an HSM client.</p>
     fn create_luna_client(
         &self,
         input: CreateLunaClientRequest,
     ) -> RusotoFuture<CreateLunaClientResponse, CreateLunaClientError> {
-        let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CloudHsmFrontendService.CreateLunaClient");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateLunaClientResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateLunaClientError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_luna_client(input)
     }
-
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes a high-availability partition group.</p>
     fn delete_hapg(
         &self,
         input: DeleteHapgRequest,
     ) -> RusotoFuture<DeleteHapgResponse, DeleteHapgError> {
-        let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHapg");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteHapgResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
+        (**self).delete_hapg(input)
+    }
+    fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        (**self).delete_hsm(input)
+    }
+    fn delete_luna_client(
+        &self,
+        input: DeleteLunaClientRequest,
+    ) -> RusotoFuture<DeleteLunaClientResponse, DeleteLunaClientError> {
+        (**self).delete_luna_client(input)
+    }
+    fn describe_hapg(
+        &self,
+        input: DescribeHapgRequest,
+    ) -> RusotoFuture<DescribeHapgResponse, DescribeHapgError> {
+        (**self).describe_hapg(input)
+    }
+    fn describe_hsm(
+        &self,
+        input: DescribeHsmRequest,
+    ) -> RusotoFuture<DescribeHsmResponse, DescribeHsmError> {
+        (**self).describe_hsm(input)
+    }
+    fn describe_luna_client(
+        &self,
+        input: DescribeLunaClientRequest,
+    ) -> RusotoFuture<DescribeLunaClientResponse, DescribeLunaClientError> {
+        (**self).describe_luna_client(input)
+    }
+    fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> RusotoFuture<GetConfigResponse, GetConfigError> {
+        (**self).get_config(input)
+    }
+    fn list_available_zones(
+        &self,
+    ) -> RusotoFuture<ListAvailableZonesResponse, ListAvailableZonesError> {
+        (**self).list_available_zones()
+    }
+    fn list_hapgs(
+        &self,
+        input: ListHapgsRequest,
+    ) -> RusotoFuture<ListHapgsResponse, ListHapgsError> {
+        (**self).list_hapgs(input)
+    }
+    fn list_hsms(&self, input: ListHsmsRequest) -> RusotoFuture<ListHsmsResponse, ListHsmsError> {
+        (**self).list_hsms(input)
+    }
+    fn list_luna_clients(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> RusotoFuture<ListLunaClientsResponse, ListLunaClientsError> {
+        (**self).list_luna_clients(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_hapg(
+        &self,
+        input: ModifyHapgRequest,
+    ) -> RusotoFuture<ModifyHapgResponse, ModifyHapgError> {
+        (**self).modify_hapg(input)
+    }
+    fn modify_hsm(
+        &self,
+        input: ModifyHsmRequest,
+    ) -> RusotoFuture<ModifyHsmResponse, ModifyHsmError> {
+        (**self).modify_hsm(input)
+    }
+    fn modify_luna_client(
+        &self,
+        input: ModifyLunaClientRequest,
+    ) -> RusotoFuture<ModifyLunaClientResponse, ModifyLunaClientError> {
+        (**self).modify_luna_client(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+}
+impl<T: CloudHsm + ?Sized> CloudHsm for Box<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn create_hapg(
+        &self,
+        input: CreateHapgRequest,
+    ) -> RusotoFuture<CreateHapgResponse, CreateHapgError> {
+        (**self).create_hapg(input)
+    }
+    fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        (**self).create_hsm(input)
+    }
+    fn create_luna_client(
+        &self,
+        input: CreateLunaClientRequest,
+    ) -> RusotoFuture<CreateLunaClientResponse, CreateLunaClientError> {
+        (**self).create_luna_client(input)
+    }
+    fn delete_hapg(
+        &self,
+        input: DeleteHapgRequest,
+    ) -> RusotoFuture<DeleteHapgResponse, DeleteHapgError> {
+        (**self).delete_hapg(input)
+    }
+    fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        (**self).delete_hsm(input)
+    }
+    fn delete_luna_client(
+        &self,
+        input: DeleteLunaClientRequest,
+    ) -> RusotoFuture<DeleteLunaClientResponse, DeleteLunaClientError> {
+        (**self).delete_luna_client(input)
+    }
+    fn describe_hapg(
+        &self,
+        input: DescribeHapgRequest,
+    ) -> RusotoFuture<DescribeHapgResponse, DescribeHapgError> {
+        (**self).describe_hapg(input)
+    }
+    fn describe_hsm(
+        &self,
+        input: DescribeHsmRequest,
+    ) -> RusotoFuture<DescribeHsmResponse, DescribeHsmError> {
+        (**self).describe_hsm(input)
+    }
+    fn describe_luna_client(
+        &self,
+        input: DescribeLunaClientRequest,
+    ) -> RusotoFuture<DescribeLunaClientResponse, DescribeLunaClientError> {
+        (**self).describe_luna_client(input)
+    }
+    fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> RusotoFuture<GetConfigResponse, GetConfigError> {
+        (**self).get_config(input)
+    }
+    fn list_available_zones(
+        &self,
+    ) -> RusotoFuture<ListAvailableZonesResponse, ListAvailableZonesError> {
+        (**self).list_available_zones()
+    }
+    fn list_hapgs(
+        &self,
+        input: ListHapgsRequest,
+    ) -> RusotoFuture<ListHapgsResponse, ListHapgsError> {
+        (**self).list_hapgs(input)
+    }
+    fn list_hsms(&self, input: ListHsmsRequest) -> RusotoFuture<ListHsmsResponse, ListHsmsError> {
+        (**self).list_hsms(input)
+    }
+    fn list_luna_clients(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> RusotoFuture<ListLunaClientsResponse, ListLunaClientsError> {
+        (**self).list_luna_clients(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_hapg(
+        &self,
+        input: ModifyHapgRequest,
+    ) -> RusotoFuture<ModifyHapgResponse, ModifyHapgError> {
+        (**self).modify_hapg(input)
+    }
+    fn modify_hsm(
+        &self,
+        input: ModifyHsmRequest,
+    ) -> RusotoFuture<ModifyHsmResponse, ModifyHsmError> {
+        (**self).modify_hsm(input)
+    }
+    fn modify_luna_client(
+        &self,
+        input: ModifyLunaClientRequest,
+    ) -> RusotoFuture<ModifyLunaClientResponse, ModifyLunaClientError> {
+        (**self).modify_luna_client(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+}
+impl<T: CloudHsm + ?Sized> CloudHsm for ::std::sync::Arc<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn create_hapg(
+        &self,
+        input: CreateHapgRequest,
+    ) -> RusotoFuture<CreateHapgResponse, CreateHapgError> {
+        (**self).create_hapg(input)
+    }
+    fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        (**self).create_hsm(input)
+    }
+    fn create_luna_client(
+        &self,
+        input: CreateLunaClientRequest,
+    ) -> RusotoFuture<CreateLunaClientResponse, CreateLunaClientError> {
+        (**self).create_luna_client(input)
+    }
+    fn delete_hapg(
+        &self,
+        input: DeleteHapgRequest,
+    ) -> RusotoFuture<DeleteHapgResponse, DeleteHapgError> {
+        (**self).delete_hapg(input)
+    }
+    fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        (**self).delete_hsm(input)
+    }
+    fn delete_luna_client(
+        &self,
+        input: DeleteLunaClientRequest,
+    ) -> RusotoFuture<DeleteLunaClientResponse, DeleteLunaClientError> {
+        (**self).delete_luna_client(input)
+    }
+    fn describe_hapg(
+        &self,
+        input: DescribeHapgRequest,
+    ) -> RusotoFuture<DescribeHapgResponse, DescribeHapgError> {
+        (**self).describe_hapg(input)
+    }
+    fn describe_hsm(
+        &self,
+        input: DescribeHsmRequest,
+    ) -> RusotoFuture<DescribeHsmResponse, DescribeHsmError> {
+        (**self).describe_hsm(input)
+    }
+    fn describe_luna_client(
+        &self,
+        input: DescribeLunaClientRequest,
+    ) -> RusotoFuture<DescribeLunaClientResponse, DescribeLunaClientError> {
+        (**self).describe_luna_client(input)
+    }
+    fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> RusotoFuture<GetConfigResponse, GetConfigError> {
+        (**self).get_config(input)
+    }
+    fn list_available_zones(
+        &self,
+    ) -> RusotoFuture<ListAvailableZonesResponse, ListAvailableZonesError> {
+        (**self).list_available_zones()
+    }
+    fn list_hapgs(
+        &self,
+        input: ListHapgsRequest,
+    ) -> RusotoFuture<ListHapgsResponse, ListHapgsError> {
+        (**self).list_hapgs(input)
+    }
+    fn list_hsms(&self, input: ListHsmsRequest) -> RusotoFuture<ListHsmsResponse, ListHsmsError> {
+        (**self).list_hsms(input)
+    }
+    fn list_luna_clients(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> RusotoFuture<ListLunaClientsResponse, ListLunaClientsError> {
+        (**self).list_luna_clients(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_hapg(
+        &self,
+        input: ModifyHapgRequest,
+    ) -> RusotoFuture<ModifyHapgResponse, ModifyHapgError> {
+        (**self).modify_hapg(input)
+    }
+    fn modify_hsm(
+        &self,
+        input: ModifyHsmRequest,
+    ) -> RusotoFuture<ModifyHsmResponse, ModifyHsmError> {
+        (**self).modify_hsm(input)
+    }
+    fn modify_luna_client(
+        &self,
+        input: ModifyLunaClientRequest,
+    ) -> RusotoFuture<ModifyLunaClientResponse, ModifyLunaClientError> {
+        (**self).modify_luna_client(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+}
+/// A client for the CloudHSM API.
+#[derive(Clone)]
+pub struct CloudHsmClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CloudHsmClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudHsmClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudHsmClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmClient {
+        CloudHsmClient { client, region }
+    }
+}
+
+impl CloudHsm for CloudHsmClient {
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Adds or overwrites one or more tags for the specified AWS CloudHSM resource.</p> <p>Each tag consists of a key and a value. Tag keys must be unique to each resource.</p>
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("AddTagsToResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CloudHsmFrontendService.AddTagsToResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddTagsToResourceResponse, _>(
+                    response,
+                    "AddTagsToResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates a high-availability partition group. A high-availability partition group is a group of partitions that spans multiple physical HSMs.</p>
+    fn create_hapg(
+        &self,
+        input: CreateHapgRequest,
+    ) -> RusotoFuture<CreateHapgResponse, CreateHapgError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateHapg");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHapg");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateHapgResponse, _>(response, "CreateHapg")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateHapgError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates an uninitialized HSM instance.</p> <p>There is an upfront fee charged for each HSM instance that you create with the <code>CreateHsm</code> operation. If you accidentally provision an HSM and want to request a refund, delete the instance using the <a>DeleteHsm</a> operation, go to the <a href="https://console.aws.amazon.com/support/home">AWS Support Center</a>, create a new case, and select <b>Account and Billing Support</b>.</p> <important> <p>It can take up to 20 minutes to create and provision an HSM. You can monitor the status of the HSM with the <a>DescribeHsm</a> operation. The HSM is ready to be initialized when the status changes to <code>RUNNING</code>.</p> </important></p>
+    fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateHsm");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHsm");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateHsmResponse, _>(response, "CreateHsm")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateHsmError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates an HSM client.</p>
+    fn create_luna_client(
+        &self,
+        input: CreateLunaClientRequest,
+    ) -> RusotoFuture<CreateLunaClientResponse, CreateLunaClientError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateLunaClient");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CloudHsmFrontendService.CreateLunaClient");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateLunaClientResponse, _>(
+                    response,
+                    "CreateLunaClient",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateLunaClientError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes a high-availability partition group.</p>
+    fn delete_hapg(
+        &self,
+        input: DeleteHapgRequest,
+    ) -> RusotoFuture<DeleteHapgResponse, DeleteHapgError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteHapg");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHapg");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteHapgResponse, _>(response, "DeleteHapg")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
                         .from_err()
                         .and_then(|response| Err(DeleteHapgError::from_response(response))),
                 )
@@ -1793,7 +2626,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: DeleteHsmRequest,
     ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHsm");
@@ -1802,10 +2644,7 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteHsmResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteHsmResponse, _>(response, "DeleteHsm")
             } else {
                 Box::new(
                     response
@@ -1822,7 +2661,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: DeleteLunaClientRequest,
     ) -> RusotoFuture<DeleteLunaClientResponse, DeleteLunaClientError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteLunaClient");
@@ -1831,10 +2679,10 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteLunaClientResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteLunaClientResponse, _>(
+                    response,
+                    "DeleteLunaClient",
+                )
             } else {
                 Box::new(
                     response
@@ -1851,7 +2699,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: DescribeHapgRequest,
     ) -> RusotoFuture<DescribeHapgResponse, DescribeHapgError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeHapg");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeHapg");
@@ -1860,10 +2717,10 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeHapgResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeHapgResponse, _>(
+                    response,
+                    "DescribeHapg",
+                )
             } else {
                 Box::new(
                     response
@@ -1880,7 +2737,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: DescribeHsmRequest,
     ) -> RusotoFuture<DescribeHsmResponse, DescribeHsmError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeHsm");
@@ -1889,10 +2755,10 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeHsmResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeHsmResponse, _>(
+                    response,
+                    "DescribeHsm",
+                )
             } else {
                 Box::new(
                     response
@@ -1909,7 +2775,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: DescribeLunaClientRequest,
     ) -> RusotoFuture<DescribeLunaClientResponse, DescribeLunaClientError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeLunaClient");
@@ -1918,10 +2793,10 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeLunaClientResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeLunaClientResponse, _>(
+                    response,
+                    "DescribeLunaClient",
+                )
             } else {
                 Box::new(
                     response
@@ -1938,7 +2813,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: GetConfigRequest,
     ) -> RusotoFuture<GetConfigResponse, GetConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("GetConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.GetConfig");
@@ -1947,10 +2831,7 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetConfigResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetConfigResponse, _>(response, "GetConfig")
             } else {
                 Box::new(
                     response
@@ -1967,6 +2848,7 @@ impl CloudHsm for CloudHsmClient {
         &self,
     ) -> RusotoFuture<ListAvailableZonesResponse, ListAvailableZonesError> {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListAvailableZones");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListAvailableZones");
@@ -1974,10 +2856,10 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAvailableZonesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListAvailableZonesResponse, _>(
+                    response,
+                    "ListAvailableZones",
+                )
             } else {
                 Box::new(
                     response
@@ -1994,7 +2876,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: ListHapgsRequest,
     ) -> RusotoFuture<ListHapgsResponse, ListHapgsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListHapgs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListHapgs");
@@ -2003,10 +2894,7 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListHapgsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListHapgsResponse, _>(response, "ListHapgs")
             } else {
                 Box::new(
                     response
@@ -2020,7 +2908,16 @@ impl CloudHsm for CloudHsmClient {
 
     /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves the identifiers of all of the HSMs provisioned for the current customer.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> member. If more results are available, the <code>NextToken</code> member of the response contains a token that you pass in the next call to <code>ListHsms</code> to retrieve the next set of items.</p>
     fn list_hsms(&self, input: ListHsmsRequest) -> RusotoFuture<ListHsmsResponse, ListHsmsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListHsms");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListHsms");
@@ -2029,10 +2926,7 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListHsmsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListHsmsResponse, _>(response, "ListHsms")
             } else {
                 Box::new(
                     response
@@ -2049,7 +2943,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: ListLunaClientsRequest,
     ) -> RusotoFuture<ListLunaClientsResponse, ListLunaClientsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListLunaClients");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListLunaClients");
@@ -2058,10 +2961,10 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLunaClientsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListLunaClientsResponse, _>(
+                    response,
+                    "ListLunaClients",
+                )
             } else {
                 Box::new(
                     response
@@ -2078,7 +2981,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2090,10 +3002,10 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2109,7 +3021,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: ModifyHapgRequest,
     ) -> RusotoFuture<ModifyHapgResponse, ModifyHapgError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ModifyHapg");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyHapg");
@@ -2118,10 +3039,7 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyHapgResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ModifyHapgResponse, _>(response, "ModifyHapg")
             } else {
                 Box::new(
                     response
@@ -2138,7 +3056,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: ModifyHsmRequest,
     ) -> RusotoFuture<ModifyHsmResponse, ModifyHsmError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ModifyHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyHsm");
@@ -2147,10 +3074,7 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyHsmResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ModifyHsmResponse, _>(response, "ModifyHsm")
             } else {
                 Box::new(
                     response
@@ -2167,7 +3091,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: ModifyLunaClientRequest,
     ) -> RusotoFuture<ModifyLunaClientResponse, ModifyLunaClientError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ModifyLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyLunaClient");
@@ -2176,10 +3109,10 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyLunaClientResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ModifyLunaClientResponse, _>(
+                    response,
+                    "ModifyLunaClient",
+                )
             } else {
                 Box::new(
                     response
@@ -2196,7 +3129,16 @@ impl CloudHsm for CloudHsmClient {
         &self,
         input: RemoveTagsFromResourceRequest,
     ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2208,10 +3150,10 @@ impl CloudHsm for CloudHsmClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveTagsFromResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RemoveTagsFromResourceResponse, _>(
+                    response,
+                    "RemoveTagsFromResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2222,3 +3164,688 @@ impl CloudHsm for CloudHsmClient {
         })
     }
 }
+
+/// A mock of the CloudHSM API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloudHsm {
+    add_tags_to_resource: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToResourceRequest,
+                ) -> ::std::result::Result<
+                    AddTagsToResourceResponse,
+                    RusotoError<AddTagsToResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_hapg: Option<
+        Box<
+            dyn Fn(
+                    CreateHapgRequest,
+                )
+                    -> ::std::result::Result<CreateHapgResponse, RusotoError<CreateHapgError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_hsm: Option<
+        Box<
+            dyn Fn(
+                    CreateHsmRequest,
+                )
+                    -> ::std::result::Result<CreateHsmResponse, RusotoError<CreateHsmError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_luna_client: Option<
+        Box<
+            dyn Fn(
+                    CreateLunaClientRequest,
+                ) -> ::std::result::Result<
+                    CreateLunaClientResponse,
+                    RusotoError<CreateLunaClientError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_hapg: Option<
+        Box<
+            dyn Fn(
+                    DeleteHapgRequest,
+                )
+                    -> ::std::result::Result<DeleteHapgResponse, RusotoError<DeleteHapgError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_hsm: Option<
+        Box<
+            dyn Fn(
+                    DeleteHsmRequest,
+                )
+                    -> ::std::result::Result<DeleteHsmResponse, RusotoError<DeleteHsmError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_luna_client: Option<
+        Box<
+            dyn Fn(
+                    DeleteLunaClientRequest,
+                ) -> ::std::result::Result<
+                    DeleteLunaClientResponse,
+                    RusotoError<DeleteLunaClientError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_hapg: Option<
+        Box<
+            dyn Fn(
+                    DescribeHapgRequest,
+                )
+                    -> ::std::result::Result<DescribeHapgResponse, RusotoError<DescribeHapgError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_hsm: Option<
+        Box<
+            dyn Fn(
+                    DescribeHsmRequest,
+                )
+                    -> ::std::result::Result<DescribeHsmResponse, RusotoError<DescribeHsmError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_luna_client: Option<
+        Box<
+            dyn Fn(
+                    DescribeLunaClientRequest,
+                ) -> ::std::result::Result<
+                    DescribeLunaClientResponse,
+                    RusotoError<DescribeLunaClientError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_config: Option<
+        Box<
+            dyn Fn(
+                    GetConfigRequest,
+                )
+                    -> ::std::result::Result<GetConfigResponse, RusotoError<GetConfigError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_available_zones: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    ListAvailableZonesResponse,
+                    RusotoError<ListAvailableZonesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_hapgs: Option<
+        Box<
+            dyn Fn(
+                    ListHapgsRequest,
+                )
+                    -> ::std::result::Result<ListHapgsResponse, RusotoError<ListHapgsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_hsms: Option<
+        Box<
+            dyn Fn(
+                    ListHsmsRequest,
+                )
+                    -> ::std::result::Result<ListHsmsResponse, RusotoError<ListHsmsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_luna_clients: Option<
+        Box<
+            dyn Fn(
+                    ListLunaClientsRequest,
+                ) -> ::std::result::Result<
+                    ListLunaClientsResponse,
+                    RusotoError<ListLunaClientsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_hapg: Option<
+        Box<
+            dyn Fn(
+                    ModifyHapgRequest,
+                )
+                    -> ::std::result::Result<ModifyHapgResponse, RusotoError<ModifyHapgError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_hsm: Option<
+        Box<
+            dyn Fn(
+                    ModifyHsmRequest,
+                )
+                    -> ::std::result::Result<ModifyHsmResponse, RusotoError<ModifyHsmError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_luna_client: Option<
+        Box<
+            dyn Fn(
+                    ModifyLunaClientRequest,
+                ) -> ::std::result::Result<
+                    ModifyLunaClientResponse,
+                    RusotoError<ModifyLunaClientError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_resource: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromResourceRequest,
+                ) -> ::std::result::Result<
+                    RemoveTagsFromResourceResponse,
+                    RusotoError<RemoveTagsFromResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloudHsm {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloudHsm {
+        Default::default()
+    }
+
+    /// Answers `add_tags_to_resource` with what `handler` returns for its input.
+    pub fn with_add_tags_to_resource<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                AddTagsToResourceRequest,
+            ) -> ::std::result::Result<
+                AddTagsToResourceResponse,
+                RusotoError<AddTagsToResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_hapg` with what `handler` returns for its input.
+    pub fn with_create_hapg<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                CreateHapgRequest,
+            )
+                -> ::std::result::Result<CreateHapgResponse, RusotoError<CreateHapgError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_hapg = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_hsm` with what `handler` returns for its input.
+    pub fn with_create_hsm<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                CreateHsmRequest,
+            ) -> ::std::result::Result<CreateHsmResponse, RusotoError<CreateHsmError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_hsm = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_luna_client` with what `handler` returns for its input.
+    pub fn with_create_luna_client<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                CreateLunaClientRequest,
+            ) -> ::std::result::Result<
+                CreateLunaClientResponse,
+                RusotoError<CreateLunaClientError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_luna_client = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_hapg` with what `handler` returns for its input.
+    pub fn with_delete_hapg<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                DeleteHapgRequest,
+            )
+                -> ::std::result::Result<DeleteHapgResponse, RusotoError<DeleteHapgError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_hapg = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_hsm` with what `handler` returns for its input.
+    pub fn with_delete_hsm<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                DeleteHsmRequest,
+            ) -> ::std::result::Result<DeleteHsmResponse, RusotoError<DeleteHsmError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_hsm = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_luna_client` with what `handler` returns for its input.
+    pub fn with_delete_luna_client<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                DeleteLunaClientRequest,
+            ) -> ::std::result::Result<
+                DeleteLunaClientResponse,
+                RusotoError<DeleteLunaClientError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_luna_client = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_hapg` with what `handler` returns for its input.
+    pub fn with_describe_hapg<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                DescribeHapgRequest,
+            )
+                -> ::std::result::Result<DescribeHapgResponse, RusotoError<DescribeHapgError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_hapg = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_hsm` with what `handler` returns for its input.
+    pub fn with_describe_hsm<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                DescribeHsmRequest,
+            )
+                -> ::std::result::Result<DescribeHsmResponse, RusotoError<DescribeHsmError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_hsm = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_luna_client` with what `handler` returns for its input.
+    pub fn with_describe_luna_client<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                DescribeLunaClientRequest,
+            ) -> ::std::result::Result<
+                DescribeLunaClientResponse,
+                RusotoError<DescribeLunaClientError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_luna_client = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_config` with what `handler` returns for its input.
+    pub fn with_get_config<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                GetConfigRequest,
+            ) -> ::std::result::Result<GetConfigResponse, RusotoError<GetConfigError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_available_zones` with what `handler` returns.
+    pub fn with_list_available_zones<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn() -> ::std::result::Result<
+                ListAvailableZonesResponse,
+                RusotoError<ListAvailableZonesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_available_zones = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_hapgs` with what `handler` returns for its input.
+    pub fn with_list_hapgs<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                ListHapgsRequest,
+            ) -> ::std::result::Result<ListHapgsResponse, RusotoError<ListHapgsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_hapgs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_hsms` with what `handler` returns for its input.
+    pub fn with_list_hsms<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                ListHsmsRequest,
+            ) -> ::std::result::Result<ListHsmsResponse, RusotoError<ListHsmsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_hsms = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_luna_clients` with what `handler` returns for its input.
+    pub fn with_list_luna_clients<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                ListLunaClientsRequest,
+            )
+                -> ::std::result::Result<ListLunaClientsResponse, RusotoError<ListLunaClientsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_luna_clients = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_hapg` with what `handler` returns for its input.
+    pub fn with_modify_hapg<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                ModifyHapgRequest,
+            )
+                -> ::std::result::Result<ModifyHapgResponse, RusotoError<ModifyHapgError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_hapg = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_hsm` with what `handler` returns for its input.
+    pub fn with_modify_hsm<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                ModifyHsmRequest,
+            ) -> ::std::result::Result<ModifyHsmResponse, RusotoError<ModifyHsmError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_hsm = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_luna_client` with what `handler` returns for its input.
+    pub fn with_modify_luna_client<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                ModifyLunaClientRequest,
+            ) -> ::std::result::Result<
+                ModifyLunaClientResponse,
+                RusotoError<ModifyLunaClientError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_luna_client = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_resource` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_resource<F>(mut self, handler: F) -> MockCloudHsm
+    where
+        F: Fn(
+                RemoveTagsFromResourceRequest,
+            ) -> ::std::result::Result<
+                RemoveTagsFromResourceResponse,
+                RusotoError<RemoveTagsFromResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_resource = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CloudHsm for MockCloudHsm {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
+        match self.add_tags_to_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for add_tags_to_resource"),
+        }
+    }
+    fn create_hapg(
+        &self,
+        input: CreateHapgRequest,
+    ) -> RusotoFuture<CreateHapgResponse, CreateHapgError> {
+        match self.create_hapg {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for create_hapg"),
+        }
+    }
+    fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        match self.create_hsm {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for create_hsm"),
+        }
+    }
+    fn create_luna_client(
+        &self,
+        input: CreateLunaClientRequest,
+    ) -> RusotoFuture<CreateLunaClientResponse, CreateLunaClientError> {
+        match self.create_luna_client {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for create_luna_client"),
+        }
+    }
+    fn delete_hapg(
+        &self,
+        input: DeleteHapgRequest,
+    ) -> RusotoFuture<DeleteHapgResponse, DeleteHapgError> {
+        match self.delete_hapg {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for delete_hapg"),
+        }
+    }
+    fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        match self.delete_hsm {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for delete_hsm"),
+        }
+    }
+    fn delete_luna_client(
+        &self,
+        input: DeleteLunaClientRequest,
+    ) -> RusotoFuture<DeleteLunaClientResponse, DeleteLunaClientError> {
+        match self.delete_luna_client {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for delete_luna_client"),
+        }
+    }
+    fn describe_hapg(
+        &self,
+        input: DescribeHapgRequest,
+    ) -> RusotoFuture<DescribeHapgResponse, DescribeHapgError> {
+        match self.describe_hapg {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for describe_hapg"),
+        }
+    }
+    fn describe_hsm(
+        &self,
+        input: DescribeHsmRequest,
+    ) -> RusotoFuture<DescribeHsmResponse, DescribeHsmError> {
+        match self.describe_hsm {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for describe_hsm"),
+        }
+    }
+    fn describe_luna_client(
+        &self,
+        input: DescribeLunaClientRequest,
+    ) -> RusotoFuture<DescribeLunaClientResponse, DescribeLunaClientError> {
+        match self.describe_luna_client {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for describe_luna_client"),
+        }
+    }
+    fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> RusotoFuture<GetConfigResponse, GetConfigError> {
+        match self.get_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for get_config"),
+        }
+    }
+    fn list_available_zones(
+        &self,
+    ) -> RusotoFuture<ListAvailableZonesResponse, ListAvailableZonesError> {
+        match self.list_available_zones {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockCloudHsm has no handler for list_available_zones"),
+        }
+    }
+    fn list_hapgs(
+        &self,
+        input: ListHapgsRequest,
+    ) -> RusotoFuture<ListHapgsResponse, ListHapgsError> {
+        match self.list_hapgs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for list_hapgs"),
+        }
+    }
+    fn list_hsms(&self, input: ListHsmsRequest) -> RusotoFuture<ListHsmsResponse, ListHsmsError> {
+        match self.list_hsms {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for list_hsms"),
+        }
+    }
+    fn list_luna_clients(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> RusotoFuture<ListLunaClientsResponse, ListLunaClientsError> {
+        match self.list_luna_clients {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for list_luna_clients"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for list_tags_for_resource"),
+        }
+    }
+    fn modify_hapg(
+        &self,
+        input: ModifyHapgRequest,
+    ) -> RusotoFuture<ModifyHapgResponse, ModifyHapgError> {
+        match self.modify_hapg {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for modify_hapg"),
+        }
+    }
+    fn modify_hsm(
+        &self,
+        input: ModifyHsmRequest,
+    ) -> RusotoFuture<ModifyHsmResponse, ModifyHsmError> {
+        match self.modify_hsm {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for modify_hsm"),
+        }
+    }
+    fn modify_luna_client(
+        &self,
+        input: ModifyLunaClientRequest,
+    ) -> RusotoFuture<ModifyLunaClientResponse, ModifyLunaClientError> {
+        match self.modify_luna_client {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for modify_luna_client"),
+        }
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        match self.remove_tags_from_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsm has no handler for remove_tags_from_resource"),
+        }
+    }
+}
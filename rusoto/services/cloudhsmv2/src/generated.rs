@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Contains information about a backup of an AWS CloudHSM cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Backup {
     /// <p>The identifier (ID) of the backup.</p>
     #[serde(rename = "BackupId")]
@@ -62,7 +63,7 @@ pub struct Backup {
 
 /// <p>Contains one or more certificates or a certificate signing request (CSR).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Certificates {
     /// <p>The HSM hardware certificate issued (signed) by AWS CloudHSM.</p>
     #[serde(rename = "AwsHardwareCertificate")]
@@ -88,7 +89,7 @@ pub struct Certificates {
 
 /// <p>Contains information about an AWS CloudHSM cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Cluster {
     /// <p>The cluster's backup policy.</p>
     #[serde(rename = "BackupPolicy")]
@@ -145,6 +146,7 @@ pub struct Cluster {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyBackupToRegionRequest {
     /// <p>The ID of the backup that will be copied to the destination region. </p>
     #[serde(rename = "BackupId")]
@@ -154,8 +156,23 @@ pub struct CopyBackupToRegionRequest {
     pub destination_region: String,
 }
 
+impl Validate for CopyBackupToRegionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "backup_id"),
+            &self.backup_id,
+            "backup-[2-7a-zA-Z]{11,16}",
+        );
+        errors.check_pattern(
+            &field_path(path, "destination_region"),
+            &self.destination_region,
+            "[a-z]{2}(-(gov))?-(east|west|north|south|central){1,2}-\\d",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CopyBackupToRegionResponse {
     /// <p>Information on the backup that will be copied to the destination region, including CreateTimestamp, SourceBackup, SourceCluster, and Source Region. CreateTimestamp of the destination backup will be the same as that of the source backup.</p> <p>You will need to use the <code>sourceBackupID</code> returned in this operation to use the <a>DescribeBackups</a> operation on the backup that will be copied to the destination region.</p>
     #[serde(rename = "DestinationBackup")]
@@ -164,6 +181,7 @@ pub struct CopyBackupToRegionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterRequest {
     /// <p>The type of HSM to use in the cluster. Currently the only allowed value is <code>hsm1.medium</code>.</p>
     #[serde(rename = "HsmType")]
@@ -177,8 +195,35 @@ pub struct CreateClusterRequest {
     pub subnet_ids: Vec<String>,
 }
 
+impl Validate for CreateClusterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "hsm_type"),
+            &self.hsm_type,
+            "(hsm1\\.medium)",
+        );
+        if let Some(ref value) = self.source_backup_id {
+            errors.check_pattern(
+                &field_path(path, "source_backup_id"),
+                &value,
+                "backup-[2-7a-zA-Z]{11,16}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "subnet_ids"),
+            self.subnet_ids.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.subnet_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subnet_ids"), i);
+            errors.check_pattern(&item_path, &item, "subnet-[0-9a-fA-F]{8,17}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateClusterResponse {
     /// <p>Information about the cluster that was created.</p>
     #[serde(rename = "Cluster")]
@@ -187,6 +232,7 @@ pub struct CreateClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHsmRequest {
     /// <p>The Availability Zone where you are creating the HSM. To find the cluster's Availability Zones, use <a>DescribeClusters</a>.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -200,8 +246,30 @@ pub struct CreateHsmRequest {
     pub ip_address: Option<String>,
 }
 
+impl Validate for CreateHsmRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "availability_zone"),
+            &self.availability_zone,
+            "[a-z]{2}(-(gov))?-(east|west|north|south|central){1,2}-\\d[a-z]",
+        );
+        errors.check_pattern(
+            &field_path(path, "cluster_id"),
+            &self.cluster_id,
+            "cluster-[2-7a-zA-Z]{11,16}",
+        );
+        if let Some(ref value) = self.ip_address {
+            errors.check_pattern(
+                &field_path(path, "ip_address"),
+                &value,
+                "\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateHsmResponse {
     /// <p>Information about the HSM that was created.</p>
     #[serde(rename = "Hsm")]
@@ -210,14 +278,25 @@ pub struct CreateHsmResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBackupRequest {
     /// <p>The ID of the backup to be deleted. To find the ID of a backup, use the <a>DescribeBackups</a> operation.</p>
     #[serde(rename = "BackupId")]
     pub backup_id: String,
 }
 
+impl Validate for DeleteBackupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "backup_id"),
+            &self.backup_id,
+            "backup-[2-7a-zA-Z]{11,16}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBackupResponse {
     /// <p>Information on the <code>Backup</code> object deleted.</p>
     #[serde(rename = "Backup")]
@@ -226,14 +305,25 @@ pub struct DeleteBackupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterRequest {
     /// <p>The identifier (ID) of the cluster that you are deleting. To find the cluster ID, use <a>DescribeClusters</a>.</p>
     #[serde(rename = "ClusterId")]
     pub cluster_id: String,
 }
 
+impl Validate for DeleteClusterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "cluster_id"),
+            &self.cluster_id,
+            "cluster-[2-7a-zA-Z]{11,16}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteClusterResponse {
     /// <p>Information about the cluster that was deleted.</p>
     #[serde(rename = "Cluster")]
@@ -242,6 +332,7 @@ pub struct DeleteClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteHsmRequest {
     /// <p>The identifier (ID) of the cluster that contains the HSM that you are deleting.</p>
     #[serde(rename = "ClusterId")]
@@ -260,8 +351,35 @@ pub struct DeleteHsmRequest {
     pub hsm_id: Option<String>,
 }
 
+impl Validate for DeleteHsmRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "cluster_id"),
+            &self.cluster_id,
+            "cluster-[2-7a-zA-Z]{11,16}",
+        );
+        if let Some(ref value) = self.eni_id {
+            errors.check_pattern(&field_path(path, "eni_id"), &value, "eni-[0-9a-fA-F]{8,17}");
+        }
+        if let Some(ref value) = self.eni_ip {
+            errors.check_pattern(
+                &field_path(path, "eni_ip"),
+                &value,
+                "\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}",
+            );
+        }
+        if let Some(ref value) = self.hsm_id {
+            errors.check_pattern(
+                &field_path(path, "hsm_id"),
+                &value,
+                "hsm-[2-7a-zA-Z]{11,16}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteHsmResponse {
     /// <p>The identifier (ID) of the HSM that was deleted.</p>
     #[serde(rename = "HsmId")]
@@ -270,6 +388,7 @@ pub struct DeleteHsmResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBackupsRequest {
     /// <p>One or more filters to limit the items returned in the response.</p> <p>Use the <code>backupIds</code> filter to return only the specified backups. Specify backups by their backup identifier (ID).</p> <p>Use the <code>sourceBackupIds</code> filter to return only the backups created from a source backup. The <code>sourceBackupID</code> of a source backup is returned by the <a>CopyBackupToRegion</a> operation.</p> <p>Use the <code>clusterIds</code> filter to return only the backups for the specified clusters. Specify clusters by their cluster identifier (ID).</p> <p>Use the <code>states</code> filter to return only backups that match the specified state.</p>
     #[serde(rename = "Filters")]
@@ -288,8 +407,25 @@ pub struct DescribeBackupsRequest {
     pub sort_ascending: Option<bool>,
 }
 
+impl Validate for DescribeBackupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBackupsResponse {
     /// <p>A list of backups.</p>
     #[serde(rename = "Backups")]
@@ -302,6 +438,7 @@ pub struct DescribeBackupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClustersRequest {
     /// <p>One or more filters to limit the items returned in the response.</p> <p>Use the <code>clusterIds</code> filter to return only the specified clusters. Specify clusters by their cluster identifier (ID).</p> <p>Use the <code>vpcIds</code> filter to return only the clusters in the specified virtual private clouds (VPCs). Specify VPCs by their VPC identifier (ID).</p> <p>Use the <code>states</code> filter to return only clusters that match the specified state.</p>
     #[serde(rename = "Filters")]
@@ -317,8 +454,25 @@ pub struct DescribeClustersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeClustersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeClustersResponse {
     /// <p>A list of clusters.</p>
     #[serde(rename = "Clusters")]
@@ -331,7 +485,7 @@ pub struct DescribeClustersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DestinationBackup {
     #[serde(rename = "CreateTimestamp")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -349,7 +503,7 @@ pub struct DestinationBackup {
 
 /// <p>Contains information about a hardware security module (HSM) in an AWS CloudHSM cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Hsm {
     /// <p>The Availability Zone that contains the HSM.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -385,6 +539,7 @@ pub struct Hsm {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InitializeClusterRequest {
     /// <p>The identifier (ID) of the cluster that you are claiming. To find the cluster ID, use <a>DescribeClusters</a>.</p>
     #[serde(rename = "ClusterId")]
@@ -397,8 +552,40 @@ pub struct InitializeClusterRequest {
     pub trust_anchor: String,
 }
 
+impl Validate for InitializeClusterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "cluster_id"),
+            &self.cluster_id,
+            "cluster-[2-7a-zA-Z]{11,16}",
+        );
+        errors.check_length(
+            &field_path(path, "signed_cert"),
+            self.signed_cert.chars().count(),
+            None,
+            Some(5000),
+        );
+        errors.check_pattern(
+            &field_path(path, "signed_cert"),
+            &self.signed_cert,
+            "[a-zA-Z0-9+-/=\\s]*",
+        );
+        errors.check_length(
+            &field_path(path, "trust_anchor"),
+            self.trust_anchor.chars().count(),
+            None,
+            Some(5000),
+        );
+        errors.check_pattern(
+            &field_path(path, "trust_anchor"),
+            &self.trust_anchor,
+            "[a-zA-Z0-9+-/=\\s]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InitializeClusterResponse {
     /// <p>The cluster's state.</p>
     #[serde(rename = "State")]
@@ -411,6 +598,7 @@ pub struct InitializeClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsRequest {
     /// <p>The maximum number of tags to return in the response. When there are more tags than the number you specify, the response contains a <code>NextToken</code> value.</p>
     #[serde(rename = "MaxResults")]
@@ -425,8 +613,30 @@ pub struct ListTagsRequest {
     pub resource_id: String,
 }
 
+impl Validate for ListTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "(?:cluster|backup)-[2-7a-zA-Z]{11,16}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsResponse {
     /// <p>An opaque string that indicates that the response contains only a subset of tags. Use this value in a subsequent <code>ListTags</code> request to get more tags.</p>
     #[serde(rename = "NextToken")]
@@ -438,14 +648,25 @@ pub struct ListTagsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreBackupRequest {
     /// <p>The ID of the backup to be restored. To find the ID of a backup, use the <a>DescribeBackups</a> operation.</p>
     #[serde(rename = "BackupId")]
     pub backup_id: String,
 }
 
+impl Validate for RestoreBackupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "backup_id"),
+            &self.backup_id,
+            "backup-[2-7a-zA-Z]{11,16}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestoreBackupResponse {
     /// <p>Information on the <code>Backup</code> object created.</p>
     #[serde(rename = "Backup")]
@@ -464,7 +685,35 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The cluster identifier (ID) for the cluster that you are tagging. To find the cluster ID, use <a>DescribeClusters</a>.</p>
     #[serde(rename = "ResourceId")]
@@ -474,11 +723,32 @@ pub struct TagResourceRequest {
     pub tag_list: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "(?:cluster|backup)-[2-7a-zA-Z]{11,16}",
+        );
+        errors.check_length(
+            &field_path(path, "tag_list"),
+            self.tag_list.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_list"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The cluster identifier (ID) for the cluster whose tags you are removing. To find the cluster ID, use <a>DescribeClusters</a>.</p>
     #[serde(rename = "ResourceId")]
@@ -488,8 +758,29 @@ pub struct UntagResourceRequest {
     pub tag_key_list: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "(?:cluster|backup)-[2-7a-zA-Z]{11,16}",
+        );
+        errors.check_length(
+            &field_path(path, "tag_key_list"),
+            self.tag_key_list.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_key_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_key_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 /// Errors returned by CopyBackupToRegion
@@ -534,7 +825,12 @@ impl CopyBackupToRegionError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(CopyBackupToRegionError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -597,7 +893,12 @@ impl CreateClusterError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(CreateClusterError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -654,7 +955,12 @@ impl CreateHsmError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(CreateHsmError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -715,7 +1021,12 @@ impl DeleteBackupError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(DeleteBackupError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -778,7 +1089,12 @@ impl DeleteClusterError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(DeleteClusterError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -835,7 +1151,12 @@ impl DeleteHsmError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(DeleteHsmError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -900,7 +1221,12 @@ impl DescribeBackupsError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(DescribeBackupsError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -958,7 +1284,12 @@ impl DescribeClustersError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(DescribeClustersError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1022,7 +1353,12 @@ impl InitializeClusterError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(InitializeClusterError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1079,7 +1415,12 @@ impl ListTagsError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(ListTagsError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1142,7 +1483,12 @@ impl RestoreBackupError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(RestoreBackupError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1201,7 +1547,12 @@ impl TagResourceError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(TagResourceError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1264,7 +1615,12 @@ impl UntagResourceError {
                 "CloudHsmServiceException" => {
                     return RusotoError::Service(UntagResourceError::CloudHsmService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1364,6 +1720,238 @@ pub trait CloudHsmv2 {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
 }
+const _: Option<&dyn CloudHsmv2> = None;
+impl<'a, T: CloudHsmv2 + ?Sized> CloudHsmv2 for &'a T {
+    fn copy_backup_to_region(
+        &self,
+        input: CopyBackupToRegionRequest,
+    ) -> RusotoFuture<CopyBackupToRegionResponse, CopyBackupToRegionError> {
+        (**self).copy_backup_to_region(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        (**self).create_hsm(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        (**self).delete_hsm(input)
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn initialize_cluster(
+        &self,
+        input: InitializeClusterRequest,
+    ) -> RusotoFuture<InitializeClusterResponse, InitializeClusterError> {
+        (**self).initialize_cluster(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn restore_backup(
+        &self,
+        input: RestoreBackupRequest,
+    ) -> RusotoFuture<RestoreBackupResponse, RestoreBackupError> {
+        (**self).restore_backup(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: CloudHsmv2 + ?Sized> CloudHsmv2 for Box<T> {
+    fn copy_backup_to_region(
+        &self,
+        input: CopyBackupToRegionRequest,
+    ) -> RusotoFuture<CopyBackupToRegionResponse, CopyBackupToRegionError> {
+        (**self).copy_backup_to_region(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        (**self).create_hsm(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        (**self).delete_hsm(input)
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn initialize_cluster(
+        &self,
+        input: InitializeClusterRequest,
+    ) -> RusotoFuture<InitializeClusterResponse, InitializeClusterError> {
+        (**self).initialize_cluster(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn restore_backup(
+        &self,
+        input: RestoreBackupRequest,
+    ) -> RusotoFuture<RestoreBackupResponse, RestoreBackupError> {
+        (**self).restore_backup(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: CloudHsmv2 + ?Sized> CloudHsmv2 for ::std::sync::Arc<T> {
+    fn copy_backup_to_region(
+        &self,
+        input: CopyBackupToRegionRequest,
+    ) -> RusotoFuture<CopyBackupToRegionResponse, CopyBackupToRegionError> {
+        (**self).copy_backup_to_region(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        (**self).create_hsm(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        (**self).delete_hsm(input)
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn initialize_cluster(
+        &self,
+        input: InitializeClusterRequest,
+    ) -> RusotoFuture<InitializeClusterResponse, InitializeClusterError> {
+        (**self).initialize_cluster(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn restore_backup(
+        &self,
+        input: RestoreBackupRequest,
+    ) -> RusotoFuture<RestoreBackupResponse, RestoreBackupError> {
+        (**self).restore_backup(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
 /// A client for the CloudHSM V2 API.
 #[derive(Clone)]
 pub struct CloudHsmv2Client {
@@ -1407,7 +1995,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: CopyBackupToRegionRequest,
     ) -> RusotoFuture<CopyBackupToRegionResponse, CopyBackupToRegionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CopyBackupToRegion");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.CopyBackupToRegion");
@@ -1416,10 +2013,10 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CopyBackupToRegionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CopyBackupToRegionResponse, _>(
+                    response,
+                    "CopyBackupToRegion",
+                )
             } else {
                 Box::new(
                     response
@@ -1436,7 +2033,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: CreateClusterRequest,
     ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateCluster");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.CreateCluster");
@@ -1445,10 +2051,10 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateClusterResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateClusterResponse, _>(
+                    response,
+                    "CreateCluster",
+                )
             } else {
                 Box::new(
                     response
@@ -1465,7 +2071,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: CreateHsmRequest,
     ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateHsm");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.CreateHsm");
@@ -1474,10 +2089,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateHsmResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateHsmResponse, _>(response, "CreateHsm")
             } else {
                 Box::new(
                     response
@@ -1494,7 +2106,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: DeleteBackupRequest,
     ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteBackup");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DeleteBackup");
@@ -1503,10 +2124,10 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBackupResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteBackupResponse, _>(
+                    response,
+                    "DeleteBackup",
+                )
             } else {
                 Box::new(
                     response
@@ -1523,7 +2144,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: DeleteClusterRequest,
     ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteCluster");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DeleteCluster");
@@ -1532,10 +2162,10 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteClusterResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteClusterResponse, _>(
+                    response,
+                    "DeleteCluster",
+                )
             } else {
                 Box::new(
                     response
@@ -1552,7 +2182,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: DeleteHsmRequest,
     ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteHsm");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DeleteHsm");
@@ -1561,10 +2200,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteHsmResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteHsmResponse, _>(response, "DeleteHsm")
             } else {
                 Box::new(
                     response
@@ -1581,7 +2217,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: DescribeBackupsRequest,
     ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeBackups");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DescribeBackups");
@@ -1590,10 +2235,10 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBackupsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeBackupsResponse, _>(
+                    response,
+                    "DescribeBackups",
+                )
             } else {
                 Box::new(
                     response
@@ -1610,7 +2255,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: DescribeClustersRequest,
     ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeClusters");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DescribeClusters");
@@ -1619,10 +2273,10 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeClustersResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeClustersResponse, _>(
+                    response,
+                    "DescribeClusters",
+                )
             } else {
                 Box::new(
                     response
@@ -1639,7 +2293,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: InitializeClusterRequest,
     ) -> RusotoFuture<InitializeClusterResponse, InitializeClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("InitializeCluster");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.InitializeCluster");
@@ -1648,10 +2311,10 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InitializeClusterResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<InitializeClusterResponse, _>(
+                    response,
+                    "InitializeCluster",
+                )
             } else {
                 Box::new(
                     response
@@ -1665,7 +2328,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
     /// <p>Gets a list of tags for the specified AWS CloudHSM cluster.</p> <p>This is a paginated operation, which means that each response might contain only a subset of all the tags. When the response contains only a subset of tags, it includes a <code>NextToken</code> value. Use this value in a subsequent <code>ListTags</code> request to get more tags. When you receive a response with no <code>NextToken</code> (or an empty or null value), that means there are no more tags to get.</p>
     fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListTags");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.ListTags");
@@ -1674,10 +2346,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsResponse, _>(response, "ListTags")
             } else {
                 Box::new(
                     response
@@ -1694,7 +2363,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: RestoreBackupRequest,
     ) -> RusotoFuture<RestoreBackupResponse, RestoreBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("RestoreBackup");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.RestoreBackup");
@@ -1703,10 +2381,10 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestoreBackupResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RestoreBackupResponse, _>(
+                    response,
+                    "RestoreBackup",
+                )
             } else {
                 Box::new(
                     response
@@ -1723,7 +2401,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("TagResource");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.TagResource");
@@ -1732,10 +2419,10 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -1752,7 +2439,16 @@ impl CloudHsmv2 for CloudHsmv2Client {
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("UntagResource");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.UntagResource");
@@ -1761,10 +2457,10 @@ impl CloudHsmv2 for CloudHsmv2Client {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -1776,3 +2472,455 @@ impl CloudHsmv2 for CloudHsmv2Client {
         })
     }
 }
+
+/// A mock of the CloudHSM V2 API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloudHsmv2 {
+    copy_backup_to_region: Option<
+        Box<
+            dyn Fn(
+                    CopyBackupToRegionRequest,
+                ) -> ::std::result::Result<
+                    CopyBackupToRegionResponse,
+                    RusotoError<CopyBackupToRegionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cluster: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterRequest,
+                )
+                    -> ::std::result::Result<CreateClusterResponse, RusotoError<CreateClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_hsm: Option<
+        Box<
+            dyn Fn(
+                    CreateHsmRequest,
+                )
+                    -> ::std::result::Result<CreateHsmResponse, RusotoError<CreateHsmError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_backup: Option<
+        Box<
+            dyn Fn(
+                    DeleteBackupRequest,
+                )
+                    -> ::std::result::Result<DeleteBackupResponse, RusotoError<DeleteBackupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_cluster: Option<
+        Box<
+            dyn Fn(
+                    DeleteClusterRequest,
+                )
+                    -> ::std::result::Result<DeleteClusterResponse, RusotoError<DeleteClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_hsm: Option<
+        Box<
+            dyn Fn(
+                    DeleteHsmRequest,
+                )
+                    -> ::std::result::Result<DeleteHsmResponse, RusotoError<DeleteHsmError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_backups: Option<
+        Box<
+            dyn Fn(
+                    DescribeBackupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeBackupsResponse,
+                    RusotoError<DescribeBackupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_clusters: Option<
+        Box<
+            dyn Fn(
+                    DescribeClustersRequest,
+                ) -> ::std::result::Result<
+                    DescribeClustersResponse,
+                    RusotoError<DescribeClustersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    initialize_cluster: Option<
+        Box<
+            dyn Fn(
+                    InitializeClusterRequest,
+                ) -> ::std::result::Result<
+                    InitializeClusterResponse,
+                    RusotoError<InitializeClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags: Option<
+        Box<
+            dyn Fn(
+                    ListTagsRequest,
+                )
+                    -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    restore_backup: Option<
+        Box<
+            dyn Fn(
+                    RestoreBackupRequest,
+                )
+                    -> ::std::result::Result<RestoreBackupResponse, RusotoError<RestoreBackupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloudHsmv2 {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloudHsmv2 {
+        Default::default()
+    }
+
+    /// Answers `copy_backup_to_region` with what `handler` returns for its input.
+    pub fn with_copy_backup_to_region<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                CopyBackupToRegionRequest,
+            ) -> ::std::result::Result<
+                CopyBackupToRegionResponse,
+                RusotoError<CopyBackupToRegionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.copy_backup_to_region = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cluster` with what `handler` returns for its input.
+    pub fn with_create_cluster<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                CreateClusterRequest,
+            )
+                -> ::std::result::Result<CreateClusterResponse, RusotoError<CreateClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_hsm` with what `handler` returns for its input.
+    pub fn with_create_hsm<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                CreateHsmRequest,
+            ) -> ::std::result::Result<CreateHsmResponse, RusotoError<CreateHsmError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_hsm = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_backup` with what `handler` returns for its input.
+    pub fn with_delete_backup<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                DeleteBackupRequest,
+            )
+                -> ::std::result::Result<DeleteBackupResponse, RusotoError<DeleteBackupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cluster` with what `handler` returns for its input.
+    pub fn with_delete_cluster<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                DeleteClusterRequest,
+            )
+                -> ::std::result::Result<DeleteClusterResponse, RusotoError<DeleteClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_hsm` with what `handler` returns for its input.
+    pub fn with_delete_hsm<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                DeleteHsmRequest,
+            ) -> ::std::result::Result<DeleteHsmResponse, RusotoError<DeleteHsmError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_hsm = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_backups` with what `handler` returns for its input.
+    pub fn with_describe_backups<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                DescribeBackupsRequest,
+            )
+                -> ::std::result::Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_backups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_clusters` with what `handler` returns for its input.
+    pub fn with_describe_clusters<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                DescribeClustersRequest,
+            ) -> ::std::result::Result<
+                DescribeClustersResponse,
+                RusotoError<DescribeClustersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `initialize_cluster` with what `handler` returns for its input.
+    pub fn with_initialize_cluster<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                InitializeClusterRequest,
+            ) -> ::std::result::Result<
+                InitializeClusterResponse,
+                RusotoError<InitializeClusterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.initialize_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags` with what `handler` returns for its input.
+    pub fn with_list_tags<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                ListTagsRequest,
+            ) -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_backup` with what `handler` returns for its input.
+    pub fn with_restore_backup<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                RestoreBackupRequest,
+            )
+                -> ::std::result::Result<RestoreBackupResponse, RusotoError<RestoreBackupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockCloudHsmv2
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CloudHsmv2 for MockCloudHsmv2 {
+    fn copy_backup_to_region(
+        &self,
+        input: CopyBackupToRegionRequest,
+    ) -> RusotoFuture<CopyBackupToRegionResponse, CopyBackupToRegionError> {
+        match self.copy_backup_to_region {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for copy_backup_to_region"),
+        }
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        match self.create_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for create_cluster"),
+        }
+    }
+    fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        match self.create_hsm {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for create_hsm"),
+        }
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        match self.delete_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for delete_backup"),
+        }
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        match self.delete_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for delete_cluster"),
+        }
+    }
+    fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        match self.delete_hsm {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for delete_hsm"),
+        }
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        match self.describe_backups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for describe_backups"),
+        }
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        match self.describe_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for describe_clusters"),
+        }
+    }
+    fn initialize_cluster(
+        &self,
+        input: InitializeClusterRequest,
+    ) -> RusotoFuture<InitializeClusterResponse, InitializeClusterError> {
+        match self.initialize_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for initialize_cluster"),
+        }
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        match self.list_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for list_tags"),
+        }
+    }
+    fn restore_backup(
+        &self,
+        input: RestoreBackupRequest,
+    ) -> RusotoFuture<RestoreBackupResponse, RestoreBackupError> {
+        match self.restore_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for restore_backup"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudHsmv2 has no handler for untag_resource"),
+        }
+    }
+}
@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -57,6 +58,8 @@ impl ARNDeserializer {
 }
 /// <p>The configured access rules for the domain's document and search endpoints, and the current status of those rules.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccessPoliciesStatus {
     pub options: String,
     pub status: OptionStatus,
@@ -96,6 +99,8 @@ impl AlgorithmicStemmingDeserializer {
 }
 /// <p>Synonyms, stopwords, and stemming options for an analysis scheme. Includes tokenization dictionary for Japanese.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnalysisOptions {
     /// <p>The level of algorithmic stemming to perform: <code>none</code>, <code>minimal</code>, <code>light</code>, or <code>full</code>. The available levels vary depending on the language. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/text-processing.html#text-processing-settings" target="_blank">Language Specific Text Processing Settings</a> in the <i>Amazon CloudSearch Developer Guide</i> </p>
     pub algorithmic_stemming: Option<String>,
@@ -184,12 +189,30 @@ impl AnalysisOptionsSerializer {
 
 /// <p>Configuration information for an analysis scheme. Each analysis scheme has a unique name and specifies the language of the text to be processed. The following options can be configured for an analysis scheme: <code>Synonyms</code>, <code>Stopwords</code>, <code>StemmingDictionary</code>, <code>JapaneseTokenizationDictionary</code> and <code>AlgorithmicStemming</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnalysisScheme {
     pub analysis_options: Option<AnalysisOptions>,
     pub analysis_scheme_language: String,
     pub analysis_scheme_name: String,
 }
 
+impl Validate for AnalysisScheme {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "analysis_scheme_name"),
+            self.analysis_scheme_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "analysis_scheme_name"),
+            &self.analysis_scheme_name,
+            "[a-z][a-z0-9_]*",
+        );
+    }
+}
+
 struct AnalysisSchemeDeserializer;
 impl AnalysisSchemeDeserializer {
     #[allow(unused_variables)]
@@ -262,6 +285,8 @@ impl AnalysisSchemeLanguageDeserializer {
 }
 /// <p>The status and configuration of an <code>AnalysisScheme</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnalysisSchemeStatus {
     pub options: AnalysisScheme,
     pub status: OptionStatus,
@@ -309,6 +334,8 @@ impl AnalysisSchemeStatusListDeserializer {
 }
 /// <p>The status and configuration of the domain's availability options.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AvailabilityOptionsStatus {
     /// <p>The availability options configured for the domain.</p>
     pub options: bool,
@@ -345,7 +372,7 @@ impl BooleanDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -353,10 +380,28 @@ impl BooleanDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>BuildSuggester</a></code> operation. Specifies the name of the domain you want to update.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BuildSuggestersRequest {
     pub domain_name: String,
 }
 
+impl Validate for BuildSuggestersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `BuildSuggestersRequest` contents to a `SignedRequest`.
 struct BuildSuggestersRequestSerializer;
 impl BuildSuggestersRequestSerializer {
@@ -372,6 +417,8 @@ impl BuildSuggestersRequestSerializer {
 
 /// <p>The result of a <code>BuildSuggester</code> request. Contains a list of the fields used for suggestions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BuildSuggestersResponse {
     pub field_names: Option<Vec<String>>,
 }
@@ -402,11 +449,29 @@ impl BuildSuggestersResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>CreateDomain</a></code> operation. Specifies a name for the new search domain.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDomainRequest {
     /// <p>A name for the domain you are creating. Allowed characters are a-z (lower-case letters), 0-9, and hyphen (-). Domain names must start with a letter or number and be at least 3 and no more than 28 characters long.</p>
     pub domain_name: String,
 }
 
+impl Validate for CreateDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `CreateDomainRequest` contents to a `SignedRequest`.
 struct CreateDomainRequestSerializer;
 impl CreateDomainRequestSerializer {
@@ -422,6 +487,8 @@ impl CreateDomainRequestSerializer {
 
 /// <p>The result of a <code>CreateDomainRequest</code>. Contains the status of a newly created domain.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDomainResponse {
     pub domain_status: Option<DomainStatus>,
 }
@@ -449,6 +516,8 @@ impl CreateDomainResponseDeserializer {
 }
 /// <p>Options for a field that contains an array of dates. Present if <code>IndexFieldType</code> specifies the field is of type <code>date-array</code>. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DateArrayOptions {
     /// <p>A value to use for the field if the field isn&#39;t specified for a document.</p>
     pub default_value: Option<String>,
@@ -462,6 +531,26 @@ pub struct DateArrayOptions {
     pub source_fields: Option<String>,
 }
 
+impl Validate for DateArrayOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_value {
+            errors.check_length(
+                &field_path(path, "default_value"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.source_fields {
+            errors.check_pattern(
+                &field_path(path, "source_fields"),
+                &value,
+                "\\s*[a-z*][a-z0-9_]*\\*?\\s*(,\\s*[a-z*][a-z0-9_]*\\*?\\s*)*",
+            );
+        }
+    }
+}
+
 struct DateArrayOptionsDeserializer;
 impl DateArrayOptionsDeserializer {
     #[allow(unused_variables)]
@@ -529,6 +618,8 @@ impl DateArrayOptionsSerializer {
 
 /// <p>Options for a date field. Dates and times are specified in UTC (Coordinated Universal Time) according to IETF RFC3339: yyyy-mm-ddT00:00:00Z. Present if <code>IndexFieldType</code> specifies the field is of type <code>date</code>. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DateOptions {
     /// <p>A value to use for the field if the field isn&#39;t specified for a document.</p>
     pub default_value: Option<String>,
@@ -543,6 +634,28 @@ pub struct DateOptions {
     pub source_field: Option<String>,
 }
 
+impl Validate for DateOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_value {
+            errors.check_length(
+                &field_path(path, "default_value"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.source_field {
+            errors.check_length(
+                &field_path(path, "source_field"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "source_field"), &value, "[a-z][a-z0-9_]*");
+        }
+    }
+}
+
 struct DateOptionsDeserializer;
 impl DateOptionsDeserializer {
     #[allow(unused_variables)]
@@ -615,11 +728,31 @@ impl DateOptionsSerializer {
 
 /// <p>Container for the parameters to the <code><a>DefineAnalysisScheme</a></code> operation. Specifies the name of the domain you want to update and the analysis scheme configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefineAnalysisSchemeRequest {
     pub analysis_scheme: AnalysisScheme,
     pub domain_name: String,
 }
 
+impl Validate for DefineAnalysisSchemeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.analysis_scheme
+            .validate_at(&field_path(path, "analysis_scheme"), errors);
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `DefineAnalysisSchemeRequest` contents to a `SignedRequest`.
 struct DefineAnalysisSchemeRequestSerializer;
 impl DefineAnalysisSchemeRequestSerializer {
@@ -640,6 +773,8 @@ impl DefineAnalysisSchemeRequestSerializer {
 
 /// <p>The result of a <code><a>DefineAnalysisScheme</a></code> request. Contains the status of the newly-configured analysis scheme.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefineAnalysisSchemeResponse {
     pub analysis_scheme: AnalysisSchemeStatus,
 }
@@ -669,11 +804,31 @@ impl DefineAnalysisSchemeResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DefineExpression</a></code> operation. Specifies the name of the domain you want to update and the expression you want to configure.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefineExpressionRequest {
     pub domain_name: String,
     pub expression: Expression,
 }
 
+impl Validate for DefineExpressionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+        self.expression
+            .validate_at(&field_path(path, "expression"), errors);
+    }
+}
+
 /// Serialize `DefineExpressionRequest` contents to a `SignedRequest`.
 struct DefineExpressionRequestSerializer;
 impl DefineExpressionRequestSerializer {
@@ -694,6 +849,8 @@ impl DefineExpressionRequestSerializer {
 
 /// <p>The result of a <code>DefineExpression</code> request. Contains the status of the newly-configured expression.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefineExpressionResponse {
     pub expression: ExpressionStatus,
 }
@@ -723,12 +880,32 @@ impl DefineExpressionResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DefineIndexField</a></code> operation. Specifies the name of the domain you want to update and the index field configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefineIndexFieldRequest {
     pub domain_name: String,
     /// <p>The index field and field options you want to configure. </p>
     pub index_field: IndexField,
 }
 
+impl Validate for DefineIndexFieldRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+        self.index_field
+            .validate_at(&field_path(path, "index_field"), errors);
+    }
+}
+
 /// Serialize `DefineIndexFieldRequest` contents to a `SignedRequest`.
 struct DefineIndexFieldRequestSerializer;
 impl DefineIndexFieldRequestSerializer {
@@ -749,6 +926,8 @@ impl DefineIndexFieldRequestSerializer {
 
 /// <p>The result of a <code><a>DefineIndexField</a></code> request. Contains the status of the newly-configured index field.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefineIndexFieldResponse {
     pub index_field: IndexFieldStatus,
 }
@@ -778,11 +957,31 @@ impl DefineIndexFieldResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DefineSuggester</a></code> operation. Specifies the name of the domain you want to update and the suggester configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefineSuggesterRequest {
     pub domain_name: String,
     pub suggester: Suggester,
 }
 
+impl Validate for DefineSuggesterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+        self.suggester
+            .validate_at(&field_path(path, "suggester"), errors);
+    }
+}
+
 /// Serialize `DefineSuggesterRequest` contents to a `SignedRequest`.
 struct DefineSuggesterRequestSerializer;
 impl DefineSuggesterRequestSerializer {
@@ -803,6 +1002,8 @@ impl DefineSuggesterRequestSerializer {
 
 /// <p>The result of a <code>DefineSuggester</code> request. Contains the status of the newly-configured suggester.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefineSuggesterResponse {
     pub suggester: SuggesterStatus,
 }
@@ -832,12 +1033,41 @@ impl DefineSuggesterResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DeleteAnalysisScheme</a></code> operation. Specifies the name of the domain you want to update and the analysis scheme you want to delete. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAnalysisSchemeRequest {
     /// <p>The name of the analysis scheme you want to delete.</p>
     pub analysis_scheme_name: String,
     pub domain_name: String,
 }
 
+impl Validate for DeleteAnalysisSchemeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "analysis_scheme_name"),
+            self.analysis_scheme_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "analysis_scheme_name"),
+            &self.analysis_scheme_name,
+            "[a-z][a-z0-9_]*",
+        );
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `DeleteAnalysisSchemeRequest` contents to a `SignedRequest`.
 struct DeleteAnalysisSchemeRequestSerializer;
 impl DeleteAnalysisSchemeRequestSerializer {
@@ -857,6 +1087,8 @@ impl DeleteAnalysisSchemeRequestSerializer {
 
 /// <p>The result of a <code>DeleteAnalysisScheme</code> request. Contains the status of the deleted analysis scheme.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAnalysisSchemeResponse {
     /// <p>The status of the analysis scheme being deleted.</p>
     pub analysis_scheme: AnalysisSchemeStatus,
@@ -887,11 +1119,29 @@ impl DeleteAnalysisSchemeResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DeleteDomain</a></code> operation. Specifies the name of the domain you want to delete.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDomainRequest {
     /// <p>The name of the domain you want to permanently delete.</p>
     pub domain_name: String,
 }
 
+impl Validate for DeleteDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `DeleteDomainRequest` contents to a `SignedRequest`.
 struct DeleteDomainRequestSerializer;
 impl DeleteDomainRequestSerializer {
@@ -907,6 +1157,8 @@ impl DeleteDomainRequestSerializer {
 
 /// <p>The result of a <code>DeleteDomain</code> request. Contains the status of a newly deleted domain, or no status if the domain has already been completely deleted.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDomainResponse {
     pub domain_status: Option<DomainStatus>,
 }
@@ -934,12 +1186,41 @@ impl DeleteDomainResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DeleteExpression</a></code> operation. Specifies the name of the domain you want to update and the name of the expression you want to delete.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteExpressionRequest {
     pub domain_name: String,
     /// <p>The name of the <code><a>Expression</a></code> to delete.</p>
     pub expression_name: String,
 }
 
+impl Validate for DeleteExpressionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+        errors.check_length(
+            &field_path(path, "expression_name"),
+            self.expression_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "expression_name"),
+            &self.expression_name,
+            "[a-z][a-z0-9_]*",
+        );
+    }
+}
+
 /// Serialize `DeleteExpressionRequest` contents to a `SignedRequest`.
 struct DeleteExpressionRequestSerializer;
 impl DeleteExpressionRequestSerializer {
@@ -959,6 +1240,8 @@ impl DeleteExpressionRequestSerializer {
 
 /// <p>The result of a <code><a>DeleteExpression</a></code> request. Specifies the expression being deleted.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteExpressionResponse {
     /// <p>The status of the expression being deleted.</p>
     pub expression: ExpressionStatus,
@@ -989,12 +1272,41 @@ impl DeleteExpressionResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DeleteIndexField</a></code> operation. Specifies the name of the domain you want to update and the name of the index field you want to delete.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIndexFieldRequest {
     pub domain_name: String,
     /// <p>The name of the index field your want to remove from the domain's indexing options.</p>
     pub index_field_name: String,
 }
 
+impl Validate for DeleteIndexFieldRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+        errors.check_length(
+            &field_path(path, "index_field_name"),
+            self.index_field_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "index_field_name"),
+            &self.index_field_name,
+            "([a-z][a-z0-9_]*\\*?|\\*[a-z0-9_]*)",
+        );
+    }
+}
+
 /// Serialize `DeleteIndexFieldRequest` contents to a `SignedRequest`.
 struct DeleteIndexFieldRequestSerializer;
 impl DeleteIndexFieldRequestSerializer {
@@ -1014,6 +1326,8 @@ impl DeleteIndexFieldRequestSerializer {
 
 /// <p>The result of a <code><a>DeleteIndexField</a></code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIndexFieldResponse {
     /// <p>The status of the index field being deleted.</p>
     pub index_field: IndexFieldStatus,
@@ -1044,12 +1358,41 @@ impl DeleteIndexFieldResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DeleteSuggester</a></code> operation. Specifies the name of the domain you want to update and name of the suggester you want to delete.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSuggesterRequest {
     pub domain_name: String,
     /// <p>Specifies the name of the suggester you want to delete.</p>
     pub suggester_name: String,
 }
 
+impl Validate for DeleteSuggesterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+        errors.check_length(
+            &field_path(path, "suggester_name"),
+            self.suggester_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "suggester_name"),
+            &self.suggester_name,
+            "[a-z][a-z0-9_]*",
+        );
+    }
+}
+
 /// Serialize `DeleteSuggesterRequest` contents to a `SignedRequest`.
 struct DeleteSuggesterRequestSerializer;
 impl DeleteSuggesterRequestSerializer {
@@ -1069,6 +1412,8 @@ impl DeleteSuggesterRequestSerializer {
 
 /// <p>The result of a <code>DeleteSuggester</code> request. Contains the status of the deleted suggester.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSuggesterResponse {
     /// <p>The status of the suggester being deleted.</p>
     pub suggester: SuggesterStatus,
@@ -1099,6 +1444,8 @@ impl DeleteSuggesterResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DescribeAnalysisSchemes</a></code> operation. Specifies the name of the domain you want to describe. To limit the response to particular analysis schemes, specify the names of the analysis schemes you want to describe. To show the active configuration and exclude any pending changes, set the <code>Deployed</code> option to <code>true</code>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAnalysisSchemesRequest {
     /// <p>The analysis schemes you want to describe.</p>
     pub analysis_scheme_names: Option<Vec<String>>,
@@ -1108,6 +1455,29 @@ pub struct DescribeAnalysisSchemesRequest {
     pub domain_name: String,
 }
 
+impl Validate for DescribeAnalysisSchemesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analysis_scheme_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "analysis_scheme_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+                errors.check_pattern(&item_path, &item, "[a-z][a-z0-9_]*");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `DescribeAnalysisSchemesRequest` contents to a `SignedRequest`.
 struct DescribeAnalysisSchemesRequestSerializer;
 impl DescribeAnalysisSchemesRequestSerializer {
@@ -1133,6 +1503,8 @@ impl DescribeAnalysisSchemesRequestSerializer {
 
 /// <p>The result of a <code>DescribeAnalysisSchemes</code> request. Contains the analysis schemes configured for the domain specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAnalysisSchemesResponse {
     /// <p>The analysis scheme descriptions.</p>
     pub analysis_schemes: Vec<AnalysisSchemeStatus>,
@@ -1167,6 +1539,8 @@ impl DescribeAnalysisSchemesResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DescribeAvailabilityOptions</a></code> operation. Specifies the name of the domain you want to describe. To show the active configuration and exclude any pending changes, set the Deployed option to <code>true</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAvailabilityOptionsRequest {
     /// <p>Whether to display the deployed configuration (<code>true</code>) or include any pending changes (<code>false</code>). Defaults to <code>false</code>.</p>
     pub deployed: Option<bool>,
@@ -1174,6 +1548,22 @@ pub struct DescribeAvailabilityOptionsRequest {
     pub domain_name: String,
 }
 
+impl Validate for DescribeAvailabilityOptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `DescribeAvailabilityOptionsRequest` contents to a `SignedRequest`.
 struct DescribeAvailabilityOptionsRequestSerializer;
 impl DescribeAvailabilityOptionsRequestSerializer {
@@ -1192,6 +1582,8 @@ impl DescribeAvailabilityOptionsRequestSerializer {
 
 /// <p>The result of a <code>DescribeAvailabilityOptions</code> request. Indicates whether or not the Multi-AZ option is enabled for the domain specified in the request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAvailabilityOptionsResponse {
     /// <p>The availability options configured for the domain. Indicates whether Multi-AZ is enabled for the domain. </p>
     pub availability_options: Option<AvailabilityOptionsStatus>,
@@ -1225,11 +1617,25 @@ impl DescribeAvailabilityOptionsResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DescribeDomains</a></code> operation. By default shows the status of all domains. To restrict the response to particular domains, specify the names of the domains you want to describe.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDomainsRequest {
     /// <p>The names of the domains you want to include in the response.</p>
     pub domain_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeDomainsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.domain_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "domain_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(3), Some(28));
+                errors.check_pattern(&item_path, &item, "[a-z][a-z0-9\\-]+");
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeDomainsRequest` contents to a `SignedRequest`.
 struct DescribeDomainsRequestSerializer;
 impl DescribeDomainsRequestSerializer {
@@ -1251,6 +1657,8 @@ impl DescribeDomainsRequestSerializer {
 
 /// <p>The result of a <code>DescribeDomains</code> request. Contains the status of the domains specified in the request or all domains owned by the account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDomainsResponse {
     pub domain_status_list: Vec<DomainStatus>,
 }
@@ -1283,6 +1691,8 @@ impl DescribeDomainsResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DescribeDomains</a></code> operation. Specifies the name of the domain you want to describe. To restrict the response to particular expressions, specify the names of the expressions you want to describe. To show the active configuration and exclude any pending changes, set the <code>Deployed</code> option to <code>true</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeExpressionsRequest {
     /// <p>Whether to display the deployed configuration (<code>true</code>) or include any pending changes (<code>false</code>). Defaults to <code>false</code>.</p>
     pub deployed: Option<bool>,
@@ -1292,6 +1702,29 @@ pub struct DescribeExpressionsRequest {
     pub expression_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeExpressionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+        if let Some(ref value) = self.expression_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "expression_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+                errors.check_pattern(&item_path, &item, "[a-z][a-z0-9_]*");
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeExpressionsRequest` contents to a `SignedRequest`.
 struct DescribeExpressionsRequestSerializer;
 impl DescribeExpressionsRequestSerializer {
@@ -1317,6 +1750,8 @@ impl DescribeExpressionsRequestSerializer {
 
 /// <p>The result of a <code>DescribeExpressions</code> request. Contains the expressions configured for the domain specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeExpressionsResponse {
     /// <p>The expressions configured for the domain.</p>
     pub expressions: Vec<ExpressionStatus>,
@@ -1350,6 +1785,8 @@ impl DescribeExpressionsResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DescribeIndexFields</a></code> operation. Specifies the name of the domain you want to describe. To restrict the response to particular index fields, specify the names of the index fields you want to describe. To show the active configuration and exclude any pending changes, set the <code>Deployed</code> option to <code>true</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeIndexFieldsRequest {
     /// <p>Whether to display the deployed configuration (<code>true</code>) or include any pending changes (<code>false</code>). Defaults to <code>false</code>.</p>
     pub deployed: Option<bool>,
@@ -1359,6 +1796,29 @@ pub struct DescribeIndexFieldsRequest {
     pub field_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeIndexFieldsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+        if let Some(ref value) = self.field_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "field_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+                errors.check_pattern(&item_path, &item, "([a-z][a-z0-9_]*\\*?|\\*[a-z0-9_]*)");
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeIndexFieldsRequest` contents to a `SignedRequest`.
 struct DescribeIndexFieldsRequestSerializer;
 impl DescribeIndexFieldsRequestSerializer {
@@ -1384,6 +1844,8 @@ impl DescribeIndexFieldsRequestSerializer {
 
 /// <p>The result of a <code>DescribeIndexFields</code> request. Contains the index fields configured for the domain specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeIndexFieldsResponse {
     /// <p>The index fields configured for the domain.</p>
     pub index_fields: Vec<IndexFieldStatus>,
@@ -1417,10 +1879,28 @@ impl DescribeIndexFieldsResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DescribeScalingParameters</a></code> operation. Specifies the name of the domain you want to describe. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScalingParametersRequest {
     pub domain_name: String,
 }
 
+impl Validate for DescribeScalingParametersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `DescribeScalingParametersRequest` contents to a `SignedRequest`.
 struct DescribeScalingParametersRequestSerializer;
 impl DescribeScalingParametersRequestSerializer {
@@ -1436,6 +1916,8 @@ impl DescribeScalingParametersRequestSerializer {
 
 /// <p>The result of a <code>DescribeScalingParameters</code> request. Contains the scaling parameters configured for the domain specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScalingParametersResponse {
     pub scaling_parameters: ScalingParametersStatus,
 }
@@ -1467,6 +1949,8 @@ impl DescribeScalingParametersResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DescribeServiceAccessPolicies</a></code> operation. Specifies the name of the domain you want to describe. To show the active configuration and exclude any pending changes, set the <code>Deployed</code> option to <code>true</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServiceAccessPoliciesRequest {
     /// <p>Whether to display the deployed configuration (<code>true</code>) or include any pending changes (<code>false</code>). Defaults to <code>false</code>.</p>
     pub deployed: Option<bool>,
@@ -1474,6 +1958,22 @@ pub struct DescribeServiceAccessPoliciesRequest {
     pub domain_name: String,
 }
 
+impl Validate for DescribeServiceAccessPoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `DescribeServiceAccessPoliciesRequest` contents to a `SignedRequest`.
 struct DescribeServiceAccessPoliciesRequestSerializer;
 impl DescribeServiceAccessPoliciesRequestSerializer {
@@ -1492,6 +1992,8 @@ impl DescribeServiceAccessPoliciesRequestSerializer {
 
 /// <p>The result of a <code>DescribeServiceAccessPolicies</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServiceAccessPoliciesResponse {
     /// <p>The access rules configured for the domain specified in the request.</p>
     pub access_policies: AccessPoliciesStatus,
@@ -1522,6 +2024,8 @@ impl DescribeServiceAccessPoliciesResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>DescribeSuggester</a></code> operation. Specifies the name of the domain you want to describe. To restrict the response to particular suggesters, specify the names of the suggesters you want to describe. To show the active configuration and exclude any pending changes, set the <code>Deployed</code> option to <code>true</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSuggestersRequest {
     /// <p>Whether to display the deployed configuration (<code>true</code>) or include any pending changes (<code>false</code>). Defaults to <code>false</code>.</p>
     pub deployed: Option<bool>,
@@ -1531,6 +2035,29 @@ pub struct DescribeSuggestersRequest {
     pub suggester_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeSuggestersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+        if let Some(ref value) = self.suggester_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "suggester_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+                errors.check_pattern(&item_path, &item, "[a-z][a-z0-9_]*");
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeSuggestersRequest` contents to a `SignedRequest`.
 struct DescribeSuggestersRequestSerializer;
 impl DescribeSuggestersRequestSerializer {
@@ -1556,6 +2083,8 @@ impl DescribeSuggestersRequestSerializer {
 
 /// <p>The result of a <code>DescribeSuggesters</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSuggestersResponse {
     /// <p>The suggesters configured for the domain specified in the request.</p>
     pub suggesters: Vec<SuggesterStatus>,
@@ -1589,6 +2118,8 @@ impl DescribeSuggestersResponseDeserializer {
 }
 /// <p>Options for a search suggester.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DocumentSuggesterOptions {
     /// <p>The level of fuzziness allowed when suggesting matches for a string: <code>none</code>, <code>low</code>, or <code>high</code>. With none, the specified string is treated as an exact prefix. With low, suggestions must differ from the specified string by no more than one character. With high, suggestions can differ by up to two characters. The default is none. </p>
     pub fuzzy_matching: Option<String>,
@@ -1598,6 +2129,22 @@ pub struct DocumentSuggesterOptions {
     pub source_field: String,
 }
 
+impl Validate for DocumentSuggesterOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "source_field"),
+            self.source_field.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "source_field"),
+            &self.source_field,
+            "[a-z][a-z0-9_]*",
+        );
+    }
+}
+
 struct DocumentSuggesterOptionsDeserializer;
 impl DocumentSuggesterOptionsDeserializer {
     #[allow(unused_variables)]
@@ -1710,6 +2257,8 @@ impl DomainNameMapDeserializer {
 }
 /// <p>The current status of the search domain.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DomainStatus {
     pub arn: Option<String>,
     /// <p>True if the search domain is created. It can take several minutes to initialize a domain when <a>CreateDomain</a> is called. Newly created search domains are returned from <a>DescribeDomains</a> with a false value for Created until domain creation is complete.</p>
@@ -1827,7 +2376,7 @@ impl DoubleDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1835,6 +2384,8 @@ impl DoubleDeserializer {
 }
 /// <p>Options for a field that contains an array of double-precision 64-bit floating point values. Present if <code>IndexFieldType</code> specifies the field is of type <code>double-array</code>. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DoubleArrayOptions {
     /// <p>A value to use for the field if the field isn&#39;t specified for a document.</p>
     pub default_value: Option<f64>,
@@ -1848,6 +2399,18 @@ pub struct DoubleArrayOptions {
     pub source_fields: Option<String>,
 }
 
+impl Validate for DoubleArrayOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_fields {
+            errors.check_pattern(
+                &field_path(path, "source_fields"),
+                &value,
+                "\\s*[a-z*][a-z0-9_]*\\*?\\s*(,\\s*[a-z*][a-z0-9_]*\\*?\\s*)*",
+            );
+        }
+    }
+}
+
 struct DoubleArrayOptionsDeserializer;
 impl DoubleArrayOptionsDeserializer {
     #[allow(unused_variables)]
@@ -1915,6 +2478,8 @@ impl DoubleArrayOptionsSerializer {
 
 /// <p>Options for a double-precision 64-bit floating point field. Present if <code>IndexFieldType</code> specifies the field is of type <code>double</code>. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DoubleOptions {
     /// <p>A value to use for the field if the field isn't specified for a document. This can be important if you are using the field in an expression and that field is not present in every document.</p>
     pub default_value: Option<f64>,
@@ -1930,6 +2495,20 @@ pub struct DoubleOptions {
     pub source_field: Option<String>,
 }
 
+impl Validate for DoubleOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_field {
+            errors.check_length(
+                &field_path(path, "source_field"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "source_field"), &value, "[a-z][a-z0-9_]*");
+        }
+    }
+}
+
 struct DoubleOptionsDeserializer;
 impl DoubleOptionsDeserializer {
     #[allow(unused_variables)]
@@ -2025,11 +2604,35 @@ impl DynamicFieldNameListSerializer {
 
 /// <p>A named expression that can be evaluated at search time. Can be used to sort the search results, define other expressions, or return computed information in the search results. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Expression {
     pub expression_name: String,
     pub expression_value: String,
 }
 
+impl Validate for Expression {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "expression_name"),
+            self.expression_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "expression_name"),
+            &self.expression_name,
+            "[a-z][a-z0-9_]*",
+        );
+        errors.check_length(
+            &field_path(path, "expression_value"),
+            self.expression_value.chars().count(),
+            Some(1),
+            Some(10240),
+        );
+    }
+}
+
 struct ExpressionDeserializer;
 impl ExpressionDeserializer {
     #[allow(unused_variables)]
@@ -2076,6 +2679,8 @@ impl ExpressionSerializer {
 
 /// <p>The value of an <code>Expression</code> and its current status.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExpressionStatus {
     /// <p>The expression that is evaluated for sorting while processing a search request.</p>
     pub options: Expression,
@@ -2183,10 +2788,28 @@ impl FieldValueDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>IndexDocuments</a></code> operation. Specifies the name of the domain you want to re-index.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IndexDocumentsRequest {
     pub domain_name: String,
 }
 
+impl Validate for IndexDocumentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `IndexDocumentsRequest` contents to a `SignedRequest`.
 struct IndexDocumentsRequestSerializer;
 impl IndexDocumentsRequestSerializer {
@@ -2202,6 +2825,8 @@ impl IndexDocumentsRequestSerializer {
 
 /// <p>The result of an <code>IndexDocuments</code> request. Contains the status of the indexing operation, including the fields being indexed.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IndexDocumentsResponse {
     /// <p>The names of the fields that are currently being indexed.</p>
     pub field_names: Option<Vec<String>>,
@@ -2229,6 +2854,8 @@ impl IndexDocumentsResponseDeserializer {
 }
 /// <p>Configuration information for a field in the index, including its name, type, and options. The supported options depend on the <code><a>IndexFieldType</a></code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IndexField {
     pub date_array_options: Option<DateArrayOptions>,
     pub date_options: Option<DateOptions>,
@@ -2246,6 +2873,55 @@ pub struct IndexField {
     pub text_options: Option<TextOptions>,
 }
 
+impl Validate for IndexField {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.date_array_options {
+            value.validate_at(&field_path(path, "date_array_options"), errors);
+        }
+        if let Some(ref value) = self.date_options {
+            value.validate_at(&field_path(path, "date_options"), errors);
+        }
+        if let Some(ref value) = self.double_array_options {
+            value.validate_at(&field_path(path, "double_array_options"), errors);
+        }
+        if let Some(ref value) = self.double_options {
+            value.validate_at(&field_path(path, "double_options"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "index_field_name"),
+            self.index_field_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "index_field_name"),
+            &self.index_field_name,
+            "([a-z][a-z0-9_]*\\*?|\\*[a-z0-9_]*)",
+        );
+        if let Some(ref value) = self.int_array_options {
+            value.validate_at(&field_path(path, "int_array_options"), errors);
+        }
+        if let Some(ref value) = self.int_options {
+            value.validate_at(&field_path(path, "int_options"), errors);
+        }
+        if let Some(ref value) = self.lat_lon_options {
+            value.validate_at(&field_path(path, "lat_lon_options"), errors);
+        }
+        if let Some(ref value) = self.literal_array_options {
+            value.validate_at(&field_path(path, "literal_array_options"), errors);
+        }
+        if let Some(ref value) = self.literal_options {
+            value.validate_at(&field_path(path, "literal_options"), errors);
+        }
+        if let Some(ref value) = self.text_array_options {
+            value.validate_at(&field_path(path, "text_array_options"), errors);
+        }
+        if let Some(ref value) = self.text_options {
+            value.validate_at(&field_path(path, "text_options"), errors);
+        }
+    }
+}
+
 struct IndexFieldDeserializer;
 impl IndexFieldDeserializer {
     #[allow(unused_variables)]
@@ -2429,6 +3105,8 @@ impl IndexFieldSerializer {
 
 /// <p>The value of an <code>IndexField</code> and its current status.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IndexFieldStatus {
     pub options: IndexField,
     pub status: OptionStatus,
@@ -2488,7 +3166,7 @@ impl InstanceCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2496,6 +3174,8 @@ impl InstanceCountDeserializer {
 }
 /// <p>Options for a field that contains an array of 64-bit signed integers. Present if <code>IndexFieldType</code> specifies the field is of type <code>int-array</code>. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IntArrayOptions {
     /// <p>A value to use for the field if the field isn&#39;t specified for a document.</p>
     pub default_value: Option<i64>,
@@ -2509,6 +3189,18 @@ pub struct IntArrayOptions {
     pub source_fields: Option<String>,
 }
 
+impl Validate for IntArrayOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_fields {
+            errors.check_pattern(
+                &field_path(path, "source_fields"),
+                &value,
+                "\\s*[a-z*][a-z0-9_]*\\*?\\s*(,\\s*[a-z*][a-z0-9_]*\\*?\\s*)*",
+            );
+        }
+    }
+}
+
 struct IntArrayOptionsDeserializer;
 impl IntArrayOptionsDeserializer {
     #[allow(unused_variables)]
@@ -2575,6 +3267,8 @@ impl IntArrayOptionsSerializer {
 
 /// <p>Options for a 64-bit signed integer field. Present if <code>IndexFieldType</code> specifies the field is of type <code>int</code>. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IntOptions {
     /// <p>A value to use for the field if the field isn&#39;t specified for a document. This can be important if you are using the field in an expression and that field is not present in every document.</p>
     pub default_value: Option<i64>,
@@ -2590,6 +3284,20 @@ pub struct IntOptions {
     pub source_field: Option<String>,
 }
 
+impl Validate for IntOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_field {
+            errors.check_length(
+                &field_path(path, "source_field"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "source_field"), &value, "[a-z][a-z0-9_]*");
+        }
+    }
+}
+
 struct IntOptionsDeserializer;
 impl IntOptionsDeserializer {
     #[allow(unused_variables)]
@@ -2661,6 +3369,8 @@ impl IntOptionsSerializer {
 
 /// <p>Options for a latlon field. A latlon field contains a location stored as a latitude and longitude value pair. Present if <code>IndexFieldType</code> specifies the field is of type <code>latlon</code>. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LatLonOptions {
     /// <p>A value to use for the field if the field isn&#39;t specified for a document.</p>
     pub default_value: Option<String>,
@@ -2675,6 +3385,28 @@ pub struct LatLonOptions {
     pub source_field: Option<String>,
 }
 
+impl Validate for LatLonOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_value {
+            errors.check_length(
+                &field_path(path, "default_value"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.source_field {
+            errors.check_length(
+                &field_path(path, "source_field"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "source_field"), &value, "[a-z][a-z0-9_]*");
+        }
+    }
+}
+
 struct LatLonOptionsDeserializer;
 impl LatLonOptionsDeserializer {
     #[allow(unused_variables)]
@@ -2746,6 +3478,8 @@ impl LatLonOptionsSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Limits {
     pub maximum_partition_count: i64,
     pub maximum_replication_count: i64,
@@ -2778,6 +3512,8 @@ impl LimitsDeserializer {
 }
 /// <p>The result of a <code>ListDomainNames</code> request. Contains a list of the domains owned by an account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDomainNamesResponse {
     /// <p>The names of the search domains owned by an account.</p>
     pub domain_names: Option<::std::collections::HashMap<String, String>>,
@@ -2810,6 +3546,8 @@ impl ListDomainNamesResponseDeserializer {
 }
 /// <p>Options for a field that contains an array of literal strings. Present if <code>IndexFieldType</code> specifies the field is of type <code>literal-array</code>. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LiteralArrayOptions {
     /// <p>A value to use for the field if the field isn&#39;t specified for a document.</p>
     pub default_value: Option<String>,
@@ -2823,6 +3561,26 @@ pub struct LiteralArrayOptions {
     pub source_fields: Option<String>,
 }
 
+impl Validate for LiteralArrayOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_value {
+            errors.check_length(
+                &field_path(path, "default_value"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.source_fields {
+            errors.check_pattern(
+                &field_path(path, "source_fields"),
+                &value,
+                "\\s*[a-z*][a-z0-9_]*\\*?\\s*(,\\s*[a-z*][a-z0-9_]*\\*?\\s*)*",
+            );
+        }
+    }
+}
+
 struct LiteralArrayOptionsDeserializer;
 impl LiteralArrayOptionsDeserializer {
     #[allow(unused_variables)]
@@ -2890,6 +3648,8 @@ impl LiteralArrayOptionsSerializer {
 
 /// <p>Options for literal field. Present if <code>IndexFieldType</code> specifies the field is of type <code>literal</code>. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LiteralOptions {
     /// <p>A value to use for the field if the field isn&#39;t specified for a document.</p>
     pub default_value: Option<String>,
@@ -2904,6 +3664,28 @@ pub struct LiteralOptions {
     pub source_field: Option<String>,
 }
 
+impl Validate for LiteralOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_value {
+            errors.check_length(
+                &field_path(path, "default_value"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.source_field {
+            errors.check_length(
+                &field_path(path, "source_field"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "source_field"), &value, "[a-z][a-z0-9_]*");
+        }
+    }
+}
+
 struct LiteralOptionsDeserializer;
 impl LiteralOptionsDeserializer {
     #[allow(unused_variables)]
@@ -2979,7 +3761,7 @@ impl LongDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2990,7 +3772,7 @@ impl MaximumPartitionCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3001,7 +3783,7 @@ impl MaximumReplicationCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3012,7 +3794,7 @@ impl MultiAZDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3031,6 +3813,8 @@ impl OptionStateDeserializer {
 }
 /// <p>The status of domain configuration option.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OptionStatus {
     /// <p>A timestamp for when this option was created.</p>
     pub creation_date: String,
@@ -3083,7 +3867,7 @@ impl PartitionCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3113,6 +3897,8 @@ impl PolicyDocumentDeserializer {
 }
 /// <p>The desired instance type and desired number of replicas of each index partition.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScalingParameters {
     /// <p>The instance type that you want to preconfigure for your domain. For example, <code>search.m1.small</code>.</p>
     pub desired_instance_type: Option<String>,
@@ -3189,6 +3975,8 @@ impl ScalingParametersSerializer {
 
 /// <p>The status and configuration of a search domain's scaling parameters. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScalingParametersStatus {
     pub options: ScalingParameters,
     pub status: OptionStatus,
@@ -3232,6 +4020,8 @@ impl SearchInstanceTypeDeserializer {
 }
 /// <p>The endpoint to which service requests can be submitted.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ServiceEndpoint {
     pub endpoint: Option<String>,
 }
@@ -3301,11 +4091,31 @@ impl StringDeserializer {
 }
 /// <p>Configuration information for a search suggester. Each suggester has a unique name and specifies the text field you want to use for suggestions. The following options can be configured for a suggester: <code>FuzzyMatching</code>, <code>SortExpression</code>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Suggester {
     pub document_suggester_options: DocumentSuggesterOptions,
     pub suggester_name: String,
 }
 
+impl Validate for Suggester {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.document_suggester_options
+            .validate_at(&field_path(path, "document_suggester_options"), errors);
+        errors.check_length(
+            &field_path(path, "suggester_name"),
+            self.suggester_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "suggester_name"),
+            &self.suggester_name,
+            "[a-z][a-z0-9_]*",
+        );
+    }
+}
+
 struct SuggesterDeserializer;
 impl SuggesterDeserializer {
     #[allow(unused_variables)]
@@ -3367,6 +4177,8 @@ impl SuggesterFuzzyMatchingDeserializer {
 }
 /// <p>The value of a <code>Suggester</code> and its current status.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SuggesterStatus {
     pub options: Suggester,
     pub status: OptionStatus,
@@ -3412,6 +4224,8 @@ impl SuggesterStatusListDeserializer {
 }
 /// <p>Options for a field that contains an array of text strings. Present if <code>IndexFieldType</code> specifies the field is of type <code>text-array</code>. A <code>text-array</code> field is always searchable. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TextArrayOptions {
     /// <p>The name of an analysis scheme for a <code>text-array</code> field.</p>
     pub analysis_scheme: Option<String>,
@@ -3425,6 +4239,29 @@ pub struct TextArrayOptions {
     pub source_fields: Option<String>,
 }
 
+impl Validate for TextArrayOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analysis_scheme {
+            errors.check_pattern(&field_path(path, "analysis_scheme"), &value, "[\\S]+");
+        }
+        if let Some(ref value) = self.default_value {
+            errors.check_length(
+                &field_path(path, "default_value"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.source_fields {
+            errors.check_pattern(
+                &field_path(path, "source_fields"),
+                &value,
+                "\\s*[a-z*][a-z0-9_]*\\*?\\s*(,\\s*[a-z*][a-z0-9_]*\\*?\\s*)*",
+            );
+        }
+    }
+}
+
 struct TextArrayOptionsDeserializer;
 impl TextArrayOptionsDeserializer {
     #[allow(unused_variables)]
@@ -3492,6 +4329,8 @@ impl TextArrayOptionsSerializer {
 
 /// <p>Options for text field. Present if <code>IndexFieldType</code> specifies the field is of type <code>text</code>. A <code>text</code> field is always searchable. All options are enabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TextOptions {
     /// <p>The name of an analysis scheme for a <code>text</code> field.</p>
     pub analysis_scheme: Option<String>,
@@ -3506,6 +4345,31 @@ pub struct TextOptions {
     pub source_field: Option<String>,
 }
 
+impl Validate for TextOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analysis_scheme {
+            errors.check_pattern(&field_path(path, "analysis_scheme"), &value, "[\\S]+");
+        }
+        if let Some(ref value) = self.default_value {
+            errors.check_length(
+                &field_path(path, "default_value"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.source_field {
+            errors.check_length(
+                &field_path(path, "source_field"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "source_field"), &value, "[a-z][a-z0-9_]*");
+        }
+    }
+}
+
 struct TextOptionsDeserializer;
 impl TextOptionsDeserializer {
     #[allow(unused_variables)]
@@ -3581,7 +4445,7 @@ impl UIntValueDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3589,12 +4453,30 @@ impl UIntValueDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>UpdateAvailabilityOptions</a></code> operation. Specifies the name of the domain you want to update and the Multi-AZ availability option.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAvailabilityOptionsRequest {
     pub domain_name: String,
     /// <p>You expand an existing search domain to a second Availability Zone by setting the Multi-AZ option to true. Similarly, you can turn off the Multi-AZ option to downgrade the domain to a single Availability Zone by setting the Multi-AZ option to <code>false</code>. </p>
     pub multi_az: bool,
 }
 
+impl Validate for UpdateAvailabilityOptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `UpdateAvailabilityOptionsRequest` contents to a `SignedRequest`.
 struct UpdateAvailabilityOptionsRequestSerializer;
 impl UpdateAvailabilityOptionsRequestSerializer {
@@ -3611,6 +4493,8 @@ impl UpdateAvailabilityOptionsRequestSerializer {
 
 /// <p>The result of a <code>UpdateAvailabilityOptions</code> request. Contains the status of the domain's availability options. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAvailabilityOptionsResponse {
     /// <p>The newly-configured availability options. Indicates whether Multi-AZ is enabled for the domain. </p>
     pub availability_options: Option<AvailabilityOptionsStatus>,
@@ -3644,11 +4528,29 @@ impl UpdateAvailabilityOptionsResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>UpdateScalingParameters</a></code> operation. Specifies the name of the domain you want to update and the scaling parameters you want to configure.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateScalingParametersRequest {
     pub domain_name: String,
     pub scaling_parameters: ScalingParameters,
 }
 
+impl Validate for UpdateScalingParametersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `UpdateScalingParametersRequest` contents to a `SignedRequest`.
 struct UpdateScalingParametersRequestSerializer;
 impl UpdateScalingParametersRequestSerializer {
@@ -3669,6 +4571,8 @@ impl UpdateScalingParametersRequestSerializer {
 
 /// <p>The result of a <code>UpdateScalingParameters</code> request. Contains the status of the newly-configured scaling parameters.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateScalingParametersResponse {
     pub scaling_parameters: ScalingParametersStatus,
 }
@@ -3700,12 +4604,30 @@ impl UpdateScalingParametersResponseDeserializer {
 }
 /// <p>Container for the parameters to the <code><a>UpdateServiceAccessPolicies</a></code> operation. Specifies the name of the domain you want to update and the access rules you want to configure.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServiceAccessPoliciesRequest {
     /// <p>The access rules you want to configure. These rules replace any existing rules. </p>
     pub access_policies: String,
     pub domain_name: String,
 }
 
+impl Validate for UpdateServiceAccessPoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(3),
+            Some(28),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "[a-z][a-z0-9\\-]+",
+        );
+    }
+}
+
 /// Serialize `UpdateServiceAccessPoliciesRequest` contents to a `SignedRequest`.
 struct UpdateServiceAccessPoliciesRequestSerializer;
 impl UpdateServiceAccessPoliciesRequestSerializer {
@@ -3725,6 +4647,8 @@ impl UpdateServiceAccessPoliciesRequestSerializer {
 
 /// <p>The result of an <code>UpdateServiceAccessPolicies</code> request. Contains the new access policies.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServiceAccessPoliciesResponse {
     /// <p>The access rules configured for the domain.</p>
     pub access_policies: AccessPoliciesStatus,
@@ -5584,19 +6508,452 @@ pub trait CloudSearch {
         input: UpdateServiceAccessPoliciesRequest,
     ) -> RusotoFuture<UpdateServiceAccessPoliciesResponse, UpdateServiceAccessPoliciesError>;
 }
-/// A client for the Amazon CloudSearch API.
-#[derive(Clone)]
-pub struct CloudSearchClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CloudSearchClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CloudSearchClient {
-        Self::new_with_client(Client::shared(), region)
+const _: Option<&dyn CloudSearch> = None;
+impl<'a, T: CloudSearch + ?Sized> CloudSearch for &'a T {
+    fn build_suggesters(
+        &self,
+        input: BuildSuggestersRequest,
+    ) -> RusotoFuture<BuildSuggestersResponse, BuildSuggestersError> {
+        (**self).build_suggesters(input)
+    }
+    fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> RusotoFuture<CreateDomainResponse, CreateDomainError> {
+        (**self).create_domain(input)
+    }
+    fn define_analysis_scheme(
+        &self,
+        input: DefineAnalysisSchemeRequest,
+    ) -> RusotoFuture<DefineAnalysisSchemeResponse, DefineAnalysisSchemeError> {
+        (**self).define_analysis_scheme(input)
+    }
+    fn define_expression(
+        &self,
+        input: DefineExpressionRequest,
+    ) -> RusotoFuture<DefineExpressionResponse, DefineExpressionError> {
+        (**self).define_expression(input)
+    }
+    fn define_index_field(
+        &self,
+        input: DefineIndexFieldRequest,
+    ) -> RusotoFuture<DefineIndexFieldResponse, DefineIndexFieldError> {
+        (**self).define_index_field(input)
+    }
+    fn define_suggester(
+        &self,
+        input: DefineSuggesterRequest,
+    ) -> RusotoFuture<DefineSuggesterResponse, DefineSuggesterError> {
+        (**self).define_suggester(input)
+    }
+    fn delete_analysis_scheme(
+        &self,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> RusotoFuture<DeleteAnalysisSchemeResponse, DeleteAnalysisSchemeError> {
+        (**self).delete_analysis_scheme(input)
+    }
+    fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> RusotoFuture<DeleteDomainResponse, DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
+    fn delete_expression(
+        &self,
+        input: DeleteExpressionRequest,
+    ) -> RusotoFuture<DeleteExpressionResponse, DeleteExpressionError> {
+        (**self).delete_expression(input)
+    }
+    fn delete_index_field(
+        &self,
+        input: DeleteIndexFieldRequest,
+    ) -> RusotoFuture<DeleteIndexFieldResponse, DeleteIndexFieldError> {
+        (**self).delete_index_field(input)
+    }
+    fn delete_suggester(
+        &self,
+        input: DeleteSuggesterRequest,
+    ) -> RusotoFuture<DeleteSuggesterResponse, DeleteSuggesterError> {
+        (**self).delete_suggester(input)
+    }
+    fn describe_analysis_schemes(
+        &self,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> RusotoFuture<DescribeAnalysisSchemesResponse, DescribeAnalysisSchemesError> {
+        (**self).describe_analysis_schemes(input)
+    }
+    fn describe_availability_options(
+        &self,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> RusotoFuture<DescribeAvailabilityOptionsResponse, DescribeAvailabilityOptionsError> {
+        (**self).describe_availability_options(input)
+    }
+    fn describe_domains(
+        &self,
+        input: DescribeDomainsRequest,
+    ) -> RusotoFuture<DescribeDomainsResponse, DescribeDomainsError> {
+        (**self).describe_domains(input)
+    }
+    fn describe_expressions(
+        &self,
+        input: DescribeExpressionsRequest,
+    ) -> RusotoFuture<DescribeExpressionsResponse, DescribeExpressionsError> {
+        (**self).describe_expressions(input)
+    }
+    fn describe_index_fields(
+        &self,
+        input: DescribeIndexFieldsRequest,
+    ) -> RusotoFuture<DescribeIndexFieldsResponse, DescribeIndexFieldsError> {
+        (**self).describe_index_fields(input)
+    }
+    fn describe_scaling_parameters(
+        &self,
+        input: DescribeScalingParametersRequest,
+    ) -> RusotoFuture<DescribeScalingParametersResponse, DescribeScalingParametersError> {
+        (**self).describe_scaling_parameters(input)
+    }
+    fn describe_service_access_policies(
+        &self,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> RusotoFuture<DescribeServiceAccessPoliciesResponse, DescribeServiceAccessPoliciesError>
+    {
+        (**self).describe_service_access_policies(input)
+    }
+    fn describe_suggesters(
+        &self,
+        input: DescribeSuggestersRequest,
+    ) -> RusotoFuture<DescribeSuggestersResponse, DescribeSuggestersError> {
+        (**self).describe_suggesters(input)
+    }
+    fn index_documents(
+        &self,
+        input: IndexDocumentsRequest,
+    ) -> RusotoFuture<IndexDocumentsResponse, IndexDocumentsError> {
+        (**self).index_documents(input)
+    }
+    fn list_domain_names(&self) -> RusotoFuture<ListDomainNamesResponse, ListDomainNamesError> {
+        (**self).list_domain_names()
+    }
+    fn update_availability_options(
+        &self,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> RusotoFuture<UpdateAvailabilityOptionsResponse, UpdateAvailabilityOptionsError> {
+        (**self).update_availability_options(input)
+    }
+    fn update_scaling_parameters(
+        &self,
+        input: UpdateScalingParametersRequest,
+    ) -> RusotoFuture<UpdateScalingParametersResponse, UpdateScalingParametersError> {
+        (**self).update_scaling_parameters(input)
+    }
+    fn update_service_access_policies(
+        &self,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> RusotoFuture<UpdateServiceAccessPoliciesResponse, UpdateServiceAccessPoliciesError> {
+        (**self).update_service_access_policies(input)
+    }
+}
+impl<T: CloudSearch + ?Sized> CloudSearch for Box<T> {
+    fn build_suggesters(
+        &self,
+        input: BuildSuggestersRequest,
+    ) -> RusotoFuture<BuildSuggestersResponse, BuildSuggestersError> {
+        (**self).build_suggesters(input)
+    }
+    fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> RusotoFuture<CreateDomainResponse, CreateDomainError> {
+        (**self).create_domain(input)
+    }
+    fn define_analysis_scheme(
+        &self,
+        input: DefineAnalysisSchemeRequest,
+    ) -> RusotoFuture<DefineAnalysisSchemeResponse, DefineAnalysisSchemeError> {
+        (**self).define_analysis_scheme(input)
+    }
+    fn define_expression(
+        &self,
+        input: DefineExpressionRequest,
+    ) -> RusotoFuture<DefineExpressionResponse, DefineExpressionError> {
+        (**self).define_expression(input)
+    }
+    fn define_index_field(
+        &self,
+        input: DefineIndexFieldRequest,
+    ) -> RusotoFuture<DefineIndexFieldResponse, DefineIndexFieldError> {
+        (**self).define_index_field(input)
+    }
+    fn define_suggester(
+        &self,
+        input: DefineSuggesterRequest,
+    ) -> RusotoFuture<DefineSuggesterResponse, DefineSuggesterError> {
+        (**self).define_suggester(input)
+    }
+    fn delete_analysis_scheme(
+        &self,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> RusotoFuture<DeleteAnalysisSchemeResponse, DeleteAnalysisSchemeError> {
+        (**self).delete_analysis_scheme(input)
+    }
+    fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> RusotoFuture<DeleteDomainResponse, DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
+    fn delete_expression(
+        &self,
+        input: DeleteExpressionRequest,
+    ) -> RusotoFuture<DeleteExpressionResponse, DeleteExpressionError> {
+        (**self).delete_expression(input)
+    }
+    fn delete_index_field(
+        &self,
+        input: DeleteIndexFieldRequest,
+    ) -> RusotoFuture<DeleteIndexFieldResponse, DeleteIndexFieldError> {
+        (**self).delete_index_field(input)
+    }
+    fn delete_suggester(
+        &self,
+        input: DeleteSuggesterRequest,
+    ) -> RusotoFuture<DeleteSuggesterResponse, DeleteSuggesterError> {
+        (**self).delete_suggester(input)
+    }
+    fn describe_analysis_schemes(
+        &self,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> RusotoFuture<DescribeAnalysisSchemesResponse, DescribeAnalysisSchemesError> {
+        (**self).describe_analysis_schemes(input)
+    }
+    fn describe_availability_options(
+        &self,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> RusotoFuture<DescribeAvailabilityOptionsResponse, DescribeAvailabilityOptionsError> {
+        (**self).describe_availability_options(input)
+    }
+    fn describe_domains(
+        &self,
+        input: DescribeDomainsRequest,
+    ) -> RusotoFuture<DescribeDomainsResponse, DescribeDomainsError> {
+        (**self).describe_domains(input)
+    }
+    fn describe_expressions(
+        &self,
+        input: DescribeExpressionsRequest,
+    ) -> RusotoFuture<DescribeExpressionsResponse, DescribeExpressionsError> {
+        (**self).describe_expressions(input)
+    }
+    fn describe_index_fields(
+        &self,
+        input: DescribeIndexFieldsRequest,
+    ) -> RusotoFuture<DescribeIndexFieldsResponse, DescribeIndexFieldsError> {
+        (**self).describe_index_fields(input)
+    }
+    fn describe_scaling_parameters(
+        &self,
+        input: DescribeScalingParametersRequest,
+    ) -> RusotoFuture<DescribeScalingParametersResponse, DescribeScalingParametersError> {
+        (**self).describe_scaling_parameters(input)
+    }
+    fn describe_service_access_policies(
+        &self,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> RusotoFuture<DescribeServiceAccessPoliciesResponse, DescribeServiceAccessPoliciesError>
+    {
+        (**self).describe_service_access_policies(input)
+    }
+    fn describe_suggesters(
+        &self,
+        input: DescribeSuggestersRequest,
+    ) -> RusotoFuture<DescribeSuggestersResponse, DescribeSuggestersError> {
+        (**self).describe_suggesters(input)
+    }
+    fn index_documents(
+        &self,
+        input: IndexDocumentsRequest,
+    ) -> RusotoFuture<IndexDocumentsResponse, IndexDocumentsError> {
+        (**self).index_documents(input)
+    }
+    fn list_domain_names(&self) -> RusotoFuture<ListDomainNamesResponse, ListDomainNamesError> {
+        (**self).list_domain_names()
+    }
+    fn update_availability_options(
+        &self,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> RusotoFuture<UpdateAvailabilityOptionsResponse, UpdateAvailabilityOptionsError> {
+        (**self).update_availability_options(input)
+    }
+    fn update_scaling_parameters(
+        &self,
+        input: UpdateScalingParametersRequest,
+    ) -> RusotoFuture<UpdateScalingParametersResponse, UpdateScalingParametersError> {
+        (**self).update_scaling_parameters(input)
+    }
+    fn update_service_access_policies(
+        &self,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> RusotoFuture<UpdateServiceAccessPoliciesResponse, UpdateServiceAccessPoliciesError> {
+        (**self).update_service_access_policies(input)
+    }
+}
+impl<T: CloudSearch + ?Sized> CloudSearch for ::std::sync::Arc<T> {
+    fn build_suggesters(
+        &self,
+        input: BuildSuggestersRequest,
+    ) -> RusotoFuture<BuildSuggestersResponse, BuildSuggestersError> {
+        (**self).build_suggesters(input)
+    }
+    fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> RusotoFuture<CreateDomainResponse, CreateDomainError> {
+        (**self).create_domain(input)
+    }
+    fn define_analysis_scheme(
+        &self,
+        input: DefineAnalysisSchemeRequest,
+    ) -> RusotoFuture<DefineAnalysisSchemeResponse, DefineAnalysisSchemeError> {
+        (**self).define_analysis_scheme(input)
+    }
+    fn define_expression(
+        &self,
+        input: DefineExpressionRequest,
+    ) -> RusotoFuture<DefineExpressionResponse, DefineExpressionError> {
+        (**self).define_expression(input)
+    }
+    fn define_index_field(
+        &self,
+        input: DefineIndexFieldRequest,
+    ) -> RusotoFuture<DefineIndexFieldResponse, DefineIndexFieldError> {
+        (**self).define_index_field(input)
+    }
+    fn define_suggester(
+        &self,
+        input: DefineSuggesterRequest,
+    ) -> RusotoFuture<DefineSuggesterResponse, DefineSuggesterError> {
+        (**self).define_suggester(input)
+    }
+    fn delete_analysis_scheme(
+        &self,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> RusotoFuture<DeleteAnalysisSchemeResponse, DeleteAnalysisSchemeError> {
+        (**self).delete_analysis_scheme(input)
+    }
+    fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> RusotoFuture<DeleteDomainResponse, DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
+    fn delete_expression(
+        &self,
+        input: DeleteExpressionRequest,
+    ) -> RusotoFuture<DeleteExpressionResponse, DeleteExpressionError> {
+        (**self).delete_expression(input)
+    }
+    fn delete_index_field(
+        &self,
+        input: DeleteIndexFieldRequest,
+    ) -> RusotoFuture<DeleteIndexFieldResponse, DeleteIndexFieldError> {
+        (**self).delete_index_field(input)
+    }
+    fn delete_suggester(
+        &self,
+        input: DeleteSuggesterRequest,
+    ) -> RusotoFuture<DeleteSuggesterResponse, DeleteSuggesterError> {
+        (**self).delete_suggester(input)
+    }
+    fn describe_analysis_schemes(
+        &self,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> RusotoFuture<DescribeAnalysisSchemesResponse, DescribeAnalysisSchemesError> {
+        (**self).describe_analysis_schemes(input)
+    }
+    fn describe_availability_options(
+        &self,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> RusotoFuture<DescribeAvailabilityOptionsResponse, DescribeAvailabilityOptionsError> {
+        (**self).describe_availability_options(input)
+    }
+    fn describe_domains(
+        &self,
+        input: DescribeDomainsRequest,
+    ) -> RusotoFuture<DescribeDomainsResponse, DescribeDomainsError> {
+        (**self).describe_domains(input)
+    }
+    fn describe_expressions(
+        &self,
+        input: DescribeExpressionsRequest,
+    ) -> RusotoFuture<DescribeExpressionsResponse, DescribeExpressionsError> {
+        (**self).describe_expressions(input)
+    }
+    fn describe_index_fields(
+        &self,
+        input: DescribeIndexFieldsRequest,
+    ) -> RusotoFuture<DescribeIndexFieldsResponse, DescribeIndexFieldsError> {
+        (**self).describe_index_fields(input)
+    }
+    fn describe_scaling_parameters(
+        &self,
+        input: DescribeScalingParametersRequest,
+    ) -> RusotoFuture<DescribeScalingParametersResponse, DescribeScalingParametersError> {
+        (**self).describe_scaling_parameters(input)
+    }
+    fn describe_service_access_policies(
+        &self,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> RusotoFuture<DescribeServiceAccessPoliciesResponse, DescribeServiceAccessPoliciesError>
+    {
+        (**self).describe_service_access_policies(input)
+    }
+    fn describe_suggesters(
+        &self,
+        input: DescribeSuggestersRequest,
+    ) -> RusotoFuture<DescribeSuggestersResponse, DescribeSuggestersError> {
+        (**self).describe_suggesters(input)
+    }
+    fn index_documents(
+        &self,
+        input: IndexDocumentsRequest,
+    ) -> RusotoFuture<IndexDocumentsResponse, IndexDocumentsError> {
+        (**self).index_documents(input)
+    }
+    fn list_domain_names(&self) -> RusotoFuture<ListDomainNamesResponse, ListDomainNamesError> {
+        (**self).list_domain_names()
+    }
+    fn update_availability_options(
+        &self,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> RusotoFuture<UpdateAvailabilityOptionsResponse, UpdateAvailabilityOptionsError> {
+        (**self).update_availability_options(input)
+    }
+    fn update_scaling_parameters(
+        &self,
+        input: UpdateScalingParametersRequest,
+    ) -> RusotoFuture<UpdateScalingParametersResponse, UpdateScalingParametersError> {
+        (**self).update_scaling_parameters(input)
+    }
+    fn update_service_access_policies(
+        &self,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> RusotoFuture<UpdateServiceAccessPoliciesResponse, UpdateServiceAccessPoliciesError> {
+        (**self).update_service_access_policies(input)
+    }
+}
+/// A client for the Amazon CloudSearch API.
+#[derive(Clone)]
+pub struct CloudSearchClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CloudSearchClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudSearchClient {
+        Self::new_with_client(Client::shared(), region)
     }
 
     pub fn new_with<P, D>(
@@ -5627,7 +6984,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: BuildSuggestersRequest,
     ) -> RusotoFuture<BuildSuggestersResponse, BuildSuggestersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("BuildSuggesters");
         let mut params = Params::new();
 
         params.put("Action", "BuildSuggesters");
@@ -5652,6 +7018,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = BuildSuggestersResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5663,7 +7030,8 @@ impl CloudSearch for CloudSearchClient {
                     result = BuildSuggestersResponseDeserializer::deserialize(
                         "BuildSuggestersResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("BuildSuggesters", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5678,7 +7046,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: CreateDomainRequest,
     ) -> RusotoFuture<CreateDomainResponse, CreateDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("CreateDomain");
         let mut params = Params::new();
 
         params.put("Action", "CreateDomain");
@@ -5703,6 +7080,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = CreateDomainResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5714,7 +7092,8 @@ impl CloudSearch for CloudSearchClient {
                     result = CreateDomainResponseDeserializer::deserialize(
                         "CreateDomainResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("CreateDomain", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5729,7 +7108,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DefineAnalysisSchemeRequest,
     ) -> RusotoFuture<DefineAnalysisSchemeResponse, DefineAnalysisSchemeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineAnalysisScheme");
         let mut params = Params::new();
 
         params.put("Action", "DefineAnalysisScheme");
@@ -5753,6 +7141,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DefineAnalysisSchemeResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5764,7 +7153,10 @@ impl CloudSearch for CloudSearchClient {
                     result = DefineAnalysisSchemeResponseDeserializer::deserialize(
                         "DefineAnalysisSchemeResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DefineAnalysisScheme", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5779,7 +7171,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DefineExpressionRequest,
     ) -> RusotoFuture<DefineExpressionResponse, DefineExpressionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineExpression");
         let mut params = Params::new();
 
         params.put("Action", "DefineExpression");
@@ -5804,6 +7205,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DefineExpressionResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5815,7 +7217,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DefineExpressionResponseDeserializer::deserialize(
                         "DefineExpressionResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DefineExpression", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5830,7 +7233,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DefineIndexFieldRequest,
     ) -> RusotoFuture<DefineIndexFieldResponse, DefineIndexFieldError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineIndexField");
         let mut params = Params::new();
 
         params.put("Action", "DefineIndexField");
@@ -5855,6 +7267,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DefineIndexFieldResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5866,7 +7279,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DefineIndexFieldResponseDeserializer::deserialize(
                         "DefineIndexFieldResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DefineIndexField", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5881,7 +7295,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DefineSuggesterRequest,
     ) -> RusotoFuture<DefineSuggesterResponse, DefineSuggesterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineSuggester");
         let mut params = Params::new();
 
         params.put("Action", "DefineSuggester");
@@ -5906,6 +7329,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DefineSuggesterResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5917,7 +7341,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DefineSuggesterResponseDeserializer::deserialize(
                         "DefineSuggesterResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DefineSuggester", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5932,7 +7357,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DeleteAnalysisSchemeRequest,
     ) -> RusotoFuture<DeleteAnalysisSchemeResponse, DeleteAnalysisSchemeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteAnalysisScheme");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAnalysisScheme");
@@ -5956,6 +7390,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DeleteAnalysisSchemeResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5967,7 +7402,10 @@ impl CloudSearch for CloudSearchClient {
                     result = DeleteAnalysisSchemeResponseDeserializer::deserialize(
                         "DeleteAnalysisSchemeResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteAnalysisScheme", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5982,7 +7420,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DeleteDomainRequest,
     ) -> RusotoFuture<DeleteDomainResponse, DeleteDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteDomain");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDomain");
@@ -6007,6 +7454,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DeleteDomainResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6018,7 +7466,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DeleteDomainResponseDeserializer::deserialize(
                         "DeleteDomainResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DeleteDomain", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6033,7 +7482,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DeleteExpressionRequest,
     ) -> RusotoFuture<DeleteExpressionResponse, DeleteExpressionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteExpression");
         let mut params = Params::new();
 
         params.put("Action", "DeleteExpression");
@@ -6058,6 +7516,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DeleteExpressionResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6069,7 +7528,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DeleteExpressionResponseDeserializer::deserialize(
                         "DeleteExpressionResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DeleteExpression", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6084,7 +7544,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DeleteIndexFieldRequest,
     ) -> RusotoFuture<DeleteIndexFieldResponse, DeleteIndexFieldError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteIndexField");
         let mut params = Params::new();
 
         params.put("Action", "DeleteIndexField");
@@ -6109,6 +7578,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DeleteIndexFieldResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6120,7 +7590,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DeleteIndexFieldResponseDeserializer::deserialize(
                         "DeleteIndexFieldResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DeleteIndexField", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6135,7 +7606,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DeleteSuggesterRequest,
     ) -> RusotoFuture<DeleteSuggesterResponse, DeleteSuggesterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteSuggester");
         let mut params = Params::new();
 
         params.put("Action", "DeleteSuggester");
@@ -6160,6 +7640,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DeleteSuggesterResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6171,7 +7652,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DeleteSuggesterResponseDeserializer::deserialize(
                         "DeleteSuggesterResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DeleteSuggester", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6186,7 +7668,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DescribeAnalysisSchemesRequest,
     ) -> RusotoFuture<DescribeAnalysisSchemesResponse, DescribeAnalysisSchemesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeAnalysisSchemes");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAnalysisSchemes");
@@ -6208,6 +7699,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DescribeAnalysisSchemesResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6219,7 +7711,10 @@ impl CloudSearch for CloudSearchClient {
                     result = DescribeAnalysisSchemesResponseDeserializer::deserialize(
                         "DescribeAnalysisSchemesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAnalysisSchemes", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6234,7 +7729,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DescribeAvailabilityOptionsRequest,
     ) -> RusotoFuture<DescribeAvailabilityOptionsResponse, DescribeAvailabilityOptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeAvailabilityOptions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAvailabilityOptions");
@@ -6256,6 +7760,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DescribeAvailabilityOptionsResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6267,7 +7772,10 @@ impl CloudSearch for CloudSearchClient {
                     result = DescribeAvailabilityOptionsResponseDeserializer::deserialize(
                         "DescribeAvailabilityOptionsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAvailabilityOptions", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6282,7 +7790,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DescribeDomainsRequest,
     ) -> RusotoFuture<DescribeDomainsResponse, DescribeDomainsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeDomains");
         let mut params = Params::new();
 
         params.put("Action", "DescribeDomains");
@@ -6307,6 +7824,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DescribeDomainsResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6318,7 +7836,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DescribeDomainsResponseDeserializer::deserialize(
                         "DescribeDomainsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DescribeDomains", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6333,7 +7852,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DescribeExpressionsRequest,
     ) -> RusotoFuture<DescribeExpressionsResponse, DescribeExpressionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeExpressions");
         let mut params = Params::new();
 
         params.put("Action", "DescribeExpressions");
@@ -6357,6 +7885,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DescribeExpressionsResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6368,7 +7897,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DescribeExpressionsResponseDeserializer::deserialize(
                         "DescribeExpressionsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DescribeExpressions", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6383,7 +7913,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DescribeIndexFieldsRequest,
     ) -> RusotoFuture<DescribeIndexFieldsResponse, DescribeIndexFieldsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeIndexFields");
         let mut params = Params::new();
 
         params.put("Action", "DescribeIndexFields");
@@ -6407,6 +7946,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DescribeIndexFieldsResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6418,7 +7958,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DescribeIndexFieldsResponseDeserializer::deserialize(
                         "DescribeIndexFieldsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DescribeIndexFields", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6433,7 +7974,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DescribeScalingParametersRequest,
     ) -> RusotoFuture<DescribeScalingParametersResponse, DescribeScalingParametersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeScalingParameters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeScalingParameters");
@@ -6455,6 +8005,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DescribeScalingParametersResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6466,7 +8017,10 @@ impl CloudSearch for CloudSearchClient {
                     result = DescribeScalingParametersResponseDeserializer::deserialize(
                         "DescribeScalingParametersResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeScalingParameters", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6482,7 +8036,16 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeServiceAccessPoliciesRequest,
     ) -> RusotoFuture<DescribeServiceAccessPoliciesResponse, DescribeServiceAccessPoliciesError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeServiceAccessPolicies");
         let mut params = Params::new();
 
         params.put("Action", "DescribeServiceAccessPolicies");
@@ -6504,6 +8067,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DescribeServiceAccessPoliciesResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6515,7 +8079,10 @@ impl CloudSearch for CloudSearchClient {
                     result = DescribeServiceAccessPoliciesResponseDeserializer::deserialize(
                         "DescribeServiceAccessPoliciesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeServiceAccessPolicies", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6530,7 +8097,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: DescribeSuggestersRequest,
     ) -> RusotoFuture<DescribeSuggestersResponse, DescribeSuggestersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeSuggesters");
         let mut params = Params::new();
 
         params.put("Action", "DescribeSuggesters");
@@ -6555,6 +8131,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = DescribeSuggestersResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6566,7 +8143,8 @@ impl CloudSearch for CloudSearchClient {
                     result = DescribeSuggestersResponseDeserializer::deserialize(
                         "DescribeSuggestersResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DescribeSuggesters", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6581,7 +8159,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: IndexDocumentsRequest,
     ) -> RusotoFuture<IndexDocumentsResponse, IndexDocumentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("IndexDocuments");
         let mut params = Params::new();
 
         params.put("Action", "IndexDocuments");
@@ -6606,6 +8193,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = IndexDocumentsResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6617,7 +8205,8 @@ impl CloudSearch for CloudSearchClient {
                     result = IndexDocumentsResponseDeserializer::deserialize(
                         "IndexDocumentsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("IndexDocuments", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6630,6 +8219,7 @@ impl CloudSearch for CloudSearchClient {
     /// <p>Lists all search domains owned by an account.</p>
     fn list_domain_names(&self) -> RusotoFuture<ListDomainNamesResponse, ListDomainNamesError> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("ListDomainNames");
         let mut params = Params::new();
 
         params.put("Action", "ListDomainNames");
@@ -6654,6 +8244,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = ListDomainNamesResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6665,7 +8256,8 @@ impl CloudSearch for CloudSearchClient {
                     result = ListDomainNamesResponseDeserializer::deserialize(
                         "ListDomainNamesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("ListDomainNames", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6680,7 +8272,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: UpdateAvailabilityOptionsRequest,
     ) -> RusotoFuture<UpdateAvailabilityOptionsResponse, UpdateAvailabilityOptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateAvailabilityOptions");
         let mut params = Params::new();
 
         params.put("Action", "UpdateAvailabilityOptions");
@@ -6702,6 +8303,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = UpdateAvailabilityOptionsResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6713,7 +8315,10 @@ impl CloudSearch for CloudSearchClient {
                     result = UpdateAvailabilityOptionsResponseDeserializer::deserialize(
                         "UpdateAvailabilityOptionsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateAvailabilityOptions", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6728,7 +8333,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: UpdateScalingParametersRequest,
     ) -> RusotoFuture<UpdateScalingParametersResponse, UpdateScalingParametersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateScalingParameters");
         let mut params = Params::new();
 
         params.put("Action", "UpdateScalingParameters");
@@ -6750,6 +8364,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = UpdateScalingParametersResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6761,7 +8376,10 @@ impl CloudSearch for CloudSearchClient {
                     result = UpdateScalingParametersResponseDeserializer::deserialize(
                         "UpdateScalingParametersResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateScalingParameters", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6776,7 +8394,16 @@ impl CloudSearch for CloudSearchClient {
         &self,
         input: UpdateServiceAccessPoliciesRequest,
     ) -> RusotoFuture<UpdateServiceAccessPoliciesResponse, UpdateServiceAccessPoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateServiceAccessPolicies");
         let mut params = Params::new();
 
         params.put("Action", "UpdateServiceAccessPolicies");
@@ -6798,6 +8425,7 @@ impl CloudSearch for CloudSearchClient {
                 if response.body.is_empty() {
                     result = UpdateServiceAccessPoliciesResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6809,7 +8437,10 @@ impl CloudSearch for CloudSearchClient {
                     result = UpdateServiceAccessPoliciesResponseDeserializer::deserialize(
                         "UpdateServiceAccessPoliciesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateServiceAccessPolicies", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6819,3 +8450,849 @@ impl CloudSearch for CloudSearchClient {
         })
     }
 }
+
+/// A mock of the Amazon CloudSearch API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloudSearch {
+    build_suggesters: Option<
+        Box<
+            dyn Fn(
+                    BuildSuggestersRequest,
+                ) -> ::std::result::Result<
+                    BuildSuggestersResponse,
+                    RusotoError<BuildSuggestersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_domain: Option<
+        Box<
+            dyn Fn(
+                    CreateDomainRequest,
+                )
+                    -> ::std::result::Result<CreateDomainResponse, RusotoError<CreateDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    define_analysis_scheme: Option<
+        Box<
+            dyn Fn(
+                    DefineAnalysisSchemeRequest,
+                ) -> ::std::result::Result<
+                    DefineAnalysisSchemeResponse,
+                    RusotoError<DefineAnalysisSchemeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    define_expression: Option<
+        Box<
+            dyn Fn(
+                    DefineExpressionRequest,
+                ) -> ::std::result::Result<
+                    DefineExpressionResponse,
+                    RusotoError<DefineExpressionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    define_index_field: Option<
+        Box<
+            dyn Fn(
+                    DefineIndexFieldRequest,
+                ) -> ::std::result::Result<
+                    DefineIndexFieldResponse,
+                    RusotoError<DefineIndexFieldError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    define_suggester: Option<
+        Box<
+            dyn Fn(
+                    DefineSuggesterRequest,
+                ) -> ::std::result::Result<
+                    DefineSuggesterResponse,
+                    RusotoError<DefineSuggesterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_analysis_scheme: Option<
+        Box<
+            dyn Fn(
+                    DeleteAnalysisSchemeRequest,
+                ) -> ::std::result::Result<
+                    DeleteAnalysisSchemeResponse,
+                    RusotoError<DeleteAnalysisSchemeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_domain: Option<
+        Box<
+            dyn Fn(
+                    DeleteDomainRequest,
+                )
+                    -> ::std::result::Result<DeleteDomainResponse, RusotoError<DeleteDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_expression: Option<
+        Box<
+            dyn Fn(
+                    DeleteExpressionRequest,
+                ) -> ::std::result::Result<
+                    DeleteExpressionResponse,
+                    RusotoError<DeleteExpressionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_index_field: Option<
+        Box<
+            dyn Fn(
+                    DeleteIndexFieldRequest,
+                ) -> ::std::result::Result<
+                    DeleteIndexFieldResponse,
+                    RusotoError<DeleteIndexFieldError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_suggester: Option<
+        Box<
+            dyn Fn(
+                    DeleteSuggesterRequest,
+                ) -> ::std::result::Result<
+                    DeleteSuggesterResponse,
+                    RusotoError<DeleteSuggesterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_analysis_schemes: Option<
+        Box<
+            dyn Fn(
+                    DescribeAnalysisSchemesRequest,
+                ) -> ::std::result::Result<
+                    DescribeAnalysisSchemesResponse,
+                    RusotoError<DescribeAnalysisSchemesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_availability_options: Option<
+        Box<
+            dyn Fn(
+                    DescribeAvailabilityOptionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAvailabilityOptionsResponse,
+                    RusotoError<DescribeAvailabilityOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_domains: Option<
+        Box<
+            dyn Fn(
+                    DescribeDomainsRequest,
+                ) -> ::std::result::Result<
+                    DescribeDomainsResponse,
+                    RusotoError<DescribeDomainsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_expressions: Option<
+        Box<
+            dyn Fn(
+                    DescribeExpressionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeExpressionsResponse,
+                    RusotoError<DescribeExpressionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_index_fields: Option<
+        Box<
+            dyn Fn(
+                    DescribeIndexFieldsRequest,
+                ) -> ::std::result::Result<
+                    DescribeIndexFieldsResponse,
+                    RusotoError<DescribeIndexFieldsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_scaling_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeScalingParametersRequest,
+                ) -> ::std::result::Result<
+                    DescribeScalingParametersResponse,
+                    RusotoError<DescribeScalingParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_service_access_policies: Option<
+        Box<
+            dyn Fn(
+                    DescribeServiceAccessPoliciesRequest,
+                ) -> ::std::result::Result<
+                    DescribeServiceAccessPoliciesResponse,
+                    RusotoError<DescribeServiceAccessPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_suggesters: Option<
+        Box<
+            dyn Fn(
+                    DescribeSuggestersRequest,
+                ) -> ::std::result::Result<
+                    DescribeSuggestersResponse,
+                    RusotoError<DescribeSuggestersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    index_documents: Option<
+        Box<
+            dyn Fn(
+                    IndexDocumentsRequest,
+                ) -> ::std::result::Result<
+                    IndexDocumentsResponse,
+                    RusotoError<IndexDocumentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_domain_names: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    ListDomainNamesResponse,
+                    RusotoError<ListDomainNamesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_availability_options: Option<
+        Box<
+            dyn Fn(
+                    UpdateAvailabilityOptionsRequest,
+                ) -> ::std::result::Result<
+                    UpdateAvailabilityOptionsResponse,
+                    RusotoError<UpdateAvailabilityOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_scaling_parameters: Option<
+        Box<
+            dyn Fn(
+                    UpdateScalingParametersRequest,
+                ) -> ::std::result::Result<
+                    UpdateScalingParametersResponse,
+                    RusotoError<UpdateScalingParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_service_access_policies: Option<
+        Box<
+            dyn Fn(
+                    UpdateServiceAccessPoliciesRequest,
+                ) -> ::std::result::Result<
+                    UpdateServiceAccessPoliciesResponse,
+                    RusotoError<UpdateServiceAccessPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloudSearch {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloudSearch {
+        Default::default()
+    }
+
+    /// Answers `build_suggesters` with what `handler` returns for its input.
+    pub fn with_build_suggesters<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                BuildSuggestersRequest,
+            )
+                -> ::std::result::Result<BuildSuggestersResponse, RusotoError<BuildSuggestersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.build_suggesters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_domain` with what `handler` returns for its input.
+    pub fn with_create_domain<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                CreateDomainRequest,
+            )
+                -> ::std::result::Result<CreateDomainResponse, RusotoError<CreateDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `define_analysis_scheme` with what `handler` returns for its input.
+    pub fn with_define_analysis_scheme<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DefineAnalysisSchemeRequest,
+            ) -> ::std::result::Result<
+                DefineAnalysisSchemeResponse,
+                RusotoError<DefineAnalysisSchemeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.define_analysis_scheme = Some(Box::new(handler));
+        self
+    }
+    /// Answers `define_expression` with what `handler` returns for its input.
+    pub fn with_define_expression<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DefineExpressionRequest,
+            ) -> ::std::result::Result<
+                DefineExpressionResponse,
+                RusotoError<DefineExpressionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.define_expression = Some(Box::new(handler));
+        self
+    }
+    /// Answers `define_index_field` with what `handler` returns for its input.
+    pub fn with_define_index_field<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DefineIndexFieldRequest,
+            ) -> ::std::result::Result<
+                DefineIndexFieldResponse,
+                RusotoError<DefineIndexFieldError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.define_index_field = Some(Box::new(handler));
+        self
+    }
+    /// Answers `define_suggester` with what `handler` returns for its input.
+    pub fn with_define_suggester<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DefineSuggesterRequest,
+            )
+                -> ::std::result::Result<DefineSuggesterResponse, RusotoError<DefineSuggesterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.define_suggester = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_analysis_scheme` with what `handler` returns for its input.
+    pub fn with_delete_analysis_scheme<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DeleteAnalysisSchemeRequest,
+            ) -> ::std::result::Result<
+                DeleteAnalysisSchemeResponse,
+                RusotoError<DeleteAnalysisSchemeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_analysis_scheme = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_domain` with what `handler` returns for its input.
+    pub fn with_delete_domain<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DeleteDomainRequest,
+            )
+                -> ::std::result::Result<DeleteDomainResponse, RusotoError<DeleteDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_expression` with what `handler` returns for its input.
+    pub fn with_delete_expression<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DeleteExpressionRequest,
+            ) -> ::std::result::Result<
+                DeleteExpressionResponse,
+                RusotoError<DeleteExpressionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_expression = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_index_field` with what `handler` returns for its input.
+    pub fn with_delete_index_field<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DeleteIndexFieldRequest,
+            ) -> ::std::result::Result<
+                DeleteIndexFieldResponse,
+                RusotoError<DeleteIndexFieldError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_index_field = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_suggester` with what `handler` returns for its input.
+    pub fn with_delete_suggester<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DeleteSuggesterRequest,
+            )
+                -> ::std::result::Result<DeleteSuggesterResponse, RusotoError<DeleteSuggesterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_suggester = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_analysis_schemes` with what `handler` returns for its input.
+    pub fn with_describe_analysis_schemes<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DescribeAnalysisSchemesRequest,
+            ) -> ::std::result::Result<
+                DescribeAnalysisSchemesResponse,
+                RusotoError<DescribeAnalysisSchemesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_analysis_schemes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_availability_options` with what `handler` returns for its input.
+    pub fn with_describe_availability_options<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DescribeAvailabilityOptionsRequest,
+            ) -> ::std::result::Result<
+                DescribeAvailabilityOptionsResponse,
+                RusotoError<DescribeAvailabilityOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_availability_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_domains` with what `handler` returns for its input.
+    pub fn with_describe_domains<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DescribeDomainsRequest,
+            )
+                -> ::std::result::Result<DescribeDomainsResponse, RusotoError<DescribeDomainsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_domains = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_expressions` with what `handler` returns for its input.
+    pub fn with_describe_expressions<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DescribeExpressionsRequest,
+            ) -> ::std::result::Result<
+                DescribeExpressionsResponse,
+                RusotoError<DescribeExpressionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_expressions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_index_fields` with what `handler` returns for its input.
+    pub fn with_describe_index_fields<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DescribeIndexFieldsRequest,
+            ) -> ::std::result::Result<
+                DescribeIndexFieldsResponse,
+                RusotoError<DescribeIndexFieldsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_index_fields = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scaling_parameters` with what `handler` returns for its input.
+    pub fn with_describe_scaling_parameters<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DescribeScalingParametersRequest,
+            ) -> ::std::result::Result<
+                DescribeScalingParametersResponse,
+                RusotoError<DescribeScalingParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scaling_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_service_access_policies` with what `handler` returns for its input.
+    pub fn with_describe_service_access_policies<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DescribeServiceAccessPoliciesRequest,
+            ) -> ::std::result::Result<
+                DescribeServiceAccessPoliciesResponse,
+                RusotoError<DescribeServiceAccessPoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_service_access_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_suggesters` with what `handler` returns for its input.
+    pub fn with_describe_suggesters<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                DescribeSuggestersRequest,
+            ) -> ::std::result::Result<
+                DescribeSuggestersResponse,
+                RusotoError<DescribeSuggestersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_suggesters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `index_documents` with what `handler` returns for its input.
+    pub fn with_index_documents<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                IndexDocumentsRequest,
+            )
+                -> ::std::result::Result<IndexDocumentsResponse, RusotoError<IndexDocumentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.index_documents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_domain_names` with what `handler` returns.
+    pub fn with_list_domain_names<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn()
+                -> ::std::result::Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_domain_names = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_availability_options` with what `handler` returns for its input.
+    pub fn with_update_availability_options<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                UpdateAvailabilityOptionsRequest,
+            ) -> ::std::result::Result<
+                UpdateAvailabilityOptionsResponse,
+                RusotoError<UpdateAvailabilityOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_availability_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_scaling_parameters` with what `handler` returns for its input.
+    pub fn with_update_scaling_parameters<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                UpdateScalingParametersRequest,
+            ) -> ::std::result::Result<
+                UpdateScalingParametersResponse,
+                RusotoError<UpdateScalingParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_scaling_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_service_access_policies` with what `handler` returns for its input.
+    pub fn with_update_service_access_policies<F>(mut self, handler: F) -> MockCloudSearch
+    where
+        F: Fn(
+                UpdateServiceAccessPoliciesRequest,
+            ) -> ::std::result::Result<
+                UpdateServiceAccessPoliciesResponse,
+                RusotoError<UpdateServiceAccessPoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_service_access_policies = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CloudSearch for MockCloudSearch {
+    fn build_suggesters(
+        &self,
+        input: BuildSuggestersRequest,
+    ) -> RusotoFuture<BuildSuggestersResponse, BuildSuggestersError> {
+        match self.build_suggesters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for build_suggesters"),
+        }
+    }
+    fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> RusotoFuture<CreateDomainResponse, CreateDomainError> {
+        match self.create_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for create_domain"),
+        }
+    }
+    fn define_analysis_scheme(
+        &self,
+        input: DefineAnalysisSchemeRequest,
+    ) -> RusotoFuture<DefineAnalysisSchemeResponse, DefineAnalysisSchemeError> {
+        match self.define_analysis_scheme {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for define_analysis_scheme"),
+        }
+    }
+    fn define_expression(
+        &self,
+        input: DefineExpressionRequest,
+    ) -> RusotoFuture<DefineExpressionResponse, DefineExpressionError> {
+        match self.define_expression {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for define_expression"),
+        }
+    }
+    fn define_index_field(
+        &self,
+        input: DefineIndexFieldRequest,
+    ) -> RusotoFuture<DefineIndexFieldResponse, DefineIndexFieldError> {
+        match self.define_index_field {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for define_index_field"),
+        }
+    }
+    fn define_suggester(
+        &self,
+        input: DefineSuggesterRequest,
+    ) -> RusotoFuture<DefineSuggesterResponse, DefineSuggesterError> {
+        match self.define_suggester {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for define_suggester"),
+        }
+    }
+    fn delete_analysis_scheme(
+        &self,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> RusotoFuture<DeleteAnalysisSchemeResponse, DeleteAnalysisSchemeError> {
+        match self.delete_analysis_scheme {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for delete_analysis_scheme"),
+        }
+    }
+    fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> RusotoFuture<DeleteDomainResponse, DeleteDomainError> {
+        match self.delete_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for delete_domain"),
+        }
+    }
+    fn delete_expression(
+        &self,
+        input: DeleteExpressionRequest,
+    ) -> RusotoFuture<DeleteExpressionResponse, DeleteExpressionError> {
+        match self.delete_expression {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for delete_expression"),
+        }
+    }
+    fn delete_index_field(
+        &self,
+        input: DeleteIndexFieldRequest,
+    ) -> RusotoFuture<DeleteIndexFieldResponse, DeleteIndexFieldError> {
+        match self.delete_index_field {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for delete_index_field"),
+        }
+    }
+    fn delete_suggester(
+        &self,
+        input: DeleteSuggesterRequest,
+    ) -> RusotoFuture<DeleteSuggesterResponse, DeleteSuggesterError> {
+        match self.delete_suggester {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for delete_suggester"),
+        }
+    }
+    fn describe_analysis_schemes(
+        &self,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> RusotoFuture<DescribeAnalysisSchemesResponse, DescribeAnalysisSchemesError> {
+        match self.describe_analysis_schemes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for describe_analysis_schemes"),
+        }
+    }
+    fn describe_availability_options(
+        &self,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> RusotoFuture<DescribeAvailabilityOptionsResponse, DescribeAvailabilityOptionsError> {
+        match self.describe_availability_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for describe_availability_options"),
+        }
+    }
+    fn describe_domains(
+        &self,
+        input: DescribeDomainsRequest,
+    ) -> RusotoFuture<DescribeDomainsResponse, DescribeDomainsError> {
+        match self.describe_domains {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for describe_domains"),
+        }
+    }
+    fn describe_expressions(
+        &self,
+        input: DescribeExpressionsRequest,
+    ) -> RusotoFuture<DescribeExpressionsResponse, DescribeExpressionsError> {
+        match self.describe_expressions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for describe_expressions"),
+        }
+    }
+    fn describe_index_fields(
+        &self,
+        input: DescribeIndexFieldsRequest,
+    ) -> RusotoFuture<DescribeIndexFieldsResponse, DescribeIndexFieldsError> {
+        match self.describe_index_fields {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for describe_index_fields"),
+        }
+    }
+    fn describe_scaling_parameters(
+        &self,
+        input: DescribeScalingParametersRequest,
+    ) -> RusotoFuture<DescribeScalingParametersResponse, DescribeScalingParametersError> {
+        match self.describe_scaling_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for describe_scaling_parameters"),
+        }
+    }
+    fn describe_service_access_policies(
+        &self,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> RusotoFuture<DescribeServiceAccessPoliciesResponse, DescribeServiceAccessPoliciesError>
+    {
+        match self.describe_service_access_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for describe_service_access_policies"),
+        }
+    }
+    fn describe_suggesters(
+        &self,
+        input: DescribeSuggestersRequest,
+    ) -> RusotoFuture<DescribeSuggestersResponse, DescribeSuggestersError> {
+        match self.describe_suggesters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for describe_suggesters"),
+        }
+    }
+    fn index_documents(
+        &self,
+        input: IndexDocumentsRequest,
+    ) -> RusotoFuture<IndexDocumentsResponse, IndexDocumentsError> {
+        match self.index_documents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for index_documents"),
+        }
+    }
+    fn list_domain_names(&self) -> RusotoFuture<ListDomainNamesResponse, ListDomainNamesError> {
+        match self.list_domain_names {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockCloudSearch has no handler for list_domain_names"),
+        }
+    }
+    fn update_availability_options(
+        &self,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> RusotoFuture<UpdateAvailabilityOptionsResponse, UpdateAvailabilityOptionsError> {
+        match self.update_availability_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for update_availability_options"),
+        }
+    }
+    fn update_scaling_parameters(
+        &self,
+        input: UpdateScalingParametersRequest,
+    ) -> RusotoFuture<UpdateScalingParametersResponse, UpdateScalingParametersError> {
+        match self.update_scaling_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for update_scaling_parameters"),
+        }
+    }
+    fn update_service_access_policies(
+        &self,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> RusotoFuture<UpdateServiceAccessPoliciesResponse, UpdateServiceAccessPoliciesError> {
+        match self.update_service_access_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearch has no handler for update_service_access_policies"),
+        }
+    }
+}
@@ -25,7 +25,7 @@ use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
 /// <p>A container for facet information. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Bucket {
     /// <p>The number of hits that contain the facet value in the specified facet field.</p>
     #[serde(rename = "count")]
@@ -39,7 +39,7 @@ pub struct Bucket {
 
 /// <p>A container for the calculated facet values and counts.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BucketInfo {
     /// <p>A list of the calculated facet values and counts.</p>
     #[serde(rename = "buckets")]
@@ -49,7 +49,7 @@ pub struct BucketInfo {
 
 /// <p>A warning returned by the document service when an issue is discovered while processing an upload request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentServiceWarning {
     /// <p>The description for a warning returned by the document service.</p>
     #[serde(rename = "message")]
@@ -59,7 +59,7 @@ pub struct DocumentServiceWarning {
 
 /// <p>The statistics for a field calculated in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FieldStats {
     /// <p>The number of documents that contain a value in the specified field in the result set.</p>
     #[serde(rename = "count")]
@@ -97,7 +97,7 @@ pub struct FieldStats {
 
 /// <p>Information about a document that matches the search request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Hit {
     /// <p>The expressions returned from a document that matches the search request.</p>
     #[serde(rename = "exprs")]
@@ -119,7 +119,7 @@ pub struct Hit {
 
 /// <p>The collection of documents that match the search request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Hits {
     /// <p>A cursor that can be used to retrieve the next set of matching documents when you want to page through a large result set.</p>
     #[serde(rename = "cursor")]
@@ -141,6 +141,7 @@ pub struct Hits {
 
 /// <p>Container for the parameters to the <code>Search</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchRequest {
     /// <p>Retrieves a cursor value you can use to page through large result sets. Use the <code>size</code> parameter to control the number of hits to include in each response. You can specify either the <code>cursor</code> or <code>start</code> parameter in a request; they are mutually exclusive. To get the first cursor, set the cursor value to <code>initial</code>. In subsequent requests, specify the cursor value returned in the hits section of the response. </p> <p>For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/paginating-results.html">Paginating Results</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
     #[serde(rename = "cursor")]
@@ -201,7 +202,7 @@ pub struct SearchRequest {
 
 /// <p>The result of a <code>Search</code> request. Contains the documents that match the specified search criteria and any requested fields, highlights, and facet information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchResponse {
     /// <p>The requested facet information.</p>
     #[serde(rename = "facets")]
@@ -223,7 +224,7 @@ pub struct SearchResponse {
 
 /// <p>Contains the resource id (<code>rid</code>) and the time it took to process the request (<code>timems</code>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchStatus {
     /// <p>The encrypted resource ID for the request.</p>
     #[serde(rename = "rid")]
@@ -237,7 +238,7 @@ pub struct SearchStatus {
 
 /// <p>Container for the suggestion information returned in a <code>SuggestResponse</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SuggestModel {
     /// <p>The number of documents that were found to match the query string.</p>
     #[serde(rename = "found")]
@@ -255,6 +256,7 @@ pub struct SuggestModel {
 
 /// <p>Container for the parameters to the <code>Suggest</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SuggestRequest {
     /// <p>Specifies the string for which you want to get suggestions.</p>
     #[serde(rename = "query")]
@@ -270,7 +272,7 @@ pub struct SuggestRequest {
 
 /// <p>Contains the response to a <code>Suggest</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SuggestResponse {
     /// <p>The status of a <code>SuggestRequest</code>. Contains the resource ID (<code>rid</code>) and how long it took to process the request (<code>timems</code>).</p>
     #[serde(rename = "status")]
@@ -284,7 +286,7 @@ pub struct SuggestResponse {
 
 /// <p>Contains the resource id (<code>rid</code>) and the time it took to process the request (<code>timems</code>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SuggestStatus {
     /// <p>The encrypted resource ID for the request.</p>
     #[serde(rename = "rid")]
@@ -298,7 +300,7 @@ pub struct SuggestStatus {
 
 /// <p>An autocomplete suggestion that matches the query string specified in a <code>SuggestRequest</code>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SuggestionMatch {
     /// <p>The document ID of the suggested document.</p>
     #[serde(rename = "id")]
@@ -316,6 +318,7 @@ pub struct SuggestionMatch {
 
 /// <p>Container for the parameters to the <code>UploadDocuments</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UploadDocumentsRequest {
     /// <p><p>The format of the batch you are uploading. Amazon CloudSearch supports two document batch formats:</p> <ul> <li>application/json</li> <li>application/xml</li> </ul></p>
     #[serde(rename = "contentType")]
@@ -332,7 +335,7 @@ pub struct UploadDocumentsRequest {
 
 /// <p>Contains the response to an <code>UploadDocuments</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UploadDocumentsResponse {
     /// <p>The number of documents that were added to the search domain.</p>
     #[serde(rename = "adds")]
@@ -364,7 +367,12 @@ impl SearchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "SearchException" => return RusotoError::Service(SearchError::Search(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -395,7 +403,12 @@ impl SuggestError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "SearchException" => return RusotoError::Service(SuggestError::Search(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -428,7 +441,12 @@ impl UploadDocumentsError {
                 "DocumentServiceException" => {
                     return RusotoError::Service(UploadDocumentsError::DocumentService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -461,6 +479,49 @@ pub trait CloudSearchDomain {
         input: UploadDocumentsRequest,
     ) -> RusotoFuture<UploadDocumentsResponse, UploadDocumentsError>;
 }
+const _: Option<&dyn CloudSearchDomain> = None;
+impl<'a, T: CloudSearchDomain + ?Sized> CloudSearchDomain for &'a T {
+    fn search(&self, input: SearchRequest) -> RusotoFuture<SearchResponse, SearchError> {
+        (**self).search(input)
+    }
+    fn suggest(&self, input: SuggestRequest) -> RusotoFuture<SuggestResponse, SuggestError> {
+        (**self).suggest(input)
+    }
+    fn upload_documents(
+        &self,
+        input: UploadDocumentsRequest,
+    ) -> RusotoFuture<UploadDocumentsResponse, UploadDocumentsError> {
+        (**self).upload_documents(input)
+    }
+}
+impl<T: CloudSearchDomain + ?Sized> CloudSearchDomain for Box<T> {
+    fn search(&self, input: SearchRequest) -> RusotoFuture<SearchResponse, SearchError> {
+        (**self).search(input)
+    }
+    fn suggest(&self, input: SuggestRequest) -> RusotoFuture<SuggestResponse, SuggestError> {
+        (**self).suggest(input)
+    }
+    fn upload_documents(
+        &self,
+        input: UploadDocumentsRequest,
+    ) -> RusotoFuture<UploadDocumentsResponse, UploadDocumentsError> {
+        (**self).upload_documents(input)
+    }
+}
+impl<T: CloudSearchDomain + ?Sized> CloudSearchDomain for ::std::sync::Arc<T> {
+    fn search(&self, input: SearchRequest) -> RusotoFuture<SearchResponse, SearchError> {
+        (**self).search(input)
+    }
+    fn suggest(&self, input: SuggestRequest) -> RusotoFuture<SuggestResponse, SuggestError> {
+        (**self).suggest(input)
+    }
+    fn upload_documents(
+        &self,
+        input: UploadDocumentsRequest,
+    ) -> RusotoFuture<UploadDocumentsResponse, UploadDocumentsError> {
+        (**self).upload_documents(input)
+    }
+}
 /// A client for the Amazon CloudSearch Domain API.
 #[derive(Clone)]
 pub struct CloudSearchDomainClient {
@@ -504,6 +565,8 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         let request_uri = "/2013-01-01/search";
 
         let mut request = SignedRequest::new("GET", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("Search");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -557,6 +620,7 @@ impl CloudSearchDomain for CloudSearchDomainClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("Search")
                         .deserialize::<SearchResponse, _>()?;
 
                     Ok(result)
@@ -577,6 +641,8 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         let request_uri = "/2013-01-01/suggest";
 
         let mut request = SignedRequest::new("GET", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("Suggest");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -595,6 +661,7 @@ impl CloudSearchDomain for CloudSearchDomainClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("Suggest")
                         .deserialize::<SuggestResponse, _>()?;
 
                     Ok(result)
@@ -618,6 +685,8 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         let request_uri = "/2013-01-01/documents/batch";
 
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("UploadDocuments");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -632,6 +701,7 @@ impl CloudSearchDomain for CloudSearchDomainClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UploadDocuments")
                         .deserialize::<UploadDocumentsResponse, _>()?;
 
                     Ok(result)
@@ -647,3 +717,110 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         })
     }
 }
+
+/// A mock of the Amazon CloudSearch Domain API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloudSearchDomain {
+    search: Option<
+        Box<
+            dyn Fn(SearchRequest) -> ::std::result::Result<SearchResponse, RusotoError<SearchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    suggest: Option<
+        Box<
+            dyn Fn(
+                    SuggestRequest,
+                )
+                    -> ::std::result::Result<SuggestResponse, RusotoError<SuggestError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    upload_documents: Option<
+        Box<
+            dyn Fn(
+                    UploadDocumentsRequest,
+                ) -> ::std::result::Result<
+                    UploadDocumentsResponse,
+                    RusotoError<UploadDocumentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloudSearchDomain {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloudSearchDomain {
+        Default::default()
+    }
+
+    /// Answers `search` with what `handler` returns for its input.
+    pub fn with_search<F>(mut self, handler: F) -> MockCloudSearchDomain
+    where
+        F: Fn(SearchRequest) -> ::std::result::Result<SearchResponse, RusotoError<SearchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search = Some(Box::new(handler));
+        self
+    }
+    /// Answers `suggest` with what `handler` returns for its input.
+    pub fn with_suggest<F>(mut self, handler: F) -> MockCloudSearchDomain
+    where
+        F: Fn(SuggestRequest) -> ::std::result::Result<SuggestResponse, RusotoError<SuggestError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.suggest = Some(Box::new(handler));
+        self
+    }
+    /// Answers `upload_documents` with what `handler` returns for its input.
+    pub fn with_upload_documents<F>(mut self, handler: F) -> MockCloudSearchDomain
+    where
+        F: Fn(
+                UploadDocumentsRequest,
+            )
+                -> ::std::result::Result<UploadDocumentsResponse, RusotoError<UploadDocumentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.upload_documents = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CloudSearchDomain for MockCloudSearchDomain {
+    fn search(&self, input: SearchRequest) -> RusotoFuture<SearchResponse, SearchError> {
+        match self.search {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearchDomain has no handler for search"),
+        }
+    }
+    fn suggest(&self, input: SuggestRequest) -> RusotoFuture<SuggestResponse, SuggestError> {
+        match self.suggest {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearchDomain has no handler for suggest"),
+        }
+    }
+    fn upload_documents(
+        &self,
+        input: UploadDocumentsRequest,
+    ) -> RusotoFuture<UploadDocumentsResponse, UploadDocumentsError> {
+        match self.upload_documents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudSearchDomain has no handler for upload_documents"),
+        }
+    }
+}
@@ -22,9 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Specifies the tags to add to a trail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsRequest {
     /// <p>Specifies the ARN of the trail to which one or more tags will be added. The format of a trail ARN is:</p> <p> <code>arn:aws:cloudtrail:us-east-2:123456789012:trail/MyTrail</code> </p>
     #[serde(rename = "ResourceId")]
@@ -35,13 +37,26 @@ pub struct AddTagsRequest {
     pub tags_list: Option<Vec<Tag>>,
 }
 
+impl Validate for AddTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags_list {
+            errors.check_length(&field_path(path, "tags_list"), value.len(), None, Some(200));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags_list"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsResponse {}
 
 /// <p>Specifies the settings for each trail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTrailRequest {
     /// <p>Specifies a log group name using an Amazon Resource Name (ARN), a unique identifier that represents the log group to which CloudTrail logs will be delivered. Not required unless you specify CloudWatchLogsRoleArn.</p>
     #[serde(rename = "CloudWatchLogsLogGroupArn")]
@@ -89,7 +104,7 @@ pub struct CreateTrailRequest {
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTrailResponse {
     /// <p>Specifies the Amazon Resource Name (ARN) of the log group to which CloudTrail logs will be delivered.</p>
     #[serde(rename = "CloudWatchLogsLogGroupArn")]
@@ -156,6 +171,7 @@ pub struct DataResource {
 
 /// <p>The request that specifies the name of a trail to delete.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTrailRequest {
     /// <p>Specifies the name or the CloudTrail ARN of the trail to be deleted. The format of a trail ARN is: <code>arn:aws:cloudtrail:us-east-2:123456789012:trail/MyTrail</code> </p>
     #[serde(rename = "Name")]
@@ -164,11 +180,12 @@ pub struct DeleteTrailRequest {
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTrailResponse {}
 
 /// <p>Returns information about the trail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTrailsRequest {
     /// <p>Specifies whether to include shadow trails in the response. A shadow trail is the replication in a region of a trail that was created in a different region, or in the case of an organization trail, the replication of an organization trail in member accounts. If you do not include shadow trails, organization trails in a member account and region replication trails will not be returned. The default is true.</p>
     #[serde(rename = "includeShadowTrails")]
@@ -182,7 +199,7 @@ pub struct DescribeTrailsRequest {
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTrailsResponse {
     /// <p>The list of trail objects.</p>
     #[serde(rename = "trailList")]
@@ -192,7 +209,7 @@ pub struct DescribeTrailsResponse {
 
 /// <p>Contains information about an event that was returned by a lookup request. The result includes a representation of a CloudTrail event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Event {
     /// <p>The AWS access key ID that was used to sign the request. If the request was made with temporary security credentials, this is the access key ID of the temporary credentials.</p>
     #[serde(rename = "AccessKeyId")]
@@ -250,6 +267,7 @@ pub struct EventSelector {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetEventSelectorsRequest {
     /// <p>Specifies the name of the trail or trail ARN. If you specify a trail name, the string must meet the following requirements:</p> <ul> <li> <p>Contain only ASCII letters (a-z, A-Z), numbers (0-9), periods (.), underscores (_), or dashes (-)</p> </li> <li> <p>Start with a letter or number, and end with a letter or number</p> </li> <li> <p>Be between 3 and 128 characters</p> </li> <li> <p>Have no adjacent periods, underscores or dashes. Names like <code>my-_namespace</code> and <code>my--namespace</code> are not valid.</p> </li> <li> <p>Not be in IP address format (for example, 192.168.5.4)</p> </li> </ul> <p>If you specify a trail ARN, it must be in the format:</p> <p> <code>arn:aws:cloudtrail:us-east-2:123456789012:trail/MyTrail</code> </p>
     #[serde(rename = "TrailName")]
@@ -257,7 +275,7 @@ pub struct GetEventSelectorsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetEventSelectorsResponse {
     /// <p>The event selectors that are configured for the trail.</p>
     #[serde(rename = "EventSelectors")]
@@ -271,6 +289,7 @@ pub struct GetEventSelectorsResponse {
 
 /// <p>The name of a trail about which you want the current status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTrailStatusRequest {
     /// <p>Specifies the name or the CloudTrail ARN of the trail for which you are requesting status. To get the status of a shadow trail (a replication of the trail in another region), you must specify its ARN. The format of a trail ARN is:</p> <p> <code>arn:aws:cloudtrail:us-east-2:123456789012:trail/MyTrail</code> </p>
     #[serde(rename = "Name")]
@@ -279,7 +298,7 @@ pub struct GetTrailStatusRequest {
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTrailStatusResponse {
     /// <p>Whether the CloudTrail is currently logging AWS API calls.</p>
     #[serde(rename = "IsLogging")]
@@ -353,6 +372,7 @@ pub struct GetTrailStatusResponse {
 
 /// <p>Requests the public keys for a specified time range.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPublicKeysRequest {
     /// <p>Optionally specifies, in UTC, the end of the time range to look up public keys for CloudTrail digest files. If not specified, the current time is used.</p>
     #[serde(rename = "EndTime")]
@@ -370,7 +390,7 @@ pub struct ListPublicKeysRequest {
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPublicKeysResponse {
     /// <p>Reserved for future use.</p>
     #[serde(rename = "NextToken")]
@@ -384,6 +404,7 @@ pub struct ListPublicKeysResponse {
 
 /// <p>Specifies a list of trail tags to return.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsRequest {
     /// <p>Reserved for future use.</p>
     #[serde(rename = "NextToken")]
@@ -396,7 +417,7 @@ pub struct ListTagsRequest {
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsResponse {
     /// <p>Reserved for future use.</p>
     #[serde(rename = "NextToken")]
@@ -410,6 +431,7 @@ pub struct ListTagsResponse {
 
 /// <p>Specifies an attribute and value that filter the events returned.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LookupAttribute {
     /// <p>Specifies an attribute on which to filter the events returned.</p>
     #[serde(rename = "AttributeKey")]
@@ -419,8 +441,20 @@ pub struct LookupAttribute {
     pub attribute_value: String,
 }
 
+impl Validate for LookupAttribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute_value"),
+            self.attribute_value.chars().count(),
+            Some(1),
+            Some(2000),
+        );
+    }
+}
+
 /// <p>Contains a request for LookupEvents.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LookupEventsRequest {
     /// <p>Specifies that only events that occur before or at the specified time are returned. If the specified end time is before the specified start time, an error is returned.</p>
     #[serde(rename = "EndTime")]
@@ -444,9 +478,23 @@ pub struct LookupEventsRequest {
     pub start_time: Option<f64>,
 }
 
+impl Validate for LookupEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.lookup_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "lookup_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 /// <p>Contains a response to a LookupEvents action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LookupEventsResponse {
     /// <p>A list of events returned based on the lookup attributes specified and the CloudTrail event. The events list is sorted by time. The most recent event is listed first.</p>
     #[serde(rename = "Events")]
@@ -460,7 +508,7 @@ pub struct LookupEventsResponse {
 
 /// <p>Contains information about a returned public key.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PublicKey {
     /// <p>The fingerprint of the public key.</p>
     #[serde(rename = "Fingerprint")]
@@ -486,6 +534,7 @@ pub struct PublicKey {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutEventSelectorsRequest {
     /// <p>Specifies the settings for your event selectors. You can configure up to five event selectors for a trail.</p>
     #[serde(rename = "EventSelectors")]
@@ -496,7 +545,7 @@ pub struct PutEventSelectorsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutEventSelectorsResponse {
     /// <p>Specifies the event selectors configured for your trail.</p>
     #[serde(rename = "EventSelectors")]
@@ -510,6 +559,7 @@ pub struct PutEventSelectorsResponse {
 
 /// <p>Specifies the tags to remove from a trail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsRequest {
     /// <p>Specifies the ARN of the trail from which tags should be removed. The format of a trail ARN is:</p> <p> <code>arn:aws:cloudtrail:us-east-2:123456789012:trail/MyTrail</code> </p>
     #[serde(rename = "ResourceId")]
@@ -520,14 +570,26 @@ pub struct RemoveTagsRequest {
     pub tags_list: Option<Vec<Tag>>,
 }
 
+impl Validate for RemoveTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags_list {
+            errors.check_length(&field_path(path, "tags_list"), value.len(), None, Some(200));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags_list"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsResponse {}
 
 /// <p>Specifies the type and name of a resource referenced by an event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Resource {
     /// <p>The name of the resource referenced by the event returned. These are user-created names whose values will depend on the environment. For example, the resource name might be "auto-scaling-test-group" for an Auto Scaling Group or "i-1234567" for an EC2 Instance.</p>
     #[serde(rename = "ResourceName")]
@@ -541,7 +603,7 @@ pub struct Resource {
 
 /// <p>A resource tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceTag {
     /// <p>Specifies the ARN of the resource.</p>
     #[serde(rename = "ResourceId")]
@@ -555,6 +617,7 @@ pub struct ResourceTag {
 
 /// <p>The request to CloudTrail to start logging AWS API calls for an account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartLoggingRequest {
     /// <p>Specifies the name or the CloudTrail ARN of the trail for which CloudTrail logs AWS API calls. The format of a trail ARN is:</p> <p> <code>arn:aws:cloudtrail:us-east-2:123456789012:trail/MyTrail</code> </p>
     #[serde(rename = "Name")]
@@ -563,11 +626,12 @@ pub struct StartLoggingRequest {
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartLoggingResponse {}
 
 /// <p>Passes the request to CloudTrail to stop logging AWS API calls for the specified account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopLoggingRequest {
     /// <p>Specifies the name or the CloudTrail ARN of the trail for which CloudTrail will stop logging AWS API calls. The format of a trail ARN is:</p> <p> <code>arn:aws:cloudtrail:us-east-2:123456789012:trail/MyTrail</code> </p>
     #[serde(rename = "Name")]
@@ -576,7 +640,7 @@ pub struct StopLoggingRequest {
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopLoggingResponse {}
 
 /// <p>A custom key-value pair associated with a resource such as a CloudTrail trail.</p>
@@ -591,9 +655,28 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>The settings for a trail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Trail {
     /// <p>Specifies an Amazon Resource Name (ARN), a unique identifier that represents the log group to which CloudTrail logs will be delivered.</p>
     #[serde(rename = "CloudWatchLogsLogGroupArn")]
@@ -655,6 +738,7 @@ pub struct Trail {
 
 /// <p>Specifies settings to update for the trail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTrailRequest {
     /// <p>Specifies a log group name using an Amazon Resource Name (ARN), a unique identifier that represents the log group to which CloudTrail logs will be delivered. Not required unless you specify CloudWatchLogsRoleArn.</p>
     #[serde(rename = "CloudWatchLogsLogGroupArn")]
@@ -703,7 +787,7 @@ pub struct UpdateTrailRequest {
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTrailResponse {
     /// <p>Specifies the Amazon Resource Name (ARN) of the log group to which CloudTrail logs will be delivered.</p>
     #[serde(rename = "CloudWatchLogsLogGroupArn")]
@@ -811,7 +895,12 @@ impl AddTagsError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(AddTagsError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -999,7 +1088,12 @@ impl CreateTrailError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(CreateTrailError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1091,7 +1185,12 @@ impl DeleteTrailError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(DeleteTrailError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1137,7 +1236,12 @@ impl DescribeTrailsError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(DescribeTrailsError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1190,7 +1294,12 @@ impl GetEventSelectorsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1231,7 +1340,12 @@ impl GetTrailStatusError {
                 "TrailNotFoundException" => {
                     return RusotoError::Service(GetTrailStatusError::TrailNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1282,7 +1396,12 @@ impl ListPublicKeysError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(ListPublicKeysError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1348,7 +1467,12 @@ impl ListTagsError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(ListTagsError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1404,7 +1528,12 @@ impl LookupEventsError {
                 "InvalidTimeRangeException" => {
                     return RusotoError::Service(LookupEventsError::InvalidTimeRange(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1487,7 +1616,12 @@ impl PutEventSelectorsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1566,7 +1700,12 @@ impl RemoveTagsError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(RemoveTagsError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1640,7 +1779,12 @@ impl StartLoggingError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(StartLoggingError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1713,7 +1857,12 @@ impl StopLoggingError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(StopLoggingError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1897,7 +2046,12 @@ impl UpdateTrailError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(UpdateTrailError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2021,6 +2175,247 @@ pub trait CloudTrail {
         input: UpdateTrailRequest,
     ) -> RusotoFuture<UpdateTrailResponse, UpdateTrailError>;
 }
+const _: Option<&dyn CloudTrail> = None;
+impl<'a, T: CloudTrail + ?Sized> CloudTrail for &'a T {
+    fn add_tags(&self, input: AddTagsRequest) -> RusotoFuture<AddTagsResponse, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_trail(
+        &self,
+        input: CreateTrailRequest,
+    ) -> RusotoFuture<CreateTrailResponse, CreateTrailError> {
+        (**self).create_trail(input)
+    }
+    fn delete_trail(
+        &self,
+        input: DeleteTrailRequest,
+    ) -> RusotoFuture<DeleteTrailResponse, DeleteTrailError> {
+        (**self).delete_trail(input)
+    }
+    fn describe_trails(
+        &self,
+        input: DescribeTrailsRequest,
+    ) -> RusotoFuture<DescribeTrailsResponse, DescribeTrailsError> {
+        (**self).describe_trails(input)
+    }
+    fn get_event_selectors(
+        &self,
+        input: GetEventSelectorsRequest,
+    ) -> RusotoFuture<GetEventSelectorsResponse, GetEventSelectorsError> {
+        (**self).get_event_selectors(input)
+    }
+    fn get_trail_status(
+        &self,
+        input: GetTrailStatusRequest,
+    ) -> RusotoFuture<GetTrailStatusResponse, GetTrailStatusError> {
+        (**self).get_trail_status(input)
+    }
+    fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> RusotoFuture<ListPublicKeysResponse, ListPublicKeysError> {
+        (**self).list_public_keys(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn lookup_events(
+        &self,
+        input: LookupEventsRequest,
+    ) -> RusotoFuture<LookupEventsResponse, LookupEventsError> {
+        (**self).lookup_events(input)
+    }
+    fn put_event_selectors(
+        &self,
+        input: PutEventSelectorsRequest,
+    ) -> RusotoFuture<PutEventSelectorsResponse, PutEventSelectorsError> {
+        (**self).put_event_selectors(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> RusotoFuture<RemoveTagsResponse, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn start_logging(
+        &self,
+        input: StartLoggingRequest,
+    ) -> RusotoFuture<StartLoggingResponse, StartLoggingError> {
+        (**self).start_logging(input)
+    }
+    fn stop_logging(
+        &self,
+        input: StopLoggingRequest,
+    ) -> RusotoFuture<StopLoggingResponse, StopLoggingError> {
+        (**self).stop_logging(input)
+    }
+    fn update_trail(
+        &self,
+        input: UpdateTrailRequest,
+    ) -> RusotoFuture<UpdateTrailResponse, UpdateTrailError> {
+        (**self).update_trail(input)
+    }
+}
+impl<T: CloudTrail + ?Sized> CloudTrail for Box<T> {
+    fn add_tags(&self, input: AddTagsRequest) -> RusotoFuture<AddTagsResponse, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_trail(
+        &self,
+        input: CreateTrailRequest,
+    ) -> RusotoFuture<CreateTrailResponse, CreateTrailError> {
+        (**self).create_trail(input)
+    }
+    fn delete_trail(
+        &self,
+        input: DeleteTrailRequest,
+    ) -> RusotoFuture<DeleteTrailResponse, DeleteTrailError> {
+        (**self).delete_trail(input)
+    }
+    fn describe_trails(
+        &self,
+        input: DescribeTrailsRequest,
+    ) -> RusotoFuture<DescribeTrailsResponse, DescribeTrailsError> {
+        (**self).describe_trails(input)
+    }
+    fn get_event_selectors(
+        &self,
+        input: GetEventSelectorsRequest,
+    ) -> RusotoFuture<GetEventSelectorsResponse, GetEventSelectorsError> {
+        (**self).get_event_selectors(input)
+    }
+    fn get_trail_status(
+        &self,
+        input: GetTrailStatusRequest,
+    ) -> RusotoFuture<GetTrailStatusResponse, GetTrailStatusError> {
+        (**self).get_trail_status(input)
+    }
+    fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> RusotoFuture<ListPublicKeysResponse, ListPublicKeysError> {
+        (**self).list_public_keys(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn lookup_events(
+        &self,
+        input: LookupEventsRequest,
+    ) -> RusotoFuture<LookupEventsResponse, LookupEventsError> {
+        (**self).lookup_events(input)
+    }
+    fn put_event_selectors(
+        &self,
+        input: PutEventSelectorsRequest,
+    ) -> RusotoFuture<PutEventSelectorsResponse, PutEventSelectorsError> {
+        (**self).put_event_selectors(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> RusotoFuture<RemoveTagsResponse, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn start_logging(
+        &self,
+        input: StartLoggingRequest,
+    ) -> RusotoFuture<StartLoggingResponse, StartLoggingError> {
+        (**self).start_logging(input)
+    }
+    fn stop_logging(
+        &self,
+        input: StopLoggingRequest,
+    ) -> RusotoFuture<StopLoggingResponse, StopLoggingError> {
+        (**self).stop_logging(input)
+    }
+    fn update_trail(
+        &self,
+        input: UpdateTrailRequest,
+    ) -> RusotoFuture<UpdateTrailResponse, UpdateTrailError> {
+        (**self).update_trail(input)
+    }
+}
+impl<T: CloudTrail + ?Sized> CloudTrail for ::std::sync::Arc<T> {
+    fn add_tags(&self, input: AddTagsRequest) -> RusotoFuture<AddTagsResponse, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_trail(
+        &self,
+        input: CreateTrailRequest,
+    ) -> RusotoFuture<CreateTrailResponse, CreateTrailError> {
+        (**self).create_trail(input)
+    }
+    fn delete_trail(
+        &self,
+        input: DeleteTrailRequest,
+    ) -> RusotoFuture<DeleteTrailResponse, DeleteTrailError> {
+        (**self).delete_trail(input)
+    }
+    fn describe_trails(
+        &self,
+        input: DescribeTrailsRequest,
+    ) -> RusotoFuture<DescribeTrailsResponse, DescribeTrailsError> {
+        (**self).describe_trails(input)
+    }
+    fn get_event_selectors(
+        &self,
+        input: GetEventSelectorsRequest,
+    ) -> RusotoFuture<GetEventSelectorsResponse, GetEventSelectorsError> {
+        (**self).get_event_selectors(input)
+    }
+    fn get_trail_status(
+        &self,
+        input: GetTrailStatusRequest,
+    ) -> RusotoFuture<GetTrailStatusResponse, GetTrailStatusError> {
+        (**self).get_trail_status(input)
+    }
+    fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> RusotoFuture<ListPublicKeysResponse, ListPublicKeysError> {
+        (**self).list_public_keys(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn lookup_events(
+        &self,
+        input: LookupEventsRequest,
+    ) -> RusotoFuture<LookupEventsResponse, LookupEventsError> {
+        (**self).lookup_events(input)
+    }
+    fn put_event_selectors(
+        &self,
+        input: PutEventSelectorsRequest,
+    ) -> RusotoFuture<PutEventSelectorsResponse, PutEventSelectorsError> {
+        (**self).put_event_selectors(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> RusotoFuture<RemoveTagsResponse, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn start_logging(
+        &self,
+        input: StartLoggingRequest,
+    ) -> RusotoFuture<StartLoggingResponse, StartLoggingError> {
+        (**self).start_logging(input)
+    }
+    fn stop_logging(
+        &self,
+        input: StopLoggingRequest,
+    ) -> RusotoFuture<StopLoggingResponse, StopLoggingError> {
+        (**self).stop_logging(input)
+    }
+    fn update_trail(
+        &self,
+        input: UpdateTrailRequest,
+    ) -> RusotoFuture<UpdateTrailResponse, UpdateTrailError> {
+        (**self).update_trail(input)
+    }
+}
 /// A client for the CloudTrail API.
 #[derive(Clone)]
 pub struct CloudTrailClient {
@@ -2061,7 +2456,16 @@ impl CloudTrailClient {
 impl CloudTrail for CloudTrailClient {
     /// <p>Adds one or more tags to a trail, up to a limit of 50. Tags must be unique per trail. Overwrites an existing tag's value when a new value is specified for an existing tag key. If you specify a key without a value, the tag will be created with the specified key and a value of null. You can tag a trail that applies to all regions only from the region in which the trail was created (that is, from its home region).</p>
     fn add_tags(&self, input: AddTagsRequest) -> RusotoFuture<AddTagsResponse, AddTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("AddTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2073,9 +2477,7 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<AddTagsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<AddTagsResponse, _>(response, "AddTags")
             } else {
                 Box::new(
                     response
@@ -2093,6 +2495,7 @@ impl CloudTrail for CloudTrailClient {
         input: CreateTrailRequest,
     ) -> RusotoFuture<CreateTrailResponse, CreateTrailError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("CreateTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2104,10 +2507,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTrailResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateTrailResponse, _>(
+                    response,
+                    "CreateTrail",
+                )
             } else {
                 Box::new(
                     response
@@ -2125,6 +2528,7 @@ impl CloudTrail for CloudTrailClient {
         input: DeleteTrailRequest,
     ) -> RusotoFuture<DeleteTrailResponse, DeleteTrailError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("DeleteTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2136,10 +2540,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTrailResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteTrailResponse, _>(
+                    response,
+                    "DeleteTrail",
+                )
             } else {
                 Box::new(
                     response
@@ -2157,6 +2561,7 @@ impl CloudTrail for CloudTrailClient {
         input: DescribeTrailsRequest,
     ) -> RusotoFuture<DescribeTrailsResponse, DescribeTrailsError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("DescribeTrails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2168,10 +2573,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTrailsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeTrailsResponse, _>(
+                    response,
+                    "DescribeTrails",
+                )
             } else {
                 Box::new(
                     response
@@ -2189,6 +2594,7 @@ impl CloudTrail for CloudTrailClient {
         input: GetEventSelectorsRequest,
     ) -> RusotoFuture<GetEventSelectorsResponse, GetEventSelectorsError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("GetEventSelectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2200,10 +2606,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetEventSelectorsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetEventSelectorsResponse, _>(
+                    response,
+                    "GetEventSelectors",
+                )
             } else {
                 Box::new(
                     response
@@ -2221,6 +2627,7 @@ impl CloudTrail for CloudTrailClient {
         input: GetTrailStatusRequest,
     ) -> RusotoFuture<GetTrailStatusResponse, GetTrailStatusError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("GetTrailStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2232,10 +2639,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTrailStatusResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetTrailStatusResponse, _>(
+                    response,
+                    "GetTrailStatus",
+                )
             } else {
                 Box::new(
                     response
@@ -2253,6 +2660,7 @@ impl CloudTrail for CloudTrailClient {
         input: ListPublicKeysRequest,
     ) -> RusotoFuture<ListPublicKeysResponse, ListPublicKeysError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("ListPublicKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2264,10 +2672,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPublicKeysResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListPublicKeysResponse, _>(
+                    response,
+                    "ListPublicKeys",
+                )
             } else {
                 Box::new(
                     response
@@ -2282,6 +2690,7 @@ impl CloudTrail for CloudTrailClient {
     /// <p>Lists the tags for the trail in the current region.</p>
     fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2293,10 +2702,7 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsResponse, _>(response, "ListTags")
             } else {
                 Box::new(
                     response
@@ -2313,7 +2719,16 @@ impl CloudTrail for CloudTrailClient {
         &self,
         input: LookupEventsRequest,
     ) -> RusotoFuture<LookupEventsResponse, LookupEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("LookupEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2325,10 +2740,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<LookupEventsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<LookupEventsResponse, _>(
+                    response,
+                    "LookupEvents",
+                )
             } else {
                 Box::new(
                     response
@@ -2346,6 +2761,7 @@ impl CloudTrail for CloudTrailClient {
         input: PutEventSelectorsRequest,
     ) -> RusotoFuture<PutEventSelectorsResponse, PutEventSelectorsError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("PutEventSelectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2357,10 +2773,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutEventSelectorsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<PutEventSelectorsResponse, _>(
+                    response,
+                    "PutEventSelectors",
+                )
             } else {
                 Box::new(
                     response
@@ -2377,7 +2793,16 @@ impl CloudTrail for CloudTrailClient {
         &self,
         input: RemoveTagsRequest,
     ) -> RusotoFuture<RemoveTagsResponse, RemoveTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("RemoveTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2389,10 +2814,7 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveTagsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RemoveTagsResponse, _>(response, "RemoveTags")
             } else {
                 Box::new(
                     response
@@ -2410,6 +2832,7 @@ impl CloudTrail for CloudTrailClient {
         input: StartLoggingRequest,
     ) -> RusotoFuture<StartLoggingResponse, StartLoggingError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("StartLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2421,10 +2844,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartLoggingResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StartLoggingResponse, _>(
+                    response,
+                    "StartLogging",
+                )
             } else {
                 Box::new(
                     response
@@ -2442,6 +2865,7 @@ impl CloudTrail for CloudTrailClient {
         input: StopLoggingRequest,
     ) -> RusotoFuture<StopLoggingResponse, StopLoggingError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("StopLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2453,10 +2877,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopLoggingResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StopLoggingResponse, _>(
+                    response,
+                    "StopLogging",
+                )
             } else {
                 Box::new(
                     response
@@ -2474,6 +2898,7 @@ impl CloudTrail for CloudTrailClient {
         input: UpdateTrailRequest,
     ) -> RusotoFuture<UpdateTrailResponse, UpdateTrailError> {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("UpdateTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2485,10 +2910,10 @@ impl CloudTrail for CloudTrailClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTrailResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateTrailResponse, _>(
+                    response,
+                    "UpdateTrail",
+                )
             } else {
                 Box::new(
                     response
@@ -2500,3 +2925,484 @@ impl CloudTrail for CloudTrailClient {
         })
     }
 }
+
+/// A mock of the CloudTrail API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloudTrail {
+    add_tags: Option<
+        Box<
+            dyn Fn(
+                    AddTagsRequest,
+                )
+                    -> ::std::result::Result<AddTagsResponse, RusotoError<AddTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_trail: Option<
+        Box<
+            dyn Fn(
+                    CreateTrailRequest,
+                )
+                    -> ::std::result::Result<CreateTrailResponse, RusotoError<CreateTrailError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_trail: Option<
+        Box<
+            dyn Fn(
+                    DeleteTrailRequest,
+                )
+                    -> ::std::result::Result<DeleteTrailResponse, RusotoError<DeleteTrailError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_trails: Option<
+        Box<
+            dyn Fn(
+                    DescribeTrailsRequest,
+                ) -> ::std::result::Result<
+                    DescribeTrailsResponse,
+                    RusotoError<DescribeTrailsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_event_selectors: Option<
+        Box<
+            dyn Fn(
+                    GetEventSelectorsRequest,
+                ) -> ::std::result::Result<
+                    GetEventSelectorsResponse,
+                    RusotoError<GetEventSelectorsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_trail_status: Option<
+        Box<
+            dyn Fn(
+                    GetTrailStatusRequest,
+                ) -> ::std::result::Result<
+                    GetTrailStatusResponse,
+                    RusotoError<GetTrailStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_public_keys: Option<
+        Box<
+            dyn Fn(
+                    ListPublicKeysRequest,
+                ) -> ::std::result::Result<
+                    ListPublicKeysResponse,
+                    RusotoError<ListPublicKeysError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags: Option<
+        Box<
+            dyn Fn(
+                    ListTagsRequest,
+                )
+                    -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    lookup_events: Option<
+        Box<
+            dyn Fn(
+                    LookupEventsRequest,
+                )
+                    -> ::std::result::Result<LookupEventsResponse, RusotoError<LookupEventsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_event_selectors: Option<
+        Box<
+            dyn Fn(
+                    PutEventSelectorsRequest,
+                ) -> ::std::result::Result<
+                    PutEventSelectorsResponse,
+                    RusotoError<PutEventSelectorsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsRequest,
+                )
+                    -> ::std::result::Result<RemoveTagsResponse, RusotoError<RemoveTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_logging: Option<
+        Box<
+            dyn Fn(
+                    StartLoggingRequest,
+                )
+                    -> ::std::result::Result<StartLoggingResponse, RusotoError<StartLoggingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_logging: Option<
+        Box<
+            dyn Fn(
+                    StopLoggingRequest,
+                )
+                    -> ::std::result::Result<StopLoggingResponse, RusotoError<StopLoggingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_trail: Option<
+        Box<
+            dyn Fn(
+                    UpdateTrailRequest,
+                )
+                    -> ::std::result::Result<UpdateTrailResponse, RusotoError<UpdateTrailError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloudTrail {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloudTrail {
+        Default::default()
+    }
+
+    /// Answers `add_tags` with what `handler` returns for its input.
+    pub fn with_add_tags<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(AddTagsRequest) -> ::std::result::Result<AddTagsResponse, RusotoError<AddTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_trail` with what `handler` returns for its input.
+    pub fn with_create_trail<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                CreateTrailRequest,
+            )
+                -> ::std::result::Result<CreateTrailResponse, RusotoError<CreateTrailError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_trail = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_trail` with what `handler` returns for its input.
+    pub fn with_delete_trail<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                DeleteTrailRequest,
+            )
+                -> ::std::result::Result<DeleteTrailResponse, RusotoError<DeleteTrailError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_trail = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_trails` with what `handler` returns for its input.
+    pub fn with_describe_trails<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                DescribeTrailsRequest,
+            )
+                -> ::std::result::Result<DescribeTrailsResponse, RusotoError<DescribeTrailsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_trails = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_event_selectors` with what `handler` returns for its input.
+    pub fn with_get_event_selectors<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                GetEventSelectorsRequest,
+            ) -> ::std::result::Result<
+                GetEventSelectorsResponse,
+                RusotoError<GetEventSelectorsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_event_selectors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_trail_status` with what `handler` returns for its input.
+    pub fn with_get_trail_status<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                GetTrailStatusRequest,
+            )
+                -> ::std::result::Result<GetTrailStatusResponse, RusotoError<GetTrailStatusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_trail_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_public_keys` with what `handler` returns for its input.
+    pub fn with_list_public_keys<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                ListPublicKeysRequest,
+            )
+                -> ::std::result::Result<ListPublicKeysResponse, RusotoError<ListPublicKeysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_public_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags` with what `handler` returns for its input.
+    pub fn with_list_tags<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                ListTagsRequest,
+            ) -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `lookup_events` with what `handler` returns for its input.
+    pub fn with_lookup_events<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                LookupEventsRequest,
+            )
+                -> ::std::result::Result<LookupEventsResponse, RusotoError<LookupEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.lookup_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_event_selectors` with what `handler` returns for its input.
+    pub fn with_put_event_selectors<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                PutEventSelectorsRequest,
+            ) -> ::std::result::Result<
+                PutEventSelectorsResponse,
+                RusotoError<PutEventSelectorsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_event_selectors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags` with what `handler` returns for its input.
+    pub fn with_remove_tags<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                RemoveTagsRequest,
+            )
+                -> ::std::result::Result<RemoveTagsResponse, RusotoError<RemoveTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_logging` with what `handler` returns for its input.
+    pub fn with_start_logging<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                StartLoggingRequest,
+            )
+                -> ::std::result::Result<StartLoggingResponse, RusotoError<StartLoggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_logging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_logging` with what `handler` returns for its input.
+    pub fn with_stop_logging<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                StopLoggingRequest,
+            )
+                -> ::std::result::Result<StopLoggingResponse, RusotoError<StopLoggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_logging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_trail` with what `handler` returns for its input.
+    pub fn with_update_trail<F>(mut self, handler: F) -> MockCloudTrail
+    where
+        F: Fn(
+                UpdateTrailRequest,
+            )
+                -> ::std::result::Result<UpdateTrailResponse, RusotoError<UpdateTrailError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_trail = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CloudTrail for MockCloudTrail {
+    fn add_tags(&self, input: AddTagsRequest) -> RusotoFuture<AddTagsResponse, AddTagsError> {
+        match self.add_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for add_tags"),
+        }
+    }
+    fn create_trail(
+        &self,
+        input: CreateTrailRequest,
+    ) -> RusotoFuture<CreateTrailResponse, CreateTrailError> {
+        match self.create_trail {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for create_trail"),
+        }
+    }
+    fn delete_trail(
+        &self,
+        input: DeleteTrailRequest,
+    ) -> RusotoFuture<DeleteTrailResponse, DeleteTrailError> {
+        match self.delete_trail {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for delete_trail"),
+        }
+    }
+    fn describe_trails(
+        &self,
+        input: DescribeTrailsRequest,
+    ) -> RusotoFuture<DescribeTrailsResponse, DescribeTrailsError> {
+        match self.describe_trails {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for describe_trails"),
+        }
+    }
+    fn get_event_selectors(
+        &self,
+        input: GetEventSelectorsRequest,
+    ) -> RusotoFuture<GetEventSelectorsResponse, GetEventSelectorsError> {
+        match self.get_event_selectors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for get_event_selectors"),
+        }
+    }
+    fn get_trail_status(
+        &self,
+        input: GetTrailStatusRequest,
+    ) -> RusotoFuture<GetTrailStatusResponse, GetTrailStatusError> {
+        match self.get_trail_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for get_trail_status"),
+        }
+    }
+    fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> RusotoFuture<ListPublicKeysResponse, ListPublicKeysError> {
+        match self.list_public_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for list_public_keys"),
+        }
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        match self.list_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for list_tags"),
+        }
+    }
+    fn lookup_events(
+        &self,
+        input: LookupEventsRequest,
+    ) -> RusotoFuture<LookupEventsResponse, LookupEventsError> {
+        match self.lookup_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for lookup_events"),
+        }
+    }
+    fn put_event_selectors(
+        &self,
+        input: PutEventSelectorsRequest,
+    ) -> RusotoFuture<PutEventSelectorsResponse, PutEventSelectorsError> {
+        match self.put_event_selectors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for put_event_selectors"),
+        }
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> RusotoFuture<RemoveTagsResponse, RemoveTagsError> {
+        match self.remove_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for remove_tags"),
+        }
+    }
+    fn start_logging(
+        &self,
+        input: StartLoggingRequest,
+    ) -> RusotoFuture<StartLoggingResponse, StartLoggingError> {
+        match self.start_logging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for start_logging"),
+        }
+    }
+    fn stop_logging(
+        &self,
+        input: StopLoggingRequest,
+    ) -> RusotoFuture<StopLoggingResponse, StopLoggingError> {
+        match self.stop_logging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for stop_logging"),
+        }
+    }
+    fn update_trail(
+        &self,
+        input: UpdateTrailRequest,
+    ) -> RusotoFuture<UpdateTrailResponse, UpdateTrailError> {
+        match self.update_trail {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudTrail has no handler for update_trail"),
+        }
+    }
+}
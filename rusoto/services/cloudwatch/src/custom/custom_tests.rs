@@ -1,12 +1,22 @@
 extern crate rusoto_mock;
 
-use crate::generated::{CloudWatch, CloudWatchClient, Dimension, MetricDatum, PutMetricDataInput};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::Future;
+
+use crate::generated::{
+    CloudWatch, CloudWatchClient, Dimension, Metric, MetricDataQuery, MetricDatum, MetricStat,
+    PutMetricDataInput,
+};
+use crate::metric_data::{MetricDataError, MetricDataOptions, SeriesStatus};
 
 use self::rusoto_mock::*;
 use rusoto_core::param::Params;
 use rusoto_core::signature::SignedRequest;
 use rusoto_core::signature::SignedRequestPayload;
-use rusoto_core::Region;
+use rusoto_core::{DispatchSignedRequest, Region};
 use serde_urlencoded;
 
 #[test]
@@ -67,3 +77,301 @@ fn should_serialize_complex_metric_data_params() {
     let response = client.put_metric_data(request).sync().unwrap();
     println!("{:#?}", response);
 }
+
+/// The parameters of each request sent.
+type Requests = Arc<Mutex<Vec<Vec<(String, String)>>>>;
+
+/// Answers `GetMetricData` calls with `pages` pages, where page `n` holds the datapoint
+/// `n` at minute `n` of every query returning data. The last page has `status`, and the
+/// others `PartialData`.
+struct MetricDataDispatcher {
+    pages: usize,
+    status: &'static str,
+    requests: Requests,
+}
+
+impl MetricDataDispatcher {
+    fn new(pages: usize, status: &'static str) -> (Self, Requests) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = MetricDataDispatcher {
+            pages,
+            status,
+            requests: requests.clone(),
+        };
+        (dispatcher, requests)
+    }
+}
+
+impl DispatchSignedRequest for MetricDataDispatcher {
+    type Future = <MockRequestDispatcher as DispatchSignedRequest>::Future;
+
+    fn dispatch(&self, request: SignedRequest, timeout: Option<Duration>) -> Self::Future {
+        let params: Vec<(String, String)> = match request.payload {
+            Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                serde_urlencoded::from_bytes(buffer).unwrap()
+            }
+            _ => Vec::new(),
+        };
+        let page = param(&params, "NextToken")
+            .map(|token| token.trim_start_matches("page-").parse().unwrap())
+            .unwrap_or(1);
+        let results: String = query_ids(&params)
+            .iter()
+            .filter(|id| {
+                let index = params
+                    .iter()
+                    .find(|(name, value)| name.ends_with(".Id") && value == *id)
+                    .map(|(name, _)| name.trim_end_matches(".Id").to_owned())
+                    .unwrap();
+                param(&params, &format!("{}.ReturnData", index)) != Some("false")
+            })
+            .map(|id| {
+                format!(
+                    "<member>
+                        <Id>{}</Id>
+                        <StatusCode>{}</StatusCode>
+                        <Timestamps><member>2019-01-01T00:0{}:00Z</member></Timestamps>
+                        <Values><member>{}</member></Values>
+                    </member>",
+                    id,
+                    if page < self.pages {
+                        "PartialData"
+                    } else {
+                        self.status
+                    },
+                    page,
+                    page
+                )
+            })
+            .collect();
+        let next_token = if page < self.pages {
+            format!("<NextToken>page-{}</NextToken>", page + 1)
+        } else {
+            String::new()
+        };
+        self.requests.lock().unwrap().push(params);
+
+        let body = format!(
+            "<GetMetricDataResponse>
+                <GetMetricDataResult>
+                    <MetricDataResults>{}</MetricDataResults>{}
+                </GetMetricDataResult>
+                <ResponseMetadata><RequestId>metric-data</RequestId></ResponseMetadata>
+            </GetMetricDataResponse>",
+            results, next_token
+        );
+        MockRequestDispatcher::with_status(200)
+            .with_body(&body)
+            .dispatch(request, timeout)
+    }
+}
+
+fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(param, _)| param == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// The ids of the queries of a request, in order.
+fn query_ids(params: &[(String, String)]) -> Vec<String> {
+    (1..)
+        .map(|n| param(params, &format!("MetricDataQueries.member.{}.Id", n)))
+        .take_while(Option::is_some)
+        .map(|id| id.unwrap().to_owned())
+        .collect()
+}
+
+fn stat_query(id: &str, period: i64) -> MetricDataQuery {
+    MetricDataQuery {
+        id: id.to_owned(),
+        metric_stat: Some(MetricStat {
+            metric: Metric {
+                namespace: Some("AWS/EC2".to_owned()),
+                metric_name: Some("CPUUtilization".to_owned()),
+                dimensions: None,
+            },
+            period,
+            stat: "Average".to_owned(),
+            unit: None,
+        }),
+        ..Default::default()
+    }
+}
+
+fn expression_query(id: &str, expression: &str) -> MetricDataQuery {
+    MetricDataQuery {
+        id: id.to_owned(),
+        expression: Some(expression.to_owned()),
+        ..Default::default()
+    }
+}
+
+fn start() -> DateTime<Utc> {
+    "2019-01-01T00:00:00Z".parse().unwrap()
+}
+
+fn an_hour_later() -> DateTime<Utc> {
+    "2019-01-01T01:00:00Z".parse().unwrap()
+}
+
+fn metric_data_client(dispatcher: MetricDataDispatcher) -> CloudWatchClient {
+    CloudWatchClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1)
+}
+
+#[test]
+fn get_metric_data_all_batches_queries_and_stitches_pages() {
+    let (dispatcher, requests) = MetricDataDispatcher::new(2, "Complete");
+    let m1 = MetricDataQuery {
+        return_data: Some(false),
+        ..stat_query("m1", 60)
+    };
+    let queries = vec![
+        m1,
+        stat_query("m2", 60),
+        expression_query("e1", "m1 * 2"),
+        stat_query("m3", 60),
+    ];
+    let options = MetricDataOptions {
+        max_datapoints: Some(120),
+        ..Default::default()
+    };
+    let data = metric_data_client(dispatcher)
+        .get_metric_data_all(queries, start(), an_hour_later(), options)
+        .wait()
+        .unwrap();
+
+    // an hour of minutes is 60 datapoints a query, so m2 doesn't fit with m1 and e1
+    let requests = requests.lock().unwrap();
+    let batches: Vec<Vec<String>> = requests.iter().map(|params| query_ids(params)).collect();
+    assert_eq!(
+        batches,
+        vec![
+            vec!["m1", "e1"],
+            vec!["m1", "e1"],
+            vec!["m2", "m3"],
+            vec!["m2", "m3"],
+        ]
+    );
+    assert_eq!(param(&requests[0], "NextToken"), None);
+    assert_eq!(param(&requests[1], "NextToken"), Some("page-2"));
+    assert_eq!(
+        param(&requests[0], "StartTime"),
+        Some("2019-01-01T00:00:00Z")
+    );
+    assert_eq!(param(&requests[0], "EndTime"), Some("2019-01-01T01:00:00Z"));
+    assert_eq!(param(&requests[0], "MaxDatapoints"), Some("120"));
+
+    let ids: Vec<&str> = data
+        .series
+        .iter()
+        .map(|series| series.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["m2", "e1", "m3"]);
+    for series in &data.series {
+        assert_eq!(
+            series.timestamps,
+            vec!["2019-01-01T00:01:00Z", "2019-01-01T00:02:00Z"]
+        );
+        assert_eq!(series.values, vec![1.0, 2.0]);
+        assert_eq!(series.status, Some(SeriesStatus::Complete));
+    }
+    assert!(data.is_complete());
+}
+
+#[test]
+fn get_metric_data_all_splits_more_queries_than_a_call_takes() {
+    let (dispatcher, requests) = MetricDataDispatcher::new(1, "Complete");
+    let queries = (0..501)
+        .map(|n| stat_query(&format!("m{}", n), 3600))
+        .collect();
+    let data = metric_data_client(dispatcher)
+        .get_metric_data_all(queries, start(), an_hour_later(), Default::default())
+        .wait()
+        .unwrap();
+
+    let sizes: Vec<usize> = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|params| query_ids(params).len())
+        .collect();
+    assert_eq!(sizes, vec![500, 1]);
+    assert_eq!(data.series.len(), 501);
+    assert_eq!(data.series[500].id, "m500");
+}
+
+#[test]
+fn get_metric_data_all_surfaces_partial_data() {
+    let (dispatcher, _) = MetricDataDispatcher::new(2, "PartialData");
+    let data = metric_data_client(dispatcher)
+        .get_metric_data_all(
+            vec![stat_query("m1", 60)],
+            start(),
+            an_hour_later(),
+            Default::default(),
+        )
+        .wait()
+        .unwrap();
+
+    assert!(!data.is_complete());
+    assert_eq!(data.partial_series().len(), 1);
+    assert_eq!(data.series[0].values, vec![1.0, 2.0]);
+}
+
+#[test]
+fn get_metric_data_all_checks_queries_before_calling() {
+    let invalid = vec![
+        (
+            vec![stat_query("m1", 60)],
+            an_hour_later(),
+            start(),
+            MetricDataError::InvalidTimeRange,
+        ),
+        (
+            vec![stat_query("m1", 60), stat_query("m1", 300)],
+            start(),
+            an_hour_later(),
+            MetricDataError::DuplicateId("m1".to_owned()),
+        ),
+        (
+            vec![stat_query("m1", 90)],
+            start(),
+            an_hour_later(),
+            MetricDataError::InvalidPeriod {
+                id: "m1".to_owned(),
+                period: 90,
+                reason: "periods of a minute or more must be a multiple of 60 seconds".to_owned(),
+            },
+        ),
+        (
+            vec![stat_query("m1", 20)],
+            start(),
+            an_hour_later(),
+            MetricDataError::InvalidPeriod {
+                id: "m1".to_owned(),
+                period: 20,
+                reason: "periods shorter than a minute must be 1, 5, 10 or 30 seconds".to_owned(),
+            },
+        ),
+        (
+            vec![stat_query("m1", 7200)],
+            start(),
+            an_hour_later(),
+            MetricDataError::InvalidPeriod {
+                id: "m1".to_owned(),
+                period: 7200,
+                reason: "it is longer than the 3600 seconds of the time range".to_owned(),
+            },
+        ),
+    ];
+    for (queries, start, end, expected) in invalid {
+        let (dispatcher, requests) = MetricDataDispatcher::new(1, "Complete");
+        let result = metric_data_client(dispatcher)
+            .get_metric_data_all(queries, start, end, Default::default())
+            .wait();
+
+        assert_eq!(result, Err(expected));
+        assert!(requests.lock().unwrap().is_empty());
+    }
+}
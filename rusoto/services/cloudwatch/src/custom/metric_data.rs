@@ -0,0 +1,429 @@
+//! Fetching the data of many metrics at once.
+//!
+//! A single `GetMetricData` call takes at most `MAX_QUERIES_PER_CALL` queries and returns
+//! at most `MAX_DATAPOINTS_PER_CALL` datapoints a page. `CloudWatchClient::get_metric_data_all`
+//! takes any number of queries: it checks their periods against the time range, splits them
+//! into batches within those limits, keeping math expressions with the queries they refer
+//! to, follows the pages of each batch and stitches the pages back into one series per
+//! query.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use chrono::{Duration, Utc};
+//! use futures::Future;
+//! use rusoto_cloudwatch::metric_data::MetricDataOptions;
+//! use rusoto_cloudwatch::{CloudWatchClient, Dimension, Metric, MetricDataQuery, MetricStat};
+//! use rusoto_core::Region;
+//!
+//! let query = MetricDataQuery {
+//!     id: "cpu".to_owned(),
+//!     metric_stat: Some(MetricStat {
+//!         metric: Metric {
+//!             namespace: Some("AWS/EC2".to_owned()),
+//!             metric_name: Some("CPUUtilization".to_owned()),
+//!             dimensions: Some(vec![Dimension {
+//!                 name: "InstanceId".to_owned(),
+//!                 value: "i-0123456789abcdef0".to_owned(),
+//!             }]),
+//!         },
+//!         period: 300,
+//!         stat: "Average".to_owned(),
+//!         unit: None,
+//!     }),
+//!     ..Default::default()
+//! };
+//! let end = Utc::now();
+//! let data = CloudWatchClient::new(Region::UsEast1)
+//!     .get_metric_data_all(vec![query], end - Duration::days(1), end, MetricDataOptions::default())
+//!     .wait()
+//!     .unwrap();
+//! for series in data.series {
+//!     println!("{}: {} datapoints", series.id, series.values.len());
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures::future::{self, Either, Loop};
+use futures::Future;
+use rusoto_core::RusotoError;
+
+use crate::generated::{
+    CloudWatch, CloudWatchClient, GetMetricDataError, GetMetricDataInput, MessageData,
+    MetricDataQuery,
+};
+
+/// The most queries a `GetMetricData` call takes.
+pub const MAX_QUERIES_PER_CALL: usize = 500;
+
+/// The most datapoints a `GetMetricData` call returns before paginating.
+pub const MAX_DATAPOINTS_PER_CALL: i64 = 100_800;
+
+/// The periods shorter than a minute CloudWatch accepts, for high-resolution metrics.
+const HIGH_RESOLUTION_PERIODS: [i64; 4] = [1, 5, 10, 30];
+
+/// Options of `CloudWatchClient::get_metric_data_all`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricDataOptions {
+    /// The order of the datapoints of each series, `TimestampDescending` (the default) or
+    /// `TimestampAscending`.
+    pub scan_by: Option<String>,
+    /// The most datapoints a page holds, at most `MAX_DATAPOINTS_PER_CALL`, which queries
+    /// are also batched by.
+    pub max_datapoints: Option<i64>,
+}
+
+/// The status of a series, once all its pages are fetched.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SeriesStatus {
+    /// All the datapoints of the time range were returned.
+    Complete,
+    /// Some datapoints are missing, such as when CloudWatch couldn't retrieve them all.
+    PartialData,
+    /// CloudWatch failed to compute the series.
+    InternalError,
+    /// The series may be incomplete because access to some of its data was denied.
+    Forbidden,
+    /// Any other status.
+    Other(String),
+}
+
+impl SeriesStatus {
+    fn from_code(code: &str) -> SeriesStatus {
+        match code {
+            "Complete" => SeriesStatus::Complete,
+            "PartialData" => SeriesStatus::PartialData,
+            "InternalError" => SeriesStatus::InternalError,
+            "Forbidden" => SeriesStatus::Forbidden,
+            other => SeriesStatus::Other(other.to_owned()),
+        }
+    }
+}
+
+/// The datapoints of a query, stitched from all the pages they were returned in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricDataSeries {
+    /// The id of the query.
+    pub id: String,
+    /// The label of the series.
+    pub label: Option<String>,
+    /// The timestamps of the datapoints, in the order of `MetricDataOptions::scan_by`.
+    pub timestamps: Vec<String>,
+    /// The values of the datapoints, in the order of their timestamps.
+    pub values: Vec<f64>,
+    /// The status of the last page holding the series, or `None` if it held none.
+    pub status: Option<SeriesStatus>,
+    /// The messages about the series.
+    pub messages: Vec<MessageData>,
+}
+
+impl MetricDataSeries {
+    fn new(id: String) -> MetricDataSeries {
+        MetricDataSeries {
+            id,
+            label: None,
+            timestamps: Vec::new(),
+            values: Vec::new(),
+            status: None,
+            messages: Vec::new(),
+        }
+    }
+}
+
+/// The data returned by `CloudWatchClient::get_metric_data_all`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricData {
+    /// A series for each query returning data, in the order of the queries.
+    pub series: Vec<MetricDataSeries>,
+    /// The messages about the calls as a whole.
+    pub messages: Vec<MessageData>,
+}
+
+impl MetricData {
+    /// Whether every series is complete.
+    pub fn is_complete(&self) -> bool {
+        self.series
+            .iter()
+            .all(|series| series.status == Some(SeriesStatus::Complete))
+    }
+
+    /// The series whose status is `PartialData`.
+    pub fn partial_series(&self) -> Vec<&MetricDataSeries> {
+        self.series
+            .iter()
+            .filter(|series| series.status == Some(SeriesStatus::PartialData))
+            .collect()
+    }
+}
+
+/// An error fetching metric data, found before sending any query, or returned by a call.
+#[derive(Debug, PartialEq)]
+pub enum MetricDataError {
+    /// The start of the time range isn't before its end.
+    InvalidTimeRange,
+    /// The period of a query isn't one CloudWatch accepts, or is longer than the time range.
+    InvalidPeriod {
+        /// The id of the query.
+        id: String,
+        /// The period of the query, in seconds.
+        period: i64,
+        /// What is wrong with it.
+        reason: String,
+    },
+    /// More than one query has the id.
+    DuplicateId(String),
+    /// The expressions among the queries with these ids refer to more queries than a single
+    /// call takes.
+    TooManyRelatedQueries(Vec<String>),
+    /// A `GetMetricData` call failed.
+    GetMetricData(RusotoError<GetMetricDataError>),
+}
+
+impl fmt::Display for MetricDataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MetricDataError::InvalidTimeRange => {
+                write!(f, "the start of the time range isn't before its end")
+            }
+            MetricDataError::InvalidPeriod {
+                ref id,
+                period,
+                ref reason,
+            } => write!(f, "invalid period {} of query {}: {}", period, id, reason),
+            MetricDataError::DuplicateId(ref id) => {
+                write!(f, "more than one query has the id {}", id)
+            }
+            MetricDataError::TooManyRelatedQueries(ref ids) => write!(
+                f,
+                "the {} queries referred to by expressions exceed the {} queries of a call",
+                ids.len(),
+                MAX_QUERIES_PER_CALL
+            ),
+            MetricDataError::GetMetricData(ref err) => {
+                write!(f, "getting metric data failed: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for MetricDataError {
+    fn description(&self) -> &str {
+        match *self {
+            MetricDataError::InvalidTimeRange => "invalid time range",
+            MetricDataError::InvalidPeriod { .. } => "invalid period",
+            MetricDataError::DuplicateId(_) => "duplicate query id",
+            MetricDataError::TooManyRelatedQueries(_) => "too many related queries",
+            MetricDataError::GetMetricData(_) => "getting metric data failed",
+        }
+    }
+}
+
+impl CloudWatchClient {
+    /// Get the data of `queries` between `start` and `end`, in as many `GetMetricData`
+    /// calls as needed.
+    ///
+    /// Batches are fetched one after the other. Every query is checked before the first
+    /// call, failing without making any call if one is invalid.
+    pub fn get_metric_data_all(
+        &self,
+        queries: Vec<MetricDataQuery>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        options: MetricDataOptions,
+    ) -> impl Future<Item = MetricData, Error = MetricDataError> + Send {
+        let batches = match batch_queries(&queries, start, end, &options) {
+            Ok(batches) => batches,
+            Err(err) => return Either::A(future::err(err)),
+        };
+        let data = MetricData {
+            series: queries
+                .iter()
+                .filter(|query| query.return_data != Some(false))
+                .map(|query| MetricDataSeries::new(query.id.clone()))
+                .collect(),
+            messages: Vec::new(),
+        };
+        let inputs: Vec<GetMetricDataInput> = batches
+            .into_iter()
+            .map(|batch| GetMetricDataInput {
+                start_time: start.to_rfc3339_opts(SecondsFormat::Secs, true),
+                end_time: end.to_rfc3339_opts(SecondsFormat::Secs, true),
+                max_datapoints: options.max_datapoints,
+                scan_by: options.scan_by.clone(),
+                metric_data_queries: batch.into_iter().map(|i| queries[i].clone()).collect(),
+                next_token: None,
+            })
+            .collect();
+
+        let client = self.clone();
+        Either::B(future::loop_fn(
+            (data, inputs.into_iter()),
+            move |(data, mut inputs)| match inputs.next() {
+                None => Either::A(future::ok(Loop::Break(data))),
+                Some(input) => Either::B(
+                    fetch_pages(client.clone(), input, data)
+                        .map(move |data| Loop::Continue((data, inputs))),
+                ),
+            },
+        ))
+    }
+}
+
+/// Follows the pages of a batch, stitching their results into `data`.
+fn fetch_pages<C: CloudWatch + Send + 'static>(
+    client: C,
+    input: GetMetricDataInput,
+    data: MetricData,
+) -> impl Future<Item = MetricData, Error = MetricDataError> + Send {
+    future::loop_fn((input, data), move |(mut input, mut data)| {
+        client
+            .get_metric_data(input.clone())
+            .map_err(MetricDataError::GetMetricData)
+            .map(move |output| {
+                data.messages.extend(output.messages.unwrap_or_default());
+                for result in output.metric_data_results.unwrap_or_default() {
+                    let id = result.id.unwrap_or_default();
+                    let index = match data.series.iter().position(|series| series.id == id) {
+                        Some(index) => index,
+                        None => {
+                            data.series.push(MetricDataSeries::new(id));
+                            data.series.len() - 1
+                        }
+                    };
+                    let series = &mut data.series[index];
+                    if result.label.is_some() {
+                        series.label = result.label;
+                    }
+                    series
+                        .timestamps
+                        .extend(result.timestamps.unwrap_or_default());
+                    series.values.extend(result.values.unwrap_or_default());
+                    series.messages.extend(result.messages.unwrap_or_default());
+                    if let Some(ref code) = result.status_code {
+                        series.status = Some(SeriesStatus::from_code(code));
+                    }
+                }
+                match output.next_token {
+                    Some(next_token) => {
+                        input.next_token = Some(next_token);
+                        Loop::Continue((input, data))
+                    }
+                    None => Loop::Break(data),
+                }
+            })
+    })
+}
+
+/// Checks `queries`, and splits them into batches of indices within the limits of a call.
+fn batch_queries(
+    queries: &[MetricDataQuery],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    options: &MetricDataOptions,
+) -> Result<Vec<Vec<usize>>, MetricDataError> {
+    if start >= end {
+        return Err(MetricDataError::InvalidTimeRange);
+    }
+    let range = (end - start).num_seconds().max(1);
+    let mut indices = HashMap::new();
+    for (index, query) in queries.iter().enumerate() {
+        if indices.insert(query.id.as_str(), index).is_some() {
+            return Err(MetricDataError::DuplicateId(query.id.clone()));
+        }
+        if let Some(ref stat) = query.metric_stat {
+            check_period(&query.id, stat.period, range)?;
+        }
+    }
+
+    // expressions are computed from queries of the same call
+    let mut groups: Vec<usize> = (0..queries.len()).collect();
+    for (index, query) in queries.iter().enumerate() {
+        let expression = match query.expression {
+            Some(ref expression) => expression,
+            None => continue,
+        };
+        for name in expression.split(|c: char| !c.is_ascii_alphanumeric() && c != '_') {
+            if let Some(&referred) = indices.get(name) {
+                let (a, b) = (
+                    group_of(&mut groups, index),
+                    group_of(&mut groups, referred),
+                );
+                groups[a.max(b)] = a.min(b);
+            }
+        }
+    }
+    let mut grouped: Vec<Vec<usize>> = Vec::new();
+    let mut positions = HashMap::new();
+    for index in 0..queries.len() {
+        let group = group_of(&mut groups, index);
+        let position = *positions.entry(group).or_insert_with(|| {
+            grouped.push(Vec::new());
+            grouped.len() - 1
+        });
+        grouped[position].push(index);
+    }
+
+    let max_datapoints = options
+        .max_datapoints
+        .unwrap_or(MAX_DATAPOINTS_PER_CALL)
+        .min(MAX_DATAPOINTS_PER_CALL);
+    let datapoints = |index: usize| match queries[index].metric_stat {
+        Some(ref stat) => (range + stat.period - 1) / stat.period,
+        // expressions are at least as coarse as a minute, unless computed from finer metrics
+        None => (range + 59) / 60,
+    };
+    let mut batches: Vec<Vec<usize>> = Vec::new();
+    let mut batch_datapoints = 0;
+    for group in grouped {
+        if group.len() > MAX_QUERIES_PER_CALL {
+            let mut ids: Vec<String> = group.iter().map(|&i| queries[i].id.clone()).collect();
+            ids.sort();
+            return Err(MetricDataError::TooManyRelatedQueries(ids));
+        }
+        let group_datapoints: i64 = group.iter().map(|&i| datapoints(i)).sum();
+        let fits = batches.last().into_iter().any(|batch| {
+            batch.len() + group.len() <= MAX_QUERIES_PER_CALL
+                && batch_datapoints + group_datapoints <= max_datapoints
+        });
+        if fits {
+            batch_datapoints += group_datapoints;
+            batches.last_mut().unwrap().extend(group);
+        } else {
+            batch_datapoints = group_datapoints;
+            batches.push(group);
+        }
+    }
+    Ok(batches)
+}
+
+/// The representative of the group of the query at `index`.
+fn group_of(groups: &mut [usize], mut index: usize) -> usize {
+    while groups[index] != index {
+        groups[index] = groups[groups[index]];
+        index = groups[index];
+    }
+    index
+}
+
+fn check_period(id: &str, period: i64, range: i64) -> Result<(), MetricDataError> {
+    let reason = if period <= 0 {
+        "it isn't positive".to_owned()
+    } else if period < 60 && !HIGH_RESOLUTION_PERIODS.contains(&period) {
+        "periods shorter than a minute must be 1, 5, 10 or 30 seconds".to_owned()
+    } else if period >= 60 && period % 60 != 0 {
+        "periods of a minute or more must be a multiple of 60 seconds".to_owned()
+    } else if period > range {
+        format!("it is longer than the {} seconds of the time range", range)
+    } else {
+        return Ok(());
+    };
+    Err(MetricDataError::InvalidPeriod {
+        id: id.to_owned(),
+        period,
+        reason,
+    })
+}
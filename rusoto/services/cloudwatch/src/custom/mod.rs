@@ -1,2 +1,5 @@
 #[cfg(test)]
 mod custom_tests;
+
+/// Fetching metric data in batches, following pagination
+pub mod metric_data;
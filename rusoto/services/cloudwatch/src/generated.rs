@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -38,7 +39,7 @@ impl ActionsEnabledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -68,6 +69,8 @@ impl AlarmDescriptionDeserializer {
 }
 /// <p>Represents the history of a specific alarm.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AlarmHistoryItem {
     /// <p>The descriptive name for the alarm.</p>
     pub alarm_name: Option<String>,
@@ -160,6 +163,8 @@ impl AlarmNamesSerializer {
 
 /// <p>An anomaly detection model associated with a particular CloudWatch metric athresnd statistic. You can use the model to display a band of expected normal values when the metric is graphed.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnomalyDetector {
     /// <p>The configuration specifies details about how the anomaly detection model is to be trained, including time ranges to exclude from use for training the model, and the time zone to use for the metric.</p>
     pub configuration: Option<AnomalyDetectorConfiguration>,
@@ -212,6 +217,8 @@ impl AnomalyDetectorDeserializer {
 }
 /// <p>The configuration specifies details about how the anomaly detection model is to be trained, including time ranges to exclude from use for training the model and the time zone to use for the metric.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnomalyDetectorConfiguration {
     /// <p>An array of time ranges to exclude from use when the anomaly detection model is trained. Use this to make sure that events that could cause unusual values for the metric, such as deployments, aren't used when CloudWatch creates the model.</p>
     pub excluded_time_ranges: Option<Vec<Range>>,
@@ -219,6 +226,20 @@ pub struct AnomalyDetectorConfiguration {
     pub metric_timezone: Option<String>,
 }
 
+impl Validate for AnomalyDetectorConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.metric_timezone {
+            errors.check_length(
+                &field_path(path, "metric_timezone"),
+                value.chars().count(),
+                None,
+                Some(50),
+            );
+            errors.check_pattern(&field_path(path, "metric_timezone"), &value, ".*");
+        }
+    }
+}
+
 struct AnomalyDetectorConfigurationDeserializer;
 impl AnomalyDetectorConfigurationDeserializer {
     #[allow(unused_variables)]
@@ -397,6 +418,8 @@ impl DashboardEntriesDeserializer {
 }
 /// <p>Represents a specific dashboard.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DashboardEntry {
     /// <p>The Amazon Resource Name (ARN) of the dashboard.</p>
     pub dashboard_arn: Option<String>,
@@ -469,6 +492,8 @@ impl DashboardNamesSerializer {
 
 /// <p>An error or warning for the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DashboardValidationMessage {
     /// <p>The data path related to the message.</p>
     pub data_path: Option<String>,
@@ -533,6 +558,8 @@ impl DataPathDeserializer {
 }
 /// <p>Encapsulates the statistical data that CloudWatch computes from metric data.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Datapoint {
     /// <p>The average of the metric values that correspond to the data point.</p>
     pub average: Option<f64>,
@@ -602,7 +629,7 @@ impl DatapointValueDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -670,18 +697,35 @@ impl DatapointsToAlarmDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAlarmsInput {
     /// <p>The alarms to be deleted.</p>
     pub alarm_names: Vec<String>,
 }
 
+impl Validate for DeleteAlarmsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "alarm_names"),
+            self.alarm_names.len(),
+            None,
+            Some(100),
+        );
+        for (i, item) in self.alarm_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "alarm_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+        }
+    }
+}
+
 /// Serialize `DeleteAlarmsInput` contents to a `SignedRequest`.
 struct DeleteAlarmsInputSerializer;
 impl DeleteAlarmsInputSerializer {
@@ -700,6 +744,8 @@ impl DeleteAlarmsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAnomalyDetectorInput {
     /// <p>The metric dimensions associated with the anomaly detection model to delete.</p>
     pub dimensions: Option<Vec<Dimension>>,
@@ -734,6 +780,8 @@ impl DeleteAnomalyDetectorInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAnomalyDetectorOutput {}
 
 struct DeleteAnomalyDetectorOutputDeserializer;
@@ -753,6 +801,8 @@ impl DeleteAnomalyDetectorOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDashboardsInput {
     /// <p>The dashboards to be deleted. This parameter is required.</p>
     pub dashboard_names: Vec<String>,
@@ -776,6 +826,8 @@ impl DeleteDashboardsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDashboardsOutput {}
 
 struct DeleteDashboardsOutputDeserializer;
@@ -795,6 +847,8 @@ impl DeleteDashboardsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAlarmHistoryInput {
     /// <p>The name of the alarm.</p>
     pub alarm_name: Option<String>,
@@ -810,6 +864,22 @@ pub struct DescribeAlarmHistoryInput {
     pub start_date: Option<String>,
 }
 
+impl Validate for DescribeAlarmHistoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.alarm_name {
+            errors.check_length(
+                &field_path(path, "alarm_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.max_records {
+            errors.check_range(&field_path(path, "max_records"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 /// Serialize `DescribeAlarmHistoryInput` contents to a `SignedRequest`.
 struct DescribeAlarmHistoryInputSerializer;
 impl DescribeAlarmHistoryInputSerializer {
@@ -841,6 +911,8 @@ impl DescribeAlarmHistoryInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAlarmHistoryOutput {
     /// <p>The alarm histories, in JSON format.</p>
     pub alarm_history_items: Option<Vec<AlarmHistoryItem>>,
@@ -877,6 +949,8 @@ impl DescribeAlarmHistoryOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAlarmsForMetricInput {
     /// <p>The dimensions associated with the metric. If the metric has any associated dimensions, you must specify them in order for the call to succeed.</p>
     pub dimensions: Option<Vec<Dimension>>,
@@ -894,6 +968,34 @@ pub struct DescribeAlarmsForMetricInput {
     pub unit: Option<String>,
 }
 
+impl Validate for DescribeAlarmsForMetricInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dimensions {
+            errors.check_length(&field_path(path, "dimensions"), value.len(), None, Some(30));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dimensions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "metric_name"),
+            self.metric_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "namespace"),
+            self.namespace.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "namespace"), &self.namespace, "[^:].*");
+        if let Some(ref value) = self.period {
+            errors.check_range(&field_path(path, "period"), *value, Some(1), None);
+        }
+    }
+}
+
 /// Serialize `DescribeAlarmsForMetricInput` contents to a `SignedRequest`.
 struct DescribeAlarmsForMetricInputSerializer;
 impl DescribeAlarmsForMetricInputSerializer {
@@ -928,6 +1030,8 @@ impl DescribeAlarmsForMetricInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAlarmsForMetricOutput {
     /// <p>The information for each alarm with the specified metric.</p>
     pub metric_alarms: Option<Vec<MetricAlarm>>,
@@ -958,6 +1062,8 @@ impl DescribeAlarmsForMetricOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAlarmsInput {
     /// <p>The action name prefix.</p>
     pub action_prefix: Option<String>,
@@ -973,6 +1079,42 @@ pub struct DescribeAlarmsInput {
     pub state_value: Option<String>,
 }
 
+impl Validate for DescribeAlarmsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.action_prefix {
+            errors.check_length(
+                &field_path(path, "action_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.alarm_name_prefix {
+            errors.check_length(
+                &field_path(path, "alarm_name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.alarm_names {
+            errors.check_length(
+                &field_path(path, "alarm_names"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "alarm_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            }
+        }
+        if let Some(ref value) = self.max_records {
+            errors.check_range(&field_path(path, "max_records"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 /// Serialize `DescribeAlarmsInput` contents to a `SignedRequest`.
 struct DescribeAlarmsInputSerializer;
 impl DescribeAlarmsInputSerializer {
@@ -1008,6 +1150,8 @@ impl DescribeAlarmsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAlarmsOutput {
     /// <p>The information for the specified alarms.</p>
     pub metric_alarms: Option<Vec<MetricAlarm>>,
@@ -1039,6 +1183,8 @@ impl DescribeAlarmsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAnomalyDetectorsInput {
     /// <p>Limits the results to only the anomaly detection models that are associated with the specified metric dimensions. If there are multiple metrics that have these dimensions and have anomaly detection models associated, they're all returned.</p>
     pub dimensions: Option<Vec<Dimension>>,
@@ -1052,6 +1198,38 @@ pub struct DescribeAnomalyDetectorsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAnomalyDetectorsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dimensions {
+            errors.check_length(&field_path(path, "dimensions"), value.len(), None, Some(30));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dimensions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.metric_name {
+            errors.check_length(
+                &field_path(path, "metric_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.namespace {
+            errors.check_length(
+                &field_path(path, "namespace"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "namespace"), &value, "[^:].*");
+        }
+    }
+}
+
 /// Serialize `DescribeAnomalyDetectorsInput` contents to a `SignedRequest`.
 struct DescribeAnomalyDetectorsInputSerializer;
 impl DescribeAnomalyDetectorsInputSerializer {
@@ -1084,6 +1262,8 @@ impl DescribeAnomalyDetectorsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAnomalyDetectorsOutput {
     /// <p>The list of anomaly detection models returned by the operation.</p>
     pub anomaly_detectors: Option<Vec<AnomalyDetector>>,
@@ -1121,6 +1301,8 @@ impl DescribeAnomalyDetectorsOutputDeserializer {
 }
 /// <p>Expands the identity of a metric.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Dimension {
     /// <p>The name of the dimension.</p>
     pub name: String,
@@ -1128,6 +1310,23 @@ pub struct Dimension {
     pub value: String,
 }
 
+impl Validate for Dimension {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 struct DimensionDeserializer;
 impl DimensionDeserializer {
     #[allow(unused_variables)]
@@ -1166,6 +1365,8 @@ impl DimensionSerializer {
 
 /// <p>Represents filters for a dimension.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DimensionFilter {
     /// <p>The dimension name to be matched.</p>
     pub name: String,
@@ -1173,6 +1374,25 @@ pub struct DimensionFilter {
     pub value: Option<String>,
 }
 
+impl Validate for DimensionFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// Serialize `DimensionFilter` contents to a `SignedRequest`.
 struct DimensionFilterSerializer;
 impl DimensionFilterSerializer {
@@ -1252,11 +1472,28 @@ impl DimensionsSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableAlarmActionsInput {
     /// <p>The names of the alarms.</p>
     pub alarm_names: Vec<String>,
 }
 
+impl Validate for DisableAlarmActionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "alarm_names"),
+            self.alarm_names.len(),
+            None,
+            Some(100),
+        );
+        for (i, item) in self.alarm_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "alarm_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+        }
+    }
+}
+
 /// Serialize `DisableAlarmActionsInput` contents to a `SignedRequest`.
 struct DisableAlarmActionsInputSerializer;
 impl DisableAlarmActionsInputSerializer {
@@ -1275,11 +1512,28 @@ impl DisableAlarmActionsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableAlarmActionsInput {
     /// <p>The names of the alarms.</p>
     pub alarm_names: Vec<String>,
 }
 
+impl Validate for EnableAlarmActionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "alarm_names"),
+            self.alarm_names.len(),
+            None,
+            Some(100),
+        );
+        for (i, item) in self.alarm_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "alarm_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+        }
+    }
+}
+
 /// Serialize `EnableAlarmActionsInput` contents to a `SignedRequest`.
 struct EnableAlarmActionsInputSerializer;
 impl EnableAlarmActionsInputSerializer {
@@ -1313,7 +1567,7 @@ impl EvaluationPeriodsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1343,6 +1597,8 @@ impl ExtendedStatisticsSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDashboardInput {
     /// <p>The name of the dashboard to be described.</p>
     pub dashboard_name: String,
@@ -1365,6 +1621,8 @@ impl GetDashboardInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDashboardOutput {
     /// <p>The Amazon Resource Name (ARN) of the dashboard.</p>
     pub dashboard_arn: Option<String>,
@@ -1408,6 +1666,8 @@ impl GetDashboardOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMetricDataInput {
     /// <p>The time stamp indicating the latest data to be returned.</p> <p>The value specified is exclusive; results include data points up to the specified time stamp.</p> <p>For better performance, specify <code>StartTime</code> and <code>EndTime</code> values that align with the value of the metric's <code>Period</code> and sync up with the beginning and end of an hour. For example, if the <code>Period</code> of a metric is 5 minutes, specifying 12:05 or 12:30 as <code>EndTime</code> can get a faster response from CloudWatch than setting 12:07 or 12:29 as the <code>EndTime</code>.</p>
     pub end_time: String,
@@ -1423,6 +1683,15 @@ pub struct GetMetricDataInput {
     pub start_time: String,
 }
 
+impl Validate for GetMetricDataInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.metric_data_queries.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "metric_data_queries"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `GetMetricDataInput` contents to a `SignedRequest`.
 struct GetMetricDataInputSerializer;
 impl GetMetricDataInputSerializer {
@@ -1452,6 +1721,8 @@ impl GetMetricDataInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMetricDataOutput {
     /// <p>Contains a message about this <code>GetMetricData</code> operation, if the operation results in such a message. An example of a message that may be returned is <code>Maximum number of allowed metrics exceeded</code>. If there is a message, as much of the operation as possible is still executed.</p> <p>A message appears here only if it is related to the global <code>GetMetricData</code> operation. Any message about a specific metric returned by the operation appears in the <code>MetricDataResult</code> object returned for that metric.</p>
     pub messages: Option<Vec<MessageData>>,
@@ -1490,6 +1761,8 @@ impl GetMetricDataOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMetricStatisticsInput {
     /// <p>The dimensions. If the metric contains multiple dimensions, you must include a value for each dimension. CloudWatch treats each unique combination of dimensions as a separate metric. If a specific combination of dimensions was not published, you can't retrieve statistics for it. You must specify the same dimensions that were used when the metrics were created. For an example, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/cloudwatch_concepts.html#dimension-combinations">Dimension Combinations</a> in the <i>Amazon CloudWatch User Guide</i>. For more information about specifying dimensions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publishing Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p>
     pub dimensions: Option<Vec<Dimension>>,
@@ -1511,6 +1784,48 @@ pub struct GetMetricStatisticsInput {
     pub unit: Option<String>,
 }
 
+impl Validate for GetMetricStatisticsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dimensions {
+            errors.check_length(&field_path(path, "dimensions"), value.len(), None, Some(30));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dimensions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.extended_statistics {
+            errors.check_length(
+                &field_path(path, "extended_statistics"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "metric_name"),
+            self.metric_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "namespace"),
+            self.namespace.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "namespace"), &self.namespace, "[^:].*");
+        errors.check_range(&field_path(path, "period"), self.period, Some(1), None);
+        if let Some(ref value) = self.statistics {
+            errors.check_length(
+                &field_path(path, "statistics"),
+                value.len(),
+                Some(1),
+                Some(5),
+            );
+        }
+    }
+}
+
 /// Serialize `GetMetricStatisticsInput` contents to a `SignedRequest`.
 struct GetMetricStatisticsInputSerializer;
 impl GetMetricStatisticsInputSerializer {
@@ -1553,6 +1868,8 @@ impl GetMetricStatisticsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMetricStatisticsOutput {
     /// <p>The data points for the specified metric.</p>
     pub datapoints: Option<Vec<Datapoint>>,
@@ -1588,6 +1905,8 @@ impl GetMetricStatisticsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMetricWidgetImageInput {
     /// <p>A JSON string that defines the bitmap graph to be retrieved. The string includes the metrics to include in the graph, statistics, annotations, title, axis limits, and so on. You can include only one <code>MetricWidget</code> parameter in each <code>GetMetricWidgetImage</code> call.</p> <p>For more information about the syntax of <code>MetricWidget</code> see <a>CloudWatch-Metric-Widget-Structure</a>.</p> <p>If any metric on the graph could not load all the requested data points, an orange triangle with an exclamation point appears next to the graph legend.</p>
     pub metric_widget: String,
@@ -1612,8 +1931,18 @@ impl GetMetricWidgetImageInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMetricWidgetImageOutput {
     /// <p>The image of the graph, in the output format specified.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(
+            deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
+            serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
+            default
+        )
+    )]
     pub metric_widget_image: Option<bytes::Bytes>,
 }
 
@@ -1687,6 +2016,8 @@ impl LastModifiedDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDashboardsInput {
     /// <p>If you specify this parameter, only the dashboards with names starting with the specified string are listed. The maximum length is 255, and valid characters are A-Z, a-z, 0-9, ".", "-", and "_". </p>
     pub dashboard_name_prefix: Option<String>,
@@ -1716,6 +2047,8 @@ impl ListDashboardsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDashboardsOutput {
     /// <p>The list of matching dashboards.</p>
     pub dashboard_entries: Option<Vec<DashboardEntry>>,
@@ -1747,6 +2080,8 @@ impl ListDashboardsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMetricsInput {
     /// <p>The dimensions to filter against.</p>
     pub dimensions: Option<Vec<DimensionFilter>>,
@@ -1758,6 +2093,35 @@ pub struct ListMetricsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListMetricsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dimensions {
+            errors.check_length(&field_path(path, "dimensions"), value.len(), None, Some(10));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dimensions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.metric_name {
+            errors.check_length(
+                &field_path(path, "metric_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.namespace {
+            errors.check_length(
+                &field_path(path, "namespace"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "namespace"), &value, "[^:].*");
+        }
+    }
+}
+
 /// Serialize `ListMetricsInput` contents to a `SignedRequest`.
 struct ListMetricsInputSerializer;
 impl ListMetricsInputSerializer {
@@ -1787,6 +2151,8 @@ impl ListMetricsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMetricsOutput {
     /// <p>The metrics.</p>
     pub metrics: Option<Vec<Metric>>,
@@ -1818,11 +2184,24 @@ impl ListMetricsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>The ARN of the CloudWatch resource that you want to view tags for. For more information on ARN format, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-cloudwatch">Example ARNs</a> in the <i>Amazon Web Services General Reference</i>.</p>
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 /// Serialize `ListTagsForResourceInput` contents to a `SignedRequest`.
 struct ListTagsForResourceInputSerializer;
 impl ListTagsForResourceInputSerializer {
@@ -1837,6 +2216,8 @@ impl ListTagsForResourceInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceOutput {
     /// <p>The list of tag keys and values associated with the resource you specified.</p>
     pub tags: Option<Vec<Tag>>,
@@ -1879,6 +2260,8 @@ impl MessageDeserializer {
 }
 /// <p>A message returned by the <code>GetMetricData</code>API, including a code and a description.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MessageData {
     /// <p>The error code or status code associated with the message.</p>
     pub code: Option<String>,
@@ -1931,6 +2314,8 @@ impl MessageDataValueDeserializer {
 }
 /// <p>Represents a specific metric.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Metric {
     /// <p>The dimensions for the metric.</p>
     pub dimensions: Option<Vec<Dimension>>,
@@ -1940,6 +2325,35 @@ pub struct Metric {
     pub namespace: Option<String>,
 }
 
+impl Validate for Metric {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dimensions {
+            errors.check_length(&field_path(path, "dimensions"), value.len(), None, Some(30));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dimensions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.metric_name {
+            errors.check_length(
+                &field_path(path, "metric_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.namespace {
+            errors.check_length(
+                &field_path(path, "namespace"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "namespace"), &value, "[^:].*");
+        }
+    }
+}
+
 struct MetricDeserializer;
 impl MetricDeserializer {
     #[allow(unused_variables)]
@@ -1992,6 +2406,8 @@ impl MetricSerializer {
 
 /// <p>Represents an alarm.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricAlarm {
     /// <p>Indicates whether actions should be executed during any changes to the alarm state.</p>
     pub actions_enabled: Option<bool>,
@@ -2256,6 +2672,8 @@ impl MetricDataQueriesSerializer {
 
 /// <p>This structure is used in both <code>GetMetricData</code> and <code>PutMetricAlarm</code>. The supported use of this structure is different for those two operations.</p> <p>When used in <code>GetMetricData</code>, it indicates the metric data to return, and whether this call is just retrieving a batch set of data for one metric, or is performing a math expression on metric data. A single <code>GetMetricData</code> call can include up to 100 <code>MetricDataQuery</code> structures.</p> <p>When used in <code>PutMetricAlarm</code>, it enables you to create an alarm based on a metric math expression. Each <code>MetricDataQuery</code> in the array specifies either a metric to retrieve, or a math expression to be performed on retrieved metrics. A single <code>PutMetricAlarm</code> call can include up to 20 <code>MetricDataQuery</code> structures in the array. The 20 structures can include as many as 10 structures that contain a <code>MetricStat</code> parameter to retrieve a metric, and as many as 10 structures that contain the <code>Expression</code> parameter to perform a math expression. Of those <code>Expression</code> structures, one must have <code>True</code> as the value for <code>ReturnData</code>. The result of this expression is the value the alarm watches.</p> <p>Any expression used in a <code>PutMetricAlarm</code> operation must return a single time series. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/using-metric-math.html#metric-math-syntax">Metric Math Syntax and Functions</a> in the <i>Amazon CloudWatch User Guide</i>.</p> <p>Some of the parameters of this structure also have different uses whether you are using this structure in a <code>GetMetricData</code> operation or a <code>PutMetricAlarm</code> operation. These differences are explained in the following parameter list.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricDataQuery {
     /// <p>The math expression to be performed on the returned data, if this object is performing a math expression. This expression can use the <code>Id</code> of the other metrics to refer to those metrics, and can also use the <code>Id</code> of other expressions to use the result of those expressions. For more information about metric math expressions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/using-metric-math.html#metric-math-syntax">Metric Math Syntax and Functions</a> in the <i>Amazon CloudWatch User Guide</i>.</p> <p>Within each MetricDataQuery object, you must specify either <code>Expression</code> or <code>MetricStat</code> but not both.</p>
     pub expression: Option<String>,
@@ -2269,6 +2687,28 @@ pub struct MetricDataQuery {
     pub return_data: Option<bool>,
 }
 
+impl Validate for MetricDataQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expression {
+            errors.check_length(
+                &field_path(path, "expression"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        if let Some(ref value) = self.metric_stat {
+            value.validate_at(&field_path(path, "metric_stat"), errors);
+        }
+    }
+}
+
 struct MetricDataQueryDeserializer;
 impl MetricDataQueryDeserializer {
     #[allow(unused_variables)]
@@ -2336,6 +2776,8 @@ impl MetricDataQuerySerializer {
 
 /// <p>A <code>GetMetricData</code> call returns an array of <code>MetricDataResult</code> structures. Each of these structures includes the data points for that metric, along with the timestamps of those data points and other identifying information.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricDataResult {
     /// <p>The short name you specified to represent this metric.</p>
     pub id: Option<String>,
@@ -2427,6 +2869,8 @@ impl MetricDataResultsDeserializer {
 }
 /// <p>Encapsulates the information sent to either create a metric or add new values to be aggregated into an existing metric.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricDatum {
     /// <p>Array of numbers that is used along with the <code>Values</code> array. Each number in the <code>Count</code> array is the number of times the corresponding value in the <code>Values</code> array occurred during the period. </p> <p>If you omit the <code>Counts</code> array, the default of 1 is used as the value for each count. If you include a <code>Counts</code> array, it must include the same amount of values as the <code>Values</code> array.</p>
     pub counts: Option<Vec<f64>>,
@@ -2448,6 +2892,32 @@ pub struct MetricDatum {
     pub values: Option<Vec<f64>>,
 }
 
+impl Validate for MetricDatum {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dimensions {
+            errors.check_length(&field_path(path, "dimensions"), value.len(), None, Some(30));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dimensions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "metric_name"),
+            self.metric_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        if let Some(ref value) = self.storage_resolution {
+            errors.check_range(
+                &field_path(path, "storage_resolution"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// Serialize `MetricDatum` contents to a `SignedRequest`.
 struct MetricDatumSerializer;
 impl MetricDatumSerializer {
@@ -2539,6 +3009,8 @@ impl MetricNameDeserializer {
 }
 /// <p>This structure defines the metric to be returned, along with the statistics, period, and units.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricStat {
     /// <p>The metric to return, including the metric name, namespace, and dimensions.</p>
     pub metric: Metric,
@@ -2550,6 +3022,13 @@ pub struct MetricStat {
     pub unit: Option<String>,
 }
 
+impl Validate for MetricStat {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.metric.validate_at(&field_path(path, "metric"), errors);
+        errors.check_range(&field_path(path, "period"), self.period, Some(1), None);
+    }
+}
+
 struct MetricStatDeserializer;
 impl MetricStatDeserializer {
     #[allow(unused_variables)]
@@ -2654,13 +3133,15 @@ impl PeriodDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutAnomalyDetectorInput {
     /// <p>The configuration specifies details about how the anomaly detection model is to be trained, including time ranges to exclude when training and updating the model. You can specify as many as 10 time ranges.</p> <p>The configuration can also include the time zone to use for the metric.</p> <p>You can in</p>
     pub configuration: Option<AnomalyDetectorConfiguration>,
@@ -2674,6 +3155,14 @@ pub struct PutAnomalyDetectorInput {
     pub stat: String,
 }
 
+impl Validate for PutAnomalyDetectorInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.configuration {
+            value.validate_at(&field_path(path, "configuration"), errors);
+        }
+    }
+}
+
 /// Serialize `PutAnomalyDetectorInput` contents to a `SignedRequest`.
 struct PutAnomalyDetectorInputSerializer;
 impl PutAnomalyDetectorInputSerializer {
@@ -2704,6 +3193,8 @@ impl PutAnomalyDetectorInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutAnomalyDetectorOutput {}
 
 struct PutAnomalyDetectorOutputDeserializer;
@@ -2723,6 +3214,8 @@ impl PutAnomalyDetectorOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutDashboardInput {
     /// <p>The detailed information about the dashboard in JSON format, including the widgets to include and their location on the dashboard. This parameter is required.</p> <p>For more information about the syntax, see <a>CloudWatch-Dashboard-Body-Structure</a>.</p>
     pub dashboard_body: String,
@@ -2751,6 +3244,8 @@ impl PutDashboardInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutDashboardOutput {
     /// <p>If the input for <code>PutDashboard</code> was correct and the dashboard was successfully created or modified, this result is empty.</p> <p>If this result includes only warning messages, then the input was valid enough for the dashboard to be created or modified, but some elements of the dashboard may not render.</p> <p>If this result includes error messages, the input was not valid and the operation failed.</p>
     pub dashboard_validation_messages: Option<Vec<DashboardValidationMessage>>,
@@ -2780,6 +3275,8 @@ impl PutDashboardOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutMetricAlarmInput {
     /// <p>Indicates whether actions should be executed during any changes to the alarm state. The default is <code>TRUE</code>.</p>
     pub actions_enabled: Option<bool>,
@@ -2827,6 +3324,133 @@ pub struct PutMetricAlarmInput {
     pub unit: Option<String>,
 }
 
+impl Validate for PutMetricAlarmInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.alarm_actions {
+            errors.check_length(
+                &field_path(path, "alarm_actions"),
+                value.len(),
+                None,
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "alarm_actions"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            }
+        }
+        if let Some(ref value) = self.alarm_description {
+            errors.check_length(
+                &field_path(path, "alarm_description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "alarm_name"),
+            self.alarm_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        if let Some(ref value) = self.datapoints_to_alarm {
+            errors.check_range(
+                &field_path(path, "datapoints_to_alarm"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.dimensions {
+            errors.check_length(&field_path(path, "dimensions"), value.len(), None, Some(30));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dimensions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.evaluate_low_sample_count_percentile {
+            errors.check_length(
+                &field_path(path, "evaluate_low_sample_count_percentile"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        errors.check_range(
+            &field_path(path, "evaluation_periods"),
+            self.evaluation_periods,
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.insufficient_data_actions {
+            errors.check_length(
+                &field_path(path, "insufficient_data_actions"),
+                value.len(),
+                None,
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "insufficient_data_actions"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            }
+        }
+        if let Some(ref value) = self.metric_name {
+            errors.check_length(
+                &field_path(path, "metric_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.metrics {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "metrics"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.namespace {
+            errors.check_length(
+                &field_path(path, "namespace"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "namespace"), &value, "[^:].*");
+        }
+        if let Some(ref value) = self.ok_actions {
+            errors.check_length(&field_path(path, "ok_actions"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "ok_actions"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            }
+        }
+        if let Some(ref value) = self.period {
+            errors.check_range(&field_path(path, "period"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.threshold_metric_id {
+            errors.check_length(
+                &field_path(path, "threshold_metric_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.treat_missing_data {
+            errors.check_length(
+                &field_path(path, "treat_missing_data"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 /// Serialize `PutMetricAlarmInput` contents to a `SignedRequest`.
 struct PutMetricAlarmInputSerializer;
 impl PutMetricAlarmInputSerializer {
@@ -2929,6 +3553,8 @@ impl PutMetricAlarmInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutMetricDataInput {
     /// <p>The data for the metric. The array can include no more than 20 metrics per call.</p>
     pub metric_data: Vec<MetricDatum>,
@@ -2936,6 +3562,22 @@ pub struct PutMetricDataInput {
     pub namespace: String,
 }
 
+impl Validate for PutMetricDataInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.metric_data.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "metric_data"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "namespace"),
+            self.namespace.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "namespace"), &self.namespace, "[^:].*");
+    }
+}
+
 /// Serialize `PutMetricDataInput` contents to a `SignedRequest`.
 struct PutMetricDataInputSerializer;
 impl PutMetricDataInputSerializer {
@@ -2956,6 +3598,8 @@ impl PutMetricDataInputSerializer {
 
 /// <p>Specifies one range of days or times to exclude from use for training an anomaly detection model.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Range {
     /// <p>The end time of the range to exclude. The format is <code>yyyy-MM-dd'T'HH:mm:ss</code>. For example, <code>2019-07-01T23:59:59</code>.</p>
     pub end_time: String,
@@ -3041,13 +3685,15 @@ impl ReturnDataDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetAlarmStateInput {
     /// <p>The name for the alarm. This name must be unique within the AWS account. The maximum length is 255 characters.</p>
     pub alarm_name: String,
@@ -3059,6 +3705,31 @@ pub struct SetAlarmStateInput {
     pub state_value: String,
 }
 
+impl Validate for SetAlarmStateInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "alarm_name"),
+            self.alarm_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "state_reason"),
+            self.state_reason.chars().count(),
+            None,
+            Some(1023),
+        );
+        if let Some(ref value) = self.state_reason_data {
+            errors.check_length(
+                &field_path(path, "state_reason_data"),
+                value.chars().count(),
+                None,
+                Some(4000),
+            );
+        }
+    }
+}
+
 /// Serialize `SetAlarmStateInput` contents to a `SignedRequest`.
 struct SetAlarmStateInputSerializer;
 impl SetAlarmStateInputSerializer {
@@ -3082,7 +3753,7 @@ impl SizeDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3156,6 +3827,8 @@ impl StatisticDeserializer {
 }
 /// <p>Represents a set of statistics that describes a specific metric. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StatisticSet {
     /// <p>The maximum value of the sample set.</p>
     pub maximum: f64,
@@ -3207,6 +3880,8 @@ impl StatusCodeDeserializer {
 }
 /// <p>A key-value pair associated with a CloudWatch resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>A string that you can use to assign a value. The combination of tag keys and values can help you organize and categorize your resources.</p>
     pub key: String,
@@ -3214,6 +3889,23 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
@@ -3300,6 +3992,8 @@ impl TagListSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p>The ARN of the CloudWatch resource that you're adding tags to. For more information on ARN format, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-cloudwatch">Example ARNs</a> in the <i>Amazon Web Services General Reference</i>.</p>
     pub resource_arn: String,
@@ -3307,6 +4001,21 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `TagResourceInput` contents to a `SignedRequest`.
 struct TagResourceInputSerializer;
 impl TagResourceInputSerializer {
@@ -3322,6 +4031,8 @@ impl TagResourceInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceOutput {}
 
 struct TagResourceOutputDeserializer;
@@ -3356,7 +4067,7 @@ impl ThresholdDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3402,6 +4113,8 @@ impl TreatMissingDataDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p>The ARN of the CloudWatch resource that you're removing tags from. For more information on ARN format, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-cloudwatch">Example ARNs</a> in the <i>Amazon Web Services General Reference</i>.</p>
     pub resource_arn: String,
@@ -3409,6 +4122,21 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 /// Serialize `UntagResourceInput` contents to a `SignedRequest`.
 struct UntagResourceInputSerializer;
 impl UntagResourceInputSerializer {
@@ -3424,6 +4152,8 @@ impl UntagResourceInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceOutput {}
 
 struct UntagResourceOutputDeserializer;
@@ -4874,81 +5604,485 @@ pub trait CloudWatch {
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError>;
 }
-/// A client for the CloudWatch API.
-#[derive(Clone)]
-pub struct CloudWatchClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CloudWatchClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CloudWatchClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CloudWatchClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchClient {
-        CloudWatchClient { client, region }
-    }
-}
-
-impl CloudWatch for CloudWatchClient {
-    /// <p>Deletes the specified alarms. You can delete up to 50 alarms in one operation. In the event of an error, no alarms are deleted.</p>
+const _: Option<&dyn CloudWatch> = None;
+impl<'a, T: CloudWatch + ?Sized> CloudWatch for &'a T {
     fn delete_alarms(&self, input: DeleteAlarmsInput) -> RusotoFuture<(), DeleteAlarmsError> {
-        let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteAlarms");
-        params.put("Version", "2010-08-01");
-        DeleteAlarmsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAlarmsError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_alarms(input)
     }
-
-    /// <p>Deletes the specified anomaly detection model from your account.</p>
     fn delete_anomaly_detector(
         &self,
         input: DeleteAnomalyDetectorInput,
     ) -> RusotoFuture<DeleteAnomalyDetectorOutput, DeleteAnomalyDetectorError> {
-        let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteAnomalyDetector");
-        params.put("Version", "2010-08-01");
-        DeleteAnomalyDetectorInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        (**self).delete_anomaly_detector(input)
+    }
+    fn delete_dashboards(
+        &self,
+        input: DeleteDashboardsInput,
+    ) -> RusotoFuture<DeleteDashboardsOutput, DeleteDashboardsError> {
+        (**self).delete_dashboards(input)
+    }
+    fn describe_alarm_history(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> RusotoFuture<DescribeAlarmHistoryOutput, DescribeAlarmHistoryError> {
+        (**self).describe_alarm_history(input)
+    }
+    fn describe_alarms(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> RusotoFuture<DescribeAlarmsOutput, DescribeAlarmsError> {
+        (**self).describe_alarms(input)
+    }
+    fn describe_alarms_for_metric(
+        &self,
+        input: DescribeAlarmsForMetricInput,
+    ) -> RusotoFuture<DescribeAlarmsForMetricOutput, DescribeAlarmsForMetricError> {
+        (**self).describe_alarms_for_metric(input)
+    }
+    fn describe_anomaly_detectors(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> RusotoFuture<DescribeAnomalyDetectorsOutput, DescribeAnomalyDetectorsError> {
+        (**self).describe_anomaly_detectors(input)
+    }
+    fn disable_alarm_actions(
+        &self,
+        input: DisableAlarmActionsInput,
+    ) -> RusotoFuture<(), DisableAlarmActionsError> {
+        (**self).disable_alarm_actions(input)
+    }
+    fn enable_alarm_actions(
+        &self,
+        input: EnableAlarmActionsInput,
+    ) -> RusotoFuture<(), EnableAlarmActionsError> {
+        (**self).enable_alarm_actions(input)
+    }
+    fn get_dashboard(
+        &self,
+        input: GetDashboardInput,
+    ) -> RusotoFuture<GetDashboardOutput, GetDashboardError> {
+        (**self).get_dashboard(input)
+    }
+    fn get_metric_data(
+        &self,
+        input: GetMetricDataInput,
+    ) -> RusotoFuture<GetMetricDataOutput, GetMetricDataError> {
+        (**self).get_metric_data(input)
+    }
+    fn get_metric_statistics(
+        &self,
+        input: GetMetricStatisticsInput,
+    ) -> RusotoFuture<GetMetricStatisticsOutput, GetMetricStatisticsError> {
+        (**self).get_metric_statistics(input)
+    }
+    fn get_metric_widget_image(
+        &self,
+        input: GetMetricWidgetImageInput,
+    ) -> RusotoFuture<GetMetricWidgetImageOutput, GetMetricWidgetImageError> {
+        (**self).get_metric_widget_image(input)
+    }
+    fn list_dashboards(
+        &self,
+        input: ListDashboardsInput,
+    ) -> RusotoFuture<ListDashboardsOutput, ListDashboardsError> {
+        (**self).list_dashboards(input)
+    }
+    fn list_metrics(
+        &self,
+        input: ListMetricsInput,
+    ) -> RusotoFuture<ListMetricsOutput, ListMetricsError> {
+        (**self).list_metrics(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_anomaly_detector(
+        &self,
+        input: PutAnomalyDetectorInput,
+    ) -> RusotoFuture<PutAnomalyDetectorOutput, PutAnomalyDetectorError> {
+        (**self).put_anomaly_detector(input)
+    }
+    fn put_dashboard(
+        &self,
+        input: PutDashboardInput,
+    ) -> RusotoFuture<PutDashboardOutput, PutDashboardError> {
+        (**self).put_dashboard(input)
+    }
+    fn put_metric_alarm(
+        &self,
+        input: PutMetricAlarmInput,
+    ) -> RusotoFuture<(), PutMetricAlarmError> {
+        (**self).put_metric_alarm(input)
+    }
+    fn put_metric_data(&self, input: PutMetricDataInput) -> RusotoFuture<(), PutMetricDataError> {
+        (**self).put_metric_data(input)
+    }
+    fn set_alarm_state(&self, input: SetAlarmStateInput) -> RusotoFuture<(), SetAlarmStateError> {
+        (**self).set_alarm_state(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: CloudWatch + ?Sized> CloudWatch for Box<T> {
+    fn delete_alarms(&self, input: DeleteAlarmsInput) -> RusotoFuture<(), DeleteAlarmsError> {
+        (**self).delete_alarms(input)
+    }
+    fn delete_anomaly_detector(
+        &self,
+        input: DeleteAnomalyDetectorInput,
+    ) -> RusotoFuture<DeleteAnomalyDetectorOutput, DeleteAnomalyDetectorError> {
+        (**self).delete_anomaly_detector(input)
+    }
+    fn delete_dashboards(
+        &self,
+        input: DeleteDashboardsInput,
+    ) -> RusotoFuture<DeleteDashboardsOutput, DeleteDashboardsError> {
+        (**self).delete_dashboards(input)
+    }
+    fn describe_alarm_history(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> RusotoFuture<DescribeAlarmHistoryOutput, DescribeAlarmHistoryError> {
+        (**self).describe_alarm_history(input)
+    }
+    fn describe_alarms(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> RusotoFuture<DescribeAlarmsOutput, DescribeAlarmsError> {
+        (**self).describe_alarms(input)
+    }
+    fn describe_alarms_for_metric(
+        &self,
+        input: DescribeAlarmsForMetricInput,
+    ) -> RusotoFuture<DescribeAlarmsForMetricOutput, DescribeAlarmsForMetricError> {
+        (**self).describe_alarms_for_metric(input)
+    }
+    fn describe_anomaly_detectors(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> RusotoFuture<DescribeAnomalyDetectorsOutput, DescribeAnomalyDetectorsError> {
+        (**self).describe_anomaly_detectors(input)
+    }
+    fn disable_alarm_actions(
+        &self,
+        input: DisableAlarmActionsInput,
+    ) -> RusotoFuture<(), DisableAlarmActionsError> {
+        (**self).disable_alarm_actions(input)
+    }
+    fn enable_alarm_actions(
+        &self,
+        input: EnableAlarmActionsInput,
+    ) -> RusotoFuture<(), EnableAlarmActionsError> {
+        (**self).enable_alarm_actions(input)
+    }
+    fn get_dashboard(
+        &self,
+        input: GetDashboardInput,
+    ) -> RusotoFuture<GetDashboardOutput, GetDashboardError> {
+        (**self).get_dashboard(input)
+    }
+    fn get_metric_data(
+        &self,
+        input: GetMetricDataInput,
+    ) -> RusotoFuture<GetMetricDataOutput, GetMetricDataError> {
+        (**self).get_metric_data(input)
+    }
+    fn get_metric_statistics(
+        &self,
+        input: GetMetricStatisticsInput,
+    ) -> RusotoFuture<GetMetricStatisticsOutput, GetMetricStatisticsError> {
+        (**self).get_metric_statistics(input)
+    }
+    fn get_metric_widget_image(
+        &self,
+        input: GetMetricWidgetImageInput,
+    ) -> RusotoFuture<GetMetricWidgetImageOutput, GetMetricWidgetImageError> {
+        (**self).get_metric_widget_image(input)
+    }
+    fn list_dashboards(
+        &self,
+        input: ListDashboardsInput,
+    ) -> RusotoFuture<ListDashboardsOutput, ListDashboardsError> {
+        (**self).list_dashboards(input)
+    }
+    fn list_metrics(
+        &self,
+        input: ListMetricsInput,
+    ) -> RusotoFuture<ListMetricsOutput, ListMetricsError> {
+        (**self).list_metrics(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_anomaly_detector(
+        &self,
+        input: PutAnomalyDetectorInput,
+    ) -> RusotoFuture<PutAnomalyDetectorOutput, PutAnomalyDetectorError> {
+        (**self).put_anomaly_detector(input)
+    }
+    fn put_dashboard(
+        &self,
+        input: PutDashboardInput,
+    ) -> RusotoFuture<PutDashboardOutput, PutDashboardError> {
+        (**self).put_dashboard(input)
+    }
+    fn put_metric_alarm(
+        &self,
+        input: PutMetricAlarmInput,
+    ) -> RusotoFuture<(), PutMetricAlarmError> {
+        (**self).put_metric_alarm(input)
+    }
+    fn put_metric_data(&self, input: PutMetricDataInput) -> RusotoFuture<(), PutMetricDataError> {
+        (**self).put_metric_data(input)
+    }
+    fn set_alarm_state(&self, input: SetAlarmStateInput) -> RusotoFuture<(), SetAlarmStateError> {
+        (**self).set_alarm_state(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: CloudWatch + ?Sized> CloudWatch for ::std::sync::Arc<T> {
+    fn delete_alarms(&self, input: DeleteAlarmsInput) -> RusotoFuture<(), DeleteAlarmsError> {
+        (**self).delete_alarms(input)
+    }
+    fn delete_anomaly_detector(
+        &self,
+        input: DeleteAnomalyDetectorInput,
+    ) -> RusotoFuture<DeleteAnomalyDetectorOutput, DeleteAnomalyDetectorError> {
+        (**self).delete_anomaly_detector(input)
+    }
+    fn delete_dashboards(
+        &self,
+        input: DeleteDashboardsInput,
+    ) -> RusotoFuture<DeleteDashboardsOutput, DeleteDashboardsError> {
+        (**self).delete_dashboards(input)
+    }
+    fn describe_alarm_history(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> RusotoFuture<DescribeAlarmHistoryOutput, DescribeAlarmHistoryError> {
+        (**self).describe_alarm_history(input)
+    }
+    fn describe_alarms(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> RusotoFuture<DescribeAlarmsOutput, DescribeAlarmsError> {
+        (**self).describe_alarms(input)
+    }
+    fn describe_alarms_for_metric(
+        &self,
+        input: DescribeAlarmsForMetricInput,
+    ) -> RusotoFuture<DescribeAlarmsForMetricOutput, DescribeAlarmsForMetricError> {
+        (**self).describe_alarms_for_metric(input)
+    }
+    fn describe_anomaly_detectors(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> RusotoFuture<DescribeAnomalyDetectorsOutput, DescribeAnomalyDetectorsError> {
+        (**self).describe_anomaly_detectors(input)
+    }
+    fn disable_alarm_actions(
+        &self,
+        input: DisableAlarmActionsInput,
+    ) -> RusotoFuture<(), DisableAlarmActionsError> {
+        (**self).disable_alarm_actions(input)
+    }
+    fn enable_alarm_actions(
+        &self,
+        input: EnableAlarmActionsInput,
+    ) -> RusotoFuture<(), EnableAlarmActionsError> {
+        (**self).enable_alarm_actions(input)
+    }
+    fn get_dashboard(
+        &self,
+        input: GetDashboardInput,
+    ) -> RusotoFuture<GetDashboardOutput, GetDashboardError> {
+        (**self).get_dashboard(input)
+    }
+    fn get_metric_data(
+        &self,
+        input: GetMetricDataInput,
+    ) -> RusotoFuture<GetMetricDataOutput, GetMetricDataError> {
+        (**self).get_metric_data(input)
+    }
+    fn get_metric_statistics(
+        &self,
+        input: GetMetricStatisticsInput,
+    ) -> RusotoFuture<GetMetricStatisticsOutput, GetMetricStatisticsError> {
+        (**self).get_metric_statistics(input)
+    }
+    fn get_metric_widget_image(
+        &self,
+        input: GetMetricWidgetImageInput,
+    ) -> RusotoFuture<GetMetricWidgetImageOutput, GetMetricWidgetImageError> {
+        (**self).get_metric_widget_image(input)
+    }
+    fn list_dashboards(
+        &self,
+        input: ListDashboardsInput,
+    ) -> RusotoFuture<ListDashboardsOutput, ListDashboardsError> {
+        (**self).list_dashboards(input)
+    }
+    fn list_metrics(
+        &self,
+        input: ListMetricsInput,
+    ) -> RusotoFuture<ListMetricsOutput, ListMetricsError> {
+        (**self).list_metrics(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_anomaly_detector(
+        &self,
+        input: PutAnomalyDetectorInput,
+    ) -> RusotoFuture<PutAnomalyDetectorOutput, PutAnomalyDetectorError> {
+        (**self).put_anomaly_detector(input)
+    }
+    fn put_dashboard(
+        &self,
+        input: PutDashboardInput,
+    ) -> RusotoFuture<PutDashboardOutput, PutDashboardError> {
+        (**self).put_dashboard(input)
+    }
+    fn put_metric_alarm(
+        &self,
+        input: PutMetricAlarmInput,
+    ) -> RusotoFuture<(), PutMetricAlarmError> {
+        (**self).put_metric_alarm(input)
+    }
+    fn put_metric_data(&self, input: PutMetricDataInput) -> RusotoFuture<(), PutMetricDataError> {
+        (**self).put_metric_data(input)
+    }
+    fn set_alarm_state(&self, input: SetAlarmStateInput) -> RusotoFuture<(), SetAlarmStateError> {
+        (**self).set_alarm_state(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// A client for the CloudWatch API.
+#[derive(Clone)]
+pub struct CloudWatchClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CloudWatchClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudWatchClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudWatchClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchClient {
+        CloudWatchClient { client, region }
+    }
+}
+
+impl CloudWatch for CloudWatchClient {
+    /// <p>Deletes the specified alarms. You can delete up to 50 alarms in one operation. In the event of an error, no alarms are deleted.</p>
+    fn delete_alarms(&self, input: DeleteAlarmsInput) -> RusotoFuture<(), DeleteAlarmsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteAlarms");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteAlarms");
+        params.put("Version", "2010-08-01");
+        DeleteAlarmsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAlarmsError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified anomaly detection model from your account.</p>
+    fn delete_anomaly_detector(
+        &self,
+        input: DeleteAnomalyDetectorInput,
+    ) -> RusotoFuture<DeleteAnomalyDetectorOutput, DeleteAnomalyDetectorError> {
+        let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteAnomalyDetector");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteAnomalyDetector");
+        params.put("Version", "2010-08-01");
+        DeleteAnomalyDetectorInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
@@ -4964,6 +6098,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = DeleteAnomalyDetectorOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -4975,7 +6110,10 @@ impl CloudWatch for CloudWatchClient {
                     result = DeleteAnomalyDetectorOutputDeserializer::deserialize(
                         "DeleteAnomalyDetectorResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteAnomalyDetector", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -4991,6 +6129,7 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteDashboardsInput,
     ) -> RusotoFuture<DeleteDashboardsOutput, DeleteDashboardsError> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteDashboards");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDashboards");
@@ -5015,6 +6154,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = DeleteDashboardsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5026,7 +6166,8 @@ impl CloudWatch for CloudWatchClient {
                     result = DeleteDashboardsOutputDeserializer::deserialize(
                         "DeleteDashboardsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DeleteDashboards", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5041,7 +6182,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: DescribeAlarmHistoryInput,
     ) -> RusotoFuture<DescribeAlarmHistoryOutput, DescribeAlarmHistoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarmHistory");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAlarmHistory");
@@ -5065,6 +6215,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = DescribeAlarmHistoryOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5076,7 +6227,10 @@ impl CloudWatch for CloudWatchClient {
                     result = DescribeAlarmHistoryOutputDeserializer::deserialize(
                         "DescribeAlarmHistoryResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAlarmHistory", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5091,7 +6245,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: DescribeAlarmsInput,
     ) -> RusotoFuture<DescribeAlarmsOutput, DescribeAlarmsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarms");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAlarms");
@@ -5116,6 +6279,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = DescribeAlarmsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5127,7 +6291,8 @@ impl CloudWatch for CloudWatchClient {
                     result = DescribeAlarmsOutputDeserializer::deserialize(
                         "DescribeAlarmsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DescribeAlarms", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5142,7 +6307,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: DescribeAlarmsForMetricInput,
     ) -> RusotoFuture<DescribeAlarmsForMetricOutput, DescribeAlarmsForMetricError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarmsForMetric");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAlarmsForMetric");
@@ -5164,6 +6338,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = DescribeAlarmsForMetricOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5175,7 +6350,10 @@ impl CloudWatch for CloudWatchClient {
                     result = DescribeAlarmsForMetricOutputDeserializer::deserialize(
                         "DescribeAlarmsForMetricResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAlarmsForMetric", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5190,7 +6368,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: DescribeAnomalyDetectorsInput,
     ) -> RusotoFuture<DescribeAnomalyDetectorsOutput, DescribeAnomalyDetectorsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAnomalyDetectors");
         let mut params = Params::new();
 
         params.put("Action", "DescribeAnomalyDetectors");
@@ -5212,6 +6399,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = DescribeAnomalyDetectorsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5223,7 +6411,10 @@ impl CloudWatch for CloudWatchClient {
                     result = DescribeAnomalyDetectorsOutputDeserializer::deserialize(
                         "DescribeAnomalyDetectorsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAnomalyDetectors", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5238,7 +6429,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: DisableAlarmActionsInput,
     ) -> RusotoFuture<(), DisableAlarmActionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DisableAlarmActions");
         let mut params = Params::new();
 
         params.put("Action", "DisableAlarmActions");
@@ -5265,7 +6465,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: EnableAlarmActionsInput,
     ) -> RusotoFuture<(), EnableAlarmActionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("EnableAlarmActions");
         let mut params = Params::new();
 
         params.put("Action", "EnableAlarmActions");
@@ -5294,6 +6503,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetDashboardInput,
     ) -> RusotoFuture<GetDashboardOutput, GetDashboardError> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetDashboard");
         let mut params = Params::new();
 
         params.put("Action", "GetDashboard");
@@ -5318,6 +6528,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = GetDashboardOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5329,7 +6540,8 @@ impl CloudWatch for CloudWatchClient {
                     result = GetDashboardOutputDeserializer::deserialize(
                         "GetDashboardResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetDashboard", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5344,7 +6556,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: GetMetricDataInput,
     ) -> RusotoFuture<GetMetricDataOutput, GetMetricDataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricData");
         let mut params = Params::new();
 
         params.put("Action", "GetMetricData");
@@ -5369,6 +6590,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = GetMetricDataOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5380,7 +6602,8 @@ impl CloudWatch for CloudWatchClient {
                     result = GetMetricDataOutputDeserializer::deserialize(
                         "GetMetricDataResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetMetricData", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5395,7 +6618,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: GetMetricStatisticsInput,
     ) -> RusotoFuture<GetMetricStatisticsOutput, GetMetricStatisticsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricStatistics");
         let mut params = Params::new();
 
         params.put("Action", "GetMetricStatistics");
@@ -5419,6 +6651,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = GetMetricStatisticsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5430,7 +6663,8 @@ impl CloudWatch for CloudWatchClient {
                     result = GetMetricStatisticsOutputDeserializer::deserialize(
                         "GetMetricStatisticsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetMetricStatistics", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5446,6 +6680,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetMetricWidgetImageInput,
     ) -> RusotoFuture<GetMetricWidgetImageOutput, GetMetricWidgetImageError> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricWidgetImage");
         let mut params = Params::new();
 
         params.put("Action", "GetMetricWidgetImage");
@@ -5469,6 +6704,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = GetMetricWidgetImageOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5480,7 +6716,10 @@ impl CloudWatch for CloudWatchClient {
                     result = GetMetricWidgetImageOutputDeserializer::deserialize(
                         "GetMetricWidgetImageResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetMetricWidgetImage", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5496,6 +6735,7 @@ impl CloudWatch for CloudWatchClient {
         input: ListDashboardsInput,
     ) -> RusotoFuture<ListDashboardsOutput, ListDashboardsError> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListDashboards");
         let mut params = Params::new();
 
         params.put("Action", "ListDashboards");
@@ -5520,6 +6760,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = ListDashboardsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5531,7 +6772,8 @@ impl CloudWatch for CloudWatchClient {
                     result = ListDashboardsOutputDeserializer::deserialize(
                         "ListDashboardsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("ListDashboards", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5546,7 +6788,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: ListMetricsInput,
     ) -> RusotoFuture<ListMetricsOutput, ListMetricsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListMetrics");
         let mut params = Params::new();
 
         params.put("Action", "ListMetrics");
@@ -5571,6 +6822,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = ListMetricsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5579,10 +6831,9 @@ impl CloudWatch for CloudWatchClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = ListMetricsOutputDeserializer::deserialize(
-                        "ListMetricsResult",
-                        &mut stack,
-                    )?;
+                    result =
+                        ListMetricsOutputDeserializer::deserialize("ListMetricsResult", &mut stack)
+                            .map_err(|err| stack.parse_error("ListMetrics", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5597,7 +6848,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
         params.put("Action", "ListTagsForResource");
@@ -5621,6 +6881,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = ListTagsForResourceOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5632,7 +6893,8 @@ impl CloudWatch for CloudWatchClient {
                     result = ListTagsForResourceOutputDeserializer::deserialize(
                         "ListTagsForResourceResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("ListTagsForResource", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5647,7 +6909,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: PutAnomalyDetectorInput,
     ) -> RusotoFuture<PutAnomalyDetectorOutput, PutAnomalyDetectorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutAnomalyDetector");
         let mut params = Params::new();
 
         params.put("Action", "PutAnomalyDetector");
@@ -5672,6 +6943,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = PutAnomalyDetectorOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5683,7 +6955,8 @@ impl CloudWatch for CloudWatchClient {
                     result = PutAnomalyDetectorOutputDeserializer::deserialize(
                         "PutAnomalyDetectorResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("PutAnomalyDetector", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5699,6 +6972,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutDashboardInput,
     ) -> RusotoFuture<PutDashboardOutput, PutDashboardError> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutDashboard");
         let mut params = Params::new();
 
         params.put("Action", "PutDashboard");
@@ -5723,6 +6997,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = PutDashboardOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5734,7 +7009,8 @@ impl CloudWatch for CloudWatchClient {
                     result = PutDashboardOutputDeserializer::deserialize(
                         "PutDashboardResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("PutDashboard", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5749,7 +7025,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: PutMetricAlarmInput,
     ) -> RusotoFuture<(), PutMetricAlarmError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutMetricAlarm");
         let mut params = Params::new();
 
         params.put("Action", "PutMetricAlarm");
@@ -5774,7 +7059,16 @@ impl CloudWatch for CloudWatchClient {
 
     /// <p><p>Publishes metric data points to Amazon CloudWatch. CloudWatch associates the data points with the specified metric. If the specified metric does not exist, CloudWatch creates the metric. When CloudWatch creates a metric, it can take up to fifteen minutes for the metric to appear in calls to <a>ListMetrics</a>.</p> <p>You can publish either individual data points in the <code>Value</code> field, or arrays of values and the number of times each value occurred during the period by using the <code>Values</code> and <code>Counts</code> fields in the <code>MetricDatum</code> structure. Using the <code>Values</code> and <code>Counts</code> method enables you to publish up to 150 values per metric with one <code>PutMetricData</code> request, and supports retrieving percentile statistics on this data.</p> <p>Each <code>PutMetricData</code> request is limited to 40 KB in size for HTTP POST requests. You can send a payload compressed by gzip. Each request is also limited to no more than 20 different metrics.</p> <p>Although the <code>Value</code> parameter accepts numbers of type <code>Double</code>, CloudWatch rejects values that are either too small or too large. Values must be in the range of 8.515920e-109 to 1.174271e+108 (Base 10) or 2e-360 to 2e360 (Base 2). In addition, special values (for example, NaN, +Infinity, -Infinity) are not supported.</p> <p>You can use up to 10 dimensions per metric to further clarify what data the metric collects. Each dimension consists of a Name and Value pair. For more information about specifying dimensions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publishing Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> <p>Data points with time stamps from 24 hours ago or longer can take at least 48 hours to become available for <a>GetMetricData</a> or <a>GetMetricStatistics</a> from the time they are submitted.</p> <p>CloudWatch needs raw data points to calculate percentile statistics. If you publish data using a statistic set instead, you can only retrieve percentile statistics for this data if one of the following conditions is true:</p> <ul> <li> <p>The <code>SampleCount</code> value of the statistic set is 1 and <code>Min</code>, <code>Max</code>, and <code>Sum</code> are all equal.</p> </li> <li> <p>The <code>Min</code> and <code>Max</code> are equal, and <code>Sum</code> is equal to <code>Min</code> multiplied by <code>SampleCount</code>.</p> </li> </ul></p>
     fn put_metric_data(&self, input: PutMetricDataInput) -> RusotoFuture<(), PutMetricDataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutMetricData");
         let mut params = Params::new();
 
         params.put("Action", "PutMetricData");
@@ -5799,7 +7093,16 @@ impl CloudWatch for CloudWatchClient {
 
     /// <p>Temporarily sets the state of an alarm for testing purposes. When the updated state differs from the previous value, the action configured for the appropriate state is invoked. For example, if your alarm is configured to send an Amazon SNS message when an alarm is triggered, temporarily changing the alarm state to <code>ALARM</code> sends an SNS message. The alarm returns to its actual state (often within seconds). Because the alarm state change happens quickly, it is typically only visible in the alarm's <b>History</b> tab in the Amazon CloudWatch console or through <a>DescribeAlarmHistory</a>.</p>
     fn set_alarm_state(&self, input: SetAlarmStateInput) -> RusotoFuture<(), SetAlarmStateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("SetAlarmState");
         let mut params = Params::new();
 
         params.put("Action", "SetAlarmState");
@@ -5827,7 +7130,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: TagResourceInput,
     ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("TagResource");
         let mut params = Params::new();
 
         params.put("Action", "TagResource");
@@ -5852,6 +7164,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = TagResourceOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5860,10 +7173,9 @@ impl CloudWatch for CloudWatchClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = TagResourceOutputDeserializer::deserialize(
-                        "TagResourceResult",
-                        &mut stack,
-                    )?;
+                    result =
+                        TagResourceOutputDeserializer::deserialize("TagResourceResult", &mut stack)
+                            .map_err(|err| stack.parse_error("TagResource", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5878,7 +7190,16 @@ impl CloudWatch for CloudWatchClient {
         &self,
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("UntagResource");
         let mut params = Params::new();
 
         params.put("Action", "UntagResource");
@@ -5903,6 +7224,7 @@ impl CloudWatch for CloudWatchClient {
                 if response.body.is_empty() {
                     result = UntagResourceOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -5914,7 +7236,8 @@ impl CloudWatch for CloudWatchClient {
                     result = UntagResourceOutputDeserializer::deserialize(
                         "UntagResourceResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("UntagResource", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -5925,6 +7248,771 @@ impl CloudWatch for CloudWatchClient {
     }
 }
 
+/// A mock of the CloudWatch API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloudWatch {
+    delete_alarms: Option<
+        Box<
+            dyn Fn(DeleteAlarmsInput) -> ::std::result::Result<(), RusotoError<DeleteAlarmsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_anomaly_detector: Option<
+        Box<
+            dyn Fn(
+                    DeleteAnomalyDetectorInput,
+                ) -> ::std::result::Result<
+                    DeleteAnomalyDetectorOutput,
+                    RusotoError<DeleteAnomalyDetectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_dashboards: Option<
+        Box<
+            dyn Fn(
+                    DeleteDashboardsInput,
+                ) -> ::std::result::Result<
+                    DeleteDashboardsOutput,
+                    RusotoError<DeleteDashboardsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_alarm_history: Option<
+        Box<
+            dyn Fn(
+                    DescribeAlarmHistoryInput,
+                ) -> ::std::result::Result<
+                    DescribeAlarmHistoryOutput,
+                    RusotoError<DescribeAlarmHistoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_alarms: Option<
+        Box<
+            dyn Fn(
+                    DescribeAlarmsInput,
+                )
+                    -> ::std::result::Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_alarms_for_metric: Option<
+        Box<
+            dyn Fn(
+                    DescribeAlarmsForMetricInput,
+                ) -> ::std::result::Result<
+                    DescribeAlarmsForMetricOutput,
+                    RusotoError<DescribeAlarmsForMetricError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_anomaly_detectors: Option<
+        Box<
+            dyn Fn(
+                    DescribeAnomalyDetectorsInput,
+                ) -> ::std::result::Result<
+                    DescribeAnomalyDetectorsOutput,
+                    RusotoError<DescribeAnomalyDetectorsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_alarm_actions: Option<
+        Box<
+            dyn Fn(
+                    DisableAlarmActionsInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DisableAlarmActionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    enable_alarm_actions: Option<
+        Box<
+            dyn Fn(
+                    EnableAlarmActionsInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<EnableAlarmActionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_dashboard: Option<
+        Box<
+            dyn Fn(
+                    GetDashboardInput,
+                )
+                    -> ::std::result::Result<GetDashboardOutput, RusotoError<GetDashboardError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_metric_data: Option<
+        Box<
+            dyn Fn(
+                    GetMetricDataInput,
+                )
+                    -> ::std::result::Result<GetMetricDataOutput, RusotoError<GetMetricDataError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_metric_statistics: Option<
+        Box<
+            dyn Fn(
+                    GetMetricStatisticsInput,
+                ) -> ::std::result::Result<
+                    GetMetricStatisticsOutput,
+                    RusotoError<GetMetricStatisticsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_metric_widget_image: Option<
+        Box<
+            dyn Fn(
+                    GetMetricWidgetImageInput,
+                ) -> ::std::result::Result<
+                    GetMetricWidgetImageOutput,
+                    RusotoError<GetMetricWidgetImageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_dashboards: Option<
+        Box<
+            dyn Fn(
+                    ListDashboardsInput,
+                )
+                    -> ::std::result::Result<ListDashboardsOutput, RusotoError<ListDashboardsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_metrics: Option<
+        Box<
+            dyn Fn(
+                    ListMetricsInput,
+                )
+                    -> ::std::result::Result<ListMetricsOutput, RusotoError<ListMetricsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceOutput,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_anomaly_detector: Option<
+        Box<
+            dyn Fn(
+                    PutAnomalyDetectorInput,
+                ) -> ::std::result::Result<
+                    PutAnomalyDetectorOutput,
+                    RusotoError<PutAnomalyDetectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_dashboard: Option<
+        Box<
+            dyn Fn(
+                    PutDashboardInput,
+                )
+                    -> ::std::result::Result<PutDashboardOutput, RusotoError<PutDashboardError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_metric_alarm: Option<
+        Box<
+            dyn Fn(
+                    PutMetricAlarmInput,
+                ) -> ::std::result::Result<(), RusotoError<PutMetricAlarmError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_metric_data: Option<
+        Box<
+            dyn Fn(PutMetricDataInput) -> ::std::result::Result<(), RusotoError<PutMetricDataError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_alarm_state: Option<
+        Box<
+            dyn Fn(SetAlarmStateInput) -> ::std::result::Result<(), RusotoError<SetAlarmStateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceInput,
+                )
+                    -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceInput,
+                )
+                    -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloudWatch {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloudWatch {
+        Default::default()
+    }
+
+    /// Answers `delete_alarms` with what `handler` returns for its input.
+    pub fn with_delete_alarms<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(DeleteAlarmsInput) -> ::std::result::Result<(), RusotoError<DeleteAlarmsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_alarms = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_anomaly_detector` with what `handler` returns for its input.
+    pub fn with_delete_anomaly_detector<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                DeleteAnomalyDetectorInput,
+            ) -> ::std::result::Result<
+                DeleteAnomalyDetectorOutput,
+                RusotoError<DeleteAnomalyDetectorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_anomaly_detector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_dashboards` with what `handler` returns for its input.
+    pub fn with_delete_dashboards<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                DeleteDashboardsInput,
+            )
+                -> ::std::result::Result<DeleteDashboardsOutput, RusotoError<DeleteDashboardsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_dashboards = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_alarm_history` with what `handler` returns for its input.
+    pub fn with_describe_alarm_history<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                DescribeAlarmHistoryInput,
+            ) -> ::std::result::Result<
+                DescribeAlarmHistoryOutput,
+                RusotoError<DescribeAlarmHistoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_alarm_history = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_alarms` with what `handler` returns for its input.
+    pub fn with_describe_alarms<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                DescribeAlarmsInput,
+            )
+                -> ::std::result::Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_alarms = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_alarms_for_metric` with what `handler` returns for its input.
+    pub fn with_describe_alarms_for_metric<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                DescribeAlarmsForMetricInput,
+            ) -> ::std::result::Result<
+                DescribeAlarmsForMetricOutput,
+                RusotoError<DescribeAlarmsForMetricError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_alarms_for_metric = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_anomaly_detectors` with what `handler` returns for its input.
+    pub fn with_describe_anomaly_detectors<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                DescribeAnomalyDetectorsInput,
+            ) -> ::std::result::Result<
+                DescribeAnomalyDetectorsOutput,
+                RusotoError<DescribeAnomalyDetectorsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_anomaly_detectors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_alarm_actions` with what `handler` returns for its input.
+    pub fn with_disable_alarm_actions<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                DisableAlarmActionsInput,
+            ) -> ::std::result::Result<(), RusotoError<DisableAlarmActionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_alarm_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_alarm_actions` with what `handler` returns for its input.
+    pub fn with_enable_alarm_actions<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                EnableAlarmActionsInput,
+            ) -> ::std::result::Result<(), RusotoError<EnableAlarmActionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_alarm_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_dashboard` with what `handler` returns for its input.
+    pub fn with_get_dashboard<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                GetDashboardInput,
+            )
+                -> ::std::result::Result<GetDashboardOutput, RusotoError<GetDashboardError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_dashboard = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_metric_data` with what `handler` returns for its input.
+    pub fn with_get_metric_data<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                GetMetricDataInput,
+            )
+                -> ::std::result::Result<GetMetricDataOutput, RusotoError<GetMetricDataError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_metric_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_metric_statistics` with what `handler` returns for its input.
+    pub fn with_get_metric_statistics<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                GetMetricStatisticsInput,
+            ) -> ::std::result::Result<
+                GetMetricStatisticsOutput,
+                RusotoError<GetMetricStatisticsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_metric_statistics = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_metric_widget_image` with what `handler` returns for its input.
+    pub fn with_get_metric_widget_image<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                GetMetricWidgetImageInput,
+            ) -> ::std::result::Result<
+                GetMetricWidgetImageOutput,
+                RusotoError<GetMetricWidgetImageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_metric_widget_image = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_dashboards` with what `handler` returns for its input.
+    pub fn with_list_dashboards<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                ListDashboardsInput,
+            )
+                -> ::std::result::Result<ListDashboardsOutput, RusotoError<ListDashboardsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_dashboards = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_metrics` with what `handler` returns for its input.
+    pub fn with_list_metrics<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                ListMetricsInput,
+            )
+                -> ::std::result::Result<ListMetricsOutput, RusotoError<ListMetricsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_metrics = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceOutput,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_anomaly_detector` with what `handler` returns for its input.
+    pub fn with_put_anomaly_detector<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                PutAnomalyDetectorInput,
+            ) -> ::std::result::Result<
+                PutAnomalyDetectorOutput,
+                RusotoError<PutAnomalyDetectorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_anomaly_detector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_dashboard` with what `handler` returns for its input.
+    pub fn with_put_dashboard<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                PutDashboardInput,
+            )
+                -> ::std::result::Result<PutDashboardOutput, RusotoError<PutDashboardError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_dashboard = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_metric_alarm` with what `handler` returns for its input.
+    pub fn with_put_metric_alarm<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(PutMetricAlarmInput) -> ::std::result::Result<(), RusotoError<PutMetricAlarmError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_metric_alarm = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_metric_data` with what `handler` returns for its input.
+    pub fn with_put_metric_data<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(PutMetricDataInput) -> ::std::result::Result<(), RusotoError<PutMetricDataError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_metric_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_alarm_state` with what `handler` returns for its input.
+    pub fn with_set_alarm_state<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(SetAlarmStateInput) -> ::std::result::Result<(), RusotoError<SetAlarmStateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_alarm_state = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                TagResourceInput,
+            )
+                -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockCloudWatch
+    where
+        F: Fn(
+                UntagResourceInput,
+            )
+                -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CloudWatch for MockCloudWatch {
+    fn delete_alarms(&self, input: DeleteAlarmsInput) -> RusotoFuture<(), DeleteAlarmsError> {
+        match self.delete_alarms {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for delete_alarms"),
+        }
+    }
+    fn delete_anomaly_detector(
+        &self,
+        input: DeleteAnomalyDetectorInput,
+    ) -> RusotoFuture<DeleteAnomalyDetectorOutput, DeleteAnomalyDetectorError> {
+        match self.delete_anomaly_detector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for delete_anomaly_detector"),
+        }
+    }
+    fn delete_dashboards(
+        &self,
+        input: DeleteDashboardsInput,
+    ) -> RusotoFuture<DeleteDashboardsOutput, DeleteDashboardsError> {
+        match self.delete_dashboards {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for delete_dashboards"),
+        }
+    }
+    fn describe_alarm_history(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> RusotoFuture<DescribeAlarmHistoryOutput, DescribeAlarmHistoryError> {
+        match self.describe_alarm_history {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for describe_alarm_history"),
+        }
+    }
+    fn describe_alarms(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> RusotoFuture<DescribeAlarmsOutput, DescribeAlarmsError> {
+        match self.describe_alarms {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for describe_alarms"),
+        }
+    }
+    fn describe_alarms_for_metric(
+        &self,
+        input: DescribeAlarmsForMetricInput,
+    ) -> RusotoFuture<DescribeAlarmsForMetricOutput, DescribeAlarmsForMetricError> {
+        match self.describe_alarms_for_metric {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for describe_alarms_for_metric"),
+        }
+    }
+    fn describe_anomaly_detectors(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> RusotoFuture<DescribeAnomalyDetectorsOutput, DescribeAnomalyDetectorsError> {
+        match self.describe_anomaly_detectors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for describe_anomaly_detectors"),
+        }
+    }
+    fn disable_alarm_actions(
+        &self,
+        input: DisableAlarmActionsInput,
+    ) -> RusotoFuture<(), DisableAlarmActionsError> {
+        match self.disable_alarm_actions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for disable_alarm_actions"),
+        }
+    }
+    fn enable_alarm_actions(
+        &self,
+        input: EnableAlarmActionsInput,
+    ) -> RusotoFuture<(), EnableAlarmActionsError> {
+        match self.enable_alarm_actions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for enable_alarm_actions"),
+        }
+    }
+    fn get_dashboard(
+        &self,
+        input: GetDashboardInput,
+    ) -> RusotoFuture<GetDashboardOutput, GetDashboardError> {
+        match self.get_dashboard {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for get_dashboard"),
+        }
+    }
+    fn get_metric_data(
+        &self,
+        input: GetMetricDataInput,
+    ) -> RusotoFuture<GetMetricDataOutput, GetMetricDataError> {
+        match self.get_metric_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for get_metric_data"),
+        }
+    }
+    fn get_metric_statistics(
+        &self,
+        input: GetMetricStatisticsInput,
+    ) -> RusotoFuture<GetMetricStatisticsOutput, GetMetricStatisticsError> {
+        match self.get_metric_statistics {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for get_metric_statistics"),
+        }
+    }
+    fn get_metric_widget_image(
+        &self,
+        input: GetMetricWidgetImageInput,
+    ) -> RusotoFuture<GetMetricWidgetImageOutput, GetMetricWidgetImageError> {
+        match self.get_metric_widget_image {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for get_metric_widget_image"),
+        }
+    }
+    fn list_dashboards(
+        &self,
+        input: ListDashboardsInput,
+    ) -> RusotoFuture<ListDashboardsOutput, ListDashboardsError> {
+        match self.list_dashboards {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for list_dashboards"),
+        }
+    }
+    fn list_metrics(
+        &self,
+        input: ListMetricsInput,
+    ) -> RusotoFuture<ListMetricsOutput, ListMetricsError> {
+        match self.list_metrics {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for list_metrics"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for list_tags_for_resource"),
+        }
+    }
+    fn put_anomaly_detector(
+        &self,
+        input: PutAnomalyDetectorInput,
+    ) -> RusotoFuture<PutAnomalyDetectorOutput, PutAnomalyDetectorError> {
+        match self.put_anomaly_detector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for put_anomaly_detector"),
+        }
+    }
+    fn put_dashboard(
+        &self,
+        input: PutDashboardInput,
+    ) -> RusotoFuture<PutDashboardOutput, PutDashboardError> {
+        match self.put_dashboard {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for put_dashboard"),
+        }
+    }
+    fn put_metric_alarm(
+        &self,
+        input: PutMetricAlarmInput,
+    ) -> RusotoFuture<(), PutMetricAlarmError> {
+        match self.put_metric_alarm {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for put_metric_alarm"),
+        }
+    }
+    fn put_metric_data(&self, input: PutMetricDataInput) -> RusotoFuture<(), PutMetricDataError> {
+        match self.put_metric_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for put_metric_data"),
+        }
+    }
+    fn set_alarm_state(&self, input: SetAlarmStateInput) -> RusotoFuture<(), SetAlarmStateError> {
+        match self.set_alarm_state {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for set_alarm_state"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatch has no handler for untag_resource"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
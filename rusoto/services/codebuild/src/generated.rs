@@ -22,16 +22,28 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteBuildsInput {
     /// <p>The IDs of the builds to delete.</p>
     #[serde(rename = "ids")]
     pub ids: Vec<String>,
 }
 
+impl Validate for BatchDeleteBuildsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "ids"), self.ids.len(), Some(1), Some(100));
+        for (i, item) in self.ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDeleteBuildsOutput {
     /// <p>The IDs of the builds that were successfully deleted.</p>
     #[serde(rename = "buildsDeleted")]
@@ -44,14 +56,25 @@ pub struct BatchDeleteBuildsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetBuildsInput {
     /// <p>The IDs of the builds.</p>
     #[serde(rename = "ids")]
     pub ids: Vec<String>,
 }
 
+impl Validate for BatchGetBuildsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "ids"), self.ids.len(), Some(1), Some(100));
+        for (i, item) in self.ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetBuildsOutput {
     /// <p>Information about the requested builds.</p>
     #[serde(rename = "builds")]
@@ -64,14 +87,30 @@ pub struct BatchGetBuildsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetProjectsInput {
     /// <p>The names of the build projects.</p>
     #[serde(rename = "names")]
     pub names: Vec<String>,
 }
 
+impl Validate for BatchGetProjectsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "names"),
+            self.names.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetProjectsOutput {
     /// <p>Information about the requested build projects.</p>
     #[serde(rename = "projects")]
@@ -85,7 +124,7 @@ pub struct BatchGetProjectsOutput {
 
 /// <p>Information about a build.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Build {
     /// <p>The Amazon Resource Name (ARN) of the build.</p>
     #[serde(rename = "arn")]
@@ -195,7 +234,7 @@ pub struct Build {
 
 /// <p>Information about build output artifacts.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BuildArtifacts {
     /// <p> An identifier for this artifact definition. </p>
     #[serde(rename = "artifactIdentifier")]
@@ -225,7 +264,7 @@ pub struct BuildArtifacts {
 
 /// <p>Information about a build that could not be successfully deleted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BuildNotDeleted {
     /// <p>The ID of the build that could not be successfully deleted.</p>
     #[serde(rename = "id")]
@@ -239,7 +278,7 @@ pub struct BuildNotDeleted {
 
 /// <p>Information about a stage for a build.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BuildPhase {
     /// <p>Additional information about a build phase, especially to help troubleshoot a failed build.</p>
     #[serde(rename = "contexts")]
@@ -284,6 +323,7 @@ pub struct CloudWatchLogsConfig {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProjectInput {
     /// <p>Information about the build output artifacts for the build project.</p>
     #[serde(rename = "artifacts")]
@@ -354,8 +394,98 @@ pub struct CreateProjectInput {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for CreateProjectInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.encryption_key {
+            errors.check_length(
+                &field_path(path, "encryption_key"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        self.environment
+            .validate_at(&field_path(path, "environment"), errors);
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(2),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[A-Za-z0-9][A-Za-z0-9\\-_]{1,254}",
+        );
+        if let Some(ref value) = self.queued_timeout_in_minutes {
+            errors.check_range(
+                &field_path(path, "queued_timeout_in_minutes"),
+                *value,
+                Some(5),
+                Some(480),
+            );
+        }
+        if let Some(ref value) = self.secondary_artifacts {
+            errors.check_length(
+                &field_path(path, "secondary_artifacts"),
+                value.len(),
+                None,
+                Some(12),
+            );
+        }
+        if let Some(ref value) = self.secondary_source_versions {
+            errors.check_length(
+                &field_path(path, "secondary_source_versions"),
+                value.len(),
+                None,
+                Some(12),
+            );
+        }
+        if let Some(ref value) = self.secondary_sources {
+            errors.check_length(
+                &field_path(path, "secondary_sources"),
+                value.len(),
+                None,
+                Some(12),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "service_role"),
+            self.service_role.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.timeout_in_minutes {
+            errors.check_range(
+                &field_path(path, "timeout_in_minutes"),
+                *value,
+                Some(5),
+                Some(480),
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProjectOutput {
     /// <p>Information about the build project that was created.</p>
     #[serde(rename = "project")]
@@ -364,6 +494,7 @@ pub struct CreateProjectOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateWebhookInput {
     /// <p><p>A regular expression used to determine which repository branches are built when a webhook is triggered. If the name of a branch matches the regular expression, then it is built. If <code>branchFilter</code> is empty, then all branches are built.</p> <note> <p> It is recommended that you use <code>filterGroups</code> instead of <code>branchFilter</code>. </p> </note></p>
     #[serde(rename = "branchFilter")]
@@ -378,8 +509,24 @@ pub struct CreateWebhookInput {
     pub project_name: String,
 }
 
+impl Validate for CreateWebhookInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(2),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "[A-Za-z0-9][A-Za-z0-9\\-_]{1,254}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateWebhookOutput {
     /// <p>Information about a webhook that connects repository events to a build project in AWS CodeBuild.</p>
     #[serde(rename = "webhook")]
@@ -388,25 +535,49 @@ pub struct CreateWebhookOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProjectInput {
     /// <p>The name of the build project.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for DeleteProjectInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProjectOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSourceCredentialsInput {
     /// <p> The Amazon Resource Name (ARN) of the token.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for DeleteSourceCredentialsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSourceCredentialsOutput {
     /// <p> The Amazon Resource Name (ARN) of the token. </p>
     #[serde(rename = "arn")]
@@ -415,19 +586,36 @@ pub struct DeleteSourceCredentialsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteWebhookInput {
     /// <p>The name of the AWS CodeBuild project.</p>
     #[serde(rename = "projectName")]
     pub project_name: String,
 }
 
+impl Validate for DeleteWebhookInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(2),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "[A-Za-z0-9][A-Za-z0-9\\-_]{1,254}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteWebhookOutput {}
 
 /// <p>Information about a Docker image that is managed by AWS CodeBuild.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnvironmentImage {
     /// <p>The description of the Docker image.</p>
     #[serde(rename = "description")]
@@ -445,7 +633,7 @@ pub struct EnvironmentImage {
 
 /// <p>A set of Docker images that are related by programming language and are managed by AWS CodeBuild.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnvironmentLanguage {
     /// <p>The list of Docker images that are related by the specified programming language.</p>
     #[serde(rename = "images")]
@@ -459,7 +647,7 @@ pub struct EnvironmentLanguage {
 
 /// <p>A set of Docker images that are related by platform and are managed by AWS CodeBuild.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnvironmentPlatform {
     /// <p>The list of programming languages that are available for the specified platform.</p>
     #[serde(rename = "languages")]
@@ -486,6 +674,17 @@ pub struct EnvironmentVariable {
     pub value: String,
 }
 
+impl Validate for EnvironmentVariable {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p> Information about the Git submodules configuration for an AWS CodeBuild build project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GitSubmodulesConfig {
@@ -495,6 +694,7 @@ pub struct GitSubmodulesConfig {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportSourceCredentialsInput {
     /// <p> The type of authentication used to connect to a GitHub, GitHub Enterprise, or Bitbucket repository. An OAUTH connection is not supported by the API and must be created using the AWS CodeBuild console. </p>
     #[serde(rename = "authType")]
@@ -515,8 +715,27 @@ pub struct ImportSourceCredentialsInput {
     pub username: Option<String>,
 }
 
+impl Validate for ImportSourceCredentialsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "token"),
+            self.token.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.username {
+            errors.check_length(
+                &field_path(path, "username"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportSourceCredentialsOutput {
     /// <p> The Amazon Resource Name (ARN) of the token. </p>
     #[serde(rename = "arn")]
@@ -525,17 +744,30 @@ pub struct ImportSourceCredentialsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InvalidateProjectCacheInput {
     /// <p>The name of the AWS CodeBuild build project that the cache is reset for.</p>
     #[serde(rename = "projectName")]
     pub project_name: String,
 }
 
+impl Validate for InvalidateProjectCacheInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InvalidateProjectCacheOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBuildsForProjectInput {
     /// <p>During a previous call, if there are more than 100 items in the list, only the first 100 items are returned, along with a unique string called a <i>next token</i>. To get the next batch of items in the list, call this operation again, adding the next token to the call. To get all of the items in the list, keep calling this operation with each subsequent next token that is returned, until no more next tokens are returned.</p>
     #[serde(rename = "nextToken")]
@@ -550,8 +782,19 @@ pub struct ListBuildsForProjectInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for ListBuildsForProjectInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBuildsForProjectOutput {
     /// <p>A list of build IDs for the specified build project, with each build ID representing a single build.</p>
     #[serde(rename = "ids")]
@@ -564,6 +807,7 @@ pub struct ListBuildsForProjectOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBuildsInput {
     /// <p>During a previous call, if there are more than 100 items in the list, only the first 100 items are returned, along with a unique string called a <i>next token</i>. To get the next batch of items in the list, call this operation again, adding the next token to the call. To get all of the items in the list, keep calling this operation with each subsequent next token that is returned, until no more next tokens are returned.</p>
     #[serde(rename = "nextToken")]
@@ -576,7 +820,7 @@ pub struct ListBuildsInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBuildsOutput {
     /// <p>A list of build IDs, with each build ID representing a single build.</p>
     #[serde(rename = "ids")]
@@ -589,10 +833,11 @@ pub struct ListBuildsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCuratedEnvironmentImagesInput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCuratedEnvironmentImagesOutput {
     /// <p>Information about supported platforms for Docker images that are managed by AWS CodeBuild.</p>
     #[serde(rename = "platforms")]
@@ -601,6 +846,7 @@ pub struct ListCuratedEnvironmentImagesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProjectsInput {
     /// <p>During a previous call, if there are more than 100 items in the list, only the first 100 items are returned, along with a unique string called a <i>next token</i>. To get the next batch of items in the list, call this operation again, adding the next token to the call. To get all of the items in the list, keep calling this operation with each subsequent next token that is returned, until no more next tokens are returned.</p>
     #[serde(rename = "nextToken")]
@@ -616,8 +862,21 @@ pub struct ListProjectsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for ListProjectsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProjectsOutput {
     /// <p>If there are more than 100 items in the list, only the first 100 items are returned, along with a unique string called a <i>next token</i>. To get the next batch of items in the list, call this operation again, adding the next token to the call.</p>
     #[serde(rename = "nextToken")]
@@ -630,10 +889,11 @@ pub struct ListProjectsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSourceCredentialsInput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSourceCredentialsOutput {
     /// <p> A list of <code>SourceCredentialsInfo</code> objects. Each <code>SourceCredentialsInfo</code> object includes the authentication type, token ARN, and type of source provider for one set of credentials. </p>
     #[serde(rename = "sourceCredentialsInfos")]
@@ -656,7 +916,7 @@ pub struct LogsConfig {
 
 /// <p>Information about build logs in Amazon CloudWatch Logs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LogsLocation {
     /// <p> Information about Amazon CloudWatch Logs for a build project. </p>
     #[serde(rename = "cloudWatchLogs")]
@@ -686,7 +946,7 @@ pub struct LogsLocation {
 
 /// <p>Describes a network interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkInterface {
     /// <p>The ID of the network interface.</p>
     #[serde(rename = "networkInterfaceId")]
@@ -700,7 +960,7 @@ pub struct NetworkInterface {
 
 /// <p>Additional information about a build phase that has an error. You can use this information for troubleshooting.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PhaseContext {
     /// <p>An explanation of the build phase's context. This might include a command ID and an exit code.</p>
     #[serde(rename = "message")]
@@ -714,7 +974,7 @@ pub struct PhaseContext {
 
 /// <p>Information about a build project.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Project {
     /// <p>The Amazon Resource Name (ARN) of the build project.</p>
     #[serde(rename = "arn")]
@@ -848,7 +1108,7 @@ pub struct ProjectArtifacts {
 
 /// <p>Information about the build badge for the build project.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProjectBadge {
     /// <p>Set this to true to generate a publicly accessible URL for your project's build badge.</p>
     #[serde(rename = "badgeEnabled")]
@@ -910,6 +1170,26 @@ pub struct ProjectEnvironment {
     pub type_: String,
 }
 
+impl Validate for ProjectEnvironment {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_variables {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "environment_variables"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "image"),
+            self.image.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.registry_credential {
+            value.validate_at(&field_path(path, "registry_credential"), errors);
+        }
+    }
+}
+
 /// <p>Information about the build input source code for the build project.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProjectSource {
@@ -972,6 +1252,17 @@ pub struct RegistryCredential {
     pub credential_provider: String,
 }
 
+impl Validate for RegistryCredential {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "credential"),
+            self.credential.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p> Information about S3 logs for a build project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3LogsConfig {
@@ -1002,7 +1293,7 @@ pub struct SourceAuth {
 
 /// <p> Information about the credentials for a GitHub, GitHub Enterprise, or Bitbucket repository. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SourceCredentialsInfo {
     /// <p> The Amazon Resource Name (ARN) of the token. </p>
     #[serde(rename = "arn")]
@@ -1019,6 +1310,7 @@ pub struct SourceCredentialsInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartBuildInput {
     /// <p>Build output artifact settings that override, for this build only, the latest ones already defined in the build project.</p>
     #[serde(rename = "artifactsOverride")]
@@ -1133,8 +1425,88 @@ pub struct StartBuildInput {
     pub timeout_in_minutes_override: Option<i64>,
 }
 
+impl Validate for StartBuildInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_variables_override {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "environment_variables_override"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.image_override {
+            errors.check_length(
+                &field_path(path, "image_override"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.queued_timeout_in_minutes_override {
+            errors.check_range(
+                &field_path(path, "queued_timeout_in_minutes_override"),
+                *value,
+                Some(5),
+                Some(480),
+            );
+        }
+        if let Some(ref value) = self.registry_credential_override {
+            value.validate_at(&field_path(path, "registry_credential_override"), errors);
+        }
+        if let Some(ref value) = self.secondary_artifacts_override {
+            errors.check_length(
+                &field_path(path, "secondary_artifacts_override"),
+                value.len(),
+                None,
+                Some(12),
+            );
+        }
+        if let Some(ref value) = self.secondary_sources_override {
+            errors.check_length(
+                &field_path(path, "secondary_sources_override"),
+                value.len(),
+                None,
+                Some(12),
+            );
+        }
+        if let Some(ref value) = self.secondary_sources_version_override {
+            errors.check_length(
+                &field_path(path, "secondary_sources_version_override"),
+                value.len(),
+                None,
+                Some(12),
+            );
+        }
+        if let Some(ref value) = self.service_role_override {
+            errors.check_length(
+                &field_path(path, "service_role_override"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.timeout_in_minutes_override {
+            errors.check_range(
+                &field_path(path, "timeout_in_minutes_override"),
+                *value,
+                Some(5),
+                Some(480),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartBuildOutput {
     /// <p>Information about the build to be run.</p>
     #[serde(rename = "build")]
@@ -1143,14 +1515,26 @@ pub struct StartBuildOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopBuildInput {
     /// <p>The ID of the build.</p>
     #[serde(rename = "id")]
     pub id: String,
 }
 
+impl Validate for StopBuildInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopBuildOutput {
     /// <p>Information about the build.</p>
     #[serde(rename = "build")]
@@ -1171,7 +1555,39 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(127),
+            );
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=@+\\-]*)$",
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=@+\\-]*)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProjectInput {
     /// <p>Information to be changed about the build output artifacts for the build project.</p>
     #[serde(rename = "artifacts")]
@@ -1246,8 +1662,96 @@ pub struct UpdateProjectInput {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for UpdateProjectInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.encryption_key {
+            errors.check_length(
+                &field_path(path, "encryption_key"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.environment {
+            value.validate_at(&field_path(path, "environment"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.queued_timeout_in_minutes {
+            errors.check_range(
+                &field_path(path, "queued_timeout_in_minutes"),
+                *value,
+                Some(5),
+                Some(480),
+            );
+        }
+        if let Some(ref value) = self.secondary_artifacts {
+            errors.check_length(
+                &field_path(path, "secondary_artifacts"),
+                value.len(),
+                None,
+                Some(12),
+            );
+        }
+        if let Some(ref value) = self.secondary_source_versions {
+            errors.check_length(
+                &field_path(path, "secondary_source_versions"),
+                value.len(),
+                None,
+                Some(12),
+            );
+        }
+        if let Some(ref value) = self.secondary_sources {
+            errors.check_length(
+                &field_path(path, "secondary_sources"),
+                value.len(),
+                None,
+                Some(12),
+            );
+        }
+        if let Some(ref value) = self.service_role {
+            errors.check_length(
+                &field_path(path, "service_role"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.timeout_in_minutes {
+            errors.check_range(
+                &field_path(path, "timeout_in_minutes"),
+                *value,
+                Some(5),
+                Some(480),
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateProjectOutput {
     /// <p>Information about the build project that was changed.</p>
     #[serde(rename = "project")]
@@ -1256,6 +1760,7 @@ pub struct UpdateProjectOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateWebhookInput {
     /// <p><p>A regular expression used to determine which repository branches are built when a webhook is triggered. If the name of a branch matches the regular expression, then it is built. If <code>branchFilter</code> is empty, then all branches are built.</p> <note> <p> It is recommended that you use <code>filterGroups</code> instead of <code>branchFilter</code>. </p> </note></p>
     #[serde(rename = "branchFilter")]
@@ -1274,8 +1779,24 @@ pub struct UpdateWebhookInput {
     pub rotate_secret: Option<bool>,
 }
 
+impl Validate for UpdateWebhookInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(2),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "[A-Za-z0-9][A-Za-z0-9\\-_]{1,254}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateWebhookOutput {
     /// <p> Information about a repository's webhook that is associated with a project in AWS CodeBuild. </p>
     #[serde(rename = "webhook")]
@@ -1300,9 +1821,41 @@ pub struct VpcConfig {
     pub vpc_id: Option<String>,
 }
 
+impl Validate for VpcConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.security_group_ids {
+            errors.check_length(
+                &field_path(path, "security_group_ids"),
+                value.len(),
+                None,
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "security_group_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), None);
+            }
+        }
+        if let Some(ref value) = self.subnets {
+            errors.check_length(&field_path(path, "subnets"), value.len(), None, Some(16));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "subnets"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), None);
+            }
+        }
+        if let Some(ref value) = self.vpc_id {
+            errors.check_length(
+                &field_path(path, "vpc_id"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Information about a webhook that connects repository events to a build project in AWS CodeBuild.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Webhook {
     /// <p><p>A regular expression used to determine which repository branches are built when a webhook is triggered. If the name of a branch matches the regular expression, then it is built. If <code>branchFilter</code> is empty, then all branches are built.</p> <note> <p> It is recommended that you use <code>filterGroups</code> instead of <code>branchFilter</code>. </p> </note></p>
     #[serde(rename = "branchFilter")]
@@ -1359,7 +1912,12 @@ impl BatchDeleteBuildsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(BatchDeleteBuildsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1392,7 +1950,12 @@ impl BatchGetBuildsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(BatchGetBuildsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1425,7 +1988,12 @@ impl BatchGetProjectsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(BatchGetProjectsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1468,7 +2036,12 @@ impl CreateProjectError {
                 "ResourceAlreadyExistsException" => {
                     return RusotoError::Service(CreateProjectError::ResourceAlreadyExists(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1518,7 +2091,12 @@ impl CreateWebhookError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateWebhookError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1554,7 +2132,12 @@ impl DeleteProjectError {
                 "InvalidInputException" => {
                     return RusotoError::Service(DeleteProjectError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1596,7 +2179,12 @@ impl DeleteSourceCredentialsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1640,7 +2228,12 @@ impl DeleteWebhookError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteWebhookError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1691,7 +2284,12 @@ impl ImportSourceCredentialsError {
                         ImportSourceCredentialsError::ResourceAlreadyExists(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1733,7 +2331,12 @@ impl InvalidateProjectCacheError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1767,7 +2370,12 @@ impl ListBuildsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(ListBuildsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1807,7 +2415,12 @@ impl ListBuildsForProjectError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1837,7 +2450,12 @@ impl ListCuratedEnvironmentImagesError {
     ) -> RusotoError<ListCuratedEnvironmentImagesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1868,7 +2486,12 @@ impl ListProjectsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(ListProjectsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1895,7 +2518,12 @@ impl ListSourceCredentialsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSourceCredentialsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1936,7 +2564,12 @@ impl StartBuildError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StartBuildError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1976,7 +2609,12 @@ impl StopBuildError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StopBuildError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2015,7 +2653,12 @@ impl UpdateProjectError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateProjectError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2059,7 +2702,12 @@ impl UpdateWebhookError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateWebhookError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2191,137 +2839,507 @@ pub trait CodeBuild {
         input: UpdateWebhookInput,
     ) -> RusotoFuture<UpdateWebhookOutput, UpdateWebhookError>;
 }
-/// A client for the AWS CodeBuild API.
-#[derive(Clone)]
-pub struct CodeBuildClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CodeBuildClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CodeBuildClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CodeBuildClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CodeBuildClient {
-        CodeBuildClient { client, region }
-    }
-}
-
-impl CodeBuild for CodeBuildClient {
-    /// <p>Deletes one or more builds.</p>
+const _: Option<&dyn CodeBuild> = None;
+impl<'a, T: CodeBuild + ?Sized> CodeBuild for &'a T {
     fn batch_delete_builds(
         &self,
         input: BatchDeleteBuildsInput,
     ) -> RusotoFuture<BatchDeleteBuildsOutput, BatchDeleteBuildsError> {
-        let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeBuild_20161006.BatchDeleteBuilds");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDeleteBuildsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchDeleteBuildsError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_delete_builds(input)
     }
-
-    /// <p>Gets information about builds.</p>
     fn batch_get_builds(
         &self,
         input: BatchGetBuildsInput,
     ) -> RusotoFuture<BatchGetBuildsOutput, BatchGetBuildsError> {
-        let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetBuilds");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetBuildsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetBuildsError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_builds(input)
     }
-
-    /// <p>Gets information about build projects.</p>
     fn batch_get_projects(
         &self,
         input: BatchGetProjectsInput,
     ) -> RusotoFuture<BatchGetProjectsOutput, BatchGetProjectsError> {
-        let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetProjects");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetProjectsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetProjectsError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_projects(input)
     }
-
-    /// <p>Creates a build project.</p>
     fn create_project(
         &self,
         input: CreateProjectInput,
     ) -> RusotoFuture<CreateProjectOutput, CreateProjectError> {
-        let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        (**self).create_project(input)
+    }
+    fn create_webhook(
+        &self,
+        input: CreateWebhookInput,
+    ) -> RusotoFuture<CreateWebhookOutput, CreateWebhookError> {
+        (**self).create_webhook(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectInput,
+    ) -> RusotoFuture<DeleteProjectOutput, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn delete_source_credentials(
+        &self,
+        input: DeleteSourceCredentialsInput,
+    ) -> RusotoFuture<DeleteSourceCredentialsOutput, DeleteSourceCredentialsError> {
+        (**self).delete_source_credentials(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn import_source_credentials(
+        &self,
+        input: ImportSourceCredentialsInput,
+    ) -> RusotoFuture<ImportSourceCredentialsOutput, ImportSourceCredentialsError> {
+        (**self).import_source_credentials(input)
+    }
+    fn invalidate_project_cache(
+        &self,
+        input: InvalidateProjectCacheInput,
+    ) -> RusotoFuture<InvalidateProjectCacheOutput, InvalidateProjectCacheError> {
+        (**self).invalidate_project_cache(input)
+    }
+    fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
+        (**self).list_builds(input)
+    }
+    fn list_builds_for_project(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> RusotoFuture<ListBuildsForProjectOutput, ListBuildsForProjectError> {
+        (**self).list_builds_for_project(input)
+    }
+    fn list_curated_environment_images(
+        &self,
+    ) -> RusotoFuture<ListCuratedEnvironmentImagesOutput, ListCuratedEnvironmentImagesError> {
+        (**self).list_curated_environment_images()
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsInput,
+    ) -> RusotoFuture<ListProjectsOutput, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_source_credentials(
+        &self,
+    ) -> RusotoFuture<ListSourceCredentialsOutput, ListSourceCredentialsError> {
+        (**self).list_source_credentials()
+    }
+    fn start_build(
+        &self,
+        input: StartBuildInput,
+    ) -> RusotoFuture<StartBuildOutput, StartBuildError> {
+        (**self).start_build(input)
+    }
+    fn stop_build(&self, input: StopBuildInput) -> RusotoFuture<StopBuildOutput, StopBuildError> {
+        (**self).stop_build(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectInput,
+    ) -> RusotoFuture<UpdateProjectOutput, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_webhook(
+        &self,
+        input: UpdateWebhookInput,
+    ) -> RusotoFuture<UpdateWebhookOutput, UpdateWebhookError> {
+        (**self).update_webhook(input)
+    }
+}
+impl<T: CodeBuild + ?Sized> CodeBuild for Box<T> {
+    fn batch_delete_builds(
+        &self,
+        input: BatchDeleteBuildsInput,
+    ) -> RusotoFuture<BatchDeleteBuildsOutput, BatchDeleteBuildsError> {
+        (**self).batch_delete_builds(input)
+    }
+    fn batch_get_builds(
+        &self,
+        input: BatchGetBuildsInput,
+    ) -> RusotoFuture<BatchGetBuildsOutput, BatchGetBuildsError> {
+        (**self).batch_get_builds(input)
+    }
+    fn batch_get_projects(
+        &self,
+        input: BatchGetProjectsInput,
+    ) -> RusotoFuture<BatchGetProjectsOutput, BatchGetProjectsError> {
+        (**self).batch_get_projects(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectInput,
+    ) -> RusotoFuture<CreateProjectOutput, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn create_webhook(
+        &self,
+        input: CreateWebhookInput,
+    ) -> RusotoFuture<CreateWebhookOutput, CreateWebhookError> {
+        (**self).create_webhook(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectInput,
+    ) -> RusotoFuture<DeleteProjectOutput, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn delete_source_credentials(
+        &self,
+        input: DeleteSourceCredentialsInput,
+    ) -> RusotoFuture<DeleteSourceCredentialsOutput, DeleteSourceCredentialsError> {
+        (**self).delete_source_credentials(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn import_source_credentials(
+        &self,
+        input: ImportSourceCredentialsInput,
+    ) -> RusotoFuture<ImportSourceCredentialsOutput, ImportSourceCredentialsError> {
+        (**self).import_source_credentials(input)
+    }
+    fn invalidate_project_cache(
+        &self,
+        input: InvalidateProjectCacheInput,
+    ) -> RusotoFuture<InvalidateProjectCacheOutput, InvalidateProjectCacheError> {
+        (**self).invalidate_project_cache(input)
+    }
+    fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
+        (**self).list_builds(input)
+    }
+    fn list_builds_for_project(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> RusotoFuture<ListBuildsForProjectOutput, ListBuildsForProjectError> {
+        (**self).list_builds_for_project(input)
+    }
+    fn list_curated_environment_images(
+        &self,
+    ) -> RusotoFuture<ListCuratedEnvironmentImagesOutput, ListCuratedEnvironmentImagesError> {
+        (**self).list_curated_environment_images()
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsInput,
+    ) -> RusotoFuture<ListProjectsOutput, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_source_credentials(
+        &self,
+    ) -> RusotoFuture<ListSourceCredentialsOutput, ListSourceCredentialsError> {
+        (**self).list_source_credentials()
+    }
+    fn start_build(
+        &self,
+        input: StartBuildInput,
+    ) -> RusotoFuture<StartBuildOutput, StartBuildError> {
+        (**self).start_build(input)
+    }
+    fn stop_build(&self, input: StopBuildInput) -> RusotoFuture<StopBuildOutput, StopBuildError> {
+        (**self).stop_build(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectInput,
+    ) -> RusotoFuture<UpdateProjectOutput, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_webhook(
+        &self,
+        input: UpdateWebhookInput,
+    ) -> RusotoFuture<UpdateWebhookOutput, UpdateWebhookError> {
+        (**self).update_webhook(input)
+    }
+}
+impl<T: CodeBuild + ?Sized> CodeBuild for ::std::sync::Arc<T> {
+    fn batch_delete_builds(
+        &self,
+        input: BatchDeleteBuildsInput,
+    ) -> RusotoFuture<BatchDeleteBuildsOutput, BatchDeleteBuildsError> {
+        (**self).batch_delete_builds(input)
+    }
+    fn batch_get_builds(
+        &self,
+        input: BatchGetBuildsInput,
+    ) -> RusotoFuture<BatchGetBuildsOutput, BatchGetBuildsError> {
+        (**self).batch_get_builds(input)
+    }
+    fn batch_get_projects(
+        &self,
+        input: BatchGetProjectsInput,
+    ) -> RusotoFuture<BatchGetProjectsOutput, BatchGetProjectsError> {
+        (**self).batch_get_projects(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectInput,
+    ) -> RusotoFuture<CreateProjectOutput, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn create_webhook(
+        &self,
+        input: CreateWebhookInput,
+    ) -> RusotoFuture<CreateWebhookOutput, CreateWebhookError> {
+        (**self).create_webhook(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectInput,
+    ) -> RusotoFuture<DeleteProjectOutput, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn delete_source_credentials(
+        &self,
+        input: DeleteSourceCredentialsInput,
+    ) -> RusotoFuture<DeleteSourceCredentialsOutput, DeleteSourceCredentialsError> {
+        (**self).delete_source_credentials(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn import_source_credentials(
+        &self,
+        input: ImportSourceCredentialsInput,
+    ) -> RusotoFuture<ImportSourceCredentialsOutput, ImportSourceCredentialsError> {
+        (**self).import_source_credentials(input)
+    }
+    fn invalidate_project_cache(
+        &self,
+        input: InvalidateProjectCacheInput,
+    ) -> RusotoFuture<InvalidateProjectCacheOutput, InvalidateProjectCacheError> {
+        (**self).invalidate_project_cache(input)
+    }
+    fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
+        (**self).list_builds(input)
+    }
+    fn list_builds_for_project(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> RusotoFuture<ListBuildsForProjectOutput, ListBuildsForProjectError> {
+        (**self).list_builds_for_project(input)
+    }
+    fn list_curated_environment_images(
+        &self,
+    ) -> RusotoFuture<ListCuratedEnvironmentImagesOutput, ListCuratedEnvironmentImagesError> {
+        (**self).list_curated_environment_images()
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsInput,
+    ) -> RusotoFuture<ListProjectsOutput, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_source_credentials(
+        &self,
+    ) -> RusotoFuture<ListSourceCredentialsOutput, ListSourceCredentialsError> {
+        (**self).list_source_credentials()
+    }
+    fn start_build(
+        &self,
+        input: StartBuildInput,
+    ) -> RusotoFuture<StartBuildOutput, StartBuildError> {
+        (**self).start_build(input)
+    }
+    fn stop_build(&self, input: StopBuildInput) -> RusotoFuture<StopBuildOutput, StopBuildError> {
+        (**self).stop_build(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectInput,
+    ) -> RusotoFuture<UpdateProjectOutput, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_webhook(
+        &self,
+        input: UpdateWebhookInput,
+    ) -> RusotoFuture<UpdateWebhookOutput, UpdateWebhookError> {
+        (**self).update_webhook(input)
+    }
+}
+/// A client for the AWS CodeBuild API.
+#[derive(Clone)]
+pub struct CodeBuildClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CodeBuildClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeBuildClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeBuildClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeBuildClient {
+        CodeBuildClient { client, region }
+    }
+}
+
+impl CodeBuild for CodeBuildClient {
+    /// <p>Deletes one or more builds.</p>
+    fn batch_delete_builds(
+        &self,
+        input: BatchDeleteBuildsInput,
+    ) -> RusotoFuture<BatchDeleteBuildsOutput, BatchDeleteBuildsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchDeleteBuilds");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeBuild_20161006.BatchDeleteBuilds");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDeleteBuildsOutput, _>(
+                    response,
+                    "BatchDeleteBuilds",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchDeleteBuildsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about builds.</p>
+    fn batch_get_builds(
+        &self,
+        input: BatchGetBuildsInput,
+    ) -> RusotoFuture<BatchGetBuildsOutput, BatchGetBuildsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchGetBuilds");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetBuilds");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetBuildsOutput, _>(
+                    response,
+                    "BatchGetBuilds",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetBuildsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about build projects.</p>
+    fn batch_get_projects(
+        &self,
+        input: BatchGetProjectsInput,
+    ) -> RusotoFuture<BatchGetProjectsOutput, BatchGetProjectsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchGetProjects");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetProjects");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetProjectsOutput, _>(
+                    response,
+                    "BatchGetProjects",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetProjectsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a build project.</p>
+    fn create_project(
+        &self,
+        input: CreateProjectInput,
+    ) -> RusotoFuture<CreateProjectOutput, CreateProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("CreateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateProject");
@@ -2330,10 +3348,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateProjectOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateProjectOutput, _>(
+                    response,
+                    "CreateProject",
+                )
             } else {
                 Box::new(
                     response
@@ -2350,7 +3368,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: CreateWebhookInput,
     ) -> RusotoFuture<CreateWebhookOutput, CreateWebhookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("CreateWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateWebhook");
@@ -2359,10 +3386,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateWebhookOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateWebhookOutput, _>(
+                    response,
+                    "CreateWebhook",
+                )
             } else {
                 Box::new(
                     response
@@ -2379,7 +3406,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: DeleteProjectInput,
     ) -> RusotoFuture<DeleteProjectOutput, DeleteProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteProject");
@@ -2388,10 +3424,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteProjectOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteProjectOutput, _>(
+                    response,
+                    "DeleteProject",
+                )
             } else {
                 Box::new(
                     response
@@ -2408,7 +3444,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: DeleteSourceCredentialsInput,
     ) -> RusotoFuture<DeleteSourceCredentialsOutput, DeleteSourceCredentialsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteSourceCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteSourceCredentials");
@@ -2417,10 +3462,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSourceCredentialsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteSourceCredentialsOutput, _>(
+                    response,
+                    "DeleteSourceCredentials",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DeleteSourceCredentialsError::from_response(response))
@@ -2434,7 +3479,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: DeleteWebhookInput,
     ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteWebhook");
@@ -2443,10 +3497,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteWebhookOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteWebhookOutput, _>(
+                    response,
+                    "DeleteWebhook",
+                )
             } else {
                 Box::new(
                     response
@@ -2463,7 +3517,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: ImportSourceCredentialsInput,
     ) -> RusotoFuture<ImportSourceCredentialsOutput, ImportSourceCredentialsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ImportSourceCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ImportSourceCredentials");
@@ -2472,10 +3535,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportSourceCredentialsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ImportSourceCredentialsOutput, _>(
+                    response,
+                    "ImportSourceCredentials",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ImportSourceCredentialsError::from_response(response))
@@ -2489,7 +3552,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: InvalidateProjectCacheInput,
     ) -> RusotoFuture<InvalidateProjectCacheOutput, InvalidateProjectCacheError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("InvalidateProjectCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.InvalidateProjectCache");
@@ -2498,10 +3570,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InvalidateProjectCacheOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<InvalidateProjectCacheOutput, _>(
+                    response,
+                    "InvalidateProjectCache",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2518,6 +3590,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListBuildsInput,
     ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListBuilds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuilds");
@@ -2526,10 +3599,7 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBuildsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListBuildsOutput, _>(response, "ListBuilds")
             } else {
                 Box::new(
                     response
@@ -2546,7 +3616,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: ListBuildsForProjectInput,
     ) -> RusotoFuture<ListBuildsForProjectOutput, ListBuildsForProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListBuildsForProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuildsForProject");
@@ -2555,10 +3634,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBuildsForProjectOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListBuildsForProjectOutput, _>(
+                    response,
+                    "ListBuildsForProject",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2574,6 +3653,7 @@ impl CodeBuild for CodeBuildClient {
         &self,
     ) -> RusotoFuture<ListCuratedEnvironmentImagesOutput, ListCuratedEnvironmentImagesError> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListCuratedEnvironmentImages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2584,10 +3664,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCuratedEnvironmentImagesOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListCuratedEnvironmentImagesOutput, _>(
+                    response,
+                    "ListCuratedEnvironmentImages",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ListCuratedEnvironmentImagesError::from_response(response))
@@ -2601,7 +3681,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: ListProjectsInput,
     ) -> RusotoFuture<ListProjectsOutput, ListProjectsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListProjects");
@@ -2610,10 +3699,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListProjectsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListProjectsOutput, _>(
+                    response,
+                    "ListProjects",
+                )
             } else {
                 Box::new(
                     response
@@ -2630,6 +3719,7 @@ impl CodeBuild for CodeBuildClient {
         &self,
     ) -> RusotoFuture<ListSourceCredentialsOutput, ListSourceCredentialsError> {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListSourceCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSourceCredentials");
@@ -2637,10 +3727,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSourceCredentialsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListSourceCredentialsOutput, _>(
+                    response,
+                    "ListSourceCredentials",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2656,7 +3746,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: StartBuildInput,
     ) -> RusotoFuture<StartBuildOutput, StartBuildError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("StartBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.StartBuild");
@@ -2665,10 +3764,7 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartBuildOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<StartBuildOutput, _>(response, "StartBuild")
             } else {
                 Box::new(
                     response
@@ -2682,7 +3778,16 @@ impl CodeBuild for CodeBuildClient {
 
     /// <p>Attempts to stop running a build.</p>
     fn stop_build(&self, input: StopBuildInput) -> RusotoFuture<StopBuildOutput, StopBuildError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("StopBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.StopBuild");
@@ -2691,9 +3796,7 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<StopBuildOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<StopBuildOutput, _>(response, "StopBuild")
             } else {
                 Box::new(
                     response
@@ -2710,7 +3813,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: UpdateProjectInput,
     ) -> RusotoFuture<UpdateProjectOutput, UpdateProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("UpdateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateProject");
@@ -2719,10 +3831,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateProjectOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateProjectOutput, _>(
+                    response,
+                    "UpdateProject",
+                )
             } else {
                 Box::new(
                     response
@@ -2739,7 +3851,16 @@ impl CodeBuild for CodeBuildClient {
         &self,
         input: UpdateWebhookInput,
     ) -> RusotoFuture<UpdateWebhookOutput, UpdateWebhookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("UpdateWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateWebhook");
@@ -2748,10 +3869,10 @@ impl CodeBuild for CodeBuildClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateWebhookOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateWebhookOutput, _>(
+                    response,
+                    "UpdateWebhook",
+                )
             } else {
                 Box::new(
                     response
@@ -2763,3 +3884,651 @@ impl CodeBuild for CodeBuildClient {
         })
     }
 }
+
+/// A mock of the AWS CodeBuild API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCodeBuild {
+    batch_delete_builds: Option<
+        Box<
+            dyn Fn(
+                    BatchDeleteBuildsInput,
+                ) -> ::std::result::Result<
+                    BatchDeleteBuildsOutput,
+                    RusotoError<BatchDeleteBuildsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_builds: Option<
+        Box<
+            dyn Fn(
+                    BatchGetBuildsInput,
+                )
+                    -> ::std::result::Result<BatchGetBuildsOutput, RusotoError<BatchGetBuildsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    batch_get_projects: Option<
+        Box<
+            dyn Fn(
+                    BatchGetProjectsInput,
+                ) -> ::std::result::Result<
+                    BatchGetProjectsOutput,
+                    RusotoError<BatchGetProjectsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_project: Option<
+        Box<
+            dyn Fn(
+                    CreateProjectInput,
+                )
+                    -> ::std::result::Result<CreateProjectOutput, RusotoError<CreateProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_webhook: Option<
+        Box<
+            dyn Fn(
+                    CreateWebhookInput,
+                )
+                    -> ::std::result::Result<CreateWebhookOutput, RusotoError<CreateWebhookError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_project: Option<
+        Box<
+            dyn Fn(
+                    DeleteProjectInput,
+                )
+                    -> ::std::result::Result<DeleteProjectOutput, RusotoError<DeleteProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_source_credentials: Option<
+        Box<
+            dyn Fn(
+                    DeleteSourceCredentialsInput,
+                ) -> ::std::result::Result<
+                    DeleteSourceCredentialsOutput,
+                    RusotoError<DeleteSourceCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_webhook: Option<
+        Box<
+            dyn Fn(
+                    DeleteWebhookInput,
+                )
+                    -> ::std::result::Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    import_source_credentials: Option<
+        Box<
+            dyn Fn(
+                    ImportSourceCredentialsInput,
+                ) -> ::std::result::Result<
+                    ImportSourceCredentialsOutput,
+                    RusotoError<ImportSourceCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    invalidate_project_cache: Option<
+        Box<
+            dyn Fn(
+                    InvalidateProjectCacheInput,
+                ) -> ::std::result::Result<
+                    InvalidateProjectCacheOutput,
+                    RusotoError<InvalidateProjectCacheError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_builds: Option<
+        Box<
+            dyn Fn(
+                    ListBuildsInput,
+                )
+                    -> ::std::result::Result<ListBuildsOutput, RusotoError<ListBuildsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_builds_for_project: Option<
+        Box<
+            dyn Fn(
+                    ListBuildsForProjectInput,
+                ) -> ::std::result::Result<
+                    ListBuildsForProjectOutput,
+                    RusotoError<ListBuildsForProjectError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_curated_environment_images: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    ListCuratedEnvironmentImagesOutput,
+                    RusotoError<ListCuratedEnvironmentImagesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_projects: Option<
+        Box<
+            dyn Fn(
+                    ListProjectsInput,
+                )
+                    -> ::std::result::Result<ListProjectsOutput, RusotoError<ListProjectsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_source_credentials: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    ListSourceCredentialsOutput,
+                    RusotoError<ListSourceCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_build: Option<
+        Box<
+            dyn Fn(
+                    StartBuildInput,
+                )
+                    -> ::std::result::Result<StartBuildOutput, RusotoError<StartBuildError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_build: Option<
+        Box<
+            dyn Fn(
+                    StopBuildInput,
+                )
+                    -> ::std::result::Result<StopBuildOutput, RusotoError<StopBuildError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_project: Option<
+        Box<
+            dyn Fn(
+                    UpdateProjectInput,
+                )
+                    -> ::std::result::Result<UpdateProjectOutput, RusotoError<UpdateProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_webhook: Option<
+        Box<
+            dyn Fn(
+                    UpdateWebhookInput,
+                )
+                    -> ::std::result::Result<UpdateWebhookOutput, RusotoError<UpdateWebhookError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCodeBuild {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCodeBuild {
+        Default::default()
+    }
+
+    /// Answers `batch_delete_builds` with what `handler` returns for its input.
+    pub fn with_batch_delete_builds<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                BatchDeleteBuildsInput,
+            ) -> ::std::result::Result<
+                BatchDeleteBuildsOutput,
+                RusotoError<BatchDeleteBuildsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_builds = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_builds` with what `handler` returns for its input.
+    pub fn with_batch_get_builds<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                BatchGetBuildsInput,
+            )
+                -> ::std::result::Result<BatchGetBuildsOutput, RusotoError<BatchGetBuildsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_builds = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_projects` with what `handler` returns for its input.
+    pub fn with_batch_get_projects<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                BatchGetProjectsInput,
+            )
+                -> ::std::result::Result<BatchGetProjectsOutput, RusotoError<BatchGetProjectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_projects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_project` with what `handler` returns for its input.
+    pub fn with_create_project<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                CreateProjectInput,
+            )
+                -> ::std::result::Result<CreateProjectOutput, RusotoError<CreateProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_webhook` with what `handler` returns for its input.
+    pub fn with_create_webhook<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                CreateWebhookInput,
+            )
+                -> ::std::result::Result<CreateWebhookOutput, RusotoError<CreateWebhookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_webhook = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_project` with what `handler` returns for its input.
+    pub fn with_delete_project<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                DeleteProjectInput,
+            )
+                -> ::std::result::Result<DeleteProjectOutput, RusotoError<DeleteProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_source_credentials` with what `handler` returns for its input.
+    pub fn with_delete_source_credentials<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                DeleteSourceCredentialsInput,
+            ) -> ::std::result::Result<
+                DeleteSourceCredentialsOutput,
+                RusotoError<DeleteSourceCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_source_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_webhook` with what `handler` returns for its input.
+    pub fn with_delete_webhook<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                DeleteWebhookInput,
+            )
+                -> ::std::result::Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_webhook = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_source_credentials` with what `handler` returns for its input.
+    pub fn with_import_source_credentials<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                ImportSourceCredentialsInput,
+            ) -> ::std::result::Result<
+                ImportSourceCredentialsOutput,
+                RusotoError<ImportSourceCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_source_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `invalidate_project_cache` with what `handler` returns for its input.
+    pub fn with_invalidate_project_cache<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                InvalidateProjectCacheInput,
+            ) -> ::std::result::Result<
+                InvalidateProjectCacheOutput,
+                RusotoError<InvalidateProjectCacheError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.invalidate_project_cache = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_builds` with what `handler` returns for its input.
+    pub fn with_list_builds<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                ListBuildsInput,
+            ) -> ::std::result::Result<ListBuildsOutput, RusotoError<ListBuildsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_builds = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_builds_for_project` with what `handler` returns for its input.
+    pub fn with_list_builds_for_project<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                ListBuildsForProjectInput,
+            ) -> ::std::result::Result<
+                ListBuildsForProjectOutput,
+                RusotoError<ListBuildsForProjectError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_builds_for_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_curated_environment_images` with what `handler` returns.
+    pub fn with_list_curated_environment_images<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn() -> ::std::result::Result<
+                ListCuratedEnvironmentImagesOutput,
+                RusotoError<ListCuratedEnvironmentImagesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_curated_environment_images = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_projects` with what `handler` returns for its input.
+    pub fn with_list_projects<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                ListProjectsInput,
+            )
+                -> ::std::result::Result<ListProjectsOutput, RusotoError<ListProjectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_projects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_source_credentials` with what `handler` returns.
+    pub fn with_list_source_credentials<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn() -> ::std::result::Result<
+                ListSourceCredentialsOutput,
+                RusotoError<ListSourceCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_source_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_build` with what `handler` returns for its input.
+    pub fn with_start_build<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                StartBuildInput,
+            ) -> ::std::result::Result<StartBuildOutput, RusotoError<StartBuildError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_build = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_build` with what `handler` returns for its input.
+    pub fn with_stop_build<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                StopBuildInput,
+            ) -> ::std::result::Result<StopBuildOutput, RusotoError<StopBuildError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_build = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_project` with what `handler` returns for its input.
+    pub fn with_update_project<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                UpdateProjectInput,
+            )
+                -> ::std::result::Result<UpdateProjectOutput, RusotoError<UpdateProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_webhook` with what `handler` returns for its input.
+    pub fn with_update_webhook<F>(mut self, handler: F) -> MockCodeBuild
+    where
+        F: Fn(
+                UpdateWebhookInput,
+            )
+                -> ::std::result::Result<UpdateWebhookOutput, RusotoError<UpdateWebhookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_webhook = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CodeBuild for MockCodeBuild {
+    fn batch_delete_builds(
+        &self,
+        input: BatchDeleteBuildsInput,
+    ) -> RusotoFuture<BatchDeleteBuildsOutput, BatchDeleteBuildsError> {
+        match self.batch_delete_builds {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for batch_delete_builds"),
+        }
+    }
+    fn batch_get_builds(
+        &self,
+        input: BatchGetBuildsInput,
+    ) -> RusotoFuture<BatchGetBuildsOutput, BatchGetBuildsError> {
+        match self.batch_get_builds {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for batch_get_builds"),
+        }
+    }
+    fn batch_get_projects(
+        &self,
+        input: BatchGetProjectsInput,
+    ) -> RusotoFuture<BatchGetProjectsOutput, BatchGetProjectsError> {
+        match self.batch_get_projects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for batch_get_projects"),
+        }
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectInput,
+    ) -> RusotoFuture<CreateProjectOutput, CreateProjectError> {
+        match self.create_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for create_project"),
+        }
+    }
+    fn create_webhook(
+        &self,
+        input: CreateWebhookInput,
+    ) -> RusotoFuture<CreateWebhookOutput, CreateWebhookError> {
+        match self.create_webhook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for create_webhook"),
+        }
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectInput,
+    ) -> RusotoFuture<DeleteProjectOutput, DeleteProjectError> {
+        match self.delete_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for delete_project"),
+        }
+    }
+    fn delete_source_credentials(
+        &self,
+        input: DeleteSourceCredentialsInput,
+    ) -> RusotoFuture<DeleteSourceCredentialsOutput, DeleteSourceCredentialsError> {
+        match self.delete_source_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for delete_source_credentials"),
+        }
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        match self.delete_webhook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for delete_webhook"),
+        }
+    }
+    fn import_source_credentials(
+        &self,
+        input: ImportSourceCredentialsInput,
+    ) -> RusotoFuture<ImportSourceCredentialsOutput, ImportSourceCredentialsError> {
+        match self.import_source_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for import_source_credentials"),
+        }
+    }
+    fn invalidate_project_cache(
+        &self,
+        input: InvalidateProjectCacheInput,
+    ) -> RusotoFuture<InvalidateProjectCacheOutput, InvalidateProjectCacheError> {
+        match self.invalidate_project_cache {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for invalidate_project_cache"),
+        }
+    }
+    fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
+        match self.list_builds {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for list_builds"),
+        }
+    }
+    fn list_builds_for_project(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> RusotoFuture<ListBuildsForProjectOutput, ListBuildsForProjectError> {
+        match self.list_builds_for_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for list_builds_for_project"),
+        }
+    }
+    fn list_curated_environment_images(
+        &self,
+    ) -> RusotoFuture<ListCuratedEnvironmentImagesOutput, ListCuratedEnvironmentImagesError> {
+        match self.list_curated_environment_images {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockCodeBuild has no handler for list_curated_environment_images"),
+        }
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsInput,
+    ) -> RusotoFuture<ListProjectsOutput, ListProjectsError> {
+        match self.list_projects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for list_projects"),
+        }
+    }
+    fn list_source_credentials(
+        &self,
+    ) -> RusotoFuture<ListSourceCredentialsOutput, ListSourceCredentialsError> {
+        match self.list_source_credentials {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockCodeBuild has no handler for list_source_credentials"),
+        }
+    }
+    fn start_build(
+        &self,
+        input: StartBuildInput,
+    ) -> RusotoFuture<StartBuildOutput, StartBuildError> {
+        match self.start_build {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for start_build"),
+        }
+    }
+    fn stop_build(&self, input: StopBuildInput) -> RusotoFuture<StopBuildOutput, StopBuildError> {
+        match self.stop_build {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for stop_build"),
+        }
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectInput,
+    ) -> RusotoFuture<UpdateProjectOutput, UpdateProjectError> {
+        match self.update_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for update_project"),
+        }
+    }
+    fn update_webhook(
+        &self,
+        input: UpdateWebhookInput,
+    ) -> RusotoFuture<UpdateWebhookOutput, UpdateWebhookError> {
+        match self.update_webhook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeBuild has no handler for update_webhook"),
+        }
+    }
+}
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Information about errors in a BatchDescribeMergeConflicts operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CodeCommitBatchDescribeMergeConflictsError {
     /// <p>The name of the exception.</p>
     #[serde(rename = "exceptionName")]
@@ -39,6 +40,7 @@ pub struct CodeCommitBatchDescribeMergeConflictsError {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDescribeMergeConflictsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
@@ -78,8 +80,24 @@ pub struct BatchDescribeMergeConflictsInput {
     pub source_commit_specifier: String,
 }
 
+impl Validate for BatchDescribeMergeConflictsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDescribeMergeConflictsOutput {
     /// <p>The commit ID of the merge base.</p>
     #[serde(rename = "baseCommitId")]
@@ -106,7 +124,7 @@ pub struct BatchDescribeMergeConflictsOutput {
 
 /// <p>Returns information about errors in a BatchGetCommits operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CodeCommitBatchGetCommitsError {
     /// <p>A commit ID that either could not be found or was not in a valid format.</p>
     #[serde(rename = "commitId")]
@@ -123,6 +141,7 @@ pub struct CodeCommitBatchGetCommitsError {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetCommitsInput {
     /// <p><p>The full commit IDs of the commits to get information about.</p> <note> <p>You must supply the full SHAs of each commit. You cannot use shortened SHAs.</p> </note></p>
     #[serde(rename = "commitIds")]
@@ -132,8 +151,24 @@ pub struct BatchGetCommitsInput {
     pub repository_name: String,
 }
 
+impl Validate for BatchGetCommitsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetCommitsOutput {
     /// <p>An array of commit data type objects, each of which contains information about a specified commit.</p>
     #[serde(rename = "commits")]
@@ -147,15 +182,26 @@ pub struct BatchGetCommitsOutput {
 
 /// <p>Represents the input of a batch get repositories operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetRepositoriesInput {
     /// <p>The names of the repositories to get information about.</p>
     #[serde(rename = "repositoryNames")]
     pub repository_names: Vec<String>,
 }
 
+impl Validate for BatchGetRepositoriesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.repository_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "repository_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+            errors.check_pattern(&item_path, &item, "[\\w\\.-]+");
+        }
+    }
+}
+
 /// <p>Represents the output of a batch get repositories operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetRepositoriesOutput {
     /// <p>A list of repositories returned by the batch get repositories operation.</p>
     #[serde(rename = "repositories")]
@@ -169,7 +215,7 @@ pub struct BatchGetRepositoriesOutput {
 
 /// <p>Returns information about a specific Git blob object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BlobMetadata {
     /// <p>The full ID of the blob.</p>
     #[serde(rename = "blobId")]
@@ -187,7 +233,7 @@ pub struct BlobMetadata {
 
 /// <p>Returns information about a branch.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BranchInfo {
     /// <p>The name of the branch.</p>
     #[serde(rename = "branchName")]
@@ -201,7 +247,7 @@ pub struct BranchInfo {
 
 /// <p>Returns information about a specific comment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Comment {
     /// <p>The Amazon Resource Name (ARN) of the person who posted the comment.</p>
     #[serde(rename = "authorArn")]
@@ -239,7 +285,7 @@ pub struct Comment {
 
 /// <p>Returns information about comments on the comparison between two commits.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CommentsForComparedCommit {
     /// <p>The full blob ID of the commit used to establish the 'after' of the comparison.</p>
     #[serde(rename = "afterBlobId")]
@@ -273,7 +319,7 @@ pub struct CommentsForComparedCommit {
 
 /// <p>Returns information about comments on a pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CommentsForPullRequest {
     /// <p>The full blob ID of the file on which you want to comment on the source commit.</p>
     #[serde(rename = "afterBlobId")]
@@ -311,7 +357,7 @@ pub struct CommentsForPullRequest {
 
 /// <p>Returns information about a specific commit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Commit {
     /// <p>Any additional data associated with the specified commit.</p>
     #[serde(rename = "additionalData")]
@@ -345,7 +391,7 @@ pub struct Commit {
 
 /// <p>Information about conflicts in a merge operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Conflict {
     /// <p>Metadata about a conflict in a merge operation.</p>
     #[serde(rename = "conflictMetadata")]
@@ -359,7 +405,7 @@ pub struct Conflict {
 
 /// <p>Information about the metadata for a conflict in a merge operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConflictMetadata {
     /// <p>A boolean value indicating whether there are conflicts in the content of a file.</p>
     #[serde(rename = "contentConflict")]
@@ -405,6 +451,7 @@ pub struct ConflictMetadata {
 
 /// <p>A list of inputs to use when resolving conflicts during a merge if AUTOMERGE is chosen as the conflict resolution strategy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConflictResolution {
     /// <p>Files that will be deleted as part of the merge conflict resolution.</p>
     #[serde(rename = "deleteFiles")]
@@ -420,8 +467,20 @@ pub struct ConflictResolution {
     pub set_file_modes: Option<Vec<SetFileModeEntry>>,
 }
 
+impl Validate for ConflictResolution {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.replace_contents {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "replace_contents"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Represents the input of a create branch operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBranchInput {
     /// <p>The name of the new branch to create.</p>
     #[serde(rename = "branchName")]
@@ -434,7 +493,30 @@ pub struct CreateBranchInput {
     pub repository_name: String,
 }
 
+impl Validate for CreateBranchInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCommitInput {
     /// <p>The name of the author who created the commit. This information will be used as both the author and committer for the commit.</p>
     #[serde(rename = "authorName")]
@@ -476,8 +558,36 @@ pub struct CreateCommitInput {
     pub set_file_modes: Option<Vec<SetFileModeEntry>>,
 }
 
+impl Validate for CreateCommitInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.put_files {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "put_files"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCommitOutput {
     /// <p>The full commit ID of the commit that contains your committed file changes.</p>
     #[serde(rename = "commitId")]
@@ -502,6 +612,7 @@ pub struct CreateCommitOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePullRequestInput {
     /// <p><p>A unique, client-generated idempotency token that when provided in a request, ensures the request cannot be repeated with a changed parameter. If a request is received with the same parameters and a token is included, the request will return information about the initial request that used that token.</p> <note> <p>The AWS SDKs prepopulate client request tokens. If using an AWS SDK, you do not have to generate an idempotency token, as this will be done for you.</p> </note></p>
     #[serde(rename = "clientRequestToken")]
@@ -519,8 +630,31 @@ pub struct CreatePullRequestInput {
     pub title: String,
 }
 
+impl Validate for CreatePullRequestInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(10240),
+            );
+        }
+        for (i, item) in self.targets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "title"),
+            self.title.chars().count(),
+            None,
+            Some(150),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePullRequestOutput {
     /// <p>Information about the newly created pull request.</p>
     #[serde(rename = "pullRequest")]
@@ -529,6 +663,7 @@ pub struct CreatePullRequestOutput {
 
 /// <p>Represents the input of a create repository operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRepositoryInput {
     /// <p><p>A comment or description about the new repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a web page could expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a web page.</p> </note></p>
     #[serde(rename = "repositoryDescription")]
@@ -543,9 +678,39 @@ pub struct CreateRepositoryInput {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateRepositoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.repository_description {
+            errors.check_length(
+                &field_path(path, "repository_description"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+        if let Some(ref value) = self.tags {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 /// <p>Represents the output of a create repository operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRepositoryOutput {
     /// <p>Information about the newly created repository.</p>
     #[serde(rename = "repositoryMetadata")]
@@ -554,6 +719,7 @@ pub struct CreateRepositoryOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUnreferencedMergeCommitInput {
     /// <p>The name of the author who created the unreferenced commit. This information will be used as both the author and committer for the commit.</p>
     #[serde(rename = "authorName")]
@@ -597,8 +763,27 @@ pub struct CreateUnreferencedMergeCommitInput {
     pub source_commit_specifier: String,
 }
 
+impl Validate for CreateUnreferencedMergeCommitInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.conflict_resolution {
+            value.validate_at(&field_path(path, "conflict_resolution"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUnreferencedMergeCommitOutput {
     /// <p>The full commit ID of the commit that contains your merge results.</p>
     #[serde(rename = "commitId")]
@@ -612,6 +797,7 @@ pub struct CreateUnreferencedMergeCommitOutput {
 
 /// <p>Represents the input of a delete branch operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBranchInput {
     /// <p>The name of the branch to delete.</p>
     #[serde(rename = "branchName")]
@@ -621,9 +807,31 @@ pub struct DeleteBranchInput {
     pub repository_name: String,
 }
 
+impl Validate for DeleteBranchInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a delete branch operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBranchOutput {
     /// <p>Information about the branch deleted by the operation, including the branch name and the commit ID that was the tip of the branch.</p>
     #[serde(rename = "deletedBranch")]
@@ -632,6 +840,7 @@ pub struct DeleteBranchOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCommentContentInput {
     /// <p>The unique, system-generated ID of the comment. To get this ID, use <a>GetCommentsForComparedCommit</a> or <a>GetCommentsForPullRequest</a>.</p>
     #[serde(rename = "commentId")]
@@ -639,7 +848,7 @@ pub struct DeleteCommentContentInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteCommentContentOutput {
     /// <p>Information about the comment you just deleted.</p>
     #[serde(rename = "comment")]
@@ -649,6 +858,7 @@ pub struct DeleteCommentContentOutput {
 
 /// <p>A file that will be deleted as part of a commit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFileEntry {
     /// <p>The full path of the file that will be deleted, including the name of the file.</p>
     #[serde(rename = "filePath")]
@@ -656,6 +866,7 @@ pub struct DeleteFileEntry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFileInput {
     /// <p>The name of the branch where the commit will be made deleting the file.</p>
     #[serde(rename = "branchName")]
@@ -687,8 +898,30 @@ pub struct DeleteFileInput {
     pub repository_name: String,
 }
 
+impl Validate for DeleteFileInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFileOutput {
     /// <p>The blob ID removed from the tree as part of deleting the file.</p>
     #[serde(rename = "blobId")]
@@ -706,15 +939,32 @@ pub struct DeleteFileOutput {
 
 /// <p>Represents the input of a delete repository operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRepositoryInput {
     /// <p>The name of the repository to delete.</p>
     #[serde(rename = "repositoryName")]
     pub repository_name: String,
 }
 
+impl Validate for DeleteRepositoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a delete repository operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRepositoryOutput {
     /// <p>The ID of the repository that was deleted.</p>
     #[serde(rename = "repositoryId")]
@@ -723,6 +973,7 @@ pub struct DeleteRepositoryOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMergeConflictsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
@@ -757,8 +1008,24 @@ pub struct DescribeMergeConflictsInput {
     pub source_commit_specifier: String,
 }
 
+impl Validate for DescribeMergeConflictsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMergeConflictsOutput {
     /// <p>The commit ID of the merge base.</p>
     #[serde(rename = "baseCommitId")]
@@ -783,6 +1050,7 @@ pub struct DescribeMergeConflictsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePullRequestEventsInput {
     /// <p>The Amazon Resource Name (ARN) of the user whose actions resulted in the event. Examples include updating the pull request with additional commits or changing the status of a pull request.</p>
     #[serde(rename = "actorArn")]
@@ -806,7 +1074,7 @@ pub struct DescribePullRequestEventsInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePullRequestEventsOutput {
     /// <p>An enumeration token that can be used in a request to return the next batch of the results.</p>
     #[serde(rename = "nextToken")]
@@ -819,7 +1087,7 @@ pub struct DescribePullRequestEventsOutput {
 
 /// <p>Returns information about a set of differences for a commit specifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Difference {
     /// <p>Information about an <code>afterBlob</code> data type object, including the ID, the file mode permission code, and the path.</p>
     #[serde(rename = "afterBlob")]
@@ -837,7 +1105,7 @@ pub struct Difference {
 
 /// <p>Returns information about a file in a repository.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct File {
     /// <p>The fully-qualified path to the file in the repository.</p>
     #[serde(rename = "absolutePath")]
@@ -859,7 +1127,7 @@ pub struct File {
 
 /// <p>A file that will be added, updated, or deleted as part of a commit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FileMetadata {
     /// <p>The full path to the file that will be added or updated, including the name of the file.</p>
     #[serde(rename = "absolutePath")]
@@ -877,7 +1145,7 @@ pub struct FileMetadata {
 
 /// <p>Information about file modes in a merge or pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FileModes {
     /// <p>The file mode of a file in the base of a merge or pull request.</p>
     #[serde(rename = "base")]
@@ -895,7 +1163,7 @@ pub struct FileModes {
 
 /// <p>Information about the size of files in a merge or pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FileSizes {
     /// <p>The size of a file in the base of a merge or pull request.</p>
     #[serde(rename = "base")]
@@ -913,7 +1181,7 @@ pub struct FileSizes {
 
 /// <p>Returns information about a folder in a repository.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Folder {
     /// <p>The fully-qualified path of the folder in the repository.</p>
     #[serde(rename = "absolutePath")]
@@ -931,6 +1199,7 @@ pub struct Folder {
 
 /// <p>Represents the input of a get blob operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBlobInput {
     /// <p>The ID of the blob, which is its SHA-1 pointer.</p>
     #[serde(rename = "blobId")]
@@ -940,9 +1209,25 @@ pub struct GetBlobInput {
     pub repository_name: String,
 }
 
+impl Validate for GetBlobInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a get blob operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBlobOutput {
     /// <p>The content of the blob, usually a file.</p>
     #[serde(rename = "content")]
@@ -956,6 +1241,7 @@ pub struct GetBlobOutput {
 
 /// <p>Represents the input of a get branch operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBranchInput {
     /// <p>The name of the branch for which you want to retrieve information.</p>
     #[serde(rename = "branchName")]
@@ -967,9 +1253,31 @@ pub struct GetBranchInput {
     pub repository_name: Option<String>,
 }
 
+impl Validate for GetBranchInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.branch_name {
+            errors.check_length(
+                &field_path(path, "branch_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.repository_name {
+            errors.check_length(
+                &field_path(path, "repository_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "repository_name"), &value, "[\\w\\.-]+");
+        }
+    }
+}
+
 /// <p>Represents the output of a get branch operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBranchOutput {
     /// <p>The name of the branch.</p>
     #[serde(rename = "branch")]
@@ -978,6 +1286,7 @@ pub struct GetBranchOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCommentInput {
     /// <p>The unique, system-generated ID of the comment. To get this ID, use <a>GetCommentsForComparedCommit</a> or <a>GetCommentsForPullRequest</a>.</p>
     #[serde(rename = "commentId")]
@@ -985,7 +1294,7 @@ pub struct GetCommentInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCommentOutput {
     /// <p>The contents of the comment.</p>
     #[serde(rename = "comment")]
@@ -994,6 +1303,7 @@ pub struct GetCommentOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCommentsForComparedCommitInput {
     /// <p>To establish the directionality of the comparison, the full commit ID of the 'after' commit.</p>
     #[serde(rename = "afterCommitId")]
@@ -1015,8 +1325,24 @@ pub struct GetCommentsForComparedCommitInput {
     pub repository_name: String,
 }
 
+impl Validate for GetCommentsForComparedCommitInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCommentsForComparedCommitOutput {
     /// <p>A list of comment objects on the compared commit.</p>
     #[serde(rename = "commentsForComparedCommitData")]
@@ -1029,6 +1355,7 @@ pub struct GetCommentsForComparedCommitOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCommentsForPullRequestInput {
     /// <p>The full commit ID of the commit in the source branch that was the tip of the branch at the time the comment was made.</p>
     #[serde(rename = "afterCommitId")]
@@ -1055,8 +1382,22 @@ pub struct GetCommentsForPullRequestInput {
     pub repository_name: Option<String>,
 }
 
+impl Validate for GetCommentsForPullRequestInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.repository_name {
+            errors.check_length(
+                &field_path(path, "repository_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "repository_name"), &value, "[\\w\\.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCommentsForPullRequestOutput {
     /// <p>An array of comment objects on the pull request.</p>
     #[serde(rename = "commentsForPullRequestData")]
@@ -1070,6 +1411,7 @@ pub struct GetCommentsForPullRequestOutput {
 
 /// <p>Represents the input of a get commit operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCommitInput {
     /// <p>The commit ID. Commit IDs are the full SHA of the commit.</p>
     #[serde(rename = "commitId")]
@@ -1079,9 +1421,25 @@ pub struct GetCommitInput {
     pub repository_name: String,
 }
 
+impl Validate for GetCommitInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a get commit operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCommitOutput {
     /// <p>A commit data type object that contains information about the specified commit.</p>
     #[serde(rename = "commit")]
@@ -1089,6 +1447,7 @@ pub struct GetCommitOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDifferencesInput {
     /// <p>A non-negative integer used to limit the number of returned results.</p>
     #[serde(rename = "MaxResults")]
@@ -1118,8 +1477,24 @@ pub struct GetDifferencesInput {
     pub repository_name: String,
 }
 
+impl Validate for GetDifferencesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDifferencesOutput {
     /// <p>An enumeration token that can be used in a request to return the next batch of the results.</p>
     #[serde(rename = "NextToken")]
@@ -1132,6 +1507,7 @@ pub struct GetDifferencesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFileInput {
     /// <p>The fully-quaified reference that identifies the commit that contains the file. For example, you could specify a full commit ID, a tag, a branch name, or a reference such as refs/heads/master. If none is provided, then the head commit will be used.</p>
     #[serde(rename = "commitSpecifier")]
@@ -1145,8 +1521,24 @@ pub struct GetFileInput {
     pub repository_name: String,
 }
 
+impl Validate for GetFileInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFileOutput {
     /// <p>The blob ID of the object that represents the file content.</p>
     #[serde(rename = "blobId")]
@@ -1174,6 +1566,7 @@ pub struct GetFileOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFolderInput {
     /// <p>A fully-qualified reference used to identify a commit that contains the version of the folder's content to return. A fully-qualified reference can be a commit ID, branch name, tag, or reference such as HEAD. If no specifier is provided, the folder content will be returned as it exists in the HEAD commit.</p>
     #[serde(rename = "commitSpecifier")]
@@ -1187,8 +1580,24 @@ pub struct GetFolderInput {
     pub repository_name: String,
 }
 
+impl Validate for GetFolderInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFolderOutput {
     /// <p>The full commit ID used as a reference for which version of the folder content is returned.</p>
     #[serde(rename = "commitId")]
@@ -1219,6 +1628,7 @@ pub struct GetFolderOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMergeCommitInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
@@ -1239,8 +1649,24 @@ pub struct GetMergeCommitInput {
     pub source_commit_specifier: String,
 }
 
+impl Validate for GetMergeCommitInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMergeCommitOutput {
     /// <p>The commit ID of the merge base.</p>
     #[serde(rename = "baseCommitId")]
@@ -1261,6 +1687,7 @@ pub struct GetMergeCommitOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMergeConflictsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
@@ -1292,8 +1719,24 @@ pub struct GetMergeConflictsInput {
     pub source_commit_specifier: String,
 }
 
+impl Validate for GetMergeConflictsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMergeConflictsOutput {
     /// <p>The commit ID of the merge base.</p>
     #[serde(rename = "baseCommitId")]
@@ -1318,6 +1761,7 @@ pub struct GetMergeConflictsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMergeOptionsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
@@ -1338,8 +1782,24 @@ pub struct GetMergeOptionsInput {
     pub source_commit_specifier: String,
 }
 
+impl Validate for GetMergeOptionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMergeOptionsOutput {
     /// <p>The commit ID of the merge base.</p>
     #[serde(rename = "baseCommitId")]
@@ -1356,6 +1816,7 @@ pub struct GetMergeOptionsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPullRequestInput {
     /// <p>The system-generated ID of the pull request. To get this ID, use <a>ListPullRequests</a>.</p>
     #[serde(rename = "pullRequestId")]
@@ -1363,7 +1824,7 @@ pub struct GetPullRequestInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPullRequestOutput {
     /// <p>Information about the specified pull request.</p>
     #[serde(rename = "pullRequest")]
@@ -1372,15 +1833,32 @@ pub struct GetPullRequestOutput {
 
 /// <p>Represents the input of a get repository operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRepositoryInput {
     /// <p>The name of the repository to get information about.</p>
     #[serde(rename = "repositoryName")]
     pub repository_name: String,
 }
 
+impl Validate for GetRepositoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a get repository operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRepositoryOutput {
     /// <p>Information about the repository.</p>
     #[serde(rename = "repositoryMetadata")]
@@ -1390,15 +1868,32 @@ pub struct GetRepositoryOutput {
 
 /// <p>Represents the input of a get repository triggers operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRepositoryTriggersInput {
     /// <p>The name of the repository for which the trigger is configured.</p>
     #[serde(rename = "repositoryName")]
     pub repository_name: String,
 }
 
+impl Validate for GetRepositoryTriggersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a get repository triggers operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRepositoryTriggersOutput {
     /// <p>The system-generated unique ID for the trigger.</p>
     #[serde(rename = "configurationId")]
@@ -1412,7 +1907,7 @@ pub struct GetRepositoryTriggersOutput {
 
 /// <p>Information about whether a file is binary or textual in a merge or pull request operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IsBinaryFile {
     /// <p>The binary or non-binary status of a file in the base of a merge or pull request.</p>
     #[serde(rename = "base")]
@@ -1430,6 +1925,7 @@ pub struct IsBinaryFile {
 
 /// <p>Represents the input of a list branches operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBranchesInput {
     /// <p>An enumeration token that allows the operation to batch the results.</p>
     #[serde(rename = "nextToken")]
@@ -1440,9 +1936,25 @@ pub struct ListBranchesInput {
     pub repository_name: String,
 }
 
+impl Validate for ListBranchesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a list branches operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBranchesOutput {
     /// <p>The list of branch names.</p>
     #[serde(rename = "branches")]
@@ -1455,6 +1967,7 @@ pub struct ListBranchesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPullRequestsInput {
     /// <p>Optional. The Amazon Resource Name (ARN) of the user who created the pull request. If used, this filters the results to pull requests created by that user.</p>
     #[serde(rename = "authorArn")]
@@ -1477,8 +1990,24 @@ pub struct ListPullRequestsInput {
     pub repository_name: String,
 }
 
+impl Validate for ListPullRequestsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPullRequestsOutput {
     /// <p>An enumeration token that when provided in a request, returns the next batch of the results.</p>
     #[serde(rename = "nextToken")]
@@ -1491,6 +2020,7 @@ pub struct ListPullRequestsOutput {
 
 /// <p>Represents the input of a list repositories operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRepositoriesInput {
     /// <p>An enumeration token that allows the operation to batch the results of the operation. Batch sizes are 1,000 for list repository operations. When the client sends the token back to AWS CodeCommit, another page of 1,000 records is retrieved.</p>
     #[serde(rename = "nextToken")]
@@ -1508,7 +2038,7 @@ pub struct ListRepositoriesInput {
 
 /// <p>Represents the output of a list repositories operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRepositoriesOutput {
     /// <p>An enumeration token that allows the operation to batch the results of the operation. Batch sizes are 1,000 for list repository operations. When the client sends the token back to AWS CodeCommit, another page of 1,000 records is retrieved.</p>
     #[serde(rename = "nextToken")]
@@ -1521,6 +2051,7 @@ pub struct ListRepositoriesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>An enumeration token that when provided in a request, returns the next batch of the results.</p>
     #[serde(rename = "nextToken")]
@@ -1532,7 +2063,7 @@ pub struct ListTagsForResourceInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceOutput {
     /// <p>An enumeration token that allows the operation to batch the next results of the operation.</p>
     #[serde(rename = "nextToken")]
@@ -1562,6 +2093,7 @@ pub struct Location {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MergeBranchesByFastForwardInput {
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit. For example, a branch name or a full commit ID.</p>
     #[serde(rename = "destinationCommitSpecifier")]
@@ -1578,8 +2110,32 @@ pub struct MergeBranchesByFastForwardInput {
     pub target_branch: Option<String>,
 }
 
+impl Validate for MergeBranchesByFastForwardInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+        if let Some(ref value) = self.target_branch {
+            errors.check_length(
+                &field_path(path, "target_branch"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergeBranchesByFastForwardOutput {
     /// <p>The commit ID of the merge in the destination or target branch.</p>
     #[serde(rename = "commitId")]
@@ -1592,6 +2148,7 @@ pub struct MergeBranchesByFastForwardOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MergeBranchesBySquashInput {
     /// <p>The name of the author who created the commit. This information will be used as both the author and committer for the commit.</p>
     #[serde(rename = "authorName")]
@@ -1636,8 +2193,35 @@ pub struct MergeBranchesBySquashInput {
     pub target_branch: Option<String>,
 }
 
+impl Validate for MergeBranchesBySquashInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.conflict_resolution {
+            value.validate_at(&field_path(path, "conflict_resolution"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+        if let Some(ref value) = self.target_branch {
+            errors.check_length(
+                &field_path(path, "target_branch"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergeBranchesBySquashOutput {
     /// <p>The commit ID of the merge in the destination or target branch.</p>
     #[serde(rename = "commitId")]
@@ -1650,6 +2234,7 @@ pub struct MergeBranchesBySquashOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MergeBranchesByThreeWayInput {
     /// <p>The name of the author who created the commit. This information will be used as both the author and committer for the commit.</p>
     #[serde(rename = "authorName")]
@@ -1694,8 +2279,35 @@ pub struct MergeBranchesByThreeWayInput {
     pub target_branch: Option<String>,
 }
 
+impl Validate for MergeBranchesByThreeWayInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.conflict_resolution {
+            value.validate_at(&field_path(path, "conflict_resolution"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+        if let Some(ref value) = self.target_branch {
+            errors.check_length(
+                &field_path(path, "target_branch"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergeBranchesByThreeWayOutput {
     /// <p>The commit ID of the merge in the destination or target branch.</p>
     #[serde(rename = "commitId")]
@@ -1709,7 +2321,7 @@ pub struct MergeBranchesByThreeWayOutput {
 
 /// <p>Information about merge hunks in a merge or pull request operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergeHunk {
     /// <p>Information about the merge hunk in the base of a merge or pull request.</p>
     #[serde(rename = "base")]
@@ -1731,7 +2343,7 @@ pub struct MergeHunk {
 
 /// <p>Information about the details of a merge hunk that contains a conflict in a merge or pull request operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergeHunkDetail {
     /// <p>The end position of the hunk in the merge result.</p>
     #[serde(rename = "endLine")]
@@ -1749,7 +2361,7 @@ pub struct MergeHunkDetail {
 
 /// <p>Returns information about a merge or potential merge between a source reference and a destination reference in a pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergeMetadata {
     /// <p>A Boolean value indicating whether the merge has been made.</p>
     #[serde(rename = "isMerged")]
@@ -1771,7 +2383,7 @@ pub struct MergeMetadata {
 
 /// <p>Information about the file operation conflicts in a merge operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergeOperations {
     /// <p>The operation on a file in the destination of a merge or pull request.</p>
     #[serde(rename = "destination")]
@@ -1784,6 +2396,7 @@ pub struct MergeOperations {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MergePullRequestByFastForwardInput {
     /// <p>The system-generated ID of the pull request. To get this ID, use <a>ListPullRequests</a>.</p>
     #[serde(rename = "pullRequestId")]
@@ -1797,8 +2410,24 @@ pub struct MergePullRequestByFastForwardInput {
     pub source_commit_id: Option<String>,
 }
 
+impl Validate for MergePullRequestByFastForwardInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergePullRequestByFastForwardOutput {
     /// <p>Information about the specified pull request, including information about the merge.</p>
     #[serde(rename = "pullRequest")]
@@ -1807,6 +2436,7 @@ pub struct MergePullRequestByFastForwardOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MergePullRequestBySquashInput {
     /// <p>The name of the author who created the commit. This information will be used as both the author and committer for the commit.</p>
     #[serde(rename = "authorName")]
@@ -1848,8 +2478,27 @@ pub struct MergePullRequestBySquashInput {
     pub source_commit_id: Option<String>,
 }
 
+impl Validate for MergePullRequestBySquashInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.conflict_resolution {
+            value.validate_at(&field_path(path, "conflict_resolution"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergePullRequestBySquashOutput {
     #[serde(rename = "pullRequest")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1857,6 +2506,7 @@ pub struct MergePullRequestBySquashOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MergePullRequestByThreeWayInput {
     /// <p>The name of the author who created the commit. This information will be used as both the author and committer for the commit.</p>
     #[serde(rename = "authorName")]
@@ -1898,8 +2548,27 @@ pub struct MergePullRequestByThreeWayInput {
     pub source_commit_id: Option<String>,
 }
 
+impl Validate for MergePullRequestByThreeWayInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.conflict_resolution {
+            value.validate_at(&field_path(path, "conflict_resolution"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergePullRequestByThreeWayOutput {
     #[serde(rename = "pullRequest")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1908,7 +2577,7 @@ pub struct MergePullRequestByThreeWayOutput {
 
 /// <p>Information about the type of an object in a merge operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ObjectTypes {
     /// <p>The type of the object in the base commit of the merge.</p>
     #[serde(rename = "base")]
@@ -1925,6 +2594,7 @@ pub struct ObjectTypes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PostCommentForComparedCommitInput {
     /// <p>To establish the directionality of the comparison, the full commit ID of the 'after' commit.</p>
     #[serde(rename = "afterCommitId")]
@@ -1949,8 +2619,24 @@ pub struct PostCommentForComparedCommitInput {
     pub repository_name: String,
 }
 
+impl Validate for PostCommentForComparedCommitInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PostCommentForComparedCommitOutput {
     /// <p>In the directionality you established, the blob ID of the 'after' blob.</p>
     #[serde(rename = "afterBlobId")]
@@ -1983,6 +2669,7 @@ pub struct PostCommentForComparedCommitOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PostCommentForPullRequestInput {
     /// <p>The full commit ID of the commit in the source branch that is the current tip of the branch for the pull request when you post the comment.</p>
     #[serde(rename = "afterCommitId")]
@@ -2009,8 +2696,24 @@ pub struct PostCommentForPullRequestInput {
     pub repository_name: String,
 }
 
+impl Validate for PostCommentForPullRequestInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PostCommentForPullRequestOutput {
     /// <p>In the directionality of the pull request, the blob ID of the 'after' blob.</p>
     #[serde(rename = "afterBlobId")]
@@ -2047,6 +2750,7 @@ pub struct PostCommentForPullRequestOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PostCommentReplyInput {
     /// <p>A unique, client-generated idempotency token that when provided in a request, ensures the request cannot be repeated with a changed parameter. If a request is received with the same parameters and a token is included, the request will return information about the initial request that used that token.</p>
     #[serde(rename = "clientRequestToken")]
@@ -2061,7 +2765,7 @@ pub struct PostCommentReplyInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PostCommentReplyOutput {
     /// <p>Information about the reply to a comment.</p>
     #[serde(rename = "comment")]
@@ -2071,7 +2775,7 @@ pub struct PostCommentReplyOutput {
 
 /// <p>Returns information about a pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PullRequest {
     /// <p>The Amazon Resource Name (ARN) of the user who created the pull request.</p>
     #[serde(rename = "authorArn")]
@@ -2113,7 +2817,7 @@ pub struct PullRequest {
 
 /// <p>Metadata about the pull request that is used when comparing the pull request source with its destination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PullRequestCreatedEventMetadata {
     /// <p>The commit ID of the tip of the branch specified as the destination branch when the pull request was created.</p>
     #[serde(rename = "destinationCommitId")]
@@ -2135,7 +2839,7 @@ pub struct PullRequestCreatedEventMetadata {
 
 /// <p>Returns information about a pull request event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PullRequestEvent {
     /// <p>The Amazon Resource Name (ARN) of the user whose actions resulted in the event. Examples include updating the pull request with additional commits or changing the status of a pull request.</p>
     #[serde(rename = "actorArn")]
@@ -2175,7 +2879,7 @@ pub struct PullRequestEvent {
 
 /// <p>Returns information about the change in the merge state for a pull request event. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PullRequestMergedStateChangedEventMetadata {
     /// <p>The name of the branch that the pull request will be merged into.</p>
     #[serde(rename = "destinationReference")]
@@ -2193,7 +2897,7 @@ pub struct PullRequestMergedStateChangedEventMetadata {
 
 /// <p>Information about an update to the source branch of a pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PullRequestSourceReferenceUpdatedEventMetadata {
     /// <p>The full commit ID of the commit in the source branch that was the tip of the branch at the time the pull request was updated.</p>
     #[serde(rename = "afterCommitId")]
@@ -2215,7 +2919,7 @@ pub struct PullRequestSourceReferenceUpdatedEventMetadata {
 
 /// <p>Information about a change to the status of a pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PullRequestStatusChangedEventMetadata {
     /// <p>The changed status of the pull request.</p>
     #[serde(rename = "pullRequestStatus")]
@@ -2225,7 +2929,7 @@ pub struct PullRequestStatusChangedEventMetadata {
 
 /// <p>Returns information about a pull request target.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PullRequestTarget {
     /// <p>The full commit ID that is the tip of the destination branch. This is the commit where the pull request was or will be merged.</p>
     #[serde(rename = "destinationCommit")]
@@ -2259,6 +2963,7 @@ pub struct PullRequestTarget {
 
 /// <p>Information about a file that will be added or updated as part of a commit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutFileEntry {
     /// <p>The content of the file, if a source file is not specified.</p>
     #[serde(rename = "fileContent")]
@@ -2282,7 +2987,21 @@ pub struct PutFileEntry {
     pub source_file: Option<SourceFileSpecifier>,
 }
 
+impl Validate for PutFileEntry {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.file_content {
+            errors.check_length(
+                &field_path(path, "file_content"),
+                value.len(),
+                None,
+                Some(6291456),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutFileInput {
     /// <p>The name of the branch where you want to add or update the file. If this is an empty repository, this branch will be created.</p>
     #[serde(rename = "branchName")]
@@ -2323,8 +3042,36 @@ pub struct PutFileInput {
     pub repository_name: String,
 }
 
+impl Validate for PutFileInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "branch_name"),
+            self.branch_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "file_content"),
+            self.file_content.len(),
+            None,
+            Some(6291456),
+        );
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutFileOutput {
     /// <p>The ID of the blob, which is its SHA-1 pointer.</p>
     #[serde(rename = "blobId")]
@@ -2339,6 +3086,7 @@ pub struct PutFileOutput {
 
 /// <p>Represents the input ofa put repository triggers operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRepositoryTriggersInput {
     /// <p>The name of the repository where you want to create or update the trigger.</p>
     #[serde(rename = "repositoryName")]
@@ -2348,9 +3096,29 @@ pub struct PutRepositoryTriggersInput {
     pub triggers: Vec<RepositoryTrigger>,
 }
 
+impl Validate for PutRepositoryTriggersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+        for (i, item) in self.triggers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "triggers"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Represents the output of a put repository triggers operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRepositoryTriggersOutput {
     /// <p>The system-generated unique ID for the create or update operation.</p>
     #[serde(rename = "configurationId")]
@@ -2360,6 +3128,7 @@ pub struct PutRepositoryTriggersOutput {
 
 /// <p>Information about a replacement content entry in the conflict of a merge or pull request operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplaceContentEntry {
     /// <p>The base-64 encoded content to use when the replacement type is USE_NEW_CONTENT.</p>
     #[serde(rename = "content")]
@@ -2382,9 +3151,22 @@ pub struct ReplaceContentEntry {
     pub replacement_type: String,
 }
 
+impl Validate for ReplaceContentEntry {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.content {
+            errors.check_length(
+                &field_path(path, "content"),
+                value.len(),
+                None,
+                Some(6291456),
+            );
+        }
+    }
+}
+
 /// <p>Information about a repository.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RepositoryMetadata {
     /// <p>The Amazon Resource Name (ARN) of the repository.</p>
     #[serde(rename = "Arn")]
@@ -2430,7 +3212,7 @@ pub struct RepositoryMetadata {
 
 /// <p>Information about a repository name and ID.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RepositoryNameIdPair {
     /// <p>The ID associated with the repository.</p>
     #[serde(rename = "repositoryId")]
@@ -2464,9 +3246,20 @@ pub struct RepositoryTrigger {
     pub name: String,
 }
 
+impl Validate for RepositoryTrigger {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.branches {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "branches"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 /// <p>A trigger failed to run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RepositoryTriggerExecutionFailure {
     /// <p>Additional message information about the trigger that did not run.</p>
     #[serde(rename = "failureMessage")]
@@ -2480,6 +3273,7 @@ pub struct RepositoryTriggerExecutionFailure {
 
 /// <p>Information about the file mode changes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetFileModeEntry {
     /// <p>The file mode for the file.</p>
     #[serde(rename = "fileMode")]
@@ -2491,6 +3285,7 @@ pub struct SetFileModeEntry {
 
 /// <p>Information about a source file that is part of changes made in a commit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SourceFileSpecifier {
     /// <p>The full path to the file, including the name of the file.</p>
     #[serde(rename = "filePath")]
@@ -2503,7 +3298,7 @@ pub struct SourceFileSpecifier {
 
 /// <p>Returns information about a submodule reference in a repository folder.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubModule {
     /// <p>The fully qualified path to the folder that contains the reference to the submodule.</p>
     #[serde(rename = "absolutePath")]
@@ -2521,7 +3316,7 @@ pub struct SubModule {
 
 /// <p>Returns information about a symbolic link in a repository folder.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SymbolicLink {
     /// <p>The fully-qualified path to the folder that contains the symbolic link.</p>
     #[serde(rename = "absolutePath")]
@@ -2542,6 +3337,7 @@ pub struct SymbolicLink {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the resource to which you want to add or update tags.</p>
     #[serde(rename = "resourceArn")]
@@ -2551,8 +3347,18 @@ pub struct TagResourceInput {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+        }
+    }
+}
+
 /// <p>Returns information about a target for a pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Target {
     /// <p>The branch of the repository where the pull request changes will be merged into. Also known as the destination branch.</p>
     #[serde(rename = "destinationReference")]
@@ -2566,8 +3372,25 @@ pub struct Target {
     pub source_reference: String,
 }
 
+impl Validate for Target {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 /// <p>Represents the input of a test repository triggers operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestRepositoryTriggersInput {
     /// <p>The name of the repository in which to test the triggers.</p>
     #[serde(rename = "repositoryName")]
@@ -2577,9 +3400,29 @@ pub struct TestRepositoryTriggersInput {
     pub triggers: Vec<RepositoryTrigger>,
 }
 
+impl Validate for TestRepositoryTriggersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+        for (i, item) in self.triggers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "triggers"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Represents the output of a test repository triggers operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TestRepositoryTriggersOutput {
     /// <p>The list of triggers that were not able to be tested. This list provides the names of the triggers that could not be tested, separated by commas.</p>
     #[serde(rename = "failedExecutions")]
@@ -2592,6 +3435,7 @@ pub struct TestRepositoryTriggersOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the resource to which you want to remove tags.</p>
     #[serde(rename = "resourceArn")]
@@ -2601,7 +3445,17 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCommentInput {
     /// <p>The system-generated ID of the comment you want to update. To get this ID, use <a>GetCommentsForComparedCommit</a> or <a>GetCommentsForPullRequest</a>.</p>
     #[serde(rename = "commentId")]
@@ -2612,7 +3466,7 @@ pub struct UpdateCommentInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateCommentOutput {
     /// <p>Information about the updated comment.</p>
     #[serde(rename = "comment")]
@@ -2622,6 +3476,7 @@ pub struct UpdateCommentOutput {
 
 /// <p>Represents the input of an update default branch operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDefaultBranchInput {
     /// <p>The name of the branch to set as the default.</p>
     #[serde(rename = "defaultBranchName")]
@@ -2631,7 +3486,30 @@ pub struct UpdateDefaultBranchInput {
     pub repository_name: String,
 }
 
+impl Validate for UpdateDefaultBranchInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "default_branch_name"),
+            self.default_branch_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePullRequestDescriptionInput {
     /// <p>The updated content of the description for the pull request. This content will replace the existing description.</p>
     #[serde(rename = "description")]
@@ -2641,8 +3519,19 @@ pub struct UpdatePullRequestDescriptionInput {
     pub pull_request_id: String,
 }
 
+impl Validate for UpdatePullRequestDescriptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "description"),
+            self.description.chars().count(),
+            None,
+            Some(10240),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePullRequestDescriptionOutput {
     /// <p>Information about the updated pull request.</p>
     #[serde(rename = "pullRequest")]
@@ -2650,6 +3539,7 @@ pub struct UpdatePullRequestDescriptionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePullRequestStatusInput {
     /// <p>The system-generated ID of the pull request. To get this ID, use <a>ListPullRequests</a>.</p>
     #[serde(rename = "pullRequestId")]
@@ -2660,7 +3550,7 @@ pub struct UpdatePullRequestStatusInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePullRequestStatusOutput {
     /// <p>Information about the pull request.</p>
     #[serde(rename = "pullRequest")]
@@ -2668,6 +3558,7 @@ pub struct UpdatePullRequestStatusOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePullRequestTitleInput {
     /// <p>The system-generated ID of the pull request. To get this ID, use <a>ListPullRequests</a>.</p>
     #[serde(rename = "pullRequestId")]
@@ -2677,8 +3568,19 @@ pub struct UpdatePullRequestTitleInput {
     pub title: String,
 }
 
+impl Validate for UpdatePullRequestTitleInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "title"),
+            self.title.chars().count(),
+            None,
+            Some(150),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePullRequestTitleOutput {
     /// <p>Information about the updated pull request.</p>
     #[serde(rename = "pullRequest")]
@@ -2687,6 +3589,7 @@ pub struct UpdatePullRequestTitleOutput {
 
 /// <p>Represents the input of an update repository description operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRepositoryDescriptionInput {
     /// <p>The new comment or description for the specified repository. Repository descriptions are limited to 1,000 characters.</p>
     #[serde(rename = "repositoryDescription")]
@@ -2697,8 +3600,33 @@ pub struct UpdateRepositoryDescriptionInput {
     pub repository_name: String,
 }
 
-/// <p>Represents the input of an update repository description operation.</p>
+impl Validate for UpdateRepositoryDescriptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.repository_description {
+            errors.check_length(
+                &field_path(path, "repository_description"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "[\\w\\.-]+",
+        );
+    }
+}
+
+/// <p>Represents the input of an update repository description operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRepositoryNameInput {
     /// <p>The new name for the repository.</p>
     #[serde(rename = "newName")]
@@ -2708,9 +3636,28 @@ pub struct UpdateRepositoryNameInput {
     pub old_name: String,
 }
 
+impl Validate for UpdateRepositoryNameInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "new_name"),
+            self.new_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "new_name"), &self.new_name, "[\\w\\.-]+");
+        errors.check_length(
+            &field_path(path, "old_name"),
+            self.old_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "old_name"), &self.old_name, "[\\w\\.-]+");
+    }
+}
+
 /// <p>Information about the user who made a specified commit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserInfo {
     /// <p>The date when the specified commit was commited, in timestamp format with GMT offset.</p>
     #[serde(rename = "date")]
@@ -2886,7 +3833,12 @@ impl BatchDescribeMergeConflictsError {
                         BatchDescribeMergeConflictsError::TipsDivergenceExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3004,7 +3956,12 @@ impl BatchGetCommitsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3097,7 +4054,12 @@ impl BatchGetRepositoriesError {
                         BatchGetRepositoriesError::RepositoryNamesRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3208,7 +4170,12 @@ impl CreateBranchError {
                 "RepositoryNameRequiredException" => {
                     return RusotoError::Service(CreateBranchError::RepositoryNameRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3467,7 +4434,12 @@ impl CreateCommitError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3695,7 +4667,12 @@ impl CreatePullRequestError {
                 "TitleRequiredException" => {
                     return RusotoError::Service(CreatePullRequestError::TitleRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3839,7 +4816,12 @@ impl CreateRepositoryError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(CreateRepositoryError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4142,7 +5124,12 @@ impl CreateUnreferencedMergeCommitError {
                         CreateUnreferencedMergeCommitError::TipsDivergenceExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4276,7 +5263,12 @@ impl DeleteBranchError {
                 "RepositoryNameRequiredException" => {
                     return RusotoError::Service(DeleteBranchError::RepositoryNameRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4340,7 +5332,12 @@ impl DeleteCommentContentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4487,7 +5484,12 @@ impl DeleteFileError {
                 "RepositoryNameRequiredException" => {
                     return RusotoError::Service(DeleteFileError::RepositoryNameRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4585,7 +5587,12 @@ impl DeleteRepositoryError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4776,7 +5783,12 @@ impl DescribeMergeConflictsError {
                         DescribeMergeConflictsError::TipsDivergenceExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4917,7 +5929,12 @@ impl DescribePullRequestEventsError {
                         DescribePullRequestEventsError::PullRequestIdRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5019,7 +6036,12 @@ impl GetBlobError {
                 "RepositoryNameRequiredException" => {
                     return RusotoError::Service(GetBlobError::RepositoryNameRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5115,7 +6137,12 @@ impl GetBranchError {
                 "RepositoryNameRequiredException" => {
                     return RusotoError::Service(GetBranchError::RepositoryNameRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5173,7 +6200,12 @@ impl GetCommentError {
                 "InvalidCommentIdException" => {
                     return RusotoError::Service(GetCommentError::InvalidCommentId(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5297,7 +6329,12 @@ impl GetCommentsForComparedCommitError {
                         GetCommentsForComparedCommitError::RepositoryNameRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5458,7 +6495,12 @@ impl GetCommentsForPullRequestError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5561,7 +6603,12 @@ impl GetCommitError {
                 "RepositoryNameRequiredException" => {
                     return RusotoError::Service(GetCommitError::RepositoryNameRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5697,7 +6744,12 @@ impl GetDifferencesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5812,7 +6864,12 @@ impl GetFileError {
                 "RepositoryNameRequiredException" => {
                     return RusotoError::Service(GetFileError::RepositoryNameRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5920,7 +6977,12 @@ impl GetFolderError {
                 "RepositoryNameRequiredException" => {
                     return RusotoError::Service(GetFolderError::RepositoryNameRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6045,7 +7107,12 @@ impl GetMergeCommitError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6235,7 +7302,12 @@ impl GetMergeConflictsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6390,7 +7462,12 @@ impl GetMergeOptionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6487,7 +7564,12 @@ impl GetPullRequestError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6572,7 +7654,12 @@ impl GetRepositoryError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6663,7 +7750,12 @@ impl GetRepositoryTriggersError {
                         GetRepositoryTriggersError::RepositoryNameRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6751,7 +7843,12 @@ impl ListBranchesError {
                 "RepositoryNameRequiredException" => {
                     return RusotoError::Service(ListBranchesError::RepositoryNameRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6872,7 +7969,12 @@ impl ListPullRequestsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6929,7 +8031,12 @@ impl ListRepositoriesError {
                 "InvalidSortByException" => {
                     return RusotoError::Service(ListRepositoriesError::InvalidSortBy(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6987,7 +8094,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7153,7 +8265,12 @@ impl MergeBranchesByFastForwardError {
                         MergeBranchesByFastForwardError::TipsDivergenceExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7466,7 +8583,12 @@ impl MergeBranchesBySquashError {
                         MergeBranchesBySquashError::TipsDivergenceExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7807,7 +8929,12 @@ impl MergeBranchesByThreeWayError {
                         MergeBranchesByThreeWayError::TipsDivergenceExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8009,7 +9136,12 @@ impl MergePullRequestByFastForwardError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8319,7 +9451,12 @@ impl MergePullRequestBySquashError {
                         MergePullRequestBySquashError::TipsDivergenceExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8652,7 +9789,12 @@ impl MergePullRequestByThreeWayError {
                         MergePullRequestByThreeWayError::TipsDivergenceExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8885,7 +10027,12 @@ impl PostCommentForComparedCommitError {
                         PostCommentForComparedCommitError::RepositoryNameRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9130,7 +10277,12 @@ impl PostCommentForPullRequestError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9240,7 +10392,12 @@ impl PostCommentReplyError {
                 "InvalidCommentIdException" => {
                     return RusotoError::Service(PostCommentReplyError::InvalidCommentId(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9437,7 +10594,12 @@ impl PutFileError {
                 "SameFileContentException" => {
                     return RusotoError::Service(PutFileError::SameFileContent(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9645,7 +10807,12 @@ impl PutRepositoryTriggersError {
                         PutRepositoryTriggersError::RepositoryTriggersListRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9738,7 +10905,12 @@ impl TagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9927,7 +11099,12 @@ impl TestRepositoryTriggersError {
                         TestRepositoryTriggersError::RepositoryTriggersListRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10026,7 +11203,12 @@ impl UntagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10103,7 +11285,12 @@ impl UpdateCommentError {
                 "InvalidCommentIdException" => {
                     return RusotoError::Service(UpdateCommentError::InvalidCommentId(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10214,7 +11401,12 @@ impl UpdateDefaultBranchError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10289,7 +11481,12 @@ impl UpdatePullRequestDescriptionError {
                         UpdatePullRequestDescriptionError::PullRequestIdRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10398,7 +11595,12 @@ impl UpdatePullRequestStatusError {
                         UpdatePullRequestStatusError::PullRequestStatusRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10476,7 +11678,12 @@ impl UpdatePullRequestTitleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10574,7 +11781,12 @@ impl UpdateRepositoryDescriptionError {
                         UpdateRepositoryDescriptionError::RepositoryNameRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10638,7 +11850,12 @@ impl UpdateRepositoryNameError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10959,1545 +12176,4630 @@ pub trait CodeCommit {
         input: UpdateRepositoryNameInput,
     ) -> RusotoFuture<(), UpdateRepositoryNameError>;
 }
-/// A client for the CodeCommit API.
-#[derive(Clone)]
-pub struct CodeCommitClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CodeCommitClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CodeCommitClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CodeCommitClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CodeCommitClient {
-        CodeCommitClient { client, region }
-    }
-}
-
-impl CodeCommit for CodeCommitClient {
-    /// <p>Returns information about one or more merge conflicts in the attempted merge of two commit specifiers using the squash or three-way merge strategy.</p>
+const _: Option<&dyn CodeCommit> = None;
+impl<'a, T: CodeCommit + ?Sized> CodeCommit for &'a T {
     fn batch_describe_merge_conflicts(
         &self,
         input: BatchDescribeMergeConflictsInput,
     ) -> RusotoFuture<BatchDescribeMergeConflictsOutput, BatchDescribeMergeConflictsError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.BatchDescribeMergeConflicts",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDescribeMergeConflictsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchDescribeMergeConflictsError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_describe_merge_conflicts(input)
     }
-
-    /// <p>Returns information about the contents of one or more commits in a repository.</p>
     fn batch_get_commits(
         &self,
         input: BatchGetCommitsInput,
     ) -> RusotoFuture<BatchGetCommitsOutput, BatchGetCommitsError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetCommits");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetCommitsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetCommitsError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_commits(input)
     }
-
-    /// <p><p>Returns information about one or more repositories.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a web page could expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a web page.</p> </note></p>
     fn batch_get_repositories(
         &self,
         input: BatchGetRepositoriesInput,
     ) -> RusotoFuture<BatchGetRepositoriesOutput, BatchGetRepositoriesError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetRepositories");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetRepositoriesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchGetRepositoriesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_get_repositories(input)
     }
-
-    /// <p><p>Creates a new branch in a repository and points the branch to a commit.</p> <note> <p>Calling the create branch operation does not set a repository&#39;s default branch. To do this, call the update default branch operation.</p> </note></p>
     fn create_branch(&self, input: CreateBranchInput) -> RusotoFuture<(), CreateBranchError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.CreateBranch");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBranchError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_branch(input)
     }
-
-    /// <p>Creates a commit for a repository on the tip of a specified branch.</p>
     fn create_commit(
         &self,
         input: CreateCommitInput,
     ) -> RusotoFuture<CreateCommitOutput, CreateCommitError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.CreateCommit");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCommitOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateCommitError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_commit(input)
     }
-
-    /// <p>Creates a pull request in the specified repository.</p>
     fn create_pull_request(
         &self,
         input: CreatePullRequestInput,
     ) -> RusotoFuture<CreatePullRequestOutput, CreatePullRequestError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.CreatePullRequest");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePullRequestOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreatePullRequestError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_pull_request(input)
     }
-
-    /// <p>Creates a new, empty repository.</p>
     fn create_repository(
         &self,
         input: CreateRepositoryInput,
     ) -> RusotoFuture<CreateRepositoryOutput, CreateRepositoryError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.CreateRepository");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRepositoryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateRepositoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_repository(input)
     }
-
-    /// <p><p>Creates an unreferenced commit that represents the result of merging two branches using a specified merge strategy. This can help you determine the outcome of a potential merge. This API cannot be used with the fast-forward merge strategy, as that strategy does not create a merge commit.</p> <note> <p>This unreferenced merge commit can only be accessed using the GetCommit API or through git commands such as git fetch. To retrieve this commit, you must specify its commit ID or otherwise reference it.</p> </note></p>
     fn create_unreferenced_merge_commit(
         &self,
         input: CreateUnreferencedMergeCommitInput,
     ) -> RusotoFuture<CreateUnreferencedMergeCommitOutput, CreateUnreferencedMergeCommitError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.CreateUnreferencedMergeCommit",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUnreferencedMergeCommitOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateUnreferencedMergeCommitError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_unreferenced_merge_commit(input)
     }
-
-    /// <p>Deletes a branch from a repository, unless that branch is the default branch for the repository. </p>
     fn delete_branch(
         &self,
         input: DeleteBranchInput,
     ) -> RusotoFuture<DeleteBranchOutput, DeleteBranchError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.DeleteBranch");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBranchOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBranchError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_branch(input)
     }
-
-    /// <p>Deletes the content of a comment made on a change, file, or commit in a repository.</p>
     fn delete_comment_content(
         &self,
         input: DeleteCommentContentInput,
     ) -> RusotoFuture<DeleteCommentContentOutput, DeleteCommentContentError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.DeleteCommentContent");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteCommentContentOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteCommentContentError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_comment_content(input)
     }
-
-    /// <p>Deletes a specified file from a specified branch. A commit is created on the branch that contains the revision. The file will still exist in the commits prior to the commit that contains the deletion.</p>
     fn delete_file(
         &self,
         input: DeleteFileInput,
     ) -> RusotoFuture<DeleteFileOutput, DeleteFileError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.DeleteFile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteFileOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFileError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_file(input)
     }
-
-    /// <p><p>Deletes a repository. If a specified repository was already deleted, a null repository ID will be returned.</p> <important> <p>Deleting a repository also deletes all associated objects and metadata. After a repository is deleted, all future push calls to the deleted repository will fail.</p> </important></p>
     fn delete_repository(
         &self,
         input: DeleteRepositoryInput,
     ) -> RusotoFuture<DeleteRepositoryOutput, DeleteRepositoryError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.DeleteRepository");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRepositoryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteRepositoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_repository(input)
     }
-
-    /// <p>Returns information about one or more merge conflicts in the attempted merge of two commit specifiers using the squash or three-way merge strategy. If the merge option for the attempted merge is specified as FAST_FORWARD_MERGE, an exception will be thrown.</p>
     fn describe_merge_conflicts(
         &self,
         input: DescribeMergeConflictsInput,
     ) -> RusotoFuture<DescribeMergeConflictsOutput, DescribeMergeConflictsError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.DescribeMergeConflicts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMergeConflictsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeMergeConflictsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_merge_conflicts(input)
     }
-
-    /// <p>Returns information about one or more pull request events.</p>
     fn describe_pull_request_events(
         &self,
         input: DescribePullRequestEventsInput,
     ) -> RusotoFuture<DescribePullRequestEventsOutput, DescribePullRequestEventsError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.DescribePullRequestEvents",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePullRequestEventsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribePullRequestEventsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_pull_request_events(input)
     }
-
-    /// <p>Returns the base-64 encoded content of an individual blob within a repository.</p>
     fn get_blob(&self, input: GetBlobInput) -> RusotoFuture<GetBlobOutput, GetBlobError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetBlob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetBlobOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBlobError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_blob(input)
     }
-
-    /// <p>Returns information about a repository branch, including its name and the last commit ID.</p>
     fn get_branch(&self, input: GetBranchInput) -> RusotoFuture<GetBranchOutput, GetBranchError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetBranch");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetBranchOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBranchError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_branch(input)
     }
-
-    /// <p>Returns the content of a comment made on a change, file, or commit in a repository.</p>
     fn get_comment(
         &self,
         input: GetCommentInput,
     ) -> RusotoFuture<GetCommentOutput, GetCommentError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetComment");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCommentOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetCommentError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_comment(input)
     }
-
-    /// <p>Returns information about comments made on the comparison between two commits.</p>
     fn get_comments_for_compared_commit(
         &self,
         input: GetCommentsForComparedCommitInput,
     ) -> RusotoFuture<GetCommentsForComparedCommitOutput, GetCommentsForComparedCommitError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.GetCommentsForComparedCommit",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCommentsForComparedCommitOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetCommentsForComparedCommitError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_comments_for_compared_commit(input)
     }
-
-    /// <p>Returns comments made on a pull request.</p>
     fn get_comments_for_pull_request(
         &self,
         input: GetCommentsForPullRequestInput,
     ) -> RusotoFuture<GetCommentsForPullRequestOutput, GetCommentsForPullRequestError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.GetCommentsForPullRequest",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCommentsForPullRequestOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetCommentsForPullRequestError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_comments_for_pull_request(input)
     }
-
-    /// <p>Returns information about a commit, including commit message and committer information.</p>
     fn get_commit(&self, input: GetCommitInput) -> RusotoFuture<GetCommitOutput, GetCommitError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetCommit");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetCommitOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetCommitError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_commit(input)
     }
-
-    /// <p>Returns information about the differences in a valid commit specifier (such as a branch, tag, HEAD, commit ID or other fully qualified reference). Results can be limited to a specified path.</p>
     fn get_differences(
         &self,
         input: GetDifferencesInput,
     ) -> RusotoFuture<GetDifferencesOutput, GetDifferencesError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetDifferences");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDifferencesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDifferencesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_differences(input)
     }
-
-    /// <p>Returns the base-64 encoded contents of a specified file and its metadata.</p>
     fn get_file(&self, input: GetFileInput) -> RusotoFuture<GetFileOutput, GetFileError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetFile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetFileOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFileError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_file(input)
     }
-
-    /// <p>Returns the contents of a specified folder in a repository.</p>
     fn get_folder(&self, input: GetFolderInput) -> RusotoFuture<GetFolderOutput, GetFolderError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetFolder");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetFolderOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFolderError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_folder(input)
     }
-
-    /// <p>Returns information about a specified merge commit.</p>
     fn get_merge_commit(
         &self,
         input: GetMergeCommitInput,
     ) -> RusotoFuture<GetMergeCommitOutput, GetMergeCommitError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeCommit");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMergeCommitOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMergeCommitError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_merge_commit(input)
     }
-
-    /// <p>Returns information about merge conflicts between the before and after commit IDs for a pull request in a repository.</p>
     fn get_merge_conflicts(
         &self,
         input: GetMergeConflictsInput,
     ) -> RusotoFuture<GetMergeConflictsOutput, GetMergeConflictsError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeConflicts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMergeConflictsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMergeConflictsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_merge_conflicts(input)
     }
-
-    /// <p>Returns information about the merge options available for merging two specified branches. For details about why a particular merge option is not available, use GetMergeConflicts or DescribeMergeConflicts.</p>
     fn get_merge_options(
         &self,
         input: GetMergeOptionsInput,
     ) -> RusotoFuture<GetMergeOptionsOutput, GetMergeOptionsError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeOptions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMergeOptionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMergeOptionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_merge_options(input)
     }
-
-    /// <p>Gets information about a pull request in a specified repository.</p>
     fn get_pull_request(
         &self,
         input: GetPullRequestInput,
     ) -> RusotoFuture<GetPullRequestOutput, GetPullRequestError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetPullRequest");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPullRequestOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPullRequestError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_pull_request(input)
     }
-
-    /// <p><p>Returns information about a repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a web page could expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a web page.</p> </note></p>
     fn get_repository(
         &self,
         input: GetRepositoryInput,
     ) -> RusotoFuture<GetRepositoryOutput, GetRepositoryError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetRepository");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRepositoryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRepositoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_repository(input)
     }
-
-    /// <p>Gets information about triggers configured for a repository.</p>
     fn get_repository_triggers(
         &self,
         input: GetRepositoryTriggersInput,
     ) -> RusotoFuture<GetRepositoryTriggersOutput, GetRepositoryTriggersError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.GetRepositoryTriggers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRepositoryTriggersOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetRepositoryTriggersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_repository_triggers(input)
     }
-
-    /// <p>Gets information about one or more branches in a repository.</p>
     fn list_branches(
         &self,
         input: ListBranchesInput,
     ) -> RusotoFuture<ListBranchesOutput, ListBranchesError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.ListBranches");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBranchesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListBranchesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_branches(input)
     }
-
-    /// <p>Returns a list of pull requests for a specified repository. The return list can be refined by pull request status or pull request author ARN.</p>
     fn list_pull_requests(
         &self,
         input: ListPullRequestsInput,
     ) -> RusotoFuture<ListPullRequestsOutput, ListPullRequestsError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.ListPullRequests");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPullRequestsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListPullRequestsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_pull_requests(input)
     }
-
-    /// <p>Gets information about one or more repositories.</p>
     fn list_repositories(
         &self,
         input: ListRepositoriesInput,
     ) -> RusotoFuture<ListRepositoriesOutput, ListRepositoriesError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.ListRepositories");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRepositoriesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRepositoriesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_repositories(input)
     }
-
-    /// <p>Gets information about AWS tags for a specified Amazon Resource Name (ARN) in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the AWS CodeCommit User Guide.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Merges two branches using the fast-forward merge strategy.</p>
     fn merge_branches_by_fast_forward(
         &self,
         input: MergeBranchesByFastForwardInput,
     ) -> RusotoFuture<MergeBranchesByFastForwardOutput, MergeBranchesByFastForwardError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.MergeBranchesByFastForward",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MergeBranchesByFastForwardOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(MergeBranchesByFastForwardError::from_response(response))
-                }))
-            }
-        })
+        (**self).merge_branches_by_fast_forward(input)
     }
-
-    /// <p>Merges two branches using the squash merge strategy.</p>
     fn merge_branches_by_squash(
         &self,
         input: MergeBranchesBySquashInput,
     ) -> RusotoFuture<MergeBranchesBySquashOutput, MergeBranchesBySquashError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.MergeBranchesBySquash");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MergeBranchesBySquashOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(MergeBranchesBySquashError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).merge_branches_by_squash(input)
     }
-
-    /// <p>Merges two specified branches using the three-way merge strategy.</p>
     fn merge_branches_by_three_way(
         &self,
         input: MergeBranchesByThreeWayInput,
     ) -> RusotoFuture<MergeBranchesByThreeWayOutput, MergeBranchesByThreeWayError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.MergeBranchesByThreeWay",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MergeBranchesByThreeWayOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(MergeBranchesByThreeWayError::from_response(response))
-                }))
-            }
-        })
+        (**self).merge_branches_by_three_way(input)
     }
-
-    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the fast-forward merge strategy. If the merge is successful, it closes the pull request.</p>
     fn merge_pull_request_by_fast_forward(
         &self,
         input: MergePullRequestByFastForwardInput,
     ) -> RusotoFuture<MergePullRequestByFastForwardOutput, MergePullRequestByFastForwardError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.MergePullRequestByFastForward",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MergePullRequestByFastForwardOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(MergePullRequestByFastForwardError::from_response(response))
-                }))
-            }
-        })
+        (**self).merge_pull_request_by_fast_forward(input)
     }
-
-    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the squash merge strategy. If the merge is successful, it closes the pull request.</p>
     fn merge_pull_request_by_squash(
         &self,
         input: MergePullRequestBySquashInput,
     ) -> RusotoFuture<MergePullRequestBySquashOutput, MergePullRequestBySquashError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.MergePullRequestBySquash",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MergePullRequestBySquashOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(MergePullRequestBySquashError::from_response(response))
-                }))
-            }
-        })
+        (**self).merge_pull_request_by_squash(input)
     }
-
-    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the three-way merge strategy. If the merge is successful, it closes the pull request.</p>
     fn merge_pull_request_by_three_way(
         &self,
         input: MergePullRequestByThreeWayInput,
     ) -> RusotoFuture<MergePullRequestByThreeWayOutput, MergePullRequestByThreeWayError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.MergePullRequestByThreeWay",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MergePullRequestByThreeWayOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(MergePullRequestByThreeWayError::from_response(response))
-                }))
-            }
-        })
+        (**self).merge_pull_request_by_three_way(input)
     }
-
-    /// <p>Posts a comment on the comparison between two commits.</p>
     fn post_comment_for_compared_commit(
         &self,
         input: PostCommentForComparedCommitInput,
     ) -> RusotoFuture<PostCommentForComparedCommitOutput, PostCommentForComparedCommitError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.PostCommentForComparedCommit",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PostCommentForComparedCommitOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PostCommentForComparedCommitError::from_response(response))
-                }))
-            }
-        })
+        (**self).post_comment_for_compared_commit(input)
     }
-
-    /// <p>Posts a comment on a pull request.</p>
     fn post_comment_for_pull_request(
         &self,
         input: PostCommentForPullRequestInput,
     ) -> RusotoFuture<PostCommentForPullRequestOutput, PostCommentForPullRequestError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.PostCommentForPullRequest",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PostCommentForPullRequestOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PostCommentForPullRequestError::from_response(response))
-                }))
-            }
-        })
+        (**self).post_comment_for_pull_request(input)
     }
-
-    /// <p>Posts a comment in reply to an existing comment on a comparison between commits or a pull request.</p>
     fn post_comment_reply(
         &self,
         input: PostCommentReplyInput,
     ) -> RusotoFuture<PostCommentReplyOutput, PostCommentReplyError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.PostCommentReply");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PostCommentReplyOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PostCommentReplyError::from_response(response))),
-                )
-            }
-        })
+        (**self).post_comment_reply(input)
     }
-
-    /// <p>Adds or updates a file in a branch in an AWS CodeCommit repository, and generates a commit for the addition in the specified branch.</p>
     fn put_file(&self, input: PutFileInput) -> RusotoFuture<PutFileOutput, PutFileError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
+        (**self).put_file(input)
+    }
+    fn put_repository_triggers(
+        &self,
+        input: PutRepositoryTriggersInput,
+    ) -> RusotoFuture<PutRepositoryTriggersOutput, PutRepositoryTriggersError> {
+        (**self).put_repository_triggers(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_repository_triggers(
+        &self,
+        input: TestRepositoryTriggersInput,
+    ) -> RusotoFuture<TestRepositoryTriggersOutput, TestRepositoryTriggersError> {
+        (**self).test_repository_triggers(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_comment(
+        &self,
+        input: UpdateCommentInput,
+    ) -> RusotoFuture<UpdateCommentOutput, UpdateCommentError> {
+        (**self).update_comment(input)
+    }
+    fn update_default_branch(
+        &self,
+        input: UpdateDefaultBranchInput,
+    ) -> RusotoFuture<(), UpdateDefaultBranchError> {
+        (**self).update_default_branch(input)
+    }
+    fn update_pull_request_description(
+        &self,
+        input: UpdatePullRequestDescriptionInput,
+    ) -> RusotoFuture<UpdatePullRequestDescriptionOutput, UpdatePullRequestDescriptionError> {
+        (**self).update_pull_request_description(input)
+    }
+    fn update_pull_request_status(
+        &self,
+        input: UpdatePullRequestStatusInput,
+    ) -> RusotoFuture<UpdatePullRequestStatusOutput, UpdatePullRequestStatusError> {
+        (**self).update_pull_request_status(input)
+    }
+    fn update_pull_request_title(
+        &self,
+        input: UpdatePullRequestTitleInput,
+    ) -> RusotoFuture<UpdatePullRequestTitleOutput, UpdatePullRequestTitleError> {
+        (**self).update_pull_request_title(input)
+    }
+    fn update_repository_description(
+        &self,
+        input: UpdateRepositoryDescriptionInput,
+    ) -> RusotoFuture<(), UpdateRepositoryDescriptionError> {
+        (**self).update_repository_description(input)
+    }
+    fn update_repository_name(
+        &self,
+        input: UpdateRepositoryNameInput,
+    ) -> RusotoFuture<(), UpdateRepositoryNameError> {
+        (**self).update_repository_name(input)
+    }
+}
+impl<T: CodeCommit + ?Sized> CodeCommit for Box<T> {
+    fn batch_describe_merge_conflicts(
+        &self,
+        input: BatchDescribeMergeConflictsInput,
+    ) -> RusotoFuture<BatchDescribeMergeConflictsOutput, BatchDescribeMergeConflictsError> {
+        (**self).batch_describe_merge_conflicts(input)
+    }
+    fn batch_get_commits(
+        &self,
+        input: BatchGetCommitsInput,
+    ) -> RusotoFuture<BatchGetCommitsOutput, BatchGetCommitsError> {
+        (**self).batch_get_commits(input)
+    }
+    fn batch_get_repositories(
+        &self,
+        input: BatchGetRepositoriesInput,
+    ) -> RusotoFuture<BatchGetRepositoriesOutput, BatchGetRepositoriesError> {
+        (**self).batch_get_repositories(input)
+    }
+    fn create_branch(&self, input: CreateBranchInput) -> RusotoFuture<(), CreateBranchError> {
+        (**self).create_branch(input)
+    }
+    fn create_commit(
+        &self,
+        input: CreateCommitInput,
+    ) -> RusotoFuture<CreateCommitOutput, CreateCommitError> {
+        (**self).create_commit(input)
+    }
+    fn create_pull_request(
+        &self,
+        input: CreatePullRequestInput,
+    ) -> RusotoFuture<CreatePullRequestOutput, CreatePullRequestError> {
+        (**self).create_pull_request(input)
+    }
+    fn create_repository(
+        &self,
+        input: CreateRepositoryInput,
+    ) -> RusotoFuture<CreateRepositoryOutput, CreateRepositoryError> {
+        (**self).create_repository(input)
+    }
+    fn create_unreferenced_merge_commit(
+        &self,
+        input: CreateUnreferencedMergeCommitInput,
+    ) -> RusotoFuture<CreateUnreferencedMergeCommitOutput, CreateUnreferencedMergeCommitError> {
+        (**self).create_unreferenced_merge_commit(input)
+    }
+    fn delete_branch(
+        &self,
+        input: DeleteBranchInput,
+    ) -> RusotoFuture<DeleteBranchOutput, DeleteBranchError> {
+        (**self).delete_branch(input)
+    }
+    fn delete_comment_content(
+        &self,
+        input: DeleteCommentContentInput,
+    ) -> RusotoFuture<DeleteCommentContentOutput, DeleteCommentContentError> {
+        (**self).delete_comment_content(input)
+    }
+    fn delete_file(
+        &self,
+        input: DeleteFileInput,
+    ) -> RusotoFuture<DeleteFileOutput, DeleteFileError> {
+        (**self).delete_file(input)
+    }
+    fn delete_repository(
+        &self,
+        input: DeleteRepositoryInput,
+    ) -> RusotoFuture<DeleteRepositoryOutput, DeleteRepositoryError> {
+        (**self).delete_repository(input)
+    }
+    fn describe_merge_conflicts(
+        &self,
+        input: DescribeMergeConflictsInput,
+    ) -> RusotoFuture<DescribeMergeConflictsOutput, DescribeMergeConflictsError> {
+        (**self).describe_merge_conflicts(input)
+    }
+    fn describe_pull_request_events(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> RusotoFuture<DescribePullRequestEventsOutput, DescribePullRequestEventsError> {
+        (**self).describe_pull_request_events(input)
+    }
+    fn get_blob(&self, input: GetBlobInput) -> RusotoFuture<GetBlobOutput, GetBlobError> {
+        (**self).get_blob(input)
+    }
+    fn get_branch(&self, input: GetBranchInput) -> RusotoFuture<GetBranchOutput, GetBranchError> {
+        (**self).get_branch(input)
+    }
+    fn get_comment(
+        &self,
+        input: GetCommentInput,
+    ) -> RusotoFuture<GetCommentOutput, GetCommentError> {
+        (**self).get_comment(input)
+    }
+    fn get_comments_for_compared_commit(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> RusotoFuture<GetCommentsForComparedCommitOutput, GetCommentsForComparedCommitError> {
+        (**self).get_comments_for_compared_commit(input)
+    }
+    fn get_comments_for_pull_request(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> RusotoFuture<GetCommentsForPullRequestOutput, GetCommentsForPullRequestError> {
+        (**self).get_comments_for_pull_request(input)
+    }
+    fn get_commit(&self, input: GetCommitInput) -> RusotoFuture<GetCommitOutput, GetCommitError> {
+        (**self).get_commit(input)
+    }
+    fn get_differences(
+        &self,
+        input: GetDifferencesInput,
+    ) -> RusotoFuture<GetDifferencesOutput, GetDifferencesError> {
+        (**self).get_differences(input)
+    }
+    fn get_file(&self, input: GetFileInput) -> RusotoFuture<GetFileOutput, GetFileError> {
+        (**self).get_file(input)
+    }
+    fn get_folder(&self, input: GetFolderInput) -> RusotoFuture<GetFolderOutput, GetFolderError> {
+        (**self).get_folder(input)
+    }
+    fn get_merge_commit(
+        &self,
+        input: GetMergeCommitInput,
+    ) -> RusotoFuture<GetMergeCommitOutput, GetMergeCommitError> {
+        (**self).get_merge_commit(input)
+    }
+    fn get_merge_conflicts(
+        &self,
+        input: GetMergeConflictsInput,
+    ) -> RusotoFuture<GetMergeConflictsOutput, GetMergeConflictsError> {
+        (**self).get_merge_conflicts(input)
+    }
+    fn get_merge_options(
+        &self,
+        input: GetMergeOptionsInput,
+    ) -> RusotoFuture<GetMergeOptionsOutput, GetMergeOptionsError> {
+        (**self).get_merge_options(input)
+    }
+    fn get_pull_request(
+        &self,
+        input: GetPullRequestInput,
+    ) -> RusotoFuture<GetPullRequestOutput, GetPullRequestError> {
+        (**self).get_pull_request(input)
+    }
+    fn get_repository(
+        &self,
+        input: GetRepositoryInput,
+    ) -> RusotoFuture<GetRepositoryOutput, GetRepositoryError> {
+        (**self).get_repository(input)
+    }
+    fn get_repository_triggers(
+        &self,
+        input: GetRepositoryTriggersInput,
+    ) -> RusotoFuture<GetRepositoryTriggersOutput, GetRepositoryTriggersError> {
+        (**self).get_repository_triggers(input)
+    }
+    fn list_branches(
+        &self,
+        input: ListBranchesInput,
+    ) -> RusotoFuture<ListBranchesOutput, ListBranchesError> {
+        (**self).list_branches(input)
+    }
+    fn list_pull_requests(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> RusotoFuture<ListPullRequestsOutput, ListPullRequestsError> {
+        (**self).list_pull_requests(input)
+    }
+    fn list_repositories(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> RusotoFuture<ListRepositoriesOutput, ListRepositoriesError> {
+        (**self).list_repositories(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn merge_branches_by_fast_forward(
+        &self,
+        input: MergeBranchesByFastForwardInput,
+    ) -> RusotoFuture<MergeBranchesByFastForwardOutput, MergeBranchesByFastForwardError> {
+        (**self).merge_branches_by_fast_forward(input)
+    }
+    fn merge_branches_by_squash(
+        &self,
+        input: MergeBranchesBySquashInput,
+    ) -> RusotoFuture<MergeBranchesBySquashOutput, MergeBranchesBySquashError> {
+        (**self).merge_branches_by_squash(input)
+    }
+    fn merge_branches_by_three_way(
+        &self,
+        input: MergeBranchesByThreeWayInput,
+    ) -> RusotoFuture<MergeBranchesByThreeWayOutput, MergeBranchesByThreeWayError> {
+        (**self).merge_branches_by_three_way(input)
+    }
+    fn merge_pull_request_by_fast_forward(
+        &self,
+        input: MergePullRequestByFastForwardInput,
+    ) -> RusotoFuture<MergePullRequestByFastForwardOutput, MergePullRequestByFastForwardError> {
+        (**self).merge_pull_request_by_fast_forward(input)
+    }
+    fn merge_pull_request_by_squash(
+        &self,
+        input: MergePullRequestBySquashInput,
+    ) -> RusotoFuture<MergePullRequestBySquashOutput, MergePullRequestBySquashError> {
+        (**self).merge_pull_request_by_squash(input)
+    }
+    fn merge_pull_request_by_three_way(
+        &self,
+        input: MergePullRequestByThreeWayInput,
+    ) -> RusotoFuture<MergePullRequestByThreeWayOutput, MergePullRequestByThreeWayError> {
+        (**self).merge_pull_request_by_three_way(input)
+    }
+    fn post_comment_for_compared_commit(
+        &self,
+        input: PostCommentForComparedCommitInput,
+    ) -> RusotoFuture<PostCommentForComparedCommitOutput, PostCommentForComparedCommitError> {
+        (**self).post_comment_for_compared_commit(input)
+    }
+    fn post_comment_for_pull_request(
+        &self,
+        input: PostCommentForPullRequestInput,
+    ) -> RusotoFuture<PostCommentForPullRequestOutput, PostCommentForPullRequestError> {
+        (**self).post_comment_for_pull_request(input)
+    }
+    fn post_comment_reply(
+        &self,
+        input: PostCommentReplyInput,
+    ) -> RusotoFuture<PostCommentReplyOutput, PostCommentReplyError> {
+        (**self).post_comment_reply(input)
+    }
+    fn put_file(&self, input: PutFileInput) -> RusotoFuture<PutFileOutput, PutFileError> {
+        (**self).put_file(input)
+    }
+    fn put_repository_triggers(
+        &self,
+        input: PutRepositoryTriggersInput,
+    ) -> RusotoFuture<PutRepositoryTriggersOutput, PutRepositoryTriggersError> {
+        (**self).put_repository_triggers(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_repository_triggers(
+        &self,
+        input: TestRepositoryTriggersInput,
+    ) -> RusotoFuture<TestRepositoryTriggersOutput, TestRepositoryTriggersError> {
+        (**self).test_repository_triggers(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_comment(
+        &self,
+        input: UpdateCommentInput,
+    ) -> RusotoFuture<UpdateCommentOutput, UpdateCommentError> {
+        (**self).update_comment(input)
+    }
+    fn update_default_branch(
+        &self,
+        input: UpdateDefaultBranchInput,
+    ) -> RusotoFuture<(), UpdateDefaultBranchError> {
+        (**self).update_default_branch(input)
+    }
+    fn update_pull_request_description(
+        &self,
+        input: UpdatePullRequestDescriptionInput,
+    ) -> RusotoFuture<UpdatePullRequestDescriptionOutput, UpdatePullRequestDescriptionError> {
+        (**self).update_pull_request_description(input)
+    }
+    fn update_pull_request_status(
+        &self,
+        input: UpdatePullRequestStatusInput,
+    ) -> RusotoFuture<UpdatePullRequestStatusOutput, UpdatePullRequestStatusError> {
+        (**self).update_pull_request_status(input)
+    }
+    fn update_pull_request_title(
+        &self,
+        input: UpdatePullRequestTitleInput,
+    ) -> RusotoFuture<UpdatePullRequestTitleOutput, UpdatePullRequestTitleError> {
+        (**self).update_pull_request_title(input)
+    }
+    fn update_repository_description(
+        &self,
+        input: UpdateRepositoryDescriptionInput,
+    ) -> RusotoFuture<(), UpdateRepositoryDescriptionError> {
+        (**self).update_repository_description(input)
+    }
+    fn update_repository_name(
+        &self,
+        input: UpdateRepositoryNameInput,
+    ) -> RusotoFuture<(), UpdateRepositoryNameError> {
+        (**self).update_repository_name(input)
+    }
+}
+impl<T: CodeCommit + ?Sized> CodeCommit for ::std::sync::Arc<T> {
+    fn batch_describe_merge_conflicts(
+        &self,
+        input: BatchDescribeMergeConflictsInput,
+    ) -> RusotoFuture<BatchDescribeMergeConflictsOutput, BatchDescribeMergeConflictsError> {
+        (**self).batch_describe_merge_conflicts(input)
+    }
+    fn batch_get_commits(
+        &self,
+        input: BatchGetCommitsInput,
+    ) -> RusotoFuture<BatchGetCommitsOutput, BatchGetCommitsError> {
+        (**self).batch_get_commits(input)
+    }
+    fn batch_get_repositories(
+        &self,
+        input: BatchGetRepositoriesInput,
+    ) -> RusotoFuture<BatchGetRepositoriesOutput, BatchGetRepositoriesError> {
+        (**self).batch_get_repositories(input)
+    }
+    fn create_branch(&self, input: CreateBranchInput) -> RusotoFuture<(), CreateBranchError> {
+        (**self).create_branch(input)
+    }
+    fn create_commit(
+        &self,
+        input: CreateCommitInput,
+    ) -> RusotoFuture<CreateCommitOutput, CreateCommitError> {
+        (**self).create_commit(input)
+    }
+    fn create_pull_request(
+        &self,
+        input: CreatePullRequestInput,
+    ) -> RusotoFuture<CreatePullRequestOutput, CreatePullRequestError> {
+        (**self).create_pull_request(input)
+    }
+    fn create_repository(
+        &self,
+        input: CreateRepositoryInput,
+    ) -> RusotoFuture<CreateRepositoryOutput, CreateRepositoryError> {
+        (**self).create_repository(input)
+    }
+    fn create_unreferenced_merge_commit(
+        &self,
+        input: CreateUnreferencedMergeCommitInput,
+    ) -> RusotoFuture<CreateUnreferencedMergeCommitOutput, CreateUnreferencedMergeCommitError> {
+        (**self).create_unreferenced_merge_commit(input)
+    }
+    fn delete_branch(
+        &self,
+        input: DeleteBranchInput,
+    ) -> RusotoFuture<DeleteBranchOutput, DeleteBranchError> {
+        (**self).delete_branch(input)
+    }
+    fn delete_comment_content(
+        &self,
+        input: DeleteCommentContentInput,
+    ) -> RusotoFuture<DeleteCommentContentOutput, DeleteCommentContentError> {
+        (**self).delete_comment_content(input)
+    }
+    fn delete_file(
+        &self,
+        input: DeleteFileInput,
+    ) -> RusotoFuture<DeleteFileOutput, DeleteFileError> {
+        (**self).delete_file(input)
+    }
+    fn delete_repository(
+        &self,
+        input: DeleteRepositoryInput,
+    ) -> RusotoFuture<DeleteRepositoryOutput, DeleteRepositoryError> {
+        (**self).delete_repository(input)
+    }
+    fn describe_merge_conflicts(
+        &self,
+        input: DescribeMergeConflictsInput,
+    ) -> RusotoFuture<DescribeMergeConflictsOutput, DescribeMergeConflictsError> {
+        (**self).describe_merge_conflicts(input)
+    }
+    fn describe_pull_request_events(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> RusotoFuture<DescribePullRequestEventsOutput, DescribePullRequestEventsError> {
+        (**self).describe_pull_request_events(input)
+    }
+    fn get_blob(&self, input: GetBlobInput) -> RusotoFuture<GetBlobOutput, GetBlobError> {
+        (**self).get_blob(input)
+    }
+    fn get_branch(&self, input: GetBranchInput) -> RusotoFuture<GetBranchOutput, GetBranchError> {
+        (**self).get_branch(input)
+    }
+    fn get_comment(
+        &self,
+        input: GetCommentInput,
+    ) -> RusotoFuture<GetCommentOutput, GetCommentError> {
+        (**self).get_comment(input)
+    }
+    fn get_comments_for_compared_commit(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> RusotoFuture<GetCommentsForComparedCommitOutput, GetCommentsForComparedCommitError> {
+        (**self).get_comments_for_compared_commit(input)
+    }
+    fn get_comments_for_pull_request(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> RusotoFuture<GetCommentsForPullRequestOutput, GetCommentsForPullRequestError> {
+        (**self).get_comments_for_pull_request(input)
+    }
+    fn get_commit(&self, input: GetCommitInput) -> RusotoFuture<GetCommitOutput, GetCommitError> {
+        (**self).get_commit(input)
+    }
+    fn get_differences(
+        &self,
+        input: GetDifferencesInput,
+    ) -> RusotoFuture<GetDifferencesOutput, GetDifferencesError> {
+        (**self).get_differences(input)
+    }
+    fn get_file(&self, input: GetFileInput) -> RusotoFuture<GetFileOutput, GetFileError> {
+        (**self).get_file(input)
+    }
+    fn get_folder(&self, input: GetFolderInput) -> RusotoFuture<GetFolderOutput, GetFolderError> {
+        (**self).get_folder(input)
+    }
+    fn get_merge_commit(
+        &self,
+        input: GetMergeCommitInput,
+    ) -> RusotoFuture<GetMergeCommitOutput, GetMergeCommitError> {
+        (**self).get_merge_commit(input)
+    }
+    fn get_merge_conflicts(
+        &self,
+        input: GetMergeConflictsInput,
+    ) -> RusotoFuture<GetMergeConflictsOutput, GetMergeConflictsError> {
+        (**self).get_merge_conflicts(input)
+    }
+    fn get_merge_options(
+        &self,
+        input: GetMergeOptionsInput,
+    ) -> RusotoFuture<GetMergeOptionsOutput, GetMergeOptionsError> {
+        (**self).get_merge_options(input)
+    }
+    fn get_pull_request(
+        &self,
+        input: GetPullRequestInput,
+    ) -> RusotoFuture<GetPullRequestOutput, GetPullRequestError> {
+        (**self).get_pull_request(input)
+    }
+    fn get_repository(
+        &self,
+        input: GetRepositoryInput,
+    ) -> RusotoFuture<GetRepositoryOutput, GetRepositoryError> {
+        (**self).get_repository(input)
+    }
+    fn get_repository_triggers(
+        &self,
+        input: GetRepositoryTriggersInput,
+    ) -> RusotoFuture<GetRepositoryTriggersOutput, GetRepositoryTriggersError> {
+        (**self).get_repository_triggers(input)
+    }
+    fn list_branches(
+        &self,
+        input: ListBranchesInput,
+    ) -> RusotoFuture<ListBranchesOutput, ListBranchesError> {
+        (**self).list_branches(input)
+    }
+    fn list_pull_requests(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> RusotoFuture<ListPullRequestsOutput, ListPullRequestsError> {
+        (**self).list_pull_requests(input)
+    }
+    fn list_repositories(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> RusotoFuture<ListRepositoriesOutput, ListRepositoriesError> {
+        (**self).list_repositories(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn merge_branches_by_fast_forward(
+        &self,
+        input: MergeBranchesByFastForwardInput,
+    ) -> RusotoFuture<MergeBranchesByFastForwardOutput, MergeBranchesByFastForwardError> {
+        (**self).merge_branches_by_fast_forward(input)
+    }
+    fn merge_branches_by_squash(
+        &self,
+        input: MergeBranchesBySquashInput,
+    ) -> RusotoFuture<MergeBranchesBySquashOutput, MergeBranchesBySquashError> {
+        (**self).merge_branches_by_squash(input)
+    }
+    fn merge_branches_by_three_way(
+        &self,
+        input: MergeBranchesByThreeWayInput,
+    ) -> RusotoFuture<MergeBranchesByThreeWayOutput, MergeBranchesByThreeWayError> {
+        (**self).merge_branches_by_three_way(input)
+    }
+    fn merge_pull_request_by_fast_forward(
+        &self,
+        input: MergePullRequestByFastForwardInput,
+    ) -> RusotoFuture<MergePullRequestByFastForwardOutput, MergePullRequestByFastForwardError> {
+        (**self).merge_pull_request_by_fast_forward(input)
+    }
+    fn merge_pull_request_by_squash(
+        &self,
+        input: MergePullRequestBySquashInput,
+    ) -> RusotoFuture<MergePullRequestBySquashOutput, MergePullRequestBySquashError> {
+        (**self).merge_pull_request_by_squash(input)
+    }
+    fn merge_pull_request_by_three_way(
+        &self,
+        input: MergePullRequestByThreeWayInput,
+    ) -> RusotoFuture<MergePullRequestByThreeWayOutput, MergePullRequestByThreeWayError> {
+        (**self).merge_pull_request_by_three_way(input)
+    }
+    fn post_comment_for_compared_commit(
+        &self,
+        input: PostCommentForComparedCommitInput,
+    ) -> RusotoFuture<PostCommentForComparedCommitOutput, PostCommentForComparedCommitError> {
+        (**self).post_comment_for_compared_commit(input)
+    }
+    fn post_comment_for_pull_request(
+        &self,
+        input: PostCommentForPullRequestInput,
+    ) -> RusotoFuture<PostCommentForPullRequestOutput, PostCommentForPullRequestError> {
+        (**self).post_comment_for_pull_request(input)
+    }
+    fn post_comment_reply(
+        &self,
+        input: PostCommentReplyInput,
+    ) -> RusotoFuture<PostCommentReplyOutput, PostCommentReplyError> {
+        (**self).post_comment_reply(input)
+    }
+    fn put_file(&self, input: PutFileInput) -> RusotoFuture<PutFileOutput, PutFileError> {
+        (**self).put_file(input)
+    }
+    fn put_repository_triggers(
+        &self,
+        input: PutRepositoryTriggersInput,
+    ) -> RusotoFuture<PutRepositoryTriggersOutput, PutRepositoryTriggersError> {
+        (**self).put_repository_triggers(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_repository_triggers(
+        &self,
+        input: TestRepositoryTriggersInput,
+    ) -> RusotoFuture<TestRepositoryTriggersOutput, TestRepositoryTriggersError> {
+        (**self).test_repository_triggers(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_comment(
+        &self,
+        input: UpdateCommentInput,
+    ) -> RusotoFuture<UpdateCommentOutput, UpdateCommentError> {
+        (**self).update_comment(input)
+    }
+    fn update_default_branch(
+        &self,
+        input: UpdateDefaultBranchInput,
+    ) -> RusotoFuture<(), UpdateDefaultBranchError> {
+        (**self).update_default_branch(input)
+    }
+    fn update_pull_request_description(
+        &self,
+        input: UpdatePullRequestDescriptionInput,
+    ) -> RusotoFuture<UpdatePullRequestDescriptionOutput, UpdatePullRequestDescriptionError> {
+        (**self).update_pull_request_description(input)
+    }
+    fn update_pull_request_status(
+        &self,
+        input: UpdatePullRequestStatusInput,
+    ) -> RusotoFuture<UpdatePullRequestStatusOutput, UpdatePullRequestStatusError> {
+        (**self).update_pull_request_status(input)
+    }
+    fn update_pull_request_title(
+        &self,
+        input: UpdatePullRequestTitleInput,
+    ) -> RusotoFuture<UpdatePullRequestTitleOutput, UpdatePullRequestTitleError> {
+        (**self).update_pull_request_title(input)
+    }
+    fn update_repository_description(
+        &self,
+        input: UpdateRepositoryDescriptionInput,
+    ) -> RusotoFuture<(), UpdateRepositoryDescriptionError> {
+        (**self).update_repository_description(input)
+    }
+    fn update_repository_name(
+        &self,
+        input: UpdateRepositoryNameInput,
+    ) -> RusotoFuture<(), UpdateRepositoryNameError> {
+        (**self).update_repository_name(input)
+    }
+}
+/// A client for the CodeCommit API.
+#[derive(Clone)]
+pub struct CodeCommitClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CodeCommitClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeCommitClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeCommitClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeCommitClient {
+        CodeCommitClient { client, region }
+    }
+}
+
+impl CodeCommit for CodeCommitClient {
+    /// <p>Returns information about one or more merge conflicts in the attempted merge of two commit specifiers using the squash or three-way merge strategy.</p>
+    fn batch_describe_merge_conflicts(
+        &self,
+        input: BatchDescribeMergeConflictsInput,
+    ) -> RusotoFuture<BatchDescribeMergeConflictsOutput, BatchDescribeMergeConflictsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchDescribeMergeConflicts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.BatchDescribeMergeConflicts",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDescribeMergeConflictsOutput, _>(
+                    response,
+                    "BatchDescribeMergeConflicts",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchDescribeMergeConflictsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about the contents of one or more commits in a repository.</p>
+    fn batch_get_commits(
+        &self,
+        input: BatchGetCommitsInput,
+    ) -> RusotoFuture<BatchGetCommitsOutput, BatchGetCommitsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchGetCommits");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetCommits");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetCommitsOutput, _>(
+                    response,
+                    "BatchGetCommits",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetCommitsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Returns information about one or more repositories.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a web page could expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a web page.</p> </note></p>
+    fn batch_get_repositories(
+        &self,
+        input: BatchGetRepositoriesInput,
+    ) -> RusotoFuture<BatchGetRepositoriesOutput, BatchGetRepositoriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchGetRepositories");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetRepositories");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetRepositoriesOutput, _>(
+                    response,
+                    "BatchGetRepositories",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchGetRepositoriesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a new branch in a repository and points the branch to a commit.</p> <note> <p>Calling the create branch operation does not set a repository&#39;s default branch. To do this, call the update default branch operation.</p> </note></p>
+    fn create_branch(&self, input: CreateBranchInput) -> RusotoFuture<(), CreateBranchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateBranch");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.CreateBranch");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBranchError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a commit for a repository on the tip of a specified branch.</p>
+    fn create_commit(
+        &self,
+        input: CreateCommitInput,
+    ) -> RusotoFuture<CreateCommitOutput, CreateCommitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateCommit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.CreateCommit");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateCommitOutput, _>(
+                    response,
+                    "CreateCommit",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateCommitError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a pull request in the specified repository.</p>
+    fn create_pull_request(
+        &self,
+        input: CreatePullRequestInput,
+    ) -> RusotoFuture<CreatePullRequestOutput, CreatePullRequestError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreatePullRequest");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.CreatePullRequest");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePullRequestOutput, _>(
+                    response,
+                    "CreatePullRequest",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreatePullRequestError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new, empty repository.</p>
+    fn create_repository(
+        &self,
+        input: CreateRepositoryInput,
+    ) -> RusotoFuture<CreateRepositoryOutput, CreateRepositoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateRepository");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.CreateRepository");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRepositoryOutput, _>(
+                    response,
+                    "CreateRepository",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateRepositoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates an unreferenced commit that represents the result of merging two branches using a specified merge strategy. This can help you determine the outcome of a potential merge. This API cannot be used with the fast-forward merge strategy, as that strategy does not create a merge commit.</p> <note> <p>This unreferenced merge commit can only be accessed using the GetCommit API or through git commands such as git fetch. To retrieve this commit, you must specify its commit ID or otherwise reference it.</p> </note></p>
+    fn create_unreferenced_merge_commit(
+        &self,
+        input: CreateUnreferencedMergeCommitInput,
+    ) -> RusotoFuture<CreateUnreferencedMergeCommitOutput, CreateUnreferencedMergeCommitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateUnreferencedMergeCommit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.CreateUnreferencedMergeCommit",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUnreferencedMergeCommitOutput, _>(
+                    response,
+                    "CreateUnreferencedMergeCommit",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateUnreferencedMergeCommitError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a branch from a repository, unless that branch is the default branch for the repository. </p>
+    fn delete_branch(
+        &self,
+        input: DeleteBranchInput,
+    ) -> RusotoFuture<DeleteBranchOutput, DeleteBranchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteBranch");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.DeleteBranch");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteBranchOutput, _>(
+                    response,
+                    "DeleteBranch",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBranchError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the content of a comment made on a change, file, or commit in a repository.</p>
+    fn delete_comment_content(
+        &self,
+        input: DeleteCommentContentInput,
+    ) -> RusotoFuture<DeleteCommentContentOutput, DeleteCommentContentError> {
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteCommentContent");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.DeleteCommentContent");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteCommentContentOutput, _>(
+                    response,
+                    "DeleteCommentContent",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteCommentContentError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified file from a specified branch. A commit is created on the branch that contains the revision. The file will still exist in the commits prior to the commit that contains the deletion.</p>
+    fn delete_file(
+        &self,
+        input: DeleteFileInput,
+    ) -> RusotoFuture<DeleteFileOutput, DeleteFileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteFile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.DeleteFile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteFileOutput, _>(response, "DeleteFile")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a repository. If a specified repository was already deleted, a null repository ID will be returned.</p> <important> <p>Deleting a repository also deletes all associated objects and metadata. After a repository is deleted, all future push calls to the deleted repository will fail.</p> </important></p>
+    fn delete_repository(
+        &self,
+        input: DeleteRepositoryInput,
+    ) -> RusotoFuture<DeleteRepositoryOutput, DeleteRepositoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteRepository");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.DeleteRepository");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRepositoryOutput, _>(
+                    response,
+                    "DeleteRepository",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteRepositoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about one or more merge conflicts in the attempted merge of two commit specifiers using the squash or three-way merge strategy. If the merge option for the attempted merge is specified as FAST_FORWARD_MERGE, an exception will be thrown.</p>
+    fn describe_merge_conflicts(
+        &self,
+        input: DescribeMergeConflictsInput,
+    ) -> RusotoFuture<DescribeMergeConflictsOutput, DescribeMergeConflictsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DescribeMergeConflicts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.DescribeMergeConflicts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMergeConflictsOutput, _>(
+                    response,
+                    "DescribeMergeConflicts",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeMergeConflictsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about one or more pull request events.</p>
+    fn describe_pull_request_events(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> RusotoFuture<DescribePullRequestEventsOutput, DescribePullRequestEventsError> {
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DescribePullRequestEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.DescribePullRequestEvents",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePullRequestEventsOutput, _>(
+                    response,
+                    "DescribePullRequestEvents",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribePullRequestEventsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the base-64 encoded content of an individual blob within a repository.</p>
+    fn get_blob(&self, input: GetBlobInput) -> RusotoFuture<GetBlobOutput, GetBlobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetBlob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetBlob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetBlobOutput, _>(response, "GetBlob")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBlobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a repository branch, including its name and the last commit ID.</p>
+    fn get_branch(&self, input: GetBranchInput) -> RusotoFuture<GetBranchOutput, GetBranchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetBranch");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetBranch");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetBranchOutput, _>(response, "GetBranch")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBranchError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the content of a comment made on a change, file, or commit in a repository.</p>
+    fn get_comment(
+        &self,
+        input: GetCommentInput,
+    ) -> RusotoFuture<GetCommentOutput, GetCommentError> {
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetComment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetComment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCommentOutput, _>(response, "GetComment")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetCommentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about comments made on the comparison between two commits.</p>
+    fn get_comments_for_compared_commit(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> RusotoFuture<GetCommentsForComparedCommitOutput, GetCommentsForComparedCommitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetCommentsForComparedCommit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.GetCommentsForComparedCommit",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCommentsForComparedCommitOutput, _>(
+                    response,
+                    "GetCommentsForComparedCommit",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetCommentsForComparedCommitError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns comments made on a pull request.</p>
+    fn get_comments_for_pull_request(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> RusotoFuture<GetCommentsForPullRequestOutput, GetCommentsForPullRequestError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetCommentsForPullRequest");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.GetCommentsForPullRequest",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCommentsForPullRequestOutput, _>(
+                    response,
+                    "GetCommentsForPullRequest",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetCommentsForPullRequestError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about a commit, including commit message and committer information.</p>
+    fn get_commit(&self, input: GetCommitInput) -> RusotoFuture<GetCommitOutput, GetCommitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetCommit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetCommit");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCommitOutput, _>(response, "GetCommit")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetCommitError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the differences in a valid commit specifier (such as a branch, tag, HEAD, commit ID or other fully qualified reference). Results can be limited to a specified path.</p>
+    fn get_differences(
+        &self,
+        input: GetDifferencesInput,
+    ) -> RusotoFuture<GetDifferencesOutput, GetDifferencesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetDifferences");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetDifferences");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDifferencesOutput, _>(
+                    response,
+                    "GetDifferences",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDifferencesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the base-64 encoded contents of a specified file and its metadata.</p>
+    fn get_file(&self, input: GetFileInput) -> RusotoFuture<GetFileOutput, GetFileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetFile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetFile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetFileOutput, _>(response, "GetFile")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the contents of a specified folder in a repository.</p>
+    fn get_folder(&self, input: GetFolderInput) -> RusotoFuture<GetFolderOutput, GetFolderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetFolder");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetFolder");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetFolderOutput, _>(response, "GetFolder")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFolderError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a specified merge commit.</p>
+    fn get_merge_commit(
+        &self,
+        input: GetMergeCommitInput,
+    ) -> RusotoFuture<GetMergeCommitOutput, GetMergeCommitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetMergeCommit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeCommit");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMergeCommitOutput, _>(
+                    response,
+                    "GetMergeCommit",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMergeCommitError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about merge conflicts between the before and after commit IDs for a pull request in a repository.</p>
+    fn get_merge_conflicts(
+        &self,
+        input: GetMergeConflictsInput,
+    ) -> RusotoFuture<GetMergeConflictsOutput, GetMergeConflictsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetMergeConflicts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeConflicts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMergeConflictsOutput, _>(
+                    response,
+                    "GetMergeConflicts",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMergeConflictsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the merge options available for merging two specified branches. For details about why a particular merge option is not available, use GetMergeConflicts or DescribeMergeConflicts.</p>
+    fn get_merge_options(
+        &self,
+        input: GetMergeOptionsInput,
+    ) -> RusotoFuture<GetMergeOptionsOutput, GetMergeOptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetMergeOptions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeOptions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMergeOptionsOutput, _>(
+                    response,
+                    "GetMergeOptions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMergeOptionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a pull request in a specified repository.</p>
+    fn get_pull_request(
+        &self,
+        input: GetPullRequestInput,
+    ) -> RusotoFuture<GetPullRequestOutput, GetPullRequestError> {
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetPullRequest");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetPullRequest");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPullRequestOutput, _>(
+                    response,
+                    "GetPullRequest",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPullRequestError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Returns information about a repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a web page could expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a web page.</p> </note></p>
+    fn get_repository(
+        &self,
+        input: GetRepositoryInput,
+    ) -> RusotoFuture<GetRepositoryOutput, GetRepositoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetRepository");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetRepository");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRepositoryOutput, _>(
+                    response,
+                    "GetRepository",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRepositoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about triggers configured for a repository.</p>
+    fn get_repository_triggers(
+        &self,
+        input: GetRepositoryTriggersInput,
+    ) -> RusotoFuture<GetRepositoryTriggersOutput, GetRepositoryTriggersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetRepositoryTriggers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.GetRepositoryTriggers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRepositoryTriggersOutput, _>(
+                    response,
+                    "GetRepositoryTriggers",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetRepositoryTriggersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about one or more branches in a repository.</p>
+    fn list_branches(
+        &self,
+        input: ListBranchesInput,
+    ) -> RusotoFuture<ListBranchesOutput, ListBranchesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListBranches");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.ListBranches");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListBranchesOutput, _>(
+                    response,
+                    "ListBranches",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListBranchesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of pull requests for a specified repository. The return list can be refined by pull request status or pull request author ARN.</p>
+    fn list_pull_requests(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> RusotoFuture<ListPullRequestsOutput, ListPullRequestsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListPullRequests");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.ListPullRequests");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPullRequestsOutput, _>(
+                    response,
+                    "ListPullRequests",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListPullRequestsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about one or more repositories.</p>
+    fn list_repositories(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> RusotoFuture<ListRepositoriesOutput, ListRepositoriesError> {
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListRepositories");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.ListRepositories");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRepositoriesOutput, _>(
+                    response,
+                    "ListRepositories",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRepositoriesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about AWS tags for a specified Amazon Resource Name (ARN) in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the AWS CodeCommit User Guide.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceOutput, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Merges two branches using the fast-forward merge strategy.</p>
+    fn merge_branches_by_fast_forward(
+        &self,
+        input: MergeBranchesByFastForwardInput,
+    ) -> RusotoFuture<MergeBranchesByFastForwardOutput, MergeBranchesByFastForwardError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergeBranchesByFastForward");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.MergeBranchesByFastForward",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<MergeBranchesByFastForwardOutput, _>(
+                    response,
+                    "MergeBranchesByFastForward",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(MergeBranchesByFastForwardError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Merges two branches using the squash merge strategy.</p>
+    fn merge_branches_by_squash(
+        &self,
+        input: MergeBranchesBySquashInput,
+    ) -> RusotoFuture<MergeBranchesBySquashOutput, MergeBranchesBySquashError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergeBranchesBySquash");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.MergeBranchesBySquash");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<MergeBranchesBySquashOutput, _>(
+                    response,
+                    "MergeBranchesBySquash",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(MergeBranchesBySquashError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Merges two specified branches using the three-way merge strategy.</p>
+    fn merge_branches_by_three_way(
+        &self,
+        input: MergeBranchesByThreeWayInput,
+    ) -> RusotoFuture<MergeBranchesByThreeWayOutput, MergeBranchesByThreeWayError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergeBranchesByThreeWay");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.MergeBranchesByThreeWay",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<MergeBranchesByThreeWayOutput, _>(
+                    response,
+                    "MergeBranchesByThreeWay",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(MergeBranchesByThreeWayError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the fast-forward merge strategy. If the merge is successful, it closes the pull request.</p>
+    fn merge_pull_request_by_fast_forward(
+        &self,
+        input: MergePullRequestByFastForwardInput,
+    ) -> RusotoFuture<MergePullRequestByFastForwardOutput, MergePullRequestByFastForwardError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergePullRequestByFastForward");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.MergePullRequestByFastForward",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<MergePullRequestByFastForwardOutput, _>(
+                    response,
+                    "MergePullRequestByFastForward",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(MergePullRequestByFastForwardError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the squash merge strategy. If the merge is successful, it closes the pull request.</p>
+    fn merge_pull_request_by_squash(
+        &self,
+        input: MergePullRequestBySquashInput,
+    ) -> RusotoFuture<MergePullRequestBySquashOutput, MergePullRequestBySquashError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergePullRequestBySquash");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.MergePullRequestBySquash",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<MergePullRequestBySquashOutput, _>(
+                    response,
+                    "MergePullRequestBySquash",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(MergePullRequestBySquashError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the three-way merge strategy. If the merge is successful, it closes the pull request.</p>
+    fn merge_pull_request_by_three_way(
+        &self,
+        input: MergePullRequestByThreeWayInput,
+    ) -> RusotoFuture<MergePullRequestByThreeWayOutput, MergePullRequestByThreeWayError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergePullRequestByThreeWay");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.MergePullRequestByThreeWay",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<MergePullRequestByThreeWayOutput, _>(
+                    response,
+                    "MergePullRequestByThreeWay",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(MergePullRequestByThreeWayError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Posts a comment on the comparison between two commits.</p>
+    fn post_comment_for_compared_commit(
+        &self,
+        input: PostCommentForComparedCommitInput,
+    ) -> RusotoFuture<PostCommentForComparedCommitOutput, PostCommentForComparedCommitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PostCommentForComparedCommit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.PostCommentForComparedCommit",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PostCommentForComparedCommitOutput, _>(
+                    response,
+                    "PostCommentForComparedCommit",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PostCommentForComparedCommitError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Posts a comment on a pull request.</p>
+    fn post_comment_for_pull_request(
+        &self,
+        input: PostCommentForPullRequestInput,
+    ) -> RusotoFuture<PostCommentForPullRequestOutput, PostCommentForPullRequestError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PostCommentForPullRequest");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.PostCommentForPullRequest",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PostCommentForPullRequestOutput, _>(
+                    response,
+                    "PostCommentForPullRequest",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PostCommentForPullRequestError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Posts a comment in reply to an existing comment on a comparison between commits or a pull request.</p>
+    fn post_comment_reply(
+        &self,
+        input: PostCommentReplyInput,
+    ) -> RusotoFuture<PostCommentReplyOutput, PostCommentReplyError> {
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PostCommentReply");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.PostCommentReply");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PostCommentReplyOutput, _>(
+                    response,
+                    "PostCommentReply",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PostCommentReplyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds or updates a file in a branch in an AWS CodeCommit repository, and generates a commit for the addition in the specified branch.</p>
+    fn put_file(&self, input: PutFileInput) -> RusotoFuture<PutFileOutput, PutFileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PutFile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.PutFile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutFileOutput, _>(response, "PutFile")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutFileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Replaces all triggers for a repository. This can be used to create or delete triggers.</p>
+    fn put_repository_triggers(
+        &self,
+        input: PutRepositoryTriggersInput,
+    ) -> RusotoFuture<PutRepositoryTriggersOutput, PutRepositoryTriggersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PutRepositoryTriggers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.PutRepositoryTriggers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutRepositoryTriggersOutput, _>(
+                    response,
+                    "PutRepositoryTriggers",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutRepositoryTriggersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds or updates tags for a resource in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the AWS CodeCommit User Guide.</p>
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Tests the functionality of repository triggers by sending information to the trigger target. If real data is available in the repository, the test will send data from the last commit. If no data is available, sample data will be generated.</p>
+    fn test_repository_triggers(
+        &self,
+        input: TestRepositoryTriggersInput,
+    ) -> RusotoFuture<TestRepositoryTriggersOutput, TestRepositoryTriggersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("TestRepositoryTriggers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.TestRepositoryTriggers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TestRepositoryTriggersOutput, _>(
+                    response,
+                    "TestRepositoryTriggers",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(TestRepositoryTriggersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes tags for a resource in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the AWS CodeCommit User Guide.</p>
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Replaces the contents of a comment.</p>
+    fn update_comment(
+        &self,
+        input: UpdateCommentInput,
+    ) -> RusotoFuture<UpdateCommentOutput, UpdateCommentError> {
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateComment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.UpdateComment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateCommentOutput, _>(
+                    response,
+                    "UpdateComment",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateCommentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Sets or changes the default branch name for the specified repository.</p> <note> <p>If you use this operation to change the default branch name to the current default branch name, a success message is returned even though the default branch did not change.</p> </note></p>
+    fn update_default_branch(
+        &self,
+        input: UpdateDefaultBranchInput,
+    ) -> RusotoFuture<(), UpdateDefaultBranchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateDefaultBranch");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.UpdateDefaultBranch");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateDefaultBranchError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Replaces the contents of the description of a pull request.</p>
+    fn update_pull_request_description(
+        &self,
+        input: UpdatePullRequestDescriptionInput,
+    ) -> RusotoFuture<UpdatePullRequestDescriptionOutput, UpdatePullRequestDescriptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestDescription");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.UpdatePullRequestDescription",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdatePullRequestDescriptionOutput, _>(
+                    response,
+                    "UpdatePullRequestDescription",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdatePullRequestDescriptionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the status of a pull request. </p>
+    fn update_pull_request_status(
+        &self,
+        input: UpdatePullRequestStatusInput,
+    ) -> RusotoFuture<UpdatePullRequestStatusOutput, UpdatePullRequestStatusError> {
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.UpdatePullRequestStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdatePullRequestStatusOutput, _>(
+                    response,
+                    "UpdatePullRequestStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdatePullRequestStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Replaces the title of a pull request.</p>
+    fn update_pull_request_title(
+        &self,
+        input: UpdatePullRequestTitleInput,
+    ) -> RusotoFuture<UpdatePullRequestTitleOutput, UpdatePullRequestTitleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestTitle");
+
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.PutFile");
+        request.add_header("x-amz-target", "CodeCommit_20150413.UpdatePullRequestTitle");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdatePullRequestTitleOutput, _>(
+                    response,
+                    "UpdatePullRequestTitle",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdatePullRequestTitleError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Sets or changes the comment or description for a repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a web page could expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a web page.</p> </note></p>
+    fn update_repository_description(
+        &self,
+        input: UpdateRepositoryDescriptionInput,
+    ) -> RusotoFuture<(), UpdateRepositoryDescriptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateRepositoryDescription");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeCommit_20150413.UpdateRepositoryDescription",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateRepositoryDescriptionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Renames a repository. The repository name must be unique across the calling AWS account. In addition, repository names are limited to 100 alphanumeric, dash, and underscore characters, and cannot include certain characters. The suffix ".git" is prohibited. For a full description of the limits on repository names, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/limits.html">Limits</a> in the AWS CodeCommit User Guide.</p>
+    fn update_repository_name(
+        &self,
+        input: UpdateRepositoryNameInput,
+    ) -> RusotoFuture<(), UpdateRepositoryNameError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateRepositoryName");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeCommit_20150413.UpdateRepositoryName");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<PutFileOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutFileError::from_response(response))),
-                )
-            }
-        })
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateRepositoryNameError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the CodeCommit API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCodeCommit {
+    batch_describe_merge_conflicts: Option<
+        Box<
+            dyn Fn(
+                    BatchDescribeMergeConflictsInput,
+                ) -> ::std::result::Result<
+                    BatchDescribeMergeConflictsOutput,
+                    RusotoError<BatchDescribeMergeConflictsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_commits: Option<
+        Box<
+            dyn Fn(
+                    BatchGetCommitsInput,
+                ) -> ::std::result::Result<
+                    BatchGetCommitsOutput,
+                    RusotoError<BatchGetCommitsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_repositories: Option<
+        Box<
+            dyn Fn(
+                    BatchGetRepositoriesInput,
+                ) -> ::std::result::Result<
+                    BatchGetRepositoriesOutput,
+                    RusotoError<BatchGetRepositoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_branch: Option<
+        Box<
+            dyn Fn(CreateBranchInput) -> ::std::result::Result<(), RusotoError<CreateBranchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_commit: Option<
+        Box<
+            dyn Fn(
+                    CreateCommitInput,
+                )
+                    -> ::std::result::Result<CreateCommitOutput, RusotoError<CreateCommitError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_pull_request: Option<
+        Box<
+            dyn Fn(
+                    CreatePullRequestInput,
+                ) -> ::std::result::Result<
+                    CreatePullRequestOutput,
+                    RusotoError<CreatePullRequestError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_repository: Option<
+        Box<
+            dyn Fn(
+                    CreateRepositoryInput,
+                ) -> ::std::result::Result<
+                    CreateRepositoryOutput,
+                    RusotoError<CreateRepositoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_unreferenced_merge_commit: Option<
+        Box<
+            dyn Fn(
+                    CreateUnreferencedMergeCommitInput,
+                ) -> ::std::result::Result<
+                    CreateUnreferencedMergeCommitOutput,
+                    RusotoError<CreateUnreferencedMergeCommitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_branch: Option<
+        Box<
+            dyn Fn(
+                    DeleteBranchInput,
+                )
+                    -> ::std::result::Result<DeleteBranchOutput, RusotoError<DeleteBranchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_comment_content: Option<
+        Box<
+            dyn Fn(
+                    DeleteCommentContentInput,
+                ) -> ::std::result::Result<
+                    DeleteCommentContentOutput,
+                    RusotoError<DeleteCommentContentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_file: Option<
+        Box<
+            dyn Fn(
+                    DeleteFileInput,
+                )
+                    -> ::std::result::Result<DeleteFileOutput, RusotoError<DeleteFileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_repository: Option<
+        Box<
+            dyn Fn(
+                    DeleteRepositoryInput,
+                ) -> ::std::result::Result<
+                    DeleteRepositoryOutput,
+                    RusotoError<DeleteRepositoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_merge_conflicts: Option<
+        Box<
+            dyn Fn(
+                    DescribeMergeConflictsInput,
+                ) -> ::std::result::Result<
+                    DescribeMergeConflictsOutput,
+                    RusotoError<DescribeMergeConflictsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_pull_request_events: Option<
+        Box<
+            dyn Fn(
+                    DescribePullRequestEventsInput,
+                ) -> ::std::result::Result<
+                    DescribePullRequestEventsOutput,
+                    RusotoError<DescribePullRequestEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_blob: Option<
+        Box<
+            dyn Fn(GetBlobInput) -> ::std::result::Result<GetBlobOutput, RusotoError<GetBlobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_branch: Option<
+        Box<
+            dyn Fn(
+                    GetBranchInput,
+                )
+                    -> ::std::result::Result<GetBranchOutput, RusotoError<GetBranchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_comment: Option<
+        Box<
+            dyn Fn(
+                    GetCommentInput,
+                )
+                    -> ::std::result::Result<GetCommentOutput, RusotoError<GetCommentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_comments_for_compared_commit: Option<
+        Box<
+            dyn Fn(
+                    GetCommentsForComparedCommitInput,
+                ) -> ::std::result::Result<
+                    GetCommentsForComparedCommitOutput,
+                    RusotoError<GetCommentsForComparedCommitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_comments_for_pull_request: Option<
+        Box<
+            dyn Fn(
+                    GetCommentsForPullRequestInput,
+                ) -> ::std::result::Result<
+                    GetCommentsForPullRequestOutput,
+                    RusotoError<GetCommentsForPullRequestError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_commit: Option<
+        Box<
+            dyn Fn(
+                    GetCommitInput,
+                )
+                    -> ::std::result::Result<GetCommitOutput, RusotoError<GetCommitError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_differences: Option<
+        Box<
+            dyn Fn(
+                    GetDifferencesInput,
+                )
+                    -> ::std::result::Result<GetDifferencesOutput, RusotoError<GetDifferencesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_file: Option<
+        Box<
+            dyn Fn(GetFileInput) -> ::std::result::Result<GetFileOutput, RusotoError<GetFileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_folder: Option<
+        Box<
+            dyn Fn(
+                    GetFolderInput,
+                )
+                    -> ::std::result::Result<GetFolderOutput, RusotoError<GetFolderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_merge_commit: Option<
+        Box<
+            dyn Fn(
+                    GetMergeCommitInput,
+                )
+                    -> ::std::result::Result<GetMergeCommitOutput, RusotoError<GetMergeCommitError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_merge_conflicts: Option<
+        Box<
+            dyn Fn(
+                    GetMergeConflictsInput,
+                ) -> ::std::result::Result<
+                    GetMergeConflictsOutput,
+                    RusotoError<GetMergeConflictsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_merge_options: Option<
+        Box<
+            dyn Fn(
+                    GetMergeOptionsInput,
+                ) -> ::std::result::Result<
+                    GetMergeOptionsOutput,
+                    RusotoError<GetMergeOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_pull_request: Option<
+        Box<
+            dyn Fn(
+                    GetPullRequestInput,
+                )
+                    -> ::std::result::Result<GetPullRequestOutput, RusotoError<GetPullRequestError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_repository: Option<
+        Box<
+            dyn Fn(
+                    GetRepositoryInput,
+                )
+                    -> ::std::result::Result<GetRepositoryOutput, RusotoError<GetRepositoryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_repository_triggers: Option<
+        Box<
+            dyn Fn(
+                    GetRepositoryTriggersInput,
+                ) -> ::std::result::Result<
+                    GetRepositoryTriggersOutput,
+                    RusotoError<GetRepositoryTriggersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_branches: Option<
+        Box<
+            dyn Fn(
+                    ListBranchesInput,
+                )
+                    -> ::std::result::Result<ListBranchesOutput, RusotoError<ListBranchesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_pull_requests: Option<
+        Box<
+            dyn Fn(
+                    ListPullRequestsInput,
+                ) -> ::std::result::Result<
+                    ListPullRequestsOutput,
+                    RusotoError<ListPullRequestsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_repositories: Option<
+        Box<
+            dyn Fn(
+                    ListRepositoriesInput,
+                ) -> ::std::result::Result<
+                    ListRepositoriesOutput,
+                    RusotoError<ListRepositoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceOutput,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    merge_branches_by_fast_forward: Option<
+        Box<
+            dyn Fn(
+                    MergeBranchesByFastForwardInput,
+                ) -> ::std::result::Result<
+                    MergeBranchesByFastForwardOutput,
+                    RusotoError<MergeBranchesByFastForwardError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    merge_branches_by_squash: Option<
+        Box<
+            dyn Fn(
+                    MergeBranchesBySquashInput,
+                ) -> ::std::result::Result<
+                    MergeBranchesBySquashOutput,
+                    RusotoError<MergeBranchesBySquashError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    merge_branches_by_three_way: Option<
+        Box<
+            dyn Fn(
+                    MergeBranchesByThreeWayInput,
+                ) -> ::std::result::Result<
+                    MergeBranchesByThreeWayOutput,
+                    RusotoError<MergeBranchesByThreeWayError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    merge_pull_request_by_fast_forward: Option<
+        Box<
+            dyn Fn(
+                    MergePullRequestByFastForwardInput,
+                ) -> ::std::result::Result<
+                    MergePullRequestByFastForwardOutput,
+                    RusotoError<MergePullRequestByFastForwardError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    merge_pull_request_by_squash: Option<
+        Box<
+            dyn Fn(
+                    MergePullRequestBySquashInput,
+                ) -> ::std::result::Result<
+                    MergePullRequestBySquashOutput,
+                    RusotoError<MergePullRequestBySquashError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    merge_pull_request_by_three_way: Option<
+        Box<
+            dyn Fn(
+                    MergePullRequestByThreeWayInput,
+                ) -> ::std::result::Result<
+                    MergePullRequestByThreeWayOutput,
+                    RusotoError<MergePullRequestByThreeWayError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    post_comment_for_compared_commit: Option<
+        Box<
+            dyn Fn(
+                    PostCommentForComparedCommitInput,
+                ) -> ::std::result::Result<
+                    PostCommentForComparedCommitOutput,
+                    RusotoError<PostCommentForComparedCommitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    post_comment_for_pull_request: Option<
+        Box<
+            dyn Fn(
+                    PostCommentForPullRequestInput,
+                ) -> ::std::result::Result<
+                    PostCommentForPullRequestOutput,
+                    RusotoError<PostCommentForPullRequestError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    post_comment_reply: Option<
+        Box<
+            dyn Fn(
+                    PostCommentReplyInput,
+                ) -> ::std::result::Result<
+                    PostCommentReplyOutput,
+                    RusotoError<PostCommentReplyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_file: Option<
+        Box<
+            dyn Fn(PutFileInput) -> ::std::result::Result<PutFileOutput, RusotoError<PutFileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_repository_triggers: Option<
+        Box<
+            dyn Fn(
+                    PutRepositoryTriggersInput,
+                ) -> ::std::result::Result<
+                    PutRepositoryTriggersOutput,
+                    RusotoError<PutRepositoryTriggersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceInput) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    test_repository_triggers: Option<
+        Box<
+            dyn Fn(
+                    TestRepositoryTriggersInput,
+                ) -> ::std::result::Result<
+                    TestRepositoryTriggersOutput,
+                    RusotoError<TestRepositoryTriggersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(UntagResourceInput) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_comment: Option<
+        Box<
+            dyn Fn(
+                    UpdateCommentInput,
+                )
+                    -> ::std::result::Result<UpdateCommentOutput, RusotoError<UpdateCommentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_default_branch: Option<
+        Box<
+            dyn Fn(
+                    UpdateDefaultBranchInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateDefaultBranchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_pull_request_description: Option<
+        Box<
+            dyn Fn(
+                    UpdatePullRequestDescriptionInput,
+                ) -> ::std::result::Result<
+                    UpdatePullRequestDescriptionOutput,
+                    RusotoError<UpdatePullRequestDescriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_pull_request_status: Option<
+        Box<
+            dyn Fn(
+                    UpdatePullRequestStatusInput,
+                ) -> ::std::result::Result<
+                    UpdatePullRequestStatusOutput,
+                    RusotoError<UpdatePullRequestStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_pull_request_title: Option<
+        Box<
+            dyn Fn(
+                    UpdatePullRequestTitleInput,
+                ) -> ::std::result::Result<
+                    UpdatePullRequestTitleOutput,
+                    RusotoError<UpdatePullRequestTitleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_repository_description: Option<
+        Box<
+            dyn Fn(
+                    UpdateRepositoryDescriptionInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateRepositoryDescriptionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_repository_name: Option<
+        Box<
+            dyn Fn(
+                    UpdateRepositoryNameInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateRepositoryNameError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCodeCommit {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCodeCommit {
+        Default::default()
+    }
+
+    /// Answers `batch_describe_merge_conflicts` with what `handler` returns for its input.
+    pub fn with_batch_describe_merge_conflicts<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                BatchDescribeMergeConflictsInput,
+            ) -> ::std::result::Result<
+                BatchDescribeMergeConflictsOutput,
+                RusotoError<BatchDescribeMergeConflictsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_describe_merge_conflicts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_commits` with what `handler` returns for its input.
+    pub fn with_batch_get_commits<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                BatchGetCommitsInput,
+            )
+                -> ::std::result::Result<BatchGetCommitsOutput, RusotoError<BatchGetCommitsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_commits = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_repositories` with what `handler` returns for its input.
+    pub fn with_batch_get_repositories<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                BatchGetRepositoriesInput,
+            ) -> ::std::result::Result<
+                BatchGetRepositoriesOutput,
+                RusotoError<BatchGetRepositoriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_repositories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_branch` with what `handler` returns for its input.
+    pub fn with_create_branch<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(CreateBranchInput) -> ::std::result::Result<(), RusotoError<CreateBranchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_branch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_commit` with what `handler` returns for its input.
+    pub fn with_create_commit<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                CreateCommitInput,
+            )
+                -> ::std::result::Result<CreateCommitOutput, RusotoError<CreateCommitError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_commit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_pull_request` with what `handler` returns for its input.
+    pub fn with_create_pull_request<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                CreatePullRequestInput,
+            ) -> ::std::result::Result<
+                CreatePullRequestOutput,
+                RusotoError<CreatePullRequestError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_pull_request = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_repository` with what `handler` returns for its input.
+    pub fn with_create_repository<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                CreateRepositoryInput,
+            )
+                -> ::std::result::Result<CreateRepositoryOutput, RusotoError<CreateRepositoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_repository = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_unreferenced_merge_commit` with what `handler` returns for its input.
+    pub fn with_create_unreferenced_merge_commit<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                CreateUnreferencedMergeCommitInput,
+            ) -> ::std::result::Result<
+                CreateUnreferencedMergeCommitOutput,
+                RusotoError<CreateUnreferencedMergeCommitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_unreferenced_merge_commit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_branch` with what `handler` returns for its input.
+    pub fn with_delete_branch<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                DeleteBranchInput,
+            )
+                -> ::std::result::Result<DeleteBranchOutput, RusotoError<DeleteBranchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_branch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_comment_content` with what `handler` returns for its input.
+    pub fn with_delete_comment_content<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                DeleteCommentContentInput,
+            ) -> ::std::result::Result<
+                DeleteCommentContentOutput,
+                RusotoError<DeleteCommentContentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_comment_content = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_file` with what `handler` returns for its input.
+    pub fn with_delete_file<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                DeleteFileInput,
+            ) -> ::std::result::Result<DeleteFileOutput, RusotoError<DeleteFileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_file = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_repository` with what `handler` returns for its input.
+    pub fn with_delete_repository<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                DeleteRepositoryInput,
+            )
+                -> ::std::result::Result<DeleteRepositoryOutput, RusotoError<DeleteRepositoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_repository = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_merge_conflicts` with what `handler` returns for its input.
+    pub fn with_describe_merge_conflicts<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                DescribeMergeConflictsInput,
+            ) -> ::std::result::Result<
+                DescribeMergeConflictsOutput,
+                RusotoError<DescribeMergeConflictsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_merge_conflicts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_pull_request_events` with what `handler` returns for its input.
+    pub fn with_describe_pull_request_events<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                DescribePullRequestEventsInput,
+            ) -> ::std::result::Result<
+                DescribePullRequestEventsOutput,
+                RusotoError<DescribePullRequestEventsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_pull_request_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_blob` with what `handler` returns for its input.
+    pub fn with_get_blob<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(GetBlobInput) -> ::std::result::Result<GetBlobOutput, RusotoError<GetBlobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_blob = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_branch` with what `handler` returns for its input.
+    pub fn with_get_branch<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetBranchInput,
+            ) -> ::std::result::Result<GetBranchOutput, RusotoError<GetBranchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_branch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_comment` with what `handler` returns for its input.
+    pub fn with_get_comment<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetCommentInput,
+            ) -> ::std::result::Result<GetCommentOutput, RusotoError<GetCommentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_comment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_comments_for_compared_commit` with what `handler` returns for its input.
+    pub fn with_get_comments_for_compared_commit<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetCommentsForComparedCommitInput,
+            ) -> ::std::result::Result<
+                GetCommentsForComparedCommitOutput,
+                RusotoError<GetCommentsForComparedCommitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_comments_for_compared_commit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_comments_for_pull_request` with what `handler` returns for its input.
+    pub fn with_get_comments_for_pull_request<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetCommentsForPullRequestInput,
+            ) -> ::std::result::Result<
+                GetCommentsForPullRequestOutput,
+                RusotoError<GetCommentsForPullRequestError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_comments_for_pull_request = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_commit` with what `handler` returns for its input.
+    pub fn with_get_commit<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetCommitInput,
+            ) -> ::std::result::Result<GetCommitOutput, RusotoError<GetCommitError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_commit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_differences` with what `handler` returns for its input.
+    pub fn with_get_differences<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetDifferencesInput,
+            )
+                -> ::std::result::Result<GetDifferencesOutput, RusotoError<GetDifferencesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_differences = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_file` with what `handler` returns for its input.
+    pub fn with_get_file<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(GetFileInput) -> ::std::result::Result<GetFileOutput, RusotoError<GetFileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_file = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_folder` with what `handler` returns for its input.
+    pub fn with_get_folder<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetFolderInput,
+            ) -> ::std::result::Result<GetFolderOutput, RusotoError<GetFolderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_folder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_merge_commit` with what `handler` returns for its input.
+    pub fn with_get_merge_commit<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetMergeCommitInput,
+            )
+                -> ::std::result::Result<GetMergeCommitOutput, RusotoError<GetMergeCommitError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_merge_commit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_merge_conflicts` with what `handler` returns for its input.
+    pub fn with_get_merge_conflicts<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetMergeConflictsInput,
+            ) -> ::std::result::Result<
+                GetMergeConflictsOutput,
+                RusotoError<GetMergeConflictsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_merge_conflicts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_merge_options` with what `handler` returns for its input.
+    pub fn with_get_merge_options<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetMergeOptionsInput,
+            )
+                -> ::std::result::Result<GetMergeOptionsOutput, RusotoError<GetMergeOptionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_merge_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_pull_request` with what `handler` returns for its input.
+    pub fn with_get_pull_request<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetPullRequestInput,
+            )
+                -> ::std::result::Result<GetPullRequestOutput, RusotoError<GetPullRequestError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_pull_request = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_repository` with what `handler` returns for its input.
+    pub fn with_get_repository<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetRepositoryInput,
+            )
+                -> ::std::result::Result<GetRepositoryOutput, RusotoError<GetRepositoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_repository = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_repository_triggers` with what `handler` returns for its input.
+    pub fn with_get_repository_triggers<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                GetRepositoryTriggersInput,
+            ) -> ::std::result::Result<
+                GetRepositoryTriggersOutput,
+                RusotoError<GetRepositoryTriggersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_repository_triggers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_branches` with what `handler` returns for its input.
+    pub fn with_list_branches<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                ListBranchesInput,
+            )
+                -> ::std::result::Result<ListBranchesOutput, RusotoError<ListBranchesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_branches = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_pull_requests` with what `handler` returns for its input.
+    pub fn with_list_pull_requests<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                ListPullRequestsInput,
+            )
+                -> ::std::result::Result<ListPullRequestsOutput, RusotoError<ListPullRequestsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_pull_requests = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_repositories` with what `handler` returns for its input.
+    pub fn with_list_repositories<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                ListRepositoriesInput,
+            )
+                -> ::std::result::Result<ListRepositoriesOutput, RusotoError<ListRepositoriesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_repositories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceOutput,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `merge_branches_by_fast_forward` with what `handler` returns for its input.
+    pub fn with_merge_branches_by_fast_forward<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                MergeBranchesByFastForwardInput,
+            ) -> ::std::result::Result<
+                MergeBranchesByFastForwardOutput,
+                RusotoError<MergeBranchesByFastForwardError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.merge_branches_by_fast_forward = Some(Box::new(handler));
+        self
+    }
+    /// Answers `merge_branches_by_squash` with what `handler` returns for its input.
+    pub fn with_merge_branches_by_squash<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                MergeBranchesBySquashInput,
+            ) -> ::std::result::Result<
+                MergeBranchesBySquashOutput,
+                RusotoError<MergeBranchesBySquashError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.merge_branches_by_squash = Some(Box::new(handler));
+        self
+    }
+    /// Answers `merge_branches_by_three_way` with what `handler` returns for its input.
+    pub fn with_merge_branches_by_three_way<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                MergeBranchesByThreeWayInput,
+            ) -> ::std::result::Result<
+                MergeBranchesByThreeWayOutput,
+                RusotoError<MergeBranchesByThreeWayError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.merge_branches_by_three_way = Some(Box::new(handler));
+        self
+    }
+    /// Answers `merge_pull_request_by_fast_forward` with what `handler` returns for its input.
+    pub fn with_merge_pull_request_by_fast_forward<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                MergePullRequestByFastForwardInput,
+            ) -> ::std::result::Result<
+                MergePullRequestByFastForwardOutput,
+                RusotoError<MergePullRequestByFastForwardError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.merge_pull_request_by_fast_forward = Some(Box::new(handler));
+        self
+    }
+    /// Answers `merge_pull_request_by_squash` with what `handler` returns for its input.
+    pub fn with_merge_pull_request_by_squash<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                MergePullRequestBySquashInput,
+            ) -> ::std::result::Result<
+                MergePullRequestBySquashOutput,
+                RusotoError<MergePullRequestBySquashError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.merge_pull_request_by_squash = Some(Box::new(handler));
+        self
+    }
+    /// Answers `merge_pull_request_by_three_way` with what `handler` returns for its input.
+    pub fn with_merge_pull_request_by_three_way<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                MergePullRequestByThreeWayInput,
+            ) -> ::std::result::Result<
+                MergePullRequestByThreeWayOutput,
+                RusotoError<MergePullRequestByThreeWayError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.merge_pull_request_by_three_way = Some(Box::new(handler));
+        self
+    }
+    /// Answers `post_comment_for_compared_commit` with what `handler` returns for its input.
+    pub fn with_post_comment_for_compared_commit<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                PostCommentForComparedCommitInput,
+            ) -> ::std::result::Result<
+                PostCommentForComparedCommitOutput,
+                RusotoError<PostCommentForComparedCommitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.post_comment_for_compared_commit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `post_comment_for_pull_request` with what `handler` returns for its input.
+    pub fn with_post_comment_for_pull_request<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                PostCommentForPullRequestInput,
+            ) -> ::std::result::Result<
+                PostCommentForPullRequestOutput,
+                RusotoError<PostCommentForPullRequestError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.post_comment_for_pull_request = Some(Box::new(handler));
+        self
+    }
+    /// Answers `post_comment_reply` with what `handler` returns for its input.
+    pub fn with_post_comment_reply<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                PostCommentReplyInput,
+            )
+                -> ::std::result::Result<PostCommentReplyOutput, RusotoError<PostCommentReplyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.post_comment_reply = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_file` with what `handler` returns for its input.
+    pub fn with_put_file<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(PutFileInput) -> ::std::result::Result<PutFileOutput, RusotoError<PutFileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_file = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_repository_triggers` with what `handler` returns for its input.
+    pub fn with_put_repository_triggers<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                PutRepositoryTriggersInput,
+            ) -> ::std::result::Result<
+                PutRepositoryTriggersOutput,
+                RusotoError<PutRepositoryTriggersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_repository_triggers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(TagResourceInput) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_repository_triggers` with what `handler` returns for its input.
+    pub fn with_test_repository_triggers<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                TestRepositoryTriggersInput,
+            ) -> ::std::result::Result<
+                TestRepositoryTriggersOutput,
+                RusotoError<TestRepositoryTriggersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.test_repository_triggers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(UntagResourceInput) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_comment` with what `handler` returns for its input.
+    pub fn with_update_comment<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                UpdateCommentInput,
+            )
+                -> ::std::result::Result<UpdateCommentOutput, RusotoError<UpdateCommentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_comment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_default_branch` with what `handler` returns for its input.
+    pub fn with_update_default_branch<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                UpdateDefaultBranchInput,
+            ) -> ::std::result::Result<(), RusotoError<UpdateDefaultBranchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_default_branch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_pull_request_description` with what `handler` returns for its input.
+    pub fn with_update_pull_request_description<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                UpdatePullRequestDescriptionInput,
+            ) -> ::std::result::Result<
+                UpdatePullRequestDescriptionOutput,
+                RusotoError<UpdatePullRequestDescriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_pull_request_description = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_pull_request_status` with what `handler` returns for its input.
+    pub fn with_update_pull_request_status<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                UpdatePullRequestStatusInput,
+            ) -> ::std::result::Result<
+                UpdatePullRequestStatusOutput,
+                RusotoError<UpdatePullRequestStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_pull_request_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_pull_request_title` with what `handler` returns for its input.
+    pub fn with_update_pull_request_title<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                UpdatePullRequestTitleInput,
+            ) -> ::std::result::Result<
+                UpdatePullRequestTitleOutput,
+                RusotoError<UpdatePullRequestTitleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_pull_request_title = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_repository_description` with what `handler` returns for its input.
+    pub fn with_update_repository_description<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                UpdateRepositoryDescriptionInput,
+            )
+                -> ::std::result::Result<(), RusotoError<UpdateRepositoryDescriptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_repository_description = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_repository_name` with what `handler` returns for its input.
+    pub fn with_update_repository_name<F>(mut self, handler: F) -> MockCodeCommit
+    where
+        F: Fn(
+                UpdateRepositoryNameInput,
+            ) -> ::std::result::Result<(), RusotoError<UpdateRepositoryNameError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_repository_name = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CodeCommit for MockCodeCommit {
+    fn batch_describe_merge_conflicts(
+        &self,
+        input: BatchDescribeMergeConflictsInput,
+    ) -> RusotoFuture<BatchDescribeMergeConflictsOutput, BatchDescribeMergeConflictsError> {
+        match self.batch_describe_merge_conflicts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for batch_describe_merge_conflicts"),
+        }
+    }
+    fn batch_get_commits(
+        &self,
+        input: BatchGetCommitsInput,
+    ) -> RusotoFuture<BatchGetCommitsOutput, BatchGetCommitsError> {
+        match self.batch_get_commits {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for batch_get_commits"),
+        }
+    }
+    fn batch_get_repositories(
+        &self,
+        input: BatchGetRepositoriesInput,
+    ) -> RusotoFuture<BatchGetRepositoriesOutput, BatchGetRepositoriesError> {
+        match self.batch_get_repositories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for batch_get_repositories"),
+        }
+    }
+    fn create_branch(&self, input: CreateBranchInput) -> RusotoFuture<(), CreateBranchError> {
+        match self.create_branch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for create_branch"),
+        }
+    }
+    fn create_commit(
+        &self,
+        input: CreateCommitInput,
+    ) -> RusotoFuture<CreateCommitOutput, CreateCommitError> {
+        match self.create_commit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for create_commit"),
+        }
+    }
+    fn create_pull_request(
+        &self,
+        input: CreatePullRequestInput,
+    ) -> RusotoFuture<CreatePullRequestOutput, CreatePullRequestError> {
+        match self.create_pull_request {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for create_pull_request"),
+        }
+    }
+    fn create_repository(
+        &self,
+        input: CreateRepositoryInput,
+    ) -> RusotoFuture<CreateRepositoryOutput, CreateRepositoryError> {
+        match self.create_repository {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for create_repository"),
+        }
+    }
+    fn create_unreferenced_merge_commit(
+        &self,
+        input: CreateUnreferencedMergeCommitInput,
+    ) -> RusotoFuture<CreateUnreferencedMergeCommitOutput, CreateUnreferencedMergeCommitError> {
+        match self.create_unreferenced_merge_commit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for create_unreferenced_merge_commit"),
+        }
+    }
+    fn delete_branch(
+        &self,
+        input: DeleteBranchInput,
+    ) -> RusotoFuture<DeleteBranchOutput, DeleteBranchError> {
+        match self.delete_branch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for delete_branch"),
+        }
+    }
+    fn delete_comment_content(
+        &self,
+        input: DeleteCommentContentInput,
+    ) -> RusotoFuture<DeleteCommentContentOutput, DeleteCommentContentError> {
+        match self.delete_comment_content {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for delete_comment_content"),
+        }
+    }
+    fn delete_file(
+        &self,
+        input: DeleteFileInput,
+    ) -> RusotoFuture<DeleteFileOutput, DeleteFileError> {
+        match self.delete_file {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for delete_file"),
+        }
+    }
+    fn delete_repository(
+        &self,
+        input: DeleteRepositoryInput,
+    ) -> RusotoFuture<DeleteRepositoryOutput, DeleteRepositoryError> {
+        match self.delete_repository {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for delete_repository"),
+        }
+    }
+    fn describe_merge_conflicts(
+        &self,
+        input: DescribeMergeConflictsInput,
+    ) -> RusotoFuture<DescribeMergeConflictsOutput, DescribeMergeConflictsError> {
+        match self.describe_merge_conflicts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for describe_merge_conflicts"),
+        }
+    }
+    fn describe_pull_request_events(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> RusotoFuture<DescribePullRequestEventsOutput, DescribePullRequestEventsError> {
+        match self.describe_pull_request_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for describe_pull_request_events"),
+        }
+    }
+    fn get_blob(&self, input: GetBlobInput) -> RusotoFuture<GetBlobOutput, GetBlobError> {
+        match self.get_blob {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_blob"),
+        }
+    }
+    fn get_branch(&self, input: GetBranchInput) -> RusotoFuture<GetBranchOutput, GetBranchError> {
+        match self.get_branch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_branch"),
+        }
+    }
+    fn get_comment(
+        &self,
+        input: GetCommentInput,
+    ) -> RusotoFuture<GetCommentOutput, GetCommentError> {
+        match self.get_comment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_comment"),
+        }
+    }
+    fn get_comments_for_compared_commit(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> RusotoFuture<GetCommentsForComparedCommitOutput, GetCommentsForComparedCommitError> {
+        match self.get_comments_for_compared_commit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_comments_for_compared_commit"),
+        }
+    }
+    fn get_comments_for_pull_request(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> RusotoFuture<GetCommentsForPullRequestOutput, GetCommentsForPullRequestError> {
+        match self.get_comments_for_pull_request {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_comments_for_pull_request"),
+        }
+    }
+    fn get_commit(&self, input: GetCommitInput) -> RusotoFuture<GetCommitOutput, GetCommitError> {
+        match self.get_commit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_commit"),
+        }
+    }
+    fn get_differences(
+        &self,
+        input: GetDifferencesInput,
+    ) -> RusotoFuture<GetDifferencesOutput, GetDifferencesError> {
+        match self.get_differences {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_differences"),
+        }
+    }
+    fn get_file(&self, input: GetFileInput) -> RusotoFuture<GetFileOutput, GetFileError> {
+        match self.get_file {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_file"),
+        }
+    }
+    fn get_folder(&self, input: GetFolderInput) -> RusotoFuture<GetFolderOutput, GetFolderError> {
+        match self.get_folder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_folder"),
+        }
+    }
+    fn get_merge_commit(
+        &self,
+        input: GetMergeCommitInput,
+    ) -> RusotoFuture<GetMergeCommitOutput, GetMergeCommitError> {
+        match self.get_merge_commit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_merge_commit"),
+        }
+    }
+    fn get_merge_conflicts(
+        &self,
+        input: GetMergeConflictsInput,
+    ) -> RusotoFuture<GetMergeConflictsOutput, GetMergeConflictsError> {
+        match self.get_merge_conflicts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_merge_conflicts"),
+        }
+    }
+    fn get_merge_options(
+        &self,
+        input: GetMergeOptionsInput,
+    ) -> RusotoFuture<GetMergeOptionsOutput, GetMergeOptionsError> {
+        match self.get_merge_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_merge_options"),
+        }
+    }
+    fn get_pull_request(
+        &self,
+        input: GetPullRequestInput,
+    ) -> RusotoFuture<GetPullRequestOutput, GetPullRequestError> {
+        match self.get_pull_request {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_pull_request"),
+        }
+    }
+    fn get_repository(
+        &self,
+        input: GetRepositoryInput,
+    ) -> RusotoFuture<GetRepositoryOutput, GetRepositoryError> {
+        match self.get_repository {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_repository"),
+        }
+    }
+    fn get_repository_triggers(
+        &self,
+        input: GetRepositoryTriggersInput,
+    ) -> RusotoFuture<GetRepositoryTriggersOutput, GetRepositoryTriggersError> {
+        match self.get_repository_triggers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for get_repository_triggers"),
+        }
+    }
+    fn list_branches(
+        &self,
+        input: ListBranchesInput,
+    ) -> RusotoFuture<ListBranchesOutput, ListBranchesError> {
+        match self.list_branches {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for list_branches"),
+        }
+    }
+    fn list_pull_requests(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> RusotoFuture<ListPullRequestsOutput, ListPullRequestsError> {
+        match self.list_pull_requests {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for list_pull_requests"),
+        }
+    }
+    fn list_repositories(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> RusotoFuture<ListRepositoriesOutput, ListRepositoriesError> {
+        match self.list_repositories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for list_repositories"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for list_tags_for_resource"),
+        }
+    }
+    fn merge_branches_by_fast_forward(
+        &self,
+        input: MergeBranchesByFastForwardInput,
+    ) -> RusotoFuture<MergeBranchesByFastForwardOutput, MergeBranchesByFastForwardError> {
+        match self.merge_branches_by_fast_forward {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for merge_branches_by_fast_forward"),
+        }
+    }
+    fn merge_branches_by_squash(
+        &self,
+        input: MergeBranchesBySquashInput,
+    ) -> RusotoFuture<MergeBranchesBySquashOutput, MergeBranchesBySquashError> {
+        match self.merge_branches_by_squash {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for merge_branches_by_squash"),
+        }
+    }
+    fn merge_branches_by_three_way(
+        &self,
+        input: MergeBranchesByThreeWayInput,
+    ) -> RusotoFuture<MergeBranchesByThreeWayOutput, MergeBranchesByThreeWayError> {
+        match self.merge_branches_by_three_way {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for merge_branches_by_three_way"),
+        }
+    }
+    fn merge_pull_request_by_fast_forward(
+        &self,
+        input: MergePullRequestByFastForwardInput,
+    ) -> RusotoFuture<MergePullRequestByFastForwardOutput, MergePullRequestByFastForwardError> {
+        match self.merge_pull_request_by_fast_forward {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for merge_pull_request_by_fast_forward"),
+        }
+    }
+    fn merge_pull_request_by_squash(
+        &self,
+        input: MergePullRequestBySquashInput,
+    ) -> RusotoFuture<MergePullRequestBySquashOutput, MergePullRequestBySquashError> {
+        match self.merge_pull_request_by_squash {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for merge_pull_request_by_squash"),
+        }
+    }
+    fn merge_pull_request_by_three_way(
+        &self,
+        input: MergePullRequestByThreeWayInput,
+    ) -> RusotoFuture<MergePullRequestByThreeWayOutput, MergePullRequestByThreeWayError> {
+        match self.merge_pull_request_by_three_way {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for merge_pull_request_by_three_way"),
+        }
+    }
+    fn post_comment_for_compared_commit(
+        &self,
+        input: PostCommentForComparedCommitInput,
+    ) -> RusotoFuture<PostCommentForComparedCommitOutput, PostCommentForComparedCommitError> {
+        match self.post_comment_for_compared_commit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for post_comment_for_compared_commit"),
+        }
+    }
+    fn post_comment_for_pull_request(
+        &self,
+        input: PostCommentForPullRequestInput,
+    ) -> RusotoFuture<PostCommentForPullRequestOutput, PostCommentForPullRequestError> {
+        match self.post_comment_for_pull_request {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for post_comment_for_pull_request"),
+        }
+    }
+    fn post_comment_reply(
+        &self,
+        input: PostCommentReplyInput,
+    ) -> RusotoFuture<PostCommentReplyOutput, PostCommentReplyError> {
+        match self.post_comment_reply {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for post_comment_reply"),
+        }
+    }
+    fn put_file(&self, input: PutFileInput) -> RusotoFuture<PutFileOutput, PutFileError> {
+        match self.put_file {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for put_file"),
+        }
     }
-
-    /// <p>Replaces all triggers for a repository. This can be used to create or delete triggers.</p>
     fn put_repository_triggers(
         &self,
         input: PutRepositoryTriggersInput,
     ) -> RusotoFuture<PutRepositoryTriggersOutput, PutRepositoryTriggersError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.PutRepositoryTriggers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutRepositoryTriggersOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutRepositoryTriggersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.put_repository_triggers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for put_repository_triggers"),
+        }
     }
-
-    /// <p>Adds or updates tags for a resource in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the AWS CodeCommit User Guide.</p>
     fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Tests the functionality of repository triggers by sending information to the trigger target. If real data is available in the repository, the test will send data from the last commit. If no data is available, sample data will be generated.</p>
     fn test_repository_triggers(
         &self,
         input: TestRepositoryTriggersInput,
     ) -> RusotoFuture<TestRepositoryTriggersOutput, TestRepositoryTriggersError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.TestRepositoryTriggers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TestRepositoryTriggersOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(TestRepositoryTriggersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.test_repository_triggers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for test_repository_triggers"),
+        }
     }
-
-    /// <p>Removes tags for a resource in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the AWS CodeCommit User Guide.</p>
     fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Replaces the contents of a comment.</p>
     fn update_comment(
         &self,
         input: UpdateCommentInput,
     ) -> RusotoFuture<UpdateCommentOutput, UpdateCommentError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.UpdateComment");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateCommentOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateCommentError::from_response(response))),
-                )
-            }
-        })
+        match self.update_comment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for update_comment"),
+        }
     }
-
-    /// <p><p>Sets or changes the default branch name for the specified repository.</p> <note> <p>If you use this operation to change the default branch name to the current default branch name, a success message is returned even though the default branch did not change.</p> </note></p>
     fn update_default_branch(
         &self,
         input: UpdateDefaultBranchInput,
     ) -> RusotoFuture<(), UpdateDefaultBranchError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.UpdateDefaultBranch");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateDefaultBranchError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_default_branch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for update_default_branch"),
+        }
     }
-
-    /// <p>Replaces the contents of the description of a pull request.</p>
     fn update_pull_request_description(
         &self,
         input: UpdatePullRequestDescriptionInput,
     ) -> RusotoFuture<UpdatePullRequestDescriptionOutput, UpdatePullRequestDescriptionError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.UpdatePullRequestDescription",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdatePullRequestDescriptionOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdatePullRequestDescriptionError::from_response(response))
-                }))
-            }
-        })
+        match self.update_pull_request_description {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for update_pull_request_description"),
+        }
     }
-
-    /// <p>Updates the status of a pull request. </p>
     fn update_pull_request_status(
         &self,
         input: UpdatePullRequestStatusInput,
     ) -> RusotoFuture<UpdatePullRequestStatusOutput, UpdatePullRequestStatusError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.UpdatePullRequestStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdatePullRequestStatusOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdatePullRequestStatusError::from_response(response))
-                }))
-            }
-        })
+        match self.update_pull_request_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for update_pull_request_status"),
+        }
     }
-
-    /// <p>Replaces the title of a pull request.</p>
     fn update_pull_request_title(
         &self,
         input: UpdatePullRequestTitleInput,
     ) -> RusotoFuture<UpdatePullRequestTitleOutput, UpdatePullRequestTitleError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.UpdatePullRequestTitle");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdatePullRequestTitleOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdatePullRequestTitleError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_pull_request_title {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for update_pull_request_title"),
+        }
     }
-
-    /// <p><p>Sets or changes the comment or description for a repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a web page could expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a web page.</p> </note></p>
     fn update_repository_description(
         &self,
         input: UpdateRepositoryDescriptionInput,
     ) -> RusotoFuture<(), UpdateRepositoryDescriptionError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeCommit_20150413.UpdateRepositoryDescription",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateRepositoryDescriptionError::from_response(response))
-                }))
-            }
-        })
+        match self.update_repository_description {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for update_repository_description"),
+        }
     }
-
-    /// <p>Renames a repository. The repository name must be unique across the calling AWS account. In addition, repository names are limited to 100 alphanumeric, dash, and underscore characters, and cannot include certain characters. The suffix ".git" is prohibited. For a full description of the limits on repository names, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/limits.html">Limits</a> in the AWS CodeCommit User Guide.</p>
     fn update_repository_name(
         &self,
         input: UpdateRepositoryNameInput,
     ) -> RusotoFuture<(), UpdateRepositoryNameError> {
-        let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeCommit_20150413.UpdateRepositoryName");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateRepositoryNameError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_repository_name {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeCommit has no handler for update_repository_name"),
+        }
     }
 }
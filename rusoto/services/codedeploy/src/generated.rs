@@ -22,9 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Represents the input of, and adds tags to, an on-premises instance operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToOnPremisesInstancesInput {
     /// <p>The names of the on-premises instances to which to add tags.</p>
     #[serde(rename = "instanceNames")]
@@ -75,7 +77,7 @@ pub struct AppSpecContent {
 
 /// <p>Information about an application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApplicationInfo {
     /// <p>The application ID.</p>
     #[serde(rename = "applicationId")]
@@ -118,7 +120,7 @@ pub struct AutoRollbackConfiguration {
 
 /// <p>Information about an Auto Scaling group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AutoScalingGroup {
     /// <p>An Auto Scaling lifecycle event hook name.</p>
     #[serde(rename = "hook")]
@@ -132,6 +134,7 @@ pub struct AutoScalingGroup {
 
 /// <p>Represents the input of a BatchGetApplicationRevisions operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetApplicationRevisionsInput {
     /// <p>The name of an AWS CodeDeploy application about which to get revision information.</p>
     #[serde(rename = "applicationName")]
@@ -141,9 +144,20 @@ pub struct BatchGetApplicationRevisionsInput {
     pub revisions: Vec<RevisionLocation>,
 }
 
+impl Validate for BatchGetApplicationRevisionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a BatchGetApplicationRevisions operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetApplicationRevisionsOutput {
     /// <p>The name of the application that corresponds to the revisions.</p>
     #[serde(rename = "applicationName")]
@@ -161,15 +175,25 @@ pub struct BatchGetApplicationRevisionsOutput {
 
 /// <p>Represents the input of a BatchGetApplications operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetApplicationsInput {
     /// <p>A list of application names separated by spaces. The maximum number of application names you can specify is 25.</p>
     #[serde(rename = "applicationNames")]
     pub application_names: Vec<String>,
 }
 
+impl Validate for BatchGetApplicationsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.application_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "application_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+        }
+    }
+}
+
 /// <p>Represents the output of a BatchGetApplications operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetApplicationsOutput {
     /// <p>Information about the applications.</p>
     #[serde(rename = "applicationsInfo")]
@@ -179,6 +203,7 @@ pub struct BatchGetApplicationsOutput {
 
 /// <p>Represents the input of a BatchGetDeploymentGroups operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetDeploymentGroupsInput {
     /// <p>The name of an AWS CodeDeploy application associated with the applicable IAM user or AWS account.</p>
     #[serde(rename = "applicationName")]
@@ -188,9 +213,24 @@ pub struct BatchGetDeploymentGroupsInput {
     pub deployment_group_names: Vec<String>,
 }
 
+impl Validate for BatchGetDeploymentGroupsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.deployment_group_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "deployment_group_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+        }
+    }
+}
+
 /// <p>Represents the output of a BatchGetDeploymentGroups operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetDeploymentGroupsOutput {
     /// <p>Information about the deployment groups.</p>
     #[serde(rename = "deploymentGroupsInfo")]
@@ -204,6 +244,7 @@ pub struct BatchGetDeploymentGroupsOutput {
 
 /// <p> Represents the input of a BatchGetDeploymentInstances operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetDeploymentInstancesInput {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -215,7 +256,7 @@ pub struct BatchGetDeploymentInstancesInput {
 
 /// <p>Represents the output of a BatchGetDeploymentInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetDeploymentInstancesOutput {
     /// <p>Information about errors that might have occurred during the API call.</p>
     #[serde(rename = "errorMessage")]
@@ -228,6 +269,7 @@ pub struct BatchGetDeploymentInstancesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetDeploymentTargetsInput {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -240,7 +282,7 @@ pub struct BatchGetDeploymentTargetsInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetDeploymentTargetsOutput {
     /// <p><p> A list of target objects for a deployment. Each target object contains details about the target, such as its status and lifecycle events. The type of the target objects depends on the deployment&#39; compute platform. </p> <ul> <li> <p> <b>EC2/On-premises</b>: Each target object is an EC2 or on-premises instance. </p> </li> <li> <p> <b>AWS Lambda</b>: The target object is a specific version of an AWS Lambda function. </p> </li> <li> <p> <b>Amazon ECS</b>: The target object is an Amazon ECS service. </p> </li> </ul></p>
     #[serde(rename = "deploymentTargets")]
@@ -250,6 +292,7 @@ pub struct BatchGetDeploymentTargetsOutput {
 
 /// <p> Represents the input of a BatchGetDeployments operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetDeploymentsInput {
     /// <p> A list of deployment IDs, separated by spaces. The maximum number of deployment IDs you can specify is 25.</p>
     #[serde(rename = "deploymentIds")]
@@ -258,7 +301,7 @@ pub struct BatchGetDeploymentsInput {
 
 /// <p> Represents the output of a BatchGetDeployments operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetDeploymentsOutput {
     /// <p> Information about the deployments. </p>
     #[serde(rename = "deploymentsInfo")]
@@ -268,6 +311,7 @@ pub struct BatchGetDeploymentsOutput {
 
 /// <p>Represents the input of a BatchGetOnPremisesInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetOnPremisesInstancesInput {
     /// <p>The names of the on-premises instances about which to get information. The maximum number of instance names you can specify is 25.</p>
     #[serde(rename = "instanceNames")]
@@ -276,7 +320,7 @@ pub struct BatchGetOnPremisesInstancesInput {
 
 /// <p>Represents the output of a BatchGetOnPremisesInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetOnPremisesInstancesOutput {
     /// <p>Information about the on-premises instances.</p>
     #[serde(rename = "instanceInfos")]
@@ -315,6 +359,7 @@ pub struct BlueInstanceTerminationOption {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContinueDeploymentInput {
     /// <p> The unique ID of a blue/green deployment for which you want to start rerouting traffic to the replacement environment. </p>
     #[serde(rename = "deploymentId")]
@@ -328,6 +373,7 @@ pub struct ContinueDeploymentInput {
 
 /// <p>Represents the input of a CreateApplication operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApplicationInput {
     /// <p>The name of the application. This name must be unique with the applicable IAM user or AWS account.</p>
     #[serde(rename = "applicationName")]
@@ -342,9 +388,20 @@ pub struct CreateApplicationInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateApplicationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a CreateApplication operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateApplicationOutput {
     /// <p>A unique application ID.</p>
     #[serde(rename = "applicationId")]
@@ -354,6 +411,7 @@ pub struct CreateApplicationOutput {
 
 /// <p>Represents the input of a CreateDeploymentConfig operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeploymentConfigInput {
     /// <p>The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
@@ -372,9 +430,20 @@ pub struct CreateDeploymentConfigInput {
     pub traffic_routing_config: Option<TrafficRoutingConfig>,
 }
 
+impl Validate for CreateDeploymentConfigInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "deployment_config_name"),
+            self.deployment_config_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a CreateDeploymentConfig operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDeploymentConfigOutput {
     /// <p>A unique deployment configuration ID.</p>
     #[serde(rename = "deploymentConfigId")]
@@ -384,6 +453,7 @@ pub struct CreateDeploymentConfigOutput {
 
 /// <p>Represents the input of a CreateDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeploymentGroupInput {
     /// <p>Information to add about Amazon CloudWatch alarms when the deployment group is created.</p>
     #[serde(rename = "alarmConfiguration")]
@@ -452,9 +522,34 @@ pub struct CreateDeploymentGroupInput {
     pub trigger_configurations: Option<Vec<TriggerConfig>>,
 }
 
+impl Validate for CreateDeploymentGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.deployment_config_name {
+            errors.check_length(
+                &field_path(path, "deployment_config_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "deployment_group_name"),
+            self.deployment_group_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a CreateDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDeploymentGroupOutput {
     /// <p>A unique deployment group ID.</p>
     #[serde(rename = "deploymentGroupId")]
@@ -464,6 +559,7 @@ pub struct CreateDeploymentGroupOutput {
 
 /// <p>Represents the input of a CreateDeployment operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeploymentInput {
     /// <p>The name of an AWS CodeDeploy application associated with the IAM user or AWS account.</p>
     #[serde(rename = "applicationName")]
@@ -506,9 +602,36 @@ pub struct CreateDeploymentInput {
     pub update_outdated_instances_only: Option<bool>,
 }
 
+impl Validate for CreateDeploymentInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.deployment_config_name {
+            errors.check_length(
+                &field_path(path, "deployment_config_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.deployment_group_name {
+            errors.check_length(
+                &field_path(path, "deployment_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p> Represents the output of a CreateDeployment operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDeploymentOutput {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -518,22 +641,47 @@ pub struct CreateDeploymentOutput {
 
 /// <p>Represents the input of a DeleteApplication operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApplicationInput {
     /// <p>The name of an AWS CodeDeploy application associated with the IAM user or AWS account.</p>
     #[serde(rename = "applicationName")]
     pub application_name: String,
 }
 
+impl Validate for DeleteApplicationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the input of a DeleteDeploymentConfig operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDeploymentConfigInput {
     /// <p>The name of a deployment configuration associated with the IAM user or AWS account.</p>
     #[serde(rename = "deploymentConfigName")]
     pub deployment_config_name: String,
 }
 
+impl Validate for DeleteDeploymentConfigInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "deployment_config_name"),
+            self.deployment_config_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the input of a DeleteDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDeploymentGroupInput {
     /// <p>The name of an AWS CodeDeploy application associated with the IAM user or AWS account.</p>
     #[serde(rename = "applicationName")]
@@ -543,9 +691,26 @@ pub struct DeleteDeploymentGroupInput {
     pub deployment_group_name: String,
 }
 
+impl Validate for DeleteDeploymentGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "deployment_group_name"),
+            self.deployment_group_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a DeleteDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDeploymentGroupOutput {
     /// <p>If the output contains no data, and the corresponding deployment group contained at least one Auto Scaling group, AWS CodeDeploy successfully removed all corresponding Auto Scaling lifecycle event hooks from the Amazon EC2 instances in the Auto Scaling group. If the output contains data, AWS CodeDeploy could not remove some Auto Scaling lifecycle event hooks from the Amazon EC2 instances in the Auto Scaling group.</p>
     #[serde(rename = "hooksNotCleanedUp")]
@@ -555,6 +720,7 @@ pub struct DeleteDeploymentGroupOutput {
 
 /// <p>Represents the input of a DeleteGitHubAccount operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGitHubAccountTokenInput {
     /// <p>The name of the GitHub account connection to delete.</p>
     #[serde(rename = "tokenName")]
@@ -564,7 +730,7 @@ pub struct DeleteGitHubAccountTokenInput {
 
 /// <p>Represents the output of a DeleteGitHubAccountToken operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteGitHubAccountTokenOutput {
     /// <p>The name of the GitHub account connection that was deleted.</p>
     #[serde(rename = "tokenName")]
@@ -574,7 +740,7 @@ pub struct DeleteGitHubAccountTokenOutput {
 
 /// <p>Information about a deployment configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeploymentConfigInfo {
     /// <p>The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
@@ -604,7 +770,7 @@ pub struct DeploymentConfigInfo {
 
 /// <p>Information about a deployment group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeploymentGroupInfo {
     /// <p>A list of alarms associated with the deployment group.</p>
     #[serde(rename = "alarmConfiguration")]
@@ -694,7 +860,7 @@ pub struct DeploymentGroupInfo {
 
 /// <p>Information about a deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeploymentInfo {
     /// <p>Provides information about the results of a deployment, such as whether instances in the original environment in a blue/green deployment were not terminated.</p>
     #[serde(rename = "additionalDeploymentStatusInfo")]
@@ -808,7 +974,7 @@ pub struct DeploymentInfo {
 
 /// <p>Information about the deployment status of the instances in the deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeploymentOverview {
     /// <p>The number of instances in the deployment in a failed state.</p>
     #[serde(rename = "Failed")]
@@ -864,7 +1030,7 @@ pub struct DeploymentStyle {
 
 /// <p> Information about the deployment target. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeploymentTarget {
     /// <p> The deployment type that is specific to the deployment's compute platform. </p>
     #[serde(rename = "deploymentTargetType")]
@@ -886,6 +1052,7 @@ pub struct DeploymentTarget {
 
 /// <p>Represents the input of a DeregisterOnPremisesInstance operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterOnPremisesInstanceInput {
     /// <p>The name of the on-premises instance to deregister.</p>
     #[serde(rename = "instanceName")]
@@ -894,7 +1061,7 @@ pub struct DeregisterOnPremisesInstanceInput {
 
 /// <p>Diagnostic information about executable scripts that are part of a deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Diagnostics {
     /// <p><p>The associated error code:</p> <ul> <li> <p>Success: The specified script ran.</p> </li> <li> <p>ScriptMissing: The specified script was not found in the specified location.</p> </li> <li> <p>ScriptNotExecutable: The specified script is not a recognized executable file type.</p> </li> <li> <p>ScriptTimedOut: The specified script did not finish running in the specified time period.</p> </li> <li> <p>ScriptFailed: The specified script failed to run as expected.</p> </li> <li> <p>UnknownError: The specified script did not run for an unknown reason.</p> </li> </ul></p>
     #[serde(rename = "errorCode")]
@@ -955,7 +1122,7 @@ pub struct ECSService {
 
 /// <p> Information about the target of an Amazon ECS deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ECSTarget {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -989,7 +1156,7 @@ pub struct ECSTarget {
 
 /// <p> Information about a set of Amazon ECS tasks in an AWS CodeDeploy deployment. An Amazon ECS task set includes details such as the desired number of tasks, how many tasks are running, and whether the task set serves production traffic. An AWS CodeDeploy application that uses the Amazon ECS compute platform deploys a containerized application in an Amazon ECS service as a task set. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ECSTaskSet {
     /// <p> The number of tasks in a task set. During a deployment that uses the Amazon ECS compute type, CodeDeploy instructs Amazon ECS to create a new task set and uses this value to determine how many tasks to create. After the updated task set is created, CodeDeploy shifts traffic to the new task set. </p>
     #[serde(rename = "desiredCount")]
@@ -1036,7 +1203,7 @@ pub struct ELBInfo {
 
 /// <p>Information about a deployment error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ErrorInformation {
     /// <p><p>For more information, see <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide/error-codes.html">Error Codes for AWS CodeDeploy</a> in the <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide">AWS CodeDeploy User Guide</a>.</p> <p>The error code:</p> <ul> <li> <p>APPLICATION<em>MISSING: The application was missing. This error code is most likely raised if the application is deleted after the deployment is created, but before it is started.</p> </li> <li> <p>DEPLOYMENT</em>GROUP<em>MISSING: The deployment group was missing. This error code is most likely raised if the deployment group is deleted after the deployment is created, but before it is started.</p> </li> <li> <p>HEALTH</em>CONSTRAINTS: The deployment failed on too many instances to be successfully deployed within the instance health constraints specified.</p> </li> <li> <p>HEALTH<em>CONSTRAINTS</em>INVALID: The revision cannot be successfully deployed within the instance health constraints specified.</p> </li> <li> <p>IAM<em>ROLE</em>MISSING: The service role cannot be accessed.</p> </li> <li> <p>IAM<em>ROLE</em>PERMISSIONS: The service role does not have the correct permissions.</p> </li> <li> <p>INTERNAL<em>ERROR: There was an internal error.</p> </li> <li> <p>NO</em>EC2<em>SUBSCRIPTION: The calling account is not subscribed to Amazon EC2.</p> </li> <li> <p>NO</em>INSTANCES: No instances were specified, or no instances can be found.</p> </li> <li> <p>OVER<em>MAX</em>INSTANCES: The maximum number of instances was exceeded.</p> </li> <li> <p>THROTTLED: The operation was throttled because the calling account exceeded the throttling limits of one or more AWS services.</p> </li> <li> <p>TIMEOUT: The deployment has timed out.</p> </li> <li> <p>REVISION_MISSING: The revision ID was missing. This error code is most likely raised if the revision is deleted after the deployment is created, but before it is started.</p> </li> </ul></p>
     #[serde(rename = "code")]
@@ -1050,7 +1217,7 @@ pub struct ErrorInformation {
 
 /// <p>Information about an application revision.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GenericRevisionInfo {
     /// <p>The deployment groups for which this is the current target revision.</p>
     #[serde(rename = "deploymentGroups")]
@@ -1076,15 +1243,27 @@ pub struct GenericRevisionInfo {
 
 /// <p>Represents the input of a GetApplication operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetApplicationInput {
     /// <p>The name of an AWS CodeDeploy application associated with the IAM user or AWS account.</p>
     #[serde(rename = "applicationName")]
     pub application_name: String,
 }
 
+impl Validate for GetApplicationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a GetApplication operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetApplicationOutput {
     /// <p>Information about the application.</p>
     #[serde(rename = "application")]
@@ -1094,6 +1273,7 @@ pub struct GetApplicationOutput {
 
 /// <p>Represents the input of a GetApplicationRevision operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetApplicationRevisionInput {
     /// <p>The name of the application that corresponds to the revision.</p>
     #[serde(rename = "applicationName")]
@@ -1103,9 +1283,20 @@ pub struct GetApplicationRevisionInput {
     pub revision: RevisionLocation,
 }
 
+impl Validate for GetApplicationRevisionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a GetApplicationRevision operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetApplicationRevisionOutput {
     /// <p>The name of the application that corresponds to the revision.</p>
     #[serde(rename = "applicationName")]
@@ -1123,15 +1314,27 @@ pub struct GetApplicationRevisionOutput {
 
 /// <p>Represents the input of a GetDeploymentConfig operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeploymentConfigInput {
     /// <p>The name of a deployment configuration associated with the IAM user or AWS account.</p>
     #[serde(rename = "deploymentConfigName")]
     pub deployment_config_name: String,
 }
 
+impl Validate for GetDeploymentConfigInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "deployment_config_name"),
+            self.deployment_config_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a GetDeploymentConfig operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeploymentConfigOutput {
     /// <p>Information about the deployment configuration.</p>
     #[serde(rename = "deploymentConfigInfo")]
@@ -1141,6 +1344,7 @@ pub struct GetDeploymentConfigOutput {
 
 /// <p>Represents the input of a GetDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeploymentGroupInput {
     /// <p>The name of an AWS CodeDeploy application associated with the IAM user or AWS account.</p>
     #[serde(rename = "applicationName")]
@@ -1150,9 +1354,26 @@ pub struct GetDeploymentGroupInput {
     pub deployment_group_name: String,
 }
 
+impl Validate for GetDeploymentGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "deployment_group_name"),
+            self.deployment_group_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a GetDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeploymentGroupOutput {
     /// <p>Information about the deployment group.</p>
     #[serde(rename = "deploymentGroupInfo")]
@@ -1162,6 +1383,7 @@ pub struct GetDeploymentGroupOutput {
 
 /// <p>Represents the input of a GetDeployment operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeploymentInput {
     /// <p> The unique ID of a deployment associated with the IAM user or AWS account. </p>
     #[serde(rename = "deploymentId")]
@@ -1170,6 +1392,7 @@ pub struct GetDeploymentInput {
 
 /// <p> Represents the input of a GetDeploymentInstance operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeploymentInstanceInput {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -1181,7 +1404,7 @@ pub struct GetDeploymentInstanceInput {
 
 /// <p> Represents the output of a GetDeploymentInstance operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeploymentInstanceOutput {
     /// <p> Information about the instance. </p>
     #[serde(rename = "instanceSummary")]
@@ -1191,7 +1414,7 @@ pub struct GetDeploymentInstanceOutput {
 
 /// <p>Represents the output of a GetDeployment operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeploymentOutput {
     /// <p>Information about the deployment.</p>
     #[serde(rename = "deploymentInfo")]
@@ -1200,6 +1423,7 @@ pub struct GetDeploymentOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeploymentTargetInput {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -1212,7 +1436,7 @@ pub struct GetDeploymentTargetInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeploymentTargetOutput {
     /// <p> A deployment target that contains information about a deployment such as its status, lifecyle events, and when it was last updated. It also contains metadata about the deployment target. The deployment target metadata depends on the deployment target's type (<code>instanceTarget</code>, <code>lambdaTarget</code>, or <code>ecsTarget</code>). </p>
     #[serde(rename = "deploymentTarget")]
@@ -1222,6 +1446,7 @@ pub struct GetDeploymentTargetOutput {
 
 /// <p> Represents the input of a GetOnPremisesInstance operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOnPremisesInstanceInput {
     /// <p> The name of the on-premises instance about which to get information. </p>
     #[serde(rename = "instanceName")]
@@ -1230,7 +1455,7 @@ pub struct GetOnPremisesInstanceInput {
 
 /// <p> Represents the output of a GetOnPremisesInstance operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOnPremisesInstanceOutput {
     /// <p> Information about the on-premises instance. </p>
     #[serde(rename = "instanceInfo")]
@@ -1262,7 +1487,7 @@ pub struct GreenFleetProvisioningOption {
 
 /// <p>Information about an on-premises instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceInfo {
     /// <p>If the on-premises instance was deregistered, the time at which the on-premises instance was deregistered.</p>
     #[serde(rename = "deregisterTime")]
@@ -1296,7 +1521,7 @@ pub struct InstanceInfo {
 
 /// <p>Information about an instance in a deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceSummary {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -1326,7 +1551,7 @@ pub struct InstanceSummary {
 
 /// <p> A target Amazon EC2 or on-premises instance during a deployment that uses the EC2/On-premises compute platform. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceTarget {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -1360,7 +1585,7 @@ pub struct InstanceTarget {
 
 /// <p> Information about a Lambda function specified in a deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionInfo {
     /// <p> The version of a Lambda function that production traffic points to. </p>
     #[serde(rename = "currentVersion")]
@@ -1386,7 +1611,7 @@ pub struct LambdaFunctionInfo {
 
 /// <p> Information about the target AWS Lambda function during an AWS Lambda deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaTarget {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -1420,7 +1645,7 @@ pub struct LambdaTarget {
 
 /// <p>Information about the most recent attempted or successful deployment to a deployment group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LastDeploymentInfo {
     /// <p>A timestamp that indicates when the most recent deployment to the deployment group started.</p>
     #[serde(rename = "createTime")]
@@ -1442,7 +1667,7 @@ pub struct LastDeploymentInfo {
 
 /// <p>Information about a deployment lifecycle event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LifecycleEvent {
     /// <p>Diagnostic information about the deployment lifecycle event.</p>
     #[serde(rename = "diagnostics")]
@@ -1468,6 +1693,7 @@ pub struct LifecycleEvent {
 
 /// <p> Represents the input of a ListApplicationRevisions operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListApplicationRevisionsInput {
     /// <p> The name of an AWS CodeDeploy application associated with the IAM user or AWS account. </p>
     #[serde(rename = "applicationName")]
@@ -1498,9 +1724,20 @@ pub struct ListApplicationRevisionsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for ListApplicationRevisionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a ListApplicationRevisions operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListApplicationRevisionsOutput {
     /// <p>If a large amount of information is returned, an identifier is also returned. It can be used in a subsequent list application revisions call to return the next set of application revisions in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1514,6 +1751,7 @@ pub struct ListApplicationRevisionsOutput {
 
 /// <p>Represents the input of a ListApplications operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListApplicationsInput {
     /// <p>An identifier returned from the previous list applications call. It can be used to return the next set of applications in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1523,7 +1761,7 @@ pub struct ListApplicationsInput {
 
 /// <p>Represents the output of a ListApplications operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListApplicationsOutput {
     /// <p>A list of application names.</p>
     #[serde(rename = "applications")]
@@ -1537,6 +1775,7 @@ pub struct ListApplicationsOutput {
 
 /// <p>Represents the input of a ListDeploymentConfigs operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeploymentConfigsInput {
     /// <p>An identifier returned from the previous <code>ListDeploymentConfigs</code> call. It can be used to return the next set of deployment configurations in the list. </p>
     #[serde(rename = "nextToken")]
@@ -1546,7 +1785,7 @@ pub struct ListDeploymentConfigsInput {
 
 /// <p>Represents the output of a ListDeploymentConfigs operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeploymentConfigsOutput {
     /// <p>A list of deployment configurations, including built-in configurations such as CodeDeployDefault.OneAtATime.</p>
     #[serde(rename = "deploymentConfigsList")]
@@ -1560,6 +1799,7 @@ pub struct ListDeploymentConfigsOutput {
 
 /// <p>Represents the input of a ListDeploymentGroups operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeploymentGroupsInput {
     /// <p>The name of an AWS CodeDeploy application associated with the IAM user or AWS account.</p>
     #[serde(rename = "applicationName")]
@@ -1570,9 +1810,20 @@ pub struct ListDeploymentGroupsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDeploymentGroupsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the output of a ListDeploymentGroups operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeploymentGroupsOutput {
     /// <p>The application name.</p>
     #[serde(rename = "applicationName")]
@@ -1590,6 +1841,7 @@ pub struct ListDeploymentGroupsOutput {
 
 /// <p> Represents the input of a ListDeploymentInstances operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeploymentInstancesInput {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -1610,7 +1862,7 @@ pub struct ListDeploymentInstancesInput {
 
 /// <p>Represents the output of a ListDeploymentInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeploymentInstancesOutput {
     /// <p>A list of instance IDs.</p>
     #[serde(rename = "instancesList")]
@@ -1623,6 +1875,7 @@ pub struct ListDeploymentInstancesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeploymentTargetsInput {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -1639,7 +1892,7 @@ pub struct ListDeploymentTargetsInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeploymentTargetsOutput {
     /// <p> If a large amount of information is returned, a token identifier is also returned. It can be used in a subsequent <code>ListDeploymentTargets</code> call to return the next set of deployment targets in the list. </p>
     #[serde(rename = "nextToken")]
@@ -1653,6 +1906,7 @@ pub struct ListDeploymentTargetsOutput {
 
 /// <p>Represents the input of a ListDeployments operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeploymentsInput {
     /// <p><p>The name of an AWS CodeDeploy application associated with the IAM user or AWS account.</p> <note> <p>If <code>applicationName</code> is specified, then <code>deploymentGroupName</code> must be specified. If it is not specified, then <code>deploymentGroupName</code> must not be specified. </p> </note></p>
     #[serde(rename = "applicationName")]
@@ -1676,9 +1930,30 @@ pub struct ListDeploymentsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDeploymentsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_name {
+            errors.check_length(
+                &field_path(path, "application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.deployment_group_name {
+            errors.check_length(
+                &field_path(path, "deployment_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Represents the output of a ListDeployments operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeploymentsOutput {
     /// <p>A list of deployment IDs.</p>
     #[serde(rename = "deployments")]
@@ -1692,6 +1967,7 @@ pub struct ListDeploymentsOutput {
 
 /// <p>Represents the input of a ListGitHubAccountTokenNames operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGitHubAccountTokenNamesInput {
     /// <p>An identifier returned from the previous ListGitHubAccountTokenNames call. It can be used to return the next set of names in the list. </p>
     #[serde(rename = "nextToken")]
@@ -1701,7 +1977,7 @@ pub struct ListGitHubAccountTokenNamesInput {
 
 /// <p>Represents the output of a ListGitHubAccountTokenNames operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGitHubAccountTokenNamesOutput {
     /// <p>If a large amount of information is returned, an identifier is also returned. It can be used in a subsequent ListGitHubAccountTokenNames call to return the next set of names in the list. </p>
     #[serde(rename = "nextToken")]
@@ -1715,6 +1991,7 @@ pub struct ListGitHubAccountTokenNamesOutput {
 
 /// <p>Represents the input of a ListOnPremisesInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOnPremisesInstancesInput {
     /// <p>An identifier returned from the previous list on-premises instances call. It can be used to return the next set of on-premises instances in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1732,7 +2009,7 @@ pub struct ListOnPremisesInstancesInput {
 
 /// <p>Represents the output of the list on-premises instances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOnPremisesInstancesOutput {
     /// <p>The list of matching on-premises instance names.</p>
     #[serde(rename = "instanceNames")]
@@ -1745,6 +2022,7 @@ pub struct ListOnPremisesInstancesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>An identifier returned from the previous <code>ListTagsForResource</code> call. It can be used to return the next set of applications in the list.</p>
     #[serde(rename = "NextToken")]
@@ -1755,8 +2033,19 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1011),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceOutput {
     /// <p>If a large amount of information is returned, an identifier is also returned. It can be used in a subsequent list application revisions call to return the next set of application revisions in the list.</p>
     #[serde(rename = "NextToken")]
@@ -1808,6 +2097,7 @@ pub struct OnPremisesTagSet {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutLifecycleEventHookExecutionStatusInput {
     /// <p> The unique ID of a deployment. Pass this ID to a Lambda function that validates a deployment lifecycle event. </p>
     #[serde(rename = "deploymentId")]
@@ -1824,7 +2114,7 @@ pub struct PutLifecycleEventHookExecutionStatusInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutLifecycleEventHookExecutionStatusOutput {
     /// <p>The execution ID of the lifecycle event hook. A hook is specified in the <code>hooks</code> section of the deployment's AppSpec file.</p>
     #[serde(rename = "lifecycleEventHookExecutionId")]
@@ -1847,6 +2137,7 @@ pub struct RawString {
 
 /// <p>Represents the input of a RegisterApplicationRevision operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterApplicationRevisionInput {
     /// <p>The name of an AWS CodeDeploy application associated with the IAM user or AWS account.</p>
     #[serde(rename = "applicationName")]
@@ -1860,8 +2151,20 @@ pub struct RegisterApplicationRevisionInput {
     pub revision: RevisionLocation,
 }
 
+impl Validate for RegisterApplicationRevisionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the input of the register on-premises instance operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterOnPremisesInstanceInput {
     /// <p>The ARN of the IAM session to associate with the on-premises instance.</p>
     #[serde(rename = "iamSessionArn")]
@@ -1878,6 +2181,7 @@ pub struct RegisterOnPremisesInstanceInput {
 
 /// <p>Represents the input of a RemoveTagsFromOnPremisesInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromOnPremisesInstancesInput {
     /// <p>The names of the on-premises instances from which to remove tags.</p>
     #[serde(rename = "instanceNames")]
@@ -1889,7 +2193,7 @@ pub struct RemoveTagsFromOnPremisesInstancesInput {
 
 /// <p>Information about an application revision.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RevisionInfo {
     /// <p>Information about an application revision, including usage details and associated deployment groups.</p>
     #[serde(rename = "genericRevisionInfo")]
@@ -1928,7 +2232,7 @@ pub struct RevisionLocation {
 
 /// <p>Information about a deployment rollback.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RollbackInfo {
     /// <p>The ID of the deployment rollback.</p>
     #[serde(rename = "rollbackDeploymentId")]
@@ -1970,6 +2274,7 @@ pub struct S3Location {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SkipWaitTimeForInstanceTerminationInput {
     /// <p> The unique ID of a blue/green deployment for which you want to skip the instance termination wait time. </p>
     #[serde(rename = "deploymentId")]
@@ -1979,6 +2284,7 @@ pub struct SkipWaitTimeForInstanceTerminationInput {
 
 /// <p> Represents the input of a StopDeployment operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopDeploymentInput {
     /// <p> Indicates, when a deployment is stopped, whether instances that have been updated should be rolled back to the previous version of the application revision. </p>
     #[serde(rename = "autoRollbackEnabled")]
@@ -1991,7 +2297,7 @@ pub struct StopDeploymentInput {
 
 /// <p> Represents the output of a StopDeployment operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopDeploymentOutput {
     /// <p><p>The status of the stop deployment operation:</p> <ul> <li> <p>Pending: The stop operation is pending.</p> </li> <li> <p>Succeeded: The stop operation was successful.</p> </li> </ul></p>
     #[serde(rename = "status")]
@@ -2034,6 +2340,7 @@ pub struct TagFilter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p> The ARN of a resource, such as a CodeDeploy application or deployment group. </p>
     #[serde(rename = "ResourceArn")]
@@ -2043,8 +2350,19 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1011),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceOutput {}
 
 /// <p>Information about a target group in Elastic Load Balancing to use in a deployment. Instances are registered as targets in a target group, and traffic is routed to the target group.</p>
@@ -2118,6 +2436,7 @@ pub struct TimeBasedLinear {
 
 /// <p>Information about a time range.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TimeRange {
     /// <p><p>The end time of the time range.</p> <note> <p>Specify null to leave the end time open-ended.</p> </note></p>
     #[serde(rename = "end")]
@@ -2173,6 +2492,7 @@ pub struct TriggerConfig {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p> The ARN that specifies from which resource to disassociate the tags with the keys in the <code>TagKeys</code> input paramter. </p>
     #[serde(rename = "ResourceArn")]
@@ -2182,12 +2502,24 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1011),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceOutput {}
 
 /// <p>Represents the input of an UpdateApplication operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApplicationInput {
     /// <p>The current name of the application you want to change.</p>
     #[serde(rename = "applicationName")]
@@ -2199,8 +2531,30 @@ pub struct UpdateApplicationInput {
     pub new_application_name: Option<String>,
 }
 
+impl Validate for UpdateApplicationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_name {
+            errors.check_length(
+                &field_path(path, "application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.new_application_name {
+            errors.check_length(
+                &field_path(path, "new_application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Represents the input of an UpdateDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDeploymentGroupInput {
     /// <p>Information to add or change about Amazon CloudWatch alarms when the deployment group is updated.</p>
     #[serde(rename = "alarmConfiguration")]
@@ -2270,9 +2624,42 @@ pub struct UpdateDeploymentGroupInput {
     pub trigger_configurations: Option<Vec<TriggerConfig>>,
 }
 
+impl Validate for UpdateDeploymentGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "current_deployment_group_name"),
+            self.current_deployment_group_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.deployment_config_name {
+            errors.check_length(
+                &field_path(path, "deployment_config_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.new_deployment_group_name {
+            errors.check_length(
+                &field_path(path, "new_deployment_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Represents the output of an UpdateDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDeploymentGroupOutput {
     /// <p>If the output contains no data, and the corresponding deployment group contained at least one Auto Scaling group, AWS CodeDeploy successfully removed all corresponding Auto Scaling lifecycle event hooks from the AWS account. If the output contains data, AWS CodeDeploy could not remove some Auto Scaling lifecycle event hooks from the AWS account.</p>
     #[serde(rename = "hooksNotCleanedUp")]
@@ -2340,7 +2727,12 @@ impl AddTagsToOnPremisesInstancesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2418,7 +2810,12 @@ impl BatchGetApplicationRevisionsError {
                         BatchGetApplicationRevisionsError::RevisionRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2479,7 +2876,12 @@ impl BatchGetApplicationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2559,7 +2961,12 @@ impl BatchGetDeploymentGroupsError {
                         BatchGetDeploymentGroupsError::InvalidDeploymentGroupName(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2644,7 +3051,12 @@ impl BatchGetDeploymentInstancesError {
                         BatchGetDeploymentInstancesError::InvalidInstanceName(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2734,7 +3146,12 @@ impl BatchGetDeploymentTargetsError {
                         BatchGetDeploymentTargetsError::InvalidDeploymentTargetId(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2790,7 +3207,12 @@ impl BatchGetDeploymentsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2843,7 +3265,12 @@ impl BatchGetOnPremisesInstancesError {
                         BatchGetOnPremisesInstancesError::InvalidInstanceName(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2929,7 +3356,12 @@ impl ContinueDeploymentError {
                         ContinueDeploymentError::UnsupportedActionForDeploymentType(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3004,7 +3436,12 @@ impl CreateApplicationError {
                 "InvalidTagsToAddException" => {
                     return RusotoError::Service(CreateApplicationError::InvalidTagsToAdd(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3188,7 +3625,12 @@ impl CreateDeploymentError {
                 "ThrottlingException" => {
                     return RusotoError::Service(CreateDeploymentError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3287,7 +3729,12 @@ impl CreateDeploymentConfigError {
                         CreateDeploymentConfigError::InvalidTrafficRoutingConfiguration(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3535,7 +3982,12 @@ impl CreateDeploymentGroupError {
                         CreateDeploymentGroupError::TriggerTargetsLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3613,7 +4065,12 @@ impl DeleteApplicationError {
                 "InvalidRoleException" => {
                     return RusotoError::Service(DeleteApplicationError::InvalidRole(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3671,7 +4128,12 @@ impl DeleteDeploymentConfigError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3735,7 +4197,12 @@ impl DeleteDeploymentGroupError {
                 "InvalidRoleException" => {
                     return RusotoError::Service(DeleteDeploymentGroupError::InvalidRole(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3802,7 +4269,12 @@ impl DeleteGitHubAccountTokenError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3850,7 +4322,12 @@ impl DeregisterOnPremisesInstanceError {
                         DeregisterOnPremisesInstanceError::InvalidInstanceName(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3900,7 +4377,12 @@ impl GetApplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3972,7 +4454,12 @@ impl GetApplicationRevisionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4022,7 +4509,12 @@ impl GetDeploymentError {
                 "InvalidDeploymentIdException" => {
                     return RusotoError::Service(GetDeploymentError::InvalidDeploymentId(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4080,7 +4572,12 @@ impl GetDeploymentConfigError {
                         GetDeploymentConfigError::InvalidDeploymentConfigName(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4160,7 +4657,12 @@ impl GetDeploymentGroupError {
                         GetDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4243,7 +4745,12 @@ impl GetDeploymentInstanceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4333,7 +4840,12 @@ impl GetDeploymentTargetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4389,7 +4901,12 @@ impl GetOnPremisesInstanceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4489,7 +5006,12 @@ impl ListApplicationRevisionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4531,7 +5053,12 @@ impl ListApplicationsError {
                 "InvalidNextTokenException" => {
                     return RusotoError::Service(ListApplicationsError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4566,7 +5093,12 @@ impl ListDeploymentConfigsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4622,7 +5154,12 @@ impl ListDeploymentGroupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4723,7 +5260,12 @@ impl ListDeploymentInstancesError {
                         ListDeploymentInstancesError::InvalidTargetFilterName(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4816,7 +5358,12 @@ impl ListDeploymentTargetsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4910,7 +5457,12 @@ impl ListDeploymentsError {
                 "InvalidTimeRangeException" => {
                     return RusotoError::Service(ListDeploymentsError::InvalidTimeRange(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4969,7 +5521,12 @@ impl ListGitHubAccountTokenNamesError {
                         ListGitHubAccountTokenNamesError::ResourceValidation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5020,7 +5577,12 @@ impl ListOnPremisesInstancesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5067,7 +5629,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5120,7 +5687,12 @@ impl PutLifecycleEventHookExecutionStatusError {
 "InvalidLifecycleEventHookExecutionStatusException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionStatus(err.msg)),
 "LifecycleEventAlreadyCompletedException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::LifecycleEventAlreadyCompleted(err.msg)),
 "UnsupportedActionForDeploymentTypeException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::UnsupportedActionForDeploymentType(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+"ValidationException" => {
+    return RusotoError::Validation {
+        message: err.msg,
+        fields: err.fields,
+    }
+}
 _ => {}
                             }
         }
@@ -5206,7 +5778,12 @@ impl RegisterApplicationRevisionError {
                         RegisterApplicationRevisionError::RevisionRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5311,7 +5888,12 @@ impl RegisterOnPremisesInstanceError {
                         RegisterOnPremisesInstanceError::MultipleIamArnsProvided(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5399,7 +5981,12 @@ impl RemoveTagsFromOnPremisesInstancesError {
                         RemoveTagsFromOnPremisesInstancesError::TagRequired(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5481,7 +6068,12 @@ impl SkipWaitTimeForInstanceTerminationError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5547,7 +6139,12 @@ impl StopDeploymentError {
                 "InvalidDeploymentIdException" => {
                     return RusotoError::Service(StopDeploymentError::InvalidDeploymentId(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5623,7 +6220,12 @@ impl TagResourceError {
                 "TagRequiredException" => {
                     return RusotoError::Service(TagResourceError::TagRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5704,7 +6306,12 @@ impl UntagResourceError {
                 "TagRequiredException" => {
                     return RusotoError::Service(UntagResourceError::TagRequired(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5767,7 +6374,12 @@ impl UpdateApplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6000,7 +6612,12 @@ impl UpdateDeploymentGroupError {
                         UpdateDeploymentGroupError::TriggerTargetsLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6329,1059 +6946,3917 @@ pub trait CodeDeploy {
         input: UpdateDeploymentGroupInput,
     ) -> RusotoFuture<UpdateDeploymentGroupOutput, UpdateDeploymentGroupError>;
 }
-/// A client for the CodeDeploy API.
-#[derive(Clone)]
-pub struct CodeDeployClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CodeDeployClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CodeDeployClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CodeDeployClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CodeDeployClient {
-        CodeDeployClient { client, region }
-    }
-}
-
-impl CodeDeploy for CodeDeployClient {
-    /// <p>Adds tags to on-premises instances.</p>
+const _: Option<&dyn CodeDeploy> = None;
+impl<'a, T: CodeDeploy + ?Sized> CodeDeploy for &'a T {
     fn add_tags_to_on_premises_instances(
         &self,
         input: AddTagsToOnPremisesInstancesInput,
     ) -> RusotoFuture<(), AddTagsToOnPremisesInstancesError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.AddTagsToOnPremisesInstances",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AddTagsToOnPremisesInstancesError::from_response(response))
-                }))
-            }
-        })
+        (**self).add_tags_to_on_premises_instances(input)
     }
-
-    /// <p>Gets information about one or more application revisions. The maximum number of application revisions that can be returned is 25.</p>
     fn batch_get_application_revisions(
         &self,
         input: BatchGetApplicationRevisionsInput,
     ) -> RusotoFuture<BatchGetApplicationRevisionsOutput, BatchGetApplicationRevisionsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.BatchGetApplicationRevisions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetApplicationRevisionsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchGetApplicationRevisionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_get_application_revisions(input)
     }
-
-    /// <p>Gets information about one or more applications. The maximum number of applications that can be returned is 25.</p>
     fn batch_get_applications(
         &self,
         input: BatchGetApplicationsInput,
     ) -> RusotoFuture<BatchGetApplicationsOutput, BatchGetApplicationsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetApplications");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetApplicationsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchGetApplicationsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_get_applications(input)
     }
-
-    /// <p>Gets information about one or more deployment groups.</p>
     fn batch_get_deployment_groups(
         &self,
         input: BatchGetDeploymentGroupsInput,
     ) -> RusotoFuture<BatchGetDeploymentGroupsOutput, BatchGetDeploymentGroupsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.BatchGetDeploymentGroups",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetDeploymentGroupsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchGetDeploymentGroupsError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_get_deployment_groups(input)
     }
-
-    /// <p><note> <p> This method works, but is deprecated. Use <code>BatchGetDeploymentTargets</code> instead. </p> </note> <p> Returns an array of one or more instances associated with a deployment. This method works with EC2/On-premises and AWS Lambda compute platforms. The newer <code>BatchGetDeploymentTargets</code> works with all compute platforms. The maximum number of instances that can be returned is 25.</p></p>
     fn batch_get_deployment_instances(
         &self,
         input: BatchGetDeploymentInstancesInput,
     ) -> RusotoFuture<BatchGetDeploymentInstancesOutput, BatchGetDeploymentInstancesError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.BatchGetDeploymentInstances",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetDeploymentInstancesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchGetDeploymentInstancesError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_get_deployment_instances(input)
     }
-
-    /// <p><p> Returns an array of one or more targets associated with a deployment. This method works with all compute types and should be used instead of the deprecated <code>BatchGetDeploymentInstances</code>. The maximum number of targets that can be returned is 25.</p> <p> The type of targets returned depends on the deployment&#39;s compute platform: </p> <ul> <li> <p> <b>EC2/On-premises</b>: Information about EC2 instance targets. </p> </li> <li> <p> <b>AWS Lambda</b>: Information about Lambda functions targets. </p> </li> <li> <p> <b>Amazon ECS</b>: Information about Amazon ECS service targets. </p> </li> </ul></p>
     fn batch_get_deployment_targets(
         &self,
         input: BatchGetDeploymentTargetsInput,
     ) -> RusotoFuture<BatchGetDeploymentTargetsOutput, BatchGetDeploymentTargetsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.BatchGetDeploymentTargets",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetDeploymentTargetsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchGetDeploymentTargetsError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_get_deployment_targets(input)
     }
-
-    /// <p>Gets information about one or more deployments. The maximum number of deployments that can be returned is 25.</p>
     fn batch_get_deployments(
         &self,
         input: BatchGetDeploymentsInput,
     ) -> RusotoFuture<BatchGetDeploymentsOutput, BatchGetDeploymentsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetDeployments");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetDeploymentsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchGetDeploymentsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_get_deployments(input)
     }
-
-    /// <p>Gets information about one or more on-premises instances. The maximum number of on-premises instances that can be returned is 25.</p>
     fn batch_get_on_premises_instances(
         &self,
         input: BatchGetOnPremisesInstancesInput,
     ) -> RusotoFuture<BatchGetOnPremisesInstancesOutput, BatchGetOnPremisesInstancesError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.BatchGetOnPremisesInstances",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetOnPremisesInstancesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchGetOnPremisesInstancesError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_get_on_premises_instances(input)
     }
-
-    /// <p>For a blue/green deployment, starts the process of rerouting traffic from instances in the original environment to instances in the replacement environment without waiting for a specified wait time to elapse. (Traffic rerouting, which is achieved by registering instances in the replacement environment with the load balancer, can start as soon as all instances have a status of Ready.) </p>
     fn continue_deployment(
         &self,
         input: ContinueDeploymentInput,
     ) -> RusotoFuture<(), ContinueDeploymentError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.ContinueDeployment");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ContinueDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).continue_deployment(input)
     }
-
-    /// <p>Creates an application.</p>
     fn create_application(
         &self,
         input: CreateApplicationInput,
     ) -> RusotoFuture<CreateApplicationOutput, CreateApplicationError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.CreateApplication");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateApplicationOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateApplicationError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_application(input)
     }
-
-    /// <p>Deploys an application revision through the specified deployment group.</p>
     fn create_deployment(
         &self,
         input: CreateDeploymentInput,
     ) -> RusotoFuture<CreateDeploymentOutput, CreateDeploymentError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeployment");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDeploymentOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_deployment(input)
     }
-
-    /// <p> Creates a deployment configuration. </p>
     fn create_deployment_config(
         &self,
         input: CreateDeploymentConfigInput,
     ) -> RusotoFuture<CreateDeploymentConfigOutput, CreateDeploymentConfigError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentConfig");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDeploymentConfigOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateDeploymentConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_deployment_config(input)
     }
-
-    /// <p>Creates a deployment group to which application revisions are deployed.</p>
     fn create_deployment_group(
         &self,
         input: CreateDeploymentGroupInput,
     ) -> RusotoFuture<CreateDeploymentGroupOutput, CreateDeploymentGroupError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDeploymentGroupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateDeploymentGroupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_deployment_group(input)
     }
-
-    /// <p>Deletes an application.</p>
     fn delete_application(
         &self,
         input: DeleteApplicationInput,
     ) -> RusotoFuture<(), DeleteApplicationError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteApplication");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteApplicationError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_application(input)
     }
-
-    /// <p><p>Deletes a deployment configuration.</p> <note> <p>A deployment configuration cannot be deleted if it is currently in use. Predefined configurations cannot be deleted.</p> </note></p>
     fn delete_deployment_config(
         &self,
         input: DeleteDeploymentConfigInput,
     ) -> RusotoFuture<(), DeleteDeploymentConfigError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentConfig");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteDeploymentConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_deployment_config(input)
     }
-
-    /// <p>Deletes a deployment group.</p>
     fn delete_deployment_group(
         &self,
         input: DeleteDeploymentGroupInput,
     ) -> RusotoFuture<DeleteDeploymentGroupOutput, DeleteDeploymentGroupError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDeploymentGroupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteDeploymentGroupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_deployment_group(input)
     }
-
-    /// <p>Deletes a GitHub account connection.</p>
     fn delete_git_hub_account_token(
         &self,
         input: DeleteGitHubAccountTokenInput,
     ) -> RusotoFuture<DeleteGitHubAccountTokenOutput, DeleteGitHubAccountTokenError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.DeleteGitHubAccountToken",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteGitHubAccountTokenOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteGitHubAccountTokenError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_git_hub_account_token(input)
     }
-
-    /// <p>Deregisters an on-premises instance.</p>
     fn deregister_on_premises_instance(
         &self,
         input: DeregisterOnPremisesInstanceInput,
     ) -> RusotoFuture<(), DeregisterOnPremisesInstanceError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.DeregisterOnPremisesInstance",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterOnPremisesInstanceError::from_response(response))
-                }))
-            }
-        })
+        (**self).deregister_on_premises_instance(input)
     }
-
-    /// <p>Gets information about an application.</p>
     fn get_application(
         &self,
         input: GetApplicationInput,
     ) -> RusotoFuture<GetApplicationOutput, GetApplicationError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplication");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetApplicationOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetApplicationError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_application(input)
     }
-
-    /// <p>Gets information about an application revision.</p>
     fn get_application_revision(
         &self,
         input: GetApplicationRevisionInput,
     ) -> RusotoFuture<GetApplicationRevisionOutput, GetApplicationRevisionError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplicationRevision");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetApplicationRevisionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetApplicationRevisionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_application_revision(input)
     }
-
-    /// <p><p>Gets information about a deployment.</p> <note> <p> The <code>content</code> property of the <code>appSpecContent</code> object in the returned revision is always null. Use <code>GetApplicationRevision</code> and the <code>sha256</code> property of the returned <code>appSpecContent</code> object to get the content of the deployment’s AppSpec file. </p> </note></p>
     fn get_deployment(
         &self,
         input: GetDeploymentInput,
     ) -> RusotoFuture<GetDeploymentOutput, GetDeploymentError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeployment");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeploymentOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_deployment(input)
     }
-
-    /// <p>Gets information about a deployment configuration.</p>
     fn get_deployment_config(
         &self,
         input: GetDeploymentConfigInput,
     ) -> RusotoFuture<GetDeploymentConfigOutput, GetDeploymentConfigError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentConfig");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeploymentConfigOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetDeploymentConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_deployment_config(input)
     }
-
-    /// <p>Gets information about a deployment group.</p>
     fn get_deployment_group(
         &self,
         input: GetDeploymentGroupInput,
     ) -> RusotoFuture<GetDeploymentGroupOutput, GetDeploymentGroupError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeploymentGroupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDeploymentGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_deployment_group(input)
     }
-
-    /// <p>Gets information about an instance as part of a deployment.</p>
     fn get_deployment_instance(
         &self,
         input: GetDeploymentInstanceInput,
     ) -> RusotoFuture<GetDeploymentInstanceOutput, GetDeploymentInstanceError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeploymentInstanceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetDeploymentInstanceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_deployment_instance(input)
     }
-
-    /// <p> Returns information about a deployment target. </p>
     fn get_deployment_target(
         &self,
         input: GetDeploymentTargetInput,
     ) -> RusotoFuture<GetDeploymentTargetOutput, GetDeploymentTargetError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentTarget");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeploymentTargetOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetDeploymentTargetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_deployment_target(input)
     }
-
-    /// <p> Gets information about an on-premises instance. </p>
     fn get_on_premises_instance(
         &self,
         input: GetOnPremisesInstanceInput,
     ) -> RusotoFuture<GetOnPremisesInstanceOutput, GetOnPremisesInstanceError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.GetOnPremisesInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOnPremisesInstanceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetOnPremisesInstanceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_on_premises_instance(input)
     }
-
-    /// <p>Lists information about revisions for an application.</p>
     fn list_application_revisions(
         &self,
         input: ListApplicationRevisionsInput,
     ) -> RusotoFuture<ListApplicationRevisionsOutput, ListApplicationRevisionsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.ListApplicationRevisions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListApplicationRevisionsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListApplicationRevisionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_application_revisions(input)
     }
-
-    /// <p>Lists the applications registered with the IAM user or AWS account.</p>
     fn list_applications(
         &self,
         input: ListApplicationsInput,
     ) -> RusotoFuture<ListApplicationsOutput, ListApplicationsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.ListApplications");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListApplicationsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListApplicationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_applications(input)
     }
-
-    /// <p>Lists the deployment configurations with the IAM user or AWS account.</p>
     fn list_deployment_configs(
         &self,
         input: ListDeploymentConfigsInput,
     ) -> RusotoFuture<ListDeploymentConfigsOutput, ListDeploymentConfigsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentConfigs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeploymentConfigsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListDeploymentConfigsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_deployment_configs(input)
     }
-
-    /// <p>Lists the deployment groups for an application registered with the IAM user or AWS account.</p>
     fn list_deployment_groups(
         &self,
         input: ListDeploymentGroupsInput,
     ) -> RusotoFuture<ListDeploymentGroupsOutput, ListDeploymentGroupsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeploymentGroupsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListDeploymentGroupsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_deployment_groups(input)
     }
-
-    /// <p><note> <p> The newer BatchGetDeploymentTargets should be used instead because it works with all compute types. <code>ListDeploymentInstances</code> throws an exception if it is used with a compute platform other than EC2/On-premises or AWS Lambda. </p> </note> <p> Lists the instance for a deployment associated with the IAM user or AWS account. </p></p>
     fn list_deployment_instances(
         &self,
         input: ListDeploymentInstancesInput,
     ) -> RusotoFuture<ListDeploymentInstancesOutput, ListDeploymentInstancesError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.ListDeploymentInstances",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeploymentInstancesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListDeploymentInstancesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_deployment_instances(input)
     }
-
-    /// <p> Returns an array of target IDs that are associated a deployment. </p>
     fn list_deployment_targets(
         &self,
         input: ListDeploymentTargetsInput,
     ) -> RusotoFuture<ListDeploymentTargetsOutput, ListDeploymentTargetsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentTargets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeploymentTargetsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListDeploymentTargetsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_deployment_targets(input)
     }
-
-    /// <p>Lists the deployments in a deployment group for an application registered with the IAM user or AWS account.</p>
     fn list_deployments(
         &self,
         input: ListDeploymentsInput,
     ) -> RusotoFuture<ListDeploymentsOutput, ListDeploymentsError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeployments");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeploymentsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDeploymentsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_deployments(input)
     }
-
-    /// <p>Lists the names of stored connections to GitHub accounts.</p>
     fn list_git_hub_account_token_names(
         &self,
         input: ListGitHubAccountTokenNamesInput,
     ) -> RusotoFuture<ListGitHubAccountTokenNamesOutput, ListGitHubAccountTokenNamesError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.ListGitHubAccountTokenNames",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGitHubAccountTokenNamesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListGitHubAccountTokenNamesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_git_hub_account_token_names(input)
     }
-
-    /// <p>Gets a list of names for one or more on-premises instances.</p> <p>Unless otherwise specified, both registered and deregistered on-premises instance names are listed. To list only registered or deregistered on-premises instance names, use the registration status parameter.</p>
     fn list_on_premises_instances(
         &self,
         input: ListOnPremisesInstancesInput,
     ) -> RusotoFuture<ListOnPremisesInstancesOutput, ListOnPremisesInstancesError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.ListOnPremisesInstances",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOnPremisesInstancesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListOnPremisesInstancesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_on_premises_instances(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_lifecycle_event_hook_execution_status(
+        &self,
+        input: PutLifecycleEventHookExecutionStatusInput,
+    ) -> RusotoFuture<
+        PutLifecycleEventHookExecutionStatusOutput,
+        PutLifecycleEventHookExecutionStatusError,
+    > {
+        (**self).put_lifecycle_event_hook_execution_status(input)
+    }
+    fn register_application_revision(
+        &self,
+        input: RegisterApplicationRevisionInput,
+    ) -> RusotoFuture<(), RegisterApplicationRevisionError> {
+        (**self).register_application_revision(input)
+    }
+    fn register_on_premises_instance(
+        &self,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> RusotoFuture<(), RegisterOnPremisesInstanceError> {
+        (**self).register_on_premises_instance(input)
+    }
+    fn remove_tags_from_on_premises_instances(
+        &self,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> RusotoFuture<(), RemoveTagsFromOnPremisesInstancesError> {
+        (**self).remove_tags_from_on_premises_instances(input)
+    }
+    fn skip_wait_time_for_instance_termination(
+        &self,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> RusotoFuture<(), SkipWaitTimeForInstanceTerminationError> {
+        (**self).skip_wait_time_for_instance_termination(input)
+    }
+    fn stop_deployment(
+        &self,
+        input: StopDeploymentInput,
+    ) -> RusotoFuture<StopDeploymentOutput, StopDeploymentError> {
+        (**self).stop_deployment(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationInput,
+    ) -> RusotoFuture<(), UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+    fn update_deployment_group(
+        &self,
+        input: UpdateDeploymentGroupInput,
+    ) -> RusotoFuture<UpdateDeploymentGroupOutput, UpdateDeploymentGroupError> {
+        (**self).update_deployment_group(input)
+    }
+}
+impl<T: CodeDeploy + ?Sized> CodeDeploy for Box<T> {
+    fn add_tags_to_on_premises_instances(
+        &self,
+        input: AddTagsToOnPremisesInstancesInput,
+    ) -> RusotoFuture<(), AddTagsToOnPremisesInstancesError> {
+        (**self).add_tags_to_on_premises_instances(input)
+    }
+    fn batch_get_application_revisions(
+        &self,
+        input: BatchGetApplicationRevisionsInput,
+    ) -> RusotoFuture<BatchGetApplicationRevisionsOutput, BatchGetApplicationRevisionsError> {
+        (**self).batch_get_application_revisions(input)
+    }
+    fn batch_get_applications(
+        &self,
+        input: BatchGetApplicationsInput,
+    ) -> RusotoFuture<BatchGetApplicationsOutput, BatchGetApplicationsError> {
+        (**self).batch_get_applications(input)
+    }
+    fn batch_get_deployment_groups(
+        &self,
+        input: BatchGetDeploymentGroupsInput,
+    ) -> RusotoFuture<BatchGetDeploymentGroupsOutput, BatchGetDeploymentGroupsError> {
+        (**self).batch_get_deployment_groups(input)
+    }
+    fn batch_get_deployment_instances(
+        &self,
+        input: BatchGetDeploymentInstancesInput,
+    ) -> RusotoFuture<BatchGetDeploymentInstancesOutput, BatchGetDeploymentInstancesError> {
+        (**self).batch_get_deployment_instances(input)
+    }
+    fn batch_get_deployment_targets(
+        &self,
+        input: BatchGetDeploymentTargetsInput,
+    ) -> RusotoFuture<BatchGetDeploymentTargetsOutput, BatchGetDeploymentTargetsError> {
+        (**self).batch_get_deployment_targets(input)
+    }
+    fn batch_get_deployments(
+        &self,
+        input: BatchGetDeploymentsInput,
+    ) -> RusotoFuture<BatchGetDeploymentsOutput, BatchGetDeploymentsError> {
+        (**self).batch_get_deployments(input)
+    }
+    fn batch_get_on_premises_instances(
+        &self,
+        input: BatchGetOnPremisesInstancesInput,
+    ) -> RusotoFuture<BatchGetOnPremisesInstancesOutput, BatchGetOnPremisesInstancesError> {
+        (**self).batch_get_on_premises_instances(input)
+    }
+    fn continue_deployment(
+        &self,
+        input: ContinueDeploymentInput,
+    ) -> RusotoFuture<(), ContinueDeploymentError> {
+        (**self).continue_deployment(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationInput,
+    ) -> RusotoFuture<CreateApplicationOutput, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentInput,
+    ) -> RusotoFuture<CreateDeploymentOutput, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_deployment_config(
+        &self,
+        input: CreateDeploymentConfigInput,
+    ) -> RusotoFuture<CreateDeploymentConfigOutput, CreateDeploymentConfigError> {
+        (**self).create_deployment_config(input)
+    }
+    fn create_deployment_group(
+        &self,
+        input: CreateDeploymentGroupInput,
+    ) -> RusotoFuture<CreateDeploymentGroupOutput, CreateDeploymentGroupError> {
+        (**self).create_deployment_group(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationInput,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_deployment_config(
+        &self,
+        input: DeleteDeploymentConfigInput,
+    ) -> RusotoFuture<(), DeleteDeploymentConfigError> {
+        (**self).delete_deployment_config(input)
+    }
+    fn delete_deployment_group(
+        &self,
+        input: DeleteDeploymentGroupInput,
+    ) -> RusotoFuture<DeleteDeploymentGroupOutput, DeleteDeploymentGroupError> {
+        (**self).delete_deployment_group(input)
+    }
+    fn delete_git_hub_account_token(
+        &self,
+        input: DeleteGitHubAccountTokenInput,
+    ) -> RusotoFuture<DeleteGitHubAccountTokenOutput, DeleteGitHubAccountTokenError> {
+        (**self).delete_git_hub_account_token(input)
+    }
+    fn deregister_on_premises_instance(
+        &self,
+        input: DeregisterOnPremisesInstanceInput,
+    ) -> RusotoFuture<(), DeregisterOnPremisesInstanceError> {
+        (**self).deregister_on_premises_instance(input)
+    }
+    fn get_application(
+        &self,
+        input: GetApplicationInput,
+    ) -> RusotoFuture<GetApplicationOutput, GetApplicationError> {
+        (**self).get_application(input)
+    }
+    fn get_application_revision(
+        &self,
+        input: GetApplicationRevisionInput,
+    ) -> RusotoFuture<GetApplicationRevisionOutput, GetApplicationRevisionError> {
+        (**self).get_application_revision(input)
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentInput,
+    ) -> RusotoFuture<GetDeploymentOutput, GetDeploymentError> {
+        (**self).get_deployment(input)
+    }
+    fn get_deployment_config(
+        &self,
+        input: GetDeploymentConfigInput,
+    ) -> RusotoFuture<GetDeploymentConfigOutput, GetDeploymentConfigError> {
+        (**self).get_deployment_config(input)
+    }
+    fn get_deployment_group(
+        &self,
+        input: GetDeploymentGroupInput,
+    ) -> RusotoFuture<GetDeploymentGroupOutput, GetDeploymentGroupError> {
+        (**self).get_deployment_group(input)
+    }
+    fn get_deployment_instance(
+        &self,
+        input: GetDeploymentInstanceInput,
+    ) -> RusotoFuture<GetDeploymentInstanceOutput, GetDeploymentInstanceError> {
+        (**self).get_deployment_instance(input)
+    }
+    fn get_deployment_target(
+        &self,
+        input: GetDeploymentTargetInput,
+    ) -> RusotoFuture<GetDeploymentTargetOutput, GetDeploymentTargetError> {
+        (**self).get_deployment_target(input)
+    }
+    fn get_on_premises_instance(
+        &self,
+        input: GetOnPremisesInstanceInput,
+    ) -> RusotoFuture<GetOnPremisesInstanceOutput, GetOnPremisesInstanceError> {
+        (**self).get_on_premises_instance(input)
+    }
+    fn list_application_revisions(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> RusotoFuture<ListApplicationRevisionsOutput, ListApplicationRevisionsError> {
+        (**self).list_application_revisions(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsInput,
+    ) -> RusotoFuture<ListApplicationsOutput, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn list_deployment_configs(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> RusotoFuture<ListDeploymentConfigsOutput, ListDeploymentConfigsError> {
+        (**self).list_deployment_configs(input)
+    }
+    fn list_deployment_groups(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> RusotoFuture<ListDeploymentGroupsOutput, ListDeploymentGroupsError> {
+        (**self).list_deployment_groups(input)
+    }
+    fn list_deployment_instances(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> RusotoFuture<ListDeploymentInstancesOutput, ListDeploymentInstancesError> {
+        (**self).list_deployment_instances(input)
+    }
+    fn list_deployment_targets(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> RusotoFuture<ListDeploymentTargetsOutput, ListDeploymentTargetsError> {
+        (**self).list_deployment_targets(input)
+    }
+    fn list_deployments(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> RusotoFuture<ListDeploymentsOutput, ListDeploymentsError> {
+        (**self).list_deployments(input)
+    }
+    fn list_git_hub_account_token_names(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> RusotoFuture<ListGitHubAccountTokenNamesOutput, ListGitHubAccountTokenNamesError> {
+        (**self).list_git_hub_account_token_names(input)
+    }
+    fn list_on_premises_instances(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> RusotoFuture<ListOnPremisesInstancesOutput, ListOnPremisesInstancesError> {
+        (**self).list_on_premises_instances(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_lifecycle_event_hook_execution_status(
+        &self,
+        input: PutLifecycleEventHookExecutionStatusInput,
+    ) -> RusotoFuture<
+        PutLifecycleEventHookExecutionStatusOutput,
+        PutLifecycleEventHookExecutionStatusError,
+    > {
+        (**self).put_lifecycle_event_hook_execution_status(input)
+    }
+    fn register_application_revision(
+        &self,
+        input: RegisterApplicationRevisionInput,
+    ) -> RusotoFuture<(), RegisterApplicationRevisionError> {
+        (**self).register_application_revision(input)
+    }
+    fn register_on_premises_instance(
+        &self,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> RusotoFuture<(), RegisterOnPremisesInstanceError> {
+        (**self).register_on_premises_instance(input)
+    }
+    fn remove_tags_from_on_premises_instances(
+        &self,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> RusotoFuture<(), RemoveTagsFromOnPremisesInstancesError> {
+        (**self).remove_tags_from_on_premises_instances(input)
+    }
+    fn skip_wait_time_for_instance_termination(
+        &self,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> RusotoFuture<(), SkipWaitTimeForInstanceTerminationError> {
+        (**self).skip_wait_time_for_instance_termination(input)
+    }
+    fn stop_deployment(
+        &self,
+        input: StopDeploymentInput,
+    ) -> RusotoFuture<StopDeploymentOutput, StopDeploymentError> {
+        (**self).stop_deployment(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationInput,
+    ) -> RusotoFuture<(), UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+    fn update_deployment_group(
+        &self,
+        input: UpdateDeploymentGroupInput,
+    ) -> RusotoFuture<UpdateDeploymentGroupOutput, UpdateDeploymentGroupError> {
+        (**self).update_deployment_group(input)
+    }
+}
+impl<T: CodeDeploy + ?Sized> CodeDeploy for ::std::sync::Arc<T> {
+    fn add_tags_to_on_premises_instances(
+        &self,
+        input: AddTagsToOnPremisesInstancesInput,
+    ) -> RusotoFuture<(), AddTagsToOnPremisesInstancesError> {
+        (**self).add_tags_to_on_premises_instances(input)
+    }
+    fn batch_get_application_revisions(
+        &self,
+        input: BatchGetApplicationRevisionsInput,
+    ) -> RusotoFuture<BatchGetApplicationRevisionsOutput, BatchGetApplicationRevisionsError> {
+        (**self).batch_get_application_revisions(input)
+    }
+    fn batch_get_applications(
+        &self,
+        input: BatchGetApplicationsInput,
+    ) -> RusotoFuture<BatchGetApplicationsOutput, BatchGetApplicationsError> {
+        (**self).batch_get_applications(input)
+    }
+    fn batch_get_deployment_groups(
+        &self,
+        input: BatchGetDeploymentGroupsInput,
+    ) -> RusotoFuture<BatchGetDeploymentGroupsOutput, BatchGetDeploymentGroupsError> {
+        (**self).batch_get_deployment_groups(input)
+    }
+    fn batch_get_deployment_instances(
+        &self,
+        input: BatchGetDeploymentInstancesInput,
+    ) -> RusotoFuture<BatchGetDeploymentInstancesOutput, BatchGetDeploymentInstancesError> {
+        (**self).batch_get_deployment_instances(input)
+    }
+    fn batch_get_deployment_targets(
+        &self,
+        input: BatchGetDeploymentTargetsInput,
+    ) -> RusotoFuture<BatchGetDeploymentTargetsOutput, BatchGetDeploymentTargetsError> {
+        (**self).batch_get_deployment_targets(input)
+    }
+    fn batch_get_deployments(
+        &self,
+        input: BatchGetDeploymentsInput,
+    ) -> RusotoFuture<BatchGetDeploymentsOutput, BatchGetDeploymentsError> {
+        (**self).batch_get_deployments(input)
+    }
+    fn batch_get_on_premises_instances(
+        &self,
+        input: BatchGetOnPremisesInstancesInput,
+    ) -> RusotoFuture<BatchGetOnPremisesInstancesOutput, BatchGetOnPremisesInstancesError> {
+        (**self).batch_get_on_premises_instances(input)
+    }
+    fn continue_deployment(
+        &self,
+        input: ContinueDeploymentInput,
+    ) -> RusotoFuture<(), ContinueDeploymentError> {
+        (**self).continue_deployment(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationInput,
+    ) -> RusotoFuture<CreateApplicationOutput, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentInput,
+    ) -> RusotoFuture<CreateDeploymentOutput, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_deployment_config(
+        &self,
+        input: CreateDeploymentConfigInput,
+    ) -> RusotoFuture<CreateDeploymentConfigOutput, CreateDeploymentConfigError> {
+        (**self).create_deployment_config(input)
+    }
+    fn create_deployment_group(
+        &self,
+        input: CreateDeploymentGroupInput,
+    ) -> RusotoFuture<CreateDeploymentGroupOutput, CreateDeploymentGroupError> {
+        (**self).create_deployment_group(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationInput,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_deployment_config(
+        &self,
+        input: DeleteDeploymentConfigInput,
+    ) -> RusotoFuture<(), DeleteDeploymentConfigError> {
+        (**self).delete_deployment_config(input)
+    }
+    fn delete_deployment_group(
+        &self,
+        input: DeleteDeploymentGroupInput,
+    ) -> RusotoFuture<DeleteDeploymentGroupOutput, DeleteDeploymentGroupError> {
+        (**self).delete_deployment_group(input)
+    }
+    fn delete_git_hub_account_token(
+        &self,
+        input: DeleteGitHubAccountTokenInput,
+    ) -> RusotoFuture<DeleteGitHubAccountTokenOutput, DeleteGitHubAccountTokenError> {
+        (**self).delete_git_hub_account_token(input)
+    }
+    fn deregister_on_premises_instance(
+        &self,
+        input: DeregisterOnPremisesInstanceInput,
+    ) -> RusotoFuture<(), DeregisterOnPremisesInstanceError> {
+        (**self).deregister_on_premises_instance(input)
+    }
+    fn get_application(
+        &self,
+        input: GetApplicationInput,
+    ) -> RusotoFuture<GetApplicationOutput, GetApplicationError> {
+        (**self).get_application(input)
+    }
+    fn get_application_revision(
+        &self,
+        input: GetApplicationRevisionInput,
+    ) -> RusotoFuture<GetApplicationRevisionOutput, GetApplicationRevisionError> {
+        (**self).get_application_revision(input)
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentInput,
+    ) -> RusotoFuture<GetDeploymentOutput, GetDeploymentError> {
+        (**self).get_deployment(input)
+    }
+    fn get_deployment_config(
+        &self,
+        input: GetDeploymentConfigInput,
+    ) -> RusotoFuture<GetDeploymentConfigOutput, GetDeploymentConfigError> {
+        (**self).get_deployment_config(input)
+    }
+    fn get_deployment_group(
+        &self,
+        input: GetDeploymentGroupInput,
+    ) -> RusotoFuture<GetDeploymentGroupOutput, GetDeploymentGroupError> {
+        (**self).get_deployment_group(input)
+    }
+    fn get_deployment_instance(
+        &self,
+        input: GetDeploymentInstanceInput,
+    ) -> RusotoFuture<GetDeploymentInstanceOutput, GetDeploymentInstanceError> {
+        (**self).get_deployment_instance(input)
+    }
+    fn get_deployment_target(
+        &self,
+        input: GetDeploymentTargetInput,
+    ) -> RusotoFuture<GetDeploymentTargetOutput, GetDeploymentTargetError> {
+        (**self).get_deployment_target(input)
+    }
+    fn get_on_premises_instance(
+        &self,
+        input: GetOnPremisesInstanceInput,
+    ) -> RusotoFuture<GetOnPremisesInstanceOutput, GetOnPremisesInstanceError> {
+        (**self).get_on_premises_instance(input)
+    }
+    fn list_application_revisions(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> RusotoFuture<ListApplicationRevisionsOutput, ListApplicationRevisionsError> {
+        (**self).list_application_revisions(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsInput,
+    ) -> RusotoFuture<ListApplicationsOutput, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn list_deployment_configs(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> RusotoFuture<ListDeploymentConfigsOutput, ListDeploymentConfigsError> {
+        (**self).list_deployment_configs(input)
+    }
+    fn list_deployment_groups(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> RusotoFuture<ListDeploymentGroupsOutput, ListDeploymentGroupsError> {
+        (**self).list_deployment_groups(input)
+    }
+    fn list_deployment_instances(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> RusotoFuture<ListDeploymentInstancesOutput, ListDeploymentInstancesError> {
+        (**self).list_deployment_instances(input)
+    }
+    fn list_deployment_targets(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> RusotoFuture<ListDeploymentTargetsOutput, ListDeploymentTargetsError> {
+        (**self).list_deployment_targets(input)
+    }
+    fn list_deployments(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> RusotoFuture<ListDeploymentsOutput, ListDeploymentsError> {
+        (**self).list_deployments(input)
+    }
+    fn list_git_hub_account_token_names(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> RusotoFuture<ListGitHubAccountTokenNamesOutput, ListGitHubAccountTokenNamesError> {
+        (**self).list_git_hub_account_token_names(input)
+    }
+    fn list_on_premises_instances(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> RusotoFuture<ListOnPremisesInstancesOutput, ListOnPremisesInstancesError> {
+        (**self).list_on_premises_instances(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_lifecycle_event_hook_execution_status(
+        &self,
+        input: PutLifecycleEventHookExecutionStatusInput,
+    ) -> RusotoFuture<
+        PutLifecycleEventHookExecutionStatusOutput,
+        PutLifecycleEventHookExecutionStatusError,
+    > {
+        (**self).put_lifecycle_event_hook_execution_status(input)
+    }
+    fn register_application_revision(
+        &self,
+        input: RegisterApplicationRevisionInput,
+    ) -> RusotoFuture<(), RegisterApplicationRevisionError> {
+        (**self).register_application_revision(input)
+    }
+    fn register_on_premises_instance(
+        &self,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> RusotoFuture<(), RegisterOnPremisesInstanceError> {
+        (**self).register_on_premises_instance(input)
+    }
+    fn remove_tags_from_on_premises_instances(
+        &self,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> RusotoFuture<(), RemoveTagsFromOnPremisesInstancesError> {
+        (**self).remove_tags_from_on_premises_instances(input)
+    }
+    fn skip_wait_time_for_instance_termination(
+        &self,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> RusotoFuture<(), SkipWaitTimeForInstanceTerminationError> {
+        (**self).skip_wait_time_for_instance_termination(input)
+    }
+    fn stop_deployment(
+        &self,
+        input: StopDeploymentInput,
+    ) -> RusotoFuture<StopDeploymentOutput, StopDeploymentError> {
+        (**self).stop_deployment(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationInput,
+    ) -> RusotoFuture<(), UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+    fn update_deployment_group(
+        &self,
+        input: UpdateDeploymentGroupInput,
+    ) -> RusotoFuture<UpdateDeploymentGroupOutput, UpdateDeploymentGroupError> {
+        (**self).update_deployment_group(input)
+    }
+}
+/// A client for the CodeDeploy API.
+#[derive(Clone)]
+pub struct CodeDeployClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CodeDeployClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeDeployClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeDeployClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeDeployClient {
+        CodeDeployClient { client, region }
+    }
+}
+
+impl CodeDeploy for CodeDeployClient {
+    /// <p>Adds tags to on-premises instances.</p>
+    fn add_tags_to_on_premises_instances(
+        &self,
+        input: AddTagsToOnPremisesInstancesInput,
+    ) -> RusotoFuture<(), AddTagsToOnPremisesInstancesError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("AddTagsToOnPremisesInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.AddTagsToOnPremisesInstances",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AddTagsToOnPremisesInstancesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about one or more application revisions. The maximum number of application revisions that can be returned is 25.</p>
+    fn batch_get_application_revisions(
+        &self,
+        input: BatchGetApplicationRevisionsInput,
+    ) -> RusotoFuture<BatchGetApplicationRevisionsOutput, BatchGetApplicationRevisionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetApplicationRevisions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.BatchGetApplicationRevisions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetApplicationRevisionsOutput, _>(
+                    response,
+                    "BatchGetApplicationRevisions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchGetApplicationRevisionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about one or more applications. The maximum number of applications that can be returned is 25.</p>
+    fn batch_get_applications(
+        &self,
+        input: BatchGetApplicationsInput,
+    ) -> RusotoFuture<BatchGetApplicationsOutput, BatchGetApplicationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetApplications");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetApplications");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetApplicationsOutput, _>(
+                    response,
+                    "BatchGetApplications",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchGetApplicationsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about one or more deployment groups.</p>
+    fn batch_get_deployment_groups(
+        &self,
+        input: BatchGetDeploymentGroupsInput,
+    ) -> RusotoFuture<BatchGetDeploymentGroupsOutput, BatchGetDeploymentGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeploymentGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.BatchGetDeploymentGroups",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetDeploymentGroupsOutput, _>(
+                    response,
+                    "BatchGetDeploymentGroups",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchGetDeploymentGroupsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><note> <p> This method works, but is deprecated. Use <code>BatchGetDeploymentTargets</code> instead. </p> </note> <p> Returns an array of one or more instances associated with a deployment. This method works with EC2/On-premises and AWS Lambda compute platforms. The newer <code>BatchGetDeploymentTargets</code> works with all compute platforms. The maximum number of instances that can be returned is 25.</p></p>
+    fn batch_get_deployment_instances(
+        &self,
+        input: BatchGetDeploymentInstancesInput,
+    ) -> RusotoFuture<BatchGetDeploymentInstancesOutput, BatchGetDeploymentInstancesError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeploymentInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.BatchGetDeploymentInstances",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetDeploymentInstancesOutput, _>(
+                    response,
+                    "BatchGetDeploymentInstances",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchGetDeploymentInstancesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p> Returns an array of one or more targets associated with a deployment. This method works with all compute types and should be used instead of the deprecated <code>BatchGetDeploymentInstances</code>. The maximum number of targets that can be returned is 25.</p> <p> The type of targets returned depends on the deployment&#39;s compute platform: </p> <ul> <li> <p> <b>EC2/On-premises</b>: Information about EC2 instance targets. </p> </li> <li> <p> <b>AWS Lambda</b>: Information about Lambda functions targets. </p> </li> <li> <p> <b>Amazon ECS</b>: Information about Amazon ECS service targets. </p> </li> </ul></p>
+    fn batch_get_deployment_targets(
+        &self,
+        input: BatchGetDeploymentTargetsInput,
+    ) -> RusotoFuture<BatchGetDeploymentTargetsOutput, BatchGetDeploymentTargetsError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeploymentTargets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.BatchGetDeploymentTargets",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetDeploymentTargetsOutput, _>(
+                    response,
+                    "BatchGetDeploymentTargets",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchGetDeploymentTargetsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about one or more deployments. The maximum number of deployments that can be returned is 25.</p>
+    fn batch_get_deployments(
+        &self,
+        input: BatchGetDeploymentsInput,
+    ) -> RusotoFuture<BatchGetDeploymentsOutput, BatchGetDeploymentsError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeployments");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetDeployments");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetDeploymentsOutput, _>(
+                    response,
+                    "BatchGetDeployments",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchGetDeploymentsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about one or more on-premises instances. The maximum number of on-premises instances that can be returned is 25.</p>
+    fn batch_get_on_premises_instances(
+        &self,
+        input: BatchGetOnPremisesInstancesInput,
+    ) -> RusotoFuture<BatchGetOnPremisesInstancesOutput, BatchGetOnPremisesInstancesError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetOnPremisesInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.BatchGetOnPremisesInstances",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetOnPremisesInstancesOutput, _>(
+                    response,
+                    "BatchGetOnPremisesInstances",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchGetOnPremisesInstancesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>For a blue/green deployment, starts the process of rerouting traffic from instances in the original environment to instances in the replacement environment without waiting for a specified wait time to elapse. (Traffic rerouting, which is achieved by registering instances in the replacement environment with the load balancer, can start as soon as all instances have a status of Ready.) </p>
+    fn continue_deployment(
+        &self,
+        input: ContinueDeploymentInput,
+    ) -> RusotoFuture<(), ContinueDeploymentError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ContinueDeployment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.ContinueDeployment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ContinueDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an application.</p>
+    fn create_application(
+        &self,
+        input: CreateApplicationInput,
+    ) -> RusotoFuture<CreateApplicationOutput, CreateApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateApplication");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.CreateApplication");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateApplicationOutput, _>(
+                    response,
+                    "CreateApplication",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateApplicationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deploys an application revision through the specified deployment group.</p>
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentInput,
+    ) -> RusotoFuture<CreateDeploymentOutput, CreateDeploymentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateDeployment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeployment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDeploymentOutput, _>(
+                    response,
+                    "CreateDeployment",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Creates a deployment configuration. </p>
+    fn create_deployment_config(
+        &self,
+        input: CreateDeploymentConfigInput,
+    ) -> RusotoFuture<CreateDeploymentConfigOutput, CreateDeploymentConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateDeploymentConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDeploymentConfigOutput, _>(
+                    response,
+                    "CreateDeploymentConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateDeploymentConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a deployment group to which application revisions are deployed.</p>
+    fn create_deployment_group(
+        &self,
+        input: CreateDeploymentGroupInput,
+    ) -> RusotoFuture<CreateDeploymentGroupOutput, CreateDeploymentGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateDeploymentGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDeploymentGroupOutput, _>(
+                    response,
+                    "CreateDeploymentGroup",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateDeploymentGroupError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an application.</p>
+    fn delete_application(
+        &self,
+        input: DeleteApplicationInput,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteApplication");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteApplication");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteApplicationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a deployment configuration.</p> <note> <p>A deployment configuration cannot be deleted if it is currently in use. Predefined configurations cannot be deleted.</p> </note></p>
+    fn delete_deployment_config(
+        &self,
+        input: DeleteDeploymentConfigInput,
+    ) -> RusotoFuture<(), DeleteDeploymentConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteDeploymentConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteDeploymentConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a deployment group.</p>
+    fn delete_deployment_group(
+        &self,
+        input: DeleteDeploymentGroupInput,
+    ) -> RusotoFuture<DeleteDeploymentGroupOutput, DeleteDeploymentGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteDeploymentGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDeploymentGroupOutput, _>(
+                    response,
+                    "DeleteDeploymentGroup",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteDeploymentGroupError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a GitHub account connection.</p>
+    fn delete_git_hub_account_token(
+        &self,
+        input: DeleteGitHubAccountTokenInput,
+    ) -> RusotoFuture<DeleteGitHubAccountTokenOutput, DeleteGitHubAccountTokenError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteGitHubAccountToken");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.DeleteGitHubAccountToken",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteGitHubAccountTokenOutput, _>(
+                    response,
+                    "DeleteGitHubAccountToken",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteGitHubAccountTokenError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deregisters an on-premises instance.</p>
+    fn deregister_on_premises_instance(
+        &self,
+        input: DeregisterOnPremisesInstanceInput,
+    ) -> RusotoFuture<(), DeregisterOnPremisesInstanceError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeregisterOnPremisesInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.DeregisterOnPremisesInstance",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterOnPremisesInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about an application.</p>
+    fn get_application(
+        &self,
+        input: GetApplicationInput,
+    ) -> RusotoFuture<GetApplicationOutput, GetApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetApplication");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplication");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetApplicationOutput, _>(
+                    response,
+                    "GetApplication",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetApplicationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about an application revision.</p>
+    fn get_application_revision(
+        &self,
+        input: GetApplicationRevisionInput,
+    ) -> RusotoFuture<GetApplicationRevisionOutput, GetApplicationRevisionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetApplicationRevision");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplicationRevision");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetApplicationRevisionOutput, _>(
+                    response,
+                    "GetApplicationRevision",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetApplicationRevisionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Gets information about a deployment.</p> <note> <p> The <code>content</code> property of the <code>appSpecContent</code> object in the returned revision is always null. Use <code>GetApplicationRevision</code> and the <code>sha256</code> property of the returned <code>appSpecContent</code> object to get the content of the deployment’s AppSpec file. </p> </note></p>
+    fn get_deployment(
+        &self,
+        input: GetDeploymentInput,
+    ) -> RusotoFuture<GetDeploymentOutput, GetDeploymentError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeployment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeployment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDeploymentOutput, _>(
+                    response,
+                    "GetDeployment",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a deployment configuration.</p>
+    fn get_deployment_config(
+        &self,
+        input: GetDeploymentConfigInput,
+    ) -> RusotoFuture<GetDeploymentConfigOutput, GetDeploymentConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDeploymentConfigOutput, _>(
+                    response,
+                    "GetDeploymentConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetDeploymentConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a deployment group.</p>
+    fn get_deployment_group(
+        &self,
+        input: GetDeploymentGroupInput,
+    ) -> RusotoFuture<GetDeploymentGroupOutput, GetDeploymentGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDeploymentGroupOutput, _>(
+                    response,
+                    "GetDeploymentGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDeploymentGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about an instance as part of a deployment.</p>
+    fn get_deployment_instance(
+        &self,
+        input: GetDeploymentInstanceInput,
+    ) -> RusotoFuture<GetDeploymentInstanceOutput, GetDeploymentInstanceError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDeploymentInstanceOutput, _>(
+                    response,
+                    "GetDeploymentInstance",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetDeploymentInstanceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> Returns information about a deployment target. </p>
+    fn get_deployment_target(
+        &self,
+        input: GetDeploymentTargetInput,
+    ) -> RusotoFuture<GetDeploymentTargetOutput, GetDeploymentTargetError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentTarget");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentTarget");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDeploymentTargetOutput, _>(
+                    response,
+                    "GetDeploymentTarget",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetDeploymentTargetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> Gets information about an on-premises instance. </p>
+    fn get_on_premises_instance(
+        &self,
+        input: GetOnPremisesInstanceInput,
+    ) -> RusotoFuture<GetOnPremisesInstanceOutput, GetOnPremisesInstanceError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetOnPremisesInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.GetOnPremisesInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetOnPremisesInstanceOutput, _>(
+                    response,
+                    "GetOnPremisesInstance",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetOnPremisesInstanceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists information about revisions for an application.</p>
+    fn list_application_revisions(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> RusotoFuture<ListApplicationRevisionsOutput, ListApplicationRevisionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListApplicationRevisions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.ListApplicationRevisions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListApplicationRevisionsOutput, _>(
+                    response,
+                    "ListApplicationRevisions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListApplicationRevisionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the applications registered with the IAM user or AWS account.</p>
+    fn list_applications(
+        &self,
+        input: ListApplicationsInput,
+    ) -> RusotoFuture<ListApplicationsOutput, ListApplicationsError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListApplications");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.ListApplications");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListApplicationsOutput, _>(
+                    response,
+                    "ListApplications",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListApplicationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the deployment configurations with the IAM user or AWS account.</p>
+    fn list_deployment_configs(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> RusotoFuture<ListDeploymentConfigsOutput, ListDeploymentConfigsError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentConfigs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentConfigs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDeploymentConfigsOutput, _>(
+                    response,
+                    "ListDeploymentConfigs",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListDeploymentConfigsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the deployment groups for an application registered with the IAM user or AWS account.</p>
+    fn list_deployment_groups(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> RusotoFuture<ListDeploymentGroupsOutput, ListDeploymentGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDeploymentGroupsOutput, _>(
+                    response,
+                    "ListDeploymentGroups",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListDeploymentGroupsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><note> <p> The newer BatchGetDeploymentTargets should be used instead because it works with all compute types. <code>ListDeploymentInstances</code> throws an exception if it is used with a compute platform other than EC2/On-premises or AWS Lambda. </p> </note> <p> Lists the instance for a deployment associated with the IAM user or AWS account. </p></p>
+    fn list_deployment_instances(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> RusotoFuture<ListDeploymentInstancesOutput, ListDeploymentInstancesError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.ListDeploymentInstances",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDeploymentInstancesOutput, _>(
+                    response,
+                    "ListDeploymentInstances",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListDeploymentInstancesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> Returns an array of target IDs that are associated a deployment. </p>
+    fn list_deployment_targets(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> RusotoFuture<ListDeploymentTargetsOutput, ListDeploymentTargetsError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentTargets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentTargets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDeploymentTargetsOutput, _>(
+                    response,
+                    "ListDeploymentTargets",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListDeploymentTargetsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the deployments in a deployment group for an application registered with the IAM user or AWS account.</p>
+    fn list_deployments(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> RusotoFuture<ListDeploymentsOutput, ListDeploymentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeployments");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeployments");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDeploymentsOutput, _>(
+                    response,
+                    "ListDeployments",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDeploymentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the names of stored connections to GitHub accounts.</p>
+    fn list_git_hub_account_token_names(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> RusotoFuture<ListGitHubAccountTokenNamesOutput, ListGitHubAccountTokenNamesError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListGitHubAccountTokenNames");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.ListGitHubAccountTokenNames",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGitHubAccountTokenNamesOutput, _>(
+                    response,
+                    "ListGitHubAccountTokenNames",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListGitHubAccountTokenNamesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets a list of names for one or more on-premises instances.</p> <p>Unless otherwise specified, both registered and deregistered on-premises instance names are listed. To list only registered or deregistered on-premises instance names, use the registration status parameter.</p>
+    fn list_on_premises_instances(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> RusotoFuture<ListOnPremisesInstancesOutput, ListOnPremisesInstancesError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListOnPremisesInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.ListOnPremisesInstances",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListOnPremisesInstancesOutput, _>(
+                    response,
+                    "ListOnPremisesInstances",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListOnPremisesInstancesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> Returns a list of tags for the resource identified by a specified ARN. Tags are used to organize and categorize your CodeDeploy resources. </p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceOutput, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> Sets the result of a Lambda validation function. The function validates one or both lifecycle events (<code>BeforeAllowTraffic</code> and <code>AfterAllowTraffic</code>) and returns <code>Succeeded</code> or <code>Failed</code>. </p>
+    fn put_lifecycle_event_hook_execution_status(
+        &self,
+        input: PutLifecycleEventHookExecutionStatusInput,
+    ) -> RusotoFuture<
+        PutLifecycleEventHookExecutionStatusOutput,
+        PutLifecycleEventHookExecutionStatusError,
+    > {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("PutLifecycleEventHookExecutionStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.PutLifecycleEventHookExecutionStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutLifecycleEventHookExecutionStatusOutput, _>(
+                    response,
+                    "PutLifecycleEventHookExecutionStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutLifecycleEventHookExecutionStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Registers with AWS CodeDeploy a revision for the specified application.</p>
+    fn register_application_revision(
+        &self,
+        input: RegisterApplicationRevisionInput,
+    ) -> RusotoFuture<(), RegisterApplicationRevisionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("RegisterApplicationRevision");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.RegisterApplicationRevision",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RegisterApplicationRevisionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Registers an on-premises instance.</p> <note> <p>Only one IAM ARN (an IAM session ARN or IAM user ARN) is supported in the request. You cannot use both.</p> </note></p>
+    fn register_on_premises_instance(
+        &self,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> RusotoFuture<(), RegisterOnPremisesInstanceError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("RegisterOnPremisesInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.RegisterOnPremisesInstance",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RegisterOnPremisesInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes one or more tags from one or more on-premises instances.</p>
+    fn remove_tags_from_on_premises_instances(
+        &self,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> RusotoFuture<(), RemoveTagsFromOnPremisesInstancesError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("RemoveTagsFromOnPremisesInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.RemoveTagsFromOnPremisesInstances",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RemoveTagsFromOnPremisesInstancesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>In a blue/green deployment, overrides any specified wait time and starts terminating instances immediately after the traffic routing is complete.</p>
+    fn skip_wait_time_for_instance_termination(
+        &self,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> RusotoFuture<(), SkipWaitTimeForInstanceTerminationError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("SkipWaitTimeForInstanceTermination");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.SkipWaitTimeForInstanceTermination",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SkipWaitTimeForInstanceTerminationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Attempts to stop an ongoing deployment.</p>
+    fn stop_deployment(
+        &self,
+        input: StopDeploymentInput,
+    ) -> RusotoFuture<StopDeploymentOutput, StopDeploymentError> {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("StopDeployment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.StopDeployment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopDeploymentOutput, _>(
+                    response,
+                    "StopDeployment",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Associates the list of tags in the input <code>Tags</code> parameter with the resource identified by the <code>ResourceArn</code> input parameter. </p>
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceOutput, _>(response, "TagResource")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Disassociates a resource from a list of tags. The resource is identified by the <code>ResourceArn</code> input parameter. The tags are identfied by the list of keys in the <code>TagKeys</code> input parameter. </p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceOutput, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes the name of an application.</p>
+    fn update_application(
+        &self,
+        input: UpdateApplicationInput,
+    ) -> RusotoFuture<(), UpdateApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("UpdateApplication");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateApplication");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateApplicationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes information about a deployment group.</p>
+    fn update_deployment_group(
+        &self,
+        input: UpdateDeploymentGroupInput,
+    ) -> RusotoFuture<UpdateDeploymentGroupOutput, UpdateDeploymentGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("UpdateDeploymentGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateDeploymentGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDeploymentGroupOutput, _>(
+                    response,
+                    "UpdateDeploymentGroup",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateDeploymentGroupError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the CodeDeploy API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCodeDeploy {
+    add_tags_to_on_premises_instances: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToOnPremisesInstancesInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<AddTagsToOnPremisesInstancesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    batch_get_application_revisions: Option<
+        Box<
+            dyn Fn(
+                    BatchGetApplicationRevisionsInput,
+                ) -> ::std::result::Result<
+                    BatchGetApplicationRevisionsOutput,
+                    RusotoError<BatchGetApplicationRevisionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_applications: Option<
+        Box<
+            dyn Fn(
+                    BatchGetApplicationsInput,
+                ) -> ::std::result::Result<
+                    BatchGetApplicationsOutput,
+                    RusotoError<BatchGetApplicationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_deployment_groups: Option<
+        Box<
+            dyn Fn(
+                    BatchGetDeploymentGroupsInput,
+                ) -> ::std::result::Result<
+                    BatchGetDeploymentGroupsOutput,
+                    RusotoError<BatchGetDeploymentGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_deployment_instances: Option<
+        Box<
+            dyn Fn(
+                    BatchGetDeploymentInstancesInput,
+                ) -> ::std::result::Result<
+                    BatchGetDeploymentInstancesOutput,
+                    RusotoError<BatchGetDeploymentInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_deployment_targets: Option<
+        Box<
+            dyn Fn(
+                    BatchGetDeploymentTargetsInput,
+                ) -> ::std::result::Result<
+                    BatchGetDeploymentTargetsOutput,
+                    RusotoError<BatchGetDeploymentTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_deployments: Option<
+        Box<
+            dyn Fn(
+                    BatchGetDeploymentsInput,
+                ) -> ::std::result::Result<
+                    BatchGetDeploymentsOutput,
+                    RusotoError<BatchGetDeploymentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_on_premises_instances: Option<
+        Box<
+            dyn Fn(
+                    BatchGetOnPremisesInstancesInput,
+                ) -> ::std::result::Result<
+                    BatchGetOnPremisesInstancesOutput,
+                    RusotoError<BatchGetOnPremisesInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    continue_deployment: Option<
+        Box<
+            dyn Fn(
+                    ContinueDeploymentInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<ContinueDeploymentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_application: Option<
+        Box<
+            dyn Fn(
+                    CreateApplicationInput,
+                ) -> ::std::result::Result<
+                    CreateApplicationOutput,
+                    RusotoError<CreateApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_deployment: Option<
+        Box<
+            dyn Fn(
+                    CreateDeploymentInput,
+                ) -> ::std::result::Result<
+                    CreateDeploymentOutput,
+                    RusotoError<CreateDeploymentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_deployment_config: Option<
+        Box<
+            dyn Fn(
+                    CreateDeploymentConfigInput,
+                ) -> ::std::result::Result<
+                    CreateDeploymentConfigOutput,
+                    RusotoError<CreateDeploymentConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_deployment_group: Option<
+        Box<
+            dyn Fn(
+                    CreateDeploymentGroupInput,
+                ) -> ::std::result::Result<
+                    CreateDeploymentGroupOutput,
+                    RusotoError<CreateDeploymentGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_application: Option<
+        Box<
+            dyn Fn(
+                    DeleteApplicationInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteApplicationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_deployment_config: Option<
+        Box<
+            dyn Fn(
+                    DeleteDeploymentConfigInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteDeploymentConfigError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_deployment_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteDeploymentGroupInput,
+                ) -> ::std::result::Result<
+                    DeleteDeploymentGroupOutput,
+                    RusotoError<DeleteDeploymentGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_git_hub_account_token: Option<
+        Box<
+            dyn Fn(
+                    DeleteGitHubAccountTokenInput,
+                ) -> ::std::result::Result<
+                    DeleteGitHubAccountTokenOutput,
+                    RusotoError<DeleteGitHubAccountTokenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deregister_on_premises_instance: Option<
+        Box<
+            dyn Fn(
+                    DeregisterOnPremisesInstanceInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeregisterOnPremisesInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_application: Option<
+        Box<
+            dyn Fn(
+                    GetApplicationInput,
+                )
+                    -> ::std::result::Result<GetApplicationOutput, RusotoError<GetApplicationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_application_revision: Option<
+        Box<
+            dyn Fn(
+                    GetApplicationRevisionInput,
+                ) -> ::std::result::Result<
+                    GetApplicationRevisionOutput,
+                    RusotoError<GetApplicationRevisionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_deployment: Option<
+        Box<
+            dyn Fn(
+                    GetDeploymentInput,
+                )
+                    -> ::std::result::Result<GetDeploymentOutput, RusotoError<GetDeploymentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_deployment_config: Option<
+        Box<
+            dyn Fn(
+                    GetDeploymentConfigInput,
+                ) -> ::std::result::Result<
+                    GetDeploymentConfigOutput,
+                    RusotoError<GetDeploymentConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_deployment_group: Option<
+        Box<
+            dyn Fn(
+                    GetDeploymentGroupInput,
+                ) -> ::std::result::Result<
+                    GetDeploymentGroupOutput,
+                    RusotoError<GetDeploymentGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_deployment_instance: Option<
+        Box<
+            dyn Fn(
+                    GetDeploymentInstanceInput,
+                ) -> ::std::result::Result<
+                    GetDeploymentInstanceOutput,
+                    RusotoError<GetDeploymentInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_deployment_target: Option<
+        Box<
+            dyn Fn(
+                    GetDeploymentTargetInput,
+                ) -> ::std::result::Result<
+                    GetDeploymentTargetOutput,
+                    RusotoError<GetDeploymentTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_on_premises_instance: Option<
+        Box<
+            dyn Fn(
+                    GetOnPremisesInstanceInput,
+                ) -> ::std::result::Result<
+                    GetOnPremisesInstanceOutput,
+                    RusotoError<GetOnPremisesInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_application_revisions: Option<
+        Box<
+            dyn Fn(
+                    ListApplicationRevisionsInput,
+                ) -> ::std::result::Result<
+                    ListApplicationRevisionsOutput,
+                    RusotoError<ListApplicationRevisionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_applications: Option<
+        Box<
+            dyn Fn(
+                    ListApplicationsInput,
+                ) -> ::std::result::Result<
+                    ListApplicationsOutput,
+                    RusotoError<ListApplicationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_deployment_configs: Option<
+        Box<
+            dyn Fn(
+                    ListDeploymentConfigsInput,
+                ) -> ::std::result::Result<
+                    ListDeploymentConfigsOutput,
+                    RusotoError<ListDeploymentConfigsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_deployment_groups: Option<
+        Box<
+            dyn Fn(
+                    ListDeploymentGroupsInput,
+                ) -> ::std::result::Result<
+                    ListDeploymentGroupsOutput,
+                    RusotoError<ListDeploymentGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_deployment_instances: Option<
+        Box<
+            dyn Fn(
+                    ListDeploymentInstancesInput,
+                ) -> ::std::result::Result<
+                    ListDeploymentInstancesOutput,
+                    RusotoError<ListDeploymentInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_deployment_targets: Option<
+        Box<
+            dyn Fn(
+                    ListDeploymentTargetsInput,
+                ) -> ::std::result::Result<
+                    ListDeploymentTargetsOutput,
+                    RusotoError<ListDeploymentTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_deployments: Option<
+        Box<
+            dyn Fn(
+                    ListDeploymentsInput,
+                ) -> ::std::result::Result<
+                    ListDeploymentsOutput,
+                    RusotoError<ListDeploymentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_git_hub_account_token_names: Option<
+        Box<
+            dyn Fn(
+                    ListGitHubAccountTokenNamesInput,
+                ) -> ::std::result::Result<
+                    ListGitHubAccountTokenNamesOutput,
+                    RusotoError<ListGitHubAccountTokenNamesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_on_premises_instances: Option<
+        Box<
+            dyn Fn(
+                    ListOnPremisesInstancesInput,
+                ) -> ::std::result::Result<
+                    ListOnPremisesInstancesOutput,
+                    RusotoError<ListOnPremisesInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceOutput,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_lifecycle_event_hook_execution_status: Option<
+        Box<
+            dyn Fn(
+                    PutLifecycleEventHookExecutionStatusInput,
+                ) -> ::std::result::Result<
+                    PutLifecycleEventHookExecutionStatusOutput,
+                    RusotoError<PutLifecycleEventHookExecutionStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_application_revision: Option<
+        Box<
+            dyn Fn(
+                    RegisterApplicationRevisionInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RegisterApplicationRevisionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_on_premises_instance: Option<
+        Box<
+            dyn Fn(
+                    RegisterOnPremisesInstanceInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RegisterOnPremisesInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_on_premises_instances: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromOnPremisesInstancesInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RemoveTagsFromOnPremisesInstancesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    skip_wait_time_for_instance_termination: Option<
+        Box<
+            dyn Fn(
+                    SkipWaitTimeForInstanceTerminationInput,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<SkipWaitTimeForInstanceTerminationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_deployment: Option<
+        Box<
+            dyn Fn(
+                    StopDeploymentInput,
+                )
+                    -> ::std::result::Result<StopDeploymentOutput, RusotoError<StopDeploymentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceInput,
+                )
+                    -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceInput,
+                )
+                    -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_application: Option<
+        Box<
+            dyn Fn(
+                    UpdateApplicationInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateApplicationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_deployment_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateDeploymentGroupInput,
+                ) -> ::std::result::Result<
+                    UpdateDeploymentGroupOutput,
+                    RusotoError<UpdateDeploymentGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCodeDeploy {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCodeDeploy {
+        Default::default()
+    }
+
+    /// Answers `add_tags_to_on_premises_instances` with what `handler` returns for its input.
+    pub fn with_add_tags_to_on_premises_instances<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                AddTagsToOnPremisesInstancesInput,
+            )
+                -> ::std::result::Result<(), RusotoError<AddTagsToOnPremisesInstancesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_on_premises_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_application_revisions` with what `handler` returns for its input.
+    pub fn with_batch_get_application_revisions<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                BatchGetApplicationRevisionsInput,
+            ) -> ::std::result::Result<
+                BatchGetApplicationRevisionsOutput,
+                RusotoError<BatchGetApplicationRevisionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_application_revisions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_applications` with what `handler` returns for its input.
+    pub fn with_batch_get_applications<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                BatchGetApplicationsInput,
+            ) -> ::std::result::Result<
+                BatchGetApplicationsOutput,
+                RusotoError<BatchGetApplicationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_applications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_deployment_groups` with what `handler` returns for its input.
+    pub fn with_batch_get_deployment_groups<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                BatchGetDeploymentGroupsInput,
+            ) -> ::std::result::Result<
+                BatchGetDeploymentGroupsOutput,
+                RusotoError<BatchGetDeploymentGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_deployment_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_deployment_instances` with what `handler` returns for its input.
+    pub fn with_batch_get_deployment_instances<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                BatchGetDeploymentInstancesInput,
+            ) -> ::std::result::Result<
+                BatchGetDeploymentInstancesOutput,
+                RusotoError<BatchGetDeploymentInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_deployment_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_deployment_targets` with what `handler` returns for its input.
+    pub fn with_batch_get_deployment_targets<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                BatchGetDeploymentTargetsInput,
+            ) -> ::std::result::Result<
+                BatchGetDeploymentTargetsOutput,
+                RusotoError<BatchGetDeploymentTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_deployment_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_deployments` with what `handler` returns for its input.
+    pub fn with_batch_get_deployments<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                BatchGetDeploymentsInput,
+            ) -> ::std::result::Result<
+                BatchGetDeploymentsOutput,
+                RusotoError<BatchGetDeploymentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_deployments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_on_premises_instances` with what `handler` returns for its input.
+    pub fn with_batch_get_on_premises_instances<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                BatchGetOnPremisesInstancesInput,
+            ) -> ::std::result::Result<
+                BatchGetOnPremisesInstancesOutput,
+                RusotoError<BatchGetOnPremisesInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_on_premises_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `continue_deployment` with what `handler` returns for its input.
+    pub fn with_continue_deployment<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ContinueDeploymentInput,
+            ) -> ::std::result::Result<(), RusotoError<ContinueDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.continue_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_application` with what `handler` returns for its input.
+    pub fn with_create_application<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                CreateApplicationInput,
+            ) -> ::std::result::Result<
+                CreateApplicationOutput,
+                RusotoError<CreateApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_deployment` with what `handler` returns for its input.
+    pub fn with_create_deployment<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                CreateDeploymentInput,
+            )
+                -> ::std::result::Result<CreateDeploymentOutput, RusotoError<CreateDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_deployment_config` with what `handler` returns for its input.
+    pub fn with_create_deployment_config<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                CreateDeploymentConfigInput,
+            ) -> ::std::result::Result<
+                CreateDeploymentConfigOutput,
+                RusotoError<CreateDeploymentConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_deployment_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_deployment_group` with what `handler` returns for its input.
+    pub fn with_create_deployment_group<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                CreateDeploymentGroupInput,
+            ) -> ::std::result::Result<
+                CreateDeploymentGroupOutput,
+                RusotoError<CreateDeploymentGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_deployment_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_application` with what `handler` returns for its input.
+    pub fn with_delete_application<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                DeleteApplicationInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteApplicationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_deployment_config` with what `handler` returns for its input.
+    pub fn with_delete_deployment_config<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                DeleteDeploymentConfigInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDeploymentConfigError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_deployment_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_deployment_group` with what `handler` returns for its input.
+    pub fn with_delete_deployment_group<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                DeleteDeploymentGroupInput,
+            ) -> ::std::result::Result<
+                DeleteDeploymentGroupOutput,
+                RusotoError<DeleteDeploymentGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_deployment_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_git_hub_account_token` with what `handler` returns for its input.
+    pub fn with_delete_git_hub_account_token<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                DeleteGitHubAccountTokenInput,
+            ) -> ::std::result::Result<
+                DeleteGitHubAccountTokenOutput,
+                RusotoError<DeleteGitHubAccountTokenError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_git_hub_account_token = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_on_premises_instance` with what `handler` returns for its input.
+    pub fn with_deregister_on_premises_instance<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                DeregisterOnPremisesInstanceInput,
+            )
+                -> ::std::result::Result<(), RusotoError<DeregisterOnPremisesInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_on_premises_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_application` with what `handler` returns for its input.
+    pub fn with_get_application<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                GetApplicationInput,
+            )
+                -> ::std::result::Result<GetApplicationOutput, RusotoError<GetApplicationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_application_revision` with what `handler` returns for its input.
+    pub fn with_get_application_revision<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                GetApplicationRevisionInput,
+            ) -> ::std::result::Result<
+                GetApplicationRevisionOutput,
+                RusotoError<GetApplicationRevisionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_application_revision = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployment` with what `handler` returns for its input.
+    pub fn with_get_deployment<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                GetDeploymentInput,
+            )
+                -> ::std::result::Result<GetDeploymentOutput, RusotoError<GetDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployment_config` with what `handler` returns for its input.
+    pub fn with_get_deployment_config<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                GetDeploymentConfigInput,
+            ) -> ::std::result::Result<
+                GetDeploymentConfigOutput,
+                RusotoError<GetDeploymentConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployment_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployment_group` with what `handler` returns for its input.
+    pub fn with_get_deployment_group<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                GetDeploymentGroupInput,
+            ) -> ::std::result::Result<
+                GetDeploymentGroupOutput,
+                RusotoError<GetDeploymentGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployment_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployment_instance` with what `handler` returns for its input.
+    pub fn with_get_deployment_instance<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                GetDeploymentInstanceInput,
+            ) -> ::std::result::Result<
+                GetDeploymentInstanceOutput,
+                RusotoError<GetDeploymentInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployment_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployment_target` with what `handler` returns for its input.
+    pub fn with_get_deployment_target<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                GetDeploymentTargetInput,
+            ) -> ::std::result::Result<
+                GetDeploymentTargetOutput,
+                RusotoError<GetDeploymentTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployment_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_on_premises_instance` with what `handler` returns for its input.
+    pub fn with_get_on_premises_instance<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                GetOnPremisesInstanceInput,
+            ) -> ::std::result::Result<
+                GetOnPremisesInstanceOutput,
+                RusotoError<GetOnPremisesInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_on_premises_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_application_revisions` with what `handler` returns for its input.
+    pub fn with_list_application_revisions<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ListApplicationRevisionsInput,
+            ) -> ::std::result::Result<
+                ListApplicationRevisionsOutput,
+                RusotoError<ListApplicationRevisionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_application_revisions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_applications` with what `handler` returns for its input.
+    pub fn with_list_applications<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ListApplicationsInput,
+            )
+                -> ::std::result::Result<ListApplicationsOutput, RusotoError<ListApplicationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_applications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_deployment_configs` with what `handler` returns for its input.
+    pub fn with_list_deployment_configs<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ListDeploymentConfigsInput,
+            ) -> ::std::result::Result<
+                ListDeploymentConfigsOutput,
+                RusotoError<ListDeploymentConfigsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_deployment_configs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_deployment_groups` with what `handler` returns for its input.
+    pub fn with_list_deployment_groups<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ListDeploymentGroupsInput,
+            ) -> ::std::result::Result<
+                ListDeploymentGroupsOutput,
+                RusotoError<ListDeploymentGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_deployment_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_deployment_instances` with what `handler` returns for its input.
+    pub fn with_list_deployment_instances<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ListDeploymentInstancesInput,
+            ) -> ::std::result::Result<
+                ListDeploymentInstancesOutput,
+                RusotoError<ListDeploymentInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_deployment_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_deployment_targets` with what `handler` returns for its input.
+    pub fn with_list_deployment_targets<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ListDeploymentTargetsInput,
+            ) -> ::std::result::Result<
+                ListDeploymentTargetsOutput,
+                RusotoError<ListDeploymentTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_deployment_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_deployments` with what `handler` returns for its input.
+    pub fn with_list_deployments<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ListDeploymentsInput,
+            )
+                -> ::std::result::Result<ListDeploymentsOutput, RusotoError<ListDeploymentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_deployments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_git_hub_account_token_names` with what `handler` returns for its input.
+    pub fn with_list_git_hub_account_token_names<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ListGitHubAccountTokenNamesInput,
+            ) -> ::std::result::Result<
+                ListGitHubAccountTokenNamesOutput,
+                RusotoError<ListGitHubAccountTokenNamesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_git_hub_account_token_names = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_on_premises_instances` with what `handler` returns for its input.
+    pub fn with_list_on_premises_instances<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ListOnPremisesInstancesInput,
+            ) -> ::std::result::Result<
+                ListOnPremisesInstancesOutput,
+                RusotoError<ListOnPremisesInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_on_premises_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceOutput,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_lifecycle_event_hook_execution_status` with what `handler` returns for its input.
+    pub fn with_put_lifecycle_event_hook_execution_status<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                PutLifecycleEventHookExecutionStatusInput,
+            ) -> ::std::result::Result<
+                PutLifecycleEventHookExecutionStatusOutput,
+                RusotoError<PutLifecycleEventHookExecutionStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_lifecycle_event_hook_execution_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_application_revision` with what `handler` returns for its input.
+    pub fn with_register_application_revision<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                RegisterApplicationRevisionInput,
+            )
+                -> ::std::result::Result<(), RusotoError<RegisterApplicationRevisionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_application_revision = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_on_premises_instance` with what `handler` returns for its input.
+    pub fn with_register_on_premises_instance<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                RegisterOnPremisesInstanceInput,
+            )
+                -> ::std::result::Result<(), RusotoError<RegisterOnPremisesInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_on_premises_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_on_premises_instances` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_on_premises_instances<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                RemoveTagsFromOnPremisesInstancesInput,
+            )
+                -> ::std::result::Result<(), RusotoError<RemoveTagsFromOnPremisesInstancesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_on_premises_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `skip_wait_time_for_instance_termination` with what `handler` returns for its input.
+    pub fn with_skip_wait_time_for_instance_termination<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                SkipWaitTimeForInstanceTerminationInput,
+            )
+                -> ::std::result::Result<(), RusotoError<SkipWaitTimeForInstanceTerminationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.skip_wait_time_for_instance_termination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_deployment` with what `handler` returns for its input.
+    pub fn with_stop_deployment<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                StopDeploymentInput,
+            )
+                -> ::std::result::Result<StopDeploymentOutput, RusotoError<StopDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                TagResourceInput,
+            )
+                -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                UntagResourceInput,
+            )
+                -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_application` with what `handler` returns for its input.
+    pub fn with_update_application<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                UpdateApplicationInput,
+            ) -> ::std::result::Result<(), RusotoError<UpdateApplicationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_deployment_group` with what `handler` returns for its input.
+    pub fn with_update_deployment_group<F>(mut self, handler: F) -> MockCodeDeploy
+    where
+        F: Fn(
+                UpdateDeploymentGroupInput,
+            ) -> ::std::result::Result<
+                UpdateDeploymentGroupOutput,
+                RusotoError<UpdateDeploymentGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_deployment_group = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CodeDeploy for MockCodeDeploy {
+    fn add_tags_to_on_premises_instances(
+        &self,
+        input: AddTagsToOnPremisesInstancesInput,
+    ) -> RusotoFuture<(), AddTagsToOnPremisesInstancesError> {
+        match self.add_tags_to_on_premises_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for add_tags_to_on_premises_instances"),
+        }
+    }
+    fn batch_get_application_revisions(
+        &self,
+        input: BatchGetApplicationRevisionsInput,
+    ) -> RusotoFuture<BatchGetApplicationRevisionsOutput, BatchGetApplicationRevisionsError> {
+        match self.batch_get_application_revisions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for batch_get_application_revisions"),
+        }
+    }
+    fn batch_get_applications(
+        &self,
+        input: BatchGetApplicationsInput,
+    ) -> RusotoFuture<BatchGetApplicationsOutput, BatchGetApplicationsError> {
+        match self.batch_get_applications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for batch_get_applications"),
+        }
+    }
+    fn batch_get_deployment_groups(
+        &self,
+        input: BatchGetDeploymentGroupsInput,
+    ) -> RusotoFuture<BatchGetDeploymentGroupsOutput, BatchGetDeploymentGroupsError> {
+        match self.batch_get_deployment_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for batch_get_deployment_groups"),
+        }
+    }
+    fn batch_get_deployment_instances(
+        &self,
+        input: BatchGetDeploymentInstancesInput,
+    ) -> RusotoFuture<BatchGetDeploymentInstancesOutput, BatchGetDeploymentInstancesError> {
+        match self.batch_get_deployment_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for batch_get_deployment_instances"),
+        }
+    }
+    fn batch_get_deployment_targets(
+        &self,
+        input: BatchGetDeploymentTargetsInput,
+    ) -> RusotoFuture<BatchGetDeploymentTargetsOutput, BatchGetDeploymentTargetsError> {
+        match self.batch_get_deployment_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for batch_get_deployment_targets"),
+        }
+    }
+    fn batch_get_deployments(
+        &self,
+        input: BatchGetDeploymentsInput,
+    ) -> RusotoFuture<BatchGetDeploymentsOutput, BatchGetDeploymentsError> {
+        match self.batch_get_deployments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for batch_get_deployments"),
+        }
+    }
+    fn batch_get_on_premises_instances(
+        &self,
+        input: BatchGetOnPremisesInstancesInput,
+    ) -> RusotoFuture<BatchGetOnPremisesInstancesOutput, BatchGetOnPremisesInstancesError> {
+        match self.batch_get_on_premises_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for batch_get_on_premises_instances"),
+        }
+    }
+    fn continue_deployment(
+        &self,
+        input: ContinueDeploymentInput,
+    ) -> RusotoFuture<(), ContinueDeploymentError> {
+        match self.continue_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for continue_deployment"),
+        }
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationInput,
+    ) -> RusotoFuture<CreateApplicationOutput, CreateApplicationError> {
+        match self.create_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for create_application"),
+        }
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentInput,
+    ) -> RusotoFuture<CreateDeploymentOutput, CreateDeploymentError> {
+        match self.create_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for create_deployment"),
+        }
+    }
+    fn create_deployment_config(
+        &self,
+        input: CreateDeploymentConfigInput,
+    ) -> RusotoFuture<CreateDeploymentConfigOutput, CreateDeploymentConfigError> {
+        match self.create_deployment_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for create_deployment_config"),
+        }
+    }
+    fn create_deployment_group(
+        &self,
+        input: CreateDeploymentGroupInput,
+    ) -> RusotoFuture<CreateDeploymentGroupOutput, CreateDeploymentGroupError> {
+        match self.create_deployment_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for create_deployment_group"),
+        }
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationInput,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        match self.delete_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for delete_application"),
+        }
+    }
+    fn delete_deployment_config(
+        &self,
+        input: DeleteDeploymentConfigInput,
+    ) -> RusotoFuture<(), DeleteDeploymentConfigError> {
+        match self.delete_deployment_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for delete_deployment_config"),
+        }
+    }
+    fn delete_deployment_group(
+        &self,
+        input: DeleteDeploymentGroupInput,
+    ) -> RusotoFuture<DeleteDeploymentGroupOutput, DeleteDeploymentGroupError> {
+        match self.delete_deployment_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for delete_deployment_group"),
+        }
+    }
+    fn delete_git_hub_account_token(
+        &self,
+        input: DeleteGitHubAccountTokenInput,
+    ) -> RusotoFuture<DeleteGitHubAccountTokenOutput, DeleteGitHubAccountTokenError> {
+        match self.delete_git_hub_account_token {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for delete_git_hub_account_token"),
+        }
+    }
+    fn deregister_on_premises_instance(
+        &self,
+        input: DeregisterOnPremisesInstanceInput,
+    ) -> RusotoFuture<(), DeregisterOnPremisesInstanceError> {
+        match self.deregister_on_premises_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for deregister_on_premises_instance"),
+        }
+    }
+    fn get_application(
+        &self,
+        input: GetApplicationInput,
+    ) -> RusotoFuture<GetApplicationOutput, GetApplicationError> {
+        match self.get_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for get_application"),
+        }
+    }
+    fn get_application_revision(
+        &self,
+        input: GetApplicationRevisionInput,
+    ) -> RusotoFuture<GetApplicationRevisionOutput, GetApplicationRevisionError> {
+        match self.get_application_revision {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for get_application_revision"),
+        }
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentInput,
+    ) -> RusotoFuture<GetDeploymentOutput, GetDeploymentError> {
+        match self.get_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for get_deployment"),
+        }
+    }
+    fn get_deployment_config(
+        &self,
+        input: GetDeploymentConfigInput,
+    ) -> RusotoFuture<GetDeploymentConfigOutput, GetDeploymentConfigError> {
+        match self.get_deployment_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for get_deployment_config"),
+        }
+    }
+    fn get_deployment_group(
+        &self,
+        input: GetDeploymentGroupInput,
+    ) -> RusotoFuture<GetDeploymentGroupOutput, GetDeploymentGroupError> {
+        match self.get_deployment_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for get_deployment_group"),
+        }
+    }
+    fn get_deployment_instance(
+        &self,
+        input: GetDeploymentInstanceInput,
+    ) -> RusotoFuture<GetDeploymentInstanceOutput, GetDeploymentInstanceError> {
+        match self.get_deployment_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for get_deployment_instance"),
+        }
+    }
+    fn get_deployment_target(
+        &self,
+        input: GetDeploymentTargetInput,
+    ) -> RusotoFuture<GetDeploymentTargetOutput, GetDeploymentTargetError> {
+        match self.get_deployment_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for get_deployment_target"),
+        }
+    }
+    fn get_on_premises_instance(
+        &self,
+        input: GetOnPremisesInstanceInput,
+    ) -> RusotoFuture<GetOnPremisesInstanceOutput, GetOnPremisesInstanceError> {
+        match self.get_on_premises_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for get_on_premises_instance"),
+        }
+    }
+    fn list_application_revisions(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> RusotoFuture<ListApplicationRevisionsOutput, ListApplicationRevisionsError> {
+        match self.list_application_revisions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for list_application_revisions"),
+        }
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsInput,
+    ) -> RusotoFuture<ListApplicationsOutput, ListApplicationsError> {
+        match self.list_applications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for list_applications"),
+        }
+    }
+    fn list_deployment_configs(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> RusotoFuture<ListDeploymentConfigsOutput, ListDeploymentConfigsError> {
+        match self.list_deployment_configs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for list_deployment_configs"),
+        }
+    }
+    fn list_deployment_groups(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> RusotoFuture<ListDeploymentGroupsOutput, ListDeploymentGroupsError> {
+        match self.list_deployment_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for list_deployment_groups"),
+        }
+    }
+    fn list_deployment_instances(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> RusotoFuture<ListDeploymentInstancesOutput, ListDeploymentInstancesError> {
+        match self.list_deployment_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for list_deployment_instances"),
+        }
+    }
+    fn list_deployment_targets(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> RusotoFuture<ListDeploymentTargetsOutput, ListDeploymentTargetsError> {
+        match self.list_deployment_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for list_deployment_targets"),
+        }
+    }
+    fn list_deployments(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> RusotoFuture<ListDeploymentsOutput, ListDeploymentsError> {
+        match self.list_deployments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for list_deployments"),
+        }
+    }
+    fn list_git_hub_account_token_names(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> RusotoFuture<ListGitHubAccountTokenNamesOutput, ListGitHubAccountTokenNamesError> {
+        match self.list_git_hub_account_token_names {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for list_git_hub_account_token_names"),
+        }
+    }
+    fn list_on_premises_instances(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> RusotoFuture<ListOnPremisesInstancesOutput, ListOnPremisesInstancesError> {
+        match self.list_on_premises_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for list_on_premises_instances"),
+        }
     }
-
-    /// <p> Returns a list of tags for the resource identified by a specified ARN. Tags are used to organize and categorize your CodeDeploy resources. </p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for list_tags_for_resource"),
+        }
     }
-
-    /// <p> Sets the result of a Lambda validation function. The function validates one or both lifecycle events (<code>BeforeAllowTraffic</code> and <code>AfterAllowTraffic</code>) and returns <code>Succeeded</code> or <code>Failed</code>. </p>
     fn put_lifecycle_event_hook_execution_status(
         &self,
         input: PutLifecycleEventHookExecutionStatusInput,
@@ -7389,278 +10864,96 @@ impl CodeDeploy for CodeDeployClient {
         PutLifecycleEventHookExecutionStatusOutput,
         PutLifecycleEventHookExecutionStatusError,
     > {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.PutLifecycleEventHookExecutionStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutLifecycleEventHookExecutionStatusOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutLifecycleEventHookExecutionStatusError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.put_lifecycle_event_hook_execution_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCodeDeploy has no handler for put_lifecycle_event_hook_execution_status"
+            ),
+        }
     }
-
-    /// <p>Registers with AWS CodeDeploy a revision for the specified application.</p>
     fn register_application_revision(
         &self,
         input: RegisterApplicationRevisionInput,
     ) -> RusotoFuture<(), RegisterApplicationRevisionError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.RegisterApplicationRevision",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegisterApplicationRevisionError::from_response(response))
-                }))
-            }
-        })
+        match self.register_application_revision {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for register_application_revision"),
+        }
     }
-
-    /// <p><p>Registers an on-premises instance.</p> <note> <p>Only one IAM ARN (an IAM session ARN or IAM user ARN) is supported in the request. You cannot use both.</p> </note></p>
     fn register_on_premises_instance(
         &self,
         input: RegisterOnPremisesInstanceInput,
     ) -> RusotoFuture<(), RegisterOnPremisesInstanceError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.RegisterOnPremisesInstance",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegisterOnPremisesInstanceError::from_response(response))
-                }))
-            }
-        })
+        match self.register_on_premises_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for register_on_premises_instance"),
+        }
     }
-
-    /// <p>Removes one or more tags from one or more on-premises instances.</p>
     fn remove_tags_from_on_premises_instances(
         &self,
         input: RemoveTagsFromOnPremisesInstancesInput,
     ) -> RusotoFuture<(), RemoveTagsFromOnPremisesInstancesError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.RemoveTagsFromOnPremisesInstances",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RemoveTagsFromOnPremisesInstancesError::from_response(
-                        response,
-                    ))
-                }))
+        match self.remove_tags_from_on_premises_instances {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCodeDeploy has no handler for remove_tags_from_on_premises_instances")
             }
-        })
+        }
     }
-
-    /// <p>In a blue/green deployment, overrides any specified wait time and starts terminating instances immediately after the traffic routing is complete.</p>
     fn skip_wait_time_for_instance_termination(
         &self,
         input: SkipWaitTimeForInstanceTerminationInput,
     ) -> RusotoFuture<(), SkipWaitTimeForInstanceTerminationError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.SkipWaitTimeForInstanceTermination",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SkipWaitTimeForInstanceTerminationError::from_response(
-                        response,
-                    ))
-                }))
+        match self.skip_wait_time_for_instance_termination {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCodeDeploy has no handler for skip_wait_time_for_instance_termination")
             }
-        })
+        }
     }
-
-    /// <p>Attempts to stop an ongoing deployment.</p>
     fn stop_deployment(
         &self,
         input: StopDeploymentInput,
     ) -> RusotoFuture<StopDeploymentOutput, StopDeploymentError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.StopDeployment");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopDeploymentOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopDeploymentError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for stop_deployment"),
+        }
     }
-
-    /// <p> Associates the list of tags in the input <code>Tags</code> parameter with the resource identified by the <code>ResourceArn</code> input parameter. </p>
     fn tag_resource(
         &self,
         input: TagResourceInput,
     ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for tag_resource"),
+        }
     }
-
-    /// <p> Disassociates a resource from a list of tags. The resource is identified by the <code>ResourceArn</code> input parameter. The tags are identfied by the list of keys in the <code>TagKeys</code> input parameter. </p>
     fn untag_resource(
         &self,
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Changes the name of an application.</p>
     fn update_application(
         &self,
         input: UpdateApplicationInput,
     ) -> RusotoFuture<(), UpdateApplicationError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateApplication");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateApplicationError::from_response(response))),
-                )
-            }
-        })
+        match self.update_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for update_application"),
+        }
     }
-
-    /// <p>Changes information about a deployment group.</p>
     fn update_deployment_group(
         &self,
         input: UpdateDeploymentGroupInput,
     ) -> RusotoFuture<UpdateDeploymentGroupOutput, UpdateDeploymentGroupError> {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateDeploymentGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDeploymentGroupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateDeploymentGroupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_deployment_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeDeploy has no handler for update_deployment_group"),
+        }
     }
 }
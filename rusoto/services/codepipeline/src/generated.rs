@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Represents an AWS session credentials object. These credentials are temporary credentials that are issued by AWS Secure Token Service (STS). They can be used to access input and output artifacts in the Amazon S3 bucket used to store artifact for the pipeline in AWS CodePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AWSSessionCredentials {
     /// <p>The access key for the session.</p>
     #[serde(rename = "accessKeyId")]
@@ -40,6 +41,7 @@ pub struct AWSSessionCredentials {
 
 /// <p>Represents the input of an AcknowledgeJob action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcknowledgeJobInput {
     /// <p>The unique system-generated ID of the job for which you want to confirm receipt.</p>
     #[serde(rename = "jobId")]
@@ -49,9 +51,25 @@ pub struct AcknowledgeJobInput {
     pub nonce: String,
 }
 
+impl Validate for AcknowledgeJobInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+        errors.check_length(
+            &field_path(path, "nonce"),
+            self.nonce.chars().count(),
+            Some(1),
+            Some(50),
+        );
+    }
+}
+
 /// <p>Represents the output of an AcknowledgeJob action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcknowledgeJobOutput {
     /// <p>Whether the job worker has received the specified job.</p>
     #[serde(rename = "status")]
@@ -61,6 +79,7 @@ pub struct AcknowledgeJobOutput {
 
 /// <p>Represents the input of an AcknowledgeThirdPartyJob action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcknowledgeThirdPartyJobInput {
     /// <p>The clientToken portion of the clientId and clientToken pair used to verify that the calling entity is allowed access to the job and its details.</p>
     #[serde(rename = "clientToken")]
@@ -73,9 +92,32 @@ pub struct AcknowledgeThirdPartyJobInput {
     pub nonce: String,
 }
 
+impl Validate for AcknowledgeThirdPartyJobInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_token"),
+            self.client_token.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_length(
+            &field_path(path, "nonce"),
+            self.nonce.chars().count(),
+            Some(1),
+            Some(50),
+        );
+    }
+}
+
 /// <p>Represents the output of an AcknowledgeThirdPartyJob action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcknowledgeThirdPartyJobOutput {
     /// <p>The status information for the third party job, if any.</p>
     #[serde(rename = "status")]
@@ -85,7 +127,7 @@ pub struct AcknowledgeThirdPartyJobOutput {
 
 /// <p>Represents information about an action configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActionConfiguration {
     /// <p>The configuration data for the action.</p>
     #[serde(rename = "configuration")]
@@ -122,9 +164,28 @@ pub struct ActionConfigurationProperty {
     pub type_: Option<String>,
 }
 
+impl Validate for ActionConfigurationProperty {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(160),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(50),
+        );
+    }
+}
+
 /// <p>Represents the context of an action within the stage of a pipeline to a job worker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActionContext {
     /// <p>The system-generated unique ID that corresponds to an action's execution.</p>
     #[serde(rename = "actionExecutionId")]
@@ -171,9 +232,65 @@ pub struct ActionDeclaration {
     pub run_order: Option<i64>,
 }
 
+impl Validate for ActionDeclaration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.action_type_id
+            .validate_at(&field_path(path, "action_type_id"), errors);
+        if let Some(ref value) = self.configuration {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "configuration"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1000));
+            }
+        }
+        if let Some(ref value) = self.input_artifacts {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "input_artifacts"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[A-Za-z0-9.@\\-_]+");
+        if let Some(ref value) = self.output_artifacts {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "output_artifacts"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.region {
+            errors.check_length(
+                &field_path(path, "region"),
+                value.chars().count(),
+                Some(4),
+                Some(30),
+            );
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "arn:aws(-[\\w]+)*:iam::[0-9]{12}:role/.*",
+            );
+        }
+        if let Some(ref value) = self.run_order {
+            errors.check_range(&field_path(path, "run_order"), *value, Some(1), Some(999));
+        }
+    }
+}
+
 /// <p>Represents information about the run of an action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActionExecution {
     /// <p>The details of an error returned by a URL external to AWS.</p>
     #[serde(rename = "errorDetails")]
@@ -215,7 +332,7 @@ pub struct ActionExecution {
 
 /// <p>Returns information about an execution of an action, including the action execution ID, and the name, version, and timing of the action. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActionExecutionDetail {
     /// <p>The action execution ID.</p>
     #[serde(rename = "actionExecutionId")]
@@ -261,6 +378,7 @@ pub struct ActionExecutionDetail {
 
 /// <p>Filter values for the action execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ActionExecutionFilter {
     /// <p>The pipeline execution ID used to filter action execution history.</p>
     #[serde(rename = "pipelineExecutionId")]
@@ -268,9 +386,21 @@ pub struct ActionExecutionFilter {
     pub pipeline_execution_id: Option<String>,
 }
 
+impl Validate for ActionExecutionFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.pipeline_execution_id {
+            errors.check_pattern(
+                &field_path(path, "pipeline_execution_id"),
+                &value,
+                "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+    }
+}
+
 /// <p>Input information used for an action execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActionExecutionInput {
     #[serde(rename = "actionTypeId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -295,7 +425,7 @@ pub struct ActionExecutionInput {
 
 /// <p>Output details listed for an action execution, such as the action execution result.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActionExecutionOutput {
     /// <p>Execution result information listed in the output details for an action execution.</p>
     #[serde(rename = "executionResult")]
@@ -309,7 +439,7 @@ pub struct ActionExecutionOutput {
 
 /// <p>Execution result information, such as the external execution ID.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActionExecutionResult {
     /// <p>The action provider's external ID for the action execution.</p>
     #[serde(rename = "externalExecutionId")]
@@ -339,9 +469,28 @@ pub struct ActionRevision {
     pub revision_id: String,
 }
 
+impl Validate for ActionRevision {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.revision_change_id {
+            errors.check_length(
+                &field_path(path, "revision_change_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "revision_id"),
+            self.revision_id.chars().count(),
+            Some(1),
+            Some(1500),
+        );
+    }
+}
+
 /// <p>Represents information about the state of an action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActionState {
     /// <p>The name of the action.</p>
     #[serde(rename = "actionName")]
@@ -367,7 +516,7 @@ pub struct ActionState {
 
 /// <p>Returns information about the details of an action type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActionType {
     /// <p>The configuration properties for the action type.</p>
     #[serde(rename = "actionConfigurationProperties")]
@@ -405,6 +554,33 @@ pub struct ActionTypeId {
     pub version: String,
 }
 
+impl Validate for ActionTypeId {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "provider"),
+            self.provider.chars().count(),
+            Some(1),
+            Some(35),
+        );
+        errors.check_pattern(
+            &field_path(path, "provider"),
+            &self.provider,
+            "[0-9A-Za-z_-]+",
+        );
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(9),
+        );
+        errors.check_pattern(
+            &field_path(path, "version"),
+            &self.version,
+            "[0-9A-Za-z_-]+",
+        );
+    }
+}
+
 /// <p>Returns information about the settings for an action type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActionTypeSettings {
@@ -426,8 +602,46 @@ pub struct ActionTypeSettings {
     pub third_party_configuration_url: Option<String>,
 }
 
+impl Validate for ActionTypeSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.entity_url_template {
+            errors.check_length(
+                &field_path(path, "entity_url_template"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.execution_url_template {
+            errors.check_length(
+                &field_path(path, "execution_url_template"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.revision_url_template {
+            errors.check_length(
+                &field_path(path, "revision_url_template"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.third_party_configuration_url {
+            errors.check_length(
+                &field_path(path, "third_party_configuration_url"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 /// <p>Represents information about the result of an approval request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApprovalResult {
     /// <p>The response submitted by a reviewer assigned to an approval action request.</p>
     #[serde(rename = "status")]
@@ -437,9 +651,20 @@ pub struct ApprovalResult {
     pub summary: String,
 }
 
+impl Validate for ApprovalResult {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "summary"),
+            self.summary.chars().count(),
+            None,
+            Some(512),
+        );
+    }
+}
+
 /// <p>Represents information about an artifact that will be worked upon by actions in the pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Artifact {
     /// <p>The location of an artifact.</p>
     #[serde(rename = "location")]
@@ -457,7 +682,7 @@ pub struct Artifact {
 
 /// <p>Artifact details for the action execution, such as the artifact location.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ArtifactDetail {
     /// <p>The artifact object name for the action execution.</p>
     #[serde(rename = "name")]
@@ -480,9 +705,26 @@ pub struct ArtifactDetails {
     pub minimum_count: i64,
 }
 
+impl Validate for ArtifactDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "maximum_count"),
+            self.maximum_count,
+            None,
+            Some(5),
+        );
+        errors.check_range(
+            &field_path(path, "minimum_count"),
+            self.minimum_count,
+            None,
+            Some(5),
+        );
+    }
+}
+
 /// <p>Represents information about the location of an artifact.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ArtifactLocation {
     /// <p>The Amazon S3 bucket that contains the artifact.</p>
     #[serde(rename = "s3Location")]
@@ -496,7 +738,7 @@ pub struct ArtifactLocation {
 
 /// <p>Represents revision details of an artifact. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ArtifactRevision {
     /// <p>The date and time when the most recent revision of the artifact was created, in timestamp format.</p>
     #[serde(rename = "created")]
@@ -539,6 +781,25 @@ pub struct ArtifactStore {
     pub type_: String,
 }
 
+impl Validate for ArtifactStore {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.encryption_key {
+            value.validate_at(&field_path(path, "encryption_key"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "location"),
+            self.location.chars().count(),
+            Some(3),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "location"),
+            &self.location,
+            "[a-zA-Z0-9\\-\\.]+",
+        );
+    }
+}
+
 /// <p>Reserved for future use.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockerDeclaration {
@@ -550,8 +811,20 @@ pub struct BlockerDeclaration {
     pub type_: String,
 }
 
+impl Validate for BlockerDeclaration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents the input of a CreateCustomActionType operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCustomActionTypeInput {
     /// <p><p>The category of the custom action, such as a build action or a test action.</p> <note> <p>Although <code>Source</code> and <code>Approval</code> are listed as valid values, they are not currently functional. These values are reserved for future use.</p> </note></p>
     #[serde(rename = "category")]
@@ -582,9 +855,61 @@ pub struct CreateCustomActionTypeInput {
     pub version: String,
 }
 
+impl Validate for CreateCustomActionTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.configuration_properties {
+            errors.check_length(
+                &field_path(path, "configuration_properties"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "configuration_properties"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.input_artifact_details
+            .validate_at(&field_path(path, "input_artifact_details"), errors);
+        self.output_artifact_details
+            .validate_at(&field_path(path, "output_artifact_details"), errors);
+        errors.check_length(
+            &field_path(path, "provider"),
+            self.provider.chars().count(),
+            Some(1),
+            Some(35),
+        );
+        errors.check_pattern(
+            &field_path(path, "provider"),
+            &self.provider,
+            "[0-9A-Za-z_-]+",
+        );
+        if let Some(ref value) = self.settings {
+            value.validate_at(&field_path(path, "settings"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(9),
+        );
+        errors.check_pattern(
+            &field_path(path, "version"),
+            &self.version,
+            "[0-9A-Za-z_-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>CreateCustomActionType</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCustomActionTypeOutput {
     /// <p>Returns information about the details of an action type.</p>
     #[serde(rename = "actionType")]
@@ -597,6 +922,7 @@ pub struct CreateCustomActionTypeOutput {
 
 /// <p>Represents the input of a <code>CreatePipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePipelineInput {
     /// <p>Represents the structure of actions and stages to be performed in the pipeline. </p>
     #[serde(rename = "pipeline")]
@@ -607,9 +933,22 @@ pub struct CreatePipelineInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreatePipelineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.pipeline
+            .validate_at(&field_path(path, "pipeline"), errors);
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>CreatePipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePipelineOutput {
     /// <p>Represents the structure of actions and stages to be performed in the pipeline. </p>
     #[serde(rename = "pipeline")]
@@ -623,6 +962,7 @@ pub struct CreatePipelineOutput {
 
 /// <p>Represents information about a current revision.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CurrentRevision {
     /// <p>The change identifier for the current revision.</p>
     #[serde(rename = "changeIdentifier")]
@@ -640,8 +980,34 @@ pub struct CurrentRevision {
     pub revision_summary: Option<String>,
 }
 
+impl Validate for CurrentRevision {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_identifier"),
+            self.change_identifier.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "revision"),
+            self.revision.chars().count(),
+            Some(1),
+            Some(1500),
+        );
+        if let Some(ref value) = self.revision_summary {
+            errors.check_length(
+                &field_path(path, "revision_summary"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 /// <p>Represents the input of a <code>DeleteCustomActionType</code> operation. The custom action will be marked as deleted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCustomActionTypeInput {
     /// <p>The category of the custom action that you want to delete, such as source or deploy.</p>
     #[serde(rename = "category")]
@@ -654,26 +1020,80 @@ pub struct DeleteCustomActionTypeInput {
     pub version: String,
 }
 
+impl Validate for DeleteCustomActionTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "provider"),
+            self.provider.chars().count(),
+            Some(1),
+            Some(35),
+        );
+        errors.check_pattern(
+            &field_path(path, "provider"),
+            &self.provider,
+            "[0-9A-Za-z_-]+",
+        );
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(9),
+        );
+        errors.check_pattern(
+            &field_path(path, "version"),
+            &self.version,
+            "[0-9A-Za-z_-]+",
+        );
+    }
+}
+
 /// <p>Represents the input of a <code>DeletePipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePipelineInput {
     /// <p>The name of the pipeline to be deleted.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for DeletePipelineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[A-Za-z0-9.@\\-_]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteWebhookInput {
     /// <p>The name of the webhook you want to delete.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for DeleteWebhookInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[A-Za-z0-9.@\\-_]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteWebhookOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterWebhookWithThirdPartyInput {
     /// <p>The name of the webhook you want to deregister.</p>
     #[serde(rename = "webhookName")]
@@ -681,12 +1101,31 @@ pub struct DeregisterWebhookWithThirdPartyInput {
     pub webhook_name: Option<String>,
 }
 
+impl Validate for DeregisterWebhookWithThirdPartyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.webhook_name {
+            errors.check_length(
+                &field_path(path, "webhook_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "webhook_name"),
+                &value,
+                "[A-Za-z0-9.@\\-_]+",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterWebhookWithThirdPartyOutput {}
 
 /// <p>Represents the input of a <code>DisableStageTransition</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableStageTransitionInput {
     /// <p>The name of the pipeline in which you want to disable the flow of artifacts from one stage to another.</p>
     #[serde(rename = "pipelineName")]
@@ -702,8 +1141,47 @@ pub struct DisableStageTransitionInput {
     pub transition_type: String,
 }
 
+impl Validate for DisableStageTransitionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+        errors.check_length(
+            &field_path(path, "reason"),
+            self.reason.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_pattern(
+            &field_path(path, "reason"),
+            &self.reason,
+            "[a-zA-Z0-9!@ \\(\\)\\.\\*\\?\\-]+",
+        );
+        errors.check_length(
+            &field_path(path, "stage_name"),
+            self.stage_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "stage_name"),
+            &self.stage_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+    }
+}
+
 /// <p>Represents the input of an <code>EnableStageTransition</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableStageTransitionInput {
     /// <p>The name of the pipeline in which you want to enable the flow of artifacts from one stage to another.</p>
     #[serde(rename = "pipelineName")]
@@ -716,6 +1194,33 @@ pub struct EnableStageTransitionInput {
     pub transition_type: String,
 }
 
+impl Validate for EnableStageTransitionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+        errors.check_length(
+            &field_path(path, "stage_name"),
+            self.stage_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "stage_name"),
+            &self.stage_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+    }
+}
+
 /// <p>Represents information about the key used to encrypt data in the artifact store, such as an AWS Key Management Service (AWS KMS) key.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionKey {
@@ -727,9 +1232,20 @@ pub struct EncryptionKey {
     pub type_: String,
 }
 
+impl Validate for EncryptionKey {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(400),
+        );
+    }
+}
+
 /// <p>Represents information about an error in AWS CodePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ErrorDetails {
     /// <p>The system ID or error number code of the error.</p>
     #[serde(rename = "code")]
@@ -743,6 +1259,7 @@ pub struct ErrorDetails {
 
 /// <p>The details of the actions taken and results produced on an artifact as it passes through stages in the pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecutionDetails {
     /// <p>The system-generated unique ID of this action used to identify this job worker in any external systems, such as AWS CodeDeploy.</p>
     #[serde(rename = "externalExecutionId")]
@@ -758,9 +1275,38 @@ pub struct ExecutionDetails {
     pub summary: Option<String>,
 }
 
+impl Validate for ExecutionDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.external_execution_id {
+            errors.check_length(
+                &field_path(path, "external_execution_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1500),
+            );
+        }
+        if let Some(ref value) = self.percent_complete {
+            errors.check_range(
+                &field_path(path, "percent_complete"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.summary {
+            errors.check_length(
+                &field_path(path, "summary"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 /// <p>The interaction or event that started a pipeline execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecutionTrigger {
     /// <p>Detail related to the event that started a pipeline execution, such as the webhook ARN of the webhook that triggered the pipeline execution or the user ARN for a user-initiated <code>start-pipeline-execution</code> CLI command.</p>
     #[serde(rename = "triggerDetail")]
@@ -774,6 +1320,7 @@ pub struct ExecutionTrigger {
 
 /// <p>Represents information about failure details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FailureDetails {
     /// <p>The external ID of the run of the action that failed.</p>
     #[serde(rename = "externalExecutionId")]
@@ -787,17 +1334,47 @@ pub struct FailureDetails {
     pub type_: String,
 }
 
+impl Validate for FailureDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.external_execution_id {
+            errors.check_length(
+                &field_path(path, "external_execution_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1500),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "message"),
+            self.message.chars().count(),
+            Some(1),
+            Some(5000),
+        );
+    }
+}
+
 /// <p>Represents the input of a <code>GetJobDetails</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobDetailsInput {
     /// <p>The unique system-generated ID for the job.</p>
     #[serde(rename = "jobId")]
     pub job_id: String,
 }
 
+impl Validate for GetJobDetailsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>GetJobDetails</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobDetailsOutput {
     /// <p><p>The details of the job.</p> <note> <p>If AWSSessionCredentials is used, a long-running job can call <code>GetJobDetails</code> again to obtain new credentials.</p> </note></p>
     #[serde(rename = "jobDetails")]
@@ -807,6 +1384,7 @@ pub struct GetJobDetailsOutput {
 
 /// <p>Represents the input of a <code>GetPipelineExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPipelineExecutionInput {
     /// <p>The ID of the pipeline execution about which you want to get execution details.</p>
     #[serde(rename = "pipelineExecutionId")]
@@ -816,9 +1394,30 @@ pub struct GetPipelineExecutionInput {
     pub pipeline_name: String,
 }
 
+impl Validate for GetPipelineExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "pipeline_execution_id"),
+            &self.pipeline_execution_id,
+            "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>GetPipelineExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPipelineExecutionOutput {
     /// <p>Represents information about the execution of a pipeline.</p>
     #[serde(rename = "pipelineExecution")]
@@ -828,6 +1427,7 @@ pub struct GetPipelineExecutionOutput {
 
 /// <p>Represents the input of a <code>GetPipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPipelineInput {
     /// <p>The name of the pipeline for which you want to get information. Pipeline names must be unique under an Amazon Web Services (AWS) user account.</p>
     #[serde(rename = "name")]
@@ -838,9 +1438,24 @@ pub struct GetPipelineInput {
     pub version: Option<i64>,
 }
 
+impl Validate for GetPipelineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[A-Za-z0-9.@\\-_]+");
+        if let Some(ref value) = self.version {
+            errors.check_range(&field_path(path, "version"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>GetPipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPipelineOutput {
     /// <p>Represents the pipeline metadata information returned as part of the output of a <code>GetPipeline</code> action.</p>
     #[serde(rename = "metadata")]
@@ -854,15 +1469,28 @@ pub struct GetPipelineOutput {
 
 /// <p>Represents the input of a <code>GetPipelineState</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPipelineStateInput {
     /// <p>The name of the pipeline about which you want to get information.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for GetPipelineStateInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[A-Za-z0-9.@\\-_]+");
+    }
+}
+
 /// <p>Represents the output of a <code>GetPipelineState</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPipelineStateOutput {
     /// <p>The date and time the pipeline was created, in timestamp format.</p>
     #[serde(rename = "created")]
@@ -888,6 +1516,7 @@ pub struct GetPipelineStateOutput {
 
 /// <p>Represents the input of a <code>GetThirdPartyJobDetails</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetThirdPartyJobDetailsInput {
     /// <p>The clientToken portion of the clientId and clientToken pair used to verify that the calling entity is allowed access to the job and its details.</p>
     #[serde(rename = "clientToken")]
@@ -897,9 +1526,26 @@ pub struct GetThirdPartyJobDetailsInput {
     pub job_id: String,
 }
 
+impl Validate for GetThirdPartyJobDetailsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_token"),
+            self.client_token.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(512),
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>GetThirdPartyJobDetails</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetThirdPartyJobDetailsOutput {
     /// <p>The details of the job, including any protected values defined for the job.</p>
     #[serde(rename = "jobDetails")]
@@ -915,9 +1561,21 @@ pub struct InputArtifact {
     pub name: String,
 }
 
+impl Validate for InputArtifact {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9_\\-]+");
+    }
+}
+
 /// <p>Represents information about a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Job {
     /// <p>The ID of the AWS account to use when performing the job.</p>
     #[serde(rename = "accountId")]
@@ -939,7 +1597,7 @@ pub struct Job {
 
 /// <p>Represents additional information about a job required for a job worker to complete the job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobData {
     /// <p>Represents information about an action configuration.</p>
     #[serde(rename = "actionConfiguration")]
@@ -977,7 +1635,7 @@ pub struct JobData {
 
 /// <p>Represents information about the details of a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobDetails {
     /// <p>The AWS account ID associated with the job.</p>
     #[serde(rename = "accountId")]
@@ -994,6 +1652,7 @@ pub struct JobDetails {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListActionExecutionsInput {
     /// <p>Input information used to filter action execution history.</p>
     #[serde(rename = "filter")]
@@ -1012,8 +1671,38 @@ pub struct ListActionExecutionsInput {
     pub pipeline_name: String,
 }
 
+impl Validate for ListActionExecutionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListActionExecutionsOutput {
     /// <p>The details for a list of recent executions, such as action execution ID.</p>
     #[serde(rename = "actionExecutionDetails")]
@@ -1027,6 +1716,7 @@ pub struct ListActionExecutionsOutput {
 
 /// <p>Represents the input of a <code>ListActionTypes</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListActionTypesInput {
     /// <p>Filters the list of action types to those created by a specified entity.</p>
     #[serde(rename = "actionOwnerFilter")]
@@ -1038,9 +1728,22 @@ pub struct ListActionTypesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListActionTypesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>ListActionTypes</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListActionTypesOutput {
     /// <p>Provides details of the action types.</p>
     #[serde(rename = "actionTypes")]
@@ -1053,6 +1756,7 @@ pub struct ListActionTypesOutput {
 
 /// <p>Represents the input of a <code>ListPipelineExecutions</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPipelineExecutionsInput {
     /// <p>The maximum number of results to return in a single call. To retrieve the remaining results, make another call with the returned nextToken value. Pipeline history is limited to the most recent 12 months, based on pipeline execution start times. Default value is 100.</p>
     #[serde(rename = "maxResults")]
@@ -1067,9 +1771,36 @@ pub struct ListPipelineExecutionsInput {
     pub pipeline_name: String,
 }
 
+impl Validate for ListPipelineExecutionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>ListPipelineExecutions</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPipelineExecutionsOutput {
     /// <p>A token that can be used in the next <code>ListPipelineExecutions</code> call. To view all items in the list, continue to call this operation with each subsequent token until no more nextToken values are returned.</p>
     #[serde(rename = "nextToken")]
@@ -1083,6 +1814,7 @@ pub struct ListPipelineExecutionsOutput {
 
 /// <p>Represents the input of a <code>ListPipelines</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPipelinesInput {
     /// <p>An identifier that was returned from the previous list pipelines call, which can be used to return the next set of pipelines in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1090,9 +1822,22 @@ pub struct ListPipelinesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListPipelinesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>ListPipelines</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPipelinesOutput {
     /// <p>If the amount of returned information is significantly large, an identifier is also returned which can be used in a subsequent list pipelines call to return the next set of pipelines in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1105,6 +1850,7 @@ pub struct ListPipelinesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>The maximum number of results to return in a single call.</p>
     #[serde(rename = "maxResults")]
@@ -1119,8 +1865,29 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:aws(-[\\w]+)*:codepipeline:.+:[0-9]{12}:.+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceOutput {
     /// <p>If the amount of returned information is significantly large, an identifier is also returned and can be used in a subsequent API call to return the next page of the list. However, the ListTagsforResource call lists all available tags in one call and does not use pagination.</p>
     #[serde(rename = "nextToken")]
@@ -1134,7 +1901,7 @@ pub struct ListTagsForResourceOutput {
 
 /// <p>The detail returned for each webhook after listing webhooks, such as the webhook URL, the webhook name, and the webhook ARN.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWebhookItem {
     /// <p>The Amazon Resource Name (ARN) of the webhook.</p>
     #[serde(rename = "arn")]
@@ -1165,6 +1932,7 @@ pub struct ListWebhookItem {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWebhooksInput {
     /// <p>The maximum number of results to return in a single call. To retrieve the remaining results, make another call with the returned nextToken value.</p>
     #[serde(rename = "MaxResults")]
@@ -1176,8 +1944,24 @@ pub struct ListWebhooksInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListWebhooksInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWebhooksOutput {
     /// <p>If the amount of returned information is significantly large, an identifier is also returned and can be used in a subsequent ListWebhooks call to return the next set of webhooks in the list. </p>
     #[serde(rename = "NextToken")]
@@ -1197,9 +1981,21 @@ pub struct OutputArtifact {
     pub name: String,
 }
 
+impl Validate for OutputArtifact {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9_\\-]+");
+    }
+}
+
 /// <p><p>Represents information about a pipeline to a job worker.</p> <note> <p>PipelineContext contains <code>pipelineArn</code> and <code>pipelineExecutionId</code> for custom action jobs. The <code>pipelineArn</code> and <code>pipelineExecutionId</code> fields are not populated for ThirdParty action jobs.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PipelineContext {
     /// <p>The context of an action to a job worker within the stage of a pipeline.</p>
     #[serde(rename = "action")]
@@ -1249,9 +2045,48 @@ pub struct PipelineDeclaration {
     pub version: Option<i64>,
 }
 
+impl Validate for PipelineDeclaration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.artifact_store {
+            value.validate_at(&field_path(path, "artifact_store"), errors);
+        }
+        if let Some(ref value) = self.artifact_stores {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "artifact_stores"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[A-Za-z0-9.@\\-_]+");
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws(-[\\w]+)*:iam::[0-9]{12}:role/.*",
+        );
+        for (i, item) in self.stages.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "stages"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.version {
+            errors.check_range(&field_path(path, "version"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents information about an execution of a pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PipelineExecution {
     /// <p>A list of <code>ArtifactRevision</code> objects included in a pipeline execution.</p>
     #[serde(rename = "artifactRevisions")]
@@ -1277,7 +2112,7 @@ pub struct PipelineExecution {
 
 /// <p>Summary information about a pipeline execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PipelineExecutionSummary {
     /// <p>The date and time of the last change to the pipeline execution, in timestamp format.</p>
     #[serde(rename = "lastUpdateTime")]
@@ -1307,7 +2142,7 @@ pub struct PipelineExecutionSummary {
 
 /// <p>Information about a pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PipelineMetadata {
     /// <p>The date and time the pipeline was created, in timestamp format.</p>
     #[serde(rename = "created")]
@@ -1325,7 +2160,7 @@ pub struct PipelineMetadata {
 
 /// <p>Returns a summary of a pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PipelineSummary {
     /// <p>The date and time the pipeline was created, in timestamp format.</p>
     #[serde(rename = "created")]
@@ -1347,6 +2182,7 @@ pub struct PipelineSummary {
 
 /// <p>Represents the input of a <code>PollForJobs</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PollForJobsInput {
     /// <p>Represents information about an action type.</p>
     #[serde(rename = "actionTypeId")]
@@ -1361,9 +2197,27 @@ pub struct PollForJobsInput {
     pub query_param: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for PollForJobsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.action_type_id
+            .validate_at(&field_path(path, "action_type_id"), errors);
+        if let Some(ref value) = self.max_batch_size {
+            errors.check_range(&field_path(path, "max_batch_size"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.query_param {
+            errors.check_length(&field_path(path, "query_param"), value.len(), None, Some(1));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "query_param"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(50));
+                errors.check_pattern(&item_path, &item, "[a-zA-Z0-9_-]+");
+            }
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>PollForJobs</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PollForJobsOutput {
     /// <p>Information about the jobs to take action on.</p>
     #[serde(rename = "jobs")]
@@ -1373,6 +2227,7 @@ pub struct PollForJobsOutput {
 
 /// <p>Represents the input of a <code>PollForThirdPartyJobs</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PollForThirdPartyJobsInput {
     /// <p>Represents information about an action type.</p>
     #[serde(rename = "actionTypeId")]
@@ -1383,9 +2238,19 @@ pub struct PollForThirdPartyJobsInput {
     pub max_batch_size: Option<i64>,
 }
 
+impl Validate for PollForThirdPartyJobsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.action_type_id
+            .validate_at(&field_path(path, "action_type_id"), errors);
+        if let Some(ref value) = self.max_batch_size {
+            errors.check_range(&field_path(path, "max_batch_size"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>PollForThirdPartyJobs</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PollForThirdPartyJobsOutput {
     /// <p>Information about the jobs to take action on.</p>
     #[serde(rename = "jobs")]
@@ -1395,6 +2260,7 @@ pub struct PollForThirdPartyJobsOutput {
 
 /// <p>Represents the input of a <code>PutActionRevision</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutActionRevisionInput {
     /// <p>The name of the action that will process the revision.</p>
     #[serde(rename = "actionName")]
@@ -1410,9 +2276,49 @@ pub struct PutActionRevisionInput {
     pub stage_name: String,
 }
 
+impl Validate for PutActionRevisionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "action_name"),
+            self.action_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "action_name"),
+            &self.action_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+        self.action_revision
+            .validate_at(&field_path(path, "action_revision"), errors);
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+        errors.check_length(
+            &field_path(path, "stage_name"),
+            self.stage_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "stage_name"),
+            &self.stage_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>PutActionRevision</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutActionRevisionOutput {
     /// <p>Indicates whether the artifact revision was previously used in an execution of the specified pipeline.</p>
     #[serde(rename = "newRevision")]
@@ -1426,6 +2332,7 @@ pub struct PutActionRevisionOutput {
 
 /// <p>Represents the input of a <code>PutApprovalResult</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutApprovalResultInput {
     /// <p>The name of the action for which approval is requested.</p>
     #[serde(rename = "actionName")]
@@ -1444,9 +2351,53 @@ pub struct PutApprovalResultInput {
     pub token: String,
 }
 
+impl Validate for PutApprovalResultInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "action_name"),
+            self.action_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "action_name"),
+            &self.action_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+        self.result.validate_at(&field_path(path, "result"), errors);
+        errors.check_length(
+            &field_path(path, "stage_name"),
+            self.stage_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "stage_name"),
+            &self.stage_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+        errors.check_pattern(
+            &field_path(path, "token"),
+            &self.token,
+            "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>PutApprovalResult</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutApprovalResultOutput {
     /// <p>The timestamp showing when the approval or rejection was submitted.</p>
     #[serde(rename = "approvedAt")]
@@ -1456,6 +2407,7 @@ pub struct PutApprovalResultOutput {
 
 /// <p>Represents the input of a <code>PutJobFailureResult</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutJobFailureResultInput {
     /// <p>The details about the failure of a job.</p>
     #[serde(rename = "failureDetails")]
@@ -1465,8 +2417,21 @@ pub struct PutJobFailureResultInput {
     pub job_id: String,
 }
 
+impl Validate for PutJobFailureResultInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.failure_details
+            .validate_at(&field_path(path, "failure_details"), errors);
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 /// <p>Represents the input of a <code>PutJobSuccessResult</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutJobSuccessResultInput {
     /// <p>A token generated by a job worker, such as an AWS CodeDeploy deployment ID, that a successful job provides to identify a custom action in progress. Future jobs will use this token in order to identify the running instance of the action. It can be reused to return additional information about the progress of the custom action. When the action is complete, no continuation token should be supplied.</p>
     #[serde(rename = "continuationToken")]
@@ -1485,8 +2450,33 @@ pub struct PutJobSuccessResultInput {
     pub job_id: String,
 }
 
-/// <p>Represents the input of a <code>PutThirdPartyJobFailureResult</code> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl Validate for PutJobSuccessResultInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.continuation_token {
+            errors.check_length(
+                &field_path(path, "continuation_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.current_revision {
+            value.validate_at(&field_path(path, "current_revision"), errors);
+        }
+        if let Some(ref value) = self.execution_details {
+            value.validate_at(&field_path(path, "execution_details"), errors);
+        }
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
+/// <p>Represents the input of a <code>PutThirdPartyJobFailureResult</code> action.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutThirdPartyJobFailureResultInput {
     /// <p>The clientToken portion of the clientId and clientToken pair used to verify that the calling entity is allowed access to the job and its details.</p>
     #[serde(rename = "clientToken")]
@@ -1499,8 +2489,28 @@ pub struct PutThirdPartyJobFailureResultInput {
     pub job_id: String,
 }
 
+impl Validate for PutThirdPartyJobFailureResultInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_token"),
+            self.client_token.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.failure_details
+            .validate_at(&field_path(path, "failure_details"), errors);
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(512),
+        );
+    }
+}
+
 /// <p>Represents the input of a <code>PutThirdPartyJobSuccessResult</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutThirdPartyJobSuccessResultInput {
     /// <p>The clientToken portion of the clientId and clientToken pair used to verify that the calling entity is allowed access to the job and its details.</p>
     #[serde(rename = "clientToken")]
@@ -1522,7 +2532,39 @@ pub struct PutThirdPartyJobSuccessResultInput {
     pub job_id: String,
 }
 
+impl Validate for PutThirdPartyJobSuccessResultInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_token"),
+            self.client_token.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.continuation_token {
+            errors.check_length(
+                &field_path(path, "continuation_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.current_revision {
+            value.validate_at(&field_path(path, "current_revision"), errors);
+        }
+        if let Some(ref value) = self.execution_details {
+            value.validate_at(&field_path(path, "execution_details"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(512),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutWebhookInput {
     /// <p>The tags for the webhook.</p>
     #[serde(rename = "tags")]
@@ -1533,8 +2575,21 @@ pub struct PutWebhookInput {
     pub webhook: WebhookDefinition,
 }
 
+impl Validate for PutWebhookInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.webhook
+            .validate_at(&field_path(path, "webhook"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutWebhookOutput {
     /// <p>The detail returned from creating the webhook, such as the webhook name, webhook URL, and webhook ARN.</p>
     #[serde(rename = "webhook")]
@@ -1543,6 +2598,7 @@ pub struct PutWebhookOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterWebhookWithThirdPartyInput {
     /// <p>The name of an existing webhook created with PutWebhook to register with a supported third party. </p>
     #[serde(rename = "webhookName")]
@@ -1550,12 +2606,31 @@ pub struct RegisterWebhookWithThirdPartyInput {
     pub webhook_name: Option<String>,
 }
 
+impl Validate for RegisterWebhookWithThirdPartyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.webhook_name {
+            errors.check_length(
+                &field_path(path, "webhook_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "webhook_name"),
+                &value,
+                "[A-Za-z0-9.@\\-_]+",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterWebhookWithThirdPartyOutput {}
 
 /// <p>Represents the input of a <code>RetryStageExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RetryStageExecutionInput {
     /// <p>The ID of the pipeline execution in the failed stage to be retried. Use the <a>GetPipelineState</a> action to retrieve the current pipelineExecutionId of the failed stage</p>
     #[serde(rename = "pipelineExecutionId")]
@@ -1571,9 +2646,41 @@ pub struct RetryStageExecutionInput {
     pub stage_name: String,
 }
 
+impl Validate for RetryStageExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "pipeline_execution_id"),
+            &self.pipeline_execution_id,
+            "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+        errors.check_length(
+            &field_path(path, "stage_name"),
+            self.stage_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "stage_name"),
+            &self.stage_name,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>RetryStageExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RetryStageExecutionOutput {
     /// <p>The ID of the current workflow execution in the failed stage.</p>
     #[serde(rename = "pipelineExecutionId")]
@@ -1583,7 +2690,7 @@ pub struct RetryStageExecutionOutput {
 
 /// <p>The location of the Amazon S3 bucket that contains a revision.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct S3ArtifactLocation {
     /// <p>The name of the Amazon S3 bucket.</p>
     #[serde(rename = "bucketName")]
@@ -1595,7 +2702,7 @@ pub struct S3ArtifactLocation {
 
 /// <p>The Amazon S3 artifact location for an action's artifacts.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct S3Location {
     /// <p>The Amazon S3 artifact bucket for an action's artifacts.</p>
     #[serde(rename = "bucket")]
@@ -1609,7 +2716,7 @@ pub struct S3Location {
 
 /// <p>Information about the version (or revision) of a source artifact that initiated a pipeline execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SourceRevision {
     /// <p>The name of the action that processed the revision to the source artifact.</p>
     #[serde(rename = "actionName")]
@@ -1630,7 +2737,7 @@ pub struct SourceRevision {
 
 /// <p>Represents information about a stage to a job worker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StageContext {
     /// <p>The name of the stage.</p>
     #[serde(rename = "name")]
@@ -1653,9 +2760,31 @@ pub struct StageDeclaration {
     pub name: String,
 }
 
+impl Validate for StageDeclaration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.actions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "actions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.blockers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "blockers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[A-Za-z0-9.@\\-_]+");
+    }
+}
+
 /// <p>Represents information about the run of a stage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StageExecution {
     /// <p>The ID of the pipeline execution associated with the stage.</p>
     #[serde(rename = "pipelineExecutionId")]
@@ -1667,7 +2796,7 @@ pub struct StageExecution {
 
 /// <p>Represents information about the state of the stage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StageState {
     /// <p>The state of the stage.</p>
     #[serde(rename = "actionStates")]
@@ -1689,6 +2818,7 @@ pub struct StageState {
 
 /// <p>Represents the input of a <code>StartPipelineExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartPipelineExecutionInput {
     /// <p>The system-generated unique ID used to identify a unique execution request.</p>
     #[serde(rename = "clientRequestToken")]
@@ -1699,9 +2829,34 @@ pub struct StartPipelineExecutionInput {
     pub name: String,
 }
 
+impl Validate for StartPipelineExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[A-Za-z0-9.@\\-_]+");
+    }
+}
+
 /// <p>Represents the output of a <code>StartPipelineExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartPipelineExecutionOutput {
     /// <p>The unique system-generated ID of the pipeline execution that was started.</p>
     #[serde(rename = "pipelineExecutionId")]
@@ -1720,7 +2875,25 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the resource you want to add tags to.</p>
     #[serde(rename = "resourceArn")]
@@ -1730,13 +2903,27 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:aws(-[\\w]+)*:codepipeline:.+:[0-9]{12}:.+",
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceOutput {}
 
 /// <p>A response to a <code>PollForThirdPartyJobs </code>request returned by AWS CodePipeline when there is a job to be worked upon by a partner action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ThirdPartyJob {
     /// <p>The <code>clientToken</code> portion of the <code>clientId</code> and <code>clientToken</code> pair used to verify that the calling entity is allowed access to the job and its details.</p>
     #[serde(rename = "clientId")]
@@ -1750,7 +2937,7 @@ pub struct ThirdPartyJob {
 
 /// <p>Represents information about the job data for a partner action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ThirdPartyJobData {
     /// <p>Represents information about an action configuration.</p>
     #[serde(rename = "actionConfiguration")]
@@ -1788,7 +2975,7 @@ pub struct ThirdPartyJobData {
 
 /// <p>The details of a job sent in response to a <code>GetThirdPartyJobDetails</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ThirdPartyJobDetails {
     /// <p>The data to be returned by the third party job worker.</p>
     #[serde(rename = "data")]
@@ -1806,7 +2993,7 @@ pub struct ThirdPartyJobDetails {
 
 /// <p>Represents information about the state of transitions between one stage and another stage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TransitionState {
     /// <p>The user-specified reason why the transition between two stages of a pipeline was disabled.</p>
     #[serde(rename = "disabledReason")]
@@ -1827,6 +3014,7 @@ pub struct TransitionState {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p> The Amazon Resource Name (ARN) of the resource to remove tags from.</p>
     #[serde(rename = "resourceArn")]
@@ -1836,21 +3024,43 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:aws(-[\\w]+)*:codepipeline:.+:[0-9]{12}:.+",
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceOutput {}
 
 /// <p>Represents the input of an <code>UpdatePipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePipelineInput {
     /// <p>The name of the pipeline to be updated.</p>
     #[serde(rename = "pipeline")]
     pub pipeline: PipelineDeclaration,
 }
 
+impl Validate for UpdatePipelineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.pipeline
+            .validate_at(&field_path(path, "pipeline"), errors);
+    }
+}
+
 /// <p>Represents the output of an <code>UpdatePipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePipelineOutput {
     /// <p>The structure of the updated pipeline.</p>
     #[serde(rename = "pipeline")]
@@ -1871,6 +3081,27 @@ pub struct WebhookAuthConfiguration {
     pub secret_token: Option<String>,
 }
 
+impl Validate for WebhookAuthConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.allowed_ip_range {
+            errors.check_length(
+                &field_path(path, "allowed_ip_range"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.secret_token {
+            errors.check_length(
+                &field_path(path, "secret_token"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Represents information about a webhook and its definition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebhookDefinition {
@@ -1894,6 +3125,52 @@ pub struct WebhookDefinition {
     pub target_pipeline: String,
 }
 
+impl Validate for WebhookDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.authentication_configuration
+            .validate_at(&field_path(path, "authentication_configuration"), errors);
+        errors.check_length(
+            &field_path(path, "filters"),
+            self.filters.len(),
+            None,
+            Some(5),
+        );
+        for (i, item) in self.filters.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[A-Za-z0-9.@\\-_]+");
+        errors.check_length(
+            &field_path(path, "target_action"),
+            self.target_action.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_action"),
+            &self.target_action,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+        errors.check_length(
+            &field_path(path, "target_pipeline"),
+            self.target_pipeline.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_pipeline"),
+            &self.target_pipeline,
+            "[A-Za-z0-9.@\\-_]+",
+        );
+    }
+}
+
 /// <p>The event criteria that specify when a webhook notification is sent to your URL.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebhookFilterRule {
@@ -1906,6 +3183,25 @@ pub struct WebhookFilterRule {
     pub match_equals: Option<String>,
 }
 
+impl Validate for WebhookFilterRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "json_path"),
+            self.json_path.chars().count(),
+            Some(1),
+            Some(150),
+        );
+        if let Some(ref value) = self.match_equals {
+            errors.check_length(
+                &field_path(path, "match_equals"),
+                value.chars().count(),
+                Some(1),
+                Some(150),
+            );
+        }
+    }
+}
+
 /// Errors returned by AcknowledgeJob
 #[derive(Debug, PartialEq)]
 pub enum AcknowledgeJobError {
@@ -1925,7 +3221,12 @@ impl AcknowledgeJobError {
                 "JobNotFoundException" => {
                     return RusotoError::Service(AcknowledgeJobError::JobNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1975,7 +3276,12 @@ impl AcknowledgeThirdPartyJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2029,7 +3335,12 @@ impl CreateCustomActionTypeError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(CreateCustomActionTypeError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2113,7 +3424,12 @@ impl CreatePipelineError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(CreatePipelineError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2156,7 +3472,12 @@ impl DeleteCustomActionTypeError {
                         DeleteCustomActionTypeError::ConcurrentModification(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2191,7 +3512,12 @@ impl DeletePipelineError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2226,7 +3552,12 @@ impl DeleteWebhookError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2263,7 +3594,12 @@ impl DeregisterWebhookWithThirdPartyError {
                         DeregisterWebhookWithThirdPartyError::WebhookNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2305,7 +3641,12 @@ impl DisableStageTransitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2346,7 +3687,12 @@ impl EnableStageTransitionError {
                 "StageNotFoundException" => {
                     return RusotoError::Service(EnableStageTransitionError::StageNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2380,7 +3726,12 @@ impl GetJobDetailsError {
                 "JobNotFoundException" => {
                     return RusotoError::Service(GetJobDetailsError::JobNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2418,7 +3769,12 @@ impl GetPipelineError {
                 "PipelineVersionNotFoundException" => {
                     return RusotoError::Service(GetPipelineError::PipelineVersionNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2461,7 +3817,12 @@ impl GetPipelineExecutionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2495,7 +3856,12 @@ impl GetPipelineStateError {
                 "PipelineNotFoundException" => {
                     return RusotoError::Service(GetPipelineStateError::PipelineNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2540,7 +3906,12 @@ impl GetThirdPartyJobDetailsError {
                 "JobNotFoundException" => {
                     return RusotoError::Service(GetThirdPartyJobDetailsError::JobNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2591,7 +3962,12 @@ impl ListActionExecutionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2626,7 +4002,12 @@ impl ListActionTypesError {
                 "InvalidNextTokenException" => {
                     return RusotoError::Service(ListActionTypesError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2668,7 +4049,12 @@ impl ListPipelineExecutionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2702,7 +4088,12 @@ impl ListPipelinesError {
                 "InvalidNextTokenException" => {
                     return RusotoError::Service(ListPipelinesError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2749,7 +4140,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2784,7 +4180,12 @@ impl ListWebhooksError {
                 "InvalidNextTokenException" => {
                     return RusotoError::Service(ListWebhooksError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2817,7 +4218,12 @@ impl PollForJobsError {
                 "ActionTypeNotFoundException" => {
                     return RusotoError::Service(PollForJobsError::ActionTypeNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2852,7 +4258,12 @@ impl PollForThirdPartyJobsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2895,7 +4306,12 @@ impl PutActionRevisionError {
                 "StageNotFoundException" => {
                     return RusotoError::Service(PutActionRevisionError::StageNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2954,7 +4370,12 @@ impl PutApprovalResultError {
                 "StageNotFoundException" => {
                     return RusotoError::Service(PutApprovalResultError::StageNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2996,7 +4417,12 @@ impl PutJobFailureResultError {
                 "JobNotFoundException" => {
                     return RusotoError::Service(PutJobFailureResultError::JobNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3035,7 +4461,12 @@ impl PutJobSuccessResultError {
                 "JobNotFoundException" => {
                     return RusotoError::Service(PutJobSuccessResultError::JobNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3087,7 +4518,12 @@ impl PutThirdPartyJobFailureResultError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3140,7 +4576,12 @@ impl PutThirdPartyJobSuccessResultError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3209,7 +4650,12 @@ impl PutWebhookError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(PutWebhookError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3252,7 +4698,12 @@ impl RegisterWebhookWithThirdPartyError {
                         RegisterWebhookWithThirdPartyError::WebhookNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3306,7 +4757,12 @@ impl RetryStageExecutionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3344,7 +4800,12 @@ impl StartPipelineExecutionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3397,7 +4858,12 @@ impl TagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3451,7 +4917,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3513,7 +4984,12 @@ impl UpdatePipelineError {
                 "LimitExceededException" => {
                     return RusotoError::Service(UpdatePipelineError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3751,1069 +5227,3264 @@ pub trait CodePipeline {
         input: UpdatePipelineInput,
     ) -> RusotoFuture<UpdatePipelineOutput, UpdatePipelineError>;
 }
-/// A client for the CodePipeline API.
-#[derive(Clone)]
-pub struct CodePipelineClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CodePipelineClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CodePipelineClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CodePipelineClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CodePipelineClient {
-        CodePipelineClient { client, region }
-    }
-}
-
-impl CodePipeline for CodePipelineClient {
-    /// <p>Returns information about a specified job and whether that job has been received by the job worker. Only used for custom actions.</p>
+const _: Option<&dyn CodePipeline> = None;
+impl<'a, T: CodePipeline + ?Sized> CodePipeline for &'a T {
     fn acknowledge_job(
         &self,
         input: AcknowledgeJobInput,
     ) -> RusotoFuture<AcknowledgeJobOutput, AcknowledgeJobError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.AcknowledgeJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AcknowledgeJobOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AcknowledgeJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).acknowledge_job(input)
     }
-
-    /// <p>Confirms a job worker has received the specified job. Only used for partner actions.</p>
     fn acknowledge_third_party_job(
         &self,
         input: AcknowledgeThirdPartyJobInput,
     ) -> RusotoFuture<AcknowledgeThirdPartyJobOutput, AcknowledgeThirdPartyJobError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.AcknowledgeThirdPartyJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AcknowledgeThirdPartyJobOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AcknowledgeThirdPartyJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).acknowledge_third_party_job(input)
     }
-
-    /// <p>Creates a new custom action that can be used in all pipelines associated with the AWS account. Only used for custom actions.</p>
     fn create_custom_action_type(
         &self,
         input: CreateCustomActionTypeInput,
     ) -> RusotoFuture<CreateCustomActionTypeOutput, CreateCustomActionTypeError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.CreateCustomActionType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCustomActionTypeOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateCustomActionTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_custom_action_type(input)
     }
-
-    /// <p><p>Creates a pipeline.</p> <note> <p>In the pipeline structure, you must include either <code>artifactStore</code> or <code>artifactStores</code> in your pipeline, but you cannot use both. If you create a cross-region action in your pipeline, you must use <code>artifactStores</code>.</p> </note></p>
     fn create_pipeline(
         &self,
         input: CreatePipelineInput,
     ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.CreatePipeline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePipelineOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreatePipelineError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_pipeline(input)
     }
-
-    /// <p><p>Marks a custom action as deleted. <code>PollForJobs</code> for the custom action will fail after the action is marked for deletion. Only used for custom actions.</p> <important> <p>To re-create a custom action after it has been deleted you must use a string in the version field that has never been used before. This string can be an incremented version number, for example. To restore a deleted custom action, use a JSON file that is identical to the deleted action, including the original string in the version field.</p> </important></p>
     fn delete_custom_action_type(
         &self,
         input: DeleteCustomActionTypeInput,
     ) -> RusotoFuture<(), DeleteCustomActionTypeError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.DeleteCustomActionType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteCustomActionTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_custom_action_type(input)
     }
-
-    /// <p>Deletes the specified pipeline.</p>
     fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.DeletePipeline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeletePipelineError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_pipeline(input)
     }
-
-    /// <p>Deletes a previously created webhook by name. Deleting the webhook stops AWS CodePipeline from starting a pipeline every time an external event occurs. The API will return successfully when trying to delete a webhook that is already deleted. If a deleted webhook is re-created by calling PutWebhook with the same name, it will have a different URL.</p>
     fn delete_webhook(
         &self,
         input: DeleteWebhookInput,
     ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.DeleteWebhook");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteWebhookOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteWebhookError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_webhook(input)
     }
-
-    /// <p>Removes the connection between the webhook that was created by CodePipeline and the external tool with events to be detected. Currently only supported for webhooks that target an action type of GitHub.</p>
     fn deregister_webhook_with_third_party(
         &self,
         input: DeregisterWebhookWithThirdPartyInput,
     ) -> RusotoFuture<DeregisterWebhookWithThirdPartyOutput, DeregisterWebhookWithThirdPartyError>
     {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.DeregisterWebhookWithThirdParty",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterWebhookWithThirdPartyOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterWebhookWithThirdPartyError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).deregister_webhook_with_third_party(input)
     }
-
-    /// <p>Prevents artifacts in a pipeline from transitioning to the next stage in the pipeline.</p>
     fn disable_stage_transition(
         &self,
         input: DisableStageTransitionInput,
     ) -> RusotoFuture<(), DisableStageTransitionError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.DisableStageTransition",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DisableStageTransitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).disable_stage_transition(input)
     }
-
-    /// <p>Enables artifacts in a pipeline to transition to a stage in a pipeline.</p>
     fn enable_stage_transition(
         &self,
         input: EnableStageTransitionInput,
     ) -> RusotoFuture<(), EnableStageTransitionError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.EnableStageTransition",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(EnableStageTransitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).enable_stage_transition(input)
     }
-
-    /// <p><p>Returns information about a job. Only used for custom actions.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the Amazon S3 bucket used to store artifacts for the pipeline, if the action requires access to that Amazon S3 bucket for input or output artifacts. Additionally, this API returns any secret values defined for the action.</p> </important></p>
     fn get_job_details(
         &self,
         input: GetJobDetailsInput,
     ) -> RusotoFuture<GetJobDetailsOutput, GetJobDetailsError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.GetJobDetails");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetJobDetailsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetJobDetailsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_job_details(input)
     }
-
-    /// <p>Returns the metadata, structure, stages, and actions of a pipeline. Can be used to return the entire structure of a pipeline in JSON format, which can then be modified and used to update the pipeline structure with <a>UpdatePipeline</a>.</p>
     fn get_pipeline(
         &self,
         input: GetPipelineInput,
     ) -> RusotoFuture<GetPipelineOutput, GetPipelineError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.GetPipeline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPipelineOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPipelineError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_pipeline(input)
     }
-
-    /// <p>Returns information about an execution of a pipeline, including details about artifacts, the pipeline execution ID, and the name, version, and status of the pipeline.</p>
     fn get_pipeline_execution(
         &self,
         input: GetPipelineExecutionInput,
     ) -> RusotoFuture<GetPipelineExecutionOutput, GetPipelineExecutionError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineExecution");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPipelineExecutionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetPipelineExecutionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_pipeline_execution(input)
     }
-
-    /// <p><p>Returns information about the state of a pipeline, including the stages and actions.</p> <note> <p>Values returned in the <code>revisionId</code> and <code>revisionUrl</code> fields indicate the source revision information, such as the commit ID, for the current state.</p> </note></p>
     fn get_pipeline_state(
         &self,
         input: GetPipelineStateInput,
     ) -> RusotoFuture<GetPipelineStateOutput, GetPipelineStateError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineState");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPipelineStateOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPipelineStateError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_pipeline_state(input)
     }
-
-    /// <p><p>Requests the details of a job for a third party action. Only used for partner actions.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the Amazon S3 bucket used to store artifacts for the pipeline, if the action requires access to that Amazon S3 bucket for input or output artifacts. Additionally, this API returns any secret values defined for the action.</p> </important></p>
     fn get_third_party_job_details(
         &self,
         input: GetThirdPartyJobDetailsInput,
     ) -> RusotoFuture<GetThirdPartyJobDetailsOutput, GetThirdPartyJobDetailsError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.GetThirdPartyJobDetails",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetThirdPartyJobDetailsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetThirdPartyJobDetailsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_third_party_job_details(input)
     }
-
-    /// <p>Lists the action executions that have occurred in a pipeline.</p>
     fn list_action_executions(
         &self,
         input: ListActionExecutionsInput,
     ) -> RusotoFuture<ListActionExecutionsOutput, ListActionExecutionsError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.ListActionExecutions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListActionExecutionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListActionExecutionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_action_executions(input)
     }
-
-    /// <p>Gets a summary of all AWS CodePipeline action types associated with your account.</p>
     fn list_action_types(
         &self,
         input: ListActionTypesInput,
     ) -> RusotoFuture<ListActionTypesOutput, ListActionTypesError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.ListActionTypes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListActionTypesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListActionTypesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_action_types(input)
     }
-
-    /// <p>Gets a summary of the most recent executions for a pipeline.</p>
     fn list_pipeline_executions(
         &self,
         input: ListPipelineExecutionsInput,
     ) -> RusotoFuture<ListPipelineExecutionsOutput, ListPipelineExecutionsError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.ListPipelineExecutions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPipelineExecutionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListPipelineExecutionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_pipeline_executions(input)
     }
-
-    /// <p>Gets a summary of all of the pipelines associated with your account.</p>
     fn list_pipelines(
         &self,
         input: ListPipelinesInput,
     ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.ListPipelines");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPipelinesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListPipelinesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_pipelines(input)
     }
-
-    /// <p>Gets the set of key/value pairs (metadata) that are used to manage the resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Gets a listing of all the webhooks in this region for this account. The output lists all webhooks and includes the webhook URL and ARN, as well the configuration for each webhook.</p>
     fn list_webhooks(
         &self,
         input: ListWebhooksInput,
     ) -> RusotoFuture<ListWebhooksOutput, ListWebhooksError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.ListWebhooks");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListWebhooksOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListWebhooksError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_webhooks(input)
     }
-
-    /// <p><p>Returns information about any jobs for AWS CodePipeline to act upon. <code>PollForJobs</code> is only valid for action types with &quot;Custom&quot; in the owner field. If the action type contains &quot;AWS&quot; or &quot;ThirdParty&quot; in the owner field, the <code>PollForJobs</code> action returns an error.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the Amazon S3 bucket used to store artifacts for the pipeline, if the action requires access to that Amazon S3 bucket for input or output artifacts. Additionally, this API returns any secret values defined for the action.</p> </important></p>
     fn poll_for_jobs(
         &self,
         input: PollForJobsInput,
     ) -> RusotoFuture<PollForJobsOutput, PollForJobsError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.PollForJobs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PollForJobsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PollForJobsError::from_response(response))),
-                )
-            }
-        })
+        (**self).poll_for_jobs(input)
     }
-
-    /// <p><p>Determines whether there are any third party jobs for a job worker to act on. Only used for partner actions.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the Amazon S3 bucket used to store artifacts for the pipeline, if the action requires access to that Amazon S3 bucket for input or output artifacts.</p> </important></p>
     fn poll_for_third_party_jobs(
         &self,
         input: PollForThirdPartyJobsInput,
     ) -> RusotoFuture<PollForThirdPartyJobsOutput, PollForThirdPartyJobsError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.PollForThirdPartyJobs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PollForThirdPartyJobsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PollForThirdPartyJobsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).poll_for_third_party_jobs(input)
     }
-
-    /// <p>Provides information to AWS CodePipeline about new revisions to a source.</p>
     fn put_action_revision(
         &self,
         input: PutActionRevisionInput,
     ) -> RusotoFuture<PutActionRevisionOutput, PutActionRevisionError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.PutActionRevision");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutActionRevisionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutActionRevisionError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_action_revision(input)
     }
-
-    /// <p>Provides the response to a manual approval request to AWS CodePipeline. Valid responses include Approved and Rejected.</p>
     fn put_approval_result(
         &self,
         input: PutApprovalResultInput,
     ) -> RusotoFuture<PutApprovalResultOutput, PutApprovalResultError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.PutApprovalResult");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutApprovalResultOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutApprovalResultError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_approval_result(input)
+    }
+    fn put_job_failure_result(
+        &self,
+        input: PutJobFailureResultInput,
+    ) -> RusotoFuture<(), PutJobFailureResultError> {
+        (**self).put_job_failure_result(input)
+    }
+    fn put_job_success_result(
+        &self,
+        input: PutJobSuccessResultInput,
+    ) -> RusotoFuture<(), PutJobSuccessResultError> {
+        (**self).put_job_success_result(input)
+    }
+    fn put_third_party_job_failure_result(
+        &self,
+        input: PutThirdPartyJobFailureResultInput,
+    ) -> RusotoFuture<(), PutThirdPartyJobFailureResultError> {
+        (**self).put_third_party_job_failure_result(input)
+    }
+    fn put_third_party_job_success_result(
+        &self,
+        input: PutThirdPartyJobSuccessResultInput,
+    ) -> RusotoFuture<(), PutThirdPartyJobSuccessResultError> {
+        (**self).put_third_party_job_success_result(input)
+    }
+    fn put_webhook(
+        &self,
+        input: PutWebhookInput,
+    ) -> RusotoFuture<PutWebhookOutput, PutWebhookError> {
+        (**self).put_webhook(input)
+    }
+    fn register_webhook_with_third_party(
+        &self,
+        input: RegisterWebhookWithThirdPartyInput,
+    ) -> RusotoFuture<RegisterWebhookWithThirdPartyOutput, RegisterWebhookWithThirdPartyError> {
+        (**self).register_webhook_with_third_party(input)
+    }
+    fn retry_stage_execution(
+        &self,
+        input: RetryStageExecutionInput,
+    ) -> RusotoFuture<RetryStageExecutionOutput, RetryStageExecutionError> {
+        (**self).retry_stage_execution(input)
+    }
+    fn start_pipeline_execution(
+        &self,
+        input: StartPipelineExecutionInput,
+    ) -> RusotoFuture<StartPipelineExecutionOutput, StartPipelineExecutionError> {
+        (**self).start_pipeline_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineInput,
+    ) -> RusotoFuture<UpdatePipelineOutput, UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+}
+impl<T: CodePipeline + ?Sized> CodePipeline for Box<T> {
+    fn acknowledge_job(
+        &self,
+        input: AcknowledgeJobInput,
+    ) -> RusotoFuture<AcknowledgeJobOutput, AcknowledgeJobError> {
+        (**self).acknowledge_job(input)
+    }
+    fn acknowledge_third_party_job(
+        &self,
+        input: AcknowledgeThirdPartyJobInput,
+    ) -> RusotoFuture<AcknowledgeThirdPartyJobOutput, AcknowledgeThirdPartyJobError> {
+        (**self).acknowledge_third_party_job(input)
+    }
+    fn create_custom_action_type(
+        &self,
+        input: CreateCustomActionTypeInput,
+    ) -> RusotoFuture<CreateCustomActionTypeOutput, CreateCustomActionTypeError> {
+        (**self).create_custom_action_type(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn delete_custom_action_type(
+        &self,
+        input: DeleteCustomActionTypeInput,
+    ) -> RusotoFuture<(), DeleteCustomActionTypeError> {
+        (**self).delete_custom_action_type(input)
+    }
+    fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn deregister_webhook_with_third_party(
+        &self,
+        input: DeregisterWebhookWithThirdPartyInput,
+    ) -> RusotoFuture<DeregisterWebhookWithThirdPartyOutput, DeregisterWebhookWithThirdPartyError>
+    {
+        (**self).deregister_webhook_with_third_party(input)
+    }
+    fn disable_stage_transition(
+        &self,
+        input: DisableStageTransitionInput,
+    ) -> RusotoFuture<(), DisableStageTransitionError> {
+        (**self).disable_stage_transition(input)
+    }
+    fn enable_stage_transition(
+        &self,
+        input: EnableStageTransitionInput,
+    ) -> RusotoFuture<(), EnableStageTransitionError> {
+        (**self).enable_stage_transition(input)
+    }
+    fn get_job_details(
+        &self,
+        input: GetJobDetailsInput,
+    ) -> RusotoFuture<GetJobDetailsOutput, GetJobDetailsError> {
+        (**self).get_job_details(input)
+    }
+    fn get_pipeline(
+        &self,
+        input: GetPipelineInput,
+    ) -> RusotoFuture<GetPipelineOutput, GetPipelineError> {
+        (**self).get_pipeline(input)
+    }
+    fn get_pipeline_execution(
+        &self,
+        input: GetPipelineExecutionInput,
+    ) -> RusotoFuture<GetPipelineExecutionOutput, GetPipelineExecutionError> {
+        (**self).get_pipeline_execution(input)
+    }
+    fn get_pipeline_state(
+        &self,
+        input: GetPipelineStateInput,
+    ) -> RusotoFuture<GetPipelineStateOutput, GetPipelineStateError> {
+        (**self).get_pipeline_state(input)
+    }
+    fn get_third_party_job_details(
+        &self,
+        input: GetThirdPartyJobDetailsInput,
+    ) -> RusotoFuture<GetThirdPartyJobDetailsOutput, GetThirdPartyJobDetailsError> {
+        (**self).get_third_party_job_details(input)
+    }
+    fn list_action_executions(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> RusotoFuture<ListActionExecutionsOutput, ListActionExecutionsError> {
+        (**self).list_action_executions(input)
+    }
+    fn list_action_types(
+        &self,
+        input: ListActionTypesInput,
+    ) -> RusotoFuture<ListActionTypesOutput, ListActionTypesError> {
+        (**self).list_action_types(input)
+    }
+    fn list_pipeline_executions(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> RusotoFuture<ListPipelineExecutionsOutput, ListPipelineExecutionsError> {
+        (**self).list_pipeline_executions(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_webhooks(
+        &self,
+        input: ListWebhooksInput,
+    ) -> RusotoFuture<ListWebhooksOutput, ListWebhooksError> {
+        (**self).list_webhooks(input)
+    }
+    fn poll_for_jobs(
+        &self,
+        input: PollForJobsInput,
+    ) -> RusotoFuture<PollForJobsOutput, PollForJobsError> {
+        (**self).poll_for_jobs(input)
+    }
+    fn poll_for_third_party_jobs(
+        &self,
+        input: PollForThirdPartyJobsInput,
+    ) -> RusotoFuture<PollForThirdPartyJobsOutput, PollForThirdPartyJobsError> {
+        (**self).poll_for_third_party_jobs(input)
+    }
+    fn put_action_revision(
+        &self,
+        input: PutActionRevisionInput,
+    ) -> RusotoFuture<PutActionRevisionOutput, PutActionRevisionError> {
+        (**self).put_action_revision(input)
+    }
+    fn put_approval_result(
+        &self,
+        input: PutApprovalResultInput,
+    ) -> RusotoFuture<PutApprovalResultOutput, PutApprovalResultError> {
+        (**self).put_approval_result(input)
+    }
+    fn put_job_failure_result(
+        &self,
+        input: PutJobFailureResultInput,
+    ) -> RusotoFuture<(), PutJobFailureResultError> {
+        (**self).put_job_failure_result(input)
+    }
+    fn put_job_success_result(
+        &self,
+        input: PutJobSuccessResultInput,
+    ) -> RusotoFuture<(), PutJobSuccessResultError> {
+        (**self).put_job_success_result(input)
+    }
+    fn put_third_party_job_failure_result(
+        &self,
+        input: PutThirdPartyJobFailureResultInput,
+    ) -> RusotoFuture<(), PutThirdPartyJobFailureResultError> {
+        (**self).put_third_party_job_failure_result(input)
+    }
+    fn put_third_party_job_success_result(
+        &self,
+        input: PutThirdPartyJobSuccessResultInput,
+    ) -> RusotoFuture<(), PutThirdPartyJobSuccessResultError> {
+        (**self).put_third_party_job_success_result(input)
+    }
+    fn put_webhook(
+        &self,
+        input: PutWebhookInput,
+    ) -> RusotoFuture<PutWebhookOutput, PutWebhookError> {
+        (**self).put_webhook(input)
+    }
+    fn register_webhook_with_third_party(
+        &self,
+        input: RegisterWebhookWithThirdPartyInput,
+    ) -> RusotoFuture<RegisterWebhookWithThirdPartyOutput, RegisterWebhookWithThirdPartyError> {
+        (**self).register_webhook_with_third_party(input)
+    }
+    fn retry_stage_execution(
+        &self,
+        input: RetryStageExecutionInput,
+    ) -> RusotoFuture<RetryStageExecutionOutput, RetryStageExecutionError> {
+        (**self).retry_stage_execution(input)
+    }
+    fn start_pipeline_execution(
+        &self,
+        input: StartPipelineExecutionInput,
+    ) -> RusotoFuture<StartPipelineExecutionOutput, StartPipelineExecutionError> {
+        (**self).start_pipeline_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineInput,
+    ) -> RusotoFuture<UpdatePipelineOutput, UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+}
+impl<T: CodePipeline + ?Sized> CodePipeline for ::std::sync::Arc<T> {
+    fn acknowledge_job(
+        &self,
+        input: AcknowledgeJobInput,
+    ) -> RusotoFuture<AcknowledgeJobOutput, AcknowledgeJobError> {
+        (**self).acknowledge_job(input)
+    }
+    fn acknowledge_third_party_job(
+        &self,
+        input: AcknowledgeThirdPartyJobInput,
+    ) -> RusotoFuture<AcknowledgeThirdPartyJobOutput, AcknowledgeThirdPartyJobError> {
+        (**self).acknowledge_third_party_job(input)
+    }
+    fn create_custom_action_type(
+        &self,
+        input: CreateCustomActionTypeInput,
+    ) -> RusotoFuture<CreateCustomActionTypeOutput, CreateCustomActionTypeError> {
+        (**self).create_custom_action_type(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn delete_custom_action_type(
+        &self,
+        input: DeleteCustomActionTypeInput,
+    ) -> RusotoFuture<(), DeleteCustomActionTypeError> {
+        (**self).delete_custom_action_type(input)
+    }
+    fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn deregister_webhook_with_third_party(
+        &self,
+        input: DeregisterWebhookWithThirdPartyInput,
+    ) -> RusotoFuture<DeregisterWebhookWithThirdPartyOutput, DeregisterWebhookWithThirdPartyError>
+    {
+        (**self).deregister_webhook_with_third_party(input)
+    }
+    fn disable_stage_transition(
+        &self,
+        input: DisableStageTransitionInput,
+    ) -> RusotoFuture<(), DisableStageTransitionError> {
+        (**self).disable_stage_transition(input)
+    }
+    fn enable_stage_transition(
+        &self,
+        input: EnableStageTransitionInput,
+    ) -> RusotoFuture<(), EnableStageTransitionError> {
+        (**self).enable_stage_transition(input)
+    }
+    fn get_job_details(
+        &self,
+        input: GetJobDetailsInput,
+    ) -> RusotoFuture<GetJobDetailsOutput, GetJobDetailsError> {
+        (**self).get_job_details(input)
+    }
+    fn get_pipeline(
+        &self,
+        input: GetPipelineInput,
+    ) -> RusotoFuture<GetPipelineOutput, GetPipelineError> {
+        (**self).get_pipeline(input)
+    }
+    fn get_pipeline_execution(
+        &self,
+        input: GetPipelineExecutionInput,
+    ) -> RusotoFuture<GetPipelineExecutionOutput, GetPipelineExecutionError> {
+        (**self).get_pipeline_execution(input)
+    }
+    fn get_pipeline_state(
+        &self,
+        input: GetPipelineStateInput,
+    ) -> RusotoFuture<GetPipelineStateOutput, GetPipelineStateError> {
+        (**self).get_pipeline_state(input)
+    }
+    fn get_third_party_job_details(
+        &self,
+        input: GetThirdPartyJobDetailsInput,
+    ) -> RusotoFuture<GetThirdPartyJobDetailsOutput, GetThirdPartyJobDetailsError> {
+        (**self).get_third_party_job_details(input)
+    }
+    fn list_action_executions(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> RusotoFuture<ListActionExecutionsOutput, ListActionExecutionsError> {
+        (**self).list_action_executions(input)
+    }
+    fn list_action_types(
+        &self,
+        input: ListActionTypesInput,
+    ) -> RusotoFuture<ListActionTypesOutput, ListActionTypesError> {
+        (**self).list_action_types(input)
+    }
+    fn list_pipeline_executions(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> RusotoFuture<ListPipelineExecutionsOutput, ListPipelineExecutionsError> {
+        (**self).list_pipeline_executions(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_webhooks(
+        &self,
+        input: ListWebhooksInput,
+    ) -> RusotoFuture<ListWebhooksOutput, ListWebhooksError> {
+        (**self).list_webhooks(input)
+    }
+    fn poll_for_jobs(
+        &self,
+        input: PollForJobsInput,
+    ) -> RusotoFuture<PollForJobsOutput, PollForJobsError> {
+        (**self).poll_for_jobs(input)
+    }
+    fn poll_for_third_party_jobs(
+        &self,
+        input: PollForThirdPartyJobsInput,
+    ) -> RusotoFuture<PollForThirdPartyJobsOutput, PollForThirdPartyJobsError> {
+        (**self).poll_for_third_party_jobs(input)
+    }
+    fn put_action_revision(
+        &self,
+        input: PutActionRevisionInput,
+    ) -> RusotoFuture<PutActionRevisionOutput, PutActionRevisionError> {
+        (**self).put_action_revision(input)
+    }
+    fn put_approval_result(
+        &self,
+        input: PutApprovalResultInput,
+    ) -> RusotoFuture<PutApprovalResultOutput, PutApprovalResultError> {
+        (**self).put_approval_result(input)
+    }
+    fn put_job_failure_result(
+        &self,
+        input: PutJobFailureResultInput,
+    ) -> RusotoFuture<(), PutJobFailureResultError> {
+        (**self).put_job_failure_result(input)
+    }
+    fn put_job_success_result(
+        &self,
+        input: PutJobSuccessResultInput,
+    ) -> RusotoFuture<(), PutJobSuccessResultError> {
+        (**self).put_job_success_result(input)
+    }
+    fn put_third_party_job_failure_result(
+        &self,
+        input: PutThirdPartyJobFailureResultInput,
+    ) -> RusotoFuture<(), PutThirdPartyJobFailureResultError> {
+        (**self).put_third_party_job_failure_result(input)
+    }
+    fn put_third_party_job_success_result(
+        &self,
+        input: PutThirdPartyJobSuccessResultInput,
+    ) -> RusotoFuture<(), PutThirdPartyJobSuccessResultError> {
+        (**self).put_third_party_job_success_result(input)
+    }
+    fn put_webhook(
+        &self,
+        input: PutWebhookInput,
+    ) -> RusotoFuture<PutWebhookOutput, PutWebhookError> {
+        (**self).put_webhook(input)
+    }
+    fn register_webhook_with_third_party(
+        &self,
+        input: RegisterWebhookWithThirdPartyInput,
+    ) -> RusotoFuture<RegisterWebhookWithThirdPartyOutput, RegisterWebhookWithThirdPartyError> {
+        (**self).register_webhook_with_third_party(input)
+    }
+    fn retry_stage_execution(
+        &self,
+        input: RetryStageExecutionInput,
+    ) -> RusotoFuture<RetryStageExecutionOutput, RetryStageExecutionError> {
+        (**self).retry_stage_execution(input)
+    }
+    fn start_pipeline_execution(
+        &self,
+        input: StartPipelineExecutionInput,
+    ) -> RusotoFuture<StartPipelineExecutionOutput, StartPipelineExecutionError> {
+        (**self).start_pipeline_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineInput,
+    ) -> RusotoFuture<UpdatePipelineOutput, UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+}
+/// A client for the CodePipeline API.
+#[derive(Clone)]
+pub struct CodePipelineClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CodePipelineClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodePipelineClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodePipelineClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CodePipelineClient {
+        CodePipelineClient { client, region }
+    }
+}
+
+impl CodePipeline for CodePipelineClient {
+    /// <p>Returns information about a specified job and whether that job has been received by the job worker. Only used for custom actions.</p>
+    fn acknowledge_job(
+        &self,
+        input: AcknowledgeJobInput,
+    ) -> RusotoFuture<AcknowledgeJobOutput, AcknowledgeJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("AcknowledgeJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.AcknowledgeJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AcknowledgeJobOutput, _>(
+                    response,
+                    "AcknowledgeJob",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AcknowledgeJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Confirms a job worker has received the specified job. Only used for partner actions.</p>
+    fn acknowledge_third_party_job(
+        &self,
+        input: AcknowledgeThirdPartyJobInput,
+    ) -> RusotoFuture<AcknowledgeThirdPartyJobOutput, AcknowledgeThirdPartyJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("AcknowledgeThirdPartyJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.AcknowledgeThirdPartyJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AcknowledgeThirdPartyJobOutput, _>(
+                    response,
+                    "AcknowledgeThirdPartyJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AcknowledgeThirdPartyJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a new custom action that can be used in all pipelines associated with the AWS account. Only used for custom actions.</p>
+    fn create_custom_action_type(
+        &self,
+        input: CreateCustomActionTypeInput,
+    ) -> RusotoFuture<CreateCustomActionTypeOutput, CreateCustomActionTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("CreateCustomActionType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.CreateCustomActionType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateCustomActionTypeOutput, _>(
+                    response,
+                    "CreateCustomActionType",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateCustomActionTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a pipeline.</p> <note> <p>In the pipeline structure, you must include either <code>artifactStore</code> or <code>artifactStores</code> in your pipeline, but you cannot use both. If you create a cross-region action in your pipeline, you must use <code>artifactStores</code>.</p> </note></p>
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("CreatePipeline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.CreatePipeline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePipelineOutput, _>(
+                    response,
+                    "CreatePipeline",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreatePipelineError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Marks a custom action as deleted. <code>PollForJobs</code> for the custom action will fail after the action is marked for deletion. Only used for custom actions.</p> <important> <p>To re-create a custom action after it has been deleted you must use a string in the version field that has never been used before. This string can be an incremented version number, for example. To restore a deleted custom action, use a JSON file that is identical to the deleted action, including the original string in the version field.</p> </important></p>
+    fn delete_custom_action_type(
+        &self,
+        input: DeleteCustomActionTypeInput,
+    ) -> RusotoFuture<(), DeleteCustomActionTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeleteCustomActionType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.DeleteCustomActionType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteCustomActionTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified pipeline.</p>
+    fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeletePipeline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.DeletePipeline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeletePipelineError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a previously created webhook by name. Deleting the webhook stops AWS CodePipeline from starting a pipeline every time an external event occurs. The API will return successfully when trying to delete a webhook that is already deleted. If a deleted webhook is re-created by calling PutWebhook with the same name, it will have a different URL.</p>
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeleteWebhook");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.DeleteWebhook");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteWebhookOutput, _>(
+                    response,
+                    "DeleteWebhook",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteWebhookError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the connection between the webhook that was created by CodePipeline and the external tool with events to be detected. Currently only supported for webhooks that target an action type of GitHub.</p>
+    fn deregister_webhook_with_third_party(
+        &self,
+        input: DeregisterWebhookWithThirdPartyInput,
+    ) -> RusotoFuture<DeregisterWebhookWithThirdPartyOutput, DeregisterWebhookWithThirdPartyError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeregisterWebhookWithThirdParty");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.DeregisterWebhookWithThirdParty",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeregisterWebhookWithThirdPartyOutput, _>(
+                    response,
+                    "DeregisterWebhookWithThirdParty",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterWebhookWithThirdPartyError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Prevents artifacts in a pipeline from transitioning to the next stage in the pipeline.</p>
+    fn disable_stage_transition(
+        &self,
+        input: DisableStageTransitionInput,
+    ) -> RusotoFuture<(), DisableStageTransitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DisableStageTransition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.DisableStageTransition",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DisableStageTransitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables artifacts in a pipeline to transition to a stage in a pipeline.</p>
+    fn enable_stage_transition(
+        &self,
+        input: EnableStageTransitionInput,
+    ) -> RusotoFuture<(), EnableStageTransitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("EnableStageTransition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.EnableStageTransition",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(EnableStageTransitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Returns information about a job. Only used for custom actions.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the Amazon S3 bucket used to store artifacts for the pipeline, if the action requires access to that Amazon S3 bucket for input or output artifacts. Additionally, this API returns any secret values defined for the action.</p> </important></p>
+    fn get_job_details(
+        &self,
+        input: GetJobDetailsInput,
+    ) -> RusotoFuture<GetJobDetailsOutput, GetJobDetailsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetJobDetails");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.GetJobDetails");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetJobDetailsOutput, _>(
+                    response,
+                    "GetJobDetails",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetJobDetailsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the metadata, structure, stages, and actions of a pipeline. Can be used to return the entire structure of a pipeline in JSON format, which can then be modified and used to update the pipeline structure with <a>UpdatePipeline</a>.</p>
+    fn get_pipeline(
+        &self,
+        input: GetPipelineInput,
+    ) -> RusotoFuture<GetPipelineOutput, GetPipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetPipeline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.GetPipeline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPipelineOutput, _>(response, "GetPipeline")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPipelineError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about an execution of a pipeline, including details about artifacts, the pipeline execution ID, and the name, version, and status of the pipeline.</p>
+    fn get_pipeline_execution(
+        &self,
+        input: GetPipelineExecutionInput,
+    ) -> RusotoFuture<GetPipelineExecutionOutput, GetPipelineExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetPipelineExecution");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineExecution");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPipelineExecutionOutput, _>(
+                    response,
+                    "GetPipelineExecution",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetPipelineExecutionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Returns information about the state of a pipeline, including the stages and actions.</p> <note> <p>Values returned in the <code>revisionId</code> and <code>revisionUrl</code> fields indicate the source revision information, such as the commit ID, for the current state.</p> </note></p>
+    fn get_pipeline_state(
+        &self,
+        input: GetPipelineStateInput,
+    ) -> RusotoFuture<GetPipelineStateOutput, GetPipelineStateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetPipelineState");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineState");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPipelineStateOutput, _>(
+                    response,
+                    "GetPipelineState",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPipelineStateError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Requests the details of a job for a third party action. Only used for partner actions.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the Amazon S3 bucket used to store artifacts for the pipeline, if the action requires access to that Amazon S3 bucket for input or output artifacts. Additionally, this API returns any secret values defined for the action.</p> </important></p>
+    fn get_third_party_job_details(
+        &self,
+        input: GetThirdPartyJobDetailsInput,
+    ) -> RusotoFuture<GetThirdPartyJobDetailsOutput, GetThirdPartyJobDetailsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetThirdPartyJobDetails");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.GetThirdPartyJobDetails",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetThirdPartyJobDetailsOutput, _>(
+                    response,
+                    "GetThirdPartyJobDetails",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetThirdPartyJobDetailsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the action executions that have occurred in a pipeline.</p>
+    fn list_action_executions(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> RusotoFuture<ListActionExecutionsOutput, ListActionExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListActionExecutions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.ListActionExecutions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListActionExecutionsOutput, _>(
+                    response,
+                    "ListActionExecutions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListActionExecutionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a summary of all AWS CodePipeline action types associated with your account.</p>
+    fn list_action_types(
+        &self,
+        input: ListActionTypesInput,
+    ) -> RusotoFuture<ListActionTypesOutput, ListActionTypesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListActionTypes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.ListActionTypes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListActionTypesOutput, _>(
+                    response,
+                    "ListActionTypes",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListActionTypesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a summary of the most recent executions for a pipeline.</p>
+    fn list_pipeline_executions(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> RusotoFuture<ListPipelineExecutionsOutput, ListPipelineExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListPipelineExecutions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.ListPipelineExecutions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPipelineExecutionsOutput, _>(
+                    response,
+                    "ListPipelineExecutions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListPipelineExecutionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a summary of all of the pipelines associated with your account.</p>
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListPipelines");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.ListPipelines");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPipelinesOutput, _>(
+                    response,
+                    "ListPipelines",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListPipelinesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the set of key/value pairs (metadata) that are used to manage the resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceOutput, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a listing of all the webhooks in this region for this account. The output lists all webhooks and includes the webhook URL and ARN, as well the configuration for each webhook.</p>
+    fn list_webhooks(
+        &self,
+        input: ListWebhooksInput,
+    ) -> RusotoFuture<ListWebhooksOutput, ListWebhooksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListWebhooks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.ListWebhooks");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListWebhooksOutput, _>(
+                    response,
+                    "ListWebhooks",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListWebhooksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Returns information about any jobs for AWS CodePipeline to act upon. <code>PollForJobs</code> is only valid for action types with &quot;Custom&quot; in the owner field. If the action type contains &quot;AWS&quot; or &quot;ThirdParty&quot; in the owner field, the <code>PollForJobs</code> action returns an error.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the Amazon S3 bucket used to store artifacts for the pipeline, if the action requires access to that Amazon S3 bucket for input or output artifacts. Additionally, this API returns any secret values defined for the action.</p> </important></p>
+    fn poll_for_jobs(
+        &self,
+        input: PollForJobsInput,
+    ) -> RusotoFuture<PollForJobsOutput, PollForJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PollForJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.PollForJobs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PollForJobsOutput, _>(response, "PollForJobs")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PollForJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Determines whether there are any third party jobs for a job worker to act on. Only used for partner actions.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the Amazon S3 bucket used to store artifacts for the pipeline, if the action requires access to that Amazon S3 bucket for input or output artifacts.</p> </important></p>
+    fn poll_for_third_party_jobs(
+        &self,
+        input: PollForThirdPartyJobsInput,
+    ) -> RusotoFuture<PollForThirdPartyJobsOutput, PollForThirdPartyJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PollForThirdPartyJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.PollForThirdPartyJobs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PollForThirdPartyJobsOutput, _>(
+                    response,
+                    "PollForThirdPartyJobs",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PollForThirdPartyJobsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides information to AWS CodePipeline about new revisions to a source.</p>
+    fn put_action_revision(
+        &self,
+        input: PutActionRevisionInput,
+    ) -> RusotoFuture<PutActionRevisionOutput, PutActionRevisionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutActionRevision");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.PutActionRevision");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutActionRevisionOutput, _>(
+                    response,
+                    "PutActionRevision",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutActionRevisionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides the response to a manual approval request to AWS CodePipeline. Valid responses include Approved and Rejected.</p>
+    fn put_approval_result(
+        &self,
+        input: PutApprovalResultInput,
+    ) -> RusotoFuture<PutApprovalResultOutput, PutApprovalResultError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutApprovalResult");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.PutApprovalResult");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutApprovalResultOutput, _>(
+                    response,
+                    "PutApprovalResult",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutApprovalResultError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents the failure of a job as returned to the pipeline by a job worker. Only used for custom actions.</p>
+    fn put_job_failure_result(
+        &self,
+        input: PutJobFailureResultInput,
+    ) -> RusotoFuture<(), PutJobFailureResultError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutJobFailureResult");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.PutJobFailureResult");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutJobFailureResultError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents the success of a job as returned to the pipeline by a job worker. Only used for custom actions.</p>
+    fn put_job_success_result(
+        &self,
+        input: PutJobSuccessResultInput,
+    ) -> RusotoFuture<(), PutJobSuccessResultError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutJobSuccessResult");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.PutJobSuccessResult");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutJobSuccessResultError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Represents the failure of a third party job as returned to the pipeline by a job worker. Only used for partner actions.</p>
+    fn put_third_party_job_failure_result(
+        &self,
+        input: PutThirdPartyJobFailureResultInput,
+    ) -> RusotoFuture<(), PutThirdPartyJobFailureResultError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutThirdPartyJobFailureResult");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.PutThirdPartyJobFailureResult",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutThirdPartyJobFailureResultError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Represents the success of a third party job as returned to the pipeline by a job worker. Only used for partner actions.</p>
+    fn put_third_party_job_success_result(
+        &self,
+        input: PutThirdPartyJobSuccessResultInput,
+    ) -> RusotoFuture<(), PutThirdPartyJobSuccessResultError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutThirdPartyJobSuccessResult");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.PutThirdPartyJobSuccessResult",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutThirdPartyJobSuccessResultError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Defines a webhook and returns a unique webhook URL generated by CodePipeline. This URL can be supplied to third party source hosting providers to call every time there's a code change. When CodePipeline receives a POST request on this URL, the pipeline defined in the webhook is started as long as the POST request satisfied the authentication and filtering requirements supplied when defining the webhook. RegisterWebhookWithThirdParty and DeregisterWebhookWithThirdParty APIs can be used to automatically configure supported third parties to call the generated webhook URL.</p>
+    fn put_webhook(
+        &self,
+        input: PutWebhookInput,
+    ) -> RusotoFuture<PutWebhookOutput, PutWebhookError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutWebhook");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.PutWebhook");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutWebhookOutput, _>(response, "PutWebhook")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutWebhookError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Configures a connection between the webhook that was created and the external tool with events to be detected.</p>
+    fn register_webhook_with_third_party(
+        &self,
+        input: RegisterWebhookWithThirdPartyInput,
+    ) -> RusotoFuture<RegisterWebhookWithThirdPartyOutput, RegisterWebhookWithThirdPartyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("RegisterWebhookWithThirdParty");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.RegisterWebhookWithThirdParty",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterWebhookWithThirdPartyOutput, _>(
+                    response,
+                    "RegisterWebhookWithThirdParty",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RegisterWebhookWithThirdPartyError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Resumes the pipeline execution by retrying the last failed actions in a stage.</p>
+    fn retry_stage_execution(
+        &self,
+        input: RetryStageExecutionInput,
+    ) -> RusotoFuture<RetryStageExecutionOutput, RetryStageExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("RetryStageExecution");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.RetryStageExecution");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RetryStageExecutionOutput, _>(
+                    response,
+                    "RetryStageExecution",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RetryStageExecutionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts the specified pipeline. Specifically, it begins processing the latest commit to the source location specified as part of the pipeline.</p>
+    fn start_pipeline_execution(
+        &self,
+        input: StartPipelineExecutionInput,
+    ) -> RusotoFuture<StartPipelineExecutionOutput, StartPipelineExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("StartPipelineExecution");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "CodePipeline_20150709.StartPipelineExecution",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartPipelineExecutionOutput, _>(
+                    response,
+                    "StartPipelineExecution",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartPipelineExecutionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata that can be used to manage a resource. </p>
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceOutput, _>(response, "TagResource")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes tags from an AWS resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceOutput, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a specified pipeline with edits or changes to its structure. Use a JSON file with the pipeline structure in conjunction with <code>UpdatePipeline</code> to provide the full structure of the pipeline. Updating the pipeline increases the version number of the pipeline by 1.</p>
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineInput,
+    ) -> RusotoFuture<UpdatePipelineOutput, UpdatePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("UpdatePipeline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodePipeline_20150709.UpdatePipeline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdatePipelineOutput, _>(
+                    response,
+                    "UpdatePipeline",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdatePipelineError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the CodePipeline API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCodePipeline {
+    acknowledge_job: Option<
+        Box<
+            dyn Fn(
+                    AcknowledgeJobInput,
+                )
+                    -> ::std::result::Result<AcknowledgeJobOutput, RusotoError<AcknowledgeJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    acknowledge_third_party_job: Option<
+        Box<
+            dyn Fn(
+                    AcknowledgeThirdPartyJobInput,
+                ) -> ::std::result::Result<
+                    AcknowledgeThirdPartyJobOutput,
+                    RusotoError<AcknowledgeThirdPartyJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_custom_action_type: Option<
+        Box<
+            dyn Fn(
+                    CreateCustomActionTypeInput,
+                ) -> ::std::result::Result<
+                    CreateCustomActionTypeOutput,
+                    RusotoError<CreateCustomActionTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_pipeline: Option<
+        Box<
+            dyn Fn(
+                    CreatePipelineInput,
+                )
+                    -> ::std::result::Result<CreatePipelineOutput, RusotoError<CreatePipelineError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_custom_action_type: Option<
+        Box<
+            dyn Fn(
+                    DeleteCustomActionTypeInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteCustomActionTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_pipeline: Option<
+        Box<
+            dyn Fn(
+                    DeletePipelineInput,
+                ) -> ::std::result::Result<(), RusotoError<DeletePipelineError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_webhook: Option<
+        Box<
+            dyn Fn(
+                    DeleteWebhookInput,
+                )
+                    -> ::std::result::Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_webhook_with_third_party: Option<
+        Box<
+            dyn Fn(
+                    DeregisterWebhookWithThirdPartyInput,
+                ) -> ::std::result::Result<
+                    DeregisterWebhookWithThirdPartyOutput,
+                    RusotoError<DeregisterWebhookWithThirdPartyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_stage_transition: Option<
+        Box<
+            dyn Fn(
+                    DisableStageTransitionInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DisableStageTransitionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    enable_stage_transition: Option<
+        Box<
+            dyn Fn(
+                    EnableStageTransitionInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<EnableStageTransitionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_job_details: Option<
+        Box<
+            dyn Fn(
+                    GetJobDetailsInput,
+                )
+                    -> ::std::result::Result<GetJobDetailsOutput, RusotoError<GetJobDetailsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_pipeline: Option<
+        Box<
+            dyn Fn(
+                    GetPipelineInput,
+                )
+                    -> ::std::result::Result<GetPipelineOutput, RusotoError<GetPipelineError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_pipeline_execution: Option<
+        Box<
+            dyn Fn(
+                    GetPipelineExecutionInput,
+                ) -> ::std::result::Result<
+                    GetPipelineExecutionOutput,
+                    RusotoError<GetPipelineExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_pipeline_state: Option<
+        Box<
+            dyn Fn(
+                    GetPipelineStateInput,
+                ) -> ::std::result::Result<
+                    GetPipelineStateOutput,
+                    RusotoError<GetPipelineStateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_third_party_job_details: Option<
+        Box<
+            dyn Fn(
+                    GetThirdPartyJobDetailsInput,
+                ) -> ::std::result::Result<
+                    GetThirdPartyJobDetailsOutput,
+                    RusotoError<GetThirdPartyJobDetailsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_action_executions: Option<
+        Box<
+            dyn Fn(
+                    ListActionExecutionsInput,
+                ) -> ::std::result::Result<
+                    ListActionExecutionsOutput,
+                    RusotoError<ListActionExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_action_types: Option<
+        Box<
+            dyn Fn(
+                    ListActionTypesInput,
+                ) -> ::std::result::Result<
+                    ListActionTypesOutput,
+                    RusotoError<ListActionTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_pipeline_executions: Option<
+        Box<
+            dyn Fn(
+                    ListPipelineExecutionsInput,
+                ) -> ::std::result::Result<
+                    ListPipelineExecutionsOutput,
+                    RusotoError<ListPipelineExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_pipelines: Option<
+        Box<
+            dyn Fn(
+                    ListPipelinesInput,
+                )
+                    -> ::std::result::Result<ListPipelinesOutput, RusotoError<ListPipelinesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceOutput,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_webhooks: Option<
+        Box<
+            dyn Fn(
+                    ListWebhooksInput,
+                )
+                    -> ::std::result::Result<ListWebhooksOutput, RusotoError<ListWebhooksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    poll_for_jobs: Option<
+        Box<
+            dyn Fn(
+                    PollForJobsInput,
+                )
+                    -> ::std::result::Result<PollForJobsOutput, RusotoError<PollForJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    poll_for_third_party_jobs: Option<
+        Box<
+            dyn Fn(
+                    PollForThirdPartyJobsInput,
+                ) -> ::std::result::Result<
+                    PollForThirdPartyJobsOutput,
+                    RusotoError<PollForThirdPartyJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_action_revision: Option<
+        Box<
+            dyn Fn(
+                    PutActionRevisionInput,
+                ) -> ::std::result::Result<
+                    PutActionRevisionOutput,
+                    RusotoError<PutActionRevisionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_approval_result: Option<
+        Box<
+            dyn Fn(
+                    PutApprovalResultInput,
+                ) -> ::std::result::Result<
+                    PutApprovalResultOutput,
+                    RusotoError<PutApprovalResultError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_job_failure_result: Option<
+        Box<
+            dyn Fn(
+                    PutJobFailureResultInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutJobFailureResultError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_job_success_result: Option<
+        Box<
+            dyn Fn(
+                    PutJobSuccessResultInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutJobSuccessResultError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_third_party_job_failure_result: Option<
+        Box<
+            dyn Fn(
+                    PutThirdPartyJobFailureResultInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutThirdPartyJobFailureResultError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_third_party_job_success_result: Option<
+        Box<
+            dyn Fn(
+                    PutThirdPartyJobSuccessResultInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutThirdPartyJobSuccessResultError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_webhook: Option<
+        Box<
+            dyn Fn(
+                    PutWebhookInput,
+                )
+                    -> ::std::result::Result<PutWebhookOutput, RusotoError<PutWebhookError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_webhook_with_third_party: Option<
+        Box<
+            dyn Fn(
+                    RegisterWebhookWithThirdPartyInput,
+                ) -> ::std::result::Result<
+                    RegisterWebhookWithThirdPartyOutput,
+                    RusotoError<RegisterWebhookWithThirdPartyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    retry_stage_execution: Option<
+        Box<
+            dyn Fn(
+                    RetryStageExecutionInput,
+                ) -> ::std::result::Result<
+                    RetryStageExecutionOutput,
+                    RusotoError<RetryStageExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_pipeline_execution: Option<
+        Box<
+            dyn Fn(
+                    StartPipelineExecutionInput,
+                ) -> ::std::result::Result<
+                    StartPipelineExecutionOutput,
+                    RusotoError<StartPipelineExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceInput,
+                )
+                    -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceInput,
+                )
+                    -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_pipeline: Option<
+        Box<
+            dyn Fn(
+                    UpdatePipelineInput,
+                )
+                    -> ::std::result::Result<UpdatePipelineOutput, RusotoError<UpdatePipelineError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCodePipeline {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCodePipeline {
+        Default::default()
+    }
+
+    /// Answers `acknowledge_job` with what `handler` returns for its input.
+    pub fn with_acknowledge_job<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                AcknowledgeJobInput,
+            )
+                -> ::std::result::Result<AcknowledgeJobOutput, RusotoError<AcknowledgeJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.acknowledge_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `acknowledge_third_party_job` with what `handler` returns for its input.
+    pub fn with_acknowledge_third_party_job<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                AcknowledgeThirdPartyJobInput,
+            ) -> ::std::result::Result<
+                AcknowledgeThirdPartyJobOutput,
+                RusotoError<AcknowledgeThirdPartyJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.acknowledge_third_party_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_custom_action_type` with what `handler` returns for its input.
+    pub fn with_create_custom_action_type<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                CreateCustomActionTypeInput,
+            ) -> ::std::result::Result<
+                CreateCustomActionTypeOutput,
+                RusotoError<CreateCustomActionTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_custom_action_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_pipeline` with what `handler` returns for its input.
+    pub fn with_create_pipeline<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                CreatePipelineInput,
+            )
+                -> ::std::result::Result<CreatePipelineOutput, RusotoError<CreatePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_custom_action_type` with what `handler` returns for its input.
+    pub fn with_delete_custom_action_type<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                DeleteCustomActionTypeInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteCustomActionTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_custom_action_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_pipeline` with what `handler` returns for its input.
+    pub fn with_delete_pipeline<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(DeletePipelineInput) -> ::std::result::Result<(), RusotoError<DeletePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_webhook` with what `handler` returns for its input.
+    pub fn with_delete_webhook<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                DeleteWebhookInput,
+            )
+                -> ::std::result::Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_webhook = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_webhook_with_third_party` with what `handler` returns for its input.
+    pub fn with_deregister_webhook_with_third_party<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                DeregisterWebhookWithThirdPartyInput,
+            ) -> ::std::result::Result<
+                DeregisterWebhookWithThirdPartyOutput,
+                RusotoError<DeregisterWebhookWithThirdPartyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_webhook_with_third_party = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_stage_transition` with what `handler` returns for its input.
+    pub fn with_disable_stage_transition<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                DisableStageTransitionInput,
+            ) -> ::std::result::Result<(), RusotoError<DisableStageTransitionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_stage_transition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_stage_transition` with what `handler` returns for its input.
+    pub fn with_enable_stage_transition<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                EnableStageTransitionInput,
+            ) -> ::std::result::Result<(), RusotoError<EnableStageTransitionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_stage_transition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job_details` with what `handler` returns for its input.
+    pub fn with_get_job_details<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                GetJobDetailsInput,
+            )
+                -> ::std::result::Result<GetJobDetailsOutput, RusotoError<GetJobDetailsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job_details = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_pipeline` with what `handler` returns for its input.
+    pub fn with_get_pipeline<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                GetPipelineInput,
+            )
+                -> ::std::result::Result<GetPipelineOutput, RusotoError<GetPipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_pipeline_execution` with what `handler` returns for its input.
+    pub fn with_get_pipeline_execution<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                GetPipelineExecutionInput,
+            ) -> ::std::result::Result<
+                GetPipelineExecutionOutput,
+                RusotoError<GetPipelineExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_pipeline_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_pipeline_state` with what `handler` returns for its input.
+    pub fn with_get_pipeline_state<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                GetPipelineStateInput,
+            )
+                -> ::std::result::Result<GetPipelineStateOutput, RusotoError<GetPipelineStateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_pipeline_state = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_third_party_job_details` with what `handler` returns for its input.
+    pub fn with_get_third_party_job_details<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                GetThirdPartyJobDetailsInput,
+            ) -> ::std::result::Result<
+                GetThirdPartyJobDetailsOutput,
+                RusotoError<GetThirdPartyJobDetailsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_third_party_job_details = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_action_executions` with what `handler` returns for its input.
+    pub fn with_list_action_executions<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                ListActionExecutionsInput,
+            ) -> ::std::result::Result<
+                ListActionExecutionsOutput,
+                RusotoError<ListActionExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_action_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_action_types` with what `handler` returns for its input.
+    pub fn with_list_action_types<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                ListActionTypesInput,
+            )
+                -> ::std::result::Result<ListActionTypesOutput, RusotoError<ListActionTypesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_action_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_pipeline_executions` with what `handler` returns for its input.
+    pub fn with_list_pipeline_executions<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                ListPipelineExecutionsInput,
+            ) -> ::std::result::Result<
+                ListPipelineExecutionsOutput,
+                RusotoError<ListPipelineExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_pipeline_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_pipelines` with what `handler` returns for its input.
+    pub fn with_list_pipelines<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                ListPipelinesInput,
+            )
+                -> ::std::result::Result<ListPipelinesOutput, RusotoError<ListPipelinesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_pipelines = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceOutput,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_webhooks` with what `handler` returns for its input.
+    pub fn with_list_webhooks<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                ListWebhooksInput,
+            )
+                -> ::std::result::Result<ListWebhooksOutput, RusotoError<ListWebhooksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_webhooks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `poll_for_jobs` with what `handler` returns for its input.
+    pub fn with_poll_for_jobs<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                PollForJobsInput,
+            )
+                -> ::std::result::Result<PollForJobsOutput, RusotoError<PollForJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.poll_for_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `poll_for_third_party_jobs` with what `handler` returns for its input.
+    pub fn with_poll_for_third_party_jobs<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                PollForThirdPartyJobsInput,
+            ) -> ::std::result::Result<
+                PollForThirdPartyJobsOutput,
+                RusotoError<PollForThirdPartyJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.poll_for_third_party_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_action_revision` with what `handler` returns for its input.
+    pub fn with_put_action_revision<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                PutActionRevisionInput,
+            ) -> ::std::result::Result<
+                PutActionRevisionOutput,
+                RusotoError<PutActionRevisionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_action_revision = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_approval_result` with what `handler` returns for its input.
+    pub fn with_put_approval_result<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                PutApprovalResultInput,
+            ) -> ::std::result::Result<
+                PutApprovalResultOutput,
+                RusotoError<PutApprovalResultError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_approval_result = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_job_failure_result` with what `handler` returns for its input.
+    pub fn with_put_job_failure_result<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                PutJobFailureResultInput,
+            ) -> ::std::result::Result<(), RusotoError<PutJobFailureResultError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_job_failure_result = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_job_success_result` with what `handler` returns for its input.
+    pub fn with_put_job_success_result<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                PutJobSuccessResultInput,
+            ) -> ::std::result::Result<(), RusotoError<PutJobSuccessResultError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_job_success_result = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_third_party_job_failure_result` with what `handler` returns for its input.
+    pub fn with_put_third_party_job_failure_result<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                PutThirdPartyJobFailureResultInput,
+            )
+                -> ::std::result::Result<(), RusotoError<PutThirdPartyJobFailureResultError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_third_party_job_failure_result = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_third_party_job_success_result` with what `handler` returns for its input.
+    pub fn with_put_third_party_job_success_result<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                PutThirdPartyJobSuccessResultInput,
+            )
+                -> ::std::result::Result<(), RusotoError<PutThirdPartyJobSuccessResultError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_third_party_job_success_result = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_webhook` with what `handler` returns for its input.
+    pub fn with_put_webhook<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                PutWebhookInput,
+            ) -> ::std::result::Result<PutWebhookOutput, RusotoError<PutWebhookError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_webhook = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_webhook_with_third_party` with what `handler` returns for its input.
+    pub fn with_register_webhook_with_third_party<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                RegisterWebhookWithThirdPartyInput,
+            ) -> ::std::result::Result<
+                RegisterWebhookWithThirdPartyOutput,
+                RusotoError<RegisterWebhookWithThirdPartyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_webhook_with_third_party = Some(Box::new(handler));
+        self
+    }
+    /// Answers `retry_stage_execution` with what `handler` returns for its input.
+    pub fn with_retry_stage_execution<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                RetryStageExecutionInput,
+            ) -> ::std::result::Result<
+                RetryStageExecutionOutput,
+                RusotoError<RetryStageExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.retry_stage_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_pipeline_execution` with what `handler` returns for its input.
+    pub fn with_start_pipeline_execution<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                StartPipelineExecutionInput,
+            ) -> ::std::result::Result<
+                StartPipelineExecutionOutput,
+                RusotoError<StartPipelineExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_pipeline_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                TagResourceInput,
+            )
+                -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                UntagResourceInput,
+            )
+                -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_pipeline` with what `handler` returns for its input.
+    pub fn with_update_pipeline<F>(mut self, handler: F) -> MockCodePipeline
+    where
+        F: Fn(
+                UpdatePipelineInput,
+            )
+                -> ::std::result::Result<UpdatePipelineOutput, RusotoError<UpdatePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_pipeline = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CodePipeline for MockCodePipeline {
+    fn acknowledge_job(
+        &self,
+        input: AcknowledgeJobInput,
+    ) -> RusotoFuture<AcknowledgeJobOutput, AcknowledgeJobError> {
+        match self.acknowledge_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for acknowledge_job"),
+        }
+    }
+    fn acknowledge_third_party_job(
+        &self,
+        input: AcknowledgeThirdPartyJobInput,
+    ) -> RusotoFuture<AcknowledgeThirdPartyJobOutput, AcknowledgeThirdPartyJobError> {
+        match self.acknowledge_third_party_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for acknowledge_third_party_job"),
+        }
+    }
+    fn create_custom_action_type(
+        &self,
+        input: CreateCustomActionTypeInput,
+    ) -> RusotoFuture<CreateCustomActionTypeOutput, CreateCustomActionTypeError> {
+        match self.create_custom_action_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for create_custom_action_type"),
+        }
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
+        match self.create_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for create_pipeline"),
+        }
+    }
+    fn delete_custom_action_type(
+        &self,
+        input: DeleteCustomActionTypeInput,
+    ) -> RusotoFuture<(), DeleteCustomActionTypeError> {
+        match self.delete_custom_action_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for delete_custom_action_type"),
+        }
+    }
+    fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        match self.delete_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for delete_pipeline"),
+        }
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        match self.delete_webhook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for delete_webhook"),
+        }
+    }
+    fn deregister_webhook_with_third_party(
+        &self,
+        input: DeregisterWebhookWithThirdPartyInput,
+    ) -> RusotoFuture<DeregisterWebhookWithThirdPartyOutput, DeregisterWebhookWithThirdPartyError>
+    {
+        match self.deregister_webhook_with_third_party {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCodePipeline has no handler for deregister_webhook_with_third_party")
+            }
+        }
+    }
+    fn disable_stage_transition(
+        &self,
+        input: DisableStageTransitionInput,
+    ) -> RusotoFuture<(), DisableStageTransitionError> {
+        match self.disable_stage_transition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for disable_stage_transition"),
+        }
+    }
+    fn enable_stage_transition(
+        &self,
+        input: EnableStageTransitionInput,
+    ) -> RusotoFuture<(), EnableStageTransitionError> {
+        match self.enable_stage_transition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for enable_stage_transition"),
+        }
+    }
+    fn get_job_details(
+        &self,
+        input: GetJobDetailsInput,
+    ) -> RusotoFuture<GetJobDetailsOutput, GetJobDetailsError> {
+        match self.get_job_details {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for get_job_details"),
+        }
+    }
+    fn get_pipeline(
+        &self,
+        input: GetPipelineInput,
+    ) -> RusotoFuture<GetPipelineOutput, GetPipelineError> {
+        match self.get_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for get_pipeline"),
+        }
+    }
+    fn get_pipeline_execution(
+        &self,
+        input: GetPipelineExecutionInput,
+    ) -> RusotoFuture<GetPipelineExecutionOutput, GetPipelineExecutionError> {
+        match self.get_pipeline_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for get_pipeline_execution"),
+        }
+    }
+    fn get_pipeline_state(
+        &self,
+        input: GetPipelineStateInput,
+    ) -> RusotoFuture<GetPipelineStateOutput, GetPipelineStateError> {
+        match self.get_pipeline_state {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for get_pipeline_state"),
+        }
+    }
+    fn get_third_party_job_details(
+        &self,
+        input: GetThirdPartyJobDetailsInput,
+    ) -> RusotoFuture<GetThirdPartyJobDetailsOutput, GetThirdPartyJobDetailsError> {
+        match self.get_third_party_job_details {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for get_third_party_job_details"),
+        }
+    }
+    fn list_action_executions(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> RusotoFuture<ListActionExecutionsOutput, ListActionExecutionsError> {
+        match self.list_action_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for list_action_executions"),
+        }
+    }
+    fn list_action_types(
+        &self,
+        input: ListActionTypesInput,
+    ) -> RusotoFuture<ListActionTypesOutput, ListActionTypesError> {
+        match self.list_action_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for list_action_types"),
+        }
+    }
+    fn list_pipeline_executions(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> RusotoFuture<ListPipelineExecutionsOutput, ListPipelineExecutionsError> {
+        match self.list_pipeline_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for list_pipeline_executions"),
+        }
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        match self.list_pipelines {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for list_pipelines"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_webhooks(
+        &self,
+        input: ListWebhooksInput,
+    ) -> RusotoFuture<ListWebhooksOutput, ListWebhooksError> {
+        match self.list_webhooks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for list_webhooks"),
+        }
+    }
+    fn poll_for_jobs(
+        &self,
+        input: PollForJobsInput,
+    ) -> RusotoFuture<PollForJobsOutput, PollForJobsError> {
+        match self.poll_for_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for poll_for_jobs"),
+        }
+    }
+    fn poll_for_third_party_jobs(
+        &self,
+        input: PollForThirdPartyJobsInput,
+    ) -> RusotoFuture<PollForThirdPartyJobsOutput, PollForThirdPartyJobsError> {
+        match self.poll_for_third_party_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for poll_for_third_party_jobs"),
+        }
+    }
+    fn put_action_revision(
+        &self,
+        input: PutActionRevisionInput,
+    ) -> RusotoFuture<PutActionRevisionOutput, PutActionRevisionError> {
+        match self.put_action_revision {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for put_action_revision"),
+        }
+    }
+    fn put_approval_result(
+        &self,
+        input: PutApprovalResultInput,
+    ) -> RusotoFuture<PutApprovalResultOutput, PutApprovalResultError> {
+        match self.put_approval_result {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for put_approval_result"),
+        }
     }
-
-    /// <p>Represents the failure of a job as returned to the pipeline by a job worker. Only used for custom actions.</p>
     fn put_job_failure_result(
         &self,
         input: PutJobFailureResultInput,
     ) -> RusotoFuture<(), PutJobFailureResultError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.PutJobFailureResult");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutJobFailureResultError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.put_job_failure_result {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for put_job_failure_result"),
+        }
     }
-
-    /// <p>Represents the success of a job as returned to the pipeline by a job worker. Only used for custom actions.</p>
     fn put_job_success_result(
         &self,
         input: PutJobSuccessResultInput,
     ) -> RusotoFuture<(), PutJobSuccessResultError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.PutJobSuccessResult");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutJobSuccessResultError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.put_job_success_result {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for put_job_success_result"),
+        }
     }
-
-    /// <p>Represents the failure of a third party job as returned to the pipeline by a job worker. Only used for partner actions.</p>
     fn put_third_party_job_failure_result(
         &self,
         input: PutThirdPartyJobFailureResultInput,
     ) -> RusotoFuture<(), PutThirdPartyJobFailureResultError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.PutThirdPartyJobFailureResult",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutThirdPartyJobFailureResultError::from_response(response))
-                }))
+        match self.put_third_party_job_failure_result {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCodePipeline has no handler for put_third_party_job_failure_result")
             }
-        })
+        }
     }
-
-    /// <p>Represents the success of a third party job as returned to the pipeline by a job worker. Only used for partner actions.</p>
     fn put_third_party_job_success_result(
         &self,
         input: PutThirdPartyJobSuccessResultInput,
     ) -> RusotoFuture<(), PutThirdPartyJobSuccessResultError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.PutThirdPartyJobSuccessResult",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutThirdPartyJobSuccessResultError::from_response(response))
-                }))
+        match self.put_third_party_job_success_result {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCodePipeline has no handler for put_third_party_job_success_result")
             }
-        })
+        }
     }
-
-    /// <p>Defines a webhook and returns a unique webhook URL generated by CodePipeline. This URL can be supplied to third party source hosting providers to call every time there's a code change. When CodePipeline receives a POST request on this URL, the pipeline defined in the webhook is started as long as the POST request satisfied the authentication and filtering requirements supplied when defining the webhook. RegisterWebhookWithThirdParty and DeregisterWebhookWithThirdParty APIs can be used to automatically configure supported third parties to call the generated webhook URL.</p>
     fn put_webhook(
         &self,
         input: PutWebhookInput,
     ) -> RusotoFuture<PutWebhookOutput, PutWebhookError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.PutWebhook");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutWebhookOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutWebhookError::from_response(response))),
-                )
-            }
-        })
+        match self.put_webhook {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for put_webhook"),
+        }
     }
-
-    /// <p>Configures a connection between the webhook that was created and the external tool with events to be detected.</p>
     fn register_webhook_with_third_party(
         &self,
         input: RegisterWebhookWithThirdPartyInput,
     ) -> RusotoFuture<RegisterWebhookWithThirdPartyOutput, RegisterWebhookWithThirdPartyError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.RegisterWebhookWithThirdParty",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterWebhookWithThirdPartyOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegisterWebhookWithThirdPartyError::from_response(response))
-                }))
-            }
-        })
+        match self.register_webhook_with_third_party {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for register_webhook_with_third_party"),
+        }
     }
-
-    /// <p>Resumes the pipeline execution by retrying the last failed actions in a stage.</p>
     fn retry_stage_execution(
         &self,
         input: RetryStageExecutionInput,
     ) -> RusotoFuture<RetryStageExecutionOutput, RetryStageExecutionError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.RetryStageExecution");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RetryStageExecutionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RetryStageExecutionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.retry_stage_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for retry_stage_execution"),
+        }
     }
-
-    /// <p>Starts the specified pipeline. Specifically, it begins processing the latest commit to the source location specified as part of the pipeline.</p>
     fn start_pipeline_execution(
         &self,
         input: StartPipelineExecutionInput,
     ) -> RusotoFuture<StartPipelineExecutionOutput, StartPipelineExecutionError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodePipeline_20150709.StartPipelineExecution",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartPipelineExecutionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartPipelineExecutionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.start_pipeline_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for start_pipeline_execution"),
+        }
     }
-
-    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata that can be used to manage a resource. </p>
     fn tag_resource(
         &self,
         input: TagResourceInput,
     ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Removes tags from an AWS resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Updates a specified pipeline with edits or changes to its structure. Use a JSON file with the pipeline structure in conjunction with <code>UpdatePipeline</code> to provide the full structure of the pipeline. Updating the pipeline increases the version number of the pipeline by 1.</p>
     fn update_pipeline(
         &self,
         input: UpdatePipelineInput,
     ) -> RusotoFuture<UpdatePipelineOutput, UpdatePipelineError> {
-        let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodePipeline_20150709.UpdatePipeline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdatePipelineOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdatePipelineError::from_response(response))),
-                )
-            }
-        })
+        match self.update_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodePipeline has no handler for update_pipeline"),
+        }
     }
 }
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateTeamMemberRequest {
     /// <p>A user- or system-generated token that identifies the entity that requested the team member association to the project. This token can be used to repeat the request.</p>
     #[serde(rename = "clientRequestToken")]
@@ -44,8 +46,49 @@ pub struct AssociateTeamMemberRequest {
     pub user_arn: String,
 }
 
+impl Validate for AssociateTeamMemberRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[\\w:/-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "project_id"),
+            self.project_id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_id"),
+            &self.project_id,
+            "^[a-z][a-z0-9-]+$",
+        );
+        errors.check_pattern(
+            &field_path(path, "project_role"),
+            &self.project_role,
+            "^(Owner|Viewer|Contributor)$",
+        );
+        errors.check_length(
+            &field_path(path, "user_arn"),
+            self.user_arn.chars().count(),
+            Some(32),
+            Some(95),
+        );
+        errors.check_pattern(&field_path(path, "user_arn"), &self.user_arn, "^arn:aws:iam::\\d{12}:user(?:(\\u002F)|(\\u002F[\\u0021-\\u007E]+\\u002F))[\\w+=,.@-]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateTeamMemberResult {
     /// <p>The user- or system-generated token from the initial request that can be used to repeat the request.</p>
     #[serde(rename = "clientRequestToken")]
@@ -55,6 +98,7 @@ pub struct AssociateTeamMemberResult {
 
 /// <p>Location and destination information about the source code files provided with the project request. The source code is uploaded to the new project source repository after project creation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Code {
     /// <p>The repository to be created in AWS CodeStar. Valid values are AWS CodeCommit or GitHub. After AWS CodeStar provisions the new repository, the source code files provided with the project request are placed in the repository.</p>
     #[serde(rename = "destination")]
@@ -64,16 +108,38 @@ pub struct Code {
     pub source: CodeSource,
 }
 
+impl Validate for Code {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.destination
+            .validate_at(&field_path(path, "destination"), errors);
+        self.source.validate_at(&field_path(path, "source"), errors);
+    }
+}
+
 /// <p>Information about the AWS CodeCommit repository to be created in AWS CodeStar. This is where the source code files provided with the project request will be uploaded after project creation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CodeCommitCodeDestination {
     /// <p>The name of the AWS CodeCommit repository to be created in AWS CodeStar.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for CodeCommitCodeDestination {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^\\S[\\w.-]*$");
+    }
+}
+
 /// <p>The repository to be created in AWS CodeStar. Valid values are AWS CodeCommit or GitHub. After AWS CodeStar provisions the new repository, the source code files provided with the project request are placed in the repository.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CodeDestination {
     /// <p>Information about the AWS CodeCommit repository to be created in AWS CodeStar. This is where the source code files provided with the project request will be uploaded after project creation.</p>
     #[serde(rename = "codeCommit")]
@@ -85,15 +151,34 @@ pub struct CodeDestination {
     pub git_hub: Option<GitHubCodeDestination>,
 }
 
+impl Validate for CodeDestination {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.code_commit {
+            value.validate_at(&field_path(path, "code_commit"), errors);
+        }
+        if let Some(ref value) = self.git_hub {
+            value.validate_at(&field_path(path, "git_hub"), errors);
+        }
+    }
+}
+
 /// <p>The location where the source code files provided with the project request are stored. AWS CodeStar retrieves the files during project creation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CodeSource {
     /// <p>Information about the Amazon S3 location where the source code files provided with the project request are stored. </p>
     #[serde(rename = "s3")]
     pub s_3: S3Location,
 }
 
+impl Validate for CodeSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.s_3.validate_at(&field_path(path, "s_3"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProjectRequest {
     /// <p>A user- or system-generated token that identifies the entity that requested project creation. This token can be used to repeat the request.</p>
     #[serde(rename = "clientRequestToken")]
@@ -123,8 +208,65 @@ pub struct CreateProjectRequest {
     pub toolchain: Option<Toolchain>,
 }
 
+impl Validate for CreateProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[\\w:/-]+$",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "^$|^\\S(.*\\S)?$");
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-z][a-z0-9-]+$");
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^\\S(.*\\S)?$");
+        if let Some(ref value) = self.source_code {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "source_code"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.tags {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+        if let Some(ref value) = self.toolchain {
+            value.validate_at(&field_path(path, "toolchain"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProjectResult {
     /// <p>The Amazon Resource Name (ARN) of the created project.</p>
     #[serde(rename = "arn")]
@@ -143,6 +285,7 @@ pub struct CreateProjectResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserProfileRequest {
     /// <p>The name that will be displayed as the friendly name for the user in AWS CodeStar. </p>
     #[serde(rename = "displayName")]
@@ -159,8 +302,55 @@ pub struct CreateUserProfileRequest {
     pub user_arn: String,
 }
 
+impl Validate for CreateUserProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "display_name"),
+            self.display_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "display_name"),
+            &self.display_name,
+            "^\\S(.*\\S)?$",
+        );
+        errors.check_length(
+            &field_path(path, "email_address"),
+            self.email_address.chars().count(),
+            Some(3),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "email_address"),
+            &self.email_address,
+            "^[\\w-.+]+@[\\w-.+]+$",
+        );
+        if let Some(ref value) = self.ssh_public_key {
+            errors.check_length(
+                &field_path(path, "ssh_public_key"),
+                value.chars().count(),
+                None,
+                Some(16384),
+            );
+            errors.check_pattern(
+                &field_path(path, "ssh_public_key"),
+                &value,
+                "^[\\t\\r\\n\\u0020-\\u00FF]*$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_arn"),
+            self.user_arn.chars().count(),
+            Some(32),
+            Some(95),
+        );
+        errors.check_pattern(&field_path(path, "user_arn"), &self.user_arn, "^arn:aws:iam::\\d{12}:user(?:(\\u002F)|(\\u002F[\\u0021-\\u007E]+\\u002F))[\\w+=,.@-]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserProfileResult {
     /// <p>The date the user profile was created, in timestamp format.</p>
     #[serde(rename = "createdTimestamp")]
@@ -188,6 +378,7 @@ pub struct CreateUserProfileResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProjectRequest {
     /// <p>A user- or system-generated token that identifies the entity that requested project deletion. This token can be used to repeat the request. </p>
     #[serde(rename = "clientRequestToken")]
@@ -202,8 +393,33 @@ pub struct DeleteProjectRequest {
     pub id: String,
 }
 
+impl Validate for DeleteProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[\\w:/-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-z][a-z0-9-]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProjectResult {
     /// <p>The Amazon Resource Name (ARN) of the deleted project.</p>
     #[serde(rename = "projectArn")]
@@ -216,14 +432,27 @@ pub struct DeleteProjectResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserProfileRequest {
     /// <p>The Amazon Resource Name (ARN) of the user to delete from AWS CodeStar.</p>
     #[serde(rename = "userArn")]
     pub user_arn: String,
 }
 
+impl Validate for DeleteUserProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_arn"),
+            self.user_arn.chars().count(),
+            Some(32),
+            Some(95),
+        );
+        errors.check_pattern(&field_path(path, "user_arn"), &self.user_arn, "^arn:aws:iam::\\d{12}:user(?:(\\u002F)|(\\u002F[\\u0021-\\u007E]+\\u002F))[\\w+=,.@-]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUserProfileResult {
     /// <p>The Amazon Resource Name (ARN) of the user deleted from AWS CodeStar.</p>
     #[serde(rename = "userArn")]
@@ -231,14 +460,27 @@ pub struct DeleteUserProfileResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProjectRequest {
     /// <p>The ID of the project.</p>
     #[serde(rename = "id")]
     pub id: String,
 }
 
+impl Validate for DescribeProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-z][a-z0-9-]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProjectResult {
     /// <p>The Amazon Resource Name (ARN) for the project.</p>
     #[serde(rename = "arn")]
@@ -279,14 +521,27 @@ pub struct DescribeProjectResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserProfileRequest {
     /// <p>The Amazon Resource Name (ARN) of the user.</p>
     #[serde(rename = "userArn")]
     pub user_arn: String,
 }
 
+impl Validate for DescribeUserProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_arn"),
+            self.user_arn.chars().count(),
+            Some(32),
+            Some(95),
+        );
+        errors.check_pattern(&field_path(path, "user_arn"), &self.user_arn, "^arn:aws:iam::\\d{12}:user(?:(\\u002F)|(\\u002F[\\u0021-\\u007E]+\\u002F))[\\w+=,.@-]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserProfileResult {
     /// <p>The date and time when the user profile was created in AWS CodeStar, in timestamp format.</p>
     #[serde(rename = "createdTimestamp")]
@@ -312,6 +567,7 @@ pub struct DescribeUserProfileResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateTeamMemberRequest {
     /// <p>The ID of the AWS CodeStar project from which you want to remove a team member.</p>
     #[serde(rename = "projectId")]
@@ -321,12 +577,36 @@ pub struct DisassociateTeamMemberRequest {
     pub user_arn: String,
 }
 
+impl Validate for DisassociateTeamMemberRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "project_id"),
+            self.project_id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_id"),
+            &self.project_id,
+            "^[a-z][a-z0-9-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "user_arn"),
+            self.user_arn.chars().count(),
+            Some(32),
+            Some(95),
+        );
+        errors.check_pattern(&field_path(path, "user_arn"), &self.user_arn, "^arn:aws:iam::\\d{12}:user(?:(\\u002F)|(\\u002F[\\u0021-\\u007E]+\\u002F))[\\w+=,.@-]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateTeamMemberResult {}
 
 /// <p>Information about the GitHub repository to be created in AWS CodeStar. This is where the source code files provided with the project request will be uploaded after project creation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GitHubCodeDestination {
     /// <p>Description for the GitHub repository to be created in AWS CodeStar. This description displays in GitHub after the repository is created.</p>
     #[serde(rename = "description")]
@@ -352,7 +632,47 @@ pub struct GitHubCodeDestination {
     pub type_: String,
 }
 
+impl Validate for GitHubCodeDestination {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "^\\S(.*\\S)?$");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^\\S[\\w.-]*$");
+        errors.check_length(
+            &field_path(path, "owner"),
+            self.owner.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "owner"), &self.owner, "^\\S(.*\\S)?$");
+        errors.check_length(
+            &field_path(path, "token"),
+            self.token.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "type_"),
+            &self.type_,
+            "^(user|organization|User|Organization)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProjectsRequest {
     /// <p>The maximum amount of data that can be contained in a single set of results.</p>
     #[serde(rename = "maxResults")]
@@ -364,8 +684,25 @@ pub struct ListProjectsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListProjectsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "^[\\w/+=]+$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProjectsResult {
     /// <p>The continuation token to use when requesting the next set of results, if there are more results to be returned.</p>
     #[serde(rename = "nextToken")]
@@ -377,6 +714,7 @@ pub struct ListProjectsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourcesRequest {
     /// <p>The maximum amount of data that can be contained in a single set of results.</p>
     #[serde(rename = "maxResults")]
@@ -391,8 +729,36 @@ pub struct ListResourcesRequest {
     pub project_id: String,
 }
 
+impl Validate for ListResourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "^[\\w/+=]+$");
+        }
+        errors.check_length(
+            &field_path(path, "project_id"),
+            self.project_id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_id"),
+            &self.project_id,
+            "^[a-z][a-z0-9-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourcesResult {
     /// <p>The continuation token to use when requesting the next set of results, if there are more results to be returned.</p>
     #[serde(rename = "nextToken")]
@@ -405,6 +771,7 @@ pub struct ListResourcesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForProjectRequest {
     /// <p>The ID of the project to get tags for.</p>
     #[serde(rename = "id")]
@@ -419,8 +786,32 @@ pub struct ListTagsForProjectRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListTagsForProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-z][a-z0-9-]+$");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "^[\\w/+=]+$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForProjectResult {
     /// <p>Reserved for future use.</p>
     #[serde(rename = "nextToken")]
@@ -433,6 +824,7 @@ pub struct ListTagsForProjectResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTeamMembersRequest {
     /// <p>The maximum number of team members you want returned in a response.</p>
     #[serde(rename = "maxResults")]
@@ -447,8 +839,36 @@ pub struct ListTeamMembersRequest {
     pub project_id: String,
 }
 
+impl Validate for ListTeamMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "^[\\w/+=]+$");
+        }
+        errors.check_length(
+            &field_path(path, "project_id"),
+            self.project_id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_id"),
+            &self.project_id,
+            "^[a-z][a-z0-9-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTeamMembersResult {
     /// <p>The continuation token to use when requesting the next set of results, if there are more results to be returned.</p>
     #[serde(rename = "nextToken")]
@@ -460,6 +880,7 @@ pub struct ListTeamMembersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUserProfilesRequest {
     /// <p>The maximum number of results to return in a response.</p>
     #[serde(rename = "maxResults")]
@@ -471,8 +892,25 @@ pub struct ListUserProfilesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListUserProfilesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "^[\\w/+=]+$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUserProfilesResult {
     /// <p>The continuation token to use when requesting the next set of results, if there are more results to be returned.</p>
     #[serde(rename = "nextToken")]
@@ -485,7 +923,7 @@ pub struct ListUserProfilesResult {
 
 /// <p>An indication of whether a project creation or deletion is failed or successful.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProjectStatus {
     /// <p>In the case of a project creation or deletion failure, a reason for the failure.</p>
     #[serde(rename = "reason")]
@@ -498,7 +936,7 @@ pub struct ProjectStatus {
 
 /// <p>Information about the metadata for a project.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProjectSummary {
     /// <p>The Amazon Resource Name (ARN) of the project.</p>
     #[serde(rename = "projectArn")]
@@ -512,7 +950,7 @@ pub struct ProjectSummary {
 
 /// <p>Information about a resource for a project.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Resource {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "id")]
@@ -521,6 +959,7 @@ pub struct Resource {
 
 /// <p>The Amazon S3 location where the source code files provided with the project request are stored.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3Location {
     /// <p>The Amazon S3 object key where the source code files provided with the project request are stored.</p>
     #[serde(rename = "bucketKey")]
@@ -532,7 +971,21 @@ pub struct S3Location {
     pub bucket_name: Option<String>,
 }
 
+impl Validate for S3Location {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket_name {
+            errors.check_length(
+                &field_path(path, "bucket_name"),
+                value.chars().count(),
+                Some(3),
+                Some(63),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagProjectRequest {
     /// <p>The ID of the project you want to add a tag to.</p>
     #[serde(rename = "id")]
@@ -542,8 +995,25 @@ pub struct TagProjectRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-z][a-z0-9-]+$");
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagProjectResult {
     /// <p>The tags for the project.</p>
     #[serde(rename = "tags")]
@@ -553,7 +1023,7 @@ pub struct TagProjectResult {
 
 /// <p>Information about a team member in a project.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TeamMember {
     /// <p>The role assigned to the user in the project. Project roles have different levels of access. For more information, see <a href="http://docs.aws.amazon.com/codestar/latest/userguide/working-with-teams.html">Working with Teams</a> in the <i>AWS CodeStar User Guide</i>. </p>
     #[serde(rename = "projectRole")]
@@ -569,6 +1039,7 @@ pub struct TeamMember {
 
 /// <p>The toolchain template file provided with the project request. AWS CodeStar uses the template to provision the toolchain stack in AWS CloudFormation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Toolchain {
     /// <p>The service role ARN for AWS CodeStar to use for the toolchain template during stack provisioning.</p>
     #[serde(rename = "roleArn")]
@@ -583,15 +1054,50 @@ pub struct Toolchain {
     pub stack_parameters: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for Toolchain {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+        }
+        self.source.validate_at(&field_path(path, "source"), errors);
+        if let Some(ref value) = self.stack_parameters {
+            errors.check_length(
+                &field_path(path, "stack_parameters"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "stack_parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+                errors.check_pattern(&item_path, &item, "^\\S(.*\\S)?$");
+            }
+        }
+    }
+}
+
 /// <p>The Amazon S3 location where the toolchain template file provided with the project request is stored. AWS CodeStar retrieves the file during project creation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ToolchainSource {
     /// <p>The Amazon S3 bucket where the toolchain template file provided with the project request is stored.</p>
     #[serde(rename = "s3")]
     pub s_3: S3Location,
 }
 
+impl Validate for ToolchainSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.s_3.validate_at(&field_path(path, "s_3"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagProjectRequest {
     /// <p>The ID of the project to remove tags from.</p>
     #[serde(rename = "id")]
@@ -601,11 +1107,29 @@ pub struct UntagProjectRequest {
     pub tags: Vec<String>,
 }
 
+impl Validate for UntagProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-z][a-z0-9-]+$");
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagProjectResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProjectRequest {
     /// <p>The description of the project, if any.</p>
     #[serde(rename = "description")]
@@ -620,11 +1144,42 @@ pub struct UpdateProjectRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "^$|^\\S(.*\\S)?$");
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-z][a-z0-9-]+$");
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "^\\S(.*\\S)?$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateProjectResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTeamMemberRequest {
     /// <p>The ID of the project.</p>
     #[serde(rename = "projectId")]
@@ -642,8 +1197,38 @@ pub struct UpdateTeamMemberRequest {
     pub user_arn: String,
 }
 
+impl Validate for UpdateTeamMemberRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "project_id"),
+            self.project_id.chars().count(),
+            Some(2),
+            Some(15),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_id"),
+            &self.project_id,
+            "^[a-z][a-z0-9-]+$",
+        );
+        if let Some(ref value) = self.project_role {
+            errors.check_pattern(
+                &field_path(path, "project_role"),
+                &value,
+                "^(Owner|Viewer|Contributor)$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_arn"),
+            self.user_arn.chars().count(),
+            Some(32),
+            Some(95),
+        );
+        errors.check_pattern(&field_path(path, "user_arn"), &self.user_arn, "^arn:aws:iam::\\d{12}:user(?:(\\u002F)|(\\u002F[\\u0021-\\u007E]+\\u002F))[\\w+=,.@-]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTeamMemberResult {
     /// <p>The project role granted to the user.</p>
     #[serde(rename = "projectRole")]
@@ -660,6 +1245,7 @@ pub struct UpdateTeamMemberResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserProfileRequest {
     /// <p>The name that is displayed as the friendly name for the user in AWS CodeStar.</p>
     #[serde(rename = "displayName")]
@@ -678,8 +1264,55 @@ pub struct UpdateUserProfileRequest {
     pub user_arn: String,
 }
 
+impl Validate for UpdateUserProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "display_name"), &value, "^\\S(.*\\S)?$");
+        }
+        if let Some(ref value) = self.email_address {
+            errors.check_length(
+                &field_path(path, "email_address"),
+                value.chars().count(),
+                Some(3),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "email_address"),
+                &value,
+                "^[\\w-.+]+@[\\w-.+]+$",
+            );
+        }
+        if let Some(ref value) = self.ssh_public_key {
+            errors.check_length(
+                &field_path(path, "ssh_public_key"),
+                value.chars().count(),
+                None,
+                Some(16384),
+            );
+            errors.check_pattern(
+                &field_path(path, "ssh_public_key"),
+                &value,
+                "^[\\t\\r\\n\\u0020-\\u00FF]*$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_arn"),
+            self.user_arn.chars().count(),
+            Some(32),
+            Some(95),
+        );
+        errors.check_pattern(&field_path(path, "user_arn"), &self.user_arn, "^arn:aws:iam::\\d{12}:user(?:(\\u002F)|(\\u002F[\\u0021-\\u007E]+\\u002F))[\\w+=,.@-]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUserProfileResult {
     /// <p>The date the user profile was created, in timestamp format.</p>
     #[serde(rename = "createdTimestamp")]
@@ -708,7 +1341,7 @@ pub struct UpdateUserProfileResult {
 
 /// <p>Information about a user's profile in AWS CodeStar.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserProfileSummary {
     /// <p>The display name of a user in AWS CodeStar. For example, this could be set to both first and last name ("Mary Major") or a single name ("Mary"). The display name is also used to generate the initial icon associated with the user in AWS CodeStar projects. If spaces are included in the display name, the first character that appears after the space will be used as the second character in the user initial icon. The initial icon displays a maximum of two characters, so a display name with more than one space (for example "Mary Jane Major") would generate an initial icon using the first character and the first character after the space ("MJ", not "MM").</p>
     #[serde(rename = "displayName")]
@@ -775,7 +1408,12 @@ impl AssociateTeamMemberError {
                         AssociateTeamMemberError::TeamMemberAlreadyAssociated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -840,7 +1478,12 @@ impl CreateProjectError {
                 "ProjectCreationFailedException" => {
                     return RusotoError::Service(CreateProjectError::ProjectCreationFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -880,7 +1523,12 @@ impl CreateUserProfileError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -920,7 +1568,12 @@ impl DeleteProjectError {
                 "InvalidServiceRoleException" => {
                     return RusotoError::Service(DeleteProjectError::InvalidServiceRole(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -948,7 +1601,12 @@ impl DeleteUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -998,7 +1656,12 @@ impl DescribeProjectError {
                 "ProjectNotFoundException" => {
                     return RusotoError::Service(DescribeProjectError::ProjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1036,7 +1699,12 @@ impl DescribeUserProfileError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1085,7 +1753,12 @@ impl DisassociateTeamMemberError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1120,7 +1793,12 @@ impl ListProjectsError {
                 "InvalidNextTokenException" => {
                     return RusotoError::Service(ListProjectsError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1158,7 +1836,12 @@ impl ListResourcesError {
                 "ProjectNotFoundException" => {
                     return RusotoError::Service(ListResourcesError::ProjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1197,7 +1880,12 @@ impl ListTagsForProjectError {
                 "ProjectNotFoundException" => {
                     return RusotoError::Service(ListTagsForProjectError::ProjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1236,7 +1924,12 @@ impl ListTeamMembersError {
                 "ProjectNotFoundException" => {
                     return RusotoError::Service(ListTeamMembersError::ProjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1270,7 +1963,12 @@ impl ListUserProfilesError {
                 "InvalidNextTokenException" => {
                     return RusotoError::Service(ListUserProfilesError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1313,7 +2011,12 @@ impl TagProjectError {
                 "ProjectNotFoundException" => {
                     return RusotoError::Service(TagProjectError::ProjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1358,7 +2061,12 @@ impl UntagProjectError {
                 "ProjectNotFoundException" => {
                     return RusotoError::Service(UntagProjectError::ProjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1393,7 +2101,12 @@ impl UpdateProjectError {
                 "ProjectNotFoundException" => {
                     return RusotoError::Service(UpdateProjectError::ProjectNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1455,7 +2168,12 @@ impl UpdateTeamMemberError {
                 "TeamMemberNotFoundException" => {
                     return RusotoError::Service(UpdateTeamMemberError::TeamMemberNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1495,7 +2213,12 @@ impl UpdateUserProfileError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1624,177 +2347,553 @@ pub trait CodeStar {
         input: UpdateUserProfileRequest,
     ) -> RusotoFuture<UpdateUserProfileResult, UpdateUserProfileError>;
 }
-/// A client for the CodeStar API.
-#[derive(Clone)]
-pub struct CodeStarClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CodeStarClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CodeStarClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CodeStarClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CodeStarClient {
-        CodeStarClient { client, region }
-    }
-}
-
-impl CodeStar for CodeStarClient {
-    /// <p>Adds an IAM user to the team for an AWS CodeStar project.</p>
+const _: Option<&dyn CodeStar> = None;
+impl<'a, T: CodeStar + ?Sized> CodeStar for &'a T {
     fn associate_team_member(
         &self,
         input: AssociateTeamMemberRequest,
     ) -> RusotoFuture<AssociateTeamMemberResult, AssociateTeamMemberError> {
-        let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeStar_20170419.AssociateTeamMember");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateTeamMemberResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AssociateTeamMemberError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).associate_team_member(input)
     }
-
-    /// <p>Creates a project, including project resources. This action creates a project based on a submitted project request. A set of source code files and a toolchain template file can be included with the project request. If these are not provided, an empty project is created.</p>
     fn create_project(
         &self,
         input: CreateProjectRequest,
     ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
-        let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeStar_20170419.CreateProject");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateProjectResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateProjectError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_project(input)
     }
-
-    /// <p>Creates a profile for a user that includes user preferences, such as the display name and email address assocciated with the user, in AWS CodeStar. The user profile is not project-specific. Information in the user profile is displayed wherever the user's information appears to other users in AWS CodeStar.</p>
     fn create_user_profile(
         &self,
         input: CreateUserProfileRequest,
     ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
-        let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeStar_20170419.CreateUserProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUserProfileError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_user_profile(input)
     }
-
-    /// <p>Deletes a project, including project resources. Does not delete users associated with the project, but does delete the IAM roles that allowed access to the project.</p>
     fn delete_project(
         &self,
         input: DeleteProjectRequest,
     ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
-        let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeStar_20170419.DeleteProject");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteProjectResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteProjectError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_project(input)
     }
-
-    /// <p>Deletes a user profile in AWS CodeStar, including all personal preference data associated with that profile, such as display name and email address. It does not delete the history of that user, for example the history of commits made by that user.</p>
     fn delete_user_profile(
         &self,
         input: DeleteUserProfileRequest,
     ) -> RusotoFuture<DeleteUserProfileResult, DeleteUserProfileError> {
-        let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeStar_20170419.DeleteUserProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
+        (**self).delete_user_profile(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> RusotoFuture<DescribeUserProfileResult, DescribeUserProfileError> {
+        (**self).describe_user_profile(input)
+    }
+    fn disassociate_team_member(
+        &self,
+        input: DisassociateTeamMemberRequest,
+    ) -> RusotoFuture<DisassociateTeamMemberResult, DisassociateTeamMemberError> {
+        (**self).disassociate_team_member(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResult, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn list_tags_for_project(
+        &self,
+        input: ListTagsForProjectRequest,
+    ) -> RusotoFuture<ListTagsForProjectResult, ListTagsForProjectError> {
+        (**self).list_tags_for_project(input)
+    }
+    fn list_team_members(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> RusotoFuture<ListTeamMembersResult, ListTeamMembersError> {
+        (**self).list_team_members(input)
+    }
+    fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> RusotoFuture<ListUserProfilesResult, ListUserProfilesError> {
+        (**self).list_user_profiles(input)
+    }
+    fn tag_project(
+        &self,
+        input: TagProjectRequest,
+    ) -> RusotoFuture<TagProjectResult, TagProjectError> {
+        (**self).tag_project(input)
+    }
+    fn untag_project(
+        &self,
+        input: UntagProjectRequest,
+    ) -> RusotoFuture<UntagProjectResult, UntagProjectError> {
+        (**self).untag_project(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_team_member(
+        &self,
+        input: UpdateTeamMemberRequest,
+    ) -> RusotoFuture<UpdateTeamMemberResult, UpdateTeamMemberError> {
+        (**self).update_team_member(input)
+    }
+    fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> RusotoFuture<UpdateUserProfileResult, UpdateUserProfileError> {
+        (**self).update_user_profile(input)
+    }
+}
+impl<T: CodeStar + ?Sized> CodeStar for Box<T> {
+    fn associate_team_member(
+        &self,
+        input: AssociateTeamMemberRequest,
+    ) -> RusotoFuture<AssociateTeamMemberResult, AssociateTeamMemberError> {
+        (**self).associate_team_member(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
+        (**self).create_user_profile(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> RusotoFuture<DeleteUserProfileResult, DeleteUserProfileError> {
+        (**self).delete_user_profile(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> RusotoFuture<DescribeUserProfileResult, DescribeUserProfileError> {
+        (**self).describe_user_profile(input)
+    }
+    fn disassociate_team_member(
+        &self,
+        input: DisassociateTeamMemberRequest,
+    ) -> RusotoFuture<DisassociateTeamMemberResult, DisassociateTeamMemberError> {
+        (**self).disassociate_team_member(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResult, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn list_tags_for_project(
+        &self,
+        input: ListTagsForProjectRequest,
+    ) -> RusotoFuture<ListTagsForProjectResult, ListTagsForProjectError> {
+        (**self).list_tags_for_project(input)
+    }
+    fn list_team_members(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> RusotoFuture<ListTeamMembersResult, ListTeamMembersError> {
+        (**self).list_team_members(input)
+    }
+    fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> RusotoFuture<ListUserProfilesResult, ListUserProfilesError> {
+        (**self).list_user_profiles(input)
+    }
+    fn tag_project(
+        &self,
+        input: TagProjectRequest,
+    ) -> RusotoFuture<TagProjectResult, TagProjectError> {
+        (**self).tag_project(input)
+    }
+    fn untag_project(
+        &self,
+        input: UntagProjectRequest,
+    ) -> RusotoFuture<UntagProjectResult, UntagProjectError> {
+        (**self).untag_project(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_team_member(
+        &self,
+        input: UpdateTeamMemberRequest,
+    ) -> RusotoFuture<UpdateTeamMemberResult, UpdateTeamMemberError> {
+        (**self).update_team_member(input)
+    }
+    fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> RusotoFuture<UpdateUserProfileResult, UpdateUserProfileError> {
+        (**self).update_user_profile(input)
+    }
+}
+impl<T: CodeStar + ?Sized> CodeStar for ::std::sync::Arc<T> {
+    fn associate_team_member(
+        &self,
+        input: AssociateTeamMemberRequest,
+    ) -> RusotoFuture<AssociateTeamMemberResult, AssociateTeamMemberError> {
+        (**self).associate_team_member(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
+        (**self).create_user_profile(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> RusotoFuture<DeleteUserProfileResult, DeleteUserProfileError> {
+        (**self).delete_user_profile(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> RusotoFuture<DescribeUserProfileResult, DescribeUserProfileError> {
+        (**self).describe_user_profile(input)
+    }
+    fn disassociate_team_member(
+        &self,
+        input: DisassociateTeamMemberRequest,
+    ) -> RusotoFuture<DisassociateTeamMemberResult, DisassociateTeamMemberError> {
+        (**self).disassociate_team_member(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResult, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn list_tags_for_project(
+        &self,
+        input: ListTagsForProjectRequest,
+    ) -> RusotoFuture<ListTagsForProjectResult, ListTagsForProjectError> {
+        (**self).list_tags_for_project(input)
+    }
+    fn list_team_members(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> RusotoFuture<ListTeamMembersResult, ListTeamMembersError> {
+        (**self).list_team_members(input)
+    }
+    fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> RusotoFuture<ListUserProfilesResult, ListUserProfilesError> {
+        (**self).list_user_profiles(input)
+    }
+    fn tag_project(
+        &self,
+        input: TagProjectRequest,
+    ) -> RusotoFuture<TagProjectResult, TagProjectError> {
+        (**self).tag_project(input)
+    }
+    fn untag_project(
+        &self,
+        input: UntagProjectRequest,
+    ) -> RusotoFuture<UntagProjectResult, UntagProjectError> {
+        (**self).untag_project(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_team_member(
+        &self,
+        input: UpdateTeamMemberRequest,
+    ) -> RusotoFuture<UpdateTeamMemberResult, UpdateTeamMemberError> {
+        (**self).update_team_member(input)
+    }
+    fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> RusotoFuture<UpdateUserProfileResult, UpdateUserProfileError> {
+        (**self).update_user_profile(input)
+    }
+}
+/// A client for the CodeStar API.
+#[derive(Clone)]
+pub struct CodeStarClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CodeStarClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeStarClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeStarClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeStarClient {
+        CodeStarClient { client, region }
+    }
+}
+
+impl CodeStar for CodeStarClient {
+    /// <p>Adds an IAM user to the team for an AWS CodeStar project.</p>
+    fn associate_team_member(
+        &self,
+        input: AssociateTeamMemberRequest,
+    ) -> RusotoFuture<AssociateTeamMemberResult, AssociateTeamMemberError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("AssociateTeamMember");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeStar_20170419.AssociateTeamMember");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateTeamMemberResult, _>(
+                    response,
+                    "AssociateTeamMember",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AssociateTeamMemberError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a project, including project resources. This action creates a project based on a submitted project request. A set of source code files and a toolchain template file can be included with the project request. If these are not provided, an empty project is created.</p>
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("CreateProject");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeStar_20170419.CreateProject");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateProjectResult, _>(
+                    response,
+                    "CreateProject",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateProjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a profile for a user that includes user preferences, such as the display name and email address assocciated with the user, in AWS CodeStar. The user profile is not project-specific. Information in the user profile is displayed wherever the user's information appears to other users in AWS CodeStar.</p>
+    fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("CreateUserProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeStar_20170419.CreateUserProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserProfileResult, _>(
+                    response,
+                    "CreateUserProfile",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUserProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a project, including project resources. Does not delete users associated with the project, but does delete the IAM roles that allowed access to the project.</p>
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DeleteProject");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeStar_20170419.DeleteProject");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteProjectResult, _>(
+                    response,
+                    "DeleteProject",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteProjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a user profile in AWS CodeStar, including all personal preference data associated with that profile, such as display name and email address. It does not delete the history of that user, for example the history of commits made by that user.</p>
+    fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> RusotoFuture<DeleteUserProfileResult, DeleteUserProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DeleteUserProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeStar_20170419.DeleteUserProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteUserProfileResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteUserProfileResult, _>(
+                    response,
+                    "DeleteUserProfile",
+                )
             } else {
                 Box::new(
                     response
@@ -1811,7 +2910,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: DescribeProjectRequest,
     ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DescribeProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DescribeProject");
@@ -1820,10 +2928,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeProjectResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeProjectResult, _>(
+                    response,
+                    "DescribeProject",
+                )
             } else {
                 Box::new(
                     response
@@ -1840,7 +2948,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: DescribeUserProfileRequest,
     ) -> RusotoFuture<DescribeUserProfileResult, DescribeUserProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DescribeUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DescribeUserProfile");
@@ -1849,10 +2966,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUserProfileResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeUserProfileResult, _>(
+                    response,
+                    "DescribeUserProfile",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1868,7 +2985,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: DisassociateTeamMemberRequest,
     ) -> RusotoFuture<DisassociateTeamMemberResult, DisassociateTeamMemberError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DisassociateTeamMember");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DisassociateTeamMember");
@@ -1877,10 +3003,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateTeamMemberResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DisassociateTeamMemberResult, _>(
+                    response,
+                    "DisassociateTeamMember",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1896,7 +3022,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: ListProjectsRequest,
     ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListProjects");
@@ -1905,10 +3040,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListProjectsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListProjectsResult, _>(
+                    response,
+                    "ListProjects",
+                )
             } else {
                 Box::new(
                     response
@@ -1925,7 +3060,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: ListResourcesRequest,
     ) -> RusotoFuture<ListResourcesResult, ListResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListResources");
@@ -1934,10 +3078,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourcesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListResourcesResult, _>(
+                    response,
+                    "ListResources",
+                )
             } else {
                 Box::new(
                     response
@@ -1954,7 +3098,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: ListTagsForProjectRequest,
     ) -> RusotoFuture<ListTagsForProjectResult, ListTagsForProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListTagsForProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListTagsForProject");
@@ -1963,10 +3116,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForProjectResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForProjectResult, _>(
+                    response,
+                    "ListTagsForProject",
+                )
             } else {
                 Box::new(
                     response
@@ -1983,7 +3136,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: ListTeamMembersRequest,
     ) -> RusotoFuture<ListTeamMembersResult, ListTeamMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListTeamMembers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListTeamMembers");
@@ -1992,10 +3154,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTeamMembersResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTeamMembersResult, _>(
+                    response,
+                    "ListTeamMembers",
+                )
             } else {
                 Box::new(
                     response
@@ -2012,7 +3174,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: ListUserProfilesRequest,
     ) -> RusotoFuture<ListUserProfilesResult, ListUserProfilesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListUserProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListUserProfiles");
@@ -2021,10 +3192,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUserProfilesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListUserProfilesResult, _>(
+                    response,
+                    "ListUserProfiles",
+                )
             } else {
                 Box::new(
                     response
@@ -2041,7 +3212,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: TagProjectRequest,
     ) -> RusotoFuture<TagProjectResult, TagProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("TagProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.TagProject");
@@ -2050,10 +3230,7 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagProjectResult, _>()
-                }))
+                proto::json::deserialize_streaming::<TagProjectResult, _>(response, "TagProject")
             } else {
                 Box::new(
                     response
@@ -2070,7 +3247,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: UntagProjectRequest,
     ) -> RusotoFuture<UntagProjectResult, UntagProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UntagProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UntagProject");
@@ -2079,10 +3265,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagProjectResult, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagProjectResult, _>(
+                    response,
+                    "UntagProject",
+                )
             } else {
                 Box::new(
                     response
@@ -2099,7 +3285,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: UpdateProjectRequest,
     ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UpdateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateProject");
@@ -2108,10 +3303,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateProjectResult, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateProjectResult, _>(
+                    response,
+                    "UpdateProject",
+                )
             } else {
                 Box::new(
                     response
@@ -2128,7 +3323,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: UpdateTeamMemberRequest,
     ) -> RusotoFuture<UpdateTeamMemberResult, UpdateTeamMemberError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UpdateTeamMember");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateTeamMember");
@@ -2137,10 +3341,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTeamMemberResult, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateTeamMemberResult, _>(
+                    response,
+                    "UpdateTeamMember",
+                )
             } else {
                 Box::new(
                     response
@@ -2157,7 +3361,16 @@ impl CodeStar for CodeStarClient {
         &self,
         input: UpdateUserProfileRequest,
     ) -> RusotoFuture<UpdateUserProfileResult, UpdateUserProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UpdateUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateUserProfile");
@@ -2166,10 +3379,10 @@ impl CodeStar for CodeStarClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateUserProfileResult, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateUserProfileResult, _>(
+                    response,
+                    "UpdateUserProfile",
+                )
             } else {
                 Box::new(
                     response
@@ -2181,3 +3394,636 @@ impl CodeStar for CodeStarClient {
         })
     }
 }
+
+/// A mock of the CodeStar API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCodeStar {
+    associate_team_member: Option<
+        Box<
+            dyn Fn(
+                    AssociateTeamMemberRequest,
+                ) -> ::std::result::Result<
+                    AssociateTeamMemberResult,
+                    RusotoError<AssociateTeamMemberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_project: Option<
+        Box<
+            dyn Fn(
+                    CreateProjectRequest,
+                )
+                    -> ::std::result::Result<CreateProjectResult, RusotoError<CreateProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_user_profile: Option<
+        Box<
+            dyn Fn(
+                    CreateUserProfileRequest,
+                ) -> ::std::result::Result<
+                    CreateUserProfileResult,
+                    RusotoError<CreateUserProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_project: Option<
+        Box<
+            dyn Fn(
+                    DeleteProjectRequest,
+                )
+                    -> ::std::result::Result<DeleteProjectResult, RusotoError<DeleteProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user_profile: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserProfileRequest,
+                ) -> ::std::result::Result<
+                    DeleteUserProfileResult,
+                    RusotoError<DeleteUserProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_project: Option<
+        Box<
+            dyn Fn(
+                    DescribeProjectRequest,
+                ) -> ::std::result::Result<
+                    DescribeProjectResult,
+                    RusotoError<DescribeProjectError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user_profile: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserProfileRequest,
+                ) -> ::std::result::Result<
+                    DescribeUserProfileResult,
+                    RusotoError<DescribeUserProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_team_member: Option<
+        Box<
+            dyn Fn(
+                    DisassociateTeamMemberRequest,
+                ) -> ::std::result::Result<
+                    DisassociateTeamMemberResult,
+                    RusotoError<DisassociateTeamMemberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_projects: Option<
+        Box<
+            dyn Fn(
+                    ListProjectsRequest,
+                )
+                    -> ::std::result::Result<ListProjectsResult, RusotoError<ListProjectsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_resources: Option<
+        Box<
+            dyn Fn(
+                    ListResourcesRequest,
+                )
+                    -> ::std::result::Result<ListResourcesResult, RusotoError<ListResourcesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_project: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForProjectRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForProjectResult,
+                    RusotoError<ListTagsForProjectError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_team_members: Option<
+        Box<
+            dyn Fn(
+                    ListTeamMembersRequest,
+                ) -> ::std::result::Result<
+                    ListTeamMembersResult,
+                    RusotoError<ListTeamMembersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_user_profiles: Option<
+        Box<
+            dyn Fn(
+                    ListUserProfilesRequest,
+                ) -> ::std::result::Result<
+                    ListUserProfilesResult,
+                    RusotoError<ListUserProfilesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_project: Option<
+        Box<
+            dyn Fn(
+                    TagProjectRequest,
+                )
+                    -> ::std::result::Result<TagProjectResult, RusotoError<TagProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_project: Option<
+        Box<
+            dyn Fn(
+                    UntagProjectRequest,
+                )
+                    -> ::std::result::Result<UntagProjectResult, RusotoError<UntagProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_project: Option<
+        Box<
+            dyn Fn(
+                    UpdateProjectRequest,
+                )
+                    -> ::std::result::Result<UpdateProjectResult, RusotoError<UpdateProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_team_member: Option<
+        Box<
+            dyn Fn(
+                    UpdateTeamMemberRequest,
+                ) -> ::std::result::Result<
+                    UpdateTeamMemberResult,
+                    RusotoError<UpdateTeamMemberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_user_profile: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserProfileRequest,
+                ) -> ::std::result::Result<
+                    UpdateUserProfileResult,
+                    RusotoError<UpdateUserProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCodeStar {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCodeStar {
+        Default::default()
+    }
+
+    /// Answers `associate_team_member` with what `handler` returns for its input.
+    pub fn with_associate_team_member<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                AssociateTeamMemberRequest,
+            ) -> ::std::result::Result<
+                AssociateTeamMemberResult,
+                RusotoError<AssociateTeamMemberError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_team_member = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_project` with what `handler` returns for its input.
+    pub fn with_create_project<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                CreateProjectRequest,
+            )
+                -> ::std::result::Result<CreateProjectResult, RusotoError<CreateProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user_profile` with what `handler` returns for its input.
+    pub fn with_create_user_profile<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                CreateUserProfileRequest,
+            ) -> ::std::result::Result<
+                CreateUserProfileResult,
+                RusotoError<CreateUserProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_project` with what `handler` returns for its input.
+    pub fn with_delete_project<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                DeleteProjectRequest,
+            )
+                -> ::std::result::Result<DeleteProjectResult, RusotoError<DeleteProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user_profile` with what `handler` returns for its input.
+    pub fn with_delete_user_profile<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                DeleteUserProfileRequest,
+            ) -> ::std::result::Result<
+                DeleteUserProfileResult,
+                RusotoError<DeleteUserProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_project` with what `handler` returns for its input.
+    pub fn with_describe_project<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                DescribeProjectRequest,
+            )
+                -> ::std::result::Result<DescribeProjectResult, RusotoError<DescribeProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user_profile` with what `handler` returns for its input.
+    pub fn with_describe_user_profile<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                DescribeUserProfileRequest,
+            ) -> ::std::result::Result<
+                DescribeUserProfileResult,
+                RusotoError<DescribeUserProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_team_member` with what `handler` returns for its input.
+    pub fn with_disassociate_team_member<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                DisassociateTeamMemberRequest,
+            ) -> ::std::result::Result<
+                DisassociateTeamMemberResult,
+                RusotoError<DisassociateTeamMemberError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_team_member = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_projects` with what `handler` returns for its input.
+    pub fn with_list_projects<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                ListProjectsRequest,
+            )
+                -> ::std::result::Result<ListProjectsResult, RusotoError<ListProjectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_projects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resources` with what `handler` returns for its input.
+    pub fn with_list_resources<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                ListResourcesRequest,
+            )
+                -> ::std::result::Result<ListResourcesResult, RusotoError<ListResourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_project` with what `handler` returns for its input.
+    pub fn with_list_tags_for_project<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                ListTagsForProjectRequest,
+            ) -> ::std::result::Result<
+                ListTagsForProjectResult,
+                RusotoError<ListTagsForProjectError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_team_members` with what `handler` returns for its input.
+    pub fn with_list_team_members<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                ListTeamMembersRequest,
+            )
+                -> ::std::result::Result<ListTeamMembersResult, RusotoError<ListTeamMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_team_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_user_profiles` with what `handler` returns for its input.
+    pub fn with_list_user_profiles<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                ListUserProfilesRequest,
+            )
+                -> ::std::result::Result<ListUserProfilesResult, RusotoError<ListUserProfilesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_user_profiles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_project` with what `handler` returns for its input.
+    pub fn with_tag_project<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                TagProjectRequest,
+            ) -> ::std::result::Result<TagProjectResult, RusotoError<TagProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_project` with what `handler` returns for its input.
+    pub fn with_untag_project<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                UntagProjectRequest,
+            )
+                -> ::std::result::Result<UntagProjectResult, RusotoError<UntagProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_project` with what `handler` returns for its input.
+    pub fn with_update_project<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                UpdateProjectRequest,
+            )
+                -> ::std::result::Result<UpdateProjectResult, RusotoError<UpdateProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_team_member` with what `handler` returns for its input.
+    pub fn with_update_team_member<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                UpdateTeamMemberRequest,
+            )
+                -> ::std::result::Result<UpdateTeamMemberResult, RusotoError<UpdateTeamMemberError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_team_member = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_profile` with what `handler` returns for its input.
+    pub fn with_update_user_profile<F>(mut self, handler: F) -> MockCodeStar
+    where
+        F: Fn(
+                UpdateUserProfileRequest,
+            ) -> ::std::result::Result<
+                UpdateUserProfileResult,
+                RusotoError<UpdateUserProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_profile = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CodeStar for MockCodeStar {
+    fn associate_team_member(
+        &self,
+        input: AssociateTeamMemberRequest,
+    ) -> RusotoFuture<AssociateTeamMemberResult, AssociateTeamMemberError> {
+        match self.associate_team_member {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for associate_team_member"),
+        }
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        match self.create_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for create_project"),
+        }
+    }
+    fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
+        match self.create_user_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for create_user_profile"),
+        }
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        match self.delete_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for delete_project"),
+        }
+    }
+    fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> RusotoFuture<DeleteUserProfileResult, DeleteUserProfileError> {
+        match self.delete_user_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for delete_user_profile"),
+        }
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        match self.describe_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for describe_project"),
+        }
+    }
+    fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> RusotoFuture<DescribeUserProfileResult, DescribeUserProfileError> {
+        match self.describe_user_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for describe_user_profile"),
+        }
+    }
+    fn disassociate_team_member(
+        &self,
+        input: DisassociateTeamMemberRequest,
+    ) -> RusotoFuture<DisassociateTeamMemberResult, DisassociateTeamMemberError> {
+        match self.disassociate_team_member {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for disassociate_team_member"),
+        }
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        match self.list_projects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for list_projects"),
+        }
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResult, ListResourcesError> {
+        match self.list_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for list_resources"),
+        }
+    }
+    fn list_tags_for_project(
+        &self,
+        input: ListTagsForProjectRequest,
+    ) -> RusotoFuture<ListTagsForProjectResult, ListTagsForProjectError> {
+        match self.list_tags_for_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for list_tags_for_project"),
+        }
+    }
+    fn list_team_members(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> RusotoFuture<ListTeamMembersResult, ListTeamMembersError> {
+        match self.list_team_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for list_team_members"),
+        }
+    }
+    fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> RusotoFuture<ListUserProfilesResult, ListUserProfilesError> {
+        match self.list_user_profiles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for list_user_profiles"),
+        }
+    }
+    fn tag_project(
+        &self,
+        input: TagProjectRequest,
+    ) -> RusotoFuture<TagProjectResult, TagProjectError> {
+        match self.tag_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for tag_project"),
+        }
+    }
+    fn untag_project(
+        &self,
+        input: UntagProjectRequest,
+    ) -> RusotoFuture<UntagProjectResult, UntagProjectError> {
+        match self.untag_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for untag_project"),
+        }
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        match self.update_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for update_project"),
+        }
+    }
+    fn update_team_member(
+        &self,
+        input: UpdateTeamMemberRequest,
+    ) -> RusotoFuture<UpdateTeamMemberResult, UpdateTeamMemberError> {
+        match self.update_team_member {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for update_team_member"),
+        }
+    }
+    fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> RusotoFuture<UpdateUserProfileResult, UpdateUserProfileError> {
+        match self.update_user_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCodeStar has no handler for update_user_profile"),
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>A provider representing an Amazon Cognito user pool and its client ID.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -40,8 +41,32 @@ pub struct CognitoIdentityProvider {
     pub server_side_token_check: Option<bool>,
 }
 
+impl Validate for CognitoIdentityProvider {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_id {
+            errors.check_length(
+                &field_path(path, "client_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "client_id"), &value, "[\\w_]+");
+        }
+        if let Some(ref value) = self.provider_name {
+            errors.check_length(
+                &field_path(path, "provider_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "provider_name"), &value, "[\\w._:/-]+");
+        }
+    }
+}
+
 /// <p>Input to the CreateIdentityPool action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateIdentityPoolInput {
     /// <p>TRUE if the identity pool supports unauthenticated logins.</p>
     #[serde(rename = "AllowUnauthenticatedIdentities")]
@@ -75,9 +100,81 @@ pub struct CreateIdentityPoolInput {
     pub supported_login_providers: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateIdentityPoolInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cognito_identity_providers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "cognito_identity_providers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.developer_provider_name {
+            errors.check_length(
+                &field_path(path, "developer_provider_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "developer_provider_name"),
+                &value,
+                "[\\w._-]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "identity_pool_name"),
+            self.identity_pool_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_name"),
+            &self.identity_pool_name,
+            "[\\w\\s+=,.@-]+",
+        );
+        if let Some(ref value) = self.identity_pool_tags {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "identity_pool_tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        if let Some(ref value) = self.open_id_connect_provider_ar_ns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "open_id_connect_provider_ar_ns"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(20), Some(2048));
+            }
+        }
+        if let Some(ref value) = self.saml_provider_ar_ns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "saml_provider_ar_ns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(20), Some(2048));
+            }
+        }
+        if let Some(ref value) = self.supported_login_providers {
+            errors.check_length(
+                &field_path(path, "supported_login_providers"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (key, item) in value.iter() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "supported_login_providers"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, "[\\w.;_/-]+");
+            }
+        }
+    }
+}
+
 /// <p>Credentials for the provided identity ID.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Credentials {
     /// <p>The Access Key portion of the credentials.</p>
     #[serde(rename = "AccessKeyId")]
@@ -99,15 +196,32 @@ pub struct Credentials {
 
 /// <p>Input to the <code>DeleteIdentities</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIdentitiesInput {
     /// <p>A list of 1-60 identities that you want to delete.</p>
     #[serde(rename = "IdentityIdsToDelete")]
     pub identity_ids_to_delete: Vec<String>,
 }
 
+impl Validate for DeleteIdentitiesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_ids_to_delete"),
+            self.identity_ids_to_delete.len(),
+            Some(1),
+            Some(60),
+        );
+        for (i, item) in self.identity_ids_to_delete.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "identity_ids_to_delete"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(55));
+            errors.check_pattern(&item_path, &item, "[\\w-]+:[0-9a-f-]+");
+        }
+    }
+}
+
 /// <p>Returned in response to a successful <code>DeleteIdentities</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteIdentitiesResponse {
     /// <p>An array of UnprocessedIdentityId objects, each of which contains an ErrorCode and IdentityId.</p>
     #[serde(rename = "UnprocessedIdentityIds")]
@@ -117,30 +231,82 @@ pub struct DeleteIdentitiesResponse {
 
 /// <p>Input to the DeleteIdentityPool action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIdentityPoolInput {
     /// <p>An identity pool ID in the format REGION:GUID.</p>
     #[serde(rename = "IdentityPoolId")]
     pub identity_pool_id: String,
 }
 
+impl Validate for DeleteIdentityPoolInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Input to the <code>DescribeIdentity</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeIdentityInput {
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
     pub identity_id: String,
 }
 
+impl Validate for DescribeIdentityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Input to the DescribeIdentityPool action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeIdentityPoolInput {
     /// <p>An identity pool ID in the format REGION:GUID.</p>
     #[serde(rename = "IdentityPoolId")]
     pub identity_pool_id: String,
 }
 
+impl Validate for DescribeIdentityPoolInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Input to the <code>GetCredentialsForIdentity</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCredentialsForIdentityInput {
     /// <p>The Amazon Resource Name (ARN) of the role to be assumed when multiple roles were received in the token from the identity provider. For example, a SAML-based identity provider. This parameter is optional for identity providers that do not support role customization.</p>
     #[serde(rename = "CustomRoleArn")]
@@ -155,9 +321,40 @@ pub struct GetCredentialsForIdentityInput {
     pub logins: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for GetCredentialsForIdentityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_role_arn {
+            errors.check_length(
+                &field_path(path, "custom_role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        if let Some(ref value) = self.logins {
+            errors.check_length(&field_path(path, "logins"), value.len(), None, Some(10));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "logins"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(50000));
+            }
+        }
+    }
+}
+
 /// <p>Returned in response to a successful <code>GetCredentialsForIdentity</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCredentialsForIdentityResponse {
     /// <p>Credentials for the provided identity ID.</p>
     #[serde(rename = "Credentials")]
@@ -171,6 +368,7 @@ pub struct GetCredentialsForIdentityResponse {
 
 /// <p>Input to the GetId action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdInput {
     /// <p>A standard AWS account ID (9+ digits).</p>
     #[serde(rename = "AccountId")]
@@ -185,9 +383,41 @@ pub struct GetIdInput {
     pub logins: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for GetIdInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_id {
+            errors.check_length(
+                &field_path(path, "account_id"),
+                value.chars().count(),
+                Some(1),
+                Some(15),
+            );
+            errors.check_pattern(&field_path(path, "account_id"), &value, "\\d+");
+        }
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        if let Some(ref value) = self.logins {
+            errors.check_length(&field_path(path, "logins"), value.len(), None, Some(10));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "logins"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(50000));
+            }
+        }
+    }
+}
+
 /// <p>Returned in response to a GetId request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIdResponse {
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
@@ -197,15 +427,32 @@ pub struct GetIdResponse {
 
 /// <p>Input to the <code>GetIdentityPoolRoles</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityPoolRolesInput {
     /// <p>An identity pool ID in the format REGION:GUID.</p>
     #[serde(rename = "IdentityPoolId")]
     pub identity_pool_id: String,
 }
 
+impl Validate for GetIdentityPoolRolesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Returned in response to a successful <code>GetIdentityPoolRoles</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIdentityPoolRolesResponse {
     /// <p>An identity pool ID in the format REGION:GUID.</p>
     #[serde(rename = "IdentityPoolId")]
@@ -223,6 +470,7 @@ pub struct GetIdentityPoolRolesResponse {
 
 /// <p>Input to the <code>GetOpenIdTokenForDeveloperIdentity</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOpenIdTokenForDeveloperIdentityInput {
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
@@ -240,9 +488,56 @@ pub struct GetOpenIdTokenForDeveloperIdentityInput {
     pub token_duration: Option<i64>,
 }
 
+impl Validate for GetOpenIdTokenForDeveloperIdentityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.identity_id {
+            errors.check_length(
+                &field_path(path, "identity_id"),
+                value.chars().count(),
+                Some(1),
+                Some(55),
+            );
+            errors.check_pattern(
+                &field_path(path, "identity_id"),
+                &value,
+                "[\\w-]+:[0-9a-f-]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "logins"),
+            self.logins.len(),
+            None,
+            Some(10),
+        );
+        for (key, item) in self.logins.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "logins"), key);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(50000));
+        }
+        if let Some(ref value) = self.token_duration {
+            errors.check_range(
+                &field_path(path, "token_duration"),
+                *value,
+                Some(1),
+                Some(86400),
+            );
+        }
+    }
+}
+
 /// <p>Returned in response to a successful <code>GetOpenIdTokenForDeveloperIdentity</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOpenIdTokenForDeveloperIdentityResponse {
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
@@ -256,6 +551,7 @@ pub struct GetOpenIdTokenForDeveloperIdentityResponse {
 
 /// <p>Input to the GetOpenIdToken action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOpenIdTokenInput {
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
@@ -266,9 +562,32 @@ pub struct GetOpenIdTokenInput {
     pub logins: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for GetOpenIdTokenInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        if let Some(ref value) = self.logins {
+            errors.check_length(&field_path(path, "logins"), value.len(), None, Some(10));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "logins"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(50000));
+            }
+        }
+    }
+}
+
 /// <p>Returned in response to a successful GetOpenIdToken request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOpenIdTokenResponse {
     /// <p>A unique identifier in the format REGION:GUID. Note that the IdentityId returned may not match the one passed on input.</p>
     #[serde(rename = "IdentityId")]
@@ -282,7 +601,7 @@ pub struct GetOpenIdTokenResponse {
 
 /// <p>A description of the identity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IdentityDescription {
     /// <p>Date on which the identity was created.</p>
     #[serde(rename = "CreationDate")]
@@ -340,9 +659,92 @@ pub struct IdentityPool {
     pub supported_login_providers: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for IdentityPool {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cognito_identity_providers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "cognito_identity_providers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.developer_provider_name {
+            errors.check_length(
+                &field_path(path, "developer_provider_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "developer_provider_name"),
+                &value,
+                "[\\w._-]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_name"),
+            self.identity_pool_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_name"),
+            &self.identity_pool_name,
+            "[\\w\\s+=,.@-]+",
+        );
+        if let Some(ref value) = self.identity_pool_tags {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "identity_pool_tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        if let Some(ref value) = self.open_id_connect_provider_ar_ns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "open_id_connect_provider_ar_ns"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(20), Some(2048));
+            }
+        }
+        if let Some(ref value) = self.saml_provider_ar_ns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "saml_provider_ar_ns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(20), Some(2048));
+            }
+        }
+        if let Some(ref value) = self.supported_login_providers {
+            errors.check_length(
+                &field_path(path, "supported_login_providers"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (key, item) in value.iter() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "supported_login_providers"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, "[\\w.;_/-]+");
+            }
+        }
+    }
+}
+
 /// <p>A description of the identity pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IdentityPoolShortDescription {
     /// <p>An identity pool ID in the format REGION:GUID.</p>
     #[serde(rename = "IdentityPoolId")]
@@ -356,6 +758,7 @@ pub struct IdentityPoolShortDescription {
 
 /// <p>Input to the ListIdentities action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIdentitiesInput {
     /// <p>An optional boolean parameter that allows you to hide disabled identities. If omitted, the ListIdentities API will include disabled identities in the response.</p>
     #[serde(rename = "HideDisabled")]
@@ -373,9 +776,40 @@ pub struct ListIdentitiesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListIdentitiesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_range(
+            &field_path(path, "max_results"),
+            self.max_results,
+            Some(1),
+            Some(60),
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(65535),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+    }
+}
+
 /// <p>The response to a ListIdentities request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListIdentitiesResponse {
     /// <p>An object containing a set of identities and associated mappings.</p>
     #[serde(rename = "Identities")]
@@ -393,6 +827,7 @@ pub struct ListIdentitiesResponse {
 
 /// <p>Input to the ListIdentityPools action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIdentityPoolsInput {
     /// <p>The maximum number of identities to return.</p>
     #[serde(rename = "MaxResults")]
@@ -403,9 +838,29 @@ pub struct ListIdentityPoolsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListIdentityPoolsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "max_results"),
+            self.max_results,
+            Some(1),
+            Some(60),
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(65535),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+    }
+}
+
 /// <p>The result of a successful ListIdentityPools action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListIdentityPoolsResponse {
     /// <p>The identity pools returned by the ListIdentityPools action.</p>
     #[serde(rename = "IdentityPools")]
@@ -418,14 +873,26 @@ pub struct ListIdentityPoolsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the identity pool that the tags are assigned to.</p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags that are assigned to the identity pool.</p>
     #[serde(rename = "Tags")]
@@ -435,6 +902,7 @@ pub struct ListTagsForResourceResponse {
 
 /// <p>Input to the <code>LookupDeveloperIdentityInput</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LookupDeveloperIdentityInput {
     /// <p>A unique ID used by your backend authentication process to identify a user. Typically, a developer identity provider would issue many developer user identifiers, in keeping with the number of users.</p>
     #[serde(rename = "DeveloperUserIdentifier")]
@@ -457,9 +925,58 @@ pub struct LookupDeveloperIdentityInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for LookupDeveloperIdentityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.developer_user_identifier {
+            errors.check_length(
+                &field_path(path, "developer_user_identifier"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.identity_id {
+            errors.check_length(
+                &field_path(path, "identity_id"),
+                value.chars().count(),
+                Some(1),
+                Some(55),
+            );
+            errors.check_pattern(
+                &field_path(path, "identity_id"),
+                &value,
+                "[\\w-]+:[0-9a-f-]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(60));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(65535),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+    }
+}
+
 /// <p>Returned in response to a successful <code>LookupDeveloperIdentity</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LookupDeveloperIdentityResponse {
     /// <p>This is the list of developer user identifiers associated with an identity ID. Cognito supports the association of multiple developer user identifiers with an identity ID.</p>
     #[serde(rename = "DeveloperUserIdentifierList")]
@@ -492,8 +1009,37 @@ pub struct MappingRule {
     pub value: String,
 }
 
+impl Validate for MappingRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "claim"),
+            self.claim.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "claim"),
+            &self.claim,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(128),
+        );
+    }
+}
+
 /// <p>Input to the <code>MergeDeveloperIdentities</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MergeDeveloperIdentitiesInput {
     /// <p>User identifier for the destination user. The value should be a <code>DeveloperUserIdentifier</code>.</p>
     #[serde(rename = "DestinationUserIdentifier")]
@@ -509,9 +1055,48 @@ pub struct MergeDeveloperIdentitiesInput {
     pub source_user_identifier: String,
 }
 
+impl Validate for MergeDeveloperIdentitiesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "destination_user_identifier"),
+            self.destination_user_identifier.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "developer_provider_name"),
+            self.developer_provider_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "developer_provider_name"),
+            &self.developer_provider_name,
+            "[\\w._-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "source_user_identifier"),
+            self.source_user_identifier.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>Returned in response to a successful <code>MergeDeveloperIdentities</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MergeDeveloperIdentitiesResponse {
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
@@ -535,6 +1120,14 @@ pub struct RoleMapping {
     pub type_: String,
 }
 
+impl Validate for RoleMapping {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.rules_configuration {
+            value.validate_at(&field_path(path, "rules_configuration"), errors);
+        }
+    }
+}
+
 /// <p>A container for rules.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RulesConfigurationType {
@@ -543,8 +1136,24 @@ pub struct RulesConfigurationType {
     pub rules: Vec<MappingRule>,
 }
 
+impl Validate for RulesConfigurationType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "rules"),
+            self.rules.len(),
+            Some(1),
+            Some(400),
+        );
+        for (i, item) in self.rules.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "rules"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Input to the <code>SetIdentityPoolRoles</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityPoolRolesInput {
     /// <p>An identity pool ID in the format REGION:GUID.</p>
     #[serde(rename = "IdentityPoolId")]
@@ -558,7 +1167,41 @@ pub struct SetIdentityPoolRolesInput {
     pub roles: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for SetIdentityPoolRolesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        if let Some(ref value) = self.role_mappings {
+            errors.check_length(
+                &field_path(path, "role_mappings"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "role_mappings"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(&field_path(path, "roles"), self.roles.len(), None, Some(2));
+        for (key, item) in self.roles.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "roles"), key);
+            errors.check_length(&item_path, item.chars().count(), Some(20), Some(2048));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the identity pool to assign the tags to.</p>
     #[serde(rename = "ResourceArn")]
@@ -569,12 +1212,30 @@ pub struct TagResourceInput {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        if let Some(ref value) = self.tags {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>Input to the <code>UnlinkDeveloperIdentity</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnlinkDeveloperIdentityInput {
     /// <p>The "domain" by which Cognito will refer to your users.</p>
     #[serde(rename = "DeveloperProviderName")]
@@ -590,8 +1251,53 @@ pub struct UnlinkDeveloperIdentityInput {
     pub identity_pool_id: String,
 }
 
+impl Validate for UnlinkDeveloperIdentityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "developer_provider_name"),
+            self.developer_provider_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "developer_provider_name"),
+            &self.developer_provider_name,
+            "[\\w._-]+",
+        );
+        errors.check_length(
+            &field_path(path, "developer_user_identifier"),
+            self.developer_user_identifier.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Input to the UnlinkIdentity action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnlinkIdentityInput {
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
@@ -604,9 +1310,39 @@ pub struct UnlinkIdentityInput {
     pub logins_to_remove: Vec<String>,
 }
 
+impl Validate for UnlinkIdentityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "logins"),
+            self.logins.len(),
+            None,
+            Some(10),
+        );
+        for (key, item) in self.logins.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "logins"), key);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(50000));
+        }
+        for (i, item) in self.logins_to_remove.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "logins_to_remove"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 /// <p>An array of UnprocessedIdentityId objects, each of which contains an ErrorCode and IdentityId.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnprocessedIdentityId {
     /// <p>The error code indicating the type of error that occurred.</p>
     #[serde(rename = "ErrorCode")]
@@ -619,6 +1355,7 @@ pub struct UnprocessedIdentityId {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the identity pool that the tags are assigned to.</p>
     #[serde(rename = "ResourceArn")]
@@ -629,8 +1366,25 @@ pub struct UntagResourceInput {
     pub tag_keys: Option<Vec<String>>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 /// Errors returned by CreateIdentityPool
@@ -672,7 +1426,12 @@ impl CreateIdentityPoolError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateIdentityPoolError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -720,7 +1479,12 @@ impl DeleteIdentitiesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteIdentitiesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -775,7 +1539,12 @@ impl DeleteIdentityPoolError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteIdentityPoolError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -832,7 +1601,12 @@ impl DescribeIdentityError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeIdentityError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -895,7 +1669,12 @@ impl DescribeIdentityPoolError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -983,7 +1762,12 @@ impl GetCredentialsForIdentityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1058,7 +1842,12 @@ impl GetIdError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetIdError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1131,7 +1920,12 @@ impl GetIdentityPoolRolesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1199,7 +1993,12 @@ impl GetOpenIdTokenError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetOpenIdTokenError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1286,7 +2085,12 @@ impl GetOpenIdTokenForDeveloperIdentityError {
                         GetOpenIdTokenForDeveloperIdentityError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1347,7 +2151,12 @@ impl ListIdentitiesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListIdentitiesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1404,7 +2213,12 @@ impl ListIdentityPoolsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListIdentityPoolsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1465,7 +2279,12 @@ impl ListTagsForResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1539,7 +2358,12 @@ impl LookupDeveloperIdentityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1614,7 +2438,12 @@ impl MergeDeveloperIdentitiesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1692,7 +2521,12 @@ impl SetIdentityPoolRolesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1751,7 +2585,12 @@ impl TagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1825,7 +2664,12 @@ impl UnlinkDeveloperIdentityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1893,7 +2737,12 @@ impl UnlinkIdentityError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UnlinkIdentityError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1952,7 +2801,12 @@ impl UntagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2026,7 +2880,12 @@ impl UpdateIdentityPoolError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateIdentityPoolError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2177,64 +3036,450 @@ pub trait CognitoIdentity {
         input: IdentityPool,
     ) -> RusotoFuture<IdentityPool, UpdateIdentityPoolError>;
 }
-/// A client for the Amazon Cognito Identity API.
-#[derive(Clone)]
-pub struct CognitoIdentityClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CognitoIdentityClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CognitoIdentityClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CognitoIdentityClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CognitoIdentityClient {
-        CognitoIdentityClient { client, region }
-    }
-}
-
-impl CognitoIdentity for CognitoIdentityClient {
-    /// <p>Creates a new identity pool. The identity pool is a store of user identity information that is specific to your AWS account. The limit on identity pools is 60 per account. The keys for <code>SupportedLoginProviders</code> are as follows:</p> <ul> <li> <p>Facebook: <code>graph.facebook.com</code> </p> </li> <li> <p>Google: <code>accounts.google.com</code> </p> </li> <li> <p>Amazon: <code>www.amazon.com</code> </p> </li> <li> <p>Twitter: <code>api.twitter.com</code> </p> </li> <li> <p>Digits: <code>www.digits.com</code> </p> </li> </ul> <p>You must use AWS Developer credentials to call this API.</p>
+const _: Option<&dyn CognitoIdentity> = None;
+impl<'a, T: CognitoIdentity + ?Sized> CognitoIdentity for &'a T {
     fn create_identity_pool(
         &self,
         input: CreateIdentityPoolInput,
     ) -> RusotoFuture<IdentityPool, CreateIdentityPoolError> {
-        let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityService.CreateIdentityPool",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
-                }))
+        (**self).create_identity_pool(input)
+    }
+    fn delete_identities(
+        &self,
+        input: DeleteIdentitiesInput,
+    ) -> RusotoFuture<DeleteIdentitiesResponse, DeleteIdentitiesError> {
+        (**self).delete_identities(input)
+    }
+    fn delete_identity_pool(
+        &self,
+        input: DeleteIdentityPoolInput,
+    ) -> RusotoFuture<(), DeleteIdentityPoolError> {
+        (**self).delete_identity_pool(input)
+    }
+    fn describe_identity(
+        &self,
+        input: DescribeIdentityInput,
+    ) -> RusotoFuture<IdentityDescription, DescribeIdentityError> {
+        (**self).describe_identity(input)
+    }
+    fn describe_identity_pool(
+        &self,
+        input: DescribeIdentityPoolInput,
+    ) -> RusotoFuture<IdentityPool, DescribeIdentityPoolError> {
+        (**self).describe_identity_pool(input)
+    }
+    fn get_credentials_for_identity(
+        &self,
+        input: GetCredentialsForIdentityInput,
+    ) -> RusotoFuture<GetCredentialsForIdentityResponse, GetCredentialsForIdentityError> {
+        (**self).get_credentials_for_identity(input)
+    }
+    fn get_id(&self, input: GetIdInput) -> RusotoFuture<GetIdResponse, GetIdError> {
+        (**self).get_id(input)
+    }
+    fn get_identity_pool_roles(
+        &self,
+        input: GetIdentityPoolRolesInput,
+    ) -> RusotoFuture<GetIdentityPoolRolesResponse, GetIdentityPoolRolesError> {
+        (**self).get_identity_pool_roles(input)
+    }
+    fn get_open_id_token(
+        &self,
+        input: GetOpenIdTokenInput,
+    ) -> RusotoFuture<GetOpenIdTokenResponse, GetOpenIdTokenError> {
+        (**self).get_open_id_token(input)
+    }
+    fn get_open_id_token_for_developer_identity(
+        &self,
+        input: GetOpenIdTokenForDeveloperIdentityInput,
+    ) -> RusotoFuture<
+        GetOpenIdTokenForDeveloperIdentityResponse,
+        GetOpenIdTokenForDeveloperIdentityError,
+    > {
+        (**self).get_open_id_token_for_developer_identity(input)
+    }
+    fn list_identities(
+        &self,
+        input: ListIdentitiesInput,
+    ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        (**self).list_identities(input)
+    }
+    fn list_identity_pools(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> RusotoFuture<ListIdentityPoolsResponse, ListIdentityPoolsError> {
+        (**self).list_identity_pools(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn lookup_developer_identity(
+        &self,
+        input: LookupDeveloperIdentityInput,
+    ) -> RusotoFuture<LookupDeveloperIdentityResponse, LookupDeveloperIdentityError> {
+        (**self).lookup_developer_identity(input)
+    }
+    fn merge_developer_identities(
+        &self,
+        input: MergeDeveloperIdentitiesInput,
+    ) -> RusotoFuture<MergeDeveloperIdentitiesResponse, MergeDeveloperIdentitiesError> {
+        (**self).merge_developer_identities(input)
+    }
+    fn set_identity_pool_roles(
+        &self,
+        input: SetIdentityPoolRolesInput,
+    ) -> RusotoFuture<(), SetIdentityPoolRolesError> {
+        (**self).set_identity_pool_roles(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unlink_developer_identity(
+        &self,
+        input: UnlinkDeveloperIdentityInput,
+    ) -> RusotoFuture<(), UnlinkDeveloperIdentityError> {
+        (**self).unlink_developer_identity(input)
+    }
+    fn unlink_identity(&self, input: UnlinkIdentityInput) -> RusotoFuture<(), UnlinkIdentityError> {
+        (**self).unlink_identity(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_identity_pool(
+        &self,
+        input: IdentityPool,
+    ) -> RusotoFuture<IdentityPool, UpdateIdentityPoolError> {
+        (**self).update_identity_pool(input)
+    }
+}
+impl<T: CognitoIdentity + ?Sized> CognitoIdentity for Box<T> {
+    fn create_identity_pool(
+        &self,
+        input: CreateIdentityPoolInput,
+    ) -> RusotoFuture<IdentityPool, CreateIdentityPoolError> {
+        (**self).create_identity_pool(input)
+    }
+    fn delete_identities(
+        &self,
+        input: DeleteIdentitiesInput,
+    ) -> RusotoFuture<DeleteIdentitiesResponse, DeleteIdentitiesError> {
+        (**self).delete_identities(input)
+    }
+    fn delete_identity_pool(
+        &self,
+        input: DeleteIdentityPoolInput,
+    ) -> RusotoFuture<(), DeleteIdentityPoolError> {
+        (**self).delete_identity_pool(input)
+    }
+    fn describe_identity(
+        &self,
+        input: DescribeIdentityInput,
+    ) -> RusotoFuture<IdentityDescription, DescribeIdentityError> {
+        (**self).describe_identity(input)
+    }
+    fn describe_identity_pool(
+        &self,
+        input: DescribeIdentityPoolInput,
+    ) -> RusotoFuture<IdentityPool, DescribeIdentityPoolError> {
+        (**self).describe_identity_pool(input)
+    }
+    fn get_credentials_for_identity(
+        &self,
+        input: GetCredentialsForIdentityInput,
+    ) -> RusotoFuture<GetCredentialsForIdentityResponse, GetCredentialsForIdentityError> {
+        (**self).get_credentials_for_identity(input)
+    }
+    fn get_id(&self, input: GetIdInput) -> RusotoFuture<GetIdResponse, GetIdError> {
+        (**self).get_id(input)
+    }
+    fn get_identity_pool_roles(
+        &self,
+        input: GetIdentityPoolRolesInput,
+    ) -> RusotoFuture<GetIdentityPoolRolesResponse, GetIdentityPoolRolesError> {
+        (**self).get_identity_pool_roles(input)
+    }
+    fn get_open_id_token(
+        &self,
+        input: GetOpenIdTokenInput,
+    ) -> RusotoFuture<GetOpenIdTokenResponse, GetOpenIdTokenError> {
+        (**self).get_open_id_token(input)
+    }
+    fn get_open_id_token_for_developer_identity(
+        &self,
+        input: GetOpenIdTokenForDeveloperIdentityInput,
+    ) -> RusotoFuture<
+        GetOpenIdTokenForDeveloperIdentityResponse,
+        GetOpenIdTokenForDeveloperIdentityError,
+    > {
+        (**self).get_open_id_token_for_developer_identity(input)
+    }
+    fn list_identities(
+        &self,
+        input: ListIdentitiesInput,
+    ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        (**self).list_identities(input)
+    }
+    fn list_identity_pools(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> RusotoFuture<ListIdentityPoolsResponse, ListIdentityPoolsError> {
+        (**self).list_identity_pools(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn lookup_developer_identity(
+        &self,
+        input: LookupDeveloperIdentityInput,
+    ) -> RusotoFuture<LookupDeveloperIdentityResponse, LookupDeveloperIdentityError> {
+        (**self).lookup_developer_identity(input)
+    }
+    fn merge_developer_identities(
+        &self,
+        input: MergeDeveloperIdentitiesInput,
+    ) -> RusotoFuture<MergeDeveloperIdentitiesResponse, MergeDeveloperIdentitiesError> {
+        (**self).merge_developer_identities(input)
+    }
+    fn set_identity_pool_roles(
+        &self,
+        input: SetIdentityPoolRolesInput,
+    ) -> RusotoFuture<(), SetIdentityPoolRolesError> {
+        (**self).set_identity_pool_roles(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unlink_developer_identity(
+        &self,
+        input: UnlinkDeveloperIdentityInput,
+    ) -> RusotoFuture<(), UnlinkDeveloperIdentityError> {
+        (**self).unlink_developer_identity(input)
+    }
+    fn unlink_identity(&self, input: UnlinkIdentityInput) -> RusotoFuture<(), UnlinkIdentityError> {
+        (**self).unlink_identity(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_identity_pool(
+        &self,
+        input: IdentityPool,
+    ) -> RusotoFuture<IdentityPool, UpdateIdentityPoolError> {
+        (**self).update_identity_pool(input)
+    }
+}
+impl<T: CognitoIdentity + ?Sized> CognitoIdentity for ::std::sync::Arc<T> {
+    fn create_identity_pool(
+        &self,
+        input: CreateIdentityPoolInput,
+    ) -> RusotoFuture<IdentityPool, CreateIdentityPoolError> {
+        (**self).create_identity_pool(input)
+    }
+    fn delete_identities(
+        &self,
+        input: DeleteIdentitiesInput,
+    ) -> RusotoFuture<DeleteIdentitiesResponse, DeleteIdentitiesError> {
+        (**self).delete_identities(input)
+    }
+    fn delete_identity_pool(
+        &self,
+        input: DeleteIdentityPoolInput,
+    ) -> RusotoFuture<(), DeleteIdentityPoolError> {
+        (**self).delete_identity_pool(input)
+    }
+    fn describe_identity(
+        &self,
+        input: DescribeIdentityInput,
+    ) -> RusotoFuture<IdentityDescription, DescribeIdentityError> {
+        (**self).describe_identity(input)
+    }
+    fn describe_identity_pool(
+        &self,
+        input: DescribeIdentityPoolInput,
+    ) -> RusotoFuture<IdentityPool, DescribeIdentityPoolError> {
+        (**self).describe_identity_pool(input)
+    }
+    fn get_credentials_for_identity(
+        &self,
+        input: GetCredentialsForIdentityInput,
+    ) -> RusotoFuture<GetCredentialsForIdentityResponse, GetCredentialsForIdentityError> {
+        (**self).get_credentials_for_identity(input)
+    }
+    fn get_id(&self, input: GetIdInput) -> RusotoFuture<GetIdResponse, GetIdError> {
+        (**self).get_id(input)
+    }
+    fn get_identity_pool_roles(
+        &self,
+        input: GetIdentityPoolRolesInput,
+    ) -> RusotoFuture<GetIdentityPoolRolesResponse, GetIdentityPoolRolesError> {
+        (**self).get_identity_pool_roles(input)
+    }
+    fn get_open_id_token(
+        &self,
+        input: GetOpenIdTokenInput,
+    ) -> RusotoFuture<GetOpenIdTokenResponse, GetOpenIdTokenError> {
+        (**self).get_open_id_token(input)
+    }
+    fn get_open_id_token_for_developer_identity(
+        &self,
+        input: GetOpenIdTokenForDeveloperIdentityInput,
+    ) -> RusotoFuture<
+        GetOpenIdTokenForDeveloperIdentityResponse,
+        GetOpenIdTokenForDeveloperIdentityError,
+    > {
+        (**self).get_open_id_token_for_developer_identity(input)
+    }
+    fn list_identities(
+        &self,
+        input: ListIdentitiesInput,
+    ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        (**self).list_identities(input)
+    }
+    fn list_identity_pools(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> RusotoFuture<ListIdentityPoolsResponse, ListIdentityPoolsError> {
+        (**self).list_identity_pools(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn lookup_developer_identity(
+        &self,
+        input: LookupDeveloperIdentityInput,
+    ) -> RusotoFuture<LookupDeveloperIdentityResponse, LookupDeveloperIdentityError> {
+        (**self).lookup_developer_identity(input)
+    }
+    fn merge_developer_identities(
+        &self,
+        input: MergeDeveloperIdentitiesInput,
+    ) -> RusotoFuture<MergeDeveloperIdentitiesResponse, MergeDeveloperIdentitiesError> {
+        (**self).merge_developer_identities(input)
+    }
+    fn set_identity_pool_roles(
+        &self,
+        input: SetIdentityPoolRolesInput,
+    ) -> RusotoFuture<(), SetIdentityPoolRolesError> {
+        (**self).set_identity_pool_roles(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unlink_developer_identity(
+        &self,
+        input: UnlinkDeveloperIdentityInput,
+    ) -> RusotoFuture<(), UnlinkDeveloperIdentityError> {
+        (**self).unlink_developer_identity(input)
+    }
+    fn unlink_identity(&self, input: UnlinkIdentityInput) -> RusotoFuture<(), UnlinkIdentityError> {
+        (**self).unlink_identity(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_identity_pool(
+        &self,
+        input: IdentityPool,
+    ) -> RusotoFuture<IdentityPool, UpdateIdentityPoolError> {
+        (**self).update_identity_pool(input)
+    }
+}
+/// A client for the Amazon Cognito Identity API.
+#[derive(Clone)]
+pub struct CognitoIdentityClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CognitoIdentityClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CognitoIdentityClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CognitoIdentityClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CognitoIdentityClient {
+        CognitoIdentityClient { client, region }
+    }
+}
+
+impl CognitoIdentity for CognitoIdentityClient {
+    /// <p>Creates a new identity pool. The identity pool is a store of user identity information that is specific to your AWS account. The limit on identity pools is 60 per account. The keys for <code>SupportedLoginProviders</code> are as follows:</p> <ul> <li> <p>Facebook: <code>graph.facebook.com</code> </p> </li> <li> <p>Google: <code>accounts.google.com</code> </p> </li> <li> <p>Amazon: <code>www.amazon.com</code> </p> </li> <li> <p>Twitter: <code>api.twitter.com</code> </p> </li> <li> <p>Digits: <code>www.digits.com</code> </p> </li> </ul> <p>You must use AWS Developer credentials to call this API.</p>
+    fn create_identity_pool(
+        &self,
+        input: CreateIdentityPoolInput,
+    ) -> RusotoFuture<IdentityPool, CreateIdentityPoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("CreateIdentityPool");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.CreateIdentityPool",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<IdentityPool, _>(
+                    response,
+                    "CreateIdentityPool",
+                )
             } else {
                 Box::new(
                     response
@@ -2251,7 +3496,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: DeleteIdentitiesInput,
     ) -> RusotoFuture<DeleteIdentitiesResponse, DeleteIdentitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DeleteIdentities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.DeleteIdentities");
@@ -2260,10 +3514,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteIdentitiesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteIdentitiesResponse, _>(
+                    response,
+                    "DeleteIdentities",
+                )
             } else {
                 Box::new(
                     response
@@ -2280,7 +3534,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: DeleteIdentityPoolInput,
     ) -> RusotoFuture<(), DeleteIdentityPoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DeleteIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2309,7 +3572,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: DescribeIdentityInput,
     ) -> RusotoFuture<IdentityDescription, DescribeIdentityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DescribeIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.DescribeIdentity");
@@ -2318,10 +3590,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<IdentityDescription, _>()
-                }))
+                proto::json::deserialize_streaming::<IdentityDescription, _>(
+                    response,
+                    "DescribeIdentity",
+                )
             } else {
                 Box::new(
                     response
@@ -2338,7 +3610,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: DescribeIdentityPoolInput,
     ) -> RusotoFuture<IdentityPool, DescribeIdentityPoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DescribeIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2350,9 +3631,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
-                }))
+                proto::json::deserialize_streaming::<IdentityPool, _>(
+                    response,
+                    "DescribeIdentityPool",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2368,7 +3650,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: GetCredentialsForIdentityInput,
     ) -> RusotoFuture<GetCredentialsForIdentityResponse, GetCredentialsForIdentityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetCredentialsForIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2380,10 +3671,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCredentialsForIdentityResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetCredentialsForIdentityResponse, _>(
+                    response,
+                    "GetCredentialsForIdentity",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetCredentialsForIdentityError::from_response(response))
@@ -2394,7 +3685,16 @@ impl CognitoIdentity for CognitoIdentityClient {
 
     /// <p>Generates (or retrieves) a Cognito ID. Supplying multiple logins will create an implicit linked account.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
     fn get_id(&self, input: GetIdInput) -> RusotoFuture<GetIdResponse, GetIdError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetId");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.GetId");
@@ -2403,9 +3703,7 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetIdResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetIdResponse, _>(response, "GetId")
             } else {
                 Box::new(
                     response
@@ -2422,7 +3720,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: GetIdentityPoolRolesInput,
     ) -> RusotoFuture<GetIdentityPoolRolesResponse, GetIdentityPoolRolesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetIdentityPoolRoles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2434,10 +3741,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIdentityPoolRolesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetIdentityPoolRolesResponse, _>(
+                    response,
+                    "GetIdentityPoolRoles",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2453,7 +3760,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: GetOpenIdTokenInput,
     ) -> RusotoFuture<GetOpenIdTokenResponse, GetOpenIdTokenError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetOpenIdToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.GetOpenIdToken");
@@ -2462,10 +3778,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOpenIdTokenResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetOpenIdTokenResponse, _>(
+                    response,
+                    "GetOpenIdToken",
+                )
             } else {
                 Box::new(
                     response
@@ -2485,7 +3801,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         GetOpenIdTokenForDeveloperIdentityResponse,
         GetOpenIdTokenForDeveloperIdentityError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetOpenIdTokenForDeveloperIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2497,10 +3822,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOpenIdTokenForDeveloperIdentityResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetOpenIdTokenForDeveloperIdentityResponse, _>(
+                    response,
+                    "GetOpenIdTokenForDeveloperIdentity",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetOpenIdTokenForDeveloperIdentityError::from_response(
@@ -2516,7 +3841,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: ListIdentitiesInput,
     ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("ListIdentities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.ListIdentities");
@@ -2525,10 +3859,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListIdentitiesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListIdentitiesResponse, _>(
+                    response,
+                    "ListIdentities",
+                )
             } else {
                 Box::new(
                     response
@@ -2545,7 +3879,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: ListIdentityPoolsInput,
     ) -> RusotoFuture<ListIdentityPoolsResponse, ListIdentityPoolsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("ListIdentityPools");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2557,10 +3900,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListIdentityPoolsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListIdentityPoolsResponse, _>(
+                    response,
+                    "ListIdentityPools",
+                )
             } else {
                 Box::new(
                     response
@@ -2577,7 +3920,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2589,10 +3941,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2608,7 +3960,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: LookupDeveloperIdentityInput,
     ) -> RusotoFuture<LookupDeveloperIdentityResponse, LookupDeveloperIdentityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("LookupDeveloperIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2620,10 +3981,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<LookupDeveloperIdentityResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<LookupDeveloperIdentityResponse, _>(
+                    response,
+                    "LookupDeveloperIdentity",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(LookupDeveloperIdentityError::from_response(response))
@@ -2637,7 +3998,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: MergeDeveloperIdentitiesInput,
     ) -> RusotoFuture<MergeDeveloperIdentitiesResponse, MergeDeveloperIdentitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("MergeDeveloperIdentities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2649,10 +4019,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MergeDeveloperIdentitiesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<MergeDeveloperIdentitiesResponse, _>(
+                    response,
+                    "MergeDeveloperIdentities",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(MergeDeveloperIdentitiesError::from_response(response))
@@ -2666,7 +4036,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: SetIdentityPoolRolesInput,
     ) -> RusotoFuture<(), SetIdentityPoolRolesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("SetIdentityPoolRoles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2694,7 +4073,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: TagResourceInput,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.TagResource");
@@ -2703,10 +4091,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -2723,7 +4111,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: UnlinkDeveloperIdentityInput,
     ) -> RusotoFuture<(), UnlinkDeveloperIdentityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UnlinkDeveloperIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2746,7 +4143,16 @@ impl CognitoIdentity for CognitoIdentityClient {
 
     /// <p>Unlinks a federated identity from an existing account. Unlinked logins will be considered new identities next time they are seen. Removing the last linked login will make this identity inaccessible.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
     fn unlink_identity(&self, input: UnlinkIdentityInput) -> RusotoFuture<(), UnlinkIdentityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UnlinkIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.UnlinkIdentity");
@@ -2772,7 +4178,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.UntagResource");
@@ -2781,10 +4196,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -2801,7 +4216,16 @@ impl CognitoIdentity for CognitoIdentityClient {
         &self,
         input: IdentityPool,
     ) -> RusotoFuture<IdentityPool, UpdateIdentityPoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UpdateIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2813,9 +4237,10 @@ impl CognitoIdentity for CognitoIdentityClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
-                }))
+                proto::json::deserialize_streaming::<IdentityPool, _>(
+                    response,
+                    "UpdateIdentityPool",
+                )
             } else {
                 Box::new(
                     response
@@ -2827,3 +4252,725 @@ impl CognitoIdentity for CognitoIdentityClient {
         })
     }
 }
+
+/// A mock of the Amazon Cognito Identity API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCognitoIdentity {
+    create_identity_pool: Option<
+        Box<
+            dyn Fn(
+                    CreateIdentityPoolInput,
+                )
+                    -> ::std::result::Result<IdentityPool, RusotoError<CreateIdentityPoolError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_identities: Option<
+        Box<
+            dyn Fn(
+                    DeleteIdentitiesInput,
+                ) -> ::std::result::Result<
+                    DeleteIdentitiesResponse,
+                    RusotoError<DeleteIdentitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_identity_pool: Option<
+        Box<
+            dyn Fn(
+                    DeleteIdentityPoolInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteIdentityPoolError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_identity: Option<
+        Box<
+            dyn Fn(
+                    DescribeIdentityInput,
+                )
+                    -> ::std::result::Result<IdentityDescription, RusotoError<DescribeIdentityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_identity_pool: Option<
+        Box<
+            dyn Fn(
+                    DescribeIdentityPoolInput,
+                )
+                    -> ::std::result::Result<IdentityPool, RusotoError<DescribeIdentityPoolError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_credentials_for_identity: Option<
+        Box<
+            dyn Fn(
+                    GetCredentialsForIdentityInput,
+                ) -> ::std::result::Result<
+                    GetCredentialsForIdentityResponse,
+                    RusotoError<GetCredentialsForIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_id: Option<
+        Box<
+            dyn Fn(GetIdInput) -> ::std::result::Result<GetIdResponse, RusotoError<GetIdError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_identity_pool_roles: Option<
+        Box<
+            dyn Fn(
+                    GetIdentityPoolRolesInput,
+                ) -> ::std::result::Result<
+                    GetIdentityPoolRolesResponse,
+                    RusotoError<GetIdentityPoolRolesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_open_id_token: Option<
+        Box<
+            dyn Fn(
+                    GetOpenIdTokenInput,
+                ) -> ::std::result::Result<
+                    GetOpenIdTokenResponse,
+                    RusotoError<GetOpenIdTokenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_open_id_token_for_developer_identity: Option<
+        Box<
+            dyn Fn(
+                    GetOpenIdTokenForDeveloperIdentityInput,
+                ) -> ::std::result::Result<
+                    GetOpenIdTokenForDeveloperIdentityResponse,
+                    RusotoError<GetOpenIdTokenForDeveloperIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_identities: Option<
+        Box<
+            dyn Fn(
+                    ListIdentitiesInput,
+                ) -> ::std::result::Result<
+                    ListIdentitiesResponse,
+                    RusotoError<ListIdentitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_identity_pools: Option<
+        Box<
+            dyn Fn(
+                    ListIdentityPoolsInput,
+                ) -> ::std::result::Result<
+                    ListIdentityPoolsResponse,
+                    RusotoError<ListIdentityPoolsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    lookup_developer_identity: Option<
+        Box<
+            dyn Fn(
+                    LookupDeveloperIdentityInput,
+                ) -> ::std::result::Result<
+                    LookupDeveloperIdentityResponse,
+                    RusotoError<LookupDeveloperIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    merge_developer_identities: Option<
+        Box<
+            dyn Fn(
+                    MergeDeveloperIdentitiesInput,
+                ) -> ::std::result::Result<
+                    MergeDeveloperIdentitiesResponse,
+                    RusotoError<MergeDeveloperIdentitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_identity_pool_roles: Option<
+        Box<
+            dyn Fn(
+                    SetIdentityPoolRolesInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetIdentityPoolRolesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceInput,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    unlink_developer_identity: Option<
+        Box<
+            dyn Fn(
+                    UnlinkDeveloperIdentityInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<UnlinkDeveloperIdentityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    unlink_identity: Option<
+        Box<
+            dyn Fn(
+                    UnlinkIdentityInput,
+                ) -> ::std::result::Result<(), RusotoError<UnlinkIdentityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceInput,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_identity_pool: Option<
+        Box<
+            dyn Fn(
+                    IdentityPool,
+                )
+                    -> ::std::result::Result<IdentityPool, RusotoError<UpdateIdentityPoolError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCognitoIdentity {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCognitoIdentity {
+        Default::default()
+    }
+
+    /// Answers `create_identity_pool` with what `handler` returns for its input.
+    pub fn with_create_identity_pool<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                CreateIdentityPoolInput,
+            )
+                -> ::std::result::Result<IdentityPool, RusotoError<CreateIdentityPoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_identity_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_identities` with what `handler` returns for its input.
+    pub fn with_delete_identities<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                DeleteIdentitiesInput,
+            ) -> ::std::result::Result<
+                DeleteIdentitiesResponse,
+                RusotoError<DeleteIdentitiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_identities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_identity_pool` with what `handler` returns for its input.
+    pub fn with_delete_identity_pool<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                DeleteIdentityPoolInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteIdentityPoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_identity_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_identity` with what `handler` returns for its input.
+    pub fn with_describe_identity<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                DescribeIdentityInput,
+            )
+                -> ::std::result::Result<IdentityDescription, RusotoError<DescribeIdentityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_identity_pool` with what `handler` returns for its input.
+    pub fn with_describe_identity_pool<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                DescribeIdentityPoolInput,
+            )
+                -> ::std::result::Result<IdentityPool, RusotoError<DescribeIdentityPoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_identity_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_credentials_for_identity` with what `handler` returns for its input.
+    pub fn with_get_credentials_for_identity<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                GetCredentialsForIdentityInput,
+            ) -> ::std::result::Result<
+                GetCredentialsForIdentityResponse,
+                RusotoError<GetCredentialsForIdentityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_credentials_for_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_id` with what `handler` returns for its input.
+    pub fn with_get_id<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(GetIdInput) -> ::std::result::Result<GetIdResponse, RusotoError<GetIdError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_id = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_identity_pool_roles` with what `handler` returns for its input.
+    pub fn with_get_identity_pool_roles<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                GetIdentityPoolRolesInput,
+            ) -> ::std::result::Result<
+                GetIdentityPoolRolesResponse,
+                RusotoError<GetIdentityPoolRolesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_identity_pool_roles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_open_id_token` with what `handler` returns for its input.
+    pub fn with_get_open_id_token<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                GetOpenIdTokenInput,
+            )
+                -> ::std::result::Result<GetOpenIdTokenResponse, RusotoError<GetOpenIdTokenError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_open_id_token = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_open_id_token_for_developer_identity` with what `handler` returns for its input.
+    pub fn with_get_open_id_token_for_developer_identity<F>(
+        mut self,
+        handler: F,
+    ) -> MockCognitoIdentity
+    where
+        F: Fn(
+                GetOpenIdTokenForDeveloperIdentityInput,
+            ) -> ::std::result::Result<
+                GetOpenIdTokenForDeveloperIdentityResponse,
+                RusotoError<GetOpenIdTokenForDeveloperIdentityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_open_id_token_for_developer_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_identities` with what `handler` returns for its input.
+    pub fn with_list_identities<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                ListIdentitiesInput,
+            )
+                -> ::std::result::Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_identities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_identity_pools` with what `handler` returns for its input.
+    pub fn with_list_identity_pools<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                ListIdentityPoolsInput,
+            ) -> ::std::result::Result<
+                ListIdentityPoolsResponse,
+                RusotoError<ListIdentityPoolsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_identity_pools = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `lookup_developer_identity` with what `handler` returns for its input.
+    pub fn with_lookup_developer_identity<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                LookupDeveloperIdentityInput,
+            ) -> ::std::result::Result<
+                LookupDeveloperIdentityResponse,
+                RusotoError<LookupDeveloperIdentityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.lookup_developer_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `merge_developer_identities` with what `handler` returns for its input.
+    pub fn with_merge_developer_identities<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                MergeDeveloperIdentitiesInput,
+            ) -> ::std::result::Result<
+                MergeDeveloperIdentitiesResponse,
+                RusotoError<MergeDeveloperIdentitiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.merge_developer_identities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_identity_pool_roles` with what `handler` returns for its input.
+    pub fn with_set_identity_pool_roles<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                SetIdentityPoolRolesInput,
+            ) -> ::std::result::Result<(), RusotoError<SetIdentityPoolRolesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_identity_pool_roles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                TagResourceInput,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unlink_developer_identity` with what `handler` returns for its input.
+    pub fn with_unlink_developer_identity<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                UnlinkDeveloperIdentityInput,
+            ) -> ::std::result::Result<(), RusotoError<UnlinkDeveloperIdentityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.unlink_developer_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unlink_identity` with what `handler` returns for its input.
+    pub fn with_unlink_identity<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(UnlinkIdentityInput) -> ::std::result::Result<(), RusotoError<UnlinkIdentityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.unlink_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                UntagResourceInput,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_identity_pool` with what `handler` returns for its input.
+    pub fn with_update_identity_pool<F>(mut self, handler: F) -> MockCognitoIdentity
+    where
+        F: Fn(
+                IdentityPool,
+            )
+                -> ::std::result::Result<IdentityPool, RusotoError<UpdateIdentityPoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_identity_pool = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CognitoIdentity for MockCognitoIdentity {
+    fn create_identity_pool(
+        &self,
+        input: CreateIdentityPoolInput,
+    ) -> RusotoFuture<IdentityPool, CreateIdentityPoolError> {
+        match self.create_identity_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for create_identity_pool"),
+        }
+    }
+    fn delete_identities(
+        &self,
+        input: DeleteIdentitiesInput,
+    ) -> RusotoFuture<DeleteIdentitiesResponse, DeleteIdentitiesError> {
+        match self.delete_identities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for delete_identities"),
+        }
+    }
+    fn delete_identity_pool(
+        &self,
+        input: DeleteIdentityPoolInput,
+    ) -> RusotoFuture<(), DeleteIdentityPoolError> {
+        match self.delete_identity_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for delete_identity_pool"),
+        }
+    }
+    fn describe_identity(
+        &self,
+        input: DescribeIdentityInput,
+    ) -> RusotoFuture<IdentityDescription, DescribeIdentityError> {
+        match self.describe_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for describe_identity"),
+        }
+    }
+    fn describe_identity_pool(
+        &self,
+        input: DescribeIdentityPoolInput,
+    ) -> RusotoFuture<IdentityPool, DescribeIdentityPoolError> {
+        match self.describe_identity_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for describe_identity_pool"),
+        }
+    }
+    fn get_credentials_for_identity(
+        &self,
+        input: GetCredentialsForIdentityInput,
+    ) -> RusotoFuture<GetCredentialsForIdentityResponse, GetCredentialsForIdentityError> {
+        match self.get_credentials_for_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for get_credentials_for_identity"),
+        }
+    }
+    fn get_id(&self, input: GetIdInput) -> RusotoFuture<GetIdResponse, GetIdError> {
+        match self.get_id {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for get_id"),
+        }
+    }
+    fn get_identity_pool_roles(
+        &self,
+        input: GetIdentityPoolRolesInput,
+    ) -> RusotoFuture<GetIdentityPoolRolesResponse, GetIdentityPoolRolesError> {
+        match self.get_identity_pool_roles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for get_identity_pool_roles"),
+        }
+    }
+    fn get_open_id_token(
+        &self,
+        input: GetOpenIdTokenInput,
+    ) -> RusotoFuture<GetOpenIdTokenResponse, GetOpenIdTokenError> {
+        match self.get_open_id_token {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for get_open_id_token"),
+        }
+    }
+    fn get_open_id_token_for_developer_identity(
+        &self,
+        input: GetOpenIdTokenForDeveloperIdentityInput,
+    ) -> RusotoFuture<
+        GetOpenIdTokenForDeveloperIdentityResponse,
+        GetOpenIdTokenForDeveloperIdentityError,
+    > {
+        match self.get_open_id_token_for_developer_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCognitoIdentity has no handler for get_open_id_token_for_developer_identity"
+            ),
+        }
+    }
+    fn list_identities(
+        &self,
+        input: ListIdentitiesInput,
+    ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        match self.list_identities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for list_identities"),
+        }
+    }
+    fn list_identity_pools(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> RusotoFuture<ListIdentityPoolsResponse, ListIdentityPoolsError> {
+        match self.list_identity_pools {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for list_identity_pools"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for list_tags_for_resource"),
+        }
+    }
+    fn lookup_developer_identity(
+        &self,
+        input: LookupDeveloperIdentityInput,
+    ) -> RusotoFuture<LookupDeveloperIdentityResponse, LookupDeveloperIdentityError> {
+        match self.lookup_developer_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for lookup_developer_identity"),
+        }
+    }
+    fn merge_developer_identities(
+        &self,
+        input: MergeDeveloperIdentitiesInput,
+    ) -> RusotoFuture<MergeDeveloperIdentitiesResponse, MergeDeveloperIdentitiesError> {
+        match self.merge_developer_identities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for merge_developer_identities"),
+        }
+    }
+    fn set_identity_pool_roles(
+        &self,
+        input: SetIdentityPoolRolesInput,
+    ) -> RusotoFuture<(), SetIdentityPoolRolesError> {
+        match self.set_identity_pool_roles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for set_identity_pool_roles"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for tag_resource"),
+        }
+    }
+    fn unlink_developer_identity(
+        &self,
+        input: UnlinkDeveloperIdentityInput,
+    ) -> RusotoFuture<(), UnlinkDeveloperIdentityError> {
+        match self.unlink_developer_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for unlink_developer_identity"),
+        }
+    }
+    fn unlink_identity(&self, input: UnlinkIdentityInput) -> RusotoFuture<(), UnlinkIdentityError> {
+        match self.unlink_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for unlink_identity"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for untag_resource"),
+        }
+    }
+    fn update_identity_pool(
+        &self,
+        input: IdentityPool,
+    ) -> RusotoFuture<IdentityPool, UpdateIdentityPoolError> {
+        match self.update_identity_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentity has no handler for update_identity_pool"),
+        }
+    }
+}
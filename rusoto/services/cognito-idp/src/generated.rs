@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Account takeover action type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -63,8 +64,17 @@ pub struct AccountTakeoverRiskConfigurationType {
     pub notify_configuration: Option<NotifyConfigurationType>,
 }
 
+impl Validate for AccountTakeoverRiskConfigurationType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.notify_configuration {
+            value.validate_at(&field_path(path, "notify_configuration"), errors);
+        }
+    }
+}
+
 /// <p>Represents the request to add custom attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddCustomAttributesRequest {
     /// <p>An array of custom attributes, such as Mutable and Name.</p>
     #[serde(rename = "CustomAttributes")]
@@ -74,12 +84,39 @@ pub struct AddCustomAttributesRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for AddCustomAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "custom_attributes"),
+            self.custom_attributes.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.custom_attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "custom_attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server for the request to add custom attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddCustomAttributesResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminAddUserToGroupRequest {
     /// <p>The group name.</p>
     #[serde(rename = "GroupName")]
@@ -92,8 +129,47 @@ pub struct AdminAddUserToGroupRequest {
     pub username: String,
 }
 
+impl Validate for AdminAddUserToGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_name"),
+            self.group_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_name"),
+            &self.group_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the request to confirm user registration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminConfirmSignUpRequest {
     /// <p>The user pool ID for which you want to confirm user registration.</p>
     #[serde(rename = "UserPoolId")]
@@ -103,9 +179,36 @@ pub struct AdminConfirmSignUpRequest {
     pub username: String,
 }
 
+impl Validate for AdminConfirmSignUpRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server for the request to confirm registration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminConfirmSignUpResponse {}
 
 /// <p>The configuration for creating a new user profile.</p>
@@ -125,8 +228,25 @@ pub struct AdminCreateUserConfigType {
     pub unused_account_validity_days: Option<i64>,
 }
 
+impl Validate for AdminCreateUserConfigType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.invite_message_template {
+            value.validate_at(&field_path(path, "invite_message_template"), errors);
+        }
+        if let Some(ref value) = self.unused_account_validity_days {
+            errors.check_range(
+                &field_path(path, "unused_account_validity_days"),
+                *value,
+                None,
+                Some(365),
+            );
+        }
+    }
+}
+
 /// <p>Represents the request to create a user in the specified user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminCreateUserRequest {
     /// <p>Specify <code>"EMAIL"</code> if email will be used to send the welcome message. Specify <code>"SMS"</code> if the phone number will be used. The default value is <code>"SMS"</code>. More than one value can be specified.</p>
     #[serde(rename = "DesiredDeliveryMediums")]
@@ -160,9 +280,57 @@ pub struct AdminCreateUserRequest {
     pub validation_data: Option<Vec<AttributeType>>,
 }
 
+impl Validate for AdminCreateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.temporary_password {
+            errors.check_length(
+                &field_path(path, "temporary_password"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "temporary_password"), &value, "[\\S]+");
+        }
+        if let Some(ref value) = self.user_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "user_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        if let Some(ref value) = self.validation_data {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "validation_data"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Represents the response from the server to the request to create the user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminCreateUserResponse {
     /// <p>The newly created user.</p>
     #[serde(rename = "User")]
@@ -172,6 +340,7 @@ pub struct AdminCreateUserResponse {
 
 /// <p>Represents the request to delete user attributes as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminDeleteUserAttributesRequest {
     /// <p>An array of strings representing the user attribute names you wish to delete.</p> <p>For custom attributes, you must prepend the <code>custom:</code> prefix to the attribute name.</p>
     #[serde(rename = "UserAttributeNames")]
@@ -184,13 +353,46 @@ pub struct AdminDeleteUserAttributesRequest {
     pub username: String,
 }
 
+impl Validate for AdminDeleteUserAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.user_attribute_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "user_attribute_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+            errors.check_pattern(&item_path, &item, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the response received from the server for a request to delete user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminDeleteUserAttributesResponse {}
 
 /// <p>Represents the request to delete a user as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminDeleteUserRequest {
     /// <p>The user pool ID for the user pool where you want to delete the user.</p>
     #[serde(rename = "UserPoolId")]
@@ -200,7 +402,35 @@ pub struct AdminDeleteUserRequest {
     pub username: String,
 }
 
+impl Validate for AdminDeleteUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminDisableProviderForUserRequest {
     /// <p>The user to be disabled.</p>
     #[serde(rename = "User")]
@@ -210,12 +440,25 @@ pub struct AdminDisableProviderForUserRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for AdminDisableProviderForUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.user.validate_at(&field_path(path, "user"), errors);
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            None,
+            Some(131072),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminDisableProviderForUserResponse {}
 
 /// <p>Represents the request to disable any user as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminDisableUserRequest {
     /// <p>The user pool ID for the user pool where you want to disable the user.</p>
     #[serde(rename = "UserPoolId")]
@@ -225,13 +468,41 @@ pub struct AdminDisableUserRequest {
     pub username: String,
 }
 
+impl Validate for AdminDisableUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the response received from the server to disable the user as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminDisableUserResponse {}
 
 /// <p>Represents the request that enables the user as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminEnableUserRequest {
     /// <p>The user pool ID for the user pool where you want to enable the user.</p>
     #[serde(rename = "UserPoolId")]
@@ -241,13 +512,41 @@ pub struct AdminEnableUserRequest {
     pub username: String,
 }
 
+impl Validate for AdminEnableUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server for the request to enable a user as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminEnableUserResponse {}
 
 /// <p>Sends the forgot device request, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminForgetDeviceRequest {
     /// <p>The device key.</p>
     #[serde(rename = "DeviceKey")]
@@ -260,8 +559,47 @@ pub struct AdminForgetDeviceRequest {
     pub username: String,
 }
 
+impl Validate for AdminForgetDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "device_key"),
+            self.device_key.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_key"),
+            &self.device_key,
+            "[\\w-]+_[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the request to get the device, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminGetDeviceRequest {
     /// <p>The device key.</p>
     #[serde(rename = "DeviceKey")]
@@ -274,9 +612,47 @@ pub struct AdminGetDeviceRequest {
     pub username: String,
 }
 
+impl Validate for AdminGetDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "device_key"),
+            self.device_key.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_key"),
+            &self.device_key,
+            "[\\w-]+_[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Gets the device response, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminGetDeviceResponse {
     /// <p>The device.</p>
     #[serde(rename = "Device")]
@@ -285,6 +661,7 @@ pub struct AdminGetDeviceResponse {
 
 /// <p>Represents the request to get the specified user as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminGetUserRequest {
     /// <p>The user pool ID for the user pool where you want to get information about the user.</p>
     #[serde(rename = "UserPoolId")]
@@ -294,9 +671,36 @@ pub struct AdminGetUserRequest {
     pub username: String,
 }
 
+impl Validate for AdminGetUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server from the request to get the specified user as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminGetUserResponse {
     /// <p>Indicates that the status is enabled.</p>
     #[serde(rename = "Enabled")]
@@ -337,6 +741,7 @@ pub struct AdminGetUserResponse {
 
 /// <p>Initiates the authorization request, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminInitiateAuthRequest {
     /// <p>The analytics metadata for collecting Amazon Pinpoint metrics for <code>AdminInitiateAuth</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -365,9 +770,50 @@ pub struct AdminInitiateAuthRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for AdminInitiateAuthRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analytics_metadata {
+            value.validate_at(&field_path(path, "analytics_metadata"), errors);
+        }
+        if let Some(ref value) = self.auth_parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "auth_parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        if let Some(ref value) = self.client_metadata {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "client_metadata"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+        if let Some(ref value) = self.context_data {
+            value.validate_at(&field_path(path, "context_data"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Initiates the authentication response, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminInitiateAuthResponse {
     /// <p>The result of the authentication response. This is only returned if the caller does not need to pass another challenge. If the caller does need to pass another challenge before it gets tokens, <code>ChallengeName</code>, <code>ChallengeParameters</code>, and <code>Session</code> are returned.</p>
     #[serde(rename = "AuthenticationResult")]
@@ -388,6 +834,7 @@ pub struct AdminInitiateAuthResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminLinkProviderForUserRequest {
     /// <p>The existing user in the user pool to be linked to the external identity provider user account. Can be a native (Username + Password) Cognito User Pools user or a federated user (for example, a SAML or Facebook user). If the user doesn't exist, an exception is thrown. This is the user that is returned when the new user (with the linked identity provider attribute) signs in.</p> <p>For a native username + password user, the <code>ProviderAttributeValue</code> for the <code>DestinationUser</code> should be the username in the user pool. For a federated user, it should be the provider-specific <code>user_id</code>.</p> <p>The <code>ProviderAttributeName</code> of the <code>DestinationUser</code> is ignored.</p> <p>The <code>ProviderName</code> should be set to <code>Cognito</code> for users in Cognito user pools.</p>
     #[serde(rename = "DestinationUser")]
@@ -400,12 +847,28 @@ pub struct AdminLinkProviderForUserRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for AdminLinkProviderForUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.destination_user
+            .validate_at(&field_path(path, "destination_user"), errors);
+        self.source_user
+            .validate_at(&field_path(path, "source_user"), errors);
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            None,
+            Some(131072),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminLinkProviderForUserResponse {}
 
 /// <p>Represents the request to list devices, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminListDevicesRequest {
     /// <p>The limit of the devices request.</p>
     #[serde(rename = "Limit")]
@@ -423,9 +886,48 @@ pub struct AdminListDevicesRequest {
     pub username: String,
 }
 
+impl Validate for AdminListDevicesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(60));
+        }
+        if let Some(ref value) = self.pagination_token {
+            errors.check_length(
+                &field_path(path, "pagination_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "pagination_token"), &value, "[\\S]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Lists the device's response, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminListDevicesResponse {
     /// <p>The devices in the list of devices response.</p>
     #[serde(rename = "Devices")]
@@ -438,6 +940,7 @@ pub struct AdminListDevicesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminListGroupsForUserRequest {
     /// <p>The limit of the request to list groups.</p>
     #[serde(rename = "Limit")]
@@ -455,8 +958,47 @@ pub struct AdminListGroupsForUserRequest {
     pub username: String,
 }
 
+impl Validate for AdminListGroupsForUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(60));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(131072),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminListGroupsForUserResponse {
     /// <p>The groups that the user belongs to.</p>
     #[serde(rename = "Groups")]
@@ -469,6 +1011,7 @@ pub struct AdminListGroupsForUserResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminListUserAuthEventsRequest {
     /// <p>The maximum number of authentication events to return.</p>
     #[serde(rename = "MaxResults")]
@@ -486,8 +1029,47 @@ pub struct AdminListUserAuthEventsRequest {
     pub username: String,
 }
 
+impl Validate for AdminListUserAuthEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(60));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(131072),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminListUserAuthEventsResponse {
     /// <p>The response object. It includes the <code>EventID</code>, <code>EventType</code>, <code>CreationDate</code>, <code>EventRisk</code>, and <code>EventResponse</code>.</p>
     #[serde(rename = "AuthEvents")]
@@ -500,6 +1082,7 @@ pub struct AdminListUserAuthEventsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminRemoveUserFromGroupRequest {
     /// <p>The group name.</p>
     #[serde(rename = "GroupName")]
@@ -512,8 +1095,47 @@ pub struct AdminRemoveUserFromGroupRequest {
     pub username: String,
 }
 
+impl Validate for AdminRemoveUserFromGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_name"),
+            self.group_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_name"),
+            &self.group_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the request to reset a user's password as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminResetUserPasswordRequest {
     /// <p>The user pool ID for the user pool where you want to reset the user's password.</p>
     #[serde(rename = "UserPoolId")]
@@ -523,13 +1145,41 @@ pub struct AdminResetUserPasswordRequest {
     pub username: String,
 }
 
+impl Validate for AdminResetUserPasswordRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server to reset a user password as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminResetUserPasswordResponse {}
 
 /// <p>The request to respond to the authentication challenge, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminRespondToAuthChallengeRequest {
     /// <p>The analytics metadata for collecting Amazon Pinpoint metrics for <code>AdminRespondToAuthChallenge</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -558,9 +1208,52 @@ pub struct AdminRespondToAuthChallengeRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for AdminRespondToAuthChallengeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analytics_metadata {
+            value.validate_at(&field_path(path, "analytics_metadata"), errors);
+        }
+        if let Some(ref value) = self.challenge_responses {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "challenge_responses"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        if let Some(ref value) = self.context_data {
+            value.validate_at(&field_path(path, "context_data"), errors);
+        }
+        if let Some(ref value) = self.session {
+            errors.check_length(
+                &field_path(path, "session"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Responds to the authentication challenge, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminRespondToAuthChallengeResponse {
     /// <p>The result returned by the server in response to the authentication request.</p>
     #[serde(rename = "AuthenticationResult")]
@@ -581,6 +1274,7 @@ pub struct AdminRespondToAuthChallengeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminSetUserMFAPreferenceRequest {
     /// <p>The SMS text message MFA settings.</p>
     #[serde(rename = "SMSMfaSettings")]
@@ -598,11 +1292,39 @@ pub struct AdminSetUserMFAPreferenceRequest {
     pub username: String,
 }
 
+impl Validate for AdminSetUserMFAPreferenceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminSetUserMFAPreferenceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminSetUserPasswordRequest {
     #[serde(rename = "Password")]
     pub password: String,
@@ -615,12 +1337,47 @@ pub struct AdminSetUserPasswordRequest {
     pub username: String,
 }
 
+impl Validate for AdminSetUserPasswordRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "password"), &self.password, "[\\S]+");
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminSetUserPasswordResponse {}
 
 /// <p>Represents the request to set user settings as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminSetUserSettingsRequest {
     /// <p>Specifies the options for MFA (e.g., email or phone number).</p>
     #[serde(rename = "MFAOptions")]
@@ -633,12 +1390,44 @@ pub struct AdminSetUserSettingsRequest {
     pub username: String,
 }
 
+impl Validate for AdminSetUserSettingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.mfa_options.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "mfa_options"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server to set user settings as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminSetUserSettingsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminUpdateAuthEventFeedbackRequest {
     /// <p>The authentication event ID.</p>
     #[serde(rename = "EventId")]
@@ -654,12 +1443,47 @@ pub struct AdminUpdateAuthEventFeedbackRequest {
     pub username: String,
 }
 
+impl Validate for AdminUpdateAuthEventFeedbackRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "event_id"),
+            self.event_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "event_id"), &self.event_id, "[\\w+-]+");
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminUpdateAuthEventFeedbackResponse {}
 
 /// <p>The request to update the device status, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminUpdateDeviceStatusRequest {
     /// <p>The device key.</p>
     #[serde(rename = "DeviceKey")]
@@ -676,13 +1500,52 @@ pub struct AdminUpdateDeviceStatusRequest {
     pub username: String,
 }
 
+impl Validate for AdminUpdateDeviceStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "device_key"),
+            self.device_key.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_key"),
+            &self.device_key,
+            "[\\w-]+_[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>The status response from the request to update the device, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminUpdateDeviceStatusResponse {}
 
 /// <p>Represents the request to update the user's attributes as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminUpdateUserAttributesRequest {
     /// <p>An array of name-value pairs representing user attributes.</p> <p>For custom attributes, you must prepend the <code>custom:</code> prefix to the attribute name.</p>
     #[serde(rename = "UserAttributes")]
@@ -695,13 +1558,45 @@ pub struct AdminUpdateUserAttributesRequest {
     pub username: String,
 }
 
+impl Validate for AdminUpdateUserAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.user_attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "user_attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server for the request to update user attributes as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminUpdateUserAttributesResponse {}
 
 /// <p>The request to sign out of all devices, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdminUserGlobalSignOutRequest {
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
@@ -711,9 +1606,36 @@ pub struct AdminUserGlobalSignOutRequest {
     pub username: String,
 }
 
+impl Validate for AdminUserGlobalSignOutRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>The global sign-out response, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AdminUserGlobalSignOutResponse {}
 
 /// <p>The Amazon Pinpoint analytics configuration for collecting metrics for a user pool.</p>
@@ -734,8 +1656,32 @@ pub struct AnalyticsConfigurationType {
     pub user_data_shared: Option<bool>,
 }
 
+impl Validate for AnalyticsConfigurationType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "application_id"),
+            &self.application_id,
+            "^[0-9a-fA-F]+$",
+        );
+        errors.check_length(
+            &field_path(path, "external_id"),
+            self.external_id.chars().count(),
+            None,
+            Some(131072),
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "role_arn"), &self.role_arn, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+    }
+}
+
 /// <p>An Amazon Pinpoint analytics endpoint.</p> <p>An endpoint uniquely identifies a mobile device, email address, or phone number that can receive messages from Amazon Pinpoint analytics.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnalyticsMetadataType {
     /// <p>The endpoint ID.</p>
     #[serde(rename = "AnalyticsEndpointId")]
@@ -743,7 +1689,21 @@ pub struct AnalyticsMetadataType {
     pub analytics_endpoint_id: Option<String>,
 }
 
+impl Validate for AnalyticsMetadataType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analytics_endpoint_id {
+            errors.check_length(
+                &field_path(path, "analytics_endpoint_id"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateSoftwareTokenRequest {
     /// <p>The access token.</p>
     #[serde(rename = "AccessToken")]
@@ -755,8 +1715,28 @@ pub struct AssociateSoftwareTokenRequest {
     pub session: Option<String>,
 }
 
+impl Validate for AssociateSoftwareTokenRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.access_token {
+            errors.check_pattern(
+                &field_path(path, "access_token"),
+                &value,
+                "[A-Za-z0-9-_=.]+",
+            );
+        }
+        if let Some(ref value) = self.session {
+            errors.check_length(
+                &field_path(path, "session"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateSoftwareTokenResponse {
     /// <p>A unique generated shared secret code that is used in the TOTP algorithm to generate a one time code.</p>
     #[serde(rename = "SecretCode")]
@@ -780,9 +1760,33 @@ pub struct AttributeType {
     pub value: Option<String>,
 }
 
+impl Validate for AttributeType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+    }
+}
+
 /// <p>The authentication event type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AuthEventType {
     /// <p>The challenge responses.</p>
     #[serde(rename = "ChallengeResponses")]
@@ -820,7 +1824,7 @@ pub struct AuthEventType {
 
 /// <p>The authentication result.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AuthenticationResultType {
     /// <p>The access token.</p>
     #[serde(rename = "AccessToken")]
@@ -850,7 +1854,7 @@ pub struct AuthenticationResultType {
 
 /// <p>The challenge response type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChallengeResponseType {
     /// <p>The challenge name</p>
     #[serde(rename = "ChallengeName")]
@@ -864,6 +1868,7 @@ pub struct ChallengeResponseType {
 
 /// <p>Represents the request to change a user password.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangePasswordRequest {
     /// <p>The access token.</p>
     #[serde(rename = "AccessToken")]
@@ -876,14 +1881,46 @@ pub struct ChangePasswordRequest {
     pub proposed_password: String,
 }
 
+impl Validate for ChangePasswordRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+        errors.check_length(
+            &field_path(path, "previous_password"),
+            self.previous_password.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "previous_password"),
+            &self.previous_password,
+            "[\\S]+",
+        );
+        errors.check_length(
+            &field_path(path, "proposed_password"),
+            self.proposed_password.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "proposed_password"),
+            &self.proposed_password,
+            "[\\S]+",
+        );
+    }
+}
+
 /// <p>The response from the server to the change password request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChangePasswordResponse {}
 
 /// <p>The code delivery details being returned from the server.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CodeDeliveryDetailsType {
     /// <p>The attribute name.</p>
     #[serde(rename = "AttributeName")]
@@ -921,6 +1958,7 @@ pub struct CompromisedCredentialsRiskConfigurationType {
 
 /// <p>Confirms the device request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfirmDeviceRequest {
     /// <p>The access token.</p>
     #[serde(rename = "AccessToken")]
@@ -938,9 +1976,41 @@ pub struct ConfirmDeviceRequest {
     pub device_secret_verifier_config: Option<DeviceSecretVerifierConfigType>,
 }
 
+impl Validate for ConfirmDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+        errors.check_length(
+            &field_path(path, "device_key"),
+            self.device_key.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_key"),
+            &self.device_key,
+            "[\\w-]+_[0-9a-f-]+",
+        );
+        if let Some(ref value) = self.device_name {
+            errors.check_length(
+                &field_path(path, "device_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.device_secret_verifier_config {
+            value.validate_at(&field_path(path, "device_secret_verifier_config"), errors);
+        }
+    }
+}
+
 /// <p>Confirms the device response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfirmDeviceResponse {
     /// <p>Indicates whether the user confirmation is necessary to confirm the device response.</p>
     #[serde(rename = "UserConfirmationNecessary")]
@@ -950,6 +2020,7 @@ pub struct ConfirmDeviceResponse {
 
 /// <p>The request representing the confirmation for a password reset.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfirmForgotPasswordRequest {
     /// <p>The Amazon Pinpoint analytics metadata for collecting metrics for <code>ConfirmForgotPassword</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -977,13 +2048,70 @@ pub struct ConfirmForgotPasswordRequest {
     pub username: String,
 }
 
+impl Validate for ConfirmForgotPasswordRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analytics_metadata {
+            value.validate_at(&field_path(path, "analytics_metadata"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        errors.check_length(
+            &field_path(path, "confirmation_code"),
+            self.confirmation_code.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "confirmation_code"),
+            &self.confirmation_code,
+            "[\\S]+",
+        );
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "password"), &self.password, "[\\S]+");
+        if let Some(ref value) = self.secret_hash {
+            errors.check_length(
+                &field_path(path, "secret_hash"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "secret_hash"), &value, "[\\w+=/]+");
+        }
+        if let Some(ref value) = self.user_context_data {
+            value.validate_at(&field_path(path, "user_context_data"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>The response from the server that results from a user's request to retrieve a forgotten password.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfirmForgotPasswordResponse {}
 
 /// <p>Represents the request to confirm registration of a user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfirmSignUpRequest {
     /// <p>The Amazon Pinpoint analytics metadata for collecting metrics for <code>ConfirmSignUp</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -1012,13 +2140,63 @@ pub struct ConfirmSignUpRequest {
     pub username: String,
 }
 
+impl Validate for ConfirmSignUpRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analytics_metadata {
+            value.validate_at(&field_path(path, "analytics_metadata"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        errors.check_length(
+            &field_path(path, "confirmation_code"),
+            self.confirmation_code.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "confirmation_code"),
+            &self.confirmation_code,
+            "[\\S]+",
+        );
+        if let Some(ref value) = self.secret_hash {
+            errors.check_length(
+                &field_path(path, "secret_hash"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "secret_hash"), &value, "[\\w+=/]+");
+        }
+        if let Some(ref value) = self.user_context_data {
+            value.validate_at(&field_path(path, "user_context_data"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server for the registration confirmation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfirmSignUpResponse {}
 
 /// <p>Contextual user data type used for evaluating the risk of an unexpected event by Amazon Cognito advanced security.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContextDataType {
     /// <p>Encoded data containing device fingerprinting details, collected using the Amazon Cognito context data collection library.</p>
     #[serde(rename = "EncodedData")]
@@ -1038,7 +2216,43 @@ pub struct ContextDataType {
     pub server_path: String,
 }
 
+impl Validate for ContextDataType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.encoded_data {
+            errors.check_length(
+                &field_path(path, "encoded_data"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        for (i, item) in self.http_headers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "http_headers"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "ip_address"),
+            self.ip_address.chars().count(),
+            None,
+            Some(131072),
+        );
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            None,
+            Some(131072),
+        );
+        errors.check_length(
+            &field_path(path, "server_path"),
+            self.server_path.chars().count(),
+            None,
+            Some(131072),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGroupRequest {
     /// <p>A string containing the description of the group.</p>
     #[serde(rename = "Description")]
@@ -1060,8 +2274,52 @@ pub struct CreateGroupRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for CreateGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "group_name"),
+            self.group_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_name"),
+            &self.group_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "role_arn"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGroupResponse {
     /// <p>The group object for the group.</p>
     #[serde(rename = "Group")]
@@ -1070,6 +2328,7 @@ pub struct CreateGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateIdentityProviderRequest {
     /// <p>A mapping of identity provider attributes to standard and custom user pool attributes.</p>
     #[serde(rename = "AttributeMapping")]
@@ -1093,8 +2352,58 @@ pub struct CreateIdentityProviderRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for CreateIdentityProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_mapping {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "attribute_mapping"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+        if let Some(ref value) = self.idp_identifiers {
+            errors.check_length(
+                &field_path(path, "idp_identifiers"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "idp_identifiers"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(40));
+                errors.check_pattern(&item_path, &item, "[\\w\\s+=.@-]+");
+            }
+        }
+        for (key, item) in self.provider_details.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "provider_details"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+        }
+        errors.check_length(
+            &field_path(path, "provider_name"),
+            self.provider_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "provider_name"),
+            &self.provider_name,
+            "[^_\\p{Z}][\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}][^_\\p{Z}]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateIdentityProviderResponse {
     /// <p>The newly created identity provider object.</p>
     #[serde(rename = "IdentityProvider")]
@@ -1102,6 +2411,7 @@ pub struct CreateIdentityProviderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateResourceServerRequest {
     /// <p>A unique resource server identifier for the resource server. This could be an HTTPS endpoint where the resource server is located. For example, <code>https://my-weather-api.example.com</code>.</p>
     #[serde(rename = "Identifier")]
@@ -1118,8 +2428,49 @@ pub struct CreateResourceServerRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for CreateResourceServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identifier"),
+            self.identifier.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "identifier"),
+            &self.identifier,
+            "[\\x21\\x23-\\x5B\\x5D-\\x7E]+",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\w\\s+=,.@-]+");
+        if let Some(ref value) = self.scopes {
+            errors.check_length(&field_path(path, "scopes"), value.len(), None, Some(100));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "scopes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateResourceServerResponse {
     /// <p>The newly created resource server.</p>
     #[serde(rename = "ResourceServer")]
@@ -1128,6 +2479,7 @@ pub struct CreateResourceServerResponse {
 
 /// <p>Represents the request to create the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserImportJobRequest {
     /// <p>The role ARN for the Amazon CloudWatch Logging role for the user import job.</p>
     #[serde(rename = "CloudWatchLogsRoleArn")]
@@ -1140,9 +2492,43 @@ pub struct CreateUserImportJobRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for CreateUserImportJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cloud_watch_logs_role_arn"),
+            self.cloud_watch_logs_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "cloud_watch_logs_role_arn"), &self.cloud_watch_logs_role_arn, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        errors.check_length(
+            &field_path(path, "job_name"),
+            self.job_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_name"),
+            &self.job_name,
+            "[\\w\\s+=,.@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server to the request to create the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserImportJobResponse {
     /// <p>The job object that represents the user import job.</p>
     #[serde(rename = "UserImportJob")]
@@ -1152,6 +2538,7 @@ pub struct CreateUserImportJobResponse {
 
 /// <p>Represents the request to create a user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserPoolClientRequest {
     /// <p>Set to <code>code</code> to initiate a code grant flow, which provides an authorization code as the response. This code can be exchanged for access tokens with the token endpoint.</p> <p>Set to <code>token</code> to specify that the client should get the access token (and, optionally, ID token, based on scopes) directly.</p>
     #[serde(rename = "AllowedOAuthFlows")]
@@ -1213,9 +2600,130 @@ pub struct CreateUserPoolClientRequest {
     pub write_attributes: Option<Vec<String>>,
 }
 
+impl Validate for CreateUserPoolClientRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.allowed_o_auth_flows {
+            errors.check_length(
+                &field_path(path, "allowed_o_auth_flows"),
+                value.len(),
+                None,
+                Some(3),
+            );
+        }
+        if let Some(ref value) = self.allowed_o_auth_scopes {
+            errors.check_length(
+                &field_path(path, "allowed_o_auth_scopes"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "allowed_o_auth_scopes"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(&item_path, &item, "[\\x21\\x23-\\x5B\\x5D-\\x7E]+");
+            }
+        }
+        if let Some(ref value) = self.analytics_configuration {
+            value.validate_at(&field_path(path, "analytics_configuration"), errors);
+        }
+        if let Some(ref value) = self.callback_ur_ls {
+            errors.check_length(
+                &field_path(path, "callback_ur_ls"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "callback_ur_ls"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(&item_path, &item, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "client_name"),
+            self.client_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "client_name"),
+            &self.client_name,
+            "[\\w\\s+=,.@-]+",
+        );
+        if let Some(ref value) = self.default_redirect_uri {
+            errors.check_length(
+                &field_path(path, "default_redirect_uri"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "default_redirect_uri"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+            );
+        }
+        if let Some(ref value) = self.logout_ur_ls {
+            errors.check_length(
+                &field_path(path, "logout_ur_ls"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "logout_ur_ls"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(&item_path, &item, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+");
+            }
+        }
+        if let Some(ref value) = self.read_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "read_attributes"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+            }
+        }
+        if let Some(ref value) = self.refresh_token_validity {
+            errors.check_range(
+                &field_path(path, "refresh_token_validity"),
+                *value,
+                None,
+                Some(315360000),
+            );
+        }
+        if let Some(ref value) = self.supported_identity_providers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "supported_identity_providers"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+                errors.check_pattern(&item_path, &item, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\p{Z}]+");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        if let Some(ref value) = self.write_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "write_attributes"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+            }
+        }
+    }
+}
+
 /// <p>Represents the response from the server to create a user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserPoolClientResponse {
     /// <p>The user pool client that was just created.</p>
     #[serde(rename = "UserPoolClient")]
@@ -1224,6 +2732,7 @@ pub struct CreateUserPoolClientResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserPoolDomainRequest {
     /// <p>The configuration for a custom domain that hosts the sign-up and sign-in webpages for your application.</p> <p>Provide this parameter only if you want to use a custom domain for your user pool. Otherwise, you can exclude this parameter and use the Amazon Cognito hosted domain instead.</p> <p>For more information about the hosted domain and custom domains, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/cognito-user-pools-assign-domain.html">Configuring a User Pool Domain</a>.</p>
     #[serde(rename = "CustomDomainConfig")]
@@ -1237,8 +2746,38 @@ pub struct CreateUserPoolDomainRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for CreateUserPoolDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_domain_config {
+            value.validate_at(&field_path(path, "custom_domain_config"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain"),
+            &self.domain,
+            "^[a-z0-9](?:[a-z0-9\\-]{0,61}[a-z0-9])?$",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserPoolDomainResponse {
     /// <p>The Amazon CloudFront endpoint that you use as the target of the alias that you set up with your Domain Name Service (DNS) provider.</p>
     #[serde(rename = "CloudFrontDomain")]
@@ -1248,6 +2787,7 @@ pub struct CreateUserPoolDomainResponse {
 
 /// <p>Represents the request to create a user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserPoolRequest {
     /// <p>The configuration for <code>AdminCreateUser</code> requests.</p>
     #[serde(rename = "AdminCreateUserConfig")]
@@ -1326,9 +2866,104 @@ pub struct CreateUserPoolRequest {
     pub verification_message_template: Option<VerificationMessageTemplateType>,
 }
 
+impl Validate for CreateUserPoolRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.admin_create_user_config {
+            value.validate_at(&field_path(path, "admin_create_user_config"), errors);
+        }
+        if let Some(ref value) = self.email_configuration {
+            value.validate_at(&field_path(path, "email_configuration"), errors);
+        }
+        if let Some(ref value) = self.email_verification_message {
+            errors.check_length(
+                &field_path(path, "email_verification_message"),
+                value.chars().count(),
+                Some(6),
+                Some(20000),
+            );
+            errors.check_pattern(&field_path(path, "email_verification_message"), &value, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*\\{####\\}[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*");
+        }
+        if let Some(ref value) = self.email_verification_subject {
+            errors.check_length(
+                &field_path(path, "email_verification_subject"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+            errors.check_pattern(
+                &field_path(path, "email_verification_subject"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s]+",
+            );
+        }
+        if let Some(ref value) = self.lambda_config {
+            value.validate_at(&field_path(path, "lambda_config"), errors);
+        }
+        if let Some(ref value) = self.policies {
+            value.validate_at(&field_path(path, "policies"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "pool_name"),
+            self.pool_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "pool_name"),
+            &self.pool_name,
+            "[\\w\\s+=,.@-]+",
+        );
+        if let Some(ref value) = self.schema {
+            errors.check_length(&field_path(path, "schema"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "schema"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.sms_authentication_message {
+            errors.check_length(
+                &field_path(path, "sms_authentication_message"),
+                value.chars().count(),
+                Some(6),
+                Some(140),
+            );
+            errors.check_pattern(
+                &field_path(path, "sms_authentication_message"),
+                &value,
+                ".*\\{####\\}.*",
+            );
+        }
+        if let Some(ref value) = self.sms_configuration {
+            value.validate_at(&field_path(path, "sms_configuration"), errors);
+        }
+        if let Some(ref value) = self.sms_verification_message {
+            errors.check_length(
+                &field_path(path, "sms_verification_message"),
+                value.chars().count(),
+                Some(6),
+                Some(140),
+            );
+            errors.check_pattern(
+                &field_path(path, "sms_verification_message"),
+                &value,
+                ".*\\{####\\}.*",
+            );
+        }
+        if let Some(ref value) = self.user_pool_tags {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "user_pool_tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        if let Some(ref value) = self.verification_message_template {
+            value.validate_at(&field_path(path, "verification_message_template"), errors);
+        }
+    }
+}
+
 /// <p>Represents the response from the server for the request to create a user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserPoolResponse {
     /// <p>A container for the user pool details.</p>
     #[serde(rename = "UserPool")]
@@ -1344,7 +2979,20 @@ pub struct CustomDomainConfigType {
     pub certificate_arn: String,
 }
 
+impl Validate for CustomDomainConfigType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate_arn"),
+            self.certificate_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "certificate_arn"), &self.certificate_arn, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGroupRequest {
     /// <p>The name of the group.</p>
     #[serde(rename = "GroupName")]
@@ -1354,7 +3002,35 @@ pub struct DeleteGroupRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for DeleteGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_name"),
+            self.group_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_name"),
+            &self.group_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIdentityProviderRequest {
     /// <p>The identity provider name.</p>
     #[serde(rename = "ProviderName")]
@@ -1364,7 +3040,35 @@ pub struct DeleteIdentityProviderRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for DeleteIdentityProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "provider_name"),
+            self.provider_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "provider_name"),
+            &self.provider_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\p{Z}]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteResourceServerRequest {
     /// <p>The identifier for the resource server.</p>
     #[serde(rename = "Identifier")]
@@ -1374,8 +3078,36 @@ pub struct DeleteResourceServerRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for DeleteResourceServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identifier"),
+            self.identifier.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "identifier"),
+            &self.identifier,
+            "[\\x21\\x23-\\x5B\\x5D-\\x7E]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the request to delete user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserAttributesRequest {
     /// <p>The access token used in the request to delete user attributes.</p>
     #[serde(rename = "AccessToken")]
@@ -1385,13 +3117,29 @@ pub struct DeleteUserAttributesRequest {
     pub user_attribute_names: Vec<String>,
 }
 
+impl Validate for DeleteUserAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+        for (i, item) in self.user_attribute_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "user_attribute_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+            errors.check_pattern(&item_path, &item, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+");
+        }
+    }
+}
+
 /// <p>Represents the response from the server to delete user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUserAttributesResponse {}
 
 /// <p>Represents the request to delete a user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserPoolClientRequest {
     /// <p>The app client ID of the app associated with the user pool.</p>
     #[serde(rename = "ClientId")]
@@ -1401,7 +3149,31 @@ pub struct DeleteUserPoolClientRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for DeleteUserPoolClientRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserPoolDomainRequest {
     /// <p>The domain string.</p>
     #[serde(rename = "Domain")]
@@ -1411,27 +3183,83 @@ pub struct DeleteUserPoolDomainRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for DeleteUserPoolDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain"),
+            &self.domain,
+            "^[a-z0-9](?:[a-z0-9\\-]{0,61}[a-z0-9])?$",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUserPoolDomainResponse {}
 
 /// <p>Represents the request to delete a user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserPoolRequest {
     /// <p>The user pool ID for the user pool you want to delete.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
 }
 
+impl Validate for DeleteUserPoolRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the request to delete a user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserRequest {
     /// <p>The access token from a request to delete a user.</p>
     #[serde(rename = "AccessToken")]
     pub access_token: String,
 }
 
+impl Validate for DeleteUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeIdentityProviderRequest {
     /// <p>The identity provider name.</p>
     #[serde(rename = "ProviderName")]
@@ -1441,8 +3269,35 @@ pub struct DescribeIdentityProviderRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for DescribeIdentityProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "provider_name"),
+            self.provider_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "provider_name"),
+            &self.provider_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\p{Z}]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeIdentityProviderResponse {
     /// <p>The identity provider that was deleted.</p>
     #[serde(rename = "IdentityProvider")]
@@ -1450,6 +3305,7 @@ pub struct DescribeIdentityProviderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeResourceServerRequest {
     /// <p>The identifier for the resource server</p>
     #[serde(rename = "Identifier")]
@@ -1459,8 +3315,35 @@ pub struct DescribeResourceServerRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for DescribeResourceServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identifier"),
+            self.identifier.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "identifier"),
+            &self.identifier,
+            "[\\x21\\x23-\\x5B\\x5D-\\x7E]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeResourceServerResponse {
     /// <p>The resource server.</p>
     #[serde(rename = "ResourceServer")]
@@ -1468,6 +3351,7 @@ pub struct DescribeResourceServerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRiskConfigurationRequest {
     /// <p>The app client ID.</p>
     #[serde(rename = "ClientId")]
@@ -1478,8 +3362,33 @@ pub struct DescribeRiskConfigurationRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for DescribeRiskConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_id {
+            errors.check_length(
+                &field_path(path, "client_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "client_id"), &value, "[\\w+]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRiskConfigurationResponse {
     /// <p>The risk configuration.</p>
     #[serde(rename = "RiskConfiguration")]
@@ -1488,6 +3397,7 @@ pub struct DescribeRiskConfigurationResponse {
 
 /// <p>Represents the request to describe the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserImportJobRequest {
     /// <p>The job ID for the user import job.</p>
     #[serde(rename = "JobId")]
@@ -1497,9 +3407,36 @@ pub struct DescribeUserImportJobRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for DescribeUserImportJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "import-[0-9a-zA-Z-]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server to the request to describe the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserImportJobResponse {
     /// <p>The job object that represents the user import job.</p>
     #[serde(rename = "UserImportJob")]
@@ -1509,6 +3446,7 @@ pub struct DescribeUserImportJobResponse {
 
 /// <p>Represents the request to describe a user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserPoolClientRequest {
     /// <p>The app client ID of the app associated with the user pool.</p>
     #[serde(rename = "ClientId")]
@@ -1518,9 +3456,32 @@ pub struct DescribeUserPoolClientRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for DescribeUserPoolClientRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server from a request to describe the user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserPoolClientResponse {
     /// <p>The user pool client from a server response to describe the user pool client.</p>
     #[serde(rename = "UserPoolClient")]
@@ -1529,14 +3490,31 @@ pub struct DescribeUserPoolClientResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserPoolDomainRequest {
     /// <p>The domain string.</p>
     #[serde(rename = "Domain")]
     pub domain: String,
 }
 
+impl Validate for DescribeUserPoolDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain"),
+            &self.domain,
+            "^[a-z0-9](?:[a-z0-9\\-]{0,61}[a-z0-9])?$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserPoolDomainResponse {
     /// <p>A domain description object containing information about the domain.</p>
     #[serde(rename = "DomainDescription")]
@@ -1546,15 +3524,32 @@ pub struct DescribeUserPoolDomainResponse {
 
 /// <p>Represents the request to describe the user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserPoolRequest {
     /// <p>The user pool ID for the user pool you want to describe.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
 }
 
+impl Validate for DescribeUserPoolRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the response to describe the user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserPoolResponse {
     /// <p>The container of metadata returned by the server to describe the pool.</p>
     #[serde(rename = "UserPool")]
@@ -1577,6 +3572,7 @@ pub struct DeviceConfigurationType {
 
 /// <p>The device verifier against which it will be authenticated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeviceSecretVerifierConfigType {
     /// <p>The password verifier.</p>
     #[serde(rename = "PasswordVerifier")]
@@ -1588,9 +3584,30 @@ pub struct DeviceSecretVerifierConfigType {
     pub salt: Option<String>,
 }
 
+impl Validate for DeviceSecretVerifierConfigType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.password_verifier {
+            errors.check_length(
+                &field_path(path, "password_verifier"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        if let Some(ref value) = self.salt {
+            errors.check_length(
+                &field_path(path, "salt"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+    }
+}
+
 /// <p>The device type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceType {
     /// <p>The device attributes.</p>
     #[serde(rename = "DeviceAttributes")]
@@ -1616,7 +3633,7 @@ pub struct DeviceType {
 
 /// <p>A container for information about a domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainDescriptionType {
     /// <p>The AWS account ID for the user pool owner.</p>
     #[serde(rename = "AWSAccountId")]
@@ -1669,9 +3686,30 @@ pub struct EmailConfigurationType {
     pub source_arn: Option<String>,
 }
 
+impl Validate for EmailConfigurationType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.reply_to_email_address {
+            errors.check_pattern(
+                &field_path(path, "reply_to_email_address"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+@[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+            );
+        }
+        if let Some(ref value) = self.source_arn {
+            errors.check_length(
+                &field_path(path, "source_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "source_arn"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+    }
+}
+
 /// <p>Specifies the user context data captured at the time of an event request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventContextDataType {
     /// <p>The user's city.</p>
     #[serde(rename = "City")]
@@ -1697,7 +3735,7 @@ pub struct EventContextDataType {
 
 /// <p>Specifies the event feedback type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventFeedbackType {
     /// <p>The event feedback date.</p>
     #[serde(rename = "FeedbackDate")]
@@ -1713,7 +3751,7 @@ pub struct EventFeedbackType {
 
 /// <p>The event risk type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventRiskType {
     /// <p>The risk decision.</p>
     #[serde(rename = "RiskDecision")]
@@ -1727,6 +3765,7 @@ pub struct EventRiskType {
 
 /// <p>Represents the request to forget the device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ForgetDeviceRequest {
     /// <p>The access token for the forgotten device request.</p>
     #[serde(rename = "AccessToken")]
@@ -1737,8 +3776,32 @@ pub struct ForgetDeviceRequest {
     pub device_key: String,
 }
 
+impl Validate for ForgetDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.access_token {
+            errors.check_pattern(
+                &field_path(path, "access_token"),
+                &value,
+                "[A-Za-z0-9-_=.]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "device_key"),
+            self.device_key.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_key"),
+            &self.device_key,
+            "[\\w-]+_[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Represents the request to reset a user's password.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ForgotPasswordRequest {
     /// <p>The Amazon Pinpoint analytics metadata for collecting metrics for <code>ForgotPassword</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -1760,9 +3823,47 @@ pub struct ForgotPasswordRequest {
     pub username: String,
 }
 
+impl Validate for ForgotPasswordRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analytics_metadata {
+            value.validate_at(&field_path(path, "analytics_metadata"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        if let Some(ref value) = self.secret_hash {
+            errors.check_length(
+                &field_path(path, "secret_hash"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "secret_hash"), &value, "[\\w+=/]+");
+        }
+        if let Some(ref value) = self.user_context_data {
+            value.validate_at(&field_path(path, "user_context_data"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>Respresents the response from the server regarding the request to reset a password.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ForgotPasswordResponse {
     /// <p>The code delivery details returned by the server in response to the request to reset a password.</p>
     #[serde(rename = "CodeDeliveryDetails")]
@@ -1772,15 +3873,32 @@ pub struct ForgotPasswordResponse {
 
 /// <p>Represents the request to get the header information for the .csv file for the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCSVHeaderRequest {
     /// <p>The user pool ID for the user pool that the users are to be imported into.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
 }
 
+impl Validate for GetCSVHeaderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server to the request to get the header information for the .csv file for the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCSVHeaderResponse {
     /// <p>The header information for the .csv file for the user import job.</p>
     #[serde(rename = "CSVHeader")]
@@ -1794,6 +3912,7 @@ pub struct GetCSVHeaderResponse {
 
 /// <p>Represents the request to get the device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeviceRequest {
     /// <p>The access token.</p>
     #[serde(rename = "AccessToken")]
@@ -1804,16 +3923,40 @@ pub struct GetDeviceRequest {
     pub device_key: String,
 }
 
-/// <p>Gets the device response.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetDeviceResponse {
+impl Validate for GetDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.access_token {
+            errors.check_pattern(
+                &field_path(path, "access_token"),
+                &value,
+                "[A-Za-z0-9-_=.]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "device_key"),
+            self.device_key.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_key"),
+            &self.device_key,
+            "[\\w-]+_[0-9a-f-]+",
+        );
+    }
+}
+
+/// <p>Gets the device response.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GetDeviceResponse {
     /// <p>The device.</p>
     #[serde(rename = "Device")]
     pub device: DeviceType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGroupRequest {
     /// <p>The name of the group.</p>
     #[serde(rename = "GroupName")]
@@ -1823,8 +3966,35 @@ pub struct GetGroupRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for GetGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_name"),
+            self.group_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_name"),
+            &self.group_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGroupResponse {
     /// <p>The group object for the group.</p>
     #[serde(rename = "Group")]
@@ -1833,6 +4003,7 @@ pub struct GetGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityProviderByIdentifierRequest {
     /// <p>The identity provider ID.</p>
     #[serde(rename = "IdpIdentifier")]
@@ -1842,8 +4013,35 @@ pub struct GetIdentityProviderByIdentifierRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for GetIdentityProviderByIdentifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "idp_identifier"),
+            self.idp_identifier.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "idp_identifier"),
+            &self.idp_identifier,
+            "[\\w\\s+=.@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIdentityProviderByIdentifierResponse {
     /// <p>The identity provider object.</p>
     #[serde(rename = "IdentityProvider")]
@@ -1852,15 +4050,32 @@ pub struct GetIdentityProviderByIdentifierResponse {
 
 /// <p>Request to get a signing certificate from Cognito.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSigningCertificateRequest {
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
 }
 
+impl Validate for GetSigningCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Response from Cognito for a signing certificate request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSigningCertificateResponse {
     /// <p>The signing certificate.</p>
     #[serde(rename = "Certificate")]
@@ -1869,6 +4084,7 @@ pub struct GetSigningCertificateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUICustomizationRequest {
     /// <p>The client ID for the client app.</p>
     #[serde(rename = "ClientId")]
@@ -1879,8 +4095,33 @@ pub struct GetUICustomizationRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for GetUICustomizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_id {
+            errors.check_length(
+                &field_path(path, "client_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "client_id"), &value, "[\\w+]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUICustomizationResponse {
     /// <p>The UI customization information.</p>
     #[serde(rename = "UICustomization")]
@@ -1889,6 +4130,7 @@ pub struct GetUICustomizationResponse {
 
 /// <p>Represents the request to get user attribute verification.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUserAttributeVerificationCodeRequest {
     /// <p>The access token returned by the server response to get the user attribute verification code.</p>
     #[serde(rename = "AccessToken")]
@@ -1898,9 +4140,30 @@ pub struct GetUserAttributeVerificationCodeRequest {
     pub attribute_name: String,
 }
 
+impl Validate for GetUserAttributeVerificationCodeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+        errors.check_length(
+            &field_path(path, "attribute_name"),
+            self.attribute_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "attribute_name"),
+            &self.attribute_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>The verification code response returned by the server response to get the user attribute verification code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUserAttributeVerificationCodeResponse {
     /// <p>The code delivery details returned by the server in response to the request to get the user attribute verification code.</p>
     #[serde(rename = "CodeDeliveryDetails")]
@@ -1909,14 +4172,31 @@ pub struct GetUserAttributeVerificationCodeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUserPoolMfaConfigRequest {
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
 }
 
+impl Validate for GetUserPoolMfaConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUserPoolMfaConfigResponse {
     /// <p>The multi-factor (MFA) configuration.</p>
     #[serde(rename = "MfaConfiguration")]
@@ -1934,15 +4214,26 @@ pub struct GetUserPoolMfaConfigResponse {
 
 /// <p>Represents the request to get information about the user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUserRequest {
     /// <p>The access token returned by the server response to get information about the user.</p>
     #[serde(rename = "AccessToken")]
     pub access_token: String,
 }
 
+impl Validate for GetUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server from the request to get information about the user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUserResponse {
     /// <p>Specifies the options for MFA (e.g., email or phone number).</p>
     #[serde(rename = "MFAOptions")]
@@ -1966,20 +4257,31 @@ pub struct GetUserResponse {
 
 /// <p>Represents the request to sign out all devices.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GlobalSignOutRequest {
     /// <p>The access token.</p>
     #[serde(rename = "AccessToken")]
     pub access_token: String,
 }
 
+impl Validate for GlobalSignOutRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+    }
+}
+
 /// <p>The response to the request to sign out all devices.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GlobalSignOutResponse {}
 
 /// <p>The group type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GroupType {
     /// <p>The date the group was created.</p>
     #[serde(rename = "CreationDate")]
@@ -2013,6 +4315,7 @@ pub struct GroupType {
 
 /// <p>The HTTP header.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HttpHeader {
     /// <p>The header name</p>
     #[serde(rename = "headerName")]
@@ -2024,9 +4327,30 @@ pub struct HttpHeader {
     pub header_value: Option<String>,
 }
 
+impl Validate for HttpHeader {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.header_name {
+            errors.check_length(
+                &field_path(path, "header_name"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        if let Some(ref value) = self.header_value {
+            errors.check_length(
+                &field_path(path, "header_value"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+    }
+}
+
 /// <p>A container for information about an identity provider.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IdentityProviderType {
     /// <p>A mapping of identity provider attributes to standard and custom user pool attributes.</p>
     #[serde(rename = "AttributeMapping")]
@@ -2064,6 +4388,7 @@ pub struct IdentityProviderType {
 
 /// <p>Initiates the authentication request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InitiateAuthRequest {
     /// <p>The Amazon Pinpoint analytics metadata for collecting metrics for <code>InitiateAuth</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -2089,9 +4414,39 @@ pub struct InitiateAuthRequest {
     pub user_context_data: Option<UserContextDataType>,
 }
 
+impl Validate for InitiateAuthRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analytics_metadata {
+            value.validate_at(&field_path(path, "analytics_metadata"), errors);
+        }
+        if let Some(ref value) = self.auth_parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "auth_parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        if let Some(ref value) = self.client_metadata {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "client_metadata"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+        if let Some(ref value) = self.user_context_data {
+            value.validate_at(&field_path(path, "user_context_data"), errors);
+        }
+    }
+}
+
 /// <p>Initiates the authentication response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InitiateAuthResponse {
     /// <p>The result of the authentication response. This is only returned if the caller does not need to pass another challenge. If the caller does need to pass another challenge before it gets tokens, <code>ChallengeName</code>, <code>ChallengeParameters</code>, and <code>Session</code> are returned.</p>
     #[serde(rename = "AuthenticationResult")]
@@ -2156,8 +4511,104 @@ pub struct LambdaConfigType {
     pub verify_auth_challenge_response: Option<String>,
 }
 
+impl Validate for LambdaConfigType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.create_auth_challenge {
+            errors.check_length(
+                &field_path(path, "create_auth_challenge"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "create_auth_challenge"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        if let Some(ref value) = self.custom_message {
+            errors.check_length(
+                &field_path(path, "custom_message"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "custom_message"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        if let Some(ref value) = self.define_auth_challenge {
+            errors.check_length(
+                &field_path(path, "define_auth_challenge"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "define_auth_challenge"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        if let Some(ref value) = self.post_authentication {
+            errors.check_length(
+                &field_path(path, "post_authentication"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "post_authentication"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        if let Some(ref value) = self.post_confirmation {
+            errors.check_length(
+                &field_path(path, "post_confirmation"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "post_confirmation"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        if let Some(ref value) = self.pre_authentication {
+            errors.check_length(
+                &field_path(path, "pre_authentication"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "pre_authentication"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        if let Some(ref value) = self.pre_sign_up {
+            errors.check_length(
+                &field_path(path, "pre_sign_up"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "pre_sign_up"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        if let Some(ref value) = self.pre_token_generation {
+            errors.check_length(
+                &field_path(path, "pre_token_generation"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "pre_token_generation"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        if let Some(ref value) = self.user_migration {
+            errors.check_length(
+                &field_path(path, "user_migration"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "user_migration"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        if let Some(ref value) = self.verify_auth_challenge_response {
+            errors.check_length(
+                &field_path(path, "verify_auth_challenge_response"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "verify_auth_challenge_response"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+    }
+}
+
 /// <p>Represents the request to list the devices.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDevicesRequest {
     /// <p>The access tokens for the request to list devices.</p>
     #[serde(rename = "AccessToken")]
@@ -2172,9 +4623,31 @@ pub struct ListDevicesRequest {
     pub pagination_token: Option<String>,
 }
 
+impl Validate for ListDevicesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(60));
+        }
+        if let Some(ref value) = self.pagination_token {
+            errors.check_length(
+                &field_path(path, "pagination_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "pagination_token"), &value, "[\\S]+");
+        }
+    }
+}
+
 /// <p>Represents the response to list devices.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDevicesResponse {
     /// <p>The devices returned in the list devices response.</p>
     #[serde(rename = "Devices")]
@@ -2187,6 +4660,7 @@ pub struct ListDevicesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGroupsRequest {
     /// <p>The limit of the request to list groups.</p>
     #[serde(rename = "Limit")]
@@ -2201,8 +4675,36 @@ pub struct ListGroupsRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for ListGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(60));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(131072),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGroupsResponse {
     /// <p>The group objects for the groups.</p>
     #[serde(rename = "Groups")]
@@ -2215,6 +4717,7 @@ pub struct ListGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIdentityProvidersRequest {
     /// <p>The maximum number of identity providers to return.</p>
     #[serde(rename = "MaxResults")]
@@ -2229,8 +4732,36 @@ pub struct ListIdentityProvidersRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for ListIdentityProvidersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(60));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListIdentityProvidersResponse {
     /// <p>A pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -2242,6 +4773,7 @@ pub struct ListIdentityProvidersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourceServersRequest {
     /// <p>The maximum number of resource servers to return.</p>
     #[serde(rename = "MaxResults")]
@@ -2256,8 +4788,36 @@ pub struct ListResourceServersRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for ListResourceServersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourceServersResponse {
     /// <p>A pagination token.</p>
     #[serde(rename = "NextToken")]
@@ -2269,14 +4829,27 @@ pub struct ListResourceServersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the user pool that the tags are assigned to.</p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags that are assigned to the user pool.</p>
     #[serde(rename = "Tags")]
@@ -2286,6 +4859,7 @@ pub struct ListTagsForResourceResponse {
 
 /// <p>Represents the request to list the user import jobs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUserImportJobsRequest {
     /// <p>The maximum number of import jobs you want the request to return.</p>
     #[serde(rename = "MaxResults")]
@@ -2299,9 +4873,40 @@ pub struct ListUserImportJobsRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for ListUserImportJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "max_results"),
+            self.max_results,
+            Some(1),
+            Some(60),
+        );
+        if let Some(ref value) = self.pagination_token {
+            errors.check_length(
+                &field_path(path, "pagination_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "pagination_token"), &value, "[\\S]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server to the request to list the user import jobs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUserImportJobsResponse {
     /// <p>An identifier that can be used to return the next set of user import jobs in the list.</p>
     #[serde(rename = "PaginationToken")]
@@ -2315,6 +4920,7 @@ pub struct ListUserImportJobsResponse {
 
 /// <p>Represents the request to list the user pool clients.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUserPoolClientsRequest {
     /// <p>The maximum number of results you want the request to return when listing the user pool clients.</p>
     #[serde(rename = "MaxResults")]
@@ -2329,9 +4935,37 @@ pub struct ListUserPoolClientsRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for ListUserPoolClientsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(60));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(131072),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server that lists user pool clients.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUserPoolClientsResponse {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "NextToken")]
@@ -2345,6 +4979,7 @@ pub struct ListUserPoolClientsResponse {
 
 /// <p>Represents the request to list user pools.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUserPoolsRequest {
     /// <p>The maximum number of results you want the request to return when listing the user pools.</p>
     #[serde(rename = "MaxResults")]
@@ -2355,9 +4990,29 @@ pub struct ListUserPoolsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListUserPoolsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "max_results"),
+            self.max_results,
+            Some(1),
+            Some(60),
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+    }
+}
+
 /// <p>Represents the response to list user pools.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUserPoolsResponse {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "NextToken")]
@@ -2370,6 +5025,7 @@ pub struct ListUserPoolsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUsersInGroupRequest {
     /// <p>The name of the group.</p>
     #[serde(rename = "GroupName")]
@@ -2387,8 +5043,47 @@ pub struct ListUsersInGroupRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for ListUsersInGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_name"),
+            self.group_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_name"),
+            &self.group_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(60));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(131072),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\S]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUsersInGroupResponse {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "NextToken")]
@@ -2402,6 +5097,7 @@ pub struct ListUsersInGroupResponse {
 
 /// <p>Represents the request to list users.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUsersRequest {
     /// <p>An array of strings, where each string is the name of a user attribute to be returned for each user in the search results. If the array is null, all attributes are returned.</p>
     #[serde(rename = "AttributesToGet")]
@@ -2424,9 +5120,52 @@ pub struct ListUsersRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for ListUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes_to_get {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes_to_get"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+                errors.check_pattern(&item_path, &item, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+");
+            }
+        }
+        if let Some(ref value) = self.filter {
+            errors.check_length(
+                &field_path(path, "filter"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(60));
+        }
+        if let Some(ref value) = self.pagination_token {
+            errors.check_length(
+                &field_path(path, "pagination_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "pagination_token"), &value, "[\\S]+");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>The response from the request to list users.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUsersResponse {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "PaginationToken")]
@@ -2451,6 +5190,24 @@ pub struct MFAOptionType {
     pub delivery_medium: Option<String>,
 }
 
+impl Validate for MFAOptionType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_name {
+            errors.check_length(
+                &field_path(path, "attribute_name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+            errors.check_pattern(
+                &field_path(path, "attribute_name"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+            );
+        }
+    }
+}
+
 /// <p>The message template structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MessageTemplateType {
@@ -2468,9 +5225,45 @@ pub struct MessageTemplateType {
     pub sms_message: Option<String>,
 }
 
+impl Validate for MessageTemplateType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.email_message {
+            errors.check_length(
+                &field_path(path, "email_message"),
+                value.chars().count(),
+                Some(6),
+                Some(20000),
+            );
+            errors.check_pattern(&field_path(path, "email_message"), &value, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*\\{####\\}[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*");
+        }
+        if let Some(ref value) = self.email_subject {
+            errors.check_length(
+                &field_path(path, "email_subject"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+            errors.check_pattern(
+                &field_path(path, "email_subject"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s]+",
+            );
+        }
+        if let Some(ref value) = self.sms_message {
+            errors.check_length(
+                &field_path(path, "sms_message"),
+                value.chars().count(),
+                Some(6),
+                Some(140),
+            );
+            errors.check_pattern(&field_path(path, "sms_message"), &value, ".*\\{####\\}.*");
+        }
+    }
+}
+
 /// <p>The new device metadata type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NewDeviceMetadataType {
     /// <p>The device group key.</p>
     #[serde(rename = "DeviceGroupKey")]
@@ -2510,6 +5303,43 @@ pub struct NotifyConfigurationType {
     pub source_arn: String,
 }
 
+impl Validate for NotifyConfigurationType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.block_email {
+            value.validate_at(&field_path(path, "block_email"), errors);
+        }
+        if let Some(ref value) = self.from {
+            errors.check_length(
+                &field_path(path, "from"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        if let Some(ref value) = self.mfa_email {
+            value.validate_at(&field_path(path, "mfa_email"), errors);
+        }
+        if let Some(ref value) = self.no_action_email {
+            value.validate_at(&field_path(path, "no_action_email"), errors);
+        }
+        if let Some(ref value) = self.reply_to {
+            errors.check_length(
+                &field_path(path, "reply_to"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "source_arn"),
+            self.source_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "source_arn"), &self.source_arn, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+    }
+}
+
 /// <p>The notify email type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NotifyEmailType {
@@ -2526,6 +5356,48 @@ pub struct NotifyEmailType {
     pub text_body: Option<String>,
 }
 
+impl Validate for NotifyEmailType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.html_body {
+            errors.check_length(
+                &field_path(path, "html_body"),
+                value.chars().count(),
+                Some(6),
+                Some(20000),
+            );
+            errors.check_pattern(
+                &field_path(path, "html_body"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "subject"),
+            self.subject.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(
+            &field_path(path, "subject"),
+            &self.subject,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s]+",
+        );
+        if let Some(ref value) = self.text_body {
+            errors.check_length(
+                &field_path(path, "text_body"),
+                value.chars().count(),
+                Some(6),
+                Some(20000),
+            );
+            errors.check_pattern(
+                &field_path(path, "text_body"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]+",
+            );
+        }
+    }
+}
+
 /// <p>The minimum and maximum value of an attribute that is of the number data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NumberAttributeConstraintsType {
@@ -2539,6 +5411,27 @@ pub struct NumberAttributeConstraintsType {
     pub min_value: Option<String>,
 }
 
+impl Validate for NumberAttributeConstraintsType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_value {
+            errors.check_length(
+                &field_path(path, "max_value"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        if let Some(ref value) = self.min_value {
+            errors.check_length(
+                &field_path(path, "min_value"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+    }
+}
+
 /// <p>The password policy type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PasswordPolicyType {
@@ -2567,9 +5460,30 @@ pub struct PasswordPolicyType {
     pub temporary_password_validity_days: Option<i64>,
 }
 
+impl Validate for PasswordPolicyType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.minimum_length {
+            errors.check_range(
+                &field_path(path, "minimum_length"),
+                *value,
+                Some(6),
+                Some(99),
+            );
+        }
+        if let Some(ref value) = self.temporary_password_validity_days {
+            errors.check_range(
+                &field_path(path, "temporary_password_validity_days"),
+                *value,
+                None,
+                Some(365),
+            );
+        }
+    }
+}
+
 /// <p>A container for identity provider details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProviderDescription {
     /// <p>The date the provider was added to the user pool.</p>
     #[serde(rename = "CreationDate")]
@@ -2591,6 +5505,7 @@ pub struct ProviderDescription {
 
 /// <p>A container for information about an identity provider for a user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ProviderUserIdentifierType {
     /// <p>The name of the provider attribute to link to, for example, <code>NameID</code>.</p>
     #[serde(rename = "ProviderAttributeName")]
@@ -2606,8 +5521,43 @@ pub struct ProviderUserIdentifierType {
     pub provider_name: Option<String>,
 }
 
+impl Validate for ProviderUserIdentifierType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.provider_attribute_name {
+            errors.check_length(
+                &field_path(path, "provider_attribute_name"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        if let Some(ref value) = self.provider_attribute_value {
+            errors.check_length(
+                &field_path(path, "provider_attribute_value"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        if let Some(ref value) = self.provider_name {
+            errors.check_length(
+                &field_path(path, "provider_name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+            errors.check_pattern(
+                &field_path(path, "provider_name"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\p{Z}]+",
+            );
+        }
+    }
+}
+
 /// <p>Represents the request to resend the confirmation code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResendConfirmationCodeRequest {
     /// <p>The Amazon Pinpoint analytics metadata for collecting metrics for <code>ResendConfirmationCode</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -2629,9 +5579,47 @@ pub struct ResendConfirmationCodeRequest {
     pub username: String,
 }
 
+impl Validate for ResendConfirmationCodeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analytics_metadata {
+            value.validate_at(&field_path(path, "analytics_metadata"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        if let Some(ref value) = self.secret_hash {
+            errors.check_length(
+                &field_path(path, "secret_hash"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "secret_hash"), &value, "[\\w+=/]+");
+        }
+        if let Some(ref value) = self.user_context_data {
+            value.validate_at(&field_path(path, "user_context_data"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 /// <p>The response from the server when the Amazon Cognito Your User Pools service makes the request to resend a confirmation code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResendConfirmationCodeResponse {
     /// <p>The code delivery details returned by the server in response to the request to resend the confirmation code.</p>
     #[serde(rename = "CodeDeliveryDetails")]
@@ -2650,9 +5638,31 @@ pub struct ResourceServerScopeType {
     pub scope_name: String,
 }
 
+impl Validate for ResourceServerScopeType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "scope_description"),
+            self.scope_description.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "scope_name"),
+            self.scope_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "scope_name"),
+            &self.scope_name,
+            "[\\x21\\x23-\\x2E\\x30-\\x5B\\x5D-\\x7E]+",
+        );
+    }
+}
+
 /// <p>A container for information about a resource server for a user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceServerType {
     /// <p>The identifier for the resource server.</p>
     #[serde(rename = "Identifier")]
@@ -2674,6 +5684,7 @@ pub struct ResourceServerType {
 
 /// <p>The request to respond to an authentication challenge.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RespondToAuthChallengeRequest {
     /// <p>The Amazon Pinpoint analytics metadata for collecting metrics for <code>RespondToAuthChallenge</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -2699,9 +5710,41 @@ pub struct RespondToAuthChallengeRequest {
     pub user_context_data: Option<UserContextDataType>,
 }
 
+impl Validate for RespondToAuthChallengeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analytics_metadata {
+            value.validate_at(&field_path(path, "analytics_metadata"), errors);
+        }
+        if let Some(ref value) = self.challenge_responses {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "challenge_responses"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        if let Some(ref value) = self.session {
+            errors.check_length(
+                &field_path(path, "session"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.user_context_data {
+            value.validate_at(&field_path(path, "user_context_data"), errors);
+        }
+    }
+}
+
 /// <p>The response to respond to the authentication challenge.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RespondToAuthChallengeResponse {
     /// <p>The result returned by the server in response to the request to respond to the authentication challenge.</p>
     #[serde(rename = "AuthenticationResult")]
@@ -2723,7 +5766,7 @@ pub struct RespondToAuthChallengeResponse {
 
 /// <p>The risk configuration type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RiskConfigurationType {
     /// <p>The account takeover risk configuration object including the <code>NotifyConfiguration</code> object and <code>Actions</code> to take in the case of an account takeover.</p>
     #[serde(rename = "AccountTakeoverRiskConfiguration")]
@@ -2765,8 +5808,38 @@ pub struct RiskExceptionConfigurationType {
     pub skipped_ip_range_list: Option<Vec<String>>,
 }
 
+impl Validate for RiskExceptionConfigurationType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.blocked_ip_range_list {
+            errors.check_length(
+                &field_path(path, "blocked_ip_range_list"),
+                value.len(),
+                None,
+                Some(200),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "blocked_ip_range_list"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+        if let Some(ref value) = self.skipped_ip_range_list {
+            errors.check_length(
+                &field_path(path, "skipped_ip_range_list"),
+                value.len(),
+                None,
+                Some(200),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "skipped_ip_range_list"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+    }
+}
+
 /// <p>The SMS multi-factor authentication (MFA) settings type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SMSMfaSettingsType {
     /// <p>Specifies whether SMS text message MFA is enabled.</p>
     #[serde(rename = "Enabled")]
@@ -2811,7 +5884,32 @@ pub struct SchemaAttributeType {
     pub string_attribute_constraints: Option<StringAttributeConstraintsType>,
 }
 
+impl Validate for SchemaAttributeType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(20),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+            );
+        }
+        if let Some(ref value) = self.number_attribute_constraints {
+            value.validate_at(&field_path(path, "number_attribute_constraints"), errors);
+        }
+        if let Some(ref value) = self.string_attribute_constraints {
+            value.validate_at(&field_path(path, "string_attribute_constraints"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetRiskConfigurationRequest {
     /// <p>The account takeover risk configuration.</p>
     #[serde(rename = "AccountTakeoverRiskConfiguration")]
@@ -2835,8 +5933,42 @@ pub struct SetRiskConfigurationRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for SetRiskConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_takeover_risk_configuration {
+            value.validate_at(
+                &field_path(path, "account_takeover_risk_configuration"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.client_id {
+            errors.check_length(
+                &field_path(path, "client_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "client_id"), &value, "[\\w+]+");
+        }
+        if let Some(ref value) = self.risk_exception_configuration {
+            value.validate_at(&field_path(path, "risk_exception_configuration"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SetRiskConfigurationResponse {
     /// <p>The risk configuration.</p>
     #[serde(rename = "RiskConfiguration")]
@@ -2844,6 +5976,7 @@ pub struct SetRiskConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetUICustomizationRequest {
     /// <p>The CSS values in the UI customization.</p>
     #[serde(rename = "CSS")]
@@ -2867,8 +6000,49 @@ pub struct SetUICustomizationRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for SetUICustomizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.css {
+            errors.check_length(
+                &field_path(path, "css"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        if let Some(ref value) = self.client_id {
+            errors.check_length(
+                &field_path(path, "client_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "client_id"), &value, "[\\w+]+");
+        }
+        if let Some(ref value) = self.image_file {
+            errors.check_length(
+                &field_path(path, "image_file"),
+                value.len(),
+                None,
+                Some(131072),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SetUICustomizationResponse {
     /// <p>The UI customization information.</p>
     #[serde(rename = "UICustomization")]
@@ -2876,6 +6050,7 @@ pub struct SetUICustomizationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetUserMFAPreferenceRequest {
     /// <p>The access token.</p>
     #[serde(rename = "AccessToken")]
@@ -2890,11 +6065,22 @@ pub struct SetUserMFAPreferenceRequest {
     pub software_token_mfa_settings: Option<SoftwareTokenMfaSettingsType>,
 }
 
+impl Validate for SetUserMFAPreferenceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SetUserMFAPreferenceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetUserPoolMfaConfigRequest {
     /// <p>The MFA configuration.</p>
     #[serde(rename = "MfaConfiguration")]
@@ -2913,8 +6099,27 @@ pub struct SetUserPoolMfaConfigRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for SetUserPoolMfaConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.sms_mfa_configuration {
+            value.validate_at(&field_path(path, "sms_mfa_configuration"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SetUserPoolMfaConfigResponse {
     /// <p>The MFA configuration.</p>
     #[serde(rename = "MfaConfiguration")]
@@ -2932,6 +6137,7 @@ pub struct SetUserPoolMfaConfigResponse {
 
 /// <p>Represents the request to set user settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetUserSettingsRequest {
     /// <p>The access token for the set user settings request.</p>
     #[serde(rename = "AccessToken")]
@@ -2941,13 +6147,28 @@ pub struct SetUserSettingsRequest {
     pub mfa_options: Vec<MFAOptionType>,
 }
 
-/// <p>The response from the server for a set user settings request.</p>
+impl Validate for SetUserSettingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+        for (i, item) in self.mfa_options.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "mfa_options"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
+/// <p>The response from the server for a set user settings request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SetUserSettingsResponse {}
 
 /// <p>Represents the request to register a user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SignUpRequest {
     /// <p>The Amazon Pinpoint analytics metadata for collecting metrics for <code>SignUp</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -2980,9 +6201,66 @@ pub struct SignUpRequest {
     pub validation_data: Option<Vec<AttributeType>>,
 }
 
+impl Validate for SignUpRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.analytics_metadata {
+            value.validate_at(&field_path(path, "analytics_metadata"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "password"), &self.password, "[\\S]+");
+        if let Some(ref value) = self.secret_hash {
+            errors.check_length(
+                &field_path(path, "secret_hash"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "secret_hash"), &value, "[\\w+=/]+");
+        }
+        if let Some(ref value) = self.user_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "user_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.user_context_data {
+            value.validate_at(&field_path(path, "user_context_data"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        if let Some(ref value) = self.validation_data {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "validation_data"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>The response from the server for a registration request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SignUpResponse {
     /// <p>The code delivery details returned by the server response to the user registration request.</p>
     #[serde(rename = "CodeDeliveryDetails")]
@@ -3008,6 +6286,26 @@ pub struct SmsConfigurationType {
     pub sns_caller_arn: String,
 }
 
+impl Validate for SmsConfigurationType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.external_id {
+            errors.check_length(
+                &field_path(path, "external_id"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "sns_caller_arn"),
+            self.sns_caller_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "sns_caller_arn"), &self.sns_caller_arn, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+    }
+}
+
 /// <p>The SMS text message multi-factor authentication (MFA) configuration type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SmsMfaConfigType {
@@ -3021,6 +6319,27 @@ pub struct SmsMfaConfigType {
     pub sms_configuration: Option<SmsConfigurationType>,
 }
 
+impl Validate for SmsMfaConfigType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.sms_authentication_message {
+            errors.check_length(
+                &field_path(path, "sms_authentication_message"),
+                value.chars().count(),
+                Some(6),
+                Some(140),
+            );
+            errors.check_pattern(
+                &field_path(path, "sms_authentication_message"),
+                &value,
+                ".*\\{####\\}.*",
+            );
+        }
+        if let Some(ref value) = self.sms_configuration {
+            value.validate_at(&field_path(path, "sms_configuration"), errors);
+        }
+    }
+}
+
 /// <p>The type used for enabling software token MFA at the user pool level.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SoftwareTokenMfaConfigType {
@@ -3032,6 +6351,7 @@ pub struct SoftwareTokenMfaConfigType {
 
 /// <p>The type used for enabling software token MFA at the user level.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SoftwareTokenMfaSettingsType {
     /// <p>Specifies whether software token MFA is enabled.</p>
     #[serde(rename = "Enabled")]
@@ -3045,6 +6365,7 @@ pub struct SoftwareTokenMfaSettingsType {
 
 /// <p>Represents the request to start the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartUserImportJobRequest {
     /// <p>The job ID for the user import job.</p>
     #[serde(rename = "JobId")]
@@ -3054,9 +6375,36 @@ pub struct StartUserImportJobRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for StartUserImportJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "import-[0-9a-zA-Z-]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server to the request to start the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartUserImportJobResponse {
     /// <p>The job object that represents the user import job.</p>
     #[serde(rename = "UserImportJob")]
@@ -3066,6 +6414,7 @@ pub struct StartUserImportJobResponse {
 
 /// <p>Represents the request to stop the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopUserImportJobRequest {
     /// <p>The job ID for the user import job.</p>
     #[serde(rename = "JobId")]
@@ -3075,9 +6424,36 @@ pub struct StopUserImportJobRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for StopUserImportJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "import-[0-9a-zA-Z-]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server to the request to stop the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopUserImportJobResponse {
     /// <p>The job object that represents the user import job.</p>
     #[serde(rename = "UserImportJob")]
@@ -3098,7 +6474,29 @@ pub struct StringAttributeConstraintsType {
     pub min_length: Option<String>,
 }
 
+impl Validate for StringAttributeConstraintsType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_length {
+            errors.check_length(
+                &field_path(path, "max_length"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        if let Some(ref value) = self.min_length {
+            errors.check_length(
+                &field_path(path, "min_length"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the user pool to assign the tags to.</p>
     #[serde(rename = "ResourceArn")]
@@ -3109,13 +6507,31 @@ pub struct TagResourceRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        if let Some(ref value) = self.tags {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>A container for the UI customization information for a user pool's built-in app UI.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UICustomizationType {
     /// <p>The CSS values in the UI customization.</p>
     #[serde(rename = "CSS")]
@@ -3148,6 +6564,7 @@ pub struct UICustomizationType {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the user pool that the tags are assigned to.</p>
     #[serde(rename = "ResourceArn")]
@@ -3158,11 +6575,30 @@ pub struct UntagResourceRequest {
     pub tag_keys: Option<Vec<String>>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAuthEventFeedbackRequest {
     /// <p>The event ID.</p>
     #[serde(rename = "EventId")]
@@ -3181,12 +6617,52 @@ pub struct UpdateAuthEventFeedbackRequest {
     pub username: String,
 }
 
+impl Validate for UpdateAuthEventFeedbackRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "event_id"),
+            self.event_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "event_id"), &self.event_id, "[\\w+-]+");
+        errors.check_pattern(
+            &field_path(path, "feedback_token"),
+            &self.feedback_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAuthEventFeedbackResponse {}
 
 /// <p>Represents the request to update the device status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDeviceStatusRequest {
     /// <p>The access token.</p>
     #[serde(rename = "AccessToken")]
@@ -3200,12 +6676,34 @@ pub struct UpdateDeviceStatusRequest {
     pub device_remembered_status: Option<String>,
 }
 
+impl Validate for UpdateDeviceStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+        errors.check_length(
+            &field_path(path, "device_key"),
+            self.device_key.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_key"),
+            &self.device_key,
+            "[\\w-]+_[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>The response to the request to update the device status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDeviceStatusResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGroupRequest {
     /// <p>A string containing the new description of the group.</p>
     #[serde(rename = "Description")]
@@ -3227,8 +6725,52 @@ pub struct UpdateGroupRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for UpdateGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "group_name"),
+            self.group_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_name"),
+            &self.group_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "role_arn"), &value, "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:([\\w+=/,.@-]*)?:[0-9]+:[\\w+=/,.@-]+(:[\\w+=/,.@-]+)?(:[\\w+=/,.@-]+)?");
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGroupResponse {
     /// <p>The group object for the group.</p>
     #[serde(rename = "Group")]
@@ -3237,6 +6779,7 @@ pub struct UpdateGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateIdentityProviderRequest {
     /// <p>The identity provider attribute mapping to be changed.</p>
     #[serde(rename = "AttributeMapping")]
@@ -3258,8 +6801,60 @@ pub struct UpdateIdentityProviderRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for UpdateIdentityProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_mapping {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "attribute_mapping"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+        if let Some(ref value) = self.idp_identifiers {
+            errors.check_length(
+                &field_path(path, "idp_identifiers"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "idp_identifiers"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(40));
+                errors.check_pattern(&item_path, &item, "[\\w\\s+=.@-]+");
+            }
+        }
+        if let Some(ref value) = self.provider_details {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "provider_details"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(131072));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "provider_name"),
+            self.provider_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "provider_name"),
+            &self.provider_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\p{Z}]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateIdentityProviderResponse {
     /// <p>The identity provider object.</p>
     #[serde(rename = "IdentityProvider")]
@@ -3267,6 +6862,7 @@ pub struct UpdateIdentityProviderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateResourceServerRequest {
     /// <p>The identifier for the resource server.</p>
     #[serde(rename = "Identifier")]
@@ -3283,8 +6879,49 @@ pub struct UpdateResourceServerRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for UpdateResourceServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identifier"),
+            self.identifier.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "identifier"),
+            &self.identifier,
+            "[\\x21\\x23-\\x5B\\x5D-\\x7E]+",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\w\\s+=,.@-]+");
+        if let Some(ref value) = self.scopes {
+            errors.check_length(&field_path(path, "scopes"), value.len(), None, Some(100));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "scopes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateResourceServerResponse {
     /// <p>The resource server.</p>
     #[serde(rename = "ResourceServer")]
@@ -3293,6 +6930,7 @@ pub struct UpdateResourceServerResponse {
 
 /// <p>Represents the request to update user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserAttributesRequest {
     /// <p>The access token for the request to update user attributes.</p>
     #[serde(rename = "AccessToken")]
@@ -3302,9 +6940,23 @@ pub struct UpdateUserAttributesRequest {
     pub user_attributes: Vec<AttributeType>,
 }
 
+impl Validate for UpdateUserAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+        for (i, item) in self.user_attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "user_attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Represents the response from the server for the request to update user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUserAttributesResponse {
     /// <p>The code delivery details list from the server for the request to update user attributes.</p>
     #[serde(rename = "CodeDeliveryDetailsList")]
@@ -3314,6 +6966,7 @@ pub struct UpdateUserAttributesResponse {
 
 /// <p>Represents the request to update the user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserPoolClientRequest {
     /// <p>Set to <code>code</code> to initiate a code grant flow, which provides an authorization code as the response. This code can be exchanged for access tokens with the token endpoint.</p>
     #[serde(rename = "AllowedOAuthFlows")]
@@ -3375,9 +7028,135 @@ pub struct UpdateUserPoolClientRequest {
     pub write_attributes: Option<Vec<String>>,
 }
 
+impl Validate for UpdateUserPoolClientRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.allowed_o_auth_flows {
+            errors.check_length(
+                &field_path(path, "allowed_o_auth_flows"),
+                value.len(),
+                None,
+                Some(3),
+            );
+        }
+        if let Some(ref value) = self.allowed_o_auth_scopes {
+            errors.check_length(
+                &field_path(path, "allowed_o_auth_scopes"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "allowed_o_auth_scopes"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(&item_path, &item, "[\\x21\\x23-\\x5B\\x5D-\\x7E]+");
+            }
+        }
+        if let Some(ref value) = self.analytics_configuration {
+            value.validate_at(&field_path(path, "analytics_configuration"), errors);
+        }
+        if let Some(ref value) = self.callback_ur_ls {
+            errors.check_length(
+                &field_path(path, "callback_ur_ls"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "callback_ur_ls"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(&item_path, &item, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[\\w+]+");
+        if let Some(ref value) = self.client_name {
+            errors.check_length(
+                &field_path(path, "client_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "client_name"), &value, "[\\w\\s+=,.@-]+");
+        }
+        if let Some(ref value) = self.default_redirect_uri {
+            errors.check_length(
+                &field_path(path, "default_redirect_uri"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "default_redirect_uri"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+            );
+        }
+        if let Some(ref value) = self.logout_ur_ls {
+            errors.check_length(
+                &field_path(path, "logout_ur_ls"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "logout_ur_ls"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(&item_path, &item, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+");
+            }
+        }
+        if let Some(ref value) = self.read_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "read_attributes"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+            }
+        }
+        if let Some(ref value) = self.refresh_token_validity {
+            errors.check_range(
+                &field_path(path, "refresh_token_validity"),
+                *value,
+                None,
+                Some(315360000),
+            );
+        }
+        if let Some(ref value) = self.supported_identity_providers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "supported_identity_providers"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+                errors.check_pattern(&item_path, &item, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\p{Z}]+");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        if let Some(ref value) = self.write_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "write_attributes"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+            }
+        }
+    }
+}
+
 /// <p>Represents the response from the server to the request to update the user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUserPoolClientResponse {
     /// <p>The user pool client value from the response from the server when an update user pool client request is made.</p>
     #[serde(rename = "UserPoolClient")]
@@ -3387,6 +7166,7 @@ pub struct UpdateUserPoolClientResponse {
 
 /// <p>The UpdateUserPoolDomain request input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserPoolDomainRequest {
     /// <p>The configuration for a custom domain that hosts the sign-up and sign-in pages for your application. Use this object to specify an SSL certificate that is managed by ACM.</p>
     #[serde(rename = "CustomDomainConfig")]
@@ -3399,9 +7179,38 @@ pub struct UpdateUserPoolDomainRequest {
     pub user_pool_id: String,
 }
 
+impl Validate for UpdateUserPoolDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.custom_domain_config
+            .validate_at(&field_path(path, "custom_domain_config"), errors);
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain"),
+            &self.domain,
+            "^[a-z0-9](?:[a-z0-9\\-]{0,61}[a-z0-9])?$",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>The UpdateUserPoolDomain response output.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUserPoolDomainResponse {
     /// <p>The Amazon CloudFront endpoint that Amazon Cognito set up when you added the custom domain to your user pool.</p>
     #[serde(rename = "CloudFrontDomain")]
@@ -3411,6 +7220,7 @@ pub struct UpdateUserPoolDomainResponse {
 
 /// <p>Represents the request to update the user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserPoolRequest {
     /// <p>The configuration for <code>AdminCreateUser</code> requests.</p>
     #[serde(rename = "AdminCreateUserConfig")]
@@ -3477,13 +7287,102 @@ pub struct UpdateUserPoolRequest {
     pub verification_message_template: Option<VerificationMessageTemplateType>,
 }
 
+impl Validate for UpdateUserPoolRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.admin_create_user_config {
+            value.validate_at(&field_path(path, "admin_create_user_config"), errors);
+        }
+        if let Some(ref value) = self.email_configuration {
+            value.validate_at(&field_path(path, "email_configuration"), errors);
+        }
+        if let Some(ref value) = self.email_verification_message {
+            errors.check_length(
+                &field_path(path, "email_verification_message"),
+                value.chars().count(),
+                Some(6),
+                Some(20000),
+            );
+            errors.check_pattern(&field_path(path, "email_verification_message"), &value, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*\\{####\\}[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*");
+        }
+        if let Some(ref value) = self.email_verification_subject {
+            errors.check_length(
+                &field_path(path, "email_verification_subject"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+            errors.check_pattern(
+                &field_path(path, "email_verification_subject"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s]+",
+            );
+        }
+        if let Some(ref value) = self.lambda_config {
+            value.validate_at(&field_path(path, "lambda_config"), errors);
+        }
+        if let Some(ref value) = self.policies {
+            value.validate_at(&field_path(path, "policies"), errors);
+        }
+        if let Some(ref value) = self.sms_authentication_message {
+            errors.check_length(
+                &field_path(path, "sms_authentication_message"),
+                value.chars().count(),
+                Some(6),
+                Some(140),
+            );
+            errors.check_pattern(
+                &field_path(path, "sms_authentication_message"),
+                &value,
+                ".*\\{####\\}.*",
+            );
+        }
+        if let Some(ref value) = self.sms_configuration {
+            value.validate_at(&field_path(path, "sms_configuration"), errors);
+        }
+        if let Some(ref value) = self.sms_verification_message {
+            errors.check_length(
+                &field_path(path, "sms_verification_message"),
+                value.chars().count(),
+                Some(6),
+                Some(140),
+            );
+            errors.check_pattern(
+                &field_path(path, "sms_verification_message"),
+                &value,
+                ".*\\{####\\}.*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_pool_id"),
+            self.user_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool_id"),
+            &self.user_pool_id,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+        if let Some(ref value) = self.user_pool_tags {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "user_pool_tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        if let Some(ref value) = self.verification_message_template {
+            value.validate_at(&field_path(path, "verification_message_template"), errors);
+        }
+    }
+}
+
 /// <p>Represents the response from the server when you make a request to update the user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUserPoolResponse {}
 
 /// <p>Contextual data such as the user's device fingerprint, IP address, or location used for evaluating the risk of an unexpected event by Amazon Cognito advanced security.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UserContextDataType {
     /// <p>Contextual data such as the user's device fingerprint, IP address, or location used for evaluating the risk of an unexpected event by Amazon Cognito advanced security.</p>
     #[serde(rename = "EncodedData")]
@@ -3491,9 +7390,22 @@ pub struct UserContextDataType {
     pub encoded_data: Option<String>,
 }
 
+impl Validate for UserContextDataType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.encoded_data {
+            errors.check_length(
+                &field_path(path, "encoded_data"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+    }
+}
+
 /// <p>The user import job type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserImportJobType {
     /// <p>The role ARN for the Amazon CloudWatch Logging role for the user import job. For more information, see "Creating the CloudWatch Logs IAM Role" in the Amazon Cognito Developer Guide.</p>
     #[serde(rename = "CloudWatchLogsRoleArn")]
@@ -3559,7 +7471,7 @@ pub struct UserPoolAddOnsType {
 
 /// <p>The description of the user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserPoolClientDescription {
     /// <p>The ID of the client associated with the user pool.</p>
     #[serde(rename = "ClientId")]
@@ -3577,7 +7489,7 @@ pub struct UserPoolClientDescription {
 
 /// <p>Contains information about a user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserPoolClientType {
     /// <p>Set to <code>code</code> to initiate a code grant flow, which provides an authorization code as the response. This code can be exchanged for access tokens with the token endpoint.</p> <p>Set to <code>token</code> to specify that the client should get the access token (and, optionally, ID token, based on scopes) directly.</p>
     #[serde(rename = "AllowedOAuthFlows")]
@@ -3655,7 +7567,7 @@ pub struct UserPoolClientType {
 
 /// <p>A user pool description.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserPoolDescriptionType {
     /// <p>The date the user pool description was created.</p>
     #[serde(rename = "CreationDate")]
@@ -3692,9 +7604,17 @@ pub struct UserPoolPolicyType {
     pub password_policy: Option<PasswordPolicyType>,
 }
 
+impl Validate for UserPoolPolicyType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.password_policy {
+            value.validate_at(&field_path(path, "password_policy"), errors);
+        }
+    }
+}
+
 /// <p>A container for information about the user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserPoolType {
     /// <p>The configuration for <code>AdminCreateUser</code> requests.</p>
     #[serde(rename = "AdminCreateUserConfig")]
@@ -3816,7 +7736,7 @@ pub struct UserPoolType {
 
 /// <p>The user type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserType {
     /// <p>A container with information about the user type attributes.</p>
     #[serde(rename = "Attributes")]
@@ -3877,7 +7797,66 @@ pub struct VerificationMessageTemplateType {
     pub sms_message: Option<String>,
 }
 
+impl Validate for VerificationMessageTemplateType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.email_message {
+            errors.check_length(
+                &field_path(path, "email_message"),
+                value.chars().count(),
+                Some(6),
+                Some(20000),
+            );
+            errors.check_pattern(&field_path(path, "email_message"), &value, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*\\{####\\}[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*");
+        }
+        if let Some(ref value) = self.email_message_by_link {
+            errors.check_length(
+                &field_path(path, "email_message_by_link"),
+                value.chars().count(),
+                Some(6),
+                Some(20000),
+            );
+            errors.check_pattern(&field_path(path, "email_message_by_link"), &value, "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*\\{##[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*##\\}[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s*]*");
+        }
+        if let Some(ref value) = self.email_subject {
+            errors.check_length(
+                &field_path(path, "email_subject"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+            errors.check_pattern(
+                &field_path(path, "email_subject"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s]+",
+            );
+        }
+        if let Some(ref value) = self.email_subject_by_link {
+            errors.check_length(
+                &field_path(path, "email_subject_by_link"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+            errors.check_pattern(
+                &field_path(path, "email_subject_by_link"),
+                &value,
+                "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}\\s]+",
+            );
+        }
+        if let Some(ref value) = self.sms_message {
+            errors.check_length(
+                &field_path(path, "sms_message"),
+                value.chars().count(),
+                Some(6),
+                Some(140),
+            );
+            errors.check_pattern(&field_path(path, "sms_message"), &value, ".*\\{####\\}.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VerifySoftwareTokenRequest {
     /// <p>The access token.</p>
     #[serde(rename = "AccessToken")]
@@ -3896,8 +7875,43 @@ pub struct VerifySoftwareTokenRequest {
     pub user_code: String,
 }
 
+impl Validate for VerifySoftwareTokenRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.access_token {
+            errors.check_pattern(
+                &field_path(path, "access_token"),
+                &value,
+                "[A-Za-z0-9-_=.]+",
+            );
+        }
+        if let Some(ref value) = self.friendly_device_name {
+            errors.check_length(
+                &field_path(path, "friendly_device_name"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+        }
+        if let Some(ref value) = self.session {
+            errors.check_length(
+                &field_path(path, "session"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_code"),
+            self.user_code.chars().count(),
+            Some(6),
+            Some(6),
+        );
+        errors.check_pattern(&field_path(path, "user_code"), &self.user_code, "[0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VerifySoftwareTokenResponse {
     /// <p>The session which should be passed both ways in challenge-response calls to the service.</p>
     #[serde(rename = "Session")]
@@ -3911,6 +7925,7 @@ pub struct VerifySoftwareTokenResponse {
 
 /// <p>Represents the request to verify user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VerifyUserAttributeRequest {
     /// <p>Represents the access token of the request to verify user attributes.</p>
     #[serde(rename = "AccessToken")]
@@ -3923,9 +7938,37 @@ pub struct VerifyUserAttributeRequest {
     pub code: String,
 }
 
+impl Validate for VerifyUserAttributeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "access_token"),
+            &self.access_token,
+            "[A-Za-z0-9-_=.]+",
+        );
+        errors.check_length(
+            &field_path(path, "attribute_name"),
+            self.attribute_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "attribute_name"),
+            &self.attribute_name,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        errors.check_length(
+            &field_path(path, "code"),
+            self.code.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "code"), &self.code, "[\\S]+");
+    }
+}
+
 /// <p>A container representing the response from the server from the request to verify user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VerifyUserAttributeResponse {}
 
 /// Errors returned by AddCustomAttributes
@@ -3973,7 +8016,12 @@ impl AddCustomAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4040,7 +8088,12 @@ impl AdminAddUserToGroupError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminAddUserToGroupError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4134,7 +8187,12 @@ impl AdminConfirmSignUpError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminConfirmSignUpError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4262,7 +8320,12 @@ impl AdminCreateUserError {
                 "UsernameExistsException" => {
                     return RusotoError::Service(AdminCreateUserError::UsernameExists(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4335,7 +8398,12 @@ impl AdminDeleteUserError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminDeleteUserError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4410,7 +8478,12 @@ impl AdminDeleteUserAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4494,7 +8567,12 @@ impl AdminDisableProviderForUserError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4558,7 +8636,12 @@ impl AdminDisableUserError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminDisableUserError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4621,7 +8704,12 @@ impl AdminEnableUserError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminEnableUserError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4691,7 +8779,12 @@ impl AdminForgetDeviceError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminForgetDeviceError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4757,7 +8850,12 @@ impl AdminGetDeviceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(AdminGetDeviceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4820,7 +8918,12 @@ impl AdminGetUserError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminGetUserError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4940,7 +9043,12 @@ impl AdminInitiateAuthError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminInitiateAuthError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5031,7 +9139,12 @@ impl AdminLinkProviderForUserError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5097,7 +9210,12 @@ impl AdminListDevicesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(AdminListDevicesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5170,7 +9288,12 @@ impl AdminListGroupsForUserError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminListGroupsForUserError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5252,7 +9375,12 @@ impl AdminListUserAuthEventsError {
                         AdminListUserAuthEventsError::UserPoolAddOnNotEnabled(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5328,7 +9456,12 @@ impl AdminRemoveUserFromGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5450,7 +9583,12 @@ impl AdminResetUserPasswordError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminResetUserPasswordError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5632,7 +9770,12 @@ impl AdminRespondToAuthChallengeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5728,7 +9871,12 @@ impl AdminSetUserMFAPreferenceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5805,7 +9953,12 @@ impl AdminSetUserPasswordError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminSetUserPasswordError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5868,7 +10021,12 @@ impl AdminSetUserSettingsError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminSetUserSettingsError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5951,7 +10109,12 @@ impl AdminUpdateAuthEventFeedbackError {
                         AdminUpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6034,7 +10197,12 @@ impl AdminUpdateDeviceStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6159,7 +10327,12 @@ impl AdminUpdateUserAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6239,7 +10412,12 @@ impl AdminUserGlobalSignOutError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(AdminUserGlobalSignOutError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6307,7 +10485,12 @@ impl AssociateSoftwareTokenError {
                         AssociateSoftwareTokenError::SoftwareTokenMFANotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6391,7 +10574,12 @@ impl ChangePasswordError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(ChangePasswordError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6490,7 +10678,12 @@ impl ConfirmDeviceError {
                 "UsernameExistsException" => {
                     return RusotoError::Service(ConfirmDeviceError::UsernameExists(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6622,7 +10815,12 @@ impl ConfirmForgotPasswordError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(ConfirmForgotPasswordError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6734,7 +10932,12 @@ impl ConfirmSignUpError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(ConfirmSignUpError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6810,7 +11013,12 @@ impl CreateGroupError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateGroupError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6893,7 +11101,12 @@ impl CreateIdentityProviderError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6963,7 +11176,12 @@ impl CreateResourceServerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7037,7 +11255,12 @@ impl CreateUserImportJobError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateUserImportJobError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7122,7 +11345,12 @@ impl CreateUserPoolError {
                 "UserPoolTaggingException" => {
                     return RusotoError::Service(CreateUserPoolError::UserPoolTagging(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7208,7 +11436,12 @@ impl CreateUserPoolClientError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7272,7 +11505,12 @@ impl CreateUserPoolDomainError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7329,7 +11567,12 @@ impl DeleteGroupError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteGroupError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7403,7 +11646,12 @@ impl DeleteIdentityProviderError {
                         DeleteIdentityProviderError::UnsupportedIdentityProvider(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7467,7 +11715,12 @@ impl DeleteResourceServerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7539,7 +11792,12 @@ impl DeleteUserError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(DeleteUserError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7624,7 +11882,12 @@ impl DeleteUserAttributesError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(DeleteUserAttributesError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7689,7 +11952,12 @@ impl DeleteUserPoolError {
                 "UserImportInProgressException" => {
                     return RusotoError::Service(DeleteUserPoolError::UserImportInProgress(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7753,7 +12021,12 @@ impl DeleteUserPoolClientError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7809,7 +12082,12 @@ impl DeleteUserPoolDomainError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7875,7 +12153,12 @@ impl DescribeIdentityProviderError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7942,7 +12225,12 @@ impl DescribeResourceServerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8016,7 +12304,12 @@ impl DescribeRiskConfigurationError {
                         DescribeRiskConfigurationError::UserPoolAddOnNotEnabled(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8080,7 +12373,12 @@ impl DescribeUserImportJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8142,7 +12440,12 @@ impl DescribeUserPoolError {
                 "UserPoolTaggingException" => {
                     return RusotoError::Service(DescribeUserPoolError::UserPoolTagging(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8210,7 +12513,12 @@ impl DescribeUserPoolClientError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8270,7 +12578,12 @@ impl DescribeUserPoolDomainError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8348,7 +12661,12 @@ impl ForgetDeviceError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(ForgetDeviceError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8467,7 +12785,12 @@ impl ForgotPasswordError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(ForgotPasswordError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8534,7 +12857,12 @@ impl GetCSVHeaderError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetCSVHeaderError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8613,7 +12941,12 @@ impl GetDeviceError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(GetDeviceError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8674,7 +13007,12 @@ impl GetGroupError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetGroupError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8743,7 +13081,12 @@ impl GetIdentityProviderByIdentifierError {
                         GetIdentityProviderByIdentifierError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8787,7 +13130,12 @@ impl GetSigningCertificateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8841,7 +13189,12 @@ impl GetUICustomizationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetUICustomizationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8913,7 +13266,12 @@ impl GetUserError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(GetUserError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9066,7 +13424,12 @@ impl GetUserAttributeVerificationCodeError {
                         GetUserAttributeVerificationCodeError::UserNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9142,7 +13505,12 @@ impl GetUserPoolMfaConfigError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9209,7 +13577,12 @@ impl GlobalSignOutError {
                 "UserNotConfirmedException" => {
                     return RusotoError::Service(GlobalSignOutError::UserNotConfirmed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9305,7 +13678,12 @@ impl InitiateAuthError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(InitiateAuthError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9391,7 +13769,12 @@ impl ListDevicesError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(ListDevicesError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9452,7 +13835,12 @@ impl ListGroupsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListGroupsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9515,7 +13903,12 @@ impl ListIdentityProvidersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9576,7 +13969,12 @@ impl ListResourceServersError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListResourceServersError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9637,7 +14035,12 @@ impl ListTagsForResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9694,7 +14097,12 @@ impl ListUserImportJobsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListUserImportJobsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9755,7 +14163,12 @@ impl ListUserPoolClientsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListUserPoolClientsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9807,7 +14220,12 @@ impl ListUserPoolsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListUserPoolsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9863,7 +14281,12 @@ impl ListUsersError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListUsersError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9920,7 +14343,12 @@ impl ListUsersInGroupError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListUsersInGroupError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10048,7 +14476,12 @@ impl ResendConfirmationCodeError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(ResendConfirmationCodeError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10221,7 +14654,12 @@ impl RespondToAuthChallengeError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(RespondToAuthChallengeError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10320,7 +14758,12 @@ impl SetRiskConfigurationError {
                         SetRiskConfigurationError::UserPoolAddOnNotEnabled(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10380,7 +14823,12 @@ impl SetUICustomizationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(SetUICustomizationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10455,7 +14903,12 @@ impl SetUserMFAPreferenceError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(SetUserMFAPreferenceError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10534,7 +14987,12 @@ impl SetUserPoolMfaConfigError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10605,7 +15063,12 @@ impl SetUserSettingsError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(SetUserSettingsError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10711,7 +15174,12 @@ impl SignUpError {
                 "UsernameExistsException" => {
                     return RusotoError::Service(SignUpError::UsernameExists(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10784,7 +15252,12 @@ impl StartUserImportJobError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(StartUserImportJobError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10849,7 +15322,12 @@ impl StopUserImportJobError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(StopUserImportJobError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10907,7 +15385,12 @@ impl TagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10964,7 +15447,12 @@ impl UntagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11045,7 +15533,12 @@ impl UpdateAuthEventFeedbackError {
                         UpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11128,7 +15621,12 @@ impl UpdateDeviceStatusError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(UpdateDeviceStatusError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11189,7 +15687,12 @@ impl UpdateGroupError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateGroupError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11263,7 +15766,12 @@ impl UpdateIdentityProviderError {
                         UpdateIdentityProviderError::UnsupportedIdentityProvider(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11327,7 +15835,12 @@ impl UpdateResourceServerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11473,7 +15986,12 @@ impl UpdateUserAttributesError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(UpdateUserAttributesError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11581,7 +16099,12 @@ impl UpdateUserPoolError {
                 "UserPoolTaggingException" => {
                     return RusotoError::Service(UpdateUserPoolError::UserPoolTagging(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11671,7 +16194,12 @@ impl UpdateUserPoolClientError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11737,7 +16265,12 @@ impl UpdateUserPoolDomainError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11843,7 +16376,12 @@ impl VerifySoftwareTokenError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(VerifySoftwareTokenError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11945,7 +16483,12 @@ impl VerifyUserAttributeError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(VerifyUserAttributeError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12557,3101 +17100,9242 @@ pub trait CognitoIdentityProvider {
         input: VerifyUserAttributeRequest,
     ) -> RusotoFuture<VerifyUserAttributeResponse, VerifyUserAttributeError>;
 }
-/// A client for the Amazon Cognito Identity Provider API.
-#[derive(Clone)]
-pub struct CognitoIdentityProviderClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CognitoIdentityProviderClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CognitoIdentityProviderClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CognitoIdentityProviderClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(
-        client: Client,
-        region: region::Region,
-    ) -> CognitoIdentityProviderClient {
-        CognitoIdentityProviderClient { client, region }
-    }
-}
-
-impl CognitoIdentityProvider for CognitoIdentityProviderClient {
-    /// <p>Adds additional user attributes to the user pool schema.</p>
+const _: Option<&dyn CognitoIdentityProvider> = None;
+impl<'a, T: CognitoIdentityProvider + ?Sized> CognitoIdentityProvider for &'a T {
     fn add_custom_attributes(
         &self,
         input: AddCustomAttributesRequest,
     ) -> RusotoFuture<AddCustomAttributesResponse, AddCustomAttributesError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AddCustomAttributes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddCustomAttributesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AddCustomAttributesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).add_custom_attributes(input)
     }
-
-    /// <p>Adds the specified user to the specified group.</p> <p>Requires developer credentials.</p>
     fn admin_add_user_to_group(
         &self,
         input: AdminAddUserToGroupRequest,
     ) -> RusotoFuture<(), AdminAddUserToGroupError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminAddUserToGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AdminAddUserToGroupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).admin_add_user_to_group(input)
     }
-
-    /// <p>Confirms user registration as an admin without using a confirmation code. Works on any user.</p> <p>Requires developer credentials.</p>
     fn admin_confirm_sign_up(
         &self,
         input: AdminConfirmSignUpRequest,
     ) -> RusotoFuture<AdminConfirmSignUpResponse, AdminConfirmSignUpError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminConfirmSignUp",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminConfirmSignUpResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AdminConfirmSignUpError::from_response(response))),
-                )
-            }
-        })
+        (**self).admin_confirm_sign_up(input)
     }
-
-    /// <p>Creates a new user in the specified user pool.</p> <p>If <code>MessageAction</code> is not set, the default is to send a welcome message via email or phone (SMS).</p> <note> <p>This message is based on a template that you configured in your call to or . This template includes your custom sign-up instructions and placeholders for user name and temporary password.</p> </note> <p>Alternatively, you can call AdminCreateUser with “SUPPRESS” for the <code>MessageAction</code> parameter, and Amazon Cognito will not send any email. </p> <p>In either case, the user will be in the <code>FORCE_CHANGE_PASSWORD</code> state until they sign in and change their password.</p> <p>AdminCreateUser requires developer credentials.</p>
     fn admin_create_user(
         &self,
         input: AdminCreateUserRequest,
     ) -> RusotoFuture<AdminCreateUserResponse, AdminCreateUserError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminCreateUser",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminCreateUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AdminCreateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).admin_create_user(input)
     }
-
-    /// <p>Deletes a user as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
     fn admin_delete_user(
         &self,
         input: AdminDeleteUserRequest,
     ) -> RusotoFuture<(), AdminDeleteUserError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminDeleteUser",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AdminDeleteUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).admin_delete_user(input)
     }
-
-    /// <p>Deletes the user attributes in a user pool as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
     fn admin_delete_user_attributes(
         &self,
         input: AdminDeleteUserAttributesRequest,
     ) -> RusotoFuture<AdminDeleteUserAttributesResponse, AdminDeleteUserAttributesError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminDeleteUserAttributes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminDeleteUserAttributesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AdminDeleteUserAttributesError::from_response(response))
-                }))
-            }
-        })
+        (**self).admin_delete_user_attributes(input)
     }
-
-    /// <p>Disables the user from signing in with the specified external (SAML or social) identity provider. If the user to disable is a Cognito User Pools native username + password user, they are not permitted to use their password to sign-in. If the user to disable is a linked external IdP user, any link between that user and an existing user is removed. The next time the external user (no longer attached to the previously linked <code>DestinationUser</code>) signs in, they must create a new user account. See .</p> <p>This action is enabled only for admin access and requires developer credentials.</p> <p>The <code>ProviderName</code> must match the value specified when creating an IdP for the pool. </p> <p>To disable a native username + password user, the <code>ProviderName</code> value must be <code>Cognito</code> and the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code>, with the <code>ProviderAttributeValue</code> being the name that is used in the user pool for the user.</p> <p>The <code>ProviderAttributeName</code> must always be <code>Cognito_Subject</code> for social identity providers. The <code>ProviderAttributeValue</code> must always be the exact subject that was used when the user was originally linked as a source user.</p> <p>For de-linking a SAML identity, there are two scenarios. If the linked identity has not yet been used to sign-in, the <code>ProviderAttributeName</code> and <code>ProviderAttributeValue</code> must be the same values that were used for the <code>SourceUser</code> when the identities were originally linked in the call. (If the linking was done with <code>ProviderAttributeName</code> set to <code>Cognito_Subject</code>, the same applies here). However, if the user has already signed in, the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code> and <code>ProviderAttributeValue</code> must be the subject of the SAML assertion.</p>
     fn admin_disable_provider_for_user(
         &self,
         input: AdminDisableProviderForUserRequest,
     ) -> RusotoFuture<AdminDisableProviderForUserResponse, AdminDisableProviderForUserError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminDisableProviderForUser",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminDisableProviderForUserResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AdminDisableProviderForUserError::from_response(response))
-                }))
-            }
-        })
+        (**self).admin_disable_provider_for_user(input)
     }
-
-    /// <p>Disables the specified user as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
     fn admin_disable_user(
         &self,
         input: AdminDisableUserRequest,
     ) -> RusotoFuture<AdminDisableUserResponse, AdminDisableUserError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminDisableUser",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminDisableUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AdminDisableUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).admin_disable_user(input)
     }
-
-    /// <p>Enables the specified user as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
     fn admin_enable_user(
         &self,
         input: AdminEnableUserRequest,
     ) -> RusotoFuture<AdminEnableUserResponse, AdminEnableUserError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminEnableUser",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminEnableUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AdminEnableUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).admin_enable_user(input)
     }
-
-    /// <p>Forgets the device, as an administrator.</p> <p>Requires developer credentials.</p>
     fn admin_forget_device(
         &self,
         input: AdminForgetDeviceRequest,
     ) -> RusotoFuture<(), AdminForgetDeviceError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminForgetDevice",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AdminForgetDeviceError::from_response(response))),
-                )
-            }
-        })
+        (**self).admin_forget_device(input)
     }
-
-    /// <p>Gets the device, as an administrator.</p> <p>Requires developer credentials.</p>
     fn admin_get_device(
         &self,
         input: AdminGetDeviceRequest,
     ) -> RusotoFuture<AdminGetDeviceResponse, AdminGetDeviceError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminGetDevice",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminGetDeviceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AdminGetDeviceError::from_response(response))),
-                )
-            }
-        })
+        (**self).admin_get_device(input)
     }
-
-    /// <p>Gets the specified user by user name in a user pool as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
     fn admin_get_user(
         &self,
         input: AdminGetUserRequest,
     ) -> RusotoFuture<AdminGetUserResponse, AdminGetUserError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminGetUser",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminGetUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AdminGetUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).admin_get_user(input)
     }
-
-    /// <p>Initiates the authentication flow, as an administrator.</p> <p>Requires developer credentials.</p>
     fn admin_initiate_auth(
         &self,
         input: AdminInitiateAuthRequest,
     ) -> RusotoFuture<AdminInitiateAuthResponse, AdminInitiateAuthError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminInitiateAuth",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminInitiateAuthResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AdminInitiateAuthError::from_response(response))),
-                )
-            }
-        })
+        (**self).admin_initiate_auth(input)
     }
-
-    /// <p>Links an existing user account in a user pool (<code>DestinationUser</code>) to an identity from an external identity provider (<code>SourceUser</code>) based on a specified attribute name and value from the external identity provider. This allows you to create a link from the existing user account to an external federated user identity that has not yet been used to sign in, so that the federated user identity can be used to sign in as the existing user account. </p> <p> For example, if there is an existing user with a username and password, this API links that user to a federated user identity, so that when the federated user identity is used, the user signs in as the existing user account. </p> <important> <p>Because this API allows a user with an external federated identity to sign in as an existing user in the user pool, it is critical that it only be used with external identity providers and provider attributes that have been trusted by the application owner.</p> </important> <p>See also .</p> <p>This action is enabled only for admin access and requires developer credentials.</p>
     fn admin_link_provider_for_user(
         &self,
         input: AdminLinkProviderForUserRequest,
     ) -> RusotoFuture<AdminLinkProviderForUserResponse, AdminLinkProviderForUserError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminLinkProviderForUser",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminLinkProviderForUserResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AdminLinkProviderForUserError::from_response(response))
-                }))
-            }
-        })
+        (**self).admin_link_provider_for_user(input)
     }
-
-    /// <p>Lists devices, as an administrator.</p> <p>Requires developer credentials.</p>
     fn admin_list_devices(
         &self,
         input: AdminListDevicesRequest,
     ) -> RusotoFuture<AdminListDevicesResponse, AdminListDevicesError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminListDevices",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminListDevicesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AdminListDevicesError::from_response(response))),
-                )
-            }
-        })
+        (**self).admin_list_devices(input)
     }
-
-    /// <p>Lists the groups that the user belongs to.</p> <p>Requires developer credentials.</p>
     fn admin_list_groups_for_user(
         &self,
         input: AdminListGroupsForUserRequest,
     ) -> RusotoFuture<AdminListGroupsForUserResponse, AdminListGroupsForUserError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminListGroupsForUser",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminListGroupsForUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AdminListGroupsForUserError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).admin_list_groups_for_user(input)
     }
-
-    /// <p>Lists a history of user activity and any risks detected as part of Amazon Cognito advanced security.</p>
     fn admin_list_user_auth_events(
         &self,
         input: AdminListUserAuthEventsRequest,
     ) -> RusotoFuture<AdminListUserAuthEventsResponse, AdminListUserAuthEventsError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminListUserAuthEvents",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminListUserAuthEventsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AdminListUserAuthEventsError::from_response(response))
-                }))
-            }
-        })
+        (**self).admin_list_user_auth_events(input)
     }
-
-    /// <p>Removes the specified user from the specified group.</p> <p>Requires developer credentials.</p>
     fn admin_remove_user_from_group(
         &self,
         input: AdminRemoveUserFromGroupRequest,
     ) -> RusotoFuture<(), AdminRemoveUserFromGroupError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminRemoveUserFromGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AdminRemoveUserFromGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).admin_remove_user_from_group(input)
     }
-
-    /// <p>Resets the specified user's password in a user pool as an administrator. Works on any user.</p> <p>When a developer calls this API, the current password is invalidated, so it must be changed. If a user tries to sign in after the API is called, the app will get a PasswordResetRequiredException exception back and should direct the user down the flow to reset the password, which is the same as the forgot password flow. In addition, if the user pool has phone verification selected and a verified phone number exists for the user, or if email verification is selected and a verified email exists for the user, calling this API will also result in sending a message to the end user with the code to change their password.</p> <p>Requires developer credentials.</p>
     fn admin_reset_user_password(
         &self,
         input: AdminResetUserPasswordRequest,
     ) -> RusotoFuture<AdminResetUserPasswordResponse, AdminResetUserPasswordError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminResetUserPassword",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminResetUserPasswordResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AdminResetUserPasswordError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).admin_reset_user_password(input)
     }
-
-    /// <p>Responds to an authentication challenge, as an administrator.</p> <p>Requires developer credentials.</p>
     fn admin_respond_to_auth_challenge(
         &self,
         input: AdminRespondToAuthChallengeRequest,
     ) -> RusotoFuture<AdminRespondToAuthChallengeResponse, AdminRespondToAuthChallengeError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminRespondToAuthChallenge",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminRespondToAuthChallengeResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AdminRespondToAuthChallengeError::from_response(response))
-                }))
-            }
-        })
+        (**self).admin_respond_to_auth_challenge(input)
     }
-
-    /// <p>Sets the user's multi-factor authentication (MFA) preference.</p>
     fn admin_set_user_mfa_preference(
         &self,
         input: AdminSetUserMFAPreferenceRequest,
     ) -> RusotoFuture<AdminSetUserMFAPreferenceResponse, AdminSetUserMFAPreferenceError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminSetUserMFAPreference",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminSetUserMFAPreferenceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AdminSetUserMFAPreferenceError::from_response(response))
-                }))
-            }
-        })
+        (**self).admin_set_user_mfa_preference(input)
     }
-
     fn admin_set_user_password(
         &self,
         input: AdminSetUserPasswordRequest,
     ) -> RusotoFuture<AdminSetUserPasswordResponse, AdminSetUserPasswordError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminSetUserPassword",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminSetUserPasswordResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AdminSetUserPasswordError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).admin_set_user_password(input)
     }
-
-    /// <p>Sets all the user settings for a specified user name. Works on any user.</p> <p>Requires developer credentials.</p>
     fn admin_set_user_settings(
         &self,
         input: AdminSetUserSettingsRequest,
     ) -> RusotoFuture<AdminSetUserSettingsResponse, AdminSetUserSettingsError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminSetUserSettings",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminSetUserSettingsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AdminSetUserSettingsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).admin_set_user_settings(input)
     }
-
-    /// <p>Provides feedback for an authentication event as to whether it was from a valid user. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
     fn admin_update_auth_event_feedback(
         &self,
         input: AdminUpdateAuthEventFeedbackRequest,
     ) -> RusotoFuture<AdminUpdateAuthEventFeedbackResponse, AdminUpdateAuthEventFeedbackError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminUpdateAuthEventFeedback",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminUpdateAuthEventFeedbackResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AdminUpdateAuthEventFeedbackError::from_response(response))
-                }))
-            }
-        })
+        (**self).admin_update_auth_event_feedback(input)
     }
-
-    /// <p>Updates the device status as an administrator.</p> <p>Requires developer credentials.</p>
     fn admin_update_device_status(
         &self,
         input: AdminUpdateDeviceStatusRequest,
     ) -> RusotoFuture<AdminUpdateDeviceStatusResponse, AdminUpdateDeviceStatusError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminUpdateDeviceStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminUpdateDeviceStatusResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AdminUpdateDeviceStatusError::from_response(response))
-                }))
-            }
-        })
+        (**self).admin_update_device_status(input)
     }
-
-    /// <p>Updates the specified user's attributes, including developer attributes, as an administrator. Works on any user.</p> <p>For custom attributes, you must prepend the <code>custom:</code> prefix to the attribute name.</p> <p>In addition to updating user attributes, this API can also be used to mark phone and email as verified.</p> <p>Requires developer credentials.</p>
     fn admin_update_user_attributes(
         &self,
         input: AdminUpdateUserAttributesRequest,
     ) -> RusotoFuture<AdminUpdateUserAttributesResponse, AdminUpdateUserAttributesError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminUpdateUserAttributes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminUpdateUserAttributesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AdminUpdateUserAttributesError::from_response(response))
-                }))
-            }
-        })
+        (**self).admin_update_user_attributes(input)
     }
-
-    /// <p>Signs out users from all devices, as an administrator.</p> <p>Requires developer credentials.</p>
     fn admin_user_global_sign_out(
         &self,
         input: AdminUserGlobalSignOutRequest,
     ) -> RusotoFuture<AdminUserGlobalSignOutResponse, AdminUserGlobalSignOutError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AdminUserGlobalSignOut",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AdminUserGlobalSignOutResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AdminUserGlobalSignOutError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).admin_user_global_sign_out(input)
     }
-
-    /// <p>Returns a unique generated shared secret key code for the user account. The request takes an access token or a session string, but not both.</p>
     fn associate_software_token(
         &self,
         input: AssociateSoftwareTokenRequest,
     ) -> RusotoFuture<AssociateSoftwareTokenResponse, AssociateSoftwareTokenError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.AssociateSoftwareToken",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateSoftwareTokenResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AssociateSoftwareTokenError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).associate_software_token(input)
     }
-
-    /// <p>Changes the password for a specified user in a user pool.</p>
     fn change_password(
         &self,
         input: ChangePasswordRequest,
     ) -> RusotoFuture<ChangePasswordResponse, ChangePasswordError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ChangePassword",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ChangePasswordResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ChangePasswordError::from_response(response))),
-                )
-            }
-        })
+        (**self).change_password(input)
     }
-
-    /// <p>Confirms tracking of the device. This API call is the call that begins device tracking.</p>
     fn confirm_device(
         &self,
         input: ConfirmDeviceRequest,
     ) -> RusotoFuture<ConfirmDeviceResponse, ConfirmDeviceError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ConfirmDevice",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ConfirmDeviceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ConfirmDeviceError::from_response(response))),
-                )
-            }
-        })
+        (**self).confirm_device(input)
     }
-
-    /// <p>Allows a user to enter a confirmation code to reset a forgotten password.</p>
     fn confirm_forgot_password(
         &self,
         input: ConfirmForgotPasswordRequest,
     ) -> RusotoFuture<ConfirmForgotPasswordResponse, ConfirmForgotPasswordError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ConfirmForgotPassword",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ConfirmForgotPasswordResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ConfirmForgotPasswordError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).confirm_forgot_password(input)
     }
-
-    /// <p>Confirms registration of a user and handles the existing alias from a previous user.</p>
     fn confirm_sign_up(
         &self,
         input: ConfirmSignUpRequest,
     ) -> RusotoFuture<ConfirmSignUpResponse, ConfirmSignUpError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ConfirmSignUp",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ConfirmSignUpResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ConfirmSignUpError::from_response(response))),
-                )
-            }
-        })
+        (**self).confirm_sign_up(input)
     }
-
-    /// <p>Creates a new group in the specified user pool.</p> <p>Requires developer credentials.</p>
     fn create_group(
         &self,
         input: CreateGroupRequest,
     ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.CreateGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_group(input)
     }
-
-    /// <p>Creates an identity provider for a user pool.</p>
     fn create_identity_provider(
         &self,
         input: CreateIdentityProviderRequest,
     ) -> RusotoFuture<CreateIdentityProviderResponse, CreateIdentityProviderError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.CreateIdentityProvider",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateIdentityProviderResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateIdentityProviderError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_identity_provider(input)
     }
-
-    /// <p>Creates a new OAuth2.0 resource server and defines custom scopes in it.</p>
     fn create_resource_server(
         &self,
         input: CreateResourceServerRequest,
     ) -> RusotoFuture<CreateResourceServerResponse, CreateResourceServerError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.CreateResourceServer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateResourceServerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateResourceServerError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_resource_server(input)
     }
-
-    /// <p>Creates the user import job.</p>
     fn create_user_import_job(
         &self,
         input: CreateUserImportJobRequest,
     ) -> RusotoFuture<CreateUserImportJobResponse, CreateUserImportJobError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.CreateUserImportJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserImportJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateUserImportJobError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_user_import_job(input)
     }
-
-    /// <p>Creates a new Amazon Cognito user pool and sets the password policy for the pool.</p>
     fn create_user_pool(
         &self,
         input: CreateUserPoolRequest,
     ) -> RusotoFuture<CreateUserPoolResponse, CreateUserPoolError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.CreateUserPool",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserPoolResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUserPoolError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_user_pool(input)
     }
-
-    /// <p>Creates the user pool client.</p>
     fn create_user_pool_client(
         &self,
         input: CreateUserPoolClientRequest,
     ) -> RusotoFuture<CreateUserPoolClientResponse, CreateUserPoolClientError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.CreateUserPoolClient",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserPoolClientResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateUserPoolClientError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_user_pool_client(input)
     }
-
-    /// <p>Creates a new domain for a user pool.</p>
     fn create_user_pool_domain(
         &self,
         input: CreateUserPoolDomainRequest,
     ) -> RusotoFuture<CreateUserPoolDomainResponse, CreateUserPoolDomainError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.CreateUserPoolDomain",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserPoolDomainResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateUserPoolDomainError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_user_pool_domain(input)
     }
-
-    /// <p>Deletes a group. Currently only groups with no members can be deleted.</p> <p>Requires developer credentials.</p>
     fn delete_group(&self, input: DeleteGroupRequest) -> RusotoFuture<(), DeleteGroupError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DeleteGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_group(input)
     }
-
-    /// <p>Deletes an identity provider for a user pool.</p>
     fn delete_identity_provider(
         &self,
         input: DeleteIdentityProviderRequest,
     ) -> RusotoFuture<(), DeleteIdentityProviderError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DeleteIdentityProvider",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteIdentityProviderError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_identity_provider(input)
     }
-
-    /// <p>Deletes a resource server.</p>
     fn delete_resource_server(
         &self,
         input: DeleteResourceServerRequest,
     ) -> RusotoFuture<(), DeleteResourceServerError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DeleteResourceServer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteResourceServerError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_resource_server(input)
     }
-
-    /// <p>Allows a user to delete himself or herself.</p>
     fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DeleteUser",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_user(input)
     }
-
-    /// <p>Deletes the attributes for a user.</p>
     fn delete_user_attributes(
         &self,
         input: DeleteUserAttributesRequest,
     ) -> RusotoFuture<DeleteUserAttributesResponse, DeleteUserAttributesError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DeleteUserAttributes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteUserAttributesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteUserAttributesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_user_attributes(input)
     }
-
-    /// <p>Deletes the specified Amazon Cognito user pool.</p>
     fn delete_user_pool(
         &self,
         input: DeleteUserPoolRequest,
     ) -> RusotoFuture<(), DeleteUserPoolError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DeleteUserPool",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUserPoolError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_user_pool(input)
     }
-
-    /// <p>Allows the developer to delete the user pool client.</p>
     fn delete_user_pool_client(
         &self,
         input: DeleteUserPoolClientRequest,
     ) -> RusotoFuture<(), DeleteUserPoolClientError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DeleteUserPoolClient",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteUserPoolClientError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_user_pool_client(input)
     }
-
-    /// <p>Deletes a domain for a user pool.</p>
     fn delete_user_pool_domain(
         &self,
         input: DeleteUserPoolDomainRequest,
     ) -> RusotoFuture<DeleteUserPoolDomainResponse, DeleteUserPoolDomainError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DeleteUserPoolDomain",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteUserPoolDomainResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteUserPoolDomainError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_user_pool_domain(input)
     }
-
-    /// <p>Gets information about a specific identity provider.</p>
     fn describe_identity_provider(
         &self,
         input: DescribeIdentityProviderRequest,
     ) -> RusotoFuture<DescribeIdentityProviderResponse, DescribeIdentityProviderError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DescribeIdentityProvider",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeIdentityProviderResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeIdentityProviderError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_identity_provider(input)
     }
-
-    /// <p>Describes a resource server.</p>
     fn describe_resource_server(
         &self,
         input: DescribeResourceServerRequest,
     ) -> RusotoFuture<DescribeResourceServerResponse, DescribeResourceServerError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DescribeResourceServer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeResourceServerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeResourceServerError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_resource_server(input)
     }
-
-    /// <p>Describes the risk configuration.</p>
     fn describe_risk_configuration(
         &self,
         input: DescribeRiskConfigurationRequest,
     ) -> RusotoFuture<DescribeRiskConfigurationResponse, DescribeRiskConfigurationError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DescribeRiskConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRiskConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeRiskConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_risk_configuration(input)
     }
-
-    /// <p>Describes the user import job.</p>
     fn describe_user_import_job(
         &self,
         input: DescribeUserImportJobRequest,
     ) -> RusotoFuture<DescribeUserImportJobResponse, DescribeUserImportJobError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DescribeUserImportJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUserImportJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeUserImportJobError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_user_import_job(input)
     }
-
-    /// <p>Returns the configuration information and metadata of the specified user pool.</p>
     fn describe_user_pool(
         &self,
         input: DescribeUserPoolRequest,
     ) -> RusotoFuture<DescribeUserPoolResponse, DescribeUserPoolError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DescribeUserPool",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUserPoolResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeUserPoolError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_user_pool(input)
     }
-
-    /// <p>Client method for returning the configuration information and metadata of the specified user pool app client.</p>
     fn describe_user_pool_client(
         &self,
         input: DescribeUserPoolClientRequest,
     ) -> RusotoFuture<DescribeUserPoolClientResponse, DescribeUserPoolClientError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DescribeUserPoolClient",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUserPoolClientResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeUserPoolClientError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_user_pool_client(input)
     }
-
-    /// <p>Gets information about a domain.</p>
     fn describe_user_pool_domain(
         &self,
         input: DescribeUserPoolDomainRequest,
     ) -> RusotoFuture<DescribeUserPoolDomainResponse, DescribeUserPoolDomainError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.DescribeUserPoolDomain",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUserPoolDomainResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeUserPoolDomainError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_user_pool_domain(input)
     }
-
-    /// <p>Forgets the specified device.</p>
     fn forget_device(&self, input: ForgetDeviceRequest) -> RusotoFuture<(), ForgetDeviceError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ForgetDevice",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ForgetDeviceError::from_response(response))),
-                )
-            }
-        })
+        (**self).forget_device(input)
     }
-
-    /// <p>Calling this API causes a message to be sent to the end user with a confirmation code that is required to change the user's password. For the <code>Username</code> parameter, you can use the username or user alias. If a verified phone number exists for the user, the confirmation code is sent to the phone number. Otherwise, if a verified email exists, the confirmation code is sent to the email. If neither a verified phone number nor a verified email exists, <code>InvalidParameterException</code> is thrown. To use the confirmation code for resetting the password, call .</p>
     fn forgot_password(
         &self,
         input: ForgotPasswordRequest,
     ) -> RusotoFuture<ForgotPasswordResponse, ForgotPasswordError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ForgotPassword",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ForgotPasswordResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ForgotPasswordError::from_response(response))),
-                )
-            }
-        })
+        (**self).forgot_password(input)
     }
-
-    /// <p>Gets the header information for the .csv file to be used as input for the user import job.</p>
     fn get_csv_header(
         &self,
         input: GetCSVHeaderRequest,
     ) -> RusotoFuture<GetCSVHeaderResponse, GetCSVHeaderError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.GetCSVHeader",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCSVHeaderResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetCSVHeaderError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_csv_header(input)
     }
-
-    /// <p>Gets the device.</p>
     fn get_device(
         &self,
         input: GetDeviceRequest,
     ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.GetDevice",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeviceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDeviceError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_device(input)
     }
-
-    /// <p>Gets a group.</p> <p>Requires developer credentials.</p>
     fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_group(input)
     }
-
-    /// <p>Gets the specified identity provider.</p>
     fn get_identity_provider_by_identifier(
         &self,
         input: GetIdentityProviderByIdentifierRequest,
     ) -> RusotoFuture<GetIdentityProviderByIdentifierResponse, GetIdentityProviderByIdentifierError>
     {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.GetIdentityProviderByIdentifier",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIdentityProviderByIdentifierResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetIdentityProviderByIdentifierError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_identity_provider_by_identifier(input)
     }
-
-    /// <p>This method takes a user pool ID, and returns the signing certificate.</p>
     fn get_signing_certificate(
         &self,
         input: GetSigningCertificateRequest,
     ) -> RusotoFuture<GetSigningCertificateResponse, GetSigningCertificateError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.GetSigningCertificate",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSigningCertificateResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetSigningCertificateError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_signing_certificate(input)
     }
-
-    /// <p>Gets the UI Customization information for a particular app client's app UI, if there is something set. If nothing is set for the particular client, but there is an existing pool level customization (app <code>clientId</code> will be <code>ALL</code>), then that is returned. If nothing is present, then an empty shape is returned.</p>
     fn get_ui_customization(
         &self,
         input: GetUICustomizationRequest,
     ) -> RusotoFuture<GetUICustomizationResponse, GetUICustomizationError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.GetUICustomization",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetUICustomizationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUICustomizationError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_ui_customization(input)
     }
-
-    /// <p>Gets the user attributes and metadata for a user.</p>
     fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_user(input)
     }
-
-    /// <p>Gets the user attribute verification code for the specified attribute name.</p>
     fn get_user_attribute_verification_code(
         &self,
         input: GetUserAttributeVerificationCodeRequest,
     ) -> RusotoFuture<GetUserAttributeVerificationCodeResponse, GetUserAttributeVerificationCodeError>
     {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetUserAttributeVerificationCodeResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetUserAttributeVerificationCodeError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_user_attribute_verification_code(input)
     }
-
-    /// <p>Gets the user pool multi-factor authentication (MFA) configuration.</p>
     fn get_user_pool_mfa_config(
         &self,
         input: GetUserPoolMfaConfigRequest,
     ) -> RusotoFuture<GetUserPoolMfaConfigResponse, GetUserPoolMfaConfigError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.GetUserPoolMfaConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetUserPoolMfaConfigResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetUserPoolMfaConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_user_pool_mfa_config(input)
     }
-
-    /// <p>Signs out users from all devices.</p>
     fn global_sign_out(
         &self,
         input: GlobalSignOutRequest,
     ) -> RusotoFuture<GlobalSignOutResponse, GlobalSignOutError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.GlobalSignOut",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GlobalSignOutResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GlobalSignOutError::from_response(response))),
-                )
-            }
-        })
+        (**self).global_sign_out(input)
     }
-
-    /// <p>Initiates the authentication flow.</p>
     fn initiate_auth(
         &self,
         input: InitiateAuthRequest,
     ) -> RusotoFuture<InitiateAuthResponse, InitiateAuthError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.InitiateAuth",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InitiateAuthResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(InitiateAuthError::from_response(response))),
-                )
-            }
-        })
+        (**self).initiate_auth(input)
     }
-
-    /// <p>Lists the devices.</p>
     fn list_devices(
         &self,
         input: ListDevicesRequest,
     ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ListDevices",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDevicesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDevicesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_devices(input)
     }
-
-    /// <p>Lists the groups associated with a user pool.</p> <p>Requires developer credentials.</p>
     fn list_groups(
         &self,
         input: ListGroupsRequest,
     ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ListGroups",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGroupsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGroupsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_groups(input)
     }
-
-    /// <p>Lists information about all identity providers for a user pool.</p>
     fn list_identity_providers(
         &self,
         input: ListIdentityProvidersRequest,
     ) -> RusotoFuture<ListIdentityProvidersResponse, ListIdentityProvidersError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ListIdentityProviders",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListIdentityProvidersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListIdentityProvidersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_identity_providers(input)
     }
-
-    /// <p>Lists the resource servers for a user pool.</p>
     fn list_resource_servers(
         &self,
         input: ListResourceServersRequest,
     ) -> RusotoFuture<ListResourceServersResponse, ListResourceServersError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ListResourceServers",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourceServersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListResourceServersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_resource_servers(input)
     }
-
-    /// <p>Lists the tags that are assigned to an Amazon Cognito user pool.</p> <p>A tag is a label that you can apply to user pools to categorize and manage them in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>You can use this action up to 10 times per second, per account.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ListTagsForResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Lists the user import jobs.</p>
     fn list_user_import_jobs(
         &self,
         input: ListUserImportJobsRequest,
     ) -> RusotoFuture<ListUserImportJobsResponse, ListUserImportJobsError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ListUserImportJobs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUserImportJobsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListUserImportJobsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_user_import_jobs(input)
     }
-
-    /// <p>Lists the clients that have been created for the specified user pool.</p>
     fn list_user_pool_clients(
         &self,
         input: ListUserPoolClientsRequest,
     ) -> RusotoFuture<ListUserPoolClientsResponse, ListUserPoolClientsError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ListUserPoolClients",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUserPoolClientsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListUserPoolClientsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_user_pool_clients(input)
     }
-
-    /// <p>Lists the user pools associated with an AWS account.</p>
     fn list_user_pools(
         &self,
         input: ListUserPoolsRequest,
     ) -> RusotoFuture<ListUserPoolsResponse, ListUserPoolsError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ListUserPools",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUserPoolsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListUserPoolsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_user_pools(input)
     }
-
-    /// <p>Lists the users in the Amazon Cognito user pool.</p>
     fn list_users(
         &self,
         input: ListUsersRequest,
     ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ListUsers",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUsersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListUsersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_users(input)
     }
-
-    /// <p>Lists the users in the specified group.</p> <p>Requires developer credentials.</p>
     fn list_users_in_group(
         &self,
         input: ListUsersInGroupRequest,
     ) -> RusotoFuture<ListUsersInGroupResponse, ListUsersInGroupError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ListUsersInGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUsersInGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListUsersInGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_users_in_group(input)
     }
-
-    /// <p>Resends the confirmation (for confirmation of registration) to a specific user in the user pool.</p>
     fn resend_confirmation_code(
         &self,
         input: ResendConfirmationCodeRequest,
     ) -> RusotoFuture<ResendConfirmationCodeResponse, ResendConfirmationCodeError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ResendConfirmationCode",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResendConfirmationCodeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ResendConfirmationCodeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).resend_confirmation_code(input)
     }
-
-    /// <p>Responds to the authentication challenge.</p>
     fn respond_to_auth_challenge(
         &self,
         input: RespondToAuthChallengeRequest,
     ) -> RusotoFuture<RespondToAuthChallengeResponse, RespondToAuthChallengeError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RespondToAuthChallengeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RespondToAuthChallengeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).respond_to_auth_challenge(input)
     }
-
-    /// <p>Configures actions on detected risks. To delete the risk configuration for <code>UserPoolId</code> or <code>ClientId</code>, pass null values for all four configuration types.</p> <p>To enable Amazon Cognito advanced security features, update the user pool to include the <code>UserPoolAddOns</code> key<code>AdvancedSecurityMode</code>.</p> <p>See .</p>
     fn set_risk_configuration(
         &self,
         input: SetRiskConfigurationRequest,
     ) -> RusotoFuture<SetRiskConfigurationResponse, SetRiskConfigurationError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.SetRiskConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SetRiskConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SetRiskConfigurationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).set_risk_configuration(input)
     }
-
-    /// <p><p>Sets the UI customization information for a user pool&#39;s built-in app UI.</p> <p>You can specify app UI customization settings for a single client (with a specific <code>clientId</code>) or for all clients (by setting the <code>clientId</code> to <code>ALL</code>). If you specify <code>ALL</code>, the default configuration will be used for every client that has no UI customization set previously. If you specify UI customization settings for a particular client, it will no longer fall back to the <code>ALL</code> configuration. </p> <note> <p>To use this API, your user pool must have a domain associated with it. Otherwise, there is no place to host the app&#39;s pages, and the service will throw an error.</p> </note></p>
     fn set_ui_customization(
         &self,
         input: SetUICustomizationRequest,
     ) -> RusotoFuture<SetUICustomizationResponse, SetUICustomizationError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.SetUICustomization",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SetUICustomizationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetUICustomizationError::from_response(response))),
-                )
-            }
-        })
+        (**self).set_ui_customization(input)
     }
-
-    /// <p>Set the user's multi-factor authentication (MFA) method preference.</p>
     fn set_user_mfa_preference(
         &self,
         input: SetUserMFAPreferenceRequest,
     ) -> RusotoFuture<SetUserMFAPreferenceResponse, SetUserMFAPreferenceError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.SetUserMFAPreference",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SetUserMFAPreferenceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SetUserMFAPreferenceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).set_user_mfa_preference(input)
     }
-
-    /// <p>Set the user pool MFA configuration.</p>
     fn set_user_pool_mfa_config(
         &self,
         input: SetUserPoolMfaConfigRequest,
     ) -> RusotoFuture<SetUserPoolMfaConfigResponse, SetUserPoolMfaConfigError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.SetUserPoolMfaConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SetUserPoolMfaConfigResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SetUserPoolMfaConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).set_user_pool_mfa_config(input)
     }
-
-    /// <p>Sets the user settings like multi-factor authentication (MFA). If MFA is to be removed for a particular attribute pass the attribute with code delivery as null. If null list is passed, all MFA options are removed.</p>
     fn set_user_settings(
         &self,
         input: SetUserSettingsRequest,
     ) -> RusotoFuture<SetUserSettingsResponse, SetUserSettingsError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.SetUserSettings",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SetUserSettingsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetUserSettingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).set_user_settings(input)
     }
-
-    /// <p>Registers the user in the specified user pool and creates a user name, password, and user attributes.</p>
     fn sign_up(&self, input: SignUpRequest) -> RusotoFuture<SignUpResponse, SignUpError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<SignUpResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SignUpError::from_response(response))),
-                )
-            }
-        })
+        (**self).sign_up(input)
     }
-
-    /// <p>Starts the user import.</p>
     fn start_user_import_job(
         &self,
         input: StartUserImportJobRequest,
     ) -> RusotoFuture<StartUserImportJobResponse, StartUserImportJobError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.StartUserImportJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartUserImportJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartUserImportJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_user_import_job(input)
     }
-
-    /// <p>Stops the user import job.</p>
     fn stop_user_import_job(
         &self,
         input: StopUserImportJobRequest,
     ) -> RusotoFuture<StopUserImportJobResponse, StopUserImportJobError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.StopUserImportJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopUserImportJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopUserImportJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).stop_user_import_job(input)
     }
-
-    /// <p>Assigns a set of tags to an Amazon Cognito user pool. A tag is a label that you can use to categorize and manage user pools in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a key and value, both of which you define. A key is a general category for more specific values. For example, if you have two versions of a user pool, one for testing and another for production, you might assign an <code>Environment</code> tag key to both user pools. The value of this key might be <code>Test</code> for one user pool and <code>Production</code> for the other.</p> <p>Tags are useful for cost tracking and access control. You can activate your tags so that they appear on the Billing and Cost Management console, where you can track the costs associated with your user pools. In an IAM policy, you can constrain permissions for user pools based on specific tags or tag values.</p> <p>You can use this action up to 5 times per second, per account. A user pool can have as many as 50 tags.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.TagResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).tag_resource(input)
     }
-
-    /// <p>Removes the specified tags from an Amazon Cognito user pool. You can use this action up to 5 times per second, per account</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.UntagResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).untag_resource(input)
     }
-
-    /// <p>Provides the feedback for an authentication event whether it was from a valid user or not. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
     fn update_auth_event_feedback(
         &self,
         input: UpdateAuthEventFeedbackRequest,
     ) -> RusotoFuture<UpdateAuthEventFeedbackResponse, UpdateAuthEventFeedbackError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.UpdateAuthEventFeedback",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAuthEventFeedbackResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateAuthEventFeedbackError::from_response(response))
-                }))
+        (**self).update_auth_event_feedback(input)
+    }
+    fn update_device_status(
+        &self,
+        input: UpdateDeviceStatusRequest,
+    ) -> RusotoFuture<UpdateDeviceStatusResponse, UpdateDeviceStatusError> {
+        (**self).update_device_status(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_identity_provider(
+        &self,
+        input: UpdateIdentityProviderRequest,
+    ) -> RusotoFuture<UpdateIdentityProviderResponse, UpdateIdentityProviderError> {
+        (**self).update_identity_provider(input)
+    }
+    fn update_resource_server(
+        &self,
+        input: UpdateResourceServerRequest,
+    ) -> RusotoFuture<UpdateResourceServerResponse, UpdateResourceServerError> {
+        (**self).update_resource_server(input)
+    }
+    fn update_user_attributes(
+        &self,
+        input: UpdateUserAttributesRequest,
+    ) -> RusotoFuture<UpdateUserAttributesResponse, UpdateUserAttributesError> {
+        (**self).update_user_attributes(input)
+    }
+    fn update_user_pool(
+        &self,
+        input: UpdateUserPoolRequest,
+    ) -> RusotoFuture<UpdateUserPoolResponse, UpdateUserPoolError> {
+        (**self).update_user_pool(input)
+    }
+    fn update_user_pool_client(
+        &self,
+        input: UpdateUserPoolClientRequest,
+    ) -> RusotoFuture<UpdateUserPoolClientResponse, UpdateUserPoolClientError> {
+        (**self).update_user_pool_client(input)
+    }
+    fn update_user_pool_domain(
+        &self,
+        input: UpdateUserPoolDomainRequest,
+    ) -> RusotoFuture<UpdateUserPoolDomainResponse, UpdateUserPoolDomainError> {
+        (**self).update_user_pool_domain(input)
+    }
+    fn verify_software_token(
+        &self,
+        input: VerifySoftwareTokenRequest,
+    ) -> RusotoFuture<VerifySoftwareTokenResponse, VerifySoftwareTokenError> {
+        (**self).verify_software_token(input)
+    }
+    fn verify_user_attribute(
+        &self,
+        input: VerifyUserAttributeRequest,
+    ) -> RusotoFuture<VerifyUserAttributeResponse, VerifyUserAttributeError> {
+        (**self).verify_user_attribute(input)
+    }
+}
+impl<T: CognitoIdentityProvider + ?Sized> CognitoIdentityProvider for Box<T> {
+    fn add_custom_attributes(
+        &self,
+        input: AddCustomAttributesRequest,
+    ) -> RusotoFuture<AddCustomAttributesResponse, AddCustomAttributesError> {
+        (**self).add_custom_attributes(input)
+    }
+    fn admin_add_user_to_group(
+        &self,
+        input: AdminAddUserToGroupRequest,
+    ) -> RusotoFuture<(), AdminAddUserToGroupError> {
+        (**self).admin_add_user_to_group(input)
+    }
+    fn admin_confirm_sign_up(
+        &self,
+        input: AdminConfirmSignUpRequest,
+    ) -> RusotoFuture<AdminConfirmSignUpResponse, AdminConfirmSignUpError> {
+        (**self).admin_confirm_sign_up(input)
+    }
+    fn admin_create_user(
+        &self,
+        input: AdminCreateUserRequest,
+    ) -> RusotoFuture<AdminCreateUserResponse, AdminCreateUserError> {
+        (**self).admin_create_user(input)
+    }
+    fn admin_delete_user(
+        &self,
+        input: AdminDeleteUserRequest,
+    ) -> RusotoFuture<(), AdminDeleteUserError> {
+        (**self).admin_delete_user(input)
+    }
+    fn admin_delete_user_attributes(
+        &self,
+        input: AdminDeleteUserAttributesRequest,
+    ) -> RusotoFuture<AdminDeleteUserAttributesResponse, AdminDeleteUserAttributesError> {
+        (**self).admin_delete_user_attributes(input)
+    }
+    fn admin_disable_provider_for_user(
+        &self,
+        input: AdminDisableProviderForUserRequest,
+    ) -> RusotoFuture<AdminDisableProviderForUserResponse, AdminDisableProviderForUserError> {
+        (**self).admin_disable_provider_for_user(input)
+    }
+    fn admin_disable_user(
+        &self,
+        input: AdminDisableUserRequest,
+    ) -> RusotoFuture<AdminDisableUserResponse, AdminDisableUserError> {
+        (**self).admin_disable_user(input)
+    }
+    fn admin_enable_user(
+        &self,
+        input: AdminEnableUserRequest,
+    ) -> RusotoFuture<AdminEnableUserResponse, AdminEnableUserError> {
+        (**self).admin_enable_user(input)
+    }
+    fn admin_forget_device(
+        &self,
+        input: AdminForgetDeviceRequest,
+    ) -> RusotoFuture<(), AdminForgetDeviceError> {
+        (**self).admin_forget_device(input)
+    }
+    fn admin_get_device(
+        &self,
+        input: AdminGetDeviceRequest,
+    ) -> RusotoFuture<AdminGetDeviceResponse, AdminGetDeviceError> {
+        (**self).admin_get_device(input)
+    }
+    fn admin_get_user(
+        &self,
+        input: AdminGetUserRequest,
+    ) -> RusotoFuture<AdminGetUserResponse, AdminGetUserError> {
+        (**self).admin_get_user(input)
+    }
+    fn admin_initiate_auth(
+        &self,
+        input: AdminInitiateAuthRequest,
+    ) -> RusotoFuture<AdminInitiateAuthResponse, AdminInitiateAuthError> {
+        (**self).admin_initiate_auth(input)
+    }
+    fn admin_link_provider_for_user(
+        &self,
+        input: AdminLinkProviderForUserRequest,
+    ) -> RusotoFuture<AdminLinkProviderForUserResponse, AdminLinkProviderForUserError> {
+        (**self).admin_link_provider_for_user(input)
+    }
+    fn admin_list_devices(
+        &self,
+        input: AdminListDevicesRequest,
+    ) -> RusotoFuture<AdminListDevicesResponse, AdminListDevicesError> {
+        (**self).admin_list_devices(input)
+    }
+    fn admin_list_groups_for_user(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> RusotoFuture<AdminListGroupsForUserResponse, AdminListGroupsForUserError> {
+        (**self).admin_list_groups_for_user(input)
+    }
+    fn admin_list_user_auth_events(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> RusotoFuture<AdminListUserAuthEventsResponse, AdminListUserAuthEventsError> {
+        (**self).admin_list_user_auth_events(input)
+    }
+    fn admin_remove_user_from_group(
+        &self,
+        input: AdminRemoveUserFromGroupRequest,
+    ) -> RusotoFuture<(), AdminRemoveUserFromGroupError> {
+        (**self).admin_remove_user_from_group(input)
+    }
+    fn admin_reset_user_password(
+        &self,
+        input: AdminResetUserPasswordRequest,
+    ) -> RusotoFuture<AdminResetUserPasswordResponse, AdminResetUserPasswordError> {
+        (**self).admin_reset_user_password(input)
+    }
+    fn admin_respond_to_auth_challenge(
+        &self,
+        input: AdminRespondToAuthChallengeRequest,
+    ) -> RusotoFuture<AdminRespondToAuthChallengeResponse, AdminRespondToAuthChallengeError> {
+        (**self).admin_respond_to_auth_challenge(input)
+    }
+    fn admin_set_user_mfa_preference(
+        &self,
+        input: AdminSetUserMFAPreferenceRequest,
+    ) -> RusotoFuture<AdminSetUserMFAPreferenceResponse, AdminSetUserMFAPreferenceError> {
+        (**self).admin_set_user_mfa_preference(input)
+    }
+    fn admin_set_user_password(
+        &self,
+        input: AdminSetUserPasswordRequest,
+    ) -> RusotoFuture<AdminSetUserPasswordResponse, AdminSetUserPasswordError> {
+        (**self).admin_set_user_password(input)
+    }
+    fn admin_set_user_settings(
+        &self,
+        input: AdminSetUserSettingsRequest,
+    ) -> RusotoFuture<AdminSetUserSettingsResponse, AdminSetUserSettingsError> {
+        (**self).admin_set_user_settings(input)
+    }
+    fn admin_update_auth_event_feedback(
+        &self,
+        input: AdminUpdateAuthEventFeedbackRequest,
+    ) -> RusotoFuture<AdminUpdateAuthEventFeedbackResponse, AdminUpdateAuthEventFeedbackError> {
+        (**self).admin_update_auth_event_feedback(input)
+    }
+    fn admin_update_device_status(
+        &self,
+        input: AdminUpdateDeviceStatusRequest,
+    ) -> RusotoFuture<AdminUpdateDeviceStatusResponse, AdminUpdateDeviceStatusError> {
+        (**self).admin_update_device_status(input)
+    }
+    fn admin_update_user_attributes(
+        &self,
+        input: AdminUpdateUserAttributesRequest,
+    ) -> RusotoFuture<AdminUpdateUserAttributesResponse, AdminUpdateUserAttributesError> {
+        (**self).admin_update_user_attributes(input)
+    }
+    fn admin_user_global_sign_out(
+        &self,
+        input: AdminUserGlobalSignOutRequest,
+    ) -> RusotoFuture<AdminUserGlobalSignOutResponse, AdminUserGlobalSignOutError> {
+        (**self).admin_user_global_sign_out(input)
+    }
+    fn associate_software_token(
+        &self,
+        input: AssociateSoftwareTokenRequest,
+    ) -> RusotoFuture<AssociateSoftwareTokenResponse, AssociateSoftwareTokenError> {
+        (**self).associate_software_token(input)
+    }
+    fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> RusotoFuture<ChangePasswordResponse, ChangePasswordError> {
+        (**self).change_password(input)
+    }
+    fn confirm_device(
+        &self,
+        input: ConfirmDeviceRequest,
+    ) -> RusotoFuture<ConfirmDeviceResponse, ConfirmDeviceError> {
+        (**self).confirm_device(input)
+    }
+    fn confirm_forgot_password(
+        &self,
+        input: ConfirmForgotPasswordRequest,
+    ) -> RusotoFuture<ConfirmForgotPasswordResponse, ConfirmForgotPasswordError> {
+        (**self).confirm_forgot_password(input)
+    }
+    fn confirm_sign_up(
+        &self,
+        input: ConfirmSignUpRequest,
+    ) -> RusotoFuture<ConfirmSignUpResponse, ConfirmSignUpError> {
+        (**self).confirm_sign_up(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_identity_provider(
+        &self,
+        input: CreateIdentityProviderRequest,
+    ) -> RusotoFuture<CreateIdentityProviderResponse, CreateIdentityProviderError> {
+        (**self).create_identity_provider(input)
+    }
+    fn create_resource_server(
+        &self,
+        input: CreateResourceServerRequest,
+    ) -> RusotoFuture<CreateResourceServerResponse, CreateResourceServerError> {
+        (**self).create_resource_server(input)
+    }
+    fn create_user_import_job(
+        &self,
+        input: CreateUserImportJobRequest,
+    ) -> RusotoFuture<CreateUserImportJobResponse, CreateUserImportJobError> {
+        (**self).create_user_import_job(input)
+    }
+    fn create_user_pool(
+        &self,
+        input: CreateUserPoolRequest,
+    ) -> RusotoFuture<CreateUserPoolResponse, CreateUserPoolError> {
+        (**self).create_user_pool(input)
+    }
+    fn create_user_pool_client(
+        &self,
+        input: CreateUserPoolClientRequest,
+    ) -> RusotoFuture<CreateUserPoolClientResponse, CreateUserPoolClientError> {
+        (**self).create_user_pool_client(input)
+    }
+    fn create_user_pool_domain(
+        &self,
+        input: CreateUserPoolDomainRequest,
+    ) -> RusotoFuture<CreateUserPoolDomainResponse, CreateUserPoolDomainError> {
+        (**self).create_user_pool_domain(input)
+    }
+    fn delete_group(&self, input: DeleteGroupRequest) -> RusotoFuture<(), DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_identity_provider(
+        &self,
+        input: DeleteIdentityProviderRequest,
+    ) -> RusotoFuture<(), DeleteIdentityProviderError> {
+        (**self).delete_identity_provider(input)
+    }
+    fn delete_resource_server(
+        &self,
+        input: DeleteResourceServerRequest,
+    ) -> RusotoFuture<(), DeleteResourceServerError> {
+        (**self).delete_resource_server(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn delete_user_attributes(
+        &self,
+        input: DeleteUserAttributesRequest,
+    ) -> RusotoFuture<DeleteUserAttributesResponse, DeleteUserAttributesError> {
+        (**self).delete_user_attributes(input)
+    }
+    fn delete_user_pool(
+        &self,
+        input: DeleteUserPoolRequest,
+    ) -> RusotoFuture<(), DeleteUserPoolError> {
+        (**self).delete_user_pool(input)
+    }
+    fn delete_user_pool_client(
+        &self,
+        input: DeleteUserPoolClientRequest,
+    ) -> RusotoFuture<(), DeleteUserPoolClientError> {
+        (**self).delete_user_pool_client(input)
+    }
+    fn delete_user_pool_domain(
+        &self,
+        input: DeleteUserPoolDomainRequest,
+    ) -> RusotoFuture<DeleteUserPoolDomainResponse, DeleteUserPoolDomainError> {
+        (**self).delete_user_pool_domain(input)
+    }
+    fn describe_identity_provider(
+        &self,
+        input: DescribeIdentityProviderRequest,
+    ) -> RusotoFuture<DescribeIdentityProviderResponse, DescribeIdentityProviderError> {
+        (**self).describe_identity_provider(input)
+    }
+    fn describe_resource_server(
+        &self,
+        input: DescribeResourceServerRequest,
+    ) -> RusotoFuture<DescribeResourceServerResponse, DescribeResourceServerError> {
+        (**self).describe_resource_server(input)
+    }
+    fn describe_risk_configuration(
+        &self,
+        input: DescribeRiskConfigurationRequest,
+    ) -> RusotoFuture<DescribeRiskConfigurationResponse, DescribeRiskConfigurationError> {
+        (**self).describe_risk_configuration(input)
+    }
+    fn describe_user_import_job(
+        &self,
+        input: DescribeUserImportJobRequest,
+    ) -> RusotoFuture<DescribeUserImportJobResponse, DescribeUserImportJobError> {
+        (**self).describe_user_import_job(input)
+    }
+    fn describe_user_pool(
+        &self,
+        input: DescribeUserPoolRequest,
+    ) -> RusotoFuture<DescribeUserPoolResponse, DescribeUserPoolError> {
+        (**self).describe_user_pool(input)
+    }
+    fn describe_user_pool_client(
+        &self,
+        input: DescribeUserPoolClientRequest,
+    ) -> RusotoFuture<DescribeUserPoolClientResponse, DescribeUserPoolClientError> {
+        (**self).describe_user_pool_client(input)
+    }
+    fn describe_user_pool_domain(
+        &self,
+        input: DescribeUserPoolDomainRequest,
+    ) -> RusotoFuture<DescribeUserPoolDomainResponse, DescribeUserPoolDomainError> {
+        (**self).describe_user_pool_domain(input)
+    }
+    fn forget_device(&self, input: ForgetDeviceRequest) -> RusotoFuture<(), ForgetDeviceError> {
+        (**self).forget_device(input)
+    }
+    fn forgot_password(
+        &self,
+        input: ForgotPasswordRequest,
+    ) -> RusotoFuture<ForgotPasswordResponse, ForgotPasswordError> {
+        (**self).forgot_password(input)
+    }
+    fn get_csv_header(
+        &self,
+        input: GetCSVHeaderRequest,
+    ) -> RusotoFuture<GetCSVHeaderResponse, GetCSVHeaderError> {
+        (**self).get_csv_header(input)
+    }
+    fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
+        (**self).get_device(input)
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_identity_provider_by_identifier(
+        &self,
+        input: GetIdentityProviderByIdentifierRequest,
+    ) -> RusotoFuture<GetIdentityProviderByIdentifierResponse, GetIdentityProviderByIdentifierError>
+    {
+        (**self).get_identity_provider_by_identifier(input)
+    }
+    fn get_signing_certificate(
+        &self,
+        input: GetSigningCertificateRequest,
+    ) -> RusotoFuture<GetSigningCertificateResponse, GetSigningCertificateError> {
+        (**self).get_signing_certificate(input)
+    }
+    fn get_ui_customization(
+        &self,
+        input: GetUICustomizationRequest,
+    ) -> RusotoFuture<GetUICustomizationResponse, GetUICustomizationError> {
+        (**self).get_ui_customization(input)
+    }
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        (**self).get_user(input)
+    }
+    fn get_user_attribute_verification_code(
+        &self,
+        input: GetUserAttributeVerificationCodeRequest,
+    ) -> RusotoFuture<GetUserAttributeVerificationCodeResponse, GetUserAttributeVerificationCodeError>
+    {
+        (**self).get_user_attribute_verification_code(input)
+    }
+    fn get_user_pool_mfa_config(
+        &self,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> RusotoFuture<GetUserPoolMfaConfigResponse, GetUserPoolMfaConfigError> {
+        (**self).get_user_pool_mfa_config(input)
+    }
+    fn global_sign_out(
+        &self,
+        input: GlobalSignOutRequest,
+    ) -> RusotoFuture<GlobalSignOutResponse, GlobalSignOutError> {
+        (**self).global_sign_out(input)
+    }
+    fn initiate_auth(
+        &self,
+        input: InitiateAuthRequest,
+    ) -> RusotoFuture<InitiateAuthResponse, InitiateAuthError> {
+        (**self).initiate_auth(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn list_identity_providers(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> RusotoFuture<ListIdentityProvidersResponse, ListIdentityProvidersError> {
+        (**self).list_identity_providers(input)
+    }
+    fn list_resource_servers(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> RusotoFuture<ListResourceServersResponse, ListResourceServersError> {
+        (**self).list_resource_servers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_user_import_jobs(
+        &self,
+        input: ListUserImportJobsRequest,
+    ) -> RusotoFuture<ListUserImportJobsResponse, ListUserImportJobsError> {
+        (**self).list_user_import_jobs(input)
+    }
+    fn list_user_pool_clients(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> RusotoFuture<ListUserPoolClientsResponse, ListUserPoolClientsError> {
+        (**self).list_user_pool_clients(input)
+    }
+    fn list_user_pools(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> RusotoFuture<ListUserPoolsResponse, ListUserPoolsError> {
+        (**self).list_user_pools(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn list_users_in_group(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> RusotoFuture<ListUsersInGroupResponse, ListUsersInGroupError> {
+        (**self).list_users_in_group(input)
+    }
+    fn resend_confirmation_code(
+        &self,
+        input: ResendConfirmationCodeRequest,
+    ) -> RusotoFuture<ResendConfirmationCodeResponse, ResendConfirmationCodeError> {
+        (**self).resend_confirmation_code(input)
+    }
+    fn respond_to_auth_challenge(
+        &self,
+        input: RespondToAuthChallengeRequest,
+    ) -> RusotoFuture<RespondToAuthChallengeResponse, RespondToAuthChallengeError> {
+        (**self).respond_to_auth_challenge(input)
+    }
+    fn set_risk_configuration(
+        &self,
+        input: SetRiskConfigurationRequest,
+    ) -> RusotoFuture<SetRiskConfigurationResponse, SetRiskConfigurationError> {
+        (**self).set_risk_configuration(input)
+    }
+    fn set_ui_customization(
+        &self,
+        input: SetUICustomizationRequest,
+    ) -> RusotoFuture<SetUICustomizationResponse, SetUICustomizationError> {
+        (**self).set_ui_customization(input)
+    }
+    fn set_user_mfa_preference(
+        &self,
+        input: SetUserMFAPreferenceRequest,
+    ) -> RusotoFuture<SetUserMFAPreferenceResponse, SetUserMFAPreferenceError> {
+        (**self).set_user_mfa_preference(input)
+    }
+    fn set_user_pool_mfa_config(
+        &self,
+        input: SetUserPoolMfaConfigRequest,
+    ) -> RusotoFuture<SetUserPoolMfaConfigResponse, SetUserPoolMfaConfigError> {
+        (**self).set_user_pool_mfa_config(input)
+    }
+    fn set_user_settings(
+        &self,
+        input: SetUserSettingsRequest,
+    ) -> RusotoFuture<SetUserSettingsResponse, SetUserSettingsError> {
+        (**self).set_user_settings(input)
+    }
+    fn sign_up(&self, input: SignUpRequest) -> RusotoFuture<SignUpResponse, SignUpError> {
+        (**self).sign_up(input)
+    }
+    fn start_user_import_job(
+        &self,
+        input: StartUserImportJobRequest,
+    ) -> RusotoFuture<StartUserImportJobResponse, StartUserImportJobError> {
+        (**self).start_user_import_job(input)
+    }
+    fn stop_user_import_job(
+        &self,
+        input: StopUserImportJobRequest,
+    ) -> RusotoFuture<StopUserImportJobResponse, StopUserImportJobError> {
+        (**self).stop_user_import_job(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_auth_event_feedback(
+        &self,
+        input: UpdateAuthEventFeedbackRequest,
+    ) -> RusotoFuture<UpdateAuthEventFeedbackResponse, UpdateAuthEventFeedbackError> {
+        (**self).update_auth_event_feedback(input)
+    }
+    fn update_device_status(
+        &self,
+        input: UpdateDeviceStatusRequest,
+    ) -> RusotoFuture<UpdateDeviceStatusResponse, UpdateDeviceStatusError> {
+        (**self).update_device_status(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_identity_provider(
+        &self,
+        input: UpdateIdentityProviderRequest,
+    ) -> RusotoFuture<UpdateIdentityProviderResponse, UpdateIdentityProviderError> {
+        (**self).update_identity_provider(input)
+    }
+    fn update_resource_server(
+        &self,
+        input: UpdateResourceServerRequest,
+    ) -> RusotoFuture<UpdateResourceServerResponse, UpdateResourceServerError> {
+        (**self).update_resource_server(input)
+    }
+    fn update_user_attributes(
+        &self,
+        input: UpdateUserAttributesRequest,
+    ) -> RusotoFuture<UpdateUserAttributesResponse, UpdateUserAttributesError> {
+        (**self).update_user_attributes(input)
+    }
+    fn update_user_pool(
+        &self,
+        input: UpdateUserPoolRequest,
+    ) -> RusotoFuture<UpdateUserPoolResponse, UpdateUserPoolError> {
+        (**self).update_user_pool(input)
+    }
+    fn update_user_pool_client(
+        &self,
+        input: UpdateUserPoolClientRequest,
+    ) -> RusotoFuture<UpdateUserPoolClientResponse, UpdateUserPoolClientError> {
+        (**self).update_user_pool_client(input)
+    }
+    fn update_user_pool_domain(
+        &self,
+        input: UpdateUserPoolDomainRequest,
+    ) -> RusotoFuture<UpdateUserPoolDomainResponse, UpdateUserPoolDomainError> {
+        (**self).update_user_pool_domain(input)
+    }
+    fn verify_software_token(
+        &self,
+        input: VerifySoftwareTokenRequest,
+    ) -> RusotoFuture<VerifySoftwareTokenResponse, VerifySoftwareTokenError> {
+        (**self).verify_software_token(input)
+    }
+    fn verify_user_attribute(
+        &self,
+        input: VerifyUserAttributeRequest,
+    ) -> RusotoFuture<VerifyUserAttributeResponse, VerifyUserAttributeError> {
+        (**self).verify_user_attribute(input)
+    }
+}
+impl<T: CognitoIdentityProvider + ?Sized> CognitoIdentityProvider for ::std::sync::Arc<T> {
+    fn add_custom_attributes(
+        &self,
+        input: AddCustomAttributesRequest,
+    ) -> RusotoFuture<AddCustomAttributesResponse, AddCustomAttributesError> {
+        (**self).add_custom_attributes(input)
+    }
+    fn admin_add_user_to_group(
+        &self,
+        input: AdminAddUserToGroupRequest,
+    ) -> RusotoFuture<(), AdminAddUserToGroupError> {
+        (**self).admin_add_user_to_group(input)
+    }
+    fn admin_confirm_sign_up(
+        &self,
+        input: AdminConfirmSignUpRequest,
+    ) -> RusotoFuture<AdminConfirmSignUpResponse, AdminConfirmSignUpError> {
+        (**self).admin_confirm_sign_up(input)
+    }
+    fn admin_create_user(
+        &self,
+        input: AdminCreateUserRequest,
+    ) -> RusotoFuture<AdminCreateUserResponse, AdminCreateUserError> {
+        (**self).admin_create_user(input)
+    }
+    fn admin_delete_user(
+        &self,
+        input: AdminDeleteUserRequest,
+    ) -> RusotoFuture<(), AdminDeleteUserError> {
+        (**self).admin_delete_user(input)
+    }
+    fn admin_delete_user_attributes(
+        &self,
+        input: AdminDeleteUserAttributesRequest,
+    ) -> RusotoFuture<AdminDeleteUserAttributesResponse, AdminDeleteUserAttributesError> {
+        (**self).admin_delete_user_attributes(input)
+    }
+    fn admin_disable_provider_for_user(
+        &self,
+        input: AdminDisableProviderForUserRequest,
+    ) -> RusotoFuture<AdminDisableProviderForUserResponse, AdminDisableProviderForUserError> {
+        (**self).admin_disable_provider_for_user(input)
+    }
+    fn admin_disable_user(
+        &self,
+        input: AdminDisableUserRequest,
+    ) -> RusotoFuture<AdminDisableUserResponse, AdminDisableUserError> {
+        (**self).admin_disable_user(input)
+    }
+    fn admin_enable_user(
+        &self,
+        input: AdminEnableUserRequest,
+    ) -> RusotoFuture<AdminEnableUserResponse, AdminEnableUserError> {
+        (**self).admin_enable_user(input)
+    }
+    fn admin_forget_device(
+        &self,
+        input: AdminForgetDeviceRequest,
+    ) -> RusotoFuture<(), AdminForgetDeviceError> {
+        (**self).admin_forget_device(input)
+    }
+    fn admin_get_device(
+        &self,
+        input: AdminGetDeviceRequest,
+    ) -> RusotoFuture<AdminGetDeviceResponse, AdminGetDeviceError> {
+        (**self).admin_get_device(input)
+    }
+    fn admin_get_user(
+        &self,
+        input: AdminGetUserRequest,
+    ) -> RusotoFuture<AdminGetUserResponse, AdminGetUserError> {
+        (**self).admin_get_user(input)
+    }
+    fn admin_initiate_auth(
+        &self,
+        input: AdminInitiateAuthRequest,
+    ) -> RusotoFuture<AdminInitiateAuthResponse, AdminInitiateAuthError> {
+        (**self).admin_initiate_auth(input)
+    }
+    fn admin_link_provider_for_user(
+        &self,
+        input: AdminLinkProviderForUserRequest,
+    ) -> RusotoFuture<AdminLinkProviderForUserResponse, AdminLinkProviderForUserError> {
+        (**self).admin_link_provider_for_user(input)
+    }
+    fn admin_list_devices(
+        &self,
+        input: AdminListDevicesRequest,
+    ) -> RusotoFuture<AdminListDevicesResponse, AdminListDevicesError> {
+        (**self).admin_list_devices(input)
+    }
+    fn admin_list_groups_for_user(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> RusotoFuture<AdminListGroupsForUserResponse, AdminListGroupsForUserError> {
+        (**self).admin_list_groups_for_user(input)
+    }
+    fn admin_list_user_auth_events(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> RusotoFuture<AdminListUserAuthEventsResponse, AdminListUserAuthEventsError> {
+        (**self).admin_list_user_auth_events(input)
+    }
+    fn admin_remove_user_from_group(
+        &self,
+        input: AdminRemoveUserFromGroupRequest,
+    ) -> RusotoFuture<(), AdminRemoveUserFromGroupError> {
+        (**self).admin_remove_user_from_group(input)
+    }
+    fn admin_reset_user_password(
+        &self,
+        input: AdminResetUserPasswordRequest,
+    ) -> RusotoFuture<AdminResetUserPasswordResponse, AdminResetUserPasswordError> {
+        (**self).admin_reset_user_password(input)
+    }
+    fn admin_respond_to_auth_challenge(
+        &self,
+        input: AdminRespondToAuthChallengeRequest,
+    ) -> RusotoFuture<AdminRespondToAuthChallengeResponse, AdminRespondToAuthChallengeError> {
+        (**self).admin_respond_to_auth_challenge(input)
+    }
+    fn admin_set_user_mfa_preference(
+        &self,
+        input: AdminSetUserMFAPreferenceRequest,
+    ) -> RusotoFuture<AdminSetUserMFAPreferenceResponse, AdminSetUserMFAPreferenceError> {
+        (**self).admin_set_user_mfa_preference(input)
+    }
+    fn admin_set_user_password(
+        &self,
+        input: AdminSetUserPasswordRequest,
+    ) -> RusotoFuture<AdminSetUserPasswordResponse, AdminSetUserPasswordError> {
+        (**self).admin_set_user_password(input)
+    }
+    fn admin_set_user_settings(
+        &self,
+        input: AdminSetUserSettingsRequest,
+    ) -> RusotoFuture<AdminSetUserSettingsResponse, AdminSetUserSettingsError> {
+        (**self).admin_set_user_settings(input)
+    }
+    fn admin_update_auth_event_feedback(
+        &self,
+        input: AdminUpdateAuthEventFeedbackRequest,
+    ) -> RusotoFuture<AdminUpdateAuthEventFeedbackResponse, AdminUpdateAuthEventFeedbackError> {
+        (**self).admin_update_auth_event_feedback(input)
+    }
+    fn admin_update_device_status(
+        &self,
+        input: AdminUpdateDeviceStatusRequest,
+    ) -> RusotoFuture<AdminUpdateDeviceStatusResponse, AdminUpdateDeviceStatusError> {
+        (**self).admin_update_device_status(input)
+    }
+    fn admin_update_user_attributes(
+        &self,
+        input: AdminUpdateUserAttributesRequest,
+    ) -> RusotoFuture<AdminUpdateUserAttributesResponse, AdminUpdateUserAttributesError> {
+        (**self).admin_update_user_attributes(input)
+    }
+    fn admin_user_global_sign_out(
+        &self,
+        input: AdminUserGlobalSignOutRequest,
+    ) -> RusotoFuture<AdminUserGlobalSignOutResponse, AdminUserGlobalSignOutError> {
+        (**self).admin_user_global_sign_out(input)
+    }
+    fn associate_software_token(
+        &self,
+        input: AssociateSoftwareTokenRequest,
+    ) -> RusotoFuture<AssociateSoftwareTokenResponse, AssociateSoftwareTokenError> {
+        (**self).associate_software_token(input)
+    }
+    fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> RusotoFuture<ChangePasswordResponse, ChangePasswordError> {
+        (**self).change_password(input)
+    }
+    fn confirm_device(
+        &self,
+        input: ConfirmDeviceRequest,
+    ) -> RusotoFuture<ConfirmDeviceResponse, ConfirmDeviceError> {
+        (**self).confirm_device(input)
+    }
+    fn confirm_forgot_password(
+        &self,
+        input: ConfirmForgotPasswordRequest,
+    ) -> RusotoFuture<ConfirmForgotPasswordResponse, ConfirmForgotPasswordError> {
+        (**self).confirm_forgot_password(input)
+    }
+    fn confirm_sign_up(
+        &self,
+        input: ConfirmSignUpRequest,
+    ) -> RusotoFuture<ConfirmSignUpResponse, ConfirmSignUpError> {
+        (**self).confirm_sign_up(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_identity_provider(
+        &self,
+        input: CreateIdentityProviderRequest,
+    ) -> RusotoFuture<CreateIdentityProviderResponse, CreateIdentityProviderError> {
+        (**self).create_identity_provider(input)
+    }
+    fn create_resource_server(
+        &self,
+        input: CreateResourceServerRequest,
+    ) -> RusotoFuture<CreateResourceServerResponse, CreateResourceServerError> {
+        (**self).create_resource_server(input)
+    }
+    fn create_user_import_job(
+        &self,
+        input: CreateUserImportJobRequest,
+    ) -> RusotoFuture<CreateUserImportJobResponse, CreateUserImportJobError> {
+        (**self).create_user_import_job(input)
+    }
+    fn create_user_pool(
+        &self,
+        input: CreateUserPoolRequest,
+    ) -> RusotoFuture<CreateUserPoolResponse, CreateUserPoolError> {
+        (**self).create_user_pool(input)
+    }
+    fn create_user_pool_client(
+        &self,
+        input: CreateUserPoolClientRequest,
+    ) -> RusotoFuture<CreateUserPoolClientResponse, CreateUserPoolClientError> {
+        (**self).create_user_pool_client(input)
+    }
+    fn create_user_pool_domain(
+        &self,
+        input: CreateUserPoolDomainRequest,
+    ) -> RusotoFuture<CreateUserPoolDomainResponse, CreateUserPoolDomainError> {
+        (**self).create_user_pool_domain(input)
+    }
+    fn delete_group(&self, input: DeleteGroupRequest) -> RusotoFuture<(), DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_identity_provider(
+        &self,
+        input: DeleteIdentityProviderRequest,
+    ) -> RusotoFuture<(), DeleteIdentityProviderError> {
+        (**self).delete_identity_provider(input)
+    }
+    fn delete_resource_server(
+        &self,
+        input: DeleteResourceServerRequest,
+    ) -> RusotoFuture<(), DeleteResourceServerError> {
+        (**self).delete_resource_server(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn delete_user_attributes(
+        &self,
+        input: DeleteUserAttributesRequest,
+    ) -> RusotoFuture<DeleteUserAttributesResponse, DeleteUserAttributesError> {
+        (**self).delete_user_attributes(input)
+    }
+    fn delete_user_pool(
+        &self,
+        input: DeleteUserPoolRequest,
+    ) -> RusotoFuture<(), DeleteUserPoolError> {
+        (**self).delete_user_pool(input)
+    }
+    fn delete_user_pool_client(
+        &self,
+        input: DeleteUserPoolClientRequest,
+    ) -> RusotoFuture<(), DeleteUserPoolClientError> {
+        (**self).delete_user_pool_client(input)
+    }
+    fn delete_user_pool_domain(
+        &self,
+        input: DeleteUserPoolDomainRequest,
+    ) -> RusotoFuture<DeleteUserPoolDomainResponse, DeleteUserPoolDomainError> {
+        (**self).delete_user_pool_domain(input)
+    }
+    fn describe_identity_provider(
+        &self,
+        input: DescribeIdentityProviderRequest,
+    ) -> RusotoFuture<DescribeIdentityProviderResponse, DescribeIdentityProviderError> {
+        (**self).describe_identity_provider(input)
+    }
+    fn describe_resource_server(
+        &self,
+        input: DescribeResourceServerRequest,
+    ) -> RusotoFuture<DescribeResourceServerResponse, DescribeResourceServerError> {
+        (**self).describe_resource_server(input)
+    }
+    fn describe_risk_configuration(
+        &self,
+        input: DescribeRiskConfigurationRequest,
+    ) -> RusotoFuture<DescribeRiskConfigurationResponse, DescribeRiskConfigurationError> {
+        (**self).describe_risk_configuration(input)
+    }
+    fn describe_user_import_job(
+        &self,
+        input: DescribeUserImportJobRequest,
+    ) -> RusotoFuture<DescribeUserImportJobResponse, DescribeUserImportJobError> {
+        (**self).describe_user_import_job(input)
+    }
+    fn describe_user_pool(
+        &self,
+        input: DescribeUserPoolRequest,
+    ) -> RusotoFuture<DescribeUserPoolResponse, DescribeUserPoolError> {
+        (**self).describe_user_pool(input)
+    }
+    fn describe_user_pool_client(
+        &self,
+        input: DescribeUserPoolClientRequest,
+    ) -> RusotoFuture<DescribeUserPoolClientResponse, DescribeUserPoolClientError> {
+        (**self).describe_user_pool_client(input)
+    }
+    fn describe_user_pool_domain(
+        &self,
+        input: DescribeUserPoolDomainRequest,
+    ) -> RusotoFuture<DescribeUserPoolDomainResponse, DescribeUserPoolDomainError> {
+        (**self).describe_user_pool_domain(input)
+    }
+    fn forget_device(&self, input: ForgetDeviceRequest) -> RusotoFuture<(), ForgetDeviceError> {
+        (**self).forget_device(input)
+    }
+    fn forgot_password(
+        &self,
+        input: ForgotPasswordRequest,
+    ) -> RusotoFuture<ForgotPasswordResponse, ForgotPasswordError> {
+        (**self).forgot_password(input)
+    }
+    fn get_csv_header(
+        &self,
+        input: GetCSVHeaderRequest,
+    ) -> RusotoFuture<GetCSVHeaderResponse, GetCSVHeaderError> {
+        (**self).get_csv_header(input)
+    }
+    fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
+        (**self).get_device(input)
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_identity_provider_by_identifier(
+        &self,
+        input: GetIdentityProviderByIdentifierRequest,
+    ) -> RusotoFuture<GetIdentityProviderByIdentifierResponse, GetIdentityProviderByIdentifierError>
+    {
+        (**self).get_identity_provider_by_identifier(input)
+    }
+    fn get_signing_certificate(
+        &self,
+        input: GetSigningCertificateRequest,
+    ) -> RusotoFuture<GetSigningCertificateResponse, GetSigningCertificateError> {
+        (**self).get_signing_certificate(input)
+    }
+    fn get_ui_customization(
+        &self,
+        input: GetUICustomizationRequest,
+    ) -> RusotoFuture<GetUICustomizationResponse, GetUICustomizationError> {
+        (**self).get_ui_customization(input)
+    }
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        (**self).get_user(input)
+    }
+    fn get_user_attribute_verification_code(
+        &self,
+        input: GetUserAttributeVerificationCodeRequest,
+    ) -> RusotoFuture<GetUserAttributeVerificationCodeResponse, GetUserAttributeVerificationCodeError>
+    {
+        (**self).get_user_attribute_verification_code(input)
+    }
+    fn get_user_pool_mfa_config(
+        &self,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> RusotoFuture<GetUserPoolMfaConfigResponse, GetUserPoolMfaConfigError> {
+        (**self).get_user_pool_mfa_config(input)
+    }
+    fn global_sign_out(
+        &self,
+        input: GlobalSignOutRequest,
+    ) -> RusotoFuture<GlobalSignOutResponse, GlobalSignOutError> {
+        (**self).global_sign_out(input)
+    }
+    fn initiate_auth(
+        &self,
+        input: InitiateAuthRequest,
+    ) -> RusotoFuture<InitiateAuthResponse, InitiateAuthError> {
+        (**self).initiate_auth(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn list_identity_providers(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> RusotoFuture<ListIdentityProvidersResponse, ListIdentityProvidersError> {
+        (**self).list_identity_providers(input)
+    }
+    fn list_resource_servers(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> RusotoFuture<ListResourceServersResponse, ListResourceServersError> {
+        (**self).list_resource_servers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_user_import_jobs(
+        &self,
+        input: ListUserImportJobsRequest,
+    ) -> RusotoFuture<ListUserImportJobsResponse, ListUserImportJobsError> {
+        (**self).list_user_import_jobs(input)
+    }
+    fn list_user_pool_clients(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> RusotoFuture<ListUserPoolClientsResponse, ListUserPoolClientsError> {
+        (**self).list_user_pool_clients(input)
+    }
+    fn list_user_pools(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> RusotoFuture<ListUserPoolsResponse, ListUserPoolsError> {
+        (**self).list_user_pools(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn list_users_in_group(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> RusotoFuture<ListUsersInGroupResponse, ListUsersInGroupError> {
+        (**self).list_users_in_group(input)
+    }
+    fn resend_confirmation_code(
+        &self,
+        input: ResendConfirmationCodeRequest,
+    ) -> RusotoFuture<ResendConfirmationCodeResponse, ResendConfirmationCodeError> {
+        (**self).resend_confirmation_code(input)
+    }
+    fn respond_to_auth_challenge(
+        &self,
+        input: RespondToAuthChallengeRequest,
+    ) -> RusotoFuture<RespondToAuthChallengeResponse, RespondToAuthChallengeError> {
+        (**self).respond_to_auth_challenge(input)
+    }
+    fn set_risk_configuration(
+        &self,
+        input: SetRiskConfigurationRequest,
+    ) -> RusotoFuture<SetRiskConfigurationResponse, SetRiskConfigurationError> {
+        (**self).set_risk_configuration(input)
+    }
+    fn set_ui_customization(
+        &self,
+        input: SetUICustomizationRequest,
+    ) -> RusotoFuture<SetUICustomizationResponse, SetUICustomizationError> {
+        (**self).set_ui_customization(input)
+    }
+    fn set_user_mfa_preference(
+        &self,
+        input: SetUserMFAPreferenceRequest,
+    ) -> RusotoFuture<SetUserMFAPreferenceResponse, SetUserMFAPreferenceError> {
+        (**self).set_user_mfa_preference(input)
+    }
+    fn set_user_pool_mfa_config(
+        &self,
+        input: SetUserPoolMfaConfigRequest,
+    ) -> RusotoFuture<SetUserPoolMfaConfigResponse, SetUserPoolMfaConfigError> {
+        (**self).set_user_pool_mfa_config(input)
+    }
+    fn set_user_settings(
+        &self,
+        input: SetUserSettingsRequest,
+    ) -> RusotoFuture<SetUserSettingsResponse, SetUserSettingsError> {
+        (**self).set_user_settings(input)
+    }
+    fn sign_up(&self, input: SignUpRequest) -> RusotoFuture<SignUpResponse, SignUpError> {
+        (**self).sign_up(input)
+    }
+    fn start_user_import_job(
+        &self,
+        input: StartUserImportJobRequest,
+    ) -> RusotoFuture<StartUserImportJobResponse, StartUserImportJobError> {
+        (**self).start_user_import_job(input)
+    }
+    fn stop_user_import_job(
+        &self,
+        input: StopUserImportJobRequest,
+    ) -> RusotoFuture<StopUserImportJobResponse, StopUserImportJobError> {
+        (**self).stop_user_import_job(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_auth_event_feedback(
+        &self,
+        input: UpdateAuthEventFeedbackRequest,
+    ) -> RusotoFuture<UpdateAuthEventFeedbackResponse, UpdateAuthEventFeedbackError> {
+        (**self).update_auth_event_feedback(input)
+    }
+    fn update_device_status(
+        &self,
+        input: UpdateDeviceStatusRequest,
+    ) -> RusotoFuture<UpdateDeviceStatusResponse, UpdateDeviceStatusError> {
+        (**self).update_device_status(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_identity_provider(
+        &self,
+        input: UpdateIdentityProviderRequest,
+    ) -> RusotoFuture<UpdateIdentityProviderResponse, UpdateIdentityProviderError> {
+        (**self).update_identity_provider(input)
+    }
+    fn update_resource_server(
+        &self,
+        input: UpdateResourceServerRequest,
+    ) -> RusotoFuture<UpdateResourceServerResponse, UpdateResourceServerError> {
+        (**self).update_resource_server(input)
+    }
+    fn update_user_attributes(
+        &self,
+        input: UpdateUserAttributesRequest,
+    ) -> RusotoFuture<UpdateUserAttributesResponse, UpdateUserAttributesError> {
+        (**self).update_user_attributes(input)
+    }
+    fn update_user_pool(
+        &self,
+        input: UpdateUserPoolRequest,
+    ) -> RusotoFuture<UpdateUserPoolResponse, UpdateUserPoolError> {
+        (**self).update_user_pool(input)
+    }
+    fn update_user_pool_client(
+        &self,
+        input: UpdateUserPoolClientRequest,
+    ) -> RusotoFuture<UpdateUserPoolClientResponse, UpdateUserPoolClientError> {
+        (**self).update_user_pool_client(input)
+    }
+    fn update_user_pool_domain(
+        &self,
+        input: UpdateUserPoolDomainRequest,
+    ) -> RusotoFuture<UpdateUserPoolDomainResponse, UpdateUserPoolDomainError> {
+        (**self).update_user_pool_domain(input)
+    }
+    fn verify_software_token(
+        &self,
+        input: VerifySoftwareTokenRequest,
+    ) -> RusotoFuture<VerifySoftwareTokenResponse, VerifySoftwareTokenError> {
+        (**self).verify_software_token(input)
+    }
+    fn verify_user_attribute(
+        &self,
+        input: VerifyUserAttributeRequest,
+    ) -> RusotoFuture<VerifyUserAttributeResponse, VerifyUserAttributeError> {
+        (**self).verify_user_attribute(input)
+    }
+}
+/// A client for the Amazon Cognito Identity Provider API.
+#[derive(Clone)]
+pub struct CognitoIdentityProviderClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CognitoIdentityProviderClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CognitoIdentityProviderClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CognitoIdentityProviderClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CognitoIdentityProviderClient {
+        CognitoIdentityProviderClient { client, region }
+    }
+}
+
+impl CognitoIdentityProvider for CognitoIdentityProviderClient {
+    /// <p>Adds additional user attributes to the user pool schema.</p>
+    fn add_custom_attributes(
+        &self,
+        input: AddCustomAttributesRequest,
+    ) -> RusotoFuture<AddCustomAttributesResponse, AddCustomAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AddCustomAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AddCustomAttributes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddCustomAttributesResponse, _>(
+                    response,
+                    "AddCustomAttributes",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AddCustomAttributesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds the specified user to the specified group.</p> <p>Requires developer credentials.</p>
+    fn admin_add_user_to_group(
+        &self,
+        input: AdminAddUserToGroupRequest,
+    ) -> RusotoFuture<(), AdminAddUserToGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminAddUserToGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminAddUserToGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AdminAddUserToGroupError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Confirms user registration as an admin without using a confirmation code. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_confirm_sign_up(
+        &self,
+        input: AdminConfirmSignUpRequest,
+    ) -> RusotoFuture<AdminConfirmSignUpResponse, AdminConfirmSignUpError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminConfirmSignUp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminConfirmSignUp",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminConfirmSignUpResponse, _>(
+                    response,
+                    "AdminConfirmSignUp",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AdminConfirmSignUpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new user in the specified user pool.</p> <p>If <code>MessageAction</code> is not set, the default is to send a welcome message via email or phone (SMS).</p> <note> <p>This message is based on a template that you configured in your call to or . This template includes your custom sign-up instructions and placeholders for user name and temporary password.</p> </note> <p>Alternatively, you can call AdminCreateUser with “SUPPRESS” for the <code>MessageAction</code> parameter, and Amazon Cognito will not send any email. </p> <p>In either case, the user will be in the <code>FORCE_CHANGE_PASSWORD</code> state until they sign in and change their password.</p> <p>AdminCreateUser requires developer credentials.</p>
+    fn admin_create_user(
+        &self,
+        input: AdminCreateUserRequest,
+    ) -> RusotoFuture<AdminCreateUserResponse, AdminCreateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminCreateUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminCreateUser",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminCreateUserResponse, _>(
+                    response,
+                    "AdminCreateUser",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AdminCreateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a user as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_delete_user(
+        &self,
+        input: AdminDeleteUserRequest,
+    ) -> RusotoFuture<(), AdminDeleteUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDeleteUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminDeleteUser",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AdminDeleteUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the user attributes in a user pool as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_delete_user_attributes(
+        &self,
+        input: AdminDeleteUserAttributesRequest,
+    ) -> RusotoFuture<AdminDeleteUserAttributesResponse, AdminDeleteUserAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDeleteUserAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminDeleteUserAttributes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminDeleteUserAttributesResponse, _>(
+                    response,
+                    "AdminDeleteUserAttributes",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AdminDeleteUserAttributesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disables the user from signing in with the specified external (SAML or social) identity provider. If the user to disable is a Cognito User Pools native username + password user, they are not permitted to use their password to sign-in. If the user to disable is a linked external IdP user, any link between that user and an existing user is removed. The next time the external user (no longer attached to the previously linked <code>DestinationUser</code>) signs in, they must create a new user account. See .</p> <p>This action is enabled only for admin access and requires developer credentials.</p> <p>The <code>ProviderName</code> must match the value specified when creating an IdP for the pool. </p> <p>To disable a native username + password user, the <code>ProviderName</code> value must be <code>Cognito</code> and the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code>, with the <code>ProviderAttributeValue</code> being the name that is used in the user pool for the user.</p> <p>The <code>ProviderAttributeName</code> must always be <code>Cognito_Subject</code> for social identity providers. The <code>ProviderAttributeValue</code> must always be the exact subject that was used when the user was originally linked as a source user.</p> <p>For de-linking a SAML identity, there are two scenarios. If the linked identity has not yet been used to sign-in, the <code>ProviderAttributeName</code> and <code>ProviderAttributeValue</code> must be the same values that were used for the <code>SourceUser</code> when the identities were originally linked in the call. (If the linking was done with <code>ProviderAttributeName</code> set to <code>Cognito_Subject</code>, the same applies here). However, if the user has already signed in, the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code> and <code>ProviderAttributeValue</code> must be the subject of the SAML assertion.</p>
+    fn admin_disable_provider_for_user(
+        &self,
+        input: AdminDisableProviderForUserRequest,
+    ) -> RusotoFuture<AdminDisableProviderForUserResponse, AdminDisableProviderForUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDisableProviderForUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminDisableProviderForUser",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminDisableProviderForUserResponse, _>(
+                    response,
+                    "AdminDisableProviderForUser",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AdminDisableProviderForUserError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disables the specified user as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_disable_user(
+        &self,
+        input: AdminDisableUserRequest,
+    ) -> RusotoFuture<AdminDisableUserResponse, AdminDisableUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDisableUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminDisableUser",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminDisableUserResponse, _>(
+                    response,
+                    "AdminDisableUser",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AdminDisableUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables the specified user as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_enable_user(
+        &self,
+        input: AdminEnableUserRequest,
+    ) -> RusotoFuture<AdminEnableUserResponse, AdminEnableUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminEnableUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminEnableUser",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminEnableUserResponse, _>(
+                    response,
+                    "AdminEnableUser",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AdminEnableUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Forgets the device, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_forget_device(
+        &self,
+        input: AdminForgetDeviceRequest,
+    ) -> RusotoFuture<(), AdminForgetDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminForgetDevice");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminForgetDevice",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AdminForgetDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the device, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_get_device(
+        &self,
+        input: AdminGetDeviceRequest,
+    ) -> RusotoFuture<AdminGetDeviceResponse, AdminGetDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminGetDevice");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminGetDevice",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminGetDeviceResponse, _>(
+                    response,
+                    "AdminGetDevice",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AdminGetDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the specified user by user name in a user pool as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_get_user(
+        &self,
+        input: AdminGetUserRequest,
+    ) -> RusotoFuture<AdminGetUserResponse, AdminGetUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminGetUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminGetUser",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminGetUserResponse, _>(
+                    response,
+                    "AdminGetUser",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AdminGetUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Initiates the authentication flow, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_initiate_auth(
+        &self,
+        input: AdminInitiateAuthRequest,
+    ) -> RusotoFuture<AdminInitiateAuthResponse, AdminInitiateAuthError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminInitiateAuth");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminInitiateAuth",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminInitiateAuthResponse, _>(
+                    response,
+                    "AdminInitiateAuth",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AdminInitiateAuthError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Links an existing user account in a user pool (<code>DestinationUser</code>) to an identity from an external identity provider (<code>SourceUser</code>) based on a specified attribute name and value from the external identity provider. This allows you to create a link from the existing user account to an external federated user identity that has not yet been used to sign in, so that the federated user identity can be used to sign in as the existing user account. </p> <p> For example, if there is an existing user with a username and password, this API links that user to a federated user identity, so that when the federated user identity is used, the user signs in as the existing user account. </p> <important> <p>Because this API allows a user with an external federated identity to sign in as an existing user in the user pool, it is critical that it only be used with external identity providers and provider attributes that have been trusted by the application owner.</p> </important> <p>See also .</p> <p>This action is enabled only for admin access and requires developer credentials.</p>
+    fn admin_link_provider_for_user(
+        &self,
+        input: AdminLinkProviderForUserRequest,
+    ) -> RusotoFuture<AdminLinkProviderForUserResponse, AdminLinkProviderForUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminLinkProviderForUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminLinkProviderForUser",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminLinkProviderForUserResponse, _>(
+                    response,
+                    "AdminLinkProviderForUser",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AdminLinkProviderForUserError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists devices, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_list_devices(
+        &self,
+        input: AdminListDevicesRequest,
+    ) -> RusotoFuture<AdminListDevicesResponse, AdminListDevicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminListDevices");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminListDevices",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminListDevicesResponse, _>(
+                    response,
+                    "AdminListDevices",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AdminListDevicesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the groups that the user belongs to.</p> <p>Requires developer credentials.</p>
+    fn admin_list_groups_for_user(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> RusotoFuture<AdminListGroupsForUserResponse, AdminListGroupsForUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminListGroupsForUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminListGroupsForUser",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminListGroupsForUserResponse, _>(
+                    response,
+                    "AdminListGroupsForUser",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AdminListGroupsForUserError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists a history of user activity and any risks detected as part of Amazon Cognito advanced security.</p>
+    fn admin_list_user_auth_events(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> RusotoFuture<AdminListUserAuthEventsResponse, AdminListUserAuthEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminListUserAuthEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminListUserAuthEvents",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminListUserAuthEventsResponse, _>(
+                    response,
+                    "AdminListUserAuthEvents",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AdminListUserAuthEventsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes the specified user from the specified group.</p> <p>Requires developer credentials.</p>
+    fn admin_remove_user_from_group(
+        &self,
+        input: AdminRemoveUserFromGroupRequest,
+    ) -> RusotoFuture<(), AdminRemoveUserFromGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminRemoveUserFromGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminRemoveUserFromGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AdminRemoveUserFromGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Resets the specified user's password in a user pool as an administrator. Works on any user.</p> <p>When a developer calls this API, the current password is invalidated, so it must be changed. If a user tries to sign in after the API is called, the app will get a PasswordResetRequiredException exception back and should direct the user down the flow to reset the password, which is the same as the forgot password flow. In addition, if the user pool has phone verification selected and a verified phone number exists for the user, or if email verification is selected and a verified email exists for the user, calling this API will also result in sending a message to the end user with the code to change their password.</p> <p>Requires developer credentials.</p>
+    fn admin_reset_user_password(
+        &self,
+        input: AdminResetUserPasswordRequest,
+    ) -> RusotoFuture<AdminResetUserPasswordResponse, AdminResetUserPasswordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminResetUserPassword");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminResetUserPassword",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminResetUserPasswordResponse, _>(
+                    response,
+                    "AdminResetUserPassword",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AdminResetUserPasswordError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Responds to an authentication challenge, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_respond_to_auth_challenge(
+        &self,
+        input: AdminRespondToAuthChallengeRequest,
+    ) -> RusotoFuture<AdminRespondToAuthChallengeResponse, AdminRespondToAuthChallengeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminRespondToAuthChallenge");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminRespondToAuthChallenge",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminRespondToAuthChallengeResponse, _>(
+                    response,
+                    "AdminRespondToAuthChallenge",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AdminRespondToAuthChallengeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Sets the user's multi-factor authentication (MFA) preference.</p>
+    fn admin_set_user_mfa_preference(
+        &self,
+        input: AdminSetUserMFAPreferenceRequest,
+    ) -> RusotoFuture<AdminSetUserMFAPreferenceResponse, AdminSetUserMFAPreferenceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminSetUserMFAPreference");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminSetUserMFAPreference",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminSetUserMFAPreferenceResponse, _>(
+                    response,
+                    "AdminSetUserMFAPreference",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AdminSetUserMFAPreferenceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    fn admin_set_user_password(
+        &self,
+        input: AdminSetUserPasswordRequest,
+    ) -> RusotoFuture<AdminSetUserPasswordResponse, AdminSetUserPasswordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminSetUserPassword");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminSetUserPassword",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminSetUserPasswordResponse, _>(
+                    response,
+                    "AdminSetUserPassword",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AdminSetUserPasswordError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets all the user settings for a specified user name. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_set_user_settings(
+        &self,
+        input: AdminSetUserSettingsRequest,
+    ) -> RusotoFuture<AdminSetUserSettingsResponse, AdminSetUserSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminSetUserSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminSetUserSettings",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminSetUserSettingsResponse, _>(
+                    response,
+                    "AdminSetUserSettings",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AdminSetUserSettingsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides feedback for an authentication event as to whether it was from a valid user. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
+    fn admin_update_auth_event_feedback(
+        &self,
+        input: AdminUpdateAuthEventFeedbackRequest,
+    ) -> RusotoFuture<AdminUpdateAuthEventFeedbackResponse, AdminUpdateAuthEventFeedbackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUpdateAuthEventFeedback");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminUpdateAuthEventFeedback",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminUpdateAuthEventFeedbackResponse, _>(
+                    response,
+                    "AdminUpdateAuthEventFeedback",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AdminUpdateAuthEventFeedbackError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the device status as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_update_device_status(
+        &self,
+        input: AdminUpdateDeviceStatusRequest,
+    ) -> RusotoFuture<AdminUpdateDeviceStatusResponse, AdminUpdateDeviceStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUpdateDeviceStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminUpdateDeviceStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminUpdateDeviceStatusResponse, _>(
+                    response,
+                    "AdminUpdateDeviceStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AdminUpdateDeviceStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the specified user's attributes, including developer attributes, as an administrator. Works on any user.</p> <p>For custom attributes, you must prepend the <code>custom:</code> prefix to the attribute name.</p> <p>In addition to updating user attributes, this API can also be used to mark phone and email as verified.</p> <p>Requires developer credentials.</p>
+    fn admin_update_user_attributes(
+        &self,
+        input: AdminUpdateUserAttributesRequest,
+    ) -> RusotoFuture<AdminUpdateUserAttributesResponse, AdminUpdateUserAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUpdateUserAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminUpdateUserAttributes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminUpdateUserAttributesResponse, _>(
+                    response,
+                    "AdminUpdateUserAttributes",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AdminUpdateUserAttributesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Signs out users from all devices, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_user_global_sign_out(
+        &self,
+        input: AdminUserGlobalSignOutRequest,
+    ) -> RusotoFuture<AdminUserGlobalSignOutResponse, AdminUserGlobalSignOutError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUserGlobalSignOut");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminUserGlobalSignOut",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AdminUserGlobalSignOutResponse, _>(
+                    response,
+                    "AdminUserGlobalSignOut",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AdminUserGlobalSignOutError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a unique generated shared secret key code for the user account. The request takes an access token or a session string, but not both.</p>
+    fn associate_software_token(
+        &self,
+        input: AssociateSoftwareTokenRequest,
+    ) -> RusotoFuture<AssociateSoftwareTokenResponse, AssociateSoftwareTokenError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AssociateSoftwareToken");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AssociateSoftwareToken",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateSoftwareTokenResponse, _>(
+                    response,
+                    "AssociateSoftwareToken",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AssociateSoftwareTokenError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes the password for a specified user in a user pool.</p>
+    fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> RusotoFuture<ChangePasswordResponse, ChangePasswordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ChangePassword");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ChangePassword",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ChangePasswordResponse, _>(
+                    response,
+                    "ChangePassword",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ChangePasswordError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Confirms tracking of the device. This API call is the call that begins device tracking.</p>
+    fn confirm_device(
+        &self,
+        input: ConfirmDeviceRequest,
+    ) -> RusotoFuture<ConfirmDeviceResponse, ConfirmDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ConfirmDevice");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ConfirmDevice",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ConfirmDeviceResponse, _>(
+                    response,
+                    "ConfirmDevice",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ConfirmDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Allows a user to enter a confirmation code to reset a forgotten password.</p>
+    fn confirm_forgot_password(
+        &self,
+        input: ConfirmForgotPasswordRequest,
+    ) -> RusotoFuture<ConfirmForgotPasswordResponse, ConfirmForgotPasswordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ConfirmForgotPassword");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ConfirmForgotPassword",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ConfirmForgotPasswordResponse, _>(
+                    response,
+                    "ConfirmForgotPassword",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ConfirmForgotPasswordError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Confirms registration of a user and handles the existing alias from a previous user.</p>
+    fn confirm_sign_up(
+        &self,
+        input: ConfirmSignUpRequest,
+    ) -> RusotoFuture<ConfirmSignUpResponse, ConfirmSignUpError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ConfirmSignUp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ConfirmSignUp",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ConfirmSignUpResponse, _>(
+                    response,
+                    "ConfirmSignUp",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ConfirmSignUpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new group in the specified user pool.</p> <p>Requires developer credentials.</p>
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateGroupResponse, _>(
+                    response,
+                    "CreateGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an identity provider for a user pool.</p>
+    fn create_identity_provider(
+        &self,
+        input: CreateIdentityProviderRequest,
+    ) -> RusotoFuture<CreateIdentityProviderResponse, CreateIdentityProviderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateIdentityProvider");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateIdentityProvider",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateIdentityProviderResponse, _>(
+                    response,
+                    "CreateIdentityProvider",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateIdentityProviderError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new OAuth2.0 resource server and defines custom scopes in it.</p>
+    fn create_resource_server(
+        &self,
+        input: CreateResourceServerRequest,
+    ) -> RusotoFuture<CreateResourceServerResponse, CreateResourceServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateResourceServer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateResourceServer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateResourceServerResponse, _>(
+                    response,
+                    "CreateResourceServer",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateResourceServerError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates the user import job.</p>
+    fn create_user_import_job(
+        &self,
+        input: CreateUserImportJobRequest,
+    ) -> RusotoFuture<CreateUserImportJobResponse, CreateUserImportJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserImportJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateUserImportJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserImportJobResponse, _>(
+                    response,
+                    "CreateUserImportJob",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateUserImportJobError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new Amazon Cognito user pool and sets the password policy for the pool.</p>
+    fn create_user_pool(
+        &self,
+        input: CreateUserPoolRequest,
+    ) -> RusotoFuture<CreateUserPoolResponse, CreateUserPoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserPool");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateUserPool",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserPoolResponse, _>(
+                    response,
+                    "CreateUserPool",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUserPoolError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates the user pool client.</p>
+    fn create_user_pool_client(
+        &self,
+        input: CreateUserPoolClientRequest,
+    ) -> RusotoFuture<CreateUserPoolClientResponse, CreateUserPoolClientError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserPoolClient");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateUserPoolClient",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserPoolClientResponse, _>(
+                    response,
+                    "CreateUserPoolClient",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateUserPoolClientError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new domain for a user pool.</p>
+    fn create_user_pool_domain(
+        &self,
+        input: CreateUserPoolDomainRequest,
+    ) -> RusotoFuture<CreateUserPoolDomainResponse, CreateUserPoolDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserPoolDomain");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateUserPoolDomain",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserPoolDomainResponse, _>(
+                    response,
+                    "CreateUserPoolDomain",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateUserPoolDomainError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a group. Currently only groups with no members can be deleted.</p> <p>Requires developer credentials.</p>
+    fn delete_group(&self, input: DeleteGroupRequest) -> RusotoFuture<(), DeleteGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an identity provider for a user pool.</p>
+    fn delete_identity_provider(
+        &self,
+        input: DeleteIdentityProviderRequest,
+    ) -> RusotoFuture<(), DeleteIdentityProviderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteIdentityProvider");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteIdentityProvider",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteIdentityProviderError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a resource server.</p>
+    fn delete_resource_server(
+        &self,
+        input: DeleteResourceServerRequest,
+    ) -> RusotoFuture<(), DeleteResourceServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteResourceServer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteResourceServer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteResourceServerError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Allows a user to delete himself or herself.</p>
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteUser",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the attributes for a user.</p>
+    fn delete_user_attributes(
+        &self,
+        input: DeleteUserAttributesRequest,
+    ) -> RusotoFuture<DeleteUserAttributesResponse, DeleteUserAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteUserAttributes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteUserAttributesResponse, _>(
+                    response,
+                    "DeleteUserAttributes",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteUserAttributesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified Amazon Cognito user pool.</p>
+    fn delete_user_pool(
+        &self,
+        input: DeleteUserPoolRequest,
+    ) -> RusotoFuture<(), DeleteUserPoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserPool");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteUserPool",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUserPoolError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Allows the developer to delete the user pool client.</p>
+    fn delete_user_pool_client(
+        &self,
+        input: DeleteUserPoolClientRequest,
+    ) -> RusotoFuture<(), DeleteUserPoolClientError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserPoolClient");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteUserPoolClient",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteUserPoolClientError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a domain for a user pool.</p>
+    fn delete_user_pool_domain(
+        &self,
+        input: DeleteUserPoolDomainRequest,
+    ) -> RusotoFuture<DeleteUserPoolDomainResponse, DeleteUserPoolDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserPoolDomain");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteUserPoolDomain",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteUserPoolDomainResponse, _>(
+                    response,
+                    "DeleteUserPoolDomain",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteUserPoolDomainError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a specific identity provider.</p>
+    fn describe_identity_provider(
+        &self,
+        input: DescribeIdentityProviderRequest,
+    ) -> RusotoFuture<DescribeIdentityProviderResponse, DescribeIdentityProviderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeIdentityProvider");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeIdentityProvider",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeIdentityProviderResponse, _>(
+                    response,
+                    "DescribeIdentityProvider",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeIdentityProviderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes a resource server.</p>
+    fn describe_resource_server(
+        &self,
+        input: DescribeResourceServerRequest,
+    ) -> RusotoFuture<DescribeResourceServerResponse, DescribeResourceServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeResourceServer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeResourceServer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeResourceServerResponse, _>(
+                    response,
+                    "DescribeResourceServer",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeResourceServerError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the risk configuration.</p>
+    fn describe_risk_configuration(
+        &self,
+        input: DescribeRiskConfigurationRequest,
+    ) -> RusotoFuture<DescribeRiskConfigurationResponse, DescribeRiskConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeRiskConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeRiskConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRiskConfigurationResponse, _>(
+                    response,
+                    "DescribeRiskConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeRiskConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the user import job.</p>
+    fn describe_user_import_job(
+        &self,
+        input: DescribeUserImportJobRequest,
+    ) -> RusotoFuture<DescribeUserImportJobResponse, DescribeUserImportJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserImportJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeUserImportJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeUserImportJobResponse, _>(
+                    response,
+                    "DescribeUserImportJob",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeUserImportJobError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the configuration information and metadata of the specified user pool.</p>
+    fn describe_user_pool(
+        &self,
+        input: DescribeUserPoolRequest,
+    ) -> RusotoFuture<DescribeUserPoolResponse, DescribeUserPoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserPool");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeUserPool",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeUserPoolResponse, _>(
+                    response,
+                    "DescribeUserPool",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeUserPoolError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Client method for returning the configuration information and metadata of the specified user pool app client.</p>
+    fn describe_user_pool_client(
+        &self,
+        input: DescribeUserPoolClientRequest,
+    ) -> RusotoFuture<DescribeUserPoolClientResponse, DescribeUserPoolClientError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserPoolClient");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeUserPoolClient",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeUserPoolClientResponse, _>(
+                    response,
+                    "DescribeUserPoolClient",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeUserPoolClientError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a domain.</p>
+    fn describe_user_pool_domain(
+        &self,
+        input: DescribeUserPoolDomainRequest,
+    ) -> RusotoFuture<DescribeUserPoolDomainResponse, DescribeUserPoolDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserPoolDomain");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeUserPoolDomain",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeUserPoolDomainResponse, _>(
+                    response,
+                    "DescribeUserPoolDomain",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeUserPoolDomainError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Forgets the specified device.</p>
+    fn forget_device(&self, input: ForgetDeviceRequest) -> RusotoFuture<(), ForgetDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ForgetDevice");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ForgetDevice",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ForgetDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Calling this API causes a message to be sent to the end user with a confirmation code that is required to change the user's password. For the <code>Username</code> parameter, you can use the username or user alias. If a verified phone number exists for the user, the confirmation code is sent to the phone number. Otherwise, if a verified email exists, the confirmation code is sent to the email. If neither a verified phone number nor a verified email exists, <code>InvalidParameterException</code> is thrown. To use the confirmation code for resetting the password, call .</p>
+    fn forgot_password(
+        &self,
+        input: ForgotPasswordRequest,
+    ) -> RusotoFuture<ForgotPasswordResponse, ForgotPasswordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ForgotPassword");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ForgotPassword",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ForgotPasswordResponse, _>(
+                    response,
+                    "ForgotPassword",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ForgotPasswordError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the header information for the .csv file to be used as input for the user import job.</p>
+    fn get_csv_header(
+        &self,
+        input: GetCSVHeaderRequest,
+    ) -> RusotoFuture<GetCSVHeaderResponse, GetCSVHeaderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetCSVHeader");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetCSVHeader",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCSVHeaderResponse, _>(
+                    response,
+                    "GetCSVHeader",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetCSVHeaderError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the device.</p>
+    fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetDevice");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetDevice",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDeviceResponse, _>(response, "GetDevice")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a group.</p> <p>Requires developer credentials.</p>
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetGroupResponse, _>(response, "GetGroup")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the specified identity provider.</p>
+    fn get_identity_provider_by_identifier(
+        &self,
+        input: GetIdentityProviderByIdentifierRequest,
+    ) -> RusotoFuture<GetIdentityProviderByIdentifierResponse, GetIdentityProviderByIdentifierError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetIdentityProviderByIdentifier");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetIdentityProviderByIdentifier",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetIdentityProviderByIdentifierResponse, _>(
+                    response,
+                    "GetIdentityProviderByIdentifier",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetIdentityProviderByIdentifierError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>This method takes a user pool ID, and returns the signing certificate.</p>
+    fn get_signing_certificate(
+        &self,
+        input: GetSigningCertificateRequest,
+    ) -> RusotoFuture<GetSigningCertificateResponse, GetSigningCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetSigningCertificate");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetSigningCertificate",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetSigningCertificateResponse, _>(
+                    response,
+                    "GetSigningCertificate",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetSigningCertificateError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the UI Customization information for a particular app client's app UI, if there is something set. If nothing is set for the particular client, but there is an existing pool level customization (app <code>clientId</code> will be <code>ALL</code>), then that is returned. If nothing is present, then an empty shape is returned.</p>
+    fn get_ui_customization(
+        &self,
+        input: GetUICustomizationRequest,
+    ) -> RusotoFuture<GetUICustomizationResponse, GetUICustomizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUICustomization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetUICustomization",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetUICustomizationResponse, _>(
+                    response,
+                    "GetUICustomization",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUICustomizationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the user attributes and metadata for a user.</p>
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetUserResponse, _>(response, "GetUser")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the user attribute verification code for the specified attribute name.</p>
+    fn get_user_attribute_verification_code(
+        &self,
+        input: GetUserAttributeVerificationCodeRequest,
+    ) -> RusotoFuture<GetUserAttributeVerificationCodeResponse, GetUserAttributeVerificationCodeError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUserAttributeVerificationCode");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetUserAttributeVerificationCodeResponse, _>(
+                    response,
+                    "GetUserAttributeVerificationCode",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetUserAttributeVerificationCodeError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the user pool multi-factor authentication (MFA) configuration.</p>
+    fn get_user_pool_mfa_config(
+        &self,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> RusotoFuture<GetUserPoolMfaConfigResponse, GetUserPoolMfaConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUserPoolMfaConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetUserPoolMfaConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetUserPoolMfaConfigResponse, _>(
+                    response,
+                    "GetUserPoolMfaConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetUserPoolMfaConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Signs out users from all devices.</p>
+    fn global_sign_out(
+        &self,
+        input: GlobalSignOutRequest,
+    ) -> RusotoFuture<GlobalSignOutResponse, GlobalSignOutError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GlobalSignOut");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GlobalSignOut",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GlobalSignOutResponse, _>(
+                    response,
+                    "GlobalSignOut",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GlobalSignOutError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Initiates the authentication flow.</p>
+    fn initiate_auth(
+        &self,
+        input: InitiateAuthRequest,
+    ) -> RusotoFuture<InitiateAuthResponse, InitiateAuthError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("InitiateAuth");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.InitiateAuth",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<InitiateAuthResponse, _>(
+                    response,
+                    "InitiateAuth",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(InitiateAuthError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the devices.</p>
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListDevices");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListDevices",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDevicesResponse, _>(
+                    response,
+                    "ListDevices",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDevicesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the groups associated with a user pool.</p> <p>Requires developer credentials.</p>
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListGroups",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGroupsResponse, _>(response, "ListGroups")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists information about all identity providers for a user pool.</p>
+    fn list_identity_providers(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> RusotoFuture<ListIdentityProvidersResponse, ListIdentityProvidersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListIdentityProviders");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListIdentityProviders",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListIdentityProvidersResponse, _>(
+                    response,
+                    "ListIdentityProviders",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListIdentityProvidersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the resource servers for a user pool.</p>
+    fn list_resource_servers(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> RusotoFuture<ListResourceServersResponse, ListResourceServersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListResourceServers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListResourceServers",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListResourceServersResponse, _>(
+                    response,
+                    "ListResourceServers",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListResourceServersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the tags that are assigned to an Amazon Cognito user pool.</p> <p>A tag is a label that you can apply to user pools to categorize and manage them in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>You can use this action up to 10 times per second, per account.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListTagsForResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the user import jobs.</p>
+    fn list_user_import_jobs(
+        &self,
+        input: ListUserImportJobsRequest,
+    ) -> RusotoFuture<ListUserImportJobsResponse, ListUserImportJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUserImportJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUserImportJobs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListUserImportJobsResponse, _>(
+                    response,
+                    "ListUserImportJobs",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListUserImportJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the clients that have been created for the specified user pool.</p>
+    fn list_user_pool_clients(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> RusotoFuture<ListUserPoolClientsResponse, ListUserPoolClientsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUserPoolClients");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUserPoolClients",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListUserPoolClientsResponse, _>(
+                    response,
+                    "ListUserPoolClients",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListUserPoolClientsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the user pools associated with an AWS account.</p>
+    fn list_user_pools(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> RusotoFuture<ListUserPoolsResponse, ListUserPoolsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUserPools");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUserPools",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListUserPoolsResponse, _>(
+                    response,
+                    "ListUserPools",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListUserPoolsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the users in the Amazon Cognito user pool.</p>
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUsers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUsers",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListUsersResponse, _>(response, "ListUsers")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListUsersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the users in the specified group.</p> <p>Requires developer credentials.</p>
+    fn list_users_in_group(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> RusotoFuture<ListUsersInGroupResponse, ListUsersInGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUsersInGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUsersInGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListUsersInGroupResponse, _>(
+                    response,
+                    "ListUsersInGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListUsersInGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Resends the confirmation (for confirmation of registration) to a specific user in the user pool.</p>
+    fn resend_confirmation_code(
+        &self,
+        input: ResendConfirmationCodeRequest,
+    ) -> RusotoFuture<ResendConfirmationCodeResponse, ResendConfirmationCodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ResendConfirmationCode");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ResendConfirmationCode",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ResendConfirmationCodeResponse, _>(
+                    response,
+                    "ResendConfirmationCode",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ResendConfirmationCodeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Responds to the authentication challenge.</p>
+    fn respond_to_auth_challenge(
+        &self,
+        input: RespondToAuthChallengeRequest,
+    ) -> RusotoFuture<RespondToAuthChallengeResponse, RespondToAuthChallengeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("RespondToAuthChallenge");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RespondToAuthChallengeResponse, _>(
+                    response,
+                    "RespondToAuthChallenge",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RespondToAuthChallengeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Configures actions on detected risks. To delete the risk configuration for <code>UserPoolId</code> or <code>ClientId</code>, pass null values for all four configuration types.</p> <p>To enable Amazon Cognito advanced security features, update the user pool to include the <code>UserPoolAddOns</code> key<code>AdvancedSecurityMode</code>.</p> <p>See .</p>
+    fn set_risk_configuration(
+        &self,
+        input: SetRiskConfigurationRequest,
+    ) -> RusotoFuture<SetRiskConfigurationResponse, SetRiskConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetRiskConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetRiskConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SetRiskConfigurationResponse, _>(
+                    response,
+                    "SetRiskConfiguration",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SetRiskConfigurationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Sets the UI customization information for a user pool&#39;s built-in app UI.</p> <p>You can specify app UI customization settings for a single client (with a specific <code>clientId</code>) or for all clients (by setting the <code>clientId</code> to <code>ALL</code>). If you specify <code>ALL</code>, the default configuration will be used for every client that has no UI customization set previously. If you specify UI customization settings for a particular client, it will no longer fall back to the <code>ALL</code> configuration. </p> <note> <p>To use this API, your user pool must have a domain associated with it. Otherwise, there is no place to host the app&#39;s pages, and the service will throw an error.</p> </note></p>
+    fn set_ui_customization(
+        &self,
+        input: SetUICustomizationRequest,
+    ) -> RusotoFuture<SetUICustomizationResponse, SetUICustomizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUICustomization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetUICustomization",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SetUICustomizationResponse, _>(
+                    response,
+                    "SetUICustomization",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetUICustomizationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Set the user's multi-factor authentication (MFA) method preference.</p>
+    fn set_user_mfa_preference(
+        &self,
+        input: SetUserMFAPreferenceRequest,
+    ) -> RusotoFuture<SetUserMFAPreferenceResponse, SetUserMFAPreferenceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUserMFAPreference");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetUserMFAPreference",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SetUserMFAPreferenceResponse, _>(
+                    response,
+                    "SetUserMFAPreference",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SetUserMFAPreferenceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Set the user pool MFA configuration.</p>
+    fn set_user_pool_mfa_config(
+        &self,
+        input: SetUserPoolMfaConfigRequest,
+    ) -> RusotoFuture<SetUserPoolMfaConfigResponse, SetUserPoolMfaConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUserPoolMfaConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetUserPoolMfaConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SetUserPoolMfaConfigResponse, _>(
+                    response,
+                    "SetUserPoolMfaConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SetUserPoolMfaConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets the user settings like multi-factor authentication (MFA). If MFA is to be removed for a particular attribute pass the attribute with code delivery as null. If null list is passed, all MFA options are removed.</p>
+    fn set_user_settings(
+        &self,
+        input: SetUserSettingsRequest,
+    ) -> RusotoFuture<SetUserSettingsResponse, SetUserSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUserSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetUserSettings",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SetUserSettingsResponse, _>(
+                    response,
+                    "SetUserSettings",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetUserSettingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers the user in the specified user pool and creates a user name, password, and user attributes.</p>
+    fn sign_up(&self, input: SignUpRequest) -> RusotoFuture<SignUpResponse, SignUpError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SignUp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SignUpResponse, _>(response, "SignUp")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SignUpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts the user import.</p>
+    fn start_user_import_job(
+        &self,
+        input: StartUserImportJobRequest,
+    ) -> RusotoFuture<StartUserImportJobResponse, StartUserImportJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("StartUserImportJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.StartUserImportJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartUserImportJobResponse, _>(
+                    response,
+                    "StartUserImportJob",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartUserImportJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops the user import job.</p>
+    fn stop_user_import_job(
+        &self,
+        input: StopUserImportJobRequest,
+    ) -> RusotoFuture<StopUserImportJobResponse, StopUserImportJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("StopUserImportJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.StopUserImportJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopUserImportJobResponse, _>(
+                    response,
+                    "StopUserImportJob",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopUserImportJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Assigns a set of tags to an Amazon Cognito user pool. A tag is a label that you can use to categorize and manage user pools in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a key and value, both of which you define. A key is a general category for more specific values. For example, if you have two versions of a user pool, one for testing and another for production, you might assign an <code>Environment</code> tag key to both user pools. The value of this key might be <code>Test</code> for one user pool and <code>Production</code> for the other.</p> <p>Tags are useful for cost tracking and access control. You can activate your tags so that they appear on the Billing and Cost Management console, where you can track the costs associated with your user pools. In an IAM policy, you can constrain permissions for user pools based on specific tags or tag values.</p> <p>You can use this action up to 5 times per second, per account. A user pool can have as many as 50 tags.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.TagResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the specified tags from an Amazon Cognito user pool. You can use this action up to 5 times per second, per account</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UntagResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides the feedback for an authentication event whether it was from a valid user or not. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
+    fn update_auth_event_feedback(
+        &self,
+        input: UpdateAuthEventFeedbackRequest,
+    ) -> RusotoFuture<UpdateAuthEventFeedbackResponse, UpdateAuthEventFeedbackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateAuthEventFeedback");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateAuthEventFeedback",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateAuthEventFeedbackResponse, _>(
+                    response,
+                    "UpdateAuthEventFeedback",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateAuthEventFeedbackError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the device status.</p>
+    fn update_device_status(
+        &self,
+        input: UpdateDeviceStatusRequest,
+    ) -> RusotoFuture<UpdateDeviceStatusResponse, UpdateDeviceStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateDeviceStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateDeviceStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDeviceStatusResponse, _>(
+                    response,
+                    "UpdateDeviceStatus",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDeviceStatusError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified group with the specified attributes.</p> <p>Requires developer credentials.</p>
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateGroupResponse, _>(
+                    response,
+                    "UpdateGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates identity provider information for a user pool.</p>
+    fn update_identity_provider(
+        &self,
+        input: UpdateIdentityProviderRequest,
+    ) -> RusotoFuture<UpdateIdentityProviderResponse, UpdateIdentityProviderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateIdentityProvider");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateIdentityProvider",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateIdentityProviderResponse, _>(
+                    response,
+                    "UpdateIdentityProvider",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateIdentityProviderError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the name and scopes of resource server. All other fields are read-only.</p>
+    fn update_resource_server(
+        &self,
+        input: UpdateResourceServerRequest,
+    ) -> RusotoFuture<UpdateResourceServerResponse, UpdateResourceServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateResourceServer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateResourceServer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateResourceServerResponse, _>(
+                    response,
+                    "UpdateResourceServer",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateResourceServerError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Allows a user to update a specific attribute (one at a time).</p>
+    fn update_user_attributes(
+        &self,
+        input: UpdateUserAttributesRequest,
+    ) -> RusotoFuture<UpdateUserAttributesResponse, UpdateUserAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateUserAttributes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateUserAttributesResponse, _>(
+                    response,
+                    "UpdateUserAttributes",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateUserAttributesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified user pool with the specified attributes. If you don't provide a value for an attribute, it will be set to the default value. You can get a list of the current user pool settings with .</p>
+    fn update_user_pool(
+        &self,
+        input: UpdateUserPoolRequest,
+    ) -> RusotoFuture<UpdateUserPoolResponse, UpdateUserPoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserPool");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateUserPool",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateUserPoolResponse, _>(
+                    response,
+                    "UpdateUserPool",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateUserPoolError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified user pool app client with the specified attributes. If you don't provide a value for an attribute, it will be set to the default value. You can get a list of the current user pool app client settings with .</p>
+    fn update_user_pool_client(
+        &self,
+        input: UpdateUserPoolClientRequest,
+    ) -> RusotoFuture<UpdateUserPoolClientResponse, UpdateUserPoolClientError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserPoolClient");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateUserPoolClient",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateUserPoolClientResponse, _>(
+                    response,
+                    "UpdateUserPoolClient",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateUserPoolClientError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the Secure Sockets Layer (SSL) certificate for the custom domain for your user pool.</p> <p>You can use this operation to provide the Amazon Resource Name (ARN) of a new certificate to Amazon Cognito. You cannot use it to change the domain for a user pool.</p> <p>A custom domain is used to host the Amazon Cognito hosted UI, which provides sign-up and sign-in pages for your application. When you set up a custom domain, you provide a certificate that you manage with AWS Certificate Manager (ACM). When necessary, you can use this operation to change the certificate that you applied to your custom domain.</p> <p>Usually, this is unnecessary following routine certificate renewal with ACM. When you renew your existing certificate in ACM, the ARN for your certificate remains the same, and your custom domain uses the new certificate automatically.</p> <p>However, if you replace your existing certificate with a new one, ACM gives the new certificate a new ARN. To apply the new certificate to your custom domain, you must provide this ARN to Amazon Cognito.</p> <p>When you add your new certificate in ACM, you must choose US East (N. Virginia) as the AWS Region.</p> <p>After you submit your request, Amazon Cognito requires up to 1 hour to distribute your new certificate to your custom domain.</p> <p>For more information about adding a custom domain to your user pool, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/cognito-user-pools-add-custom-domain.html">Using Your Own Domain for the Hosted UI</a>.</p>
+    fn update_user_pool_domain(
+        &self,
+        input: UpdateUserPoolDomainRequest,
+    ) -> RusotoFuture<UpdateUserPoolDomainResponse, UpdateUserPoolDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserPoolDomain");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateUserPoolDomain",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateUserPoolDomainResponse, _>(
+                    response,
+                    "UpdateUserPoolDomain",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateUserPoolDomainError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Use this API to register a user's entered TOTP code and mark the user's software token MFA status as "verified" if successful. The request takes an access token or a session string, but not both.</p>
+    fn verify_software_token(
+        &self,
+        input: VerifySoftwareTokenRequest,
+    ) -> RusotoFuture<VerifySoftwareTokenResponse, VerifySoftwareTokenError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("VerifySoftwareToken");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.VerifySoftwareToken",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VerifySoftwareTokenResponse, _>(
+                    response,
+                    "VerifySoftwareToken",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(VerifySoftwareTokenError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Verifies the specified user attributes in the user pool.</p>
+    fn verify_user_attribute(
+        &self,
+        input: VerifyUserAttributeRequest,
+    ) -> RusotoFuture<VerifyUserAttributeResponse, VerifyUserAttributeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("VerifyUserAttribute");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.VerifyUserAttribute",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VerifyUserAttributeResponse, _>(
+                    response,
+                    "VerifyUserAttribute",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(VerifyUserAttributeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon Cognito Identity Provider API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCognitoIdentityProvider {
+    add_custom_attributes: Option<
+        Box<
+            dyn Fn(
+                    AddCustomAttributesRequest,
+                ) -> ::std::result::Result<
+                    AddCustomAttributesResponse,
+                    RusotoError<AddCustomAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_add_user_to_group: Option<
+        Box<
+            dyn Fn(
+                    AdminAddUserToGroupRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<AdminAddUserToGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    admin_confirm_sign_up: Option<
+        Box<
+            dyn Fn(
+                    AdminConfirmSignUpRequest,
+                ) -> ::std::result::Result<
+                    AdminConfirmSignUpResponse,
+                    RusotoError<AdminConfirmSignUpError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_create_user: Option<
+        Box<
+            dyn Fn(
+                    AdminCreateUserRequest,
+                ) -> ::std::result::Result<
+                    AdminCreateUserResponse,
+                    RusotoError<AdminCreateUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_delete_user: Option<
+        Box<
+            dyn Fn(
+                    AdminDeleteUserRequest,
+                ) -> ::std::result::Result<(), RusotoError<AdminDeleteUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    admin_delete_user_attributes: Option<
+        Box<
+            dyn Fn(
+                    AdminDeleteUserAttributesRequest,
+                ) -> ::std::result::Result<
+                    AdminDeleteUserAttributesResponse,
+                    RusotoError<AdminDeleteUserAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_disable_provider_for_user: Option<
+        Box<
+            dyn Fn(
+                    AdminDisableProviderForUserRequest,
+                ) -> ::std::result::Result<
+                    AdminDisableProviderForUserResponse,
+                    RusotoError<AdminDisableProviderForUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_disable_user: Option<
+        Box<
+            dyn Fn(
+                    AdminDisableUserRequest,
+                ) -> ::std::result::Result<
+                    AdminDisableUserResponse,
+                    RusotoError<AdminDisableUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_enable_user: Option<
+        Box<
+            dyn Fn(
+                    AdminEnableUserRequest,
+                ) -> ::std::result::Result<
+                    AdminEnableUserResponse,
+                    RusotoError<AdminEnableUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_forget_device: Option<
+        Box<
+            dyn Fn(
+                    AdminForgetDeviceRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<AdminForgetDeviceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    admin_get_device: Option<
+        Box<
+            dyn Fn(
+                    AdminGetDeviceRequest,
+                ) -> ::std::result::Result<
+                    AdminGetDeviceResponse,
+                    RusotoError<AdminGetDeviceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_get_user: Option<
+        Box<
+            dyn Fn(
+                    AdminGetUserRequest,
+                )
+                    -> ::std::result::Result<AdminGetUserResponse, RusotoError<AdminGetUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    admin_initiate_auth: Option<
+        Box<
+            dyn Fn(
+                    AdminInitiateAuthRequest,
+                ) -> ::std::result::Result<
+                    AdminInitiateAuthResponse,
+                    RusotoError<AdminInitiateAuthError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_link_provider_for_user: Option<
+        Box<
+            dyn Fn(
+                    AdminLinkProviderForUserRequest,
+                ) -> ::std::result::Result<
+                    AdminLinkProviderForUserResponse,
+                    RusotoError<AdminLinkProviderForUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_list_devices: Option<
+        Box<
+            dyn Fn(
+                    AdminListDevicesRequest,
+                ) -> ::std::result::Result<
+                    AdminListDevicesResponse,
+                    RusotoError<AdminListDevicesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_list_groups_for_user: Option<
+        Box<
+            dyn Fn(
+                    AdminListGroupsForUserRequest,
+                ) -> ::std::result::Result<
+                    AdminListGroupsForUserResponse,
+                    RusotoError<AdminListGroupsForUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_list_user_auth_events: Option<
+        Box<
+            dyn Fn(
+                    AdminListUserAuthEventsRequest,
+                ) -> ::std::result::Result<
+                    AdminListUserAuthEventsResponse,
+                    RusotoError<AdminListUserAuthEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_remove_user_from_group: Option<
+        Box<
+            dyn Fn(
+                    AdminRemoveUserFromGroupRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<AdminRemoveUserFromGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    admin_reset_user_password: Option<
+        Box<
+            dyn Fn(
+                    AdminResetUserPasswordRequest,
+                ) -> ::std::result::Result<
+                    AdminResetUserPasswordResponse,
+                    RusotoError<AdminResetUserPasswordError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_respond_to_auth_challenge: Option<
+        Box<
+            dyn Fn(
+                    AdminRespondToAuthChallengeRequest,
+                ) -> ::std::result::Result<
+                    AdminRespondToAuthChallengeResponse,
+                    RusotoError<AdminRespondToAuthChallengeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_set_user_mfa_preference: Option<
+        Box<
+            dyn Fn(
+                    AdminSetUserMFAPreferenceRequest,
+                ) -> ::std::result::Result<
+                    AdminSetUserMFAPreferenceResponse,
+                    RusotoError<AdminSetUserMFAPreferenceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_set_user_password: Option<
+        Box<
+            dyn Fn(
+                    AdminSetUserPasswordRequest,
+                ) -> ::std::result::Result<
+                    AdminSetUserPasswordResponse,
+                    RusotoError<AdminSetUserPasswordError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_set_user_settings: Option<
+        Box<
+            dyn Fn(
+                    AdminSetUserSettingsRequest,
+                ) -> ::std::result::Result<
+                    AdminSetUserSettingsResponse,
+                    RusotoError<AdminSetUserSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_update_auth_event_feedback: Option<
+        Box<
+            dyn Fn(
+                    AdminUpdateAuthEventFeedbackRequest,
+                ) -> ::std::result::Result<
+                    AdminUpdateAuthEventFeedbackResponse,
+                    RusotoError<AdminUpdateAuthEventFeedbackError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_update_device_status: Option<
+        Box<
+            dyn Fn(
+                    AdminUpdateDeviceStatusRequest,
+                ) -> ::std::result::Result<
+                    AdminUpdateDeviceStatusResponse,
+                    RusotoError<AdminUpdateDeviceStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_update_user_attributes: Option<
+        Box<
+            dyn Fn(
+                    AdminUpdateUserAttributesRequest,
+                ) -> ::std::result::Result<
+                    AdminUpdateUserAttributesResponse,
+                    RusotoError<AdminUpdateUserAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    admin_user_global_sign_out: Option<
+        Box<
+            dyn Fn(
+                    AdminUserGlobalSignOutRequest,
+                ) -> ::std::result::Result<
+                    AdminUserGlobalSignOutResponse,
+                    RusotoError<AdminUserGlobalSignOutError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_software_token: Option<
+        Box<
+            dyn Fn(
+                    AssociateSoftwareTokenRequest,
+                ) -> ::std::result::Result<
+                    AssociateSoftwareTokenResponse,
+                    RusotoError<AssociateSoftwareTokenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    change_password: Option<
+        Box<
+            dyn Fn(
+                    ChangePasswordRequest,
+                ) -> ::std::result::Result<
+                    ChangePasswordResponse,
+                    RusotoError<ChangePasswordError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    confirm_device: Option<
+        Box<
+            dyn Fn(
+                    ConfirmDeviceRequest,
+                )
+                    -> ::std::result::Result<ConfirmDeviceResponse, RusotoError<ConfirmDeviceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    confirm_forgot_password: Option<
+        Box<
+            dyn Fn(
+                    ConfirmForgotPasswordRequest,
+                ) -> ::std::result::Result<
+                    ConfirmForgotPasswordResponse,
+                    RusotoError<ConfirmForgotPasswordError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    confirm_sign_up: Option<
+        Box<
+            dyn Fn(
+                    ConfirmSignUpRequest,
+                )
+                    -> ::std::result::Result<ConfirmSignUpResponse, RusotoError<ConfirmSignUpError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_group: Option<
+        Box<
+            dyn Fn(
+                    CreateGroupRequest,
+                )
+                    -> ::std::result::Result<CreateGroupResponse, RusotoError<CreateGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_identity_provider: Option<
+        Box<
+            dyn Fn(
+                    CreateIdentityProviderRequest,
+                ) -> ::std::result::Result<
+                    CreateIdentityProviderResponse,
+                    RusotoError<CreateIdentityProviderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_resource_server: Option<
+        Box<
+            dyn Fn(
+                    CreateResourceServerRequest,
+                ) -> ::std::result::Result<
+                    CreateResourceServerResponse,
+                    RusotoError<CreateResourceServerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_user_import_job: Option<
+        Box<
+            dyn Fn(
+                    CreateUserImportJobRequest,
+                ) -> ::std::result::Result<
+                    CreateUserImportJobResponse,
+                    RusotoError<CreateUserImportJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_user_pool: Option<
+        Box<
+            dyn Fn(
+                    CreateUserPoolRequest,
+                ) -> ::std::result::Result<
+                    CreateUserPoolResponse,
+                    RusotoError<CreateUserPoolError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_user_pool_client: Option<
+        Box<
+            dyn Fn(
+                    CreateUserPoolClientRequest,
+                ) -> ::std::result::Result<
+                    CreateUserPoolClientResponse,
+                    RusotoError<CreateUserPoolClientError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_user_pool_domain: Option<
+        Box<
+            dyn Fn(
+                    CreateUserPoolDomainRequest,
+                ) -> ::std::result::Result<
+                    CreateUserPoolDomainResponse,
+                    RusotoError<CreateUserPoolDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_group: Option<
+        Box<
+            dyn Fn(DeleteGroupRequest) -> ::std::result::Result<(), RusotoError<DeleteGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_identity_provider: Option<
+        Box<
+            dyn Fn(
+                    DeleteIdentityProviderRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteIdentityProviderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_resource_server: Option<
+        Box<
+            dyn Fn(
+                    DeleteResourceServerRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteResourceServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user: Option<
+        Box<
+            dyn Fn(DeleteUserRequest) -> ::std::result::Result<(), RusotoError<DeleteUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user_attributes: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserAttributesRequest,
+                ) -> ::std::result::Result<
+                    DeleteUserAttributesResponse,
+                    RusotoError<DeleteUserAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_user_pool: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserPoolRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteUserPoolError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user_pool_client: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserPoolClientRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteUserPoolClientError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user_pool_domain: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserPoolDomainRequest,
+                ) -> ::std::result::Result<
+                    DeleteUserPoolDomainResponse,
+                    RusotoError<DeleteUserPoolDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_identity_provider: Option<
+        Box<
+            dyn Fn(
+                    DescribeIdentityProviderRequest,
+                ) -> ::std::result::Result<
+                    DescribeIdentityProviderResponse,
+                    RusotoError<DescribeIdentityProviderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_resource_server: Option<
+        Box<
+            dyn Fn(
+                    DescribeResourceServerRequest,
+                ) -> ::std::result::Result<
+                    DescribeResourceServerResponse,
+                    RusotoError<DescribeResourceServerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_risk_configuration: Option<
+        Box<
+            dyn Fn(
+                    DescribeRiskConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DescribeRiskConfigurationResponse,
+                    RusotoError<DescribeRiskConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user_import_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserImportJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeUserImportJobResponse,
+                    RusotoError<DescribeUserImportJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user_pool: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserPoolRequest,
+                ) -> ::std::result::Result<
+                    DescribeUserPoolResponse,
+                    RusotoError<DescribeUserPoolError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user_pool_client: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserPoolClientRequest,
+                ) -> ::std::result::Result<
+                    DescribeUserPoolClientResponse,
+                    RusotoError<DescribeUserPoolClientError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user_pool_domain: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserPoolDomainRequest,
+                ) -> ::std::result::Result<
+                    DescribeUserPoolDomainResponse,
+                    RusotoError<DescribeUserPoolDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    forget_device: Option<
+        Box<
+            dyn Fn(ForgetDeviceRequest) -> ::std::result::Result<(), RusotoError<ForgetDeviceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    forgot_password: Option<
+        Box<
+            dyn Fn(
+                    ForgotPasswordRequest,
+                ) -> ::std::result::Result<
+                    ForgotPasswordResponse,
+                    RusotoError<ForgotPasswordError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_csv_header: Option<
+        Box<
+            dyn Fn(
+                    GetCSVHeaderRequest,
+                )
+                    -> ::std::result::Result<GetCSVHeaderResponse, RusotoError<GetCSVHeaderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_device: Option<
+        Box<
+            dyn Fn(
+                    GetDeviceRequest,
+                )
+                    -> ::std::result::Result<GetDeviceResponse, RusotoError<GetDeviceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_group: Option<
+        Box<
+            dyn Fn(
+                    GetGroupRequest,
+                )
+                    -> ::std::result::Result<GetGroupResponse, RusotoError<GetGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_identity_provider_by_identifier: Option<
+        Box<
+            dyn Fn(
+                    GetIdentityProviderByIdentifierRequest,
+                ) -> ::std::result::Result<
+                    GetIdentityProviderByIdentifierResponse,
+                    RusotoError<GetIdentityProviderByIdentifierError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_signing_certificate: Option<
+        Box<
+            dyn Fn(
+                    GetSigningCertificateRequest,
+                ) -> ::std::result::Result<
+                    GetSigningCertificateResponse,
+                    RusotoError<GetSigningCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_ui_customization: Option<
+        Box<
+            dyn Fn(
+                    GetUICustomizationRequest,
+                ) -> ::std::result::Result<
+                    GetUICustomizationResponse,
+                    RusotoError<GetUICustomizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_user: Option<
+        Box<
+            dyn Fn(
+                    GetUserRequest,
+                )
+                    -> ::std::result::Result<GetUserResponse, RusotoError<GetUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_user_attribute_verification_code: Option<
+        Box<
+            dyn Fn(
+                    GetUserAttributeVerificationCodeRequest,
+                ) -> ::std::result::Result<
+                    GetUserAttributeVerificationCodeResponse,
+                    RusotoError<GetUserAttributeVerificationCodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_user_pool_mfa_config: Option<
+        Box<
+            dyn Fn(
+                    GetUserPoolMfaConfigRequest,
+                ) -> ::std::result::Result<
+                    GetUserPoolMfaConfigResponse,
+                    RusotoError<GetUserPoolMfaConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    global_sign_out: Option<
+        Box<
+            dyn Fn(
+                    GlobalSignOutRequest,
+                )
+                    -> ::std::result::Result<GlobalSignOutResponse, RusotoError<GlobalSignOutError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    initiate_auth: Option<
+        Box<
+            dyn Fn(
+                    InitiateAuthRequest,
+                )
+                    -> ::std::result::Result<InitiateAuthResponse, RusotoError<InitiateAuthError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_devices: Option<
+        Box<
+            dyn Fn(
+                    ListDevicesRequest,
+                )
+                    -> ::std::result::Result<ListDevicesResponse, RusotoError<ListDevicesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_groups: Option<
+        Box<
+            dyn Fn(
+                    ListGroupsRequest,
+                )
+                    -> ::std::result::Result<ListGroupsResponse, RusotoError<ListGroupsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_identity_providers: Option<
+        Box<
+            dyn Fn(
+                    ListIdentityProvidersRequest,
+                ) -> ::std::result::Result<
+                    ListIdentityProvidersResponse,
+                    RusotoError<ListIdentityProvidersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resource_servers: Option<
+        Box<
+            dyn Fn(
+                    ListResourceServersRequest,
+                ) -> ::std::result::Result<
+                    ListResourceServersResponse,
+                    RusotoError<ListResourceServersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_user_import_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListUserImportJobsRequest,
+                ) -> ::std::result::Result<
+                    ListUserImportJobsResponse,
+                    RusotoError<ListUserImportJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_user_pool_clients: Option<
+        Box<
+            dyn Fn(
+                    ListUserPoolClientsRequest,
+                ) -> ::std::result::Result<
+                    ListUserPoolClientsResponse,
+                    RusotoError<ListUserPoolClientsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_user_pools: Option<
+        Box<
+            dyn Fn(
+                    ListUserPoolsRequest,
+                )
+                    -> ::std::result::Result<ListUserPoolsResponse, RusotoError<ListUserPoolsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_users: Option<
+        Box<
+            dyn Fn(
+                    ListUsersRequest,
+                )
+                    -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_users_in_group: Option<
+        Box<
+            dyn Fn(
+                    ListUsersInGroupRequest,
+                ) -> ::std::result::Result<
+                    ListUsersInGroupResponse,
+                    RusotoError<ListUsersInGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    resend_confirmation_code: Option<
+        Box<
+            dyn Fn(
+                    ResendConfirmationCodeRequest,
+                ) -> ::std::result::Result<
+                    ResendConfirmationCodeResponse,
+                    RusotoError<ResendConfirmationCodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    respond_to_auth_challenge: Option<
+        Box<
+            dyn Fn(
+                    RespondToAuthChallengeRequest,
+                ) -> ::std::result::Result<
+                    RespondToAuthChallengeResponse,
+                    RusotoError<RespondToAuthChallengeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_risk_configuration: Option<
+        Box<
+            dyn Fn(
+                    SetRiskConfigurationRequest,
+                ) -> ::std::result::Result<
+                    SetRiskConfigurationResponse,
+                    RusotoError<SetRiskConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_ui_customization: Option<
+        Box<
+            dyn Fn(
+                    SetUICustomizationRequest,
+                ) -> ::std::result::Result<
+                    SetUICustomizationResponse,
+                    RusotoError<SetUICustomizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_user_mfa_preference: Option<
+        Box<
+            dyn Fn(
+                    SetUserMFAPreferenceRequest,
+                ) -> ::std::result::Result<
+                    SetUserMFAPreferenceResponse,
+                    RusotoError<SetUserMFAPreferenceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_user_pool_mfa_config: Option<
+        Box<
+            dyn Fn(
+                    SetUserPoolMfaConfigRequest,
+                ) -> ::std::result::Result<
+                    SetUserPoolMfaConfigResponse,
+                    RusotoError<SetUserPoolMfaConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_user_settings: Option<
+        Box<
+            dyn Fn(
+                    SetUserSettingsRequest,
+                ) -> ::std::result::Result<
+                    SetUserSettingsResponse,
+                    RusotoError<SetUserSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    sign_up: Option<
+        Box<
+            dyn Fn(SignUpRequest) -> ::std::result::Result<SignUpResponse, RusotoError<SignUpError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_user_import_job: Option<
+        Box<
+            dyn Fn(
+                    StartUserImportJobRequest,
+                ) -> ::std::result::Result<
+                    StartUserImportJobResponse,
+                    RusotoError<StartUserImportJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_user_import_job: Option<
+        Box<
+            dyn Fn(
+                    StopUserImportJobRequest,
+                ) -> ::std::result::Result<
+                    StopUserImportJobResponse,
+                    RusotoError<StopUserImportJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_auth_event_feedback: Option<
+        Box<
+            dyn Fn(
+                    UpdateAuthEventFeedbackRequest,
+                ) -> ::std::result::Result<
+                    UpdateAuthEventFeedbackResponse,
+                    RusotoError<UpdateAuthEventFeedbackError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_device_status: Option<
+        Box<
+            dyn Fn(
+                    UpdateDeviceStatusRequest,
+                ) -> ::std::result::Result<
+                    UpdateDeviceStatusResponse,
+                    RusotoError<UpdateDeviceStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateGroupRequest,
+                )
+                    -> ::std::result::Result<UpdateGroupResponse, RusotoError<UpdateGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_identity_provider: Option<
+        Box<
+            dyn Fn(
+                    UpdateIdentityProviderRequest,
+                ) -> ::std::result::Result<
+                    UpdateIdentityProviderResponse,
+                    RusotoError<UpdateIdentityProviderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_resource_server: Option<
+        Box<
+            dyn Fn(
+                    UpdateResourceServerRequest,
+                ) -> ::std::result::Result<
+                    UpdateResourceServerResponse,
+                    RusotoError<UpdateResourceServerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_user_attributes: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserAttributesRequest,
+                ) -> ::std::result::Result<
+                    UpdateUserAttributesResponse,
+                    RusotoError<UpdateUserAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_user_pool: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserPoolRequest,
+                ) -> ::std::result::Result<
+                    UpdateUserPoolResponse,
+                    RusotoError<UpdateUserPoolError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_user_pool_client: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserPoolClientRequest,
+                ) -> ::std::result::Result<
+                    UpdateUserPoolClientResponse,
+                    RusotoError<UpdateUserPoolClientError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_user_pool_domain: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserPoolDomainRequest,
+                ) -> ::std::result::Result<
+                    UpdateUserPoolDomainResponse,
+                    RusotoError<UpdateUserPoolDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    verify_software_token: Option<
+        Box<
+            dyn Fn(
+                    VerifySoftwareTokenRequest,
+                ) -> ::std::result::Result<
+                    VerifySoftwareTokenResponse,
+                    RusotoError<VerifySoftwareTokenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    verify_user_attribute: Option<
+        Box<
+            dyn Fn(
+                    VerifyUserAttributeRequest,
+                ) -> ::std::result::Result<
+                    VerifyUserAttributeResponse,
+                    RusotoError<VerifyUserAttributeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCognitoIdentityProvider {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCognitoIdentityProvider {
+        Default::default()
+    }
+
+    /// Answers `add_custom_attributes` with what `handler` returns for its input.
+    pub fn with_add_custom_attributes<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AddCustomAttributesRequest,
+            ) -> ::std::result::Result<
+                AddCustomAttributesResponse,
+                RusotoError<AddCustomAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_custom_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_add_user_to_group` with what `handler` returns for its input.
+    pub fn with_admin_add_user_to_group<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminAddUserToGroupRequest,
+            ) -> ::std::result::Result<(), RusotoError<AdminAddUserToGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_add_user_to_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_confirm_sign_up` with what `handler` returns for its input.
+    pub fn with_admin_confirm_sign_up<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminConfirmSignUpRequest,
+            ) -> ::std::result::Result<
+                AdminConfirmSignUpResponse,
+                RusotoError<AdminConfirmSignUpError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_confirm_sign_up = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_create_user` with what `handler` returns for its input.
+    pub fn with_admin_create_user<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminCreateUserRequest,
+            )
+                -> ::std::result::Result<AdminCreateUserResponse, RusotoError<AdminCreateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_create_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_delete_user` with what `handler` returns for its input.
+    pub fn with_admin_delete_user<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminDeleteUserRequest,
+            ) -> ::std::result::Result<(), RusotoError<AdminDeleteUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_delete_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_delete_user_attributes` with what `handler` returns for its input.
+    pub fn with_admin_delete_user_attributes<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminDeleteUserAttributesRequest,
+            ) -> ::std::result::Result<
+                AdminDeleteUserAttributesResponse,
+                RusotoError<AdminDeleteUserAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_delete_user_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_disable_provider_for_user` with what `handler` returns for its input.
+    pub fn with_admin_disable_provider_for_user<F>(
+        mut self,
+        handler: F,
+    ) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminDisableProviderForUserRequest,
+            ) -> ::std::result::Result<
+                AdminDisableProviderForUserResponse,
+                RusotoError<AdminDisableProviderForUserError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_disable_provider_for_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_disable_user` with what `handler` returns for its input.
+    pub fn with_admin_disable_user<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminDisableUserRequest,
+            ) -> ::std::result::Result<
+                AdminDisableUserResponse,
+                RusotoError<AdminDisableUserError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_disable_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_enable_user` with what `handler` returns for its input.
+    pub fn with_admin_enable_user<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminEnableUserRequest,
+            )
+                -> ::std::result::Result<AdminEnableUserResponse, RusotoError<AdminEnableUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_enable_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_forget_device` with what `handler` returns for its input.
+    pub fn with_admin_forget_device<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminForgetDeviceRequest,
+            ) -> ::std::result::Result<(), RusotoError<AdminForgetDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_forget_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_get_device` with what `handler` returns for its input.
+    pub fn with_admin_get_device<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminGetDeviceRequest,
+            )
+                -> ::std::result::Result<AdminGetDeviceResponse, RusotoError<AdminGetDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_get_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_get_user` with what `handler` returns for its input.
+    pub fn with_admin_get_user<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminGetUserRequest,
+            )
+                -> ::std::result::Result<AdminGetUserResponse, RusotoError<AdminGetUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_get_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_initiate_auth` with what `handler` returns for its input.
+    pub fn with_admin_initiate_auth<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminInitiateAuthRequest,
+            ) -> ::std::result::Result<
+                AdminInitiateAuthResponse,
+                RusotoError<AdminInitiateAuthError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_initiate_auth = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_link_provider_for_user` with what `handler` returns for its input.
+    pub fn with_admin_link_provider_for_user<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminLinkProviderForUserRequest,
+            ) -> ::std::result::Result<
+                AdminLinkProviderForUserResponse,
+                RusotoError<AdminLinkProviderForUserError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_link_provider_for_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_list_devices` with what `handler` returns for its input.
+    pub fn with_admin_list_devices<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminListDevicesRequest,
+            ) -> ::std::result::Result<
+                AdminListDevicesResponse,
+                RusotoError<AdminListDevicesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_list_devices = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_list_groups_for_user` with what `handler` returns for its input.
+    pub fn with_admin_list_groups_for_user<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminListGroupsForUserRequest,
+            ) -> ::std::result::Result<
+                AdminListGroupsForUserResponse,
+                RusotoError<AdminListGroupsForUserError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_list_groups_for_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_list_user_auth_events` with what `handler` returns for its input.
+    pub fn with_admin_list_user_auth_events<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminListUserAuthEventsRequest,
+            ) -> ::std::result::Result<
+                AdminListUserAuthEventsResponse,
+                RusotoError<AdminListUserAuthEventsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_list_user_auth_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_remove_user_from_group` with what `handler` returns for its input.
+    pub fn with_admin_remove_user_from_group<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminRemoveUserFromGroupRequest,
+            ) -> ::std::result::Result<(), RusotoError<AdminRemoveUserFromGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_remove_user_from_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_reset_user_password` with what `handler` returns for its input.
+    pub fn with_admin_reset_user_password<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminResetUserPasswordRequest,
+            ) -> ::std::result::Result<
+                AdminResetUserPasswordResponse,
+                RusotoError<AdminResetUserPasswordError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_reset_user_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_respond_to_auth_challenge` with what `handler` returns for its input.
+    pub fn with_admin_respond_to_auth_challenge<F>(
+        mut self,
+        handler: F,
+    ) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminRespondToAuthChallengeRequest,
+            ) -> ::std::result::Result<
+                AdminRespondToAuthChallengeResponse,
+                RusotoError<AdminRespondToAuthChallengeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_respond_to_auth_challenge = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_set_user_mfa_preference` with what `handler` returns for its input.
+    pub fn with_admin_set_user_mfa_preference<F>(
+        mut self,
+        handler: F,
+    ) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminSetUserMFAPreferenceRequest,
+            ) -> ::std::result::Result<
+                AdminSetUserMFAPreferenceResponse,
+                RusotoError<AdminSetUserMFAPreferenceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_set_user_mfa_preference = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_set_user_password` with what `handler` returns for its input.
+    pub fn with_admin_set_user_password<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminSetUserPasswordRequest,
+            ) -> ::std::result::Result<
+                AdminSetUserPasswordResponse,
+                RusotoError<AdminSetUserPasswordError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_set_user_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_set_user_settings` with what `handler` returns for its input.
+    pub fn with_admin_set_user_settings<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminSetUserSettingsRequest,
+            ) -> ::std::result::Result<
+                AdminSetUserSettingsResponse,
+                RusotoError<AdminSetUserSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_set_user_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_update_auth_event_feedback` with what `handler` returns for its input.
+    pub fn with_admin_update_auth_event_feedback<F>(
+        mut self,
+        handler: F,
+    ) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminUpdateAuthEventFeedbackRequest,
+            ) -> ::std::result::Result<
+                AdminUpdateAuthEventFeedbackResponse,
+                RusotoError<AdminUpdateAuthEventFeedbackError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_update_auth_event_feedback = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_update_device_status` with what `handler` returns for its input.
+    pub fn with_admin_update_device_status<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminUpdateDeviceStatusRequest,
+            ) -> ::std::result::Result<
+                AdminUpdateDeviceStatusResponse,
+                RusotoError<AdminUpdateDeviceStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_update_device_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_update_user_attributes` with what `handler` returns for its input.
+    pub fn with_admin_update_user_attributes<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminUpdateUserAttributesRequest,
+            ) -> ::std::result::Result<
+                AdminUpdateUserAttributesResponse,
+                RusotoError<AdminUpdateUserAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_update_user_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `admin_user_global_sign_out` with what `handler` returns for its input.
+    pub fn with_admin_user_global_sign_out<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AdminUserGlobalSignOutRequest,
+            ) -> ::std::result::Result<
+                AdminUserGlobalSignOutResponse,
+                RusotoError<AdminUserGlobalSignOutError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.admin_user_global_sign_out = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_software_token` with what `handler` returns for its input.
+    pub fn with_associate_software_token<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                AssociateSoftwareTokenRequest,
+            ) -> ::std::result::Result<
+                AssociateSoftwareTokenResponse,
+                RusotoError<AssociateSoftwareTokenError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_software_token = Some(Box::new(handler));
+        self
+    }
+    /// Answers `change_password` with what `handler` returns for its input.
+    pub fn with_change_password<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ChangePasswordRequest,
+            )
+                -> ::std::result::Result<ChangePasswordResponse, RusotoError<ChangePasswordError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.change_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `confirm_device` with what `handler` returns for its input.
+    pub fn with_confirm_device<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ConfirmDeviceRequest,
+            )
+                -> ::std::result::Result<ConfirmDeviceResponse, RusotoError<ConfirmDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.confirm_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `confirm_forgot_password` with what `handler` returns for its input.
+    pub fn with_confirm_forgot_password<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ConfirmForgotPasswordRequest,
+            ) -> ::std::result::Result<
+                ConfirmForgotPasswordResponse,
+                RusotoError<ConfirmForgotPasswordError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.confirm_forgot_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `confirm_sign_up` with what `handler` returns for its input.
+    pub fn with_confirm_sign_up<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ConfirmSignUpRequest,
+            )
+                -> ::std::result::Result<ConfirmSignUpResponse, RusotoError<ConfirmSignUpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.confirm_sign_up = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_group` with what `handler` returns for its input.
+    pub fn with_create_group<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                CreateGroupRequest,
+            )
+                -> ::std::result::Result<CreateGroupResponse, RusotoError<CreateGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_identity_provider` with what `handler` returns for its input.
+    pub fn with_create_identity_provider<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                CreateIdentityProviderRequest,
+            ) -> ::std::result::Result<
+                CreateIdentityProviderResponse,
+                RusotoError<CreateIdentityProviderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_identity_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_resource_server` with what `handler` returns for its input.
+    pub fn with_create_resource_server<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                CreateResourceServerRequest,
+            ) -> ::std::result::Result<
+                CreateResourceServerResponse,
+                RusotoError<CreateResourceServerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_resource_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user_import_job` with what `handler` returns for its input.
+    pub fn with_create_user_import_job<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                CreateUserImportJobRequest,
+            ) -> ::std::result::Result<
+                CreateUserImportJobResponse,
+                RusotoError<CreateUserImportJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user_import_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user_pool` with what `handler` returns for its input.
+    pub fn with_create_user_pool<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                CreateUserPoolRequest,
+            )
+                -> ::std::result::Result<CreateUserPoolResponse, RusotoError<CreateUserPoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user_pool_client` with what `handler` returns for its input.
+    pub fn with_create_user_pool_client<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                CreateUserPoolClientRequest,
+            ) -> ::std::result::Result<
+                CreateUserPoolClientResponse,
+                RusotoError<CreateUserPoolClientError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user_pool_client = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user_pool_domain` with what `handler` returns for its input.
+    pub fn with_create_user_pool_domain<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                CreateUserPoolDomainRequest,
+            ) -> ::std::result::Result<
+                CreateUserPoolDomainResponse,
+                RusotoError<CreateUserPoolDomainError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user_pool_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_group` with what `handler` returns for its input.
+    pub fn with_delete_group<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(DeleteGroupRequest) -> ::std::result::Result<(), RusotoError<DeleteGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_identity_provider` with what `handler` returns for its input.
+    pub fn with_delete_identity_provider<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DeleteIdentityProviderRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteIdentityProviderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_identity_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_resource_server` with what `handler` returns for its input.
+    pub fn with_delete_resource_server<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DeleteResourceServerRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteResourceServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_resource_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user` with what `handler` returns for its input.
+    pub fn with_delete_user<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(DeleteUserRequest) -> ::std::result::Result<(), RusotoError<DeleteUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user_attributes` with what `handler` returns for its input.
+    pub fn with_delete_user_attributes<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DeleteUserAttributesRequest,
+            ) -> ::std::result::Result<
+                DeleteUserAttributesResponse,
+                RusotoError<DeleteUserAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user_pool` with what `handler` returns for its input.
+    pub fn with_delete_user_pool<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(DeleteUserPoolRequest) -> ::std::result::Result<(), RusotoError<DeleteUserPoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user_pool_client` with what `handler` returns for its input.
+    pub fn with_delete_user_pool_client<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DeleteUserPoolClientRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteUserPoolClientError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user_pool_client = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user_pool_domain` with what `handler` returns for its input.
+    pub fn with_delete_user_pool_domain<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DeleteUserPoolDomainRequest,
+            ) -> ::std::result::Result<
+                DeleteUserPoolDomainResponse,
+                RusotoError<DeleteUserPoolDomainError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user_pool_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_identity_provider` with what `handler` returns for its input.
+    pub fn with_describe_identity_provider<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DescribeIdentityProviderRequest,
+            ) -> ::std::result::Result<
+                DescribeIdentityProviderResponse,
+                RusotoError<DescribeIdentityProviderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_identity_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_resource_server` with what `handler` returns for its input.
+    pub fn with_describe_resource_server<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DescribeResourceServerRequest,
+            ) -> ::std::result::Result<
+                DescribeResourceServerResponse,
+                RusotoError<DescribeResourceServerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_resource_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_risk_configuration` with what `handler` returns for its input.
+    pub fn with_describe_risk_configuration<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DescribeRiskConfigurationRequest,
+            ) -> ::std::result::Result<
+                DescribeRiskConfigurationResponse,
+                RusotoError<DescribeRiskConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_risk_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user_import_job` with what `handler` returns for its input.
+    pub fn with_describe_user_import_job<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DescribeUserImportJobRequest,
+            ) -> ::std::result::Result<
+                DescribeUserImportJobResponse,
+                RusotoError<DescribeUserImportJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user_import_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user_pool` with what `handler` returns for its input.
+    pub fn with_describe_user_pool<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DescribeUserPoolRequest,
+            ) -> ::std::result::Result<
+                DescribeUserPoolResponse,
+                RusotoError<DescribeUserPoolError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user_pool_client` with what `handler` returns for its input.
+    pub fn with_describe_user_pool_client<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DescribeUserPoolClientRequest,
+            ) -> ::std::result::Result<
+                DescribeUserPoolClientResponse,
+                RusotoError<DescribeUserPoolClientError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user_pool_client = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user_pool_domain` with what `handler` returns for its input.
+    pub fn with_describe_user_pool_domain<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                DescribeUserPoolDomainRequest,
+            ) -> ::std::result::Result<
+                DescribeUserPoolDomainResponse,
+                RusotoError<DescribeUserPoolDomainError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user_pool_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `forget_device` with what `handler` returns for its input.
+    pub fn with_forget_device<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(ForgetDeviceRequest) -> ::std::result::Result<(), RusotoError<ForgetDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.forget_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `forgot_password` with what `handler` returns for its input.
+    pub fn with_forgot_password<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ForgotPasswordRequest,
+            )
+                -> ::std::result::Result<ForgotPasswordResponse, RusotoError<ForgotPasswordError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.forgot_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_csv_header` with what `handler` returns for its input.
+    pub fn with_get_csv_header<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                GetCSVHeaderRequest,
+            )
+                -> ::std::result::Result<GetCSVHeaderResponse, RusotoError<GetCSVHeaderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_csv_header = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_device` with what `handler` returns for its input.
+    pub fn with_get_device<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                GetDeviceRequest,
+            ) -> ::std::result::Result<GetDeviceResponse, RusotoError<GetDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_group` with what `handler` returns for its input.
+    pub fn with_get_group<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                GetGroupRequest,
+            ) -> ::std::result::Result<GetGroupResponse, RusotoError<GetGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_identity_provider_by_identifier` with what `handler` returns for its input.
+    pub fn with_get_identity_provider_by_identifier<F>(
+        mut self,
+        handler: F,
+    ) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                GetIdentityProviderByIdentifierRequest,
+            ) -> ::std::result::Result<
+                GetIdentityProviderByIdentifierResponse,
+                RusotoError<GetIdentityProviderByIdentifierError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_identity_provider_by_identifier = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_signing_certificate` with what `handler` returns for its input.
+    pub fn with_get_signing_certificate<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                GetSigningCertificateRequest,
+            ) -> ::std::result::Result<
+                GetSigningCertificateResponse,
+                RusotoError<GetSigningCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_signing_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_ui_customization` with what `handler` returns for its input.
+    pub fn with_get_ui_customization<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                GetUICustomizationRequest,
+            ) -> ::std::result::Result<
+                GetUICustomizationResponse,
+                RusotoError<GetUICustomizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_ui_customization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_user` with what `handler` returns for its input.
+    pub fn with_get_user<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(GetUserRequest) -> ::std::result::Result<GetUserResponse, RusotoError<GetUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_user_attribute_verification_code` with what `handler` returns for its input.
+    pub fn with_get_user_attribute_verification_code<F>(
+        mut self,
+        handler: F,
+    ) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                GetUserAttributeVerificationCodeRequest,
+            ) -> ::std::result::Result<
+                GetUserAttributeVerificationCodeResponse,
+                RusotoError<GetUserAttributeVerificationCodeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_user_attribute_verification_code = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_user_pool_mfa_config` with what `handler` returns for its input.
+    pub fn with_get_user_pool_mfa_config<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                GetUserPoolMfaConfigRequest,
+            ) -> ::std::result::Result<
+                GetUserPoolMfaConfigResponse,
+                RusotoError<GetUserPoolMfaConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_user_pool_mfa_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `global_sign_out` with what `handler` returns for its input.
+    pub fn with_global_sign_out<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                GlobalSignOutRequest,
+            )
+                -> ::std::result::Result<GlobalSignOutResponse, RusotoError<GlobalSignOutError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.global_sign_out = Some(Box::new(handler));
+        self
+    }
+    /// Answers `initiate_auth` with what `handler` returns for its input.
+    pub fn with_initiate_auth<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                InitiateAuthRequest,
+            )
+                -> ::std::result::Result<InitiateAuthResponse, RusotoError<InitiateAuthError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.initiate_auth = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_devices` with what `handler` returns for its input.
+    pub fn with_list_devices<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ListDevicesRequest,
+            )
+                -> ::std::result::Result<ListDevicesResponse, RusotoError<ListDevicesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_devices = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_groups` with what `handler` returns for its input.
+    pub fn with_list_groups<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ListGroupsRequest,
+            )
+                -> ::std::result::Result<ListGroupsResponse, RusotoError<ListGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_identity_providers` with what `handler` returns for its input.
+    pub fn with_list_identity_providers<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ListIdentityProvidersRequest,
+            ) -> ::std::result::Result<
+                ListIdentityProvidersResponse,
+                RusotoError<ListIdentityProvidersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_identity_providers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resource_servers` with what `handler` returns for its input.
+    pub fn with_list_resource_servers<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ListResourceServersRequest,
+            ) -> ::std::result::Result<
+                ListResourceServersResponse,
+                RusotoError<ListResourceServersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resource_servers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_user_import_jobs` with what `handler` returns for its input.
+    pub fn with_list_user_import_jobs<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ListUserImportJobsRequest,
+            ) -> ::std::result::Result<
+                ListUserImportJobsResponse,
+                RusotoError<ListUserImportJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_user_import_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_user_pool_clients` with what `handler` returns for its input.
+    pub fn with_list_user_pool_clients<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ListUserPoolClientsRequest,
+            ) -> ::std::result::Result<
+                ListUserPoolClientsResponse,
+                RusotoError<ListUserPoolClientsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_user_pool_clients = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_user_pools` with what `handler` returns for its input.
+    pub fn with_list_user_pools<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ListUserPoolsRequest,
+            )
+                -> ::std::result::Result<ListUserPoolsResponse, RusotoError<ListUserPoolsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_user_pools = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_users` with what `handler` returns for its input.
+    pub fn with_list_users<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ListUsersRequest,
+            ) -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_users_in_group` with what `handler` returns for its input.
+    pub fn with_list_users_in_group<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ListUsersInGroupRequest,
+            ) -> ::std::result::Result<
+                ListUsersInGroupResponse,
+                RusotoError<ListUsersInGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_users_in_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `resend_confirmation_code` with what `handler` returns for its input.
+    pub fn with_resend_confirmation_code<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                ResendConfirmationCodeRequest,
+            ) -> ::std::result::Result<
+                ResendConfirmationCodeResponse,
+                RusotoError<ResendConfirmationCodeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.resend_confirmation_code = Some(Box::new(handler));
+        self
+    }
+    /// Answers `respond_to_auth_challenge` with what `handler` returns for its input.
+    pub fn with_respond_to_auth_challenge<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                RespondToAuthChallengeRequest,
+            ) -> ::std::result::Result<
+                RespondToAuthChallengeResponse,
+                RusotoError<RespondToAuthChallengeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.respond_to_auth_challenge = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_risk_configuration` with what `handler` returns for its input.
+    pub fn with_set_risk_configuration<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                SetRiskConfigurationRequest,
+            ) -> ::std::result::Result<
+                SetRiskConfigurationResponse,
+                RusotoError<SetRiskConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_risk_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_ui_customization` with what `handler` returns for its input.
+    pub fn with_set_ui_customization<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                SetUICustomizationRequest,
+            ) -> ::std::result::Result<
+                SetUICustomizationResponse,
+                RusotoError<SetUICustomizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_ui_customization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_user_mfa_preference` with what `handler` returns for its input.
+    pub fn with_set_user_mfa_preference<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                SetUserMFAPreferenceRequest,
+            ) -> ::std::result::Result<
+                SetUserMFAPreferenceResponse,
+                RusotoError<SetUserMFAPreferenceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_user_mfa_preference = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_user_pool_mfa_config` with what `handler` returns for its input.
+    pub fn with_set_user_pool_mfa_config<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                SetUserPoolMfaConfigRequest,
+            ) -> ::std::result::Result<
+                SetUserPoolMfaConfigResponse,
+                RusotoError<SetUserPoolMfaConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_user_pool_mfa_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_user_settings` with what `handler` returns for its input.
+    pub fn with_set_user_settings<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                SetUserSettingsRequest,
+            )
+                -> ::std::result::Result<SetUserSettingsResponse, RusotoError<SetUserSettingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_user_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `sign_up` with what `handler` returns for its input.
+    pub fn with_sign_up<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(SignUpRequest) -> ::std::result::Result<SignUpResponse, RusotoError<SignUpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.sign_up = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_user_import_job` with what `handler` returns for its input.
+    pub fn with_start_user_import_job<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                StartUserImportJobRequest,
+            ) -> ::std::result::Result<
+                StartUserImportJobResponse,
+                RusotoError<StartUserImportJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_user_import_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_user_import_job` with what `handler` returns for its input.
+    pub fn with_stop_user_import_job<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                StopUserImportJobRequest,
+            ) -> ::std::result::Result<
+                StopUserImportJobResponse,
+                RusotoError<StopUserImportJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_user_import_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_auth_event_feedback` with what `handler` returns for its input.
+    pub fn with_update_auth_event_feedback<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                UpdateAuthEventFeedbackRequest,
+            ) -> ::std::result::Result<
+                UpdateAuthEventFeedbackResponse,
+                RusotoError<UpdateAuthEventFeedbackError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_auth_event_feedback = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_device_status` with what `handler` returns for its input.
+    pub fn with_update_device_status<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                UpdateDeviceStatusRequest,
+            ) -> ::std::result::Result<
+                UpdateDeviceStatusResponse,
+                RusotoError<UpdateDeviceStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_device_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_group` with what `handler` returns for its input.
+    pub fn with_update_group<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                UpdateGroupRequest,
+            )
+                -> ::std::result::Result<UpdateGroupResponse, RusotoError<UpdateGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_identity_provider` with what `handler` returns for its input.
+    pub fn with_update_identity_provider<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                UpdateIdentityProviderRequest,
+            ) -> ::std::result::Result<
+                UpdateIdentityProviderResponse,
+                RusotoError<UpdateIdentityProviderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_identity_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_resource_server` with what `handler` returns for its input.
+    pub fn with_update_resource_server<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                UpdateResourceServerRequest,
+            ) -> ::std::result::Result<
+                UpdateResourceServerResponse,
+                RusotoError<UpdateResourceServerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_resource_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_attributes` with what `handler` returns for its input.
+    pub fn with_update_user_attributes<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                UpdateUserAttributesRequest,
+            ) -> ::std::result::Result<
+                UpdateUserAttributesResponse,
+                RusotoError<UpdateUserAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_pool` with what `handler` returns for its input.
+    pub fn with_update_user_pool<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                UpdateUserPoolRequest,
+            )
+                -> ::std::result::Result<UpdateUserPoolResponse, RusotoError<UpdateUserPoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_pool_client` with what `handler` returns for its input.
+    pub fn with_update_user_pool_client<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                UpdateUserPoolClientRequest,
+            ) -> ::std::result::Result<
+                UpdateUserPoolClientResponse,
+                RusotoError<UpdateUserPoolClientError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_pool_client = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_pool_domain` with what `handler` returns for its input.
+    pub fn with_update_user_pool_domain<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                UpdateUserPoolDomainRequest,
+            ) -> ::std::result::Result<
+                UpdateUserPoolDomainResponse,
+                RusotoError<UpdateUserPoolDomainError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_pool_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `verify_software_token` with what `handler` returns for its input.
+    pub fn with_verify_software_token<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                VerifySoftwareTokenRequest,
+            ) -> ::std::result::Result<
+                VerifySoftwareTokenResponse,
+                RusotoError<VerifySoftwareTokenError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.verify_software_token = Some(Box::new(handler));
+        self
+    }
+    /// Answers `verify_user_attribute` with what `handler` returns for its input.
+    pub fn with_verify_user_attribute<F>(mut self, handler: F) -> MockCognitoIdentityProvider
+    where
+        F: Fn(
+                VerifyUserAttributeRequest,
+            ) -> ::std::result::Result<
+                VerifyUserAttributeResponse,
+                RusotoError<VerifyUserAttributeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.verify_user_attribute = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CognitoIdentityProvider for MockCognitoIdentityProvider {
+    fn add_custom_attributes(
+        &self,
+        input: AddCustomAttributesRequest,
+    ) -> RusotoFuture<AddCustomAttributesResponse, AddCustomAttributesError> {
+        match self.add_custom_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for add_custom_attributes"),
+        }
+    }
+    fn admin_add_user_to_group(
+        &self,
+        input: AdminAddUserToGroupRequest,
+    ) -> RusotoFuture<(), AdminAddUserToGroupError> {
+        match self.admin_add_user_to_group {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for admin_add_user_to_group")
+            }
+        }
+    }
+    fn admin_confirm_sign_up(
+        &self,
+        input: AdminConfirmSignUpRequest,
+    ) -> RusotoFuture<AdminConfirmSignUpResponse, AdminConfirmSignUpError> {
+        match self.admin_confirm_sign_up {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for admin_confirm_sign_up"),
+        }
+    }
+    fn admin_create_user(
+        &self,
+        input: AdminCreateUserRequest,
+    ) -> RusotoFuture<AdminCreateUserResponse, AdminCreateUserError> {
+        match self.admin_create_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for admin_create_user"),
+        }
+    }
+    fn admin_delete_user(
+        &self,
+        input: AdminDeleteUserRequest,
+    ) -> RusotoFuture<(), AdminDeleteUserError> {
+        match self.admin_delete_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for admin_delete_user"),
+        }
+    }
+    fn admin_delete_user_attributes(
+        &self,
+        input: AdminDeleteUserAttributesRequest,
+    ) -> RusotoFuture<AdminDeleteUserAttributesResponse, AdminDeleteUserAttributesError> {
+        match self.admin_delete_user_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCognitoIdentityProvider has no handler for admin_delete_user_attributes"
+            ),
+        }
+    }
+    fn admin_disable_provider_for_user(
+        &self,
+        input: AdminDisableProviderForUserRequest,
+    ) -> RusotoFuture<AdminDisableProviderForUserResponse, AdminDisableProviderForUserError> {
+        match self.admin_disable_provider_for_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCognitoIdentityProvider has no handler for admin_disable_provider_for_user"
+            ),
+        }
+    }
+    fn admin_disable_user(
+        &self,
+        input: AdminDisableUserRequest,
+    ) -> RusotoFuture<AdminDisableUserResponse, AdminDisableUserError> {
+        match self.admin_disable_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for admin_disable_user"),
+        }
+    }
+    fn admin_enable_user(
+        &self,
+        input: AdminEnableUserRequest,
+    ) -> RusotoFuture<AdminEnableUserResponse, AdminEnableUserError> {
+        match self.admin_enable_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for admin_enable_user"),
+        }
+    }
+    fn admin_forget_device(
+        &self,
+        input: AdminForgetDeviceRequest,
+    ) -> RusotoFuture<(), AdminForgetDeviceError> {
+        match self.admin_forget_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for admin_forget_device"),
+        }
+    }
+    fn admin_get_device(
+        &self,
+        input: AdminGetDeviceRequest,
+    ) -> RusotoFuture<AdminGetDeviceResponse, AdminGetDeviceError> {
+        match self.admin_get_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for admin_get_device"),
+        }
+    }
+    fn admin_get_user(
+        &self,
+        input: AdminGetUserRequest,
+    ) -> RusotoFuture<AdminGetUserResponse, AdminGetUserError> {
+        match self.admin_get_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for admin_get_user"),
+        }
+    }
+    fn admin_initiate_auth(
+        &self,
+        input: AdminInitiateAuthRequest,
+    ) -> RusotoFuture<AdminInitiateAuthResponse, AdminInitiateAuthError> {
+        match self.admin_initiate_auth {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for admin_initiate_auth"),
+        }
+    }
+    fn admin_link_provider_for_user(
+        &self,
+        input: AdminLinkProviderForUserRequest,
+    ) -> RusotoFuture<AdminLinkProviderForUserResponse, AdminLinkProviderForUserError> {
+        match self.admin_link_provider_for_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCognitoIdentityProvider has no handler for admin_link_provider_for_user"
+            ),
+        }
+    }
+    fn admin_list_devices(
+        &self,
+        input: AdminListDevicesRequest,
+    ) -> RusotoFuture<AdminListDevicesResponse, AdminListDevicesError> {
+        match self.admin_list_devices {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for admin_list_devices"),
+        }
+    }
+    fn admin_list_groups_for_user(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> RusotoFuture<AdminListGroupsForUserResponse, AdminListGroupsForUserError> {
+        match self.admin_list_groups_for_user {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for admin_list_groups_for_user")
+            }
+        }
+    }
+    fn admin_list_user_auth_events(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> RusotoFuture<AdminListUserAuthEventsResponse, AdminListUserAuthEventsError> {
+        match self.admin_list_user_auth_events {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for admin_list_user_auth_events")
+            }
+        }
+    }
+    fn admin_remove_user_from_group(
+        &self,
+        input: AdminRemoveUserFromGroupRequest,
+    ) -> RusotoFuture<(), AdminRemoveUserFromGroupError> {
+        match self.admin_remove_user_from_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCognitoIdentityProvider has no handler for admin_remove_user_from_group"
+            ),
+        }
+    }
+    fn admin_reset_user_password(
+        &self,
+        input: AdminResetUserPasswordRequest,
+    ) -> RusotoFuture<AdminResetUserPasswordResponse, AdminResetUserPasswordError> {
+        match self.admin_reset_user_password {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for admin_reset_user_password")
+            }
+        }
+    }
+    fn admin_respond_to_auth_challenge(
+        &self,
+        input: AdminRespondToAuthChallengeRequest,
+    ) -> RusotoFuture<AdminRespondToAuthChallengeResponse, AdminRespondToAuthChallengeError> {
+        match self.admin_respond_to_auth_challenge {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCognitoIdentityProvider has no handler for admin_respond_to_auth_challenge"
+            ),
+        }
+    }
+    fn admin_set_user_mfa_preference(
+        &self,
+        input: AdminSetUserMFAPreferenceRequest,
+    ) -> RusotoFuture<AdminSetUserMFAPreferenceResponse, AdminSetUserMFAPreferenceError> {
+        match self.admin_set_user_mfa_preference {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCognitoIdentityProvider has no handler for admin_set_user_mfa_preference"
+            ),
+        }
+    }
+    fn admin_set_user_password(
+        &self,
+        input: AdminSetUserPasswordRequest,
+    ) -> RusotoFuture<AdminSetUserPasswordResponse, AdminSetUserPasswordError> {
+        match self.admin_set_user_password {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for admin_set_user_password")
+            }
+        }
+    }
+    fn admin_set_user_settings(
+        &self,
+        input: AdminSetUserSettingsRequest,
+    ) -> RusotoFuture<AdminSetUserSettingsResponse, AdminSetUserSettingsError> {
+        match self.admin_set_user_settings {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for admin_set_user_settings")
+            }
+        }
+    }
+    fn admin_update_auth_event_feedback(
+        &self,
+        input: AdminUpdateAuthEventFeedbackRequest,
+    ) -> RusotoFuture<AdminUpdateAuthEventFeedbackResponse, AdminUpdateAuthEventFeedbackError> {
+        match self.admin_update_auth_event_feedback {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCognitoIdentityProvider has no handler for admin_update_auth_event_feedback"
+            ),
+        }
+    }
+    fn admin_update_device_status(
+        &self,
+        input: AdminUpdateDeviceStatusRequest,
+    ) -> RusotoFuture<AdminUpdateDeviceStatusResponse, AdminUpdateDeviceStatusError> {
+        match self.admin_update_device_status {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for admin_update_device_status")
+            }
+        }
+    }
+    fn admin_update_user_attributes(
+        &self,
+        input: AdminUpdateUserAttributesRequest,
+    ) -> RusotoFuture<AdminUpdateUserAttributesResponse, AdminUpdateUserAttributesError> {
+        match self.admin_update_user_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockCognitoIdentityProvider has no handler for admin_update_user_attributes"
+            ),
+        }
+    }
+    fn admin_user_global_sign_out(
+        &self,
+        input: AdminUserGlobalSignOutRequest,
+    ) -> RusotoFuture<AdminUserGlobalSignOutResponse, AdminUserGlobalSignOutError> {
+        match self.admin_user_global_sign_out {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for admin_user_global_sign_out")
+            }
+        }
+    }
+    fn associate_software_token(
+        &self,
+        input: AssociateSoftwareTokenRequest,
+    ) -> RusotoFuture<AssociateSoftwareTokenResponse, AssociateSoftwareTokenError> {
+        match self.associate_software_token {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for associate_software_token")
+            }
+        }
+    }
+    fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> RusotoFuture<ChangePasswordResponse, ChangePasswordError> {
+        match self.change_password {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for change_password"),
+        }
+    }
+    fn confirm_device(
+        &self,
+        input: ConfirmDeviceRequest,
+    ) -> RusotoFuture<ConfirmDeviceResponse, ConfirmDeviceError> {
+        match self.confirm_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for confirm_device"),
+        }
+    }
+    fn confirm_forgot_password(
+        &self,
+        input: ConfirmForgotPasswordRequest,
+    ) -> RusotoFuture<ConfirmForgotPasswordResponse, ConfirmForgotPasswordError> {
+        match self.confirm_forgot_password {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for confirm_forgot_password")
+            }
+        }
+    }
+    fn confirm_sign_up(
+        &self,
+        input: ConfirmSignUpRequest,
+    ) -> RusotoFuture<ConfirmSignUpResponse, ConfirmSignUpError> {
+        match self.confirm_sign_up {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for confirm_sign_up"),
+        }
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        match self.create_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for create_group"),
+        }
+    }
+    fn create_identity_provider(
+        &self,
+        input: CreateIdentityProviderRequest,
+    ) -> RusotoFuture<CreateIdentityProviderResponse, CreateIdentityProviderError> {
+        match self.create_identity_provider {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for create_identity_provider")
+            }
+        }
+    }
+    fn create_resource_server(
+        &self,
+        input: CreateResourceServerRequest,
+    ) -> RusotoFuture<CreateResourceServerResponse, CreateResourceServerError> {
+        match self.create_resource_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for create_resource_server"),
+        }
+    }
+    fn create_user_import_job(
+        &self,
+        input: CreateUserImportJobRequest,
+    ) -> RusotoFuture<CreateUserImportJobResponse, CreateUserImportJobError> {
+        match self.create_user_import_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for create_user_import_job"),
+        }
+    }
+    fn create_user_pool(
+        &self,
+        input: CreateUserPoolRequest,
+    ) -> RusotoFuture<CreateUserPoolResponse, CreateUserPoolError> {
+        match self.create_user_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for create_user_pool"),
+        }
+    }
+    fn create_user_pool_client(
+        &self,
+        input: CreateUserPoolClientRequest,
+    ) -> RusotoFuture<CreateUserPoolClientResponse, CreateUserPoolClientError> {
+        match self.create_user_pool_client {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for create_user_pool_client")
+            }
+        }
+    }
+    fn create_user_pool_domain(
+        &self,
+        input: CreateUserPoolDomainRequest,
+    ) -> RusotoFuture<CreateUserPoolDomainResponse, CreateUserPoolDomainError> {
+        match self.create_user_pool_domain {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for create_user_pool_domain")
+            }
+        }
+    }
+    fn delete_group(&self, input: DeleteGroupRequest) -> RusotoFuture<(), DeleteGroupError> {
+        match self.delete_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for delete_group"),
+        }
+    }
+    fn delete_identity_provider(
+        &self,
+        input: DeleteIdentityProviderRequest,
+    ) -> RusotoFuture<(), DeleteIdentityProviderError> {
+        match self.delete_identity_provider {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for delete_identity_provider")
+            }
+        }
+    }
+    fn delete_resource_server(
+        &self,
+        input: DeleteResourceServerRequest,
+    ) -> RusotoFuture<(), DeleteResourceServerError> {
+        match self.delete_resource_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for delete_resource_server"),
+        }
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        match self.delete_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for delete_user"),
+        }
+    }
+    fn delete_user_attributes(
+        &self,
+        input: DeleteUserAttributesRequest,
+    ) -> RusotoFuture<DeleteUserAttributesResponse, DeleteUserAttributesError> {
+        match self.delete_user_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for delete_user_attributes"),
+        }
+    }
+    fn delete_user_pool(
+        &self,
+        input: DeleteUserPoolRequest,
+    ) -> RusotoFuture<(), DeleteUserPoolError> {
+        match self.delete_user_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for delete_user_pool"),
+        }
+    }
+    fn delete_user_pool_client(
+        &self,
+        input: DeleteUserPoolClientRequest,
+    ) -> RusotoFuture<(), DeleteUserPoolClientError> {
+        match self.delete_user_pool_client {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for delete_user_pool_client")
+            }
+        }
+    }
+    fn delete_user_pool_domain(
+        &self,
+        input: DeleteUserPoolDomainRequest,
+    ) -> RusotoFuture<DeleteUserPoolDomainResponse, DeleteUserPoolDomainError> {
+        match self.delete_user_pool_domain {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for delete_user_pool_domain")
+            }
+        }
+    }
+    fn describe_identity_provider(
+        &self,
+        input: DescribeIdentityProviderRequest,
+    ) -> RusotoFuture<DescribeIdentityProviderResponse, DescribeIdentityProviderError> {
+        match self.describe_identity_provider {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for describe_identity_provider")
+            }
+        }
+    }
+    fn describe_resource_server(
+        &self,
+        input: DescribeResourceServerRequest,
+    ) -> RusotoFuture<DescribeResourceServerResponse, DescribeResourceServerError> {
+        match self.describe_resource_server {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for describe_resource_server")
+            }
+        }
+    }
+    fn describe_risk_configuration(
+        &self,
+        input: DescribeRiskConfigurationRequest,
+    ) -> RusotoFuture<DescribeRiskConfigurationResponse, DescribeRiskConfigurationError> {
+        match self.describe_risk_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for describe_risk_configuration")
+            }
+        }
+    }
+    fn describe_user_import_job(
+        &self,
+        input: DescribeUserImportJobRequest,
+    ) -> RusotoFuture<DescribeUserImportJobResponse, DescribeUserImportJobError> {
+        match self.describe_user_import_job {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for describe_user_import_job")
+            }
+        }
+    }
+    fn describe_user_pool(
+        &self,
+        input: DescribeUserPoolRequest,
+    ) -> RusotoFuture<DescribeUserPoolResponse, DescribeUserPoolError> {
+        match self.describe_user_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for describe_user_pool"),
+        }
+    }
+    fn describe_user_pool_client(
+        &self,
+        input: DescribeUserPoolClientRequest,
+    ) -> RusotoFuture<DescribeUserPoolClientResponse, DescribeUserPoolClientError> {
+        match self.describe_user_pool_client {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for describe_user_pool_client")
+            }
+        }
+    }
+    fn describe_user_pool_domain(
+        &self,
+        input: DescribeUserPoolDomainRequest,
+    ) -> RusotoFuture<DescribeUserPoolDomainResponse, DescribeUserPoolDomainError> {
+        match self.describe_user_pool_domain {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for describe_user_pool_domain")
+            }
+        }
+    }
+    fn forget_device(&self, input: ForgetDeviceRequest) -> RusotoFuture<(), ForgetDeviceError> {
+        match self.forget_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for forget_device"),
+        }
+    }
+    fn forgot_password(
+        &self,
+        input: ForgotPasswordRequest,
+    ) -> RusotoFuture<ForgotPasswordResponse, ForgotPasswordError> {
+        match self.forgot_password {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for forgot_password"),
+        }
+    }
+    fn get_csv_header(
+        &self,
+        input: GetCSVHeaderRequest,
+    ) -> RusotoFuture<GetCSVHeaderResponse, GetCSVHeaderError> {
+        match self.get_csv_header {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for get_csv_header"),
+        }
+    }
+    fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
+        match self.get_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for get_device"),
+        }
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        match self.get_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for get_group"),
+        }
+    }
+    fn get_identity_provider_by_identifier(
+        &self,
+        input: GetIdentityProviderByIdentifierRequest,
+    ) -> RusotoFuture<GetIdentityProviderByIdentifierResponse, GetIdentityProviderByIdentifierError>
+    {
+        match self.get_identity_provider_by_identifier {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockCognitoIdentityProvider has no handler for get_identity_provider_by_identifier"),
+}
+    }
+    fn get_signing_certificate(
+        &self,
+        input: GetSigningCertificateRequest,
+    ) -> RusotoFuture<GetSigningCertificateResponse, GetSigningCertificateError> {
+        match self.get_signing_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for get_signing_certificate")
+            }
+        }
+    }
+    fn get_ui_customization(
+        &self,
+        input: GetUICustomizationRequest,
+    ) -> RusotoFuture<GetUICustomizationResponse, GetUICustomizationError> {
+        match self.get_ui_customization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for get_ui_customization"),
+        }
+    }
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        match self.get_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for get_user"),
+        }
+    }
+    fn get_user_attribute_verification_code(
+        &self,
+        input: GetUserAttributeVerificationCodeRequest,
+    ) -> RusotoFuture<GetUserAttributeVerificationCodeResponse, GetUserAttributeVerificationCodeError>
+    {
+        match self.get_user_attribute_verification_code {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockCognitoIdentityProvider has no handler for get_user_attribute_verification_code"),
+}
+    }
+    fn get_user_pool_mfa_config(
+        &self,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> RusotoFuture<GetUserPoolMfaConfigResponse, GetUserPoolMfaConfigError> {
+        match self.get_user_pool_mfa_config {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for get_user_pool_mfa_config")
+            }
+        }
+    }
+    fn global_sign_out(
+        &self,
+        input: GlobalSignOutRequest,
+    ) -> RusotoFuture<GlobalSignOutResponse, GlobalSignOutError> {
+        match self.global_sign_out {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for global_sign_out"),
+        }
+    }
+    fn initiate_auth(
+        &self,
+        input: InitiateAuthRequest,
+    ) -> RusotoFuture<InitiateAuthResponse, InitiateAuthError> {
+        match self.initiate_auth {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for initiate_auth"),
+        }
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        match self.list_devices {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for list_devices"),
+        }
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        match self.list_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for list_groups"),
+        }
+    }
+    fn list_identity_providers(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> RusotoFuture<ListIdentityProvidersResponse, ListIdentityProvidersError> {
+        match self.list_identity_providers {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for list_identity_providers")
+            }
+        }
+    }
+    fn list_resource_servers(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> RusotoFuture<ListResourceServersResponse, ListResourceServersError> {
+        match self.list_resource_servers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for list_resource_servers"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_user_import_jobs(
+        &self,
+        input: ListUserImportJobsRequest,
+    ) -> RusotoFuture<ListUserImportJobsResponse, ListUserImportJobsError> {
+        match self.list_user_import_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for list_user_import_jobs"),
+        }
+    }
+    fn list_user_pool_clients(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> RusotoFuture<ListUserPoolClientsResponse, ListUserPoolClientsError> {
+        match self.list_user_pool_clients {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for list_user_pool_clients"),
+        }
+    }
+    fn list_user_pools(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> RusotoFuture<ListUserPoolsResponse, ListUserPoolsError> {
+        match self.list_user_pools {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for list_user_pools"),
+        }
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        match self.list_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for list_users"),
+        }
+    }
+    fn list_users_in_group(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> RusotoFuture<ListUsersInGroupResponse, ListUsersInGroupError> {
+        match self.list_users_in_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for list_users_in_group"),
+        }
+    }
+    fn resend_confirmation_code(
+        &self,
+        input: ResendConfirmationCodeRequest,
+    ) -> RusotoFuture<ResendConfirmationCodeResponse, ResendConfirmationCodeError> {
+        match self.resend_confirmation_code {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for resend_confirmation_code")
+            }
+        }
+    }
+    fn respond_to_auth_challenge(
+        &self,
+        input: RespondToAuthChallengeRequest,
+    ) -> RusotoFuture<RespondToAuthChallengeResponse, RespondToAuthChallengeError> {
+        match self.respond_to_auth_challenge {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for respond_to_auth_challenge")
+            }
+        }
+    }
+    fn set_risk_configuration(
+        &self,
+        input: SetRiskConfigurationRequest,
+    ) -> RusotoFuture<SetRiskConfigurationResponse, SetRiskConfigurationError> {
+        match self.set_risk_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for set_risk_configuration"),
+        }
+    }
+    fn set_ui_customization(
+        &self,
+        input: SetUICustomizationRequest,
+    ) -> RusotoFuture<SetUICustomizationResponse, SetUICustomizationError> {
+        match self.set_ui_customization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for set_ui_customization"),
+        }
+    }
+    fn set_user_mfa_preference(
+        &self,
+        input: SetUserMFAPreferenceRequest,
+    ) -> RusotoFuture<SetUserMFAPreferenceResponse, SetUserMFAPreferenceError> {
+        match self.set_user_mfa_preference {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for set_user_mfa_preference")
+            }
+        }
+    }
+    fn set_user_pool_mfa_config(
+        &self,
+        input: SetUserPoolMfaConfigRequest,
+    ) -> RusotoFuture<SetUserPoolMfaConfigResponse, SetUserPoolMfaConfigError> {
+        match self.set_user_pool_mfa_config {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for set_user_pool_mfa_config")
+            }
+        }
+    }
+    fn set_user_settings(
+        &self,
+        input: SetUserSettingsRequest,
+    ) -> RusotoFuture<SetUserSettingsResponse, SetUserSettingsError> {
+        match self.set_user_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for set_user_settings"),
+        }
+    }
+    fn sign_up(&self, input: SignUpRequest) -> RusotoFuture<SignUpResponse, SignUpError> {
+        match self.sign_up {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for sign_up"),
+        }
+    }
+    fn start_user_import_job(
+        &self,
+        input: StartUserImportJobRequest,
+    ) -> RusotoFuture<StartUserImportJobResponse, StartUserImportJobError> {
+        match self.start_user_import_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for start_user_import_job"),
+        }
+    }
+    fn stop_user_import_job(
+        &self,
+        input: StopUserImportJobRequest,
+    ) -> RusotoFuture<StopUserImportJobResponse, StopUserImportJobError> {
+        match self.stop_user_import_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for stop_user_import_job"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for untag_resource"),
+        }
+    }
+    fn update_auth_event_feedback(
+        &self,
+        input: UpdateAuthEventFeedbackRequest,
+    ) -> RusotoFuture<UpdateAuthEventFeedbackResponse, UpdateAuthEventFeedbackError> {
+        match self.update_auth_event_feedback {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for update_auth_event_feedback")
             }
-        })
+        }
     }
-
-    /// <p>Updates the device status.</p>
     fn update_device_status(
         &self,
         input: UpdateDeviceStatusRequest,
     ) -> RusotoFuture<UpdateDeviceStatusResponse, UpdateDeviceStatusError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.UpdateDeviceStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDeviceStatusResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDeviceStatusError::from_response(response))),
-                )
-            }
-        })
+        match self.update_device_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for update_device_status"),
+        }
     }
-
-    /// <p>Updates the specified group with the specified attributes.</p> <p>Requires developer credentials.</p>
     fn update_group(
         &self,
         input: UpdateGroupRequest,
     ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.UpdateGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateGroupError::from_response(response))),
-                )
-            }
-        })
+        match self.update_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for update_group"),
+        }
     }
-
-    /// <p>Updates identity provider information for a user pool.</p>
     fn update_identity_provider(
         &self,
         input: UpdateIdentityProviderRequest,
     ) -> RusotoFuture<UpdateIdentityProviderResponse, UpdateIdentityProviderError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.UpdateIdentityProvider",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateIdentityProviderResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateIdentityProviderError::from_response(response))
-                    }),
-                )
+        match self.update_identity_provider {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for update_identity_provider")
             }
-        })
+        }
     }
-
-    /// <p>Updates the name and scopes of resource server. All other fields are read-only.</p>
     fn update_resource_server(
         &self,
         input: UpdateResourceServerRequest,
     ) -> RusotoFuture<UpdateResourceServerResponse, UpdateResourceServerError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.UpdateResourceServer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateResourceServerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateResourceServerError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_resource_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for update_resource_server"),
+        }
     }
-
-    /// <p>Allows a user to update a specific attribute (one at a time).</p>
     fn update_user_attributes(
         &self,
         input: UpdateUserAttributesRequest,
     ) -> RusotoFuture<UpdateUserAttributesResponse, UpdateUserAttributesError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.UpdateUserAttributes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateUserAttributesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateUserAttributesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_user_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for update_user_attributes"),
+        }
     }
-
-    /// <p>Updates the specified user pool with the specified attributes. If you don't provide a value for an attribute, it will be set to the default value. You can get a list of the current user pool settings with .</p>
     fn update_user_pool(
         &self,
         input: UpdateUserPoolRequest,
     ) -> RusotoFuture<UpdateUserPoolResponse, UpdateUserPoolError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.UpdateUserPool",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateUserPoolResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateUserPoolError::from_response(response))),
-                )
-            }
-        })
+        match self.update_user_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for update_user_pool"),
+        }
     }
-
-    /// <p>Updates the specified user pool app client with the specified attributes. If you don't provide a value for an attribute, it will be set to the default value. You can get a list of the current user pool app client settings with .</p>
     fn update_user_pool_client(
         &self,
         input: UpdateUserPoolClientRequest,
     ) -> RusotoFuture<UpdateUserPoolClientResponse, UpdateUserPoolClientError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.UpdateUserPoolClient",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateUserPoolClientResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateUserPoolClientError::from_response(response))
-                    }),
-                )
+        match self.update_user_pool_client {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for update_user_pool_client")
             }
-        })
+        }
     }
-
-    /// <p>Updates the Secure Sockets Layer (SSL) certificate for the custom domain for your user pool.</p> <p>You can use this operation to provide the Amazon Resource Name (ARN) of a new certificate to Amazon Cognito. You cannot use it to change the domain for a user pool.</p> <p>A custom domain is used to host the Amazon Cognito hosted UI, which provides sign-up and sign-in pages for your application. When you set up a custom domain, you provide a certificate that you manage with AWS Certificate Manager (ACM). When necessary, you can use this operation to change the certificate that you applied to your custom domain.</p> <p>Usually, this is unnecessary following routine certificate renewal with ACM. When you renew your existing certificate in ACM, the ARN for your certificate remains the same, and your custom domain uses the new certificate automatically.</p> <p>However, if you replace your existing certificate with a new one, ACM gives the new certificate a new ARN. To apply the new certificate to your custom domain, you must provide this ARN to Amazon Cognito.</p> <p>When you add your new certificate in ACM, you must choose US East (N. Virginia) as the AWS Region.</p> <p>After you submit your request, Amazon Cognito requires up to 1 hour to distribute your new certificate to your custom domain.</p> <p>For more information about adding a custom domain to your user pool, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/cognito-user-pools-add-custom-domain.html">Using Your Own Domain for the Hosted UI</a>.</p>
     fn update_user_pool_domain(
         &self,
         input: UpdateUserPoolDomainRequest,
     ) -> RusotoFuture<UpdateUserPoolDomainResponse, UpdateUserPoolDomainError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.UpdateUserPoolDomain",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateUserPoolDomainResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateUserPoolDomainError::from_response(response))
-                    }),
-                )
+        match self.update_user_pool_domain {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockCognitoIdentityProvider has no handler for update_user_pool_domain")
             }
-        })
+        }
     }
-
-    /// <p>Use this API to register a user's entered TOTP code and mark the user's software token MFA status as "verified" if successful. The request takes an access token or a session string, but not both.</p>
     fn verify_software_token(
         &self,
         input: VerifySoftwareTokenRequest,
     ) -> RusotoFuture<VerifySoftwareTokenResponse, VerifySoftwareTokenError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.VerifySoftwareToken",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VerifySoftwareTokenResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(VerifySoftwareTokenError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.verify_software_token {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for verify_software_token"),
+        }
     }
-
-    /// <p>Verifies the specified user attributes in the user pool.</p>
     fn verify_user_attribute(
         &self,
         input: VerifyUserAttributeRequest,
     ) -> RusotoFuture<VerifyUserAttributeResponse, VerifyUserAttributeError> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.VerifyUserAttribute",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VerifyUserAttributeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(VerifyUserAttributeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.verify_user_attribute {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoIdentityProvider has no handler for verify_user_attribute"),
+        }
     }
 }
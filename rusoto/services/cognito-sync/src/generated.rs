@@ -23,18 +23,36 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The input for the BulkPublish operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BulkPublishRequest {
     /// <p>A name-spaced GUID (for example, us-east-1:23EC4050-6AEA-7089-A2DD-08002EXAMPLE) created by Amazon Cognito. GUID generation is unique within a region.</p>
     #[serde(rename = "IdentityPoolId")]
     pub identity_pool_id: String,
 }
 
+impl Validate for BulkPublishRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>The output for the BulkPublish operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BulkPublishResponse {
     /// <p>A name-spaced GUID (for example, us-east-1:23EC4050-6AEA-7089-A2DD-08002EXAMPLE) created by Amazon Cognito. GUID generation is unique within a region.</p>
     #[serde(rename = "IdentityPoolId")]
@@ -59,9 +77,35 @@ pub struct CognitoStreams {
     pub streaming_status: Option<String>,
 }
 
+impl Validate for CognitoStreams {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "arn:aws:iam::\\d+:role/.*",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+    }
+}
+
 /// <p>A collection of data for an identity pool. An identity pool can have multiple datasets. A dataset is per identity and can be general or associated with a particular entity in an application (like a saved game). Datasets are automatically created if they don&#39;t exist. Data is synced by dataset, and a dataset can hold up to 1MB of key-value pairs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Dataset {
     /// <p>Date on which the dataset was created.</p>
     #[serde(rename = "CreationDate")]
@@ -95,6 +139,7 @@ pub struct Dataset {
 
 /// <p>A request to delete the specific dataset.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDatasetRequest {
     /// <p>A string of up to 128 characters. Allowed characters are a-z, A-Z, 0-9, &#39;_&#39; (underscore), &#39;-&#39; (dash), and &#39;.&#39; (dot).</p>
     #[serde(rename = "DatasetName")]
@@ -107,9 +152,47 @@ pub struct DeleteDatasetRequest {
     pub identity_pool_id: String,
 }
 
+impl Validate for DeleteDatasetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "[a-zA-Z0-9_.:-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Response to a successful DeleteDataset request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDatasetResponse {
     /// <p>A collection of data for an identity pool. An identity pool can have multiple datasets. A dataset is per identity and can be general or associated with a particular entity in an application (like a saved game). Datasets are automatically created if they don&#39;t exist. Data is synced by dataset, and a dataset can hold up to 1MB of key-value pairs.</p>
     #[serde(rename = "Dataset")]
@@ -119,6 +202,7 @@ pub struct DeleteDatasetResponse {
 
 /// <p>A request for meta data about a dataset (creation date, number of records, size) by owner and dataset name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDatasetRequest {
     /// <p>A string of up to 128 characters. Allowed characters are a-z, A-Z, 0-9, &#39;_&#39; (underscore), &#39;-&#39; (dash), and &#39;.&#39; (dot).</p>
     #[serde(rename = "DatasetName")]
@@ -131,9 +215,47 @@ pub struct DescribeDatasetRequest {
     pub identity_pool_id: String,
 }
 
+impl Validate for DescribeDatasetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "[a-zA-Z0-9_.:-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Response to a successful DescribeDataset request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDatasetResponse {
     /// <p>Meta data for a collection of data for an identity. An identity can have multiple datasets. A dataset can be general or associated with a particular entity in an application (like a saved game). Datasets are automatically created if they don&#39;t exist. Data is synced by dataset, and a dataset can hold up to 1MB of key-value pairs.</p>
     #[serde(rename = "Dataset")]
@@ -143,15 +265,32 @@ pub struct DescribeDatasetResponse {
 
 /// <p>A request for usage information about the identity pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeIdentityPoolUsageRequest {
     /// <p>A name-spaced GUID (for example, us-east-1:23EC4050-6AEA-7089-A2DD-08002EXAMPLE) created by Amazon Cognito. GUID generation is unique within a region.</p>
     #[serde(rename = "IdentityPoolId")]
     pub identity_pool_id: String,
 }
 
+impl Validate for DescribeIdentityPoolUsageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Response to a successful DescribeIdentityPoolUsage request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeIdentityPoolUsageResponse {
     /// <p>Information about the usage of the identity pool.</p>
     #[serde(rename = "IdentityPoolUsage")]
@@ -161,6 +300,7 @@ pub struct DescribeIdentityPoolUsageResponse {
 
 /// <p>A request for information about the usage of an identity pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeIdentityUsageRequest {
     /// <p>A name-spaced GUID (for example, us-east-1:23EC4050-6AEA-7089-A2DD-08002EXAMPLE) created by Amazon Cognito. GUID generation is unique within a region.</p>
     #[serde(rename = "IdentityId")]
@@ -170,9 +310,36 @@ pub struct DescribeIdentityUsageRequest {
     pub identity_pool_id: String,
 }
 
+impl Validate for DescribeIdentityUsageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>The response to a successful DescribeIdentityUsage request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeIdentityUsageResponse {
     /// <p>Usage information for the identity.</p>
     #[serde(rename = "IdentityUsage")]
@@ -182,15 +349,32 @@ pub struct DescribeIdentityUsageResponse {
 
 /// <p>The input for the GetBulkPublishDetails operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBulkPublishDetailsRequest {
     /// <p>A name-spaced GUID (for example, us-east-1:23EC4050-6AEA-7089-A2DD-08002EXAMPLE) created by Amazon Cognito. GUID generation is unique within a region.</p>
     #[serde(rename = "IdentityPoolId")]
     pub identity_pool_id: String,
 }
 
+impl Validate for GetBulkPublishDetailsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>The output for the GetBulkPublishDetails operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBulkPublishDetailsResponse {
     /// <p>If BulkPublishStatus is SUCCEEDED, the time the last bulk publish operation completed.</p>
     #[serde(rename = "BulkPublishCompleteTime")]
@@ -216,15 +400,32 @@ pub struct GetBulkPublishDetailsResponse {
 
 /// <p>A request for a list of the configured Cognito Events</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCognitoEventsRequest {
     /// <p>The Cognito Identity Pool ID for the request</p>
     #[serde(rename = "IdentityPoolId")]
     pub identity_pool_id: String,
 }
 
+impl Validate for GetCognitoEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>The response from the GetCognitoEvents request</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCognitoEventsResponse {
     /// <p>The Cognito Events returned from the GetCognitoEvents request</p>
     #[serde(rename = "Events")]
@@ -234,15 +435,32 @@ pub struct GetCognitoEventsResponse {
 
 /// <p>The input for the GetIdentityPoolConfiguration operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityPoolConfigurationRequest {
     /// <p>A name-spaced GUID (for example, us-east-1:23EC4050-6AEA-7089-A2DD-08002EXAMPLE) created by Amazon Cognito. This is the ID of the pool for which to return a configuration.</p>
     #[serde(rename = "IdentityPoolId")]
     pub identity_pool_id: String,
 }
 
+impl Validate for GetIdentityPoolConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>The output for the GetIdentityPoolConfiguration operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIdentityPoolConfigurationResponse {
     /// <p>Options to apply to this identity pool for Amazon Cognito streams.</p>
     #[serde(rename = "CognitoStreams")]
@@ -260,7 +478,7 @@ pub struct GetIdentityPoolConfigurationResponse {
 
 /// <p>Usage information for the identity pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IdentityPoolUsage {
     /// <p>Data storage information for the identity pool.</p>
     #[serde(rename = "DataStorage")]
@@ -282,7 +500,7 @@ pub struct IdentityPoolUsage {
 
 /// <p>Usage information for the identity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IdentityUsage {
     /// <p>Total data storage for this identity.</p>
     #[serde(rename = "DataStorage")]
@@ -308,6 +526,7 @@ pub struct IdentityUsage {
 
 /// <p>Request for a list of datasets for an identity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDatasetsRequest {
     /// <p>A name-spaced GUID (for example, us-east-1:23EC4050-6AEA-7089-A2DD-08002EXAMPLE) created by Amazon Cognito. GUID generation is unique within a region.</p>
     #[serde(rename = "IdentityId")]
@@ -325,9 +544,36 @@ pub struct ListDatasetsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDatasetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Returned for a successful ListDatasets request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDatasetsResponse {
     /// <p>Number of datasets returned.</p>
     #[serde(rename = "Count")]
@@ -345,6 +591,7 @@ pub struct ListDatasetsResponse {
 
 /// <p>A request for usage information on an identity pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIdentityPoolUsageRequest {
     /// <p>The maximum number of results to be returned.</p>
     #[serde(rename = "MaxResults")]
@@ -358,7 +605,7 @@ pub struct ListIdentityPoolUsageRequest {
 
 /// <p>Returned for a successful ListIdentityPoolUsage request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListIdentityPoolUsageResponse {
     /// <p>Total number of identities for the identity pool.</p>
     #[serde(rename = "Count")]
@@ -380,6 +627,7 @@ pub struct ListIdentityPoolUsageResponse {
 
 /// <p>A request for a list of records.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRecordsRequest {
     /// <p>A string of up to 128 characters. Allowed characters are a-z, A-Z, 0-9, &#39;_&#39; (underscore), &#39;-&#39; (dash), and &#39;.&#39; (dot).</p>
     #[serde(rename = "DatasetName")]
@@ -408,9 +656,47 @@ pub struct ListRecordsRequest {
     pub sync_session_token: Option<String>,
 }
 
+impl Validate for ListRecordsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "[a-zA-Z0-9_.:-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Returned for a successful ListRecordsRequest.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRecordsResponse {
     /// <p>Total number of records.</p>
     #[serde(rename = "Count")]
@@ -463,9 +749,37 @@ pub struct PushSync {
     pub role_arn: Option<String>,
 }
 
+impl Validate for PushSync {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "application_arns"), i);
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "arn:aws:sns:[-0-9a-z]+:\\d+:app/[A-Z_]+/[a-zA-Z0-9_.-]+",
+                );
+            }
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "arn:aws:iam::\\d+:role/.*",
+            );
+        }
+    }
+}
+
 /// <p>The basic data structure of a dataset.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Record {
     /// <p>The last modified date of the client device.</p>
     #[serde(rename = "DeviceLastModifiedDate")]
@@ -495,6 +809,7 @@ pub struct Record {
 
 /// <p>An update operation for a record.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RecordPatch {
     /// <p>The last modified date of the client device.</p>
     #[serde(rename = "DeviceLastModifiedDate")]
@@ -515,8 +830,28 @@ pub struct RecordPatch {
     pub value: Option<String>,
 }
 
+impl Validate for RecordPatch {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(1048575),
+            );
+        }
+    }
+}
+
 /// <p>A request to RegisterDevice.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterDeviceRequest {
     /// <p>The unique ID for this identity.</p>
     #[serde(rename = "IdentityId")]
@@ -532,9 +867,36 @@ pub struct RegisterDeviceRequest {
     pub token: String,
 }
 
+impl Validate for RegisterDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Response to a RegisterDevice request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterDeviceResponse {
     /// <p>The unique ID generated for this device by Cognito.</p>
     #[serde(rename = "DeviceId")]
@@ -544,6 +906,7 @@ pub struct RegisterDeviceResponse {
 
 /// <p>A request to configure Cognito Events</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetCognitoEventsRequest {
     /// <p>The events to configure</p>
     #[serde(rename = "Events")]
@@ -553,8 +916,31 @@ pub struct SetCognitoEventsRequest {
     pub identity_pool_id: String,
 }
 
+impl Validate for SetCognitoEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "events"),
+            self.events.len(),
+            None,
+            Some(1),
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>The input for the SetIdentityPoolConfiguration operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityPoolConfigurationRequest {
     /// <p>Options to apply to this identity pool for Amazon Cognito streams.</p>
     #[serde(rename = "CognitoStreams")]
@@ -569,9 +955,31 @@ pub struct SetIdentityPoolConfigurationRequest {
     pub push_sync: Option<PushSync>,
 }
 
+impl Validate for SetIdentityPoolConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cognito_streams {
+            value.validate_at(&field_path(path, "cognito_streams"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        if let Some(ref value) = self.push_sync {
+            value.validate_at(&field_path(path, "push_sync"), errors);
+        }
+    }
+}
+
 /// <p>The output for the SetIdentityPoolConfiguration operation</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SetIdentityPoolConfigurationResponse {
     /// <p>Options to apply to this identity pool for Amazon Cognito streams.</p>
     #[serde(rename = "CognitoStreams")]
@@ -589,6 +997,7 @@ pub struct SetIdentityPoolConfigurationResponse {
 
 /// <p>A request to SubscribeToDatasetRequest.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SubscribeToDatasetRequest {
     /// <p>The name of the dataset to subcribe to.</p>
     #[serde(rename = "DatasetName")]
@@ -604,13 +1013,58 @@ pub struct SubscribeToDatasetRequest {
     pub identity_pool_id: String,
 }
 
+impl Validate for SubscribeToDatasetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "[a-zA-Z0-9_.:-]+",
+        );
+        errors.check_length(
+            &field_path(path, "device_id"),
+            self.device_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Response to a SubscribeToDataset request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubscribeToDatasetResponse {}
 
 /// <p>A request to UnsubscribeFromDataset.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnsubscribeFromDatasetRequest {
     /// <p>The name of the dataset from which to unsubcribe.</p>
     #[serde(rename = "DatasetName")]
@@ -626,13 +1080,58 @@ pub struct UnsubscribeFromDatasetRequest {
     pub identity_pool_id: String,
 }
 
+impl Validate for UnsubscribeFromDatasetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "[a-zA-Z0-9_.:-]+",
+        );
+        errors.check_length(
+            &field_path(path, "device_id"),
+            self.device_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+    }
+}
+
 /// <p>Response to an UnsubscribeFromDataset request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnsubscribeFromDatasetResponse {}
 
 /// <p>A request to post updates to records or add and delete records for a dataset and user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRecordsRequest {
     /// <p>Intended to supply a device ID that will populate the lastModifiedBy field referenced in other methods. The ClientContext field is not yet implemented.</p>
     #[serde(rename = "ClientContext")]
@@ -660,9 +1159,61 @@ pub struct UpdateRecordsRequest {
     pub sync_session_token: String,
 }
 
+impl Validate for UpdateRecordsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "[a-zA-Z0-9_.:-]+",
+        );
+        if let Some(ref value) = self.device_id {
+            errors.check_length(
+                &field_path(path, "device_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "identity_id"),
+            self.identity_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_id"),
+            &self.identity_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        errors.check_length(
+            &field_path(path, "identity_pool_id"),
+            self.identity_pool_id.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "identity_pool_id"),
+            &self.identity_pool_id,
+            "[\\w-]+:[0-9a-f-]+",
+        );
+        if let Some(ref value) = self.record_patches {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "record_patches"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Returned for a successful UpdateRecordsRequest.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRecordsResponse {
     /// <p>A list of records that have been updated.</p>
     #[serde(rename = "Records")]
@@ -709,7 +1260,12 @@ impl BulkPublishError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(BulkPublishError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -772,7 +1328,12 @@ impl DeleteDatasetError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteDatasetError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -830,7 +1391,12 @@ impl DescribeDatasetError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeDatasetError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -897,7 +1463,12 @@ impl DescribeIdentityPoolUsageError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -960,7 +1531,12 @@ impl DescribeIdentityUsageError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1016,7 +1592,12 @@ impl GetBulkPublishDetailsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1072,7 +1653,12 @@ impl GetCognitoEventsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetCognitoEventsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1141,7 +1727,12 @@ impl GetIdentityPoolConfigurationError {
                         GetIdentityPoolConfigurationError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1193,7 +1784,12 @@ impl ListDatasetsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListDatasetsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1248,7 +1844,12 @@ impl ListIdentityPoolUsageError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1299,7 +1900,12 @@ impl ListRecordsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListRecordsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1360,7 +1966,12 @@ impl RegisterDeviceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(RegisterDeviceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1418,7 +2029,12 @@ impl SetCognitoEventsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(SetCognitoEventsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1494,7 +2110,12 @@ impl SetIdentityPoolConfigurationError {
                         SetIdentityPoolConfigurationError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1559,7 +2180,12 @@ impl SubscribeToDatasetError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(SubscribeToDatasetError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1634,7 +2260,12 @@ impl UnsubscribeFromDatasetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1714,7 +2345,12 @@ impl UpdateRecordsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateRecordsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1845,129 +2481,482 @@ pub trait CognitoSync {
         input: UpdateRecordsRequest,
     ) -> RusotoFuture<UpdateRecordsResponse, UpdateRecordsError>;
 }
-/// A client for the Amazon Cognito Sync API.
-#[derive(Clone)]
-pub struct CognitoSyncClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CognitoSyncClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CognitoSyncClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CognitoSyncClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CognitoSyncClient {
-        CognitoSyncClient { client, region }
-    }
-}
-
-impl CognitoSync for CognitoSyncClient {
-    /// <p>Initiates a bulk publish of all existing datasets for an Identity Pool to the configured stream. Customers are limited to one successful bulk publish per 24 hours. Bulk publish is an asynchronous request, customers can see the status of the request via the GetBulkPublishDetails operation.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+const _: Option<&dyn CognitoSync> = None;
+impl<'a, T: CognitoSync + ?Sized> CognitoSync for &'a T {
     fn bulk_publish(
         &self,
         input: BulkPublishRequest,
     ) -> RusotoFuture<BulkPublishResponse, BulkPublishError> {
-        let request_uri = format!(
-            "/identitypools/{identity_pool_id}/bulkpublish",
-            identity_pool_id = input.identity_pool_id
-        );
-
-        let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BulkPublishResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BulkPublishError::from_response(response))),
-                )
-            }
-        })
+        (**self).bulk_publish(input)
     }
-
-    /// <p>Deletes the specific dataset. The dataset will be deleted permanently, and the action can't be undone. Datasets that this dataset was merged with will no longer report the merge. Any subsequent operation on this dataset will result in a ResourceNotFoundException.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
     fn delete_dataset(
         &self,
         input: DeleteDatasetRequest,
     ) -> RusotoFuture<DeleteDatasetResponse, DeleteDatasetError> {
-        let request_uri = format!(
-            "/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}",
-            dataset_name = input.dataset_name,
-            identity_id = input.identity_id,
-            identity_pool_id = input.identity_pool_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDatasetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDatasetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_dataset(input)
     }
-
-    /// <p>Gets meta data about a dataset by identity and dataset name. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use Cognito Identity credentials to make this API call.</p>
     fn describe_dataset(
         &self,
         input: DescribeDatasetRequest,
     ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
-        let request_uri = format!(
-            "/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}",
-            dataset_name = input.dataset_name,
-            identity_id = input.identity_id,
-            identity_pool_id = input.identity_pool_id
-        );
-
-        let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
+        (**self).describe_dataset(input)
+    }
+    fn describe_identity_pool_usage(
+        &self,
+        input: DescribeIdentityPoolUsageRequest,
+    ) -> RusotoFuture<DescribeIdentityPoolUsageResponse, DescribeIdentityPoolUsageError> {
+        (**self).describe_identity_pool_usage(input)
+    }
+    fn describe_identity_usage(
+        &self,
+        input: DescribeIdentityUsageRequest,
+    ) -> RusotoFuture<DescribeIdentityUsageResponse, DescribeIdentityUsageError> {
+        (**self).describe_identity_usage(input)
+    }
+    fn get_bulk_publish_details(
+        &self,
+        input: GetBulkPublishDetailsRequest,
+    ) -> RusotoFuture<GetBulkPublishDetailsResponse, GetBulkPublishDetailsError> {
+        (**self).get_bulk_publish_details(input)
+    }
+    fn get_cognito_events(
+        &self,
+        input: GetCognitoEventsRequest,
+    ) -> RusotoFuture<GetCognitoEventsResponse, GetCognitoEventsError> {
+        (**self).get_cognito_events(input)
+    }
+    fn get_identity_pool_configuration(
+        &self,
+        input: GetIdentityPoolConfigurationRequest,
+    ) -> RusotoFuture<GetIdentityPoolConfigurationResponse, GetIdentityPoolConfigurationError> {
+        (**self).get_identity_pool_configuration(input)
+    }
+    fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        (**self).list_datasets(input)
+    }
+    fn list_identity_pool_usage(
+        &self,
+        input: ListIdentityPoolUsageRequest,
+    ) -> RusotoFuture<ListIdentityPoolUsageResponse, ListIdentityPoolUsageError> {
+        (**self).list_identity_pool_usage(input)
+    }
+    fn list_records(
+        &self,
+        input: ListRecordsRequest,
+    ) -> RusotoFuture<ListRecordsResponse, ListRecordsError> {
+        (**self).list_records(input)
+    }
+    fn register_device(
+        &self,
+        input: RegisterDeviceRequest,
+    ) -> RusotoFuture<RegisterDeviceResponse, RegisterDeviceError> {
+        (**self).register_device(input)
+    }
+    fn set_cognito_events(
+        &self,
+        input: SetCognitoEventsRequest,
+    ) -> RusotoFuture<(), SetCognitoEventsError> {
+        (**self).set_cognito_events(input)
+    }
+    fn set_identity_pool_configuration(
+        &self,
+        input: SetIdentityPoolConfigurationRequest,
+    ) -> RusotoFuture<SetIdentityPoolConfigurationResponse, SetIdentityPoolConfigurationError> {
+        (**self).set_identity_pool_configuration(input)
+    }
+    fn subscribe_to_dataset(
+        &self,
+        input: SubscribeToDatasetRequest,
+    ) -> RusotoFuture<SubscribeToDatasetResponse, SubscribeToDatasetError> {
+        (**self).subscribe_to_dataset(input)
+    }
+    fn unsubscribe_from_dataset(
+        &self,
+        input: UnsubscribeFromDatasetRequest,
+    ) -> RusotoFuture<UnsubscribeFromDatasetResponse, UnsubscribeFromDatasetError> {
+        (**self).unsubscribe_from_dataset(input)
+    }
+    fn update_records(
+        &self,
+        input: UpdateRecordsRequest,
+    ) -> RusotoFuture<UpdateRecordsResponse, UpdateRecordsError> {
+        (**self).update_records(input)
+    }
+}
+impl<T: CognitoSync + ?Sized> CognitoSync for Box<T> {
+    fn bulk_publish(
+        &self,
+        input: BulkPublishRequest,
+    ) -> RusotoFuture<BulkPublishResponse, BulkPublishError> {
+        (**self).bulk_publish(input)
+    }
+    fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> RusotoFuture<DeleteDatasetResponse, DeleteDatasetError> {
+        (**self).delete_dataset(input)
+    }
+    fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
+        (**self).describe_dataset(input)
+    }
+    fn describe_identity_pool_usage(
+        &self,
+        input: DescribeIdentityPoolUsageRequest,
+    ) -> RusotoFuture<DescribeIdentityPoolUsageResponse, DescribeIdentityPoolUsageError> {
+        (**self).describe_identity_pool_usage(input)
+    }
+    fn describe_identity_usage(
+        &self,
+        input: DescribeIdentityUsageRequest,
+    ) -> RusotoFuture<DescribeIdentityUsageResponse, DescribeIdentityUsageError> {
+        (**self).describe_identity_usage(input)
+    }
+    fn get_bulk_publish_details(
+        &self,
+        input: GetBulkPublishDetailsRequest,
+    ) -> RusotoFuture<GetBulkPublishDetailsResponse, GetBulkPublishDetailsError> {
+        (**self).get_bulk_publish_details(input)
+    }
+    fn get_cognito_events(
+        &self,
+        input: GetCognitoEventsRequest,
+    ) -> RusotoFuture<GetCognitoEventsResponse, GetCognitoEventsError> {
+        (**self).get_cognito_events(input)
+    }
+    fn get_identity_pool_configuration(
+        &self,
+        input: GetIdentityPoolConfigurationRequest,
+    ) -> RusotoFuture<GetIdentityPoolConfigurationResponse, GetIdentityPoolConfigurationError> {
+        (**self).get_identity_pool_configuration(input)
+    }
+    fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        (**self).list_datasets(input)
+    }
+    fn list_identity_pool_usage(
+        &self,
+        input: ListIdentityPoolUsageRequest,
+    ) -> RusotoFuture<ListIdentityPoolUsageResponse, ListIdentityPoolUsageError> {
+        (**self).list_identity_pool_usage(input)
+    }
+    fn list_records(
+        &self,
+        input: ListRecordsRequest,
+    ) -> RusotoFuture<ListRecordsResponse, ListRecordsError> {
+        (**self).list_records(input)
+    }
+    fn register_device(
+        &self,
+        input: RegisterDeviceRequest,
+    ) -> RusotoFuture<RegisterDeviceResponse, RegisterDeviceError> {
+        (**self).register_device(input)
+    }
+    fn set_cognito_events(
+        &self,
+        input: SetCognitoEventsRequest,
+    ) -> RusotoFuture<(), SetCognitoEventsError> {
+        (**self).set_cognito_events(input)
+    }
+    fn set_identity_pool_configuration(
+        &self,
+        input: SetIdentityPoolConfigurationRequest,
+    ) -> RusotoFuture<SetIdentityPoolConfigurationResponse, SetIdentityPoolConfigurationError> {
+        (**self).set_identity_pool_configuration(input)
+    }
+    fn subscribe_to_dataset(
+        &self,
+        input: SubscribeToDatasetRequest,
+    ) -> RusotoFuture<SubscribeToDatasetResponse, SubscribeToDatasetError> {
+        (**self).subscribe_to_dataset(input)
+    }
+    fn unsubscribe_from_dataset(
+        &self,
+        input: UnsubscribeFromDatasetRequest,
+    ) -> RusotoFuture<UnsubscribeFromDatasetResponse, UnsubscribeFromDatasetError> {
+        (**self).unsubscribe_from_dataset(input)
+    }
+    fn update_records(
+        &self,
+        input: UpdateRecordsRequest,
+    ) -> RusotoFuture<UpdateRecordsResponse, UpdateRecordsError> {
+        (**self).update_records(input)
+    }
+}
+impl<T: CognitoSync + ?Sized> CognitoSync for ::std::sync::Arc<T> {
+    fn bulk_publish(
+        &self,
+        input: BulkPublishRequest,
+    ) -> RusotoFuture<BulkPublishResponse, BulkPublishError> {
+        (**self).bulk_publish(input)
+    }
+    fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> RusotoFuture<DeleteDatasetResponse, DeleteDatasetError> {
+        (**self).delete_dataset(input)
+    }
+    fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
+        (**self).describe_dataset(input)
+    }
+    fn describe_identity_pool_usage(
+        &self,
+        input: DescribeIdentityPoolUsageRequest,
+    ) -> RusotoFuture<DescribeIdentityPoolUsageResponse, DescribeIdentityPoolUsageError> {
+        (**self).describe_identity_pool_usage(input)
+    }
+    fn describe_identity_usage(
+        &self,
+        input: DescribeIdentityUsageRequest,
+    ) -> RusotoFuture<DescribeIdentityUsageResponse, DescribeIdentityUsageError> {
+        (**self).describe_identity_usage(input)
+    }
+    fn get_bulk_publish_details(
+        &self,
+        input: GetBulkPublishDetailsRequest,
+    ) -> RusotoFuture<GetBulkPublishDetailsResponse, GetBulkPublishDetailsError> {
+        (**self).get_bulk_publish_details(input)
+    }
+    fn get_cognito_events(
+        &self,
+        input: GetCognitoEventsRequest,
+    ) -> RusotoFuture<GetCognitoEventsResponse, GetCognitoEventsError> {
+        (**self).get_cognito_events(input)
+    }
+    fn get_identity_pool_configuration(
+        &self,
+        input: GetIdentityPoolConfigurationRequest,
+    ) -> RusotoFuture<GetIdentityPoolConfigurationResponse, GetIdentityPoolConfigurationError> {
+        (**self).get_identity_pool_configuration(input)
+    }
+    fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        (**self).list_datasets(input)
+    }
+    fn list_identity_pool_usage(
+        &self,
+        input: ListIdentityPoolUsageRequest,
+    ) -> RusotoFuture<ListIdentityPoolUsageResponse, ListIdentityPoolUsageError> {
+        (**self).list_identity_pool_usage(input)
+    }
+    fn list_records(
+        &self,
+        input: ListRecordsRequest,
+    ) -> RusotoFuture<ListRecordsResponse, ListRecordsError> {
+        (**self).list_records(input)
+    }
+    fn register_device(
+        &self,
+        input: RegisterDeviceRequest,
+    ) -> RusotoFuture<RegisterDeviceResponse, RegisterDeviceError> {
+        (**self).register_device(input)
+    }
+    fn set_cognito_events(
+        &self,
+        input: SetCognitoEventsRequest,
+    ) -> RusotoFuture<(), SetCognitoEventsError> {
+        (**self).set_cognito_events(input)
+    }
+    fn set_identity_pool_configuration(
+        &self,
+        input: SetIdentityPoolConfigurationRequest,
+    ) -> RusotoFuture<SetIdentityPoolConfigurationResponse, SetIdentityPoolConfigurationError> {
+        (**self).set_identity_pool_configuration(input)
+    }
+    fn subscribe_to_dataset(
+        &self,
+        input: SubscribeToDatasetRequest,
+    ) -> RusotoFuture<SubscribeToDatasetResponse, SubscribeToDatasetError> {
+        (**self).subscribe_to_dataset(input)
+    }
+    fn unsubscribe_from_dataset(
+        &self,
+        input: UnsubscribeFromDatasetRequest,
+    ) -> RusotoFuture<UnsubscribeFromDatasetResponse, UnsubscribeFromDatasetError> {
+        (**self).unsubscribe_from_dataset(input)
+    }
+    fn update_records(
+        &self,
+        input: UpdateRecordsRequest,
+    ) -> RusotoFuture<UpdateRecordsResponse, UpdateRecordsError> {
+        (**self).update_records(input)
+    }
+}
+/// A client for the Amazon Cognito Sync API.
+#[derive(Clone)]
+pub struct CognitoSyncClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CognitoSyncClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CognitoSyncClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CognitoSyncClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CognitoSyncClient {
+        CognitoSyncClient { client, region }
+    }
+}
+
+impl CognitoSync for CognitoSyncClient {
+    /// <p>Initiates a bulk publish of all existing datasets for an Identity Pool to the configured stream. Customers are limited to one successful bulk publish per 24 hours. Bulk publish is an asynchronous request, customers can see the status of the request via the GetBulkPublishDetails operation.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    fn bulk_publish(
+        &self,
+        input: BulkPublishRequest,
+    ) -> RusotoFuture<BulkPublishResponse, BulkPublishError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/identitypools/{identity_pool_id}/bulkpublish",
+            identity_pool_id = input.identity_pool_id
+        );
+
+        let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("BulkPublish");
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BulkPublish")
+                        .deserialize::<BulkPublishResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BulkPublishError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specific dataset. The dataset will be deleted permanently, and the action can't be undone. Datasets that this dataset was merged with will no longer report the merge. Any subsequent operation on this dataset will result in a ResourceNotFoundException.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
+    fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> RusotoFuture<DeleteDatasetResponse, DeleteDatasetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}",
+            dataset_name = input.dataset_name,
+            identity_id = input.identity_id,
+            identity_pool_id = input.identity_pool_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DeleteDataset");
+        request.add_resource_id("DatasetName", &input.dataset_name.to_string());
+        request.add_resource_id("IdentityId", &input.identity_id.to_string());
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteDataset")
+                        .deserialize::<DeleteDatasetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDatasetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets meta data about a dataset by identity and dataset name. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use Cognito Identity credentials to make this API call.</p>
+    fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}",
+            dataset_name = input.dataset_name,
+            identity_id = input.identity_id,
+            identity_pool_id = input.identity_pool_id
+        );
+
+        let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeDataset");
+        request.add_resource_id("DatasetName", &input.dataset_name.to_string());
+        request.add_resource_id("IdentityId", &input.identity_id.to_string());
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeDataset")
                         .deserialize::<DescribeDatasetResponse, _>()?;
 
                     Ok(result)
@@ -1988,18 +2977,30 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: DescribeIdentityPoolUsageRequest,
     ) -> RusotoFuture<DescribeIdentityPoolUsageResponse, DescribeIdentityPoolUsageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/identitypools/{identity_pool_id}",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityPoolUsage");
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeIdentityPoolUsage")
                         .deserialize::<DescribeIdentityPoolUsageResponse, _>()?;
 
                     Ok(result)
@@ -2017,6 +3018,14 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: DescribeIdentityUsageRequest,
     ) -> RusotoFuture<DescribeIdentityUsageResponse, DescribeIdentityUsageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/identities/{identity_id}",
             identity_id = input.identity_id,
@@ -2024,12 +3033,17 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityUsage");
+        request.add_resource_id("IdentityId", &input.identity_id.to_string());
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeIdentityUsage")
                         .deserialize::<DescribeIdentityUsageResponse, _>()?;
 
                     Ok(result)
@@ -2049,18 +3063,30 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: GetBulkPublishDetailsRequest,
     ) -> RusotoFuture<GetBulkPublishDetailsResponse, GetBulkPublishDetailsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/getBulkPublishDetails",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetBulkPublishDetails");
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBulkPublishDetails")
                         .deserialize::<GetBulkPublishDetailsResponse, _>()?;
 
                     Ok(result)
@@ -2080,18 +3106,30 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: GetCognitoEventsRequest,
     ) -> RusotoFuture<GetCognitoEventsResponse, GetCognitoEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/events",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetCognitoEvents");
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetCognitoEvents")
                         .deserialize::<GetCognitoEventsResponse, _>()?;
 
                     Ok(result)
@@ -2112,18 +3150,30 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: GetIdentityPoolConfigurationRequest,
     ) -> RusotoFuture<GetIdentityPoolConfigurationResponse, GetIdentityPoolConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/configuration",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetIdentityPoolConfiguration");
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIdentityPoolConfiguration")
                         .deserialize::<GetIdentityPoolConfigurationResponse, _>()?;
 
                     Ok(result)
@@ -2141,6 +3191,14 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: ListDatasetsRequest,
     ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/identities/{identity_id}/datasets",
             identity_id = input.identity_id,
@@ -2148,6 +3206,10 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListDatasets");
+        request.add_resource_id("IdentityId", &input.identity_id.to_string());
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2163,6 +3225,7 @@ impl CognitoSync for CognitoSyncClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDatasets")
                         .deserialize::<ListDatasetsResponse, _>()?;
 
                     Ok(result)
@@ -2186,6 +3249,8 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = "/identitypools";
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListIdentityPoolUsage");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2201,6 +3266,7 @@ impl CognitoSync for CognitoSyncClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListIdentityPoolUsage")
                         .deserialize::<ListIdentityPoolUsageResponse, _>()?;
 
                     Ok(result)
@@ -2220,9 +3286,22 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: ListRecordsRequest,
     ) -> RusotoFuture<ListRecordsResponse, ListRecordsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/records", dataset_name = input.dataset_name, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListRecords");
+        request.add_resource_id("DatasetName", &input.dataset_name.to_string());
+        request.add_resource_id("IdentityId", &input.identity_id.to_string());
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2244,6 +3323,7 @@ impl CognitoSync for CognitoSyncClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListRecords")
                         .deserialize::<ListRecordsResponse, _>()?;
 
                     Ok(result)
@@ -2264,6 +3344,14 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: RegisterDeviceRequest,
     ) -> RusotoFuture<RegisterDeviceResponse, RegisterDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/identity/{identity_id}/device",
             identity_id = input.identity_id,
@@ -2271,6 +3359,10 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("RegisterDevice");
+        request.add_resource_id("IdentityId", &input.identity_id.to_string());
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2280,6 +3372,7 @@ impl CognitoSync for CognitoSyncClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RegisterDevice")
                         .deserialize::<RegisterDeviceResponse, _>()?;
 
                     Ok(result)
@@ -2300,12 +3393,22 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: SetCognitoEventsRequest,
     ) -> RusotoFuture<(), SetCognitoEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/events",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SetCognitoEvents");
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2334,12 +3437,23 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: SetIdentityPoolConfigurationRequest,
     ) -> RusotoFuture<SetIdentityPoolConfigurationResponse, SetIdentityPoolConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/configuration",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SetIdentityPoolConfiguration");
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2349,6 +3463,7 @@ impl CognitoSync for CognitoSyncClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("SetIdentityPoolConfiguration")
                         .deserialize::<SetIdentityPoolConfigurationResponse, _>()?;
 
                     Ok(result)
@@ -2366,15 +3481,29 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: SubscribeToDatasetRequest,
     ) -> RusotoFuture<SubscribeToDatasetResponse, SubscribeToDatasetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SubscribeToDataset");
+        request.add_resource_id("DatasetName", &input.dataset_name.to_string());
+        request.add_resource_id("DeviceId", &input.device_id.to_string());
+        request.add_resource_id("IdentityId", &input.identity_id.to_string());
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("SubscribeToDataset")
                         .deserialize::<SubscribeToDatasetResponse, _>()?;
 
                     Ok(result)
@@ -2395,15 +3524,29 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: UnsubscribeFromDatasetRequest,
     ) -> RusotoFuture<UnsubscribeFromDatasetResponse, UnsubscribeFromDatasetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("UnsubscribeFromDataset");
+        request.add_resource_id("DatasetName", &input.dataset_name.to_string());
+        request.add_resource_id("DeviceId", &input.device_id.to_string());
+        request.add_resource_id("IdentityId", &input.identity_id.to_string());
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UnsubscribeFromDataset")
                         .deserialize::<UnsubscribeFromDatasetResponse, _>()?;
 
                     Ok(result)
@@ -2423,6 +3566,14 @@ impl CognitoSync for CognitoSyncClient {
         &self,
         input: UpdateRecordsRequest,
     ) -> RusotoFuture<UpdateRecordsResponse, UpdateRecordsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}",
             dataset_name = input.dataset_name,
@@ -2431,6 +3582,11 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("UpdateRecords");
+        request.add_resource_id("DatasetName", &input.dataset_name.to_string());
+        request.add_resource_id("IdentityId", &input.identity_id.to_string());
+        request.add_resource_id("IdentityPoolId", &input.identity_pool_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2444,6 +3600,7 @@ impl CognitoSync for CognitoSyncClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateRecords")
                         .deserialize::<UpdateRecordsResponse, _>()?;
 
                     Ok(result)
@@ -2459,3 +3616,604 @@ impl CognitoSync for CognitoSyncClient {
         })
     }
 }
+
+/// A mock of the Amazon Cognito Sync API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCognitoSync {
+    bulk_publish: Option<
+        Box<
+            dyn Fn(
+                    BulkPublishRequest,
+                )
+                    -> ::std::result::Result<BulkPublishResponse, RusotoError<BulkPublishError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_dataset: Option<
+        Box<
+            dyn Fn(
+                    DeleteDatasetRequest,
+                )
+                    -> ::std::result::Result<DeleteDatasetResponse, RusotoError<DeleteDatasetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_dataset: Option<
+        Box<
+            dyn Fn(
+                    DescribeDatasetRequest,
+                ) -> ::std::result::Result<
+                    DescribeDatasetResponse,
+                    RusotoError<DescribeDatasetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_identity_pool_usage: Option<
+        Box<
+            dyn Fn(
+                    DescribeIdentityPoolUsageRequest,
+                ) -> ::std::result::Result<
+                    DescribeIdentityPoolUsageResponse,
+                    RusotoError<DescribeIdentityPoolUsageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_identity_usage: Option<
+        Box<
+            dyn Fn(
+                    DescribeIdentityUsageRequest,
+                ) -> ::std::result::Result<
+                    DescribeIdentityUsageResponse,
+                    RusotoError<DescribeIdentityUsageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bulk_publish_details: Option<
+        Box<
+            dyn Fn(
+                    GetBulkPublishDetailsRequest,
+                ) -> ::std::result::Result<
+                    GetBulkPublishDetailsResponse,
+                    RusotoError<GetBulkPublishDetailsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_cognito_events: Option<
+        Box<
+            dyn Fn(
+                    GetCognitoEventsRequest,
+                ) -> ::std::result::Result<
+                    GetCognitoEventsResponse,
+                    RusotoError<GetCognitoEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_identity_pool_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetIdentityPoolConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetIdentityPoolConfigurationResponse,
+                    RusotoError<GetIdentityPoolConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_datasets: Option<
+        Box<
+            dyn Fn(
+                    ListDatasetsRequest,
+                )
+                    -> ::std::result::Result<ListDatasetsResponse, RusotoError<ListDatasetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_identity_pool_usage: Option<
+        Box<
+            dyn Fn(
+                    ListIdentityPoolUsageRequest,
+                ) -> ::std::result::Result<
+                    ListIdentityPoolUsageResponse,
+                    RusotoError<ListIdentityPoolUsageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_records: Option<
+        Box<
+            dyn Fn(
+                    ListRecordsRequest,
+                )
+                    -> ::std::result::Result<ListRecordsResponse, RusotoError<ListRecordsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_device: Option<
+        Box<
+            dyn Fn(
+                    RegisterDeviceRequest,
+                ) -> ::std::result::Result<
+                    RegisterDeviceResponse,
+                    RusotoError<RegisterDeviceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_cognito_events: Option<
+        Box<
+            dyn Fn(
+                    SetCognitoEventsRequest,
+                ) -> ::std::result::Result<(), RusotoError<SetCognitoEventsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_identity_pool_configuration: Option<
+        Box<
+            dyn Fn(
+                    SetIdentityPoolConfigurationRequest,
+                ) -> ::std::result::Result<
+                    SetIdentityPoolConfigurationResponse,
+                    RusotoError<SetIdentityPoolConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    subscribe_to_dataset: Option<
+        Box<
+            dyn Fn(
+                    SubscribeToDatasetRequest,
+                ) -> ::std::result::Result<
+                    SubscribeToDatasetResponse,
+                    RusotoError<SubscribeToDatasetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    unsubscribe_from_dataset: Option<
+        Box<
+            dyn Fn(
+                    UnsubscribeFromDatasetRequest,
+                ) -> ::std::result::Result<
+                    UnsubscribeFromDatasetResponse,
+                    RusotoError<UnsubscribeFromDatasetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_records: Option<
+        Box<
+            dyn Fn(
+                    UpdateRecordsRequest,
+                )
+                    -> ::std::result::Result<UpdateRecordsResponse, RusotoError<UpdateRecordsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCognitoSync {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCognitoSync {
+        Default::default()
+    }
+
+    /// Answers `bulk_publish` with what `handler` returns for its input.
+    pub fn with_bulk_publish<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                BulkPublishRequest,
+            )
+                -> ::std::result::Result<BulkPublishResponse, RusotoError<BulkPublishError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.bulk_publish = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_dataset` with what `handler` returns for its input.
+    pub fn with_delete_dataset<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                DeleteDatasetRequest,
+            )
+                -> ::std::result::Result<DeleteDatasetResponse, RusotoError<DeleteDatasetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_dataset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_dataset` with what `handler` returns for its input.
+    pub fn with_describe_dataset<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                DescribeDatasetRequest,
+            )
+                -> ::std::result::Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_dataset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_identity_pool_usage` with what `handler` returns for its input.
+    pub fn with_describe_identity_pool_usage<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                DescribeIdentityPoolUsageRequest,
+            ) -> ::std::result::Result<
+                DescribeIdentityPoolUsageResponse,
+                RusotoError<DescribeIdentityPoolUsageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_identity_pool_usage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_identity_usage` with what `handler` returns for its input.
+    pub fn with_describe_identity_usage<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                DescribeIdentityUsageRequest,
+            ) -> ::std::result::Result<
+                DescribeIdentityUsageResponse,
+                RusotoError<DescribeIdentityUsageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_identity_usage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bulk_publish_details` with what `handler` returns for its input.
+    pub fn with_get_bulk_publish_details<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                GetBulkPublishDetailsRequest,
+            ) -> ::std::result::Result<
+                GetBulkPublishDetailsResponse,
+                RusotoError<GetBulkPublishDetailsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bulk_publish_details = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_cognito_events` with what `handler` returns for its input.
+    pub fn with_get_cognito_events<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                GetCognitoEventsRequest,
+            ) -> ::std::result::Result<
+                GetCognitoEventsResponse,
+                RusotoError<GetCognitoEventsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_cognito_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_identity_pool_configuration` with what `handler` returns for its input.
+    pub fn with_get_identity_pool_configuration<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                GetIdentityPoolConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetIdentityPoolConfigurationResponse,
+                RusotoError<GetIdentityPoolConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_identity_pool_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_datasets` with what `handler` returns for its input.
+    pub fn with_list_datasets<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                ListDatasetsRequest,
+            )
+                -> ::std::result::Result<ListDatasetsResponse, RusotoError<ListDatasetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_datasets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_identity_pool_usage` with what `handler` returns for its input.
+    pub fn with_list_identity_pool_usage<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                ListIdentityPoolUsageRequest,
+            ) -> ::std::result::Result<
+                ListIdentityPoolUsageResponse,
+                RusotoError<ListIdentityPoolUsageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_identity_pool_usage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_records` with what `handler` returns for its input.
+    pub fn with_list_records<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                ListRecordsRequest,
+            )
+                -> ::std::result::Result<ListRecordsResponse, RusotoError<ListRecordsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_records = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_device` with what `handler` returns for its input.
+    pub fn with_register_device<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                RegisterDeviceRequest,
+            )
+                -> ::std::result::Result<RegisterDeviceResponse, RusotoError<RegisterDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_cognito_events` with what `handler` returns for its input.
+    pub fn with_set_cognito_events<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                SetCognitoEventsRequest,
+            ) -> ::std::result::Result<(), RusotoError<SetCognitoEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_cognito_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_identity_pool_configuration` with what `handler` returns for its input.
+    pub fn with_set_identity_pool_configuration<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                SetIdentityPoolConfigurationRequest,
+            ) -> ::std::result::Result<
+                SetIdentityPoolConfigurationResponse,
+                RusotoError<SetIdentityPoolConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_identity_pool_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `subscribe_to_dataset` with what `handler` returns for its input.
+    pub fn with_subscribe_to_dataset<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                SubscribeToDatasetRequest,
+            ) -> ::std::result::Result<
+                SubscribeToDatasetResponse,
+                RusotoError<SubscribeToDatasetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.subscribe_to_dataset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unsubscribe_from_dataset` with what `handler` returns for its input.
+    pub fn with_unsubscribe_from_dataset<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                UnsubscribeFromDatasetRequest,
+            ) -> ::std::result::Result<
+                UnsubscribeFromDatasetResponse,
+                RusotoError<UnsubscribeFromDatasetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.unsubscribe_from_dataset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_records` with what `handler` returns for its input.
+    pub fn with_update_records<F>(mut self, handler: F) -> MockCognitoSync
+    where
+        F: Fn(
+                UpdateRecordsRequest,
+            )
+                -> ::std::result::Result<UpdateRecordsResponse, RusotoError<UpdateRecordsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_records = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CognitoSync for MockCognitoSync {
+    fn bulk_publish(
+        &self,
+        input: BulkPublishRequest,
+    ) -> RusotoFuture<BulkPublishResponse, BulkPublishError> {
+        match self.bulk_publish {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for bulk_publish"),
+        }
+    }
+    fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> RusotoFuture<DeleteDatasetResponse, DeleteDatasetError> {
+        match self.delete_dataset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for delete_dataset"),
+        }
+    }
+    fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
+        match self.describe_dataset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for describe_dataset"),
+        }
+    }
+    fn describe_identity_pool_usage(
+        &self,
+        input: DescribeIdentityPoolUsageRequest,
+    ) -> RusotoFuture<DescribeIdentityPoolUsageResponse, DescribeIdentityPoolUsageError> {
+        match self.describe_identity_pool_usage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for describe_identity_pool_usage"),
+        }
+    }
+    fn describe_identity_usage(
+        &self,
+        input: DescribeIdentityUsageRequest,
+    ) -> RusotoFuture<DescribeIdentityUsageResponse, DescribeIdentityUsageError> {
+        match self.describe_identity_usage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for describe_identity_usage"),
+        }
+    }
+    fn get_bulk_publish_details(
+        &self,
+        input: GetBulkPublishDetailsRequest,
+    ) -> RusotoFuture<GetBulkPublishDetailsResponse, GetBulkPublishDetailsError> {
+        match self.get_bulk_publish_details {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for get_bulk_publish_details"),
+        }
+    }
+    fn get_cognito_events(
+        &self,
+        input: GetCognitoEventsRequest,
+    ) -> RusotoFuture<GetCognitoEventsResponse, GetCognitoEventsError> {
+        match self.get_cognito_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for get_cognito_events"),
+        }
+    }
+    fn get_identity_pool_configuration(
+        &self,
+        input: GetIdentityPoolConfigurationRequest,
+    ) -> RusotoFuture<GetIdentityPoolConfigurationResponse, GetIdentityPoolConfigurationError> {
+        match self.get_identity_pool_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for get_identity_pool_configuration"),
+        }
+    }
+    fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        match self.list_datasets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for list_datasets"),
+        }
+    }
+    fn list_identity_pool_usage(
+        &self,
+        input: ListIdentityPoolUsageRequest,
+    ) -> RusotoFuture<ListIdentityPoolUsageResponse, ListIdentityPoolUsageError> {
+        match self.list_identity_pool_usage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for list_identity_pool_usage"),
+        }
+    }
+    fn list_records(
+        &self,
+        input: ListRecordsRequest,
+    ) -> RusotoFuture<ListRecordsResponse, ListRecordsError> {
+        match self.list_records {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for list_records"),
+        }
+    }
+    fn register_device(
+        &self,
+        input: RegisterDeviceRequest,
+    ) -> RusotoFuture<RegisterDeviceResponse, RegisterDeviceError> {
+        match self.register_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for register_device"),
+        }
+    }
+    fn set_cognito_events(
+        &self,
+        input: SetCognitoEventsRequest,
+    ) -> RusotoFuture<(), SetCognitoEventsError> {
+        match self.set_cognito_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for set_cognito_events"),
+        }
+    }
+    fn set_identity_pool_configuration(
+        &self,
+        input: SetIdentityPoolConfigurationRequest,
+    ) -> RusotoFuture<SetIdentityPoolConfigurationResponse, SetIdentityPoolConfigurationError> {
+        match self.set_identity_pool_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for set_identity_pool_configuration"),
+        }
+    }
+    fn subscribe_to_dataset(
+        &self,
+        input: SubscribeToDatasetRequest,
+    ) -> RusotoFuture<SubscribeToDatasetResponse, SubscribeToDatasetError> {
+        match self.subscribe_to_dataset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for subscribe_to_dataset"),
+        }
+    }
+    fn unsubscribe_from_dataset(
+        &self,
+        input: UnsubscribeFromDatasetRequest,
+    ) -> RusotoFuture<UnsubscribeFromDatasetResponse, UnsubscribeFromDatasetError> {
+        match self.unsubscribe_from_dataset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for unsubscribe_from_dataset"),
+        }
+    }
+    fn update_records(
+        &self,
+        input: UpdateRecordsRequest,
+    ) -> RusotoFuture<UpdateRecordsResponse, UpdateRecordsError> {
+        match self.update_records {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCognitoSync has no handler for update_records"),
+        }
+    }
+}
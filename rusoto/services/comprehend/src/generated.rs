@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The result of calling the operation. The operation returns one object for each document that is successfully processed by the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetectDominantLanguageItemResult {
     /// <p>The zero-based index of the document in the input list.</p>
     #[serde(rename = "Index")]
@@ -38,14 +39,30 @@ pub struct BatchDetectDominantLanguageItemResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDetectDominantLanguageRequest {
     /// <p>A list containing the text of the input documents. The list can contain a maximum of 25 documents. Each document should contain at least 20 characters and must contain fewer than 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "TextList")]
     pub text_list: Vec<String>,
 }
 
+impl Validate for BatchDetectDominantLanguageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text_list"),
+            self.text_list.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.text_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "text_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetectDominantLanguageResponse {
     /// <p>A list containing one object for each document that contained an error. The results are sorted in ascending order by the <code>Index</code> field and match the order of the documents in the input list. If there are no errors in the batch, the <code>ErrorList</code> is empty.</p>
     #[serde(rename = "ErrorList")]
@@ -57,7 +74,7 @@ pub struct BatchDetectDominantLanguageResponse {
 
 /// <p>The result of calling the operation. The operation returns one object for each document that is successfully processed by the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetectEntitiesItemResult {
     /// <p>One or more <a>Entity</a> objects, one for each entity detected in the document.</p>
     #[serde(rename = "Entities")]
@@ -70,6 +87,7 @@ pub struct BatchDetectEntitiesItemResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDetectEntitiesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
@@ -79,8 +97,23 @@ pub struct BatchDetectEntitiesRequest {
     pub text_list: Vec<String>,
 }
 
+impl Validate for BatchDetectEntitiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text_list"),
+            self.text_list.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.text_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "text_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetectEntitiesResponse {
     /// <p>A list containing one object for each document that contained an error. The results are sorted in ascending order by the <code>Index</code> field and match the order of the documents in the input list. If there are no errors in the batch, the <code>ErrorList</code> is empty.</p>
     #[serde(rename = "ErrorList")]
@@ -92,7 +125,7 @@ pub struct BatchDetectEntitiesResponse {
 
 /// <p>The result of calling the operation. The operation returns one object for each document that is successfully processed by the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetectKeyPhrasesItemResult {
     /// <p>The zero-based index of the document in the input list.</p>
     #[serde(rename = "Index")]
@@ -105,6 +138,7 @@ pub struct BatchDetectKeyPhrasesItemResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDetectKeyPhrasesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
@@ -114,8 +148,23 @@ pub struct BatchDetectKeyPhrasesRequest {
     pub text_list: Vec<String>,
 }
 
+impl Validate for BatchDetectKeyPhrasesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text_list"),
+            self.text_list.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.text_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "text_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetectKeyPhrasesResponse {
     /// <p>A list containing one object for each document that contained an error. The results are sorted in ascending order by the <code>Index</code> field and match the order of the documents in the input list. If there are no errors in the batch, the <code>ErrorList</code> is empty.</p>
     #[serde(rename = "ErrorList")]
@@ -127,7 +176,7 @@ pub struct BatchDetectKeyPhrasesResponse {
 
 /// <p>The result of calling the operation. The operation returns one object for each document that is successfully processed by the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetectSentimentItemResult {
     /// <p>The zero-based index of the document in the input list.</p>
     #[serde(rename = "Index")]
@@ -144,6 +193,7 @@ pub struct BatchDetectSentimentItemResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDetectSentimentRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
@@ -153,8 +203,23 @@ pub struct BatchDetectSentimentRequest {
     pub text_list: Vec<String>,
 }
 
+impl Validate for BatchDetectSentimentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text_list"),
+            self.text_list.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.text_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "text_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetectSentimentResponse {
     /// <p>A list containing one object for each document that contained an error. The results are sorted in ascending order by the <code>Index</code> field and match the order of the documents in the input list. If there are no errors in the batch, the <code>ErrorList</code> is empty.</p>
     #[serde(rename = "ErrorList")]
@@ -166,7 +231,7 @@ pub struct BatchDetectSentimentResponse {
 
 /// <p>The result of calling the operation. The operation returns one object that is successfully processed by the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetectSyntaxItemResult {
     /// <p>The zero-based index of the document in the input list.</p>
     #[serde(rename = "Index")]
@@ -179,6 +244,7 @@ pub struct BatchDetectSyntaxItemResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDetectSyntaxRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
@@ -188,8 +254,23 @@ pub struct BatchDetectSyntaxRequest {
     pub text_list: Vec<String>,
 }
 
+impl Validate for BatchDetectSyntaxRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text_list"),
+            self.text_list.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.text_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "text_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDetectSyntaxResponse {
     /// <p>A list containing one object for each document that contained an error. The results are sorted in ascending order by the <code>Index</code> field and match the order of the documents in the input list. If there are no errors in the batch, the <code>ErrorList</code> is empty.</p>
     #[serde(rename = "ErrorList")]
@@ -201,7 +282,7 @@ pub struct BatchDetectSyntaxResponse {
 
 /// <p>Describes an error that occurred while processing a document in a batch. The operation returns on <code>BatchItemError</code> object for each document that contained an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchItemError {
     /// <p>The numeric error code of the error.</p>
     #[serde(rename = "ErrorCode")]
@@ -219,7 +300,7 @@ pub struct BatchItemError {
 
 /// <p>Describes the result metrics for the test data associated with an documentation classifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClassifierEvaluationMetrics {
     /// <p>The fraction of the labels that were correct recognized. It is computed by dividing the number of labels in the test documents that were correctly recognized by the total number of labels in the test documents.</p>
     #[serde(rename = "Accuracy")]
@@ -241,7 +322,7 @@ pub struct ClassifierEvaluationMetrics {
 
 /// <p>Provides information about a document classifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClassifierMetadata {
     /// <p> Describes the result metrics for the test data associated with an documentation classifier.</p>
     #[serde(rename = "EvaluationMetrics")]
@@ -262,6 +343,7 @@ pub struct ClassifierMetadata {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDocumentClassifierRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -297,8 +379,75 @@ pub struct CreateDocumentClassifierRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for CreateDocumentClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "data_access_role_arn"),
+            self.data_access_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_access_role_arn"),
+            &self.data_access_role_arn,
+            "arn:aws(-[^:]+)?:iam::[0-9]{12}:role/.+",
+        );
+        errors.check_length(
+            &field_path(path, "document_classifier_name"),
+            self.document_classifier_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_classifier_name"),
+            &self.document_classifier_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*$",
+        );
+        self.input_data_config
+            .validate_at(&field_path(path, "input_data_config"), errors);
+        if let Some(ref value) = self.output_data_config {
+            value.validate_at(&field_path(path, "output_data_config"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "volume_kms_key_id"),
+                &value,
+                "^\\p{ASCII}+$",
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDocumentClassifierResponse {
     /// <p>The Amazon Resource Name (ARN) that identifies the document classifier.</p>
     #[serde(rename = "DocumentClassifierArn")]
@@ -307,6 +456,7 @@ pub struct CreateDocumentClassifierResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEntityRecognizerRequest {
     /// <p> A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -338,8 +488,72 @@ pub struct CreateEntityRecognizerRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for CreateEntityRecognizerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "data_access_role_arn"),
+            self.data_access_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_access_role_arn"),
+            &self.data_access_role_arn,
+            "arn:aws(-[^:]+)?:iam::[0-9]{12}:role/.+",
+        );
+        self.input_data_config
+            .validate_at(&field_path(path, "input_data_config"), errors);
+        errors.check_length(
+            &field_path(path, "recognizer_name"),
+            self.recognizer_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "recognizer_name"),
+            &self.recognizer_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*$",
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "volume_kms_key_id"),
+                &value,
+                "^\\p{ASCII}+$",
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateEntityRecognizerResponse {
     /// <p>The Amazon Resource Name (ARN) that identifies the entity recognizer.</p>
     #[serde(rename = "EntityRecognizerArn")]
@@ -348,36 +562,79 @@ pub struct CreateEntityRecognizerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDocumentClassifierRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the document classifier. </p>
     #[serde(rename = "DocumentClassifierArn")]
     pub document_classifier_arn: String,
 }
 
+impl Validate for DeleteDocumentClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "document_classifier_arn"),
+            self.document_classifier_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "document_classifier_arn"), &self.document_classifier_arn, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:document-classifier/[a-zA-Z0-9](-*[a-zA-Z0-9])*(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*)?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDocumentClassifierResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEntityRecognizerRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the entity recognizer.</p>
     #[serde(rename = "EntityRecognizerArn")]
     pub entity_recognizer_arn: String,
 }
 
+impl Validate for DeleteEntityRecognizerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entity_recognizer_arn"),
+            self.entity_recognizer_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "entity_recognizer_arn"), &self.entity_recognizer_arn, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:entity-recognizer/[a-zA-Z0-9](-*[a-zA-Z0-9])*(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*)?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteEntityRecognizerResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDocumentClassificationJobRequest {
     /// <p>The identifier that Amazon Comprehend generated for the job. The operation returns this identifier in its response.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for DescribeDocumentClassificationJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDocumentClassificationJobResponse {
     /// <p>An object that describes the properties associated with the document classification job.</p>
     #[serde(rename = "DocumentClassificationJobProperties")]
@@ -386,14 +643,27 @@ pub struct DescribeDocumentClassificationJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDocumentClassifierRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the document classifier. The operation returns this identifier in its response.</p>
     #[serde(rename = "DocumentClassifierArn")]
     pub document_classifier_arn: String,
 }
 
+impl Validate for DescribeDocumentClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "document_classifier_arn"),
+            self.document_classifier_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "document_classifier_arn"), &self.document_classifier_arn, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:document-classifier/[a-zA-Z0-9](-*[a-zA-Z0-9])*(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*)?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDocumentClassifierResponse {
     /// <p>An object that contains the properties associated with a document classifier.</p>
     #[serde(rename = "DocumentClassifierProperties")]
@@ -402,14 +672,31 @@ pub struct DescribeDocumentClassifierResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDominantLanguageDetectionJobRequest {
     /// <p>The identifier that Amazon Comprehend generated for the job. The operation returns this identifier in its response.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for DescribeDominantLanguageDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDominantLanguageDetectionJobResponse {
     /// <p>An object that contains the properties associated with a dominant language detection job.</p>
     #[serde(rename = "DominantLanguageDetectionJobProperties")]
@@ -418,14 +705,31 @@ pub struct DescribeDominantLanguageDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEntitiesDetectionJobRequest {
     /// <p>The identifier that Amazon Comprehend generated for the job. The operation returns this identifier in its response.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for DescribeEntitiesDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEntitiesDetectionJobResponse {
     /// <p>An object that contains the properties associated with an entities detection job.</p>
     #[serde(rename = "EntitiesDetectionJobProperties")]
@@ -434,14 +738,27 @@ pub struct DescribeEntitiesDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEntityRecognizerRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the entity recognizer.</p>
     #[serde(rename = "EntityRecognizerArn")]
     pub entity_recognizer_arn: String,
 }
 
+impl Validate for DescribeEntityRecognizerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entity_recognizer_arn"),
+            self.entity_recognizer_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "entity_recognizer_arn"), &self.entity_recognizer_arn, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:entity-recognizer/[a-zA-Z0-9](-*[a-zA-Z0-9])*(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*)?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEntityRecognizerResponse {
     /// <p>Describes information associated with an entity recognizer.</p>
     #[serde(rename = "EntityRecognizerProperties")]
@@ -450,14 +767,31 @@ pub struct DescribeEntityRecognizerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeKeyPhrasesDetectionJobRequest {
     /// <p>The identifier that Amazon Comprehend generated for the job. The operation returns this identifier in its response.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for DescribeKeyPhrasesDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeKeyPhrasesDetectionJobResponse {
     /// <p>An object that contains the properties associated with a key phrases detection job. </p>
     #[serde(rename = "KeyPhrasesDetectionJobProperties")]
@@ -466,14 +800,31 @@ pub struct DescribeKeyPhrasesDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSentimentDetectionJobRequest {
     /// <p>The identifier that Amazon Comprehend generated for the job. The operation returns this identifier in its response.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for DescribeSentimentDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSentimentDetectionJobResponse {
     /// <p>An object that contains the properties associated with a sentiment detection job.</p>
     #[serde(rename = "SentimentDetectionJobProperties")]
@@ -482,14 +833,31 @@ pub struct DescribeSentimentDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTopicsDetectionJobRequest {
     /// <p>The identifier assigned by the user to the detection job.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for DescribeTopicsDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTopicsDetectionJobResponse {
     /// <p>The list of properties for the requested job.</p>
     #[serde(rename = "TopicsDetectionJobProperties")]
@@ -498,14 +866,26 @@ pub struct DescribeTopicsDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectDominantLanguageRequest {
     /// <p>A UTF-8 text string. Each string should contain at least 20 characters and must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "Text")]
     pub text: String,
 }
 
+impl Validate for DetectDominantLanguageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text"),
+            self.text.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectDominantLanguageResponse {
     /// <p>The languages that Amazon Comprehend detected in the input text. For each language, the response returns the RFC 5646 language code and the level of confidence that Amazon Comprehend has in the accuracy of its inference. For more information about RFC 5646, see <a href="https://tools.ietf.org/html/rfc5646">Tags for Identifying Languages</a> on the <i>IETF Tools</i> web site.</p>
     #[serde(rename = "Languages")]
@@ -514,6 +894,7 @@ pub struct DetectDominantLanguageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectEntitiesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
@@ -523,8 +904,19 @@ pub struct DetectEntitiesRequest {
     pub text: String,
 }
 
+impl Validate for DetectEntitiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text"),
+            self.text.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectEntitiesResponse {
     /// <p>A collection of entities identified in the input text. For each entity, the response provides the entity text, entity type, where the entity text begins and ends, and the level of confidence that Amazon Comprehend has in the detection. For a list of entity types, see <a>how-entities</a>. </p>
     #[serde(rename = "Entities")]
@@ -533,6 +925,7 @@ pub struct DetectEntitiesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectKeyPhrasesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
@@ -542,8 +935,19 @@ pub struct DetectKeyPhrasesRequest {
     pub text: String,
 }
 
+impl Validate for DetectKeyPhrasesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text"),
+            self.text.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectKeyPhrasesResponse {
     /// <p>A collection of key phrases that Amazon Comprehend identified in the input text. For each key phrase, the response provides the text of the key phrase, where the key phrase begins and ends, and the level of confidence that Amazon Comprehend has in the accuracy of the detection. </p>
     #[serde(rename = "KeyPhrases")]
@@ -552,6 +956,7 @@ pub struct DetectKeyPhrasesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectSentimentRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
@@ -561,8 +966,19 @@ pub struct DetectSentimentRequest {
     pub text: String,
 }
 
+impl Validate for DetectSentimentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text"),
+            self.text.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectSentimentResponse {
     /// <p>The inferred sentiment that Amazon Comprehend has the highest level of confidence in.</p>
     #[serde(rename = "Sentiment")]
@@ -575,6 +991,7 @@ pub struct DetectSentimentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectSyntaxRequest {
     /// <p>The language code of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt").</p>
     #[serde(rename = "LanguageCode")]
@@ -584,8 +1001,19 @@ pub struct DetectSyntaxRequest {
     pub text: String,
 }
 
+impl Validate for DetectSyntaxRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text"),
+            self.text.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectSyntaxResponse {
     /// <p>A collection of syntax tokens describing the text. For each token, the response provides the text, the token type, where the text begins and ends, and the level of confidence that Amazon Comprehend has that the token is correct. For a list of token types, see <a>how-syntax</a>.</p>
     #[serde(rename = "SyntaxTokens")]
@@ -595,6 +1023,7 @@ pub struct DetectSyntaxResponse {
 
 /// <p>Provides information for filtering a list of document classification jobs. For more information, see the operation. You can provide only one filter parameter in each request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DocumentClassificationJobFilter {
     /// <p>Filters on the name of the job.</p>
     #[serde(rename = "JobName")]
@@ -614,9 +1043,27 @@ pub struct DocumentClassificationJobFilter {
     pub submit_time_before: Option<f64>,
 }
 
+impl Validate for DocumentClassificationJobFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+    }
+}
+
 /// <p>Provides information about a document classification job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentClassificationJobProperties {
     /// <p>The Amazon Resource Name (ARN) of the AWS identity and Access Management (IAM) role that grants Amazon Comprehend read access to your input data.</p>
     #[serde(rename = "DataAccessRoleArn")]
@@ -670,6 +1117,7 @@ pub struct DocumentClassificationJobProperties {
 
 /// <p>Provides information for filtering a list of document classifiers. You can only specify one filtering parameter in a request. For more information, see the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DocumentClassifierFilter {
     /// <p>Filters the list of classifiers based on status. </p>
     #[serde(rename = "Status")]
@@ -693,6 +1141,22 @@ pub struct DocumentClassifierInputDataConfig {
     pub s3_uri: String,
 }
 
+impl Validate for DocumentClassifierInputDataConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "s3_uri"),
+            self.s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_uri"),
+            &self.s3_uri,
+            "s3://[a-z0-9][\\.\\-a-z0-9]{1,61}[a-z0-9](/.*)?",
+        );
+    }
+}
+
 /// <p>Provides output results configuration parameters for custom classifier jobs. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DocumentClassifierOutputDataConfig {
@@ -706,9 +1170,36 @@ pub struct DocumentClassifierOutputDataConfig {
     pub s3_uri: Option<String>,
 }
 
+impl Validate for DocumentClassifierOutputDataConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key_id"), &value, "^\\p{ASCII}+$");
+        }
+        if let Some(ref value) = self.s3_uri {
+            errors.check_length(
+                &field_path(path, "s3_uri"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "s3_uri"),
+                &value,
+                "s3://[a-z0-9][\\.\\-a-z0-9]{1,61}[a-z0-9](/.*)?",
+            );
+        }
+    }
+}
+
 /// <p>Provides information about a document classifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentClassifierProperties {
     /// <p>Information about the document classifier, including the number of documents used for training the classifier, the number of documents used for test the classifier, and an accuracy rating.</p>
     #[serde(rename = "ClassifierMetadata")]
@@ -770,7 +1261,7 @@ pub struct DocumentClassifierProperties {
 
 /// <p>Returns the code for the dominant language in the input text and the level of confidence that Amazon Comprehend has in the accuracy of the detection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DominantLanguage {
     /// <p>The RFC 5646 language code for the dominant language. For more information about RFC 5646, see <a href="https://tools.ietf.org/html/rfc5646">Tags for Identifying Languages</a> on the <i>IETF Tools</i> web site.</p>
     #[serde(rename = "LanguageCode")]
@@ -784,6 +1275,7 @@ pub struct DominantLanguage {
 
 /// <p>Provides information for filtering a list of dominant language detection jobs. For more information, see the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DominantLanguageDetectionJobFilter {
     /// <p>Filters on the name of the job.</p>
     #[serde(rename = "JobName")]
@@ -803,9 +1295,27 @@ pub struct DominantLanguageDetectionJobFilter {
     pub submit_time_before: Option<f64>,
 }
 
+impl Validate for DominantLanguageDetectionJobFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+    }
+}
+
 /// <p>Provides information about a dominant language detection job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DominantLanguageDetectionJobProperties {
     /// <p>The Amazon Resource Name (ARN) that gives Amazon Comprehend read access to your input data.</p>
     #[serde(rename = "DataAccessRoleArn")]
@@ -855,6 +1365,7 @@ pub struct DominantLanguageDetectionJobProperties {
 
 /// <p>Provides information for filtering a list of dominant language detection jobs. For more information, see the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EntitiesDetectionJobFilter {
     /// <p>Filters on the name of the job.</p>
     #[serde(rename = "JobName")]
@@ -874,9 +1385,27 @@ pub struct EntitiesDetectionJobFilter {
     pub submit_time_before: Option<f64>,
 }
 
+impl Validate for EntitiesDetectionJobFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+    }
+}
+
 /// <p>Provides information about an entities detection job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EntitiesDetectionJobProperties {
     /// <p>The Amazon Resource Name (ARN) that gives Amazon Comprehend read access to your input data.</p>
     #[serde(rename = "DataAccessRoleArn")]
@@ -934,7 +1463,7 @@ pub struct EntitiesDetectionJobProperties {
 
 /// <p>Provides information about an entity. </p> <p> </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Entity {
     /// <p>A character offset in the input text that shows where the entity begins (the first character is at position 0). The offset returns the position of each UTF-8 code point in the string. A <i>code point</i> is the abstract character from a particular graphical representation. For example, a multi-byte UTF-8 character maps to a single code point.</p>
     #[serde(rename = "BeginOffset")]
@@ -966,6 +1495,22 @@ pub struct EntityRecognizerAnnotations {
     pub s3_uri: String,
 }
 
+impl Validate for EntityRecognizerAnnotations {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "s3_uri"),
+            self.s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_uri"),
+            &self.s3_uri,
+            "s3://[a-z0-9][\\.\\-a-z0-9]{1,61}[a-z0-9](/.*)?",
+        );
+    }
+}
+
 /// <p>Describes the training documents submitted with an entity recognizer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EntityRecognizerDocuments {
@@ -974,6 +1519,22 @@ pub struct EntityRecognizerDocuments {
     pub s3_uri: String,
 }
 
+impl Validate for EntityRecognizerDocuments {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "s3_uri"),
+            self.s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_uri"),
+            &self.s3_uri,
+            "s3://[a-z0-9][\\.\\-a-z0-9]{1,61}[a-z0-9](/.*)?",
+        );
+    }
+}
+
 /// <p>Describes the entity recognizer submitted with an entity recognizer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EntityRecognizerEntityList {
@@ -982,9 +1543,25 @@ pub struct EntityRecognizerEntityList {
     pub s3_uri: String,
 }
 
+impl Validate for EntityRecognizerEntityList {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "s3_uri"),
+            self.s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_uri"),
+            &self.s3_uri,
+            "s3://[a-z0-9][\\.\\-a-z0-9]{1,61}[a-z0-9](/.*)?",
+        );
+    }
+}
+
 /// <p>Detailed information about the accuracy of an entity recognizer. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EntityRecognizerEvaluationMetrics {
     /// <p>A measure of how accurate the recognizer results are for the test data. It is derived from the <code>Precision</code> and <code>Recall</code> values. The <code>F1Score</code> is the harmonic average of the two scores. The highest score is 1, and the worst score is 0. </p>
     #[serde(rename = "F1Score")]
@@ -1002,6 +1579,7 @@ pub struct EntityRecognizerEvaluationMetrics {
 
 /// <p>Provides information for filtering a list of entity recognizers. You can only specify one filtering parameter in a request. For more information, see the operation./&gt;</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EntityRecognizerFilter {
     /// <p>The status of an entity recognizer.</p>
     #[serde(rename = "Status")]
@@ -1036,9 +1614,26 @@ pub struct EntityRecognizerInputDataConfig {
     pub entity_types: Vec<EntityTypesListItem>,
 }
 
+impl Validate for EntityRecognizerInputDataConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.annotations {
+            value.validate_at(&field_path(path, "annotations"), errors);
+        }
+        self.documents
+            .validate_at(&field_path(path, "documents"), errors);
+        if let Some(ref value) = self.entity_list {
+            value.validate_at(&field_path(path, "entity_list"), errors);
+        }
+        for (i, item) in self.entity_types.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "entity_types"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Detailed information about an entity recognizer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EntityRecognizerMetadata {
     /// <p>Entity types from the metadata of an entity recognizer.</p>
     #[serde(rename = "EntityTypes")]
@@ -1060,7 +1655,7 @@ pub struct EntityRecognizerMetadata {
 
 /// <p>Individual item from the list of entity types in the metadata of an entity recognizer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EntityRecognizerMetadataEntityTypesListItem {
     /// <p>Detailed information about the accuracy of the entity recognizer for a specific item on the list of entity types. </p>
     #[serde(rename = "EvaluationMetrics")]
@@ -1078,7 +1673,7 @@ pub struct EntityRecognizerMetadataEntityTypesListItem {
 
 /// <p>Describes information about an entity recognizer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EntityRecognizerProperties {
     /// <p> The Amazon Resource Name (ARN) of the AWS Identity and Management (IAM) role that grants Amazon Comprehend read access to your input data.</p>
     #[serde(rename = "DataAccessRoleArn")]
@@ -1136,7 +1731,7 @@ pub struct EntityRecognizerProperties {
 
 /// <p>Detailed information about the accuracy of an entity recognizer for a specific entity type. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EntityTypesEvaluationMetrics {
     /// <p>A measure of how accurate the recognizer results are for for a specific entity type in the test data. It is derived from the <code>Precision</code> and <code>Recall</code> values. The <code>F1Score</code> is the harmonic average of the two scores. The highest score is 1, and the worst score is 0. </p>
     #[serde(rename = "F1Score")]
@@ -1160,6 +1755,22 @@ pub struct EntityTypesListItem {
     pub type_: String,
 }
 
+impl Validate for EntityTypesListItem {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "type_"),
+            self.type_.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_"),
+            &self.type_,
+            "^(?![^\\n\\r\\t,]*\\\\n|\\\\r|\\\\t)[^\\n\\r\\t,]+$",
+        );
+    }
+}
+
 /// <p>The input properties for a topic detection job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputDataConfig {
@@ -1172,9 +1783,25 @@ pub struct InputDataConfig {
     pub s3_uri: String,
 }
 
+impl Validate for InputDataConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "s3_uri"),
+            self.s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_uri"),
+            &self.s3_uri,
+            "s3://[a-z0-9][\\.\\-a-z0-9]{1,61}[a-z0-9](/.*)?",
+        );
+    }
+}
+
 /// <p>Describes a key noun phrase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KeyPhrase {
     /// <p>A character offset in the input text that shows where the key phrase begins (the first character is at position 0). The offset returns the position of each UTF-8 code point in the string. A <i>code point</i> is the abstract character from a particular graphical representation. For example, a multi-byte UTF-8 character maps to a single code point.</p>
     #[serde(rename = "BeginOffset")]
@@ -1196,6 +1823,7 @@ pub struct KeyPhrase {
 
 /// <p>Provides information for filtering a list of dominant language detection jobs. For more information, see the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KeyPhrasesDetectionJobFilter {
     /// <p>Filters on the name of the job.</p>
     #[serde(rename = "JobName")]
@@ -1215,9 +1843,27 @@ pub struct KeyPhrasesDetectionJobFilter {
     pub submit_time_before: Option<f64>,
 }
 
+impl Validate for KeyPhrasesDetectionJobFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+    }
+}
+
 /// <p>Provides information about a key phrases detection job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KeyPhrasesDetectionJobProperties {
     /// <p>The Amazon Resource Name (ARN) that gives Amazon Comprehend read access to your input data.</p>
     #[serde(rename = "DataAccessRoleArn")]
@@ -1270,6 +1916,7 @@ pub struct KeyPhrasesDetectionJobProperties {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDocumentClassificationJobsRequest {
     /// <p>Filters the jobs that are returned. You can filter jobs on their names, status, or the date and time that they were submitted. You can only set one filter at a time.</p>
     #[serde(rename = "Filter")]
@@ -1285,8 +1932,27 @@ pub struct ListDocumentClassificationJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDocumentClassificationJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDocumentClassificationJobsResponse {
     /// <p>A list containing the properties of each job returned.</p>
     #[serde(rename = "DocumentClassificationJobPropertiesList")]
@@ -1300,6 +1966,7 @@ pub struct ListDocumentClassificationJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDocumentClassifiersRequest {
     /// <p>Filters the jobs that are returned. You can filter jobs on their name, status, or the date and time that they were submitted. You can only set one filter at a time.</p>
     #[serde(rename = "Filter")]
@@ -1315,8 +1982,24 @@ pub struct ListDocumentClassifiersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDocumentClassifiersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDocumentClassifiersResponse {
     /// <p>A list containing the properties of each job returned.</p>
     #[serde(rename = "DocumentClassifierPropertiesList")]
@@ -1329,6 +2012,7 @@ pub struct ListDocumentClassifiersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDominantLanguageDetectionJobsRequest {
     /// <p>Filters that jobs that are returned. You can filter jobs on their name, status, or the date and time that they were submitted. You can only set one filter at a time.</p>
     #[serde(rename = "Filter")]
@@ -1344,8 +2028,27 @@ pub struct ListDominantLanguageDetectionJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDominantLanguageDetectionJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDominantLanguageDetectionJobsResponse {
     /// <p>A list containing the properties of each job that is returned.</p>
     #[serde(rename = "DominantLanguageDetectionJobPropertiesList")]
@@ -1359,6 +2062,7 @@ pub struct ListDominantLanguageDetectionJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEntitiesDetectionJobsRequest {
     /// <p>Filters the jobs that are returned. You can filter jobs on their name, status, or the date and time that they were submitted. You can only set one filter at a time.</p>
     #[serde(rename = "Filter")]
@@ -1374,8 +2078,27 @@ pub struct ListEntitiesDetectionJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListEntitiesDetectionJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListEntitiesDetectionJobsResponse {
     /// <p>A list containing the properties of each job that is returned.</p>
     #[serde(rename = "EntitiesDetectionJobPropertiesList")]
@@ -1388,6 +2111,7 @@ pub struct ListEntitiesDetectionJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEntityRecognizersRequest {
     /// <p>Filters the list of entities returned. You can filter on <code>Status</code>, <code>SubmitTimeBefore</code>, or <code>SubmitTimeAfter</code>. You can only set one filter at a time.</p>
     #[serde(rename = "Filter")]
@@ -1403,8 +2127,24 @@ pub struct ListEntityRecognizersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListEntityRecognizersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListEntityRecognizersResponse {
     /// <p>The list of properties of an entity recognizer.</p>
     #[serde(rename = "EntityRecognizerPropertiesList")]
@@ -1417,6 +2157,7 @@ pub struct ListEntityRecognizersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListKeyPhrasesDetectionJobsRequest {
     /// <p>Filters the jobs that are returned. You can filter jobs on their name, status, or the date and time that they were submitted. You can only set one filter at a time.</p>
     #[serde(rename = "Filter")]
@@ -1432,8 +2173,27 @@ pub struct ListKeyPhrasesDetectionJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListKeyPhrasesDetectionJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListKeyPhrasesDetectionJobsResponse {
     /// <p>A list containing the properties of each job that is returned.</p>
     #[serde(rename = "KeyPhrasesDetectionJobPropertiesList")]
@@ -1446,6 +2206,7 @@ pub struct ListKeyPhrasesDetectionJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSentimentDetectionJobsRequest {
     /// <p>Filters the jobs that are returned. You can filter jobs on their name, status, or the date and time that they were submitted. You can only set one filter at a time.</p>
     #[serde(rename = "Filter")]
@@ -1461,8 +2222,27 @@ pub struct ListSentimentDetectionJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListSentimentDetectionJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSentimentDetectionJobsResponse {
     /// <p>Identifies the next page of results to return.</p>
     #[serde(rename = "NextToken")]
@@ -1475,14 +2255,27 @@ pub struct ListSentimentDetectionJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the given Amazon Comprehend resource you are querying. </p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:[a-zA-Z0-9-]{1,64}/[a-zA-Z0-9](-*[a-zA-Z0-9])*((/dataset/[a-zA-Z0-9](-*[a-zA-Z0-9])*)|(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*))?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The Amazon Resource Name (ARN) of the given Amazon Comprehend resource you are querying.</p>
     #[serde(rename = "ResourceArn")]
@@ -1495,6 +2288,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTopicsDetectionJobsRequest {
     /// <p>Filters the jobs that are returned. Jobs can be filtered on their name, status, or the date and time that they were submitted. You can set only one filter at a time.</p>
     #[serde(rename = "Filter")]
@@ -1510,8 +2304,27 @@ pub struct ListTopicsDetectionJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListTopicsDetectionJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTopicsDetectionJobsResponse {
     /// <p>Identifies the next page of results to return.</p>
     #[serde(rename = "NextToken")]
@@ -1535,9 +2348,34 @@ pub struct OutputDataConfig {
     pub s3_uri: String,
 }
 
+impl Validate for OutputDataConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key_id"), &value, "^\\p{ASCII}+$");
+        }
+        errors.check_length(
+            &field_path(path, "s3_uri"),
+            self.s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_uri"),
+            &self.s3_uri,
+            "s3://[a-z0-9][\\.\\-a-z0-9]{1,61}[a-z0-9](/.*)?",
+        );
+    }
+}
+
 /// <p>Identifies the part of speech represented by the token and gives the confidence that Amazon Comprehend has that the part of speech was correctly identified. For more information about the parts of speech that Amazon Comprehend can identify, see <a>how-syntax</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PartOfSpeechTag {
     /// <p>The confidence that Amazon Comprehend has that the part of speech was correctly identified.</p>
     #[serde(rename = "Score")]
@@ -1551,6 +2389,7 @@ pub struct PartOfSpeechTag {
 
 /// <p>Provides information for filtering a list of dominant language detection jobs. For more information, see the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SentimentDetectionJobFilter {
     /// <p>Filters on the name of the job.</p>
     #[serde(rename = "JobName")]
@@ -1570,9 +2409,27 @@ pub struct SentimentDetectionJobFilter {
     pub submit_time_before: Option<f64>,
 }
 
+impl Validate for SentimentDetectionJobFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+    }
+}
+
 /// <p>Provides information about a sentiment detection job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SentimentDetectionJobProperties {
     /// <p>The Amazon Resource Name (ARN) that gives Amazon Comprehend read access to your input data.</p>
     #[serde(rename = "DataAccessRoleArn")]
@@ -1626,7 +2483,7 @@ pub struct SentimentDetectionJobProperties {
 
 /// <p>Describes the level of confidence that Amazon Comprehend has in the accuracy of its detection of sentiments.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SentimentScore {
     /// <p>The level of confidence that Amazon Comprehend has in the accuracy of its detection of the <code>MIXED</code> sentiment.</p>
     #[serde(rename = "Mixed")]
@@ -1647,6 +2504,7 @@ pub struct SentimentScore {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartDocumentClassificationJobRequest {
     /// <p>A unique identifier for the request. If you do not set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -1678,8 +2536,77 @@ pub struct StartDocumentClassificationJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for StartDocumentClassificationJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "data_access_role_arn"),
+            self.data_access_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_access_role_arn"),
+            &self.data_access_role_arn,
+            "arn:aws(-[^:]+)?:iam::[0-9]{12}:role/.+",
+        );
+        errors.check_length(
+            &field_path(path, "document_classifier_arn"),
+            self.document_classifier_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "document_classifier_arn"), &self.document_classifier_arn, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:document-classifier/[a-zA-Z0-9](-*[a-zA-Z0-9])*(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*)?");
+        self.input_data_config
+            .validate_at(&field_path(path, "input_data_config"), errors);
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+        self.output_data_config
+            .validate_at(&field_path(path, "output_data_config"), errors);
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "volume_kms_key_id"),
+                &value,
+                "^\\p{ASCII}+$",
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartDocumentClassificationJobResponse {
     /// <p>The identifier generated for the job. To get the status of the job, use this identifier with the operation.</p>
     #[serde(rename = "JobId")]
@@ -1692,6 +2619,7 @@ pub struct StartDocumentClassificationJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartDominantLanguageDetectionJobRequest {
     /// <p>A unique identifier for the request. If you do not set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -1720,8 +2648,70 @@ pub struct StartDominantLanguageDetectionJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for StartDominantLanguageDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "data_access_role_arn"),
+            self.data_access_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_access_role_arn"),
+            &self.data_access_role_arn,
+            "arn:aws(-[^:]+)?:iam::[0-9]{12}:role/.+",
+        );
+        self.input_data_config
+            .validate_at(&field_path(path, "input_data_config"), errors);
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+        self.output_data_config
+            .validate_at(&field_path(path, "output_data_config"), errors);
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "volume_kms_key_id"),
+                &value,
+                "^\\p{ASCII}+$",
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartDominantLanguageDetectionJobResponse {
     /// <p>The identifier generated for the job. To get the status of a job, use this identifier with the operation.</p>
     #[serde(rename = "JobId")]
@@ -1734,6 +2724,7 @@ pub struct StartDominantLanguageDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartEntitiesDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -1769,11 +2760,82 @@ pub struct StartEntitiesDetectionJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct StartEntitiesDetectionJobResponse {
-    /// <p>The identifier generated for the job. To get the status of job, use this identifier with the operation.</p>
-    #[serde(rename = "JobId")]
+impl Validate for StartEntitiesDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "data_access_role_arn"),
+            self.data_access_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_access_role_arn"),
+            &self.data_access_role_arn,
+            "arn:aws(-[^:]+)?:iam::[0-9]{12}:role/.+",
+        );
+        if let Some(ref value) = self.entity_recognizer_arn {
+            errors.check_length(
+                &field_path(path, "entity_recognizer_arn"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "entity_recognizer_arn"), &value, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:entity-recognizer/[a-zA-Z0-9](-*[a-zA-Z0-9])*(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*)?");
+        }
+        self.input_data_config
+            .validate_at(&field_path(path, "input_data_config"), errors);
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+        self.output_data_config
+            .validate_at(&field_path(path, "output_data_config"), errors);
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "volume_kms_key_id"),
+                &value,
+                "^\\p{ASCII}+$",
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct StartEntitiesDetectionJobResponse {
+    /// <p>The identifier generated for the job. To get the status of job, use this identifier with the operation.</p>
+    #[serde(rename = "JobId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub job_id: Option<String>,
     /// <p><p>The status of the job. </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN<em>PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the operation.</p> </li> <li> <p>STOP</em>REQUESTED - Amazon Comprehend has received a stop request for the job and is processing the request.</p> </li> <li> <p>STOPPED - The job was successfully stopped without completing.</p> </li> </ul></p>
@@ -1783,6 +2845,7 @@ pub struct StartEntitiesDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartKeyPhrasesDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -1814,8 +2877,70 @@ pub struct StartKeyPhrasesDetectionJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for StartKeyPhrasesDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "data_access_role_arn"),
+            self.data_access_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_access_role_arn"),
+            &self.data_access_role_arn,
+            "arn:aws(-[^:]+)?:iam::[0-9]{12}:role/.+",
+        );
+        self.input_data_config
+            .validate_at(&field_path(path, "input_data_config"), errors);
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+        self.output_data_config
+            .validate_at(&field_path(path, "output_data_config"), errors);
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "volume_kms_key_id"),
+                &value,
+                "^\\p{ASCII}+$",
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartKeyPhrasesDetectionJobResponse {
     /// <p>The identifier generated for the job. To get the status of a job, use this identifier with the operation.</p>
     #[serde(rename = "JobId")]
@@ -1828,6 +2953,7 @@ pub struct StartKeyPhrasesDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartSentimentDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -1859,8 +2985,70 @@ pub struct StartSentimentDetectionJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for StartSentimentDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "data_access_role_arn"),
+            self.data_access_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_access_role_arn"),
+            &self.data_access_role_arn,
+            "arn:aws(-[^:]+)?:iam::[0-9]{12}:role/.+",
+        );
+        self.input_data_config
+            .validate_at(&field_path(path, "input_data_config"), errors);
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+        self.output_data_config
+            .validate_at(&field_path(path, "output_data_config"), errors);
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "volume_kms_key_id"),
+                &value,
+                "^\\p{ASCII}+$",
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartSentimentDetectionJobResponse {
     /// <p>The identifier generated for the job. To get the status of a job, use this identifier with the operation.</p>
     #[serde(rename = "JobId")]
@@ -1873,6 +3061,7 @@ pub struct StartSentimentDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartTopicsDetectionJobRequest {
     /// <p>A unique identifier for the request. If you do not set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -1905,8 +3094,78 @@ pub struct StartTopicsDetectionJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for StartTopicsDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "data_access_role_arn"),
+            self.data_access_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_access_role_arn"),
+            &self.data_access_role_arn,
+            "arn:aws(-[^:]+)?:iam::[0-9]{12}:role/.+",
+        );
+        self.input_data_config
+            .validate_at(&field_path(path, "input_data_config"), errors);
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+        if let Some(ref value) = self.number_of_topics {
+            errors.check_range(
+                &field_path(path, "number_of_topics"),
+                *value,
+                Some(1),
+                Some(100),
+            );
+        }
+        self.output_data_config
+            .validate_at(&field_path(path, "output_data_config"), errors);
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "volume_kms_key_id"),
+                &value,
+                "^\\p{ASCII}+$",
+            );
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartTopicsDetectionJobResponse {
     /// <p>The identifier generated for the job. To get the status of the job, use this identifier with the <code>DescribeTopicDetectionJob</code> operation.</p>
     #[serde(rename = "JobId")]
@@ -1919,14 +3178,31 @@ pub struct StartTopicsDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopDominantLanguageDetectionJobRequest {
     /// <p>The identifier of the dominant language detection job to stop.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for StopDominantLanguageDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopDominantLanguageDetectionJobResponse {
     /// <p>The identifier of the dominant language detection job to stop.</p>
     #[serde(rename = "JobId")]
@@ -1939,14 +3215,31 @@ pub struct StopDominantLanguageDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopEntitiesDetectionJobRequest {
     /// <p>The identifier of the entities detection job to stop.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for StopEntitiesDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopEntitiesDetectionJobResponse {
     /// <p>The identifier of the entities detection job to stop.</p>
     #[serde(rename = "JobId")]
@@ -1959,14 +3252,31 @@ pub struct StopEntitiesDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopKeyPhrasesDetectionJobRequest {
     /// <p>The identifier of the key phrases detection job to stop.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for StopKeyPhrasesDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopKeyPhrasesDetectionJobResponse {
     /// <p>The identifier of the key phrases detection job to stop.</p>
     #[serde(rename = "JobId")]
@@ -1979,14 +3289,31 @@ pub struct StopKeyPhrasesDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopSentimentDetectionJobRequest {
     /// <p>The identifier of the sentiment detection job to stop.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for StopSentimentDetectionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopSentimentDetectionJobResponse {
     /// <p>The identifier of the sentiment detection job to stop.</p>
     #[serde(rename = "JobId")]
@@ -1999,30 +3326,56 @@ pub struct StopSentimentDetectionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopTrainingDocumentClassifierRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the document classifier currently being trained.</p>
     #[serde(rename = "DocumentClassifierArn")]
     pub document_classifier_arn: String,
 }
 
+impl Validate for StopTrainingDocumentClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "document_classifier_arn"),
+            self.document_classifier_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "document_classifier_arn"), &self.document_classifier_arn, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:document-classifier/[a-zA-Z0-9](-*[a-zA-Z0-9])*(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*)?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopTrainingDocumentClassifierResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopTrainingEntityRecognizerRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the entity recognizer currently being trained.</p>
     #[serde(rename = "EntityRecognizerArn")]
     pub entity_recognizer_arn: String,
 }
 
+impl Validate for StopTrainingEntityRecognizerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entity_recognizer_arn"),
+            self.entity_recognizer_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "entity_recognizer_arn"), &self.entity_recognizer_arn, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:entity-recognizer/[a-zA-Z0-9](-*[a-zA-Z0-9])*(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*)?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopTrainingEntityRecognizerResponse {}
 
 /// <p>Represents a work in the input text that was recognized and assigned a part of speech. There is one syntax token record for each word in the source text.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SyntaxToken {
     /// <p>The zero-based offset from the beginning of the source text to the first character in the word.</p>
     #[serde(rename = "BeginOffset")]
@@ -2058,7 +3411,27 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the given Amazon Comprehend resource to which you want to associate the tags. </p>
     #[serde(rename = "ResourceArn")]
@@ -2068,12 +3441,29 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:[a-zA-Z0-9-]{1,64}/[a-zA-Z0-9](-*[a-zA-Z0-9])*((/dataset/[a-zA-Z0-9](-*[a-zA-Z0-9])*)|(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*))?");
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>Provides information for filtering topic detection jobs. For more information, see .</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TopicsDetectionJobFilter {
     /// <p><p/></p>
     #[serde(rename = "JobName")]
@@ -2093,9 +3483,27 @@ pub struct TopicsDetectionJobFilter {
     pub submit_time_before: Option<f64>,
 }
 
+impl Validate for TopicsDetectionJobFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-%@]*)$",
+            );
+        }
+    }
+}
+
 /// <p>Provides information about a topic detection job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TopicsDetectionJobProperties {
     /// <p>The Amazon Resource Name (ARN) of the AWS Identity and Management (IAM) role that grants Amazon Comprehend read access to your job data. </p>
     #[serde(rename = "DataAccessRoleArn")]
@@ -2148,6 +3556,7 @@ pub struct TopicsDetectionJobProperties {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p> The Amazon Resource Name (ARN) of the given Amazon Comprehend resource from which you want to remove the tags. </p>
     #[serde(rename = "ResourceArn")]
@@ -2157,8 +3566,24 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "arn:aws(-[^:]+)?:comprehend:[a-zA-Z0-9-]*:[0-9]{12}:[a-zA-Z0-9-]{1,64}/[a-zA-Z0-9](-*[a-zA-Z0-9])*((/dataset/[a-zA-Z0-9](-*[a-zA-Z0-9])*)|(/version/[a-zA-Z0-9](-*[a-zA-Z0-9])*))?");
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 /// <p> Configuration parameters for an optional private Virtual Private Cloud (VPC) containing the resources you are using for the job. For For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/what-is-amazon-vpc.html">Amazon VPC</a>. </p>
@@ -2172,6 +3597,33 @@ pub struct VpcConfig {
     pub subnets: Vec<String>,
 }
 
+impl Validate for VpcConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "security_group_ids"),
+            self.security_group_ids.len(),
+            Some(1),
+            Some(5),
+        );
+        for (i, item) in self.security_group_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "security_group_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+            errors.check_pattern(&item_path, &item, "[-0-9a-zA-Z]+");
+        }
+        errors.check_length(
+            &field_path(path, "subnets"),
+            self.subnets.len(),
+            Some(1),
+            Some(16),
+        );
+        for (i, item) in self.subnets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subnets"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+            errors.check_pattern(&item_path, &item, "[-0-9a-zA-Z]+");
+        }
+    }
+}
+
 /// Errors returned by BatchDetectDominantLanguage
 #[derive(Debug, PartialEq)]
 pub enum BatchDetectDominantLanguageError {
@@ -2211,7 +3663,12 @@ impl BatchDetectDominantLanguageError {
                         BatchDetectDominantLanguageError::TextSizeLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2273,7 +3730,12 @@ impl BatchDetectEntitiesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2340,7 +3802,12 @@ impl BatchDetectKeyPhrasesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2403,7 +3870,12 @@ impl BatchDetectSentimentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2466,7 +3938,12 @@ impl BatchDetectSyntaxError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2554,7 +4031,12 @@ impl CreateDocumentClassifierError {
                         CreateDocumentClassifierError::UnsupportedLanguage(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2643,7 +4125,12 @@ impl CreateEntityRecognizerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2720,7 +4207,12 @@ impl DeleteDocumentClassifierError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2795,7 +4287,12 @@ impl DeleteEntityRecognizerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2858,7 +4355,12 @@ impl DescribeDocumentClassificationJobError {
                         DescribeDocumentClassificationJobError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2919,7 +4421,12 @@ impl DescribeDocumentClassifierError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2980,7 +4487,12 @@ impl DescribeDominantLanguageDetectionJobError {
                         DescribeDominantLanguageDetectionJobError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3041,7 +4553,12 @@ impl DescribeEntitiesDetectionJobError {
                         DescribeEntitiesDetectionJobError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3100,7 +4617,12 @@ impl DescribeEntityRecognizerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3161,7 +4683,12 @@ impl DescribeKeyPhrasesDetectionJobError {
                         DescribeKeyPhrasesDetectionJobError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3222,7 +4749,12 @@ impl DescribeSentimentDetectionJobError {
                         DescribeSentimentDetectionJobError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3283,7 +4815,12 @@ impl DescribeTopicsDetectionJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3335,7 +4872,12 @@ impl DetectDominantLanguageError {
                         DetectDominantLanguageError::TextSizeLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3387,7 +4929,12 @@ impl DetectEntitiesError {
                 "UnsupportedLanguageException" => {
                     return RusotoError::Service(DetectEntitiesError::UnsupportedLanguage(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3442,7 +4989,12 @@ impl DetectKeyPhrasesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3495,7 +5047,12 @@ impl DetectSentimentError {
                 "UnsupportedLanguageException" => {
                     return RusotoError::Service(DetectSentimentError::UnsupportedLanguage(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3546,7 +5103,12 @@ impl DetectSyntaxError {
                 "UnsupportedLanguageException" => {
                     return RusotoError::Service(DetectSyntaxError::UnsupportedLanguage(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3607,7 +5169,12 @@ impl ListDocumentClassificationJobsError {
                         ListDocumentClassificationJobsError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3666,7 +5233,12 @@ impl ListDocumentClassifiersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3727,7 +5299,12 @@ impl ListDominantLanguageDetectionJobsError {
                         ListDominantLanguageDetectionJobsError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3786,7 +5363,12 @@ impl ListEntitiesDetectionJobsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3843,7 +5425,12 @@ impl ListEntityRecognizersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3904,7 +5491,12 @@ impl ListKeyPhrasesDetectionJobsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3965,7 +5557,12 @@ impl ListSentimentDetectionJobsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4013,7 +5610,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4071,7 +5673,12 @@ impl ListTopicsDetectionJobsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4146,7 +5753,12 @@ impl StartDocumentClassificationJobError {
                         StartDocumentClassificationJobError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4209,7 +5821,12 @@ impl StartDominantLanguageDetectionJobError {
                         StartDominantLanguageDetectionJobError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4282,7 +5899,12 @@ impl StartEntitiesDetectionJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4345,7 +5967,12 @@ impl StartKeyPhrasesDetectionJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4406,7 +6033,12 @@ impl StartSentimentDetectionJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4465,7 +6097,12 @@ impl StartTopicsDetectionJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4519,7 +6156,12 @@ impl StopDominantLanguageDetectionJobError {
                         StopDominantLanguageDetectionJobError::JobNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4570,7 +6212,12 @@ impl StopEntitiesDetectionJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4623,7 +6270,12 @@ impl StopKeyPhrasesDetectionJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4674,7 +6326,12 @@ impl StopSentimentDetectionJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4734,7 +6391,12 @@ impl StopTrainingDocumentClassifierError {
                         StopTrainingDocumentClassifierError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4795,7 +6457,12 @@ impl StopTrainingEntityRecognizerError {
                         StopTrainingEntityRecognizerError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4851,7 +6518,12 @@ impl TagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4910,7 +6582,12 @@ impl UntagResourceError {
                 "TooManyTagKeysException" => {
                     return RusotoError::Service(UntagResourceError::TooManyTagKeys(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5217,301 +6894,62 @@ pub trait Comprehend {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
 }
-/// A client for the Amazon Comprehend API.
-#[derive(Clone)]
-pub struct ComprehendClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ComprehendClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ComprehendClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ComprehendClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ComprehendClient {
-        ComprehendClient { client, region }
-    }
-}
-
-impl Comprehend for ComprehendClient {
-    /// <p>Determines the dominant language of the input text for a batch of documents. For a list of languages that Amazon Comprehend can detect, see <a href="https://docs.aws.amazon.com/comprehend/latest/dg/how-languages.html">Amazon Comprehend Supported Languages</a>. </p>
+const _: Option<&dyn Comprehend> = None;
+impl<'a, T: Comprehend + ?Sized> Comprehend for &'a T {
     fn batch_detect_dominant_language(
         &self,
         input: BatchDetectDominantLanguageRequest,
     ) -> RusotoFuture<BatchDetectDominantLanguageResponse, BatchDetectDominantLanguageError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.BatchDetectDominantLanguage",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDetectDominantLanguageResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchDetectDominantLanguageError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_detect_dominant_language(input)
     }
-
-    /// <p>Inspects the text of a batch of documents for named entities and returns information about them. For more information about named entities, see <a>how-entities</a> </p>
     fn batch_detect_entities(
         &self,
         input: BatchDetectEntitiesRequest,
     ) -> RusotoFuture<BatchDetectEntitiesResponse, BatchDetectEntitiesError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectEntities");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDetectEntitiesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchDetectEntitiesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_detect_entities(input)
     }
-
-    /// <p>Detects the key noun phrases found in a batch of documents.</p>
     fn batch_detect_key_phrases(
         &self,
         input: BatchDetectKeyPhrasesRequest,
     ) -> RusotoFuture<BatchDetectKeyPhrasesResponse, BatchDetectKeyPhrasesError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectKeyPhrases");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDetectKeyPhrasesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchDetectKeyPhrasesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_detect_key_phrases(input)
     }
-
-    /// <p>Inspects a batch of documents and returns an inference of the prevailing sentiment, <code>POSITIVE</code>, <code>NEUTRAL</code>, <code>MIXED</code>, or <code>NEGATIVE</code>, in each one.</p>
     fn batch_detect_sentiment(
         &self,
         input: BatchDetectSentimentRequest,
     ) -> RusotoFuture<BatchDetectSentimentResponse, BatchDetectSentimentError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSentiment");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDetectSentimentResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchDetectSentimentError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_detect_sentiment(input)
     }
-
-    /// <p>Inspects the text of a batch of documents for the syntax and part of speech of the words in the document and returns information about them. For more information, see <a>how-syntax</a>.</p>
     fn batch_detect_syntax(
         &self,
         input: BatchDetectSyntaxRequest,
     ) -> RusotoFuture<BatchDetectSyntaxResponse, BatchDetectSyntaxError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSyntax");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDetectSyntaxResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchDetectSyntaxError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_detect_syntax(input)
     }
-
-    /// <p>Creates a new document classifier that you can use to categorize documents. To create a classifier you provide a set of training documents that labeled with the categories that you want to use. After the classifier is trained you can use it to categorize a set of labeled documents into the categories. For more information, see <a>how-document-classification</a>.</p>
     fn create_document_classifier(
         &self,
         input: CreateDocumentClassifierRequest,
     ) -> RusotoFuture<CreateDocumentClassifierResponse, CreateDocumentClassifierError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.CreateDocumentClassifier",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDocumentClassifierResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDocumentClassifierError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_document_classifier(input)
     }
-
-    /// <p>Creates an entity recognizer using submitted files. After your <code>CreateEntityRecognizer</code> request is submitted, you can check job status using the API. </p>
     fn create_entity_recognizer(
         &self,
         input: CreateEntityRecognizerRequest,
     ) -> RusotoFuture<CreateEntityRecognizerResponse, CreateEntityRecognizerError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.CreateEntityRecognizer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateEntityRecognizerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateEntityRecognizerError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_entity_recognizer(input)
     }
-
-    /// <p>Deletes a previously created document classifier</p> <p>Only those classifiers that are in terminated states (IN_ERROR, TRAINED) will be deleted. If an active inference job is using the model, a <code>ResourceInUseException</code> will be returned.</p> <p>This is an asynchronous action that puts the classifier into a DELETING state, and it is then removed by a background job. Once removed, the classifier disappears from your account and is no longer available for use. </p>
     fn delete_document_classifier(
         &self,
         input: DeleteDocumentClassifierRequest,
     ) -> RusotoFuture<DeleteDocumentClassifierResponse, DeleteDocumentClassifierError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.DeleteDocumentClassifier",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDocumentClassifierResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteDocumentClassifierError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_document_classifier(input)
     }
-
-    /// <p>Deletes an entity recognizer.</p> <p>Only those recognizers that are in terminated states (IN_ERROR, TRAINED) will be deleted. If an active inference job is using the model, a <code>ResourceInUseException</code> will be returned.</p> <p>This is an asynchronous action that puts the recognizer into a DELETING state, and it is then removed by a background job. Once removed, the recognizer disappears from your account and is no longer available for use. </p>
     fn delete_entity_recognizer(
         &self,
         input: DeleteEntityRecognizerRequest,
     ) -> RusotoFuture<DeleteEntityRecognizerResponse, DeleteEntityRecognizerError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.DeleteEntityRecognizer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteEntityRecognizerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteEntityRecognizerError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_entity_recognizer(input)
     }
-
-    /// <p>Gets the properties associated with a document classification job. Use this operation to get the status of a classification job.</p>
     fn describe_document_classification_job(
         &self,
         input: DescribeDocumentClassificationJobRequest,
@@ -5519,62 +6957,14 @@ impl Comprehend for ComprehendClient {
         DescribeDocumentClassificationJobResponse,
         DescribeDocumentClassificationJobError,
     > {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.DescribeDocumentClassificationJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDocumentClassificationJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDocumentClassificationJobError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_document_classification_job(input)
     }
-
-    /// <p>Gets the properties associated with a document classifier.</p>
     fn describe_document_classifier(
         &self,
         input: DescribeDocumentClassifierRequest,
     ) -> RusotoFuture<DescribeDocumentClassifierResponse, DescribeDocumentClassifierError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.DescribeDocumentClassifier",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDocumentClassifierResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDocumentClassifierError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_document_classifier(input)
     }
-
-    /// <p>Gets the properties associated with a dominant language detection job. Use this operation to get the status of a detection job.</p>
     fn describe_dominant_language_detection_job(
         &self,
         input: DescribeDominantLanguageDetectionJobRequest,
@@ -5582,383 +6972,83 @@ impl Comprehend for ComprehendClient {
         DescribeDominantLanguageDetectionJobResponse,
         DescribeDominantLanguageDetectionJobError,
     > {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.DescribeDominantLanguageDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDominantLanguageDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDominantLanguageDetectionJobError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_dominant_language_detection_job(input)
     }
-
-    /// <p>Gets the properties associated with an entities detection job. Use this operation to get the status of a detection job.</p>
     fn describe_entities_detection_job(
         &self,
         input: DescribeEntitiesDetectionJobRequest,
     ) -> RusotoFuture<DescribeEntitiesDetectionJobResponse, DescribeEntitiesDetectionJobError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.DescribeEntitiesDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEntitiesDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEntitiesDetectionJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_entities_detection_job(input)
     }
-
-    /// <p>Provides details about an entity recognizer including status, S3 buckets containing training data, recognizer metadata, metrics, and so on.</p>
     fn describe_entity_recognizer(
         &self,
         input: DescribeEntityRecognizerRequest,
     ) -> RusotoFuture<DescribeEntityRecognizerResponse, DescribeEntityRecognizerError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.DescribeEntityRecognizer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEntityRecognizerResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEntityRecognizerError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_entity_recognizer(input)
     }
-
-    /// <p>Gets the properties associated with a key phrases detection job. Use this operation to get the status of a detection job.</p>
     fn describe_key_phrases_detection_job(
         &self,
         input: DescribeKeyPhrasesDetectionJobRequest,
     ) -> RusotoFuture<DescribeKeyPhrasesDetectionJobResponse, DescribeKeyPhrasesDetectionJobError>
     {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.DescribeKeyPhrasesDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeKeyPhrasesDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeKeyPhrasesDetectionJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_key_phrases_detection_job(input)
     }
-
-    /// <p>Gets the properties associated with a sentiment detection job. Use this operation to get the status of a detection job.</p>
     fn describe_sentiment_detection_job(
         &self,
         input: DescribeSentimentDetectionJobRequest,
     ) -> RusotoFuture<DescribeSentimentDetectionJobResponse, DescribeSentimentDetectionJobError>
     {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.DescribeSentimentDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSentimentDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeSentimentDetectionJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_sentiment_detection_job(input)
     }
-
-    /// <p>Gets the properties associated with a topic detection job. Use this operation to get the status of a detection job.</p>
     fn describe_topics_detection_job(
         &self,
         input: DescribeTopicsDetectionJobRequest,
     ) -> RusotoFuture<DescribeTopicsDetectionJobResponse, DescribeTopicsDetectionJobError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.DescribeTopicsDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTopicsDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeTopicsDetectionJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_topics_detection_job(input)
     }
-
-    /// <p>Determines the dominant language of the input text. For a list of languages that Amazon Comprehend can detect, see <a href="https://docs.aws.amazon.com/comprehend/latest/dg/how-languages.html">Amazon Comprehend Supported Languages</a>. </p>
     fn detect_dominant_language(
         &self,
         input: DetectDominantLanguageRequest,
     ) -> RusotoFuture<DetectDominantLanguageResponse, DetectDominantLanguageError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.DetectDominantLanguage");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectDominantLanguageResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DetectDominantLanguageError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).detect_dominant_language(input)
     }
-
-    /// <p>Inspects text for named entities, and returns information about them. For more information, about named entities, see <a>how-entities</a>. </p>
     fn detect_entities(
         &self,
         input: DetectEntitiesRequest,
     ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.DetectEntities");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectEntitiesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetectEntitiesError::from_response(response))),
-                )
-            }
-        })
+        (**self).detect_entities(input)
     }
-
-    /// <p>Detects the key noun phrases found in the text. </p>
     fn detect_key_phrases(
         &self,
         input: DetectKeyPhrasesRequest,
     ) -> RusotoFuture<DetectKeyPhrasesResponse, DetectKeyPhrasesError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.DetectKeyPhrases");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectKeyPhrasesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetectKeyPhrasesError::from_response(response))),
-                )
-            }
-        })
+        (**self).detect_key_phrases(input)
     }
-
-    /// <p>Inspects text and returns an inference of the prevailing sentiment (<code>POSITIVE</code>, <code>NEUTRAL</code>, <code>MIXED</code>, or <code>NEGATIVE</code>). </p>
     fn detect_sentiment(
         &self,
         input: DetectSentimentRequest,
     ) -> RusotoFuture<DetectSentimentResponse, DetectSentimentError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.DetectSentiment");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectSentimentResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetectSentimentError::from_response(response))),
-                )
-            }
-        })
+        (**self).detect_sentiment(input)
     }
-
-    /// <p>Inspects text for syntax and the part of speech of words in the document. For more information, <a>how-syntax</a>.</p>
     fn detect_syntax(
         &self,
         input: DetectSyntaxRequest,
     ) -> RusotoFuture<DetectSyntaxResponse, DetectSyntaxError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.DetectSyntax");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectSyntaxResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetectSyntaxError::from_response(response))),
-                )
-            }
-        })
+        (**self).detect_syntax(input)
     }
-
-    /// <p>Gets a list of the documentation classification jobs that you have submitted.</p>
     fn list_document_classification_jobs(
         &self,
         input: ListDocumentClassificationJobsRequest,
     ) -> RusotoFuture<ListDocumentClassificationJobsResponse, ListDocumentClassificationJobsError>
     {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.ListDocumentClassificationJobs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDocumentClassificationJobsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListDocumentClassificationJobsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_document_classification_jobs(input)
     }
-
-    /// <p>Gets a list of the document classifiers that you have created.</p>
     fn list_document_classifiers(
         &self,
         input: ListDocumentClassifiersRequest,
     ) -> RusotoFuture<ListDocumentClassifiersResponse, ListDocumentClassifiersError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.ListDocumentClassifiers",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDocumentClassifiersResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListDocumentClassifiersError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_document_classifiers(input)
     }
-
-    /// <p>Gets a list of the dominant language detection jobs that you have submitted.</p>
     fn list_dominant_language_detection_jobs(
         &self,
         input: ListDominantLanguageDetectionJobsRequest,
@@ -5966,235 +7056,51 @@ impl Comprehend for ComprehendClient {
         ListDominantLanguageDetectionJobsResponse,
         ListDominantLanguageDetectionJobsError,
     > {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.ListDominantLanguageDetectionJobs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDominantLanguageDetectionJobsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListDominantLanguageDetectionJobsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_dominant_language_detection_jobs(input)
     }
-
-    /// <p>Gets a list of the entity detection jobs that you have submitted.</p>
     fn list_entities_detection_jobs(
         &self,
         input: ListEntitiesDetectionJobsRequest,
     ) -> RusotoFuture<ListEntitiesDetectionJobsResponse, ListEntitiesDetectionJobsError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.ListEntitiesDetectionJobs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListEntitiesDetectionJobsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListEntitiesDetectionJobsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_entities_detection_jobs(input)
     }
-
-    /// <p>Gets a list of the properties of all entity recognizers that you created, including recognizers currently in training. Allows you to filter the list of recognizers based on criteria such as status and submission time. This call returns up to 500 entity recognizers in the list, with a default number of 100 recognizers in the list.</p> <p>The results of this list are not in any particular order. Please get the list and sort locally if needed.</p>
     fn list_entity_recognizers(
         &self,
         input: ListEntityRecognizersRequest,
     ) -> RusotoFuture<ListEntityRecognizersResponse, ListEntityRecognizersError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.ListEntityRecognizers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListEntityRecognizersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListEntityRecognizersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_entity_recognizers(input)
     }
-
-    /// <p>Get a list of key phrase detection jobs that you have submitted.</p>
     fn list_key_phrases_detection_jobs(
         &self,
         input: ListKeyPhrasesDetectionJobsRequest,
     ) -> RusotoFuture<ListKeyPhrasesDetectionJobsResponse, ListKeyPhrasesDetectionJobsError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.ListKeyPhrasesDetectionJobs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListKeyPhrasesDetectionJobsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListKeyPhrasesDetectionJobsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_key_phrases_detection_jobs(input)
     }
-
-    /// <p>Gets a list of sentiment detection jobs that you have submitted.</p>
     fn list_sentiment_detection_jobs(
         &self,
         input: ListSentimentDetectionJobsRequest,
     ) -> RusotoFuture<ListSentimentDetectionJobsResponse, ListSentimentDetectionJobsError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.ListSentimentDetectionJobs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSentimentDetectionJobsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSentimentDetectionJobsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_sentiment_detection_jobs(input)
     }
-
-    /// <p>Lists all tags associated with a given Amazon Comprehend resource. </p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Gets a list of the topic detection jobs that you have submitted.</p>
     fn list_topics_detection_jobs(
         &self,
         input: ListTopicsDetectionJobsRequest,
     ) -> RusotoFuture<ListTopicsDetectionJobsResponse, ListTopicsDetectionJobsError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.ListTopicsDetectionJobs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTopicsDetectionJobsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListTopicsDetectionJobsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_topics_detection_jobs(input)
     }
-
-    /// <p>Starts an asynchronous document classification job. Use the operation to track the progress of the job.</p>
     fn start_document_classification_job(
         &self,
         input: StartDocumentClassificationJobRequest,
     ) -> RusotoFuture<StartDocumentClassificationJobResponse, StartDocumentClassificationJobError>
     {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StartDocumentClassificationJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartDocumentClassificationJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartDocumentClassificationJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).start_document_classification_job(input)
     }
-
-    /// <p>Starts an asynchronous dominant language detection job for a collection of documents. Use the operation to track the status of a job.</p>
     fn start_dominant_language_detection_job(
         &self,
         input: StartDominantLanguageDetectionJobRequest,
@@ -6202,381 +7108,4042 @@ impl Comprehend for ComprehendClient {
         StartDominantLanguageDetectionJobResponse,
         StartDominantLanguageDetectionJobError,
     > {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StartDominantLanguageDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartDominantLanguageDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartDominantLanguageDetectionJobError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).start_dominant_language_detection_job(input)
     }
-
-    /// <p>Starts an asynchronous entity detection job for a collection of documents. Use the operation to track the status of a job.</p> <p>This API can be used for either standard entity detection or custom entity recognition. In order to be used for custom entity recognition, the optional <code>EntityRecognizerArn</code> must be used in order to provide access to the recognizer being used to detect the custom entity.</p>
     fn start_entities_detection_job(
         &self,
         input: StartEntitiesDetectionJobRequest,
     ) -> RusotoFuture<StartEntitiesDetectionJobResponse, StartEntitiesDetectionJobError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StartEntitiesDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartEntitiesDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartEntitiesDetectionJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).start_entities_detection_job(input)
     }
-
-    /// <p>Starts an asynchronous key phrase detection job for a collection of documents. Use the operation to track the status of a job.</p>
     fn start_key_phrases_detection_job(
         &self,
         input: StartKeyPhrasesDetectionJobRequest,
     ) -> RusotoFuture<StartKeyPhrasesDetectionJobResponse, StartKeyPhrasesDetectionJobError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StartKeyPhrasesDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartKeyPhrasesDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartKeyPhrasesDetectionJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).start_key_phrases_detection_job(input)
     }
-
-    /// <p>Starts an asynchronous sentiment detection job for a collection of documents. use the operation to track the status of a job.</p>
     fn start_sentiment_detection_job(
         &self,
         input: StartSentimentDetectionJobRequest,
     ) -> RusotoFuture<StartSentimentDetectionJobResponse, StartSentimentDetectionJobError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StartSentimentDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartSentimentDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartSentimentDetectionJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).start_sentiment_detection_job(input)
     }
-
-    /// <p>Starts an asynchronous topic detection job. Use the <code>DescribeTopicDetectionJob</code> operation to track the status of a job.</p>
     fn start_topics_detection_job(
         &self,
         input: StartTopicsDetectionJobRequest,
     ) -> RusotoFuture<StartTopicsDetectionJobResponse, StartTopicsDetectionJobError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StartTopicsDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartTopicsDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartTopicsDetectionJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).start_topics_detection_job(input)
     }
-
-    /// <p>Stops a dominant language detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
     fn stop_dominant_language_detection_job(
         &self,
         input: StopDominantLanguageDetectionJobRequest,
     ) -> RusotoFuture<StopDominantLanguageDetectionJobResponse, StopDominantLanguageDetectionJobError>
     {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StopDominantLanguageDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopDominantLanguageDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopDominantLanguageDetectionJobError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).stop_dominant_language_detection_job(input)
     }
-
-    /// <p>Stops an entities detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
     fn stop_entities_detection_job(
         &self,
         input: StopEntitiesDetectionJobRequest,
     ) -> RusotoFuture<StopEntitiesDetectionJobResponse, StopEntitiesDetectionJobError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StopEntitiesDetectionJob",
+        (**self).stop_entities_detection_job(input)
+    }
+    fn stop_key_phrases_detection_job(
+        &self,
+        input: StopKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<StopKeyPhrasesDetectionJobResponse, StopKeyPhrasesDetectionJobError> {
+        (**self).stop_key_phrases_detection_job(input)
+    }
+    fn stop_sentiment_detection_job(
+        &self,
+        input: StopSentimentDetectionJobRequest,
+    ) -> RusotoFuture<StopSentimentDetectionJobResponse, StopSentimentDetectionJobError> {
+        (**self).stop_sentiment_detection_job(input)
+    }
+    fn stop_training_document_classifier(
+        &self,
+        input: StopTrainingDocumentClassifierRequest,
+    ) -> RusotoFuture<StopTrainingDocumentClassifierResponse, StopTrainingDocumentClassifierError>
+    {
+        (**self).stop_training_document_classifier(input)
+    }
+    fn stop_training_entity_recognizer(
+        &self,
+        input: StopTrainingEntityRecognizerRequest,
+    ) -> RusotoFuture<StopTrainingEntityRecognizerResponse, StopTrainingEntityRecognizerError> {
+        (**self).stop_training_entity_recognizer(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: Comprehend + ?Sized> Comprehend for Box<T> {
+    fn batch_detect_dominant_language(
+        &self,
+        input: BatchDetectDominantLanguageRequest,
+    ) -> RusotoFuture<BatchDetectDominantLanguageResponse, BatchDetectDominantLanguageError> {
+        (**self).batch_detect_dominant_language(input)
+    }
+    fn batch_detect_entities(
+        &self,
+        input: BatchDetectEntitiesRequest,
+    ) -> RusotoFuture<BatchDetectEntitiesResponse, BatchDetectEntitiesError> {
+        (**self).batch_detect_entities(input)
+    }
+    fn batch_detect_key_phrases(
+        &self,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> RusotoFuture<BatchDetectKeyPhrasesResponse, BatchDetectKeyPhrasesError> {
+        (**self).batch_detect_key_phrases(input)
+    }
+    fn batch_detect_sentiment(
+        &self,
+        input: BatchDetectSentimentRequest,
+    ) -> RusotoFuture<BatchDetectSentimentResponse, BatchDetectSentimentError> {
+        (**self).batch_detect_sentiment(input)
+    }
+    fn batch_detect_syntax(
+        &self,
+        input: BatchDetectSyntaxRequest,
+    ) -> RusotoFuture<BatchDetectSyntaxResponse, BatchDetectSyntaxError> {
+        (**self).batch_detect_syntax(input)
+    }
+    fn create_document_classifier(
+        &self,
+        input: CreateDocumentClassifierRequest,
+    ) -> RusotoFuture<CreateDocumentClassifierResponse, CreateDocumentClassifierError> {
+        (**self).create_document_classifier(input)
+    }
+    fn create_entity_recognizer(
+        &self,
+        input: CreateEntityRecognizerRequest,
+    ) -> RusotoFuture<CreateEntityRecognizerResponse, CreateEntityRecognizerError> {
+        (**self).create_entity_recognizer(input)
+    }
+    fn delete_document_classifier(
+        &self,
+        input: DeleteDocumentClassifierRequest,
+    ) -> RusotoFuture<DeleteDocumentClassifierResponse, DeleteDocumentClassifierError> {
+        (**self).delete_document_classifier(input)
+    }
+    fn delete_entity_recognizer(
+        &self,
+        input: DeleteEntityRecognizerRequest,
+    ) -> RusotoFuture<DeleteEntityRecognizerResponse, DeleteEntityRecognizerError> {
+        (**self).delete_entity_recognizer(input)
+    }
+    fn describe_document_classification_job(
+        &self,
+        input: DescribeDocumentClassificationJobRequest,
+    ) -> RusotoFuture<
+        DescribeDocumentClassificationJobResponse,
+        DescribeDocumentClassificationJobError,
+    > {
+        (**self).describe_document_classification_job(input)
+    }
+    fn describe_document_classifier(
+        &self,
+        input: DescribeDocumentClassifierRequest,
+    ) -> RusotoFuture<DescribeDocumentClassifierResponse, DescribeDocumentClassifierError> {
+        (**self).describe_document_classifier(input)
+    }
+    fn describe_dominant_language_detection_job(
+        &self,
+        input: DescribeDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<
+        DescribeDominantLanguageDetectionJobResponse,
+        DescribeDominantLanguageDetectionJobError,
+    > {
+        (**self).describe_dominant_language_detection_job(input)
+    }
+    fn describe_entities_detection_job(
+        &self,
+        input: DescribeEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<DescribeEntitiesDetectionJobResponse, DescribeEntitiesDetectionJobError> {
+        (**self).describe_entities_detection_job(input)
+    }
+    fn describe_entity_recognizer(
+        &self,
+        input: DescribeEntityRecognizerRequest,
+    ) -> RusotoFuture<DescribeEntityRecognizerResponse, DescribeEntityRecognizerError> {
+        (**self).describe_entity_recognizer(input)
+    }
+    fn describe_key_phrases_detection_job(
+        &self,
+        input: DescribeKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<DescribeKeyPhrasesDetectionJobResponse, DescribeKeyPhrasesDetectionJobError>
+    {
+        (**self).describe_key_phrases_detection_job(input)
+    }
+    fn describe_sentiment_detection_job(
+        &self,
+        input: DescribeSentimentDetectionJobRequest,
+    ) -> RusotoFuture<DescribeSentimentDetectionJobResponse, DescribeSentimentDetectionJobError>
+    {
+        (**self).describe_sentiment_detection_job(input)
+    }
+    fn describe_topics_detection_job(
+        &self,
+        input: DescribeTopicsDetectionJobRequest,
+    ) -> RusotoFuture<DescribeTopicsDetectionJobResponse, DescribeTopicsDetectionJobError> {
+        (**self).describe_topics_detection_job(input)
+    }
+    fn detect_dominant_language(
+        &self,
+        input: DetectDominantLanguageRequest,
+    ) -> RusotoFuture<DetectDominantLanguageResponse, DetectDominantLanguageError> {
+        (**self).detect_dominant_language(input)
+    }
+    fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        (**self).detect_entities(input)
+    }
+    fn detect_key_phrases(
+        &self,
+        input: DetectKeyPhrasesRequest,
+    ) -> RusotoFuture<DetectKeyPhrasesResponse, DetectKeyPhrasesError> {
+        (**self).detect_key_phrases(input)
+    }
+    fn detect_sentiment(
+        &self,
+        input: DetectSentimentRequest,
+    ) -> RusotoFuture<DetectSentimentResponse, DetectSentimentError> {
+        (**self).detect_sentiment(input)
+    }
+    fn detect_syntax(
+        &self,
+        input: DetectSyntaxRequest,
+    ) -> RusotoFuture<DetectSyntaxResponse, DetectSyntaxError> {
+        (**self).detect_syntax(input)
+    }
+    fn list_document_classification_jobs(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> RusotoFuture<ListDocumentClassificationJobsResponse, ListDocumentClassificationJobsError>
+    {
+        (**self).list_document_classification_jobs(input)
+    }
+    fn list_document_classifiers(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> RusotoFuture<ListDocumentClassifiersResponse, ListDocumentClassifiersError> {
+        (**self).list_document_classifiers(input)
+    }
+    fn list_dominant_language_detection_jobs(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> RusotoFuture<
+        ListDominantLanguageDetectionJobsResponse,
+        ListDominantLanguageDetectionJobsError,
+    > {
+        (**self).list_dominant_language_detection_jobs(input)
+    }
+    fn list_entities_detection_jobs(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> RusotoFuture<ListEntitiesDetectionJobsResponse, ListEntitiesDetectionJobsError> {
+        (**self).list_entities_detection_jobs(input)
+    }
+    fn list_entity_recognizers(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> RusotoFuture<ListEntityRecognizersResponse, ListEntityRecognizersError> {
+        (**self).list_entity_recognizers(input)
+    }
+    fn list_key_phrases_detection_jobs(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> RusotoFuture<ListKeyPhrasesDetectionJobsResponse, ListKeyPhrasesDetectionJobsError> {
+        (**self).list_key_phrases_detection_jobs(input)
+    }
+    fn list_sentiment_detection_jobs(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> RusotoFuture<ListSentimentDetectionJobsResponse, ListSentimentDetectionJobsError> {
+        (**self).list_sentiment_detection_jobs(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_topics_detection_jobs(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> RusotoFuture<ListTopicsDetectionJobsResponse, ListTopicsDetectionJobsError> {
+        (**self).list_topics_detection_jobs(input)
+    }
+    fn start_document_classification_job(
+        &self,
+        input: StartDocumentClassificationJobRequest,
+    ) -> RusotoFuture<StartDocumentClassificationJobResponse, StartDocumentClassificationJobError>
+    {
+        (**self).start_document_classification_job(input)
+    }
+    fn start_dominant_language_detection_job(
+        &self,
+        input: StartDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<
+        StartDominantLanguageDetectionJobResponse,
+        StartDominantLanguageDetectionJobError,
+    > {
+        (**self).start_dominant_language_detection_job(input)
+    }
+    fn start_entities_detection_job(
+        &self,
+        input: StartEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<StartEntitiesDetectionJobResponse, StartEntitiesDetectionJobError> {
+        (**self).start_entities_detection_job(input)
+    }
+    fn start_key_phrases_detection_job(
+        &self,
+        input: StartKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<StartKeyPhrasesDetectionJobResponse, StartKeyPhrasesDetectionJobError> {
+        (**self).start_key_phrases_detection_job(input)
+    }
+    fn start_sentiment_detection_job(
+        &self,
+        input: StartSentimentDetectionJobRequest,
+    ) -> RusotoFuture<StartSentimentDetectionJobResponse, StartSentimentDetectionJobError> {
+        (**self).start_sentiment_detection_job(input)
+    }
+    fn start_topics_detection_job(
+        &self,
+        input: StartTopicsDetectionJobRequest,
+    ) -> RusotoFuture<StartTopicsDetectionJobResponse, StartTopicsDetectionJobError> {
+        (**self).start_topics_detection_job(input)
+    }
+    fn stop_dominant_language_detection_job(
+        &self,
+        input: StopDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<StopDominantLanguageDetectionJobResponse, StopDominantLanguageDetectionJobError>
+    {
+        (**self).stop_dominant_language_detection_job(input)
+    }
+    fn stop_entities_detection_job(
+        &self,
+        input: StopEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<StopEntitiesDetectionJobResponse, StopEntitiesDetectionJobError> {
+        (**self).stop_entities_detection_job(input)
+    }
+    fn stop_key_phrases_detection_job(
+        &self,
+        input: StopKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<StopKeyPhrasesDetectionJobResponse, StopKeyPhrasesDetectionJobError> {
+        (**self).stop_key_phrases_detection_job(input)
+    }
+    fn stop_sentiment_detection_job(
+        &self,
+        input: StopSentimentDetectionJobRequest,
+    ) -> RusotoFuture<StopSentimentDetectionJobResponse, StopSentimentDetectionJobError> {
+        (**self).stop_sentiment_detection_job(input)
+    }
+    fn stop_training_document_classifier(
+        &self,
+        input: StopTrainingDocumentClassifierRequest,
+    ) -> RusotoFuture<StopTrainingDocumentClassifierResponse, StopTrainingDocumentClassifierError>
+    {
+        (**self).stop_training_document_classifier(input)
+    }
+    fn stop_training_entity_recognizer(
+        &self,
+        input: StopTrainingEntityRecognizerRequest,
+    ) -> RusotoFuture<StopTrainingEntityRecognizerResponse, StopTrainingEntityRecognizerError> {
+        (**self).stop_training_entity_recognizer(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: Comprehend + ?Sized> Comprehend for ::std::sync::Arc<T> {
+    fn batch_detect_dominant_language(
+        &self,
+        input: BatchDetectDominantLanguageRequest,
+    ) -> RusotoFuture<BatchDetectDominantLanguageResponse, BatchDetectDominantLanguageError> {
+        (**self).batch_detect_dominant_language(input)
+    }
+    fn batch_detect_entities(
+        &self,
+        input: BatchDetectEntitiesRequest,
+    ) -> RusotoFuture<BatchDetectEntitiesResponse, BatchDetectEntitiesError> {
+        (**self).batch_detect_entities(input)
+    }
+    fn batch_detect_key_phrases(
+        &self,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> RusotoFuture<BatchDetectKeyPhrasesResponse, BatchDetectKeyPhrasesError> {
+        (**self).batch_detect_key_phrases(input)
+    }
+    fn batch_detect_sentiment(
+        &self,
+        input: BatchDetectSentimentRequest,
+    ) -> RusotoFuture<BatchDetectSentimentResponse, BatchDetectSentimentError> {
+        (**self).batch_detect_sentiment(input)
+    }
+    fn batch_detect_syntax(
+        &self,
+        input: BatchDetectSyntaxRequest,
+    ) -> RusotoFuture<BatchDetectSyntaxResponse, BatchDetectSyntaxError> {
+        (**self).batch_detect_syntax(input)
+    }
+    fn create_document_classifier(
+        &self,
+        input: CreateDocumentClassifierRequest,
+    ) -> RusotoFuture<CreateDocumentClassifierResponse, CreateDocumentClassifierError> {
+        (**self).create_document_classifier(input)
+    }
+    fn create_entity_recognizer(
+        &self,
+        input: CreateEntityRecognizerRequest,
+    ) -> RusotoFuture<CreateEntityRecognizerResponse, CreateEntityRecognizerError> {
+        (**self).create_entity_recognizer(input)
+    }
+    fn delete_document_classifier(
+        &self,
+        input: DeleteDocumentClassifierRequest,
+    ) -> RusotoFuture<DeleteDocumentClassifierResponse, DeleteDocumentClassifierError> {
+        (**self).delete_document_classifier(input)
+    }
+    fn delete_entity_recognizer(
+        &self,
+        input: DeleteEntityRecognizerRequest,
+    ) -> RusotoFuture<DeleteEntityRecognizerResponse, DeleteEntityRecognizerError> {
+        (**self).delete_entity_recognizer(input)
+    }
+    fn describe_document_classification_job(
+        &self,
+        input: DescribeDocumentClassificationJobRequest,
+    ) -> RusotoFuture<
+        DescribeDocumentClassificationJobResponse,
+        DescribeDocumentClassificationJobError,
+    > {
+        (**self).describe_document_classification_job(input)
+    }
+    fn describe_document_classifier(
+        &self,
+        input: DescribeDocumentClassifierRequest,
+    ) -> RusotoFuture<DescribeDocumentClassifierResponse, DescribeDocumentClassifierError> {
+        (**self).describe_document_classifier(input)
+    }
+    fn describe_dominant_language_detection_job(
+        &self,
+        input: DescribeDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<
+        DescribeDominantLanguageDetectionJobResponse,
+        DescribeDominantLanguageDetectionJobError,
+    > {
+        (**self).describe_dominant_language_detection_job(input)
+    }
+    fn describe_entities_detection_job(
+        &self,
+        input: DescribeEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<DescribeEntitiesDetectionJobResponse, DescribeEntitiesDetectionJobError> {
+        (**self).describe_entities_detection_job(input)
+    }
+    fn describe_entity_recognizer(
+        &self,
+        input: DescribeEntityRecognizerRequest,
+    ) -> RusotoFuture<DescribeEntityRecognizerResponse, DescribeEntityRecognizerError> {
+        (**self).describe_entity_recognizer(input)
+    }
+    fn describe_key_phrases_detection_job(
+        &self,
+        input: DescribeKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<DescribeKeyPhrasesDetectionJobResponse, DescribeKeyPhrasesDetectionJobError>
+    {
+        (**self).describe_key_phrases_detection_job(input)
+    }
+    fn describe_sentiment_detection_job(
+        &self,
+        input: DescribeSentimentDetectionJobRequest,
+    ) -> RusotoFuture<DescribeSentimentDetectionJobResponse, DescribeSentimentDetectionJobError>
+    {
+        (**self).describe_sentiment_detection_job(input)
+    }
+    fn describe_topics_detection_job(
+        &self,
+        input: DescribeTopicsDetectionJobRequest,
+    ) -> RusotoFuture<DescribeTopicsDetectionJobResponse, DescribeTopicsDetectionJobError> {
+        (**self).describe_topics_detection_job(input)
+    }
+    fn detect_dominant_language(
+        &self,
+        input: DetectDominantLanguageRequest,
+    ) -> RusotoFuture<DetectDominantLanguageResponse, DetectDominantLanguageError> {
+        (**self).detect_dominant_language(input)
+    }
+    fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        (**self).detect_entities(input)
+    }
+    fn detect_key_phrases(
+        &self,
+        input: DetectKeyPhrasesRequest,
+    ) -> RusotoFuture<DetectKeyPhrasesResponse, DetectKeyPhrasesError> {
+        (**self).detect_key_phrases(input)
+    }
+    fn detect_sentiment(
+        &self,
+        input: DetectSentimentRequest,
+    ) -> RusotoFuture<DetectSentimentResponse, DetectSentimentError> {
+        (**self).detect_sentiment(input)
+    }
+    fn detect_syntax(
+        &self,
+        input: DetectSyntaxRequest,
+    ) -> RusotoFuture<DetectSyntaxResponse, DetectSyntaxError> {
+        (**self).detect_syntax(input)
+    }
+    fn list_document_classification_jobs(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> RusotoFuture<ListDocumentClassificationJobsResponse, ListDocumentClassificationJobsError>
+    {
+        (**self).list_document_classification_jobs(input)
+    }
+    fn list_document_classifiers(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> RusotoFuture<ListDocumentClassifiersResponse, ListDocumentClassifiersError> {
+        (**self).list_document_classifiers(input)
+    }
+    fn list_dominant_language_detection_jobs(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> RusotoFuture<
+        ListDominantLanguageDetectionJobsResponse,
+        ListDominantLanguageDetectionJobsError,
+    > {
+        (**self).list_dominant_language_detection_jobs(input)
+    }
+    fn list_entities_detection_jobs(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> RusotoFuture<ListEntitiesDetectionJobsResponse, ListEntitiesDetectionJobsError> {
+        (**self).list_entities_detection_jobs(input)
+    }
+    fn list_entity_recognizers(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> RusotoFuture<ListEntityRecognizersResponse, ListEntityRecognizersError> {
+        (**self).list_entity_recognizers(input)
+    }
+    fn list_key_phrases_detection_jobs(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> RusotoFuture<ListKeyPhrasesDetectionJobsResponse, ListKeyPhrasesDetectionJobsError> {
+        (**self).list_key_phrases_detection_jobs(input)
+    }
+    fn list_sentiment_detection_jobs(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> RusotoFuture<ListSentimentDetectionJobsResponse, ListSentimentDetectionJobsError> {
+        (**self).list_sentiment_detection_jobs(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_topics_detection_jobs(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> RusotoFuture<ListTopicsDetectionJobsResponse, ListTopicsDetectionJobsError> {
+        (**self).list_topics_detection_jobs(input)
+    }
+    fn start_document_classification_job(
+        &self,
+        input: StartDocumentClassificationJobRequest,
+    ) -> RusotoFuture<StartDocumentClassificationJobResponse, StartDocumentClassificationJobError>
+    {
+        (**self).start_document_classification_job(input)
+    }
+    fn start_dominant_language_detection_job(
+        &self,
+        input: StartDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<
+        StartDominantLanguageDetectionJobResponse,
+        StartDominantLanguageDetectionJobError,
+    > {
+        (**self).start_dominant_language_detection_job(input)
+    }
+    fn start_entities_detection_job(
+        &self,
+        input: StartEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<StartEntitiesDetectionJobResponse, StartEntitiesDetectionJobError> {
+        (**self).start_entities_detection_job(input)
+    }
+    fn start_key_phrases_detection_job(
+        &self,
+        input: StartKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<StartKeyPhrasesDetectionJobResponse, StartKeyPhrasesDetectionJobError> {
+        (**self).start_key_phrases_detection_job(input)
+    }
+    fn start_sentiment_detection_job(
+        &self,
+        input: StartSentimentDetectionJobRequest,
+    ) -> RusotoFuture<StartSentimentDetectionJobResponse, StartSentimentDetectionJobError> {
+        (**self).start_sentiment_detection_job(input)
+    }
+    fn start_topics_detection_job(
+        &self,
+        input: StartTopicsDetectionJobRequest,
+    ) -> RusotoFuture<StartTopicsDetectionJobResponse, StartTopicsDetectionJobError> {
+        (**self).start_topics_detection_job(input)
+    }
+    fn stop_dominant_language_detection_job(
+        &self,
+        input: StopDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<StopDominantLanguageDetectionJobResponse, StopDominantLanguageDetectionJobError>
+    {
+        (**self).stop_dominant_language_detection_job(input)
+    }
+    fn stop_entities_detection_job(
+        &self,
+        input: StopEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<StopEntitiesDetectionJobResponse, StopEntitiesDetectionJobError> {
+        (**self).stop_entities_detection_job(input)
+    }
+    fn stop_key_phrases_detection_job(
+        &self,
+        input: StopKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<StopKeyPhrasesDetectionJobResponse, StopKeyPhrasesDetectionJobError> {
+        (**self).stop_key_phrases_detection_job(input)
+    }
+    fn stop_sentiment_detection_job(
+        &self,
+        input: StopSentimentDetectionJobRequest,
+    ) -> RusotoFuture<StopSentimentDetectionJobResponse, StopSentimentDetectionJobError> {
+        (**self).stop_sentiment_detection_job(input)
+    }
+    fn stop_training_document_classifier(
+        &self,
+        input: StopTrainingDocumentClassifierRequest,
+    ) -> RusotoFuture<StopTrainingDocumentClassifierResponse, StopTrainingDocumentClassifierError>
+    {
+        (**self).stop_training_document_classifier(input)
+    }
+    fn stop_training_entity_recognizer(
+        &self,
+        input: StopTrainingEntityRecognizerRequest,
+    ) -> RusotoFuture<StopTrainingEntityRecognizerResponse, StopTrainingEntityRecognizerError> {
+        (**self).stop_training_entity_recognizer(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// A client for the Amazon Comprehend API.
+#[derive(Clone)]
+pub struct ComprehendClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ComprehendClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ComprehendClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ComprehendClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ComprehendClient {
+        ComprehendClient { client, region }
+    }
+}
+
+impl Comprehend for ComprehendClient {
+    /// <p>Determines the dominant language of the input text for a batch of documents. For a list of languages that Amazon Comprehend can detect, see <a href="https://docs.aws.amazon.com/comprehend/latest/dg/how-languages.html">Amazon Comprehend Supported Languages</a>. </p>
+    fn batch_detect_dominant_language(
+        &self,
+        input: BatchDetectDominantLanguageRequest,
+    ) -> RusotoFuture<BatchDetectDominantLanguageResponse, BatchDetectDominantLanguageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectDominantLanguage");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.BatchDetectDominantLanguage",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDetectDominantLanguageResponse, _>(
+                    response,
+                    "BatchDetectDominantLanguage",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchDetectDominantLanguageError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Inspects the text of a batch of documents for named entities and returns information about them. For more information about named entities, see <a>how-entities</a> </p>
+    fn batch_detect_entities(
+        &self,
+        input: BatchDetectEntitiesRequest,
+    ) -> RusotoFuture<BatchDetectEntitiesResponse, BatchDetectEntitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectEntities");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectEntities");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDetectEntitiesResponse, _>(
+                    response,
+                    "BatchDetectEntities",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchDetectEntitiesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Detects the key noun phrases found in a batch of documents.</p>
+    fn batch_detect_key_phrases(
+        &self,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> RusotoFuture<BatchDetectKeyPhrasesResponse, BatchDetectKeyPhrasesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectKeyPhrases");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectKeyPhrases");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDetectKeyPhrasesResponse, _>(
+                    response,
+                    "BatchDetectKeyPhrases",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchDetectKeyPhrasesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Inspects a batch of documents and returns an inference of the prevailing sentiment, <code>POSITIVE</code>, <code>NEUTRAL</code>, <code>MIXED</code>, or <code>NEGATIVE</code>, in each one.</p>
+    fn batch_detect_sentiment(
+        &self,
+        input: BatchDetectSentimentRequest,
+    ) -> RusotoFuture<BatchDetectSentimentResponse, BatchDetectSentimentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectSentiment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSentiment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDetectSentimentResponse, _>(
+                    response,
+                    "BatchDetectSentiment",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchDetectSentimentError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Inspects the text of a batch of documents for the syntax and part of speech of the words in the document and returns information about them. For more information, see <a>how-syntax</a>.</p>
+    fn batch_detect_syntax(
+        &self,
+        input: BatchDetectSyntaxRequest,
+    ) -> RusotoFuture<BatchDetectSyntaxResponse, BatchDetectSyntaxError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectSyntax");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSyntax");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDetectSyntaxResponse, _>(
+                    response,
+                    "BatchDetectSyntax",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchDetectSyntaxError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new document classifier that you can use to categorize documents. To create a classifier you provide a set of training documents that labeled with the categories that you want to use. After the classifier is trained you can use it to categorize a set of labeled documents into the categories. For more information, see <a>how-document-classification</a>.</p>
+    fn create_document_classifier(
+        &self,
+        input: CreateDocumentClassifierRequest,
+    ) -> RusotoFuture<CreateDocumentClassifierResponse, CreateDocumentClassifierError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("CreateDocumentClassifier");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.CreateDocumentClassifier",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDocumentClassifierResponse, _>(
+                    response,
+                    "CreateDocumentClassifier",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDocumentClassifierError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates an entity recognizer using submitted files. After your <code>CreateEntityRecognizer</code> request is submitted, you can check job status using the API. </p>
+    fn create_entity_recognizer(
+        &self,
+        input: CreateEntityRecognizerRequest,
+    ) -> RusotoFuture<CreateEntityRecognizerResponse, CreateEntityRecognizerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("CreateEntityRecognizer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.CreateEntityRecognizer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateEntityRecognizerResponse, _>(
+                    response,
+                    "CreateEntityRecognizer",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateEntityRecognizerError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a previously created document classifier</p> <p>Only those classifiers that are in terminated states (IN_ERROR, TRAINED) will be deleted. If an active inference job is using the model, a <code>ResourceInUseException</code> will be returned.</p> <p>This is an asynchronous action that puts the classifier into a DELETING state, and it is then removed by a background job. Once removed, the classifier disappears from your account and is no longer available for use. </p>
+    fn delete_document_classifier(
+        &self,
+        input: DeleteDocumentClassifierRequest,
+    ) -> RusotoFuture<DeleteDocumentClassifierResponse, DeleteDocumentClassifierError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DeleteDocumentClassifier");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.DeleteDocumentClassifier",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDocumentClassifierResponse, _>(
+                    response,
+                    "DeleteDocumentClassifier",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteDocumentClassifierError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes an entity recognizer.</p> <p>Only those recognizers that are in terminated states (IN_ERROR, TRAINED) will be deleted. If an active inference job is using the model, a <code>ResourceInUseException</code> will be returned.</p> <p>This is an asynchronous action that puts the recognizer into a DELETING state, and it is then removed by a background job. Once removed, the recognizer disappears from your account and is no longer available for use. </p>
+    fn delete_entity_recognizer(
+        &self,
+        input: DeleteEntityRecognizerRequest,
+    ) -> RusotoFuture<DeleteEntityRecognizerResponse, DeleteEntityRecognizerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DeleteEntityRecognizer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.DeleteEntityRecognizer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteEntityRecognizerResponse, _>(
+                    response,
+                    "DeleteEntityRecognizer",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteEntityRecognizerError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the properties associated with a document classification job. Use this operation to get the status of a classification job.</p>
+    fn describe_document_classification_job(
+        &self,
+        input: DescribeDocumentClassificationJobRequest,
+    ) -> RusotoFuture<
+        DescribeDocumentClassificationJobResponse,
+        DescribeDocumentClassificationJobError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeDocumentClassificationJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.DescribeDocumentClassificationJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDocumentClassificationJobResponse, _>(
+                    response,
+                    "DescribeDocumentClassificationJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDocumentClassificationJobError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the properties associated with a document classifier.</p>
+    fn describe_document_classifier(
+        &self,
+        input: DescribeDocumentClassifierRequest,
+    ) -> RusotoFuture<DescribeDocumentClassifierResponse, DescribeDocumentClassifierError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeDocumentClassifier");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.DescribeDocumentClassifier",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDocumentClassifierResponse, _>(
+                    response,
+                    "DescribeDocumentClassifier",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDocumentClassifierError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the properties associated with a dominant language detection job. Use this operation to get the status of a detection job.</p>
+    fn describe_dominant_language_detection_job(
+        &self,
+        input: DescribeDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<
+        DescribeDominantLanguageDetectionJobResponse,
+        DescribeDominantLanguageDetectionJobError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeDominantLanguageDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.DescribeDominantLanguageDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDominantLanguageDetectionJobResponse, _>(response, "DescribeDominantLanguageDetectionJob")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDominantLanguageDetectionJobError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the properties associated with an entities detection job. Use this operation to get the status of a detection job.</p>
+    fn describe_entities_detection_job(
+        &self,
+        input: DescribeEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<DescribeEntitiesDetectionJobResponse, DescribeEntitiesDetectionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeEntitiesDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.DescribeEntitiesDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEntitiesDetectionJobResponse, _>(
+                    response,
+                    "DescribeEntitiesDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEntitiesDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides details about an entity recognizer including status, S3 buckets containing training data, recognizer metadata, metrics, and so on.</p>
+    fn describe_entity_recognizer(
+        &self,
+        input: DescribeEntityRecognizerRequest,
+    ) -> RusotoFuture<DescribeEntityRecognizerResponse, DescribeEntityRecognizerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeEntityRecognizer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.DescribeEntityRecognizer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEntityRecognizerResponse, _>(
+                    response,
+                    "DescribeEntityRecognizer",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEntityRecognizerError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the properties associated with a key phrases detection job. Use this operation to get the status of a detection job.</p>
+    fn describe_key_phrases_detection_job(
+        &self,
+        input: DescribeKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<DescribeKeyPhrasesDetectionJobResponse, DescribeKeyPhrasesDetectionJobError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeKeyPhrasesDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.DescribeKeyPhrasesDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeKeyPhrasesDetectionJobResponse, _>(
+                    response,
+                    "DescribeKeyPhrasesDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeKeyPhrasesDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the properties associated with a sentiment detection job. Use this operation to get the status of a detection job.</p>
+    fn describe_sentiment_detection_job(
+        &self,
+        input: DescribeSentimentDetectionJobRequest,
+    ) -> RusotoFuture<DescribeSentimentDetectionJobResponse, DescribeSentimentDetectionJobError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeSentimentDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.DescribeSentimentDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSentimentDetectionJobResponse, _>(
+                    response,
+                    "DescribeSentimentDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeSentimentDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the properties associated with a topic detection job. Use this operation to get the status of a detection job.</p>
+    fn describe_topics_detection_job(
+        &self,
+        input: DescribeTopicsDetectionJobRequest,
+    ) -> RusotoFuture<DescribeTopicsDetectionJobResponse, DescribeTopicsDetectionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeTopicsDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.DescribeTopicsDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTopicsDetectionJobResponse, _>(
+                    response,
+                    "DescribeTopicsDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeTopicsDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Determines the dominant language of the input text. For a list of languages that Amazon Comprehend can detect, see <a href="https://docs.aws.amazon.com/comprehend/latest/dg/how-languages.html">Amazon Comprehend Supported Languages</a>. </p>
+    fn detect_dominant_language(
+        &self,
+        input: DetectDominantLanguageRequest,
+    ) -> RusotoFuture<DetectDominantLanguageResponse, DetectDominantLanguageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectDominantLanguage");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.DetectDominantLanguage");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetectDominantLanguageResponse, _>(
+                    response,
+                    "DetectDominantLanguage",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DetectDominantLanguageError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Inspects text for named entities, and returns information about them. For more information, about named entities, see <a>how-entities</a>. </p>
+    fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectEntities");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.DetectEntities");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetectEntitiesResponse, _>(
+                    response,
+                    "DetectEntities",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetectEntitiesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Detects the key noun phrases found in the text. </p>
+    fn detect_key_phrases(
+        &self,
+        input: DetectKeyPhrasesRequest,
+    ) -> RusotoFuture<DetectKeyPhrasesResponse, DetectKeyPhrasesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectKeyPhrases");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.DetectKeyPhrases");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetectKeyPhrasesResponse, _>(
+                    response,
+                    "DetectKeyPhrases",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetectKeyPhrasesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Inspects text and returns an inference of the prevailing sentiment (<code>POSITIVE</code>, <code>NEUTRAL</code>, <code>MIXED</code>, or <code>NEGATIVE</code>). </p>
+    fn detect_sentiment(
+        &self,
+        input: DetectSentimentRequest,
+    ) -> RusotoFuture<DetectSentimentResponse, DetectSentimentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectSentiment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.DetectSentiment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetectSentimentResponse, _>(
+                    response,
+                    "DetectSentiment",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetectSentimentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Inspects text for syntax and the part of speech of words in the document. For more information, <a>how-syntax</a>.</p>
+    fn detect_syntax(
+        &self,
+        input: DetectSyntaxRequest,
+    ) -> RusotoFuture<DetectSyntaxResponse, DetectSyntaxError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectSyntax");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.DetectSyntax");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetectSyntaxResponse, _>(
+                    response,
+                    "DetectSyntax",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetectSyntaxError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of the documentation classification jobs that you have submitted.</p>
+    fn list_document_classification_jobs(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> RusotoFuture<ListDocumentClassificationJobsResponse, ListDocumentClassificationJobsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListDocumentClassificationJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.ListDocumentClassificationJobs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDocumentClassificationJobsResponse, _>(
+                    response,
+                    "ListDocumentClassificationJobs",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListDocumentClassificationJobsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets a list of the document classifiers that you have created.</p>
+    fn list_document_classifiers(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> RusotoFuture<ListDocumentClassifiersResponse, ListDocumentClassifiersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListDocumentClassifiers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.ListDocumentClassifiers",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDocumentClassifiersResponse, _>(
+                    response,
+                    "ListDocumentClassifiers",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListDocumentClassifiersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets a list of the dominant language detection jobs that you have submitted.</p>
+    fn list_dominant_language_detection_jobs(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> RusotoFuture<
+        ListDominantLanguageDetectionJobsResponse,
+        ListDominantLanguageDetectionJobsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListDominantLanguageDetectionJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.ListDominantLanguageDetectionJobs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDominantLanguageDetectionJobsResponse, _>(
+                    response,
+                    "ListDominantLanguageDetectionJobs",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListDominantLanguageDetectionJobsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets a list of the entity detection jobs that you have submitted.</p>
+    fn list_entities_detection_jobs(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> RusotoFuture<ListEntitiesDetectionJobsResponse, ListEntitiesDetectionJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListEntitiesDetectionJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.ListEntitiesDetectionJobs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListEntitiesDetectionJobsResponse, _>(
+                    response,
+                    "ListEntitiesDetectionJobs",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListEntitiesDetectionJobsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets a list of the properties of all entity recognizers that you created, including recognizers currently in training. Allows you to filter the list of recognizers based on criteria such as status and submission time. This call returns up to 500 entity recognizers in the list, with a default number of 100 recognizers in the list.</p> <p>The results of this list are not in any particular order. Please get the list and sort locally if needed.</p>
+    fn list_entity_recognizers(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> RusotoFuture<ListEntityRecognizersResponse, ListEntityRecognizersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListEntityRecognizers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.ListEntityRecognizers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListEntityRecognizersResponse, _>(
+                    response,
+                    "ListEntityRecognizers",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListEntityRecognizersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Get a list of key phrase detection jobs that you have submitted.</p>
+    fn list_key_phrases_detection_jobs(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> RusotoFuture<ListKeyPhrasesDetectionJobsResponse, ListKeyPhrasesDetectionJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListKeyPhrasesDetectionJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.ListKeyPhrasesDetectionJobs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListKeyPhrasesDetectionJobsResponse, _>(
+                    response,
+                    "ListKeyPhrasesDetectionJobs",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListKeyPhrasesDetectionJobsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets a list of sentiment detection jobs that you have submitted.</p>
+    fn list_sentiment_detection_jobs(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> RusotoFuture<ListSentimentDetectionJobsResponse, ListSentimentDetectionJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListSentimentDetectionJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.ListSentimentDetectionJobs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSentimentDetectionJobsResponse, _>(
+                    response,
+                    "ListSentimentDetectionJobs",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListSentimentDetectionJobsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all tags associated with a given Amazon Comprehend resource. </p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of the topic detection jobs that you have submitted.</p>
+    fn list_topics_detection_jobs(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> RusotoFuture<ListTopicsDetectionJobsResponse, ListTopicsDetectionJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListTopicsDetectionJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.ListTopicsDetectionJobs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTopicsDetectionJobsResponse, _>(
+                    response,
+                    "ListTopicsDetectionJobs",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListTopicsDetectionJobsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts an asynchronous document classification job. Use the operation to track the progress of the job.</p>
+    fn start_document_classification_job(
+        &self,
+        input: StartDocumentClassificationJobRequest,
+    ) -> RusotoFuture<StartDocumentClassificationJobResponse, StartDocumentClassificationJobError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartDocumentClassificationJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StartDocumentClassificationJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartDocumentClassificationJobResponse, _>(
+                    response,
+                    "StartDocumentClassificationJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartDocumentClassificationJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts an asynchronous dominant language detection job for a collection of documents. Use the operation to track the status of a job.</p>
+    fn start_dominant_language_detection_job(
+        &self,
+        input: StartDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<
+        StartDominantLanguageDetectionJobResponse,
+        StartDominantLanguageDetectionJobError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartDominantLanguageDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StartDominantLanguageDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartDominantLanguageDetectionJobResponse, _>(
+                    response,
+                    "StartDominantLanguageDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartDominantLanguageDetectionJobError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts an asynchronous entity detection job for a collection of documents. Use the operation to track the status of a job.</p> <p>This API can be used for either standard entity detection or custom entity recognition. In order to be used for custom entity recognition, the optional <code>EntityRecognizerArn</code> must be used in order to provide access to the recognizer being used to detect the custom entity.</p>
+    fn start_entities_detection_job(
+        &self,
+        input: StartEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<StartEntitiesDetectionJobResponse, StartEntitiesDetectionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartEntitiesDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StartEntitiesDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartEntitiesDetectionJobResponse, _>(
+                    response,
+                    "StartEntitiesDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartEntitiesDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts an asynchronous key phrase detection job for a collection of documents. Use the operation to track the status of a job.</p>
+    fn start_key_phrases_detection_job(
+        &self,
+        input: StartKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<StartKeyPhrasesDetectionJobResponse, StartKeyPhrasesDetectionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartKeyPhrasesDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StartKeyPhrasesDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartKeyPhrasesDetectionJobResponse, _>(
+                    response,
+                    "StartKeyPhrasesDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartKeyPhrasesDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts an asynchronous sentiment detection job for a collection of documents. use the operation to track the status of a job.</p>
+    fn start_sentiment_detection_job(
+        &self,
+        input: StartSentimentDetectionJobRequest,
+    ) -> RusotoFuture<StartSentimentDetectionJobResponse, StartSentimentDetectionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartSentimentDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StartSentimentDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartSentimentDetectionJobResponse, _>(
+                    response,
+                    "StartSentimentDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartSentimentDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts an asynchronous topic detection job. Use the <code>DescribeTopicDetectionJob</code> operation to track the status of a job.</p>
+    fn start_topics_detection_job(
+        &self,
+        input: StartTopicsDetectionJobRequest,
+    ) -> RusotoFuture<StartTopicsDetectionJobResponse, StartTopicsDetectionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartTopicsDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StartTopicsDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartTopicsDetectionJobResponse, _>(
+                    response,
+                    "StartTopicsDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartTopicsDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Stops a dominant language detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    fn stop_dominant_language_detection_job(
+        &self,
+        input: StopDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<StopDominantLanguageDetectionJobResponse, StopDominantLanguageDetectionJobError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopDominantLanguageDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StopDominantLanguageDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopDominantLanguageDetectionJobResponse, _>(
+                    response,
+                    "StopDominantLanguageDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopDominantLanguageDetectionJobError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Stops an entities detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    fn stop_entities_detection_job(
+        &self,
+        input: StopEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<StopEntitiesDetectionJobResponse, StopEntitiesDetectionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopEntitiesDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StopEntitiesDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopEntitiesDetectionJobResponse, _>(
+                    response,
+                    "StopEntitiesDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopEntitiesDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Stops a key phrases detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    fn stop_key_phrases_detection_job(
+        &self,
+        input: StopKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<StopKeyPhrasesDetectionJobResponse, StopKeyPhrasesDetectionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopKeyPhrasesDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StopKeyPhrasesDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopKeyPhrasesDetectionJobResponse, _>(
+                    response,
+                    "StopKeyPhrasesDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopKeyPhrasesDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Stops a sentiment detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is be stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    fn stop_sentiment_detection_job(
+        &self,
+        input: StopSentimentDetectionJobRequest,
+    ) -> RusotoFuture<StopSentimentDetectionJobResponse, StopSentimentDetectionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopSentimentDetectionJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StopSentimentDetectionJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopSentimentDetectionJobResponse, _>(
+                    response,
+                    "StopSentimentDetectionJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopSentimentDetectionJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Stops a document classifier training job while in progress.</p> <p>If the training job state is <code>TRAINING</code>, the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the training job completes before it can be stopped, it is put into the <code>TRAINED</code>; otherwise the training job is stopped and put into the <code>STOPPED</code> state and the service sends back an HTTP 200 response with an empty HTTP body. </p>
+    fn stop_training_document_classifier(
+        &self,
+        input: StopTrainingDocumentClassifierRequest,
+    ) -> RusotoFuture<StopTrainingDocumentClassifierResponse, StopTrainingDocumentClassifierError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopTrainingDocumentClassifier");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StopTrainingDocumentClassifier",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopTrainingDocumentClassifierResponse, _>(
+                    response,
+                    "StopTrainingDocumentClassifier",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopTrainingDocumentClassifierError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Stops an entity recognizer training job while in progress.</p> <p>If the training job state is <code>TRAINING</code>, the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the training job completes before it can be stopped, it is put into the <code>TRAINED</code>; otherwise the training job is stopped and putted into the <code>STOPPED</code> state and the service sends back an HTTP 200 response with an empty HTTP body.</p>
+    fn stop_training_entity_recognizer(
+        &self,
+        input: StopTrainingEntityRecognizerRequest,
+    ) -> RusotoFuture<StopTrainingEntityRecognizerResponse, StopTrainingEntityRecognizerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopTrainingEntityRecognizer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.StopTrainingEntityRecognizer",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopEntitiesDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopEntitiesDetectionJobError::from_response(response))
-                }))
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopTrainingEntityRecognizerResponse, _>(
+                    response,
+                    "StopTrainingEntityRecognizer",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopTrainingEntityRecognizerError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates a specific tag with an Amazon Comprehend resource. A tag is a key-value pair that adds as a metadata to a resource used by Amazon Comprehend. For example, a tag with "Sales" as the key might be added to a resource to indicate its use by the sales department. </p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes a specific tag associated with an Amazon Comprehend resource. </p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon Comprehend API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockComprehend {
+    batch_detect_dominant_language: Option<
+        Box<
+            dyn Fn(
+                    BatchDetectDominantLanguageRequest,
+                ) -> ::std::result::Result<
+                    BatchDetectDominantLanguageResponse,
+                    RusotoError<BatchDetectDominantLanguageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_detect_entities: Option<
+        Box<
+            dyn Fn(
+                    BatchDetectEntitiesRequest,
+                ) -> ::std::result::Result<
+                    BatchDetectEntitiesResponse,
+                    RusotoError<BatchDetectEntitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_detect_key_phrases: Option<
+        Box<
+            dyn Fn(
+                    BatchDetectKeyPhrasesRequest,
+                ) -> ::std::result::Result<
+                    BatchDetectKeyPhrasesResponse,
+                    RusotoError<BatchDetectKeyPhrasesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_detect_sentiment: Option<
+        Box<
+            dyn Fn(
+                    BatchDetectSentimentRequest,
+                ) -> ::std::result::Result<
+                    BatchDetectSentimentResponse,
+                    RusotoError<BatchDetectSentimentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_detect_syntax: Option<
+        Box<
+            dyn Fn(
+                    BatchDetectSyntaxRequest,
+                ) -> ::std::result::Result<
+                    BatchDetectSyntaxResponse,
+                    RusotoError<BatchDetectSyntaxError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_document_classifier: Option<
+        Box<
+            dyn Fn(
+                    CreateDocumentClassifierRequest,
+                ) -> ::std::result::Result<
+                    CreateDocumentClassifierResponse,
+                    RusotoError<CreateDocumentClassifierError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_entity_recognizer: Option<
+        Box<
+            dyn Fn(
+                    CreateEntityRecognizerRequest,
+                ) -> ::std::result::Result<
+                    CreateEntityRecognizerResponse,
+                    RusotoError<CreateEntityRecognizerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_document_classifier: Option<
+        Box<
+            dyn Fn(
+                    DeleteDocumentClassifierRequest,
+                ) -> ::std::result::Result<
+                    DeleteDocumentClassifierResponse,
+                    RusotoError<DeleteDocumentClassifierError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_entity_recognizer: Option<
+        Box<
+            dyn Fn(
+                    DeleteEntityRecognizerRequest,
+                ) -> ::std::result::Result<
+                    DeleteEntityRecognizerResponse,
+                    RusotoError<DeleteEntityRecognizerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_document_classification_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeDocumentClassificationJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeDocumentClassificationJobResponse,
+                    RusotoError<DescribeDocumentClassificationJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_document_classifier: Option<
+        Box<
+            dyn Fn(
+                    DescribeDocumentClassifierRequest,
+                ) -> ::std::result::Result<
+                    DescribeDocumentClassifierResponse,
+                    RusotoError<DescribeDocumentClassifierError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_dominant_language_detection_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeDominantLanguageDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeDominantLanguageDetectionJobResponse,
+                    RusotoError<DescribeDominantLanguageDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_entities_detection_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeEntitiesDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeEntitiesDetectionJobResponse,
+                    RusotoError<DescribeEntitiesDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_entity_recognizer: Option<
+        Box<
+            dyn Fn(
+                    DescribeEntityRecognizerRequest,
+                ) -> ::std::result::Result<
+                    DescribeEntityRecognizerResponse,
+                    RusotoError<DescribeEntityRecognizerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_key_phrases_detection_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeKeyPhrasesDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeKeyPhrasesDetectionJobResponse,
+                    RusotoError<DescribeKeyPhrasesDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_sentiment_detection_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeSentimentDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeSentimentDetectionJobResponse,
+                    RusotoError<DescribeSentimentDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_topics_detection_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeTopicsDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeTopicsDetectionJobResponse,
+                    RusotoError<DescribeTopicsDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detect_dominant_language: Option<
+        Box<
+            dyn Fn(
+                    DetectDominantLanguageRequest,
+                ) -> ::std::result::Result<
+                    DetectDominantLanguageResponse,
+                    RusotoError<DetectDominantLanguageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detect_entities: Option<
+        Box<
+            dyn Fn(
+                    DetectEntitiesRequest,
+                ) -> ::std::result::Result<
+                    DetectEntitiesResponse,
+                    RusotoError<DetectEntitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detect_key_phrases: Option<
+        Box<
+            dyn Fn(
+                    DetectKeyPhrasesRequest,
+                ) -> ::std::result::Result<
+                    DetectKeyPhrasesResponse,
+                    RusotoError<DetectKeyPhrasesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detect_sentiment: Option<
+        Box<
+            dyn Fn(
+                    DetectSentimentRequest,
+                ) -> ::std::result::Result<
+                    DetectSentimentResponse,
+                    RusotoError<DetectSentimentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detect_syntax: Option<
+        Box<
+            dyn Fn(
+                    DetectSyntaxRequest,
+                )
+                    -> ::std::result::Result<DetectSyntaxResponse, RusotoError<DetectSyntaxError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_document_classification_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListDocumentClassificationJobsRequest,
+                ) -> ::std::result::Result<
+                    ListDocumentClassificationJobsResponse,
+                    RusotoError<ListDocumentClassificationJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_document_classifiers: Option<
+        Box<
+            dyn Fn(
+                    ListDocumentClassifiersRequest,
+                ) -> ::std::result::Result<
+                    ListDocumentClassifiersResponse,
+                    RusotoError<ListDocumentClassifiersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_dominant_language_detection_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListDominantLanguageDetectionJobsRequest,
+                ) -> ::std::result::Result<
+                    ListDominantLanguageDetectionJobsResponse,
+                    RusotoError<ListDominantLanguageDetectionJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_entities_detection_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListEntitiesDetectionJobsRequest,
+                ) -> ::std::result::Result<
+                    ListEntitiesDetectionJobsResponse,
+                    RusotoError<ListEntitiesDetectionJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_entity_recognizers: Option<
+        Box<
+            dyn Fn(
+                    ListEntityRecognizersRequest,
+                ) -> ::std::result::Result<
+                    ListEntityRecognizersResponse,
+                    RusotoError<ListEntityRecognizersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_key_phrases_detection_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListKeyPhrasesDetectionJobsRequest,
+                ) -> ::std::result::Result<
+                    ListKeyPhrasesDetectionJobsResponse,
+                    RusotoError<ListKeyPhrasesDetectionJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_sentiment_detection_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListSentimentDetectionJobsRequest,
+                ) -> ::std::result::Result<
+                    ListSentimentDetectionJobsResponse,
+                    RusotoError<ListSentimentDetectionJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_topics_detection_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListTopicsDetectionJobsRequest,
+                ) -> ::std::result::Result<
+                    ListTopicsDetectionJobsResponse,
+                    RusotoError<ListTopicsDetectionJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_document_classification_job: Option<
+        Box<
+            dyn Fn(
+                    StartDocumentClassificationJobRequest,
+                ) -> ::std::result::Result<
+                    StartDocumentClassificationJobResponse,
+                    RusotoError<StartDocumentClassificationJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_dominant_language_detection_job: Option<
+        Box<
+            dyn Fn(
+                    StartDominantLanguageDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    StartDominantLanguageDetectionJobResponse,
+                    RusotoError<StartDominantLanguageDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_entities_detection_job: Option<
+        Box<
+            dyn Fn(
+                    StartEntitiesDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    StartEntitiesDetectionJobResponse,
+                    RusotoError<StartEntitiesDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_key_phrases_detection_job: Option<
+        Box<
+            dyn Fn(
+                    StartKeyPhrasesDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    StartKeyPhrasesDetectionJobResponse,
+                    RusotoError<StartKeyPhrasesDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_sentiment_detection_job: Option<
+        Box<
+            dyn Fn(
+                    StartSentimentDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    StartSentimentDetectionJobResponse,
+                    RusotoError<StartSentimentDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_topics_detection_job: Option<
+        Box<
+            dyn Fn(
+                    StartTopicsDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    StartTopicsDetectionJobResponse,
+                    RusotoError<StartTopicsDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_dominant_language_detection_job: Option<
+        Box<
+            dyn Fn(
+                    StopDominantLanguageDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    StopDominantLanguageDetectionJobResponse,
+                    RusotoError<StopDominantLanguageDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_entities_detection_job: Option<
+        Box<
+            dyn Fn(
+                    StopEntitiesDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    StopEntitiesDetectionJobResponse,
+                    RusotoError<StopEntitiesDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_key_phrases_detection_job: Option<
+        Box<
+            dyn Fn(
+                    StopKeyPhrasesDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    StopKeyPhrasesDetectionJobResponse,
+                    RusotoError<StopKeyPhrasesDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_sentiment_detection_job: Option<
+        Box<
+            dyn Fn(
+                    StopSentimentDetectionJobRequest,
+                ) -> ::std::result::Result<
+                    StopSentimentDetectionJobResponse,
+                    RusotoError<StopSentimentDetectionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_training_document_classifier: Option<
+        Box<
+            dyn Fn(
+                    StopTrainingDocumentClassifierRequest,
+                ) -> ::std::result::Result<
+                    StopTrainingDocumentClassifierResponse,
+                    RusotoError<StopTrainingDocumentClassifierError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_training_entity_recognizer: Option<
+        Box<
+            dyn Fn(
+                    StopTrainingEntityRecognizerRequest,
+                ) -> ::std::result::Result<
+                    StopTrainingEntityRecognizerResponse,
+                    RusotoError<StopTrainingEntityRecognizerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockComprehend {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockComprehend {
+        Default::default()
+    }
+
+    /// Answers `batch_detect_dominant_language` with what `handler` returns for its input.
+    pub fn with_batch_detect_dominant_language<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                BatchDetectDominantLanguageRequest,
+            ) -> ::std::result::Result<
+                BatchDetectDominantLanguageResponse,
+                RusotoError<BatchDetectDominantLanguageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_detect_dominant_language = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_detect_entities` with what `handler` returns for its input.
+    pub fn with_batch_detect_entities<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                BatchDetectEntitiesRequest,
+            ) -> ::std::result::Result<
+                BatchDetectEntitiesResponse,
+                RusotoError<BatchDetectEntitiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_detect_entities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_detect_key_phrases` with what `handler` returns for its input.
+    pub fn with_batch_detect_key_phrases<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                BatchDetectKeyPhrasesRequest,
+            ) -> ::std::result::Result<
+                BatchDetectKeyPhrasesResponse,
+                RusotoError<BatchDetectKeyPhrasesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_detect_key_phrases = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_detect_sentiment` with what `handler` returns for its input.
+    pub fn with_batch_detect_sentiment<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                BatchDetectSentimentRequest,
+            ) -> ::std::result::Result<
+                BatchDetectSentimentResponse,
+                RusotoError<BatchDetectSentimentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_detect_sentiment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_detect_syntax` with what `handler` returns for its input.
+    pub fn with_batch_detect_syntax<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                BatchDetectSyntaxRequest,
+            ) -> ::std::result::Result<
+                BatchDetectSyntaxResponse,
+                RusotoError<BatchDetectSyntaxError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_detect_syntax = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_document_classifier` with what `handler` returns for its input.
+    pub fn with_create_document_classifier<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                CreateDocumentClassifierRequest,
+            ) -> ::std::result::Result<
+                CreateDocumentClassifierResponse,
+                RusotoError<CreateDocumentClassifierError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_document_classifier = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_entity_recognizer` with what `handler` returns for its input.
+    pub fn with_create_entity_recognizer<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                CreateEntityRecognizerRequest,
+            ) -> ::std::result::Result<
+                CreateEntityRecognizerResponse,
+                RusotoError<CreateEntityRecognizerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_entity_recognizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_document_classifier` with what `handler` returns for its input.
+    pub fn with_delete_document_classifier<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DeleteDocumentClassifierRequest,
+            ) -> ::std::result::Result<
+                DeleteDocumentClassifierResponse,
+                RusotoError<DeleteDocumentClassifierError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_document_classifier = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_entity_recognizer` with what `handler` returns for its input.
+    pub fn with_delete_entity_recognizer<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DeleteEntityRecognizerRequest,
+            ) -> ::std::result::Result<
+                DeleteEntityRecognizerResponse,
+                RusotoError<DeleteEntityRecognizerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_entity_recognizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_document_classification_job` with what `handler` returns for its input.
+    pub fn with_describe_document_classification_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DescribeDocumentClassificationJobRequest,
+            ) -> ::std::result::Result<
+                DescribeDocumentClassificationJobResponse,
+                RusotoError<DescribeDocumentClassificationJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_document_classification_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_document_classifier` with what `handler` returns for its input.
+    pub fn with_describe_document_classifier<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DescribeDocumentClassifierRequest,
+            ) -> ::std::result::Result<
+                DescribeDocumentClassifierResponse,
+                RusotoError<DescribeDocumentClassifierError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_document_classifier = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_dominant_language_detection_job` with what `handler` returns for its input.
+    pub fn with_describe_dominant_language_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DescribeDominantLanguageDetectionJobRequest,
+            ) -> ::std::result::Result<
+                DescribeDominantLanguageDetectionJobResponse,
+                RusotoError<DescribeDominantLanguageDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_dominant_language_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_entities_detection_job` with what `handler` returns for its input.
+    pub fn with_describe_entities_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DescribeEntitiesDetectionJobRequest,
+            ) -> ::std::result::Result<
+                DescribeEntitiesDetectionJobResponse,
+                RusotoError<DescribeEntitiesDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_entities_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_entity_recognizer` with what `handler` returns for its input.
+    pub fn with_describe_entity_recognizer<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DescribeEntityRecognizerRequest,
+            ) -> ::std::result::Result<
+                DescribeEntityRecognizerResponse,
+                RusotoError<DescribeEntityRecognizerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_entity_recognizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_key_phrases_detection_job` with what `handler` returns for its input.
+    pub fn with_describe_key_phrases_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DescribeKeyPhrasesDetectionJobRequest,
+            ) -> ::std::result::Result<
+                DescribeKeyPhrasesDetectionJobResponse,
+                RusotoError<DescribeKeyPhrasesDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_key_phrases_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_sentiment_detection_job` with what `handler` returns for its input.
+    pub fn with_describe_sentiment_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DescribeSentimentDetectionJobRequest,
+            ) -> ::std::result::Result<
+                DescribeSentimentDetectionJobResponse,
+                RusotoError<DescribeSentimentDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_sentiment_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_topics_detection_job` with what `handler` returns for its input.
+    pub fn with_describe_topics_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DescribeTopicsDetectionJobRequest,
+            ) -> ::std::result::Result<
+                DescribeTopicsDetectionJobResponse,
+                RusotoError<DescribeTopicsDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_topics_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_dominant_language` with what `handler` returns for its input.
+    pub fn with_detect_dominant_language<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DetectDominantLanguageRequest,
+            ) -> ::std::result::Result<
+                DetectDominantLanguageResponse,
+                RusotoError<DetectDominantLanguageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_dominant_language = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_entities` with what `handler` returns for its input.
+    pub fn with_detect_entities<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DetectEntitiesRequest,
+            )
+                -> ::std::result::Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_entities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_key_phrases` with what `handler` returns for its input.
+    pub fn with_detect_key_phrases<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DetectKeyPhrasesRequest,
+            ) -> ::std::result::Result<
+                DetectKeyPhrasesResponse,
+                RusotoError<DetectKeyPhrasesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_key_phrases = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_sentiment` with what `handler` returns for its input.
+    pub fn with_detect_sentiment<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DetectSentimentRequest,
+            )
+                -> ::std::result::Result<DetectSentimentResponse, RusotoError<DetectSentimentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_sentiment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_syntax` with what `handler` returns for its input.
+    pub fn with_detect_syntax<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                DetectSyntaxRequest,
+            )
+                -> ::std::result::Result<DetectSyntaxResponse, RusotoError<DetectSyntaxError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_syntax = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_document_classification_jobs` with what `handler` returns for its input.
+    pub fn with_list_document_classification_jobs<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                ListDocumentClassificationJobsRequest,
+            ) -> ::std::result::Result<
+                ListDocumentClassificationJobsResponse,
+                RusotoError<ListDocumentClassificationJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_document_classification_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_document_classifiers` with what `handler` returns for its input.
+    pub fn with_list_document_classifiers<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                ListDocumentClassifiersRequest,
+            ) -> ::std::result::Result<
+                ListDocumentClassifiersResponse,
+                RusotoError<ListDocumentClassifiersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_document_classifiers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_dominant_language_detection_jobs` with what `handler` returns for its input.
+    pub fn with_list_dominant_language_detection_jobs<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                ListDominantLanguageDetectionJobsRequest,
+            ) -> ::std::result::Result<
+                ListDominantLanguageDetectionJobsResponse,
+                RusotoError<ListDominantLanguageDetectionJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_dominant_language_detection_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_entities_detection_jobs` with what `handler` returns for its input.
+    pub fn with_list_entities_detection_jobs<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                ListEntitiesDetectionJobsRequest,
+            ) -> ::std::result::Result<
+                ListEntitiesDetectionJobsResponse,
+                RusotoError<ListEntitiesDetectionJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_entities_detection_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_entity_recognizers` with what `handler` returns for its input.
+    pub fn with_list_entity_recognizers<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                ListEntityRecognizersRequest,
+            ) -> ::std::result::Result<
+                ListEntityRecognizersResponse,
+                RusotoError<ListEntityRecognizersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_entity_recognizers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_key_phrases_detection_jobs` with what `handler` returns for its input.
+    pub fn with_list_key_phrases_detection_jobs<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                ListKeyPhrasesDetectionJobsRequest,
+            ) -> ::std::result::Result<
+                ListKeyPhrasesDetectionJobsResponse,
+                RusotoError<ListKeyPhrasesDetectionJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_key_phrases_detection_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_sentiment_detection_jobs` with what `handler` returns for its input.
+    pub fn with_list_sentiment_detection_jobs<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                ListSentimentDetectionJobsRequest,
+            ) -> ::std::result::Result<
+                ListSentimentDetectionJobsResponse,
+                RusotoError<ListSentimentDetectionJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_sentiment_detection_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_topics_detection_jobs` with what `handler` returns for its input.
+    pub fn with_list_topics_detection_jobs<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                ListTopicsDetectionJobsRequest,
+            ) -> ::std::result::Result<
+                ListTopicsDetectionJobsResponse,
+                RusotoError<ListTopicsDetectionJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_topics_detection_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_document_classification_job` with what `handler` returns for its input.
+    pub fn with_start_document_classification_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StartDocumentClassificationJobRequest,
+            ) -> ::std::result::Result<
+                StartDocumentClassificationJobResponse,
+                RusotoError<StartDocumentClassificationJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_document_classification_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_dominant_language_detection_job` with what `handler` returns for its input.
+    pub fn with_start_dominant_language_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StartDominantLanguageDetectionJobRequest,
+            ) -> ::std::result::Result<
+                StartDominantLanguageDetectionJobResponse,
+                RusotoError<StartDominantLanguageDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_dominant_language_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_entities_detection_job` with what `handler` returns for its input.
+    pub fn with_start_entities_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StartEntitiesDetectionJobRequest,
+            ) -> ::std::result::Result<
+                StartEntitiesDetectionJobResponse,
+                RusotoError<StartEntitiesDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_entities_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_key_phrases_detection_job` with what `handler` returns for its input.
+    pub fn with_start_key_phrases_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StartKeyPhrasesDetectionJobRequest,
+            ) -> ::std::result::Result<
+                StartKeyPhrasesDetectionJobResponse,
+                RusotoError<StartKeyPhrasesDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_key_phrases_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_sentiment_detection_job` with what `handler` returns for its input.
+    pub fn with_start_sentiment_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StartSentimentDetectionJobRequest,
+            ) -> ::std::result::Result<
+                StartSentimentDetectionJobResponse,
+                RusotoError<StartSentimentDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_sentiment_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_topics_detection_job` with what `handler` returns for its input.
+    pub fn with_start_topics_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StartTopicsDetectionJobRequest,
+            ) -> ::std::result::Result<
+                StartTopicsDetectionJobResponse,
+                RusotoError<StartTopicsDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_topics_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_dominant_language_detection_job` with what `handler` returns for its input.
+    pub fn with_stop_dominant_language_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StopDominantLanguageDetectionJobRequest,
+            ) -> ::std::result::Result<
+                StopDominantLanguageDetectionJobResponse,
+                RusotoError<StopDominantLanguageDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_dominant_language_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_entities_detection_job` with what `handler` returns for its input.
+    pub fn with_stop_entities_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StopEntitiesDetectionJobRequest,
+            ) -> ::std::result::Result<
+                StopEntitiesDetectionJobResponse,
+                RusotoError<StopEntitiesDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_entities_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_key_phrases_detection_job` with what `handler` returns for its input.
+    pub fn with_stop_key_phrases_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StopKeyPhrasesDetectionJobRequest,
+            ) -> ::std::result::Result<
+                StopKeyPhrasesDetectionJobResponse,
+                RusotoError<StopKeyPhrasesDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_key_phrases_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_sentiment_detection_job` with what `handler` returns for its input.
+    pub fn with_stop_sentiment_detection_job<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StopSentimentDetectionJobRequest,
+            ) -> ::std::result::Result<
+                StopSentimentDetectionJobResponse,
+                RusotoError<StopSentimentDetectionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_sentiment_detection_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_training_document_classifier` with what `handler` returns for its input.
+    pub fn with_stop_training_document_classifier<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StopTrainingDocumentClassifierRequest,
+            ) -> ::std::result::Result<
+                StopTrainingDocumentClassifierResponse,
+                RusotoError<StopTrainingDocumentClassifierError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_training_document_classifier = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_training_entity_recognizer` with what `handler` returns for its input.
+    pub fn with_stop_training_entity_recognizer<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                StopTrainingEntityRecognizerRequest,
+            ) -> ::std::result::Result<
+                StopTrainingEntityRecognizerResponse,
+                RusotoError<StopTrainingEntityRecognizerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_training_entity_recognizer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockComprehend
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Comprehend for MockComprehend {
+    fn batch_detect_dominant_language(
+        &self,
+        input: BatchDetectDominantLanguageRequest,
+    ) -> RusotoFuture<BatchDetectDominantLanguageResponse, BatchDetectDominantLanguageError> {
+        match self.batch_detect_dominant_language {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for batch_detect_dominant_language"),
+        }
+    }
+    fn batch_detect_entities(
+        &self,
+        input: BatchDetectEntitiesRequest,
+    ) -> RusotoFuture<BatchDetectEntitiesResponse, BatchDetectEntitiesError> {
+        match self.batch_detect_entities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for batch_detect_entities"),
+        }
+    }
+    fn batch_detect_key_phrases(
+        &self,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> RusotoFuture<BatchDetectKeyPhrasesResponse, BatchDetectKeyPhrasesError> {
+        match self.batch_detect_key_phrases {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for batch_detect_key_phrases"),
+        }
+    }
+    fn batch_detect_sentiment(
+        &self,
+        input: BatchDetectSentimentRequest,
+    ) -> RusotoFuture<BatchDetectSentimentResponse, BatchDetectSentimentError> {
+        match self.batch_detect_sentiment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for batch_detect_sentiment"),
+        }
+    }
+    fn batch_detect_syntax(
+        &self,
+        input: BatchDetectSyntaxRequest,
+    ) -> RusotoFuture<BatchDetectSyntaxResponse, BatchDetectSyntaxError> {
+        match self.batch_detect_syntax {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for batch_detect_syntax"),
+        }
+    }
+    fn create_document_classifier(
+        &self,
+        input: CreateDocumentClassifierRequest,
+    ) -> RusotoFuture<CreateDocumentClassifierResponse, CreateDocumentClassifierError> {
+        match self.create_document_classifier {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for create_document_classifier"),
+        }
+    }
+    fn create_entity_recognizer(
+        &self,
+        input: CreateEntityRecognizerRequest,
+    ) -> RusotoFuture<CreateEntityRecognizerResponse, CreateEntityRecognizerError> {
+        match self.create_entity_recognizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for create_entity_recognizer"),
+        }
+    }
+    fn delete_document_classifier(
+        &self,
+        input: DeleteDocumentClassifierRequest,
+    ) -> RusotoFuture<DeleteDocumentClassifierResponse, DeleteDocumentClassifierError> {
+        match self.delete_document_classifier {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for delete_document_classifier"),
+        }
+    }
+    fn delete_entity_recognizer(
+        &self,
+        input: DeleteEntityRecognizerRequest,
+    ) -> RusotoFuture<DeleteEntityRecognizerResponse, DeleteEntityRecognizerError> {
+        match self.delete_entity_recognizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for delete_entity_recognizer"),
+        }
+    }
+    fn describe_document_classification_job(
+        &self,
+        input: DescribeDocumentClassificationJobRequest,
+    ) -> RusotoFuture<
+        DescribeDocumentClassificationJobResponse,
+        DescribeDocumentClassificationJobError,
+    > {
+        match self.describe_document_classification_job {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockComprehend has no handler for describe_document_classification_job")
+            }
+        }
+    }
+    fn describe_document_classifier(
+        &self,
+        input: DescribeDocumentClassifierRequest,
+    ) -> RusotoFuture<DescribeDocumentClassifierResponse, DescribeDocumentClassifierError> {
+        match self.describe_document_classifier {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for describe_document_classifier"),
+        }
+    }
+    fn describe_dominant_language_detection_job(
+        &self,
+        input: DescribeDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<
+        DescribeDominantLanguageDetectionJobResponse,
+        DescribeDominantLanguageDetectionJobError,
+    > {
+        match self.describe_dominant_language_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockComprehend has no handler for describe_dominant_language_detection_job")
+            }
+        }
+    }
+    fn describe_entities_detection_job(
+        &self,
+        input: DescribeEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<DescribeEntitiesDetectionJobResponse, DescribeEntitiesDetectionJobError> {
+        match self.describe_entities_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for describe_entities_detection_job"),
+        }
+    }
+    fn describe_entity_recognizer(
+        &self,
+        input: DescribeEntityRecognizerRequest,
+    ) -> RusotoFuture<DescribeEntityRecognizerResponse, DescribeEntityRecognizerError> {
+        match self.describe_entity_recognizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for describe_entity_recognizer"),
+        }
+    }
+    fn describe_key_phrases_detection_job(
+        &self,
+        input: DescribeKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<DescribeKeyPhrasesDetectionJobResponse, DescribeKeyPhrasesDetectionJobError>
+    {
+        match self.describe_key_phrases_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for describe_key_phrases_detection_job"),
+        }
+    }
+    fn describe_sentiment_detection_job(
+        &self,
+        input: DescribeSentimentDetectionJobRequest,
+    ) -> RusotoFuture<DescribeSentimentDetectionJobResponse, DescribeSentimentDetectionJobError>
+    {
+        match self.describe_sentiment_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for describe_sentiment_detection_job"),
+        }
+    }
+    fn describe_topics_detection_job(
+        &self,
+        input: DescribeTopicsDetectionJobRequest,
+    ) -> RusotoFuture<DescribeTopicsDetectionJobResponse, DescribeTopicsDetectionJobError> {
+        match self.describe_topics_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for describe_topics_detection_job"),
+        }
+    }
+    fn detect_dominant_language(
+        &self,
+        input: DetectDominantLanguageRequest,
+    ) -> RusotoFuture<DetectDominantLanguageResponse, DetectDominantLanguageError> {
+        match self.detect_dominant_language {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for detect_dominant_language"),
+        }
+    }
+    fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        match self.detect_entities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for detect_entities"),
+        }
+    }
+    fn detect_key_phrases(
+        &self,
+        input: DetectKeyPhrasesRequest,
+    ) -> RusotoFuture<DetectKeyPhrasesResponse, DetectKeyPhrasesError> {
+        match self.detect_key_phrases {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for detect_key_phrases"),
+        }
+    }
+    fn detect_sentiment(
+        &self,
+        input: DetectSentimentRequest,
+    ) -> RusotoFuture<DetectSentimentResponse, DetectSentimentError> {
+        match self.detect_sentiment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for detect_sentiment"),
+        }
+    }
+    fn detect_syntax(
+        &self,
+        input: DetectSyntaxRequest,
+    ) -> RusotoFuture<DetectSyntaxResponse, DetectSyntaxError> {
+        match self.detect_syntax {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for detect_syntax"),
+        }
+    }
+    fn list_document_classification_jobs(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> RusotoFuture<ListDocumentClassificationJobsResponse, ListDocumentClassificationJobsError>
+    {
+        match self.list_document_classification_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for list_document_classification_jobs"),
+        }
+    }
+    fn list_document_classifiers(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> RusotoFuture<ListDocumentClassifiersResponse, ListDocumentClassifiersError> {
+        match self.list_document_classifiers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for list_document_classifiers"),
+        }
+    }
+    fn list_dominant_language_detection_jobs(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> RusotoFuture<
+        ListDominantLanguageDetectionJobsResponse,
+        ListDominantLanguageDetectionJobsError,
+    > {
+        match self.list_dominant_language_detection_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockComprehend has no handler for list_dominant_language_detection_jobs")
             }
-        })
+        }
+    }
+    fn list_entities_detection_jobs(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> RusotoFuture<ListEntitiesDetectionJobsResponse, ListEntitiesDetectionJobsError> {
+        match self.list_entities_detection_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for list_entities_detection_jobs"),
+        }
+    }
+    fn list_entity_recognizers(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> RusotoFuture<ListEntityRecognizersResponse, ListEntityRecognizersError> {
+        match self.list_entity_recognizers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for list_entity_recognizers"),
+        }
+    }
+    fn list_key_phrases_detection_jobs(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> RusotoFuture<ListKeyPhrasesDetectionJobsResponse, ListKeyPhrasesDetectionJobsError> {
+        match self.list_key_phrases_detection_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for list_key_phrases_detection_jobs"),
+        }
+    }
+    fn list_sentiment_detection_jobs(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> RusotoFuture<ListSentimentDetectionJobsResponse, ListSentimentDetectionJobsError> {
+        match self.list_sentiment_detection_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for list_sentiment_detection_jobs"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_topics_detection_jobs(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> RusotoFuture<ListTopicsDetectionJobsResponse, ListTopicsDetectionJobsError> {
+        match self.list_topics_detection_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for list_topics_detection_jobs"),
+        }
+    }
+    fn start_document_classification_job(
+        &self,
+        input: StartDocumentClassificationJobRequest,
+    ) -> RusotoFuture<StartDocumentClassificationJobResponse, StartDocumentClassificationJobError>
+    {
+        match self.start_document_classification_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for start_document_classification_job"),
+        }
+    }
+    fn start_dominant_language_detection_job(
+        &self,
+        input: StartDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<
+        StartDominantLanguageDetectionJobResponse,
+        StartDominantLanguageDetectionJobError,
+    > {
+        match self.start_dominant_language_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockComprehend has no handler for start_dominant_language_detection_job")
+            }
+        }
+    }
+    fn start_entities_detection_job(
+        &self,
+        input: StartEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<StartEntitiesDetectionJobResponse, StartEntitiesDetectionJobError> {
+        match self.start_entities_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for start_entities_detection_job"),
+        }
+    }
+    fn start_key_phrases_detection_job(
+        &self,
+        input: StartKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<StartKeyPhrasesDetectionJobResponse, StartKeyPhrasesDetectionJobError> {
+        match self.start_key_phrases_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for start_key_phrases_detection_job"),
+        }
+    }
+    fn start_sentiment_detection_job(
+        &self,
+        input: StartSentimentDetectionJobRequest,
+    ) -> RusotoFuture<StartSentimentDetectionJobResponse, StartSentimentDetectionJobError> {
+        match self.start_sentiment_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for start_sentiment_detection_job"),
+        }
+    }
+    fn start_topics_detection_job(
+        &self,
+        input: StartTopicsDetectionJobRequest,
+    ) -> RusotoFuture<StartTopicsDetectionJobResponse, StartTopicsDetectionJobError> {
+        match self.start_topics_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for start_topics_detection_job"),
+        }
+    }
+    fn stop_dominant_language_detection_job(
+        &self,
+        input: StopDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<StopDominantLanguageDetectionJobResponse, StopDominantLanguageDetectionJobError>
+    {
+        match self.stop_dominant_language_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockComprehend has no handler for stop_dominant_language_detection_job")
+            }
+        }
+    }
+    fn stop_entities_detection_job(
+        &self,
+        input: StopEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<StopEntitiesDetectionJobResponse, StopEntitiesDetectionJobError> {
+        match self.stop_entities_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for stop_entities_detection_job"),
+        }
     }
-
-    /// <p>Stops a key phrases detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
     fn stop_key_phrases_detection_job(
         &self,
         input: StopKeyPhrasesDetectionJobRequest,
     ) -> RusotoFuture<StopKeyPhrasesDetectionJobResponse, StopKeyPhrasesDetectionJobError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StopKeyPhrasesDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopKeyPhrasesDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopKeyPhrasesDetectionJobError::from_response(response))
-                }))
-            }
-        })
+        match self.stop_key_phrases_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for stop_key_phrases_detection_job"),
+        }
     }
-
-    /// <p>Stops a sentiment detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is be stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
     fn stop_sentiment_detection_job(
         &self,
         input: StopSentimentDetectionJobRequest,
     ) -> RusotoFuture<StopSentimentDetectionJobResponse, StopSentimentDetectionJobError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StopSentimentDetectionJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopSentimentDetectionJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopSentimentDetectionJobError::from_response(response))
-                }))
-            }
-        })
+        match self.stop_sentiment_detection_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for stop_sentiment_detection_job"),
+        }
     }
-
-    /// <p>Stops a document classifier training job while in progress.</p> <p>If the training job state is <code>TRAINING</code>, the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the training job completes before it can be stopped, it is put into the <code>TRAINED</code>; otherwise the training job is stopped and put into the <code>STOPPED</code> state and the service sends back an HTTP 200 response with an empty HTTP body. </p>
     fn stop_training_document_classifier(
         &self,
         input: StopTrainingDocumentClassifierRequest,
     ) -> RusotoFuture<StopTrainingDocumentClassifierResponse, StopTrainingDocumentClassifierError>
     {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StopTrainingDocumentClassifier",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopTrainingDocumentClassifierResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopTrainingDocumentClassifierError::from_response(response))
-                }))
-            }
-        })
+        match self.stop_training_document_classifier {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for stop_training_document_classifier"),
+        }
     }
-
-    /// <p>Stops an entity recognizer training job while in progress.</p> <p>If the training job state is <code>TRAINING</code>, the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the training job completes before it can be stopped, it is put into the <code>TRAINED</code>; otherwise the training job is stopped and putted into the <code>STOPPED</code> state and the service sends back an HTTP 200 response with an empty HTTP body.</p>
     fn stop_training_entity_recognizer(
         &self,
         input: StopTrainingEntityRecognizerRequest,
     ) -> RusotoFuture<StopTrainingEntityRecognizerResponse, StopTrainingEntityRecognizerError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.StopTrainingEntityRecognizer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopTrainingEntityRecognizerResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopTrainingEntityRecognizerError::from_response(response))
-                }))
-            }
-        })
+        match self.stop_training_entity_recognizer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for stop_training_entity_recognizer"),
+        }
     }
-
-    /// <p>Associates a specific tag with an Amazon Comprehend resource. A tag is a key-value pair that adds as a metadata to a resource used by Amazon Comprehend. For example, a tag with "Sales" as the key might be added to a resource to indicate its use by the sales department. </p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Removes a specific tag associated with an Amazon Comprehend resource. </p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehend has no handler for untag_resource"),
+        }
     }
 }
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p> An extracted segment of the text that is an attribute of an entity, or otherwise related to an entity, such as the dosage of a medication taken. It contains information about the attribute such as id, begin and end offset within the input text, and the segment of the input text. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Attribute {
     /// <p> The 0-based character offset in the input text that shows where the attribute begins. The offset returns the UTF-8 code point in the string. </p>
     #[serde(rename = "BeginOffset")]
@@ -62,14 +63,26 @@ pub struct Attribute {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectEntitiesRequest {
     /// <p> A UTF-8 text string containing the clinical content being examined for entities. Each string must contain fewer than 20,000 bytes of characters.</p>
     #[serde(rename = "Text")]
     pub text: String,
 }
 
+impl Validate for DetectEntitiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text"),
+            self.text.chars().count(),
+            Some(1),
+            Some(20000),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectEntitiesResponse {
     /// <p> The collection of medical entities extracted from the input text and their associated information. For each entity, the response provides the entity text, the entity category, where the entity text begins and ends, and the level of confidence that Comprehend Medical has in the detection and analysis. Attributes and traits of the entity are also returned.</p>
     #[serde(rename = "Entities")]
@@ -85,14 +98,26 @@ pub struct DetectEntitiesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectPHIRequest {
     /// <p> A UTF-8 text string containing the clinical content being examined for PHI entities. Each string must contain fewer than 20,000 bytes of characters. </p>
     #[serde(rename = "Text")]
     pub text: String,
 }
 
+impl Validate for DetectPHIRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "text"),
+            self.text.chars().count(),
+            Some(1),
+            Some(20000),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectPHIResponse {
     /// <p> The collection of PHI entities extracted from the input text and their associated information. For each entity, the response provides the entity text, the entity category, where the entity text begins and ends, and the level of confidence that Comprehend Medical has in its detection. </p>
     #[serde(rename = "Entities")]
@@ -105,7 +130,7 @@ pub struct DetectPHIResponse {
 
 /// <p> Provides information about an extracted medical entity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Entity {
     /// <p> The extracted attributes that relate to this entity.</p>
     #[serde(rename = "Attributes")]
@@ -147,7 +172,7 @@ pub struct Entity {
 
 /// <p> Provides contextual information about the extracted entity. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Trait {
     /// <p> Provides a name or contextual description about the trait. </p>
     #[serde(rename = "Name")]
@@ -161,7 +186,7 @@ pub struct Trait {
 
 /// <p> An attribute that we extracted, but were unable to relate to an entity. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnmappedAttribute {
     /// <p> The specific attribute that has been extracted but not mapped to an entity. </p>
     #[serde(rename = "Attribute")]
@@ -214,7 +239,12 @@ impl DetectEntitiesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DetectEntitiesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -277,7 +307,12 @@ impl DetectPHIError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DetectPHIError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -315,6 +350,49 @@ pub trait ComprehendMedical {
         input: DetectPHIRequest,
     ) -> RusotoFuture<DetectPHIResponse, DetectPHIError>;
 }
+const _: Option<&dyn ComprehendMedical> = None;
+impl<'a, T: ComprehendMedical + ?Sized> ComprehendMedical for &'a T {
+    fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        (**self).detect_entities(input)
+    }
+    fn detect_phi(
+        &self,
+        input: DetectPHIRequest,
+    ) -> RusotoFuture<DetectPHIResponse, DetectPHIError> {
+        (**self).detect_phi(input)
+    }
+}
+impl<T: ComprehendMedical + ?Sized> ComprehendMedical for Box<T> {
+    fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        (**self).detect_entities(input)
+    }
+    fn detect_phi(
+        &self,
+        input: DetectPHIRequest,
+    ) -> RusotoFuture<DetectPHIResponse, DetectPHIError> {
+        (**self).detect_phi(input)
+    }
+}
+impl<T: ComprehendMedical + ?Sized> ComprehendMedical for ::std::sync::Arc<T> {
+    fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        (**self).detect_entities(input)
+    }
+    fn detect_phi(
+        &self,
+        input: DetectPHIRequest,
+    ) -> RusotoFuture<DetectPHIResponse, DetectPHIError> {
+        (**self).detect_phi(input)
+    }
+}
 /// A client for the ComprehendMedical API.
 #[derive(Clone)]
 pub struct ComprehendMedicalClient {
@@ -358,7 +436,16 @@ impl ComprehendMedical for ComprehendMedicalClient {
         &self,
         input: DetectEntitiesRequest,
     ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DetectEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ComprehendMedical_20181030.DetectEntities");
@@ -367,10 +454,10 @@ impl ComprehendMedical for ComprehendMedicalClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectEntitiesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DetectEntitiesResponse, _>(
+                    response,
+                    "DetectEntities",
+                )
             } else {
                 Box::new(
                     response
@@ -387,7 +474,16 @@ impl ComprehendMedical for ComprehendMedicalClient {
         &self,
         input: DetectPHIRequest,
     ) -> RusotoFuture<DetectPHIResponse, DetectPHIError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DetectPHI");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ComprehendMedical_20181030.DetectPHI");
@@ -396,10 +492,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectPHIResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DetectPHIResponse, _>(response, "DetectPHI")
             } else {
                 Box::new(
                     response
@@ -411,3 +504,91 @@ impl ComprehendMedical for ComprehendMedicalClient {
         })
     }
 }
+
+/// A mock of the ComprehendMedical API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockComprehendMedical {
+    detect_entities: Option<
+        Box<
+            dyn Fn(
+                    DetectEntitiesRequest,
+                ) -> ::std::result::Result<
+                    DetectEntitiesResponse,
+                    RusotoError<DetectEntitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detect_phi: Option<
+        Box<
+            dyn Fn(
+                    DetectPHIRequest,
+                )
+                    -> ::std::result::Result<DetectPHIResponse, RusotoError<DetectPHIError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockComprehendMedical {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockComprehendMedical {
+        Default::default()
+    }
+
+    /// Answers `detect_entities` with what `handler` returns for its input.
+    pub fn with_detect_entities<F>(mut self, handler: F) -> MockComprehendMedical
+    where
+        F: Fn(
+                DetectEntitiesRequest,
+            )
+                -> ::std::result::Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_entities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_phi` with what `handler` returns for its input.
+    pub fn with_detect_phi<F>(mut self, handler: F) -> MockComprehendMedical
+    where
+        F: Fn(
+                DetectPHIRequest,
+            ) -> ::std::result::Result<DetectPHIResponse, RusotoError<DetectPHIError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_phi = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ComprehendMedical for MockComprehendMedical {
+    fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        match self.detect_entities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehendMedical has no handler for detect_entities"),
+        }
+    }
+    fn detect_phi(
+        &self,
+        input: DetectPHIRequest,
+    ) -> RusotoFuture<DetectPHIResponse, DetectPHIError> {
+        match self.detect_phi {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockComprehendMedical has no handler for detect_phi"),
+        }
+    }
+}
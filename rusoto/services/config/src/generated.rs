@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>A collection of accounts and regions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -39,9 +40,27 @@ pub struct AccountAggregationSource {
     pub aws_regions: Option<Vec<String>>,
 }
 
+impl Validate for AccountAggregationSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_ids"),
+            self.account_ids.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_pattern(&item_path, &item, "\\d{12}");
+        }
+        if let Some(ref value) = self.aws_regions {
+            errors.check_length(&field_path(path, "aws_regions"), value.len(), Some(1), None);
+        }
+    }
+}
+
 /// <p>Indicates whether an AWS Config rule is compliant based on account ID, region, compliance, and rule name.</p> <p>A rule is compliant if all of the resources that the rule evaluated comply with it. It is noncompliant if any of these resources do not comply.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AggregateComplianceByConfigRule {
     /// <p>The 12-digit account ID of the source account.</p>
     #[serde(rename = "AccountId")]
@@ -63,7 +82,7 @@ pub struct AggregateComplianceByConfigRule {
 
 /// <p>Returns the number of compliant and noncompliant rules for one or more accounts and regions in an aggregator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AggregateComplianceCount {
     /// <p>The number of compliant and noncompliant AWS Config rules.</p>
     #[serde(rename = "ComplianceSummary")]
@@ -77,7 +96,7 @@ pub struct AggregateComplianceCount {
 
 /// <p>The details of an AWS Config evaluation for an account ID and region in an aggregator. Provides the AWS resource that was evaluated, the compliance of the resource, related time stamps, and supplementary information. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AggregateEvaluationResult {
     /// <p>The 12-digit account ID of the source account.</p>
     #[serde(rename = "AccountId")]
@@ -130,9 +149,31 @@ pub struct AggregateResourceIdentifier {
     pub source_region: String,
 }
 
+impl Validate for AggregateResourceIdentifier {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(768),
+        );
+        errors.check_pattern(
+            &field_path(path, "source_account_id"),
+            &self.source_account_id,
+            "\\d{12}",
+        );
+        errors.check_length(
+            &field_path(path, "source_region"),
+            self.source_region.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// <p>The current sync status between the source and the aggregator account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AggregatedSourceStatus {
     /// <p>The region authorized to collect aggregated data.</p>
     #[serde(rename = "AwsRegion")]
@@ -166,7 +207,7 @@ pub struct AggregatedSourceStatus {
 
 /// <p>An object that represents the authorizations granted to aggregator accounts and regions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AggregationAuthorization {
     /// <p>The Amazon Resource Name (ARN) of the aggregation object.</p>
     #[serde(rename = "AggregationAuthorizationArn")]
@@ -188,7 +229,7 @@ pub struct AggregationAuthorization {
 
 /// <p>The detailed configuration of a specified resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BaseConfigurationItem {
     /// <p>The 12-digit AWS account ID associated with the resource.</p>
     #[serde(rename = "accountId")]
@@ -249,6 +290,7 @@ pub struct BaseConfigurationItem {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetAggregateResourceConfigRequest {
     /// <p>The name of the configuration aggregator.</p>
     #[serde(rename = "ConfigurationAggregatorName")]
@@ -258,8 +300,34 @@ pub struct BatchGetAggregateResourceConfigRequest {
     pub resource_identifiers: Vec<AggregateResourceIdentifier>,
 }
 
+impl Validate for BatchGetAggregateResourceConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_aggregator_name"),
+            self.configuration_aggregator_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_aggregator_name"),
+            &self.configuration_aggregator_name,
+            "[\\w\\-]+",
+        );
+        errors.check_length(
+            &field_path(path, "resource_identifiers"),
+            self.resource_identifiers.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.resource_identifiers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_identifiers"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetAggregateResourceConfigResponse {
     /// <p>A list that contains the current configuration of one or more resources.</p>
     #[serde(rename = "BaseConfigurationItems")]
@@ -272,14 +340,30 @@ pub struct BatchGetAggregateResourceConfigResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetResourceConfigRequest {
     /// <p>A list of resource keys to be processed with the current request. Each element in the list consists of the resource type and resource ID.</p>
     #[serde(rename = "resourceKeys")]
     pub resource_keys: Vec<ResourceKey>,
 }
 
+impl Validate for BatchGetResourceConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_keys"),
+            self.resource_keys.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.resource_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_keys"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetResourceConfigResponse {
     /// <p>A list that contains the current configuration of one or more resources.</p>
     #[serde(rename = "baseConfigurationItems")]
@@ -293,7 +377,7 @@ pub struct BatchGetResourceConfigResponse {
 
 /// <p>Indicates whether an AWS resource or AWS Config rule is compliant and provides the number of contributors that affect the compliance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Compliance {
     /// <p>The number of AWS resources or AWS Config rules that cause a result of <code>NON_COMPLIANT</code>, up to a maximum number.</p>
     #[serde(rename = "ComplianceContributorCount")]
@@ -307,7 +391,7 @@ pub struct Compliance {
 
 /// <p>Indicates whether an AWS Config rule is compliant. A rule is compliant if all of the resources that the rule evaluated comply with it. A rule is noncompliant if any of these resources do not comply.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComplianceByConfigRule {
     /// <p>Indicates whether the AWS Config rule is compliant.</p>
     #[serde(rename = "Compliance")]
@@ -321,7 +405,7 @@ pub struct ComplianceByConfigRule {
 
 /// <p>Indicates whether an AWS resource that is evaluated according to one or more AWS Config rules is compliant. A resource is compliant if it complies with all of the rules that evaluate it. A resource is noncompliant if it does not comply with one or more of these rules.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComplianceByResource {
     /// <p>Indicates whether the AWS resource complies with all of the AWS Config rules that evaluated it.</p>
     #[serde(rename = "Compliance")]
@@ -339,7 +423,7 @@ pub struct ComplianceByResource {
 
 /// <p>The number of AWS resources or AWS Config rules responsible for the current compliance of the item, up to a maximum number.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComplianceContributorCount {
     /// <p>Indicates whether the maximum count is reached.</p>
     #[serde(rename = "CapExceeded")]
@@ -353,7 +437,7 @@ pub struct ComplianceContributorCount {
 
 /// <p>The number of AWS Config rules or AWS resources that are compliant and noncompliant.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComplianceSummary {
     /// <p>The time that AWS Config created the compliance summary.</p>
     #[serde(rename = "ComplianceSummaryTimestamp")]
@@ -371,7 +455,7 @@ pub struct ComplianceSummary {
 
 /// <p>The number of AWS resources of a specific type that are compliant or noncompliant, up to a maximum of 100 for each.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComplianceSummaryByResourceType {
     /// <p>The number of AWS resources that are compliant or noncompliant, up to a maximum of 100 for each.</p>
     #[serde(rename = "ComplianceSummary")]
@@ -385,7 +469,7 @@ pub struct ComplianceSummaryByResourceType {
 
 /// <p>Provides status of the delivery of the snapshot or the configuration history to the specified Amazon S3 bucket. Also provides the status of notifications about the Amazon S3 delivery to the specified Amazon SNS topic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfigExportDeliveryInfo {
     /// <p>The time of the last attempted delivery.</p>
     #[serde(rename = "lastAttemptTime")]
@@ -457,8 +541,67 @@ pub struct ConfigRule {
     pub source: Source,
 }
 
+impl Validate for ConfigRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.config_rule_arn {
+            errors.check_length(
+                &field_path(path, "config_rule_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.config_rule_id {
+            errors.check_length(
+                &field_path(path, "config_rule_id"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.config_rule_name {
+            errors.check_length(
+                &field_path(path, "config_rule_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "config_rule_name"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.created_by {
+            errors.check_length(
+                &field_path(path, "created_by"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.input_parameters {
+            errors.check_length(
+                &field_path(path, "input_parameters"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.scope {
+            value.validate_at(&field_path(path, "scope"), errors);
+        }
+        self.source.validate_at(&field_path(path, "source"), errors);
+    }
+}
+
 /// <p>Filters the compliance results based on account ID, region, compliance type, and rule name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigRuleComplianceFilters {
     /// <p>The 12-digit account ID of the source account. </p>
     #[serde(rename = "AccountId")]
@@ -478,8 +621,34 @@ pub struct ConfigRuleComplianceFilters {
     pub config_rule_name: Option<String>,
 }
 
+impl Validate for ConfigRuleComplianceFilters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_id {
+            errors.check_pattern(&field_path(path, "account_id"), &value, "\\d{12}");
+        }
+        if let Some(ref value) = self.aws_region {
+            errors.check_length(
+                &field_path(path, "aws_region"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.config_rule_name {
+            errors.check_length(
+                &field_path(path, "config_rule_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "config_rule_name"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>Filters the results based on the account IDs and regions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigRuleComplianceSummaryFilters {
     /// <p>The 12-digit account ID of the source account.</p>
     #[serde(rename = "AccountId")]
@@ -491,9 +660,25 @@ pub struct ConfigRuleComplianceSummaryFilters {
     pub aws_region: Option<String>,
 }
 
+impl Validate for ConfigRuleComplianceSummaryFilters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_id {
+            errors.check_pattern(&field_path(path, "account_id"), &value, "\\d{12}");
+        }
+        if let Some(ref value) = self.aws_region {
+            errors.check_length(
+                &field_path(path, "aws_region"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+    }
+}
+
 /// <p>Status information for your AWS managed Config rules. The status includes information such as the last time the rule ran, the last time it failed, and the related error for the last failure.</p> <p>This action does not return status information about custom AWS Config rules.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfigRuleEvaluationStatus {
     /// <p>The Amazon Resource Name (ARN) of the AWS Config rule.</p>
     #[serde(rename = "ConfigRuleArn")]
@@ -552,7 +737,7 @@ pub struct ConfigSnapshotDeliveryProperties {
 
 /// <p>A list that contains the status of the delivery of the configuration stream notification to the Amazon SNS topic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfigStreamDeliveryInfo {
     /// <p>The error code from the last attempted delivery.</p>
     #[serde(rename = "lastErrorCode")]
@@ -574,7 +759,7 @@ pub struct ConfigStreamDeliveryInfo {
 
 /// <p>The details about the configuration aggregator, including information about source accounts, regions, and metadata of the aggregator. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfigurationAggregator {
     /// <p>Provides a list of source accounts and regions to be aggregated.</p>
     #[serde(rename = "AccountAggregationSources")]
@@ -604,7 +789,7 @@ pub struct ConfigurationAggregator {
 
 /// <p>A list that contains detailed configurations of a specified resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfigurationItem {
     /// <p>The 12-digit AWS account ID associated with the resource.</p>
     #[serde(rename = "accountId")]
@@ -697,9 +882,22 @@ pub struct ConfigurationRecorder {
     pub role_arn: Option<String>,
 }
 
+impl Validate for ConfigurationRecorder {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>The current status of the configuration recorder.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfigurationRecorderStatus {
     /// <p>The error code indicating that the recording failed.</p>
     #[serde(rename = "lastErrorCode")]
@@ -736,6 +934,7 @@ pub struct ConfigurationRecorderStatus {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAggregationAuthorizationRequest {
     /// <p>The 12-digit account ID of the account authorized to aggregate data.</p>
     #[serde(rename = "AuthorizedAccountId")]
@@ -745,58 +944,162 @@ pub struct DeleteAggregationAuthorizationRequest {
     pub authorized_aws_region: String,
 }
 
+impl Validate for DeleteAggregationAuthorizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "authorized_account_id"),
+            &self.authorized_account_id,
+            "\\d{12}",
+        );
+        errors.check_length(
+            &field_path(path, "authorized_aws_region"),
+            self.authorized_aws_region.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConfigRuleRequest {
     /// <p>The name of the AWS Config rule that you want to delete.</p>
     #[serde(rename = "ConfigRuleName")]
     pub config_rule_name: String,
 }
 
+impl Validate for DeleteConfigRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "config_rule_name"),
+            &self.config_rule_name,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConfigurationAggregatorRequest {
     /// <p>The name of the configuration aggregator.</p>
     #[serde(rename = "ConfigurationAggregatorName")]
     pub configuration_aggregator_name: String,
 }
 
+impl Validate for DeleteConfigurationAggregatorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_aggregator_name"),
+            self.configuration_aggregator_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_aggregator_name"),
+            &self.configuration_aggregator_name,
+            "[\\w\\-]+",
+        );
+    }
+}
+
 /// <p>The request object for the <code>DeleteConfigurationRecorder</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConfigurationRecorderRequest {
     /// <p>The name of the configuration recorder to be deleted. You can retrieve the name of your configuration recorder by using the <code>DescribeConfigurationRecorders</code> action.</p>
     #[serde(rename = "ConfigurationRecorderName")]
     pub configuration_recorder_name: String,
 }
 
+impl Validate for DeleteConfigurationRecorderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_recorder_name"),
+            self.configuration_recorder_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>The input for the <a>DeleteDeliveryChannel</a> action. The action accepts the following data, in JSON format. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDeliveryChannelRequest {
     /// <p>The name of the delivery channel to delete.</p>
     #[serde(rename = "DeliveryChannelName")]
     pub delivery_channel_name: String,
 }
 
+impl Validate for DeleteDeliveryChannelRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_channel_name"),
+            self.delivery_channel_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEvaluationResultsRequest {
     /// <p>The name of the AWS Config rule for which you want to delete the evaluation results.</p>
     #[serde(rename = "ConfigRuleName")]
     pub config_rule_name: String,
 }
 
+impl Validate for DeleteEvaluationResultsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// <p>The output when you delete the evaluation results for the specified AWS Config rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteEvaluationResultsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteOrganizationConfigRuleRequest {
     /// <p>The name of organization config rule that you want to delete.</p>
     #[serde(rename = "OrganizationConfigRuleName")]
     pub organization_config_rule_name: String,
 }
 
+impl Validate for DeleteOrganizationConfigRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organization_config_rule_name"),
+            self.organization_config_rule_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_config_rule_name"),
+            &self.organization_config_rule_name,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePendingAggregationRequestRequest {
     /// <p>The 12-digit account ID of the account requesting to aggregate data.</p>
     #[serde(rename = "RequesterAccountId")]
@@ -806,7 +1109,24 @@ pub struct DeletePendingAggregationRequestRequest {
     pub requester_aws_region: String,
 }
 
+impl Validate for DeletePendingAggregationRequestRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "requester_account_id"),
+            &self.requester_account_id,
+            "\\d{12}",
+        );
+        errors.check_length(
+            &field_path(path, "requester_aws_region"),
+            self.requester_aws_region.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRemediationConfigurationRequest {
     /// <p>The name of the AWS Config rule for which you want to delete remediation configuration.</p>
     #[serde(rename = "ConfigRuleName")]
@@ -817,11 +1137,28 @@ pub struct DeleteRemediationConfigurationRequest {
     pub resource_type: Option<String>,
 }
 
+impl Validate for DeleteRemediationConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "config_rule_name"),
+            &self.config_rule_name,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRemediationConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRemediationExceptionsRequest {
     /// <p>The name of the AWS Config rule for which you want to delete remediation exception configuration.</p>
     #[serde(rename = "ConfigRuleName")]
@@ -831,8 +1168,34 @@ pub struct DeleteRemediationExceptionsRequest {
     pub resource_keys: Vec<RemediationExceptionResourceKey>,
 }
 
+impl Validate for DeleteRemediationExceptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "config_rule_name"),
+            &self.config_rule_name,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "resource_keys"),
+            self.resource_keys.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.resource_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_keys"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRemediationExceptionsResponse {
     /// <p>Returns a list of failed delete remediation exceptions batch objects. Each object in the batch consists of a list of failed items and failure messages.</p>
     #[serde(rename = "FailedBatches")]
@@ -841,23 +1204,52 @@ pub struct DeleteRemediationExceptionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRetentionConfigurationRequest {
     /// <p>The name of the retention configuration to delete.</p>
     #[serde(rename = "RetentionConfigurationName")]
     pub retention_configuration_name: String,
 }
 
+impl Validate for DeleteRetentionConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "retention_configuration_name"),
+            self.retention_configuration_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "retention_configuration_name"),
+            &self.retention_configuration_name,
+            "[\\w\\-]+",
+        );
+    }
+}
+
 /// <p>The input for the <a>DeliverConfigSnapshot</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeliverConfigSnapshotRequest {
     /// <p>The name of the delivery channel through which the snapshot is delivered.</p>
     #[serde(rename = "deliveryChannelName")]
     pub delivery_channel_name: String,
 }
 
+impl Validate for DeliverConfigSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_channel_name"),
+            self.delivery_channel_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>The output for the <a>DeliverConfigSnapshot</a> action, in JSON format.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeliverConfigSnapshotResponse {
     /// <p>The ID of the snapshot that is being created.</p>
     #[serde(rename = "configSnapshotId")]
@@ -890,9 +1282,22 @@ pub struct DeliveryChannel {
     pub sns_topic_arn: Option<String>,
 }
 
+impl Validate for DeliveryChannel {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>The status of a specified delivery channel.</p> <p>Valid values: <code>Success</code> | <code>Failure</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeliveryChannelStatus {
     /// <p>A list that contains the status of the delivery of the configuration history to the specified Amazon S3 bucket.</p>
     #[serde(rename = "configHistoryDeliveryInfo")]
@@ -913,6 +1318,7 @@ pub struct DeliveryChannelStatus {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAggregateComplianceByConfigRulesRequest {
     /// <p>The name of the configuration aggregator.</p>
     #[serde(rename = "ConfigurationAggregatorName")]
@@ -931,8 +1337,30 @@ pub struct DescribeAggregateComplianceByConfigRulesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAggregateComplianceByConfigRulesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_aggregator_name"),
+            self.configuration_aggregator_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_aggregator_name"),
+            &self.configuration_aggregator_name,
+            "[\\w\\-]+",
+        );
+        if let Some(ref value) = self.filters {
+            value.validate_at(&field_path(path, "filters"), errors);
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(1000));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAggregateComplianceByConfigRulesResponse {
     /// <p>Returns a list of AggregateComplianceByConfigRule object.</p>
     #[serde(rename = "AggregateComplianceByConfigRules")]
@@ -945,6 +1373,7 @@ pub struct DescribeAggregateComplianceByConfigRulesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAggregationAuthorizationsRequest {
     /// <p>The maximum number of AggregationAuthorizations returned on each page. The default is maximum. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "Limit")]
@@ -956,8 +1385,16 @@ pub struct DescribeAggregationAuthorizationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAggregationAuthorizationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAggregationAuthorizationsResponse {
     /// <p>Returns a list of authorizations granted to various aggregator accounts and regions.</p>
     #[serde(rename = "AggregationAuthorizations")]
@@ -971,6 +1408,7 @@ pub struct DescribeAggregationAuthorizationsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeComplianceByConfigRuleRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>.</p>
     #[serde(rename = "ComplianceTypes")]
@@ -986,9 +1424,35 @@ pub struct DescribeComplianceByConfigRuleRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeComplianceByConfigRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.compliance_types {
+            errors.check_length(
+                &field_path(path, "compliance_types"),
+                value.len(),
+                None,
+                Some(3),
+            );
+        }
+        if let Some(ref value) = self.config_rule_names {
+            errors.check_length(
+                &field_path(path, "config_rule_names"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "config_rule_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeComplianceByConfigRuleResponse {
     /// <p>Indicates whether each of the specified AWS Config rules is compliant.</p>
     #[serde(rename = "ComplianceByConfigRules")]
@@ -1002,6 +1466,7 @@ pub struct DescribeComplianceByConfigRuleResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeComplianceByResourceRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>INSUFFICIENT_DATA</code>.</p>
     #[serde(rename = "ComplianceTypes")]
@@ -1025,9 +1490,41 @@ pub struct DescribeComplianceByResourceRequest {
     pub resource_type: Option<String>,
 }
 
+impl Validate for DescribeComplianceByResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.compliance_types {
+            errors.check_length(
+                &field_path(path, "compliance_types"),
+                value.len(),
+                None,
+                Some(3),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.resource_id {
+            errors.check_length(
+                &field_path(path, "resource_id"),
+                value.chars().count(),
+                Some(1),
+                Some(768),
+            );
+        }
+        if let Some(ref value) = self.resource_type {
+            errors.check_length(
+                &field_path(path, "resource_type"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeComplianceByResourceResponse {
     /// <p>Indicates whether the specified AWS resource complies with all of the AWS Config rules that evaluate it.</p>
     #[serde(rename = "ComplianceByResources")]
@@ -1041,6 +1538,7 @@ pub struct DescribeComplianceByResourceResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigRuleEvaluationStatusRequest {
     /// <p>The name of the AWS managed Config rules for which you want status information. If you do not specify any names, AWS Config returns status information for all AWS managed Config rules that you use.</p>
     #[serde(rename = "ConfigRuleNames")]
@@ -1056,9 +1554,30 @@ pub struct DescribeConfigRuleEvaluationStatusRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeConfigRuleEvaluationStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.config_rule_names {
+            errors.check_length(
+                &field_path(path, "config_rule_names"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "config_rule_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(50));
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigRuleEvaluationStatusResponse {
     /// <p>Status information about your AWS managed Config rules.</p>
     #[serde(rename = "ConfigRulesEvaluationStatus")]
@@ -1072,6 +1591,7 @@ pub struct DescribeConfigRuleEvaluationStatusResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigRulesRequest {
     /// <p>The names of the AWS Config rules for which you want details. If you do not specify any names, AWS Config returns details for all your rules.</p>
     #[serde(rename = "ConfigRuleNames")]
@@ -1083,9 +1603,27 @@ pub struct DescribeConfigRulesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeConfigRulesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.config_rule_names {
+            errors.check_length(
+                &field_path(path, "config_rule_names"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "config_rule_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigRulesResponse {
     /// <p>The details about your AWS Config rules.</p>
     #[serde(rename = "ConfigRules")]
@@ -1098,6 +1636,7 @@ pub struct DescribeConfigRulesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationAggregatorSourcesStatusRequest {
     /// <p>The name of the configuration aggregator.</p>
     #[serde(rename = "ConfigurationAggregatorName")]
@@ -1116,8 +1655,35 @@ pub struct DescribeConfigurationAggregatorSourcesStatusRequest {
     pub update_status: Option<Vec<String>>,
 }
 
+impl Validate for DescribeConfigurationAggregatorSourcesStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_aggregator_name"),
+            self.configuration_aggregator_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_aggregator_name"),
+            &self.configuration_aggregator_name,
+            "[\\w\\-]+",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.update_status {
+            errors.check_length(
+                &field_path(path, "update_status"),
+                value.len(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigurationAggregatorSourcesStatusResponse {
     /// <p>Returns an AggregatedSourceStatus object. </p>
     #[serde(rename = "AggregatedSourceStatusList")]
@@ -1130,6 +1696,7 @@ pub struct DescribeConfigurationAggregatorSourcesStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationAggregatorsRequest {
     /// <p>The name of the configuration aggregators.</p>
     #[serde(rename = "ConfigurationAggregatorNames")]
@@ -1145,8 +1712,33 @@ pub struct DescribeConfigurationAggregatorsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeConfigurationAggregatorsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.configuration_aggregator_names {
+            errors.check_length(
+                &field_path(path, "configuration_aggregator_names"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "configuration_aggregator_names"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(&item_path, &item, "[\\w\\-]+");
+            }
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigurationAggregatorsResponse {
     /// <p>Returns a ConfigurationAggregators object.</p>
     #[serde(rename = "ConfigurationAggregators")]
@@ -1160,6 +1752,7 @@ pub struct DescribeConfigurationAggregatorsResponse {
 
 /// <p>The input for the <a>DescribeConfigurationRecorderStatus</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationRecorderStatusRequest {
     /// <p>The name(s) of the configuration recorder. If the name is not specified, the action returns the current status of all the configuration recorders associated with the account.</p>
     #[serde(rename = "ConfigurationRecorderNames")]
@@ -1167,9 +1760,24 @@ pub struct DescribeConfigurationRecorderStatusRequest {
     pub configuration_recorder_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeConfigurationRecorderStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.configuration_recorder_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "configuration_recorder_names"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 /// <p>The output for the <a>DescribeConfigurationRecorderStatus</a> action, in JSON format.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigurationRecorderStatusResponse {
     /// <p>A list that contains status of the specified recorders.</p>
     #[serde(rename = "ConfigurationRecordersStatus")]
@@ -1179,6 +1787,7 @@ pub struct DescribeConfigurationRecorderStatusResponse {
 
 /// <p>The input for the <a>DescribeConfigurationRecorders</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationRecordersRequest {
     /// <p>A list of configuration recorder names.</p>
     #[serde(rename = "ConfigurationRecorderNames")]
@@ -1186,9 +1795,24 @@ pub struct DescribeConfigurationRecordersRequest {
     pub configuration_recorder_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeConfigurationRecordersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.configuration_recorder_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "configuration_recorder_names"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 /// <p>The output for the <a>DescribeConfigurationRecorders</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigurationRecordersResponse {
     /// <p>A list that contains the descriptions of the specified configuration recorders.</p>
     #[serde(rename = "ConfigurationRecorders")]
@@ -1198,6 +1822,7 @@ pub struct DescribeConfigurationRecordersResponse {
 
 /// <p>The input for the <a>DeliveryChannelStatus</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDeliveryChannelStatusRequest {
     /// <p>A list of delivery channel names.</p>
     #[serde(rename = "DeliveryChannelNames")]
@@ -1205,9 +1830,20 @@ pub struct DescribeDeliveryChannelStatusRequest {
     pub delivery_channel_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeDeliveryChannelStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.delivery_channel_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "delivery_channel_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 /// <p>The output for the <a>DescribeDeliveryChannelStatus</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDeliveryChannelStatusResponse {
     /// <p>A list that contains the status of a specified delivery channel.</p>
     #[serde(rename = "DeliveryChannelsStatus")]
@@ -1217,6 +1853,7 @@ pub struct DescribeDeliveryChannelStatusResponse {
 
 /// <p>The input for the <a>DescribeDeliveryChannels</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDeliveryChannelsRequest {
     /// <p>A list of delivery channel names.</p>
     #[serde(rename = "DeliveryChannelNames")]
@@ -1224,9 +1861,20 @@ pub struct DescribeDeliveryChannelsRequest {
     pub delivery_channel_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeDeliveryChannelsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.delivery_channel_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "delivery_channel_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 /// <p>The output for the <a>DescribeDeliveryChannels</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDeliveryChannelsResponse {
     /// <p>A list that contains the descriptions of the specified delivery channel.</p>
     #[serde(rename = "DeliveryChannels")]
@@ -1235,6 +1883,7 @@ pub struct DescribeDeliveryChannelsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeOrganizationConfigRuleStatusesRequest {
     /// <p>The maximum number of <code>OrganizationConfigRuleStatuses</code> returned on each page. If you do no specify a number, AWS Config uses the default. The default is 100.</p>
     #[serde(rename = "Limit")]
@@ -1250,8 +1899,32 @@ pub struct DescribeOrganizationConfigRuleStatusesRequest {
     pub organization_config_rule_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeOrganizationConfigRuleStatusesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.organization_config_rule_names {
+            errors.check_length(
+                &field_path(path, "organization_config_rule_names"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "organization_config_rule_names"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeOrganizationConfigRuleStatusesResponse {
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response. </p>
     #[serde(rename = "NextToken")]
@@ -1264,6 +1937,7 @@ pub struct DescribeOrganizationConfigRuleStatusesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeOrganizationConfigRulesRequest {
     /// <p>The maximum number of organization config rules returned on each page. If you do no specify a number, AWS Config uses the default. The default is 100.</p>
     #[serde(rename = "Limit")]
@@ -1279,8 +1953,32 @@ pub struct DescribeOrganizationConfigRulesRequest {
     pub organization_config_rule_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeOrganizationConfigRulesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.organization_config_rule_names {
+            errors.check_length(
+                &field_path(path, "organization_config_rule_names"),
+                value.len(),
+                None,
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "organization_config_rule_names"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeOrganizationConfigRulesResponse {
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response. </p>
     #[serde(rename = "NextToken")]
@@ -1293,6 +1991,7 @@ pub struct DescribeOrganizationConfigRulesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePendingAggregationRequestsRequest {
     /// <p>The maximum number of evaluation results returned on each page. The default is maximum. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "Limit")]
@@ -1304,8 +2003,16 @@ pub struct DescribePendingAggregationRequestsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribePendingAggregationRequestsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(20));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePendingAggregationRequestsResponse {
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response.</p>
     #[serde(rename = "NextToken")]
@@ -1318,14 +2025,31 @@ pub struct DescribePendingAggregationRequestsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRemediationConfigurationsRequest {
     /// <p>A list of AWS Config rule names of remediation configurations for which you want details. </p>
     #[serde(rename = "ConfigRuleNames")]
     pub config_rule_names: Vec<String>,
 }
 
+impl Validate for DescribeRemediationConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "config_rule_names"),
+            self.config_rule_names.len(),
+            None,
+            Some(25),
+        );
+        for (i, item) in self.config_rule_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "config_rule_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRemediationConfigurationsResponse {
     /// <p>Returns a remediation configuration object.</p>
     #[serde(rename = "RemediationConfigurations")]
@@ -1334,6 +2058,7 @@ pub struct DescribeRemediationConfigurationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRemediationExceptionsRequest {
     /// <p>The name of the AWS Config rule.</p>
     #[serde(rename = "ConfigRuleName")]
@@ -1352,8 +2077,39 @@ pub struct DescribeRemediationExceptionsRequest {
     pub resource_keys: Option<Vec<RemediationExceptionResourceKey>>,
 }
 
+impl Validate for DescribeRemediationExceptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "config_rule_name"),
+            &self.config_rule_name,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.resource_keys {
+            errors.check_length(
+                &field_path(path, "resource_keys"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_keys"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRemediationExceptionsResponse {
     /// <p>The <code>nextToken</code> string returned in a previous request that you use to request the next page of results in a paginated response.</p>
     #[serde(rename = "NextToken")]
@@ -1366,6 +2122,7 @@ pub struct DescribeRemediationExceptionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRemediationExecutionStatusRequest {
     /// <p>A list of AWS Config rule names.</p>
     #[serde(rename = "ConfigRuleName")]
@@ -1384,8 +2141,39 @@ pub struct DescribeRemediationExecutionStatusRequest {
     pub resource_keys: Option<Vec<ResourceKey>>,
 }
 
+impl Validate for DescribeRemediationExecutionStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "config_rule_name"),
+            &self.config_rule_name,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.resource_keys {
+            errors.check_length(
+                &field_path(path, "resource_keys"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_keys"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRemediationExecutionStatusResponse {
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response.</p>
     #[serde(rename = "NextToken")]
@@ -1398,6 +2186,7 @@ pub struct DescribeRemediationExecutionStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRetentionConfigurationsRequest {
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response. </p>
     #[serde(rename = "NextToken")]
@@ -1409,8 +2198,30 @@ pub struct DescribeRetentionConfigurationsRequest {
     pub retention_configuration_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeRetentionConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.retention_configuration_names {
+            errors.check_length(
+                &field_path(path, "retention_configuration_names"),
+                value.len(),
+                None,
+                Some(1),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "retention_configuration_names"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(&item_path, &item, "[\\w\\-]+");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRetentionConfigurationsResponse {
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response. </p>
     #[serde(rename = "NextToken")]
@@ -1443,9 +2254,34 @@ pub struct Evaluation {
     pub ordering_timestamp: f64,
 }
 
+impl Validate for Evaluation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.annotation {
+            errors.check_length(
+                &field_path(path, "annotation"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "compliance_resource_id"),
+            self.compliance_resource_id.chars().count(),
+            Some(1),
+            Some(768),
+        );
+        errors.check_length(
+            &field_path(path, "compliance_resource_type"),
+            self.compliance_resource_type.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>The details of an AWS Config evaluation. Provides the AWS resource that was evaluated, the compliance of the resource, related time stamps, and supplementary information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EvaluationResult {
     /// <p>Supplementary information about how the evaluation determined the compliance.</p>
     #[serde(rename = "Annotation")]
@@ -1475,7 +2311,7 @@ pub struct EvaluationResult {
 
 /// <p>Uniquely identifies an evaluation result.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EvaluationResultIdentifier {
     /// <p>Identifies an AWS Config rule used to evaluate an AWS resource, and provides the type and ID of the evaluated resource.</p>
     #[serde(rename = "EvaluationResultQualifier")]
@@ -1489,7 +2325,7 @@ pub struct EvaluationResultIdentifier {
 
 /// <p>Identifies an AWS Config rule that evaluated an AWS resource, and provides the type and ID of the resource that the rule evaluated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EvaluationResultQualifier {
     /// <p>The name of the AWS Config rule that was used in the evaluation.</p>
     #[serde(rename = "ConfigRuleName")]
@@ -1514,9 +2350,17 @@ pub struct ExecutionControls {
     pub ssm_controls: Option<SsmControls>,
 }
 
+impl Validate for ExecutionControls {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ssm_controls {
+            value.validate_at(&field_path(path, "ssm_controls"), errors);
+        }
+    }
+}
+
 /// <p>List of each of the failed delete remediation exceptions with specific reasons.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailedDeleteRemediationExceptionsBatch {
     /// <p>Returns remediation exception resource key object of the failed items.</p>
     #[serde(rename = "FailedItems")]
@@ -1530,7 +2374,7 @@ pub struct FailedDeleteRemediationExceptionsBatch {
 
 /// <p>List of each of the failed remediations with specific reasons.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailedRemediationBatch {
     /// <p>Returns remediation configurations of the failed items.</p>
     #[serde(rename = "FailedItems")]
@@ -1544,7 +2388,7 @@ pub struct FailedRemediationBatch {
 
 /// <p>List of each of the failed remediation exceptions with specific reasons.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailedRemediationExceptionBatch {
     /// <p>Returns remediation exception resource key object of the failed items.</p>
     #[serde(rename = "FailedItems")]
@@ -1558,7 +2402,7 @@ pub struct FailedRemediationExceptionBatch {
 
 /// <p>Details about the fields such as name of the field.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FieldInfo {
     /// <p>Name of the field.</p>
     #[serde(rename = "Name")]
@@ -1567,6 +2411,7 @@ pub struct FieldInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAggregateComplianceDetailsByConfigRuleRequest {
     /// <p>The 12-digit account ID of the source account.</p>
     #[serde(rename = "AccountId")]
@@ -1594,8 +2439,45 @@ pub struct GetAggregateComplianceDetailsByConfigRuleRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetAggregateComplianceDetailsByConfigRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "account_id"), &self.account_id, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "aws_region"),
+            self.aws_region.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "config_rule_name"),
+            &self.config_rule_name,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "configuration_aggregator_name"),
+            self.configuration_aggregator_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_aggregator_name"),
+            &self.configuration_aggregator_name,
+            "[\\w\\-]+",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAggregateComplianceDetailsByConfigRuleResponse {
     /// <p>Returns an AggregateEvaluationResults object.</p>
     #[serde(rename = "AggregateEvaluationResults")]
@@ -1608,6 +2490,7 @@ pub struct GetAggregateComplianceDetailsByConfigRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAggregateConfigRuleComplianceSummaryRequest {
     /// <p>The name of the configuration aggregator.</p>
     #[serde(rename = "ConfigurationAggregatorName")]
@@ -1630,8 +2513,30 @@ pub struct GetAggregateConfigRuleComplianceSummaryRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetAggregateConfigRuleComplianceSummaryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_aggregator_name"),
+            self.configuration_aggregator_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_aggregator_name"),
+            &self.configuration_aggregator_name,
+            "[\\w\\-]+",
+        );
+        if let Some(ref value) = self.filters {
+            value.validate_at(&field_path(path, "filters"), errors);
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(1000));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAggregateConfigRuleComplianceSummaryResponse {
     /// <p>Returns a list of AggregateComplianceCounts object.</p>
     #[serde(rename = "AggregateComplianceCounts")]
@@ -1648,6 +2553,7 @@ pub struct GetAggregateConfigRuleComplianceSummaryResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAggregateDiscoveredResourceCountsRequest {
     /// <p>The name of the configuration aggregator.</p>
     #[serde(rename = "ConfigurationAggregatorName")]
@@ -1670,8 +2576,30 @@ pub struct GetAggregateDiscoveredResourceCountsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetAggregateDiscoveredResourceCountsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_aggregator_name"),
+            self.configuration_aggregator_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_aggregator_name"),
+            &self.configuration_aggregator_name,
+            "[\\w\\-]+",
+        );
+        if let Some(ref value) = self.filters {
+            value.validate_at(&field_path(path, "filters"), errors);
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(1000));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAggregateDiscoveredResourceCountsResponse {
     /// <p>The key passed into the request object. If <code>GroupByKey</code> is not provided, the result will be empty.</p>
     #[serde(rename = "GroupByKey")]
@@ -1691,6 +2619,7 @@ pub struct GetAggregateDiscoveredResourceCountsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAggregateResourceConfigRequest {
     /// <p>The name of the configuration aggregator.</p>
     #[serde(rename = "ConfigurationAggregatorName")]
@@ -1700,9 +2629,27 @@ pub struct GetAggregateResourceConfigRequest {
     pub resource_identifier: AggregateResourceIdentifier,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetAggregateResourceConfigResponse {
+impl Validate for GetAggregateResourceConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_aggregator_name"),
+            self.configuration_aggregator_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_aggregator_name"),
+            &self.configuration_aggregator_name,
+            "[\\w\\-]+",
+        );
+        self.resource_identifier
+            .validate_at(&field_path(path, "resource_identifier"), errors);
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GetAggregateResourceConfigResponse {
     /// <p>Returns a <code>ConfigurationItem</code> object.</p>
     #[serde(rename = "ConfigurationItem")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1711,6 +2658,7 @@ pub struct GetAggregateResourceConfigResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetComplianceDetailsByConfigRuleRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>NOT_APPLICABLE</code>.</p>
     #[serde(rename = "ComplianceTypes")]
@@ -1729,9 +2677,31 @@ pub struct GetComplianceDetailsByConfigRuleRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetComplianceDetailsByConfigRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.compliance_types {
+            errors.check_length(
+                &field_path(path, "compliance_types"),
+                value.len(),
+                None,
+                Some(3),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetComplianceDetailsByConfigRuleResponse {
     /// <p>Indicates whether the AWS resource complies with the specified AWS Config rule.</p>
     #[serde(rename = "EvaluationResults")]
@@ -1745,6 +2715,7 @@ pub struct GetComplianceDetailsByConfigRuleResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetComplianceDetailsByResourceRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>NOT_APPLICABLE</code>.</p>
     #[serde(rename = "ComplianceTypes")]
@@ -1762,9 +2733,34 @@ pub struct GetComplianceDetailsByResourceRequest {
     pub resource_type: String,
 }
 
+impl Validate for GetComplianceDetailsByResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.compliance_types {
+            errors.check_length(
+                &field_path(path, "compliance_types"),
+                value.len(),
+                None,
+                Some(3),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(768),
+        );
+        errors.check_length(
+            &field_path(path, "resource_type"),
+            self.resource_type.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetComplianceDetailsByResourceResponse {
     /// <p>Indicates whether the specified AWS resource complies each AWS Config rule.</p>
     #[serde(rename = "EvaluationResults")]
@@ -1778,7 +2774,7 @@ pub struct GetComplianceDetailsByResourceResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetComplianceSummaryByConfigRuleResponse {
     /// <p>The number of AWS Config rules that are compliant and the number that are noncompliant, up to a maximum of 25 for each.</p>
     #[serde(rename = "ComplianceSummary")]
@@ -1788,6 +2784,7 @@ pub struct GetComplianceSummaryByConfigRuleResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetComplianceSummaryByResourceTypeRequest {
     /// <p>Specify one or more resource types to get the number of resources that are compliant and the number that are noncompliant for each resource type.</p> <p>For this request, you can specify an AWS resource type such as <code>AWS::EC2::Instance</code>. You can specify that the resource type is an AWS account by specifying <code>AWS::::Account</code>.</p>
     #[serde(rename = "ResourceTypes")]
@@ -1795,9 +2792,26 @@ pub struct GetComplianceSummaryByResourceTypeRequest {
     pub resource_types: Option<Vec<String>>,
 }
 
+impl Validate for GetComplianceSummaryByResourceTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_types {
+            errors.check_length(
+                &field_path(path, "resource_types"),
+                value.len(),
+                None,
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_types"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetComplianceSummaryByResourceTypeResponse {
     /// <p>The number of resources that are compliant and the number that are noncompliant. If one or more resource types were provided with the request, the numbers are returned for each resource type. The maximum number returned is 100.</p>
     #[serde(rename = "ComplianceSummariesByResourceType")]
@@ -1806,6 +2820,7 @@ pub struct GetComplianceSummaryByResourceTypeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDiscoveredResourceCountsRequest {
     /// <p>The maximum number of <a>ResourceCount</a> objects returned on each page. The default is 100. You cannot specify a number greater than 100. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "limit")]
@@ -1821,8 +2836,28 @@ pub struct GetDiscoveredResourceCountsRequest {
     pub resource_types: Option<Vec<String>>,
 }
 
+impl Validate for GetDiscoveredResourceCountsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.resource_types {
+            errors.check_length(
+                &field_path(path, "resource_types"),
+                value.len(),
+                None,
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_types"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDiscoveredResourceCountsResponse {
     /// <p>The string that you use in a subsequent request to get the next page of results in a paginated response.</p>
     #[serde(rename = "nextToken")]
@@ -1839,6 +2874,7 @@ pub struct GetDiscoveredResourceCountsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOrganizationConfigRuleDetailedStatusRequest {
     /// <p>A <code>StatusDetailFilters</code> object.</p>
     #[serde(rename = "Filters")]
@@ -1857,8 +2893,30 @@ pub struct GetOrganizationConfigRuleDetailedStatusRequest {
     pub organization_config_rule_name: String,
 }
 
+impl Validate for GetOrganizationConfigRuleDetailedStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            value.validate_at(&field_path(path, "filters"), errors);
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "organization_config_rule_name"),
+            self.organization_config_rule_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_config_rule_name"),
+            &self.organization_config_rule_name,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOrganizationConfigRuleDetailedStatusResponse {
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response. </p>
     #[serde(rename = "NextToken")]
@@ -1872,6 +2930,7 @@ pub struct GetOrganizationConfigRuleDetailedStatusResponse {
 
 /// <p>The input for the <a>GetResourceConfigHistory</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourceConfigHistoryRequest {
     /// <p>The chronological order for configuration items listed. By default, the results are listed in reverse chronological order.</p>
     #[serde(rename = "chronologicalOrder")]
@@ -1901,9 +2960,23 @@ pub struct GetResourceConfigHistoryRequest {
     pub resource_type: String,
 }
 
+impl Validate for GetResourceConfigHistoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(768),
+        );
+    }
+}
+
 /// <p>The output for the <a>GetResourceConfigHistory</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourceConfigHistoryResponse {
     /// <p>A list that contains the configuration history of one or more resources.</p>
     #[serde(rename = "configurationItems")]
@@ -1917,7 +2990,7 @@ pub struct GetResourceConfigHistoryResponse {
 
 /// <p>The count of resources that are grouped by the group name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GroupedResourceCount {
     /// <p>The name of the group that can be region, account ID, or resource type. For example, region1, region2 if the region was chosen as <code>GroupByKey</code>.</p>
     #[serde(rename = "GroupName")]
@@ -1928,6 +3001,7 @@ pub struct GroupedResourceCount {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAggregateDiscoveredResourcesRequest {
     /// <p>The name of the configuration aggregator. </p>
     #[serde(rename = "ConfigurationAggregatorName")]
@@ -1949,8 +3023,30 @@ pub struct ListAggregateDiscoveredResourcesRequest {
     pub resource_type: String,
 }
 
+impl Validate for ListAggregateDiscoveredResourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_aggregator_name"),
+            self.configuration_aggregator_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_aggregator_name"),
+            &self.configuration_aggregator_name,
+            "[\\w\\-]+",
+        );
+        if let Some(ref value) = self.filters {
+            value.validate_at(&field_path(path, "filters"), errors);
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAggregateDiscoveredResourcesResponse {
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response.</p>
     #[serde(rename = "NextToken")]
@@ -1964,6 +3060,7 @@ pub struct ListAggregateDiscoveredResourcesResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDiscoveredResourcesRequest {
     /// <p>Specifies whether AWS Config includes deleted resources in the results. By default, deleted resources are not included.</p>
     #[serde(rename = "includeDeletedResources")]
@@ -1990,9 +3087,23 @@ pub struct ListDiscoveredResourcesRequest {
     pub resource_type: String,
 }
 
+impl Validate for ListDiscoveredResourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.resource_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(768));
+            }
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDiscoveredResourcesResponse {
     /// <p>The string that you use in a subsequent request to get the next page of results in a paginated response.</p>
     #[serde(rename = "nextToken")]
@@ -2005,6 +3116,7 @@ pub struct ListDiscoveredResourcesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The maximum number of tags returned on each page. The limit maximum is 50. You cannot specify a number greater than 50. If you specify 0, AWS Config uses the default. </p>
     #[serde(rename = "Limit")]
@@ -2019,8 +3131,22 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response. </p>
     #[serde(rename = "NextToken")]
@@ -2034,7 +3160,7 @@ pub struct ListTagsForResourceResponse {
 
 /// <p>Organization config rule creation or deletion status in each member account. This includes the name of the rule, the status, error code and error message when the rule creation or deletion failed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MemberAccountStatus {
     /// <p>The 12-digit account ID of a member account.</p>
     #[serde(rename = "AccountId")]
@@ -2075,9 +3201,17 @@ pub struct OrganizationAggregationSource {
     pub role_arn: String,
 }
 
+impl Validate for OrganizationAggregationSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aws_regions {
+            errors.check_length(&field_path(path, "aws_regions"), value.len(), Some(1), None);
+        }
+    }
+}
+
 /// <p>An organization config rule that has information about config rules that AWS Config creates in member accounts.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OrganizationConfigRule {
     /// <p>A comma-separated list of accounts excluded from organization config rule.</p>
     #[serde(rename = "ExcludedAccounts")]
@@ -2105,7 +3239,7 @@ pub struct OrganizationConfigRule {
 
 /// <p>Returns the status for an organization config rule in an organization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OrganizationConfigRuleStatus {
     /// <p>An error code that is returned when organization config rule creation or deletion has failed.</p>
     #[serde(rename = "ErrorCode")]
@@ -2166,6 +3300,69 @@ pub struct OrganizationCustomRuleMetadata {
     pub tag_value_scope: Option<String>,
 }
 
+impl Validate for OrganizationCustomRuleMetadata {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.input_parameters {
+            errors.check_length(
+                &field_path(path, "input_parameters"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "lambda_function_arn"),
+            self.lambda_function_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.resource_id_scope {
+            errors.check_length(
+                &field_path(path, "resource_id_scope"),
+                value.chars().count(),
+                Some(1),
+                Some(768),
+            );
+        }
+        if let Some(ref value) = self.resource_types_scope {
+            errors.check_length(
+                &field_path(path, "resource_types_scope"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_types_scope"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+        if let Some(ref value) = self.tag_key_scope {
+            errors.check_length(
+                &field_path(path, "tag_key_scope"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.tag_value_scope {
+            errors.check_length(
+                &field_path(path, "tag_value_scope"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>An object that specifies organization managed rule metadata such as resource type and ID of AWS resource along with the rule identifier. It also provides the frequency with which you want AWS Config to run evaluations for the rule if the trigger type is periodic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrganizationManagedRuleMetadata {
@@ -2202,9 +3399,72 @@ pub struct OrganizationManagedRuleMetadata {
     pub tag_value_scope: Option<String>,
 }
 
+impl Validate for OrganizationManagedRuleMetadata {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.input_parameters {
+            errors.check_length(
+                &field_path(path, "input_parameters"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.resource_id_scope {
+            errors.check_length(
+                &field_path(path, "resource_id_scope"),
+                value.chars().count(),
+                Some(1),
+                Some(768),
+            );
+        }
+        if let Some(ref value) = self.resource_types_scope {
+            errors.check_length(
+                &field_path(path, "resource_types_scope"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_types_scope"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "rule_identifier"),
+            self.rule_identifier.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.tag_key_scope {
+            errors.check_length(
+                &field_path(path, "tag_key_scope"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.tag_value_scope {
+            errors.check_length(
+                &field_path(path, "tag_value_scope"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>An object that represents the account ID and region of an aggregator account that is requesting authorization but is not yet authorized.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PendingAggregationRequest {
     /// <p>The 12-digit account ID of the account requesting to aggregate data.</p>
     #[serde(rename = "RequesterAccountId")]
@@ -2217,6 +3477,7 @@ pub struct PendingAggregationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutAggregationAuthorizationRequest {
     /// <p>The 12-digit account ID of the account authorized to aggregate data.</p>
     #[serde(rename = "AuthorizedAccountId")]
@@ -2230,8 +3491,31 @@ pub struct PutAggregationAuthorizationRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for PutAggregationAuthorizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "authorized_account_id"),
+            &self.authorized_account_id,
+            "\\d{12}",
+        );
+        errors.check_length(
+            &field_path(path, "authorized_aws_region"),
+            self.authorized_aws_region.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutAggregationAuthorizationResponse {
     /// <p>Returns an AggregationAuthorization object. </p>
     #[serde(rename = "AggregationAuthorization")]
@@ -2240,6 +3524,7 @@ pub struct PutAggregationAuthorizationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutConfigRuleRequest {
     /// <p>The rule that you want to add to your account.</p>
     #[serde(rename = "ConfigRule")]
@@ -2250,7 +3535,22 @@ pub struct PutConfigRuleRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for PutConfigRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.config_rule
+            .validate_at(&field_path(path, "config_rule"), errors);
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutConfigurationAggregatorRequest {
     /// <p>A list of AccountAggregationSource object. </p>
     #[serde(rename = "AccountAggregationSources")]
@@ -2269,8 +3569,47 @@ pub struct PutConfigurationAggregatorRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for PutConfigurationAggregatorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_aggregation_sources {
+            errors.check_length(
+                &field_path(path, "account_aggregation_sources"),
+                value.len(),
+                None,
+                Some(1),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "account_aggregation_sources"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "configuration_aggregator_name"),
+            self.configuration_aggregator_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_aggregator_name"),
+            &self.configuration_aggregator_name,
+            "[\\w\\-]+",
+        );
+        if let Some(ref value) = self.organization_aggregation_source {
+            value.validate_at(&field_path(path, "organization_aggregation_source"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutConfigurationAggregatorResponse {
     /// <p>Returns a ConfigurationAggregator object.</p>
     #[serde(rename = "ConfigurationAggregator")]
@@ -2280,22 +3619,39 @@ pub struct PutConfigurationAggregatorResponse {
 
 /// <p>The input for the <a>PutConfigurationRecorder</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutConfigurationRecorderRequest {
     /// <p>The configuration recorder object that records each configuration change made to the resources.</p>
     #[serde(rename = "ConfigurationRecorder")]
     pub configuration_recorder: ConfigurationRecorder,
 }
 
+impl Validate for PutConfigurationRecorderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.configuration_recorder
+            .validate_at(&field_path(path, "configuration_recorder"), errors);
+    }
+}
+
 /// <p>The input for the <a>PutDeliveryChannel</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutDeliveryChannelRequest {
     /// <p>The configuration delivery channel object that delivers the configuration information to an Amazon S3 bucket and to an Amazon SNS topic.</p>
     #[serde(rename = "DeliveryChannel")]
     pub delivery_channel: DeliveryChannel,
 }
 
+impl Validate for PutDeliveryChannelRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.delivery_channel
+            .validate_at(&field_path(path, "delivery_channel"), errors);
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutEvaluationsRequest {
     /// <p>The assessments that the AWS Lambda function performs. Each evaluation identifies an AWS resource and indicates whether it complies with the AWS Config rule that invokes the AWS Lambda function.</p>
     #[serde(rename = "Evaluations")]
@@ -2310,9 +3666,26 @@ pub struct PutEvaluationsRequest {
     pub test_mode: Option<bool>,
 }
 
+impl Validate for PutEvaluationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.evaluations {
+            errors.check_length(
+                &field_path(path, "evaluations"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "evaluations"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutEvaluationsResponse {
     /// <p>Requests that failed because of a client or server error.</p>
     #[serde(rename = "FailedEvaluations")]
@@ -2321,6 +3694,7 @@ pub struct PutEvaluationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutOrganizationConfigRuleRequest {
     /// <p>A comma-separated list of accounts that you want to exclude from an organization config rule.</p>
     #[serde(rename = "ExcludedAccounts")]
@@ -2339,8 +3713,48 @@ pub struct PutOrganizationConfigRuleRequest {
     pub organization_managed_rule_metadata: Option<OrganizationManagedRuleMetadata>,
 }
 
+impl Validate for PutOrganizationConfigRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.excluded_accounts {
+            errors.check_length(
+                &field_path(path, "excluded_accounts"),
+                value.len(),
+                None,
+                Some(1000),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "excluded_accounts"), i);
+                errors.check_pattern(&item_path, &item, "\\d{12}");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "organization_config_rule_name"),
+            self.organization_config_rule_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_config_rule_name"),
+            &self.organization_config_rule_name,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.organization_custom_rule_metadata {
+            value.validate_at(
+                &field_path(path, "organization_custom_rule_metadata"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.organization_managed_rule_metadata {
+            value.validate_at(
+                &field_path(path, "organization_managed_rule_metadata"),
+                errors,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutOrganizationConfigRuleResponse {
     /// <p>The Amazon Resource Name (ARN) of an organization config rule.</p>
     #[serde(rename = "OrganizationConfigRuleArn")]
@@ -2349,14 +3763,30 @@ pub struct PutOrganizationConfigRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRemediationConfigurationsRequest {
     /// <p>A list of remediation configuration objects.</p>
     #[serde(rename = "RemediationConfigurations")]
     pub remediation_configurations: Vec<RemediationConfiguration>,
 }
 
+impl Validate for PutRemediationConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "remediation_configurations"),
+            self.remediation_configurations.len(),
+            None,
+            Some(25),
+        );
+        for (i, item) in self.remediation_configurations.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "remediation_configurations"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRemediationConfigurationsResponse {
     /// <p>Returns a list of failed remediation batch objects.</p>
     #[serde(rename = "FailedBatches")]
@@ -2365,6 +3795,7 @@ pub struct PutRemediationConfigurationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRemediationExceptionsRequest {
     /// <p>The name of the AWS Config rule for which you want to create remediation exception.</p>
     #[serde(rename = "ConfigRuleName")]
@@ -2382,8 +3813,42 @@ pub struct PutRemediationExceptionsRequest {
     pub resource_keys: Vec<RemediationExceptionResourceKey>,
 }
 
+impl Validate for PutRemediationExceptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "config_rule_name"),
+            &self.config_rule_name,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.message {
+            errors.check_length(
+                &field_path(path, "message"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_keys"),
+            self.resource_keys.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.resource_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_keys"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRemediationExceptionsResponse {
     /// <p>Returns a list of failed remediation exceptions batch objects. Each object in the batch consists of a list of failed items and failure messages.</p>
     #[serde(rename = "FailedBatches")]
@@ -2392,14 +3857,26 @@ pub struct PutRemediationExceptionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRetentionConfigurationRequest {
     /// <p><p>Number of days AWS Config stores your historical information.</p> <note> <p>Currently, only applicable to the configuration item history.</p> </note></p>
     #[serde(rename = "RetentionPeriodInDays")]
     pub retention_period_in_days: i64,
 }
 
+impl Validate for PutRetentionConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "retention_period_in_days"),
+            self.retention_period_in_days,
+            Some(30),
+            Some(2557),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRetentionConfigurationResponse {
     /// <p>Returns a retention configuration object.</p>
     #[serde(rename = "RetentionConfiguration")]
@@ -2409,7 +3886,7 @@ pub struct PutRetentionConfigurationResponse {
 
 /// <p>Details about the query.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QueryInfo {
     /// <p>Returns a <code>FieldInfo</code> object.</p>
     #[serde(rename = "SelectFields")]
@@ -2436,7 +3913,7 @@ pub struct RecordingGroup {
 
 /// <p>The relationship of the related resource to the main resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Relationship {
     /// <p>The type of relationship with the related resource.</p>
     #[serde(rename = "relationshipName")]
@@ -2506,9 +3983,73 @@ pub struct RemediationConfiguration {
     pub target_version: Option<String>,
 }
 
+impl Validate for RemediationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.arn {
+            errors.check_length(
+                &field_path(path, "arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "config_rule_name"),
+            &self.config_rule_name,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.created_by_service {
+            errors.check_length(
+                &field_path(path, "created_by_service"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.execution_controls {
+            value.validate_at(&field_path(path, "execution_controls"), errors);
+        }
+        if let Some(ref value) = self.maximum_automatic_attempts {
+            errors.check_range(
+                &field_path(path, "maximum_automatic_attempts"),
+                *value,
+                Some(1),
+                Some(25),
+            );
+        }
+        if let Some(ref value) = self.parameters {
+            errors.check_length(&field_path(path, "parameters"), value.len(), None, Some(25));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.retry_attempt_seconds {
+            errors.check_range(
+                &field_path(path, "retry_attempt_seconds"),
+                *value,
+                Some(1),
+                Some(2678000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "target_id"),
+            self.target_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>An object that represents the details about the remediation exception. The details include the rule name, an explanation of an exception, the time when the exception will be deleted, the resource ID, and resource type. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemediationException {
     /// <p>The name of the AWS Config rule.</p>
     #[serde(rename = "ConfigRuleName")]
@@ -2542,9 +4083,30 @@ pub struct RemediationExceptionResourceKey {
     pub resource_type: Option<String>,
 }
 
+impl Validate for RemediationExceptionResourceKey {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_id {
+            errors.check_length(
+                &field_path(path, "resource_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.resource_type {
+            errors.check_length(
+                &field_path(path, "resource_type"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>Provides details of the current status of the invoked remediation action for that resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemediationExecutionStatus {
     /// <p>Start time when the remediation was executed.</p>
     #[serde(rename = "InvocationTime")]
@@ -2569,7 +4131,7 @@ pub struct RemediationExecutionStatus {
 
 /// <p>Name of the step from the SSM document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemediationExecutionStep {
     /// <p>An error message if the step was interrupted during execution.</p>
     #[serde(rename = "ErrorMessage")]
@@ -2606,9 +4168,17 @@ pub struct RemediationParameterValue {
     pub static_value: Option<StaticValue>,
 }
 
+impl Validate for RemediationParameterValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.static_value {
+            value.validate_at(&field_path(path, "static_value"), errors);
+        }
+    }
+}
+
 /// <p>An object that contains the resource type and the number of resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceCount {
     /// <p>The number of resources.</p>
     #[serde(rename = "count")]
@@ -2622,6 +4192,7 @@ pub struct ResourceCount {
 
 /// <p>Filters the resource count based on account ID, region, and resource type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceCountFilters {
     /// <p>The 12-digit ID of the account.</p>
     #[serde(rename = "AccountId")]
@@ -2637,8 +4208,25 @@ pub struct ResourceCountFilters {
     pub resource_type: Option<String>,
 }
 
+impl Validate for ResourceCountFilters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_id {
+            errors.check_pattern(&field_path(path, "account_id"), &value, "\\d{12}");
+        }
+        if let Some(ref value) = self.region {
+            errors.check_length(
+                &field_path(path, "region"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+    }
+}
+
 /// <p>Filters the results by resource account ID, region, resource ID, and resource name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceFilters {
     /// <p>The 12-digit source account ID.</p>
     #[serde(rename = "AccountId")]
@@ -2658,9 +4246,33 @@ pub struct ResourceFilters {
     pub resource_name: Option<String>,
 }
 
+impl Validate for ResourceFilters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_id {
+            errors.check_pattern(&field_path(path, "account_id"), &value, "\\d{12}");
+        }
+        if let Some(ref value) = self.region {
+            errors.check_length(
+                &field_path(path, "region"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.resource_id {
+            errors.check_length(
+                &field_path(path, "resource_id"),
+                value.chars().count(),
+                Some(1),
+                Some(768),
+            );
+        }
+    }
+}
+
 /// <p>The details that identify a resource that is discovered by AWS Config, including the resource type, ID, and (if available) the custom resource name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceIdentifier {
     /// <p>The time that the resource was deleted.</p>
     #[serde(rename = "resourceDeletionTime")]
@@ -2691,6 +4303,17 @@ pub struct ResourceKey {
     pub resource_type: String,
 }
 
+impl Validate for ResourceKey {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(768),
+        );
+    }
+}
+
 /// <p>The dynamic value of the resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceValue {
@@ -2701,7 +4324,7 @@ pub struct ResourceValue {
 
 /// <p>An object with the name of the retention configuration and the retention period in days. The object stores the configuration for data retention in AWS Config.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RetentionConfiguration {
     /// <p>The name of the retention configuration object.</p>
     #[serde(rename = "Name")]
@@ -2732,7 +4355,49 @@ pub struct Scope {
     pub tag_value: Option<String>,
 }
 
+impl Validate for Scope {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.compliance_resource_id {
+            errors.check_length(
+                &field_path(path, "compliance_resource_id"),
+                value.chars().count(),
+                Some(1),
+                Some(768),
+            );
+        }
+        if let Some(ref value) = self.compliance_resource_types {
+            errors.check_length(
+                &field_path(path, "compliance_resource_types"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "compliance_resource_types"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+        if let Some(ref value) = self.tag_key {
+            errors.check_length(
+                &field_path(path, "tag_key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.tag_value {
+            errors.check_length(
+                &field_path(path, "tag_value"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SelectResourceConfigRequest {
     /// <p>The SQL query <code>SELECT</code> command.</p>
     #[serde(rename = "Expression")]
@@ -2747,8 +4412,22 @@ pub struct SelectResourceConfigRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for SelectResourceConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "expression"),
+            self.expression.chars().count(),
+            Some(1),
+            Some(4096),
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SelectResourceConfigResponse {
     /// <p>The <code>nextToken</code> string returned in a previous request that you use to request the next page of results in a paginated response. </p>
     #[serde(rename = "NextToken")]
@@ -2779,6 +4458,25 @@ pub struct Source {
     pub source_identifier: String,
 }
 
+impl Validate for Source {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_details {
+            errors.check_length(
+                &field_path(path, "source_details"),
+                value.len(),
+                None,
+                Some(25),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "source_identifier"),
+            self.source_identifier.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Provides the source and the message types that trigger AWS Config to evaluate your AWS resources against a rule. It also provides the frequency with which you want AWS Config to run evaluations for the rule if the trigger type is periodic. You can specify the parameter values for <code>SourceDetail</code> only for custom rules. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SourceDetail {
@@ -2809,8 +4507,30 @@ pub struct SsmControls {
     pub error_percentage: Option<i64>,
 }
 
+impl Validate for SsmControls {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.concurrent_execution_rate_percentage {
+            errors.check_range(
+                &field_path(path, "concurrent_execution_rate_percentage"),
+                *value,
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.error_percentage {
+            errors.check_range(
+                &field_path(path, "error_percentage"),
+                *value,
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartConfigRulesEvaluationRequest {
     /// <p>The list of names of AWS Config rules that you want to run evaluations for.</p>
     #[serde(rename = "ConfigRuleNames")]
@@ -2818,20 +4538,51 @@ pub struct StartConfigRulesEvaluationRequest {
     pub config_rule_names: Option<Vec<String>>,
 }
 
+impl Validate for StartConfigRulesEvaluationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.config_rule_names {
+            errors.check_length(
+                &field_path(path, "config_rule_names"),
+                value.len(),
+                Some(1),
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "config_rule_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+    }
+}
+
 /// <p>The output when you start the evaluation for the specified AWS Config rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartConfigRulesEvaluationResponse {}
 
 /// <p>The input for the <a>StartConfigurationRecorder</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartConfigurationRecorderRequest {
     /// <p>The name of the recorder object that records each configuration change made to the resources.</p>
     #[serde(rename = "ConfigurationRecorderName")]
     pub configuration_recorder_name: String,
 }
 
+impl Validate for StartConfigurationRecorderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_recorder_name"),
+            self.configuration_recorder_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartRemediationExecutionRequest {
     /// <p>The list of names of AWS Config rules that you want to run remediation execution for.</p>
     #[serde(rename = "ConfigRuleName")]
@@ -2841,8 +4592,34 @@ pub struct StartRemediationExecutionRequest {
     pub resource_keys: Vec<ResourceKey>,
 }
 
+impl Validate for StartRemediationExecutionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "config_rule_name"),
+            self.config_rule_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "config_rule_name"),
+            &self.config_rule_name,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "resource_keys"),
+            self.resource_keys.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.resource_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_keys"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartRemediationExecutionResponse {
     /// <p>For resources that have failed to start execution, the API returns a resource key object.</p>
     #[serde(rename = "FailedItems")]
@@ -2862,8 +4639,24 @@ pub struct StaticValue {
     pub values: Vec<String>,
 }
 
+impl Validate for StaticValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            None,
+            Some(25),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+        }
+    }
+}
+
 /// <p>Status filter object to filter results based on specific member account ID or status type for an organization config rule. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StatusDetailFilters {
     /// <p>The 12-digit account ID of the member account within an organization.</p>
     #[serde(rename = "AccountId")]
@@ -2875,14 +4668,34 @@ pub struct StatusDetailFilters {
     pub member_account_rule_status: Option<String>,
 }
 
+impl Validate for StatusDetailFilters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_id {
+            errors.check_pattern(&field_path(path, "account_id"), &value, "\\d{12}");
+        }
+    }
+}
+
 /// <p>The input for the <a>StopConfigurationRecorder</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopConfigurationRecorderRequest {
     /// <p>The name of the recorder object that records each configuration change made to the resources.</p>
     #[serde(rename = "ConfigurationRecorderName")]
     pub configuration_recorder_name: String,
 }
 
+impl Validate for StopConfigurationRecorderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_recorder_name"),
+            self.configuration_recorder_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>The tags for the resource. The metadata that you apply to a resource to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define. Tag keys can have a maximum character length of 128 characters, and tag values can have a maximum length of 256 characters.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -2896,7 +4709,29 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the resource for which to list the tags. Currently, the supported resources are <code>ConfigRule</code>, <code>ConfigurationAggregator</code> and <code>AggregatorAuthorization</code>.</p>
     #[serde(rename = "ResourceArn")]
@@ -2906,7 +4741,29 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1000),
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the resource for which to list the tags. Currently, the supported resources are <code>ConfigRule</code>, <code>ConfigurationAggregator</code> and <code>AggregatorAuthorization</code>.</p>
     #[serde(rename = "ResourceArn")]
@@ -2916,6 +4773,27 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1000),
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 /// Errors returned by BatchGetAggregateResourceConfig
 #[derive(Debug, PartialEq)]
 pub enum BatchGetAggregateResourceConfigError {
@@ -2936,7 +4814,12 @@ impl BatchGetAggregateResourceConfigError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2971,7 +4854,12 @@ impl BatchGetResourceConfigError {
                         BatchGetResourceConfigError::NoAvailableConfigurationRecorder(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3008,7 +4896,12 @@ impl DeleteAggregationAuthorizationError {
                         DeleteAggregationAuthorizationError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3046,7 +4939,12 @@ impl DeleteConfigRuleError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(DeleteConfigRuleError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3084,7 +4982,12 @@ impl DeleteConfigurationAggregatorError {
                         DeleteConfigurationAggregatorError::NoSuchConfigurationAggregator(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3121,7 +5024,12 @@ impl DeleteConfigurationRecorderError {
                         DeleteConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3163,7 +5071,12 @@ impl DeleteDeliveryChannelError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3206,7 +5119,12 @@ impl DeleteEvaluationResultsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3258,7 +5176,12 @@ impl DeleteOrganizationConfigRuleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3297,7 +5220,12 @@ impl DeletePendingAggregationRequestError {
                         DeletePendingAggregationRequestError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3343,7 +5271,12 @@ impl DeleteRemediationConfigurationError {
                         DeleteRemediationConfigurationError::RemediationInProgress(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3381,7 +5314,12 @@ impl DeleteRemediationExceptionsError {
                         DeleteRemediationExceptionsError::NoSuchRemediation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3425,7 +5363,12 @@ impl DeleteRetentionConfigurationError {
                         DeleteRetentionConfigurationError::NoSuchRetentionConfiguration(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3475,7 +5418,12 @@ impl DeliverConfigSnapshotError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3528,7 +5476,12 @@ impl DescribeAggregateComplianceByConfigRulesError {
                         err.msg,
                     ),
                 ),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3583,7 +5536,12 @@ impl DescribeAggregationAuthorizationsError {
                         DescribeAggregationAuthorizationsError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3636,7 +5594,12 @@ impl DescribeComplianceByConfigRuleError {
                         DescribeComplianceByConfigRuleError::NoSuchConfigRule(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3682,7 +5645,12 @@ impl DescribeComplianceByResourceError {
                         DescribeComplianceByResourceError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3734,7 +5702,12 @@ impl DescribeConfigRuleEvaluationStatusError {
                         DescribeConfigRuleEvaluationStatusError::NoSuchConfigRule(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3778,7 +5751,12 @@ impl DescribeConfigRulesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3821,7 +5799,12 @@ impl DescribeConfigurationAggregatorSourcesStatusError {
 "InvalidNextTokenException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidNextToken(err.msg)),
 "InvalidParameterValueException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidParameterValue(err.msg)),
 "NoSuchConfigurationAggregatorException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::NoSuchConfigurationAggregator(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+"ValidationException" => {
+    return RusotoError::Validation {
+        message: err.msg,
+        fields: err.fields,
+    }
+}
 _ => {}
                             }
         }
@@ -3888,7 +5871,12 @@ impl DescribeConfigurationAggregatorsError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3932,7 +5920,12 @@ impl DescribeConfigurationRecorderStatusError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3971,7 +5964,12 @@ impl DescribeConfigurationRecordersError {
                         DescribeConfigurationRecordersError::NoSuchConfigurationRecorder(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4008,7 +6006,12 @@ impl DescribeDeliveryChannelStatusError {
                         DescribeDeliveryChannelStatusError::NoSuchDeliveryChannel(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4043,7 +6046,12 @@ impl DescribeDeliveryChannelsError {
                         DescribeDeliveryChannelsError::NoSuchDeliveryChannel(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4105,7 +6113,12 @@ impl DescribeOrganizationConfigRuleStatusesError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4170,7 +6183,12 @@ impl DescribeOrganizationConfigRulesError {
                         DescribeOrganizationConfigRulesError::OrganizationAccessDenied(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4224,7 +6242,12 @@ impl DescribePendingAggregationRequestsError {
                         DescribePendingAggregationRequestsError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4255,7 +6278,12 @@ impl DescribeRemediationConfigurationsError {
     ) -> RusotoError<DescribeRemediationConfigurationsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4297,7 +6325,12 @@ impl DescribeRemediationExceptionsError {
                         DescribeRemediationExceptionsError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4344,7 +6377,12 @@ impl DescribeRemediationExecutionStatusError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4398,7 +6436,12 @@ impl DescribeRetentionConfigurationsError {
                         DescribeRetentionConfigurationsError::NoSuchRetentionConfiguration(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4451,7 +6494,12 @@ impl GetAggregateComplianceDetailsByConfigRuleError {
                         err.msg,
                     ),
                 ),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4508,7 +6556,12 @@ impl GetAggregateConfigRuleComplianceSummaryError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4565,7 +6618,12 @@ impl GetAggregateDiscoveredResourceCountsError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4620,7 +6678,12 @@ impl GetAggregateResourceConfigError {
                         GetAggregateResourceConfigError::ResourceNotDiscovered(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4673,7 +6736,12 @@ impl GetComplianceDetailsByConfigRuleError {
                         GetComplianceDetailsByConfigRuleError::NoSuchConfigRule(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4712,7 +6780,12 @@ impl GetComplianceDetailsByResourceError {
                         GetComplianceDetailsByResourceError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4741,7 +6814,12 @@ impl GetComplianceSummaryByConfigRuleError {
     ) -> RusotoError<GetComplianceSummaryByConfigRuleError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4776,7 +6854,12 @@ impl GetComplianceSummaryByResourceTypeError {
                         GetComplianceSummaryByResourceTypeError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4820,7 +6903,12 @@ impl GetDiscoveredResourceCountsError {
                         GetDiscoveredResourceCountsError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4883,7 +6971,12 @@ impl GetOrganizationConfigRuleDetailedStatusError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4953,7 +7046,12 @@ impl GetResourceConfigHistoryError {
                         GetResourceConfigHistoryError::ResourceNotDiscovered(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5010,7 +7108,12 @@ impl ListAggregateDiscoveredResourcesError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5063,7 +7166,12 @@ impl ListDiscoveredResourcesError {
                         ListDiscoveredResourcesError::NoAvailableConfigurationRecorder(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5112,7 +7220,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5151,7 +7264,12 @@ impl PutAggregationAuthorizationError {
                         PutAggregationAuthorizationError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5210,7 +7328,12 @@ impl PutConfigRuleError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(PutConfigRuleError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5286,7 +7409,12 @@ impl PutConfigurationAggregatorError {
                         PutConfigurationAggregatorError::OrganizationAllFeaturesNotEnabled(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5349,7 +7477,12 @@ impl PutConfigurationRecorderError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5429,7 +7562,12 @@ impl PutDeliveryChannelError {
                 "NoSuchBucketException" => {
                     return RusotoError::Service(PutDeliveryChannelError::NoSuchBucket(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5480,7 +7618,12 @@ impl PutEvaluationsError {
                 "NoSuchConfigRuleException" => {
                     return RusotoError::Service(PutEvaluationsError::NoSuchConfigRule(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5561,7 +7704,12 @@ impl PutOrganizationConfigRuleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5613,7 +7761,12 @@ impl PutRemediationConfigurationsError {
                         PutRemediationConfigurationsError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5649,7 +7802,12 @@ impl PutRemediationExceptionsError {
                         PutRemediationExceptionsError::InvalidParameterValue(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5693,7 +7851,12 @@ impl PutRetentionConfigurationError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5743,7 +7906,12 @@ impl SelectResourceConfigError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5803,7 +7971,12 @@ impl StartConfigRulesEvaluationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5850,7 +8023,12 @@ impl StartConfigurationRecorderError {
                         StartConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5900,7 +8078,12 @@ impl StartRemediationExecutionError {
                         StartRemediationExecutionError::NoSuchRemediationConfiguration(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5937,7 +8120,12 @@ impl StopConfigurationRecorderError {
                         StopConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5975,7 +8163,12 @@ impl TagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6009,7 +8202,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6446,430 +8644,94 @@ pub trait ConfigService {
     /// <p>Deletes specified tags from a resource.</p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError>;
 }
-/// A client for the Config Service API.
-#[derive(Clone)]
-pub struct ConfigServiceClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ConfigServiceClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ConfigServiceClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ConfigServiceClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ConfigServiceClient {
-        ConfigServiceClient { client, region }
-    }
-}
-
-impl ConfigService for ConfigServiceClient {
-    /// <p><p>Returns the current configuration items for resources that are present in your AWS Config aggregator. The operation also returns a list of resources that are not processed in the current request. If there are no unprocessed resources, the operation returns an empty <code>unprocessedResourceIdentifiers</code> list. </p> <note> <ul> <li> <p>The API does not return results for deleted resources.</p> </li> <li> <p> The API does not return tags and relationships.</p> </li> </ul> </note></p>
+const _: Option<&dyn ConfigService> = None;
+impl<'a, T: ConfigService + ?Sized> ConfigService for &'a T {
     fn batch_get_aggregate_resource_config(
         &self,
         input: BatchGetAggregateResourceConfigRequest,
     ) -> RusotoFuture<BatchGetAggregateResourceConfigResponse, BatchGetAggregateResourceConfigError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.BatchGetAggregateResourceConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetAggregateResourceConfigResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchGetAggregateResourceConfigError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).batch_get_aggregate_resource_config(input)
     }
-
-    /// <p><p>Returns the current configuration for one or more requested resources. The operation also returns a list of resources that are not processed in the current request. If there are no unprocessed resources, the operation returns an empty unprocessedResourceKeys list. </p> <note> <ul> <li> <p>The API does not return results for deleted resources.</p> </li> <li> <p> The API does not return any tags for the requested resources. This information is filtered out of the supplementaryConfiguration section of the API response.</p> </li> </ul> </note></p>
     fn batch_get_resource_config(
         &self,
         input: BatchGetResourceConfigRequest,
     ) -> RusotoFuture<BatchGetResourceConfigResponse, BatchGetResourceConfigError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.BatchGetResourceConfig");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetResourceConfigResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchGetResourceConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_get_resource_config(input)
     }
-
-    /// <p>Deletes the authorization granted to the specified configuration aggregator account in a specified region.</p>
     fn delete_aggregation_authorization(
         &self,
         input: DeleteAggregationAuthorizationRequest,
     ) -> RusotoFuture<(), DeleteAggregationAuthorizationError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DeleteAggregationAuthorization",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteAggregationAuthorizationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_aggregation_authorization(input)
     }
-
-    /// <p>Deletes the specified AWS Config rule and all of its evaluation results.</p> <p>AWS Config sets the state of a rule to <code>DELETING</code> until the deletion is complete. You cannot update a rule while it is in this state. If you make a <code>PutConfigRule</code> or <code>DeleteConfigRule</code> request for the rule, you will receive a <code>ResourceInUseException</code>.</p> <p>You can check the state of a rule by using the <code>DescribeConfigRules</code> request.</p>
     fn delete_config_rule(
         &self,
         input: DeleteConfigRuleRequest,
     ) -> RusotoFuture<(), DeleteConfigRuleError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.DeleteConfigRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteConfigRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_config_rule(input)
     }
-
-    /// <p>Deletes the specified configuration aggregator and the aggregated data associated with the aggregator.</p>
     fn delete_configuration_aggregator(
         &self,
         input: DeleteConfigurationAggregatorRequest,
     ) -> RusotoFuture<(), DeleteConfigurationAggregatorError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DeleteConfigurationAggregator",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteConfigurationAggregatorError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_configuration_aggregator(input)
     }
-
-    /// <p>Deletes the configuration recorder.</p> <p>After the configuration recorder is deleted, AWS Config will not record resource configuration changes until you create a new configuration recorder.</p> <p>This action does not delete the configuration information that was previously recorded. You will be able to access the previously recorded information by using the <code>GetResourceConfigHistory</code> action, but you will not be able to access this information in the AWS Config console until you create a new configuration recorder.</p>
     fn delete_configuration_recorder(
         &self,
         input: DeleteConfigurationRecorderRequest,
     ) -> RusotoFuture<(), DeleteConfigurationRecorderError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DeleteConfigurationRecorder",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteConfigurationRecorderError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_configuration_recorder(input)
     }
-
-    /// <p>Deletes the delivery channel.</p> <p>Before you can delete the delivery channel, you must stop the configuration recorder by using the <a>StopConfigurationRecorder</a> action.</p>
     fn delete_delivery_channel(
         &self,
         input: DeleteDeliveryChannelRequest,
     ) -> RusotoFuture<(), DeleteDeliveryChannelError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.DeleteDeliveryChannel");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteDeliveryChannelError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_delivery_channel(input)
     }
-
-    /// <p>Deletes the evaluation results for the specified AWS Config rule. You can specify one AWS Config rule per request. After you delete the evaluation results, you can call the <a>StartConfigRulesEvaluation</a> API to start evaluating your AWS resources against the rule.</p>
     fn delete_evaluation_results(
         &self,
         input: DeleteEvaluationResultsRequest,
     ) -> RusotoFuture<DeleteEvaluationResultsResponse, DeleteEvaluationResultsError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DeleteEvaluationResults",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteEvaluationResultsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteEvaluationResultsError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_evaluation_results(input)
     }
-
-    /// <p>Deletes the specified organization config rule and all of its evaluation results from all member accounts in that organization. Only a master account can delete an organization config rule.</p> <p>AWS Config sets the state of a rule to DELETE_IN_PROGRESS until the deletion is complete. You cannot update a rule while it is in this state.</p>
     fn delete_organization_config_rule(
         &self,
         input: DeleteOrganizationConfigRuleRequest,
     ) -> RusotoFuture<(), DeleteOrganizationConfigRuleError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DeleteOrganizationConfigRule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteOrganizationConfigRuleError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_organization_config_rule(input)
     }
-
-    /// <p>Deletes pending authorization requests for a specified aggregator account in a specified region.</p>
     fn delete_pending_aggregation_request(
         &self,
         input: DeletePendingAggregationRequestRequest,
     ) -> RusotoFuture<(), DeletePendingAggregationRequestError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DeletePendingAggregationRequest",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeletePendingAggregationRequestError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).delete_pending_aggregation_request(input)
     }
-
-    /// <p>Deletes the remediation configuration.</p>
     fn delete_remediation_configuration(
         &self,
         input: DeleteRemediationConfigurationRequest,
     ) -> RusotoFuture<DeleteRemediationConfigurationResponse, DeleteRemediationConfigurationError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DeleteRemediationConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRemediationConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteRemediationConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_remediation_configuration(input)
     }
-
-    /// <p>Deletes one or more remediation exceptions mentioned in the resource keys.</p>
     fn delete_remediation_exceptions(
         &self,
         input: DeleteRemediationExceptionsRequest,
     ) -> RusotoFuture<DeleteRemediationExceptionsResponse, DeleteRemediationExceptionsError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DeleteRemediationExceptions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRemediationExceptionsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteRemediationExceptionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_remediation_exceptions(input)
     }
-
-    /// <p>Deletes the retention configuration.</p>
     fn delete_retention_configuration(
         &self,
         input: DeleteRetentionConfigurationRequest,
     ) -> RusotoFuture<(), DeleteRetentionConfigurationError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DeleteRetentionConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteRetentionConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_retention_configuration(input)
     }
-
-    /// <p><p>Schedules delivery of a configuration snapshot to the Amazon S3 bucket in the specified delivery channel. After the delivery has started, AWS Config sends the following notifications using an Amazon SNS topic that you have specified.</p> <ul> <li> <p>Notification of the start of the delivery.</p> </li> <li> <p>Notification of the completion of the delivery, if the delivery was successfully completed.</p> </li> <li> <p>Notification of delivery failure, if the delivery failed.</p> </li> </ul></p>
     fn deliver_config_snapshot(
         &self,
         input: DeliverConfigSnapshotRequest,
     ) -> RusotoFuture<DeliverConfigSnapshotResponse, DeliverConfigSnapshotError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.DeliverConfigSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeliverConfigSnapshotResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeliverConfigSnapshotError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).deliver_config_snapshot(input)
     }
-
-    /// <p><p>Returns a list of compliant and noncompliant rules with the number of resources for compliant and noncompliant rules. </p> <note> <p>The results can return an empty result page, but if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
     fn describe_aggregate_compliance_by_config_rules(
         &self,
         input: DescribeAggregateComplianceByConfigRulesRequest,
@@ -6877,31 +8739,8 @@ impl ConfigService for ConfigServiceClient {
         DescribeAggregateComplianceByConfigRulesResponse,
         DescribeAggregateComplianceByConfigRulesError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeAggregateComplianceByConfigRules",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAggregateComplianceByConfigRulesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAggregateComplianceByConfigRulesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_aggregate_compliance_by_config_rules(input)
     }
-
-    /// <p>Returns a list of authorizations granted to various aggregator accounts and regions.</p>
     fn describe_aggregation_authorizations(
         &self,
         input: DescribeAggregationAuthorizationsRequest,
@@ -6909,92 +8748,21 @@ impl ConfigService for ConfigServiceClient {
         DescribeAggregationAuthorizationsResponse,
         DescribeAggregationAuthorizationsError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeAggregationAuthorizations",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAggregationAuthorizationsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAggregationAuthorizationsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_aggregation_authorizations(input)
     }
-
-    /// <p><p>Indicates whether the specified AWS Config rules are compliant. If a rule is noncompliant, this action returns the number of AWS resources that do not comply with the rule.</p> <p>A rule is compliant if all of the evaluated resources comply with it. It is noncompliant if any of these resources do not comply.</p> <p>If AWS Config has no current evaluation results for the rule, it returns <code>INSUFFICIENT<em>DATA</code>. This result might indicate one of the following conditions:</p> <ul> <li> <p>AWS Config has never invoked an evaluation for the rule. To check whether it has, use the <code>DescribeConfigRuleEvaluationStatus</code> action to get the <code>LastSuccessfulInvocationTime</code> and <code>LastFailedInvocationTime</code>.</p> </li> <li> <p>The rule&#39;s AWS Lambda function is failing to send evaluation results to AWS Config. Verify that the role you assigned to your configuration recorder includes the <code>config:PutEvaluations</code> permission. If the rule is a custom rule, verify that the AWS Lambda execution role includes the <code>config:PutEvaluations</code> permission.</p> </li> <li> <p>The rule&#39;s AWS Lambda function has returned <code>NOT</em>APPLICABLE</code> for all evaluation results. This can occur if the resources were deleted or removed from the rule&#39;s scope.</p> </li> </ul></p>
     fn describe_compliance_by_config_rule(
         &self,
         input: DescribeComplianceByConfigRuleRequest,
     ) -> RusotoFuture<DescribeComplianceByConfigRuleResponse, DescribeComplianceByConfigRuleError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeComplianceByConfigRule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeComplianceByConfigRuleResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeComplianceByConfigRuleError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_compliance_by_config_rule(input)
     }
-
-    /// <p><p>Indicates whether the specified AWS resources are compliant. If a resource is noncompliant, this action returns the number of AWS Config rules that the resource does not comply with.</p> <p>A resource is compliant if it complies with all the AWS Config rules that evaluate it. It is noncompliant if it does not comply with one or more of these rules.</p> <p>If AWS Config has no current evaluation results for the resource, it returns <code>INSUFFICIENT<em>DATA</code>. This result might indicate one of the following conditions about the rules that evaluate the resource:</p> <ul> <li> <p>AWS Config has never invoked an evaluation for the rule. To check whether it has, use the <code>DescribeConfigRuleEvaluationStatus</code> action to get the <code>LastSuccessfulInvocationTime</code> and <code>LastFailedInvocationTime</code>.</p> </li> <li> <p>The rule&#39;s AWS Lambda function is failing to send evaluation results to AWS Config. Verify that the role that you assigned to your configuration recorder includes the <code>config:PutEvaluations</code> permission. If the rule is a custom rule, verify that the AWS Lambda execution role includes the <code>config:PutEvaluations</code> permission.</p> </li> <li> <p>The rule&#39;s AWS Lambda function has returned <code>NOT</em>APPLICABLE</code> for all evaluation results. This can occur if the resources were deleted or removed from the rule&#39;s scope.</p> </li> </ul></p>
     fn describe_compliance_by_resource(
         &self,
         input: DescribeComplianceByResourceRequest,
     ) -> RusotoFuture<DescribeComplianceByResourceResponse, DescribeComplianceByResourceError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeComplianceByResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeComplianceByResourceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeComplianceByResourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_compliance_by_resource(input)
     }
-
-    /// <p>Returns status information for each of your AWS managed Config rules. The status includes information such as the last time AWS Config invoked the rule, the last time AWS Config failed to invoke the rule, and the related error for the last failure.</p>
     fn describe_config_rule_evaluation_status(
         &self,
         input: DescribeConfigRuleEvaluationStatusRequest,
@@ -7002,61 +8770,14 @@ impl ConfigService for ConfigServiceClient {
         DescribeConfigRuleEvaluationStatusResponse,
         DescribeConfigRuleEvaluationStatusError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeConfigRuleEvaluationStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeConfigRuleEvaluationStatusResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeConfigRuleEvaluationStatusError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_config_rule_evaluation_status(input)
     }
-
-    /// <p>Returns details about your AWS Config rules.</p>
     fn describe_config_rules(
         &self,
         input: DescribeConfigRulesRequest,
     ) -> RusotoFuture<DescribeConfigRulesResponse, DescribeConfigRulesError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.DescribeConfigRules");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeConfigRulesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeConfigRulesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_config_rules(input)
     }
-
-    /// <p>Returns status information for sources within an aggregator. The status includes information about the last time AWS Config verified authorization between the source account and an aggregator account. In case of a failure, the status contains the related error code or message. </p>
     fn describe_configuration_aggregator_sources_status(
         &self,
         input: DescribeConfigurationAggregatorSourcesStatusRequest,
@@ -7064,62 +8785,15 @@ impl ConfigService for ConfigServiceClient {
         DescribeConfigurationAggregatorSourcesStatusResponse,
         DescribeConfigurationAggregatorSourcesStatusError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeConfigurationAggregatorSourcesStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-                        if response.status.is_success() {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DescribeConfigurationAggregatorSourcesStatusResponse, _>()
-                }))
-                        } else {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                                Err(DescribeConfigurationAggregatorSourcesStatusError::from_response(response))
-                            }))
-                        }
-                    })
+        (**self).describe_configuration_aggregator_sources_status(input)
     }
-
-    /// <p>Returns the details of one or more configuration aggregators. If the configuration aggregator is not specified, this action returns the details for all the configuration aggregators associated with the account. </p>
     fn describe_configuration_aggregators(
         &self,
         input: DescribeConfigurationAggregatorsRequest,
     ) -> RusotoFuture<DescribeConfigurationAggregatorsResponse, DescribeConfigurationAggregatorsError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeConfigurationAggregators",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeConfigurationAggregatorsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeConfigurationAggregatorsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_configuration_aggregators(input)
     }
-
-    /// <p><p>Returns the current status of the specified configuration recorder. If a configuration recorder is not specified, this action returns the status of all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
     fn describe_configuration_recorder_status(
         &self,
         input: DescribeConfigurationRecorderStatusRequest,
@@ -7127,122 +8801,28 @@ impl ConfigService for ConfigServiceClient {
         DescribeConfigurationRecorderStatusResponse,
         DescribeConfigurationRecorderStatusError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeConfigurationRecorderStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeConfigurationRecorderStatusResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeConfigurationRecorderStatusError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_configuration_recorder_status(input)
     }
-
-    /// <p><p>Returns the details for the specified configuration recorders. If the configuration recorder is not specified, this action returns the details for all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
     fn describe_configuration_recorders(
         &self,
         input: DescribeConfigurationRecordersRequest,
     ) -> RusotoFuture<DescribeConfigurationRecordersResponse, DescribeConfigurationRecordersError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeConfigurationRecorders",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeConfigurationRecordersResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeConfigurationRecordersError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_configuration_recorders(input)
     }
-
-    /// <p><p>Returns the current status of the specified delivery channel. If a delivery channel is not specified, this action returns the current status of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
     fn describe_delivery_channel_status(
         &self,
         input: DescribeDeliveryChannelStatusRequest,
     ) -> RusotoFuture<DescribeDeliveryChannelStatusResponse, DescribeDeliveryChannelStatusError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeDeliveryChannelStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDeliveryChannelStatusResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDeliveryChannelStatusError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_delivery_channel_status(input)
     }
-
-    /// <p><p>Returns details about the specified delivery channel. If a delivery channel is not specified, this action returns the details of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
     fn describe_delivery_channels(
         &self,
         input: DescribeDeliveryChannelsRequest,
     ) -> RusotoFuture<DescribeDeliveryChannelsResponse, DescribeDeliveryChannelsError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeDeliveryChannels",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDeliveryChannelsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDeliveryChannelsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_delivery_channels(input)
     }
-
-    /// <p><p>Provides organization config rule deployment status for an organization.</p> <note> <p>The status is not considered successful until organization config rule is successfully deployed in all the member accounts with an exception of excluded accounts.</p> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
     fn describe_organization_config_rule_statuses(
         &self,
         input: DescribeOrganizationConfigRuleStatusesRequest,
@@ -7250,65 +8830,15 @@ impl ConfigService for ConfigServiceClient {
         DescribeOrganizationConfigRuleStatusesResponse,
         DescribeOrganizationConfigRuleStatusesError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeOrganizationConfigRuleStatuses",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeOrganizationConfigRuleStatusesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeOrganizationConfigRuleStatusesError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_organization_config_rule_statuses(input)
     }
-
-    /// <p><p>Returns a list of organization config rules.</p> <note> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
     fn describe_organization_config_rules(
         &self,
         input: DescribeOrganizationConfigRulesRequest,
     ) -> RusotoFuture<DescribeOrganizationConfigRulesResponse, DescribeOrganizationConfigRulesError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeOrganizationConfigRules",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeOrganizationConfigRulesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeOrganizationConfigRulesError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_organization_config_rules(input)
     }
-
-    /// <p>Returns a list of all pending aggregation requests.</p>
     fn describe_pending_aggregation_requests(
         &self,
         input: DescribePendingAggregationRequestsRequest,
@@ -7316,33 +8846,8 @@ impl ConfigService for ConfigServiceClient {
         DescribePendingAggregationRequestsResponse,
         DescribePendingAggregationRequestsError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribePendingAggregationRequests",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePendingAggregationRequestsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribePendingAggregationRequestsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_pending_aggregation_requests(input)
     }
-
-    /// <p>Returns the details of one or more remediation configurations.</p>
     fn describe_remediation_configurations(
         &self,
         input: DescribeRemediationConfigurationsRequest,
@@ -7350,63 +8855,15 @@ impl ConfigService for ConfigServiceClient {
         DescribeRemediationConfigurationsResponse,
         DescribeRemediationConfigurationsError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeRemediationConfigurations",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRemediationConfigurationsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeRemediationConfigurationsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_remediation_configurations(input)
     }
-
-    /// <p><p>Returns the details of one or more remediation exceptions. A detailed view of a remediation exception for a set of resources that includes an explanation of an exception and the time when the exception will be deleted. When you specify the limit and the next token, you receive a paginated response. </p> <note> <p>When you specify the limit and the next token, you receive a paginated response. </p> <p>Limit and next token are not applicable if you request resources in batch. It is only applicable, when you request all resources.</p> </note></p>
     fn describe_remediation_exceptions(
         &self,
         input: DescribeRemediationExceptionsRequest,
     ) -> RusotoFuture<DescribeRemediationExceptionsResponse, DescribeRemediationExceptionsError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeRemediationExceptions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRemediationExceptionsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeRemediationExceptionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_remediation_exceptions(input)
     }
-
-    /// <p>Provides a detailed view of a Remediation Execution for a set of resources including state, timestamps for when steps for the remediation execution occur, and any error messages for steps that have failed. When you specify the limit and the next token, you receive a paginated response.</p>
     fn describe_remediation_execution_status(
         &self,
         input: DescribeRemediationExecutionStatusRequest,
@@ -7414,65 +8871,15 @@ impl ConfigService for ConfigServiceClient {
         DescribeRemediationExecutionStatusResponse,
         DescribeRemediationExecutionStatusError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeRemediationExecutionStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRemediationExecutionStatusResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeRemediationExecutionStatusError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_remediation_execution_status(input)
     }
-
-    /// <p><p>Returns the details of one or more retention configurations. If the retention configuration name is not specified, this action returns the details for all the retention configurations for that account.</p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
     fn describe_retention_configurations(
         &self,
         input: DescribeRetentionConfigurationsRequest,
     ) -> RusotoFuture<DescribeRetentionConfigurationsResponse, DescribeRetentionConfigurationsError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.DescribeRetentionConfigurations",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRetentionConfigurationsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeRetentionConfigurationsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_retention_configurations(input)
     }
-
-    /// <p><p>Returns the evaluation results for the specified AWS Config rule for a specific resource in a rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule. </p> <note> <p>The results can return an empty result page. But if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
     fn get_aggregate_compliance_details_by_config_rule(
         &self,
         input: GetAggregateComplianceDetailsByConfigRuleRequest,
@@ -7480,31 +8887,8 @@ impl ConfigService for ConfigServiceClient {
         GetAggregateComplianceDetailsByConfigRuleResponse,
         GetAggregateComplianceDetailsByConfigRuleError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetAggregateComplianceDetailsByConfigRule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAggregateComplianceDetailsByConfigRuleResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetAggregateComplianceDetailsByConfigRuleError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_aggregate_compliance_details_by_config_rule(input)
     }
-
-    /// <p><p>Returns the number of compliant and noncompliant rules for one or more accounts and regions in an aggregator.</p> <note> <p>The results can return an empty result page, but if you have a nextToken, the results are displayed on the next page.</p> </note></p>
     fn get_aggregate_config_rule_compliance_summary(
         &self,
         input: GetAggregateConfigRuleComplianceSummaryRequest,
@@ -7512,33 +8896,8 @@ impl ConfigService for ConfigServiceClient {
         GetAggregateConfigRuleComplianceSummaryResponse,
         GetAggregateConfigRuleComplianceSummaryError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetAggregateConfigRuleComplianceSummary",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAggregateConfigRuleComplianceSummaryResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetAggregateConfigRuleComplianceSummaryError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_aggregate_config_rule_compliance_summary(input)
     }
-
-    /// <p>Returns the resource counts across accounts and regions that are present in your AWS Config aggregator. You can request the resource counts by providing filters and GroupByKey.</p> <p>For example, if the input contains accountID 12345678910 and region us-east-1 in filters, the API returns the count of resources in account ID 12345678910 and region us-east-1. If the input contains ACCOUNT_ID as a GroupByKey, the API returns resource counts for all source accounts that are present in your aggregator.</p>
     fn get_aggregate_discovered_resource_counts(
         &self,
         input: GetAggregateDiscoveredResourceCountsRequest,
@@ -7546,154 +8905,34 @@ impl ConfigService for ConfigServiceClient {
         GetAggregateDiscoveredResourceCountsResponse,
         GetAggregateDiscoveredResourceCountsError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetAggregateDiscoveredResourceCounts",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAggregateDiscoveredResourceCountsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetAggregateDiscoveredResourceCountsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_aggregate_discovered_resource_counts(input)
     }
-
-    /// <p>Returns configuration item that is aggregated for your specific resource in a specific source account and region.</p>
     fn get_aggregate_resource_config(
         &self,
         input: GetAggregateResourceConfigRequest,
     ) -> RusotoFuture<GetAggregateResourceConfigResponse, GetAggregateResourceConfigError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetAggregateResourceConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAggregateResourceConfigResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetAggregateResourceConfigError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_aggregate_resource_config(input)
     }
-
-    /// <p>Returns the evaluation results for the specified AWS Config rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule.</p>
     fn get_compliance_details_by_config_rule(
         &self,
         input: GetComplianceDetailsByConfigRuleRequest,
     ) -> RusotoFuture<GetComplianceDetailsByConfigRuleResponse, GetComplianceDetailsByConfigRuleError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetComplianceDetailsByConfigRule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetComplianceDetailsByConfigRuleResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetComplianceDetailsByConfigRuleError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_compliance_details_by_config_rule(input)
     }
-
-    /// <p>Returns the evaluation results for the specified AWS resource. The results indicate which AWS Config rules were used to evaluate the resource, when each rule was last used, and whether the resource complies with each rule.</p>
     fn get_compliance_details_by_resource(
         &self,
         input: GetComplianceDetailsByResourceRequest,
     ) -> RusotoFuture<GetComplianceDetailsByResourceResponse, GetComplianceDetailsByResourceError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetComplianceDetailsByResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetComplianceDetailsByResourceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetComplianceDetailsByResourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_compliance_details_by_resource(input)
     }
-
-    /// <p>Returns the number of AWS Config rules that are compliant and noncompliant, up to a maximum of 25 for each.</p>
     fn get_compliance_summary_by_config_rule(
         &self,
     ) -> RusotoFuture<GetComplianceSummaryByConfigRuleResponse, GetComplianceSummaryByConfigRuleError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetComplianceSummaryByConfigRule",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetComplianceSummaryByConfigRuleResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetComplianceSummaryByConfigRuleError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_compliance_summary_by_config_rule()
     }
-
-    /// <p>Returns the number of resources that are compliant and the number that are noncompliant. You can specify one or more resource types to get these numbers for each resource type. The maximum number returned is 100.</p>
     fn get_compliance_summary_by_resource_type(
         &self,
         input: GetComplianceSummaryByResourceTypeRequest,
@@ -7701,62 +8940,14 @@ impl ConfigService for ConfigServiceClient {
         GetComplianceSummaryByResourceTypeResponse,
         GetComplianceSummaryByResourceTypeError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetComplianceSummaryByResourceType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetComplianceSummaryByResourceTypeResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetComplianceSummaryByResourceTypeError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_compliance_summary_by_resource_type(input)
     }
-
-    /// <p><p>Returns the resource types, the number of each resource type, and the total number of resources that AWS Config is recording in this region for your AWS account. </p> <p class="title"> <b>Example</b> </p> <ol> <li> <p>AWS Config is recording three resource types in the US East (Ohio) Region for your account: 25 EC2 instances, 20 IAM users, and 15 S3 buckets.</p> </li> <li> <p>You make a call to the <code>GetDiscoveredResourceCounts</code> action and specify that you want all resource types. </p> </li> <li> <p>AWS Config returns the following:</p> <ul> <li> <p>The resource types (EC2 instances, IAM users, and S3 buckets).</p> </li> <li> <p>The number of each resource type (25, 20, and 15).</p> </li> <li> <p>The total number of all resources (60).</p> </li> </ul> </li> </ol> <p>The response is paginated. By default, AWS Config lists 100 <a>ResourceCount</a> objects on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>If you make a call to the <a>GetDiscoveredResourceCounts</a> action, you might not immediately receive resource counts in the following situations:</p> <ul> <li> <p>You are a new AWS Config customer.</p> </li> <li> <p>You just enabled resource recording.</p> </li> </ul> <p>It might take a few minutes for AWS Config to record and count your resources. Wait a few minutes and then retry the <a>GetDiscoveredResourceCounts</a> action. </p> </note></p>
     fn get_discovered_resource_counts(
         &self,
         input: GetDiscoveredResourceCountsRequest,
     ) -> RusotoFuture<GetDiscoveredResourceCountsResponse, GetDiscoveredResourceCountsError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetDiscoveredResourceCounts",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDiscoveredResourceCountsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetDiscoveredResourceCountsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_discovered_resource_counts(input)
     }
-
-    /// <p><p>Returns detailed status for each member account within an organization for a given organization config rule.</p> <note> <p>Only a master account can call this API.</p> </note></p>
     fn get_organization_config_rule_detailed_status(
         &self,
         input: GetOrganizationConfigRuleDetailedStatusRequest,
@@ -7764,609 +8955,5792 @@ impl ConfigService for ConfigServiceClient {
         GetOrganizationConfigRuleDetailedStatusResponse,
         GetOrganizationConfigRuleDetailedStatusError,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetOrganizationConfigRuleDetailedStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOrganizationConfigRuleDetailedStatusResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetOrganizationConfigRuleDetailedStatusError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_organization_config_rule_detailed_status(input)
     }
-
-    /// <p><p>Returns a list of configuration items for the specified resource. The list contains details about each state of the resource during the specified time interval. If you specified a retention period to retain your <code>ConfigurationItems</code> between a minimum of 30 days and a maximum of 7 years (2557 days), AWS Config returns the <code>ConfigurationItems</code> for the specified retention period. </p> <p>The response is paginated. By default, AWS Config returns a limit of 10 configuration items per page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>Each call to the API is limited to span a duration of seven days. It is likely that the number of records returned is smaller than the specified <code>limit</code>. In such cases, you can make another call, using the <code>nextToken</code>.</p> </note></p>
     fn get_resource_config_history(
         &self,
         input: GetResourceConfigHistoryRequest,
     ) -> RusotoFuture<GetResourceConfigHistoryResponse, GetResourceConfigHistoryError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetResourceConfigHistory",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetResourceConfigHistoryResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetResourceConfigHistoryError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_resource_config_history(input)
     }
-
-    /// <p>Accepts a resource type and returns a list of resource identifiers that are aggregated for a specific resource type across accounts and regions. A resource identifier includes the resource type, ID, (if available) the custom resource name, source account, and source region. You can narrow the results to include only resources that have specific resource IDs, or a resource name, or source account ID, or source region.</p> <p>For example, if the input consists of accountID 12345678910 and the region is us-east-1 for resource type <code>AWS::EC2::Instance</code> then the API returns all the EC2 instance identifiers of accountID 12345678910 and region us-east-1.</p>
     fn list_aggregate_discovered_resources(
         &self,
         input: ListAggregateDiscoveredResourcesRequest,
     ) -> RusotoFuture<ListAggregateDiscoveredResourcesResponse, ListAggregateDiscoveredResourcesError>
     {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.ListAggregateDiscoveredResources",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAggregateDiscoveredResourcesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListAggregateDiscoveredResourcesError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_aggregate_discovered_resources(input)
     }
-
-    /// <p>Accepts a resource type and returns a list of resource identifiers for the resources of that type. A resource identifier includes the resource type, ID, and (if available) the custom resource name. The results consist of resources that AWS Config has discovered, including those that AWS Config is not currently recording. You can narrow the results to include only resources that have specific resource IDs or a resource name.</p> <note> <p>You can specify either resource IDs or a resource name, but not both, in the same request.</p> </note> <p>The response is paginated. By default, AWS Config lists 100 resource identifiers on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p>
     fn list_discovered_resources(
         &self,
         input: ListDiscoveredResourcesRequest,
     ) -> RusotoFuture<ListDiscoveredResourcesResponse, ListDiscoveredResourcesError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.ListDiscoveredResources",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDiscoveredResourcesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListDiscoveredResourcesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_discovered_resources(input)
     }
-
-    /// <p>List the tags for AWS Config resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Authorizes the aggregator account and region to collect data from the source account and region. </p>
     fn put_aggregation_authorization(
         &self,
         input: PutAggregationAuthorizationRequest,
     ) -> RusotoFuture<PutAggregationAuthorizationResponse, PutAggregationAuthorizationError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.PutAggregationAuthorization",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutAggregationAuthorizationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutAggregationAuthorizationError::from_response(response))
-                }))
-            }
-        })
+        (**self).put_aggregation_authorization(input)
     }
-
-    /// <p>Adds or updates an AWS Config rule for evaluating whether your AWS resources comply with your desired configurations.</p> <p>You can use this action for custom AWS Config rules and AWS managed Config rules. A custom AWS Config rule is a rule that you develop and maintain. An AWS managed Config rule is a customizable, predefined rule that AWS Config provides.</p> <p>If you are adding a new custom AWS Config rule, you must first create the AWS Lambda function that the rule invokes to evaluate your resources. When you use the <code>PutConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. Specify the ARN for the <code>SourceIdentifier</code> key. This key is part of the <code>Source</code> object, which is part of the <code>ConfigRule</code> object. </p> <p>If you are adding an AWS managed Config rule, specify the rule's identifier for the <code>SourceIdentifier</code> key. To reference AWS managed Config rule identifiers, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config_use-managed-rules.html">About AWS Managed Config Rules</a>.</p> <p>For any new rule that you add, specify the <code>ConfigRuleName</code> in the <code>ConfigRule</code> object. Do not specify the <code>ConfigRuleArn</code> or the <code>ConfigRuleId</code>. These values are generated by AWS Config for new rules.</p> <p>If you are updating a rule that you added previously, you can specify the rule by <code>ConfigRuleName</code>, <code>ConfigRuleId</code>, or <code>ConfigRuleArn</code> in the <code>ConfigRule</code> data type that you use in this request.</p> <p>The maximum number of rules that AWS Config supports is 150.</p> <p>For information about requesting a rule limit increase, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_config">AWS Config Limits</a> in the <i>AWS General Reference Guide</i>.</p> <p>For more information about developing and using AWS Config rules, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config.html">Evaluating AWS Resource Configurations with AWS Config</a> in the <i>AWS Config Developer Guide</i>.</p>
     fn put_config_rule(&self, input: PutConfigRuleRequest) -> RusotoFuture<(), PutConfigRuleError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.PutConfigRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutConfigRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_config_rule(input)
     }
-
-    /// <p><p>Creates and updates the configuration aggregator with the selected source accounts and regions. The source account can be individual account(s) or an organization.</p> <note> <p>AWS Config should be enabled in source accounts and regions you want to aggregate.</p> <p>If your source type is an organization, you must be signed in to the master account and all features must be enabled in your organization. AWS Config calls <code>EnableAwsServiceAccess</code> API to enable integration between AWS Config and AWS Organizations. </p> </note></p>
     fn put_configuration_aggregator(
         &self,
         input: PutConfigurationAggregatorRequest,
     ) -> RusotoFuture<PutConfigurationAggregatorResponse, PutConfigurationAggregatorError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.PutConfigurationAggregator",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutConfigurationAggregatorResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutConfigurationAggregatorError::from_response(response))
-                }))
-            }
-        })
+        (**self).put_configuration_aggregator(input)
     }
-
-    /// <p><p>Creates a new configuration recorder to record the selected resource configurations.</p> <p>You can use this action to change the role <code>roleARN</code> or the <code>recordingGroup</code> of an existing recorder. To change the role, call the action on the existing configuration recorder and specify a role.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> <p>If <code>ConfigurationRecorder</code> does not have the <b>recordingGroup</b> parameter specified, the default is to record all supported resource types.</p> </note></p>
     fn put_configuration_recorder(
         &self,
         input: PutConfigurationRecorderRequest,
     ) -> RusotoFuture<(), PutConfigurationRecorderError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.PutConfigurationRecorder",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutConfigurationRecorderError::from_response(response))
-                }))
-            }
-        })
+        (**self).put_configuration_recorder(input)
     }
-
-    /// <p><p>Creates a delivery channel object to deliver configuration information to an Amazon S3 bucket and Amazon SNS topic.</p> <p>Before you can create a delivery channel, you must create a configuration recorder.</p> <p>You can use this action to change the Amazon S3 bucket or an Amazon SNS topic of the existing delivery channel. To change the Amazon S3 bucket or an Amazon SNS topic, call this action and specify the changed values for the S3 bucket and the SNS topic. If you specify a different value for either the S3 bucket or the SNS topic, this action will keep the existing value for the parameter that is not changed.</p> <note> <p>You can have only one delivery channel per region in your account.</p> </note></p>
     fn put_delivery_channel(
         &self,
         input: PutDeliveryChannelRequest,
     ) -> RusotoFuture<(), PutDeliveryChannelError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.PutDeliveryChannel");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutDeliveryChannelError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_delivery_channel(input)
     }
-
-    /// <p>Used by an AWS Lambda function to deliver evaluation results to AWS Config. This action is required in every AWS Lambda function that is invoked by an AWS Config rule.</p>
     fn put_evaluations(
         &self,
         input: PutEvaluationsRequest,
     ) -> RusotoFuture<PutEvaluationsResponse, PutEvaluationsError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.PutEvaluations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutEvaluationsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutEvaluationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_evaluations(input)
     }
-
-    /// <p><p>Adds or updates organization config rule for your entire organization evaluating whether your AWS resources comply with your desired configurations. Only a master account can create or update an organization config rule.</p> <p>This API enables organization service access through the <code>EnableAWSServiceAccess</code> action and creates a service linked role <code>AWSServiceRoleForConfigMultiAccountSetup</code> in the master account of your organization. The service linked role is created only when the role does not exist in the master account. AWS Config verifies the existence of role with <code>GetRole</code> action.</p> <p>You can use this action to create both custom AWS Config rules and AWS managed Config rules. If you are adding a new custom AWS Config rule, you must first create AWS Lambda function in the master account that the rule invokes to evaluate your resources. When you use the <code>PutOrganizationConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. If you are adding an AWS managed Config rule, specify the rule&#39;s identifier for the <code>RuleIdentifier</code> key.</p> <p>The maximum number of organization config rules that AWS Config supports is 150.</p> <note> <p>Specify either <code>OrganizationCustomRuleMetadata</code> or <code>OrganizationManagedRuleMetadata</code>.</p> </note></p>
     fn put_organization_config_rule(
         &self,
         input: PutOrganizationConfigRuleRequest,
     ) -> RusotoFuture<PutOrganizationConfigRuleResponse, PutOrganizationConfigRuleError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
+        (**self).put_organization_config_rule(input)
+    }
+    fn put_remediation_configurations(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> RusotoFuture<PutRemediationConfigurationsResponse, PutRemediationConfigurationsError> {
+        (**self).put_remediation_configurations(input)
+    }
+    fn put_remediation_exceptions(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> RusotoFuture<PutRemediationExceptionsResponse, PutRemediationExceptionsError> {
+        (**self).put_remediation_exceptions(input)
+    }
+    fn put_retention_configuration(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> RusotoFuture<PutRetentionConfigurationResponse, PutRetentionConfigurationError> {
+        (**self).put_retention_configuration(input)
+    }
+    fn select_resource_config(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> RusotoFuture<SelectResourceConfigResponse, SelectResourceConfigError> {
+        (**self).select_resource_config(input)
+    }
+    fn start_config_rules_evaluation(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> RusotoFuture<StartConfigRulesEvaluationResponse, StartConfigRulesEvaluationError> {
+        (**self).start_config_rules_evaluation(input)
+    }
+    fn start_configuration_recorder(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), StartConfigurationRecorderError> {
+        (**self).start_configuration_recorder(input)
+    }
+    fn start_remediation_execution(
+        &self,
+        input: StartRemediationExecutionRequest,
+    ) -> RusotoFuture<StartRemediationExecutionResponse, StartRemediationExecutionError> {
+        (**self).start_remediation_execution(input)
+    }
+    fn stop_configuration_recorder(
+        &self,
+        input: StopConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), StopConfigurationRecorderError> {
+        (**self).stop_configuration_recorder(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: ConfigService + ?Sized> ConfigService for Box<T> {
+    fn batch_get_aggregate_resource_config(
+        &self,
+        input: BatchGetAggregateResourceConfigRequest,
+    ) -> RusotoFuture<BatchGetAggregateResourceConfigResponse, BatchGetAggregateResourceConfigError>
+    {
+        (**self).batch_get_aggregate_resource_config(input)
+    }
+    fn batch_get_resource_config(
+        &self,
+        input: BatchGetResourceConfigRequest,
+    ) -> RusotoFuture<BatchGetResourceConfigResponse, BatchGetResourceConfigError> {
+        (**self).batch_get_resource_config(input)
+    }
+    fn delete_aggregation_authorization(
+        &self,
+        input: DeleteAggregationAuthorizationRequest,
+    ) -> RusotoFuture<(), DeleteAggregationAuthorizationError> {
+        (**self).delete_aggregation_authorization(input)
+    }
+    fn delete_config_rule(
+        &self,
+        input: DeleteConfigRuleRequest,
+    ) -> RusotoFuture<(), DeleteConfigRuleError> {
+        (**self).delete_config_rule(input)
+    }
+    fn delete_configuration_aggregator(
+        &self,
+        input: DeleteConfigurationAggregatorRequest,
+    ) -> RusotoFuture<(), DeleteConfigurationAggregatorError> {
+        (**self).delete_configuration_aggregator(input)
+    }
+    fn delete_configuration_recorder(
+        &self,
+        input: DeleteConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), DeleteConfigurationRecorderError> {
+        (**self).delete_configuration_recorder(input)
+    }
+    fn delete_delivery_channel(
+        &self,
+        input: DeleteDeliveryChannelRequest,
+    ) -> RusotoFuture<(), DeleteDeliveryChannelError> {
+        (**self).delete_delivery_channel(input)
+    }
+    fn delete_evaluation_results(
+        &self,
+        input: DeleteEvaluationResultsRequest,
+    ) -> RusotoFuture<DeleteEvaluationResultsResponse, DeleteEvaluationResultsError> {
+        (**self).delete_evaluation_results(input)
+    }
+    fn delete_organization_config_rule(
+        &self,
+        input: DeleteOrganizationConfigRuleRequest,
+    ) -> RusotoFuture<(), DeleteOrganizationConfigRuleError> {
+        (**self).delete_organization_config_rule(input)
+    }
+    fn delete_pending_aggregation_request(
+        &self,
+        input: DeletePendingAggregationRequestRequest,
+    ) -> RusotoFuture<(), DeletePendingAggregationRequestError> {
+        (**self).delete_pending_aggregation_request(input)
+    }
+    fn delete_remediation_configuration(
+        &self,
+        input: DeleteRemediationConfigurationRequest,
+    ) -> RusotoFuture<DeleteRemediationConfigurationResponse, DeleteRemediationConfigurationError>
+    {
+        (**self).delete_remediation_configuration(input)
+    }
+    fn delete_remediation_exceptions(
+        &self,
+        input: DeleteRemediationExceptionsRequest,
+    ) -> RusotoFuture<DeleteRemediationExceptionsResponse, DeleteRemediationExceptionsError> {
+        (**self).delete_remediation_exceptions(input)
+    }
+    fn delete_retention_configuration(
+        &self,
+        input: DeleteRetentionConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteRetentionConfigurationError> {
+        (**self).delete_retention_configuration(input)
+    }
+    fn deliver_config_snapshot(
+        &self,
+        input: DeliverConfigSnapshotRequest,
+    ) -> RusotoFuture<DeliverConfigSnapshotResponse, DeliverConfigSnapshotError> {
+        (**self).deliver_config_snapshot(input)
+    }
+    fn describe_aggregate_compliance_by_config_rules(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> RusotoFuture<
+        DescribeAggregateComplianceByConfigRulesResponse,
+        DescribeAggregateComplianceByConfigRulesError,
+    > {
+        (**self).describe_aggregate_compliance_by_config_rules(input)
+    }
+    fn describe_aggregation_authorizations(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> RusotoFuture<
+        DescribeAggregationAuthorizationsResponse,
+        DescribeAggregationAuthorizationsError,
+    > {
+        (**self).describe_aggregation_authorizations(input)
+    }
+    fn describe_compliance_by_config_rule(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> RusotoFuture<DescribeComplianceByConfigRuleResponse, DescribeComplianceByConfigRuleError>
+    {
+        (**self).describe_compliance_by_config_rule(input)
+    }
+    fn describe_compliance_by_resource(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> RusotoFuture<DescribeComplianceByResourceResponse, DescribeComplianceByResourceError> {
+        (**self).describe_compliance_by_resource(input)
+    }
+    fn describe_config_rule_evaluation_status(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigRuleEvaluationStatusResponse,
+        DescribeConfigRuleEvaluationStatusError,
+    > {
+        (**self).describe_config_rule_evaluation_status(input)
+    }
+    fn describe_config_rules(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> RusotoFuture<DescribeConfigRulesResponse, DescribeConfigRulesError> {
+        (**self).describe_config_rules(input)
+    }
+    fn describe_configuration_aggregator_sources_status(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigurationAggregatorSourcesStatusResponse,
+        DescribeConfigurationAggregatorSourcesStatusError,
+    > {
+        (**self).describe_configuration_aggregator_sources_status(input)
+    }
+    fn describe_configuration_aggregators(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> RusotoFuture<DescribeConfigurationAggregatorsResponse, DescribeConfigurationAggregatorsError>
+    {
+        (**self).describe_configuration_aggregators(input)
+    }
+    fn describe_configuration_recorder_status(
+        &self,
+        input: DescribeConfigurationRecorderStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigurationRecorderStatusResponse,
+        DescribeConfigurationRecorderStatusError,
+    > {
+        (**self).describe_configuration_recorder_status(input)
+    }
+    fn describe_configuration_recorders(
+        &self,
+        input: DescribeConfigurationRecordersRequest,
+    ) -> RusotoFuture<DescribeConfigurationRecordersResponse, DescribeConfigurationRecordersError>
+    {
+        (**self).describe_configuration_recorders(input)
+    }
+    fn describe_delivery_channel_status(
+        &self,
+        input: DescribeDeliveryChannelStatusRequest,
+    ) -> RusotoFuture<DescribeDeliveryChannelStatusResponse, DescribeDeliveryChannelStatusError>
+    {
+        (**self).describe_delivery_channel_status(input)
+    }
+    fn describe_delivery_channels(
+        &self,
+        input: DescribeDeliveryChannelsRequest,
+    ) -> RusotoFuture<DescribeDeliveryChannelsResponse, DescribeDeliveryChannelsError> {
+        (**self).describe_delivery_channels(input)
+    }
+    fn describe_organization_config_rule_statuses(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> RusotoFuture<
+        DescribeOrganizationConfigRuleStatusesResponse,
+        DescribeOrganizationConfigRuleStatusesError,
+    > {
+        (**self).describe_organization_config_rule_statuses(input)
+    }
+    fn describe_organization_config_rules(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> RusotoFuture<DescribeOrganizationConfigRulesResponse, DescribeOrganizationConfigRulesError>
+    {
+        (**self).describe_organization_config_rules(input)
+    }
+    fn describe_pending_aggregation_requests(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> RusotoFuture<
+        DescribePendingAggregationRequestsResponse,
+        DescribePendingAggregationRequestsError,
+    > {
+        (**self).describe_pending_aggregation_requests(input)
+    }
+    fn describe_remediation_configurations(
+        &self,
+        input: DescribeRemediationConfigurationsRequest,
+    ) -> RusotoFuture<
+        DescribeRemediationConfigurationsResponse,
+        DescribeRemediationConfigurationsError,
+    > {
+        (**self).describe_remediation_configurations(input)
+    }
+    fn describe_remediation_exceptions(
+        &self,
+        input: DescribeRemediationExceptionsRequest,
+    ) -> RusotoFuture<DescribeRemediationExceptionsResponse, DescribeRemediationExceptionsError>
+    {
+        (**self).describe_remediation_exceptions(input)
+    }
+    fn describe_remediation_execution_status(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeRemediationExecutionStatusResponse,
+        DescribeRemediationExecutionStatusError,
+    > {
+        (**self).describe_remediation_execution_status(input)
+    }
+    fn describe_retention_configurations(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> RusotoFuture<DescribeRetentionConfigurationsResponse, DescribeRetentionConfigurationsError>
+    {
+        (**self).describe_retention_configurations(input)
+    }
+    fn get_aggregate_compliance_details_by_config_rule(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> RusotoFuture<
+        GetAggregateComplianceDetailsByConfigRuleResponse,
+        GetAggregateComplianceDetailsByConfigRuleError,
+    > {
+        (**self).get_aggregate_compliance_details_by_config_rule(input)
+    }
+    fn get_aggregate_config_rule_compliance_summary(
+        &self,
+        input: GetAggregateConfigRuleComplianceSummaryRequest,
+    ) -> RusotoFuture<
+        GetAggregateConfigRuleComplianceSummaryResponse,
+        GetAggregateConfigRuleComplianceSummaryError,
+    > {
+        (**self).get_aggregate_config_rule_compliance_summary(input)
+    }
+    fn get_aggregate_discovered_resource_counts(
+        &self,
+        input: GetAggregateDiscoveredResourceCountsRequest,
+    ) -> RusotoFuture<
+        GetAggregateDiscoveredResourceCountsResponse,
+        GetAggregateDiscoveredResourceCountsError,
+    > {
+        (**self).get_aggregate_discovered_resource_counts(input)
+    }
+    fn get_aggregate_resource_config(
+        &self,
+        input: GetAggregateResourceConfigRequest,
+    ) -> RusotoFuture<GetAggregateResourceConfigResponse, GetAggregateResourceConfigError> {
+        (**self).get_aggregate_resource_config(input)
+    }
+    fn get_compliance_details_by_config_rule(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> RusotoFuture<GetComplianceDetailsByConfigRuleResponse, GetComplianceDetailsByConfigRuleError>
+    {
+        (**self).get_compliance_details_by_config_rule(input)
+    }
+    fn get_compliance_details_by_resource(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> RusotoFuture<GetComplianceDetailsByResourceResponse, GetComplianceDetailsByResourceError>
+    {
+        (**self).get_compliance_details_by_resource(input)
+    }
+    fn get_compliance_summary_by_config_rule(
+        &self,
+    ) -> RusotoFuture<GetComplianceSummaryByConfigRuleResponse, GetComplianceSummaryByConfigRuleError>
+    {
+        (**self).get_compliance_summary_by_config_rule()
+    }
+    fn get_compliance_summary_by_resource_type(
+        &self,
+        input: GetComplianceSummaryByResourceTypeRequest,
+    ) -> RusotoFuture<
+        GetComplianceSummaryByResourceTypeResponse,
+        GetComplianceSummaryByResourceTypeError,
+    > {
+        (**self).get_compliance_summary_by_resource_type(input)
+    }
+    fn get_discovered_resource_counts(
+        &self,
+        input: GetDiscoveredResourceCountsRequest,
+    ) -> RusotoFuture<GetDiscoveredResourceCountsResponse, GetDiscoveredResourceCountsError> {
+        (**self).get_discovered_resource_counts(input)
+    }
+    fn get_organization_config_rule_detailed_status(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> RusotoFuture<
+        GetOrganizationConfigRuleDetailedStatusResponse,
+        GetOrganizationConfigRuleDetailedStatusError,
+    > {
+        (**self).get_organization_config_rule_detailed_status(input)
+    }
+    fn get_resource_config_history(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> RusotoFuture<GetResourceConfigHistoryResponse, GetResourceConfigHistoryError> {
+        (**self).get_resource_config_history(input)
+    }
+    fn list_aggregate_discovered_resources(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListAggregateDiscoveredResourcesResponse, ListAggregateDiscoveredResourcesError>
+    {
+        (**self).list_aggregate_discovered_resources(input)
+    }
+    fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListDiscoveredResourcesResponse, ListDiscoveredResourcesError> {
+        (**self).list_discovered_resources(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_aggregation_authorization(
+        &self,
+        input: PutAggregationAuthorizationRequest,
+    ) -> RusotoFuture<PutAggregationAuthorizationResponse, PutAggregationAuthorizationError> {
+        (**self).put_aggregation_authorization(input)
+    }
+    fn put_config_rule(&self, input: PutConfigRuleRequest) -> RusotoFuture<(), PutConfigRuleError> {
+        (**self).put_config_rule(input)
+    }
+    fn put_configuration_aggregator(
+        &self,
+        input: PutConfigurationAggregatorRequest,
+    ) -> RusotoFuture<PutConfigurationAggregatorResponse, PutConfigurationAggregatorError> {
+        (**self).put_configuration_aggregator(input)
+    }
+    fn put_configuration_recorder(
+        &self,
+        input: PutConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), PutConfigurationRecorderError> {
+        (**self).put_configuration_recorder(input)
+    }
+    fn put_delivery_channel(
+        &self,
+        input: PutDeliveryChannelRequest,
+    ) -> RusotoFuture<(), PutDeliveryChannelError> {
+        (**self).put_delivery_channel(input)
+    }
+    fn put_evaluations(
+        &self,
+        input: PutEvaluationsRequest,
+    ) -> RusotoFuture<PutEvaluationsResponse, PutEvaluationsError> {
+        (**self).put_evaluations(input)
+    }
+    fn put_organization_config_rule(
+        &self,
+        input: PutOrganizationConfigRuleRequest,
+    ) -> RusotoFuture<PutOrganizationConfigRuleResponse, PutOrganizationConfigRuleError> {
+        (**self).put_organization_config_rule(input)
+    }
+    fn put_remediation_configurations(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> RusotoFuture<PutRemediationConfigurationsResponse, PutRemediationConfigurationsError> {
+        (**self).put_remediation_configurations(input)
+    }
+    fn put_remediation_exceptions(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> RusotoFuture<PutRemediationExceptionsResponse, PutRemediationExceptionsError> {
+        (**self).put_remediation_exceptions(input)
+    }
+    fn put_retention_configuration(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> RusotoFuture<PutRetentionConfigurationResponse, PutRetentionConfigurationError> {
+        (**self).put_retention_configuration(input)
+    }
+    fn select_resource_config(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> RusotoFuture<SelectResourceConfigResponse, SelectResourceConfigError> {
+        (**self).select_resource_config(input)
+    }
+    fn start_config_rules_evaluation(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> RusotoFuture<StartConfigRulesEvaluationResponse, StartConfigRulesEvaluationError> {
+        (**self).start_config_rules_evaluation(input)
+    }
+    fn start_configuration_recorder(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), StartConfigurationRecorderError> {
+        (**self).start_configuration_recorder(input)
+    }
+    fn start_remediation_execution(
+        &self,
+        input: StartRemediationExecutionRequest,
+    ) -> RusotoFuture<StartRemediationExecutionResponse, StartRemediationExecutionError> {
+        (**self).start_remediation_execution(input)
+    }
+    fn stop_configuration_recorder(
+        &self,
+        input: StopConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), StopConfigurationRecorderError> {
+        (**self).stop_configuration_recorder(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: ConfigService + ?Sized> ConfigService for ::std::sync::Arc<T> {
+    fn batch_get_aggregate_resource_config(
+        &self,
+        input: BatchGetAggregateResourceConfigRequest,
+    ) -> RusotoFuture<BatchGetAggregateResourceConfigResponse, BatchGetAggregateResourceConfigError>
+    {
+        (**self).batch_get_aggregate_resource_config(input)
+    }
+    fn batch_get_resource_config(
+        &self,
+        input: BatchGetResourceConfigRequest,
+    ) -> RusotoFuture<BatchGetResourceConfigResponse, BatchGetResourceConfigError> {
+        (**self).batch_get_resource_config(input)
+    }
+    fn delete_aggregation_authorization(
+        &self,
+        input: DeleteAggregationAuthorizationRequest,
+    ) -> RusotoFuture<(), DeleteAggregationAuthorizationError> {
+        (**self).delete_aggregation_authorization(input)
+    }
+    fn delete_config_rule(
+        &self,
+        input: DeleteConfigRuleRequest,
+    ) -> RusotoFuture<(), DeleteConfigRuleError> {
+        (**self).delete_config_rule(input)
+    }
+    fn delete_configuration_aggregator(
+        &self,
+        input: DeleteConfigurationAggregatorRequest,
+    ) -> RusotoFuture<(), DeleteConfigurationAggregatorError> {
+        (**self).delete_configuration_aggregator(input)
+    }
+    fn delete_configuration_recorder(
+        &self,
+        input: DeleteConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), DeleteConfigurationRecorderError> {
+        (**self).delete_configuration_recorder(input)
+    }
+    fn delete_delivery_channel(
+        &self,
+        input: DeleteDeliveryChannelRequest,
+    ) -> RusotoFuture<(), DeleteDeliveryChannelError> {
+        (**self).delete_delivery_channel(input)
+    }
+    fn delete_evaluation_results(
+        &self,
+        input: DeleteEvaluationResultsRequest,
+    ) -> RusotoFuture<DeleteEvaluationResultsResponse, DeleteEvaluationResultsError> {
+        (**self).delete_evaluation_results(input)
+    }
+    fn delete_organization_config_rule(
+        &self,
+        input: DeleteOrganizationConfigRuleRequest,
+    ) -> RusotoFuture<(), DeleteOrganizationConfigRuleError> {
+        (**self).delete_organization_config_rule(input)
+    }
+    fn delete_pending_aggregation_request(
+        &self,
+        input: DeletePendingAggregationRequestRequest,
+    ) -> RusotoFuture<(), DeletePendingAggregationRequestError> {
+        (**self).delete_pending_aggregation_request(input)
+    }
+    fn delete_remediation_configuration(
+        &self,
+        input: DeleteRemediationConfigurationRequest,
+    ) -> RusotoFuture<DeleteRemediationConfigurationResponse, DeleteRemediationConfigurationError>
+    {
+        (**self).delete_remediation_configuration(input)
+    }
+    fn delete_remediation_exceptions(
+        &self,
+        input: DeleteRemediationExceptionsRequest,
+    ) -> RusotoFuture<DeleteRemediationExceptionsResponse, DeleteRemediationExceptionsError> {
+        (**self).delete_remediation_exceptions(input)
+    }
+    fn delete_retention_configuration(
+        &self,
+        input: DeleteRetentionConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteRetentionConfigurationError> {
+        (**self).delete_retention_configuration(input)
+    }
+    fn deliver_config_snapshot(
+        &self,
+        input: DeliverConfigSnapshotRequest,
+    ) -> RusotoFuture<DeliverConfigSnapshotResponse, DeliverConfigSnapshotError> {
+        (**self).deliver_config_snapshot(input)
+    }
+    fn describe_aggregate_compliance_by_config_rules(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> RusotoFuture<
+        DescribeAggregateComplianceByConfigRulesResponse,
+        DescribeAggregateComplianceByConfigRulesError,
+    > {
+        (**self).describe_aggregate_compliance_by_config_rules(input)
+    }
+    fn describe_aggregation_authorizations(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> RusotoFuture<
+        DescribeAggregationAuthorizationsResponse,
+        DescribeAggregationAuthorizationsError,
+    > {
+        (**self).describe_aggregation_authorizations(input)
+    }
+    fn describe_compliance_by_config_rule(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> RusotoFuture<DescribeComplianceByConfigRuleResponse, DescribeComplianceByConfigRuleError>
+    {
+        (**self).describe_compliance_by_config_rule(input)
+    }
+    fn describe_compliance_by_resource(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> RusotoFuture<DescribeComplianceByResourceResponse, DescribeComplianceByResourceError> {
+        (**self).describe_compliance_by_resource(input)
+    }
+    fn describe_config_rule_evaluation_status(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigRuleEvaluationStatusResponse,
+        DescribeConfigRuleEvaluationStatusError,
+    > {
+        (**self).describe_config_rule_evaluation_status(input)
+    }
+    fn describe_config_rules(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> RusotoFuture<DescribeConfigRulesResponse, DescribeConfigRulesError> {
+        (**self).describe_config_rules(input)
+    }
+    fn describe_configuration_aggregator_sources_status(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigurationAggregatorSourcesStatusResponse,
+        DescribeConfigurationAggregatorSourcesStatusError,
+    > {
+        (**self).describe_configuration_aggregator_sources_status(input)
+    }
+    fn describe_configuration_aggregators(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> RusotoFuture<DescribeConfigurationAggregatorsResponse, DescribeConfigurationAggregatorsError>
+    {
+        (**self).describe_configuration_aggregators(input)
+    }
+    fn describe_configuration_recorder_status(
+        &self,
+        input: DescribeConfigurationRecorderStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigurationRecorderStatusResponse,
+        DescribeConfigurationRecorderStatusError,
+    > {
+        (**self).describe_configuration_recorder_status(input)
+    }
+    fn describe_configuration_recorders(
+        &self,
+        input: DescribeConfigurationRecordersRequest,
+    ) -> RusotoFuture<DescribeConfigurationRecordersResponse, DescribeConfigurationRecordersError>
+    {
+        (**self).describe_configuration_recorders(input)
+    }
+    fn describe_delivery_channel_status(
+        &self,
+        input: DescribeDeliveryChannelStatusRequest,
+    ) -> RusotoFuture<DescribeDeliveryChannelStatusResponse, DescribeDeliveryChannelStatusError>
+    {
+        (**self).describe_delivery_channel_status(input)
+    }
+    fn describe_delivery_channels(
+        &self,
+        input: DescribeDeliveryChannelsRequest,
+    ) -> RusotoFuture<DescribeDeliveryChannelsResponse, DescribeDeliveryChannelsError> {
+        (**self).describe_delivery_channels(input)
+    }
+    fn describe_organization_config_rule_statuses(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> RusotoFuture<
+        DescribeOrganizationConfigRuleStatusesResponse,
+        DescribeOrganizationConfigRuleStatusesError,
+    > {
+        (**self).describe_organization_config_rule_statuses(input)
+    }
+    fn describe_organization_config_rules(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> RusotoFuture<DescribeOrganizationConfigRulesResponse, DescribeOrganizationConfigRulesError>
+    {
+        (**self).describe_organization_config_rules(input)
+    }
+    fn describe_pending_aggregation_requests(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> RusotoFuture<
+        DescribePendingAggregationRequestsResponse,
+        DescribePendingAggregationRequestsError,
+    > {
+        (**self).describe_pending_aggregation_requests(input)
+    }
+    fn describe_remediation_configurations(
+        &self,
+        input: DescribeRemediationConfigurationsRequest,
+    ) -> RusotoFuture<
+        DescribeRemediationConfigurationsResponse,
+        DescribeRemediationConfigurationsError,
+    > {
+        (**self).describe_remediation_configurations(input)
+    }
+    fn describe_remediation_exceptions(
+        &self,
+        input: DescribeRemediationExceptionsRequest,
+    ) -> RusotoFuture<DescribeRemediationExceptionsResponse, DescribeRemediationExceptionsError>
+    {
+        (**self).describe_remediation_exceptions(input)
+    }
+    fn describe_remediation_execution_status(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeRemediationExecutionStatusResponse,
+        DescribeRemediationExecutionStatusError,
+    > {
+        (**self).describe_remediation_execution_status(input)
+    }
+    fn describe_retention_configurations(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> RusotoFuture<DescribeRetentionConfigurationsResponse, DescribeRetentionConfigurationsError>
+    {
+        (**self).describe_retention_configurations(input)
+    }
+    fn get_aggregate_compliance_details_by_config_rule(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> RusotoFuture<
+        GetAggregateComplianceDetailsByConfigRuleResponse,
+        GetAggregateComplianceDetailsByConfigRuleError,
+    > {
+        (**self).get_aggregate_compliance_details_by_config_rule(input)
+    }
+    fn get_aggregate_config_rule_compliance_summary(
+        &self,
+        input: GetAggregateConfigRuleComplianceSummaryRequest,
+    ) -> RusotoFuture<
+        GetAggregateConfigRuleComplianceSummaryResponse,
+        GetAggregateConfigRuleComplianceSummaryError,
+    > {
+        (**self).get_aggregate_config_rule_compliance_summary(input)
+    }
+    fn get_aggregate_discovered_resource_counts(
+        &self,
+        input: GetAggregateDiscoveredResourceCountsRequest,
+    ) -> RusotoFuture<
+        GetAggregateDiscoveredResourceCountsResponse,
+        GetAggregateDiscoveredResourceCountsError,
+    > {
+        (**self).get_aggregate_discovered_resource_counts(input)
+    }
+    fn get_aggregate_resource_config(
+        &self,
+        input: GetAggregateResourceConfigRequest,
+    ) -> RusotoFuture<GetAggregateResourceConfigResponse, GetAggregateResourceConfigError> {
+        (**self).get_aggregate_resource_config(input)
+    }
+    fn get_compliance_details_by_config_rule(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> RusotoFuture<GetComplianceDetailsByConfigRuleResponse, GetComplianceDetailsByConfigRuleError>
+    {
+        (**self).get_compliance_details_by_config_rule(input)
+    }
+    fn get_compliance_details_by_resource(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> RusotoFuture<GetComplianceDetailsByResourceResponse, GetComplianceDetailsByResourceError>
+    {
+        (**self).get_compliance_details_by_resource(input)
+    }
+    fn get_compliance_summary_by_config_rule(
+        &self,
+    ) -> RusotoFuture<GetComplianceSummaryByConfigRuleResponse, GetComplianceSummaryByConfigRuleError>
+    {
+        (**self).get_compliance_summary_by_config_rule()
+    }
+    fn get_compliance_summary_by_resource_type(
+        &self,
+        input: GetComplianceSummaryByResourceTypeRequest,
+    ) -> RusotoFuture<
+        GetComplianceSummaryByResourceTypeResponse,
+        GetComplianceSummaryByResourceTypeError,
+    > {
+        (**self).get_compliance_summary_by_resource_type(input)
+    }
+    fn get_discovered_resource_counts(
+        &self,
+        input: GetDiscoveredResourceCountsRequest,
+    ) -> RusotoFuture<GetDiscoveredResourceCountsResponse, GetDiscoveredResourceCountsError> {
+        (**self).get_discovered_resource_counts(input)
+    }
+    fn get_organization_config_rule_detailed_status(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> RusotoFuture<
+        GetOrganizationConfigRuleDetailedStatusResponse,
+        GetOrganizationConfigRuleDetailedStatusError,
+    > {
+        (**self).get_organization_config_rule_detailed_status(input)
+    }
+    fn get_resource_config_history(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> RusotoFuture<GetResourceConfigHistoryResponse, GetResourceConfigHistoryError> {
+        (**self).get_resource_config_history(input)
+    }
+    fn list_aggregate_discovered_resources(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListAggregateDiscoveredResourcesResponse, ListAggregateDiscoveredResourcesError>
+    {
+        (**self).list_aggregate_discovered_resources(input)
+    }
+    fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListDiscoveredResourcesResponse, ListDiscoveredResourcesError> {
+        (**self).list_discovered_resources(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_aggregation_authorization(
+        &self,
+        input: PutAggregationAuthorizationRequest,
+    ) -> RusotoFuture<PutAggregationAuthorizationResponse, PutAggregationAuthorizationError> {
+        (**self).put_aggregation_authorization(input)
+    }
+    fn put_config_rule(&self, input: PutConfigRuleRequest) -> RusotoFuture<(), PutConfigRuleError> {
+        (**self).put_config_rule(input)
+    }
+    fn put_configuration_aggregator(
+        &self,
+        input: PutConfigurationAggregatorRequest,
+    ) -> RusotoFuture<PutConfigurationAggregatorResponse, PutConfigurationAggregatorError> {
+        (**self).put_configuration_aggregator(input)
+    }
+    fn put_configuration_recorder(
+        &self,
+        input: PutConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), PutConfigurationRecorderError> {
+        (**self).put_configuration_recorder(input)
+    }
+    fn put_delivery_channel(
+        &self,
+        input: PutDeliveryChannelRequest,
+    ) -> RusotoFuture<(), PutDeliveryChannelError> {
+        (**self).put_delivery_channel(input)
+    }
+    fn put_evaluations(
+        &self,
+        input: PutEvaluationsRequest,
+    ) -> RusotoFuture<PutEvaluationsResponse, PutEvaluationsError> {
+        (**self).put_evaluations(input)
+    }
+    fn put_organization_config_rule(
+        &self,
+        input: PutOrganizationConfigRuleRequest,
+    ) -> RusotoFuture<PutOrganizationConfigRuleResponse, PutOrganizationConfigRuleError> {
+        (**self).put_organization_config_rule(input)
+    }
+    fn put_remediation_configurations(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> RusotoFuture<PutRemediationConfigurationsResponse, PutRemediationConfigurationsError> {
+        (**self).put_remediation_configurations(input)
+    }
+    fn put_remediation_exceptions(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> RusotoFuture<PutRemediationExceptionsResponse, PutRemediationExceptionsError> {
+        (**self).put_remediation_exceptions(input)
+    }
+    fn put_retention_configuration(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> RusotoFuture<PutRetentionConfigurationResponse, PutRetentionConfigurationError> {
+        (**self).put_retention_configuration(input)
+    }
+    fn select_resource_config(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> RusotoFuture<SelectResourceConfigResponse, SelectResourceConfigError> {
+        (**self).select_resource_config(input)
+    }
+    fn start_config_rules_evaluation(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> RusotoFuture<StartConfigRulesEvaluationResponse, StartConfigRulesEvaluationError> {
+        (**self).start_config_rules_evaluation(input)
+    }
+    fn start_configuration_recorder(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), StartConfigurationRecorderError> {
+        (**self).start_configuration_recorder(input)
+    }
+    fn start_remediation_execution(
+        &self,
+        input: StartRemediationExecutionRequest,
+    ) -> RusotoFuture<StartRemediationExecutionResponse, StartRemediationExecutionError> {
+        (**self).start_remediation_execution(input)
+    }
+    fn stop_configuration_recorder(
+        &self,
+        input: StopConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), StopConfigurationRecorderError> {
+        (**self).stop_configuration_recorder(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// A client for the Config Service API.
+#[derive(Clone)]
+pub struct ConfigServiceClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ConfigServiceClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ConfigServiceClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ConfigServiceClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ConfigServiceClient {
+        ConfigServiceClient { client, region }
+    }
+}
+
+impl ConfigService for ConfigServiceClient {
+    /// <p><p>Returns the current configuration items for resources that are present in your AWS Config aggregator. The operation also returns a list of resources that are not processed in the current request. If there are no unprocessed resources, the operation returns an empty <code>unprocessedResourceIdentifiers</code> list. </p> <note> <ul> <li> <p>The API does not return results for deleted resources.</p> </li> <li> <p> The API does not return tags and relationships.</p> </li> </ul> </note></p>
+    fn batch_get_aggregate_resource_config(
+        &self,
+        input: BatchGetAggregateResourceConfigRequest,
+    ) -> RusotoFuture<BatchGetAggregateResourceConfigResponse, BatchGetAggregateResourceConfigError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("BatchGetAggregateResourceConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.BatchGetAggregateResourceConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetAggregateResourceConfigResponse, _>(
+                    response,
+                    "BatchGetAggregateResourceConfig",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchGetAggregateResourceConfigError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns the current configuration for one or more requested resources. The operation also returns a list of resources that are not processed in the current request. If there are no unprocessed resources, the operation returns an empty unprocessedResourceKeys list. </p> <note> <ul> <li> <p>The API does not return results for deleted resources.</p> </li> <li> <p> The API does not return any tags for the requested resources. This information is filtered out of the supplementaryConfiguration section of the API response.</p> </li> </ul> </note></p>
+    fn batch_get_resource_config(
+        &self,
+        input: BatchGetResourceConfigRequest,
+    ) -> RusotoFuture<BatchGetResourceConfigResponse, BatchGetResourceConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("BatchGetResourceConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.BatchGetResourceConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetResourceConfigResponse, _>(
+                    response,
+                    "BatchGetResourceConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchGetResourceConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the authorization granted to the specified configuration aggregator account in a specified region.</p>
+    fn delete_aggregation_authorization(
+        &self,
+        input: DeleteAggregationAuthorizationRequest,
+    ) -> RusotoFuture<(), DeleteAggregationAuthorizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteAggregationAuthorization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DeleteAggregationAuthorization",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteAggregationAuthorizationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified AWS Config rule and all of its evaluation results.</p> <p>AWS Config sets the state of a rule to <code>DELETING</code> until the deletion is complete. You cannot update a rule while it is in this state. If you make a <code>PutConfigRule</code> or <code>DeleteConfigRule</code> request for the rule, you will receive a <code>ResourceInUseException</code>.</p> <p>You can check the state of a rule by using the <code>DescribeConfigRules</code> request.</p>
+    fn delete_config_rule(
+        &self,
+        input: DeleteConfigRuleRequest,
+    ) -> RusotoFuture<(), DeleteConfigRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConfigRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.DeleteConfigRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteConfigRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified configuration aggregator and the aggregated data associated with the aggregator.</p>
+    fn delete_configuration_aggregator(
+        &self,
+        input: DeleteConfigurationAggregatorRequest,
+    ) -> RusotoFuture<(), DeleteConfigurationAggregatorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConfigurationAggregator");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DeleteConfigurationAggregator",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteConfigurationAggregatorError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the configuration recorder.</p> <p>After the configuration recorder is deleted, AWS Config will not record resource configuration changes until you create a new configuration recorder.</p> <p>This action does not delete the configuration information that was previously recorded. You will be able to access the previously recorded information by using the <code>GetResourceConfigHistory</code> action, but you will not be able to access this information in the AWS Config console until you create a new configuration recorder.</p>
+    fn delete_configuration_recorder(
+        &self,
+        input: DeleteConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), DeleteConfigurationRecorderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConfigurationRecorder");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DeleteConfigurationRecorder",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteConfigurationRecorderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the delivery channel.</p> <p>Before you can delete the delivery channel, you must stop the configuration recorder by using the <a>StopConfigurationRecorder</a> action.</p>
+    fn delete_delivery_channel(
+        &self,
+        input: DeleteDeliveryChannelRequest,
+    ) -> RusotoFuture<(), DeleteDeliveryChannelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteDeliveryChannel");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.DeleteDeliveryChannel");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteDeliveryChannelError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the evaluation results for the specified AWS Config rule. You can specify one AWS Config rule per request. After you delete the evaluation results, you can call the <a>StartConfigRulesEvaluation</a> API to start evaluating your AWS resources against the rule.</p>
+    fn delete_evaluation_results(
+        &self,
+        input: DeleteEvaluationResultsRequest,
+    ) -> RusotoFuture<DeleteEvaluationResultsResponse, DeleteEvaluationResultsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteEvaluationResults");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DeleteEvaluationResults",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteEvaluationResultsResponse, _>(
+                    response,
+                    "DeleteEvaluationResults",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteEvaluationResultsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified organization config rule and all of its evaluation results from all member accounts in that organization. Only a master account can delete an organization config rule.</p> <p>AWS Config sets the state of a rule to DELETE_IN_PROGRESS until the deletion is complete. You cannot update a rule while it is in this state.</p>
+    fn delete_organization_config_rule(
+        &self,
+        input: DeleteOrganizationConfigRuleRequest,
+    ) -> RusotoFuture<(), DeleteOrganizationConfigRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteOrganizationConfigRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DeleteOrganizationConfigRule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteOrganizationConfigRuleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes pending authorization requests for a specified aggregator account in a specified region.</p>
+    fn delete_pending_aggregation_request(
+        &self,
+        input: DeletePendingAggregationRequestRequest,
+    ) -> RusotoFuture<(), DeletePendingAggregationRequestError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeletePendingAggregationRequest");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DeletePendingAggregationRequest",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeletePendingAggregationRequestError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the remediation configuration.</p>
+    fn delete_remediation_configuration(
+        &self,
+        input: DeleteRemediationConfigurationRequest,
+    ) -> RusotoFuture<DeleteRemediationConfigurationResponse, DeleteRemediationConfigurationError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteRemediationConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DeleteRemediationConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRemediationConfigurationResponse, _>(
+                    response,
+                    "DeleteRemediationConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteRemediationConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes one or more remediation exceptions mentioned in the resource keys.</p>
+    fn delete_remediation_exceptions(
+        &self,
+        input: DeleteRemediationExceptionsRequest,
+    ) -> RusotoFuture<DeleteRemediationExceptionsResponse, DeleteRemediationExceptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteRemediationExceptions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DeleteRemediationExceptions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRemediationExceptionsResponse, _>(
+                    response,
+                    "DeleteRemediationExceptions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteRemediationExceptionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the retention configuration.</p>
+    fn delete_retention_configuration(
+        &self,
+        input: DeleteRetentionConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteRetentionConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteRetentionConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DeleteRetentionConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteRetentionConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Schedules delivery of a configuration snapshot to the Amazon S3 bucket in the specified delivery channel. After the delivery has started, AWS Config sends the following notifications using an Amazon SNS topic that you have specified.</p> <ul> <li> <p>Notification of the start of the delivery.</p> </li> <li> <p>Notification of the completion of the delivery, if the delivery was successfully completed.</p> </li> <li> <p>Notification of delivery failure, if the delivery failed.</p> </li> </ul></p>
+    fn deliver_config_snapshot(
+        &self,
+        input: DeliverConfigSnapshotRequest,
+    ) -> RusotoFuture<DeliverConfigSnapshotResponse, DeliverConfigSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeliverConfigSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.DeliverConfigSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeliverConfigSnapshotResponse, _>(
+                    response,
+                    "DeliverConfigSnapshot",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeliverConfigSnapshotError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Returns a list of compliant and noncompliant rules with the number of resources for compliant and noncompliant rules. </p> <note> <p>The results can return an empty result page, but if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
+    fn describe_aggregate_compliance_by_config_rules(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> RusotoFuture<
+        DescribeAggregateComplianceByConfigRulesResponse,
+        DescribeAggregateComplianceByConfigRulesError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeAggregateComplianceByConfigRules");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeAggregateComplianceByConfigRules",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DescribeAggregateComplianceByConfigRulesResponse,
+                    _,
+                >(response, "DescribeAggregateComplianceByConfigRules")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAggregateComplianceByConfigRulesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of authorizations granted to various aggregator accounts and regions.</p>
+    fn describe_aggregation_authorizations(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> RusotoFuture<
+        DescribeAggregationAuthorizationsResponse,
+        DescribeAggregationAuthorizationsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeAggregationAuthorizations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeAggregationAuthorizations",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAggregationAuthorizationsResponse, _>(
+                    response,
+                    "DescribeAggregationAuthorizations",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAggregationAuthorizationsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Indicates whether the specified AWS Config rules are compliant. If a rule is noncompliant, this action returns the number of AWS resources that do not comply with the rule.</p> <p>A rule is compliant if all of the evaluated resources comply with it. It is noncompliant if any of these resources do not comply.</p> <p>If AWS Config has no current evaluation results for the rule, it returns <code>INSUFFICIENT<em>DATA</code>. This result might indicate one of the following conditions:</p> <ul> <li> <p>AWS Config has never invoked an evaluation for the rule. To check whether it has, use the <code>DescribeConfigRuleEvaluationStatus</code> action to get the <code>LastSuccessfulInvocationTime</code> and <code>LastFailedInvocationTime</code>.</p> </li> <li> <p>The rule&#39;s AWS Lambda function is failing to send evaluation results to AWS Config. Verify that the role you assigned to your configuration recorder includes the <code>config:PutEvaluations</code> permission. If the rule is a custom rule, verify that the AWS Lambda execution role includes the <code>config:PutEvaluations</code> permission.</p> </li> <li> <p>The rule&#39;s AWS Lambda function has returned <code>NOT</em>APPLICABLE</code> for all evaluation results. This can occur if the resources were deleted or removed from the rule&#39;s scope.</p> </li> </ul></p>
+    fn describe_compliance_by_config_rule(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> RusotoFuture<DescribeComplianceByConfigRuleResponse, DescribeComplianceByConfigRuleError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeComplianceByConfigRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeComplianceByConfigRule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeComplianceByConfigRuleResponse, _>(
+                    response,
+                    "DescribeComplianceByConfigRule",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeComplianceByConfigRuleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Indicates whether the specified AWS resources are compliant. If a resource is noncompliant, this action returns the number of AWS Config rules that the resource does not comply with.</p> <p>A resource is compliant if it complies with all the AWS Config rules that evaluate it. It is noncompliant if it does not comply with one or more of these rules.</p> <p>If AWS Config has no current evaluation results for the resource, it returns <code>INSUFFICIENT<em>DATA</code>. This result might indicate one of the following conditions about the rules that evaluate the resource:</p> <ul> <li> <p>AWS Config has never invoked an evaluation for the rule. To check whether it has, use the <code>DescribeConfigRuleEvaluationStatus</code> action to get the <code>LastSuccessfulInvocationTime</code> and <code>LastFailedInvocationTime</code>.</p> </li> <li> <p>The rule&#39;s AWS Lambda function is failing to send evaluation results to AWS Config. Verify that the role that you assigned to your configuration recorder includes the <code>config:PutEvaluations</code> permission. If the rule is a custom rule, verify that the AWS Lambda execution role includes the <code>config:PutEvaluations</code> permission.</p> </li> <li> <p>The rule&#39;s AWS Lambda function has returned <code>NOT</em>APPLICABLE</code> for all evaluation results. This can occur if the resources were deleted or removed from the rule&#39;s scope.</p> </li> </ul></p>
+    fn describe_compliance_by_resource(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> RusotoFuture<DescribeComplianceByResourceResponse, DescribeComplianceByResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeComplianceByResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeComplianceByResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeComplianceByResourceResponse, _>(
+                    response,
+                    "DescribeComplianceByResource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeComplianceByResourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns status information for each of your AWS managed Config rules. The status includes information such as the last time AWS Config invoked the rule, the last time AWS Config failed to invoke the rule, and the related error for the last failure.</p>
+    fn describe_config_rule_evaluation_status(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigRuleEvaluationStatusResponse,
+        DescribeConfigRuleEvaluationStatusError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigRuleEvaluationStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeConfigRuleEvaluationStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeConfigRuleEvaluationStatusResponse, _>(
+                    response,
+                    "DescribeConfigRuleEvaluationStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeConfigRuleEvaluationStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns details about your AWS Config rules.</p>
+    fn describe_config_rules(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> RusotoFuture<DescribeConfigRulesResponse, DescribeConfigRulesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigRules");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.DescribeConfigRules");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeConfigRulesResponse, _>(
+                    response,
+                    "DescribeConfigRules",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeConfigRulesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns status information for sources within an aggregator. The status includes information about the last time AWS Config verified authorization between the source account and an aggregator account. In case of a failure, the status contains the related error code or message. </p>
+    fn describe_configuration_aggregator_sources_status(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigurationAggregatorSourcesStatusResponse,
+        DescribeConfigurationAggregatorSourcesStatusError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationAggregatorSourcesStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeConfigurationAggregatorSourcesStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DescribeConfigurationAggregatorSourcesStatusResponse,
+                    _,
+                >(response, "DescribeConfigurationAggregatorSourcesStatus")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeConfigurationAggregatorSourcesStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the details of one or more configuration aggregators. If the configuration aggregator is not specified, this action returns the details for all the configuration aggregators associated with the account. </p>
+    fn describe_configuration_aggregators(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> RusotoFuture<DescribeConfigurationAggregatorsResponse, DescribeConfigurationAggregatorsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationAggregators");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeConfigurationAggregators",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeConfigurationAggregatorsResponse, _>(
+                    response,
+                    "DescribeConfigurationAggregators",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeConfigurationAggregatorsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns the current status of the specified configuration recorder. If a configuration recorder is not specified, this action returns the status of all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
+    fn describe_configuration_recorder_status(
+        &self,
+        input: DescribeConfigurationRecorderStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigurationRecorderStatusResponse,
+        DescribeConfigurationRecorderStatusError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationRecorderStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeConfigurationRecorderStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeConfigurationRecorderStatusResponse, _>(response, "DescribeConfigurationRecorderStatus")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeConfigurationRecorderStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns the details for the specified configuration recorders. If the configuration recorder is not specified, this action returns the details for all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
+    fn describe_configuration_recorders(
+        &self,
+        input: DescribeConfigurationRecordersRequest,
+    ) -> RusotoFuture<DescribeConfigurationRecordersResponse, DescribeConfigurationRecordersError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationRecorders");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeConfigurationRecorders",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeConfigurationRecordersResponse, _>(
+                    response,
+                    "DescribeConfigurationRecorders",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeConfigurationRecordersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns the current status of the specified delivery channel. If a delivery channel is not specified, this action returns the current status of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
+    fn describe_delivery_channel_status(
+        &self,
+        input: DescribeDeliveryChannelStatusRequest,
+    ) -> RusotoFuture<DescribeDeliveryChannelStatusResponse, DescribeDeliveryChannelStatusError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeDeliveryChannelStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeDeliveryChannelStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDeliveryChannelStatusResponse, _>(
+                    response,
+                    "DescribeDeliveryChannelStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDeliveryChannelStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns details about the specified delivery channel. If a delivery channel is not specified, this action returns the details of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
+    fn describe_delivery_channels(
+        &self,
+        input: DescribeDeliveryChannelsRequest,
+    ) -> RusotoFuture<DescribeDeliveryChannelsResponse, DescribeDeliveryChannelsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeDeliveryChannels");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeDeliveryChannels",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDeliveryChannelsResponse, _>(
+                    response,
+                    "DescribeDeliveryChannels",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDeliveryChannelsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Provides organization config rule deployment status for an organization.</p> <note> <p>The status is not considered successful until organization config rule is successfully deployed in all the member accounts with an exception of excluded accounts.</p> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
+    fn describe_organization_config_rule_statuses(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> RusotoFuture<
+        DescribeOrganizationConfigRuleStatusesResponse,
+        DescribeOrganizationConfigRuleStatusesError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeOrganizationConfigRuleStatuses");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeOrganizationConfigRuleStatuses",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DescribeOrganizationConfigRuleStatusesResponse,
+                    _,
+                >(response, "DescribeOrganizationConfigRuleStatuses")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeOrganizationConfigRuleStatusesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns a list of organization config rules.</p> <note> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
+    fn describe_organization_config_rules(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> RusotoFuture<DescribeOrganizationConfigRulesResponse, DescribeOrganizationConfigRulesError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeOrganizationConfigRules");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeOrganizationConfigRules",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeOrganizationConfigRulesResponse, _>(
+                    response,
+                    "DescribeOrganizationConfigRules",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeOrganizationConfigRulesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of all pending aggregation requests.</p>
+    fn describe_pending_aggregation_requests(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> RusotoFuture<
+        DescribePendingAggregationRequestsResponse,
+        DescribePendingAggregationRequestsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribePendingAggregationRequests");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribePendingAggregationRequests",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePendingAggregationRequestsResponse, _>(
+                    response,
+                    "DescribePendingAggregationRequests",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribePendingAggregationRequestsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the details of one or more remediation configurations.</p>
+    fn describe_remediation_configurations(
+        &self,
+        input: DescribeRemediationConfigurationsRequest,
+    ) -> RusotoFuture<
+        DescribeRemediationConfigurationsResponse,
+        DescribeRemediationConfigurationsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRemediationConfigurations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeRemediationConfigurations",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRemediationConfigurationsResponse, _>(
+                    response,
+                    "DescribeRemediationConfigurations",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeRemediationConfigurationsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns the details of one or more remediation exceptions. A detailed view of a remediation exception for a set of resources that includes an explanation of an exception and the time when the exception will be deleted. When you specify the limit and the next token, you receive a paginated response. </p> <note> <p>When you specify the limit and the next token, you receive a paginated response. </p> <p>Limit and next token are not applicable if you request resources in batch. It is only applicable, when you request all resources.</p> </note></p>
+    fn describe_remediation_exceptions(
+        &self,
+        input: DescribeRemediationExceptionsRequest,
+    ) -> RusotoFuture<DescribeRemediationExceptionsResponse, DescribeRemediationExceptionsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRemediationExceptions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeRemediationExceptions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRemediationExceptionsResponse, _>(
+                    response,
+                    "DescribeRemediationExceptions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeRemediationExceptionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides a detailed view of a Remediation Execution for a set of resources including state, timestamps for when steps for the remediation execution occur, and any error messages for steps that have failed. When you specify the limit and the next token, you receive a paginated response.</p>
+    fn describe_remediation_execution_status(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeRemediationExecutionStatusResponse,
+        DescribeRemediationExecutionStatusError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRemediationExecutionStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeRemediationExecutionStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRemediationExecutionStatusResponse, _>(
+                    response,
+                    "DescribeRemediationExecutionStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeRemediationExecutionStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns the details of one or more retention configurations. If the retention configuration name is not specified, this action returns the details for all the retention configurations for that account.</p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    fn describe_retention_configurations(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> RusotoFuture<DescribeRetentionConfigurationsResponse, DescribeRetentionConfigurationsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRetentionConfigurations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.DescribeRetentionConfigurations",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRetentionConfigurationsResponse, _>(
+                    response,
+                    "DescribeRetentionConfigurations",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeRetentionConfigurationsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns the evaluation results for the specified AWS Config rule for a specific resource in a rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule. </p> <note> <p>The results can return an empty result page. But if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
+    fn get_aggregate_compliance_details_by_config_rule(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> RusotoFuture<
+        GetAggregateComplianceDetailsByConfigRuleResponse,
+        GetAggregateComplianceDetailsByConfigRuleError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateComplianceDetailsByConfigRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetAggregateComplianceDetailsByConfigRule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    GetAggregateComplianceDetailsByConfigRuleResponse,
+                    _,
+                >(response, "GetAggregateComplianceDetailsByConfigRule")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetAggregateComplianceDetailsByConfigRuleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns the number of compliant and noncompliant rules for one or more accounts and regions in an aggregator.</p> <note> <p>The results can return an empty result page, but if you have a nextToken, the results are displayed on the next page.</p> </note></p>
+    fn get_aggregate_config_rule_compliance_summary(
+        &self,
+        input: GetAggregateConfigRuleComplianceSummaryRequest,
+    ) -> RusotoFuture<
+        GetAggregateConfigRuleComplianceSummaryResponse,
+        GetAggregateConfigRuleComplianceSummaryError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateConfigRuleComplianceSummary");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetAggregateConfigRuleComplianceSummary",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    GetAggregateConfigRuleComplianceSummaryResponse,
+                    _,
+                >(response, "GetAggregateConfigRuleComplianceSummary")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetAggregateConfigRuleComplianceSummaryError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the resource counts across accounts and regions that are present in your AWS Config aggregator. You can request the resource counts by providing filters and GroupByKey.</p> <p>For example, if the input contains accountID 12345678910 and region us-east-1 in filters, the API returns the count of resources in account ID 12345678910 and region us-east-1. If the input contains ACCOUNT_ID as a GroupByKey, the API returns resource counts for all source accounts that are present in your aggregator.</p>
+    fn get_aggregate_discovered_resource_counts(
+        &self,
+        input: GetAggregateDiscoveredResourceCountsRequest,
+    ) -> RusotoFuture<
+        GetAggregateDiscoveredResourceCountsResponse,
+        GetAggregateDiscoveredResourceCountsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateDiscoveredResourceCounts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetAggregateDiscoveredResourceCounts",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAggregateDiscoveredResourceCountsResponse, _>(response, "GetAggregateDiscoveredResourceCounts")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetAggregateDiscoveredResourceCountsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns configuration item that is aggregated for your specific resource in a specific source account and region.</p>
+    fn get_aggregate_resource_config(
+        &self,
+        input: GetAggregateResourceConfigRequest,
+    ) -> RusotoFuture<GetAggregateResourceConfigResponse, GetAggregateResourceConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateResourceConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetAggregateResourceConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAggregateResourceConfigResponse, _>(
+                    response,
+                    "GetAggregateResourceConfig",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetAggregateResourceConfigError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the evaluation results for the specified AWS Config rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule.</p>
+    fn get_compliance_details_by_config_rule(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> RusotoFuture<GetComplianceDetailsByConfigRuleResponse, GetComplianceDetailsByConfigRuleError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceDetailsByConfigRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetComplianceDetailsByConfigRule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetComplianceDetailsByConfigRuleResponse, _>(
+                    response,
+                    "GetComplianceDetailsByConfigRule",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetComplianceDetailsByConfigRuleError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the evaluation results for the specified AWS resource. The results indicate which AWS Config rules were used to evaluate the resource, when each rule was last used, and whether the resource complies with each rule.</p>
+    fn get_compliance_details_by_resource(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> RusotoFuture<GetComplianceDetailsByResourceResponse, GetComplianceDetailsByResourceError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceDetailsByResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetComplianceDetailsByResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetComplianceDetailsByResourceResponse, _>(
+                    response,
+                    "GetComplianceDetailsByResource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetComplianceDetailsByResourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the number of AWS Config rules that are compliant and noncompliant, up to a maximum of 25 for each.</p>
+    fn get_compliance_summary_by_config_rule(
+        &self,
+    ) -> RusotoFuture<GetComplianceSummaryByConfigRuleResponse, GetComplianceSummaryByConfigRuleError>
+    {
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceSummaryByConfigRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetComplianceSummaryByConfigRule",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetComplianceSummaryByConfigRuleResponse, _>(
+                    response,
+                    "GetComplianceSummaryByConfigRule",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetComplianceSummaryByConfigRuleError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the number of resources that are compliant and the number that are noncompliant. You can specify one or more resource types to get these numbers for each resource type. The maximum number returned is 100.</p>
+    fn get_compliance_summary_by_resource_type(
+        &self,
+        input: GetComplianceSummaryByResourceTypeRequest,
+    ) -> RusotoFuture<
+        GetComplianceSummaryByResourceTypeResponse,
+        GetComplianceSummaryByResourceTypeError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceSummaryByResourceType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetComplianceSummaryByResourceType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetComplianceSummaryByResourceTypeResponse, _>(
+                    response,
+                    "GetComplianceSummaryByResourceType",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetComplianceSummaryByResourceTypeError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns the resource types, the number of each resource type, and the total number of resources that AWS Config is recording in this region for your AWS account. </p> <p class="title"> <b>Example</b> </p> <ol> <li> <p>AWS Config is recording three resource types in the US East (Ohio) Region for your account: 25 EC2 instances, 20 IAM users, and 15 S3 buckets.</p> </li> <li> <p>You make a call to the <code>GetDiscoveredResourceCounts</code> action and specify that you want all resource types. </p> </li> <li> <p>AWS Config returns the following:</p> <ul> <li> <p>The resource types (EC2 instances, IAM users, and S3 buckets).</p> </li> <li> <p>The number of each resource type (25, 20, and 15).</p> </li> <li> <p>The total number of all resources (60).</p> </li> </ul> </li> </ol> <p>The response is paginated. By default, AWS Config lists 100 <a>ResourceCount</a> objects on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>If you make a call to the <a>GetDiscoveredResourceCounts</a> action, you might not immediately receive resource counts in the following situations:</p> <ul> <li> <p>You are a new AWS Config customer.</p> </li> <li> <p>You just enabled resource recording.</p> </li> </ul> <p>It might take a few minutes for AWS Config to record and count your resources. Wait a few minutes and then retry the <a>GetDiscoveredResourceCounts</a> action. </p> </note></p>
+    fn get_discovered_resource_counts(
+        &self,
+        input: GetDiscoveredResourceCountsRequest,
+    ) -> RusotoFuture<GetDiscoveredResourceCountsResponse, GetDiscoveredResourceCountsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetDiscoveredResourceCounts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetDiscoveredResourceCounts",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDiscoveredResourceCountsResponse, _>(
+                    response,
+                    "GetDiscoveredResourceCounts",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetDiscoveredResourceCountsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns detailed status for each member account within an organization for a given organization config rule.</p> <note> <p>Only a master account can call this API.</p> </note></p>
+    fn get_organization_config_rule_detailed_status(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> RusotoFuture<
+        GetOrganizationConfigRuleDetailedStatusResponse,
+        GetOrganizationConfigRuleDetailedStatusError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetOrganizationConfigRuleDetailedStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetOrganizationConfigRuleDetailedStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    GetOrganizationConfigRuleDetailedStatusResponse,
+                    _,
+                >(response, "GetOrganizationConfigRuleDetailedStatus")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetOrganizationConfigRuleDetailedStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns a list of configuration items for the specified resource. The list contains details about each state of the resource during the specified time interval. If you specified a retention period to retain your <code>ConfigurationItems</code> between a minimum of 30 days and a maximum of 7 years (2557 days), AWS Config returns the <code>ConfigurationItems</code> for the specified retention period. </p> <p>The response is paginated. By default, AWS Config returns a limit of 10 configuration items per page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>Each call to the API is limited to span a duration of seven days. It is likely that the number of records returned is smaller than the specified <code>limit</code>. In such cases, you can make another call, using the <code>nextToken</code>.</p> </note></p>
+    fn get_resource_config_history(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> RusotoFuture<GetResourceConfigHistoryResponse, GetResourceConfigHistoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetResourceConfigHistory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetResourceConfigHistory",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetResourceConfigHistoryResponse, _>(
+                    response,
+                    "GetResourceConfigHistory",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetResourceConfigHistoryError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Accepts a resource type and returns a list of resource identifiers that are aggregated for a specific resource type across accounts and regions. A resource identifier includes the resource type, ID, (if available) the custom resource name, source account, and source region. You can narrow the results to include only resources that have specific resource IDs, or a resource name, or source account ID, or source region.</p> <p>For example, if the input consists of accountID 12345678910 and the region is us-east-1 for resource type <code>AWS::EC2::Instance</code> then the API returns all the EC2 instance identifiers of accountID 12345678910 and region us-east-1.</p>
+    fn list_aggregate_discovered_resources(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListAggregateDiscoveredResourcesResponse, ListAggregateDiscoveredResourcesError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("ListAggregateDiscoveredResources");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.ListAggregateDiscoveredResources",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAggregateDiscoveredResourcesResponse, _>(
+                    response,
+                    "ListAggregateDiscoveredResources",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAggregateDiscoveredResourcesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Accepts a resource type and returns a list of resource identifiers for the resources of that type. A resource identifier includes the resource type, ID, and (if available) the custom resource name. The results consist of resources that AWS Config has discovered, including those that AWS Config is not currently recording. You can narrow the results to include only resources that have specific resource IDs or a resource name.</p> <note> <p>You can specify either resource IDs or a resource name, but not both, in the same request.</p> </note> <p>The response is paginated. By default, AWS Config lists 100 resource identifiers on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p>
+    fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListDiscoveredResourcesResponse, ListDiscoveredResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("ListDiscoveredResources");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.ListDiscoveredResources",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDiscoveredResourcesResponse, _>(
+                    response,
+                    "ListDiscoveredResources",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListDiscoveredResourcesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>List the tags for AWS Config resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Authorizes the aggregator account and region to collect data from the source account and region. </p>
+    fn put_aggregation_authorization(
+        &self,
+        input: PutAggregationAuthorizationRequest,
+    ) -> RusotoFuture<PutAggregationAuthorizationResponse, PutAggregationAuthorizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutAggregationAuthorization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.PutAggregationAuthorization",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutAggregationAuthorizationResponse, _>(
+                    response,
+                    "PutAggregationAuthorization",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutAggregationAuthorizationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds or updates an AWS Config rule for evaluating whether your AWS resources comply with your desired configurations.</p> <p>You can use this action for custom AWS Config rules and AWS managed Config rules. A custom AWS Config rule is a rule that you develop and maintain. An AWS managed Config rule is a customizable, predefined rule that AWS Config provides.</p> <p>If you are adding a new custom AWS Config rule, you must first create the AWS Lambda function that the rule invokes to evaluate your resources. When you use the <code>PutConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. Specify the ARN for the <code>SourceIdentifier</code> key. This key is part of the <code>Source</code> object, which is part of the <code>ConfigRule</code> object. </p> <p>If you are adding an AWS managed Config rule, specify the rule's identifier for the <code>SourceIdentifier</code> key. To reference AWS managed Config rule identifiers, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config_use-managed-rules.html">About AWS Managed Config Rules</a>.</p> <p>For any new rule that you add, specify the <code>ConfigRuleName</code> in the <code>ConfigRule</code> object. Do not specify the <code>ConfigRuleArn</code> or the <code>ConfigRuleId</code>. These values are generated by AWS Config for new rules.</p> <p>If you are updating a rule that you added previously, you can specify the rule by <code>ConfigRuleName</code>, <code>ConfigRuleId</code>, or <code>ConfigRuleArn</code> in the <code>ConfigRule</code> data type that you use in this request.</p> <p>The maximum number of rules that AWS Config supports is 150.</p> <p>For information about requesting a rule limit increase, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_config">AWS Config Limits</a> in the <i>AWS General Reference Guide</i>.</p> <p>For more information about developing and using AWS Config rules, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config.html">Evaluating AWS Resource Configurations with AWS Config</a> in the <i>AWS Config Developer Guide</i>.</p>
+    fn put_config_rule(&self, input: PutConfigRuleRequest) -> RusotoFuture<(), PutConfigRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConfigRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.PutConfigRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutConfigRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates and updates the configuration aggregator with the selected source accounts and regions. The source account can be individual account(s) or an organization.</p> <note> <p>AWS Config should be enabled in source accounts and regions you want to aggregate.</p> <p>If your source type is an organization, you must be signed in to the master account and all features must be enabled in your organization. AWS Config calls <code>EnableAwsServiceAccess</code> API to enable integration between AWS Config and AWS Organizations. </p> </note></p>
+    fn put_configuration_aggregator(
+        &self,
+        input: PutConfigurationAggregatorRequest,
+    ) -> RusotoFuture<PutConfigurationAggregatorResponse, PutConfigurationAggregatorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConfigurationAggregator");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.PutConfigurationAggregator",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutConfigurationAggregatorResponse, _>(
+                    response,
+                    "PutConfigurationAggregator",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutConfigurationAggregatorError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates a new configuration recorder to record the selected resource configurations.</p> <p>You can use this action to change the role <code>roleARN</code> or the <code>recordingGroup</code> of an existing recorder. To change the role, call the action on the existing configuration recorder and specify a role.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> <p>If <code>ConfigurationRecorder</code> does not have the <b>recordingGroup</b> parameter specified, the default is to record all supported resource types.</p> </note></p>
+    fn put_configuration_recorder(
+        &self,
+        input: PutConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), PutConfigurationRecorderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConfigurationRecorder");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.PutConfigurationRecorder",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutConfigurationRecorderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates a delivery channel object to deliver configuration information to an Amazon S3 bucket and Amazon SNS topic.</p> <p>Before you can create a delivery channel, you must create a configuration recorder.</p> <p>You can use this action to change the Amazon S3 bucket or an Amazon SNS topic of the existing delivery channel. To change the Amazon S3 bucket or an Amazon SNS topic, call this action and specify the changed values for the S3 bucket and the SNS topic. If you specify a different value for either the S3 bucket or the SNS topic, this action will keep the existing value for the parameter that is not changed.</p> <note> <p>You can have only one delivery channel per region in your account.</p> </note></p>
+    fn put_delivery_channel(
+        &self,
+        input: PutDeliveryChannelRequest,
+    ) -> RusotoFuture<(), PutDeliveryChannelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutDeliveryChannel");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.PutDeliveryChannel");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutDeliveryChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Used by an AWS Lambda function to deliver evaluation results to AWS Config. This action is required in every AWS Lambda function that is invoked by an AWS Config rule.</p>
+    fn put_evaluations(
+        &self,
+        input: PutEvaluationsRequest,
+    ) -> RusotoFuture<PutEvaluationsResponse, PutEvaluationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutEvaluations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.PutEvaluations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutEvaluationsResponse, _>(
+                    response,
+                    "PutEvaluations",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutEvaluationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Adds or updates organization config rule for your entire organization evaluating whether your AWS resources comply with your desired configurations. Only a master account can create or update an organization config rule.</p> <p>This API enables organization service access through the <code>EnableAWSServiceAccess</code> action and creates a service linked role <code>AWSServiceRoleForConfigMultiAccountSetup</code> in the master account of your organization. The service linked role is created only when the role does not exist in the master account. AWS Config verifies the existence of role with <code>GetRole</code> action.</p> <p>You can use this action to create both custom AWS Config rules and AWS managed Config rules. If you are adding a new custom AWS Config rule, you must first create AWS Lambda function in the master account that the rule invokes to evaluate your resources. When you use the <code>PutOrganizationConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. If you are adding an AWS managed Config rule, specify the rule&#39;s identifier for the <code>RuleIdentifier</code> key.</p> <p>The maximum number of organization config rules that AWS Config supports is 150.</p> <note> <p>Specify either <code>OrganizationCustomRuleMetadata</code> or <code>OrganizationManagedRuleMetadata</code>.</p> </note></p>
+    fn put_organization_config_rule(
+        &self,
+        input: PutOrganizationConfigRuleRequest,
+    ) -> RusotoFuture<PutOrganizationConfigRuleResponse, PutOrganizationConfigRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutOrganizationConfigRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.PutOrganizationConfigRule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutOrganizationConfigRuleResponse, _>(
+                    response,
+                    "PutOrganizationConfigRule",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutOrganizationConfigRuleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds or updates the remediation configuration with a specific AWS Config rule with the selected target or action. The API creates the <code>RemediationConfiguration</code> object for the AWS Config rule. The AWS Config rule must already exist for you to add a remediation configuration. The target (SSM document) must exist and have permissions to use the target. </p>
+    fn put_remediation_configurations(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> RusotoFuture<PutRemediationConfigurationsResponse, PutRemediationConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutRemediationConfigurations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.PutRemediationConfigurations",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutRemediationConfigurationsResponse, _>(
+                    response,
+                    "PutRemediationConfigurations",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutRemediationConfigurationsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>A remediation exception is when a specific resource is no longer considered for auto-remediation. This API adds a new exception or updates an exisiting exception for a specific resource with a specific AWS Config rule. </p>
+    fn put_remediation_exceptions(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> RusotoFuture<PutRemediationExceptionsResponse, PutRemediationExceptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutRemediationExceptions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.PutRemediationExceptions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutRemediationExceptionsResponse, _>(
+                    response,
+                    "PutRemediationExceptions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutRemediationExceptionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates and updates the retention configuration with details about retention period (number of days) that AWS Config stores your historical information. The API creates the <code>RetentionConfiguration</code> object and names the object as <b>default</b>. When you have a <code>RetentionConfiguration</code> object named <b>default</b>, calling the API modifies the default object. </p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    fn put_retention_configuration(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> RusotoFuture<PutRetentionConfigurationResponse, PutRetentionConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutRetentionConfiguration");
+
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "StarlingDoveService.PutOrganizationConfigRule",
+            "StarlingDoveService.PutRetentionConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutRetentionConfigurationResponse, _>(
+                    response,
+                    "PutRetentionConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutRetentionConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Accepts a structured query language (SQL) <code>SELECT</code> command, performs the corresponding search, and returns resource configurations matching the properties.</p> <p>For more information about query components, see the <a href="https://docs.aws.amazon.com/config/latest/developerguide/query-components.html"> <b>Query Components</b> </a> section in the AWS Config Developer Guide.</p>
+    fn select_resource_config(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> RusotoFuture<SelectResourceConfigResponse, SelectResourceConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("SelectResourceConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.SelectResourceConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SelectResourceConfigResponse, _>(
+                    response,
+                    "SelectResourceConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SelectResourceConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Runs an on-demand evaluation for the specified AWS Config rules against the last known configuration state of the resources. Use <code>StartConfigRulesEvaluation</code> when you want to test that a rule you updated is working as expected. <code>StartConfigRulesEvaluation</code> does not re-record the latest configuration state for your resources. It re-runs an evaluation against the last known state of your resources. </p> <p>You can specify up to 25 AWS Config rules per request. </p> <p>An existing <code>StartConfigRulesEvaluation</code> call for the specified rules must complete before you can call the API again. If you chose to have AWS Config stream to an Amazon SNS topic, you will receive a <code>ConfigRuleEvaluationStarted</code> notification when the evaluation starts.</p> <note> <p>You don&#39;t need to call the <code>StartConfigRulesEvaluation</code> API to run an evaluation for a new rule. When you create a rule, AWS Config evaluates your resources against the rule automatically. </p> </note> <p>The <code>StartConfigRulesEvaluation</code> API is useful if you want to run on-demand evaluations, such as the following example:</p> <ol> <li> <p>You have a custom rule that evaluates your IAM resources every 24 hours.</p> </li> <li> <p>You update your Lambda function to add additional conditions to your rule.</p> </li> <li> <p>Instead of waiting for the next periodic evaluation, you call the <code>StartConfigRulesEvaluation</code> API.</p> </li> <li> <p>AWS Config invokes your Lambda function and evaluates your IAM resources.</p> </li> <li> <p>Your custom rule will still run periodic evaluations every 24 hours.</p> </li> </ol></p>
+    fn start_config_rules_evaluation(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> RusotoFuture<StartConfigRulesEvaluationResponse, StartConfigRulesEvaluationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StartConfigRulesEvaluation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.StartConfigRulesEvaluation",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartConfigRulesEvaluationResponse, _>(
+                    response,
+                    "StartConfigRulesEvaluation",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartConfigRulesEvaluationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts recording configurations of the AWS resources you have selected to record in your AWS account.</p> <p>You must have created at least one delivery channel to successfully start the configuration recorder.</p>
+    fn start_configuration_recorder(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), StartConfigurationRecorderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StartConfigurationRecorder");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.StartConfigurationRecorder",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartConfigurationRecorderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Runs an on-demand remediation for the specified AWS Config rules against the last known remediation configuration. It runs an execution against the current state of your resources. Remediation execution is asynchronous.</p> <p>You can specify up to 100 resource keys per request. An existing StartRemediationExecution call for the specified resource keys must complete before you can call the API again.</p>
+    fn start_remediation_execution(
+        &self,
+        input: StartRemediationExecutionRequest,
+    ) -> RusotoFuture<StartRemediationExecutionResponse, StartRemediationExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StartRemediationExecution");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.StartRemediationExecution",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartRemediationExecutionResponse, _>(
+                    response,
+                    "StartRemediationExecution",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartRemediationExecutionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Stops recording configurations of the AWS resources you have selected to record in your AWS account.</p>
+    fn stop_configuration_recorder(
+        &self,
+        input: StopConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), StopConfigurationRecorderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StopConfigurationRecorder");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.StopConfigurationRecorder",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutOrganizationConfigRuleResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutOrganizationConfigRuleError::from_response(response))
-                }))
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopConfigurationRecorderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Config Service API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockConfigService {
+    batch_get_aggregate_resource_config: Option<
+        Box<
+            dyn Fn(
+                    BatchGetAggregateResourceConfigRequest,
+                ) -> ::std::result::Result<
+                    BatchGetAggregateResourceConfigResponse,
+                    RusotoError<BatchGetAggregateResourceConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_resource_config: Option<
+        Box<
+            dyn Fn(
+                    BatchGetResourceConfigRequest,
+                ) -> ::std::result::Result<
+                    BatchGetResourceConfigResponse,
+                    RusotoError<BatchGetResourceConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_aggregation_authorization: Option<
+        Box<
+            dyn Fn(
+                    DeleteAggregationAuthorizationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteAggregationAuthorizationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_config_rule: Option<
+        Box<
+            dyn Fn(
+                    DeleteConfigRuleRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteConfigRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_configuration_aggregator: Option<
+        Box<
+            dyn Fn(
+                    DeleteConfigurationAggregatorRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteConfigurationAggregatorError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_configuration_recorder: Option<
+        Box<
+            dyn Fn(
+                    DeleteConfigurationRecorderRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteConfigurationRecorderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_delivery_channel: Option<
+        Box<
+            dyn Fn(
+                    DeleteDeliveryChannelRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteDeliveryChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_evaluation_results: Option<
+        Box<
+            dyn Fn(
+                    DeleteEvaluationResultsRequest,
+                ) -> ::std::result::Result<
+                    DeleteEvaluationResultsResponse,
+                    RusotoError<DeleteEvaluationResultsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_organization_config_rule: Option<
+        Box<
+            dyn Fn(
+                    DeleteOrganizationConfigRuleRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteOrganizationConfigRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_pending_aggregation_request: Option<
+        Box<
+            dyn Fn(
+                    DeletePendingAggregationRequestRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeletePendingAggregationRequestError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_remediation_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteRemediationConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DeleteRemediationConfigurationResponse,
+                    RusotoError<DeleteRemediationConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_remediation_exceptions: Option<
+        Box<
+            dyn Fn(
+                    DeleteRemediationExceptionsRequest,
+                ) -> ::std::result::Result<
+                    DeleteRemediationExceptionsResponse,
+                    RusotoError<DeleteRemediationExceptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_retention_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteRetentionConfigurationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteRetentionConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deliver_config_snapshot: Option<
+        Box<
+            dyn Fn(
+                    DeliverConfigSnapshotRequest,
+                ) -> ::std::result::Result<
+                    DeliverConfigSnapshotResponse,
+                    RusotoError<DeliverConfigSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_aggregate_compliance_by_config_rules: Option<
+        Box<
+            dyn Fn(
+                    DescribeAggregateComplianceByConfigRulesRequest,
+                ) -> ::std::result::Result<
+                    DescribeAggregateComplianceByConfigRulesResponse,
+                    RusotoError<DescribeAggregateComplianceByConfigRulesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_aggregation_authorizations: Option<
+        Box<
+            dyn Fn(
+                    DescribeAggregationAuthorizationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAggregationAuthorizationsResponse,
+                    RusotoError<DescribeAggregationAuthorizationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_compliance_by_config_rule: Option<
+        Box<
+            dyn Fn(
+                    DescribeComplianceByConfigRuleRequest,
+                ) -> ::std::result::Result<
+                    DescribeComplianceByConfigRuleResponse,
+                    RusotoError<DescribeComplianceByConfigRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_compliance_by_resource: Option<
+        Box<
+            dyn Fn(
+                    DescribeComplianceByResourceRequest,
+                ) -> ::std::result::Result<
+                    DescribeComplianceByResourceResponse,
+                    RusotoError<DescribeComplianceByResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_config_rule_evaluation_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigRuleEvaluationStatusRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigRuleEvaluationStatusResponse,
+                    RusotoError<DescribeConfigRuleEvaluationStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_config_rules: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigRulesRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigRulesResponse,
+                    RusotoError<DescribeConfigRulesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration_aggregator_sources_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationAggregatorSourcesStatusRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigurationAggregatorSourcesStatusResponse,
+                    RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration_aggregators: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationAggregatorsRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigurationAggregatorsResponse,
+                    RusotoError<DescribeConfigurationAggregatorsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration_recorder_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationRecorderStatusRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigurationRecorderStatusResponse,
+                    RusotoError<DescribeConfigurationRecorderStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration_recorders: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationRecordersRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigurationRecordersResponse,
+                    RusotoError<DescribeConfigurationRecordersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_delivery_channel_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeDeliveryChannelStatusRequest,
+                ) -> ::std::result::Result<
+                    DescribeDeliveryChannelStatusResponse,
+                    RusotoError<DescribeDeliveryChannelStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_delivery_channels: Option<
+        Box<
+            dyn Fn(
+                    DescribeDeliveryChannelsRequest,
+                ) -> ::std::result::Result<
+                    DescribeDeliveryChannelsResponse,
+                    RusotoError<DescribeDeliveryChannelsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_organization_config_rule_statuses: Option<
+        Box<
+            dyn Fn(
+                    DescribeOrganizationConfigRuleStatusesRequest,
+                ) -> ::std::result::Result<
+                    DescribeOrganizationConfigRuleStatusesResponse,
+                    RusotoError<DescribeOrganizationConfigRuleStatusesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_organization_config_rules: Option<
+        Box<
+            dyn Fn(
+                    DescribeOrganizationConfigRulesRequest,
+                ) -> ::std::result::Result<
+                    DescribeOrganizationConfigRulesResponse,
+                    RusotoError<DescribeOrganizationConfigRulesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_pending_aggregation_requests: Option<
+        Box<
+            dyn Fn(
+                    DescribePendingAggregationRequestsRequest,
+                ) -> ::std::result::Result<
+                    DescribePendingAggregationRequestsResponse,
+                    RusotoError<DescribePendingAggregationRequestsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_remediation_configurations: Option<
+        Box<
+            dyn Fn(
+                    DescribeRemediationConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeRemediationConfigurationsResponse,
+                    RusotoError<DescribeRemediationConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_remediation_exceptions: Option<
+        Box<
+            dyn Fn(
+                    DescribeRemediationExceptionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeRemediationExceptionsResponse,
+                    RusotoError<DescribeRemediationExceptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_remediation_execution_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeRemediationExecutionStatusRequest,
+                ) -> ::std::result::Result<
+                    DescribeRemediationExecutionStatusResponse,
+                    RusotoError<DescribeRemediationExecutionStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_retention_configurations: Option<
+        Box<
+            dyn Fn(
+                    DescribeRetentionConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeRetentionConfigurationsResponse,
+                    RusotoError<DescribeRetentionConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_aggregate_compliance_details_by_config_rule: Option<
+        Box<
+            dyn Fn(
+                    GetAggregateComplianceDetailsByConfigRuleRequest,
+                ) -> ::std::result::Result<
+                    GetAggregateComplianceDetailsByConfigRuleResponse,
+                    RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_aggregate_config_rule_compliance_summary: Option<
+        Box<
+            dyn Fn(
+                    GetAggregateConfigRuleComplianceSummaryRequest,
+                ) -> ::std::result::Result<
+                    GetAggregateConfigRuleComplianceSummaryResponse,
+                    RusotoError<GetAggregateConfigRuleComplianceSummaryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_aggregate_discovered_resource_counts: Option<
+        Box<
+            dyn Fn(
+                    GetAggregateDiscoveredResourceCountsRequest,
+                ) -> ::std::result::Result<
+                    GetAggregateDiscoveredResourceCountsResponse,
+                    RusotoError<GetAggregateDiscoveredResourceCountsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_aggregate_resource_config: Option<
+        Box<
+            dyn Fn(
+                    GetAggregateResourceConfigRequest,
+                ) -> ::std::result::Result<
+                    GetAggregateResourceConfigResponse,
+                    RusotoError<GetAggregateResourceConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_compliance_details_by_config_rule: Option<
+        Box<
+            dyn Fn(
+                    GetComplianceDetailsByConfigRuleRequest,
+                ) -> ::std::result::Result<
+                    GetComplianceDetailsByConfigRuleResponse,
+                    RusotoError<GetComplianceDetailsByConfigRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_compliance_details_by_resource: Option<
+        Box<
+            dyn Fn(
+                    GetComplianceDetailsByResourceRequest,
+                ) -> ::std::result::Result<
+                    GetComplianceDetailsByResourceResponse,
+                    RusotoError<GetComplianceDetailsByResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_compliance_summary_by_config_rule: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetComplianceSummaryByConfigRuleResponse,
+                    RusotoError<GetComplianceSummaryByConfigRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_compliance_summary_by_resource_type: Option<
+        Box<
+            dyn Fn(
+                    GetComplianceSummaryByResourceTypeRequest,
+                ) -> ::std::result::Result<
+                    GetComplianceSummaryByResourceTypeResponse,
+                    RusotoError<GetComplianceSummaryByResourceTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_discovered_resource_counts: Option<
+        Box<
+            dyn Fn(
+                    GetDiscoveredResourceCountsRequest,
+                ) -> ::std::result::Result<
+                    GetDiscoveredResourceCountsResponse,
+                    RusotoError<GetDiscoveredResourceCountsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_organization_config_rule_detailed_status: Option<
+        Box<
+            dyn Fn(
+                    GetOrganizationConfigRuleDetailedStatusRequest,
+                ) -> ::std::result::Result<
+                    GetOrganizationConfigRuleDetailedStatusResponse,
+                    RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resource_config_history: Option<
+        Box<
+            dyn Fn(
+                    GetResourceConfigHistoryRequest,
+                ) -> ::std::result::Result<
+                    GetResourceConfigHistoryResponse,
+                    RusotoError<GetResourceConfigHistoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_aggregate_discovered_resources: Option<
+        Box<
+            dyn Fn(
+                    ListAggregateDiscoveredResourcesRequest,
+                ) -> ::std::result::Result<
+                    ListAggregateDiscoveredResourcesResponse,
+                    RusotoError<ListAggregateDiscoveredResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_discovered_resources: Option<
+        Box<
+            dyn Fn(
+                    ListDiscoveredResourcesRequest,
+                ) -> ::std::result::Result<
+                    ListDiscoveredResourcesResponse,
+                    RusotoError<ListDiscoveredResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_aggregation_authorization: Option<
+        Box<
+            dyn Fn(
+                    PutAggregationAuthorizationRequest,
+                ) -> ::std::result::Result<
+                    PutAggregationAuthorizationResponse,
+                    RusotoError<PutAggregationAuthorizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_config_rule: Option<
+        Box<
+            dyn Fn(
+                    PutConfigRuleRequest,
+                ) -> ::std::result::Result<(), RusotoError<PutConfigRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_configuration_aggregator: Option<
+        Box<
+            dyn Fn(
+                    PutConfigurationAggregatorRequest,
+                ) -> ::std::result::Result<
+                    PutConfigurationAggregatorResponse,
+                    RusotoError<PutConfigurationAggregatorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_configuration_recorder: Option<
+        Box<
+            dyn Fn(
+                    PutConfigurationRecorderRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutConfigurationRecorderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_delivery_channel: Option<
+        Box<
+            dyn Fn(
+                    PutDeliveryChannelRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutDeliveryChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_evaluations: Option<
+        Box<
+            dyn Fn(
+                    PutEvaluationsRequest,
+                ) -> ::std::result::Result<
+                    PutEvaluationsResponse,
+                    RusotoError<PutEvaluationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_organization_config_rule: Option<
+        Box<
+            dyn Fn(
+                    PutOrganizationConfigRuleRequest,
+                ) -> ::std::result::Result<
+                    PutOrganizationConfigRuleResponse,
+                    RusotoError<PutOrganizationConfigRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_remediation_configurations: Option<
+        Box<
+            dyn Fn(
+                    PutRemediationConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    PutRemediationConfigurationsResponse,
+                    RusotoError<PutRemediationConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_remediation_exceptions: Option<
+        Box<
+            dyn Fn(
+                    PutRemediationExceptionsRequest,
+                ) -> ::std::result::Result<
+                    PutRemediationExceptionsResponse,
+                    RusotoError<PutRemediationExceptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_retention_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutRetentionConfigurationRequest,
+                ) -> ::std::result::Result<
+                    PutRetentionConfigurationResponse,
+                    RusotoError<PutRetentionConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    select_resource_config: Option<
+        Box<
+            dyn Fn(
+                    SelectResourceConfigRequest,
+                ) -> ::std::result::Result<
+                    SelectResourceConfigResponse,
+                    RusotoError<SelectResourceConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_config_rules_evaluation: Option<
+        Box<
+            dyn Fn(
+                    StartConfigRulesEvaluationRequest,
+                ) -> ::std::result::Result<
+                    StartConfigRulesEvaluationResponse,
+                    RusotoError<StartConfigRulesEvaluationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_configuration_recorder: Option<
+        Box<
+            dyn Fn(
+                    StartConfigurationRecorderRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<StartConfigurationRecorderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_remediation_execution: Option<
+        Box<
+            dyn Fn(
+                    StartRemediationExecutionRequest,
+                ) -> ::std::result::Result<
+                    StartRemediationExecutionResponse,
+                    RusotoError<StartRemediationExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_configuration_recorder: Option<
+        Box<
+            dyn Fn(
+                    StopConfigurationRecorderRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<StopConfigurationRecorderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockConfigService {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockConfigService {
+        Default::default()
+    }
+
+    /// Answers `batch_get_aggregate_resource_config` with what `handler` returns for its input.
+    pub fn with_batch_get_aggregate_resource_config<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                BatchGetAggregateResourceConfigRequest,
+            ) -> ::std::result::Result<
+                BatchGetAggregateResourceConfigResponse,
+                RusotoError<BatchGetAggregateResourceConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_aggregate_resource_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_resource_config` with what `handler` returns for its input.
+    pub fn with_batch_get_resource_config<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                BatchGetResourceConfigRequest,
+            ) -> ::std::result::Result<
+                BatchGetResourceConfigResponse,
+                RusotoError<BatchGetResourceConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_resource_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_aggregation_authorization` with what `handler` returns for its input.
+    pub fn with_delete_aggregation_authorization<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeleteAggregationAuthorizationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteAggregationAuthorizationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_aggregation_authorization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_config_rule` with what `handler` returns for its input.
+    pub fn with_delete_config_rule<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeleteConfigRuleRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteConfigRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_config_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_configuration_aggregator` with what `handler` returns for its input.
+    pub fn with_delete_configuration_aggregator<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeleteConfigurationAggregatorRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteConfigurationAggregatorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_configuration_aggregator = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_configuration_recorder` with what `handler` returns for its input.
+    pub fn with_delete_configuration_recorder<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeleteConfigurationRecorderRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteConfigurationRecorderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_configuration_recorder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_delivery_channel` with what `handler` returns for its input.
+    pub fn with_delete_delivery_channel<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeleteDeliveryChannelRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDeliveryChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_delivery_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_evaluation_results` with what `handler` returns for its input.
+    pub fn with_delete_evaluation_results<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeleteEvaluationResultsRequest,
+            ) -> ::std::result::Result<
+                DeleteEvaluationResultsResponse,
+                RusotoError<DeleteEvaluationResultsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_evaluation_results = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_organization_config_rule` with what `handler` returns for its input.
+    pub fn with_delete_organization_config_rule<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeleteOrganizationConfigRuleRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteOrganizationConfigRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_organization_config_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_pending_aggregation_request` with what `handler` returns for its input.
+    pub fn with_delete_pending_aggregation_request<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeletePendingAggregationRequestRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeletePendingAggregationRequestError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_pending_aggregation_request = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_remediation_configuration` with what `handler` returns for its input.
+    pub fn with_delete_remediation_configuration<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeleteRemediationConfigurationRequest,
+            ) -> ::std::result::Result<
+                DeleteRemediationConfigurationResponse,
+                RusotoError<DeleteRemediationConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_remediation_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_remediation_exceptions` with what `handler` returns for its input.
+    pub fn with_delete_remediation_exceptions<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeleteRemediationExceptionsRequest,
+            ) -> ::std::result::Result<
+                DeleteRemediationExceptionsResponse,
+                RusotoError<DeleteRemediationExceptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_remediation_exceptions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_retention_configuration` with what `handler` returns for its input.
+    pub fn with_delete_retention_configuration<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeleteRetentionConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteRetentionConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_retention_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deliver_config_snapshot` with what `handler` returns for its input.
+    pub fn with_deliver_config_snapshot<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DeliverConfigSnapshotRequest,
+            ) -> ::std::result::Result<
+                DeliverConfigSnapshotResponse,
+                RusotoError<DeliverConfigSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deliver_config_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_aggregate_compliance_by_config_rules` with what `handler` returns for its input.
+    pub fn with_describe_aggregate_compliance_by_config_rules<F>(
+        mut self,
+        handler: F,
+    ) -> MockConfigService
+    where
+        F: Fn(
+                DescribeAggregateComplianceByConfigRulesRequest,
+            ) -> ::std::result::Result<
+                DescribeAggregateComplianceByConfigRulesResponse,
+                RusotoError<DescribeAggregateComplianceByConfigRulesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_aggregate_compliance_by_config_rules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_aggregation_authorizations` with what `handler` returns for its input.
+    pub fn with_describe_aggregation_authorizations<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeAggregationAuthorizationsRequest,
+            ) -> ::std::result::Result<
+                DescribeAggregationAuthorizationsResponse,
+                RusotoError<DescribeAggregationAuthorizationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_aggregation_authorizations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_compliance_by_config_rule` with what `handler` returns for its input.
+    pub fn with_describe_compliance_by_config_rule<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeComplianceByConfigRuleRequest,
+            ) -> ::std::result::Result<
+                DescribeComplianceByConfigRuleResponse,
+                RusotoError<DescribeComplianceByConfigRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_compliance_by_config_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_compliance_by_resource` with what `handler` returns for its input.
+    pub fn with_describe_compliance_by_resource<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeComplianceByResourceRequest,
+            ) -> ::std::result::Result<
+                DescribeComplianceByResourceResponse,
+                RusotoError<DescribeComplianceByResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_compliance_by_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_config_rule_evaluation_status` with what `handler` returns for its input.
+    pub fn with_describe_config_rule_evaluation_status<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeConfigRuleEvaluationStatusRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigRuleEvaluationStatusResponse,
+                RusotoError<DescribeConfigRuleEvaluationStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_config_rule_evaluation_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_config_rules` with what `handler` returns for its input.
+    pub fn with_describe_config_rules<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeConfigRulesRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigRulesResponse,
+                RusotoError<DescribeConfigRulesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_config_rules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration_aggregator_sources_status` with what `handler` returns for its input.
+    pub fn with_describe_configuration_aggregator_sources_status<F>(
+        mut self,
+        handler: F,
+    ) -> MockConfigService
+    where
+        F: Fn(
+                DescribeConfigurationAggregatorSourcesStatusRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigurationAggregatorSourcesStatusResponse,
+                RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration_aggregator_sources_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration_aggregators` with what `handler` returns for its input.
+    pub fn with_describe_configuration_aggregators<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeConfigurationAggregatorsRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigurationAggregatorsResponse,
+                RusotoError<DescribeConfigurationAggregatorsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration_aggregators = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration_recorder_status` with what `handler` returns for its input.
+    pub fn with_describe_configuration_recorder_status<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeConfigurationRecorderStatusRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigurationRecorderStatusResponse,
+                RusotoError<DescribeConfigurationRecorderStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration_recorder_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration_recorders` with what `handler` returns for its input.
+    pub fn with_describe_configuration_recorders<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeConfigurationRecordersRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigurationRecordersResponse,
+                RusotoError<DescribeConfigurationRecordersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration_recorders = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_delivery_channel_status` with what `handler` returns for its input.
+    pub fn with_describe_delivery_channel_status<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeDeliveryChannelStatusRequest,
+            ) -> ::std::result::Result<
+                DescribeDeliveryChannelStatusResponse,
+                RusotoError<DescribeDeliveryChannelStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_delivery_channel_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_delivery_channels` with what `handler` returns for its input.
+    pub fn with_describe_delivery_channels<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeDeliveryChannelsRequest,
+            ) -> ::std::result::Result<
+                DescribeDeliveryChannelsResponse,
+                RusotoError<DescribeDeliveryChannelsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_delivery_channels = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_organization_config_rule_statuses` with what `handler` returns for its input.
+    pub fn with_describe_organization_config_rule_statuses<F>(
+        mut self,
+        handler: F,
+    ) -> MockConfigService
+    where
+        F: Fn(
+                DescribeOrganizationConfigRuleStatusesRequest,
+            ) -> ::std::result::Result<
+                DescribeOrganizationConfigRuleStatusesResponse,
+                RusotoError<DescribeOrganizationConfigRuleStatusesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_organization_config_rule_statuses = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_organization_config_rules` with what `handler` returns for its input.
+    pub fn with_describe_organization_config_rules<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeOrganizationConfigRulesRequest,
+            ) -> ::std::result::Result<
+                DescribeOrganizationConfigRulesResponse,
+                RusotoError<DescribeOrganizationConfigRulesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_organization_config_rules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_pending_aggregation_requests` with what `handler` returns for its input.
+    pub fn with_describe_pending_aggregation_requests<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribePendingAggregationRequestsRequest,
+            ) -> ::std::result::Result<
+                DescribePendingAggregationRequestsResponse,
+                RusotoError<DescribePendingAggregationRequestsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_pending_aggregation_requests = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_remediation_configurations` with what `handler` returns for its input.
+    pub fn with_describe_remediation_configurations<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeRemediationConfigurationsRequest,
+            ) -> ::std::result::Result<
+                DescribeRemediationConfigurationsResponse,
+                RusotoError<DescribeRemediationConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_remediation_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_remediation_exceptions` with what `handler` returns for its input.
+    pub fn with_describe_remediation_exceptions<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeRemediationExceptionsRequest,
+            ) -> ::std::result::Result<
+                DescribeRemediationExceptionsResponse,
+                RusotoError<DescribeRemediationExceptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_remediation_exceptions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_remediation_execution_status` with what `handler` returns for its input.
+    pub fn with_describe_remediation_execution_status<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeRemediationExecutionStatusRequest,
+            ) -> ::std::result::Result<
+                DescribeRemediationExecutionStatusResponse,
+                RusotoError<DescribeRemediationExecutionStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_remediation_execution_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_retention_configurations` with what `handler` returns for its input.
+    pub fn with_describe_retention_configurations<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                DescribeRetentionConfigurationsRequest,
+            ) -> ::std::result::Result<
+                DescribeRetentionConfigurationsResponse,
+                RusotoError<DescribeRetentionConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_retention_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_aggregate_compliance_details_by_config_rule` with what `handler` returns for its input.
+    pub fn with_get_aggregate_compliance_details_by_config_rule<F>(
+        mut self,
+        handler: F,
+    ) -> MockConfigService
+    where
+        F: Fn(
+                GetAggregateComplianceDetailsByConfigRuleRequest,
+            ) -> ::std::result::Result<
+                GetAggregateComplianceDetailsByConfigRuleResponse,
+                RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_aggregate_compliance_details_by_config_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_aggregate_config_rule_compliance_summary` with what `handler` returns for its input.
+    pub fn with_get_aggregate_config_rule_compliance_summary<F>(
+        mut self,
+        handler: F,
+    ) -> MockConfigService
+    where
+        F: Fn(
+                GetAggregateConfigRuleComplianceSummaryRequest,
+            ) -> ::std::result::Result<
+                GetAggregateConfigRuleComplianceSummaryResponse,
+                RusotoError<GetAggregateConfigRuleComplianceSummaryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_aggregate_config_rule_compliance_summary = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_aggregate_discovered_resource_counts` with what `handler` returns for its input.
+    pub fn with_get_aggregate_discovered_resource_counts<F>(
+        mut self,
+        handler: F,
+    ) -> MockConfigService
+    where
+        F: Fn(
+                GetAggregateDiscoveredResourceCountsRequest,
+            ) -> ::std::result::Result<
+                GetAggregateDiscoveredResourceCountsResponse,
+                RusotoError<GetAggregateDiscoveredResourceCountsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_aggregate_discovered_resource_counts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_aggregate_resource_config` with what `handler` returns for its input.
+    pub fn with_get_aggregate_resource_config<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                GetAggregateResourceConfigRequest,
+            ) -> ::std::result::Result<
+                GetAggregateResourceConfigResponse,
+                RusotoError<GetAggregateResourceConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_aggregate_resource_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_compliance_details_by_config_rule` with what `handler` returns for its input.
+    pub fn with_get_compliance_details_by_config_rule<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                GetComplianceDetailsByConfigRuleRequest,
+            ) -> ::std::result::Result<
+                GetComplianceDetailsByConfigRuleResponse,
+                RusotoError<GetComplianceDetailsByConfigRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_compliance_details_by_config_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_compliance_details_by_resource` with what `handler` returns for its input.
+    pub fn with_get_compliance_details_by_resource<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                GetComplianceDetailsByResourceRequest,
+            ) -> ::std::result::Result<
+                GetComplianceDetailsByResourceResponse,
+                RusotoError<GetComplianceDetailsByResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_compliance_details_by_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_compliance_summary_by_config_rule` with what `handler` returns.
+    pub fn with_get_compliance_summary_by_config_rule<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn() -> ::std::result::Result<
+                GetComplianceSummaryByConfigRuleResponse,
+                RusotoError<GetComplianceSummaryByConfigRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_compliance_summary_by_config_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_compliance_summary_by_resource_type` with what `handler` returns for its input.
+    pub fn with_get_compliance_summary_by_resource_type<F>(
+        mut self,
+        handler: F,
+    ) -> MockConfigService
+    where
+        F: Fn(
+                GetComplianceSummaryByResourceTypeRequest,
+            ) -> ::std::result::Result<
+                GetComplianceSummaryByResourceTypeResponse,
+                RusotoError<GetComplianceSummaryByResourceTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_compliance_summary_by_resource_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_discovered_resource_counts` with what `handler` returns for its input.
+    pub fn with_get_discovered_resource_counts<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                GetDiscoveredResourceCountsRequest,
+            ) -> ::std::result::Result<
+                GetDiscoveredResourceCountsResponse,
+                RusotoError<GetDiscoveredResourceCountsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_discovered_resource_counts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_organization_config_rule_detailed_status` with what `handler` returns for its input.
+    pub fn with_get_organization_config_rule_detailed_status<F>(
+        mut self,
+        handler: F,
+    ) -> MockConfigService
+    where
+        F: Fn(
+                GetOrganizationConfigRuleDetailedStatusRequest,
+            ) -> ::std::result::Result<
+                GetOrganizationConfigRuleDetailedStatusResponse,
+                RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_organization_config_rule_detailed_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource_config_history` with what `handler` returns for its input.
+    pub fn with_get_resource_config_history<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                GetResourceConfigHistoryRequest,
+            ) -> ::std::result::Result<
+                GetResourceConfigHistoryResponse,
+                RusotoError<GetResourceConfigHistoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource_config_history = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_aggregate_discovered_resources` with what `handler` returns for its input.
+    pub fn with_list_aggregate_discovered_resources<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                ListAggregateDiscoveredResourcesRequest,
+            ) -> ::std::result::Result<
+                ListAggregateDiscoveredResourcesResponse,
+                RusotoError<ListAggregateDiscoveredResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_aggregate_discovered_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_discovered_resources` with what `handler` returns for its input.
+    pub fn with_list_discovered_resources<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                ListDiscoveredResourcesRequest,
+            ) -> ::std::result::Result<
+                ListDiscoveredResourcesResponse,
+                RusotoError<ListDiscoveredResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_discovered_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_aggregation_authorization` with what `handler` returns for its input.
+    pub fn with_put_aggregation_authorization<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                PutAggregationAuthorizationRequest,
+            ) -> ::std::result::Result<
+                PutAggregationAuthorizationResponse,
+                RusotoError<PutAggregationAuthorizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_aggregation_authorization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_config_rule` with what `handler` returns for its input.
+    pub fn with_put_config_rule<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(PutConfigRuleRequest) -> ::std::result::Result<(), RusotoError<PutConfigRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_config_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_configuration_aggregator` with what `handler` returns for its input.
+    pub fn with_put_configuration_aggregator<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                PutConfigurationAggregatorRequest,
+            ) -> ::std::result::Result<
+                PutConfigurationAggregatorResponse,
+                RusotoError<PutConfigurationAggregatorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_configuration_aggregator = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_configuration_recorder` with what `handler` returns for its input.
+    pub fn with_put_configuration_recorder<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                PutConfigurationRecorderRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutConfigurationRecorderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_configuration_recorder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_delivery_channel` with what `handler` returns for its input.
+    pub fn with_put_delivery_channel<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                PutDeliveryChannelRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutDeliveryChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_delivery_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_evaluations` with what `handler` returns for its input.
+    pub fn with_put_evaluations<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                PutEvaluationsRequest,
+            )
+                -> ::std::result::Result<PutEvaluationsResponse, RusotoError<PutEvaluationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_evaluations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_organization_config_rule` with what `handler` returns for its input.
+    pub fn with_put_organization_config_rule<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                PutOrganizationConfigRuleRequest,
+            ) -> ::std::result::Result<
+                PutOrganizationConfigRuleResponse,
+                RusotoError<PutOrganizationConfigRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_organization_config_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_remediation_configurations` with what `handler` returns for its input.
+    pub fn with_put_remediation_configurations<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                PutRemediationConfigurationsRequest,
+            ) -> ::std::result::Result<
+                PutRemediationConfigurationsResponse,
+                RusotoError<PutRemediationConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_remediation_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_remediation_exceptions` with what `handler` returns for its input.
+    pub fn with_put_remediation_exceptions<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                PutRemediationExceptionsRequest,
+            ) -> ::std::result::Result<
+                PutRemediationExceptionsResponse,
+                RusotoError<PutRemediationExceptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_remediation_exceptions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_retention_configuration` with what `handler` returns for its input.
+    pub fn with_put_retention_configuration<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                PutRetentionConfigurationRequest,
+            ) -> ::std::result::Result<
+                PutRetentionConfigurationResponse,
+                RusotoError<PutRetentionConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_retention_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `select_resource_config` with what `handler` returns for its input.
+    pub fn with_select_resource_config<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                SelectResourceConfigRequest,
+            ) -> ::std::result::Result<
+                SelectResourceConfigResponse,
+                RusotoError<SelectResourceConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.select_resource_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_config_rules_evaluation` with what `handler` returns for its input.
+    pub fn with_start_config_rules_evaluation<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                StartConfigRulesEvaluationRequest,
+            ) -> ::std::result::Result<
+                StartConfigRulesEvaluationResponse,
+                RusotoError<StartConfigRulesEvaluationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_config_rules_evaluation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_configuration_recorder` with what `handler` returns for its input.
+    pub fn with_start_configuration_recorder<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                StartConfigurationRecorderRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<StartConfigurationRecorderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_configuration_recorder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_remediation_execution` with what `handler` returns for its input.
+    pub fn with_start_remediation_execution<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                StartRemediationExecutionRequest,
+            ) -> ::std::result::Result<
+                StartRemediationExecutionResponse,
+                RusotoError<StartRemediationExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_remediation_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_configuration_recorder` with what `handler` returns for its input.
+    pub fn with_stop_configuration_recorder<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(
+                StopConfigurationRecorderRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<StopConfigurationRecorderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_configuration_recorder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockConfigService
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ConfigService for MockConfigService {
+    fn batch_get_aggregate_resource_config(
+        &self,
+        input: BatchGetAggregateResourceConfigRequest,
+    ) -> RusotoFuture<BatchGetAggregateResourceConfigResponse, BatchGetAggregateResourceConfigError>
+    {
+        match self.batch_get_aggregate_resource_config {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for batch_get_aggregate_resource_config")
+            }
+        }
+    }
+    fn batch_get_resource_config(
+        &self,
+        input: BatchGetResourceConfigRequest,
+    ) -> RusotoFuture<BatchGetResourceConfigResponse, BatchGetResourceConfigError> {
+        match self.batch_get_resource_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for batch_get_resource_config"),
+        }
+    }
+    fn delete_aggregation_authorization(
+        &self,
+        input: DeleteAggregationAuthorizationRequest,
+    ) -> RusotoFuture<(), DeleteAggregationAuthorizationError> {
+        match self.delete_aggregation_authorization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for delete_aggregation_authorization"),
+        }
+    }
+    fn delete_config_rule(
+        &self,
+        input: DeleteConfigRuleRequest,
+    ) -> RusotoFuture<(), DeleteConfigRuleError> {
+        match self.delete_config_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for delete_config_rule"),
+        }
+    }
+    fn delete_configuration_aggregator(
+        &self,
+        input: DeleteConfigurationAggregatorRequest,
+    ) -> RusotoFuture<(), DeleteConfigurationAggregatorError> {
+        match self.delete_configuration_aggregator {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for delete_configuration_aggregator"),
+        }
+    }
+    fn delete_configuration_recorder(
+        &self,
+        input: DeleteConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), DeleteConfigurationRecorderError> {
+        match self.delete_configuration_recorder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for delete_configuration_recorder"),
+        }
+    }
+    fn delete_delivery_channel(
+        &self,
+        input: DeleteDeliveryChannelRequest,
+    ) -> RusotoFuture<(), DeleteDeliveryChannelError> {
+        match self.delete_delivery_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for delete_delivery_channel"),
+        }
+    }
+    fn delete_evaluation_results(
+        &self,
+        input: DeleteEvaluationResultsRequest,
+    ) -> RusotoFuture<DeleteEvaluationResultsResponse, DeleteEvaluationResultsError> {
+        match self.delete_evaluation_results {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for delete_evaluation_results"),
+        }
+    }
+    fn delete_organization_config_rule(
+        &self,
+        input: DeleteOrganizationConfigRuleRequest,
+    ) -> RusotoFuture<(), DeleteOrganizationConfigRuleError> {
+        match self.delete_organization_config_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for delete_organization_config_rule"),
+        }
+    }
+    fn delete_pending_aggregation_request(
+        &self,
+        input: DeletePendingAggregationRequestRequest,
+    ) -> RusotoFuture<(), DeletePendingAggregationRequestError> {
+        match self.delete_pending_aggregation_request {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for delete_pending_aggregation_request")
+            }
+        }
+    }
+    fn delete_remediation_configuration(
+        &self,
+        input: DeleteRemediationConfigurationRequest,
+    ) -> RusotoFuture<DeleteRemediationConfigurationResponse, DeleteRemediationConfigurationError>
+    {
+        match self.delete_remediation_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for delete_remediation_configuration"),
+        }
+    }
+    fn delete_remediation_exceptions(
+        &self,
+        input: DeleteRemediationExceptionsRequest,
+    ) -> RusotoFuture<DeleteRemediationExceptionsResponse, DeleteRemediationExceptionsError> {
+        match self.delete_remediation_exceptions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for delete_remediation_exceptions"),
+        }
+    }
+    fn delete_retention_configuration(
+        &self,
+        input: DeleteRetentionConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteRetentionConfigurationError> {
+        match self.delete_retention_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for delete_retention_configuration"),
+        }
+    }
+    fn deliver_config_snapshot(
+        &self,
+        input: DeliverConfigSnapshotRequest,
+    ) -> RusotoFuture<DeliverConfigSnapshotResponse, DeliverConfigSnapshotError> {
+        match self.deliver_config_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for deliver_config_snapshot"),
+        }
+    }
+    fn describe_aggregate_compliance_by_config_rules(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> RusotoFuture<
+        DescribeAggregateComplianceByConfigRulesResponse,
+        DescribeAggregateComplianceByConfigRulesError,
+    > {
+        match self.describe_aggregate_compliance_by_config_rules {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockConfigService has no handler for describe_aggregate_compliance_by_config_rules"),
+}
+    }
+    fn describe_aggregation_authorizations(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> RusotoFuture<
+        DescribeAggregationAuthorizationsResponse,
+        DescribeAggregationAuthorizationsError,
+    > {
+        match self.describe_aggregation_authorizations {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for describe_aggregation_authorizations")
             }
-        })
+        }
+    }
+    fn describe_compliance_by_config_rule(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> RusotoFuture<DescribeComplianceByConfigRuleResponse, DescribeComplianceByConfigRuleError>
+    {
+        match self.describe_compliance_by_config_rule {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for describe_compliance_by_config_rule")
+            }
+        }
+    }
+    fn describe_compliance_by_resource(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> RusotoFuture<DescribeComplianceByResourceResponse, DescribeComplianceByResourceError> {
+        match self.describe_compliance_by_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for describe_compliance_by_resource"),
+        }
+    }
+    fn describe_config_rule_evaluation_status(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigRuleEvaluationStatusResponse,
+        DescribeConfigRuleEvaluationStatusError,
+    > {
+        match self.describe_config_rule_evaluation_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockConfigService has no handler for describe_config_rule_evaluation_status"
+            ),
+        }
+    }
+    fn describe_config_rules(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> RusotoFuture<DescribeConfigRulesResponse, DescribeConfigRulesError> {
+        match self.describe_config_rules {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for describe_config_rules"),
+        }
+    }
+    fn describe_configuration_aggregator_sources_status(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigurationAggregatorSourcesStatusResponse,
+        DescribeConfigurationAggregatorSourcesStatusError,
+    > {
+        match self.describe_configuration_aggregator_sources_status {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockConfigService has no handler for describe_configuration_aggregator_sources_status"),
+}
+    }
+    fn describe_configuration_aggregators(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> RusotoFuture<DescribeConfigurationAggregatorsResponse, DescribeConfigurationAggregatorsError>
+    {
+        match self.describe_configuration_aggregators {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for describe_configuration_aggregators")
+            }
+        }
+    }
+    fn describe_configuration_recorder_status(
+        &self,
+        input: DescribeConfigurationRecorderStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigurationRecorderStatusResponse,
+        DescribeConfigurationRecorderStatusError,
+    > {
+        match self.describe_configuration_recorder_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockConfigService has no handler for describe_configuration_recorder_status"
+            ),
+        }
+    }
+    fn describe_configuration_recorders(
+        &self,
+        input: DescribeConfigurationRecordersRequest,
+    ) -> RusotoFuture<DescribeConfigurationRecordersResponse, DescribeConfigurationRecordersError>
+    {
+        match self.describe_configuration_recorders {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for describe_configuration_recorders"),
+        }
+    }
+    fn describe_delivery_channel_status(
+        &self,
+        input: DescribeDeliveryChannelStatusRequest,
+    ) -> RusotoFuture<DescribeDeliveryChannelStatusResponse, DescribeDeliveryChannelStatusError>
+    {
+        match self.describe_delivery_channel_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for describe_delivery_channel_status"),
+        }
+    }
+    fn describe_delivery_channels(
+        &self,
+        input: DescribeDeliveryChannelsRequest,
+    ) -> RusotoFuture<DescribeDeliveryChannelsResponse, DescribeDeliveryChannelsError> {
+        match self.describe_delivery_channels {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for describe_delivery_channels"),
+        }
+    }
+    fn describe_organization_config_rule_statuses(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> RusotoFuture<
+        DescribeOrganizationConfigRuleStatusesResponse,
+        DescribeOrganizationConfigRuleStatusesError,
+    > {
+        match self.describe_organization_config_rule_statuses {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockConfigService has no handler for describe_organization_config_rule_statuses"
+            ),
+        }
+    }
+    fn describe_organization_config_rules(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> RusotoFuture<DescribeOrganizationConfigRulesResponse, DescribeOrganizationConfigRulesError>
+    {
+        match self.describe_organization_config_rules {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for describe_organization_config_rules")
+            }
+        }
+    }
+    fn describe_pending_aggregation_requests(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> RusotoFuture<
+        DescribePendingAggregationRequestsResponse,
+        DescribePendingAggregationRequestsError,
+    > {
+        match self.describe_pending_aggregation_requests {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for describe_pending_aggregation_requests")
+            }
+        }
+    }
+    fn describe_remediation_configurations(
+        &self,
+        input: DescribeRemediationConfigurationsRequest,
+    ) -> RusotoFuture<
+        DescribeRemediationConfigurationsResponse,
+        DescribeRemediationConfigurationsError,
+    > {
+        match self.describe_remediation_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for describe_remediation_configurations")
+            }
+        }
+    }
+    fn describe_remediation_exceptions(
+        &self,
+        input: DescribeRemediationExceptionsRequest,
+    ) -> RusotoFuture<DescribeRemediationExceptionsResponse, DescribeRemediationExceptionsError>
+    {
+        match self.describe_remediation_exceptions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for describe_remediation_exceptions"),
+        }
+    }
+    fn describe_remediation_execution_status(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeRemediationExecutionStatusResponse,
+        DescribeRemediationExecutionStatusError,
+    > {
+        match self.describe_remediation_execution_status {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for describe_remediation_execution_status")
+            }
+        }
+    }
+    fn describe_retention_configurations(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> RusotoFuture<DescribeRetentionConfigurationsResponse, DescribeRetentionConfigurationsError>
+    {
+        match self.describe_retention_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for describe_retention_configurations")
+            }
+        }
+    }
+    fn get_aggregate_compliance_details_by_config_rule(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> RusotoFuture<
+        GetAggregateComplianceDetailsByConfigRuleResponse,
+        GetAggregateComplianceDetailsByConfigRuleError,
+    > {
+        match self.get_aggregate_compliance_details_by_config_rule {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockConfigService has no handler for get_aggregate_compliance_details_by_config_rule"),
+}
+    }
+    fn get_aggregate_config_rule_compliance_summary(
+        &self,
+        input: GetAggregateConfigRuleComplianceSummaryRequest,
+    ) -> RusotoFuture<
+        GetAggregateConfigRuleComplianceSummaryResponse,
+        GetAggregateConfigRuleComplianceSummaryError,
+    > {
+        match self.get_aggregate_config_rule_compliance_summary {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockConfigService has no handler for get_aggregate_config_rule_compliance_summary"
+            ),
+        }
+    }
+    fn get_aggregate_discovered_resource_counts(
+        &self,
+        input: GetAggregateDiscoveredResourceCountsRequest,
+    ) -> RusotoFuture<
+        GetAggregateDiscoveredResourceCountsResponse,
+        GetAggregateDiscoveredResourceCountsError,
+    > {
+        match self.get_aggregate_discovered_resource_counts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockConfigService has no handler for get_aggregate_discovered_resource_counts"
+            ),
+        }
+    }
+    fn get_aggregate_resource_config(
+        &self,
+        input: GetAggregateResourceConfigRequest,
+    ) -> RusotoFuture<GetAggregateResourceConfigResponse, GetAggregateResourceConfigError> {
+        match self.get_aggregate_resource_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for get_aggregate_resource_config"),
+        }
+    }
+    fn get_compliance_details_by_config_rule(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> RusotoFuture<GetComplianceDetailsByConfigRuleResponse, GetComplianceDetailsByConfigRuleError>
+    {
+        match self.get_compliance_details_by_config_rule {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for get_compliance_details_by_config_rule")
+            }
+        }
+    }
+    fn get_compliance_details_by_resource(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> RusotoFuture<GetComplianceDetailsByResourceResponse, GetComplianceDetailsByResourceError>
+    {
+        match self.get_compliance_details_by_resource {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for get_compliance_details_by_resource")
+            }
+        }
+    }
+    fn get_compliance_summary_by_config_rule(
+        &self,
+    ) -> RusotoFuture<GetComplianceSummaryByConfigRuleResponse, GetComplianceSummaryByConfigRuleError>
+    {
+        match self.get_compliance_summary_by_config_rule {
+            Some(ref handler) => handler().into(),
+            None => {
+                panic!("MockConfigService has no handler for get_compliance_summary_by_config_rule")
+            }
+        }
+    }
+    fn get_compliance_summary_by_resource_type(
+        &self,
+        input: GetComplianceSummaryByResourceTypeRequest,
+    ) -> RusotoFuture<
+        GetComplianceSummaryByResourceTypeResponse,
+        GetComplianceSummaryByResourceTypeError,
+    > {
+        match self.get_compliance_summary_by_resource_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockConfigService has no handler for get_compliance_summary_by_resource_type"
+            ),
+        }
+    }
+    fn get_discovered_resource_counts(
+        &self,
+        input: GetDiscoveredResourceCountsRequest,
+    ) -> RusotoFuture<GetDiscoveredResourceCountsResponse, GetDiscoveredResourceCountsError> {
+        match self.get_discovered_resource_counts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for get_discovered_resource_counts"),
+        }
+    }
+    fn get_organization_config_rule_detailed_status(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> RusotoFuture<
+        GetOrganizationConfigRuleDetailedStatusResponse,
+        GetOrganizationConfigRuleDetailedStatusError,
+    > {
+        match self.get_organization_config_rule_detailed_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockConfigService has no handler for get_organization_config_rule_detailed_status"
+            ),
+        }
+    }
+    fn get_resource_config_history(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> RusotoFuture<GetResourceConfigHistoryResponse, GetResourceConfigHistoryError> {
+        match self.get_resource_config_history {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for get_resource_config_history"),
+        }
+    }
+    fn list_aggregate_discovered_resources(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListAggregateDiscoveredResourcesResponse, ListAggregateDiscoveredResourcesError>
+    {
+        match self.list_aggregate_discovered_resources {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockConfigService has no handler for list_aggregate_discovered_resources")
+            }
+        }
+    }
+    fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListDiscoveredResourcesResponse, ListDiscoveredResourcesError> {
+        match self.list_discovered_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for list_discovered_resources"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for list_tags_for_resource"),
+        }
+    }
+    fn put_aggregation_authorization(
+        &self,
+        input: PutAggregationAuthorizationRequest,
+    ) -> RusotoFuture<PutAggregationAuthorizationResponse, PutAggregationAuthorizationError> {
+        match self.put_aggregation_authorization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for put_aggregation_authorization"),
+        }
+    }
+    fn put_config_rule(&self, input: PutConfigRuleRequest) -> RusotoFuture<(), PutConfigRuleError> {
+        match self.put_config_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for put_config_rule"),
+        }
+    }
+    fn put_configuration_aggregator(
+        &self,
+        input: PutConfigurationAggregatorRequest,
+    ) -> RusotoFuture<PutConfigurationAggregatorResponse, PutConfigurationAggregatorError> {
+        match self.put_configuration_aggregator {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for put_configuration_aggregator"),
+        }
+    }
+    fn put_configuration_recorder(
+        &self,
+        input: PutConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), PutConfigurationRecorderError> {
+        match self.put_configuration_recorder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for put_configuration_recorder"),
+        }
+    }
+    fn put_delivery_channel(
+        &self,
+        input: PutDeliveryChannelRequest,
+    ) -> RusotoFuture<(), PutDeliveryChannelError> {
+        match self.put_delivery_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for put_delivery_channel"),
+        }
+    }
+    fn put_evaluations(
+        &self,
+        input: PutEvaluationsRequest,
+    ) -> RusotoFuture<PutEvaluationsResponse, PutEvaluationsError> {
+        match self.put_evaluations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for put_evaluations"),
+        }
+    }
+    fn put_organization_config_rule(
+        &self,
+        input: PutOrganizationConfigRuleRequest,
+    ) -> RusotoFuture<PutOrganizationConfigRuleResponse, PutOrganizationConfigRuleError> {
+        match self.put_organization_config_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for put_organization_config_rule"),
+        }
     }
-
-    /// <p>Adds or updates the remediation configuration with a specific AWS Config rule with the selected target or action. The API creates the <code>RemediationConfiguration</code> object for the AWS Config rule. The AWS Config rule must already exist for you to add a remediation configuration. The target (SSM document) must exist and have permissions to use the target. </p>
     fn put_remediation_configurations(
         &self,
         input: PutRemediationConfigurationsRequest,
     ) -> RusotoFuture<PutRemediationConfigurationsResponse, PutRemediationConfigurationsError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.PutRemediationConfigurations",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutRemediationConfigurationsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutRemediationConfigurationsError::from_response(response))
-                }))
-            }
-        })
+        match self.put_remediation_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for put_remediation_configurations"),
+        }
     }
-
-    /// <p>A remediation exception is when a specific resource is no longer considered for auto-remediation. This API adds a new exception or updates an exisiting exception for a specific resource with a specific AWS Config rule. </p>
     fn put_remediation_exceptions(
         &self,
         input: PutRemediationExceptionsRequest,
     ) -> RusotoFuture<PutRemediationExceptionsResponse, PutRemediationExceptionsError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.PutRemediationExceptions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutRemediationExceptionsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutRemediationExceptionsError::from_response(response))
-                }))
-            }
-        })
+        match self.put_remediation_exceptions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for put_remediation_exceptions"),
+        }
     }
-
-    /// <p><p>Creates and updates the retention configuration with details about retention period (number of days) that AWS Config stores your historical information. The API creates the <code>RetentionConfiguration</code> object and names the object as <b>default</b>. When you have a <code>RetentionConfiguration</code> object named <b>default</b>, calling the API modifies the default object. </p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
     fn put_retention_configuration(
         &self,
         input: PutRetentionConfigurationRequest,
     ) -> RusotoFuture<PutRetentionConfigurationResponse, PutRetentionConfigurationError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.PutRetentionConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutRetentionConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutRetentionConfigurationError::from_response(response))
-                }))
-            }
-        })
+        match self.put_retention_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for put_retention_configuration"),
+        }
     }
-
-    /// <p>Accepts a structured query language (SQL) <code>SELECT</code> command, performs the corresponding search, and returns resource configurations matching the properties.</p> <p>For more information about query components, see the <a href="https://docs.aws.amazon.com/config/latest/developerguide/query-components.html"> <b>Query Components</b> </a> section in the AWS Config Developer Guide.</p>
     fn select_resource_config(
         &self,
         input: SelectResourceConfigRequest,
     ) -> RusotoFuture<SelectResourceConfigResponse, SelectResourceConfigError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.SelectResourceConfig");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SelectResourceConfigResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SelectResourceConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.select_resource_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for select_resource_config"),
+        }
     }
-
-    /// <p><p>Runs an on-demand evaluation for the specified AWS Config rules against the last known configuration state of the resources. Use <code>StartConfigRulesEvaluation</code> when you want to test that a rule you updated is working as expected. <code>StartConfigRulesEvaluation</code> does not re-record the latest configuration state for your resources. It re-runs an evaluation against the last known state of your resources. </p> <p>You can specify up to 25 AWS Config rules per request. </p> <p>An existing <code>StartConfigRulesEvaluation</code> call for the specified rules must complete before you can call the API again. If you chose to have AWS Config stream to an Amazon SNS topic, you will receive a <code>ConfigRuleEvaluationStarted</code> notification when the evaluation starts.</p> <note> <p>You don&#39;t need to call the <code>StartConfigRulesEvaluation</code> API to run an evaluation for a new rule. When you create a rule, AWS Config evaluates your resources against the rule automatically. </p> </note> <p>The <code>StartConfigRulesEvaluation</code> API is useful if you want to run on-demand evaluations, such as the following example:</p> <ol> <li> <p>You have a custom rule that evaluates your IAM resources every 24 hours.</p> </li> <li> <p>You update your Lambda function to add additional conditions to your rule.</p> </li> <li> <p>Instead of waiting for the next periodic evaluation, you call the <code>StartConfigRulesEvaluation</code> API.</p> </li> <li> <p>AWS Config invokes your Lambda function and evaluates your IAM resources.</p> </li> <li> <p>Your custom rule will still run periodic evaluations every 24 hours.</p> </li> </ol></p>
     fn start_config_rules_evaluation(
         &self,
         input: StartConfigRulesEvaluationRequest,
     ) -> RusotoFuture<StartConfigRulesEvaluationResponse, StartConfigRulesEvaluationError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.StartConfigRulesEvaluation",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartConfigRulesEvaluationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartConfigRulesEvaluationError::from_response(response))
-                }))
-            }
-        })
+        match self.start_config_rules_evaluation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for start_config_rules_evaluation"),
+        }
     }
-
-    /// <p>Starts recording configurations of the AWS resources you have selected to record in your AWS account.</p> <p>You must have created at least one delivery channel to successfully start the configuration recorder.</p>
     fn start_configuration_recorder(
         &self,
         input: StartConfigurationRecorderRequest,
     ) -> RusotoFuture<(), StartConfigurationRecorderError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.StartConfigurationRecorder",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartConfigurationRecorderError::from_response(response))
-                }))
-            }
-        })
+        match self.start_configuration_recorder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for start_configuration_recorder"),
+        }
     }
-
-    /// <p>Runs an on-demand remediation for the specified AWS Config rules against the last known remediation configuration. It runs an execution against the current state of your resources. Remediation execution is asynchronous.</p> <p>You can specify up to 100 resource keys per request. An existing StartRemediationExecution call for the specified resource keys must complete before you can call the API again.</p>
     fn start_remediation_execution(
         &self,
         input: StartRemediationExecutionRequest,
     ) -> RusotoFuture<StartRemediationExecutionResponse, StartRemediationExecutionError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.StartRemediationExecution",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartRemediationExecutionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartRemediationExecutionError::from_response(response))
-                }))
-            }
-        })
+        match self.start_remediation_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for start_remediation_execution"),
+        }
     }
-
-    /// <p>Stops recording configurations of the AWS resources you have selected to record in your AWS account.</p>
     fn stop_configuration_recorder(
         &self,
         input: StopConfigurationRecorderRequest,
     ) -> RusotoFuture<(), StopConfigurationRecorderError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.StopConfigurationRecorder",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopConfigurationRecorderError::from_response(response))
-                }))
-            }
-        })
+        match self.stop_configuration_recorder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for stop_configuration_recorder"),
+        }
     }
-
-    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
     fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Deletes specified tags from a resource.</p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConfigService has no handler for untag_resource"),
+        }
     }
 }
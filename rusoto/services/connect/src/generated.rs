@@ -23,8 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserRequest {
     /// <p>The unique identifier for the user account in the directory service directory used for identity management. If Amazon Connect is unable to access the existing directory, you can use the <code>DirectoryUserId</code> to authenticate users. If you include the parameter, it is assumed that Amazon Connect cannot access the directory. If the parameter is not included, the <code>UserIdentityInfo</code> is used to authenticate users from your existing directory.</p> <p>This parameter is required if you are using an existing directory for identity management in Amazon Connect when Amazon Connect cannot access your directory to authenticate users. If you are using SAML for identity management and include this parameter, an <code>InvalidRequestException</code> is returned.</p>
     #[serde(rename = "DirectoryUserId")]
@@ -59,8 +61,43 @@ pub struct CreateUserRequest {
     pub username: String,
 }
 
+impl Validate for CreateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.identity_info {
+            value.validate_at(&field_path(path, "identity_info"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.password {
+            errors.check_pattern(
+                &field_path(path, "password"),
+                &value,
+                "/^(?=.*[a-z])(?=.*[A-Z])(?=.*\\d)[a-zA-Z\\d\\S]{8,64}$/",
+            );
+        }
+        self.phone_config
+            .validate_at(&field_path(path, "phone_config"), errors);
+        errors.check_length(
+            &field_path(path, "security_profile_ids"),
+            self.security_profile_ids.len(),
+            Some(1),
+            Some(10),
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserResponse {
     /// <p>The Amazon Resource Name (ARN) of the user account created.</p>
     #[serde(rename = "UserArn")]
@@ -74,7 +111,7 @@ pub struct CreateUserResponse {
 
 /// <p>The credentials to use for federation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Credentials {
     /// <p>An access token generated for a federated user to access Amazon Connect</p>
     #[serde(rename = "AccessToken")]
@@ -109,7 +146,7 @@ pub struct CurrentMetric {
 
 /// <p>A <code>CurrentMetricData</code> object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CurrentMetricData {
     /// <p>The metric in a <code>CurrentMetricData</code> object.</p>
     #[serde(rename = "Metric")]
@@ -123,7 +160,7 @@ pub struct CurrentMetricData {
 
 /// <p>A <code>CurrentMetricResult</code> object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CurrentMetricResult {
     /// <p>The <code>Collections</code> for the <code>CurrentMetricResult</code> object.</p>
     #[serde(rename = "Collections")]
@@ -136,6 +173,7 @@ pub struct CurrentMetricResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
@@ -145,7 +183,19 @@ pub struct DeleteUserRequest {
     pub user_id: String,
 }
 
+impl Validate for DeleteUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserHierarchyGroupRequest {
     /// <p>The identifier for the hierarchy group to return.</p>
     #[serde(rename = "HierarchyGroupId")]
@@ -155,8 +205,19 @@ pub struct DescribeUserHierarchyGroupRequest {
     pub instance_id: String,
 }
 
+impl Validate for DescribeUserHierarchyGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserHierarchyGroupResponse {
     /// <p>Returns a <code>HierarchyGroup</code> object.</p>
     #[serde(rename = "HierarchyGroup")]
@@ -165,14 +226,26 @@ pub struct DescribeUserHierarchyGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserHierarchyStructureRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
     pub instance_id: String,
 }
 
+impl Validate for DescribeUserHierarchyStructureRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserHierarchyStructureResponse {
     /// <p>A <code>HierarchyStructure</code> object.</p>
     #[serde(rename = "HierarchyStructure")]
@@ -181,6 +254,7 @@ pub struct DescribeUserHierarchyStructureResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
@@ -190,8 +264,19 @@ pub struct DescribeUserRequest {
     pub user_id: String,
 }
 
+impl Validate for DescribeUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserResponse {
     /// <p>A <code>User</code> object that contains information about the user account and configuration settings.</p>
     #[serde(rename = "User")]
@@ -201,7 +286,7 @@ pub struct DescribeUserResponse {
 
 /// <p>A <code>Dimensions</code> object that includes the Channel and Queue for the metric.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Dimensions {
     /// <p>The channel used for grouping and filters. Only VOICE is supported.</p>
     #[serde(rename = "Channel")]
@@ -215,6 +300,7 @@ pub struct Dimensions {
 
 /// <p>The filter, either channel or queues, to apply to the metric results retrieved.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filters {
     /// <p>The Channel to use as a filter for the metrics returned. Only VOICE is supported.</p>
     #[serde(rename = "Channels")]
@@ -226,7 +312,19 @@ pub struct Filters {
     pub queues: Option<Vec<String>>,
 }
 
+impl Validate for Filters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.channels {
+            errors.check_length(&field_path(path, "channels"), value.len(), None, Some(3));
+        }
+        if let Some(ref value) = self.queues {
+            errors.check_length(&field_path(path, "queues"), value.len(), Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetContactAttributesRequest {
     /// <p>The ID for the initial contact in Amazon Connect associated with the attributes to update.</p>
     #[serde(rename = "InitialContactId")]
@@ -236,8 +334,25 @@ pub struct GetContactAttributesRequest {
     pub instance_id: String,
 }
 
+impl Validate for GetContactAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "initial_contact_id"),
+            self.initial_contact_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetContactAttributesResponse {
     /// <p>The attributes to update.</p>
     #[serde(rename = "Attributes")]
@@ -246,6 +361,7 @@ pub struct GetContactAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCurrentMetricDataRequest {
     /// <p><p>A list of <code>CurrentMetric</code> objects for the metrics to retrieve. Each <code>CurrentMetric</code> includes a name of a metric to retrieve and the unit to use for it. You must list each metric to retrieve data for in the request.</p> <p>The following metrics are available:</p> <dl> <dt>AGENTS<em>AVAILABLE</dt> <dd> <p>Unit: COUNT</p> </dd> <dt>AGENTS</em>ONLINE</dt> <dd> <p>Unit: COUNT</p> </dd> <dt>AGENTS<em>ON</em>CALL</dt> <dd> <p>Unit: COUNT</p> </dd> <dt>AGENTS<em>STAFFED</dt> <dd> <p>Unit: COUNT</p> </dd> <dt>AGENTS</em>AFTER<em>CONTACT</em>WORK</dt> <dd> <p>Unit: COUNT</p> </dd> <dt>AGENTS<em>NON</em>PRODUCTIVE</dt> <dd> <p>Unit: COUNT</p> </dd> <dt>AGENTS<em>ERROR</dt> <dd> <p>Unit: COUNT</p> </dd> <dt>CONTACTS</em>IN<em>QUEUE</dt> <dd> <p>Unit: COUNT</p> </dd> <dt>OLDEST</em>CONTACT<em>AGE</dt> <dd> <p>Unit: SECONDS</p> </dd> <dt>CONTACTS</em>SCHEDULED</dt> <dd> <p>Unit: COUNT</p> </dd> </dl></p>
     #[serde(rename = "CurrentMetrics")]
@@ -270,8 +386,27 @@ pub struct GetCurrentMetricDataRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetCurrentMetricDataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.filters
+            .validate_at(&field_path(path, "filters"), errors);
+        if let Some(ref value) = self.groupings {
+            errors.check_length(&field_path(path, "groupings"), value.len(), None, Some(2));
+        }
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCurrentMetricDataResponse {
     /// <p>The time at which <code>CurrentMetricData</code> was retrieved and cached for pagination.</p>
     #[serde(rename = "DataSnapshotTime")]
@@ -288,14 +423,26 @@ pub struct GetCurrentMetricDataResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFederationTokenRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
     pub instance_id: String,
 }
 
+impl Validate for GetFederationTokenRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFederationTokenResponse {
     /// <p>The credentials to use for federation.</p>
     #[serde(rename = "Credentials")]
@@ -304,6 +451,7 @@ pub struct GetFederationTokenResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMetricDataRequest {
     /// <p>The timestamp, in UNIX Epoch time format, at which to end the reporting interval for the retrieval of historical metrics data. The time must be specified using an interval of 5 minutes, such as 11:00, 11:05, 11:10, and must be later than the <code>StartTime</code> timestamp.</p> <p>The time range between <code>StartTime</code> and <code>EndTime</code> must be less than 24 hours.</p>
     #[serde(rename = "EndTime")]
@@ -334,8 +482,27 @@ pub struct GetMetricDataRequest {
     pub start_time: f64,
 }
 
+impl Validate for GetMetricDataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.filters
+            .validate_at(&field_path(path, "filters"), errors);
+        if let Some(ref value) = self.groupings {
+            errors.check_length(&field_path(path, "groupings"), value.len(), None, Some(2));
+        }
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMetricDataResponse {
     /// <p>A list of <code>HistoricalMetricResult</code> objects, organized by <code>Dimensions</code>, which is the ID of the resource specified in the <code>Filters</code> used for the request. The metrics are combined with the metrics included in <code>Collections</code>, which is a list of <code>HisotricalMetricData</code> objects.</p> <p>If no <code>Grouping</code> is specified in the request, <code>Collections</code> includes summary data for the <code>HistoricalMetrics</code>.</p>
     #[serde(rename = "MetricResults")]
@@ -349,7 +516,7 @@ pub struct GetMetricDataResponse {
 
 /// <p>A <code>HierarchyGroup</code> object that contains information about a hierarchy group in your Amazon Connect instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HierarchyGroup {
     /// <p>The Amazon Resource Name (ARN) for the hierarchy group.</p>
     #[serde(rename = "Arn")]
@@ -375,7 +542,7 @@ pub struct HierarchyGroup {
 
 /// <p>A <code>HierarchyGroupSummary</code> object that contains information about the hierarchy group, including ARN, Id, and Name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HierarchyGroupSummary {
     /// <p>The ARN for the hierarchy group.</p>
     #[serde(rename = "Arn")]
@@ -393,7 +560,7 @@ pub struct HierarchyGroupSummary {
 
 /// <p>A <code>HierarchyLevel</code> object that contains information about the levels in a hierarchy group, including ARN, Id, and Name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HierarchyLevel {
     /// <p>The ARN for the hierarchy group level.</p>
     #[serde(rename = "Arn")]
@@ -411,7 +578,7 @@ pub struct HierarchyLevel {
 
 /// <p>A <code>HierarchyPath</code> object that contains information about the levels of the hierarchy group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HierarchyPath {
     /// <p>A <code>HierarchyGroupSummary</code> object that contains information about the level of the hierarchy group, including ARN, Id, and Name.</p>
     #[serde(rename = "LevelFive")]
@@ -437,7 +604,7 @@ pub struct HierarchyPath {
 
 /// <p>A <code>HierarchyStructure</code> object that contains information about the hierarchy group structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HierarchyStructure {
     /// <p>A <code>HierarchyLevel</code> object that contains information about the hierarchy group level.</p>
     #[serde(rename = "LevelFive")]
@@ -484,7 +651,7 @@ pub struct HistoricalMetric {
 
 /// <p>A <code>HistoricalMetricData</code> object than contains a <code>Metric</code> and a <code>Value</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HistoricalMetricData {
     /// <p>A <code>HistoricalMetric</code> object.</p>
     #[serde(rename = "Metric")]
@@ -498,7 +665,7 @@ pub struct HistoricalMetricData {
 
 /// <p>The metrics data returned from a <code>GetMetricData</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HistoricalMetricResult {
     /// <p>A list of <code>HistoricalMetricData</code> objects.</p>
     #[serde(rename = "Collections")]
@@ -511,6 +678,7 @@ pub struct HistoricalMetricResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRoutingProfilesRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
@@ -525,8 +693,27 @@ pub struct ListRoutingProfilesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListRoutingProfilesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRoutingProfilesResponse {
     /// <p>A string returned in the response. Use the value returned in the response as the value of the NextToken in a subsequent request to retrieve the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -539,6 +726,7 @@ pub struct ListRoutingProfilesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSecurityProfilesRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
@@ -553,8 +741,27 @@ pub struct ListSecurityProfilesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListSecurityProfilesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSecurityProfilesResponse {
     /// <p>A string returned in the response. Use the value returned in the response as the value of the NextToken in a subsequent request to retrieve the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -567,6 +774,7 @@ pub struct ListSecurityProfilesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUserHierarchyGroupsRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
@@ -581,8 +789,27 @@ pub struct ListUserHierarchyGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListUserHierarchyGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUserHierarchyGroupsResponse {
     /// <p>A string returned in the response. Use the value returned in the response as the value of the NextToken in a subsequent request to retrieve the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -595,6 +822,7 @@ pub struct ListUserHierarchyGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUsersRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
@@ -609,8 +837,27 @@ pub struct ListUsersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUsersResponse {
     /// <p>A string returned in the response. Use the value returned in the response as the value of the NextToken in a subsequent request to retrieve the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -624,7 +871,7 @@ pub struct ListUsersResponse {
 
 /// <p>A QueueReference object that contains the the QueueId and ARN for the queue resource for which metrics are returned.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QueueReference {
     /// <p>The Amazon Resource Name (ARN) of queue.</p>
     #[serde(rename = "Arn")]
@@ -638,7 +885,7 @@ pub struct QueueReference {
 
 /// <p>A <code>RoutingProfileSummary</code> object that contains information about a routing profile, including ARN, Id, and Name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RoutingProfileSummary {
     /// <p>The ARN of the routing profile.</p>
     #[serde(rename = "Arn")]
@@ -656,7 +903,7 @@ pub struct RoutingProfileSummary {
 
 /// <p>A <code>SecurityProfileSummary</code> object that contains information about a security profile, including ARN, Id, Name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SecurityProfileSummary {
     /// <p>The ARN of the security profile.</p>
     #[serde(rename = "Arn")]
@@ -673,6 +920,7 @@ pub struct SecurityProfileSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartOutboundVoiceContactRequest {
     /// <p>Specify a custom key-value pair using an attribute map. The attributes are standard Amazon Connect attributes, and can be accessed in contact flows just like any other contact attributes.</p> <p>There can be up to 32,768 UTF-8 bytes across all key-value pairs per contact. Attribute keys can include only alphanumeric, dash, and underscore characters.</p> <p>For example, if you want play a greeting when the customer answers the call, you can pass the customer name in attributes similar to the following:</p>
     #[serde(rename = "Attributes")]
@@ -701,8 +949,51 @@ pub struct StartOutboundVoiceContactRequest {
     pub source_phone_number: Option<String>,
 }
 
+impl Validate for StartOutboundVoiceContactRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(32767));
+            }
+        }
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "contact_flow_id"),
+            self.contact_flow_id.chars().count(),
+            None,
+            Some(500),
+        );
+        errors.check_pattern(
+            &field_path(path, "destination_phone_number"),
+            &self.destination_phone_number,
+            "\\\\+[1-9]\\\\d{1,14}$",
+        );
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.source_phone_number {
+            errors.check_pattern(
+                &field_path(path, "source_phone_number"),
+                &value,
+                "\\\\+[1-9]\\\\d{1,14}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartOutboundVoiceContactResponse {
     /// <p>The unique identifier of this contact within your Amazon Connect instance.</p>
     #[serde(rename = "ContactId")]
@@ -711,6 +1002,7 @@ pub struct StartOutboundVoiceContactResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopContactRequest {
     /// <p>The unique identifier of the contact to end.</p>
     #[serde(rename = "ContactId")]
@@ -720,8 +1012,25 @@ pub struct StopContactRequest {
     pub instance_id: String,
 }
 
+impl Validate for StopContactRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "contact_id"),
+            self.contact_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopContactResponse {}
 
 /// <p>A <code>Threshold</code> object that includes a comparison and <code>ThresholdValue</code> to compare to. Used with service level metrics.</p>
@@ -738,6 +1047,7 @@ pub struct Threshold {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateContactAttributesRequest {
     /// <p>Specify a custom key-value pair using an attribute map. The attributes are standard Amazon Connect attributes, and can be accessed in contact flows just like any other contact attributes.</p> <p>There can be up to 32,768 UTF-8 bytes across all key-value pairs per contact. Attribute keys can include only alphanumeric, dash, and underscore characters.</p>
     #[serde(rename = "Attributes")]
@@ -750,11 +1060,33 @@ pub struct UpdateContactAttributesRequest {
     pub instance_id: String,
 }
 
+impl Validate for UpdateContactAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (key, item) in self.attributes.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(32767));
+        }
+        errors.check_length(
+            &field_path(path, "initial_contact_id"),
+            self.initial_contact_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateContactAttributesResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserHierarchyRequest {
     /// <p>The identifier for the hierarchy group to assign to the user.</p>
     #[serde(rename = "HierarchyGroupId")]
@@ -768,7 +1100,19 @@ pub struct UpdateUserHierarchyRequest {
     pub user_id: String,
 }
 
+impl Validate for UpdateUserHierarchyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserIdentityInfoRequest {
     /// <p>A <code>UserIdentityInfo</code> object.</p>
     #[serde(rename = "IdentityInfo")]
@@ -781,7 +1125,21 @@ pub struct UpdateUserIdentityInfoRequest {
     pub user_id: String,
 }
 
+impl Validate for UpdateUserIdentityInfoRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.identity_info
+            .validate_at(&field_path(path, "identity_info"), errors);
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserPhoneConfigRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
@@ -794,7 +1152,21 @@ pub struct UpdateUserPhoneConfigRequest {
     pub user_id: String,
 }
 
+impl Validate for UpdateUserPhoneConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        self.phone_config
+            .validate_at(&field_path(path, "phone_config"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserRoutingProfileRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
@@ -807,7 +1179,19 @@ pub struct UpdateUserRoutingProfileRequest {
     pub user_id: String,
 }
 
+impl Validate for UpdateUserRoutingProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserSecurityProfilesRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
@@ -820,9 +1204,26 @@ pub struct UpdateUserSecurityProfilesRequest {
     pub user_id: String,
 }
 
+impl Validate for UpdateUserSecurityProfilesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "security_profile_ids"),
+            self.security_profile_ids.len(),
+            Some(1),
+            Some(10),
+        );
+    }
+}
+
 /// <p>A <code>User</code> object that contains information about a user account in your Amazon Connect instance, including configuration settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct User {
     /// <p>The ARN of the user account.</p>
     #[serde(rename = "Arn")]
@@ -879,6 +1280,27 @@ pub struct UserIdentityInfo {
     pub last_name: Option<String>,
 }
 
+impl Validate for UserIdentityInfo {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.first_name {
+            errors.check_length(
+                &field_path(path, "first_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.last_name {
+            errors.check_length(
+                &field_path(path, "last_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>A <code>UserPhoneConfig</code> object that contains information about the user phone configuration settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserPhoneConfig {
@@ -899,9 +1321,21 @@ pub struct UserPhoneConfig {
     pub phone_type: String,
 }
 
+impl Validate for UserPhoneConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.desk_phone_number {
+            errors.check_pattern(
+                &field_path(path, "desk_phone_number"),
+                &value,
+                "\\\\+[1-9]\\\\d{1,14}$",
+            );
+        }
+    }
+}
+
 /// <p>A <code>UserSummary</code> object that contains Information about a user, including ARN, Id, and user name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserSummary {
     /// <p>The ARN for the user account.</p>
     #[serde(rename = "Arn")]
@@ -961,7 +1395,12 @@ impl CreateUserError {
                 "ThrottlingException" => {
                     return RusotoError::Service(CreateUserError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1020,7 +1459,12 @@ impl DeleteUserError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DeleteUserError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1077,7 +1521,12 @@ impl DescribeUserError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DescribeUserError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1146,7 +1595,12 @@ impl DescribeUserHierarchyGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1215,7 +1669,12 @@ impl DescribeUserHierarchyStructureError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1266,7 +1725,12 @@ impl GetContactAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1327,7 +1791,12 @@ impl GetCurrentMetricDataError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetCurrentMetricDataError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1391,7 +1860,12 @@ impl GetFederationTokenError {
                 "UserNotFoundException" => {
                     return RusotoError::Service(GetFederationTokenError::UserNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1449,7 +1923,12 @@ impl GetMetricDataError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetMetricDataError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1510,7 +1989,12 @@ impl ListRoutingProfilesError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListRoutingProfilesError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1573,7 +2057,12 @@ impl ListSecurityProfilesError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListSecurityProfilesError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1638,7 +2127,12 @@ impl ListUserHierarchyGroupsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListUserHierarchyGroupsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1695,7 +2189,12 @@ impl ListUsersError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListUsersError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1776,7 +2275,12 @@ impl StartOutboundVoiceContactError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1835,7 +2339,12 @@ impl StopContactError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StopContactError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1895,7 +2404,12 @@ impl UpdateContactAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1955,7 +2469,12 @@ impl UpdateUserHierarchyError {
                 "ThrottlingException" => {
                     return RusotoError::Service(UpdateUserHierarchyError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2020,7 +2539,12 @@ impl UpdateUserIdentityInfoError {
                 "ThrottlingException" => {
                     return RusotoError::Service(UpdateUserIdentityInfoError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2085,7 +2609,12 @@ impl UpdateUserPhoneConfigError {
                 "ThrottlingException" => {
                     return RusotoError::Service(UpdateUserPhoneConfigError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2150,7 +2679,12 @@ impl UpdateUserRoutingProfileError {
                 "ThrottlingException" => {
                     return RusotoError::Service(UpdateUserRoutingProfileError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2219,7 +2753,12 @@ impl UpdateUserSecurityProfilesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2367,131 +2906,546 @@ pub trait Connect {
         input: UpdateUserSecurityProfilesRequest,
     ) -> RusotoFuture<(), UpdateUserSecurityProfilesError>;
 }
-/// A client for the Amazon Connect API.
-#[derive(Clone)]
-pub struct ConnectClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ConnectClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ConnectClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ConnectClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ConnectClient {
-        ConnectClient { client, region }
-    }
-}
-
-impl Connect for ConnectClient {
-    /// <p>Creates a new user account in your Amazon Connect instance.</p>
+const _: Option<&dyn Connect> = None;
+impl<'a, T: Connect + ?Sized> Connect for &'a T {
     fn create_user(
         &self,
         input: CreateUserRequest,
     ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
-        let request_uri = format!("/users/{instance_id}", instance_id = input.instance_id);
-
-        let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_user(input)
     }
-
-    /// <p>Deletes a user account from Amazon Connect.</p>
     fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
-        let request_uri = format!(
-            "/users/{instance_id}/{user_id}",
-            instance_id = input.instance_id,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_user(input)
     }
-
-    /// <p>Returns a <code>User</code> object that contains information about the user account specified by the <code>UserId</code>.</p>
     fn describe_user(
         &self,
         input: DescribeUserRequest,
     ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
-        let request_uri = format!(
-            "/users/{instance_id}/{user_id}",
-            instance_id = input.instance_id,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
+        (**self).describe_user(input)
+    }
+    fn describe_user_hierarchy_group(
+        &self,
+        input: DescribeUserHierarchyGroupRequest,
+    ) -> RusotoFuture<DescribeUserHierarchyGroupResponse, DescribeUserHierarchyGroupError> {
+        (**self).describe_user_hierarchy_group(input)
+    }
+    fn describe_user_hierarchy_structure(
+        &self,
+        input: DescribeUserHierarchyStructureRequest,
+    ) -> RusotoFuture<DescribeUserHierarchyStructureResponse, DescribeUserHierarchyStructureError>
+    {
+        (**self).describe_user_hierarchy_structure(input)
+    }
+    fn get_contact_attributes(
+        &self,
+        input: GetContactAttributesRequest,
+    ) -> RusotoFuture<GetContactAttributesResponse, GetContactAttributesError> {
+        (**self).get_contact_attributes(input)
+    }
+    fn get_current_metric_data(
+        &self,
+        input: GetCurrentMetricDataRequest,
+    ) -> RusotoFuture<GetCurrentMetricDataResponse, GetCurrentMetricDataError> {
+        (**self).get_current_metric_data(input)
+    }
+    fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        (**self).get_federation_token(input)
+    }
+    fn get_metric_data(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> RusotoFuture<GetMetricDataResponse, GetMetricDataError> {
+        (**self).get_metric_data(input)
+    }
+    fn list_routing_profiles(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> RusotoFuture<ListRoutingProfilesResponse, ListRoutingProfilesError> {
+        (**self).list_routing_profiles(input)
+    }
+    fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> RusotoFuture<ListSecurityProfilesResponse, ListSecurityProfilesError> {
+        (**self).list_security_profiles(input)
+    }
+    fn list_user_hierarchy_groups(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> RusotoFuture<ListUserHierarchyGroupsResponse, ListUserHierarchyGroupsError> {
+        (**self).list_user_hierarchy_groups(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn start_outbound_voice_contact(
+        &self,
+        input: StartOutboundVoiceContactRequest,
+    ) -> RusotoFuture<StartOutboundVoiceContactResponse, StartOutboundVoiceContactError> {
+        (**self).start_outbound_voice_contact(input)
+    }
+    fn stop_contact(
+        &self,
+        input: StopContactRequest,
+    ) -> RusotoFuture<StopContactResponse, StopContactError> {
+        (**self).stop_contact(input)
+    }
+    fn update_contact_attributes(
+        &self,
+        input: UpdateContactAttributesRequest,
+    ) -> RusotoFuture<UpdateContactAttributesResponse, UpdateContactAttributesError> {
+        (**self).update_contact_attributes(input)
+    }
+    fn update_user_hierarchy(
+        &self,
+        input: UpdateUserHierarchyRequest,
+    ) -> RusotoFuture<(), UpdateUserHierarchyError> {
+        (**self).update_user_hierarchy(input)
+    }
+    fn update_user_identity_info(
+        &self,
+        input: UpdateUserIdentityInfoRequest,
+    ) -> RusotoFuture<(), UpdateUserIdentityInfoError> {
+        (**self).update_user_identity_info(input)
+    }
+    fn update_user_phone_config(
+        &self,
+        input: UpdateUserPhoneConfigRequest,
+    ) -> RusotoFuture<(), UpdateUserPhoneConfigError> {
+        (**self).update_user_phone_config(input)
+    }
+    fn update_user_routing_profile(
+        &self,
+        input: UpdateUserRoutingProfileRequest,
+    ) -> RusotoFuture<(), UpdateUserRoutingProfileError> {
+        (**self).update_user_routing_profile(input)
+    }
+    fn update_user_security_profiles(
+        &self,
+        input: UpdateUserSecurityProfilesRequest,
+    ) -> RusotoFuture<(), UpdateUserSecurityProfilesError> {
+        (**self).update_user_security_profiles(input)
+    }
+}
+impl<T: Connect + ?Sized> Connect for Box<T> {
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn describe_user_hierarchy_group(
+        &self,
+        input: DescribeUserHierarchyGroupRequest,
+    ) -> RusotoFuture<DescribeUserHierarchyGroupResponse, DescribeUserHierarchyGroupError> {
+        (**self).describe_user_hierarchy_group(input)
+    }
+    fn describe_user_hierarchy_structure(
+        &self,
+        input: DescribeUserHierarchyStructureRequest,
+    ) -> RusotoFuture<DescribeUserHierarchyStructureResponse, DescribeUserHierarchyStructureError>
+    {
+        (**self).describe_user_hierarchy_structure(input)
+    }
+    fn get_contact_attributes(
+        &self,
+        input: GetContactAttributesRequest,
+    ) -> RusotoFuture<GetContactAttributesResponse, GetContactAttributesError> {
+        (**self).get_contact_attributes(input)
+    }
+    fn get_current_metric_data(
+        &self,
+        input: GetCurrentMetricDataRequest,
+    ) -> RusotoFuture<GetCurrentMetricDataResponse, GetCurrentMetricDataError> {
+        (**self).get_current_metric_data(input)
+    }
+    fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        (**self).get_federation_token(input)
+    }
+    fn get_metric_data(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> RusotoFuture<GetMetricDataResponse, GetMetricDataError> {
+        (**self).get_metric_data(input)
+    }
+    fn list_routing_profiles(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> RusotoFuture<ListRoutingProfilesResponse, ListRoutingProfilesError> {
+        (**self).list_routing_profiles(input)
+    }
+    fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> RusotoFuture<ListSecurityProfilesResponse, ListSecurityProfilesError> {
+        (**self).list_security_profiles(input)
+    }
+    fn list_user_hierarchy_groups(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> RusotoFuture<ListUserHierarchyGroupsResponse, ListUserHierarchyGroupsError> {
+        (**self).list_user_hierarchy_groups(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn start_outbound_voice_contact(
+        &self,
+        input: StartOutboundVoiceContactRequest,
+    ) -> RusotoFuture<StartOutboundVoiceContactResponse, StartOutboundVoiceContactError> {
+        (**self).start_outbound_voice_contact(input)
+    }
+    fn stop_contact(
+        &self,
+        input: StopContactRequest,
+    ) -> RusotoFuture<StopContactResponse, StopContactError> {
+        (**self).stop_contact(input)
+    }
+    fn update_contact_attributes(
+        &self,
+        input: UpdateContactAttributesRequest,
+    ) -> RusotoFuture<UpdateContactAttributesResponse, UpdateContactAttributesError> {
+        (**self).update_contact_attributes(input)
+    }
+    fn update_user_hierarchy(
+        &self,
+        input: UpdateUserHierarchyRequest,
+    ) -> RusotoFuture<(), UpdateUserHierarchyError> {
+        (**self).update_user_hierarchy(input)
+    }
+    fn update_user_identity_info(
+        &self,
+        input: UpdateUserIdentityInfoRequest,
+    ) -> RusotoFuture<(), UpdateUserIdentityInfoError> {
+        (**self).update_user_identity_info(input)
+    }
+    fn update_user_phone_config(
+        &self,
+        input: UpdateUserPhoneConfigRequest,
+    ) -> RusotoFuture<(), UpdateUserPhoneConfigError> {
+        (**self).update_user_phone_config(input)
+    }
+    fn update_user_routing_profile(
+        &self,
+        input: UpdateUserRoutingProfileRequest,
+    ) -> RusotoFuture<(), UpdateUserRoutingProfileError> {
+        (**self).update_user_routing_profile(input)
+    }
+    fn update_user_security_profiles(
+        &self,
+        input: UpdateUserSecurityProfilesRequest,
+    ) -> RusotoFuture<(), UpdateUserSecurityProfilesError> {
+        (**self).update_user_security_profiles(input)
+    }
+}
+impl<T: Connect + ?Sized> Connect for ::std::sync::Arc<T> {
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn describe_user_hierarchy_group(
+        &self,
+        input: DescribeUserHierarchyGroupRequest,
+    ) -> RusotoFuture<DescribeUserHierarchyGroupResponse, DescribeUserHierarchyGroupError> {
+        (**self).describe_user_hierarchy_group(input)
+    }
+    fn describe_user_hierarchy_structure(
+        &self,
+        input: DescribeUserHierarchyStructureRequest,
+    ) -> RusotoFuture<DescribeUserHierarchyStructureResponse, DescribeUserHierarchyStructureError>
+    {
+        (**self).describe_user_hierarchy_structure(input)
+    }
+    fn get_contact_attributes(
+        &self,
+        input: GetContactAttributesRequest,
+    ) -> RusotoFuture<GetContactAttributesResponse, GetContactAttributesError> {
+        (**self).get_contact_attributes(input)
+    }
+    fn get_current_metric_data(
+        &self,
+        input: GetCurrentMetricDataRequest,
+    ) -> RusotoFuture<GetCurrentMetricDataResponse, GetCurrentMetricDataError> {
+        (**self).get_current_metric_data(input)
+    }
+    fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        (**self).get_federation_token(input)
+    }
+    fn get_metric_data(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> RusotoFuture<GetMetricDataResponse, GetMetricDataError> {
+        (**self).get_metric_data(input)
+    }
+    fn list_routing_profiles(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> RusotoFuture<ListRoutingProfilesResponse, ListRoutingProfilesError> {
+        (**self).list_routing_profiles(input)
+    }
+    fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> RusotoFuture<ListSecurityProfilesResponse, ListSecurityProfilesError> {
+        (**self).list_security_profiles(input)
+    }
+    fn list_user_hierarchy_groups(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> RusotoFuture<ListUserHierarchyGroupsResponse, ListUserHierarchyGroupsError> {
+        (**self).list_user_hierarchy_groups(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn start_outbound_voice_contact(
+        &self,
+        input: StartOutboundVoiceContactRequest,
+    ) -> RusotoFuture<StartOutboundVoiceContactResponse, StartOutboundVoiceContactError> {
+        (**self).start_outbound_voice_contact(input)
+    }
+    fn stop_contact(
+        &self,
+        input: StopContactRequest,
+    ) -> RusotoFuture<StopContactResponse, StopContactError> {
+        (**self).stop_contact(input)
+    }
+    fn update_contact_attributes(
+        &self,
+        input: UpdateContactAttributesRequest,
+    ) -> RusotoFuture<UpdateContactAttributesResponse, UpdateContactAttributesError> {
+        (**self).update_contact_attributes(input)
+    }
+    fn update_user_hierarchy(
+        &self,
+        input: UpdateUserHierarchyRequest,
+    ) -> RusotoFuture<(), UpdateUserHierarchyError> {
+        (**self).update_user_hierarchy(input)
+    }
+    fn update_user_identity_info(
+        &self,
+        input: UpdateUserIdentityInfoRequest,
+    ) -> RusotoFuture<(), UpdateUserIdentityInfoError> {
+        (**self).update_user_identity_info(input)
+    }
+    fn update_user_phone_config(
+        &self,
+        input: UpdateUserPhoneConfigRequest,
+    ) -> RusotoFuture<(), UpdateUserPhoneConfigError> {
+        (**self).update_user_phone_config(input)
+    }
+    fn update_user_routing_profile(
+        &self,
+        input: UpdateUserRoutingProfileRequest,
+    ) -> RusotoFuture<(), UpdateUserRoutingProfileError> {
+        (**self).update_user_routing_profile(input)
+    }
+    fn update_user_security_profiles(
+        &self,
+        input: UpdateUserSecurityProfilesRequest,
+    ) -> RusotoFuture<(), UpdateUserSecurityProfilesError> {
+        (**self).update_user_security_profiles(input)
+    }
+}
+/// A client for the Amazon Connect API.
+#[derive(Clone)]
+pub struct ConnectClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ConnectClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ConnectClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ConnectClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ConnectClient {
+        ConnectClient { client, region }
+    }
+}
+
+impl Connect for ConnectClient {
+    /// <p>Creates a new user account in your Amazon Connect instance.</p>
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/users/{instance_id}", instance_id = input.instance_id);
+
+        let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateUser");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateUser")
+                        .deserialize::<CreateUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a user account from Amazon Connect.</p>
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/users/{instance_id}/{user_id}",
+            instance_id = input.instance_id,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a <code>User</code> object that contains information about the user account specified by the <code>UserId</code>.</p>
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/users/{instance_id}/{user_id}",
+            instance_id = input.instance_id,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeUser")
+                        .deserialize::<DescribeUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
                         .from_err()
                         .and_then(|response| Err(DescribeUserError::from_response(response))),
                 )
@@ -2504,6 +3458,14 @@ impl Connect for ConnectClient {
         &self,
         input: DescribeUserHierarchyGroupRequest,
     ) -> RusotoFuture<DescribeUserHierarchyGroupResponse, DescribeUserHierarchyGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/user-hierarchy-groups/{instance_id}/{hierarchy_group_id}",
             hierarchy_group_id = input.hierarchy_group_id,
@@ -2511,12 +3473,17 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUserHierarchyGroup");
+        request.add_resource_id("HierarchyGroupId", &input.hierarchy_group_id.to_string());
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeUserHierarchyGroup")
                         .deserialize::<DescribeUserHierarchyGroupResponse, _>()?;
 
                     Ok(result)
@@ -2535,18 +3502,30 @@ impl Connect for ConnectClient {
         input: DescribeUserHierarchyStructureRequest,
     ) -> RusotoFuture<DescribeUserHierarchyStructureResponse, DescribeUserHierarchyStructureError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/user-hierarchy-structure/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUserHierarchyStructure");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeUserHierarchyStructure")
                         .deserialize::<DescribeUserHierarchyStructureResponse, _>()?;
 
                     Ok(result)
@@ -2564,6 +3543,14 @@ impl Connect for ConnectClient {
         &self,
         input: GetContactAttributesRequest,
     ) -> RusotoFuture<GetContactAttributesResponse, GetContactAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/contact/attributes/{instance_id}/{initial_contact_id}",
             initial_contact_id = input.initial_contact_id,
@@ -2571,12 +3558,17 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("GetContactAttributes");
+        request.add_resource_id("InitialContactId", &input.initial_contact_id.to_string());
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetContactAttributes")
                         .deserialize::<GetContactAttributesResponse, _>()?;
 
                     Ok(result)
@@ -2596,12 +3588,23 @@ impl Connect for ConnectClient {
         &self,
         input: GetCurrentMetricDataRequest,
     ) -> RusotoFuture<GetCurrentMetricDataResponse, GetCurrentMetricDataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/metrics/current/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("GetCurrentMetricData");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2611,6 +3614,7 @@ impl Connect for ConnectClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetCurrentMetricData")
                         .deserialize::<GetCurrentMetricDataResponse, _>()?;
 
                     Ok(result)
@@ -2630,18 +3634,30 @@ impl Connect for ConnectClient {
         &self,
         input: GetFederationTokenRequest,
     ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/user/federate/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("GetFederationToken");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFederationToken")
                         .deserialize::<GetFederationTokenResponse, _>()?;
 
                     Ok(result)
@@ -2662,12 +3678,23 @@ impl Connect for ConnectClient {
         &self,
         input: GetMetricDataRequest,
     ) -> RusotoFuture<GetMetricDataResponse, GetMetricDataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/metrics/historical/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("GetMetricData");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2677,6 +3704,7 @@ impl Connect for ConnectClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetMetricData")
                         .deserialize::<GetMetricDataResponse, _>()?;
 
                     Ok(result)
@@ -2697,12 +3725,23 @@ impl Connect for ConnectClient {
         &self,
         input: ListRoutingProfilesRequest,
     ) -> RusotoFuture<ListRoutingProfilesResponse, ListRoutingProfilesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/routing-profiles-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListRoutingProfiles");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2718,6 +3757,7 @@ impl Connect for ConnectClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListRoutingProfiles")
                         .deserialize::<ListRoutingProfilesResponse, _>()?;
 
                     Ok(result)
@@ -2737,12 +3777,23 @@ impl Connect for ConnectClient {
         &self,
         input: ListSecurityProfilesRequest,
     ) -> RusotoFuture<ListSecurityProfilesResponse, ListSecurityProfilesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/security-profiles-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListSecurityProfiles");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2758,6 +3809,7 @@ impl Connect for ConnectClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListSecurityProfiles")
                         .deserialize::<ListSecurityProfilesResponse, _>()?;
 
                     Ok(result)
@@ -2777,12 +3829,23 @@ impl Connect for ConnectClient {
         &self,
         input: ListUserHierarchyGroupsRequest,
     ) -> RusotoFuture<ListUserHierarchyGroupsResponse, ListUserHierarchyGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/user-hierarchy-groups-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListUserHierarchyGroups");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2798,6 +3861,7 @@ impl Connect for ConnectClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListUserHierarchyGroups")
                         .deserialize::<ListUserHierarchyGroupsResponse, _>()?;
 
                     Ok(result)
@@ -2815,12 +3879,23 @@ impl Connect for ConnectClient {
         &self,
         input: ListUsersRequest,
     ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/users-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListUsers");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2836,6 +3911,7 @@ impl Connect for ConnectClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListUsers")
                         .deserialize::<ListUsersResponse, _>()?;
 
                     Ok(result)
@@ -2856,9 +3932,19 @@ impl Connect for ConnectClient {
         &self,
         input: StartOutboundVoiceContactRequest,
     ) -> RusotoFuture<StartOutboundVoiceContactResponse, StartOutboundVoiceContactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/contact/outbound-voice";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("StartOutboundVoiceContact");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2868,6 +3954,7 @@ impl Connect for ConnectClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartOutboundVoiceContact")
                         .deserialize::<StartOutboundVoiceContactResponse, _>()?;
 
                     Ok(result)
@@ -2885,9 +3972,18 @@ impl Connect for ConnectClient {
         &self,
         input: StopContactRequest,
     ) -> RusotoFuture<StopContactResponse, StopContactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/contact/stop";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("StopContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2897,6 +3993,7 @@ impl Connect for ConnectClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StopContact")
                         .deserialize::<StopContactResponse, _>()?;
 
                     Ok(result)
@@ -2917,9 +4014,18 @@ impl Connect for ConnectClient {
         &self,
         input: UpdateContactAttributesRequest,
     ) -> RusotoFuture<UpdateContactAttributesResponse, UpdateContactAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/contact/attributes";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateContactAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2929,6 +4035,7 @@ impl Connect for ConnectClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateContactAttributes")
                         .deserialize::<UpdateContactAttributesResponse, _>()?;
 
                     Ok(result)
@@ -2946,6 +4053,14 @@ impl Connect for ConnectClient {
         &self,
         input: UpdateUserHierarchyRequest,
     ) -> RusotoFuture<(), UpdateUserHierarchyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/users/{instance_id}/{user_id}/hierarchy",
             instance_id = input.instance_id,
@@ -2953,6 +4068,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserHierarchy");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2980,6 +4098,14 @@ impl Connect for ConnectClient {
         &self,
         input: UpdateUserIdentityInfoRequest,
     ) -> RusotoFuture<(), UpdateUserIdentityInfoError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/users/{instance_id}/{user_id}/identity-info",
             instance_id = input.instance_id,
@@ -2987,6 +4113,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserIdentityInfo");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3014,6 +4143,14 @@ impl Connect for ConnectClient {
         &self,
         input: UpdateUserPhoneConfigRequest,
     ) -> RusotoFuture<(), UpdateUserPhoneConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/users/{instance_id}/{user_id}/phone-config",
             instance_id = input.instance_id,
@@ -3021,6 +4158,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserPhoneConfig");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3048,6 +4188,14 @@ impl Connect for ConnectClient {
         &self,
         input: UpdateUserRoutingProfileRequest,
     ) -> RusotoFuture<(), UpdateUserRoutingProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/users/{instance_id}/{user_id}/routing-profile",
             instance_id = input.instance_id,
@@ -3055,6 +4203,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserRoutingProfile");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3080,6 +4231,14 @@ impl Connect for ConnectClient {
         &self,
         input: UpdateUserSecurityProfilesRequest,
     ) -> RusotoFuture<(), UpdateUserSecurityProfilesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/users/{instance_id}/{user_id}/security-profiles",
             instance_id = input.instance_id,
@@ -3087,6 +4246,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserSecurityProfiles");
+        request.add_resource_id("InstanceId", &input.instance_id.to_string());
+        request.add_resource_id("UserId", &input.user_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3107,3 +4269,725 @@ impl Connect for ConnectClient {
         })
     }
 }
+
+/// A mock of the Amazon Connect API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockConnect {
+    create_user: Option<
+        Box<
+            dyn Fn(
+                    CreateUserRequest,
+                )
+                    -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user: Option<
+        Box<
+            dyn Fn(DeleteUserRequest) -> ::std::result::Result<(), RusotoError<DeleteUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_user: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserRequest,
+                )
+                    -> ::std::result::Result<DescribeUserResponse, RusotoError<DescribeUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_user_hierarchy_group: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserHierarchyGroupRequest,
+                ) -> ::std::result::Result<
+                    DescribeUserHierarchyGroupResponse,
+                    RusotoError<DescribeUserHierarchyGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user_hierarchy_structure: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserHierarchyStructureRequest,
+                ) -> ::std::result::Result<
+                    DescribeUserHierarchyStructureResponse,
+                    RusotoError<DescribeUserHierarchyStructureError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_contact_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetContactAttributesRequest,
+                ) -> ::std::result::Result<
+                    GetContactAttributesResponse,
+                    RusotoError<GetContactAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_current_metric_data: Option<
+        Box<
+            dyn Fn(
+                    GetCurrentMetricDataRequest,
+                ) -> ::std::result::Result<
+                    GetCurrentMetricDataResponse,
+                    RusotoError<GetCurrentMetricDataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_federation_token: Option<
+        Box<
+            dyn Fn(
+                    GetFederationTokenRequest,
+                ) -> ::std::result::Result<
+                    GetFederationTokenResponse,
+                    RusotoError<GetFederationTokenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_metric_data: Option<
+        Box<
+            dyn Fn(
+                    GetMetricDataRequest,
+                )
+                    -> ::std::result::Result<GetMetricDataResponse, RusotoError<GetMetricDataError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_routing_profiles: Option<
+        Box<
+            dyn Fn(
+                    ListRoutingProfilesRequest,
+                ) -> ::std::result::Result<
+                    ListRoutingProfilesResponse,
+                    RusotoError<ListRoutingProfilesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_security_profiles: Option<
+        Box<
+            dyn Fn(
+                    ListSecurityProfilesRequest,
+                ) -> ::std::result::Result<
+                    ListSecurityProfilesResponse,
+                    RusotoError<ListSecurityProfilesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_user_hierarchy_groups: Option<
+        Box<
+            dyn Fn(
+                    ListUserHierarchyGroupsRequest,
+                ) -> ::std::result::Result<
+                    ListUserHierarchyGroupsResponse,
+                    RusotoError<ListUserHierarchyGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_users: Option<
+        Box<
+            dyn Fn(
+                    ListUsersRequest,
+                )
+                    -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_outbound_voice_contact: Option<
+        Box<
+            dyn Fn(
+                    StartOutboundVoiceContactRequest,
+                ) -> ::std::result::Result<
+                    StartOutboundVoiceContactResponse,
+                    RusotoError<StartOutboundVoiceContactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_contact: Option<
+        Box<
+            dyn Fn(
+                    StopContactRequest,
+                )
+                    -> ::std::result::Result<StopContactResponse, RusotoError<StopContactError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_contact_attributes: Option<
+        Box<
+            dyn Fn(
+                    UpdateContactAttributesRequest,
+                ) -> ::std::result::Result<
+                    UpdateContactAttributesResponse,
+                    RusotoError<UpdateContactAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_user_hierarchy: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserHierarchyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateUserHierarchyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_user_identity_info: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserIdentityInfoRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateUserIdentityInfoError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_user_phone_config: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserPhoneConfigRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateUserPhoneConfigError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_user_routing_profile: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserRoutingProfileRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateUserRoutingProfileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_user_security_profiles: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserSecurityProfilesRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateUserSecurityProfilesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockConnect {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockConnect {
+        Default::default()
+    }
+
+    /// Answers `create_user` with what `handler` returns for its input.
+    pub fn with_create_user<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                CreateUserRequest,
+            )
+                -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user` with what `handler` returns for its input.
+    pub fn with_delete_user<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(DeleteUserRequest) -> ::std::result::Result<(), RusotoError<DeleteUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user` with what `handler` returns for its input.
+    pub fn with_describe_user<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                DescribeUserRequest,
+            )
+                -> ::std::result::Result<DescribeUserResponse, RusotoError<DescribeUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user_hierarchy_group` with what `handler` returns for its input.
+    pub fn with_describe_user_hierarchy_group<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                DescribeUserHierarchyGroupRequest,
+            ) -> ::std::result::Result<
+                DescribeUserHierarchyGroupResponse,
+                RusotoError<DescribeUserHierarchyGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user_hierarchy_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user_hierarchy_structure` with what `handler` returns for its input.
+    pub fn with_describe_user_hierarchy_structure<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                DescribeUserHierarchyStructureRequest,
+            ) -> ::std::result::Result<
+                DescribeUserHierarchyStructureResponse,
+                RusotoError<DescribeUserHierarchyStructureError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user_hierarchy_structure = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_contact_attributes` with what `handler` returns for its input.
+    pub fn with_get_contact_attributes<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                GetContactAttributesRequest,
+            ) -> ::std::result::Result<
+                GetContactAttributesResponse,
+                RusotoError<GetContactAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_contact_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_current_metric_data` with what `handler` returns for its input.
+    pub fn with_get_current_metric_data<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                GetCurrentMetricDataRequest,
+            ) -> ::std::result::Result<
+                GetCurrentMetricDataResponse,
+                RusotoError<GetCurrentMetricDataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_current_metric_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_federation_token` with what `handler` returns for its input.
+    pub fn with_get_federation_token<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                GetFederationTokenRequest,
+            ) -> ::std::result::Result<
+                GetFederationTokenResponse,
+                RusotoError<GetFederationTokenError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_federation_token = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_metric_data` with what `handler` returns for its input.
+    pub fn with_get_metric_data<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                GetMetricDataRequest,
+            )
+                -> ::std::result::Result<GetMetricDataResponse, RusotoError<GetMetricDataError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_metric_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_routing_profiles` with what `handler` returns for its input.
+    pub fn with_list_routing_profiles<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                ListRoutingProfilesRequest,
+            ) -> ::std::result::Result<
+                ListRoutingProfilesResponse,
+                RusotoError<ListRoutingProfilesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_routing_profiles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_security_profiles` with what `handler` returns for its input.
+    pub fn with_list_security_profiles<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                ListSecurityProfilesRequest,
+            ) -> ::std::result::Result<
+                ListSecurityProfilesResponse,
+                RusotoError<ListSecurityProfilesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_security_profiles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_user_hierarchy_groups` with what `handler` returns for its input.
+    pub fn with_list_user_hierarchy_groups<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                ListUserHierarchyGroupsRequest,
+            ) -> ::std::result::Result<
+                ListUserHierarchyGroupsResponse,
+                RusotoError<ListUserHierarchyGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_user_hierarchy_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_users` with what `handler` returns for its input.
+    pub fn with_list_users<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                ListUsersRequest,
+            ) -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_outbound_voice_contact` with what `handler` returns for its input.
+    pub fn with_start_outbound_voice_contact<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                StartOutboundVoiceContactRequest,
+            ) -> ::std::result::Result<
+                StartOutboundVoiceContactResponse,
+                RusotoError<StartOutboundVoiceContactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_outbound_voice_contact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_contact` with what `handler` returns for its input.
+    pub fn with_stop_contact<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                StopContactRequest,
+            )
+                -> ::std::result::Result<StopContactResponse, RusotoError<StopContactError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_contact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_contact_attributes` with what `handler` returns for its input.
+    pub fn with_update_contact_attributes<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                UpdateContactAttributesRequest,
+            ) -> ::std::result::Result<
+                UpdateContactAttributesResponse,
+                RusotoError<UpdateContactAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_contact_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_hierarchy` with what `handler` returns for its input.
+    pub fn with_update_user_hierarchy<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                UpdateUserHierarchyRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateUserHierarchyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_hierarchy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_identity_info` with what `handler` returns for its input.
+    pub fn with_update_user_identity_info<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                UpdateUserIdentityInfoRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateUserIdentityInfoError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_identity_info = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_phone_config` with what `handler` returns for its input.
+    pub fn with_update_user_phone_config<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                UpdateUserPhoneConfigRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateUserPhoneConfigError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_phone_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_routing_profile` with what `handler` returns for its input.
+    pub fn with_update_user_routing_profile<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                UpdateUserRoutingProfileRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateUserRoutingProfileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_routing_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_security_profiles` with what `handler` returns for its input.
+    pub fn with_update_user_security_profiles<F>(mut self, handler: F) -> MockConnect
+    where
+        F: Fn(
+                UpdateUserSecurityProfilesRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<UpdateUserSecurityProfilesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_security_profiles = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Connect for MockConnect {
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        match self.create_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for create_user"),
+        }
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        match self.delete_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for delete_user"),
+        }
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        match self.describe_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for describe_user"),
+        }
+    }
+    fn describe_user_hierarchy_group(
+        &self,
+        input: DescribeUserHierarchyGroupRequest,
+    ) -> RusotoFuture<DescribeUserHierarchyGroupResponse, DescribeUserHierarchyGroupError> {
+        match self.describe_user_hierarchy_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for describe_user_hierarchy_group"),
+        }
+    }
+    fn describe_user_hierarchy_structure(
+        &self,
+        input: DescribeUserHierarchyStructureRequest,
+    ) -> RusotoFuture<DescribeUserHierarchyStructureResponse, DescribeUserHierarchyStructureError>
+    {
+        match self.describe_user_hierarchy_structure {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for describe_user_hierarchy_structure"),
+        }
+    }
+    fn get_contact_attributes(
+        &self,
+        input: GetContactAttributesRequest,
+    ) -> RusotoFuture<GetContactAttributesResponse, GetContactAttributesError> {
+        match self.get_contact_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for get_contact_attributes"),
+        }
+    }
+    fn get_current_metric_data(
+        &self,
+        input: GetCurrentMetricDataRequest,
+    ) -> RusotoFuture<GetCurrentMetricDataResponse, GetCurrentMetricDataError> {
+        match self.get_current_metric_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for get_current_metric_data"),
+        }
+    }
+    fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        match self.get_federation_token {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for get_federation_token"),
+        }
+    }
+    fn get_metric_data(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> RusotoFuture<GetMetricDataResponse, GetMetricDataError> {
+        match self.get_metric_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for get_metric_data"),
+        }
+    }
+    fn list_routing_profiles(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> RusotoFuture<ListRoutingProfilesResponse, ListRoutingProfilesError> {
+        match self.list_routing_profiles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for list_routing_profiles"),
+        }
+    }
+    fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> RusotoFuture<ListSecurityProfilesResponse, ListSecurityProfilesError> {
+        match self.list_security_profiles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for list_security_profiles"),
+        }
+    }
+    fn list_user_hierarchy_groups(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> RusotoFuture<ListUserHierarchyGroupsResponse, ListUserHierarchyGroupsError> {
+        match self.list_user_hierarchy_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for list_user_hierarchy_groups"),
+        }
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        match self.list_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for list_users"),
+        }
+    }
+    fn start_outbound_voice_contact(
+        &self,
+        input: StartOutboundVoiceContactRequest,
+    ) -> RusotoFuture<StartOutboundVoiceContactResponse, StartOutboundVoiceContactError> {
+        match self.start_outbound_voice_contact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for start_outbound_voice_contact"),
+        }
+    }
+    fn stop_contact(
+        &self,
+        input: StopContactRequest,
+    ) -> RusotoFuture<StopContactResponse, StopContactError> {
+        match self.stop_contact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for stop_contact"),
+        }
+    }
+    fn update_contact_attributes(
+        &self,
+        input: UpdateContactAttributesRequest,
+    ) -> RusotoFuture<UpdateContactAttributesResponse, UpdateContactAttributesError> {
+        match self.update_contact_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for update_contact_attributes"),
+        }
+    }
+    fn update_user_hierarchy(
+        &self,
+        input: UpdateUserHierarchyRequest,
+    ) -> RusotoFuture<(), UpdateUserHierarchyError> {
+        match self.update_user_hierarchy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for update_user_hierarchy"),
+        }
+    }
+    fn update_user_identity_info(
+        &self,
+        input: UpdateUserIdentityInfoRequest,
+    ) -> RusotoFuture<(), UpdateUserIdentityInfoError> {
+        match self.update_user_identity_info {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for update_user_identity_info"),
+        }
+    }
+    fn update_user_phone_config(
+        &self,
+        input: UpdateUserPhoneConfigRequest,
+    ) -> RusotoFuture<(), UpdateUserPhoneConfigError> {
+        match self.update_user_phone_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for update_user_phone_config"),
+        }
+    }
+    fn update_user_routing_profile(
+        &self,
+        input: UpdateUserRoutingProfileRequest,
+    ) -> RusotoFuture<(), UpdateUserRoutingProfileError> {
+        match self.update_user_routing_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for update_user_routing_profile"),
+        }
+    }
+    fn update_user_security_profiles(
+        &self,
+        input: UpdateUserSecurityProfilesRequest,
+    ) -> RusotoFuture<(), UpdateUserSecurityProfilesError> {
+        match self.update_user_security_profiles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockConnect has no handler for update_user_security_profiles"),
+        }
+    }
+}
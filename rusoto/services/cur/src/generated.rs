@@ -22,18 +22,38 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Deletes the specified report.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReportDefinitionRequest {
     #[serde(rename = "ReportName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub report_name: Option<String>,
 }
 
+impl Validate for DeleteReportDefinitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.report_name {
+            errors.check_length(
+                &field_path(path, "report_name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "report_name"),
+                &value,
+                "[0-9A-Za-z!\\-_.*\\'()]+",
+            );
+        }
+    }
+}
+
 /// <p>If the action is successful, the service sends back an HTTP 200 response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteReportDefinitionResponse {
     #[serde(rename = "ResponseMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -42,6 +62,7 @@ pub struct DeleteReportDefinitionResponse {
 
 /// <p>Requests a list of AWS Cost and Usage reports owned by the account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReportDefinitionsRequest {
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,9 +72,30 @@ pub struct DescribeReportDefinitionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeReportDefinitionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(5), Some(5));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[A-Za-z0-9_\\.\\-=]*",
+            );
+        }
+    }
+}
+
 /// <p>If the action is successful, the service sends back an HTTP 200 response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeReportDefinitionsResponse {
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -65,6 +107,7 @@ pub struct DescribeReportDefinitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyReportDefinitionRequest {
     #[serde(rename = "ReportDefinition")]
     pub report_definition: ReportDefinition,
@@ -72,21 +115,47 @@ pub struct ModifyReportDefinitionRequest {
     pub report_name: String,
 }
 
+impl Validate for ModifyReportDefinitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.report_definition
+            .validate_at(&field_path(path, "report_definition"), errors);
+        errors.check_length(
+            &field_path(path, "report_name"),
+            self.report_name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "report_name"),
+            &self.report_name,
+            "[0-9A-Za-z!\\-_.*\\'()]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyReportDefinitionResponse {}
 
 /// <p>Creates a Cost and Usage Report.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutReportDefinitionRequest {
     /// <p>Represents the output of the PutReportDefinition operation. The content consists of the detailed metadata and data file information. </p>
     #[serde(rename = "ReportDefinition")]
     pub report_definition: ReportDefinition,
 }
 
+impl Validate for PutReportDefinitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.report_definition
+            .validate_at(&field_path(path, "report_definition"), errors);
+    }
+}
+
 /// <p>If the action is successful, the service sends back an HTTP 200 response with an empty HTTP body.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutReportDefinitionResponse {}
 
 /// <p>The definition of AWS Cost and Usage Report. You can specify the report name, time unit, report format, compression format, S3 bucket, additional artifacts, and schema elements in the definition. </p>
@@ -123,6 +192,44 @@ pub struct ReportDefinition {
     pub time_unit: String,
 }
 
+impl Validate for ReportDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "report_name"),
+            self.report_name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "report_name"),
+            &self.report_name,
+            "[0-9A-Za-z!\\-_.*\\'()]+",
+        );
+        errors.check_length(
+            &field_path(path, "s3_bucket"),
+            self.s3_bucket.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_bucket"),
+            &self.s3_bucket,
+            "[A-Za-z0-9_\\.\\-]+",
+        );
+        errors.check_length(
+            &field_path(path, "s3_prefix"),
+            self.s3_prefix.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_prefix"),
+            &self.s3_prefix,
+            "[0-9A-Za-z!\\-_.*\\'()/]*",
+        );
+    }
+}
+
 /// Errors returned by DeleteReportDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteReportDefinitionError {
@@ -139,7 +246,12 @@ impl DeleteReportDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -174,7 +286,12 @@ impl DescribeReportDefinitionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -209,7 +326,12 @@ impl ModifyReportDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -256,7 +378,12 @@ impl PutReportDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -303,6 +430,85 @@ pub trait CostAndUsageReport {
         input: PutReportDefinitionRequest,
     ) -> RusotoFuture<PutReportDefinitionResponse, PutReportDefinitionError>;
 }
+const _: Option<&dyn CostAndUsageReport> = None;
+impl<'a, T: CostAndUsageReport + ?Sized> CostAndUsageReport for &'a T {
+    fn delete_report_definition(
+        &self,
+        input: DeleteReportDefinitionRequest,
+    ) -> RusotoFuture<DeleteReportDefinitionResponse, DeleteReportDefinitionError> {
+        (**self).delete_report_definition(input)
+    }
+    fn describe_report_definitions(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> RusotoFuture<DescribeReportDefinitionsResponse, DescribeReportDefinitionsError> {
+        (**self).describe_report_definitions(input)
+    }
+    fn modify_report_definition(
+        &self,
+        input: ModifyReportDefinitionRequest,
+    ) -> RusotoFuture<ModifyReportDefinitionResponse, ModifyReportDefinitionError> {
+        (**self).modify_report_definition(input)
+    }
+    fn put_report_definition(
+        &self,
+        input: PutReportDefinitionRequest,
+    ) -> RusotoFuture<PutReportDefinitionResponse, PutReportDefinitionError> {
+        (**self).put_report_definition(input)
+    }
+}
+impl<T: CostAndUsageReport + ?Sized> CostAndUsageReport for Box<T> {
+    fn delete_report_definition(
+        &self,
+        input: DeleteReportDefinitionRequest,
+    ) -> RusotoFuture<DeleteReportDefinitionResponse, DeleteReportDefinitionError> {
+        (**self).delete_report_definition(input)
+    }
+    fn describe_report_definitions(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> RusotoFuture<DescribeReportDefinitionsResponse, DescribeReportDefinitionsError> {
+        (**self).describe_report_definitions(input)
+    }
+    fn modify_report_definition(
+        &self,
+        input: ModifyReportDefinitionRequest,
+    ) -> RusotoFuture<ModifyReportDefinitionResponse, ModifyReportDefinitionError> {
+        (**self).modify_report_definition(input)
+    }
+    fn put_report_definition(
+        &self,
+        input: PutReportDefinitionRequest,
+    ) -> RusotoFuture<PutReportDefinitionResponse, PutReportDefinitionError> {
+        (**self).put_report_definition(input)
+    }
+}
+impl<T: CostAndUsageReport + ?Sized> CostAndUsageReport for ::std::sync::Arc<T> {
+    fn delete_report_definition(
+        &self,
+        input: DeleteReportDefinitionRequest,
+    ) -> RusotoFuture<DeleteReportDefinitionResponse, DeleteReportDefinitionError> {
+        (**self).delete_report_definition(input)
+    }
+    fn describe_report_definitions(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> RusotoFuture<DescribeReportDefinitionsResponse, DescribeReportDefinitionsError> {
+        (**self).describe_report_definitions(input)
+    }
+    fn modify_report_definition(
+        &self,
+        input: ModifyReportDefinitionRequest,
+    ) -> RusotoFuture<ModifyReportDefinitionResponse, ModifyReportDefinitionError> {
+        (**self).modify_report_definition(input)
+    }
+    fn put_report_definition(
+        &self,
+        input: PutReportDefinitionRequest,
+    ) -> RusotoFuture<PutReportDefinitionResponse, PutReportDefinitionError> {
+        (**self).put_report_definition(input)
+    }
+}
 /// A client for the AWS Cost and Usage Report Service API.
 #[derive(Clone)]
 pub struct CostAndUsageReportClient {
@@ -346,7 +552,16 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         &self,
         input: DeleteReportDefinitionRequest,
     ) -> RusotoFuture<DeleteReportDefinitionResponse, DeleteReportDefinitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("DeleteReportDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -358,10 +573,10 @@ impl CostAndUsageReport for CostAndUsageReportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteReportDefinitionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteReportDefinitionResponse, _>(
+                    response,
+                    "DeleteReportDefinition",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -377,7 +592,16 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         &self,
         input: DescribeReportDefinitionsRequest,
     ) -> RusotoFuture<DescribeReportDefinitionsResponse, DescribeReportDefinitionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("DescribeReportDefinitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -389,10 +613,10 @@ impl CostAndUsageReport for CostAndUsageReportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeReportDefinitionsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeReportDefinitionsResponse, _>(
+                    response,
+                    "DescribeReportDefinitions",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeReportDefinitionsError::from_response(response))
@@ -406,7 +630,16 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         &self,
         input: ModifyReportDefinitionRequest,
     ) -> RusotoFuture<ModifyReportDefinitionResponse, ModifyReportDefinitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("ModifyReportDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -418,10 +651,10 @@ impl CostAndUsageReport for CostAndUsageReportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyReportDefinitionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ModifyReportDefinitionResponse, _>(
+                    response,
+                    "ModifyReportDefinition",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -437,7 +670,16 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         &self,
         input: PutReportDefinitionRequest,
     ) -> RusotoFuture<PutReportDefinitionResponse, PutReportDefinitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("PutReportDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -449,10 +691,10 @@ impl CostAndUsageReport for CostAndUsageReportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutReportDefinitionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<PutReportDefinitionResponse, _>(
+                    response,
+                    "PutReportDefinition",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -463,3 +705,165 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         })
     }
 }
+
+/// A mock of the AWS Cost and Usage Report Service API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCostAndUsageReport {
+    delete_report_definition: Option<
+        Box<
+            dyn Fn(
+                    DeleteReportDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DeleteReportDefinitionResponse,
+                    RusotoError<DeleteReportDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_report_definitions: Option<
+        Box<
+            dyn Fn(
+                    DescribeReportDefinitionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeReportDefinitionsResponse,
+                    RusotoError<DescribeReportDefinitionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_report_definition: Option<
+        Box<
+            dyn Fn(
+                    ModifyReportDefinitionRequest,
+                ) -> ::std::result::Result<
+                    ModifyReportDefinitionResponse,
+                    RusotoError<ModifyReportDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_report_definition: Option<
+        Box<
+            dyn Fn(
+                    PutReportDefinitionRequest,
+                ) -> ::std::result::Result<
+                    PutReportDefinitionResponse,
+                    RusotoError<PutReportDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCostAndUsageReport {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCostAndUsageReport {
+        Default::default()
+    }
+
+    /// Answers `delete_report_definition` with what `handler` returns for its input.
+    pub fn with_delete_report_definition<F>(mut self, handler: F) -> MockCostAndUsageReport
+    where
+        F: Fn(
+                DeleteReportDefinitionRequest,
+            ) -> ::std::result::Result<
+                DeleteReportDefinitionResponse,
+                RusotoError<DeleteReportDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_report_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_report_definitions` with what `handler` returns for its input.
+    pub fn with_describe_report_definitions<F>(mut self, handler: F) -> MockCostAndUsageReport
+    where
+        F: Fn(
+                DescribeReportDefinitionsRequest,
+            ) -> ::std::result::Result<
+                DescribeReportDefinitionsResponse,
+                RusotoError<DescribeReportDefinitionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_report_definitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_report_definition` with what `handler` returns for its input.
+    pub fn with_modify_report_definition<F>(mut self, handler: F) -> MockCostAndUsageReport
+    where
+        F: Fn(
+                ModifyReportDefinitionRequest,
+            ) -> ::std::result::Result<
+                ModifyReportDefinitionResponse,
+                RusotoError<ModifyReportDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_report_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_report_definition` with what `handler` returns for its input.
+    pub fn with_put_report_definition<F>(mut self, handler: F) -> MockCostAndUsageReport
+    where
+        F: Fn(
+                PutReportDefinitionRequest,
+            ) -> ::std::result::Result<
+                PutReportDefinitionResponse,
+                RusotoError<PutReportDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_report_definition = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CostAndUsageReport for MockCostAndUsageReport {
+    fn delete_report_definition(
+        &self,
+        input: DeleteReportDefinitionRequest,
+    ) -> RusotoFuture<DeleteReportDefinitionResponse, DeleteReportDefinitionError> {
+        match self.delete_report_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostAndUsageReport has no handler for delete_report_definition"),
+        }
+    }
+    fn describe_report_definitions(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> RusotoFuture<DescribeReportDefinitionsResponse, DescribeReportDefinitionsError> {
+        match self.describe_report_definitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostAndUsageReport has no handler for describe_report_definitions"),
+        }
+    }
+    fn modify_report_definition(
+        &self,
+        input: ModifyReportDefinitionRequest,
+    ) -> RusotoFuture<ModifyReportDefinitionResponse, ModifyReportDefinitionError> {
+        match self.modify_report_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostAndUsageReport has no handler for modify_report_definition"),
+        }
+    }
+    fn put_report_definition(
+        &self,
+        input: PutReportDefinitionRequest,
+    ) -> RusotoFuture<PutReportDefinitionResponse, PutReportDefinitionError> {
+        match self.put_report_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCostAndUsageReport has no handler for put_report_definition"),
+        }
+    }
+}
@@ -22,9 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Contains the parameters for ActivatePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ActivatePipelineInput {
     /// <p>A list of parameter values to pass to the pipeline at activation.</p>
     #[serde(rename = "parameterValues")]
@@ -39,13 +41,36 @@ pub struct ActivatePipelineInput {
     pub start_timestamp: Option<f64>,
 }
 
+impl Validate for ActivatePipelineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.parameter_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "parameter_values"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the output of ActivatePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivatePipelineOutput {}
 
 /// <p>Contains the parameters for AddTags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsInput {
     /// <p>The ID of the pipeline.</p>
     #[serde(rename = "pipelineId")]
@@ -55,13 +80,35 @@ pub struct AddTagsInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for AddTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), None, Some(10));
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Contains the output of AddTags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsOutput {}
 
 /// <p>Contains the parameters for CreatePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePipelineInput {
     /// <p>The description for the pipeline.</p>
     #[serde(rename = "description")]
@@ -79,9 +126,56 @@ pub struct CreatePipelineInput {
     pub unique_id: String,
 }
 
+impl Validate for CreatePipelineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(10));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "unique_id"),
+            self.unique_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "unique_id"),
+            &self.unique_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the output of CreatePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePipelineOutput {
     /// <p>The ID that AWS Data Pipeline assigns the newly created pipeline. For example, <code>df-06372391ZG65EXAMPLE</code>.</p>
     #[serde(rename = "pipelineId")]
@@ -90,6 +184,7 @@ pub struct CreatePipelineOutput {
 
 /// <p>Contains the parameters for DeactivatePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeactivatePipelineInput {
     /// <p>Indicates whether to cancel any running objects. The default is true, which sets the state of any running objects to <code>CANCELED</code>. If this value is false, the pipeline is deactivated after all running objects finish.</p>
     #[serde(rename = "cancelActive")]
@@ -100,21 +195,55 @@ pub struct DeactivatePipelineInput {
     pub pipeline_id: String,
 }
 
+impl Validate for DeactivatePipelineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the output of DeactivatePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeactivatePipelineOutput {}
 
 /// <p>Contains the parameters for DeletePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePipelineInput {
     /// <p>The ID of the pipeline.</p>
     #[serde(rename = "pipelineId")]
     pub pipeline_id: String,
 }
 
+impl Validate for DeletePipelineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the parameters for DescribeObjects.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeObjectsInput {
     /// <p>Indicates whether any expressions in the object should be evaluated when the object descriptions are returned.</p>
     #[serde(rename = "evaluateExpressions")]
@@ -132,9 +261,47 @@ pub struct DescribeObjectsInput {
     pub pipeline_id: String,
 }
 
+impl Validate for DescribeObjectsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "marker"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        for (i, item) in self.object_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "object_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the output of DescribeObjects.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeObjectsOutput {
     /// <p>Indicates whether there are more results to return.</p>
     #[serde(rename = "hasMoreResults")]
@@ -151,15 +318,30 @@ pub struct DescribeObjectsOutput {
 
 /// <p>Contains the parameters for DescribePipelines.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePipelinesInput {
     /// <p>The IDs of the pipelines to describe. You can pass as many as 25 identifiers in a single call. To obtain pipeline IDs, call <a>ListPipelines</a>.</p>
     #[serde(rename = "pipelineIds")]
     pub pipeline_ids: Vec<String>,
 }
 
+impl Validate for DescribePipelinesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.pipeline_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "pipeline_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Contains the output of DescribePipelines.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePipelinesOutput {
     /// <p>An array of descriptions for the specified pipelines.</p>
     #[serde(rename = "pipelineDescriptionList")]
@@ -168,6 +350,7 @@ pub struct DescribePipelinesOutput {
 
 /// <p>Contains the parameters for EvaluateExpression.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EvaluateExpressionInput {
     /// <p>The expression to evaluate.</p>
     #[serde(rename = "expression")]
@@ -180,9 +363,47 @@ pub struct EvaluateExpressionInput {
     pub pipeline_id: String,
 }
 
+impl Validate for EvaluateExpressionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "expression"),
+            self.expression.chars().count(),
+            None,
+            Some(20971520),
+        );
+        errors.check_pattern(
+            &field_path(path, "expression"),
+            &self.expression,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "object_id"),
+            self.object_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "object_id"),
+            &self.object_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the output of EvaluateExpression.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EvaluateExpressionOutput {
     /// <p>The evaluated expression.</p>
     #[serde(rename = "evaluatedExpression")]
@@ -205,8 +426,51 @@ pub struct Field {
     pub string_value: Option<String>,
 }
 
+impl Validate for Field {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.ref_value {
+            errors.check_length(
+                &field_path(path, "ref_value"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "ref_value"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.string_value {
+            errors.check_length(
+                &field_path(path, "string_value"),
+                value.chars().count(),
+                None,
+                Some(10240),
+            );
+            errors.check_pattern(
+                &field_path(path, "string_value"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Contains the parameters for GetPipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPipelineDefinitionInput {
     /// <p>The ID of the pipeline.</p>
     #[serde(rename = "pipelineId")]
@@ -217,9 +481,38 @@ pub struct GetPipelineDefinitionInput {
     pub version: Option<String>,
 }
 
+impl Validate for GetPipelineDefinitionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.version {
+            errors.check_length(
+                &field_path(path, "version"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "version"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Contains the output of GetPipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPipelineDefinitionOutput {
     /// <p>The parameter objects used in the pipeline definition.</p>
     #[serde(rename = "parameterObjects")]
@@ -237,6 +530,7 @@ pub struct GetPipelineDefinitionOutput {
 
 /// <p><p>Identity information for the EC2 instance that is hosting the task runner. You can get this value by calling a metadata URI from the EC2 instance. For more information, see <a href="http://docs.aws.amazon.com/AWSEC2/latest/UserGuide/AESDG-chapter-instancedata.html">Instance Metadata</a> in the <i>Amazon Elastic Compute Cloud User Guide.</i> Passing in this value proves that your task runner is running on an EC2 instance, and ensures the proper AWS Data Pipeline service charges are applied to your pipeline.</p></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceIdentity {
     /// <p>A description of an EC2 instance that is generated when the instance is launched and exposed to the instance via the instance metadata service in the form of a JSON representation of an object.</p>
     #[serde(rename = "document")]
@@ -248,8 +542,40 @@ pub struct InstanceIdentity {
     pub signature: Option<String>,
 }
 
+impl Validate for InstanceIdentity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.document {
+            errors.check_length(
+                &field_path(path, "document"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "document"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.signature {
+            errors.check_length(
+                &field_path(path, "signature"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "signature"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Contains the parameters for ListPipelines.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPipelinesInput {
     /// <p>The starting point for the results to be returned. For the first call, this value should be empty. As long as there are more results, continue to call <code>ListPipelines</code> with the marker value from the previous call to retrieve the next set of results.</p>
     #[serde(rename = "marker")]
@@ -257,9 +583,27 @@ pub struct ListPipelinesInput {
     pub marker: Option<String>,
 }
 
+impl Validate for ListPipelinesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "marker"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Contains the output of ListPipelines.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPipelinesOutput {
     /// <p>Indicates whether there are more results that can be obtained by a subsequent call.</p>
     #[serde(rename = "hasMoreResults")]
@@ -276,6 +620,7 @@ pub struct ListPipelinesOutput {
 
 /// <p>Contains a logical operation for comparing the value of a field with a specified value.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Operator {
     /// <p> The logical operation to be performed: equal (<code>EQ</code>), equal reference (<code>REF_EQ</code>), less than or equal (<code>LE</code>), greater than or equal (<code>GE</code>), or between (<code>BETWEEN</code>). Equal reference (<code>REF_EQ</code>) can be used only with reference fields. The other comparison types can be used only with String fields. The comparison types you can use apply only to certain object fields, as detailed below. </p> <p> The comparison operators EQ and REF_EQ act on the following fields: </p> <ul> <li>name</li> <li>@sphere</li> <li>parent</li> <li>@componentParent</li> <li>@instanceParent</li> <li>@status</li> <li>@scheduledStartTime</li> <li>@scheduledEndTime</li> <li>@actualStartTime</li> <li>@actualEndTime</li> </ul> <p> The comparison operators <code>GE</code>, <code>LE</code>, and <code>BETWEEN</code> act on the following fields: </p> <ul> <li>@scheduledStartTime</li> <li>@scheduledEndTime</li> <li>@actualStartTime</li> <li>@actualEndTime</li> </ul> <p>Note that fields beginning with the at sign (@) are read-only and set by the web service. When you name fields, you should choose names containing only alpha-numeric values, as symbols may be reserved by AWS Data Pipeline. User-defined fields that you add to a pipeline should prefix their name with the string "my".</p>
     #[serde(rename = "type")]
@@ -287,6 +632,22 @@ pub struct Operator {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for Operator {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// <p>The attributes allowed or specified with a parameter object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParameterAttribute {
@@ -298,6 +659,33 @@ pub struct ParameterAttribute {
     pub string_value: String,
 }
 
+impl Validate for ParameterAttribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "string_value"),
+            self.string_value.chars().count(),
+            None,
+            Some(10240),
+        );
+        errors.check_pattern(
+            &field_path(path, "string_value"),
+            &self.string_value,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains information about a parameter object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParameterObject {
@@ -309,6 +697,26 @@ pub struct ParameterObject {
     pub id: String,
 }
 
+impl Validate for ParameterObject {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "id"),
+            &self.id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>A value or list of parameter values. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParameterValue {
@@ -320,9 +728,36 @@ pub struct ParameterValue {
     pub string_value: String,
 }
 
+impl Validate for ParameterValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "id"),
+            &self.id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "string_value"),
+            self.string_value.chars().count(),
+            None,
+            Some(10240),
+        );
+        errors.check_pattern(
+            &field_path(path, "string_value"),
+            &self.string_value,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains pipeline metadata.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PipelineDescription {
     /// <p>Description of the pipeline.</p>
     #[serde(rename = "description")]
@@ -345,7 +780,7 @@ pub struct PipelineDescription {
 
 /// <p>Contains the name and identifier of a pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PipelineIdName {
     /// <p>The ID of the pipeline that was assigned by AWS Data Pipeline. This is a string of the form <code>df-297EG78HU43EEXAMPLE</code>.</p>
     #[serde(rename = "id")]
@@ -371,8 +806,40 @@ pub struct PipelineObject {
     pub name: String,
 }
 
+impl Validate for PipelineObject {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.fields.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "fields"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "id"),
+            &self.id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the parameters for PollForTask.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PollForTaskInput {
     /// <p>The public DNS name of the calling task runner.</p>
     #[serde(rename = "hostname")]
@@ -387,9 +854,41 @@ pub struct PollForTaskInput {
     pub worker_group: String,
 }
 
+impl Validate for PollForTaskInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hostname {
+            errors.check_length(
+                &field_path(path, "hostname"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "hostname"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.instance_identity {
+            value.validate_at(&field_path(path, "instance_identity"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "worker_group"),
+            self.worker_group.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "worker_group"),
+            &self.worker_group,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the output of PollForTask.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PollForTaskOutput {
     /// <p>The information needed to complete the task that is being assigned to the task runner. One of the fields returned in this object is <code>taskId</code>, which contains an identifier for the task being assigned. The calling task runner uses <code>taskId</code> in subsequent calls to <a>ReportTaskProgress</a> and <a>SetTaskStatus</a>.</p>
     #[serde(rename = "taskObject")]
@@ -399,6 +898,7 @@ pub struct PollForTaskOutput {
 
 /// <p>Contains the parameters for PutPipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutPipelineDefinitionInput {
     /// <p>The parameter objects used with the pipeline.</p>
     #[serde(rename = "parameterObjects")]
@@ -416,9 +916,41 @@ pub struct PutPipelineDefinitionInput {
     pub pipeline_objects: Vec<PipelineObject>,
 }
 
+impl Validate for PutPipelineDefinitionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.parameter_objects {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "parameter_objects"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.parameter_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "parameter_values"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.pipeline_objects.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "pipeline_objects"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Contains the output of PutPipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutPipelineDefinitionOutput {
     /// <p>Indicates whether there were validation errors, and the pipeline definition is stored but cannot be activated until you correct the pipeline and call <code>PutPipelineDefinition</code> to commit the corrected pipeline.</p>
     #[serde(rename = "errored")]
@@ -435,6 +967,7 @@ pub struct PutPipelineDefinitionOutput {
 
 /// <p>Defines the query to run against an object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Query {
     /// <p>List of selectors that define the query. An object must satisfy all of the selectors to match the query.</p>
     #[serde(rename = "selectors")]
@@ -442,8 +975,20 @@ pub struct Query {
     pub selectors: Option<Vec<Selector>>,
 }
 
+impl Validate for Query {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.selectors {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "selectors"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Contains the parameters for QueryObjects.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueryObjectsInput {
     /// <p>The maximum number of object names that <code>QueryObjects</code> will return in a single call. The default value is 100. </p>
     #[serde(rename = "limit")]
@@ -465,9 +1010,52 @@ pub struct QueryObjectsInput {
     pub sphere: String,
 }
 
+impl Validate for QueryObjectsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "marker"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.query {
+            value.validate_at(&field_path(path, "query"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "sphere"),
+            self.sphere.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "sphere"),
+            &self.sphere,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the output of QueryObjects.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QueryObjectsOutput {
     /// <p>Indicates whether there are more results that can be obtained by a subsequent call.</p>
     #[serde(rename = "hasMoreResults")]
@@ -485,6 +1073,7 @@ pub struct QueryObjectsOutput {
 
 /// <p>Contains the parameters for RemoveTags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsInput {
     /// <p>The ID of the pipeline.</p>
     #[serde(rename = "pipelineId")]
@@ -494,13 +1083,39 @@ pub struct RemoveTagsInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for RemoveTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Contains the output of RemoveTags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsOutput {}
 
 /// <p>Contains the parameters for ReportTaskProgress.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReportTaskProgressInput {
     /// <p>Key-value pairs that define the properties of the ReportTaskProgressInput object.</p>
     #[serde(rename = "fields")]
@@ -511,9 +1126,31 @@ pub struct ReportTaskProgressInput {
     pub task_id: String,
 }
 
+impl Validate for ReportTaskProgressInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.fields {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "fields"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "task_id"),
+            self.task_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "task_id"),
+            &self.task_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the output of ReportTaskProgress.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReportTaskProgressOutput {
     /// <p>If true, the calling task runner should cancel processing of the task. The task runner does not need to call <a>SetTaskStatus</a> for canceled tasks.</p>
     #[serde(rename = "canceled")]
@@ -522,6 +1159,7 @@ pub struct ReportTaskProgressOutput {
 
 /// <p>Contains the parameters for ReportTaskRunnerHeartbeat.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReportTaskRunnerHeartbeatInput {
     /// <p>The public DNS name of the task runner.</p>
     #[serde(rename = "hostname")]
@@ -536,9 +1174,51 @@ pub struct ReportTaskRunnerHeartbeatInput {
     pub worker_group: Option<String>,
 }
 
+impl Validate for ReportTaskRunnerHeartbeatInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hostname {
+            errors.check_length(
+                &field_path(path, "hostname"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "hostname"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "taskrunner_id"),
+            self.taskrunner_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "taskrunner_id"),
+            &self.taskrunner_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.worker_group {
+            errors.check_length(
+                &field_path(path, "worker_group"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "worker_group"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Contains the output of ReportTaskRunnerHeartbeat.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReportTaskRunnerHeartbeatOutput {
     /// <p>Indicates whether the calling task runner should terminate.</p>
     #[serde(rename = "terminate")]
@@ -547,6 +1227,7 @@ pub struct ReportTaskRunnerHeartbeatOutput {
 
 /// <p>A comparision that is used to determine whether a query should return this object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Selector {
     /// <p>The name of the field that the operator will be applied to. The field name is the "key" portion of the field definition in the pipeline definition syntax that is used by the AWS Data Pipeline API. If the field is not set on the object, the condition fails.</p>
     #[serde(rename = "fieldName")]
@@ -557,8 +1238,30 @@ pub struct Selector {
     pub operator: Option<Operator>,
 }
 
+impl Validate for Selector {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.field_name {
+            errors.check_length(
+                &field_path(path, "field_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "field_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.operator {
+            value.validate_at(&field_path(path, "operator"), errors);
+        }
+    }
+}
+
 /// <p>Contains the parameters for SetStatus.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetStatusInput {
     /// <p>The IDs of the objects. The corresponding objects can be either physical or components, but not a mix of both types.</p>
     #[serde(rename = "objectIds")]
@@ -571,8 +1274,45 @@ pub struct SetStatusInput {
     pub status: String,
 }
 
+impl Validate for SetStatusInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.object_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "object_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "status"),
+            self.status.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "status"),
+            &self.status,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the parameters for SetTaskStatus.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetTaskStatusInput {
     /// <p>If an error occurred during the task, this value specifies the error code. This value is set on the physical attempt object. It is used to display error information to the user. It should not start with string "Service_" which is reserved by the system.</p>
     #[serde(rename = "errorId")]
@@ -594,9 +1334,51 @@ pub struct SetTaskStatusInput {
     pub task_status: String,
 }
 
+impl Validate for SetTaskStatusInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.error_id {
+            errors.check_length(
+                &field_path(path, "error_id"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "error_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.error_stack_trace {
+            errors.check_length(
+                &field_path(path, "error_stack_trace"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "error_stack_trace"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "task_id"),
+            self.task_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "task_id"),
+            &self.task_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Contains the output of SetTaskStatus.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SetTaskStatusOutput {}
 
 /// <p>Tags are key/value pairs defined by a user and associated with a pipeline to control access. AWS Data Pipeline allows you to associate ten tags per pipeline. For more information, see <a href="http://docs.aws.amazon.com/datapipeline/latest/DeveloperGuide/dp-control-access.html">Controlling User Access to Pipelines</a> in the <i>AWS Data Pipeline Developer Guide</i>.</p>
@@ -610,9 +1392,26 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 /// <p>Contains information about a pipeline task that is assigned to a task runner.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskObject {
     /// <p>The ID of the pipeline task attempt object. AWS Data Pipeline uses this value to track how many times a task is attempted.</p>
     #[serde(rename = "attemptId")]
@@ -634,6 +1433,7 @@ pub struct TaskObject {
 
 /// <p>Contains the parameters for ValidatePipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ValidatePipelineDefinitionInput {
     /// <p>The parameter objects used with the pipeline.</p>
     #[serde(rename = "parameterObjects")]
@@ -651,9 +1451,41 @@ pub struct ValidatePipelineDefinitionInput {
     pub pipeline_objects: Vec<PipelineObject>,
 }
 
+impl Validate for ValidatePipelineDefinitionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.parameter_objects {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "parameter_objects"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.parameter_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "parameter_values"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "pipeline_id"),
+            self.pipeline_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.pipeline_objects.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "pipeline_objects"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Contains the output of ValidatePipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ValidatePipelineDefinitionOutput {
     /// <p>Indicates whether there were validation errors.</p>
     #[serde(rename = "errored")]
@@ -670,7 +1502,7 @@ pub struct ValidatePipelineDefinitionOutput {
 
 /// <p>Defines a validation error. Validation errors prevent pipeline activation. The set of validation errors that can be returned are defined by AWS Data Pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ValidationError {
     /// <p>A description of the validation error.</p>
     #[serde(rename = "errors")]
@@ -684,7 +1516,7 @@ pub struct ValidationError {
 
 /// <p>Defines a validation warning. Validation warnings do not prevent pipeline activation. The set of validation warnings that can be returned are defined by AWS Data Pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ValidationWarning {
     /// <p>The identifier of the object that contains the validation warning.</p>
     #[serde(rename = "id")]
@@ -727,7 +1559,12 @@ impl ActivatePipelineError {
                 "PipelineNotFoundException" => {
                     return RusotoError::Service(ActivatePipelineError::PipelineNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -778,7 +1615,12 @@ impl AddTagsError {
                 "PipelineNotFoundException" => {
                     return RusotoError::Service(AddTagsError::PipelineNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -819,7 +1661,12 @@ impl CreatePipelineError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(CreatePipelineError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -870,7 +1717,12 @@ impl DeactivatePipelineError {
                 "PipelineNotFoundException" => {
                     return RusotoError::Service(DeactivatePipelineError::PipelineNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -916,7 +1768,12 @@ impl DeletePipelineError {
                 "PipelineNotFoundException" => {
                     return RusotoError::Service(DeletePipelineError::PipelineNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -968,7 +1825,12 @@ impl DescribeObjectsError {
                 "PipelineNotFoundException" => {
                     return RusotoError::Service(DescribeObjectsError::PipelineNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1021,7 +1883,12 @@ impl DescribePipelinesError {
                 "PipelineNotFoundException" => {
                     return RusotoError::Service(DescribePipelinesError::PipelineNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1079,7 +1946,12 @@ impl EvaluateExpressionError {
                 "TaskNotFoundException" => {
                     return RusotoError::Service(EvaluateExpressionError::TaskNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1139,7 +2011,12 @@ impl GetPipelineDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1180,7 +2057,12 @@ impl ListPipelinesError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListPipelinesError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1224,7 +2106,12 @@ impl PollForTaskError {
                 "TaskNotFoundException" => {
                     return RusotoError::Service(PollForTaskError::TaskNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1282,7 +2169,12 @@ impl PutPipelineDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1333,7 +2225,12 @@ impl QueryObjectsError {
                 "PipelineNotFoundException" => {
                     return RusotoError::Service(QueryObjectsError::PipelineNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1384,7 +2281,12 @@ impl RemoveTagsError {
                 "PipelineNotFoundException" => {
                     return RusotoError::Service(RemoveTagsError::PipelineNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1442,7 +2344,12 @@ impl ReportTaskProgressError {
                 "TaskNotFoundException" => {
                     return RusotoError::Service(ReportTaskProgressError::TaskNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1488,7 +2395,12 @@ impl ReportTaskRunnerHeartbeatError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1537,7 +2449,12 @@ impl SetStatusError {
                 "PipelineNotFoundException" => {
                     return RusotoError::Service(SetStatusError::PipelineNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1593,7 +2510,12 @@ impl SetTaskStatusError {
                 "TaskNotFoundException" => {
                     return RusotoError::Service(SetTaskStatusError::TaskNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1655,7 +2577,12 @@ impl ValidatePipelineDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1784,133 +2711,489 @@ pub trait DataPipeline {
         input: ValidatePipelineDefinitionInput,
     ) -> RusotoFuture<ValidatePipelineDefinitionOutput, ValidatePipelineDefinitionError>;
 }
-/// A client for the AWS Data Pipeline API.
-#[derive(Clone)]
-pub struct DataPipelineClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl DataPipelineClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> DataPipelineClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> DataPipelineClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> DataPipelineClient {
-        DataPipelineClient { client, region }
-    }
-}
-
-impl DataPipeline for DataPipelineClient {
-    /// <p>Validates the specified pipeline and starts processing pipeline tasks. If the pipeline does not pass validation, activation fails.</p> <p>If you need to pause the pipeline to investigate an issue with a component, such as a data source or script, call <a>DeactivatePipeline</a>.</p> <p>To activate a finished pipeline, modify the end date for the pipeline and then activate it.</p>
+const _: Option<&dyn DataPipeline> = None;
+impl<'a, T: DataPipeline + ?Sized> DataPipeline for &'a T {
     fn activate_pipeline(
         &self,
         input: ActivatePipelineInput,
     ) -> RusotoFuture<ActivatePipelineOutput, ActivatePipelineError> {
-        let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DataPipeline.ActivatePipeline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ActivatePipelineOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ActivatePipelineError::from_response(response))),
-                )
-            }
-        })
+        (**self).activate_pipeline(input)
     }
-
-    /// <p>Adds or modifies tags for the specified pipeline.</p>
     fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
-        let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DataPipeline.AddTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_tags(input)
     }
-
-    /// <p>Creates a new, empty pipeline. Use <a>PutPipelineDefinition</a> to populate the pipeline.</p>
     fn create_pipeline(
         &self,
         input: CreatePipelineInput,
     ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
-        let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DataPipeline.CreatePipeline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePipelineOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreatePipelineError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_pipeline(input)
     }
-
-    /// <p>Deactivates the specified running pipeline. The pipeline is set to the <code>DEACTIVATING</code> state until the deactivation process completes.</p> <p>To resume a deactivated pipeline, use <a>ActivatePipeline</a>. By default, the pipeline resumes from the last completed execution. Optionally, you can specify the date and time to resume the pipeline.</p>
     fn deactivate_pipeline(
         &self,
         input: DeactivatePipelineInput,
     ) -> RusotoFuture<DeactivatePipelineOutput, DeactivatePipelineError> {
+        (**self).deactivate_pipeline(input)
+    }
+    fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn describe_objects(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> RusotoFuture<DescribeObjectsOutput, DescribeObjectsError> {
+        (**self).describe_objects(input)
+    }
+    fn describe_pipelines(
+        &self,
+        input: DescribePipelinesInput,
+    ) -> RusotoFuture<DescribePipelinesOutput, DescribePipelinesError> {
+        (**self).describe_pipelines(input)
+    }
+    fn evaluate_expression(
+        &self,
+        input: EvaluateExpressionInput,
+    ) -> RusotoFuture<EvaluateExpressionOutput, EvaluateExpressionError> {
+        (**self).evaluate_expression(input)
+    }
+    fn get_pipeline_definition(
+        &self,
+        input: GetPipelineDefinitionInput,
+    ) -> RusotoFuture<GetPipelineDefinitionOutput, GetPipelineDefinitionError> {
+        (**self).get_pipeline_definition(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn poll_for_task(
+        &self,
+        input: PollForTaskInput,
+    ) -> RusotoFuture<PollForTaskOutput, PollForTaskError> {
+        (**self).poll_for_task(input)
+    }
+    fn put_pipeline_definition(
+        &self,
+        input: PutPipelineDefinitionInput,
+    ) -> RusotoFuture<PutPipelineDefinitionOutput, PutPipelineDefinitionError> {
+        (**self).put_pipeline_definition(input)
+    }
+    fn query_objects(
+        &self,
+        input: QueryObjectsInput,
+    ) -> RusotoFuture<QueryObjectsOutput, QueryObjectsError> {
+        (**self).query_objects(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn report_task_progress(
+        &self,
+        input: ReportTaskProgressInput,
+    ) -> RusotoFuture<ReportTaskProgressOutput, ReportTaskProgressError> {
+        (**self).report_task_progress(input)
+    }
+    fn report_task_runner_heartbeat(
+        &self,
+        input: ReportTaskRunnerHeartbeatInput,
+    ) -> RusotoFuture<ReportTaskRunnerHeartbeatOutput, ReportTaskRunnerHeartbeatError> {
+        (**self).report_task_runner_heartbeat(input)
+    }
+    fn set_status(&self, input: SetStatusInput) -> RusotoFuture<(), SetStatusError> {
+        (**self).set_status(input)
+    }
+    fn set_task_status(
+        &self,
+        input: SetTaskStatusInput,
+    ) -> RusotoFuture<SetTaskStatusOutput, SetTaskStatusError> {
+        (**self).set_task_status(input)
+    }
+    fn validate_pipeline_definition(
+        &self,
+        input: ValidatePipelineDefinitionInput,
+    ) -> RusotoFuture<ValidatePipelineDefinitionOutput, ValidatePipelineDefinitionError> {
+        (**self).validate_pipeline_definition(input)
+    }
+}
+impl<T: DataPipeline + ?Sized> DataPipeline for Box<T> {
+    fn activate_pipeline(
+        &self,
+        input: ActivatePipelineInput,
+    ) -> RusotoFuture<ActivatePipelineOutput, ActivatePipelineError> {
+        (**self).activate_pipeline(input)
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn deactivate_pipeline(
+        &self,
+        input: DeactivatePipelineInput,
+    ) -> RusotoFuture<DeactivatePipelineOutput, DeactivatePipelineError> {
+        (**self).deactivate_pipeline(input)
+    }
+    fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn describe_objects(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> RusotoFuture<DescribeObjectsOutput, DescribeObjectsError> {
+        (**self).describe_objects(input)
+    }
+    fn describe_pipelines(
+        &self,
+        input: DescribePipelinesInput,
+    ) -> RusotoFuture<DescribePipelinesOutput, DescribePipelinesError> {
+        (**self).describe_pipelines(input)
+    }
+    fn evaluate_expression(
+        &self,
+        input: EvaluateExpressionInput,
+    ) -> RusotoFuture<EvaluateExpressionOutput, EvaluateExpressionError> {
+        (**self).evaluate_expression(input)
+    }
+    fn get_pipeline_definition(
+        &self,
+        input: GetPipelineDefinitionInput,
+    ) -> RusotoFuture<GetPipelineDefinitionOutput, GetPipelineDefinitionError> {
+        (**self).get_pipeline_definition(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn poll_for_task(
+        &self,
+        input: PollForTaskInput,
+    ) -> RusotoFuture<PollForTaskOutput, PollForTaskError> {
+        (**self).poll_for_task(input)
+    }
+    fn put_pipeline_definition(
+        &self,
+        input: PutPipelineDefinitionInput,
+    ) -> RusotoFuture<PutPipelineDefinitionOutput, PutPipelineDefinitionError> {
+        (**self).put_pipeline_definition(input)
+    }
+    fn query_objects(
+        &self,
+        input: QueryObjectsInput,
+    ) -> RusotoFuture<QueryObjectsOutput, QueryObjectsError> {
+        (**self).query_objects(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn report_task_progress(
+        &self,
+        input: ReportTaskProgressInput,
+    ) -> RusotoFuture<ReportTaskProgressOutput, ReportTaskProgressError> {
+        (**self).report_task_progress(input)
+    }
+    fn report_task_runner_heartbeat(
+        &self,
+        input: ReportTaskRunnerHeartbeatInput,
+    ) -> RusotoFuture<ReportTaskRunnerHeartbeatOutput, ReportTaskRunnerHeartbeatError> {
+        (**self).report_task_runner_heartbeat(input)
+    }
+    fn set_status(&self, input: SetStatusInput) -> RusotoFuture<(), SetStatusError> {
+        (**self).set_status(input)
+    }
+    fn set_task_status(
+        &self,
+        input: SetTaskStatusInput,
+    ) -> RusotoFuture<SetTaskStatusOutput, SetTaskStatusError> {
+        (**self).set_task_status(input)
+    }
+    fn validate_pipeline_definition(
+        &self,
+        input: ValidatePipelineDefinitionInput,
+    ) -> RusotoFuture<ValidatePipelineDefinitionOutput, ValidatePipelineDefinitionError> {
+        (**self).validate_pipeline_definition(input)
+    }
+}
+impl<T: DataPipeline + ?Sized> DataPipeline for ::std::sync::Arc<T> {
+    fn activate_pipeline(
+        &self,
+        input: ActivatePipelineInput,
+    ) -> RusotoFuture<ActivatePipelineOutput, ActivatePipelineError> {
+        (**self).activate_pipeline(input)
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn deactivate_pipeline(
+        &self,
+        input: DeactivatePipelineInput,
+    ) -> RusotoFuture<DeactivatePipelineOutput, DeactivatePipelineError> {
+        (**self).deactivate_pipeline(input)
+    }
+    fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn describe_objects(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> RusotoFuture<DescribeObjectsOutput, DescribeObjectsError> {
+        (**self).describe_objects(input)
+    }
+    fn describe_pipelines(
+        &self,
+        input: DescribePipelinesInput,
+    ) -> RusotoFuture<DescribePipelinesOutput, DescribePipelinesError> {
+        (**self).describe_pipelines(input)
+    }
+    fn evaluate_expression(
+        &self,
+        input: EvaluateExpressionInput,
+    ) -> RusotoFuture<EvaluateExpressionOutput, EvaluateExpressionError> {
+        (**self).evaluate_expression(input)
+    }
+    fn get_pipeline_definition(
+        &self,
+        input: GetPipelineDefinitionInput,
+    ) -> RusotoFuture<GetPipelineDefinitionOutput, GetPipelineDefinitionError> {
+        (**self).get_pipeline_definition(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn poll_for_task(
+        &self,
+        input: PollForTaskInput,
+    ) -> RusotoFuture<PollForTaskOutput, PollForTaskError> {
+        (**self).poll_for_task(input)
+    }
+    fn put_pipeline_definition(
+        &self,
+        input: PutPipelineDefinitionInput,
+    ) -> RusotoFuture<PutPipelineDefinitionOutput, PutPipelineDefinitionError> {
+        (**self).put_pipeline_definition(input)
+    }
+    fn query_objects(
+        &self,
+        input: QueryObjectsInput,
+    ) -> RusotoFuture<QueryObjectsOutput, QueryObjectsError> {
+        (**self).query_objects(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn report_task_progress(
+        &self,
+        input: ReportTaskProgressInput,
+    ) -> RusotoFuture<ReportTaskProgressOutput, ReportTaskProgressError> {
+        (**self).report_task_progress(input)
+    }
+    fn report_task_runner_heartbeat(
+        &self,
+        input: ReportTaskRunnerHeartbeatInput,
+    ) -> RusotoFuture<ReportTaskRunnerHeartbeatOutput, ReportTaskRunnerHeartbeatError> {
+        (**self).report_task_runner_heartbeat(input)
+    }
+    fn set_status(&self, input: SetStatusInput) -> RusotoFuture<(), SetStatusError> {
+        (**self).set_status(input)
+    }
+    fn set_task_status(
+        &self,
+        input: SetTaskStatusInput,
+    ) -> RusotoFuture<SetTaskStatusOutput, SetTaskStatusError> {
+        (**self).set_task_status(input)
+    }
+    fn validate_pipeline_definition(
+        &self,
+        input: ValidatePipelineDefinitionInput,
+    ) -> RusotoFuture<ValidatePipelineDefinitionOutput, ValidatePipelineDefinitionError> {
+        (**self).validate_pipeline_definition(input)
+    }
+}
+/// A client for the AWS Data Pipeline API.
+#[derive(Clone)]
+pub struct DataPipelineClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl DataPipelineClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DataPipelineClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DataPipelineClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> DataPipelineClient {
+        DataPipelineClient { client, region }
+    }
+}
+
+impl DataPipeline for DataPipelineClient {
+    /// <p>Validates the specified pipeline and starts processing pipeline tasks. If the pipeline does not pass validation, activation fails.</p> <p>If you need to pause the pipeline to investigate an issue with a component, such as a data source or script, call <a>DeactivatePipeline</a>.</p> <p>To activate a finished pipeline, modify the end date for the pipeline and then activate it.</p>
+    fn activate_pipeline(
+        &self,
+        input: ActivatePipelineInput,
+    ) -> RusotoFuture<ActivatePipelineOutput, ActivatePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ActivatePipeline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DataPipeline.ActivatePipeline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ActivatePipelineOutput, _>(
+                    response,
+                    "ActivatePipeline",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ActivatePipelineError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds or modifies tags for the specified pipeline.</p>
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("AddTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DataPipeline.AddTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddTagsOutput, _>(response, "AddTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new, empty pipeline. Use <a>PutPipelineDefinition</a> to populate the pipeline.</p>
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("CreatePipeline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DataPipeline.CreatePipeline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePipelineOutput, _>(
+                    response,
+                    "CreatePipeline",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreatePipelineError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deactivates the specified running pipeline. The pipeline is set to the <code>DEACTIVATING</code> state until the deactivation process completes.</p> <p>To resume a deactivated pipeline, use <a>ActivatePipeline</a>. By default, the pipeline resumes from the last completed execution. Optionally, you can specify the date and time to resume the pipeline.</p>
+    fn deactivate_pipeline(
+        &self,
+        input: DeactivatePipelineInput,
+    ) -> RusotoFuture<DeactivatePipelineOutput, DeactivatePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DeactivatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DeactivatePipeline");
@@ -1919,10 +3202,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeactivatePipelineOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DeactivatePipelineOutput, _>(
+                    response,
+                    "DeactivatePipeline",
+                )
             } else {
                 Box::new(
                     response
@@ -1936,7 +3219,16 @@ impl DataPipeline for DataPipelineClient {
 
     /// <p>Deletes a pipeline, its pipeline definition, and its run history. AWS Data Pipeline attempts to cancel instances associated with the pipeline that are currently being processed by task runners.</p> <p>Deleting a pipeline cannot be undone. You cannot query or restore a deleted pipeline. To temporarily pause a pipeline instead of deleting it, call <a>SetStatus</a> with the status set to <code>PAUSE</code> on individual components. Components that are paused by <a>SetStatus</a> can be resumed.</p>
     fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DeletePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DeletePipeline");
@@ -1962,7 +3254,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: DescribeObjectsInput,
     ) -> RusotoFuture<DescribeObjectsOutput, DescribeObjectsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DescribeObjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DescribeObjects");
@@ -1971,10 +3272,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeObjectsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeObjectsOutput, _>(
+                    response,
+                    "DescribeObjects",
+                )
             } else {
                 Box::new(
                     response
@@ -1991,7 +3292,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: DescribePipelinesInput,
     ) -> RusotoFuture<DescribePipelinesOutput, DescribePipelinesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DescribePipelines");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DescribePipelines");
@@ -2000,10 +3310,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePipelinesOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribePipelinesOutput, _>(
+                    response,
+                    "DescribePipelines",
+                )
             } else {
                 Box::new(
                     response
@@ -2020,7 +3330,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: EvaluateExpressionInput,
     ) -> RusotoFuture<EvaluateExpressionOutput, EvaluateExpressionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("EvaluateExpression");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.EvaluateExpression");
@@ -2029,10 +3348,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EvaluateExpressionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<EvaluateExpressionOutput, _>(
+                    response,
+                    "EvaluateExpression",
+                )
             } else {
                 Box::new(
                     response
@@ -2049,7 +3368,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: GetPipelineDefinitionInput,
     ) -> RusotoFuture<GetPipelineDefinitionOutput, GetPipelineDefinitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("GetPipelineDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.GetPipelineDefinition");
@@ -2058,10 +3386,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPipelineDefinitionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetPipelineDefinitionOutput, _>(
+                    response,
+                    "GetPipelineDefinition",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2077,7 +3405,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: ListPipelinesInput,
     ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ListPipelines");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ListPipelines");
@@ -2086,10 +3423,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPipelinesOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListPipelinesOutput, _>(
+                    response,
+                    "ListPipelines",
+                )
             } else {
                 Box::new(
                     response
@@ -2106,7 +3443,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: PollForTaskInput,
     ) -> RusotoFuture<PollForTaskOutput, PollForTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("PollForTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.PollForTask");
@@ -2115,10 +3461,7 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PollForTaskOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<PollForTaskOutput, _>(response, "PollForTask")
             } else {
                 Box::new(
                     response
@@ -2135,7 +3478,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: PutPipelineDefinitionInput,
     ) -> RusotoFuture<PutPipelineDefinitionOutput, PutPipelineDefinitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("PutPipelineDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.PutPipelineDefinition");
@@ -2144,10 +3496,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutPipelineDefinitionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<PutPipelineDefinitionOutput, _>(
+                    response,
+                    "PutPipelineDefinition",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2163,7 +3515,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: QueryObjectsInput,
     ) -> RusotoFuture<QueryObjectsOutput, QueryObjectsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("QueryObjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.QueryObjects");
@@ -2172,10 +3533,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<QueryObjectsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<QueryObjectsOutput, _>(
+                    response,
+                    "QueryObjects",
+                )
             } else {
                 Box::new(
                     response
@@ -2192,7 +3553,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: RemoveTagsInput,
     ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("RemoveTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.RemoveTags");
@@ -2201,10 +3571,7 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveTagsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<RemoveTagsOutput, _>(response, "RemoveTags")
             } else {
                 Box::new(
                     response
@@ -2221,7 +3588,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: ReportTaskProgressInput,
     ) -> RusotoFuture<ReportTaskProgressOutput, ReportTaskProgressError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ReportTaskProgress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ReportTaskProgress");
@@ -2230,10 +3606,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ReportTaskProgressOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ReportTaskProgressOutput, _>(
+                    response,
+                    "ReportTaskProgress",
+                )
             } else {
                 Box::new(
                     response
@@ -2250,7 +3626,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: ReportTaskRunnerHeartbeatInput,
     ) -> RusotoFuture<ReportTaskRunnerHeartbeatOutput, ReportTaskRunnerHeartbeatError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ReportTaskRunnerHeartbeat");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ReportTaskRunnerHeartbeat");
@@ -2259,10 +3644,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ReportTaskRunnerHeartbeatOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ReportTaskRunnerHeartbeatOutput, _>(
+                    response,
+                    "ReportTaskRunnerHeartbeat",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ReportTaskRunnerHeartbeatError::from_response(response))
@@ -2273,7 +3658,16 @@ impl DataPipeline for DataPipelineClient {
 
     /// <p>Requests that the status of the specified physical or logical pipeline objects be updated in the specified pipeline. This update might not occur immediately, but is eventually consistent. The status that can be set depends on the type of object (for example, DataNode or Activity). You cannot perform this operation on <code>FINISHED</code> pipelines and attempting to do so returns <code>InvalidRequestException</code>.</p>
     fn set_status(&self, input: SetStatusInput) -> RusotoFuture<(), SetStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("SetStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.SetStatus");
@@ -2299,7 +3693,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: SetTaskStatusInput,
     ) -> RusotoFuture<SetTaskStatusOutput, SetTaskStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("SetTaskStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.SetTaskStatus");
@@ -2308,10 +3711,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SetTaskStatusOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<SetTaskStatusOutput, _>(
+                    response,
+                    "SetTaskStatus",
+                )
             } else {
                 Box::new(
                     response
@@ -2328,7 +3731,16 @@ impl DataPipeline for DataPipelineClient {
         &self,
         input: ValidatePipelineDefinitionInput,
     ) -> RusotoFuture<ValidatePipelineDefinitionOutput, ValidatePipelineDefinitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ValidatePipelineDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ValidatePipelineDefinition");
@@ -2337,10 +3749,10 @@ impl DataPipeline for DataPipelineClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ValidatePipelineDefinitionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ValidatePipelineDefinitionOutput, _>(
+                    response,
+                    "ValidatePipelineDefinition",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ValidatePipelineDefinitionError::from_response(response))
@@ -2349,3 +3761,644 @@ impl DataPipeline for DataPipelineClient {
         })
     }
 }
+
+/// A mock of the AWS Data Pipeline API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockDataPipeline {
+    activate_pipeline: Option<
+        Box<
+            dyn Fn(
+                    ActivatePipelineInput,
+                ) -> ::std::result::Result<
+                    ActivatePipelineOutput,
+                    RusotoError<ActivatePipelineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_tags: Option<
+        Box<
+            dyn Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_pipeline: Option<
+        Box<
+            dyn Fn(
+                    CreatePipelineInput,
+                )
+                    -> ::std::result::Result<CreatePipelineOutput, RusotoError<CreatePipelineError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deactivate_pipeline: Option<
+        Box<
+            dyn Fn(
+                    DeactivatePipelineInput,
+                ) -> ::std::result::Result<
+                    DeactivatePipelineOutput,
+                    RusotoError<DeactivatePipelineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_pipeline: Option<
+        Box<
+            dyn Fn(
+                    DeletePipelineInput,
+                ) -> ::std::result::Result<(), RusotoError<DeletePipelineError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_objects: Option<
+        Box<
+            dyn Fn(
+                    DescribeObjectsInput,
+                ) -> ::std::result::Result<
+                    DescribeObjectsOutput,
+                    RusotoError<DescribeObjectsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_pipelines: Option<
+        Box<
+            dyn Fn(
+                    DescribePipelinesInput,
+                ) -> ::std::result::Result<
+                    DescribePipelinesOutput,
+                    RusotoError<DescribePipelinesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    evaluate_expression: Option<
+        Box<
+            dyn Fn(
+                    EvaluateExpressionInput,
+                ) -> ::std::result::Result<
+                    EvaluateExpressionOutput,
+                    RusotoError<EvaluateExpressionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_pipeline_definition: Option<
+        Box<
+            dyn Fn(
+                    GetPipelineDefinitionInput,
+                ) -> ::std::result::Result<
+                    GetPipelineDefinitionOutput,
+                    RusotoError<GetPipelineDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_pipelines: Option<
+        Box<
+            dyn Fn(
+                    ListPipelinesInput,
+                )
+                    -> ::std::result::Result<ListPipelinesOutput, RusotoError<ListPipelinesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    poll_for_task: Option<
+        Box<
+            dyn Fn(
+                    PollForTaskInput,
+                )
+                    -> ::std::result::Result<PollForTaskOutput, RusotoError<PollForTaskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_pipeline_definition: Option<
+        Box<
+            dyn Fn(
+                    PutPipelineDefinitionInput,
+                ) -> ::std::result::Result<
+                    PutPipelineDefinitionOutput,
+                    RusotoError<PutPipelineDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    query_objects: Option<
+        Box<
+            dyn Fn(
+                    QueryObjectsInput,
+                )
+                    -> ::std::result::Result<QueryObjectsOutput, RusotoError<QueryObjectsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_tags: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsInput,
+                )
+                    -> ::std::result::Result<RemoveTagsOutput, RusotoError<RemoveTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    report_task_progress: Option<
+        Box<
+            dyn Fn(
+                    ReportTaskProgressInput,
+                ) -> ::std::result::Result<
+                    ReportTaskProgressOutput,
+                    RusotoError<ReportTaskProgressError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    report_task_runner_heartbeat: Option<
+        Box<
+            dyn Fn(
+                    ReportTaskRunnerHeartbeatInput,
+                ) -> ::std::result::Result<
+                    ReportTaskRunnerHeartbeatOutput,
+                    RusotoError<ReportTaskRunnerHeartbeatError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_status: Option<
+        Box<
+            dyn Fn(SetStatusInput) -> ::std::result::Result<(), RusotoError<SetStatusError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_task_status: Option<
+        Box<
+            dyn Fn(
+                    SetTaskStatusInput,
+                )
+                    -> ::std::result::Result<SetTaskStatusOutput, RusotoError<SetTaskStatusError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    validate_pipeline_definition: Option<
+        Box<
+            dyn Fn(
+                    ValidatePipelineDefinitionInput,
+                ) -> ::std::result::Result<
+                    ValidatePipelineDefinitionOutput,
+                    RusotoError<ValidatePipelineDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockDataPipeline {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockDataPipeline {
+        Default::default()
+    }
+
+    /// Answers `activate_pipeline` with what `handler` returns for its input.
+    pub fn with_activate_pipeline<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                ActivatePipelineInput,
+            )
+                -> ::std::result::Result<ActivatePipelineOutput, RusotoError<ActivatePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.activate_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_tags` with what `handler` returns for its input.
+    pub fn with_add_tags<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_pipeline` with what `handler` returns for its input.
+    pub fn with_create_pipeline<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                CreatePipelineInput,
+            )
+                -> ::std::result::Result<CreatePipelineOutput, RusotoError<CreatePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deactivate_pipeline` with what `handler` returns for its input.
+    pub fn with_deactivate_pipeline<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                DeactivatePipelineInput,
+            ) -> ::std::result::Result<
+                DeactivatePipelineOutput,
+                RusotoError<DeactivatePipelineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deactivate_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_pipeline` with what `handler` returns for its input.
+    pub fn with_delete_pipeline<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(DeletePipelineInput) -> ::std::result::Result<(), RusotoError<DeletePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_objects` with what `handler` returns for its input.
+    pub fn with_describe_objects<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                DescribeObjectsInput,
+            )
+                -> ::std::result::Result<DescribeObjectsOutput, RusotoError<DescribeObjectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_objects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_pipelines` with what `handler` returns for its input.
+    pub fn with_describe_pipelines<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                DescribePipelinesInput,
+            ) -> ::std::result::Result<
+                DescribePipelinesOutput,
+                RusotoError<DescribePipelinesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_pipelines = Some(Box::new(handler));
+        self
+    }
+    /// Answers `evaluate_expression` with what `handler` returns for its input.
+    pub fn with_evaluate_expression<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                EvaluateExpressionInput,
+            ) -> ::std::result::Result<
+                EvaluateExpressionOutput,
+                RusotoError<EvaluateExpressionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.evaluate_expression = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_pipeline_definition` with what `handler` returns for its input.
+    pub fn with_get_pipeline_definition<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                GetPipelineDefinitionInput,
+            ) -> ::std::result::Result<
+                GetPipelineDefinitionOutput,
+                RusotoError<GetPipelineDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_pipeline_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_pipelines` with what `handler` returns for its input.
+    pub fn with_list_pipelines<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                ListPipelinesInput,
+            )
+                -> ::std::result::Result<ListPipelinesOutput, RusotoError<ListPipelinesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_pipelines = Some(Box::new(handler));
+        self
+    }
+    /// Answers `poll_for_task` with what `handler` returns for its input.
+    pub fn with_poll_for_task<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                PollForTaskInput,
+            )
+                -> ::std::result::Result<PollForTaskOutput, RusotoError<PollForTaskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.poll_for_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_pipeline_definition` with what `handler` returns for its input.
+    pub fn with_put_pipeline_definition<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                PutPipelineDefinitionInput,
+            ) -> ::std::result::Result<
+                PutPipelineDefinitionOutput,
+                RusotoError<PutPipelineDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_pipeline_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `query_objects` with what `handler` returns for its input.
+    pub fn with_query_objects<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                QueryObjectsInput,
+            )
+                -> ::std::result::Result<QueryObjectsOutput, RusotoError<QueryObjectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.query_objects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags` with what `handler` returns for its input.
+    pub fn with_remove_tags<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                RemoveTagsInput,
+            ) -> ::std::result::Result<RemoveTagsOutput, RusotoError<RemoveTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `report_task_progress` with what `handler` returns for its input.
+    pub fn with_report_task_progress<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                ReportTaskProgressInput,
+            ) -> ::std::result::Result<
+                ReportTaskProgressOutput,
+                RusotoError<ReportTaskProgressError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.report_task_progress = Some(Box::new(handler));
+        self
+    }
+    /// Answers `report_task_runner_heartbeat` with what `handler` returns for its input.
+    pub fn with_report_task_runner_heartbeat<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                ReportTaskRunnerHeartbeatInput,
+            ) -> ::std::result::Result<
+                ReportTaskRunnerHeartbeatOutput,
+                RusotoError<ReportTaskRunnerHeartbeatError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.report_task_runner_heartbeat = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_status` with what `handler` returns for its input.
+    pub fn with_set_status<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(SetStatusInput) -> ::std::result::Result<(), RusotoError<SetStatusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_task_status` with what `handler` returns for its input.
+    pub fn with_set_task_status<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                SetTaskStatusInput,
+            )
+                -> ::std::result::Result<SetTaskStatusOutput, RusotoError<SetTaskStatusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_task_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `validate_pipeline_definition` with what `handler` returns for its input.
+    pub fn with_validate_pipeline_definition<F>(mut self, handler: F) -> MockDataPipeline
+    where
+        F: Fn(
+                ValidatePipelineDefinitionInput,
+            ) -> ::std::result::Result<
+                ValidatePipelineDefinitionOutput,
+                RusotoError<ValidatePipelineDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.validate_pipeline_definition = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl DataPipeline for MockDataPipeline {
+    fn activate_pipeline(
+        &self,
+        input: ActivatePipelineInput,
+    ) -> RusotoFuture<ActivatePipelineOutput, ActivatePipelineError> {
+        match self.activate_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for activate_pipeline"),
+        }
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        match self.add_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for add_tags"),
+        }
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
+        match self.create_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for create_pipeline"),
+        }
+    }
+    fn deactivate_pipeline(
+        &self,
+        input: DeactivatePipelineInput,
+    ) -> RusotoFuture<DeactivatePipelineOutput, DeactivatePipelineError> {
+        match self.deactivate_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for deactivate_pipeline"),
+        }
+    }
+    fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        match self.delete_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for delete_pipeline"),
+        }
+    }
+    fn describe_objects(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> RusotoFuture<DescribeObjectsOutput, DescribeObjectsError> {
+        match self.describe_objects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for describe_objects"),
+        }
+    }
+    fn describe_pipelines(
+        &self,
+        input: DescribePipelinesInput,
+    ) -> RusotoFuture<DescribePipelinesOutput, DescribePipelinesError> {
+        match self.describe_pipelines {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for describe_pipelines"),
+        }
+    }
+    fn evaluate_expression(
+        &self,
+        input: EvaluateExpressionInput,
+    ) -> RusotoFuture<EvaluateExpressionOutput, EvaluateExpressionError> {
+        match self.evaluate_expression {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for evaluate_expression"),
+        }
+    }
+    fn get_pipeline_definition(
+        &self,
+        input: GetPipelineDefinitionInput,
+    ) -> RusotoFuture<GetPipelineDefinitionOutput, GetPipelineDefinitionError> {
+        match self.get_pipeline_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for get_pipeline_definition"),
+        }
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        match self.list_pipelines {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for list_pipelines"),
+        }
+    }
+    fn poll_for_task(
+        &self,
+        input: PollForTaskInput,
+    ) -> RusotoFuture<PollForTaskOutput, PollForTaskError> {
+        match self.poll_for_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for poll_for_task"),
+        }
+    }
+    fn put_pipeline_definition(
+        &self,
+        input: PutPipelineDefinitionInput,
+    ) -> RusotoFuture<PutPipelineDefinitionOutput, PutPipelineDefinitionError> {
+        match self.put_pipeline_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for put_pipeline_definition"),
+        }
+    }
+    fn query_objects(
+        &self,
+        input: QueryObjectsInput,
+    ) -> RusotoFuture<QueryObjectsOutput, QueryObjectsError> {
+        match self.query_objects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for query_objects"),
+        }
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        match self.remove_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for remove_tags"),
+        }
+    }
+    fn report_task_progress(
+        &self,
+        input: ReportTaskProgressInput,
+    ) -> RusotoFuture<ReportTaskProgressOutput, ReportTaskProgressError> {
+        match self.report_task_progress {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for report_task_progress"),
+        }
+    }
+    fn report_task_runner_heartbeat(
+        &self,
+        input: ReportTaskRunnerHeartbeatInput,
+    ) -> RusotoFuture<ReportTaskRunnerHeartbeatOutput, ReportTaskRunnerHeartbeatError> {
+        match self.report_task_runner_heartbeat {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for report_task_runner_heartbeat"),
+        }
+    }
+    fn set_status(&self, input: SetStatusInput) -> RusotoFuture<(), SetStatusError> {
+        match self.set_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for set_status"),
+        }
+    }
+    fn set_task_status(
+        &self,
+        input: SetTaskStatusInput,
+    ) -> RusotoFuture<SetTaskStatusOutput, SetTaskStatusError> {
+        match self.set_task_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for set_task_status"),
+        }
+    }
+    fn validate_pipeline_definition(
+        &self,
+        input: ValidatePipelineDefinitionInput,
+    ) -> RusotoFuture<ValidatePipelineDefinitionOutput, ValidatePipelineDefinitionError> {
+        match self.validate_pipeline_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDataPipeline has no handler for validate_pipeline_definition"),
+        }
+    }
+}
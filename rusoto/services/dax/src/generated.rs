@@ -25,7 +25,7 @@ use rusoto_core::signature::SignedRequest;
 use serde_json;
 /// <p>Contains all of the attributes of a specific DAX cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Cluster {
     /// <p>The number of nodes in the cluster that are active (i.e., capable of serving requests).</p>
     #[serde(rename = "ActiveNodes")]
@@ -98,6 +98,7 @@ pub struct Cluster {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterRequest {
     /// <p>The Availability Zones (AZs) in which the cluster nodes will be created. All nodes belonging to the cluster are placed in these Availability Zones. Use this parameter if you want to distribute the nodes across multiple AZs.</p>
     #[serde(rename = "AvailabilityZones")]
@@ -150,7 +151,7 @@ pub struct CreateClusterRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateClusterResponse {
     /// <p>A description of the DAX cluster that you have created.</p>
     #[serde(rename = "Cluster")]
@@ -159,6 +160,7 @@ pub struct CreateClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateParameterGroupRequest {
     /// <p>A description of the parameter group.</p>
     #[serde(rename = "Description")]
@@ -170,7 +172,7 @@ pub struct CreateParameterGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateParameterGroupResponse {
     /// <p>Represents the output of a <i>CreateParameterGroup</i> action.</p>
     #[serde(rename = "ParameterGroup")]
@@ -179,6 +181,7 @@ pub struct CreateParameterGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSubnetGroupRequest {
     /// <p>A description for the subnet group</p>
     #[serde(rename = "Description")]
@@ -193,7 +196,7 @@ pub struct CreateSubnetGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSubnetGroupResponse {
     /// <p>Represents the output of a <i>CreateSubnetGroup</i> operation.</p>
     #[serde(rename = "SubnetGroup")]
@@ -202,6 +205,7 @@ pub struct CreateSubnetGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DecreaseReplicationFactorRequest {
     /// <p>The Availability Zone(s) from which to remove nodes.</p>
     #[serde(rename = "AvailabilityZones")]
@@ -220,7 +224,7 @@ pub struct DecreaseReplicationFactorRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DecreaseReplicationFactorResponse {
     /// <p>A description of the DAX cluster, after you have decreased its replication factor.</p>
     #[serde(rename = "Cluster")]
@@ -229,6 +233,7 @@ pub struct DecreaseReplicationFactorResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterRequest {
     /// <p>The name of the cluster to be deleted.</p>
     #[serde(rename = "ClusterName")]
@@ -236,7 +241,7 @@ pub struct DeleteClusterRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteClusterResponse {
     /// <p>A description of the DAX cluster that is being deleted.</p>
     #[serde(rename = "Cluster")]
@@ -245,6 +250,7 @@ pub struct DeleteClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteParameterGroupRequest {
     /// <p>The name of the parameter group to delete.</p>
     #[serde(rename = "ParameterGroupName")]
@@ -252,7 +258,7 @@ pub struct DeleteParameterGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteParameterGroupResponse {
     /// <p>A user-specified message for this action (i.e., a reason for deleting the parameter group).</p>
     #[serde(rename = "DeletionMessage")]
@@ -261,6 +267,7 @@ pub struct DeleteParameterGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSubnetGroupRequest {
     /// <p>The name of the subnet group to delete.</p>
     #[serde(rename = "SubnetGroupName")]
@@ -268,7 +275,7 @@ pub struct DeleteSubnetGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSubnetGroupResponse {
     /// <p>A user-specified message for this action (i.e., a reason for deleting the subnet group).</p>
     #[serde(rename = "DeletionMessage")]
@@ -277,6 +284,7 @@ pub struct DeleteSubnetGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClustersRequest {
     /// <p>The names of the DAX clusters being described.</p>
     #[serde(rename = "ClusterNames")]
@@ -293,7 +301,7 @@ pub struct DescribeClustersRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeClustersResponse {
     /// <p>The descriptions of your DAX clusters, in response to a <i>DescribeClusters</i> request.</p>
     #[serde(rename = "Clusters")]
@@ -306,6 +314,7 @@ pub struct DescribeClustersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDefaultParametersRequest {
     /// <p>The maximum number of results to include in the response. If more results exist than the specified <code>MaxResults</code> value, a token is included in the response so that the remaining results can be retrieved.</p> <p>The value for <code>MaxResults</code> must be between 20 and 100.</p>
     #[serde(rename = "MaxResults")]
@@ -318,7 +327,7 @@ pub struct DescribeDefaultParametersRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDefaultParametersResponse {
     /// <p>Provides an identifier to allow retrieval of paginated results.</p>
     #[serde(rename = "NextToken")]
@@ -331,6 +340,7 @@ pub struct DescribeDefaultParametersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventsRequest {
     /// <p>The number of minutes' worth of events to retrieve.</p>
     #[serde(rename = "Duration")]
@@ -363,7 +373,7 @@ pub struct DescribeEventsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventsResponse {
     /// <p>An array of events. Each element in the array represents one event.</p>
     #[serde(rename = "Events")]
@@ -376,6 +386,7 @@ pub struct DescribeEventsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeParameterGroupsRequest {
     /// <p>The maximum number of results to include in the response. If more results exist than the specified <code>MaxResults</code> value, a token is included in the response so that the remaining results can be retrieved.</p> <p>The value for <code>MaxResults</code> must be between 20 and 100.</p>
     #[serde(rename = "MaxResults")]
@@ -392,7 +403,7 @@ pub struct DescribeParameterGroupsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeParameterGroupsResponse {
     /// <p>Provides an identifier to allow retrieval of paginated results.</p>
     #[serde(rename = "NextToken")]
@@ -405,6 +416,7 @@ pub struct DescribeParameterGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeParametersRequest {
     /// <p>The maximum number of results to include in the response. If more results exist than the specified <code>MaxResults</code> value, a token is included in the response so that the remaining results can be retrieved.</p> <p>The value for <code>MaxResults</code> must be between 20 and 100.</p>
     #[serde(rename = "MaxResults")]
@@ -424,7 +436,7 @@ pub struct DescribeParametersRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeParametersResponse {
     /// <p>Provides an identifier to allow retrieval of paginated results.</p>
     #[serde(rename = "NextToken")]
@@ -437,6 +449,7 @@ pub struct DescribeParametersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSubnetGroupsRequest {
     /// <p>The maximum number of results to include in the response. If more results exist than the specified <code>MaxResults</code> value, a token is included in the response so that the remaining results can be retrieved.</p> <p>The value for <code>MaxResults</code> must be between 20 and 100.</p>
     #[serde(rename = "MaxResults")]
@@ -453,7 +466,7 @@ pub struct DescribeSubnetGroupsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSubnetGroupsResponse {
     /// <p>Provides an identifier to allow retrieval of paginated results.</p>
     #[serde(rename = "NextToken")]
@@ -467,7 +480,7 @@ pub struct DescribeSubnetGroupsResponse {
 
 /// <p>Represents the information required for client programs to connect to the configuration endpoint for a DAX cluster, or to an individual node within the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Endpoint {
     /// <p>The DNS hostname of the endpoint.</p>
     #[serde(rename = "Address")]
@@ -481,7 +494,7 @@ pub struct Endpoint {
 
 /// <p>Represents a single occurrence of something interesting within the system. Some examples of events are creating a DAX cluster, adding or removing a node, or rebooting a node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Event {
     /// <p>The date and time when the event occurred.</p>
     #[serde(rename = "Date")]
@@ -502,6 +515,7 @@ pub struct Event {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IncreaseReplicationFactorRequest {
     /// <p>The Availability Zones (AZs) in which the cluster nodes will be created. All nodes belonging to the cluster are placed in these Availability Zones. Use this parameter if you want to distribute the nodes across multiple AZs.</p>
     #[serde(rename = "AvailabilityZones")]
@@ -516,7 +530,7 @@ pub struct IncreaseReplicationFactorRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IncreaseReplicationFactorResponse {
     /// <p>A description of the DAX cluster. with its new replication factor.</p>
     #[serde(rename = "Cluster")]
@@ -525,6 +539,7 @@ pub struct IncreaseReplicationFactorResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsRequest {
     /// <p>An optional token returned from a prior request. Use this token for pagination of results from this action. If this parameter is specified, the response includes only results beyond the token.</p>
     #[serde(rename = "NextToken")]
@@ -536,7 +551,7 @@ pub struct ListTagsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsResponse {
     /// <p>If this value is present, there are additional results to be displayed. To retrieve them, call <code>ListTags</code> again, with <code>NextToken</code> set to this value.</p>
     #[serde(rename = "NextToken")]
@@ -550,7 +565,7 @@ pub struct ListTagsResponse {
 
 /// <p>Represents an individual node within a DAX cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Node {
     /// <p>The Availability Zone (AZ) in which the node has been deployed.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -580,7 +595,7 @@ pub struct Node {
 
 /// <p>Represents a parameter value that is applicable to a particular node type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NodeTypeSpecificValue {
     /// <p>A node type to which the parameter value applies.</p>
     #[serde(rename = "NodeType")]
@@ -594,7 +609,7 @@ pub struct NodeTypeSpecificValue {
 
 /// <p>Describes a notification topic and its status. Notification topics are used for publishing DAX events to subscribers using Amazon Simple Notification Service (SNS).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NotificationConfiguration {
     /// <p>The Amazon Resource Name (ARN) that identifies the topic. </p>
     #[serde(rename = "TopicArn")]
@@ -608,7 +623,7 @@ pub struct NotificationConfiguration {
 
 /// <p>Describes an individual setting that controls some aspect of DAX behavior.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Parameter {
     /// <p>A range of values within which the parameter can be set.</p>
     #[serde(rename = "AllowedValues")]
@@ -654,7 +669,7 @@ pub struct Parameter {
 
 /// <p>A named set of parameters that are applied to all of the nodes in a DAX cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ParameterGroup {
     /// <p>A description of the parameter group.</p>
     #[serde(rename = "Description")]
@@ -668,7 +683,7 @@ pub struct ParameterGroup {
 
 /// <p>The status of a parameter group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ParameterGroupStatus {
     /// <p>The node IDs of one or more nodes to be rebooted.</p>
     #[serde(rename = "NodeIdsToReboot")]
@@ -686,6 +701,7 @@ pub struct ParameterGroupStatus {
 
 /// <p>An individual DAX parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ParameterNameValue {
     /// <p>The name of the parameter.</p>
     #[serde(rename = "ParameterName")]
@@ -698,6 +714,7 @@ pub struct ParameterNameValue {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootNodeRequest {
     /// <p>The name of the DAX cluster containing the node to be rebooted.</p>
     #[serde(rename = "ClusterName")]
@@ -708,7 +725,7 @@ pub struct RebootNodeRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RebootNodeResponse {
     /// <p>A description of the DAX cluster after a node has been rebooted.</p>
     #[serde(rename = "Cluster")]
@@ -718,7 +735,7 @@ pub struct RebootNodeResponse {
 
 /// <p>The description of the server-side encryption status on the specified DAX cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SSEDescription {
     /// <p><p>The current state of server-side encryption:</p> <ul> <li> <p> <code>ENABLING</code> - Server-side encryption is being enabled.</p> </li> <li> <p> <code>ENABLED</code> - Server-side encryption is enabled.</p> </li> <li> <p> <code>DISABLING</code> - Server-side encryption is being disabled.</p> </li> <li> <p> <code>DISABLED</code> - Server-side encryption is disabled.</p> </li> </ul></p>
     #[serde(rename = "Status")]
@@ -728,6 +745,7 @@ pub struct SSEDescription {
 
 /// <p>Represents the settings used to enable server-side encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SSESpecification {
     /// <p>Indicates whether server-side encryption is enabled (true) or disabled (false) on the cluster.</p>
     #[serde(rename = "Enabled")]
@@ -736,7 +754,7 @@ pub struct SSESpecification {
 
 /// <p>An individual VPC security group and its status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SecurityGroupMembership {
     /// <p>The unique ID for this security group.</p>
     #[serde(rename = "SecurityGroupIdentifier")]
@@ -750,7 +768,7 @@ pub struct SecurityGroupMembership {
 
 /// <p>Represents the subnet associated with a DAX cluster. This parameter refers to subnets defined in Amazon Virtual Private Cloud (Amazon VPC) and used with DAX.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Subnet {
     /// <p>The Availability Zone (AZ) for subnet subnet.</p>
     #[serde(rename = "SubnetAvailabilityZone")]
@@ -764,7 +782,7 @@ pub struct Subnet {
 
 /// <p><p>Represents the output of one of the following actions:</p> <ul> <li> <p> <i>CreateSubnetGroup</i> </p> </li> <li> <p> <i>ModifySubnetGroup</i> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubnetGroup {
     /// <p>The description of the subnet group.</p>
     #[serde(rename = "Description")]
@@ -798,6 +816,7 @@ pub struct Tag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The name of the DAX resource to which tags should be added.</p>
     #[serde(rename = "ResourceName")]
@@ -808,7 +827,7 @@ pub struct TagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {
     /// <p>The list of tags that are associated with the DAX resource.</p>
     #[serde(rename = "Tags")]
@@ -817,6 +836,7 @@ pub struct TagResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The name of the DAX resource from which the tags should be removed.</p>
     #[serde(rename = "ResourceName")]
@@ -827,7 +847,7 @@ pub struct UntagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {
     /// <p>The tag keys that have been removed from the cluster.</p>
     #[serde(rename = "Tags")]
@@ -836,6 +856,7 @@ pub struct UntagResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateClusterRequest {
     /// <p>The name of the DAX cluster to be modified.</p>
     #[serde(rename = "ClusterName")]
@@ -867,7 +888,7 @@ pub struct UpdateClusterRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateClusterResponse {
     /// <p>A description of the DAX cluster, after it has been modified.</p>
     #[serde(rename = "Cluster")]
@@ -876,6 +897,7 @@ pub struct UpdateClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateParameterGroupRequest {
     /// <p>The name of the parameter group.</p>
     #[serde(rename = "ParameterGroupName")]
@@ -886,7 +908,7 @@ pub struct UpdateParameterGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateParameterGroupResponse {
     /// <p>The parameter group that has been modified.</p>
     #[serde(rename = "ParameterGroup")]
@@ -895,6 +917,7 @@ pub struct UpdateParameterGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSubnetGroupRequest {
     /// <p>A description of the subnet group.</p>
     #[serde(rename = "Description")]
@@ -910,7 +933,7 @@ pub struct UpdateSubnetGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSubnetGroupResponse {
     /// <p>The subnet group that has been modified.</p>
     #[serde(rename = "SubnetGroup")]
@@ -1023,7 +1046,12 @@ impl CreateClusterError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1106,7 +1134,12 @@ impl CreateParameterGroupError {
                         CreateParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1172,7 +1205,12 @@ impl CreateSubnetGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1246,7 +1284,12 @@ impl DecreaseReplicationFactorError {
                         DecreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1310,7 +1353,12 @@ impl DeleteClusterError {
                         DeleteClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1377,7 +1425,12 @@ impl DeleteParameterGroupError {
                         DeleteParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1429,7 +1482,12 @@ impl DeleteSubnetGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1487,7 +1545,12 @@ impl DescribeClustersError {
                         DescribeClustersError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1539,7 +1602,12 @@ impl DescribeDefaultParametersError {
                         DescribeDefaultParametersError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1590,7 +1658,12 @@ impl DescribeEventsError {
                         DescribeEventsError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1648,7 +1721,12 @@ impl DescribeParameterGroupsError {
                         DescribeParameterGroupsError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1707,7 +1785,12 @@ impl DescribeParametersError {
                         DescribeParametersError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1751,7 +1834,12 @@ impl DescribeSubnetGroupsError {
                         DescribeSubnetGroupsError::SubnetGroupNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1843,7 +1931,12 @@ impl IncreaseReplicationFactorError {
                         IncreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1913,7 +2006,12 @@ impl ListTagsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1980,7 +2078,12 @@ impl RebootNodeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2056,7 +2159,12 @@ impl TagResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2131,7 +2239,12 @@ impl UntagResourceError {
                 "TagNotFoundFault" => {
                     return RusotoError::Service(UntagResourceError::TagNotFoundFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2210,7 +2323,12 @@ impl UpdateClusterError {
                         UpdateClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2279,7 +2397,12 @@ impl UpdateParameterGroupError {
                         UpdateParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2342,7 +2465,12 @@ impl UpdateSubnetGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2490,315 +2618,701 @@ pub trait DynamodbAccelerator {
         input: UpdateSubnetGroupRequest,
     ) -> RusotoFuture<UpdateSubnetGroupResponse, UpdateSubnetGroupError>;
 }
-/// A client for the Amazon DAX API.
-#[derive(Clone)]
-pub struct DynamodbAcceleratorClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl DynamodbAcceleratorClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> DynamodbAcceleratorClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> DynamodbAcceleratorClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> DynamodbAcceleratorClient {
-        DynamodbAcceleratorClient { client, region }
-    }
-}
-
-impl DynamodbAccelerator for DynamodbAcceleratorClient {
-    /// <p>Creates a DAX cluster. All nodes in the cluster run the same DAX caching software.</p>
+const _: Option<&dyn DynamodbAccelerator> = None;
+impl<'a, T: DynamodbAccelerator + ?Sized> DynamodbAccelerator for &'a T {
     fn create_cluster(
         &self,
         input: CreateClusterRequest,
     ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
-        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDAXV3.CreateCluster");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateClusterResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateClusterError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_cluster(input)
     }
-
-    /// <p>Creates a new parameter group. A parameter group is a collection of parameters that you apply to all of the nodes in a DAX cluster.</p>
     fn create_parameter_group(
         &self,
         input: CreateParameterGroupRequest,
     ) -> RusotoFuture<CreateParameterGroupResponse, CreateParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDAXV3.CreateParameterGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateParameterGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateParameterGroupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_parameter_group(input)
     }
-
-    /// <p>Creates a new subnet group.</p>
     fn create_subnet_group(
         &self,
         input: CreateSubnetGroupRequest,
     ) -> RusotoFuture<CreateSubnetGroupResponse, CreateSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDAXV3.CreateSubnetGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSubnetGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSubnetGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_subnet_group(input)
     }
-
-    /// <p><p>Removes one or more nodes from a DAX cluster.</p> <note> <p>You cannot use <code>DecreaseReplicationFactor</code> to remove the last node in a DAX cluster. If you need to do this, use <code>DeleteCluster</code> instead.</p> </note></p>
     fn decrease_replication_factor(
         &self,
         input: DecreaseReplicationFactorRequest,
     ) -> RusotoFuture<DecreaseReplicationFactorResponse, DecreaseReplicationFactorError> {
-        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDAXV3.DecreaseReplicationFactor");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DecreaseReplicationFactorResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DecreaseReplicationFactorError::from_response(response))
-                }))
-            }
-        })
+        (**self).decrease_replication_factor(input)
     }
-
-    /// <p>Deletes a previously provisioned DAX cluster. <i>DeleteCluster</i> deletes all associated nodes, node endpoints and the DAX cluster itself. When you receive a successful response from this action, DAX immediately begins deleting the cluster; you cannot cancel or revert this action.</p>
     fn delete_cluster(
         &self,
         input: DeleteClusterRequest,
     ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
-        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDAXV3.DeleteCluster");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteClusterResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteClusterError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_cluster(input)
     }
-
-    /// <p>Deletes the specified parameter group. You cannot delete a parameter group if it is associated with any DAX clusters.</p>
     fn delete_parameter_group(
         &self,
         input: DeleteParameterGroupRequest,
     ) -> RusotoFuture<DeleteParameterGroupResponse, DeleteParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDAXV3.DeleteParameterGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteParameterGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteParameterGroupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_parameter_group(input)
     }
-
-    /// <p><p>Deletes a subnet group.</p> <note> <p>You cannot delete a subnet group if it is associated with any DAX clusters.</p> </note></p>
     fn delete_subnet_group(
         &self,
         input: DeleteSubnetGroupRequest,
     ) -> RusotoFuture<DeleteSubnetGroupResponse, DeleteSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDAXV3.DeleteSubnetGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSubnetGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteSubnetGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_subnet_group(input)
     }
-
-    /// <p>Returns information about all provisioned DAX clusters if no cluster identifier is specified, or about a specific DAX cluster if a cluster identifier is supplied.</p> <p>If the cluster is in the CREATING state, only cluster level information will be displayed until all of the nodes are successfully provisioned.</p> <p>If the cluster is in the DELETING state, only cluster level information will be displayed.</p> <p>If nodes are currently being added to the DAX cluster, node endpoint information and creation time for the additional nodes will not be displayed until they are completely provisioned. When the DAX cluster state is <i>available</i>, the cluster is ready for use.</p> <p>If nodes are currently being removed from the DAX cluster, no endpoint information for the removed nodes is displayed.</p>
     fn describe_clusters(
         &self,
         input: DescribeClustersRequest,
     ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
-        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDAXV3.DescribeClusters");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeClustersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeClustersError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_clusters(input)
     }
-
-    /// <p>Returns the default system parameter information for the DAX caching software.</p>
     fn describe_default_parameters(
         &self,
         input: DescribeDefaultParametersRequest,
     ) -> RusotoFuture<DescribeDefaultParametersResponse, DescribeDefaultParametersError> {
-        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDAXV3.DescribeDefaultParameters");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDefaultParametersResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDefaultParametersError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_default_parameters(input)
     }
-
-    /// <p>Returns events related to DAX clusters and parameter groups. You can obtain events specific to a particular DAX cluster or parameter group by providing the name as a parameter.</p> <p>By default, only the events occurring within the last hour are returned; however, you can retrieve up to 14 days' worth of events if necessary.</p>
     fn describe_events(
         &self,
         input: DescribeEventsRequest,
     ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
-        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDAXV3.DescribeEvents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+        (**self).describe_events(input)
+    }
+    fn describe_parameter_groups(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> RusotoFuture<DescribeParameterGroupsResponse, DescribeParameterGroupsError> {
+        (**self).describe_parameter_groups(input)
+    }
+    fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> RusotoFuture<DescribeParametersResponse, DescribeParametersError> {
+        (**self).describe_parameters(input)
+    }
+    fn describe_subnet_groups(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> RusotoFuture<DescribeSubnetGroupsResponse, DescribeSubnetGroupsError> {
+        (**self).describe_subnet_groups(input)
+    }
+    fn increase_replication_factor(
+        &self,
+        input: IncreaseReplicationFactorRequest,
+    ) -> RusotoFuture<IncreaseReplicationFactorResponse, IncreaseReplicationFactorError> {
+        (**self).increase_replication_factor(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn reboot_node(
+        &self,
+        input: RebootNodeRequest,
+    ) -> RusotoFuture<RebootNodeResponse, RebootNodeError> {
+        (**self).reboot_node(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> RusotoFuture<UpdateClusterResponse, UpdateClusterError> {
+        (**self).update_cluster(input)
+    }
+    fn update_parameter_group(
+        &self,
+        input: UpdateParameterGroupRequest,
+    ) -> RusotoFuture<UpdateParameterGroupResponse, UpdateParameterGroupError> {
+        (**self).update_parameter_group(input)
+    }
+    fn update_subnet_group(
+        &self,
+        input: UpdateSubnetGroupRequest,
+    ) -> RusotoFuture<UpdateSubnetGroupResponse, UpdateSubnetGroupError> {
+        (**self).update_subnet_group(input)
+    }
+}
+impl<T: DynamodbAccelerator + ?Sized> DynamodbAccelerator for Box<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_parameter_group(
+        &self,
+        input: CreateParameterGroupRequest,
+    ) -> RusotoFuture<CreateParameterGroupResponse, CreateParameterGroupError> {
+        (**self).create_parameter_group(input)
+    }
+    fn create_subnet_group(
+        &self,
+        input: CreateSubnetGroupRequest,
+    ) -> RusotoFuture<CreateSubnetGroupResponse, CreateSubnetGroupError> {
+        (**self).create_subnet_group(input)
+    }
+    fn decrease_replication_factor(
+        &self,
+        input: DecreaseReplicationFactorRequest,
+    ) -> RusotoFuture<DecreaseReplicationFactorResponse, DecreaseReplicationFactorError> {
+        (**self).decrease_replication_factor(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_parameter_group(
+        &self,
+        input: DeleteParameterGroupRequest,
+    ) -> RusotoFuture<DeleteParameterGroupResponse, DeleteParameterGroupError> {
+        (**self).delete_parameter_group(input)
+    }
+    fn delete_subnet_group(
+        &self,
+        input: DeleteSubnetGroupRequest,
+    ) -> RusotoFuture<DeleteSubnetGroupResponse, DeleteSubnetGroupError> {
+        (**self).delete_subnet_group(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn describe_default_parameters(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> RusotoFuture<DescribeDefaultParametersResponse, DescribeDefaultParametersError> {
+        (**self).describe_default_parameters(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_parameter_groups(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> RusotoFuture<DescribeParameterGroupsResponse, DescribeParameterGroupsError> {
+        (**self).describe_parameter_groups(input)
+    }
+    fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> RusotoFuture<DescribeParametersResponse, DescribeParametersError> {
+        (**self).describe_parameters(input)
+    }
+    fn describe_subnet_groups(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> RusotoFuture<DescribeSubnetGroupsResponse, DescribeSubnetGroupsError> {
+        (**self).describe_subnet_groups(input)
+    }
+    fn increase_replication_factor(
+        &self,
+        input: IncreaseReplicationFactorRequest,
+    ) -> RusotoFuture<IncreaseReplicationFactorResponse, IncreaseReplicationFactorError> {
+        (**self).increase_replication_factor(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn reboot_node(
+        &self,
+        input: RebootNodeRequest,
+    ) -> RusotoFuture<RebootNodeResponse, RebootNodeError> {
+        (**self).reboot_node(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> RusotoFuture<UpdateClusterResponse, UpdateClusterError> {
+        (**self).update_cluster(input)
+    }
+    fn update_parameter_group(
+        &self,
+        input: UpdateParameterGroupRequest,
+    ) -> RusotoFuture<UpdateParameterGroupResponse, UpdateParameterGroupError> {
+        (**self).update_parameter_group(input)
+    }
+    fn update_subnet_group(
+        &self,
+        input: UpdateSubnetGroupRequest,
+    ) -> RusotoFuture<UpdateSubnetGroupResponse, UpdateSubnetGroupError> {
+        (**self).update_subnet_group(input)
+    }
+}
+impl<T: DynamodbAccelerator + ?Sized> DynamodbAccelerator for ::std::sync::Arc<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_parameter_group(
+        &self,
+        input: CreateParameterGroupRequest,
+    ) -> RusotoFuture<CreateParameterGroupResponse, CreateParameterGroupError> {
+        (**self).create_parameter_group(input)
+    }
+    fn create_subnet_group(
+        &self,
+        input: CreateSubnetGroupRequest,
+    ) -> RusotoFuture<CreateSubnetGroupResponse, CreateSubnetGroupError> {
+        (**self).create_subnet_group(input)
+    }
+    fn decrease_replication_factor(
+        &self,
+        input: DecreaseReplicationFactorRequest,
+    ) -> RusotoFuture<DecreaseReplicationFactorResponse, DecreaseReplicationFactorError> {
+        (**self).decrease_replication_factor(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_parameter_group(
+        &self,
+        input: DeleteParameterGroupRequest,
+    ) -> RusotoFuture<DeleteParameterGroupResponse, DeleteParameterGroupError> {
+        (**self).delete_parameter_group(input)
+    }
+    fn delete_subnet_group(
+        &self,
+        input: DeleteSubnetGroupRequest,
+    ) -> RusotoFuture<DeleteSubnetGroupResponse, DeleteSubnetGroupError> {
+        (**self).delete_subnet_group(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn describe_default_parameters(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> RusotoFuture<DescribeDefaultParametersResponse, DescribeDefaultParametersError> {
+        (**self).describe_default_parameters(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_parameter_groups(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> RusotoFuture<DescribeParameterGroupsResponse, DescribeParameterGroupsError> {
+        (**self).describe_parameter_groups(input)
+    }
+    fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> RusotoFuture<DescribeParametersResponse, DescribeParametersError> {
+        (**self).describe_parameters(input)
+    }
+    fn describe_subnet_groups(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> RusotoFuture<DescribeSubnetGroupsResponse, DescribeSubnetGroupsError> {
+        (**self).describe_subnet_groups(input)
+    }
+    fn increase_replication_factor(
+        &self,
+        input: IncreaseReplicationFactorRequest,
+    ) -> RusotoFuture<IncreaseReplicationFactorResponse, IncreaseReplicationFactorError> {
+        (**self).increase_replication_factor(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn reboot_node(
+        &self,
+        input: RebootNodeRequest,
+    ) -> RusotoFuture<RebootNodeResponse, RebootNodeError> {
+        (**self).reboot_node(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> RusotoFuture<UpdateClusterResponse, UpdateClusterError> {
+        (**self).update_cluster(input)
+    }
+    fn update_parameter_group(
+        &self,
+        input: UpdateParameterGroupRequest,
+    ) -> RusotoFuture<UpdateParameterGroupResponse, UpdateParameterGroupError> {
+        (**self).update_parameter_group(input)
+    }
+    fn update_subnet_group(
+        &self,
+        input: UpdateSubnetGroupRequest,
+    ) -> RusotoFuture<UpdateSubnetGroupResponse, UpdateSubnetGroupError> {
+        (**self).update_subnet_group(input)
+    }
+}
+/// A client for the Amazon DAX API.
+#[derive(Clone)]
+pub struct DynamodbAcceleratorClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl DynamodbAcceleratorClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DynamodbAcceleratorClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DynamodbAcceleratorClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> DynamodbAcceleratorClient {
+        DynamodbAcceleratorClient { client, region }
+    }
+}
+
+impl DynamodbAccelerator for DynamodbAcceleratorClient {
+    /// <p>Creates a DAX cluster. All nodes in the cluster run the same DAX caching software.</p>
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("CreateCluster");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDAXV3.CreateCluster");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateClusterResponse, _>(
+                    response,
+                    "CreateCluster",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateClusterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new parameter group. A parameter group is a collection of parameters that you apply to all of the nodes in a DAX cluster.</p>
+    fn create_parameter_group(
+        &self,
+        input: CreateParameterGroupRequest,
+    ) -> RusotoFuture<CreateParameterGroupResponse, CreateParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("CreateParameterGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDAXV3.CreateParameterGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateParameterGroupResponse, _>(
+                    response,
+                    "CreateParameterGroup",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateParameterGroupError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new subnet group.</p>
+    fn create_subnet_group(
+        &self,
+        input: CreateSubnetGroupRequest,
+    ) -> RusotoFuture<CreateSubnetGroupResponse, CreateSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("CreateSubnetGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDAXV3.CreateSubnetGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSubnetGroupResponse, _>(
+                    response,
+                    "CreateSubnetGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSubnetGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Removes one or more nodes from a DAX cluster.</p> <note> <p>You cannot use <code>DecreaseReplicationFactor</code> to remove the last node in a DAX cluster. If you need to do this, use <code>DeleteCluster</code> instead.</p> </note></p>
+    fn decrease_replication_factor(
+        &self,
+        input: DecreaseReplicationFactorRequest,
+    ) -> RusotoFuture<DecreaseReplicationFactorResponse, DecreaseReplicationFactorError> {
+        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DecreaseReplicationFactor");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDAXV3.DecreaseReplicationFactor");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DecreaseReplicationFactorResponse, _>(
+                    response,
+                    "DecreaseReplicationFactor",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DecreaseReplicationFactorError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a previously provisioned DAX cluster. <i>DeleteCluster</i> deletes all associated nodes, node endpoints and the DAX cluster itself. When you receive a successful response from this action, DAX immediately begins deleting the cluster; you cannot cancel or revert this action.</p>
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DeleteCluster");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDAXV3.DeleteCluster");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteClusterResponse, _>(
+                    response,
+                    "DeleteCluster",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteClusterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified parameter group. You cannot delete a parameter group if it is associated with any DAX clusters.</p>
+    fn delete_parameter_group(
+        &self,
+        input: DeleteParameterGroupRequest,
+    ) -> RusotoFuture<DeleteParameterGroupResponse, DeleteParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DeleteParameterGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDAXV3.DeleteParameterGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteParameterGroupResponse, _>(
+                    response,
+                    "DeleteParameterGroup",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteParameterGroupError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a subnet group.</p> <note> <p>You cannot delete a subnet group if it is associated with any DAX clusters.</p> </note></p>
+    fn delete_subnet_group(
+        &self,
+        input: DeleteSubnetGroupRequest,
+    ) -> RusotoFuture<DeleteSubnetGroupResponse, DeleteSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DeleteSubnetGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDAXV3.DeleteSubnetGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSubnetGroupResponse, _>(
+                    response,
+                    "DeleteSubnetGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteSubnetGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about all provisioned DAX clusters if no cluster identifier is specified, or about a specific DAX cluster if a cluster identifier is supplied.</p> <p>If the cluster is in the CREATING state, only cluster level information will be displayed until all of the nodes are successfully provisioned.</p> <p>If the cluster is in the DELETING state, only cluster level information will be displayed.</p> <p>If nodes are currently being added to the DAX cluster, node endpoint information and creation time for the additional nodes will not be displayed until they are completely provisioned. When the DAX cluster state is <i>available</i>, the cluster is ready for use.</p> <p>If nodes are currently being removed from the DAX cluster, no endpoint information for the removed nodes is displayed.</p>
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeClusters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDAXV3.DescribeClusters");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeClustersResponse, _>(
+                    response,
+                    "DescribeClusters",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeClustersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the default system parameter information for the DAX caching software.</p>
+    fn describe_default_parameters(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> RusotoFuture<DescribeDefaultParametersResponse, DescribeDefaultParametersError> {
+        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeDefaultParameters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDAXV3.DescribeDefaultParameters");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDefaultParametersResponse, _>(
+                    response,
+                    "DescribeDefaultParameters",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventsResponse, _>()
+                    Err(DescribeDefaultParametersError::from_response(response))
                 }))
+            }
+        })
+    }
+
+    /// <p>Returns events related to DAX clusters and parameter groups. You can obtain events specific to a particular DAX cluster or parameter group by providing the name as a parameter.</p> <p>By default, only the events occurring within the last hour are returned; however, you can retrieve up to 14 days' worth of events if necessary.</p>
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDAXV3.DescribeEvents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEventsResponse, _>(
+                    response,
+                    "DescribeEvents",
+                )
             } else {
                 Box::new(
                     response
@@ -2816,6 +3330,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeParameterGroupsRequest,
     ) -> RusotoFuture<DescribeParameterGroupsResponse, DescribeParameterGroupsError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeParameterGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeParameterGroups");
@@ -2824,10 +3339,10 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeParameterGroupsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeParameterGroupsResponse, _>(
+                    response,
+                    "DescribeParameterGroups",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeParameterGroupsError::from_response(response))
@@ -2842,6 +3357,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeParametersRequest,
     ) -> RusotoFuture<DescribeParametersResponse, DescribeParametersError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeParameters");
@@ -2850,10 +3366,10 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeParametersResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeParametersResponse, _>(
+                    response,
+                    "DescribeParameters",
+                )
             } else {
                 Box::new(
                     response
@@ -2871,6 +3387,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeSubnetGroupsRequest,
     ) -> RusotoFuture<DescribeSubnetGroupsResponse, DescribeSubnetGroupsError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeSubnetGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeSubnetGroups");
@@ -2879,10 +3396,10 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSubnetGroupsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeSubnetGroupsResponse, _>(
+                    response,
+                    "DescribeSubnetGroups",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2899,6 +3416,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: IncreaseReplicationFactorRequest,
     ) -> RusotoFuture<IncreaseReplicationFactorResponse, IncreaseReplicationFactorError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("IncreaseReplicationFactor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.IncreaseReplicationFactor");
@@ -2907,10 +3425,10 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<IncreaseReplicationFactorResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<IncreaseReplicationFactorResponse, _>(
+                    response,
+                    "IncreaseReplicationFactor",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(IncreaseReplicationFactorError::from_response(response))
@@ -2922,6 +3440,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
     /// <p>List all of the tags for a DAX cluster. You can call <code>ListTags</code> up to 10 times per second, per account.</p>
     fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.ListTags");
@@ -2930,10 +3449,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsResponse, _>(response, "ListTags")
             } else {
                 Box::new(
                     response
@@ -2951,6 +3467,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: RebootNodeRequest,
     ) -> RusotoFuture<RebootNodeResponse, RebootNodeError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("RebootNode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.RebootNode");
@@ -2959,10 +3476,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RebootNodeResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RebootNodeResponse, _>(response, "RebootNode")
             } else {
                 Box::new(
                     response
@@ -2980,6 +3494,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.TagResource");
@@ -2988,10 +3503,10 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -3009,6 +3524,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UntagResource");
@@ -3017,10 +3533,10 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -3038,6 +3554,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateClusterRequest,
     ) -> RusotoFuture<UpdateClusterResponse, UpdateClusterError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UpdateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateCluster");
@@ -3046,10 +3563,10 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateClusterResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateClusterResponse, _>(
+                    response,
+                    "UpdateCluster",
+                )
             } else {
                 Box::new(
                     response
@@ -3067,6 +3584,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateParameterGroupRequest,
     ) -> RusotoFuture<UpdateParameterGroupResponse, UpdateParameterGroupError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UpdateParameterGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateParameterGroup");
@@ -3075,10 +3593,10 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateParameterGroupResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateParameterGroupResponse, _>(
+                    response,
+                    "UpdateParameterGroup",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3095,6 +3613,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateSubnetGroupRequest,
     ) -> RusotoFuture<UpdateSubnetGroupResponse, UpdateSubnetGroupError> {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UpdateSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateSubnetGroup");
@@ -3103,10 +3622,10 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSubnetGroupResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateSubnetGroupResponse, _>(
+                    response,
+                    "UpdateSubnetGroup",
+                )
             } else {
                 Box::new(
                     response
@@ -3118,3 +3637,747 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         })
     }
 }
+
+/// A mock of the Amazon DAX API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockDynamodbAccelerator {
+    create_cluster: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterRequest,
+                )
+                    -> ::std::result::Result<CreateClusterResponse, RusotoError<CreateClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    CreateParameterGroupRequest,
+                ) -> ::std::result::Result<
+                    CreateParameterGroupResponse,
+                    RusotoError<CreateParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    CreateSubnetGroupRequest,
+                ) -> ::std::result::Result<
+                    CreateSubnetGroupResponse,
+                    RusotoError<CreateSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    decrease_replication_factor: Option<
+        Box<
+            dyn Fn(
+                    DecreaseReplicationFactorRequest,
+                ) -> ::std::result::Result<
+                    DecreaseReplicationFactorResponse,
+                    RusotoError<DecreaseReplicationFactorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_cluster: Option<
+        Box<
+            dyn Fn(
+                    DeleteClusterRequest,
+                )
+                    -> ::std::result::Result<DeleteClusterResponse, RusotoError<DeleteClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteParameterGroupRequest,
+                ) -> ::std::result::Result<
+                    DeleteParameterGroupResponse,
+                    RusotoError<DeleteParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteSubnetGroupRequest,
+                ) -> ::std::result::Result<
+                    DeleteSubnetGroupResponse,
+                    RusotoError<DeleteSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_clusters: Option<
+        Box<
+            dyn Fn(
+                    DescribeClustersRequest,
+                ) -> ::std::result::Result<
+                    DescribeClustersResponse,
+                    RusotoError<DescribeClustersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_default_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeDefaultParametersRequest,
+                ) -> ::std::result::Result<
+                    DescribeDefaultParametersResponse,
+                    RusotoError<DescribeDefaultParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_events: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventsRequest,
+                ) -> ::std::result::Result<
+                    DescribeEventsResponse,
+                    RusotoError<DescribeEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_parameter_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeParameterGroupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeParameterGroupsResponse,
+                    RusotoError<DescribeParameterGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeParametersRequest,
+                ) -> ::std::result::Result<
+                    DescribeParametersResponse,
+                    RusotoError<DescribeParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_subnet_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeSubnetGroupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeSubnetGroupsResponse,
+                    RusotoError<DescribeSubnetGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    increase_replication_factor: Option<
+        Box<
+            dyn Fn(
+                    IncreaseReplicationFactorRequest,
+                ) -> ::std::result::Result<
+                    IncreaseReplicationFactorResponse,
+                    RusotoError<IncreaseReplicationFactorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags: Option<
+        Box<
+            dyn Fn(
+                    ListTagsRequest,
+                )
+                    -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    reboot_node: Option<
+        Box<
+            dyn Fn(
+                    RebootNodeRequest,
+                )
+                    -> ::std::result::Result<RebootNodeResponse, RusotoError<RebootNodeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_cluster: Option<
+        Box<
+            dyn Fn(
+                    UpdateClusterRequest,
+                )
+                    -> ::std::result::Result<UpdateClusterResponse, RusotoError<UpdateClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateParameterGroupRequest,
+                ) -> ::std::result::Result<
+                    UpdateParameterGroupResponse,
+                    RusotoError<UpdateParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateSubnetGroupRequest,
+                ) -> ::std::result::Result<
+                    UpdateSubnetGroupResponse,
+                    RusotoError<UpdateSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockDynamodbAccelerator {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockDynamodbAccelerator {
+        Default::default()
+    }
+
+    /// Answers `create_cluster` with what `handler` returns for its input.
+    pub fn with_create_cluster<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                CreateClusterRequest,
+            )
+                -> ::std::result::Result<CreateClusterResponse, RusotoError<CreateClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_parameter_group` with what `handler` returns for its input.
+    pub fn with_create_parameter_group<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                CreateParameterGroupRequest,
+            ) -> ::std::result::Result<
+                CreateParameterGroupResponse,
+                RusotoError<CreateParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_subnet_group` with what `handler` returns for its input.
+    pub fn with_create_subnet_group<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                CreateSubnetGroupRequest,
+            ) -> ::std::result::Result<
+                CreateSubnetGroupResponse,
+                RusotoError<CreateSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `decrease_replication_factor` with what `handler` returns for its input.
+    pub fn with_decrease_replication_factor<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                DecreaseReplicationFactorRequest,
+            ) -> ::std::result::Result<
+                DecreaseReplicationFactorResponse,
+                RusotoError<DecreaseReplicationFactorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.decrease_replication_factor = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cluster` with what `handler` returns for its input.
+    pub fn with_delete_cluster<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                DeleteClusterRequest,
+            )
+                -> ::std::result::Result<DeleteClusterResponse, RusotoError<DeleteClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_parameter_group` with what `handler` returns for its input.
+    pub fn with_delete_parameter_group<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                DeleteParameterGroupRequest,
+            ) -> ::std::result::Result<
+                DeleteParameterGroupResponse,
+                RusotoError<DeleteParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_subnet_group` with what `handler` returns for its input.
+    pub fn with_delete_subnet_group<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                DeleteSubnetGroupRequest,
+            ) -> ::std::result::Result<
+                DeleteSubnetGroupResponse,
+                RusotoError<DeleteSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_clusters` with what `handler` returns for its input.
+    pub fn with_describe_clusters<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                DescribeClustersRequest,
+            ) -> ::std::result::Result<
+                DescribeClustersResponse,
+                RusotoError<DescribeClustersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_default_parameters` with what `handler` returns for its input.
+    pub fn with_describe_default_parameters<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                DescribeDefaultParametersRequest,
+            ) -> ::std::result::Result<
+                DescribeDefaultParametersResponse,
+                RusotoError<DescribeDefaultParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_default_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_events` with what `handler` returns for its input.
+    pub fn with_describe_events<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                DescribeEventsRequest,
+            )
+                -> ::std::result::Result<DescribeEventsResponse, RusotoError<DescribeEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_parameter_groups` with what `handler` returns for its input.
+    pub fn with_describe_parameter_groups<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                DescribeParameterGroupsRequest,
+            ) -> ::std::result::Result<
+                DescribeParameterGroupsResponse,
+                RusotoError<DescribeParameterGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_parameter_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_parameters` with what `handler` returns for its input.
+    pub fn with_describe_parameters<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                DescribeParametersRequest,
+            ) -> ::std::result::Result<
+                DescribeParametersResponse,
+                RusotoError<DescribeParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_subnet_groups` with what `handler` returns for its input.
+    pub fn with_describe_subnet_groups<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                DescribeSubnetGroupsRequest,
+            ) -> ::std::result::Result<
+                DescribeSubnetGroupsResponse,
+                RusotoError<DescribeSubnetGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_subnet_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `increase_replication_factor` with what `handler` returns for its input.
+    pub fn with_increase_replication_factor<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                IncreaseReplicationFactorRequest,
+            ) -> ::std::result::Result<
+                IncreaseReplicationFactorResponse,
+                RusotoError<IncreaseReplicationFactorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.increase_replication_factor = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags` with what `handler` returns for its input.
+    pub fn with_list_tags<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                ListTagsRequest,
+            ) -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reboot_node` with what `handler` returns for its input.
+    pub fn with_reboot_node<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                RebootNodeRequest,
+            )
+                -> ::std::result::Result<RebootNodeResponse, RusotoError<RebootNodeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reboot_node = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_cluster` with what `handler` returns for its input.
+    pub fn with_update_cluster<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                UpdateClusterRequest,
+            )
+                -> ::std::result::Result<UpdateClusterResponse, RusotoError<UpdateClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_parameter_group` with what `handler` returns for its input.
+    pub fn with_update_parameter_group<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                UpdateParameterGroupRequest,
+            ) -> ::std::result::Result<
+                UpdateParameterGroupResponse,
+                RusotoError<UpdateParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_subnet_group` with what `handler` returns for its input.
+    pub fn with_update_subnet_group<F>(mut self, handler: F) -> MockDynamodbAccelerator
+    where
+        F: Fn(
+                UpdateSubnetGroupRequest,
+            ) -> ::std::result::Result<
+                UpdateSubnetGroupResponse,
+                RusotoError<UpdateSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_subnet_group = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl DynamodbAccelerator for MockDynamodbAccelerator {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        match self.create_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for create_cluster"),
+        }
+    }
+    fn create_parameter_group(
+        &self,
+        input: CreateParameterGroupRequest,
+    ) -> RusotoFuture<CreateParameterGroupResponse, CreateParameterGroupError> {
+        match self.create_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for create_parameter_group"),
+        }
+    }
+    fn create_subnet_group(
+        &self,
+        input: CreateSubnetGroupRequest,
+    ) -> RusotoFuture<CreateSubnetGroupResponse, CreateSubnetGroupError> {
+        match self.create_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for create_subnet_group"),
+        }
+    }
+    fn decrease_replication_factor(
+        &self,
+        input: DecreaseReplicationFactorRequest,
+    ) -> RusotoFuture<DecreaseReplicationFactorResponse, DecreaseReplicationFactorError> {
+        match self.decrease_replication_factor {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDynamodbAccelerator has no handler for decrease_replication_factor")
+            }
+        }
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        match self.delete_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for delete_cluster"),
+        }
+    }
+    fn delete_parameter_group(
+        &self,
+        input: DeleteParameterGroupRequest,
+    ) -> RusotoFuture<DeleteParameterGroupResponse, DeleteParameterGroupError> {
+        match self.delete_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for delete_parameter_group"),
+        }
+    }
+    fn delete_subnet_group(
+        &self,
+        input: DeleteSubnetGroupRequest,
+    ) -> RusotoFuture<DeleteSubnetGroupResponse, DeleteSubnetGroupError> {
+        match self.delete_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for delete_subnet_group"),
+        }
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        match self.describe_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for describe_clusters"),
+        }
+    }
+    fn describe_default_parameters(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> RusotoFuture<DescribeDefaultParametersResponse, DescribeDefaultParametersError> {
+        match self.describe_default_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDynamodbAccelerator has no handler for describe_default_parameters")
+            }
+        }
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        match self.describe_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for describe_events"),
+        }
+    }
+    fn describe_parameter_groups(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> RusotoFuture<DescribeParameterGroupsResponse, DescribeParameterGroupsError> {
+        match self.describe_parameter_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for describe_parameter_groups"),
+        }
+    }
+    fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> RusotoFuture<DescribeParametersResponse, DescribeParametersError> {
+        match self.describe_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for describe_parameters"),
+        }
+    }
+    fn describe_subnet_groups(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> RusotoFuture<DescribeSubnetGroupsResponse, DescribeSubnetGroupsError> {
+        match self.describe_subnet_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for describe_subnet_groups"),
+        }
+    }
+    fn increase_replication_factor(
+        &self,
+        input: IncreaseReplicationFactorRequest,
+    ) -> RusotoFuture<IncreaseReplicationFactorResponse, IncreaseReplicationFactorError> {
+        match self.increase_replication_factor {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDynamodbAccelerator has no handler for increase_replication_factor")
+            }
+        }
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        match self.list_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for list_tags"),
+        }
+    }
+    fn reboot_node(
+        &self,
+        input: RebootNodeRequest,
+    ) -> RusotoFuture<RebootNodeResponse, RebootNodeError> {
+        match self.reboot_node {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for reboot_node"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for untag_resource"),
+        }
+    }
+    fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> RusotoFuture<UpdateClusterResponse, UpdateClusterError> {
+        match self.update_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for update_cluster"),
+        }
+    }
+    fn update_parameter_group(
+        &self,
+        input: UpdateParameterGroupRequest,
+    ) -> RusotoFuture<UpdateParameterGroupResponse, UpdateParameterGroupError> {
+        match self.update_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for update_parameter_group"),
+        }
+    }
+    fn update_subnet_group(
+        &self,
+        input: UpdateSubnetGroupRequest,
+    ) -> RusotoFuture<UpdateSubnetGroupResponse, UpdateSubnetGroupError> {
+        match self.update_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamodbAccelerator has no handler for update_subnet_group"),
+        }
+    }
+}
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>A container for account-level settings within AWS Device Farm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AccountSettings {
     /// <p>The AWS account number specified in the <code>AccountSettings</code> container.</p>
     #[serde(rename = "awsAccountNumber")]
@@ -63,7 +64,7 @@ pub struct AccountSettings {
 
 /// <p>Represents the output of a test. Examples of artifacts include logs and screenshots.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Artifact {
     /// <p>The artifact's ARN.</p>
     #[serde(rename = "arn")]
@@ -89,7 +90,7 @@ pub struct Artifact {
 
 /// <p>Represents the amount of CPU that an app is using on a physical device.</p> <p>Note that this does not represent system-wide CPU usage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CPU {
     /// <p>The CPU's architecture, for example x86 or ARM.</p>
     #[serde(rename = "architecture")]
@@ -107,7 +108,7 @@ pub struct CPU {
 
 /// <p>Represents entity counters.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Counters {
     /// <p>The number of errored entities.</p>
     #[serde(rename = "errored")]
@@ -141,6 +142,7 @@ pub struct Counters {
 
 /// <p>Represents a request to the create device pool operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDevicePoolRequest {
     /// <p>The device pool's description.</p>
     #[serde(rename = "description")]
@@ -161,9 +163,39 @@ pub struct CreateDevicePoolRequest {
     pub rules: Vec<Rule>,
 }
 
+impl Validate for CreateDevicePoolRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(16384),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "project_arn"),
+            self.project_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_arn"),
+            &self.project_arn,
+            "^arn:.+",
+        );
+    }
+}
+
 /// <p>Represents the result of a create device pool request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDevicePoolResult {
     /// <p>The newly created device pool.</p>
     #[serde(rename = "devicePool")]
@@ -172,6 +204,7 @@ pub struct CreateDevicePoolResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInstanceProfileRequest {
     /// <p>The description of your instance profile.</p>
     #[serde(rename = "description")]
@@ -194,8 +227,27 @@ pub struct CreateInstanceProfileRequest {
     pub reboot_after_use: Option<bool>,
 }
 
+impl Validate for CreateInstanceProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(16384),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateInstanceProfileResult {
     /// <p>An object containing information about your instance profile.</p>
     #[serde(rename = "instanceProfile")]
@@ -204,6 +256,7 @@ pub struct CreateInstanceProfileResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateNetworkProfileRequest {
     /// <p>The description of the network profile.</p>
     #[serde(rename = "description")]
@@ -253,8 +306,54 @@ pub struct CreateNetworkProfileRequest {
     pub uplink_loss_percent: Option<i64>,
 }
 
+impl Validate for CreateNetworkProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(16384),
+            );
+        }
+        if let Some(ref value) = self.downlink_loss_percent {
+            errors.check_range(
+                &field_path(path, "downlink_loss_percent"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "project_arn"),
+            self.project_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_arn"),
+            &self.project_arn,
+            "^arn:.+",
+        );
+        if let Some(ref value) = self.uplink_loss_percent {
+            errors.check_range(
+                &field_path(path, "uplink_loss_percent"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateNetworkProfileResult {
     /// <p>The network profile that is returned by the create network profile request.</p>
     #[serde(rename = "networkProfile")]
@@ -264,6 +363,7 @@ pub struct CreateNetworkProfileResult {
 
 /// <p>Represents a request to the create project operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProjectRequest {
     /// <p>Sets the execution timeout value (in minutes) for a project. All test runs in this project will use the specified execution timeout value unless overridden when scheduling a run.</p>
     #[serde(rename = "defaultJobTimeoutMinutes")]
@@ -274,9 +374,20 @@ pub struct CreateProjectRequest {
     pub name: String,
 }
 
+impl Validate for CreateProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 /// <p>Represents the result of a create project request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProjectResult {
     /// <p>The newly created project.</p>
     #[serde(rename = "project")]
@@ -286,6 +397,7 @@ pub struct CreateProjectResult {
 
 /// <p>Configuration settings for a remote access session, including billing method.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRemoteAccessSessionConfiguration {
     /// <p>The billing method for the remote access session.</p>
     #[serde(rename = "billingMethod")]
@@ -297,8 +409,21 @@ pub struct CreateRemoteAccessSessionConfiguration {
     pub vpce_configuration_arns: Option<Vec<String>>,
 }
 
+impl Validate for CreateRemoteAccessSessionConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.vpce_configuration_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "vpce_configuration_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(32), Some(1011));
+                errors.check_pattern(&item_path, &item, "^arn:.+");
+            }
+        }
+    }
+}
+
 /// <p>Creates and submits a request to start a remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRemoteAccessSessionRequest {
     /// <p>Unique identifier for the client. If you want access to multiple devices on the same client, you should pass the same <code>clientId</code> value in each call to <code>CreateRemoteAccessSession</code>. This is required only if <code>remoteDebugEnabled</code> is set to <code>true</code>.</p>
     #[serde(rename = "clientId")]
@@ -348,9 +473,89 @@ pub struct CreateRemoteAccessSessionRequest {
     pub ssh_public_key: Option<String>,
 }
 
+impl Validate for CreateRemoteAccessSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_id {
+            errors.check_length(
+                &field_path(path, "client_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.configuration {
+            value.validate_at(&field_path(path, "configuration"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "device_arn"),
+            self.device_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "device_arn"), &self.device_arn, "^arn:.+");
+        if let Some(ref value) = self.instance_arn {
+            errors.check_length(
+                &field_path(path, "instance_arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(&field_path(path, "instance_arn"), &value, "^arn:.+");
+        }
+        if let Some(ref value) = self.interaction_mode {
+            errors.check_length(
+                &field_path(path, "interaction_mode"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "project_arn"),
+            self.project_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_arn"),
+            &self.project_arn,
+            "^arn:.+",
+        );
+        if let Some(ref value) = self.remote_record_app_arn {
+            errors.check_length(
+                &field_path(path, "remote_record_app_arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(
+                &field_path(path, "remote_record_app_arn"),
+                &value,
+                "^arn:.+",
+            );
+        }
+        if let Some(ref value) = self.ssh_public_key {
+            errors.check_length(
+                &field_path(path, "ssh_public_key"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+        }
+    }
+}
+
 /// <p>Represents the server response from a request to create a remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRemoteAccessSessionResult {
     /// <p>A container that describes the remote access session when the request to create a remote access session is sent.</p>
     #[serde(rename = "remoteAccessSession")]
@@ -360,6 +565,7 @@ pub struct CreateRemoteAccessSessionResult {
 
 /// <p>Represents a request to the create upload operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUploadRequest {
     /// <p>The upload's content type (for example, "application/octet-stream").</p>
     #[serde(rename = "contentType")]
@@ -376,9 +582,39 @@ pub struct CreateUploadRequest {
     pub type_: String,
 }
 
+impl Validate for CreateUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.content_type {
+            errors.check_length(
+                &field_path(path, "content_type"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "project_arn"),
+            self.project_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_arn"),
+            &self.project_arn,
+            "^arn:.+",
+        );
+    }
+}
+
 /// <p>Represents the result of a create upload request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUploadResult {
     /// <p>The newly created upload.</p>
     #[serde(rename = "upload")]
@@ -387,6 +623,7 @@ pub struct CreateUploadResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVPCEConfigurationRequest {
     /// <p>The DNS name of the service running in your VPC that you want Device Farm to test.</p>
     #[serde(rename = "serviceDnsName")]
@@ -403,8 +640,39 @@ pub struct CreateVPCEConfigurationRequest {
     pub vpce_service_name: String,
 }
 
+impl Validate for CreateVPCEConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "service_dns_name"),
+            self.service_dns_name.chars().count(),
+            None,
+            Some(2048),
+        );
+        if let Some(ref value) = self.vpce_configuration_description {
+            errors.check_length(
+                &field_path(path, "vpce_configuration_description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "vpce_configuration_name"),
+            self.vpce_configuration_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "vpce_service_name"),
+            self.vpce_service_name.chars().count(),
+            None,
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateVPCEConfigurationResult {
     /// <p>An object containing information about your VPC endpoint configuration.</p>
     #[serde(rename = "vpceConfiguration")]
@@ -431,105 +699,209 @@ pub struct CustomerArtifactPaths {
 
 /// <p>Represents a request to the delete device pool operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDevicePoolRequest {
     /// <p>Represents the Amazon Resource Name (ARN) of the Device Farm device pool you wish to delete.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for DeleteDevicePoolRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a delete device pool request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDevicePoolResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInstanceProfileRequest {
     /// <p>The Amazon Resource Name (ARN) of the instance profile you are requesting to delete.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for DeleteInstanceProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteInstanceProfileResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteNetworkProfileRequest {
     /// <p>The Amazon Resource Name (ARN) of the network profile you want to delete.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for DeleteNetworkProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteNetworkProfileResult {}
 
 /// <p>Represents a request to the delete project operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProjectRequest {
     /// <p>Represents the Amazon Resource Name (ARN) of the Device Farm project you wish to delete.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for DeleteProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a delete project request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProjectResult {}
 
 /// <p>Represents the request to delete the specified remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRemoteAccessSessionRequest {
     /// <p>The Amazon Resource Name (ARN) of the session for which you want to delete remote access.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for DeleteRemoteAccessSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>The response from the server when a request is made to delete the remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRemoteAccessSessionResult {}
 
 /// <p>Represents a request to the delete run operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRunRequest {
     /// <p>The Amazon Resource Name (ARN) for the run you wish to delete.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for DeleteRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a delete run request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRunResult {}
 
 /// <p>Represents a request to the delete upload operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUploadRequest {
     /// <p>Represents the Amazon Resource Name (ARN) of the Device Farm upload you wish to delete.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for DeleteUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a delete upload request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUploadResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVPCEConfigurationRequest {
     /// <p>The Amazon Resource Name (ARN) of the VPC endpoint configuration you want to delete.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for DeleteVPCEConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteVPCEConfigurationResult {}
 
 /// <p>Represents a device type that an app is tested against.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Device {
     /// <p>The device's ARN.</p>
     #[serde(rename = "arn")]
@@ -636,7 +1008,7 @@ pub struct DeviceFilter {
 
 /// <p>Represents the device instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceInstance {
     /// <p>The Amazon Resource Name (ARN) of the device instance.</p>
     #[serde(rename = "arn")]
@@ -666,7 +1038,7 @@ pub struct DeviceInstance {
 
 /// <p>Represents the total (metered or unmetered) minutes used by the resource to run tests. Contains the sum of minutes consumed by all children.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceMinutes {
     /// <p>When specified, represents only the sum of metered minutes used by the resource to run tests.</p>
     #[serde(rename = "metered")]
@@ -684,7 +1056,7 @@ pub struct DeviceMinutes {
 
 /// <p>Represents a collection of device types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DevicePool {
     /// <p>The device pool's ARN.</p>
     #[serde(rename = "arn")]
@@ -714,7 +1086,7 @@ pub struct DevicePool {
 
 /// <p>Represents a device pool compatibility result.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DevicePoolCompatibilityResult {
     /// <p>Whether the result was compatible with the device pool.</p>
     #[serde(rename = "compatible")]
@@ -732,6 +1104,7 @@ pub struct DevicePoolCompatibilityResult {
 
 /// <p>Represents the device filters used in a test run as well as the maximum number of devices to be included in the run. It is passed in as the <code>deviceSelectionConfiguration</code> request parameter in <a>ScheduleRun</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeviceSelectionConfiguration {
     /// <p><p>Used to dynamically select a set of devices for a test run. A filter is made up of an attribute, an operator, and one or more values.</p> <ul> <li> <p> <b>Attribute</b> </p> <p>The aspect of a device such as platform or model used as the selection criteria in a device filter.</p> <p>Allowed values include:</p> <ul> <li> <p>ARN: The Amazon Resource Name (ARN) of the device. For example, &quot;arn:aws:devicefarm:us-west-2::device:12345Example&quot;.</p> </li> <li> <p>PLATFORM: The device platform. Valid values are &quot;ANDROID&quot; or &quot;IOS&quot;.</p> </li> <li> <p>OS<em>VERSION: The operating system version. For example, &quot;10.3.2&quot;.</p> </li> <li> <p>MODEL: The device model. For example, &quot;iPad 5th Gen&quot;.</p> </li> <li> <p>AVAILABILITY: The current availability of the device. Valid values are &quot;AVAILABLE&quot;, &quot;HIGHLY</em>AVAILABLE&quot;, &quot;BUSY&quot;, or &quot;TEMPORARY<em>NOT</em>AVAILABLE&quot;.</p> </li> <li> <p>FORM<em>FACTOR: The device form factor. Valid values are &quot;PHONE&quot; or &quot;TABLET&quot;.</p> </li> <li> <p>MANUFACTURER: The device manufacturer. For example, &quot;Apple&quot;.</p> </li> <li> <p>REMOTE</em>ACCESS<em>ENABLED: Whether the device is enabled for remote access. Valid values are &quot;TRUE&quot; or &quot;FALSE&quot;.</p> </li> <li> <p>REMOTE</em>DEBUG<em>ENABLED: Whether the device is enabled for remote debugging. Valid values are &quot;TRUE&quot; or &quot;FALSE&quot;.</p> </li> <li> <p>INSTANCE</em>ARN: The Amazon Resource Name (ARN) of the device instance.</p> </li> <li> <p>INSTANCE<em>LABELS: The label of the device instance.</p> </li> <li> <p>FLEET</em>TYPE: The fleet type. Valid values are &quot;PUBLIC&quot; or &quot;PRIVATE&quot;.</p> </li> </ul> </li> <li> <p> <b>Operator</b> </p> <p>The filter operator.</p> <ul> <li> <p>The EQUALS operator is available for every attribute except INSTANCE<em>LABELS.</p> </li> <li> <p>The CONTAINS operator is available for the INSTANCE</em>LABELS and MODEL attributes.</p> </li> <li> <p>The IN and NOT<em>IN operators are available for the ARN, OS</em>VERSION, MODEL, MANUFACTURER, and INSTANCE<em>ARN attributes.</p> </li> <li> <p>The LESS</em>THAN, GREATER<em>THAN, LESS</em>THAN<em>OR</em>EQUALS, and GREATER<em>THAN</em>OR<em>EQUALS operators are also available for the OS</em>VERSION attribute.</p> </li> </ul> </li> <li> <p> <b>Values</b> </p> <p>An array of one or more filter values.</p> <p class="title"> <b>Operator Values</b> </p> <ul> <li> <p>The IN and NOT<em>IN operators can take a values array that has more than one element.</p> </li> <li> <p>The other operators require an array with a single element.</p> </li> </ul> <p class="title"> <b>Attribute Values</b> </p> <ul> <li> <p>The PLATFORM attribute can be set to &quot;ANDROID&quot; or &quot;IOS&quot;.</p> </li> <li> <p>The AVAILABILITY attribute can be set to &quot;AVAILABLE&quot;, &quot;HIGHLY</em>AVAILABLE&quot;, &quot;BUSY&quot;, or &quot;TEMPORARY<em>NOT</em>AVAILABLE&quot;.</p> </li> <li> <p>The FORM<em>FACTOR attribute can be set to &quot;PHONE&quot; or &quot;TABLET&quot;.</p> </li> <li> <p>The FLEET</em>TYPE attribute can be set to &quot;PUBLIC&quot; or &quot;PRIVATE&quot;.</p> </li> </ul> </li> </ul></p>
     #[serde(rename = "filters")]
@@ -743,7 +1116,7 @@ pub struct DeviceSelectionConfiguration {
 
 /// <p>Contains the run results requested by the device selection configuration as well as how many devices were returned. For an example of the JSON response syntax, see <a>ScheduleRun</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceSelectionResult {
     /// <p>The filters in a device selection result.</p>
     #[serde(rename = "filters")]
@@ -761,6 +1134,7 @@ pub struct DeviceSelectionResult {
 
 /// <p>Represents configuration information about a test run, such as the execution timeout (in minutes).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecutionConfiguration {
     /// <p>True if account cleanup is enabled at the beginning of the test; otherwise, false.</p>
     #[serde(rename = "accountsCleanup")]
@@ -786,11 +1160,12 @@ pub struct ExecutionConfiguration {
 
 /// <p>Represents the request sent to retrieve the account settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccountSettingsRequest {}
 
 /// <p>Represents the account settings return values from the <code>GetAccountSettings</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAccountSettingsResult {
     /// <p>The account settings.</p>
     #[serde(rename = "accountSettings")]
@@ -799,14 +1174,27 @@ pub struct GetAccountSettingsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeviceInstanceRequest {
     /// <p>The Amazon Resource Name (ARN) of the instance you're requesting information about.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetDeviceInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeviceInstanceResult {
     /// <p>An object containing information about your device instance.</p>
     #[serde(rename = "deviceInstance")]
@@ -816,6 +1204,7 @@ pub struct GetDeviceInstanceResult {
 
 /// <p>Represents a request to the get device pool compatibility operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDevicePoolCompatibilityRequest {
     /// <p>The ARN of the app that is associated with the specified device pool.</p>
     #[serde(rename = "appArn")]
@@ -838,9 +1227,40 @@ pub struct GetDevicePoolCompatibilityRequest {
     pub test_type: Option<String>,
 }
 
+impl Validate for GetDevicePoolCompatibilityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.app_arn {
+            errors.check_length(
+                &field_path(path, "app_arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(&field_path(path, "app_arn"), &value, "^arn:.+");
+        }
+        if let Some(ref value) = self.configuration {
+            value.validate_at(&field_path(path, "configuration"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "device_pool_arn"),
+            self.device_pool_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_pool_arn"),
+            &self.device_pool_arn,
+            "^arn:.+",
+        );
+        if let Some(ref value) = self.test {
+            value.validate_at(&field_path(path, "test"), errors);
+        }
+    }
+}
+
 /// <p>Represents the result of describe device pool compatibility request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDevicePoolCompatibilityResult {
     /// <p>Information about compatible devices.</p>
     #[serde(rename = "compatibleDevices")]
@@ -854,15 +1274,28 @@ pub struct GetDevicePoolCompatibilityResult {
 
 /// <p>Represents a request to the get device pool operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDevicePoolRequest {
     /// <p>The device pool's ARN.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetDevicePoolRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a get device pool request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDevicePoolResult {
     /// <p>An object containing information about the requested device pool.</p>
     #[serde(rename = "devicePool")]
@@ -872,15 +1305,28 @@ pub struct GetDevicePoolResult {
 
 /// <p>Represents a request to the get device request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeviceRequest {
     /// <p>The device type's ARN.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a get device request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeviceResult {
     /// <p>An object containing information about the requested device.</p>
     #[serde(rename = "device")]
@@ -889,14 +1335,27 @@ pub struct GetDeviceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstanceProfileRequest {
     /// <p>The Amazon Resource Name (ARN) of your instance profile.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetInstanceProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstanceProfileResult {
     /// <p>An object containing information about your instance profile.</p>
     #[serde(rename = "instanceProfile")]
@@ -906,15 +1365,28 @@ pub struct GetInstanceProfileResult {
 
 /// <p>Represents a request to the get job operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobRequest {
     /// <p>The job's ARN.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a get job request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobResult {
     /// <p>An object containing information about the requested job.</p>
     #[serde(rename = "job")]
@@ -923,14 +1395,27 @@ pub struct GetJobResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetNetworkProfileRequest {
     /// <p>The Amazon Resource Name (ARN) of the network profile you want to return information about.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetNetworkProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetNetworkProfileResult {
     /// <p>The network profile.</p>
     #[serde(rename = "networkProfile")]
@@ -940,6 +1425,7 @@ pub struct GetNetworkProfileResult {
 
 /// <p>Represents the request to retrieve the offering status for the specified customer or account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOfferingStatusRequest {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -947,9 +1433,22 @@ pub struct GetOfferingStatusRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetOfferingStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Returns the status result for a device offering.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOfferingStatusResult {
     /// <p>When specified, gets the offering status for the current period.</p>
     #[serde(rename = "current")]
@@ -967,15 +1466,28 @@ pub struct GetOfferingStatusResult {
 
 /// <p>Represents a request to the get project operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetProjectRequest {
     /// <p>The project's ARN.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a get project request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetProjectResult {
     /// <p>The project you wish to get information about.</p>
     #[serde(rename = "project")]
@@ -985,15 +1497,28 @@ pub struct GetProjectResult {
 
 /// <p>Represents the request to get information about the specified remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRemoteAccessSessionRequest {
     /// <p>The Amazon Resource Name (ARN) of the remote access session about which you want to get session information.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetRemoteAccessSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the response from the server that lists detailed information about the remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRemoteAccessSessionResult {
     /// <p>A container that lists detailed information about the remote access session.</p>
     #[serde(rename = "remoteAccessSession")]
@@ -1003,15 +1528,28 @@ pub struct GetRemoteAccessSessionResult {
 
 /// <p>Represents a request to the get run operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRunRequest {
     /// <p>The run's ARN.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a get run request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRunResult {
     /// <p>The run you wish to get results from.</p>
     #[serde(rename = "run")]
@@ -1021,15 +1559,28 @@ pub struct GetRunResult {
 
 /// <p>Represents a request to the get suite operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSuiteRequest {
     /// <p>The suite's ARN.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetSuiteRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a get suite request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSuiteResult {
     /// <p>A collection of one or more tests.</p>
     #[serde(rename = "suite")]
@@ -1039,15 +1590,28 @@ pub struct GetSuiteResult {
 
 /// <p>Represents a request to the get test operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTestRequest {
     /// <p>The test's ARN.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetTestRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a get test request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTestResult {
     /// <p>A test condition that is evaluated.</p>
     #[serde(rename = "test")]
@@ -1057,15 +1621,28 @@ pub struct GetTestResult {
 
 /// <p>Represents a request to the get upload operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUploadRequest {
     /// <p>The upload's ARN.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the result of a get upload request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUploadResult {
     /// <p>An app or a set of one or more tests to upload or that have been uploaded.</p>
     #[serde(rename = "upload")]
@@ -1074,14 +1651,27 @@ pub struct GetUploadResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVPCEConfigurationRequest {
     /// <p>The Amazon Resource Name (ARN) of the VPC endpoint configuration you want to describe.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for GetVPCEConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetVPCEConfigurationResult {
     /// <p>An object containing information about your VPC endpoint configuration.</p>
     #[serde(rename = "vpceConfiguration")]
@@ -1091,7 +1681,7 @@ pub struct GetVPCEConfigurationResult {
 
 /// <p>Represents information about incompatibility.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IncompatibilityMessage {
     /// <p>A message about the incompatibility.</p>
     #[serde(rename = "message")]
@@ -1105,6 +1695,7 @@ pub struct IncompatibilityMessage {
 
 /// <p>Represents the request to install an Android application (in .apk format) or an iOS application (in .ipa format) as part of a remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstallToRemoteAccessSessionRequest {
     /// <p>The Amazon Resource Name (ARN) of the app about which you are requesting information.</p>
     #[serde(rename = "appArn")]
@@ -1114,9 +1705,32 @@ pub struct InstallToRemoteAccessSessionRequest {
     pub remote_access_session_arn: String,
 }
 
+impl Validate for InstallToRemoteAccessSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "app_arn"),
+            self.app_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "app_arn"), &self.app_arn, "^arn:.+");
+        errors.check_length(
+            &field_path(path, "remote_access_session_arn"),
+            self.remote_access_session_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(
+            &field_path(path, "remote_access_session_arn"),
+            &self.remote_access_session_arn,
+            "^arn:.+",
+        );
+    }
+}
+
 /// <p>Represents the response from the server after AWS Device Farm makes a request to install to a remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstallToRemoteAccessSessionResult {
     /// <p>An app to upload or that has been uploaded.</p>
     #[serde(rename = "appUpload")]
@@ -1126,7 +1740,7 @@ pub struct InstallToRemoteAccessSessionResult {
 
 /// <p>Represents the instance profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceProfile {
     /// <p>The Amazon Resource Name (ARN) of the instance profile.</p>
     #[serde(rename = "arn")]
@@ -1156,7 +1770,7 @@ pub struct InstanceProfile {
 
 /// <p>Represents a device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Job {
     /// <p>The job's ARN.</p>
     #[serde(rename = "arn")]
@@ -1222,6 +1836,7 @@ pub struct Job {
 
 /// <p>Represents a request to the list artifacts operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListArtifactsRequest {
     /// <p>The Run, Job, Suite, or Test ARN.</p>
     #[serde(rename = "arn")]
@@ -1235,9 +1850,29 @@ pub struct ListArtifactsRequest {
     pub type_: String,
 }
 
+impl Validate for ListArtifactsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list artifacts operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListArtifactsResult {
     /// <p>Information about the artifacts.</p>
     #[serde(rename = "artifacts")]
@@ -1250,6 +1885,7 @@ pub struct ListArtifactsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeviceInstancesRequest {
     /// <p>An integer specifying the maximum number of items you want to return in the API response.</p>
     #[serde(rename = "maxResults")]
@@ -1261,8 +1897,21 @@ pub struct ListDeviceInstancesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDeviceInstancesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeviceInstancesResult {
     /// <p>An object containing information about your device instances.</p>
     #[serde(rename = "deviceInstances")]
@@ -1276,6 +1925,7 @@ pub struct ListDeviceInstancesResult {
 
 /// <p>Represents the result of a list device pools request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDevicePoolsRequest {
     /// <p>The project ARN.</p>
     #[serde(rename = "arn")]
@@ -1290,9 +1940,29 @@ pub struct ListDevicePoolsRequest {
     pub type_: Option<String>,
 }
 
+impl Validate for ListDevicePoolsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list device pools request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDevicePoolsResult {
     /// <p>Information about the device pools.</p>
     #[serde(rename = "devicePools")]
@@ -1306,6 +1976,7 @@ pub struct ListDevicePoolsResult {
 
 /// <p>Represents the result of a list devices request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDevicesRequest {
     /// <p>The Amazon Resource Name (ARN) of the project.</p>
     #[serde(rename = "arn")]
@@ -1321,9 +1992,31 @@ pub struct ListDevicesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDevicesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.arn {
+            errors.check_length(
+                &field_path(path, "arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(&field_path(path, "arn"), &value, "^arn:.+");
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list devices operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDevicesResult {
     /// <p>Information about the devices.</p>
     #[serde(rename = "devices")]
@@ -1336,6 +2029,7 @@ pub struct ListDevicesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInstanceProfilesRequest {
     /// <p>An integer specifying the maximum number of items you want to return in the API response.</p>
     #[serde(rename = "maxResults")]
@@ -1347,8 +2041,21 @@ pub struct ListInstanceProfilesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListInstanceProfilesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListInstanceProfilesResult {
     /// <p>An object containing information about your instance profiles.</p>
     #[serde(rename = "instanceProfiles")]
@@ -1362,6 +2069,7 @@ pub struct ListInstanceProfilesResult {
 
 /// <p>Represents a request to the list jobs operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsRequest {
     /// <p>The run's Amazon Resource Name (ARN).</p>
     #[serde(rename = "arn")]
@@ -1372,9 +2080,29 @@ pub struct ListJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list jobs request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJobsResult {
     /// <p>Information about the jobs.</p>
     #[serde(rename = "jobs")]
@@ -1387,6 +2115,7 @@ pub struct ListJobsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListNetworkProfilesRequest {
     /// <p>The Amazon Resource Name (ARN) of the project for which you want to list network profiles.</p>
     #[serde(rename = "arn")]
@@ -1401,8 +2130,28 @@ pub struct ListNetworkProfilesRequest {
     pub type_: Option<String>,
 }
 
+impl Validate for ListNetworkProfilesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListNetworkProfilesResult {
     /// <p>A list of the available network profiles.</p>
     #[serde(rename = "networkProfiles")]
@@ -1415,6 +2164,7 @@ pub struct ListNetworkProfilesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOfferingPromotionsRequest {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1422,8 +2172,21 @@ pub struct ListOfferingPromotionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListOfferingPromotionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOfferingPromotionsResult {
     /// <p>An identifier to be used in the next call to this operation, to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1437,6 +2200,7 @@ pub struct ListOfferingPromotionsResult {
 
 /// <p>Represents the request to list the offering transaction history.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOfferingTransactionsRequest {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1444,9 +2208,22 @@ pub struct ListOfferingTransactionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListOfferingTransactionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Returns the transaction log of the specified offerings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOfferingTransactionsResult {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1460,6 +2237,7 @@ pub struct ListOfferingTransactionsResult {
 
 /// <p>Represents the request to list all offerings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOfferingsRequest {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1467,9 +2245,22 @@ pub struct ListOfferingsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListOfferingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the return values of the list of offerings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOfferingsResult {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1483,6 +2274,7 @@ pub struct ListOfferingsResult {
 
 /// <p>Represents a request to the list projects operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProjectsRequest {
     /// <p>Optional. If no Amazon Resource Name (ARN) is specified, then AWS Device Farm returns a list of all projects for the AWS account. You can also specify a project ARN.</p>
     #[serde(rename = "arn")]
@@ -1494,9 +2286,31 @@ pub struct ListProjectsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListProjectsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.arn {
+            errors.check_length(
+                &field_path(path, "arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(&field_path(path, "arn"), &value, "^arn:.+");
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list projects request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProjectsResult {
     /// <p>If the number of items that are returned is significantly large, this is an identifier that is also returned, which can be used in a subsequent call to this operation to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1510,6 +2324,7 @@ pub struct ListProjectsResult {
 
 /// <p>Represents the request to return information about the remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRemoteAccessSessionsRequest {
     /// <p>The Amazon Resource Name (ARN) of the remote access session about which you are requesting information.</p>
     #[serde(rename = "arn")]
@@ -1520,9 +2335,29 @@ pub struct ListRemoteAccessSessionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListRemoteAccessSessionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the response from the server after AWS Device Farm makes a request to return information about the remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRemoteAccessSessionsResult {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1536,6 +2371,7 @@ pub struct ListRemoteAccessSessionsResult {
 
 /// <p>Represents a request to the list runs operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRunsRequest {
     /// <p>The Amazon Resource Name (ARN) of the project for which you want to list runs.</p>
     #[serde(rename = "arn")]
@@ -1546,9 +2382,29 @@ pub struct ListRunsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListRunsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list runs request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRunsResult {
     /// <p>If the number of items that are returned is significantly large, this is an identifier that is also returned, which can be used in a subsequent call to this operation to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1562,6 +2418,7 @@ pub struct ListRunsResult {
 
 /// <p>Represents a request to the list samples operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSamplesRequest {
     /// <p>The Amazon Resource Name (ARN) of the job used to list samples.</p>
     #[serde(rename = "arn")]
@@ -1572,9 +2429,29 @@ pub struct ListSamplesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListSamplesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list samples request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSamplesResult {
     /// <p>If the number of items that are returned is significantly large, this is an identifier that is also returned, which can be used in a subsequent call to this operation to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1588,6 +2465,7 @@ pub struct ListSamplesResult {
 
 /// <p>Represents a request to the list suites operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSuitesRequest {
     /// <p>The job's Amazon Resource Name (ARN).</p>
     #[serde(rename = "arn")]
@@ -1598,9 +2476,29 @@ pub struct ListSuitesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListSuitesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list suites request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSuitesResult {
     /// <p>If the number of items that are returned is significantly large, this is an identifier that is also returned, which can be used in a subsequent call to this operation to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1613,14 +2511,31 @@ pub struct ListSuitesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource(s) for which to list tags. You can associate tags with the following Device Farm resources: <code>PROJECT</code>, <code>RUN</code>, <code>NETWORK_PROFILE</code>, <code>INSTANCE_PROFILE</code>, <code>DEVICE_INSTANCE</code>, <code>SESSION</code>, <code>DEVICE_POOL</code>, <code>DEVICE</code>, and <code>VPCE_CONFIGURATION</code>.</p>
     #[serde(rename = "ResourceARN")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:devicefarm:.+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags to add to the resource. A tag is an array of key-value pairs. Tag keys can have a maximum character length of 128 characters, and tag values can have a maximum length of 256 characters.</p>
     #[serde(rename = "Tags")]
@@ -1630,6 +2545,7 @@ pub struct ListTagsForResourceResponse {
 
 /// <p>Represents a request to the list tests operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTestsRequest {
     /// <p>The test suite's Amazon Resource Name (ARN).</p>
     #[serde(rename = "arn")]
@@ -1640,9 +2556,29 @@ pub struct ListTestsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListTestsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list tests request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTestsResult {
     /// <p>If the number of items that are returned is significantly large, this is an identifier that is also returned, which can be used in a subsequent call to this operation to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1656,6 +2592,7 @@ pub struct ListTestsResult {
 
 /// <p>Represents a request to the list unique problems operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUniqueProblemsRequest {
     /// <p>The unique problems' ARNs.</p>
     #[serde(rename = "arn")]
@@ -1666,9 +2603,29 @@ pub struct ListUniqueProblemsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListUniqueProblemsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list unique problems request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUniqueProblemsResult {
     /// <p>If the number of items that are returned is significantly large, this is an identifier that is also returned, which can be used in a subsequent call to this operation to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1682,6 +2639,7 @@ pub struct ListUniqueProblemsResult {
 
 /// <p>Represents a request to the list uploads operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUploadsRequest {
     /// <p>The Amazon Resource Name (ARN) of the project for which you want to list uploads.</p>
     #[serde(rename = "arn")]
@@ -1696,9 +2654,29 @@ pub struct ListUploadsRequest {
     pub type_: Option<String>,
 }
 
+impl Validate for ListUploadsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of a list uploads request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUploadsResult {
     /// <p>If the number of items that are returned is significantly large, this is an identifier that is also returned, which can be used in a subsequent call to this operation to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1711,6 +2689,7 @@ pub struct ListUploadsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVPCEConfigurationsRequest {
     /// <p>An integer specifying the maximum number of items you want to return in the API response.</p>
     #[serde(rename = "maxResults")]
@@ -1722,8 +2701,21 @@ pub struct ListVPCEConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListVPCEConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVPCEConfigurationsResult {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "nextToken")]
@@ -1748,7 +2740,7 @@ pub struct Location {
 
 /// <p>A number representing the monetary amount for an offering or transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MonetaryAmount {
     /// <p>The numerical amount of an offering or transaction.</p>
     #[serde(rename = "amount")]
@@ -1762,7 +2754,7 @@ pub struct MonetaryAmount {
 
 /// <p>An array of settings that describes characteristics of a network profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkProfile {
     /// <p>The Amazon Resource Name (ARN) of the network profile.</p>
     #[serde(rename = "arn")]
@@ -1816,7 +2808,7 @@ pub struct NetworkProfile {
 
 /// <p>Represents the metadata of a device offering.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Offering {
     /// <p>A string describing the offering.</p>
     #[serde(rename = "description")]
@@ -1842,7 +2834,7 @@ pub struct Offering {
 
 /// <p>Represents information about an offering promotion.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OfferingPromotion {
     /// <p>A string describing the offering promotion.</p>
     #[serde(rename = "description")]
@@ -1856,7 +2848,7 @@ pub struct OfferingPromotion {
 
 /// <p>The status of the offering.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OfferingStatus {
     /// <p>The date on which the offering is effective.</p>
     #[serde(rename = "effectiveOn")]
@@ -1878,7 +2870,7 @@ pub struct OfferingStatus {
 
 /// <p>Represents the metadata of an offering transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OfferingTransaction {
     /// <p>The cost of an offering transaction.</p>
     #[serde(rename = "cost")]
@@ -1904,7 +2896,7 @@ pub struct OfferingTransaction {
 
 /// <p>Represents a specific warning or failure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Problem {
     /// <p>Information about the associated device.</p>
     #[serde(rename = "device")]
@@ -1938,7 +2930,7 @@ pub struct Problem {
 
 /// <p>Information about a problem detail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProblemDetail {
     /// <p>The problem detail's ARN.</p>
     #[serde(rename = "arn")]
@@ -1952,7 +2944,7 @@ pub struct ProblemDetail {
 
 /// <p>Represents an operating-system neutral workspace for running and managing tests.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Project {
     /// <p>The project's ARN.</p>
     #[serde(rename = "arn")]
@@ -1974,6 +2966,7 @@ pub struct Project {
 
 /// <p>Represents a request for a purchase offering.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PurchaseOfferingRequest {
     /// <p>The ID of the offering.</p>
     #[serde(rename = "offeringId")]
@@ -1989,9 +2982,30 @@ pub struct PurchaseOfferingRequest {
     pub quantity: Option<i64>,
 }
 
+impl Validate for PurchaseOfferingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.offering_id {
+            errors.check_length(
+                &field_path(path, "offering_id"),
+                value.chars().count(),
+                Some(32),
+                None,
+            );
+        }
+        if let Some(ref value) = self.offering_promotion_id {
+            errors.check_length(
+                &field_path(path, "offering_promotion_id"),
+                value.chars().count(),
+                Some(4),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>The result of the purchase offering (e.g., success or failure).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PurchaseOfferingResult {
     /// <p>Represents the offering transaction for the purchase result.</p>
     #[serde(rename = "offeringTransaction")]
@@ -2022,7 +3036,7 @@ pub struct Radios {
 
 /// <p>Specifies whether charges for devices will be recurring.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RecurringCharge {
     /// <p>The cost of the recurring charge.</p>
     #[serde(rename = "cost")]
@@ -2036,7 +3050,7 @@ pub struct RecurringCharge {
 
 /// <p>Represents information about the remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoteAccessSession {
     /// <p>The Amazon Resource Name (ARN) of the remote access session.</p>
     #[serde(rename = "arn")]
@@ -2126,6 +3140,7 @@ pub struct RemoteAccessSession {
 
 /// <p>A request representing an offering renewal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RenewOfferingRequest {
     /// <p>The ID of a request to renew an offering.</p>
     #[serde(rename = "offeringId")]
@@ -2137,9 +3152,22 @@ pub struct RenewOfferingRequest {
     pub quantity: Option<i64>,
 }
 
+impl Validate for RenewOfferingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.offering_id {
+            errors.check_length(
+                &field_path(path, "offering_id"),
+                value.chars().count(),
+                Some(32),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>The result of a renewal offering.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RenewOfferingResult {
     /// <p>Represents the status of the offering transaction for the renewal.</p>
     #[serde(rename = "offeringTransaction")]
@@ -2149,7 +3177,7 @@ pub struct RenewOfferingResult {
 
 /// <p>Represents the screen resolution of a device in height and width, expressed in pixels.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Resolution {
     /// <p>The screen resolution's height, expressed in pixels.</p>
     #[serde(rename = "height")]
@@ -2180,7 +3208,7 @@ pub struct Rule {
 
 /// <p>Represents a test run on a set of devices with a given app package, test parameters, etc.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Run {
     /// <p>An app to upload or that has been uploaded.</p>
     #[serde(rename = "appUpload")]
@@ -2310,7 +3338,7 @@ pub struct Run {
 
 /// <p>Represents a sample of performance data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Sample {
     /// <p>The sample's ARN.</p>
     #[serde(rename = "arn")]
@@ -2328,6 +3356,7 @@ pub struct Sample {
 
 /// <p>Represents the settings for a run. Includes things like location, radio states, auxiliary apps, and network profiles.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScheduleRunConfiguration {
     /// <p>A list of auxiliary apps for the run.</p>
     #[serde(rename = "auxiliaryApps")]
@@ -2367,14 +3396,56 @@ pub struct ScheduleRunConfiguration {
     pub vpce_configuration_arns: Option<Vec<String>>,
 }
 
-/// <p>Represents a request to the schedule run operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ScheduleRunRequest {
-    /// <p>The ARN of the app to schedule a run.</p>
-    #[serde(rename = "appArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub app_arn: Option<String>,
-    /// <p>Information about the settings for the run to be scheduled.</p>
+impl Validate for ScheduleRunConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.auxiliary_apps {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "auxiliary_apps"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(32), Some(1011));
+                errors.check_pattern(&item_path, &item, "^arn:.+");
+            }
+        }
+        if let Some(ref value) = self.extra_data_package_arn {
+            errors.check_length(
+                &field_path(path, "extra_data_package_arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(
+                &field_path(path, "extra_data_package_arn"),
+                &value,
+                "^arn:.+",
+            );
+        }
+        if let Some(ref value) = self.network_profile_arn {
+            errors.check_length(
+                &field_path(path, "network_profile_arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(&field_path(path, "network_profile_arn"), &value, "^arn:.+");
+        }
+        if let Some(ref value) = self.vpce_configuration_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "vpce_configuration_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(32), Some(1011));
+                errors.check_pattern(&item_path, &item, "^arn:.+");
+            }
+        }
+    }
+}
+
+/// <p>Represents a request to the schedule run operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
+pub struct ScheduleRunRequest {
+    /// <p>The ARN of the app to schedule a run.</p>
+    #[serde(rename = "appArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_arn: Option<String>,
+    /// <p>Information about the settings for the run to be scheduled.</p>
     #[serde(rename = "configuration")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub configuration: Option<ScheduleRunConfiguration>,
@@ -2402,9 +3473,55 @@ pub struct ScheduleRunRequest {
     pub test: ScheduleRunTest,
 }
 
+impl Validate for ScheduleRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.app_arn {
+            errors.check_length(
+                &field_path(path, "app_arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(&field_path(path, "app_arn"), &value, "^arn:.+");
+        }
+        if let Some(ref value) = self.configuration {
+            value.validate_at(&field_path(path, "configuration"), errors);
+        }
+        if let Some(ref value) = self.device_pool_arn {
+            errors.check_length(
+                &field_path(path, "device_pool_arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(&field_path(path, "device_pool_arn"), &value, "^arn:.+");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "project_arn"),
+            self.project_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_arn"),
+            &self.project_arn,
+            "^arn:.+",
+        );
+        self.test.validate_at(&field_path(path, "test"), errors);
+    }
+}
+
 /// <p>Represents the result of a schedule run request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScheduleRunResult {
     /// <p>Information about the scheduled run.</p>
     #[serde(rename = "run")]
@@ -2414,6 +3531,7 @@ pub struct ScheduleRunResult {
 
 /// <p>Represents test settings. This data structure is passed in as the "test" parameter to ScheduleRun. For an example of the JSON request syntax, see <a>ScheduleRun</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScheduleRunTest {
     /// <p>The test's filter.</p>
     #[serde(rename = "filter")]
@@ -2436,15 +3554,59 @@ pub struct ScheduleRunTest {
     pub type_: String,
 }
 
+impl Validate for ScheduleRunTest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            errors.check_length(
+                &field_path(path, "filter"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+        }
+        if let Some(ref value) = self.test_package_arn {
+            errors.check_length(
+                &field_path(path, "test_package_arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(&field_path(path, "test_package_arn"), &value, "^arn:.+");
+        }
+        if let Some(ref value) = self.test_spec_arn {
+            errors.check_length(
+                &field_path(path, "test_spec_arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(&field_path(path, "test_spec_arn"), &value, "^arn:.+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopJobRequest {
     /// <p>Represents the Amazon Resource Name (ARN) of the Device Farm job you wish to stop.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for StopJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopJobResult {
     /// <p>The job that was stopped.</p>
     #[serde(rename = "job")]
@@ -2454,15 +3616,28 @@ pub struct StopJobResult {
 
 /// <p>Represents the request to stop the remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopRemoteAccessSessionRequest {
     /// <p>The Amazon Resource Name (ARN) of the remote access session you wish to stop.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for StopRemoteAccessSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the response from the server that describes the remote access session when AWS Device Farm stops the session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopRemoteAccessSessionResult {
     /// <p>A container representing the metadata from the service about the remote access session you are stopping.</p>
     #[serde(rename = "remoteAccessSession")]
@@ -2472,15 +3647,28 @@ pub struct StopRemoteAccessSessionResult {
 
 /// <p>Represents the request to stop a specific run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopRunRequest {
     /// <p>Represents the Amazon Resource Name (ARN) of the Device Farm run you wish to stop.</p>
     #[serde(rename = "arn")]
     pub arn: String,
 }
 
+impl Validate for StopRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+    }
+}
+
 /// <p>Represents the results of your stop run attempt.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopRunResult {
     /// <p>The run that was stopped.</p>
     #[serde(rename = "run")]
@@ -2490,7 +3678,7 @@ pub struct StopRunResult {
 
 /// <p>Represents a collection of one or more tests.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Suite {
     /// <p>The suite's ARN.</p>
     #[serde(rename = "arn")]
@@ -2549,7 +3737,25 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource(s) to which to add tags. You can associate tags with the following Device Farm resources: <code>PROJECT</code>, <code>RUN</code>, <code>NETWORK_PROFILE</code>, <code>INSTANCE_PROFILE</code>, <code>DEVICE_INSTANCE</code>, <code>SESSION</code>, <code>DEVICE_POOL</code>, <code>DEVICE</code>, and <code>VPCE_CONFIGURATION</code>.</p>
     #[serde(rename = "ResourceARN")]
@@ -2559,13 +3765,34 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:devicefarm:.+",
+        );
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), None, Some(150));
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>Represents a condition that is evaluated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Test {
     /// <p>The test's ARN.</p>
     #[serde(rename = "arn")]
@@ -2615,7 +3842,7 @@ pub struct Test {
 
 /// <p>Represents information about free trial device minutes for an AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrialMinutes {
     /// <p>The number of free trial minutes remaining in the account.</p>
     #[serde(rename = "remaining")]
@@ -2629,7 +3856,7 @@ pub struct TrialMinutes {
 
 /// <p>A collection of one or more problems, grouped by their result.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UniqueProblem {
     /// <p>A message about the unique problems' result.</p>
     #[serde(rename = "message")]
@@ -2642,6 +3869,7 @@ pub struct UniqueProblem {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource(s) from which to delete tags. You can associate tags with the following Device Farm resources: <code>PROJECT</code>, <code>RUN</code>, <code>NETWORK_PROFILE</code>, <code>INSTANCE_PROFILE</code>, <code>DEVICE_INSTANCE</code>, <code>SESSION</code>, <code>DEVICE_POOL</code>, <code>DEVICE</code>, and <code>VPCE_CONFIGURATION</code>.</p>
     #[serde(rename = "ResourceARN")]
@@ -2651,11 +3879,38 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:devicefarm:.+",
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            None,
+            Some(150),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDeviceInstanceRequest {
     /// <p>The Amazon Resource Name (ARN) of the device instance.</p>
     #[serde(rename = "arn")]
@@ -2670,8 +3925,29 @@ pub struct UpdateDeviceInstanceRequest {
     pub profile_arn: Option<String>,
 }
 
+impl Validate for UpdateDeviceInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.profile_arn {
+            errors.check_length(
+                &field_path(path, "profile_arn"),
+                value.chars().count(),
+                Some(32),
+                Some(1011),
+            );
+            errors.check_pattern(&field_path(path, "profile_arn"), &value, "^arn:.+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDeviceInstanceResult {
     /// <p>An object containing information about your device instance.</p>
     #[serde(rename = "deviceInstance")]
@@ -2681,6 +3957,7 @@ pub struct UpdateDeviceInstanceResult {
 
 /// <p>Represents a request to the update device pool operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDevicePoolRequest {
     /// <p>The Amazon Resource Name (ARN) of the Device Farm device pool you wish to update.</p>
     #[serde(rename = "arn")]
@@ -2707,9 +3984,37 @@ pub struct UpdateDevicePoolRequest {
     pub rules: Option<Vec<Rule>>,
 }
 
+impl Validate for UpdateDevicePoolRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(16384),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of an update device pool request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDevicePoolResult {
     /// <p>The device pool you just updated.</p>
     #[serde(rename = "devicePool")]
@@ -2718,6 +4023,7 @@ pub struct UpdateDevicePoolResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateInstanceProfileRequest {
     /// <p>The Amazon Resource Name (ARN) of the instance profile.</p>
     #[serde(rename = "arn")]
@@ -2744,8 +4050,36 @@ pub struct UpdateInstanceProfileRequest {
     pub reboot_after_use: Option<bool>,
 }
 
+impl Validate for UpdateInstanceProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(16384),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateInstanceProfileResult {
     /// <p>An object containing information about your instance profile.</p>
     #[serde(rename = "instanceProfile")]
@@ -2754,6 +4088,7 @@ pub struct UpdateInstanceProfileResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateNetworkProfileRequest {
     /// <p>The Amazon Resource Name (ARN) of the project for which you want to update network profile settings.</p>
     #[serde(rename = "arn")]
@@ -2804,8 +4139,52 @@ pub struct UpdateNetworkProfileRequest {
     pub uplink_loss_percent: Option<i64>,
 }
 
+impl Validate for UpdateNetworkProfileRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(16384),
+            );
+        }
+        if let Some(ref value) = self.downlink_loss_percent {
+            errors.check_range(
+                &field_path(path, "downlink_loss_percent"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.uplink_loss_percent {
+            errors.check_range(
+                &field_path(path, "uplink_loss_percent"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateNetworkProfileResult {
     /// <p>A list of the available network profiles.</p>
     #[serde(rename = "networkProfile")]
@@ -2815,6 +4194,7 @@ pub struct UpdateNetworkProfileResult {
 
 /// <p>Represents a request to the update project operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProjectRequest {
     /// <p>The Amazon Resource Name (ARN) of the project whose name you wish to update.</p>
     #[serde(rename = "arn")]
@@ -2829,9 +4209,29 @@ pub struct UpdateProjectRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>Represents the result of an update project request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateProjectResult {
     /// <p>The project you wish to update.</p>
     #[serde(rename = "project")]
@@ -2840,6 +4240,7 @@ pub struct UpdateProjectResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUploadRequest {
     /// <p>The Amazon Resource Name (ARN) of the uploaded test spec.</p>
     #[serde(rename = "arn")]
@@ -2858,8 +4259,36 @@ pub struct UpdateUploadRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.content_type {
+            errors.check_length(
+                &field_path(path, "content_type"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUploadResult {
     /// <p>A test spec uploaded to Device Farm.</p>
     #[serde(rename = "upload")]
@@ -2868,6 +4297,7 @@ pub struct UpdateUploadResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateVPCEConfigurationRequest {
     /// <p>The Amazon Resource Name (ARN) of the VPC endpoint configuration you want to update.</p>
     #[serde(rename = "arn")]
@@ -2890,8 +4320,52 @@ pub struct UpdateVPCEConfigurationRequest {
     pub vpce_service_name: Option<String>,
 }
 
+impl Validate for UpdateVPCEConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(32),
+            Some(1011),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "^arn:.+");
+        if let Some(ref value) = self.service_dns_name {
+            errors.check_length(
+                &field_path(path, "service_dns_name"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.vpce_configuration_description {
+            errors.check_length(
+                &field_path(path, "vpce_configuration_description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.vpce_configuration_name {
+            errors.check_length(
+                &field_path(path, "vpce_configuration_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.vpce_service_name {
+            errors.check_length(
+                &field_path(path, "vpce_service_name"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateVPCEConfigurationResult {
     /// <p>An object containing information about your VPC endpoint configuration.</p>
     #[serde(rename = "vpceConfiguration")]
@@ -2901,7 +4375,7 @@ pub struct UpdateVPCEConfigurationResult {
 
 /// <p>An app or a set of one or more tests to upload or that have been uploaded.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Upload {
     /// <p>The upload's ARN.</p>
     #[serde(rename = "arn")]
@@ -2947,7 +4421,7 @@ pub struct Upload {
 
 /// <p>Represents an Amazon Virtual Private Cloud (VPC) endpoint configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VPCEConfiguration {
     /// <p>The Amazon Resource Name (ARN) of the VPC endpoint configuration.</p>
     #[serde(rename = "arn")]
@@ -3000,7 +4474,12 @@ impl CreateDevicePoolError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(CreateDevicePoolError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3053,7 +4532,12 @@ impl CreateInstanceProfileError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3104,7 +4588,12 @@ impl CreateNetworkProfileError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(CreateNetworkProfileError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3160,7 +4649,12 @@ impl CreateProjectError {
                 "TagOperationException" => {
                     return RusotoError::Service(CreateProjectError::TagOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3216,7 +4710,12 @@ impl CreateRemoteAccessSessionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3267,7 +4766,12 @@ impl CreateUploadError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(CreateUploadError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3317,7 +4821,12 @@ impl CreateVPCEConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3367,7 +4876,12 @@ impl DeleteDevicePoolError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(DeleteDevicePoolError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3420,7 +4934,12 @@ impl DeleteInstanceProfileError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3471,7 +4990,12 @@ impl DeleteNetworkProfileError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(DeleteNetworkProfileError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3522,7 +5046,12 @@ impl DeleteProjectError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(DeleteProjectError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3577,7 +5106,12 @@ impl DeleteRemoteAccessSessionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3628,7 +5162,12 @@ impl DeleteRunError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(DeleteRunError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3679,7 +5218,12 @@ impl DeleteUploadError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(DeleteUploadError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3734,7 +5278,12 @@ impl DeleteVPCEConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3785,7 +5334,12 @@ impl GetAccountSettingsError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetAccountSettingsError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3836,7 +5390,12 @@ impl GetDeviceError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetDeviceError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3887,7 +5446,12 @@ impl GetDeviceInstanceError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetDeviceInstanceError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3938,7 +5502,12 @@ impl GetDevicePoolError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetDevicePoolError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3995,7 +5564,12 @@ impl GetDevicePoolCompatibilityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4046,7 +5620,12 @@ impl GetInstanceProfileError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetInstanceProfileError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4093,7 +5672,12 @@ impl GetJobError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetJobError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4144,7 +5728,12 @@ impl GetNetworkProfileError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetNetworkProfileError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4200,7 +5789,12 @@ impl GetOfferingStatusError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetOfferingStatusError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4252,7 +5846,12 @@ impl GetProjectError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetProjectError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4307,7 +5906,12 @@ impl GetRemoteAccessSessionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4354,7 +5958,12 @@ impl GetRunError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetRunError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4405,7 +6014,12 @@ impl GetSuiteError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetSuiteError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4456,7 +6070,12 @@ impl GetTestError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetTestError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4507,7 +6126,12 @@ impl GetUploadError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetUploadError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4553,7 +6177,12 @@ impl GetVPCEConfigurationError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(GetVPCEConfigurationError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4613,7 +6242,12 @@ impl InstallToRemoteAccessSessionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4664,7 +6298,12 @@ impl ListArtifactsError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListArtifactsError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4715,7 +6354,12 @@ impl ListDeviceInstancesError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListDeviceInstancesError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4766,7 +6410,12 @@ impl ListDevicePoolsError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListDevicePoolsError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4817,7 +6466,12 @@ impl ListDevicesError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListDevicesError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4868,7 +6522,12 @@ impl ListInstanceProfilesError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListInstanceProfilesError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4919,7 +6578,12 @@ impl ListJobsError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListJobsError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4970,7 +6634,12 @@ impl ListNetworkProfilesError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListNetworkProfilesError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5030,7 +6699,12 @@ impl ListOfferingPromotionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5093,7 +6767,12 @@ impl ListOfferingTransactionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5150,7 +6829,12 @@ impl ListOfferingsError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListOfferingsError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5202,7 +6886,12 @@ impl ListProjectsError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListProjectsError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5257,7 +6946,12 @@ impl ListRemoteAccessSessionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5308,7 +7002,12 @@ impl ListRunsError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListRunsError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5359,7 +7058,12 @@ impl ListSamplesError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListSamplesError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5410,7 +7114,12 @@ impl ListSuitesError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListSuitesError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5451,7 +7160,12 @@ impl ListTagsForResourceError {
                 "TagOperationException" => {
                     return RusotoError::Service(ListTagsForResourceError::TagOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5500,7 +7214,12 @@ impl ListTestsError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListTestsError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5551,7 +7270,12 @@ impl ListUniqueProblemsError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListUniqueProblemsError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5602,7 +7326,12 @@ impl ListUploadsError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ListUploadsError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5645,7 +7374,12 @@ impl ListVPCEConfigurationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5699,7 +7433,12 @@ impl PurchaseOfferingError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(PurchaseOfferingError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5756,7 +7495,12 @@ impl RenewOfferingError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(RenewOfferingError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5813,7 +7557,12 @@ impl ScheduleRunError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(ScheduleRunError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5865,7 +7614,12 @@ impl StopJobError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(StopJobError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5920,7 +7674,12 @@ impl StopRemoteAccessSessionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5971,7 +7730,12 @@ impl StopRunError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(StopRunError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6022,7 +7786,12 @@ impl TagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6063,7 +7832,12 @@ impl UntagResourceError {
                 "TagOperationException" => {
                     return RusotoError::Service(UntagResourceError::TagOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6112,7 +7886,12 @@ impl UpdateDeviceInstanceError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(UpdateDeviceInstanceError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6163,7 +7942,12 @@ impl UpdateDevicePoolError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(UpdateDevicePoolError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6216,7 +8000,12 @@ impl UpdateInstanceProfileError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6267,7 +8056,12 @@ impl UpdateNetworkProfileError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(UpdateNetworkProfileError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6318,7 +8112,12 @@ impl UpdateProjectError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(UpdateProjectError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6369,7 +8168,12 @@ impl UpdateUploadError {
                 "ServiceAccountException" => {
                     return RusotoError::Service(UpdateUploadError::ServiceAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6424,7 +8228,12 @@ impl UpdateVPCEConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6813,1919 +8622,5797 @@ pub trait DeviceFarm {
         input: UpdateVPCEConfigurationRequest,
     ) -> RusotoFuture<UpdateVPCEConfigurationResult, UpdateVPCEConfigurationError>;
 }
-/// A client for the AWS Device Farm API.
-#[derive(Clone)]
-pub struct DeviceFarmClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl DeviceFarmClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> DeviceFarmClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> DeviceFarmClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> DeviceFarmClient {
-        DeviceFarmClient { client, region }
-    }
-}
-
-impl DeviceFarm for DeviceFarmClient {
-    /// <p>Creates a device pool.</p>
+const _: Option<&dyn DeviceFarm> = None;
+impl<'a, T: DeviceFarm + ?Sized> DeviceFarm for &'a T {
     fn create_device_pool(
         &self,
         input: CreateDevicePoolRequest,
     ) -> RusotoFuture<CreateDevicePoolResult, CreateDevicePoolError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.CreateDevicePool");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDevicePoolResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDevicePoolError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_device_pool(input)
     }
-
-    /// <p>Creates a profile that can be applied to one or more private fleet device instances.</p>
     fn create_instance_profile(
         &self,
         input: CreateInstanceProfileRequest,
     ) -> RusotoFuture<CreateInstanceProfileResult, CreateInstanceProfileError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.CreateInstanceProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateInstanceProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateInstanceProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_instance_profile(input)
     }
-
-    /// <p>Creates a network profile.</p>
     fn create_network_profile(
         &self,
         input: CreateNetworkProfileRequest,
     ) -> RusotoFuture<CreateNetworkProfileResult, CreateNetworkProfileError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.CreateNetworkProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateNetworkProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateNetworkProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_network_profile(input)
     }
-
-    /// <p>Creates a new project.</p>
     fn create_project(
         &self,
         input: CreateProjectRequest,
     ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.CreateProject");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateProjectResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateProjectError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_project(input)
     }
-
-    /// <p>Specifies and starts a remote access session.</p>
     fn create_remote_access_session(
         &self,
         input: CreateRemoteAccessSessionRequest,
     ) -> RusotoFuture<CreateRemoteAccessSessionResult, CreateRemoteAccessSessionError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DeviceFarm_20150623.CreateRemoteAccessSession",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRemoteAccessSessionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateRemoteAccessSessionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_remote_access_session(input)
     }
-
-    /// <p>Uploads an app or test scripts.</p>
     fn create_upload(
         &self,
         input: CreateUploadRequest,
     ) -> RusotoFuture<CreateUploadResult, CreateUploadError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.CreateUpload");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUploadResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUploadError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_upload(input)
     }
-
-    /// <p>Creates a configuration record in Device Farm for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
     fn create_vpce_configuration(
         &self,
         input: CreateVPCEConfigurationRequest,
     ) -> RusotoFuture<CreateVPCEConfigurationResult, CreateVPCEConfigurationError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DeviceFarm_20150623.CreateVPCEConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateVPCEConfigurationResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateVPCEConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_vpce_configuration(input)
     }
-
-    /// <p>Deletes a device pool given the pool ARN. Does not allow deletion of curated pools owned by the system.</p>
     fn delete_device_pool(
         &self,
         input: DeleteDevicePoolRequest,
     ) -> RusotoFuture<DeleteDevicePoolResult, DeleteDevicePoolError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteDevicePool");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDevicePoolResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDevicePoolError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_device_pool(input)
     }
-
-    /// <p>Deletes a profile that can be applied to one or more private device instances.</p>
     fn delete_instance_profile(
         &self,
         input: DeleteInstanceProfileRequest,
     ) -> RusotoFuture<DeleteInstanceProfileResult, DeleteInstanceProfileError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteInstanceProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteInstanceProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteInstanceProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_instance_profile(input)
     }
-
-    /// <p>Deletes a network profile.</p>
     fn delete_network_profile(
         &self,
         input: DeleteNetworkProfileRequest,
     ) -> RusotoFuture<DeleteNetworkProfileResult, DeleteNetworkProfileError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteNetworkProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteNetworkProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteNetworkProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_network_profile(input)
     }
-
-    /// <p>Deletes an AWS Device Farm project, given the project ARN.</p> <p> <b>Note</b> Deleting this resource does not stop an in-progress run.</p>
     fn delete_project(
         &self,
         input: DeleteProjectRequest,
     ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteProject");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteProjectResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteProjectError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_project(input)
     }
-
-    /// <p>Deletes a completed remote access session and its results.</p>
     fn delete_remote_access_session(
         &self,
         input: DeleteRemoteAccessSessionRequest,
     ) -> RusotoFuture<DeleteRemoteAccessSessionResult, DeleteRemoteAccessSessionError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DeviceFarm_20150623.DeleteRemoteAccessSession",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRemoteAccessSessionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteRemoteAccessSessionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_remote_access_session(input)
     }
-
-    /// <p>Deletes the run, given the run ARN.</p> <p> <b>Note</b> Deleting this resource does not stop an in-progress run.</p>
     fn delete_run(&self, input: DeleteRunRequest) -> RusotoFuture<DeleteRunResult, DeleteRunError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DeleteRunResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteRunError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_run(input)
     }
-
-    /// <p>Deletes an upload given the upload ARN.</p>
     fn delete_upload(
         &self,
         input: DeleteUploadRequest,
     ) -> RusotoFuture<DeleteUploadResult, DeleteUploadError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteUpload");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteUploadResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUploadError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_upload(input)
     }
-
-    /// <p>Deletes a configuration for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
     fn delete_vpce_configuration(
         &self,
         input: DeleteVPCEConfigurationRequest,
     ) -> RusotoFuture<DeleteVPCEConfigurationResult, DeleteVPCEConfigurationError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DeviceFarm_20150623.DeleteVPCEConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteVPCEConfigurationResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteVPCEConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_vpce_configuration(input)
     }
-
-    /// <p>Returns the number of unmetered iOS and/or unmetered Android devices that have been purchased by the account.</p>
     fn get_account_settings(
         &self,
     ) -> RusotoFuture<GetAccountSettingsResult, GetAccountSettingsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetAccountSettings");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAccountSettingsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAccountSettingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_account_settings()
     }
-
-    /// <p>Gets information about a unique device type.</p>
     fn get_device(&self, input: GetDeviceRequest) -> RusotoFuture<GetDeviceResult, GetDeviceError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevice");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetDeviceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDeviceError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_device(input)
     }
-
-    /// <p>Returns information about a device instance belonging to a private device fleet.</p>
     fn get_device_instance(
         &self,
         input: GetDeviceInstanceRequest,
     ) -> RusotoFuture<GetDeviceInstanceResult, GetDeviceInstanceError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetDeviceInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeviceInstanceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDeviceInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_device_instance(input)
     }
-
-    /// <p>Gets information about a device pool.</p>
     fn get_device_pool(
         &self,
         input: GetDevicePoolRequest,
     ) -> RusotoFuture<GetDevicePoolResult, GetDevicePoolError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevicePool");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDevicePoolResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDevicePoolError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_device_pool(input)
     }
-
-    /// <p>Gets information about compatibility with a device pool.</p>
     fn get_device_pool_compatibility(
         &self,
         input: GetDevicePoolCompatibilityRequest,
     ) -> RusotoFuture<GetDevicePoolCompatibilityResult, GetDevicePoolCompatibilityError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DeviceFarm_20150623.GetDevicePoolCompatibility",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDevicePoolCompatibilityResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetDevicePoolCompatibilityError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_device_pool_compatibility(input)
     }
-
-    /// <p>Returns information about the specified instance profile.</p>
     fn get_instance_profile(
         &self,
         input: GetInstanceProfileRequest,
     ) -> RusotoFuture<GetInstanceProfileResult, GetInstanceProfileError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetInstanceProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstanceProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetInstanceProfileError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_instance_profile(input)
     }
-
-    /// <p>Gets information about a job.</p>
     fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetJobResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_job(input)
     }
-
-    /// <p>Returns information about a network profile.</p>
     fn get_network_profile(
         &self,
         input: GetNetworkProfileRequest,
     ) -> RusotoFuture<GetNetworkProfileResult, GetNetworkProfileError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetNetworkProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetNetworkProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetNetworkProfileError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_network_profile(input)
     }
-
-    /// <p>Gets the current status and future status of all offerings purchased by an AWS account. The response indicates how many offerings are currently available and the offerings that will be available in the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
     fn get_offering_status(
         &self,
         input: GetOfferingStatusRequest,
     ) -> RusotoFuture<GetOfferingStatusResult, GetOfferingStatusError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetOfferingStatus");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOfferingStatusResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetOfferingStatusError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_offering_status(input)
     }
-
-    /// <p>Gets information about a project.</p>
     fn get_project(
         &self,
         input: GetProjectRequest,
     ) -> RusotoFuture<GetProjectResult, GetProjectError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetProject");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetProjectResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetProjectError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_project(input)
     }
-
-    /// <p>Returns a link to a currently running remote access session.</p>
     fn get_remote_access_session(
         &self,
         input: GetRemoteAccessSessionRequest,
     ) -> RusotoFuture<GetRemoteAccessSessionResult, GetRemoteAccessSessionError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetRemoteAccessSession");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRemoteAccessSessionResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetRemoteAccessSessionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_remote_access_session(input)
     }
-
-    /// <p>Gets information about a run.</p>
     fn get_run(&self, input: GetRunRequest) -> RusotoFuture<GetRunResult, GetRunError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetRunResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRunError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_run(input)
     }
-
-    /// <p>Gets information about a suite.</p>
     fn get_suite(&self, input: GetSuiteRequest) -> RusotoFuture<GetSuiteResult, GetSuiteError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetSuite");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetSuiteResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSuiteError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_suite(input)
     }
-
-    /// <p>Gets information about a test.</p>
     fn get_test(&self, input: GetTestRequest) -> RusotoFuture<GetTestResult, GetTestError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetTest");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetTestResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTestError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_test(input)
     }
-
-    /// <p>Gets information about an upload.</p>
     fn get_upload(&self, input: GetUploadRequest) -> RusotoFuture<GetUploadResult, GetUploadError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetUpload");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetUploadResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUploadError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_upload(input)
     }
-
-    /// <p>Returns information about the configuration settings for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
     fn get_vpce_configuration(
         &self,
         input: GetVPCEConfigurationRequest,
     ) -> RusotoFuture<GetVPCEConfigurationResult, GetVPCEConfigurationError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.GetVPCEConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetVPCEConfigurationResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetVPCEConfigurationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_vpce_configuration(input)
     }
-
-    /// <p>Installs an application to the device in a remote access session. For Android applications, the file must be in .apk format. For iOS applications, the file must be in .ipa format.</p>
     fn install_to_remote_access_session(
         &self,
         input: InstallToRemoteAccessSessionRequest,
     ) -> RusotoFuture<InstallToRemoteAccessSessionResult, InstallToRemoteAccessSessionError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DeviceFarm_20150623.InstallToRemoteAccessSession",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InstallToRemoteAccessSessionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(InstallToRemoteAccessSessionError::from_response(response))
-                }))
-            }
-        })
+        (**self).install_to_remote_access_session(input)
     }
-
-    /// <p>Gets information about artifacts.</p>
     fn list_artifacts(
         &self,
         input: ListArtifactsRequest,
     ) -> RusotoFuture<ListArtifactsResult, ListArtifactsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListArtifacts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListArtifactsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListArtifactsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_artifacts(input)
     }
-
-    /// <p>Returns information about the private device instances associated with one or more AWS accounts.</p>
     fn list_device_instances(
         &self,
         input: ListDeviceInstancesRequest,
     ) -> RusotoFuture<ListDeviceInstancesResult, ListDeviceInstancesError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListDeviceInstances");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeviceInstancesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListDeviceInstancesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_device_instances(input)
+    }
+    fn list_device_pools(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> RusotoFuture<ListDevicePoolsResult, ListDevicePoolsError> {
+        (**self).list_device_pools(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResult, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> RusotoFuture<ListInstanceProfilesResult, ListInstanceProfilesError> {
+        (**self).list_instance_profiles(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_network_profiles(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> RusotoFuture<ListNetworkProfilesResult, ListNetworkProfilesError> {
+        (**self).list_network_profiles(input)
+    }
+    fn list_offering_promotions(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> RusotoFuture<ListOfferingPromotionsResult, ListOfferingPromotionsError> {
+        (**self).list_offering_promotions(input)
+    }
+    fn list_offering_transactions(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> RusotoFuture<ListOfferingTransactionsResult, ListOfferingTransactionsError> {
+        (**self).list_offering_transactions(input)
+    }
+    fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> RusotoFuture<ListOfferingsResult, ListOfferingsError> {
+        (**self).list_offerings(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_remote_access_sessions(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> RusotoFuture<ListRemoteAccessSessionsResult, ListRemoteAccessSessionsError> {
+        (**self).list_remote_access_sessions(input)
+    }
+    fn list_runs(&self, input: ListRunsRequest) -> RusotoFuture<ListRunsResult, ListRunsError> {
+        (**self).list_runs(input)
+    }
+    fn list_samples(
+        &self,
+        input: ListSamplesRequest,
+    ) -> RusotoFuture<ListSamplesResult, ListSamplesError> {
+        (**self).list_samples(input)
+    }
+    fn list_suites(
+        &self,
+        input: ListSuitesRequest,
+    ) -> RusotoFuture<ListSuitesResult, ListSuitesError> {
+        (**self).list_suites(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tests(&self, input: ListTestsRequest) -> RusotoFuture<ListTestsResult, ListTestsError> {
+        (**self).list_tests(input)
+    }
+    fn list_unique_problems(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> RusotoFuture<ListUniqueProblemsResult, ListUniqueProblemsError> {
+        (**self).list_unique_problems(input)
+    }
+    fn list_uploads(
+        &self,
+        input: ListUploadsRequest,
+    ) -> RusotoFuture<ListUploadsResult, ListUploadsError> {
+        (**self).list_uploads(input)
+    }
+    fn list_vpce_configurations(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> RusotoFuture<ListVPCEConfigurationsResult, ListVPCEConfigurationsError> {
+        (**self).list_vpce_configurations(input)
+    }
+    fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> RusotoFuture<PurchaseOfferingResult, PurchaseOfferingError> {
+        (**self).purchase_offering(input)
+    }
+    fn renew_offering(
+        &self,
+        input: RenewOfferingRequest,
+    ) -> RusotoFuture<RenewOfferingResult, RenewOfferingError> {
+        (**self).renew_offering(input)
+    }
+    fn schedule_run(
+        &self,
+        input: ScheduleRunRequest,
+    ) -> RusotoFuture<ScheduleRunResult, ScheduleRunError> {
+        (**self).schedule_run(input)
+    }
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        (**self).stop_job(input)
+    }
+    fn stop_remote_access_session(
+        &self,
+        input: StopRemoteAccessSessionRequest,
+    ) -> RusotoFuture<StopRemoteAccessSessionResult, StopRemoteAccessSessionError> {
+        (**self).stop_remote_access_session(input)
+    }
+    fn stop_run(&self, input: StopRunRequest) -> RusotoFuture<StopRunResult, StopRunError> {
+        (**self).stop_run(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_device_instance(
+        &self,
+        input: UpdateDeviceInstanceRequest,
+    ) -> RusotoFuture<UpdateDeviceInstanceResult, UpdateDeviceInstanceError> {
+        (**self).update_device_instance(input)
+    }
+    fn update_device_pool(
+        &self,
+        input: UpdateDevicePoolRequest,
+    ) -> RusotoFuture<UpdateDevicePoolResult, UpdateDevicePoolError> {
+        (**self).update_device_pool(input)
+    }
+    fn update_instance_profile(
+        &self,
+        input: UpdateInstanceProfileRequest,
+    ) -> RusotoFuture<UpdateInstanceProfileResult, UpdateInstanceProfileError> {
+        (**self).update_instance_profile(input)
+    }
+    fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> RusotoFuture<UpdateNetworkProfileResult, UpdateNetworkProfileError> {
+        (**self).update_network_profile(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_upload(
+        &self,
+        input: UpdateUploadRequest,
+    ) -> RusotoFuture<UpdateUploadResult, UpdateUploadError> {
+        (**self).update_upload(input)
+    }
+    fn update_vpce_configuration(
+        &self,
+        input: UpdateVPCEConfigurationRequest,
+    ) -> RusotoFuture<UpdateVPCEConfigurationResult, UpdateVPCEConfigurationError> {
+        (**self).update_vpce_configuration(input)
+    }
+}
+impl<T: DeviceFarm + ?Sized> DeviceFarm for Box<T> {
+    fn create_device_pool(
+        &self,
+        input: CreateDevicePoolRequest,
+    ) -> RusotoFuture<CreateDevicePoolResult, CreateDevicePoolError> {
+        (**self).create_device_pool(input)
+    }
+    fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> RusotoFuture<CreateInstanceProfileResult, CreateInstanceProfileError> {
+        (**self).create_instance_profile(input)
+    }
+    fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> RusotoFuture<CreateNetworkProfileResult, CreateNetworkProfileError> {
+        (**self).create_network_profile(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn create_remote_access_session(
+        &self,
+        input: CreateRemoteAccessSessionRequest,
+    ) -> RusotoFuture<CreateRemoteAccessSessionResult, CreateRemoteAccessSessionError> {
+        (**self).create_remote_access_session(input)
+    }
+    fn create_upload(
+        &self,
+        input: CreateUploadRequest,
+    ) -> RusotoFuture<CreateUploadResult, CreateUploadError> {
+        (**self).create_upload(input)
+    }
+    fn create_vpce_configuration(
+        &self,
+        input: CreateVPCEConfigurationRequest,
+    ) -> RusotoFuture<CreateVPCEConfigurationResult, CreateVPCEConfigurationError> {
+        (**self).create_vpce_configuration(input)
+    }
+    fn delete_device_pool(
+        &self,
+        input: DeleteDevicePoolRequest,
+    ) -> RusotoFuture<DeleteDevicePoolResult, DeleteDevicePoolError> {
+        (**self).delete_device_pool(input)
+    }
+    fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> RusotoFuture<DeleteInstanceProfileResult, DeleteInstanceProfileError> {
+        (**self).delete_instance_profile(input)
+    }
+    fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> RusotoFuture<DeleteNetworkProfileResult, DeleteNetworkProfileError> {
+        (**self).delete_network_profile(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn delete_remote_access_session(
+        &self,
+        input: DeleteRemoteAccessSessionRequest,
+    ) -> RusotoFuture<DeleteRemoteAccessSessionResult, DeleteRemoteAccessSessionError> {
+        (**self).delete_remote_access_session(input)
+    }
+    fn delete_run(&self, input: DeleteRunRequest) -> RusotoFuture<DeleteRunResult, DeleteRunError> {
+        (**self).delete_run(input)
+    }
+    fn delete_upload(
+        &self,
+        input: DeleteUploadRequest,
+    ) -> RusotoFuture<DeleteUploadResult, DeleteUploadError> {
+        (**self).delete_upload(input)
+    }
+    fn delete_vpce_configuration(
+        &self,
+        input: DeleteVPCEConfigurationRequest,
+    ) -> RusotoFuture<DeleteVPCEConfigurationResult, DeleteVPCEConfigurationError> {
+        (**self).delete_vpce_configuration(input)
+    }
+    fn get_account_settings(
+        &self,
+    ) -> RusotoFuture<GetAccountSettingsResult, GetAccountSettingsError> {
+        (**self).get_account_settings()
+    }
+    fn get_device(&self, input: GetDeviceRequest) -> RusotoFuture<GetDeviceResult, GetDeviceError> {
+        (**self).get_device(input)
+    }
+    fn get_device_instance(
+        &self,
+        input: GetDeviceInstanceRequest,
+    ) -> RusotoFuture<GetDeviceInstanceResult, GetDeviceInstanceError> {
+        (**self).get_device_instance(input)
+    }
+    fn get_device_pool(
+        &self,
+        input: GetDevicePoolRequest,
+    ) -> RusotoFuture<GetDevicePoolResult, GetDevicePoolError> {
+        (**self).get_device_pool(input)
+    }
+    fn get_device_pool_compatibility(
+        &self,
+        input: GetDevicePoolCompatibilityRequest,
+    ) -> RusotoFuture<GetDevicePoolCompatibilityResult, GetDevicePoolCompatibilityError> {
+        (**self).get_device_pool_compatibility(input)
+    }
+    fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> RusotoFuture<GetInstanceProfileResult, GetInstanceProfileError> {
+        (**self).get_instance_profile(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> RusotoFuture<GetNetworkProfileResult, GetNetworkProfileError> {
+        (**self).get_network_profile(input)
+    }
+    fn get_offering_status(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> RusotoFuture<GetOfferingStatusResult, GetOfferingStatusError> {
+        (**self).get_offering_status(input)
+    }
+    fn get_project(
+        &self,
+        input: GetProjectRequest,
+    ) -> RusotoFuture<GetProjectResult, GetProjectError> {
+        (**self).get_project(input)
+    }
+    fn get_remote_access_session(
+        &self,
+        input: GetRemoteAccessSessionRequest,
+    ) -> RusotoFuture<GetRemoteAccessSessionResult, GetRemoteAccessSessionError> {
+        (**self).get_remote_access_session(input)
+    }
+    fn get_run(&self, input: GetRunRequest) -> RusotoFuture<GetRunResult, GetRunError> {
+        (**self).get_run(input)
+    }
+    fn get_suite(&self, input: GetSuiteRequest) -> RusotoFuture<GetSuiteResult, GetSuiteError> {
+        (**self).get_suite(input)
+    }
+    fn get_test(&self, input: GetTestRequest) -> RusotoFuture<GetTestResult, GetTestError> {
+        (**self).get_test(input)
+    }
+    fn get_upload(&self, input: GetUploadRequest) -> RusotoFuture<GetUploadResult, GetUploadError> {
+        (**self).get_upload(input)
+    }
+    fn get_vpce_configuration(
+        &self,
+        input: GetVPCEConfigurationRequest,
+    ) -> RusotoFuture<GetVPCEConfigurationResult, GetVPCEConfigurationError> {
+        (**self).get_vpce_configuration(input)
+    }
+    fn install_to_remote_access_session(
+        &self,
+        input: InstallToRemoteAccessSessionRequest,
+    ) -> RusotoFuture<InstallToRemoteAccessSessionResult, InstallToRemoteAccessSessionError> {
+        (**self).install_to_remote_access_session(input)
+    }
+    fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> RusotoFuture<ListArtifactsResult, ListArtifactsError> {
+        (**self).list_artifacts(input)
+    }
+    fn list_device_instances(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> RusotoFuture<ListDeviceInstancesResult, ListDeviceInstancesError> {
+        (**self).list_device_instances(input)
+    }
+    fn list_device_pools(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> RusotoFuture<ListDevicePoolsResult, ListDevicePoolsError> {
+        (**self).list_device_pools(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResult, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> RusotoFuture<ListInstanceProfilesResult, ListInstanceProfilesError> {
+        (**self).list_instance_profiles(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_network_profiles(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> RusotoFuture<ListNetworkProfilesResult, ListNetworkProfilesError> {
+        (**self).list_network_profiles(input)
+    }
+    fn list_offering_promotions(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> RusotoFuture<ListOfferingPromotionsResult, ListOfferingPromotionsError> {
+        (**self).list_offering_promotions(input)
+    }
+    fn list_offering_transactions(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> RusotoFuture<ListOfferingTransactionsResult, ListOfferingTransactionsError> {
+        (**self).list_offering_transactions(input)
+    }
+    fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> RusotoFuture<ListOfferingsResult, ListOfferingsError> {
+        (**self).list_offerings(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_remote_access_sessions(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> RusotoFuture<ListRemoteAccessSessionsResult, ListRemoteAccessSessionsError> {
+        (**self).list_remote_access_sessions(input)
+    }
+    fn list_runs(&self, input: ListRunsRequest) -> RusotoFuture<ListRunsResult, ListRunsError> {
+        (**self).list_runs(input)
+    }
+    fn list_samples(
+        &self,
+        input: ListSamplesRequest,
+    ) -> RusotoFuture<ListSamplesResult, ListSamplesError> {
+        (**self).list_samples(input)
+    }
+    fn list_suites(
+        &self,
+        input: ListSuitesRequest,
+    ) -> RusotoFuture<ListSuitesResult, ListSuitesError> {
+        (**self).list_suites(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tests(&self, input: ListTestsRequest) -> RusotoFuture<ListTestsResult, ListTestsError> {
+        (**self).list_tests(input)
+    }
+    fn list_unique_problems(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> RusotoFuture<ListUniqueProblemsResult, ListUniqueProblemsError> {
+        (**self).list_unique_problems(input)
+    }
+    fn list_uploads(
+        &self,
+        input: ListUploadsRequest,
+    ) -> RusotoFuture<ListUploadsResult, ListUploadsError> {
+        (**self).list_uploads(input)
+    }
+    fn list_vpce_configurations(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> RusotoFuture<ListVPCEConfigurationsResult, ListVPCEConfigurationsError> {
+        (**self).list_vpce_configurations(input)
+    }
+    fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> RusotoFuture<PurchaseOfferingResult, PurchaseOfferingError> {
+        (**self).purchase_offering(input)
+    }
+    fn renew_offering(
+        &self,
+        input: RenewOfferingRequest,
+    ) -> RusotoFuture<RenewOfferingResult, RenewOfferingError> {
+        (**self).renew_offering(input)
+    }
+    fn schedule_run(
+        &self,
+        input: ScheduleRunRequest,
+    ) -> RusotoFuture<ScheduleRunResult, ScheduleRunError> {
+        (**self).schedule_run(input)
+    }
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        (**self).stop_job(input)
+    }
+    fn stop_remote_access_session(
+        &self,
+        input: StopRemoteAccessSessionRequest,
+    ) -> RusotoFuture<StopRemoteAccessSessionResult, StopRemoteAccessSessionError> {
+        (**self).stop_remote_access_session(input)
+    }
+    fn stop_run(&self, input: StopRunRequest) -> RusotoFuture<StopRunResult, StopRunError> {
+        (**self).stop_run(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_device_instance(
+        &self,
+        input: UpdateDeviceInstanceRequest,
+    ) -> RusotoFuture<UpdateDeviceInstanceResult, UpdateDeviceInstanceError> {
+        (**self).update_device_instance(input)
+    }
+    fn update_device_pool(
+        &self,
+        input: UpdateDevicePoolRequest,
+    ) -> RusotoFuture<UpdateDevicePoolResult, UpdateDevicePoolError> {
+        (**self).update_device_pool(input)
+    }
+    fn update_instance_profile(
+        &self,
+        input: UpdateInstanceProfileRequest,
+    ) -> RusotoFuture<UpdateInstanceProfileResult, UpdateInstanceProfileError> {
+        (**self).update_instance_profile(input)
+    }
+    fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> RusotoFuture<UpdateNetworkProfileResult, UpdateNetworkProfileError> {
+        (**self).update_network_profile(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_upload(
+        &self,
+        input: UpdateUploadRequest,
+    ) -> RusotoFuture<UpdateUploadResult, UpdateUploadError> {
+        (**self).update_upload(input)
+    }
+    fn update_vpce_configuration(
+        &self,
+        input: UpdateVPCEConfigurationRequest,
+    ) -> RusotoFuture<UpdateVPCEConfigurationResult, UpdateVPCEConfigurationError> {
+        (**self).update_vpce_configuration(input)
+    }
+}
+impl<T: DeviceFarm + ?Sized> DeviceFarm for ::std::sync::Arc<T> {
+    fn create_device_pool(
+        &self,
+        input: CreateDevicePoolRequest,
+    ) -> RusotoFuture<CreateDevicePoolResult, CreateDevicePoolError> {
+        (**self).create_device_pool(input)
+    }
+    fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> RusotoFuture<CreateInstanceProfileResult, CreateInstanceProfileError> {
+        (**self).create_instance_profile(input)
+    }
+    fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> RusotoFuture<CreateNetworkProfileResult, CreateNetworkProfileError> {
+        (**self).create_network_profile(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn create_remote_access_session(
+        &self,
+        input: CreateRemoteAccessSessionRequest,
+    ) -> RusotoFuture<CreateRemoteAccessSessionResult, CreateRemoteAccessSessionError> {
+        (**self).create_remote_access_session(input)
+    }
+    fn create_upload(
+        &self,
+        input: CreateUploadRequest,
+    ) -> RusotoFuture<CreateUploadResult, CreateUploadError> {
+        (**self).create_upload(input)
+    }
+    fn create_vpce_configuration(
+        &self,
+        input: CreateVPCEConfigurationRequest,
+    ) -> RusotoFuture<CreateVPCEConfigurationResult, CreateVPCEConfigurationError> {
+        (**self).create_vpce_configuration(input)
+    }
+    fn delete_device_pool(
+        &self,
+        input: DeleteDevicePoolRequest,
+    ) -> RusotoFuture<DeleteDevicePoolResult, DeleteDevicePoolError> {
+        (**self).delete_device_pool(input)
+    }
+    fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> RusotoFuture<DeleteInstanceProfileResult, DeleteInstanceProfileError> {
+        (**self).delete_instance_profile(input)
+    }
+    fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> RusotoFuture<DeleteNetworkProfileResult, DeleteNetworkProfileError> {
+        (**self).delete_network_profile(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn delete_remote_access_session(
+        &self,
+        input: DeleteRemoteAccessSessionRequest,
+    ) -> RusotoFuture<DeleteRemoteAccessSessionResult, DeleteRemoteAccessSessionError> {
+        (**self).delete_remote_access_session(input)
+    }
+    fn delete_run(&self, input: DeleteRunRequest) -> RusotoFuture<DeleteRunResult, DeleteRunError> {
+        (**self).delete_run(input)
+    }
+    fn delete_upload(
+        &self,
+        input: DeleteUploadRequest,
+    ) -> RusotoFuture<DeleteUploadResult, DeleteUploadError> {
+        (**self).delete_upload(input)
+    }
+    fn delete_vpce_configuration(
+        &self,
+        input: DeleteVPCEConfigurationRequest,
+    ) -> RusotoFuture<DeleteVPCEConfigurationResult, DeleteVPCEConfigurationError> {
+        (**self).delete_vpce_configuration(input)
+    }
+    fn get_account_settings(
+        &self,
+    ) -> RusotoFuture<GetAccountSettingsResult, GetAccountSettingsError> {
+        (**self).get_account_settings()
+    }
+    fn get_device(&self, input: GetDeviceRequest) -> RusotoFuture<GetDeviceResult, GetDeviceError> {
+        (**self).get_device(input)
+    }
+    fn get_device_instance(
+        &self,
+        input: GetDeviceInstanceRequest,
+    ) -> RusotoFuture<GetDeviceInstanceResult, GetDeviceInstanceError> {
+        (**self).get_device_instance(input)
+    }
+    fn get_device_pool(
+        &self,
+        input: GetDevicePoolRequest,
+    ) -> RusotoFuture<GetDevicePoolResult, GetDevicePoolError> {
+        (**self).get_device_pool(input)
+    }
+    fn get_device_pool_compatibility(
+        &self,
+        input: GetDevicePoolCompatibilityRequest,
+    ) -> RusotoFuture<GetDevicePoolCompatibilityResult, GetDevicePoolCompatibilityError> {
+        (**self).get_device_pool_compatibility(input)
+    }
+    fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> RusotoFuture<GetInstanceProfileResult, GetInstanceProfileError> {
+        (**self).get_instance_profile(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> RusotoFuture<GetNetworkProfileResult, GetNetworkProfileError> {
+        (**self).get_network_profile(input)
+    }
+    fn get_offering_status(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> RusotoFuture<GetOfferingStatusResult, GetOfferingStatusError> {
+        (**self).get_offering_status(input)
+    }
+    fn get_project(
+        &self,
+        input: GetProjectRequest,
+    ) -> RusotoFuture<GetProjectResult, GetProjectError> {
+        (**self).get_project(input)
+    }
+    fn get_remote_access_session(
+        &self,
+        input: GetRemoteAccessSessionRequest,
+    ) -> RusotoFuture<GetRemoteAccessSessionResult, GetRemoteAccessSessionError> {
+        (**self).get_remote_access_session(input)
+    }
+    fn get_run(&self, input: GetRunRequest) -> RusotoFuture<GetRunResult, GetRunError> {
+        (**self).get_run(input)
+    }
+    fn get_suite(&self, input: GetSuiteRequest) -> RusotoFuture<GetSuiteResult, GetSuiteError> {
+        (**self).get_suite(input)
+    }
+    fn get_test(&self, input: GetTestRequest) -> RusotoFuture<GetTestResult, GetTestError> {
+        (**self).get_test(input)
+    }
+    fn get_upload(&self, input: GetUploadRequest) -> RusotoFuture<GetUploadResult, GetUploadError> {
+        (**self).get_upload(input)
+    }
+    fn get_vpce_configuration(
+        &self,
+        input: GetVPCEConfigurationRequest,
+    ) -> RusotoFuture<GetVPCEConfigurationResult, GetVPCEConfigurationError> {
+        (**self).get_vpce_configuration(input)
+    }
+    fn install_to_remote_access_session(
+        &self,
+        input: InstallToRemoteAccessSessionRequest,
+    ) -> RusotoFuture<InstallToRemoteAccessSessionResult, InstallToRemoteAccessSessionError> {
+        (**self).install_to_remote_access_session(input)
+    }
+    fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> RusotoFuture<ListArtifactsResult, ListArtifactsError> {
+        (**self).list_artifacts(input)
+    }
+    fn list_device_instances(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> RusotoFuture<ListDeviceInstancesResult, ListDeviceInstancesError> {
+        (**self).list_device_instances(input)
+    }
+    fn list_device_pools(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> RusotoFuture<ListDevicePoolsResult, ListDevicePoolsError> {
+        (**self).list_device_pools(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResult, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> RusotoFuture<ListInstanceProfilesResult, ListInstanceProfilesError> {
+        (**self).list_instance_profiles(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_network_profiles(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> RusotoFuture<ListNetworkProfilesResult, ListNetworkProfilesError> {
+        (**self).list_network_profiles(input)
+    }
+    fn list_offering_promotions(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> RusotoFuture<ListOfferingPromotionsResult, ListOfferingPromotionsError> {
+        (**self).list_offering_promotions(input)
+    }
+    fn list_offering_transactions(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> RusotoFuture<ListOfferingTransactionsResult, ListOfferingTransactionsError> {
+        (**self).list_offering_transactions(input)
+    }
+    fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> RusotoFuture<ListOfferingsResult, ListOfferingsError> {
+        (**self).list_offerings(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_remote_access_sessions(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> RusotoFuture<ListRemoteAccessSessionsResult, ListRemoteAccessSessionsError> {
+        (**self).list_remote_access_sessions(input)
+    }
+    fn list_runs(&self, input: ListRunsRequest) -> RusotoFuture<ListRunsResult, ListRunsError> {
+        (**self).list_runs(input)
+    }
+    fn list_samples(
+        &self,
+        input: ListSamplesRequest,
+    ) -> RusotoFuture<ListSamplesResult, ListSamplesError> {
+        (**self).list_samples(input)
+    }
+    fn list_suites(
+        &self,
+        input: ListSuitesRequest,
+    ) -> RusotoFuture<ListSuitesResult, ListSuitesError> {
+        (**self).list_suites(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tests(&self, input: ListTestsRequest) -> RusotoFuture<ListTestsResult, ListTestsError> {
+        (**self).list_tests(input)
+    }
+    fn list_unique_problems(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> RusotoFuture<ListUniqueProblemsResult, ListUniqueProblemsError> {
+        (**self).list_unique_problems(input)
+    }
+    fn list_uploads(
+        &self,
+        input: ListUploadsRequest,
+    ) -> RusotoFuture<ListUploadsResult, ListUploadsError> {
+        (**self).list_uploads(input)
+    }
+    fn list_vpce_configurations(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> RusotoFuture<ListVPCEConfigurationsResult, ListVPCEConfigurationsError> {
+        (**self).list_vpce_configurations(input)
+    }
+    fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> RusotoFuture<PurchaseOfferingResult, PurchaseOfferingError> {
+        (**self).purchase_offering(input)
+    }
+    fn renew_offering(
+        &self,
+        input: RenewOfferingRequest,
+    ) -> RusotoFuture<RenewOfferingResult, RenewOfferingError> {
+        (**self).renew_offering(input)
+    }
+    fn schedule_run(
+        &self,
+        input: ScheduleRunRequest,
+    ) -> RusotoFuture<ScheduleRunResult, ScheduleRunError> {
+        (**self).schedule_run(input)
+    }
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        (**self).stop_job(input)
+    }
+    fn stop_remote_access_session(
+        &self,
+        input: StopRemoteAccessSessionRequest,
+    ) -> RusotoFuture<StopRemoteAccessSessionResult, StopRemoteAccessSessionError> {
+        (**self).stop_remote_access_session(input)
+    }
+    fn stop_run(&self, input: StopRunRequest) -> RusotoFuture<StopRunResult, StopRunError> {
+        (**self).stop_run(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_device_instance(
+        &self,
+        input: UpdateDeviceInstanceRequest,
+    ) -> RusotoFuture<UpdateDeviceInstanceResult, UpdateDeviceInstanceError> {
+        (**self).update_device_instance(input)
+    }
+    fn update_device_pool(
+        &self,
+        input: UpdateDevicePoolRequest,
+    ) -> RusotoFuture<UpdateDevicePoolResult, UpdateDevicePoolError> {
+        (**self).update_device_pool(input)
+    }
+    fn update_instance_profile(
+        &self,
+        input: UpdateInstanceProfileRequest,
+    ) -> RusotoFuture<UpdateInstanceProfileResult, UpdateInstanceProfileError> {
+        (**self).update_instance_profile(input)
+    }
+    fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> RusotoFuture<UpdateNetworkProfileResult, UpdateNetworkProfileError> {
+        (**self).update_network_profile(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_upload(
+        &self,
+        input: UpdateUploadRequest,
+    ) -> RusotoFuture<UpdateUploadResult, UpdateUploadError> {
+        (**self).update_upload(input)
+    }
+    fn update_vpce_configuration(
+        &self,
+        input: UpdateVPCEConfigurationRequest,
+    ) -> RusotoFuture<UpdateVPCEConfigurationResult, UpdateVPCEConfigurationError> {
+        (**self).update_vpce_configuration(input)
+    }
+}
+/// A client for the AWS Device Farm API.
+#[derive(Clone)]
+pub struct DeviceFarmClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl DeviceFarmClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DeviceFarmClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DeviceFarmClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> DeviceFarmClient {
+        DeviceFarmClient { client, region }
+    }
+}
+
+impl DeviceFarm for DeviceFarmClient {
+    /// <p>Creates a device pool.</p>
+    fn create_device_pool(
+        &self,
+        input: CreateDevicePoolRequest,
+    ) -> RusotoFuture<CreateDevicePoolResult, CreateDevicePoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateDevicePool");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.CreateDevicePool");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDevicePoolResult, _>(
+                    response,
+                    "CreateDevicePool",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDevicePoolError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a profile that can be applied to one or more private fleet device instances.</p>
+    fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> RusotoFuture<CreateInstanceProfileResult, CreateInstanceProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateInstanceProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.CreateInstanceProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateInstanceProfileResult, _>(
+                    response,
+                    "CreateInstanceProfile",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateInstanceProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a network profile.</p>
+    fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> RusotoFuture<CreateNetworkProfileResult, CreateNetworkProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateNetworkProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.CreateNetworkProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateNetworkProfileResult, _>(
+                    response,
+                    "CreateNetworkProfile",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateNetworkProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new project.</p>
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateProject");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.CreateProject");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateProjectResult, _>(
+                    response,
+                    "CreateProject",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateProjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Specifies and starts a remote access session.</p>
+    fn create_remote_access_session(
+        &self,
+        input: CreateRemoteAccessSessionRequest,
+    ) -> RusotoFuture<CreateRemoteAccessSessionResult, CreateRemoteAccessSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateRemoteAccessSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DeviceFarm_20150623.CreateRemoteAccessSession",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRemoteAccessSessionResult, _>(
+                    response,
+                    "CreateRemoteAccessSession",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateRemoteAccessSessionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Uploads an app or test scripts.</p>
+    fn create_upload(
+        &self,
+        input: CreateUploadRequest,
+    ) -> RusotoFuture<CreateUploadResult, CreateUploadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateUpload");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.CreateUpload");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUploadResult, _>(
+                    response,
+                    "CreateUpload",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUploadError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a configuration record in Device Farm for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    fn create_vpce_configuration(
+        &self,
+        input: CreateVPCEConfigurationRequest,
+    ) -> RusotoFuture<CreateVPCEConfigurationResult, CreateVPCEConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateVPCEConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DeviceFarm_20150623.CreateVPCEConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateVPCEConfigurationResult, _>(
+                    response,
+                    "CreateVPCEConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateVPCEConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a device pool given the pool ARN. Does not allow deletion of curated pools owned by the system.</p>
+    fn delete_device_pool(
+        &self,
+        input: DeleteDevicePoolRequest,
+    ) -> RusotoFuture<DeleteDevicePoolResult, DeleteDevicePoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteDevicePool");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteDevicePool");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDevicePoolResult, _>(
+                    response,
+                    "DeleteDevicePool",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDevicePoolError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a profile that can be applied to one or more private device instances.</p>
+    fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> RusotoFuture<DeleteInstanceProfileResult, DeleteInstanceProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteInstanceProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteInstanceProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteInstanceProfileResult, _>(
+                    response,
+                    "DeleteInstanceProfile",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteInstanceProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a network profile.</p>
+    fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> RusotoFuture<DeleteNetworkProfileResult, DeleteNetworkProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteNetworkProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteNetworkProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteNetworkProfileResult, _>(
+                    response,
+                    "DeleteNetworkProfile",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteNetworkProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an AWS Device Farm project, given the project ARN.</p> <p> <b>Note</b> Deleting this resource does not stop an in-progress run.</p>
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteProject");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteProject");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteProjectResult, _>(
+                    response,
+                    "DeleteProject",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteProjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a completed remote access session and its results.</p>
+    fn delete_remote_access_session(
+        &self,
+        input: DeleteRemoteAccessSessionRequest,
+    ) -> RusotoFuture<DeleteRemoteAccessSessionResult, DeleteRemoteAccessSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteRemoteAccessSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DeviceFarm_20150623.DeleteRemoteAccessSession",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRemoteAccessSessionResult, _>(
+                    response,
+                    "DeleteRemoteAccessSession",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteRemoteAccessSessionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the run, given the run ARN.</p> <p> <b>Note</b> Deleting this resource does not stop an in-progress run.</p>
+    fn delete_run(&self, input: DeleteRunRequest) -> RusotoFuture<DeleteRunResult, DeleteRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRunResult, _>(response, "DeleteRun")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an upload given the upload ARN.</p>
+    fn delete_upload(
+        &self,
+        input: DeleteUploadRequest,
+    ) -> RusotoFuture<DeleteUploadResult, DeleteUploadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteUpload");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteUpload");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteUploadResult, _>(
+                    response,
+                    "DeleteUpload",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUploadError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a configuration for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    fn delete_vpce_configuration(
+        &self,
+        input: DeleteVPCEConfigurationRequest,
+    ) -> RusotoFuture<DeleteVPCEConfigurationResult, DeleteVPCEConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteVPCEConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DeviceFarm_20150623.DeleteVPCEConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteVPCEConfigurationResult, _>(
+                    response,
+                    "DeleteVPCEConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteVPCEConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the number of unmetered iOS and/or unmetered Android devices that have been purchased by the account.</p>
+    fn get_account_settings(
+        &self,
+    ) -> RusotoFuture<GetAccountSettingsResult, GetAccountSettingsError> {
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetAccountSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetAccountSettings");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAccountSettingsResult, _>(
+                    response,
+                    "GetAccountSettings",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAccountSettingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a unique device type.</p>
+    fn get_device(&self, input: GetDeviceRequest) -> RusotoFuture<GetDeviceResult, GetDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDevice");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevice");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDeviceResult, _>(response, "GetDevice")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a device instance belonging to a private device fleet.</p>
+    fn get_device_instance(
+        &self,
+        input: GetDeviceInstanceRequest,
+    ) -> RusotoFuture<GetDeviceInstanceResult, GetDeviceInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDeviceInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetDeviceInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDeviceInstanceResult, _>(
+                    response,
+                    "GetDeviceInstance",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDeviceInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a device pool.</p>
+    fn get_device_pool(
+        &self,
+        input: GetDevicePoolRequest,
+    ) -> RusotoFuture<GetDevicePoolResult, GetDevicePoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDevicePool");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevicePool");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDevicePoolResult, _>(
+                    response,
+                    "GetDevicePool",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDevicePoolError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about compatibility with a device pool.</p>
+    fn get_device_pool_compatibility(
+        &self,
+        input: GetDevicePoolCompatibilityRequest,
+    ) -> RusotoFuture<GetDevicePoolCompatibilityResult, GetDevicePoolCompatibilityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDevicePoolCompatibility");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DeviceFarm_20150623.GetDevicePoolCompatibility",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDevicePoolCompatibilityResult, _>(
+                    response,
+                    "GetDevicePoolCompatibility",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetDevicePoolCompatibilityError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about the specified instance profile.</p>
+    fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> RusotoFuture<GetInstanceProfileResult, GetInstanceProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetInstanceProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetInstanceProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInstanceProfileResult, _>(
+                    response,
+                    "GetInstanceProfile",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetInstanceProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a job.</p>
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetJobResult, _>(response, "GetJob")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a network profile.</p>
+    fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> RusotoFuture<GetNetworkProfileResult, GetNetworkProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetNetworkProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetNetworkProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetNetworkProfileResult, _>(
+                    response,
+                    "GetNetworkProfile",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetNetworkProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the current status and future status of all offerings purchased by an AWS account. The response indicates how many offerings are currently available and the offerings that will be available in the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn get_offering_status(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> RusotoFuture<GetOfferingStatusResult, GetOfferingStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetOfferingStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetOfferingStatus");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetOfferingStatusResult, _>(
+                    response,
+                    "GetOfferingStatus",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetOfferingStatusError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a project.</p>
+    fn get_project(
+        &self,
+        input: GetProjectRequest,
+    ) -> RusotoFuture<GetProjectResult, GetProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetProject");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetProject");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetProjectResult, _>(response, "GetProject")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetProjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a link to a currently running remote access session.</p>
+    fn get_remote_access_session(
+        &self,
+        input: GetRemoteAccessSessionRequest,
+    ) -> RusotoFuture<GetRemoteAccessSessionResult, GetRemoteAccessSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetRemoteAccessSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetRemoteAccessSession");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRemoteAccessSessionResult, _>(
+                    response,
+                    "GetRemoteAccessSession",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetRemoteAccessSessionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a run.</p>
+    fn get_run(&self, input: GetRunRequest) -> RusotoFuture<GetRunResult, GetRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRunResult, _>(response, "GetRun")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a suite.</p>
+    fn get_suite(&self, input: GetSuiteRequest) -> RusotoFuture<GetSuiteResult, GetSuiteError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetSuite");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetSuite");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetSuiteResult, _>(response, "GetSuite")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSuiteError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a test.</p>
+    fn get_test(&self, input: GetTestRequest) -> RusotoFuture<GetTestResult, GetTestError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetTest");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetTest");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetTestResult, _>(response, "GetTest")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTestError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about an upload.</p>
+    fn get_upload(&self, input: GetUploadRequest) -> RusotoFuture<GetUploadResult, GetUploadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetUpload");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetUpload");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetUploadResult, _>(response, "GetUpload")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUploadError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the configuration settings for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    fn get_vpce_configuration(
+        &self,
+        input: GetVPCEConfigurationRequest,
+    ) -> RusotoFuture<GetVPCEConfigurationResult, GetVPCEConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetVPCEConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.GetVPCEConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetVPCEConfigurationResult, _>(
+                    response,
+                    "GetVPCEConfiguration",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetVPCEConfigurationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Installs an application to the device in a remote access session. For Android applications, the file must be in .apk format. For iOS applications, the file must be in .ipa format.</p>
+    fn install_to_remote_access_session(
+        &self,
+        input: InstallToRemoteAccessSessionRequest,
+    ) -> RusotoFuture<InstallToRemoteAccessSessionResult, InstallToRemoteAccessSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("InstallToRemoteAccessSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DeviceFarm_20150623.InstallToRemoteAccessSession",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<InstallToRemoteAccessSessionResult, _>(
+                    response,
+                    "InstallToRemoteAccessSession",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(InstallToRemoteAccessSessionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about artifacts.</p>
+    fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> RusotoFuture<ListArtifactsResult, ListArtifactsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListArtifacts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListArtifacts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListArtifactsResult, _>(
+                    response,
+                    "ListArtifacts",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListArtifactsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the private device instances associated with one or more AWS accounts.</p>
+    fn list_device_instances(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> RusotoFuture<ListDeviceInstancesResult, ListDeviceInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListDeviceInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListDeviceInstances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDeviceInstancesResult, _>(
+                    response,
+                    "ListDeviceInstances",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListDeviceInstancesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about device pools.</p>
+    fn list_device_pools(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> RusotoFuture<ListDevicePoolsResult, ListDevicePoolsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListDevicePools");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevicePools");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDevicePoolsResult, _>(
+                    response,
+                    "ListDevicePools",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDevicePoolsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about unique device types.</p>
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResult, ListDevicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListDevices");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevices");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDevicesResult, _>(response, "ListDevices")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDevicesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about all the instance profiles in an AWS account.</p>
+    fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> RusotoFuture<ListInstanceProfilesResult, ListInstanceProfilesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListInstanceProfiles");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListInstanceProfiles");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListInstanceProfilesResult, _>(
+                    response,
+                    "ListInstanceProfiles",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListInstanceProfilesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about jobs for a given test run.</p>
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListJobs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListJobsResult, _>(response, "ListJobs")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the list of available network profiles.</p>
+    fn list_network_profiles(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> RusotoFuture<ListNetworkProfilesResult, ListNetworkProfilesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListNetworkProfiles");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListNetworkProfiles");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListNetworkProfilesResult, _>(
+                    response,
+                    "ListNetworkProfiles",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListNetworkProfilesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of offering promotions. Each offering promotion record contains the ID and description of the promotion. The API returns a <code>NotEligible</code> error if the caller is not permitted to invoke the operation. Contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn list_offering_promotions(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> RusotoFuture<ListOfferingPromotionsResult, ListOfferingPromotionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListOfferingPromotions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferingPromotions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListOfferingPromotionsResult, _>(
+                    response,
+                    "ListOfferingPromotions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListOfferingPromotionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of all historical purchases, renewals, and system renewal transactions for an AWS account. The list is paginated and ordered by a descending timestamp (most recent transactions are first). The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn list_offering_transactions(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> RusotoFuture<ListOfferingTransactionsResult, ListOfferingTransactionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListOfferingTransactions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DeviceFarm_20150623.ListOfferingTransactions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListOfferingTransactionsResult, _>(
+                    response,
+                    "ListOfferingTransactions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListOfferingTransactionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of products or offerings that the user can manage through the API. Each offering record indicates the recurring price per unit and the frequency for that offering. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> RusotoFuture<ListOfferingsResult, ListOfferingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListOfferings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferings");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListOfferingsResult, _>(
+                    response,
+                    "ListOfferings",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListOfferingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about projects.</p>
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListProjects");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListProjects");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListProjectsResult, _>(
+                    response,
+                    "ListProjects",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListProjectsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of all currently running remote access sessions.</p>
+    fn list_remote_access_sessions(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> RusotoFuture<ListRemoteAccessSessionsResult, ListRemoteAccessSessionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListRemoteAccessSessions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DeviceFarm_20150623.ListRemoteAccessSessions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRemoteAccessSessionsResult, _>(
+                    response,
+                    "ListRemoteAccessSessions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListRemoteAccessSessionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about runs, given an AWS Device Farm project ARN.</p>
+    fn list_runs(&self, input: ListRunsRequest) -> RusotoFuture<ListRunsResult, ListRunsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListRuns");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListRuns");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRunsResult, _>(response, "ListRuns")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRunsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about samples, given an AWS Device Farm job ARN.</p>
+    fn list_samples(
+        &self,
+        input: ListSamplesRequest,
+    ) -> RusotoFuture<ListSamplesResult, ListSamplesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListSamples");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListSamples");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSamplesResult, _>(response, "ListSamples")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListSamplesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about test suites for a given job.</p>
+    fn list_suites(
+        &self,
+        input: ListSuitesRequest,
+    ) -> RusotoFuture<ListSuitesResult, ListSuitesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListSuites");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListSuites");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSuitesResult, _>(response, "ListSuites")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListSuitesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>List the tags for an AWS Device Farm resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about tests in a given test suite.</p>
+    fn list_tests(&self, input: ListTestsRequest) -> RusotoFuture<ListTestsResult, ListTestsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTests");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListTests");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTestsResult, _>(response, "ListTests")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTestsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about unique problems.</p>
+    fn list_unique_problems(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> RusotoFuture<ListUniqueProblemsResult, ListUniqueProblemsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListUniqueProblems");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListUniqueProblems");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListUniqueProblemsResult, _>(
+                    response,
+                    "ListUniqueProblems",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListUniqueProblemsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about uploads, given an AWS Device Farm project ARN.</p>
+    fn list_uploads(
+        &self,
+        input: ListUploadsRequest,
+    ) -> RusotoFuture<ListUploadsResult, ListUploadsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListUploads");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListUploads");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListUploadsResult, _>(response, "ListUploads")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListUploadsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about all Amazon Virtual Private Cloud (VPC) endpoint configurations in the AWS account.</p>
+    fn list_vpce_configurations(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> RusotoFuture<ListVPCEConfigurationsResult, ListVPCEConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListVPCEConfigurations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ListVPCEConfigurations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListVPCEConfigurationsResult, _>(
+                    response,
+                    "ListVPCEConfigurations",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListVPCEConfigurationsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Immediately purchases offerings for an AWS account. Offerings renew with the latest total purchased quantity for an offering, unless the renewal was overridden. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> RusotoFuture<PurchaseOfferingResult, PurchaseOfferingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("PurchaseOffering");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.PurchaseOffering");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PurchaseOfferingResult, _>(
+                    response,
+                    "PurchaseOffering",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PurchaseOfferingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Explicitly sets the quantity of devices to renew for an offering, starting from the <code>effectiveDate</code> of the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn renew_offering(
+        &self,
+        input: RenewOfferingRequest,
+    ) -> RusotoFuture<RenewOfferingResult, RenewOfferingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("RenewOffering");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.RenewOffering");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RenewOfferingResult, _>(
+                    response,
+                    "RenewOffering",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RenewOfferingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Schedules a run.</p>
+    fn schedule_run(
+        &self,
+        input: ScheduleRunRequest,
+    ) -> RusotoFuture<ScheduleRunResult, ScheduleRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ScheduleRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.ScheduleRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ScheduleRunResult, _>(response, "ScheduleRun")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ScheduleRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Initiates a stop request for the current job. AWS Device Farm will immediately stop the job on the device where tests have not started executing, and you will not be billed for this device. On the device where tests have started executing, Setup Suite and Teardown Suite tests will run to completion before stopping execution on the device. You will be billed for Setup, Teardown, and any tests that were in progress or already completed.</p>
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("StopJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.StopJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopJobResult, _>(response, "StopJob")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Ends a specified remote access session.</p>
+    fn stop_remote_access_session(
+        &self,
+        input: StopRemoteAccessSessionRequest,
+    ) -> RusotoFuture<StopRemoteAccessSessionResult, StopRemoteAccessSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("StopRemoteAccessSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DeviceFarm_20150623.StopRemoteAccessSession",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopRemoteAccessSessionResult, _>(
+                    response,
+                    "StopRemoteAccessSession",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopRemoteAccessSessionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Initiates a stop request for the current test run. AWS Device Farm will immediately stop the run on devices where tests have not started executing, and you will not be billed for these devices. On devices where tests have started executing, Setup Suite and Teardown Suite tests will run to completion before stopping execution on those devices. You will be billed for Setup, Teardown, and any tests that were in progress or already completed.</p>
+    fn stop_run(&self, input: StopRunRequest) -> RusotoFuture<StopRunResult, StopRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("StopRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.StopRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopRunResult, _>(response, "StopRun")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified tags from a resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates information about an existing private device instance.</p>
+    fn update_device_instance(
+        &self,
+        input: UpdateDeviceInstanceRequest,
+    ) -> RusotoFuture<UpdateDeviceInstanceResult, UpdateDeviceInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateDeviceInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDeviceInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDeviceInstanceResult, _>(
+                    response,
+                    "UpdateDeviceInstance",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateDeviceInstanceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies the name, description, and rules in a device pool given the attributes and the pool ARN. Rule updates are all-or-nothing, meaning they can only be updated as a whole (or not at all).</p>
+    fn update_device_pool(
+        &self,
+        input: UpdateDevicePoolRequest,
+    ) -> RusotoFuture<UpdateDevicePoolResult, UpdateDevicePoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateDevicePool");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDevicePool");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDevicePoolResult, _>(
+                    response,
+                    "UpdateDevicePool",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDevicePoolError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates information about an existing private device instance profile.</p>
+    fn update_instance_profile(
+        &self,
+        input: UpdateInstanceProfileRequest,
+    ) -> RusotoFuture<UpdateInstanceProfileResult, UpdateInstanceProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateInstanceProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateInstanceProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateInstanceProfileResult, _>(
+                    response,
+                    "UpdateInstanceProfile",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateInstanceProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the network profile with specific settings.</p>
+    fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> RusotoFuture<UpdateNetworkProfileResult, UpdateNetworkProfileError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateNetworkProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateNetworkProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateNetworkProfileResult, _>(
+                    response,
+                    "UpdateNetworkProfile",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateNetworkProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies the specified project name, given the project ARN and a new name.</p>
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateProject");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateProject");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateProjectResult, _>(
+                    response,
+                    "UpdateProject",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateProjectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Update an uploaded test specification (test spec).</p>
+    fn update_upload(
+        &self,
+        input: UpdateUploadRequest,
+    ) -> RusotoFuture<UpdateUploadResult, UpdateUploadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateUpload");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateUpload");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateUploadResult, _>(
+                    response,
+                    "UpdateUpload",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateUploadError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates information about an existing Amazon Virtual Private Cloud (VPC) endpoint configuration.</p>
+    fn update_vpce_configuration(
+        &self,
+        input: UpdateVPCEConfigurationRequest,
+    ) -> RusotoFuture<UpdateVPCEConfigurationResult, UpdateVPCEConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateVPCEConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DeviceFarm_20150623.UpdateVPCEConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateVPCEConfigurationResult, _>(
+                    response,
+                    "UpdateVPCEConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateVPCEConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+}
+
+/// A mock of the AWS Device Farm API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockDeviceFarm {
+    create_device_pool: Option<
+        Box<
+            dyn Fn(
+                    CreateDevicePoolRequest,
+                ) -> ::std::result::Result<
+                    CreateDevicePoolResult,
+                    RusotoError<CreateDevicePoolError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_instance_profile: Option<
+        Box<
+            dyn Fn(
+                    CreateInstanceProfileRequest,
+                ) -> ::std::result::Result<
+                    CreateInstanceProfileResult,
+                    RusotoError<CreateInstanceProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_network_profile: Option<
+        Box<
+            dyn Fn(
+                    CreateNetworkProfileRequest,
+                ) -> ::std::result::Result<
+                    CreateNetworkProfileResult,
+                    RusotoError<CreateNetworkProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_project: Option<
+        Box<
+            dyn Fn(
+                    CreateProjectRequest,
+                )
+                    -> ::std::result::Result<CreateProjectResult, RusotoError<CreateProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_remote_access_session: Option<
+        Box<
+            dyn Fn(
+                    CreateRemoteAccessSessionRequest,
+                ) -> ::std::result::Result<
+                    CreateRemoteAccessSessionResult,
+                    RusotoError<CreateRemoteAccessSessionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_upload: Option<
+        Box<
+            dyn Fn(
+                    CreateUploadRequest,
+                )
+                    -> ::std::result::Result<CreateUploadResult, RusotoError<CreateUploadError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_vpce_configuration: Option<
+        Box<
+            dyn Fn(
+                    CreateVPCEConfigurationRequest,
+                ) -> ::std::result::Result<
+                    CreateVPCEConfigurationResult,
+                    RusotoError<CreateVPCEConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_device_pool: Option<
+        Box<
+            dyn Fn(
+                    DeleteDevicePoolRequest,
+                ) -> ::std::result::Result<
+                    DeleteDevicePoolResult,
+                    RusotoError<DeleteDevicePoolError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_instance_profile: Option<
+        Box<
+            dyn Fn(
+                    DeleteInstanceProfileRequest,
+                ) -> ::std::result::Result<
+                    DeleteInstanceProfileResult,
+                    RusotoError<DeleteInstanceProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_network_profile: Option<
+        Box<
+            dyn Fn(
+                    DeleteNetworkProfileRequest,
+                ) -> ::std::result::Result<
+                    DeleteNetworkProfileResult,
+                    RusotoError<DeleteNetworkProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_project: Option<
+        Box<
+            dyn Fn(
+                    DeleteProjectRequest,
+                )
+                    -> ::std::result::Result<DeleteProjectResult, RusotoError<DeleteProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_remote_access_session: Option<
+        Box<
+            dyn Fn(
+                    DeleteRemoteAccessSessionRequest,
+                ) -> ::std::result::Result<
+                    DeleteRemoteAccessSessionResult,
+                    RusotoError<DeleteRemoteAccessSessionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_run: Option<
+        Box<
+            dyn Fn(
+                    DeleteRunRequest,
+                )
+                    -> ::std::result::Result<DeleteRunResult, RusotoError<DeleteRunError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_upload: Option<
+        Box<
+            dyn Fn(
+                    DeleteUploadRequest,
+                )
+                    -> ::std::result::Result<DeleteUploadResult, RusotoError<DeleteUploadError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_vpce_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteVPCEConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DeleteVPCEConfigurationResult,
+                    RusotoError<DeleteVPCEConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_account_settings: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetAccountSettingsResult,
+                    RusotoError<GetAccountSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_device: Option<
+        Box<
+            dyn Fn(
+                    GetDeviceRequest,
+                )
+                    -> ::std::result::Result<GetDeviceResult, RusotoError<GetDeviceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_device_instance: Option<
+        Box<
+            dyn Fn(
+                    GetDeviceInstanceRequest,
+                ) -> ::std::result::Result<
+                    GetDeviceInstanceResult,
+                    RusotoError<GetDeviceInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_device_pool: Option<
+        Box<
+            dyn Fn(
+                    GetDevicePoolRequest,
+                )
+                    -> ::std::result::Result<GetDevicePoolResult, RusotoError<GetDevicePoolError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_device_pool_compatibility: Option<
+        Box<
+            dyn Fn(
+                    GetDevicePoolCompatibilityRequest,
+                ) -> ::std::result::Result<
+                    GetDevicePoolCompatibilityResult,
+                    RusotoError<GetDevicePoolCompatibilityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_instance_profile: Option<
+        Box<
+            dyn Fn(
+                    GetInstanceProfileRequest,
+                ) -> ::std::result::Result<
+                    GetInstanceProfileResult,
+                    RusotoError<GetInstanceProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_job: Option<
+        Box<
+            dyn Fn(GetJobRequest) -> ::std::result::Result<GetJobResult, RusotoError<GetJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_network_profile: Option<
+        Box<
+            dyn Fn(
+                    GetNetworkProfileRequest,
+                ) -> ::std::result::Result<
+                    GetNetworkProfileResult,
+                    RusotoError<GetNetworkProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_offering_status: Option<
+        Box<
+            dyn Fn(
+                    GetOfferingStatusRequest,
+                ) -> ::std::result::Result<
+                    GetOfferingStatusResult,
+                    RusotoError<GetOfferingStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_project: Option<
+        Box<
+            dyn Fn(
+                    GetProjectRequest,
+                )
+                    -> ::std::result::Result<GetProjectResult, RusotoError<GetProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_remote_access_session: Option<
+        Box<
+            dyn Fn(
+                    GetRemoteAccessSessionRequest,
+                ) -> ::std::result::Result<
+                    GetRemoteAccessSessionResult,
+                    RusotoError<GetRemoteAccessSessionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_run: Option<
+        Box<
+            dyn Fn(GetRunRequest) -> ::std::result::Result<GetRunResult, RusotoError<GetRunError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_suite: Option<
+        Box<
+            dyn Fn(
+                    GetSuiteRequest,
+                )
+                    -> ::std::result::Result<GetSuiteResult, RusotoError<GetSuiteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_test: Option<
+        Box<
+            dyn Fn(
+                    GetTestRequest,
+                )
+                    -> ::std::result::Result<GetTestResult, RusotoError<GetTestError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_upload: Option<
+        Box<
+            dyn Fn(
+                    GetUploadRequest,
+                )
+                    -> ::std::result::Result<GetUploadResult, RusotoError<GetUploadError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_vpce_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetVPCEConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetVPCEConfigurationResult,
+                    RusotoError<GetVPCEConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    install_to_remote_access_session: Option<
+        Box<
+            dyn Fn(
+                    InstallToRemoteAccessSessionRequest,
+                ) -> ::std::result::Result<
+                    InstallToRemoteAccessSessionResult,
+                    RusotoError<InstallToRemoteAccessSessionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_artifacts: Option<
+        Box<
+            dyn Fn(
+                    ListArtifactsRequest,
+                )
+                    -> ::std::result::Result<ListArtifactsResult, RusotoError<ListArtifactsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_device_instances: Option<
+        Box<
+            dyn Fn(
+                    ListDeviceInstancesRequest,
+                ) -> ::std::result::Result<
+                    ListDeviceInstancesResult,
+                    RusotoError<ListDeviceInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_device_pools: Option<
+        Box<
+            dyn Fn(
+                    ListDevicePoolsRequest,
+                ) -> ::std::result::Result<
+                    ListDevicePoolsResult,
+                    RusotoError<ListDevicePoolsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_devices: Option<
+        Box<
+            dyn Fn(
+                    ListDevicesRequest,
+                )
+                    -> ::std::result::Result<ListDevicesResult, RusotoError<ListDevicesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_instance_profiles: Option<
+        Box<
+            dyn Fn(
+                    ListInstanceProfilesRequest,
+                ) -> ::std::result::Result<
+                    ListInstanceProfilesResult,
+                    RusotoError<ListInstanceProfilesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListJobsRequest,
+                )
+                    -> ::std::result::Result<ListJobsResult, RusotoError<ListJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_network_profiles: Option<
+        Box<
+            dyn Fn(
+                    ListNetworkProfilesRequest,
+                ) -> ::std::result::Result<
+                    ListNetworkProfilesResult,
+                    RusotoError<ListNetworkProfilesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_offering_promotions: Option<
+        Box<
+            dyn Fn(
+                    ListOfferingPromotionsRequest,
+                ) -> ::std::result::Result<
+                    ListOfferingPromotionsResult,
+                    RusotoError<ListOfferingPromotionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_offering_transactions: Option<
+        Box<
+            dyn Fn(
+                    ListOfferingTransactionsRequest,
+                ) -> ::std::result::Result<
+                    ListOfferingTransactionsResult,
+                    RusotoError<ListOfferingTransactionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_offerings: Option<
+        Box<
+            dyn Fn(
+                    ListOfferingsRequest,
+                )
+                    -> ::std::result::Result<ListOfferingsResult, RusotoError<ListOfferingsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_projects: Option<
+        Box<
+            dyn Fn(
+                    ListProjectsRequest,
+                )
+                    -> ::std::result::Result<ListProjectsResult, RusotoError<ListProjectsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_remote_access_sessions: Option<
+        Box<
+            dyn Fn(
+                    ListRemoteAccessSessionsRequest,
+                ) -> ::std::result::Result<
+                    ListRemoteAccessSessionsResult,
+                    RusotoError<ListRemoteAccessSessionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_runs: Option<
+        Box<
+            dyn Fn(
+                    ListRunsRequest,
+                )
+                    -> ::std::result::Result<ListRunsResult, RusotoError<ListRunsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_samples: Option<
+        Box<
+            dyn Fn(
+                    ListSamplesRequest,
+                )
+                    -> ::std::result::Result<ListSamplesResult, RusotoError<ListSamplesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_suites: Option<
+        Box<
+            dyn Fn(
+                    ListSuitesRequest,
+                )
+                    -> ::std::result::Result<ListSuitesResult, RusotoError<ListSuitesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tests: Option<
+        Box<
+            dyn Fn(
+                    ListTestsRequest,
+                )
+                    -> ::std::result::Result<ListTestsResult, RusotoError<ListTestsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_unique_problems: Option<
+        Box<
+            dyn Fn(
+                    ListUniqueProblemsRequest,
+                ) -> ::std::result::Result<
+                    ListUniqueProblemsResult,
+                    RusotoError<ListUniqueProblemsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_uploads: Option<
+        Box<
+            dyn Fn(
+                    ListUploadsRequest,
+                )
+                    -> ::std::result::Result<ListUploadsResult, RusotoError<ListUploadsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_vpce_configurations: Option<
+        Box<
+            dyn Fn(
+                    ListVPCEConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    ListVPCEConfigurationsResult,
+                    RusotoError<ListVPCEConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    purchase_offering: Option<
+        Box<
+            dyn Fn(
+                    PurchaseOfferingRequest,
+                ) -> ::std::result::Result<
+                    PurchaseOfferingResult,
+                    RusotoError<PurchaseOfferingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    renew_offering: Option<
+        Box<
+            dyn Fn(
+                    RenewOfferingRequest,
+                )
+                    -> ::std::result::Result<RenewOfferingResult, RusotoError<RenewOfferingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    schedule_run: Option<
+        Box<
+            dyn Fn(
+                    ScheduleRunRequest,
+                )
+                    -> ::std::result::Result<ScheduleRunResult, RusotoError<ScheduleRunError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_job: Option<
+        Box<
+            dyn Fn(
+                    StopJobRequest,
+                )
+                    -> ::std::result::Result<StopJobResult, RusotoError<StopJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_remote_access_session: Option<
+        Box<
+            dyn Fn(
+                    StopRemoteAccessSessionRequest,
+                ) -> ::std::result::Result<
+                    StopRemoteAccessSessionResult,
+                    RusotoError<StopRemoteAccessSessionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_run: Option<
+        Box<
+            dyn Fn(
+                    StopRunRequest,
+                )
+                    -> ::std::result::Result<StopRunResult, RusotoError<StopRunError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_device_instance: Option<
+        Box<
+            dyn Fn(
+                    UpdateDeviceInstanceRequest,
+                ) -> ::std::result::Result<
+                    UpdateDeviceInstanceResult,
+                    RusotoError<UpdateDeviceInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_device_pool: Option<
+        Box<
+            dyn Fn(
+                    UpdateDevicePoolRequest,
+                ) -> ::std::result::Result<
+                    UpdateDevicePoolResult,
+                    RusotoError<UpdateDevicePoolError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_instance_profile: Option<
+        Box<
+            dyn Fn(
+                    UpdateInstanceProfileRequest,
+                ) -> ::std::result::Result<
+                    UpdateInstanceProfileResult,
+                    RusotoError<UpdateInstanceProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_network_profile: Option<
+        Box<
+            dyn Fn(
+                    UpdateNetworkProfileRequest,
+                ) -> ::std::result::Result<
+                    UpdateNetworkProfileResult,
+                    RusotoError<UpdateNetworkProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_project: Option<
+        Box<
+            dyn Fn(
+                    UpdateProjectRequest,
+                )
+                    -> ::std::result::Result<UpdateProjectResult, RusotoError<UpdateProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_upload: Option<
+        Box<
+            dyn Fn(
+                    UpdateUploadRequest,
+                )
+                    -> ::std::result::Result<UpdateUploadResult, RusotoError<UpdateUploadError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_vpce_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateVPCEConfigurationRequest,
+                ) -> ::std::result::Result<
+                    UpdateVPCEConfigurationResult,
+                    RusotoError<UpdateVPCEConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockDeviceFarm {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockDeviceFarm {
+        Default::default()
+    }
+
+    /// Answers `create_device_pool` with what `handler` returns for its input.
+    pub fn with_create_device_pool<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                CreateDevicePoolRequest,
+            )
+                -> ::std::result::Result<CreateDevicePoolResult, RusotoError<CreateDevicePoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_device_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_instance_profile` with what `handler` returns for its input.
+    pub fn with_create_instance_profile<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                CreateInstanceProfileRequest,
+            ) -> ::std::result::Result<
+                CreateInstanceProfileResult,
+                RusotoError<CreateInstanceProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_instance_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_network_profile` with what `handler` returns for its input.
+    pub fn with_create_network_profile<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                CreateNetworkProfileRequest,
+            ) -> ::std::result::Result<
+                CreateNetworkProfileResult,
+                RusotoError<CreateNetworkProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_network_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_project` with what `handler` returns for its input.
+    pub fn with_create_project<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                CreateProjectRequest,
+            )
+                -> ::std::result::Result<CreateProjectResult, RusotoError<CreateProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_remote_access_session` with what `handler` returns for its input.
+    pub fn with_create_remote_access_session<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                CreateRemoteAccessSessionRequest,
+            ) -> ::std::result::Result<
+                CreateRemoteAccessSessionResult,
+                RusotoError<CreateRemoteAccessSessionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_remote_access_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_upload` with what `handler` returns for its input.
+    pub fn with_create_upload<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                CreateUploadRequest,
+            )
+                -> ::std::result::Result<CreateUploadResult, RusotoError<CreateUploadError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_vpce_configuration` with what `handler` returns for its input.
+    pub fn with_create_vpce_configuration<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                CreateVPCEConfigurationRequest,
+            ) -> ::std::result::Result<
+                CreateVPCEConfigurationResult,
+                RusotoError<CreateVPCEConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_vpce_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_device_pool` with what `handler` returns for its input.
+    pub fn with_delete_device_pool<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                DeleteDevicePoolRequest,
+            )
+                -> ::std::result::Result<DeleteDevicePoolResult, RusotoError<DeleteDevicePoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_device_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_instance_profile` with what `handler` returns for its input.
+    pub fn with_delete_instance_profile<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                DeleteInstanceProfileRequest,
+            ) -> ::std::result::Result<
+                DeleteInstanceProfileResult,
+                RusotoError<DeleteInstanceProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_instance_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_network_profile` with what `handler` returns for its input.
+    pub fn with_delete_network_profile<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                DeleteNetworkProfileRequest,
+            ) -> ::std::result::Result<
+                DeleteNetworkProfileResult,
+                RusotoError<DeleteNetworkProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_network_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_project` with what `handler` returns for its input.
+    pub fn with_delete_project<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                DeleteProjectRequest,
+            )
+                -> ::std::result::Result<DeleteProjectResult, RusotoError<DeleteProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_remote_access_session` with what `handler` returns for its input.
+    pub fn with_delete_remote_access_session<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                DeleteRemoteAccessSessionRequest,
+            ) -> ::std::result::Result<
+                DeleteRemoteAccessSessionResult,
+                RusotoError<DeleteRemoteAccessSessionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_remote_access_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_run` with what `handler` returns for its input.
+    pub fn with_delete_run<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                DeleteRunRequest,
+            ) -> ::std::result::Result<DeleteRunResult, RusotoError<DeleteRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_upload` with what `handler` returns for its input.
+    pub fn with_delete_upload<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                DeleteUploadRequest,
+            )
+                -> ::std::result::Result<DeleteUploadResult, RusotoError<DeleteUploadError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_vpce_configuration` with what `handler` returns for its input.
+    pub fn with_delete_vpce_configuration<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                DeleteVPCEConfigurationRequest,
+            ) -> ::std::result::Result<
+                DeleteVPCEConfigurationResult,
+                RusotoError<DeleteVPCEConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_vpce_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_account_settings` with what `handler` returns.
+    pub fn with_get_account_settings<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn() -> ::std::result::Result<
+                GetAccountSettingsResult,
+                RusotoError<GetAccountSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_account_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_device` with what `handler` returns for its input.
+    pub fn with_get_device<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetDeviceRequest,
+            ) -> ::std::result::Result<GetDeviceResult, RusotoError<GetDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_device_instance` with what `handler` returns for its input.
+    pub fn with_get_device_instance<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetDeviceInstanceRequest,
+            ) -> ::std::result::Result<
+                GetDeviceInstanceResult,
+                RusotoError<GetDeviceInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_device_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_device_pool` with what `handler` returns for its input.
+    pub fn with_get_device_pool<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetDevicePoolRequest,
+            )
+                -> ::std::result::Result<GetDevicePoolResult, RusotoError<GetDevicePoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_device_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_device_pool_compatibility` with what `handler` returns for its input.
+    pub fn with_get_device_pool_compatibility<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetDevicePoolCompatibilityRequest,
+            ) -> ::std::result::Result<
+                GetDevicePoolCompatibilityResult,
+                RusotoError<GetDevicePoolCompatibilityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_device_pool_compatibility = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instance_profile` with what `handler` returns for its input.
+    pub fn with_get_instance_profile<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetInstanceProfileRequest,
+            ) -> ::std::result::Result<
+                GetInstanceProfileResult,
+                RusotoError<GetInstanceProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instance_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job` with what `handler` returns for its input.
+    pub fn with_get_job<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(GetJobRequest) -> ::std::result::Result<GetJobResult, RusotoError<GetJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_network_profile` with what `handler` returns for its input.
+    pub fn with_get_network_profile<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetNetworkProfileRequest,
+            ) -> ::std::result::Result<
+                GetNetworkProfileResult,
+                RusotoError<GetNetworkProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_network_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_offering_status` with what `handler` returns for its input.
+    pub fn with_get_offering_status<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetOfferingStatusRequest,
+            ) -> ::std::result::Result<
+                GetOfferingStatusResult,
+                RusotoError<GetOfferingStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_offering_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_project` with what `handler` returns for its input.
+    pub fn with_get_project<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetProjectRequest,
+            ) -> ::std::result::Result<GetProjectResult, RusotoError<GetProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_remote_access_session` with what `handler` returns for its input.
+    pub fn with_get_remote_access_session<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetRemoteAccessSessionRequest,
+            ) -> ::std::result::Result<
+                GetRemoteAccessSessionResult,
+                RusotoError<GetRemoteAccessSessionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_remote_access_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_run` with what `handler` returns for its input.
+    pub fn with_get_run<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(GetRunRequest) -> ::std::result::Result<GetRunResult, RusotoError<GetRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_suite` with what `handler` returns for its input.
+    pub fn with_get_suite<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(GetSuiteRequest) -> ::std::result::Result<GetSuiteResult, RusotoError<GetSuiteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_suite = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_test` with what `handler` returns for its input.
+    pub fn with_get_test<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(GetTestRequest) -> ::std::result::Result<GetTestResult, RusotoError<GetTestError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_test = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_upload` with what `handler` returns for its input.
+    pub fn with_get_upload<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetUploadRequest,
+            ) -> ::std::result::Result<GetUploadResult, RusotoError<GetUploadError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_vpce_configuration` with what `handler` returns for its input.
+    pub fn with_get_vpce_configuration<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                GetVPCEConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetVPCEConfigurationResult,
+                RusotoError<GetVPCEConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_vpce_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `install_to_remote_access_session` with what `handler` returns for its input.
+    pub fn with_install_to_remote_access_session<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                InstallToRemoteAccessSessionRequest,
+            ) -> ::std::result::Result<
+                InstallToRemoteAccessSessionResult,
+                RusotoError<InstallToRemoteAccessSessionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.install_to_remote_access_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_artifacts` with what `handler` returns for its input.
+    pub fn with_list_artifacts<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListArtifactsRequest,
+            )
+                -> ::std::result::Result<ListArtifactsResult, RusotoError<ListArtifactsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_artifacts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_device_instances` with what `handler` returns for its input.
+    pub fn with_list_device_instances<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListDeviceInstancesRequest,
+            ) -> ::std::result::Result<
+                ListDeviceInstancesResult,
+                RusotoError<ListDeviceInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_device_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_device_pools` with what `handler` returns for its input.
+    pub fn with_list_device_pools<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListDevicePoolsRequest,
+            )
+                -> ::std::result::Result<ListDevicePoolsResult, RusotoError<ListDevicePoolsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_device_pools = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_devices` with what `handler` returns for its input.
+    pub fn with_list_devices<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListDevicesRequest,
+            )
+                -> ::std::result::Result<ListDevicesResult, RusotoError<ListDevicesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_devices = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_instance_profiles` with what `handler` returns for its input.
+    pub fn with_list_instance_profiles<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListInstanceProfilesRequest,
+            ) -> ::std::result::Result<
+                ListInstanceProfilesResult,
+                RusotoError<ListInstanceProfilesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_instance_profiles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_jobs` with what `handler` returns for its input.
+    pub fn with_list_jobs<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(ListJobsRequest) -> ::std::result::Result<ListJobsResult, RusotoError<ListJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_network_profiles` with what `handler` returns for its input.
+    pub fn with_list_network_profiles<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListNetworkProfilesRequest,
+            ) -> ::std::result::Result<
+                ListNetworkProfilesResult,
+                RusotoError<ListNetworkProfilesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_network_profiles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_offering_promotions` with what `handler` returns for its input.
+    pub fn with_list_offering_promotions<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListOfferingPromotionsRequest,
+            ) -> ::std::result::Result<
+                ListOfferingPromotionsResult,
+                RusotoError<ListOfferingPromotionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_offering_promotions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_offering_transactions` with what `handler` returns for its input.
+    pub fn with_list_offering_transactions<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListOfferingTransactionsRequest,
+            ) -> ::std::result::Result<
+                ListOfferingTransactionsResult,
+                RusotoError<ListOfferingTransactionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_offering_transactions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_offerings` with what `handler` returns for its input.
+    pub fn with_list_offerings<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListOfferingsRequest,
+            )
+                -> ::std::result::Result<ListOfferingsResult, RusotoError<ListOfferingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_offerings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_projects` with what `handler` returns for its input.
+    pub fn with_list_projects<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListProjectsRequest,
+            )
+                -> ::std::result::Result<ListProjectsResult, RusotoError<ListProjectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_projects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_remote_access_sessions` with what `handler` returns for its input.
+    pub fn with_list_remote_access_sessions<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListRemoteAccessSessionsRequest,
+            ) -> ::std::result::Result<
+                ListRemoteAccessSessionsResult,
+                RusotoError<ListRemoteAccessSessionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_remote_access_sessions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_runs` with what `handler` returns for its input.
+    pub fn with_list_runs<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(ListRunsRequest) -> ::std::result::Result<ListRunsResult, RusotoError<ListRunsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_runs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_samples` with what `handler` returns for its input.
+    pub fn with_list_samples<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListSamplesRequest,
+            )
+                -> ::std::result::Result<ListSamplesResult, RusotoError<ListSamplesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_samples = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_suites` with what `handler` returns for its input.
+    pub fn with_list_suites<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListSuitesRequest,
+            ) -> ::std::result::Result<ListSuitesResult, RusotoError<ListSuitesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_suites = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tests` with what `handler` returns for its input.
+    pub fn with_list_tests<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListTestsRequest,
+            ) -> ::std::result::Result<ListTestsResult, RusotoError<ListTestsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tests = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_unique_problems` with what `handler` returns for its input.
+    pub fn with_list_unique_problems<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListUniqueProblemsRequest,
+            ) -> ::std::result::Result<
+                ListUniqueProblemsResult,
+                RusotoError<ListUniqueProblemsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_unique_problems = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_uploads` with what `handler` returns for its input.
+    pub fn with_list_uploads<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListUploadsRequest,
+            )
+                -> ::std::result::Result<ListUploadsResult, RusotoError<ListUploadsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_uploads = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_vpce_configurations` with what `handler` returns for its input.
+    pub fn with_list_vpce_configurations<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ListVPCEConfigurationsRequest,
+            ) -> ::std::result::Result<
+                ListVPCEConfigurationsResult,
+                RusotoError<ListVPCEConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_vpce_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `purchase_offering` with what `handler` returns for its input.
+    pub fn with_purchase_offering<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                PurchaseOfferingRequest,
+            )
+                -> ::std::result::Result<PurchaseOfferingResult, RusotoError<PurchaseOfferingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.purchase_offering = Some(Box::new(handler));
+        self
+    }
+    /// Answers `renew_offering` with what `handler` returns for its input.
+    pub fn with_renew_offering<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                RenewOfferingRequest,
+            )
+                -> ::std::result::Result<RenewOfferingResult, RusotoError<RenewOfferingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.renew_offering = Some(Box::new(handler));
+        self
+    }
+    /// Answers `schedule_run` with what `handler` returns for its input.
+    pub fn with_schedule_run<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                ScheduleRunRequest,
+            )
+                -> ::std::result::Result<ScheduleRunResult, RusotoError<ScheduleRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.schedule_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_job` with what `handler` returns for its input.
+    pub fn with_stop_job<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(StopJobRequest) -> ::std::result::Result<StopJobResult, RusotoError<StopJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_remote_access_session` with what `handler` returns for its input.
+    pub fn with_stop_remote_access_session<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                StopRemoteAccessSessionRequest,
+            ) -> ::std::result::Result<
+                StopRemoteAccessSessionResult,
+                RusotoError<StopRemoteAccessSessionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_remote_access_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_run` with what `handler` returns for its input.
+    pub fn with_stop_run<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(StopRunRequest) -> ::std::result::Result<StopRunResult, RusotoError<StopRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_device_instance` with what `handler` returns for its input.
+    pub fn with_update_device_instance<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                UpdateDeviceInstanceRequest,
+            ) -> ::std::result::Result<
+                UpdateDeviceInstanceResult,
+                RusotoError<UpdateDeviceInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_device_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_device_pool` with what `handler` returns for its input.
+    pub fn with_update_device_pool<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                UpdateDevicePoolRequest,
+            )
+                -> ::std::result::Result<UpdateDevicePoolResult, RusotoError<UpdateDevicePoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_device_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_instance_profile` with what `handler` returns for its input.
+    pub fn with_update_instance_profile<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                UpdateInstanceProfileRequest,
+            ) -> ::std::result::Result<
+                UpdateInstanceProfileResult,
+                RusotoError<UpdateInstanceProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_instance_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_network_profile` with what `handler` returns for its input.
+    pub fn with_update_network_profile<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                UpdateNetworkProfileRequest,
+            ) -> ::std::result::Result<
+                UpdateNetworkProfileResult,
+                RusotoError<UpdateNetworkProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_network_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_project` with what `handler` returns for its input.
+    pub fn with_update_project<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                UpdateProjectRequest,
+            )
+                -> ::std::result::Result<UpdateProjectResult, RusotoError<UpdateProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_upload` with what `handler` returns for its input.
+    pub fn with_update_upload<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                UpdateUploadRequest,
+            )
+                -> ::std::result::Result<UpdateUploadResult, RusotoError<UpdateUploadError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_vpce_configuration` with what `handler` returns for its input.
+    pub fn with_update_vpce_configuration<F>(mut self, handler: F) -> MockDeviceFarm
+    where
+        F: Fn(
+                UpdateVPCEConfigurationRequest,
+            ) -> ::std::result::Result<
+                UpdateVPCEConfigurationResult,
+                RusotoError<UpdateVPCEConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_vpce_configuration = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl DeviceFarm for MockDeviceFarm {
+    fn create_device_pool(
+        &self,
+        input: CreateDevicePoolRequest,
+    ) -> RusotoFuture<CreateDevicePoolResult, CreateDevicePoolError> {
+        match self.create_device_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for create_device_pool"),
+        }
+    }
+    fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> RusotoFuture<CreateInstanceProfileResult, CreateInstanceProfileError> {
+        match self.create_instance_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for create_instance_profile"),
+        }
+    }
+    fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> RusotoFuture<CreateNetworkProfileResult, CreateNetworkProfileError> {
+        match self.create_network_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for create_network_profile"),
+        }
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        match self.create_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for create_project"),
+        }
+    }
+    fn create_remote_access_session(
+        &self,
+        input: CreateRemoteAccessSessionRequest,
+    ) -> RusotoFuture<CreateRemoteAccessSessionResult, CreateRemoteAccessSessionError> {
+        match self.create_remote_access_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for create_remote_access_session"),
+        }
+    }
+    fn create_upload(
+        &self,
+        input: CreateUploadRequest,
+    ) -> RusotoFuture<CreateUploadResult, CreateUploadError> {
+        match self.create_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for create_upload"),
+        }
+    }
+    fn create_vpce_configuration(
+        &self,
+        input: CreateVPCEConfigurationRequest,
+    ) -> RusotoFuture<CreateVPCEConfigurationResult, CreateVPCEConfigurationError> {
+        match self.create_vpce_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for create_vpce_configuration"),
+        }
+    }
+    fn delete_device_pool(
+        &self,
+        input: DeleteDevicePoolRequest,
+    ) -> RusotoFuture<DeleteDevicePoolResult, DeleteDevicePoolError> {
+        match self.delete_device_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for delete_device_pool"),
+        }
+    }
+    fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> RusotoFuture<DeleteInstanceProfileResult, DeleteInstanceProfileError> {
+        match self.delete_instance_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for delete_instance_profile"),
+        }
+    }
+    fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> RusotoFuture<DeleteNetworkProfileResult, DeleteNetworkProfileError> {
+        match self.delete_network_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for delete_network_profile"),
+        }
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        match self.delete_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for delete_project"),
+        }
+    }
+    fn delete_remote_access_session(
+        &self,
+        input: DeleteRemoteAccessSessionRequest,
+    ) -> RusotoFuture<DeleteRemoteAccessSessionResult, DeleteRemoteAccessSessionError> {
+        match self.delete_remote_access_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for delete_remote_access_session"),
+        }
+    }
+    fn delete_run(&self, input: DeleteRunRequest) -> RusotoFuture<DeleteRunResult, DeleteRunError> {
+        match self.delete_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for delete_run"),
+        }
+    }
+    fn delete_upload(
+        &self,
+        input: DeleteUploadRequest,
+    ) -> RusotoFuture<DeleteUploadResult, DeleteUploadError> {
+        match self.delete_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for delete_upload"),
+        }
+    }
+    fn delete_vpce_configuration(
+        &self,
+        input: DeleteVPCEConfigurationRequest,
+    ) -> RusotoFuture<DeleteVPCEConfigurationResult, DeleteVPCEConfigurationError> {
+        match self.delete_vpce_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for delete_vpce_configuration"),
+        }
+    }
+    fn get_account_settings(
+        &self,
+    ) -> RusotoFuture<GetAccountSettingsResult, GetAccountSettingsError> {
+        match self.get_account_settings {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockDeviceFarm has no handler for get_account_settings"),
+        }
+    }
+    fn get_device(&self, input: GetDeviceRequest) -> RusotoFuture<GetDeviceResult, GetDeviceError> {
+        match self.get_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_device"),
+        }
+    }
+    fn get_device_instance(
+        &self,
+        input: GetDeviceInstanceRequest,
+    ) -> RusotoFuture<GetDeviceInstanceResult, GetDeviceInstanceError> {
+        match self.get_device_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_device_instance"),
+        }
+    }
+    fn get_device_pool(
+        &self,
+        input: GetDevicePoolRequest,
+    ) -> RusotoFuture<GetDevicePoolResult, GetDevicePoolError> {
+        match self.get_device_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_device_pool"),
+        }
+    }
+    fn get_device_pool_compatibility(
+        &self,
+        input: GetDevicePoolCompatibilityRequest,
+    ) -> RusotoFuture<GetDevicePoolCompatibilityResult, GetDevicePoolCompatibilityError> {
+        match self.get_device_pool_compatibility {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_device_pool_compatibility"),
+        }
+    }
+    fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> RusotoFuture<GetInstanceProfileResult, GetInstanceProfileError> {
+        match self.get_instance_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_instance_profile"),
+        }
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        match self.get_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_job"),
+        }
+    }
+    fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> RusotoFuture<GetNetworkProfileResult, GetNetworkProfileError> {
+        match self.get_network_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_network_profile"),
+        }
+    }
+    fn get_offering_status(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> RusotoFuture<GetOfferingStatusResult, GetOfferingStatusError> {
+        match self.get_offering_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_offering_status"),
+        }
+    }
+    fn get_project(
+        &self,
+        input: GetProjectRequest,
+    ) -> RusotoFuture<GetProjectResult, GetProjectError> {
+        match self.get_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_project"),
+        }
+    }
+    fn get_remote_access_session(
+        &self,
+        input: GetRemoteAccessSessionRequest,
+    ) -> RusotoFuture<GetRemoteAccessSessionResult, GetRemoteAccessSessionError> {
+        match self.get_remote_access_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_remote_access_session"),
+        }
+    }
+    fn get_run(&self, input: GetRunRequest) -> RusotoFuture<GetRunResult, GetRunError> {
+        match self.get_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_run"),
+        }
+    }
+    fn get_suite(&self, input: GetSuiteRequest) -> RusotoFuture<GetSuiteResult, GetSuiteError> {
+        match self.get_suite {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_suite"),
+        }
+    }
+    fn get_test(&self, input: GetTestRequest) -> RusotoFuture<GetTestResult, GetTestError> {
+        match self.get_test {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_test"),
+        }
+    }
+    fn get_upload(&self, input: GetUploadRequest) -> RusotoFuture<GetUploadResult, GetUploadError> {
+        match self.get_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_upload"),
+        }
+    }
+    fn get_vpce_configuration(
+        &self,
+        input: GetVPCEConfigurationRequest,
+    ) -> RusotoFuture<GetVPCEConfigurationResult, GetVPCEConfigurationError> {
+        match self.get_vpce_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for get_vpce_configuration"),
+        }
+    }
+    fn install_to_remote_access_session(
+        &self,
+        input: InstallToRemoteAccessSessionRequest,
+    ) -> RusotoFuture<InstallToRemoteAccessSessionResult, InstallToRemoteAccessSessionError> {
+        match self.install_to_remote_access_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for install_to_remote_access_session"),
+        }
+    }
+    fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> RusotoFuture<ListArtifactsResult, ListArtifactsError> {
+        match self.list_artifacts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_artifacts"),
+        }
+    }
+    fn list_device_instances(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> RusotoFuture<ListDeviceInstancesResult, ListDeviceInstancesError> {
+        match self.list_device_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_device_instances"),
+        }
     }
-
-    /// <p>Gets information about device pools.</p>
     fn list_device_pools(
         &self,
         input: ListDevicePoolsRequest,
     ) -> RusotoFuture<ListDevicePoolsResult, ListDevicePoolsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevicePools");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDevicePoolsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDevicePoolsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_device_pools {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_device_pools"),
+        }
     }
-
-    /// <p>Gets information about unique device types.</p>
     fn list_devices(
         &self,
         input: ListDevicesRequest,
     ) -> RusotoFuture<ListDevicesResult, ListDevicesError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevices");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDevicesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDevicesError::from_response(response))),
-                )
-            }
-        })
+        match self.list_devices {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_devices"),
+        }
     }
-
-    /// <p>Returns information about all the instance profiles in an AWS account.</p>
     fn list_instance_profiles(
         &self,
         input: ListInstanceProfilesRequest,
     ) -> RusotoFuture<ListInstanceProfilesResult, ListInstanceProfilesError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListInstanceProfiles");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListInstanceProfilesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListInstanceProfilesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_instance_profiles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_instance_profiles"),
+        }
     }
-
-    /// <p>Gets information about jobs for a given test run.</p>
     fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListJobs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListJobsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_jobs"),
+        }
     }
-
-    /// <p>Returns the list of available network profiles.</p>
     fn list_network_profiles(
         &self,
         input: ListNetworkProfilesRequest,
     ) -> RusotoFuture<ListNetworkProfilesResult, ListNetworkProfilesError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListNetworkProfiles");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListNetworkProfilesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListNetworkProfilesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_network_profiles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_network_profiles"),
+        }
     }
-
-    /// <p>Returns a list of offering promotions. Each offering promotion record contains the ID and description of the promotion. The API returns a <code>NotEligible</code> error if the caller is not permitted to invoke the operation. Contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
     fn list_offering_promotions(
         &self,
         input: ListOfferingPromotionsRequest,
     ) -> RusotoFuture<ListOfferingPromotionsResult, ListOfferingPromotionsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferingPromotions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOfferingPromotionsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListOfferingPromotionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_offering_promotions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_offering_promotions"),
+        }
     }
-
-    /// <p>Returns a list of all historical purchases, renewals, and system renewal transactions for an AWS account. The list is paginated and ordered by a descending timestamp (most recent transactions are first). The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
     fn list_offering_transactions(
         &self,
         input: ListOfferingTransactionsRequest,
     ) -> RusotoFuture<ListOfferingTransactionsResult, ListOfferingTransactionsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DeviceFarm_20150623.ListOfferingTransactions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOfferingTransactionsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListOfferingTransactionsError::from_response(response))
-                }))
-            }
-        })
+        match self.list_offering_transactions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_offering_transactions"),
+        }
     }
-
-    /// <p>Returns a list of products or offerings that the user can manage through the API. Each offering record indicates the recurring price per unit and the frequency for that offering. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
     fn list_offerings(
         &self,
         input: ListOfferingsRequest,
     ) -> RusotoFuture<ListOfferingsResult, ListOfferingsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferings");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOfferingsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListOfferingsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_offerings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_offerings"),
+        }
     }
-
-    /// <p>Gets information about projects.</p>
     fn list_projects(
         &self,
         input: ListProjectsRequest,
     ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListProjects");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListProjectsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListProjectsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_projects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_projects"),
+        }
     }
-
-    /// <p>Returns a list of all currently running remote access sessions.</p>
     fn list_remote_access_sessions(
         &self,
         input: ListRemoteAccessSessionsRequest,
     ) -> RusotoFuture<ListRemoteAccessSessionsResult, ListRemoteAccessSessionsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DeviceFarm_20150623.ListRemoteAccessSessions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRemoteAccessSessionsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListRemoteAccessSessionsError::from_response(response))
-                }))
-            }
-        })
-    }
-
-    /// <p>Gets information about runs, given an AWS Device Farm project ARN.</p>
-    fn list_runs(&self, input: ListRunsRequest) -> RusotoFuture<ListRunsResult, ListRunsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListRuns");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ListRunsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRunsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_remote_access_sessions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_remote_access_sessions"),
+        }
+    }
+    fn list_runs(&self, input: ListRunsRequest) -> RusotoFuture<ListRunsResult, ListRunsError> {
+        match self.list_runs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_runs"),
+        }
     }
-
-    /// <p>Gets information about samples, given an AWS Device Farm job ARN.</p>
     fn list_samples(
         &self,
         input: ListSamplesRequest,
     ) -> RusotoFuture<ListSamplesResult, ListSamplesError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListSamples");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSamplesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListSamplesError::from_response(response))),
-                )
-            }
-        })
+        match self.list_samples {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_samples"),
+        }
     }
-
-    /// <p>Gets information about test suites for a given job.</p>
     fn list_suites(
         &self,
         input: ListSuitesRequest,
     ) -> RusotoFuture<ListSuitesResult, ListSuitesError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListSuites");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSuitesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListSuitesError::from_response(response))),
-                )
-            }
-        })
+        match self.list_suites {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_suites"),
+        }
     }
-
-    /// <p>List the tags for an AWS Device Farm resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_tags_for_resource"),
+        }
     }
-
-    /// <p>Gets information about tests in a given test suite.</p>
     fn list_tests(&self, input: ListTestsRequest) -> RusotoFuture<ListTestsResult, ListTestsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListTests");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ListTestsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTestsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_tests {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_tests"),
+        }
     }
-
-    /// <p>Gets information about unique problems.</p>
     fn list_unique_problems(
         &self,
         input: ListUniqueProblemsRequest,
     ) -> RusotoFuture<ListUniqueProblemsResult, ListUniqueProblemsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListUniqueProblems");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUniqueProblemsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListUniqueProblemsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_unique_problems {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_unique_problems"),
+        }
     }
-
-    /// <p>Gets information about uploads, given an AWS Device Farm project ARN.</p>
     fn list_uploads(
         &self,
         input: ListUploadsRequest,
     ) -> RusotoFuture<ListUploadsResult, ListUploadsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListUploads");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUploadsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListUploadsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_uploads {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_uploads"),
+        }
     }
-
-    /// <p>Returns information about all Amazon Virtual Private Cloud (VPC) endpoint configurations in the AWS account.</p>
     fn list_vpce_configurations(
         &self,
         input: ListVPCEConfigurationsRequest,
     ) -> RusotoFuture<ListVPCEConfigurationsResult, ListVPCEConfigurationsError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ListVPCEConfigurations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVPCEConfigurationsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListVPCEConfigurationsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_vpce_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for list_vpce_configurations"),
+        }
     }
-
-    /// <p>Immediately purchases offerings for an AWS account. Offerings renew with the latest total purchased quantity for an offering, unless the renewal was overridden. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
     fn purchase_offering(
         &self,
         input: PurchaseOfferingRequest,
     ) -> RusotoFuture<PurchaseOfferingResult, PurchaseOfferingError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.PurchaseOffering");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PurchaseOfferingResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PurchaseOfferingError::from_response(response))),
-                )
-            }
-        })
+        match self.purchase_offering {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for purchase_offering"),
+        }
     }
-
-    /// <p>Explicitly sets the quantity of devices to renew for an offering, starting from the <code>effectiveDate</code> of the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
     fn renew_offering(
         &self,
         input: RenewOfferingRequest,
     ) -> RusotoFuture<RenewOfferingResult, RenewOfferingError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.RenewOffering");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RenewOfferingResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RenewOfferingError::from_response(response))),
-                )
-            }
-        })
+        match self.renew_offering {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for renew_offering"),
+        }
     }
-
-    /// <p>Schedules a run.</p>
     fn schedule_run(
         &self,
-        input: ScheduleRunRequest,
-    ) -> RusotoFuture<ScheduleRunResult, ScheduleRunError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.ScheduleRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ScheduleRunResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ScheduleRunError::from_response(response))),
-                )
-            }
-        })
-    }
-
-    /// <p>Initiates a stop request for the current job. AWS Device Farm will immediately stop the job on the device where tests have not started executing, and you will not be billed for this device. On the device where tests have started executing, Setup Suite and Teardown Suite tests will run to completion before stopping execution on the device. You will be billed for Setup, Teardown, and any tests that were in progress or already completed.</p>
-    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.StopJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<StopJobResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopJobError::from_response(response))),
-                )
-            }
-        })
+        input: ScheduleRunRequest,
+    ) -> RusotoFuture<ScheduleRunResult, ScheduleRunError> {
+        match self.schedule_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for schedule_run"),
+        }
+    }
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        match self.stop_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for stop_job"),
+        }
     }
-
-    /// <p>Ends a specified remote access session.</p>
     fn stop_remote_access_session(
         &self,
         input: StopRemoteAccessSessionRequest,
     ) -> RusotoFuture<StopRemoteAccessSessionResult, StopRemoteAccessSessionError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DeviceFarm_20150623.StopRemoteAccessSession",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopRemoteAccessSessionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopRemoteAccessSessionError::from_response(response))
-                }))
-            }
-        })
+        match self.stop_remote_access_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for stop_remote_access_session"),
+        }
     }
-
-    /// <p>Initiates a stop request for the current test run. AWS Device Farm will immediately stop the run on devices where tests have not started executing, and you will not be billed for these devices. On devices where tests have started executing, Setup Suite and Teardown Suite tests will run to completion before stopping execution on those devices. You will be billed for Setup, Teardown, and any tests that were in progress or already completed.</p>
     fn stop_run(&self, input: StopRunRequest) -> RusotoFuture<StopRunResult, StopRunError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.StopRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<StopRunResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopRunError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for stop_run"),
+        }
     }
-
-    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Deletes the specified tags from a resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Updates information about an existing private device instance.</p>
     fn update_device_instance(
         &self,
         input: UpdateDeviceInstanceRequest,
     ) -> RusotoFuture<UpdateDeviceInstanceResult, UpdateDeviceInstanceError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDeviceInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDeviceInstanceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateDeviceInstanceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_device_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for update_device_instance"),
+        }
     }
-
-    /// <p>Modifies the name, description, and rules in a device pool given the attributes and the pool ARN. Rule updates are all-or-nothing, meaning they can only be updated as a whole (or not at all).</p>
     fn update_device_pool(
         &self,
         input: UpdateDevicePoolRequest,
     ) -> RusotoFuture<UpdateDevicePoolResult, UpdateDevicePoolError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDevicePool");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDevicePoolResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDevicePoolError::from_response(response))),
-                )
-            }
-        })
+        match self.update_device_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for update_device_pool"),
+        }
     }
-
-    /// <p>Updates information about an existing private device instance profile.</p>
     fn update_instance_profile(
         &self,
         input: UpdateInstanceProfileRequest,
     ) -> RusotoFuture<UpdateInstanceProfileResult, UpdateInstanceProfileError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateInstanceProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateInstanceProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateInstanceProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_instance_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for update_instance_profile"),
+        }
     }
-
-    /// <p>Updates the network profile with specific settings.</p>
     fn update_network_profile(
         &self,
         input: UpdateNetworkProfileRequest,
     ) -> RusotoFuture<UpdateNetworkProfileResult, UpdateNetworkProfileError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateNetworkProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateNetworkProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateNetworkProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_network_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for update_network_profile"),
+        }
     }
-
-    /// <p>Modifies the specified project name, given the project ARN and a new name.</p>
     fn update_project(
         &self,
         input: UpdateProjectRequest,
     ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateProject");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateProjectResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateProjectError::from_response(response))),
-                )
-            }
-        })
+        match self.update_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for update_project"),
+        }
     }
-
-    /// <p>Update an uploaded test specification (test spec).</p>
     fn update_upload(
         &self,
         input: UpdateUploadRequest,
     ) -> RusotoFuture<UpdateUploadResult, UpdateUploadError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateUpload");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateUploadResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateUploadError::from_response(response))),
-                )
-            }
-        })
+        match self.update_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for update_upload"),
+        }
     }
-
-    /// <p>Updates information about an existing Amazon Virtual Private Cloud (VPC) endpoint configuration.</p>
     fn update_vpce_configuration(
         &self,
         input: UpdateVPCEConfigurationRequest,
     ) -> RusotoFuture<UpdateVPCEConfigurationResult, UpdateVPCEConfigurationError> {
-        let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DeviceFarm_20150623.UpdateVPCEConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateVPCEConfigurationResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateVPCEConfigurationError::from_response(response))
-                }))
-            }
-        })
+        match self.update_vpce_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDeviceFarm has no handler for update_vpce_configuration"),
+        }
     }
 }
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptDirectConnectGatewayAssociationProposalRequest {
     /// <p>The ID of the AWS account that owns the virtual private gateway or transit gateway.</p>
     #[serde(rename = "associatedGatewayOwnerAccount")]
@@ -41,7 +43,7 @@ pub struct AcceptDirectConnectGatewayAssociationProposalRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcceptDirectConnectGatewayAssociationProposalResult {
     #[serde(rename = "directConnectGatewayAssociation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -49,6 +51,7 @@ pub struct AcceptDirectConnectGatewayAssociationProposalResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AllocateConnectionOnInterconnectRequest {
     /// <p>The bandwidth of the connection. The possible values are 50Mbps, 100Mbps, 200Mbps, 300Mbps, 400Mbps, 500Mbps, 1Gbps, 2Gbps, 5Gbps, and 10Gbps. Note that only those AWS Direct Connect Partners who have met specific requirements are allowed to create a 1Gbps, 2Gbps, 5Gbps or 10Gbps hosted connection.</p>
     #[serde(rename = "bandwidth")]
@@ -68,6 +71,7 @@ pub struct AllocateConnectionOnInterconnectRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AllocateHostedConnectionRequest {
     /// <p>The bandwidth of the connection. The possible values are 50Mbps, 100Mbps, 200Mbps, 300Mbps, 400Mbps, 500Mbps, 1Gbps, 2Gbps, 5Gbps, and 10Gbps. Note that only those AWS Direct Connect Partners who have met specific requirements are allowed to create a 1Gbps, 2Gbps, 5Gbps or 10Gbps hosted connection. </p>
     #[serde(rename = "bandwidth")]
@@ -90,7 +94,20 @@ pub struct AllocateHostedConnectionRequest {
     pub vlan: i64,
 }
 
+impl Validate for AllocateHostedConnectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AllocatePrivateVirtualInterfaceRequest {
     /// <p>The ID of the connection on which the private virtual interface is provisioned.</p>
     #[serde(rename = "connectionId")]
@@ -103,7 +120,17 @@ pub struct AllocatePrivateVirtualInterfaceRequest {
     pub owner_account: String,
 }
 
+impl Validate for AllocatePrivateVirtualInterfaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.new_private_virtual_interface_allocation.validate_at(
+            &field_path(path, "new_private_virtual_interface_allocation"),
+            errors,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AllocatePublicVirtualInterfaceRequest {
     /// <p>The ID of the connection on which the public virtual interface is provisioned.</p>
     #[serde(rename = "connectionId")]
@@ -116,7 +143,17 @@ pub struct AllocatePublicVirtualInterfaceRequest {
     pub owner_account: String,
 }
 
+impl Validate for AllocatePublicVirtualInterfaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.new_public_virtual_interface_allocation.validate_at(
+            &field_path(path, "new_public_virtual_interface_allocation"),
+            errors,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AllocateTransitVirtualInterfaceRequest {
     /// <p>The ID of the connection on which the transit virtual interface is provisioned.</p>
     #[serde(rename = "connectionId")]
@@ -129,8 +166,17 @@ pub struct AllocateTransitVirtualInterfaceRequest {
     pub owner_account: String,
 }
 
+impl Validate for AllocateTransitVirtualInterfaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.new_transit_virtual_interface_allocation.validate_at(
+            &field_path(path, "new_transit_virtual_interface_allocation"),
+            errors,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AllocateTransitVirtualInterfaceResult {
     #[serde(rename = "virtualInterface")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -138,6 +184,7 @@ pub struct AllocateTransitVirtualInterfaceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateConnectionWithLagRequest {
     /// <p>The ID of the connection.</p>
     #[serde(rename = "connectionId")]
@@ -148,6 +195,7 @@ pub struct AssociateConnectionWithLagRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateHostedConnectionRequest {
     /// <p>The ID of the hosted connection.</p>
     #[serde(rename = "connectionId")]
@@ -158,6 +206,7 @@ pub struct AssociateHostedConnectionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateVirtualInterfaceRequest {
     /// <p>The ID of the LAG or connection.</p>
     #[serde(rename = "connectionId")]
@@ -169,7 +218,7 @@ pub struct AssociateVirtualInterfaceRequest {
 
 /// <p>Information about the associated gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociatedGateway {
     /// <p>The ID of the associated gateway.</p>
     #[serde(rename = "id")]
@@ -191,7 +240,7 @@ pub struct AssociatedGateway {
 
 /// <p>Information about a BGP peer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BGPPeer {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
@@ -232,6 +281,7 @@ pub struct BGPPeer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfirmConnectionRequest {
     /// <p>The ID of the hosted connection.</p>
     #[serde(rename = "connectionId")]
@@ -239,7 +289,7 @@ pub struct ConfirmConnectionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfirmConnectionResponse {
     /// <p><p>The state of the connection. The following are the possible values:</p> <ul> <li> <p> <code>ordering</code>: The initial state of a hosted connection provisioned on an interconnect. The connection stays in the ordering state until the owner of the hosted connection confirms or declines the connection order.</p> </li> <li> <p> <code>requested</code>: The initial state of a standard connection. The connection stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The connection has been approved and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up and the connection is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The connection is being deleted.</p> </li> <li> <p> <code>deleted</code>: The connection has been deleted.</p> </li> <li> <p> <code>rejected</code>: A hosted connection in the <code>ordering</code> state enters the <code>rejected</code> state if it is deleted by the customer.</p> </li> <li> <p> <code>unknown</code>: The state of the connection is not available.</p> </li> </ul></p>
     #[serde(rename = "connectionState")]
@@ -248,6 +298,7 @@ pub struct ConfirmConnectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfirmPrivateVirtualInterfaceRequest {
     /// <p>The ID of the Direct Connect gateway.</p>
     #[serde(rename = "directConnectGatewayId")]
@@ -263,7 +314,7 @@ pub struct ConfirmPrivateVirtualInterfaceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfirmPrivateVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
@@ -272,6 +323,7 @@ pub struct ConfirmPrivateVirtualInterfaceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfirmPublicVirtualInterfaceRequest {
     /// <p>The ID of the virtual interface.</p>
     #[serde(rename = "virtualInterfaceId")]
@@ -279,7 +331,7 @@ pub struct ConfirmPublicVirtualInterfaceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfirmPublicVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
@@ -288,6 +340,7 @@ pub struct ConfirmPublicVirtualInterfaceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfirmTransitVirtualInterfaceRequest {
     /// <p>The ID of the Direct Connect gateway.</p>
     #[serde(rename = "directConnectGatewayId")]
@@ -298,7 +351,7 @@ pub struct ConfirmTransitVirtualInterfaceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfirmTransitVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
@@ -308,7 +361,7 @@ pub struct ConfirmTransitVirtualInterfaceResponse {
 
 /// <p>Information about an AWS Direct Connect connection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Connection {
     /// <p>The Direct Connect endpoint on which the physical connection terminates.</p>
     #[serde(rename = "awsDevice")]
@@ -377,7 +430,7 @@ pub struct Connection {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Connections {
     /// <p>The connections.</p>
     #[serde(rename = "connections")]
@@ -386,6 +439,7 @@ pub struct Connections {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBGPPeerRequest {
     /// <p>Information about the BGP peer.</p>
     #[serde(rename = "newBGPPeer")]
@@ -398,7 +452,7 @@ pub struct CreateBGPPeerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBGPPeerResponse {
     /// <p>The virtual interface.</p>
     #[serde(rename = "virtualInterface")]
@@ -407,6 +461,7 @@ pub struct CreateBGPPeerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConnectionRequest {
     /// <p>The bandwidth of the connection.</p>
     #[serde(rename = "bandwidth")]
@@ -427,7 +482,20 @@ pub struct CreateConnectionRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateConnectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDirectConnectGatewayAssociationProposalRequest {
     /// <p>The Amazon VPC prefixes to advertise to the Direct Connect gateway.</p>
     #[serde(rename = "addAllowedPrefixesToDirectConnectGateway")]
@@ -449,7 +517,7 @@ pub struct CreateDirectConnectGatewayAssociationProposalRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDirectConnectGatewayAssociationProposalResult {
     /// <p>Information about the Direct Connect gateway proposal.</p>
     #[serde(rename = "directConnectGatewayAssociationProposal")]
@@ -459,6 +527,7 @@ pub struct CreateDirectConnectGatewayAssociationProposalResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDirectConnectGatewayAssociationRequest {
     /// <p>The Amazon VPC prefixes to advertise to the Direct Connect gateway</p> <p>For information about how to set the prefixes, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/multi-account-associate-vgw.html#allowed-prefixes">Allowed Prefixes</a> in the <i>AWS Direct Connect User Guide</i>.</p>
     #[serde(rename = "addAllowedPrefixesToDirectConnectGateway")]
@@ -478,7 +547,7 @@ pub struct CreateDirectConnectGatewayAssociationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDirectConnectGatewayAssociationResult {
     /// <p>The association to be created.</p>
     #[serde(rename = "directConnectGatewayAssociation")]
@@ -487,6 +556,7 @@ pub struct CreateDirectConnectGatewayAssociationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDirectConnectGatewayRequest {
     /// <p>The autonomous system number (ASN) for Border Gateway Protocol (BGP) to be configured on the Amazon side of the connection. The ASN must be in the private range of 64,512 to 65,534 or 4,200,000,000 to 4,294,967,294. The default is 64512.</p>
     #[serde(rename = "amazonSideAsn")]
@@ -498,7 +568,7 @@ pub struct CreateDirectConnectGatewayRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDirectConnectGatewayResult {
     /// <p>The Direct Connect gateway.</p>
     #[serde(rename = "directConnectGateway")]
@@ -507,6 +577,7 @@ pub struct CreateDirectConnectGatewayResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInterconnectRequest {
     /// <p>The port bandwidth, in Gbps. The possible values are 1 and 10.</p>
     #[serde(rename = "bandwidth")]
@@ -527,7 +598,20 @@ pub struct CreateInterconnectRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateInterconnectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLagRequest {
     /// <p>The tags to assign to the child connections of the LAG. Only newly created child connections as the result of creating a LAG connection are assigned the provided tags. The tags are not assigned to an existing connection that is provided via the “connectionId” parameter that will be migrated to the LAG.</p>
     #[serde(rename = "childConnectionTags")]
@@ -555,7 +639,32 @@ pub struct CreateLagRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateLagRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.child_connection_tags {
+            errors.check_length(
+                &field_path(path, "child_connection_tags"),
+                value.len(),
+                Some(1),
+                None,
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "child_connection_tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePrivateVirtualInterfaceRequest {
     /// <p>The ID of the connection.</p>
     #[serde(rename = "connectionId")]
@@ -565,7 +674,15 @@ pub struct CreatePrivateVirtualInterfaceRequest {
     pub new_private_virtual_interface: NewPrivateVirtualInterface,
 }
 
+impl Validate for CreatePrivateVirtualInterfaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.new_private_virtual_interface
+            .validate_at(&field_path(path, "new_private_virtual_interface"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePublicVirtualInterfaceRequest {
     /// <p>The ID of the connection.</p>
     #[serde(rename = "connectionId")]
@@ -575,7 +692,15 @@ pub struct CreatePublicVirtualInterfaceRequest {
     pub new_public_virtual_interface: NewPublicVirtualInterface,
 }
 
+impl Validate for CreatePublicVirtualInterfaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.new_public_virtual_interface
+            .validate_at(&field_path(path, "new_public_virtual_interface"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTransitVirtualInterfaceRequest {
     /// <p>The ID of the connection.</p>
     #[serde(rename = "connectionId")]
@@ -585,8 +710,15 @@ pub struct CreateTransitVirtualInterfaceRequest {
     pub new_transit_virtual_interface: NewTransitVirtualInterface,
 }
 
+impl Validate for CreateTransitVirtualInterfaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.new_transit_virtual_interface
+            .validate_at(&field_path(path, "new_transit_virtual_interface"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTransitVirtualInterfaceResult {
     #[serde(rename = "virtualInterface")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -594,6 +726,7 @@ pub struct CreateTransitVirtualInterfaceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBGPPeerRequest {
     /// <p>The autonomous system (AS) number for Border Gateway Protocol (BGP) configuration.</p>
     #[serde(rename = "asn")]
@@ -614,7 +747,7 @@ pub struct DeleteBGPPeerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBGPPeerResponse {
     /// <p>The virtual interface.</p>
     #[serde(rename = "virtualInterface")]
@@ -623,6 +756,7 @@ pub struct DeleteBGPPeerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConnectionRequest {
     /// <p>The ID of the connection.</p>
     #[serde(rename = "connectionId")]
@@ -630,6 +764,7 @@ pub struct DeleteConnectionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDirectConnectGatewayAssociationProposalRequest {
     /// <p>The ID of the proposal.</p>
     #[serde(rename = "proposalId")]
@@ -637,7 +772,7 @@ pub struct DeleteDirectConnectGatewayAssociationProposalRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDirectConnectGatewayAssociationProposalResult {
     /// <p>The ID of the associated gateway.</p>
     #[serde(rename = "directConnectGatewayAssociationProposal")]
@@ -647,6 +782,7 @@ pub struct DeleteDirectConnectGatewayAssociationProposalResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDirectConnectGatewayAssociationRequest {
     /// <p>The ID of the Direct Connect gateway association.</p>
     #[serde(rename = "associationId")]
@@ -663,7 +799,7 @@ pub struct DeleteDirectConnectGatewayAssociationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDirectConnectGatewayAssociationResult {
     /// <p>Information about the deleted association.</p>
     #[serde(rename = "directConnectGatewayAssociation")]
@@ -672,6 +808,7 @@ pub struct DeleteDirectConnectGatewayAssociationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDirectConnectGatewayRequest {
     /// <p>The ID of the Direct Connect gateway.</p>
     #[serde(rename = "directConnectGatewayId")]
@@ -679,7 +816,7 @@ pub struct DeleteDirectConnectGatewayRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDirectConnectGatewayResult {
     /// <p>The Direct Connect gateway.</p>
     #[serde(rename = "directConnectGateway")]
@@ -688,6 +825,7 @@ pub struct DeleteDirectConnectGatewayResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInterconnectRequest {
     /// <p>The ID of the interconnect.</p>
     #[serde(rename = "interconnectId")]
@@ -695,7 +833,7 @@ pub struct DeleteInterconnectRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteInterconnectResponse {
     /// <p><p>The state of the interconnect. The following are the possible values:</p> <ul> <li> <p> <code>requested</code>: The initial state of an interconnect. The interconnect stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The interconnect is approved, and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up, and the interconnect is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The interconnect is being deleted.</p> </li> <li> <p> <code>deleted</code>: The interconnect is deleted.</p> </li> <li> <p> <code>unknown</code>: The state of the interconnect is not available.</p> </li> </ul></p>
     #[serde(rename = "interconnectState")]
@@ -704,6 +842,7 @@ pub struct DeleteInterconnectResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLagRequest {
     /// <p>The ID of the LAG.</p>
     #[serde(rename = "lagId")]
@@ -711,6 +850,7 @@ pub struct DeleteLagRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVirtualInterfaceRequest {
     /// <p>The ID of the virtual interface.</p>
     #[serde(rename = "virtualInterfaceId")]
@@ -718,7 +858,7 @@ pub struct DeleteVirtualInterfaceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
@@ -727,6 +867,7 @@ pub struct DeleteVirtualInterfaceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConnectionLoaRequest {
     /// <p>The ID of the connection.</p>
     #[serde(rename = "connectionId")]
@@ -742,7 +883,7 @@ pub struct DescribeConnectionLoaRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConnectionLoaResponse {
     /// <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA).</p>
     #[serde(rename = "loa")]
@@ -751,6 +892,7 @@ pub struct DescribeConnectionLoaResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConnectionsOnInterconnectRequest {
     /// <p>The ID of the interconnect.</p>
     #[serde(rename = "interconnectId")]
@@ -758,6 +900,7 @@ pub struct DescribeConnectionsOnInterconnectRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConnectionsRequest {
     /// <p>The ID of the connection.</p>
     #[serde(rename = "connectionId")]
@@ -766,6 +909,7 @@ pub struct DescribeConnectionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDirectConnectGatewayAssociationProposalsRequest {
     /// <p>The ID of the associated gateway.</p>
     #[serde(rename = "associatedGatewayId")]
@@ -790,7 +934,7 @@ pub struct DescribeDirectConnectGatewayAssociationProposalsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDirectConnectGatewayAssociationProposalsResult {
     /// <p>Describes the Direct Connect gateway association proposals.</p>
     #[serde(rename = "directConnectGatewayAssociationProposals")]
@@ -804,6 +948,7 @@ pub struct DescribeDirectConnectGatewayAssociationProposalsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDirectConnectGatewayAssociationsRequest {
     /// <p>The ID of the associated gateway.</p>
     #[serde(rename = "associatedGatewayId")]
@@ -832,7 +977,7 @@ pub struct DescribeDirectConnectGatewayAssociationsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDirectConnectGatewayAssociationsResult {
     /// <p>Information about the associations.</p>
     #[serde(rename = "directConnectGatewayAssociations")]
@@ -845,6 +990,7 @@ pub struct DescribeDirectConnectGatewayAssociationsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDirectConnectGatewayAttachmentsRequest {
     /// <p>The ID of the Direct Connect gateway.</p>
     #[serde(rename = "directConnectGatewayId")]
@@ -865,7 +1011,7 @@ pub struct DescribeDirectConnectGatewayAttachmentsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDirectConnectGatewayAttachmentsResult {
     /// <p>The attachments.</p>
     #[serde(rename = "directConnectGatewayAttachments")]
@@ -878,6 +1024,7 @@ pub struct DescribeDirectConnectGatewayAttachmentsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDirectConnectGatewaysRequest {
     /// <p>The ID of the Direct Connect gateway.</p>
     #[serde(rename = "directConnectGatewayId")]
@@ -894,7 +1041,7 @@ pub struct DescribeDirectConnectGatewaysRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDirectConnectGatewaysResult {
     /// <p>The Direct Connect gateways.</p>
     #[serde(rename = "directConnectGateways")]
@@ -907,6 +1054,7 @@ pub struct DescribeDirectConnectGatewaysResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeHostedConnectionsRequest {
     /// <p>The ID of the interconnect or LAG.</p>
     #[serde(rename = "connectionId")]
@@ -914,6 +1062,7 @@ pub struct DescribeHostedConnectionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInterconnectLoaRequest {
     /// <p>The ID of the interconnect.</p>
     #[serde(rename = "interconnectId")]
@@ -929,7 +1078,7 @@ pub struct DescribeInterconnectLoaRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInterconnectLoaResponse {
     /// <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA).</p>
     #[serde(rename = "loa")]
@@ -938,6 +1087,7 @@ pub struct DescribeInterconnectLoaResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInterconnectsRequest {
     /// <p>The ID of the interconnect.</p>
     #[serde(rename = "interconnectId")]
@@ -946,6 +1096,7 @@ pub struct DescribeInterconnectsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLagsRequest {
     /// <p>The ID of the LAG.</p>
     #[serde(rename = "lagId")]
@@ -954,6 +1105,7 @@ pub struct DescribeLagsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoaRequest {
     /// <p>The ID of a connection, LAG, or interconnect.</p>
     #[serde(rename = "connectionId")]
@@ -969,6 +1121,7 @@ pub struct DescribeLoaRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsRequest {
     /// <p>The Amazon Resource Names (ARNs) of the resources.</p>
     #[serde(rename = "resourceArns")]
@@ -976,7 +1129,7 @@ pub struct DescribeTagsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTagsResponse {
     /// <p>Information about the tags.</p>
     #[serde(rename = "resourceTags")]
@@ -985,6 +1138,7 @@ pub struct DescribeTagsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeVirtualInterfacesRequest {
     /// <p>The ID of the connection.</p>
     #[serde(rename = "connectionId")]
@@ -998,7 +1152,7 @@ pub struct DescribeVirtualInterfacesRequest {
 
 /// <p>Information about a Direct Connect gateway, which enables you to connect virtual interfaces and virtual private gateway or transit gateways.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DirectConnectGateway {
     /// <p>The autonomous system number (ASN) for the Amazon side of the connection.</p>
     #[serde(rename = "amazonSideAsn")]
@@ -1028,7 +1182,7 @@ pub struct DirectConnectGateway {
 
 /// <p>Information about an association between a Direct Connect gateway and a virtual private gateway or transit gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DirectConnectGatewayAssociation {
     /// <p>The Amazon VPC prefixes to advertise to the Direct Connect gateway.</p>
     #[serde(rename = "allowedPrefixesToDirectConnectGateway")]
@@ -1074,7 +1228,7 @@ pub struct DirectConnectGatewayAssociation {
 
 /// <p>Information about the proposal request to attach a virtual private gateway to a Direct Connect gateway. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DirectConnectGatewayAssociationProposal {
     /// <p>Information about the associated gateway.</p>
     #[serde(rename = "associatedGateway")]
@@ -1108,7 +1262,7 @@ pub struct DirectConnectGatewayAssociationProposal {
 
 /// <p>Information about an attachment between a Direct Connect gateway and a virtual interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DirectConnectGatewayAttachment {
     /// <p><p>The state of the attachment. The following are the possible values:</p> <ul> <li> <p> <code>attaching</code>: The initial state after a virtual interface is created using the Direct Connect gateway.</p> </li> <li> <p> <code>attached</code>: The Direct Connect gateway and virtual interface are attached and ready to pass traffic.</p> </li> <li> <p> <code>detaching</code>: The initial state after calling <a>DeleteVirtualInterface</a>.</p> </li> <li> <p> <code>detached</code>: The virtual interface is detached from the Direct Connect gateway. Traffic flow between the Direct Connect gateway and virtual interface is stopped.</p> </li> </ul></p>
     #[serde(rename = "attachmentState")]
@@ -1141,6 +1295,7 @@ pub struct DirectConnectGatewayAttachment {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateConnectionFromLagRequest {
     /// <p>The ID of the connection.</p>
     #[serde(rename = "connectionId")]
@@ -1152,7 +1307,7 @@ pub struct DisassociateConnectionFromLagRequest {
 
 /// <p>Information about an interconnect.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Interconnect {
     /// <p>The Direct Connect endpoint on which the physical connection terminates.</p>
     #[serde(rename = "awsDevice")]
@@ -1209,7 +1364,7 @@ pub struct Interconnect {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Interconnects {
     /// <p>The interconnects.</p>
     #[serde(rename = "interconnects")]
@@ -1219,7 +1374,7 @@ pub struct Interconnects {
 
 /// <p>Information about a link aggregation group (LAG).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Lag {
     /// <p>Indicates whether the LAG can host other connections.</p>
     #[serde(rename = "allowsHostedConnections")]
@@ -1288,7 +1443,7 @@ pub struct Lag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Lags {
     /// <p>The LAGs.</p>
     #[serde(rename = "lags")]
@@ -1298,7 +1453,7 @@ pub struct Lags {
 
 /// <p>Information about a Letter of Authorization - Connecting Facility Assignment (LOA-CFA) for a connection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Loa {
     /// <p>The binary contents of the LOA-CFA document.</p>
     #[serde(rename = "loaContent")]
@@ -1317,7 +1472,7 @@ pub struct Loa {
 
 /// <p>Information about an AWS Direct Connect location.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Location {
     /// <p>The available port speeds for the location.</p>
     #[serde(rename = "availablePortSpeeds")]
@@ -1338,7 +1493,7 @@ pub struct Location {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Locations {
     /// <p>The locations.</p>
     #[serde(rename = "locations")]
@@ -1348,6 +1503,7 @@ pub struct Locations {
 
 /// <p>Information about a new BGP peer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NewBGPPeer {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
@@ -1373,6 +1529,7 @@ pub struct NewBGPPeer {
 
 /// <p>Information about a private virtual interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NewPrivateVirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
@@ -1417,8 +1574,21 @@ pub struct NewPrivateVirtualInterface {
     pub vlan: i64,
 }
 
+impl Validate for NewPrivateVirtualInterface {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Information about a private virtual interface to be provisioned on a connection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NewPrivateVirtualInterfaceAllocation {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
@@ -1455,8 +1625,21 @@ pub struct NewPrivateVirtualInterfaceAllocation {
     pub vlan: i64,
 }
 
+impl Validate for NewPrivateVirtualInterfaceAllocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Information about a public virtual interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NewPublicVirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
@@ -1493,8 +1676,21 @@ pub struct NewPublicVirtualInterface {
     pub vlan: i64,
 }
 
+impl Validate for NewPublicVirtualInterface {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Information about a public virtual interface to be provisioned on a connection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NewPublicVirtualInterfaceAllocation {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
@@ -1531,8 +1727,21 @@ pub struct NewPublicVirtualInterfaceAllocation {
     pub vlan: i64,
 }
 
+impl Validate for NewPublicVirtualInterfaceAllocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Information about the transit virtual interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NewTransitVirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
@@ -1576,8 +1785,21 @@ pub struct NewTransitVirtualInterface {
     pub vlan: Option<i64>,
 }
 
+impl Validate for NewTransitVirtualInterface {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Information about a transit virtual interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NewTransitVirtualInterfaceAllocation {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
@@ -1617,9 +1839,21 @@ pub struct NewTransitVirtualInterfaceAllocation {
     pub vlan: Option<i64>,
 }
 
+impl Validate for NewTransitVirtualInterfaceAllocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Information about a tag associated with an AWS Direct Connect resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceTag {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "resourceArn")]
@@ -1652,7 +1886,37 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "resourceArn")]
@@ -1662,11 +1926,22 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), Some(1), None);
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "resourceArn")]
@@ -1676,11 +1951,22 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDirectConnectGatewayAssociationRequest {
     /// <p>The Amazon VPC prefixes to advertise to the Direct Connect gateway.</p>
     #[serde(rename = "addAllowedPrefixesToDirectConnectGateway")]
@@ -1697,7 +1983,7 @@ pub struct UpdateDirectConnectGatewayAssociationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDirectConnectGatewayAssociationResult {
     #[serde(rename = "directConnectGatewayAssociation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1705,6 +1991,7 @@ pub struct UpdateDirectConnectGatewayAssociationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateLagRequest {
     /// <p>The ID of the LAG.</p>
     #[serde(rename = "lagId")]
@@ -1720,6 +2007,7 @@ pub struct UpdateLagRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateVirtualInterfaceAttributesRequest {
     /// <p>The maximum transmission unit (MTU), in bytes. The supported values are 1500 and 9001. The default value is 1500.</p>
     #[serde(rename = "mtu")]
@@ -1732,7 +2020,7 @@ pub struct UpdateVirtualInterfaceAttributesRequest {
 
 /// <p>Information about a virtual private gateway for a private virtual interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualGateway {
     /// <p>The ID of the virtual private gateway.</p>
     #[serde(rename = "virtualGatewayId")]
@@ -1745,7 +2033,7 @@ pub struct VirtualGateway {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualGateways {
     /// <p>The virtual private gateways.</p>
     #[serde(rename = "virtualGateways")]
@@ -1755,7 +2043,7 @@ pub struct VirtualGateways {
 
 /// <p>Information about a virtual interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
@@ -1856,7 +2144,7 @@ pub struct VirtualInterface {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VirtualInterfaces {
     /// <p>The virtual interfaces</p>
     #[serde(rename = "virtualInterfaces")]
@@ -1893,7 +2181,12 @@ impl AcceptDirectConnectGatewayAssociationProposalError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1942,7 +2235,12 @@ impl AllocateConnectionOnInterconnectError {
                         AllocateConnectionOnInterconnectError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1999,7 +2297,12 @@ impl AllocateHostedConnectionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2060,7 +2363,12 @@ impl AllocatePrivateVirtualInterfaceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2121,7 +2429,12 @@ impl AllocatePublicVirtualInterfaceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2182,7 +2495,12 @@ impl AllocateTransitVirtualInterfaceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2229,7 +2547,12 @@ impl AssociateConnectionWithLagError {
                         AssociateConnectionWithLagError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2272,7 +2595,12 @@ impl AssociateHostedConnectionError {
                         AssociateHostedConnectionError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2315,7 +2643,12 @@ impl AssociateVirtualInterfaceError {
                         AssociateVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2358,7 +2691,12 @@ impl ConfirmConnectionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2403,7 +2741,12 @@ impl ConfirmPrivateVirtualInterfaceError {
                         ConfirmPrivateVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2448,7 +2791,12 @@ impl ConfirmPublicVirtualInterfaceError {
                         ConfirmPublicVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2493,7 +2841,12 @@ impl ConfirmTransitVirtualInterfaceError {
                         ConfirmTransitVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2532,7 +2885,12 @@ impl CreateBGPPeerError {
                 "DirectConnectServerException" => {
                     return RusotoError::Service(CreateBGPPeerError::DirectConnectServer(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2585,7 +2943,12 @@ impl CreateConnectionError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(CreateConnectionError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2632,7 +2995,12 @@ impl CreateDirectConnectGatewayError {
                         CreateDirectConnectGatewayError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2677,7 +3045,12 @@ impl CreateDirectConnectGatewayAssociationError {
                         CreateDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2726,7 +3099,12 @@ impl CreateDirectConnectGatewayAssociationProposalError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2783,7 +3161,12 @@ impl CreateInterconnectError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(CreateInterconnectError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2834,7 +3217,12 @@ impl CreateLagError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(CreateLagError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2895,7 +3283,12 @@ impl CreatePrivateVirtualInterfaceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2956,7 +3349,12 @@ impl CreatePublicVirtualInterfaceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3017,7 +3415,12 @@ impl CreateTransitVirtualInterfaceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3058,7 +3461,12 @@ impl DeleteBGPPeerError {
                 "DirectConnectServerException" => {
                     return RusotoError::Service(DeleteBGPPeerError::DirectConnectServer(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3101,7 +3509,12 @@ impl DeleteConnectionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3146,7 +3559,12 @@ impl DeleteDirectConnectGatewayError {
                         DeleteDirectConnectGatewayError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3191,7 +3609,12 @@ impl DeleteDirectConnectGatewayAssociationError {
                         DeleteDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3240,7 +3663,12 @@ impl DeleteDirectConnectGatewayAssociationProposalError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3287,7 +3715,12 @@ impl DeleteInterconnectError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3326,7 +3759,12 @@ impl DeleteLagError {
                 "DirectConnectServerException" => {
                     return RusotoError::Service(DeleteLagError::DirectConnectServer(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3369,7 +3807,12 @@ impl DeleteVirtualInterfaceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3412,7 +3855,12 @@ impl DescribeConnectionLoaError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3455,7 +3903,12 @@ impl DescribeConnectionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3500,7 +3953,12 @@ impl DescribeConnectionsOnInterconnectError {
                         DescribeConnectionsOnInterconnectError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3549,7 +4007,12 @@ impl DescribeDirectConnectGatewayAssociationProposalsError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3598,7 +4061,12 @@ impl DescribeDirectConnectGatewayAssociationsError {
                         DescribeDirectConnectGatewayAssociationsError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3643,7 +4111,12 @@ impl DescribeDirectConnectGatewayAttachmentsError {
                         DescribeDirectConnectGatewayAttachmentsError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3688,7 +4161,12 @@ impl DescribeDirectConnectGatewaysError {
                         DescribeDirectConnectGatewaysError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3731,7 +4209,12 @@ impl DescribeHostedConnectionsError {
                         DescribeHostedConnectionsError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3774,7 +4257,12 @@ impl DescribeInterconnectLoaError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3817,7 +4305,12 @@ impl DescribeInterconnectsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3856,7 +4349,12 @@ impl DescribeLagsError {
                 "DirectConnectServerException" => {
                     return RusotoError::Service(DescribeLagsError::DirectConnectServer(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3895,7 +4393,12 @@ impl DescribeLoaError {
                 "DirectConnectServerException" => {
                     return RusotoError::Service(DescribeLoaError::DirectConnectServer(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3938,7 +4441,12 @@ impl DescribeLocationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3977,7 +4485,12 @@ impl DescribeTagsError {
                 "DirectConnectServerException" => {
                     return RusotoError::Service(DescribeTagsError::DirectConnectServer(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4020,7 +4533,12 @@ impl DescribeVirtualGatewaysError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4063,7 +4581,12 @@ impl DescribeVirtualInterfacesError {
                         DescribeVirtualInterfacesError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4108,7 +4631,12 @@ impl DisassociateConnectionFromLagError {
                         DisassociateConnectionFromLagError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4157,7 +4685,12 @@ impl TagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4198,7 +4731,12 @@ impl UntagResourceError {
                 "DirectConnectServerException" => {
                     return RusotoError::Service(UntagResourceError::DirectConnectServer(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4243,7 +4781,12 @@ impl UpdateDirectConnectGatewayAssociationError {
                         UpdateDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4282,7 +4825,12 @@ impl UpdateLagError {
                 "DirectConnectServerException" => {
                     return RusotoError::Service(UpdateLagError::DirectConnectServer(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4327,7 +4875,12 @@ impl UpdateVirtualInterfaceAttributesError {
                         UpdateVirtualInterfaceAttributesError::DirectConnectServer(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4675,45 +5228,8 @@ pub trait DirectConnect {
         input: UpdateVirtualInterfaceAttributesRequest,
     ) -> RusotoFuture<VirtualInterface, UpdateVirtualInterfaceAttributesError>;
 }
-/// A client for the AWS Direct Connect API.
-#[derive(Clone)]
-pub struct DirectConnectClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl DirectConnectClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> DirectConnectClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> DirectConnectClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> DirectConnectClient {
-        DirectConnectClient { client, region }
-    }
-}
-
-impl DirectConnect for DirectConnectClient {
-    /// <p>Accepts a proposal request to attach a virtual private gateway or transit gateway to a Direct Connect gateway.</p>
+const _: Option<&dyn DirectConnect> = None;
+impl<'a, T: DirectConnect + ?Sized> DirectConnect for &'a T {
     fn accept_direct_connect_gateway_association_proposal(
         &self,
         input: AcceptDirectConnectGatewayAssociationProposalRequest,
@@ -4721,456 +5237,102 @@ impl DirectConnect for DirectConnectClient {
         AcceptDirectConnectGatewayAssociationProposalResult,
         AcceptDirectConnectGatewayAssociationProposalError,
     > {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.AcceptDirectConnectGatewayAssociationProposal",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AcceptDirectConnectGatewayAssociationProposalResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AcceptDirectConnectGatewayAssociationProposalError::from_response(response))
-                }))
-            }
-        })
+        (**self).accept_direct_connect_gateway_association_proposal(input)
     }
-
-    /// <p><p>Deprecated. Use <a>AllocateHostedConnection</a> instead.</p> <p>Creates a hosted connection on an interconnect.</p> <p>Allocates a VLAN number and a specified amount of bandwidth for use by a hosted connection on the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
     fn allocate_connection_on_interconnect(
         &self,
         input: AllocateConnectionOnInterconnectRequest,
     ) -> RusotoFuture<Connection, AllocateConnectionOnInterconnectError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.AllocateConnectionOnInterconnect",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AllocateConnectionOnInterconnectError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).allocate_connection_on_interconnect(input)
     }
-
-    /// <p><p>Creates a hosted connection on the specified interconnect or a link aggregation group (LAG) of interconnects.</p> <p>Allocates a VLAN number and a specified amount of capacity (bandwidth) for use by a hosted connection on the specified interconnect or LAG of interconnects. AWS polices the hosted connection for the specified capacity and the AWS Direct Connect Partner must also police the hosted connection for the specified capacity.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
     fn allocate_hosted_connection(
         &self,
         input: AllocateHostedConnectionRequest,
     ) -> RusotoFuture<Connection, AllocateHostedConnectionError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.AllocateHostedConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AllocateHostedConnectionError::from_response(response))
-                }))
-            }
-        })
+        (**self).allocate_hosted_connection(input)
     }
-
-    /// <p>Provisions a private virtual interface to be owned by the specified AWS account.</p> <p>Virtual interfaces created using this action must be confirmed by the owner using <a>ConfirmPrivateVirtualInterface</a>. Until then, the virtual interface is in the <code>Confirming</code> state and is not available to handle traffic.</p>
     fn allocate_private_virtual_interface(
         &self,
         input: AllocatePrivateVirtualInterfaceRequest,
     ) -> RusotoFuture<VirtualInterface, AllocatePrivateVirtualInterfaceError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.AllocatePrivateVirtualInterface",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VirtualInterface, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AllocatePrivateVirtualInterfaceError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).allocate_private_virtual_interface(input)
     }
-
-    /// <p>Provisions a public virtual interface to be owned by the specified AWS account.</p> <p>The owner of a connection calls this function to provision a public virtual interface to be owned by the specified AWS account.</p> <p>Virtual interfaces created using this function must be confirmed by the owner using <a>ConfirmPublicVirtualInterface</a>. Until this step has been completed, the virtual interface is in the <code>confirming</code> state and is not available to handle traffic.</p> <p>When creating an IPv6 public virtual interface, omit the Amazon address and customer address. IPv6 addresses are automatically assigned from the Amazon pool of IPv6 addresses; you cannot specify custom IPv6 addresses.</p>
     fn allocate_public_virtual_interface(
         &self,
         input: AllocatePublicVirtualInterfaceRequest,
     ) -> RusotoFuture<VirtualInterface, AllocatePublicVirtualInterfaceError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.AllocatePublicVirtualInterface",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VirtualInterface, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AllocatePublicVirtualInterfaceError::from_response(response))
-                }))
-            }
-        })
+        (**self).allocate_public_virtual_interface(input)
     }
-
-    /// <p>Provisions a transit virtual interface to be owned by the specified AWS account. Use this type of interface to connect a transit gateway to your Direct Connect gateway.</p> <p>The owner of a connection provisions a transit virtual interface to be owned by the specified AWS account.</p> <p>After you create a transit virtual interface, it must be confirmed by the owner using <a>ConfirmTransitVirtualInterface</a>. Until this step has been completed, the transit virtual interface is in the <code>requested</code> state and is not available to handle traffic.</p>
     fn allocate_transit_virtual_interface(
         &self,
         input: AllocateTransitVirtualInterfaceRequest,
     ) -> RusotoFuture<AllocateTransitVirtualInterfaceResult, AllocateTransitVirtualInterfaceError>
     {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.AllocateTransitVirtualInterface",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AllocateTransitVirtualInterfaceResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AllocateTransitVirtualInterfaceError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).allocate_transit_virtual_interface(input)
     }
-
-    /// <p>Associates an existing connection with a link aggregation group (LAG). The connection is interrupted and re-established as a member of the LAG (connectivity to AWS is interrupted). The connection must be hosted on the same AWS Direct Connect endpoint as the LAG, and its bandwidth must match the bandwidth for the LAG. You can re-associate a connection that's currently associated with a different LAG; however, if removing the connection would cause the original LAG to fall below its setting for minimum number of operational connections, the request fails.</p> <p>Any virtual interfaces that are directly associated with the connection are automatically re-associated with the LAG. If the connection was originally associated with a different LAG, the virtual interfaces remain associated with the original LAG.</p> <p>For interconnects, any hosted connections are automatically re-associated with the LAG. If the interconnect was originally associated with a different LAG, the hosted connections remain associated with the original LAG.</p>
     fn associate_connection_with_lag(
         &self,
         input: AssociateConnectionWithLagRequest,
     ) -> RusotoFuture<Connection, AssociateConnectionWithLagError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.AssociateConnectionWithLag");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateConnectionWithLagError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_connection_with_lag(input)
     }
-
-    /// <p><p>Associates a hosted connection and its virtual interfaces with a link aggregation group (LAG) or interconnect. If the target interconnect or LAG has an existing hosted connection with a conflicting VLAN number or IP address, the operation fails. This action temporarily interrupts the hosted connection&#39;s connectivity to AWS as it is being migrated.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
     fn associate_hosted_connection(
         &self,
         input: AssociateHostedConnectionRequest,
     ) -> RusotoFuture<Connection, AssociateHostedConnectionError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.AssociateHostedConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateHostedConnectionError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_hosted_connection(input)
     }
-
-    /// <p>Associates a virtual interface with a specified link aggregation group (LAG) or connection. Connectivity to AWS is temporarily interrupted as the virtual interface is being migrated. If the target connection or LAG has an associated virtual interface with a conflicting VLAN number or a conflicting IP address, the operation fails.</p> <p>Virtual interfaces associated with a hosted connection cannot be associated with a LAG; hosted connections must be migrated along with their virtual interfaces using <a>AssociateHostedConnection</a>.</p> <p>To reassociate a virtual interface to a new connection or LAG, the requester must own either the virtual interface itself or the connection to which the virtual interface is currently associated. Additionally, the requester must own the connection or LAG for the association.</p>
     fn associate_virtual_interface(
         &self,
         input: AssociateVirtualInterfaceRequest,
     ) -> RusotoFuture<VirtualInterface, AssociateVirtualInterfaceError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.AssociateVirtualInterface");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VirtualInterface, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateVirtualInterfaceError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_virtual_interface(input)
     }
-
-    /// <p>Confirms the creation of the specified hosted connection on an interconnect.</p> <p>Upon creation, the hosted connection is initially in the <code>Ordering</code> state, and remains in this state until the owner confirms creation of the hosted connection.</p>
     fn confirm_connection(
         &self,
         input: ConfirmConnectionRequest,
     ) -> RusotoFuture<ConfirmConnectionResponse, ConfirmConnectionError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.ConfirmConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ConfirmConnectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ConfirmConnectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).confirm_connection(input)
     }
-
-    /// <p>Accepts ownership of a private virtual interface created by another AWS account.</p> <p>After the virtual interface owner makes this call, the virtual interface is created and attached to the specified virtual private gateway or Direct Connect gateway, and is made available to handle traffic.</p>
     fn confirm_private_virtual_interface(
         &self,
         input: ConfirmPrivateVirtualInterfaceRequest,
     ) -> RusotoFuture<ConfirmPrivateVirtualInterfaceResponse, ConfirmPrivateVirtualInterfaceError>
     {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.ConfirmPrivateVirtualInterface",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ConfirmPrivateVirtualInterfaceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ConfirmPrivateVirtualInterfaceError::from_response(response))
-                }))
-            }
-        })
+        (**self).confirm_private_virtual_interface(input)
     }
-
-    /// <p>Accepts ownership of a public virtual interface created by another AWS account.</p> <p>After the virtual interface owner makes this call, the specified virtual interface is created and made available to handle traffic.</p>
     fn confirm_public_virtual_interface(
         &self,
         input: ConfirmPublicVirtualInterfaceRequest,
     ) -> RusotoFuture<ConfirmPublicVirtualInterfaceResponse, ConfirmPublicVirtualInterfaceError>
     {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.ConfirmPublicVirtualInterface",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ConfirmPublicVirtualInterfaceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ConfirmPublicVirtualInterfaceError::from_response(response))
-                }))
-            }
-        })
+        (**self).confirm_public_virtual_interface(input)
     }
-
-    /// <p>Accepts ownership of a transit virtual interface created by another AWS account.</p> <p> After the owner of the transit virtual interface makes this call, the specified transit virtual interface is created and made available to handle traffic.</p>
     fn confirm_transit_virtual_interface(
         &self,
         input: ConfirmTransitVirtualInterfaceRequest,
     ) -> RusotoFuture<ConfirmTransitVirtualInterfaceResponse, ConfirmTransitVirtualInterfaceError>
     {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.ConfirmTransitVirtualInterface",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ConfirmTransitVirtualInterfaceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ConfirmTransitVirtualInterfaceError::from_response(response))
-                }))
-            }
-        })
+        (**self).confirm_transit_virtual_interface(input)
     }
-
-    /// <p>Creates a BGP peer on the specified virtual interface.</p> <p>You must create a BGP peer for the corresponding address family (IPv4/IPv6) in order to access AWS resources that also use that address family.</p> <p>If logical redundancy is not supported by the connection, interconnect, or LAG, the BGP peer cannot be in the same address family as an existing BGP peer on the virtual interface.</p> <p>When creating a IPv6 BGP peer, omit the Amazon address and customer address. IPv6 addresses are automatically assigned from the Amazon pool of IPv6 addresses; you cannot specify custom IPv6 addresses.</p> <p>For a public virtual interface, the Autonomous System Number (ASN) must be private or already whitelisted for the virtual interface.</p>
     fn create_bgp_peer(
         &self,
         input: CreateBGPPeerRequest,
     ) -> RusotoFuture<CreateBGPPeerResponse, CreateBGPPeerError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.CreateBGPPeer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBGPPeerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBGPPeerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_bgp_peer(input)
     }
-
-    /// <p>Creates a connection between a customer network and a specific AWS Direct Connect location.</p> <p>A connection links your internal network to an AWS Direct Connect location over a standard Ethernet fiber-optic cable. One end of the cable is connected to your router, the other to an AWS Direct Connect router.</p> <p>To find the locations for your Region, use <a>DescribeLocations</a>.</p> <p>You can automatically add the new connection to a link aggregation group (LAG) by specifying a LAG ID in the request. This ensures that the new connection is allocated on the same AWS Direct Connect endpoint that hosts the specified LAG. If there are no available ports on the endpoint, the request fails and no connection is created.</p>
     fn create_connection(
         &self,
         input: CreateConnectionRequest,
     ) -> RusotoFuture<Connection, CreateConnectionError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.CreateConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateConnectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_connection(input)
     }
-
-    /// <p>Creates a Direct Connect gateway, which is an intermediate object that enables you to connect a set of virtual interfaces and virtual private gateways. A Direct Connect gateway is global and visible in any AWS Region after it is created. The virtual interfaces and virtual private gateways that are connected through a Direct Connect gateway can be in different AWS Regions. This enables you to connect to a VPC in any Region, regardless of the Region in which the virtual interfaces are located, and pass traffic between them.</p>
     fn create_direct_connect_gateway(
         &self,
         input: CreateDirectConnectGatewayRequest,
     ) -> RusotoFuture<CreateDirectConnectGatewayResult, CreateDirectConnectGatewayError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.CreateDirectConnectGateway");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDirectConnectGatewayResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDirectConnectGatewayError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_direct_connect_gateway(input)
     }
-
-    /// <p>Creates an association between a Direct Connect gateway and a virtual private gateway. The virtual private gateway must be attached to a VPC and must not be associated with another Direct Connect gateway.</p>
     fn create_direct_connect_gateway_association(
         &self,
         input: CreateDirectConnectGatewayAssociationRequest,
@@ -5178,33 +5340,8 @@ impl DirectConnect for DirectConnectClient {
         CreateDirectConnectGatewayAssociationResult,
         CreateDirectConnectGatewayAssociationError,
     > {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.CreateDirectConnectGatewayAssociation",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDirectConnectGatewayAssociationResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDirectConnectGatewayAssociationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_direct_connect_gateway_association(input)
     }
-
-    /// <p>Creates a proposal to associate the specified virtual private gateway or transit gateway with the specified Direct Connect gateway.</p> <p>You can only associate a Direct Connect gateway and virtual private gateway or transit gateway when the account that owns the Direct Connect gateway and the account that owns the virtual private gateway or transit gateway have the same AWS Payer ID.</p>
     fn create_direct_connect_gateway_association_proposal(
         &self,
         input: CreateDirectConnectGatewayAssociationProposalRequest,
@@ -5212,254 +5349,53 @@ impl DirectConnect for DirectConnectClient {
         CreateDirectConnectGatewayAssociationProposalResult,
         CreateDirectConnectGatewayAssociationProposalError,
     > {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.CreateDirectConnectGatewayAssociationProposal",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDirectConnectGatewayAssociationProposalResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDirectConnectGatewayAssociationProposalError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_direct_connect_gateway_association_proposal(input)
     }
-
-    /// <p><p>Creates an interconnect between an AWS Direct Connect Partner&#39;s network and a specific AWS Direct Connect location.</p> <p>An interconnect is a connection that is capable of hosting other connections. The AWS Direct Connect partner can use an interconnect to provide AWS Direct Connect hosted connections to customers through their own network services. Like a standard connection, an interconnect links the partner&#39;s network to an AWS Direct Connect location over a standard Ethernet fiber-optic cable. One end is connected to the partner&#39;s router, the other to an AWS Direct Connect router.</p> <p>You can automatically add the new interconnect to a link aggregation group (LAG) by specifying a LAG ID in the request. This ensures that the new interconnect is allocated on the same AWS Direct Connect endpoint that hosts the specified LAG. If there are no available ports on the endpoint, the request fails and no interconnect is created.</p> <p>For each end customer, the AWS Direct Connect Partner provisions a connection on their interconnect by calling <a>AllocateHostedConnection</a>. The end customer can then connect to AWS resources by creating a virtual interface on their connection, using the VLAN assigned to them by the AWS Direct Connect Partner.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
     fn create_interconnect(
         &self,
         input: CreateInterconnectRequest,
     ) -> RusotoFuture<Interconnect, CreateInterconnectError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.CreateInterconnect");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Interconnect, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateInterconnectError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_interconnect(input)
     }
-
-    /// <p>Creates a link aggregation group (LAG) with the specified number of bundled physical connections between the customer network and a specific AWS Direct Connect location. A LAG is a logical interface that uses the Link Aggregation Control Protocol (LACP) to aggregate multiple interfaces, enabling you to treat them as a single interface.</p> <p>All connections in a LAG must use the same bandwidth and must terminate at the same AWS Direct Connect endpoint.</p> <p>You can have up to 10 connections per LAG. Regardless of this limit, if you request more connections for the LAG than AWS Direct Connect can allocate on a single endpoint, no LAG is created.</p> <p>You can specify an existing physical connection or interconnect to include in the LAG (which counts towards the total number of connections). Doing so interrupts the current physical connection or hosted connections, and re-establishes them as a member of the LAG. The LAG will be created on the same AWS Direct Connect endpoint to which the connection terminates. Any virtual interfaces associated with the connection are automatically disassociated and re-associated with the LAG. The connection ID does not change.</p> <p>If the AWS account used to create a LAG is a registered AWS Direct Connect Partner, the LAG is automatically enabled to host sub-connections. For a LAG owned by a partner, any associated virtual interfaces cannot be directly configured.</p>
     fn create_lag(&self, input: CreateLagRequest) -> RusotoFuture<Lag, CreateLagError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.CreateLag");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateLagError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_lag(input)
     }
-
-    /// <p>Creates a private virtual interface. A virtual interface is the VLAN that transports AWS Direct Connect traffic. A private virtual interface can be connected to either a Direct Connect gateway or a Virtual Private Gateway (VGW). Connecting the private virtual interface to a Direct Connect gateway enables the possibility for connecting to multiple VPCs, including VPCs in different AWS Regions. Connecting the private virtual interface to a VGW only provides access to a single VPC within the same Region.</p>
     fn create_private_virtual_interface(
         &self,
         input: CreatePrivateVirtualInterfaceRequest,
     ) -> RusotoFuture<VirtualInterface, CreatePrivateVirtualInterfaceError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.CreatePrivateVirtualInterface",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VirtualInterface, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreatePrivateVirtualInterfaceError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_private_virtual_interface(input)
     }
-
-    /// <p>Creates a public virtual interface. A virtual interface is the VLAN that transports AWS Direct Connect traffic. A public virtual interface supports sending traffic to public services of AWS such as Amazon S3.</p> <p>When creating an IPv6 public virtual interface (<code>addressFamily</code> is <code>ipv6</code>), leave the <code>customer</code> and <code>amazon</code> address fields blank to use auto-assigned IPv6 space. Custom IPv6 addresses are not supported.</p>
     fn create_public_virtual_interface(
         &self,
         input: CreatePublicVirtualInterfaceRequest,
     ) -> RusotoFuture<VirtualInterface, CreatePublicVirtualInterfaceError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.CreatePublicVirtualInterface",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VirtualInterface, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreatePublicVirtualInterfaceError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_public_virtual_interface(input)
     }
-
-    /// <p><p>Creates a transit virtual interface. A transit virtual interface should be used to access one or more transit gateways associated with Direct Connect gateways. A transit virtual interface enables the connection of multiple VPCs attached to a transit gateway to a Direct Connect gateway.</p> <important> <p>If you associate your transit gateway with one or more Direct Connect gateways, the Autonomous System Number (ASN) used by the transit gateway and the Direct Connect gateway must be different. For example, if you use the default ASN 64512 for both your the transit gateway and Direct Connect gateway, the association request fails.</p> </important></p>
     fn create_transit_virtual_interface(
         &self,
         input: CreateTransitVirtualInterfaceRequest,
     ) -> RusotoFuture<CreateTransitVirtualInterfaceResult, CreateTransitVirtualInterfaceError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.CreateTransitVirtualInterface",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTransitVirtualInterfaceResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateTransitVirtualInterfaceError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_transit_virtual_interface(input)
     }
-
-    /// <p>Deletes the specified BGP peer on the specified virtual interface with the specified customer address and ASN.</p> <p>You cannot delete the last BGP peer from a virtual interface.</p>
     fn delete_bgp_peer(
         &self,
         input: DeleteBGPPeerRequest,
     ) -> RusotoFuture<DeleteBGPPeerResponse, DeleteBGPPeerError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DeleteBGPPeer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBGPPeerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBGPPeerError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_bgp_peer(input)
     }
-
-    /// <p>Deletes the specified connection.</p> <p>Deleting a connection only stops the AWS Direct Connect port hour and data transfer charges. If you are partnering with any third parties to connect with the AWS Direct Connect location, you must cancel your service with them separately.</p>
     fn delete_connection(
         &self,
         input: DeleteConnectionRequest,
     ) -> RusotoFuture<Connection, DeleteConnectionError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DeleteConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteConnectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_connection(input)
     }
-
-    /// <p>Deletes the specified Direct Connect gateway. You must first delete all virtual interfaces that are attached to the Direct Connect gateway and disassociate all virtual private gateways that are associated with the Direct Connect gateway.</p>
     fn delete_direct_connect_gateway(
         &self,
         input: DeleteDirectConnectGatewayRequest,
     ) -> RusotoFuture<DeleteDirectConnectGatewayResult, DeleteDirectConnectGatewayError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DeleteDirectConnectGateway");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDirectConnectGatewayResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteDirectConnectGatewayError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_direct_connect_gateway(input)
     }
-
-    /// <p>Deletes the association between the specified Direct Connect gateway and virtual private gateway.</p>
     fn delete_direct_connect_gateway_association(
         &self,
         input: DeleteDirectConnectGatewayAssociationRequest,
@@ -5467,33 +5403,8 @@ impl DirectConnect for DirectConnectClient {
         DeleteDirectConnectGatewayAssociationResult,
         DeleteDirectConnectGatewayAssociationError,
     > {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.DeleteDirectConnectGatewayAssociation",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDirectConnectGatewayAssociationResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteDirectConnectGatewayAssociationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).delete_direct_connect_gateway_association(input)
     }
-
-    /// <p>Deletes the association proposal request between the specified Direct Connect gateway and virtual private gateway or transit gateway.</p>
     fn delete_direct_connect_gateway_association_proposal(
         &self,
         input: DeleteDirectConnectGatewayAssociationProposalRequest,
@@ -5501,198 +5412,41 @@ impl DirectConnect for DirectConnectClient {
         DeleteDirectConnectGatewayAssociationProposalResult,
         DeleteDirectConnectGatewayAssociationProposalError,
     > {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.DeleteDirectConnectGatewayAssociationProposal",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDirectConnectGatewayAssociationProposalResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteDirectConnectGatewayAssociationProposalError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_direct_connect_gateway_association_proposal(input)
     }
-
-    /// <p><p>Deletes the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
     fn delete_interconnect(
         &self,
         input: DeleteInterconnectRequest,
     ) -> RusotoFuture<DeleteInterconnectResponse, DeleteInterconnectError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DeleteInterconnect");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteInterconnectResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteInterconnectError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_interconnect(input)
     }
-
-    /// <p>Deletes the specified link aggregation group (LAG). You cannot delete a LAG if it has active virtual interfaces or hosted connections.</p>
     fn delete_lag(&self, input: DeleteLagRequest) -> RusotoFuture<Lag, DeleteLagError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DeleteLag");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteLagError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_lag(input)
     }
-
-    /// <p>Deletes a virtual interface.</p>
     fn delete_virtual_interface(
         &self,
         input: DeleteVirtualInterfaceRequest,
     ) -> RusotoFuture<DeleteVirtualInterfaceResponse, DeleteVirtualInterfaceError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DeleteVirtualInterface");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteVirtualInterfaceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteVirtualInterfaceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_virtual_interface(input)
     }
-
-    /// <p>Deprecated. Use <a>DescribeLoa</a> instead.</p> <p>Gets the LOA-CFA for a connection.</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that your APN partner or service provider uses when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
     fn describe_connection_loa(
         &self,
         input: DescribeConnectionLoaRequest,
     ) -> RusotoFuture<DescribeConnectionLoaResponse, DescribeConnectionLoaError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeConnectionLoa");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeConnectionLoaResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeConnectionLoaError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_connection_loa(input)
     }
-
-    /// <p>Displays the specified connection or all connections in this Region.</p>
     fn describe_connections(
         &self,
         input: DescribeConnectionsRequest,
     ) -> RusotoFuture<Connections, DescribeConnectionsError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeConnections");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeConnectionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_connections(input)
     }
-
-    /// <p><p>Deprecated. Use <a>DescribeHostedConnections</a> instead.</p> <p>Lists the connections that have been provisioned on the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
     fn describe_connections_on_interconnect(
         &self,
         input: DescribeConnectionsOnInterconnectRequest,
     ) -> RusotoFuture<Connections, DescribeConnectionsOnInterconnectError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.DescribeConnectionsOnInterconnect",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeConnectionsOnInterconnectError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_connections_on_interconnect(input)
     }
-
-    /// <p>Describes one or more association proposals for connection between a virtual private gateway or transit gateway and a Direct Connect gateway. </p>
     fn describe_direct_connect_gateway_association_proposals(
         &self,
         input: DescribeDirectConnectGatewayAssociationProposalsRequest,
@@ -5700,30 +5454,8 @@ impl DirectConnect for DirectConnectClient {
         DescribeDirectConnectGatewayAssociationProposalsResult,
         DescribeDirectConnectGatewayAssociationProposalsError,
     > {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.DescribeDirectConnectGatewayAssociationProposals",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-                        if response.status.is_success() {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DescribeDirectConnectGatewayAssociationProposalsResult, _>()
-                }))
-                        } else {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                                Err(DescribeDirectConnectGatewayAssociationProposalsError::from_response(response))
-                            }))
-                        }
-                    })
+        (**self).describe_direct_connect_gateway_association_proposals(input)
     }
-
-    /// <p>Lists the associations between your Direct Connect gateways and virtual private gateways. You must specify a Direct Connect gateway, a virtual private gateway, or both. If you specify a Direct Connect gateway, the response contains all virtual private gateways associated with the Direct Connect gateway. If you specify a virtual private gateway, the response contains all Direct Connect gateways associated with the virtual private gateway. If you specify both, the response contains the association between the Direct Connect gateway and the virtual private gateway.</p>
     fn describe_direct_connect_gateway_associations(
         &self,
         input: DescribeDirectConnectGatewayAssociationsRequest,
@@ -5731,31 +5463,8 @@ impl DirectConnect for DirectConnectClient {
         DescribeDirectConnectGatewayAssociationsResult,
         DescribeDirectConnectGatewayAssociationsError,
     > {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.DescribeDirectConnectGatewayAssociations",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDirectConnectGatewayAssociationsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDirectConnectGatewayAssociationsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_direct_connect_gateway_associations(input)
     }
-
-    /// <p>Lists the attachments between your Direct Connect gateways and virtual interfaces. You must specify a Direct Connect gateway, a virtual interface, or both. If you specify a Direct Connect gateway, the response contains all virtual interfaces attached to the Direct Connect gateway. If you specify a virtual interface, the response contains all Direct Connect gateways attached to the virtual interface. If you specify both, the response contains the attachment between the Direct Connect gateway and the virtual interface.</p>
     fn describe_direct_connect_gateway_attachments(
         &self,
         input: DescribeDirectConnectGatewayAttachmentsRequest,
@@ -5763,378 +5472,76 @@ impl DirectConnect for DirectConnectClient {
         DescribeDirectConnectGatewayAttachmentsResult,
         DescribeDirectConnectGatewayAttachmentsError,
     > {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.DescribeDirectConnectGatewayAttachments",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDirectConnectGatewayAttachmentsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDirectConnectGatewayAttachmentsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_direct_connect_gateway_attachments(input)
     }
-
-    /// <p>Lists all your Direct Connect gateways or only the specified Direct Connect gateway. Deleted Direct Connect gateways are not returned.</p>
     fn describe_direct_connect_gateways(
         &self,
         input: DescribeDirectConnectGatewaysRequest,
     ) -> RusotoFuture<DescribeDirectConnectGatewaysResult, DescribeDirectConnectGatewaysError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.DescribeDirectConnectGateways",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDirectConnectGatewaysResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDirectConnectGatewaysError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_direct_connect_gateways(input)
     }
-
-    /// <p><p>Lists the hosted connections that have been provisioned on the specified interconnect or link aggregation group (LAG).</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
     fn describe_hosted_connections(
         &self,
         input: DescribeHostedConnectionsRequest,
     ) -> RusotoFuture<Connections, DescribeHostedConnectionsError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeHostedConnections");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeHostedConnectionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_hosted_connections(input)
     }
-
-    /// <p>Deprecated. Use <a>DescribeLoa</a> instead.</p> <p>Gets the LOA-CFA for the specified interconnect.</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that is used when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
     fn describe_interconnect_loa(
         &self,
         input: DescribeInterconnectLoaRequest,
     ) -> RusotoFuture<DescribeInterconnectLoaResponse, DescribeInterconnectLoaError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeInterconnectLoa");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInterconnectLoaResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeInterconnectLoaError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_interconnect_loa(input)
     }
-
-    /// <p>Lists the interconnects owned by the AWS account or only the specified interconnect.</p>
     fn describe_interconnects(
         &self,
         input: DescribeInterconnectsRequest,
     ) -> RusotoFuture<Interconnects, DescribeInterconnectsError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeInterconnects");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Interconnects, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeInterconnectsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_interconnects(input)
     }
-
-    /// <p>Describes all your link aggregation groups (LAG) or the specified LAG.</p>
     fn describe_lags(&self, input: DescribeLagsRequest) -> RusotoFuture<Lags, DescribeLagsError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeLags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Lags, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeLagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_lags(input)
     }
-
-    /// <p>Gets the LOA-CFA for a connection, interconnect, or link aggregation group (LAG).</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that is used when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
     fn describe_loa(&self, input: DescribeLoaRequest) -> RusotoFuture<Loa, DescribeLoaError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeLoa");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Loa, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeLoaError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_loa(input)
     }
-
-    /// <p>Lists the AWS Direct Connect locations in the current AWS Region. These are the locations that can be selected when calling <a>CreateConnection</a> or <a>CreateInterconnect</a>.</p>
     fn describe_locations(&self) -> RusotoFuture<Locations, DescribeLocationsError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeLocations");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Locations, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeLocationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_locations()
     }
-
-    /// <p>Describes the tags associated with the specified AWS Direct Connect resources.</p>
     fn describe_tags(
         &self,
         input: DescribeTagsRequest,
     ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTagsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_tags(input)
     }
-
-    /// <p>Lists the virtual private gateways owned by the AWS account.</p> <p>You can create one or more AWS Direct Connect private virtual interfaces linked to a virtual private gateway.</p>
     fn describe_virtual_gateways(
         &self,
     ) -> RusotoFuture<VirtualGateways, DescribeVirtualGatewaysError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeVirtualGateways");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<VirtualGateways, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeVirtualGatewaysError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_virtual_gateways()
     }
-
-    /// <p>Displays all virtual interfaces for an AWS account. Virtual interfaces deleted fewer than 15 minutes before you make the request are also returned. If you specify a connection ID, only the virtual interfaces associated with the connection are returned. If you specify a virtual interface ID, then only a single virtual interface is returned.</p> <p>A virtual interface (VLAN) transmits the traffic between the AWS Direct Connect location and the customer network.</p>
     fn describe_virtual_interfaces(
         &self,
         input: DescribeVirtualInterfacesRequest,
     ) -> RusotoFuture<VirtualInterfaces, DescribeVirtualInterfacesError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.DescribeVirtualInterfaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VirtualInterfaces, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeVirtualInterfacesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_virtual_interfaces(input)
     }
-
-    /// <p>Disassociates a connection from a link aggregation group (LAG). The connection is interrupted and re-established as a standalone connection (the connection is not deleted; to delete the connection, use the <a>DeleteConnection</a> request). If the LAG has associated virtual interfaces or hosted connections, they remain associated with the LAG. A disassociated connection owned by an AWS Direct Connect Partner is automatically converted to an interconnect.</p> <p>If disassociating the connection would cause the LAG to fall below its setting for minimum number of operational connections, the request fails, except when it's the last member of the LAG. If all connections are disassociated, the LAG continues to exist as an empty LAG with no physical connections. </p>
     fn disassociate_connection_from_lag(
         &self,
         input: DisassociateConnectionFromLagRequest,
     ) -> RusotoFuture<Connection, DisassociateConnectionFromLagError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.DisassociateConnectionFromLag",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateConnectionFromLagError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_connection_from_lag(input)
     }
-
-    /// <p>Adds the specified tags to the specified AWS Direct Connect resource. Each resource can have a maximum of 50 tags.</p> <p>Each tag consists of a key and an optional value. If a tag with the same key is already associated with the resource, this action updates its value.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).tag_resource(input)
     }
-
-    /// <p>Removes one or more tags from the specified AWS Direct Connect resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).untag_resource(input)
     }
-
-    /// <p>Updates the specified attributes of the Direct Connect gateway association.</p> <p>Add or remove prefixes from the association.</p>
     fn update_direct_connect_gateway_association(
         &self,
         input: UpdateDirectConnectGatewayAssociationRequest,
@@ -6142,46 +5549,2325 @@ impl DirectConnect for DirectConnectClient {
         UpdateDirectConnectGatewayAssociationResult,
         UpdateDirectConnectGatewayAssociationError,
     > {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.UpdateDirectConnectGatewayAssociation",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDirectConnectGatewayAssociationResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateDirectConnectGatewayAssociationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).update_direct_connect_gateway_association(input)
     }
-
-    /// <p>Updates the attributes of the specified link aggregation group (LAG).</p> <p>You can update the following attributes:</p> <ul> <li> <p>The name of the LAG.</p> </li> <li> <p>The value for the minimum number of connections that must be operational for the LAG itself to be operational. </p> </li> </ul> <p>When you create a LAG, the default value for the minimum number of operational connections is zero (0). If you update this value and the number of operational connections falls below the specified value, the LAG automatically goes down to avoid over-utilization of the remaining connections. Adjust this value with care, as it could force the LAG down if it is set higher than the current number of operational connections.</p>
     fn update_lag(&self, input: UpdateLagRequest) -> RusotoFuture<Lag, UpdateLagError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OvertureService.UpdateLag");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
+        (**self).update_lag(input)
+    }
+    fn update_virtual_interface_attributes(
+        &self,
+        input: UpdateVirtualInterfaceAttributesRequest,
+    ) -> RusotoFuture<VirtualInterface, UpdateVirtualInterfaceAttributesError> {
+        (**self).update_virtual_interface_attributes(input)
+    }
+}
+impl<T: DirectConnect + ?Sized> DirectConnect for Box<T> {
+    fn accept_direct_connect_gateway_association_proposal(
+        &self,
+        input: AcceptDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        AcceptDirectConnectGatewayAssociationProposalResult,
+        AcceptDirectConnectGatewayAssociationProposalError,
+    > {
+        (**self).accept_direct_connect_gateway_association_proposal(input)
+    }
+    fn allocate_connection_on_interconnect(
+        &self,
+        input: AllocateConnectionOnInterconnectRequest,
+    ) -> RusotoFuture<Connection, AllocateConnectionOnInterconnectError> {
+        (**self).allocate_connection_on_interconnect(input)
+    }
+    fn allocate_hosted_connection(
+        &self,
+        input: AllocateHostedConnectionRequest,
+    ) -> RusotoFuture<Connection, AllocateHostedConnectionError> {
+        (**self).allocate_hosted_connection(input)
+    }
+    fn allocate_private_virtual_interface(
+        &self,
+        input: AllocatePrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AllocatePrivateVirtualInterfaceError> {
+        (**self).allocate_private_virtual_interface(input)
+    }
+    fn allocate_public_virtual_interface(
+        &self,
+        input: AllocatePublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AllocatePublicVirtualInterfaceError> {
+        (**self).allocate_public_virtual_interface(input)
+    }
+    fn allocate_transit_virtual_interface(
+        &self,
+        input: AllocateTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<AllocateTransitVirtualInterfaceResult, AllocateTransitVirtualInterfaceError>
+    {
+        (**self).allocate_transit_virtual_interface(input)
+    }
+    fn associate_connection_with_lag(
+        &self,
+        input: AssociateConnectionWithLagRequest,
+    ) -> RusotoFuture<Connection, AssociateConnectionWithLagError> {
+        (**self).associate_connection_with_lag(input)
+    }
+    fn associate_hosted_connection(
+        &self,
+        input: AssociateHostedConnectionRequest,
+    ) -> RusotoFuture<Connection, AssociateHostedConnectionError> {
+        (**self).associate_hosted_connection(input)
+    }
+    fn associate_virtual_interface(
+        &self,
+        input: AssociateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AssociateVirtualInterfaceError> {
+        (**self).associate_virtual_interface(input)
+    }
+    fn confirm_connection(
+        &self,
+        input: ConfirmConnectionRequest,
+    ) -> RusotoFuture<ConfirmConnectionResponse, ConfirmConnectionError> {
+        (**self).confirm_connection(input)
+    }
+    fn confirm_private_virtual_interface(
+        &self,
+        input: ConfirmPrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmPrivateVirtualInterfaceResponse, ConfirmPrivateVirtualInterfaceError>
+    {
+        (**self).confirm_private_virtual_interface(input)
+    }
+    fn confirm_public_virtual_interface(
+        &self,
+        input: ConfirmPublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmPublicVirtualInterfaceResponse, ConfirmPublicVirtualInterfaceError>
+    {
+        (**self).confirm_public_virtual_interface(input)
+    }
+    fn confirm_transit_virtual_interface(
+        &self,
+        input: ConfirmTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmTransitVirtualInterfaceResponse, ConfirmTransitVirtualInterfaceError>
+    {
+        (**self).confirm_transit_virtual_interface(input)
+    }
+    fn create_bgp_peer(
+        &self,
+        input: CreateBGPPeerRequest,
+    ) -> RusotoFuture<CreateBGPPeerResponse, CreateBGPPeerError> {
+        (**self).create_bgp_peer(input)
+    }
+    fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> RusotoFuture<Connection, CreateConnectionError> {
+        (**self).create_connection(input)
+    }
+    fn create_direct_connect_gateway(
+        &self,
+        input: CreateDirectConnectGatewayRequest,
+    ) -> RusotoFuture<CreateDirectConnectGatewayResult, CreateDirectConnectGatewayError> {
+        (**self).create_direct_connect_gateway(input)
+    }
+    fn create_direct_connect_gateway_association(
+        &self,
+        input: CreateDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        CreateDirectConnectGatewayAssociationResult,
+        CreateDirectConnectGatewayAssociationError,
+    > {
+        (**self).create_direct_connect_gateway_association(input)
+    }
+    fn create_direct_connect_gateway_association_proposal(
+        &self,
+        input: CreateDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        CreateDirectConnectGatewayAssociationProposalResult,
+        CreateDirectConnectGatewayAssociationProposalError,
+    > {
+        (**self).create_direct_connect_gateway_association_proposal(input)
+    }
+    fn create_interconnect(
+        &self,
+        input: CreateInterconnectRequest,
+    ) -> RusotoFuture<Interconnect, CreateInterconnectError> {
+        (**self).create_interconnect(input)
+    }
+    fn create_lag(&self, input: CreateLagRequest) -> RusotoFuture<Lag, CreateLagError> {
+        (**self).create_lag(input)
+    }
+    fn create_private_virtual_interface(
+        &self,
+        input: CreatePrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, CreatePrivateVirtualInterfaceError> {
+        (**self).create_private_virtual_interface(input)
+    }
+    fn create_public_virtual_interface(
+        &self,
+        input: CreatePublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, CreatePublicVirtualInterfaceError> {
+        (**self).create_public_virtual_interface(input)
+    }
+    fn create_transit_virtual_interface(
+        &self,
+        input: CreateTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<CreateTransitVirtualInterfaceResult, CreateTransitVirtualInterfaceError> {
+        (**self).create_transit_virtual_interface(input)
+    }
+    fn delete_bgp_peer(
+        &self,
+        input: DeleteBGPPeerRequest,
+    ) -> RusotoFuture<DeleteBGPPeerResponse, DeleteBGPPeerError> {
+        (**self).delete_bgp_peer(input)
+    }
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<Connection, DeleteConnectionError> {
+        (**self).delete_connection(input)
+    }
+    fn delete_direct_connect_gateway(
+        &self,
+        input: DeleteDirectConnectGatewayRequest,
+    ) -> RusotoFuture<DeleteDirectConnectGatewayResult, DeleteDirectConnectGatewayError> {
+        (**self).delete_direct_connect_gateway(input)
+    }
+    fn delete_direct_connect_gateway_association(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        DeleteDirectConnectGatewayAssociationResult,
+        DeleteDirectConnectGatewayAssociationError,
+    > {
+        (**self).delete_direct_connect_gateway_association(input)
+    }
+    fn delete_direct_connect_gateway_association_proposal(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        DeleteDirectConnectGatewayAssociationProposalResult,
+        DeleteDirectConnectGatewayAssociationProposalError,
+    > {
+        (**self).delete_direct_connect_gateway_association_proposal(input)
+    }
+    fn delete_interconnect(
+        &self,
+        input: DeleteInterconnectRequest,
+    ) -> RusotoFuture<DeleteInterconnectResponse, DeleteInterconnectError> {
+        (**self).delete_interconnect(input)
+    }
+    fn delete_lag(&self, input: DeleteLagRequest) -> RusotoFuture<Lag, DeleteLagError> {
+        (**self).delete_lag(input)
+    }
+    fn delete_virtual_interface(
+        &self,
+        input: DeleteVirtualInterfaceRequest,
+    ) -> RusotoFuture<DeleteVirtualInterfaceResponse, DeleteVirtualInterfaceError> {
+        (**self).delete_virtual_interface(input)
+    }
+    fn describe_connection_loa(
+        &self,
+        input: DescribeConnectionLoaRequest,
+    ) -> RusotoFuture<DescribeConnectionLoaResponse, DescribeConnectionLoaError> {
+        (**self).describe_connection_loa(input)
+    }
+    fn describe_connections(
+        &self,
+        input: DescribeConnectionsRequest,
+    ) -> RusotoFuture<Connections, DescribeConnectionsError> {
+        (**self).describe_connections(input)
+    }
+    fn describe_connections_on_interconnect(
+        &self,
+        input: DescribeConnectionsOnInterconnectRequest,
+    ) -> RusotoFuture<Connections, DescribeConnectionsOnInterconnectError> {
+        (**self).describe_connections_on_interconnect(input)
+    }
+    fn describe_direct_connect_gateway_association_proposals(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationProposalsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAssociationProposalsResult,
+        DescribeDirectConnectGatewayAssociationProposalsError,
+    > {
+        (**self).describe_direct_connect_gateway_association_proposals(input)
+    }
+    fn describe_direct_connect_gateway_associations(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAssociationsResult,
+        DescribeDirectConnectGatewayAssociationsError,
+    > {
+        (**self).describe_direct_connect_gateway_associations(input)
+    }
+    fn describe_direct_connect_gateway_attachments(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAttachmentsResult,
+        DescribeDirectConnectGatewayAttachmentsError,
+    > {
+        (**self).describe_direct_connect_gateway_attachments(input)
+    }
+    fn describe_direct_connect_gateways(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> RusotoFuture<DescribeDirectConnectGatewaysResult, DescribeDirectConnectGatewaysError> {
+        (**self).describe_direct_connect_gateways(input)
+    }
+    fn describe_hosted_connections(
+        &self,
+        input: DescribeHostedConnectionsRequest,
+    ) -> RusotoFuture<Connections, DescribeHostedConnectionsError> {
+        (**self).describe_hosted_connections(input)
+    }
+    fn describe_interconnect_loa(
+        &self,
+        input: DescribeInterconnectLoaRequest,
+    ) -> RusotoFuture<DescribeInterconnectLoaResponse, DescribeInterconnectLoaError> {
+        (**self).describe_interconnect_loa(input)
+    }
+    fn describe_interconnects(
+        &self,
+        input: DescribeInterconnectsRequest,
+    ) -> RusotoFuture<Interconnects, DescribeInterconnectsError> {
+        (**self).describe_interconnects(input)
+    }
+    fn describe_lags(&self, input: DescribeLagsRequest) -> RusotoFuture<Lags, DescribeLagsError> {
+        (**self).describe_lags(input)
+    }
+    fn describe_loa(&self, input: DescribeLoaRequest) -> RusotoFuture<Loa, DescribeLoaError> {
+        (**self).describe_loa(input)
+    }
+    fn describe_locations(&self) -> RusotoFuture<Locations, DescribeLocationsError> {
+        (**self).describe_locations()
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_virtual_gateways(
+        &self,
+    ) -> RusotoFuture<VirtualGateways, DescribeVirtualGatewaysError> {
+        (**self).describe_virtual_gateways()
+    }
+    fn describe_virtual_interfaces(
+        &self,
+        input: DescribeVirtualInterfacesRequest,
+    ) -> RusotoFuture<VirtualInterfaces, DescribeVirtualInterfacesError> {
+        (**self).describe_virtual_interfaces(input)
+    }
+    fn disassociate_connection_from_lag(
+        &self,
+        input: DisassociateConnectionFromLagRequest,
+    ) -> RusotoFuture<Connection, DisassociateConnectionFromLagError> {
+        (**self).disassociate_connection_from_lag(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_direct_connect_gateway_association(
+        &self,
+        input: UpdateDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        UpdateDirectConnectGatewayAssociationResult,
+        UpdateDirectConnectGatewayAssociationError,
+    > {
+        (**self).update_direct_connect_gateway_association(input)
+    }
+    fn update_lag(&self, input: UpdateLagRequest) -> RusotoFuture<Lag, UpdateLagError> {
+        (**self).update_lag(input)
+    }
+    fn update_virtual_interface_attributes(
+        &self,
+        input: UpdateVirtualInterfaceAttributesRequest,
+    ) -> RusotoFuture<VirtualInterface, UpdateVirtualInterfaceAttributesError> {
+        (**self).update_virtual_interface_attributes(input)
+    }
+}
+impl<T: DirectConnect + ?Sized> DirectConnect for ::std::sync::Arc<T> {
+    fn accept_direct_connect_gateway_association_proposal(
+        &self,
+        input: AcceptDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        AcceptDirectConnectGatewayAssociationProposalResult,
+        AcceptDirectConnectGatewayAssociationProposalError,
+    > {
+        (**self).accept_direct_connect_gateway_association_proposal(input)
+    }
+    fn allocate_connection_on_interconnect(
+        &self,
+        input: AllocateConnectionOnInterconnectRequest,
+    ) -> RusotoFuture<Connection, AllocateConnectionOnInterconnectError> {
+        (**self).allocate_connection_on_interconnect(input)
+    }
+    fn allocate_hosted_connection(
+        &self,
+        input: AllocateHostedConnectionRequest,
+    ) -> RusotoFuture<Connection, AllocateHostedConnectionError> {
+        (**self).allocate_hosted_connection(input)
+    }
+    fn allocate_private_virtual_interface(
+        &self,
+        input: AllocatePrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AllocatePrivateVirtualInterfaceError> {
+        (**self).allocate_private_virtual_interface(input)
+    }
+    fn allocate_public_virtual_interface(
+        &self,
+        input: AllocatePublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AllocatePublicVirtualInterfaceError> {
+        (**self).allocate_public_virtual_interface(input)
+    }
+    fn allocate_transit_virtual_interface(
+        &self,
+        input: AllocateTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<AllocateTransitVirtualInterfaceResult, AllocateTransitVirtualInterfaceError>
+    {
+        (**self).allocate_transit_virtual_interface(input)
+    }
+    fn associate_connection_with_lag(
+        &self,
+        input: AssociateConnectionWithLagRequest,
+    ) -> RusotoFuture<Connection, AssociateConnectionWithLagError> {
+        (**self).associate_connection_with_lag(input)
+    }
+    fn associate_hosted_connection(
+        &self,
+        input: AssociateHostedConnectionRequest,
+    ) -> RusotoFuture<Connection, AssociateHostedConnectionError> {
+        (**self).associate_hosted_connection(input)
+    }
+    fn associate_virtual_interface(
+        &self,
+        input: AssociateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AssociateVirtualInterfaceError> {
+        (**self).associate_virtual_interface(input)
+    }
+    fn confirm_connection(
+        &self,
+        input: ConfirmConnectionRequest,
+    ) -> RusotoFuture<ConfirmConnectionResponse, ConfirmConnectionError> {
+        (**self).confirm_connection(input)
+    }
+    fn confirm_private_virtual_interface(
+        &self,
+        input: ConfirmPrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmPrivateVirtualInterfaceResponse, ConfirmPrivateVirtualInterfaceError>
+    {
+        (**self).confirm_private_virtual_interface(input)
+    }
+    fn confirm_public_virtual_interface(
+        &self,
+        input: ConfirmPublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmPublicVirtualInterfaceResponse, ConfirmPublicVirtualInterfaceError>
+    {
+        (**self).confirm_public_virtual_interface(input)
+    }
+    fn confirm_transit_virtual_interface(
+        &self,
+        input: ConfirmTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmTransitVirtualInterfaceResponse, ConfirmTransitVirtualInterfaceError>
+    {
+        (**self).confirm_transit_virtual_interface(input)
+    }
+    fn create_bgp_peer(
+        &self,
+        input: CreateBGPPeerRequest,
+    ) -> RusotoFuture<CreateBGPPeerResponse, CreateBGPPeerError> {
+        (**self).create_bgp_peer(input)
+    }
+    fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> RusotoFuture<Connection, CreateConnectionError> {
+        (**self).create_connection(input)
+    }
+    fn create_direct_connect_gateway(
+        &self,
+        input: CreateDirectConnectGatewayRequest,
+    ) -> RusotoFuture<CreateDirectConnectGatewayResult, CreateDirectConnectGatewayError> {
+        (**self).create_direct_connect_gateway(input)
+    }
+    fn create_direct_connect_gateway_association(
+        &self,
+        input: CreateDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        CreateDirectConnectGatewayAssociationResult,
+        CreateDirectConnectGatewayAssociationError,
+    > {
+        (**self).create_direct_connect_gateway_association(input)
+    }
+    fn create_direct_connect_gateway_association_proposal(
+        &self,
+        input: CreateDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        CreateDirectConnectGatewayAssociationProposalResult,
+        CreateDirectConnectGatewayAssociationProposalError,
+    > {
+        (**self).create_direct_connect_gateway_association_proposal(input)
+    }
+    fn create_interconnect(
+        &self,
+        input: CreateInterconnectRequest,
+    ) -> RusotoFuture<Interconnect, CreateInterconnectError> {
+        (**self).create_interconnect(input)
+    }
+    fn create_lag(&self, input: CreateLagRequest) -> RusotoFuture<Lag, CreateLagError> {
+        (**self).create_lag(input)
+    }
+    fn create_private_virtual_interface(
+        &self,
+        input: CreatePrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, CreatePrivateVirtualInterfaceError> {
+        (**self).create_private_virtual_interface(input)
+    }
+    fn create_public_virtual_interface(
+        &self,
+        input: CreatePublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, CreatePublicVirtualInterfaceError> {
+        (**self).create_public_virtual_interface(input)
+    }
+    fn create_transit_virtual_interface(
+        &self,
+        input: CreateTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<CreateTransitVirtualInterfaceResult, CreateTransitVirtualInterfaceError> {
+        (**self).create_transit_virtual_interface(input)
+    }
+    fn delete_bgp_peer(
+        &self,
+        input: DeleteBGPPeerRequest,
+    ) -> RusotoFuture<DeleteBGPPeerResponse, DeleteBGPPeerError> {
+        (**self).delete_bgp_peer(input)
+    }
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<Connection, DeleteConnectionError> {
+        (**self).delete_connection(input)
+    }
+    fn delete_direct_connect_gateway(
+        &self,
+        input: DeleteDirectConnectGatewayRequest,
+    ) -> RusotoFuture<DeleteDirectConnectGatewayResult, DeleteDirectConnectGatewayError> {
+        (**self).delete_direct_connect_gateway(input)
+    }
+    fn delete_direct_connect_gateway_association(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        DeleteDirectConnectGatewayAssociationResult,
+        DeleteDirectConnectGatewayAssociationError,
+    > {
+        (**self).delete_direct_connect_gateway_association(input)
+    }
+    fn delete_direct_connect_gateway_association_proposal(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        DeleteDirectConnectGatewayAssociationProposalResult,
+        DeleteDirectConnectGatewayAssociationProposalError,
+    > {
+        (**self).delete_direct_connect_gateway_association_proposal(input)
+    }
+    fn delete_interconnect(
+        &self,
+        input: DeleteInterconnectRequest,
+    ) -> RusotoFuture<DeleteInterconnectResponse, DeleteInterconnectError> {
+        (**self).delete_interconnect(input)
+    }
+    fn delete_lag(&self, input: DeleteLagRequest) -> RusotoFuture<Lag, DeleteLagError> {
+        (**self).delete_lag(input)
+    }
+    fn delete_virtual_interface(
+        &self,
+        input: DeleteVirtualInterfaceRequest,
+    ) -> RusotoFuture<DeleteVirtualInterfaceResponse, DeleteVirtualInterfaceError> {
+        (**self).delete_virtual_interface(input)
+    }
+    fn describe_connection_loa(
+        &self,
+        input: DescribeConnectionLoaRequest,
+    ) -> RusotoFuture<DescribeConnectionLoaResponse, DescribeConnectionLoaError> {
+        (**self).describe_connection_loa(input)
+    }
+    fn describe_connections(
+        &self,
+        input: DescribeConnectionsRequest,
+    ) -> RusotoFuture<Connections, DescribeConnectionsError> {
+        (**self).describe_connections(input)
+    }
+    fn describe_connections_on_interconnect(
+        &self,
+        input: DescribeConnectionsOnInterconnectRequest,
+    ) -> RusotoFuture<Connections, DescribeConnectionsOnInterconnectError> {
+        (**self).describe_connections_on_interconnect(input)
+    }
+    fn describe_direct_connect_gateway_association_proposals(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationProposalsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAssociationProposalsResult,
+        DescribeDirectConnectGatewayAssociationProposalsError,
+    > {
+        (**self).describe_direct_connect_gateway_association_proposals(input)
+    }
+    fn describe_direct_connect_gateway_associations(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAssociationsResult,
+        DescribeDirectConnectGatewayAssociationsError,
+    > {
+        (**self).describe_direct_connect_gateway_associations(input)
+    }
+    fn describe_direct_connect_gateway_attachments(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAttachmentsResult,
+        DescribeDirectConnectGatewayAttachmentsError,
+    > {
+        (**self).describe_direct_connect_gateway_attachments(input)
+    }
+    fn describe_direct_connect_gateways(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> RusotoFuture<DescribeDirectConnectGatewaysResult, DescribeDirectConnectGatewaysError> {
+        (**self).describe_direct_connect_gateways(input)
+    }
+    fn describe_hosted_connections(
+        &self,
+        input: DescribeHostedConnectionsRequest,
+    ) -> RusotoFuture<Connections, DescribeHostedConnectionsError> {
+        (**self).describe_hosted_connections(input)
+    }
+    fn describe_interconnect_loa(
+        &self,
+        input: DescribeInterconnectLoaRequest,
+    ) -> RusotoFuture<DescribeInterconnectLoaResponse, DescribeInterconnectLoaError> {
+        (**self).describe_interconnect_loa(input)
+    }
+    fn describe_interconnects(
+        &self,
+        input: DescribeInterconnectsRequest,
+    ) -> RusotoFuture<Interconnects, DescribeInterconnectsError> {
+        (**self).describe_interconnects(input)
+    }
+    fn describe_lags(&self, input: DescribeLagsRequest) -> RusotoFuture<Lags, DescribeLagsError> {
+        (**self).describe_lags(input)
+    }
+    fn describe_loa(&self, input: DescribeLoaRequest) -> RusotoFuture<Loa, DescribeLoaError> {
+        (**self).describe_loa(input)
+    }
+    fn describe_locations(&self) -> RusotoFuture<Locations, DescribeLocationsError> {
+        (**self).describe_locations()
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_virtual_gateways(
+        &self,
+    ) -> RusotoFuture<VirtualGateways, DescribeVirtualGatewaysError> {
+        (**self).describe_virtual_gateways()
+    }
+    fn describe_virtual_interfaces(
+        &self,
+        input: DescribeVirtualInterfacesRequest,
+    ) -> RusotoFuture<VirtualInterfaces, DescribeVirtualInterfacesError> {
+        (**self).describe_virtual_interfaces(input)
+    }
+    fn disassociate_connection_from_lag(
+        &self,
+        input: DisassociateConnectionFromLagRequest,
+    ) -> RusotoFuture<Connection, DisassociateConnectionFromLagError> {
+        (**self).disassociate_connection_from_lag(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_direct_connect_gateway_association(
+        &self,
+        input: UpdateDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        UpdateDirectConnectGatewayAssociationResult,
+        UpdateDirectConnectGatewayAssociationError,
+    > {
+        (**self).update_direct_connect_gateway_association(input)
+    }
+    fn update_lag(&self, input: UpdateLagRequest) -> RusotoFuture<Lag, UpdateLagError> {
+        (**self).update_lag(input)
+    }
+    fn update_virtual_interface_attributes(
+        &self,
+        input: UpdateVirtualInterfaceAttributesRequest,
+    ) -> RusotoFuture<VirtualInterface, UpdateVirtualInterfaceAttributesError> {
+        (**self).update_virtual_interface_attributes(input)
+    }
+}
+/// A client for the AWS Direct Connect API.
+#[derive(Clone)]
+pub struct DirectConnectClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl DirectConnectClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DirectConnectClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DirectConnectClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> DirectConnectClient {
+        DirectConnectClient { client, region }
+    }
+}
+
+impl DirectConnect for DirectConnectClient {
+    /// <p>Accepts a proposal request to attach a virtual private gateway or transit gateway to a Direct Connect gateway.</p>
+    fn accept_direct_connect_gateway_association_proposal(
+        &self,
+        input: AcceptDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        AcceptDirectConnectGatewayAssociationProposalResult,
+        AcceptDirectConnectGatewayAssociationProposalError,
+    > {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AcceptDirectConnectGatewayAssociationProposal");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.AcceptDirectConnectGatewayAssociationProposal",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    AcceptDirectConnectGatewayAssociationProposalResult,
+                    _,
+                >(response, "AcceptDirectConnectGatewayAssociationProposal")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AcceptDirectConnectGatewayAssociationProposalError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deprecated. Use <a>AllocateHostedConnection</a> instead.</p> <p>Creates a hosted connection on an interconnect.</p> <p>Allocates a VLAN number and a specified amount of bandwidth for use by a hosted connection on the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    fn allocate_connection_on_interconnect(
+        &self,
+        input: AllocateConnectionOnInterconnectRequest,
+    ) -> RusotoFuture<Connection, AllocateConnectionOnInterconnectError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocateConnectionOnInterconnect");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.AllocateConnectionOnInterconnect",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Connection, _>(
+                    response,
+                    "AllocateConnectionOnInterconnect",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AllocateConnectionOnInterconnectError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates a hosted connection on the specified interconnect or a link aggregation group (LAG) of interconnects.</p> <p>Allocates a VLAN number and a specified amount of capacity (bandwidth) for use by a hosted connection on the specified interconnect or LAG of interconnects. AWS polices the hosted connection for the specified capacity and the AWS Direct Connect Partner must also police the hosted connection for the specified capacity.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    fn allocate_hosted_connection(
+        &self,
+        input: AllocateHostedConnectionRequest,
+    ) -> RusotoFuture<Connection, AllocateHostedConnectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocateHostedConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.AllocateHostedConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Connection, _>(
+                    response,
+                    "AllocateHostedConnection",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AllocateHostedConnectionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provisions a private virtual interface to be owned by the specified AWS account.</p> <p>Virtual interfaces created using this action must be confirmed by the owner using <a>ConfirmPrivateVirtualInterface</a>. Until then, the virtual interface is in the <code>Confirming</code> state and is not available to handle traffic.</p>
+    fn allocate_private_virtual_interface(
+        &self,
+        input: AllocatePrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AllocatePrivateVirtualInterfaceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocatePrivateVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.AllocatePrivateVirtualInterface",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VirtualInterface, _>(
+                    response,
+                    "AllocatePrivateVirtualInterface",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AllocatePrivateVirtualInterfaceError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provisions a public virtual interface to be owned by the specified AWS account.</p> <p>The owner of a connection calls this function to provision a public virtual interface to be owned by the specified AWS account.</p> <p>Virtual interfaces created using this function must be confirmed by the owner using <a>ConfirmPublicVirtualInterface</a>. Until this step has been completed, the virtual interface is in the <code>confirming</code> state and is not available to handle traffic.</p> <p>When creating an IPv6 public virtual interface, omit the Amazon address and customer address. IPv6 addresses are automatically assigned from the Amazon pool of IPv6 addresses; you cannot specify custom IPv6 addresses.</p>
+    fn allocate_public_virtual_interface(
+        &self,
+        input: AllocatePublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AllocatePublicVirtualInterfaceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocatePublicVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.AllocatePublicVirtualInterface",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VirtualInterface, _>(
+                    response,
+                    "AllocatePublicVirtualInterface",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AllocatePublicVirtualInterfaceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provisions a transit virtual interface to be owned by the specified AWS account. Use this type of interface to connect a transit gateway to your Direct Connect gateway.</p> <p>The owner of a connection provisions a transit virtual interface to be owned by the specified AWS account.</p> <p>After you create a transit virtual interface, it must be confirmed by the owner using <a>ConfirmTransitVirtualInterface</a>. Until this step has been completed, the transit virtual interface is in the <code>requested</code> state and is not available to handle traffic.</p>
+    fn allocate_transit_virtual_interface(
+        &self,
+        input: AllocateTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<AllocateTransitVirtualInterfaceResult, AllocateTransitVirtualInterfaceError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocateTransitVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.AllocateTransitVirtualInterface",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AllocateTransitVirtualInterfaceResult, _>(
+                    response,
+                    "AllocateTransitVirtualInterface",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AllocateTransitVirtualInterfaceError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates an existing connection with a link aggregation group (LAG). The connection is interrupted and re-established as a member of the LAG (connectivity to AWS is interrupted). The connection must be hosted on the same AWS Direct Connect endpoint as the LAG, and its bandwidth must match the bandwidth for the LAG. You can re-associate a connection that's currently associated with a different LAG; however, if removing the connection would cause the original LAG to fall below its setting for minimum number of operational connections, the request fails.</p> <p>Any virtual interfaces that are directly associated with the connection are automatically re-associated with the LAG. If the connection was originally associated with a different LAG, the virtual interfaces remain associated with the original LAG.</p> <p>For interconnects, any hosted connections are automatically re-associated with the LAG. If the interconnect was originally associated with a different LAG, the hosted connections remain associated with the original LAG.</p>
+    fn associate_connection_with_lag(
+        &self,
+        input: AssociateConnectionWithLagRequest,
+    ) -> RusotoFuture<Connection, AssociateConnectionWithLagError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AssociateConnectionWithLag");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.AssociateConnectionWithLag");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Connection, _>(
+                    response,
+                    "AssociateConnectionWithLag",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateConnectionWithLagError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Associates a hosted connection and its virtual interfaces with a link aggregation group (LAG) or interconnect. If the target interconnect or LAG has an existing hosted connection with a conflicting VLAN number or IP address, the operation fails. This action temporarily interrupts the hosted connection&#39;s connectivity to AWS as it is being migrated.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    fn associate_hosted_connection(
+        &self,
+        input: AssociateHostedConnectionRequest,
+    ) -> RusotoFuture<Connection, AssociateHostedConnectionError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AssociateHostedConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.AssociateHostedConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Connection, _>(
+                    response,
+                    "AssociateHostedConnection",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateHostedConnectionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates a virtual interface with a specified link aggregation group (LAG) or connection. Connectivity to AWS is temporarily interrupted as the virtual interface is being migrated. If the target connection or LAG has an associated virtual interface with a conflicting VLAN number or a conflicting IP address, the operation fails.</p> <p>Virtual interfaces associated with a hosted connection cannot be associated with a LAG; hosted connections must be migrated along with their virtual interfaces using <a>AssociateHostedConnection</a>.</p> <p>To reassociate a virtual interface to a new connection or LAG, the requester must own either the virtual interface itself or the connection to which the virtual interface is currently associated. Additionally, the requester must own the connection or LAG for the association.</p>
+    fn associate_virtual_interface(
+        &self,
+        input: AssociateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AssociateVirtualInterfaceError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AssociateVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.AssociateVirtualInterface");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VirtualInterface, _>(
+                    response,
+                    "AssociateVirtualInterface",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateVirtualInterfaceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Confirms the creation of the specified hosted connection on an interconnect.</p> <p>Upon creation, the hosted connection is initially in the <code>Ordering</code> state, and remains in this state until the owner confirms creation of the hosted connection.</p>
+    fn confirm_connection(
+        &self,
+        input: ConfirmConnectionRequest,
+    ) -> RusotoFuture<ConfirmConnectionResponse, ConfirmConnectionError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.ConfirmConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ConfirmConnectionResponse, _>(
+                    response,
+                    "ConfirmConnection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ConfirmConnectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Accepts ownership of a private virtual interface created by another AWS account.</p> <p>After the virtual interface owner makes this call, the virtual interface is created and attached to the specified virtual private gateway or Direct Connect gateway, and is made available to handle traffic.</p>
+    fn confirm_private_virtual_interface(
+        &self,
+        input: ConfirmPrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmPrivateVirtualInterfaceResponse, ConfirmPrivateVirtualInterfaceError>
+    {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmPrivateVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.ConfirmPrivateVirtualInterface",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ConfirmPrivateVirtualInterfaceResponse, _>(
+                    response,
+                    "ConfirmPrivateVirtualInterface",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ConfirmPrivateVirtualInterfaceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Accepts ownership of a public virtual interface created by another AWS account.</p> <p>After the virtual interface owner makes this call, the specified virtual interface is created and made available to handle traffic.</p>
+    fn confirm_public_virtual_interface(
+        &self,
+        input: ConfirmPublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmPublicVirtualInterfaceResponse, ConfirmPublicVirtualInterfaceError>
+    {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmPublicVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.ConfirmPublicVirtualInterface",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ConfirmPublicVirtualInterfaceResponse, _>(
+                    response,
+                    "ConfirmPublicVirtualInterface",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ConfirmPublicVirtualInterfaceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Accepts ownership of a transit virtual interface created by another AWS account.</p> <p> After the owner of the transit virtual interface makes this call, the specified transit virtual interface is created and made available to handle traffic.</p>
+    fn confirm_transit_virtual_interface(
+        &self,
+        input: ConfirmTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmTransitVirtualInterfaceResponse, ConfirmTransitVirtualInterfaceError>
+    {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmTransitVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.ConfirmTransitVirtualInterface",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ConfirmTransitVirtualInterfaceResponse, _>(
+                    response,
+                    "ConfirmTransitVirtualInterface",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ConfirmTransitVirtualInterfaceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a BGP peer on the specified virtual interface.</p> <p>You must create a BGP peer for the corresponding address family (IPv4/IPv6) in order to access AWS resources that also use that address family.</p> <p>If logical redundancy is not supported by the connection, interconnect, or LAG, the BGP peer cannot be in the same address family as an existing BGP peer on the virtual interface.</p> <p>When creating a IPv6 BGP peer, omit the Amazon address and customer address. IPv6 addresses are automatically assigned from the Amazon pool of IPv6 addresses; you cannot specify custom IPv6 addresses.</p> <p>For a public virtual interface, the Autonomous System Number (ASN) must be private or already whitelisted for the virtual interface.</p>
+    fn create_bgp_peer(
+        &self,
+        input: CreateBGPPeerRequest,
+    ) -> RusotoFuture<CreateBGPPeerResponse, CreateBGPPeerError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateBGPPeer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.CreateBGPPeer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateBGPPeerResponse, _>(
+                    response,
+                    "CreateBGPPeer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBGPPeerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a connection between a customer network and a specific AWS Direct Connect location.</p> <p>A connection links your internal network to an AWS Direct Connect location over a standard Ethernet fiber-optic cable. One end of the cable is connected to your router, the other to an AWS Direct Connect router.</p> <p>To find the locations for your Region, use <a>DescribeLocations</a>.</p> <p>You can automatically add the new connection to a link aggregation group (LAG) by specifying a LAG ID in the request. This ensures that the new connection is allocated on the same AWS Direct Connect endpoint that hosts the specified LAG. If there are no available ports on the endpoint, the request fails and no connection is created.</p>
+    fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> RusotoFuture<Connection, CreateConnectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.CreateConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Connection, _>(response, "CreateConnection")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateConnectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Direct Connect gateway, which is an intermediate object that enables you to connect a set of virtual interfaces and virtual private gateways. A Direct Connect gateway is global and visible in any AWS Region after it is created. The virtual interfaces and virtual private gateways that are connected through a Direct Connect gateway can be in different AWS Regions. This enables you to connect to a VPC in any Region, regardless of the Region in which the virtual interfaces are located, and pass traffic between them.</p>
+    fn create_direct_connect_gateway(
+        &self,
+        input: CreateDirectConnectGatewayRequest,
+    ) -> RusotoFuture<CreateDirectConnectGatewayResult, CreateDirectConnectGatewayError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateDirectConnectGateway");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.CreateDirectConnectGateway");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDirectConnectGatewayResult, _>(
+                    response,
+                    "CreateDirectConnectGateway",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDirectConnectGatewayError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates an association between a Direct Connect gateway and a virtual private gateway. The virtual private gateway must be attached to a VPC and must not be associated with another Direct Connect gateway.</p>
+    fn create_direct_connect_gateway_association(
+        &self,
+        input: CreateDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        CreateDirectConnectGatewayAssociationResult,
+        CreateDirectConnectGatewayAssociationError,
+    > {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateDirectConnectGatewayAssociation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.CreateDirectConnectGatewayAssociation",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDirectConnectGatewayAssociationResult, _>(response, "CreateDirectConnectGatewayAssociation")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDirectConnectGatewayAssociationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a proposal to associate the specified virtual private gateway or transit gateway with the specified Direct Connect gateway.</p> <p>You can only associate a Direct Connect gateway and virtual private gateway or transit gateway when the account that owns the Direct Connect gateway and the account that owns the virtual private gateway or transit gateway have the same AWS Payer ID.</p>
+    fn create_direct_connect_gateway_association_proposal(
+        &self,
+        input: CreateDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        CreateDirectConnectGatewayAssociationProposalResult,
+        CreateDirectConnectGatewayAssociationProposalError,
+    > {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateDirectConnectGatewayAssociationProposal");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.CreateDirectConnectGatewayAssociationProposal",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    CreateDirectConnectGatewayAssociationProposalResult,
+                    _,
+                >(response, "CreateDirectConnectGatewayAssociationProposal")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDirectConnectGatewayAssociationProposalError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates an interconnect between an AWS Direct Connect Partner&#39;s network and a specific AWS Direct Connect location.</p> <p>An interconnect is a connection that is capable of hosting other connections. The AWS Direct Connect partner can use an interconnect to provide AWS Direct Connect hosted connections to customers through their own network services. Like a standard connection, an interconnect links the partner&#39;s network to an AWS Direct Connect location over a standard Ethernet fiber-optic cable. One end is connected to the partner&#39;s router, the other to an AWS Direct Connect router.</p> <p>You can automatically add the new interconnect to a link aggregation group (LAG) by specifying a LAG ID in the request. This ensures that the new interconnect is allocated on the same AWS Direct Connect endpoint that hosts the specified LAG. If there are no available ports on the endpoint, the request fails and no interconnect is created.</p> <p>For each end customer, the AWS Direct Connect Partner provisions a connection on their interconnect by calling <a>AllocateHostedConnection</a>. The end customer can then connect to AWS resources by creating a virtual interface on their connection, using the VLAN assigned to them by the AWS Direct Connect Partner.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    fn create_interconnect(
+        &self,
+        input: CreateInterconnectRequest,
+    ) -> RusotoFuture<Interconnect, CreateInterconnectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateInterconnect");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.CreateInterconnect");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Interconnect, _>(
+                    response,
+                    "CreateInterconnect",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateInterconnectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a link aggregation group (LAG) with the specified number of bundled physical connections between the customer network and a specific AWS Direct Connect location. A LAG is a logical interface that uses the Link Aggregation Control Protocol (LACP) to aggregate multiple interfaces, enabling you to treat them as a single interface.</p> <p>All connections in a LAG must use the same bandwidth and must terminate at the same AWS Direct Connect endpoint.</p> <p>You can have up to 10 connections per LAG. Regardless of this limit, if you request more connections for the LAG than AWS Direct Connect can allocate on a single endpoint, no LAG is created.</p> <p>You can specify an existing physical connection or interconnect to include in the LAG (which counts towards the total number of connections). Doing so interrupts the current physical connection or hosted connections, and re-establishes them as a member of the LAG. The LAG will be created on the same AWS Direct Connect endpoint to which the connection terminates. Any virtual interfaces associated with the connection are automatically disassociated and re-associated with the LAG. The connection ID does not change.</p> <p>If the AWS account used to create a LAG is a registered AWS Direct Connect Partner, the LAG is automatically enabled to host sub-connections. For a LAG owned by a partner, any associated virtual interfaces cannot be directly configured.</p>
+    fn create_lag(&self, input: CreateLagRequest) -> RusotoFuture<Lag, CreateLagError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateLag");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.CreateLag");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Lag, _>(response, "CreateLag")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateLagError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a private virtual interface. A virtual interface is the VLAN that transports AWS Direct Connect traffic. A private virtual interface can be connected to either a Direct Connect gateway or a Virtual Private Gateway (VGW). Connecting the private virtual interface to a Direct Connect gateway enables the possibility for connecting to multiple VPCs, including VPCs in different AWS Regions. Connecting the private virtual interface to a VGW only provides access to a single VPC within the same Region.</p>
+    fn create_private_virtual_interface(
+        &self,
+        input: CreatePrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, CreatePrivateVirtualInterfaceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreatePrivateVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.CreatePrivateVirtualInterface",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VirtualInterface, _>(
+                    response,
+                    "CreatePrivateVirtualInterface",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreatePrivateVirtualInterfaceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a public virtual interface. A virtual interface is the VLAN that transports AWS Direct Connect traffic. A public virtual interface supports sending traffic to public services of AWS such as Amazon S3.</p> <p>When creating an IPv6 public virtual interface (<code>addressFamily</code> is <code>ipv6</code>), leave the <code>customer</code> and <code>amazon</code> address fields blank to use auto-assigned IPv6 space. Custom IPv6 addresses are not supported.</p>
+    fn create_public_virtual_interface(
+        &self,
+        input: CreatePublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, CreatePublicVirtualInterfaceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreatePublicVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.CreatePublicVirtualInterface",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VirtualInterface, _>(
+                    response,
+                    "CreatePublicVirtualInterface",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreatePublicVirtualInterfaceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates a transit virtual interface. A transit virtual interface should be used to access one or more transit gateways associated with Direct Connect gateways. A transit virtual interface enables the connection of multiple VPCs attached to a transit gateway to a Direct Connect gateway.</p> <important> <p>If you associate your transit gateway with one or more Direct Connect gateways, the Autonomous System Number (ASN) used by the transit gateway and the Direct Connect gateway must be different. For example, if you use the default ASN 64512 for both your the transit gateway and Direct Connect gateway, the association request fails.</p> </important></p>
+    fn create_transit_virtual_interface(
+        &self,
+        input: CreateTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<CreateTransitVirtualInterfaceResult, CreateTransitVirtualInterfaceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateTransitVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.CreateTransitVirtualInterface",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTransitVirtualInterfaceResult, _>(
+                    response,
+                    "CreateTransitVirtualInterface",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateTransitVirtualInterfaceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified BGP peer on the specified virtual interface with the specified customer address and ASN.</p> <p>You cannot delete the last BGP peer from a virtual interface.</p>
+    fn delete_bgp_peer(
+        &self,
+        input: DeleteBGPPeerRequest,
+    ) -> RusotoFuture<DeleteBGPPeerResponse, DeleteBGPPeerError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteBGPPeer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DeleteBGPPeer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteBGPPeerResponse, _>(
+                    response,
+                    "DeleteBGPPeer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBGPPeerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified connection.</p> <p>Deleting a connection only stops the AWS Direct Connect port hour and data transfer charges. If you are partnering with any third parties to connect with the AWS Direct Connect location, you must cancel your service with them separately.</p>
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<Connection, DeleteConnectionError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DeleteConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Connection, _>(response, "DeleteConnection")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteConnectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified Direct Connect gateway. You must first delete all virtual interfaces that are attached to the Direct Connect gateway and disassociate all virtual private gateways that are associated with the Direct Connect gateway.</p>
+    fn delete_direct_connect_gateway(
+        &self,
+        input: DeleteDirectConnectGatewayRequest,
+    ) -> RusotoFuture<DeleteDirectConnectGatewayResult, DeleteDirectConnectGatewayError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteDirectConnectGateway");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DeleteDirectConnectGateway");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDirectConnectGatewayResult, _>(
+                    response,
+                    "DeleteDirectConnectGateway",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteDirectConnectGatewayError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the association between the specified Direct Connect gateway and virtual private gateway.</p>
+    fn delete_direct_connect_gateway_association(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        DeleteDirectConnectGatewayAssociationResult,
+        DeleteDirectConnectGatewayAssociationError,
+    > {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteDirectConnectGatewayAssociation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.DeleteDirectConnectGatewayAssociation",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDirectConnectGatewayAssociationResult, _>(response, "DeleteDirectConnectGatewayAssociation")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteDirectConnectGatewayAssociationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the association proposal request between the specified Direct Connect gateway and virtual private gateway or transit gateway.</p>
+    fn delete_direct_connect_gateway_association_proposal(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        DeleteDirectConnectGatewayAssociationProposalResult,
+        DeleteDirectConnectGatewayAssociationProposalError,
+    > {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteDirectConnectGatewayAssociationProposal");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.DeleteDirectConnectGatewayAssociationProposal",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DeleteDirectConnectGatewayAssociationProposalResult,
+                    _,
+                >(response, "DeleteDirectConnectGatewayAssociationProposal")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteDirectConnectGatewayAssociationProposalError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    fn delete_interconnect(
+        &self,
+        input: DeleteInterconnectRequest,
+    ) -> RusotoFuture<DeleteInterconnectResponse, DeleteInterconnectError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteInterconnect");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DeleteInterconnect");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteInterconnectResponse, _>(
+                    response,
+                    "DeleteInterconnect",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteInterconnectError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified link aggregation group (LAG). You cannot delete a LAG if it has active virtual interfaces or hosted connections.</p>
+    fn delete_lag(&self, input: DeleteLagRequest) -> RusotoFuture<Lag, DeleteLagError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteLag");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DeleteLag");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Lag, _>(response, "DeleteLag")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteLagError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a virtual interface.</p>
+    fn delete_virtual_interface(
+        &self,
+        input: DeleteVirtualInterfaceRequest,
+    ) -> RusotoFuture<DeleteVirtualInterfaceResponse, DeleteVirtualInterfaceError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteVirtualInterface");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DeleteVirtualInterface");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteVirtualInterfaceResponse, _>(
+                    response,
+                    "DeleteVirtualInterface",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteVirtualInterfaceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deprecated. Use <a>DescribeLoa</a> instead.</p> <p>Gets the LOA-CFA for a connection.</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that your APN partner or service provider uses when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
+    fn describe_connection_loa(
+        &self,
+        input: DescribeConnectionLoaRequest,
+    ) -> RusotoFuture<DescribeConnectionLoaResponse, DescribeConnectionLoaError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeConnectionLoa");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeConnectionLoa");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeConnectionLoaResponse, _>(
+                    response,
+                    "DescribeConnectionLoa",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeConnectionLoaError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Displays the specified connection or all connections in this Region.</p>
+    fn describe_connections(
+        &self,
+        input: DescribeConnectionsRequest,
+    ) -> RusotoFuture<Connections, DescribeConnectionsError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeConnections");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeConnections");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Connections, _>(
+                    response,
+                    "DescribeConnections",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeConnectionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deprecated. Use <a>DescribeHostedConnections</a> instead.</p> <p>Lists the connections that have been provisioned on the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    fn describe_connections_on_interconnect(
+        &self,
+        input: DescribeConnectionsOnInterconnectRequest,
+    ) -> RusotoFuture<Connections, DescribeConnectionsOnInterconnectError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeConnectionsOnInterconnect");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.DescribeConnectionsOnInterconnect",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Connections, _>(
+                    response,
+                    "DescribeConnectionsOnInterconnect",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeConnectionsOnInterconnectError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes one or more association proposals for connection between a virtual private gateway or transit gateway and a Direct Connect gateway. </p>
+    fn describe_direct_connect_gateway_association_proposals(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationProposalsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAssociationProposalsResult,
+        DescribeDirectConnectGatewayAssociationProposalsError,
+    > {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGatewayAssociationProposals");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.DescribeDirectConnectGatewayAssociationProposals",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DescribeDirectConnectGatewayAssociationProposalsResult,
+                    _,
+                >(response, "DescribeDirectConnectGatewayAssociationProposals")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(
+                        DescribeDirectConnectGatewayAssociationProposalsError::from_response(
+                            response,
+                        ),
+                    )
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the associations between your Direct Connect gateways and virtual private gateways. You must specify a Direct Connect gateway, a virtual private gateway, or both. If you specify a Direct Connect gateway, the response contains all virtual private gateways associated with the Direct Connect gateway. If you specify a virtual private gateway, the response contains all Direct Connect gateways associated with the virtual private gateway. If you specify both, the response contains the association between the Direct Connect gateway and the virtual private gateway.</p>
+    fn describe_direct_connect_gateway_associations(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAssociationsResult,
+        DescribeDirectConnectGatewayAssociationsError,
+    > {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGatewayAssociations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.DescribeDirectConnectGatewayAssociations",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DescribeDirectConnectGatewayAssociationsResult,
+                    _,
+                >(response, "DescribeDirectConnectGatewayAssociations")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDirectConnectGatewayAssociationsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the attachments between your Direct Connect gateways and virtual interfaces. You must specify a Direct Connect gateway, a virtual interface, or both. If you specify a Direct Connect gateway, the response contains all virtual interfaces attached to the Direct Connect gateway. If you specify a virtual interface, the response contains all Direct Connect gateways attached to the virtual interface. If you specify both, the response contains the attachment between the Direct Connect gateway and the virtual interface.</p>
+    fn describe_direct_connect_gateway_attachments(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAttachmentsResult,
+        DescribeDirectConnectGatewayAttachmentsError,
+    > {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGatewayAttachments");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.DescribeDirectConnectGatewayAttachments",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDirectConnectGatewayAttachmentsResult, _>(response, "DescribeDirectConnectGatewayAttachments")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDirectConnectGatewayAttachmentsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all your Direct Connect gateways or only the specified Direct Connect gateway. Deleted Direct Connect gateways are not returned.</p>
+    fn describe_direct_connect_gateways(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> RusotoFuture<DescribeDirectConnectGatewaysResult, DescribeDirectConnectGatewaysError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGateways");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.DescribeDirectConnectGateways",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDirectConnectGatewaysResult, _>(
+                    response,
+                    "DescribeDirectConnectGateways",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDirectConnectGatewaysError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the hosted connections that have been provisioned on the specified interconnect or link aggregation group (LAG).</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    fn describe_hosted_connections(
+        &self,
+        input: DescribeHostedConnectionsRequest,
+    ) -> RusotoFuture<Connections, DescribeHostedConnectionsError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeHostedConnections");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeHostedConnections");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Connections, _>(
+                    response,
+                    "DescribeHostedConnections",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeHostedConnectionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deprecated. Use <a>DescribeLoa</a> instead.</p> <p>Gets the LOA-CFA for the specified interconnect.</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that is used when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
+    fn describe_interconnect_loa(
+        &self,
+        input: DescribeInterconnectLoaRequest,
+    ) -> RusotoFuture<DescribeInterconnectLoaResponse, DescribeInterconnectLoaError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeInterconnectLoa");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeInterconnectLoa");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeInterconnectLoaResponse, _>(
+                    response,
+                    "DescribeInterconnectLoa",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeInterconnectLoaError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the interconnects owned by the AWS account or only the specified interconnect.</p>
+    fn describe_interconnects(
+        &self,
+        input: DescribeInterconnectsRequest,
+    ) -> RusotoFuture<Interconnects, DescribeInterconnectsError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeInterconnects");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeInterconnects");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Interconnects, _>(
+                    response,
+                    "DescribeInterconnects",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeInterconnectsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes all your link aggregation groups (LAG) or the specified LAG.</p>
+    fn describe_lags(&self, input: DescribeLagsRequest) -> RusotoFuture<Lags, DescribeLagsError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeLags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeLags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Lags, _>(response, "DescribeLags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeLagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the LOA-CFA for a connection, interconnect, or link aggregation group (LAG).</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that is used when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
+    fn describe_loa(&self, input: DescribeLoaRequest) -> RusotoFuture<Loa, DescribeLoaError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeLoa");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeLoa");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Loa, _>(response, "DescribeLoa")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeLoaError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the AWS Direct Connect locations in the current AWS Region. These are the locations that can be selected when calling <a>CreateConnection</a> or <a>CreateInterconnect</a>.</p>
+    fn describe_locations(&self) -> RusotoFuture<Locations, DescribeLocationsError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeLocations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeLocations");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Locations, _>(response, "DescribeLocations")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeLocationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the tags associated with the specified AWS Direct Connect resources.</p>
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTagsResponse, _>(
+                    response,
+                    "DescribeTags",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the virtual private gateways owned by the AWS account.</p> <p>You can create one or more AWS Direct Connect private virtual interfaces linked to a virtual private gateway.</p>
+    fn describe_virtual_gateways(
+        &self,
+    ) -> RusotoFuture<VirtualGateways, DescribeVirtualGatewaysError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeVirtualGateways");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeVirtualGateways");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VirtualGateways, _>(
+                    response,
+                    "DescribeVirtualGateways",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeVirtualGatewaysError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Displays all virtual interfaces for an AWS account. Virtual interfaces deleted fewer than 15 minutes before you make the request are also returned. If you specify a connection ID, only the virtual interfaces associated with the connection are returned. If you specify a virtual interface ID, then only a single virtual interface is returned.</p> <p>A virtual interface (VLAN) transmits the traffic between the AWS Direct Connect location and the customer network.</p>
+    fn describe_virtual_interfaces(
+        &self,
+        input: DescribeVirtualInterfacesRequest,
+    ) -> RusotoFuture<VirtualInterfaces, DescribeVirtualInterfacesError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeVirtualInterfaces");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.DescribeVirtualInterfaces");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VirtualInterfaces, _>(
+                    response,
+                    "DescribeVirtualInterfaces",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeVirtualInterfacesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates a connection from a link aggregation group (LAG). The connection is interrupted and re-established as a standalone connection (the connection is not deleted; to delete the connection, use the <a>DeleteConnection</a> request). If the LAG has associated virtual interfaces or hosted connections, they remain associated with the LAG. A disassociated connection owned by an AWS Direct Connect Partner is automatically converted to an interconnect.</p> <p>If disassociating the connection would cause the LAG to fall below its setting for minimum number of operational connections, the request fails, except when it's the last member of the LAG. If all connections are disassociated, the LAG continues to exist as an empty LAG with no physical connections. </p>
+    fn disassociate_connection_from_lag(
+        &self,
+        input: DisassociateConnectionFromLagRequest,
+    ) -> RusotoFuture<Connection, DisassociateConnectionFromLagError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DisassociateConnectionFromLag");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.DisassociateConnectionFromLag",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Connection, _>(
+                    response,
+                    "DisassociateConnectionFromLag",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateConnectionFromLagError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds the specified tags to the specified AWS Direct Connect resource. Each resource can have a maximum of 50 tags.</p> <p>Each tag consists of a key and an optional value. If a tag with the same key is already associated with the resource, this action updates its value.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes one or more tags from the specified AWS Direct Connect resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified attributes of the Direct Connect gateway association.</p> <p>Add or remove prefixes from the association.</p>
+    fn update_direct_connect_gateway_association(
+        &self,
+        input: UpdateDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        UpdateDirectConnectGatewayAssociationResult,
+        UpdateDirectConnectGatewayAssociationError,
+    > {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UpdateDirectConnectGatewayAssociation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.UpdateDirectConnectGatewayAssociation",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDirectConnectGatewayAssociationResult, _>(response, "UpdateDirectConnectGatewayAssociation")
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()
+                    Err(UpdateDirectConnectGatewayAssociationError::from_response(
+                        response,
+                    ))
                 }))
+            }
+        })
+    }
+
+    /// <p>Updates the attributes of the specified link aggregation group (LAG).</p> <p>You can update the following attributes:</p> <ul> <li> <p>The name of the LAG.</p> </li> <li> <p>The value for the minimum number of connections that must be operational for the LAG itself to be operational. </p> </li> </ul> <p>When you create a LAG, the default value for the minimum number of operational connections is zero (0). If you update this value and the number of operational connections falls below the specified value, the LAG automatically goes down to avoid over-utilization of the remaining connections. Adjust this value with care, as it could force the LAG down if it is set higher than the current number of operational connections.</p>
+    fn update_lag(&self, input: UpdateLagRequest) -> RusotoFuture<Lag, UpdateLagError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UpdateLag");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OvertureService.UpdateLag");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Lag, _>(response, "UpdateLag")
             } else {
                 Box::new(
                     response
@@ -6190,37 +7876,1900 @@ impl DirectConnect for DirectConnectClient {
                         .and_then(|response| Err(UpdateLagError::from_response(response))),
                 )
             }
-        })
+        })
+    }
+
+    /// <p>Updates the specified attributes of the specified virtual private interface.</p> <p>Setting the MTU of a virtual interface to 9001 (jumbo frames) can cause an update to the underlying physical connection if it wasn't updated to support jumbo frames. Updating the connection disrupts network connectivity for all virtual interfaces associated with the connection for up to 30 seconds. To check whether your connection supports jumbo frames, call <a>DescribeConnections</a>. To check whether your virtual interface supports jumbo frames, call <a>DescribeVirtualInterfaces</a>.</p>
+    fn update_virtual_interface_attributes(
+        &self,
+        input: UpdateVirtualInterfaceAttributesRequest,
+    ) -> RusotoFuture<VirtualInterface, UpdateVirtualInterfaceAttributesError> {
+        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UpdateVirtualInterfaceAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OvertureService.UpdateVirtualInterfaceAttributes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VirtualInterface, _>(
+                    response,
+                    "UpdateVirtualInterfaceAttributes",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateVirtualInterfaceAttributesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+}
+
+/// A mock of the AWS Direct Connect API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockDirectConnect {
+    accept_direct_connect_gateway_association_proposal: Option<
+        Box<
+            dyn Fn(
+                    AcceptDirectConnectGatewayAssociationProposalRequest,
+                ) -> ::std::result::Result<
+                    AcceptDirectConnectGatewayAssociationProposalResult,
+                    RusotoError<AcceptDirectConnectGatewayAssociationProposalError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    allocate_connection_on_interconnect: Option<
+        Box<
+            dyn Fn(
+                    AllocateConnectionOnInterconnectRequest,
+                ) -> ::std::result::Result<
+                    Connection,
+                    RusotoError<AllocateConnectionOnInterconnectError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    allocate_hosted_connection: Option<
+        Box<
+            dyn Fn(
+                    AllocateHostedConnectionRequest,
+                )
+                    -> ::std::result::Result<Connection, RusotoError<AllocateHostedConnectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    allocate_private_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    AllocatePrivateVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    VirtualInterface,
+                    RusotoError<AllocatePrivateVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    allocate_public_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    AllocatePublicVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    VirtualInterface,
+                    RusotoError<AllocatePublicVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    allocate_transit_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    AllocateTransitVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    AllocateTransitVirtualInterfaceResult,
+                    RusotoError<AllocateTransitVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_connection_with_lag: Option<
+        Box<
+            dyn Fn(
+                    AssociateConnectionWithLagRequest,
+                ) -> ::std::result::Result<
+                    Connection,
+                    RusotoError<AssociateConnectionWithLagError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_hosted_connection: Option<
+        Box<
+            dyn Fn(
+                    AssociateHostedConnectionRequest,
+                )
+                    -> ::std::result::Result<Connection, RusotoError<AssociateHostedConnectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    associate_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    AssociateVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    VirtualInterface,
+                    RusotoError<AssociateVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    confirm_connection: Option<
+        Box<
+            dyn Fn(
+                    ConfirmConnectionRequest,
+                ) -> ::std::result::Result<
+                    ConfirmConnectionResponse,
+                    RusotoError<ConfirmConnectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    confirm_private_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    ConfirmPrivateVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    ConfirmPrivateVirtualInterfaceResponse,
+                    RusotoError<ConfirmPrivateVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    confirm_public_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    ConfirmPublicVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    ConfirmPublicVirtualInterfaceResponse,
+                    RusotoError<ConfirmPublicVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    confirm_transit_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    ConfirmTransitVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    ConfirmTransitVirtualInterfaceResponse,
+                    RusotoError<ConfirmTransitVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_bgp_peer: Option<
+        Box<
+            dyn Fn(
+                    CreateBGPPeerRequest,
+                )
+                    -> ::std::result::Result<CreateBGPPeerResponse, RusotoError<CreateBGPPeerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_connection: Option<
+        Box<
+            dyn Fn(
+                    CreateConnectionRequest,
+                )
+                    -> ::std::result::Result<Connection, RusotoError<CreateConnectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_direct_connect_gateway: Option<
+        Box<
+            dyn Fn(
+                    CreateDirectConnectGatewayRequest,
+                ) -> ::std::result::Result<
+                    CreateDirectConnectGatewayResult,
+                    RusotoError<CreateDirectConnectGatewayError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_direct_connect_gateway_association: Option<
+        Box<
+            dyn Fn(
+                    CreateDirectConnectGatewayAssociationRequest,
+                ) -> ::std::result::Result<
+                    CreateDirectConnectGatewayAssociationResult,
+                    RusotoError<CreateDirectConnectGatewayAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_direct_connect_gateway_association_proposal: Option<
+        Box<
+            dyn Fn(
+                    CreateDirectConnectGatewayAssociationProposalRequest,
+                ) -> ::std::result::Result<
+                    CreateDirectConnectGatewayAssociationProposalResult,
+                    RusotoError<CreateDirectConnectGatewayAssociationProposalError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_interconnect: Option<
+        Box<
+            dyn Fn(
+                    CreateInterconnectRequest,
+                )
+                    -> ::std::result::Result<Interconnect, RusotoError<CreateInterconnectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_lag: Option<
+        Box<
+            dyn Fn(CreateLagRequest) -> ::std::result::Result<Lag, RusotoError<CreateLagError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_private_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    CreatePrivateVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    VirtualInterface,
+                    RusotoError<CreatePrivateVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_public_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    CreatePublicVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    VirtualInterface,
+                    RusotoError<CreatePublicVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_transit_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    CreateTransitVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    CreateTransitVirtualInterfaceResult,
+                    RusotoError<CreateTransitVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_bgp_peer: Option<
+        Box<
+            dyn Fn(
+                    DeleteBGPPeerRequest,
+                )
+                    -> ::std::result::Result<DeleteBGPPeerResponse, RusotoError<DeleteBGPPeerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_connection: Option<
+        Box<
+            dyn Fn(
+                    DeleteConnectionRequest,
+                )
+                    -> ::std::result::Result<Connection, RusotoError<DeleteConnectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_direct_connect_gateway: Option<
+        Box<
+            dyn Fn(
+                    DeleteDirectConnectGatewayRequest,
+                ) -> ::std::result::Result<
+                    DeleteDirectConnectGatewayResult,
+                    RusotoError<DeleteDirectConnectGatewayError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_direct_connect_gateway_association: Option<
+        Box<
+            dyn Fn(
+                    DeleteDirectConnectGatewayAssociationRequest,
+                ) -> ::std::result::Result<
+                    DeleteDirectConnectGatewayAssociationResult,
+                    RusotoError<DeleteDirectConnectGatewayAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_direct_connect_gateway_association_proposal: Option<
+        Box<
+            dyn Fn(
+                    DeleteDirectConnectGatewayAssociationProposalRequest,
+                ) -> ::std::result::Result<
+                    DeleteDirectConnectGatewayAssociationProposalResult,
+                    RusotoError<DeleteDirectConnectGatewayAssociationProposalError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_interconnect: Option<
+        Box<
+            dyn Fn(
+                    DeleteInterconnectRequest,
+                ) -> ::std::result::Result<
+                    DeleteInterconnectResponse,
+                    RusotoError<DeleteInterconnectError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_lag: Option<
+        Box<
+            dyn Fn(DeleteLagRequest) -> ::std::result::Result<Lag, RusotoError<DeleteLagError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_virtual_interface: Option<
+        Box<
+            dyn Fn(
+                    DeleteVirtualInterfaceRequest,
+                ) -> ::std::result::Result<
+                    DeleteVirtualInterfaceResponse,
+                    RusotoError<DeleteVirtualInterfaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_connection_loa: Option<
+        Box<
+            dyn Fn(
+                    DescribeConnectionLoaRequest,
+                ) -> ::std::result::Result<
+                    DescribeConnectionLoaResponse,
+                    RusotoError<DescribeConnectionLoaError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_connections: Option<
+        Box<
+            dyn Fn(
+                    DescribeConnectionsRequest,
+                )
+                    -> ::std::result::Result<Connections, RusotoError<DescribeConnectionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_connections_on_interconnect: Option<
+        Box<
+            dyn Fn(
+                    DescribeConnectionsOnInterconnectRequest,
+                ) -> ::std::result::Result<
+                    Connections,
+                    RusotoError<DescribeConnectionsOnInterconnectError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_direct_connect_gateway_association_proposals: Option<
+        Box<
+            dyn Fn(
+                    DescribeDirectConnectGatewayAssociationProposalsRequest,
+                ) -> ::std::result::Result<
+                    DescribeDirectConnectGatewayAssociationProposalsResult,
+                    RusotoError<DescribeDirectConnectGatewayAssociationProposalsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_direct_connect_gateway_associations: Option<
+        Box<
+            dyn Fn(
+                    DescribeDirectConnectGatewayAssociationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeDirectConnectGatewayAssociationsResult,
+                    RusotoError<DescribeDirectConnectGatewayAssociationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_direct_connect_gateway_attachments: Option<
+        Box<
+            dyn Fn(
+                    DescribeDirectConnectGatewayAttachmentsRequest,
+                ) -> ::std::result::Result<
+                    DescribeDirectConnectGatewayAttachmentsResult,
+                    RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_direct_connect_gateways: Option<
+        Box<
+            dyn Fn(
+                    DescribeDirectConnectGatewaysRequest,
+                ) -> ::std::result::Result<
+                    DescribeDirectConnectGatewaysResult,
+                    RusotoError<DescribeDirectConnectGatewaysError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_hosted_connections: Option<
+        Box<
+            dyn Fn(
+                    DescribeHostedConnectionsRequest,
+                ) -> ::std::result::Result<
+                    Connections,
+                    RusotoError<DescribeHostedConnectionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_interconnect_loa: Option<
+        Box<
+            dyn Fn(
+                    DescribeInterconnectLoaRequest,
+                ) -> ::std::result::Result<
+                    DescribeInterconnectLoaResponse,
+                    RusotoError<DescribeInterconnectLoaError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_interconnects: Option<
+        Box<
+            dyn Fn(
+                    DescribeInterconnectsRequest,
+                )
+                    -> ::std::result::Result<Interconnects, RusotoError<DescribeInterconnectsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_lags: Option<
+        Box<
+            dyn Fn(
+                    DescribeLagsRequest,
+                ) -> ::std::result::Result<Lags, RusotoError<DescribeLagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_loa: Option<
+        Box<
+            dyn Fn(DescribeLoaRequest) -> ::std::result::Result<Loa, RusotoError<DescribeLoaError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_locations: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<Locations, RusotoError<DescribeLocationsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_tags: Option<
+        Box<
+            dyn Fn(
+                    DescribeTagsRequest,
+                )
+                    -> ::std::result::Result<DescribeTagsResponse, RusotoError<DescribeTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_virtual_gateways: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    VirtualGateways,
+                    RusotoError<DescribeVirtualGatewaysError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_virtual_interfaces: Option<
+        Box<
+            dyn Fn(
+                    DescribeVirtualInterfacesRequest,
+                ) -> ::std::result::Result<
+                    VirtualInterfaces,
+                    RusotoError<DescribeVirtualInterfacesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_connection_from_lag: Option<
+        Box<
+            dyn Fn(
+                    DisassociateConnectionFromLagRequest,
+                ) -> ::std::result::Result<
+                    Connection,
+                    RusotoError<DisassociateConnectionFromLagError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_direct_connect_gateway_association: Option<
+        Box<
+            dyn Fn(
+                    UpdateDirectConnectGatewayAssociationRequest,
+                ) -> ::std::result::Result<
+                    UpdateDirectConnectGatewayAssociationResult,
+                    RusotoError<UpdateDirectConnectGatewayAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_lag: Option<
+        Box<
+            dyn Fn(UpdateLagRequest) -> ::std::result::Result<Lag, RusotoError<UpdateLagError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_virtual_interface_attributes: Option<
+        Box<
+            dyn Fn(
+                    UpdateVirtualInterfaceAttributesRequest,
+                ) -> ::std::result::Result<
+                    VirtualInterface,
+                    RusotoError<UpdateVirtualInterfaceAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockDirectConnect {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockDirectConnect {
+        Default::default()
+    }
+
+    /// Answers `accept_direct_connect_gateway_association_proposal` with what `handler` returns for its input.
+    pub fn with_accept_direct_connect_gateway_association_proposal<F>(
+        mut self,
+        handler: F,
+    ) -> MockDirectConnect
+    where
+        F: Fn(
+                AcceptDirectConnectGatewayAssociationProposalRequest,
+            ) -> ::std::result::Result<
+                AcceptDirectConnectGatewayAssociationProposalResult,
+                RusotoError<AcceptDirectConnectGatewayAssociationProposalError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.accept_direct_connect_gateway_association_proposal = Some(Box::new(handler));
+        self
+    }
+    /// Answers `allocate_connection_on_interconnect` with what `handler` returns for its input.
+    pub fn with_allocate_connection_on_interconnect<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                AllocateConnectionOnInterconnectRequest,
+            ) -> ::std::result::Result<
+                Connection,
+                RusotoError<AllocateConnectionOnInterconnectError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.allocate_connection_on_interconnect = Some(Box::new(handler));
+        self
+    }
+    /// Answers `allocate_hosted_connection` with what `handler` returns for its input.
+    pub fn with_allocate_hosted_connection<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                AllocateHostedConnectionRequest,
+            )
+                -> ::std::result::Result<Connection, RusotoError<AllocateHostedConnectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.allocate_hosted_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `allocate_private_virtual_interface` with what `handler` returns for its input.
+    pub fn with_allocate_private_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                AllocatePrivateVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                VirtualInterface,
+                RusotoError<AllocatePrivateVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.allocate_private_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `allocate_public_virtual_interface` with what `handler` returns for its input.
+    pub fn with_allocate_public_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                AllocatePublicVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                VirtualInterface,
+                RusotoError<AllocatePublicVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.allocate_public_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `allocate_transit_virtual_interface` with what `handler` returns for its input.
+    pub fn with_allocate_transit_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                AllocateTransitVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                AllocateTransitVirtualInterfaceResult,
+                RusotoError<AllocateTransitVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.allocate_transit_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_connection_with_lag` with what `handler` returns for its input.
+    pub fn with_associate_connection_with_lag<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                AssociateConnectionWithLagRequest,
+            )
+                -> ::std::result::Result<Connection, RusotoError<AssociateConnectionWithLagError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_connection_with_lag = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_hosted_connection` with what `handler` returns for its input.
+    pub fn with_associate_hosted_connection<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                AssociateHostedConnectionRequest,
+            )
+                -> ::std::result::Result<Connection, RusotoError<AssociateHostedConnectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_hosted_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_virtual_interface` with what `handler` returns for its input.
+    pub fn with_associate_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                AssociateVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                VirtualInterface,
+                RusotoError<AssociateVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `confirm_connection` with what `handler` returns for its input.
+    pub fn with_confirm_connection<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                ConfirmConnectionRequest,
+            ) -> ::std::result::Result<
+                ConfirmConnectionResponse,
+                RusotoError<ConfirmConnectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.confirm_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `confirm_private_virtual_interface` with what `handler` returns for its input.
+    pub fn with_confirm_private_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                ConfirmPrivateVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                ConfirmPrivateVirtualInterfaceResponse,
+                RusotoError<ConfirmPrivateVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.confirm_private_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `confirm_public_virtual_interface` with what `handler` returns for its input.
+    pub fn with_confirm_public_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                ConfirmPublicVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                ConfirmPublicVirtualInterfaceResponse,
+                RusotoError<ConfirmPublicVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.confirm_public_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `confirm_transit_virtual_interface` with what `handler` returns for its input.
+    pub fn with_confirm_transit_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                ConfirmTransitVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                ConfirmTransitVirtualInterfaceResponse,
+                RusotoError<ConfirmTransitVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.confirm_transit_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_bgp_peer` with what `handler` returns for its input.
+    pub fn with_create_bgp_peer<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                CreateBGPPeerRequest,
+            )
+                -> ::std::result::Result<CreateBGPPeerResponse, RusotoError<CreateBGPPeerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_bgp_peer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_connection` with what `handler` returns for its input.
+    pub fn with_create_connection<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                CreateConnectionRequest,
+            ) -> ::std::result::Result<Connection, RusotoError<CreateConnectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_direct_connect_gateway` with what `handler` returns for its input.
+    pub fn with_create_direct_connect_gateway<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                CreateDirectConnectGatewayRequest,
+            ) -> ::std::result::Result<
+                CreateDirectConnectGatewayResult,
+                RusotoError<CreateDirectConnectGatewayError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_direct_connect_gateway = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_direct_connect_gateway_association` with what `handler` returns for its input.
+    pub fn with_create_direct_connect_gateway_association<F>(
+        mut self,
+        handler: F,
+    ) -> MockDirectConnect
+    where
+        F: Fn(
+                CreateDirectConnectGatewayAssociationRequest,
+            ) -> ::std::result::Result<
+                CreateDirectConnectGatewayAssociationResult,
+                RusotoError<CreateDirectConnectGatewayAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_direct_connect_gateway_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_direct_connect_gateway_association_proposal` with what `handler` returns for its input.
+    pub fn with_create_direct_connect_gateway_association_proposal<F>(
+        mut self,
+        handler: F,
+    ) -> MockDirectConnect
+    where
+        F: Fn(
+                CreateDirectConnectGatewayAssociationProposalRequest,
+            ) -> ::std::result::Result<
+                CreateDirectConnectGatewayAssociationProposalResult,
+                RusotoError<CreateDirectConnectGatewayAssociationProposalError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_direct_connect_gateway_association_proposal = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_interconnect` with what `handler` returns for its input.
+    pub fn with_create_interconnect<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                CreateInterconnectRequest,
+            )
+                -> ::std::result::Result<Interconnect, RusotoError<CreateInterconnectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_interconnect = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_lag` with what `handler` returns for its input.
+    pub fn with_create_lag<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(CreateLagRequest) -> ::std::result::Result<Lag, RusotoError<CreateLagError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_lag = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_private_virtual_interface` with what `handler` returns for its input.
+    pub fn with_create_private_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                CreatePrivateVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                VirtualInterface,
+                RusotoError<CreatePrivateVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_private_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_public_virtual_interface` with what `handler` returns for its input.
+    pub fn with_create_public_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                CreatePublicVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                VirtualInterface,
+                RusotoError<CreatePublicVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_public_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_transit_virtual_interface` with what `handler` returns for its input.
+    pub fn with_create_transit_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                CreateTransitVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                CreateTransitVirtualInterfaceResult,
+                RusotoError<CreateTransitVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_transit_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bgp_peer` with what `handler` returns for its input.
+    pub fn with_delete_bgp_peer<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DeleteBGPPeerRequest,
+            )
+                -> ::std::result::Result<DeleteBGPPeerResponse, RusotoError<DeleteBGPPeerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bgp_peer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_connection` with what `handler` returns for its input.
+    pub fn with_delete_connection<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DeleteConnectionRequest,
+            ) -> ::std::result::Result<Connection, RusotoError<DeleteConnectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_direct_connect_gateway` with what `handler` returns for its input.
+    pub fn with_delete_direct_connect_gateway<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DeleteDirectConnectGatewayRequest,
+            ) -> ::std::result::Result<
+                DeleteDirectConnectGatewayResult,
+                RusotoError<DeleteDirectConnectGatewayError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_direct_connect_gateway = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_direct_connect_gateway_association` with what `handler` returns for its input.
+    pub fn with_delete_direct_connect_gateway_association<F>(
+        mut self,
+        handler: F,
+    ) -> MockDirectConnect
+    where
+        F: Fn(
+                DeleteDirectConnectGatewayAssociationRequest,
+            ) -> ::std::result::Result<
+                DeleteDirectConnectGatewayAssociationResult,
+                RusotoError<DeleteDirectConnectGatewayAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_direct_connect_gateway_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_direct_connect_gateway_association_proposal` with what `handler` returns for its input.
+    pub fn with_delete_direct_connect_gateway_association_proposal<F>(
+        mut self,
+        handler: F,
+    ) -> MockDirectConnect
+    where
+        F: Fn(
+                DeleteDirectConnectGatewayAssociationProposalRequest,
+            ) -> ::std::result::Result<
+                DeleteDirectConnectGatewayAssociationProposalResult,
+                RusotoError<DeleteDirectConnectGatewayAssociationProposalError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_direct_connect_gateway_association_proposal = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_interconnect` with what `handler` returns for its input.
+    pub fn with_delete_interconnect<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DeleteInterconnectRequest,
+            ) -> ::std::result::Result<
+                DeleteInterconnectResponse,
+                RusotoError<DeleteInterconnectError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_interconnect = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_lag` with what `handler` returns for its input.
+    pub fn with_delete_lag<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(DeleteLagRequest) -> ::std::result::Result<Lag, RusotoError<DeleteLagError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_lag = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_virtual_interface` with what `handler` returns for its input.
+    pub fn with_delete_virtual_interface<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DeleteVirtualInterfaceRequest,
+            ) -> ::std::result::Result<
+                DeleteVirtualInterfaceResponse,
+                RusotoError<DeleteVirtualInterfaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_virtual_interface = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_connection_loa` with what `handler` returns for its input.
+    pub fn with_describe_connection_loa<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeConnectionLoaRequest,
+            ) -> ::std::result::Result<
+                DescribeConnectionLoaResponse,
+                RusotoError<DescribeConnectionLoaError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_connection_loa = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_connections` with what `handler` returns for its input.
+    pub fn with_describe_connections<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeConnectionsRequest,
+            )
+                -> ::std::result::Result<Connections, RusotoError<DescribeConnectionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_connections = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_connections_on_interconnect` with what `handler` returns for its input.
+    pub fn with_describe_connections_on_interconnect<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeConnectionsOnInterconnectRequest,
+            ) -> ::std::result::Result<
+                Connections,
+                RusotoError<DescribeConnectionsOnInterconnectError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_connections_on_interconnect = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_direct_connect_gateway_association_proposals` with what `handler` returns for its input.
+    pub fn with_describe_direct_connect_gateway_association_proposals<F>(
+        mut self,
+        handler: F,
+    ) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeDirectConnectGatewayAssociationProposalsRequest,
+            ) -> ::std::result::Result<
+                DescribeDirectConnectGatewayAssociationProposalsResult,
+                RusotoError<DescribeDirectConnectGatewayAssociationProposalsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_direct_connect_gateway_association_proposals = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_direct_connect_gateway_associations` with what `handler` returns for its input.
+    pub fn with_describe_direct_connect_gateway_associations<F>(
+        mut self,
+        handler: F,
+    ) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeDirectConnectGatewayAssociationsRequest,
+            ) -> ::std::result::Result<
+                DescribeDirectConnectGatewayAssociationsResult,
+                RusotoError<DescribeDirectConnectGatewayAssociationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_direct_connect_gateway_associations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_direct_connect_gateway_attachments` with what `handler` returns for its input.
+    pub fn with_describe_direct_connect_gateway_attachments<F>(
+        mut self,
+        handler: F,
+    ) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeDirectConnectGatewayAttachmentsRequest,
+            ) -> ::std::result::Result<
+                DescribeDirectConnectGatewayAttachmentsResult,
+                RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_direct_connect_gateway_attachments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_direct_connect_gateways` with what `handler` returns for its input.
+    pub fn with_describe_direct_connect_gateways<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeDirectConnectGatewaysRequest,
+            ) -> ::std::result::Result<
+                DescribeDirectConnectGatewaysResult,
+                RusotoError<DescribeDirectConnectGatewaysError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_direct_connect_gateways = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_hosted_connections` with what `handler` returns for its input.
+    pub fn with_describe_hosted_connections<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeHostedConnectionsRequest,
+            )
+                -> ::std::result::Result<Connections, RusotoError<DescribeHostedConnectionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_hosted_connections = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_interconnect_loa` with what `handler` returns for its input.
+    pub fn with_describe_interconnect_loa<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeInterconnectLoaRequest,
+            ) -> ::std::result::Result<
+                DescribeInterconnectLoaResponse,
+                RusotoError<DescribeInterconnectLoaError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_interconnect_loa = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_interconnects` with what `handler` returns for its input.
+    pub fn with_describe_interconnects<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeInterconnectsRequest,
+            )
+                -> ::std::result::Result<Interconnects, RusotoError<DescribeInterconnectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_interconnects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_lags` with what `handler` returns for its input.
+    pub fn with_describe_lags<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(DescribeLagsRequest) -> ::std::result::Result<Lags, RusotoError<DescribeLagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_lags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_loa` with what `handler` returns for its input.
+    pub fn with_describe_loa<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(DescribeLoaRequest) -> ::std::result::Result<Loa, RusotoError<DescribeLoaError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_loa = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_locations` with what `handler` returns.
+    pub fn with_describe_locations<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn() -> ::std::result::Result<Locations, RusotoError<DescribeLocationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_locations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tags` with what `handler` returns for its input.
+    pub fn with_describe_tags<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeTagsRequest,
+            )
+                -> ::std::result::Result<DescribeTagsResponse, RusotoError<DescribeTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_virtual_gateways` with what `handler` returns.
+    pub fn with_describe_virtual_gateways<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn()
+                -> ::std::result::Result<VirtualGateways, RusotoError<DescribeVirtualGatewaysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_virtual_gateways = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_virtual_interfaces` with what `handler` returns for its input.
+    pub fn with_describe_virtual_interfaces<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DescribeVirtualInterfacesRequest,
+            ) -> ::std::result::Result<
+                VirtualInterfaces,
+                RusotoError<DescribeVirtualInterfacesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_virtual_interfaces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_connection_from_lag` with what `handler` returns for its input.
+    pub fn with_disassociate_connection_from_lag<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                DisassociateConnectionFromLagRequest,
+            )
+                -> ::std::result::Result<Connection, RusotoError<DisassociateConnectionFromLagError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_connection_from_lag = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_direct_connect_gateway_association` with what `handler` returns for its input.
+    pub fn with_update_direct_connect_gateway_association<F>(
+        mut self,
+        handler: F,
+    ) -> MockDirectConnect
+    where
+        F: Fn(
+                UpdateDirectConnectGatewayAssociationRequest,
+            ) -> ::std::result::Result<
+                UpdateDirectConnectGatewayAssociationResult,
+                RusotoError<UpdateDirectConnectGatewayAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_direct_connect_gateway_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_lag` with what `handler` returns for its input.
+    pub fn with_update_lag<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(UpdateLagRequest) -> ::std::result::Result<Lag, RusotoError<UpdateLagError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_lag = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_virtual_interface_attributes` with what `handler` returns for its input.
+    pub fn with_update_virtual_interface_attributes<F>(mut self, handler: F) -> MockDirectConnect
+    where
+        F: Fn(
+                UpdateVirtualInterfaceAttributesRequest,
+            ) -> ::std::result::Result<
+                VirtualInterface,
+                RusotoError<UpdateVirtualInterfaceAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_virtual_interface_attributes = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl DirectConnect for MockDirectConnect {
+    fn accept_direct_connect_gateway_association_proposal(
+        &self,
+        input: AcceptDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        AcceptDirectConnectGatewayAssociationProposalResult,
+        AcceptDirectConnectGatewayAssociationProposalError,
+    > {
+        match self.accept_direct_connect_gateway_association_proposal {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockDirectConnect has no handler for accept_direct_connect_gateway_association_proposal"),
+}
+    }
+    fn allocate_connection_on_interconnect(
+        &self,
+        input: AllocateConnectionOnInterconnectRequest,
+    ) -> RusotoFuture<Connection, AllocateConnectionOnInterconnectError> {
+        match self.allocate_connection_on_interconnect {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDirectConnect has no handler for allocate_connection_on_interconnect")
+            }
+        }
+    }
+    fn allocate_hosted_connection(
+        &self,
+        input: AllocateHostedConnectionRequest,
+    ) -> RusotoFuture<Connection, AllocateHostedConnectionError> {
+        match self.allocate_hosted_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for allocate_hosted_connection"),
+        }
+    }
+    fn allocate_private_virtual_interface(
+        &self,
+        input: AllocatePrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AllocatePrivateVirtualInterfaceError> {
+        match self.allocate_private_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDirectConnect has no handler for allocate_private_virtual_interface")
+            }
+        }
+    }
+    fn allocate_public_virtual_interface(
+        &self,
+        input: AllocatePublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AllocatePublicVirtualInterfaceError> {
+        match self.allocate_public_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDirectConnect has no handler for allocate_public_virtual_interface")
+            }
+        }
+    }
+    fn allocate_transit_virtual_interface(
+        &self,
+        input: AllocateTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<AllocateTransitVirtualInterfaceResult, AllocateTransitVirtualInterfaceError>
+    {
+        match self.allocate_transit_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDirectConnect has no handler for allocate_transit_virtual_interface")
+            }
+        }
+    }
+    fn associate_connection_with_lag(
+        &self,
+        input: AssociateConnectionWithLagRequest,
+    ) -> RusotoFuture<Connection, AssociateConnectionWithLagError> {
+        match self.associate_connection_with_lag {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for associate_connection_with_lag"),
+        }
+    }
+    fn associate_hosted_connection(
+        &self,
+        input: AssociateHostedConnectionRequest,
+    ) -> RusotoFuture<Connection, AssociateHostedConnectionError> {
+        match self.associate_hosted_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for associate_hosted_connection"),
+        }
+    }
+    fn associate_virtual_interface(
+        &self,
+        input: AssociateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AssociateVirtualInterfaceError> {
+        match self.associate_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for associate_virtual_interface"),
+        }
+    }
+    fn confirm_connection(
+        &self,
+        input: ConfirmConnectionRequest,
+    ) -> RusotoFuture<ConfirmConnectionResponse, ConfirmConnectionError> {
+        match self.confirm_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for confirm_connection"),
+        }
+    }
+    fn confirm_private_virtual_interface(
+        &self,
+        input: ConfirmPrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmPrivateVirtualInterfaceResponse, ConfirmPrivateVirtualInterfaceError>
+    {
+        match self.confirm_private_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDirectConnect has no handler for confirm_private_virtual_interface")
+            }
+        }
+    }
+    fn confirm_public_virtual_interface(
+        &self,
+        input: ConfirmPublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmPublicVirtualInterfaceResponse, ConfirmPublicVirtualInterfaceError>
+    {
+        match self.confirm_public_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for confirm_public_virtual_interface"),
+        }
+    }
+    fn confirm_transit_virtual_interface(
+        &self,
+        input: ConfirmTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmTransitVirtualInterfaceResponse, ConfirmTransitVirtualInterfaceError>
+    {
+        match self.confirm_transit_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDirectConnect has no handler for confirm_transit_virtual_interface")
+            }
+        }
+    }
+    fn create_bgp_peer(
+        &self,
+        input: CreateBGPPeerRequest,
+    ) -> RusotoFuture<CreateBGPPeerResponse, CreateBGPPeerError> {
+        match self.create_bgp_peer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for create_bgp_peer"),
+        }
+    }
+    fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> RusotoFuture<Connection, CreateConnectionError> {
+        match self.create_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for create_connection"),
+        }
+    }
+    fn create_direct_connect_gateway(
+        &self,
+        input: CreateDirectConnectGatewayRequest,
+    ) -> RusotoFuture<CreateDirectConnectGatewayResult, CreateDirectConnectGatewayError> {
+        match self.create_direct_connect_gateway {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for create_direct_connect_gateway"),
+        }
+    }
+    fn create_direct_connect_gateway_association(
+        &self,
+        input: CreateDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        CreateDirectConnectGatewayAssociationResult,
+        CreateDirectConnectGatewayAssociationError,
+    > {
+        match self.create_direct_connect_gateway_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDirectConnect has no handler for create_direct_connect_gateway_association"
+            ),
+        }
+    }
+    fn create_direct_connect_gateway_association_proposal(
+        &self,
+        input: CreateDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        CreateDirectConnectGatewayAssociationProposalResult,
+        CreateDirectConnectGatewayAssociationProposalError,
+    > {
+        match self.create_direct_connect_gateway_association_proposal {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockDirectConnect has no handler for create_direct_connect_gateway_association_proposal"),
+}
+    }
+    fn create_interconnect(
+        &self,
+        input: CreateInterconnectRequest,
+    ) -> RusotoFuture<Interconnect, CreateInterconnectError> {
+        match self.create_interconnect {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for create_interconnect"),
+        }
+    }
+    fn create_lag(&self, input: CreateLagRequest) -> RusotoFuture<Lag, CreateLagError> {
+        match self.create_lag {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for create_lag"),
+        }
+    }
+    fn create_private_virtual_interface(
+        &self,
+        input: CreatePrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, CreatePrivateVirtualInterfaceError> {
+        match self.create_private_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for create_private_virtual_interface"),
+        }
+    }
+    fn create_public_virtual_interface(
+        &self,
+        input: CreatePublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, CreatePublicVirtualInterfaceError> {
+        match self.create_public_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for create_public_virtual_interface"),
+        }
+    }
+    fn create_transit_virtual_interface(
+        &self,
+        input: CreateTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<CreateTransitVirtualInterfaceResult, CreateTransitVirtualInterfaceError> {
+        match self.create_transit_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for create_transit_virtual_interface"),
+        }
+    }
+    fn delete_bgp_peer(
+        &self,
+        input: DeleteBGPPeerRequest,
+    ) -> RusotoFuture<DeleteBGPPeerResponse, DeleteBGPPeerError> {
+        match self.delete_bgp_peer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for delete_bgp_peer"),
+        }
+    }
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<Connection, DeleteConnectionError> {
+        match self.delete_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for delete_connection"),
+        }
+    }
+    fn delete_direct_connect_gateway(
+        &self,
+        input: DeleteDirectConnectGatewayRequest,
+    ) -> RusotoFuture<DeleteDirectConnectGatewayResult, DeleteDirectConnectGatewayError> {
+        match self.delete_direct_connect_gateway {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for delete_direct_connect_gateway"),
+        }
+    }
+    fn delete_direct_connect_gateway_association(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        DeleteDirectConnectGatewayAssociationResult,
+        DeleteDirectConnectGatewayAssociationError,
+    > {
+        match self.delete_direct_connect_gateway_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDirectConnect has no handler for delete_direct_connect_gateway_association"
+            ),
+        }
+    }
+    fn delete_direct_connect_gateway_association_proposal(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        DeleteDirectConnectGatewayAssociationProposalResult,
+        DeleteDirectConnectGatewayAssociationProposalError,
+    > {
+        match self.delete_direct_connect_gateway_association_proposal {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockDirectConnect has no handler for delete_direct_connect_gateway_association_proposal"),
+}
+    }
+    fn delete_interconnect(
+        &self,
+        input: DeleteInterconnectRequest,
+    ) -> RusotoFuture<DeleteInterconnectResponse, DeleteInterconnectError> {
+        match self.delete_interconnect {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for delete_interconnect"),
+        }
+    }
+    fn delete_lag(&self, input: DeleteLagRequest) -> RusotoFuture<Lag, DeleteLagError> {
+        match self.delete_lag {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for delete_lag"),
+        }
+    }
+    fn delete_virtual_interface(
+        &self,
+        input: DeleteVirtualInterfaceRequest,
+    ) -> RusotoFuture<DeleteVirtualInterfaceResponse, DeleteVirtualInterfaceError> {
+        match self.delete_virtual_interface {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for delete_virtual_interface"),
+        }
+    }
+    fn describe_connection_loa(
+        &self,
+        input: DescribeConnectionLoaRequest,
+    ) -> RusotoFuture<DescribeConnectionLoaResponse, DescribeConnectionLoaError> {
+        match self.describe_connection_loa {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for describe_connection_loa"),
+        }
+    }
+    fn describe_connections(
+        &self,
+        input: DescribeConnectionsRequest,
+    ) -> RusotoFuture<Connections, DescribeConnectionsError> {
+        match self.describe_connections {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for describe_connections"),
+        }
+    }
+    fn describe_connections_on_interconnect(
+        &self,
+        input: DescribeConnectionsOnInterconnectRequest,
+    ) -> RusotoFuture<Connections, DescribeConnectionsOnInterconnectError> {
+        match self.describe_connections_on_interconnect {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDirectConnect has no handler for describe_connections_on_interconnect")
+            }
+        }
+    }
+    fn describe_direct_connect_gateway_association_proposals(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationProposalsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAssociationProposalsResult,
+        DescribeDirectConnectGatewayAssociationProposalsError,
+    > {
+        match self.describe_direct_connect_gateway_association_proposals {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockDirectConnect has no handler for describe_direct_connect_gateway_association_proposals"),
+}
+    }
+    fn describe_direct_connect_gateway_associations(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAssociationsResult,
+        DescribeDirectConnectGatewayAssociationsError,
+    > {
+        match self.describe_direct_connect_gateway_associations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDirectConnect has no handler for describe_direct_connect_gateway_associations"
+            ),
+        }
+    }
+    fn describe_direct_connect_gateway_attachments(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAttachmentsResult,
+        DescribeDirectConnectGatewayAttachmentsError,
+    > {
+        match self.describe_direct_connect_gateway_attachments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDirectConnect has no handler for describe_direct_connect_gateway_attachments"
+            ),
+        }
+    }
+    fn describe_direct_connect_gateways(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> RusotoFuture<DescribeDirectConnectGatewaysResult, DescribeDirectConnectGatewaysError> {
+        match self.describe_direct_connect_gateways {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for describe_direct_connect_gateways"),
+        }
+    }
+    fn describe_hosted_connections(
+        &self,
+        input: DescribeHostedConnectionsRequest,
+    ) -> RusotoFuture<Connections, DescribeHostedConnectionsError> {
+        match self.describe_hosted_connections {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for describe_hosted_connections"),
+        }
+    }
+    fn describe_interconnect_loa(
+        &self,
+        input: DescribeInterconnectLoaRequest,
+    ) -> RusotoFuture<DescribeInterconnectLoaResponse, DescribeInterconnectLoaError> {
+        match self.describe_interconnect_loa {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for describe_interconnect_loa"),
+        }
+    }
+    fn describe_interconnects(
+        &self,
+        input: DescribeInterconnectsRequest,
+    ) -> RusotoFuture<Interconnects, DescribeInterconnectsError> {
+        match self.describe_interconnects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for describe_interconnects"),
+        }
+    }
+    fn describe_lags(&self, input: DescribeLagsRequest) -> RusotoFuture<Lags, DescribeLagsError> {
+        match self.describe_lags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for describe_lags"),
+        }
+    }
+    fn describe_loa(&self, input: DescribeLoaRequest) -> RusotoFuture<Loa, DescribeLoaError> {
+        match self.describe_loa {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for describe_loa"),
+        }
+    }
+    fn describe_locations(&self) -> RusotoFuture<Locations, DescribeLocationsError> {
+        match self.describe_locations {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockDirectConnect has no handler for describe_locations"),
+        }
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        match self.describe_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for describe_tags"),
+        }
+    }
+    fn describe_virtual_gateways(
+        &self,
+    ) -> RusotoFuture<VirtualGateways, DescribeVirtualGatewaysError> {
+        match self.describe_virtual_gateways {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockDirectConnect has no handler for describe_virtual_gateways"),
+        }
+    }
+    fn describe_virtual_interfaces(
+        &self,
+        input: DescribeVirtualInterfacesRequest,
+    ) -> RusotoFuture<VirtualInterfaces, DescribeVirtualInterfacesError> {
+        match self.describe_virtual_interfaces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for describe_virtual_interfaces"),
+        }
+    }
+    fn disassociate_connection_from_lag(
+        &self,
+        input: DisassociateConnectionFromLagRequest,
+    ) -> RusotoFuture<Connection, DisassociateConnectionFromLagError> {
+        match self.disassociate_connection_from_lag {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for disassociate_connection_from_lag"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for untag_resource"),
+        }
+    }
+    fn update_direct_connect_gateway_association(
+        &self,
+        input: UpdateDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        UpdateDirectConnectGatewayAssociationResult,
+        UpdateDirectConnectGatewayAssociationError,
+    > {
+        match self.update_direct_connect_gateway_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDirectConnect has no handler for update_direct_connect_gateway_association"
+            ),
+        }
+    }
+    fn update_lag(&self, input: UpdateLagRequest) -> RusotoFuture<Lag, UpdateLagError> {
+        match self.update_lag {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectConnect has no handler for update_lag"),
+        }
     }
-
-    /// <p>Updates the specified attributes of the specified virtual private interface.</p> <p>Setting the MTU of a virtual interface to 9001 (jumbo frames) can cause an update to the underlying physical connection if it wasn't updated to support jumbo frames. Updating the connection disrupts network connectivity for all virtual interfaces associated with the connection for up to 30 seconds. To check whether your connection supports jumbo frames, call <a>DescribeConnections</a>. To check whether your virtual interface supports jumbo frames, call <a>DescribeVirtualInterfaces</a>.</p>
     fn update_virtual_interface_attributes(
         &self,
         input: UpdateVirtualInterfaceAttributesRequest,
     ) -> RusotoFuture<VirtualInterface, UpdateVirtualInterfaceAttributesError> {
-        let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OvertureService.UpdateVirtualInterfaceAttributes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VirtualInterface, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateVirtualInterfaceAttributesError::from_response(
-                        response,
-                    ))
-                }))
+        match self.update_virtual_interface_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDirectConnect has no handler for update_virtual_interface_attributes")
             }
-        })
+        }
     }
 }
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Information about agents or connectors that were instructed to start collecting data. Information includes the agent/connector ID, a description of the operation, and whether the agent/connector configuration was updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AgentConfigurationStatus {
     /// <p>The agent/connector ID.</p>
     #[serde(rename = "agentId")]
@@ -43,7 +44,7 @@ pub struct AgentConfigurationStatus {
 
 /// <p>Information about agents or connectors associated with the user’s AWS account. Information includes agent or connector IDs, IP addresses, media access control (MAC) addresses, agent or connector health, hostname where the agent or connector resides, and agent version for each agent.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AgentInfo {
     /// <p>The agent or connector ID.</p>
     #[serde(rename = "agentId")]
@@ -89,7 +90,7 @@ pub struct AgentInfo {
 
 /// <p>Network details about the host where the agent/connector resides.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AgentNetworkInfo {
     /// <p>The IP address for the host where the agent/connector resides.</p>
     #[serde(rename = "ipAddress")]
@@ -102,6 +103,7 @@ pub struct AgentNetworkInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateConfigurationItemsToApplicationRequest {
     /// <p>The configuration ID of an application with which items are to be associated.</p>
     #[serde(rename = "applicationConfigurationId")]
@@ -111,13 +113,34 @@ pub struct AssociateConfigurationItemsToApplicationRequest {
     pub configuration_ids: Vec<String>,
 }
 
+impl Validate for AssociateConfigurationItemsToApplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_configuration_id"),
+            self.application_configuration_id.chars().count(),
+            None,
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_configuration_id"),
+            &self.application_configuration_id,
+            "\\S+",
+        );
+        for (i, item) in self.configuration_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "configuration_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(200));
+            errors.check_pattern(&item_path, &item, "\\S*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateConfigurationItemsToApplicationResponse {}
 
 /// <p>Error messages returned for each import task that you deleted as a response for this command.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DiscoveryBatchDeleteImportDataError {
     /// <p>The type of error that occurred for a specific import task.</p>
     #[serde(rename = "errorCode")]
@@ -134,14 +157,31 @@ pub struct DiscoveryBatchDeleteImportDataError {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteImportDataRequest {
     /// <p>The IDs for the import tasks that you want to delete.</p>
     #[serde(rename = "importTaskIds")]
     pub import_task_ids: Vec<String>,
 }
 
+impl Validate for BatchDeleteImportDataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "import_task_ids"),
+            self.import_task_ids.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.import_task_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "import_task_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(200));
+            errors.check_pattern(&item_path, &item, "^import-task-[a-fA-F0-9]{32}$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDeleteImportDataResponse {
     /// <p>Error messages returned for each import task that you deleted as a response for this command.</p>
     #[serde(rename = "errors")]
@@ -151,7 +191,7 @@ pub struct BatchDeleteImportDataResponse {
 
 /// <p>Tags for a configuration item. Tags are metadata that help you categorize IT assets.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfigurationTag {
     /// <p>The configuration ID for the item to tag. You can specify a list of keys and values.</p>
     #[serde(rename = "configurationId")]
@@ -177,7 +217,7 @@ pub struct ConfigurationTag {
 
 /// <p>A list of continuous export descriptions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ContinuousExportDescription {
     /// <p>The type of data collector used to gather this data (currently only offered for AGENT).</p>
     #[serde(rename = "dataSource")]
@@ -214,6 +254,7 @@ pub struct ContinuousExportDescription {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApplicationRequest {
     /// <p>Description of the application to be created.</p>
     #[serde(rename = "description")]
@@ -224,8 +265,37 @@ pub struct CreateApplicationRequest {
     pub name: String,
 }
 
+impl Validate for CreateApplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "(^$|[\\s\\S]*\\S[\\s\\S]*)",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(127),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\s\\S]*\\S[\\s\\S]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateApplicationResponse {
     /// <p>Configuration ID of an application to be created.</p>
     #[serde(rename = "configurationId")]
@@ -234,6 +304,7 @@ pub struct CreateApplicationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTagsRequest {
     /// <p>A list of configuration items that you want to tag.</p>
     #[serde(rename = "configurationIds")]
@@ -243,13 +314,23 @@ pub struct CreateTagsRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for CreateTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.configuration_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "configuration_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(200));
+            errors.check_pattern(&item_path, &item, "\\S*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTagsResponse {}
 
 /// <p>Inventory data for installed discovery agents.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CustomerAgentInfo {
     /// <p>Number of active discovery agents.</p>
     #[serde(rename = "activeAgents")]
@@ -276,7 +357,7 @@ pub struct CustomerAgentInfo {
 
 /// <p>Inventory data for installed discovery connectors.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CustomerConnectorInfo {
     /// <p>Number of active discovery connectors.</p>
     #[serde(rename = "activeConnectors")]
@@ -302,17 +383,29 @@ pub struct CustomerConnectorInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApplicationsRequest {
     /// <p>Configuration ID of an application to be deleted.</p>
     #[serde(rename = "configurationIds")]
     pub configuration_ids: Vec<String>,
 }
 
+impl Validate for DeleteApplicationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.configuration_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "configuration_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(200));
+            errors.check_pattern(&item_path, &item, "\\S+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteApplicationsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagsRequest {
     /// <p>A list of configuration items with tags that you want to delete.</p>
     #[serde(rename = "configurationIds")]
@@ -323,11 +416,22 @@ pub struct DeleteTagsRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for DeleteTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.configuration_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "configuration_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(200));
+            errors.check_pattern(&item_path, &item, "\\S*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTagsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAgentsRequest {
     /// <p>The agent or the Connector IDs for which you want information. If you specify no IDs, the system returns information about all agents/Connectors associated with your AWS user account.</p>
     #[serde(rename = "agentIds")]
@@ -347,8 +451,26 @@ pub struct DescribeAgentsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAgentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.agent_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "agent_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(10), Some(20));
+                errors.check_pattern(&item_path, &item, "\\S+");
+            }
+        }
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAgentsResponse {
     /// <p>Lists agents or the Connector by ID or lists all agents/Connectors associated with your user account if you did not specify an agent/Connector ID. The output includes agent/Connector IDs, IP addresses, media access control (MAC) addresses, agent/Connector health, host name where the agent/Connector resides, and the version number of each agent/Connector.</p>
     #[serde(rename = "agentsInfo")]
@@ -361,14 +483,25 @@ pub struct DescribeAgentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationsRequest {
     /// <p>One or more configuration IDs.</p>
     #[serde(rename = "configurationIds")]
     pub configuration_ids: Vec<String>,
 }
 
+impl Validate for DescribeConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.configuration_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "configuration_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(200));
+            errors.check_pattern(&item_path, &item, "\\S*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigurationsResponse {
     /// <p>A key in the response map. The value is an array of data.</p>
     #[serde(rename = "configurations")]
@@ -377,6 +510,7 @@ pub struct DescribeConfigurationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeContinuousExportsRequest {
     /// <p>The unique IDs assigned to the exports.</p>
     #[serde(rename = "exportIds")]
@@ -392,8 +526,23 @@ pub struct DescribeContinuousExportsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeContinuousExportsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.export_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "export_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(200));
+                errors.check_pattern(&item_path, &item, "\\S*");
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeContinuousExportsResponse {
     /// <p>A list of continuous export descriptions.</p>
     #[serde(rename = "descriptions")]
@@ -406,6 +555,7 @@ pub struct DescribeContinuousExportsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeExportConfigurationsRequest {
     /// <p>A list of continuous export ids to search for.</p>
     #[serde(rename = "exportIds")]
@@ -421,8 +571,20 @@ pub struct DescribeExportConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeExportConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.export_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "export_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(200));
+                errors.check_pattern(&item_path, &item, "\\S*");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeExportConfigurationsResponse {
     /// <p><p/></p>
     #[serde(rename = "exportsInfo")]
@@ -435,6 +597,7 @@ pub struct DescribeExportConfigurationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeExportTasksRequest {
     /// <p>One or more unique identifiers used to query the status of an export request.</p>
     #[serde(rename = "exportIds")]
@@ -454,8 +617,26 @@ pub struct DescribeExportTasksRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeExportTasksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.export_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "export_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(200));
+                errors.check_pattern(&item_path, &item, "\\S*");
+            }
+        }
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeExportTasksResponse {
     /// <p>Contains one or more sets of export request details. When the status of a request is <code>SUCCEEDED</code>, the response includes a URL for an Amazon S3 bucket where you can view the data in a CSV file.</p>
     #[serde(rename = "exportsInfo")]
@@ -468,6 +649,7 @@ pub struct DescribeExportTasksResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeImportTasksRequest {
     /// <p>An array of name-value pairs that you provide to filter the results for the <code>DescribeImportTask</code> request to a specific subset of results. Currently, wildcard values aren't supported for filters.</p>
     #[serde(rename = "filters")]
@@ -483,8 +665,22 @@ pub struct DescribeImportTasksRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeImportTasksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeImportTasksResponse {
     /// <p>The token to request the next page of results.</p>
     #[serde(rename = "nextToken")]
@@ -497,6 +693,7 @@ pub struct DescribeImportTasksResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsRequest {
     /// <p>You can filter the list using a <i>key</i>-<i>value</i> format. You can separate these items by using logical operators. Allowed filters include <code>tagKey</code>, <code>tagValue</code>, and <code>configurationId</code>. </p>
     #[serde(rename = "filters")]
@@ -512,8 +709,19 @@ pub struct DescribeTagsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTagsResponse {
     /// <p>The call returns a token. Use this token to get the next set of results.</p>
     #[serde(rename = "nextToken")]
@@ -526,6 +734,7 @@ pub struct DescribeTagsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateConfigurationItemsFromApplicationRequest {
     /// <p>Configuration ID of an application from which each item is disassociated.</p>
     #[serde(rename = "applicationConfigurationId")]
@@ -535,12 +744,33 @@ pub struct DisassociateConfigurationItemsFromApplicationRequest {
     pub configuration_ids: Vec<String>,
 }
 
+impl Validate for DisassociateConfigurationItemsFromApplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_configuration_id"),
+            self.application_configuration_id.chars().count(),
+            None,
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_configuration_id"),
+            &self.application_configuration_id,
+            "\\S+",
+        );
+        for (i, item) in self.configuration_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "configuration_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(200));
+            errors.check_pattern(&item_path, &item, "\\S*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateConfigurationItemsFromApplicationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportConfigurationsResponse {
     /// <p>A unique identifier that you can use to query the export status.</p>
     #[serde(rename = "exportId")]
@@ -550,6 +780,7 @@ pub struct ExportConfigurationsResponse {
 
 /// <p>Used to select which agent's data is to be exported. A single agent ID may be selected for export using the <a href="http://docs.aws.amazon.com/application-discovery/latest/APIReference/API_StartExportTask.html">StartExportTask</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExportFilter {
     /// <p>Supported condition: <code>EQUALS</code> </p>
     #[serde(rename = "condition")]
@@ -562,9 +793,37 @@ pub struct ExportFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for ExportFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "condition"),
+            self.condition.chars().count(),
+            None,
+            Some(200),
+        );
+        errors.check_pattern(&field_path(path, "condition"), &self.condition, "\\S+");
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(1000),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\s\\S]*\\S[\\s\\S]*",
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1000));
+            errors.check_pattern(&item_path, &item, "(^$|[\\s\\S]*\\S[\\s\\S]*)");
+        }
+    }
+}
+
 /// <p>Information regarding the export status of discovered data. The value is an array of objects.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportInfo {
     /// <p>A URL for an Amazon S3 bucket where you can review the exported data. The URL is displayed only if the export succeeded.</p>
     #[serde(rename = "configurationsDownloadUrl")]
@@ -598,6 +857,7 @@ pub struct ExportInfo {
 
 /// <p>A filter that can use conditional operators.</p> <p>For more information about filters, see <a href="http://docs.aws.amazon.com/application-discovery/latest/APIReference/discovery-api-queries.html">Querying Discovered Configuration Items</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filter {
     /// <p>A conditional operator. The following operators are valid: EQUALS, NOT_EQUALS, CONTAINS, NOT_CONTAINS. If you specify multiple filters, the system utilizes all filters as though concatenated by <i>AND</i>. If you specify multiple values for a particular filter, the system differentiates the values using <i>OR</i>. Calling either <i>DescribeConfigurations</i> or <i>ListConfigurations</i> returns attributes of matching configuration items.</p>
     #[serde(rename = "condition")]
@@ -610,11 +870,36 @@ pub struct Filter {
     pub values: Vec<String>,
 }
 
+impl Validate for Filter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "condition"),
+            self.condition.chars().count(),
+            None,
+            Some(200),
+        );
+        errors.check_pattern(&field_path(path, "condition"), &self.condition, "\\S+");
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(10000),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\s\\S]*");
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1000));
+            errors.check_pattern(&item_path, &item, "(^$|[\\s\\S]*\\S[\\s\\S]*)");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDiscoverySummaryRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDiscoverySummaryResponse {
     /// <p>Details about discovered agents, including agent status and health.</p>
     #[serde(rename = "agentSummary")]
@@ -644,7 +929,7 @@ pub struct GetDiscoverySummaryResponse {
 
 /// <p>An array of information related to the import task request that includes status information, times, IDs, the Amazon S3 Object URL for the import file, and more.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportTask {
     /// <p>The total number of application records in the import file that failed to be imported.</p>
     #[serde(rename = "applicationImportFailure")]
@@ -702,6 +987,7 @@ pub struct ImportTask {
 
 /// <p><p>A name-values pair of elements you can use to filter the results when querying your import tasks. Currently, wildcards are not supported for filters.</p> <note> <p>When filtering by import status, all other filter values are ignored.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportTaskFilter {
     /// <p>The name, status, or import task ID for a specific import task.</p>
     #[serde(rename = "name")]
@@ -713,7 +999,20 @@ pub struct ImportTaskFilter {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for ImportTaskFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.values {
+            errors.check_length(&field_path(path, "values"), value.len(), Some(1), Some(100));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConfigurationsRequest {
     /// <p>A valid configuration identified by Application Discovery Service. </p>
     #[serde(rename = "configurationType")]
@@ -736,8 +1035,25 @@ pub struct ListConfigurationsRequest {
     pub order_by: Option<Vec<OrderByElement>>,
 }
 
+impl Validate for ListConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.order_by {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "order_by"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListConfigurationsResponse {
     /// <p>Returns configuration details, including the configuration ID, attribute names, and attribute values.</p>
     #[serde(rename = "configurations")]
@@ -750,6 +1066,7 @@ pub struct ListConfigurationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListServerNeighborsRequest {
     /// <p>Configuration ID of the server for which neighbors are being listed.</p>
     #[serde(rename = "configurationId")]
@@ -772,8 +1089,41 @@ pub struct ListServerNeighborsRequest {
     pub port_information_needed: Option<bool>,
 }
 
+impl Validate for ListServerNeighborsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_id"),
+            self.configuration_id.chars().count(),
+            None,
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_id"),
+            &self.configuration_id,
+            "\\S*",
+        );
+        if let Some(ref value) = self.neighbor_configuration_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "neighbor_configuration_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(200));
+                errors.check_pattern(&item_path, &item, "\\S*");
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListServerNeighborsResponse {
     /// <p>Count of distinct servers that are one hop away from the given server.</p>
     #[serde(rename = "knownDependencyCount")]
@@ -790,7 +1140,7 @@ pub struct ListServerNeighborsResponse {
 
 /// <p>Details about neighboring servers.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NeighborConnectionDetail {
     /// <p>The number of open network connections with the neighboring server.</p>
     #[serde(rename = "connectionsCount")]
@@ -813,6 +1163,7 @@ pub struct NeighborConnectionDetail {
 
 /// <p>A field and direction for ordered output.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OrderByElement {
     /// <p>The field on which to order.</p>
     #[serde(rename = "fieldName")]
@@ -823,11 +1174,28 @@ pub struct OrderByElement {
     pub sort_order: Option<String>,
 }
 
+impl Validate for OrderByElement {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "field_name"),
+            self.field_name.chars().count(),
+            None,
+            Some(1000),
+        );
+        errors.check_pattern(
+            &field_path(path, "field_name"),
+            &self.field_name,
+            "[\\s\\S]*\\S[\\s\\S]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartContinuousExportRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartContinuousExportResponse {
     /// <p>The type of data collector used to gather this data (currently only offered for AGENT).</p>
     #[serde(rename = "dataSource")]
@@ -852,14 +1220,25 @@ pub struct StartContinuousExportResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartDataCollectionByAgentIdsRequest {
     /// <p>The IDs of the agents or connectors from which to start collecting data. If you send a request to an agent/connector ID that you do not have permission to contact, according to your AWS account, the service does not throw an exception. Instead, it returns the error in the <i>Description</i> field. If you send a request to multiple agents/connectors and you do not have permission to contact some of those agents/connectors, the system does not throw an exception. Instead, the system shows <code>Failed</code> in the <i>Description</i> field.</p>
     #[serde(rename = "agentIds")]
     pub agent_ids: Vec<String>,
 }
 
+impl Validate for StartDataCollectionByAgentIdsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.agent_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "agent_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(10), Some(20));
+            errors.check_pattern(&item_path, &item, "\\S+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartDataCollectionByAgentIdsResponse {
     /// <p>Information about agents or the connector that were instructed to start collecting data. Information includes the agent/connector ID, a description of the operation performed, and whether the agent/connector configuration was updated.</p>
     #[serde(rename = "agentsConfigurationStatus")]
@@ -868,6 +1247,7 @@ pub struct StartDataCollectionByAgentIdsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartExportTaskRequest {
     /// <p>The end timestamp for exported data from the single Application Discovery Agent selected in the filters. If no value is specified, exported data includes the most recent data collected by the agent.</p>
     #[serde(rename = "endTime")]
@@ -887,8 +1267,19 @@ pub struct StartExportTaskRequest {
     pub start_time: Option<f64>,
 }
 
+impl Validate for StartExportTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartExportTaskResponse {
     /// <p>A unique identifier used to query the status of an export request.</p>
     #[serde(rename = "exportId")]
@@ -897,6 +1288,7 @@ pub struct StartExportTaskResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartImportTaskRequest {
     /// <p>Optional. A unique token that you can provide to prevent the same import request from occurring more than once. If you don't provide a token, a token is automatically generated.</p> <p>Sending more than one <code>StartImportTask</code> request with the same client request token will return information about the original import task with that client request token.</p>
     #[serde(rename = "clientRequestToken")]
@@ -910,8 +1302,43 @@ pub struct StartImportTaskRequest {
     pub name: String,
 }
 
+impl Validate for StartImportTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "import_url"),
+            self.import_url.chars().count(),
+            Some(1),
+            Some(4000),
+        );
+        errors.check_pattern(
+            &field_path(path, "import_url"),
+            &self.import_url,
+            "\\S+://\\S+/[\\s\\S]*\\S[\\s\\S]*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\s\\S]*\\S[\\s\\S]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartImportTaskResponse {
     /// <p>An array of information related to the import task request including status information, times, IDs, the Amazon S3 Object URL for the import file, and more. </p>
     #[serde(rename = "task")]
@@ -920,14 +1347,27 @@ pub struct StartImportTaskResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopContinuousExportRequest {
     /// <p>The unique ID assigned to this export.</p>
     #[serde(rename = "exportId")]
     pub export_id: String,
 }
 
+impl Validate for StopContinuousExportRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "export_id"),
+            self.export_id.chars().count(),
+            None,
+            Some(200),
+        );
+        errors.check_pattern(&field_path(path, "export_id"), &self.export_id, "\\S*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopContinuousExportResponse {
     /// <p>Timestamp that represents when this continuous export started collecting data.</p>
     #[serde(rename = "startTime")]
@@ -940,14 +1380,25 @@ pub struct StopContinuousExportResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopDataCollectionByAgentIdsRequest {
     /// <p>The IDs of the agents or connectors from which to stop collecting data.</p>
     #[serde(rename = "agentIds")]
     pub agent_ids: Vec<String>,
 }
 
+impl Validate for StopDataCollectionByAgentIdsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.agent_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "agent_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(10), Some(20));
+            errors.check_pattern(&item_path, &item, "\\S+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopDataCollectionByAgentIdsResponse {
     /// <p>Information about the agents or connector that were instructed to stop collecting data. Information includes the agent/connector ID, a description of the operation performed, and whether the agent/connector configuration was updated.</p>
     #[serde(rename = "agentsConfigurationStatus")]
@@ -957,6 +1408,7 @@ pub struct StopDataCollectionByAgentIdsResponse {
 
 /// <p>Metadata that help you categorize IT assets.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>The type of tag on which to filter.</p>
     #[serde(rename = "key")]
@@ -968,6 +1420,7 @@ pub struct Tag {
 
 /// <p>The tag filter. Valid names are: <code>tagKey</code>, <code>tagValue</code>, <code>configurationId</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagFilter {
     /// <p>A name of the tag filter.</p>
     #[serde(rename = "name")]
@@ -977,7 +1430,29 @@ pub struct TagFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for TagFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(1000),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\s\\S]*\\S[\\s\\S]*",
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1000));
+            errors.check_pattern(&item_path, &item, "(^$|[\\s\\S]*\\S[\\s\\S]*)");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApplicationRequest {
     /// <p>Configuration ID of the application to be updated.</p>
     #[serde(rename = "configurationId")]
@@ -992,8 +1467,46 @@ pub struct UpdateApplicationRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateApplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_id"),
+            self.configuration_id.chars().count(),
+            None,
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_id"),
+            &self.configuration_id,
+            "\\S+",
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1000),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "(^$|[\\s\\S]*\\S[\\s\\S]*)",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(127),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "[\\s\\S]*\\S[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateApplicationResponse {}
 
 /// Errors returned by AssociateConfigurationItemsToApplication
@@ -1037,7 +1550,12 @@ impl AssociateConfigurationItemsToApplicationError {
                         AssociateConfigurationItemsToApplicationError::ServerInternalError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1091,7 +1609,12 @@ impl BatchDeleteImportDataError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1147,7 +1670,12 @@ impl CreateApplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1203,7 +1731,12 @@ impl CreateTagsError {
                 "ServerInternalErrorException" => {
                     return RusotoError::Service(CreateTagsError::ServerInternalError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1261,7 +1794,12 @@ impl DeleteApplicationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1317,7 +1855,12 @@ impl DeleteTagsError {
                 "ServerInternalErrorException" => {
                     return RusotoError::Service(DeleteTagsError::ServerInternalError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1371,7 +1914,12 @@ impl DescribeAgentsError {
                 "ServerInternalErrorException" => {
                     return RusotoError::Service(DescribeAgentsError::ServerInternalError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1430,7 +1978,12 @@ impl DescribeConfigurationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1503,7 +2056,12 @@ impl DescribeContinuousExportsError {
                         DescribeContinuousExportsError::ServerInternalError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1573,7 +2131,12 @@ impl DescribeExportConfigurationsError {
                         DescribeExportConfigurationsError::ServerInternalError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1633,7 +2196,12 @@ impl DescribeExportTasksError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1685,7 +2253,12 @@ impl DescribeImportTasksError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1740,7 +2313,12 @@ impl DescribeTagsError {
                 "ServerInternalErrorException" => {
                     return RusotoError::Service(DescribeTagsError::ServerInternalError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1810,7 +2388,12 @@ impl DisassociateConfigurationItemsFromApplicationError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1884,7 +2467,12 @@ impl ExportConfigurationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1944,7 +2532,12 @@ impl GetDiscoverySummaryError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2006,7 +2599,12 @@ impl ListConfigurationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2066,7 +2664,12 @@ impl ListServerNeighborsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2142,7 +2745,12 @@ impl StartContinuousExportError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2206,7 +2814,12 @@ impl StartDataCollectionByAgentIdsError {
                         StartDataCollectionByAgentIdsError::ServerInternalError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2266,7 +2879,12 @@ impl StartExportTaskError {
                 "ServerInternalErrorException" => {
                     return RusotoError::Service(StartExportTaskError::ServerInternalError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2320,7 +2938,12 @@ impl StartImportTaskError {
                 "ServerInternalErrorException" => {
                     return RusotoError::Service(StartImportTaskError::ServerInternalError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2398,7 +3021,12 @@ impl StopContinuousExportError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2462,7 +3090,12 @@ impl StopDataCollectionByAgentIdsError {
                         StopDataCollectionByAgentIdsError::ServerInternalError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2519,7 +3152,12 @@ impl UpdateApplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2696,45 +3334,8 @@ pub trait Discovery {
         input: UpdateApplicationRequest,
     ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError>;
 }
-/// A client for the AWS Application Discovery Service API.
-#[derive(Clone)]
-pub struct DiscoveryClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl DiscoveryClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> DiscoveryClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> DiscoveryClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> DiscoveryClient {
-        DiscoveryClient { client, region }
-    }
-}
-
-impl Discovery for DiscoveryClient {
-    /// <p>Associates one or more configuration items with an application.</p>
+const _: Option<&dyn Discovery> = None;
+impl<'a, T: Discovery + ?Sized> Discovery for &'a T {
     fn associate_configuration_items_to_application(
         &self,
         input: AssociateConfigurationItemsToApplicationRequest,
@@ -2742,56 +3343,580 @@ impl Discovery for DiscoveryClient {
         AssociateConfigurationItemsToApplicationResponse,
         AssociateConfigurationItemsToApplicationError,
     > {
-        let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSPoseidonService_V2015_11_01.AssociateConfigurationItemsToApplication",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateConfigurationItemsToApplicationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateConfigurationItemsToApplicationError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_configuration_items_to_application(input)
     }
-
-    /// <p>Deletes one or more import tasks, each identified by their import ID. Each import task has a number of records that can identify servers or applications. </p> <p>AWS Application Discovery Service has built-in matching logic that will identify when discovered servers match existing entries that you've previously discovered, the information for the already-existing discovered server is updated. When you delete an import task that contains records that were used to match, the information in those matched records that comes from the deleted records will also be deleted.</p>
     fn batch_delete_import_data(
         &self,
         input: BatchDeleteImportDataRequest,
     ) -> RusotoFuture<BatchDeleteImportDataResponse, BatchDeleteImportDataError> {
-        let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSPoseidonService_V2015_11_01.BatchDeleteImportData",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDeleteImportDataResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchDeleteImportDataError::from_response(response))
-                    }),
+        (**self).batch_delete_import_data(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResponse, CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_applications(
+        &self,
+        input: DeleteApplicationsRequest,
+    ) -> RusotoFuture<DeleteApplicationsResponse, DeleteApplicationsError> {
+        (**self).delete_applications(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResponse, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_agents(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> RusotoFuture<DescribeAgentsResponse, DescribeAgentsError> {
+        (**self).describe_agents(input)
+    }
+    fn describe_configurations(
+        &self,
+        input: DescribeConfigurationsRequest,
+    ) -> RusotoFuture<DescribeConfigurationsResponse, DescribeConfigurationsError> {
+        (**self).describe_configurations(input)
+    }
+    fn describe_continuous_exports(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> RusotoFuture<DescribeContinuousExportsResponse, DescribeContinuousExportsError> {
+        (**self).describe_continuous_exports(input)
+    }
+    fn describe_export_configurations(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> RusotoFuture<DescribeExportConfigurationsResponse, DescribeExportConfigurationsError> {
+        (**self).describe_export_configurations(input)
+    }
+    fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        (**self).describe_export_tasks(input)
+    }
+    fn describe_import_tasks(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> RusotoFuture<DescribeImportTasksResponse, DescribeImportTasksError> {
+        (**self).describe_import_tasks(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn disassociate_configuration_items_from_application(
+        &self,
+        input: DisassociateConfigurationItemsFromApplicationRequest,
+    ) -> RusotoFuture<
+        DisassociateConfigurationItemsFromApplicationResponse,
+        DisassociateConfigurationItemsFromApplicationError,
+    > {
+        (**self).disassociate_configuration_items_from_application(input)
+    }
+    fn export_configurations(
+        &self,
+    ) -> RusotoFuture<ExportConfigurationsResponse, ExportConfigurationsError> {
+        (**self).export_configurations()
+    }
+    fn get_discovery_summary(
+        &self,
+    ) -> RusotoFuture<GetDiscoverySummaryResponse, GetDiscoverySummaryError> {
+        (**self).get_discovery_summary()
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_server_neighbors(
+        &self,
+        input: ListServerNeighborsRequest,
+    ) -> RusotoFuture<ListServerNeighborsResponse, ListServerNeighborsError> {
+        (**self).list_server_neighbors(input)
+    }
+    fn start_continuous_export(
+        &self,
+    ) -> RusotoFuture<StartContinuousExportResponse, StartContinuousExportError> {
+        (**self).start_continuous_export()
+    }
+    fn start_data_collection_by_agent_ids(
+        &self,
+        input: StartDataCollectionByAgentIdsRequest,
+    ) -> RusotoFuture<StartDataCollectionByAgentIdsResponse, StartDataCollectionByAgentIdsError>
+    {
+        (**self).start_data_collection_by_agent_ids(input)
+    }
+    fn start_export_task(
+        &self,
+        input: StartExportTaskRequest,
+    ) -> RusotoFuture<StartExportTaskResponse, StartExportTaskError> {
+        (**self).start_export_task(input)
+    }
+    fn start_import_task(
+        &self,
+        input: StartImportTaskRequest,
+    ) -> RusotoFuture<StartImportTaskResponse, StartImportTaskError> {
+        (**self).start_import_task(input)
+    }
+    fn stop_continuous_export(
+        &self,
+        input: StopContinuousExportRequest,
+    ) -> RusotoFuture<StopContinuousExportResponse, StopContinuousExportError> {
+        (**self).stop_continuous_export(input)
+    }
+    fn stop_data_collection_by_agent_ids(
+        &self,
+        input: StopDataCollectionByAgentIdsRequest,
+    ) -> RusotoFuture<StopDataCollectionByAgentIdsResponse, StopDataCollectionByAgentIdsError> {
+        (**self).stop_data_collection_by_agent_ids(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+impl<T: Discovery + ?Sized> Discovery for Box<T> {
+    fn associate_configuration_items_to_application(
+        &self,
+        input: AssociateConfigurationItemsToApplicationRequest,
+    ) -> RusotoFuture<
+        AssociateConfigurationItemsToApplicationResponse,
+        AssociateConfigurationItemsToApplicationError,
+    > {
+        (**self).associate_configuration_items_to_application(input)
+    }
+    fn batch_delete_import_data(
+        &self,
+        input: BatchDeleteImportDataRequest,
+    ) -> RusotoFuture<BatchDeleteImportDataResponse, BatchDeleteImportDataError> {
+        (**self).batch_delete_import_data(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResponse, CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_applications(
+        &self,
+        input: DeleteApplicationsRequest,
+    ) -> RusotoFuture<DeleteApplicationsResponse, DeleteApplicationsError> {
+        (**self).delete_applications(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResponse, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_agents(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> RusotoFuture<DescribeAgentsResponse, DescribeAgentsError> {
+        (**self).describe_agents(input)
+    }
+    fn describe_configurations(
+        &self,
+        input: DescribeConfigurationsRequest,
+    ) -> RusotoFuture<DescribeConfigurationsResponse, DescribeConfigurationsError> {
+        (**self).describe_configurations(input)
+    }
+    fn describe_continuous_exports(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> RusotoFuture<DescribeContinuousExportsResponse, DescribeContinuousExportsError> {
+        (**self).describe_continuous_exports(input)
+    }
+    fn describe_export_configurations(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> RusotoFuture<DescribeExportConfigurationsResponse, DescribeExportConfigurationsError> {
+        (**self).describe_export_configurations(input)
+    }
+    fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        (**self).describe_export_tasks(input)
+    }
+    fn describe_import_tasks(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> RusotoFuture<DescribeImportTasksResponse, DescribeImportTasksError> {
+        (**self).describe_import_tasks(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn disassociate_configuration_items_from_application(
+        &self,
+        input: DisassociateConfigurationItemsFromApplicationRequest,
+    ) -> RusotoFuture<
+        DisassociateConfigurationItemsFromApplicationResponse,
+        DisassociateConfigurationItemsFromApplicationError,
+    > {
+        (**self).disassociate_configuration_items_from_application(input)
+    }
+    fn export_configurations(
+        &self,
+    ) -> RusotoFuture<ExportConfigurationsResponse, ExportConfigurationsError> {
+        (**self).export_configurations()
+    }
+    fn get_discovery_summary(
+        &self,
+    ) -> RusotoFuture<GetDiscoverySummaryResponse, GetDiscoverySummaryError> {
+        (**self).get_discovery_summary()
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_server_neighbors(
+        &self,
+        input: ListServerNeighborsRequest,
+    ) -> RusotoFuture<ListServerNeighborsResponse, ListServerNeighborsError> {
+        (**self).list_server_neighbors(input)
+    }
+    fn start_continuous_export(
+        &self,
+    ) -> RusotoFuture<StartContinuousExportResponse, StartContinuousExportError> {
+        (**self).start_continuous_export()
+    }
+    fn start_data_collection_by_agent_ids(
+        &self,
+        input: StartDataCollectionByAgentIdsRequest,
+    ) -> RusotoFuture<StartDataCollectionByAgentIdsResponse, StartDataCollectionByAgentIdsError>
+    {
+        (**self).start_data_collection_by_agent_ids(input)
+    }
+    fn start_export_task(
+        &self,
+        input: StartExportTaskRequest,
+    ) -> RusotoFuture<StartExportTaskResponse, StartExportTaskError> {
+        (**self).start_export_task(input)
+    }
+    fn start_import_task(
+        &self,
+        input: StartImportTaskRequest,
+    ) -> RusotoFuture<StartImportTaskResponse, StartImportTaskError> {
+        (**self).start_import_task(input)
+    }
+    fn stop_continuous_export(
+        &self,
+        input: StopContinuousExportRequest,
+    ) -> RusotoFuture<StopContinuousExportResponse, StopContinuousExportError> {
+        (**self).stop_continuous_export(input)
+    }
+    fn stop_data_collection_by_agent_ids(
+        &self,
+        input: StopDataCollectionByAgentIdsRequest,
+    ) -> RusotoFuture<StopDataCollectionByAgentIdsResponse, StopDataCollectionByAgentIdsError> {
+        (**self).stop_data_collection_by_agent_ids(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+impl<T: Discovery + ?Sized> Discovery for ::std::sync::Arc<T> {
+    fn associate_configuration_items_to_application(
+        &self,
+        input: AssociateConfigurationItemsToApplicationRequest,
+    ) -> RusotoFuture<
+        AssociateConfigurationItemsToApplicationResponse,
+        AssociateConfigurationItemsToApplicationError,
+    > {
+        (**self).associate_configuration_items_to_application(input)
+    }
+    fn batch_delete_import_data(
+        &self,
+        input: BatchDeleteImportDataRequest,
+    ) -> RusotoFuture<BatchDeleteImportDataResponse, BatchDeleteImportDataError> {
+        (**self).batch_delete_import_data(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResponse, CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_applications(
+        &self,
+        input: DeleteApplicationsRequest,
+    ) -> RusotoFuture<DeleteApplicationsResponse, DeleteApplicationsError> {
+        (**self).delete_applications(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResponse, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_agents(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> RusotoFuture<DescribeAgentsResponse, DescribeAgentsError> {
+        (**self).describe_agents(input)
+    }
+    fn describe_configurations(
+        &self,
+        input: DescribeConfigurationsRequest,
+    ) -> RusotoFuture<DescribeConfigurationsResponse, DescribeConfigurationsError> {
+        (**self).describe_configurations(input)
+    }
+    fn describe_continuous_exports(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> RusotoFuture<DescribeContinuousExportsResponse, DescribeContinuousExportsError> {
+        (**self).describe_continuous_exports(input)
+    }
+    fn describe_export_configurations(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> RusotoFuture<DescribeExportConfigurationsResponse, DescribeExportConfigurationsError> {
+        (**self).describe_export_configurations(input)
+    }
+    fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        (**self).describe_export_tasks(input)
+    }
+    fn describe_import_tasks(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> RusotoFuture<DescribeImportTasksResponse, DescribeImportTasksError> {
+        (**self).describe_import_tasks(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn disassociate_configuration_items_from_application(
+        &self,
+        input: DisassociateConfigurationItemsFromApplicationRequest,
+    ) -> RusotoFuture<
+        DisassociateConfigurationItemsFromApplicationResponse,
+        DisassociateConfigurationItemsFromApplicationError,
+    > {
+        (**self).disassociate_configuration_items_from_application(input)
+    }
+    fn export_configurations(
+        &self,
+    ) -> RusotoFuture<ExportConfigurationsResponse, ExportConfigurationsError> {
+        (**self).export_configurations()
+    }
+    fn get_discovery_summary(
+        &self,
+    ) -> RusotoFuture<GetDiscoverySummaryResponse, GetDiscoverySummaryError> {
+        (**self).get_discovery_summary()
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_server_neighbors(
+        &self,
+        input: ListServerNeighborsRequest,
+    ) -> RusotoFuture<ListServerNeighborsResponse, ListServerNeighborsError> {
+        (**self).list_server_neighbors(input)
+    }
+    fn start_continuous_export(
+        &self,
+    ) -> RusotoFuture<StartContinuousExportResponse, StartContinuousExportError> {
+        (**self).start_continuous_export()
+    }
+    fn start_data_collection_by_agent_ids(
+        &self,
+        input: StartDataCollectionByAgentIdsRequest,
+    ) -> RusotoFuture<StartDataCollectionByAgentIdsResponse, StartDataCollectionByAgentIdsError>
+    {
+        (**self).start_data_collection_by_agent_ids(input)
+    }
+    fn start_export_task(
+        &self,
+        input: StartExportTaskRequest,
+    ) -> RusotoFuture<StartExportTaskResponse, StartExportTaskError> {
+        (**self).start_export_task(input)
+    }
+    fn start_import_task(
+        &self,
+        input: StartImportTaskRequest,
+    ) -> RusotoFuture<StartImportTaskResponse, StartImportTaskError> {
+        (**self).start_import_task(input)
+    }
+    fn stop_continuous_export(
+        &self,
+        input: StopContinuousExportRequest,
+    ) -> RusotoFuture<StopContinuousExportResponse, StopContinuousExportError> {
+        (**self).stop_continuous_export(input)
+    }
+    fn stop_data_collection_by_agent_ids(
+        &self,
+        input: StopDataCollectionByAgentIdsRequest,
+    ) -> RusotoFuture<StopDataCollectionByAgentIdsResponse, StopDataCollectionByAgentIdsError> {
+        (**self).stop_data_collection_by_agent_ids(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+/// A client for the AWS Application Discovery Service API.
+#[derive(Clone)]
+pub struct DiscoveryClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl DiscoveryClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DiscoveryClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DiscoveryClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> DiscoveryClient {
+        DiscoveryClient { client, region }
+    }
+}
+
+impl Discovery for DiscoveryClient {
+    /// <p>Associates one or more configuration items with an application.</p>
+    fn associate_configuration_items_to_application(
+        &self,
+        input: AssociateConfigurationItemsToApplicationRequest,
+    ) -> RusotoFuture<
+        AssociateConfigurationItemsToApplicationResponse,
+        AssociateConfigurationItemsToApplicationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("AssociateConfigurationItemsToApplication");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSPoseidonService_V2015_11_01.AssociateConfigurationItemsToApplication",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    AssociateConfigurationItemsToApplicationResponse,
+                    _,
+                >(response, "AssociateConfigurationItemsToApplication")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateConfigurationItemsToApplicationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes one or more import tasks, each identified by their import ID. Each import task has a number of records that can identify servers or applications. </p> <p>AWS Application Discovery Service has built-in matching logic that will identify when discovered servers match existing entries that you've previously discovered, the information for the already-existing discovered server is updated. When you delete an import task that contains records that were used to match, the information in those matched records that comes from the deleted records will also be deleted.</p>
+    fn batch_delete_import_data(
+        &self,
+        input: BatchDeleteImportDataRequest,
+    ) -> RusotoFuture<BatchDeleteImportDataResponse, BatchDeleteImportDataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("BatchDeleteImportData");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSPoseidonService_V2015_11_01.BatchDeleteImportData",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDeleteImportDataResponse, _>(
+                    response,
+                    "BatchDeleteImportData",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchDeleteImportDataError::from_response(response))
+                    }),
                 )
             }
         })
@@ -2802,7 +3927,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: CreateApplicationRequest,
     ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2814,10 +3948,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateApplicationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateApplicationResponse, _>(
+                    response,
+                    "CreateApplication",
+                )
             } else {
                 Box::new(
                     response
@@ -2834,7 +3968,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: CreateTagsRequest,
     ) -> RusotoFuture<CreateTagsResponse, CreateTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("CreateTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPoseidonService_V2015_11_01.CreateTags");
@@ -2843,10 +3986,7 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTagsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateTagsResponse, _>(response, "CreateTags")
             } else {
                 Box::new(
                     response
@@ -2863,7 +4003,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: DeleteApplicationsRequest,
     ) -> RusotoFuture<DeleteApplicationsResponse, DeleteApplicationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DeleteApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2875,10 +4024,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteApplicationsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteApplicationsResponse, _>(
+                    response,
+                    "DeleteApplications",
+                )
             } else {
                 Box::new(
                     response
@@ -2895,7 +4044,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: DeleteTagsRequest,
     ) -> RusotoFuture<DeleteTagsResponse, DeleteTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DeleteTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPoseidonService_V2015_11_01.DeleteTags");
@@ -2904,10 +4062,7 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTagsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteTagsResponse, _>(response, "DeleteTags")
             } else {
                 Box::new(
                     response
@@ -2924,7 +4079,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: DescribeAgentsRequest,
     ) -> RusotoFuture<DescribeAgentsResponse, DescribeAgentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeAgents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2936,10 +4100,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAgentsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeAgentsResponse, _>(
+                    response,
+                    "DescribeAgents",
+                )
             } else {
                 Box::new(
                     response
@@ -2956,7 +4120,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: DescribeConfigurationsRequest,
     ) -> RusotoFuture<DescribeConfigurationsResponse, DescribeConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2968,10 +4141,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeConfigurationsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeConfigurationsResponse, _>(
+                    response,
+                    "DescribeConfigurations",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2987,7 +4160,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: DescribeContinuousExportsRequest,
     ) -> RusotoFuture<DescribeContinuousExportsResponse, DescribeContinuousExportsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeContinuousExports");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2999,10 +4181,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeContinuousExportsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeContinuousExportsResponse, _>(
+                    response,
+                    "DescribeContinuousExports",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeContinuousExportsError::from_response(response))
@@ -3016,7 +4198,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: DescribeExportConfigurationsRequest,
     ) -> RusotoFuture<DescribeExportConfigurationsResponse, DescribeExportConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeExportConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3028,10 +4219,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeExportConfigurationsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeExportConfigurationsResponse, _>(
+                    response,
+                    "DescribeExportConfigurations",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeExportConfigurationsError::from_response(response))
@@ -3045,7 +4236,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: DescribeExportTasksRequest,
     ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeExportTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3057,10 +4257,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeExportTasksResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeExportTasksResponse, _>(
+                    response,
+                    "DescribeExportTasks",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3076,7 +4276,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: DescribeImportTasksRequest,
     ) -> RusotoFuture<DescribeImportTasksResponse, DescribeImportTasksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeImportTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3088,10 +4297,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeImportTasksResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeImportTasksResponse, _>(
+                    response,
+                    "DescribeImportTasks",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3107,7 +4316,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: DescribeTagsRequest,
     ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3119,10 +4337,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTagsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeTagsResponse, _>(
+                    response,
+                    "DescribeTags",
+                )
             } else {
                 Box::new(
                     response
@@ -3142,7 +4360,16 @@ impl Discovery for DiscoveryClient {
         DisassociateConfigurationItemsFromApplicationResponse,
         DisassociateConfigurationItemsFromApplicationError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DisassociateConfigurationItemsFromApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3153,16 +4380,17 @@ impl Discovery for DiscoveryClient {
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
-                        if response.status.is_success() {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DisassociateConfigurationItemsFromApplicationResponse, _>()
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DisassociateConfigurationItemsFromApplicationResponse,
+                    _,
+                >(response, "DisassociateConfigurationItemsFromApplication")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateConfigurationItemsFromApplicationError::from_response(response))
                 }))
-                        } else {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                                Err(DisassociateConfigurationItemsFromApplicationError::from_response(response))
-                            }))
-                        }
-                    })
+            }
+        })
     }
 
     /// <p>Deprecated. Use <code>StartExportTask</code> instead.</p> <p>Exports all discovered configuration data to an Amazon S3 bucket or an application that enables you to view and evaluate the data. Data includes tags and tag associations, processes, connections, servers, and system performance. This API returns an export ID that you can query using the <i>DescribeExportConfigurations</i> API. The system imposes a limit of two configuration exports in six hours.</p>
@@ -3170,6 +4398,7 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> RusotoFuture<ExportConfigurationsResponse, ExportConfigurationsError> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("ExportConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3180,10 +4409,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ExportConfigurationsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ExportConfigurationsResponse, _>(
+                    response,
+                    "ExportConfigurations",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3199,6 +4428,7 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> RusotoFuture<GetDiscoverySummaryResponse, GetDiscoverySummaryError> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("GetDiscoverySummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3209,10 +4439,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDiscoverySummaryResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetDiscoverySummaryResponse, _>(
+                    response,
+                    "GetDiscoverySummary",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3228,7 +4458,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: ListConfigurationsRequest,
     ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("ListConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3240,10 +4479,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListConfigurationsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListConfigurationsResponse, _>(
+                    response,
+                    "ListConfigurations",
+                )
             } else {
                 Box::new(
                     response
@@ -3260,7 +4499,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: ListServerNeighborsRequest,
     ) -> RusotoFuture<ListServerNeighborsResponse, ListServerNeighborsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("ListServerNeighbors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3272,10 +4520,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListServerNeighborsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListServerNeighborsResponse, _>(
+                    response,
+                    "ListServerNeighbors",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3291,6 +4539,7 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> RusotoFuture<StartContinuousExportResponse, StartContinuousExportError> {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartContinuousExport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3301,10 +4550,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartContinuousExportResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StartContinuousExportResponse, _>(
+                    response,
+                    "StartContinuousExport",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3321,7 +4570,16 @@ impl Discovery for DiscoveryClient {
         input: StartDataCollectionByAgentIdsRequest,
     ) -> RusotoFuture<StartDataCollectionByAgentIdsResponse, StartDataCollectionByAgentIdsError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartDataCollectionByAgentIds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3333,10 +4591,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartDataCollectionByAgentIdsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StartDataCollectionByAgentIdsResponse, _>(
+                    response,
+                    "StartDataCollectionByAgentIds",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(StartDataCollectionByAgentIdsError::from_response(response))
@@ -3350,7 +4608,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: StartExportTaskRequest,
     ) -> RusotoFuture<StartExportTaskResponse, StartExportTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartExportTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3361,11 +4628,11 @@ impl Discovery for DiscoveryClient {
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartExportTaskResponse, _>()
-                }))
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartExportTaskResponse, _>(
+                    response,
+                    "StartExportTask",
+                )
             } else {
                 Box::new(
                     response
@@ -3382,7 +4649,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: StartImportTaskRequest,
     ) -> RusotoFuture<StartImportTaskResponse, StartImportTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartImportTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3394,10 +4670,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartImportTaskResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StartImportTaskResponse, _>(
+                    response,
+                    "StartImportTask",
+                )
             } else {
                 Box::new(
                     response
@@ -3414,7 +4690,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: StopContinuousExportRequest,
     ) -> RusotoFuture<StopContinuousExportResponse, StopContinuousExportError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StopContinuousExport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3426,10 +4711,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopContinuousExportResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StopContinuousExportResponse, _>(
+                    response,
+                    "StopContinuousExport",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3445,7 +4730,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: StopDataCollectionByAgentIdsRequest,
     ) -> RusotoFuture<StopDataCollectionByAgentIdsResponse, StopDataCollectionByAgentIdsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StopDataCollectionByAgentIds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3457,10 +4751,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopDataCollectionByAgentIdsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StopDataCollectionByAgentIdsResponse, _>(
+                    response,
+                    "StopDataCollectionByAgentIds",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(StopDataCollectionByAgentIdsError::from_response(response))
@@ -3474,7 +4768,16 @@ impl Discovery for DiscoveryClient {
         &self,
         input: UpdateApplicationRequest,
     ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3486,10 +4789,10 @@ impl Discovery for DiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateApplicationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateApplicationResponse, _>(
+                    response,
+                    "UpdateApplication",
+                )
             } else {
                 Box::new(
                     response
@@ -3501,3 +4804,891 @@ impl Discovery for DiscoveryClient {
         })
     }
 }
+
+/// A mock of the AWS Application Discovery Service API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockDiscovery {
+    associate_configuration_items_to_application: Option<
+        Box<
+            dyn Fn(
+                    AssociateConfigurationItemsToApplicationRequest,
+                ) -> ::std::result::Result<
+                    AssociateConfigurationItemsToApplicationResponse,
+                    RusotoError<AssociateConfigurationItemsToApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_delete_import_data: Option<
+        Box<
+            dyn Fn(
+                    BatchDeleteImportDataRequest,
+                ) -> ::std::result::Result<
+                    BatchDeleteImportDataResponse,
+                    RusotoError<BatchDeleteImportDataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_application: Option<
+        Box<
+            dyn Fn(
+                    CreateApplicationRequest,
+                ) -> ::std::result::Result<
+                    CreateApplicationResponse,
+                    RusotoError<CreateApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_tags: Option<
+        Box<
+            dyn Fn(
+                    CreateTagsRequest,
+                )
+                    -> ::std::result::Result<CreateTagsResponse, RusotoError<CreateTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_applications: Option<
+        Box<
+            dyn Fn(
+                    DeleteApplicationsRequest,
+                ) -> ::std::result::Result<
+                    DeleteApplicationsResponse,
+                    RusotoError<DeleteApplicationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_tags: Option<
+        Box<
+            dyn Fn(
+                    DeleteTagsRequest,
+                )
+                    -> ::std::result::Result<DeleteTagsResponse, RusotoError<DeleteTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_agents: Option<
+        Box<
+            dyn Fn(
+                    DescribeAgentsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAgentsResponse,
+                    RusotoError<DescribeAgentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configurations: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigurationsResponse,
+                    RusotoError<DescribeConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_continuous_exports: Option<
+        Box<
+            dyn Fn(
+                    DescribeContinuousExportsRequest,
+                ) -> ::std::result::Result<
+                    DescribeContinuousExportsResponse,
+                    RusotoError<DescribeContinuousExportsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_export_configurations: Option<
+        Box<
+            dyn Fn(
+                    DescribeExportConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeExportConfigurationsResponse,
+                    RusotoError<DescribeExportConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_export_tasks: Option<
+        Box<
+            dyn Fn(
+                    DescribeExportTasksRequest,
+                ) -> ::std::result::Result<
+                    DescribeExportTasksResponse,
+                    RusotoError<DescribeExportTasksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_import_tasks: Option<
+        Box<
+            dyn Fn(
+                    DescribeImportTasksRequest,
+                ) -> ::std::result::Result<
+                    DescribeImportTasksResponse,
+                    RusotoError<DescribeImportTasksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tags: Option<
+        Box<
+            dyn Fn(
+                    DescribeTagsRequest,
+                )
+                    -> ::std::result::Result<DescribeTagsResponse, RusotoError<DescribeTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disassociate_configuration_items_from_application: Option<
+        Box<
+            dyn Fn(
+                    DisassociateConfigurationItemsFromApplicationRequest,
+                ) -> ::std::result::Result<
+                    DisassociateConfigurationItemsFromApplicationResponse,
+                    RusotoError<DisassociateConfigurationItemsFromApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    export_configurations: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    ExportConfigurationsResponse,
+                    RusotoError<ExportConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_discovery_summary: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetDiscoverySummaryResponse,
+                    RusotoError<GetDiscoverySummaryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_configurations: Option<
+        Box<
+            dyn Fn(
+                    ListConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    ListConfigurationsResponse,
+                    RusotoError<ListConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_server_neighbors: Option<
+        Box<
+            dyn Fn(
+                    ListServerNeighborsRequest,
+                ) -> ::std::result::Result<
+                    ListServerNeighborsResponse,
+                    RusotoError<ListServerNeighborsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_continuous_export: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    StartContinuousExportResponse,
+                    RusotoError<StartContinuousExportError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_data_collection_by_agent_ids: Option<
+        Box<
+            dyn Fn(
+                    StartDataCollectionByAgentIdsRequest,
+                ) -> ::std::result::Result<
+                    StartDataCollectionByAgentIdsResponse,
+                    RusotoError<StartDataCollectionByAgentIdsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_export_task: Option<
+        Box<
+            dyn Fn(
+                    StartExportTaskRequest,
+                ) -> ::std::result::Result<
+                    StartExportTaskResponse,
+                    RusotoError<StartExportTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_import_task: Option<
+        Box<
+            dyn Fn(
+                    StartImportTaskRequest,
+                ) -> ::std::result::Result<
+                    StartImportTaskResponse,
+                    RusotoError<StartImportTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_continuous_export: Option<
+        Box<
+            dyn Fn(
+                    StopContinuousExportRequest,
+                ) -> ::std::result::Result<
+                    StopContinuousExportResponse,
+                    RusotoError<StopContinuousExportError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_data_collection_by_agent_ids: Option<
+        Box<
+            dyn Fn(
+                    StopDataCollectionByAgentIdsRequest,
+                ) -> ::std::result::Result<
+                    StopDataCollectionByAgentIdsResponse,
+                    RusotoError<StopDataCollectionByAgentIdsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_application: Option<
+        Box<
+            dyn Fn(
+                    UpdateApplicationRequest,
+                ) -> ::std::result::Result<
+                    UpdateApplicationResponse,
+                    RusotoError<UpdateApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockDiscovery {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockDiscovery {
+        Default::default()
+    }
+
+    /// Answers `associate_configuration_items_to_application` with what `handler` returns for its input.
+    pub fn with_associate_configuration_items_to_application<F>(
+        mut self,
+        handler: F,
+    ) -> MockDiscovery
+    where
+        F: Fn(
+                AssociateConfigurationItemsToApplicationRequest,
+            ) -> ::std::result::Result<
+                AssociateConfigurationItemsToApplicationResponse,
+                RusotoError<AssociateConfigurationItemsToApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_configuration_items_to_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_delete_import_data` with what `handler` returns for its input.
+    pub fn with_batch_delete_import_data<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                BatchDeleteImportDataRequest,
+            ) -> ::std::result::Result<
+                BatchDeleteImportDataResponse,
+                RusotoError<BatchDeleteImportDataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_import_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_application` with what `handler` returns for its input.
+    pub fn with_create_application<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                CreateApplicationRequest,
+            ) -> ::std::result::Result<
+                CreateApplicationResponse,
+                RusotoError<CreateApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_tags` with what `handler` returns for its input.
+    pub fn with_create_tags<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                CreateTagsRequest,
+            )
+                -> ::std::result::Result<CreateTagsResponse, RusotoError<CreateTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_applications` with what `handler` returns for its input.
+    pub fn with_delete_applications<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                DeleteApplicationsRequest,
+            ) -> ::std::result::Result<
+                DeleteApplicationsResponse,
+                RusotoError<DeleteApplicationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_applications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tags` with what `handler` returns for its input.
+    pub fn with_delete_tags<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                DeleteTagsRequest,
+            )
+                -> ::std::result::Result<DeleteTagsResponse, RusotoError<DeleteTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_agents` with what `handler` returns for its input.
+    pub fn with_describe_agents<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                DescribeAgentsRequest,
+            )
+                -> ::std::result::Result<DescribeAgentsResponse, RusotoError<DescribeAgentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_agents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configurations` with what `handler` returns for its input.
+    pub fn with_describe_configurations<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                DescribeConfigurationsRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigurationsResponse,
+                RusotoError<DescribeConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_continuous_exports` with what `handler` returns for its input.
+    pub fn with_describe_continuous_exports<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                DescribeContinuousExportsRequest,
+            ) -> ::std::result::Result<
+                DescribeContinuousExportsResponse,
+                RusotoError<DescribeContinuousExportsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_continuous_exports = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_export_configurations` with what `handler` returns for its input.
+    pub fn with_describe_export_configurations<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                DescribeExportConfigurationsRequest,
+            ) -> ::std::result::Result<
+                DescribeExportConfigurationsResponse,
+                RusotoError<DescribeExportConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_export_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_export_tasks` with what `handler` returns for its input.
+    pub fn with_describe_export_tasks<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                DescribeExportTasksRequest,
+            ) -> ::std::result::Result<
+                DescribeExportTasksResponse,
+                RusotoError<DescribeExportTasksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_export_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_import_tasks` with what `handler` returns for its input.
+    pub fn with_describe_import_tasks<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                DescribeImportTasksRequest,
+            ) -> ::std::result::Result<
+                DescribeImportTasksResponse,
+                RusotoError<DescribeImportTasksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_import_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tags` with what `handler` returns for its input.
+    pub fn with_describe_tags<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                DescribeTagsRequest,
+            )
+                -> ::std::result::Result<DescribeTagsResponse, RusotoError<DescribeTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_configuration_items_from_application` with what `handler` returns for its input.
+    pub fn with_disassociate_configuration_items_from_application<F>(
+        mut self,
+        handler: F,
+    ) -> MockDiscovery
+    where
+        F: Fn(
+                DisassociateConfigurationItemsFromApplicationRequest,
+            ) -> ::std::result::Result<
+                DisassociateConfigurationItemsFromApplicationResponse,
+                RusotoError<DisassociateConfigurationItemsFromApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_configuration_items_from_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `export_configurations` with what `handler` returns.
+    pub fn with_export_configurations<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn() -> ::std::result::Result<
+                ExportConfigurationsResponse,
+                RusotoError<ExportConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.export_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_discovery_summary` with what `handler` returns.
+    pub fn with_get_discovery_summary<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn() -> ::std::result::Result<
+                GetDiscoverySummaryResponse,
+                RusotoError<GetDiscoverySummaryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_discovery_summary = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_configurations` with what `handler` returns for its input.
+    pub fn with_list_configurations<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                ListConfigurationsRequest,
+            ) -> ::std::result::Result<
+                ListConfigurationsResponse,
+                RusotoError<ListConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_server_neighbors` with what `handler` returns for its input.
+    pub fn with_list_server_neighbors<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                ListServerNeighborsRequest,
+            ) -> ::std::result::Result<
+                ListServerNeighborsResponse,
+                RusotoError<ListServerNeighborsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_server_neighbors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_continuous_export` with what `handler` returns.
+    pub fn with_start_continuous_export<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn() -> ::std::result::Result<
+                StartContinuousExportResponse,
+                RusotoError<StartContinuousExportError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_continuous_export = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_data_collection_by_agent_ids` with what `handler` returns for its input.
+    pub fn with_start_data_collection_by_agent_ids<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                StartDataCollectionByAgentIdsRequest,
+            ) -> ::std::result::Result<
+                StartDataCollectionByAgentIdsResponse,
+                RusotoError<StartDataCollectionByAgentIdsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_data_collection_by_agent_ids = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_export_task` with what `handler` returns for its input.
+    pub fn with_start_export_task<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                StartExportTaskRequest,
+            )
+                -> ::std::result::Result<StartExportTaskResponse, RusotoError<StartExportTaskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_export_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_import_task` with what `handler` returns for its input.
+    pub fn with_start_import_task<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                StartImportTaskRequest,
+            )
+                -> ::std::result::Result<StartImportTaskResponse, RusotoError<StartImportTaskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_import_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_continuous_export` with what `handler` returns for its input.
+    pub fn with_stop_continuous_export<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                StopContinuousExportRequest,
+            ) -> ::std::result::Result<
+                StopContinuousExportResponse,
+                RusotoError<StopContinuousExportError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_continuous_export = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_data_collection_by_agent_ids` with what `handler` returns for its input.
+    pub fn with_stop_data_collection_by_agent_ids<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                StopDataCollectionByAgentIdsRequest,
+            ) -> ::std::result::Result<
+                StopDataCollectionByAgentIdsResponse,
+                RusotoError<StopDataCollectionByAgentIdsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_data_collection_by_agent_ids = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_application` with what `handler` returns for its input.
+    pub fn with_update_application<F>(mut self, handler: F) -> MockDiscovery
+    where
+        F: Fn(
+                UpdateApplicationRequest,
+            ) -> ::std::result::Result<
+                UpdateApplicationResponse,
+                RusotoError<UpdateApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_application = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Discovery for MockDiscovery {
+    fn associate_configuration_items_to_application(
+        &self,
+        input: AssociateConfigurationItemsToApplicationRequest,
+    ) -> RusotoFuture<
+        AssociateConfigurationItemsToApplicationResponse,
+        AssociateConfigurationItemsToApplicationError,
+    > {
+        match self.associate_configuration_items_to_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDiscovery has no handler for associate_configuration_items_to_application"
+            ),
+        }
+    }
+    fn batch_delete_import_data(
+        &self,
+        input: BatchDeleteImportDataRequest,
+    ) -> RusotoFuture<BatchDeleteImportDataResponse, BatchDeleteImportDataError> {
+        match self.batch_delete_import_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for batch_delete_import_data"),
+        }
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        match self.create_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for create_application"),
+        }
+    }
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResponse, CreateTagsError> {
+        match self.create_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for create_tags"),
+        }
+    }
+    fn delete_applications(
+        &self,
+        input: DeleteApplicationsRequest,
+    ) -> RusotoFuture<DeleteApplicationsResponse, DeleteApplicationsError> {
+        match self.delete_applications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for delete_applications"),
+        }
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResponse, DeleteTagsError> {
+        match self.delete_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for delete_tags"),
+        }
+    }
+    fn describe_agents(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> RusotoFuture<DescribeAgentsResponse, DescribeAgentsError> {
+        match self.describe_agents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for describe_agents"),
+        }
+    }
+    fn describe_configurations(
+        &self,
+        input: DescribeConfigurationsRequest,
+    ) -> RusotoFuture<DescribeConfigurationsResponse, DescribeConfigurationsError> {
+        match self.describe_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for describe_configurations"),
+        }
+    }
+    fn describe_continuous_exports(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> RusotoFuture<DescribeContinuousExportsResponse, DescribeContinuousExportsError> {
+        match self.describe_continuous_exports {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for describe_continuous_exports"),
+        }
+    }
+    fn describe_export_configurations(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> RusotoFuture<DescribeExportConfigurationsResponse, DescribeExportConfigurationsError> {
+        match self.describe_export_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for describe_export_configurations"),
+        }
+    }
+    fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        match self.describe_export_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for describe_export_tasks"),
+        }
+    }
+    fn describe_import_tasks(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> RusotoFuture<DescribeImportTasksResponse, DescribeImportTasksError> {
+        match self.describe_import_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for describe_import_tasks"),
+        }
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        match self.describe_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for describe_tags"),
+        }
+    }
+    fn disassociate_configuration_items_from_application(
+        &self,
+        input: DisassociateConfigurationItemsFromApplicationRequest,
+    ) -> RusotoFuture<
+        DisassociateConfigurationItemsFromApplicationResponse,
+        DisassociateConfigurationItemsFromApplicationError,
+    > {
+        match self.disassociate_configuration_items_from_application {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockDiscovery has no handler for disassociate_configuration_items_from_application"),
+}
+    }
+    fn export_configurations(
+        &self,
+    ) -> RusotoFuture<ExportConfigurationsResponse, ExportConfigurationsError> {
+        match self.export_configurations {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockDiscovery has no handler for export_configurations"),
+        }
+    }
+    fn get_discovery_summary(
+        &self,
+    ) -> RusotoFuture<GetDiscoverySummaryResponse, GetDiscoverySummaryError> {
+        match self.get_discovery_summary {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockDiscovery has no handler for get_discovery_summary"),
+        }
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        match self.list_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for list_configurations"),
+        }
+    }
+    fn list_server_neighbors(
+        &self,
+        input: ListServerNeighborsRequest,
+    ) -> RusotoFuture<ListServerNeighborsResponse, ListServerNeighborsError> {
+        match self.list_server_neighbors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for list_server_neighbors"),
+        }
+    }
+    fn start_continuous_export(
+        &self,
+    ) -> RusotoFuture<StartContinuousExportResponse, StartContinuousExportError> {
+        match self.start_continuous_export {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockDiscovery has no handler for start_continuous_export"),
+        }
+    }
+    fn start_data_collection_by_agent_ids(
+        &self,
+        input: StartDataCollectionByAgentIdsRequest,
+    ) -> RusotoFuture<StartDataCollectionByAgentIdsResponse, StartDataCollectionByAgentIdsError>
+    {
+        match self.start_data_collection_by_agent_ids {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for start_data_collection_by_agent_ids"),
+        }
+    }
+    fn start_export_task(
+        &self,
+        input: StartExportTaskRequest,
+    ) -> RusotoFuture<StartExportTaskResponse, StartExportTaskError> {
+        match self.start_export_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for start_export_task"),
+        }
+    }
+    fn start_import_task(
+        &self,
+        input: StartImportTaskRequest,
+    ) -> RusotoFuture<StartImportTaskResponse, StartImportTaskError> {
+        match self.start_import_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for start_import_task"),
+        }
+    }
+    fn stop_continuous_export(
+        &self,
+        input: StopContinuousExportRequest,
+    ) -> RusotoFuture<StopContinuousExportResponse, StopContinuousExportError> {
+        match self.stop_continuous_export {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for stop_continuous_export"),
+        }
+    }
+    fn stop_data_collection_by_agent_ids(
+        &self,
+        input: StopDataCollectionByAgentIdsRequest,
+    ) -> RusotoFuture<StopDataCollectionByAgentIdsResponse, StopDataCollectionByAgentIdsError> {
+        match self.stop_data_collection_by_agent_ids {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for stop_data_collection_by_agent_ids"),
+        }
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        match self.update_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDiscovery has no handler for update_application"),
+        }
+    }
+}
@@ -25,7 +25,7 @@ use rusoto_core::signature::SignedRequest;
 use serde_json;
 /// <p>Describes a quota for an AWS account, for example, the number of replication instances allowed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AccountQuota {
     /// <p>The name of the AWS DMS quota for this AWS account.</p>
     #[serde(rename = "AccountQuotaName")]
@@ -43,6 +43,7 @@ pub struct AccountQuota {
 
 /// <p>Associates a set of tags with an AWS DMS resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToResourceMessage {
     /// <p>Identifies the AWS DMS resource to which tags should be added. The value for this parameter is an Amazon Resource Name (ARN).</p> <p>For AWS DMS, you can tag a replication instance, an endpoint, or a replication task.</p>
     #[serde(rename = "ResourceArn")]
@@ -54,11 +55,12 @@ pub struct AddTagsToResourceMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsToResourceResponse {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplyPendingMaintenanceActionMessage {
     /// <p>The pending maintenance action to apply to this resource.</p>
     #[serde(rename = "ApplyAction")]
@@ -73,7 +75,7 @@ pub struct ApplyPendingMaintenanceActionMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApplyPendingMaintenanceActionResponse {
     /// <p>The AWS DMS resource that the pending maintenance action will be applied to.</p>
     #[serde(rename = "ResourcePendingMaintenanceActions")]
@@ -83,7 +85,7 @@ pub struct ApplyPendingMaintenanceActionResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AvailabilityZone {
     /// <p>The name of the availability zone.</p>
     #[serde(rename = "Name")]
@@ -93,7 +95,7 @@ pub struct AvailabilityZone {
 
 /// <p>The SSL certificate that can be used to encrypt connections between the endpoints and the replication instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Certificate {
     /// <p>The Amazon Resource Name (ARN) for the certificate.</p>
     #[serde(rename = "CertificateArn")]
@@ -144,7 +146,7 @@ pub struct Certificate {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Connection {
     /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
     #[serde(rename = "EndpointArn")]
@@ -174,6 +176,7 @@ pub struct Connection {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEndpointMessage {
     /// <p>The Amazon Resource Name (ARN) for the certificate.</p>
     #[serde(rename = "CertificateArn")]
@@ -263,7 +266,7 @@ pub struct CreateEndpointMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateEndpointResponse {
     /// <p>The endpoint that was created.</p>
     #[serde(rename = "Endpoint")]
@@ -273,6 +276,7 @@ pub struct CreateEndpointResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEventSubscriptionMessage {
     /// <p> A Boolean value; set to <code>true</code> to activate the subscription, or set to <code>false</code> to create the subscription but not activate it. </p>
     #[serde(rename = "Enabled")]
@@ -304,7 +308,7 @@ pub struct CreateEventSubscriptionMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateEventSubscriptionResponse {
     /// <p>The event subscription that was created.</p>
     #[serde(rename = "EventSubscription")]
@@ -314,6 +318,7 @@ pub struct CreateEventSubscriptionResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReplicationInstanceMessage {
     /// <p>The amount of storage (in gigabytes) to be initially allocated for the replication instance.</p>
     #[serde(rename = "AllocatedStorage")]
@@ -373,7 +378,7 @@ pub struct CreateReplicationInstanceMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateReplicationInstanceResponse {
     /// <p>The replication instance that was created.</p>
     #[serde(rename = "ReplicationInstance")]
@@ -383,6 +388,7 @@ pub struct CreateReplicationInstanceResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReplicationSubnetGroupMessage {
     /// <p>The description for the subnet group.</p>
     #[serde(rename = "ReplicationSubnetGroupDescription")]
@@ -401,7 +407,7 @@ pub struct CreateReplicationSubnetGroupMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateReplicationSubnetGroupResponse {
     /// <p>The replication subnet group that was created.</p>
     #[serde(rename = "ReplicationSubnetGroup")]
@@ -411,6 +417,7 @@ pub struct CreateReplicationSubnetGroupResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReplicationTaskMessage {
     /// <p>Indicates when you want a change data capture (CDC) operation to start. Use either CdcStartPosition or CdcStartTime to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p> The value can be in date, checkpoint, or LSN/SCN format.</p> <p>Date Example: --cdc-start-position “2018-03-08T12:12:12”</p> <p>Checkpoint Example: --cdc-start-position "checkpoint:V1#27#mysql-bin-changelog.157832:1975:-1:2002:677883278264080:mysql-bin-changelog.157832:1876#0#0#*#0#93"</p> <p>LSN Example: --cdc-start-position “mysql-bin-changelog.000024:373”</p>
     #[serde(rename = "CdcStartPosition")]
@@ -454,7 +461,7 @@ pub struct CreateReplicationTaskMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateReplicationTaskResponse {
     /// <p>The replication task that was created.</p>
     #[serde(rename = "ReplicationTask")]
@@ -463,6 +470,7 @@ pub struct CreateReplicationTaskResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCertificateMessage {
     /// <p>The Amazon Resource Name (ARN) of the deleted certificate.</p>
     #[serde(rename = "CertificateArn")]
@@ -470,7 +478,7 @@ pub struct DeleteCertificateMessage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteCertificateResponse {
     /// <p>The Secure Sockets Layer (SSL) certificate.</p>
     #[serde(rename = "Certificate")]
@@ -480,6 +488,7 @@ pub struct DeleteCertificateResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEndpointMessage {
     /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
     #[serde(rename = "EndpointArn")]
@@ -488,7 +497,7 @@ pub struct DeleteEndpointMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteEndpointResponse {
     /// <p>The endpoint that was deleted.</p>
     #[serde(rename = "Endpoint")]
@@ -498,6 +507,7 @@ pub struct DeleteEndpointResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEventSubscriptionMessage {
     /// <p>The name of the DMS event notification subscription to be deleted.</p>
     #[serde(rename = "SubscriptionName")]
@@ -506,7 +516,7 @@ pub struct DeleteEventSubscriptionMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteEventSubscriptionResponse {
     /// <p>The event subscription that was deleted.</p>
     #[serde(rename = "EventSubscription")]
@@ -516,6 +526,7 @@ pub struct DeleteEventSubscriptionResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReplicationInstanceMessage {
     /// <p>The Amazon Resource Name (ARN) of the replication instance to be deleted.</p>
     #[serde(rename = "ReplicationInstanceArn")]
@@ -524,7 +535,7 @@ pub struct DeleteReplicationInstanceMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteReplicationInstanceResponse {
     /// <p>The replication instance that was deleted.</p>
     #[serde(rename = "ReplicationInstance")]
@@ -534,6 +545,7 @@ pub struct DeleteReplicationInstanceResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReplicationSubnetGroupMessage {
     /// <p>The subnet group name of the replication instance.</p>
     #[serde(rename = "ReplicationSubnetGroupIdentifier")]
@@ -542,11 +554,12 @@ pub struct DeleteReplicationSubnetGroupMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteReplicationSubnetGroupResponse {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReplicationTaskMessage {
     /// <p>The Amazon Resource Name (ARN) of the replication task to be deleted.</p>
     #[serde(rename = "ReplicationTaskArn")]
@@ -555,7 +568,7 @@ pub struct DeleteReplicationTaskMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteReplicationTaskResponse {
     /// <p>The deleted replication task.</p>
     #[serde(rename = "ReplicationTask")]
@@ -565,11 +578,12 @@ pub struct DeleteReplicationTaskResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountAttributesMessage {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAccountAttributesResponse {
     /// <p>Account quota information.</p>
     #[serde(rename = "AccountQuotas")]
@@ -582,6 +596,7 @@ pub struct DescribeAccountAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCertificatesMessage {
     /// <p>Filters applied to the certificate described in the form of key-value pairs.</p>
     #[serde(rename = "Filters")]
@@ -598,7 +613,7 @@ pub struct DescribeCertificatesMessage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCertificatesResponse {
     /// <p>The Secure Sockets Layer (SSL) certificates associated with the replication instance.</p>
     #[serde(rename = "Certificates")]
@@ -612,6 +627,7 @@ pub struct DescribeCertificatesResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConnectionsMessage {
     /// <p>The filters applied to the connection.</p> <p>Valid filter names: endpoint-arn | replication-instance-arn</p>
     #[serde(rename = "Filters")]
@@ -629,7 +645,7 @@ pub struct DescribeConnectionsMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConnectionsResponse {
     /// <p>A description of the connections.</p>
     #[serde(rename = "Connections")]
@@ -643,6 +659,7 @@ pub struct DescribeConnectionsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEndpointTypesMessage {
     /// <p>Filters applied to the describe action.</p> <p>Valid filter names: engine-name | endpoint-type</p>
     #[serde(rename = "Filters")]
@@ -660,7 +677,7 @@ pub struct DescribeEndpointTypesMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEndpointTypesResponse {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
     #[serde(rename = "Marker")]
@@ -674,6 +691,7 @@ pub struct DescribeEndpointTypesResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEndpointsMessage {
     /// <p>Filters applied to the describe action.</p> <p>Valid filter names: endpoint-arn | endpoint-type | endpoint-id | engine-name</p>
     #[serde(rename = "Filters")]
@@ -691,7 +709,7 @@ pub struct DescribeEndpointsMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEndpointsResponse {
     /// <p>Endpoint description.</p>
     #[serde(rename = "Endpoints")]
@@ -705,6 +723,7 @@ pub struct DescribeEndpointsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventCategoriesMessage {
     /// <p>Filters applied to the action.</p>
     #[serde(rename = "Filters")]
@@ -718,7 +737,7 @@ pub struct DescribeEventCategoriesMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventCategoriesResponse {
     /// <p>A list of event categories.</p>
     #[serde(rename = "EventCategoryGroupList")]
@@ -728,6 +747,7 @@ pub struct DescribeEventCategoriesResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventSubscriptionsMessage {
     /// <p>Filters applied to the action.</p>
     #[serde(rename = "Filters")]
@@ -749,7 +769,7 @@ pub struct DescribeEventSubscriptionsMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventSubscriptionsResponse {
     /// <p>A list of event subscriptions.</p>
     #[serde(rename = "EventSubscriptionsList")]
@@ -763,6 +783,7 @@ pub struct DescribeEventSubscriptionsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventsMessage {
     /// <p>The duration of the events to be listed.</p>
     #[serde(rename = "Duration")]
@@ -804,7 +825,7 @@ pub struct DescribeEventsMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventsResponse {
     /// <p>The events described.</p>
     #[serde(rename = "Events")]
@@ -818,6 +839,7 @@ pub struct DescribeEventsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeOrderableReplicationInstancesMessage {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
     #[serde(rename = "Marker")]
@@ -831,7 +853,7 @@ pub struct DescribeOrderableReplicationInstancesMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeOrderableReplicationInstancesResponse {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
     #[serde(rename = "Marker")]
@@ -845,6 +867,7 @@ pub struct DescribeOrderableReplicationInstancesResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePendingMaintenanceActionsMessage {
     /// <p><p/></p>
     #[serde(rename = "Filters")]
@@ -866,7 +889,7 @@ pub struct DescribePendingMaintenanceActionsMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePendingMaintenanceActionsResponse {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
     #[serde(rename = "Marker")]
@@ -880,6 +903,7 @@ pub struct DescribePendingMaintenanceActionsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRefreshSchemasStatusMessage {
     /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
     #[serde(rename = "EndpointArn")]
@@ -888,7 +912,7 @@ pub struct DescribeRefreshSchemasStatusMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRefreshSchemasStatusResponse {
     /// <p>The status of the schema.</p>
     #[serde(rename = "RefreshSchemasStatus")]
@@ -897,6 +921,7 @@ pub struct DescribeRefreshSchemasStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReplicationInstanceTaskLogsMessage {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     #[serde(rename = "Marker")]
@@ -912,7 +937,7 @@ pub struct DescribeReplicationInstanceTaskLogsMessage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeReplicationInstanceTaskLogsResponse {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     #[serde(rename = "Marker")]
@@ -930,6 +955,7 @@ pub struct DescribeReplicationInstanceTaskLogsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReplicationInstancesMessage {
     /// <p>Filters applied to the describe action.</p> <p>Valid filter names: replication-instance-arn | replication-instance-id | replication-instance-class | engine-version</p>
     #[serde(rename = "Filters")]
@@ -947,7 +973,7 @@ pub struct DescribeReplicationInstancesMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeReplicationInstancesResponse {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
     #[serde(rename = "Marker")]
@@ -961,6 +987,7 @@ pub struct DescribeReplicationInstancesResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReplicationSubnetGroupsMessage {
     /// <p>Filters applied to the describe action.</p>
     #[serde(rename = "Filters")]
@@ -978,7 +1005,7 @@ pub struct DescribeReplicationSubnetGroupsMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeReplicationSubnetGroupsResponse {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
     #[serde(rename = "Marker")]
@@ -992,6 +1019,7 @@ pub struct DescribeReplicationSubnetGroupsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReplicationTaskAssessmentResultsMessage {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
     #[serde(rename = "Marker")]
@@ -1009,7 +1037,7 @@ pub struct DescribeReplicationTaskAssessmentResultsMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeReplicationTaskAssessmentResultsResponse {
     /// <p>- The Amazon S3 bucket where the task assessment report is located. </p>
     #[serde(rename = "BucketName")]
@@ -1027,6 +1055,7 @@ pub struct DescribeReplicationTaskAssessmentResultsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReplicationTasksMessage {
     /// <p>Filters applied to the describe action.</p> <p>Valid filter names: replication-task-arn | replication-task-id | migration-type | endpoint-arn | replication-instance-arn</p>
     #[serde(rename = "Filters")]
@@ -1048,7 +1077,7 @@ pub struct DescribeReplicationTasksMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeReplicationTasksResponse {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
     #[serde(rename = "Marker")]
@@ -1062,6 +1091,7 @@ pub struct DescribeReplicationTasksResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSchemasMessage {
     /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
     #[serde(rename = "EndpointArn")]
@@ -1078,7 +1108,7 @@ pub struct DescribeSchemasMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSchemasResponse {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
     #[serde(rename = "Marker")]
@@ -1092,6 +1122,7 @@ pub struct DescribeSchemasResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTableStatisticsMessage {
     /// <p>Filters applied to the describe table statistics action.</p> <p>Valid filter names: schema-name | table-name | table-state</p> <p>A combination of filters creates an AND condition where each record matches all specified filters.</p>
     #[serde(rename = "Filters")]
@@ -1112,7 +1143,7 @@ pub struct DescribeTableStatisticsMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTableStatisticsResponse {
     /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
     #[serde(rename = "Marker")]
@@ -1170,7 +1201,7 @@ pub struct ElasticsearchSettings {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Endpoint {
     /// <p>The Amazon Resource Name (ARN) used for SSL connection to the endpoint.</p>
     #[serde(rename = "CertificateArn")]
@@ -1272,7 +1303,7 @@ pub struct Endpoint {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Event {
     /// <p>The date of the event.</p>
     #[serde(rename = "Date")]
@@ -1298,7 +1329,7 @@ pub struct Event {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventCategoryGroup {
     /// <p> A list of event categories from a source type that you've chosen.</p>
     #[serde(rename = "EventCategories")]
@@ -1312,7 +1343,7 @@ pub struct EventCategoryGroup {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventSubscription {
     /// <p>The AWS DMS event notification subscription Id.</p>
     #[serde(rename = "CustSubscriptionId")]
@@ -1354,6 +1385,7 @@ pub struct EventSubscription {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "Name")]
@@ -1364,6 +1396,7 @@ pub struct Filter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportCertificateMessage {
     /// <p>A customer-assigned name for the certificate. Identifiers must begin with a letter; must contain only ASCII letters, digits, and hyphens; and must not end with a hyphen or contain two consecutive hyphens.</p>
     #[serde(rename = "CertificateIdentifier")]
@@ -1388,7 +1421,7 @@ pub struct ImportCertificateMessage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportCertificateResponse {
     /// <p>The certificate to be uploaded.</p>
     #[serde(rename = "Certificate")]
@@ -1415,6 +1448,7 @@ pub struct KinesisSettings {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceMessage {
     /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the AWS DMS resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -1423,7 +1457,7 @@ pub struct ListTagsForResourceMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>A list of tags for the resource.</p>
     #[serde(rename = "TagList")]
@@ -1433,6 +1467,7 @@ pub struct ListTagsForResourceResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyEndpointMessage {
     /// <p>The Amazon Resource Name (ARN) of the certificate used for SSL connection.</p>
     #[serde(rename = "CertificateArn")]
@@ -1520,7 +1555,7 @@ pub struct ModifyEndpointMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyEndpointResponse {
     /// <p>The modified endpoint.</p>
     #[serde(rename = "Endpoint")]
@@ -1530,6 +1565,7 @@ pub struct ModifyEndpointResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyEventSubscriptionMessage {
     /// <p> A Boolean value; set to <b>true</b> to activate the subscription. </p>
     #[serde(rename = "Enabled")]
@@ -1554,7 +1590,7 @@ pub struct ModifyEventSubscriptionMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyEventSubscriptionResponse {
     /// <p>The modified event subscription.</p>
     #[serde(rename = "EventSubscription")]
@@ -1564,6 +1600,7 @@ pub struct ModifyEventSubscriptionResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyReplicationInstanceMessage {
     /// <p>The amount of storage (in gigabytes) to be allocated for the replication instance.</p>
     #[serde(rename = "AllocatedStorage")]
@@ -1612,7 +1649,7 @@ pub struct ModifyReplicationInstanceMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyReplicationInstanceResponse {
     /// <p>The modified replication instance.</p>
     #[serde(rename = "ReplicationInstance")]
@@ -1622,6 +1659,7 @@ pub struct ModifyReplicationInstanceResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyReplicationSubnetGroupMessage {
     /// <p>A description for the replication instance subnet group.</p>
     #[serde(rename = "ReplicationSubnetGroupDescription")]
@@ -1637,7 +1675,7 @@ pub struct ModifyReplicationSubnetGroupMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyReplicationSubnetGroupResponse {
     /// <p>The modified replication subnet group.</p>
     #[serde(rename = "ReplicationSubnetGroup")]
@@ -1647,6 +1685,7 @@ pub struct ModifyReplicationSubnetGroupResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyReplicationTaskMessage {
     /// <p>Indicates when you want a change data capture (CDC) operation to start. Use either CdcStartPosition or CdcStartTime to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p> The value can be in date, checkpoint, or LSN/SCN format.</p> <p>Date Example: --cdc-start-position “2018-03-08T12:12:12”</p> <p>Checkpoint Example: --cdc-start-position "checkpoint:V1#27#mysql-bin-changelog.157832:1975:-1:2002:677883278264080:mysql-bin-changelog.157832:1876#0#0#*#0#93"</p> <p>LSN Example: --cdc-start-position “mysql-bin-changelog.000024:373”</p>
     #[serde(rename = "CdcStartPosition")]
@@ -1683,7 +1722,7 @@ pub struct ModifyReplicationTaskMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyReplicationTaskResponse {
     /// <p>The replication task that was modified.</p>
     #[serde(rename = "ReplicationTask")]
@@ -1746,7 +1785,7 @@ pub struct MongoDbSettings {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OrderableReplicationInstance {
     /// <p>List of Availability Zones for this replication instance.</p>
     #[serde(rename = "AvailabilityZones")]
@@ -1788,7 +1827,7 @@ pub struct OrderableReplicationInstance {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PendingMaintenanceAction {
     /// <p>The type of pending maintenance action that is available for the resource.</p>
     #[serde(rename = "Action")]
@@ -1817,6 +1856,7 @@ pub struct PendingMaintenanceAction {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootReplicationInstanceMessage {
     /// <p>If this parameter is <code>true</code>, the reboot is conducted through a Multi-AZ failover. (If the instance isn't configured for Multi-AZ, then you can't specify <code>true</code>.)</p>
     #[serde(rename = "ForceFailover")]
@@ -1828,7 +1868,7 @@ pub struct RebootReplicationInstanceMessage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RebootReplicationInstanceResponse {
     /// <p>The replication instance that is being rebooted. </p>
     #[serde(rename = "ReplicationInstance")]
@@ -1943,6 +1983,7 @@ pub struct RedshiftSettings {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RefreshSchemasMessage {
     /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
     #[serde(rename = "EndpointArn")]
@@ -1954,7 +1995,7 @@ pub struct RefreshSchemasMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RefreshSchemasResponse {
     /// <p>The status of the refreshed schema.</p>
     #[serde(rename = "RefreshSchemasStatus")]
@@ -1964,7 +2005,7 @@ pub struct RefreshSchemasResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RefreshSchemasStatus {
     /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
     #[serde(rename = "EndpointArn")]
@@ -1989,6 +2030,7 @@ pub struct RefreshSchemasStatus {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReloadTablesMessage {
     /// <p>Options for reload. Specify <code>data-reload</code> to reload the data and re-validate it if validation is enabled. Specify <code>validate-only</code> to re-validate the table. This option applies only when validation is enabled for the task. </p> <p>Valid values: data-reload, validate-only</p> <p>Default value is data-reload.</p>
     #[serde(rename = "ReloadOption")]
@@ -2003,7 +2045,7 @@ pub struct ReloadTablesMessage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReloadTablesResponse {
     /// <p>The Amazon Resource Name (ARN) of the replication task. </p>
     #[serde(rename = "ReplicationTaskArn")]
@@ -2013,6 +2055,7 @@ pub struct ReloadTablesResponse {
 
 /// <p>Removes one or more tags from an AWS DMS resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromResourceMessage {
     /// <p>An AWS DMS resource from which you want to remove tag(s). The value for this parameter is an Amazon Resource Name (ARN).</p>
     #[serde(rename = "ResourceArn")]
@@ -2024,12 +2067,12 @@ pub struct RemoveTagsFromResourceMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsFromResourceResponse {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicationInstance {
     /// <p>The amount of storage (in gigabytes) that is allocated for the replication instance.</p>
     #[serde(rename = "AllocatedStorage")]
@@ -2119,7 +2162,7 @@ pub struct ReplicationInstance {
 
 /// <p>Contains metadata for a replication instance task log.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicationInstanceTaskLog {
     /// <p>The size, in bytes, of the replication task log.</p>
     #[serde(rename = "ReplicationInstanceTaskLogSize")]
@@ -2137,7 +2180,7 @@ pub struct ReplicationInstanceTaskLog {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicationPendingModifiedValues {
     /// <p>The amount of storage (in gigabytes) that is allocated for the replication instance.</p>
     #[serde(rename = "AllocatedStorage")]
@@ -2159,7 +2202,7 @@ pub struct ReplicationPendingModifiedValues {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicationSubnetGroup {
     /// <p>A description for the replication subnet group.</p>
     #[serde(rename = "ReplicationSubnetGroupDescription")]
@@ -2185,7 +2228,7 @@ pub struct ReplicationSubnetGroup {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicationTask {
     /// <p>Indicates when you want a change data capture (CDC) operation to start. Use either <code>CdcStartPosition</code> or <code>CdcStartTime</code> to specify when you want the CDC operation to start. Specifying both values results in an error.</p> <p>The value can be in date, checkpoint, or LSN/SCN format.</p> <p>Date Example: --cdc-start-position “2018-03-08T12:12:12”</p> <p>Checkpoint Example: --cdc-start-position "checkpoint:V1#27#mysql-bin-changelog.157832:1975:-1:2002:677883278264080:mysql-bin-changelog.157832:1876#0#0#*#0#93"</p> <p>LSN Example: --cdc-start-position “mysql-bin-changelog.000024:373”</p>
     #[serde(rename = "CdcStartPosition")]
@@ -2259,7 +2302,7 @@ pub struct ReplicationTask {
 
 /// <p> The task assessment report in JSON format. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicationTaskAssessmentResult {
     /// <p> The task assessment results in JSON format. </p>
     #[serde(rename = "AssessmentResults")]
@@ -2293,7 +2336,7 @@ pub struct ReplicationTaskAssessmentResult {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicationTaskStats {
     /// <p>The elapsed time of the task, in milliseconds.</p>
     #[serde(rename = "ElapsedTimeMillis")]
@@ -2323,7 +2366,7 @@ pub struct ReplicationTaskStats {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourcePendingMaintenanceActions {
     /// <p>Detailed information about the pending maintenance action.</p>
     #[serde(rename = "PendingMaintenanceActionDetails")]
@@ -2418,6 +2461,7 @@ pub struct S3Settings {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartReplicationTaskAssessmentMessage {
     /// <p> The Amazon Resource Name (ARN) of the replication task. </p>
     #[serde(rename = "ReplicationTaskArn")]
@@ -2426,7 +2470,7 @@ pub struct StartReplicationTaskAssessmentMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartReplicationTaskAssessmentResponse {
     /// <p> The assessed replication task. </p>
     #[serde(rename = "ReplicationTask")]
@@ -2436,6 +2480,7 @@ pub struct StartReplicationTaskAssessmentResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartReplicationTaskMessage {
     /// <p>Indicates when you want a change data capture (CDC) operation to start. Use either CdcStartPosition or CdcStartTime to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p> The value can be in date, checkpoint, or LSN/SCN format.</p> <p>Date Example: --cdc-start-position “2018-03-08T12:12:12”</p> <p>Checkpoint Example: --cdc-start-position "checkpoint:V1#27#mysql-bin-changelog.157832:1975:-1:2002:677883278264080:mysql-bin-changelog.157832:1876#0#0#*#0#93"</p> <p>LSN Example: --cdc-start-position “mysql-bin-changelog.000024:373”</p>
     #[serde(rename = "CdcStartPosition")]
@@ -2459,7 +2504,7 @@ pub struct StartReplicationTaskMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartReplicationTaskResponse {
     /// <p>The replication task started.</p>
     #[serde(rename = "ReplicationTask")]
@@ -2469,6 +2514,7 @@ pub struct StartReplicationTaskResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopReplicationTaskMessage {
     /// <p>The Amazon Resource Name(ARN) of the replication task to be stopped.</p>
     #[serde(rename = "ReplicationTaskArn")]
@@ -2477,7 +2523,7 @@ pub struct StopReplicationTaskMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopReplicationTaskResponse {
     /// <p>The replication task stopped.</p>
     #[serde(rename = "ReplicationTask")]
@@ -2487,7 +2533,7 @@ pub struct StopReplicationTaskResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Subnet {
     /// <p>The Availability Zone of the subnet.</p>
     #[serde(rename = "SubnetAvailabilityZone")]
@@ -2505,7 +2551,7 @@ pub struct Subnet {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SupportedEndpointType {
     /// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
     #[serde(rename = "EndpointType")]
@@ -2527,7 +2573,7 @@ pub struct SupportedEndpointType {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TableStatistics {
     /// <p>The Data Definition Language (DDL) used to build and modify the structure of your tables.</p>
     #[serde(rename = "Ddls")]
@@ -2597,6 +2643,7 @@ pub struct TableStatistics {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TableToReload {
     /// <p>The schema name of the table to be reloaded.</p>
     #[serde(rename = "SchemaName")]
@@ -2623,6 +2670,7 @@ pub struct Tag {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestConnectionMessage {
     /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
     #[serde(rename = "EndpointArn")]
@@ -2634,7 +2682,7 @@ pub struct TestConnectionMessage {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TestConnectionResponse {
     /// <p>The connection tested.</p>
     #[serde(rename = "Connection")]
@@ -2644,7 +2692,7 @@ pub struct TestConnectionResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VpcSecurityGroupMembership {
     /// <p>The status of the VPC security group.</p>
     #[serde(rename = "Status")]
@@ -2672,7 +2720,12 @@ impl AddTagsToResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2709,7 +2762,12 @@ impl ApplyPendingMaintenanceActionError {
                         ApplyPendingMaintenanceActionError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2777,7 +2835,12 @@ impl CreateEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2880,7 +2943,12 @@ impl CreateEventSubscriptionError {
                         CreateEventSubscriptionError::SNSNoAuthorizationFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2989,7 +3057,12 @@ impl CreateReplicationInstanceError {
                         CreateReplicationInstanceError::StorageQuotaExceededFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3072,7 +3145,12 @@ impl CreateReplicationSubnetGroupError {
                         CreateReplicationSubnetGroupError::ResourceQuotaExceededFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3149,7 +3227,12 @@ impl CreateReplicationTaskError {
                         CreateReplicationTaskError::ResourceQuotaExceededFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3196,7 +3279,12 @@ impl DeleteCertificateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3239,7 +3327,12 @@ impl DeleteEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3282,7 +3375,12 @@ impl DeleteEventSubscriptionError {
                         DeleteEventSubscriptionError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3325,7 +3423,12 @@ impl DeleteReplicationInstanceError {
                         DeleteReplicationInstanceError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3370,7 +3473,12 @@ impl DeleteReplicationSubnetGroupError {
                         DeleteReplicationSubnetGroupError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3413,7 +3521,12 @@ impl DeleteReplicationTaskError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3441,7 +3554,12 @@ impl DescribeAccountAttributesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAccountAttributesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3474,7 +3592,12 @@ impl DescribeCertificatesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3509,7 +3632,12 @@ impl DescribeConnectionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3536,7 +3664,12 @@ impl DescribeEndpointTypesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointTypesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3569,7 +3702,12 @@ impl DescribeEndpointsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3596,7 +3734,12 @@ impl DescribeEventCategoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventCategoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3631,7 +3774,12 @@ impl DescribeEventSubscriptionsError {
                         DescribeEventSubscriptionsError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3658,7 +3806,12 @@ impl DescribeEventsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3685,7 +3838,12 @@ impl DescribeOrderableReplicationInstancesError {
     ) -> RusotoError<DescribeOrderableReplicationInstancesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3720,7 +3878,12 @@ impl DescribePendingMaintenanceActionsError {
                         DescribePendingMaintenanceActionsError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3764,7 +3927,12 @@ impl DescribeRefreshSchemasStatusError {
                         DescribeRefreshSchemasStatusError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3811,7 +3979,12 @@ impl DescribeReplicationInstanceTaskLogsError {
                         DescribeReplicationInstanceTaskLogsError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3849,7 +4022,12 @@ impl DescribeReplicationInstancesError {
                         DescribeReplicationInstancesError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3886,7 +4064,12 @@ impl DescribeReplicationSubnetGroupsError {
                         DescribeReplicationSubnetGroupsError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3925,7 +4108,12 @@ impl DescribeReplicationTaskAssessmentResultsError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3962,7 +4150,12 @@ impl DescribeReplicationTasksError {
                         DescribeReplicationTasksError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4004,7 +4197,12 @@ impl DescribeSchemasError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4047,7 +4245,12 @@ impl DescribeTableStatisticsError {
                         DescribeTableStatisticsError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4097,7 +4300,12 @@ impl ImportCertificateError {
                         ImportCertificateError::ResourceQuotaExceededFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4134,7 +4342,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4195,7 +4408,12 @@ impl ModifyEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4290,7 +4508,12 @@ impl ModifyEventSubscriptionError {
                         ModifyEventSubscriptionError::SNSNoAuthorizationFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4375,7 +4598,12 @@ impl ModifyReplicationInstanceError {
                         ModifyReplicationInstanceError::UpgradeDependencyFailureFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4453,7 +4681,12 @@ impl ModifyReplicationSubnetGroupError {
                         ModifyReplicationSubnetGroupError::SubnetAlreadyInUse(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4516,7 +4749,12 @@ impl ModifyReplicationTaskError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4561,7 +4799,12 @@ impl RebootReplicationInstanceError {
                         RebootReplicationInstanceError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4618,7 +4861,12 @@ impl RefreshSchemasError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4661,7 +4909,12 @@ impl ReloadTablesError {
                 "ResourceNotFoundFault" => {
                     return RusotoError::Service(ReloadTablesError::ResourceNotFoundFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4697,7 +4950,12 @@ impl RemoveTagsFromResourceError {
                         RemoveTagsFromResourceError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4746,7 +5004,12 @@ impl StartReplicationTaskError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4792,7 +5055,12 @@ impl StartReplicationTaskAssessmentError {
                         StartReplicationTaskAssessmentError::ResourceNotFoundFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4835,7 +5103,12 @@ impl StopReplicationTaskError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4892,7 +5165,12 @@ impl TestConnectionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5203,642 +5481,134 @@ pub trait DatabaseMigrationService {
         input: TestConnectionMessage,
     ) -> RusotoFuture<TestConnectionResponse, TestConnectionError>;
 }
-/// A client for the AWS Database Migration Service API.
-#[derive(Clone)]
-pub struct DatabaseMigrationServiceClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl DatabaseMigrationServiceClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> DatabaseMigrationServiceClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> DatabaseMigrationServiceClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(
-        client: Client,
-        region: region::Region,
-    ) -> DatabaseMigrationServiceClient {
-        DatabaseMigrationServiceClient { client, region }
-    }
-}
-
-impl DatabaseMigrationService for DatabaseMigrationServiceClient {
-    /// <p>Adds metadata tags to an AWS DMS resource, including replication instance, endpoint, security group, and migration task. These tags can also be used with cost allocation reporting to track cost associated with DMS resources, or used in a Condition statement in an IAM policy for DMS.</p>
+const _: Option<&dyn DatabaseMigrationService> = None;
+impl<'a, T: DatabaseMigrationService + ?Sized> DatabaseMigrationService for &'a T {
     fn add_tags_to_resource(
         &self,
         input: AddTagsToResourceMessage,
     ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.AddTagsToResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddTagsToResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_tags_to_resource(input)
     }
-
-    /// <p>Applies a pending maintenance action to a resource (for example, to a replication instance).</p>
     fn apply_pending_maintenance_action(
         &self,
         input: ApplyPendingMaintenanceActionMessage,
     ) -> RusotoFuture<ApplyPendingMaintenanceActionResponse, ApplyPendingMaintenanceActionError>
     {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.ApplyPendingMaintenanceAction",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ApplyPendingMaintenanceActionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ApplyPendingMaintenanceActionError::from_response(response))
-                }))
-            }
-        })
+        (**self).apply_pending_maintenance_action(input)
     }
-
-    /// <p>Creates an endpoint using the provided settings.</p>
     fn create_endpoint(
         &self,
         input: CreateEndpointMessage,
     ) -> RusotoFuture<CreateEndpointResponse, CreateEndpointError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateEndpointResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateEndpointError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_endpoint(input)
     }
-
-    /// <p> Creates an AWS DMS event notification subscription. </p> <p>You can specify the type of source (<code>SourceType</code>) you want to be notified of, provide a list of AWS DMS source IDs (<code>SourceIds</code>) that triggers the events, and provide a list of event categories (<code>EventCategories</code>) for events you want to be notified of. If you specify both the <code>SourceType</code> and <code>SourceIds</code>, such as <code>SourceType = replication-instance</code> and <code>SourceIdentifier = my-replinstance</code>, you will be notified of all the replication instance events for the specified source. If you specify a <code>SourceType</code> but don't specify a <code>SourceIdentifier</code>, you receive notice of the events for that source type for all your AWS DMS sources. If you don't specify either <code>SourceType</code> nor <code>SourceIdentifier</code>, you will be notified of events generated from all AWS DMS sources belonging to your customer account.</p> <p>For more information about AWS DMS events, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration Service User Guide.</i> </p>
     fn create_event_subscription(
         &self,
         input: CreateEventSubscriptionMessage,
     ) -> RusotoFuture<CreateEventSubscriptionResponse, CreateEventSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEventSubscription");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateEventSubscriptionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateEventSubscriptionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_event_subscription(input)
     }
-
-    /// <p>Creates the replication instance using the specified parameters.</p>
     fn create_replication_instance(
         &self,
         input: CreateReplicationInstanceMessage,
     ) -> RusotoFuture<CreateReplicationInstanceResponse, CreateReplicationInstanceError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.CreateReplicationInstance",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateReplicationInstanceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateReplicationInstanceError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_replication_instance(input)
     }
-
-    /// <p>Creates a replication subnet group given a list of the subnet IDs in a VPC.</p>
     fn create_replication_subnet_group(
         &self,
         input: CreateReplicationSubnetGroupMessage,
     ) -> RusotoFuture<CreateReplicationSubnetGroupResponse, CreateReplicationSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.CreateReplicationSubnetGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateReplicationSubnetGroupResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateReplicationSubnetGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_replication_subnet_group(input)
     }
-
-    /// <p>Creates a replication task using the specified parameters.</p>
     fn create_replication_task(
         &self,
         input: CreateReplicationTaskMessage,
     ) -> RusotoFuture<CreateReplicationTaskResponse, CreateReplicationTaskError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.CreateReplicationTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateReplicationTaskResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateReplicationTaskError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_replication_task(input)
     }
-
-    /// <p>Deletes the specified certificate. </p>
     fn delete_certificate(
         &self,
         input: DeleteCertificateMessage,
     ) -> RusotoFuture<DeleteCertificateResponse, DeleteCertificateError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteCertificate");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteCertificateResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteCertificateError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_certificate(input)
     }
-
-    /// <p><p>Deletes the specified endpoint.</p> <note> <p>All tasks associated with the endpoint must be deleted before you can delete the endpoint.</p> </note> <p/></p>
     fn delete_endpoint(
         &self,
         input: DeleteEndpointMessage,
     ) -> RusotoFuture<DeleteEndpointResponse, DeleteEndpointError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteEndpointResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteEndpointError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_endpoint(input)
     }
-
-    /// <p> Deletes an AWS DMS event subscription. </p>
     fn delete_event_subscription(
         &self,
         input: DeleteEventSubscriptionMessage,
     ) -> RusotoFuture<DeleteEventSubscriptionResponse, DeleteEventSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEventSubscription");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteEventSubscriptionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteEventSubscriptionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_event_subscription(input)
     }
-
-    /// <p><p>Deletes the specified replication instance.</p> <note> <p>You must delete any migration tasks that are associated with the replication instance before you can delete it.</p> </note> <p/></p>
     fn delete_replication_instance(
         &self,
         input: DeleteReplicationInstanceMessage,
     ) -> RusotoFuture<DeleteReplicationInstanceResponse, DeleteReplicationInstanceError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DeleteReplicationInstance",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteReplicationInstanceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteReplicationInstanceError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_replication_instance(input)
     }
-
-    /// <p>Deletes a subnet group.</p>
     fn delete_replication_subnet_group(
         &self,
         input: DeleteReplicationSubnetGroupMessage,
     ) -> RusotoFuture<DeleteReplicationSubnetGroupResponse, DeleteReplicationSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DeleteReplicationSubnetGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteReplicationSubnetGroupResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteReplicationSubnetGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_replication_subnet_group(input)
     }
-
-    /// <p>Deletes the specified replication task.</p>
     fn delete_replication_task(
         &self,
         input: DeleteReplicationTaskMessage,
     ) -> RusotoFuture<DeleteReplicationTaskResponse, DeleteReplicationTaskError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteReplicationTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteReplicationTaskResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteReplicationTaskError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_replication_task(input)
     }
-
-    /// <p>Lists all of the AWS DMS attributes for a customer account. These attributes include AWS DMS quotas for the account and a unique account identifier in a particular DMS region. DMS quotas include a list of resource quotas supported by the account, such as the number of replication instances allowed. The description for each resource quota, includes the quota name, current usage toward that quota, and the quota's maximum value. DMS uses the unique account identifier to name each artifact used by DMS in the given region.</p> <p>This command does not take any parameters.</p>
     fn describe_account_attributes(
         &self,
     ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DescribeAccountAttributes",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAccountAttributesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAccountAttributesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_account_attributes()
     }
-
-    /// <p>Provides a description of the certificate.</p>
     fn describe_certificates(
         &self,
         input: DescribeCertificatesMessage,
     ) -> RusotoFuture<DescribeCertificatesResponse, DescribeCertificatesError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeCertificates");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCertificatesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeCertificatesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_certificates(input)
     }
-
-    /// <p>Describes the status of the connections that have been made between the replication instance and an endpoint. Connections are created when you test an endpoint.</p>
     fn describe_connections(
         &self,
         input: DescribeConnectionsMessage,
     ) -> RusotoFuture<DescribeConnectionsResponse, DescribeConnectionsError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeConnections");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeConnectionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeConnectionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_connections(input)
     }
-
-    /// <p>Returns information about the type of endpoints available.</p>
     fn describe_endpoint_types(
         &self,
         input: DescribeEndpointTypesMessage,
     ) -> RusotoFuture<DescribeEndpointTypesResponse, DescribeEndpointTypesError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpointTypes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEndpointTypesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeEndpointTypesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_endpoint_types(input)
     }
-
-    /// <p>Returns information about the endpoints for your account in the current region.</p>
     fn describe_endpoints(
         &self,
         input: DescribeEndpointsMessage,
     ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpoints");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEndpointsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeEndpointsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_endpoints(input)
     }
-
-    /// <p>Lists categories for all event source types, or, if specified, for a specified source type. You can see a list of the event categories and source types in <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration Service User Guide.</i> </p>
     fn describe_event_categories(
         &self,
         input: DescribeEventCategoriesMessage,
     ) -> RusotoFuture<DescribeEventCategoriesResponse, DescribeEventCategoriesError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEventCategories");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventCategoriesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEventCategoriesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_event_categories(input)
     }
-
-    /// <p>Lists all the event subscriptions for a customer account. The description of a subscription includes <code>SubscriptionName</code>, <code>SNSTopicARN</code>, <code>CustomerID</code>, <code>SourceType</code>, <code>SourceID</code>, <code>CreationTime</code>, and <code>Status</code>. </p> <p>If you specify <code>SubscriptionName</code>, this action lists the description for that subscription.</p>
     fn describe_event_subscriptions(
         &self,
         input: DescribeEventSubscriptionsMessage,
     ) -> RusotoFuture<DescribeEventSubscriptionsResponse, DescribeEventSubscriptionsError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DescribeEventSubscriptions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventSubscriptionsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEventSubscriptionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_event_subscriptions(input)
     }
-
-    /// <p> Lists events for a given source identifier and source type. You can also specify a start and end time. For more information on AWS DMS events, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration User Guide.</i> </p>
     fn describe_events(
         &self,
         input: DescribeEventsMessage,
     ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEvents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeEventsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_events(input)
     }
-
-    /// <p>Returns information about the replication instance types that can be created in the specified region.</p>
     fn describe_orderable_replication_instances(
         &self,
         input: DescribeOrderableReplicationInstancesMessage,
@@ -5846,33 +5616,8 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         DescribeOrderableReplicationInstancesResponse,
         DescribeOrderableReplicationInstancesError,
     > {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DescribeOrderableReplicationInstances",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeOrderableReplicationInstancesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeOrderableReplicationInstancesError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_orderable_replication_instances(input)
     }
-
-    /// <p>For internal use only</p>
     fn describe_pending_maintenance_actions(
         &self,
         input: DescribePendingMaintenanceActionsMessage,
@@ -5880,62 +5625,14 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         DescribePendingMaintenanceActionsResponse,
         DescribePendingMaintenanceActionsError,
     > {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DescribePendingMaintenanceActions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePendingMaintenanceActionsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribePendingMaintenanceActionsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_pending_maintenance_actions(input)
     }
-
-    /// <p>Returns the status of the RefreshSchemas operation.</p>
     fn describe_refresh_schemas_status(
         &self,
         input: DescribeRefreshSchemasStatusMessage,
     ) -> RusotoFuture<DescribeRefreshSchemasStatusResponse, DescribeRefreshSchemasStatusError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DescribeRefreshSchemasStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRefreshSchemasStatusResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeRefreshSchemasStatusError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_refresh_schemas_status(input)
     }
-
-    /// <p>Returns information about the task logs for the specified task.</p>
     fn describe_replication_instance_task_logs(
         &self,
         input: DescribeReplicationInstanceTaskLogsMessage,
@@ -5943,94 +5640,21 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         DescribeReplicationInstanceTaskLogsResponse,
         DescribeReplicationInstanceTaskLogsError,
     > {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DescribeReplicationInstanceTaskLogs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeReplicationInstanceTaskLogsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReplicationInstanceTaskLogsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_replication_instance_task_logs(input)
     }
-
-    /// <p>Returns information about replication instances for your account in the current region.</p>
     fn describe_replication_instances(
         &self,
         input: DescribeReplicationInstancesMessage,
     ) -> RusotoFuture<DescribeReplicationInstancesResponse, DescribeReplicationInstancesError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DescribeReplicationInstances",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeReplicationInstancesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReplicationInstancesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_replication_instances(input)
     }
-
-    /// <p>Returns information about the replication subnet groups.</p>
     fn describe_replication_subnet_groups(
         &self,
         input: DescribeReplicationSubnetGroupsMessage,
     ) -> RusotoFuture<DescribeReplicationSubnetGroupsResponse, DescribeReplicationSubnetGroupsError>
     {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DescribeReplicationSubnetGroups",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeReplicationSubnetGroupsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReplicationSubnetGroupsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_replication_subnet_groups(input)
     }
-
-    /// <p>Returns the task assessment results from Amazon S3. This action always returns the latest results.</p>
     fn describe_replication_task_assessment_results(
         &self,
         input: DescribeReplicationTaskAssessmentResultsMessage,
@@ -6038,539 +5662,3834 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         DescribeReplicationTaskAssessmentResultsResponse,
         DescribeReplicationTaskAssessmentResultsError,
     > {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DescribeReplicationTaskAssessmentResults",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeReplicationTaskAssessmentResultsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReplicationTaskAssessmentResultsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_replication_task_assessment_results(input)
     }
-
-    /// <p>Returns information about replication tasks for your account in the current region.</p>
     fn describe_replication_tasks(
         &self,
         input: DescribeReplicationTasksMessage,
     ) -> RusotoFuture<DescribeReplicationTasksResponse, DescribeReplicationTasksError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.DescribeReplicationTasks",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeReplicationTasksResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReplicationTasksError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_replication_tasks(input)
     }
-
-    /// <p><p>Returns information about the schema for the specified endpoint.</p> <p/></p>
     fn describe_schemas(
         &self,
         input: DescribeSchemasMessage,
     ) -> RusotoFuture<DescribeSchemasResponse, DescribeSchemasError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeSchemas");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSchemasResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeSchemasError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_schemas(input)
     }
-
-    /// <p>Returns table statistics on the database migration task, including table name, rows inserted, rows updated, and rows deleted.</p> <p>Note that the "last updated" column the DMS console only indicates the time that AWS DMS last updated the table statistics record for a table. It does not indicate the time of the last update to the table.</p>
     fn describe_table_statistics(
         &self,
         input: DescribeTableStatisticsMessage,
     ) -> RusotoFuture<DescribeTableStatisticsResponse, DescribeTableStatisticsError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeTableStatistics");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTableStatisticsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeTableStatisticsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_table_statistics(input)
     }
-
-    /// <p>Uploads the specified certificate.</p>
     fn import_certificate(
         &self,
         input: ImportCertificateMessage,
     ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.ImportCertificate");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportCertificateResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ImportCertificateError::from_response(response))),
-                )
-            }
-        })
+        (**self).import_certificate(input)
     }
-
-    /// <p>Lists all tags for an AWS DMS resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceMessage,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Modifies the specified endpoint.</p>
     fn modify_endpoint(
         &self,
         input: ModifyEndpointMessage,
     ) -> RusotoFuture<ModifyEndpointResponse, ModifyEndpointError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyEndpointResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ModifyEndpointError::from_response(response))),
-                )
-            }
-        })
+        (**self).modify_endpoint(input)
     }
-
-    /// <p>Modifies an existing AWS DMS event notification subscription. </p>
     fn modify_event_subscription(
         &self,
         input: ModifyEventSubscriptionMessage,
     ) -> RusotoFuture<ModifyEventSubscriptionResponse, ModifyEventSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEventSubscription");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyEventSubscriptionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyEventSubscriptionError::from_response(response))
-                }))
+        (**self).modify_event_subscription(input)
+    }
+    fn modify_replication_instance(
+        &self,
+        input: ModifyReplicationInstanceMessage,
+    ) -> RusotoFuture<ModifyReplicationInstanceResponse, ModifyReplicationInstanceError> {
+        (**self).modify_replication_instance(input)
+    }
+    fn modify_replication_subnet_group(
+        &self,
+        input: ModifyReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyReplicationSubnetGroupResponse, ModifyReplicationSubnetGroupError> {
+        (**self).modify_replication_subnet_group(input)
+    }
+    fn modify_replication_task(
+        &self,
+        input: ModifyReplicationTaskMessage,
+    ) -> RusotoFuture<ModifyReplicationTaskResponse, ModifyReplicationTaskError> {
+        (**self).modify_replication_task(input)
+    }
+    fn reboot_replication_instance(
+        &self,
+        input: RebootReplicationInstanceMessage,
+    ) -> RusotoFuture<RebootReplicationInstanceResponse, RebootReplicationInstanceError> {
+        (**self).reboot_replication_instance(input)
+    }
+    fn refresh_schemas(
+        &self,
+        input: RefreshSchemasMessage,
+    ) -> RusotoFuture<RefreshSchemasResponse, RefreshSchemasError> {
+        (**self).refresh_schemas(input)
+    }
+    fn reload_tables(
+        &self,
+        input: ReloadTablesMessage,
+    ) -> RusotoFuture<ReloadTablesResponse, ReloadTablesError> {
+        (**self).reload_tables(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn start_replication_task(
+        &self,
+        input: StartReplicationTaskMessage,
+    ) -> RusotoFuture<StartReplicationTaskResponse, StartReplicationTaskError> {
+        (**self).start_replication_task(input)
+    }
+    fn start_replication_task_assessment(
+        &self,
+        input: StartReplicationTaskAssessmentMessage,
+    ) -> RusotoFuture<StartReplicationTaskAssessmentResponse, StartReplicationTaskAssessmentError>
+    {
+        (**self).start_replication_task_assessment(input)
+    }
+    fn stop_replication_task(
+        &self,
+        input: StopReplicationTaskMessage,
+    ) -> RusotoFuture<StopReplicationTaskResponse, StopReplicationTaskError> {
+        (**self).stop_replication_task(input)
+    }
+    fn test_connection(
+        &self,
+        input: TestConnectionMessage,
+    ) -> RusotoFuture<TestConnectionResponse, TestConnectionError> {
+        (**self).test_connection(input)
+    }
+}
+impl<T: DatabaseMigrationService + ?Sized> DatabaseMigrationService for Box<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResponse, ApplyPendingMaintenanceActionError>
+    {
+        (**self).apply_pending_maintenance_action(input)
+    }
+    fn create_endpoint(
+        &self,
+        input: CreateEndpointMessage,
+    ) -> RusotoFuture<CreateEndpointResponse, CreateEndpointError> {
+        (**self).create_endpoint(input)
+    }
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResponse, CreateEventSubscriptionError> {
+        (**self).create_event_subscription(input)
+    }
+    fn create_replication_instance(
+        &self,
+        input: CreateReplicationInstanceMessage,
+    ) -> RusotoFuture<CreateReplicationInstanceResponse, CreateReplicationInstanceError> {
+        (**self).create_replication_instance(input)
+    }
+    fn create_replication_subnet_group(
+        &self,
+        input: CreateReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<CreateReplicationSubnetGroupResponse, CreateReplicationSubnetGroupError> {
+        (**self).create_replication_subnet_group(input)
+    }
+    fn create_replication_task(
+        &self,
+        input: CreateReplicationTaskMessage,
+    ) -> RusotoFuture<CreateReplicationTaskResponse, CreateReplicationTaskError> {
+        (**self).create_replication_task(input)
+    }
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateMessage,
+    ) -> RusotoFuture<DeleteCertificateResponse, DeleteCertificateError> {
+        (**self).delete_certificate(input)
+    }
+    fn delete_endpoint(
+        &self,
+        input: DeleteEndpointMessage,
+    ) -> RusotoFuture<DeleteEndpointResponse, DeleteEndpointError> {
+        (**self).delete_endpoint(input)
+    }
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<DeleteEventSubscriptionResponse, DeleteEventSubscriptionError> {
+        (**self).delete_event_subscription(input)
+    }
+    fn delete_replication_instance(
+        &self,
+        input: DeleteReplicationInstanceMessage,
+    ) -> RusotoFuture<DeleteReplicationInstanceResponse, DeleteReplicationInstanceError> {
+        (**self).delete_replication_instance(input)
+    }
+    fn delete_replication_subnet_group(
+        &self,
+        input: DeleteReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<DeleteReplicationSubnetGroupResponse, DeleteReplicationSubnetGroupError> {
+        (**self).delete_replication_subnet_group(input)
+    }
+    fn delete_replication_task(
+        &self,
+        input: DeleteReplicationTaskMessage,
+    ) -> RusotoFuture<DeleteReplicationTaskResponse, DeleteReplicationTaskError> {
+        (**self).delete_replication_task(input)
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes()
+    }
+    fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> RusotoFuture<DescribeCertificatesResponse, DescribeCertificatesError> {
+        (**self).describe_certificates(input)
+    }
+    fn describe_connections(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> RusotoFuture<DescribeConnectionsResponse, DescribeConnectionsError> {
+        (**self).describe_connections(input)
+    }
+    fn describe_endpoint_types(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> RusotoFuture<DescribeEndpointTypesResponse, DescribeEndpointTypesError> {
+        (**self).describe_endpoint_types(input)
+    }
+    fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        (**self).describe_endpoints(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<DescribeEventCategoriesResponse, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<DescribeEventSubscriptionsResponse, DescribeEventSubscriptionsError> {
+        (**self).describe_event_subscriptions(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_orderable_replication_instances(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> RusotoFuture<
+        DescribeOrderableReplicationInstancesResponse,
+        DescribeOrderableReplicationInstancesError,
+    > {
+        (**self).describe_orderable_replication_instances(input)
+    }
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<
+        DescribePendingMaintenanceActionsResponse,
+        DescribePendingMaintenanceActionsError,
+    > {
+        (**self).describe_pending_maintenance_actions(input)
+    }
+    fn describe_refresh_schemas_status(
+        &self,
+        input: DescribeRefreshSchemasStatusMessage,
+    ) -> RusotoFuture<DescribeRefreshSchemasStatusResponse, DescribeRefreshSchemasStatusError> {
+        (**self).describe_refresh_schemas_status(input)
+    }
+    fn describe_replication_instance_task_logs(
+        &self,
+        input: DescribeReplicationInstanceTaskLogsMessage,
+    ) -> RusotoFuture<
+        DescribeReplicationInstanceTaskLogsResponse,
+        DescribeReplicationInstanceTaskLogsError,
+    > {
+        (**self).describe_replication_instance_task_logs(input)
+    }
+    fn describe_replication_instances(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> RusotoFuture<DescribeReplicationInstancesResponse, DescribeReplicationInstancesError> {
+        (**self).describe_replication_instances(input)
+    }
+    fn describe_replication_subnet_groups(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> RusotoFuture<DescribeReplicationSubnetGroupsResponse, DescribeReplicationSubnetGroupsError>
+    {
+        (**self).describe_replication_subnet_groups(input)
+    }
+    fn describe_replication_task_assessment_results(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> RusotoFuture<
+        DescribeReplicationTaskAssessmentResultsResponse,
+        DescribeReplicationTaskAssessmentResultsError,
+    > {
+        (**self).describe_replication_task_assessment_results(input)
+    }
+    fn describe_replication_tasks(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> RusotoFuture<DescribeReplicationTasksResponse, DescribeReplicationTasksError> {
+        (**self).describe_replication_tasks(input)
+    }
+    fn describe_schemas(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> RusotoFuture<DescribeSchemasResponse, DescribeSchemasError> {
+        (**self).describe_schemas(input)
+    }
+    fn describe_table_statistics(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> RusotoFuture<DescribeTableStatisticsResponse, DescribeTableStatisticsError> {
+        (**self).describe_table_statistics(input)
+    }
+    fn import_certificate(
+        &self,
+        input: ImportCertificateMessage,
+    ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        (**self).import_certificate(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_endpoint(
+        &self,
+        input: ModifyEndpointMessage,
+    ) -> RusotoFuture<ModifyEndpointResponse, ModifyEndpointError> {
+        (**self).modify_endpoint(input)
+    }
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResponse, ModifyEventSubscriptionError> {
+        (**self).modify_event_subscription(input)
+    }
+    fn modify_replication_instance(
+        &self,
+        input: ModifyReplicationInstanceMessage,
+    ) -> RusotoFuture<ModifyReplicationInstanceResponse, ModifyReplicationInstanceError> {
+        (**self).modify_replication_instance(input)
+    }
+    fn modify_replication_subnet_group(
+        &self,
+        input: ModifyReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyReplicationSubnetGroupResponse, ModifyReplicationSubnetGroupError> {
+        (**self).modify_replication_subnet_group(input)
+    }
+    fn modify_replication_task(
+        &self,
+        input: ModifyReplicationTaskMessage,
+    ) -> RusotoFuture<ModifyReplicationTaskResponse, ModifyReplicationTaskError> {
+        (**self).modify_replication_task(input)
+    }
+    fn reboot_replication_instance(
+        &self,
+        input: RebootReplicationInstanceMessage,
+    ) -> RusotoFuture<RebootReplicationInstanceResponse, RebootReplicationInstanceError> {
+        (**self).reboot_replication_instance(input)
+    }
+    fn refresh_schemas(
+        &self,
+        input: RefreshSchemasMessage,
+    ) -> RusotoFuture<RefreshSchemasResponse, RefreshSchemasError> {
+        (**self).refresh_schemas(input)
+    }
+    fn reload_tables(
+        &self,
+        input: ReloadTablesMessage,
+    ) -> RusotoFuture<ReloadTablesResponse, ReloadTablesError> {
+        (**self).reload_tables(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn start_replication_task(
+        &self,
+        input: StartReplicationTaskMessage,
+    ) -> RusotoFuture<StartReplicationTaskResponse, StartReplicationTaskError> {
+        (**self).start_replication_task(input)
+    }
+    fn start_replication_task_assessment(
+        &self,
+        input: StartReplicationTaskAssessmentMessage,
+    ) -> RusotoFuture<StartReplicationTaskAssessmentResponse, StartReplicationTaskAssessmentError>
+    {
+        (**self).start_replication_task_assessment(input)
+    }
+    fn stop_replication_task(
+        &self,
+        input: StopReplicationTaskMessage,
+    ) -> RusotoFuture<StopReplicationTaskResponse, StopReplicationTaskError> {
+        (**self).stop_replication_task(input)
+    }
+    fn test_connection(
+        &self,
+        input: TestConnectionMessage,
+    ) -> RusotoFuture<TestConnectionResponse, TestConnectionError> {
+        (**self).test_connection(input)
+    }
+}
+impl<T: DatabaseMigrationService + ?Sized> DatabaseMigrationService for ::std::sync::Arc<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResponse, ApplyPendingMaintenanceActionError>
+    {
+        (**self).apply_pending_maintenance_action(input)
+    }
+    fn create_endpoint(
+        &self,
+        input: CreateEndpointMessage,
+    ) -> RusotoFuture<CreateEndpointResponse, CreateEndpointError> {
+        (**self).create_endpoint(input)
+    }
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResponse, CreateEventSubscriptionError> {
+        (**self).create_event_subscription(input)
+    }
+    fn create_replication_instance(
+        &self,
+        input: CreateReplicationInstanceMessage,
+    ) -> RusotoFuture<CreateReplicationInstanceResponse, CreateReplicationInstanceError> {
+        (**self).create_replication_instance(input)
+    }
+    fn create_replication_subnet_group(
+        &self,
+        input: CreateReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<CreateReplicationSubnetGroupResponse, CreateReplicationSubnetGroupError> {
+        (**self).create_replication_subnet_group(input)
+    }
+    fn create_replication_task(
+        &self,
+        input: CreateReplicationTaskMessage,
+    ) -> RusotoFuture<CreateReplicationTaskResponse, CreateReplicationTaskError> {
+        (**self).create_replication_task(input)
+    }
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateMessage,
+    ) -> RusotoFuture<DeleteCertificateResponse, DeleteCertificateError> {
+        (**self).delete_certificate(input)
+    }
+    fn delete_endpoint(
+        &self,
+        input: DeleteEndpointMessage,
+    ) -> RusotoFuture<DeleteEndpointResponse, DeleteEndpointError> {
+        (**self).delete_endpoint(input)
+    }
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<DeleteEventSubscriptionResponse, DeleteEventSubscriptionError> {
+        (**self).delete_event_subscription(input)
+    }
+    fn delete_replication_instance(
+        &self,
+        input: DeleteReplicationInstanceMessage,
+    ) -> RusotoFuture<DeleteReplicationInstanceResponse, DeleteReplicationInstanceError> {
+        (**self).delete_replication_instance(input)
+    }
+    fn delete_replication_subnet_group(
+        &self,
+        input: DeleteReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<DeleteReplicationSubnetGroupResponse, DeleteReplicationSubnetGroupError> {
+        (**self).delete_replication_subnet_group(input)
+    }
+    fn delete_replication_task(
+        &self,
+        input: DeleteReplicationTaskMessage,
+    ) -> RusotoFuture<DeleteReplicationTaskResponse, DeleteReplicationTaskError> {
+        (**self).delete_replication_task(input)
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes()
+    }
+    fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> RusotoFuture<DescribeCertificatesResponse, DescribeCertificatesError> {
+        (**self).describe_certificates(input)
+    }
+    fn describe_connections(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> RusotoFuture<DescribeConnectionsResponse, DescribeConnectionsError> {
+        (**self).describe_connections(input)
+    }
+    fn describe_endpoint_types(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> RusotoFuture<DescribeEndpointTypesResponse, DescribeEndpointTypesError> {
+        (**self).describe_endpoint_types(input)
+    }
+    fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        (**self).describe_endpoints(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<DescribeEventCategoriesResponse, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<DescribeEventSubscriptionsResponse, DescribeEventSubscriptionsError> {
+        (**self).describe_event_subscriptions(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_orderable_replication_instances(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> RusotoFuture<
+        DescribeOrderableReplicationInstancesResponse,
+        DescribeOrderableReplicationInstancesError,
+    > {
+        (**self).describe_orderable_replication_instances(input)
+    }
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<
+        DescribePendingMaintenanceActionsResponse,
+        DescribePendingMaintenanceActionsError,
+    > {
+        (**self).describe_pending_maintenance_actions(input)
+    }
+    fn describe_refresh_schemas_status(
+        &self,
+        input: DescribeRefreshSchemasStatusMessage,
+    ) -> RusotoFuture<DescribeRefreshSchemasStatusResponse, DescribeRefreshSchemasStatusError> {
+        (**self).describe_refresh_schemas_status(input)
+    }
+    fn describe_replication_instance_task_logs(
+        &self,
+        input: DescribeReplicationInstanceTaskLogsMessage,
+    ) -> RusotoFuture<
+        DescribeReplicationInstanceTaskLogsResponse,
+        DescribeReplicationInstanceTaskLogsError,
+    > {
+        (**self).describe_replication_instance_task_logs(input)
+    }
+    fn describe_replication_instances(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> RusotoFuture<DescribeReplicationInstancesResponse, DescribeReplicationInstancesError> {
+        (**self).describe_replication_instances(input)
+    }
+    fn describe_replication_subnet_groups(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> RusotoFuture<DescribeReplicationSubnetGroupsResponse, DescribeReplicationSubnetGroupsError>
+    {
+        (**self).describe_replication_subnet_groups(input)
+    }
+    fn describe_replication_task_assessment_results(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> RusotoFuture<
+        DescribeReplicationTaskAssessmentResultsResponse,
+        DescribeReplicationTaskAssessmentResultsError,
+    > {
+        (**self).describe_replication_task_assessment_results(input)
+    }
+    fn describe_replication_tasks(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> RusotoFuture<DescribeReplicationTasksResponse, DescribeReplicationTasksError> {
+        (**self).describe_replication_tasks(input)
+    }
+    fn describe_schemas(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> RusotoFuture<DescribeSchemasResponse, DescribeSchemasError> {
+        (**self).describe_schemas(input)
+    }
+    fn describe_table_statistics(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> RusotoFuture<DescribeTableStatisticsResponse, DescribeTableStatisticsError> {
+        (**self).describe_table_statistics(input)
+    }
+    fn import_certificate(
+        &self,
+        input: ImportCertificateMessage,
+    ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        (**self).import_certificate(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_endpoint(
+        &self,
+        input: ModifyEndpointMessage,
+    ) -> RusotoFuture<ModifyEndpointResponse, ModifyEndpointError> {
+        (**self).modify_endpoint(input)
+    }
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResponse, ModifyEventSubscriptionError> {
+        (**self).modify_event_subscription(input)
+    }
+    fn modify_replication_instance(
+        &self,
+        input: ModifyReplicationInstanceMessage,
+    ) -> RusotoFuture<ModifyReplicationInstanceResponse, ModifyReplicationInstanceError> {
+        (**self).modify_replication_instance(input)
+    }
+    fn modify_replication_subnet_group(
+        &self,
+        input: ModifyReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyReplicationSubnetGroupResponse, ModifyReplicationSubnetGroupError> {
+        (**self).modify_replication_subnet_group(input)
+    }
+    fn modify_replication_task(
+        &self,
+        input: ModifyReplicationTaskMessage,
+    ) -> RusotoFuture<ModifyReplicationTaskResponse, ModifyReplicationTaskError> {
+        (**self).modify_replication_task(input)
+    }
+    fn reboot_replication_instance(
+        &self,
+        input: RebootReplicationInstanceMessage,
+    ) -> RusotoFuture<RebootReplicationInstanceResponse, RebootReplicationInstanceError> {
+        (**self).reboot_replication_instance(input)
+    }
+    fn refresh_schemas(
+        &self,
+        input: RefreshSchemasMessage,
+    ) -> RusotoFuture<RefreshSchemasResponse, RefreshSchemasError> {
+        (**self).refresh_schemas(input)
+    }
+    fn reload_tables(
+        &self,
+        input: ReloadTablesMessage,
+    ) -> RusotoFuture<ReloadTablesResponse, ReloadTablesError> {
+        (**self).reload_tables(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn start_replication_task(
+        &self,
+        input: StartReplicationTaskMessage,
+    ) -> RusotoFuture<StartReplicationTaskResponse, StartReplicationTaskError> {
+        (**self).start_replication_task(input)
+    }
+    fn start_replication_task_assessment(
+        &self,
+        input: StartReplicationTaskAssessmentMessage,
+    ) -> RusotoFuture<StartReplicationTaskAssessmentResponse, StartReplicationTaskAssessmentError>
+    {
+        (**self).start_replication_task_assessment(input)
+    }
+    fn stop_replication_task(
+        &self,
+        input: StopReplicationTaskMessage,
+    ) -> RusotoFuture<StopReplicationTaskResponse, StopReplicationTaskError> {
+        (**self).stop_replication_task(input)
+    }
+    fn test_connection(
+        &self,
+        input: TestConnectionMessage,
+    ) -> RusotoFuture<TestConnectionResponse, TestConnectionError> {
+        (**self).test_connection(input)
+    }
+}
+/// A client for the AWS Database Migration Service API.
+#[derive(Clone)]
+pub struct DatabaseMigrationServiceClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl DatabaseMigrationServiceClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DatabaseMigrationServiceClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DatabaseMigrationServiceClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> DatabaseMigrationServiceClient {
+        DatabaseMigrationServiceClient { client, region }
+    }
+}
+
+impl DatabaseMigrationService for DatabaseMigrationServiceClient {
+    /// <p>Adds metadata tags to an AWS DMS resource, including replication instance, endpoint, security group, and migration task. These tags can also be used with cost allocation reporting to track cost associated with DMS resources, or used in a Condition statement in an IAM policy for DMS.</p>
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("AddTagsToResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.AddTagsToResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddTagsToResourceResponse, _>(
+                    response,
+                    "AddTagsToResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Applies a pending maintenance action to a resource (for example, to a replication instance).</p>
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResponse, ApplyPendingMaintenanceActionError>
+    {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.ApplyPendingMaintenanceAction",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ApplyPendingMaintenanceActionResponse, _>(
+                    response,
+                    "ApplyPendingMaintenanceAction",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ApplyPendingMaintenanceActionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates an endpoint using the provided settings.</p>
+    fn create_endpoint(
+        &self,
+        input: CreateEndpointMessage,
+    ) -> RusotoFuture<CreateEndpointResponse, CreateEndpointError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateEndpoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateEndpointResponse, _>(
+                    response,
+                    "CreateEndpoint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Creates an AWS DMS event notification subscription. </p> <p>You can specify the type of source (<code>SourceType</code>) you want to be notified of, provide a list of AWS DMS source IDs (<code>SourceIds</code>) that triggers the events, and provide a list of event categories (<code>EventCategories</code>) for events you want to be notified of. If you specify both the <code>SourceType</code> and <code>SourceIds</code>, such as <code>SourceType = replication-instance</code> and <code>SourceIdentifier = my-replinstance</code>, you will be notified of all the replication instance events for the specified source. If you specify a <code>SourceType</code> but don't specify a <code>SourceIdentifier</code>, you receive notice of the events for that source type for all your AWS DMS sources. If you don't specify either <code>SourceType</code> nor <code>SourceIdentifier</code>, you will be notified of events generated from all AWS DMS sources belonging to your customer account.</p> <p>For more information about AWS DMS events, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration Service User Guide.</i> </p>
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResponse, CreateEventSubscriptionError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEventSubscription");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateEventSubscriptionResponse, _>(
+                    response,
+                    "CreateEventSubscription",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateEventSubscriptionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates the replication instance using the specified parameters.</p>
+    fn create_replication_instance(
+        &self,
+        input: CreateReplicationInstanceMessage,
+    ) -> RusotoFuture<CreateReplicationInstanceResponse, CreateReplicationInstanceError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateReplicationInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.CreateReplicationInstance",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateReplicationInstanceResponse, _>(
+                    response,
+                    "CreateReplicationInstance",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateReplicationInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a replication subnet group given a list of the subnet IDs in a VPC.</p>
+    fn create_replication_subnet_group(
+        &self,
+        input: CreateReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<CreateReplicationSubnetGroupResponse, CreateReplicationSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateReplicationSubnetGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.CreateReplicationSubnetGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateReplicationSubnetGroupResponse, _>(
+                    response,
+                    "CreateReplicationSubnetGroup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateReplicationSubnetGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a replication task using the specified parameters.</p>
+    fn create_replication_task(
+        &self,
+        input: CreateReplicationTaskMessage,
+    ) -> RusotoFuture<CreateReplicationTaskResponse, CreateReplicationTaskError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateReplicationTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.CreateReplicationTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateReplicationTaskResponse, _>(
+                    response,
+                    "CreateReplicationTask",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateReplicationTaskError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified certificate. </p>
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateMessage,
+    ) -> RusotoFuture<DeleteCertificateResponse, DeleteCertificateError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteCertificate");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteCertificate");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteCertificateResponse, _>(
+                    response,
+                    "DeleteCertificate",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteCertificateError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes the specified endpoint.</p> <note> <p>All tasks associated with the endpoint must be deleted before you can delete the endpoint.</p> </note> <p/></p>
+    fn delete_endpoint(
+        &self,
+        input: DeleteEndpointMessage,
+    ) -> RusotoFuture<DeleteEndpointResponse, DeleteEndpointError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteEndpointResponse, _>(
+                    response,
+                    "DeleteEndpoint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Deletes an AWS DMS event subscription. </p>
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<DeleteEventSubscriptionResponse, DeleteEventSubscriptionError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEventSubscription");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteEventSubscriptionResponse, _>(
+                    response,
+                    "DeleteEventSubscription",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteEventSubscriptionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes the specified replication instance.</p> <note> <p>You must delete any migration tasks that are associated with the replication instance before you can delete it.</p> </note> <p/></p>
+    fn delete_replication_instance(
+        &self,
+        input: DeleteReplicationInstanceMessage,
+    ) -> RusotoFuture<DeleteReplicationInstanceResponse, DeleteReplicationInstanceError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteReplicationInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DeleteReplicationInstance",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteReplicationInstanceResponse, _>(
+                    response,
+                    "DeleteReplicationInstance",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteReplicationInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a subnet group.</p>
+    fn delete_replication_subnet_group(
+        &self,
+        input: DeleteReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<DeleteReplicationSubnetGroupResponse, DeleteReplicationSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteReplicationSubnetGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DeleteReplicationSubnetGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteReplicationSubnetGroupResponse, _>(
+                    response,
+                    "DeleteReplicationSubnetGroup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteReplicationSubnetGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified replication task.</p>
+    fn delete_replication_task(
+        &self,
+        input: DeleteReplicationTaskMessage,
+    ) -> RusotoFuture<DeleteReplicationTaskResponse, DeleteReplicationTaskError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteReplicationTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteReplicationTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteReplicationTaskResponse, _>(
+                    response,
+                    "DeleteReplicationTask",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteReplicationTaskError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all of the AWS DMS attributes for a customer account. These attributes include AWS DMS quotas for the account and a unique account identifier in a particular DMS region. DMS quotas include a list of resource quotas supported by the account, such as the number of replication instances allowed. The description for each resource quota, includes the quota name, current usage toward that quota, and the quota's maximum value. DMS uses the unique account identifier to name each artifact used by DMS in the given region.</p> <p>This command does not take any parameters.</p>
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DescribeAccountAttributes",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAccountAttributesResponse, _>(
+                    response,
+                    "DescribeAccountAttributes",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAccountAttributesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides a description of the certificate.</p>
+    fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> RusotoFuture<DescribeCertificatesResponse, DescribeCertificatesError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeCertificates");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeCertificates");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCertificatesResponse, _>(
+                    response,
+                    "DescribeCertificates",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeCertificatesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the status of the connections that have been made between the replication instance and an endpoint. Connections are created when you test an endpoint.</p>
+    fn describe_connections(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> RusotoFuture<DescribeConnectionsResponse, DescribeConnectionsError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeConnections");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeConnections");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeConnectionsResponse, _>(
+                    response,
+                    "DescribeConnections",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeConnectionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the type of endpoints available.</p>
+    fn describe_endpoint_types(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> RusotoFuture<DescribeEndpointTypesResponse, DescribeEndpointTypesError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEndpointTypes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpointTypes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEndpointTypesResponse, _>(
+                    response,
+                    "DescribeEndpointTypes",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeEndpointTypesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the endpoints for your account in the current region.</p>
+    fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEndpoints");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpoints");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEndpointsResponse, _>(
+                    response,
+                    "DescribeEndpoints",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeEndpointsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists categories for all event source types, or, if specified, for a specified source type. You can see a list of the event categories and source types in <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration Service User Guide.</i> </p>
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<DescribeEventCategoriesResponse, DescribeEventCategoriesError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEventCategories");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEventCategoriesResponse, _>(
+                    response,
+                    "DescribeEventCategories",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEventCategoriesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all the event subscriptions for a customer account. The description of a subscription includes <code>SubscriptionName</code>, <code>SNSTopicARN</code>, <code>CustomerID</code>, <code>SourceType</code>, <code>SourceID</code>, <code>CreationTime</code>, and <code>Status</code>. </p> <p>If you specify <code>SubscriptionName</code>, this action lists the description for that subscription.</p>
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<DescribeEventSubscriptionsResponse, DescribeEventSubscriptionsError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DescribeEventSubscriptions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEventSubscriptionsResponse, _>(
+                    response,
+                    "DescribeEventSubscriptions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEventSubscriptionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> Lists events for a given source identifier and source type. You can also specify a start and end time. For more information on AWS DMS events, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration User Guide.</i> </p>
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEvents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEventsResponse, _>(
+                    response,
+                    "DescribeEvents",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeEventsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the replication instance types that can be created in the specified region.</p>
+    fn describe_orderable_replication_instances(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> RusotoFuture<
+        DescribeOrderableReplicationInstancesResponse,
+        DescribeOrderableReplicationInstancesError,
+    > {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeOrderableReplicationInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DescribeOrderableReplicationInstances",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeOrderableReplicationInstancesResponse, _>(response, "DescribeOrderableReplicationInstances")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeOrderableReplicationInstancesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>For internal use only</p>
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<
+        DescribePendingMaintenanceActionsResponse,
+        DescribePendingMaintenanceActionsError,
+    > {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DescribePendingMaintenanceActions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePendingMaintenanceActionsResponse, _>(
+                    response,
+                    "DescribePendingMaintenanceActions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribePendingMaintenanceActionsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the status of the RefreshSchemas operation.</p>
+    fn describe_refresh_schemas_status(
+        &self,
+        input: DescribeRefreshSchemasStatusMessage,
+    ) -> RusotoFuture<DescribeRefreshSchemasStatusResponse, DescribeRefreshSchemasStatusError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeRefreshSchemasStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DescribeRefreshSchemasStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRefreshSchemasStatusResponse, _>(
+                    response,
+                    "DescribeRefreshSchemasStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeRefreshSchemasStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about the task logs for the specified task.</p>
+    fn describe_replication_instance_task_logs(
+        &self,
+        input: DescribeReplicationInstanceTaskLogsMessage,
+    ) -> RusotoFuture<
+        DescribeReplicationInstanceTaskLogsResponse,
+        DescribeReplicationInstanceTaskLogsError,
+    > {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationInstanceTaskLogs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DescribeReplicationInstanceTaskLogs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeReplicationInstanceTaskLogsResponse, _>(response, "DescribeReplicationInstanceTaskLogs")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReplicationInstanceTaskLogsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about replication instances for your account in the current region.</p>
+    fn describe_replication_instances(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> RusotoFuture<DescribeReplicationInstancesResponse, DescribeReplicationInstancesError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DescribeReplicationInstances",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeReplicationInstancesResponse, _>(
+                    response,
+                    "DescribeReplicationInstances",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReplicationInstancesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about the replication subnet groups.</p>
+    fn describe_replication_subnet_groups(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> RusotoFuture<DescribeReplicationSubnetGroupsResponse, DescribeReplicationSubnetGroupsError>
+    {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationSubnetGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DescribeReplicationSubnetGroups",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeReplicationSubnetGroupsResponse, _>(
+                    response,
+                    "DescribeReplicationSubnetGroups",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReplicationSubnetGroupsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the task assessment results from Amazon S3. This action always returns the latest results.</p>
+    fn describe_replication_task_assessment_results(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> RusotoFuture<
+        DescribeReplicationTaskAssessmentResultsResponse,
+        DescribeReplicationTaskAssessmentResultsError,
+    > {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationTaskAssessmentResults");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DescribeReplicationTaskAssessmentResults",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DescribeReplicationTaskAssessmentResultsResponse,
+                    _,
+                >(response, "DescribeReplicationTaskAssessmentResults")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReplicationTaskAssessmentResultsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about replication tasks for your account in the current region.</p>
+    fn describe_replication_tasks(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> RusotoFuture<DescribeReplicationTasksResponse, DescribeReplicationTasksError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationTasks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.DescribeReplicationTasks",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeReplicationTasksResponse, _>(
+                    response,
+                    "DescribeReplicationTasks",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReplicationTasksError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns information about the schema for the specified endpoint.</p> <p/></p>
+    fn describe_schemas(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> RusotoFuture<DescribeSchemasResponse, DescribeSchemasError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeSchemas");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeSchemas");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSchemasResponse, _>(
+                    response,
+                    "DescribeSchemas",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeSchemasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns table statistics on the database migration task, including table name, rows inserted, rows updated, and rows deleted.</p> <p>Note that the "last updated" column the DMS console only indicates the time that AWS DMS last updated the table statistics record for a table. It does not indicate the time of the last update to the table.</p>
+    fn describe_table_statistics(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> RusotoFuture<DescribeTableStatisticsResponse, DescribeTableStatisticsError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeTableStatistics");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeTableStatistics");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTableStatisticsResponse, _>(
+                    response,
+                    "DescribeTableStatistics",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeTableStatisticsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Uploads the specified certificate.</p>
+    fn import_certificate(
+        &self,
+        input: ImportCertificateMessage,
+    ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ImportCertificate");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.ImportCertificate");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ImportCertificateResponse, _>(
+                    response,
+                    "ImportCertificate",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ImportCertificateError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all tags for an AWS DMS resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies the specified endpoint.</p>
+    fn modify_endpoint(
+        &self,
+        input: ModifyEndpointMessage,
+    ) -> RusotoFuture<ModifyEndpointResponse, ModifyEndpointError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyEndpoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ModifyEndpointResponse, _>(
+                    response,
+                    "ModifyEndpoint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ModifyEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies an existing AWS DMS event notification subscription. </p>
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResponse, ModifyEventSubscriptionError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEventSubscription");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ModifyEventSubscriptionResponse, _>(
+                    response,
+                    "ModifyEventSubscription",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyEventSubscriptionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Modifies the replication instance to apply new settings. You can change one or more parameters by specifying these parameters and the new values in the request.</p> <p>Some settings are applied during the maintenance window.</p> <p/></p>
+    fn modify_replication_instance(
+        &self,
+        input: ModifyReplicationInstanceMessage,
+    ) -> RusotoFuture<ModifyReplicationInstanceResponse, ModifyReplicationInstanceError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyReplicationInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.ModifyReplicationInstance",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ModifyReplicationInstanceResponse, _>(
+                    response,
+                    "ModifyReplicationInstance",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyReplicationInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Modifies the settings for the specified replication subnet group.</p>
+    fn modify_replication_subnet_group(
+        &self,
+        input: ModifyReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyReplicationSubnetGroupResponse, ModifyReplicationSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyReplicationSubnetGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.ModifyReplicationSubnetGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ModifyReplicationSubnetGroupResponse, _>(
+                    response,
+                    "ModifyReplicationSubnetGroup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyReplicationSubnetGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Modifies the specified replication task.</p> <p>You can't modify the task endpoints. The task must be stopped before you can modify it. </p> <p>For more information about AWS DMS tasks, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Tasks.html">Working with Migration Tasks</a> in the <i>AWS Database Migration Service User Guide</i>.</p>
+    fn modify_replication_task(
+        &self,
+        input: ModifyReplicationTaskMessage,
+    ) -> RusotoFuture<ModifyReplicationTaskResponse, ModifyReplicationTaskError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyReplicationTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyReplicationTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ModifyReplicationTaskResponse, _>(
+                    response,
+                    "ModifyReplicationTask",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ModifyReplicationTaskError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Reboots a replication instance. Rebooting results in a momentary outage, until the replication instance becomes available again.</p>
+    fn reboot_replication_instance(
+        &self,
+        input: RebootReplicationInstanceMessage,
+    ) -> RusotoFuture<RebootReplicationInstanceResponse, RebootReplicationInstanceError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("RebootReplicationInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.RebootReplicationInstance",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RebootReplicationInstanceResponse, _>(
+                    response,
+                    "RebootReplicationInstance",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RebootReplicationInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Populates the schema for the specified endpoint. This is an asynchronous operation and can take several minutes. You can check the status of this operation by calling the DescribeRefreshSchemasStatus operation.</p>
+    fn refresh_schemas(
+        &self,
+        input: RefreshSchemasMessage,
+    ) -> RusotoFuture<RefreshSchemasResponse, RefreshSchemasError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("RefreshSchemas");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.RefreshSchemas");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RefreshSchemasResponse, _>(
+                    response,
+                    "RefreshSchemas",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RefreshSchemasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Reloads the target database table with the source data. </p>
+    fn reload_tables(
+        &self,
+        input: ReloadTablesMessage,
+    ) -> RusotoFuture<ReloadTablesResponse, ReloadTablesError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ReloadTables");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.ReloadTables");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ReloadTablesResponse, _>(
+                    response,
+                    "ReloadTables",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ReloadTablesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes metadata tags from a DMS resource.</p>
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.RemoveTagsFromResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RemoveTagsFromResourceResponse, _>(
+                    response,
+                    "RemoveTagsFromResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RemoveTagsFromResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts the replication task.</p> <p>For more information about AWS DMS tasks, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Tasks.html">Working with Migration Tasks </a> in the <i>AWS Database Migration Service User Guide.</i> </p>
+    fn start_replication_task(
+        &self,
+        input: StartReplicationTaskMessage,
+    ) -> RusotoFuture<StartReplicationTaskResponse, StartReplicationTaskError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("StartReplicationTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.StartReplicationTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartReplicationTaskResponse, _>(
+                    response,
+                    "StartReplicationTask",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartReplicationTaskError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> Starts the replication task assessment for unsupported data types in the source database. </p>
+    fn start_replication_task_assessment(
+        &self,
+        input: StartReplicationTaskAssessmentMessage,
+    ) -> RusotoFuture<StartReplicationTaskAssessmentResponse, StartReplicationTaskAssessmentError>
+    {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("StartReplicationTaskAssessment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonDMSv20160101.StartReplicationTaskAssessment",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartReplicationTaskAssessmentResponse, _>(
+                    response,
+                    "StartReplicationTaskAssessment",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartReplicationTaskAssessmentError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Stops the replication task.</p> <p/></p>
+    fn stop_replication_task(
+        &self,
+        input: StopReplicationTaskMessage,
+    ) -> RusotoFuture<StopReplicationTaskResponse, StopReplicationTaskError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("StopReplicationTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.StopReplicationTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopReplicationTaskResponse, _>(
+                    response,
+                    "StopReplicationTask",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StopReplicationTaskError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Tests the connection between the replication instance and the endpoint.</p>
+    fn test_connection(
+        &self,
+        input: TestConnectionMessage,
+    ) -> RusotoFuture<TestConnectionResponse, TestConnectionError> {
+        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("TestConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonDMSv20160101.TestConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TestConnectionResponse, _>(
+                    response,
+                    "TestConnection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TestConnectionError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the AWS Database Migration Service API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockDatabaseMigrationService {
+    add_tags_to_resource: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToResourceMessage,
+                ) -> ::std::result::Result<
+                    AddTagsToResourceResponse,
+                    RusotoError<AddTagsToResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    apply_pending_maintenance_action: Option<
+        Box<
+            dyn Fn(
+                    ApplyPendingMaintenanceActionMessage,
+                ) -> ::std::result::Result<
+                    ApplyPendingMaintenanceActionResponse,
+                    RusotoError<ApplyPendingMaintenanceActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_endpoint: Option<
+        Box<
+            dyn Fn(
+                    CreateEndpointMessage,
+                ) -> ::std::result::Result<
+                    CreateEndpointResponse,
+                    RusotoError<CreateEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_event_subscription: Option<
+        Box<
+            dyn Fn(
+                    CreateEventSubscriptionMessage,
+                ) -> ::std::result::Result<
+                    CreateEventSubscriptionResponse,
+                    RusotoError<CreateEventSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_replication_instance: Option<
+        Box<
+            dyn Fn(
+                    CreateReplicationInstanceMessage,
+                ) -> ::std::result::Result<
+                    CreateReplicationInstanceResponse,
+                    RusotoError<CreateReplicationInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_replication_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    CreateReplicationSubnetGroupMessage,
+                ) -> ::std::result::Result<
+                    CreateReplicationSubnetGroupResponse,
+                    RusotoError<CreateReplicationSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_replication_task: Option<
+        Box<
+            dyn Fn(
+                    CreateReplicationTaskMessage,
+                ) -> ::std::result::Result<
+                    CreateReplicationTaskResponse,
+                    RusotoError<CreateReplicationTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_certificate: Option<
+        Box<
+            dyn Fn(
+                    DeleteCertificateMessage,
+                ) -> ::std::result::Result<
+                    DeleteCertificateResponse,
+                    RusotoError<DeleteCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_endpoint: Option<
+        Box<
+            dyn Fn(
+                    DeleteEndpointMessage,
+                ) -> ::std::result::Result<
+                    DeleteEndpointResponse,
+                    RusotoError<DeleteEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_event_subscription: Option<
+        Box<
+            dyn Fn(
+                    DeleteEventSubscriptionMessage,
+                ) -> ::std::result::Result<
+                    DeleteEventSubscriptionResponse,
+                    RusotoError<DeleteEventSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_replication_instance: Option<
+        Box<
+            dyn Fn(
+                    DeleteReplicationInstanceMessage,
+                ) -> ::std::result::Result<
+                    DeleteReplicationInstanceResponse,
+                    RusotoError<DeleteReplicationInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_replication_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteReplicationSubnetGroupMessage,
+                ) -> ::std::result::Result<
+                    DeleteReplicationSubnetGroupResponse,
+                    RusotoError<DeleteReplicationSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_replication_task: Option<
+        Box<
+            dyn Fn(
+                    DeleteReplicationTaskMessage,
+                ) -> ::std::result::Result<
+                    DeleteReplicationTaskResponse,
+                    RusotoError<DeleteReplicationTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_account_attributes: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeAccountAttributesResponse,
+                    RusotoError<DescribeAccountAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_certificates: Option<
+        Box<
+            dyn Fn(
+                    DescribeCertificatesMessage,
+                ) -> ::std::result::Result<
+                    DescribeCertificatesResponse,
+                    RusotoError<DescribeCertificatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_connections: Option<
+        Box<
+            dyn Fn(
+                    DescribeConnectionsMessage,
+                ) -> ::std::result::Result<
+                    DescribeConnectionsResponse,
+                    RusotoError<DescribeConnectionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_endpoint_types: Option<
+        Box<
+            dyn Fn(
+                    DescribeEndpointTypesMessage,
+                ) -> ::std::result::Result<
+                    DescribeEndpointTypesResponse,
+                    RusotoError<DescribeEndpointTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_endpoints: Option<
+        Box<
+            dyn Fn(
+                    DescribeEndpointsMessage,
+                ) -> ::std::result::Result<
+                    DescribeEndpointsResponse,
+                    RusotoError<DescribeEndpointsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_event_categories: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventCategoriesMessage,
+                ) -> ::std::result::Result<
+                    DescribeEventCategoriesResponse,
+                    RusotoError<DescribeEventCategoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_event_subscriptions: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventSubscriptionsMessage,
+                ) -> ::std::result::Result<
+                    DescribeEventSubscriptionsResponse,
+                    RusotoError<DescribeEventSubscriptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_events: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventsMessage,
+                ) -> ::std::result::Result<
+                    DescribeEventsResponse,
+                    RusotoError<DescribeEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_orderable_replication_instances: Option<
+        Box<
+            dyn Fn(
+                    DescribeOrderableReplicationInstancesMessage,
+                ) -> ::std::result::Result<
+                    DescribeOrderableReplicationInstancesResponse,
+                    RusotoError<DescribeOrderableReplicationInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_pending_maintenance_actions: Option<
+        Box<
+            dyn Fn(
+                    DescribePendingMaintenanceActionsMessage,
+                ) -> ::std::result::Result<
+                    DescribePendingMaintenanceActionsResponse,
+                    RusotoError<DescribePendingMaintenanceActionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_refresh_schemas_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeRefreshSchemasStatusMessage,
+                ) -> ::std::result::Result<
+                    DescribeRefreshSchemasStatusResponse,
+                    RusotoError<DescribeRefreshSchemasStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_replication_instance_task_logs: Option<
+        Box<
+            dyn Fn(
+                    DescribeReplicationInstanceTaskLogsMessage,
+                ) -> ::std::result::Result<
+                    DescribeReplicationInstanceTaskLogsResponse,
+                    RusotoError<DescribeReplicationInstanceTaskLogsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_replication_instances: Option<
+        Box<
+            dyn Fn(
+                    DescribeReplicationInstancesMessage,
+                ) -> ::std::result::Result<
+                    DescribeReplicationInstancesResponse,
+                    RusotoError<DescribeReplicationInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_replication_subnet_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeReplicationSubnetGroupsMessage,
+                ) -> ::std::result::Result<
+                    DescribeReplicationSubnetGroupsResponse,
+                    RusotoError<DescribeReplicationSubnetGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_replication_task_assessment_results: Option<
+        Box<
+            dyn Fn(
+                    DescribeReplicationTaskAssessmentResultsMessage,
+                ) -> ::std::result::Result<
+                    DescribeReplicationTaskAssessmentResultsResponse,
+                    RusotoError<DescribeReplicationTaskAssessmentResultsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_replication_tasks: Option<
+        Box<
+            dyn Fn(
+                    DescribeReplicationTasksMessage,
+                ) -> ::std::result::Result<
+                    DescribeReplicationTasksResponse,
+                    RusotoError<DescribeReplicationTasksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_schemas: Option<
+        Box<
+            dyn Fn(
+                    DescribeSchemasMessage,
+                ) -> ::std::result::Result<
+                    DescribeSchemasResponse,
+                    RusotoError<DescribeSchemasError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_table_statistics: Option<
+        Box<
+            dyn Fn(
+                    DescribeTableStatisticsMessage,
+                ) -> ::std::result::Result<
+                    DescribeTableStatisticsResponse,
+                    RusotoError<DescribeTableStatisticsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    import_certificate: Option<
+        Box<
+            dyn Fn(
+                    ImportCertificateMessage,
+                ) -> ::std::result::Result<
+                    ImportCertificateResponse,
+                    RusotoError<ImportCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceMessage,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_endpoint: Option<
+        Box<
+            dyn Fn(
+                    ModifyEndpointMessage,
+                ) -> ::std::result::Result<
+                    ModifyEndpointResponse,
+                    RusotoError<ModifyEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_event_subscription: Option<
+        Box<
+            dyn Fn(
+                    ModifyEventSubscriptionMessage,
+                ) -> ::std::result::Result<
+                    ModifyEventSubscriptionResponse,
+                    RusotoError<ModifyEventSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_replication_instance: Option<
+        Box<
+            dyn Fn(
+                    ModifyReplicationInstanceMessage,
+                ) -> ::std::result::Result<
+                    ModifyReplicationInstanceResponse,
+                    RusotoError<ModifyReplicationInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_replication_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    ModifyReplicationSubnetGroupMessage,
+                ) -> ::std::result::Result<
+                    ModifyReplicationSubnetGroupResponse,
+                    RusotoError<ModifyReplicationSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_replication_task: Option<
+        Box<
+            dyn Fn(
+                    ModifyReplicationTaskMessage,
+                ) -> ::std::result::Result<
+                    ModifyReplicationTaskResponse,
+                    RusotoError<ModifyReplicationTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reboot_replication_instance: Option<
+        Box<
+            dyn Fn(
+                    RebootReplicationInstanceMessage,
+                ) -> ::std::result::Result<
+                    RebootReplicationInstanceResponse,
+                    RusotoError<RebootReplicationInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    refresh_schemas: Option<
+        Box<
+            dyn Fn(
+                    RefreshSchemasMessage,
+                ) -> ::std::result::Result<
+                    RefreshSchemasResponse,
+                    RusotoError<RefreshSchemasError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reload_tables: Option<
+        Box<
+            dyn Fn(
+                    ReloadTablesMessage,
+                )
+                    -> ::std::result::Result<ReloadTablesResponse, RusotoError<ReloadTablesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_resource: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromResourceMessage,
+                ) -> ::std::result::Result<
+                    RemoveTagsFromResourceResponse,
+                    RusotoError<RemoveTagsFromResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_replication_task: Option<
+        Box<
+            dyn Fn(
+                    StartReplicationTaskMessage,
+                ) -> ::std::result::Result<
+                    StartReplicationTaskResponse,
+                    RusotoError<StartReplicationTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_replication_task_assessment: Option<
+        Box<
+            dyn Fn(
+                    StartReplicationTaskAssessmentMessage,
+                ) -> ::std::result::Result<
+                    StartReplicationTaskAssessmentResponse,
+                    RusotoError<StartReplicationTaskAssessmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_replication_task: Option<
+        Box<
+            dyn Fn(
+                    StopReplicationTaskMessage,
+                ) -> ::std::result::Result<
+                    StopReplicationTaskResponse,
+                    RusotoError<StopReplicationTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    test_connection: Option<
+        Box<
+            dyn Fn(
+                    TestConnectionMessage,
+                ) -> ::std::result::Result<
+                    TestConnectionResponse,
+                    RusotoError<TestConnectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockDatabaseMigrationService {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockDatabaseMigrationService {
+        Default::default()
+    }
+
+    /// Answers `add_tags_to_resource` with what `handler` returns for its input.
+    pub fn with_add_tags_to_resource<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                AddTagsToResourceMessage,
+            ) -> ::std::result::Result<
+                AddTagsToResourceResponse,
+                RusotoError<AddTagsToResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `apply_pending_maintenance_action` with what `handler` returns for its input.
+    pub fn with_apply_pending_maintenance_action<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                ApplyPendingMaintenanceActionMessage,
+            ) -> ::std::result::Result<
+                ApplyPendingMaintenanceActionResponse,
+                RusotoError<ApplyPendingMaintenanceActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.apply_pending_maintenance_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_endpoint` with what `handler` returns for its input.
+    pub fn with_create_endpoint<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                CreateEndpointMessage,
+            )
+                -> ::std::result::Result<CreateEndpointResponse, RusotoError<CreateEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_event_subscription` with what `handler` returns for its input.
+    pub fn with_create_event_subscription<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                CreateEventSubscriptionMessage,
+            ) -> ::std::result::Result<
+                CreateEventSubscriptionResponse,
+                RusotoError<CreateEventSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_event_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_replication_instance` with what `handler` returns for its input.
+    pub fn with_create_replication_instance<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                CreateReplicationInstanceMessage,
+            ) -> ::std::result::Result<
+                CreateReplicationInstanceResponse,
+                RusotoError<CreateReplicationInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_replication_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_replication_subnet_group` with what `handler` returns for its input.
+    pub fn with_create_replication_subnet_group<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                CreateReplicationSubnetGroupMessage,
+            ) -> ::std::result::Result<
+                CreateReplicationSubnetGroupResponse,
+                RusotoError<CreateReplicationSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_replication_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_replication_task` with what `handler` returns for its input.
+    pub fn with_create_replication_task<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                CreateReplicationTaskMessage,
+            ) -> ::std::result::Result<
+                CreateReplicationTaskResponse,
+                RusotoError<CreateReplicationTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_replication_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_certificate` with what `handler` returns for its input.
+    pub fn with_delete_certificate<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DeleteCertificateMessage,
+            ) -> ::std::result::Result<
+                DeleteCertificateResponse,
+                RusotoError<DeleteCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_endpoint` with what `handler` returns for its input.
+    pub fn with_delete_endpoint<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DeleteEndpointMessage,
+            )
+                -> ::std::result::Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_event_subscription` with what `handler` returns for its input.
+    pub fn with_delete_event_subscription<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DeleteEventSubscriptionMessage,
+            ) -> ::std::result::Result<
+                DeleteEventSubscriptionResponse,
+                RusotoError<DeleteEventSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_event_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_replication_instance` with what `handler` returns for its input.
+    pub fn with_delete_replication_instance<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DeleteReplicationInstanceMessage,
+            ) -> ::std::result::Result<
+                DeleteReplicationInstanceResponse,
+                RusotoError<DeleteReplicationInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_replication_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_replication_subnet_group` with what `handler` returns for its input.
+    pub fn with_delete_replication_subnet_group<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DeleteReplicationSubnetGroupMessage,
+            ) -> ::std::result::Result<
+                DeleteReplicationSubnetGroupResponse,
+                RusotoError<DeleteReplicationSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_replication_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_replication_task` with what `handler` returns for its input.
+    pub fn with_delete_replication_task<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DeleteReplicationTaskMessage,
+            ) -> ::std::result::Result<
+                DeleteReplicationTaskResponse,
+                RusotoError<DeleteReplicationTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_replication_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account_attributes` with what `handler` returns.
+    pub fn with_describe_account_attributes<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeAccountAttributesResponse,
+                RusotoError<DescribeAccountAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_certificates` with what `handler` returns for its input.
+    pub fn with_describe_certificates<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeCertificatesMessage,
+            ) -> ::std::result::Result<
+                DescribeCertificatesResponse,
+                RusotoError<DescribeCertificatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_certificates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_connections` with what `handler` returns for its input.
+    pub fn with_describe_connections<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeConnectionsMessage,
+            ) -> ::std::result::Result<
+                DescribeConnectionsResponse,
+                RusotoError<DescribeConnectionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_connections = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_endpoint_types` with what `handler` returns for its input.
+    pub fn with_describe_endpoint_types<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeEndpointTypesMessage,
+            ) -> ::std::result::Result<
+                DescribeEndpointTypesResponse,
+                RusotoError<DescribeEndpointTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_endpoint_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_endpoints` with what `handler` returns for its input.
+    pub fn with_describe_endpoints<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeEndpointsMessage,
+            ) -> ::std::result::Result<
+                DescribeEndpointsResponse,
+                RusotoError<DescribeEndpointsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_endpoints = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_categories` with what `handler` returns for its input.
+    pub fn with_describe_event_categories<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeEventCategoriesMessage,
+            ) -> ::std::result::Result<
+                DescribeEventCategoriesResponse,
+                RusotoError<DescribeEventCategoriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_categories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_subscriptions` with what `handler` returns for its input.
+    pub fn with_describe_event_subscriptions<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeEventSubscriptionsMessage,
+            ) -> ::std::result::Result<
+                DescribeEventSubscriptionsResponse,
+                RusotoError<DescribeEventSubscriptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_subscriptions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_events` with what `handler` returns for its input.
+    pub fn with_describe_events<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeEventsMessage,
+            )
+                -> ::std::result::Result<DescribeEventsResponse, RusotoError<DescribeEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_orderable_replication_instances` with what `handler` returns for its input.
+    pub fn with_describe_orderable_replication_instances<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeOrderableReplicationInstancesMessage,
+            ) -> ::std::result::Result<
+                DescribeOrderableReplicationInstancesResponse,
+                RusotoError<DescribeOrderableReplicationInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_orderable_replication_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_pending_maintenance_actions` with what `handler` returns for its input.
+    pub fn with_describe_pending_maintenance_actions<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribePendingMaintenanceActionsMessage,
+            ) -> ::std::result::Result<
+                DescribePendingMaintenanceActionsResponse,
+                RusotoError<DescribePendingMaintenanceActionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_pending_maintenance_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_refresh_schemas_status` with what `handler` returns for its input.
+    pub fn with_describe_refresh_schemas_status<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeRefreshSchemasStatusMessage,
+            ) -> ::std::result::Result<
+                DescribeRefreshSchemasStatusResponse,
+                RusotoError<DescribeRefreshSchemasStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_refresh_schemas_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_replication_instance_task_logs` with what `handler` returns for its input.
+    pub fn with_describe_replication_instance_task_logs<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeReplicationInstanceTaskLogsMessage,
+            ) -> ::std::result::Result<
+                DescribeReplicationInstanceTaskLogsResponse,
+                RusotoError<DescribeReplicationInstanceTaskLogsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_replication_instance_task_logs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_replication_instances` with what `handler` returns for its input.
+    pub fn with_describe_replication_instances<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeReplicationInstancesMessage,
+            ) -> ::std::result::Result<
+                DescribeReplicationInstancesResponse,
+                RusotoError<DescribeReplicationInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_replication_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_replication_subnet_groups` with what `handler` returns for its input.
+    pub fn with_describe_replication_subnet_groups<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeReplicationSubnetGroupsMessage,
+            ) -> ::std::result::Result<
+                DescribeReplicationSubnetGroupsResponse,
+                RusotoError<DescribeReplicationSubnetGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_replication_subnet_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_replication_task_assessment_results` with what `handler` returns for its input.
+    pub fn with_describe_replication_task_assessment_results<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeReplicationTaskAssessmentResultsMessage,
+            ) -> ::std::result::Result<
+                DescribeReplicationTaskAssessmentResultsResponse,
+                RusotoError<DescribeReplicationTaskAssessmentResultsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_replication_task_assessment_results = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_replication_tasks` with what `handler` returns for its input.
+    pub fn with_describe_replication_tasks<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeReplicationTasksMessage,
+            ) -> ::std::result::Result<
+                DescribeReplicationTasksResponse,
+                RusotoError<DescribeReplicationTasksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_replication_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_schemas` with what `handler` returns for its input.
+    pub fn with_describe_schemas<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeSchemasMessage,
+            )
+                -> ::std::result::Result<DescribeSchemasResponse, RusotoError<DescribeSchemasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_schemas = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_table_statistics` with what `handler` returns for its input.
+    pub fn with_describe_table_statistics<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                DescribeTableStatisticsMessage,
+            ) -> ::std::result::Result<
+                DescribeTableStatisticsResponse,
+                RusotoError<DescribeTableStatisticsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_table_statistics = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_certificate` with what `handler` returns for its input.
+    pub fn with_import_certificate<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                ImportCertificateMessage,
+            ) -> ::std::result::Result<
+                ImportCertificateResponse,
+                RusotoError<ImportCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                ListTagsForResourceMessage,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_endpoint` with what `handler` returns for its input.
+    pub fn with_modify_endpoint<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                ModifyEndpointMessage,
+            )
+                -> ::std::result::Result<ModifyEndpointResponse, RusotoError<ModifyEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_event_subscription` with what `handler` returns for its input.
+    pub fn with_modify_event_subscription<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                ModifyEventSubscriptionMessage,
+            ) -> ::std::result::Result<
+                ModifyEventSubscriptionResponse,
+                RusotoError<ModifyEventSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_event_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_replication_instance` with what `handler` returns for its input.
+    pub fn with_modify_replication_instance<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                ModifyReplicationInstanceMessage,
+            ) -> ::std::result::Result<
+                ModifyReplicationInstanceResponse,
+                RusotoError<ModifyReplicationInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_replication_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_replication_subnet_group` with what `handler` returns for its input.
+    pub fn with_modify_replication_subnet_group<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                ModifyReplicationSubnetGroupMessage,
+            ) -> ::std::result::Result<
+                ModifyReplicationSubnetGroupResponse,
+                RusotoError<ModifyReplicationSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_replication_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_replication_task` with what `handler` returns for its input.
+    pub fn with_modify_replication_task<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                ModifyReplicationTaskMessage,
+            ) -> ::std::result::Result<
+                ModifyReplicationTaskResponse,
+                RusotoError<ModifyReplicationTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_replication_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reboot_replication_instance` with what `handler` returns for its input.
+    pub fn with_reboot_replication_instance<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                RebootReplicationInstanceMessage,
+            ) -> ::std::result::Result<
+                RebootReplicationInstanceResponse,
+                RusotoError<RebootReplicationInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reboot_replication_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `refresh_schemas` with what `handler` returns for its input.
+    pub fn with_refresh_schemas<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                RefreshSchemasMessage,
+            )
+                -> ::std::result::Result<RefreshSchemasResponse, RusotoError<RefreshSchemasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.refresh_schemas = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reload_tables` with what `handler` returns for its input.
+    pub fn with_reload_tables<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                ReloadTablesMessage,
+            )
+                -> ::std::result::Result<ReloadTablesResponse, RusotoError<ReloadTablesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reload_tables = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_resource` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_resource<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                RemoveTagsFromResourceMessage,
+            ) -> ::std::result::Result<
+                RemoveTagsFromResourceResponse,
+                RusotoError<RemoveTagsFromResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_replication_task` with what `handler` returns for its input.
+    pub fn with_start_replication_task<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                StartReplicationTaskMessage,
+            ) -> ::std::result::Result<
+                StartReplicationTaskResponse,
+                RusotoError<StartReplicationTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_replication_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_replication_task_assessment` with what `handler` returns for its input.
+    pub fn with_start_replication_task_assessment<F>(
+        mut self,
+        handler: F,
+    ) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                StartReplicationTaskAssessmentMessage,
+            ) -> ::std::result::Result<
+                StartReplicationTaskAssessmentResponse,
+                RusotoError<StartReplicationTaskAssessmentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_replication_task_assessment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_replication_task` with what `handler` returns for its input.
+    pub fn with_stop_replication_task<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                StopReplicationTaskMessage,
+            ) -> ::std::result::Result<
+                StopReplicationTaskResponse,
+                RusotoError<StopReplicationTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_replication_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_connection` with what `handler` returns for its input.
+    pub fn with_test_connection<F>(mut self, handler: F) -> MockDatabaseMigrationService
+    where
+        F: Fn(
+                TestConnectionMessage,
+            )
+                -> ::std::result::Result<TestConnectionResponse, RusotoError<TestConnectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.test_connection = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl DatabaseMigrationService for MockDatabaseMigrationService {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
+        match self.add_tags_to_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for add_tags_to_resource"),
+        }
+    }
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResponse, ApplyPendingMaintenanceActionError>
+    {
+        match self.apply_pending_maintenance_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for apply_pending_maintenance_action"
+            ),
+        }
+    }
+    fn create_endpoint(
+        &self,
+        input: CreateEndpointMessage,
+    ) -> RusotoFuture<CreateEndpointResponse, CreateEndpointError> {
+        match self.create_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for create_endpoint"),
+        }
+    }
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResponse, CreateEventSubscriptionError> {
+        match self.create_event_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for create_event_subscription")
+            }
+        }
+    }
+    fn create_replication_instance(
+        &self,
+        input: CreateReplicationInstanceMessage,
+    ) -> RusotoFuture<CreateReplicationInstanceResponse, CreateReplicationInstanceError> {
+        match self.create_replication_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for create_replication_instance"
+            ),
+        }
+    }
+    fn create_replication_subnet_group(
+        &self,
+        input: CreateReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<CreateReplicationSubnetGroupResponse, CreateReplicationSubnetGroupError> {
+        match self.create_replication_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for create_replication_subnet_group"
+            ),
+        }
+    }
+    fn create_replication_task(
+        &self,
+        input: CreateReplicationTaskMessage,
+    ) -> RusotoFuture<CreateReplicationTaskResponse, CreateReplicationTaskError> {
+        match self.create_replication_task {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for create_replication_task")
+            }
+        }
+    }
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateMessage,
+    ) -> RusotoFuture<DeleteCertificateResponse, DeleteCertificateError> {
+        match self.delete_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for delete_certificate"),
+        }
+    }
+    fn delete_endpoint(
+        &self,
+        input: DeleteEndpointMessage,
+    ) -> RusotoFuture<DeleteEndpointResponse, DeleteEndpointError> {
+        match self.delete_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for delete_endpoint"),
+        }
+    }
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<DeleteEventSubscriptionResponse, DeleteEventSubscriptionError> {
+        match self.delete_event_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for delete_event_subscription")
+            }
+        }
+    }
+    fn delete_replication_instance(
+        &self,
+        input: DeleteReplicationInstanceMessage,
+    ) -> RusotoFuture<DeleteReplicationInstanceResponse, DeleteReplicationInstanceError> {
+        match self.delete_replication_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for delete_replication_instance"
+            ),
+        }
+    }
+    fn delete_replication_subnet_group(
+        &self,
+        input: DeleteReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<DeleteReplicationSubnetGroupResponse, DeleteReplicationSubnetGroupError> {
+        match self.delete_replication_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for delete_replication_subnet_group"
+            ),
+        }
+    }
+    fn delete_replication_task(
+        &self,
+        input: DeleteReplicationTaskMessage,
+    ) -> RusotoFuture<DeleteReplicationTaskResponse, DeleteReplicationTaskError> {
+        match self.delete_replication_task {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for delete_replication_task")
+            }
+        }
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
+        match self.describe_account_attributes {
+            Some(ref handler) => handler().into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for describe_account_attributes"
+            ),
+        }
+    }
+    fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> RusotoFuture<DescribeCertificatesResponse, DescribeCertificatesError> {
+        match self.describe_certificates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for describe_certificates"),
+        }
+    }
+    fn describe_connections(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> RusotoFuture<DescribeConnectionsResponse, DescribeConnectionsError> {
+        match self.describe_connections {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for describe_connections"),
+        }
+    }
+    fn describe_endpoint_types(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> RusotoFuture<DescribeEndpointTypesResponse, DescribeEndpointTypesError> {
+        match self.describe_endpoint_types {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for describe_endpoint_types")
+            }
+        }
+    }
+    fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        match self.describe_endpoints {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for describe_endpoints"),
+        }
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<DescribeEventCategoriesResponse, DescribeEventCategoriesError> {
+        match self.describe_event_categories {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for describe_event_categories")
+            }
+        }
+    }
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<DescribeEventSubscriptionsResponse, DescribeEventSubscriptionsError> {
+        match self.describe_event_subscriptions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for describe_event_subscriptions"
+            ),
+        }
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        match self.describe_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for describe_events"),
+        }
+    }
+    fn describe_orderable_replication_instances(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> RusotoFuture<
+        DescribeOrderableReplicationInstancesResponse,
+        DescribeOrderableReplicationInstancesError,
+    > {
+        match self.describe_orderable_replication_instances {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockDatabaseMigrationService has no handler for describe_orderable_replication_instances"),
+}
+    }
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<
+        DescribePendingMaintenanceActionsResponse,
+        DescribePendingMaintenanceActionsError,
+    > {
+        match self.describe_pending_maintenance_actions {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockDatabaseMigrationService has no handler for describe_pending_maintenance_actions"),
+}
+    }
+    fn describe_refresh_schemas_status(
+        &self,
+        input: DescribeRefreshSchemasStatusMessage,
+    ) -> RusotoFuture<DescribeRefreshSchemasStatusResponse, DescribeRefreshSchemasStatusError> {
+        match self.describe_refresh_schemas_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for describe_refresh_schemas_status"
+            ),
+        }
+    }
+    fn describe_replication_instance_task_logs(
+        &self,
+        input: DescribeReplicationInstanceTaskLogsMessage,
+    ) -> RusotoFuture<
+        DescribeReplicationInstanceTaskLogsResponse,
+        DescribeReplicationInstanceTaskLogsError,
+    > {
+        match self.describe_replication_instance_task_logs {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockDatabaseMigrationService has no handler for describe_replication_instance_task_logs"),
+}
+    }
+    fn describe_replication_instances(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> RusotoFuture<DescribeReplicationInstancesResponse, DescribeReplicationInstancesError> {
+        match self.describe_replication_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for describe_replication_instances"
+            ),
+        }
+    }
+    fn describe_replication_subnet_groups(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> RusotoFuture<DescribeReplicationSubnetGroupsResponse, DescribeReplicationSubnetGroupsError>
+    {
+        match self.describe_replication_subnet_groups {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockDatabaseMigrationService has no handler for describe_replication_subnet_groups"),
+}
+    }
+    fn describe_replication_task_assessment_results(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> RusotoFuture<
+        DescribeReplicationTaskAssessmentResultsResponse,
+        DescribeReplicationTaskAssessmentResultsError,
+    > {
+        match self.describe_replication_task_assessment_results {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockDatabaseMigrationService has no handler for describe_replication_task_assessment_results"),
+}
+    }
+    fn describe_replication_tasks(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> RusotoFuture<DescribeReplicationTasksResponse, DescribeReplicationTasksError> {
+        match self.describe_replication_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for describe_replication_tasks")
+            }
+        }
+    }
+    fn describe_schemas(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> RusotoFuture<DescribeSchemasResponse, DescribeSchemasError> {
+        match self.describe_schemas {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for describe_schemas"),
+        }
+    }
+    fn describe_table_statistics(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> RusotoFuture<DescribeTableStatisticsResponse, DescribeTableStatisticsError> {
+        match self.describe_table_statistics {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for describe_table_statistics")
+            }
+        }
+    }
+    fn import_certificate(
+        &self,
+        input: ImportCertificateMessage,
+    ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        match self.import_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for import_certificate"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for list_tags_for_resource")
+            }
+        }
+    }
+    fn modify_endpoint(
+        &self,
+        input: ModifyEndpointMessage,
+    ) -> RusotoFuture<ModifyEndpointResponse, ModifyEndpointError> {
+        match self.modify_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for modify_endpoint"),
+        }
+    }
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResponse, ModifyEventSubscriptionError> {
+        match self.modify_event_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for modify_event_subscription")
             }
-        })
+        }
     }
-
-    /// <p><p>Modifies the replication instance to apply new settings. You can change one or more parameters by specifying these parameters and the new values in the request.</p> <p>Some settings are applied during the maintenance window.</p> <p/></p>
     fn modify_replication_instance(
         &self,
         input: ModifyReplicationInstanceMessage,
     ) -> RusotoFuture<ModifyReplicationInstanceResponse, ModifyReplicationInstanceError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.ModifyReplicationInstance",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyReplicationInstanceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyReplicationInstanceError::from_response(response))
-                }))
-            }
-        })
+        match self.modify_replication_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for modify_replication_instance"
+            ),
+        }
     }
-
-    /// <p>Modifies the settings for the specified replication subnet group.</p>
     fn modify_replication_subnet_group(
         &self,
         input: ModifyReplicationSubnetGroupMessage,
     ) -> RusotoFuture<ModifyReplicationSubnetGroupResponse, ModifyReplicationSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.ModifyReplicationSubnetGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyReplicationSubnetGroupResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyReplicationSubnetGroupError::from_response(response))
-                }))
-            }
-        })
+        match self.modify_replication_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for modify_replication_subnet_group"
+            ),
+        }
     }
-
-    /// <p>Modifies the specified replication task.</p> <p>You can't modify the task endpoints. The task must be stopped before you can modify it. </p> <p>For more information about AWS DMS tasks, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Tasks.html">Working with Migration Tasks</a> in the <i>AWS Database Migration Service User Guide</i>.</p>
     fn modify_replication_task(
         &self,
         input: ModifyReplicationTaskMessage,
     ) -> RusotoFuture<ModifyReplicationTaskResponse, ModifyReplicationTaskError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyReplicationTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyReplicationTaskResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ModifyReplicationTaskError::from_response(response))
-                    }),
-                )
+        match self.modify_replication_task {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for modify_replication_task")
             }
-        })
+        }
     }
-
-    /// <p>Reboots a replication instance. Rebooting results in a momentary outage, until the replication instance becomes available again.</p>
     fn reboot_replication_instance(
         &self,
         input: RebootReplicationInstanceMessage,
     ) -> RusotoFuture<RebootReplicationInstanceResponse, RebootReplicationInstanceError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.RebootReplicationInstance",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RebootReplicationInstanceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RebootReplicationInstanceError::from_response(response))
-                }))
-            }
-        })
+        match self.reboot_replication_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for reboot_replication_instance"
+            ),
+        }
     }
-
-    /// <p>Populates the schema for the specified endpoint. This is an asynchronous operation and can take several minutes. You can check the status of this operation by calling the DescribeRefreshSchemasStatus operation.</p>
     fn refresh_schemas(
         &self,
         input: RefreshSchemasMessage,
     ) -> RusotoFuture<RefreshSchemasResponse, RefreshSchemasError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.RefreshSchemas");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RefreshSchemasResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RefreshSchemasError::from_response(response))),
-                )
-            }
-        })
+        match self.refresh_schemas {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for refresh_schemas"),
+        }
     }
-
-    /// <p>Reloads the target database table with the source data. </p>
     fn reload_tables(
         &self,
         input: ReloadTablesMessage,
     ) -> RusotoFuture<ReloadTablesResponse, ReloadTablesError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.ReloadTables");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ReloadTablesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ReloadTablesError::from_response(response))),
-                )
-            }
-        })
+        match self.reload_tables {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for reload_tables"),
+        }
     }
-
-    /// <p>Removes metadata tags from a DMS resource.</p>
     fn remove_tags_from_resource(
         &self,
         input: RemoveTagsFromResourceMessage,
     ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.RemoveTagsFromResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveTagsFromResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RemoveTagsFromResourceError::from_response(response))
-                    }),
-                )
+        match self.remove_tags_from_resource {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for remove_tags_from_resource")
             }
-        })
+        }
     }
-
-    /// <p>Starts the replication task.</p> <p>For more information about AWS DMS tasks, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Tasks.html">Working with Migration Tasks </a> in the <i>AWS Database Migration Service User Guide.</i> </p>
     fn start_replication_task(
         &self,
         input: StartReplicationTaskMessage,
     ) -> RusotoFuture<StartReplicationTaskResponse, StartReplicationTaskError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.StartReplicationTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartReplicationTaskResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartReplicationTaskError::from_response(response))
-                    }),
-                )
+        match self.start_replication_task {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDatabaseMigrationService has no handler for start_replication_task")
             }
-        })
+        }
     }
-
-    /// <p> Starts the replication task assessment for unsupported data types in the source database. </p>
     fn start_replication_task_assessment(
         &self,
         input: StartReplicationTaskAssessmentMessage,
     ) -> RusotoFuture<StartReplicationTaskAssessmentResponse, StartReplicationTaskAssessmentError>
     {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonDMSv20160101.StartReplicationTaskAssessment",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartReplicationTaskAssessmentResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartReplicationTaskAssessmentError::from_response(response))
-                }))
-            }
-        })
+        match self.start_replication_task_assessment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDatabaseMigrationService has no handler for start_replication_task_assessment"
+            ),
+        }
     }
-
-    /// <p><p>Stops the replication task.</p> <p/></p>
     fn stop_replication_task(
         &self,
         input: StopReplicationTaskMessage,
     ) -> RusotoFuture<StopReplicationTaskResponse, StopReplicationTaskError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.StopReplicationTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopReplicationTaskResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StopReplicationTaskError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.stop_replication_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for stop_replication_task"),
+        }
     }
-
-    /// <p>Tests the connection between the replication instance and the endpoint.</p>
     fn test_connection(
         &self,
         input: TestConnectionMessage,
     ) -> RusotoFuture<TestConnectionResponse, TestConnectionError> {
-        let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonDMSv20160101.TestConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TestConnectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TestConnectionError::from_response(response))),
-                )
-            }
-        })
+        match self.test_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDatabaseMigrationService has no handler for test_connection"),
+        }
     }
 }
@@ -1 +1,5 @@
+#[cfg(test)]
+mod custom_tests;
 
+/// Presigned URLs for copying encrypted cluster snapshots across regions
+pub mod presign;
@@ -23,8 +23,8 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
@@ -35,6 +35,8 @@ use xml::EventReader;
 
 /// <p>Represents the input to <a>AddTagsToResource</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToResourceMessage {
     /// <p>The Amazon DocumentDB resource that the tags are added to. This value is an Amazon Resource Name (ARN).</p>
     pub resource_name: String,
@@ -69,6 +71,8 @@ impl ApplyMethodDeserializer {
 }
 /// <p>Represents the input to <a>ApplyPendingMaintenanceAction</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplyPendingMaintenanceActionMessage {
     /// <p>The pending maintenance action to apply to this resource.</p> <p>Valid values: <code>system-update</code>, <code>db-upgrade</code> </p>
     pub apply_action: String,
@@ -97,6 +101,8 @@ impl ApplyPendingMaintenanceActionMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplyPendingMaintenanceActionResult {
     pub resource_pending_maintenance_actions: Option<ResourcePendingMaintenanceActions>,
 }
@@ -158,6 +164,8 @@ impl AttributeValueListSerializer {
 
 /// <p>Information about an Availability Zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AvailabilityZone {
     /// <p>The name of the Availability Zone.</p>
     pub name: Option<String>,
@@ -235,7 +243,7 @@ impl BooleanDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -246,7 +254,7 @@ impl BooleanOptionalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -254,6 +262,8 @@ impl BooleanOptionalDeserializer {
 }
 /// <p>The configuration setting for the log types to be enabled for export to Amazon CloudWatch Logs for a specific DB instance or DB cluster.</p> <p>The <code>EnableLogTypes</code> and <code>DisableLogTypes</code> arrays determine which logs are exported (or not exported) to CloudWatch Logs. The values within these arrays depend on the DB engine that is being used.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudwatchLogsExportConfiguration {
     /// <p>The list of log types to disable.</p>
     pub disable_log_types: Option<Vec<String>>,
@@ -289,6 +299,8 @@ impl CloudwatchLogsExportConfigurationSerializer {
 
 /// <p>Represents the input to <a>CopyDBClusterParameterGroup</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyDBClusterParameterGroupMessage {
     /// <p><p>The identifier or Amazon Resource Name (ARN) for the source DB cluster parameter group.</p> <p>Constraints:</p> <ul> <li> <p>Must specify a valid DB cluster parameter group.</p> </li> <li> <p>If the source DB cluster parameter group is in the same AWS Region as the copy, specify a valid DB parameter group identifier; for example, <code>my-db-cluster-param-group</code>, or a valid ARN.</p> </li> <li> <p>If the source DB parameter group is in a different AWS Region than the copy, specify a valid DB cluster parameter group ARN; for example, <code>arn:aws:rds:us-east-1:123456789012:cluster-pg:custom-cluster-group1</code>.</p> </li> </ul></p>
     pub source_db_cluster_parameter_group_identifier: String,
@@ -328,6 +340,8 @@ impl CopyDBClusterParameterGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyDBClusterParameterGroupResult {
     pub db_cluster_parameter_group: Option<DBClusterParameterGroup>,
 }
@@ -360,6 +374,8 @@ impl CopyDBClusterParameterGroupResultDeserializer {
 }
 /// <p>Represents the input to <a>CopyDBClusterSnapshot</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyDBClusterSnapshotMessage {
     /// <p>Set to <code>true</code> to copy all tags from the source DB cluster snapshot to the target DB cluster snapshot, and otherwise <code>false</code>. The default is <code>false</code>.</p>
     pub copy_tags: Option<bool>,
@@ -408,6 +424,8 @@ impl CopyDBClusterSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
@@ -439,6 +457,8 @@ impl CopyDBClusterSnapshotResultDeserializer {
 }
 /// <p>Represents the input to <a>CreateDBCluster</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDBClusterMessage {
     /// <p>A list of Amazon EC2 Availability Zones that instances in the DB cluster can be created in.</p>
     pub availability_zones: Option<Vec<String>>,
@@ -571,6 +591,8 @@ impl CreateDBClusterMessageSerializer {
 
 /// <p>Represents the input of <a>CreateDBClusterParameterGroup</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDBClusterParameterGroupMessage {
     /// <p><p>The name of the DB cluster parameter group.</p> <p>Constraints:</p> <ul> <li> <p>Must match the name of an existing <code>DBClusterParameterGroup</code>.</p> </li> </ul> <note> <p>This value is stored as a lowercase string.</p> </note></p>
     pub db_cluster_parameter_group_name: String,
@@ -607,6 +629,8 @@ impl CreateDBClusterParameterGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDBClusterParameterGroupResult {
     pub db_cluster_parameter_group: Option<DBClusterParameterGroup>,
 }
@@ -638,6 +662,8 @@ impl CreateDBClusterParameterGroupResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
@@ -662,6 +688,8 @@ impl CreateDBClusterResultDeserializer {
 }
 /// <p>Represents the input of <a>CreateDBClusterSnapshot</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDBClusterSnapshotMessage {
     /// <p>The identifier of the DB cluster to create a snapshot for. This parameter is not case sensitive.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing <code>DBCluster</code>.</p> </li> </ul> <p>Example: <code>my-cluster</code> </p>
     pub db_cluster_identifier: String,
@@ -695,6 +723,8 @@ impl CreateDBClusterSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
@@ -726,6 +756,8 @@ impl CreateDBClusterSnapshotResultDeserializer {
 }
 /// <p>Represents the input to <a>CreateDBInstance</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDBInstanceMessage {
     /// <p>Indicates that minor engine upgrades are applied automatically to the DB instance during the maintenance window.</p> <p>Default: <code>true</code> </p>
     pub auto_minor_version_upgrade: Option<bool>,
@@ -794,6 +826,8 @@ impl CreateDBInstanceMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
@@ -819,6 +853,8 @@ impl CreateDBInstanceResultDeserializer {
 }
 /// <p>Represents the input to <a>CreateDBSubnetGroup</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDBSubnetGroupMessage {
     /// <p>The description for the DB subnet group.</p>
     pub db_subnet_group_description: String,
@@ -859,6 +895,8 @@ impl CreateDBSubnetGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDBSubnetGroupResult {
     pub db_subnet_group: Option<DBSubnetGroup>,
 }
@@ -890,6 +928,8 @@ impl CreateDBSubnetGroupResultDeserializer {
 }
 /// <p>Detailed information about a DB cluster. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBCluster {
     /// <p>Provides a list of the AWS Identity and Access Management (IAM) roles that are associated with the DB cluster. IAM roles that are associated with a DB cluster grant permission for the DB cluster to access other AWS services on your behalf.</p>
     pub associated_roles: Option<Vec<DBClusterRole>>,
@@ -1124,6 +1164,8 @@ impl DBClusterListDeserializer {
 }
 /// <p>Contains information about an instance that is part of a DB cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterMember {
     /// <p>Specifies the status of the DB cluster parameter group for this member of the DB cluster.</p>
     pub db_cluster_parameter_group_status: Option<String>,
@@ -1194,6 +1236,8 @@ impl DBClusterMemberListDeserializer {
 }
 /// <p>Represents the output of <a>DescribeDBClusters</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterMessage {
     /// <p>A list of DB clusters.</p>
     pub db_clusters: Option<Vec<DBCluster>>,
@@ -1226,6 +1270,8 @@ impl DBClusterMessageDeserializer {
 }
 /// <p>Detailed information about a DB cluster parameter group. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterParameterGroup {
     /// <p>The Amazon Resource Name (ARN) for the DB cluster parameter group.</p>
     pub db_cluster_parameter_group_arn: Option<String>,
@@ -1279,6 +1325,8 @@ impl DBClusterParameterGroupDeserializer {
 }
 /// <p>Represents the output of <a>DBClusterParameterGroup</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterParameterGroupDetails {
     /// <p>An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
@@ -1335,6 +1383,8 @@ impl DBClusterParameterGroupListDeserializer {
 }
 /// <p>Contains the name of a DB cluster parameter group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterParameterGroupNameMessage {
     /// <p><p>The name of a DB cluster parameter group.</p> <p>Constraints:</p> <ul> <li> <p>Must be from 1 to 255 letters or numbers.</p> </li> <li> <p>The first character must be a letter.</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens.</p> </li> </ul> <note> <p>This value is stored as a lowercase string.</p> </note></p>
     pub db_cluster_parameter_group_name: Option<String>,
@@ -1366,6 +1416,8 @@ impl DBClusterParameterGroupNameMessageDeserializer {
 }
 /// <p>Represents the output of <a>DBClusterParameterGroups</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterParameterGroupsMessage {
     /// <p>A list of DB cluster parameter groups.</p>
     pub db_cluster_parameter_groups: Option<Vec<DBClusterParameterGroup>>,
@@ -1405,6 +1457,8 @@ impl DBClusterParameterGroupsMessageDeserializer {
 }
 /// <p>Describes an AWS Identity and Access Management (IAM) role that is associated with a DB cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterRole {
     /// <p>The Amazon Resource Name (ARN) of the IAM role that is associated with the DB cluster.</p>
     pub role_arn: Option<String>,
@@ -1455,6 +1509,8 @@ impl DBClusterRolesDeserializer {
 }
 /// <p>Detailed information about a DB cluster snapshot. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterSnapshot {
     /// <p>Provides the list of Amazon EC2 Availability Zones that instances in the DB cluster snapshot can be restored in.</p>
     pub availability_zones: Option<Vec<String>>,
@@ -1583,6 +1639,8 @@ impl DBClusterSnapshotDeserializer {
 }
 /// <p>Contains the name and values of a manual DB cluster snapshot attribute.</p> <p>Manual DB cluster snapshot attributes are used to authorize other AWS accounts to restore a manual DB cluster snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterSnapshotAttribute {
     /// <p>The name of the manual DB cluster snapshot attribute.</p> <p>The attribute named <code>restore</code> refers to the list of AWS accounts that have permission to copy or restore the manual DB cluster snapshot.</p>
     pub attribute_name: Option<String>,
@@ -1640,6 +1698,8 @@ impl DBClusterSnapshotAttributeListDeserializer {
 }
 /// <p>Detailed information about the attributes that are associated with a DB cluster snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterSnapshotAttributesResult {
     /// <p>The list of attributes and values for the DB cluster snapshot.</p>
     pub db_cluster_snapshot_attributes: Option<Vec<DBClusterSnapshotAttribute>>,
@@ -1702,6 +1762,8 @@ impl DBClusterSnapshotListDeserializer {
 }
 /// <p>Represents the output of <a>DescribeDBClusterSnapshots</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBClusterSnapshotMessage {
     /// <p>Provides a list of DB cluster snapshots.</p>
     pub db_cluster_snapshots: Option<Vec<DBClusterSnapshot>>,
@@ -1741,6 +1803,8 @@ impl DBClusterSnapshotMessageDeserializer {
 }
 /// <p> Detailed information about a DB engine version. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBEngineVersion {
     /// <p>The description of the database engine.</p>
     pub db_engine_description: Option<String>,
@@ -1842,6 +1906,8 @@ impl DBEngineVersionListDeserializer {
 }
 /// <p>Represents the output of <a>DescribeDBEngineVersions</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBEngineVersionMessage {
     /// <p>Detailed information about one or more DB engine versions.</p>
     pub db_engine_versions: Option<Vec<DBEngineVersion>>,
@@ -1874,6 +1940,8 @@ impl DBEngineVersionMessageDeserializer {
 }
 /// <p>Detailed information about a DB instance. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBInstance {
     /// <p>Indicates that minor version patches are applied automatically.</p>
     pub auto_minor_version_upgrade: Option<bool>,
@@ -2092,6 +2160,8 @@ impl DBInstanceListDeserializer {
 }
 /// <p>Represents the output of <a>DescribeDBInstances</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBInstanceMessage {
     /// <p>Detailed information about one or more DB instances. </p>
     pub db_instances: Option<Vec<DBInstance>>,
@@ -2124,6 +2194,8 @@ impl DBInstanceMessageDeserializer {
 }
 /// <p>Provides a list of status information for a DB instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBInstanceStatusInfo {
     /// <p>Details of the error if there is an error for the instance. If the instance is not in an error state, this value is blank.</p>
     pub message: Option<String>,
@@ -2184,6 +2256,8 @@ impl DBInstanceStatusInfoListDeserializer {
 }
 /// <p>Detailed information about a DB subnet group. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBSubnetGroup {
     /// <p>The Amazon Resource Identifier (ARN) for the DB subnet group.</p>
     pub db_subnet_group_arn: Option<String>,
@@ -2242,6 +2316,8 @@ impl DBSubnetGroupDeserializer {
 }
 /// <p>Represents the output of <a>DescribeDBSubnetGroups</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DBSubnetGroupMessage {
     /// <p>Detailed information about one or more DB subnet groups.</p>
     pub db_subnet_groups: Option<Vec<DBSubnetGroup>>,
@@ -2294,6 +2370,8 @@ impl DBSubnetGroupsDeserializer {
 }
 /// <p>Represents the input to <a>DeleteDBCluster</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDBClusterMessage {
     /// <p><p>The DB cluster identifier for the DB cluster to be deleted. This parameter isn&#39;t case sensitive.</p> <p>Constraints:</p> <ul> <li> <p>Must match an existing <code>DBClusterIdentifier</code>.</p> </li> </ul></p>
     pub db_cluster_identifier: String,
@@ -2330,6 +2408,8 @@ impl DeleteDBClusterMessageSerializer {
 
 /// <p>Represents the input to <a>DeleteDBClusterParameterGroup</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDBClusterParameterGroupMessage {
     /// <p><p>The name of the DB cluster parameter group.</p> <p>Constraints:</p> <ul> <li> <p>Must be the name of an existing DB cluster parameter group.</p> </li> <li> <p>You can&#39;t delete a default DB cluster parameter group.</p> </li> <li> <p>Cannot be associated with any DB clusters.</p> </li> </ul></p>
     pub db_cluster_parameter_group_name: String,
@@ -2352,6 +2432,8 @@ impl DeleteDBClusterParameterGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
@@ -2376,6 +2458,8 @@ impl DeleteDBClusterResultDeserializer {
 }
 /// <p>Represents the input to <a>DeleteDBClusterSnapshot</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDBClusterSnapshotMessage {
     /// <p>The identifier of the DB cluster snapshot to delete.</p> <p>Constraints: Must be the name of an existing DB cluster snapshot in the <code>available</code> state.</p>
     pub db_cluster_snapshot_identifier: String,
@@ -2398,6 +2482,8 @@ impl DeleteDBClusterSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
@@ -2429,6 +2515,8 @@ impl DeleteDBClusterSnapshotResultDeserializer {
 }
 /// <p>Represents the input to <a>DeleteDBInstance</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDBInstanceMessage {
     /// <p><p>The DB instance identifier for the DB instance to be deleted. This parameter isn&#39;t case sensitive.</p> <p>Constraints:</p> <ul> <li> <p>Must match the name of an existing DB instance.</p> </li> </ul></p>
     pub db_instance_identifier: String,
@@ -2451,6 +2539,8 @@ impl DeleteDBInstanceMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
@@ -2476,6 +2566,8 @@ impl DeleteDBInstanceResultDeserializer {
 }
 /// <p>Represents the input to <a>DeleteDBSubnetGroup</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDBSubnetGroupMessage {
     /// <p>The name of the database subnet group to delete.</p> <note> <p>You can't delete the default subnet group.</p> </note> <p>Constraints:</p> <p>Must match the name of an existing <code>DBSubnetGroup</code>. Must not be default.</p> <p>Example: <code>mySubnetgroup</code> </p>
     pub db_subnet_group_name: String,
@@ -2499,6 +2591,8 @@ impl DeleteDBSubnetGroupMessageSerializer {
 
 /// <p>Represents the input to <a>DescribeDBClusterParameterGroups</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDBClusterParameterGroupsMessage {
     /// <p><p>The name of a specific DB cluster parameter group to return details for.</p> <p>Constraints:</p> <ul> <li> <p>If provided, must match the name of an existing <code>DBClusterParameterGroup</code>.</p> </li> </ul></p>
     pub db_cluster_parameter_group_name: Option<String>,
@@ -2543,6 +2637,8 @@ impl DescribeDBClusterParameterGroupsMessageSerializer {
 
 /// <p>Represents the input to <a>DescribeDBClusterParameters</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDBClusterParametersMessage {
     /// <p><p>The name of a specific DB cluster parameter group to return parameter details for.</p> <p>Constraints:</p> <ul> <li> <p>If provided, must match the name of an existing <code>DBClusterParameterGroup</code>.</p> </li> </ul></p>
     pub db_cluster_parameter_group_name: String,
@@ -2590,6 +2686,8 @@ impl DescribeDBClusterParametersMessageSerializer {
 
 /// <p>Represents the input to <a>DescribeDBClusterSnapshotAttributes</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDBClusterSnapshotAttributesMessage {
     /// <p>The identifier for the DB cluster snapshot to describe the attributes for.</p>
     pub db_cluster_snapshot_identifier: String,
@@ -2616,6 +2714,8 @@ impl DescribeDBClusterSnapshotAttributesMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDBClusterSnapshotAttributesResult {
     pub db_cluster_snapshot_attributes_result: Option<DBClusterSnapshotAttributesResult>,
 }
@@ -2648,6 +2748,8 @@ impl DescribeDBClusterSnapshotAttributesResultDeserializer {
 }
 /// <p>Represents the input to <a>DescribeDBClusterSnapshots</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDBClusterSnapshotsMessage {
     /// <p><p>The ID of the DB cluster to retrieve the list of DB cluster snapshots for. This parameter can&#39;t be used with the <code>DBClusterSnapshotIdentifier</code> parameter. This parameter is not case sensitive. </p> <p>Constraints:</p> <ul> <li> <p>If provided, must match the identifier of an existing <code>DBCluster</code>.</p> </li> </ul></p>
     pub db_cluster_identifier: Option<String>,
@@ -2715,6 +2817,8 @@ impl DescribeDBClusterSnapshotsMessageSerializer {
 
 /// <p>Represents the input to <a>DescribeDBClusters</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDBClustersMessage {
     /// <p><p>The user-provided DB cluster identifier. If this parameter is specified, information from only the specific DB cluster is returned. This parameter isn&#39;t case sensitive.</p> <p>Constraints:</p> <ul> <li> <p>If provided, must match an existing <code>DBClusterIdentifier</code>.</p> </li> </ul></p>
     pub db_cluster_identifier: Option<String>,
@@ -2759,6 +2863,8 @@ impl DescribeDBClustersMessageSerializer {
 
 /// <p>Represents the input to <a>DescribeDBEngineVersions</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDBEngineVersionsMessage {
     /// <p><p>The name of a specific DB parameter group family to return details for.</p> <p>Constraints:</p> <ul> <li> <p>If provided, must match an existing <code>DBParameterGroupFamily</code>.</p> </li> </ul></p>
     pub db_parameter_group_family: Option<String>,
@@ -2834,6 +2940,8 @@ impl DescribeDBEngineVersionsMessageSerializer {
 
 /// <p>Represents the input to <a>DescribeDBInstances</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDBInstancesMessage {
     /// <p><p>The user-provided instance identifier. If this parameter is specified, information from only the specific DB instance is returned. This parameter isn&#39;t case sensitive.</p> <p>Constraints:</p> <ul> <li> <p>If provided, must match the identifier of an existing <code>DBInstance</code>.</p> </li> </ul></p>
     pub db_instance_identifier: Option<String>,
@@ -2878,6 +2986,8 @@ impl DescribeDBInstancesMessageSerializer {
 
 /// <p>Represents the input to <a>DescribeDBSubnetGroups</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDBSubnetGroupsMessage {
     /// <p>The name of the DB subnet group to return details for.</p>
     pub db_subnet_group_name: Option<String>,
@@ -2919,6 +3029,8 @@ impl DescribeDBSubnetGroupsMessageSerializer {
 
 /// <p>Represents the input to <a>DescribeEngineDefaultClusterParameters</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEngineDefaultClusterParametersMessage {
     /// <p>The name of the DB cluster parameter group family to return the engine parameter information for.</p>
     pub db_parameter_group_family: String,
@@ -2964,6 +3076,8 @@ impl DescribeEngineDefaultClusterParametersMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEngineDefaultClusterParametersResult {
     pub engine_defaults: Option<EngineDefaults>,
 }
@@ -2995,6 +3109,8 @@ impl DescribeEngineDefaultClusterParametersResultDeserializer {
 }
 /// <p>Represents the input to <a>DescribeEventCategories</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventCategoriesMessage {
     /// <p>This parameter is not currently supported.</p>
     pub filters: Option<Vec<Filter>>,
@@ -3026,6 +3142,8 @@ impl DescribeEventCategoriesMessageSerializer {
 
 /// <p>Represents the input to <a>DescribeEvents</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventsMessage {
     /// <p>The number of minutes to retrieve events for.</p> <p>Default: 60</p>
     pub duration: Option<i64>,
@@ -3096,6 +3214,8 @@ impl DescribeEventsMessageSerializer {
 
 /// <p>Represents the input to <a>DescribeOrderableDBInstanceOptions</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeOrderableDBInstanceOptionsMessage {
     /// <p>The DB instance class filter value. Specify this parameter to show only the available offerings that match the specified DB instance class.</p>
     pub db_instance_class: Option<String>,
@@ -3155,6 +3275,8 @@ impl DescribeOrderableDBInstanceOptionsMessageSerializer {
 
 /// <p>Represents the input to <a>DescribePendingMaintenanceActions</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePendingMaintenanceActionsMessage {
     /// <p><p>A filter that specifies one or more resources to return pending maintenance actions for.</p> <p>Supported filters:</p> <ul> <li> <p> <code>db-cluster-id</code> - Accepts DB cluster identifiers and DB cluster Amazon Resource Names (ARNs). The results list includes only pending maintenance actions for the DB clusters identified by these ARNs.</p> </li> <li> <p> <code>db-instance-id</code> - Accepts DB instance identifiers and DB instance ARNs. The results list includes only pending maintenance actions for the DB instances identified by these ARNs.</p> </li> </ul></p>
     pub filters: Option<Vec<Filter>>,
@@ -3196,6 +3318,8 @@ impl DescribePendingMaintenanceActionsMessageSerializer {
 
 /// <p>Network information for accessing a DB cluster or DB instance. Client programs must specify a valid endpoint to access these Amazon DocumentDB resources.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Endpoint {
     /// <p>Specifies the DNS address of the DB instance.</p>
     pub address: Option<String>,
@@ -3232,6 +3356,8 @@ impl EndpointDeserializer {
 }
 /// <p>Contains the result of a successful invocation of the <code>DescribeEngineDefaultClusterParameters</code> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EngineDefaults {
     /// <p>The name of the DB cluster parameter group family to return the engine parameter information for.</p>
     pub db_parameter_group_family: Option<String>,
@@ -3272,6 +3398,8 @@ impl EngineDefaultsDeserializer {
 }
 /// <p>Detailed information about an event.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Event {
     /// <p>Specifies the date and time of the event.</p>
     pub date: Option<String>,
@@ -3352,6 +3480,8 @@ impl EventCategoriesListSerializer {
 
 /// <p>An event source type, accompanied by one or more event category names.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventCategoriesMap {
     /// <p>The event categories for the specified source type.</p>
     pub event_categories: Option<Vec<String>>,
@@ -3404,6 +3534,8 @@ impl EventCategoriesMapListDeserializer {
 }
 /// <p>Represents the output of <a>DescribeEventCategories</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventCategoriesMessage {
     /// <p>A list of event category maps.</p>
     pub event_categories_map_list: Option<Vec<EventCategoriesMap>>,
@@ -3451,6 +3583,8 @@ impl EventListDeserializer {
 }
 /// <p>Represents the output of <a>DescribeEvents</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventsMessage {
     /// <p>Detailed information about one or more events. </p>
     pub events: Option<Vec<Event>>,
@@ -3483,6 +3617,8 @@ impl EventsMessageDeserializer {
 }
 /// <p>Represents the input to <a>FailoverDBCluster</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FailoverDBClusterMessage {
     /// <p><p>A DB cluster identifier to force a failover for. This parameter is not case sensitive.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing <code>DBCluster</code>.</p> </li> </ul></p>
     pub db_cluster_identifier: Option<String>,
@@ -3515,6 +3651,8 @@ impl FailoverDBClusterMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FailoverDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
@@ -3544,6 +3682,8 @@ impl FailoverDBClusterResultDeserializer {
 }
 /// <p>A named set of filter values, used to return a more specific list of results. You can use a filter to match a set of resources by specific criteria, such as IDs.</p> <p>Wildcards are not supported in filters.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filter {
     /// <p>The name of the filter. Filter names are case sensitive.</p>
     pub name: String,
@@ -3596,7 +3736,7 @@ impl IntegerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3607,7 +3747,7 @@ impl IntegerOptionalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3627,6 +3767,8 @@ impl KeyListSerializer {
 
 /// <p>Represents the input to <a>ListTagsForResource</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceMessage {
     /// <p>This parameter is not currently supported.</p>
     pub filters: Option<Vec<Filter>>,
@@ -3685,6 +3827,8 @@ impl LogTypeListSerializer {
 
 /// <p>Represents the input to <a>ModifyDBCluster</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyDBClusterMessage {
     /// <p>A value that specifies whether the changes in this request and any pending changes are asynchronously applied as soon as possible, regardless of the <code>PreferredMaintenanceWindow</code> setting for the DB cluster. If this parameter is set to <code>false</code>, changes to the DB cluster are applied during the next maintenance window.</p> <p>The <code>ApplyImmediately</code> parameter affects only the <code>NewDBClusterIdentifier</code> and <code>MasterUserPassword</code> values. If you set this parameter value to <code>false</code>, the changes to the <code>NewDBClusterIdentifier</code> and <code>MasterUserPassword</code> values are applied during the next maintenance window. All other changes are applied immediately, regardless of the value of the <code>ApplyImmediately</code> parameter.</p> <p>Default: <code>false</code> </p>
     pub apply_immediately: Option<bool>,
@@ -3791,6 +3935,8 @@ impl ModifyDBClusterMessageSerializer {
 
 /// <p>Represents the input to <a>ModifyDBClusterParameterGroup</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyDBClusterParameterGroupMessage {
     /// <p>The name of the DB cluster parameter group to modify.</p>
     pub db_cluster_parameter_group_name: String,
@@ -3820,6 +3966,8 @@ impl ModifyDBClusterParameterGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
@@ -3844,6 +3992,8 @@ impl ModifyDBClusterResultDeserializer {
 }
 /// <p>Represents the input to <a>ModifyDBClusterSnapshotAttribute</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyDBClusterSnapshotAttributeMessage {
     /// <p>The name of the DB cluster snapshot attribute to modify.</p> <p>To manage authorization for other AWS accounts to copy or restore a manual DB cluster snapshot, set this value to <code>restore</code>.</p>
     pub attribute_name: String,
@@ -3890,6 +4040,8 @@ impl ModifyDBClusterSnapshotAttributeMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyDBClusterSnapshotAttributeResult {
     pub db_cluster_snapshot_attributes_result: Option<DBClusterSnapshotAttributesResult>,
 }
@@ -3922,6 +4074,8 @@ impl ModifyDBClusterSnapshotAttributeResultDeserializer {
 }
 /// <p>Represents the input to <a>ModifyDBInstance</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyDBInstanceMessage {
     /// <p>Specifies whether the modifications in this request and any pending modifications are asynchronously applied as soon as possible, regardless of the <code>PreferredMaintenanceWindow</code> setting for the DB instance. </p> <p> If this parameter is set to <code>false</code>, changes to the DB instance are applied during the next maintenance window. Some parameter changes can cause an outage and are applied on the next reboot.</p> <p>Default: <code>false</code> </p>
     pub apply_immediately: Option<bool>,
@@ -3983,6 +4137,8 @@ impl ModifyDBInstanceMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
@@ -4008,6 +4164,8 @@ impl ModifyDBInstanceResultDeserializer {
 }
 /// <p>Represents the input to <a>ModifyDBSubnetGroup</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyDBSubnetGroupMessage {
     /// <p>The description for the DB subnet group.</p>
     pub db_subnet_group_description: Option<String>,
@@ -4045,6 +4203,8 @@ impl ModifyDBSubnetGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyDBSubnetGroupResult {
     pub db_subnet_group: Option<DBSubnetGroup>,
 }
@@ -4076,6 +4236,8 @@ impl ModifyDBSubnetGroupResultDeserializer {
 }
 /// <p>The options that are available for a DB instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OrderableDBInstanceOption {
     /// <p>A list of Availability Zones for a DB instance.</p>
     pub availability_zones: Option<Vec<AvailabilityZone>>,
@@ -4158,6 +4320,8 @@ impl OrderableDBInstanceOptionsListDeserializer {
 }
 /// <p>Represents the output of <a>DescribeOrderableDBInstanceOptions</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OrderableDBInstanceOptionsMessage {
     /// <p>An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
@@ -4197,6 +4361,8 @@ impl OrderableDBInstanceOptionsMessageDeserializer {
 }
 /// <p>Detailed information about an individual parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Parameter {
     /// <p>Specifies the valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
@@ -4350,6 +4516,8 @@ impl ParametersListSerializer {
 
 /// <p>A list of the log types whose configuration is still pending. These log types are in the process of being activated or deactivated.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PendingCloudwatchLogsExports {
     /// <p>Log types that are in the process of being enabled. After they are enabled, these log types are exported to Amazon CloudWatch Logs.</p>
     pub log_types_to_disable: Option<Vec<String>>,
@@ -4388,6 +4556,8 @@ impl PendingCloudwatchLogsExportsDeserializer {
 }
 /// <p>Provides information about a pending maintenance action for a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PendingMaintenanceAction {
     /// <p>The type of pending maintenance action that is available for the resource.</p>
     pub action: Option<String>,
@@ -4489,6 +4659,8 @@ impl PendingMaintenanceActionsDeserializer {
 }
 /// <p>Represents the output of <a>DescribePendingMaintenanceActions</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PendingMaintenanceActionsMessage {
     /// <p>An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
@@ -4528,6 +4700,8 @@ impl PendingMaintenanceActionsMessageDeserializer {
 }
 /// <p> One or more modified settings for a DB instance. These modified settings have been requested, but haven't been applied yet.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PendingModifiedValues {
     /// <p> Contains the new <code>AllocatedStorage</code> size for the DB instance that will be applied or is currently being applied. </p>
     pub allocated_storage: Option<i64>,
@@ -4642,6 +4816,8 @@ impl PendingModifiedValuesDeserializer {
 }
 /// <p>Represents the input to <a>RebootDBInstance</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootDBInstanceMessage {
     /// <p><p>The DB instance identifier. This parameter is stored as a lowercase string.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing <code>DBInstance</code>.</p> </li> </ul></p>
     pub db_instance_identifier: String,
@@ -4669,6 +4845,8 @@ impl RebootDBInstanceMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
@@ -4694,6 +4872,8 @@ impl RebootDBInstanceResultDeserializer {
 }
 /// <p>Represents the input to <a>RemoveTagsFromResource</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromResourceMessage {
     /// <p>The Amazon DocumentDB resource that the tags are removed from. This value is an Amazon Resource Name (ARN).</p>
     pub resource_name: String,
@@ -4717,6 +4897,8 @@ impl RemoveTagsFromResourceMessageSerializer {
 
 /// <p>Represents the input to <a>ResetDBClusterParameterGroup</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResetDBClusterParameterGroupMessage {
     /// <p>The name of the DB cluster parameter group to reset.</p>
     pub db_cluster_parameter_group_name: String,
@@ -4754,6 +4936,8 @@ impl ResetDBClusterParameterGroupMessageSerializer {
 
 /// <p>Represents the output of <a>ApplyPendingMaintenanceAction</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourcePendingMaintenanceActions {
     /// <p>A list that provides details about the pending maintenance actions for the resource.</p>
     pub pending_maintenance_action_details: Option<Vec<PendingMaintenanceAction>>,
@@ -4796,6 +4980,8 @@ impl ResourcePendingMaintenanceActionsDeserializer {
 }
 /// <p>Represents the input to <a>RestoreDBClusterFromSnapshot</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreDBClusterFromSnapshotMessage {
     /// <p>Provides the list of Amazon EC2 Availability Zones that instances in the restored DB cluster can be created in.</p>
     pub availability_zones: Option<Vec<String>>,
@@ -4884,6 +5070,8 @@ impl RestoreDBClusterFromSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreDBClusterFromSnapshotResult {
     pub db_cluster: Option<DBCluster>,
 }
@@ -4913,6 +5101,8 @@ impl RestoreDBClusterFromSnapshotResultDeserializer {
 }
 /// <p>Represents the input to <a>RestoreDBClusterToPointInTime</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreDBClusterToPointInTimeMessage {
     /// <p><p>The name of the new DB cluster to be created.</p> <p>Constraints:</p> <ul> <li> <p>Must contain from 1 to 63 letters, numbers, or hyphens.</p> </li> <li> <p>The first character must be a letter.</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens.</p> </li> </ul></p>
     pub db_cluster_identifier: String,
@@ -4997,6 +5187,8 @@ impl RestoreDBClusterToPointInTimeMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreDBClusterToPointInTimeResult {
     pub db_cluster: Option<DBCluster>,
 }
@@ -5036,6 +5228,8 @@ impl SourceTypeDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartDBClusterMessage {
     /// <p>The identifier of the cluster to restart. Example: <code>docdb-2019-05-28-15-24-52</code> </p>
     pub db_cluster_identifier: String,
@@ -5058,6 +5252,8 @@ impl StartDBClusterMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
@@ -5081,6 +5277,8 @@ impl StartDBClusterResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopDBClusterMessage {
     /// <p>The identifier of the cluster to stop. Example: <code>docdb-2019-05-28-15-24-52</code> </p>
     pub db_cluster_identifier: String,
@@ -5103,6 +5301,8 @@ impl StopDBClusterMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
@@ -5138,6 +5338,8 @@ impl StringDeserializer {
 }
 /// <p> Detailed information about a subnet. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Subnet {
     /// <p>Specifies the Availability Zone for the subnet.</p>
     pub subnet_availability_zone: Option<AvailabilityZone>,
@@ -5215,6 +5417,8 @@ impl TStampDeserializer {
 }
 /// <p>Metadata assigned to an Amazon DocumentDB resource consisting of a key-value pair.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>The required name of the tag. The string value can be from 1 to 128 Unicode characters in length and can't be prefixed with "aws:" or "rds:". The string can contain only the set of Unicode letters, digits, white space, '_', '.', '/', '=', '+', '-' (Java regex: "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-]*)$").</p>
     pub key: Option<String>,
@@ -5290,6 +5494,8 @@ impl TagListSerializer {
 
 /// <p>Represents the output of <a>ListTagsForResource</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagListMessage {
     /// <p>A list of one or more tags.</p>
     pub tag_list: Option<Vec<Tag>>,
@@ -5317,6 +5523,8 @@ impl TagListMessageDeserializer {
 }
 /// <p>The version of the database engine that a DB instance can be upgraded to.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpgradeTarget {
     /// <p>A value that indicates whether the target version is applied to any source DB instances that have <code>AutoMinorVersionUpgrade</code> set to <code>true</code>.</p>
     pub auto_upgrade: Option<bool>,
@@ -5399,6 +5607,8 @@ impl VpcSecurityGroupIdListSerializer {
 
 /// <p>Used as a response element for queries on virtual private cloud (VPC) security group membership.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VpcSecurityGroupMembership {
     /// <p>The status of the VPC security group.</p>
     pub status: Option<String>,
@@ -8881,765 +9091,104 @@ pub trait Docdb {
         input: StopDBClusterMessage,
     ) -> RusotoFuture<StopDBClusterResult, StopDBClusterError>;
 }
-/// A client for the Amazon DocDB API.
-#[derive(Clone)]
-pub struct DocdbClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl DocdbClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> DocdbClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> DocdbClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> DocdbClient {
-        DocdbClient { client, region }
-    }
-}
-
-impl Docdb for DocdbClient {
-    /// <p>Adds metadata tags to an Amazon DocumentDB resource. You can use these tags with cost allocation reporting to track costs that are associated with Amazon DocumentDB resources. or in a <code>Condition</code> statement in an AWS Identity and Access Management (IAM) policy for Amazon DocumentDB.</p>
+const _: Option<&dyn Docdb> = None;
+impl<'a, T: Docdb + ?Sized> Docdb for &'a T {
     fn add_tags_to_resource(
         &self,
         input: AddTagsToResourceMessage,
     ) -> RusotoFuture<(), AddTagsToResourceError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddTagsToResource");
-        params.put("Version", "2014-10-31");
-        AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).add_tags_to_resource(input)
     }
-
-    /// <p>Applies a pending maintenance action to a resource (for example, to a DB instance).</p>
     fn apply_pending_maintenance_action(
         &self,
         input: ApplyPendingMaintenanceActionMessage,
     ) -> RusotoFuture<ApplyPendingMaintenanceActionResult, ApplyPendingMaintenanceActionError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ApplyPendingMaintenanceAction");
-        params.put("Version", "2014-10-31");
-        ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ApplyPendingMaintenanceActionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ApplyPendingMaintenanceActionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ApplyPendingMaintenanceActionResultDeserializer::deserialize(
-                        "ApplyPendingMaintenanceActionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).apply_pending_maintenance_action(input)
     }
-
-    /// <p>Copies the specified DB cluster parameter group.</p>
     fn copy_db_cluster_parameter_group(
         &self,
         input: CopyDBClusterParameterGroupMessage,
     ) -> RusotoFuture<CopyDBClusterParameterGroupResult, CopyDBClusterParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CopyDBClusterParameterGroup");
-        params.put("Version", "2014-10-31");
-        CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CopyDBClusterParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CopyDBClusterParameterGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CopyDBClusterParameterGroupResultDeserializer::deserialize(
-                        "CopyDBClusterParameterGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).copy_db_cluster_parameter_group(input)
     }
-
-    /// <p>Copies a snapshot of a DB cluster.</p> <p>To copy a DB cluster snapshot from a shared manual DB cluster snapshot, <code>SourceDBClusterSnapshotIdentifier</code> must be the Amazon Resource Name (ARN) of the shared DB cluster snapshot.</p> <p>To cancel the copy operation after it is in progress, delete the target DB cluster snapshot identified by <code>TargetDBClusterSnapshotIdentifier</code> while that DB cluster snapshot is in the <i>copying</i> status.</p>
     fn copy_db_cluster_snapshot(
         &self,
         input: CopyDBClusterSnapshotMessage,
     ) -> RusotoFuture<CopyDBClusterSnapshotResult, CopyDBClusterSnapshotError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CopyDBClusterSnapshot");
-        params.put("Version", "2014-10-31");
-        CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CopyDBClusterSnapshotError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CopyDBClusterSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CopyDBClusterSnapshotResultDeserializer::deserialize(
-                        "CopyDBClusterSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).copy_db_cluster_snapshot(input)
     }
-
-    /// <p>Creates a new Amazon DocumentDB DB cluster.</p>
     fn create_db_cluster(
         &self,
         input: CreateDBClusterMessage,
     ) -> RusotoFuture<CreateDBClusterResult, CreateDBClusterError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateDBCluster");
-        params.put("Version", "2014-10-31");
-        CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDBClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateDBClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateDBClusterResultDeserializer::deserialize(
-                        "CreateDBClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_db_cluster(input)
     }
-
-    /// <p><p>Creates a new DB cluster parameter group.</p> <p>Parameters in a DB cluster parameter group apply to all of the instances in a DB cluster.</p> <p>A DB cluster parameter group is initially created with the default parameters for the database engine used by instances in the DB cluster. To provide custom values for any of the parameters, you must modify the group after you create it. After you create a DB cluster parameter group, you must associate it with your DB cluster. For the new DB cluster parameter group and associated settings to take effect, you must then reboot the DB instances in the DB cluster without failover.</p> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon DocumentDB to fully complete the create action before the DB cluster parameter group is used as the default for a new DB cluster. This step is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter.</p> </important></p>
     fn create_db_cluster_parameter_group(
         &self,
         input: CreateDBClusterParameterGroupMessage,
     ) -> RusotoFuture<CreateDBClusterParameterGroupResult, CreateDBClusterParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateDBClusterParameterGroup");
-        params.put("Version", "2014-10-31");
-        CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDBClusterParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateDBClusterParameterGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateDBClusterParameterGroupResultDeserializer::deserialize(
-                        "CreateDBClusterParameterGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_db_cluster_parameter_group(input)
     }
-
-    /// <p>Creates a snapshot of a DB cluster. </p>
     fn create_db_cluster_snapshot(
         &self,
         input: CreateDBClusterSnapshotMessage,
     ) -> RusotoFuture<CreateDBClusterSnapshotResult, CreateDBClusterSnapshotError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateDBClusterSnapshot");
-        params.put("Version", "2014-10-31");
-        CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDBClusterSnapshotError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateDBClusterSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateDBClusterSnapshotResultDeserializer::deserialize(
-                        "CreateDBClusterSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_db_cluster_snapshot(input)
     }
-
-    /// <p>Creates a new DB instance.</p>
     fn create_db_instance(
         &self,
         input: CreateDBInstanceMessage,
     ) -> RusotoFuture<CreateDBInstanceResult, CreateDBInstanceError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateDBInstance");
-        params.put("Version", "2014-10-31");
-        CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDBInstanceError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateDBInstanceResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateDBInstanceResultDeserializer::deserialize(
-                        "CreateDBInstanceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_db_instance(input)
     }
-
-    /// <p>Creates a new DB subnet group. DB subnet groups must contain at least one subnet in at least two Availability Zones in the AWS Region.</p>
     fn create_db_subnet_group(
         &self,
         input: CreateDBSubnetGroupMessage,
     ) -> RusotoFuture<CreateDBSubnetGroupResult, CreateDBSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateDBSubnetGroup");
-        params.put("Version", "2014-10-31");
-        CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateDBSubnetGroupError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateDBSubnetGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateDBSubnetGroupResultDeserializer::deserialize(
-                        "CreateDBSubnetGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_db_subnet_group(input)
     }
-
-    /// <p><p>Deletes a previously provisioned DB cluster. When you delete a DB cluster, all automated backups for that DB cluster are deleted and can&#39;t be recovered. Manual DB cluster snapshots of the specified DB cluster are not deleted.</p> <p/></p>
     fn delete_db_cluster(
         &self,
         input: DeleteDBClusterMessage,
     ) -> RusotoFuture<DeleteDBClusterResult, DeleteDBClusterError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteDBCluster");
-        params.put("Version", "2014-10-31");
-        DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDBClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteDBClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteDBClusterResultDeserializer::deserialize(
-                        "DeleteDBClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_db_cluster(input)
     }
-
-    /// <p>Deletes a specified DB cluster parameter group. The DB cluster parameter group to be deleted can't be associated with any DB clusters.</p>
     fn delete_db_cluster_parameter_group(
         &self,
         input: DeleteDBClusterParameterGroupMessage,
     ) -> RusotoFuture<(), DeleteDBClusterParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteDBClusterParameterGroup");
-        params.put("Version", "2014-10-31");
-        DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteDBClusterParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_db_cluster_parameter_group(input)
     }
-
-    /// <p><p>Deletes a DB cluster snapshot. If the snapshot is being copied, the copy operation is terminated.</p> <note> <p>The DB cluster snapshot must be in the <code>available</code> state to be deleted.</p> </note></p>
     fn delete_db_cluster_snapshot(
         &self,
         input: DeleteDBClusterSnapshotMessage,
     ) -> RusotoFuture<DeleteDBClusterSnapshotResult, DeleteDBClusterSnapshotError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteDBClusterSnapshot");
-        params.put("Version", "2014-10-31");
-        DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteDBClusterSnapshotError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteDBClusterSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteDBClusterSnapshotResultDeserializer::deserialize(
-                        "DeleteDBClusterSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_db_cluster_snapshot(input)
     }
-
-    /// <p>Deletes a previously provisioned DB instance. </p>
     fn delete_db_instance(
         &self,
         input: DeleteDBInstanceMessage,
     ) -> RusotoFuture<DeleteDBInstanceResult, DeleteDBInstanceError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteDBInstance");
-        params.put("Version", "2014-10-31");
-        DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDBInstanceError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteDBInstanceResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteDBInstanceResultDeserializer::deserialize(
-                        "DeleteDBInstanceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_db_instance(input)
     }
-
-    /// <p><p>Deletes a DB subnet group.</p> <note> <p>The specified database subnet group must not be associated with any DB instances.</p> </note></p>
     fn delete_db_subnet_group(
         &self,
         input: DeleteDBSubnetGroupMessage,
     ) -> RusotoFuture<(), DeleteDBSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteDBSubnetGroup");
-        params.put("Version", "2014-10-31");
-        DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteDBSubnetGroupError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_db_subnet_group(input)
     }
-
-    /// <p>Returns a list of <code>DBClusterParameterGroup</code> descriptions. If a <code>DBClusterParameterGroupName</code> parameter is specified, the list contains only the description of the specified DB cluster parameter group. </p>
     fn describe_db_cluster_parameter_groups(
         &self,
         input: DescribeDBClusterParameterGroupsMessage,
     ) -> RusotoFuture<DBClusterParameterGroupsMessage, DescribeDBClusterParameterGroupsError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeDBClusterParameterGroups");
-        params.put("Version", "2014-10-31");
-        DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDBClusterParameterGroupsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DBClusterParameterGroupsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DBClusterParameterGroupsMessageDeserializer::deserialize(
-                        "DescribeDBClusterParameterGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_db_cluster_parameter_groups(input)
     }
-
-    /// <p>Returns the detailed parameter list for a particular DB cluster parameter group.</p>
     fn describe_db_cluster_parameters(
         &self,
         input: DescribeDBClusterParametersMessage,
     ) -> RusotoFuture<DBClusterParameterGroupDetails, DescribeDBClusterParametersError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeDBClusterParameters");
-        params.put("Version", "2014-10-31");
-        DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDBClusterParametersError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DBClusterParameterGroupDetails::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DBClusterParameterGroupDetailsDeserializer::deserialize(
-                        "DescribeDBClusterParametersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_db_cluster_parameters(input)
     }
-
-    /// <p>Returns a list of DB cluster snapshot attribute names and values for a manual DB cluster snapshot.</p> <p>When you share snapshots with other AWS accounts, <code>DescribeDBClusterSnapshotAttributes</code> returns the <code>restore</code> attribute and a list of IDs for the AWS accounts that are authorized to copy or restore the manual DB cluster snapshot. If <code>all</code> is included in the list of values for the <code>restore</code> attribute, then the manual DB cluster snapshot is public and can be copied or restored by all AWS accounts.</p>
     fn describe_db_cluster_snapshot_attributes(
         &self,
         input: DescribeDBClusterSnapshotAttributesMessage,
@@ -9647,297 +9196,38 @@ impl Docdb for DocdbClient {
         DescribeDBClusterSnapshotAttributesResult,
         DescribeDBClusterSnapshotAttributesError,
     > {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeDBClusterSnapshotAttributes");
-        params.put("Version", "2014-10-31");
-        DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDBClusterSnapshotAttributesError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeDBClusterSnapshotAttributesResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeDBClusterSnapshotAttributesResultDeserializer::deserialize(
-                        "DescribeDBClusterSnapshotAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_db_cluster_snapshot_attributes(input)
     }
-
-    /// <p>Returns information about DB cluster snapshots. This API operation supports pagination.</p>
     fn describe_db_cluster_snapshots(
         &self,
         input: DescribeDBClusterSnapshotsMessage,
     ) -> RusotoFuture<DBClusterSnapshotMessage, DescribeDBClusterSnapshotsError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeDBClusterSnapshots");
-        params.put("Version", "2014-10-31");
-        DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDBClusterSnapshotsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DBClusterSnapshotMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DBClusterSnapshotMessageDeserializer::deserialize(
-                        "DescribeDBClusterSnapshotsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_db_cluster_snapshots(input)
     }
-
-    /// <p>Returns information about provisioned Amazon DocumentDB DB clusters. This API operation supports pagination.</p>
     fn describe_db_clusters(
         &self,
         input: DescribeDBClustersMessage,
     ) -> RusotoFuture<DBClusterMessage, DescribeDBClustersError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeDBClusters");
-        params.put("Version", "2014-10-31");
-        DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeDBClustersError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DBClusterMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DBClusterMessageDeserializer::deserialize(
-                        "DescribeDBClustersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_db_clusters(input)
     }
-
-    /// <p>Returns a list of the available DB engines.</p>
     fn describe_db_engine_versions(
         &self,
         input: DescribeDBEngineVersionsMessage,
     ) -> RusotoFuture<DBEngineVersionMessage, DescribeDBEngineVersionsError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeDBEngineVersions");
-        params.put("Version", "2014-10-31");
-        DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDBEngineVersionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DBEngineVersionMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DBEngineVersionMessageDeserializer::deserialize(
-                        "DescribeDBEngineVersionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_db_engine_versions(input)
     }
-
-    /// <p>Returns information about provisioned Amazon DocumentDB instances. This API supports pagination.</p>
     fn describe_db_instances(
         &self,
         input: DescribeDBInstancesMessage,
     ) -> RusotoFuture<DBInstanceMessage, DescribeDBInstancesError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeDBInstances");
-        params.put("Version", "2014-10-31");
-        DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeDBInstancesError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DBInstanceMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DBInstanceMessageDeserializer::deserialize(
-                        "DescribeDBInstancesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_db_instances(input)
     }
-
-    /// <p>Returns a list of <code>DBSubnetGroup</code> descriptions. If a <code>DBSubnetGroupName</code> is specified, the list will contain only the descriptions of the specified <code>DBSubnetGroup</code>.</p>
     fn describe_db_subnet_groups(
         &self,
         input: DescribeDBSubnetGroupsMessage,
     ) -> RusotoFuture<DBSubnetGroupMessage, DescribeDBSubnetGroupsError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeDBSubnetGroups");
-        params.put("Version", "2014-10-31");
-        DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDBSubnetGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DBSubnetGroupMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DBSubnetGroupMessageDeserializer::deserialize(
-                        "DescribeDBSubnetGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_db_subnet_groups(input)
     }
-
-    /// <p>Returns the default engine and system parameter information for the cluster database engine.</p>
     fn describe_engine_default_cluster_parameters(
         &self,
         input: DescribeEngineDefaultClusterParametersMessage,
@@ -9945,921 +9235,4219 @@ impl Docdb for DocdbClient {
         DescribeEngineDefaultClusterParametersResult,
         DescribeEngineDefaultClusterParametersError,
     > {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEngineDefaultClusterParameters");
-        params.put("Version", "2014-10-31");
-        DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEngineDefaultClusterParametersError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeEngineDefaultClusterParametersResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeEngineDefaultClusterParametersResultDeserializer::deserialize(
-                        "DescribeEngineDefaultClusterParametersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_engine_default_cluster_parameters(input)
     }
-
-    /// <p>Displays a list of categories for all event source types, or, if specified, for a specified source type. </p>
     fn describe_event_categories(
         &self,
         input: DescribeEventCategoriesMessage,
     ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEventCategories");
-        params.put("Version", "2014-10-31");
-        DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEventCategoriesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EventCategoriesMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EventCategoriesMessageDeserializer::deserialize(
-                        "DescribeEventCategoriesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_event_categories(input)
     }
-
-    /// <p>Returns events related to DB instances, DB security groups, DB snapshots, and DB parameter groups for the past 14 days. You can obtain events specific to a particular DB instance, DB security group, DB snapshot, or DB parameter group by providing the name as a parameter. By default, the events of the past hour are returned.</p>
     fn describe_events(
         &self,
         input: DescribeEventsMessage,
     ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEvents");
-        params.put("Version", "2014-10-31");
-        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeEventsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EventsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        EventsMessageDeserializer::deserialize("DescribeEventsResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_events(input)
     }
-
-    /// <p>Returns a list of orderable DB instance options for the specified engine.</p>
     fn describe_orderable_db_instance_options(
         &self,
         input: DescribeOrderableDBInstanceOptionsMessage,
     ) -> RusotoFuture<OrderableDBInstanceOptionsMessage, DescribeOrderableDBInstanceOptionsError>
     {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeOrderableDBInstanceOptions");
-        params.put("Version", "2014-10-31");
-        DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeOrderableDBInstanceOptionsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = OrderableDBInstanceOptionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = OrderableDBInstanceOptionsMessageDeserializer::deserialize(
-                        "DescribeOrderableDBInstanceOptionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_orderable_db_instance_options(input)
     }
-
-    /// <p>Returns a list of resources (for example, DB instances) that have at least one pending maintenance action.</p>
     fn describe_pending_maintenance_actions(
         &self,
         input: DescribePendingMaintenanceActionsMessage,
     ) -> RusotoFuture<PendingMaintenanceActionsMessage, DescribePendingMaintenanceActionsError>
     {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribePendingMaintenanceActions");
-        params.put("Version", "2014-10-31");
-        DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribePendingMaintenanceActionsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = PendingMaintenanceActionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = PendingMaintenanceActionsMessageDeserializer::deserialize(
-                        "DescribePendingMaintenanceActionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_pending_maintenance_actions(input)
     }
-
-    /// <p>Forces a failover for a DB cluster.</p> <p>A failover for a DB cluster promotes one of the Amazon DocumentDB replicas (read-only instances) in the DB cluster to be the primary instance (the cluster writer).</p> <p>If the primary instance fails, Amazon DocumentDB automatically fails over to an Amazon DocumentDB replica, if one exists. You can force a failover when you want to simulate a failure of a primary instance for testing.</p>
     fn failover_db_cluster(
         &self,
         input: FailoverDBClusterMessage,
     ) -> RusotoFuture<FailoverDBClusterResult, FailoverDBClusterError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "FailoverDBCluster");
-        params.put("Version", "2014-10-31");
-        FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(FailoverDBClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = FailoverDBClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = FailoverDBClusterResultDeserializer::deserialize(
-                        "FailoverDBClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).failover_db_cluster(input)
     }
-
-    /// <p>Lists all tags on an Amazon DocumentDB resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceMessage,
     ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListTagsForResource");
-        params.put("Version", "2014-10-31");
-        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TagListMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TagListMessageDeserializer::deserialize(
-                        "ListTagsForResourceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Modifies a setting for an Amazon DocumentDB DB cluster. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. </p>
     fn modify_db_cluster(
         &self,
         input: ModifyDBClusterMessage,
     ) -> RusotoFuture<ModifyDBClusterResult, ModifyDBClusterError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyDBCluster");
-        params.put("Version", "2014-10-31");
-        ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ModifyDBClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyDBClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyDBClusterResultDeserializer::deserialize(
-                        "ModifyDBClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_db_cluster(input)
     }
-
-    /// <p><p> Modifies the parameters of a DB cluster parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request. </p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot or maintenance window before the change can take effect.</p> </note> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon DocumentDB to fully complete the create action before the parameter group is used as the default for a new DB cluster. This step is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter.</p> </important></p>
     fn modify_db_cluster_parameter_group(
         &self,
         input: ModifyDBClusterParameterGroupMessage,
     ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ModifyDBClusterParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyDBClusterParameterGroup");
-        params.put("Version", "2014-10-31");
-        ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyDBClusterParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DBClusterParameterGroupNameMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DBClusterParameterGroupNameMessageDeserializer::deserialize(
-                        "ModifyDBClusterParameterGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_db_cluster_parameter_group(input)
     }
-
-    /// <p>Adds an attribute and values to, or removes an attribute and values from, a manual DB cluster snapshot.</p> <p>To share a manual DB cluster snapshot with other AWS accounts, specify <code>restore</code> as the <code>AttributeName</code>, and use the <code>ValuesToAdd</code> parameter to add a list of IDs of the AWS accounts that are authorized to restore the manual DB cluster snapshot. Use the value <code>all</code> to make the manual DB cluster snapshot public, which means that it can be copied or restored by all AWS accounts. Do not add the <code>all</code> value for any manual DB cluster snapshots that contain private information that you don't want available to all AWS accounts. If a manual DB cluster snapshot is encrypted, it can be shared, but only by specifying a list of authorized AWS account IDs for the <code>ValuesToAdd</code> parameter. You can't use <code>all</code> as a value for that parameter in this case.</p>
     fn modify_db_cluster_snapshot_attribute(
         &self,
         input: ModifyDBClusterSnapshotAttributeMessage,
     ) -> RusotoFuture<ModifyDBClusterSnapshotAttributeResult, ModifyDBClusterSnapshotAttributeError>
     {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyDBClusterSnapshotAttribute");
-        params.put("Version", "2014-10-31");
-        ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyDBClusterSnapshotAttributeError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyDBClusterSnapshotAttributeResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyDBClusterSnapshotAttributeResultDeserializer::deserialize(
-                        "ModifyDBClusterSnapshotAttributeResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_db_cluster_snapshot_attribute(input)
     }
-
-    /// <p>Modifies settings for a DB instance. You can change one or more database configuration parameters by specifying these parameters and the new values in the request.</p>
     fn modify_db_instance(
         &self,
         input: ModifyDBInstanceMessage,
     ) -> RusotoFuture<ModifyDBInstanceResult, ModifyDBInstanceError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyDBInstance");
-        params.put("Version", "2014-10-31");
-        ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ModifyDBInstanceError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyDBInstanceResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyDBInstanceResultDeserializer::deserialize(
-                        "ModifyDBInstanceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_db_instance(input)
     }
-
-    /// <p>Modifies an existing DB subnet group. DB subnet groups must contain at least one subnet in at least two Availability Zones in the AWS Region.</p>
     fn modify_db_subnet_group(
         &self,
         input: ModifyDBSubnetGroupMessage,
     ) -> RusotoFuture<ModifyDBSubnetGroupResult, ModifyDBSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyDBSubnetGroup");
-        params.put("Version", "2014-10-31");
-        ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ModifyDBSubnetGroupError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyDBSubnetGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyDBSubnetGroupResultDeserializer::deserialize(
-                        "ModifyDBSubnetGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_db_subnet_group(input)
     }
-
-    /// <p>You might need to reboot your DB instance, usually for maintenance reasons. For example, if you make certain changes, or if you change the DB cluster parameter group that is associated with the DB instance, you must reboot the instance for the changes to take effect. </p> <p>Rebooting a DB instance restarts the database engine service. Rebooting a DB instance results in a momentary outage, during which the DB instance status is set to <i>rebooting</i>. </p>
     fn reboot_db_instance(
         &self,
         input: RebootDBInstanceMessage,
     ) -> RusotoFuture<RebootDBInstanceResult, RebootDBInstanceError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RebootDBInstance");
-        params.put("Version", "2014-10-31");
-        RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RebootDBInstanceError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RebootDBInstanceResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RebootDBInstanceResultDeserializer::deserialize(
-                        "RebootDBInstanceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).reboot_db_instance(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<(), RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ResetDBClusterParameterGroupError> {
+        (**self).reset_db_cluster_parameter_group(input)
+    }
+    fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> RusotoFuture<RestoreDBClusterFromSnapshotResult, RestoreDBClusterFromSnapshotError> {
+        (**self).restore_db_cluster_from_snapshot(input)
+    }
+    fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> RusotoFuture<RestoreDBClusterToPointInTimeResult, RestoreDBClusterToPointInTimeError> {
+        (**self).restore_db_cluster_to_point_in_time(input)
+    }
+    fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> RusotoFuture<StartDBClusterResult, StartDBClusterError> {
+        (**self).start_db_cluster(input)
+    }
+    fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> RusotoFuture<StopDBClusterResult, StopDBClusterError> {
+        (**self).stop_db_cluster(input)
+    }
+}
+impl<T: Docdb + ?Sized> Docdb for Box<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<(), AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResult, ApplyPendingMaintenanceActionError> {
+        (**self).apply_pending_maintenance_action(input)
+    }
+    fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CopyDBClusterParameterGroupResult, CopyDBClusterParameterGroupError> {
+        (**self).copy_db_cluster_parameter_group(input)
+    }
+    fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyDBClusterSnapshotResult, CopyDBClusterSnapshotError> {
+        (**self).copy_db_cluster_snapshot(input)
+    }
+    fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> RusotoFuture<CreateDBClusterResult, CreateDBClusterError> {
+        (**self).create_db_cluster(input)
+    }
+    fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateDBClusterParameterGroupResult, CreateDBClusterParameterGroupError> {
+        (**self).create_db_cluster_parameter_group(input)
+    }
+    fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateDBClusterSnapshotResult, CreateDBClusterSnapshotError> {
+        (**self).create_db_cluster_snapshot(input)
+    }
+    fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> RusotoFuture<CreateDBInstanceResult, CreateDBInstanceError> {
+        (**self).create_db_instance(input)
+    }
+    fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> RusotoFuture<CreateDBSubnetGroupResult, CreateDBSubnetGroupError> {
+        (**self).create_db_subnet_group(input)
+    }
+    fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> RusotoFuture<DeleteDBClusterResult, DeleteDBClusterError> {
+        (**self).delete_db_cluster(input)
+    }
+    fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBClusterParameterGroupError> {
+        (**self).delete_db_cluster_parameter_group(input)
+    }
+    fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteDBClusterSnapshotResult, DeleteDBClusterSnapshotError> {
+        (**self).delete_db_cluster_snapshot(input)
+    }
+    fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> RusotoFuture<DeleteDBInstanceResult, DeleteDBInstanceError> {
+        (**self).delete_db_instance(input)
+    }
+    fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBSubnetGroupError> {
+        (**self).delete_db_subnet_group(input)
+    }
+    fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupsMessage, DescribeDBClusterParameterGroupsError> {
+        (**self).describe_db_cluster_parameter_groups(input)
+    }
+    fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupDetails, DescribeDBClusterParametersError> {
+        (**self).describe_db_cluster_parameters(input)
+    }
+    fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> RusotoFuture<
+        DescribeDBClusterSnapshotAttributesResult,
+        DescribeDBClusterSnapshotAttributesError,
+    > {
+        (**self).describe_db_cluster_snapshot_attributes(input)
+    }
+    fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> RusotoFuture<DBClusterSnapshotMessage, DescribeDBClusterSnapshotsError> {
+        (**self).describe_db_cluster_snapshots(input)
+    }
+    fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> RusotoFuture<DBClusterMessage, DescribeDBClustersError> {
+        (**self).describe_db_clusters(input)
+    }
+    fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> RusotoFuture<DBEngineVersionMessage, DescribeDBEngineVersionsError> {
+        (**self).describe_db_engine_versions(input)
+    }
+    fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> RusotoFuture<DBInstanceMessage, DescribeDBInstancesError> {
+        (**self).describe_db_instances(input)
+    }
+    fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> RusotoFuture<DBSubnetGroupMessage, DescribeDBSubnetGroupsError> {
+        (**self).describe_db_subnet_groups(input)
+    }
+    fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> RusotoFuture<
+        DescribeEngineDefaultClusterParametersResult,
+        DescribeEngineDefaultClusterParametersError,
+    > {
+        (**self).describe_engine_default_cluster_parameters(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> RusotoFuture<OrderableDBInstanceOptionsMessage, DescribeOrderableDBInstanceOptionsError>
+    {
+        (**self).describe_orderable_db_instance_options(input)
+    }
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<PendingMaintenanceActionsMessage, DescribePendingMaintenanceActionsError>
+    {
+        (**self).describe_pending_maintenance_actions(input)
+    }
+    fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> RusotoFuture<FailoverDBClusterResult, FailoverDBClusterError> {
+        (**self).failover_db_cluster(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> RusotoFuture<ModifyDBClusterResult, ModifyDBClusterError> {
+        (**self).modify_db_cluster(input)
+    }
+    fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ModifyDBClusterParameterGroupError> {
+        (**self).modify_db_cluster_parameter_group(input)
+    }
+    fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> RusotoFuture<ModifyDBClusterSnapshotAttributeResult, ModifyDBClusterSnapshotAttributeError>
+    {
+        (**self).modify_db_cluster_snapshot_attribute(input)
+    }
+    fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> RusotoFuture<ModifyDBInstanceResult, ModifyDBInstanceError> {
+        (**self).modify_db_instance(input)
+    }
+    fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyDBSubnetGroupResult, ModifyDBSubnetGroupError> {
+        (**self).modify_db_subnet_group(input)
+    }
+    fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> RusotoFuture<RebootDBInstanceResult, RebootDBInstanceError> {
+        (**self).reboot_db_instance(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<(), RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ResetDBClusterParameterGroupError> {
+        (**self).reset_db_cluster_parameter_group(input)
+    }
+    fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> RusotoFuture<RestoreDBClusterFromSnapshotResult, RestoreDBClusterFromSnapshotError> {
+        (**self).restore_db_cluster_from_snapshot(input)
+    }
+    fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> RusotoFuture<RestoreDBClusterToPointInTimeResult, RestoreDBClusterToPointInTimeError> {
+        (**self).restore_db_cluster_to_point_in_time(input)
+    }
+    fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> RusotoFuture<StartDBClusterResult, StartDBClusterError> {
+        (**self).start_db_cluster(input)
+    }
+    fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> RusotoFuture<StopDBClusterResult, StopDBClusterError> {
+        (**self).stop_db_cluster(input)
+    }
+}
+impl<T: Docdb + ?Sized> Docdb for ::std::sync::Arc<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<(), AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResult, ApplyPendingMaintenanceActionError> {
+        (**self).apply_pending_maintenance_action(input)
+    }
+    fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CopyDBClusterParameterGroupResult, CopyDBClusterParameterGroupError> {
+        (**self).copy_db_cluster_parameter_group(input)
+    }
+    fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyDBClusterSnapshotResult, CopyDBClusterSnapshotError> {
+        (**self).copy_db_cluster_snapshot(input)
+    }
+    fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> RusotoFuture<CreateDBClusterResult, CreateDBClusterError> {
+        (**self).create_db_cluster(input)
+    }
+    fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateDBClusterParameterGroupResult, CreateDBClusterParameterGroupError> {
+        (**self).create_db_cluster_parameter_group(input)
+    }
+    fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateDBClusterSnapshotResult, CreateDBClusterSnapshotError> {
+        (**self).create_db_cluster_snapshot(input)
+    }
+    fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> RusotoFuture<CreateDBInstanceResult, CreateDBInstanceError> {
+        (**self).create_db_instance(input)
+    }
+    fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> RusotoFuture<CreateDBSubnetGroupResult, CreateDBSubnetGroupError> {
+        (**self).create_db_subnet_group(input)
+    }
+    fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> RusotoFuture<DeleteDBClusterResult, DeleteDBClusterError> {
+        (**self).delete_db_cluster(input)
+    }
+    fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBClusterParameterGroupError> {
+        (**self).delete_db_cluster_parameter_group(input)
+    }
+    fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteDBClusterSnapshotResult, DeleteDBClusterSnapshotError> {
+        (**self).delete_db_cluster_snapshot(input)
+    }
+    fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> RusotoFuture<DeleteDBInstanceResult, DeleteDBInstanceError> {
+        (**self).delete_db_instance(input)
+    }
+    fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBSubnetGroupError> {
+        (**self).delete_db_subnet_group(input)
+    }
+    fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupsMessage, DescribeDBClusterParameterGroupsError> {
+        (**self).describe_db_cluster_parameter_groups(input)
+    }
+    fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupDetails, DescribeDBClusterParametersError> {
+        (**self).describe_db_cluster_parameters(input)
+    }
+    fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> RusotoFuture<
+        DescribeDBClusterSnapshotAttributesResult,
+        DescribeDBClusterSnapshotAttributesError,
+    > {
+        (**self).describe_db_cluster_snapshot_attributes(input)
+    }
+    fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> RusotoFuture<DBClusterSnapshotMessage, DescribeDBClusterSnapshotsError> {
+        (**self).describe_db_cluster_snapshots(input)
+    }
+    fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> RusotoFuture<DBClusterMessage, DescribeDBClustersError> {
+        (**self).describe_db_clusters(input)
+    }
+    fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> RusotoFuture<DBEngineVersionMessage, DescribeDBEngineVersionsError> {
+        (**self).describe_db_engine_versions(input)
+    }
+    fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> RusotoFuture<DBInstanceMessage, DescribeDBInstancesError> {
+        (**self).describe_db_instances(input)
+    }
+    fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> RusotoFuture<DBSubnetGroupMessage, DescribeDBSubnetGroupsError> {
+        (**self).describe_db_subnet_groups(input)
+    }
+    fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> RusotoFuture<
+        DescribeEngineDefaultClusterParametersResult,
+        DescribeEngineDefaultClusterParametersError,
+    > {
+        (**self).describe_engine_default_cluster_parameters(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> RusotoFuture<OrderableDBInstanceOptionsMessage, DescribeOrderableDBInstanceOptionsError>
+    {
+        (**self).describe_orderable_db_instance_options(input)
+    }
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<PendingMaintenanceActionsMessage, DescribePendingMaintenanceActionsError>
+    {
+        (**self).describe_pending_maintenance_actions(input)
+    }
+    fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> RusotoFuture<FailoverDBClusterResult, FailoverDBClusterError> {
+        (**self).failover_db_cluster(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> RusotoFuture<ModifyDBClusterResult, ModifyDBClusterError> {
+        (**self).modify_db_cluster(input)
+    }
+    fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ModifyDBClusterParameterGroupError> {
+        (**self).modify_db_cluster_parameter_group(input)
+    }
+    fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> RusotoFuture<ModifyDBClusterSnapshotAttributeResult, ModifyDBClusterSnapshotAttributeError>
+    {
+        (**self).modify_db_cluster_snapshot_attribute(input)
+    }
+    fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> RusotoFuture<ModifyDBInstanceResult, ModifyDBInstanceError> {
+        (**self).modify_db_instance(input)
+    }
+    fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyDBSubnetGroupResult, ModifyDBSubnetGroupError> {
+        (**self).modify_db_subnet_group(input)
+    }
+    fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> RusotoFuture<RebootDBInstanceResult, RebootDBInstanceError> {
+        (**self).reboot_db_instance(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<(), RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ResetDBClusterParameterGroupError> {
+        (**self).reset_db_cluster_parameter_group(input)
+    }
+    fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> RusotoFuture<RestoreDBClusterFromSnapshotResult, RestoreDBClusterFromSnapshotError> {
+        (**self).restore_db_cluster_from_snapshot(input)
+    }
+    fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> RusotoFuture<RestoreDBClusterToPointInTimeResult, RestoreDBClusterToPointInTimeError> {
+        (**self).restore_db_cluster_to_point_in_time(input)
+    }
+    fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> RusotoFuture<StartDBClusterResult, StartDBClusterError> {
+        (**self).start_db_cluster(input)
+    }
+    fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> RusotoFuture<StopDBClusterResult, StopDBClusterError> {
+        (**self).stop_db_cluster(input)
+    }
+}
+/// A client for the Amazon DocDB API.
+#[derive(Clone)]
+pub struct DocdbClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl DocdbClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DocdbClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DocdbClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> DocdbClient {
+        DocdbClient { client, region }
+    }
+}
+
+impl Docdb for DocdbClient {
+    /// <p>Adds metadata tags to an Amazon DocumentDB resource. You can use these tags with cost allocation reporting to track costs that are associated with Amazon DocumentDB resources. or in a <code>Condition</code> statement in an AWS Identity and Access Management (IAM) policy for Amazon DocumentDB.</p>
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<(), AddTagsToResourceError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
+        let mut params = Params::new();
+
+        params.put("Action", "AddTagsToResource");
+        params.put("Version", "2014-10-31");
+        AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Applies a pending maintenance action to a resource (for example, to a DB instance).</p>
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResult, ApplyPendingMaintenanceActionError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
+        let mut params = Params::new();
+
+        params.put("Action", "ApplyPendingMaintenanceAction");
+        params.put("Version", "2014-10-31");
+        ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ApplyPendingMaintenanceActionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ApplyPendingMaintenanceActionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ApplyPendingMaintenanceActionResultDeserializer::deserialize(
+                        "ApplyPendingMaintenanceActionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ApplyPendingMaintenanceAction", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Copies the specified DB cluster parameter group.</p>
+    fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CopyDBClusterParameterGroupResult, CopyDBClusterParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CopyDBClusterParameterGroup");
+        params.put("Version", "2014-10-31");
+        CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CopyDBClusterParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CopyDBClusterParameterGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CopyDBClusterParameterGroupResultDeserializer::deserialize(
+                        "CopyDBClusterParameterGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CopyDBClusterParameterGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Copies a snapshot of a DB cluster.</p> <p>To copy a DB cluster snapshot from a shared manual DB cluster snapshot, <code>SourceDBClusterSnapshotIdentifier</code> must be the Amazon Resource Name (ARN) of the shared DB cluster snapshot.</p> <p>To cancel the copy operation after it is in progress, delete the target DB cluster snapshot identified by <code>TargetDBClusterSnapshotIdentifier</code> while that DB cluster snapshot is in the <i>copying</i> status.</p>
+    fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyDBClusterSnapshotResult, CopyDBClusterSnapshotError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "CopyDBClusterSnapshot");
+        params.put("Version", "2014-10-31");
+        CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CopyDBClusterSnapshotError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CopyDBClusterSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CopyDBClusterSnapshotResultDeserializer::deserialize(
+                        "CopyDBClusterSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CopyDBClusterSnapshot", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new Amazon DocumentDB DB cluster.</p>
+    fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> RusotoFuture<CreateDBClusterResult, CreateDBClusterError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateDBCluster");
+        params.put("Version", "2014-10-31");
+        CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDBClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateDBClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateDBClusterResultDeserializer::deserialize(
+                        "CreateDBClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateDBCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Creates a new DB cluster parameter group.</p> <p>Parameters in a DB cluster parameter group apply to all of the instances in a DB cluster.</p> <p>A DB cluster parameter group is initially created with the default parameters for the database engine used by instances in the DB cluster. To provide custom values for any of the parameters, you must modify the group after you create it. After you create a DB cluster parameter group, you must associate it with your DB cluster. For the new DB cluster parameter group and associated settings to take effect, you must then reboot the DB instances in the DB cluster without failover.</p> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon DocumentDB to fully complete the create action before the DB cluster parameter group is used as the default for a new DB cluster. This step is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter.</p> </important></p>
+    fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateDBClusterParameterGroupResult, CreateDBClusterParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateDBClusterParameterGroup");
+        params.put("Version", "2014-10-31");
+        CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDBClusterParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateDBClusterParameterGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateDBClusterParameterGroupResultDeserializer::deserialize(
+                        "CreateDBClusterParameterGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateDBClusterParameterGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a snapshot of a DB cluster. </p>
+    fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateDBClusterSnapshotResult, CreateDBClusterSnapshotError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateDBClusterSnapshot");
+        params.put("Version", "2014-10-31");
+        CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDBClusterSnapshotError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateDBClusterSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateDBClusterSnapshotResultDeserializer::deserialize(
+                        "CreateDBClusterSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateDBClusterSnapshot", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new DB instance.</p>
+    fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> RusotoFuture<CreateDBInstanceResult, CreateDBInstanceError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstance");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateDBInstance");
+        params.put("Version", "2014-10-31");
+        CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDBInstanceError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateDBInstanceResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateDBInstanceResultDeserializer::deserialize(
+                        "CreateDBInstanceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateDBInstance", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new DB subnet group. DB subnet groups must contain at least one subnet in at least two Availability Zones in the AWS Region.</p>
+    fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> RusotoFuture<CreateDBSubnetGroupResult, CreateDBSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSubnetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateDBSubnetGroup");
+        params.put("Version", "2014-10-31");
+        CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateDBSubnetGroupError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateDBSubnetGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateDBSubnetGroupResultDeserializer::deserialize(
+                        "CreateDBSubnetGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateDBSubnetGroup", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Deletes a previously provisioned DB cluster. When you delete a DB cluster, all automated backups for that DB cluster are deleted and can&#39;t be recovered. Manual DB cluster snapshots of the specified DB cluster are not deleted.</p> <p/></p>
+    fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> RusotoFuture<DeleteDBClusterResult, DeleteDBClusterError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteDBCluster");
+        params.put("Version", "2014-10-31");
+        DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDBClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteDBClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteDBClusterResultDeserializer::deserialize(
+                        "DeleteDBClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteDBCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a specified DB cluster parameter group. The DB cluster parameter group to be deleted can't be associated with any DB clusters.</p>
+    fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBClusterParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteDBClusterParameterGroup");
+        params.put("Version", "2014-10-31");
+        DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteDBClusterParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p><p>Deletes a DB cluster snapshot. If the snapshot is being copied, the copy operation is terminated.</p> <note> <p>The DB cluster snapshot must be in the <code>available</code> state to be deleted.</p> </note></p>
+    fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteDBClusterSnapshotResult, DeleteDBClusterSnapshotError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteDBClusterSnapshot");
+        params.put("Version", "2014-10-31");
+        DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteDBClusterSnapshotError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteDBClusterSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteDBClusterSnapshotResultDeserializer::deserialize(
+                        "DeleteDBClusterSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteDBClusterSnapshot", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a previously provisioned DB instance. </p>
+    fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> RusotoFuture<DeleteDBInstanceResult, DeleteDBInstanceError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstance");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteDBInstance");
+        params.put("Version", "2014-10-31");
+        DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDBInstanceError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteDBInstanceResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteDBInstanceResultDeserializer::deserialize(
+                        "DeleteDBInstanceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteDBInstance", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Deletes a DB subnet group.</p> <note> <p>The specified database subnet group must not be associated with any DB instances.</p> </note></p>
+    fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSubnetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteDBSubnetGroup");
+        params.put("Version", "2014-10-31");
+        DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteDBSubnetGroupError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Returns a list of <code>DBClusterParameterGroup</code> descriptions. If a <code>DBClusterParameterGroupName</code> parameter is specified, the list contains only the description of the specified DB cluster parameter group. </p>
+    fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupsMessage, DescribeDBClusterParameterGroupsError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameterGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeDBClusterParameterGroups");
+        params.put("Version", "2014-10-31");
+        DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDBClusterParameterGroupsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DBClusterParameterGroupsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DBClusterParameterGroupsMessageDeserializer::deserialize(
+                        "DescribeDBClusterParameterGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeDBClusterParameterGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the detailed parameter list for a particular DB cluster parameter group.</p>
+    fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupDetails, DescribeDBClusterParametersError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameters");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeDBClusterParameters");
+        params.put("Version", "2014-10-31");
+        DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDBClusterParametersError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DBClusterParameterGroupDetails::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DBClusterParameterGroupDetailsDeserializer::deserialize(
+                        "DescribeDBClusterParametersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeDBClusterParameters", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of DB cluster snapshot attribute names and values for a manual DB cluster snapshot.</p> <p>When you share snapshots with other AWS accounts, <code>DescribeDBClusterSnapshotAttributes</code> returns the <code>restore</code> attribute and a list of IDs for the AWS accounts that are authorized to copy or restore the manual DB cluster snapshot. If <code>all</code> is included in the list of values for the <code>restore</code> attribute, then the manual DB cluster snapshot is public and can be copied or restored by all AWS accounts.</p>
+    fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> RusotoFuture<
+        DescribeDBClusterSnapshotAttributesResult,
+        DescribeDBClusterSnapshotAttributesError,
+    > {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshotAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeDBClusterSnapshotAttributes");
+        params.put("Version", "2014-10-31");
+        DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDBClusterSnapshotAttributesError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeDBClusterSnapshotAttributesResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeDBClusterSnapshotAttributesResultDeserializer::deserialize(
+                        "DescribeDBClusterSnapshotAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "DescribeDBClusterSnapshotAttributes",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about DB cluster snapshots. This API operation supports pagination.</p>
+    fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> RusotoFuture<DBClusterSnapshotMessage, DescribeDBClusterSnapshotsError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshots");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeDBClusterSnapshots");
+        params.put("Version", "2014-10-31");
+        DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDBClusterSnapshotsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DBClusterSnapshotMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DBClusterSnapshotMessageDeserializer::deserialize(
+                        "DescribeDBClusterSnapshotsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeDBClusterSnapshots", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about provisioned Amazon DocumentDB DB clusters. This API operation supports pagination.</p>
+    fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> RusotoFuture<DBClusterMessage, DescribeDBClustersError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusters");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeDBClusters");
+        params.put("Version", "2014-10-31");
+        DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeDBClustersError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DBClusterMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DBClusterMessageDeserializer::deserialize(
+                        "DescribeDBClustersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeDBClusters", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of the available DB engines.</p>
+    fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> RusotoFuture<DBEngineVersionMessage, DescribeDBEngineVersionsError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBEngineVersions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeDBEngineVersions");
+        params.put("Version", "2014-10-31");
+        DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDBEngineVersionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DBEngineVersionMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DBEngineVersionMessageDeserializer::deserialize(
+                        "DescribeDBEngineVersionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeDBEngineVersions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about provisioned Amazon DocumentDB instances. This API supports pagination.</p>
+    fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> RusotoFuture<DBInstanceMessage, DescribeDBInstancesError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstances");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeDBInstances");
+        params.put("Version", "2014-10-31");
+        DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeDBInstancesError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DBInstanceMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DBInstanceMessageDeserializer::deserialize(
+                        "DescribeDBInstancesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeDBInstances", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of <code>DBSubnetGroup</code> descriptions. If a <code>DBSubnetGroupName</code> is specified, the list will contain only the descriptions of the specified <code>DBSubnetGroup</code>.</p>
+    fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> RusotoFuture<DBSubnetGroupMessage, DescribeDBSubnetGroupsError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSubnetGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeDBSubnetGroups");
+        params.put("Version", "2014-10-31");
+        DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDBSubnetGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DBSubnetGroupMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DBSubnetGroupMessageDeserializer::deserialize(
+                        "DescribeDBSubnetGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeDBSubnetGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the default engine and system parameter information for the cluster database engine.</p>
+    fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> RusotoFuture<
+        DescribeEngineDefaultClusterParametersResult,
+        DescribeEngineDefaultClusterParametersError,
+    > {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultClusterParameters");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEngineDefaultClusterParameters");
+        params.put("Version", "2014-10-31");
+        DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEngineDefaultClusterParametersError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeEngineDefaultClusterParametersResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeEngineDefaultClusterParametersResultDeserializer::deserialize(
+                        "DescribeEngineDefaultClusterParametersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "DescribeEngineDefaultClusterParameters",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Displays a list of categories for all event source types, or, if specified, for a specified source type. </p>
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEventCategories");
+        params.put("Version", "2014-10-31");
+        DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEventCategoriesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EventCategoriesMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EventCategoriesMessageDeserializer::deserialize(
+                        "DescribeEventCategoriesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeEventCategories", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns events related to DB instances, DB security groups, DB snapshots, and DB parameter groups for the past 14 days. You can obtain events specific to a particular DB instance, DB security group, DB snapshot, or DB parameter group by providing the name as a parameter. By default, the events of the past hour are returned.</p>
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEvents");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEvents");
+        params.put("Version", "2014-10-31");
+        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeEventsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EventsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        EventsMessageDeserializer::deserialize("DescribeEventsResult", &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("DescribeEvents", err, &response.body)
+                            })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of orderable DB instance options for the specified engine.</p>
+    fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> RusotoFuture<OrderableDBInstanceOptionsMessage, DescribeOrderableDBInstanceOptionsError>
+    {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOrderableDBInstanceOptions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeOrderableDBInstanceOptions");
+        params.put("Version", "2014-10-31");
+        DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeOrderableDBInstanceOptionsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = OrderableDBInstanceOptionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = OrderableDBInstanceOptionsMessageDeserializer::deserialize(
+                        "DescribeOrderableDBInstanceOptionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeOrderableDBInstanceOptions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of resources (for example, DB instances) that have at least one pending maintenance action.</p>
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<PendingMaintenanceActionsMessage, DescribePendingMaintenanceActionsError>
+    {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribePendingMaintenanceActions");
+        params.put("Version", "2014-10-31");
+        DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribePendingMaintenanceActionsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = PendingMaintenanceActionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = PendingMaintenanceActionsMessageDeserializer::deserialize(
+                        "DescribePendingMaintenanceActionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribePendingMaintenanceActions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Forces a failover for a DB cluster.</p> <p>A failover for a DB cluster promotes one of the Amazon DocumentDB replicas (read-only instances) in the DB cluster to be the primary instance (the cluster writer).</p> <p>If the primary instance fails, Amazon DocumentDB automatically fails over to an Amazon DocumentDB replica, if one exists. You can force a failover when you want to simulate a failure of a primary instance for testing.</p>
+    fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> RusotoFuture<FailoverDBClusterResult, FailoverDBClusterError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("FailoverDBCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "FailoverDBCluster");
+        params.put("Version", "2014-10-31");
+        FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(FailoverDBClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = FailoverDBClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = FailoverDBClusterResultDeserializer::deserialize(
+                        "FailoverDBClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("FailoverDBCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists all tags on an Amazon DocumentDB resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+        let mut params = Params::new();
+
+        params.put("Action", "ListTagsForResource");
+        params.put("Version", "2014-10-31");
+        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TagListMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TagListMessageDeserializer::deserialize(
+                        "ListTagsForResourceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListTagsForResource", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies a setting for an Amazon DocumentDB DB cluster. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. </p>
+    fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> RusotoFuture<ModifyDBClusterResult, ModifyDBClusterError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyDBCluster");
+        params.put("Version", "2014-10-31");
+        ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ModifyDBClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyDBClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyDBClusterResultDeserializer::deserialize(
+                        "ModifyDBClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ModifyDBCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p> Modifies the parameters of a DB cluster parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request. </p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot or maintenance window before the change can take effect.</p> </note> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon DocumentDB to fully complete the create action before the parameter group is used as the default for a new DB cluster. This step is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter.</p> </important></p>
+    fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ModifyDBClusterParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyDBClusterParameterGroup");
+        params.put("Version", "2014-10-31");
+        ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyDBClusterParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DBClusterParameterGroupNameMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DBClusterParameterGroupNameMessageDeserializer::deserialize(
+                        "ModifyDBClusterParameterGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyDBClusterParameterGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Adds an attribute and values to, or removes an attribute and values from, a manual DB cluster snapshot.</p> <p>To share a manual DB cluster snapshot with other AWS accounts, specify <code>restore</code> as the <code>AttributeName</code>, and use the <code>ValuesToAdd</code> parameter to add a list of IDs of the AWS accounts that are authorized to restore the manual DB cluster snapshot. Use the value <code>all</code> to make the manual DB cluster snapshot public, which means that it can be copied or restored by all AWS accounts. Do not add the <code>all</code> value for any manual DB cluster snapshots that contain private information that you don't want available to all AWS accounts. If a manual DB cluster snapshot is encrypted, it can be shared, but only by specifying a list of authorized AWS account IDs for the <code>ValuesToAdd</code> parameter. You can't use <code>all</code> as a value for that parameter in this case.</p>
+    fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> RusotoFuture<ModifyDBClusterSnapshotAttributeResult, ModifyDBClusterSnapshotAttributeError>
+    {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterSnapshotAttribute");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyDBClusterSnapshotAttribute");
+        params.put("Version", "2014-10-31");
+        ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyDBClusterSnapshotAttributeError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyDBClusterSnapshotAttributeResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyDBClusterSnapshotAttributeResultDeserializer::deserialize(
+                        "ModifyDBClusterSnapshotAttributeResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyDBClusterSnapshotAttribute", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies settings for a DB instance. You can change one or more database configuration parameters by specifying these parameters and the new values in the request.</p>
+    fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> RusotoFuture<ModifyDBInstanceResult, ModifyDBInstanceError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBInstance");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyDBInstance");
+        params.put("Version", "2014-10-31");
+        ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ModifyDBInstanceError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyDBInstanceResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyDBInstanceResultDeserializer::deserialize(
+                        "ModifyDBInstanceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ModifyDBInstance", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies an existing DB subnet group. DB subnet groups must contain at least one subnet in at least two Availability Zones in the AWS Region.</p>
+    fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyDBSubnetGroupResult, ModifyDBSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSubnetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyDBSubnetGroup");
+        params.put("Version", "2014-10-31");
+        ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ModifyDBSubnetGroupError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyDBSubnetGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyDBSubnetGroupResultDeserializer::deserialize(
+                        "ModifyDBSubnetGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ModifyDBSubnetGroup", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>You might need to reboot your DB instance, usually for maintenance reasons. For example, if you make certain changes, or if you change the DB cluster parameter group that is associated with the DB instance, you must reboot the instance for the changes to take effect. </p> <p>Rebooting a DB instance restarts the database engine service. Rebooting a DB instance results in a momentary outage, during which the DB instance status is set to <i>rebooting</i>. </p>
+    fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> RusotoFuture<RebootDBInstanceResult, RebootDBInstanceError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RebootDBInstance");
+        let mut params = Params::new();
+
+        params.put("Action", "RebootDBInstance");
+        params.put("Version", "2014-10-31");
+        RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RebootDBInstanceError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RebootDBInstanceResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RebootDBInstanceResultDeserializer::deserialize(
+                        "RebootDBInstanceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("RebootDBInstance", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Removes metadata tags from an Amazon DocumentDB resource.</p>
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<(), RemoveTagsFromResourceError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
+        let mut params = Params::new();
+
+        params.put("Action", "RemoveTagsFromResource");
+        params.put("Version", "2014-10-31");
+        RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RemoveTagsFromResourceError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p> Modifies the parameters of a DB cluster parameter group to the default value. To reset specific parameters, submit a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB cluster parameter group, specify the <code>DBClusterParameterGroupName</code> and <code>ResetAllParameters</code> parameters. </p> <p> When you reset the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance reboot.</p>
+    fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ResetDBClusterParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBClusterParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "ResetDBClusterParameterGroup");
+        params.put("Version", "2014-10-31");
+        ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ResetDBClusterParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DBClusterParameterGroupNameMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DBClusterParameterGroupNameMessageDeserializer::deserialize(
+                        "ResetDBClusterParameterGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ResetDBClusterParameterGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new DB cluster from a DB snapshot or DB cluster snapshot.</p> <p>If a DB snapshot is specified, the target DB cluster is created from the source DB snapshot with a default configuration and default security group.</p> <p>If a DB cluster snapshot is specified, the target DB cluster is created from the source DB cluster restore point with the same configuration as the original source DB cluster, except that the new DB cluster is created with the default security group.</p>
+    fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> RusotoFuture<RestoreDBClusterFromSnapshotResult, RestoreDBClusterFromSnapshotError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "RestoreDBClusterFromSnapshot");
+        params.put("Version", "2014-10-31");
+        RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RestoreDBClusterFromSnapshotError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RestoreDBClusterFromSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RestoreDBClusterFromSnapshotResultDeserializer::deserialize(
+                        "RestoreDBClusterFromSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RestoreDBClusterFromSnapshot", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Restores a DB cluster to an arbitrary point in time. Users can restore to any point in time before <code>LatestRestorableTime</code> for up to <code>BackupRetentionPeriod</code> days. The target DB cluster is created from the source DB cluster with the same configuration as the original DB cluster, except that the new DB cluster is created with the default DB security group. </p>
+    fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> RusotoFuture<RestoreDBClusterToPointInTimeResult, RestoreDBClusterToPointInTimeError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterToPointInTime");
+        let mut params = Params::new();
+
+        params.put("Action", "RestoreDBClusterToPointInTime");
+        params.put("Version", "2014-10-31");
+        RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RestoreDBClusterToPointInTimeError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RestoreDBClusterToPointInTimeResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RestoreDBClusterToPointInTimeResultDeserializer::deserialize(
+                        "RestoreDBClusterToPointInTimeResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RestoreDBClusterToPointInTime", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Restarts the stopped cluster that is specified by <code>DBClusterIdentifier</code>. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/db-cluster-stop-start.html">Stopping and Starting an Amazon DocumentDB Cluster</a>.</p>
+    fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> RusotoFuture<StartDBClusterResult, StartDBClusterError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartDBCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "StartDBCluster");
+        params.put("Version", "2014-10-31");
+        StartDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartDBClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = StartDBClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = StartDBClusterResultDeserializer::deserialize(
+                        "StartDBClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("StartDBCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Stops the running cluster that is specified by <code>DBClusterIdentifier</code>. The cluster must be in the <i>available</i> state. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/db-cluster-stop-start.html">Stopping and Starting an Amazon DocumentDB Cluster</a>.</p>
+    fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> RusotoFuture<StopDBClusterResult, StopDBClusterError> {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopDBCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "StopDBCluster");
+        params.put("Version", "2014-10-31");
+        StopDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopDBClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = StopDBClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = StopDBClusterResultDeserializer::deserialize(
+                        "StopDBClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("StopDBCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+}
+
+/// A mock of the Amazon DocDB API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockDocdb {
+    add_tags_to_resource: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToResourceMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<AddTagsToResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    apply_pending_maintenance_action: Option<
+        Box<
+            dyn Fn(
+                    ApplyPendingMaintenanceActionMessage,
+                ) -> ::std::result::Result<
+                    ApplyPendingMaintenanceActionResult,
+                    RusotoError<ApplyPendingMaintenanceActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    copy_db_cluster_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    CopyDBClusterParameterGroupMessage,
+                ) -> ::std::result::Result<
+                    CopyDBClusterParameterGroupResult,
+                    RusotoError<CopyDBClusterParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    copy_db_cluster_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CopyDBClusterSnapshotMessage,
+                ) -> ::std::result::Result<
+                    CopyDBClusterSnapshotResult,
+                    RusotoError<CopyDBClusterSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_db_cluster: Option<
+        Box<
+            dyn Fn(
+                    CreateDBClusterMessage,
+                ) -> ::std::result::Result<
+                    CreateDBClusterResult,
+                    RusotoError<CreateDBClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_db_cluster_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    CreateDBClusterParameterGroupMessage,
+                ) -> ::std::result::Result<
+                    CreateDBClusterParameterGroupResult,
+                    RusotoError<CreateDBClusterParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_db_cluster_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateDBClusterSnapshotMessage,
+                ) -> ::std::result::Result<
+                    CreateDBClusterSnapshotResult,
+                    RusotoError<CreateDBClusterSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_db_instance: Option<
+        Box<
+            dyn Fn(
+                    CreateDBInstanceMessage,
+                ) -> ::std::result::Result<
+                    CreateDBInstanceResult,
+                    RusotoError<CreateDBInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_db_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    CreateDBSubnetGroupMessage,
+                ) -> ::std::result::Result<
+                    CreateDBSubnetGroupResult,
+                    RusotoError<CreateDBSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_db_cluster: Option<
+        Box<
+            dyn Fn(
+                    DeleteDBClusterMessage,
+                ) -> ::std::result::Result<
+                    DeleteDBClusterResult,
+                    RusotoError<DeleteDBClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_db_cluster_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteDBClusterParameterGroupMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteDBClusterParameterGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_db_cluster_snapshot: Option<
+        Box<
+            dyn Fn(
+                    DeleteDBClusterSnapshotMessage,
+                ) -> ::std::result::Result<
+                    DeleteDBClusterSnapshotResult,
+                    RusotoError<DeleteDBClusterSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_db_instance: Option<
+        Box<
+            dyn Fn(
+                    DeleteDBInstanceMessage,
+                ) -> ::std::result::Result<
+                    DeleteDBInstanceResult,
+                    RusotoError<DeleteDBInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_db_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteDBSubnetGroupMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteDBSubnetGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_db_cluster_parameter_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeDBClusterParameterGroupsMessage,
+                ) -> ::std::result::Result<
+                    DBClusterParameterGroupsMessage,
+                    RusotoError<DescribeDBClusterParameterGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_db_cluster_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeDBClusterParametersMessage,
+                ) -> ::std::result::Result<
+                    DBClusterParameterGroupDetails,
+                    RusotoError<DescribeDBClusterParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_db_cluster_snapshot_attributes: Option<
+        Box<
+            dyn Fn(
+                    DescribeDBClusterSnapshotAttributesMessage,
+                ) -> ::std::result::Result<
+                    DescribeDBClusterSnapshotAttributesResult,
+                    RusotoError<DescribeDBClusterSnapshotAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_db_cluster_snapshots: Option<
+        Box<
+            dyn Fn(
+                    DescribeDBClusterSnapshotsMessage,
+                ) -> ::std::result::Result<
+                    DBClusterSnapshotMessage,
+                    RusotoError<DescribeDBClusterSnapshotsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_db_clusters: Option<
+        Box<
+            dyn Fn(
+                    DescribeDBClustersMessage,
+                )
+                    -> ::std::result::Result<DBClusterMessage, RusotoError<DescribeDBClustersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_db_engine_versions: Option<
+        Box<
+            dyn Fn(
+                    DescribeDBEngineVersionsMessage,
+                ) -> ::std::result::Result<
+                    DBEngineVersionMessage,
+                    RusotoError<DescribeDBEngineVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_db_instances: Option<
+        Box<
+            dyn Fn(
+                    DescribeDBInstancesMessage,
+                ) -> ::std::result::Result<
+                    DBInstanceMessage,
+                    RusotoError<DescribeDBInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_db_subnet_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeDBSubnetGroupsMessage,
+                ) -> ::std::result::Result<
+                    DBSubnetGroupMessage,
+                    RusotoError<DescribeDBSubnetGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_engine_default_cluster_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeEngineDefaultClusterParametersMessage,
+                ) -> ::std::result::Result<
+                    DescribeEngineDefaultClusterParametersResult,
+                    RusotoError<DescribeEngineDefaultClusterParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_event_categories: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventCategoriesMessage,
+                ) -> ::std::result::Result<
+                    EventCategoriesMessage,
+                    RusotoError<DescribeEventCategoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_events: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventsMessage,
+                )
+                    -> ::std::result::Result<EventsMessage, RusotoError<DescribeEventsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_orderable_db_instance_options: Option<
+        Box<
+            dyn Fn(
+                    DescribeOrderableDBInstanceOptionsMessage,
+                ) -> ::std::result::Result<
+                    OrderableDBInstanceOptionsMessage,
+                    RusotoError<DescribeOrderableDBInstanceOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_pending_maintenance_actions: Option<
+        Box<
+            dyn Fn(
+                    DescribePendingMaintenanceActionsMessage,
+                ) -> ::std::result::Result<
+                    PendingMaintenanceActionsMessage,
+                    RusotoError<DescribePendingMaintenanceActionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    failover_db_cluster: Option<
+        Box<
+            dyn Fn(
+                    FailoverDBClusterMessage,
+                ) -> ::std::result::Result<
+                    FailoverDBClusterResult,
+                    RusotoError<FailoverDBClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceMessage,
+                )
+                    -> ::std::result::Result<TagListMessage, RusotoError<ListTagsForResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_db_cluster: Option<
+        Box<
+            dyn Fn(
+                    ModifyDBClusterMessage,
+                ) -> ::std::result::Result<
+                    ModifyDBClusterResult,
+                    RusotoError<ModifyDBClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_db_cluster_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    ModifyDBClusterParameterGroupMessage,
+                ) -> ::std::result::Result<
+                    DBClusterParameterGroupNameMessage,
+                    RusotoError<ModifyDBClusterParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_db_cluster_snapshot_attribute: Option<
+        Box<
+            dyn Fn(
+                    ModifyDBClusterSnapshotAttributeMessage,
+                ) -> ::std::result::Result<
+                    ModifyDBClusterSnapshotAttributeResult,
+                    RusotoError<ModifyDBClusterSnapshotAttributeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_db_instance: Option<
+        Box<
+            dyn Fn(
+                    ModifyDBInstanceMessage,
+                ) -> ::std::result::Result<
+                    ModifyDBInstanceResult,
+                    RusotoError<ModifyDBInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_db_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    ModifyDBSubnetGroupMessage,
+                ) -> ::std::result::Result<
+                    ModifyDBSubnetGroupResult,
+                    RusotoError<ModifyDBSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reboot_db_instance: Option<
+        Box<
+            dyn Fn(
+                    RebootDBInstanceMessage,
+                ) -> ::std::result::Result<
+                    RebootDBInstanceResult,
+                    RusotoError<RebootDBInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_resource: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromResourceMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<RemoveTagsFromResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    reset_db_cluster_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    ResetDBClusterParameterGroupMessage,
+                ) -> ::std::result::Result<
+                    DBClusterParameterGroupNameMessage,
+                    RusotoError<ResetDBClusterParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    restore_db_cluster_from_snapshot: Option<
+        Box<
+            dyn Fn(
+                    RestoreDBClusterFromSnapshotMessage,
+                ) -> ::std::result::Result<
+                    RestoreDBClusterFromSnapshotResult,
+                    RusotoError<RestoreDBClusterFromSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    restore_db_cluster_to_point_in_time: Option<
+        Box<
+            dyn Fn(
+                    RestoreDBClusterToPointInTimeMessage,
+                ) -> ::std::result::Result<
+                    RestoreDBClusterToPointInTimeResult,
+                    RusotoError<RestoreDBClusterToPointInTimeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_db_cluster: Option<
+        Box<
+            dyn Fn(
+                    StartDBClusterMessage,
+                )
+                    -> ::std::result::Result<StartDBClusterResult, RusotoError<StartDBClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_db_cluster: Option<
+        Box<
+            dyn Fn(
+                    StopDBClusterMessage,
+                )
+                    -> ::std::result::Result<StopDBClusterResult, RusotoError<StopDBClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockDocdb {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockDocdb {
+        Default::default()
+    }
+
+    /// Answers `add_tags_to_resource` with what `handler` returns for its input.
+    pub fn with_add_tags_to_resource<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                AddTagsToResourceMessage,
+            ) -> ::std::result::Result<(), RusotoError<AddTagsToResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `apply_pending_maintenance_action` with what `handler` returns for its input.
+    pub fn with_apply_pending_maintenance_action<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                ApplyPendingMaintenanceActionMessage,
+            ) -> ::std::result::Result<
+                ApplyPendingMaintenanceActionResult,
+                RusotoError<ApplyPendingMaintenanceActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.apply_pending_maintenance_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `copy_db_cluster_parameter_group` with what `handler` returns for its input.
+    pub fn with_copy_db_cluster_parameter_group<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                CopyDBClusterParameterGroupMessage,
+            ) -> ::std::result::Result<
+                CopyDBClusterParameterGroupResult,
+                RusotoError<CopyDBClusterParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.copy_db_cluster_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `copy_db_cluster_snapshot` with what `handler` returns for its input.
+    pub fn with_copy_db_cluster_snapshot<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                CopyDBClusterSnapshotMessage,
+            ) -> ::std::result::Result<
+                CopyDBClusterSnapshotResult,
+                RusotoError<CopyDBClusterSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.copy_db_cluster_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_db_cluster` with what `handler` returns for its input.
+    pub fn with_create_db_cluster<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                CreateDBClusterMessage,
+            )
+                -> ::std::result::Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_db_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_db_cluster_parameter_group` with what `handler` returns for its input.
+    pub fn with_create_db_cluster_parameter_group<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                CreateDBClusterParameterGroupMessage,
+            ) -> ::std::result::Result<
+                CreateDBClusterParameterGroupResult,
+                RusotoError<CreateDBClusterParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_db_cluster_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_db_cluster_snapshot` with what `handler` returns for its input.
+    pub fn with_create_db_cluster_snapshot<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                CreateDBClusterSnapshotMessage,
+            ) -> ::std::result::Result<
+                CreateDBClusterSnapshotResult,
+                RusotoError<CreateDBClusterSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_db_cluster_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_db_instance` with what `handler` returns for its input.
+    pub fn with_create_db_instance<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                CreateDBInstanceMessage,
+            )
+                -> ::std::result::Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_db_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_db_subnet_group` with what `handler` returns for its input.
+    pub fn with_create_db_subnet_group<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                CreateDBSubnetGroupMessage,
+            ) -> ::std::result::Result<
+                CreateDBSubnetGroupResult,
+                RusotoError<CreateDBSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_db_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_db_cluster` with what `handler` returns for its input.
+    pub fn with_delete_db_cluster<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DeleteDBClusterMessage,
+            )
+                -> ::std::result::Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_db_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_db_cluster_parameter_group` with what `handler` returns for its input.
+    pub fn with_delete_db_cluster_parameter_group<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DeleteDBClusterParameterGroupMessage,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteDBClusterParameterGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_db_cluster_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_db_cluster_snapshot` with what `handler` returns for its input.
+    pub fn with_delete_db_cluster_snapshot<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DeleteDBClusterSnapshotMessage,
+            ) -> ::std::result::Result<
+                DeleteDBClusterSnapshotResult,
+                RusotoError<DeleteDBClusterSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_db_cluster_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_db_instance` with what `handler` returns for its input.
+    pub fn with_delete_db_instance<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DeleteDBInstanceMessage,
+            )
+                -> ::std::result::Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_db_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_db_subnet_group` with what `handler` returns for its input.
+    pub fn with_delete_db_subnet_group<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DeleteDBSubnetGroupMessage,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDBSubnetGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_db_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_db_cluster_parameter_groups` with what `handler` returns for its input.
+    pub fn with_describe_db_cluster_parameter_groups<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeDBClusterParameterGroupsMessage,
+            ) -> ::std::result::Result<
+                DBClusterParameterGroupsMessage,
+                RusotoError<DescribeDBClusterParameterGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_db_cluster_parameter_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_db_cluster_parameters` with what `handler` returns for its input.
+    pub fn with_describe_db_cluster_parameters<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeDBClusterParametersMessage,
+            ) -> ::std::result::Result<
+                DBClusterParameterGroupDetails,
+                RusotoError<DescribeDBClusterParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_db_cluster_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_db_cluster_snapshot_attributes` with what `handler` returns for its input.
+    pub fn with_describe_db_cluster_snapshot_attributes<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeDBClusterSnapshotAttributesMessage,
+            ) -> ::std::result::Result<
+                DescribeDBClusterSnapshotAttributesResult,
+                RusotoError<DescribeDBClusterSnapshotAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_db_cluster_snapshot_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_db_cluster_snapshots` with what `handler` returns for its input.
+    pub fn with_describe_db_cluster_snapshots<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeDBClusterSnapshotsMessage,
+            ) -> ::std::result::Result<
+                DBClusterSnapshotMessage,
+                RusotoError<DescribeDBClusterSnapshotsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_db_cluster_snapshots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_db_clusters` with what `handler` returns for its input.
+    pub fn with_describe_db_clusters<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeDBClustersMessage,
+            )
+                -> ::std::result::Result<DBClusterMessage, RusotoError<DescribeDBClustersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_db_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_db_engine_versions` with what `handler` returns for its input.
+    pub fn with_describe_db_engine_versions<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeDBEngineVersionsMessage,
+            ) -> ::std::result::Result<
+                DBEngineVersionMessage,
+                RusotoError<DescribeDBEngineVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_db_engine_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_db_instances` with what `handler` returns for its input.
+    pub fn with_describe_db_instances<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeDBInstancesMessage,
+            )
+                -> ::std::result::Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_db_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_db_subnet_groups` with what `handler` returns for its input.
+    pub fn with_describe_db_subnet_groups<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeDBSubnetGroupsMessage,
+            ) -> ::std::result::Result<
+                DBSubnetGroupMessage,
+                RusotoError<DescribeDBSubnetGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_db_subnet_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_engine_default_cluster_parameters` with what `handler` returns for its input.
+    pub fn with_describe_engine_default_cluster_parameters<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeEngineDefaultClusterParametersMessage,
+            ) -> ::std::result::Result<
+                DescribeEngineDefaultClusterParametersResult,
+                RusotoError<DescribeEngineDefaultClusterParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_engine_default_cluster_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_categories` with what `handler` returns for its input.
+    pub fn with_describe_event_categories<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeEventCategoriesMessage,
+            ) -> ::std::result::Result<
+                EventCategoriesMessage,
+                RusotoError<DescribeEventCategoriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_categories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_events` with what `handler` returns for its input.
+    pub fn with_describe_events<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeEventsMessage,
+            )
+                -> ::std::result::Result<EventsMessage, RusotoError<DescribeEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_orderable_db_instance_options` with what `handler` returns for its input.
+    pub fn with_describe_orderable_db_instance_options<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribeOrderableDBInstanceOptionsMessage,
+            ) -> ::std::result::Result<
+                OrderableDBInstanceOptionsMessage,
+                RusotoError<DescribeOrderableDBInstanceOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_orderable_db_instance_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_pending_maintenance_actions` with what `handler` returns for its input.
+    pub fn with_describe_pending_maintenance_actions<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                DescribePendingMaintenanceActionsMessage,
+            ) -> ::std::result::Result<
+                PendingMaintenanceActionsMessage,
+                RusotoError<DescribePendingMaintenanceActionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_pending_maintenance_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `failover_db_cluster` with what `handler` returns for its input.
+    pub fn with_failover_db_cluster<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                FailoverDBClusterMessage,
+            ) -> ::std::result::Result<
+                FailoverDBClusterResult,
+                RusotoError<FailoverDBClusterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.failover_db_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                ListTagsForResourceMessage,
+            )
+                -> ::std::result::Result<TagListMessage, RusotoError<ListTagsForResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_db_cluster` with what `handler` returns for its input.
+    pub fn with_modify_db_cluster<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                ModifyDBClusterMessage,
+            )
+                -> ::std::result::Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_db_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_db_cluster_parameter_group` with what `handler` returns for its input.
+    pub fn with_modify_db_cluster_parameter_group<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                ModifyDBClusterParameterGroupMessage,
+            ) -> ::std::result::Result<
+                DBClusterParameterGroupNameMessage,
+                RusotoError<ModifyDBClusterParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_db_cluster_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_db_cluster_snapshot_attribute` with what `handler` returns for its input.
+    pub fn with_modify_db_cluster_snapshot_attribute<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                ModifyDBClusterSnapshotAttributeMessage,
+            ) -> ::std::result::Result<
+                ModifyDBClusterSnapshotAttributeResult,
+                RusotoError<ModifyDBClusterSnapshotAttributeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_db_cluster_snapshot_attribute = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_db_instance` with what `handler` returns for its input.
+    pub fn with_modify_db_instance<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                ModifyDBInstanceMessage,
+            )
+                -> ::std::result::Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_db_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_db_subnet_group` with what `handler` returns for its input.
+    pub fn with_modify_db_subnet_group<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                ModifyDBSubnetGroupMessage,
+            ) -> ::std::result::Result<
+                ModifyDBSubnetGroupResult,
+                RusotoError<ModifyDBSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_db_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reboot_db_instance` with what `handler` returns for its input.
+    pub fn with_reboot_db_instance<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                RebootDBInstanceMessage,
+            )
+                -> ::std::result::Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reboot_db_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_resource` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_resource<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                RemoveTagsFromResourceMessage,
+            ) -> ::std::result::Result<(), RusotoError<RemoveTagsFromResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reset_db_cluster_parameter_group` with what `handler` returns for its input.
+    pub fn with_reset_db_cluster_parameter_group<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                ResetDBClusterParameterGroupMessage,
+            ) -> ::std::result::Result<
+                DBClusterParameterGroupNameMessage,
+                RusotoError<ResetDBClusterParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reset_db_cluster_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_db_cluster_from_snapshot` with what `handler` returns for its input.
+    pub fn with_restore_db_cluster_from_snapshot<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                RestoreDBClusterFromSnapshotMessage,
+            ) -> ::std::result::Result<
+                RestoreDBClusterFromSnapshotResult,
+                RusotoError<RestoreDBClusterFromSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_db_cluster_from_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_db_cluster_to_point_in_time` with what `handler` returns for its input.
+    pub fn with_restore_db_cluster_to_point_in_time<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                RestoreDBClusterToPointInTimeMessage,
+            ) -> ::std::result::Result<
+                RestoreDBClusterToPointInTimeResult,
+                RusotoError<RestoreDBClusterToPointInTimeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_db_cluster_to_point_in_time = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_db_cluster` with what `handler` returns for its input.
+    pub fn with_start_db_cluster<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                StartDBClusterMessage,
+            )
+                -> ::std::result::Result<StartDBClusterResult, RusotoError<StartDBClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_db_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_db_cluster` with what `handler` returns for its input.
+    pub fn with_stop_db_cluster<F>(mut self, handler: F) -> MockDocdb
+    where
+        F: Fn(
+                StopDBClusterMessage,
+            )
+                -> ::std::result::Result<StopDBClusterResult, RusotoError<StopDBClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_db_cluster = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Docdb for MockDocdb {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<(), AddTagsToResourceError> {
+        match self.add_tags_to_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for add_tags_to_resource"),
+        }
+    }
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResult, ApplyPendingMaintenanceActionError> {
+        match self.apply_pending_maintenance_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for apply_pending_maintenance_action"),
+        }
+    }
+    fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CopyDBClusterParameterGroupResult, CopyDBClusterParameterGroupError> {
+        match self.copy_db_cluster_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for copy_db_cluster_parameter_group"),
+        }
+    }
+    fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyDBClusterSnapshotResult, CopyDBClusterSnapshotError> {
+        match self.copy_db_cluster_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for copy_db_cluster_snapshot"),
+        }
+    }
+    fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> RusotoFuture<CreateDBClusterResult, CreateDBClusterError> {
+        match self.create_db_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for create_db_cluster"),
+        }
+    }
+    fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateDBClusterParameterGroupResult, CreateDBClusterParameterGroupError> {
+        match self.create_db_cluster_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for create_db_cluster_parameter_group"),
+        }
+    }
+    fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateDBClusterSnapshotResult, CreateDBClusterSnapshotError> {
+        match self.create_db_cluster_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for create_db_cluster_snapshot"),
+        }
+    }
+    fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> RusotoFuture<CreateDBInstanceResult, CreateDBInstanceError> {
+        match self.create_db_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for create_db_instance"),
+        }
+    }
+    fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> RusotoFuture<CreateDBSubnetGroupResult, CreateDBSubnetGroupError> {
+        match self.create_db_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for create_db_subnet_group"),
+        }
+    }
+    fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> RusotoFuture<DeleteDBClusterResult, DeleteDBClusterError> {
+        match self.delete_db_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for delete_db_cluster"),
+        }
+    }
+    fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBClusterParameterGroupError> {
+        match self.delete_db_cluster_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for delete_db_cluster_parameter_group"),
+        }
+    }
+    fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteDBClusterSnapshotResult, DeleteDBClusterSnapshotError> {
+        match self.delete_db_cluster_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for delete_db_cluster_snapshot"),
+        }
+    }
+    fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> RusotoFuture<DeleteDBInstanceResult, DeleteDBInstanceError> {
+        match self.delete_db_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for delete_db_instance"),
+        }
+    }
+    fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBSubnetGroupError> {
+        match self.delete_db_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for delete_db_subnet_group"),
+        }
+    }
+    fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupsMessage, DescribeDBClusterParameterGroupsError> {
+        match self.describe_db_cluster_parameter_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_db_cluster_parameter_groups"),
+        }
+    }
+    fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupDetails, DescribeDBClusterParametersError> {
+        match self.describe_db_cluster_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_db_cluster_parameters"),
+        }
+    }
+    fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> RusotoFuture<
+        DescribeDBClusterSnapshotAttributesResult,
+        DescribeDBClusterSnapshotAttributesError,
+    > {
+        match self.describe_db_cluster_snapshot_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_db_cluster_snapshot_attributes"),
+        }
+    }
+    fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> RusotoFuture<DBClusterSnapshotMessage, DescribeDBClusterSnapshotsError> {
+        match self.describe_db_cluster_snapshots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_db_cluster_snapshots"),
+        }
+    }
+    fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> RusotoFuture<DBClusterMessage, DescribeDBClustersError> {
+        match self.describe_db_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_db_clusters"),
+        }
+    }
+    fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> RusotoFuture<DBEngineVersionMessage, DescribeDBEngineVersionsError> {
+        match self.describe_db_engine_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_db_engine_versions"),
+        }
+    }
+    fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> RusotoFuture<DBInstanceMessage, DescribeDBInstancesError> {
+        match self.describe_db_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_db_instances"),
+        }
+    }
+    fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> RusotoFuture<DBSubnetGroupMessage, DescribeDBSubnetGroupsError> {
+        match self.describe_db_subnet_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_db_subnet_groups"),
+        }
+    }
+    fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> RusotoFuture<
+        DescribeEngineDefaultClusterParametersResult,
+        DescribeEngineDefaultClusterParametersError,
+    > {
+        match self.describe_engine_default_cluster_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDocdb has no handler for describe_engine_default_cluster_parameters")
+            }
+        }
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        match self.describe_event_categories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_event_categories"),
+        }
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        match self.describe_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_events"),
+        }
+    }
+    fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> RusotoFuture<OrderableDBInstanceOptionsMessage, DescribeOrderableDBInstanceOptionsError>
+    {
+        match self.describe_orderable_db_instance_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_orderable_db_instance_options"),
+        }
+    }
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<PendingMaintenanceActionsMessage, DescribePendingMaintenanceActionsError>
+    {
+        match self.describe_pending_maintenance_actions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for describe_pending_maintenance_actions"),
+        }
+    }
+    fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> RusotoFuture<FailoverDBClusterResult, FailoverDBClusterError> {
+        match self.failover_db_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for failover_db_cluster"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for list_tags_for_resource"),
+        }
+    }
+    fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> RusotoFuture<ModifyDBClusterResult, ModifyDBClusterError> {
+        match self.modify_db_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for modify_db_cluster"),
+        }
+    }
+    fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ModifyDBClusterParameterGroupError> {
+        match self.modify_db_cluster_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for modify_db_cluster_parameter_group"),
+        }
+    }
+    fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> RusotoFuture<ModifyDBClusterSnapshotAttributeResult, ModifyDBClusterSnapshotAttributeError>
+    {
+        match self.modify_db_cluster_snapshot_attribute {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for modify_db_cluster_snapshot_attribute"),
+        }
+    }
+    fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> RusotoFuture<ModifyDBInstanceResult, ModifyDBInstanceError> {
+        match self.modify_db_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for modify_db_instance"),
+        }
+    }
+    fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyDBSubnetGroupResult, ModifyDBSubnetGroupError> {
+        match self.modify_db_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for modify_db_subnet_group"),
+        }
+    }
+    fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> RusotoFuture<RebootDBInstanceResult, RebootDBInstanceError> {
+        match self.reboot_db_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for reboot_db_instance"),
+        }
     }
-
-    /// <p>Removes metadata tags from an Amazon DocumentDB resource.</p>
     fn remove_tags_from_resource(
         &self,
         input: RemoveTagsFromResourceMessage,
     ) -> RusotoFuture<(), RemoveTagsFromResourceError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RemoveTagsFromResource");
-        params.put("Version", "2014-10-31");
-        RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RemoveTagsFromResourceError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.remove_tags_from_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for remove_tags_from_resource"),
+        }
     }
-
-    /// <p> Modifies the parameters of a DB cluster parameter group to the default value. To reset specific parameters, submit a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB cluster parameter group, specify the <code>DBClusterParameterGroupName</code> and <code>ResetAllParameters</code> parameters. </p> <p> When you reset the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance reboot.</p>
     fn reset_db_cluster_parameter_group(
         &self,
         input: ResetDBClusterParameterGroupMessage,
     ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ResetDBClusterParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ResetDBClusterParameterGroup");
-        params.put("Version", "2014-10-31");
-        ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ResetDBClusterParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DBClusterParameterGroupNameMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DBClusterParameterGroupNameMessageDeserializer::deserialize(
-                        "ResetDBClusterParameterGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.reset_db_cluster_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for reset_db_cluster_parameter_group"),
+        }
     }
-
-    /// <p>Creates a new DB cluster from a DB snapshot or DB cluster snapshot.</p> <p>If a DB snapshot is specified, the target DB cluster is created from the source DB snapshot with a default configuration and default security group.</p> <p>If a DB cluster snapshot is specified, the target DB cluster is created from the source DB cluster restore point with the same configuration as the original source DB cluster, except that the new DB cluster is created with the default security group.</p>
     fn restore_db_cluster_from_snapshot(
         &self,
         input: RestoreDBClusterFromSnapshotMessage,
     ) -> RusotoFuture<RestoreDBClusterFromSnapshotResult, RestoreDBClusterFromSnapshotError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RestoreDBClusterFromSnapshot");
-        params.put("Version", "2014-10-31");
-        RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RestoreDBClusterFromSnapshotError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RestoreDBClusterFromSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RestoreDBClusterFromSnapshotResultDeserializer::deserialize(
-                        "RestoreDBClusterFromSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.restore_db_cluster_from_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for restore_db_cluster_from_snapshot"),
+        }
     }
-
-    /// <p>Restores a DB cluster to an arbitrary point in time. Users can restore to any point in time before <code>LatestRestorableTime</code> for up to <code>BackupRetentionPeriod</code> days. The target DB cluster is created from the source DB cluster with the same configuration as the original DB cluster, except that the new DB cluster is created with the default DB security group. </p>
     fn restore_db_cluster_to_point_in_time(
         &self,
         input: RestoreDBClusterToPointInTimeMessage,
     ) -> RusotoFuture<RestoreDBClusterToPointInTimeResult, RestoreDBClusterToPointInTimeError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RestoreDBClusterToPointInTime");
-        params.put("Version", "2014-10-31");
-        RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RestoreDBClusterToPointInTimeError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RestoreDBClusterToPointInTimeResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RestoreDBClusterToPointInTimeResultDeserializer::deserialize(
-                        "RestoreDBClusterToPointInTimeResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.restore_db_cluster_to_point_in_time {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for restore_db_cluster_to_point_in_time"),
+        }
     }
-
-    /// <p>Restarts the stopped cluster that is specified by <code>DBClusterIdentifier</code>. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/db-cluster-stop-start.html">Stopping and Starting an Amazon DocumentDB Cluster</a>.</p>
     fn start_db_cluster(
         &self,
         input: StartDBClusterMessage,
     ) -> RusotoFuture<StartDBClusterResult, StartDBClusterError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "StartDBCluster");
-        params.put("Version", "2014-10-31");
-        StartDBClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartDBClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = StartDBClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = StartDBClusterResultDeserializer::deserialize(
-                        "StartDBClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.start_db_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for start_db_cluster"),
+        }
     }
-
-    /// <p>Stops the running cluster that is specified by <code>DBClusterIdentifier</code>. The cluster must be in the <i>available</i> state. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/db-cluster-stop-start.html">Stopping and Starting an Amazon DocumentDB Cluster</a>.</p>
     fn stop_db_cluster(
         &self,
         input: StopDBClusterMessage,
     ) -> RusotoFuture<StopDBClusterResult, StopDBClusterError> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "StopDBCluster");
-        params.put("Version", "2014-10-31");
-        StopDBClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopDBClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = StopDBClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = StopDBClusterResultDeserializer::deserialize(
-                        "StopDBClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.stop_db_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDocdb has no handler for stop_db_cluster"),
+        }
     }
 }
@@ -22,16 +22,28 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptSharedDirectoryRequest {
     /// <p>Identifier of the shared directory in the directory consumer account. This identifier is different for each directory owner account. </p>
     #[serde(rename = "SharedDirectoryId")]
     pub shared_directory_id: String,
 }
 
+impl Validate for AcceptSharedDirectoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "shared_directory_id"),
+            &self.shared_directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcceptSharedDirectoryResult {
     /// <p>The shared directory in the directory consumer account.</p>
     #[serde(rename = "SharedDirectory")]
@@ -40,6 +52,7 @@ pub struct AcceptSharedDirectoryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddIpRoutesRequest {
     /// <p>Identifier (ID) of the directory to which to add the address block.</p>
     #[serde(rename = "DirectoryId")]
@@ -53,11 +66,26 @@ pub struct AddIpRoutesRequest {
     pub update_security_group_for_directory_controllers: Option<bool>,
 }
 
+impl Validate for AddIpRoutesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        for (i, item) in self.ip_routes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "ip_routes"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddIpRoutesResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToResourceRequest {
     /// <p>Identifier (ID) for the directory to which to add the tag.</p>
     #[serde(rename = "ResourceId")]
@@ -67,8 +95,22 @@ pub struct AddTagsToResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for AddTagsToResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "^[d]-[0-9a-f]{10}$",
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsToResourceResult {}
 
 /// <p>Represents a named directory attribute.</p>
@@ -84,7 +126,21 @@ pub struct Attribute {
     pub value: Option<String>,
 }
 
+impl Validate for Attribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelSchemaExtensionRequest {
     /// <p>The identifier of the directory whose schema extension will be canceled.</p>
     #[serde(rename = "DirectoryId")]
@@ -94,13 +150,28 @@ pub struct CancelSchemaExtensionRequest {
     pub schema_extension_id: String,
 }
 
+impl Validate for CancelSchemaExtensionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        errors.check_pattern(
+            &field_path(path, "schema_extension_id"),
+            &self.schema_extension_id,
+            "^e-[0-9a-f]{10}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelSchemaExtensionResult {}
 
 /// <p>Contains information about a computer account in a directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Computer {
     /// <p>An array of <a>Attribute</a> objects containing the LDAP attributes that belong to the computer account.</p>
     #[serde(rename = "ComputerAttributes")]
@@ -118,7 +189,7 @@ pub struct Computer {
 
 /// <p>Points to a remote domain with which you are setting up a trust relationship. Conditional forwarders are required in order to set up a trust relationship with another domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConditionalForwarder {
     /// <p>The IP addresses of the remote DNS server associated with RemoteDomainName. This is the IP address of the DNS server that your conditional forwarder points to.</p>
     #[serde(rename = "DnsIpAddrs")]
@@ -136,6 +207,7 @@ pub struct ConditionalForwarder {
 
 /// <p>Contains the inputs for the <a>ConnectDirectory</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConnectDirectoryRequest {
     /// <p>A <a>DirectoryConnectSettings</a> object that contains additional information for the operation.</p>
     #[serde(rename = "ConnectSettings")]
@@ -163,9 +235,53 @@ pub struct ConnectDirectoryRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for ConnectDirectoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.connect_settings
+            .validate_at(&field_path(path, "connect_settings"), errors);
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "^([a-zA-Z0-9_])[\\\\a-zA-Z0-9_@#%*+=:?./!\\s-]*$",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^([a-zA-Z0-9]+[\\\\.-])+([a-zA-Z0-9])+$",
+        );
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.short_name {
+            errors.check_pattern(
+                &field_path(path, "short_name"),
+                &value,
+                "^[^\\\\/:*?\"<>|.]+[^\\\\/:*?\"<>|]*$",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Contains the results of the <a>ConnectDirectory</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConnectDirectoryResult {
     /// <p>The identifier of the new directory.</p>
     #[serde(rename = "DirectoryId")]
@@ -175,6 +291,7 @@ pub struct ConnectDirectoryResult {
 
 /// <p>Contains the inputs for the <a>CreateAlias</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAliasRequest {
     /// <p>The requested alias.</p> <p>The alias must be unique amongst all aliases in AWS. This operation throws an <code>EntityAlreadyExistsException</code> error if the alias already exists.</p>
     #[serde(rename = "Alias")]
@@ -184,9 +301,30 @@ pub struct CreateAliasRequest {
     pub directory_id: String,
 }
 
+impl Validate for CreateAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "alias"),
+            self.alias.chars().count(),
+            Some(1),
+            Some(62),
+        );
+        errors.check_pattern(
+            &field_path(path, "alias"),
+            &self.alias,
+            "^(?!D-|d-)([\\da-zA-Z]+)([-]*[\\da-zA-Z])*",
+        );
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 /// <p>Contains the results of the <a>CreateAlias</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAliasResult {
     /// <p>The alias for the directory.</p>
     #[serde(rename = "Alias")]
@@ -200,6 +338,7 @@ pub struct CreateAliasResult {
 
 /// <p>Contains the inputs for the <a>CreateComputer</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateComputerRequest {
     /// <p>An array of <a>Attribute</a> objects that contain any LDAP attributes to apply to the computer account.</p>
     #[serde(rename = "ComputerAttributes")]
@@ -220,9 +359,50 @@ pub struct CreateComputerRequest {
     pub password: String,
 }
 
+impl Validate for CreateComputerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.computer_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "computer_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "computer_name"),
+            self.computer_name.chars().count(),
+            Some(1),
+            Some(15),
+        );
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        if let Some(ref value) = self.organizational_unit_distinguished_name {
+            errors.check_length(
+                &field_path(path, "organizational_unit_distinguished_name"),
+                value.chars().count(),
+                Some(1),
+                Some(2000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            Some(8),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "password"),
+            &self.password,
+            "[\\u0020-\\u00FF]+",
+        );
+    }
+}
+
 /// <p>Contains the results for the <a>CreateComputer</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateComputerResult {
     /// <p>A <a>Computer</a> object that represents the computer account.</p>
     #[serde(rename = "Computer")]
@@ -232,6 +412,7 @@ pub struct CreateComputerResult {
 
 /// <p>Initiates the creation of a conditional forwarder for your AWS Directory Service for Microsoft Active Directory. Conditional forwarders are required in order to set up a trust relationship with another domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConditionalForwarderRequest {
     /// <p>The directory ID of the AWS directory for which you are creating the conditional forwarder.</p>
     #[serde(rename = "DirectoryId")]
@@ -244,13 +425,39 @@ pub struct CreateConditionalForwarderRequest {
     pub remote_domain_name: String,
 }
 
+impl Validate for CreateConditionalForwarderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        for (i, item) in self.dns_ip_addrs.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "dns_ip_addrs"), i);
+            errors.check_pattern(&item_path, &item, "^(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)$");
+        }
+        errors.check_length(
+            &field_path(path, "remote_domain_name"),
+            self.remote_domain_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "remote_domain_name"),
+            &self.remote_domain_name,
+            "^([a-zA-Z0-9]+[\\\\.-])+([a-zA-Z0-9])+[.]?$",
+        );
+    }
+}
+
 /// <p>The result of a CreateConditinalForwarder request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateConditionalForwarderResult {}
 
 /// <p>Contains the inputs for the <a>CreateDirectory</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDirectoryRequest {
     /// <p>A textual description for the directory.</p>
     #[serde(rename = "Description")]
@@ -279,9 +486,49 @@ pub struct CreateDirectoryRequest {
     pub vpc_settings: Option<DirectoryVpcSettings>,
 }
 
+impl Validate for CreateDirectoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "^([a-zA-Z0-9_])[\\\\a-zA-Z0-9_@#%*+=:?./!\\s-]*$",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^([a-zA-Z0-9]+[\\\\.-])+([a-zA-Z0-9])+$",
+        );
+        errors.check_pattern(&field_path(path, "password"), &self.password, "(?=^.{8,64}$)((?=.*\\d)(?=.*[A-Z])(?=.*[a-z])|(?=.*\\d)(?=.*[^A-Za-z0-9\\s])(?=.*[a-z])|(?=.*[^A-Za-z0-9\\s])(?=.*[A-Z])(?=.*[a-z])|(?=.*\\d)(?=.*[A-Z])(?=.*[^A-Za-z0-9\\s]))^.*");
+        if let Some(ref value) = self.short_name {
+            errors.check_pattern(
+                &field_path(path, "short_name"),
+                &value,
+                "^[^\\\\/:*?\"<>|.]+[^\\\\/:*?\"<>|]*$",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.vpc_settings {
+            value.validate_at(&field_path(path, "vpc_settings"), errors);
+        }
+    }
+}
+
 /// <p>Contains the results of the <a>CreateDirectory</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDirectoryResult {
     /// <p>The identifier of the directory that was created.</p>
     #[serde(rename = "DirectoryId")]
@@ -290,6 +537,7 @@ pub struct CreateDirectoryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLogSubscriptionRequest {
     /// <p>Identifier (ID) of the directory to which you want to subscribe and receive real-time logs to your specified CloudWatch log group.</p>
     #[serde(rename = "DirectoryId")]
@@ -299,12 +547,34 @@ pub struct CreateLogSubscriptionRequest {
     pub log_group_name: String,
 }
 
+impl Validate for CreateLogSubscriptionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[-._/#A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLogSubscriptionResult {}
 
 /// <p>Creates an AWS Managed Microsoft AD directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMicrosoftADRequest {
     /// <p>A textual description for the directory. This label will appear on the AWS console <code>Directory Details</code> page after the directory is created.</p>
     #[serde(rename = "Description")]
@@ -333,9 +603,48 @@ pub struct CreateMicrosoftADRequest {
     pub vpc_settings: DirectoryVpcSettings,
 }
 
+impl Validate for CreateMicrosoftADRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "^([a-zA-Z0-9_])[\\\\a-zA-Z0-9_@#%*+=:?./!\\s-]*$",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^([a-zA-Z0-9]+[\\\\.-])+([a-zA-Z0-9])+$",
+        );
+        errors.check_pattern(&field_path(path, "password"), &self.password, "(?=^.{8,64}$)((?=.*\\d)(?=.*[A-Z])(?=.*[a-z])|(?=.*\\d)(?=.*[^A-Za-z0-9\\s])(?=.*[a-z])|(?=.*[^A-Za-z0-9\\s])(?=.*[A-Z])(?=.*[a-z])|(?=.*\\d)(?=.*[A-Z])(?=.*[^A-Za-z0-9\\s]))^.*");
+        if let Some(ref value) = self.short_name {
+            errors.check_pattern(
+                &field_path(path, "short_name"),
+                &value,
+                "^[^\\\\/:*?\"<>|.]+[^\\\\/:*?\"<>|]*$",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.vpc_settings
+            .validate_at(&field_path(path, "vpc_settings"), errors);
+    }
+}
+
 /// <p>Result of a CreateMicrosoftAD request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateMicrosoftADResult {
     /// <p>The identifier of the directory that was created.</p>
     #[serde(rename = "DirectoryId")]
@@ -345,6 +654,7 @@ pub struct CreateMicrosoftADResult {
 
 /// <p>Contains the inputs for the <a>CreateSnapshot</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSnapshotRequest {
     /// <p>The identifier of the directory of which to take a snapshot.</p>
     #[serde(rename = "DirectoryId")]
@@ -355,9 +665,32 @@ pub struct CreateSnapshotRequest {
     pub name: Option<String>,
 }
 
+impl Validate for CreateSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^([a-zA-Z0-9_])[\\\\a-zA-Z0-9_@#%*+=:?./!\\s-]*$",
+            );
+        }
+    }
+}
+
 /// <p>Contains the results of the <a>CreateSnapshot</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSnapshotResult {
     /// <p>The identifier of the snapshot that was created.</p>
     #[serde(rename = "SnapshotId")]
@@ -367,6 +700,7 @@ pub struct CreateSnapshotResult {
 
 /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure trust relationships. For example, you can establish a trust between your AWS Managed Microsoft AD directory, and your existing on-premises Microsoft Active Directory. This would allow you to provide users and groups access to resources in either domain, with a single set of credentials.</p> <p>This action initiates the creation of the AWS side of a trust relationship between an AWS Managed Microsoft AD directory and an external domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTrustRequest {
     /// <p>The IP addresses of the remote DNS server associated with RemoteDomainName.</p>
     #[serde(rename = "ConditionalForwarderIpAddrs")]
@@ -394,9 +728,51 @@ pub struct CreateTrustRequest {
     pub trust_type: Option<String>,
 }
 
+impl Validate for CreateTrustRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.conditional_forwarder_ip_addrs {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "conditional_forwarder_ip_addrs"),
+                    i
+                );
+                errors.check_pattern(&item_path, &item, "^(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)$");
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        errors.check_length(
+            &field_path(path, "remote_domain_name"),
+            self.remote_domain_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "remote_domain_name"),
+            &self.remote_domain_name,
+            "^([a-zA-Z0-9]+[\\\\.-])+([a-zA-Z0-9])+[.]?$",
+        );
+        errors.check_length(
+            &field_path(path, "trust_password"),
+            self.trust_password.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "trust_password"),
+            &self.trust_password,
+            "^(\\p{LD}|\\p{Punct}| )+$",
+        );
+    }
+}
+
 /// <p>The result of a CreateTrust request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTrustResult {
     /// <p>A unique identifier for the trust relationship that was created.</p>
     #[serde(rename = "TrustId")]
@@ -406,6 +782,7 @@ pub struct CreateTrustResult {
 
 /// <p>Deletes a conditional forwarder.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConditionalForwarderRequest {
     /// <p>The directory ID for which you are deleting the conditional forwarder.</p>
     #[serde(rename = "DirectoryId")]
@@ -415,22 +792,54 @@ pub struct DeleteConditionalForwarderRequest {
     pub remote_domain_name: String,
 }
 
+impl Validate for DeleteConditionalForwarderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        errors.check_length(
+            &field_path(path, "remote_domain_name"),
+            self.remote_domain_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "remote_domain_name"),
+            &self.remote_domain_name,
+            "^([a-zA-Z0-9]+[\\\\.-])+([a-zA-Z0-9])+[.]?$",
+        );
+    }
+}
+
 /// <p>The result of a DeleteConditionalForwarder request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteConditionalForwarderResult {}
 
 /// <p>Contains the inputs for the <a>DeleteDirectory</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDirectoryRequest {
     /// <p>The identifier of the directory to delete.</p>
     #[serde(rename = "DirectoryId")]
     pub directory_id: String,
 }
 
+impl Validate for DeleteDirectoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 /// <p>Contains the results of the <a>DeleteDirectory</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDirectoryResult {
     /// <p>The directory identifier.</p>
     #[serde(rename = "DirectoryId")]
@@ -439,27 +848,49 @@ pub struct DeleteDirectoryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLogSubscriptionRequest {
     /// <p>Identifier (ID) of the directory whose log subscription you want to delete.</p>
     #[serde(rename = "DirectoryId")]
     pub directory_id: String,
 }
 
+impl Validate for DeleteLogSubscriptionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLogSubscriptionResult {}
 
 /// <p>Contains the inputs for the <a>DeleteSnapshot</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSnapshotRequest {
     /// <p>The identifier of the directory snapshot to be deleted.</p>
     #[serde(rename = "SnapshotId")]
     pub snapshot_id: String,
 }
 
+impl Validate for DeleteSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "snapshot_id"),
+            &self.snapshot_id,
+            "^s-[0-9a-f]{10}$",
+        );
+    }
+}
+
 /// <p>Contains the results of the <a>DeleteSnapshot</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSnapshotResult {
     /// <p>The identifier of the directory snapshot that was deleted.</p>
     #[serde(rename = "SnapshotId")]
@@ -469,6 +900,7 @@ pub struct DeleteSnapshotResult {
 
 /// <p>Deletes the local side of an existing trust relationship between the AWS Managed Microsoft AD directory and the external domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTrustRequest {
     /// <p>Delete a conditional forwarder as part of a DeleteTrustRequest.</p>
     #[serde(rename = "DeleteAssociatedConditionalForwarder")]
@@ -479,9 +911,19 @@ pub struct DeleteTrustRequest {
     pub trust_id: String,
 }
 
+impl Validate for DeleteTrustRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "trust_id"),
+            &self.trust_id,
+            "^t-[0-9a-f]{10}$",
+        );
+    }
+}
+
 /// <p>The result of a DeleteTrust request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTrustResult {
     /// <p>The Trust ID of the trust relationship that was deleted.</p>
     #[serde(rename = "TrustId")]
@@ -491,6 +933,7 @@ pub struct DeleteTrustResult {
 
 /// <p>Removes the specified directory as a publisher to the specified SNS topic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterEventTopicRequest {
     /// <p>The Directory ID to remove as a publisher. This directory will no longer send messages to the specified SNS topic.</p>
     #[serde(rename = "DirectoryId")]
@@ -500,13 +943,35 @@ pub struct DeregisterEventTopicRequest {
     pub topic_name: String,
 }
 
+impl Validate for DeregisterEventTopicRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        errors.check_length(
+            &field_path(path, "topic_name"),
+            self.topic_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "topic_name"),
+            &self.topic_name,
+            "[a-zA-Z0-9_-]+",
+        );
+    }
+}
+
 /// <p>The result of a DeregisterEventTopic request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterEventTopicResult {}
 
 /// <p>Describes a conditional forwarder.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConditionalForwardersRequest {
     /// <p>The directory ID for which to get the list of associated conditional forwarders.</p>
     #[serde(rename = "DirectoryId")]
@@ -517,9 +982,30 @@ pub struct DescribeConditionalForwardersRequest {
     pub remote_domain_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeConditionalForwardersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        if let Some(ref value) = self.remote_domain_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "remote_domain_names"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "^([a-zA-Z0-9]+[\\\\.-])+([a-zA-Z0-9])+[.]?$",
+                );
+            }
+        }
+    }
+}
+
 /// <p>The result of a DescribeConditionalForwarder request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConditionalForwardersResult {
     /// <p>The list of conditional forwarders that have been created.</p>
     #[serde(rename = "ConditionalForwarders")]
@@ -529,6 +1015,7 @@ pub struct DescribeConditionalForwardersResult {
 
 /// <p>Contains the inputs for the <a>DescribeDirectories</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDirectoriesRequest {
     /// <p>A list of identifiers of the directories for which to obtain the information. If this member is null, all directories that belong to the current account are returned.</p> <p>An empty list results in an <code>InvalidParameterException</code> being thrown.</p>
     #[serde(rename = "DirectoryIds")]
@@ -544,9 +1031,20 @@ pub struct DescribeDirectoriesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeDirectoriesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.directory_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "directory_ids"), i);
+                errors.check_pattern(&item_path, &item, "^d-[0-9a-f]{10}$");
+            }
+        }
+    }
+}
+
 /// <p>Contains the results of the <a>DescribeDirectories</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDirectoriesResult {
     /// <p>The list of <a>DirectoryDescription</a> objects that were retrieved.</p> <p>It is possible that this list contains less than the number of items specified in the <code>Limit</code> member of the request. This occurs if there are less than the requested number of items left to retrieve, or if the limitations of the operation have been exceeded.</p>
     #[serde(rename = "DirectoryDescriptions")]
@@ -559,6 +1057,7 @@ pub struct DescribeDirectoriesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDomainControllersRequest {
     /// <p>Identifier of the directory for which to retrieve the domain controller information.</p>
     #[serde(rename = "DirectoryId")]
@@ -577,8 +1076,24 @@ pub struct DescribeDomainControllersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeDomainControllersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        if let Some(ref value) = self.domain_controller_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "domain_controller_ids"), i);
+                errors.check_pattern(&item_path, &item, "^dc-[0-9a-f]{10}$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDomainControllersResult {
     /// <p>List of the <a>DomainController</a> objects that were retrieved.</p>
     #[serde(rename = "DomainControllers")]
@@ -592,6 +1107,7 @@ pub struct DescribeDomainControllersResult {
 
 /// <p>Describes event topics.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventTopicsRequest {
     /// <p>The Directory ID for which to get the list of associated SNS topics. If this member is null, associations for all Directory IDs are returned.</p>
     #[serde(rename = "DirectoryId")]
@@ -603,9 +1119,28 @@ pub struct DescribeEventTopicsRequest {
     pub topic_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeEventTopicsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.directory_id {
+            errors.check_pattern(
+                &field_path(path, "directory_id"),
+                &value,
+                "^d-[0-9a-f]{10}$",
+            );
+        }
+        if let Some(ref value) = self.topic_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "topic_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(&item_path, &item, "[a-zA-Z0-9_-]+");
+            }
+        }
+    }
+}
+
 /// <p>The result of a DescribeEventTopic request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventTopicsResult {
     /// <p>A list of SNS topic names that receive status messages from the specified Directory ID.</p>
     #[serde(rename = "EventTopics")]
@@ -614,6 +1149,7 @@ pub struct DescribeEventTopicsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSharedDirectoriesRequest {
     /// <p>The number of shared directories to return in the response object.</p>
     #[serde(rename = "Limit")]
@@ -632,8 +1168,24 @@ pub struct DescribeSharedDirectoriesRequest {
     pub shared_directory_ids: Option<Vec<String>>,
 }
 
+impl Validate for DescribeSharedDirectoriesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "owner_directory_id"),
+            &self.owner_directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        if let Some(ref value) = self.shared_directory_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "shared_directory_ids"), i);
+                errors.check_pattern(&item_path, &item, "^d-[0-9a-f]{10}$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSharedDirectoriesResult {
     /// <p>If not null, token that indicates that more results are available. Pass this value for the <code>NextToken</code> parameter in a subsequent call to <a>DescribeSharedDirectories</a> to retrieve the next set of items.</p>
     #[serde(rename = "NextToken")]
@@ -647,6 +1199,7 @@ pub struct DescribeSharedDirectoriesResult {
 
 /// <p>Contains the inputs for the <a>DescribeSnapshots</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSnapshotsRequest {
     /// <p>The identifier of the directory for which to retrieve snapshot information.</p>
     #[serde(rename = "DirectoryId")]
@@ -666,9 +1219,27 @@ pub struct DescribeSnapshotsRequest {
     pub snapshot_ids: Option<Vec<String>>,
 }
 
+impl Validate for DescribeSnapshotsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.directory_id {
+            errors.check_pattern(
+                &field_path(path, "directory_id"),
+                &value,
+                "^d-[0-9a-f]{10}$",
+            );
+        }
+        if let Some(ref value) = self.snapshot_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "snapshot_ids"), i);
+                errors.check_pattern(&item_path, &item, "^s-[0-9a-f]{10}$");
+            }
+        }
+    }
+}
+
 /// <p>Contains the results of the <a>DescribeSnapshots</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSnapshotsResult {
     /// <p>If not null, more results are available. Pass this value in the <i>NextToken</i> member of a subsequent call to <a>DescribeSnapshots</a>.</p>
     #[serde(rename = "NextToken")]
@@ -682,6 +1253,7 @@ pub struct DescribeSnapshotsResult {
 
 /// <p>Describes the trust relationships for a particular AWS Managed Microsoft AD directory. If no input parameters are are provided, such as directory ID or trust ID, this request describes all the trust relationships.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTrustsRequest {
     /// <p>The Directory ID of the AWS directory that is a part of the requested trust relationship.</p>
     #[serde(rename = "DirectoryId")]
@@ -701,9 +1273,27 @@ pub struct DescribeTrustsRequest {
     pub trust_ids: Option<Vec<String>>,
 }
 
+impl Validate for DescribeTrustsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.directory_id {
+            errors.check_pattern(
+                &field_path(path, "directory_id"),
+                &value,
+                "^d-[0-9a-f]{10}$",
+            );
+        }
+        if let Some(ref value) = self.trust_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "trust_ids"), i);
+                errors.check_pattern(&item_path, &item, "^t-[0-9a-f]{10}$");
+            }
+        }
+    }
+}
+
 /// <p>The result of a DescribeTrust request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTrustsResult {
     /// <p>If not null, more results are available. Pass this value for the <i>NextToken</i> parameter in a subsequent call to <a>DescribeTrusts</a> to retrieve the next set of items.</p>
     #[serde(rename = "NextToken")]
@@ -717,6 +1307,7 @@ pub struct DescribeTrustsResult {
 
 /// <p>Contains information for the <a>ConnectDirectory</a> operation when an AD Connector directory is being created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DirectoryConnectSettings {
     /// <p>A list of one or more IP addresses of DNS servers or domain controllers in the on-premises directory.</p>
     #[serde(rename = "CustomerDnsIps")]
@@ -732,9 +1323,42 @@ pub struct DirectoryConnectSettings {
     pub vpc_id: String,
 }
 
+impl Validate for DirectoryConnectSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.customer_dns_ips.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "customer_dns_ips"), i);
+            errors.check_pattern(&item_path, &item, "^(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)$");
+        }
+        errors.check_length(
+            &field_path(path, "customer_user_name"),
+            self.customer_user_name.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "customer_user_name"),
+            &self.customer_user_name,
+            "[a-zA-Z0-9._-]+",
+        );
+        for (i, item) in self.subnet_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subnet_ids"), i);
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "^(subnet-[0-9a-f]{8}|subnet-[0-9a-f]{17})$",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "vpc_id"),
+            &self.vpc_id,
+            "^(vpc-[0-9a-f]{8}|vpc-[0-9a-f]{17})$",
+        );
+    }
+}
+
 /// <p>Contains information about an AD Connector directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DirectoryConnectSettingsDescription {
     /// <p>A list of the Availability Zones that the directory is in.</p>
     #[serde(rename = "AvailabilityZones")]
@@ -764,7 +1388,7 @@ pub struct DirectoryConnectSettingsDescription {
 
 /// <p>Contains information about an AWS Directory Service directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DirectoryDescription {
     /// <p>The access URL for the directory, such as <code>http://&lt;alias&gt;.awsapps.com</code>. If no alias has been created for the directory, <code>&lt;alias&gt;</code> is the directory identifier, such as <code>d-XXXXXXXXXX</code>.</p>
     #[serde(rename = "AccessUrl")]
@@ -866,7 +1490,7 @@ pub struct DirectoryDescription {
 
 /// <p>Contains directory limit information for a region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DirectoryLimits {
     /// <p>The current number of cloud directories in the region.</p>
     #[serde(rename = "CloudOnlyDirectoriesCurrentCount")]
@@ -908,6 +1532,7 @@ pub struct DirectoryLimits {
 
 /// <p>Contains VPC information for the <a>CreateDirectory</a> or <a>CreateMicrosoftAD</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DirectoryVpcSettings {
     /// <p>The identifiers of the subnets for the directory servers. The two subnets must be in different Availability Zones. AWS Directory Service creates a directory server and a DNS server in each of these subnets.</p>
     #[serde(rename = "SubnetIds")]
@@ -917,9 +1542,27 @@ pub struct DirectoryVpcSettings {
     pub vpc_id: String,
 }
 
+impl Validate for DirectoryVpcSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.subnet_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subnet_ids"), i);
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "^(subnet-[0-9a-f]{8}|subnet-[0-9a-f]{17})$",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "vpc_id"),
+            &self.vpc_id,
+            "^(vpc-[0-9a-f]{8}|vpc-[0-9a-f]{17})$",
+        );
+    }
+}
+
 /// <p>Contains information about the directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DirectoryVpcSettingsDescription {
     /// <p>The list of Availability Zones that the directory is in.</p>
     #[serde(rename = "AvailabilityZones")]
@@ -941,19 +1584,31 @@ pub struct DirectoryVpcSettingsDescription {
 
 /// <p>Contains the inputs for the <a>DisableRadius</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableRadiusRequest {
     /// <p>The identifier of the directory for which to disable MFA.</p>
     #[serde(rename = "DirectoryId")]
     pub directory_id: String,
 }
 
+impl Validate for DisableRadiusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 /// <p>Contains the results of the <a>DisableRadius</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisableRadiusResult {}
 
 /// <p>Contains the inputs for the <a>DisableSso</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableSsoRequest {
     /// <p>The identifier of the directory for which to disable single-sign on.</p>
     #[serde(rename = "DirectoryId")]
@@ -968,14 +1623,41 @@ pub struct DisableSsoRequest {
     pub user_name: Option<String>,
 }
 
+impl Validate for DisableSsoRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        if let Some(ref value) = self.password {
+            errors.check_length(
+                &field_path(path, "password"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.user_name {
+            errors.check_length(
+                &field_path(path, "user_name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "user_name"), &value, "[a-zA-Z0-9._-]+");
+        }
+    }
+}
+
 /// <p>Contains the results of the <a>DisableSso</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisableSsoResult {}
 
 /// <p>Contains information about the domain controllers for a specified directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainController {
     /// <p>The Availability Zone where the domain controller is located.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -1021,6 +1703,7 @@ pub struct DomainController {
 
 /// <p>Contains the inputs for the <a>EnableRadius</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableRadiusRequest {
     /// <p>The identifier of the directory for which to enable MFA.</p>
     #[serde(rename = "DirectoryId")]
@@ -1030,13 +1713,26 @@ pub struct EnableRadiusRequest {
     pub radius_settings: RadiusSettings,
 }
 
+impl Validate for EnableRadiusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        self.radius_settings
+            .validate_at(&field_path(path, "radius_settings"), errors);
+    }
+}
+
 /// <p>Contains the results of the <a>EnableRadius</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableRadiusResult {}
 
 /// <p>Contains the inputs for the <a>EnableSso</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableSsoRequest {
     /// <p>The identifier of the directory for which to enable single-sign on.</p>
     #[serde(rename = "DirectoryId")]
@@ -1051,14 +1747,41 @@ pub struct EnableSsoRequest {
     pub user_name: Option<String>,
 }
 
+impl Validate for EnableSsoRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        if let Some(ref value) = self.password {
+            errors.check_length(
+                &field_path(path, "password"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.user_name {
+            errors.check_length(
+                &field_path(path, "user_name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "user_name"), &value, "[a-zA-Z0-9._-]+");
+        }
+    }
+}
+
 /// <p>Contains the results of the <a>EnableSso</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableSsoResult {}
 
 /// <p>Information about SNS topic and AWS Directory Service directory associations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventTopic {
     /// <p>The date and time of when you associated your directory with the SNS topic.</p>
     #[serde(rename = "CreatedDateTime")]
@@ -1084,11 +1807,12 @@ pub struct EventTopic {
 
 /// <p>Contains the inputs for the <a>GetDirectoryLimits</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDirectoryLimitsRequest {}
 
 /// <p>Contains the results of the <a>GetDirectoryLimits</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDirectoryLimitsResult {
     /// <p>A <a>DirectoryLimits</a> object that contains the directory limits for the current region.</p>
     #[serde(rename = "DirectoryLimits")]
@@ -1098,15 +1822,26 @@ pub struct GetDirectoryLimitsResult {
 
 /// <p>Contains the inputs for the <a>GetSnapshotLimits</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSnapshotLimitsRequest {
     /// <p>Contains the identifier of the directory to obtain the limits for.</p>
     #[serde(rename = "DirectoryId")]
     pub directory_id: String,
 }
 
+impl Validate for GetSnapshotLimitsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 /// <p>Contains the results of the <a>GetSnapshotLimits</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSnapshotLimitsResult {
     /// <p>A <a>SnapshotLimits</a> object that contains the manual snapshot limits for the specified directory.</p>
     #[serde(rename = "SnapshotLimits")]
@@ -1116,6 +1851,7 @@ pub struct GetSnapshotLimitsResult {
 
 /// <p>IP address block. This is often the address block of the DNS server used for your on-premises domain. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IpRoute {
     /// <p>IP address block using CIDR format, for example 10.0.0.0/24. This is often the address block of the DNS server used for your on-premises domain. For a single IP address use a CIDR address block with /32. For example 10.0.0.0/32.</p>
     #[serde(rename = "CidrIp")]
@@ -1127,9 +1863,30 @@ pub struct IpRoute {
     pub description: Option<String>,
 }
 
+impl Validate for IpRoute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cidr_ip {
+            errors.check_pattern(&field_path(path, "cidr_ip"), &value, "^(([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])(\\/([1-9]|[1-2][0-9]|3[0-2]))$");
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "^([a-zA-Z0-9_])[\\\\a-zA-Z0-9_@#%*+=:?./!\\s-]*$",
+            );
+        }
+    }
+}
+
 /// <p>Information about one or more IP address blocks.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IpRouteInfo {
     /// <p>The date and time the address block was added to the directory.</p>
     #[serde(rename = "AddedDateTime")]
@@ -1158,6 +1915,7 @@ pub struct IpRouteInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIpRoutesRequest {
     /// <p>Identifier (ID) of the directory for which you want to retrieve the IP addresses.</p>
     #[serde(rename = "DirectoryId")]
@@ -1172,8 +1930,18 @@ pub struct ListIpRoutesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListIpRoutesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListIpRoutesResult {
     /// <p>A list of <a>IpRoute</a>s.</p>
     #[serde(rename = "IpRoutesInfo")]
@@ -1186,6 +1954,7 @@ pub struct ListIpRoutesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLogSubscriptionsRequest {
     /// <p>If a <i>DirectoryID</i> is provided, lists only the log subscription associated with that directory. If no <i>DirectoryId</i> is provided, lists all log subscriptions associated with your AWS account. If there are no log subscriptions for the AWS account or the directory, an empty list will be returned.</p>
     #[serde(rename = "DirectoryId")]
@@ -1201,8 +1970,20 @@ pub struct ListLogSubscriptionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListLogSubscriptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.directory_id {
+            errors.check_pattern(
+                &field_path(path, "directory_id"),
+                &value,
+                "^d-[0-9a-f]{10}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLogSubscriptionsResult {
     /// <p>A list of active <a>LogSubscription</a> objects for calling the AWS account.</p>
     #[serde(rename = "LogSubscriptions")]
@@ -1215,6 +1996,7 @@ pub struct ListLogSubscriptionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSchemaExtensionsRequest {
     /// <p>The identifier of the directory from which to retrieve the schema extension information.</p>
     #[serde(rename = "DirectoryId")]
@@ -1229,8 +2011,18 @@ pub struct ListSchemaExtensionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListSchemaExtensionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSchemaExtensionsResult {
     /// <p>If not null, more results are available. Pass this value for the <code>NextToken</code> parameter in a subsequent call to <code>ListSchemaExtensions</code> to retrieve the next set of items.</p>
     #[serde(rename = "NextToken")]
@@ -1243,6 +2035,7 @@ pub struct ListSchemaExtensionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>Reserved for future use.</p>
     #[serde(rename = "Limit")]
@@ -1257,8 +2050,18 @@ pub struct ListTagsForResourceRequest {
     pub resource_id: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "^[d]-[0-9a-f]{10}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResult {
     /// <p>Reserved for future use.</p>
     #[serde(rename = "NextToken")]
@@ -1272,7 +2075,7 @@ pub struct ListTagsForResourceResult {
 
 /// <p>Represents a log subscription, which tracks real-time data from a chosen log group to a specified destination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LogSubscription {
     /// <p>Identifier (ID) of the directory that you want to associate with the log subscription.</p>
     #[serde(rename = "DirectoryId")]
@@ -1290,7 +2093,7 @@ pub struct LogSubscription {
 
 /// <p>Describes the directory owner account details that have been shared to the directory consumer account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OwnerDirectoryDescription {
     /// <p>Identifier of the directory owner account.</p>
     #[serde(rename = "AccountId")]
@@ -1355,8 +2158,60 @@ pub struct RadiusSettings {
     pub use_same_username: Option<bool>,
 }
 
+impl Validate for RadiusSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.display_label {
+            errors.check_length(
+                &field_path(path, "display_label"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.radius_port {
+            errors.check_range(
+                &field_path(path, "radius_port"),
+                *value,
+                Some(1025),
+                Some(65535),
+            );
+        }
+        if let Some(ref value) = self.radius_retries {
+            errors.check_range(&field_path(path, "radius_retries"), *value, None, Some(10));
+        }
+        if let Some(ref value) = self.radius_servers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "radius_servers"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+        if let Some(ref value) = self.radius_timeout {
+            errors.check_range(
+                &field_path(path, "radius_timeout"),
+                *value,
+                Some(1),
+                Some(20),
+            );
+        }
+        if let Some(ref value) = self.shared_secret {
+            errors.check_length(
+                &field_path(path, "shared_secret"),
+                value.chars().count(),
+                Some(8),
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "shared_secret"),
+                &value,
+                "^(\\p{LD}|\\p{Punct}| )+$",
+            );
+        }
+    }
+}
+
 /// <p>Registers a new event topic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterEventTopicRequest {
     /// <p>The Directory ID that will publish status messages to the SNS topic.</p>
     #[serde(rename = "DirectoryId")]
@@ -1366,20 +2221,52 @@ pub struct RegisterEventTopicRequest {
     pub topic_name: String,
 }
 
+impl Validate for RegisterEventTopicRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        errors.check_length(
+            &field_path(path, "topic_name"),
+            self.topic_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "topic_name"),
+            &self.topic_name,
+            "[a-zA-Z0-9_-]+",
+        );
+    }
+}
+
 /// <p>The result of a RegisterEventTopic request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterEventTopicResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RejectSharedDirectoryRequest {
     /// <p>Identifier of the shared directory in the directory consumer account. This identifier is different for each directory owner account.</p>
     #[serde(rename = "SharedDirectoryId")]
     pub shared_directory_id: String,
 }
 
+impl Validate for RejectSharedDirectoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "shared_directory_id"),
+            &self.shared_directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RejectSharedDirectoryResult {
     /// <p>Identifier of the shared directory in the directory consumer account.</p>
     #[serde(rename = "SharedDirectoryId")]
@@ -1388,6 +2275,7 @@ pub struct RejectSharedDirectoryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveIpRoutesRequest {
     /// <p>IP address blocks that you want to remove.</p>
     #[serde(rename = "CidrIps")]
@@ -1397,11 +2285,26 @@ pub struct RemoveIpRoutesRequest {
     pub directory_id: String,
 }
 
+impl Validate for RemoveIpRoutesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.cidr_ips.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "cidr_ips"), i);
+            errors.check_pattern(&item_path, &item, "^(([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])(\\/([1-9]|[1-2][0-9]|3[0-2]))$");
+        }
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveIpRoutesResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromResourceRequest {
     /// <p>Identifier (ID) of the directory from which to remove the tag.</p>
     #[serde(rename = "ResourceId")]
@@ -1411,11 +2314,27 @@ pub struct RemoveTagsFromResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for RemoveTagsFromResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "^[d]-[0-9a-f]{10}$",
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsFromResourceResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResetUserPasswordRequest {
     /// <p>Identifier of the AWS Managed Microsoft AD or Simple AD directory in which the user resides.</p>
     #[serde(rename = "DirectoryId")]
@@ -1428,26 +2347,60 @@ pub struct ResetUserPasswordRequest {
     pub user_name: String,
 }
 
+impl Validate for ResetUserPasswordRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        errors.check_length(
+            &field_path(path, "new_password"),
+            self.new_password.chars().count(),
+            Some(1),
+            Some(127),
+        );
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "user_name"), &self.user_name, "^(?!.*\\\\|.*\"|.*\\/|.*\\[|.*\\]|.*:|.*;|.*\\||.*=|.*,|.*\\+|.*\\*|.*\\?|.*<|.*>|.*@).*$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResetUserPasswordResult {}
 
 /// <p>An object representing the inputs for the <a>RestoreFromSnapshot</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreFromSnapshotRequest {
     /// <p>The identifier of the snapshot to restore from.</p>
     #[serde(rename = "SnapshotId")]
     pub snapshot_id: String,
 }
 
+impl Validate for RestoreFromSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "snapshot_id"),
+            &self.snapshot_id,
+            "^s-[0-9a-f]{10}$",
+        );
+    }
+}
+
 /// <p>Contains the results of the <a>RestoreFromSnapshot</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestoreFromSnapshotResult {}
 
 /// <p>Information about a schema extension.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SchemaExtensionInfo {
     /// <p>A description of the schema extension.</p>
     #[serde(rename = "Description")]
@@ -1480,6 +2433,7 @@ pub struct SchemaExtensionInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ShareDirectoryRequest {
     /// <p>Identifier of the AWS Managed Microsoft AD directory that you want to share with other AWS accounts.</p>
     #[serde(rename = "DirectoryId")]
@@ -1496,8 +2450,28 @@ pub struct ShareDirectoryRequest {
     pub share_target: ShareTarget,
 }
 
+impl Validate for ShareDirectoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        if let Some(ref value) = self.share_notes {
+            errors.check_length(
+                &field_path(path, "share_notes"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        self.share_target
+            .validate_at(&field_path(path, "share_target"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ShareDirectoryResult {
     /// <p>Identifier of the directory that is stored in the directory consumer account that is shared from the specified directory (<code>DirectoryId</code>).</p>
     #[serde(rename = "SharedDirectoryId")]
@@ -1507,6 +2481,7 @@ pub struct ShareDirectoryResult {
 
 /// <p>Identifier that contains details about the directory consumer account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ShareTarget {
     /// <p>Identifier of the directory consumer account.</p>
     #[serde(rename = "Id")]
@@ -1516,9 +2491,20 @@ pub struct ShareTarget {
     pub type_: String,
 }
 
+impl Validate for ShareTarget {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// <p>Details about the shared directory in the directory owner account for which the share request in the directory consumer account has been accepted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SharedDirectory {
     /// <p>The date and time that the shared directory was created.</p>
     #[serde(rename = "CreatedDateTime")]
@@ -1560,7 +2546,7 @@ pub struct SharedDirectory {
 
 /// <p>Describes a directory snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Snapshot {
     /// <p>The directory identifier.</p>
     #[serde(rename = "DirectoryId")]
@@ -1590,7 +2576,7 @@ pub struct Snapshot {
 
 /// <p>Contains manual snapshot limit information for a directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SnapshotLimits {
     /// <p>The current number of manual snapshots of the directory.</p>
     #[serde(rename = "ManualSnapshotsCurrentCount")]
@@ -1607,6 +2593,7 @@ pub struct SnapshotLimits {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartSchemaExtensionRequest {
     /// <p>If true, creates a snapshot of the directory before applying the schema extension.</p>
     #[serde(rename = "CreateSnapshotBeforeSchemaExtension")]
@@ -1622,8 +2609,35 @@ pub struct StartSchemaExtensionRequest {
     pub ldif_content: String,
 }
 
+impl Validate for StartSchemaExtensionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "description"),
+            self.description.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "description"),
+            &self.description,
+            "^([a-zA-Z0-9_])[\\\\a-zA-Z0-9_@#%*+=:?./!\\s-]*$",
+        );
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        errors.check_length(
+            &field_path(path, "ldif_content"),
+            self.ldif_content.chars().count(),
+            Some(1),
+            Some(500000),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartSchemaExtensionResult {
     /// <p>The identifier of the schema extension that will be applied.</p>
     #[serde(rename = "SchemaExtensionId")]
@@ -1642,9 +2656,36 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 /// <p>Describes a trust relationship between an AWS Managed Microsoft AD directory and an external domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Trust {
     /// <p>The date and time that the trust relationship was created.</p>
     #[serde(rename = "CreatedDateTime")]
@@ -1693,6 +2734,7 @@ pub struct Trust {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnshareDirectoryRequest {
     /// <p>The identifier of the AWS Managed Microsoft AD directory that you want to stop sharing.</p>
     #[serde(rename = "DirectoryId")]
@@ -1702,8 +2744,20 @@ pub struct UnshareDirectoryRequest {
     pub unshare_target: UnshareTarget,
 }
 
+impl Validate for UnshareDirectoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        self.unshare_target
+            .validate_at(&field_path(path, "unshare_target"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnshareDirectoryResult {
     /// <p>Identifier of the directory stored in the directory consumer account that is to be unshared from the specified directory (<code>DirectoryId</code>).</p>
     #[serde(rename = "SharedDirectoryId")]
@@ -1713,6 +2767,7 @@ pub struct UnshareDirectoryResult {
 
 /// <p>Identifier that contains details about the directory consumer account with whom the directory is being unshared.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnshareTarget {
     /// <p>Identifier of the directory consumer account.</p>
     #[serde(rename = "Id")]
@@ -1722,8 +2777,20 @@ pub struct UnshareTarget {
     pub type_: String,
 }
 
+impl Validate for UnshareTarget {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// <p>Updates a conditional forwarder.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConditionalForwarderRequest {
     /// <p>The directory ID of the AWS directory for which to update the conditional forwarder.</p>
     #[serde(rename = "DirectoryId")]
@@ -1736,12 +2803,38 @@ pub struct UpdateConditionalForwarderRequest {
     pub remote_domain_name: String,
 }
 
+impl Validate for UpdateConditionalForwarderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        for (i, item) in self.dns_ip_addrs.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "dns_ip_addrs"), i);
+            errors.check_pattern(&item_path, &item, "^(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)$");
+        }
+        errors.check_length(
+            &field_path(path, "remote_domain_name"),
+            self.remote_domain_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "remote_domain_name"),
+            &self.remote_domain_name,
+            "^([a-zA-Z0-9]+[\\\\.-])+([a-zA-Z0-9])+[.]?$",
+        );
+    }
+}
+
 /// <p>The result of an UpdateConditionalForwarder request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateConditionalForwarderResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateNumberOfDomainControllersRequest {
     /// <p>The number of domain controllers desired in the directory.</p>
     #[serde(rename = "DesiredNumber")]
@@ -1751,12 +2844,29 @@ pub struct UpdateNumberOfDomainControllersRequest {
     pub directory_id: String,
 }
 
+impl Validate for UpdateNumberOfDomainControllersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "desired_number"),
+            self.desired_number,
+            Some(2),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateNumberOfDomainControllersResult {}
 
 /// <p>Contains the inputs for the <a>UpdateRadius</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRadiusRequest {
     /// <p>The identifier of the directory for which to update the RADIUS server information.</p>
     #[serde(rename = "DirectoryId")]
@@ -1766,12 +2876,25 @@ pub struct UpdateRadiusRequest {
     pub radius_settings: RadiusSettings,
 }
 
+impl Validate for UpdateRadiusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{10}$",
+        );
+        self.radius_settings
+            .validate_at(&field_path(path, "radius_settings"), errors);
+    }
+}
+
 /// <p>Contains the results of the <a>UpdateRadius</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRadiusResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTrustRequest {
     /// <p>Updates selective authentication for the trust.</p>
     #[serde(rename = "SelectiveAuth")]
@@ -1782,8 +2905,18 @@ pub struct UpdateTrustRequest {
     pub trust_id: String,
 }
 
+impl Validate for UpdateTrustRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "trust_id"),
+            &self.trust_id,
+            "^t-[0-9a-f]{10}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTrustResult {
     #[serde(rename = "RequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1796,15 +2929,26 @@ pub struct UpdateTrustResult {
 
 /// <p>Initiates the verification of an existing trust relationship between an AWS Managed Microsoft AD directory and an external domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VerifyTrustRequest {
     /// <p>The unique Trust ID of the trust relationship to verify.</p>
     #[serde(rename = "TrustId")]
     pub trust_id: String,
 }
 
+impl Validate for VerifyTrustRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "trust_id"),
+            &self.trust_id,
+            "^t-[0-9a-f]{10}$",
+        );
+    }
+}
+
 /// <p>Result of a VerifyTrust request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VerifyTrustResult {
     /// <p>The unique Trust ID of the trust relationship that was verified.</p>
     #[serde(rename = "TrustId")]
@@ -1852,7 +2996,12 @@ impl AcceptSharedDirectoryError {
                 "ServiceException" => {
                     return RusotoError::Service(AcceptSharedDirectoryError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1919,7 +3068,12 @@ impl AddIpRoutesError {
                 "ServiceException" => {
                     return RusotoError::Service(AddIpRoutesError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1980,7 +3134,12 @@ impl AddTagsToResourceError {
                 "TagLimitExceededException" => {
                     return RusotoError::Service(AddTagsToResourceError::TagLimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2029,7 +3188,12 @@ impl CancelSchemaExtensionError {
                 "ServiceException" => {
                     return RusotoError::Service(CancelSchemaExtensionError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2081,7 +3245,12 @@ impl ConnectDirectoryError {
                 "ServiceException" => {
                     return RusotoError::Service(ConnectDirectoryError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2137,7 +3306,12 @@ impl CreateAliasError {
                 "ServiceException" => {
                     return RusotoError::Service(CreateAliasError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2209,7 +3383,12 @@ impl CreateComputerError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(CreateComputerError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2291,7 +3470,12 @@ impl CreateConditionalForwarderError {
                         CreateConditionalForwarderError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2347,7 +3531,12 @@ impl CreateDirectoryError {
                 "ServiceException" => {
                     return RusotoError::Service(CreateDirectoryError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2416,7 +3605,12 @@ impl CreateLogSubscriptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2478,7 +3672,12 @@ impl CreateMicrosoftADError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2537,7 +3736,12 @@ impl CreateSnapshotError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2599,7 +3803,12 @@ impl CreateTrustError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(CreateTrustError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2672,7 +3881,12 @@ impl DeleteConditionalForwarderError {
                         DeleteConditionalForwarderError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2720,7 +3934,12 @@ impl DeleteDirectoryError {
                 "ServiceException" => {
                     return RusotoError::Service(DeleteDirectoryError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2774,7 +3993,12 @@ impl DeleteLogSubscriptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2825,7 +4049,12 @@ impl DeleteSnapshotError {
                 "ServiceException" => {
                     return RusotoError::Service(DeleteSnapshotError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2881,7 +4110,12 @@ impl DeleteTrustError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(DeleteTrustError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2937,7 +4171,12 @@ impl DeregisterEventTopicError {
                 "ServiceException" => {
                     return RusotoError::Service(DeregisterEventTopicError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3012,7 +4251,12 @@ impl DescribeConditionalForwardersError {
                         DescribeConditionalForwardersError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3076,7 +4320,12 @@ impl DescribeDirectoriesError {
                 "ServiceException" => {
                     return RusotoError::Service(DescribeDirectoriesError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3146,7 +4395,12 @@ impl DescribeDomainControllersError {
                         DescribeDomainControllersError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3203,7 +4457,12 @@ impl DescribeEventTopicsError {
                 "ServiceException" => {
                     return RusotoError::Service(DescribeEventTopicsError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3272,7 +4531,12 @@ impl DescribeSharedDirectoriesError {
                         DescribeSharedDirectoriesError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3332,7 +4596,12 @@ impl DescribeSnapshotsError {
                 "ServiceException" => {
                     return RusotoError::Service(DescribeSnapshotsError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3394,7 +4663,12 @@ impl DescribeTrustsError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(DescribeTrustsError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3442,7 +4716,12 @@ impl DisableRadiusError {
                 "ServiceException" => {
                     return RusotoError::Service(DisableRadiusError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3495,7 +4774,12 @@ impl DisableSsoError {
                 "ServiceException" => {
                     return RusotoError::Service(DisableSsoError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3552,7 +4836,12 @@ impl EnableRadiusError {
                 "ServiceException" => {
                     return RusotoError::Service(EnableRadiusError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3607,7 +4896,12 @@ impl EnableSsoError {
                 "ServiceException" => {
                     return RusotoError::Service(EnableSsoError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3656,7 +4950,12 @@ impl GetDirectoryLimitsError {
                 "ServiceException" => {
                     return RusotoError::Service(GetDirectoryLimitsError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3703,7 +5002,12 @@ impl GetSnapshotLimitsError {
                 "ServiceException" => {
                     return RusotoError::Service(GetSnapshotLimitsError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3758,7 +5062,12 @@ impl ListIpRoutesError {
                 "ServiceException" => {
                     return RusotoError::Service(ListIpRoutesError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3814,7 +5123,12 @@ impl ListLogSubscriptionsError {
                 "ServiceException" => {
                     return RusotoError::Service(ListLogSubscriptionsError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3869,7 +5183,12 @@ impl ListSchemaExtensionsError {
                 "ServiceException" => {
                     return RusotoError::Service(ListSchemaExtensionsError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3931,7 +5250,12 @@ impl ListTagsForResourceError {
                 "ServiceException" => {
                     return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3985,7 +5309,12 @@ impl RegisterEventTopicError {
                 "ServiceException" => {
                     return RusotoError::Service(RegisterEventTopicError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4047,7 +5376,12 @@ impl RejectSharedDirectoryError {
                 "ServiceException" => {
                     return RusotoError::Service(RejectSharedDirectoryError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4104,7 +5438,12 @@ impl RemoveIpRoutesError {
                 "ServiceException" => {
                     return RusotoError::Service(RemoveIpRoutesError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4160,7 +5499,12 @@ impl RemoveTagsFromResourceError {
                 "ServiceException" => {
                     return RusotoError::Service(RemoveTagsFromResourceError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4232,7 +5576,12 @@ impl ResetUserPasswordError {
                 "UserDoesNotExistException" => {
                     return RusotoError::Service(ResetUserPasswordError::UserDoesNotExist(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4290,7 +5639,12 @@ impl RestoreFromSnapshotError {
                 "ServiceException" => {
                     return RusotoError::Service(RestoreFromSnapshotError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4373,7 +5727,12 @@ impl ShareDirectoryError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(ShareDirectoryError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4448,7 +5807,12 @@ impl StartSchemaExtensionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4506,7 +5870,12 @@ impl UnshareDirectoryError {
                 "ServiceException" => {
                     return RusotoError::Service(UnshareDirectoryError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4578,7 +5947,12 @@ impl UpdateConditionalForwarderError {
                         UpdateConditionalForwarderError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4664,7 +6038,12 @@ impl UpdateNumberOfDomainControllersError {
                         UpdateNumberOfDomainControllersError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4718,7 +6097,12 @@ impl UpdateRadiusError {
                 "ServiceException" => {
                     return RusotoError::Service(UpdateRadiusError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4769,7 +6153,12 @@ impl UpdateTrustError {
                 "ServiceException" => {
                     return RusotoError::Service(UpdateTrustError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4825,7 +6214,12 @@ impl VerifyTrustError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(VerifyTrustError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5146,1537 +6540,4581 @@ pub trait DirectoryService {
         input: VerifyTrustRequest,
     ) -> RusotoFuture<VerifyTrustResult, VerifyTrustError>;
 }
-/// A client for the Directory Service API.
-#[derive(Clone)]
-pub struct DirectoryServiceClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl DirectoryServiceClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> DirectoryServiceClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> DirectoryServiceClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> DirectoryServiceClient {
-        DirectoryServiceClient { client, region }
-    }
-}
-
-impl DirectoryService for DirectoryServiceClient {
-    /// <p>Accepts a directory sharing request that was sent from the directory owner account.</p>
+const _: Option<&dyn DirectoryService> = None;
+impl<'a, T: DirectoryService + ?Sized> DirectoryService for &'a T {
     fn accept_shared_directory(
         &self,
         input: AcceptSharedDirectoryRequest,
     ) -> RusotoFuture<AcceptSharedDirectoryResult, AcceptSharedDirectoryError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.AcceptSharedDirectory",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AcceptSharedDirectoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AcceptSharedDirectoryError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).accept_shared_directory(input)
     }
-
-    /// <p>If the DNS server for your on-premises domain uses a publicly addressable IP address, you must add a CIDR address block to correctly route traffic to and from your Microsoft AD on Amazon Web Services. <i>AddIpRoutes</i> adds this address block. You can also use <i>AddIpRoutes</i> to facilitate routing traffic that uses public IP ranges from your Microsoft AD on AWS to a peer VPC. </p> <p>Before you call <i>AddIpRoutes</i>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <i>AddIpRoutes</i> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
     fn add_ip_routes(
         &self,
         input: AddIpRoutesRequest,
     ) -> RusotoFuture<AddIpRoutesResult, AddIpRoutesError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.AddIpRoutes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddIpRoutesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddIpRoutesError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_ip_routes(input)
     }
-
-    /// <p>Adds or overwrites one or more tags for the specified directory. Each directory can have a maximum of 50 tags. Each tag consists of a key and optional value. Tag keys must be unique to each resource.</p>
     fn add_tags_to_resource(
         &self,
         input: AddTagsToResourceRequest,
     ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.AddTagsToResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddTagsToResourceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_tags_to_resource(input)
     }
-
-    /// <p>Cancels an in-progress schema extension to a Microsoft AD directory. Once a schema extension has started replicating to all domain controllers, the task can no longer be canceled. A schema extension can be canceled during any of the following states; <code>Initializing</code>, <code>CreatingSnapshot</code>, and <code>UpdatingSchema</code>.</p>
     fn cancel_schema_extension(
         &self,
         input: CancelSchemaExtensionRequest,
     ) -> RusotoFuture<CancelSchemaExtensionResult, CancelSchemaExtensionError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.CancelSchemaExtension",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelSchemaExtensionResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CancelSchemaExtensionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).cancel_schema_extension(input)
     }
-
-    /// <p>Creates an AD Connector to connect to an on-premises directory.</p> <p>Before you call <code>ConnectDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>ConnectDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
     fn connect_directory(
         &self,
         input: ConnectDirectoryRequest,
     ) -> RusotoFuture<ConnectDirectoryResult, ConnectDirectoryError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.ConnectDirectory");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ConnectDirectoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ConnectDirectoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).connect_directory(input)
     }
-
-    /// <p><p>Creates an alias for a directory and assigns the alias to the directory. The alias is used to construct the access URL for the directory, such as <code>http://&lt;alias&gt;.awsapps.com</code>.</p> <important> <p>After an alias has been created, it cannot be deleted or reused, so this operation should only be used when absolutely necessary.</p> </important></p>
     fn create_alias(
         &self,
         input: CreateAliasRequest,
     ) -> RusotoFuture<CreateAliasResult, CreateAliasError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.CreateAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAliasResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_alias(input)
     }
-
-    /// <p>Creates a computer account in the specified directory, and joins the computer to the directory.</p>
     fn create_computer(
         &self,
         input: CreateComputerRequest,
     ) -> RusotoFuture<CreateComputerResult, CreateComputerError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.CreateComputer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateComputerResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateComputerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_computer(input)
     }
-
-    /// <p>Creates a conditional forwarder associated with your AWS directory. Conditional forwarders are required in order to set up a trust relationship with another domain. The conditional forwarder points to the trusted domain.</p>
     fn create_conditional_forwarder(
         &self,
         input: CreateConditionalForwarderRequest,
     ) -> RusotoFuture<CreateConditionalForwarderResult, CreateConditionalForwarderError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.CreateConditionalForwarder",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateConditionalForwarderResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateConditionalForwarderError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_conditional_forwarder(input)
     }
-
-    /// <p>Creates a Simple AD directory.</p> <p>Before you call <code>CreateDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>CreateDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
     fn create_directory(
         &self,
         input: CreateDirectoryRequest,
     ) -> RusotoFuture<CreateDirectoryResult, CreateDirectoryError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.CreateDirectory");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDirectoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDirectoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_directory(input)
     }
-
-    /// <p>Creates a subscription to forward real time Directory Service domain controller security logs to the specified CloudWatch log group in your AWS account.</p>
     fn create_log_subscription(
         &self,
         input: CreateLogSubscriptionRequest,
     ) -> RusotoFuture<CreateLogSubscriptionResult, CreateLogSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.CreateLogSubscription",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateLogSubscriptionResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateLogSubscriptionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_log_subscription(input)
     }
-
-    /// <p>Creates an AWS Managed Microsoft AD directory.</p> <p>Before you call <i>CreateMicrosoftAD</i>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <i>CreateMicrosoftAD</i> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
     fn create_microsoft_ad(
         &self,
         input: CreateMicrosoftADRequest,
     ) -> RusotoFuture<CreateMicrosoftADResult, CreateMicrosoftADError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.CreateMicrosoftAD",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateMicrosoftADResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateMicrosoftADError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_microsoft_ad(input)
     }
-
-    /// <p><p>Creates a snapshot of a Simple AD or Microsoft AD directory in the AWS cloud.</p> <note> <p>You cannot take snapshots of AD Connector directories.</p> </note></p>
     fn create_snapshot(
         &self,
         input: CreateSnapshotRequest,
     ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.CreateSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSnapshotError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_snapshot(input)
     }
-
-    /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure trust relationships. For example, you can establish a trust between your AWS Managed Microsoft AD directory, and your existing on-premises Microsoft Active Directory. This would allow you to provide users and groups access to resources in either domain, with a single set of credentials.</p> <p>This action initiates the creation of the AWS side of a trust relationship between an AWS Managed Microsoft AD directory and an external domain. You can create either a forest trust or an external trust.</p>
     fn create_trust(
         &self,
         input: CreateTrustRequest,
     ) -> RusotoFuture<CreateTrustResult, CreateTrustError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.CreateTrust");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTrustResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTrustError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_trust(input)
     }
-
-    /// <p>Deletes a conditional forwarder that has been set up for your AWS directory.</p>
     fn delete_conditional_forwarder(
         &self,
         input: DeleteConditionalForwarderRequest,
     ) -> RusotoFuture<DeleteConditionalForwarderResult, DeleteConditionalForwarderError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.DeleteConditionalForwarder",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteConditionalForwarderResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteConditionalForwarderError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_conditional_forwarder(input)
     }
-
-    /// <p>Deletes an AWS Directory Service directory.</p> <p>Before you call <code>DeleteDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>DeleteDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
     fn delete_directory(
         &self,
         input: DeleteDirectoryRequest,
     ) -> RusotoFuture<DeleteDirectoryResult, DeleteDirectoryError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.DeleteDirectory");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDirectoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDirectoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_directory(input)
     }
-
-    /// <p>Deletes the specified log subscription.</p>
     fn delete_log_subscription(
         &self,
         input: DeleteLogSubscriptionRequest,
     ) -> RusotoFuture<DeleteLogSubscriptionResult, DeleteLogSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.DeleteLogSubscription",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteLogSubscriptionResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteLogSubscriptionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_log_subscription(input)
     }
-
-    /// <p>Deletes a directory snapshot.</p>
     fn delete_snapshot(
         &self,
         input: DeleteSnapshotRequest,
     ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.DeleteSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteSnapshotError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_snapshot(input)
     }
-
-    /// <p>Deletes an existing trust relationship between your AWS Managed Microsoft AD directory and an external domain.</p>
     fn delete_trust(
         &self,
         input: DeleteTrustRequest,
     ) -> RusotoFuture<DeleteTrustResult, DeleteTrustError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.DeleteTrust");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTrustResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTrustError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_trust(input)
     }
-
-    /// <p>Removes the specified directory as a publisher to the specified SNS topic.</p>
     fn deregister_event_topic(
         &self,
         input: DeregisterEventTopicRequest,
     ) -> RusotoFuture<DeregisterEventTopicResult, DeregisterEventTopicError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.DeregisterEventTopic",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterEventTopicResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeregisterEventTopicError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).deregister_event_topic(input)
     }
-
-    /// <p>Obtains information about the conditional forwarders for this account.</p> <p>If no input parameters are provided for RemoteDomainNames, this request describes all conditional forwarders for the specified directory ID.</p>
     fn describe_conditional_forwarders(
         &self,
         input: DescribeConditionalForwardersRequest,
     ) -> RusotoFuture<DescribeConditionalForwardersResult, DescribeConditionalForwardersError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.DescribeConditionalForwarders",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeConditionalForwardersResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeConditionalForwardersError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_conditional_forwarders(input)
     }
-
-    /// <p>Obtains information about the directories that belong to this account.</p> <p>You can retrieve information about specific directories by passing the directory identifiers in the <code>DirectoryIds</code> parameter. Otherwise, all directories that belong to the current account are returned.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> request and response parameters. If more results are available, the <code>DescribeDirectoriesResult.NextToken</code> member contains a token that you pass in the next call to <a>DescribeDirectories</a> to retrieve the next set of items.</p> <p>You can also specify a maximum number of return results with the <code>Limit</code> parameter.</p>
     fn describe_directories(
         &self,
         input: DescribeDirectoriesRequest,
     ) -> RusotoFuture<DescribeDirectoriesResult, DescribeDirectoriesError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.DescribeDirectories",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDirectoriesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeDirectoriesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_directories(input)
     }
-
-    /// <p>Provides information about any domain controllers in your directory.</p>
     fn describe_domain_controllers(
         &self,
         input: DescribeDomainControllersRequest,
     ) -> RusotoFuture<DescribeDomainControllersResult, DescribeDomainControllersError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.DescribeDomainControllers",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDomainControllersResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDomainControllersError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_domain_controllers(input)
     }
-
-    /// <p>Obtains information about which SNS topics receive status messages from the specified directory.</p> <p>If no input parameters are provided, such as DirectoryId or TopicName, this request describes all of the associations in the account.</p>
     fn describe_event_topics(
         &self,
         input: DescribeEventTopicsRequest,
     ) -> RusotoFuture<DescribeEventTopicsResult, DescribeEventTopicsError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.DescribeEventTopics",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventTopicsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeEventTopicsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_event_topics(input)
     }
-
-    /// <p>Returns the shared directories in your account. </p>
     fn describe_shared_directories(
         &self,
         input: DescribeSharedDirectoriesRequest,
     ) -> RusotoFuture<DescribeSharedDirectoriesResult, DescribeSharedDirectoriesError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.DescribeSharedDirectories",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSharedDirectoriesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeSharedDirectoriesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_shared_directories(input)
     }
-
-    /// <p>Obtains information about the directory snapshots that belong to this account.</p> <p>This operation supports pagination with the use of the <i>NextToken</i> request and response parameters. If more results are available, the <i>DescribeSnapshots.NextToken</i> member contains a token that you pass in the next call to <a>DescribeSnapshots</a> to retrieve the next set of items.</p> <p>You can also specify a maximum number of return results with the <i>Limit</i> parameter.</p>
     fn describe_snapshots(
         &self,
         input: DescribeSnapshotsRequest,
     ) -> RusotoFuture<DescribeSnapshotsResult, DescribeSnapshotsError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.DescribeSnapshots",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSnapshotsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeSnapshotsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_snapshots(input)
     }
-
-    /// <p>Obtains information about the trust relationships for this account.</p> <p>If no input parameters are provided, such as DirectoryId or TrustIds, this request describes all the trust relationships belonging to the account.</p>
     fn describe_trusts(
         &self,
         input: DescribeTrustsRequest,
     ) -> RusotoFuture<DescribeTrustsResult, DescribeTrustsError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.DescribeTrusts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTrustsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTrustsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_trusts(input)
     }
-
-    /// <p>Disables multi-factor authentication (MFA) with the Remote Authentication Dial In User Service (RADIUS) server for an AD Connector or Microsoft AD directory.</p>
     fn disable_radius(
         &self,
         input: DisableRadiusRequest,
     ) -> RusotoFuture<DisableRadiusResult, DisableRadiusError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.DisableRadius");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisableRadiusResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisableRadiusError::from_response(response))),
-                )
-            }
-        })
+        (**self).disable_radius(input)
     }
-
-    /// <p>Disables single-sign on for a directory.</p>
     fn disable_sso(
         &self,
         input: DisableSsoRequest,
     ) -> RusotoFuture<DisableSsoResult, DisableSsoError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.DisableSso");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisableSsoResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisableSsoError::from_response(response))),
-                )
-            }
-        })
+        (**self).disable_sso(input)
     }
-
-    /// <p>Enables multi-factor authentication (MFA) with the Remote Authentication Dial In User Service (RADIUS) server for an AD Connector or Microsoft AD directory.</p>
     fn enable_radius(
         &self,
         input: EnableRadiusRequest,
     ) -> RusotoFuture<EnableRadiusResult, EnableRadiusError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.EnableRadius");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnableRadiusResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableRadiusError::from_response(response))),
-                )
-            }
-        })
+        (**self).enable_radius(input)
     }
-
-    /// <p>Enables single sign-on for a directory.</p>
     fn enable_sso(&self, input: EnableSsoRequest) -> RusotoFuture<EnableSsoResult, EnableSsoError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.EnableSso");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<EnableSsoResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableSsoError::from_response(response))),
-                )
-            }
-        })
+        (**self).enable_sso(input)
     }
-
-    /// <p>Obtains directory limit information for the current region.</p>
     fn get_directory_limits(
         &self,
     ) -> RusotoFuture<GetDirectoryLimitsResult, GetDirectoryLimitsError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.GetDirectoryLimits",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDirectoryLimitsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDirectoryLimitsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_directory_limits()
     }
-
-    /// <p>Obtains the manual snapshot limits for a directory.</p>
     fn get_snapshot_limits(
         &self,
         input: GetSnapshotLimitsRequest,
     ) -> RusotoFuture<GetSnapshotLimitsResult, GetSnapshotLimitsError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.GetSnapshotLimits",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSnapshotLimitsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSnapshotLimitsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_snapshot_limits(input)
     }
-
-    /// <p>Lists the address blocks that you have added to a directory.</p>
     fn list_ip_routes(
         &self,
         input: ListIpRoutesRequest,
     ) -> RusotoFuture<ListIpRoutesResult, ListIpRoutesError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.ListIpRoutes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListIpRoutesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListIpRoutesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_ip_routes(input)
     }
-
-    /// <p>Lists the active log subscriptions for the AWS account.</p>
     fn list_log_subscriptions(
         &self,
         input: ListLogSubscriptionsRequest,
     ) -> RusotoFuture<ListLogSubscriptionsResult, ListLogSubscriptionsError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.ListLogSubscriptions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLogSubscriptionsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListLogSubscriptionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_log_subscriptions(input)
     }
-
-    /// <p>Lists all schema extensions applied to a Microsoft AD Directory.</p>
     fn list_schema_extensions(
         &self,
         input: ListSchemaExtensionsRequest,
     ) -> RusotoFuture<ListSchemaExtensionsResult, ListSchemaExtensionsError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.ListSchemaExtensions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSchemaExtensionsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListSchemaExtensionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_schema_extensions(input)
     }
-
-    /// <p>Lists all tags on a directory.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.ListTagsForResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Associates a directory with an SNS topic. This establishes the directory as a publisher to the specified SNS topic. You can then receive email or text (SMS) messages when the status of your directory changes. You get notified if your directory goes from an Active status to an Impaired or Inoperable status. You also receive a notification when the directory returns to an Active status.</p>
     fn register_event_topic(
         &self,
         input: RegisterEventTopicRequest,
     ) -> RusotoFuture<RegisterEventTopicResult, RegisterEventTopicError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.RegisterEventTopic",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterEventTopicResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RegisterEventTopicError::from_response(response))),
-                )
-            }
-        })
+        (**self).register_event_topic(input)
     }
-
-    /// <p>Rejects a directory sharing request that was sent from the directory owner account.</p>
     fn reject_shared_directory(
         &self,
         input: RejectSharedDirectoryRequest,
     ) -> RusotoFuture<RejectSharedDirectoryResult, RejectSharedDirectoryError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.RejectSharedDirectory",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RejectSharedDirectoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RejectSharedDirectoryError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).reject_shared_directory(input)
     }
-
-    /// <p>Removes IP address blocks from a directory.</p>
     fn remove_ip_routes(
         &self,
         input: RemoveIpRoutesRequest,
     ) -> RusotoFuture<RemoveIpRoutesResult, RemoveIpRoutesError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.RemoveIpRoutes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveIpRoutesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RemoveIpRoutesError::from_response(response))),
-                )
-            }
-        })
+        (**self).remove_ip_routes(input)
     }
-
-    /// <p>Removes tags from a directory.</p>
     fn remove_tags_from_resource(
         &self,
         input: RemoveTagsFromResourceRequest,
     ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.RemoveTagsFromResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveTagsFromResourceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RemoveTagsFromResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_user_password(
+        &self,
+        input: ResetUserPasswordRequest,
+    ) -> RusotoFuture<ResetUserPasswordResult, ResetUserPasswordError> {
+        (**self).reset_user_password(input)
+    }
+    fn restore_from_snapshot(
+        &self,
+        input: RestoreFromSnapshotRequest,
+    ) -> RusotoFuture<RestoreFromSnapshotResult, RestoreFromSnapshotError> {
+        (**self).restore_from_snapshot(input)
+    }
+    fn share_directory(
+        &self,
+        input: ShareDirectoryRequest,
+    ) -> RusotoFuture<ShareDirectoryResult, ShareDirectoryError> {
+        (**self).share_directory(input)
+    }
+    fn start_schema_extension(
+        &self,
+        input: StartSchemaExtensionRequest,
+    ) -> RusotoFuture<StartSchemaExtensionResult, StartSchemaExtensionError> {
+        (**self).start_schema_extension(input)
+    }
+    fn unshare_directory(
+        &self,
+        input: UnshareDirectoryRequest,
+    ) -> RusotoFuture<UnshareDirectoryResult, UnshareDirectoryError> {
+        (**self).unshare_directory(input)
+    }
+    fn update_conditional_forwarder(
+        &self,
+        input: UpdateConditionalForwarderRequest,
+    ) -> RusotoFuture<UpdateConditionalForwarderResult, UpdateConditionalForwarderError> {
+        (**self).update_conditional_forwarder(input)
+    }
+    fn update_number_of_domain_controllers(
+        &self,
+        input: UpdateNumberOfDomainControllersRequest,
+    ) -> RusotoFuture<UpdateNumberOfDomainControllersResult, UpdateNumberOfDomainControllersError>
+    {
+        (**self).update_number_of_domain_controllers(input)
+    }
+    fn update_radius(
+        &self,
+        input: UpdateRadiusRequest,
+    ) -> RusotoFuture<UpdateRadiusResult, UpdateRadiusError> {
+        (**self).update_radius(input)
+    }
+    fn update_trust(
+        &self,
+        input: UpdateTrustRequest,
+    ) -> RusotoFuture<UpdateTrustResult, UpdateTrustError> {
+        (**self).update_trust(input)
+    }
+    fn verify_trust(
+        &self,
+        input: VerifyTrustRequest,
+    ) -> RusotoFuture<VerifyTrustResult, VerifyTrustError> {
+        (**self).verify_trust(input)
+    }
+}
+impl<T: DirectoryService + ?Sized> DirectoryService for Box<T> {
+    fn accept_shared_directory(
+        &self,
+        input: AcceptSharedDirectoryRequest,
+    ) -> RusotoFuture<AcceptSharedDirectoryResult, AcceptSharedDirectoryError> {
+        (**self).accept_shared_directory(input)
+    }
+    fn add_ip_routes(
+        &self,
+        input: AddIpRoutesRequest,
+    ) -> RusotoFuture<AddIpRoutesResult, AddIpRoutesError> {
+        (**self).add_ip_routes(input)
+    }
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn cancel_schema_extension(
+        &self,
+        input: CancelSchemaExtensionRequest,
+    ) -> RusotoFuture<CancelSchemaExtensionResult, CancelSchemaExtensionError> {
+        (**self).cancel_schema_extension(input)
+    }
+    fn connect_directory(
+        &self,
+        input: ConnectDirectoryRequest,
+    ) -> RusotoFuture<ConnectDirectoryResult, ConnectDirectoryError> {
+        (**self).connect_directory(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<CreateAliasResult, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_computer(
+        &self,
+        input: CreateComputerRequest,
+    ) -> RusotoFuture<CreateComputerResult, CreateComputerError> {
+        (**self).create_computer(input)
+    }
+    fn create_conditional_forwarder(
+        &self,
+        input: CreateConditionalForwarderRequest,
+    ) -> RusotoFuture<CreateConditionalForwarderResult, CreateConditionalForwarderError> {
+        (**self).create_conditional_forwarder(input)
+    }
+    fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> RusotoFuture<CreateDirectoryResult, CreateDirectoryError> {
+        (**self).create_directory(input)
+    }
+    fn create_log_subscription(
+        &self,
+        input: CreateLogSubscriptionRequest,
+    ) -> RusotoFuture<CreateLogSubscriptionResult, CreateLogSubscriptionError> {
+        (**self).create_log_subscription(input)
+    }
+    fn create_microsoft_ad(
+        &self,
+        input: CreateMicrosoftADRequest,
+    ) -> RusotoFuture<CreateMicrosoftADResult, CreateMicrosoftADError> {
+        (**self).create_microsoft_ad(input)
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
+        (**self).create_snapshot(input)
+    }
+    fn create_trust(
+        &self,
+        input: CreateTrustRequest,
+    ) -> RusotoFuture<CreateTrustResult, CreateTrustError> {
+        (**self).create_trust(input)
+    }
+    fn delete_conditional_forwarder(
+        &self,
+        input: DeleteConditionalForwarderRequest,
+    ) -> RusotoFuture<DeleteConditionalForwarderResult, DeleteConditionalForwarderError> {
+        (**self).delete_conditional_forwarder(input)
+    }
+    fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> RusotoFuture<DeleteDirectoryResult, DeleteDirectoryError> {
+        (**self).delete_directory(input)
+    }
+    fn delete_log_subscription(
+        &self,
+        input: DeleteLogSubscriptionRequest,
+    ) -> RusotoFuture<DeleteLogSubscriptionResult, DeleteLogSubscriptionError> {
+        (**self).delete_log_subscription(input)
+    }
+    fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
+        (**self).delete_snapshot(input)
+    }
+    fn delete_trust(
+        &self,
+        input: DeleteTrustRequest,
+    ) -> RusotoFuture<DeleteTrustResult, DeleteTrustError> {
+        (**self).delete_trust(input)
+    }
+    fn deregister_event_topic(
+        &self,
+        input: DeregisterEventTopicRequest,
+    ) -> RusotoFuture<DeregisterEventTopicResult, DeregisterEventTopicError> {
+        (**self).deregister_event_topic(input)
+    }
+    fn describe_conditional_forwarders(
+        &self,
+        input: DescribeConditionalForwardersRequest,
+    ) -> RusotoFuture<DescribeConditionalForwardersResult, DescribeConditionalForwardersError> {
+        (**self).describe_conditional_forwarders(input)
+    }
+    fn describe_directories(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> RusotoFuture<DescribeDirectoriesResult, DescribeDirectoriesError> {
+        (**self).describe_directories(input)
+    }
+    fn describe_domain_controllers(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> RusotoFuture<DescribeDomainControllersResult, DescribeDomainControllersError> {
+        (**self).describe_domain_controllers(input)
+    }
+    fn describe_event_topics(
+        &self,
+        input: DescribeEventTopicsRequest,
+    ) -> RusotoFuture<DescribeEventTopicsResult, DescribeEventTopicsError> {
+        (**self).describe_event_topics(input)
+    }
+    fn describe_shared_directories(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> RusotoFuture<DescribeSharedDirectoriesResult, DescribeSharedDirectoriesError> {
+        (**self).describe_shared_directories(input)
+    }
+    fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> RusotoFuture<DescribeSnapshotsResult, DescribeSnapshotsError> {
+        (**self).describe_snapshots(input)
+    }
+    fn describe_trusts(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> RusotoFuture<DescribeTrustsResult, DescribeTrustsError> {
+        (**self).describe_trusts(input)
+    }
+    fn disable_radius(
+        &self,
+        input: DisableRadiusRequest,
+    ) -> RusotoFuture<DisableRadiusResult, DisableRadiusError> {
+        (**self).disable_radius(input)
+    }
+    fn disable_sso(
+        &self,
+        input: DisableSsoRequest,
+    ) -> RusotoFuture<DisableSsoResult, DisableSsoError> {
+        (**self).disable_sso(input)
+    }
+    fn enable_radius(
+        &self,
+        input: EnableRadiusRequest,
+    ) -> RusotoFuture<EnableRadiusResult, EnableRadiusError> {
+        (**self).enable_radius(input)
+    }
+    fn enable_sso(&self, input: EnableSsoRequest) -> RusotoFuture<EnableSsoResult, EnableSsoError> {
+        (**self).enable_sso(input)
+    }
+    fn get_directory_limits(
+        &self,
+    ) -> RusotoFuture<GetDirectoryLimitsResult, GetDirectoryLimitsError> {
+        (**self).get_directory_limits()
+    }
+    fn get_snapshot_limits(
+        &self,
+        input: GetSnapshotLimitsRequest,
+    ) -> RusotoFuture<GetSnapshotLimitsResult, GetSnapshotLimitsError> {
+        (**self).get_snapshot_limits(input)
+    }
+    fn list_ip_routes(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> RusotoFuture<ListIpRoutesResult, ListIpRoutesError> {
+        (**self).list_ip_routes(input)
+    }
+    fn list_log_subscriptions(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> RusotoFuture<ListLogSubscriptionsResult, ListLogSubscriptionsError> {
+        (**self).list_log_subscriptions(input)
+    }
+    fn list_schema_extensions(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> RusotoFuture<ListSchemaExtensionsResult, ListSchemaExtensionsError> {
+        (**self).list_schema_extensions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn register_event_topic(
+        &self,
+        input: RegisterEventTopicRequest,
+    ) -> RusotoFuture<RegisterEventTopicResult, RegisterEventTopicError> {
+        (**self).register_event_topic(input)
+    }
+    fn reject_shared_directory(
+        &self,
+        input: RejectSharedDirectoryRequest,
+    ) -> RusotoFuture<RejectSharedDirectoryResult, RejectSharedDirectoryError> {
+        (**self).reject_shared_directory(input)
+    }
+    fn remove_ip_routes(
+        &self,
+        input: RemoveIpRoutesRequest,
+    ) -> RusotoFuture<RemoveIpRoutesResult, RemoveIpRoutesError> {
+        (**self).remove_ip_routes(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_user_password(
+        &self,
+        input: ResetUserPasswordRequest,
+    ) -> RusotoFuture<ResetUserPasswordResult, ResetUserPasswordError> {
+        (**self).reset_user_password(input)
+    }
+    fn restore_from_snapshot(
+        &self,
+        input: RestoreFromSnapshotRequest,
+    ) -> RusotoFuture<RestoreFromSnapshotResult, RestoreFromSnapshotError> {
+        (**self).restore_from_snapshot(input)
+    }
+    fn share_directory(
+        &self,
+        input: ShareDirectoryRequest,
+    ) -> RusotoFuture<ShareDirectoryResult, ShareDirectoryError> {
+        (**self).share_directory(input)
+    }
+    fn start_schema_extension(
+        &self,
+        input: StartSchemaExtensionRequest,
+    ) -> RusotoFuture<StartSchemaExtensionResult, StartSchemaExtensionError> {
+        (**self).start_schema_extension(input)
+    }
+    fn unshare_directory(
+        &self,
+        input: UnshareDirectoryRequest,
+    ) -> RusotoFuture<UnshareDirectoryResult, UnshareDirectoryError> {
+        (**self).unshare_directory(input)
+    }
+    fn update_conditional_forwarder(
+        &self,
+        input: UpdateConditionalForwarderRequest,
+    ) -> RusotoFuture<UpdateConditionalForwarderResult, UpdateConditionalForwarderError> {
+        (**self).update_conditional_forwarder(input)
+    }
+    fn update_number_of_domain_controllers(
+        &self,
+        input: UpdateNumberOfDomainControllersRequest,
+    ) -> RusotoFuture<UpdateNumberOfDomainControllersResult, UpdateNumberOfDomainControllersError>
+    {
+        (**self).update_number_of_domain_controllers(input)
+    }
+    fn update_radius(
+        &self,
+        input: UpdateRadiusRequest,
+    ) -> RusotoFuture<UpdateRadiusResult, UpdateRadiusError> {
+        (**self).update_radius(input)
+    }
+    fn update_trust(
+        &self,
+        input: UpdateTrustRequest,
+    ) -> RusotoFuture<UpdateTrustResult, UpdateTrustError> {
+        (**self).update_trust(input)
+    }
+    fn verify_trust(
+        &self,
+        input: VerifyTrustRequest,
+    ) -> RusotoFuture<VerifyTrustResult, VerifyTrustError> {
+        (**self).verify_trust(input)
+    }
+}
+impl<T: DirectoryService + ?Sized> DirectoryService for ::std::sync::Arc<T> {
+    fn accept_shared_directory(
+        &self,
+        input: AcceptSharedDirectoryRequest,
+    ) -> RusotoFuture<AcceptSharedDirectoryResult, AcceptSharedDirectoryError> {
+        (**self).accept_shared_directory(input)
+    }
+    fn add_ip_routes(
+        &self,
+        input: AddIpRoutesRequest,
+    ) -> RusotoFuture<AddIpRoutesResult, AddIpRoutesError> {
+        (**self).add_ip_routes(input)
+    }
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn cancel_schema_extension(
+        &self,
+        input: CancelSchemaExtensionRequest,
+    ) -> RusotoFuture<CancelSchemaExtensionResult, CancelSchemaExtensionError> {
+        (**self).cancel_schema_extension(input)
+    }
+    fn connect_directory(
+        &self,
+        input: ConnectDirectoryRequest,
+    ) -> RusotoFuture<ConnectDirectoryResult, ConnectDirectoryError> {
+        (**self).connect_directory(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<CreateAliasResult, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_computer(
+        &self,
+        input: CreateComputerRequest,
+    ) -> RusotoFuture<CreateComputerResult, CreateComputerError> {
+        (**self).create_computer(input)
+    }
+    fn create_conditional_forwarder(
+        &self,
+        input: CreateConditionalForwarderRequest,
+    ) -> RusotoFuture<CreateConditionalForwarderResult, CreateConditionalForwarderError> {
+        (**self).create_conditional_forwarder(input)
+    }
+    fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> RusotoFuture<CreateDirectoryResult, CreateDirectoryError> {
+        (**self).create_directory(input)
+    }
+    fn create_log_subscription(
+        &self,
+        input: CreateLogSubscriptionRequest,
+    ) -> RusotoFuture<CreateLogSubscriptionResult, CreateLogSubscriptionError> {
+        (**self).create_log_subscription(input)
+    }
+    fn create_microsoft_ad(
+        &self,
+        input: CreateMicrosoftADRequest,
+    ) -> RusotoFuture<CreateMicrosoftADResult, CreateMicrosoftADError> {
+        (**self).create_microsoft_ad(input)
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
+        (**self).create_snapshot(input)
+    }
+    fn create_trust(
+        &self,
+        input: CreateTrustRequest,
+    ) -> RusotoFuture<CreateTrustResult, CreateTrustError> {
+        (**self).create_trust(input)
+    }
+    fn delete_conditional_forwarder(
+        &self,
+        input: DeleteConditionalForwarderRequest,
+    ) -> RusotoFuture<DeleteConditionalForwarderResult, DeleteConditionalForwarderError> {
+        (**self).delete_conditional_forwarder(input)
+    }
+    fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> RusotoFuture<DeleteDirectoryResult, DeleteDirectoryError> {
+        (**self).delete_directory(input)
+    }
+    fn delete_log_subscription(
+        &self,
+        input: DeleteLogSubscriptionRequest,
+    ) -> RusotoFuture<DeleteLogSubscriptionResult, DeleteLogSubscriptionError> {
+        (**self).delete_log_subscription(input)
+    }
+    fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
+        (**self).delete_snapshot(input)
+    }
+    fn delete_trust(
+        &self,
+        input: DeleteTrustRequest,
+    ) -> RusotoFuture<DeleteTrustResult, DeleteTrustError> {
+        (**self).delete_trust(input)
+    }
+    fn deregister_event_topic(
+        &self,
+        input: DeregisterEventTopicRequest,
+    ) -> RusotoFuture<DeregisterEventTopicResult, DeregisterEventTopicError> {
+        (**self).deregister_event_topic(input)
+    }
+    fn describe_conditional_forwarders(
+        &self,
+        input: DescribeConditionalForwardersRequest,
+    ) -> RusotoFuture<DescribeConditionalForwardersResult, DescribeConditionalForwardersError> {
+        (**self).describe_conditional_forwarders(input)
+    }
+    fn describe_directories(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> RusotoFuture<DescribeDirectoriesResult, DescribeDirectoriesError> {
+        (**self).describe_directories(input)
+    }
+    fn describe_domain_controllers(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> RusotoFuture<DescribeDomainControllersResult, DescribeDomainControllersError> {
+        (**self).describe_domain_controllers(input)
+    }
+    fn describe_event_topics(
+        &self,
+        input: DescribeEventTopicsRequest,
+    ) -> RusotoFuture<DescribeEventTopicsResult, DescribeEventTopicsError> {
+        (**self).describe_event_topics(input)
+    }
+    fn describe_shared_directories(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> RusotoFuture<DescribeSharedDirectoriesResult, DescribeSharedDirectoriesError> {
+        (**self).describe_shared_directories(input)
+    }
+    fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> RusotoFuture<DescribeSnapshotsResult, DescribeSnapshotsError> {
+        (**self).describe_snapshots(input)
+    }
+    fn describe_trusts(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> RusotoFuture<DescribeTrustsResult, DescribeTrustsError> {
+        (**self).describe_trusts(input)
+    }
+    fn disable_radius(
+        &self,
+        input: DisableRadiusRequest,
+    ) -> RusotoFuture<DisableRadiusResult, DisableRadiusError> {
+        (**self).disable_radius(input)
+    }
+    fn disable_sso(
+        &self,
+        input: DisableSsoRequest,
+    ) -> RusotoFuture<DisableSsoResult, DisableSsoError> {
+        (**self).disable_sso(input)
+    }
+    fn enable_radius(
+        &self,
+        input: EnableRadiusRequest,
+    ) -> RusotoFuture<EnableRadiusResult, EnableRadiusError> {
+        (**self).enable_radius(input)
+    }
+    fn enable_sso(&self, input: EnableSsoRequest) -> RusotoFuture<EnableSsoResult, EnableSsoError> {
+        (**self).enable_sso(input)
+    }
+    fn get_directory_limits(
+        &self,
+    ) -> RusotoFuture<GetDirectoryLimitsResult, GetDirectoryLimitsError> {
+        (**self).get_directory_limits()
+    }
+    fn get_snapshot_limits(
+        &self,
+        input: GetSnapshotLimitsRequest,
+    ) -> RusotoFuture<GetSnapshotLimitsResult, GetSnapshotLimitsError> {
+        (**self).get_snapshot_limits(input)
+    }
+    fn list_ip_routes(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> RusotoFuture<ListIpRoutesResult, ListIpRoutesError> {
+        (**self).list_ip_routes(input)
+    }
+    fn list_log_subscriptions(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> RusotoFuture<ListLogSubscriptionsResult, ListLogSubscriptionsError> {
+        (**self).list_log_subscriptions(input)
+    }
+    fn list_schema_extensions(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> RusotoFuture<ListSchemaExtensionsResult, ListSchemaExtensionsError> {
+        (**self).list_schema_extensions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn register_event_topic(
+        &self,
+        input: RegisterEventTopicRequest,
+    ) -> RusotoFuture<RegisterEventTopicResult, RegisterEventTopicError> {
+        (**self).register_event_topic(input)
+    }
+    fn reject_shared_directory(
+        &self,
+        input: RejectSharedDirectoryRequest,
+    ) -> RusotoFuture<RejectSharedDirectoryResult, RejectSharedDirectoryError> {
+        (**self).reject_shared_directory(input)
+    }
+    fn remove_ip_routes(
+        &self,
+        input: RemoveIpRoutesRequest,
+    ) -> RusotoFuture<RemoveIpRoutesResult, RemoveIpRoutesError> {
+        (**self).remove_ip_routes(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_user_password(
+        &self,
+        input: ResetUserPasswordRequest,
+    ) -> RusotoFuture<ResetUserPasswordResult, ResetUserPasswordError> {
+        (**self).reset_user_password(input)
+    }
+    fn restore_from_snapshot(
+        &self,
+        input: RestoreFromSnapshotRequest,
+    ) -> RusotoFuture<RestoreFromSnapshotResult, RestoreFromSnapshotError> {
+        (**self).restore_from_snapshot(input)
+    }
+    fn share_directory(
+        &self,
+        input: ShareDirectoryRequest,
+    ) -> RusotoFuture<ShareDirectoryResult, ShareDirectoryError> {
+        (**self).share_directory(input)
+    }
+    fn start_schema_extension(
+        &self,
+        input: StartSchemaExtensionRequest,
+    ) -> RusotoFuture<StartSchemaExtensionResult, StartSchemaExtensionError> {
+        (**self).start_schema_extension(input)
+    }
+    fn unshare_directory(
+        &self,
+        input: UnshareDirectoryRequest,
+    ) -> RusotoFuture<UnshareDirectoryResult, UnshareDirectoryError> {
+        (**self).unshare_directory(input)
+    }
+    fn update_conditional_forwarder(
+        &self,
+        input: UpdateConditionalForwarderRequest,
+    ) -> RusotoFuture<UpdateConditionalForwarderResult, UpdateConditionalForwarderError> {
+        (**self).update_conditional_forwarder(input)
+    }
+    fn update_number_of_domain_controllers(
+        &self,
+        input: UpdateNumberOfDomainControllersRequest,
+    ) -> RusotoFuture<UpdateNumberOfDomainControllersResult, UpdateNumberOfDomainControllersError>
+    {
+        (**self).update_number_of_domain_controllers(input)
+    }
+    fn update_radius(
+        &self,
+        input: UpdateRadiusRequest,
+    ) -> RusotoFuture<UpdateRadiusResult, UpdateRadiusError> {
+        (**self).update_radius(input)
+    }
+    fn update_trust(
+        &self,
+        input: UpdateTrustRequest,
+    ) -> RusotoFuture<UpdateTrustResult, UpdateTrustError> {
+        (**self).update_trust(input)
+    }
+    fn verify_trust(
+        &self,
+        input: VerifyTrustRequest,
+    ) -> RusotoFuture<VerifyTrustResult, VerifyTrustError> {
+        (**self).verify_trust(input)
+    }
+}
+/// A client for the Directory Service API.
+#[derive(Clone)]
+pub struct DirectoryServiceClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl DirectoryServiceClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DirectoryServiceClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DirectoryServiceClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> DirectoryServiceClient {
+        DirectoryServiceClient { client, region }
+    }
+}
+
+impl DirectoryService for DirectoryServiceClient {
+    /// <p>Accepts a directory sharing request that was sent from the directory owner account.</p>
+    fn accept_shared_directory(
+        &self,
+        input: AcceptSharedDirectoryRequest,
+    ) -> RusotoFuture<AcceptSharedDirectoryResult, AcceptSharedDirectoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("AcceptSharedDirectory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.AcceptSharedDirectory",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AcceptSharedDirectoryResult, _>(
+                    response,
+                    "AcceptSharedDirectory",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AcceptSharedDirectoryError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>If the DNS server for your on-premises domain uses a publicly addressable IP address, you must add a CIDR address block to correctly route traffic to and from your Microsoft AD on Amazon Web Services. <i>AddIpRoutes</i> adds this address block. You can also use <i>AddIpRoutes</i> to facilitate routing traffic that uses public IP ranges from your Microsoft AD on AWS to a peer VPC. </p> <p>Before you call <i>AddIpRoutes</i>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <i>AddIpRoutes</i> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    fn add_ip_routes(
+        &self,
+        input: AddIpRoutesRequest,
+    ) -> RusotoFuture<AddIpRoutesResult, AddIpRoutesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("AddIpRoutes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.AddIpRoutes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddIpRoutesResult, _>(response, "AddIpRoutes")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddIpRoutesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds or overwrites one or more tags for the specified directory. Each directory can have a maximum of 50 tags. Each tag consists of a key and optional value. Tag keys must be unique to each resource.</p>
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.AddTagsToResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddTagsToResourceResult, _>(
+                    response,
+                    "AddTagsToResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Cancels an in-progress schema extension to a Microsoft AD directory. Once a schema extension has started replicating to all domain controllers, the task can no longer be canceled. A schema extension can be canceled during any of the following states; <code>Initializing</code>, <code>CreatingSnapshot</code>, and <code>UpdatingSchema</code>.</p>
+    fn cancel_schema_extension(
+        &self,
+        input: CancelSchemaExtensionRequest,
+    ) -> RusotoFuture<CancelSchemaExtensionResult, CancelSchemaExtensionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CancelSchemaExtension");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.CancelSchemaExtension",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelSchemaExtensionResult, _>(
+                    response,
+                    "CancelSchemaExtension",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CancelSchemaExtensionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an AD Connector to connect to an on-premises directory.</p> <p>Before you call <code>ConnectDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>ConnectDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    fn connect_directory(
+        &self,
+        input: ConnectDirectoryRequest,
+    ) -> RusotoFuture<ConnectDirectoryResult, ConnectDirectoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ConnectDirectory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.ConnectDirectory");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ConnectDirectoryResult, _>(
+                    response,
+                    "ConnectDirectory",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ConnectDirectoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates an alias for a directory and assigns the alias to the directory. The alias is used to construct the access URL for the directory, such as <code>http://&lt;alias&gt;.awsapps.com</code>.</p> <important> <p>After an alias has been created, it cannot be deleted or reused, so this operation should only be used when absolutely necessary.</p> </important></p>
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<CreateAliasResult, CreateAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.CreateAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAliasResult, _>(response, "CreateAlias")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a computer account in the specified directory, and joins the computer to the directory.</p>
+    fn create_computer(
+        &self,
+        input: CreateComputerRequest,
+    ) -> RusotoFuture<CreateComputerResult, CreateComputerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateComputer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.CreateComputer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateComputerResult, _>(
+                    response,
+                    "CreateComputer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateComputerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a conditional forwarder associated with your AWS directory. Conditional forwarders are required in order to set up a trust relationship with another domain. The conditional forwarder points to the trusted domain.</p>
+    fn create_conditional_forwarder(
+        &self,
+        input: CreateConditionalForwarderRequest,
+    ) -> RusotoFuture<CreateConditionalForwarderResult, CreateConditionalForwarderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateConditionalForwarder");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.CreateConditionalForwarder",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateConditionalForwarderResult, _>(
+                    response,
+                    "CreateConditionalForwarder",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateConditionalForwarderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a Simple AD directory.</p> <p>Before you call <code>CreateDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>CreateDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> RusotoFuture<CreateDirectoryResult, CreateDirectoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateDirectory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.CreateDirectory");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDirectoryResult, _>(
+                    response,
+                    "CreateDirectory",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDirectoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a subscription to forward real time Directory Service domain controller security logs to the specified CloudWatch log group in your AWS account.</p>
+    fn create_log_subscription(
+        &self,
+        input: CreateLogSubscriptionRequest,
+    ) -> RusotoFuture<CreateLogSubscriptionResult, CreateLogSubscriptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateLogSubscription");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.CreateLogSubscription",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateLogSubscriptionResult, _>(
+                    response,
+                    "CreateLogSubscription",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateLogSubscriptionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an AWS Managed Microsoft AD directory.</p> <p>Before you call <i>CreateMicrosoftAD</i>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <i>CreateMicrosoftAD</i> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    fn create_microsoft_ad(
+        &self,
+        input: CreateMicrosoftADRequest,
+    ) -> RusotoFuture<CreateMicrosoftADResult, CreateMicrosoftADError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateMicrosoftAD");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.CreateMicrosoftAD",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateMicrosoftADResult, _>(
+                    response,
+                    "CreateMicrosoftAD",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateMicrosoftADError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a snapshot of a Simple AD or Microsoft AD directory in the AWS cloud.</p> <note> <p>You cannot take snapshots of AD Connector directories.</p> </note></p>
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.CreateSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSnapshotResult, _>(
+                    response,
+                    "CreateSnapshot",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSnapshotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure trust relationships. For example, you can establish a trust between your AWS Managed Microsoft AD directory, and your existing on-premises Microsoft Active Directory. This would allow you to provide users and groups access to resources in either domain, with a single set of credentials.</p> <p>This action initiates the creation of the AWS side of a trust relationship between an AWS Managed Microsoft AD directory and an external domain. You can create either a forest trust or an external trust.</p>
+    fn create_trust(
+        &self,
+        input: CreateTrustRequest,
+    ) -> RusotoFuture<CreateTrustResult, CreateTrustError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateTrust");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.CreateTrust");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTrustResult, _>(response, "CreateTrust")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTrustError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a conditional forwarder that has been set up for your AWS directory.</p>
+    fn delete_conditional_forwarder(
+        &self,
+        input: DeleteConditionalForwarderRequest,
+    ) -> RusotoFuture<DeleteConditionalForwarderResult, DeleteConditionalForwarderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteConditionalForwarder");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.DeleteConditionalForwarder",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteConditionalForwarderResult, _>(
+                    response,
+                    "DeleteConditionalForwarder",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteConditionalForwarderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes an AWS Directory Service directory.</p> <p>Before you call <code>DeleteDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>DeleteDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> RusotoFuture<DeleteDirectoryResult, DeleteDirectoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteDirectory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.DeleteDirectory");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDirectoryResult, _>(
+                    response,
+                    "DeleteDirectory",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDirectoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified log subscription.</p>
+    fn delete_log_subscription(
+        &self,
+        input: DeleteLogSubscriptionRequest,
+    ) -> RusotoFuture<DeleteLogSubscriptionResult, DeleteLogSubscriptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteLogSubscription");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.DeleteLogSubscription",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteLogSubscriptionResult, _>(
+                    response,
+                    "DeleteLogSubscription",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteLogSubscriptionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a directory snapshot.</p>
+    fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.DeleteSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSnapshotResult, _>(
+                    response,
+                    "DeleteSnapshot",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteSnapshotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing trust relationship between your AWS Managed Microsoft AD directory and an external domain.</p>
+    fn delete_trust(
+        &self,
+        input: DeleteTrustRequest,
+    ) -> RusotoFuture<DeleteTrustResult, DeleteTrustError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteTrust");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.DeleteTrust");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTrustResult, _>(response, "DeleteTrust")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTrustError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the specified directory as a publisher to the specified SNS topic.</p>
+    fn deregister_event_topic(
+        &self,
+        input: DeregisterEventTopicRequest,
+    ) -> RusotoFuture<DeregisterEventTopicResult, DeregisterEventTopicError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeregisterEventTopic");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.DeregisterEventTopic",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeregisterEventTopicResult, _>(
+                    response,
+                    "DeregisterEventTopic",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeregisterEventTopicError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Obtains information about the conditional forwarders for this account.</p> <p>If no input parameters are provided for RemoteDomainNames, this request describes all conditional forwarders for the specified directory ID.</p>
+    fn describe_conditional_forwarders(
+        &self,
+        input: DescribeConditionalForwardersRequest,
+    ) -> RusotoFuture<DescribeConditionalForwardersResult, DescribeConditionalForwardersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeConditionalForwarders");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.DescribeConditionalForwarders",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeConditionalForwardersResult, _>(
+                    response,
+                    "DescribeConditionalForwarders",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeConditionalForwardersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Obtains information about the directories that belong to this account.</p> <p>You can retrieve information about specific directories by passing the directory identifiers in the <code>DirectoryIds</code> parameter. Otherwise, all directories that belong to the current account are returned.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> request and response parameters. If more results are available, the <code>DescribeDirectoriesResult.NextToken</code> member contains a token that you pass in the next call to <a>DescribeDirectories</a> to retrieve the next set of items.</p> <p>You can also specify a maximum number of return results with the <code>Limit</code> parameter.</p>
+    fn describe_directories(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> RusotoFuture<DescribeDirectoriesResult, DescribeDirectoriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeDirectories");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.DescribeDirectories",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDirectoriesResult, _>(
+                    response,
+                    "DescribeDirectories",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeDirectoriesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides information about any domain controllers in your directory.</p>
+    fn describe_domain_controllers(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> RusotoFuture<DescribeDomainControllersResult, DescribeDomainControllersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeDomainControllers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.DescribeDomainControllers",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDomainControllersResult, _>(
+                    response,
+                    "DescribeDomainControllers",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDomainControllersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Obtains information about which SNS topics receive status messages from the specified directory.</p> <p>If no input parameters are provided, such as DirectoryId or TopicName, this request describes all of the associations in the account.</p>
+    fn describe_event_topics(
+        &self,
+        input: DescribeEventTopicsRequest,
+    ) -> RusotoFuture<DescribeEventTopicsResult, DescribeEventTopicsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeEventTopics");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.DescribeEventTopics",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEventTopicsResult, _>(
+                    response,
+                    "DescribeEventTopics",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeEventTopicsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the shared directories in your account. </p>
+    fn describe_shared_directories(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> RusotoFuture<DescribeSharedDirectoriesResult, DescribeSharedDirectoriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeSharedDirectories");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.DescribeSharedDirectories",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSharedDirectoriesResult, _>(
+                    response,
+                    "DescribeSharedDirectories",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeSharedDirectoriesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Obtains information about the directory snapshots that belong to this account.</p> <p>This operation supports pagination with the use of the <i>NextToken</i> request and response parameters. If more results are available, the <i>DescribeSnapshots.NextToken</i> member contains a token that you pass in the next call to <a>DescribeSnapshots</a> to retrieve the next set of items.</p> <p>You can also specify a maximum number of return results with the <i>Limit</i> parameter.</p>
+    fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> RusotoFuture<DescribeSnapshotsResult, DescribeSnapshotsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeSnapshots");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.DescribeSnapshots",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSnapshotsResult, _>(
+                    response,
+                    "DescribeSnapshots",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeSnapshotsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Obtains information about the trust relationships for this account.</p> <p>If no input parameters are provided, such as DirectoryId or TrustIds, this request describes all the trust relationships belonging to the account.</p>
+    fn describe_trusts(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> RusotoFuture<DescribeTrustsResult, DescribeTrustsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeTrusts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.DescribeTrusts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTrustsResult, _>(
+                    response,
+                    "DescribeTrusts",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTrustsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables multi-factor authentication (MFA) with the Remote Authentication Dial In User Service (RADIUS) server for an AD Connector or Microsoft AD directory.</p>
+    fn disable_radius(
+        &self,
+        input: DisableRadiusRequest,
+    ) -> RusotoFuture<DisableRadiusResult, DisableRadiusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DisableRadius");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.DisableRadius");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisableRadiusResult, _>(
+                    response,
+                    "DisableRadius",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisableRadiusError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables single-sign on for a directory.</p>
+    fn disable_sso(
+        &self,
+        input: DisableSsoRequest,
+    ) -> RusotoFuture<DisableSsoResult, DisableSsoError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DisableSso");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.DisableSso");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisableSsoResult, _>(response, "DisableSso")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisableSsoError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables multi-factor authentication (MFA) with the Remote Authentication Dial In User Service (RADIUS) server for an AD Connector or Microsoft AD directory.</p>
+    fn enable_radius(
+        &self,
+        input: EnableRadiusRequest,
+    ) -> RusotoFuture<EnableRadiusResult, EnableRadiusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("EnableRadius");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.EnableRadius");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<EnableRadiusResult, _>(
+                    response,
+                    "EnableRadius",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableRadiusError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables single sign-on for a directory.</p>
+    fn enable_sso(&self, input: EnableSsoRequest) -> RusotoFuture<EnableSsoResult, EnableSsoError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("EnableSso");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.EnableSso");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<EnableSsoResult, _>(response, "EnableSso")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableSsoError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Obtains directory limit information for the current region.</p>
+    fn get_directory_limits(
+        &self,
+    ) -> RusotoFuture<GetDirectoryLimitsResult, GetDirectoryLimitsError> {
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("GetDirectoryLimits");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.GetDirectoryLimits",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDirectoryLimitsResult, _>(
+                    response,
+                    "GetDirectoryLimits",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDirectoryLimitsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Obtains the manual snapshot limits for a directory.</p>
+    fn get_snapshot_limits(
+        &self,
+        input: GetSnapshotLimitsRequest,
+    ) -> RusotoFuture<GetSnapshotLimitsResult, GetSnapshotLimitsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("GetSnapshotLimits");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.GetSnapshotLimits",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetSnapshotLimitsResult, _>(
+                    response,
+                    "GetSnapshotLimits",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSnapshotLimitsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the address blocks that you have added to a directory.</p>
+    fn list_ip_routes(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> RusotoFuture<ListIpRoutesResult, ListIpRoutesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListIpRoutes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.ListIpRoutes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListIpRoutesResult, _>(
+                    response,
+                    "ListIpRoutes",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListIpRoutesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the active log subscriptions for the AWS account.</p>
+    fn list_log_subscriptions(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> RusotoFuture<ListLogSubscriptionsResult, ListLogSubscriptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListLogSubscriptions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.ListLogSubscriptions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListLogSubscriptionsResult, _>(
+                    response,
+                    "ListLogSubscriptions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListLogSubscriptionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all schema extensions applied to a Microsoft AD Directory.</p>
+    fn list_schema_extensions(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> RusotoFuture<ListSchemaExtensionsResult, ListSchemaExtensionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListSchemaExtensions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.ListSchemaExtensions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSchemaExtensionsResult, _>(
+                    response,
+                    "ListSchemaExtensions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListSchemaExtensionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all tags on a directory.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.ListTagsForResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResult, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates a directory with an SNS topic. This establishes the directory as a publisher to the specified SNS topic. You can then receive email or text (SMS) messages when the status of your directory changes. You get notified if your directory goes from an Active status to an Impaired or Inoperable status. You also receive a notification when the directory returns to an Active status.</p>
+    fn register_event_topic(
+        &self,
+        input: RegisterEventTopicRequest,
+    ) -> RusotoFuture<RegisterEventTopicResult, RegisterEventTopicError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RegisterEventTopic");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.RegisterEventTopic",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterEventTopicResult, _>(
+                    response,
+                    "RegisterEventTopic",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RegisterEventTopicError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Rejects a directory sharing request that was sent from the directory owner account.</p>
+    fn reject_shared_directory(
+        &self,
+        input: RejectSharedDirectoryRequest,
+    ) -> RusotoFuture<RejectSharedDirectoryResult, RejectSharedDirectoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RejectSharedDirectory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.RejectSharedDirectory",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RejectSharedDirectoryResult, _>(
+                    response,
+                    "RejectSharedDirectory",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RejectSharedDirectoryError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes IP address blocks from a directory.</p>
+    fn remove_ip_routes(
+        &self,
+        input: RemoveIpRoutesRequest,
+    ) -> RusotoFuture<RemoveIpRoutesResult, RemoveIpRoutesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RemoveIpRoutes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.RemoveIpRoutes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RemoveIpRoutesResult, _>(
+                    response,
+                    "RemoveIpRoutes",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RemoveIpRoutesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes tags from a directory.</p>
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.RemoveTagsFromResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RemoveTagsFromResourceResult, _>(
+                    response,
+                    "RemoveTagsFromResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RemoveTagsFromResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Resets the password for any user in your AWS Managed Microsoft AD or Simple AD directory.</p>
+    fn reset_user_password(
+        &self,
+        input: ResetUserPasswordRequest,
+    ) -> RusotoFuture<ResetUserPasswordResult, ResetUserPasswordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ResetUserPassword");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.ResetUserPassword",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ResetUserPasswordResult, _>(
+                    response,
+                    "ResetUserPassword",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResetUserPasswordError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Restores a directory using an existing directory snapshot.</p> <p>When you restore a directory from a snapshot, any changes made to the directory after the snapshot date are overwritten.</p> <p>This action returns as soon as the restore operation is initiated. You can monitor the progress of the restore operation by calling the <a>DescribeDirectories</a> operation with the directory identifier. When the <b>DirectoryDescription.Stage</b> value changes to <code>Active</code>, the restore operation is complete.</p>
+    fn restore_from_snapshot(
+        &self,
+        input: RestoreFromSnapshotRequest,
+    ) -> RusotoFuture<RestoreFromSnapshotResult, RestoreFromSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RestoreFromSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.RestoreFromSnapshot",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RestoreFromSnapshotResult, _>(
+                    response,
+                    "RestoreFromSnapshot",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RestoreFromSnapshotError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Shares a specified directory (<code>DirectoryId</code>) in your AWS account (directory owner) with another AWS account (directory consumer). With this operation you can use your directory from any AWS account and from any Amazon VPC within an AWS Region.</p> <p>When you share your AWS Managed Microsoft AD directory, AWS Directory Service creates a shared directory in the directory consumer account. This shared directory contains the metadata to provide access to the directory within the directory owner account. The shared directory is visible in all VPCs in the directory consumer account.</p> <p>The <code>ShareMethod</code> parameter determines whether the specified directory can be shared between AWS accounts inside the same AWS organization (<code>ORGANIZATIONS</code>). It also determines whether you can share the directory with any other AWS account either inside or outside of the organization (<code>HANDSHAKE</code>).</p> <p>The <code>ShareNotes</code> parameter is only used when <code>HANDSHAKE</code> is called, which sends a directory sharing request to the directory consumer. </p>
+    fn share_directory(
+        &self,
+        input: ShareDirectoryRequest,
+    ) -> RusotoFuture<ShareDirectoryResult, ShareDirectoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ShareDirectory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.ShareDirectory");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ShareDirectoryResult, _>(
+                    response,
+                    "ShareDirectory",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ShareDirectoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Applies a schema extension to a Microsoft AD directory.</p>
+    fn start_schema_extension(
+        &self,
+        input: StartSchemaExtensionRequest,
+    ) -> RusotoFuture<StartSchemaExtensionResult, StartSchemaExtensionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("StartSchemaExtension");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.StartSchemaExtension",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartSchemaExtensionResult, _>(
+                    response,
+                    "StartSchemaExtension",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartSchemaExtensionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops the directory sharing between the directory owner and consumer accounts. </p>
+    fn unshare_directory(
+        &self,
+        input: UnshareDirectoryRequest,
+    ) -> RusotoFuture<UnshareDirectoryResult, UnshareDirectoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UnshareDirectory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.UnshareDirectory");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UnshareDirectoryResult, _>(
+                    response,
+                    "UnshareDirectory",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UnshareDirectoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a conditional forwarder that has been set up for your AWS directory.</p>
+    fn update_conditional_forwarder(
+        &self,
+        input: UpdateConditionalForwarderRequest,
+    ) -> RusotoFuture<UpdateConditionalForwarderResult, UpdateConditionalForwarderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateConditionalForwarder");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.UpdateConditionalForwarder",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateConditionalForwarderResult, _>(
+                    response,
+                    "UpdateConditionalForwarder",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateConditionalForwarderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds or removes domain controllers to or from the directory. Based on the difference between current value and new value (provided through this API call), domain controllers will be added or removed. It may take up to 45 minutes for any new domain controllers to become fully active once the requested number of domain controllers is updated. During this time, you cannot make another update request.</p>
+    fn update_number_of_domain_controllers(
+        &self,
+        input: UpdateNumberOfDomainControllersRequest,
+    ) -> RusotoFuture<UpdateNumberOfDomainControllersResult, UpdateNumberOfDomainControllersError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateNumberOfDomainControllers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DirectoryService_20150416.UpdateNumberOfDomainControllers",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateNumberOfDomainControllersResult, _>(
+                    response,
+                    "UpdateNumberOfDomainControllers",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateNumberOfDomainControllersError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the Remote Authentication Dial In User Service (RADIUS) server information for an AD Connector or Microsoft AD directory.</p>
+    fn update_radius(
+        &self,
+        input: UpdateRadiusRequest,
+    ) -> RusotoFuture<UpdateRadiusResult, UpdateRadiusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateRadius");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.UpdateRadius");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateRadiusResult, _>(
+                    response,
+                    "UpdateRadius",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateRadiusError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the trust that has been set up between your AWS Managed Microsoft AD directory and an on-premises Active Directory.</p>
+    fn update_trust(
+        &self,
+        input: UpdateTrustRequest,
+    ) -> RusotoFuture<UpdateTrustResult, UpdateTrustError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateTrust");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.UpdateTrust");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateTrustResult, _>(response, "UpdateTrust")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateTrustError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure and verify trust relationships.</p> <p>This action verifies a trust relationship between your AWS Managed Microsoft AD directory and an external domain.</p>
+    fn verify_trust(
+        &self,
+        input: VerifyTrustRequest,
+    ) -> RusotoFuture<VerifyTrustResult, VerifyTrustError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("VerifyTrust");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "DirectoryService_20150416.VerifyTrust");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<VerifyTrustResult, _>(response, "VerifyTrust")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(VerifyTrustError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Directory Service API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockDirectoryService {
+    accept_shared_directory: Option<
+        Box<
+            dyn Fn(
+                    AcceptSharedDirectoryRequest,
+                ) -> ::std::result::Result<
+                    AcceptSharedDirectoryResult,
+                    RusotoError<AcceptSharedDirectoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_ip_routes: Option<
+        Box<
+            dyn Fn(
+                    AddIpRoutesRequest,
+                )
+                    -> ::std::result::Result<AddIpRoutesResult, RusotoError<AddIpRoutesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    add_tags_to_resource: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToResourceRequest,
+                ) -> ::std::result::Result<
+                    AddTagsToResourceResult,
+                    RusotoError<AddTagsToResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    cancel_schema_extension: Option<
+        Box<
+            dyn Fn(
+                    CancelSchemaExtensionRequest,
+                ) -> ::std::result::Result<
+                    CancelSchemaExtensionResult,
+                    RusotoError<CancelSchemaExtensionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    connect_directory: Option<
+        Box<
+            dyn Fn(
+                    ConnectDirectoryRequest,
+                ) -> ::std::result::Result<
+                    ConnectDirectoryResult,
+                    RusotoError<ConnectDirectoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_alias: Option<
+        Box<
+            dyn Fn(
+                    CreateAliasRequest,
+                )
+                    -> ::std::result::Result<CreateAliasResult, RusotoError<CreateAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_computer: Option<
+        Box<
+            dyn Fn(
+                    CreateComputerRequest,
+                )
+                    -> ::std::result::Result<CreateComputerResult, RusotoError<CreateComputerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_conditional_forwarder: Option<
+        Box<
+            dyn Fn(
+                    CreateConditionalForwarderRequest,
+                ) -> ::std::result::Result<
+                    CreateConditionalForwarderResult,
+                    RusotoError<CreateConditionalForwarderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_directory: Option<
+        Box<
+            dyn Fn(
+                    CreateDirectoryRequest,
+                ) -> ::std::result::Result<
+                    CreateDirectoryResult,
+                    RusotoError<CreateDirectoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_log_subscription: Option<
+        Box<
+            dyn Fn(
+                    CreateLogSubscriptionRequest,
+                ) -> ::std::result::Result<
+                    CreateLogSubscriptionResult,
+                    RusotoError<CreateLogSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_microsoft_ad: Option<
+        Box<
+            dyn Fn(
+                    CreateMicrosoftADRequest,
+                ) -> ::std::result::Result<
+                    CreateMicrosoftADResult,
+                    RusotoError<CreateMicrosoftADError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateSnapshotRequest,
+                )
+                    -> ::std::result::Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_trust: Option<
+        Box<
+            dyn Fn(
+                    CreateTrustRequest,
+                )
+                    -> ::std::result::Result<CreateTrustResult, RusotoError<CreateTrustError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_conditional_forwarder: Option<
+        Box<
+            dyn Fn(
+                    DeleteConditionalForwarderRequest,
+                ) -> ::std::result::Result<
+                    DeleteConditionalForwarderResult,
+                    RusotoError<DeleteConditionalForwarderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_directory: Option<
+        Box<
+            dyn Fn(
+                    DeleteDirectoryRequest,
+                ) -> ::std::result::Result<
+                    DeleteDirectoryResult,
+                    RusotoError<DeleteDirectoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_log_subscription: Option<
+        Box<
+            dyn Fn(
+                    DeleteLogSubscriptionRequest,
+                ) -> ::std::result::Result<
+                    DeleteLogSubscriptionResult,
+                    RusotoError<DeleteLogSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_snapshot: Option<
+        Box<
+            dyn Fn(
+                    DeleteSnapshotRequest,
+                )
+                    -> ::std::result::Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_trust: Option<
+        Box<
+            dyn Fn(
+                    DeleteTrustRequest,
+                )
+                    -> ::std::result::Result<DeleteTrustResult, RusotoError<DeleteTrustError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_event_topic: Option<
+        Box<
+            dyn Fn(
+                    DeregisterEventTopicRequest,
+                ) -> ::std::result::Result<
+                    DeregisterEventTopicResult,
+                    RusotoError<DeregisterEventTopicError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_conditional_forwarders: Option<
+        Box<
+            dyn Fn(
+                    DescribeConditionalForwardersRequest,
+                ) -> ::std::result::Result<
+                    DescribeConditionalForwardersResult,
+                    RusotoError<DescribeConditionalForwardersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_directories: Option<
+        Box<
+            dyn Fn(
+                    DescribeDirectoriesRequest,
+                ) -> ::std::result::Result<
+                    DescribeDirectoriesResult,
+                    RusotoError<DescribeDirectoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_domain_controllers: Option<
+        Box<
+            dyn Fn(
+                    DescribeDomainControllersRequest,
+                ) -> ::std::result::Result<
+                    DescribeDomainControllersResult,
+                    RusotoError<DescribeDomainControllersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_event_topics: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventTopicsRequest,
+                ) -> ::std::result::Result<
+                    DescribeEventTopicsResult,
+                    RusotoError<DescribeEventTopicsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_shared_directories: Option<
+        Box<
+            dyn Fn(
+                    DescribeSharedDirectoriesRequest,
+                ) -> ::std::result::Result<
+                    DescribeSharedDirectoriesResult,
+                    RusotoError<DescribeSharedDirectoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_snapshots: Option<
+        Box<
+            dyn Fn(
+                    DescribeSnapshotsRequest,
+                ) -> ::std::result::Result<
+                    DescribeSnapshotsResult,
+                    RusotoError<DescribeSnapshotsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_trusts: Option<
+        Box<
+            dyn Fn(
+                    DescribeTrustsRequest,
+                )
+                    -> ::std::result::Result<DescribeTrustsResult, RusotoError<DescribeTrustsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_radius: Option<
+        Box<
+            dyn Fn(
+                    DisableRadiusRequest,
+                )
+                    -> ::std::result::Result<DisableRadiusResult, RusotoError<DisableRadiusError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_sso: Option<
+        Box<
+            dyn Fn(
+                    DisableSsoRequest,
+                )
+                    -> ::std::result::Result<DisableSsoResult, RusotoError<DisableSsoError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    enable_radius: Option<
+        Box<
+            dyn Fn(
+                    EnableRadiusRequest,
+                )
+                    -> ::std::result::Result<EnableRadiusResult, RusotoError<EnableRadiusError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    enable_sso: Option<
+        Box<
+            dyn Fn(
+                    EnableSsoRequest,
+                )
+                    -> ::std::result::Result<EnableSsoResult, RusotoError<EnableSsoError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_directory_limits: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetDirectoryLimitsResult,
+                    RusotoError<GetDirectoryLimitsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_snapshot_limits: Option<
+        Box<
+            dyn Fn(
+                    GetSnapshotLimitsRequest,
+                ) -> ::std::result::Result<
+                    GetSnapshotLimitsResult,
+                    RusotoError<GetSnapshotLimitsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_ip_routes: Option<
+        Box<
+            dyn Fn(
+                    ListIpRoutesRequest,
+                )
+                    -> ::std::result::Result<ListIpRoutesResult, RusotoError<ListIpRoutesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_log_subscriptions: Option<
+        Box<
+            dyn Fn(
+                    ListLogSubscriptionsRequest,
+                ) -> ::std::result::Result<
+                    ListLogSubscriptionsResult,
+                    RusotoError<ListLogSubscriptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_schema_extensions: Option<
+        Box<
+            dyn Fn(
+                    ListSchemaExtensionsRequest,
+                ) -> ::std::result::Result<
+                    ListSchemaExtensionsResult,
+                    RusotoError<ListSchemaExtensionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResult,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_event_topic: Option<
+        Box<
+            dyn Fn(
+                    RegisterEventTopicRequest,
+                ) -> ::std::result::Result<
+                    RegisterEventTopicResult,
+                    RusotoError<RegisterEventTopicError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reject_shared_directory: Option<
+        Box<
+            dyn Fn(
+                    RejectSharedDirectoryRequest,
+                ) -> ::std::result::Result<
+                    RejectSharedDirectoryResult,
+                    RusotoError<RejectSharedDirectoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_ip_routes: Option<
+        Box<
+            dyn Fn(
+                    RemoveIpRoutesRequest,
+                )
+                    -> ::std::result::Result<RemoveIpRoutesResult, RusotoError<RemoveIpRoutesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_resource: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromResourceRequest,
+                ) -> ::std::result::Result<
+                    RemoveTagsFromResourceResult,
+                    RusotoError<RemoveTagsFromResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reset_user_password: Option<
+        Box<
+            dyn Fn(
+                    ResetUserPasswordRequest,
+                ) -> ::std::result::Result<
+                    ResetUserPasswordResult,
+                    RusotoError<ResetUserPasswordError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    restore_from_snapshot: Option<
+        Box<
+            dyn Fn(
+                    RestoreFromSnapshotRequest,
+                ) -> ::std::result::Result<
+                    RestoreFromSnapshotResult,
+                    RusotoError<RestoreFromSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    share_directory: Option<
+        Box<
+            dyn Fn(
+                    ShareDirectoryRequest,
+                )
+                    -> ::std::result::Result<ShareDirectoryResult, RusotoError<ShareDirectoryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_schema_extension: Option<
+        Box<
+            dyn Fn(
+                    StartSchemaExtensionRequest,
+                ) -> ::std::result::Result<
+                    StartSchemaExtensionResult,
+                    RusotoError<StartSchemaExtensionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    unshare_directory: Option<
+        Box<
+            dyn Fn(
+                    UnshareDirectoryRequest,
+                ) -> ::std::result::Result<
+                    UnshareDirectoryResult,
+                    RusotoError<UnshareDirectoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_conditional_forwarder: Option<
+        Box<
+            dyn Fn(
+                    UpdateConditionalForwarderRequest,
+                ) -> ::std::result::Result<
+                    UpdateConditionalForwarderResult,
+                    RusotoError<UpdateConditionalForwarderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_number_of_domain_controllers: Option<
+        Box<
+            dyn Fn(
+                    UpdateNumberOfDomainControllersRequest,
+                ) -> ::std::result::Result<
+                    UpdateNumberOfDomainControllersResult,
+                    RusotoError<UpdateNumberOfDomainControllersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_radius: Option<
+        Box<
+            dyn Fn(
+                    UpdateRadiusRequest,
+                )
+                    -> ::std::result::Result<UpdateRadiusResult, RusotoError<UpdateRadiusError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_trust: Option<
+        Box<
+            dyn Fn(
+                    UpdateTrustRequest,
+                )
+                    -> ::std::result::Result<UpdateTrustResult, RusotoError<UpdateTrustError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    verify_trust: Option<
+        Box<
+            dyn Fn(
+                    VerifyTrustRequest,
+                )
+                    -> ::std::result::Result<VerifyTrustResult, RusotoError<VerifyTrustError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockDirectoryService {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockDirectoryService {
+        Default::default()
+    }
+
+    /// Answers `accept_shared_directory` with what `handler` returns for its input.
+    pub fn with_accept_shared_directory<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                AcceptSharedDirectoryRequest,
+            ) -> ::std::result::Result<
+                AcceptSharedDirectoryResult,
+                RusotoError<AcceptSharedDirectoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.accept_shared_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_ip_routes` with what `handler` returns for its input.
+    pub fn with_add_ip_routes<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                AddIpRoutesRequest,
+            )
+                -> ::std::result::Result<AddIpRoutesResult, RusotoError<AddIpRoutesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_ip_routes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_tags_to_resource` with what `handler` returns for its input.
+    pub fn with_add_tags_to_resource<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                AddTagsToResourceRequest,
+            ) -> ::std::result::Result<
+                AddTagsToResourceResult,
+                RusotoError<AddTagsToResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_schema_extension` with what `handler` returns for its input.
+    pub fn with_cancel_schema_extension<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                CancelSchemaExtensionRequest,
+            ) -> ::std::result::Result<
+                CancelSchemaExtensionResult,
+                RusotoError<CancelSchemaExtensionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_schema_extension = Some(Box::new(handler));
+        self
+    }
+    /// Answers `connect_directory` with what `handler` returns for its input.
+    pub fn with_connect_directory<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                ConnectDirectoryRequest,
+            )
+                -> ::std::result::Result<ConnectDirectoryResult, RusotoError<ConnectDirectoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.connect_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_alias` with what `handler` returns for its input.
+    pub fn with_create_alias<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                CreateAliasRequest,
+            )
+                -> ::std::result::Result<CreateAliasResult, RusotoError<CreateAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_computer` with what `handler` returns for its input.
+    pub fn with_create_computer<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                CreateComputerRequest,
+            )
+                -> ::std::result::Result<CreateComputerResult, RusotoError<CreateComputerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_computer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_conditional_forwarder` with what `handler` returns for its input.
+    pub fn with_create_conditional_forwarder<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                CreateConditionalForwarderRequest,
+            ) -> ::std::result::Result<
+                CreateConditionalForwarderResult,
+                RusotoError<CreateConditionalForwarderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_conditional_forwarder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_directory` with what `handler` returns for its input.
+    pub fn with_create_directory<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                CreateDirectoryRequest,
+            )
+                -> ::std::result::Result<CreateDirectoryResult, RusotoError<CreateDirectoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_log_subscription` with what `handler` returns for its input.
+    pub fn with_create_log_subscription<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                CreateLogSubscriptionRequest,
+            ) -> ::std::result::Result<
+                CreateLogSubscriptionResult,
+                RusotoError<CreateLogSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_log_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_microsoft_ad` with what `handler` returns for its input.
+    pub fn with_create_microsoft_ad<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                CreateMicrosoftADRequest,
+            ) -> ::std::result::Result<
+                CreateMicrosoftADResult,
+                RusotoError<CreateMicrosoftADError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_microsoft_ad = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_snapshot` with what `handler` returns for its input.
+    pub fn with_create_snapshot<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                CreateSnapshotRequest,
+            )
+                -> ::std::result::Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_trust` with what `handler` returns for its input.
+    pub fn with_create_trust<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                CreateTrustRequest,
+            )
+                -> ::std::result::Result<CreateTrustResult, RusotoError<CreateTrustError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_trust = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_conditional_forwarder` with what `handler` returns for its input.
+    pub fn with_delete_conditional_forwarder<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DeleteConditionalForwarderRequest,
+            ) -> ::std::result::Result<
+                DeleteConditionalForwarderResult,
+                RusotoError<DeleteConditionalForwarderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_conditional_forwarder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_directory` with what `handler` returns for its input.
+    pub fn with_delete_directory<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DeleteDirectoryRequest,
+            )
+                -> ::std::result::Result<DeleteDirectoryResult, RusotoError<DeleteDirectoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_log_subscription` with what `handler` returns for its input.
+    pub fn with_delete_log_subscription<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DeleteLogSubscriptionRequest,
+            ) -> ::std::result::Result<
+                DeleteLogSubscriptionResult,
+                RusotoError<DeleteLogSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_log_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_snapshot` with what `handler` returns for its input.
+    pub fn with_delete_snapshot<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DeleteSnapshotRequest,
+            )
+                -> ::std::result::Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_trust` with what `handler` returns for its input.
+    pub fn with_delete_trust<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DeleteTrustRequest,
+            )
+                -> ::std::result::Result<DeleteTrustResult, RusotoError<DeleteTrustError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_trust = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_event_topic` with what `handler` returns for its input.
+    pub fn with_deregister_event_topic<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DeregisterEventTopicRequest,
+            ) -> ::std::result::Result<
+                DeregisterEventTopicResult,
+                RusotoError<DeregisterEventTopicError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_event_topic = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_conditional_forwarders` with what `handler` returns for its input.
+    pub fn with_describe_conditional_forwarders<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DescribeConditionalForwardersRequest,
+            ) -> ::std::result::Result<
+                DescribeConditionalForwardersResult,
+                RusotoError<DescribeConditionalForwardersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_conditional_forwarders = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_directories` with what `handler` returns for its input.
+    pub fn with_describe_directories<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DescribeDirectoriesRequest,
+            ) -> ::std::result::Result<
+                DescribeDirectoriesResult,
+                RusotoError<DescribeDirectoriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_directories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_domain_controllers` with what `handler` returns for its input.
+    pub fn with_describe_domain_controllers<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DescribeDomainControllersRequest,
+            ) -> ::std::result::Result<
+                DescribeDomainControllersResult,
+                RusotoError<DescribeDomainControllersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_domain_controllers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_topics` with what `handler` returns for its input.
+    pub fn with_describe_event_topics<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DescribeEventTopicsRequest,
+            ) -> ::std::result::Result<
+                DescribeEventTopicsResult,
+                RusotoError<DescribeEventTopicsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_topics = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_shared_directories` with what `handler` returns for its input.
+    pub fn with_describe_shared_directories<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DescribeSharedDirectoriesRequest,
+            ) -> ::std::result::Result<
+                DescribeSharedDirectoriesResult,
+                RusotoError<DescribeSharedDirectoriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_shared_directories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_snapshots` with what `handler` returns for its input.
+    pub fn with_describe_snapshots<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DescribeSnapshotsRequest,
+            ) -> ::std::result::Result<
+                DescribeSnapshotsResult,
+                RusotoError<DescribeSnapshotsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_snapshots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_trusts` with what `handler` returns for its input.
+    pub fn with_describe_trusts<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DescribeTrustsRequest,
+            )
+                -> ::std::result::Result<DescribeTrustsResult, RusotoError<DescribeTrustsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_trusts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_radius` with what `handler` returns for its input.
+    pub fn with_disable_radius<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DisableRadiusRequest,
+            )
+                -> ::std::result::Result<DisableRadiusResult, RusotoError<DisableRadiusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_radius = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_sso` with what `handler` returns for its input.
+    pub fn with_disable_sso<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                DisableSsoRequest,
+            ) -> ::std::result::Result<DisableSsoResult, RusotoError<DisableSsoError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_sso = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_radius` with what `handler` returns for its input.
+    pub fn with_enable_radius<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                EnableRadiusRequest,
+            )
+                -> ::std::result::Result<EnableRadiusResult, RusotoError<EnableRadiusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_radius = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_sso` with what `handler` returns for its input.
+    pub fn with_enable_sso<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                EnableSsoRequest,
+            ) -> ::std::result::Result<EnableSsoResult, RusotoError<EnableSsoError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_sso = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_directory_limits` with what `handler` returns.
+    pub fn with_get_directory_limits<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn() -> ::std::result::Result<
+                GetDirectoryLimitsResult,
+                RusotoError<GetDirectoryLimitsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_directory_limits = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_snapshot_limits` with what `handler` returns for its input.
+    pub fn with_get_snapshot_limits<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                GetSnapshotLimitsRequest,
+            ) -> ::std::result::Result<
+                GetSnapshotLimitsResult,
+                RusotoError<GetSnapshotLimitsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_snapshot_limits = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_ip_routes` with what `handler` returns for its input.
+    pub fn with_list_ip_routes<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                ListIpRoutesRequest,
+            )
+                -> ::std::result::Result<ListIpRoutesResult, RusotoError<ListIpRoutesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_ip_routes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_log_subscriptions` with what `handler` returns for its input.
+    pub fn with_list_log_subscriptions<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                ListLogSubscriptionsRequest,
+            ) -> ::std::result::Result<
+                ListLogSubscriptionsResult,
+                RusotoError<ListLogSubscriptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_log_subscriptions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_schema_extensions` with what `handler` returns for its input.
+    pub fn with_list_schema_extensions<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                ListSchemaExtensionsRequest,
+            ) -> ::std::result::Result<
+                ListSchemaExtensionsResult,
+                RusotoError<ListSchemaExtensionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_schema_extensions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResult,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_event_topic` with what `handler` returns for its input.
+    pub fn with_register_event_topic<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                RegisterEventTopicRequest,
+            ) -> ::std::result::Result<
+                RegisterEventTopicResult,
+                RusotoError<RegisterEventTopicError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_event_topic = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reject_shared_directory` with what `handler` returns for its input.
+    pub fn with_reject_shared_directory<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                RejectSharedDirectoryRequest,
+            ) -> ::std::result::Result<
+                RejectSharedDirectoryResult,
+                RusotoError<RejectSharedDirectoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reject_shared_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_ip_routes` with what `handler` returns for its input.
+    pub fn with_remove_ip_routes<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                RemoveIpRoutesRequest,
+            )
+                -> ::std::result::Result<RemoveIpRoutesResult, RusotoError<RemoveIpRoutesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_ip_routes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_resource` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_resource<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                RemoveTagsFromResourceRequest,
+            ) -> ::std::result::Result<
+                RemoveTagsFromResourceResult,
+                RusotoError<RemoveTagsFromResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reset_user_password` with what `handler` returns for its input.
+    pub fn with_reset_user_password<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                ResetUserPasswordRequest,
+            ) -> ::std::result::Result<
+                ResetUserPasswordResult,
+                RusotoError<ResetUserPasswordError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reset_user_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_from_snapshot` with what `handler` returns for its input.
+    pub fn with_restore_from_snapshot<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                RestoreFromSnapshotRequest,
+            ) -> ::std::result::Result<
+                RestoreFromSnapshotResult,
+                RusotoError<RestoreFromSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_from_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `share_directory` with what `handler` returns for its input.
+    pub fn with_share_directory<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                ShareDirectoryRequest,
+            )
+                -> ::std::result::Result<ShareDirectoryResult, RusotoError<ShareDirectoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.share_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_schema_extension` with what `handler` returns for its input.
+    pub fn with_start_schema_extension<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                StartSchemaExtensionRequest,
+            ) -> ::std::result::Result<
+                StartSchemaExtensionResult,
+                RusotoError<StartSchemaExtensionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_schema_extension = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unshare_directory` with what `handler` returns for its input.
+    pub fn with_unshare_directory<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                UnshareDirectoryRequest,
+            )
+                -> ::std::result::Result<UnshareDirectoryResult, RusotoError<UnshareDirectoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.unshare_directory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_conditional_forwarder` with what `handler` returns for its input.
+    pub fn with_update_conditional_forwarder<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                UpdateConditionalForwarderRequest,
+            ) -> ::std::result::Result<
+                UpdateConditionalForwarderResult,
+                RusotoError<UpdateConditionalForwarderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_conditional_forwarder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_number_of_domain_controllers` with what `handler` returns for its input.
+    pub fn with_update_number_of_domain_controllers<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                UpdateNumberOfDomainControllersRequest,
+            ) -> ::std::result::Result<
+                UpdateNumberOfDomainControllersResult,
+                RusotoError<UpdateNumberOfDomainControllersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_number_of_domain_controllers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_radius` with what `handler` returns for its input.
+    pub fn with_update_radius<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                UpdateRadiusRequest,
+            )
+                -> ::std::result::Result<UpdateRadiusResult, RusotoError<UpdateRadiusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_radius = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_trust` with what `handler` returns for its input.
+    pub fn with_update_trust<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                UpdateTrustRequest,
+            )
+                -> ::std::result::Result<UpdateTrustResult, RusotoError<UpdateTrustError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_trust = Some(Box::new(handler));
+        self
+    }
+    /// Answers `verify_trust` with what `handler` returns for its input.
+    pub fn with_verify_trust<F>(mut self, handler: F) -> MockDirectoryService
+    where
+        F: Fn(
+                VerifyTrustRequest,
+            )
+                -> ::std::result::Result<VerifyTrustResult, RusotoError<VerifyTrustError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.verify_trust = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl DirectoryService for MockDirectoryService {
+    fn accept_shared_directory(
+        &self,
+        input: AcceptSharedDirectoryRequest,
+    ) -> RusotoFuture<AcceptSharedDirectoryResult, AcceptSharedDirectoryError> {
+        match self.accept_shared_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for accept_shared_directory"),
+        }
+    }
+    fn add_ip_routes(
+        &self,
+        input: AddIpRoutesRequest,
+    ) -> RusotoFuture<AddIpRoutesResult, AddIpRoutesError> {
+        match self.add_ip_routes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for add_ip_routes"),
+        }
+    }
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
+        match self.add_tags_to_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for add_tags_to_resource"),
+        }
+    }
+    fn cancel_schema_extension(
+        &self,
+        input: CancelSchemaExtensionRequest,
+    ) -> RusotoFuture<CancelSchemaExtensionResult, CancelSchemaExtensionError> {
+        match self.cancel_schema_extension {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for cancel_schema_extension"),
+        }
+    }
+    fn connect_directory(
+        &self,
+        input: ConnectDirectoryRequest,
+    ) -> RusotoFuture<ConnectDirectoryResult, ConnectDirectoryError> {
+        match self.connect_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for connect_directory"),
+        }
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<CreateAliasResult, CreateAliasError> {
+        match self.create_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for create_alias"),
+        }
+    }
+    fn create_computer(
+        &self,
+        input: CreateComputerRequest,
+    ) -> RusotoFuture<CreateComputerResult, CreateComputerError> {
+        match self.create_computer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for create_computer"),
+        }
+    }
+    fn create_conditional_forwarder(
+        &self,
+        input: CreateConditionalForwarderRequest,
+    ) -> RusotoFuture<CreateConditionalForwarderResult, CreateConditionalForwarderError> {
+        match self.create_conditional_forwarder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for create_conditional_forwarder"),
+        }
+    }
+    fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> RusotoFuture<CreateDirectoryResult, CreateDirectoryError> {
+        match self.create_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for create_directory"),
+        }
+    }
+    fn create_log_subscription(
+        &self,
+        input: CreateLogSubscriptionRequest,
+    ) -> RusotoFuture<CreateLogSubscriptionResult, CreateLogSubscriptionError> {
+        match self.create_log_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for create_log_subscription"),
+        }
+    }
+    fn create_microsoft_ad(
+        &self,
+        input: CreateMicrosoftADRequest,
+    ) -> RusotoFuture<CreateMicrosoftADResult, CreateMicrosoftADError> {
+        match self.create_microsoft_ad {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for create_microsoft_ad"),
+        }
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
+        match self.create_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for create_snapshot"),
+        }
+    }
+    fn create_trust(
+        &self,
+        input: CreateTrustRequest,
+    ) -> RusotoFuture<CreateTrustResult, CreateTrustError> {
+        match self.create_trust {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for create_trust"),
+        }
+    }
+    fn delete_conditional_forwarder(
+        &self,
+        input: DeleteConditionalForwarderRequest,
+    ) -> RusotoFuture<DeleteConditionalForwarderResult, DeleteConditionalForwarderError> {
+        match self.delete_conditional_forwarder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for delete_conditional_forwarder"),
+        }
+    }
+    fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> RusotoFuture<DeleteDirectoryResult, DeleteDirectoryError> {
+        match self.delete_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for delete_directory"),
+        }
+    }
+    fn delete_log_subscription(
+        &self,
+        input: DeleteLogSubscriptionRequest,
+    ) -> RusotoFuture<DeleteLogSubscriptionResult, DeleteLogSubscriptionError> {
+        match self.delete_log_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for delete_log_subscription"),
+        }
+    }
+    fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
+        match self.delete_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for delete_snapshot"),
+        }
+    }
+    fn delete_trust(
+        &self,
+        input: DeleteTrustRequest,
+    ) -> RusotoFuture<DeleteTrustResult, DeleteTrustError> {
+        match self.delete_trust {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for delete_trust"),
+        }
+    }
+    fn deregister_event_topic(
+        &self,
+        input: DeregisterEventTopicRequest,
+    ) -> RusotoFuture<DeregisterEventTopicResult, DeregisterEventTopicError> {
+        match self.deregister_event_topic {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for deregister_event_topic"),
+        }
+    }
+    fn describe_conditional_forwarders(
+        &self,
+        input: DescribeConditionalForwardersRequest,
+    ) -> RusotoFuture<DescribeConditionalForwardersResult, DescribeConditionalForwardersError> {
+        match self.describe_conditional_forwarders {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockDirectoryService has no handler for describe_conditional_forwarders")
+            }
+        }
+    }
+    fn describe_directories(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> RusotoFuture<DescribeDirectoriesResult, DescribeDirectoriesError> {
+        match self.describe_directories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for describe_directories"),
+        }
+    }
+    fn describe_domain_controllers(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> RusotoFuture<DescribeDomainControllersResult, DescribeDomainControllersError> {
+        match self.describe_domain_controllers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for describe_domain_controllers"),
+        }
+    }
+    fn describe_event_topics(
+        &self,
+        input: DescribeEventTopicsRequest,
+    ) -> RusotoFuture<DescribeEventTopicsResult, DescribeEventTopicsError> {
+        match self.describe_event_topics {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for describe_event_topics"),
+        }
+    }
+    fn describe_shared_directories(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> RusotoFuture<DescribeSharedDirectoriesResult, DescribeSharedDirectoriesError> {
+        match self.describe_shared_directories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for describe_shared_directories"),
+        }
+    }
+    fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> RusotoFuture<DescribeSnapshotsResult, DescribeSnapshotsError> {
+        match self.describe_snapshots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for describe_snapshots"),
+        }
+    }
+    fn describe_trusts(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> RusotoFuture<DescribeTrustsResult, DescribeTrustsError> {
+        match self.describe_trusts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for describe_trusts"),
+        }
+    }
+    fn disable_radius(
+        &self,
+        input: DisableRadiusRequest,
+    ) -> RusotoFuture<DisableRadiusResult, DisableRadiusError> {
+        match self.disable_radius {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for disable_radius"),
+        }
+    }
+    fn disable_sso(
+        &self,
+        input: DisableSsoRequest,
+    ) -> RusotoFuture<DisableSsoResult, DisableSsoError> {
+        match self.disable_sso {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for disable_sso"),
+        }
+    }
+    fn enable_radius(
+        &self,
+        input: EnableRadiusRequest,
+    ) -> RusotoFuture<EnableRadiusResult, EnableRadiusError> {
+        match self.enable_radius {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for enable_radius"),
+        }
+    }
+    fn enable_sso(&self, input: EnableSsoRequest) -> RusotoFuture<EnableSsoResult, EnableSsoError> {
+        match self.enable_sso {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for enable_sso"),
+        }
+    }
+    fn get_directory_limits(
+        &self,
+    ) -> RusotoFuture<GetDirectoryLimitsResult, GetDirectoryLimitsError> {
+        match self.get_directory_limits {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockDirectoryService has no handler for get_directory_limits"),
+        }
+    }
+    fn get_snapshot_limits(
+        &self,
+        input: GetSnapshotLimitsRequest,
+    ) -> RusotoFuture<GetSnapshotLimitsResult, GetSnapshotLimitsError> {
+        match self.get_snapshot_limits {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for get_snapshot_limits"),
+        }
+    }
+    fn list_ip_routes(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> RusotoFuture<ListIpRoutesResult, ListIpRoutesError> {
+        match self.list_ip_routes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for list_ip_routes"),
+        }
+    }
+    fn list_log_subscriptions(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> RusotoFuture<ListLogSubscriptionsResult, ListLogSubscriptionsError> {
+        match self.list_log_subscriptions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for list_log_subscriptions"),
+        }
+    }
+    fn list_schema_extensions(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> RusotoFuture<ListSchemaExtensionsResult, ListSchemaExtensionsError> {
+        match self.list_schema_extensions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for list_schema_extensions"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for list_tags_for_resource"),
+        }
+    }
+    fn register_event_topic(
+        &self,
+        input: RegisterEventTopicRequest,
+    ) -> RusotoFuture<RegisterEventTopicResult, RegisterEventTopicError> {
+        match self.register_event_topic {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for register_event_topic"),
+        }
+    }
+    fn reject_shared_directory(
+        &self,
+        input: RejectSharedDirectoryRequest,
+    ) -> RusotoFuture<RejectSharedDirectoryResult, RejectSharedDirectoryError> {
+        match self.reject_shared_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for reject_shared_directory"),
+        }
+    }
+    fn remove_ip_routes(
+        &self,
+        input: RemoveIpRoutesRequest,
+    ) -> RusotoFuture<RemoveIpRoutesResult, RemoveIpRoutesError> {
+        match self.remove_ip_routes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for remove_ip_routes"),
+        }
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
+        match self.remove_tags_from_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for remove_tags_from_resource"),
+        }
     }
-
-    /// <p>Resets the password for any user in your AWS Managed Microsoft AD or Simple AD directory.</p>
     fn reset_user_password(
         &self,
         input: ResetUserPasswordRequest,
     ) -> RusotoFuture<ResetUserPasswordResult, ResetUserPasswordError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.ResetUserPassword",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResetUserPasswordResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResetUserPasswordError::from_response(response))),
-                )
-            }
-        })
+        match self.reset_user_password {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for reset_user_password"),
+        }
     }
-
-    /// <p>Restores a directory using an existing directory snapshot.</p> <p>When you restore a directory from a snapshot, any changes made to the directory after the snapshot date are overwritten.</p> <p>This action returns as soon as the restore operation is initiated. You can monitor the progress of the restore operation by calling the <a>DescribeDirectories</a> operation with the directory identifier. When the <b>DirectoryDescription.Stage</b> value changes to <code>Active</code>, the restore operation is complete.</p>
     fn restore_from_snapshot(
         &self,
         input: RestoreFromSnapshotRequest,
     ) -> RusotoFuture<RestoreFromSnapshotResult, RestoreFromSnapshotError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.RestoreFromSnapshot",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestoreFromSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RestoreFromSnapshotError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.restore_from_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for restore_from_snapshot"),
+        }
     }
-
-    /// <p>Shares a specified directory (<code>DirectoryId</code>) in your AWS account (directory owner) with another AWS account (directory consumer). With this operation you can use your directory from any AWS account and from any Amazon VPC within an AWS Region.</p> <p>When you share your AWS Managed Microsoft AD directory, AWS Directory Service creates a shared directory in the directory consumer account. This shared directory contains the metadata to provide access to the directory within the directory owner account. The shared directory is visible in all VPCs in the directory consumer account.</p> <p>The <code>ShareMethod</code> parameter determines whether the specified directory can be shared between AWS accounts inside the same AWS organization (<code>ORGANIZATIONS</code>). It also determines whether you can share the directory with any other AWS account either inside or outside of the organization (<code>HANDSHAKE</code>).</p> <p>The <code>ShareNotes</code> parameter is only used when <code>HANDSHAKE</code> is called, which sends a directory sharing request to the directory consumer. </p>
     fn share_directory(
         &self,
         input: ShareDirectoryRequest,
     ) -> RusotoFuture<ShareDirectoryResult, ShareDirectoryError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.ShareDirectory");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ShareDirectoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ShareDirectoryError::from_response(response))),
-                )
-            }
-        })
+        match self.share_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for share_directory"),
+        }
     }
-
-    /// <p>Applies a schema extension to a Microsoft AD directory.</p>
     fn start_schema_extension(
         &self,
         input: StartSchemaExtensionRequest,
     ) -> RusotoFuture<StartSchemaExtensionResult, StartSchemaExtensionError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.StartSchemaExtension",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartSchemaExtensionResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartSchemaExtensionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.start_schema_extension {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for start_schema_extension"),
+        }
     }
-
-    /// <p>Stops the directory sharing between the directory owner and consumer accounts. </p>
     fn unshare_directory(
         &self,
         input: UnshareDirectoryRequest,
     ) -> RusotoFuture<UnshareDirectoryResult, UnshareDirectoryError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.UnshareDirectory");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UnshareDirectoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UnshareDirectoryError::from_response(response))),
-                )
-            }
-        })
+        match self.unshare_directory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for unshare_directory"),
+        }
     }
-
-    /// <p>Updates a conditional forwarder that has been set up for your AWS directory.</p>
     fn update_conditional_forwarder(
         &self,
         input: UpdateConditionalForwarderRequest,
     ) -> RusotoFuture<UpdateConditionalForwarderResult, UpdateConditionalForwarderError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.UpdateConditionalForwarder",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateConditionalForwarderResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateConditionalForwarderError::from_response(response))
-                }))
-            }
-        })
+        match self.update_conditional_forwarder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for update_conditional_forwarder"),
+        }
     }
-
-    /// <p>Adds or removes domain controllers to or from the directory. Based on the difference between current value and new value (provided through this API call), domain controllers will be added or removed. It may take up to 45 minutes for any new domain controllers to become fully active once the requested number of domain controllers is updated. During this time, you cannot make another update request.</p>
     fn update_number_of_domain_controllers(
         &self,
         input: UpdateNumberOfDomainControllersRequest,
     ) -> RusotoFuture<UpdateNumberOfDomainControllersResult, UpdateNumberOfDomainControllersError>
     {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DirectoryService_20150416.UpdateNumberOfDomainControllers",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateNumberOfDomainControllersResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateNumberOfDomainControllersError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.update_number_of_domain_controllers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockDirectoryService has no handler for update_number_of_domain_controllers"
+            ),
+        }
     }
-
-    /// <p>Updates the Remote Authentication Dial In User Service (RADIUS) server information for an AD Connector or Microsoft AD directory.</p>
     fn update_radius(
         &self,
         input: UpdateRadiusRequest,
     ) -> RusotoFuture<UpdateRadiusResult, UpdateRadiusError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.UpdateRadius");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRadiusResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateRadiusError::from_response(response))),
-                )
-            }
-        })
+        match self.update_radius {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for update_radius"),
+        }
     }
-
-    /// <p>Updates the trust that has been set up between your AWS Managed Microsoft AD directory and an on-premises Active Directory.</p>
     fn update_trust(
         &self,
         input: UpdateTrustRequest,
     ) -> RusotoFuture<UpdateTrustResult, UpdateTrustError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.UpdateTrust");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTrustResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTrustError::from_response(response))),
-                )
-            }
-        })
+        match self.update_trust {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for update_trust"),
+        }
     }
-
-    /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure and verify trust relationships.</p> <p>This action verifies a trust relationship between your AWS Managed Microsoft AD directory and an external domain.</p>
     fn verify_trust(
         &self,
         input: VerifyTrustRequest,
     ) -> RusotoFuture<VerifyTrustResult, VerifyTrustError> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "DirectoryService_20150416.VerifyTrust");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<VerifyTrustResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(VerifyTrustError::from_response(response))),
-                )
-            }
-        })
+        match self.verify_trust {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDirectoryService has no handler for verify_trust"),
+        }
     }
 }
@@ -0,0 +1,413 @@
+//! Accounting of the read and write capacity consumed by DynamoDB requests.
+//!
+//! DynamoDB only reports the capacity a request consumed when the request asks for it
+//! through `ReturnConsumedCapacity`. [`CapacityTrackingClient`](struct.CapacityTrackingClient.html)
+//! wraps a `DynamoDb` client, asks for the total consumed capacity on every request that
+//! supports it and hands each table's share to a callback.
+//!
+//! ```rust,no_run
+//! use rusoto_core::Region;
+//! use rusoto_dynamodb::capacity::CapacityTrackingClient;
+//! use rusoto_dynamodb::DynamoDbClient;
+//!
+//! let client = CapacityTrackingClient::new(DynamoDbClient::new(Region::UsEast1))
+//!     .on_consumed_capacity(|table, units, operation| {
+//!         println!("{:?} consumed {} capacity units of {}", operation, units, table);
+//!     });
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use futures::Future;
+use rusoto_core::RusotoFuture;
+
+use crate::generated::*;
+
+/// The DynamoDB operations which can report the capacity they consumed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operation {
+    BatchGetItem,
+    BatchWriteItem,
+    DeleteItem,
+    GetItem,
+    PutItem,
+    Query,
+    Scan,
+    TransactGetItems,
+    TransactWriteItems,
+    UpdateItem,
+}
+
+type Callback = Arc<dyn Fn(&str, f64, Operation) + Send + Sync>;
+
+/// A `DynamoDb` client reporting the capacity consumed by each request to a callback.
+///
+/// Without a callback every request is passed through to the inner client untouched. Once
+/// one is set with [`on_consumed_capacity`](#method.on_consumed_capacity), requests of the
+/// operations listed in [`Operation`](enum.Operation.html) are sent with
+/// `ReturnConsumedCapacity` set to `TOTAL`, unless they already ask for `INDEXES`, and the
+/// callback is invoked once per table listed in the response. Batch and transaction
+/// responses list every table they touched. Responses are returned unchanged.
+#[derive(Clone)]
+pub struct CapacityTrackingClient<C = DynamoDbClient> {
+    inner: C,
+    callback: Option<Callback>,
+}
+
+impl<C: DynamoDb> CapacityTrackingClient<C> {
+    /// Wraps `inner` without tracking consumed capacity.
+    pub fn new(inner: C) -> Self {
+        CapacityTrackingClient {
+            inner,
+            callback: None,
+        }
+    }
+
+    /// Reports the capacity units consumed on each table to `callback`, along with the
+    /// operation that consumed them.
+    pub fn on_consumed_capacity<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, f64, Operation) + Send + Sync + 'static,
+    {
+        self.callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    fn track<I, O, E, F>(&self, operation: Operation, mut input: I, call: F) -> RusotoFuture<O, E>
+    where
+        I: ReturnsConsumedCapacity,
+        O: ReportsConsumedCapacity + Send + 'static,
+        E: Send + 'static,
+        F: FnOnce(&C, I) -> RusotoFuture<O, E>,
+    {
+        let callback = match self.callback {
+            Some(ref callback) => callback.clone(),
+            None => return call(&self.inner, input),
+        };
+
+        let requested = input.return_consumed_capacity();
+        if requested.as_ref().map(String::as_str) != Some("INDEXES") {
+            *requested = Some("TOTAL".to_owned());
+        }
+
+        RusotoFuture::from_future(call(&self.inner, input).map(move |output| {
+            for capacity in output.consumed_capacity() {
+                if let (Some(table), Some(units)) = (&capacity.table_name, capacity.capacity_units)
+                {
+                    callback(table, units, operation);
+                }
+            }
+            output
+        }))
+    }
+}
+
+impl<C: fmt::Debug> fmt::Debug for CapacityTrackingClient<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CapacityTrackingClient")
+            .field("inner", &self.inner)
+            .field("tracking", &self.callback.is_some())
+            .finish()
+    }
+}
+
+/// Requests which can ask for the capacity they consume.
+trait ReturnsConsumedCapacity {
+    fn return_consumed_capacity(&mut self) -> &mut Option<String>;
+}
+
+/// Responses which can report the capacity consumed by their request.
+trait ReportsConsumedCapacity {
+    fn consumed_capacity(&self) -> Vec<&ConsumedCapacity>;
+}
+
+macro_rules! consumed_capacity {
+    ($($input:ident => $output:ident: $capacity:ident),*) => {
+        $(
+            impl ReturnsConsumedCapacity for $input {
+                fn return_consumed_capacity(&mut self) -> &mut Option<String> {
+                    &mut self.return_consumed_capacity
+                }
+            }
+
+            impl ReportsConsumedCapacity for $output {
+                fn consumed_capacity(&self) -> Vec<&ConsumedCapacity> {
+                    consumed_capacity!(@$capacity self.consumed_capacity)
+                }
+            }
+        )*
+    };
+    (@single $capacity:expr) => {
+        $capacity.iter().collect()
+    };
+    (@per_table $capacity:expr) => {
+        $capacity.iter().flat_map(|capacities| capacities.iter()).collect()
+    };
+}
+
+consumed_capacity!(
+    BatchGetItemInput => BatchGetItemOutput: per_table,
+    BatchWriteItemInput => BatchWriteItemOutput: per_table,
+    DeleteItemInput => DeleteItemOutput: single,
+    GetItemInput => GetItemOutput: single,
+    PutItemInput => PutItemOutput: single,
+    QueryInput => QueryOutput: single,
+    ScanInput => ScanOutput: single,
+    TransactGetItemsInput => TransactGetItemsOutput: per_table,
+    TransactWriteItemsInput => TransactWriteItemsOutput: per_table,
+    UpdateItemInput => UpdateItemOutput: single
+);
+
+impl<C: DynamoDb> DynamoDb for CapacityTrackingClient<C> {
+    fn batch_get_item(
+        &self,
+        input: BatchGetItemInput,
+    ) -> RusotoFuture<BatchGetItemOutput, BatchGetItemError> {
+        self.track(Operation::BatchGetItem, input, |client, input| {
+            client.batch_get_item(input)
+        })
+    }
+
+    fn batch_write_item(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> RusotoFuture<BatchWriteItemOutput, BatchWriteItemError> {
+        self.track(Operation::BatchWriteItem, input, |client, input| {
+            client.batch_write_item(input)
+        })
+    }
+
+    fn create_backup(
+        &self,
+        input: CreateBackupInput,
+    ) -> RusotoFuture<CreateBackupOutput, CreateBackupError> {
+        self.inner.create_backup(input)
+    }
+
+    fn create_global_table(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> RusotoFuture<CreateGlobalTableOutput, CreateGlobalTableError> {
+        self.inner.create_global_table(input)
+    }
+
+    fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> RusotoFuture<CreateTableOutput, CreateTableError> {
+        self.inner.create_table(input)
+    }
+
+    fn delete_backup(
+        &self,
+        input: DeleteBackupInput,
+    ) -> RusotoFuture<DeleteBackupOutput, DeleteBackupError> {
+        self.inner.delete_backup(input)
+    }
+
+    fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> RusotoFuture<DeleteItemOutput, DeleteItemError> {
+        self.track(Operation::DeleteItem, input, |client, input| {
+            client.delete_item(input)
+        })
+    }
+
+    fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> RusotoFuture<DeleteTableOutput, DeleteTableError> {
+        self.inner.delete_table(input)
+    }
+
+    fn describe_backup(
+        &self,
+        input: DescribeBackupInput,
+    ) -> RusotoFuture<DescribeBackupOutput, DescribeBackupError> {
+        self.inner.describe_backup(input)
+    }
+
+    fn describe_continuous_backups(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> RusotoFuture<DescribeContinuousBackupsOutput, DescribeContinuousBackupsError> {
+        self.inner.describe_continuous_backups(input)
+    }
+
+    fn describe_endpoints(
+        &self,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        self.inner.describe_endpoints()
+    }
+
+    fn describe_global_table(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> RusotoFuture<DescribeGlobalTableOutput, DescribeGlobalTableError> {
+        self.inner.describe_global_table(input)
+    }
+
+    fn describe_global_table_settings(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> RusotoFuture<DescribeGlobalTableSettingsOutput, DescribeGlobalTableSettingsError> {
+        self.inner.describe_global_table_settings(input)
+    }
+
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        self.inner.describe_limits()
+    }
+
+    fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> RusotoFuture<DescribeTableOutput, DescribeTableError> {
+        self.inner.describe_table(input)
+    }
+
+    fn describe_time_to_live(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> RusotoFuture<DescribeTimeToLiveOutput, DescribeTimeToLiveError> {
+        self.inner.describe_time_to_live(input)
+    }
+
+    fn get_item(&self, input: GetItemInput) -> RusotoFuture<GetItemOutput, GetItemError> {
+        self.track(Operation::GetItem, input, |client, input| {
+            client.get_item(input)
+        })
+    }
+
+    fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> RusotoFuture<ListBackupsOutput, ListBackupsError> {
+        self.inner.list_backups(input)
+    }
+
+    fn list_global_tables(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> RusotoFuture<ListGlobalTablesOutput, ListGlobalTablesError> {
+        self.inner.list_global_tables(input)
+    }
+
+    fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> RusotoFuture<ListTablesOutput, ListTablesError> {
+        self.inner.list_tables(input)
+    }
+
+    fn list_tags_of_resource(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> RusotoFuture<ListTagsOfResourceOutput, ListTagsOfResourceError> {
+        self.inner.list_tags_of_resource(input)
+    }
+
+    fn put_item(&self, input: PutItemInput) -> RusotoFuture<PutItemOutput, PutItemError> {
+        self.track(Operation::PutItem, input, |client, input| {
+            client.put_item(input)
+        })
+    }
+
+    fn query(&self, input: QueryInput) -> RusotoFuture<QueryOutput, QueryError> {
+        self.track(Operation::Query, input, |client, input| client.query(input))
+    }
+
+    fn restore_table_from_backup(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> RusotoFuture<RestoreTableFromBackupOutput, RestoreTableFromBackupError> {
+        self.inner.restore_table_from_backup(input)
+    }
+
+    fn restore_table_to_point_in_time(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> RusotoFuture<RestoreTableToPointInTimeOutput, RestoreTableToPointInTimeError> {
+        self.inner.restore_table_to_point_in_time(input)
+    }
+
+    fn scan(&self, input: ScanInput) -> RusotoFuture<ScanOutput, ScanError> {
+        self.track(Operation::Scan, input, |client, input| client.scan(input))
+    }
+
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        self.inner.tag_resource(input)
+    }
+
+    fn transact_get_items(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> RusotoFuture<TransactGetItemsOutput, TransactGetItemsError> {
+        self.track(Operation::TransactGetItems, input, |client, input| {
+            client.transact_get_items(input)
+        })
+    }
+
+    fn transact_write_items(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> RusotoFuture<TransactWriteItemsOutput, TransactWriteItemsError> {
+        self.track(Operation::TransactWriteItems, input, |client, input| {
+            client.transact_write_items(input)
+        })
+    }
+
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        self.inner.untag_resource(input)
+    }
+
+    fn update_continuous_backups(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> RusotoFuture<UpdateContinuousBackupsOutput, UpdateContinuousBackupsError> {
+        self.inner.update_continuous_backups(input)
+    }
+
+    fn update_global_table(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> RusotoFuture<UpdateGlobalTableOutput, UpdateGlobalTableError> {
+        self.inner.update_global_table(input)
+    }
+
+    fn update_global_table_settings(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> RusotoFuture<UpdateGlobalTableSettingsOutput, UpdateGlobalTableSettingsError> {
+        self.inner.update_global_table_settings(input)
+    }
+
+    fn update_item(
+        &self,
+        input: UpdateItemInput,
+    ) -> RusotoFuture<UpdateItemOutput, UpdateItemError> {
+        self.track(Operation::UpdateItem, input, |client, input| {
+            client.update_item(input)
+        })
+    }
+
+    fn update_table(
+        &self,
+        input: UpdateTableInput,
+    ) -> RusotoFuture<UpdateTableOutput, UpdateTableError> {
+        self.inner.update_table(input)
+    }
+
+    fn update_time_to_live(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> RusotoFuture<UpdateTimeToLiveOutput, UpdateTimeToLiveError> {
+        self.inner.update_time_to_live(input)
+    }
+}
@@ -1,4 +1,21 @@
-use crate::generated::AttributeValue;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::{Future, Stream};
+
+use crate::capacity::{CapacityTrackingClient, Operation};
+use crate::generated::{
+    AttributeValue, BatchGetItemInput, CreateTableInput, DeleteItemInput, DeleteItemOutput,
+    DynamoDb, DynamoDbClient, GetItemInput, GetItemOutput, KeySchemaElement, KeysAndAttributes,
+    ListTablesError, ListTablesInput, ListTablesOutput, MockDynamoDb, PutItemInput, ScanInput,
+};
+use crate::item::{from_item, to_item};
+use crate::table::{Expression, Key, KeySchema, Table, TableError};
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::validation::Validate;
+use rusoto_core::{Client, DispatchSignedRequest, Region, RusotoError};
+use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
 
 #[test]
 fn attribute_value_default_is_empty() {
@@ -46,3 +63,744 @@ fn attribute_value_with_binary_set() {
     let serialized = serde_json::to_string(&all_default).unwrap();
     assert_eq!(&serialized, r#"{"BS":["Zm9v","YmFy","YmF6"]}"#);
 }
+
+#[test]
+fn validation_reports_range_length_and_pattern_violations() {
+    let input = ScanInput {
+        table_name: "!!".to_owned(),
+        limit: Some(0),
+        ..Default::default()
+    };
+
+    let errors = input.validate().unwrap_err();
+    let messages: Vec<String> = errors.errors().iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "limit: value 0 is less than the minimum of 1",
+            "table_name: length 2 is less than the minimum of 3",
+            "table_name: value does not match the pattern [a-zA-Z0-9_.-]+",
+        ]
+    );
+}
+
+#[test]
+fn validation_reports_nested_field_paths() {
+    let input = CreateTableInput {
+        table_name: "table".to_owned(),
+        key_schema: vec![
+            KeySchemaElement {
+                attribute_name: "id".to_owned(),
+                key_type: "HASH".to_owned(),
+            },
+            KeySchemaElement {
+                attribute_name: "".to_owned(),
+                key_type: "RANGE".to_owned(),
+            },
+        ],
+        ..Default::default()
+    };
+
+    let errors = input.validate().unwrap_err();
+    let fields: Vec<&str> = errors.errors().iter().map(|e| e.field.as_str()).collect();
+    assert_eq!(fields, vec!["key_schema[1].attribute_name"]);
+}
+
+#[test]
+fn valid_input_passes_validation() {
+    let input = ScanInput {
+        table_name: "my-table.v2".to_owned(),
+        limit: Some(10),
+        ..Default::default()
+    };
+    assert_eq!(input.validate(), Ok(()));
+}
+
+#[test]
+fn validating_client_rejects_invalid_requests_before_sending() {
+    let client = Client::new_with(
+        MockCredentialsProvider,
+        MockRequestDispatcher::with_status(200).with_request_checker(|_| {
+            panic!("invalid request was sent");
+        }),
+    )
+    .validate_requests(true);
+    let client = DynamoDbClient::new_with_client(client, Region::UsEast1);
+
+    let input = ScanInput {
+        table_name: "t".to_owned(),
+        ..Default::default()
+    };
+    match client.scan(input).sync() {
+        Err(RusotoError::Validation { message, fields }) => {
+            assert_eq!(
+                message,
+                "table_name: length 1 is less than the minimum of 3"
+            );
+            assert_eq!(fields.len(), 1);
+            assert_eq!(fields[0].field, "table_name");
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn responses_without_a_body_are_empty_outputs() {
+    let client = DynamoDbClient::new_with(
+        MockRequestDispatcher::with_status(204),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+
+    let mut key = HashMap::new();
+    key.insert(
+        "id".to_owned(),
+        AttributeValue {
+            s: Some("42".to_owned()),
+            ..Default::default()
+        },
+    );
+    let input = DeleteItemInput {
+        table_name: "users".to_owned(),
+        key,
+        ..Default::default()
+    };
+    assert_eq!(
+        client.delete_item(input).sync(),
+        Ok(DeleteItemOutput::default())
+    );
+}
+
+#[test]
+fn ranges_and_patterns_are_not_validated_by_default() {
+    let client = DynamoDbClient::new_with(
+        MockRequestDispatcher::with_status(200).with_body("{}"),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+
+    let input = ScanInput {
+        table_name: "!!!".to_owned(),
+        limit: Some(0),
+        ..Default::default()
+    };
+    assert!(client.scan(input).sync().is_ok());
+}
+
+#[test]
+fn lengths_are_validated_by_default() {
+    let client = DynamoDbClient::new_with(
+        MockRequestDispatcher::with_status(200).with_request_checker(|_| {
+            panic!("oversized request was sent");
+        }),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+
+    let input = ScanInput {
+        table_name: "t".to_owned(),
+        limit: Some(0),
+        ..Default::default()
+    };
+    match client.scan(input).sync() {
+        Err(RusotoError::Validation { message, fields }) => {
+            assert_eq!(
+                message,
+                "table_name: length 1 is less than the minimum of 3"
+            );
+            assert_eq!(fields.len(), 1);
+            assert_eq!(fields[0].field, "table_name");
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+const BATCH_GET_ITEM_RESPONSE: &str = r#"{
+    "Responses": {"orders": [], "customers": []},
+    "ConsumedCapacity": [
+        {"TableName": "orders", "CapacityUnits": 1.5},
+        {"TableName": "customers", "CapacityUnits": 3.0}
+    ]
+}"#;
+
+fn batch_get_item_input() -> BatchGetItemInput {
+    let mut key = HashMap::new();
+    key.insert(
+        "id".to_owned(),
+        AttributeValue {
+            s: Some("1".to_owned()),
+            ..Default::default()
+        },
+    );
+    let keys = KeysAndAttributes {
+        keys: vec![key],
+        ..Default::default()
+    };
+    let mut request_items = HashMap::new();
+    request_items.insert("orders".to_owned(), keys.clone());
+    request_items.insert("customers".to_owned(), keys);
+    BatchGetItemInput {
+        request_items,
+        ..Default::default()
+    }
+}
+
+fn request_body(payload: &Option<SignedRequestPayload>) -> serde_json::Value {
+    match *payload {
+        Some(SignedRequestPayload::Buffer(ref buffer)) => serde_json::from_slice(buffer).unwrap(),
+        _ => panic!("request without a body"),
+    }
+}
+
+#[test]
+fn capacity_tracking_reports_each_table_of_a_batch() {
+    let dispatcher = MockRequestDispatcher::with_status(200)
+        .with_body(BATCH_GET_ITEM_RESPONSE)
+        .with_request_checker(|request| {
+            assert_eq!(
+                request_body(&request.payload)["ReturnConsumedCapacity"],
+                "TOTAL"
+            );
+        });
+    let consumed = Arc::new(Mutex::new(Vec::new()));
+    let reported = consumed.clone();
+    let client = CapacityTrackingClient::new(DynamoDbClient::new_with(
+        dispatcher,
+        MockCredentialsProvider,
+        Region::UsEast1,
+    ))
+    .on_consumed_capacity(move |table, units, operation| {
+        reported
+            .lock()
+            .unwrap()
+            .push((table.to_owned(), units, operation));
+    });
+
+    let output = client
+        .batch_get_item(batch_get_item_input())
+        .sync()
+        .unwrap();
+    assert_eq!(
+        output.consumed_capacity.map(|capacity| capacity.len()),
+        Some(2)
+    );
+
+    let consumed = consumed.lock().unwrap();
+    assert_eq!(
+        *consumed,
+        vec![
+            ("orders".to_owned(), 1.5, Operation::BatchGetItem),
+            ("customers".to_owned(), 3.0, Operation::BatchGetItem),
+        ]
+    );
+    let total: f64 = consumed.iter().map(|&(_, units, _)| units).sum();
+    assert_eq!(total, 4.5);
+}
+
+#[test]
+fn capacity_tracking_keeps_requested_index_breakdown() {
+    let dispatcher = MockRequestDispatcher::with_status(200)
+        .with_body(BATCH_GET_ITEM_RESPONSE)
+        .with_request_checker(|request| {
+            assert_eq!(
+                request_body(&request.payload)["ReturnConsumedCapacity"],
+                "INDEXES"
+            );
+        });
+    let client = CapacityTrackingClient::new(DynamoDbClient::new_with(
+        dispatcher,
+        MockCredentialsProvider,
+        Region::UsEast1,
+    ))
+    .on_consumed_capacity(|_, _, _| {});
+
+    let input = BatchGetItemInput {
+        return_consumed_capacity: Some("INDEXES".to_owned()),
+        ..batch_get_item_input()
+    };
+    assert!(client.batch_get_item(input).sync().is_ok());
+}
+
+#[test]
+fn capacity_tracking_without_callback_leaves_requests_untouched() {
+    let dispatcher = MockRequestDispatcher::with_status(200)
+        .with_body(BATCH_GET_ITEM_RESPONSE)
+        .with_request_checker(|request| {
+            assert!(request_body(&request.payload)
+                .get("ReturnConsumedCapacity")
+                .is_none());
+        });
+    let client = CapacityTrackingClient::new(DynamoDbClient::new_with(
+        dispatcher,
+        MockCredentialsProvider,
+        Region::UsEast1,
+    ));
+
+    assert!(client.batch_get_item(batch_get_item_input()).sync().is_ok());
+}
+
+#[test]
+fn validation_errors_of_the_service_list_failed_members() {
+    let client = DynamoDbClient::new_with(
+        MockRequestDispatcher::with_status(400).with_body(
+            r#"{"__type":"com.amazon.coral.validate#ValidationException",
+            "message":"1 validation error detected: Value '0' at 'limit' failed to satisfy constraint: Member must have value greater than or equal to 1"}"#,
+        ),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+
+    let input = ScanInput {
+        table_name: "table".to_owned(),
+        ..Default::default()
+    };
+    match client.scan(input).sync() {
+        Err(RusotoError::Validation { message, fields }) => {
+            assert!(message.starts_with("1 validation error detected"));
+            assert_eq!(fields.len(), 1);
+            assert_eq!(fields[0].field, "limit");
+            assert_eq!(
+                fields[0].message,
+                "Member must have value greater than or equal to 1"
+            );
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+fn binary_item() -> HashMap<String, AttributeValue> {
+    let mut item = HashMap::new();
+    item.insert(
+        "id".to_owned(),
+        AttributeValue {
+            s: Some("1".to_owned()),
+            ..Default::default()
+        },
+    );
+    item.insert(
+        "thumbnail".to_owned(),
+        AttributeValue {
+            b: Some(vec![0x89, 0x50, 0x4e, 0x47].into()),
+            ..Default::default()
+        },
+    );
+    item
+}
+
+#[test]
+fn requests_round_trip_through_json_as_sent() {
+    let input = PutItemInput {
+        table_name: "images".to_owned(),
+        item: binary_item(),
+        condition_expression: Some("attribute_not_exists(id)".to_owned()),
+        ..Default::default()
+    };
+    let sent = Arc::new(Mutex::new(None));
+    let body = sent.clone();
+    let client = DynamoDbClient::new_with(
+        MockRequestDispatcher::with_status(200)
+            .with_body("{}")
+            .with_request_checker(move |request| {
+                *body.lock().unwrap() = Some(request_body(&request.payload));
+            }),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    client.put_item(input.clone()).sync().unwrap();
+
+    let sent = sent.lock().unwrap().take().unwrap();
+    assert_eq!(serde_json::to_value(&input).unwrap(), sent);
+    let reloaded: PutItemInput = serde_json::from_value(sent).unwrap();
+    assert_eq!(reloaded, input);
+}
+
+#[test]
+fn responses_round_trip_through_json() {
+    let client = DynamoDbClient::new_with(
+        MockRequestDispatcher::with_status(200)
+            .with_body(r#"{"Item": {"id": {"S": "1"}, "thumbnail": {"B": "iVBORw=="}}}"#),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let output = client
+        .get_item(GetItemInput {
+            table_name: "images".to_owned(),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap();
+    assert_eq!(output.item, Some(binary_item()));
+
+    let stored = serde_json::to_string(&output).unwrap();
+    let reloaded: GetItemOutput = serde_json::from_str(&stored).unwrap();
+    assert_eq!(reloaded, output);
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Order {
+    customer: String,
+    id: u64,
+    quantity: u32,
+    notes: Option<String>,
+}
+
+fn order(id: u64, quantity: u32) -> Order {
+    Order {
+        customer: "alice".to_owned(),
+        id,
+        quantity,
+        notes: None,
+    }
+}
+
+fn order_json(id: u64, quantity: u32) -> String {
+    format!(
+        r#"{{"customer": {{"S": "alice"}}, "id": {{"N": "{}"}}, "quantity": {{"N": "{}"}}, "notes": {{"NULL": true}}}}"#,
+        id, quantity
+    )
+}
+
+/// Answers each request with the next of a list of mock dispatchers, recording the
+/// operation and body of the requests.
+struct ScriptedDispatcher {
+    responses: Mutex<VecDeque<MockRequestDispatcher>>,
+    requests: Arc<Mutex<Vec<(String, serde_json::Value)>>>,
+}
+
+impl DispatchSignedRequest for ScriptedDispatcher {
+    type Future = <MockRequestDispatcher as DispatchSignedRequest>::Future;
+
+    fn dispatch(&self, request: SignedRequest, timeout: Option<Duration>) -> Self::Future {
+        let target = String::from_utf8(request.headers["x-amz-target"][0].clone()).unwrap();
+        let operation = target.trim_start_matches("DynamoDB_20120810.").to_owned();
+        self.requests
+            .lock()
+            .unwrap()
+            .push((operation, request_body(&request.payload)));
+        let response = self.responses.lock().unwrap().pop_front();
+        response
+            .expect("more requests than responses")
+            .dispatch(request, timeout)
+    }
+}
+
+type Requests = Arc<Mutex<Vec<(String, serde_json::Value)>>>;
+
+fn orders_table(responses: Vec<(u16, String)>) -> (Table<Order>, Requests) {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let dispatcher = ScriptedDispatcher {
+        responses: Mutex::new(
+            responses
+                .into_iter()
+                .map(|(status, body)| MockRequestDispatcher::with_status(status).with_body(&body))
+                .collect(),
+        ),
+        requests: requests.clone(),
+    };
+    let client = DynamoDbClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+    let table = Table::new(
+        client,
+        "orders",
+        KeySchema::new("customer").with_sort_key("id"),
+    );
+    (table, requests)
+}
+
+#[test]
+fn items_convert_to_and_from_attribute_values() {
+    let item = to_item(&order(42, 3)).unwrap();
+    assert_eq!(item["customer"].s, Some("alice".to_owned()));
+    assert_eq!(item["id"].n, Some("42".to_owned()));
+    assert_eq!(item["notes"].null, Some(true));
+    assert_eq!(from_item::<Order>(item).unwrap(), order(42, 3));
+
+    let sets: HashMap<String, AttributeValue> =
+        serde_json::from_str(r#"{"tags": {"SS": ["a", "b"]}, "sizes": {"NS": ["1", "2.5"]}}"#)
+            .unwrap();
+    let value: serde_json::Value = from_item(sets).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({"tags": ["a", "b"], "sizes": [1, 2.5]})
+    );
+    assert!(to_item(&3).is_err());
+}
+
+#[test]
+fn table_get_reads_typed_items() {
+    let (table, requests) = orders_table(vec![
+        (200, format!(r#"{{"Item": {}}}"#, order_json(42, 3))),
+        (200, "{}".to_owned()),
+    ]);
+    let table = table
+        .consistent_read(true)
+        .projection(vec!["customer", "id", "quantity"]);
+
+    let found = table.get(&Key::new("alice").with_sort(42)).sync().unwrap();
+    assert_eq!(found, Some(order(42, 3)));
+    let missing = table.get(&Key::new("alice").with_sort(43)).sync().unwrap();
+    assert_eq!(missing, None);
+
+    let requests = requests.lock().unwrap();
+    let (ref operation, ref body) = requests[0];
+    assert_eq!(operation, "GetItem");
+    assert_eq!(
+        *body,
+        serde_json::json!({
+            "TableName": "orders",
+            "Key": {"customer": {"S": "alice"}, "id": {"N": "42"}},
+            "ConsistentRead": true,
+            "ProjectionExpression": "#projection0, #projection1, #projection2",
+            "ExpressionAttributeNames": {
+                "#projection0": "customer",
+                "#projection1": "id",
+                "#projection2": "quantity"
+            }
+        })
+    );
+}
+
+#[test]
+fn table_put_writes_items_with_their_condition() {
+    let (table, requests) = orders_table(vec![(200, "{}".to_owned())]);
+
+    table
+        .put(
+            &order(42, 3),
+            Some(Expression::new("attribute_not_exists(#id)").name("#id", "id")),
+        )
+        .sync()
+        .unwrap();
+
+    let requests = requests.lock().unwrap();
+    let (ref operation, ref body) = requests[0];
+    assert_eq!(operation, "PutItem");
+    assert_eq!(body["Item"]["quantity"], serde_json::json!({"N": "3"}));
+    assert_eq!(body["ConditionExpression"], "attribute_not_exists(#id)");
+    assert_eq!(
+        body["ExpressionAttributeNames"],
+        serde_json::json!({"#id": "id"})
+    );
+    assert!(body.get("ExpressionAttributeValues").is_none());
+}
+
+#[test]
+fn table_put_condition_failure_carries_the_current_item() {
+    let (table, requests) = orders_table(vec![
+        (
+            400,
+            r#"{"__type": "ConditionalCheckFailedException", "message": "The conditional request failed"}"#
+                .to_owned(),
+        ),
+        (200, format!(r#"{{"Item": {}}}"#, order_json(42, 7))),
+    ]);
+
+    let result = table
+        .put(
+            &order(42, 3),
+            Some(Expression::new("quantity < :max").value(":max", &5)),
+        )
+        .sync();
+    match result {
+        Err(RusotoError::Service(TableError::ConditionFailed { message, item })) => {
+            assert_eq!(message, "The conditional request failed");
+            assert_eq!(item, Some(order(42, 7)));
+        }
+        other => panic!("expected a failed condition, got {:?}", other),
+    }
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        requests[0].1["ExpressionAttributeValues"],
+        serde_json::json!({":max": {"N": "5"}})
+    );
+    let (ref operation, ref body) = requests[1];
+    assert_eq!(operation, "GetItem");
+    assert_eq!(
+        body["Key"],
+        serde_json::json!({"customer": {"S": "alice"}, "id": {"N": "42"}})
+    );
+    assert_eq!(body["ConsistentRead"], true);
+}
+
+#[test]
+fn table_update_returns_the_updated_item() {
+    let (table, requests) = orders_table(vec![(
+        200,
+        format!(r#"{{"Attributes": {}}}"#, order_json(42, 4)),
+    )]);
+
+    let updated = table
+        .update(
+            &Key::new("alice").with_sort(42),
+            Expression::new("SET quantity = quantity + :more").value(":more", &1),
+        )
+        .sync()
+        .unwrap();
+    assert_eq!(updated, order(42, 4));
+
+    let requests = requests.lock().unwrap();
+    let (ref operation, ref body) = requests[0];
+    assert_eq!(operation, "UpdateItem");
+    assert_eq!(body["UpdateExpression"], "SET quantity = quantity + :more");
+    assert_eq!(
+        body["ExpressionAttributeValues"],
+        serde_json::json!({":more": {"N": "1"}})
+    );
+    assert_eq!(body["ReturnValues"], "ALL_NEW");
+}
+
+#[test]
+fn table_delete_returns_the_deleted_item() {
+    let (table, requests) = orders_table(vec![
+        (200, format!(r#"{{"Attributes": {}}}"#, order_json(42, 3))),
+        (
+            400,
+            r#"{"__type": "ConditionalCheckFailedException", "message": "The conditional request failed"}"#
+                .to_owned(),
+        ),
+        (200, "{}".to_owned()),
+    ]);
+
+    let deleted = table
+        .delete(&Key::new("alice").with_sort(42), None)
+        .sync()
+        .unwrap();
+    assert_eq!(deleted, Some(order(42, 3)));
+
+    let result = table
+        .delete(
+            &Key::new("alice").with_sort(43),
+            Some(Expression::new("attribute_exists(id)")),
+        )
+        .sync();
+    match result {
+        Err(RusotoError::Service(TableError::ConditionFailed { item: None, .. })) => {}
+        other => panic!("expected a failed condition, got {:?}", other),
+    }
+
+    let requests = requests.lock().unwrap();
+    let operations: Vec<&str> = requests.iter().map(|request| &request.0[..]).collect();
+    assert_eq!(operations, vec!["DeleteItem", "DeleteItem", "GetItem"]);
+    assert_eq!(requests[0].1["ReturnValues"], "ALL_OLD");
+    assert_eq!(requests[1].1["ConditionExpression"], "attribute_exists(id)");
+}
+
+#[test]
+fn table_query_follows_every_page() {
+    let last_key = r#"{"customer": {"S": "alice"}, "id": {"N": "2"}}"#;
+    let (table, requests) = orders_table(vec![
+        (
+            200,
+            format!(
+                r#"{{"Items": [{}, {}], "LastEvaluatedKey": {}}}"#,
+                order_json(1, 1),
+                order_json(2, 2),
+                last_key
+            ),
+        ),
+        (200, format!(r#"{{"Items": [{}]}}"#, order_json(3, 3))),
+    ]);
+    let table = table.projection(vec!["customer", "id", "quantity"]);
+
+    let orders = table
+        .query(
+            Expression::new("#customer = :customer")
+                .name("#customer", "customer")
+                .value(":customer", "alice"),
+        )
+        .collect()
+        .wait()
+        .unwrap();
+    assert_eq!(orders, vec![order(1, 1), order(2, 2), order(3, 3)]);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    let (ref operation, ref first) = requests[0];
+    assert_eq!(operation, "Query");
+    assert_eq!(first["KeyConditionExpression"], "#customer = :customer");
+    assert_eq!(
+        first["ExpressionAttributeNames"],
+        serde_json::json!({
+            "#customer": "customer",
+            "#projection0": "customer",
+            "#projection1": "id",
+            "#projection2": "quantity"
+        })
+    );
+    assert!(first.get("ExclusiveStartKey").is_none());
+    assert_eq!(
+        requests[1].1["ExclusiveStartKey"],
+        serde_json::from_str::<serde_json::Value>(last_key).unwrap()
+    );
+}
+
+fn table_count<C: DynamoDb + ?Sized>(dynamo: &C) -> usize {
+    dynamo
+        .list_tables(ListTablesInput::default())
+        .sync()
+        .unwrap()
+        .table_names
+        .map_or(0, |names| names.len())
+}
+
+#[test]
+fn clients_are_shared_by_reference_box_and_arc() {
+    let mock = MockDynamoDb::new().with_list_tables(|input| {
+        assert_eq!(input.limit, None);
+        Ok(ListTablesOutput {
+            table_names: Some(vec!["orders".to_owned()]),
+            ..Default::default()
+        })
+    });
+    assert_eq!(table_count(&&mock), 1);
+
+    let boxed: Box<dyn DynamoDb + Send + Sync> = Box::new(mock);
+    assert_eq!(table_count(&boxed), 1);
+    assert_eq!(table_count(&*boxed), 1);
+
+    let shared: Arc<dyn DynamoDb + Send + Sync> = Arc::from(boxed);
+    let handle = Arc::clone(&shared);
+    let counted = std::thread::spawn(move || table_count(&handle))
+        .join()
+        .unwrap();
+    assert_eq!(counted, 1);
+    assert_eq!(table_count(&shared), 1);
+
+    let client: Arc<dyn DynamoDb> = Arc::new(DynamoDbClient::new(Region::UsEast1));
+    let _: &dyn DynamoDb = &client;
+}
+
+#[test]
+fn mocks_answer_with_what_their_handlers_return() {
+    let mock = MockDynamoDb::new()
+        .with_list_tables(|_| {
+            Err(RusotoError::Service(ListTablesError::InternalServerError(
+                "unavailable".to_owned(),
+            )))
+        })
+        .with_get_item(|input| {
+            assert_eq!(input.table_name, "orders");
+            Ok(GetItemOutput::default())
+        });
+
+    match mock.list_tables(ListTablesInput::default()).sync() {
+        Err(RusotoError::Service(ListTablesError::InternalServerError(ref msg))) => {
+            assert_eq!(msg, "unavailable")
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+    let input = GetItemInput {
+        table_name: "orders".to_owned(),
+        ..Default::default()
+    };
+    assert_eq!(
+        mock.get_item(input).sync().unwrap(),
+        GetItemOutput::default()
+    );
+}
+
+#[test]
+#[should_panic(expected = "MockDynamoDb has no handler for scan")]
+fn mocks_panic_for_operations_without_a_handler() {
+    let _ = MockDynamoDb::new().scan(ScanInput::default());
+}
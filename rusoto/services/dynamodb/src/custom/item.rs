@@ -0,0 +1,197 @@
+//! Conversion of serde types to and from DynamoDB attribute values.
+//!
+//! Values are converted through their JSON representation: `null` becomes `NULL`, booleans
+//! `BOOL`, numbers `N`, strings `S`, arrays `L` and objects `M`. Attribute values read
+//! from DynamoDB convert back the same way, with string and number sets read as arrays,
+//! and binary values, sets of them included, read as arrays of bytes.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate serde_derive;
+//! use rusoto_dynamodb::item::{from_item, to_item};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Order {
+//!     id: String,
+//!     quantity: u32,
+//!     notes: Option<String>,
+//! }
+//!
+//! # fn main() {
+//! let order = Order { id: "1".to_owned(), quantity: 3, notes: None };
+//! let item = to_item(&order).unwrap();
+//! assert_eq!(item["quantity"].n, Some("3".to_owned()));
+//! assert_eq!(from_item::<Order>(item).unwrap(), order);
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+
+use crate::generated::AttributeValue;
+
+/// An error converting a value to or from attribute values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemError {
+    message: String,
+}
+
+impl ItemError {
+    fn new<S: Into<String>>(message: S) -> ItemError {
+        ItemError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ItemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ItemError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<serde_json::Error> for ItemError {
+    fn from(err: serde_json::Error) -> ItemError {
+        ItemError::new(err.to_string())
+    }
+}
+
+/// The attribute value of `value`.
+pub fn to_attribute_value<V>(value: &V) -> Result<AttributeValue, ItemError>
+where
+    V: Serialize + ?Sized,
+{
+    Ok(to_attribute(serde_json::to_value(value)?))
+}
+
+/// The value of type `V` read from `value`.
+pub fn from_attribute_value<V: DeserializeOwned>(value: AttributeValue) -> Result<V, ItemError> {
+    Ok(serde_json::from_value(from_attribute(value)?)?)
+}
+
+/// The item of `value`, whose type must serialize to a map, such as a struct.
+pub fn to_item<T>(value: &T) -> Result<HashMap<String, AttributeValue>, ItemError>
+where
+    T: Serialize + ?Sized,
+{
+    match serde_json::to_value(value)? {
+        Value::Object(fields) => Ok(fields
+            .into_iter()
+            .map(|(name, value)| (name, to_attribute(value)))
+            .collect()),
+        _ => Err(ItemError::new("items must serialize to a map")),
+    }
+}
+
+/// The value of type `T` read from `item`.
+pub fn from_item<T: DeserializeOwned>(
+    item: HashMap<String, AttributeValue>,
+) -> Result<T, ItemError> {
+    Ok(serde_json::from_value(from_attributes(item)?)?)
+}
+
+fn to_attribute(value: Value) -> AttributeValue {
+    match value {
+        Value::Null => AttributeValue {
+            null: Some(true),
+            ..Default::default()
+        },
+        Value::Bool(value) => AttributeValue {
+            bool: Some(value),
+            ..Default::default()
+        },
+        Value::Number(value) => AttributeValue {
+            n: Some(value.to_string()),
+            ..Default::default()
+        },
+        Value::String(value) => AttributeValue {
+            s: Some(value),
+            ..Default::default()
+        },
+        Value::Array(values) => AttributeValue {
+            l: Some(values.into_iter().map(to_attribute).collect()),
+            ..Default::default()
+        },
+        Value::Object(fields) => AttributeValue {
+            m: Some(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| (name, to_attribute(value)))
+                    .collect(),
+            ),
+            ..Default::default()
+        },
+    }
+}
+
+fn from_attributes(attributes: HashMap<String, AttributeValue>) -> Result<Value, ItemError> {
+    let mut fields = Map::new();
+    for (name, value) in attributes {
+        let value = from_attribute(value)?;
+        fields.insert(name, value);
+    }
+    Ok(Value::Object(fields))
+}
+
+fn from_attribute(value: AttributeValue) -> Result<Value, ItemError> {
+    let AttributeValue {
+        b,
+        bool,
+        bs,
+        l,
+        m,
+        n,
+        ns,
+        null,
+        s,
+        ss,
+    } = value;
+    if null == Some(true) {
+        Ok(Value::Null)
+    } else if let Some(value) = bool {
+        Ok(Value::Bool(value))
+    } else if let Some(value) = n {
+        from_number(&value)
+    } else if let Some(value) = s {
+        Ok(Value::String(value))
+    } else if let Some(value) = b {
+        Ok(from_bytes(&value))
+    } else if let Some(values) = l {
+        values.into_iter().map(from_attribute).collect()
+    } else if let Some(fields) = m {
+        from_attributes(fields)
+    } else if let Some(values) = ns {
+        values.iter().map(|value| from_number(value)).collect()
+    } else if let Some(values) = ss {
+        Ok(Value::Array(
+            values.into_iter().map(Value::String).collect(),
+        ))
+    } else if let Some(values) = bs {
+        Ok(Value::Array(
+            values.iter().map(|value| from_bytes(value)).collect(),
+        ))
+    } else {
+        Err(ItemError::new("attribute value without a type"))
+    }
+}
+
+fn from_number(value: &str) -> Result<Value, ItemError> {
+    serde_json::from_str::<Number>(value)
+        .map(Value::Number)
+        .map_err(|_| ItemError::new(format!("invalid number {}", value)))
+}
+
+fn from_bytes(value: &[u8]) -> Value {
+    Value::Array(value.iter().map(|byte| Value::from(*byte)).collect())
+}
@@ -1,2 +1,9 @@
+/// Accounting of the capacity consumed by requests
+pub mod capacity;
+/// Conversion of serde types to and from attribute values
+pub mod item;
+/// A typed view of a table
+pub mod table;
+
 #[cfg(test)]
 mod custom_tests;
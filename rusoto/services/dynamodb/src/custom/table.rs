@@ -0,0 +1,630 @@
+//! A typed view of a DynamoDB table.
+//!
+//! [`Table`](struct.Table.html) reads and writes the items of a table as values of a serde
+//! type, converted with the [`item`](../item/index.html) module, and keys them with the
+//! table's key schema:
+//!
+//! ```rust,no_run
+//! # #[macro_use]
+//! # extern crate serde_derive;
+//! use futures::{Future, Stream};
+//! use rusoto_core::Region;
+//! use rusoto_dynamodb::table::{Expression, Key, KeySchema, Table};
+//! use rusoto_dynamodb::DynamoDbClient;
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! struct Order {
+//!     customer: String,
+//!     id: u64,
+//!     quantity: u32,
+//! }
+//!
+//! # fn main() {
+//! let orders: Table<Order> = Table::new(
+//!     DynamoDbClient::new(Region::UsEast1),
+//!     "orders",
+//!     KeySchema::new("customer").with_sort_key("id"),
+//! )
+//! .consistent_read(true);
+//!
+//! let order = orders.get(&Key::new("alice").with_sort(42)).sync().unwrap();
+//! orders
+//!     .update(
+//!         &Key::new("alice").with_sort(42),
+//!         Expression::new("SET quantity = quantity + :more").value(":more", &1),
+//!     )
+//!     .sync()
+//!     .unwrap();
+//! let all = orders
+//!     .query(Expression::new("customer = :customer").value(":customer", "alice"))
+//!     .collect()
+//!     .wait()
+//!     .unwrap();
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use futures::future::{self, Either};
+use futures::{stream, Future, Stream};
+use rusoto_core::{RusotoError, RusotoFuture};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::generated::*;
+use crate::item::{from_item, to_attribute_value, to_item, ItemError};
+
+type Attributes = HashMap<String, AttributeValue>;
+
+/// The names of the key attributes of a table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeySchema {
+    partition_key: String,
+    sort_key: Option<String>,
+}
+
+impl KeySchema {
+    /// The key schema of a table keyed by its partition key alone.
+    pub fn new<S: Into<String>>(partition_key: S) -> KeySchema {
+        KeySchema {
+            partition_key: partition_key.into(),
+            sort_key: None,
+        }
+    }
+
+    /// Adds the sort key of the table.
+    pub fn with_sort_key<S: Into<String>>(mut self, sort_key: S) -> KeySchema {
+        self.sort_key = Some(sort_key.into());
+        self
+    }
+
+    /// The key attributes of `item`.
+    fn key_of(&self, item: &Attributes) -> Attributes {
+        let mut key = HashMap::new();
+        for name in Some(&self.partition_key).into_iter().chain(&self.sort_key) {
+            if let Some(value) = item.get(name) {
+                key.insert(name.clone(), value.clone());
+            }
+        }
+        key
+    }
+}
+
+/// A string, number or binary value of a key attribute.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyValue(AttributeValue);
+
+impl From<String> for KeyValue {
+    fn from(value: String) -> KeyValue {
+        KeyValue(AttributeValue {
+            s: Some(value),
+            ..Default::default()
+        })
+    }
+}
+
+impl<'a> From<&'a str> for KeyValue {
+    fn from(value: &'a str) -> KeyValue {
+        KeyValue::from(value.to_owned())
+    }
+}
+
+impl From<Vec<u8>> for KeyValue {
+    fn from(value: Vec<u8>) -> KeyValue {
+        KeyValue(AttributeValue {
+            b: Some(value.into()),
+            ..Default::default()
+        })
+    }
+}
+
+macro_rules! number_key_values {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for KeyValue {
+                fn from(value: $number) -> KeyValue {
+                    KeyValue(AttributeValue {
+                        n: Some(value.to_string()),
+                        ..Default::default()
+                    })
+                }
+            }
+        )*
+    };
+}
+
+number_key_values!(i32, i64, u32, u64);
+
+/// The key of an item: its partition key and, for tables which have one, its sort key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Key {
+    partition: KeyValue,
+    sort: Option<KeyValue>,
+}
+
+impl Key {
+    /// The key of an item of a table keyed by its partition key alone.
+    pub fn new<V: Into<KeyValue>>(partition: V) -> Key {
+        Key {
+            partition: partition.into(),
+            sort: None,
+        }
+    }
+
+    /// Adds the sort key of the item.
+    pub fn with_sort<V: Into<KeyValue>>(mut self, sort: V) -> Key {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    fn attributes(&self, schema: &KeySchema) -> Attributes {
+        let mut key = HashMap::new();
+        key.insert(schema.partition_key.clone(), self.partition.0.clone());
+        if let (Some(name), Some(value)) = (&schema.sort_key, &self.sort) {
+            key.insert(name.clone(), value.0.clone());
+        }
+        key
+    }
+}
+
+/// A condition, update or key condition expression, with the attribute names and values
+/// its placeholders stand for.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Expression {
+    expression: String,
+    names: HashMap<String, String>,
+    values: Attributes,
+    error: Option<ItemError>,
+}
+
+impl Expression {
+    /// An expression without placeholders, until they are added.
+    pub fn new<S: Into<String>>(expression: S) -> Expression {
+        Expression {
+            expression: expression.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Stands the placeholder `placeholder`, such as `#count`, for the attribute `name`.
+    pub fn name<P, N>(mut self, placeholder: P, name: N) -> Expression
+    where
+        P: Into<String>,
+        N: Into<String>,
+    {
+        self.names.insert(placeholder.into(), name.into());
+        self
+    }
+
+    /// Stands the placeholder `placeholder`, such as `:count`, for `value`.
+    ///
+    /// A value which can't be converted to an attribute value fails the request the
+    /// expression is used in.
+    pub fn value<P, V>(mut self, placeholder: P, value: &V) -> Expression
+    where
+        P: Into<String>,
+        V: Serialize + ?Sized,
+    {
+        match to_attribute_value(value) {
+            Ok(value) => {
+                self.values.insert(placeholder.into(), value);
+            }
+            Err(err) => {
+                self.error = self.error.or(Some(err));
+            }
+        }
+        self
+    }
+
+    fn into_parts(self) -> Result<ExpressionParts, ItemError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(ExpressionParts {
+                expression: self.expression,
+                names: non_empty(self.names),
+                values: non_empty(self.values),
+            }),
+        }
+    }
+}
+
+struct ExpressionParts {
+    expression: String,
+    names: Option<HashMap<String, String>>,
+    values: Option<Attributes>,
+}
+
+fn non_empty<V>(map: HashMap<String, V>) -> Option<HashMap<String, V>> {
+    if map.is_empty() {
+        None
+    } else {
+        Some(map)
+    }
+}
+
+/// Errors returned by the requests of a `Table`.
+#[derive(Debug, PartialEq)]
+pub enum TableError<T> {
+    /// The condition of a put or delete wasn't met. `item` is the item the condition was
+    /// checked against, read again with a consistent read, unless it no longer exists or
+    /// couldn't be read.
+    ConditionFailed { message: String, item: Option<T> },
+    /// A value couldn't be converted to or from attribute values.
+    Item(ItemError),
+    /// An error returned by GetItem.
+    GetItem(GetItemError),
+    /// An error returned by PutItem, other than a failed condition.
+    PutItem(PutItemError),
+    /// An error returned by UpdateItem.
+    UpdateItem(UpdateItemError),
+    /// An error returned by DeleteItem, other than a failed condition.
+    DeleteItem(DeleteItemError),
+    /// An error returned by Query.
+    Query(QueryError),
+}
+
+impl<T: fmt::Debug> fmt::Display for TableError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<T: fmt::Debug> Error for TableError<T> {
+    fn description(&self) -> &str {
+        match *self {
+            TableError::ConditionFailed { ref message, .. } => message,
+            TableError::Item(ref err) => err.description(),
+            TableError::GetItem(ref err) => err.description(),
+            TableError::PutItem(ref err) => err.description(),
+            TableError::UpdateItem(ref err) => err.description(),
+            TableError::DeleteItem(ref err) => err.description(),
+            TableError::Query(ref err) => err.description(),
+        }
+    }
+}
+
+/// Wraps the service error of an operation into a `TableError`.
+fn table_error<E, T, F>(err: RusotoError<E>, wrap: F) -> RusotoError<TableError<T>>
+where
+    F: FnOnce(E) -> TableError<T>,
+{
+    match err {
+        RusotoError::Service(err) => RusotoError::Service(wrap(err)),
+        RusotoError::HttpDispatch(err) => RusotoError::HttpDispatch(err),
+        RusotoError::Credentials(err) => RusotoError::Credentials(err),
+        RusotoError::Validation { message, fields } => RusotoError::Validation { message, fields },
+        RusotoError::ParseError(message) => RusotoError::ParseError(message),
+        RusotoError::Unknown(response) => RusotoError::Unknown(response),
+        RusotoError::UnexpectedContentType {
+            status,
+            content_type,
+            body,
+        } => RusotoError::UnexpectedContentType {
+            status,
+            content_type,
+            body,
+        },
+        RusotoError::Timeout => RusotoError::Timeout,
+        RusotoError::Cancelled => RusotoError::Cancelled,
+    }
+}
+
+fn item_error<T>(err: ItemError) -> RusotoError<TableError<T>> {
+    RusotoError::Service(TableError::Item(err))
+}
+
+/// The items of a DynamoDB table, read and written as values of type `T`.
+///
+/// Reads are eventually consistent and return every attribute of an item unless
+/// [`consistent_read`](#method.consistent_read) or [`projection`](#method.projection) say
+/// otherwise. A projection must leave out only the fields `T` can do without, such as
+/// `Option`s.
+pub struct Table<T, C = DynamoDbClient> {
+    client: Arc<C>,
+    name: String,
+    key_schema: KeySchema,
+    consistent_read: bool,
+    projection: Option<Vec<String>>,
+    item: PhantomData<fn() -> T>,
+}
+
+impl<T, C> Clone for Table<T, C> {
+    fn clone(&self) -> Self {
+        Table {
+            client: self.client.clone(),
+            name: self.name.clone(),
+            key_schema: self.key_schema.clone(),
+            consistent_read: self.consistent_read,
+            projection: self.projection.clone(),
+            item: PhantomData,
+        }
+    }
+}
+
+impl<T, C: fmt::Debug> fmt::Debug for Table<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Table")
+            .field("client", &self.client)
+            .field("name", &self.name)
+            .field("key_schema", &self.key_schema)
+            .field("consistent_read", &self.consistent_read)
+            .field("projection", &self.projection)
+            .finish()
+    }
+}
+
+impl<T, C> Table<T, C>
+where
+    T: Serialize + DeserializeOwned + Send + 'static,
+    C: DynamoDb + Send + Sync + 'static,
+{
+    /// The table named `name`, keyed by `key_schema`, accessed through `client`.
+    pub fn new<S: Into<String>>(client: C, name: S, key_schema: KeySchema) -> Self {
+        Table {
+            client: Arc::new(client),
+            name: name.into(),
+            key_schema,
+            consistent_read: false,
+            projection: None,
+            item: PhantomData,
+        }
+    }
+
+    /// Whether `get` and `query` use strongly consistent reads.
+    pub fn consistent_read(mut self, consistent_read: bool) -> Self {
+        self.consistent_read = consistent_read;
+        self
+    }
+
+    /// Has `get` and `query` read only the attributes named in `attributes`.
+    pub fn projection<I, S>(mut self, attributes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.projection = Some(attributes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// The name of the table.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The item with the key `key`, if there is one.
+    pub fn get(&self, key: &Key) -> RusotoFuture<Option<T>, TableError<T>> {
+        let mut input = GetItemInput {
+            table_name: self.name.clone(),
+            key: key.attributes(&self.key_schema),
+            consistent_read: self.consistent_read_option(),
+            ..Default::default()
+        };
+        if let Some((projection, names)) = self.projection_expression() {
+            input.projection_expression = Some(projection);
+            input.expression_attribute_names = Some(names);
+        }
+
+        RusotoFuture::from_future(self.client.get_item(input).then(|result| match result {
+            Ok(output) => match output.item {
+                Some(item) => from_item(item).map(Some).map_err(item_error),
+                None => Ok(None),
+            },
+            Err(err) => Err(table_error(err, TableError::GetItem)),
+        }))
+    }
+
+    /// Writes `item`, replacing any item with the same key, provided `condition` holds.
+    pub fn put(&self, item: &T, condition: Option<Expression>) -> RusotoFuture<(), TableError<T>> {
+        let item = match to_item(item) {
+            Ok(item) => item,
+            Err(err) => return RusotoFuture::from_future(Err(item_error(err))),
+        };
+        let key = self.key_schema.key_of(&item);
+        let mut input = PutItemInput {
+            table_name: self.name.clone(),
+            item,
+            ..Default::default()
+        };
+        if let Some(condition) = condition {
+            match condition.into_parts() {
+                Ok(condition) => {
+                    input.condition_expression = Some(condition.expression);
+                    input.expression_attribute_names = condition.names;
+                    input.expression_attribute_values = condition.values;
+                }
+                Err(err) => return RusotoFuture::from_future(Err(item_error(err))),
+            }
+        }
+
+        let table = self.clone();
+        RusotoFuture::from_future(
+            self.client
+                .put_item(input)
+                .then(move |result| match result {
+                    Ok(_) => Either::A(future::ok(())),
+                    Err(RusotoError::Service(PutItemError::ConditionalCheckFailed(message))) => {
+                        Either::B(table.condition_failed(key, message))
+                    }
+                    Err(err) => Either::A(future::err(table_error(err, TableError::PutItem))),
+                }),
+        )
+    }
+
+    /// Applies the update expression `update` to the item with the key `key`, creating
+    /// it if it doesn't exist, and returns the item as updated.
+    pub fn update(&self, key: &Key, update: Expression) -> RusotoFuture<T, TableError<T>> {
+        let update = match update.into_parts() {
+            Ok(update) => update,
+            Err(err) => return RusotoFuture::from_future(Err(item_error(err))),
+        };
+        let input = UpdateItemInput {
+            table_name: self.name.clone(),
+            key: key.attributes(&self.key_schema),
+            update_expression: Some(update.expression),
+            expression_attribute_names: update.names,
+            expression_attribute_values: update.values,
+            return_values: Some("ALL_NEW".to_owned()),
+            ..Default::default()
+        };
+
+        RusotoFuture::from_future(self.client.update_item(input).then(|result| match result {
+            Ok(output) => match output.attributes {
+                Some(item) => from_item(item).map_err(item_error),
+                None => Err(RusotoError::ParseError(
+                    "UpdateItem response without the updated item".to_owned(),
+                )),
+            },
+            Err(err) => Err(table_error(err, TableError::UpdateItem)),
+        }))
+    }
+
+    /// Deletes the item with the key `key`, provided `condition` holds, and returns it if
+    /// there was one.
+    pub fn delete(
+        &self,
+        key: &Key,
+        condition: Option<Expression>,
+    ) -> RusotoFuture<Option<T>, TableError<T>> {
+        let key = key.attributes(&self.key_schema);
+        let mut input = DeleteItemInput {
+            table_name: self.name.clone(),
+            key: key.clone(),
+            return_values: Some("ALL_OLD".to_owned()),
+            ..Default::default()
+        };
+        if let Some(condition) = condition {
+            match condition.into_parts() {
+                Ok(condition) => {
+                    input.condition_expression = Some(condition.expression);
+                    input.expression_attribute_names = condition.names;
+                    input.expression_attribute_values = condition.values;
+                }
+                Err(err) => return RusotoFuture::from_future(Err(item_error(err))),
+            }
+        }
+
+        let table = self.clone();
+        RusotoFuture::from_future(
+            self.client
+                .delete_item(input)
+                .then(move |result| match result {
+                    Ok(output) => Either::A(future::result(match output.attributes {
+                        Some(item) => from_item(item).map(Some).map_err(item_error),
+                        None => Ok(None),
+                    })),
+                    Err(RusotoError::Service(DeleteItemError::ConditionalCheckFailed(message))) => {
+                        Either::B(table.condition_failed(key, message))
+                    }
+                    Err(err) => Either::A(future::err(table_error(err, TableError::DeleteItem))),
+                }),
+        )
+    }
+
+    /// The items matching the key condition expression `key_condition`, in the order of
+    /// their sort keys, fetched a page at a time as the stream is polled.
+    pub fn query(
+        &self,
+        key_condition: Expression,
+    ) -> impl Stream<Item = T, Error = RusotoError<TableError<T>>> + Send {
+        let key_condition = match key_condition.into_parts() {
+            Ok(key_condition) => key_condition,
+            Err(err) => return Either::A(stream::once(Err(item_error(err)))),
+        };
+        let mut input = QueryInput {
+            table_name: self.name.clone(),
+            key_condition_expression: Some(key_condition.expression),
+            expression_attribute_names: key_condition.names,
+            expression_attribute_values: key_condition.values,
+            consistent_read: self.consistent_read_option(),
+            ..Default::default()
+        };
+        if let Some((projection, names)) = self.projection_expression() {
+            input.projection_expression = Some(projection);
+            input
+                .expression_attribute_names
+                .get_or_insert_with(HashMap::new)
+                .extend(names);
+        }
+
+        let client = self.client.clone();
+        let pages = stream::unfold(Some(None), move |start_key| {
+            let exclusive_start_key = start_key?;
+            let input = QueryInput {
+                exclusive_start_key,
+                ..input.clone()
+            };
+            Some(
+                client
+                    .query(input)
+                    .then(|result| -> Result<_, RusotoError<TableError<T>>> {
+                        let output = result.map_err(|err| table_error(err, TableError::Query))?;
+                        let items = output
+                            .items
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(from_item)
+                            .collect::<Result<Vec<T>, _>>()
+                            .map_err(item_error)?;
+                        let next = output
+                            .last_evaluated_key
+                            .filter(|key| !key.is_empty())
+                            .map(Some);
+                        Ok((items, next))
+                    }),
+            )
+        });
+        Either::B(pages.map(stream::iter_ok).flatten())
+    }
+
+    fn consistent_read_option(&self) -> Option<bool> {
+        if self.consistent_read {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    /// The projection expression reading the attributes of the projection, with
+    /// placeholders for their names so that reserved words can be projected.
+    fn projection_expression(&self) -> Option<(String, HashMap<String, String>)> {
+        let attributes = self.projection.as_ref()?;
+        let names = attributes
+            .iter()
+            .enumerate()
+            .map(|(i, attribute)| (format!("#projection{}", i), attribute.clone()))
+            .collect::<HashMap<_, _>>();
+        let expression = (0..attributes.len())
+            .map(|i| format!("#projection{}", i))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some((expression, names))
+    }
+
+    /// Fails with `ConditionFailed`, carrying the item with the key `key` as it is now.
+    fn condition_failed<O>(
+        &self,
+        key: Attributes,
+        message: String,
+    ) -> impl Future<Item = O, Error = RusotoError<TableError<T>>> {
+        let input = GetItemInput {
+            table_name: self.name.clone(),
+            key,
+            consistent_read: Some(true),
+            ..Default::default()
+        };
+        self.client.get_item(input).then(|result| {
+            let item = result
+                .ok()
+                .and_then(|output| output.item)
+                .and_then(|item| from_item(item).ok());
+            Err(RusotoError::Service(TableError::ConditionFailed {
+                message,
+                item,
+            }))
+        })
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Represents an attribute for describing the key schema for the table and indexes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -34,6 +35,17 @@ pub struct AttributeDefinition {
     pub attribute_type: String,
 }
 
+impl Validate for AttributeDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute_name"),
+            self.attribute_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 /// <p>Represents the data for an attribute.</p> <p>Each attribute value is described as a name-value pair. The name is the data type, and the value is the data itself.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.NamingRulesDataTypes.html#HowItWorks.DataTypes">Data Types</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributeValue {
@@ -91,6 +103,7 @@ pub struct AttributeValue {
 
 /// <p>For the <code>UpdateItem</code> operation, represents the attributes to be modified, the action to perform on each, and the new value for each.</p> <note> <p>You cannot use <code>UpdateItem</code> to update any primary key attributes. Instead, you will need to delete the item, and then use <code>PutItem</code> to create a new item with new attributes.</p> </note> <p>Attribute values cannot be null; string and binary type attributes must have lengths greater than zero; and set type attributes must not be empty. Requests with empty values will be rejected with a <code>ValidationException</code> exception.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttributeValueUpdate {
     /// <p><p>Specifies how to perform the update. Valid values are <code>PUT</code> (default), <code>DELETE</code>, and <code>ADD</code>. The behavior depends on whether the specified primary key already exists in the table.</p> <p> <b>If an item with the specified <i>Key</i> is found in the table:</b> </p> <ul> <li> <p> <code>PUT</code> - Adds the specified attribute to the item. If the attribute already exists, it is replaced by the new value. </p> </li> <li> <p> <code>DELETE</code> - If no value is specified, the attribute and its value are removed from the item. The data type of the specified value must match the existing value&#39;s data type.</p> <p>If a <i>set</i> of values is specified, then those values are subtracted from the old set. For example, if the attribute value was the set <code>[a,b,c]</code> and the <code>DELETE</code> action specified <code>[a,c]</code>, then the final attribute value would be <code>[b]</code>. Specifying an empty set is an error.</p> </li> <li> <p> <code>ADD</code> - If the attribute does not already exist, then the attribute and its values are added to the item. If the attribute does exist, then the behavior of <code>ADD</code> depends on the data type of the attribute:</p> <ul> <li> <p>If the existing attribute is a number, and if <code>Value</code> is also a number, then the <code>Value</code> is mathematically added to the existing attribute. If <code>Value</code> is a negative number, then it is subtracted from the existing attribute.</p> <note> <p> If you use <code>ADD</code> to increment or decrement a number value for an item that doesn&#39;t exist before the update, DynamoDB uses 0 as the initial value.</p> <p>In addition, if you use <code>ADD</code> to update an existing item, and intend to increment or decrement an attribute value which does not yet exist, DynamoDB uses <code>0</code> as the initial value. For example, suppose that the item you want to update does not yet have an attribute named <i>itemcount</i>, but you decide to <code>ADD</code> the number <code>3</code> to this attribute anyway, even though it currently does not exist. DynamoDB will create the <i>itemcount</i> attribute, set its initial value to <code>0</code>, and finally add <code>3</code> to it. The result will be a new <i>itemcount</i> attribute in the item, with a value of <code>3</code>.</p> </note> </li> <li> <p>If the existing data type is a set, and if the <code>Value</code> is also a set, then the <code>Value</code> is added to the existing set. (This is a <i>set</i> operation, not mathematical addition.) For example, if the attribute value was the set <code>[1,2]</code>, and the <code>ADD</code> action specified <code>[3]</code>, then the final attribute value would be <code>[1,2,3]</code>. An error occurs if an Add action is specified for a set attribute and the attribute type specified does not match the existing set type. </p> <p>Both sets must have the same primitive data type. For example, if the existing data type is a set of strings, the <code>Value</code> must also be a set of strings. The same holds true for number sets and binary sets.</p> </li> </ul> <p>This action is only valid for an existing attribute whose data type is number or is a set. Do not use <code>ADD</code> for any other data types.</p> </li> </ul> <p> <b>If no item with the specified <i>Key</i> is found:</b> </p> <ul> <li> <p> <code>PUT</code> - DynamoDB creates a new item with the specified primary key, and then adds the attribute. </p> </li> <li> <p> <code>DELETE</code> - Nothing happens; there is no attribute to delete.</p> </li> <li> <p> <code>ADD</code> - DynamoDB creates an item with the supplied primary key and number (or set of numbers) for the attribute value. The only data types allowed are number and number set; no other data types can be specified.</p> </li> </ul></p>
     #[serde(rename = "Action")]
@@ -104,7 +117,7 @@ pub struct AttributeValueUpdate {
 
 /// <p>Represents the properties of the scaling policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AutoScalingPolicyDescription {
     /// <p>The name of the scaling policy.</p>
     #[serde(rename = "PolicyName")]
@@ -119,6 +132,7 @@ pub struct AutoScalingPolicyDescription {
 
 /// <p>Represents the autoscaling policy to be modified.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutoScalingPolicyUpdate {
     /// <p>The name of the scaling policy.</p>
     #[serde(rename = "PolicyName")]
@@ -132,7 +146,7 @@ pub struct AutoScalingPolicyUpdate {
 
 /// <p>Represents the autoscaling settings for a global table or global secondary index.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AutoScalingSettingsDescription {
     /// <p>Disabled autoscaling for this global table or global secondary index.</p>
     #[serde(rename = "AutoScalingDisabled")]
@@ -158,6 +172,7 @@ pub struct AutoScalingSettingsDescription {
 
 /// <p>Represents the autoscaling settings to be modified for a global table or global secondary index.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutoScalingSettingsUpdate {
     /// <p>Disabled autoscaling for this global table or global secondary index.</p>
     #[serde(rename = "AutoScalingDisabled")]
@@ -183,7 +198,7 @@ pub struct AutoScalingSettingsUpdate {
 
 /// <p>Represents the properties of a target tracking scaling policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AutoScalingTargetTrackingScalingPolicyConfigurationDescription {
     /// <p>Indicates whether scale in by the target tracking policy is disabled. If the value is true, scale in is disabled and the target tracking policy won't remove capacity from the scalable resource. Otherwise, scale in is enabled and the target tracking policy can remove capacity from the scalable resource. The default value is false.</p>
     #[serde(rename = "DisableScaleIn")]
@@ -204,6 +219,7 @@ pub struct AutoScalingTargetTrackingScalingPolicyConfigurationDescription {
 
 /// <p>Represents the settings of a target tracking scaling policy that will be modified.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutoScalingTargetTrackingScalingPolicyConfigurationUpdate {
     /// <p>Indicates whether scale in by the target tracking policy is disabled. If the value is true, scale in is disabled and the target tracking policy won't remove capacity from the scalable resource. Otherwise, scale in is enabled and the target tracking policy can remove capacity from the scalable resource. The default value is false.</p>
     #[serde(rename = "DisableScaleIn")]
@@ -224,7 +240,7 @@ pub struct AutoScalingTargetTrackingScalingPolicyConfigurationUpdate {
 
 /// <p>Contains the description of the backup created for the table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BackupDescription {
     /// <p>Contains the details of the backup created for the table. </p>
     #[serde(rename = "BackupDetails")]
@@ -242,7 +258,7 @@ pub struct BackupDescription {
 
 /// <p>Contains the details of the backup created for the table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BackupDetails {
     /// <p>ARN associated with the backup.</p>
     #[serde(rename = "BackupArn")]
@@ -271,7 +287,7 @@ pub struct BackupDetails {
 
 /// <p>Contains details for the backup.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BackupSummary {
     /// <p>ARN associated with the backup.</p>
     #[serde(rename = "BackupArn")]
@@ -317,6 +333,7 @@ pub struct BackupSummary {
 
 /// <p>Represents the input of a <code>BatchGetItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetItemInput {
     /// <p><p>A map of one or more table names and, for each table, a map that describes one or more items to retrieve from that table. Each table name can be used only once per <code>BatchGetItem</code> request.</p> <p>Each element in the map of items to retrieve consists of the following:</p> <ul> <li> <p> <code>ConsistentRead</code> - If <code>true</code>, a strongly consistent read is used; if <code>false</code> (the default), an eventually consistent read is used.</p> </li> <li> <p> <code>ExpressionAttributeNames</code> - One or more substitution tokens for attribute names in the <code>ProjectionExpression</code> parameter. The following are some use cases for using <code>ExpressionAttributeNames</code>:</p> <ul> <li> <p>To access an attribute whose name conflicts with a DynamoDB reserved word.</p> </li> <li> <p>To create a placeholder for repeating occurrences of an attribute name in an expression.</p> </li> <li> <p>To prevent special characters in an attribute name from being misinterpreted in an expression.</p> </li> </ul> <p>Use the <b>#</b> character in an expression to dereference an attribute name. For example, consider the following attribute name:</p> <ul> <li> <p> <code>Percentile</code> </p> </li> </ul> <p>The name of this attribute conflicts with a reserved word, so it cannot be used directly in an expression. (For the complete list of reserved words, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ReservedWords.html">Reserved Words</a> in the <i>Amazon DynamoDB Developer Guide</i>). To work around this, you could specify the following for <code>ExpressionAttributeNames</code>:</p> <ul> <li> <p> <code>{&quot;#P&quot;:&quot;Percentile&quot;}</code> </p> </li> </ul> <p>You could then use this substitution in an expression, as in this example:</p> <ul> <li> <p> <code>#P = :val</code> </p> </li> </ul> <note> <p>Tokens that begin with the <b>:</b> character are <i>expression attribute values</i>, which are placeholders for the actual value at runtime.</p> </note> <p>For more information about expression attribute names, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.AccessingItemAttributes.html">Accessing Item Attributes</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </li> <li> <p> <code>Keys</code> - An array of primary key attribute values that define specific items in the table. For each primary key, you must provide <i>all</i> of the key attributes. For example, with a simple primary key, you only need to provide the partition key value. For a composite key, you must provide <i>both</i> the partition key value and the sort key value.</p> </li> <li> <p> <code>ProjectionExpression</code> - A string that identifies one or more attributes to retrieve from the table. These attributes can include scalars, sets, or elements of a JSON document. The attributes in the expression must be separated by commas.</p> <p>If no attribute names are specified, then all attributes are returned. If any of the requested attributes are not found, they do not appear in the result.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.AccessingItemAttributes.html">Accessing Item Attributes</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </li> <li> <p> <code>AttributesToGet</code> - This is a legacy parameter. Use <code>ProjectionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.AttributesToGet.html">AttributesToGet</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> </li> </ul></p>
     #[serde(rename = "RequestItems")]
@@ -326,9 +343,24 @@ pub struct BatchGetItemInput {
     pub return_consumed_capacity: Option<String>,
 }
 
+impl Validate for BatchGetItemInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "request_items"),
+            self.request_items.len(),
+            Some(1),
+            Some(100),
+        );
+        for (key, item) in self.request_items.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "request_items"), key);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>BatchGetItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetItemOutput {
     /// <p><p>The read capacity units consumed by the entire <code>BatchGetItem</code> operation.</p> <p>Each element consists of:</p> <ul> <li> <p> <code>TableName</code> - The table that consumed the provisioned throughput.</p> </li> <li> <p> <code>CapacityUnits</code> - The total number of capacity units consumed.</p> </li> </ul></p>
     #[serde(rename = "ConsumedCapacity")]
@@ -351,6 +383,7 @@ pub struct BatchGetItemOutput {
 
 /// <p>Represents the input of a <code>BatchWriteItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchWriteItemInput {
     /// <p><p>A map of one or more table names and, for each table, a list of operations to be performed (<code>DeleteRequest</code> or <code>PutRequest</code>). Each element in the map consists of the following:</p> <ul> <li> <p> <code>DeleteRequest</code> - Perform a <code>DeleteItem</code> operation on the specified item. The item to be deleted is identified by a <code>Key</code> subelement:</p> <ul> <li> <p> <code>Key</code> - A map of primary key attribute values that uniquely identify the item. Each entry in this map consists of an attribute name and an attribute value. For each primary key, you must provide <i>all</i> of the key attributes. For example, with a simple primary key, you only need to provide a value for the partition key. For a composite primary key, you must provide values for <i>both</i> the partition key and the sort key.</p> </li> </ul> </li> <li> <p> <code>PutRequest</code> - Perform a <code>PutItem</code> operation on the specified item. The item to be put is identified by an <code>Item</code> subelement:</p> <ul> <li> <p> <code>Item</code> - A map of attributes and their values. Each entry in this map consists of an attribute name and an attribute value. Attribute values must not be null; string and binary type attributes must have lengths greater than zero; and set type attributes must not be empty. Requests that contain empty values are rejected with a <code>ValidationException</code> exception.</p> <p>If you specify any attributes that are part of an index key, then the data types for those attributes must match those of the schema in the table&#39;s attribute definition.</p> </li> </ul> </li> </ul></p>
     #[serde(rename = "RequestItems")]
@@ -364,9 +397,24 @@ pub struct BatchWriteItemInput {
     pub return_item_collection_metrics: Option<String>,
 }
 
+impl Validate for BatchWriteItemInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "request_items"),
+            self.request_items.len(),
+            Some(1),
+            Some(25),
+        );
+        for (key, item) in self.request_items.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "request_items"), key);
+            errors.check_length(&item_path, item.len(), Some(1), Some(25));
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>BatchWriteItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchWriteItemOutput {
     /// <p><p>The capacity units consumed by the entire <code>BatchWriteItem</code> operation.</p> <p>Each element consists of:</p> <ul> <li> <p> <code>TableName</code> - The table that consumed the provisioned throughput.</p> </li> <li> <p> <code>CapacityUnits</code> - The total number of capacity units consumed.</p> </li> </ul></p>
     #[serde(rename = "ConsumedCapacity")]
@@ -385,7 +433,7 @@ pub struct BatchWriteItemOutput {
 
 /// <p>Contains the details for the read/write capacity mode.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BillingModeSummary {
     /// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. This setting can be changed later.</p> <ul> <li> <p> <code>PROVISIONED</code> - Sets the read/write capacity mode to <code>PROVISIONED</code>. We recommend using <code>PROVISIONED</code> for predictable workloads.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - Sets the read/write capacity mode to <code>PAY<em>PER</em>REQUEST</code>. We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. </p> </li> </ul></p>
     #[serde(rename = "BillingMode")]
@@ -410,7 +458,7 @@ pub struct CancellationReason {
 
 /// <p>Represents the amount of provisioned throughput capacity consumed on a table or an index.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Capacity {
     /// <p>The total number of capacity units consumed on a table or an index.</p>
     #[serde(rename = "CapacityUnits")]
@@ -428,6 +476,7 @@ pub struct Capacity {
 
 /// <p><p>Represents the selection criteria for a <code>Query</code> or <code>Scan</code> operation:</p> <ul> <li> <p>For a <code>Query</code> operation, <code>Condition</code> is used for specifying the <code>KeyConditions</code> to use when querying a table or an index. For <code>KeyConditions</code>, only the following comparison operators are supported:</p> <p> <code>EQ | LE | LT | GE | GT | BEGINS_WITH | BETWEEN</code> </p> <p> <code>Condition</code> is also used in a <code>QueryFilter</code>, which evaluates the query results and returns only the desired values.</p> </li> <li> <p>For a <code>Scan</code> operation, <code>Condition</code> is used in a <code>ScanFilter</code>, which evaluates the scan results and returns only the desired values.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Condition {
     /// <p>One or more values to evaluate against the supplied attribute. The number of values in the list depends on the <code>ComparisonOperator</code> being used.</p> <p>For type Number, value comparisons are numeric.</p> <p>String value comparisons for greater than, equals, or less than are based on ASCII character code values. For example, <code>a</code> is greater than <code>A</code>, and <code>a</code> is greater than <code>B</code>. For a list of code values, see <a href="http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters">http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters</a>.</p> <p>For Binary, DynamoDB treats each byte of the binary data as unsigned when it compares binary values.</p>
     #[serde(rename = "AttributeValueList")]
@@ -440,6 +489,7 @@ pub struct Condition {
 
 /// <p>Represents a request to perform a check that an item exists or to check the condition of specific attributes of the item..</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConditionCheck {
     /// <p>A condition that must be satisfied in order for a conditional update to succeed.</p>
     #[serde(rename = "ConditionExpression")]
@@ -464,9 +514,35 @@ pub struct ConditionCheck {
     pub table_name: String,
 }
 
+impl Validate for ConditionCheck {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>The capacity units consumed by an operation. The data returned includes the total provisioned throughput consumed, along with statistics for the table and any indexes involved in the operation. <code>ConsumedCapacity</code> is only returned if the request asked for it. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ProvisionedThroughputIntro.html">Provisioned Throughput</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConsumedCapacity {
     /// <p>The total number of capacity units consumed by the operation.</p>
     #[serde(rename = "CapacityUnits")]
@@ -500,7 +576,7 @@ pub struct ConsumedCapacity {
 
 /// <p>Represents the continuous backups and point in time recovery settings on the table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ContinuousBackupsDescription {
     /// <p> <code>ContinuousBackupsStatus</code> can be one of the following states: ENABLED, DISABLED</p>
     #[serde(rename = "ContinuousBackupsStatus")]
@@ -512,6 +588,7 @@ pub struct ContinuousBackupsDescription {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBackupInput {
     /// <p>Specified name for the backup.</p>
     #[serde(rename = "BackupName")]
@@ -521,8 +598,35 @@ pub struct CreateBackupInput {
     pub table_name: String,
 }
 
+impl Validate for CreateBackupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "backup_name"),
+            self.backup_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "backup_name"),
+            &self.backup_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBackupOutput {
     /// <p>Contains the details of the backup created for the table.</p>
     #[serde(rename = "BackupDetails")]
@@ -532,6 +636,7 @@ pub struct CreateBackupOutput {
 
 /// <p>Represents a new global secondary index to be added to an existing table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGlobalSecondaryIndexAction {
     /// <p>The name of the global secondary index to be created.</p>
     #[serde(rename = "IndexName")]
@@ -548,7 +653,39 @@ pub struct CreateGlobalSecondaryIndexAction {
     pub provisioned_throughput: Option<ProvisionedThroughput>,
 }
 
+impl Validate for CreateGlobalSecondaryIndexAction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "index_name"),
+            self.index_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "index_name"),
+            &self.index_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "key_schema"),
+            self.key_schema.len(),
+            Some(1),
+            Some(2),
+        );
+        for (i, item) in self.key_schema.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "key_schema"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.projection
+            .validate_at(&field_path(path, "projection"), errors);
+        if let Some(ref value) = self.provisioned_throughput {
+            value.validate_at(&field_path(path, "provisioned_throughput"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGlobalTableInput {
     /// <p>The global table name.</p>
     #[serde(rename = "GlobalTableName")]
@@ -558,8 +695,24 @@ pub struct CreateGlobalTableInput {
     pub replication_group: Vec<Replica>,
 }
 
+impl Validate for CreateGlobalTableInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "global_table_name"),
+            self.global_table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "global_table_name"),
+            &self.global_table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGlobalTableOutput {
     /// <p>Contains the details of the global table.</p>
     #[serde(rename = "GlobalTableDescription")]
@@ -569,6 +722,7 @@ pub struct CreateGlobalTableOutput {
 
 /// <p>Represents a replica to be added.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReplicaAction {
     /// <p>The region of the replica to be added.</p>
     #[serde(rename = "RegionName")]
@@ -577,6 +731,7 @@ pub struct CreateReplicaAction {
 
 /// <p>Represents the input of a <code>CreateTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTableInput {
     /// <p>An array of attributes that describe the key schema for the table and indexes.</p>
     #[serde(rename = "AttributeDefinitions")]
@@ -617,9 +772,60 @@ pub struct CreateTableInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateTableInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attribute_definitions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_definitions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.global_secondary_indexes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "global_secondary_indexes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "key_schema"),
+            self.key_schema.len(),
+            Some(1),
+            Some(2),
+        );
+        for (i, item) in self.key_schema.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "key_schema"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.local_secondary_indexes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "local_secondary_indexes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.provisioned_throughput {
+            value.validate_at(&field_path(path, "provisioned_throughput"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>CreateTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTableOutput {
     /// <p>Represents the properties of the table.</p>
     #[serde(rename = "TableDescription")]
@@ -629,6 +835,7 @@ pub struct CreateTableOutput {
 
 /// <p>Represents a request to perform a <code>DeleteItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Delete {
     /// <p>A condition that must be satisfied in order for a conditional delete to succeed.</p>
     #[serde(rename = "ConditionExpression")]
@@ -654,15 +861,53 @@ pub struct Delete {
     pub table_name: String,
 }
 
+impl Validate for Delete {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBackupInput {
     /// <p>The ARN associated with the backup.</p>
     #[serde(rename = "BackupArn")]
     pub backup_arn: String,
 }
 
+impl Validate for DeleteBackupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "backup_arn"),
+            self.backup_arn.chars().count(),
+            Some(37),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBackupOutput {
     /// <p>Contains the description of the backup created for the table.</p>
     #[serde(rename = "BackupDescription")]
@@ -672,14 +917,32 @@ pub struct DeleteBackupOutput {
 
 /// <p>Represents a global secondary index to be deleted from an existing table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGlobalSecondaryIndexAction {
     /// <p>The name of the global secondary index to be deleted.</p>
     #[serde(rename = "IndexName")]
     pub index_name: String,
 }
 
+impl Validate for DeleteGlobalSecondaryIndexAction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "index_name"),
+            self.index_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "index_name"),
+            &self.index_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the input of a <code>DeleteItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteItemInput {
     /// <p>A condition that must be satisfied in order for a conditional <code>DeleteItem</code> to succeed.</p> <p>An expression can contain any of the following:</p> <ul> <li> <p>Functions: <code>attribute_exists | attribute_not_exists | attribute_type | contains | begins_with | size</code> </p> <p>These function names are case-sensitive.</p> </li> <li> <p>Comparison operators: <code>= | &lt;&gt; | &lt; | &gt; | &lt;= | &gt;= | BETWEEN | IN </code> </p> </li> <li> <p> Logical operators: <code>AND | OR | NOT</code> </p> </li> </ul> <p>For more information about condition expressions, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.SpecifyingConditions.html">Condition Expressions</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionExpression")]
@@ -720,9 +983,35 @@ pub struct DeleteItemInput {
     pub table_name: String,
 }
 
+impl Validate for DeleteItemInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>DeleteItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteItemOutput {
     /// <p>A map of attribute names to <code>AttributeValue</code> objects, representing the item as it appeared before the <code>DeleteItem</code> operation. This map appears in the response only if <code>ReturnValues</code> was specified as <code>ALL_OLD</code> in the request.</p>
     #[serde(rename = "Attributes")]
@@ -740,6 +1029,7 @@ pub struct DeleteItemOutput {
 
 /// <p>Represents a replica to be removed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReplicaAction {
     /// <p>The region of the replica to be removed.</p>
     #[serde(rename = "RegionName")]
@@ -756,15 +1046,32 @@ pub struct DeleteRequest {
 
 /// <p>Represents the input of a <code>DeleteTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTableInput {
     /// <p>The name of the table to delete.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
+impl Validate for DeleteTableInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>DeleteTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTableOutput {
     /// <p>Represents the properties of a table.</p>
     #[serde(rename = "TableDescription")]
@@ -773,14 +1080,26 @@ pub struct DeleteTableOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBackupInput {
     /// <p>The Amazon Resource Name (ARN) associated with the backup.</p>
     #[serde(rename = "BackupArn")]
     pub backup_arn: String,
 }
 
+impl Validate for DescribeBackupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "backup_arn"),
+            self.backup_arn.chars().count(),
+            Some(37),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBackupOutput {
     /// <p>Contains the description of the backup created for the table.</p>
     #[serde(rename = "BackupDescription")]
@@ -789,14 +1108,31 @@ pub struct DescribeBackupOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeContinuousBackupsInput {
     /// <p>Name of the table for which the customer wants to check the continuous backups and point in time recovery settings.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
+impl Validate for DescribeContinuousBackupsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeContinuousBackupsOutput {
     /// <p>Represents the continuous backups and point in time recovery settings on the table.</p>
     #[serde(rename = "ContinuousBackupsDescription")]
@@ -805,10 +1141,11 @@ pub struct DescribeContinuousBackupsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEndpointsRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEndpointsResponse {
     /// <p>List of endpoints.</p>
     #[serde(rename = "Endpoints")]
@@ -816,14 +1153,31 @@ pub struct DescribeEndpointsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeGlobalTableInput {
     /// <p>The name of the global table.</p>
     #[serde(rename = "GlobalTableName")]
     pub global_table_name: String,
 }
 
+impl Validate for DescribeGlobalTableInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "global_table_name"),
+            self.global_table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "global_table_name"),
+            &self.global_table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeGlobalTableOutput {
     /// <p>Contains the details of the global table.</p>
     #[serde(rename = "GlobalTableDescription")]
@@ -832,14 +1186,31 @@ pub struct DescribeGlobalTableOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeGlobalTableSettingsInput {
     /// <p>The name of the global table to describe.</p>
     #[serde(rename = "GlobalTableName")]
     pub global_table_name: String,
 }
 
+impl Validate for DescribeGlobalTableSettingsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "global_table_name"),
+            self.global_table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "global_table_name"),
+            &self.global_table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeGlobalTableSettingsOutput {
     /// <p>The name of the global table.</p>
     #[serde(rename = "GlobalTableName")]
@@ -853,11 +1224,12 @@ pub struct DescribeGlobalTableSettingsOutput {
 
 /// <p>Represents the input of a <code>DescribeLimits</code> operation. Has no content.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLimitsInput {}
 
 /// <p>Represents the output of a <code>DescribeLimits</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeLimitsOutput {
     /// <p>The maximum total read capacity units that your account allows you to provision across all of your tables in this Region.</p>
     #[serde(rename = "AccountMaxReadCapacityUnits")]
@@ -879,15 +1251,32 @@ pub struct DescribeLimitsOutput {
 
 /// <p>Represents the input of a <code>DescribeTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTableInput {
     /// <p>The name of the table to describe.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
+impl Validate for DescribeTableInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>DescribeTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTableOutput {
     /// <p>The properties of the table.</p>
     #[serde(rename = "Table")]
@@ -896,14 +1285,31 @@ pub struct DescribeTableOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTimeToLiveInput {
     /// <p>The name of the table to be described.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
+impl Validate for DescribeTimeToLiveInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTimeToLiveOutput {
     /// <p><p/></p>
     #[serde(rename = "TimeToLiveDescription")]
@@ -913,7 +1319,7 @@ pub struct DescribeTimeToLiveOutput {
 
 /// <p>An endpoint information details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Endpoint {
     /// <p>IP address of the endpoint.</p>
     #[serde(rename = "Address")]
@@ -925,6 +1331,7 @@ pub struct Endpoint {
 
 /// <p>Represents a condition to be compared with an attribute value. This condition can be used with <code>DeleteItem</code>, <code>PutItem</code> or <code>UpdateItem</code> operations; if the comparison evaluates to true, the operation succeeds; if not, the operation fails. You can use <code>ExpectedAttributeValue</code> in one of two different ways:</p> <ul> <li> <p>Use <code>AttributeValueList</code> to specify one or more values to compare against an attribute. Use <code>ComparisonOperator</code> to specify how you want to perform the comparison. If the comparison evaluates to true, then the conditional operation succeeds.</p> </li> <li> <p>Use <code>Value</code> to specify a value that DynamoDB will compare against an attribute. If the values match, then <code>ExpectedAttributeValue</code> evaluates to true and the conditional operation succeeds. Optionally, you can also set <code>Exists</code> to false, indicating that you <i>do not</i> expect to find the attribute value in the table. In this case, the conditional operation succeeds only if the comparison evaluates to false.</p> </li> </ul> <p> <code>Value</code> and <code>Exists</code> are incompatible with <code>AttributeValueList</code> and <code>ComparisonOperator</code>. Note that if you use both sets of parameters at once, DynamoDB will return a <code>ValidationException</code> exception.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExpectedAttributeValue {
     /// <p>One or more values to evaluate against the supplied attribute. The number of values in the list depends on the <code>ComparisonOperator</code> being used.</p> <p>For type Number, value comparisons are numeric.</p> <p>String value comparisons for greater than, equals, or less than are based on ASCII character code values. For example, <code>a</code> is greater than <code>A</code>, and <code>a</code> is greater than <code>B</code>. For a list of code values, see <a href="http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters">http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters</a>.</p> <p>For Binary, DynamoDB treats each byte of the binary data as unsigned when it compares binary values.</p> <p>For information on specifying data types in JSON, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/DataFormat.html">JSON Data Format</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "AttributeValueList")]
@@ -946,6 +1353,7 @@ pub struct ExpectedAttributeValue {
 
 /// <p>Specifies an item and related attribute values to retrieve in a <code>TransactGetItem</code> object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Get {
     /// <p>One or more substitution tokens for attribute names in the ProjectionExpression parameter.</p>
     #[serde(rename = "ExpressionAttributeNames")]
@@ -963,8 +1371,35 @@ pub struct Get {
     pub table_name: String,
 }
 
+impl Validate for Get {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the input of a <code>GetItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetItemInput {
     /// <p>This is a legacy parameter. Use <code>ProjectionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.AttributesToGet.html">AttributesToGet</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "AttributesToGet")]
@@ -993,9 +1428,47 @@ pub struct GetItemInput {
     pub table_name: String,
 }
 
+impl Validate for GetItemInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes_to_get {
+            errors.check_length(
+                &field_path(path, "attributes_to_get"),
+                value.len(),
+                Some(1),
+                None,
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes_to_get"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>GetItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetItemOutput {
     /// <p>The capacity units consumed by the <code>GetItem</code> operation. The data returned includes the total provisioned throughput consumed, along with statistics for the table and any indexes involved in the operation. <code>ConsumedCapacity</code> is only returned if the <code>ReturnConsumedCapacity</code> parameter was specified. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ProvisionedThroughputIntro.html">Read/Write Capacity Mode</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConsumedCapacity")]
@@ -1009,6 +1482,7 @@ pub struct GetItemOutput {
 
 /// <p>Represents the properties of a global secondary index.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GlobalSecondaryIndex {
     /// <p>The name of the global secondary index. The name must be unique among all other indexes on this table.</p>
     #[serde(rename = "IndexName")]
@@ -1025,9 +1499,40 @@ pub struct GlobalSecondaryIndex {
     pub provisioned_throughput: Option<ProvisionedThroughput>,
 }
 
+impl Validate for GlobalSecondaryIndex {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "index_name"),
+            self.index_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "index_name"),
+            &self.index_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "key_schema"),
+            self.key_schema.len(),
+            Some(1),
+            Some(2),
+        );
+        for (i, item) in self.key_schema.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "key_schema"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.projection
+            .validate_at(&field_path(path, "projection"), errors);
+        if let Some(ref value) = self.provisioned_throughput {
+            value.validate_at(&field_path(path, "provisioned_throughput"), errors);
+        }
+    }
+}
+
 /// <p>Represents the properties of a global secondary index.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GlobalSecondaryIndexDescription {
     /// <p><p>Indicates whether the index is currently backfilling. <i>Backfilling</i> is the process of reading items from the table and determining whether they can be added to the index. (Not all items will qualify: For example, a partition key cannot have any duplicate values.) If an item can be added to the index, DynamoDB will do so. After all items have been processed, the backfilling operation is complete and <code>Backfilling</code> is false.</p> <note> <p>For indexes that were created during a <code>CreateTable</code> operation, the <code>Backfilling</code> attribute does not appear in the <code>DescribeTable</code> output.</p> </note></p>
     #[serde(rename = "Backfilling")]
@@ -1069,7 +1574,7 @@ pub struct GlobalSecondaryIndexDescription {
 
 /// <p>Represents the properties of a global secondary index for the table when the backup was created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GlobalSecondaryIndexInfo {
     /// <p>The name of the global secondary index.</p>
     #[serde(rename = "IndexName")]
@@ -1091,6 +1596,7 @@ pub struct GlobalSecondaryIndexInfo {
 
 /// <p><p>Represents one of the following:</p> <ul> <li> <p>A new global secondary index to be added to an existing table.</p> </li> <li> <p>New provisioned throughput parameters for an existing global secondary index.</p> </li> <li> <p>An existing global secondary index to be removed from an existing table.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GlobalSecondaryIndexUpdate {
     /// <p><p>The parameters required for creating a global secondary index on an existing table:</p> <ul> <li> <p> <code>IndexName </code> </p> </li> <li> <p> <code>KeySchema </code> </p> </li> <li> <p> <code>AttributeDefinitions </code> </p> </li> <li> <p> <code>Projection </code> </p> </li> <li> <p> <code>ProvisionedThroughput </code> </p> </li> </ul></p>
     #[serde(rename = "Create")]
@@ -1106,9 +1612,23 @@ pub struct GlobalSecondaryIndexUpdate {
     pub update: Option<UpdateGlobalSecondaryIndexAction>,
 }
 
+impl Validate for GlobalSecondaryIndexUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.create {
+            value.validate_at(&field_path(path, "create"), errors);
+        }
+        if let Some(ref value) = self.delete {
+            value.validate_at(&field_path(path, "delete"), errors);
+        }
+        if let Some(ref value) = self.update {
+            value.validate_at(&field_path(path, "update"), errors);
+        }
+    }
+}
+
 /// <p>Represents the properties of a global table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GlobalTable {
     /// <p>The global table name.</p>
     #[serde(rename = "GlobalTableName")]
@@ -1122,7 +1642,7 @@ pub struct GlobalTable {
 
 /// <p>Contains details about the global table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GlobalTableDescription {
     /// <p>The creation time of the global table.</p>
     #[serde(rename = "CreationDateTime")]
@@ -1148,6 +1668,7 @@ pub struct GlobalTableDescription {
 
 /// <p>Represents the settings of a global secondary index for a global table that will be modified.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GlobalTableGlobalSecondaryIndexSettingsUpdate {
     /// <p>The name of the global secondary index. The name must be unique among all other indexes on this table.</p>
     #[serde(rename = "IndexName")]
@@ -1164,7 +1685,7 @@ pub struct GlobalTableGlobalSecondaryIndexSettingsUpdate {
 
 /// <p>Information about item collections, if any, that were affected by the operation. <code>ItemCollectionMetrics</code> is only returned if the request asked for it. If the table does not have any local secondary indexes, this information is not returned in the response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ItemCollectionMetrics {
     /// <p>The partition key value of the item collection. This value is the same as the partition key value of the item.</p>
     #[serde(rename = "ItemCollectionKey")]
@@ -1178,7 +1699,7 @@ pub struct ItemCollectionMetrics {
 
 /// <p>Details for the requested item.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ItemResponse {
     /// <p>Map of attribute data consisting of the data type and attribute value.</p>
     #[serde(rename = "Item")]
@@ -1197,6 +1718,17 @@ pub struct KeySchemaElement {
     pub key_type: String,
 }
 
+impl Validate for KeySchemaElement {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute_name"),
+            self.attribute_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 /// <p>Represents a set of primary keys and, for each key, the attributes to retrieve from the table.</p> <p>For each primary key, you must provide <i>all</i> of the key attributes. For example, with a simple primary key, you only need to provide the partition key. For a composite primary key, you must provide <i>both</i> the partition key and the sort key.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeysAndAttributes {
@@ -1221,7 +1753,41 @@ pub struct KeysAndAttributes {
     pub projection_expression: Option<String>,
 }
 
+impl Validate for KeysAndAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes_to_get {
+            errors.check_length(
+                &field_path(path, "attributes_to_get"),
+                value.len(),
+                Some(1),
+                None,
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes_to_get"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "keys"),
+            self.keys.len(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBackupsInput {
     /// <p><p>The backups from the table specified by <code>BackupType</code> are listed.</p> <p>Where <code>BackupType</code> can be:</p> <ul> <li> <p> <code>USER</code> - On-demand backup created by you.</p> </li> <li> <p> <code>SYSTEM</code> - On-demand backup automatically created by DynamoDB.</p> </li> <li> <p> <code>ALL</code> - All types of on-demand backups (USER and SYSTEM).</p> </li> </ul></p>
     #[serde(rename = "BackupType")]
@@ -1249,8 +1815,33 @@ pub struct ListBackupsInput {
     pub time_range_upper_bound: Option<f64>,
 }
 
+impl Validate for ListBackupsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_backup_arn {
+            errors.check_length(
+                &field_path(path, "exclusive_start_backup_arn"),
+                value.chars().count(),
+                Some(37),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.table_name {
+            errors.check_length(
+                &field_path(path, "table_name"),
+                value.chars().count(),
+                Some(3),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "table_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBackupsOutput {
     /// <p>List of <code>BackupSummary</code> objects.</p>
     #[serde(rename = "BackupSummaries")]
@@ -1263,6 +1854,7 @@ pub struct ListBackupsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGlobalTablesInput {
     /// <p>The first global table name that this operation will evaluate.</p>
     #[serde(rename = "ExclusiveStartGlobalTableName")]
@@ -1278,8 +1870,29 @@ pub struct ListGlobalTablesInput {
     pub region_name: Option<String>,
 }
 
+impl Validate for ListGlobalTablesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_global_table_name {
+            errors.check_length(
+                &field_path(path, "exclusive_start_global_table_name"),
+                value.chars().count(),
+                Some(3),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "exclusive_start_global_table_name"),
+                &value,
+                "[a-zA-Z0-9_.-]+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGlobalTablesOutput {
     /// <p>List of global table names.</p>
     #[serde(rename = "GlobalTables")]
@@ -1293,6 +1906,7 @@ pub struct ListGlobalTablesOutput {
 
 /// <p>Represents the input of a <code>ListTables</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTablesInput {
     /// <p>The first table name that this operation will evaluate. Use the value that was returned for <code>LastEvaluatedTableName</code> in a previous operation, so that you can obtain the next page of results.</p>
     #[serde(rename = "ExclusiveStartTableName")]
@@ -1304,9 +1918,30 @@ pub struct ListTablesInput {
     pub limit: Option<i64>,
 }
 
+impl Validate for ListTablesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_table_name {
+            errors.check_length(
+                &field_path(path, "exclusive_start_table_name"),
+                value.chars().count(),
+                Some(3),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "exclusive_start_table_name"),
+                &value,
+                "[a-zA-Z0-9_.-]+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>ListTables</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTablesOutput {
     /// <p>The name of the last table in the current page of results. Use this value as the <code>ExclusiveStartTableName</code> in a new request to obtain the next page of results, until all the table names are returned.</p> <p>If you do not receive a <code>LastEvaluatedTableName</code> value in the response, this means that there are no more table names to be retrieved.</p>
     #[serde(rename = "LastEvaluatedTableName")]
@@ -1319,6 +1954,7 @@ pub struct ListTablesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsOfResourceInput {
     /// <p>An optional string that, if supplied, must be copied from the output of a previous call to ListTagOfResource. When provided in this manner, this API fetches the next page of results.</p>
     #[serde(rename = "NextToken")]
@@ -1329,8 +1965,19 @@ pub struct ListTagsOfResourceInput {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsOfResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1283),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsOfResourceOutput {
     /// <p>If this value is returned, there are additional results to be displayed. To retrieve them, call ListTagsOfResource again, with NextToken set to this value.</p>
     #[serde(rename = "NextToken")]
@@ -1344,6 +1991,7 @@ pub struct ListTagsOfResourceOutput {
 
 /// <p>Represents the properties of a local secondary index.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LocalSecondaryIndex {
     /// <p>The name of the local secondary index. The name must be unique among all other indexes on this table.</p>
     #[serde(rename = "IndexName")]
@@ -1356,9 +2004,37 @@ pub struct LocalSecondaryIndex {
     pub projection: Projection,
 }
 
+impl Validate for LocalSecondaryIndex {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "index_name"),
+            self.index_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "index_name"),
+            &self.index_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "key_schema"),
+            self.key_schema.len(),
+            Some(1),
+            Some(2),
+        );
+        for (i, item) in self.key_schema.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "key_schema"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.projection
+            .validate_at(&field_path(path, "projection"), errors);
+    }
+}
+
 /// <p>Represents the properties of a local secondary index.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LocalSecondaryIndexDescription {
     /// <p>The Amazon Resource Name (ARN) that uniquely identifies the index.</p>
     #[serde(rename = "IndexArn")]
@@ -1388,7 +2064,7 @@ pub struct LocalSecondaryIndexDescription {
 
 /// <p>Represents the properties of a local secondary index for the table when the backup was created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LocalSecondaryIndexInfo {
     /// <p>Represents the name of the local secondary index.</p>
     #[serde(rename = "IndexName")]
@@ -1406,7 +2082,7 @@ pub struct LocalSecondaryIndexInfo {
 
 /// <p>The description of the point in time settings applied to the table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PointInTimeRecoveryDescription {
     /// <p>Specifies the earliest point in time you can restore your table to. It You can restore your table to any point in time during the last 35 days. </p>
     #[serde(rename = "EarliestRestorableDateTime")]
@@ -1424,6 +2100,7 @@ pub struct PointInTimeRecoveryDescription {
 
 /// <p>Represents the settings used to enable point in time recovery.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PointInTimeRecoverySpecification {
     /// <p>Indicates whether point in time recovery is enabled (true) or disabled (false) on the table.</p>
     #[serde(rename = "PointInTimeRecoveryEnabled")]
@@ -1443,6 +2120,23 @@ pub struct Projection {
     pub projection_type: Option<String>,
 }
 
+impl Validate for Projection {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.non_key_attributes {
+            errors.check_length(
+                &field_path(path, "non_key_attributes"),
+                value.len(),
+                Some(1),
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "non_key_attributes"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            }
+        }
+    }
+}
+
 /// <p>Represents the provisioned throughput settings for a specified table or index. The settings can be modified using the <code>UpdateTable</code> operation.</p> <p>For current minimum and maximum provisioned throughput values, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProvisionedThroughput {
@@ -1454,9 +2148,26 @@ pub struct ProvisionedThroughput {
     pub write_capacity_units: i64,
 }
 
+impl Validate for ProvisionedThroughput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "read_capacity_units"),
+            self.read_capacity_units,
+            Some(1),
+            None,
+        );
+        errors.check_range(
+            &field_path(path, "write_capacity_units"),
+            self.write_capacity_units,
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Represents the provisioned throughput settings for the table, consisting of read and write capacity units, along with data about increases and decreases.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisionedThroughputDescription {
     /// <p>The date and time of the last provisioned throughput decrease for this table.</p>
     #[serde(rename = "LastDecreaseDateTime")]
@@ -1482,6 +2193,7 @@ pub struct ProvisionedThroughputDescription {
 
 /// <p>Represents a request to perform a <code>PutItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Put {
     /// <p>A condition that must be satisfied in order for a conditional update to succeed.</p>
     #[serde(rename = "ConditionExpression")]
@@ -1507,8 +2219,35 @@ pub struct Put {
     pub table_name: String,
 }
 
+impl Validate for Put {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the input of a <code>PutItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutItemInput {
     /// <p>A condition that must be satisfied in order for a conditional <code>PutItem</code> operation to succeed.</p> <p>An expression can contain any of the following:</p> <ul> <li> <p>Functions: <code>attribute_exists | attribute_not_exists | attribute_type | contains | begins_with | size</code> </p> <p>These function names are case-sensitive.</p> </li> <li> <p>Comparison operators: <code>= | &lt;&gt; | &lt; | &gt; | &lt;= | &gt;= | BETWEEN | IN </code> </p> </li> <li> <p> Logical operators: <code>AND | OR | NOT</code> </p> </li> </ul> <p>For more information on condition expressions, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.SpecifyingConditions.html">Condition Expressions</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionExpression")]
@@ -1549,9 +2288,35 @@ pub struct PutItemInput {
     pub table_name: String,
 }
 
+impl Validate for PutItemInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>PutItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutItemOutput {
     /// <p>The attribute values as they appeared before the <code>PutItem</code> operation, but only if <code>ReturnValues</code> is specified as <code>ALL_OLD</code> in the request. Each element consists of an attribute name and an attribute value.</p>
     #[serde(rename = "Attributes")]
@@ -1577,6 +2342,7 @@ pub struct PutRequest {
 
 /// <p>Represents the input of a <code>Query</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueryInput {
     /// <p>This is a legacy parameter. Use <code>ProjectionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.AttributesToGet.html">AttributesToGet</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "AttributesToGet")]
@@ -1646,9 +2412,59 @@ pub struct QueryInput {
     pub table_name: String,
 }
 
+impl Validate for QueryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes_to_get {
+            errors.check_length(
+                &field_path(path, "attributes_to_get"),
+                value.len(),
+                Some(1),
+                None,
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes_to_get"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        if let Some(ref value) = self.index_name {
+            errors.check_length(
+                &field_path(path, "index_name"),
+                value.chars().count(),
+                Some(3),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "index_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>Query</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QueryOutput {
     /// <p>The capacity units consumed by the <code>Query</code> operation. The data returned includes the total provisioned throughput consumed, along with statistics for the table and any indexes involved in the operation. <code>ConsumedCapacity</code> is only returned if the <code>ReturnConsumedCapacity</code> parameter was specified. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ProvisionedThroughputIntro.html">Provisioned Throughput</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConsumedCapacity")]
@@ -1683,7 +2499,7 @@ pub struct Replica {
 
 /// <p>Contains the details of the replica.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicaDescription {
     /// <p>The name of the region.</p>
     #[serde(rename = "RegionName")]
@@ -1693,7 +2509,7 @@ pub struct ReplicaDescription {
 
 /// <p>Represents the properties of a global secondary index.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicaGlobalSecondaryIndexSettingsDescription {
     /// <p>The name of the global secondary index. The name must be unique among all other indexes on this table.</p>
     #[serde(rename = "IndexName")]
@@ -1722,6 +2538,7 @@ pub struct ReplicaGlobalSecondaryIndexSettingsDescription {
 
 /// <p>Represents the settings of a global secondary index for a global table that will be modified.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplicaGlobalSecondaryIndexSettingsUpdate {
     /// <p>The name of the global secondary index. The name must be unique among all other indexes on this table.</p>
     #[serde(rename = "IndexName")]
@@ -1738,7 +2555,7 @@ pub struct ReplicaGlobalSecondaryIndexSettingsUpdate {
 
 /// <p>Represents the properties of a replica.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicaSettingsDescription {
     /// <p>The region name of the replica.</p>
     #[serde(rename = "RegionName")]
@@ -1778,6 +2595,7 @@ pub struct ReplicaSettingsDescription {
 
 /// <p>Represents the settings for a global table in a region that will be modified.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplicaSettingsUpdate {
     /// <p>The region of the replica to be added.</p>
     #[serde(rename = "RegionName")]
@@ -1798,8 +2616,22 @@ pub struct ReplicaSettingsUpdate {
     pub replica_provisioned_read_capacity_units: Option<i64>,
 }
 
+impl Validate for ReplicaSettingsUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.replica_provisioned_read_capacity_units {
+            errors.check_range(
+                &field_path(path, "replica_provisioned_read_capacity_units"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p><p>Represents one of the following:</p> <ul> <li> <p>A new replica to be added to an existing global table.</p> </li> <li> <p>New parameters for an existing replica.</p> </li> <li> <p>An existing replica to be removed from an existing global table.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplicaUpdate {
     /// <p>The parameters required for creating a replica on an existing global table.</p>
     #[serde(rename = "Create")]
@@ -1813,7 +2645,7 @@ pub struct ReplicaUpdate {
 
 /// <p>Contains details for the restore.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestoreSummary {
     /// <p>Point in time or source backup time.</p>
     #[serde(rename = "RestoreDateTime")]
@@ -1832,6 +2664,7 @@ pub struct RestoreSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreTableFromBackupInput {
     /// <p>The Amazon Resource Name (ARN) associated with the backup.</p>
     #[serde(rename = "BackupArn")]
@@ -1841,8 +2674,30 @@ pub struct RestoreTableFromBackupInput {
     pub target_table_name: String,
 }
 
+impl Validate for RestoreTableFromBackupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "backup_arn"),
+            self.backup_arn.chars().count(),
+            Some(37),
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "target_table_name"),
+            self.target_table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_table_name"),
+            &self.target_table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestoreTableFromBackupOutput {
     /// <p>The description of the table created from an existing backup.</p>
     #[serde(rename = "TableDescription")]
@@ -1851,6 +2706,7 @@ pub struct RestoreTableFromBackupOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreTableToPointInTimeInput {
     /// <p>Time in the past to restore the table to.</p>
     #[serde(rename = "RestoreDateTime")]
@@ -1868,8 +2724,35 @@ pub struct RestoreTableToPointInTimeInput {
     pub use_latest_restorable_time: Option<bool>,
 }
 
+impl Validate for RestoreTableToPointInTimeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "source_table_name"),
+            self.source_table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "source_table_name"),
+            &self.source_table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "target_table_name"),
+            self.target_table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_table_name"),
+            &self.target_table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestoreTableToPointInTimeOutput {
     /// <p>Represents the properties of a table.</p>
     #[serde(rename = "TableDescription")]
@@ -1879,7 +2762,7 @@ pub struct RestoreTableToPointInTimeOutput {
 
 /// <p>The description of the server-side encryption status on the specified table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SSEDescription {
     /// <p>The KMS customer master key (CMK) ARN used for the KMS encryption.</p>
     #[serde(rename = "KMSMasterKeyArn")]
@@ -1897,6 +2780,7 @@ pub struct SSEDescription {
 
 /// <p>Represents the settings used to enable server-side encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SSESpecification {
     /// <p>Indicates whether server-side encryption is done using an AWS managed CMK or an AWS owned CMK. If enabled (true), server-side encryption type is set to <code>KMS</code> and an AWS managed CMK is used (AWS KMS charges apply). If disabled (false) or not specified, server-side encryption is set to AWS owned CMK.</p>
     #[serde(rename = "Enabled")]
@@ -1914,6 +2798,7 @@ pub struct SSESpecification {
 
 /// <p>Represents the input of a <code>Scan</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScanInput {
     /// <p>This is a legacy parameter. Use <code>ProjectionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.AttributesToGet.html">AttributesToGet</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "AttributesToGet")]
@@ -1979,9 +2864,70 @@ pub struct ScanInput {
     pub total_segments: Option<i64>,
 }
 
+impl Validate for ScanInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes_to_get {
+            errors.check_length(
+                &field_path(path, "attributes_to_get"),
+                value.len(),
+                Some(1),
+                None,
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes_to_get"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        if let Some(ref value) = self.index_name {
+            errors.check_length(
+                &field_path(path, "index_name"),
+                value.chars().count(),
+                Some(3),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "index_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.segment {
+            errors.check_range(&field_path(path, "segment"), *value, None, Some(999999));
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.total_segments {
+            errors.check_range(
+                &field_path(path, "total_segments"),
+                *value,
+                Some(1),
+                Some(1000000),
+            );
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>Scan</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScanOutput {
     /// <p>The capacity units consumed by the <code>Scan</code> operation. The data returned includes the total provisioned throughput consumed, along with statistics for the table and any indexes involved in the operation. <code>ConsumedCapacity</code> is only returned if the <code>ReturnConsumedCapacity</code> parameter was specified. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ProvisionedThroughputIntro.html">Provisioned Throughput</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConsumedCapacity")]
@@ -2007,7 +2953,7 @@ pub struct ScanOutput {
 
 /// <p>Contains the details of the table when the backup was created. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SourceTableDetails {
     /// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. This setting can be changed later.</p> <ul> <li> <p> <code>PROVISIONED</code> - Sets the read/write capacity mode to <code>PROVISIONED</code>. We recommend using <code>PROVISIONED</code> for predictable workloads.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - Sets the read/write capacity mode to <code>PAY<em>PER</em>REQUEST</code>. We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. </p> </li> </ul></p>
     #[serde(rename = "BillingMode")]
@@ -2044,7 +2990,7 @@ pub struct SourceTableDetails {
 
 /// <p>Contains the details of the features enabled on the table when the backup was created. For example, LSIs, GSIs, streams, TTL. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SourceTableFeatureDetails {
     /// <p>Represents the GSI properties for the table when the backup was created. It includes the IndexName, KeySchema, Projection and ProvisionedThroughput for the GSIs on the table at the time of backup. </p>
     #[serde(rename = "GlobalSecondaryIndexes")]
@@ -2083,7 +3029,7 @@ pub struct StreamSpecification {
 
 /// <p>Represents the properties of a table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TableDescription {
     /// <p><p>An array of <code>AttributeDefinition</code> objects. Each of these objects describes one attribute in the table and index key schema.</p> <p>Each <code>AttributeDefinition</code> object in this array is composed of:</p> <ul> <li> <p> <code>AttributeName</code> - The name of the attribute.</p> </li> <li> <p> <code>AttributeType</code> - The data type for the attribute.</p> </li> </ul></p>
     #[serde(rename = "AttributeDefinitions")]
@@ -2170,7 +3116,25 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p>Identifies the Amazon DynamoDB resource to which tags should be added. This value is an Amazon Resource Name (ARN).</p>
     #[serde(rename = "ResourceArn")]
@@ -2180,11 +3144,26 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
-/// <p>The description of the Time to Live (TTL) status on the specified table. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct TimeToLiveDescription {
-    /// <p> The name of the TTL attribute for items in the table.</p>
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1283),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
+/// <p>The description of the Time to Live (TTL) status on the specified table. </p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct TimeToLiveDescription {
+    /// <p> The name of the TTL attribute for items in the table.</p>
     #[serde(rename = "AttributeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attribute_name: Option<String>,
@@ -2205,15 +3184,34 @@ pub struct TimeToLiveSpecification {
     pub enabled: bool,
 }
 
+impl Validate for TimeToLiveSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute_name"),
+            self.attribute_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 /// <p>Specifies an item to be retrieved as part of the transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TransactGetItem {
     /// <p>Contains the primary key that identifies the item to get, together with the name of the table that contains the item, and optionally the specific attributes of the item to retrieve.</p>
     #[serde(rename = "Get")]
     pub get: Get,
 }
 
+impl Validate for TransactGetItem {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.get.validate_at(&field_path(path, "get"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TransactGetItemsInput {
     /// <p>A value of <code>TOTAL</code> causes consumed capacity information to be returned, and a value of <code>NONE</code> prevents that information from being returned. No other value is valid.</p>
     #[serde(rename = "ReturnConsumedCapacity")]
@@ -2224,8 +3222,23 @@ pub struct TransactGetItemsInput {
     pub transact_items: Vec<TransactGetItem>,
 }
 
+impl Validate for TransactGetItemsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transact_items"),
+            self.transact_items.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.transact_items.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "transact_items"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TransactGetItemsOutput {
     /// <p>If the <i>ReturnConsumedCapacity</i> value was <code>TOTAL</code>, this is an array of <code>ConsumedCapacity</code> objects, one for each table addressed by <code>TransactGetItem</code> objects in the <i>TransactItems</i> parameter. These <code>ConsumedCapacity</code> objects report the read-capacity units consumed by the <code>TransactGetItems</code> call in that table.</p>
     #[serde(rename = "ConsumedCapacity")]
@@ -2239,6 +3252,7 @@ pub struct TransactGetItemsOutput {
 
 /// <p>A list of requests that can perform update, put, delete, or check operations on multiple items in one or more tables atomically.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TransactWriteItem {
     /// <p>A request to perform a check item operation.</p>
     #[serde(rename = "ConditionCheck")]
@@ -2258,7 +3272,25 @@ pub struct TransactWriteItem {
     pub update: Option<Update>,
 }
 
+impl Validate for TransactWriteItem {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.condition_check {
+            value.validate_at(&field_path(path, "condition_check"), errors);
+        }
+        if let Some(ref value) = self.delete {
+            value.validate_at(&field_path(path, "delete"), errors);
+        }
+        if let Some(ref value) = self.put {
+            value.validate_at(&field_path(path, "put"), errors);
+        }
+        if let Some(ref value) = self.update {
+            value.validate_at(&field_path(path, "update"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TransactWriteItemsInput {
     /// <p>Providing a <code>ClientRequestToken</code> makes the call to <code>TransactWriteItems</code> idempotent, meaning that multiple identical calls have the same effect as one single call.</p> <p>Although multiple identical calls using the same client request token produce the same result on the server (no side effects), the responses to the calls might not be the same. If the <code>ReturnConsumedCapacity&gt;</code> parameter is set, then the initial <code>TransactWriteItems</code> call returns the amount of write capacity units consumed in making the changes. Subsequent <code>TransactWriteItems</code> calls with the same client token return the number of read capacity units consumed in reading the item.</p> <p>A client request token is valid for 10 minutes after the first request that uses it is completed. After 10 minutes, any request with the same client token is treated as a new request. Do not resubmit the same request with the same client token for more than 10 minutes, or the result might not be idempotent.</p> <p>If you submit a request with the same client token but a change in other parameters within the 10-minute idempotency window, DynamoDB returns an <code>IdempotentParameterMismatch</code> exception.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -2276,8 +3308,31 @@ pub struct TransactWriteItemsInput {
     pub transact_items: Vec<TransactWriteItem>,
 }
 
+impl Validate for TransactWriteItemsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(36),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "transact_items"),
+            self.transact_items.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.transact_items.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "transact_items"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TransactWriteItemsOutput {
     /// <p>The capacity units consumed by the entire <code>TransactWriteItems</code> operation. The values of the list are ordered according to the ordering of the <code>TransactItems</code> request parameter. </p>
     #[serde(rename = "ConsumedCapacity")]
@@ -2291,6 +3346,7 @@ pub struct TransactWriteItemsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p>The DynamoDB resource that the tags will be removed from. This value is an Amazon Resource Name (ARN).</p>
     #[serde(rename = "ResourceArn")]
@@ -2300,8 +3356,24 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1283),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 /// <p>Represents a request to perform an <code>UpdateItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Update {
     /// <p>A condition that must be satisfied in order for a conditional update to succeed.</p>
     #[serde(rename = "ConditionExpression")]
@@ -2330,7 +3402,34 @@ pub struct Update {
     pub update_expression: String,
 }
 
+impl Validate for Update {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateContinuousBackupsInput {
     /// <p>Represents the settings used to enable point in time recovery.</p>
     #[serde(rename = "PointInTimeRecoverySpecification")]
@@ -2340,8 +3439,24 @@ pub struct UpdateContinuousBackupsInput {
     pub table_name: String,
 }
 
+impl Validate for UpdateContinuousBackupsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateContinuousBackupsOutput {
     /// <p>Represents the continuous backups and point in time recovery settings on the table.</p>
     #[serde(rename = "ContinuousBackupsDescription")]
@@ -2351,6 +3466,7 @@ pub struct UpdateContinuousBackupsOutput {
 
 /// <p>Represents the new provisioned throughput settings to be applied to a global secondary index.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGlobalSecondaryIndexAction {
     /// <p>The name of the global secondary index to be updated.</p>
     #[serde(rename = "IndexName")]
@@ -2360,7 +3476,26 @@ pub struct UpdateGlobalSecondaryIndexAction {
     pub provisioned_throughput: ProvisionedThroughput,
 }
 
+impl Validate for UpdateGlobalSecondaryIndexAction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "index_name"),
+            self.index_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "index_name"),
+            &self.index_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        self.provisioned_throughput
+            .validate_at(&field_path(path, "provisioned_throughput"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGlobalTableInput {
     /// <p>The global table name.</p>
     #[serde(rename = "GlobalTableName")]
@@ -2370,8 +3505,24 @@ pub struct UpdateGlobalTableInput {
     pub replica_updates: Vec<ReplicaUpdate>,
 }
 
+impl Validate for UpdateGlobalTableInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "global_table_name"),
+            self.global_table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "global_table_name"),
+            &self.global_table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGlobalTableOutput {
     /// <p>Contains the details of the global table.</p>
     #[serde(rename = "GlobalTableDescription")]
@@ -2380,6 +3531,7 @@ pub struct UpdateGlobalTableOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGlobalTableSettingsInput {
     /// <p>The billing mode of the global table. If <code>GlobalTableBillingMode</code> is not specified, the global table defaults to <code>PROVISIONED</code> capacity billing mode.</p>
     #[serde(rename = "GlobalTableBillingMode")]
@@ -2408,8 +3560,44 @@ pub struct UpdateGlobalTableSettingsInput {
     pub replica_settings_update: Option<Vec<ReplicaSettingsUpdate>>,
 }
 
+impl Validate for UpdateGlobalTableSettingsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "global_table_name"),
+            self.global_table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "global_table_name"),
+            &self.global_table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.global_table_provisioned_write_capacity_units {
+            errors.check_range(
+                &field_path(path, "global_table_provisioned_write_capacity_units"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.replica_settings_update {
+            errors.check_length(
+                &field_path(path, "replica_settings_update"),
+                value.len(),
+                Some(1),
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "replica_settings_update"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGlobalTableSettingsOutput {
     /// <p>The name of the global table.</p>
     #[serde(rename = "GlobalTableName")]
@@ -2423,6 +3611,7 @@ pub struct UpdateGlobalTableSettingsOutput {
 
 /// <p>Represents the input of an <code>UpdateItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateItemInput {
     /// <p>This is a legacy parameter. Use <code>UpdateExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.AttributeUpdates.html">AttributeUpdates</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "AttributeUpdates")]
@@ -2471,9 +3660,35 @@ pub struct UpdateItemInput {
     pub update_expression: Option<String>,
 }
 
+impl Validate for UpdateItemInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expression_attribute_names {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "expression_attribute_names"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(65535));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateItemOutput {
     /// <p>A map of attribute values as they appear before or after the <code>UpdateItem</code> operation, as determined by the <code>ReturnValues</code> parameter.</p> <p>The <code>Attributes</code> map is only present if <code>ReturnValues</code> was specified as something other than <code>NONE</code> in the request. Each element represents one attribute.</p>
     #[serde(rename = "Attributes")]
@@ -2491,6 +3706,7 @@ pub struct UpdateItemOutput {
 
 /// <p>Represents the input of an <code>UpdateTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTableInput {
     /// <p>An array of attributes that describe the key schema for the table and indexes. If you are adding a new global secondary index to the table, <code>AttributeDefinitions</code> must include the key element(s) of the new index.</p>
     #[serde(rename = "AttributeDefinitions")]
@@ -2521,9 +3737,44 @@ pub struct UpdateTableInput {
     pub table_name: String,
 }
 
+impl Validate for UpdateTableInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_definitions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attribute_definitions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.global_secondary_index_updates {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "global_secondary_index_updates"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.provisioned_throughput {
+            value.validate_at(&field_path(path, "provisioned_throughput"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTableOutput {
     /// <p>Represents the properties of the table.</p>
     #[serde(rename = "TableDescription")]
@@ -2533,6 +3784,7 @@ pub struct UpdateTableOutput {
 
 /// <p>Represents the input of an <code>UpdateTimeToLive</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTimeToLiveInput {
     /// <p>The name of the table to be configured.</p>
     #[serde(rename = "TableName")]
@@ -2542,8 +3794,26 @@ pub struct UpdateTimeToLiveInput {
     pub time_to_live_specification: TimeToLiveSpecification,
 }
 
+impl Validate for UpdateTimeToLiveInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        self.time_to_live_specification
+            .validate_at(&field_path(path, "time_to_live_specification"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTimeToLiveOutput {
     /// <p>Represents the output of an <code>UpdateTimeToLive</code> operation.</p>
     #[serde(rename = "TimeToLiveSpecification")]
@@ -2595,7 +3865,12 @@ impl BatchGetItemError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(BatchGetItemError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2655,7 +3930,12 @@ impl BatchWriteItemError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(BatchWriteItemError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2719,7 +3999,12 @@ impl CreateBackupError {
                 "TableNotFoundException" => {
                     return RusotoError::Service(CreateBackupError::TableNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2776,7 +4061,12 @@ impl CreateGlobalTableError {
                 "TableNotFoundException" => {
                     return RusotoError::Service(CreateGlobalTableError::TableNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2822,7 +4112,12 @@ impl CreateTableError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(CreateTableError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2872,7 +4167,12 @@ impl DeleteBackupError {
                 "LimitExceededException" => {
                     return RusotoError::Service(DeleteBackupError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2942,7 +4242,12 @@ impl DeleteItemError {
                 "TransactionConflictException" => {
                     return RusotoError::Service(DeleteItemError::TransactionConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2996,7 +4301,12 @@ impl DeleteTableError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteTableError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3037,7 +4347,12 @@ impl DescribeBackupError {
                 "InternalServerError" => {
                     return RusotoError::Service(DescribeBackupError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3080,7 +4395,12 @@ impl DescribeContinuousBackupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3108,7 +4428,12 @@ impl DescribeEndpointsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3148,7 +4473,12 @@ impl DescribeGlobalTableError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3193,7 +4523,12 @@ impl DescribeGlobalTableSettingsError {
                         DescribeGlobalTableSettingsError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3227,7 +4562,12 @@ impl DescribeLimitsError {
                 "InternalServerError" => {
                     return RusotoError::Service(DescribeLimitsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3265,7 +4605,12 @@ impl DescribeTableError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeTableError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3306,7 +4651,12 @@ impl DescribeTimeToLiveError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeTimeToLiveError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3357,7 +4707,12 @@ impl GetItemError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetItemError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3393,7 +4748,12 @@ impl ListBackupsError {
                 "InternalServerError" => {
                     return RusotoError::Service(ListBackupsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3428,7 +4788,12 @@ impl ListGlobalTablesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3461,7 +4826,12 @@ impl ListTablesError {
                 "InternalServerError" => {
                     return RusotoError::Service(ListTablesError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3501,7 +4871,12 @@ impl ListTagsOfResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListTagsOfResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3569,7 +4944,12 @@ impl PutItemError {
                 "TransactionConflictException" => {
                     return RusotoError::Service(PutItemError::TransactionConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3623,7 +5003,12 @@ impl QueryError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(QueryError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3692,7 +5077,12 @@ impl RestoreTableFromBackupError {
                 "TableInUseException" => {
                     return RusotoError::Service(RestoreTableFromBackupError::TableInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3774,7 +5164,12 @@ impl RestoreTableToPointInTimeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3828,7 +5223,12 @@ impl ScanError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ScanError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3879,7 +5279,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3936,7 +5341,12 @@ impl TransactGetItemsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4007,7 +5417,12 @@ impl TransactWriteItemsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4060,7 +5475,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4112,7 +5532,12 @@ impl UpdateContinuousBackupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4173,7 +5598,12 @@ impl UpdateGlobalTableError {
                 "TableNotFoundException" => {
                     return RusotoError::Service(UpdateGlobalTableError::TableNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4247,7 +5677,12 @@ impl UpdateGlobalTableSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4319,7 +5754,12 @@ impl UpdateItemError {
                 "TransactionConflictException" => {
                     return RusotoError::Service(UpdateItemError::TransactionConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4373,7 +5813,12 @@ impl UpdateTableError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateTableError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4426,7 +5871,12 @@ impl UpdateTimeToLiveError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateTimeToLiveError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4643,1046 +6093,3083 @@ pub trait DynamoDb {
         input: UpdateTimeToLiveInput,
     ) -> RusotoFuture<UpdateTimeToLiveOutput, UpdateTimeToLiveError>;
 }
-/// A client for the DynamoDB API.
-#[derive(Clone)]
-pub struct DynamoDbClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl DynamoDbClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> DynamoDbClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> DynamoDbClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbClient {
-        DynamoDbClient { client, region }
-    }
-}
-
-impl DynamoDb for DynamoDbClient {
-    /// <p>The <code>BatchGetItem</code> operation returns the attributes of one or more items from one or more tables. You identify requested items by primary key.</p> <p>A single operation can retrieve up to 16 MB of data, which can contain as many as 100 items. <code>BatchGetItem</code> returns a partial result if the response size limit is exceeded, the table's provisioned throughput is exceeded, or an internal processing failure occurs. If a partial result is returned, the operation returns a value for <code>UnprocessedKeys</code>. You can use this value to retry the operation starting with the next item to get.</p> <important> <p>If you request more than 100 items, <code>BatchGetItem</code> returns a <code>ValidationException</code> with the message "Too many items requested for the BatchGetItem call."</p> </important> <p>For example, if you ask to retrieve 100 items, but each individual item is 300 KB in size, the system returns 52 items (so as not to exceed the 16 MB limit). It also returns an appropriate <code>UnprocessedKeys</code> value so you can get the next page of results. If desired, your application can include its own logic to assemble the pages of results into one dataset.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchGetItem</code> returns a <code>ProvisionedThroughputExceededException</code>. If <i>at least one</i> of the items is successfully processed, then <code>BatchGetItem</code> completes successfully, while returning the keys of the unread items in <code>UnprocessedKeys</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>By default, <code>BatchGetItem</code> performs eventually consistent reads on every table in the request. If you want strongly consistent reads instead, you can set <code>ConsistentRead</code> to <code>true</code> for any or all tables.</p> <p>In order to minimize response latency, <code>BatchGetItem</code> retrieves items in parallel.</p> <p>When designing your application, keep in mind that DynamoDB does not return items in any particular order. To help parse the response by item, include the primary key values for the items in your request in the <code>ProjectionExpression</code> parameter.</p> <p>If a requested item does not exist, it is not returned in the result. Requests for nonexistent items consume the minimum read capacity units according to the type of read. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithTables.html#CapacityUnitCalculations">Working with Tables</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+const _: Option<&dyn DynamoDb> = None;
+impl<'a, T: DynamoDb + ?Sized> DynamoDb for &'a T {
     fn batch_get_item(
         &self,
         input: BatchGetItemInput,
     ) -> RusotoFuture<BatchGetItemOutput, BatchGetItemError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.BatchGetItem");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetItemError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_item(input)
     }
-
-    /// <p><p>The <code>BatchWriteItem</code> operation puts or deletes multiple items in one or more tables. A single call to <code>BatchWriteItem</code> can write up to 16 MB of data, which can comprise as many as 25 put or delete requests. Individual items to be written can be as large as 400 KB.</p> <note> <p> <code>BatchWriteItem</code> cannot update items. To update items, use the <code>UpdateItem</code> action.</p> </note> <p>The individual <code>PutItem</code> and <code>DeleteItem</code> operations specified in <code>BatchWriteItem</code> are atomic; however <code>BatchWriteItem</code> as a whole is not. If any requested operations fail because the table&#39;s provisioned throughput is exceeded or an internal processing failure occurs, the failed operations are returned in the <code>UnprocessedItems</code> response parameter. You can investigate and optionally resend the requests. Typically, you would call <code>BatchWriteItem</code> in a loop. Each iteration would check for unprocessed items and submit a new <code>BatchWriteItem</code> request with those unprocessed items until all items have been processed.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchWriteItem</code> returns a <code>ProvisionedThroughputExceededException</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#Programming.Errors.BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>With <code>BatchWriteItem</code>, you can efficiently write or delete large amounts of data, such as from Amazon EMR, or copy data from another database into DynamoDB. In order to improve performance with these large-scale operations, <code>BatchWriteItem</code> does not behave in the same way as individual <code>PutItem</code> and <code>DeleteItem</code> calls would. For example, you cannot specify conditions on individual put and delete requests, and <code>BatchWriteItem</code> does not return deleted items in the response.</p> <p>If you use a programming language that supports concurrency, you can use threads to write items in parallel. Your application must include the necessary logic to manage the threads. With languages that don&#39;t support threading, you must update or delete the specified items one at a time. In both situations, <code>BatchWriteItem</code> performs the specified put and delete operations in parallel, giving you the power of the thread pool approach without having to introduce complexity into your application.</p> <p>Parallel processing reduces latency, but each specified put and delete request consumes the same number of write capacity units whether it is processed in parallel or not. Delete operations on nonexistent items consume one write capacity unit.</p> <p>If one or more of the following is true, DynamoDB rejects the entire batch write operation:</p> <ul> <li> <p>One or more tables specified in the <code>BatchWriteItem</code> request does not exist.</p> </li> <li> <p>Primary key attributes specified on an item in the request do not match those in the corresponding table&#39;s primary key schema.</p> </li> <li> <p>You try to perform multiple operations on the same item in the same <code>BatchWriteItem</code> request. For example, you cannot put and delete the same item in the same <code>BatchWriteItem</code> request. </p> </li> <li> <p> Your request contains at least two items with identical hash and range keys (which essentially is two put operations). </p> </li> <li> <p>There are more than 25 requests in the batch.</p> </li> <li> <p>Any individual item in a batch exceeds 400 KB.</p> </li> <li> <p>The total request size exceeds 16 MB.</p> </li> </ul></p>
     fn batch_write_item(
         &self,
         input: BatchWriteItemInput,
     ) -> RusotoFuture<BatchWriteItemOutput, BatchWriteItemError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.BatchWriteItem");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchWriteItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchWriteItemError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_write_item(input)
     }
-
-    /// <p><p>Creates a backup for an existing table.</p> <p> Each time you create an on-demand backup, the entire table data is backed up. There is no limit to the number of on-demand backups that can be taken. </p> <p> When you create an on-demand backup, a time marker of the request is cataloged, and the backup is created asynchronously, by applying all changes until the time of the request to the last full table snapshot. Backup requests are processed instantaneously and become available for restore within minutes. </p> <p>You can call <code>CreateBackup</code> at a maximum rate of 50 times per second.</p> <p>All backups in DynamoDB work without consuming any provisioned throughput on the table.</p> <p> If you submit a backup request on 2018-12-14 at 14:25:00, the backup is guaranteed to contain all data committed to the table up to 14:24:00, and data committed after 14:26:00 will not be. The backup might contain data modifications made between 14:24:00 and 14:26:00. On-demand backup does not support causal consistency. </p> <p> Along with data, the following are also included on the backups: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Streams</p> </li> <li> <p>Provisioned read and write capacity</p> </li> </ul></p>
     fn create_backup(
         &self,
         input: CreateBackupInput,
     ) -> RusotoFuture<CreateBackupOutput, CreateBackupError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.CreateBackup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBackupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBackupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_backup(input)
     }
-
-    /// <p><p>Creates a global table from an existing table. A global table creates a replication relationship between two or more DynamoDB tables with the same table name in the provided Regions. </p> <p>If you want to add a new replica table to a global table, each of the following conditions must be true:</p> <ul> <li> <p>The table must have the same primary key as all of the other replicas.</p> </li> <li> <p>The table must have the same name as all of the other replicas.</p> </li> <li> <p>The table must have DynamoDB Streams enabled, with the stream containing both the new and the old images of the item.</p> </li> <li> <p>None of the replica tables in the global table can contain any data.</p> </li> </ul> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> </ul> <important> <p> Write capacity settings should be set consistently across your replica tables and secondary indexes. DynamoDB strongly recommends enabling auto scaling to manage the write capacity settings for all of your global tables replicas and indexes. </p> <p> If you prefer to manage write capacity settings manually, you should provision equal replicated write capacity units to your replica tables. You should also provision equal replicated write capacity units to matching secondary indexes across your global table. </p> </important></p>
     fn create_global_table(
         &self,
         input: CreateGlobalTableInput,
     ) -> RusotoFuture<CreateGlobalTableOutput, CreateGlobalTableError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.CreateGlobalTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGlobalTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGlobalTableError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_global_table(input)
     }
-
-    /// <p>The <code>CreateTable</code> operation adds a new table to your account. In an AWS account, table names must be unique within each Region. That is, you can have two tables with same name if you create the tables in different Regions.</p> <p> <code>CreateTable</code> is an asynchronous operation. Upon receiving a <code>CreateTable</code> request, DynamoDB immediately returns a response with a <code>TableStatus</code> of <code>CREATING</code>. After the table is created, DynamoDB sets the <code>TableStatus</code> to <code>ACTIVE</code>. You can perform read and write operations only on an <code>ACTIVE</code> table. </p> <p>You can optionally define secondary indexes on the new table, as part of the <code>CreateTable</code> operation. If you want to create multiple tables with secondary indexes on them, you must create the tables sequentially. Only one table with secondary indexes can be in the <code>CREATING</code> state at any given time.</p> <p>You can use the <code>DescribeTable</code> action to check the table status.</p>
     fn create_table(
         &self,
         input: CreateTableInput,
     ) -> RusotoFuture<CreateTableOutput, CreateTableError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.CreateTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTableError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_table(input)
     }
-
-    /// <p>Deletes an existing backup of a table.</p> <p>You can call <code>DeleteBackup</code> at a maximum rate of 10 times per second.</p>
     fn delete_backup(
         &self,
         input: DeleteBackupInput,
     ) -> RusotoFuture<DeleteBackupOutput, DeleteBackupError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.DeleteBackup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBackupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBackupError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_backup(input)
     }
-
-    /// <p>Deletes a single item in a table by primary key. You can perform a conditional delete operation that deletes the item if it exists, or if it has an expected attribute value.</p> <p>In addition to deleting an item, you can also return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <p>Unless you specify conditions, the <code>DeleteItem</code> is an idempotent operation; running it multiple times on the same item or attribute does <i>not</i> result in an error response.</p> <p>Conditional deletes are useful for deleting items only if specific conditions are met. If those conditions are met, DynamoDB performs the delete. Otherwise, the item is not deleted.</p>
     fn delete_item(
         &self,
         input: DeleteItemInput,
     ) -> RusotoFuture<DeleteItemOutput, DeleteItemError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.DeleteItem");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteItemError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_item(input)
     }
-
-    /// <p>The <code>DeleteTable</code> operation deletes a table and all of its items. After a <code>DeleteTable</code> request, the specified table is in the <code>DELETING</code> state until DynamoDB completes the deletion. If the table is in the <code>ACTIVE</code> state, you can delete it. If a table is in <code>CREATING</code> or <code>UPDATING</code> states, then DynamoDB returns a <code>ResourceInUseException</code>. If the specified table does not exist, DynamoDB returns a <code>ResourceNotFoundException</code>. If table is already in the <code>DELETING</code> state, no error is returned. </p> <note> <p>DynamoDB might continue to accept data read and write operations, such as <code>GetItem</code> and <code>PutItem</code>, on a table in the <code>DELETING</code> state until the table deletion is complete.</p> </note> <p>When you delete a table, any indexes on that table are also deleted.</p> <p>If you have DynamoDB Streams enabled on the table, then the corresponding stream on that table goes into the <code>DISABLED</code> state, and the stream is automatically deleted after 24 hours.</p> <p>Use the <code>DescribeTable</code> action to check the status of the table. </p>
     fn delete_table(
         &self,
         input: DeleteTableInput,
     ) -> RusotoFuture<DeleteTableOutput, DeleteTableError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.DeleteTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTableError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_table(input)
     }
-
-    /// <p>Describes an existing backup of a table.</p> <p>You can call <code>DescribeBackup</code> at a maximum rate of 10 times per second.</p>
     fn describe_backup(
         &self,
         input: DescribeBackupInput,
     ) -> RusotoFuture<DescribeBackupOutput, DescribeBackupError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeBackup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBackupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeBackupError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_backup(input)
     }
-
-    /// <p>Checks the status of continuous backups and point in time recovery on the specified table. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> After continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p> <p>You can call <code>DescribeContinuousBackups</code> at a maximum rate of 10 times per second.</p>
     fn describe_continuous_backups(
         &self,
         input: DescribeContinuousBackupsInput,
     ) -> RusotoFuture<DescribeContinuousBackupsOutput, DescribeContinuousBackupsError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DynamoDB_20120810.DescribeContinuousBackups",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeContinuousBackupsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeContinuousBackupsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_continuous_backups(input)
     }
-
-    /// <p>Returns the regional endpoint information.</p>
     fn describe_endpoints(
         &self,
     ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeEndpoints");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEndpointsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeEndpointsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_endpoints()
     }
-
-    /// <p>Returns information about the specified global table.</p>
     fn describe_global_table(
         &self,
         input: DescribeGlobalTableInput,
     ) -> RusotoFuture<DescribeGlobalTableOutput, DescribeGlobalTableError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeGlobalTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGlobalTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeGlobalTableError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_global_table(input)
     }
-
-    /// <p>Describes Region-specific settings for a global table.</p>
     fn describe_global_table_settings(
         &self,
         input: DescribeGlobalTableSettingsInput,
     ) -> RusotoFuture<DescribeGlobalTableSettingsOutput, DescribeGlobalTableSettingsError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DynamoDB_20120810.DescribeGlobalTableSettings",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGlobalTableSettingsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeGlobalTableSettingsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_global_table_settings(input)
     }
-
-    /// <p>Returns the current provisioned-capacity limits for your AWS account in a Region, both for the Region as a whole and for any one DynamoDB table that you create there.</p> <p>When you establish an AWS account, the account has initial limits on the maximum read capacity units and write capacity units that you can provision across all of your DynamoDB tables in a given Region. Also, there are per-table limits that apply when you create a table there. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> page in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p>Although you can increase these limits by filing a case at <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>, obtaining the increase is not instantaneous. The <code>DescribeLimits</code> action lets you write code to compare the capacity you are currently using to those limits imposed by your account so that you have enough time to apply for an increase before you hit a limit.</p> <p>For example, you could use one of the AWS SDKs to do the following:</p> <ol> <li> <p>Call <code>DescribeLimits</code> for a particular Region to obtain your current account limits on provisioned capacity there.</p> </li> <li> <p>Create a variable to hold the aggregate read capacity units provisioned for all your tables in that Region, and one to hold the aggregate write capacity units. Zero them both.</p> </li> <li> <p>Call <code>ListTables</code> to obtain a list of all your DynamoDB tables.</p> </li> <li> <p>For each table name listed by <code>ListTables</code>, do the following:</p> <ul> <li> <p>Call <code>DescribeTable</code> with the table name.</p> </li> <li> <p>Use the data returned by <code>DescribeTable</code> to add the read capacity units and write capacity units provisioned for the table itself to your variables.</p> </li> <li> <p>If the table has one or more global secondary indexes (GSIs), loop over these GSIs and add their provisioned capacity values to your variables as well.</p> </li> </ul> </li> <li> <p>Report the account limits for that Region returned by <code>DescribeLimits</code>, along with the total current provisioned capacity levels you have calculated.</p> </li> </ol> <p>This will let you see whether you are getting close to your account-level limits.</p> <p>The per-table limits apply only when you are creating a new table. They restrict the sum of the provisioned capacity of the new table itself and all its global secondary indexes.</p> <p>For existing tables and their GSIs, DynamoDB doesn't let you increase provisioned capacity extremely rapidly. But the only upper limit that applies is that the aggregate provisioned capacity over all your tables and GSIs cannot exceed either of the per-account limits.</p> <note> <p> <code>DescribeLimits</code> should only be called periodically. You can expect throttling errors if you call it more than once in a minute.</p> </note> <p>The <code>DescribeLimits</code> Request element has no content.</p>
     fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeLimits");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeLimitsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeLimitsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_limits()
     }
-
-    /// <p><p>Returns information about the table, including the current status of the table, when it was created, the primary key schema, and any indexes on the table.</p> <note> <p>If you issue a <code>DescribeTable</code> request immediately after a <code>CreateTable</code> request, DynamoDB might return a <code>ResourceNotFoundException</code>. This is because <code>DescribeTable</code> uses an eventually consistent query, and the metadata for your table might not be available at that moment. Wait for a few seconds, and then try the <code>DescribeTable</code> request again.</p> </note></p>
     fn describe_table(
         &self,
         input: DescribeTableInput,
     ) -> RusotoFuture<DescribeTableOutput, DescribeTableError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTableError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_table(input)
     }
-
-    /// <p>Gives a description of the Time to Live (TTL) status on the specified table. </p>
     fn describe_time_to_live(
         &self,
         input: DescribeTimeToLiveInput,
     ) -> RusotoFuture<DescribeTimeToLiveOutput, DescribeTimeToLiveError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTimeToLive");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTimeToLiveOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTimeToLiveError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_time_to_live(input)
     }
-
-    /// <p>The <code>GetItem</code> operation returns a set of attributes for the item with the given primary key. If there is no matching item, <code>GetItem</code> does not return any data and there will be no <code>Item</code> element in the response.</p> <p> <code>GetItem</code> provides an eventually consistent read by default. If your application requires a strongly consistent read, set <code>ConsistentRead</code> to <code>true</code>. Although a strongly consistent read might take more time than an eventually consistent read, it always returns the last updated value.</p>
     fn get_item(&self, input: GetItemInput) -> RusotoFuture<GetItemOutput, GetItemError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.GetItem");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetItemError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_item(input)
     }
-
-    /// <p>List backups associated with an AWS account. To list backups for a given table, specify <code>TableName</code>. <code>ListBackups</code> returns a paginated list of results with at most 1 MB worth of items in a page. You can also specify a limit for the maximum number of entries to be returned in a page. </p> <p>In the request, start time is inclusive, but end time is exclusive. Note that these limits are for the time at which the original backup was requested.</p> <p>You can call <code>ListBackups</code> a maximum of five times per second.</p>
     fn list_backups(
         &self,
         input: ListBackupsInput,
     ) -> RusotoFuture<ListBackupsOutput, ListBackupsError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.ListBackups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBackupsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListBackupsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_backups(input)
     }
-
-    /// <p>Lists all global tables that have a replica in the specified Region.</p>
     fn list_global_tables(
         &self,
         input: ListGlobalTablesInput,
     ) -> RusotoFuture<ListGlobalTablesOutput, ListGlobalTablesError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.ListGlobalTables");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGlobalTablesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGlobalTablesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_global_tables(input)
     }
-
-    /// <p>Returns an array of table names associated with the current account and endpoint. The output from <code>ListTables</code> is paginated, with each page returning a maximum of 100 table names.</p>
     fn list_tables(
         &self,
         input: ListTablesInput,
     ) -> RusotoFuture<ListTablesOutput, ListTablesError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.ListTables");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTablesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTablesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tables(input)
     }
-
-    /// <p>List all tags on an Amazon DynamoDB resource. You can call ListTagsOfResource up to 10 times per second, per account.</p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     fn list_tags_of_resource(
         &self,
         input: ListTagsOfResourceInput,
     ) -> RusotoFuture<ListTagsOfResourceOutput, ListTagsOfResourceError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.ListTagsOfResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsOfResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTagsOfResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tags_of_resource(input)
     }
-
-    /// <p>Creates a new item, or replaces an old item with a new item. If an item that has the same primary key as the new item already exists in the specified table, the new item completely replaces the existing item. You can perform a conditional put operation (add a new item if one with the specified primary key doesn't exist), or replace an existing item if it has certain attribute values. You can return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <important> <p>This topic provides general information about the <code>PutItem</code> API.</p> <p>For information on how to call the <code>PutItem</code> API using the AWS SDK in specific languages, see the following:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/goto/aws-cli/dynamodb-2012-08-10/PutItem"> PutItem in the AWS Command Line Interface</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/DotNetSDKV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for .NET</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForCpp/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for C++</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForGoV1/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Go</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForJava/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Java</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/AWSJavaScriptSDK/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for JavaScript</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForPHPV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for PHP V3</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/boto3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Python</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForRubyV2/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Ruby V2</a> </p> </li> </ul> </important> <p>When you add an item, the primary key attributes are the only required attributes. Attribute values cannot be null. String and Binary type attributes must have lengths greater than zero. Set type attributes cannot be empty. Requests with empty values will be rejected with a <code>ValidationException</code> exception.</p> <note> <p>To prevent a new item from replacing an existing item, use a conditional expression that contains the <code>attribute_not_exists</code> function with the name of the attribute being used as the partition key for the table. Since every record must contain that attribute, the <code>attribute_not_exists</code> function will only succeed if no matching item exists.</p> </note> <p>For more information about <code>PutItem</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithItems.html">Working with Items</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     fn put_item(&self, input: PutItemInput) -> RusotoFuture<PutItemOutput, PutItemError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.PutItem");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<PutItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutItemError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_item(input)
     }
-
-    /// <p>The <code>Query</code> operation finds items based on primary key values. You can query any table or secondary index that has a composite primary key (a partition key and a sort key). </p> <p>Use the <code>KeyConditionExpression</code> parameter to provide a specific value for the partition key. The <code>Query</code> operation will return all of the items from the table or index with that partition key value. You can optionally narrow the scope of the <code>Query</code> operation by specifying a sort key value and a comparison operator in <code>KeyConditionExpression</code>. To further refine the <code>Query</code> results, you can optionally provide a <code>FilterExpression</code>. A <code>FilterExpression</code> determines which items within the results should be returned to you. All of the other results are discarded. </p> <p> A <code>Query</code> operation always returns a result set. If no matching items are found, the result set will be empty. Queries that do not return results consume the minimum number of read capacity units for that type of read operation. </p> <note> <p> DynamoDB calculates the number of read capacity units consumed based on item size, not on the amount of data that is returned to an application. The number of capacity units consumed will be the same whether you request all of the attributes (the default behavior) or just some of them (using a projection expression). The number will also be the same whether or not you use a <code>FilterExpression</code>. </p> </note> <p> <code>Query</code> results are always sorted by the sort key value. If the data type of the sort key is Number, the results are returned in numeric order; otherwise, the results are returned in order of UTF-8 bytes. By default, the sort order is ascending. To reverse the order, set the <code>ScanIndexForward</code> parameter to false. </p> <p> A single <code>Query</code> operation will read up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you will need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Query.html#Query.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>FilterExpression</code> is applied after a <code>Query</code> finishes, but before the results are returned. A <code>FilterExpression</code> cannot contain partition key or sort key attributes. You need to specify those attributes in the <code>KeyConditionExpression</code>. </p> <note> <p> A <code>Query</code> operation can return an empty result set and a <code>LastEvaluatedKey</code> if all the items read for the page of results are filtered out. </p> </note> <p>You can query a table, a local secondary index, or a global secondary index. For a query on a table or on a local secondary index, you can set the <code>ConsistentRead</code> parameter to <code>true</code> and obtain a strongly consistent result. Global secondary indexes support eventually consistent reads only, so do not specify <code>ConsistentRead</code> when querying a global secondary index.</p>
     fn query(&self, input: QueryInput) -> RusotoFuture<QueryOutput, QueryError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.Query");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<QueryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(QueryError::from_response(response))),
-                )
-            }
-        })
+        (**self).query(input)
     }
-
-    /// <p><p>Creates a new table from an existing backup. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p>You can call <code>RestoreTableFromBackup</code> at a maximum rate of 10 times per second.</p> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> </ul></p>
     fn restore_table_from_backup(
         &self,
         input: RestoreTableFromBackupInput,
     ) -> RusotoFuture<RestoreTableFromBackupOutput, RestoreTableFromBackupError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.RestoreTableFromBackup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestoreTableFromBackupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RestoreTableFromBackupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).restore_table_from_backup(input)
     }
-
-    /// <p><p>Restores the specified table to the specified point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. You can restore your table to any point in time during the last 35 days. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p> When you restore using point in time recovery, DynamoDB restores your table data to the state based on the selected date and time (day:hour:minute:second) to a new table. </p> <p> Along with data, the following are also included on the new restored table using point in time recovery: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Provisioned read and write capacity</p> </li> <li> <p>Encryption settings</p> <important> <p> All these settings come from the current settings of the source table at the time of restore. </p> </important> </li> </ul> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> <li> <p>Point in time recovery settings</p> </li> </ul></p>
     fn restore_table_to_point_in_time(
         &self,
         input: RestoreTableToPointInTimeInput,
     ) -> RusotoFuture<RestoreTableToPointInTimeOutput, RestoreTableToPointInTimeError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DynamoDB_20120810.RestoreTableToPointInTime",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestoreTableToPointInTimeOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RestoreTableToPointInTimeError::from_response(response))
-                }))
-            }
-        })
+        (**self).restore_table_to_point_in_time(input)
+    }
+    fn scan(&self, input: ScanInput) -> RusotoFuture<ScanOutput, ScanError> {
+        (**self).scan(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn transact_get_items(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> RusotoFuture<TransactGetItemsOutput, TransactGetItemsError> {
+        (**self).transact_get_items(input)
+    }
+    fn transact_write_items(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> RusotoFuture<TransactWriteItemsOutput, TransactWriteItemsError> {
+        (**self).transact_write_items(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_continuous_backups(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> RusotoFuture<UpdateContinuousBackupsOutput, UpdateContinuousBackupsError> {
+        (**self).update_continuous_backups(input)
+    }
+    fn update_global_table(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> RusotoFuture<UpdateGlobalTableOutput, UpdateGlobalTableError> {
+        (**self).update_global_table(input)
+    }
+    fn update_global_table_settings(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> RusotoFuture<UpdateGlobalTableSettingsOutput, UpdateGlobalTableSettingsError> {
+        (**self).update_global_table_settings(input)
+    }
+    fn update_item(
+        &self,
+        input: UpdateItemInput,
+    ) -> RusotoFuture<UpdateItemOutput, UpdateItemError> {
+        (**self).update_item(input)
+    }
+    fn update_table(
+        &self,
+        input: UpdateTableInput,
+    ) -> RusotoFuture<UpdateTableOutput, UpdateTableError> {
+        (**self).update_table(input)
+    }
+    fn update_time_to_live(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> RusotoFuture<UpdateTimeToLiveOutput, UpdateTimeToLiveError> {
+        (**self).update_time_to_live(input)
+    }
+}
+impl<T: DynamoDb + ?Sized> DynamoDb for Box<T> {
+    fn batch_get_item(
+        &self,
+        input: BatchGetItemInput,
+    ) -> RusotoFuture<BatchGetItemOutput, BatchGetItemError> {
+        (**self).batch_get_item(input)
+    }
+    fn batch_write_item(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> RusotoFuture<BatchWriteItemOutput, BatchWriteItemError> {
+        (**self).batch_write_item(input)
+    }
+    fn create_backup(
+        &self,
+        input: CreateBackupInput,
+    ) -> RusotoFuture<CreateBackupOutput, CreateBackupError> {
+        (**self).create_backup(input)
+    }
+    fn create_global_table(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> RusotoFuture<CreateGlobalTableOutput, CreateGlobalTableError> {
+        (**self).create_global_table(input)
+    }
+    fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> RusotoFuture<CreateTableOutput, CreateTableError> {
+        (**self).create_table(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupInput,
+    ) -> RusotoFuture<DeleteBackupOutput, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> RusotoFuture<DeleteItemOutput, DeleteItemError> {
+        (**self).delete_item(input)
+    }
+    fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> RusotoFuture<DeleteTableOutput, DeleteTableError> {
+        (**self).delete_table(input)
+    }
+    fn describe_backup(
+        &self,
+        input: DescribeBackupInput,
+    ) -> RusotoFuture<DescribeBackupOutput, DescribeBackupError> {
+        (**self).describe_backup(input)
+    }
+    fn describe_continuous_backups(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> RusotoFuture<DescribeContinuousBackupsOutput, DescribeContinuousBackupsError> {
+        (**self).describe_continuous_backups(input)
+    }
+    fn describe_endpoints(
+        &self,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        (**self).describe_endpoints()
+    }
+    fn describe_global_table(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> RusotoFuture<DescribeGlobalTableOutput, DescribeGlobalTableError> {
+        (**self).describe_global_table(input)
+    }
+    fn describe_global_table_settings(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> RusotoFuture<DescribeGlobalTableSettingsOutput, DescribeGlobalTableSettingsError> {
+        (**self).describe_global_table_settings(input)
+    }
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        (**self).describe_limits()
+    }
+    fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> RusotoFuture<DescribeTableOutput, DescribeTableError> {
+        (**self).describe_table(input)
+    }
+    fn describe_time_to_live(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> RusotoFuture<DescribeTimeToLiveOutput, DescribeTimeToLiveError> {
+        (**self).describe_time_to_live(input)
+    }
+    fn get_item(&self, input: GetItemInput) -> RusotoFuture<GetItemOutput, GetItemError> {
+        (**self).get_item(input)
+    }
+    fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> RusotoFuture<ListBackupsOutput, ListBackupsError> {
+        (**self).list_backups(input)
+    }
+    fn list_global_tables(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> RusotoFuture<ListGlobalTablesOutput, ListGlobalTablesError> {
+        (**self).list_global_tables(input)
+    }
+    fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> RusotoFuture<ListTablesOutput, ListTablesError> {
+        (**self).list_tables(input)
+    }
+    fn list_tags_of_resource(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> RusotoFuture<ListTagsOfResourceOutput, ListTagsOfResourceError> {
+        (**self).list_tags_of_resource(input)
+    }
+    fn put_item(&self, input: PutItemInput) -> RusotoFuture<PutItemOutput, PutItemError> {
+        (**self).put_item(input)
+    }
+    fn query(&self, input: QueryInput) -> RusotoFuture<QueryOutput, QueryError> {
+        (**self).query(input)
+    }
+    fn restore_table_from_backup(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> RusotoFuture<RestoreTableFromBackupOutput, RestoreTableFromBackupError> {
+        (**self).restore_table_from_backup(input)
+    }
+    fn restore_table_to_point_in_time(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> RusotoFuture<RestoreTableToPointInTimeOutput, RestoreTableToPointInTimeError> {
+        (**self).restore_table_to_point_in_time(input)
+    }
+    fn scan(&self, input: ScanInput) -> RusotoFuture<ScanOutput, ScanError> {
+        (**self).scan(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn transact_get_items(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> RusotoFuture<TransactGetItemsOutput, TransactGetItemsError> {
+        (**self).transact_get_items(input)
+    }
+    fn transact_write_items(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> RusotoFuture<TransactWriteItemsOutput, TransactWriteItemsError> {
+        (**self).transact_write_items(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_continuous_backups(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> RusotoFuture<UpdateContinuousBackupsOutput, UpdateContinuousBackupsError> {
+        (**self).update_continuous_backups(input)
+    }
+    fn update_global_table(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> RusotoFuture<UpdateGlobalTableOutput, UpdateGlobalTableError> {
+        (**self).update_global_table(input)
+    }
+    fn update_global_table_settings(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> RusotoFuture<UpdateGlobalTableSettingsOutput, UpdateGlobalTableSettingsError> {
+        (**self).update_global_table_settings(input)
+    }
+    fn update_item(
+        &self,
+        input: UpdateItemInput,
+    ) -> RusotoFuture<UpdateItemOutput, UpdateItemError> {
+        (**self).update_item(input)
+    }
+    fn update_table(
+        &self,
+        input: UpdateTableInput,
+    ) -> RusotoFuture<UpdateTableOutput, UpdateTableError> {
+        (**self).update_table(input)
+    }
+    fn update_time_to_live(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> RusotoFuture<UpdateTimeToLiveOutput, UpdateTimeToLiveError> {
+        (**self).update_time_to_live(input)
+    }
+}
+impl<T: DynamoDb + ?Sized> DynamoDb for ::std::sync::Arc<T> {
+    fn batch_get_item(
+        &self,
+        input: BatchGetItemInput,
+    ) -> RusotoFuture<BatchGetItemOutput, BatchGetItemError> {
+        (**self).batch_get_item(input)
+    }
+    fn batch_write_item(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> RusotoFuture<BatchWriteItemOutput, BatchWriteItemError> {
+        (**self).batch_write_item(input)
+    }
+    fn create_backup(
+        &self,
+        input: CreateBackupInput,
+    ) -> RusotoFuture<CreateBackupOutput, CreateBackupError> {
+        (**self).create_backup(input)
+    }
+    fn create_global_table(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> RusotoFuture<CreateGlobalTableOutput, CreateGlobalTableError> {
+        (**self).create_global_table(input)
+    }
+    fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> RusotoFuture<CreateTableOutput, CreateTableError> {
+        (**self).create_table(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupInput,
+    ) -> RusotoFuture<DeleteBackupOutput, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> RusotoFuture<DeleteItemOutput, DeleteItemError> {
+        (**self).delete_item(input)
+    }
+    fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> RusotoFuture<DeleteTableOutput, DeleteTableError> {
+        (**self).delete_table(input)
+    }
+    fn describe_backup(
+        &self,
+        input: DescribeBackupInput,
+    ) -> RusotoFuture<DescribeBackupOutput, DescribeBackupError> {
+        (**self).describe_backup(input)
+    }
+    fn describe_continuous_backups(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> RusotoFuture<DescribeContinuousBackupsOutput, DescribeContinuousBackupsError> {
+        (**self).describe_continuous_backups(input)
+    }
+    fn describe_endpoints(
+        &self,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        (**self).describe_endpoints()
+    }
+    fn describe_global_table(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> RusotoFuture<DescribeGlobalTableOutput, DescribeGlobalTableError> {
+        (**self).describe_global_table(input)
+    }
+    fn describe_global_table_settings(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> RusotoFuture<DescribeGlobalTableSettingsOutput, DescribeGlobalTableSettingsError> {
+        (**self).describe_global_table_settings(input)
+    }
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        (**self).describe_limits()
+    }
+    fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> RusotoFuture<DescribeTableOutput, DescribeTableError> {
+        (**self).describe_table(input)
+    }
+    fn describe_time_to_live(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> RusotoFuture<DescribeTimeToLiveOutput, DescribeTimeToLiveError> {
+        (**self).describe_time_to_live(input)
+    }
+    fn get_item(&self, input: GetItemInput) -> RusotoFuture<GetItemOutput, GetItemError> {
+        (**self).get_item(input)
+    }
+    fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> RusotoFuture<ListBackupsOutput, ListBackupsError> {
+        (**self).list_backups(input)
+    }
+    fn list_global_tables(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> RusotoFuture<ListGlobalTablesOutput, ListGlobalTablesError> {
+        (**self).list_global_tables(input)
+    }
+    fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> RusotoFuture<ListTablesOutput, ListTablesError> {
+        (**self).list_tables(input)
+    }
+    fn list_tags_of_resource(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> RusotoFuture<ListTagsOfResourceOutput, ListTagsOfResourceError> {
+        (**self).list_tags_of_resource(input)
+    }
+    fn put_item(&self, input: PutItemInput) -> RusotoFuture<PutItemOutput, PutItemError> {
+        (**self).put_item(input)
+    }
+    fn query(&self, input: QueryInput) -> RusotoFuture<QueryOutput, QueryError> {
+        (**self).query(input)
+    }
+    fn restore_table_from_backup(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> RusotoFuture<RestoreTableFromBackupOutput, RestoreTableFromBackupError> {
+        (**self).restore_table_from_backup(input)
+    }
+    fn restore_table_to_point_in_time(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> RusotoFuture<RestoreTableToPointInTimeOutput, RestoreTableToPointInTimeError> {
+        (**self).restore_table_to_point_in_time(input)
+    }
+    fn scan(&self, input: ScanInput) -> RusotoFuture<ScanOutput, ScanError> {
+        (**self).scan(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn transact_get_items(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> RusotoFuture<TransactGetItemsOutput, TransactGetItemsError> {
+        (**self).transact_get_items(input)
+    }
+    fn transact_write_items(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> RusotoFuture<TransactWriteItemsOutput, TransactWriteItemsError> {
+        (**self).transact_write_items(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_continuous_backups(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> RusotoFuture<UpdateContinuousBackupsOutput, UpdateContinuousBackupsError> {
+        (**self).update_continuous_backups(input)
+    }
+    fn update_global_table(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> RusotoFuture<UpdateGlobalTableOutput, UpdateGlobalTableError> {
+        (**self).update_global_table(input)
+    }
+    fn update_global_table_settings(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> RusotoFuture<UpdateGlobalTableSettingsOutput, UpdateGlobalTableSettingsError> {
+        (**self).update_global_table_settings(input)
+    }
+    fn update_item(
+        &self,
+        input: UpdateItemInput,
+    ) -> RusotoFuture<UpdateItemOutput, UpdateItemError> {
+        (**self).update_item(input)
+    }
+    fn update_table(
+        &self,
+        input: UpdateTableInput,
+    ) -> RusotoFuture<UpdateTableOutput, UpdateTableError> {
+        (**self).update_table(input)
+    }
+    fn update_time_to_live(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> RusotoFuture<UpdateTimeToLiveOutput, UpdateTimeToLiveError> {
+        (**self).update_time_to_live(input)
+    }
+}
+/// A client for the DynamoDB API.
+#[derive(Clone)]
+pub struct DynamoDbClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl DynamoDbClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DynamoDbClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DynamoDbClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbClient {
+        DynamoDbClient { client, region }
+    }
+}
+
+impl DynamoDb for DynamoDbClient {
+    /// <p>The <code>BatchGetItem</code> operation returns the attributes of one or more items from one or more tables. You identify requested items by primary key.</p> <p>A single operation can retrieve up to 16 MB of data, which can contain as many as 100 items. <code>BatchGetItem</code> returns a partial result if the response size limit is exceeded, the table's provisioned throughput is exceeded, or an internal processing failure occurs. If a partial result is returned, the operation returns a value for <code>UnprocessedKeys</code>. You can use this value to retry the operation starting with the next item to get.</p> <important> <p>If you request more than 100 items, <code>BatchGetItem</code> returns a <code>ValidationException</code> with the message "Too many items requested for the BatchGetItem call."</p> </important> <p>For example, if you ask to retrieve 100 items, but each individual item is 300 KB in size, the system returns 52 items (so as not to exceed the 16 MB limit). It also returns an appropriate <code>UnprocessedKeys</code> value so you can get the next page of results. If desired, your application can include its own logic to assemble the pages of results into one dataset.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchGetItem</code> returns a <code>ProvisionedThroughputExceededException</code>. If <i>at least one</i> of the items is successfully processed, then <code>BatchGetItem</code> completes successfully, while returning the keys of the unread items in <code>UnprocessedKeys</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>By default, <code>BatchGetItem</code> performs eventually consistent reads on every table in the request. If you want strongly consistent reads instead, you can set <code>ConsistentRead</code> to <code>true</code> for any or all tables.</p> <p>In order to minimize response latency, <code>BatchGetItem</code> retrieves items in parallel.</p> <p>When designing your application, keep in mind that DynamoDB does not return items in any particular order. To help parse the response by item, include the primary key values for the items in your request in the <code>ProjectionExpression</code> parameter.</p> <p>If a requested item does not exist, it is not returned in the result. Requests for nonexistent items consume the minimum read capacity units according to the type of read. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithTables.html#CapacityUnitCalculations">Working with Tables</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    fn batch_get_item(
+        &self,
+        input: BatchGetItemInput,
+    ) -> RusotoFuture<BatchGetItemOutput, BatchGetItemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("BatchGetItem");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.BatchGetItem");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetItemOutput, _>(
+                    response,
+                    "BatchGetItem",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetItemError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>The <code>BatchWriteItem</code> operation puts or deletes multiple items in one or more tables. A single call to <code>BatchWriteItem</code> can write up to 16 MB of data, which can comprise as many as 25 put or delete requests. Individual items to be written can be as large as 400 KB.</p> <note> <p> <code>BatchWriteItem</code> cannot update items. To update items, use the <code>UpdateItem</code> action.</p> </note> <p>The individual <code>PutItem</code> and <code>DeleteItem</code> operations specified in <code>BatchWriteItem</code> are atomic; however <code>BatchWriteItem</code> as a whole is not. If any requested operations fail because the table&#39;s provisioned throughput is exceeded or an internal processing failure occurs, the failed operations are returned in the <code>UnprocessedItems</code> response parameter. You can investigate and optionally resend the requests. Typically, you would call <code>BatchWriteItem</code> in a loop. Each iteration would check for unprocessed items and submit a new <code>BatchWriteItem</code> request with those unprocessed items until all items have been processed.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchWriteItem</code> returns a <code>ProvisionedThroughputExceededException</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#Programming.Errors.BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>With <code>BatchWriteItem</code>, you can efficiently write or delete large amounts of data, such as from Amazon EMR, or copy data from another database into DynamoDB. In order to improve performance with these large-scale operations, <code>BatchWriteItem</code> does not behave in the same way as individual <code>PutItem</code> and <code>DeleteItem</code> calls would. For example, you cannot specify conditions on individual put and delete requests, and <code>BatchWriteItem</code> does not return deleted items in the response.</p> <p>If you use a programming language that supports concurrency, you can use threads to write items in parallel. Your application must include the necessary logic to manage the threads. With languages that don&#39;t support threading, you must update or delete the specified items one at a time. In both situations, <code>BatchWriteItem</code> performs the specified put and delete operations in parallel, giving you the power of the thread pool approach without having to introduce complexity into your application.</p> <p>Parallel processing reduces latency, but each specified put and delete request consumes the same number of write capacity units whether it is processed in parallel or not. Delete operations on nonexistent items consume one write capacity unit.</p> <p>If one or more of the following is true, DynamoDB rejects the entire batch write operation:</p> <ul> <li> <p>One or more tables specified in the <code>BatchWriteItem</code> request does not exist.</p> </li> <li> <p>Primary key attributes specified on an item in the request do not match those in the corresponding table&#39;s primary key schema.</p> </li> <li> <p>You try to perform multiple operations on the same item in the same <code>BatchWriteItem</code> request. For example, you cannot put and delete the same item in the same <code>BatchWriteItem</code> request. </p> </li> <li> <p> Your request contains at least two items with identical hash and range keys (which essentially is two put operations). </p> </li> <li> <p>There are more than 25 requests in the batch.</p> </li> <li> <p>Any individual item in a batch exceeds 400 KB.</p> </li> <li> <p>The total request size exceeds 16 MB.</p> </li> </ul></p>
+    fn batch_write_item(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> RusotoFuture<BatchWriteItemOutput, BatchWriteItemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("BatchWriteItem");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.BatchWriteItem");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchWriteItemOutput, _>(
+                    response,
+                    "BatchWriteItem",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchWriteItemError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a backup for an existing table.</p> <p> Each time you create an on-demand backup, the entire table data is backed up. There is no limit to the number of on-demand backups that can be taken. </p> <p> When you create an on-demand backup, a time marker of the request is cataloged, and the backup is created asynchronously, by applying all changes until the time of the request to the last full table snapshot. Backup requests are processed instantaneously and become available for restore within minutes. </p> <p>You can call <code>CreateBackup</code> at a maximum rate of 50 times per second.</p> <p>All backups in DynamoDB work without consuming any provisioned throughput on the table.</p> <p> If you submit a backup request on 2018-12-14 at 14:25:00, the backup is guaranteed to contain all data committed to the table up to 14:24:00, and data committed after 14:26:00 will not be. The backup might contain data modifications made between 14:24:00 and 14:26:00. On-demand backup does not support causal consistency. </p> <p> Along with data, the following are also included on the backups: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Streams</p> </li> <li> <p>Provisioned read and write capacity</p> </li> </ul></p>
+    fn create_backup(
+        &self,
+        input: CreateBackupInput,
+    ) -> RusotoFuture<CreateBackupOutput, CreateBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("CreateBackup");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.CreateBackup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateBackupOutput, _>(
+                    response,
+                    "CreateBackup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBackupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a global table from an existing table. A global table creates a replication relationship between two or more DynamoDB tables with the same table name in the provided Regions. </p> <p>If you want to add a new replica table to a global table, each of the following conditions must be true:</p> <ul> <li> <p>The table must have the same primary key as all of the other replicas.</p> </li> <li> <p>The table must have the same name as all of the other replicas.</p> </li> <li> <p>The table must have DynamoDB Streams enabled, with the stream containing both the new and the old images of the item.</p> </li> <li> <p>None of the replica tables in the global table can contain any data.</p> </li> </ul> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> </ul> <important> <p> Write capacity settings should be set consistently across your replica tables and secondary indexes. DynamoDB strongly recommends enabling auto scaling to manage the write capacity settings for all of your global tables replicas and indexes. </p> <p> If you prefer to manage write capacity settings manually, you should provision equal replicated write capacity units to your replica tables. You should also provision equal replicated write capacity units to matching secondary indexes across your global table. </p> </important></p>
+    fn create_global_table(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> RusotoFuture<CreateGlobalTableOutput, CreateGlobalTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("CreateGlobalTable");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.CreateGlobalTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateGlobalTableOutput, _>(
+                    response,
+                    "CreateGlobalTable",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGlobalTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>The <code>CreateTable</code> operation adds a new table to your account. In an AWS account, table names must be unique within each Region. That is, you can have two tables with same name if you create the tables in different Regions.</p> <p> <code>CreateTable</code> is an asynchronous operation. Upon receiving a <code>CreateTable</code> request, DynamoDB immediately returns a response with a <code>TableStatus</code> of <code>CREATING</code>. After the table is created, DynamoDB sets the <code>TableStatus</code> to <code>ACTIVE</code>. You can perform read and write operations only on an <code>ACTIVE</code> table. </p> <p>You can optionally define secondary indexes on the new table, as part of the <code>CreateTable</code> operation. If you want to create multiple tables with secondary indexes on them, you must create the tables sequentially. Only one table with secondary indexes can be in the <code>CREATING</code> state at any given time.</p> <p>You can use the <code>DescribeTable</code> action to check the table status.</p>
+    fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> RusotoFuture<CreateTableOutput, CreateTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("CreateTable");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.CreateTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTableOutput, _>(response, "CreateTable")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing backup of a table.</p> <p>You can call <code>DeleteBackup</code> at a maximum rate of 10 times per second.</p>
+    fn delete_backup(
+        &self,
+        input: DeleteBackupInput,
+    ) -> RusotoFuture<DeleteBackupOutput, DeleteBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DeleteBackup");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.DeleteBackup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteBackupOutput, _>(
+                    response,
+                    "DeleteBackup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBackupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a single item in a table by primary key. You can perform a conditional delete operation that deletes the item if it exists, or if it has an expected attribute value.</p> <p>In addition to deleting an item, you can also return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <p>Unless you specify conditions, the <code>DeleteItem</code> is an idempotent operation; running it multiple times on the same item or attribute does <i>not</i> result in an error response.</p> <p>Conditional deletes are useful for deleting items only if specific conditions are met. If those conditions are met, DynamoDB performs the delete. Otherwise, the item is not deleted.</p>
+    fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> RusotoFuture<DeleteItemOutput, DeleteItemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DeleteItem");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.DeleteItem");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteItemOutput, _>(response, "DeleteItem")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteItemError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>The <code>DeleteTable</code> operation deletes a table and all of its items. After a <code>DeleteTable</code> request, the specified table is in the <code>DELETING</code> state until DynamoDB completes the deletion. If the table is in the <code>ACTIVE</code> state, you can delete it. If a table is in <code>CREATING</code> or <code>UPDATING</code> states, then DynamoDB returns a <code>ResourceInUseException</code>. If the specified table does not exist, DynamoDB returns a <code>ResourceNotFoundException</code>. If table is already in the <code>DELETING</code> state, no error is returned. </p> <note> <p>DynamoDB might continue to accept data read and write operations, such as <code>GetItem</code> and <code>PutItem</code>, on a table in the <code>DELETING</code> state until the table deletion is complete.</p> </note> <p>When you delete a table, any indexes on that table are also deleted.</p> <p>If you have DynamoDB Streams enabled on the table, then the corresponding stream on that table goes into the <code>DISABLED</code> state, and the stream is automatically deleted after 24 hours.</p> <p>Use the <code>DescribeTable</code> action to check the status of the table. </p>
+    fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> RusotoFuture<DeleteTableOutput, DeleteTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DeleteTable");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.DeleteTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTableOutput, _>(response, "DeleteTable")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes an existing backup of a table.</p> <p>You can call <code>DescribeBackup</code> at a maximum rate of 10 times per second.</p>
+    fn describe_backup(
+        &self,
+        input: DescribeBackupInput,
+    ) -> RusotoFuture<DescribeBackupOutput, DescribeBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeBackup");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeBackup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeBackupOutput, _>(
+                    response,
+                    "DescribeBackup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeBackupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Checks the status of continuous backups and point in time recovery on the specified table. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> After continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p> <p>You can call <code>DescribeContinuousBackups</code> at a maximum rate of 10 times per second.</p>
+    fn describe_continuous_backups(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> RusotoFuture<DescribeContinuousBackupsOutput, DescribeContinuousBackupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeContinuousBackups");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DynamoDB_20120810.DescribeContinuousBackups",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeContinuousBackupsOutput, _>(
+                    response,
+                    "DescribeBackup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeContinuousBackupsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the regional endpoint information.</p>
+    fn describe_endpoints(
+        &self,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeEndpoints");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeEndpoints");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEndpointsResponse, _>(
+                    response,
+                    "DescribeEndpoints",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeEndpointsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the specified global table.</p>
+    fn describe_global_table(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> RusotoFuture<DescribeGlobalTableOutput, DescribeGlobalTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeGlobalTable");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeGlobalTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeGlobalTableOutput, _>(
+                    response,
+                    "DescribeGlobalTable",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeGlobalTableError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes Region-specific settings for a global table.</p>
+    fn describe_global_table_settings(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> RusotoFuture<DescribeGlobalTableSettingsOutput, DescribeGlobalTableSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeGlobalTableSettings");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DynamoDB_20120810.DescribeGlobalTableSettings",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeGlobalTableSettingsOutput, _>(
+                    response,
+                    "DescribeGlobalTable",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeGlobalTableSettingsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the current provisioned-capacity limits for your AWS account in a Region, both for the Region as a whole and for any one DynamoDB table that you create there.</p> <p>When you establish an AWS account, the account has initial limits on the maximum read capacity units and write capacity units that you can provision across all of your DynamoDB tables in a given Region. Also, there are per-table limits that apply when you create a table there. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> page in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p>Although you can increase these limits by filing a case at <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>, obtaining the increase is not instantaneous. The <code>DescribeLimits</code> action lets you write code to compare the capacity you are currently using to those limits imposed by your account so that you have enough time to apply for an increase before you hit a limit.</p> <p>For example, you could use one of the AWS SDKs to do the following:</p> <ol> <li> <p>Call <code>DescribeLimits</code> for a particular Region to obtain your current account limits on provisioned capacity there.</p> </li> <li> <p>Create a variable to hold the aggregate read capacity units provisioned for all your tables in that Region, and one to hold the aggregate write capacity units. Zero them both.</p> </li> <li> <p>Call <code>ListTables</code> to obtain a list of all your DynamoDB tables.</p> </li> <li> <p>For each table name listed by <code>ListTables</code>, do the following:</p> <ul> <li> <p>Call <code>DescribeTable</code> with the table name.</p> </li> <li> <p>Use the data returned by <code>DescribeTable</code> to add the read capacity units and write capacity units provisioned for the table itself to your variables.</p> </li> <li> <p>If the table has one or more global secondary indexes (GSIs), loop over these GSIs and add their provisioned capacity values to your variables as well.</p> </li> </ul> </li> <li> <p>Report the account limits for that Region returned by <code>DescribeLimits</code>, along with the total current provisioned capacity levels you have calculated.</p> </li> </ol> <p>This will let you see whether you are getting close to your account-level limits.</p> <p>The per-table limits apply only when you are creating a new table. They restrict the sum of the provisioned capacity of the new table itself and all its global secondary indexes.</p> <p>For existing tables and their GSIs, DynamoDB doesn't let you increase provisioned capacity extremely rapidly. But the only upper limit that applies is that the aggregate provisioned capacity over all your tables and GSIs cannot exceed either of the per-account limits.</p> <note> <p> <code>DescribeLimits</code> should only be called periodically. You can expect throttling errors if you call it more than once in a minute.</p> </note> <p>The <code>DescribeLimits</code> Request element has no content.</p>
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeLimits");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeLimits");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeLimitsOutput, _>(
+                    response,
+                    "DescribeLimits",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeLimitsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Returns information about the table, including the current status of the table, when it was created, the primary key schema, and any indexes on the table.</p> <note> <p>If you issue a <code>DescribeTable</code> request immediately after a <code>CreateTable</code> request, DynamoDB might return a <code>ResourceNotFoundException</code>. This is because <code>DescribeTable</code> uses an eventually consistent query, and the metadata for your table might not be available at that moment. Wait for a few seconds, and then try the <code>DescribeTable</code> request again.</p> </note></p>
+    fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> RusotoFuture<DescribeTableOutput, DescribeTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeTable");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTableOutput, _>(
+                    response,
+                    "DescribeTable",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gives a description of the Time to Live (TTL) status on the specified table. </p>
+    fn describe_time_to_live(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> RusotoFuture<DescribeTimeToLiveOutput, DescribeTimeToLiveError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeTimeToLive");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTimeToLive");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTimeToLiveOutput, _>(
+                    response,
+                    "DescribeTimeToLive",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTimeToLiveError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>The <code>GetItem</code> operation returns a set of attributes for the item with the given primary key. If there is no matching item, <code>GetItem</code> does not return any data and there will be no <code>Item</code> element in the response.</p> <p> <code>GetItem</code> provides an eventually consistent read by default. If your application requires a strongly consistent read, set <code>ConsistentRead</code> to <code>true</code>. Although a strongly consistent read might take more time than an eventually consistent read, it always returns the last updated value.</p>
+    fn get_item(&self, input: GetItemInput) -> RusotoFuture<GetItemOutput, GetItemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("GetItem");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.GetItem");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetItemOutput, _>(response, "GetItem")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetItemError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>List backups associated with an AWS account. To list backups for a given table, specify <code>TableName</code>. <code>ListBackups</code> returns a paginated list of results with at most 1 MB worth of items in a page. You can also specify a limit for the maximum number of entries to be returned in a page. </p> <p>In the request, start time is inclusive, but end time is exclusive. Note that these limits are for the time at which the original backup was requested.</p> <p>You can call <code>ListBackups</code> a maximum of five times per second.</p>
+    fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> RusotoFuture<ListBackupsOutput, ListBackupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListBackups");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.ListBackups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListBackupsOutput, _>(response, "ListBackups")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListBackupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all global tables that have a replica in the specified Region.</p>
+    fn list_global_tables(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> RusotoFuture<ListGlobalTablesOutput, ListGlobalTablesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListGlobalTables");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.ListGlobalTables");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGlobalTablesOutput, _>(
+                    response,
+                    "ListGlobalTables",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGlobalTablesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of table names associated with the current account and endpoint. The output from <code>ListTables</code> is paginated, with each page returning a maximum of 100 table names.</p>
+    fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> RusotoFuture<ListTablesOutput, ListTablesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListTables");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.ListTables");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTablesOutput, _>(response, "ListTables")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTablesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>List all tags on an Amazon DynamoDB resource. You can call ListTagsOfResource up to 10 times per second, per account.</p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    fn list_tags_of_resource(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> RusotoFuture<ListTagsOfResourceOutput, ListTagsOfResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListTagsOfResource");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.ListTagsOfResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsOfResourceOutput, _>(
+                    response,
+                    "ListTagsOfResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTagsOfResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new item, or replaces an old item with a new item. If an item that has the same primary key as the new item already exists in the specified table, the new item completely replaces the existing item. You can perform a conditional put operation (add a new item if one with the specified primary key doesn't exist), or replace an existing item if it has certain attribute values. You can return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <important> <p>This topic provides general information about the <code>PutItem</code> API.</p> <p>For information on how to call the <code>PutItem</code> API using the AWS SDK in specific languages, see the following:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/goto/aws-cli/dynamodb-2012-08-10/PutItem"> PutItem in the AWS Command Line Interface</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/DotNetSDKV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for .NET</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForCpp/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for C++</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForGoV1/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Go</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForJava/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Java</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/AWSJavaScriptSDK/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for JavaScript</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForPHPV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for PHP V3</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/boto3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Python</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForRubyV2/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Ruby V2</a> </p> </li> </ul> </important> <p>When you add an item, the primary key attributes are the only required attributes. Attribute values cannot be null. String and Binary type attributes must have lengths greater than zero. Set type attributes cannot be empty. Requests with empty values will be rejected with a <code>ValidationException</code> exception.</p> <note> <p>To prevent a new item from replacing an existing item, use a conditional expression that contains the <code>attribute_not_exists</code> function with the name of the attribute being used as the partition key for the table. Since every record must contain that attribute, the <code>attribute_not_exists</code> function will only succeed if no matching item exists.</p> </note> <p>For more information about <code>PutItem</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithItems.html">Working with Items</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    fn put_item(&self, input: PutItemInput) -> RusotoFuture<PutItemOutput, PutItemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("PutItem");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.PutItem");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutItemOutput, _>(response, "PutItem")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutItemError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>The <code>Query</code> operation finds items based on primary key values. You can query any table or secondary index that has a composite primary key (a partition key and a sort key). </p> <p>Use the <code>KeyConditionExpression</code> parameter to provide a specific value for the partition key. The <code>Query</code> operation will return all of the items from the table or index with that partition key value. You can optionally narrow the scope of the <code>Query</code> operation by specifying a sort key value and a comparison operator in <code>KeyConditionExpression</code>. To further refine the <code>Query</code> results, you can optionally provide a <code>FilterExpression</code>. A <code>FilterExpression</code> determines which items within the results should be returned to you. All of the other results are discarded. </p> <p> A <code>Query</code> operation always returns a result set. If no matching items are found, the result set will be empty. Queries that do not return results consume the minimum number of read capacity units for that type of read operation. </p> <note> <p> DynamoDB calculates the number of read capacity units consumed based on item size, not on the amount of data that is returned to an application. The number of capacity units consumed will be the same whether you request all of the attributes (the default behavior) or just some of them (using a projection expression). The number will also be the same whether or not you use a <code>FilterExpression</code>. </p> </note> <p> <code>Query</code> results are always sorted by the sort key value. If the data type of the sort key is Number, the results are returned in numeric order; otherwise, the results are returned in order of UTF-8 bytes. By default, the sort order is ascending. To reverse the order, set the <code>ScanIndexForward</code> parameter to false. </p> <p> A single <code>Query</code> operation will read up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you will need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Query.html#Query.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>FilterExpression</code> is applied after a <code>Query</code> finishes, but before the results are returned. A <code>FilterExpression</code> cannot contain partition key or sort key attributes. You need to specify those attributes in the <code>KeyConditionExpression</code>. </p> <note> <p> A <code>Query</code> operation can return an empty result set and a <code>LastEvaluatedKey</code> if all the items read for the page of results are filtered out. </p> </note> <p>You can query a table, a local secondary index, or a global secondary index. For a query on a table or on a local secondary index, you can set the <code>ConsistentRead</code> parameter to <code>true</code> and obtain a strongly consistent result. Global secondary indexes support eventually consistent reads only, so do not specify <code>ConsistentRead</code> when querying a global secondary index.</p>
+    fn query(&self, input: QueryInput) -> RusotoFuture<QueryOutput, QueryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("Query");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.Query");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<QueryOutput, _>(response, "Query")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(QueryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a new table from an existing backup. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p>You can call <code>RestoreTableFromBackup</code> at a maximum rate of 10 times per second.</p> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> </ul></p>
+    fn restore_table_from_backup(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> RusotoFuture<RestoreTableFromBackupOutput, RestoreTableFromBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("RestoreTableFromBackup");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.RestoreTableFromBackup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RestoreTableFromBackupOutput, _>(
+                    response,
+                    "RestoreTableFromBackup",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RestoreTableFromBackupError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Restores the specified table to the specified point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. You can restore your table to any point in time during the last 35 days. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p> When you restore using point in time recovery, DynamoDB restores your table data to the state based on the selected date and time (day:hour:minute:second) to a new table. </p> <p> Along with data, the following are also included on the new restored table using point in time recovery: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Provisioned read and write capacity</p> </li> <li> <p>Encryption settings</p> <important> <p> All these settings come from the current settings of the source table at the time of restore. </p> </important> </li> </ul> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> <li> <p>Point in time recovery settings</p> </li> </ul></p>
+    fn restore_table_to_point_in_time(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> RusotoFuture<RestoreTableToPointInTimeOutput, RestoreTableToPointInTimeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("RestoreTableToPointInTime");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DynamoDB_20120810.RestoreTableToPointInTime",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RestoreTableToPointInTimeOutput, _>(
+                    response,
+                    "RestoreTableFromBackup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RestoreTableToPointInTimeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>The <code>Scan</code> operation returns one or more items and item attributes by accessing every item in a table or a secondary index. To have DynamoDB return fewer items, you can provide a <code>FilterExpression</code> operation.</p> <p>If the total number of scanned items exceeds the maximum dataset size limit of 1 MB, the scan stops and results are returned to the user as a <code>LastEvaluatedKey</code> value to continue the scan in a subsequent operation. The results also include the number of items exceeding the limit. A scan can result in no table data meeting the filter criteria. </p> <p>A single <code>Scan</code> operation reads up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>Scan</code> operations proceed sequentially; however, for faster performance on a large table or secondary index, applications can request a parallel <code>Scan</code> operation by providing the <code>Segment</code> and <code>TotalSegments</code> parameters. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.ParallelScan">Parallel Scan</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p> <code>Scan</code> uses eventually consistent reads when accessing the data in a table; therefore, the result set might not include the changes to data in the table immediately before the operation began. If you need a consistent copy of the data, as of the time that the <code>Scan</code> begins, you can set the <code>ConsistentRead</code> parameter to <code>true</code>.</p>
+    fn scan(&self, input: ScanInput) -> RusotoFuture<ScanOutput, ScanError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("Scan");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.Scan");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ScanOutput, _>(response, "Scan")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ScanError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Associate a set of tags with an Amazon DynamoDB resource. You can then activate these user-defined tags so that they appear on the Billing and Cost Management console for cost allocation tracking. You can call TagResource up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p> <code>TransactGetItems</code> is a synchronous operation that atomically retrieves multiple items from one or more tables (but not from indexes) in a single account and Region. A <code>TransactGetItems</code> call can contain up to 25 <code>TransactGetItem</code> objects, each of which contains a <code>Get</code> structure that specifies an item to retrieve from a table in the account and Region. A call to <code>TransactGetItems</code> cannot retrieve items from tables in more than one AWS account or Region. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <note> <p>All AWS Regions and AWS GovCloud (US) support up to 25 items per transaction with up to 4 MB of data, except the following AWS Regions: </p> <ul> <li> <p>China (Beijing)</p> </li> <li> <p>China (Ningxia)</p> </li> </ul> <p>The China (Beijing) and China (Ningxia) Regions support up to 10 items per transaction with up to 4 MB of data. </p> </note> <p>DynamoDB rejects the entire <code>TransactGetItems</code> request if any of the following is true:</p> <ul> <li> <p>A conflicting operation is in the process of updating an item to be read.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> <li> <p>The aggregate size of the items in the transaction cannot exceed 4 MB.</p> </li> </ul></p>
+    fn transact_get_items(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> RusotoFuture<TransactGetItemsOutput, TransactGetItemsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("TransactGetItems");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.TransactGetItems");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TransactGetItemsOutput, _>(
+                    response,
+                    "TransactGetItems",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TransactGetItemsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p> <code>TransactWriteItems</code> is a synchronous write operation that groups up to 25 action requests. These actions can target items in different tables, but not in different AWS accounts or Regions, and no two actions can target the same item. For example, you cannot both <code>ConditionCheck</code> and <code>Update</code> the same item. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <note> <p>All AWS Regions and AWS GovCloud (US) support up to 25 items per transaction with up to 4 MB of data, except the following AWS Regions: </p> <ul> <li> <p>China (Beijing)</p> </li> <li> <p>China (Ningxia)</p> </li> </ul> <p>The China (Beijing) and China (Ningxia) Regions support up to 10 items per transaction with up to 4 MB of data. </p> </note> <p>The actions are completed atomically so that either all of them succeed, or all of them fail. They are defined by the following objects:</p> <ul> <li> <p> <code>Put</code>  &#x97;   Initiates a <code>PutItem</code> operation to write a new item. This structure specifies the primary key of the item to be written, the name of the table to write it in, an optional condition expression that must be satisfied for the write to succeed, a list of the item&#39;s attributes, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Update</code>  &#x97;   Initiates an <code>UpdateItem</code> operation to update an existing item. This structure specifies the primary key of the item to be updated, the name of the table where it resides, an optional condition expression that must be satisfied for the update to succeed, an expression that defines one or more attributes to be updated, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Delete</code>  &#x97;   Initiates a <code>DeleteItem</code> operation to delete an existing item. This structure specifies the primary key of the item to be deleted, the name of the table where it resides, an optional condition expression that must be satisfied for the deletion to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>ConditionCheck</code>  &#x97;   Applies a condition to an item that is not being modified by the transaction. This structure specifies the primary key of the item to be checked, the name of the table where it resides, a condition expression that must be satisfied for the transaction to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> </ul> <p>DynamoDB rejects the entire <code>TransactWriteItems</code> request if any of the following is true:</p> <ul> <li> <p>A condition in one of the condition expressions is not met.</p> </li> <li> <p>An ongoing operation is in the process of updating the same item.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>An item size becomes too large (bigger than 400 KB), a local secondary index (LSI) becomes too large, or a similar validation error occurs because of changes made by the transaction.</p> </li> <li> <p>The aggregate size of the items in the transaction exceeds 4 MB.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> </ul></p>
+    fn transact_write_items(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> RusotoFuture<TransactWriteItemsOutput, TransactWriteItemsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("TransactWriteItems");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.TransactWriteItems");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TransactWriteItemsOutput, _>(
+                    response,
+                    "TransactWriteItems",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TransactWriteItemsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the association of tags from an Amazon DynamoDB resource. You can call <code>UntagResource</code> up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> <code>UpdateContinuousBackups</code> enables or disables point in time recovery for the specified table. A successful <code>UpdateContinuousBackups</code> call returns the current <code>ContinuousBackupsDescription</code>. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> Once continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p>
+    fn update_continuous_backups(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> RusotoFuture<UpdateContinuousBackupsOutput, UpdateContinuousBackupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateContinuousBackups");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.UpdateContinuousBackups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateContinuousBackupsOutput, _>(
+                    response,
+                    "UpdateContinuousBackups",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateContinuousBackupsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Adds or removes replicas in the specified global table. The global table must already exist to be able to use this operation. Any replica to be added must be empty, have the same name as the global table, have the same key schema, have DynamoDB Streams enabled, and have the same provisioned and maximum write capacity units.</p> <note> <p>Although you can use <code>UpdateGlobalTable</code> to add replicas and remove replicas in a single request, for simplicity we recommend that you issue separate requests for adding or removing replicas.</p> </note> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> <li> <p> The global secondary indexes must have the same provisioned and maximum write capacity units. </p> </li> </ul></p>
+    fn update_global_table(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> RusotoFuture<UpdateGlobalTableOutput, UpdateGlobalTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateGlobalTable");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.UpdateGlobalTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateGlobalTableOutput, _>(
+                    response,
+                    "UpdateGlobalTable",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateGlobalTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates settings for a global table.</p>
+    fn update_global_table_settings(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> RusotoFuture<UpdateGlobalTableSettingsOutput, UpdateGlobalTableSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateGlobalTableSettings");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "DynamoDB_20120810.UpdateGlobalTableSettings",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateGlobalTableSettingsOutput, _>(
+                    response,
+                    "UpdateGlobalTable",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateGlobalTableSettingsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Edits an existing item's attributes, or adds a new item to the table if it does not already exist. You can put, delete, or add attribute values. You can also perform a conditional update on an existing item (insert a new attribute name-value pair if it doesn't exist, or replace an existing name-value pair if it has certain expected attribute values).</p> <p>You can also return the item's attribute values in the same <code>UpdateItem</code> operation using the <code>ReturnValues</code> parameter.</p>
+    fn update_item(
+        &self,
+        input: UpdateItemInput,
+    ) -> RusotoFuture<UpdateItemOutput, UpdateItemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateItem");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.UpdateItem");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateItemOutput, _>(response, "UpdateItem")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateItemError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies the provisioned throughput settings, global secondary indexes, or DynamoDB Streams settings for a given table.</p> <p>You can only perform one of the following operations at once:</p> <ul> <li> <p>Modify the provisioned throughput settings of the table.</p> </li> <li> <p>Enable or disable DynamoDB Streams on the table.</p> </li> <li> <p>Remove a global secondary index from the table.</p> </li> <li> <p>Create a new global secondary index on the table. After the index begins backfilling, you can use <code>UpdateTable</code> to perform other operations.</p> </li> </ul> <p> <code>UpdateTable</code> is an asynchronous operation; while it is executing, the table status changes from <code>ACTIVE</code> to <code>UPDATING</code>. While it is <code>UPDATING</code>, you cannot issue another <code>UpdateTable</code> request. When the table returns to the <code>ACTIVE</code> state, the <code>UpdateTable</code> operation is complete.</p>
+    fn update_table(
+        &self,
+        input: UpdateTableInput,
+    ) -> RusotoFuture<UpdateTableOutput, UpdateTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateTable");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateTableOutput, _>(response, "UpdateTable")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>The <code>UpdateTimeToLive</code> method enables or disables Time to Live (TTL) for the specified table. A successful <code>UpdateTimeToLive</code> call returns the current <code>TimeToLiveSpecification</code>. It can take up to one hour for the change to fully process. Any additional <code>UpdateTimeToLive</code> calls for the same table during this one hour duration result in a <code>ValidationException</code>. </p> <p>TTL compares the current time in epoch time format to the time stored in the TTL attribute of an item. If the epoch time value stored in the attribute is less than the current time, the item is marked as expired and subsequently deleted.</p> <note> <p> The epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC. </p> </note> <p>DynamoDB deletes expired items on a best-effort basis to ensure availability of throughput for other data operations. </p> <important> <p>DynamoDB typically deletes expired items within two days of expiration. The exact duration within which an item gets deleted after expiration is specific to the nature of the workload. Items that have expired and not been deleted will still show up in reads, queries, and scans.</p> </important> <p>As items are deleted, they are removed from any local secondary index and global secondary index immediately in the same eventually consistent way as a standard delete operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/TTL.html">Time To Live</a> in the Amazon DynamoDB Developer Guide. </p>
+    fn update_time_to_live(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> RusotoFuture<UpdateTimeToLiveOutput, UpdateTimeToLiveError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateTimeToLive");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTimeToLive");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateTimeToLiveOutput, _>(
+                    response,
+                    "UpdateTimeToLive",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateTimeToLiveError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the DynamoDB API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockDynamoDb {
+    batch_get_item: Option<
+        Box<
+            dyn Fn(
+                    BatchGetItemInput,
+                )
+                    -> ::std::result::Result<BatchGetItemOutput, RusotoError<BatchGetItemError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    batch_write_item: Option<
+        Box<
+            dyn Fn(
+                    BatchWriteItemInput,
+                )
+                    -> ::std::result::Result<BatchWriteItemOutput, RusotoError<BatchWriteItemError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_backup: Option<
+        Box<
+            dyn Fn(
+                    CreateBackupInput,
+                )
+                    -> ::std::result::Result<CreateBackupOutput, RusotoError<CreateBackupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_global_table: Option<
+        Box<
+            dyn Fn(
+                    CreateGlobalTableInput,
+                ) -> ::std::result::Result<
+                    CreateGlobalTableOutput,
+                    RusotoError<CreateGlobalTableError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_table: Option<
+        Box<
+            dyn Fn(
+                    CreateTableInput,
+                )
+                    -> ::std::result::Result<CreateTableOutput, RusotoError<CreateTableError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_backup: Option<
+        Box<
+            dyn Fn(
+                    DeleteBackupInput,
+                )
+                    -> ::std::result::Result<DeleteBackupOutput, RusotoError<DeleteBackupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_item: Option<
+        Box<
+            dyn Fn(
+                    DeleteItemInput,
+                )
+                    -> ::std::result::Result<DeleteItemOutput, RusotoError<DeleteItemError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_table: Option<
+        Box<
+            dyn Fn(
+                    DeleteTableInput,
+                )
+                    -> ::std::result::Result<DeleteTableOutput, RusotoError<DeleteTableError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_backup: Option<
+        Box<
+            dyn Fn(
+                    DescribeBackupInput,
+                )
+                    -> ::std::result::Result<DescribeBackupOutput, RusotoError<DescribeBackupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_continuous_backups: Option<
+        Box<
+            dyn Fn(
+                    DescribeContinuousBackupsInput,
+                ) -> ::std::result::Result<
+                    DescribeContinuousBackupsOutput,
+                    RusotoError<DescribeContinuousBackupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_endpoints: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeEndpointsResponse,
+                    RusotoError<DescribeEndpointsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_global_table: Option<
+        Box<
+            dyn Fn(
+                    DescribeGlobalTableInput,
+                ) -> ::std::result::Result<
+                    DescribeGlobalTableOutput,
+                    RusotoError<DescribeGlobalTableError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_global_table_settings: Option<
+        Box<
+            dyn Fn(
+                    DescribeGlobalTableSettingsInput,
+                ) -> ::std::result::Result<
+                    DescribeGlobalTableSettingsOutput,
+                    RusotoError<DescribeGlobalTableSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_limits: Option<
+        Box<
+            dyn Fn()
+                    -> ::std::result::Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_table: Option<
+        Box<
+            dyn Fn(
+                    DescribeTableInput,
+                )
+                    -> ::std::result::Result<DescribeTableOutput, RusotoError<DescribeTableError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_time_to_live: Option<
+        Box<
+            dyn Fn(
+                    DescribeTimeToLiveInput,
+                ) -> ::std::result::Result<
+                    DescribeTimeToLiveOutput,
+                    RusotoError<DescribeTimeToLiveError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_item: Option<
+        Box<
+            dyn Fn(GetItemInput) -> ::std::result::Result<GetItemOutput, RusotoError<GetItemError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_backups: Option<
+        Box<
+            dyn Fn(
+                    ListBackupsInput,
+                )
+                    -> ::std::result::Result<ListBackupsOutput, RusotoError<ListBackupsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_global_tables: Option<
+        Box<
+            dyn Fn(
+                    ListGlobalTablesInput,
+                ) -> ::std::result::Result<
+                    ListGlobalTablesOutput,
+                    RusotoError<ListGlobalTablesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tables: Option<
+        Box<
+            dyn Fn(
+                    ListTablesInput,
+                )
+                    -> ::std::result::Result<ListTablesOutput, RusotoError<ListTablesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_of_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsOfResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsOfResourceOutput,
+                    RusotoError<ListTagsOfResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_item: Option<
+        Box<
+            dyn Fn(PutItemInput) -> ::std::result::Result<PutItemOutput, RusotoError<PutItemError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    query: Option<
+        Box<
+            dyn Fn(QueryInput) -> ::std::result::Result<QueryOutput, RusotoError<QueryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    restore_table_from_backup: Option<
+        Box<
+            dyn Fn(
+                    RestoreTableFromBackupInput,
+                ) -> ::std::result::Result<
+                    RestoreTableFromBackupOutput,
+                    RusotoError<RestoreTableFromBackupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    restore_table_to_point_in_time: Option<
+        Box<
+            dyn Fn(
+                    RestoreTableToPointInTimeInput,
+                ) -> ::std::result::Result<
+                    RestoreTableToPointInTimeOutput,
+                    RusotoError<RestoreTableToPointInTimeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    scan: Option<
+        Box<
+            dyn Fn(ScanInput) -> ::std::result::Result<ScanOutput, RusotoError<ScanError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceInput) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    transact_get_items: Option<
+        Box<
+            dyn Fn(
+                    TransactGetItemsInput,
+                ) -> ::std::result::Result<
+                    TransactGetItemsOutput,
+                    RusotoError<TransactGetItemsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    transact_write_items: Option<
+        Box<
+            dyn Fn(
+                    TransactWriteItemsInput,
+                ) -> ::std::result::Result<
+                    TransactWriteItemsOutput,
+                    RusotoError<TransactWriteItemsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(UntagResourceInput) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_continuous_backups: Option<
+        Box<
+            dyn Fn(
+                    UpdateContinuousBackupsInput,
+                ) -> ::std::result::Result<
+                    UpdateContinuousBackupsOutput,
+                    RusotoError<UpdateContinuousBackupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_global_table: Option<
+        Box<
+            dyn Fn(
+                    UpdateGlobalTableInput,
+                ) -> ::std::result::Result<
+                    UpdateGlobalTableOutput,
+                    RusotoError<UpdateGlobalTableError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_global_table_settings: Option<
+        Box<
+            dyn Fn(
+                    UpdateGlobalTableSettingsInput,
+                ) -> ::std::result::Result<
+                    UpdateGlobalTableSettingsOutput,
+                    RusotoError<UpdateGlobalTableSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_item: Option<
+        Box<
+            dyn Fn(
+                    UpdateItemInput,
+                )
+                    -> ::std::result::Result<UpdateItemOutput, RusotoError<UpdateItemError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_table: Option<
+        Box<
+            dyn Fn(
+                    UpdateTableInput,
+                )
+                    -> ::std::result::Result<UpdateTableOutput, RusotoError<UpdateTableError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_time_to_live: Option<
+        Box<
+            dyn Fn(
+                    UpdateTimeToLiveInput,
+                ) -> ::std::result::Result<
+                    UpdateTimeToLiveOutput,
+                    RusotoError<UpdateTimeToLiveError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockDynamoDb {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockDynamoDb {
+        Default::default()
+    }
+
+    /// Answers `batch_get_item` with what `handler` returns for its input.
+    pub fn with_batch_get_item<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                BatchGetItemInput,
+            )
+                -> ::std::result::Result<BatchGetItemOutput, RusotoError<BatchGetItemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_item = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_write_item` with what `handler` returns for its input.
+    pub fn with_batch_write_item<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                BatchWriteItemInput,
+            )
+                -> ::std::result::Result<BatchWriteItemOutput, RusotoError<BatchWriteItemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_write_item = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_backup` with what `handler` returns for its input.
+    pub fn with_create_backup<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                CreateBackupInput,
+            )
+                -> ::std::result::Result<CreateBackupOutput, RusotoError<CreateBackupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_global_table` with what `handler` returns for its input.
+    pub fn with_create_global_table<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                CreateGlobalTableInput,
+            ) -> ::std::result::Result<
+                CreateGlobalTableOutput,
+                RusotoError<CreateGlobalTableError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_global_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_table` with what `handler` returns for its input.
+    pub fn with_create_table<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                CreateTableInput,
+            )
+                -> ::std::result::Result<CreateTableOutput, RusotoError<CreateTableError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_backup` with what `handler` returns for its input.
+    pub fn with_delete_backup<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                DeleteBackupInput,
+            )
+                -> ::std::result::Result<DeleteBackupOutput, RusotoError<DeleteBackupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_item` with what `handler` returns for its input.
+    pub fn with_delete_item<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                DeleteItemInput,
+            ) -> ::std::result::Result<DeleteItemOutput, RusotoError<DeleteItemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_item = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_table` with what `handler` returns for its input.
+    pub fn with_delete_table<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                DeleteTableInput,
+            )
+                -> ::std::result::Result<DeleteTableOutput, RusotoError<DeleteTableError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_backup` with what `handler` returns for its input.
+    pub fn with_describe_backup<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                DescribeBackupInput,
+            )
+                -> ::std::result::Result<DescribeBackupOutput, RusotoError<DescribeBackupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_continuous_backups` with what `handler` returns for its input.
+    pub fn with_describe_continuous_backups<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                DescribeContinuousBackupsInput,
+            ) -> ::std::result::Result<
+                DescribeContinuousBackupsOutput,
+                RusotoError<DescribeContinuousBackupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_continuous_backups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_endpoints` with what `handler` returns.
+    pub fn with_describe_endpoints<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeEndpointsResponse,
+                RusotoError<DescribeEndpointsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_endpoints = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_global_table` with what `handler` returns for its input.
+    pub fn with_describe_global_table<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                DescribeGlobalTableInput,
+            ) -> ::std::result::Result<
+                DescribeGlobalTableOutput,
+                RusotoError<DescribeGlobalTableError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_global_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_global_table_settings` with what `handler` returns for its input.
+    pub fn with_describe_global_table_settings<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                DescribeGlobalTableSettingsInput,
+            ) -> ::std::result::Result<
+                DescribeGlobalTableSettingsOutput,
+                RusotoError<DescribeGlobalTableSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_global_table_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_limits` with what `handler` returns.
+    pub fn with_describe_limits<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn() -> ::std::result::Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_limits = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_table` with what `handler` returns for its input.
+    pub fn with_describe_table<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                DescribeTableInput,
+            )
+                -> ::std::result::Result<DescribeTableOutput, RusotoError<DescribeTableError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_time_to_live` with what `handler` returns for its input.
+    pub fn with_describe_time_to_live<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                DescribeTimeToLiveInput,
+            ) -> ::std::result::Result<
+                DescribeTimeToLiveOutput,
+                RusotoError<DescribeTimeToLiveError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_time_to_live = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_item` with what `handler` returns for its input.
+    pub fn with_get_item<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(GetItemInput) -> ::std::result::Result<GetItemOutput, RusotoError<GetItemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_item = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_backups` with what `handler` returns for its input.
+    pub fn with_list_backups<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                ListBackupsInput,
+            )
+                -> ::std::result::Result<ListBackupsOutput, RusotoError<ListBackupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_backups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_global_tables` with what `handler` returns for its input.
+    pub fn with_list_global_tables<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                ListGlobalTablesInput,
+            )
+                -> ::std::result::Result<ListGlobalTablesOutput, RusotoError<ListGlobalTablesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_global_tables = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tables` with what `handler` returns for its input.
+    pub fn with_list_tables<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                ListTablesInput,
+            ) -> ::std::result::Result<ListTablesOutput, RusotoError<ListTablesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tables = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_of_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_of_resource<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                ListTagsOfResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsOfResourceOutput,
+                RusotoError<ListTagsOfResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_of_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_item` with what `handler` returns for its input.
+    pub fn with_put_item<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(PutItemInput) -> ::std::result::Result<PutItemOutput, RusotoError<PutItemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_item = Some(Box::new(handler));
+        self
+    }
+    /// Answers `query` with what `handler` returns for its input.
+    pub fn with_query<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(QueryInput) -> ::std::result::Result<QueryOutput, RusotoError<QueryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.query = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_table_from_backup` with what `handler` returns for its input.
+    pub fn with_restore_table_from_backup<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                RestoreTableFromBackupInput,
+            ) -> ::std::result::Result<
+                RestoreTableFromBackupOutput,
+                RusotoError<RestoreTableFromBackupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_table_from_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_table_to_point_in_time` with what `handler` returns for its input.
+    pub fn with_restore_table_to_point_in_time<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                RestoreTableToPointInTimeInput,
+            ) -> ::std::result::Result<
+                RestoreTableToPointInTimeOutput,
+                RusotoError<RestoreTableToPointInTimeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_table_to_point_in_time = Some(Box::new(handler));
+        self
+    }
+    /// Answers `scan` with what `handler` returns for its input.
+    pub fn with_scan<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(ScanInput) -> ::std::result::Result<ScanOutput, RusotoError<ScanError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.scan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(TagResourceInput) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `transact_get_items` with what `handler` returns for its input.
+    pub fn with_transact_get_items<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                TransactGetItemsInput,
+            )
+                -> ::std::result::Result<TransactGetItemsOutput, RusotoError<TransactGetItemsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.transact_get_items = Some(Box::new(handler));
+        self
+    }
+    /// Answers `transact_write_items` with what `handler` returns for its input.
+    pub fn with_transact_write_items<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                TransactWriteItemsInput,
+            ) -> ::std::result::Result<
+                TransactWriteItemsOutput,
+                RusotoError<TransactWriteItemsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.transact_write_items = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(UntagResourceInput) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_continuous_backups` with what `handler` returns for its input.
+    pub fn with_update_continuous_backups<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                UpdateContinuousBackupsInput,
+            ) -> ::std::result::Result<
+                UpdateContinuousBackupsOutput,
+                RusotoError<UpdateContinuousBackupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_continuous_backups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_global_table` with what `handler` returns for its input.
+    pub fn with_update_global_table<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                UpdateGlobalTableInput,
+            ) -> ::std::result::Result<
+                UpdateGlobalTableOutput,
+                RusotoError<UpdateGlobalTableError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_global_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_global_table_settings` with what `handler` returns for its input.
+    pub fn with_update_global_table_settings<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                UpdateGlobalTableSettingsInput,
+            ) -> ::std::result::Result<
+                UpdateGlobalTableSettingsOutput,
+                RusotoError<UpdateGlobalTableSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_global_table_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_item` with what `handler` returns for its input.
+    pub fn with_update_item<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                UpdateItemInput,
+            ) -> ::std::result::Result<UpdateItemOutput, RusotoError<UpdateItemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_item = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_table` with what `handler` returns for its input.
+    pub fn with_update_table<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                UpdateTableInput,
+            )
+                -> ::std::result::Result<UpdateTableOutput, RusotoError<UpdateTableError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_time_to_live` with what `handler` returns for its input.
+    pub fn with_update_time_to_live<F>(mut self, handler: F) -> MockDynamoDb
+    where
+        F: Fn(
+                UpdateTimeToLiveInput,
+            )
+                -> ::std::result::Result<UpdateTimeToLiveOutput, RusotoError<UpdateTimeToLiveError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_time_to_live = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl DynamoDb for MockDynamoDb {
+    fn batch_get_item(
+        &self,
+        input: BatchGetItemInput,
+    ) -> RusotoFuture<BatchGetItemOutput, BatchGetItemError> {
+        match self.batch_get_item {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for batch_get_item"),
+        }
+    }
+    fn batch_write_item(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> RusotoFuture<BatchWriteItemOutput, BatchWriteItemError> {
+        match self.batch_write_item {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for batch_write_item"),
+        }
+    }
+    fn create_backup(
+        &self,
+        input: CreateBackupInput,
+    ) -> RusotoFuture<CreateBackupOutput, CreateBackupError> {
+        match self.create_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for create_backup"),
+        }
+    }
+    fn create_global_table(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> RusotoFuture<CreateGlobalTableOutput, CreateGlobalTableError> {
+        match self.create_global_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for create_global_table"),
+        }
+    }
+    fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> RusotoFuture<CreateTableOutput, CreateTableError> {
+        match self.create_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for create_table"),
+        }
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupInput,
+    ) -> RusotoFuture<DeleteBackupOutput, DeleteBackupError> {
+        match self.delete_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for delete_backup"),
+        }
+    }
+    fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> RusotoFuture<DeleteItemOutput, DeleteItemError> {
+        match self.delete_item {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for delete_item"),
+        }
+    }
+    fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> RusotoFuture<DeleteTableOutput, DeleteTableError> {
+        match self.delete_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for delete_table"),
+        }
+    }
+    fn describe_backup(
+        &self,
+        input: DescribeBackupInput,
+    ) -> RusotoFuture<DescribeBackupOutput, DescribeBackupError> {
+        match self.describe_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for describe_backup"),
+        }
+    }
+    fn describe_continuous_backups(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> RusotoFuture<DescribeContinuousBackupsOutput, DescribeContinuousBackupsError> {
+        match self.describe_continuous_backups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for describe_continuous_backups"),
+        }
+    }
+    fn describe_endpoints(
+        &self,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        match self.describe_endpoints {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockDynamoDb has no handler for describe_endpoints"),
+        }
+    }
+    fn describe_global_table(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> RusotoFuture<DescribeGlobalTableOutput, DescribeGlobalTableError> {
+        match self.describe_global_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for describe_global_table"),
+        }
+    }
+    fn describe_global_table_settings(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> RusotoFuture<DescribeGlobalTableSettingsOutput, DescribeGlobalTableSettingsError> {
+        match self.describe_global_table_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for describe_global_table_settings"),
+        }
+    }
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        match self.describe_limits {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockDynamoDb has no handler for describe_limits"),
+        }
+    }
+    fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> RusotoFuture<DescribeTableOutput, DescribeTableError> {
+        match self.describe_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for describe_table"),
+        }
+    }
+    fn describe_time_to_live(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> RusotoFuture<DescribeTimeToLiveOutput, DescribeTimeToLiveError> {
+        match self.describe_time_to_live {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for describe_time_to_live"),
+        }
+    }
+    fn get_item(&self, input: GetItemInput) -> RusotoFuture<GetItemOutput, GetItemError> {
+        match self.get_item {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for get_item"),
+        }
+    }
+    fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> RusotoFuture<ListBackupsOutput, ListBackupsError> {
+        match self.list_backups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for list_backups"),
+        }
+    }
+    fn list_global_tables(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> RusotoFuture<ListGlobalTablesOutput, ListGlobalTablesError> {
+        match self.list_global_tables {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for list_global_tables"),
+        }
+    }
+    fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> RusotoFuture<ListTablesOutput, ListTablesError> {
+        match self.list_tables {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for list_tables"),
+        }
+    }
+    fn list_tags_of_resource(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> RusotoFuture<ListTagsOfResourceOutput, ListTagsOfResourceError> {
+        match self.list_tags_of_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for list_tags_of_resource"),
+        }
+    }
+    fn put_item(&self, input: PutItemInput) -> RusotoFuture<PutItemOutput, PutItemError> {
+        match self.put_item {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for put_item"),
+        }
+    }
+    fn query(&self, input: QueryInput) -> RusotoFuture<QueryOutput, QueryError> {
+        match self.query {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for query"),
+        }
+    }
+    fn restore_table_from_backup(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> RusotoFuture<RestoreTableFromBackupOutput, RestoreTableFromBackupError> {
+        match self.restore_table_from_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for restore_table_from_backup"),
+        }
+    }
+    fn restore_table_to_point_in_time(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> RusotoFuture<RestoreTableToPointInTimeOutput, RestoreTableToPointInTimeError> {
+        match self.restore_table_to_point_in_time {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for restore_table_to_point_in_time"),
+        }
     }
-
-    /// <p>The <code>Scan</code> operation returns one or more items and item attributes by accessing every item in a table or a secondary index. To have DynamoDB return fewer items, you can provide a <code>FilterExpression</code> operation.</p> <p>If the total number of scanned items exceeds the maximum dataset size limit of 1 MB, the scan stops and results are returned to the user as a <code>LastEvaluatedKey</code> value to continue the scan in a subsequent operation. The results also include the number of items exceeding the limit. A scan can result in no table data meeting the filter criteria. </p> <p>A single <code>Scan</code> operation reads up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>Scan</code> operations proceed sequentially; however, for faster performance on a large table or secondary index, applications can request a parallel <code>Scan</code> operation by providing the <code>Segment</code> and <code>TotalSegments</code> parameters. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.ParallelScan">Parallel Scan</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p> <code>Scan</code> uses eventually consistent reads when accessing the data in a table; therefore, the result set might not include the changes to data in the table immediately before the operation began. If you need a consistent copy of the data, as of the time that the <code>Scan</code> begins, you can set the <code>ConsistentRead</code> parameter to <code>true</code>.</p>
     fn scan(&self, input: ScanInput) -> RusotoFuture<ScanOutput, ScanError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.Scan");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ScanOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ScanError::from_response(response))),
-                )
-            }
-        })
+        match self.scan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for scan"),
+        }
     }
-
-    /// <p>Associate a set of tags with an Amazon DynamoDB resource. You can then activate these user-defined tags so that they appear on the Billing and Cost Management console for cost allocation tracking. You can call TagResource up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for tag_resource"),
+        }
     }
-
-    /// <p><p> <code>TransactGetItems</code> is a synchronous operation that atomically retrieves multiple items from one or more tables (but not from indexes) in a single account and Region. A <code>TransactGetItems</code> call can contain up to 25 <code>TransactGetItem</code> objects, each of which contains a <code>Get</code> structure that specifies an item to retrieve from a table in the account and Region. A call to <code>TransactGetItems</code> cannot retrieve items from tables in more than one AWS account or Region. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <note> <p>All AWS Regions and AWS GovCloud (US) support up to 25 items per transaction with up to 4 MB of data, except the following AWS Regions: </p> <ul> <li> <p>China (Beijing)</p> </li> <li> <p>China (Ningxia)</p> </li> </ul> <p>The China (Beijing) and China (Ningxia) Regions support up to 10 items per transaction with up to 4 MB of data. </p> </note> <p>DynamoDB rejects the entire <code>TransactGetItems</code> request if any of the following is true:</p> <ul> <li> <p>A conflicting operation is in the process of updating an item to be read.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> <li> <p>The aggregate size of the items in the transaction cannot exceed 4 MB.</p> </li> </ul></p>
     fn transact_get_items(
         &self,
         input: TransactGetItemsInput,
     ) -> RusotoFuture<TransactGetItemsOutput, TransactGetItemsError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.TransactGetItems");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TransactGetItemsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TransactGetItemsError::from_response(response))),
-                )
-            }
-        })
+        match self.transact_get_items {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for transact_get_items"),
+        }
     }
-
-    /// <p><p> <code>TransactWriteItems</code> is a synchronous write operation that groups up to 25 action requests. These actions can target items in different tables, but not in different AWS accounts or Regions, and no two actions can target the same item. For example, you cannot both <code>ConditionCheck</code> and <code>Update</code> the same item. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <note> <p>All AWS Regions and AWS GovCloud (US) support up to 25 items per transaction with up to 4 MB of data, except the following AWS Regions: </p> <ul> <li> <p>China (Beijing)</p> </li> <li> <p>China (Ningxia)</p> </li> </ul> <p>The China (Beijing) and China (Ningxia) Regions support up to 10 items per transaction with up to 4 MB of data. </p> </note> <p>The actions are completed atomically so that either all of them succeed, or all of them fail. They are defined by the following objects:</p> <ul> <li> <p> <code>Put</code>  &#x97;   Initiates a <code>PutItem</code> operation to write a new item. This structure specifies the primary key of the item to be written, the name of the table to write it in, an optional condition expression that must be satisfied for the write to succeed, a list of the item&#39;s attributes, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Update</code>  &#x97;   Initiates an <code>UpdateItem</code> operation to update an existing item. This structure specifies the primary key of the item to be updated, the name of the table where it resides, an optional condition expression that must be satisfied for the update to succeed, an expression that defines one or more attributes to be updated, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Delete</code>  &#x97;   Initiates a <code>DeleteItem</code> operation to delete an existing item. This structure specifies the primary key of the item to be deleted, the name of the table where it resides, an optional condition expression that must be satisfied for the deletion to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>ConditionCheck</code>  &#x97;   Applies a condition to an item that is not being modified by the transaction. This structure specifies the primary key of the item to be checked, the name of the table where it resides, a condition expression that must be satisfied for the transaction to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> </ul> <p>DynamoDB rejects the entire <code>TransactWriteItems</code> request if any of the following is true:</p> <ul> <li> <p>A condition in one of the condition expressions is not met.</p> </li> <li> <p>An ongoing operation is in the process of updating the same item.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>An item size becomes too large (bigger than 400 KB), a local secondary index (LSI) becomes too large, or a similar validation error occurs because of changes made by the transaction.</p> </li> <li> <p>The aggregate size of the items in the transaction exceeds 4 MB.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> </ul></p>
     fn transact_write_items(
         &self,
         input: TransactWriteItemsInput,
     ) -> RusotoFuture<TransactWriteItemsOutput, TransactWriteItemsError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.TransactWriteItems");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TransactWriteItemsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TransactWriteItemsError::from_response(response))),
-                )
-            }
-        })
+        match self.transact_write_items {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for transact_write_items"),
+        }
     }
-
-    /// <p>Removes the association of tags from an Amazon DynamoDB resource. You can call <code>UntagResource</code> up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for untag_resource"),
+        }
     }
-
-    /// <p> <code>UpdateContinuousBackups</code> enables or disables point in time recovery for the specified table. A successful <code>UpdateContinuousBackups</code> call returns the current <code>ContinuousBackupsDescription</code>. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> Once continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p>
     fn update_continuous_backups(
         &self,
         input: UpdateContinuousBackupsInput,
     ) -> RusotoFuture<UpdateContinuousBackupsOutput, UpdateContinuousBackupsError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.UpdateContinuousBackups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateContinuousBackupsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateContinuousBackupsError::from_response(response))
-                }))
-            }
-        })
+        match self.update_continuous_backups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for update_continuous_backups"),
+        }
     }
-
-    /// <p><p>Adds or removes replicas in the specified global table. The global table must already exist to be able to use this operation. Any replica to be added must be empty, have the same name as the global table, have the same key schema, have DynamoDB Streams enabled, and have the same provisioned and maximum write capacity units.</p> <note> <p>Although you can use <code>UpdateGlobalTable</code> to add replicas and remove replicas in a single request, for simplicity we recommend that you issue separate requests for adding or removing replicas.</p> </note> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> <li> <p> The global secondary indexes must have the same provisioned and maximum write capacity units. </p> </li> </ul></p>
     fn update_global_table(
         &self,
         input: UpdateGlobalTableInput,
     ) -> RusotoFuture<UpdateGlobalTableOutput, UpdateGlobalTableError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.UpdateGlobalTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGlobalTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateGlobalTableError::from_response(response))),
-                )
-            }
-        })
+        match self.update_global_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for update_global_table"),
+        }
     }
-
-    /// <p>Updates settings for a global table.</p>
     fn update_global_table_settings(
         &self,
         input: UpdateGlobalTableSettingsInput,
     ) -> RusotoFuture<UpdateGlobalTableSettingsOutput, UpdateGlobalTableSettingsError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "DynamoDB_20120810.UpdateGlobalTableSettings",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGlobalTableSettingsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateGlobalTableSettingsError::from_response(response))
-                }))
-            }
-        })
+        match self.update_global_table_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for update_global_table_settings"),
+        }
     }
-
-    /// <p>Edits an existing item's attributes, or adds a new item to the table if it does not already exist. You can put, delete, or add attribute values. You can also perform a conditional update on an existing item (insert a new attribute name-value pair if it doesn't exist, or replace an existing name-value pair if it has certain expected attribute values).</p> <p>You can also return the item's attribute values in the same <code>UpdateItem</code> operation using the <code>ReturnValues</code> parameter.</p>
     fn update_item(
         &self,
         input: UpdateItemInput,
     ) -> RusotoFuture<UpdateItemOutput, UpdateItemError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.UpdateItem");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateItemError::from_response(response))),
-                )
-            }
-        })
+        match self.update_item {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for update_item"),
+        }
     }
-
-    /// <p>Modifies the provisioned throughput settings, global secondary indexes, or DynamoDB Streams settings for a given table.</p> <p>You can only perform one of the following operations at once:</p> <ul> <li> <p>Modify the provisioned throughput settings of the table.</p> </li> <li> <p>Enable or disable DynamoDB Streams on the table.</p> </li> <li> <p>Remove a global secondary index from the table.</p> </li> <li> <p>Create a new global secondary index on the table. After the index begins backfilling, you can use <code>UpdateTable</code> to perform other operations.</p> </li> </ul> <p> <code>UpdateTable</code> is an asynchronous operation; while it is executing, the table status changes from <code>ACTIVE</code> to <code>UPDATING</code>. While it is <code>UPDATING</code>, you cannot issue another <code>UpdateTable</code> request. When the table returns to the <code>ACTIVE</code> state, the <code>UpdateTable</code> operation is complete.</p>
     fn update_table(
         &self,
         input: UpdateTableInput,
     ) -> RusotoFuture<UpdateTableOutput, UpdateTableError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTableError::from_response(response))),
-                )
-            }
-        })
+        match self.update_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for update_table"),
+        }
     }
-
-    /// <p>The <code>UpdateTimeToLive</code> method enables or disables Time to Live (TTL) for the specified table. A successful <code>UpdateTimeToLive</code> call returns the current <code>TimeToLiveSpecification</code>. It can take up to one hour for the change to fully process. Any additional <code>UpdateTimeToLive</code> calls for the same table during this one hour duration result in a <code>ValidationException</code>. </p> <p>TTL compares the current time in epoch time format to the time stored in the TTL attribute of an item. If the epoch time value stored in the attribute is less than the current time, the item is marked as expired and subsequently deleted.</p> <note> <p> The epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC. </p> </note> <p>DynamoDB deletes expired items on a best-effort basis to ensure availability of throughput for other data operations. </p> <important> <p>DynamoDB typically deletes expired items within two days of expiration. The exact duration within which an item gets deleted after expiration is specific to the nature of the workload. Items that have expired and not been deleted will still show up in reads, queries, and scans.</p> </important> <p>As items are deleted, they are removed from any local secondary index and global secondary index immediately in the same eventually consistent way as a standard delete operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/TTL.html">Time To Live</a> in the Amazon DynamoDB Developer Guide. </p>
     fn update_time_to_live(
         &self,
         input: UpdateTimeToLiveInput,
     ) -> RusotoFuture<UpdateTimeToLiveOutput, UpdateTimeToLiveError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTimeToLive");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTimeToLiveOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTimeToLiveError::from_response(response))),
-                )
-            }
-        })
+        match self.update_time_to_live {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDb has no handler for update_time_to_live"),
+        }
     }
 }
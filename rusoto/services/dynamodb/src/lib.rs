@@ -51,6 +51,67 @@
 //!     }
 //! }
 //! ```
+//!
+//! ## Dependency Injection
+//!
+//! `DynamoDb` is implemented by references, boxes and `Arc`s of its implementations,
+//! trait objects included, so code can be handed any client as an
+//! `Arc<dyn DynamoDb + Send + Sync>`. In tests, `MockDynamoDb` from the `mocks` feature
+//! stands in for `DynamoDbClient`, answering each operation with what its handler returns.
+//!
+//! Without `Send + Sync`, a shared client can't be moved to other threads:
+//!
+//! ```rust,compile_fail
+//! use std::sync::Arc;
+//! use std::thread;
+//!
+//! use rusoto_core::Region;
+//! use rusoto_dynamodb::{DynamoDb, DynamoDbClient};
+//!
+//! let dynamo: Arc<dyn DynamoDb> = Arc::new(DynamoDbClient::new(Region::UsEast1));
+//! thread::spawn(move || dynamo.list_tables(Default::default()).sync());
+//! ```
+//!
+//! ```rust,no_run
+//! extern crate rusoto_core;
+//! extern crate rusoto_dynamodb;
+//! 
+//! use std::sync::Arc;
+//! 
+//! use rusoto_core::Region;
+//! use rusoto_dynamodb::{DynamoDb, DynamoDbClient, ListTablesInput};
+//! 
+//! /// Lists the tables of whichever client it was given.
+//! struct TableLister {
+//!     dynamo: Arc<dyn DynamoDb + Send + Sync>,
+//! }
+//! 
+//! impl TableLister {
+//!     fn table_names(&self) -> Vec<String> {
+//!         let input = ListTablesInput {
+//!             limit: Some(10),
+//!             ..Default::default()
+//!         };
+//!         match self.dynamo.list_tables(input).sync() {
+//!             Ok(output) => output.table_names.unwrap_or_default(),
+//!             Err(error) => {
+//!                 println!("Error: {:?}", error);
+//!                 Vec::new()
+//!             }
+//!         }
+//!     }
+//! }
+//! 
+//! fn main() {
+//!     let lister = TableLister {
+//!         dynamo: Arc::new(DynamoDbClient::new(Region::UsEast1)),
+//!     };
+//! 
+//!     for table_name in lister.table_names() {
+//!         println!("{}", table_name);
+//!     }
+//! }
+//! ```
 
 extern crate bytes;
 extern crate futures;
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Represents the data for an attribute. You can set one, and only one, of the elements.</p> <p>Each attribute in an item is a name-value pair. An attribute can be single-valued or multi-valued set. For example, a book item can have title and authors attributes. Each book has one title but can have many authors. The multi-valued attribute is a set; duplicate values are not allowed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttributeValue {
     /// <p>A Binary data type.</p>
     #[serde(rename = "B")]
@@ -81,6 +82,7 @@ pub struct AttributeValue {
 
 /// <p>Represents the input of a <code>DescribeStream</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStreamInput {
     /// <p>The shard ID of the first item that this operation will evaluate. Use the value that was returned for <code>LastEvaluatedShardId</code> in the previous operation. </p>
     #[serde(rename = "ExclusiveStartShardId")]
@@ -95,9 +97,31 @@ pub struct DescribeStreamInput {
     pub stream_arn: String,
 }
 
+impl Validate for DescribeStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_shard_id {
+            errors.check_length(
+                &field_path(path, "exclusive_start_shard_id"),
+                value.chars().count(),
+                Some(28),
+                Some(65),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        errors.check_length(
+            &field_path(path, "stream_arn"),
+            self.stream_arn.chars().count(),
+            Some(37),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>DescribeStream</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStreamOutput {
     /// <p>A complete description of the stream, including its creation date and time, the DynamoDB table associated with the stream, the shard IDs within the stream, and the beginning and ending sequence numbers of stream records within the shards.</p>
     #[serde(rename = "StreamDescription")]
@@ -107,6 +131,7 @@ pub struct DescribeStreamOutput {
 
 /// <p>Represents the input of a <code>GetRecords</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRecordsInput {
     /// <p>The maximum number of records to return from the shard. The upper limit is 1000.</p>
     #[serde(rename = "Limit")]
@@ -117,9 +142,23 @@ pub struct GetRecordsInput {
     pub shard_iterator: String,
 }
 
+impl Validate for GetRecordsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        errors.check_length(
+            &field_path(path, "shard_iterator"),
+            self.shard_iterator.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>GetRecords</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRecordsOutput {
     /// <p>The next position in the shard from which to start sequentially reading stream records. If set to <code>null</code>, the shard has been closed and the requested iterator will not return any more data.</p>
     #[serde(rename = "NextShardIterator")]
@@ -133,6 +172,7 @@ pub struct GetRecordsOutput {
 
 /// <p>Represents the input of a <code>GetShardIterator</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetShardIteratorInput {
     /// <p>The sequence number of a stream record in the shard from which to start reading.</p>
     #[serde(rename = "SequenceNumber")]
@@ -149,9 +189,34 @@ pub struct GetShardIteratorInput {
     pub stream_arn: String,
 }
 
+impl Validate for GetShardIteratorInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.sequence_number {
+            errors.check_length(
+                &field_path(path, "sequence_number"),
+                value.chars().count(),
+                Some(21),
+                Some(40),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "shard_id"),
+            self.shard_id.chars().count(),
+            Some(28),
+            Some(65),
+        );
+        errors.check_length(
+            &field_path(path, "stream_arn"),
+            self.stream_arn.chars().count(),
+            Some(37),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>GetShardIterator</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetShardIteratorOutput {
     /// <p>The position in the shard from which to start reading stream records sequentially. A shard iterator specifies this position using the sequence number of a stream record in a shard.</p>
     #[serde(rename = "ShardIterator")]
@@ -161,7 +226,7 @@ pub struct GetShardIteratorOutput {
 
 /// <p>Contains details about the type of identity that made the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Identity {
     /// <p>A unique identifier for the entity that made the call. For Time To Live, the principalId is "dynamodb.amazonaws.com".</p>
     #[serde(rename = "PrincipalId")]
@@ -175,7 +240,7 @@ pub struct Identity {
 
 /// <p><p>Represents <i>a single element</i> of a key schema. A key schema specifies the attributes that make up the primary key of a table, or the key attributes of an index.</p> <p>A <code>KeySchemaElement</code> represents exactly one attribute of the primary key. For example, a simple primary key (partition key) would be represented by one <code>KeySchemaElement</code>. A composite primary key (partition key and sort key) would require one <code>KeySchemaElement</code> for the partition key, and another <code>KeySchemaElement</code> for the sort key.</p> <note> <p>The partition key of an item is also known as its <i>hash attribute</i>. The term &quot;hash attribute&quot; derives from DynamoDB&#39;s usage of an internal hash function to evenly distribute data items across partitions, based on their partition key values.</p> <p>The sort key of an item is also known as its <i>range attribute</i>. The term &quot;range attribute&quot; derives from the way DynamoDB stores items with the same partition key physically close together, in sorted order by the sort key value.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KeySchemaElement {
     /// <p>The name of a key attribute.</p>
     #[serde(rename = "AttributeName")]
@@ -187,6 +252,7 @@ pub struct KeySchemaElement {
 
 /// <p>Represents the input of a <code>ListStreams</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStreamsInput {
     /// <p>The ARN (Amazon Resource Name) of the first item that this operation will evaluate. Use the value that was returned for <code>LastEvaluatedStreamArn</code> in the previous operation. </p>
     #[serde(rename = "ExclusiveStartStreamArn")]
@@ -202,9 +268,34 @@ pub struct ListStreamsInput {
     pub table_name: Option<String>,
 }
 
+impl Validate for ListStreamsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_stream_arn {
+            errors.check_length(
+                &field_path(path, "exclusive_start_stream_arn"),
+                value.chars().count(),
+                Some(37),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.table_name {
+            errors.check_length(
+                &field_path(path, "table_name"),
+                value.chars().count(),
+                Some(3),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "table_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>ListStreams</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListStreamsOutput {
     /// <p>The stream ARN of the item where the operation stopped, inclusive of the previous result set. Use this value to start a new operation, excluding this value in the new request.</p> <p>If <code>LastEvaluatedStreamArn</code> is empty, then the "last page" of results has been processed and there is no more data to be retrieved.</p> <p>If <code>LastEvaluatedStreamArn</code> is not empty, it does not necessarily mean that there is more data in the result set. The only way to know when you have reached the end of the result set is when <code>LastEvaluatedStreamArn</code> is empty.</p>
     #[serde(rename = "LastEvaluatedStreamArn")]
@@ -218,7 +309,7 @@ pub struct ListStreamsOutput {
 
 /// <p>A description of a unique event within a stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Record {
     /// <p>The region in which the <code>GetRecords</code> request was received.</p>
     #[serde(rename = "awsRegion")]
@@ -252,7 +343,7 @@ pub struct Record {
 
 /// <p>The beginning and ending sequence numbers for the stream records contained within a shard.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SequenceNumberRange {
     /// <p>The last sequence number.</p>
     #[serde(rename = "EndingSequenceNumber")]
@@ -266,7 +357,7 @@ pub struct SequenceNumberRange {
 
 /// <p>A uniquely identified group of stream records within a stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Shard {
     /// <p>The shard ID of the current shard's parent.</p>
     #[serde(rename = "ParentShardId")]
@@ -284,7 +375,7 @@ pub struct Shard {
 
 /// <p>Represents all of the data describing a particular stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Stream {
     /// <p>The Amazon Resource Name (ARN) for the stream.</p>
     #[serde(rename = "StreamArn")]
@@ -302,7 +393,7 @@ pub struct Stream {
 
 /// <p>Represents all of the data describing a particular stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StreamDescription {
     /// <p>The date and time when the request to create this stream was issued.</p>
     #[serde(rename = "CreationRequestDateTime")]
@@ -344,7 +435,7 @@ pub struct StreamDescription {
 
 /// <p>A description of a single data modification that was performed on an item in a DynamoDB table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StreamRecord {
     /// <p>The approximate date and time when the stream record was created, in <a href="http://www.epochconverter.com/">UNIX epoch time</a> format.</p>
     #[serde(rename = "ApproximateCreationDateTime")]
@@ -395,7 +486,12 @@ impl DescribeStreamError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -449,7 +545,12 @@ impl GetRecordsError {
                 "TrimmedDataAccessException" => {
                     return RusotoError::Service(GetRecordsError::TrimmedDataAccess(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -498,7 +599,12 @@ impl GetShardIteratorError {
                 "TrimmedDataAccessException" => {
                     return RusotoError::Service(GetShardIteratorError::TrimmedDataAccess(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -538,7 +644,12 @@ impl ListStreamsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListStreamsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -584,6 +695,85 @@ pub trait DynamoDbStreams {
         input: ListStreamsInput,
     ) -> RusotoFuture<ListStreamsOutput, ListStreamsError>;
 }
+const _: Option<&dyn DynamoDbStreams> = None;
+impl<'a, T: DynamoDbStreams + ?Sized> DynamoDbStreams for &'a T {
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        (**self).get_records(input)
+    }
+    fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        (**self).get_shard_iterator(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+}
+impl<T: DynamoDbStreams + ?Sized> DynamoDbStreams for Box<T> {
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        (**self).get_records(input)
+    }
+    fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        (**self).get_shard_iterator(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+}
+impl<T: DynamoDbStreams + ?Sized> DynamoDbStreams for ::std::sync::Arc<T> {
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        (**self).get_records(input)
+    }
+    fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        (**self).get_shard_iterator(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+}
 /// A client for the Amazon DynamoDB Streams API.
 #[derive(Clone)]
 pub struct DynamoDbStreamsClient {
@@ -627,7 +817,16 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         &self,
         input: DescribeStreamInput,
     ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeStream");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.DescribeStream");
@@ -636,10 +835,10 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStreamOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeStreamOutput, _>(
+                    response,
+                    "DescribeStream",
+                )
             } else {
                 Box::new(
                     response
@@ -656,7 +855,16 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         &self,
         input: GetRecordsInput,
     ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("GetRecords");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.GetRecords");
@@ -665,10 +873,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRecordsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetRecordsOutput, _>(response, "GetRecords")
             } else {
                 Box::new(
                     response
@@ -685,7 +890,16 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         &self,
         input: GetShardIteratorInput,
     ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("GetShardIterator");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.GetShardIterator");
@@ -694,10 +908,10 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetShardIteratorOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetShardIteratorOutput, _>(
+                    response,
+                    "GetShardIterator",
+                )
             } else {
                 Box::new(
                     response
@@ -714,7 +928,16 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         &self,
         input: ListStreamsInput,
     ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListStreams");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.ListStreams");
@@ -723,10 +946,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListStreamsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListStreamsOutput, _>(response, "ListStreams")
             } else {
                 Box::new(
                     response
@@ -738,3 +958,157 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         })
     }
 }
+
+/// A mock of the Amazon DynamoDB Streams API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockDynamoDbStreams {
+    describe_stream: Option<
+        Box<
+            dyn Fn(
+                    DescribeStreamInput,
+                )
+                    -> ::std::result::Result<DescribeStreamOutput, RusotoError<DescribeStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_records: Option<
+        Box<
+            dyn Fn(
+                    GetRecordsInput,
+                )
+                    -> ::std::result::Result<GetRecordsOutput, RusotoError<GetRecordsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_shard_iterator: Option<
+        Box<
+            dyn Fn(
+                    GetShardIteratorInput,
+                ) -> ::std::result::Result<
+                    GetShardIteratorOutput,
+                    RusotoError<GetShardIteratorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_streams: Option<
+        Box<
+            dyn Fn(
+                    ListStreamsInput,
+                )
+                    -> ::std::result::Result<ListStreamsOutput, RusotoError<ListStreamsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockDynamoDbStreams {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockDynamoDbStreams {
+        Default::default()
+    }
+
+    /// Answers `describe_stream` with what `handler` returns for its input.
+    pub fn with_describe_stream<F>(mut self, handler: F) -> MockDynamoDbStreams
+    where
+        F: Fn(
+                DescribeStreamInput,
+            )
+                -> ::std::result::Result<DescribeStreamOutput, RusotoError<DescribeStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_records` with what `handler` returns for its input.
+    pub fn with_get_records<F>(mut self, handler: F) -> MockDynamoDbStreams
+    where
+        F: Fn(
+                GetRecordsInput,
+            ) -> ::std::result::Result<GetRecordsOutput, RusotoError<GetRecordsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_records = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_shard_iterator` with what `handler` returns for its input.
+    pub fn with_get_shard_iterator<F>(mut self, handler: F) -> MockDynamoDbStreams
+    where
+        F: Fn(
+                GetShardIteratorInput,
+            )
+                -> ::std::result::Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_shard_iterator = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_streams` with what `handler` returns for its input.
+    pub fn with_list_streams<F>(mut self, handler: F) -> MockDynamoDbStreams
+    where
+        F: Fn(
+                ListStreamsInput,
+            )
+                -> ::std::result::Result<ListStreamsOutput, RusotoError<ListStreamsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_streams = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl DynamoDbStreams for MockDynamoDbStreams {
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        match self.describe_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDbStreams has no handler for describe_stream"),
+        }
+    }
+    fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        match self.get_records {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDbStreams has no handler for get_records"),
+        }
+    }
+    fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        match self.get_shard_iterator {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDbStreams has no handler for get_shard_iterator"),
+        }
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        match self.list_streams {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockDynamoDbStreams has no handler for list_streams"),
+        }
+    }
+}
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendSSHPublicKeyRequest {
     /// <p>The availability zone the EC2 instance was launched in.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -39,8 +41,52 @@ pub struct SendSSHPublicKeyRequest {
     pub ssh_public_key: String,
 }
 
+impl Validate for SendSSHPublicKeyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "availability_zone"),
+            self.availability_zone.chars().count(),
+            Some(6),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "availability_zone"),
+            &self.availability_zone,
+            "^(\\w+-){2,3}\\d+\\w+$",
+        );
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(10),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "^i-[a-f0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "instance_os_user"),
+            self.instance_os_user.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_os_user"),
+            &self.instance_os_user,
+            "^[A-Za-z_][A-Za-z0-9\\@\\._-]{0,30}[A-Za-z0-9\\$_-]?$",
+        );
+        errors.check_length(
+            &field_path(path, "ssh_public_key"),
+            self.ssh_public_key.chars().count(),
+            Some(80),
+            Some(4096),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendSSHPublicKeyResponse {
     /// <p>The request ID as logged by EC2 Connect. Please provide this when contacting AWS Support.</p>
     #[serde(rename = "RequestId")]
@@ -88,7 +134,12 @@ impl SendSSHPublicKeyError {
                 "ThrottlingException" => {
                     return RusotoError::Service(SendSSHPublicKeyError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -119,6 +170,31 @@ pub trait Ec2InstanceConnect {
         input: SendSSHPublicKeyRequest,
     ) -> RusotoFuture<SendSSHPublicKeyResponse, SendSSHPublicKeyError>;
 }
+const _: Option<&dyn Ec2InstanceConnect> = None;
+impl<'a, T: Ec2InstanceConnect + ?Sized> Ec2InstanceConnect for &'a T {
+    fn send_ssh_public_key(
+        &self,
+        input: SendSSHPublicKeyRequest,
+    ) -> RusotoFuture<SendSSHPublicKeyResponse, SendSSHPublicKeyError> {
+        (**self).send_ssh_public_key(input)
+    }
+}
+impl<T: Ec2InstanceConnect + ?Sized> Ec2InstanceConnect for Box<T> {
+    fn send_ssh_public_key(
+        &self,
+        input: SendSSHPublicKeyRequest,
+    ) -> RusotoFuture<SendSSHPublicKeyResponse, SendSSHPublicKeyError> {
+        (**self).send_ssh_public_key(input)
+    }
+}
+impl<T: Ec2InstanceConnect + ?Sized> Ec2InstanceConnect for ::std::sync::Arc<T> {
+    fn send_ssh_public_key(
+        &self,
+        input: SendSSHPublicKeyRequest,
+    ) -> RusotoFuture<SendSSHPublicKeyResponse, SendSSHPublicKeyError> {
+        (**self).send_ssh_public_key(input)
+    }
+}
 /// A client for the EC2 Instance Connect API.
 #[derive(Clone)]
 pub struct Ec2InstanceConnectClient {
@@ -162,7 +238,16 @@ impl Ec2InstanceConnect for Ec2InstanceConnectClient {
         &self,
         input: SendSSHPublicKeyRequest,
     ) -> RusotoFuture<SendSSHPublicKeyResponse, SendSSHPublicKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ec2-instance-connect", &self.region, "/");
+        request.set_operation("SendSSHPublicKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -174,10 +259,10 @@ impl Ec2InstanceConnect for Ec2InstanceConnectClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendSSHPublicKeyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<SendSSHPublicKeyResponse, _>(
+                    response,
+                    "SendSSHPublicKey",
+                )
             } else {
                 Box::new(
                     response
@@ -189,3 +274,60 @@ impl Ec2InstanceConnect for Ec2InstanceConnectClient {
         })
     }
 }
+
+/// A mock of the EC2 Instance Connect API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockEc2InstanceConnect {
+    send_ssh_public_key: Option<
+        Box<
+            dyn Fn(
+                    SendSSHPublicKeyRequest,
+                ) -> ::std::result::Result<
+                    SendSSHPublicKeyResponse,
+                    RusotoError<SendSSHPublicKeyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockEc2InstanceConnect {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockEc2InstanceConnect {
+        Default::default()
+    }
+
+    /// Answers `send_ssh_public_key` with what `handler` returns for its input.
+    pub fn with_send_ssh_public_key<F>(mut self, handler: F) -> MockEc2InstanceConnect
+    where
+        F: Fn(
+                SendSSHPublicKeyRequest,
+            ) -> ::std::result::Result<
+                SendSSHPublicKeyResponse,
+                RusotoError<SendSSHPublicKeyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.send_ssh_public_key = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Ec2InstanceConnect for MockEc2InstanceConnect {
+    fn send_ssh_public_key(
+        &self,
+        input: SendSSHPublicKeyRequest,
+    ) -> RusotoFuture<SendSSHPublicKeyResponse, SendSSHPublicKeyError> {
+        match self.send_ssh_public_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEc2InstanceConnect has no handler for send_ssh_public_key"),
+        }
+    }
+}
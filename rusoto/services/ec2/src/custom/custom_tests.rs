@@ -0,0 +1,107 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::{Future, Stream};
+use rusoto_core::pagination::Paginator;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::{DispatchSignedRequest, Region};
+
+use self::rusoto_mock::*;
+use crate::generated::{DescribeInstancesRequest, Ec2, Ec2Client, Reservation};
+
+/// Answers `DescribeInstances` with three pages, whose tokens are `page-2` and `page-3`,
+/// recording the token of each request.
+struct InstancesDispatcher {
+    tokens: Arc<Mutex<Vec<Option<String>>>>,
+}
+
+impl DispatchSignedRequest for InstancesDispatcher {
+    type Future = <MockRequestDispatcher as DispatchSignedRequest>::Future;
+
+    fn dispatch(&self, request: SignedRequest, timeout: Option<Duration>) -> Self::Future {
+        let params: Vec<(String, String)> = match request.payload {
+            Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                serde_urlencoded::from_bytes(buffer).unwrap()
+            }
+            _ => Vec::new(),
+        };
+        let token = params
+            .iter()
+            .find(|(name, _)| name == "NextToken")
+            .map(|(_, value)| value.clone());
+        self.tokens.lock().unwrap().push(token.clone());
+
+        let (reservations, next_token) = match token.as_ref().map(|token| &token[..]) {
+            None => (
+                reservation(&["i-1", "i-2"]) + &reservation(&["i-3"]),
+                "<nextToken>page-2</nextToken>",
+            ),
+            Some("page-2") => (reservation(&[]), "<nextToken>page-3</nextToken>"),
+            Some("page-3") => (reservation(&["i-4"]), ""),
+            Some(other) => panic!("unexpected token {}", other),
+        };
+        let body = format!(
+            "<DescribeInstancesResponse>
+                <requestId>instances</requestId>
+                <reservationSet>{}</reservationSet>
+                {}
+            </DescribeInstancesResponse>",
+            reservations, next_token
+        );
+        MockRequestDispatcher::with_status(200)
+            .with_body(&body)
+            .dispatch(request, timeout)
+    }
+}
+
+fn reservation(instance_ids: &[&str]) -> String {
+    let instances: String = instance_ids
+        .iter()
+        .map(|id| format!("<item><instanceId>{}</instanceId></item>", id))
+        .collect();
+    format!(
+        "<item>
+            <reservationId>r-{}</reservationId>
+            <instancesSet>{}</instancesSet>
+        </item>",
+        instance_ids.len(),
+        instances
+    )
+}
+
+#[test]
+fn instance_ids_are_selected_from_every_page() {
+    let tokens = Arc::new(Mutex::new(Vec::new()));
+    let dispatcher = InstancesDispatcher {
+        tokens: tokens.clone(),
+    };
+    let client = Ec2Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+
+    let instance_ids = Paginator::new(move |next_token| {
+        let request = DescribeInstancesRequest {
+            next_token,
+            ..Default::default()
+        };
+        client
+            .describe_instances(request)
+            .map(|page| (page.reservations.unwrap_or_default(), page.next_token))
+    })
+    .select(|reservation: Reservation| {
+        reservation
+            .instances
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|instance| instance.instance_id)
+    });
+
+    assert_eq!(
+        instance_ids.collect().wait().unwrap(),
+        vec!["i-1", "i-2", "i-3", "i-4"]
+    );
+    assert_eq!(
+        *tokens.lock().unwrap(),
+        vec![None, Some("page-2".to_owned()), Some("page-3".to_owned())]
+    );
+}
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>An object representing authorization data for an Amazon ECR registry.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AuthorizationData {
     /// <p>A base64-encoded string that contains authorization data for the specified Amazon ECR registry. When the string is decoded, it is presented in the format <code>user:password</code> for private registry authentication using <code>docker login</code>.</p>
     #[serde(rename = "authorizationToken")]
@@ -42,6 +43,7 @@ pub struct AuthorizationData {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchCheckLayerAvailabilityRequest {
     /// <p>The digests of the image layers to check.</p>
     #[serde(rename = "layerDigests")]
@@ -55,8 +57,37 @@ pub struct BatchCheckLayerAvailabilityRequest {
     pub repository_name: String,
 }
 
+impl Validate for BatchCheckLayerAvailabilityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "layer_digests"),
+            self.layer_digests.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.layer_digests.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "layer_digests"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1000));
+        }
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchCheckLayerAvailabilityResponse {
     /// <p>Any failures associated with the call.</p>
     #[serde(rename = "failures")]
@@ -70,6 +101,7 @@ pub struct BatchCheckLayerAvailabilityResponse {
 
 /// <p>Deletes specified images within a specified repository. Images are specified with either the <code>imageTag</code> or <code>imageDigest</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteImageRequest {
     /// <p>A list of image ID references that correspond to images to delete. The format of the <code>imageIds</code> reference is <code>imageTag=tag</code> or <code>imageDigest=digest</code>.</p>
     #[serde(rename = "imageIds")]
@@ -83,8 +115,37 @@ pub struct BatchDeleteImageRequest {
     pub repository_name: String,
 }
 
+impl Validate for BatchDeleteImageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "image_ids"),
+            self.image_ids.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.image_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "image_ids"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDeleteImageResponse {
     /// <p>Any failures associated with the call.</p>
     #[serde(rename = "failures")]
@@ -97,6 +158,7 @@ pub struct BatchDeleteImageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetImageRequest {
     /// <p>The accepted media types for the request.</p> <p>Valid values: <code>application/vnd.docker.distribution.manifest.v1+json</code> | <code>application/vnd.docker.distribution.manifest.v2+json</code> | <code>application/vnd.oci.image.manifest.v1+json</code> </p>
     #[serde(rename = "acceptedMediaTypes")]
@@ -114,8 +176,45 @@ pub struct BatchGetImageRequest {
     pub repository_name: String,
 }
 
+impl Validate for BatchGetImageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accepted_media_types {
+            errors.check_length(
+                &field_path(path, "accepted_media_types"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "image_ids"),
+            self.image_ids.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.image_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "image_ids"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetImageResponse {
     /// <p>Any failures associated with the call.</p>
     #[serde(rename = "failures")]
@@ -128,6 +227,7 @@ pub struct BatchGetImageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompleteLayerUploadRequest {
     /// <p>The <code>sha256</code> digest of the image layer.</p>
     #[serde(rename = "layerDigests")]
@@ -144,8 +244,42 @@ pub struct CompleteLayerUploadRequest {
     pub upload_id: String,
 }
 
+impl Validate for CompleteLayerUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "layer_digests"),
+            self.layer_digests.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.layer_digests.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "layer_digests"), i);
+            errors.check_pattern(&item_path, &item, "[a-zA-Z0-9-_+.]+:[a-fA-F0-9]+");
+        }
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+        errors.check_pattern(
+            &field_path(path, "upload_id"),
+            &self.upload_id,
+            "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CompleteLayerUploadResponse {
     /// <p>The <code>sha256</code> digest of the image layer.</p>
     #[serde(rename = "layerDigest")]
@@ -166,6 +300,7 @@ pub struct CompleteLayerUploadResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRepositoryRequest {
     /// <p>The tag mutability setting for the repository. If this parameter is omitted, the default setting of <code>MUTABLE</code> will be used which will allow image tags to be overwritten. If <code>IMMUTABLE</code> is specified, all image tags within the repository will be immutable which will prevent them from being overwritten.</p>
     #[serde(rename = "imageTagMutability")]
@@ -180,8 +315,24 @@ pub struct CreateRepositoryRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateRepositoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRepositoryResponse {
     /// <p>The repository that was created.</p>
     #[serde(rename = "repository")]
@@ -190,6 +341,7 @@ pub struct CreateRepositoryResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLifecyclePolicyRequest {
     /// <p>The AWS account ID associated with the registry that contains the repository. If you do not specify a registry, the default registry is assumed.</p>
     #[serde(rename = "registryId")]
@@ -200,8 +352,27 @@ pub struct DeleteLifecyclePolicyRequest {
     pub repository_name: String,
 }
 
+impl Validate for DeleteLifecyclePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLifecyclePolicyResponse {
     /// <p>The time stamp of the last time that the lifecycle policy was run.</p>
     #[serde(rename = "lastEvaluatedAt")]
@@ -222,6 +393,7 @@ pub struct DeleteLifecyclePolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRepositoryPolicyRequest {
     /// <p>The AWS account ID associated with the registry that contains the repository policy to delete. If you do not specify a registry, the default registry is assumed.</p>
     #[serde(rename = "registryId")]
@@ -232,8 +404,27 @@ pub struct DeleteRepositoryPolicyRequest {
     pub repository_name: String,
 }
 
+impl Validate for DeleteRepositoryPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRepositoryPolicyResponse {
     /// <p>The JSON repository policy that was deleted from the repository.</p>
     #[serde(rename = "policyText")]
@@ -250,6 +441,7 @@ pub struct DeleteRepositoryPolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRepositoryRequest {
     /// <p> If a repository contains images, forces the deletion.</p>
     #[serde(rename = "force")]
@@ -264,8 +456,27 @@ pub struct DeleteRepositoryRequest {
     pub repository_name: String,
 }
 
+impl Validate for DeleteRepositoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRepositoryResponse {
     /// <p>The repository that was deleted.</p>
     #[serde(rename = "repository")]
@@ -275,6 +486,7 @@ pub struct DeleteRepositoryResponse {
 
 /// <p>An object representing a filter on a <a>DescribeImages</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeImagesFilter {
     /// <p>The tag status with which to filter your <a>DescribeImages</a> results. You can filter results based on whether they are <code>TAGGED</code> or <code>UNTAGGED</code>.</p>
     #[serde(rename = "tagStatus")]
@@ -283,6 +495,7 @@ pub struct DescribeImagesFilter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeImagesRequest {
     /// <p>The filter key and value with which to filter your <code>DescribeImages</code> results.</p>
     #[serde(rename = "filter")]
@@ -309,8 +522,47 @@ pub struct DescribeImagesRequest {
     pub repository_name: String,
 }
 
+impl Validate for DescribeImagesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.image_ids {
+            errors.check_length(
+                &field_path(path, "image_ids"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "image_ids"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeImagesResponse {
     /// <p>A list of <a>ImageDetail</a> objects that contain data about the image.</p>
     #[serde(rename = "imageDetails")]
@@ -323,6 +575,7 @@ pub struct DescribeImagesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRepositoriesRequest {
     /// <p>The maximum number of repository results returned by <code>DescribeRepositories</code> in paginated output. When this parameter is used, <code>DescribeRepositories</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>DescribeRepositories</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 1000. If this parameter is not used, then <code>DescribeRepositories</code> returns up to 100 results and a <code>nextToken</code> value, if applicable. This option cannot be used when you specify repositories with <code>repositoryNames</code>.</p>
     #[serde(rename = "maxResults")]
@@ -342,8 +595,41 @@ pub struct DescribeRepositoriesRequest {
     pub repository_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeRepositoriesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        if let Some(ref value) = self.repository_names {
+            errors.check_length(
+                &field_path(path, "repository_names"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "repository_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(2), Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+                );
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRepositoriesResponse {
     /// <p>The <code>nextToken</code> value to include in a future <code>DescribeRepositories</code> request. When the results of a <code>DescribeRepositories</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -356,6 +642,7 @@ pub struct DescribeRepositoriesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAuthorizationTokenRequest {
     /// <p>A list of AWS account IDs that are associated with the registries for which to get authorization tokens. If you do not specify a registry, the default registry is assumed.</p>
     #[serde(rename = "registryIds")]
@@ -364,7 +651,7 @@ pub struct GetAuthorizationTokenRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAuthorizationTokenResponse {
     /// <p>A list of authorization token data objects that correspond to the <code>registryIds</code> values in the request.</p>
     #[serde(rename = "authorizationData")]
@@ -373,6 +660,7 @@ pub struct GetAuthorizationTokenResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDownloadUrlForLayerRequest {
     /// <p>The digest of the image layer to download.</p>
     #[serde(rename = "layerDigest")]
@@ -386,8 +674,32 @@ pub struct GetDownloadUrlForLayerRequest {
     pub repository_name: String,
 }
 
+impl Validate for GetDownloadUrlForLayerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "layer_digest"),
+            &self.layer_digest,
+            "[a-zA-Z0-9-_+.]+:[a-fA-F0-9]+",
+        );
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDownloadUrlForLayerResponse {
     /// <p>The pre-signed Amazon S3 download URL for the requested layer.</p>
     #[serde(rename = "downloadUrl")]
@@ -400,6 +712,7 @@ pub struct GetDownloadUrlForLayerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLifecyclePolicyPreviewRequest {
     /// <p>An optional parameter that filters results based on image tag status and all tags, if tagged.</p>
     #[serde(rename = "filter")]
@@ -426,8 +739,42 @@ pub struct GetLifecyclePolicyPreviewRequest {
     pub repository_name: String,
 }
 
+impl Validate for GetLifecyclePolicyPreviewRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.image_ids {
+            errors.check_length(
+                &field_path(path, "image_ids"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "image_ids"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLifecyclePolicyPreviewResponse {
     /// <p>The JSON lifecycle policy text.</p>
     #[serde(rename = "lifecyclePolicyText")]
@@ -460,6 +807,7 @@ pub struct GetLifecyclePolicyPreviewResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLifecyclePolicyRequest {
     /// <p>The AWS account ID associated with the registry that contains the repository. If you do not specify a registry, the default registry is assumed.</p>
     #[serde(rename = "registryId")]
@@ -470,8 +818,27 @@ pub struct GetLifecyclePolicyRequest {
     pub repository_name: String,
 }
 
+impl Validate for GetLifecyclePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLifecyclePolicyResponse {
     /// <p>The time stamp of the last time that the lifecycle policy was run.</p>
     #[serde(rename = "lastEvaluatedAt")]
@@ -492,6 +859,7 @@ pub struct GetLifecyclePolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRepositoryPolicyRequest {
     /// <p>The AWS account ID associated with the registry that contains the repository. If you do not specify a registry, the default registry is assumed.</p>
     #[serde(rename = "registryId")]
@@ -502,8 +870,27 @@ pub struct GetRepositoryPolicyRequest {
     pub repository_name: String,
 }
 
+impl Validate for GetRepositoryPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRepositoryPolicyResponse {
     /// <p>The JSON repository policy text associated with the repository.</p>
     #[serde(rename = "policyText")]
@@ -521,7 +908,7 @@ pub struct GetRepositoryPolicyResponse {
 
 /// <p>An object representing an Amazon ECR image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Image {
     /// <p>An object containing the image tag and image digest associated with an image.</p>
     #[serde(rename = "imageId")]
@@ -543,7 +930,7 @@ pub struct Image {
 
 /// <p>An object that describes an image returned by a <a>DescribeImages</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImageDetail {
     /// <p>The <code>sha256</code> digest of the image manifest.</p>
     #[serde(rename = "imageDigest")]
@@ -573,7 +960,7 @@ pub struct ImageDetail {
 
 /// <p>An object representing an Amazon ECR image failure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImageFailure {
     /// <p>The code associated with the failure.</p>
     #[serde(rename = "failureCode")]
@@ -602,7 +989,21 @@ pub struct ImageIdentifier {
     pub image_tag: Option<String>,
 }
 
+impl Validate for ImageIdentifier {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.image_tag {
+            errors.check_length(
+                &field_path(path, "image_tag"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InitiateLayerUploadRequest {
     /// <p>The AWS account ID associated with the registry to which you intend to upload layers. If you do not specify a registry, the default registry is assumed.</p>
     #[serde(rename = "registryId")]
@@ -613,8 +1014,27 @@ pub struct InitiateLayerUploadRequest {
     pub repository_name: String,
 }
 
+impl Validate for InitiateLayerUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InitiateLayerUploadResponse {
     /// <p>The size, in bytes, that Amazon ECR expects future layer part uploads to be.</p>
     #[serde(rename = "partSize")]
@@ -628,7 +1048,7 @@ pub struct InitiateLayerUploadResponse {
 
 /// <p>An object representing an Amazon ECR image layer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Layer {
     /// <p>The availability status of the image layer.</p>
     #[serde(rename = "layerAvailability")]
@@ -650,7 +1070,7 @@ pub struct Layer {
 
 /// <p>An object representing an Amazon ECR image layer failure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LayerFailure {
     /// <p>The failure code associated with the failure.</p>
     #[serde(rename = "failureCode")]
@@ -668,6 +1088,7 @@ pub struct LayerFailure {
 
 /// <p>The filter for the lifecycle policy preview.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LifecyclePolicyPreviewFilter {
     /// <p>The tag status of the image.</p>
     #[serde(rename = "tagStatus")]
@@ -677,7 +1098,7 @@ pub struct LifecyclePolicyPreviewFilter {
 
 /// <p>The result of the lifecycle policy preview.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LifecyclePolicyPreviewResult {
     /// <p>The type of action to be taken.</p>
     #[serde(rename = "action")]
@@ -703,7 +1124,7 @@ pub struct LifecyclePolicyPreviewResult {
 
 /// <p>The summary of the lifecycle policy preview request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LifecyclePolicyPreviewSummary {
     /// <p>The number of expiring images.</p>
     #[serde(rename = "expiringImageTotalCount")]
@@ -713,7 +1134,7 @@ pub struct LifecyclePolicyPreviewSummary {
 
 /// <p>The type of action to be taken.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LifecyclePolicyRuleAction {
     /// <p>The type of action to be taken.</p>
     #[serde(rename = "type")]
@@ -723,6 +1144,7 @@ pub struct LifecyclePolicyRuleAction {
 
 /// <p>An object representing a filter on a <a>ListImages</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListImagesFilter {
     /// <p>The tag status with which to filter your <a>ListImages</a> results. You can filter results based on whether they are <code>TAGGED</code> or <code>UNTAGGED</code>.</p>
     #[serde(rename = "tagStatus")]
@@ -731,6 +1153,7 @@ pub struct ListImagesFilter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListImagesRequest {
     /// <p>The filter key and value with which to filter your <code>ListImages</code> results.</p>
     #[serde(rename = "filter")]
@@ -753,8 +1176,35 @@ pub struct ListImagesRequest {
     pub repository_name: String,
 }
 
+impl Validate for ListImagesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListImagesResponse {
     /// <p>The list of image IDs for the requested repository.</p>
     #[serde(rename = "imageIds")]
@@ -767,6 +1217,7 @@ pub struct ListImagesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the resource for which to list the tags. Currently, the only supported resource is an Amazon ECR repository.</p>
     #[serde(rename = "resourceArn")]
@@ -774,7 +1225,7 @@ pub struct ListTagsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags for the resource.</p>
     #[serde(rename = "tags")]
@@ -783,6 +1234,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutImageRequest {
     /// <p>The image manifest corresponding to the image to be uploaded.</p>
     #[serde(rename = "imageManifest")]
@@ -800,8 +1252,41 @@ pub struct PutImageRequest {
     pub repository_name: String,
 }
 
+impl Validate for PutImageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "image_manifest"),
+            self.image_manifest.chars().count(),
+            Some(1),
+            Some(4194304),
+        );
+        if let Some(ref value) = self.image_tag {
+            errors.check_length(
+                &field_path(path, "image_tag"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutImageResponse {
     /// <p>Details of the image uploaded.</p>
     #[serde(rename = "image")]
@@ -810,6 +1295,7 @@ pub struct PutImageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutImageTagMutabilityRequest {
     /// <p>The tag mutability setting for the repository. If <code>MUTABLE</code> is specified, image tags can be overwritten. If <code>IMMUTABLE</code> is specified, all image tags within the repository will be immutable which will prevent them from being overwritten.</p>
     #[serde(rename = "imageTagMutability")]
@@ -823,8 +1309,27 @@ pub struct PutImageTagMutabilityRequest {
     pub repository_name: String,
 }
 
+impl Validate for PutImageTagMutabilityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutImageTagMutabilityResponse {
     /// <p>The image tag mutability setting for the repository.</p>
     #[serde(rename = "imageTagMutability")]
@@ -841,6 +1346,7 @@ pub struct PutImageTagMutabilityResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutLifecyclePolicyRequest {
     /// <p>The JSON repository policy text to apply to the repository.</p>
     #[serde(rename = "lifecyclePolicyText")]
@@ -854,8 +1360,33 @@ pub struct PutLifecyclePolicyRequest {
     pub repository_name: String,
 }
 
+impl Validate for PutLifecyclePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "lifecycle_policy_text"),
+            self.lifecycle_policy_text.chars().count(),
+            Some(100),
+            Some(30720),
+        );
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutLifecyclePolicyResponse {
     /// <p>The JSON repository policy text.</p>
     #[serde(rename = "lifecyclePolicyText")]
@@ -873,7 +1404,7 @@ pub struct PutLifecyclePolicyResponse {
 
 /// <p>An object representing a repository.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Repository {
     /// <p>The date and time, in JavaScript date format, when the repository was created.</p>
     #[serde(rename = "createdAt")]
@@ -902,6 +1433,7 @@ pub struct Repository {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetRepositoryPolicyRequest {
     /// <p>If the policy you are attempting to set on a repository policy would prevent you from setting another policy in the future, you must force the <a>SetRepositoryPolicy</a> operation. This is intended to prevent accidental repository lock outs.</p>
     #[serde(rename = "force")]
@@ -919,8 +1451,33 @@ pub struct SetRepositoryPolicyRequest {
     pub repository_name: String,
 }
 
+impl Validate for SetRepositoryPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy_text"),
+            self.policy_text.chars().count(),
+            None,
+            Some(10240),
+        );
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SetRepositoryPolicyResponse {
     /// <p>The JSON repository policy text applied to the repository.</p>
     #[serde(rename = "policyText")]
@@ -937,6 +1494,7 @@ pub struct SetRepositoryPolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartLifecyclePolicyPreviewRequest {
     /// <p>The policy to be evaluated against. If you do not specify a policy, the current policy for the repository is used.</p>
     #[serde(rename = "lifecyclePolicyText")]
@@ -951,8 +1509,35 @@ pub struct StartLifecyclePolicyPreviewRequest {
     pub repository_name: String,
 }
 
+impl Validate for StartLifecyclePolicyPreviewRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.lifecycle_policy_text {
+            errors.check_length(
+                &field_path(path, "lifecycle_policy_text"),
+                value.chars().count(),
+                Some(100),
+                Some(30720),
+            );
+        }
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartLifecyclePolicyPreviewResponse {
     /// <p>The JSON repository policy text.</p>
     #[serde(rename = "lifecyclePolicyText")]
@@ -986,6 +1571,7 @@ pub struct Tag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the the resource to which to add tags. Currently, the only supported resource is an Amazon ECR repository.</p>
     #[serde(rename = "resourceArn")]
@@ -996,10 +1582,11 @@ pub struct TagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource from which to remove tags. Currently, the only supported resource is an Amazon ECR repository.</p>
     #[serde(rename = "resourceArn")]
@@ -1010,10 +1597,11 @@ pub struct UntagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UploadLayerPartRequest {
     /// <p>The base64-encoded layer part payload.</p>
     #[serde(rename = "layerPartBlob")]
@@ -1041,8 +1629,38 @@ pub struct UploadLayerPartRequest {
     pub upload_id: String,
 }
 
+impl Validate for UploadLayerPartRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "layer_part_blob"),
+            self.layer_part_blob.len(),
+            None,
+            Some(20971520),
+        );
+        if let Some(ref value) = self.registry_id {
+            errors.check_pattern(&field_path(path, "registry_id"), &value, "[0-9]{12}");
+        }
+        errors.check_length(
+            &field_path(path, "repository_name"),
+            self.repository_name.chars().count(),
+            Some(2),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_name"),
+            &self.repository_name,
+            "(?:[a-z0-9]+(?:[._-][a-z0-9]+)*/)*[a-z0-9]+(?:[._-][a-z0-9]+)*",
+        );
+        errors.check_pattern(
+            &field_path(path, "upload_id"),
+            &self.upload_id,
+            "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UploadLayerPartResponse {
     /// <p>The integer value of the last byte received in the request.</p>
     #[serde(rename = "lastByteReceived")]
@@ -1092,7 +1710,12 @@ impl BatchCheckLayerAvailabilityError {
                 "ServerException" => {
                     return RusotoError::Service(BatchCheckLayerAvailabilityError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1137,7 +1760,12 @@ impl BatchDeleteImageError {
                 "ServerException" => {
                     return RusotoError::Service(BatchDeleteImageError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1182,7 +1810,12 @@ impl BatchGetImageError {
                 "ServerException" => {
                     return RusotoError::Service(BatchGetImageError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1260,7 +1893,12 @@ impl CompleteLayerUploadError {
                 "UploadNotFoundException" => {
                     return RusotoError::Service(CompleteLayerUploadError::UploadNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1329,7 +1967,12 @@ impl CreateRepositoryError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(CreateRepositoryError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1388,7 +2031,12 @@ impl DeleteLifecyclePolicyError {
                 "ServerException" => {
                     return RusotoError::Service(DeleteLifecyclePolicyError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1439,7 +2087,12 @@ impl DeleteRepositoryError {
                 "ServerException" => {
                     return RusotoError::Service(DeleteRepositoryError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1496,7 +2149,12 @@ impl DeleteRepositoryPolicyError {
                 "ServerException" => {
                     return RusotoError::Service(DeleteRepositoryPolicyError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1547,7 +2205,12 @@ impl DescribeImagesError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeImagesError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1597,7 +2260,12 @@ impl DescribeRepositoriesError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeRepositoriesError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1639,7 +2307,12 @@ impl GetAuthorizationTokenError {
                 "ServerException" => {
                     return RusotoError::Service(GetAuthorizationTokenError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1701,7 +2374,12 @@ impl GetDownloadUrlForLayerError {
                 "ServerException" => {
                     return RusotoError::Service(GetDownloadUrlForLayerError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1757,7 +2435,12 @@ impl GetLifecyclePolicyError {
                 "ServerException" => {
                     return RusotoError::Service(GetLifecyclePolicyError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1814,7 +2497,12 @@ impl GetLifecyclePolicyPreviewError {
                 "ServerException" => {
                     return RusotoError::Service(GetLifecyclePolicyPreviewError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1871,7 +2559,12 @@ impl GetRepositoryPolicyError {
                 "ServerException" => {
                     return RusotoError::Service(GetRepositoryPolicyError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1921,7 +2614,12 @@ impl InitiateLayerUploadError {
                 "ServerException" => {
                     return RusotoError::Service(InitiateLayerUploadError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1964,7 +2662,12 @@ impl ListImagesError {
                     return RusotoError::Service(ListImagesError::RepositoryNotFound(err.msg))
                 }
                 "ServerException" => return RusotoError::Service(ListImagesError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2013,7 +2716,12 @@ impl ListTagsForResourceError {
                 "ServerException" => {
                     return RusotoError::Service(ListTagsForResourceError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2076,7 +2784,12 @@ impl PutImageError {
                     return RusotoError::Service(PutImageError::RepositoryNotFound(err.msg))
                 }
                 "ServerException" => return RusotoError::Service(PutImageError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2129,7 +2842,12 @@ impl PutImageTagMutabilityError {
                 "ServerException" => {
                     return RusotoError::Service(PutImageTagMutabilityError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2176,7 +2894,12 @@ impl PutLifecyclePolicyError {
                 "ServerException" => {
                     return RusotoError::Service(PutLifecyclePolicyError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2225,7 +2948,12 @@ impl SetRepositoryPolicyError {
                 "ServerException" => {
                     return RusotoError::Service(SetRepositoryPolicyError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2290,7 +3018,12 @@ impl StartLifecyclePolicyPreviewError {
                 "ServerException" => {
                     return RusotoError::Service(StartLifecyclePolicyPreviewError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2347,7 +3080,12 @@ impl TagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2404,7 +3142,12 @@ impl UntagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2466,7 +3209,12 @@ impl UploadLayerPartError {
                 "UploadNotFoundException" => {
                     return RusotoError::Service(UploadLayerPartError::UploadNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2645,94 +3393,578 @@ pub trait Ecr {
         input: UploadLayerPartRequest,
     ) -> RusotoFuture<UploadLayerPartResponse, UploadLayerPartError>;
 }
-/// A client for the Amazon ECR API.
-#[derive(Clone)]
-pub struct EcrClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl EcrClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> EcrClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> EcrClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> EcrClient {
-        EcrClient { client, region }
-    }
-}
-
-impl Ecr for EcrClient {
-    /// <p><p>Check the availability of multiple image layers in a specified registry and repository.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+const _: Option<&dyn Ecr> = None;
+impl<'a, T: Ecr + ?Sized> Ecr for &'a T {
     fn batch_check_layer_availability(
         &self,
         input: BatchCheckLayerAvailabilityRequest,
     ) -> RusotoFuture<BatchCheckLayerAvailabilityResponse, BatchCheckLayerAvailabilityError> {
-        let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
-        request.set_endpoint_prefix("api.ecr".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerRegistry_V20150921.BatchCheckLayerAvailability",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchCheckLayerAvailabilityResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchCheckLayerAvailabilityError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_check_layer_availability(input)
     }
-
-    /// <p>Deletes a list of specified images within a specified repository. Images are specified with either <code>imageTag</code> or <code>imageDigest</code>.</p> <p>You can remove a tag from an image by specifying the image's tag in your request. When you remove the last tag from an image, the image is deleted from your repository.</p> <p>You can completely delete an image (and all of its tags) by specifying the image's digest in your request.</p>
     fn batch_delete_image(
         &self,
         input: BatchDeleteImageRequest,
     ) -> RusotoFuture<BatchDeleteImageResponse, BatchDeleteImageError> {
-        let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
-        request.set_endpoint_prefix("api.ecr".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerRegistry_V20150921.BatchDeleteImage",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
+        (**self).batch_delete_image(input)
+    }
+    fn batch_get_image(
+        &self,
+        input: BatchGetImageRequest,
+    ) -> RusotoFuture<BatchGetImageResponse, BatchGetImageError> {
+        (**self).batch_get_image(input)
+    }
+    fn complete_layer_upload(
+        &self,
+        input: CompleteLayerUploadRequest,
+    ) -> RusotoFuture<CompleteLayerUploadResponse, CompleteLayerUploadError> {
+        (**self).complete_layer_upload(input)
+    }
+    fn create_repository(
+        &self,
+        input: CreateRepositoryRequest,
+    ) -> RusotoFuture<CreateRepositoryResponse, CreateRepositoryError> {
+        (**self).create_repository(input)
+    }
+    fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> RusotoFuture<DeleteLifecyclePolicyResponse, DeleteLifecyclePolicyError> {
+        (**self).delete_lifecycle_policy(input)
+    }
+    fn delete_repository(
+        &self,
+        input: DeleteRepositoryRequest,
+    ) -> RusotoFuture<DeleteRepositoryResponse, DeleteRepositoryError> {
+        (**self).delete_repository(input)
+    }
+    fn delete_repository_policy(
+        &self,
+        input: DeleteRepositoryPolicyRequest,
+    ) -> RusotoFuture<DeleteRepositoryPolicyResponse, DeleteRepositoryPolicyError> {
+        (**self).delete_repository_policy(input)
+    }
+    fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> RusotoFuture<DescribeImagesResponse, DescribeImagesError> {
+        (**self).describe_images(input)
+    }
+    fn describe_repositories(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> RusotoFuture<DescribeRepositoriesResponse, DescribeRepositoriesError> {
+        (**self).describe_repositories(input)
+    }
+    fn get_authorization_token(
+        &self,
+        input: GetAuthorizationTokenRequest,
+    ) -> RusotoFuture<GetAuthorizationTokenResponse, GetAuthorizationTokenError> {
+        (**self).get_authorization_token(input)
+    }
+    fn get_download_url_for_layer(
+        &self,
+        input: GetDownloadUrlForLayerRequest,
+    ) -> RusotoFuture<GetDownloadUrlForLayerResponse, GetDownloadUrlForLayerError> {
+        (**self).get_download_url_for_layer(input)
+    }
+    fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> RusotoFuture<GetLifecyclePolicyResponse, GetLifecyclePolicyError> {
+        (**self).get_lifecycle_policy(input)
+    }
+    fn get_lifecycle_policy_preview(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> RusotoFuture<GetLifecyclePolicyPreviewResponse, GetLifecyclePolicyPreviewError> {
+        (**self).get_lifecycle_policy_preview(input)
+    }
+    fn get_repository_policy(
+        &self,
+        input: GetRepositoryPolicyRequest,
+    ) -> RusotoFuture<GetRepositoryPolicyResponse, GetRepositoryPolicyError> {
+        (**self).get_repository_policy(input)
+    }
+    fn initiate_layer_upload(
+        &self,
+        input: InitiateLayerUploadRequest,
+    ) -> RusotoFuture<InitiateLayerUploadResponse, InitiateLayerUploadError> {
+        (**self).initiate_layer_upload(input)
+    }
+    fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> RusotoFuture<ListImagesResponse, ListImagesError> {
+        (**self).list_images(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_image(&self, input: PutImageRequest) -> RusotoFuture<PutImageResponse, PutImageError> {
+        (**self).put_image(input)
+    }
+    fn put_image_tag_mutability(
+        &self,
+        input: PutImageTagMutabilityRequest,
+    ) -> RusotoFuture<PutImageTagMutabilityResponse, PutImageTagMutabilityError> {
+        (**self).put_image_tag_mutability(input)
+    }
+    fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyRequest,
+    ) -> RusotoFuture<PutLifecyclePolicyResponse, PutLifecyclePolicyError> {
+        (**self).put_lifecycle_policy(input)
+    }
+    fn set_repository_policy(
+        &self,
+        input: SetRepositoryPolicyRequest,
+    ) -> RusotoFuture<SetRepositoryPolicyResponse, SetRepositoryPolicyError> {
+        (**self).set_repository_policy(input)
+    }
+    fn start_lifecycle_policy_preview(
+        &self,
+        input: StartLifecyclePolicyPreviewRequest,
+    ) -> RusotoFuture<StartLifecyclePolicyPreviewResponse, StartLifecyclePolicyPreviewError> {
+        (**self).start_lifecycle_policy_preview(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn upload_layer_part(
+        &self,
+        input: UploadLayerPartRequest,
+    ) -> RusotoFuture<UploadLayerPartResponse, UploadLayerPartError> {
+        (**self).upload_layer_part(input)
+    }
+}
+impl<T: Ecr + ?Sized> Ecr for Box<T> {
+    fn batch_check_layer_availability(
+        &self,
+        input: BatchCheckLayerAvailabilityRequest,
+    ) -> RusotoFuture<BatchCheckLayerAvailabilityResponse, BatchCheckLayerAvailabilityError> {
+        (**self).batch_check_layer_availability(input)
+    }
+    fn batch_delete_image(
+        &self,
+        input: BatchDeleteImageRequest,
+    ) -> RusotoFuture<BatchDeleteImageResponse, BatchDeleteImageError> {
+        (**self).batch_delete_image(input)
+    }
+    fn batch_get_image(
+        &self,
+        input: BatchGetImageRequest,
+    ) -> RusotoFuture<BatchGetImageResponse, BatchGetImageError> {
+        (**self).batch_get_image(input)
+    }
+    fn complete_layer_upload(
+        &self,
+        input: CompleteLayerUploadRequest,
+    ) -> RusotoFuture<CompleteLayerUploadResponse, CompleteLayerUploadError> {
+        (**self).complete_layer_upload(input)
+    }
+    fn create_repository(
+        &self,
+        input: CreateRepositoryRequest,
+    ) -> RusotoFuture<CreateRepositoryResponse, CreateRepositoryError> {
+        (**self).create_repository(input)
+    }
+    fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> RusotoFuture<DeleteLifecyclePolicyResponse, DeleteLifecyclePolicyError> {
+        (**self).delete_lifecycle_policy(input)
+    }
+    fn delete_repository(
+        &self,
+        input: DeleteRepositoryRequest,
+    ) -> RusotoFuture<DeleteRepositoryResponse, DeleteRepositoryError> {
+        (**self).delete_repository(input)
+    }
+    fn delete_repository_policy(
+        &self,
+        input: DeleteRepositoryPolicyRequest,
+    ) -> RusotoFuture<DeleteRepositoryPolicyResponse, DeleteRepositoryPolicyError> {
+        (**self).delete_repository_policy(input)
+    }
+    fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> RusotoFuture<DescribeImagesResponse, DescribeImagesError> {
+        (**self).describe_images(input)
+    }
+    fn describe_repositories(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> RusotoFuture<DescribeRepositoriesResponse, DescribeRepositoriesError> {
+        (**self).describe_repositories(input)
+    }
+    fn get_authorization_token(
+        &self,
+        input: GetAuthorizationTokenRequest,
+    ) -> RusotoFuture<GetAuthorizationTokenResponse, GetAuthorizationTokenError> {
+        (**self).get_authorization_token(input)
+    }
+    fn get_download_url_for_layer(
+        &self,
+        input: GetDownloadUrlForLayerRequest,
+    ) -> RusotoFuture<GetDownloadUrlForLayerResponse, GetDownloadUrlForLayerError> {
+        (**self).get_download_url_for_layer(input)
+    }
+    fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> RusotoFuture<GetLifecyclePolicyResponse, GetLifecyclePolicyError> {
+        (**self).get_lifecycle_policy(input)
+    }
+    fn get_lifecycle_policy_preview(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> RusotoFuture<GetLifecyclePolicyPreviewResponse, GetLifecyclePolicyPreviewError> {
+        (**self).get_lifecycle_policy_preview(input)
+    }
+    fn get_repository_policy(
+        &self,
+        input: GetRepositoryPolicyRequest,
+    ) -> RusotoFuture<GetRepositoryPolicyResponse, GetRepositoryPolicyError> {
+        (**self).get_repository_policy(input)
+    }
+    fn initiate_layer_upload(
+        &self,
+        input: InitiateLayerUploadRequest,
+    ) -> RusotoFuture<InitiateLayerUploadResponse, InitiateLayerUploadError> {
+        (**self).initiate_layer_upload(input)
+    }
+    fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> RusotoFuture<ListImagesResponse, ListImagesError> {
+        (**self).list_images(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_image(&self, input: PutImageRequest) -> RusotoFuture<PutImageResponse, PutImageError> {
+        (**self).put_image(input)
+    }
+    fn put_image_tag_mutability(
+        &self,
+        input: PutImageTagMutabilityRequest,
+    ) -> RusotoFuture<PutImageTagMutabilityResponse, PutImageTagMutabilityError> {
+        (**self).put_image_tag_mutability(input)
+    }
+    fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyRequest,
+    ) -> RusotoFuture<PutLifecyclePolicyResponse, PutLifecyclePolicyError> {
+        (**self).put_lifecycle_policy(input)
+    }
+    fn set_repository_policy(
+        &self,
+        input: SetRepositoryPolicyRequest,
+    ) -> RusotoFuture<SetRepositoryPolicyResponse, SetRepositoryPolicyError> {
+        (**self).set_repository_policy(input)
+    }
+    fn start_lifecycle_policy_preview(
+        &self,
+        input: StartLifecyclePolicyPreviewRequest,
+    ) -> RusotoFuture<StartLifecyclePolicyPreviewResponse, StartLifecyclePolicyPreviewError> {
+        (**self).start_lifecycle_policy_preview(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn upload_layer_part(
+        &self,
+        input: UploadLayerPartRequest,
+    ) -> RusotoFuture<UploadLayerPartResponse, UploadLayerPartError> {
+        (**self).upload_layer_part(input)
+    }
+}
+impl<T: Ecr + ?Sized> Ecr for ::std::sync::Arc<T> {
+    fn batch_check_layer_availability(
+        &self,
+        input: BatchCheckLayerAvailabilityRequest,
+    ) -> RusotoFuture<BatchCheckLayerAvailabilityResponse, BatchCheckLayerAvailabilityError> {
+        (**self).batch_check_layer_availability(input)
+    }
+    fn batch_delete_image(
+        &self,
+        input: BatchDeleteImageRequest,
+    ) -> RusotoFuture<BatchDeleteImageResponse, BatchDeleteImageError> {
+        (**self).batch_delete_image(input)
+    }
+    fn batch_get_image(
+        &self,
+        input: BatchGetImageRequest,
+    ) -> RusotoFuture<BatchGetImageResponse, BatchGetImageError> {
+        (**self).batch_get_image(input)
+    }
+    fn complete_layer_upload(
+        &self,
+        input: CompleteLayerUploadRequest,
+    ) -> RusotoFuture<CompleteLayerUploadResponse, CompleteLayerUploadError> {
+        (**self).complete_layer_upload(input)
+    }
+    fn create_repository(
+        &self,
+        input: CreateRepositoryRequest,
+    ) -> RusotoFuture<CreateRepositoryResponse, CreateRepositoryError> {
+        (**self).create_repository(input)
+    }
+    fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> RusotoFuture<DeleteLifecyclePolicyResponse, DeleteLifecyclePolicyError> {
+        (**self).delete_lifecycle_policy(input)
+    }
+    fn delete_repository(
+        &self,
+        input: DeleteRepositoryRequest,
+    ) -> RusotoFuture<DeleteRepositoryResponse, DeleteRepositoryError> {
+        (**self).delete_repository(input)
+    }
+    fn delete_repository_policy(
+        &self,
+        input: DeleteRepositoryPolicyRequest,
+    ) -> RusotoFuture<DeleteRepositoryPolicyResponse, DeleteRepositoryPolicyError> {
+        (**self).delete_repository_policy(input)
+    }
+    fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> RusotoFuture<DescribeImagesResponse, DescribeImagesError> {
+        (**self).describe_images(input)
+    }
+    fn describe_repositories(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> RusotoFuture<DescribeRepositoriesResponse, DescribeRepositoriesError> {
+        (**self).describe_repositories(input)
+    }
+    fn get_authorization_token(
+        &self,
+        input: GetAuthorizationTokenRequest,
+    ) -> RusotoFuture<GetAuthorizationTokenResponse, GetAuthorizationTokenError> {
+        (**self).get_authorization_token(input)
+    }
+    fn get_download_url_for_layer(
+        &self,
+        input: GetDownloadUrlForLayerRequest,
+    ) -> RusotoFuture<GetDownloadUrlForLayerResponse, GetDownloadUrlForLayerError> {
+        (**self).get_download_url_for_layer(input)
+    }
+    fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> RusotoFuture<GetLifecyclePolicyResponse, GetLifecyclePolicyError> {
+        (**self).get_lifecycle_policy(input)
+    }
+    fn get_lifecycle_policy_preview(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> RusotoFuture<GetLifecyclePolicyPreviewResponse, GetLifecyclePolicyPreviewError> {
+        (**self).get_lifecycle_policy_preview(input)
+    }
+    fn get_repository_policy(
+        &self,
+        input: GetRepositoryPolicyRequest,
+    ) -> RusotoFuture<GetRepositoryPolicyResponse, GetRepositoryPolicyError> {
+        (**self).get_repository_policy(input)
+    }
+    fn initiate_layer_upload(
+        &self,
+        input: InitiateLayerUploadRequest,
+    ) -> RusotoFuture<InitiateLayerUploadResponse, InitiateLayerUploadError> {
+        (**self).initiate_layer_upload(input)
+    }
+    fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> RusotoFuture<ListImagesResponse, ListImagesError> {
+        (**self).list_images(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_image(&self, input: PutImageRequest) -> RusotoFuture<PutImageResponse, PutImageError> {
+        (**self).put_image(input)
+    }
+    fn put_image_tag_mutability(
+        &self,
+        input: PutImageTagMutabilityRequest,
+    ) -> RusotoFuture<PutImageTagMutabilityResponse, PutImageTagMutabilityError> {
+        (**self).put_image_tag_mutability(input)
+    }
+    fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyRequest,
+    ) -> RusotoFuture<PutLifecyclePolicyResponse, PutLifecyclePolicyError> {
+        (**self).put_lifecycle_policy(input)
+    }
+    fn set_repository_policy(
+        &self,
+        input: SetRepositoryPolicyRequest,
+    ) -> RusotoFuture<SetRepositoryPolicyResponse, SetRepositoryPolicyError> {
+        (**self).set_repository_policy(input)
+    }
+    fn start_lifecycle_policy_preview(
+        &self,
+        input: StartLifecyclePolicyPreviewRequest,
+    ) -> RusotoFuture<StartLifecyclePolicyPreviewResponse, StartLifecyclePolicyPreviewError> {
+        (**self).start_lifecycle_policy_preview(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn upload_layer_part(
+        &self,
+        input: UploadLayerPartRequest,
+    ) -> RusotoFuture<UploadLayerPartResponse, UploadLayerPartError> {
+        (**self).upload_layer_part(input)
+    }
+}
+/// A client for the Amazon ECR API.
+#[derive(Clone)]
+pub struct EcrClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl EcrClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EcrClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EcrClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> EcrClient {
+        EcrClient { client, region }
+    }
+}
+
+impl Ecr for EcrClient {
+    /// <p><p>Check the availability of multiple image layers in a specified registry and repository.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    fn batch_check_layer_availability(
+        &self,
+        input: BatchCheckLayerAvailabilityRequest,
+    ) -> RusotoFuture<BatchCheckLayerAvailabilityResponse, BatchCheckLayerAvailabilityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("BatchCheckLayerAvailability");
+        request.set_endpoint_prefix("api.ecr".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerRegistry_V20150921.BatchCheckLayerAvailability",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchCheckLayerAvailabilityResponse, _>(
+                    response,
+                    "BatchCheckLayerAvailability",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDeleteImageResponse, _>()
+                    Err(BatchCheckLayerAvailabilityError::from_response(response))
                 }))
+            }
+        })
+    }
+
+    /// <p>Deletes a list of specified images within a specified repository. Images are specified with either <code>imageTag</code> or <code>imageDigest</code>.</p> <p>You can remove a tag from an image by specifying the image's tag in your request. When you remove the last tag from an image, the image is deleted from your repository.</p> <p>You can completely delete an image (and all of its tags) by specifying the image's digest in your request.</p>
+    fn batch_delete_image(
+        &self,
+        input: BatchDeleteImageRequest,
+    ) -> RusotoFuture<BatchDeleteImageResponse, BatchDeleteImageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("BatchDeleteImage");
+        request.set_endpoint_prefix("api.ecr".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerRegistry_V20150921.BatchDeleteImage",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDeleteImageResponse, _>(
+                    response,
+                    "BatchDeleteImage",
+                )
             } else {
                 Box::new(
                     response
@@ -2749,7 +3981,16 @@ impl Ecr for EcrClient {
         &self,
         input: BatchGetImageRequest,
     ) -> RusotoFuture<BatchGetImageResponse, BatchGetImageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("BatchGetImage");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2761,10 +4002,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetImageResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<BatchGetImageResponse, _>(
+                    response,
+                    "BatchGetImage",
+                )
             } else {
                 Box::new(
                     response
@@ -2781,7 +4022,16 @@ impl Ecr for EcrClient {
         &self,
         input: CompleteLayerUploadRequest,
     ) -> RusotoFuture<CompleteLayerUploadResponse, CompleteLayerUploadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("CompleteLayerUpload");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2793,10 +4043,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CompleteLayerUploadResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CompleteLayerUploadResponse, _>(
+                    response,
+                    "CompleteLayerUpload",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2812,7 +4062,16 @@ impl Ecr for EcrClient {
         &self,
         input: CreateRepositoryRequest,
     ) -> RusotoFuture<CreateRepositoryResponse, CreateRepositoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("CreateRepository");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2824,10 +4083,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRepositoryResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateRepositoryResponse, _>(
+                    response,
+                    "CreateRepository",
+                )
             } else {
                 Box::new(
                     response
@@ -2844,7 +4103,16 @@ impl Ecr for EcrClient {
         &self,
         input: DeleteLifecyclePolicyRequest,
     ) -> RusotoFuture<DeleteLifecyclePolicyResponse, DeleteLifecyclePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DeleteLifecyclePolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2856,10 +4124,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteLifecyclePolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteLifecyclePolicyResponse, _>(
+                    response,
+                    "DeleteLifecyclePolicy",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2875,7 +4143,16 @@ impl Ecr for EcrClient {
         &self,
         input: DeleteRepositoryRequest,
     ) -> RusotoFuture<DeleteRepositoryResponse, DeleteRepositoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DeleteRepository");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2887,10 +4164,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRepositoryResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteRepositoryResponse, _>(
+                    response,
+                    "DeleteRepository",
+                )
             } else {
                 Box::new(
                     response
@@ -2907,7 +4184,16 @@ impl Ecr for EcrClient {
         &self,
         input: DeleteRepositoryPolicyRequest,
     ) -> RusotoFuture<DeleteRepositoryPolicyResponse, DeleteRepositoryPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DeleteRepositoryPolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2919,10 +4205,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRepositoryPolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteRepositoryPolicyResponse, _>(
+                    response,
+                    "DeleteRepositoryPolicy",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2938,7 +4224,16 @@ impl Ecr for EcrClient {
         &self,
         input: DescribeImagesRequest,
     ) -> RusotoFuture<DescribeImagesResponse, DescribeImagesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DescribeImages");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2950,10 +4245,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeImagesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeImagesResponse, _>(
+                    response,
+                    "DescribeImages",
+                )
             } else {
                 Box::new(
                     response
@@ -2970,7 +4265,16 @@ impl Ecr for EcrClient {
         &self,
         input: DescribeRepositoriesRequest,
     ) -> RusotoFuture<DescribeRepositoriesResponse, DescribeRepositoriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DescribeRepositories");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2982,10 +4286,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRepositoriesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeRepositoriesResponse, _>(
+                    response,
+                    "DescribeRepositories",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3002,6 +4306,7 @@ impl Ecr for EcrClient {
         input: GetAuthorizationTokenRequest,
     ) -> RusotoFuture<GetAuthorizationTokenResponse, GetAuthorizationTokenError> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetAuthorizationToken");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3013,10 +4318,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAuthorizationTokenResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetAuthorizationTokenResponse, _>(
+                    response,
+                    "GetAuthorizationToken",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3032,7 +4337,16 @@ impl Ecr for EcrClient {
         &self,
         input: GetDownloadUrlForLayerRequest,
     ) -> RusotoFuture<GetDownloadUrlForLayerResponse, GetDownloadUrlForLayerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetDownloadUrlForLayer");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3044,10 +4358,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDownloadUrlForLayerResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetDownloadUrlForLayerResponse, _>(
+                    response,
+                    "GetDownloadUrlForLayer",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3063,7 +4377,16 @@ impl Ecr for EcrClient {
         &self,
         input: GetLifecyclePolicyRequest,
     ) -> RusotoFuture<GetLifecyclePolicyResponse, GetLifecyclePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetLifecyclePolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3075,10 +4398,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLifecyclePolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetLifecyclePolicyResponse, _>(
+                    response,
+                    "GetLifecyclePolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -3095,7 +4418,16 @@ impl Ecr for EcrClient {
         &self,
         input: GetLifecyclePolicyPreviewRequest,
     ) -> RusotoFuture<GetLifecyclePolicyPreviewResponse, GetLifecyclePolicyPreviewError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetLifecyclePolicyPreview");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3107,10 +4439,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLifecyclePolicyPreviewResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetLifecyclePolicyPreviewResponse, _>(
+                    response,
+                    "GetLifecyclePolicyPreview",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetLifecyclePolicyPreviewError::from_response(response))
@@ -3124,7 +4456,16 @@ impl Ecr for EcrClient {
         &self,
         input: GetRepositoryPolicyRequest,
     ) -> RusotoFuture<GetRepositoryPolicyResponse, GetRepositoryPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetRepositoryPolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3136,10 +4477,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRepositoryPolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetRepositoryPolicyResponse, _>(
+                    response,
+                    "GetRepositoryPolicy",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3155,7 +4496,16 @@ impl Ecr for EcrClient {
         &self,
         input: InitiateLayerUploadRequest,
     ) -> RusotoFuture<InitiateLayerUploadResponse, InitiateLayerUploadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("InitiateLayerUpload");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3167,10 +4517,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InitiateLayerUploadResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<InitiateLayerUploadResponse, _>(
+                    response,
+                    "InitiateLayerUpload",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3186,7 +4536,16 @@ impl Ecr for EcrClient {
         &self,
         input: ListImagesRequest,
     ) -> RusotoFuture<ListImagesResponse, ListImagesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("ListImages");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3198,10 +4557,7 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListImagesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListImagesResponse, _>(response, "ListImages")
             } else {
                 Box::new(
                     response
@@ -3219,6 +4575,7 @@ impl Ecr for EcrClient {
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3230,10 +4587,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3246,7 +4603,16 @@ impl Ecr for EcrClient {
 
     /// <p><p>Creates or updates the image manifest and tags associated with an image.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
     fn put_image(&self, input: PutImageRequest) -> RusotoFuture<PutImageResponse, PutImageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutImage");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3258,10 +4624,7 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutImageResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<PutImageResponse, _>(response, "PutImage")
             } else {
                 Box::new(
                     response
@@ -3278,7 +4641,16 @@ impl Ecr for EcrClient {
         &self,
         input: PutImageTagMutabilityRequest,
     ) -> RusotoFuture<PutImageTagMutabilityResponse, PutImageTagMutabilityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutImageTagMutability");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3290,10 +4662,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutImageTagMutabilityResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<PutImageTagMutabilityResponse, _>(
+                    response,
+                    "PutImageTagMutability",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3309,7 +4681,16 @@ impl Ecr for EcrClient {
         &self,
         input: PutLifecyclePolicyRequest,
     ) -> RusotoFuture<PutLifecyclePolicyResponse, PutLifecyclePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutLifecyclePolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3321,10 +4702,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutLifecyclePolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<PutLifecyclePolicyResponse, _>(
+                    response,
+                    "PutLifecyclePolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -3341,7 +4722,16 @@ impl Ecr for EcrClient {
         &self,
         input: SetRepositoryPolicyRequest,
     ) -> RusotoFuture<SetRepositoryPolicyResponse, SetRepositoryPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("SetRepositoryPolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3353,10 +4743,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SetRepositoryPolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<SetRepositoryPolicyResponse, _>(
+                    response,
+                    "SetRepositoryPolicy",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3372,7 +4762,16 @@ impl Ecr for EcrClient {
         &self,
         input: StartLifecyclePolicyPreviewRequest,
     ) -> RusotoFuture<StartLifecyclePolicyPreviewResponse, StartLifecyclePolicyPreviewError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("StartLifecyclePolicyPreview");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3384,10 +4783,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartLifecyclePolicyPreviewResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StartLifecyclePolicyPreviewResponse, _>(
+                    response,
+                    "StartLifecyclePolicyPreview",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(StartLifecyclePolicyPreviewError::from_response(response))
@@ -3402,6 +4801,7 @@ impl Ecr for EcrClient {
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("TagResource");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3413,10 +4813,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -3434,6 +4834,7 @@ impl Ecr for EcrClient {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("UntagResource");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3445,10 +4846,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -3465,7 +4866,16 @@ impl Ecr for EcrClient {
         &self,
         input: UploadLayerPartRequest,
     ) -> RusotoFuture<UploadLayerPartResponse, UploadLayerPartError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("UploadLayerPart");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3477,10 +4887,10 @@ impl Ecr for EcrClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UploadLayerPartResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UploadLayerPartResponse, _>(
+                    response,
+                    "UploadLayerPart",
+                )
             } else {
                 Box::new(
                     response
@@ -3492,3 +4902,919 @@ impl Ecr for EcrClient {
         })
     }
 }
+
+/// A mock of the Amazon ECR API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockEcr {
+    batch_check_layer_availability: Option<
+        Box<
+            dyn Fn(
+                    BatchCheckLayerAvailabilityRequest,
+                ) -> ::std::result::Result<
+                    BatchCheckLayerAvailabilityResponse,
+                    RusotoError<BatchCheckLayerAvailabilityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_delete_image: Option<
+        Box<
+            dyn Fn(
+                    BatchDeleteImageRequest,
+                ) -> ::std::result::Result<
+                    BatchDeleteImageResponse,
+                    RusotoError<BatchDeleteImageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_image: Option<
+        Box<
+            dyn Fn(
+                    BatchGetImageRequest,
+                )
+                    -> ::std::result::Result<BatchGetImageResponse, RusotoError<BatchGetImageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    complete_layer_upload: Option<
+        Box<
+            dyn Fn(
+                    CompleteLayerUploadRequest,
+                ) -> ::std::result::Result<
+                    CompleteLayerUploadResponse,
+                    RusotoError<CompleteLayerUploadError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_repository: Option<
+        Box<
+            dyn Fn(
+                    CreateRepositoryRequest,
+                ) -> ::std::result::Result<
+                    CreateRepositoryResponse,
+                    RusotoError<CreateRepositoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_lifecycle_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteLifecyclePolicyRequest,
+                ) -> ::std::result::Result<
+                    DeleteLifecyclePolicyResponse,
+                    RusotoError<DeleteLifecyclePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_repository: Option<
+        Box<
+            dyn Fn(
+                    DeleteRepositoryRequest,
+                ) -> ::std::result::Result<
+                    DeleteRepositoryResponse,
+                    RusotoError<DeleteRepositoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_repository_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteRepositoryPolicyRequest,
+                ) -> ::std::result::Result<
+                    DeleteRepositoryPolicyResponse,
+                    RusotoError<DeleteRepositoryPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_images: Option<
+        Box<
+            dyn Fn(
+                    DescribeImagesRequest,
+                ) -> ::std::result::Result<
+                    DescribeImagesResponse,
+                    RusotoError<DescribeImagesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_repositories: Option<
+        Box<
+            dyn Fn(
+                    DescribeRepositoriesRequest,
+                ) -> ::std::result::Result<
+                    DescribeRepositoriesResponse,
+                    RusotoError<DescribeRepositoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_authorization_token: Option<
+        Box<
+            dyn Fn(
+                    GetAuthorizationTokenRequest,
+                ) -> ::std::result::Result<
+                    GetAuthorizationTokenResponse,
+                    RusotoError<GetAuthorizationTokenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_download_url_for_layer: Option<
+        Box<
+            dyn Fn(
+                    GetDownloadUrlForLayerRequest,
+                ) -> ::std::result::Result<
+                    GetDownloadUrlForLayerResponse,
+                    RusotoError<GetDownloadUrlForLayerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_lifecycle_policy: Option<
+        Box<
+            dyn Fn(
+                    GetLifecyclePolicyRequest,
+                ) -> ::std::result::Result<
+                    GetLifecyclePolicyResponse,
+                    RusotoError<GetLifecyclePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_lifecycle_policy_preview: Option<
+        Box<
+            dyn Fn(
+                    GetLifecyclePolicyPreviewRequest,
+                ) -> ::std::result::Result<
+                    GetLifecyclePolicyPreviewResponse,
+                    RusotoError<GetLifecyclePolicyPreviewError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_repository_policy: Option<
+        Box<
+            dyn Fn(
+                    GetRepositoryPolicyRequest,
+                ) -> ::std::result::Result<
+                    GetRepositoryPolicyResponse,
+                    RusotoError<GetRepositoryPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    initiate_layer_upload: Option<
+        Box<
+            dyn Fn(
+                    InitiateLayerUploadRequest,
+                ) -> ::std::result::Result<
+                    InitiateLayerUploadResponse,
+                    RusotoError<InitiateLayerUploadError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_images: Option<
+        Box<
+            dyn Fn(
+                    ListImagesRequest,
+                )
+                    -> ::std::result::Result<ListImagesResponse, RusotoError<ListImagesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_image: Option<
+        Box<
+            dyn Fn(
+                    PutImageRequest,
+                )
+                    -> ::std::result::Result<PutImageResponse, RusotoError<PutImageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_image_tag_mutability: Option<
+        Box<
+            dyn Fn(
+                    PutImageTagMutabilityRequest,
+                ) -> ::std::result::Result<
+                    PutImageTagMutabilityResponse,
+                    RusotoError<PutImageTagMutabilityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_lifecycle_policy: Option<
+        Box<
+            dyn Fn(
+                    PutLifecyclePolicyRequest,
+                ) -> ::std::result::Result<
+                    PutLifecyclePolicyResponse,
+                    RusotoError<PutLifecyclePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_repository_policy: Option<
+        Box<
+            dyn Fn(
+                    SetRepositoryPolicyRequest,
+                ) -> ::std::result::Result<
+                    SetRepositoryPolicyResponse,
+                    RusotoError<SetRepositoryPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_lifecycle_policy_preview: Option<
+        Box<
+            dyn Fn(
+                    StartLifecyclePolicyPreviewRequest,
+                ) -> ::std::result::Result<
+                    StartLifecyclePolicyPreviewResponse,
+                    RusotoError<StartLifecyclePolicyPreviewError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    upload_layer_part: Option<
+        Box<
+            dyn Fn(
+                    UploadLayerPartRequest,
+                ) -> ::std::result::Result<
+                    UploadLayerPartResponse,
+                    RusotoError<UploadLayerPartError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockEcr {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockEcr {
+        Default::default()
+    }
+
+    /// Answers `batch_check_layer_availability` with what `handler` returns for its input.
+    pub fn with_batch_check_layer_availability<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                BatchCheckLayerAvailabilityRequest,
+            ) -> ::std::result::Result<
+                BatchCheckLayerAvailabilityResponse,
+                RusotoError<BatchCheckLayerAvailabilityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_check_layer_availability = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_delete_image` with what `handler` returns for its input.
+    pub fn with_batch_delete_image<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                BatchDeleteImageRequest,
+            ) -> ::std::result::Result<
+                BatchDeleteImageResponse,
+                RusotoError<BatchDeleteImageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_image = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_image` with what `handler` returns for its input.
+    pub fn with_batch_get_image<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                BatchGetImageRequest,
+            )
+                -> ::std::result::Result<BatchGetImageResponse, RusotoError<BatchGetImageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_image = Some(Box::new(handler));
+        self
+    }
+    /// Answers `complete_layer_upload` with what `handler` returns for its input.
+    pub fn with_complete_layer_upload<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                CompleteLayerUploadRequest,
+            ) -> ::std::result::Result<
+                CompleteLayerUploadResponse,
+                RusotoError<CompleteLayerUploadError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.complete_layer_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_repository` with what `handler` returns for its input.
+    pub fn with_create_repository<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                CreateRepositoryRequest,
+            ) -> ::std::result::Result<
+                CreateRepositoryResponse,
+                RusotoError<CreateRepositoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_repository = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_lifecycle_policy` with what `handler` returns for its input.
+    pub fn with_delete_lifecycle_policy<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                DeleteLifecyclePolicyRequest,
+            ) -> ::std::result::Result<
+                DeleteLifecyclePolicyResponse,
+                RusotoError<DeleteLifecyclePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_lifecycle_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_repository` with what `handler` returns for its input.
+    pub fn with_delete_repository<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                DeleteRepositoryRequest,
+            ) -> ::std::result::Result<
+                DeleteRepositoryResponse,
+                RusotoError<DeleteRepositoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_repository = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_repository_policy` with what `handler` returns for its input.
+    pub fn with_delete_repository_policy<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                DeleteRepositoryPolicyRequest,
+            ) -> ::std::result::Result<
+                DeleteRepositoryPolicyResponse,
+                RusotoError<DeleteRepositoryPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_repository_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_images` with what `handler` returns for its input.
+    pub fn with_describe_images<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                DescribeImagesRequest,
+            )
+                -> ::std::result::Result<DescribeImagesResponse, RusotoError<DescribeImagesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_images = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_repositories` with what `handler` returns for its input.
+    pub fn with_describe_repositories<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                DescribeRepositoriesRequest,
+            ) -> ::std::result::Result<
+                DescribeRepositoriesResponse,
+                RusotoError<DescribeRepositoriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_repositories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_authorization_token` with what `handler` returns for its input.
+    pub fn with_get_authorization_token<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                GetAuthorizationTokenRequest,
+            ) -> ::std::result::Result<
+                GetAuthorizationTokenResponse,
+                RusotoError<GetAuthorizationTokenError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_authorization_token = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_download_url_for_layer` with what `handler` returns for its input.
+    pub fn with_get_download_url_for_layer<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                GetDownloadUrlForLayerRequest,
+            ) -> ::std::result::Result<
+                GetDownloadUrlForLayerResponse,
+                RusotoError<GetDownloadUrlForLayerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_download_url_for_layer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_lifecycle_policy` with what `handler` returns for its input.
+    pub fn with_get_lifecycle_policy<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                GetLifecyclePolicyRequest,
+            ) -> ::std::result::Result<
+                GetLifecyclePolicyResponse,
+                RusotoError<GetLifecyclePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_lifecycle_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_lifecycle_policy_preview` with what `handler` returns for its input.
+    pub fn with_get_lifecycle_policy_preview<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                GetLifecyclePolicyPreviewRequest,
+            ) -> ::std::result::Result<
+                GetLifecyclePolicyPreviewResponse,
+                RusotoError<GetLifecyclePolicyPreviewError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_lifecycle_policy_preview = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_repository_policy` with what `handler` returns for its input.
+    pub fn with_get_repository_policy<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                GetRepositoryPolicyRequest,
+            ) -> ::std::result::Result<
+                GetRepositoryPolicyResponse,
+                RusotoError<GetRepositoryPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_repository_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `initiate_layer_upload` with what `handler` returns for its input.
+    pub fn with_initiate_layer_upload<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                InitiateLayerUploadRequest,
+            ) -> ::std::result::Result<
+                InitiateLayerUploadResponse,
+                RusotoError<InitiateLayerUploadError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.initiate_layer_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_images` with what `handler` returns for its input.
+    pub fn with_list_images<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                ListImagesRequest,
+            )
+                -> ::std::result::Result<ListImagesResponse, RusotoError<ListImagesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_images = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_image` with what `handler` returns for its input.
+    pub fn with_put_image<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                PutImageRequest,
+            ) -> ::std::result::Result<PutImageResponse, RusotoError<PutImageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_image = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_image_tag_mutability` with what `handler` returns for its input.
+    pub fn with_put_image_tag_mutability<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                PutImageTagMutabilityRequest,
+            ) -> ::std::result::Result<
+                PutImageTagMutabilityResponse,
+                RusotoError<PutImageTagMutabilityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_image_tag_mutability = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_lifecycle_policy` with what `handler` returns for its input.
+    pub fn with_put_lifecycle_policy<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                PutLifecyclePolicyRequest,
+            ) -> ::std::result::Result<
+                PutLifecyclePolicyResponse,
+                RusotoError<PutLifecyclePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_lifecycle_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_repository_policy` with what `handler` returns for its input.
+    pub fn with_set_repository_policy<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                SetRepositoryPolicyRequest,
+            ) -> ::std::result::Result<
+                SetRepositoryPolicyResponse,
+                RusotoError<SetRepositoryPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_repository_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_lifecycle_policy_preview` with what `handler` returns for its input.
+    pub fn with_start_lifecycle_policy_preview<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                StartLifecyclePolicyPreviewRequest,
+            ) -> ::std::result::Result<
+                StartLifecyclePolicyPreviewResponse,
+                RusotoError<StartLifecyclePolicyPreviewError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_lifecycle_policy_preview = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `upload_layer_part` with what `handler` returns for its input.
+    pub fn with_upload_layer_part<F>(mut self, handler: F) -> MockEcr
+    where
+        F: Fn(
+                UploadLayerPartRequest,
+            )
+                -> ::std::result::Result<UploadLayerPartResponse, RusotoError<UploadLayerPartError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.upload_layer_part = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Ecr for MockEcr {
+    fn batch_check_layer_availability(
+        &self,
+        input: BatchCheckLayerAvailabilityRequest,
+    ) -> RusotoFuture<BatchCheckLayerAvailabilityResponse, BatchCheckLayerAvailabilityError> {
+        match self.batch_check_layer_availability {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for batch_check_layer_availability"),
+        }
+    }
+    fn batch_delete_image(
+        &self,
+        input: BatchDeleteImageRequest,
+    ) -> RusotoFuture<BatchDeleteImageResponse, BatchDeleteImageError> {
+        match self.batch_delete_image {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for batch_delete_image"),
+        }
+    }
+    fn batch_get_image(
+        &self,
+        input: BatchGetImageRequest,
+    ) -> RusotoFuture<BatchGetImageResponse, BatchGetImageError> {
+        match self.batch_get_image {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for batch_get_image"),
+        }
+    }
+    fn complete_layer_upload(
+        &self,
+        input: CompleteLayerUploadRequest,
+    ) -> RusotoFuture<CompleteLayerUploadResponse, CompleteLayerUploadError> {
+        match self.complete_layer_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for complete_layer_upload"),
+        }
+    }
+    fn create_repository(
+        &self,
+        input: CreateRepositoryRequest,
+    ) -> RusotoFuture<CreateRepositoryResponse, CreateRepositoryError> {
+        match self.create_repository {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for create_repository"),
+        }
+    }
+    fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> RusotoFuture<DeleteLifecyclePolicyResponse, DeleteLifecyclePolicyError> {
+        match self.delete_lifecycle_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for delete_lifecycle_policy"),
+        }
+    }
+    fn delete_repository(
+        &self,
+        input: DeleteRepositoryRequest,
+    ) -> RusotoFuture<DeleteRepositoryResponse, DeleteRepositoryError> {
+        match self.delete_repository {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for delete_repository"),
+        }
+    }
+    fn delete_repository_policy(
+        &self,
+        input: DeleteRepositoryPolicyRequest,
+    ) -> RusotoFuture<DeleteRepositoryPolicyResponse, DeleteRepositoryPolicyError> {
+        match self.delete_repository_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for delete_repository_policy"),
+        }
+    }
+    fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> RusotoFuture<DescribeImagesResponse, DescribeImagesError> {
+        match self.describe_images {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for describe_images"),
+        }
+    }
+    fn describe_repositories(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> RusotoFuture<DescribeRepositoriesResponse, DescribeRepositoriesError> {
+        match self.describe_repositories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for describe_repositories"),
+        }
+    }
+    fn get_authorization_token(
+        &self,
+        input: GetAuthorizationTokenRequest,
+    ) -> RusotoFuture<GetAuthorizationTokenResponse, GetAuthorizationTokenError> {
+        match self.get_authorization_token {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for get_authorization_token"),
+        }
+    }
+    fn get_download_url_for_layer(
+        &self,
+        input: GetDownloadUrlForLayerRequest,
+    ) -> RusotoFuture<GetDownloadUrlForLayerResponse, GetDownloadUrlForLayerError> {
+        match self.get_download_url_for_layer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for get_download_url_for_layer"),
+        }
+    }
+    fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> RusotoFuture<GetLifecyclePolicyResponse, GetLifecyclePolicyError> {
+        match self.get_lifecycle_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for get_lifecycle_policy"),
+        }
+    }
+    fn get_lifecycle_policy_preview(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> RusotoFuture<GetLifecyclePolicyPreviewResponse, GetLifecyclePolicyPreviewError> {
+        match self.get_lifecycle_policy_preview {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for get_lifecycle_policy_preview"),
+        }
+    }
+    fn get_repository_policy(
+        &self,
+        input: GetRepositoryPolicyRequest,
+    ) -> RusotoFuture<GetRepositoryPolicyResponse, GetRepositoryPolicyError> {
+        match self.get_repository_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for get_repository_policy"),
+        }
+    }
+    fn initiate_layer_upload(
+        &self,
+        input: InitiateLayerUploadRequest,
+    ) -> RusotoFuture<InitiateLayerUploadResponse, InitiateLayerUploadError> {
+        match self.initiate_layer_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for initiate_layer_upload"),
+        }
+    }
+    fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> RusotoFuture<ListImagesResponse, ListImagesError> {
+        match self.list_images {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for list_images"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for list_tags_for_resource"),
+        }
+    }
+    fn put_image(&self, input: PutImageRequest) -> RusotoFuture<PutImageResponse, PutImageError> {
+        match self.put_image {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for put_image"),
+        }
+    }
+    fn put_image_tag_mutability(
+        &self,
+        input: PutImageTagMutabilityRequest,
+    ) -> RusotoFuture<PutImageTagMutabilityResponse, PutImageTagMutabilityError> {
+        match self.put_image_tag_mutability {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for put_image_tag_mutability"),
+        }
+    }
+    fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyRequest,
+    ) -> RusotoFuture<PutLifecyclePolicyResponse, PutLifecyclePolicyError> {
+        match self.put_lifecycle_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for put_lifecycle_policy"),
+        }
+    }
+    fn set_repository_policy(
+        &self,
+        input: SetRepositoryPolicyRequest,
+    ) -> RusotoFuture<SetRepositoryPolicyResponse, SetRepositoryPolicyError> {
+        match self.set_repository_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for set_repository_policy"),
+        }
+    }
+    fn start_lifecycle_policy_preview(
+        &self,
+        input: StartLifecyclePolicyPreviewRequest,
+    ) -> RusotoFuture<StartLifecyclePolicyPreviewResponse, StartLifecyclePolicyPreviewError> {
+        match self.start_lifecycle_policy_preview {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for start_lifecycle_policy_preview"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for untag_resource"),
+        }
+    }
+    fn upload_layer_part(
+        &self,
+        input: UploadLayerPartRequest,
+    ) -> RusotoFuture<UploadLayerPartResponse, UploadLayerPartError> {
+        match self.upload_layer_part {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcr has no handler for upload_layer_part"),
+        }
+    }
+}
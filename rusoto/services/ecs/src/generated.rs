@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>An object representing a container instance or task attachment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Attachment {
     /// <p>Details of the attachment. For elastic network interfaces, this includes the network interface ID, the MAC address, the subnet ID, and the private IPv4 address.</p>
     #[serde(rename = "details")]
@@ -47,6 +48,7 @@ pub struct Attachment {
 
 /// <p>An object representing a change in state for a task attachment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachmentStateChange {
     /// <p>The Amazon Resource Name (ARN) of the attachment.</p>
     #[serde(rename = "attachmentArn")]
@@ -94,7 +96,7 @@ pub struct AwsVpcConfiguration {
 
 /// <p>A regional grouping of one or more container instances on which you can run task requests. Each account receives a default cluster the first time you use the Amazon ECS service, but you may also create other clusters. Clusters may contain more than one instance type simultaneously.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Cluster {
     /// <p>The number of services that are running on the cluster in an <code>ACTIVE</code> state. You can view these services with <a>ListServices</a>.</p>
     #[serde(rename = "activeServicesCount")]
@@ -153,7 +155,7 @@ pub struct ClusterSetting {
 
 /// <p>A Docker container that is part of a task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Container {
     /// <p>The Amazon Resource Name (ARN) of the container.</p>
     #[serde(rename = "containerArn")]
@@ -387,7 +389,7 @@ pub struct ContainerDependency {
 
 /// <p>An EC2 instance that is running the Amazon ECS agent and has been registered with a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ContainerInstance {
     /// <p>This parameter returns <code>true</code> if the agent is connected to Amazon ECS. Registered instances with an agent that may be unhealthy or stopped return <code>false</code>. Only instances connected to an agent can accept placement requests.</p>
     #[serde(rename = "agentConnected")]
@@ -490,6 +492,7 @@ pub struct ContainerOverride {
 
 /// <p>An object representing a change in state for a container.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContainerStateChange {
     /// <p>The name of the container.</p>
     #[serde(rename = "containerName")]
@@ -518,6 +521,7 @@ pub struct ContainerStateChange {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterRequest {
     /// <p>The name of your cluster. If you do not specify a name for your cluster, you create a cluster named <code>default</code>. Up to 255 letters (uppercase and lowercase), numbers, and hyphens are allowed. </p>
     #[serde(rename = "clusterName")]
@@ -533,8 +537,20 @@ pub struct CreateClusterRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateClusterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateClusterResponse {
     /// <p>The full description of your new cluster.</p>
     #[serde(rename = "cluster")]
@@ -543,6 +559,7 @@ pub struct CreateClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateServiceRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 32 ASCII characters are allowed.</p>
     #[serde(rename = "clientToken")]
@@ -625,8 +642,20 @@ pub struct CreateServiceRequest {
     pub task_definition: Option<String>,
 }
 
+impl Validate for CreateServiceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateServiceResponse {
     /// <p>The full description of your service following the create call.</p> <p>If a service is using the <code>ECS</code> deployment controller, the <code>deploymentController</code> and <code>taskSets</code> parameters will not be returned.</p> <p>If the service is using the <code>CODE_DEPLOY</code> deployment controller, the <code>deploymentController</code>, <code>taskSets</code> and <code>deployments</code> parameters will be returned, however the <code>deployments</code> parameter will be an empty list.</p>
     #[serde(rename = "service")]
@@ -635,6 +664,7 @@ pub struct CreateServiceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTaskSetRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 32 ASCII characters are allowed.</p>
     #[serde(rename = "clientToken")]
@@ -678,7 +708,7 @@ pub struct CreateTaskSetRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTaskSetResponse {
     #[serde(rename = "taskSet")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -686,6 +716,7 @@ pub struct CreateTaskSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAccountSettingRequest {
     /// <p>The resource name for which to disable the account setting. If <code>serviceLongArnFormat</code> is specified, the ARN for your Amazon ECS services is affected. If <code>taskLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS tasks is affected. If <code>containerInstanceLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS container instances is affected. If <code>awsvpcTrunking</code> is specified, the ENI limit for your Amazon ECS container instances is affected.</p>
     #[serde(rename = "name")]
@@ -697,7 +728,7 @@ pub struct DeleteAccountSettingRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteAccountSettingResponse {
     /// <p>The account setting for the specified principal ARN.</p>
     #[serde(rename = "setting")]
@@ -706,6 +737,7 @@ pub struct DeleteAccountSettingResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAttributesRequest {
     /// <p>The attributes to delete from your resource. You can specify up to 10 attributes per request. For custom attributes, specify the attribute name and target ID, but do not specify the value. If you specify the target ID using the short form, you must also specify the target type.</p>
     #[serde(rename = "attributes")]
@@ -717,7 +749,7 @@ pub struct DeleteAttributesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteAttributesResponse {
     /// <p>A list of attribute objects that were successfully deleted from your resource.</p>
     #[serde(rename = "attributes")]
@@ -726,6 +758,7 @@ pub struct DeleteAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster to delete.</p>
     #[serde(rename = "cluster")]
@@ -733,7 +766,7 @@ pub struct DeleteClusterRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteClusterResponse {
     /// <p>The full description of the deleted cluster.</p>
     #[serde(rename = "cluster")]
@@ -742,6 +775,7 @@ pub struct DeleteClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteServiceRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the service to delete. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -757,7 +791,7 @@ pub struct DeleteServiceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteServiceResponse {
     /// <p>The full description of the deleted service.</p>
     #[serde(rename = "service")]
@@ -766,6 +800,7 @@ pub struct DeleteServiceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTaskSetRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the service that the task set exists in to delete.</p>
     #[serde(rename = "cluster")]
@@ -783,7 +818,7 @@ pub struct DeleteTaskSetRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTaskSetResponse {
     #[serde(rename = "taskSet")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -792,7 +827,7 @@ pub struct DeleteTaskSetResponse {
 
 /// <p>The details of an Amazon ECS service deployment. This is used only when a service uses the <code>ECS</code> deployment controller type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Deployment {
     /// <p>The Unix timestamp for when the service deployment was created.</p>
     #[serde(rename = "createdAt")]
@@ -862,6 +897,7 @@ pub struct DeploymentController {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterContainerInstanceRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the container instance to deregister. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -877,7 +913,7 @@ pub struct DeregisterContainerInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterContainerInstanceResponse {
     /// <p>The container instance that was deregistered.</p>
     #[serde(rename = "containerInstance")]
@@ -886,6 +922,7 @@ pub struct DeregisterContainerInstanceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterTaskDefinitionRequest {
     /// <p>The <code>family</code> and <code>revision</code> (<code>family:revision</code>) or full Amazon Resource Name (ARN) of the task definition to deregister. You must specify a <code>revision</code>.</p>
     #[serde(rename = "taskDefinition")]
@@ -893,7 +930,7 @@ pub struct DeregisterTaskDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterTaskDefinitionResponse {
     /// <p>The full description of the deregistered task.</p>
     #[serde(rename = "taskDefinition")]
@@ -902,6 +939,7 @@ pub struct DeregisterTaskDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClustersRequest {
     /// <p>A list of up to 100 cluster names or full cluster Amazon Resource Name (ARN) entries. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "clusters")]
@@ -914,7 +952,7 @@ pub struct DescribeClustersRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeClustersResponse {
     /// <p>The list of clusters.</p>
     #[serde(rename = "clusters")]
@@ -927,6 +965,7 @@ pub struct DescribeClustersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeContainerInstancesRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the container instances to describe. If you do not specify a cluster, the default cluster is assumed. This parameter is required if the container instance or container instances you are describing were launched in any cluster other than the default cluster.</p>
     #[serde(rename = "cluster")]
@@ -942,7 +981,7 @@ pub struct DescribeContainerInstancesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeContainerInstancesResponse {
     /// <p>The list of container instances.</p>
     #[serde(rename = "containerInstances")]
@@ -955,6 +994,7 @@ pub struct DescribeContainerInstancesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServicesRequest {
     /// <p>The short name or full Amazon Resource Name (ARN)the cluster that hosts the service to describe. If you do not specify a cluster, the default cluster is assumed. This parameter is required if the service or services you are describing were launched in any cluster other than the default cluster.</p>
     #[serde(rename = "cluster")]
@@ -970,7 +1010,7 @@ pub struct DescribeServicesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeServicesResponse {
     /// <p>Any failures associated with the call.</p>
     #[serde(rename = "failures")]
@@ -983,6 +1023,7 @@ pub struct DescribeServicesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTaskDefinitionRequest {
     /// <p>Specifies whether to see the resource tags for the task definition. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
@@ -994,7 +1035,7 @@ pub struct DescribeTaskDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTaskDefinitionResponse {
     /// <p><p>The metadata that is applied to the task definition to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per resource - 50</p> </li> <li> <p>For each resource, each tag key must be unique, and each tag key can have only one value.</p> </li> <li> <p>Maximum key length - 128 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length - 256 Unicode characters in UTF-8</p> </li> <li> <p>If your tagging schema is used across multiple services and resources, remember that other services may have restrictions on allowed characters. Generally allowed characters are: letters, numbers, and spaces representable in UTF-8, and the following characters: + - = . _ : / @.</p> </li> <li> <p>Tag keys and values are case-sensitive.</p> </li> <li> <p>Do not use <code>aws:</code>, <code>AWS:</code>, or any upper or lowercase combination of such as a prefix for either keys or values as it is reserved for AWS use. You cannot edit or delete tag keys or values with this prefix. Tags with this prefix do not count against your tags per resource limit.</p> </li> </ul></p>
     #[serde(rename = "tags")]
@@ -1007,6 +1048,7 @@ pub struct DescribeTaskDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTaskSetsRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the service that the task sets exist in.</p>
     #[serde(rename = "cluster")]
@@ -1021,7 +1063,7 @@ pub struct DescribeTaskSetsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTaskSetsResponse {
     /// <p>Any failures associated with the call.</p>
     #[serde(rename = "failures")]
@@ -1034,6 +1076,7 @@ pub struct DescribeTaskSetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTasksRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the task or tasks to describe. If you do not specify a cluster, the default cluster is assumed. This parameter is required if the task or tasks you are describing were launched in any cluster other than the default cluster.</p>
     #[serde(rename = "cluster")]
@@ -1049,7 +1092,7 @@ pub struct DescribeTasksRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTasksResponse {
     /// <p>Any failures associated with the call.</p>
     #[serde(rename = "failures")]
@@ -1078,6 +1121,7 @@ pub struct Device {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DiscoverPollEndpointRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster to which the container instance belongs.</p>
     #[serde(rename = "cluster")]
@@ -1090,7 +1134,7 @@ pub struct DiscoverPollEndpointRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DiscoverPollEndpointResponse {
     /// <p>The endpoint for the Amazon ECS agent to poll.</p>
     #[serde(rename = "endpoint")]
@@ -1129,7 +1173,7 @@ pub struct DockerVolumeConfiguration {
 
 /// <p>A failed resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Failure {
     /// <p>The Amazon Resource Name (ARN) of the failed resource.</p>
     #[serde(rename = "arn")]
@@ -1281,6 +1325,7 @@ pub struct LinuxParameters {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAccountSettingsRequest {
     /// <p>Specifies whether to return the effective settings. If <code>true</code>, the account settings for the root user or the default setting for the <code>principalArn</code> are returned. If <code>false</code>, the account settings for the <code>principalArn</code> are returned if they are set. Otherwise, no account settings are returned.</p>
     #[serde(rename = "effectiveSettings")]
@@ -1309,7 +1354,7 @@ pub struct ListAccountSettingsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAccountSettingsResponse {
     /// <p>The <code>nextToken</code> value to include in a future <code>ListAccountSettings</code> request. When the results of a <code>ListAccountSettings</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -1322,6 +1367,7 @@ pub struct ListAccountSettingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAttributesRequest {
     /// <p>The name of the attribute with which to filter the results. </p>
     #[serde(rename = "attributeName")]
@@ -1349,7 +1395,7 @@ pub struct ListAttributesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAttributesResponse {
     /// <p>A list of attribute objects that meet the criteria of the request.</p>
     #[serde(rename = "attributes")]
@@ -1362,6 +1408,7 @@ pub struct ListAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListClustersRequest {
     /// <p>The maximum number of cluster results returned by <code>ListClusters</code> in paginated output. When this parameter is used, <code>ListClusters</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>ListClusters</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If this parameter is not used, then <code>ListClusters</code> returns up to 100 results and a <code>nextToken</code> value if applicable.</p>
     #[serde(rename = "maxResults")]
@@ -1374,7 +1421,7 @@ pub struct ListClustersRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListClustersResponse {
     /// <p>The list of full Amazon Resource Name (ARN) entries for each cluster associated with your account.</p>
     #[serde(rename = "clusterArns")]
@@ -1387,6 +1434,7 @@ pub struct ListClustersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListContainerInstancesRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the container instances to list. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -1411,7 +1459,7 @@ pub struct ListContainerInstancesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListContainerInstancesResponse {
     /// <p>The list of container instances with full ARN entries for each container instance associated with the specified cluster.</p>
     #[serde(rename = "containerInstanceArns")]
@@ -1424,6 +1472,7 @@ pub struct ListContainerInstancesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListServicesRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the services to list. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -1448,7 +1497,7 @@ pub struct ListServicesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListServicesResponse {
     /// <p>The <code>nextToken</code> value to include in a future <code>ListServices</code> request. When the results of a <code>ListServices</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -1461,6 +1510,7 @@ pub struct ListServicesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the resource for which to list the tags. Currently, the supported resources are Amazon ECS tasks, services, task definitions, clusters, and container instances.</p>
     #[serde(rename = "resourceArn")]
@@ -1468,7 +1518,7 @@ pub struct ListTagsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags for the resource.</p>
     #[serde(rename = "tags")]
@@ -1477,6 +1527,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTaskDefinitionFamiliesRequest {
     /// <p>The <code>familyPrefix</code> is a string that is used to filter the results of <code>ListTaskDefinitionFamilies</code>. If you specify a <code>familyPrefix</code>, only task definition family names that begin with the <code>familyPrefix</code> string are returned.</p>
     #[serde(rename = "familyPrefix")]
@@ -1497,7 +1548,7 @@ pub struct ListTaskDefinitionFamiliesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTaskDefinitionFamiliesResponse {
     /// <p>The list of task definition family names that match the <code>ListTaskDefinitionFamilies</code> request.</p>
     #[serde(rename = "families")]
@@ -1510,6 +1561,7 @@ pub struct ListTaskDefinitionFamiliesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTaskDefinitionsRequest {
     /// <p>The full family name with which to filter the <code>ListTaskDefinitions</code> results. Specifying a <code>familyPrefix</code> limits the listed task definitions to task definition revisions that belong to that family.</p>
     #[serde(rename = "familyPrefix")]
@@ -1534,7 +1586,7 @@ pub struct ListTaskDefinitionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTaskDefinitionsResponse {
     /// <p>The <code>nextToken</code> value to include in a future <code>ListTaskDefinitions</code> request. When the results of a <code>ListTaskDefinitions</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -1547,6 +1599,7 @@ pub struct ListTaskDefinitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTasksRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the tasks to list. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -1587,7 +1640,7 @@ pub struct ListTasksRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTasksResponse {
     /// <p>The <code>nextToken</code> value to include in a future <code>ListTasks</code> request. When the results of a <code>ListTasks</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -1685,7 +1738,7 @@ pub struct NetworkConfiguration {
 
 /// <p>An object representing the elastic network interface for tasks that use the <code>awsvpc</code> network mode.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkInterface {
     /// <p>The attachment ID for the network interface.</p>
     #[serde(rename = "attachmentId")]
@@ -1729,6 +1782,7 @@ pub struct PlacementStrategy {
 
 /// <p>The devices that are available on the container instance. The only supported device type is a GPU.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PlatformDevice {
     /// <p>The ID for the GPU(s) on the container instance. The available GPU IDs can also be obtained on the container instance in the <code>/var/lib/ecs/gpu/nvidia_gpu_info.json</code> file.</p>
     #[serde(rename = "id")]
@@ -1772,6 +1826,7 @@ pub struct ProxyConfiguration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutAccountSettingDefaultRequest {
     /// <p>The resource name for which to modify the account setting. If <code>serviceLongArnFormat</code> is specified, the ARN for your Amazon ECS services is affected. If <code>taskLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS tasks is affected. If <code>containerInstanceLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS container instances is affected. If <code>awsvpcTrunking</code> is specified, the ENI limit for your Amazon ECS container instances is affected. If <code>containerInsights</code> is specified, the default setting for CloudWatch Container Insights for your clusters is affected.</p>
     #[serde(rename = "name")]
@@ -1782,7 +1837,7 @@ pub struct PutAccountSettingDefaultRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutAccountSettingDefaultResponse {
     #[serde(rename = "setting")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1790,6 +1845,7 @@ pub struct PutAccountSettingDefaultResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutAccountSettingRequest {
     /// <p>The Amazon ECS resource name for which to modify the account setting. If <code>serviceLongArnFormat</code> is specified, the ARN for your Amazon ECS services is affected. If <code>taskLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS tasks is affected. If <code>containerInstanceLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS container instances is affected. If <code>awsvpcTrunking</code> is specified, the elastic network interface (ENI) limit for your Amazon ECS container instances is affected. If <code>containerInsights</code> is specified, the default setting for CloudWatch Container Insights for your clusters is affected.</p>
     #[serde(rename = "name")]
@@ -1804,7 +1860,7 @@ pub struct PutAccountSettingRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutAccountSettingResponse {
     /// <p>The current account setting for a resource.</p>
     #[serde(rename = "setting")]
@@ -1813,6 +1869,7 @@ pub struct PutAccountSettingResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutAttributesRequest {
     /// <p>The attributes to apply to your resource. You can specify up to 10 custom attributes per resource. You can specify up to 10 attributes in a single call.</p>
     #[serde(rename = "attributes")]
@@ -1824,7 +1881,7 @@ pub struct PutAttributesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutAttributesResponse {
     /// <p>The attributes applied to your resource.</p>
     #[serde(rename = "attributes")]
@@ -1833,6 +1890,7 @@ pub struct PutAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterContainerInstanceRequest {
     /// <p>The container instance attributes that this container instance supports.</p>
     #[serde(rename = "attributes")]
@@ -1872,8 +1930,20 @@ pub struct RegisterContainerInstanceRequest {
     pub version_info: Option<VersionInfo>,
 }
 
+impl Validate for RegisterContainerInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterContainerInstanceResponse {
     /// <p>The container instance that was registered.</p>
     #[serde(rename = "containerInstance")]
@@ -1882,6 +1952,7 @@ pub struct RegisterContainerInstanceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterTaskDefinitionRequest {
     /// <p>A list of container definitions in JSON format that describe the different containers that make up your task.</p>
     #[serde(rename = "containerDefinitions")]
@@ -1942,8 +2013,20 @@ pub struct RegisterTaskDefinitionRequest {
     pub volumes: Option<Vec<Volume>>,
 }
 
+impl Validate for RegisterTaskDefinitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterTaskDefinitionResponse {
     /// <p>The list of tags associated with the task definition.</p>
     #[serde(rename = "tags")]
@@ -2004,6 +2087,7 @@ pub struct ResourceRequirement {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RunTaskRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster on which to run your task. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -2062,8 +2146,20 @@ pub struct RunTaskRequest {
     pub task_definition: String,
 }
 
+impl Validate for RunTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RunTaskResponse {
     /// <p>Any failures associated with the call.</p>
     #[serde(rename = "failures")]
@@ -2101,7 +2197,7 @@ pub struct Secret {
 
 /// <p>Details on a service within a cluster</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Service {
     /// <p>The Amazon Resource Name (ARN) of the cluster that hosts the service.</p>
     #[serde(rename = "clusterArn")]
@@ -2219,7 +2315,7 @@ pub struct Service {
 
 /// <p>Details on an event associated with a service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceEvent {
     /// <p>The Unix timestamp for when the event was triggered.</p>
     #[serde(rename = "createdAt")]
@@ -2258,7 +2354,7 @@ pub struct ServiceRegistry {
 
 /// <p>The current account setting for a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Setting {
     /// <p>The Amazon ECS resource name.</p>
     #[serde(rename = "name")]
@@ -2275,6 +2371,7 @@ pub struct Setting {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartTaskRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster on which to start your task. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -2316,8 +2413,20 @@ pub struct StartTaskRequest {
     pub task_definition: String,
 }
 
+impl Validate for StartTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartTaskResponse {
     /// <p>Any failures associated with the call.</p>
     #[serde(rename = "failures")]
@@ -2330,6 +2439,7 @@ pub struct StartTaskResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopTaskRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the task to stop. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -2345,7 +2455,7 @@ pub struct StopTaskRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopTaskResponse {
     /// <p>The task that was stopped.</p>
     #[serde(rename = "task")]
@@ -2354,6 +2464,7 @@ pub struct StopTaskResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SubmitAttachmentStateChangesRequest {
     /// <p>Any attachments associated with the state change request.</p>
     #[serde(rename = "attachments")]
@@ -2365,7 +2476,7 @@ pub struct SubmitAttachmentStateChangesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubmitAttachmentStateChangesResponse {
     /// <p>Acknowledgement of the state change.</p>
     #[serde(rename = "acknowledgment")]
@@ -2374,6 +2485,7 @@ pub struct SubmitAttachmentStateChangesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SubmitContainerStateChangeRequest {
     /// <p>The short name or full ARN of the cluster that hosts the container.</p>
     #[serde(rename = "cluster")]
@@ -2410,7 +2522,7 @@ pub struct SubmitContainerStateChangeRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubmitContainerStateChangeResponse {
     /// <p>Acknowledgement of the state change.</p>
     #[serde(rename = "acknowledgment")]
@@ -2419,6 +2531,7 @@ pub struct SubmitContainerStateChangeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SubmitTaskStateChangeRequest {
     /// <p>Any attachments associated with the state change request.</p>
     #[serde(rename = "attachments")]
@@ -2459,7 +2572,7 @@ pub struct SubmitTaskStateChangeRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubmitTaskStateChangeResponse {
     /// <p>Acknowledgement of the state change.</p>
     #[serde(rename = "acknowledgment")]
@@ -2493,7 +2606,39 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource to which to add tags. Currently, the supported resources are Amazon ECS tasks, services, task definitions, clusters, and container instances.</p>
     #[serde(rename = "resourceArn")]
@@ -2503,13 +2648,23 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), None, Some(50));
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>Details on a task in a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Task {
     /// <p>The Elastic Network Adapter associated with the task if the task uses the <code>awsvpc</code> network mode.</p>
     #[serde(rename = "attachments")]
@@ -2635,7 +2790,7 @@ pub struct Task {
 
 /// <p>The details of a task definition which describes the container and volume definitions of an Amazon Elastic Container Service task. You can specify which Docker images to use, the required resources, and other configurations related to launching the task definition through an Amazon ECS service or task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskDefinition {
     /// <p>The launch type to use with your task. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "compatibilities")]
@@ -2747,7 +2902,7 @@ pub struct TaskOverride {
 
 /// <p>Information about a set of Amazon ECS tasks in either an AWS CodeDeploy or an <code>EXTERNAL</code> deployment. An Amazon ECS task set includes details such as the desired number of tasks, how many tasks are running, and whether the task set serves production traffic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskSet {
     /// <p>The Amazon Resource Name (ARN) of the cluster that the service that hosts the task set exists in.</p>
     #[serde(rename = "clusterArn")]
@@ -2865,6 +3020,7 @@ pub struct Ulimit {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource from which to delete tags. Currently, the supported resources are Amazon ECS tasks, services, task definitions, clusters, and container instances.</p>
     #[serde(rename = "resourceArn")]
@@ -2874,11 +3030,22 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateClusterSettingsRequest {
     /// <p>The name of the cluster to modify the settings for.</p>
     #[serde(rename = "cluster")]
@@ -2889,7 +3056,7 @@ pub struct UpdateClusterSettingsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateClusterSettingsResponse {
     #[serde(rename = "cluster")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2897,6 +3064,7 @@ pub struct UpdateClusterSettingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateContainerAgentRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that your container instance is running on. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -2908,7 +3076,7 @@ pub struct UpdateContainerAgentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateContainerAgentResponse {
     /// <p>The container instance for which the container agent was updated.</p>
     #[serde(rename = "containerInstance")]
@@ -2917,6 +3085,7 @@ pub struct UpdateContainerAgentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateContainerInstancesStateRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the container instance to update. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -2931,7 +3100,7 @@ pub struct UpdateContainerInstancesStateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateContainerInstancesStateResponse {
     /// <p>The list of container instances.</p>
     #[serde(rename = "containerInstances")]
@@ -2944,6 +3113,7 @@ pub struct UpdateContainerInstancesStateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServicePrimaryTaskSetRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the service that the task set exists in.</p>
     #[serde(rename = "cluster")]
@@ -2957,7 +3127,7 @@ pub struct UpdateServicePrimaryTaskSetRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateServicePrimaryTaskSetResponse {
     #[serde(rename = "taskSet")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2965,6 +3135,7 @@ pub struct UpdateServicePrimaryTaskSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServiceRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that your service is running on. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -3004,7 +3175,7 @@ pub struct UpdateServiceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateServiceResponse {
     /// <p>The full description of your service following the update call.</p>
     #[serde(rename = "service")]
@@ -3013,6 +3184,7 @@ pub struct UpdateServiceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTaskSetRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the service that the task set exists in.</p>
     #[serde(rename = "cluster")]
@@ -3028,7 +3200,7 @@ pub struct UpdateTaskSetRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTaskSetResponse {
     #[serde(rename = "taskSet")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3106,7 +3278,12 @@ impl CreateClusterError {
                 "ServerException" => {
                     return RusotoError::Service(CreateClusterError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3178,7 +3355,12 @@ impl CreateServiceError {
                 "UnsupportedFeatureException" => {
                     return RusotoError::Service(CreateServiceError::UnsupportedFeature(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3265,7 +3447,12 @@ impl CreateTaskSetError {
                 "UnsupportedFeatureException" => {
                     return RusotoError::Service(CreateTaskSetError::UnsupportedFeature(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3319,7 +3506,12 @@ impl DeleteAccountSettingError {
                 "ServerException" => {
                     return RusotoError::Service(DeleteAccountSettingError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3364,7 +3556,12 @@ impl DeleteAttributesError {
                 "TargetNotFoundException" => {
                     return RusotoError::Service(DeleteAttributesError::TargetNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3433,7 +3630,12 @@ impl DeleteClusterError {
                 "ServerException" => {
                     return RusotoError::Service(DeleteClusterError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3492,7 +3694,12 @@ impl DeleteServiceError {
                 "ServiceNotFoundException" => {
                     return RusotoError::Service(DeleteServiceError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3569,7 +3776,12 @@ impl DeleteTaskSetError {
                 "UnsupportedFeatureException" => {
                     return RusotoError::Service(DeleteTaskSetError::UnsupportedFeature(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3631,7 +3843,12 @@ impl DeregisterContainerInstanceError {
                 "ServerException" => {
                     return RusotoError::Service(DeregisterContainerInstanceError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3679,7 +3896,12 @@ impl DeregisterTaskDefinitionError {
                 "ServerException" => {
                     return RusotoError::Service(DeregisterTaskDefinitionError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3724,7 +3946,12 @@ impl DescribeClustersError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeClustersError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3780,7 +4007,12 @@ impl DescribeContainerInstancesError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeContainerInstancesError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3831,7 +4063,12 @@ impl DescribeServicesError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeServicesError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3879,7 +4116,12 @@ impl DescribeTaskDefinitionError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeTaskDefinitionError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3949,7 +4191,12 @@ impl DescribeTaskSetsError {
                 "UnsupportedFeatureException" => {
                     return RusotoError::Service(DescribeTaskSetsError::UnsupportedFeature(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4004,7 +4251,12 @@ impl DescribeTasksError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeTasksError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4045,7 +4297,12 @@ impl DiscoverPollEndpointError {
                 "ServerException" => {
                     return RusotoError::Service(DiscoverPollEndpointError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4091,7 +4348,12 @@ impl ListAccountSettingsError {
                 "ServerException" => {
                     return RusotoError::Service(ListAccountSettingsError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4131,7 +4393,12 @@ impl ListAttributesError {
                 "InvalidParameterException" => {
                     return RusotoError::Service(ListAttributesError::InvalidParameter(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4175,7 +4442,12 @@ impl ListClustersError {
                 "ServerException" => {
                     return RusotoError::Service(ListClustersError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4229,7 +4501,12 @@ impl ListContainerInstancesError {
                 "ServerException" => {
                     return RusotoError::Service(ListContainerInstancesError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4280,7 +4557,12 @@ impl ListServicesError {
                 "ServerException" => {
                     return RusotoError::Service(ListServicesError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4333,7 +4615,12 @@ impl ListTagsForResourceError {
                 "ServerException" => {
                     return RusotoError::Service(ListTagsForResourceError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4383,7 +4670,12 @@ impl ListTaskDefinitionFamiliesError {
                 "ServerException" => {
                     return RusotoError::Service(ListTaskDefinitionFamiliesError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4430,7 +4722,12 @@ impl ListTaskDefinitionsError {
                 "ServerException" => {
                     return RusotoError::Service(ListTaskDefinitionsError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4481,7 +4778,12 @@ impl ListTasksError {
                 "ServiceNotFoundException" => {
                     return RusotoError::Service(ListTasksError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4528,7 +4830,12 @@ impl PutAccountSettingError {
                 "ServerException" => {
                     return RusotoError::Service(PutAccountSettingError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4575,7 +4882,12 @@ impl PutAccountSettingDefaultError {
                 "ServerException" => {
                     return RusotoError::Service(PutAccountSettingDefaultError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4627,7 +4939,12 @@ impl PutAttributesError {
                 "TargetNotFoundException" => {
                     return RusotoError::Service(PutAttributesError::TargetNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4675,7 +4992,12 @@ impl RegisterContainerInstanceError {
                 "ServerException" => {
                     return RusotoError::Service(RegisterContainerInstanceError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4722,7 +5044,12 @@ impl RegisterTaskDefinitionError {
                 "ServerException" => {
                     return RusotoError::Service(RegisterTaskDefinitionError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4793,7 +5120,12 @@ impl RunTaskError {
                 "UnsupportedFeatureException" => {
                     return RusotoError::Service(RunTaskError::UnsupportedFeature(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4845,7 +5177,12 @@ impl StartTaskError {
                     return RusotoError::Service(StartTaskError::InvalidParameter(err.msg))
                 }
                 "ServerException" => return RusotoError::Service(StartTaskError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4892,7 +5229,12 @@ impl StopTaskError {
                     return RusotoError::Service(StopTaskError::InvalidParameter(err.msg))
                 }
                 "ServerException" => return RusotoError::Service(StopTaskError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4949,7 +5291,12 @@ impl SubmitAttachmentStateChangesError {
                 "ServerException" => {
                     return RusotoError::Service(SubmitAttachmentStateChangesError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4999,7 +5346,12 @@ impl SubmitContainerStateChangeError {
                 "ServerException" => {
                     return RusotoError::Service(SubmitContainerStateChangeError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5044,7 +5396,12 @@ impl SubmitTaskStateChangeError {
                 "ServerException" => {
                     return RusotoError::Service(SubmitTaskStateChangeError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5099,7 +5456,12 @@ impl TagResourceError {
                 "ServerException" => {
                     return RusotoError::Service(TagResourceError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5156,7 +5518,12 @@ impl UntagResourceError {
                 "ServerException" => {
                     return RusotoError::Service(UntagResourceError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5212,7 +5579,12 @@ impl UpdateClusterSettingsError {
                 "ServerException" => {
                     return RusotoError::Service(UpdateClusterSettingsError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5286,7 +5658,12 @@ impl UpdateContainerAgentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5350,7 +5727,12 @@ impl UpdateContainerInstancesStateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5428,7 +5810,12 @@ impl UpdateServiceError {
                 "ServiceNotFoundException" => {
                     return RusotoError::Service(UpdateServiceError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5525,7 +5912,12 @@ impl UpdateServicePrimaryTaskSetError {
                         UpdateServicePrimaryTaskSetError::UnsupportedFeature(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5606,7 +5998,12 @@ impl UpdateTaskSetError {
                 "UnsupportedFeatureException" => {
                     return RusotoError::Service(UpdateTaskSetError::UnsupportedFeature(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5899,1431 +6296,3880 @@ pub trait Ecs {
         input: UpdateTaskSetRequest,
     ) -> RusotoFuture<UpdateTaskSetResponse, UpdateTaskSetError>;
 }
-/// A client for the Amazon ECS API.
-#[derive(Clone)]
-pub struct EcsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl EcsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> EcsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> EcsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> EcsClient {
-        EcsClient { client, region }
-    }
-}
-
-impl Ecs for EcsClient {
-    /// <p><p>Creates a new Amazon ECS cluster. By default, your account receives a <code>default</code> cluster when you launch your first container instance. However, you can create your own cluster with a unique name with the <code>CreateCluster</code> action.</p> <note> <p>When you call the <a>CreateCluster</a> API operation, Amazon ECS attempts to create the service-linked role for your account so that required resources in other AWS services can be managed on your behalf. However, if the IAM user that makes the call does not have permissions to create the service-linked role, it is not created. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </note></p>
+const _: Option<&dyn Ecs> = None;
+impl<'a, T: Ecs + ?Sized> Ecs for &'a T {
     fn create_cluster(
         &self,
         input: CreateClusterRequest,
     ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.CreateCluster",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateClusterResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateClusterError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_cluster(input)
     }
-
-    /// <p><p>Runs and maintains a desired number of tasks from a specified task definition. If the number of tasks running in a service drops below the <code>desiredCount</code>, Amazon ECS runs another copy of the task in the specified cluster. To update an existing service, see <a>UpdateService</a>.</p> <p>In addition to maintaining the desired count of tasks in your service, you can optionally run your service behind one or more load balancers. The load balancers distribute traffic across the tasks that are associated with the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/service-load-balancing.html">Service Load Balancing</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>Tasks for services that <i>do not</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state. Tasks for services that <i>do</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state and the container instance that they&#39;re hosted on is reported as healthy by the load balancer.</p> <p>There are two service scheduler strategies available:</p> <ul> <li> <p> <code>REPLICA</code> - The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Service Scheduler Concepts</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </li> <li> <p> <code>DAEMON</code> - The daemon scheduling strategy deploys exactly one task on each active container instance that meets all of the task placement constraints that you specify in your cluster. When using this strategy, you don&#39;t need to specify a desired number of tasks, a task placement strategy, or use Service Auto Scaling policies. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Service Scheduler Concepts</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </li> </ul> <p>You can optionally specify a deployment configuration for your service. The deployment is triggered by changing properties, such as the task definition or the desired count of a service, with an <a>UpdateService</a> operation. The default value for a replica service for <code>minimumHealthyPercent</code> is 100%. The default value for a daemon service for <code>minimumHealthyPercent</code> is 0%.</p> <p>If a service is using the <code>ECS</code> deployment controller, the minimum healthy percent represents a lower limit on the number of tasks in a service that must remain in the <code>RUNNING</code> state during a deployment, as a percentage of the desired number of tasks (rounded up to the nearest integer), and while any container instances are in the <code>DRAINING</code> state if the service contains tasks using the EC2 launch type. This parameter enables you to deploy without using additional cluster capacity. For example, if your service has a desired number of four tasks and a minimum healthy percent of 50%, the scheduler might stop two existing tasks to free up cluster capacity before starting two new tasks. Tasks for services that <i>do not</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state. Tasks for services that <i>do</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state and they&#39;re reported as healthy by the load balancer. The default value for minimum healthy percent is 100%.</p> <p>If a service is using the <code>ECS</code> deployment controller, the <b>maximum percent</b> parameter represents an upper limit on the number of tasks in a service that are allowed in the <code>RUNNING</code> or <code>PENDING</code> state during a deployment, as a percentage of the desired number of tasks (rounded down to the nearest integer), and while any container instances are in the <code>DRAINING</code> state if the service contains tasks using the EC2 launch type. This parameter enables you to define the deployment batch size. For example, if your service has a desired number of four tasks and a maximum percent value of 200%, the scheduler may start four new tasks before stopping the four older tasks (provided that the cluster resources required to do this are available). The default value for maximum percent is 200%.</p> <p>If a service is using either the <code>CODE_DEPLOY</code> or <code>EXTERNAL</code> deployment controller types and tasks that use the EC2 launch type, the <b>minimum healthy percent</b> and <b>maximum percent</b> values are used only to define the lower and upper limit on the number of the tasks in the service that remain in the <code>RUNNING</code> state while the container instances are in the <code>DRAINING</code> state. If the tasks in the service use the Fargate launch type, the minimum healthy percent and maximum percent values aren&#39;t used, although they&#39;re currently visible when describing your service.</p> <p>When creating a service that uses the <code>EXTERNAL</code> deployment controller, you can specify only parameters that aren&#39;t controlled at the task set level. The only required parameter is the service name. You control your services using the <a>CreateTaskSet</a> operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When the service scheduler launches new tasks, it determines task placement in your cluster using the following logic:</p> <ul> <li> <p>Determine which of the container instances in your cluster can support your service&#39;s task definition (for example, they have the required CPU, memory, ports, and container instance attributes).</p> </li> <li> <p>By default, the service scheduler attempts to balance tasks across Availability Zones in this manner (although you can choose a different placement strategy) with the <code>placementStrategy</code> parameter):</p> <ul> <li> <p>Sort the valid container instances, giving priority to instances that have the fewest number of running tasks for this service in their respective Availability Zone. For example, if zone A has one running service task and zones B and C each have zero, valid container instances in either zone B or C are considered optimal for placement.</p> </li> <li> <p>Place the new service task on a valid container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the fewest number of running tasks for this service.</p> </li> </ul> </li> </ul></p>
     fn create_service(
         &self,
         input: CreateServiceRequest,
     ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.CreateService",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateServiceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateServiceError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_service(input)
     }
-
-    /// <p>Create a task set in the specified cluster and service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn create_task_set(
         &self,
         input: CreateTaskSetRequest,
     ) -> RusotoFuture<CreateTaskSetResponse, CreateTaskSetError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.CreateTaskSet",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTaskSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTaskSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_task_set(input)
     }
-
-    /// <p>Disables an account setting for a specified IAM user, IAM role, or the root user for an account.</p>
     fn delete_account_setting(
         &self,
         input: DeleteAccountSettingRequest,
     ) -> RusotoFuture<DeleteAccountSettingResponse, DeleteAccountSettingError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DeleteAccountSetting",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteAccountSettingResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteAccountSettingError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_account_setting(input)
     }
-
-    /// <p>Deletes one or more custom attributes from an Amazon ECS resource.</p>
     fn delete_attributes(
         &self,
         input: DeleteAttributesRequest,
     ) -> RusotoFuture<DeleteAttributesResponse, DeleteAttributesError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DeleteAttributes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteAttributesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAttributesError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_attributes(input)
     }
-
-    /// <p>Deletes the specified cluster. You must deregister all container instances from this cluster before you may delete it. You can list the container instances in a cluster with <a>ListContainerInstances</a> and deregister them with <a>DeregisterContainerInstance</a>.</p>
     fn delete_cluster(
         &self,
         input: DeleteClusterRequest,
     ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DeleteCluster",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteClusterResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteClusterError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_cluster(input)
     }
-
-    /// <p><p>Deletes a specified service within a cluster. You can delete a service if you have no running tasks in it and the desired task count is zero. If the service is actively maintaining tasks, you cannot delete it, and you must update the service to a desired task count of zero. For more information, see <a>UpdateService</a>.</p> <note> <p>When you delete a service, if there are still running tasks that require cleanup, the service status moves from <code>ACTIVE</code> to <code>DRAINING</code>, and the service is no longer visible in the console or in the <a>ListServices</a> API operation. After all tasks have transitioned to either <code>STOPPING</code> or <code>STOPPED</code> status, the service status moves from <code>DRAINING</code> to <code>INACTIVE</code>. Services in the <code>DRAINING</code> or <code>INACTIVE</code> status can still be viewed with the <a>DescribeServices</a> API operation. However, in the future, <code>INACTIVE</code> services may be cleaned up and purged from Amazon ECS record keeping, and <a>DescribeServices</a> calls on those services return a <code>ServiceNotFoundException</code> error.</p> </note> <important> <p>If you attempt to create a new service with the same name as an existing service in either <code>ACTIVE</code> or <code>DRAINING</code> status, you receive an error.</p> </important></p>
     fn delete_service(
         &self,
         input: DeleteServiceRequest,
     ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DeleteService",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteServiceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteServiceError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_service(input)
     }
-
-    /// <p>Deletes a specified task set within a service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn delete_task_set(
         &self,
         input: DeleteTaskSetRequest,
     ) -> RusotoFuture<DeleteTaskSetResponse, DeleteTaskSetError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DeleteTaskSet",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTaskSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTaskSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_task_set(input)
     }
-
-    /// <p><p>Deregisters an Amazon ECS container instance from the specified cluster. This instance is no longer available to run tasks.</p> <p>If you intend to use the container instance for some other purpose after deregistration, you should stop all of the tasks running on the container instance before deregistration. That prevents any orphaned tasks from consuming resources.</p> <p>Deregistering a container instance removes the instance from a cluster, but it does not terminate the EC2 instance. If you are finished using the instance, be sure to terminate it in the Amazon EC2 console to stop billing.</p> <note> <p>If you terminate a running container instance, Amazon ECS automatically deregisters the instance from your cluster (stopped container instances or instances with disconnected agents are not automatically deregistered when terminated).</p> </note></p>
     fn deregister_container_instance(
         &self,
         input: DeregisterContainerInstanceRequest,
     ) -> RusotoFuture<DeregisterContainerInstanceResponse, DeregisterContainerInstanceError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DeregisterContainerInstance",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterContainerInstanceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterContainerInstanceError::from_response(response))
-                }))
-            }
-        })
+        (**self).deregister_container_instance(input)
     }
-
-    /// <p><p>Deregisters the specified task definition by family and revision. Upon deregistration, the task definition is marked as <code>INACTIVE</code>. Existing tasks and services that reference an <code>INACTIVE</code> task definition continue to run without disruption. Existing services that reference an <code>INACTIVE</code> task definition can still scale up or down by modifying the service&#39;s desired count.</p> <p>You cannot use an <code>INACTIVE</code> task definition to run new tasks or create new services, and you cannot update an existing service to reference an <code>INACTIVE</code> task definition. However, there may be up to a 10-minute window following deregistration where these restrictions have not yet taken effect.</p> <note> <p>At this time, <code>INACTIVE</code> task definitions remain discoverable in your account indefinitely. However, this behavior is subject to change in the future, so you should not rely on <code>INACTIVE</code> task definitions persisting beyond the lifecycle of any associated tasks and services.</p> </note></p>
     fn deregister_task_definition(
         &self,
         input: DeregisterTaskDefinitionRequest,
     ) -> RusotoFuture<DeregisterTaskDefinitionResponse, DeregisterTaskDefinitionError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DeregisterTaskDefinition",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterTaskDefinitionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterTaskDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).deregister_task_definition(input)
     }
-
-    /// <p>Describes one or more of your clusters.</p>
     fn describe_clusters(
         &self,
         input: DescribeClustersRequest,
     ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DescribeClusters",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeClustersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeClustersError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_clusters(input)
     }
-
-    /// <p>Describes Amazon Elastic Container Service container instances. Returns metadata about registered and remaining resources on each container instance requested.</p>
     fn describe_container_instances(
         &self,
         input: DescribeContainerInstancesRequest,
     ) -> RusotoFuture<DescribeContainerInstancesResponse, DescribeContainerInstancesError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DescribeContainerInstances",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeContainerInstancesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeContainerInstancesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_container_instances(input)
     }
-
-    /// <p>Describes the specified services running in your cluster.</p>
     fn describe_services(
         &self,
         input: DescribeServicesRequest,
     ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DescribeServices",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeServicesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeServicesError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_services(input)
     }
-
-    /// <p><p>Describes a task definition. You can specify a <code>family</code> and <code>revision</code> to find information about a specific task definition, or you can simply specify the family to find the latest <code>ACTIVE</code> revision in that family.</p> <note> <p>You can only describe <code>INACTIVE</code> task definitions while an active task or service references them.</p> </note></p>
     fn describe_task_definition(
         &self,
         input: DescribeTaskDefinitionRequest,
     ) -> RusotoFuture<DescribeTaskDefinitionResponse, DescribeTaskDefinitionError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DescribeTaskDefinition",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTaskDefinitionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeTaskDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_task_definition(input)
     }
-
-    /// <p>Describes the task sets in the specified cluster and service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn describe_task_sets(
         &self,
         input: DescribeTaskSetsRequest,
     ) -> RusotoFuture<DescribeTaskSetsResponse, DescribeTaskSetsError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DescribeTaskSets",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTaskSetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTaskSetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_task_sets(input)
     }
-
-    /// <p>Describes a specified task or tasks.</p>
     fn describe_tasks(
         &self,
         input: DescribeTasksRequest,
     ) -> RusotoFuture<DescribeTasksResponse, DescribeTasksError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DescribeTasks",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTasksResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTasksError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_tasks(input)
     }
-
-    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Returns an endpoint for the Amazon ECS agent to poll for updates.</p></p>
     fn discover_poll_endpoint(
         &self,
         input: DiscoverPollEndpointRequest,
     ) -> RusotoFuture<DiscoverPollEndpointResponse, DiscoverPollEndpointError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.DiscoverPollEndpoint",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DiscoverPollEndpointResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DiscoverPollEndpointError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).discover_poll_endpoint(input)
     }
-
-    /// <p>Lists the account settings for a specified principal.</p>
     fn list_account_settings(
         &self,
         input: ListAccountSettingsRequest,
     ) -> RusotoFuture<ListAccountSettingsResponse, ListAccountSettingsError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.ListAccountSettings",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAccountSettingsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListAccountSettingsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_account_settings(input)
     }
-
-    /// <p>Lists the attributes for Amazon ECS resources within a specified target type and cluster. When you specify a target type and cluster, <code>ListAttributes</code> returns a list of attribute objects, one for each attribute on each resource. You can filter the list of results to a single attribute name to only return results that have that name. You can also filter the results by attribute name and value, for example, to see which container instances in a cluster are running a Linux AMI (<code>ecs.os-type=linux</code>). </p>
     fn list_attributes(
         &self,
         input: ListAttributesRequest,
     ) -> RusotoFuture<ListAttributesResponse, ListAttributesError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.ListAttributes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAttributesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAttributesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_attributes(input)
     }
-
-    /// <p>Returns a list of existing clusters.</p>
     fn list_clusters(
         &self,
         input: ListClustersRequest,
     ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.ListClusters",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListClustersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListClustersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_clusters(input)
     }
-
-    /// <p>Returns a list of container instances in a specified cluster. You can filter the results of a <code>ListContainerInstances</code> operation with cluster query language statements inside the <code>filter</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cluster-query-language.html">Cluster Query Language</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn list_container_instances(
         &self,
         input: ListContainerInstancesRequest,
     ) -> RusotoFuture<ListContainerInstancesResponse, ListContainerInstancesError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.ListContainerInstances",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListContainerInstancesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListContainerInstancesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_container_instances(input)
     }
-
-    /// <p>Lists the services that are running in a specified cluster.</p>
     fn list_services(
         &self,
         input: ListServicesRequest,
     ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.ListServices",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListServicesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListServicesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_services(input)
     }
-
-    /// <p>List the tags for an Amazon ECS resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.ListTagsForResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Returns a list of task definition families that are registered to your account (which may include task definition families that no longer have any <code>ACTIVE</code> task definition revisions).</p> <p>You can filter out task definition families that do not contain any <code>ACTIVE</code> task definition revisions by setting the <code>status</code> parameter to <code>ACTIVE</code>. You can also filter the results with the <code>familyPrefix</code> parameter.</p>
     fn list_task_definition_families(
         &self,
         input: ListTaskDefinitionFamiliesRequest,
     ) -> RusotoFuture<ListTaskDefinitionFamiliesResponse, ListTaskDefinitionFamiliesError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.ListTaskDefinitionFamilies",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTaskDefinitionFamiliesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListTaskDefinitionFamiliesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_task_definition_families(input)
     }
-
-    /// <p>Returns a list of task definitions that are registered to your account. You can filter the results by family name with the <code>familyPrefix</code> parameter or by status with the <code>status</code> parameter.</p>
     fn list_task_definitions(
         &self,
         input: ListTaskDefinitionsRequest,
     ) -> RusotoFuture<ListTaskDefinitionsResponse, ListTaskDefinitionsError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.ListTaskDefinitions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTaskDefinitionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTaskDefinitionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_task_definitions(input)
     }
-
-    /// <p>Returns a list of tasks for a specified cluster. You can filter the results by family name, by a particular container instance, or by the desired status of the task with the <code>family</code>, <code>containerInstance</code>, and <code>desiredStatus</code> parameters.</p> <p>Recently stopped tasks might appear in the returned results. Currently, stopped tasks appear in the returned results for at least one hour. </p>
     fn list_tasks(
         &self,
         input: ListTasksRequest,
     ) -> RusotoFuture<ListTasksResponse, ListTasksError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.ListTasks",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTasksResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTasksError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tasks(input)
     }
-
-    /// <p>Modifies an account setting. Account settings are set on a per-Region basis.</p> <p>If you change the account setting for the root user, the default settings for all of the IAM users and roles for which no individual account setting has been specified are reset. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-account-settings.html">Account Settings</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When <code>serviceLongArnFormat</code>, <code>taskLongArnFormat</code>, or <code>containerInstanceLongArnFormat</code> are specified, the Amazon Resource Name (ARN) and resource ID format of the resource type for a specified IAM user, IAM role, or the root user for an account is affected. The opt-in and opt-out account setting must be set for each Amazon ECS resource separately. The ARN and resource ID format of a resource will be defined by the opt-in status of the IAM user or role that created the resource. You must enable this setting to use Amazon ECS features such as resource tagging.</p> <p>When <code>awsvpcTrunking</code> is specified, the elastic network interface (ENI) limit for any new container instances that support the feature is changed. If <code>awsvpcTrunking</code> is enabled, any new container instances that support the feature are launched have the increased ENI limits available to them. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/container-instance-eni.html">Elastic Network Interface Trunking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When <code>containerInsights</code> is specified, the default setting indicating whether CloudWatch Container Insights is enabled for your clusters is changed. If <code>containerInsights</code> is enabled, any new clusters that are created will have Container Insights enabled unless you disable it during cluster creation. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cloudwatch-container-insights.html">CloudWatch Container Insights</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn put_account_setting(
         &self,
         input: PutAccountSettingRequest,
     ) -> RusotoFuture<PutAccountSettingResponse, PutAccountSettingError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.PutAccountSetting",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutAccountSettingResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutAccountSettingError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_account_setting(input)
     }
-
-    /// <p>Modifies an account setting for all IAM users on an account for whom no individual account setting has been specified. Account settings are set on a per-Region basis.</p>
     fn put_account_setting_default(
         &self,
         input: PutAccountSettingDefaultRequest,
     ) -> RusotoFuture<PutAccountSettingDefaultResponse, PutAccountSettingDefaultError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.PutAccountSettingDefault",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutAccountSettingDefaultResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutAccountSettingDefaultError::from_response(response))
-                }))
-            }
-        })
+        (**self).put_account_setting_default(input)
     }
-
-    /// <p>Create or update an attribute on an Amazon ECS resource. If the attribute does not exist, it is created. If the attribute exists, its value is replaced with the specified value. To delete an attribute, use <a>DeleteAttributes</a>. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html#attributes">Attributes</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn put_attributes(
         &self,
         input: PutAttributesRequest,
     ) -> RusotoFuture<PutAttributesResponse, PutAttributesError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.PutAttributes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutAttributesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutAttributesError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_attributes(input)
     }
-
-    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Registers an EC2 instance into the specified cluster. This instance becomes available to place containers on.</p></p>
     fn register_container_instance(
         &self,
         input: RegisterContainerInstanceRequest,
     ) -> RusotoFuture<RegisterContainerInstanceResponse, RegisterContainerInstanceError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.RegisterContainerInstance",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterContainerInstanceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegisterContainerInstanceError::from_response(response))
-                }))
-            }
-        })
+        (**self).register_container_instance(input)
     }
-
-    /// <p>Registers a new task definition from the supplied <code>family</code> and <code>containerDefinitions</code>. Optionally, you can add data volumes to your containers with the <code>volumes</code> parameter. For more information about task definition parameters and defaults, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_defintions.html">Amazon ECS Task Definitions</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>You can specify an IAM role for your task with the <code>taskRoleArn</code> parameter. When you specify an IAM role for a task, its containers can then use the latest versions of the AWS CLI or SDKs to make API requests to the AWS services that are specified in the IAM policy associated with the role. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-iam-roles.html">IAM Roles for Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>You can specify a Docker networking mode for the containers in your task definition with the <code>networkMode</code> parameter. The available network modes correspond to those described in <a href="https://docs.docker.com/engine/reference/run/#/network-settings">Network settings</a> in the Docker run reference. If you specify the <code>awsvpc</code> network mode, the task is allocated an elastic network interface, and you must specify a <a>NetworkConfiguration</a> when you create a service or run a task with the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn register_task_definition(
         &self,
         input: RegisterTaskDefinitionRequest,
     ) -> RusotoFuture<RegisterTaskDefinitionResponse, RegisterTaskDefinitionError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.RegisterTaskDefinition",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterTaskDefinitionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RegisterTaskDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).register_task_definition(input)
     }
-
-    /// <p><p>Starts a new task using the specified task definition.</p> <p>You can allow Amazon ECS to place tasks for you, or you can customize how Amazon ECS places tasks using placement constraints and placement strategies. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/scheduling_tasks.html">Scheduling Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>Alternatively, you can use <a>StartTask</a> to use your own scheduler or place tasks manually on specific container instances.</p> <p>The Amazon ECS API follows an eventual consistency model, due to the distributed nature of the system supporting the API. This means that the result of an API command you run that affects your Amazon ECS resources might not be immediately visible to all subsequent commands you run. Keep this in mind when you carry out an API command that immediately follows a previous API command.</p> <p>To manage eventual consistency, you can do the following:</p> <ul> <li> <p>Confirm the state of the resource before you run a command to modify it. Run the DescribeTasks command using an exponential backoff algorithm to ensure that you allow enough time for the previous command to propagate through the system. To do this, run the DescribeTasks command repeatedly, starting with a couple of seconds of wait time and increasing gradually up to five minutes of wait time.</p> </li> <li> <p>Add wait time between subsequent commands, even if the DescribeTasks command returns an accurate response. Apply an exponential backoff algorithm starting with a couple of seconds of wait time, and increase gradually up to about five minutes of wait time.</p> </li> </ul></p>
     fn run_task(&self, input: RunTaskRequest) -> RusotoFuture<RunTaskResponse, RunTaskError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonEC2ContainerServiceV20141113.RunTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<RunTaskResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RunTaskError::from_response(response))),
-                )
-            }
-        })
+        (**self).run_task(input)
     }
-
-    /// <p>Starts a new task from the specified task definition on the specified container instance or instances.</p> <p>Alternatively, you can use <a>RunTask</a> to place tasks for you. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/scheduling_tasks.html">Scheduling Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn start_task(
         &self,
         input: StartTaskRequest,
     ) -> RusotoFuture<StartTaskResponse, StartTaskError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.StartTask",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartTaskResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartTaskError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_task(input)
     }
-
-    /// <p><p>Stops a running task. Any tags associated with the task will be deleted.</p> <p>When <a>StopTask</a> is called on a task, the equivalent of <code>docker stop</code> is issued to the containers running in the task. This results in a <code>SIGTERM</code> value and a default 30-second timeout, after which the <code>SIGKILL</code> value is sent and the containers are forcibly stopped. If the container handles the <code>SIGTERM</code> value gracefully and exits within 30 seconds from receiving it, no <code>SIGKILL</code> value is sent.</p> <note> <p>The default 30-second timeout can be configured on the Amazon ECS container agent with the <code>ECS<em>CONTAINER</em>STOP_TIMEOUT</code> variable. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-config.html">Amazon ECS Container Agent Configuration</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </note></p>
     fn stop_task(&self, input: StopTaskRequest) -> RusotoFuture<StopTaskResponse, StopTaskError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.StopTask",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopTaskResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopTaskError::from_response(response))),
-                )
-            }
-        })
+        (**self).stop_task(input)
     }
-
-    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that an attachment changed states.</p></p>
     fn submit_attachment_state_changes(
         &self,
         input: SubmitAttachmentStateChangesRequest,
     ) -> RusotoFuture<SubmitAttachmentStateChangesResponse, SubmitAttachmentStateChangesError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.SubmitAttachmentStateChanges",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SubmitAttachmentStateChangesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SubmitAttachmentStateChangesError::from_response(response))
-                }))
-            }
-        })
+        (**self).submit_attachment_state_changes(input)
+    }
+    fn submit_container_state_change(
+        &self,
+        input: SubmitContainerStateChangeRequest,
+    ) -> RusotoFuture<SubmitContainerStateChangeResponse, SubmitContainerStateChangeError> {
+        (**self).submit_container_state_change(input)
+    }
+    fn submit_task_state_change(
+        &self,
+        input: SubmitTaskStateChangeRequest,
+    ) -> RusotoFuture<SubmitTaskStateChangeResponse, SubmitTaskStateChangeError> {
+        (**self).submit_task_state_change(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster_settings(
+        &self,
+        input: UpdateClusterSettingsRequest,
+    ) -> RusotoFuture<UpdateClusterSettingsResponse, UpdateClusterSettingsError> {
+        (**self).update_cluster_settings(input)
+    }
+    fn update_container_agent(
+        &self,
+        input: UpdateContainerAgentRequest,
+    ) -> RusotoFuture<UpdateContainerAgentResponse, UpdateContainerAgentError> {
+        (**self).update_container_agent(input)
+    }
+    fn update_container_instances_state(
+        &self,
+        input: UpdateContainerInstancesStateRequest,
+    ) -> RusotoFuture<UpdateContainerInstancesStateResponse, UpdateContainerInstancesStateError>
+    {
+        (**self).update_container_instances_state(input)
+    }
+    fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        (**self).update_service(input)
+    }
+    fn update_service_primary_task_set(
+        &self,
+        input: UpdateServicePrimaryTaskSetRequest,
+    ) -> RusotoFuture<UpdateServicePrimaryTaskSetResponse, UpdateServicePrimaryTaskSetError> {
+        (**self).update_service_primary_task_set(input)
+    }
+    fn update_task_set(
+        &self,
+        input: UpdateTaskSetRequest,
+    ) -> RusotoFuture<UpdateTaskSetResponse, UpdateTaskSetError> {
+        (**self).update_task_set(input)
+    }
+}
+impl<T: Ecs + ?Sized> Ecs for Box<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        (**self).create_service(input)
+    }
+    fn create_task_set(
+        &self,
+        input: CreateTaskSetRequest,
+    ) -> RusotoFuture<CreateTaskSetResponse, CreateTaskSetError> {
+        (**self).create_task_set(input)
+    }
+    fn delete_account_setting(
+        &self,
+        input: DeleteAccountSettingRequest,
+    ) -> RusotoFuture<DeleteAccountSettingResponse, DeleteAccountSettingError> {
+        (**self).delete_account_setting(input)
+    }
+    fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> RusotoFuture<DeleteAttributesResponse, DeleteAttributesError> {
+        (**self).delete_attributes(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        (**self).delete_service(input)
+    }
+    fn delete_task_set(
+        &self,
+        input: DeleteTaskSetRequest,
+    ) -> RusotoFuture<DeleteTaskSetResponse, DeleteTaskSetError> {
+        (**self).delete_task_set(input)
+    }
+    fn deregister_container_instance(
+        &self,
+        input: DeregisterContainerInstanceRequest,
+    ) -> RusotoFuture<DeregisterContainerInstanceResponse, DeregisterContainerInstanceError> {
+        (**self).deregister_container_instance(input)
+    }
+    fn deregister_task_definition(
+        &self,
+        input: DeregisterTaskDefinitionRequest,
+    ) -> RusotoFuture<DeregisterTaskDefinitionResponse, DeregisterTaskDefinitionError> {
+        (**self).deregister_task_definition(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn describe_container_instances(
+        &self,
+        input: DescribeContainerInstancesRequest,
+    ) -> RusotoFuture<DescribeContainerInstancesResponse, DescribeContainerInstancesError> {
+        (**self).describe_container_instances(input)
+    }
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn describe_task_definition(
+        &self,
+        input: DescribeTaskDefinitionRequest,
+    ) -> RusotoFuture<DescribeTaskDefinitionResponse, DescribeTaskDefinitionError> {
+        (**self).describe_task_definition(input)
+    }
+    fn describe_task_sets(
+        &self,
+        input: DescribeTaskSetsRequest,
+    ) -> RusotoFuture<DescribeTaskSetsResponse, DescribeTaskSetsError> {
+        (**self).describe_task_sets(input)
+    }
+    fn describe_tasks(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> RusotoFuture<DescribeTasksResponse, DescribeTasksError> {
+        (**self).describe_tasks(input)
+    }
+    fn discover_poll_endpoint(
+        &self,
+        input: DiscoverPollEndpointRequest,
+    ) -> RusotoFuture<DiscoverPollEndpointResponse, DiscoverPollEndpointError> {
+        (**self).discover_poll_endpoint(input)
+    }
+    fn list_account_settings(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> RusotoFuture<ListAccountSettingsResponse, ListAccountSettingsError> {
+        (**self).list_account_settings(input)
+    }
+    fn list_attributes(
+        &self,
+        input: ListAttributesRequest,
+    ) -> RusotoFuture<ListAttributesResponse, ListAttributesError> {
+        (**self).list_attributes(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_container_instances(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> RusotoFuture<ListContainerInstancesResponse, ListContainerInstancesError> {
+        (**self).list_container_instances(input)
+    }
+    fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        (**self).list_services(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_task_definition_families(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> RusotoFuture<ListTaskDefinitionFamiliesResponse, ListTaskDefinitionFamiliesError> {
+        (**self).list_task_definition_families(input)
+    }
+    fn list_task_definitions(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> RusotoFuture<ListTaskDefinitionsResponse, ListTaskDefinitionsError> {
+        (**self).list_task_definitions(input)
+    }
+    fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> RusotoFuture<ListTasksResponse, ListTasksError> {
+        (**self).list_tasks(input)
+    }
+    fn put_account_setting(
+        &self,
+        input: PutAccountSettingRequest,
+    ) -> RusotoFuture<PutAccountSettingResponse, PutAccountSettingError> {
+        (**self).put_account_setting(input)
+    }
+    fn put_account_setting_default(
+        &self,
+        input: PutAccountSettingDefaultRequest,
+    ) -> RusotoFuture<PutAccountSettingDefaultResponse, PutAccountSettingDefaultError> {
+        (**self).put_account_setting_default(input)
+    }
+    fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> RusotoFuture<PutAttributesResponse, PutAttributesError> {
+        (**self).put_attributes(input)
+    }
+    fn register_container_instance(
+        &self,
+        input: RegisterContainerInstanceRequest,
+    ) -> RusotoFuture<RegisterContainerInstanceResponse, RegisterContainerInstanceError> {
+        (**self).register_container_instance(input)
+    }
+    fn register_task_definition(
+        &self,
+        input: RegisterTaskDefinitionRequest,
+    ) -> RusotoFuture<RegisterTaskDefinitionResponse, RegisterTaskDefinitionError> {
+        (**self).register_task_definition(input)
+    }
+    fn run_task(&self, input: RunTaskRequest) -> RusotoFuture<RunTaskResponse, RunTaskError> {
+        (**self).run_task(input)
+    }
+    fn start_task(
+        &self,
+        input: StartTaskRequest,
+    ) -> RusotoFuture<StartTaskResponse, StartTaskError> {
+        (**self).start_task(input)
+    }
+    fn stop_task(&self, input: StopTaskRequest) -> RusotoFuture<StopTaskResponse, StopTaskError> {
+        (**self).stop_task(input)
+    }
+    fn submit_attachment_state_changes(
+        &self,
+        input: SubmitAttachmentStateChangesRequest,
+    ) -> RusotoFuture<SubmitAttachmentStateChangesResponse, SubmitAttachmentStateChangesError> {
+        (**self).submit_attachment_state_changes(input)
+    }
+    fn submit_container_state_change(
+        &self,
+        input: SubmitContainerStateChangeRequest,
+    ) -> RusotoFuture<SubmitContainerStateChangeResponse, SubmitContainerStateChangeError> {
+        (**self).submit_container_state_change(input)
+    }
+    fn submit_task_state_change(
+        &self,
+        input: SubmitTaskStateChangeRequest,
+    ) -> RusotoFuture<SubmitTaskStateChangeResponse, SubmitTaskStateChangeError> {
+        (**self).submit_task_state_change(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster_settings(
+        &self,
+        input: UpdateClusterSettingsRequest,
+    ) -> RusotoFuture<UpdateClusterSettingsResponse, UpdateClusterSettingsError> {
+        (**self).update_cluster_settings(input)
+    }
+    fn update_container_agent(
+        &self,
+        input: UpdateContainerAgentRequest,
+    ) -> RusotoFuture<UpdateContainerAgentResponse, UpdateContainerAgentError> {
+        (**self).update_container_agent(input)
+    }
+    fn update_container_instances_state(
+        &self,
+        input: UpdateContainerInstancesStateRequest,
+    ) -> RusotoFuture<UpdateContainerInstancesStateResponse, UpdateContainerInstancesStateError>
+    {
+        (**self).update_container_instances_state(input)
+    }
+    fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        (**self).update_service(input)
+    }
+    fn update_service_primary_task_set(
+        &self,
+        input: UpdateServicePrimaryTaskSetRequest,
+    ) -> RusotoFuture<UpdateServicePrimaryTaskSetResponse, UpdateServicePrimaryTaskSetError> {
+        (**self).update_service_primary_task_set(input)
+    }
+    fn update_task_set(
+        &self,
+        input: UpdateTaskSetRequest,
+    ) -> RusotoFuture<UpdateTaskSetResponse, UpdateTaskSetError> {
+        (**self).update_task_set(input)
+    }
+}
+impl<T: Ecs + ?Sized> Ecs for ::std::sync::Arc<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        (**self).create_service(input)
+    }
+    fn create_task_set(
+        &self,
+        input: CreateTaskSetRequest,
+    ) -> RusotoFuture<CreateTaskSetResponse, CreateTaskSetError> {
+        (**self).create_task_set(input)
+    }
+    fn delete_account_setting(
+        &self,
+        input: DeleteAccountSettingRequest,
+    ) -> RusotoFuture<DeleteAccountSettingResponse, DeleteAccountSettingError> {
+        (**self).delete_account_setting(input)
+    }
+    fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> RusotoFuture<DeleteAttributesResponse, DeleteAttributesError> {
+        (**self).delete_attributes(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        (**self).delete_service(input)
+    }
+    fn delete_task_set(
+        &self,
+        input: DeleteTaskSetRequest,
+    ) -> RusotoFuture<DeleteTaskSetResponse, DeleteTaskSetError> {
+        (**self).delete_task_set(input)
+    }
+    fn deregister_container_instance(
+        &self,
+        input: DeregisterContainerInstanceRequest,
+    ) -> RusotoFuture<DeregisterContainerInstanceResponse, DeregisterContainerInstanceError> {
+        (**self).deregister_container_instance(input)
+    }
+    fn deregister_task_definition(
+        &self,
+        input: DeregisterTaskDefinitionRequest,
+    ) -> RusotoFuture<DeregisterTaskDefinitionResponse, DeregisterTaskDefinitionError> {
+        (**self).deregister_task_definition(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn describe_container_instances(
+        &self,
+        input: DescribeContainerInstancesRequest,
+    ) -> RusotoFuture<DescribeContainerInstancesResponse, DescribeContainerInstancesError> {
+        (**self).describe_container_instances(input)
+    }
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn describe_task_definition(
+        &self,
+        input: DescribeTaskDefinitionRequest,
+    ) -> RusotoFuture<DescribeTaskDefinitionResponse, DescribeTaskDefinitionError> {
+        (**self).describe_task_definition(input)
+    }
+    fn describe_task_sets(
+        &self,
+        input: DescribeTaskSetsRequest,
+    ) -> RusotoFuture<DescribeTaskSetsResponse, DescribeTaskSetsError> {
+        (**self).describe_task_sets(input)
+    }
+    fn describe_tasks(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> RusotoFuture<DescribeTasksResponse, DescribeTasksError> {
+        (**self).describe_tasks(input)
+    }
+    fn discover_poll_endpoint(
+        &self,
+        input: DiscoverPollEndpointRequest,
+    ) -> RusotoFuture<DiscoverPollEndpointResponse, DiscoverPollEndpointError> {
+        (**self).discover_poll_endpoint(input)
+    }
+    fn list_account_settings(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> RusotoFuture<ListAccountSettingsResponse, ListAccountSettingsError> {
+        (**self).list_account_settings(input)
+    }
+    fn list_attributes(
+        &self,
+        input: ListAttributesRequest,
+    ) -> RusotoFuture<ListAttributesResponse, ListAttributesError> {
+        (**self).list_attributes(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_container_instances(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> RusotoFuture<ListContainerInstancesResponse, ListContainerInstancesError> {
+        (**self).list_container_instances(input)
+    }
+    fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        (**self).list_services(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_task_definition_families(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> RusotoFuture<ListTaskDefinitionFamiliesResponse, ListTaskDefinitionFamiliesError> {
+        (**self).list_task_definition_families(input)
+    }
+    fn list_task_definitions(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> RusotoFuture<ListTaskDefinitionsResponse, ListTaskDefinitionsError> {
+        (**self).list_task_definitions(input)
+    }
+    fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> RusotoFuture<ListTasksResponse, ListTasksError> {
+        (**self).list_tasks(input)
+    }
+    fn put_account_setting(
+        &self,
+        input: PutAccountSettingRequest,
+    ) -> RusotoFuture<PutAccountSettingResponse, PutAccountSettingError> {
+        (**self).put_account_setting(input)
+    }
+    fn put_account_setting_default(
+        &self,
+        input: PutAccountSettingDefaultRequest,
+    ) -> RusotoFuture<PutAccountSettingDefaultResponse, PutAccountSettingDefaultError> {
+        (**self).put_account_setting_default(input)
+    }
+    fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> RusotoFuture<PutAttributesResponse, PutAttributesError> {
+        (**self).put_attributes(input)
+    }
+    fn register_container_instance(
+        &self,
+        input: RegisterContainerInstanceRequest,
+    ) -> RusotoFuture<RegisterContainerInstanceResponse, RegisterContainerInstanceError> {
+        (**self).register_container_instance(input)
+    }
+    fn register_task_definition(
+        &self,
+        input: RegisterTaskDefinitionRequest,
+    ) -> RusotoFuture<RegisterTaskDefinitionResponse, RegisterTaskDefinitionError> {
+        (**self).register_task_definition(input)
+    }
+    fn run_task(&self, input: RunTaskRequest) -> RusotoFuture<RunTaskResponse, RunTaskError> {
+        (**self).run_task(input)
+    }
+    fn start_task(
+        &self,
+        input: StartTaskRequest,
+    ) -> RusotoFuture<StartTaskResponse, StartTaskError> {
+        (**self).start_task(input)
+    }
+    fn stop_task(&self, input: StopTaskRequest) -> RusotoFuture<StopTaskResponse, StopTaskError> {
+        (**self).stop_task(input)
+    }
+    fn submit_attachment_state_changes(
+        &self,
+        input: SubmitAttachmentStateChangesRequest,
+    ) -> RusotoFuture<SubmitAttachmentStateChangesResponse, SubmitAttachmentStateChangesError> {
+        (**self).submit_attachment_state_changes(input)
+    }
+    fn submit_container_state_change(
+        &self,
+        input: SubmitContainerStateChangeRequest,
+    ) -> RusotoFuture<SubmitContainerStateChangeResponse, SubmitContainerStateChangeError> {
+        (**self).submit_container_state_change(input)
+    }
+    fn submit_task_state_change(
+        &self,
+        input: SubmitTaskStateChangeRequest,
+    ) -> RusotoFuture<SubmitTaskStateChangeResponse, SubmitTaskStateChangeError> {
+        (**self).submit_task_state_change(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster_settings(
+        &self,
+        input: UpdateClusterSettingsRequest,
+    ) -> RusotoFuture<UpdateClusterSettingsResponse, UpdateClusterSettingsError> {
+        (**self).update_cluster_settings(input)
+    }
+    fn update_container_agent(
+        &self,
+        input: UpdateContainerAgentRequest,
+    ) -> RusotoFuture<UpdateContainerAgentResponse, UpdateContainerAgentError> {
+        (**self).update_container_agent(input)
+    }
+    fn update_container_instances_state(
+        &self,
+        input: UpdateContainerInstancesStateRequest,
+    ) -> RusotoFuture<UpdateContainerInstancesStateResponse, UpdateContainerInstancesStateError>
+    {
+        (**self).update_container_instances_state(input)
+    }
+    fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        (**self).update_service(input)
+    }
+    fn update_service_primary_task_set(
+        &self,
+        input: UpdateServicePrimaryTaskSetRequest,
+    ) -> RusotoFuture<UpdateServicePrimaryTaskSetResponse, UpdateServicePrimaryTaskSetError> {
+        (**self).update_service_primary_task_set(input)
+    }
+    fn update_task_set(
+        &self,
+        input: UpdateTaskSetRequest,
+    ) -> RusotoFuture<UpdateTaskSetResponse, UpdateTaskSetError> {
+        (**self).update_task_set(input)
+    }
+}
+/// A client for the Amazon ECS API.
+#[derive(Clone)]
+pub struct EcsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl EcsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EcsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EcsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> EcsClient {
+        EcsClient { client, region }
+    }
+}
+
+impl Ecs for EcsClient {
+    /// <p><p>Creates a new Amazon ECS cluster. By default, your account receives a <code>default</code> cluster when you launch your first container instance. However, you can create your own cluster with a unique name with the <code>CreateCluster</code> action.</p> <note> <p>When you call the <a>CreateCluster</a> API operation, Amazon ECS attempts to create the service-linked role for your account so that required resources in other AWS services can be managed on your behalf. However, if the IAM user that makes the call does not have permissions to create the service-linked role, it is not created. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </note></p>
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateCluster");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.CreateCluster",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateClusterResponse, _>(
+                    response,
+                    "CreateCluster",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateClusterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Runs and maintains a desired number of tasks from a specified task definition. If the number of tasks running in a service drops below the <code>desiredCount</code>, Amazon ECS runs another copy of the task in the specified cluster. To update an existing service, see <a>UpdateService</a>.</p> <p>In addition to maintaining the desired count of tasks in your service, you can optionally run your service behind one or more load balancers. The load balancers distribute traffic across the tasks that are associated with the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/service-load-balancing.html">Service Load Balancing</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>Tasks for services that <i>do not</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state. Tasks for services that <i>do</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state and the container instance that they&#39;re hosted on is reported as healthy by the load balancer.</p> <p>There are two service scheduler strategies available:</p> <ul> <li> <p> <code>REPLICA</code> - The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Service Scheduler Concepts</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </li> <li> <p> <code>DAEMON</code> - The daemon scheduling strategy deploys exactly one task on each active container instance that meets all of the task placement constraints that you specify in your cluster. When using this strategy, you don&#39;t need to specify a desired number of tasks, a task placement strategy, or use Service Auto Scaling policies. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Service Scheduler Concepts</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </li> </ul> <p>You can optionally specify a deployment configuration for your service. The deployment is triggered by changing properties, such as the task definition or the desired count of a service, with an <a>UpdateService</a> operation. The default value for a replica service for <code>minimumHealthyPercent</code> is 100%. The default value for a daemon service for <code>minimumHealthyPercent</code> is 0%.</p> <p>If a service is using the <code>ECS</code> deployment controller, the minimum healthy percent represents a lower limit on the number of tasks in a service that must remain in the <code>RUNNING</code> state during a deployment, as a percentage of the desired number of tasks (rounded up to the nearest integer), and while any container instances are in the <code>DRAINING</code> state if the service contains tasks using the EC2 launch type. This parameter enables you to deploy without using additional cluster capacity. For example, if your service has a desired number of four tasks and a minimum healthy percent of 50%, the scheduler might stop two existing tasks to free up cluster capacity before starting two new tasks. Tasks for services that <i>do not</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state. Tasks for services that <i>do</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state and they&#39;re reported as healthy by the load balancer. The default value for minimum healthy percent is 100%.</p> <p>If a service is using the <code>ECS</code> deployment controller, the <b>maximum percent</b> parameter represents an upper limit on the number of tasks in a service that are allowed in the <code>RUNNING</code> or <code>PENDING</code> state during a deployment, as a percentage of the desired number of tasks (rounded down to the nearest integer), and while any container instances are in the <code>DRAINING</code> state if the service contains tasks using the EC2 launch type. This parameter enables you to define the deployment batch size. For example, if your service has a desired number of four tasks and a maximum percent value of 200%, the scheduler may start four new tasks before stopping the four older tasks (provided that the cluster resources required to do this are available). The default value for maximum percent is 200%.</p> <p>If a service is using either the <code>CODE_DEPLOY</code> or <code>EXTERNAL</code> deployment controller types and tasks that use the EC2 launch type, the <b>minimum healthy percent</b> and <b>maximum percent</b> values are used only to define the lower and upper limit on the number of the tasks in the service that remain in the <code>RUNNING</code> state while the container instances are in the <code>DRAINING</code> state. If the tasks in the service use the Fargate launch type, the minimum healthy percent and maximum percent values aren&#39;t used, although they&#39;re currently visible when describing your service.</p> <p>When creating a service that uses the <code>EXTERNAL</code> deployment controller, you can specify only parameters that aren&#39;t controlled at the task set level. The only required parameter is the service name. You control your services using the <a>CreateTaskSet</a> operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When the service scheduler launches new tasks, it determines task placement in your cluster using the following logic:</p> <ul> <li> <p>Determine which of the container instances in your cluster can support your service&#39;s task definition (for example, they have the required CPU, memory, ports, and container instance attributes).</p> </li> <li> <p>By default, the service scheduler attempts to balance tasks across Availability Zones in this manner (although you can choose a different placement strategy) with the <code>placementStrategy</code> parameter):</p> <ul> <li> <p>Sort the valid container instances, giving priority to instances that have the fewest number of running tasks for this service in their respective Availability Zone. For example, if zone A has one running service task and zones B and C each have zero, valid container instances in either zone B or C are considered optimal for placement.</p> </li> <li> <p>Place the new service task on a valid container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the fewest number of running tasks for this service.</p> </li> </ul> </li> </ul></p>
+    fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateService");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.CreateService",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateServiceResponse, _>(
+                    response,
+                    "CreateService",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateServiceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Create a task set in the specified cluster and service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn create_task_set(
+        &self,
+        input: CreateTaskSetRequest,
+    ) -> RusotoFuture<CreateTaskSetResponse, CreateTaskSetError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateTaskSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.CreateTaskSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTaskSetResponse, _>(
+                    response,
+                    "CreateTaskSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTaskSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables an account setting for a specified IAM user, IAM role, or the root user for an account.</p>
+    fn delete_account_setting(
+        &self,
+        input: DeleteAccountSettingRequest,
+    ) -> RusotoFuture<DeleteAccountSettingResponse, DeleteAccountSettingError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteAccountSetting");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DeleteAccountSetting",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteAccountSettingResponse, _>(
+                    response,
+                    "DeleteAccountSetting",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteAccountSettingError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes one or more custom attributes from an Amazon ECS resource.</p>
+    fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> RusotoFuture<DeleteAttributesResponse, DeleteAttributesError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DeleteAttributes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteAttributesResponse, _>(
+                    response,
+                    "DeleteAttributes",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAttributesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified cluster. You must deregister all container instances from this cluster before you may delete it. You can list the container instances in a cluster with <a>ListContainerInstances</a> and deregister them with <a>DeregisterContainerInstance</a>.</p>
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteCluster");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DeleteCluster",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteClusterResponse, _>(
+                    response,
+                    "DeleteCluster",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteClusterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a specified service within a cluster. You can delete a service if you have no running tasks in it and the desired task count is zero. If the service is actively maintaining tasks, you cannot delete it, and you must update the service to a desired task count of zero. For more information, see <a>UpdateService</a>.</p> <note> <p>When you delete a service, if there are still running tasks that require cleanup, the service status moves from <code>ACTIVE</code> to <code>DRAINING</code>, and the service is no longer visible in the console or in the <a>ListServices</a> API operation. After all tasks have transitioned to either <code>STOPPING</code> or <code>STOPPED</code> status, the service status moves from <code>DRAINING</code> to <code>INACTIVE</code>. Services in the <code>DRAINING</code> or <code>INACTIVE</code> status can still be viewed with the <a>DescribeServices</a> API operation. However, in the future, <code>INACTIVE</code> services may be cleaned up and purged from Amazon ECS record keeping, and <a>DescribeServices</a> calls on those services return a <code>ServiceNotFoundException</code> error.</p> </note> <important> <p>If you attempt to create a new service with the same name as an existing service in either <code>ACTIVE</code> or <code>DRAINING</code> status, you receive an error.</p> </important></p>
+    fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteService");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DeleteService",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteServiceResponse, _>(
+                    response,
+                    "DeleteService",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteServiceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified task set within a service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn delete_task_set(
+        &self,
+        input: DeleteTaskSetRequest,
+    ) -> RusotoFuture<DeleteTaskSetResponse, DeleteTaskSetError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteTaskSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DeleteTaskSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTaskSetResponse, _>(
+                    response,
+                    "DeleteTaskSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTaskSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deregisters an Amazon ECS container instance from the specified cluster. This instance is no longer available to run tasks.</p> <p>If you intend to use the container instance for some other purpose after deregistration, you should stop all of the tasks running on the container instance before deregistration. That prevents any orphaned tasks from consuming resources.</p> <p>Deregistering a container instance removes the instance from a cluster, but it does not terminate the EC2 instance. If you are finished using the instance, be sure to terminate it in the Amazon EC2 console to stop billing.</p> <note> <p>If you terminate a running container instance, Amazon ECS automatically deregisters the instance from your cluster (stopped container instances or instances with disconnected agents are not automatically deregistered when terminated).</p> </note></p>
+    fn deregister_container_instance(
+        &self,
+        input: DeregisterContainerInstanceRequest,
+    ) -> RusotoFuture<DeregisterContainerInstanceResponse, DeregisterContainerInstanceError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeregisterContainerInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DeregisterContainerInstance",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeregisterContainerInstanceResponse, _>(
+                    response,
+                    "DeregisterContainerInstance",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterContainerInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deregisters the specified task definition by family and revision. Upon deregistration, the task definition is marked as <code>INACTIVE</code>. Existing tasks and services that reference an <code>INACTIVE</code> task definition continue to run without disruption. Existing services that reference an <code>INACTIVE</code> task definition can still scale up or down by modifying the service&#39;s desired count.</p> <p>You cannot use an <code>INACTIVE</code> task definition to run new tasks or create new services, and you cannot update an existing service to reference an <code>INACTIVE</code> task definition. However, there may be up to a 10-minute window following deregistration where these restrictions have not yet taken effect.</p> <note> <p>At this time, <code>INACTIVE</code> task definitions remain discoverable in your account indefinitely. However, this behavior is subject to change in the future, so you should not rely on <code>INACTIVE</code> task definitions persisting beyond the lifecycle of any associated tasks and services.</p> </note></p>
+    fn deregister_task_definition(
+        &self,
+        input: DeregisterTaskDefinitionRequest,
+    ) -> RusotoFuture<DeregisterTaskDefinitionResponse, DeregisterTaskDefinitionError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeregisterTaskDefinition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DeregisterTaskDefinition",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeregisterTaskDefinitionResponse, _>(
+                    response,
+                    "DeregisterTaskDefinition",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterTaskDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes one or more of your clusters.</p>
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeClusters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DescribeClusters",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeClustersResponse, _>(
+                    response,
+                    "DescribeClusters",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeClustersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes Amazon Elastic Container Service container instances. Returns metadata about registered and remaining resources on each container instance requested.</p>
+    fn describe_container_instances(
+        &self,
+        input: DescribeContainerInstancesRequest,
+    ) -> RusotoFuture<DescribeContainerInstancesResponse, DescribeContainerInstancesError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeContainerInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DescribeContainerInstances",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeContainerInstancesResponse, _>(
+                    response,
+                    "DescribeContainerInstances",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeContainerInstancesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the specified services running in your cluster.</p>
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeServices");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DescribeServices",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeServicesResponse, _>(
+                    response,
+                    "DescribeServices",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeServicesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Describes a task definition. You can specify a <code>family</code> and <code>revision</code> to find information about a specific task definition, or you can simply specify the family to find the latest <code>ACTIVE</code> revision in that family.</p> <note> <p>You can only describe <code>INACTIVE</code> task definitions while an active task or service references them.</p> </note></p>
+    fn describe_task_definition(
+        &self,
+        input: DescribeTaskDefinitionRequest,
+    ) -> RusotoFuture<DescribeTaskDefinitionResponse, DescribeTaskDefinitionError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeTaskDefinition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DescribeTaskDefinition",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTaskDefinitionResponse, _>(
+                    response,
+                    "DescribeTaskDefinition",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeTaskDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the task sets in the specified cluster and service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn describe_task_sets(
+        &self,
+        input: DescribeTaskSetsRequest,
+    ) -> RusotoFuture<DescribeTaskSetsResponse, DescribeTaskSetsError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeTaskSets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DescribeTaskSets",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTaskSetsResponse, _>(
+                    response,
+                    "DescribeTaskSets",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTaskSetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes a specified task or tasks.</p>
+    fn describe_tasks(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> RusotoFuture<DescribeTasksResponse, DescribeTasksError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeTasks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DescribeTasks",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTasksResponse, _>(
+                    response,
+                    "DescribeTasks",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTasksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Returns an endpoint for the Amazon ECS agent to poll for updates.</p></p>
+    fn discover_poll_endpoint(
+        &self,
+        input: DiscoverPollEndpointRequest,
+    ) -> RusotoFuture<DiscoverPollEndpointResponse, DiscoverPollEndpointError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DiscoverPollEndpoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.DiscoverPollEndpoint",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DiscoverPollEndpointResponse, _>(
+                    response,
+                    "DiscoverPollEndpoint",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DiscoverPollEndpointError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the account settings for a specified principal.</p>
+    fn list_account_settings(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> RusotoFuture<ListAccountSettingsResponse, ListAccountSettingsError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListAccountSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.ListAccountSettings",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAccountSettingsResponse, _>(
+                    response,
+                    "ListAccountSettings",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListAccountSettingsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the attributes for Amazon ECS resources within a specified target type and cluster. When you specify a target type and cluster, <code>ListAttributes</code> returns a list of attribute objects, one for each attribute on each resource. You can filter the list of results to a single attribute name to only return results that have that name. You can also filter the results by attribute name and value, for example, to see which container instances in a cluster are running a Linux AMI (<code>ecs.os-type=linux</code>). </p>
+    fn list_attributes(
+        &self,
+        input: ListAttributesRequest,
+    ) -> RusotoFuture<ListAttributesResponse, ListAttributesError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.ListAttributes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAttributesResponse, _>(
+                    response,
+                    "ListAttributes",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAttributesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of existing clusters.</p>
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListClusters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.ListClusters",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListClustersResponse, _>(
+                    response,
+                    "ListClusters",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListClustersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of container instances in a specified cluster. You can filter the results of a <code>ListContainerInstances</code> operation with cluster query language statements inside the <code>filter</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cluster-query-language.html">Cluster Query Language</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn list_container_instances(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> RusotoFuture<ListContainerInstancesResponse, ListContainerInstancesError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListContainerInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.ListContainerInstances",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListContainerInstancesResponse, _>(
+                    response,
+                    "ListContainerInstances",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListContainerInstancesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the services that are running in a specified cluster.</p>
+    fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListServices");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.ListServices",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListServicesResponse, _>(
+                    response,
+                    "ListServices",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListServicesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>List the tags for an Amazon ECS resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.ListTagsForResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of task definition families that are registered to your account (which may include task definition families that no longer have any <code>ACTIVE</code> task definition revisions).</p> <p>You can filter out task definition families that do not contain any <code>ACTIVE</code> task definition revisions by setting the <code>status</code> parameter to <code>ACTIVE</code>. You can also filter the results with the <code>familyPrefix</code> parameter.</p>
+    fn list_task_definition_families(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> RusotoFuture<ListTaskDefinitionFamiliesResponse, ListTaskDefinitionFamiliesError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTaskDefinitionFamilies");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.ListTaskDefinitionFamilies",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTaskDefinitionFamiliesResponse, _>(
+                    response,
+                    "ListTaskDefinitionFamilies",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListTaskDefinitionFamiliesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of task definitions that are registered to your account. You can filter the results by family name with the <code>familyPrefix</code> parameter or by status with the <code>status</code> parameter.</p>
+    fn list_task_definitions(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> RusotoFuture<ListTaskDefinitionsResponse, ListTaskDefinitionsError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTaskDefinitions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.ListTaskDefinitions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTaskDefinitionsResponse, _>(
+                    response,
+                    "ListTaskDefinitions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTaskDefinitionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of tasks for a specified cluster. You can filter the results by family name, by a particular container instance, or by the desired status of the task with the <code>family</code>, <code>containerInstance</code>, and <code>desiredStatus</code> parameters.</p> <p>Recently stopped tasks might appear in the returned results. Currently, stopped tasks appear in the returned results for at least one hour. </p>
+    fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> RusotoFuture<ListTasksResponse, ListTasksError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTasks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.ListTasks",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTasksResponse, _>(response, "ListTasks")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTasksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies an account setting. Account settings are set on a per-Region basis.</p> <p>If you change the account setting for the root user, the default settings for all of the IAM users and roles for which no individual account setting has been specified are reset. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-account-settings.html">Account Settings</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When <code>serviceLongArnFormat</code>, <code>taskLongArnFormat</code>, or <code>containerInstanceLongArnFormat</code> are specified, the Amazon Resource Name (ARN) and resource ID format of the resource type for a specified IAM user, IAM role, or the root user for an account is affected. The opt-in and opt-out account setting must be set for each Amazon ECS resource separately. The ARN and resource ID format of a resource will be defined by the opt-in status of the IAM user or role that created the resource. You must enable this setting to use Amazon ECS features such as resource tagging.</p> <p>When <code>awsvpcTrunking</code> is specified, the elastic network interface (ENI) limit for any new container instances that support the feature is changed. If <code>awsvpcTrunking</code> is enabled, any new container instances that support the feature are launched have the increased ENI limits available to them. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/container-instance-eni.html">Elastic Network Interface Trunking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When <code>containerInsights</code> is specified, the default setting indicating whether CloudWatch Container Insights is enabled for your clusters is changed. If <code>containerInsights</code> is enabled, any new clusters that are created will have Container Insights enabled unless you disable it during cluster creation. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cloudwatch-container-insights.html">CloudWatch Container Insights</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn put_account_setting(
+        &self,
+        input: PutAccountSettingRequest,
+    ) -> RusotoFuture<PutAccountSettingResponse, PutAccountSettingError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutAccountSetting");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.PutAccountSetting",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutAccountSettingResponse, _>(
+                    response,
+                    "PutAccountSetting",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutAccountSettingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies an account setting for all IAM users on an account for whom no individual account setting has been specified. Account settings are set on a per-Region basis.</p>
+    fn put_account_setting_default(
+        &self,
+        input: PutAccountSettingDefaultRequest,
+    ) -> RusotoFuture<PutAccountSettingDefaultResponse, PutAccountSettingDefaultError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutAccountSettingDefault");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.PutAccountSettingDefault",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutAccountSettingDefaultResponse, _>(
+                    response,
+                    "PutAccountSettingDefault",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutAccountSettingDefaultError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Create or update an attribute on an Amazon ECS resource. If the attribute does not exist, it is created. If the attribute exists, its value is replaced with the specified value. To delete an attribute, use <a>DeleteAttributes</a>. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html#attributes">Attributes</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> RusotoFuture<PutAttributesResponse, PutAttributesError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.PutAttributes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutAttributesResponse, _>(
+                    response,
+                    "PutAttributes",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutAttributesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Registers an EC2 instance into the specified cluster. This instance becomes available to place containers on.</p></p>
+    fn register_container_instance(
+        &self,
+        input: RegisterContainerInstanceRequest,
+    ) -> RusotoFuture<RegisterContainerInstanceResponse, RegisterContainerInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("RegisterContainerInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.RegisterContainerInstance",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterContainerInstanceResponse, _>(
+                    response,
+                    "RegisterContainerInstance",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RegisterContainerInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Registers a new task definition from the supplied <code>family</code> and <code>containerDefinitions</code>. Optionally, you can add data volumes to your containers with the <code>volumes</code> parameter. For more information about task definition parameters and defaults, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_defintions.html">Amazon ECS Task Definitions</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>You can specify an IAM role for your task with the <code>taskRoleArn</code> parameter. When you specify an IAM role for a task, its containers can then use the latest versions of the AWS CLI or SDKs to make API requests to the AWS services that are specified in the IAM policy associated with the role. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-iam-roles.html">IAM Roles for Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>You can specify a Docker networking mode for the containers in your task definition with the <code>networkMode</code> parameter. The available network modes correspond to those described in <a href="https://docs.docker.com/engine/reference/run/#/network-settings">Network settings</a> in the Docker run reference. If you specify the <code>awsvpc</code> network mode, the task is allocated an elastic network interface, and you must specify a <a>NetworkConfiguration</a> when you create a service or run a task with the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn register_task_definition(
+        &self,
+        input: RegisterTaskDefinitionRequest,
+    ) -> RusotoFuture<RegisterTaskDefinitionResponse, RegisterTaskDefinitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("RegisterTaskDefinition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.RegisterTaskDefinition",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterTaskDefinitionResponse, _>(
+                    response,
+                    "RegisterTaskDefinition",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RegisterTaskDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Starts a new task using the specified task definition.</p> <p>You can allow Amazon ECS to place tasks for you, or you can customize how Amazon ECS places tasks using placement constraints and placement strategies. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/scheduling_tasks.html">Scheduling Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>Alternatively, you can use <a>StartTask</a> to use your own scheduler or place tasks manually on specific container instances.</p> <p>The Amazon ECS API follows an eventual consistency model, due to the distributed nature of the system supporting the API. This means that the result of an API command you run that affects your Amazon ECS resources might not be immediately visible to all subsequent commands you run. Keep this in mind when you carry out an API command that immediately follows a previous API command.</p> <p>To manage eventual consistency, you can do the following:</p> <ul> <li> <p>Confirm the state of the resource before you run a command to modify it. Run the DescribeTasks command using an exponential backoff algorithm to ensure that you allow enough time for the previous command to propagate through the system. To do this, run the DescribeTasks command repeatedly, starting with a couple of seconds of wait time and increasing gradually up to five minutes of wait time.</p> </li> <li> <p>Add wait time between subsequent commands, even if the DescribeTasks command returns an accurate response. Apply an exponential backoff algorithm starting with a couple of seconds of wait time, and increase gradually up to about five minutes of wait time.</p> </li> </ul></p>
+    fn run_task(&self, input: RunTaskRequest) -> RusotoFuture<RunTaskResponse, RunTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("RunTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonEC2ContainerServiceV20141113.RunTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RunTaskResponse, _>(response, "RunTask")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RunTaskError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a new task from the specified task definition on the specified container instance or instances.</p> <p>Alternatively, you can use <a>RunTask</a> to place tasks for you. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/scheduling_tasks.html">Scheduling Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn start_task(
+        &self,
+        input: StartTaskRequest,
+    ) -> RusotoFuture<StartTaskResponse, StartTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("StartTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.StartTask",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartTaskResponse, _>(response, "StartTask")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartTaskError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Stops a running task. Any tags associated with the task will be deleted.</p> <p>When <a>StopTask</a> is called on a task, the equivalent of <code>docker stop</code> is issued to the containers running in the task. This results in a <code>SIGTERM</code> value and a default 30-second timeout, after which the <code>SIGKILL</code> value is sent and the containers are forcibly stopped. If the container handles the <code>SIGTERM</code> value gracefully and exits within 30 seconds from receiving it, no <code>SIGKILL</code> value is sent.</p> <note> <p>The default 30-second timeout can be configured on the Amazon ECS container agent with the <code>ECS<em>CONTAINER</em>STOP_TIMEOUT</code> variable. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-config.html">Amazon ECS Container Agent Configuration</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </note></p>
+    fn stop_task(&self, input: StopTaskRequest) -> RusotoFuture<StopTaskResponse, StopTaskError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("StopTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.StopTask",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopTaskResponse, _>(response, "StopTask")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopTaskError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that an attachment changed states.</p></p>
+    fn submit_attachment_state_changes(
+        &self,
+        input: SubmitAttachmentStateChangesRequest,
+    ) -> RusotoFuture<SubmitAttachmentStateChangesResponse, SubmitAttachmentStateChangesError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("SubmitAttachmentStateChanges");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.SubmitAttachmentStateChanges",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SubmitAttachmentStateChangesResponse, _>(
+                    response,
+                    "SubmitAttachmentStateChanges",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SubmitAttachmentStateChangesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that a container changed states.</p></p>
+    fn submit_container_state_change(
+        &self,
+        input: SubmitContainerStateChangeRequest,
+    ) -> RusotoFuture<SubmitContainerStateChangeResponse, SubmitContainerStateChangeError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("SubmitContainerStateChange");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.SubmitContainerStateChange",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SubmitContainerStateChangeResponse, _>(
+                    response,
+                    "SubmitContainerStateChange",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SubmitContainerStateChangeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that a task changed states.</p></p>
+    fn submit_task_state_change(
+        &self,
+        input: SubmitTaskStateChangeRequest,
+    ) -> RusotoFuture<SubmitTaskStateChangeResponse, SubmitTaskStateChangeError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("SubmitTaskStateChange");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.SubmitTaskStateChange",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SubmitTaskStateChangeResponse, _>(
+                    response,
+                    "SubmitTaskStateChange",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SubmitTaskStateChangeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.TagResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.UntagResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies the settings to use for a cluster.</p>
+    fn update_cluster_settings(
+        &self,
+        input: UpdateClusterSettingsRequest,
+    ) -> RusotoFuture<UpdateClusterSettingsResponse, UpdateClusterSettingsError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateClusterSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.UpdateClusterSettings",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateClusterSettingsResponse, _>(
+                    response,
+                    "UpdateClusterSettings",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateClusterSettingsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the Amazon ECS container agent on a specified container instance. Updating the Amazon ECS container agent does not interrupt running tasks or services on the container instance. The process for updating the agent differs depending on whether your container instance was launched with the Amazon ECS-optimized AMI or another operating system.</p> <p> <code>UpdateContainerAgent</code> requires the Amazon ECS-optimized AMI or Amazon Linux with the <code>ecs-init</code> service installed and running. For help updating the Amazon ECS container agent on other operating systems, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-update.html#manually_update_agent">Manually Updating the Amazon ECS Container Agent</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn update_container_agent(
+        &self,
+        input: UpdateContainerAgentRequest,
+    ) -> RusotoFuture<UpdateContainerAgentResponse, UpdateContainerAgentError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateContainerAgent");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.UpdateContainerAgent",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateContainerAgentResponse, _>(
+                    response,
+                    "UpdateContainerAgent",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateContainerAgentError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies the status of an Amazon ECS container instance.</p> <p>Once a container instance has reached an <code>ACTIVE</code> state, you can change the status of a container instance to <code>DRAINING</code> to manually remove an instance from a cluster, for example to perform system updates, update the Docker daemon, or scale down the cluster size.</p> <important> <p>A container instance cannot be changed to <code>DRAINING</code> until it has reached an <code>ACTIVE</code> status. If the instance is in any other status, an error will be received.</p> </important> <p>When you set a container instance to <code>DRAINING</code>, Amazon ECS prevents new tasks from being scheduled for placement on the container instance and replacement service tasks are started on other container instances in the cluster if the resources are available. Service tasks on the container instance that are in the <code>PENDING</code> state are stopped immediately.</p> <p>Service tasks on the container instance that are in the <code>RUNNING</code> state are stopped and replaced according to the service's deployment configuration parameters, <code>minimumHealthyPercent</code> and <code>maximumPercent</code>. You can change the deployment configuration of your service using <a>UpdateService</a>.</p> <ul> <li> <p>If <code>minimumHealthyPercent</code> is below 100%, the scheduler can ignore <code>desiredCount</code> temporarily during task replacement. For example, <code>desiredCount</code> is four tasks, a minimum of 50% allows the scheduler to stop two existing tasks before starting two new tasks. If the minimum is 100%, the service scheduler can't remove existing tasks until the replacement tasks are considered healthy. Tasks for services that do not use a load balancer are considered healthy if they are in the <code>RUNNING</code> state. Tasks for services that use a load balancer are considered healthy if they are in the <code>RUNNING</code> state and the container instance they are hosted on is reported as healthy by the load balancer.</p> </li> <li> <p>The <code>maximumPercent</code> parameter represents an upper limit on the number of running tasks during task replacement, which enables you to define the replacement batch size. For example, if <code>desiredCount</code> is four tasks, a maximum of 200% starts four new tasks before stopping the four tasks to be drained, provided that the cluster resources required to do this are available. If the maximum is 100%, then replacement tasks can't start until the draining tasks have stopped.</p> </li> </ul> <p>Any <code>PENDING</code> or <code>RUNNING</code> tasks that do not belong to a service are not affected. You must wait for them to finish or stop them manually.</p> <p>A container instance has completed draining when it has no more <code>RUNNING</code> tasks. You can verify this using <a>ListTasks</a>.</p> <p>When a container instance has been drained, you can set a container instance to <code>ACTIVE</code> status and once it has reached that status the Amazon ECS scheduler can begin scheduling tasks on the instance again.</p>
+    fn update_container_instances_state(
+        &self,
+        input: UpdateContainerInstancesStateRequest,
+    ) -> RusotoFuture<UpdateContainerInstancesStateResponse, UpdateContainerInstancesStateError>
+    {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateContainerInstancesState");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.UpdateContainerInstancesState",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateContainerInstancesStateResponse, _>(
+                    response,
+                    "UpdateContainerInstancesState",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateContainerInstancesStateError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Modifies the parameters of a service.</p> <p>For services using the rolling update (<code>ECS</code>) deployment controller, the desired count, deployment configuration, network configuration, or task definition used can be updated.</p> <p>For services using the blue/green (<code>CODE<em>DEPLOY</code>) deployment controller, only the desired count, deployment configuration, and health check grace period can be updated using this API. If the network configuration, platform version, or task definition need to be updated, a new AWS CodeDeploy deployment should be created. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/codedeploy/latest/APIReference/API</em>CreateDeployment.html&quot;&gt;CreateDeployment</a> in the <i>AWS CodeDeploy API Reference</i>.</p> <p>For services using an external deployment controller, you can update only the desired count and health check grace period using this API. If the launch type, load balancer, network configuration, platform version, or task definition need to be updated, you should create a new task set. For more information, see <a>CreateTaskSet</a>.</p> <p>You can add to or subtract from the number of instantiations of a task definition in a service by specifying the cluster that the service is running in and a new <code>desiredCount</code> parameter.</p> <p>If you have updated the Docker image of your application, you can create a new task definition with that image and deploy it to your service. The service scheduler uses the minimum healthy percent and maximum percent parameters (in the service&#39;s deployment configuration) to determine the deployment strategy.</p> <note> <p>If your updated Docker image uses the same tag as what is in the existing task definition for your service (for example, <code>my_image:latest</code>), you do not need to create a new revision of your task definition. You can update the service using the <code>forceNewDeployment</code> option. The new tasks launched by the deployment pull the current image/tag combination from your repository when they start.</p> </note> <p>You can also update the deployment configuration of a service. When a deployment is triggered by updating the task definition of a service, the service scheduler uses the deployment configuration parameters, <code>minimumHealthyPercent</code> and <code>maximumPercent</code>, to determine the deployment strategy.</p> <ul> <li> <p>If <code>minimumHealthyPercent</code> is below 100%, the scheduler can ignore <code>desiredCount</code> temporarily during a deployment. For example, if <code>desiredCount</code> is four tasks, a minimum of 50% allows the scheduler to stop two existing tasks before starting two new tasks. Tasks for services that do not use a load balancer are considered healthy if they are in the <code>RUNNING</code> state. Tasks for services that use a load balancer are considered healthy if they are in the <code>RUNNING</code> state and the container instance they are hosted on is reported as healthy by the load balancer.</p> </li> <li> <p>The <code>maximumPercent</code> parameter represents an upper limit on the number of running tasks during a deployment, which enables you to define the deployment batch size. For example, if <code>desiredCount</code> is four tasks, a maximum of 200% starts four new tasks before stopping the four older tasks (provided that the cluster resources required to do this are available).</p> </li> </ul> <p>When <a>UpdateService</a> stops a task during a deployment, the equivalent of <code>docker stop</code> is issued to the containers running in the task. This results in a <code>SIGTERM</code> and a 30-second timeout, after which <code>SIGKILL</code> is sent and the containers are forcibly stopped. If the container handles the <code>SIGTERM</code> gracefully and exits within 30 seconds from receiving it, no <code>SIGKILL</code> is sent.</p> <p>When the service scheduler launches new tasks, it determines task placement in your cluster with the following logic:</p> <ul> <li> <p>Determine which of the container instances in your cluster can support your service&#39;s task definition (for example, they have the required CPU, memory, ports, and container instance attributes).</p> </li> <li> <p>By default, the service scheduler attempts to balance tasks across Availability Zones in this manner (although you can choose a different placement strategy):</p> <ul> <li> <p>Sort the valid container instances by the fewest number of running tasks for this service in the same Availability Zone as the instance. For example, if zone A has one running service task and zones B and C each have zero, valid container instances in either zone B or C are considered optimal for placement.</p> </li> <li> <p>Place the new service task on a valid container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the fewest number of running tasks for this service.</p> </li> </ul> </li> </ul> <p>When the service scheduler stops running tasks, it attempts to maintain balance across the Availability Zones in your cluster using the following logic: </p> <ul> <li> <p>Sort the container instances by the largest number of running tasks for this service in the same Availability Zone as the instance. For example, if zone A has one running service task and zones B and C each have two, container instances in either zone B or C are considered optimal for termination.</p> </li> <li> <p>Stop the task on a container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the largest number of running tasks for this service.</p> </li> </ul></p>
+    fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateService");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.UpdateService",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateServiceResponse, _>(
+                    response,
+                    "UpdateService",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateServiceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies which task set in a service is the primary task set. Any parameters that are updated on the primary task set in a service will transition to the service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn update_service_primary_task_set(
+        &self,
+        input: UpdateServicePrimaryTaskSetRequest,
+    ) -> RusotoFuture<UpdateServicePrimaryTaskSetResponse, UpdateServicePrimaryTaskSetError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateServicePrimaryTaskSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.UpdateServicePrimaryTaskSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateServicePrimaryTaskSetResponse, _>(
+                    response,
+                    "UpdateServicePrimaryTaskSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateServicePrimaryTaskSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Modifies a task set. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    fn update_task_set(
+        &self,
+        input: UpdateTaskSetRequest,
+    ) -> RusotoFuture<UpdateTaskSetResponse, UpdateTaskSetError> {
+        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateTaskSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonEC2ContainerServiceV20141113.UpdateTaskSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateTaskSetResponse, _>(
+                    response,
+                    "UpdateTaskSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateTaskSetError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon ECS API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockEcs {
+    create_cluster: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterRequest,
+                )
+                    -> ::std::result::Result<CreateClusterResponse, RusotoError<CreateClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_service: Option<
+        Box<
+            dyn Fn(
+                    CreateServiceRequest,
+                )
+                    -> ::std::result::Result<CreateServiceResponse, RusotoError<CreateServiceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_task_set: Option<
+        Box<
+            dyn Fn(
+                    CreateTaskSetRequest,
+                )
+                    -> ::std::result::Result<CreateTaskSetResponse, RusotoError<CreateTaskSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_account_setting: Option<
+        Box<
+            dyn Fn(
+                    DeleteAccountSettingRequest,
+                ) -> ::std::result::Result<
+                    DeleteAccountSettingResponse,
+                    RusotoError<DeleteAccountSettingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_attributes: Option<
+        Box<
+            dyn Fn(
+                    DeleteAttributesRequest,
+                ) -> ::std::result::Result<
+                    DeleteAttributesResponse,
+                    RusotoError<DeleteAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_cluster: Option<
+        Box<
+            dyn Fn(
+                    DeleteClusterRequest,
+                )
+                    -> ::std::result::Result<DeleteClusterResponse, RusotoError<DeleteClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_service: Option<
+        Box<
+            dyn Fn(
+                    DeleteServiceRequest,
+                )
+                    -> ::std::result::Result<DeleteServiceResponse, RusotoError<DeleteServiceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_task_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteTaskSetRequest,
+                )
+                    -> ::std::result::Result<DeleteTaskSetResponse, RusotoError<DeleteTaskSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_container_instance: Option<
+        Box<
+            dyn Fn(
+                    DeregisterContainerInstanceRequest,
+                ) -> ::std::result::Result<
+                    DeregisterContainerInstanceResponse,
+                    RusotoError<DeregisterContainerInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deregister_task_definition: Option<
+        Box<
+            dyn Fn(
+                    DeregisterTaskDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DeregisterTaskDefinitionResponse,
+                    RusotoError<DeregisterTaskDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_clusters: Option<
+        Box<
+            dyn Fn(
+                    DescribeClustersRequest,
+                ) -> ::std::result::Result<
+                    DescribeClustersResponse,
+                    RusotoError<DescribeClustersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_container_instances: Option<
+        Box<
+            dyn Fn(
+                    DescribeContainerInstancesRequest,
+                ) -> ::std::result::Result<
+                    DescribeContainerInstancesResponse,
+                    RusotoError<DescribeContainerInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_services: Option<
+        Box<
+            dyn Fn(
+                    DescribeServicesRequest,
+                ) -> ::std::result::Result<
+                    DescribeServicesResponse,
+                    RusotoError<DescribeServicesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_task_definition: Option<
+        Box<
+            dyn Fn(
+                    DescribeTaskDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DescribeTaskDefinitionResponse,
+                    RusotoError<DescribeTaskDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_task_sets: Option<
+        Box<
+            dyn Fn(
+                    DescribeTaskSetsRequest,
+                ) -> ::std::result::Result<
+                    DescribeTaskSetsResponse,
+                    RusotoError<DescribeTaskSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tasks: Option<
+        Box<
+            dyn Fn(
+                    DescribeTasksRequest,
+                )
+                    -> ::std::result::Result<DescribeTasksResponse, RusotoError<DescribeTasksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    discover_poll_endpoint: Option<
+        Box<
+            dyn Fn(
+                    DiscoverPollEndpointRequest,
+                ) -> ::std::result::Result<
+                    DiscoverPollEndpointResponse,
+                    RusotoError<DiscoverPollEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_account_settings: Option<
+        Box<
+            dyn Fn(
+                    ListAccountSettingsRequest,
+                ) -> ::std::result::Result<
+                    ListAccountSettingsResponse,
+                    RusotoError<ListAccountSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_attributes: Option<
+        Box<
+            dyn Fn(
+                    ListAttributesRequest,
+                ) -> ::std::result::Result<
+                    ListAttributesResponse,
+                    RusotoError<ListAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_clusters: Option<
+        Box<
+            dyn Fn(
+                    ListClustersRequest,
+                )
+                    -> ::std::result::Result<ListClustersResponse, RusotoError<ListClustersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_container_instances: Option<
+        Box<
+            dyn Fn(
+                    ListContainerInstancesRequest,
+                ) -> ::std::result::Result<
+                    ListContainerInstancesResponse,
+                    RusotoError<ListContainerInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_services: Option<
+        Box<
+            dyn Fn(
+                    ListServicesRequest,
+                )
+                    -> ::std::result::Result<ListServicesResponse, RusotoError<ListServicesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_task_definition_families: Option<
+        Box<
+            dyn Fn(
+                    ListTaskDefinitionFamiliesRequest,
+                ) -> ::std::result::Result<
+                    ListTaskDefinitionFamiliesResponse,
+                    RusotoError<ListTaskDefinitionFamiliesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_task_definitions: Option<
+        Box<
+            dyn Fn(
+                    ListTaskDefinitionsRequest,
+                ) -> ::std::result::Result<
+                    ListTaskDefinitionsResponse,
+                    RusotoError<ListTaskDefinitionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tasks: Option<
+        Box<
+            dyn Fn(
+                    ListTasksRequest,
+                )
+                    -> ::std::result::Result<ListTasksResponse, RusotoError<ListTasksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_account_setting: Option<
+        Box<
+            dyn Fn(
+                    PutAccountSettingRequest,
+                ) -> ::std::result::Result<
+                    PutAccountSettingResponse,
+                    RusotoError<PutAccountSettingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_account_setting_default: Option<
+        Box<
+            dyn Fn(
+                    PutAccountSettingDefaultRequest,
+                ) -> ::std::result::Result<
+                    PutAccountSettingDefaultResponse,
+                    RusotoError<PutAccountSettingDefaultError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_attributes: Option<
+        Box<
+            dyn Fn(
+                    PutAttributesRequest,
+                )
+                    -> ::std::result::Result<PutAttributesResponse, RusotoError<PutAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_container_instance: Option<
+        Box<
+            dyn Fn(
+                    RegisterContainerInstanceRequest,
+                ) -> ::std::result::Result<
+                    RegisterContainerInstanceResponse,
+                    RusotoError<RegisterContainerInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_task_definition: Option<
+        Box<
+            dyn Fn(
+                    RegisterTaskDefinitionRequest,
+                ) -> ::std::result::Result<
+                    RegisterTaskDefinitionResponse,
+                    RusotoError<RegisterTaskDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    run_task: Option<
+        Box<
+            dyn Fn(
+                    RunTaskRequest,
+                )
+                    -> ::std::result::Result<RunTaskResponse, RusotoError<RunTaskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_task: Option<
+        Box<
+            dyn Fn(
+                    StartTaskRequest,
+                )
+                    -> ::std::result::Result<StartTaskResponse, RusotoError<StartTaskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_task: Option<
+        Box<
+            dyn Fn(
+                    StopTaskRequest,
+                )
+                    -> ::std::result::Result<StopTaskResponse, RusotoError<StopTaskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    submit_attachment_state_changes: Option<
+        Box<
+            dyn Fn(
+                    SubmitAttachmentStateChangesRequest,
+                ) -> ::std::result::Result<
+                    SubmitAttachmentStateChangesResponse,
+                    RusotoError<SubmitAttachmentStateChangesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    submit_container_state_change: Option<
+        Box<
+            dyn Fn(
+                    SubmitContainerStateChangeRequest,
+                ) -> ::std::result::Result<
+                    SubmitContainerStateChangeResponse,
+                    RusotoError<SubmitContainerStateChangeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    submit_task_state_change: Option<
+        Box<
+            dyn Fn(
+                    SubmitTaskStateChangeRequest,
+                ) -> ::std::result::Result<
+                    SubmitTaskStateChangeResponse,
+                    RusotoError<SubmitTaskStateChangeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_cluster_settings: Option<
+        Box<
+            dyn Fn(
+                    UpdateClusterSettingsRequest,
+                ) -> ::std::result::Result<
+                    UpdateClusterSettingsResponse,
+                    RusotoError<UpdateClusterSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_container_agent: Option<
+        Box<
+            dyn Fn(
+                    UpdateContainerAgentRequest,
+                ) -> ::std::result::Result<
+                    UpdateContainerAgentResponse,
+                    RusotoError<UpdateContainerAgentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_container_instances_state: Option<
+        Box<
+            dyn Fn(
+                    UpdateContainerInstancesStateRequest,
+                ) -> ::std::result::Result<
+                    UpdateContainerInstancesStateResponse,
+                    RusotoError<UpdateContainerInstancesStateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_service: Option<
+        Box<
+            dyn Fn(
+                    UpdateServiceRequest,
+                )
+                    -> ::std::result::Result<UpdateServiceResponse, RusotoError<UpdateServiceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_service_primary_task_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateServicePrimaryTaskSetRequest,
+                ) -> ::std::result::Result<
+                    UpdateServicePrimaryTaskSetResponse,
+                    RusotoError<UpdateServicePrimaryTaskSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_task_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateTaskSetRequest,
+                )
+                    -> ::std::result::Result<UpdateTaskSetResponse, RusotoError<UpdateTaskSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockEcs {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockEcs {
+        Default::default()
+    }
+
+    /// Answers `create_cluster` with what `handler` returns for its input.
+    pub fn with_create_cluster<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                CreateClusterRequest,
+            )
+                -> ::std::result::Result<CreateClusterResponse, RusotoError<CreateClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_service` with what `handler` returns for its input.
+    pub fn with_create_service<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                CreateServiceRequest,
+            )
+                -> ::std::result::Result<CreateServiceResponse, RusotoError<CreateServiceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_service = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_task_set` with what `handler` returns for its input.
+    pub fn with_create_task_set<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                CreateTaskSetRequest,
+            )
+                -> ::std::result::Result<CreateTaskSetResponse, RusotoError<CreateTaskSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_task_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_account_setting` with what `handler` returns for its input.
+    pub fn with_delete_account_setting<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DeleteAccountSettingRequest,
+            ) -> ::std::result::Result<
+                DeleteAccountSettingResponse,
+                RusotoError<DeleteAccountSettingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_account_setting = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_attributes` with what `handler` returns for its input.
+    pub fn with_delete_attributes<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DeleteAttributesRequest,
+            ) -> ::std::result::Result<
+                DeleteAttributesResponse,
+                RusotoError<DeleteAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cluster` with what `handler` returns for its input.
+    pub fn with_delete_cluster<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DeleteClusterRequest,
+            )
+                -> ::std::result::Result<DeleteClusterResponse, RusotoError<DeleteClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_service` with what `handler` returns for its input.
+    pub fn with_delete_service<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DeleteServiceRequest,
+            )
+                -> ::std::result::Result<DeleteServiceResponse, RusotoError<DeleteServiceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_service = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_task_set` with what `handler` returns for its input.
+    pub fn with_delete_task_set<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DeleteTaskSetRequest,
+            )
+                -> ::std::result::Result<DeleteTaskSetResponse, RusotoError<DeleteTaskSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_task_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_container_instance` with what `handler` returns for its input.
+    pub fn with_deregister_container_instance<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DeregisterContainerInstanceRequest,
+            ) -> ::std::result::Result<
+                DeregisterContainerInstanceResponse,
+                RusotoError<DeregisterContainerInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_container_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_task_definition` with what `handler` returns for its input.
+    pub fn with_deregister_task_definition<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DeregisterTaskDefinitionRequest,
+            ) -> ::std::result::Result<
+                DeregisterTaskDefinitionResponse,
+                RusotoError<DeregisterTaskDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_task_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_clusters` with what `handler` returns for its input.
+    pub fn with_describe_clusters<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DescribeClustersRequest,
+            ) -> ::std::result::Result<
+                DescribeClustersResponse,
+                RusotoError<DescribeClustersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_container_instances` with what `handler` returns for its input.
+    pub fn with_describe_container_instances<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DescribeContainerInstancesRequest,
+            ) -> ::std::result::Result<
+                DescribeContainerInstancesResponse,
+                RusotoError<DescribeContainerInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_container_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_services` with what `handler` returns for its input.
+    pub fn with_describe_services<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DescribeServicesRequest,
+            ) -> ::std::result::Result<
+                DescribeServicesResponse,
+                RusotoError<DescribeServicesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_services = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_task_definition` with what `handler` returns for its input.
+    pub fn with_describe_task_definition<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DescribeTaskDefinitionRequest,
+            ) -> ::std::result::Result<
+                DescribeTaskDefinitionResponse,
+                RusotoError<DescribeTaskDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_task_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_task_sets` with what `handler` returns for its input.
+    pub fn with_describe_task_sets<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DescribeTaskSetsRequest,
+            ) -> ::std::result::Result<
+                DescribeTaskSetsResponse,
+                RusotoError<DescribeTaskSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_task_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tasks` with what `handler` returns for its input.
+    pub fn with_describe_tasks<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DescribeTasksRequest,
+            )
+                -> ::std::result::Result<DescribeTasksResponse, RusotoError<DescribeTasksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `discover_poll_endpoint` with what `handler` returns for its input.
+    pub fn with_discover_poll_endpoint<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                DiscoverPollEndpointRequest,
+            ) -> ::std::result::Result<
+                DiscoverPollEndpointResponse,
+                RusotoError<DiscoverPollEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.discover_poll_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_account_settings` with what `handler` returns for its input.
+    pub fn with_list_account_settings<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                ListAccountSettingsRequest,
+            ) -> ::std::result::Result<
+                ListAccountSettingsResponse,
+                RusotoError<ListAccountSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_account_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_attributes` with what `handler` returns for its input.
+    pub fn with_list_attributes<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                ListAttributesRequest,
+            )
+                -> ::std::result::Result<ListAttributesResponse, RusotoError<ListAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_clusters` with what `handler` returns for its input.
+    pub fn with_list_clusters<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                ListClustersRequest,
+            )
+                -> ::std::result::Result<ListClustersResponse, RusotoError<ListClustersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_container_instances` with what `handler` returns for its input.
+    pub fn with_list_container_instances<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                ListContainerInstancesRequest,
+            ) -> ::std::result::Result<
+                ListContainerInstancesResponse,
+                RusotoError<ListContainerInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_container_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_services` with what `handler` returns for its input.
+    pub fn with_list_services<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                ListServicesRequest,
+            )
+                -> ::std::result::Result<ListServicesResponse, RusotoError<ListServicesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_services = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_task_definition_families` with what `handler` returns for its input.
+    pub fn with_list_task_definition_families<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                ListTaskDefinitionFamiliesRequest,
+            ) -> ::std::result::Result<
+                ListTaskDefinitionFamiliesResponse,
+                RusotoError<ListTaskDefinitionFamiliesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_task_definition_families = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_task_definitions` with what `handler` returns for its input.
+    pub fn with_list_task_definitions<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                ListTaskDefinitionsRequest,
+            ) -> ::std::result::Result<
+                ListTaskDefinitionsResponse,
+                RusotoError<ListTaskDefinitionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_task_definitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tasks` with what `handler` returns for its input.
+    pub fn with_list_tasks<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                ListTasksRequest,
+            ) -> ::std::result::Result<ListTasksResponse, RusotoError<ListTasksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_account_setting` with what `handler` returns for its input.
+    pub fn with_put_account_setting<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                PutAccountSettingRequest,
+            ) -> ::std::result::Result<
+                PutAccountSettingResponse,
+                RusotoError<PutAccountSettingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_account_setting = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_account_setting_default` with what `handler` returns for its input.
+    pub fn with_put_account_setting_default<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                PutAccountSettingDefaultRequest,
+            ) -> ::std::result::Result<
+                PutAccountSettingDefaultResponse,
+                RusotoError<PutAccountSettingDefaultError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_account_setting_default = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_attributes` with what `handler` returns for its input.
+    pub fn with_put_attributes<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                PutAttributesRequest,
+            )
+                -> ::std::result::Result<PutAttributesResponse, RusotoError<PutAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_container_instance` with what `handler` returns for its input.
+    pub fn with_register_container_instance<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                RegisterContainerInstanceRequest,
+            ) -> ::std::result::Result<
+                RegisterContainerInstanceResponse,
+                RusotoError<RegisterContainerInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_container_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_task_definition` with what `handler` returns for its input.
+    pub fn with_register_task_definition<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                RegisterTaskDefinitionRequest,
+            ) -> ::std::result::Result<
+                RegisterTaskDefinitionResponse,
+                RusotoError<RegisterTaskDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_task_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `run_task` with what `handler` returns for its input.
+    pub fn with_run_task<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(RunTaskRequest) -> ::std::result::Result<RunTaskResponse, RusotoError<RunTaskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.run_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_task` with what `handler` returns for its input.
+    pub fn with_start_task<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                StartTaskRequest,
+            ) -> ::std::result::Result<StartTaskResponse, RusotoError<StartTaskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_task` with what `handler` returns for its input.
+    pub fn with_stop_task<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                StopTaskRequest,
+            ) -> ::std::result::Result<StopTaskResponse, RusotoError<StopTaskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `submit_attachment_state_changes` with what `handler` returns for its input.
+    pub fn with_submit_attachment_state_changes<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                SubmitAttachmentStateChangesRequest,
+            ) -> ::std::result::Result<
+                SubmitAttachmentStateChangesResponse,
+                RusotoError<SubmitAttachmentStateChangesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.submit_attachment_state_changes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `submit_container_state_change` with what `handler` returns for its input.
+    pub fn with_submit_container_state_change<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                SubmitContainerStateChangeRequest,
+            ) -> ::std::result::Result<
+                SubmitContainerStateChangeResponse,
+                RusotoError<SubmitContainerStateChangeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.submit_container_state_change = Some(Box::new(handler));
+        self
+    }
+    /// Answers `submit_task_state_change` with what `handler` returns for its input.
+    pub fn with_submit_task_state_change<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                SubmitTaskStateChangeRequest,
+            ) -> ::std::result::Result<
+                SubmitTaskStateChangeResponse,
+                RusotoError<SubmitTaskStateChangeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.submit_task_state_change = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_cluster_settings` with what `handler` returns for its input.
+    pub fn with_update_cluster_settings<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                UpdateClusterSettingsRequest,
+            ) -> ::std::result::Result<
+                UpdateClusterSettingsResponse,
+                RusotoError<UpdateClusterSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_cluster_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_container_agent` with what `handler` returns for its input.
+    pub fn with_update_container_agent<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                UpdateContainerAgentRequest,
+            ) -> ::std::result::Result<
+                UpdateContainerAgentResponse,
+                RusotoError<UpdateContainerAgentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_container_agent = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_container_instances_state` with what `handler` returns for its input.
+    pub fn with_update_container_instances_state<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                UpdateContainerInstancesStateRequest,
+            ) -> ::std::result::Result<
+                UpdateContainerInstancesStateResponse,
+                RusotoError<UpdateContainerInstancesStateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_container_instances_state = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_service` with what `handler` returns for its input.
+    pub fn with_update_service<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                UpdateServiceRequest,
+            )
+                -> ::std::result::Result<UpdateServiceResponse, RusotoError<UpdateServiceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_service = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_service_primary_task_set` with what `handler` returns for its input.
+    pub fn with_update_service_primary_task_set<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                UpdateServicePrimaryTaskSetRequest,
+            ) -> ::std::result::Result<
+                UpdateServicePrimaryTaskSetResponse,
+                RusotoError<UpdateServicePrimaryTaskSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_service_primary_task_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_task_set` with what `handler` returns for its input.
+    pub fn with_update_task_set<F>(mut self, handler: F) -> MockEcs
+    where
+        F: Fn(
+                UpdateTaskSetRequest,
+            )
+                -> ::std::result::Result<UpdateTaskSetResponse, RusotoError<UpdateTaskSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_task_set = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Ecs for MockEcs {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        match self.create_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for create_cluster"),
+        }
+    }
+    fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        match self.create_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for create_service"),
+        }
+    }
+    fn create_task_set(
+        &self,
+        input: CreateTaskSetRequest,
+    ) -> RusotoFuture<CreateTaskSetResponse, CreateTaskSetError> {
+        match self.create_task_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for create_task_set"),
+        }
+    }
+    fn delete_account_setting(
+        &self,
+        input: DeleteAccountSettingRequest,
+    ) -> RusotoFuture<DeleteAccountSettingResponse, DeleteAccountSettingError> {
+        match self.delete_account_setting {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for delete_account_setting"),
+        }
+    }
+    fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> RusotoFuture<DeleteAttributesResponse, DeleteAttributesError> {
+        match self.delete_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for delete_attributes"),
+        }
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        match self.delete_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for delete_cluster"),
+        }
+    }
+    fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        match self.delete_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for delete_service"),
+        }
+    }
+    fn delete_task_set(
+        &self,
+        input: DeleteTaskSetRequest,
+    ) -> RusotoFuture<DeleteTaskSetResponse, DeleteTaskSetError> {
+        match self.delete_task_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for delete_task_set"),
+        }
+    }
+    fn deregister_container_instance(
+        &self,
+        input: DeregisterContainerInstanceRequest,
+    ) -> RusotoFuture<DeregisterContainerInstanceResponse, DeregisterContainerInstanceError> {
+        match self.deregister_container_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for deregister_container_instance"),
+        }
+    }
+    fn deregister_task_definition(
+        &self,
+        input: DeregisterTaskDefinitionRequest,
+    ) -> RusotoFuture<DeregisterTaskDefinitionResponse, DeregisterTaskDefinitionError> {
+        match self.deregister_task_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for deregister_task_definition"),
+        }
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        match self.describe_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for describe_clusters"),
+        }
+    }
+    fn describe_container_instances(
+        &self,
+        input: DescribeContainerInstancesRequest,
+    ) -> RusotoFuture<DescribeContainerInstancesResponse, DescribeContainerInstancesError> {
+        match self.describe_container_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for describe_container_instances"),
+        }
+    }
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        match self.describe_services {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for describe_services"),
+        }
+    }
+    fn describe_task_definition(
+        &self,
+        input: DescribeTaskDefinitionRequest,
+    ) -> RusotoFuture<DescribeTaskDefinitionResponse, DescribeTaskDefinitionError> {
+        match self.describe_task_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for describe_task_definition"),
+        }
+    }
+    fn describe_task_sets(
+        &self,
+        input: DescribeTaskSetsRequest,
+    ) -> RusotoFuture<DescribeTaskSetsResponse, DescribeTaskSetsError> {
+        match self.describe_task_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for describe_task_sets"),
+        }
+    }
+    fn describe_tasks(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> RusotoFuture<DescribeTasksResponse, DescribeTasksError> {
+        match self.describe_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for describe_tasks"),
+        }
+    }
+    fn discover_poll_endpoint(
+        &self,
+        input: DiscoverPollEndpointRequest,
+    ) -> RusotoFuture<DiscoverPollEndpointResponse, DiscoverPollEndpointError> {
+        match self.discover_poll_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for discover_poll_endpoint"),
+        }
+    }
+    fn list_account_settings(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> RusotoFuture<ListAccountSettingsResponse, ListAccountSettingsError> {
+        match self.list_account_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for list_account_settings"),
+        }
+    }
+    fn list_attributes(
+        &self,
+        input: ListAttributesRequest,
+    ) -> RusotoFuture<ListAttributesResponse, ListAttributesError> {
+        match self.list_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for list_attributes"),
+        }
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        match self.list_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for list_clusters"),
+        }
+    }
+    fn list_container_instances(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> RusotoFuture<ListContainerInstancesResponse, ListContainerInstancesError> {
+        match self.list_container_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for list_container_instances"),
+        }
+    }
+    fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        match self.list_services {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for list_services"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_task_definition_families(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> RusotoFuture<ListTaskDefinitionFamiliesResponse, ListTaskDefinitionFamiliesError> {
+        match self.list_task_definition_families {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for list_task_definition_families"),
+        }
+    }
+    fn list_task_definitions(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> RusotoFuture<ListTaskDefinitionsResponse, ListTaskDefinitionsError> {
+        match self.list_task_definitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for list_task_definitions"),
+        }
+    }
+    fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> RusotoFuture<ListTasksResponse, ListTasksError> {
+        match self.list_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for list_tasks"),
+        }
+    }
+    fn put_account_setting(
+        &self,
+        input: PutAccountSettingRequest,
+    ) -> RusotoFuture<PutAccountSettingResponse, PutAccountSettingError> {
+        match self.put_account_setting {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for put_account_setting"),
+        }
+    }
+    fn put_account_setting_default(
+        &self,
+        input: PutAccountSettingDefaultRequest,
+    ) -> RusotoFuture<PutAccountSettingDefaultResponse, PutAccountSettingDefaultError> {
+        match self.put_account_setting_default {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for put_account_setting_default"),
+        }
+    }
+    fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> RusotoFuture<PutAttributesResponse, PutAttributesError> {
+        match self.put_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for put_attributes"),
+        }
+    }
+    fn register_container_instance(
+        &self,
+        input: RegisterContainerInstanceRequest,
+    ) -> RusotoFuture<RegisterContainerInstanceResponse, RegisterContainerInstanceError> {
+        match self.register_container_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for register_container_instance"),
+        }
+    }
+    fn register_task_definition(
+        &self,
+        input: RegisterTaskDefinitionRequest,
+    ) -> RusotoFuture<RegisterTaskDefinitionResponse, RegisterTaskDefinitionError> {
+        match self.register_task_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for register_task_definition"),
+        }
+    }
+    fn run_task(&self, input: RunTaskRequest) -> RusotoFuture<RunTaskResponse, RunTaskError> {
+        match self.run_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for run_task"),
+        }
+    }
+    fn start_task(
+        &self,
+        input: StartTaskRequest,
+    ) -> RusotoFuture<StartTaskResponse, StartTaskError> {
+        match self.start_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for start_task"),
+        }
+    }
+    fn stop_task(&self, input: StopTaskRequest) -> RusotoFuture<StopTaskResponse, StopTaskError> {
+        match self.stop_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for stop_task"),
+        }
+    }
+    fn submit_attachment_state_changes(
+        &self,
+        input: SubmitAttachmentStateChangesRequest,
+    ) -> RusotoFuture<SubmitAttachmentStateChangesResponse, SubmitAttachmentStateChangesError> {
+        match self.submit_attachment_state_changes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for submit_attachment_state_changes"),
+        }
     }
-
-    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that a container changed states.</p></p>
     fn submit_container_state_change(
         &self,
         input: SubmitContainerStateChangeRequest,
     ) -> RusotoFuture<SubmitContainerStateChangeResponse, SubmitContainerStateChangeError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.SubmitContainerStateChange",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SubmitContainerStateChangeResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SubmitContainerStateChangeError::from_response(response))
-                }))
-            }
-        })
+        match self.submit_container_state_change {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for submit_container_state_change"),
+        }
     }
-
-    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that a task changed states.</p></p>
     fn submit_task_state_change(
         &self,
         input: SubmitTaskStateChangeRequest,
     ) -> RusotoFuture<SubmitTaskStateChangeResponse, SubmitTaskStateChangeError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.SubmitTaskStateChange",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SubmitTaskStateChangeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SubmitTaskStateChangeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.submit_task_state_change {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for submit_task_state_change"),
+        }
     }
-
-    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.TagResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Deletes specified tags from a resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.UntagResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Modifies the settings to use for a cluster.</p>
     fn update_cluster_settings(
         &self,
         input: UpdateClusterSettingsRequest,
     ) -> RusotoFuture<UpdateClusterSettingsResponse, UpdateClusterSettingsError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.UpdateClusterSettings",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateClusterSettingsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateClusterSettingsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_cluster_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for update_cluster_settings"),
+        }
     }
-
-    /// <p>Updates the Amazon ECS container agent on a specified container instance. Updating the Amazon ECS container agent does not interrupt running tasks or services on the container instance. The process for updating the agent differs depending on whether your container instance was launched with the Amazon ECS-optimized AMI or another operating system.</p> <p> <code>UpdateContainerAgent</code> requires the Amazon ECS-optimized AMI or Amazon Linux with the <code>ecs-init</code> service installed and running. For help updating the Amazon ECS container agent on other operating systems, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-update.html#manually_update_agent">Manually Updating the Amazon ECS Container Agent</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn update_container_agent(
         &self,
         input: UpdateContainerAgentRequest,
     ) -> RusotoFuture<UpdateContainerAgentResponse, UpdateContainerAgentError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.UpdateContainerAgent",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateContainerAgentResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateContainerAgentError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_container_agent {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for update_container_agent"),
+        }
     }
-
-    /// <p>Modifies the status of an Amazon ECS container instance.</p> <p>Once a container instance has reached an <code>ACTIVE</code> state, you can change the status of a container instance to <code>DRAINING</code> to manually remove an instance from a cluster, for example to perform system updates, update the Docker daemon, or scale down the cluster size.</p> <important> <p>A container instance cannot be changed to <code>DRAINING</code> until it has reached an <code>ACTIVE</code> status. If the instance is in any other status, an error will be received.</p> </important> <p>When you set a container instance to <code>DRAINING</code>, Amazon ECS prevents new tasks from being scheduled for placement on the container instance and replacement service tasks are started on other container instances in the cluster if the resources are available. Service tasks on the container instance that are in the <code>PENDING</code> state are stopped immediately.</p> <p>Service tasks on the container instance that are in the <code>RUNNING</code> state are stopped and replaced according to the service's deployment configuration parameters, <code>minimumHealthyPercent</code> and <code>maximumPercent</code>. You can change the deployment configuration of your service using <a>UpdateService</a>.</p> <ul> <li> <p>If <code>minimumHealthyPercent</code> is below 100%, the scheduler can ignore <code>desiredCount</code> temporarily during task replacement. For example, <code>desiredCount</code> is four tasks, a minimum of 50% allows the scheduler to stop two existing tasks before starting two new tasks. If the minimum is 100%, the service scheduler can't remove existing tasks until the replacement tasks are considered healthy. Tasks for services that do not use a load balancer are considered healthy if they are in the <code>RUNNING</code> state. Tasks for services that use a load balancer are considered healthy if they are in the <code>RUNNING</code> state and the container instance they are hosted on is reported as healthy by the load balancer.</p> </li> <li> <p>The <code>maximumPercent</code> parameter represents an upper limit on the number of running tasks during task replacement, which enables you to define the replacement batch size. For example, if <code>desiredCount</code> is four tasks, a maximum of 200% starts four new tasks before stopping the four tasks to be drained, provided that the cluster resources required to do this are available. If the maximum is 100%, then replacement tasks can't start until the draining tasks have stopped.</p> </li> </ul> <p>Any <code>PENDING</code> or <code>RUNNING</code> tasks that do not belong to a service are not affected. You must wait for them to finish or stop them manually.</p> <p>A container instance has completed draining when it has no more <code>RUNNING</code> tasks. You can verify this using <a>ListTasks</a>.</p> <p>When a container instance has been drained, you can set a container instance to <code>ACTIVE</code> status and once it has reached that status the Amazon ECS scheduler can begin scheduling tasks on the instance again.</p>
     fn update_container_instances_state(
         &self,
         input: UpdateContainerInstancesStateRequest,
     ) -> RusotoFuture<UpdateContainerInstancesStateResponse, UpdateContainerInstancesStateError>
     {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.UpdateContainerInstancesState",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateContainerInstancesStateResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateContainerInstancesStateError::from_response(response))
-                }))
-            }
-        })
+        match self.update_container_instances_state {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for update_container_instances_state"),
+        }
     }
-
-    /// <p><p>Modifies the parameters of a service.</p> <p>For services using the rolling update (<code>ECS</code>) deployment controller, the desired count, deployment configuration, network configuration, or task definition used can be updated.</p> <p>For services using the blue/green (<code>CODE<em>DEPLOY</code>) deployment controller, only the desired count, deployment configuration, and health check grace period can be updated using this API. If the network configuration, platform version, or task definition need to be updated, a new AWS CodeDeploy deployment should be created. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/codedeploy/latest/APIReference/API</em>CreateDeployment.html&quot;&gt;CreateDeployment</a> in the <i>AWS CodeDeploy API Reference</i>.</p> <p>For services using an external deployment controller, you can update only the desired count and health check grace period using this API. If the launch type, load balancer, network configuration, platform version, or task definition need to be updated, you should create a new task set. For more information, see <a>CreateTaskSet</a>.</p> <p>You can add to or subtract from the number of instantiations of a task definition in a service by specifying the cluster that the service is running in and a new <code>desiredCount</code> parameter.</p> <p>If you have updated the Docker image of your application, you can create a new task definition with that image and deploy it to your service. The service scheduler uses the minimum healthy percent and maximum percent parameters (in the service&#39;s deployment configuration) to determine the deployment strategy.</p> <note> <p>If your updated Docker image uses the same tag as what is in the existing task definition for your service (for example, <code>my_image:latest</code>), you do not need to create a new revision of your task definition. You can update the service using the <code>forceNewDeployment</code> option. The new tasks launched by the deployment pull the current image/tag combination from your repository when they start.</p> </note> <p>You can also update the deployment configuration of a service. When a deployment is triggered by updating the task definition of a service, the service scheduler uses the deployment configuration parameters, <code>minimumHealthyPercent</code> and <code>maximumPercent</code>, to determine the deployment strategy.</p> <ul> <li> <p>If <code>minimumHealthyPercent</code> is below 100%, the scheduler can ignore <code>desiredCount</code> temporarily during a deployment. For example, if <code>desiredCount</code> is four tasks, a minimum of 50% allows the scheduler to stop two existing tasks before starting two new tasks. Tasks for services that do not use a load balancer are considered healthy if they are in the <code>RUNNING</code> state. Tasks for services that use a load balancer are considered healthy if they are in the <code>RUNNING</code> state and the container instance they are hosted on is reported as healthy by the load balancer.</p> </li> <li> <p>The <code>maximumPercent</code> parameter represents an upper limit on the number of running tasks during a deployment, which enables you to define the deployment batch size. For example, if <code>desiredCount</code> is four tasks, a maximum of 200% starts four new tasks before stopping the four older tasks (provided that the cluster resources required to do this are available).</p> </li> </ul> <p>When <a>UpdateService</a> stops a task during a deployment, the equivalent of <code>docker stop</code> is issued to the containers running in the task. This results in a <code>SIGTERM</code> and a 30-second timeout, after which <code>SIGKILL</code> is sent and the containers are forcibly stopped. If the container handles the <code>SIGTERM</code> gracefully and exits within 30 seconds from receiving it, no <code>SIGKILL</code> is sent.</p> <p>When the service scheduler launches new tasks, it determines task placement in your cluster with the following logic:</p> <ul> <li> <p>Determine which of the container instances in your cluster can support your service&#39;s task definition (for example, they have the required CPU, memory, ports, and container instance attributes).</p> </li> <li> <p>By default, the service scheduler attempts to balance tasks across Availability Zones in this manner (although you can choose a different placement strategy):</p> <ul> <li> <p>Sort the valid container instances by the fewest number of running tasks for this service in the same Availability Zone as the instance. For example, if zone A has one running service task and zones B and C each have zero, valid container instances in either zone B or C are considered optimal for placement.</p> </li> <li> <p>Place the new service task on a valid container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the fewest number of running tasks for this service.</p> </li> </ul> </li> </ul> <p>When the service scheduler stops running tasks, it attempts to maintain balance across the Availability Zones in your cluster using the following logic: </p> <ul> <li> <p>Sort the container instances by the largest number of running tasks for this service in the same Availability Zone as the instance. For example, if zone A has one running service task and zones B and C each have two, container instances in either zone B or C are considered optimal for termination.</p> </li> <li> <p>Stop the task on a container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the largest number of running tasks for this service.</p> </li> </ul></p>
     fn update_service(
         &self,
         input: UpdateServiceRequest,
     ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.UpdateService",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateServiceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateServiceError::from_response(response))),
-                )
-            }
-        })
+        match self.update_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for update_service"),
+        }
     }
-
-    /// <p>Modifies which task set in a service is the primary task set. Any parameters that are updated on the primary task set in a service will transition to the service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn update_service_primary_task_set(
         &self,
         input: UpdateServicePrimaryTaskSetRequest,
     ) -> RusotoFuture<UpdateServicePrimaryTaskSetResponse, UpdateServicePrimaryTaskSetError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.UpdateServicePrimaryTaskSet",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateServicePrimaryTaskSetResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateServicePrimaryTaskSetError::from_response(response))
-                }))
-            }
-        })
+        match self.update_service_primary_task_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for update_service_primary_task_set"),
+        }
     }
-
-    /// <p>Modifies a task set. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     fn update_task_set(
         &self,
         input: UpdateTaskSetRequest,
     ) -> RusotoFuture<UpdateTaskSetResponse, UpdateTaskSetError> {
-        let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonEC2ContainerServiceV20141113.UpdateTaskSet",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTaskSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTaskSetError::from_response(response))),
-                )
-            }
-        })
+        match self.update_task_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEcs has no handler for update_task_set"),
+        }
     }
 }
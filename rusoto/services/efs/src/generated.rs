@@ -23,8 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFileSystemRequest {
     /// <p>A string of up to 64 ASCII characters. Amazon EFS uses this to ensure idempotent creation.</p>
     #[serde(rename = "CreationToken")]
@@ -55,8 +57,48 @@ pub struct CreateFileSystemRequest {
     pub throughput_mode: Option<String>,
 }
 
+impl Validate for CreateFileSystemRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "creation_token"),
+            self.creation_token.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "creation_token"),
+            &self.creation_token,
+            ".+",
+        );
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key_id"), &value, "^([0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}|mrk-[0-9a-f]{32}|alias/[a-zA-Z0-9/_-]+|(arn:aws[-a-z]*:kms:[a-z0-9-]+:\\d{12}:((key/[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12})|(key/mrk-[0-9a-f]{32})|(alias/[a-zA-Z0-9/_-]+))))$");
+        }
+        if let Some(ref value) = self.provisioned_throughput_in_mibps {
+            errors.check_range(
+                &field_path(path, "provisioned_throughput_in_mibps"),
+                *value,
+                Some(1.0),
+                None,
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMountTargetRequest {
     /// <p>The ID of the file system for which to create the mount target.</p>
     #[serde(rename = "FileSystemId")]
@@ -74,8 +116,58 @@ pub struct CreateMountTargetRequest {
     pub subnet_id: String,
 }
 
+impl Validate for CreateMountTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "file_system_id"), &self.file_system_id, "^(arn:aws[-a-z]*:elasticfilesystem:[0-9a-z-:]+:file-system/fs-[0-9a-f]{8,40}|fs-[0-9a-f]{8,40})$");
+        if let Some(ref value) = self.ip_address {
+            errors.check_length(
+                &field_path(path, "ip_address"),
+                value.chars().count(),
+                Some(7),
+                Some(15),
+            );
+            errors.check_pattern(
+                &field_path(path, "ip_address"),
+                &value,
+                "^[0-9]{1,3}\\.[0-9]{1,3}\\.[0-9]{1,3}\\.[0-9]{1,3}$",
+            );
+        }
+        if let Some(ref value) = self.security_groups {
+            errors.check_length(
+                &field_path(path, "security_groups"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "security_groups"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(11), Some(43));
+                errors.check_pattern(&item_path, &item, "^sg-[0-9a-f]{8,40}");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "subnet_id"),
+            self.subnet_id.chars().count(),
+            Some(15),
+            Some(47),
+        );
+        errors.check_pattern(
+            &field_path(path, "subnet_id"),
+            &self.subnet_id,
+            "^subnet-[0-9a-f]{8,40}$",
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTagsRequest {
     /// <p>The ID of the file system whose tags you want to modify (String). This operation modifies the tags only, not the file system.</p>
     #[serde(rename = "FileSystemId")]
@@ -85,24 +177,71 @@ pub struct CreateTagsRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for CreateTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "file_system_id"), &self.file_system_id, "^(arn:aws[-a-z]*:elasticfilesystem:[0-9a-z-:]+:file-system/fs-[0-9a-f]{8,40}|fs-[0-9a-f]{8,40})$");
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFileSystemRequest {
     /// <p>The ID of the file system you want to delete.</p>
     #[serde(rename = "FileSystemId")]
     pub file_system_id: String,
 }
 
+impl Validate for DeleteFileSystemRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "file_system_id"), &self.file_system_id, "^(arn:aws[-a-z]*:elasticfilesystem:[0-9a-z-:]+:file-system/fs-[0-9a-f]{8,40}|fs-[0-9a-f]{8,40})$");
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMountTargetRequest {
     /// <p>The ID of the mount target to delete (String).</p>
     #[serde(rename = "MountTargetId")]
     pub mount_target_id: String,
 }
 
+impl Validate for DeleteMountTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mount_target_id"),
+            self.mount_target_id.chars().count(),
+            Some(13),
+            Some(45),
+        );
+        errors.check_pattern(
+            &field_path(path, "mount_target_id"),
+            &self.mount_target_id,
+            "^fsmt-[0-9a-f]{8,40}$",
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagsRequest {
     /// <p>The ID of the file system whose tags you want to delete (String).</p>
     #[serde(rename = "FileSystemId")]
@@ -112,8 +251,36 @@ pub struct DeleteTagsRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for DeleteTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "file_system_id"), &self.file_system_id, "^(arn:aws[-a-z]*:elasticfilesystem:[0-9a-z-:]+:file-system/fs-[0-9a-f]{8,40}|fs-[0-9a-f]{8,40})$");
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "^(?![aA]{1}[wW]{1}[sS]{1}:)([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]+)$",
+            );
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFileSystemsRequest {
     /// <p>(Optional) Restricts the list to the file system with this creation token (String). You specify a creation token when you create an Amazon EFS file system.</p>
     #[serde(rename = "CreationToken")]
@@ -133,8 +300,43 @@ pub struct DescribeFileSystemsRequest {
     pub max_items: Option<i64>,
 }
 
+impl Validate for DescribeFileSystemsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.creation_token {
+            errors.check_length(
+                &field_path(path, "creation_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "creation_token"), &value, ".+");
+        }
+        if let Some(ref value) = self.file_system_id {
+            errors.check_length(
+                &field_path(path, "file_system_id"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "file_system_id"), &value, "^(arn:aws[-a-z]*:elasticfilesystem:[0-9a-z-:]+:file-system/fs-[0-9a-f]{8,40}|fs-[0-9a-f]{8,40})$");
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, ".+");
+        }
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFileSystemsResponse {
     /// <p>An array of file system descriptions.</p>
     #[serde(rename = "FileSystems")]
@@ -151,22 +353,52 @@ pub struct DescribeFileSystemsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLifecycleConfigurationRequest {
     /// <p>The ID of the file system whose <code>LifecycleConfiguration</code> object you want to retrieve (String).</p>
     #[serde(rename = "FileSystemId")]
     pub file_system_id: String,
 }
 
+impl Validate for DescribeLifecycleConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "file_system_id"), &self.file_system_id, "^(arn:aws[-a-z]*:elasticfilesystem:[0-9a-z-:]+:file-system/fs-[0-9a-f]{8,40}|fs-[0-9a-f]{8,40})$");
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMountTargetSecurityGroupsRequest {
     /// <p>The ID of the mount target whose security groups you want to retrieve.</p>
     #[serde(rename = "MountTargetId")]
     pub mount_target_id: String,
 }
 
+impl Validate for DescribeMountTargetSecurityGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mount_target_id"),
+            self.mount_target_id.chars().count(),
+            Some(13),
+            Some(45),
+        );
+        errors.check_pattern(
+            &field_path(path, "mount_target_id"),
+            &self.mount_target_id,
+            "^fsmt-[0-9a-f]{8,40}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMountTargetSecurityGroupsResponse {
     /// <p>An array of security groups.</p>
     #[serde(rename = "SecurityGroups")]
@@ -175,6 +407,7 @@ pub struct DescribeMountTargetSecurityGroupsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMountTargetsRequest {
     /// <p>(Optional) ID of the file system whose mount targets you want to list (String). It must be included in your request if <code>MountTargetId</code> is not included.</p>
     #[serde(rename = "FileSystemId")]
@@ -194,9 +427,48 @@ pub struct DescribeMountTargetsRequest {
     pub mount_target_id: Option<String>,
 }
 
+impl Validate for DescribeMountTargetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.file_system_id {
+            errors.check_length(
+                &field_path(path, "file_system_id"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "file_system_id"), &value, "^(arn:aws[-a-z]*:elasticfilesystem:[0-9a-z-:]+:file-system/fs-[0-9a-f]{8,40}|fs-[0-9a-f]{8,40})$");
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, ".+");
+        }
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.mount_target_id {
+            errors.check_length(
+                &field_path(path, "mount_target_id"),
+                value.chars().count(),
+                Some(13),
+                Some(45),
+            );
+            errors.check_pattern(
+                &field_path(path, "mount_target_id"),
+                &value,
+                "^fsmt-[0-9a-f]{8,40}$",
+            );
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMountTargetsResponse {
     /// <p>If the request included the <code>Marker</code>, the response returns that value in this field.</p>
     #[serde(rename = "Marker")]
@@ -214,6 +486,7 @@ pub struct DescribeMountTargetsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsRequest {
     /// <p>The ID of the file system whose tag set you want to retrieve.</p>
     #[serde(rename = "FileSystemId")]
@@ -228,9 +501,33 @@ pub struct DescribeTagsRequest {
     pub max_items: Option<i64>,
 }
 
+impl Validate for DescribeTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "file_system_id"), &self.file_system_id, "^(arn:aws[-a-z]*:elasticfilesystem:[0-9a-z-:]+:file-system/fs-[0-9a-f]{8,40}|fs-[0-9a-f]{8,40})$");
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, ".+");
+        }
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTagsResponse {
     /// <p>If the request included a <code>Marker</code>, the response returns that value in this field.</p>
     #[serde(rename = "Marker")]
@@ -247,7 +544,7 @@ pub struct DescribeTagsResponse {
 
 /// <p>A description of the file system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FileSystemDescription {
     /// <p>The time that the file system was created, in seconds (since 1970-01-01T00:00:00Z).</p>
     #[serde(rename = "CreationTime")]
@@ -300,7 +597,7 @@ pub struct FileSystemDescription {
 
 /// <p>The latest known metered size (in bytes) of data stored in the file system, in its <code>Value</code> field, and the time at which that size was determined in its <code>Timestamp</code> field. The value doesn't represent the size of a consistent snapshot of the file system, but it is eventually consistent when there are no writes to the file system. That is, the value represents the actual size only if the file system is not modified for a period longer than a couple of hours. Otherwise, the value is not necessarily the exact size the file system was at any instant in time.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FileSystemSize {
     /// <p>The time at which the size of data, returned in the <code>Value</code> field, was determined. The value is the integer number of seconds since 1970-01-01T00:00:00Z.</p>
     #[serde(rename = "Timestamp")]
@@ -320,7 +617,7 @@ pub struct FileSystemSize {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LifecycleConfigurationDescription {
     /// <p>An array of lifecycle management policies. Currently, EFS supports a maximum of one policy per file system.</p>
     #[serde(rename = "LifecyclePolicies")]
@@ -339,6 +636,7 @@ pub struct LifecyclePolicy {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyMountTargetSecurityGroupsRequest {
     /// <p>The ID of the mount target whose security groups you want to modify.</p>
     #[serde(rename = "MountTargetId")]
@@ -349,9 +647,38 @@ pub struct ModifyMountTargetSecurityGroupsRequest {
     pub security_groups: Option<Vec<String>>,
 }
 
+impl Validate for ModifyMountTargetSecurityGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "mount_target_id"),
+            self.mount_target_id.chars().count(),
+            Some(13),
+            Some(45),
+        );
+        errors.check_pattern(
+            &field_path(path, "mount_target_id"),
+            &self.mount_target_id,
+            "^fsmt-[0-9a-f]{8,40}$",
+        );
+        if let Some(ref value) = self.security_groups {
+            errors.check_length(
+                &field_path(path, "security_groups"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "security_groups"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(11), Some(43));
+                errors.check_pattern(&item_path, &item, "^sg-[0-9a-f]{8,40}");
+            }
+        }
+    }
+}
+
 /// <p>Provides a description of a mount target.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MountTargetDescription {
     /// <p>The ID of the file system for which the mount target is intended.</p>
     #[serde(rename = "FileSystemId")]
@@ -380,6 +707,7 @@ pub struct MountTargetDescription {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutLifecycleConfigurationRequest {
     /// <p>The ID of the file system for which you are creating the <code>LifecycleConfiguration</code> object (String).</p>
     #[serde(rename = "FileSystemId")]
@@ -389,6 +717,24 @@ pub struct PutLifecycleConfigurationRequest {
     pub lifecycle_policies: Vec<LifecyclePolicy>,
 }
 
+impl Validate for PutLifecycleConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "file_system_id"), &self.file_system_id, "^(arn:aws[-a-z]*:elasticfilesystem:[0-9a-z-:]+:file-system/fs-[0-9a-f]{8,40}|fs-[0-9a-f]{8,40})$");
+        errors.check_length(
+            &field_path(path, "lifecycle_policies"),
+            self.lifecycle_policies.len(),
+            None,
+            Some(3),
+        );
+    }
+}
+
 /// <p>A tag is a key-value pair. Allowed characters are letters, white space, and numbers that can be represented in UTF-8, and the following characters:<code> + - = . _ : /</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -400,7 +746,35 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^(?![aA]{1}[wW]{1}[sS]{1}:)([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]+)$",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFileSystemRequest {
     /// <p>The ID of the file system that you want to update.</p>
     #[serde(rename = "FileSystemId")]
@@ -415,6 +789,26 @@ pub struct UpdateFileSystemRequest {
     pub throughput_mode: Option<String>,
 }
 
+impl Validate for UpdateFileSystemRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "file_system_id"), &self.file_system_id, "^(arn:aws[-a-z]*:elasticfilesystem:[0-9a-z-:]+:file-system/fs-[0-9a-f]{8,40}|fs-[0-9a-f]{8,40})$");
+        if let Some(ref value) = self.provisioned_throughput_in_mibps {
+            errors.check_range(
+                &field_path(path, "provisioned_throughput_in_mibps"),
+                *value,
+                Some(1.0),
+                None,
+            );
+        }
+    }
+}
+
 /// Errors returned by CreateFileSystem
 #[derive(Debug, PartialEq)]
 pub enum CreateFileSystemError {
@@ -464,7 +858,12 @@ impl CreateFileSystemError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -575,7 +974,12 @@ impl CreateMountTargetError {
                         CreateMountTargetError::UnsupportedAvailabilityZone(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -627,7 +1031,12 @@ impl CreateTagsError {
                 "InternalServerError" => {
                     return RusotoError::Service(CreateTagsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -679,7 +1088,12 @@ impl DeleteFileSystemError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -734,7 +1148,12 @@ impl DeleteMountTargetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -778,7 +1197,12 @@ impl DeleteTagsError {
                 "InternalServerError" => {
                     return RusotoError::Service(DeleteTagsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -827,7 +1251,12 @@ impl DescribeFileSystemsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -880,7 +1309,12 @@ impl DescribeLifecycleConfigurationError {
                         DescribeLifecycleConfigurationError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -940,7 +1374,12 @@ impl DescribeMountTargetSecurityGroupsError {
                         DescribeMountTargetSecurityGroupsError::MountTargetNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -997,7 +1436,12 @@ impl DescribeMountTargetsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1043,7 +1487,12 @@ impl DescribeTagsError {
                 "InternalServerError" => {
                     return RusotoError::Service(DescribeTagsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1117,7 +1566,12 @@ impl ModifyMountTargetSecurityGroupsError {
                         ModifyMountTargetSecurityGroupsError::SecurityGroupNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1178,7 +1632,12 @@ impl PutLifecycleConfigurationError {
                         PutLifecycleConfigurationError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1252,7 +1711,12 @@ impl UpdateFileSystemError {
                 "TooManyRequests" => {
                     return RusotoError::Service(UpdateFileSystemError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1360,6 +1824,256 @@ pub trait Efs {
         input: UpdateFileSystemRequest,
     ) -> RusotoFuture<FileSystemDescription, UpdateFileSystemError>;
 }
+const _: Option<&dyn Efs> = None;
+impl<'a, T: Efs + ?Sized> Efs for &'a T {
+    fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> RusotoFuture<FileSystemDescription, CreateFileSystemError> {
+        (**self).create_file_system(input)
+    }
+    fn create_mount_target(
+        &self,
+        input: CreateMountTargetRequest,
+    ) -> RusotoFuture<MountTargetDescription, CreateMountTargetError> {
+        (**self).create_mount_target(input)
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> RusotoFuture<(), DeleteFileSystemError> {
+        (**self).delete_file_system(input)
+    }
+    fn delete_mount_target(
+        &self,
+        input: DeleteMountTargetRequest,
+    ) -> RusotoFuture<(), DeleteMountTargetError> {
+        (**self).delete_mount_target(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        (**self).describe_file_systems(input)
+    }
+    fn describe_lifecycle_configuration(
+        &self,
+        input: DescribeLifecycleConfigurationRequest,
+    ) -> RusotoFuture<LifecycleConfigurationDescription, DescribeLifecycleConfigurationError> {
+        (**self).describe_lifecycle_configuration(input)
+    }
+    fn describe_mount_target_security_groups(
+        &self,
+        input: DescribeMountTargetSecurityGroupsRequest,
+    ) -> RusotoFuture<
+        DescribeMountTargetSecurityGroupsResponse,
+        DescribeMountTargetSecurityGroupsError,
+    > {
+        (**self).describe_mount_target_security_groups(input)
+    }
+    fn describe_mount_targets(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> RusotoFuture<DescribeMountTargetsResponse, DescribeMountTargetsError> {
+        (**self).describe_mount_targets(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn modify_mount_target_security_groups(
+        &self,
+        input: ModifyMountTargetSecurityGroupsRequest,
+    ) -> RusotoFuture<(), ModifyMountTargetSecurityGroupsError> {
+        (**self).modify_mount_target_security_groups(input)
+    }
+    fn put_lifecycle_configuration(
+        &self,
+        input: PutLifecycleConfigurationRequest,
+    ) -> RusotoFuture<LifecycleConfigurationDescription, PutLifecycleConfigurationError> {
+        (**self).put_lifecycle_configuration(input)
+    }
+    fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> RusotoFuture<FileSystemDescription, UpdateFileSystemError> {
+        (**self).update_file_system(input)
+    }
+}
+impl<T: Efs + ?Sized> Efs for Box<T> {
+    fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> RusotoFuture<FileSystemDescription, CreateFileSystemError> {
+        (**self).create_file_system(input)
+    }
+    fn create_mount_target(
+        &self,
+        input: CreateMountTargetRequest,
+    ) -> RusotoFuture<MountTargetDescription, CreateMountTargetError> {
+        (**self).create_mount_target(input)
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> RusotoFuture<(), DeleteFileSystemError> {
+        (**self).delete_file_system(input)
+    }
+    fn delete_mount_target(
+        &self,
+        input: DeleteMountTargetRequest,
+    ) -> RusotoFuture<(), DeleteMountTargetError> {
+        (**self).delete_mount_target(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        (**self).describe_file_systems(input)
+    }
+    fn describe_lifecycle_configuration(
+        &self,
+        input: DescribeLifecycleConfigurationRequest,
+    ) -> RusotoFuture<LifecycleConfigurationDescription, DescribeLifecycleConfigurationError> {
+        (**self).describe_lifecycle_configuration(input)
+    }
+    fn describe_mount_target_security_groups(
+        &self,
+        input: DescribeMountTargetSecurityGroupsRequest,
+    ) -> RusotoFuture<
+        DescribeMountTargetSecurityGroupsResponse,
+        DescribeMountTargetSecurityGroupsError,
+    > {
+        (**self).describe_mount_target_security_groups(input)
+    }
+    fn describe_mount_targets(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> RusotoFuture<DescribeMountTargetsResponse, DescribeMountTargetsError> {
+        (**self).describe_mount_targets(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn modify_mount_target_security_groups(
+        &self,
+        input: ModifyMountTargetSecurityGroupsRequest,
+    ) -> RusotoFuture<(), ModifyMountTargetSecurityGroupsError> {
+        (**self).modify_mount_target_security_groups(input)
+    }
+    fn put_lifecycle_configuration(
+        &self,
+        input: PutLifecycleConfigurationRequest,
+    ) -> RusotoFuture<LifecycleConfigurationDescription, PutLifecycleConfigurationError> {
+        (**self).put_lifecycle_configuration(input)
+    }
+    fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> RusotoFuture<FileSystemDescription, UpdateFileSystemError> {
+        (**self).update_file_system(input)
+    }
+}
+impl<T: Efs + ?Sized> Efs for ::std::sync::Arc<T> {
+    fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> RusotoFuture<FileSystemDescription, CreateFileSystemError> {
+        (**self).create_file_system(input)
+    }
+    fn create_mount_target(
+        &self,
+        input: CreateMountTargetRequest,
+    ) -> RusotoFuture<MountTargetDescription, CreateMountTargetError> {
+        (**self).create_mount_target(input)
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> RusotoFuture<(), DeleteFileSystemError> {
+        (**self).delete_file_system(input)
+    }
+    fn delete_mount_target(
+        &self,
+        input: DeleteMountTargetRequest,
+    ) -> RusotoFuture<(), DeleteMountTargetError> {
+        (**self).delete_mount_target(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        (**self).describe_file_systems(input)
+    }
+    fn describe_lifecycle_configuration(
+        &self,
+        input: DescribeLifecycleConfigurationRequest,
+    ) -> RusotoFuture<LifecycleConfigurationDescription, DescribeLifecycleConfigurationError> {
+        (**self).describe_lifecycle_configuration(input)
+    }
+    fn describe_mount_target_security_groups(
+        &self,
+        input: DescribeMountTargetSecurityGroupsRequest,
+    ) -> RusotoFuture<
+        DescribeMountTargetSecurityGroupsResponse,
+        DescribeMountTargetSecurityGroupsError,
+    > {
+        (**self).describe_mount_target_security_groups(input)
+    }
+    fn describe_mount_targets(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> RusotoFuture<DescribeMountTargetsResponse, DescribeMountTargetsError> {
+        (**self).describe_mount_targets(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn modify_mount_target_security_groups(
+        &self,
+        input: ModifyMountTargetSecurityGroupsRequest,
+    ) -> RusotoFuture<(), ModifyMountTargetSecurityGroupsError> {
+        (**self).modify_mount_target_security_groups(input)
+    }
+    fn put_lifecycle_configuration(
+        &self,
+        input: PutLifecycleConfigurationRequest,
+    ) -> RusotoFuture<LifecycleConfigurationDescription, PutLifecycleConfigurationError> {
+        (**self).put_lifecycle_configuration(input)
+    }
+    fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> RusotoFuture<FileSystemDescription, UpdateFileSystemError> {
+        (**self).update_file_system(input)
+    }
+}
 /// A client for the EFS API.
 #[derive(Clone)]
 pub struct EfsClient {
@@ -1403,10 +2117,20 @@ impl Efs for EfsClient {
         &self,
         input: CreateFileSystemRequest,
     ) -> RusotoFuture<FileSystemDescription, CreateFileSystemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2015-02-01/file-systems";
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateFileSystem");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1416,6 +2140,7 @@ impl Efs for EfsClient {
             if response.status.as_u16() == 201 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateFileSystem")
                         .deserialize::<FileSystemDescription, _>()?;
 
                     Ok(result)
@@ -1436,10 +2161,20 @@ impl Efs for EfsClient {
         &self,
         input: CreateMountTargetRequest,
     ) -> RusotoFuture<MountTargetDescription, CreateMountTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2015-02-01/mount-targets";
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateMountTarget");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1449,6 +2184,7 @@ impl Efs for EfsClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateMountTarget")
                         .deserialize::<MountTargetDescription, _>()?;
 
                     Ok(result)
@@ -1466,6 +2202,14 @@ impl Efs for EfsClient {
 
     /// <p>Creates or overwrites tags associated with a file system. Each tag is a key-value pair. If a tag key specified in the request already exists on the file system, this operation overwrites its value with the value provided in the request. If you add the <code>Name</code> tag to your file system, Amazon EFS returns it in the response to the <a>DescribeFileSystems</a> operation. </p> <p>This operation requires permission for the <code>elasticfilesystem:CreateTags</code> action.</p>
     fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2015-02-01/create-tags/{file_system_id}",
             file_system_id = input.file_system_id
@@ -1473,6 +2217,8 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateTags");
+        request.add_resource_id("FileSystemId", &input.file_system_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1501,6 +2247,14 @@ impl Efs for EfsClient {
         &self,
         input: DeleteFileSystemRequest,
     ) -> RusotoFuture<(), DeleteFileSystemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}",
             file_system_id = input.file_system_id
@@ -1508,6 +2262,8 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteFileSystem");
+        request.add_resource_id("FileSystemId", &input.file_system_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
@@ -1533,6 +2289,14 @@ impl Efs for EfsClient {
         &self,
         input: DeleteMountTargetRequest,
     ) -> RusotoFuture<(), DeleteMountTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2015-02-01/mount-targets/{mount_target_id}",
             mount_target_id = input.mount_target_id
@@ -1540,6 +2304,8 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteMountTarget");
+        request.add_resource_id("MountTargetId", &input.mount_target_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
@@ -1562,6 +2328,14 @@ impl Efs for EfsClient {
 
     /// <p>Deletes the specified tags from a file system. If the <code>DeleteTags</code> request includes a tag key that doesn't exist, Amazon EFS ignores it and doesn't cause an error. For more information about tags and related restrictions, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteTags</code> action.</p>
     fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2015-02-01/delete-tags/{file_system_id}",
             file_system_id = input.file_system_id
@@ -1569,6 +2343,8 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
+        request.add_resource_id("FileSystemId", &input.file_system_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1597,10 +2373,20 @@ impl Efs for EfsClient {
         &self,
         input: DescribeFileSystemsRequest,
     ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2015-02-01/file-systems";
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeFileSystems");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1622,6 +2408,7 @@ impl Efs for EfsClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeFileSystems")
                         .deserialize::<DescribeFileSystemsResponse, _>()?;
 
                     Ok(result)
@@ -1641,6 +2428,14 @@ impl Efs for EfsClient {
         &self,
         input: DescribeLifecycleConfigurationRequest,
     ) -> RusotoFuture<LifecycleConfigurationDescription, DescribeLifecycleConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}/lifecycle-configuration",
             file_system_id = input.file_system_id
@@ -1648,12 +2443,16 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeLifecycleConfiguration");
+        request.add_resource_id("FileSystemId", &input.file_system_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeLifecycleConfiguration")
                         .deserialize::<LifecycleConfigurationDescription, _>()?;
 
                     Ok(result)
@@ -1674,6 +2473,14 @@ impl Efs for EfsClient {
         DescribeMountTargetSecurityGroupsResponse,
         DescribeMountTargetSecurityGroupsError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2015-02-01/mount-targets/{mount_target_id}/security-groups",
             mount_target_id = input.mount_target_id
@@ -1681,12 +2488,16 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeMountTargetSecurityGroups");
+        request.add_resource_id("MountTargetId", &input.mount_target_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeMountTargetSecurityGroups")
                         .deserialize::<DescribeMountTargetSecurityGroupsResponse, _>()?;
 
                     Ok(result)
@@ -1706,10 +2517,20 @@ impl Efs for EfsClient {
         &self,
         input: DescribeMountTargetsRequest,
     ) -> RusotoFuture<DescribeMountTargetsResponse, DescribeMountTargetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2015-02-01/mount-targets";
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeMountTargets");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1731,6 +2552,7 @@ impl Efs for EfsClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeMountTargets")
                         .deserialize::<DescribeMountTargetsResponse, _>()?;
 
                     Ok(result)
@@ -1750,6 +2572,14 @@ impl Efs for EfsClient {
         &self,
         input: DescribeTagsRequest,
     ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2015-02-01/tags/{file_system_id}/",
             file_system_id = input.file_system_id
@@ -1757,6 +2587,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeTags");
+        request.add_resource_id("FileSystemId", &input.file_system_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1772,6 +2605,7 @@ impl Efs for EfsClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeTags")
                         .deserialize::<DescribeTagsResponse, _>()?;
 
                     Ok(result)
@@ -1792,6 +2626,14 @@ impl Efs for EfsClient {
         &self,
         input: ModifyMountTargetSecurityGroupsRequest,
     ) -> RusotoFuture<(), ModifyMountTargetSecurityGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2015-02-01/mount-targets/{mount_target_id}/security-groups",
             mount_target_id = input.mount_target_id
@@ -1799,6 +2641,8 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("ModifyMountTargetSecurityGroups");
+        request.add_resource_id("MountTargetId", &input.mount_target_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1826,6 +2670,14 @@ impl Efs for EfsClient {
         &self,
         input: PutLifecycleConfigurationRequest,
     ) -> RusotoFuture<LifecycleConfigurationDescription, PutLifecycleConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}/lifecycle-configuration",
             file_system_id = input.file_system_id
@@ -1833,6 +2685,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutLifecycleConfiguration");
+        request.add_resource_id("FileSystemId", &input.file_system_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1842,6 +2697,7 @@ impl Efs for EfsClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutLifecycleConfiguration")
                         .deserialize::<LifecycleConfigurationDescription, _>()?;
 
                     Ok(result)
@@ -1859,6 +2715,14 @@ impl Efs for EfsClient {
         &self,
         input: UpdateFileSystemRequest,
     ) -> RusotoFuture<FileSystemDescription, UpdateFileSystemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}",
             file_system_id = input.file_system_id
@@ -1866,6 +2730,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("UpdateFileSystem");
+        request.add_resource_id("FileSystemId", &input.file_system_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1875,6 +2742,7 @@ impl Efs for EfsClient {
             if response.status.as_u16() == 202 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateFileSystem")
                         .deserialize::<FileSystemDescription, _>()?;
 
                     Ok(result)
@@ -1890,3 +2758,482 @@ impl Efs for EfsClient {
         })
     }
 }
+
+/// A mock of the EFS API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockEfs {
+    create_file_system: Option<
+        Box<
+            dyn Fn(
+                    CreateFileSystemRequest,
+                ) -> ::std::result::Result<
+                    FileSystemDescription,
+                    RusotoError<CreateFileSystemError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_mount_target: Option<
+        Box<
+            dyn Fn(
+                    CreateMountTargetRequest,
+                ) -> ::std::result::Result<
+                    MountTargetDescription,
+                    RusotoError<CreateMountTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_tags: Option<
+        Box<
+            dyn Fn(CreateTagsRequest) -> ::std::result::Result<(), RusotoError<CreateTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_file_system: Option<
+        Box<
+            dyn Fn(
+                    DeleteFileSystemRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteFileSystemError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_mount_target: Option<
+        Box<
+            dyn Fn(
+                    DeleteMountTargetRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteMountTargetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_tags: Option<
+        Box<
+            dyn Fn(DeleteTagsRequest) -> ::std::result::Result<(), RusotoError<DeleteTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_file_systems: Option<
+        Box<
+            dyn Fn(
+                    DescribeFileSystemsRequest,
+                ) -> ::std::result::Result<
+                    DescribeFileSystemsResponse,
+                    RusotoError<DescribeFileSystemsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_lifecycle_configuration: Option<
+        Box<
+            dyn Fn(
+                    DescribeLifecycleConfigurationRequest,
+                ) -> ::std::result::Result<
+                    LifecycleConfigurationDescription,
+                    RusotoError<DescribeLifecycleConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_mount_target_security_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeMountTargetSecurityGroupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeMountTargetSecurityGroupsResponse,
+                    RusotoError<DescribeMountTargetSecurityGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_mount_targets: Option<
+        Box<
+            dyn Fn(
+                    DescribeMountTargetsRequest,
+                ) -> ::std::result::Result<
+                    DescribeMountTargetsResponse,
+                    RusotoError<DescribeMountTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tags: Option<
+        Box<
+            dyn Fn(
+                    DescribeTagsRequest,
+                )
+                    -> ::std::result::Result<DescribeTagsResponse, RusotoError<DescribeTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_mount_target_security_groups: Option<
+        Box<
+            dyn Fn(
+                    ModifyMountTargetSecurityGroupsRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<ModifyMountTargetSecurityGroupsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_lifecycle_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutLifecycleConfigurationRequest,
+                ) -> ::std::result::Result<
+                    LifecycleConfigurationDescription,
+                    RusotoError<PutLifecycleConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_file_system: Option<
+        Box<
+            dyn Fn(
+                    UpdateFileSystemRequest,
+                ) -> ::std::result::Result<
+                    FileSystemDescription,
+                    RusotoError<UpdateFileSystemError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockEfs {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockEfs {
+        Default::default()
+    }
+
+    /// Answers `create_file_system` with what `handler` returns for its input.
+    pub fn with_create_file_system<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                CreateFileSystemRequest,
+            )
+                -> ::std::result::Result<FileSystemDescription, RusotoError<CreateFileSystemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_file_system = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_mount_target` with what `handler` returns for its input.
+    pub fn with_create_mount_target<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                CreateMountTargetRequest,
+            )
+                -> ::std::result::Result<MountTargetDescription, RusotoError<CreateMountTargetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_mount_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_tags` with what `handler` returns for its input.
+    pub fn with_create_tags<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(CreateTagsRequest) -> ::std::result::Result<(), RusotoError<CreateTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_file_system` with what `handler` returns for its input.
+    pub fn with_delete_file_system<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                DeleteFileSystemRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteFileSystemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_file_system = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_mount_target` with what `handler` returns for its input.
+    pub fn with_delete_mount_target<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                DeleteMountTargetRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteMountTargetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_mount_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tags` with what `handler` returns for its input.
+    pub fn with_delete_tags<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(DeleteTagsRequest) -> ::std::result::Result<(), RusotoError<DeleteTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_file_systems` with what `handler` returns for its input.
+    pub fn with_describe_file_systems<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                DescribeFileSystemsRequest,
+            ) -> ::std::result::Result<
+                DescribeFileSystemsResponse,
+                RusotoError<DescribeFileSystemsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_file_systems = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_lifecycle_configuration` with what `handler` returns for its input.
+    pub fn with_describe_lifecycle_configuration<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                DescribeLifecycleConfigurationRequest,
+            ) -> ::std::result::Result<
+                LifecycleConfigurationDescription,
+                RusotoError<DescribeLifecycleConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_lifecycle_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_mount_target_security_groups` with what `handler` returns for its input.
+    pub fn with_describe_mount_target_security_groups<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                DescribeMountTargetSecurityGroupsRequest,
+            ) -> ::std::result::Result<
+                DescribeMountTargetSecurityGroupsResponse,
+                RusotoError<DescribeMountTargetSecurityGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_mount_target_security_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_mount_targets` with what `handler` returns for its input.
+    pub fn with_describe_mount_targets<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                DescribeMountTargetsRequest,
+            ) -> ::std::result::Result<
+                DescribeMountTargetsResponse,
+                RusotoError<DescribeMountTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_mount_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tags` with what `handler` returns for its input.
+    pub fn with_describe_tags<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                DescribeTagsRequest,
+            )
+                -> ::std::result::Result<DescribeTagsResponse, RusotoError<DescribeTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_mount_target_security_groups` with what `handler` returns for its input.
+    pub fn with_modify_mount_target_security_groups<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                ModifyMountTargetSecurityGroupsRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<ModifyMountTargetSecurityGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_mount_target_security_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_lifecycle_configuration` with what `handler` returns for its input.
+    pub fn with_put_lifecycle_configuration<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                PutLifecycleConfigurationRequest,
+            ) -> ::std::result::Result<
+                LifecycleConfigurationDescription,
+                RusotoError<PutLifecycleConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_lifecycle_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_file_system` with what `handler` returns for its input.
+    pub fn with_update_file_system<F>(mut self, handler: F) -> MockEfs
+    where
+        F: Fn(
+                UpdateFileSystemRequest,
+            )
+                -> ::std::result::Result<FileSystemDescription, RusotoError<UpdateFileSystemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_file_system = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Efs for MockEfs {
+    fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> RusotoFuture<FileSystemDescription, CreateFileSystemError> {
+        match self.create_file_system {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for create_file_system"),
+        }
+    }
+    fn create_mount_target(
+        &self,
+        input: CreateMountTargetRequest,
+    ) -> RusotoFuture<MountTargetDescription, CreateMountTargetError> {
+        match self.create_mount_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for create_mount_target"),
+        }
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        match self.create_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for create_tags"),
+        }
+    }
+    fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> RusotoFuture<(), DeleteFileSystemError> {
+        match self.delete_file_system {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for delete_file_system"),
+        }
+    }
+    fn delete_mount_target(
+        &self,
+        input: DeleteMountTargetRequest,
+    ) -> RusotoFuture<(), DeleteMountTargetError> {
+        match self.delete_mount_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for delete_mount_target"),
+        }
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        match self.delete_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for delete_tags"),
+        }
+    }
+    fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        match self.describe_file_systems {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for describe_file_systems"),
+        }
+    }
+    fn describe_lifecycle_configuration(
+        &self,
+        input: DescribeLifecycleConfigurationRequest,
+    ) -> RusotoFuture<LifecycleConfigurationDescription, DescribeLifecycleConfigurationError> {
+        match self.describe_lifecycle_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for describe_lifecycle_configuration"),
+        }
+    }
+    fn describe_mount_target_security_groups(
+        &self,
+        input: DescribeMountTargetSecurityGroupsRequest,
+    ) -> RusotoFuture<
+        DescribeMountTargetSecurityGroupsResponse,
+        DescribeMountTargetSecurityGroupsError,
+    > {
+        match self.describe_mount_target_security_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for describe_mount_target_security_groups"),
+        }
+    }
+    fn describe_mount_targets(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> RusotoFuture<DescribeMountTargetsResponse, DescribeMountTargetsError> {
+        match self.describe_mount_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for describe_mount_targets"),
+        }
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        match self.describe_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for describe_tags"),
+        }
+    }
+    fn modify_mount_target_security_groups(
+        &self,
+        input: ModifyMountTargetSecurityGroupsRequest,
+    ) -> RusotoFuture<(), ModifyMountTargetSecurityGroupsError> {
+        match self.modify_mount_target_security_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for modify_mount_target_security_groups"),
+        }
+    }
+    fn put_lifecycle_configuration(
+        &self,
+        input: PutLifecycleConfigurationRequest,
+    ) -> RusotoFuture<LifecycleConfigurationDescription, PutLifecycleConfigurationError> {
+        match self.put_lifecycle_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for put_lifecycle_configuration"),
+        }
+    }
+    fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> RusotoFuture<FileSystemDescription, UpdateFileSystemError> {
+        match self.update_file_system {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEfs has no handler for update_file_system"),
+        }
+    }
+}
@@ -23,10 +23,11 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>An object representing the <code>certificate-authority-data</code> for your cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Certificate {
     /// <p>The Base64-encoded certificate data required to communicate with your cluster. Add this to the <code>certificate-authority-data</code> section of the <code>kubeconfig</code> file for your cluster.</p>
     #[serde(rename = "data")]
@@ -36,7 +37,7 @@ pub struct Certificate {
 
 /// <p>An object representing an Amazon EKS cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Cluster {
     /// <p>The Amazon Resource Name (ARN) of the cluster.</p>
     #[serde(rename = "arn")]
@@ -97,6 +98,7 @@ pub struct Cluster {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientRequestToken")]
@@ -125,8 +127,31 @@ pub struct CreateClusterRequest {
     pub version: Option<String>,
 }
 
+impl Validate for CreateClusterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[0-9A-Za-z][A-Za-z0-9\\-_]*",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateClusterResponse {
     /// <p>The full description of your new cluster.</p>
     #[serde(rename = "cluster")]
@@ -135,6 +160,7 @@ pub struct CreateClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterRequest {
     /// <p>The name of the cluster to delete.</p>
     #[serde(rename = "name")]
@@ -142,7 +168,7 @@ pub struct DeleteClusterRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteClusterResponse {
     /// <p>The full description of the cluster to delete.</p>
     #[serde(rename = "cluster")]
@@ -151,6 +177,7 @@ pub struct DeleteClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterRequest {
     /// <p>The name of the cluster to describe.</p>
     #[serde(rename = "name")]
@@ -158,7 +185,7 @@ pub struct DescribeClusterRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeClusterResponse {
     /// <p>The full description of your specified cluster.</p>
     #[serde(rename = "cluster")]
@@ -167,6 +194,7 @@ pub struct DescribeClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUpdateRequest {
     /// <p>The name of the Amazon EKS cluster to update.</p>
     #[serde(rename = "name")]
@@ -177,7 +205,7 @@ pub struct DescribeUpdateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUpdateResponse {
     /// <p>The full description of the specified update.</p>
     #[serde(rename = "update")]
@@ -187,7 +215,7 @@ pub struct DescribeUpdateResponse {
 
 /// <p>An object representing an error when an asynchronous operation fails.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ErrorDetail {
     /// <p><p>A brief description of the error. </p> <ul> <li> <p> <b>SubnetNotFound</b>: We couldn&#39;t find one of the subnets associated with the cluster.</p> </li> <li> <p> <b>SecurityGroupNotFound</b>: We couldn&#39;t find one of the security groups associated with the cluster.</p> </li> <li> <p> <b>EniLimitReached</b>: You have reached the elastic network interface limit for your account.</p> </li> <li> <p> <b>IpNotAvailable</b>: A subnet associated with the cluster doesn&#39;t have any free IP addresses.</p> </li> <li> <p> <b>AccessDenied</b>: You don&#39;t have permissions to perform the specified operation.</p> </li> <li> <p> <b>OperationNotPermitted</b>: The service role associated with the cluster doesn&#39;t have the required access permissions for Amazon EKS.</p> </li> <li> <p> <b>VpcIdNotFound</b>: We couldn&#39;t find the VPC associated with the cluster.</p> </li> </ul></p>
     #[serde(rename = "errorCode")]
@@ -205,7 +233,7 @@ pub struct ErrorDetail {
 
 /// <p>An object representing an identity provider for authentication credentials.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Identity {
     /// <p>The <a href="https://openid.net/connect/">OpenID Connect</a> identity provider information for the cluster.</p>
     #[serde(rename = "oidc")]
@@ -214,6 +242,7 @@ pub struct Identity {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListClustersRequest {
     /// <p>The maximum number of cluster results returned by <code>ListClusters</code> in paginated output. When you use this parameter, <code>ListClusters</code> returns only <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. You can see the remaining results of the initial request by sending another <code>ListClusters</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If you don't use this parameter, <code>ListClusters</code> returns up to 100 results and a <code>nextToken</code> value if applicable.</p>
     #[serde(rename = "maxResults")]
@@ -225,8 +254,16 @@ pub struct ListClustersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListClustersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListClustersResponse {
     /// <p>A list of all of the clusters for your account in the specified Region.</p>
     #[serde(rename = "clusters")]
@@ -239,6 +276,7 @@ pub struct ListClustersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the resource for which to list the tags. Currently, the supported resources are Amazon EKS clusters.</p>
     #[serde(rename = "resourceArn")]
@@ -246,7 +284,7 @@ pub struct ListTagsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags for the resource.</p>
     #[serde(rename = "tags")]
@@ -255,6 +293,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUpdatesRequest {
     /// <p>The maximum number of update results returned by <code>ListUpdates</code> in paginated output. When you use this parameter, <code>ListUpdates</code> returns only <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. You can see the remaining results of the initial request by sending another <code>ListUpdates</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If you don't use this parameter, <code>ListUpdates</code> returns up to 100 results and a <code>nextToken</code> value if applicable.</p>
     #[serde(rename = "maxResults")]
@@ -269,8 +308,16 @@ pub struct ListUpdatesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListUpdatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUpdatesResponse {
     /// <p>The <code>nextToken</code> value to include in a future <code>ListUpdates</code> request. When the results of a <code>ListUpdates</code> request exceed <code>maxResults</code>, you can use this value to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -306,7 +353,7 @@ pub struct Logging {
 
 /// <p>An object representing the <a href="https://openid.net/connect/">OpenID Connect</a> identity provider information for the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OIDC {
     /// <p>The issuer URL for the OpenID Connect identity provider.</p>
     #[serde(rename = "issuer")]
@@ -315,6 +362,7 @@ pub struct OIDC {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource to which to add tags. Currently, the supported resources are Amazon EKS clusters.</p>
     #[serde(rename = "resourceArn")]
@@ -324,11 +372,27 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource from which to delete tags. Currently, the supported resources are Amazon EKS clusters.</p>
     #[serde(rename = "resourceArn")]
@@ -338,13 +402,28 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 /// <p>An object representing an asynchronous update.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Update {
     /// <p>The Unix epoch timestamp in seconds for when the update was created.</p>
     #[serde(rename = "createdAt")]
@@ -373,6 +452,7 @@ pub struct Update {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateClusterConfigRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientRequestToken")]
@@ -391,7 +471,7 @@ pub struct UpdateClusterConfigRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateClusterConfigResponse {
     #[serde(rename = "update")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -399,6 +479,7 @@ pub struct UpdateClusterConfigResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateClusterVersionRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientRequestToken")]
@@ -413,7 +494,7 @@ pub struct UpdateClusterVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateClusterVersionResponse {
     /// <p>The full description of the specified update</p>
     #[serde(rename = "update")]
@@ -423,7 +504,7 @@ pub struct UpdateClusterVersionResponse {
 
 /// <p>An object representing the details of an update request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateParam {
     /// <p>The keys associated with an update request.</p>
     #[serde(rename = "type")]
@@ -437,6 +518,7 @@ pub struct UpdateParam {
 
 /// <p>An object representing the VPC configuration to use for an Amazon EKS cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VpcConfigRequest {
     /// <p>Set this value to <code>true</code> to enable private access for your cluster's Kubernetes API server endpoint. If you enable private access, Kubernetes API requests from within your cluster's VPC use the private VPC endpoint. The default value for this parameter is <code>false</code>, which disables private access for your Kubernetes API server. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/cluster-endpoint.html">Amazon EKS Cluster Endpoint Access Control</a> in the <i> <i>Amazon EKS User Guide</i> </i>.</p>
     #[serde(rename = "endpointPrivateAccess")]
@@ -458,7 +540,7 @@ pub struct VpcConfigRequest {
 
 /// <p>An object representing an Amazon EKS cluster VPC configuration response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VpcConfigResponse {
     /// <p>This parameter indicates whether the Amazon EKS private API server endpoint is enabled. If the Amazon EKS private API server endpoint is enabled, Kubernetes API requests that originate from within your cluster's VPC use the private VPC endpoint instead of traversing the internet.</p>
     #[serde(rename = "endpointPrivateAccess")]
@@ -528,7 +610,12 @@ impl CreateClusterError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -587,7 +674,12 @@ impl DeleteClusterError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DeleteClusterError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -639,7 +731,12 @@ impl DescribeClusterError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DescribeClusterError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -690,7 +787,12 @@ impl DescribeUpdateError {
                 "ServerException" => {
                     return RusotoError::Service(DescribeUpdateError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -741,7 +843,12 @@ impl ListClustersError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(ListClustersError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -782,7 +889,12 @@ impl ListTagsForResourceError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -831,7 +943,12 @@ impl ListUpdatesError {
                 "ServerException" => {
                     return RusotoError::Service(ListUpdatesError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -872,7 +989,12 @@ impl TagResourceError {
                 "NotFoundException" => {
                     return RusotoError::Service(TagResourceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -911,7 +1033,12 @@ impl UntagResourceError {
                 "NotFoundException" => {
                     return RusotoError::Service(UntagResourceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -974,7 +1101,12 @@ impl UpdateClusterConfigError {
                 "ServerException" => {
                     return RusotoError::Service(UpdateClusterConfigError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1041,7 +1173,12 @@ impl UpdateClusterVersionError {
                 "ServerException" => {
                     return RusotoError::Service(UpdateClusterVersionError::Server(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1133,6 +1270,211 @@ pub trait Eks {
         input: UpdateClusterVersionRequest,
     ) -> RusotoFuture<UpdateClusterVersionResponse, UpdateClusterVersionError>;
 }
+const _: Option<&dyn Eks> = None;
+impl<'a, T: Eks + ?Sized> Eks for &'a T {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResponse, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_update(
+        &self,
+        input: DescribeUpdateRequest,
+    ) -> RusotoFuture<DescribeUpdateResponse, DescribeUpdateError> {
+        (**self).describe_update(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_updates(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> RusotoFuture<ListUpdatesResponse, ListUpdatesError> {
+        (**self).list_updates(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster_config(
+        &self,
+        input: UpdateClusterConfigRequest,
+    ) -> RusotoFuture<UpdateClusterConfigResponse, UpdateClusterConfigError> {
+        (**self).update_cluster_config(input)
+    }
+    fn update_cluster_version(
+        &self,
+        input: UpdateClusterVersionRequest,
+    ) -> RusotoFuture<UpdateClusterVersionResponse, UpdateClusterVersionError> {
+        (**self).update_cluster_version(input)
+    }
+}
+impl<T: Eks + ?Sized> Eks for Box<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResponse, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_update(
+        &self,
+        input: DescribeUpdateRequest,
+    ) -> RusotoFuture<DescribeUpdateResponse, DescribeUpdateError> {
+        (**self).describe_update(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_updates(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> RusotoFuture<ListUpdatesResponse, ListUpdatesError> {
+        (**self).list_updates(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster_config(
+        &self,
+        input: UpdateClusterConfigRequest,
+    ) -> RusotoFuture<UpdateClusterConfigResponse, UpdateClusterConfigError> {
+        (**self).update_cluster_config(input)
+    }
+    fn update_cluster_version(
+        &self,
+        input: UpdateClusterVersionRequest,
+    ) -> RusotoFuture<UpdateClusterVersionResponse, UpdateClusterVersionError> {
+        (**self).update_cluster_version(input)
+    }
+}
+impl<T: Eks + ?Sized> Eks for ::std::sync::Arc<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResponse, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_update(
+        &self,
+        input: DescribeUpdateRequest,
+    ) -> RusotoFuture<DescribeUpdateResponse, DescribeUpdateError> {
+        (**self).describe_update(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_updates(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> RusotoFuture<ListUpdatesResponse, ListUpdatesError> {
+        (**self).list_updates(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster_config(
+        &self,
+        input: UpdateClusterConfigRequest,
+    ) -> RusotoFuture<UpdateClusterConfigResponse, UpdateClusterConfigError> {
+        (**self).update_cluster_config(input)
+    }
+    fn update_cluster_version(
+        &self,
+        input: UpdateClusterVersionRequest,
+    ) -> RusotoFuture<UpdateClusterVersionResponse, UpdateClusterVersionError> {
+        (**self).update_cluster_version(input)
+    }
+}
 /// A client for the Amazon EKS API.
 #[derive(Clone)]
 pub struct EksClient {
@@ -1176,9 +1518,19 @@ impl Eks for EksClient {
         &self,
         input: CreateClusterRequest,
     ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateCluster");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1188,6 +1540,7 @@ impl Eks for EksClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateCluster")
                         .deserialize::<CreateClusterResponse, _>()?;
 
                     Ok(result)
@@ -1211,12 +1564,16 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteCluster");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteCluster")
                         .deserialize::<DeleteClusterResponse, _>()?;
 
                     Ok(result)
@@ -1240,12 +1597,16 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeCluster");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeCluster")
                         .deserialize::<DescribeClusterResponse, _>()?;
 
                     Ok(result)
@@ -1273,12 +1634,17 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeUpdate");
+        request.add_resource_id("name", &input.name.to_string());
+        request.add_resource_id("updateId", &input.update_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeUpdate")
                         .deserialize::<DescribeUpdateResponse, _>()?;
 
                     Ok(result)
@@ -1299,9 +1665,19 @@ impl Eks for EksClient {
         &self,
         input: ListClustersRequest,
     ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListClusters");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1317,6 +1693,7 @@ impl Eks for EksClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListClusters")
                         .deserialize::<ListClustersResponse, _>()?;
 
                     Ok(result)
@@ -1340,12 +1717,16 @@ impl Eks for EksClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
                         .deserialize::<ListTagsForResourceResponse, _>()?;
 
                     Ok(result)
@@ -1365,9 +1746,20 @@ impl Eks for EksClient {
         &self,
         input: ListUpdatesRequest,
     ) -> RusotoFuture<ListUpdatesResponse, ListUpdatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListUpdates");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1383,6 +1775,7 @@ impl Eks for EksClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListUpdates")
                         .deserialize::<ListUpdatesResponse, _>()?;
 
                     Ok(result)
@@ -1403,9 +1796,19 @@ impl Eks for EksClient {
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1415,6 +1818,7 @@ impl Eks for EksClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
                         .deserialize::<TagResourceResponse, _>()?;
 
                     Ok(result)
@@ -1435,9 +1839,19 @@ impl Eks for EksClient {
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1450,6 +1864,7 @@ impl Eks for EksClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
                         .deserialize::<UntagResourceResponse, _>()?;
 
                     Ok(result)
@@ -1473,6 +1888,9 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/update-config", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateClusterConfig");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1482,6 +1900,7 @@ impl Eks for EksClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateClusterConfig")
                         .deserialize::<UpdateClusterConfigResponse, _>()?;
 
                     Ok(result)
@@ -1504,6 +1923,9 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateClusterVersion");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1513,6 +1935,7 @@ impl Eks for EksClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateClusterVersion")
                         .deserialize::<UpdateClusterVersionResponse, _>()?;
 
                     Ok(result)
@@ -1527,3 +1950,396 @@ impl Eks for EksClient {
         })
     }
 }
+
+/// A mock of the Amazon EKS API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockEks {
+    create_cluster: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterRequest,
+                )
+                    -> ::std::result::Result<CreateClusterResponse, RusotoError<CreateClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_cluster: Option<
+        Box<
+            dyn Fn(
+                    DeleteClusterRequest,
+                )
+                    -> ::std::result::Result<DeleteClusterResponse, RusotoError<DeleteClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterRequest,
+                ) -> ::std::result::Result<
+                    DescribeClusterResponse,
+                    RusotoError<DescribeClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_update: Option<
+        Box<
+            dyn Fn(
+                    DescribeUpdateRequest,
+                ) -> ::std::result::Result<
+                    DescribeUpdateResponse,
+                    RusotoError<DescribeUpdateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_clusters: Option<
+        Box<
+            dyn Fn(
+                    ListClustersRequest,
+                )
+                    -> ::std::result::Result<ListClustersResponse, RusotoError<ListClustersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_updates: Option<
+        Box<
+            dyn Fn(
+                    ListUpdatesRequest,
+                )
+                    -> ::std::result::Result<ListUpdatesResponse, RusotoError<ListUpdatesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_cluster_config: Option<
+        Box<
+            dyn Fn(
+                    UpdateClusterConfigRequest,
+                ) -> ::std::result::Result<
+                    UpdateClusterConfigResponse,
+                    RusotoError<UpdateClusterConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_cluster_version: Option<
+        Box<
+            dyn Fn(
+                    UpdateClusterVersionRequest,
+                ) -> ::std::result::Result<
+                    UpdateClusterVersionResponse,
+                    RusotoError<UpdateClusterVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockEks {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockEks {
+        Default::default()
+    }
+
+    /// Answers `create_cluster` with what `handler` returns for its input.
+    pub fn with_create_cluster<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                CreateClusterRequest,
+            )
+                -> ::std::result::Result<CreateClusterResponse, RusotoError<CreateClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cluster` with what `handler` returns for its input.
+    pub fn with_delete_cluster<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                DeleteClusterRequest,
+            )
+                -> ::std::result::Result<DeleteClusterResponse, RusotoError<DeleteClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster` with what `handler` returns for its input.
+    pub fn with_describe_cluster<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                DescribeClusterRequest,
+            )
+                -> ::std::result::Result<DescribeClusterResponse, RusotoError<DescribeClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_update` with what `handler` returns for its input.
+    pub fn with_describe_update<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                DescribeUpdateRequest,
+            )
+                -> ::std::result::Result<DescribeUpdateResponse, RusotoError<DescribeUpdateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_update = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_clusters` with what `handler` returns for its input.
+    pub fn with_list_clusters<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                ListClustersRequest,
+            )
+                -> ::std::result::Result<ListClustersResponse, RusotoError<ListClustersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_updates` with what `handler` returns for its input.
+    pub fn with_list_updates<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                ListUpdatesRequest,
+            )
+                -> ::std::result::Result<ListUpdatesResponse, RusotoError<ListUpdatesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_updates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_cluster_config` with what `handler` returns for its input.
+    pub fn with_update_cluster_config<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                UpdateClusterConfigRequest,
+            ) -> ::std::result::Result<
+                UpdateClusterConfigResponse,
+                RusotoError<UpdateClusterConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_cluster_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_cluster_version` with what `handler` returns for its input.
+    pub fn with_update_cluster_version<F>(mut self, handler: F) -> MockEks
+    where
+        F: Fn(
+                UpdateClusterVersionRequest,
+            ) -> ::std::result::Result<
+                UpdateClusterVersionResponse,
+                RusotoError<UpdateClusterVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_cluster_version = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Eks for MockEks {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        match self.create_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for create_cluster"),
+        }
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        match self.delete_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for delete_cluster"),
+        }
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResponse, DescribeClusterError> {
+        match self.describe_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for describe_cluster"),
+        }
+    }
+    fn describe_update(
+        &self,
+        input: DescribeUpdateRequest,
+    ) -> RusotoFuture<DescribeUpdateResponse, DescribeUpdateError> {
+        match self.describe_update {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for describe_update"),
+        }
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        match self.list_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for list_clusters"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_updates(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> RusotoFuture<ListUpdatesResponse, ListUpdatesError> {
+        match self.list_updates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for list_updates"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for untag_resource"),
+        }
+    }
+    fn update_cluster_config(
+        &self,
+        input: UpdateClusterConfigRequest,
+    ) -> RusotoFuture<UpdateClusterConfigResponse, UpdateClusterConfigError> {
+        match self.update_cluster_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for update_cluster_config"),
+        }
+    }
+    fn update_cluster_version(
+        &self,
+        input: UpdateClusterVersionRequest,
+    ) -> RusotoFuture<UpdateClusterVersionResponse, UpdateClusterVersionError> {
+        match self.update_cluster_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEks has no handler for update_cluster_version"),
+        }
+    }
+}
@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -35,6 +36,8 @@ use xml::EventReader;
 
 /// <p>Represents the input of an AddTagsToResource operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToResourceMessage {
     /// <p>The Amazon Resource Name (ARN) of the resource to which the tags are to be added, for example <code>arn:aws:elasticache:us-west-2:0123456789:cluster:myCluster</code> or <code>arn:aws:elasticache:us-west-2:0123456789:snapshot:mySnapshot</code>. ElastiCache resources are <i>cluster</i> and <i>snapshot</i>.</p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     pub resource_name: String,
@@ -69,6 +72,8 @@ impl AllowedNodeGroupIdDeserializer {
 }
 /// <p>Represents the allowed node types you can use to modify your cluster or replication group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AllowedNodeTypeModificationsMessage {
     /// <p>A string list, each element of which specifies a cache node type which you can use to scale your cluster or replication group.</p> <p>When scaling down on a Redis cluster or replication group using <code>ModifyCacheCluster</code> or <code>ModifyReplicationGroup</code>, use a value from this list for the <code>CacheNodeType</code> parameter.</p>
     pub scale_down_modifications: Option<Vec<String>>,
@@ -107,6 +112,8 @@ impl AllowedNodeTypeModificationsMessageDeserializer {
 }
 /// <p>Represents the input of an AuthorizeCacheSecurityGroupIngress operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AuthorizeCacheSecurityGroupIngressMessage {
     /// <p>The cache security group that allows network ingress.</p>
     pub cache_security_group_name: String,
@@ -141,6 +148,8 @@ impl AuthorizeCacheSecurityGroupIngressMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AuthorizeCacheSecurityGroupIngressResult {
     pub cache_security_group: Option<CacheSecurityGroup>,
 }
@@ -184,6 +193,8 @@ impl AutomaticFailoverStatusDeserializer {
 }
 /// <p>Describes an Availability Zone in which the cluster is launched.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AvailabilityZone {
     /// <p>The name of the Availability Zone.</p>
     pub name: Option<String>,
@@ -237,6 +248,8 @@ impl AvailabilityZonesListSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchApplyUpdateActionMessage {
     /// <p>The replication group IDs</p>
     pub replication_group_ids: Vec<String>,
@@ -244,6 +257,17 @@ pub struct BatchApplyUpdateActionMessage {
     pub service_update_name: String,
 }
 
+impl Validate for BatchApplyUpdateActionMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "replication_group_ids"),
+            self.replication_group_ids.len(),
+            None,
+            Some(20),
+        );
+    }
+}
+
 /// Serialize `BatchApplyUpdateActionMessage` contents to a `SignedRequest`.
 struct BatchApplyUpdateActionMessageSerializer;
 impl BatchApplyUpdateActionMessageSerializer {
@@ -266,6 +290,8 @@ impl BatchApplyUpdateActionMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchStopUpdateActionMessage {
     /// <p>The replication group IDs</p>
     pub replication_group_ids: Vec<String>,
@@ -273,6 +299,17 @@ pub struct BatchStopUpdateActionMessage {
     pub service_update_name: String,
 }
 
+impl Validate for BatchStopUpdateActionMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "replication_group_ids"),
+            self.replication_group_ids.len(),
+            None,
+            Some(20),
+        );
+    }
+}
+
 /// Serialize `BatchStopUpdateActionMessage` contents to a `SignedRequest`.
 struct BatchStopUpdateActionMessageSerializer;
 impl BatchStopUpdateActionMessageSerializer {
@@ -299,7 +336,7 @@ impl BooleanDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -310,7 +347,7 @@ impl BooleanOptionalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -318,6 +355,8 @@ impl BooleanOptionalDeserializer {
 }
 /// <p>Contains all of the attributes of a specific cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheCluster {
     /// <p>A flag that enables encryption at-rest when set to <code>true</code>.</p> <p>You cannot modify the value of <code>AtRestEncryptionEnabled</code> after the cluster is created. To enable at-rest encryption on a cluster you must set <code>AtRestEncryptionEnabled</code> to <code>true</code> when you create a cluster.</p> <p> <b>Required:</b> Only available when creating a replication group in an Amazon VPC using redis version <code>3.2.6</code>, <code>4.x</code> or later.</p> <p>Default: <code>false</code> </p>
     pub at_rest_encryption_enabled: Option<bool>,
@@ -552,6 +591,8 @@ impl CacheClusterListDeserializer {
 }
 /// <p>Represents the output of a <code>DescribeCacheClusters</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheClusterMessage {
     /// <p>A list of clusters. Each item in the list contains detailed information about one cluster.</p>
     pub cache_clusters: Option<Vec<CacheCluster>>,
@@ -584,6 +625,8 @@ impl CacheClusterMessageDeserializer {
 }
 /// <p>Provides all of the details about a particular cache engine version.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheEngineVersion {
     /// <p>The description of the cache engine.</p>
     pub cache_engine_description: Option<String>,
@@ -659,6 +702,8 @@ impl CacheEngineVersionListDeserializer {
 }
 /// <p>Represents the output of a <a>DescribeCacheEngineVersions</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheEngineVersionMessage {
     /// <p>A list of cache engine version details. Each element in the list contains detailed information about one cache engine version.</p>
     pub cache_engine_versions: Option<Vec<CacheEngineVersion>>,
@@ -698,6 +743,8 @@ impl CacheEngineVersionMessageDeserializer {
 }
 /// <p><p>Represents an individual cache node within a cluster. Each cache node runs its own instance of the cluster&#39;s protocol-compliant caching software - either Memcached or Redis.</p> <p>The following node types are supported by ElastiCache. Generally speaking, the current generation types provide more memory and computational power at lower cost when compared to their equivalent previous generation counterparts.</p> <ul> <li> <p>General purpose:</p> <ul> <li> <p>Current generation: </p> <p> <b>M5 node types:</b> <code>cache.m5.large</code>, <code>cache.m5.xlarge</code>, <code>cache.m5.2xlarge</code>, <code>cache.m5.4xlarge</code>, <code>cache.m5.12xlarge</code>, <code>cache.m5.24xlarge</code> </p> <p> <b>M4 node types:</b> <code>cache.m4.large</code>, <code>cache.m4.xlarge</code>, <code>cache.m4.2xlarge</code>, <code>cache.m4.4xlarge</code>, <code>cache.m4.10xlarge</code> </p> <p> <b>T2 node types:</b> <code>cache.t2.micro</code>, <code>cache.t2.small</code>, <code>cache.t2.medium</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>T1 node types:</b> <code>cache.t1.micro</code> </p> <p> <b>M1 node types:</b> <code>cache.m1.small</code>, <code>cache.m1.medium</code>, <code>cache.m1.large</code>, <code>cache.m1.xlarge</code> </p> <p> <b>M3 node types:</b> <code>cache.m3.medium</code>, <code>cache.m3.large</code>, <code>cache.m3.xlarge</code>, <code>cache.m3.2xlarge</code> </p> </li> </ul> </li> <li> <p>Compute optimized:</p> <ul> <li> <p>Previous generation: (not recommended)</p> <p> <b>C1 node types:</b> <code>cache.c1.xlarge</code> </p> </li> </ul> </li> <li> <p>Memory optimized:</p> <ul> <li> <p>Current generation: </p> <p> <b>R5 node types:</b> <code>cache.r5.large</code>, <code>cache.r5.xlarge</code>, <code>cache.r5.2xlarge</code>, <code>cache.r5.4xlarge</code>, <code>cache.r5.12xlarge</code>, <code>cache.r5.24xlarge</code> </p> <p> <b>R4 node types:</b> <code>cache.r4.large</code>, <code>cache.r4.xlarge</code>, <code>cache.r4.2xlarge</code>, <code>cache.r4.4xlarge</code>, <code>cache.r4.8xlarge</code>, <code>cache.r4.16xlarge</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>M2 node types:</b> <code>cache.m2.xlarge</code>, <code>cache.m2.2xlarge</code>, <code>cache.m2.4xlarge</code> </p> <p> <b>R3 node types:</b> <code>cache.r3.large</code>, <code>cache.r3.xlarge</code>, <code>cache.r3.2xlarge</code>, <code>cache.r3.4xlarge</code>, <code>cache.r3.8xlarge</code> </p> </li> </ul> </li> </ul> <p> <b>Additional node type info</b> </p> <ul> <li> <p>All current generation instance types are created in Amazon VPC by default.</p> </li> <li> <p>Redis append-only files (AOF) are not supported for T1 or T2 instances.</p> </li> <li> <p>Redis Multi-AZ with automatic failover is not supported on T1 instances.</p> </li> <li> <p>Redis configuration variables <code>appendonly</code> and <code>appendfsync</code> are not supported on Redis version 2.8.22 and later.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheNode {
     /// <p>The date and time when the cache node was created.</p>
     pub cache_node_create_time: Option<String>,
@@ -811,6 +858,8 @@ impl CacheNodeListDeserializer {
 }
 /// <p>A parameter that has a different value for each cache node type it is applied to. For example, in a Redis cluster, a <code>cache.m1.large</code> cache node type would have a larger <code>maxmemory</code> value than a <code>cache.m1.small</code> type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheNodeTypeSpecificParameter {
     /// <p>The valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
@@ -913,6 +962,8 @@ impl CacheNodeTypeSpecificParametersListDeserializer {
 }
 /// <p>A value that applies only to a certain cache node type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheNodeTypeSpecificValue {
     /// <p>The cache node type for which this value applies.</p>
     pub cache_node_type: Option<String>,
@@ -968,6 +1019,8 @@ impl CacheNodeTypeSpecificValueListDeserializer {
 }
 /// <p>Represents the output of a <code>CreateCacheParameterGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheParameterGroup {
     /// <p>The name of the cache parameter group family that this cache parameter group is compatible with.</p> <p>Valid values are: <code>memcached1.4</code> | <code>memcached1.5</code> | <code>redis2.6</code> | <code>redis2.8</code> | <code>redis3.2</code> | <code>redis4.0</code> | <code>redis5.0</code> | </p>
     pub cache_parameter_group_family: Option<String>,
@@ -1009,6 +1062,8 @@ impl CacheParameterGroupDeserializer {
 }
 /// <p>Represents the output of a <code>DescribeCacheParameters</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheParameterGroupDetails {
     /// <p>A list of parameters specific to a particular cache node type. Each element in the list contains detailed information about one parameter.</p>
     pub cache_node_type_specific_parameters: Option<Vec<CacheNodeTypeSpecificParameter>>,
@@ -1077,6 +1132,8 @@ impl CacheParameterGroupListDeserializer {
 }
 /// <p><p>Represents the output of one of the following operations:</p> <ul> <li> <p> <code>ModifyCacheParameterGroup</code> </p> </li> <li> <p> <code>ResetCacheParameterGroup</code> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheParameterGroupNameMessage {
     /// <p>The name of the cache parameter group.</p>
     pub cache_parameter_group_name: Option<String>,
@@ -1109,6 +1166,8 @@ impl CacheParameterGroupNameMessageDeserializer {
 }
 /// <p>Status of the cache parameter group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheParameterGroupStatus {
     /// <p>A list of the cache node IDs which need to be rebooted for parameter changes to be applied. A node ID is a numeric identifier (0001, 0002, etc.).</p>
     pub cache_node_ids_to_reboot: Option<Vec<String>>,
@@ -1159,6 +1218,8 @@ impl CacheParameterGroupStatusDeserializer {
 }
 /// <p>Represents the output of a <code>DescribeCacheParameterGroups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheParameterGroupsMessage {
     /// <p>A list of cache parameter groups. Each element in the list contains detailed information about one cache parameter group.</p>
     pub cache_parameter_groups: Option<Vec<CacheParameterGroup>>,
@@ -1198,6 +1259,8 @@ impl CacheParameterGroupsMessageDeserializer {
 }
 /// <p><p>Represents the output of one of the following operations:</p> <ul> <li> <p> <code>AuthorizeCacheSecurityGroupIngress</code> </p> </li> <li> <p> <code>CreateCacheSecurityGroup</code> </p> </li> <li> <p> <code>RevokeCacheSecurityGroupIngress</code> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheSecurityGroup {
     /// <p>The name of the cache security group.</p>
     pub cache_security_group_name: Option<String>,
@@ -1243,6 +1306,8 @@ impl CacheSecurityGroupDeserializer {
 }
 /// <p>Represents a cluster's status within a particular cache security group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheSecurityGroupMembership {
     /// <p>The name of the cache security group.</p>
     pub cache_security_group_name: Option<String>,
@@ -1300,6 +1365,8 @@ impl CacheSecurityGroupMembershipListDeserializer {
 }
 /// <p>Represents the output of a <code>DescribeCacheSecurityGroups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheSecurityGroupMessage {
     /// <p>A list of cache security groups. Each element in the list contains detailed information about one group.</p>
     pub cache_security_groups: Option<Vec<CacheSecurityGroup>>,
@@ -1371,6 +1438,8 @@ impl CacheSecurityGroupsDeserializer {
 }
 /// <p><p>Represents the output of one of the following operations:</p> <ul> <li> <p> <code>CreateCacheSubnetGroup</code> </p> </li> <li> <p> <code>ModifyCacheSubnetGroup</code> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheSubnetGroup {
     /// <p>The description of the cache subnet group.</p>
     pub cache_subnet_group_description: Option<String>,
@@ -1419,6 +1488,8 @@ impl CacheSubnetGroupDeserializer {
 }
 /// <p>Represents the output of a <code>DescribeCacheSubnetGroups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CacheSubnetGroupMessage {
     /// <p>A list of cache subnet groups. Each element in the list contains detailed information about one group.</p>
     pub cache_subnet_groups: Option<Vec<CacheSubnetGroup>>,
@@ -1503,6 +1574,8 @@ impl ClusterIdListDeserializer {
 }
 /// <p>Node group (shard) configuration options when adding or removing replicas. Each node group (shard) configuration has the following members: NodeGroupId, NewReplicaCount, and PreferredAvailabilityZones. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigureShard {
     /// <p><p>The number of replicas you want in this node group at the end of this operation. The maximum value for <code>NewReplicaCount</code> is 5. The minimum value depends upon the type of Redis replication group you are working with.</p> <p>The minimum number of replicas in a shard or replication group is:</p> <ul> <li> <p>Redis (cluster mode disabled)</p> <ul> <li> <p>If Multi-AZ with Automatic Failover is enabled: 1</p> </li> <li> <p>If Multi-AZ with Automatic Failover is not enable: 0</p> </li> </ul> </li> <li> <p>Redis (cluster mode enabled): 0 (though you will not be able to failover to a replica if your primary node fails)</p> </li> </ul></p>
     pub new_replica_count: i64,
@@ -1512,6 +1585,22 @@ pub struct ConfigureShard {
     pub preferred_availability_zones: Option<Vec<String>>,
 }
 
+impl Validate for ConfigureShard {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "node_group_id"),
+            self.node_group_id.chars().count(),
+            Some(1),
+            Some(4),
+        );
+        errors.check_pattern(
+            &field_path(path, "node_group_id"),
+            &self.node_group_id,
+            "\\d+",
+        );
+    }
+}
+
 /// Serialize `ConfigureShard` contents to a `SignedRequest`.
 struct ConfigureShardSerializer;
 impl ConfigureShardSerializer {
@@ -1538,6 +1627,8 @@ impl ConfigureShardSerializer {
 
 /// <p>Represents the input of a <code>CopySnapshotMessage</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopySnapshotMessage {
     /// <p>The ID of the KMS key used to encrypt the target snapshot.</p>
     pub kms_key_id: Option<String>,
@@ -1576,6 +1667,8 @@ impl CopySnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopySnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
@@ -1600,6 +1693,8 @@ impl CopySnapshotResultDeserializer {
 }
 /// <p>Represents the input of a CreateCacheCluster operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCacheClusterMessage {
     /// <p>Specifies whether the nodes in this Memcached cluster are created in a single Availability Zone or created across multiple Availability Zones in the cluster's region.</p> <p>This parameter is only supported for Memcached clusters.</p> <p>If the <code>AZMode</code> and <code>PreferredAvailabilityZones</code> are not specified, ElastiCache assumes <code>single-az</code> mode.</p>
     pub az_mode: Option<String>,
@@ -1769,6 +1864,8 @@ impl CreateCacheClusterMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCacheClusterResult {
     pub cache_cluster: Option<CacheCluster>,
 }
@@ -1800,6 +1897,8 @@ impl CreateCacheClusterResultDeserializer {
 }
 /// <p>Represents the input of a <code>CreateCacheParameterGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCacheParameterGroupMessage {
     /// <p>The name of the cache parameter group family that the cache parameter group can be used with.</p> <p>Valid values are: <code>memcached1.4</code> | <code>memcached1.5</code> | <code>redis2.6</code> | <code>redis2.8</code> | <code>redis3.2</code> | <code>redis4.0</code> | <code>redis5.0</code> | </p>
     pub cache_parameter_group_family: String,
@@ -1831,6 +1930,8 @@ impl CreateCacheParameterGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCacheParameterGroupResult {
     pub cache_parameter_group: Option<CacheParameterGroup>,
 }
@@ -1863,6 +1964,8 @@ impl CreateCacheParameterGroupResultDeserializer {
 }
 /// <p>Represents the input of a <code>CreateCacheSecurityGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCacheSecurityGroupMessage {
     /// <p>A name for the cache security group. This value is stored as a lowercase string.</p> <p>Constraints: Must contain no more than 255 alphanumeric characters. Cannot be the word "Default".</p> <p>Example: <code>mysecuritygroup</code> </p>
     pub cache_security_group_name: String,
@@ -1888,6 +1991,8 @@ impl CreateCacheSecurityGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCacheSecurityGroupResult {
     pub cache_security_group: Option<CacheSecurityGroup>,
 }
@@ -1920,6 +2025,8 @@ impl CreateCacheSecurityGroupResultDeserializer {
 }
 /// <p>Represents the input of a <code>CreateCacheSubnetGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCacheSubnetGroupMessage {
     /// <p>A description for the cache subnet group.</p>
     pub cache_subnet_group_description: String,
@@ -1955,6 +2062,8 @@ impl CreateCacheSubnetGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCacheSubnetGroupResult {
     pub cache_subnet_group: Option<CacheSubnetGroup>,
 }
@@ -1986,6 +2095,8 @@ impl CreateCacheSubnetGroupResultDeserializer {
 }
 /// <p>Represents the input of a <code>CreateReplicationGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReplicationGroupMessage {
     /// <p>A flag that enables encryption at rest when set to <code>true</code>.</p> <p>You cannot modify the value of <code>AtRestEncryptionEnabled</code> after the replication group is created. To enable encryption at rest on a replication group you must set <code>AtRestEncryptionEnabled</code> to <code>true</code> when you create the replication group. </p> <p> <b>Required:</b> Only available when creating a replication group in an Amazon VPC using redis version <code>3.2.6</code>, <code>4.x</code> or later.</p> <p>Default: <code>false</code> </p>
     pub at_rest_encryption_enabled: Option<bool>,
@@ -2047,6 +2158,17 @@ pub struct CreateReplicationGroupMessage {
     pub transit_encryption_enabled: Option<bool>,
 }
 
+impl Validate for CreateReplicationGroupMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.node_group_configuration {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "node_group_configuration"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateReplicationGroupMessage` contents to a `SignedRequest`.
 struct CreateReplicationGroupMessageSerializer;
 impl CreateReplicationGroupMessageSerializer {
@@ -2199,6 +2321,8 @@ impl CreateReplicationGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReplicationGroupResult {
     pub replication_group: Option<ReplicationGroup>,
 }
@@ -2230,6 +2354,8 @@ impl CreateReplicationGroupResultDeserializer {
 }
 /// <p>Represents the input of a <code>CreateSnapshot</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSnapshotMessage {
     /// <p>The identifier of an existing cluster. The snapshot is created from this cluster.</p>
     pub cache_cluster_id: Option<String>,
@@ -2264,6 +2390,8 @@ impl CreateSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
@@ -2287,6 +2415,8 @@ impl CreateSnapshotResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DecreaseReplicaCountMessage {
     /// <p>If <code>True</code>, the number of replica nodes is decreased immediately. <code>ApplyImmediately=False</code> is not currently supported.</p>
     pub apply_immediately: bool,
@@ -2300,6 +2430,17 @@ pub struct DecreaseReplicaCountMessage {
     pub replication_group_id: String,
 }
 
+impl Validate for DecreaseReplicaCountMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.replica_configuration {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "replica_configuration"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `DecreaseReplicaCountMessage` contents to a `SignedRequest`.
 struct DecreaseReplicaCountMessageSerializer;
 impl DecreaseReplicaCountMessageSerializer {
@@ -2338,6 +2479,8 @@ impl DecreaseReplicaCountMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DecreaseReplicaCountResult {
     pub replication_group: Option<ReplicationGroup>,
 }
@@ -2369,6 +2512,8 @@ impl DecreaseReplicaCountResultDeserializer {
 }
 /// <p>Represents the input of a <code>DeleteCacheCluster</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCacheClusterMessage {
     /// <p>The cluster identifier for the cluster to be deleted. This parameter is not case sensitive.</p>
     pub cache_cluster_id: String,
@@ -2399,6 +2544,8 @@ impl DeleteCacheClusterMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCacheClusterResult {
     pub cache_cluster: Option<CacheCluster>,
 }
@@ -2430,6 +2577,8 @@ impl DeleteCacheClusterResultDeserializer {
 }
 /// <p>Represents the input of a <code>DeleteCacheParameterGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCacheParameterGroupMessage {
     /// <p><p>The name of the cache parameter group to delete.</p> <note> <p>The specified cache security group must not be associated with any clusters.</p> </note></p>
     pub cache_parameter_group_name: String,
@@ -2453,6 +2602,8 @@ impl DeleteCacheParameterGroupMessageSerializer {
 
 /// <p>Represents the input of a <code>DeleteCacheSecurityGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCacheSecurityGroupMessage {
     /// <p><p>The name of the cache security group to delete.</p> <note> <p>You cannot delete the default security group.</p> </note></p>
     pub cache_security_group_name: String,
@@ -2476,6 +2627,8 @@ impl DeleteCacheSecurityGroupMessageSerializer {
 
 /// <p>Represents the input of a <code>DeleteCacheSubnetGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCacheSubnetGroupMessage {
     /// <p>The name of the cache subnet group to delete.</p> <p>Constraints: Must contain no more than 255 alphanumeric characters or hyphens.</p>
     pub cache_subnet_group_name: String,
@@ -2499,6 +2652,8 @@ impl DeleteCacheSubnetGroupMessageSerializer {
 
 /// <p>Represents the input of a <code>DeleteReplicationGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReplicationGroupMessage {
     /// <p>The name of a final node group (shard) snapshot. ElastiCache creates the snapshot from the primary node in the cluster, rather than one of the replicas; this is to ensure that it captures the freshest data. After the final snapshot is taken, the replication group is immediately deleted.</p>
     pub final_snapshot_identifier: Option<String>,
@@ -2537,6 +2692,8 @@ impl DeleteReplicationGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReplicationGroupResult {
     pub replication_group: Option<ReplicationGroup>,
 }
@@ -2568,6 +2725,8 @@ impl DeleteReplicationGroupResultDeserializer {
 }
 /// <p>Represents the input of a <code>DeleteSnapshot</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSnapshotMessage {
     /// <p>The name of the snapshot to be deleted.</p>
     pub snapshot_name: String,
@@ -2587,6 +2746,8 @@ impl DeleteSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
@@ -2611,6 +2772,8 @@ impl DeleteSnapshotResultDeserializer {
 }
 /// <p>Represents the input of a <code>DescribeCacheClusters</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCacheClustersMessage {
     /// <p>The user-supplied cluster identifier. If this parameter is specified, only information about that specific cluster is returned. This parameter isn't case sensitive.</p>
     pub cache_cluster_id: Option<String>,
@@ -2656,6 +2819,8 @@ impl DescribeCacheClustersMessageSerializer {
 
 /// <p>Represents the input of a <code>DescribeCacheEngineVersions</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCacheEngineVersionsMessage {
     /// <p><p>The name of a specific cache parameter group family to return details for.</p> <p>Valid values are: <code>memcached1.4</code> | <code>memcached1.5</code> | <code>redis2.6</code> | <code>redis2.8</code> | <code>redis3.2</code> | <code>redis4.0</code> | <code>redis5.0</code> | </p> <p>Constraints:</p> <ul> <li> <p>Must be 1 to 255 alphanumeric characters</p> </li> <li> <p>First character must be a letter</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens</p> </li> </ul></p>
     pub cache_parameter_group_family: Option<String>,
@@ -2706,6 +2871,8 @@ impl DescribeCacheEngineVersionsMessageSerializer {
 
 /// <p>Represents the input of a <code>DescribeCacheParameterGroups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCacheParameterGroupsMessage {
     /// <p>The name of a specific cache parameter group to return details for.</p>
     pub cache_parameter_group_name: Option<String>,
@@ -2741,6 +2908,8 @@ impl DescribeCacheParameterGroupsMessageSerializer {
 
 /// <p>Represents the input of a <code>DescribeCacheParameters</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCacheParametersMessage {
     /// <p>The name of a specific cache parameter group to return details for.</p>
     pub cache_parameter_group_name: String,
@@ -2779,6 +2948,8 @@ impl DescribeCacheParametersMessageSerializer {
 
 /// <p>Represents the input of a <code>DescribeCacheSecurityGroups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCacheSecurityGroupsMessage {
     /// <p>The name of the cache security group to return details for.</p>
     pub cache_security_group_name: Option<String>,
@@ -2814,6 +2985,8 @@ impl DescribeCacheSecurityGroupsMessageSerializer {
 
 /// <p>Represents the input of a <code>DescribeCacheSubnetGroups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCacheSubnetGroupsMessage {
     /// <p>The name of the cache subnet group to return details for.</p>
     pub cache_subnet_group_name: Option<String>,
@@ -2849,6 +3022,8 @@ impl DescribeCacheSubnetGroupsMessageSerializer {
 
 /// <p>Represents the input of a <code>DescribeEngineDefaultParameters</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEngineDefaultParametersMessage {
     /// <p>The name of the cache parameter group family.</p> <p>Valid values are: <code>memcached1.4</code> | <code>memcached1.5</code> | <code>redis2.6</code> | <code>redis2.8</code> | <code>redis3.2</code> | <code>redis4.0</code> | <code>redis5.0</code> | </p>
     pub cache_parameter_group_family: String,
@@ -2881,6 +3056,8 @@ impl DescribeEngineDefaultParametersMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEngineDefaultParametersResult {
     pub engine_defaults: Option<EngineDefaults>,
 }
@@ -2912,6 +3089,8 @@ impl DescribeEngineDefaultParametersResultDeserializer {
 }
 /// <p>Represents the input of a <code>DescribeEvents</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventsMessage {
     /// <p>The number of minutes worth of events to retrieve.</p>
     pub duration: Option<i64>,
@@ -2964,6 +3143,8 @@ impl DescribeEventsMessageSerializer {
 
 /// <p>Represents the input of a <code>DescribeReplicationGroups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReplicationGroupsMessage {
     /// <p>An optional marker returned from a prior request. Use this marker for pagination of results from this operation. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
@@ -2996,6 +3177,8 @@ impl DescribeReplicationGroupsMessageSerializer {
 
 /// <p>Represents the input of a <code>DescribeReservedCacheNodes</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReservedCacheNodesMessage {
     /// <p><p>The cache node type filter value. Use this parameter to show only those reservations matching the specified cache node type.</p> <p>The following node types are supported by ElastiCache. Generally speaking, the current generation types provide more memory and computational power at lower cost when compared to their equivalent previous generation counterparts.</p> <ul> <li> <p>General purpose:</p> <ul> <li> <p>Current generation: </p> <p> <b>M5 node types:</b> <code>cache.m5.large</code>, <code>cache.m5.xlarge</code>, <code>cache.m5.2xlarge</code>, <code>cache.m5.4xlarge</code>, <code>cache.m5.12xlarge</code>, <code>cache.m5.24xlarge</code> </p> <p> <b>M4 node types:</b> <code>cache.m4.large</code>, <code>cache.m4.xlarge</code>, <code>cache.m4.2xlarge</code>, <code>cache.m4.4xlarge</code>, <code>cache.m4.10xlarge</code> </p> <p> <b>T2 node types:</b> <code>cache.t2.micro</code>, <code>cache.t2.small</code>, <code>cache.t2.medium</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>T1 node types:</b> <code>cache.t1.micro</code> </p> <p> <b>M1 node types:</b> <code>cache.m1.small</code>, <code>cache.m1.medium</code>, <code>cache.m1.large</code>, <code>cache.m1.xlarge</code> </p> <p> <b>M3 node types:</b> <code>cache.m3.medium</code>, <code>cache.m3.large</code>, <code>cache.m3.xlarge</code>, <code>cache.m3.2xlarge</code> </p> </li> </ul> </li> <li> <p>Compute optimized:</p> <ul> <li> <p>Previous generation: (not recommended)</p> <p> <b>C1 node types:</b> <code>cache.c1.xlarge</code> </p> </li> </ul> </li> <li> <p>Memory optimized:</p> <ul> <li> <p>Current generation: </p> <p> <b>R5 node types:</b> <code>cache.r5.large</code>, <code>cache.r5.xlarge</code>, <code>cache.r5.2xlarge</code>, <code>cache.r5.4xlarge</code>, <code>cache.r5.12xlarge</code>, <code>cache.r5.24xlarge</code> </p> <p> <b>R4 node types:</b> <code>cache.r4.large</code>, <code>cache.r4.xlarge</code>, <code>cache.r4.2xlarge</code>, <code>cache.r4.4xlarge</code>, <code>cache.r4.8xlarge</code>, <code>cache.r4.16xlarge</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>M2 node types:</b> <code>cache.m2.xlarge</code>, <code>cache.m2.2xlarge</code>, <code>cache.m2.4xlarge</code> </p> <p> <b>R3 node types:</b> <code>cache.r3.large</code>, <code>cache.r3.xlarge</code>, <code>cache.r3.2xlarge</code>, <code>cache.r3.4xlarge</code>, <code>cache.r3.8xlarge</code> </p> </li> </ul> </li> </ul> <p> <b>Additional node type info</b> </p> <ul> <li> <p>All current generation instance types are created in Amazon VPC by default.</p> </li> <li> <p>Redis append-only files (AOF) are not supported for T1 or T2 instances.</p> </li> <li> <p>Redis Multi-AZ with automatic failover is not supported on T1 instances.</p> </li> <li> <p>Redis configuration variables <code>appendonly</code> and <code>appendfsync</code> are not supported on Redis version 2.8.22 and later.</p> </li> </ul></p>
     pub cache_node_type: Option<String>,
@@ -3059,6 +3242,8 @@ impl DescribeReservedCacheNodesMessageSerializer {
 
 /// <p>Represents the input of a <code>DescribeReservedCacheNodesOfferings</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReservedCacheNodesOfferingsMessage {
     /// <p><p>The cache node type filter value. Use this parameter to show only the available offerings matching the specified cache node type.</p> <p>The following node types are supported by ElastiCache. Generally speaking, the current generation types provide more memory and computational power at lower cost when compared to their equivalent previous generation counterparts.</p> <ul> <li> <p>General purpose:</p> <ul> <li> <p>Current generation: </p> <p> <b>M5 node types:</b> <code>cache.m5.large</code>, <code>cache.m5.xlarge</code>, <code>cache.m5.2xlarge</code>, <code>cache.m5.4xlarge</code>, <code>cache.m5.12xlarge</code>, <code>cache.m5.24xlarge</code> </p> <p> <b>M4 node types:</b> <code>cache.m4.large</code>, <code>cache.m4.xlarge</code>, <code>cache.m4.2xlarge</code>, <code>cache.m4.4xlarge</code>, <code>cache.m4.10xlarge</code> </p> <p> <b>T2 node types:</b> <code>cache.t2.micro</code>, <code>cache.t2.small</code>, <code>cache.t2.medium</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>T1 node types:</b> <code>cache.t1.micro</code> </p> <p> <b>M1 node types:</b> <code>cache.m1.small</code>, <code>cache.m1.medium</code>, <code>cache.m1.large</code>, <code>cache.m1.xlarge</code> </p> <p> <b>M3 node types:</b> <code>cache.m3.medium</code>, <code>cache.m3.large</code>, <code>cache.m3.xlarge</code>, <code>cache.m3.2xlarge</code> </p> </li> </ul> </li> <li> <p>Compute optimized:</p> <ul> <li> <p>Previous generation: (not recommended)</p> <p> <b>C1 node types:</b> <code>cache.c1.xlarge</code> </p> </li> </ul> </li> <li> <p>Memory optimized:</p> <ul> <li> <p>Current generation: </p> <p> <b>R5 node types:</b> <code>cache.r5.large</code>, <code>cache.r5.xlarge</code>, <code>cache.r5.2xlarge</code>, <code>cache.r5.4xlarge</code>, <code>cache.r5.12xlarge</code>, <code>cache.r5.24xlarge</code> </p> <p> <b>R4 node types:</b> <code>cache.r4.large</code>, <code>cache.r4.xlarge</code>, <code>cache.r4.2xlarge</code>, <code>cache.r4.4xlarge</code>, <code>cache.r4.8xlarge</code>, <code>cache.r4.16xlarge</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>M2 node types:</b> <code>cache.m2.xlarge</code>, <code>cache.m2.2xlarge</code>, <code>cache.m2.4xlarge</code> </p> <p> <b>R3 node types:</b> <code>cache.r3.large</code>, <code>cache.r3.xlarge</code>, <code>cache.r3.2xlarge</code>, <code>cache.r3.4xlarge</code>, <code>cache.r3.8xlarge</code> </p> </li> </ul> </li> </ul> <p> <b>Additional node type info</b> </p> <ul> <li> <p>All current generation instance types are created in Amazon VPC by default.</p> </li> <li> <p>Redis append-only files (AOF) are not supported for T1 or T2 instances.</p> </li> <li> <p>Redis Multi-AZ with automatic failover is not supported on T1 instances.</p> </li> <li> <p>Redis configuration variables <code>appendonly</code> and <code>appendfsync</code> are not supported on Redis version 2.8.22 and later.</p> </li> </ul></p>
     pub cache_node_type: Option<String>,
@@ -3117,6 +3302,8 @@ impl DescribeReservedCacheNodesOfferingsMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServiceUpdatesMessage {
     /// <p>An optional marker returned from a prior request. Use this marker for pagination of results from this operation. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
@@ -3128,6 +3315,19 @@ pub struct DescribeServiceUpdatesMessage {
     pub service_update_status: Option<Vec<String>>,
 }
 
+impl Validate for DescribeServiceUpdatesMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.service_update_status {
+            errors.check_length(
+                &field_path(path, "service_update_status"),
+                value.len(),
+                None,
+                Some(3),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeServiceUpdatesMessage` contents to a `SignedRequest`.
 struct DescribeServiceUpdatesMessageSerializer;
 impl DescribeServiceUpdatesMessageSerializer {
@@ -3158,6 +3358,8 @@ impl DescribeServiceUpdatesMessageSerializer {
 
 /// <p>Represents the output of a <code>DescribeSnapshots</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSnapshotsListMessage {
     /// <p>An optional marker returned from a prior request. Use this marker for pagination of results from this operation. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
@@ -3194,6 +3396,8 @@ impl DescribeSnapshotsListMessageDeserializer {
 }
 /// <p>Represents the input of a <code>DescribeSnapshotsMessage</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSnapshotsMessage {
     /// <p>A user-supplied cluster identifier. If this parameter is specified, only snapshots associated with that specific cluster are described.</p>
     pub cache_cluster_id: Option<String>,
@@ -3248,6 +3452,8 @@ impl DescribeSnapshotsMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUpdateActionsMessage {
     /// <p>An optional marker returned from a prior request. Use this marker for pagination of results from this operation. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
@@ -3267,6 +3473,35 @@ pub struct DescribeUpdateActionsMessage {
     pub update_action_status: Option<Vec<String>>,
 }
 
+impl Validate for DescribeUpdateActionsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.replication_group_ids {
+            errors.check_length(
+                &field_path(path, "replication_group_ids"),
+                value.len(),
+                None,
+                Some(20),
+            );
+        }
+        if let Some(ref value) = self.service_update_status {
+            errors.check_length(
+                &field_path(path, "service_update_status"),
+                value.len(),
+                None,
+                Some(3),
+            );
+        }
+        if let Some(ref value) = self.update_action_status {
+            errors.check_length(
+                &field_path(path, "update_action_status"),
+                value.len(),
+                None,
+                Some(9),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeUpdateActionsMessage` contents to a `SignedRequest`.
 struct DescribeUpdateActionsMessageSerializer;
 impl DescribeUpdateActionsMessageSerializer {
@@ -3327,7 +3562,7 @@ impl DoubleDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3335,6 +3570,8 @@ impl DoubleDeserializer {
 }
 /// <p>Provides ownership and status information for an Amazon EC2 security group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EC2SecurityGroup {
     /// <p>The name of the Amazon EC2 security group.</p>
     pub ec2_security_group_name: Option<String>,
@@ -3396,6 +3633,8 @@ impl EC2SecurityGroupListDeserializer {
 }
 /// <p>Represents the information required for client programs to connect to a cache node.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Endpoint {
     /// <p>The DNS hostname of the cache node.</p>
     pub address: Option<String>,
@@ -3426,6 +3665,8 @@ impl EndpointDeserializer {
 }
 /// <p>Represents the output of a <code>DescribeEngineDefaultParameters</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EngineDefaults {
     /// <p>A list of parameters specific to a particular cache node type. Each element in the list contains detailed information about one parameter.</p>
     pub cache_node_type_specific_parameters: Option<Vec<CacheNodeTypeSpecificParameter>>,
@@ -3478,6 +3719,8 @@ impl EngineDefaultsDeserializer {
 }
 /// <p>Represents a single occurrence of something interesting within the system. Some examples of events are creating a cluster, adding or removing a cache node, or rebooting a node.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Event {
     /// <p>The date and time when the event occurred.</p>
     pub date: Option<String>,
@@ -3534,6 +3777,8 @@ impl EventListDeserializer {
 }
 /// <p>Represents the output of a <code>DescribeEvents</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventsMessage {
     /// <p>A list of events. Each element in the list contains detailed information about one event.</p>
     pub events: Option<Vec<Event>>,
@@ -3565,6 +3810,8 @@ impl EventsMessageDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IncreaseReplicaCountMessage {
     /// <p>If <code>True</code>, the number of replica nodes is increased immediately. <code>ApplyImmediately=False</code> is not currently supported.</p>
     pub apply_immediately: bool,
@@ -3576,6 +3823,17 @@ pub struct IncreaseReplicaCountMessage {
     pub replication_group_id: String,
 }
 
+impl Validate for IncreaseReplicaCountMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.replica_configuration {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "replica_configuration"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `IncreaseReplicaCountMessage` contents to a `SignedRequest`.
 struct IncreaseReplicaCountMessageSerializer;
 impl IncreaseReplicaCountMessageSerializer {
@@ -3607,6 +3865,8 @@ impl IncreaseReplicaCountMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IncreaseReplicaCountResult {
     pub replication_group: Option<ReplicationGroup>,
 }
@@ -3641,7 +3901,7 @@ impl IntegerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3652,7 +3912,7 @@ impl IntegerOptionalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3672,6 +3932,8 @@ impl KeyListSerializer {
 
 /// <p>The input parameters for the <code>ListAllowedNodeTypeModifications</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAllowedNodeTypeModificationsMessage {
     /// <p><p>The name of the cluster you want to scale up to a larger node instanced type. ElastiCache uses the cluster id to identify the current node type of this cluster and from that to create a list of node types you can scale up to.</p> <important> <p>You must provide a value for either the <code>CacheClusterId</code> or the <code>ReplicationGroupId</code>.</p> </important></p>
     pub cache_cluster_id: Option<String>,
@@ -3699,6 +3961,8 @@ impl ListAllowedNodeTypeModificationsMessageSerializer {
 
 /// <p>The input parameters for the <code>ListTagsForResource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceMessage {
     /// <p>The Amazon Resource Name (ARN) of the resource for which you want the list of tags, for example <code>arn:aws:elasticache:us-west-2:0123456789:cluster:myCluster</code> or <code>arn:aws:elasticache:us-west-2:0123456789:snapshot:mySnapshot</code>.</p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     pub resource_name: String,
@@ -3719,6 +3983,8 @@ impl ListTagsForResourceMessageSerializer {
 
 /// <p>Represents the input of a <code>ModifyCacheCluster</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyCacheClusterMessage {
     /// <p><p>Specifies whether the new nodes in this Memcached cluster are all created in a single Availability Zone or created across multiple Availability Zones.</p> <p>Valid values: <code>single-az</code> | <code>cross-az</code>.</p> <p>This option is only supported for Memcached clusters.</p> <note> <p>You cannot specify <code>single-az</code> if the Memcached cluster already has cache nodes in different Availability Zones. If <code>cross-az</code> is specified, existing Memcached nodes remain in their current Availability Zone.</p> <p>Only newly created nodes are located in different Availability Zones. For instructions on how to move existing Memcached nodes to different Availability Zones, see the <b>Availability Zone Considerations</b> section of <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/mem-ug/CacheNodes.SupportedTypes.html">Cache Node Considerations for Memcached</a>.</p> </note></p>
     pub az_mode: Option<String>,
@@ -3855,6 +4121,8 @@ impl ModifyCacheClusterMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyCacheClusterResult {
     pub cache_cluster: Option<CacheCluster>,
 }
@@ -3886,6 +4154,8 @@ impl ModifyCacheClusterResultDeserializer {
 }
 /// <p>Represents the input of a <code>ModifyCacheParameterGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyCacheParameterGroupMessage {
     /// <p>The name of the cache parameter group to modify.</p>
     pub cache_parameter_group_name: String,
@@ -3916,6 +4186,8 @@ impl ModifyCacheParameterGroupMessageSerializer {
 
 /// <p>Represents the input of a <code>ModifyCacheSubnetGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyCacheSubnetGroupMessage {
     /// <p>A description of the cache subnet group.</p>
     pub cache_subnet_group_description: Option<String>,
@@ -3955,6 +4227,8 @@ impl ModifyCacheSubnetGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyCacheSubnetGroupResult {
     pub cache_subnet_group: Option<CacheSubnetGroup>,
 }
@@ -3986,6 +4260,8 @@ impl ModifyCacheSubnetGroupResultDeserializer {
 }
 /// <p>Represents the input of a <code>ModifyReplicationGroups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyReplicationGroupMessage {
     /// <p>If <code>true</code>, this parameter causes the modifications in this request and any pending modifications to be applied, asynchronously and as soon as possible, regardless of the <code>PreferredMaintenanceWindow</code> setting for the replication group.</p> <p>If <code>false</code>, changes to the nodes in the replication group are applied on the next maintenance reboot, or the next failure reboot, whichever occurs first.</p> <p>Valid values: <code>true</code> | <code>false</code> </p> <p>Default: <code>false</code> </p>
     pub apply_immediately: Option<bool>,
@@ -4124,6 +4400,8 @@ impl ModifyReplicationGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyReplicationGroupResult {
     pub replication_group: Option<ReplicationGroup>,
 }
@@ -4155,6 +4433,8 @@ impl ModifyReplicationGroupResultDeserializer {
 }
 /// <p>Represents the input for a <code>ModifyReplicationGroupShardConfiguration</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyReplicationGroupShardConfigurationMessage {
     /// <p>Indicates that the shard reconfiguration process begins immediately. At present, the only permitted value for this parameter is <code>true</code>.</p> <p>Value: true</p>
     pub apply_immediately: bool,
@@ -4170,6 +4450,31 @@ pub struct ModifyReplicationGroupShardConfigurationMessage {
     pub resharding_configuration: Option<Vec<ReshardingConfiguration>>,
 }
 
+impl Validate for ModifyReplicationGroupShardConfigurationMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.node_groups_to_remove {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "node_groups_to_remove"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(4));
+                errors.check_pattern(&item_path, &item, "\\d+");
+            }
+        }
+        if let Some(ref value) = self.node_groups_to_retain {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "node_groups_to_retain"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(4));
+                errors.check_pattern(&item_path, &item, "\\d+");
+            }
+        }
+        if let Some(ref value) = self.resharding_configuration {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resharding_configuration"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `ModifyReplicationGroupShardConfigurationMessage` contents to a `SignedRequest`.
 struct ModifyReplicationGroupShardConfigurationMessageSerializer;
 impl ModifyReplicationGroupShardConfigurationMessageSerializer {
@@ -4220,6 +4525,8 @@ impl ModifyReplicationGroupShardConfigurationMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyReplicationGroupShardConfigurationResult {
     pub replication_group: Option<ReplicationGroup>,
 }
@@ -4251,6 +4558,8 @@ impl ModifyReplicationGroupShardConfigurationResultDeserializer {
 }
 /// <p>Represents a collection of cache nodes in a replication group. One node in the node group is the read/write primary node. All the other nodes are read-only Replica nodes.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NodeGroup {
     /// <p>The identifier for the node group (shard). A Redis (cluster mode disabled) replication group contains only 1 node group; therefore, the node group ID is 0001. A Redis (cluster mode enabled) replication group contains 1 to 90 node groups numbered 0001 to 0090. Optionally, the user can provide the id for a node group. </p>
     pub node_group_id: Option<String>,
@@ -4306,6 +4615,8 @@ impl NodeGroupDeserializer {
 }
 /// <p>Node group (shard) configuration options. Each node group (shard) configuration has the following: <code>Slots</code>, <code>PrimaryAvailabilityZone</code>, <code>ReplicaAvailabilityZones</code>, <code>ReplicaCount</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NodeGroupConfiguration {
     /// <p>Either the ElastiCache for Redis supplied 4-digit id or a user supplied id for the node group these configuration values apply to.</p>
     pub node_group_id: Option<String>,
@@ -4319,6 +4630,20 @@ pub struct NodeGroupConfiguration {
     pub slots: Option<String>,
 }
 
+impl Validate for NodeGroupConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.node_group_id {
+            errors.check_length(
+                &field_path(path, "node_group_id"),
+                value.chars().count(),
+                Some(1),
+                Some(4),
+            );
+            errors.check_pattern(&field_path(path, "node_group_id"), &value, "\\d+");
+        }
+    }
+}
+
 struct NodeGroupConfigurationDeserializer;
 impl NodeGroupConfigurationDeserializer {
     #[allow(unused_variables)]
@@ -4428,6 +4753,8 @@ impl NodeGroupListDeserializer {
 }
 /// <p>Represents a single node within a node group (shard).</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NodeGroupMember {
     /// <p>The ID of the cluster to which the node belongs.</p>
     pub cache_cluster_id: Option<String>,
@@ -4499,6 +4826,8 @@ impl NodeGroupMemberListDeserializer {
 }
 /// <p>The status of the service update on the node group member </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NodeGroupMemberUpdateStatus {
     /// <p>The cache cluster ID</p>
     pub cache_cluster_id: Option<String>,
@@ -4607,6 +4936,8 @@ impl NodeGroupMemberUpdateStatusListDeserializer {
 }
 /// <p>The status of the service update on the node group </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NodeGroupUpdateStatus {
     /// <p>The ID of the node group</p>
     pub node_group_id: Option<String>,
@@ -4686,6 +5017,8 @@ impl NodeGroupsToRetainListSerializer {
 
 /// <p>Represents an individual cache node in a snapshot of a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NodeSnapshot {
     /// <p>A unique identifier for the source cluster.</p>
     pub cache_cluster_id: Option<String>,
@@ -4813,6 +5146,8 @@ impl NodeUpdateStatusDeserializer {
 }
 /// <p>Describes a notification topic and its status. Notification topics are used for publishing ElastiCache events to subscribers using Amazon Simple Notification Service (SNS).</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotificationConfiguration {
     /// <p>The Amazon Resource Name (ARN) that identifies the topic.</p>
     pub topic_arn: Option<String>,
@@ -4848,6 +5183,8 @@ impl NotificationConfigurationDeserializer {
 }
 /// <p>Describes an individual setting that controls some aspect of ElastiCache behavior.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Parameter {
     /// <p>The valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
@@ -4921,6 +5258,8 @@ impl ParameterDeserializer {
 }
 /// <p>Describes a name-value pair that is used to update the value of a parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ParameterNameValue {
     /// <p>The name of the parameter.</p>
     pub parameter_name: Option<String>,
@@ -4987,6 +5326,8 @@ impl PendingAutomaticFailoverStatusDeserializer {
 }
 /// <p>A group of settings that are applied to the cluster in the future, or that are currently being applied.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PendingModifiedValues {
     /// <p>A list of cache node IDs that are being removed (or will be removed) from the cluster. A node ID is a 4-digit numeric identifier (0001, 0002, etc.).</p>
     pub cache_node_ids_to_remove: Option<Vec<String>>,
@@ -5046,6 +5387,8 @@ impl PreferredAvailabilityZoneListSerializer {
 
 /// <p>Update action that has been processed for the corresponding apply/stop request</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ProcessedUpdateAction {
     /// <p>The ID of the replication group</p>
     pub replication_group_id: Option<String>,
@@ -5108,6 +5451,8 @@ impl ProcessedUpdateActionListDeserializer {
 }
 /// <p>Represents the input of a <code>PurchaseReservedCacheNodesOffering</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PurchaseReservedCacheNodesOfferingMessage {
     /// <p>The number of cache node instances to reserve.</p> <p>Default: <code>1</code> </p>
     pub cache_node_count: Option<i64>,
@@ -5143,6 +5488,8 @@ impl PurchaseReservedCacheNodesOfferingMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PurchaseReservedCacheNodesOfferingResult {
     pub reserved_cache_node: Option<ReservedCacheNode>,
 }
@@ -5174,6 +5521,8 @@ impl PurchaseReservedCacheNodesOfferingResultDeserializer {
 }
 /// <p>Represents the input of a <code>RebootCacheCluster</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootCacheClusterMessage {
     /// <p>The cluster identifier. This parameter is stored as a lowercase string.</p>
     pub cache_cluster_id: String,
@@ -5203,6 +5552,8 @@ impl RebootCacheClusterMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootCacheClusterResult {
     pub cache_cluster: Option<CacheCluster>,
 }
@@ -5234,6 +5585,8 @@ impl RebootCacheClusterResultDeserializer {
 }
 /// <p>Contains the specific price and frequency of a recurring charges for a reserved cache node, or for a reserved cache node offering.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RecurringCharge {
     /// <p>The monetary amount of the recurring charge.</p>
     pub recurring_charge_amount: Option<f64>,
@@ -5302,6 +5655,8 @@ impl RemoveReplicasListSerializer {
 
 /// <p>Represents the input of a <code>RemoveTagsFromResource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromResourceMessage {
     /// <p>The Amazon Resource Name (ARN) of the resource from which you want the tags removed, for example <code>arn:aws:elasticache:us-west-2:0123456789:cluster:myCluster</code> or <code>arn:aws:elasticache:us-west-2:0123456789:snapshot:mySnapshot</code>.</p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     pub resource_name: String,
@@ -5336,6 +5691,8 @@ impl ReplicaConfigurationListSerializer {
 
 /// <p>Contains all of the attributes of a specific Redis replication group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplicationGroup {
     /// <p>A flag that enables encryption at-rest when set to <code>true</code>.</p> <p>You cannot modify the value of <code>AtRestEncryptionEnabled</code> after the cluster is created. To enable encryption at-rest on a cluster you must set <code>AtRestEncryptionEnabled</code> to <code>true</code> when you create a cluster.</p> <p> <b>Required:</b> Only available when creating a replication group in an Amazon VPC using redis version <code>3.2.6</code>, <code>4.x</code> or later.</p> <p>Default: <code>false</code> </p>
     pub at_rest_encryption_enabled: Option<bool>,
@@ -5512,6 +5869,8 @@ impl ReplicationGroupListDeserializer {
 }
 /// <p>Represents the output of a <code>DescribeReplicationGroups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplicationGroupMessage {
     /// <p>Provides an identifier to allow retrieval of paginated results.</p>
     pub marker: Option<String>,
@@ -5551,6 +5910,8 @@ impl ReplicationGroupMessageDeserializer {
 }
 /// <p>The settings to be applied to the Redis replication group, either immediately or during the next maintenance window.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplicationGroupPendingModifiedValues {
     /// <p><p>Indicates the status of Multi-AZ with automatic failover for this Redis replication group.</p> <p>Amazon ElastiCache for Redis does not support Multi-AZ with automatic failover on:</p> <ul> <li> <p>Redis versions earlier than 2.8.6.</p> </li> <li> <p>Redis (cluster mode disabled): T1 node types.</p> </li> <li> <p>Redis (cluster mode enabled): T1 node types.</p> </li> </ul></p>
     pub automatic_failover_status: Option<String>,
@@ -5598,6 +5959,8 @@ impl ReplicationGroupPendingModifiedValuesDeserializer {
 }
 /// <p>Represents the output of a <code>PurchaseReservedCacheNodesOffering</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReservedCacheNode {
     /// <p>The number of cache nodes that have been reserved.</p>
     pub cache_node_count: Option<i64>,
@@ -5718,6 +6081,8 @@ impl ReservedCacheNodeListDeserializer {
 }
 /// <p>Represents the output of a <code>DescribeReservedCacheNodes</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReservedCacheNodeMessage {
     /// <p>Provides an identifier to allow retrieval of paginated results.</p>
     pub marker: Option<String>,
@@ -5757,6 +6122,8 @@ impl ReservedCacheNodeMessageDeserializer {
 }
 /// <p>Describes all of the attributes of a reserved cache node offering.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReservedCacheNodesOffering {
     /// <p><p>The cache node type for the reserved cache node.</p> <p>The following node types are supported by ElastiCache. Generally speaking, the current generation types provide more memory and computational power at lower cost when compared to their equivalent previous generation counterparts.</p> <ul> <li> <p>General purpose:</p> <ul> <li> <p>Current generation: </p> <p> <b>M5 node types:</b> <code>cache.m5.large</code>, <code>cache.m5.xlarge</code>, <code>cache.m5.2xlarge</code>, <code>cache.m5.4xlarge</code>, <code>cache.m5.12xlarge</code>, <code>cache.m5.24xlarge</code> </p> <p> <b>M4 node types:</b> <code>cache.m4.large</code>, <code>cache.m4.xlarge</code>, <code>cache.m4.2xlarge</code>, <code>cache.m4.4xlarge</code>, <code>cache.m4.10xlarge</code> </p> <p> <b>T2 node types:</b> <code>cache.t2.micro</code>, <code>cache.t2.small</code>, <code>cache.t2.medium</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>T1 node types:</b> <code>cache.t1.micro</code> </p> <p> <b>M1 node types:</b> <code>cache.m1.small</code>, <code>cache.m1.medium</code>, <code>cache.m1.large</code>, <code>cache.m1.xlarge</code> </p> <p> <b>M3 node types:</b> <code>cache.m3.medium</code>, <code>cache.m3.large</code>, <code>cache.m3.xlarge</code>, <code>cache.m3.2xlarge</code> </p> </li> </ul> </li> <li> <p>Compute optimized:</p> <ul> <li> <p>Previous generation: (not recommended)</p> <p> <b>C1 node types:</b> <code>cache.c1.xlarge</code> </p> </li> </ul> </li> <li> <p>Memory optimized:</p> <ul> <li> <p>Current generation: </p> <p> <b>R5 node types:</b> <code>cache.r5.large</code>, <code>cache.r5.xlarge</code>, <code>cache.r5.2xlarge</code>, <code>cache.r5.4xlarge</code>, <code>cache.r5.12xlarge</code>, <code>cache.r5.24xlarge</code> </p> <p> <b>R4 node types:</b> <code>cache.r4.large</code>, <code>cache.r4.xlarge</code>, <code>cache.r4.2xlarge</code>, <code>cache.r4.4xlarge</code>, <code>cache.r4.8xlarge</code>, <code>cache.r4.16xlarge</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>M2 node types:</b> <code>cache.m2.xlarge</code>, <code>cache.m2.2xlarge</code>, <code>cache.m2.4xlarge</code> </p> <p> <b>R3 node types:</b> <code>cache.r3.large</code>, <code>cache.r3.xlarge</code>, <code>cache.r3.2xlarge</code>, <code>cache.r3.4xlarge</code>, <code>cache.r3.8xlarge</code> </p> </li> </ul> </li> </ul> <p> <b>Additional node type info</b> </p> <ul> <li> <p>All current generation instance types are created in Amazon VPC by default.</p> </li> <li> <p>Redis append-only files (AOF) are not supported for T1 or T2 instances.</p> </li> <li> <p>Redis Multi-AZ with automatic failover is not supported on T1 instances.</p> </li> <li> <p>Redis configuration variables <code>appendonly</code> and <code>appendfsync</code> are not supported on Redis version 2.8.22 and later.</p> </li> </ul></p>
     pub cache_node_type: Option<String>,
@@ -5855,6 +6222,8 @@ impl ReservedCacheNodesOfferingListDeserializer {
 }
 /// <p>Represents the output of a <code>DescribeReservedCacheNodesOfferings</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReservedCacheNodesOfferingMessage {
     /// <p>Provides an identifier to allow retrieval of paginated results.</p>
     pub marker: Option<String>,
@@ -5894,6 +6263,8 @@ impl ReservedCacheNodesOfferingMessageDeserializer {
 }
 /// <p>Represents the input of a <code>ResetCacheParameterGroup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResetCacheParameterGroupMessage {
     /// <p>The name of the cache parameter group to reset.</p>
     pub cache_parameter_group_name: String,
@@ -5931,6 +6302,8 @@ impl ResetCacheParameterGroupMessageSerializer {
 
 /// <p>A list of <code>PreferredAvailabilityZones</code> objects that specifies the configuration of a node group in the resharded cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReshardingConfiguration {
     /// <p>Either the ElastiCache for Redis supplied 4-digit id or a user supplied id for the node group these configuration values apply to.</p>
     pub node_group_id: Option<String>,
@@ -5938,6 +6311,20 @@ pub struct ReshardingConfiguration {
     pub preferred_availability_zones: Option<Vec<String>>,
 }
 
+impl Validate for ReshardingConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.node_group_id {
+            errors.check_length(
+                &field_path(path, "node_group_id"),
+                value.chars().count(),
+                Some(1),
+                Some(4),
+            );
+            errors.check_pattern(&field_path(path, "node_group_id"), &value, "\\d+");
+        }
+    }
+}
+
 /// Serialize `ReshardingConfiguration` contents to a `SignedRequest`.
 struct ReshardingConfigurationSerializer;
 impl ReshardingConfigurationSerializer {
@@ -5973,6 +6360,8 @@ impl ReshardingConfigurationListSerializer {
 
 /// <p>The status of an online resharding operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReshardingStatus {
     /// <p>Represents the progress of an online resharding operation.</p>
     pub slot_migration: Option<SlotMigration>,
@@ -6001,6 +6390,8 @@ impl ReshardingStatusDeserializer {
 }
 /// <p>Represents the input of a <code>RevokeCacheSecurityGroupIngress</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeCacheSecurityGroupIngressMessage {
     /// <p>The name of the cache security group to revoke ingress from.</p>
     pub cache_security_group_name: String,
@@ -6035,6 +6426,8 @@ impl RevokeCacheSecurityGroupIngressMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeCacheSecurityGroupIngressResult {
     pub cache_security_group: Option<CacheSecurityGroup>,
 }
@@ -6079,6 +6472,8 @@ impl SecurityGroupIdsListSerializer {
 
 /// <p>Represents a single cache security group and its status.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SecurityGroupMembership {
     /// <p>The identifier of the cache security group.</p>
     pub security_group_id: Option<String>,
@@ -6133,6 +6528,8 @@ impl SecurityGroupMembershipListDeserializer {
 }
 /// <p>An update that you can apply to your Redis clusters.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ServiceUpdate {
     /// <p>Indicates whether the service update will be automatically applied once the recommended apply-by date has expired. </p>
     pub auto_update_after_recommended_apply_by_date: Option<bool>,
@@ -6309,6 +6706,8 @@ impl ServiceUpdateTypeDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ServiceUpdatesMessage {
     /// <p>An optional marker returned from a prior request. Use this marker for pagination of results from this operation. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
@@ -6352,6 +6751,8 @@ impl SlaMetDeserializer {
 }
 /// <p>Represents the progress of an online resharding operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SlotMigration {
     /// <p>The percentage of the slot migration that is complete.</p>
     pub progress_percentage: Option<f64>,
@@ -6380,6 +6781,8 @@ impl SlotMigrationDeserializer {
 }
 /// <p>Represents a copy of an entire Redis cluster as of the time when the snapshot was taken.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Snapshot {
     /// <p>This parameter is currently disabled.</p>
     pub auto_minor_version_upgrade: Option<bool>,
@@ -6622,6 +7025,8 @@ impl StringDeserializer {
 }
 /// <p>Represents the subnet associated with a cluster. This parameter refers to subnets defined in Amazon Virtual Private Cloud (Amazon VPC) and used with ElastiCache.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Subnet {
     /// <p>The Availability Zone associated with the subnet.</p>
     pub subnet_availability_zone: Option<AvailabilityZone>,
@@ -6693,6 +7098,8 @@ impl TStampDeserializer {
 }
 /// <p>A cost allocation Tag that can be added to an ElastiCache cluster or replication group. Tags are composed of a Key/Value pair. A tag with a null Value is permitted.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>The key for the tag. May not be null.</p>
     pub key: Option<String>,
@@ -6768,6 +7175,8 @@ impl TagListSerializer {
 
 /// <p>Represents the output from the <code>AddTagsToResource</code>, <code>ListTagsForResource</code>, and <code>RemoveTagsFromResource</code> operations.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagListMessage {
     /// <p>A list of cost allocation tags as key-value pairs.</p>
     pub tag_list: Option<Vec<Tag>>,
@@ -6794,6 +7203,8 @@ impl TagListMessageDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestFailoverMessage {
     /// <p>The name of the node group (called shard in the console) in this replication group on which automatic failover is to be tested. You may test automatic failover on up to 5 node groups in any rolling 24-hour period.</p>
     pub node_group_id: String,
@@ -6801,6 +7212,22 @@ pub struct TestFailoverMessage {
     pub replication_group_id: String,
 }
 
+impl Validate for TestFailoverMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "node_group_id"),
+            self.node_group_id.chars().count(),
+            Some(1),
+            Some(4),
+        );
+        errors.check_pattern(
+            &field_path(path, "node_group_id"),
+            &self.node_group_id,
+            "\\d+",
+        );
+    }
+}
+
 /// Serialize `TestFailoverMessage` contents to a `SignedRequest`.
 struct TestFailoverMessageSerializer;
 impl TestFailoverMessageSerializer {
@@ -6819,6 +7246,8 @@ impl TestFailoverMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestFailoverResult {
     pub replication_group: Option<ReplicationGroup>,
 }
@@ -6846,6 +7275,8 @@ impl TestFailoverResultDeserializer {
 }
 /// <p>Filters update actions from the service updates that are in available status during the time range.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TimeRangeFilter {
     /// <p>The end time of the time range filter</p>
     pub end_time: Option<String>,
@@ -6873,6 +7304,8 @@ impl TimeRangeFilterSerializer {
 
 /// <p>Update action that has failed to be processed for the corresponding apply/stop request</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnprocessedUpdateAction {
     /// <p>The error message that describes the reason the request was not processed</p>
     pub error_message: Option<String>,
@@ -6942,6 +7375,8 @@ impl UnprocessedUpdateActionListDeserializer {
 }
 /// <p>The status of the service update for a specific replication group</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAction {
     /// <p>The estimated length of time for the update to complete</p>
     pub estimated_update_time: Option<String>,
@@ -7090,6 +7525,8 @@ impl UpdateActionListDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateActionResultsMessage {
     /// <p>Update actions that have been processed successfully</p>
     pub processed_update_actions: Option<Vec<ProcessedUpdateAction>>,
@@ -7156,6 +7593,8 @@ impl UpdateActionStatusListSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateActionsMessage {
     /// <p>An optional marker returned from a prior request. Use this marker for pagination of results from this operation. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
@@ -11319,96 +11758,14 @@ pub trait ElastiCache {
         input: TestFailoverMessage,
     ) -> RusotoFuture<TestFailoverResult, TestFailoverError>;
 }
-/// A client for the Amazon ElastiCache API.
-#[derive(Clone)]
-pub struct ElastiCacheClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ElastiCacheClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ElastiCacheClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ElastiCacheClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ElastiCacheClient {
-        ElastiCacheClient { client, region }
-    }
-}
-
-impl ElastiCache for ElastiCacheClient {
-    /// <p>Adds up to 50 cost allocation tags to the named resource. A cost allocation tag is a key-value pair where the key and value are case-sensitive. You can use cost allocation tags to categorize and track your AWS costs.</p> <p> When you apply tags to your ElastiCache resources, AWS generates a cost allocation report as a comma-separated value (CSV) file with your usage and costs aggregated by your tags. You can apply tags that represent business categories (such as cost centers, application names, or owners) to organize your costs across multiple services. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Tagging.html">Using Cost Allocation Tags in Amazon ElastiCache</a> in the <i>ElastiCache User Guide</i>.</p>
+const _: Option<&dyn ElastiCache> = None;
+impl<'a, T: ElastiCache + ?Sized> ElastiCache for &'a T {
     fn add_tags_to_resource(
         &self,
         input: AddTagsToResourceMessage,
     ) -> RusotoFuture<TagListMessage, AddTagsToResourceError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddTagsToResource");
-        params.put("Version", "2015-02-02");
-        AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TagListMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TagListMessageDeserializer::deserialize(
-                        "AddTagsToResourceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).add_tags_to_resource(input)
     }
-
-    /// <p><p>Allows network ingress to a cache security group. Applications using ElastiCache must be running on Amazon EC2, and Amazon EC2 security groups are used as the authorization mechanism.</p> <note> <p>You cannot authorize ingress from an Amazon EC2 security group in one region to an ElastiCache cluster in another region.</p> </note></p>
     fn authorize_cache_security_group_ingress(
         &self,
         input: AuthorizeCacheSecurityGroupIngressMessage,
@@ -11416,1796 +11773,233 @@ impl ElastiCache for ElastiCacheClient {
         AuthorizeCacheSecurityGroupIngressResult,
         AuthorizeCacheSecurityGroupIngressError,
     > {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AuthorizeCacheSecurityGroupIngress");
-        params.put("Version", "2015-02-02");
-        AuthorizeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AuthorizeCacheSecurityGroupIngressError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AuthorizeCacheSecurityGroupIngressResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AuthorizeCacheSecurityGroupIngressResultDeserializer::deserialize(
-                        "AuthorizeCacheSecurityGroupIngressResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).authorize_cache_security_group_ingress(input)
     }
-
-    /// <p>Apply the service update. For more information on service updates and applying them, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/applying-updates.html">Applying Service Updates</a>.</p>
     fn batch_apply_update_action(
         &self,
         input: BatchApplyUpdateActionMessage,
     ) -> RusotoFuture<UpdateActionResultsMessage, BatchApplyUpdateActionError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "BatchApplyUpdateAction");
-        params.put("Version", "2015-02-02");
-        BatchApplyUpdateActionMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchApplyUpdateActionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateActionResultsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = UpdateActionResultsMessageDeserializer::deserialize(
-                        "BatchApplyUpdateActionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).batch_apply_update_action(input)
     }
-
-    /// <p>Stop the service update. For more information on service updates and stopping them, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/stopping-self-service-updates.html">Stopping Service Updates</a>.</p>
     fn batch_stop_update_action(
         &self,
         input: BatchStopUpdateActionMessage,
     ) -> RusotoFuture<UpdateActionResultsMessage, BatchStopUpdateActionError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "BatchStopUpdateAction");
-        params.put("Version", "2015-02-02");
-        BatchStopUpdateActionMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchStopUpdateActionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateActionResultsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = UpdateActionResultsMessageDeserializer::deserialize(
-                        "BatchStopUpdateActionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).batch_stop_update_action(input)
     }
-
-    /// <p><p>Makes a copy of an existing snapshot.</p> <note> <p>This operation is valid for Redis only.</p> </note> <important> <p>Users or groups that have permissions to use the <code>CopySnapshot</code> operation can create their own Amazon S3 buckets and copy snapshots to it. To control access to your snapshots, use an IAM policy to control who has the ability to use the <code>CopySnapshot</code> operation. For more information about using IAM to control the use of ElastiCache operations, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html">Exporting Snapshots</a> and <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/IAM.html">Authentication &amp; Access Control</a>.</p> </important> <p>You could receive the following error messages.</p> <p class="title"> <b>Error Messages</b> </p> <ul> <li> <p> <b>Error Message:</b> The S3 bucket %s is outside of the region.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s does not exist.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s is not owned by the authenticated user.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The authenticated user does not have sufficient permissions to perform the desired activity.</p> <p> <b>Solution:</b> Contact your system administrator to get the needed permissions.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s already contains an object with key %s.</p> <p> <b>Solution:</b> Give the <code>TargetSnapshotName</code> a new and unique value. If exporting a snapshot, you could alternatively create a new Amazon S3 bucket and use this same value for <code>TargetSnapshotName</code>.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted READ permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add List and Read permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted WRITE permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add Upload/Delete permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted READ_ACP permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add View Permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> </ul></p>
     fn copy_snapshot(
         &self,
         input: CopySnapshotMessage,
     ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CopySnapshot");
-        params.put("Version", "2015-02-02");
-        CopySnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CopySnapshotError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CopySnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CopySnapshotResultDeserializer::deserialize(
-                        "CopySnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).copy_snapshot(input)
     }
-
-    /// <p>Creates a cluster. All nodes in the cluster run the same protocol-compliant cache engine software, either Memcached or Redis.</p> <p>This operation is not supported for Redis (cluster mode enabled) clusters.</p>
     fn create_cache_cluster(
         &self,
         input: CreateCacheClusterMessage,
     ) -> RusotoFuture<CreateCacheClusterResult, CreateCacheClusterError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateCacheCluster");
-        params.put("Version", "2015-02-02");
-        CreateCacheClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateCacheClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateCacheClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateCacheClusterResultDeserializer::deserialize(
-                        "CreateCacheClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_cache_cluster(input)
     }
-
-    /// <p><p>Creates a new Amazon ElastiCache cache parameter group. An ElastiCache cache parameter group is a collection of parameters and their values that are applied to all of the nodes in any cluster or replication group using the CacheParameterGroup.</p> <p>A newly created CacheParameterGroup is an exact duplicate of the default parameter group for the CacheParameterGroupFamily. To customize the newly created CacheParameterGroup you can change the values of specific parameters. For more information, see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_ModifyCacheParameterGroup.html">ModifyCacheParameterGroup</a> in the ElastiCache API Reference.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ParameterGroups.html">Parameters and Parameter Groups</a> in the ElastiCache User Guide.</p> </li> </ul></p>
     fn create_cache_parameter_group(
         &self,
         input: CreateCacheParameterGroupMessage,
     ) -> RusotoFuture<CreateCacheParameterGroupResult, CreateCacheParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateCacheParameterGroup");
-        params.put("Version", "2015-02-02");
-        CreateCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateCacheParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateCacheParameterGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateCacheParameterGroupResultDeserializer::deserialize(
-                        "CreateCacheParameterGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_cache_parameter_group(input)
     }
-
-    /// <p>Creates a new cache security group. Use a cache security group to control access to one or more clusters.</p> <p>Cache security groups are only used when you are creating a cluster outside of an Amazon Virtual Private Cloud (Amazon VPC). If you are creating a cluster inside of a VPC, use a cache subnet group instead. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_CreateCacheSubnetGroup.html">CreateCacheSubnetGroup</a>.</p>
     fn create_cache_security_group(
         &self,
         input: CreateCacheSecurityGroupMessage,
     ) -> RusotoFuture<CreateCacheSecurityGroupResult, CreateCacheSecurityGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateCacheSecurityGroup");
-        params.put("Version", "2015-02-02");
-        CreateCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateCacheSecurityGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateCacheSecurityGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateCacheSecurityGroupResultDeserializer::deserialize(
-                        "CreateCacheSecurityGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_cache_security_group(input)
     }
-
-    /// <p>Creates a new cache subnet group.</p> <p>Use this parameter only when you are creating a cluster in an Amazon Virtual Private Cloud (Amazon VPC).</p>
     fn create_cache_subnet_group(
         &self,
         input: CreateCacheSubnetGroupMessage,
     ) -> RusotoFuture<CreateCacheSubnetGroupResult, CreateCacheSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateCacheSubnetGroup");
-        params.put("Version", "2015-02-02");
-        CreateCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateCacheSubnetGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateCacheSubnetGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateCacheSubnetGroupResultDeserializer::deserialize(
-                        "CreateCacheSubnetGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_cache_subnet_group(input)
     }
-
-    /// <p><p>Creates a Redis (cluster mode disabled) or a Redis (cluster mode enabled) replication group.</p> <p>A Redis (cluster mode disabled) replication group is a collection of clusters, where one of the clusters is a read/write primary and the others are read-only replicas. Writes to the primary are asynchronously propagated to the replicas.</p> <p>A Redis (cluster mode enabled) replication group is a collection of 1 to 90 node groups (shards). Each node group (shard) has one read/write primary node and up to 5 read-only replica nodes. Writes to the primary are asynchronously propagated to the replicas. Redis (cluster mode enabled) replication groups partition the data across node groups (shards).</p> <p>When a Redis (cluster mode disabled) replication group has been successfully created, you can add one or more read replicas to it, up to a total of 5 read replicas. You cannot alter a Redis (cluster mode enabled) replication group after it has been created. However, if you need to increase or decrease the number of node groups (console: shards), you can avail yourself of ElastiCache for Redis&#39; enhanced backup and restore. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-restoring.html">Restoring From a Backup with Cluster Resizing</a> in the <i>ElastiCache User Guide</i>.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
     fn create_replication_group(
         &self,
         input: CreateReplicationGroupMessage,
     ) -> RusotoFuture<CreateReplicationGroupResult, CreateReplicationGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateReplicationGroup");
-        params.put("Version", "2015-02-02");
-        CreateReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateReplicationGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateReplicationGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateReplicationGroupResultDeserializer::deserialize(
-                        "CreateReplicationGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_replication_group(input)
     }
-
-    /// <p><p>Creates a copy of an entire cluster or replication group at a specific moment in time.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
     fn create_snapshot(
         &self,
         input: CreateSnapshotMessage,
     ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateSnapshot");
-        params.put("Version", "2015-02-02");
-        CreateSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSnapshotError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateSnapshotResultDeserializer::deserialize(
-                        "CreateSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_snapshot(input)
     }
-
-    /// <p>Dynamically decreases the number of replics in a Redis (cluster mode disabled) replication group or the number of replica nodes in one or more node groups (shards) of a Redis (cluster mode enabled) replication group. This operation is performed with no cluster down time.</p>
     fn decrease_replica_count(
         &self,
         input: DecreaseReplicaCountMessage,
     ) -> RusotoFuture<DecreaseReplicaCountResult, DecreaseReplicaCountError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DecreaseReplicaCount");
-        params.put("Version", "2015-02-02");
-        DecreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DecreaseReplicaCountError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DecreaseReplicaCountResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DecreaseReplicaCountResultDeserializer::deserialize(
-                        "DecreaseReplicaCountResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).decrease_replica_count(input)
     }
-
-    /// <p><p>Deletes a previously provisioned cluster. <code>DeleteCacheCluster</code> deletes all associated cache nodes, node endpoints and the cluster itself. When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the cluster; you cannot cancel or revert this operation.</p> <p>This operation is not valid for:</p> <ul> <li> <p>Redis (cluster mode enabled) clusters</p> </li> <li> <p>A cluster that is the last read replica of a replication group</p> </li> <li> <p>A node group (shard) that has Multi-AZ mode enabled</p> </li> <li> <p>A cluster from a Redis (cluster mode enabled) replication group</p> </li> <li> <p>A cluster that is not in the <code>available</code> state</p> </li> </ul></p>
     fn delete_cache_cluster(
         &self,
         input: DeleteCacheClusterMessage,
     ) -> RusotoFuture<DeleteCacheClusterResult, DeleteCacheClusterError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteCacheCluster");
-        params.put("Version", "2015-02-02");
-        DeleteCacheClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteCacheClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteCacheClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteCacheClusterResultDeserializer::deserialize(
-                        "DeleteCacheClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_cache_cluster(input)
     }
-
-    /// <p>Deletes the specified cache parameter group. You cannot delete a cache parameter group if it is associated with any cache clusters.</p>
     fn delete_cache_parameter_group(
         &self,
         input: DeleteCacheParameterGroupMessage,
     ) -> RusotoFuture<(), DeleteCacheParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteCacheParameterGroup");
-        params.put("Version", "2015-02-02");
-        DeleteCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteCacheParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_cache_parameter_group(input)
     }
-
-    /// <p><p>Deletes a cache security group.</p> <note> <p>You cannot delete a cache security group if it is associated with any clusters.</p> </note></p>
     fn delete_cache_security_group(
         &self,
         input: DeleteCacheSecurityGroupMessage,
     ) -> RusotoFuture<(), DeleteCacheSecurityGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteCacheSecurityGroup");
-        params.put("Version", "2015-02-02");
-        DeleteCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteCacheSecurityGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_cache_security_group(input)
     }
-
-    /// <p><p>Deletes a cache subnet group.</p> <note> <p>You cannot delete a cache subnet group if it is associated with any clusters.</p> </note></p>
     fn delete_cache_subnet_group(
         &self,
         input: DeleteCacheSubnetGroupMessage,
     ) -> RusotoFuture<(), DeleteCacheSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteCacheSubnetGroup");
-        params.put("Version", "2015-02-02");
-        DeleteCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteCacheSubnetGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_cache_subnet_group(input)
     }
-
-    /// <p><p>Deletes an existing replication group. By default, this operation deletes the entire replication group, including the primary/primaries and all of the read replicas. If the replication group has only one primary, you can optionally delete only the read replicas, while retaining the primary by setting <code>RetainPrimaryCluster=true</code>.</p> <p>When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the selected resources; you cannot cancel or revert this operation.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
     fn delete_replication_group(
         &self,
         input: DeleteReplicationGroupMessage,
     ) -> RusotoFuture<DeleteReplicationGroupResult, DeleteReplicationGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteReplicationGroup");
-        params.put("Version", "2015-02-02");
-        DeleteReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteReplicationGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteReplicationGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteReplicationGroupResultDeserializer::deserialize(
-                        "DeleteReplicationGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_replication_group(input)
     }
-
-    /// <p><p>Deletes an existing snapshot. When you receive a successful response from this operation, ElastiCache immediately begins deleting the snapshot; you cannot cancel or revert this operation.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
     fn delete_snapshot(
         &self,
         input: DeleteSnapshotMessage,
     ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteSnapshot");
-        params.put("Version", "2015-02-02");
-        DeleteSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteSnapshotError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteSnapshotResultDeserializer::deserialize(
-                        "DeleteSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_snapshot(input)
     }
-
-    /// <p>Returns information about all provisioned clusters if no cluster identifier is specified, or about a specific cache cluster if a cluster identifier is supplied.</p> <p>By default, abbreviated information about the clusters is returned. You can use the optional <i>ShowCacheNodeInfo</i> flag to retrieve detailed information about the cache nodes associated with the clusters. These details include the DNS address and port for the cache node endpoint.</p> <p>If the cluster is in the <i>creating</i> state, only cluster-level information is displayed until all of the nodes are successfully provisioned.</p> <p>If the cluster is in the <i>deleting</i> state, only cluster-level information is displayed.</p> <p>If cache nodes are currently being added to the cluster, node endpoint information and creation time for the additional nodes are not displayed until they are completely provisioned. When the cluster state is <i>available</i>, the cluster is ready for use.</p> <p>If cache nodes are currently being removed from the cluster, no endpoint information for the removed nodes is displayed.</p>
     fn describe_cache_clusters(
         &self,
         input: DescribeCacheClustersMessage,
     ) -> RusotoFuture<CacheClusterMessage, DescribeCacheClustersError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeCacheClusters");
-        params.put("Version", "2015-02-02");
-        DescribeCacheClustersMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCacheClustersError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CacheClusterMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CacheClusterMessageDeserializer::deserialize(
-                        "DescribeCacheClustersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cache_clusters(input)
     }
-
-    /// <p>Returns a list of the available cache engines and their versions.</p>
     fn describe_cache_engine_versions(
         &self,
         input: DescribeCacheEngineVersionsMessage,
     ) -> RusotoFuture<CacheEngineVersionMessage, DescribeCacheEngineVersionsError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeCacheEngineVersions");
-        params.put("Version", "2015-02-02");
-        DescribeCacheEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCacheEngineVersionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CacheEngineVersionMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CacheEngineVersionMessageDeserializer::deserialize(
-                        "DescribeCacheEngineVersionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cache_engine_versions(input)
     }
-
-    /// <p>Returns a list of cache parameter group descriptions. If a cache parameter group name is specified, the list contains only the descriptions for that group.</p>
     fn describe_cache_parameter_groups(
         &self,
         input: DescribeCacheParameterGroupsMessage,
     ) -> RusotoFuture<CacheParameterGroupsMessage, DescribeCacheParameterGroupsError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeCacheParameterGroups");
-        params.put("Version", "2015-02-02");
-        DescribeCacheParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCacheParameterGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CacheParameterGroupsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CacheParameterGroupsMessageDeserializer::deserialize(
-                        "DescribeCacheParameterGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cache_parameter_groups(input)
     }
-
-    /// <p>Returns the detailed parameter list for a particular cache parameter group.</p>
     fn describe_cache_parameters(
         &self,
         input: DescribeCacheParametersMessage,
     ) -> RusotoFuture<CacheParameterGroupDetails, DescribeCacheParametersError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeCacheParameters");
-        params.put("Version", "2015-02-02");
-        DescribeCacheParametersMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCacheParametersError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CacheParameterGroupDetails::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CacheParameterGroupDetailsDeserializer::deserialize(
-                        "DescribeCacheParametersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cache_parameters(input)
     }
-
-    /// <p>Returns a list of cache security group descriptions. If a cache security group name is specified, the list contains only the description of that group. This applicable only when you have ElastiCache in Classic setup </p>
     fn describe_cache_security_groups(
         &self,
         input: DescribeCacheSecurityGroupsMessage,
     ) -> RusotoFuture<CacheSecurityGroupMessage, DescribeCacheSecurityGroupsError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeCacheSecurityGroups");
-        params.put("Version", "2015-02-02");
-        DescribeCacheSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCacheSecurityGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CacheSecurityGroupMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CacheSecurityGroupMessageDeserializer::deserialize(
-                        "DescribeCacheSecurityGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cache_security_groups(input)
     }
-
-    /// <p>Returns a list of cache subnet group descriptions. If a subnet group name is specified, the list contains only the description of that group. This is applicable only when you have ElastiCache in VPC setup. All ElastiCache clusters now launch in VPC by default. </p>
     fn describe_cache_subnet_groups(
         &self,
         input: DescribeCacheSubnetGroupsMessage,
     ) -> RusotoFuture<CacheSubnetGroupMessage, DescribeCacheSubnetGroupsError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeCacheSubnetGroups");
-        params.put("Version", "2015-02-02");
-        DescribeCacheSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCacheSubnetGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CacheSubnetGroupMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CacheSubnetGroupMessageDeserializer::deserialize(
-                        "DescribeCacheSubnetGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cache_subnet_groups(input)
     }
-
-    /// <p>Returns the default engine and system parameter information for the specified cache engine.</p>
     fn describe_engine_default_parameters(
         &self,
         input: DescribeEngineDefaultParametersMessage,
     ) -> RusotoFuture<DescribeEngineDefaultParametersResult, DescribeEngineDefaultParametersError>
     {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEngineDefaultParameters");
-        params.put("Version", "2015-02-02");
-        DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEngineDefaultParametersError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeEngineDefaultParametersResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeEngineDefaultParametersResultDeserializer::deserialize(
-                        "DescribeEngineDefaultParametersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_engine_default_parameters(input)
     }
-
-    /// <p>Returns events related to clusters, cache security groups, and cache parameter groups. You can obtain events specific to a particular cluster, cache security group, or cache parameter group by providing the name as a parameter.</p> <p>By default, only the events occurring within the last hour are returned; however, you can retrieve up to 14 days' worth of events if necessary.</p>
     fn describe_events(
         &self,
         input: DescribeEventsMessage,
     ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEvents");
-        params.put("Version", "2015-02-02");
-        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeEventsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EventsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        EventsMessageDeserializer::deserialize("DescribeEventsResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_events(input)
     }
-
-    /// <p><p>Returns information about a particular replication group. If no identifier is specified, <code>DescribeReplicationGroups</code> returns information about all replication groups.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
     fn describe_replication_groups(
         &self,
         input: DescribeReplicationGroupsMessage,
     ) -> RusotoFuture<ReplicationGroupMessage, DescribeReplicationGroupsError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeReplicationGroups");
-        params.put("Version", "2015-02-02");
-        DescribeReplicationGroupsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReplicationGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ReplicationGroupMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ReplicationGroupMessageDeserializer::deserialize(
-                        "DescribeReplicationGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_replication_groups(input)
     }
-
-    /// <p>Returns information about reserved cache nodes for this account, or about a specified reserved cache node.</p>
     fn describe_reserved_cache_nodes(
         &self,
         input: DescribeReservedCacheNodesMessage,
     ) -> RusotoFuture<ReservedCacheNodeMessage, DescribeReservedCacheNodesError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeReservedCacheNodes");
-        params.put("Version", "2015-02-02");
-        DescribeReservedCacheNodesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReservedCacheNodesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ReservedCacheNodeMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ReservedCacheNodeMessageDeserializer::deserialize(
-                        "DescribeReservedCacheNodesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_reserved_cache_nodes(input)
     }
-
-    /// <p>Lists available reserved cache node offerings.</p>
     fn describe_reserved_cache_nodes_offerings(
         &self,
         input: DescribeReservedCacheNodesOfferingsMessage,
     ) -> RusotoFuture<ReservedCacheNodesOfferingMessage, DescribeReservedCacheNodesOfferingsError>
     {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeReservedCacheNodesOfferings");
-        params.put("Version", "2015-02-02");
-        DescribeReservedCacheNodesOfferingsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReservedCacheNodesOfferingsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ReservedCacheNodesOfferingMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ReservedCacheNodesOfferingMessageDeserializer::deserialize(
-                        "DescribeReservedCacheNodesOfferingsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_reserved_cache_nodes_offerings(input)
     }
-
-    /// <p>Returns details of the service updates</p>
     fn describe_service_updates(
         &self,
         input: DescribeServiceUpdatesMessage,
     ) -> RusotoFuture<ServiceUpdatesMessage, DescribeServiceUpdatesError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeServiceUpdates");
-        params.put("Version", "2015-02-02");
-        DescribeServiceUpdatesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeServiceUpdatesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ServiceUpdatesMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ServiceUpdatesMessageDeserializer::deserialize(
-                        "DescribeServiceUpdatesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_service_updates(input)
     }
-
-    /// <p><p>Returns information about cluster or replication group snapshots. By default, <code>DescribeSnapshots</code> lists all of your snapshots; it can optionally describe a single snapshot, or just the snapshots associated with a particular cache cluster.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
     fn describe_snapshots(
         &self,
         input: DescribeSnapshotsMessage,
     ) -> RusotoFuture<DescribeSnapshotsListMessage, DescribeSnapshotsError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeSnapshots");
-        params.put("Version", "2015-02-02");
-        DescribeSnapshotsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeSnapshotsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeSnapshotsListMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeSnapshotsListMessageDeserializer::deserialize(
-                        "DescribeSnapshotsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_snapshots(input)
     }
-
-    /// <p>Returns details of the update actions </p>
     fn describe_update_actions(
         &self,
         input: DescribeUpdateActionsMessage,
     ) -> RusotoFuture<UpdateActionsMessage, DescribeUpdateActionsError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeUpdateActions");
-        params.put("Version", "2015-02-02");
-        DescribeUpdateActionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeUpdateActionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateActionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = UpdateActionsMessageDeserializer::deserialize(
-                        "DescribeUpdateActionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_update_actions(input)
     }
-
-    /// <p>Dynamically increases the number of replics in a Redis (cluster mode disabled) replication group or the number of replica nodes in one or more node groups (shards) of a Redis (cluster mode enabled) replication group. This operation is performed with no cluster down time.</p>
     fn increase_replica_count(
         &self,
         input: IncreaseReplicaCountMessage,
     ) -> RusotoFuture<IncreaseReplicaCountResult, IncreaseReplicaCountError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "IncreaseReplicaCount");
-        params.put("Version", "2015-02-02");
-        IncreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(IncreaseReplicaCountError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = IncreaseReplicaCountResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = IncreaseReplicaCountResultDeserializer::deserialize(
-                        "IncreaseReplicaCountResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).increase_replica_count(input)
     }
-
-    /// <p>Lists all available node types that you can scale your Redis cluster's or replication group's current node type.</p> <p>When you use the <code>ModifyCacheCluster</code> or <code>ModifyReplicationGroup</code> operations to scale your cluster or replication group, the value of the <code>CacheNodeType</code> parameter must be one of the node types returned by this operation.</p>
     fn list_allowed_node_type_modifications(
         &self,
         input: ListAllowedNodeTypeModificationsMessage,
     ) -> RusotoFuture<AllowedNodeTypeModificationsMessage, ListAllowedNodeTypeModificationsError>
     {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListAllowedNodeTypeModifications");
-        params.put("Version", "2015-02-02");
-        ListAllowedNodeTypeModificationsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListAllowedNodeTypeModificationsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AllowedNodeTypeModificationsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AllowedNodeTypeModificationsMessageDeserializer::deserialize(
-                        "ListAllowedNodeTypeModificationsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_allowed_node_type_modifications(input)
     }
-
-    /// <p>Lists all cost allocation tags currently on the named resource. A <code>cost allocation tag</code> is a key-value pair where the key is case-sensitive and the value is optional. You can use cost allocation tags to categorize and track your AWS costs.</p> <p>If the cluster is not in the <i>available</i> state, <code>ListTagsForResource</code> returns an error.</p> <p>You can have a maximum of 50 cost allocation tags on an ElastiCache resource. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Tagging.html">Monitoring Costs with Tags</a>.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceMessage,
     ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListTagsForResource");
-        params.put("Version", "2015-02-02");
-        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TagListMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TagListMessageDeserializer::deserialize(
-                        "ListTagsForResourceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Modifies the settings for a cluster. You can use this operation to change one or more cluster configuration parameters by specifying the parameters and the new values.</p>
     fn modify_cache_cluster(
         &self,
         input: ModifyCacheClusterMessage,
     ) -> RusotoFuture<ModifyCacheClusterResult, ModifyCacheClusterError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyCacheCluster");
-        params.put("Version", "2015-02-02");
-        ModifyCacheClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ModifyCacheClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyCacheClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyCacheClusterResultDeserializer::deserialize(
-                        "ModifyCacheClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_cache_cluster(input)
     }
-
-    /// <p>Modifies the parameters of a cache parameter group. You can modify up to 20 parameters in a single request by submitting a list parameter name and value pairs.</p>
     fn modify_cache_parameter_group(
         &self,
         input: ModifyCacheParameterGroupMessage,
     ) -> RusotoFuture<CacheParameterGroupNameMessage, ModifyCacheParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyCacheParameterGroup");
-        params.put("Version", "2015-02-02");
-        ModifyCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyCacheParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CacheParameterGroupNameMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CacheParameterGroupNameMessageDeserializer::deserialize(
-                        "ModifyCacheParameterGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_cache_parameter_group(input)
     }
-
-    /// <p>Modifies an existing cache subnet group.</p>
     fn modify_cache_subnet_group(
         &self,
         input: ModifyCacheSubnetGroupMessage,
     ) -> RusotoFuture<ModifyCacheSubnetGroupResult, ModifyCacheSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyCacheSubnetGroup");
-        params.put("Version", "2015-02-02");
-        ModifyCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyCacheSubnetGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyCacheSubnetGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyCacheSubnetGroupResultDeserializer::deserialize(
-                        "ModifyCacheSubnetGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_cache_subnet_group(input)
     }
-
-    /// <p><p>Modifies the settings for a replication group.</p> <p>For Redis (cluster mode enabled) clusters, this operation cannot be used to change a cluster&#39;s node type or engine version. For more information, see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/scaling-redis-cluster-mode-enabled.html">Scaling for Amazon ElastiCache for Redis (cluster mode enabled)</a> in the ElastiCache User Guide</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_ModifyReplicationGroupShardConfiguration.html">ModifyReplicationGroupShardConfiguration</a> in the ElastiCache API Reference</p> </li> </ul> <note> <p>This operation is valid for Redis only.</p> </note></p>
     fn modify_replication_group(
         &self,
         input: ModifyReplicationGroupMessage,
     ) -> RusotoFuture<ModifyReplicationGroupResult, ModifyReplicationGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyReplicationGroup");
-        params.put("Version", "2015-02-02");
-        ModifyReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyReplicationGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyReplicationGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyReplicationGroupResultDeserializer::deserialize(
-                        "ModifyReplicationGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_replication_group(input)
     }
-
-    /// <p>Modifies a replication group's shards (node groups) by allowing you to add shards, remove shards, or rebalance the keyspaces among exisiting shards.</p>
     fn modify_replication_group_shard_configuration(
         &self,
         input: ModifyReplicationGroupShardConfigurationMessage,
@@ -13213,55 +12007,8 @@ impl ElastiCache for ElastiCacheClient {
         ModifyReplicationGroupShardConfigurationResult,
         ModifyReplicationGroupShardConfigurationError,
     > {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyReplicationGroupShardConfiguration");
-        params.put("Version", "2015-02-02");
-        ModifyReplicationGroupShardConfigurationMessageSerializer::serialize(
-            &mut params,
-            "",
-            &input,
-        );
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyReplicationGroupShardConfigurationError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyReplicationGroupShardConfigurationResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        ModifyReplicationGroupShardConfigurationResultDeserializer::deserialize(
-                            "ModifyReplicationGroupShardConfigurationResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_replication_group_shard_configuration(input)
     }
-
-    /// <p>Allows you to purchase a reserved cache node offering.</p>
     fn purchase_reserved_cache_nodes_offering(
         &self,
         input: PurchaseReservedCacheNodesOfferingMessage,
@@ -13269,42 +12016,3133 @@ impl ElastiCache for ElastiCacheClient {
         PurchaseReservedCacheNodesOfferingResult,
         PurchaseReservedCacheNodesOfferingError,
     > {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "PurchaseReservedCacheNodesOffering");
-        params.put("Version", "2015-02-02");
-        PurchaseReservedCacheNodesOfferingMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PurchaseReservedCacheNodesOfferingError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = PurchaseReservedCacheNodesOfferingResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        (**self).purchase_reserved_cache_nodes_offering(input)
+    }
+    fn reboot_cache_cluster(
+        &self,
+        input: RebootCacheClusterMessage,
+    ) -> RusotoFuture<RebootCacheClusterResult, RebootCacheClusterError> {
+        (**self).reboot_cache_cluster(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<TagListMessage, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_cache_parameter_group(
+        &self,
+        input: ResetCacheParameterGroupMessage,
+    ) -> RusotoFuture<CacheParameterGroupNameMessage, ResetCacheParameterGroupError> {
+        (**self).reset_cache_parameter_group(input)
+    }
+    fn revoke_cache_security_group_ingress(
+        &self,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeCacheSecurityGroupIngressResult, RevokeCacheSecurityGroupIngressError>
+    {
+        (**self).revoke_cache_security_group_ingress(input)
+    }
+    fn test_failover(
+        &self,
+        input: TestFailoverMessage,
+    ) -> RusotoFuture<TestFailoverResult, TestFailoverError> {
+        (**self).test_failover(input)
+    }
+}
+impl<T: ElastiCache + ?Sized> ElastiCache for Box<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<TagListMessage, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn authorize_cache_security_group_ingress(
+        &self,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> RusotoFuture<
+        AuthorizeCacheSecurityGroupIngressResult,
+        AuthorizeCacheSecurityGroupIngressError,
+    > {
+        (**self).authorize_cache_security_group_ingress(input)
+    }
+    fn batch_apply_update_action(
+        &self,
+        input: BatchApplyUpdateActionMessage,
+    ) -> RusotoFuture<UpdateActionResultsMessage, BatchApplyUpdateActionError> {
+        (**self).batch_apply_update_action(input)
+    }
+    fn batch_stop_update_action(
+        &self,
+        input: BatchStopUpdateActionMessage,
+    ) -> RusotoFuture<UpdateActionResultsMessage, BatchStopUpdateActionError> {
+        (**self).batch_stop_update_action(input)
+    }
+    fn copy_snapshot(
+        &self,
+        input: CopySnapshotMessage,
+    ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
+        (**self).copy_snapshot(input)
+    }
+    fn create_cache_cluster(
+        &self,
+        input: CreateCacheClusterMessage,
+    ) -> RusotoFuture<CreateCacheClusterResult, CreateCacheClusterError> {
+        (**self).create_cache_cluster(input)
+    }
+    fn create_cache_parameter_group(
+        &self,
+        input: CreateCacheParameterGroupMessage,
+    ) -> RusotoFuture<CreateCacheParameterGroupResult, CreateCacheParameterGroupError> {
+        (**self).create_cache_parameter_group(input)
+    }
+    fn create_cache_security_group(
+        &self,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> RusotoFuture<CreateCacheSecurityGroupResult, CreateCacheSecurityGroupError> {
+        (**self).create_cache_security_group(input)
+    }
+    fn create_cache_subnet_group(
+        &self,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> RusotoFuture<CreateCacheSubnetGroupResult, CreateCacheSubnetGroupError> {
+        (**self).create_cache_subnet_group(input)
+    }
+    fn create_replication_group(
+        &self,
+        input: CreateReplicationGroupMessage,
+    ) -> RusotoFuture<CreateReplicationGroupResult, CreateReplicationGroupError> {
+        (**self).create_replication_group(input)
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotMessage,
+    ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
+        (**self).create_snapshot(input)
+    }
+    fn decrease_replica_count(
+        &self,
+        input: DecreaseReplicaCountMessage,
+    ) -> RusotoFuture<DecreaseReplicaCountResult, DecreaseReplicaCountError> {
+        (**self).decrease_replica_count(input)
+    }
+    fn delete_cache_cluster(
+        &self,
+        input: DeleteCacheClusterMessage,
+    ) -> RusotoFuture<DeleteCacheClusterResult, DeleteCacheClusterError> {
+        (**self).delete_cache_cluster(input)
+    }
+    fn delete_cache_parameter_group(
+        &self,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheParameterGroupError> {
+        (**self).delete_cache_parameter_group(input)
+    }
+    fn delete_cache_security_group(
+        &self,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheSecurityGroupError> {
+        (**self).delete_cache_security_group(input)
+    }
+    fn delete_cache_subnet_group(
+        &self,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheSubnetGroupError> {
+        (**self).delete_cache_subnet_group(input)
+    }
+    fn delete_replication_group(
+        &self,
+        input: DeleteReplicationGroupMessage,
+    ) -> RusotoFuture<DeleteReplicationGroupResult, DeleteReplicationGroupError> {
+        (**self).delete_replication_group(input)
+    }
+    fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotMessage,
+    ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
+        (**self).delete_snapshot(input)
+    }
+    fn describe_cache_clusters(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> RusotoFuture<CacheClusterMessage, DescribeCacheClustersError> {
+        (**self).describe_cache_clusters(input)
+    }
+    fn describe_cache_engine_versions(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> RusotoFuture<CacheEngineVersionMessage, DescribeCacheEngineVersionsError> {
+        (**self).describe_cache_engine_versions(input)
+    }
+    fn describe_cache_parameter_groups(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> RusotoFuture<CacheParameterGroupsMessage, DescribeCacheParameterGroupsError> {
+        (**self).describe_cache_parameter_groups(input)
+    }
+    fn describe_cache_parameters(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> RusotoFuture<CacheParameterGroupDetails, DescribeCacheParametersError> {
+        (**self).describe_cache_parameters(input)
+    }
+    fn describe_cache_security_groups(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> RusotoFuture<CacheSecurityGroupMessage, DescribeCacheSecurityGroupsError> {
+        (**self).describe_cache_security_groups(input)
+    }
+    fn describe_cache_subnet_groups(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> RusotoFuture<CacheSubnetGroupMessage, DescribeCacheSubnetGroupsError> {
+        (**self).describe_cache_subnet_groups(input)
+    }
+    fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> RusotoFuture<DescribeEngineDefaultParametersResult, DescribeEngineDefaultParametersError>
+    {
+        (**self).describe_engine_default_parameters(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_replication_groups(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> RusotoFuture<ReplicationGroupMessage, DescribeReplicationGroupsError> {
+        (**self).describe_replication_groups(input)
+    }
+    fn describe_reserved_cache_nodes(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> RusotoFuture<ReservedCacheNodeMessage, DescribeReservedCacheNodesError> {
+        (**self).describe_reserved_cache_nodes(input)
+    }
+    fn describe_reserved_cache_nodes_offerings(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> RusotoFuture<ReservedCacheNodesOfferingMessage, DescribeReservedCacheNodesOfferingsError>
+    {
+        (**self).describe_reserved_cache_nodes_offerings(input)
+    }
+    fn describe_service_updates(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> RusotoFuture<ServiceUpdatesMessage, DescribeServiceUpdatesError> {
+        (**self).describe_service_updates(input)
+    }
+    fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> RusotoFuture<DescribeSnapshotsListMessage, DescribeSnapshotsError> {
+        (**self).describe_snapshots(input)
+    }
+    fn describe_update_actions(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> RusotoFuture<UpdateActionsMessage, DescribeUpdateActionsError> {
+        (**self).describe_update_actions(input)
+    }
+    fn increase_replica_count(
+        &self,
+        input: IncreaseReplicaCountMessage,
+    ) -> RusotoFuture<IncreaseReplicaCountResult, IncreaseReplicaCountError> {
+        (**self).increase_replica_count(input)
+    }
+    fn list_allowed_node_type_modifications(
+        &self,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> RusotoFuture<AllowedNodeTypeModificationsMessage, ListAllowedNodeTypeModificationsError>
+    {
+        (**self).list_allowed_node_type_modifications(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_cache_cluster(
+        &self,
+        input: ModifyCacheClusterMessage,
+    ) -> RusotoFuture<ModifyCacheClusterResult, ModifyCacheClusterError> {
+        (**self).modify_cache_cluster(input)
+    }
+    fn modify_cache_parameter_group(
+        &self,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> RusotoFuture<CacheParameterGroupNameMessage, ModifyCacheParameterGroupError> {
+        (**self).modify_cache_parameter_group(input)
+    }
+    fn modify_cache_subnet_group(
+        &self,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyCacheSubnetGroupResult, ModifyCacheSubnetGroupError> {
+        (**self).modify_cache_subnet_group(input)
+    }
+    fn modify_replication_group(
+        &self,
+        input: ModifyReplicationGroupMessage,
+    ) -> RusotoFuture<ModifyReplicationGroupResult, ModifyReplicationGroupError> {
+        (**self).modify_replication_group(input)
+    }
+    fn modify_replication_group_shard_configuration(
+        &self,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> RusotoFuture<
+        ModifyReplicationGroupShardConfigurationResult,
+        ModifyReplicationGroupShardConfigurationError,
+    > {
+        (**self).modify_replication_group_shard_configuration(input)
+    }
+    fn purchase_reserved_cache_nodes_offering(
+        &self,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> RusotoFuture<
+        PurchaseReservedCacheNodesOfferingResult,
+        PurchaseReservedCacheNodesOfferingError,
+    > {
+        (**self).purchase_reserved_cache_nodes_offering(input)
+    }
+    fn reboot_cache_cluster(
+        &self,
+        input: RebootCacheClusterMessage,
+    ) -> RusotoFuture<RebootCacheClusterResult, RebootCacheClusterError> {
+        (**self).reboot_cache_cluster(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<TagListMessage, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_cache_parameter_group(
+        &self,
+        input: ResetCacheParameterGroupMessage,
+    ) -> RusotoFuture<CacheParameterGroupNameMessage, ResetCacheParameterGroupError> {
+        (**self).reset_cache_parameter_group(input)
+    }
+    fn revoke_cache_security_group_ingress(
+        &self,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeCacheSecurityGroupIngressResult, RevokeCacheSecurityGroupIngressError>
+    {
+        (**self).revoke_cache_security_group_ingress(input)
+    }
+    fn test_failover(
+        &self,
+        input: TestFailoverMessage,
+    ) -> RusotoFuture<TestFailoverResult, TestFailoverError> {
+        (**self).test_failover(input)
+    }
+}
+impl<T: ElastiCache + ?Sized> ElastiCache for ::std::sync::Arc<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<TagListMessage, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn authorize_cache_security_group_ingress(
+        &self,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> RusotoFuture<
+        AuthorizeCacheSecurityGroupIngressResult,
+        AuthorizeCacheSecurityGroupIngressError,
+    > {
+        (**self).authorize_cache_security_group_ingress(input)
+    }
+    fn batch_apply_update_action(
+        &self,
+        input: BatchApplyUpdateActionMessage,
+    ) -> RusotoFuture<UpdateActionResultsMessage, BatchApplyUpdateActionError> {
+        (**self).batch_apply_update_action(input)
+    }
+    fn batch_stop_update_action(
+        &self,
+        input: BatchStopUpdateActionMessage,
+    ) -> RusotoFuture<UpdateActionResultsMessage, BatchStopUpdateActionError> {
+        (**self).batch_stop_update_action(input)
+    }
+    fn copy_snapshot(
+        &self,
+        input: CopySnapshotMessage,
+    ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
+        (**self).copy_snapshot(input)
+    }
+    fn create_cache_cluster(
+        &self,
+        input: CreateCacheClusterMessage,
+    ) -> RusotoFuture<CreateCacheClusterResult, CreateCacheClusterError> {
+        (**self).create_cache_cluster(input)
+    }
+    fn create_cache_parameter_group(
+        &self,
+        input: CreateCacheParameterGroupMessage,
+    ) -> RusotoFuture<CreateCacheParameterGroupResult, CreateCacheParameterGroupError> {
+        (**self).create_cache_parameter_group(input)
+    }
+    fn create_cache_security_group(
+        &self,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> RusotoFuture<CreateCacheSecurityGroupResult, CreateCacheSecurityGroupError> {
+        (**self).create_cache_security_group(input)
+    }
+    fn create_cache_subnet_group(
+        &self,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> RusotoFuture<CreateCacheSubnetGroupResult, CreateCacheSubnetGroupError> {
+        (**self).create_cache_subnet_group(input)
+    }
+    fn create_replication_group(
+        &self,
+        input: CreateReplicationGroupMessage,
+    ) -> RusotoFuture<CreateReplicationGroupResult, CreateReplicationGroupError> {
+        (**self).create_replication_group(input)
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotMessage,
+    ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
+        (**self).create_snapshot(input)
+    }
+    fn decrease_replica_count(
+        &self,
+        input: DecreaseReplicaCountMessage,
+    ) -> RusotoFuture<DecreaseReplicaCountResult, DecreaseReplicaCountError> {
+        (**self).decrease_replica_count(input)
+    }
+    fn delete_cache_cluster(
+        &self,
+        input: DeleteCacheClusterMessage,
+    ) -> RusotoFuture<DeleteCacheClusterResult, DeleteCacheClusterError> {
+        (**self).delete_cache_cluster(input)
+    }
+    fn delete_cache_parameter_group(
+        &self,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheParameterGroupError> {
+        (**self).delete_cache_parameter_group(input)
+    }
+    fn delete_cache_security_group(
+        &self,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheSecurityGroupError> {
+        (**self).delete_cache_security_group(input)
+    }
+    fn delete_cache_subnet_group(
+        &self,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheSubnetGroupError> {
+        (**self).delete_cache_subnet_group(input)
+    }
+    fn delete_replication_group(
+        &self,
+        input: DeleteReplicationGroupMessage,
+    ) -> RusotoFuture<DeleteReplicationGroupResult, DeleteReplicationGroupError> {
+        (**self).delete_replication_group(input)
+    }
+    fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotMessage,
+    ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
+        (**self).delete_snapshot(input)
+    }
+    fn describe_cache_clusters(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> RusotoFuture<CacheClusterMessage, DescribeCacheClustersError> {
+        (**self).describe_cache_clusters(input)
+    }
+    fn describe_cache_engine_versions(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> RusotoFuture<CacheEngineVersionMessage, DescribeCacheEngineVersionsError> {
+        (**self).describe_cache_engine_versions(input)
+    }
+    fn describe_cache_parameter_groups(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> RusotoFuture<CacheParameterGroupsMessage, DescribeCacheParameterGroupsError> {
+        (**self).describe_cache_parameter_groups(input)
+    }
+    fn describe_cache_parameters(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> RusotoFuture<CacheParameterGroupDetails, DescribeCacheParametersError> {
+        (**self).describe_cache_parameters(input)
+    }
+    fn describe_cache_security_groups(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> RusotoFuture<CacheSecurityGroupMessage, DescribeCacheSecurityGroupsError> {
+        (**self).describe_cache_security_groups(input)
+    }
+    fn describe_cache_subnet_groups(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> RusotoFuture<CacheSubnetGroupMessage, DescribeCacheSubnetGroupsError> {
+        (**self).describe_cache_subnet_groups(input)
+    }
+    fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> RusotoFuture<DescribeEngineDefaultParametersResult, DescribeEngineDefaultParametersError>
+    {
+        (**self).describe_engine_default_parameters(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_replication_groups(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> RusotoFuture<ReplicationGroupMessage, DescribeReplicationGroupsError> {
+        (**self).describe_replication_groups(input)
+    }
+    fn describe_reserved_cache_nodes(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> RusotoFuture<ReservedCacheNodeMessage, DescribeReservedCacheNodesError> {
+        (**self).describe_reserved_cache_nodes(input)
+    }
+    fn describe_reserved_cache_nodes_offerings(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> RusotoFuture<ReservedCacheNodesOfferingMessage, DescribeReservedCacheNodesOfferingsError>
+    {
+        (**self).describe_reserved_cache_nodes_offerings(input)
+    }
+    fn describe_service_updates(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> RusotoFuture<ServiceUpdatesMessage, DescribeServiceUpdatesError> {
+        (**self).describe_service_updates(input)
+    }
+    fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> RusotoFuture<DescribeSnapshotsListMessage, DescribeSnapshotsError> {
+        (**self).describe_snapshots(input)
+    }
+    fn describe_update_actions(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> RusotoFuture<UpdateActionsMessage, DescribeUpdateActionsError> {
+        (**self).describe_update_actions(input)
+    }
+    fn increase_replica_count(
+        &self,
+        input: IncreaseReplicaCountMessage,
+    ) -> RusotoFuture<IncreaseReplicaCountResult, IncreaseReplicaCountError> {
+        (**self).increase_replica_count(input)
+    }
+    fn list_allowed_node_type_modifications(
+        &self,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> RusotoFuture<AllowedNodeTypeModificationsMessage, ListAllowedNodeTypeModificationsError>
+    {
+        (**self).list_allowed_node_type_modifications(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_cache_cluster(
+        &self,
+        input: ModifyCacheClusterMessage,
+    ) -> RusotoFuture<ModifyCacheClusterResult, ModifyCacheClusterError> {
+        (**self).modify_cache_cluster(input)
+    }
+    fn modify_cache_parameter_group(
+        &self,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> RusotoFuture<CacheParameterGroupNameMessage, ModifyCacheParameterGroupError> {
+        (**self).modify_cache_parameter_group(input)
+    }
+    fn modify_cache_subnet_group(
+        &self,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyCacheSubnetGroupResult, ModifyCacheSubnetGroupError> {
+        (**self).modify_cache_subnet_group(input)
+    }
+    fn modify_replication_group(
+        &self,
+        input: ModifyReplicationGroupMessage,
+    ) -> RusotoFuture<ModifyReplicationGroupResult, ModifyReplicationGroupError> {
+        (**self).modify_replication_group(input)
+    }
+    fn modify_replication_group_shard_configuration(
+        &self,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> RusotoFuture<
+        ModifyReplicationGroupShardConfigurationResult,
+        ModifyReplicationGroupShardConfigurationError,
+    > {
+        (**self).modify_replication_group_shard_configuration(input)
+    }
+    fn purchase_reserved_cache_nodes_offering(
+        &self,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> RusotoFuture<
+        PurchaseReservedCacheNodesOfferingResult,
+        PurchaseReservedCacheNodesOfferingError,
+    > {
+        (**self).purchase_reserved_cache_nodes_offering(input)
+    }
+    fn reboot_cache_cluster(
+        &self,
+        input: RebootCacheClusterMessage,
+    ) -> RusotoFuture<RebootCacheClusterResult, RebootCacheClusterError> {
+        (**self).reboot_cache_cluster(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<TagListMessage, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_cache_parameter_group(
+        &self,
+        input: ResetCacheParameterGroupMessage,
+    ) -> RusotoFuture<CacheParameterGroupNameMessage, ResetCacheParameterGroupError> {
+        (**self).reset_cache_parameter_group(input)
+    }
+    fn revoke_cache_security_group_ingress(
+        &self,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeCacheSecurityGroupIngressResult, RevokeCacheSecurityGroupIngressError>
+    {
+        (**self).revoke_cache_security_group_ingress(input)
+    }
+    fn test_failover(
+        &self,
+        input: TestFailoverMessage,
+    ) -> RusotoFuture<TestFailoverResult, TestFailoverError> {
+        (**self).test_failover(input)
+    }
+}
+/// A client for the Amazon ElastiCache API.
+#[derive(Clone)]
+pub struct ElastiCacheClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ElastiCacheClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ElastiCacheClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElastiCacheClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ElastiCacheClient {
+        ElastiCacheClient { client, region }
+    }
+}
+
+impl ElastiCache for ElastiCacheClient {
+    /// <p>Adds up to 50 cost allocation tags to the named resource. A cost allocation tag is a key-value pair where the key and value are case-sensitive. You can use cost allocation tags to categorize and track your AWS costs.</p> <p> When you apply tags to your ElastiCache resources, AWS generates a cost allocation report as a comma-separated value (CSV) file with your usage and costs aggregated by your tags. You can apply tags that represent business categories (such as cost centers, application names, or owners) to organize your costs across multiple services. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Tagging.html">Using Cost Allocation Tags in Amazon ElastiCache</a> in the <i>ElastiCache User Guide</i>.</p>
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<TagListMessage, AddTagsToResourceError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("AddTagsToResource");
+        let mut params = Params::new();
+
+        params.put("Action", "AddTagsToResource");
+        params.put("Version", "2015-02-02");
+        AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TagListMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TagListMessageDeserializer::deserialize(
+                        "AddTagsToResourceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("AddTagsToResource", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Allows network ingress to a cache security group. Applications using ElastiCache must be running on Amazon EC2, and Amazon EC2 security groups are used as the authorization mechanism.</p> <note> <p>You cannot authorize ingress from an Amazon EC2 security group in one region to an ElastiCache cluster in another region.</p> </note></p>
+    fn authorize_cache_security_group_ingress(
+        &self,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> RusotoFuture<
+        AuthorizeCacheSecurityGroupIngressResult,
+        AuthorizeCacheSecurityGroupIngressError,
+    > {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("AuthorizeCacheSecurityGroupIngress");
+        let mut params = Params::new();
+
+        params.put("Action", "AuthorizeCacheSecurityGroupIngress");
+        params.put("Version", "2015-02-02");
+        AuthorizeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AuthorizeCacheSecurityGroupIngressError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AuthorizeCacheSecurityGroupIngressResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AuthorizeCacheSecurityGroupIngressResultDeserializer::deserialize(
+                        "AuthorizeCacheSecurityGroupIngressResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("AuthorizeCacheSecurityGroupIngress", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Apply the service update. For more information on service updates and applying them, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/applying-updates.html">Applying Service Updates</a>.</p>
+    fn batch_apply_update_action(
+        &self,
+        input: BatchApplyUpdateActionMessage,
+    ) -> RusotoFuture<UpdateActionResultsMessage, BatchApplyUpdateActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("BatchApplyUpdateAction");
+        let mut params = Params::new();
+
+        params.put("Action", "BatchApplyUpdateAction");
+        params.put("Version", "2015-02-02");
+        BatchApplyUpdateActionMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchApplyUpdateActionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateActionResultsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = UpdateActionResultsMessageDeserializer::deserialize(
+                        "BatchApplyUpdateActionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("BatchApplyUpdateAction", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Stop the service update. For more information on service updates and stopping them, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/stopping-self-service-updates.html">Stopping Service Updates</a>.</p>
+    fn batch_stop_update_action(
+        &self,
+        input: BatchStopUpdateActionMessage,
+    ) -> RusotoFuture<UpdateActionResultsMessage, BatchStopUpdateActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("BatchStopUpdateAction");
+        let mut params = Params::new();
+
+        params.put("Action", "BatchStopUpdateAction");
+        params.put("Version", "2015-02-02");
+        BatchStopUpdateActionMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchStopUpdateActionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateActionResultsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = UpdateActionResultsMessageDeserializer::deserialize(
+                        "BatchStopUpdateActionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("BatchStopUpdateAction", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Makes a copy of an existing snapshot.</p> <note> <p>This operation is valid for Redis only.</p> </note> <important> <p>Users or groups that have permissions to use the <code>CopySnapshot</code> operation can create their own Amazon S3 buckets and copy snapshots to it. To control access to your snapshots, use an IAM policy to control who has the ability to use the <code>CopySnapshot</code> operation. For more information about using IAM to control the use of ElastiCache operations, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html">Exporting Snapshots</a> and <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/IAM.html">Authentication &amp; Access Control</a>.</p> </important> <p>You could receive the following error messages.</p> <p class="title"> <b>Error Messages</b> </p> <ul> <li> <p> <b>Error Message:</b> The S3 bucket %s is outside of the region.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s does not exist.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s is not owned by the authenticated user.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The authenticated user does not have sufficient permissions to perform the desired activity.</p> <p> <b>Solution:</b> Contact your system administrator to get the needed permissions.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s already contains an object with key %s.</p> <p> <b>Solution:</b> Give the <code>TargetSnapshotName</code> a new and unique value. If exporting a snapshot, you could alternatively create a new Amazon S3 bucket and use this same value for <code>TargetSnapshotName</code>.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted READ permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add List and Read permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted WRITE permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add Upload/Delete permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted READ_ACP permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add View Permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> </ul></p>
+    fn copy_snapshot(
+        &self,
+        input: CopySnapshotMessage,
+    ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CopySnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "CopySnapshot");
+        params.put("Version", "2015-02-02");
+        CopySnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CopySnapshotError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CopySnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CopySnapshotResultDeserializer::deserialize(
+                        "CopySnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CopySnapshot", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a cluster. All nodes in the cluster run the same protocol-compliant cache engine software, either Memcached or Redis.</p> <p>This operation is not supported for Redis (cluster mode enabled) clusters.</p>
+    fn create_cache_cluster(
+        &self,
+        input: CreateCacheClusterMessage,
+    ) -> RusotoFuture<CreateCacheClusterResult, CreateCacheClusterError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateCacheCluster");
+        params.put("Version", "2015-02-02");
+        CreateCacheClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateCacheClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateCacheClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateCacheClusterResultDeserializer::deserialize(
+                        "CreateCacheClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateCacheCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Creates a new Amazon ElastiCache cache parameter group. An ElastiCache cache parameter group is a collection of parameters and their values that are applied to all of the nodes in any cluster or replication group using the CacheParameterGroup.</p> <p>A newly created CacheParameterGroup is an exact duplicate of the default parameter group for the CacheParameterGroupFamily. To customize the newly created CacheParameterGroup you can change the values of specific parameters. For more information, see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_ModifyCacheParameterGroup.html">ModifyCacheParameterGroup</a> in the ElastiCache API Reference.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ParameterGroups.html">Parameters and Parameter Groups</a> in the ElastiCache User Guide.</p> </li> </ul></p>
+    fn create_cache_parameter_group(
+        &self,
+        input: CreateCacheParameterGroupMessage,
+    ) -> RusotoFuture<CreateCacheParameterGroupResult, CreateCacheParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateCacheParameterGroup");
+        params.put("Version", "2015-02-02");
+        CreateCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateCacheParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateCacheParameterGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateCacheParameterGroupResultDeserializer::deserialize(
+                        "CreateCacheParameterGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateCacheParameterGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new cache security group. Use a cache security group to control access to one or more clusters.</p> <p>Cache security groups are only used when you are creating a cluster outside of an Amazon Virtual Private Cloud (Amazon VPC). If you are creating a cluster inside of a VPC, use a cache subnet group instead. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_CreateCacheSubnetGroup.html">CreateCacheSubnetGroup</a>.</p>
+    fn create_cache_security_group(
+        &self,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> RusotoFuture<CreateCacheSecurityGroupResult, CreateCacheSecurityGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheSecurityGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateCacheSecurityGroup");
+        params.put("Version", "2015-02-02");
+        CreateCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateCacheSecurityGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateCacheSecurityGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateCacheSecurityGroupResultDeserializer::deserialize(
+                        "CreateCacheSecurityGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateCacheSecurityGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new cache subnet group.</p> <p>Use this parameter only when you are creating a cluster in an Amazon Virtual Private Cloud (Amazon VPC).</p>
+    fn create_cache_subnet_group(
+        &self,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> RusotoFuture<CreateCacheSubnetGroupResult, CreateCacheSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheSubnetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateCacheSubnetGroup");
+        params.put("Version", "2015-02-02");
+        CreateCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateCacheSubnetGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateCacheSubnetGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateCacheSubnetGroupResultDeserializer::deserialize(
+                        "CreateCacheSubnetGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateCacheSubnetGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Creates a Redis (cluster mode disabled) or a Redis (cluster mode enabled) replication group.</p> <p>A Redis (cluster mode disabled) replication group is a collection of clusters, where one of the clusters is a read/write primary and the others are read-only replicas. Writes to the primary are asynchronously propagated to the replicas.</p> <p>A Redis (cluster mode enabled) replication group is a collection of 1 to 90 node groups (shards). Each node group (shard) has one read/write primary node and up to 5 read-only replica nodes. Writes to the primary are asynchronously propagated to the replicas. Redis (cluster mode enabled) replication groups partition the data across node groups (shards).</p> <p>When a Redis (cluster mode disabled) replication group has been successfully created, you can add one or more read replicas to it, up to a total of 5 read replicas. You cannot alter a Redis (cluster mode enabled) replication group after it has been created. However, if you need to increase or decrease the number of node groups (console: shards), you can avail yourself of ElastiCache for Redis&#39; enhanced backup and restore. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-restoring.html">Restoring From a Backup with Cluster Resizing</a> in the <i>ElastiCache User Guide</i>.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    fn create_replication_group(
+        &self,
+        input: CreateReplicationGroupMessage,
+    ) -> RusotoFuture<CreateReplicationGroupResult, CreateReplicationGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateReplicationGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateReplicationGroup");
+        params.put("Version", "2015-02-02");
+        CreateReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateReplicationGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateReplicationGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateReplicationGroupResultDeserializer::deserialize(
+                        "CreateReplicationGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateReplicationGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Creates a copy of an entire cluster or replication group at a specific moment in time.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotMessage,
+    ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateSnapshot");
+        params.put("Version", "2015-02-02");
+        CreateSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSnapshotError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateSnapshotResultDeserializer::deserialize(
+                        "CreateSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateSnapshot", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Dynamically decreases the number of replics in a Redis (cluster mode disabled) replication group or the number of replica nodes in one or more node groups (shards) of a Redis (cluster mode enabled) replication group. This operation is performed with no cluster down time.</p>
+    fn decrease_replica_count(
+        &self,
+        input: DecreaseReplicaCountMessage,
+    ) -> RusotoFuture<DecreaseReplicaCountResult, DecreaseReplicaCountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DecreaseReplicaCount");
+        let mut params = Params::new();
+
+        params.put("Action", "DecreaseReplicaCount");
+        params.put("Version", "2015-02-02");
+        DecreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DecreaseReplicaCountError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DecreaseReplicaCountResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DecreaseReplicaCountResultDeserializer::deserialize(
+                        "DecreaseReplicaCountResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DecreaseReplicaCount", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Deletes a previously provisioned cluster. <code>DeleteCacheCluster</code> deletes all associated cache nodes, node endpoints and the cluster itself. When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the cluster; you cannot cancel or revert this operation.</p> <p>This operation is not valid for:</p> <ul> <li> <p>Redis (cluster mode enabled) clusters</p> </li> <li> <p>A cluster that is the last read replica of a replication group</p> </li> <li> <p>A node group (shard) that has Multi-AZ mode enabled</p> </li> <li> <p>A cluster from a Redis (cluster mode enabled) replication group</p> </li> <li> <p>A cluster that is not in the <code>available</code> state</p> </li> </ul></p>
+    fn delete_cache_cluster(
+        &self,
+        input: DeleteCacheClusterMessage,
+    ) -> RusotoFuture<DeleteCacheClusterResult, DeleteCacheClusterError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteCacheCluster");
+        params.put("Version", "2015-02-02");
+        DeleteCacheClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteCacheClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteCacheClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteCacheClusterResultDeserializer::deserialize(
+                        "DeleteCacheClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteCacheCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified cache parameter group. You cannot delete a cache parameter group if it is associated with any cache clusters.</p>
+    fn delete_cache_parameter_group(
+        &self,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteCacheParameterGroup");
+        params.put("Version", "2015-02-02");
+        DeleteCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteCacheParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p><p>Deletes a cache security group.</p> <note> <p>You cannot delete a cache security group if it is associated with any clusters.</p> </note></p>
+    fn delete_cache_security_group(
+        &self,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheSecurityGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheSecurityGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteCacheSecurityGroup");
+        params.put("Version", "2015-02-02");
+        DeleteCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteCacheSecurityGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p><p>Deletes a cache subnet group.</p> <note> <p>You cannot delete a cache subnet group if it is associated with any clusters.</p> </note></p>
+    fn delete_cache_subnet_group(
+        &self,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheSubnetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteCacheSubnetGroup");
+        params.put("Version", "2015-02-02");
+        DeleteCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteCacheSubnetGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p><p>Deletes an existing replication group. By default, this operation deletes the entire replication group, including the primary/primaries and all of the read replicas. If the replication group has only one primary, you can optionally delete only the read replicas, while retaining the primary by setting <code>RetainPrimaryCluster=true</code>.</p> <p>When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the selected resources; you cannot cancel or revert this operation.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    fn delete_replication_group(
+        &self,
+        input: DeleteReplicationGroupMessage,
+    ) -> RusotoFuture<DeleteReplicationGroupResult, DeleteReplicationGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteReplicationGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteReplicationGroup");
+        params.put("Version", "2015-02-02");
+        DeleteReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteReplicationGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteReplicationGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteReplicationGroupResultDeserializer::deserialize(
+                        "DeleteReplicationGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteReplicationGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Deletes an existing snapshot. When you receive a successful response from this operation, ElastiCache immediately begins deleting the snapshot; you cannot cancel or revert this operation.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotMessage,
+    ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteSnapshot");
+        params.put("Version", "2015-02-02");
+        DeleteSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteSnapshotError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteSnapshotResultDeserializer::deserialize(
+                        "DeleteSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteSnapshot", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about all provisioned clusters if no cluster identifier is specified, or about a specific cache cluster if a cluster identifier is supplied.</p> <p>By default, abbreviated information about the clusters is returned. You can use the optional <i>ShowCacheNodeInfo</i> flag to retrieve detailed information about the cache nodes associated with the clusters. These details include the DNS address and port for the cache node endpoint.</p> <p>If the cluster is in the <i>creating</i> state, only cluster-level information is displayed until all of the nodes are successfully provisioned.</p> <p>If the cluster is in the <i>deleting</i> state, only cluster-level information is displayed.</p> <p>If cache nodes are currently being added to the cluster, node endpoint information and creation time for the additional nodes are not displayed until they are completely provisioned. When the cluster state is <i>available</i>, the cluster is ready for use.</p> <p>If cache nodes are currently being removed from the cluster, no endpoint information for the removed nodes is displayed.</p>
+    fn describe_cache_clusters(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> RusotoFuture<CacheClusterMessage, DescribeCacheClustersError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheClusters");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeCacheClusters");
+        params.put("Version", "2015-02-02");
+        DescribeCacheClustersMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCacheClustersError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CacheClusterMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CacheClusterMessageDeserializer::deserialize(
+                        "DescribeCacheClustersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeCacheClusters", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of the available cache engines and their versions.</p>
+    fn describe_cache_engine_versions(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> RusotoFuture<CacheEngineVersionMessage, DescribeCacheEngineVersionsError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheEngineVersions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeCacheEngineVersions");
+        params.put("Version", "2015-02-02");
+        DescribeCacheEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCacheEngineVersionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CacheEngineVersionMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CacheEngineVersionMessageDeserializer::deserialize(
+                        "DescribeCacheEngineVersionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeCacheEngineVersions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of cache parameter group descriptions. If a cache parameter group name is specified, the list contains only the descriptions for that group.</p>
+    fn describe_cache_parameter_groups(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> RusotoFuture<CacheParameterGroupsMessage, DescribeCacheParameterGroupsError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheParameterGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeCacheParameterGroups");
+        params.put("Version", "2015-02-02");
+        DescribeCacheParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCacheParameterGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CacheParameterGroupsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CacheParameterGroupsMessageDeserializer::deserialize(
+                        "DescribeCacheParameterGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeCacheParameterGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the detailed parameter list for a particular cache parameter group.</p>
+    fn describe_cache_parameters(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> RusotoFuture<CacheParameterGroupDetails, DescribeCacheParametersError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheParameters");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeCacheParameters");
+        params.put("Version", "2015-02-02");
+        DescribeCacheParametersMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCacheParametersError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CacheParameterGroupDetails::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CacheParameterGroupDetailsDeserializer::deserialize(
+                        "DescribeCacheParametersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeCacheParameters", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of cache security group descriptions. If a cache security group name is specified, the list contains only the description of that group. This applicable only when you have ElastiCache in Classic setup </p>
+    fn describe_cache_security_groups(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> RusotoFuture<CacheSecurityGroupMessage, DescribeCacheSecurityGroupsError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheSecurityGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeCacheSecurityGroups");
+        params.put("Version", "2015-02-02");
+        DescribeCacheSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCacheSecurityGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CacheSecurityGroupMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CacheSecurityGroupMessageDeserializer::deserialize(
+                        "DescribeCacheSecurityGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeCacheSecurityGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of cache subnet group descriptions. If a subnet group name is specified, the list contains only the description of that group. This is applicable only when you have ElastiCache in VPC setup. All ElastiCache clusters now launch in VPC by default. </p>
+    fn describe_cache_subnet_groups(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> RusotoFuture<CacheSubnetGroupMessage, DescribeCacheSubnetGroupsError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheSubnetGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeCacheSubnetGroups");
+        params.put("Version", "2015-02-02");
+        DescribeCacheSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCacheSubnetGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CacheSubnetGroupMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CacheSubnetGroupMessageDeserializer::deserialize(
+                        "DescribeCacheSubnetGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeCacheSubnetGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the default engine and system parameter information for the specified cache engine.</p>
+    fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> RusotoFuture<DescribeEngineDefaultParametersResult, DescribeEngineDefaultParametersError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultParameters");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEngineDefaultParameters");
+        params.put("Version", "2015-02-02");
+        DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEngineDefaultParametersError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeEngineDefaultParametersResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeEngineDefaultParametersResultDeserializer::deserialize(
+                        "DescribeEngineDefaultParametersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeEngineDefaultParameters", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns events related to clusters, cache security groups, and cache parameter groups. You can obtain events specific to a particular cluster, cache security group, or cache parameter group by providing the name as a parameter.</p> <p>By default, only the events occurring within the last hour are returned; however, you can retrieve up to 14 days' worth of events if necessary.</p>
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeEvents");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEvents");
+        params.put("Version", "2015-02-02");
+        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeEventsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EventsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        EventsMessageDeserializer::deserialize("DescribeEventsResult", &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("DescribeEvents", err, &response.body)
+                            })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Returns information about a particular replication group. If no identifier is specified, <code>DescribeReplicationGroups</code> returns information about all replication groups.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    fn describe_replication_groups(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> RusotoFuture<ReplicationGroupMessage, DescribeReplicationGroupsError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReplicationGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeReplicationGroups");
+        params.put("Version", "2015-02-02");
+        DescribeReplicationGroupsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReplicationGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ReplicationGroupMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ReplicationGroupMessageDeserializer::deserialize(
+                        "DescribeReplicationGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeReplicationGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about reserved cache nodes for this account, or about a specified reserved cache node.</p>
+    fn describe_reserved_cache_nodes(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> RusotoFuture<ReservedCacheNodeMessage, DescribeReservedCacheNodesError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReservedCacheNodes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeReservedCacheNodes");
+        params.put("Version", "2015-02-02");
+        DescribeReservedCacheNodesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReservedCacheNodesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ReservedCacheNodeMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ReservedCacheNodeMessageDeserializer::deserialize(
+                        "DescribeReservedCacheNodesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeReservedCacheNodes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists available reserved cache node offerings.</p>
+    fn describe_reserved_cache_nodes_offerings(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> RusotoFuture<ReservedCacheNodesOfferingMessage, DescribeReservedCacheNodesOfferingsError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReservedCacheNodesOfferings");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeReservedCacheNodesOfferings");
+        params.put("Version", "2015-02-02");
+        DescribeReservedCacheNodesOfferingsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReservedCacheNodesOfferingsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ReservedCacheNodesOfferingMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ReservedCacheNodesOfferingMessageDeserializer::deserialize(
+                        "DescribeReservedCacheNodesOfferingsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "DescribeReservedCacheNodesOfferings",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns details of the service updates</p>
+    fn describe_service_updates(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> RusotoFuture<ServiceUpdatesMessage, DescribeServiceUpdatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeServiceUpdates");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeServiceUpdates");
+        params.put("Version", "2015-02-02");
+        DescribeServiceUpdatesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeServiceUpdatesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ServiceUpdatesMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ServiceUpdatesMessageDeserializer::deserialize(
+                        "DescribeServiceUpdatesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeServiceUpdates", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Returns information about cluster or replication group snapshots. By default, <code>DescribeSnapshots</code> lists all of your snapshots; it can optionally describe a single snapshot, or just the snapshots associated with a particular cache cluster.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> RusotoFuture<DescribeSnapshotsListMessage, DescribeSnapshotsError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeSnapshots");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeSnapshots");
+        params.put("Version", "2015-02-02");
+        DescribeSnapshotsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeSnapshotsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeSnapshotsListMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeSnapshotsListMessageDeserializer::deserialize(
+                        "DescribeSnapshotsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeSnapshots", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns details of the update actions </p>
+    fn describe_update_actions(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> RusotoFuture<UpdateActionsMessage, DescribeUpdateActionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeUpdateActions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeUpdateActions");
+        params.put("Version", "2015-02-02");
+        DescribeUpdateActionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeUpdateActionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateActionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = UpdateActionsMessageDeserializer::deserialize(
+                        "DescribeUpdateActionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeUpdateActions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Dynamically increases the number of replics in a Redis (cluster mode disabled) replication group or the number of replica nodes in one or more node groups (shards) of a Redis (cluster mode enabled) replication group. This operation is performed with no cluster down time.</p>
+    fn increase_replica_count(
+        &self,
+        input: IncreaseReplicaCountMessage,
+    ) -> RusotoFuture<IncreaseReplicaCountResult, IncreaseReplicaCountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("IncreaseReplicaCount");
+        let mut params = Params::new();
+
+        params.put("Action", "IncreaseReplicaCount");
+        params.put("Version", "2015-02-02");
+        IncreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(IncreaseReplicaCountError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = IncreaseReplicaCountResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = IncreaseReplicaCountResultDeserializer::deserialize(
+                        "IncreaseReplicaCountResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("IncreaseReplicaCount", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists all available node types that you can scale your Redis cluster's or replication group's current node type.</p> <p>When you use the <code>ModifyCacheCluster</code> or <code>ModifyReplicationGroup</code> operations to scale your cluster or replication group, the value of the <code>CacheNodeType</code> parameter must be one of the node types returned by this operation.</p>
+    fn list_allowed_node_type_modifications(
+        &self,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> RusotoFuture<AllowedNodeTypeModificationsMessage, ListAllowedNodeTypeModificationsError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ListAllowedNodeTypeModifications");
+        let mut params = Params::new();
+
+        params.put("Action", "ListAllowedNodeTypeModifications");
+        params.put("Version", "2015-02-02");
+        ListAllowedNodeTypeModificationsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAllowedNodeTypeModificationsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AllowedNodeTypeModificationsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AllowedNodeTypeModificationsMessageDeserializer::deserialize(
+                        "ListAllowedNodeTypeModificationsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListAllowedNodeTypeModifications", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists all cost allocation tags currently on the named resource. A <code>cost allocation tag</code> is a key-value pair where the key is case-sensitive and the value is optional. You can use cost allocation tags to categorize and track your AWS costs.</p> <p>If the cluster is not in the <i>available</i> state, <code>ListTagsForResource</code> returns an error.</p> <p>You can have a maximum of 50 cost allocation tags on an ElastiCache resource. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Tagging.html">Monitoring Costs with Tags</a>.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+        let mut params = Params::new();
+
+        params.put("Action", "ListTagsForResource");
+        params.put("Version", "2015-02-02");
+        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TagListMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TagListMessageDeserializer::deserialize(
+                        "ListTagsForResourceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListTagsForResource", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the settings for a cluster. You can use this operation to change one or more cluster configuration parameters by specifying the parameters and the new values.</p>
+    fn modify_cache_cluster(
+        &self,
+        input: ModifyCacheClusterMessage,
+    ) -> RusotoFuture<ModifyCacheClusterResult, ModifyCacheClusterError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyCacheCluster");
+        params.put("Version", "2015-02-02");
+        ModifyCacheClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ModifyCacheClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyCacheClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyCacheClusterResultDeserializer::deserialize(
+                        "ModifyCacheClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ModifyCacheCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the parameters of a cache parameter group. You can modify up to 20 parameters in a single request by submitting a list parameter name and value pairs.</p>
+    fn modify_cache_parameter_group(
+        &self,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> RusotoFuture<CacheParameterGroupNameMessage, ModifyCacheParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyCacheParameterGroup");
+        params.put("Version", "2015-02-02");
+        ModifyCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyCacheParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CacheParameterGroupNameMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CacheParameterGroupNameMessageDeserializer::deserialize(
+                        "ModifyCacheParameterGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyCacheParameterGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies an existing cache subnet group.</p>
+    fn modify_cache_subnet_group(
+        &self,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyCacheSubnetGroupResult, ModifyCacheSubnetGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheSubnetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyCacheSubnetGroup");
+        params.put("Version", "2015-02-02");
+        ModifyCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyCacheSubnetGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyCacheSubnetGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyCacheSubnetGroupResultDeserializer::deserialize(
+                        "ModifyCacheSubnetGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyCacheSubnetGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Modifies the settings for a replication group.</p> <p>For Redis (cluster mode enabled) clusters, this operation cannot be used to change a cluster&#39;s node type or engine version. For more information, see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/scaling-redis-cluster-mode-enabled.html">Scaling for Amazon ElastiCache for Redis (cluster mode enabled)</a> in the ElastiCache User Guide</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_ModifyReplicationGroupShardConfiguration.html">ModifyReplicationGroupShardConfiguration</a> in the ElastiCache API Reference</p> </li> </ul> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    fn modify_replication_group(
+        &self,
+        input: ModifyReplicationGroupMessage,
+    ) -> RusotoFuture<ModifyReplicationGroupResult, ModifyReplicationGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyReplicationGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyReplicationGroup");
+        params.put("Version", "2015-02-02");
+        ModifyReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyReplicationGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyReplicationGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyReplicationGroupResultDeserializer::deserialize(
+                        "ModifyReplicationGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyReplicationGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies a replication group's shards (node groups) by allowing you to add shards, remove shards, or rebalance the keyspaces among exisiting shards.</p>
+    fn modify_replication_group_shard_configuration(
+        &self,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> RusotoFuture<
+        ModifyReplicationGroupShardConfigurationResult,
+        ModifyReplicationGroupShardConfigurationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyReplicationGroupShardConfiguration");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyReplicationGroupShardConfiguration");
+        params.put("Version", "2015-02-02");
+        ModifyReplicationGroupShardConfigurationMessageSerializer::serialize(
+            &mut params,
+            "",
+            &input,
+        );
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyReplicationGroupShardConfigurationError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyReplicationGroupShardConfigurationResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        ModifyReplicationGroupShardConfigurationResultDeserializer::deserialize(
+                            "ModifyReplicationGroupShardConfigurationResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "ModifyReplicationGroupShardConfiguration",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Allows you to purchase a reserved cache node offering.</p>
+    fn purchase_reserved_cache_nodes_offering(
+        &self,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> RusotoFuture<
+        PurchaseReservedCacheNodesOfferingResult,
+        PurchaseReservedCacheNodesOfferingError,
+    > {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("PurchaseReservedCacheNodesOffering");
+        let mut params = Params::new();
+
+        params.put("Action", "PurchaseReservedCacheNodesOffering");
+        params.put("Version", "2015-02-02");
+        PurchaseReservedCacheNodesOfferingMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PurchaseReservedCacheNodesOfferingError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = PurchaseReservedCacheNodesOfferingResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = PurchaseReservedCacheNodesOfferingResultDeserializer::deserialize(
+                        "PurchaseReservedCacheNodesOfferingResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("PurchaseReservedCacheNodesOffering", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Reboots some, or all, of the cache nodes within a provisioned cluster. This operation applies any modified cache parameter groups to the cluster. The reboot operation takes place as soon as possible, and results in a momentary outage to the cluster. During the reboot, the cluster status is set to REBOOTING.</p> <p>The reboot causes the contents of the cache (for each cache node being rebooted) to be lost.</p> <p>When the reboot is complete, a cluster event is created.</p> <p>Rebooting a cluster is currently supported on Memcached and Redis (cluster mode disabled) clusters. Rebooting is not supported on Redis (cluster mode enabled) clusters.</p> <p>If you make changes to parameters that require a Redis (cluster mode enabled) cluster reboot for the changes to be applied, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Clusters.Rebooting.html">Rebooting a Cluster</a> for an alternate process.</p>
+    fn reboot_cache_cluster(
+        &self,
+        input: RebootCacheClusterMessage,
+    ) -> RusotoFuture<RebootCacheClusterResult, RebootCacheClusterError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RebootCacheCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "RebootCacheCluster");
+        params.put("Version", "2015-02-02");
+        RebootCacheClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RebootCacheClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RebootCacheClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RebootCacheClusterResultDeserializer::deserialize(
+                        "RebootCacheClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("RebootCacheCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Removes the tags identified by the <code>TagKeys</code> list from the named resource.</p>
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<TagListMessage, RemoveTagsFromResourceError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
+        let mut params = Params::new();
+
+        params.put("Action", "RemoveTagsFromResource");
+        params.put("Version", "2015-02-02");
+        RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RemoveTagsFromResourceError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TagListMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TagListMessageDeserializer::deserialize(
+                        "RemoveTagsFromResourceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RemoveTagsFromResource", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the parameters of a cache parameter group to the engine or system default value. You can reset specific parameters by submitting a list of parameter names. To reset the entire cache parameter group, specify the <code>ResetAllParameters</code> and <code>CacheParameterGroupName</code> parameters.</p>
+    fn reset_cache_parameter_group(
+        &self,
+        input: ResetCacheParameterGroupMessage,
+    ) -> RusotoFuture<CacheParameterGroupNameMessage, ResetCacheParameterGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ResetCacheParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "ResetCacheParameterGroup");
+        params.put("Version", "2015-02-02");
+        ResetCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ResetCacheParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CacheParameterGroupNameMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = PurchaseReservedCacheNodesOfferingResultDeserializer::deserialize(
-                        "PurchaseReservedCacheNodesOfferingResult",
+                    result = CacheParameterGroupNameMessageDeserializer::deserialize(
+                        "ResetCacheParameterGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ResetCacheParameterGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Revokes ingress from a cache security group. Use this operation to disallow access from an Amazon EC2 security group that had been previously authorized.</p>
+    fn revoke_cache_security_group_ingress(
+        &self,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeCacheSecurityGroupIngressResult, RevokeCacheSecurityGroupIngressError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RevokeCacheSecurityGroupIngress");
+        let mut params = Params::new();
+
+        params.put("Action", "RevokeCacheSecurityGroupIngress");
+        params.put("Version", "2015-02-02");
+        RevokeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RevokeCacheSecurityGroupIngressError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RevokeCacheSecurityGroupIngressResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RevokeCacheSecurityGroupIngressResultDeserializer::deserialize(
+                        "RevokeCacheSecurityGroupIngressResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RevokeCacheSecurityGroupIngress", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Represents the input of a <code>TestFailover</code> operation which test automatic failover on a specified node group (called shard in the console) in a replication group (called cluster in the console).</p> <p class="title"> <b>Note the following</b> </p> <ul> <li> <p>A customer can use this operation to test automatic failover on up to 5 shards (called node groups in the ElastiCache API and AWS CLI) in any rolling 24-hour period.</p> </li> <li> <p>If calling this operation on shards in different clusters (called replication groups in the API and CLI), the calls can be made concurrently.</p> <p> </p> </li> <li> <p>If calling this operation multiple times on different shards in the same Redis (cluster mode enabled) replication group, the first node replacement must complete before a subsequent call can be made.</p> </li> <li> <p>To determine whether the node replacement is complete you can check Events using the Amazon ElastiCache console, the AWS CLI, or the ElastiCache API. Look for the following automatic failover related events, listed here in order of occurrance:</p> <ol> <li> <p>Replication group message: <code>Test Failover API called for node group &lt;node-group-id&gt;</code> </p> </li> <li> <p>Cache cluster message: <code>Failover from master node &lt;primary-node-id&gt; to replica node &lt;node-id&gt; completed</code> </p> </li> <li> <p>Replication group message: <code>Failover from master node &lt;primary-node-id&gt; to replica node &lt;node-id&gt; completed</code> </p> </li> <li> <p>Cache cluster message: <code>Recovering cache nodes &lt;node-id&gt;</code> </p> </li> <li> <p>Cache cluster message: <code>Finished recovery for cache nodes &lt;node-id&gt;</code> </p> </li> </ol> <p>For more information see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ECEvents.Viewing.html">Viewing ElastiCache Events</a> in the <i>ElastiCache User Guide</i> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_DescribeEvents.html">DescribeEvents</a> in the ElastiCache API Reference</p> </li> </ul> </li> </ul> <p>Also see, <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/AutoFailover.html#auto-failover-test">Testing Multi-AZ with Automatic Failover</a> in the <i>ElastiCache User Guide</i>.</p>
+    fn test_failover(
+        &self,
+        input: TestFailoverMessage,
+    ) -> RusotoFuture<TestFailoverResult, TestFailoverError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("TestFailover");
+        let mut params = Params::new();
+
+        params.put("Action", "TestFailover");
+        params.put("Version", "2015-02-02");
+        TestFailoverMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TestFailoverError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TestFailoverResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TestFailoverResultDeserializer::deserialize(
+                        "TestFailoverResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("TestFailover", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -13313,253 +15151,1641 @@ impl ElastiCache for ElastiCacheClient {
             }))
         })
     }
-
-    /// <p>Reboots some, or all, of the cache nodes within a provisioned cluster. This operation applies any modified cache parameter groups to the cluster. The reboot operation takes place as soon as possible, and results in a momentary outage to the cluster. During the reboot, the cluster status is set to REBOOTING.</p> <p>The reboot causes the contents of the cache (for each cache node being rebooted) to be lost.</p> <p>When the reboot is complete, a cluster event is created.</p> <p>Rebooting a cluster is currently supported on Memcached and Redis (cluster mode disabled) clusters. Rebooting is not supported on Redis (cluster mode enabled) clusters.</p> <p>If you make changes to parameters that require a Redis (cluster mode enabled) cluster reboot for the changes to be applied, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Clusters.Rebooting.html">Rebooting a Cluster</a> for an alternate process.</p>
+}
+
+/// A mock of the Amazon ElastiCache API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockElastiCache {
+    add_tags_to_resource: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToResourceMessage,
+                )
+                    -> ::std::result::Result<TagListMessage, RusotoError<AddTagsToResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    authorize_cache_security_group_ingress: Option<
+        Box<
+            dyn Fn(
+                    AuthorizeCacheSecurityGroupIngressMessage,
+                ) -> ::std::result::Result<
+                    AuthorizeCacheSecurityGroupIngressResult,
+                    RusotoError<AuthorizeCacheSecurityGroupIngressError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_apply_update_action: Option<
+        Box<
+            dyn Fn(
+                    BatchApplyUpdateActionMessage,
+                ) -> ::std::result::Result<
+                    UpdateActionResultsMessage,
+                    RusotoError<BatchApplyUpdateActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_stop_update_action: Option<
+        Box<
+            dyn Fn(
+                    BatchStopUpdateActionMessage,
+                ) -> ::std::result::Result<
+                    UpdateActionResultsMessage,
+                    RusotoError<BatchStopUpdateActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    copy_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CopySnapshotMessage,
+                )
+                    -> ::std::result::Result<CopySnapshotResult, RusotoError<CopySnapshotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_cache_cluster: Option<
+        Box<
+            dyn Fn(
+                    CreateCacheClusterMessage,
+                ) -> ::std::result::Result<
+                    CreateCacheClusterResult,
+                    RusotoError<CreateCacheClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cache_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    CreateCacheParameterGroupMessage,
+                ) -> ::std::result::Result<
+                    CreateCacheParameterGroupResult,
+                    RusotoError<CreateCacheParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cache_security_group: Option<
+        Box<
+            dyn Fn(
+                    CreateCacheSecurityGroupMessage,
+                ) -> ::std::result::Result<
+                    CreateCacheSecurityGroupResult,
+                    RusotoError<CreateCacheSecurityGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cache_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    CreateCacheSubnetGroupMessage,
+                ) -> ::std::result::Result<
+                    CreateCacheSubnetGroupResult,
+                    RusotoError<CreateCacheSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_replication_group: Option<
+        Box<
+            dyn Fn(
+                    CreateReplicationGroupMessage,
+                ) -> ::std::result::Result<
+                    CreateReplicationGroupResult,
+                    RusotoError<CreateReplicationGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateSnapshotMessage,
+                )
+                    -> ::std::result::Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    decrease_replica_count: Option<
+        Box<
+            dyn Fn(
+                    DecreaseReplicaCountMessage,
+                ) -> ::std::result::Result<
+                    DecreaseReplicaCountResult,
+                    RusotoError<DecreaseReplicaCountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_cache_cluster: Option<
+        Box<
+            dyn Fn(
+                    DeleteCacheClusterMessage,
+                ) -> ::std::result::Result<
+                    DeleteCacheClusterResult,
+                    RusotoError<DeleteCacheClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_cache_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteCacheParameterGroupMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteCacheParameterGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_cache_security_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteCacheSecurityGroupMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteCacheSecurityGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_cache_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteCacheSubnetGroupMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteCacheSubnetGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_replication_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteReplicationGroupMessage,
+                ) -> ::std::result::Result<
+                    DeleteReplicationGroupResult,
+                    RusotoError<DeleteReplicationGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_snapshot: Option<
+        Box<
+            dyn Fn(
+                    DeleteSnapshotMessage,
+                )
+                    -> ::std::result::Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_cache_clusters: Option<
+        Box<
+            dyn Fn(
+                    DescribeCacheClustersMessage,
+                ) -> ::std::result::Result<
+                    CacheClusterMessage,
+                    RusotoError<DescribeCacheClustersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cache_engine_versions: Option<
+        Box<
+            dyn Fn(
+                    DescribeCacheEngineVersionsMessage,
+                ) -> ::std::result::Result<
+                    CacheEngineVersionMessage,
+                    RusotoError<DescribeCacheEngineVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cache_parameter_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeCacheParameterGroupsMessage,
+                ) -> ::std::result::Result<
+                    CacheParameterGroupsMessage,
+                    RusotoError<DescribeCacheParameterGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cache_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeCacheParametersMessage,
+                ) -> ::std::result::Result<
+                    CacheParameterGroupDetails,
+                    RusotoError<DescribeCacheParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cache_security_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeCacheSecurityGroupsMessage,
+                ) -> ::std::result::Result<
+                    CacheSecurityGroupMessage,
+                    RusotoError<DescribeCacheSecurityGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cache_subnet_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeCacheSubnetGroupsMessage,
+                ) -> ::std::result::Result<
+                    CacheSubnetGroupMessage,
+                    RusotoError<DescribeCacheSubnetGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_engine_default_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeEngineDefaultParametersMessage,
+                ) -> ::std::result::Result<
+                    DescribeEngineDefaultParametersResult,
+                    RusotoError<DescribeEngineDefaultParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_events: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventsMessage,
+                )
+                    -> ::std::result::Result<EventsMessage, RusotoError<DescribeEventsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_replication_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeReplicationGroupsMessage,
+                ) -> ::std::result::Result<
+                    ReplicationGroupMessage,
+                    RusotoError<DescribeReplicationGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_reserved_cache_nodes: Option<
+        Box<
+            dyn Fn(
+                    DescribeReservedCacheNodesMessage,
+                ) -> ::std::result::Result<
+                    ReservedCacheNodeMessage,
+                    RusotoError<DescribeReservedCacheNodesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_reserved_cache_nodes_offerings: Option<
+        Box<
+            dyn Fn(
+                    DescribeReservedCacheNodesOfferingsMessage,
+                ) -> ::std::result::Result<
+                    ReservedCacheNodesOfferingMessage,
+                    RusotoError<DescribeReservedCacheNodesOfferingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_service_updates: Option<
+        Box<
+            dyn Fn(
+                    DescribeServiceUpdatesMessage,
+                ) -> ::std::result::Result<
+                    ServiceUpdatesMessage,
+                    RusotoError<DescribeServiceUpdatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_snapshots: Option<
+        Box<
+            dyn Fn(
+                    DescribeSnapshotsMessage,
+                ) -> ::std::result::Result<
+                    DescribeSnapshotsListMessage,
+                    RusotoError<DescribeSnapshotsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_update_actions: Option<
+        Box<
+            dyn Fn(
+                    DescribeUpdateActionsMessage,
+                ) -> ::std::result::Result<
+                    UpdateActionsMessage,
+                    RusotoError<DescribeUpdateActionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    increase_replica_count: Option<
+        Box<
+            dyn Fn(
+                    IncreaseReplicaCountMessage,
+                ) -> ::std::result::Result<
+                    IncreaseReplicaCountResult,
+                    RusotoError<IncreaseReplicaCountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_allowed_node_type_modifications: Option<
+        Box<
+            dyn Fn(
+                    ListAllowedNodeTypeModificationsMessage,
+                ) -> ::std::result::Result<
+                    AllowedNodeTypeModificationsMessage,
+                    RusotoError<ListAllowedNodeTypeModificationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceMessage,
+                )
+                    -> ::std::result::Result<TagListMessage, RusotoError<ListTagsForResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_cache_cluster: Option<
+        Box<
+            dyn Fn(
+                    ModifyCacheClusterMessage,
+                ) -> ::std::result::Result<
+                    ModifyCacheClusterResult,
+                    RusotoError<ModifyCacheClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_cache_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    ModifyCacheParameterGroupMessage,
+                ) -> ::std::result::Result<
+                    CacheParameterGroupNameMessage,
+                    RusotoError<ModifyCacheParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_cache_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    ModifyCacheSubnetGroupMessage,
+                ) -> ::std::result::Result<
+                    ModifyCacheSubnetGroupResult,
+                    RusotoError<ModifyCacheSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_replication_group: Option<
+        Box<
+            dyn Fn(
+                    ModifyReplicationGroupMessage,
+                ) -> ::std::result::Result<
+                    ModifyReplicationGroupResult,
+                    RusotoError<ModifyReplicationGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_replication_group_shard_configuration: Option<
+        Box<
+            dyn Fn(
+                    ModifyReplicationGroupShardConfigurationMessage,
+                ) -> ::std::result::Result<
+                    ModifyReplicationGroupShardConfigurationResult,
+                    RusotoError<ModifyReplicationGroupShardConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    purchase_reserved_cache_nodes_offering: Option<
+        Box<
+            dyn Fn(
+                    PurchaseReservedCacheNodesOfferingMessage,
+                ) -> ::std::result::Result<
+                    PurchaseReservedCacheNodesOfferingResult,
+                    RusotoError<PurchaseReservedCacheNodesOfferingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reboot_cache_cluster: Option<
+        Box<
+            dyn Fn(
+                    RebootCacheClusterMessage,
+                ) -> ::std::result::Result<
+                    RebootCacheClusterResult,
+                    RusotoError<RebootCacheClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_resource: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromResourceMessage,
+                ) -> ::std::result::Result<
+                    TagListMessage,
+                    RusotoError<RemoveTagsFromResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reset_cache_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    ResetCacheParameterGroupMessage,
+                ) -> ::std::result::Result<
+                    CacheParameterGroupNameMessage,
+                    RusotoError<ResetCacheParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    revoke_cache_security_group_ingress: Option<
+        Box<
+            dyn Fn(
+                    RevokeCacheSecurityGroupIngressMessage,
+                ) -> ::std::result::Result<
+                    RevokeCacheSecurityGroupIngressResult,
+                    RusotoError<RevokeCacheSecurityGroupIngressError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    test_failover: Option<
+        Box<
+            dyn Fn(
+                    TestFailoverMessage,
+                )
+                    -> ::std::result::Result<TestFailoverResult, RusotoError<TestFailoverError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockElastiCache {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockElastiCache {
+        Default::default()
+    }
+
+    /// Answers `add_tags_to_resource` with what `handler` returns for its input.
+    pub fn with_add_tags_to_resource<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                AddTagsToResourceMessage,
+            )
+                -> ::std::result::Result<TagListMessage, RusotoError<AddTagsToResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `authorize_cache_security_group_ingress` with what `handler` returns for its input.
+    pub fn with_authorize_cache_security_group_ingress<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                AuthorizeCacheSecurityGroupIngressMessage,
+            ) -> ::std::result::Result<
+                AuthorizeCacheSecurityGroupIngressResult,
+                RusotoError<AuthorizeCacheSecurityGroupIngressError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.authorize_cache_security_group_ingress = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_apply_update_action` with what `handler` returns for its input.
+    pub fn with_batch_apply_update_action<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                BatchApplyUpdateActionMessage,
+            ) -> ::std::result::Result<
+                UpdateActionResultsMessage,
+                RusotoError<BatchApplyUpdateActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_apply_update_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_stop_update_action` with what `handler` returns for its input.
+    pub fn with_batch_stop_update_action<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                BatchStopUpdateActionMessage,
+            ) -> ::std::result::Result<
+                UpdateActionResultsMessage,
+                RusotoError<BatchStopUpdateActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_stop_update_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `copy_snapshot` with what `handler` returns for its input.
+    pub fn with_copy_snapshot<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                CopySnapshotMessage,
+            )
+                -> ::std::result::Result<CopySnapshotResult, RusotoError<CopySnapshotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.copy_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cache_cluster` with what `handler` returns for its input.
+    pub fn with_create_cache_cluster<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                CreateCacheClusterMessage,
+            ) -> ::std::result::Result<
+                CreateCacheClusterResult,
+                RusotoError<CreateCacheClusterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cache_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cache_parameter_group` with what `handler` returns for its input.
+    pub fn with_create_cache_parameter_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                CreateCacheParameterGroupMessage,
+            ) -> ::std::result::Result<
+                CreateCacheParameterGroupResult,
+                RusotoError<CreateCacheParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cache_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cache_security_group` with what `handler` returns for its input.
+    pub fn with_create_cache_security_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                CreateCacheSecurityGroupMessage,
+            ) -> ::std::result::Result<
+                CreateCacheSecurityGroupResult,
+                RusotoError<CreateCacheSecurityGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cache_security_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cache_subnet_group` with what `handler` returns for its input.
+    pub fn with_create_cache_subnet_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                CreateCacheSubnetGroupMessage,
+            ) -> ::std::result::Result<
+                CreateCacheSubnetGroupResult,
+                RusotoError<CreateCacheSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cache_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_replication_group` with what `handler` returns for its input.
+    pub fn with_create_replication_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                CreateReplicationGroupMessage,
+            ) -> ::std::result::Result<
+                CreateReplicationGroupResult,
+                RusotoError<CreateReplicationGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_replication_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_snapshot` with what `handler` returns for its input.
+    pub fn with_create_snapshot<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                CreateSnapshotMessage,
+            )
+                -> ::std::result::Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `decrease_replica_count` with what `handler` returns for its input.
+    pub fn with_decrease_replica_count<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DecreaseReplicaCountMessage,
+            ) -> ::std::result::Result<
+                DecreaseReplicaCountResult,
+                RusotoError<DecreaseReplicaCountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.decrease_replica_count = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cache_cluster` with what `handler` returns for its input.
+    pub fn with_delete_cache_cluster<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DeleteCacheClusterMessage,
+            ) -> ::std::result::Result<
+                DeleteCacheClusterResult,
+                RusotoError<DeleteCacheClusterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cache_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cache_parameter_group` with what `handler` returns for its input.
+    pub fn with_delete_cache_parameter_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DeleteCacheParameterGroupMessage,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteCacheParameterGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cache_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cache_security_group` with what `handler` returns for its input.
+    pub fn with_delete_cache_security_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DeleteCacheSecurityGroupMessage,
+            ) -> ::std::result::Result<(), RusotoError<DeleteCacheSecurityGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cache_security_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cache_subnet_group` with what `handler` returns for its input.
+    pub fn with_delete_cache_subnet_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DeleteCacheSubnetGroupMessage,
+            ) -> ::std::result::Result<(), RusotoError<DeleteCacheSubnetGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cache_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_replication_group` with what `handler` returns for its input.
+    pub fn with_delete_replication_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DeleteReplicationGroupMessage,
+            ) -> ::std::result::Result<
+                DeleteReplicationGroupResult,
+                RusotoError<DeleteReplicationGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_replication_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_snapshot` with what `handler` returns for its input.
+    pub fn with_delete_snapshot<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DeleteSnapshotMessage,
+            )
+                -> ::std::result::Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cache_clusters` with what `handler` returns for its input.
+    pub fn with_describe_cache_clusters<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeCacheClustersMessage,
+            ) -> ::std::result::Result<
+                CacheClusterMessage,
+                RusotoError<DescribeCacheClustersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cache_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cache_engine_versions` with what `handler` returns for its input.
+    pub fn with_describe_cache_engine_versions<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeCacheEngineVersionsMessage,
+            ) -> ::std::result::Result<
+                CacheEngineVersionMessage,
+                RusotoError<DescribeCacheEngineVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cache_engine_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cache_parameter_groups` with what `handler` returns for its input.
+    pub fn with_describe_cache_parameter_groups<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeCacheParameterGroupsMessage,
+            ) -> ::std::result::Result<
+                CacheParameterGroupsMessage,
+                RusotoError<DescribeCacheParameterGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cache_parameter_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cache_parameters` with what `handler` returns for its input.
+    pub fn with_describe_cache_parameters<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeCacheParametersMessage,
+            ) -> ::std::result::Result<
+                CacheParameterGroupDetails,
+                RusotoError<DescribeCacheParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cache_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cache_security_groups` with what `handler` returns for its input.
+    pub fn with_describe_cache_security_groups<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeCacheSecurityGroupsMessage,
+            ) -> ::std::result::Result<
+                CacheSecurityGroupMessage,
+                RusotoError<DescribeCacheSecurityGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cache_security_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cache_subnet_groups` with what `handler` returns for its input.
+    pub fn with_describe_cache_subnet_groups<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeCacheSubnetGroupsMessage,
+            ) -> ::std::result::Result<
+                CacheSubnetGroupMessage,
+                RusotoError<DescribeCacheSubnetGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cache_subnet_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_engine_default_parameters` with what `handler` returns for its input.
+    pub fn with_describe_engine_default_parameters<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeEngineDefaultParametersMessage,
+            ) -> ::std::result::Result<
+                DescribeEngineDefaultParametersResult,
+                RusotoError<DescribeEngineDefaultParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_engine_default_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_events` with what `handler` returns for its input.
+    pub fn with_describe_events<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeEventsMessage,
+            )
+                -> ::std::result::Result<EventsMessage, RusotoError<DescribeEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_replication_groups` with what `handler` returns for its input.
+    pub fn with_describe_replication_groups<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeReplicationGroupsMessage,
+            ) -> ::std::result::Result<
+                ReplicationGroupMessage,
+                RusotoError<DescribeReplicationGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_replication_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_reserved_cache_nodes` with what `handler` returns for its input.
+    pub fn with_describe_reserved_cache_nodes<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeReservedCacheNodesMessage,
+            ) -> ::std::result::Result<
+                ReservedCacheNodeMessage,
+                RusotoError<DescribeReservedCacheNodesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_reserved_cache_nodes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_reserved_cache_nodes_offerings` with what `handler` returns for its input.
+    pub fn with_describe_reserved_cache_nodes_offerings<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeReservedCacheNodesOfferingsMessage,
+            ) -> ::std::result::Result<
+                ReservedCacheNodesOfferingMessage,
+                RusotoError<DescribeReservedCacheNodesOfferingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_reserved_cache_nodes_offerings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_service_updates` with what `handler` returns for its input.
+    pub fn with_describe_service_updates<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeServiceUpdatesMessage,
+            ) -> ::std::result::Result<
+                ServiceUpdatesMessage,
+                RusotoError<DescribeServiceUpdatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_service_updates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_snapshots` with what `handler` returns for its input.
+    pub fn with_describe_snapshots<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeSnapshotsMessage,
+            ) -> ::std::result::Result<
+                DescribeSnapshotsListMessage,
+                RusotoError<DescribeSnapshotsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_snapshots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_update_actions` with what `handler` returns for its input.
+    pub fn with_describe_update_actions<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                DescribeUpdateActionsMessage,
+            ) -> ::std::result::Result<
+                UpdateActionsMessage,
+                RusotoError<DescribeUpdateActionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_update_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `increase_replica_count` with what `handler` returns for its input.
+    pub fn with_increase_replica_count<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                IncreaseReplicaCountMessage,
+            ) -> ::std::result::Result<
+                IncreaseReplicaCountResult,
+                RusotoError<IncreaseReplicaCountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.increase_replica_count = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_allowed_node_type_modifications` with what `handler` returns for its input.
+    pub fn with_list_allowed_node_type_modifications<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                ListAllowedNodeTypeModificationsMessage,
+            ) -> ::std::result::Result<
+                AllowedNodeTypeModificationsMessage,
+                RusotoError<ListAllowedNodeTypeModificationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_allowed_node_type_modifications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                ListTagsForResourceMessage,
+            )
+                -> ::std::result::Result<TagListMessage, RusotoError<ListTagsForResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cache_cluster` with what `handler` returns for its input.
+    pub fn with_modify_cache_cluster<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                ModifyCacheClusterMessage,
+            ) -> ::std::result::Result<
+                ModifyCacheClusterResult,
+                RusotoError<ModifyCacheClusterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cache_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cache_parameter_group` with what `handler` returns for its input.
+    pub fn with_modify_cache_parameter_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                ModifyCacheParameterGroupMessage,
+            ) -> ::std::result::Result<
+                CacheParameterGroupNameMessage,
+                RusotoError<ModifyCacheParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cache_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cache_subnet_group` with what `handler` returns for its input.
+    pub fn with_modify_cache_subnet_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                ModifyCacheSubnetGroupMessage,
+            ) -> ::std::result::Result<
+                ModifyCacheSubnetGroupResult,
+                RusotoError<ModifyCacheSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cache_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_replication_group` with what `handler` returns for its input.
+    pub fn with_modify_replication_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                ModifyReplicationGroupMessage,
+            ) -> ::std::result::Result<
+                ModifyReplicationGroupResult,
+                RusotoError<ModifyReplicationGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_replication_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_replication_group_shard_configuration` with what `handler` returns for its input.
+    pub fn with_modify_replication_group_shard_configuration<F>(
+        mut self,
+        handler: F,
+    ) -> MockElastiCache
+    where
+        F: Fn(
+                ModifyReplicationGroupShardConfigurationMessage,
+            ) -> ::std::result::Result<
+                ModifyReplicationGroupShardConfigurationResult,
+                RusotoError<ModifyReplicationGroupShardConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_replication_group_shard_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `purchase_reserved_cache_nodes_offering` with what `handler` returns for its input.
+    pub fn with_purchase_reserved_cache_nodes_offering<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                PurchaseReservedCacheNodesOfferingMessage,
+            ) -> ::std::result::Result<
+                PurchaseReservedCacheNodesOfferingResult,
+                RusotoError<PurchaseReservedCacheNodesOfferingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.purchase_reserved_cache_nodes_offering = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reboot_cache_cluster` with what `handler` returns for its input.
+    pub fn with_reboot_cache_cluster<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                RebootCacheClusterMessage,
+            ) -> ::std::result::Result<
+                RebootCacheClusterResult,
+                RusotoError<RebootCacheClusterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reboot_cache_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_resource` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_resource<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                RemoveTagsFromResourceMessage,
+            )
+                -> ::std::result::Result<TagListMessage, RusotoError<RemoveTagsFromResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reset_cache_parameter_group` with what `handler` returns for its input.
+    pub fn with_reset_cache_parameter_group<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                ResetCacheParameterGroupMessage,
+            ) -> ::std::result::Result<
+                CacheParameterGroupNameMessage,
+                RusotoError<ResetCacheParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reset_cache_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `revoke_cache_security_group_ingress` with what `handler` returns for its input.
+    pub fn with_revoke_cache_security_group_ingress<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                RevokeCacheSecurityGroupIngressMessage,
+            ) -> ::std::result::Result<
+                RevokeCacheSecurityGroupIngressResult,
+                RusotoError<RevokeCacheSecurityGroupIngressError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.revoke_cache_security_group_ingress = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_failover` with what `handler` returns for its input.
+    pub fn with_test_failover<F>(mut self, handler: F) -> MockElastiCache
+    where
+        F: Fn(
+                TestFailoverMessage,
+            )
+                -> ::std::result::Result<TestFailoverResult, RusotoError<TestFailoverError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.test_failover = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ElastiCache for MockElastiCache {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<TagListMessage, AddTagsToResourceError> {
+        match self.add_tags_to_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for add_tags_to_resource"),
+        }
+    }
+    fn authorize_cache_security_group_ingress(
+        &self,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> RusotoFuture<
+        AuthorizeCacheSecurityGroupIngressResult,
+        AuthorizeCacheSecurityGroupIngressError,
+    > {
+        match self.authorize_cache_security_group_ingress {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElastiCache has no handler for authorize_cache_security_group_ingress")
+            }
+        }
+    }
+    fn batch_apply_update_action(
+        &self,
+        input: BatchApplyUpdateActionMessage,
+    ) -> RusotoFuture<UpdateActionResultsMessage, BatchApplyUpdateActionError> {
+        match self.batch_apply_update_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for batch_apply_update_action"),
+        }
+    }
+    fn batch_stop_update_action(
+        &self,
+        input: BatchStopUpdateActionMessage,
+    ) -> RusotoFuture<UpdateActionResultsMessage, BatchStopUpdateActionError> {
+        match self.batch_stop_update_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for batch_stop_update_action"),
+        }
+    }
+    fn copy_snapshot(
+        &self,
+        input: CopySnapshotMessage,
+    ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
+        match self.copy_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for copy_snapshot"),
+        }
+    }
+    fn create_cache_cluster(
+        &self,
+        input: CreateCacheClusterMessage,
+    ) -> RusotoFuture<CreateCacheClusterResult, CreateCacheClusterError> {
+        match self.create_cache_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for create_cache_cluster"),
+        }
+    }
+    fn create_cache_parameter_group(
+        &self,
+        input: CreateCacheParameterGroupMessage,
+    ) -> RusotoFuture<CreateCacheParameterGroupResult, CreateCacheParameterGroupError> {
+        match self.create_cache_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for create_cache_parameter_group"),
+        }
+    }
+    fn create_cache_security_group(
+        &self,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> RusotoFuture<CreateCacheSecurityGroupResult, CreateCacheSecurityGroupError> {
+        match self.create_cache_security_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for create_cache_security_group"),
+        }
+    }
+    fn create_cache_subnet_group(
+        &self,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> RusotoFuture<CreateCacheSubnetGroupResult, CreateCacheSubnetGroupError> {
+        match self.create_cache_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for create_cache_subnet_group"),
+        }
+    }
+    fn create_replication_group(
+        &self,
+        input: CreateReplicationGroupMessage,
+    ) -> RusotoFuture<CreateReplicationGroupResult, CreateReplicationGroupError> {
+        match self.create_replication_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for create_replication_group"),
+        }
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotMessage,
+    ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
+        match self.create_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for create_snapshot"),
+        }
+    }
+    fn decrease_replica_count(
+        &self,
+        input: DecreaseReplicaCountMessage,
+    ) -> RusotoFuture<DecreaseReplicaCountResult, DecreaseReplicaCountError> {
+        match self.decrease_replica_count {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for decrease_replica_count"),
+        }
+    }
+    fn delete_cache_cluster(
+        &self,
+        input: DeleteCacheClusterMessage,
+    ) -> RusotoFuture<DeleteCacheClusterResult, DeleteCacheClusterError> {
+        match self.delete_cache_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for delete_cache_cluster"),
+        }
+    }
+    fn delete_cache_parameter_group(
+        &self,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheParameterGroupError> {
+        match self.delete_cache_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for delete_cache_parameter_group"),
+        }
+    }
+    fn delete_cache_security_group(
+        &self,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheSecurityGroupError> {
+        match self.delete_cache_security_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for delete_cache_security_group"),
+        }
+    }
+    fn delete_cache_subnet_group(
+        &self,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheSubnetGroupError> {
+        match self.delete_cache_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for delete_cache_subnet_group"),
+        }
+    }
+    fn delete_replication_group(
+        &self,
+        input: DeleteReplicationGroupMessage,
+    ) -> RusotoFuture<DeleteReplicationGroupResult, DeleteReplicationGroupError> {
+        match self.delete_replication_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for delete_replication_group"),
+        }
+    }
+    fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotMessage,
+    ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
+        match self.delete_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for delete_snapshot"),
+        }
+    }
+    fn describe_cache_clusters(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> RusotoFuture<CacheClusterMessage, DescribeCacheClustersError> {
+        match self.describe_cache_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_cache_clusters"),
+        }
+    }
+    fn describe_cache_engine_versions(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> RusotoFuture<CacheEngineVersionMessage, DescribeCacheEngineVersionsError> {
+        match self.describe_cache_engine_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_cache_engine_versions"),
+        }
+    }
+    fn describe_cache_parameter_groups(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> RusotoFuture<CacheParameterGroupsMessage, DescribeCacheParameterGroupsError> {
+        match self.describe_cache_parameter_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_cache_parameter_groups"),
+        }
+    }
+    fn describe_cache_parameters(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> RusotoFuture<CacheParameterGroupDetails, DescribeCacheParametersError> {
+        match self.describe_cache_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_cache_parameters"),
+        }
+    }
+    fn describe_cache_security_groups(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> RusotoFuture<CacheSecurityGroupMessage, DescribeCacheSecurityGroupsError> {
+        match self.describe_cache_security_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_cache_security_groups"),
+        }
+    }
+    fn describe_cache_subnet_groups(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> RusotoFuture<CacheSubnetGroupMessage, DescribeCacheSubnetGroupsError> {
+        match self.describe_cache_subnet_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_cache_subnet_groups"),
+        }
+    }
+    fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> RusotoFuture<DescribeEngineDefaultParametersResult, DescribeEngineDefaultParametersError>
+    {
+        match self.describe_engine_default_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_engine_default_parameters"),
+        }
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        match self.describe_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_events"),
+        }
+    }
+    fn describe_replication_groups(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> RusotoFuture<ReplicationGroupMessage, DescribeReplicationGroupsError> {
+        match self.describe_replication_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_replication_groups"),
+        }
+    }
+    fn describe_reserved_cache_nodes(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> RusotoFuture<ReservedCacheNodeMessage, DescribeReservedCacheNodesError> {
+        match self.describe_reserved_cache_nodes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_reserved_cache_nodes"),
+        }
+    }
+    fn describe_reserved_cache_nodes_offerings(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> RusotoFuture<ReservedCacheNodesOfferingMessage, DescribeReservedCacheNodesOfferingsError>
+    {
+        match self.describe_reserved_cache_nodes_offerings {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElastiCache has no handler for describe_reserved_cache_nodes_offerings")
+            }
+        }
+    }
+    fn describe_service_updates(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> RusotoFuture<ServiceUpdatesMessage, DescribeServiceUpdatesError> {
+        match self.describe_service_updates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_service_updates"),
+        }
+    }
+    fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> RusotoFuture<DescribeSnapshotsListMessage, DescribeSnapshotsError> {
+        match self.describe_snapshots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_snapshots"),
+        }
+    }
+    fn describe_update_actions(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> RusotoFuture<UpdateActionsMessage, DescribeUpdateActionsError> {
+        match self.describe_update_actions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for describe_update_actions"),
+        }
+    }
+    fn increase_replica_count(
+        &self,
+        input: IncreaseReplicaCountMessage,
+    ) -> RusotoFuture<IncreaseReplicaCountResult, IncreaseReplicaCountError> {
+        match self.increase_replica_count {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for increase_replica_count"),
+        }
+    }
+    fn list_allowed_node_type_modifications(
+        &self,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> RusotoFuture<AllowedNodeTypeModificationsMessage, ListAllowedNodeTypeModificationsError>
+    {
+        match self.list_allowed_node_type_modifications {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElastiCache has no handler for list_allowed_node_type_modifications")
+            }
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for list_tags_for_resource"),
+        }
+    }
+    fn modify_cache_cluster(
+        &self,
+        input: ModifyCacheClusterMessage,
+    ) -> RusotoFuture<ModifyCacheClusterResult, ModifyCacheClusterError> {
+        match self.modify_cache_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for modify_cache_cluster"),
+        }
+    }
+    fn modify_cache_parameter_group(
+        &self,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> RusotoFuture<CacheParameterGroupNameMessage, ModifyCacheParameterGroupError> {
+        match self.modify_cache_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for modify_cache_parameter_group"),
+        }
+    }
+    fn modify_cache_subnet_group(
+        &self,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyCacheSubnetGroupResult, ModifyCacheSubnetGroupError> {
+        match self.modify_cache_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for modify_cache_subnet_group"),
+        }
+    }
+    fn modify_replication_group(
+        &self,
+        input: ModifyReplicationGroupMessage,
+    ) -> RusotoFuture<ModifyReplicationGroupResult, ModifyReplicationGroupError> {
+        match self.modify_replication_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for modify_replication_group"),
+        }
+    }
+    fn modify_replication_group_shard_configuration(
+        &self,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> RusotoFuture<
+        ModifyReplicationGroupShardConfigurationResult,
+        ModifyReplicationGroupShardConfigurationError,
+    > {
+        match self.modify_replication_group_shard_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockElastiCache has no handler for modify_replication_group_shard_configuration"
+            ),
+        }
+    }
+    fn purchase_reserved_cache_nodes_offering(
+        &self,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> RusotoFuture<
+        PurchaseReservedCacheNodesOfferingResult,
+        PurchaseReservedCacheNodesOfferingError,
+    > {
+        match self.purchase_reserved_cache_nodes_offering {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElastiCache has no handler for purchase_reserved_cache_nodes_offering")
+            }
+        }
+    }
     fn reboot_cache_cluster(
         &self,
         input: RebootCacheClusterMessage,
     ) -> RusotoFuture<RebootCacheClusterResult, RebootCacheClusterError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RebootCacheCluster");
-        params.put("Version", "2015-02-02");
-        RebootCacheClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RebootCacheClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RebootCacheClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RebootCacheClusterResultDeserializer::deserialize(
-                        "RebootCacheClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.reboot_cache_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for reboot_cache_cluster"),
+        }
     }
-
-    /// <p>Removes the tags identified by the <code>TagKeys</code> list from the named resource.</p>
     fn remove_tags_from_resource(
         &self,
         input: RemoveTagsFromResourceMessage,
     ) -> RusotoFuture<TagListMessage, RemoveTagsFromResourceError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RemoveTagsFromResource");
-        params.put("Version", "2015-02-02");
-        RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RemoveTagsFromResourceError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TagListMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TagListMessageDeserializer::deserialize(
-                        "RemoveTagsFromResourceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.remove_tags_from_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for remove_tags_from_resource"),
+        }
     }
-
-    /// <p>Modifies the parameters of a cache parameter group to the engine or system default value. You can reset specific parameters by submitting a list of parameter names. To reset the entire cache parameter group, specify the <code>ResetAllParameters</code> and <code>CacheParameterGroupName</code> parameters.</p>
     fn reset_cache_parameter_group(
         &self,
         input: ResetCacheParameterGroupMessage,
     ) -> RusotoFuture<CacheParameterGroupNameMessage, ResetCacheParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ResetCacheParameterGroup");
-        params.put("Version", "2015-02-02");
-        ResetCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ResetCacheParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CacheParameterGroupNameMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CacheParameterGroupNameMessageDeserializer::deserialize(
-                        "ResetCacheParameterGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.reset_cache_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for reset_cache_parameter_group"),
+        }
     }
-
-    /// <p>Revokes ingress from a cache security group. Use this operation to disallow access from an Amazon EC2 security group that had been previously authorized.</p>
     fn revoke_cache_security_group_ingress(
         &self,
         input: RevokeCacheSecurityGroupIngressMessage,
     ) -> RusotoFuture<RevokeCacheSecurityGroupIngressResult, RevokeCacheSecurityGroupIngressError>
     {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RevokeCacheSecurityGroupIngress");
-        params.put("Version", "2015-02-02");
-        RevokeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RevokeCacheSecurityGroupIngressError::from_response(
-                        response,
-                    ))
-                }));
+        match self.revoke_cache_security_group_ingress {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElastiCache has no handler for revoke_cache_security_group_ingress")
             }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RevokeCacheSecurityGroupIngressResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RevokeCacheSecurityGroupIngressResultDeserializer::deserialize(
-                        "RevokeCacheSecurityGroupIngressResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        }
     }
-
-    /// <p>Represents the input of a <code>TestFailover</code> operation which test automatic failover on a specified node group (called shard in the console) in a replication group (called cluster in the console).</p> <p class="title"> <b>Note the following</b> </p> <ul> <li> <p>A customer can use this operation to test automatic failover on up to 5 shards (called node groups in the ElastiCache API and AWS CLI) in any rolling 24-hour period.</p> </li> <li> <p>If calling this operation on shards in different clusters (called replication groups in the API and CLI), the calls can be made concurrently.</p> <p> </p> </li> <li> <p>If calling this operation multiple times on different shards in the same Redis (cluster mode enabled) replication group, the first node replacement must complete before a subsequent call can be made.</p> </li> <li> <p>To determine whether the node replacement is complete you can check Events using the Amazon ElastiCache console, the AWS CLI, or the ElastiCache API. Look for the following automatic failover related events, listed here in order of occurrance:</p> <ol> <li> <p>Replication group message: <code>Test Failover API called for node group &lt;node-group-id&gt;</code> </p> </li> <li> <p>Cache cluster message: <code>Failover from master node &lt;primary-node-id&gt; to replica node &lt;node-id&gt; completed</code> </p> </li> <li> <p>Replication group message: <code>Failover from master node &lt;primary-node-id&gt; to replica node &lt;node-id&gt; completed</code> </p> </li> <li> <p>Cache cluster message: <code>Recovering cache nodes &lt;node-id&gt;</code> </p> </li> <li> <p>Cache cluster message: <code>Finished recovery for cache nodes &lt;node-id&gt;</code> </p> </li> </ol> <p>For more information see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ECEvents.Viewing.html">Viewing ElastiCache Events</a> in the <i>ElastiCache User Guide</i> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_DescribeEvents.html">DescribeEvents</a> in the ElastiCache API Reference</p> </li> </ul> </li> </ul> <p>Also see, <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/AutoFailover.html#auto-failover-test">Testing Multi-AZ with Automatic Failover</a> in the <i>ElastiCache User Guide</i>.</p>
     fn test_failover(
         &self,
         input: TestFailoverMessage,
     ) -> RusotoFuture<TestFailoverResult, TestFailoverError> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "TestFailover");
-        params.put("Version", "2015-02-02");
-        TestFailoverMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TestFailoverError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TestFailoverResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TestFailoverResultDeserializer::deserialize(
-                        "TestFailoverResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.test_failover {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElastiCache has no handler for test_failover"),
+        }
     }
 }
@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -46,6 +47,8 @@ impl ARNDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AbortEnvironmentUpdateMessage {
     /// <p>This specifies the ID of the environment with the in-progress update that you want to cancel.</p>
     pub environment_id: Option<String>,
@@ -53,6 +56,19 @@ pub struct AbortEnvironmentUpdateMessage {
     pub environment_name: Option<String>,
 }
 
+impl Validate for AbortEnvironmentUpdateMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+    }
+}
+
 /// Serialize `AbortEnvironmentUpdateMessage` contents to a `SignedRequest`.
 struct AbortEnvironmentUpdateMessageSerializer;
 impl AbortEnvironmentUpdateMessageSerializer {
@@ -76,7 +92,7 @@ impl AbortableOperationInProgressDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -128,6 +144,8 @@ impl ApplicationArnDeserializer {
 }
 /// <p>Describes the properties of an application.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationDescription {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     pub application_arn: Option<String>,
@@ -227,6 +245,8 @@ impl ApplicationDescriptionListDeserializer {
 }
 /// <p>Result message containing a single description of an application.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationDescriptionMessage {
     /// <p> The <a>ApplicationDescription</a> of the application. </p>
     pub application: Option<ApplicationDescription>,
@@ -259,6 +279,8 @@ impl ApplicationDescriptionMessageDeserializer {
 }
 /// <p>Result message containing a list of application descriptions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationDescriptionsMessage {
     /// <p>This parameter contains a list of <a>ApplicationDescription</a>.</p>
     pub applications: Option<Vec<ApplicationDescription>>,
@@ -293,6 +315,8 @@ impl ApplicationDescriptionsMessageDeserializer {
 }
 /// <p>Application request metrics for an AWS Elastic Beanstalk environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationMetrics {
     /// <p>The amount of time that the metrics cover (usually 10 seconds). For example, you might have 5 requests (<code>request_count</code>) within the most recent time slice of 10 seconds (<code>duration</code>).</p>
     pub duration: Option<i64>,
@@ -361,6 +385,8 @@ impl ApplicationNamesListSerializer {
 
 /// <p>The resource lifecycle configuration for an application. Defines lifecycle settings for resources that belong to the application, and the service role that Elastic Beanstalk assumes in order to apply lifecycle settings. The version lifecycle configuration defines lifecycle settings for application versions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationResourceLifecycleConfig {
     /// <p>The ARN of an IAM service role that Elastic Beanstalk has permission to assume.</p> <p>The <code>ServiceRole</code> property is required the first time that you provide a <code>VersionLifecycleConfig</code> for the application in one of the supporting calls (<code>CreateApplication</code> or <code>UpdateApplicationResourceLifecycle</code>). After you provide it once, in either one of the calls, Elastic Beanstalk persists the Service Role with the application, and you don't need to specify it again in subsequent <code>UpdateApplicationResourceLifecycle</code> calls. You can, however, specify it in subsequent calls to change the Service Role to another value.</p>
     pub service_role: Option<String>,
@@ -422,6 +448,8 @@ impl ApplicationResourceLifecycleConfigSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationResourceLifecycleDescriptionMessage {
     /// <p>The name of the application.</p>
     pub application_name: Option<String>,
@@ -474,6 +502,8 @@ impl ApplicationVersionArnDeserializer {
 }
 /// <p>Describes the properties of an application version.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationVersionDescription {
     /// <p>The name of the application to which the application version belongs.</p>
     pub application_name: Option<String>,
@@ -587,6 +617,8 @@ impl ApplicationVersionDescriptionListDeserializer {
 }
 /// <p>Result message wrapping a single description of an application version.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationVersionDescriptionMessage {
     /// <p> The <a>ApplicationVersionDescription</a> of the application version. </p>
     pub application_version: Option<ApplicationVersionDescription>,
@@ -620,6 +652,8 @@ impl ApplicationVersionDescriptionMessageDeserializer {
 }
 /// <p>Result message wrapping a list of application version descriptions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationVersionDescriptionsMessage {
     /// <p>List of <code>ApplicationVersionDescription</code> objects sorted in order of creation.</p>
     pub application_versions: Option<Vec<ApplicationVersionDescription>>,
@@ -659,6 +693,8 @@ impl ApplicationVersionDescriptionsMessageDeserializer {
 }
 /// <p>The application version lifecycle settings for an application. Defines the rules that Elastic Beanstalk applies to an application's versions in order to avoid hitting the per-region limit for application versions.</p> <p>When Elastic Beanstalk deletes an application version from its database, you can no longer deploy that version to an environment. The source bundle remains in S3 unless you configure the rule to delete it.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationVersionLifecycleConfig {
     /// <p>Specify a max age rule to restrict the length of time that application versions are retained for an application.</p>
     pub max_age_rule: Option<MaxAgeRule>,
@@ -735,6 +771,8 @@ impl ApplicationVersionStatusDeserializer {
 }
 /// <p>Request to execute a scheduled managed action immediately.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplyEnvironmentManagedActionRequest {
     /// <p>The action ID of the scheduled managed action to execute.</p>
     pub action_id: String,
@@ -765,6 +803,8 @@ impl ApplyEnvironmentManagedActionRequestSerializer {
 
 /// <p>The result message containing information about the managed action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplyEnvironmentManagedActionResult {
     /// <p>A description of the managed action.</p>
     pub action_description: Option<String>,
@@ -811,6 +851,8 @@ impl ApplyEnvironmentManagedActionResultDeserializer {
 }
 /// <p>Describes an Auto Scaling launch configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutoScalingGroup {
     /// <p>The name of the <code>AutoScalingGroup</code> . </p>
     pub name: Option<String>,
@@ -892,7 +934,7 @@ impl BoxedBooleanDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -903,7 +945,7 @@ impl BoxedIntDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -911,6 +953,8 @@ impl BoxedIntDeserializer {
 }
 /// <p>Settings for an AWS CodeBuild build.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BuildConfiguration {
     /// <p>The name of the artifact of the CodeBuild build. If provided, Elastic Beanstalk stores the build artifact in the S3 location <i>S3-bucket</i>/resources/<i>application-name</i>/codebuild/codebuild-<i>version-label</i>-<i>artifact-name</i>.zip. If not provided, Elastic Beanstalk stores the build artifact in the S3 location <i>S3-bucket</i>/resources/<i>application-name</i>/codebuild/codebuild-<i>version-label</i>.zip. </p>
     pub artifact_name: Option<String>,
@@ -924,6 +968,17 @@ pub struct BuildConfiguration {
     pub timeout_in_minutes: Option<i64>,
 }
 
+impl Validate for BuildConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "code_build_service_role"),
+            &self.code_build_service_role,
+            ".*\\S.*",
+        );
+        errors.check_pattern(&field_path(path, "image"), &self.image, ".*\\S.*");
+    }
+}
+
 /// Serialize `BuildConfiguration` contents to a `SignedRequest`.
 struct BuildConfigurationSerializer;
 impl BuildConfigurationSerializer {
@@ -952,6 +1007,8 @@ impl BuildConfigurationSerializer {
 
 /// <p>The builder used to build the custom platform.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Builder {
     /// <p>The ARN of the builder.</p>
     pub arn: Option<String>,
@@ -977,6 +1034,8 @@ impl BuilderDeserializer {
 }
 /// <p>CPU utilization metrics for an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CPUUtilization {
     /// <p>Available on Linux environments only.</p> <p>Percentage of time that the CPU has spent in the <code>I/O Wait</code> state over the last 10 seconds.</p>
     pub io_wait: Option<f64>,
@@ -1068,11 +1127,24 @@ impl CausesDeserializer {
 }
 /// <p>Results message indicating whether a CNAME is available.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CheckDNSAvailabilityMessage {
     /// <p>The prefix used when this CNAME is reserved.</p>
     pub cname_prefix: String,
 }
 
+impl Validate for CheckDNSAvailabilityMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cname_prefix"),
+            self.cname_prefix.chars().count(),
+            Some(4),
+            Some(63),
+        );
+    }
+}
+
 /// Serialize `CheckDNSAvailabilityMessage` contents to a `SignedRequest`.
 struct CheckDNSAvailabilityMessageSerializer;
 impl CheckDNSAvailabilityMessageSerializer {
@@ -1088,6 +1160,8 @@ impl CheckDNSAvailabilityMessageSerializer {
 
 /// <p>Indicates if the specified CNAME is available.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CheckDNSAvailabilityResultMessage {
     /// <p><p>Indicates if the specified CNAME is available:</p> <ul> <li> <p> <code>true</code> : The CNAME is available.</p> </li> <li> <p> <code>false</code> : The CNAME is not available.</p> </li> </ul></p>
     pub available: Option<bool>,
@@ -1131,7 +1205,7 @@ impl CnameAvailabilityDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1139,6 +1213,8 @@ impl CnameAvailabilityDeserializer {
 }
 /// <p>Request to create or update a group of environments.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ComposeEnvironmentsMessage {
     /// <p>The name of the application to which the specified source bundles belong.</p>
     pub application_name: Option<String>,
@@ -1148,6 +1224,33 @@ pub struct ComposeEnvironmentsMessage {
     pub version_labels: Option<Vec<String>>,
 }
 
+impl Validate for ComposeEnvironmentsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_name {
+            errors.check_length(
+                &field_path(path, "application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.group_name {
+            errors.check_length(
+                &field_path(path, "group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(19),
+            );
+        }
+        if let Some(ref value) = self.version_labels {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "version_labels"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+            }
+        }
+    }
+}
+
 /// Serialize `ComposeEnvironmentsMessage` contents to a `SignedRequest`.
 struct ComposeEnvironmentsMessageSerializer;
 impl ComposeEnvironmentsMessageSerializer {
@@ -1197,6 +1300,8 @@ impl ConfigurationOptionDefaultValueDeserializer {
 }
 /// <p>Describes the possible values for a configuration option.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigurationOptionDescription {
     /// <p><p>An indication of which action is required if the value for this configuration option changes:</p> <ul> <li> <p> <code>NoInterruption</code> : There is no interruption to the environment or application availability.</p> </li> <li> <p> <code>RestartEnvironment</code> : The environment is entirely restarted, all AWS resources are deleted and recreated, and the environment is unavailable during the process.</p> </li> <li> <p> <code>RestartApplicationServer</code> : The environment is available the entire time. However, a short application outage occurs when the application servers on the running Amazon EC2 instances are restarted.</p> </li> </ul></p>
     pub change_severity: Option<String>,
@@ -1370,6 +1475,8 @@ impl ConfigurationOptionPossibleValuesDeserializer {
 }
 /// <p> A specification identifying an individual configuration option along with its current value. For a list of possible option values, go to <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/command-options.html">Option Values</a> in the <i>AWS Elastic Beanstalk Developer Guide</i>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigurationOptionSetting {
     /// <p>A unique namespace identifying the option's associated AWS resource.</p>
     pub namespace: Option<String>,
@@ -1381,6 +1488,19 @@ pub struct ConfigurationOptionSetting {
     pub value: Option<String>,
 }
 
+impl Validate for ConfigurationOptionSetting {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_name {
+            errors.check_length(
+                &field_path(path, "resource_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 struct ConfigurationOptionSettingDeserializer;
 impl ConfigurationOptionSettingDeserializer {
     #[allow(unused_variables)]
@@ -1514,6 +1634,8 @@ impl ConfigurationOptionValueTypeDeserializer {
 }
 /// <p>Describes the settings for a specified configuration set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigurationOptionsDescription {
     /// <p> A list of <a>ConfigurationOptionDescription</a>. </p>
     pub options: Option<Vec<ConfigurationOptionDescription>>,
@@ -1561,6 +1683,8 @@ impl ConfigurationOptionsDescriptionDeserializer {
 }
 /// <p>Describes the settings for a configuration set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigurationSettingsDescription {
     /// <p>The name of the application associated with this configuration set.</p>
     pub application_name: Option<String>,
@@ -1680,6 +1804,8 @@ impl ConfigurationSettingsDescriptionListDeserializer {
 }
 /// <p>The results from a request to change the configuration settings of an environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigurationSettingsDescriptions {
     /// <p> A list of <a>ConfigurationSettingsDescription</a>. </p>
     pub configuration_settings: Option<Vec<ConfigurationSettingsDescription>>,
@@ -1714,6 +1840,8 @@ impl ConfigurationSettingsDescriptionsDeserializer {
 }
 /// <p>Provides a list of validation messages.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigurationSettingsValidationMessages {
     /// <p> A list of <a>ValidationMessage</a>. </p>
     pub messages: Option<Vec<ValidationMessage>>,
@@ -1775,6 +1903,8 @@ impl ConfigurationTemplateNamesListDeserializer {
 }
 /// <p>Request to create an application.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApplicationMessage {
     /// <p>The name of the application.</p> <p>Constraint: This name must be unique within your account. If the specified name already exists, the action returns an <code>InvalidParameterValue</code> error.</p>
     pub application_name: String,
@@ -1786,6 +1916,31 @@ pub struct CreateApplicationMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateApplicationMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateApplicationMessage` contents to a `SignedRequest`.
 struct CreateApplicationMessageSerializer;
 impl CreateApplicationMessageSerializer {
@@ -1817,6 +1972,8 @@ impl CreateApplicationMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApplicationVersionMessage {
     /// <p> The name of the application. If no application is found with this name, and <code>AutoCreateApplication</code> is <code>false</code>, returns an <code>InvalidParameterValue</code> error. </p>
     pub application_name: String,
@@ -1838,6 +1995,46 @@ pub struct CreateApplicationVersionMessage {
     pub version_label: String,
 }
 
+impl Validate for CreateApplicationVersionMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.build_configuration {
+            value.validate_at(&field_path(path, "build_configuration"), errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.source_build_information {
+            value.validate_at(&field_path(path, "source_build_information"), errors);
+        }
+        if let Some(ref value) = self.source_bundle {
+            value.validate_at(&field_path(path, "source_bundle"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "version_label"),
+            self.version_label.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// Serialize `CreateApplicationVersionMessage` contents to a `SignedRequest`.
 struct CreateApplicationVersionMessageSerializer;
 impl CreateApplicationVersionMessageSerializer {
@@ -1893,6 +2090,8 @@ impl CreateApplicationVersionMessageSerializer {
 
 /// <p>Request to create a configuration template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConfigurationTemplateMessage {
     /// <p>The name of the application to associate with this configuration template. If no application is found with this name, AWS Elastic Beanstalk returns an <code>InvalidParameterValue</code> error. </p>
     pub application_name: String,
@@ -1914,6 +2113,46 @@ pub struct CreateConfigurationTemplateMessage {
     pub template_name: String,
 }
 
+impl Validate for CreateConfigurationTemplateMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.option_settings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "option_settings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.source_configuration {
+            value.validate_at(&field_path(path, "source_configuration"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "template_name"),
+            self.template_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// Serialize `CreateConfigurationTemplateMessage` contents to a `SignedRequest`.
 struct CreateConfigurationTemplateMessageSerializer;
 impl CreateConfigurationTemplateMessageSerializer {
@@ -1962,6 +2201,8 @@ impl CreateConfigurationTemplateMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEnvironmentMessage {
     /// <p>The name of the application that contains the version to be deployed.</p> <p> If no application is found with this name, <code>CreateEnvironment</code> returns an <code>InvalidParameterValue</code> error. </p>
     pub application_name: String,
@@ -1991,6 +2232,83 @@ pub struct CreateEnvironmentMessage {
     pub version_label: Option<String>,
 }
 
+impl Validate for CreateEnvironmentMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.cname_prefix {
+            errors.check_length(
+                &field_path(path, "cname_prefix"),
+                value.chars().count(),
+                Some(4),
+                Some(63),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.group_name {
+            errors.check_length(
+                &field_path(path, "group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(19),
+            );
+        }
+        if let Some(ref value) = self.option_settings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "option_settings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.options_to_remove {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "options_to_remove"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.template_name {
+            errors.check_length(
+                &field_path(path, "template_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.version_label {
+            errors.check_length(
+                &field_path(path, "version_label"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// Serialize `CreateEnvironmentMessage` contents to a `SignedRequest`.
 struct CreateEnvironmentMessageSerializer;
 impl CreateEnvironmentMessageSerializer {
@@ -2057,6 +2375,8 @@ impl CreateEnvironmentMessageSerializer {
 
 /// <p>Request to create a new platform version.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePlatformVersionRequest {
     /// <p>The name of the builder environment.</p>
     pub environment_name: Option<String>,
@@ -2072,6 +2392,33 @@ pub struct CreatePlatformVersionRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreatePlatformVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.option_settings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "option_settings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.platform_definition_bundle
+            .validate_at(&field_path(path, "platform_definition_bundle"), errors);
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreatePlatformVersionRequest` contents to a `SignedRequest`.
 struct CreatePlatformVersionRequestSerializer;
 impl CreatePlatformVersionRequestSerializer {
@@ -2108,6 +2455,8 @@ impl CreatePlatformVersionRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePlatformVersionResult {
     /// <p>The builder used to create the custom platform.</p>
     pub builder: Option<Builder>,
@@ -2145,6 +2494,8 @@ impl CreatePlatformVersionResultDeserializer {
 }
 /// <p>Results of a <a>CreateStorageLocationResult</a> call.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStorageLocationResultMessage {
     /// <p>The name of the Amazon S3 bucket created.</p>
     pub s3_bucket: Option<String>,
@@ -2185,6 +2536,8 @@ impl CreationDateDeserializer {
 }
 /// <p>A custom AMI available to platforms.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CustomAmi {
     /// <p>THe ID of the image used to create the custom AMI.</p>
     pub image_id: Option<String>,
@@ -2246,6 +2599,8 @@ impl DNSCnameDeserializer {
 }
 /// <p>Request to delete an application.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApplicationMessage {
     /// <p>The name of the application to delete.</p>
     pub application_name: String,
@@ -2253,6 +2608,17 @@ pub struct DeleteApplicationMessage {
     pub terminate_env_by_force: Option<bool>,
 }
 
+impl Validate for DeleteApplicationMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// Serialize `DeleteApplicationMessage` contents to a `SignedRequest`.
 struct DeleteApplicationMessageSerializer;
 impl DeleteApplicationMessageSerializer {
@@ -2277,6 +2643,8 @@ impl DeleteApplicationMessageSerializer {
 
 /// <p>Request to delete an application version.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApplicationVersionMessage {
     /// <p>The name of the application to which the version belongs.</p>
     pub application_name: String,
@@ -2286,6 +2654,23 @@ pub struct DeleteApplicationVersionMessage {
     pub version_label: String,
 }
 
+impl Validate for DeleteApplicationVersionMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "version_label"),
+            self.version_label.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// Serialize `DeleteApplicationVersionMessage` contents to a `SignedRequest`.
 struct DeleteApplicationVersionMessageSerializer;
 impl DeleteApplicationVersionMessageSerializer {
@@ -2308,6 +2693,8 @@ impl DeleteApplicationVersionMessageSerializer {
 
 /// <p>Request to delete a configuration template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConfigurationTemplateMessage {
     /// <p>The name of the application to delete the configuration template from.</p>
     pub application_name: String,
@@ -2315,6 +2702,23 @@ pub struct DeleteConfigurationTemplateMessage {
     pub template_name: String,
 }
 
+impl Validate for DeleteConfigurationTemplateMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "template_name"),
+            self.template_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// Serialize `DeleteConfigurationTemplateMessage` contents to a `SignedRequest`.
 struct DeleteConfigurationTemplateMessageSerializer;
 impl DeleteConfigurationTemplateMessageSerializer {
@@ -2334,6 +2738,8 @@ impl DeleteConfigurationTemplateMessageSerializer {
 
 /// <p>Request to delete a draft environment configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEnvironmentConfigurationMessage {
     /// <p>The name of the application the environment is associated with.</p>
     pub application_name: String,
@@ -2341,6 +2747,23 @@ pub struct DeleteEnvironmentConfigurationMessage {
     pub environment_name: String,
 }
 
+impl Validate for DeleteEnvironmentConfigurationMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "environment_name"),
+            self.environment_name.chars().count(),
+            Some(4),
+            Some(40),
+        );
+    }
+}
+
 /// Serialize `DeleteEnvironmentConfigurationMessage` contents to a `SignedRequest`.
 struct DeleteEnvironmentConfigurationMessageSerializer;
 impl DeleteEnvironmentConfigurationMessageSerializer {
@@ -2362,6 +2785,8 @@ impl DeleteEnvironmentConfigurationMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePlatformVersionRequest {
     /// <p>The ARN of the version of the custom platform.</p>
     pub platform_arn: Option<String>,
@@ -2383,6 +2808,8 @@ impl DeletePlatformVersionRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePlatformVersionResult {
     /// <p>Detailed information about the version of the custom platform.</p>
     pub platform_summary: Option<PlatformSummary>,
@@ -2415,6 +2842,8 @@ impl DeletePlatformVersionResultDeserializer {
 }
 /// <p>Information about an application version deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Deployment {
     /// <p>The ID of the deployment. This number increases by one each time that you deploy source code or change instance configuration settings.</p>
     pub deployment_id: Option<i64>,
@@ -2472,6 +2901,8 @@ impl DeploymentTimestampDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountAttributesResult {
     /// <p>The Elastic Beanstalk resource quotas associated with the calling AWS account.</p>
     pub resource_quotas: Option<ResourceQuotas>,
@@ -2504,6 +2935,8 @@ impl DescribeAccountAttributesResultDeserializer {
 }
 /// <p>Request to describe application versions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeApplicationVersionsMessage {
     /// <p>Specify an application name to show only application versions for that application.</p>
     pub application_name: Option<String>,
@@ -2515,6 +2948,33 @@ pub struct DescribeApplicationVersionsMessage {
     pub version_labels: Option<Vec<String>>,
 }
 
+impl Validate for DescribeApplicationVersionsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_name {
+            errors.check_length(
+                &field_path(path, "application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.max_records {
+            errors.check_range(
+                &field_path(path, "max_records"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.version_labels {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "version_labels"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeApplicationVersionsMessage` contents to a `SignedRequest`.
 struct DescribeApplicationVersionsMessageSerializer;
 impl DescribeApplicationVersionsMessageSerializer {
@@ -2545,11 +3005,24 @@ impl DescribeApplicationVersionsMessageSerializer {
 
 /// <p>Request to describe one or more applications.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeApplicationsMessage {
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to only include those with the specified names.</p>
     pub application_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeApplicationsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "application_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeApplicationsMessage` contents to a `SignedRequest`.
 struct DescribeApplicationsMessageSerializer;
 impl DescribeApplicationsMessageSerializer {
@@ -2571,6 +3044,8 @@ impl DescribeApplicationsMessageSerializer {
 
 /// <p>Result message containing a list of application version descriptions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationOptionsMessage {
     /// <p>The name of the application associated with the configuration template or environment. Only needed if you want to describe the configuration options associated with either the configuration template or environment.</p>
     pub application_name: Option<String>,
@@ -2586,6 +3061,41 @@ pub struct DescribeConfigurationOptionsMessage {
     pub template_name: Option<String>,
 }
 
+impl Validate for DescribeConfigurationOptionsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_name {
+            errors.check_length(
+                &field_path(path, "application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.options {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "options"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.template_name {
+            errors.check_length(
+                &field_path(path, "template_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeConfigurationOptionsMessage` contents to a `SignedRequest`.
 struct DescribeConfigurationOptionsMessageSerializer;
 impl DescribeConfigurationOptionsMessageSerializer {
@@ -2622,6 +3132,8 @@ impl DescribeConfigurationOptionsMessageSerializer {
 
 /// <p>Result message containing all of the configuration settings for a specified solution stack or configuration template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationSettingsMessage {
     /// <p>The application for the environment or configuration template.</p>
     pub application_name: String,
@@ -2631,6 +3143,33 @@ pub struct DescribeConfigurationSettingsMessage {
     pub template_name: Option<String>,
 }
 
+impl Validate for DescribeConfigurationSettingsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.template_name {
+            errors.check_length(
+                &field_path(path, "template_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeConfigurationSettingsMessage` contents to a `SignedRequest`.
 struct DescribeConfigurationSettingsMessageSerializer;
 impl DescribeConfigurationSettingsMessageSerializer {
@@ -2655,6 +3194,8 @@ impl DescribeConfigurationSettingsMessageSerializer {
 
 /// <p>See the example below to learn how to create a request body.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentHealthRequest {
     /// <p>Specify the response elements to return. To retrieve all attributes, set to <code>All</code>. If no attribute names are specified, returns the name of the environment.</p>
     pub attribute_names: Option<Vec<String>>,
@@ -2664,6 +3205,19 @@ pub struct DescribeEnvironmentHealthRequest {
     pub environment_name: Option<String>,
 }
 
+impl Validate for DescribeEnvironmentHealthRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeEnvironmentHealthRequest` contents to a `SignedRequest`.
 struct DescribeEnvironmentHealthRequestSerializer;
 impl DescribeEnvironmentHealthRequestSerializer {
@@ -2691,6 +3245,8 @@ impl DescribeEnvironmentHealthRequestSerializer {
 
 /// <p>Health details for an AWS Elastic Beanstalk environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentHealthResult {
     /// <p>Application request metrics for the environment.</p>
     pub application_metrics: Option<ApplicationMetrics>,
@@ -2771,6 +3327,8 @@ impl DescribeEnvironmentHealthResultDeserializer {
 }
 /// <p>Request to list completed and failed managed actions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentManagedActionHistoryRequest {
     /// <p>The environment ID of the target environment.</p>
     pub environment_id: Option<String>,
@@ -2782,6 +3340,22 @@ pub struct DescribeEnvironmentManagedActionHistoryRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeEnvironmentManagedActionHistoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 /// Serialize `DescribeEnvironmentManagedActionHistoryRequest` contents to a `SignedRequest`.
 struct DescribeEnvironmentManagedActionHistoryRequestSerializer;
 impl DescribeEnvironmentManagedActionHistoryRequestSerializer {
@@ -2812,6 +3386,8 @@ impl DescribeEnvironmentManagedActionHistoryRequestSerializer {
 
 /// <p>A result message containing a list of completed and failed managed actions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentManagedActionHistoryResult {
     /// <p>A list of completed and failed managed actions.</p>
     pub managed_action_history_items: Option<Vec<ManagedActionHistoryItem>>,
@@ -2851,6 +3427,8 @@ impl DescribeEnvironmentManagedActionHistoryResultDeserializer {
 }
 /// <p>Request to list an environment's upcoming and in-progress managed actions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentManagedActionsRequest {
     /// <p>The environment ID of the target environment.</p>
     pub environment_id: Option<String>,
@@ -2883,6 +3461,8 @@ impl DescribeEnvironmentManagedActionsRequestSerializer {
 
 /// <p>The result message containing a list of managed actions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentManagedActionsResult {
     /// <p>A list of upcoming and in-progress managed actions.</p>
     pub managed_actions: Option<Vec<ManagedAction>>,
@@ -2914,6 +3494,8 @@ impl DescribeEnvironmentManagedActionsResultDeserializer {
 }
 /// <p>Request to describe the resources in an environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentResourcesMessage {
     /// <p>The ID of the environment to retrieve AWS resource usage data.</p> <p> Condition: You must specify either this or an EnvironmentName, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error. </p>
     pub environment_id: Option<String>,
@@ -2921,6 +3503,19 @@ pub struct DescribeEnvironmentResourcesMessage {
     pub environment_name: Option<String>,
 }
 
+impl Validate for DescribeEnvironmentResourcesMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeEnvironmentResourcesMessage` contents to a `SignedRequest`.
 struct DescribeEnvironmentResourcesMessageSerializer;
 impl DescribeEnvironmentResourcesMessageSerializer {
@@ -2941,6 +3536,8 @@ impl DescribeEnvironmentResourcesMessageSerializer {
 
 /// <p>Request to describe one or more environments.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEnvironmentsMessage {
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to include only those that are associated with this application.</p>
     pub application_name: Option<String>,
@@ -2960,6 +3557,41 @@ pub struct DescribeEnvironmentsMessage {
     pub version_label: Option<String>,
 }
 
+impl Validate for DescribeEnvironmentsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_name {
+            errors.check_length(
+                &field_path(path, "application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.environment_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "environment_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(4), Some(40));
+            }
+        }
+        if let Some(ref value) = self.max_records {
+            errors.check_range(
+                &field_path(path, "max_records"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.version_label {
+            errors.check_length(
+                &field_path(path, "version_label"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeEnvironmentsMessage` contents to a `SignedRequest`.
 struct DescribeEnvironmentsMessageSerializer;
 impl DescribeEnvironmentsMessageSerializer {
@@ -3009,6 +3641,8 @@ impl DescribeEnvironmentsMessageSerializer {
 
 /// <p>Request to retrieve a list of events for an environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventsMessage {
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to include only those associated with this application.</p>
     pub application_name: Option<String>,
@@ -3036,6 +3670,51 @@ pub struct DescribeEventsMessage {
     pub version_label: Option<String>,
 }
 
+impl Validate for DescribeEventsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_name {
+            errors.check_length(
+                &field_path(path, "application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.max_records {
+            errors.check_range(
+                &field_path(path, "max_records"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.template_name {
+            errors.check_length(
+                &field_path(path, "template_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.version_label {
+            errors.check_length(
+                &field_path(path, "version_label"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
 struct DescribeEventsMessageSerializer;
 impl DescribeEventsMessageSerializer {
@@ -3086,6 +3765,8 @@ impl DescribeEventsMessageSerializer {
 
 /// <p>Parameters for a call to <code>DescribeInstancesHealth</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInstancesHealthRequest {
     /// <p>Specifies the response elements you wish to receive. To retrieve all attributes, set to <code>All</code>. If no attribute names are specified, returns a list of instances.</p>
     pub attribute_names: Option<Vec<String>>,
@@ -3097,6 +3778,27 @@ pub struct DescribeInstancesHealthRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeInstancesHealthRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeInstancesHealthRequest` contents to a `SignedRequest`.
 struct DescribeInstancesHealthRequestSerializer;
 impl DescribeInstancesHealthRequestSerializer {
@@ -3127,6 +3829,8 @@ impl DescribeInstancesHealthRequestSerializer {
 
 /// <p>Detailed health information about the Amazon EC2 instances in an AWS Elastic Beanstalk environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInstancesHealthResult {
     /// <p>Detailed health information about each instance.</p> <p>The output differs slightly between Linux and Windows environments. There is a difference in the members that are supported under the <code>&lt;CPUUtilization&gt;</code> type.</p>
     pub instance_health_list: Option<Vec<SingleInstanceHealth>>,
@@ -3172,6 +3876,8 @@ impl DescribeInstancesHealthResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePlatformVersionRequest {
     /// <p>The ARN of the version of the platform.</p>
     pub platform_arn: Option<String>,
@@ -3193,6 +3899,8 @@ impl DescribePlatformVersionRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePlatformVersionResult {
     /// <p>Detailed information about the version of the platform.</p>
     pub platform_description: Option<PlatformDescription>,
@@ -3270,6 +3978,8 @@ impl EnvironmentArnDeserializer {
 }
 /// <p>Describes the properties of an environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnvironmentDescription {
     /// <p>Indicates if there is an in-progress environment configuration update or application version deployment that you can cancel.</p> <p> <code>true:</code> There is an update in progress. </p> <p> <code>false:</code> There are no updates currently in progress. </p>
     pub abortable_operation_in_progress: Option<bool>,
@@ -3447,6 +4157,8 @@ impl EnvironmentDescriptionsListDeserializer {
 }
 /// <p>Result message containing a list of environment descriptions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnvironmentDescriptionsMessage {
     /// <p> Returns an <a>EnvironmentDescription</a> list. </p>
     pub environments: Option<Vec<EnvironmentDescription>>,
@@ -3543,6 +4255,8 @@ impl EnvironmentIdListSerializer {
 
 /// <p>The information retrieved from the Amazon EC2 instances.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnvironmentInfoDescription {
     /// <p>The Amazon EC2 Instance ID for this information.</p>
     pub ec_2_instance_id: Option<String>,
@@ -3625,6 +4339,8 @@ impl EnvironmentInfoTypeDeserializer {
 }
 /// <p>A link to another environment, defined in the environment's manifest. Links provide connection information in system properties that can be used to connect to another environment in the same group. See <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/environment-cfg-manifest.html">Environment Manifest (env.yaml)</a> for details.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnvironmentLink {
     /// <p>The name of the linked environment (the dependency).</p>
     pub environment_name: Option<String>,
@@ -3696,6 +4412,8 @@ impl EnvironmentNamesListSerializer {
 
 /// <p>Describes the AWS resources in use by this environment. This data is live.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnvironmentResourceDescription {
     /// <p> The <code>AutoScalingGroups</code> used by this environment. </p>
     pub auto_scaling_groups: Option<Vec<AutoScalingGroup>>,
@@ -3783,6 +4501,8 @@ impl EnvironmentResourceDescriptionDeserializer {
 }
 /// <p>Result message containing a list of environment resource descriptions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnvironmentResourceDescriptionsMessage {
     /// <p> A list of <a>EnvironmentResourceDescription</a>. </p>
     pub environment_resources: Option<EnvironmentResourceDescription>,
@@ -3816,6 +4536,8 @@ impl EnvironmentResourceDescriptionsMessageDeserializer {
 }
 /// <p>Describes the AWS resources in use by this environment. This data is not live data.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnvironmentResourcesDescription {
     /// <p>Describes the LoadBalancer.</p>
     pub load_balancer: Option<LoadBalancerDescription>,
@@ -3859,6 +4581,8 @@ impl EnvironmentStatusDeserializer {
 }
 /// <p>Describes the properties of an environment tier</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnvironmentTier {
     /// <p><p>The name of this environment tier.</p> <p>Valid values:</p> <ul> <li> <p>For <i>Web server tier</i> – <code>WebServer</code> </p> </li> <li> <p>For <i>Worker tier</i> – <code>Worker</code> </p> </li> </ul></p>
     pub name: Option<String>,
@@ -3927,6 +4651,8 @@ impl EventDateDeserializer {
 }
 /// <p>Describes an event.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventDescription {
     /// <p>The application associated with the event.</p>
     pub application_name: Option<String>,
@@ -4022,6 +4748,8 @@ impl EventDescriptionListDeserializer {
 }
 /// <p>Result message wrapping a list of event descriptions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventDescriptionsMessage {
     /// <p> A list of <a>EventDescription</a>. </p>
     pub events: Option<Vec<EventDescription>>,
@@ -4113,6 +4841,8 @@ impl ImageIdDeserializer {
 }
 /// <p>The description of an Amazon EC2 instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Instance {
     /// <p>The ID of the Amazon EC2 instance.</p>
     pub id: Option<String>,
@@ -4157,6 +4887,8 @@ impl InstanceHealthListDeserializer {
 }
 /// <p>Represents summary information about the health of an instance. For more information, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/health-enhanced-status.html">Health Colors and Statuses</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceHealthSummary {
     /// <p> <b>Red.</b> The health agent is reporting a high number of request failures or other issues for an instance or environment.</p>
     pub degraded: Option<i64>,
@@ -4261,7 +4993,7 @@ impl IntegerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4269,6 +5001,8 @@ impl IntegerDeserializer {
 }
 /// <p>Represents the average latency for the slowest X percent of requests over the last 10 seconds.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Latency {
     /// <p>The average latency for the slowest 90 percent of requests over the last 10 seconds.</p>
     pub p10: Option<f64>,
@@ -4329,6 +5063,8 @@ impl LatencyDeserializer {
 }
 /// <p>Describes an Auto Scaling launch configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LaunchConfiguration {
     /// <p>The name of the launch configuration.</p>
     pub name: Option<String>,
@@ -4373,6 +5109,8 @@ impl LaunchConfigurationListDeserializer {
 }
 /// <p>Describes an Amazon EC2 launch template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LaunchTemplate {
     /// <p>The ID of the launch template.</p>
     pub id: Option<String>,
@@ -4426,6 +5164,8 @@ impl LaunchedAtDeserializer {
 }
 /// <p>A list of available AWS Elastic Beanstalk solution stacks.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAvailableSolutionStacksResultMessage {
     /// <p> A list of available solution stacks and their <a>SolutionStackDescription</a>. </p>
     pub solution_stack_details: Option<Vec<SolutionStackDescription>>,
@@ -4469,6 +5209,8 @@ impl ListAvailableSolutionStacksResultMessageDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPlatformVersionsRequest {
     /// <p>List only the platforms where the platform member value relates to one of the supplied values.</p>
     pub filters: Option<Vec<PlatformFilter>>,
@@ -4478,6 +5220,14 @@ pub struct ListPlatformVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListPlatformVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_records {
+            errors.check_range(&field_path(path, "max_records"), *value, Some(1), None);
+        }
+    }
+}
+
 /// Serialize `ListPlatformVersionsRequest` contents to a `SignedRequest`.
 struct ListPlatformVersionsRequestSerializer;
 impl ListPlatformVersionsRequestSerializer {
@@ -4504,6 +5254,8 @@ impl ListPlatformVersionsRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPlatformVersionsResult {
     /// <p>The starting index into the remaining list of platforms. if this value is not <code>null</code>, you can use it in a subsequent <code>ListPlatformVersion</code> call. </p>
     pub next_token: Option<String>,
@@ -4542,6 +5294,8 @@ impl ListPlatformVersionsResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceMessage {
     /// <p>The Amazon Resource Name (ARN) of the resouce for which a tag list is requested.</p> <p>Must be the ARN of an Elastic Beanstalk environment.</p>
     pub resource_arn: String,
@@ -4562,6 +5316,8 @@ impl ListTagsForResourceMessageSerializer {
 
 /// <p>Describes the properties of a Listener for the LoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Listener {
     /// <p>The port that is used by the Listener.</p>
     pub port: Option<i64>,
@@ -4612,7 +5368,7 @@ impl LoadAverageValueDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4620,6 +5376,8 @@ impl LoadAverageValueDeserializer {
 }
 /// <p>Describes a LoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoadBalancer {
     /// <p>The name of the LoadBalancer.</p>
     pub name: Option<String>,
@@ -4645,6 +5403,8 @@ impl LoadBalancerDeserializer {
 }
 /// <p>Describes the details of a LoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoadBalancerDescription {
     /// <p>The domain name of the LoadBalancer.</p>
     pub domain: Option<String>,
@@ -4735,6 +5495,8 @@ impl MaintainerDeserializer {
 }
 /// <p>The record of an upcoming or in-progress managed action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ManagedAction {
     /// <p>A description of the managed action.</p>
     pub action_description: Option<String>,
@@ -4785,6 +5547,8 @@ impl ManagedActionDeserializer {
 }
 /// <p>The record of a completed or failed managed action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ManagedActionHistoryItem {
     /// <p>A description of the managed action.</p>
     pub action_description: Option<String>,
@@ -4895,6 +5659,8 @@ impl ManagedActionsDeserializer {
 }
 /// <p>A lifecycle rule that deletes application versions after the specified number of days.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MaxAgeRule {
     /// <p>Set to <code>true</code> to delete a version's source bundle from Amazon S3 when Elastic Beanstalk deletes the application version.</p>
     pub delete_source_from_s3: Option<bool>,
@@ -4954,6 +5720,8 @@ impl MaxAgeRuleSerializer {
 
 /// <p>A lifecycle rule that deletes the oldest application version when the maximum count is exceeded.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MaxCountRule {
     /// <p>Set to <code>true</code> to delete a version's source bundle from Amazon S3 when Elastic Beanstalk deletes the application version.</p>
     pub delete_source_from_s3: Option<bool>,
@@ -5037,7 +5805,7 @@ impl NullableDoubleDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5048,7 +5816,7 @@ impl NullableIntegerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5059,7 +5827,7 @@ impl NullableLongDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5103,7 +5871,7 @@ impl OptionRestrictionMaxLengthDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5114,7 +5882,7 @@ impl OptionRestrictionMaxValueDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5125,7 +5893,7 @@ impl OptionRestrictionMinValueDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5133,6 +5901,8 @@ impl OptionRestrictionMinValueDeserializer {
 }
 /// <p>A regular expression representing a restriction on a string configuration option value.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OptionRestrictionRegex {
     /// <p>A unique name representing this regular expression.</p>
     pub label: Option<String>,
@@ -5163,6 +5933,8 @@ impl OptionRestrictionRegexDeserializer {
 }
 /// <p>A specification identifying an individual configuration option.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OptionSpecification {
     /// <p>A unique namespace identifying the option's associated AWS resource.</p>
     pub namespace: Option<String>,
@@ -5172,6 +5944,19 @@ pub struct OptionSpecification {
     pub resource_name: Option<String>,
 }
 
+impl Validate for OptionSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_name {
+            errors.check_length(
+                &field_path(path, "resource_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// Serialize `OptionSpecification` contents to a `SignedRequest`.
 struct OptionSpecificationSerializer;
 impl OptionSpecificationSerializer {
@@ -5228,6 +6013,8 @@ impl PlatformCategoryDeserializer {
 }
 /// <p>Detailed information about a platform.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PlatformDescription {
     /// <p>The custom AMIs supported by the platform.</p>
     pub custom_ami_list: Option<Vec<CustomAmi>>,
@@ -5381,6 +6168,8 @@ impl PlatformDescriptionDeserializer {
 }
 /// <p>Specify criteria to restrict the results when listing custom platforms.</p> <p>The filter is evaluated as the expression:</p> <p> <code>Type</code> <code>Operator</code> <code>Values[i]</code> </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PlatformFilter {
     /// <p>The operator to apply to the <code>Type</code> with each of the <code>Values</code>.</p> <p> Valid Values: <code>=</code> (equal to) | <code>!=</code> (not equal to) | <code>&lt;</code> (less than) | <code>&lt;=</code> (less than or equal to) | <code>&gt;</code> (greater than) | <code>&gt;=</code> (greater than or equal to) | <code>contains</code> | <code>begins_with</code> | <code>ends_with</code> </p>
     pub operator: Option<String>,
@@ -5439,6 +6228,8 @@ impl PlatformFiltersSerializer {
 
 /// <p>A framework supported by the custom platform.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PlatformFramework {
     /// <p>The name of the framework.</p>
     pub name: Option<String>,
@@ -5508,6 +6299,8 @@ impl PlatformOwnerDeserializer {
 }
 /// <p>A programming language supported by the platform.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PlatformProgrammingLanguage {
     /// <p>The name of the programming language.</p>
     pub name: Option<String>,
@@ -5572,6 +6365,8 @@ impl PlatformStatusDeserializer {
 }
 /// <p>Detailed information about a platform.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PlatformSummary {
     /// <p>The operating system used by the platform.</p>
     pub operating_system_name: Option<String>,
@@ -5681,6 +6476,8 @@ impl PlatformVersionDeserializer {
 }
 /// <p>Describes a queue.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Queue {
     /// <p>The name of the queue.</p>
     pub name: Option<String>,
@@ -5725,6 +6522,8 @@ impl QueueListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebuildEnvironmentMessage {
     /// <p>The ID of the environment to rebuild.</p> <p> Condition: You must specify either this or an EnvironmentName, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error. </p>
     pub environment_id: Option<String>,
@@ -5732,6 +6531,19 @@ pub struct RebuildEnvironmentMessage {
     pub environment_name: Option<String>,
 }
 
+impl Validate for RebuildEnvironmentMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+    }
+}
+
 /// Serialize `RebuildEnvironmentMessage` contents to a `SignedRequest`.
 struct RebuildEnvironmentMessageSerializer;
 impl RebuildEnvironmentMessageSerializer {
@@ -5788,7 +6600,7 @@ impl RequestCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5796,6 +6608,8 @@ impl RequestCountDeserializer {
 }
 /// <p>Request to retrieve logs from an environment and store them in your Elastic Beanstalk storage bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RequestEnvironmentInfoMessage {
     /// <p>The ID of the environment of the requested data.</p> <p>If no such environment is found, <code>RequestEnvironmentInfo</code> returns an <code>InvalidParameterValue</code> error. </p> <p>Condition: You must specify either this or an EnvironmentName, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error. </p>
     pub environment_id: Option<String>,
@@ -5805,6 +6619,19 @@ pub struct RequestEnvironmentInfoMessage {
     pub info_type: String,
 }
 
+impl Validate for RequestEnvironmentInfoMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+    }
+}
+
 /// Serialize `RequestEnvironmentInfoMessage` contents to a `SignedRequest`.
 struct RequestEnvironmentInfoMessageSerializer;
 impl RequestEnvironmentInfoMessageSerializer {
@@ -5870,6 +6697,8 @@ impl ResourceNameDeserializer {
 }
 /// <p>The AWS Elastic Beanstalk quota information for a single resource type in an AWS account. It reflects the resource's limits for this account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceQuota {
     /// <p>The maximum number of instances of this Elastic Beanstalk resource type that an AWS account can use.</p>
     pub maximum: Option<i64>,
@@ -5895,6 +6724,8 @@ impl ResourceQuotaDeserializer {
 }
 /// <p>A set of per-resource AWS Elastic Beanstalk quotas associated with an AWS account. They reflect Elastic Beanstalk resource limits for this account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceQuotas {
     /// <p>The quota for applications in the AWS account.</p>
     pub application_quota: Option<ResourceQuota>,
@@ -5955,6 +6786,8 @@ impl ResourceQuotasDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceTagsDescriptionMessage {
     /// <p>The Amazon Resource Name (ARN) of the resouce for which a tag list was requested.</p>
     pub resource_arn: Option<String>,
@@ -5992,6 +6825,8 @@ impl ResourceTagsDescriptionMessageDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestartAppServerMessage {
     /// <p>The ID of the environment to restart the server for.</p> <p> Condition: You must specify either this or an EnvironmentName, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error. </p>
     pub environment_id: Option<String>,
@@ -5999,6 +6834,19 @@ pub struct RestartAppServerMessage {
     pub environment_name: Option<String>,
 }
 
+impl Validate for RestartAppServerMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+    }
+}
+
 /// Serialize `RestartAppServerMessage` contents to a `SignedRequest`.
 struct RestartAppServerMessageSerializer;
 impl RestartAppServerMessageSerializer {
@@ -6019,6 +6867,8 @@ impl RestartAppServerMessageSerializer {
 
 /// <p>Request to download logs retrieved with <a>RequestEnvironmentInfo</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RetrieveEnvironmentInfoMessage {
     /// <p>The ID of the data's environment.</p> <p>If no such environment is found, returns an <code>InvalidParameterValue</code> error.</p> <p>Condition: You must specify either this or an EnvironmentName, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error.</p>
     pub environment_id: Option<String>,
@@ -6028,6 +6878,19 @@ pub struct RetrieveEnvironmentInfoMessage {
     pub info_type: String,
 }
 
+impl Validate for RetrieveEnvironmentInfoMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+    }
+}
+
 /// Serialize `RetrieveEnvironmentInfoMessage` contents to a `SignedRequest`.
 struct RetrieveEnvironmentInfoMessageSerializer;
 impl RetrieveEnvironmentInfoMessageSerializer {
@@ -6049,6 +6912,8 @@ impl RetrieveEnvironmentInfoMessageSerializer {
 
 /// <p>Result message containing a description of the requested environment info.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RetrieveEnvironmentInfoResultMessage {
     /// <p> The <a>EnvironmentInfoDescription</a> of the environment. </p>
     pub environment_info: Option<Vec<EnvironmentInfoDescription>>,
@@ -6105,6 +6970,8 @@ impl S3KeyDeserializer {
 }
 /// <p>The bucket and key of an item stored in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3Location {
     /// <p>The Amazon S3 bucket where the data is located.</p>
     pub s3_bucket: Option<String>,
@@ -6112,6 +6979,27 @@ pub struct S3Location {
     pub s3_key: Option<String>,
 }
 
+impl Validate for S3Location {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.s3_bucket {
+            errors.check_length(
+                &field_path(path, "s3_bucket"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.s3_key {
+            errors.check_length(
+                &field_path(path, "s3_key"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 struct S3LocationDeserializer;
 impl S3LocationDeserializer {
     #[allow(unused_variables)]
@@ -6165,6 +7053,8 @@ impl SampleTimestampDeserializer {
 }
 /// <p>Detailed health information about an Amazon EC2 instance in your Elastic Beanstalk environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SingleInstanceHealth {
     /// <p>Request metrics from your application.</p>
     pub application_metrics: Option<ApplicationMetrics>,
@@ -6246,6 +7136,8 @@ impl SingleInstanceHealthDeserializer {
 }
 /// <p>Describes the solution stack.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SolutionStackDescription {
     /// <p>The permitted file types allowed for a solution stack.</p>
     pub permitted_file_types: Option<Vec<String>>,
@@ -6316,6 +7208,8 @@ impl SolutionStackNameDeserializer {
 }
 /// <p>Location of the source code for an application version.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SourceBuildInformation {
     /// <p><p>The location of the source code, as a formatted string, depending on the value of <code>SourceRepository</code> </p> <ul> <li> <p>For <code>CodeCommit</code>, the format is the repository name and commit ID, separated by a forward slash. For example, <code>my-git-repo/265cfa0cf6af46153527f55d6503ec030551f57a</code>.</p> </li> <li> <p>For <code>S3</code>, the format is the S3 bucket name and object key, separated by a forward slash. For example, <code>my-s3-bucket/Folders/my-source-file</code>.</p> </li> </ul></p>
     pub source_location: String,
@@ -6325,6 +7219,22 @@ pub struct SourceBuildInformation {
     pub source_type: String,
 }
 
+impl Validate for SourceBuildInformation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "source_location"),
+            self.source_location.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "source_location"),
+            &self.source_location,
+            ".+/.+",
+        );
+    }
+}
+
 struct SourceBuildInformationDeserializer;
 impl SourceBuildInformationDeserializer {
     #[allow(unused_variables)]
@@ -6375,6 +7285,8 @@ impl SourceBuildInformationSerializer {
 
 /// <p>A specification for an environment configuration</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SourceConfiguration {
     /// <p>The name of the application associated with the configuration.</p>
     pub application_name: Option<String>,
@@ -6382,6 +7294,27 @@ pub struct SourceConfiguration {
     pub template_name: Option<String>,
 }
 
+impl Validate for SourceConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_name {
+            errors.check_length(
+                &field_path(path, "application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.template_name {
+            errors.check_length(
+                &field_path(path, "template_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// Serialize `SourceConfiguration` contents to a `SignedRequest`.
 struct SourceConfigurationSerializer;
 impl SourceConfigurationSerializer {
@@ -6435,6 +7368,8 @@ impl SourceTypeDeserializer {
 }
 /// <p>Represents the percentage of requests over the last 10 seconds that resulted in each type of status code response. For more information, see <a href="http://www.w3.org/Protocols/rfc2616/rfc2616-sec10.html">Status Code Definitions</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StatusCodes {
     /// <p>The percentage of requests over the last 10 seconds that resulted in a 2xx (200, 201, etc.) status code.</p>
     pub status_2xx: Option<i64>,
@@ -6554,6 +7489,8 @@ impl SupportedTierListDeserializer {
 }
 /// <p>Swaps the CNAMEs of two environments.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SwapEnvironmentCNAMEsMessage {
     /// <p>The ID of the destination environment.</p> <p> Condition: You must specify at least the <code>DestinationEnvironmentID</code> or the <code>DestinationEnvironmentName</code>. You may also specify both. You must specify the <code>SourceEnvironmentId</code> with the <code>DestinationEnvironmentId</code>. </p>
     pub destination_environment_id: Option<String>,
@@ -6565,6 +7502,27 @@ pub struct SwapEnvironmentCNAMEsMessage {
     pub source_environment_name: Option<String>,
 }
 
+impl Validate for SwapEnvironmentCNAMEsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination_environment_name {
+            errors.check_length(
+                &field_path(path, "destination_environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.source_environment_name {
+            errors.check_length(
+                &field_path(path, "source_environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+    }
+}
+
 /// Serialize `SwapEnvironmentCNAMEsMessage` contents to a `SignedRequest`.
 struct SwapEnvironmentCNAMEsMessageSerializer;
 impl SwapEnvironmentCNAMEsMessageSerializer {
@@ -6603,6 +7561,8 @@ impl SwapEnvironmentCNAMEsMessageSerializer {
 
 /// <p>CPU utilization and load average metrics for an Amazon EC2 instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SystemStatus {
     /// <p>CPU utilization metrics for the instance.</p>
     pub cpu_utilization: Option<CPUUtilization>,
@@ -6638,6 +7598,8 @@ impl SystemStatusDeserializer {
 }
 /// <p>Describes a tag applied to a resource in an environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>The key of the tag.</p>
     pub key: Option<String>,
@@ -6645,6 +7607,27 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
@@ -6759,6 +7742,8 @@ impl TagsSerializer {
 
 /// <p>Request to terminate an environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminateEnvironmentMessage {
     /// <p>The ID of the environment to terminate.</p> <p> Condition: You must specify either this or an EnvironmentName, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error. </p>
     pub environment_id: Option<String>,
@@ -6770,6 +7755,19 @@ pub struct TerminateEnvironmentMessage {
     pub terminate_resources: Option<bool>,
 }
 
+impl Validate for TerminateEnvironmentMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+    }
+}
+
 /// Serialize `TerminateEnvironmentMessage` contents to a `SignedRequest`.
 struct TerminateEnvironmentMessageSerializer;
 impl TerminateEnvironmentMessageSerializer {
@@ -6818,6 +7816,8 @@ impl TokenDeserializer {
 }
 /// <p>Describes a trigger.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Trigger {
     /// <p>The name of the trigger.</p>
     pub name: Option<String>,
@@ -6860,6 +7860,8 @@ impl TriggerListDeserializer {
 }
 /// <p>Request to update an application.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApplicationMessage {
     /// <p>The name of the application to update. If no such application is found, <code>UpdateApplication</code> returns an <code>InvalidParameterValue</code> error. </p>
     pub application_name: String,
@@ -6867,7 +7869,26 @@ pub struct UpdateApplicationMessage {
     pub description: Option<String>,
 }
 
-/// Serialize `UpdateApplicationMessage` contents to a `SignedRequest`.
+impl Validate for UpdateApplicationMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+    }
+}
+
+/// Serialize `UpdateApplicationMessage` contents to a `SignedRequest`.
 struct UpdateApplicationMessageSerializer;
 impl UpdateApplicationMessageSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &UpdateApplicationMessage) {
@@ -6887,6 +7908,8 @@ impl UpdateApplicationMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApplicationResourceLifecycleMessage {
     /// <p>The name of the application.</p>
     pub application_name: String,
@@ -6894,6 +7917,17 @@ pub struct UpdateApplicationResourceLifecycleMessage {
     pub resource_lifecycle_config: ApplicationResourceLifecycleConfig,
 }
 
+impl Validate for UpdateApplicationResourceLifecycleMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// Serialize `UpdateApplicationResourceLifecycleMessage` contents to a `SignedRequest`.
 struct UpdateApplicationResourceLifecycleMessageSerializer;
 impl UpdateApplicationResourceLifecycleMessageSerializer {
@@ -6917,6 +7951,8 @@ impl UpdateApplicationResourceLifecycleMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApplicationVersionMessage {
     /// <p>The name of the application associated with this version.</p> <p> If no application is found with this name, <code>UpdateApplication</code> returns an <code>InvalidParameterValue</code> error.</p>
     pub application_name: String,
@@ -6926,6 +7962,31 @@ pub struct UpdateApplicationVersionMessage {
     pub version_label: String,
 }
 
+impl Validate for UpdateApplicationVersionMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "version_label"),
+            self.version_label.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// Serialize `UpdateApplicationVersionMessage` contents to a `SignedRequest`.
 struct UpdateApplicationVersionMessageSerializer;
 impl UpdateApplicationVersionMessageSerializer {
@@ -6948,6 +8009,8 @@ impl UpdateApplicationVersionMessageSerializer {
 
 /// <p>The result message containing the options for the specified solution stack.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConfigurationTemplateMessage {
     /// <p>The name of the application associated with the configuration template to update.</p> <p> If no application is found with this name, <code>UpdateConfigurationTemplate</code> returns an <code>InvalidParameterValue</code> error. </p>
     pub application_name: String,
@@ -6961,6 +8024,43 @@ pub struct UpdateConfigurationTemplateMessage {
     pub template_name: String,
 }
 
+impl Validate for UpdateConfigurationTemplateMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.option_settings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "option_settings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.options_to_remove {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "options_to_remove"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "template_name"),
+            self.template_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 /// Serialize `UpdateConfigurationTemplateMessage` contents to a `SignedRequest`.
 struct UpdateConfigurationTemplateMessageSerializer;
 impl UpdateConfigurationTemplateMessageSerializer {
@@ -7008,6 +8108,8 @@ impl UpdateDateDeserializer {
 }
 /// <p>Request to update an environment.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateEnvironmentMessage {
     /// <p>The name of the application with which the environment is associated.</p>
     pub application_name: Option<String>,
@@ -7035,6 +8137,71 @@ pub struct UpdateEnvironmentMessage {
     pub version_label: Option<String>,
 }
 
+impl Validate for UpdateEnvironmentMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_name {
+            errors.check_length(
+                &field_path(path, "application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.group_name {
+            errors.check_length(
+                &field_path(path, "group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(19),
+            );
+        }
+        if let Some(ref value) = self.option_settings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "option_settings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.options_to_remove {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "options_to_remove"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.template_name {
+            errors.check_length(
+                &field_path(path, "template_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.version_label {
+            errors.check_length(
+                &field_path(path, "version_label"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// Serialize `UpdateEnvironmentMessage` contents to a `SignedRequest`.
 struct UpdateEnvironmentMessageSerializer;
 impl UpdateEnvironmentMessageSerializer {
@@ -7096,6 +8263,8 @@ impl UpdateEnvironmentMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTagsForResourceMessage {
     /// <p>The Amazon Resource Name (ARN) of the resouce to be updated.</p> <p>Must be the ARN of an Elastic Beanstalk environment.</p>
     pub resource_arn: String,
@@ -7105,6 +8274,23 @@ pub struct UpdateTagsForResourceMessage {
     pub tags_to_remove: Option<Vec<String>>,
 }
 
+impl Validate for UpdateTagsForResourceMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags_to_add {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags_to_add"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.tags_to_remove {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags_to_remove"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            }
+        }
+    }
+}
+
 /// Serialize `UpdateTagsForResourceMessage` contents to a `SignedRequest`.
 struct UpdateTagsForResourceMessageSerializer;
 impl UpdateTagsForResourceMessageSerializer {
@@ -7137,7 +8323,7 @@ impl UserDefinedOptionDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7145,6 +8331,8 @@ impl UserDefinedOptionDeserializer {
 }
 /// <p>A list of validation messages for a specified configuration template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ValidateConfigurationSettingsMessage {
     /// <p>The name of the application that the configuration template or environment belongs to.</p>
     pub application_name: String,
@@ -7156,6 +8344,37 @@ pub struct ValidateConfigurationSettingsMessage {
     pub template_name: Option<String>,
 }
 
+impl Validate for ValidateConfigurationSettingsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.environment_name {
+            errors.check_length(
+                &field_path(path, "environment_name"),
+                value.chars().count(),
+                Some(4),
+                Some(40),
+            );
+        }
+        for (i, item) in self.option_settings.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "option_settings"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.template_name {
+            errors.check_length(
+                &field_path(path, "template_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// Serialize `ValidateConfigurationSettingsMessage` contents to a `SignedRequest`.
 struct ValidateConfigurationSettingsMessageSerializer;
 impl ValidateConfigurationSettingsMessageSerializer {
@@ -7185,6 +8404,8 @@ impl ValidateConfigurationSettingsMessageSerializer {
 
 /// <p>An error or warning for a desired configuration option value.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ValidationMessage {
     /// <p>A message describing the error or warning.</p>
     pub message: Option<String>,
@@ -9906,951 +11127,132 @@ pub trait ElasticBeanstalk {
         input: ValidateConfigurationSettingsMessage,
     ) -> RusotoFuture<ConfigurationSettingsValidationMessages, ValidateConfigurationSettingsError>;
 }
-/// A client for the Elastic Beanstalk API.
-#[derive(Clone)]
-pub struct ElasticBeanstalkClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ElasticBeanstalkClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ElasticBeanstalkClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ElasticBeanstalkClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ElasticBeanstalkClient {
-        ElasticBeanstalkClient { client, region }
-    }
-}
-
-impl ElasticBeanstalk for ElasticBeanstalkClient {
-    /// <p>Cancels in-progress environment configuration update or application version deployment.</p>
+const _: Option<&dyn ElasticBeanstalk> = None;
+impl<'a, T: ElasticBeanstalk + ?Sized> ElasticBeanstalk for &'a T {
     fn abort_environment_update(
         &self,
         input: AbortEnvironmentUpdateMessage,
     ) -> RusotoFuture<(), AbortEnvironmentUpdateError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AbortEnvironmentUpdate");
-        params.put("Version", "2010-12-01");
-        AbortEnvironmentUpdateMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AbortEnvironmentUpdateError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).abort_environment_update(input)
     }
-
-    /// <p>Applies a scheduled managed action immediately. A managed action can be applied only if its status is <code>Scheduled</code>. Get the status and action ID of a managed action with <a>DescribeEnvironmentManagedActions</a>.</p>
     fn apply_environment_managed_action(
         &self,
         input: ApplyEnvironmentManagedActionRequest,
     ) -> RusotoFuture<ApplyEnvironmentManagedActionResult, ApplyEnvironmentManagedActionError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ApplyEnvironmentManagedAction");
-        params.put("Version", "2010-12-01");
-        ApplyEnvironmentManagedActionRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ApplyEnvironmentManagedActionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ApplyEnvironmentManagedActionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ApplyEnvironmentManagedActionResultDeserializer::deserialize(
-                        "ApplyEnvironmentManagedActionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).apply_environment_managed_action(input)
     }
-
-    /// <p>Checks if the specified CNAME is available.</p>
     fn check_dns_availability(
         &self,
         input: CheckDNSAvailabilityMessage,
     ) -> RusotoFuture<CheckDNSAvailabilityResultMessage, CheckDNSAvailabilityError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CheckDNSAvailability");
-        params.put("Version", "2010-12-01");
-        CheckDNSAvailabilityMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CheckDNSAvailabilityError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CheckDNSAvailabilityResultMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CheckDNSAvailabilityResultMessageDeserializer::deserialize(
-                        "CheckDNSAvailabilityResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).check_dns_availability(input)
     }
-
-    /// <p>Create or update a group of environments that each run a separate component of a single application. Takes a list of version labels that specify application source bundles for each of the environments to create or update. The name of each environment and other required information must be included in the source bundles in an environment manifest named <code>env.yaml</code>. See <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/environment-mgmt-compose.html">Compose Environments</a> for details.</p>
     fn compose_environments(
         &self,
         input: ComposeEnvironmentsMessage,
     ) -> RusotoFuture<EnvironmentDescriptionsMessage, ComposeEnvironmentsError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ComposeEnvironments");
-        params.put("Version", "2010-12-01");
-        ComposeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ComposeEnvironmentsError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EnvironmentDescriptionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EnvironmentDescriptionsMessageDeserializer::deserialize(
-                        "ComposeEnvironmentsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).compose_environments(input)
     }
-
-    /// <p> Creates an application that has one configuration template named <code>default</code> and no application versions. </p>
     fn create_application(
         &self,
         input: CreateApplicationMessage,
     ) -> RusotoFuture<ApplicationDescriptionMessage, CreateApplicationError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateApplication");
-        params.put("Version", "2010-12-01");
-        CreateApplicationMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateApplicationError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ApplicationDescriptionMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ApplicationDescriptionMessageDeserializer::deserialize(
-                        "CreateApplicationResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_application(input)
     }
-
-    /// <p><p>Creates an application version for the specified application. You can create an application version from a source bundle in Amazon S3, a commit in AWS CodeCommit, or the output of an AWS CodeBuild build as follows:</p> <p>Specify a commit in an AWS CodeCommit repository with <code>SourceBuildInformation</code>.</p> <p>Specify a build in an AWS CodeBuild with <code>SourceBuildInformation</code> and <code>BuildConfiguration</code>.</p> <p>Specify a source bundle in S3 with <code>SourceBundle</code> </p> <p>Omit both <code>SourceBuildInformation</code> and <code>SourceBundle</code> to use the default sample application.</p> <note> <p>Once you create an application version with a specified Amazon S3 bucket and key location, you cannot change that Amazon S3 location. If you change the Amazon S3 location, you receive an exception when you attempt to launch an environment from the application version.</p> </note></p>
     fn create_application_version(
         &self,
         input: CreateApplicationVersionMessage,
     ) -> RusotoFuture<ApplicationVersionDescriptionMessage, CreateApplicationVersionError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateApplicationVersion");
-        params.put("Version", "2010-12-01");
-        CreateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateApplicationVersionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ApplicationVersionDescriptionMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ApplicationVersionDescriptionMessageDeserializer::deserialize(
-                        "CreateApplicationVersionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_application_version(input)
     }
-
-    /// <p><p>Creates a configuration template. Templates are associated with a specific application and are used to deploy different versions of the application with the same configuration settings.</p> <p>Templates aren&#39;t associated with any environment. The <code>EnvironmentName</code> response element is always <code>null</code>.</p> <p>Related Topics</p> <ul> <li> <p> <a>DescribeConfigurationOptions</a> </p> </li> <li> <p> <a>DescribeConfigurationSettings</a> </p> </li> <li> <p> <a>ListAvailableSolutionStacks</a> </p> </li> </ul></p>
     fn create_configuration_template(
         &self,
         input: CreateConfigurationTemplateMessage,
     ) -> RusotoFuture<ConfigurationSettingsDescription, CreateConfigurationTemplateError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateConfigurationTemplate");
-        params.put("Version", "2010-12-01");
-        CreateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateConfigurationTemplateError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ConfigurationSettingsDescription::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ConfigurationSettingsDescriptionDeserializer::deserialize(
-                        "CreateConfigurationTemplateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_configuration_template(input)
     }
-
-    /// <p>Launches an environment for the specified application using the specified configuration.</p>
     fn create_environment(
         &self,
         input: CreateEnvironmentMessage,
     ) -> RusotoFuture<EnvironmentDescription, CreateEnvironmentError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateEnvironment");
-        params.put("Version", "2010-12-01");
-        CreateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateEnvironmentError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EnvironmentDescription::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EnvironmentDescriptionDeserializer::deserialize(
-                        "CreateEnvironmentResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_environment(input)
     }
-
-    /// <p>Create a new version of your custom platform.</p>
     fn create_platform_version(
         &self,
         input: CreatePlatformVersionRequest,
     ) -> RusotoFuture<CreatePlatformVersionResult, CreatePlatformVersionError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreatePlatformVersion");
-        params.put("Version", "2010-12-01");
-        CreatePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreatePlatformVersionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreatePlatformVersionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreatePlatformVersionResultDeserializer::deserialize(
-                        "CreatePlatformVersionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_platform_version(input)
     }
-
-    /// <p>Creates a bucket in Amazon S3 to store application versions, logs, and other files used by Elastic Beanstalk environments. The Elastic Beanstalk console and EB CLI call this API the first time you create an environment in a region. If the storage location already exists, <code>CreateStorageLocation</code> still returns the bucket name but does not create a new bucket.</p>
     fn create_storage_location(
         &self,
     ) -> RusotoFuture<CreateStorageLocationResultMessage, CreateStorageLocationError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateStorageLocation");
-        params.put("Version", "2010-12-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateStorageLocationError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateStorageLocationResultMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateStorageLocationResultMessageDeserializer::deserialize(
-                        "CreateStorageLocationResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_storage_location()
     }
-
-    /// <p><p>Deletes the specified application along with all associated versions and configurations. The application versions will not be deleted from your Amazon S3 bucket.</p> <note> <p>You cannot delete an application that has a running environment.</p> </note></p>
     fn delete_application(
         &self,
         input: DeleteApplicationMessage,
     ) -> RusotoFuture<(), DeleteApplicationError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteApplication");
-        params.put("Version", "2010-12-01");
-        DeleteApplicationMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteApplicationError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_application(input)
     }
-
-    /// <p><p>Deletes the specified version from the specified application.</p> <note> <p>You cannot delete an application version that is associated with a running environment.</p> </note></p>
     fn delete_application_version(
         &self,
         input: DeleteApplicationVersionMessage,
     ) -> RusotoFuture<(), DeleteApplicationVersionError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteApplicationVersion");
-        params.put("Version", "2010-12-01");
-        DeleteApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteApplicationVersionError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_application_version(input)
     }
-
-    /// <p><p>Deletes the specified configuration template.</p> <note> <p>When you launch an environment using a configuration template, the environment gets a copy of the template. You can delete or modify the environment&#39;s copy of the template without affecting the running environment.</p> </note></p>
     fn delete_configuration_template(
         &self,
         input: DeleteConfigurationTemplateMessage,
     ) -> RusotoFuture<(), DeleteConfigurationTemplateError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteConfigurationTemplate");
-        params.put("Version", "2010-12-01");
-        DeleteConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteConfigurationTemplateError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_configuration_template(input)
     }
-
-    /// <p>Deletes the draft configuration associated with the running environment.</p> <p>Updating a running environment with any configuration changes creates a draft configuration set. You can get the draft configuration using <a>DescribeConfigurationSettings</a> while the update is in progress or if the update fails. The <code>DeploymentStatus</code> for the draft configuration indicates whether the deployment is in process or has failed. The draft configuration remains in existence until it is deleted with this action.</p>
     fn delete_environment_configuration(
         &self,
         input: DeleteEnvironmentConfigurationMessage,
     ) -> RusotoFuture<(), DeleteEnvironmentConfigurationError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteEnvironmentConfiguration");
-        params.put("Version", "2010-12-01");
-        DeleteEnvironmentConfigurationMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteEnvironmentConfigurationError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_environment_configuration(input)
     }
-
-    /// <p>Deletes the specified version of a custom platform.</p>
     fn delete_platform_version(
         &self,
         input: DeletePlatformVersionRequest,
     ) -> RusotoFuture<DeletePlatformVersionResult, DeletePlatformVersionError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeletePlatformVersion");
-        params.put("Version", "2010-12-01");
-        DeletePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeletePlatformVersionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeletePlatformVersionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeletePlatformVersionResultDeserializer::deserialize(
-                        "DeletePlatformVersionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_platform_version(input)
     }
-
-    /// <p>Returns attributes related to AWS Elastic Beanstalk that are associated with the calling AWS account.</p> <p>The result currently has one set of attributes—resource quotas.</p>
     fn describe_account_attributes(
         &self,
     ) -> RusotoFuture<DescribeAccountAttributesResult, DescribeAccountAttributesError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeAccountAttributes");
-        params.put("Version", "2010-12-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAccountAttributesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeAccountAttributesResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeAccountAttributesResultDeserializer::deserialize(
-                        "DescribeAccountAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_account_attributes()
     }
-
-    /// <p>Retrieve a list of application versions.</p>
     fn describe_application_versions(
         &self,
         input: DescribeApplicationVersionsMessage,
     ) -> RusotoFuture<ApplicationVersionDescriptionsMessage, DescribeApplicationVersionsError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeApplicationVersions");
-        params.put("Version", "2010-12-01");
-        DescribeApplicationVersionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeApplicationVersionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ApplicationVersionDescriptionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ApplicationVersionDescriptionsMessageDeserializer::deserialize(
-                        "DescribeApplicationVersionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_application_versions(input)
     }
-
-    /// <p>Returns the descriptions of existing applications.</p>
     fn describe_applications(
         &self,
         input: DescribeApplicationsMessage,
     ) -> RusotoFuture<ApplicationDescriptionsMessage, DescribeApplicationsError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeApplications");
-        params.put("Version", "2010-12-01");
-        DescribeApplicationsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeApplicationsError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ApplicationDescriptionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ApplicationDescriptionsMessageDeserializer::deserialize(
-                        "DescribeApplicationsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_applications(input)
     }
-
-    /// <p>Describes the configuration options that are used in a particular configuration template or environment, or that a specified solution stack defines. The description includes the values the options, their default values, and an indication of the required action on a running environment if an option value is changed.</p>
     fn describe_configuration_options(
         &self,
         input: DescribeConfigurationOptionsMessage,
     ) -> RusotoFuture<ConfigurationOptionsDescription, DescribeConfigurationOptionsError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeConfigurationOptions");
-        params.put("Version", "2010-12-01");
-        DescribeConfigurationOptionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeConfigurationOptionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ConfigurationOptionsDescription::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ConfigurationOptionsDescriptionDeserializer::deserialize(
-                        "DescribeConfigurationOptionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_configuration_options(input)
     }
-
-    /// <p><p>Returns a description of the settings for the specified configuration set, that is, either a configuration template or the configuration set associated with a running environment.</p> <p>When describing the settings for the configuration set associated with a running environment, it is possible to receive two sets of setting descriptions. One is the deployed configuration set, and the other is a draft configuration of an environment that is either in the process of deployment or that failed to deploy.</p> <p>Related Topics</p> <ul> <li> <p> <a>DeleteEnvironmentConfiguration</a> </p> </li> </ul></p>
     fn describe_configuration_settings(
         &self,
         input: DescribeConfigurationSettingsMessage,
     ) -> RusotoFuture<ConfigurationSettingsDescriptions, DescribeConfigurationSettingsError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeConfigurationSettings");
-        params.put("Version", "2010-12-01");
-        DescribeConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeConfigurationSettingsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ConfigurationSettingsDescriptions::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ConfigurationSettingsDescriptionsDeserializer::deserialize(
-                        "DescribeConfigurationSettingsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_configuration_settings(input)
     }
-
-    /// <p>Returns information about the overall health of the specified environment. The <b>DescribeEnvironmentHealth</b> operation is only available with AWS Elastic Beanstalk Enhanced Health.</p>
     fn describe_environment_health(
         &self,
         input: DescribeEnvironmentHealthRequest,
     ) -> RusotoFuture<DescribeEnvironmentHealthResult, DescribeEnvironmentHealthError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEnvironmentHealth");
-        params.put("Version", "2010-12-01");
-        DescribeEnvironmentHealthRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEnvironmentHealthError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeEnvironmentHealthResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeEnvironmentHealthResultDeserializer::deserialize(
-                        "DescribeEnvironmentHealthResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_environment_health(input)
     }
-
-    /// <p>Lists an environment's completed and failed managed actions.</p>
     fn describe_environment_managed_action_history(
         &self,
         input: DescribeEnvironmentManagedActionHistoryRequest,
@@ -10858,747 +11260,3123 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         DescribeEnvironmentManagedActionHistoryResult,
         DescribeEnvironmentManagedActionHistoryError,
     > {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEnvironmentManagedActionHistory");
-        params.put("Version", "2010-12-01");
-        DescribeEnvironmentManagedActionHistoryRequestSerializer::serialize(
-            &mut params,
-            "",
-            &input,
-        );
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEnvironmentManagedActionHistoryError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeEnvironmentManagedActionHistoryResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        DescribeEnvironmentManagedActionHistoryResultDeserializer::deserialize(
-                            "DescribeEnvironmentManagedActionHistoryResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_environment_managed_action_history(input)
     }
-
-    /// <p>Lists an environment's upcoming and in-progress managed actions.</p>
     fn describe_environment_managed_actions(
         &self,
         input: DescribeEnvironmentManagedActionsRequest,
     ) -> RusotoFuture<DescribeEnvironmentManagedActionsResult, DescribeEnvironmentManagedActionsError>
     {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEnvironmentManagedActions");
-        params.put("Version", "2010-12-01");
-        DescribeEnvironmentManagedActionsRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEnvironmentManagedActionsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeEnvironmentManagedActionsResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeEnvironmentManagedActionsResultDeserializer::deserialize(
-                        "DescribeEnvironmentManagedActionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_environment_managed_actions(input)
     }
-
-    /// <p>Returns AWS resources for this environment.</p>
     fn describe_environment_resources(
         &self,
         input: DescribeEnvironmentResourcesMessage,
     ) -> RusotoFuture<EnvironmentResourceDescriptionsMessage, DescribeEnvironmentResourcesError>
     {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEnvironmentResources");
-        params.put("Version", "2010-12-01");
-        DescribeEnvironmentResourcesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEnvironmentResourcesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EnvironmentResourceDescriptionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EnvironmentResourceDescriptionsMessageDeserializer::deserialize(
-                        "DescribeEnvironmentResourcesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_environment_resources(input)
     }
-
-    /// <p>Returns descriptions for existing environments.</p>
     fn describe_environments(
         &self,
         input: DescribeEnvironmentsMessage,
     ) -> RusotoFuture<EnvironmentDescriptionsMessage, DescribeEnvironmentsError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEnvironments");
-        params.put("Version", "2010-12-01");
-        DescribeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeEnvironmentsError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EnvironmentDescriptionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EnvironmentDescriptionsMessageDeserializer::deserialize(
-                        "DescribeEnvironmentsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_environments(input)
     }
-
-    /// <p><p>Returns list of event descriptions matching criteria up to the last 6 weeks.</p> <note> <p>This action returns the most recent 1,000 events from the specified <code>NextToken</code>.</p> </note></p>
     fn describe_events(
         &self,
         input: DescribeEventsMessage,
     ) -> RusotoFuture<EventDescriptionsMessage, DescribeEventsError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEvents");
-        params.put("Version", "2010-12-01");
-        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeEventsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EventDescriptionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EventDescriptionsMessageDeserializer::deserialize(
-                        "DescribeEventsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_events(input)
     }
-
-    /// <p>Retrieves detailed information about the health of instances in your AWS Elastic Beanstalk. This operation requires <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/health-enhanced.html">enhanced health reporting</a>.</p>
     fn describe_instances_health(
         &self,
         input: DescribeInstancesHealthRequest,
     ) -> RusotoFuture<DescribeInstancesHealthResult, DescribeInstancesHealthError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeInstancesHealth");
-        params.put("Version", "2010-12-01");
-        DescribeInstancesHealthRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeInstancesHealthError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeInstancesHealthResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeInstancesHealthResultDeserializer::deserialize(
-                        "DescribeInstancesHealthResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_instances_health(input)
     }
-
-    /// <p>Describes the version of the platform.</p>
     fn describe_platform_version(
         &self,
         input: DescribePlatformVersionRequest,
     ) -> RusotoFuture<DescribePlatformVersionResult, DescribePlatformVersionError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribePlatformVersion");
-        params.put("Version", "2010-12-01");
-        DescribePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribePlatformVersionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribePlatformVersionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribePlatformVersionResultDeserializer::deserialize(
-                        "DescribePlatformVersionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_platform_version(input)
     }
-
-    /// <p>Returns a list of the available solution stack names, with the public version first and then in reverse chronological order.</p>
     fn list_available_solution_stacks(
         &self,
     ) -> RusotoFuture<ListAvailableSolutionStacksResultMessage, ListAvailableSolutionStacksError>
     {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListAvailableSolutionStacks");
-        params.put("Version", "2010-12-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListAvailableSolutionStacksError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListAvailableSolutionStacksResultMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListAvailableSolutionStacksResultMessageDeserializer::deserialize(
-                        "ListAvailableSolutionStacksResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_available_solution_stacks()
     }
-
-    /// <p>Lists the available platforms.</p>
     fn list_platform_versions(
         &self,
         input: ListPlatformVersionsRequest,
     ) -> RusotoFuture<ListPlatformVersionsResult, ListPlatformVersionsError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListPlatformVersions");
-        params.put("Version", "2010-12-01");
-        ListPlatformVersionsRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListPlatformVersionsError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListPlatformVersionsResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListPlatformVersionsResultDeserializer::deserialize(
-                        "ListPlatformVersionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_platform_versions(input)
     }
-
-    /// <p>Returns the tags applied to an AWS Elastic Beanstalk resource. The response contains a list of tag key-value pairs.</p> <p>Currently, Elastic Beanstalk only supports tagging of Elastic Beanstalk environments. For details about environment tagging, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/using-features.tagging.html">Tagging Resources in Your Elastic Beanstalk Environment</a>.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceMessage,
     ) -> RusotoFuture<ResourceTagsDescriptionMessage, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListTagsForResource");
-        params.put("Version", "2010-12-01");
-        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ResourceTagsDescriptionMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ResourceTagsDescriptionMessageDeserializer::deserialize(
-                        "ListTagsForResourceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Deletes and recreates all of the AWS resources (for example: the Auto Scaling group, load balancer, etc.) for a specified environment and forces a restart.</p>
     fn rebuild_environment(
         &self,
         input: RebuildEnvironmentMessage,
     ) -> RusotoFuture<(), RebuildEnvironmentError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RebuildEnvironment");
-        params.put("Version", "2010-12-01");
-        RebuildEnvironmentMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RebuildEnvironmentError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).rebuild_environment(input)
     }
-
-    /// <p><p>Initiates a request to compile the specified type of information of the deployed environment.</p> <p> Setting the <code>InfoType</code> to <code>tail</code> compiles the last lines from the application server log files of every Amazon EC2 instance in your environment. </p> <p> Setting the <code>InfoType</code> to <code>bundle</code> compresses the application server log files for every Amazon EC2 instance into a <code>.zip</code> file. Legacy and .NET containers do not support bundle logs. </p> <p> Use <a>RetrieveEnvironmentInfo</a> to obtain the set of logs. </p> <p>Related Topics</p> <ul> <li> <p> <a>RetrieveEnvironmentInfo</a> </p> </li> </ul></p>
     fn request_environment_info(
         &self,
         input: RequestEnvironmentInfoMessage,
     ) -> RusotoFuture<(), RequestEnvironmentInfoError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RequestEnvironmentInfo");
-        params.put("Version", "2010-12-01");
-        RequestEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RequestEnvironmentInfoError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).request_environment_info(input)
     }
-
-    /// <p>Causes the environment to restart the application container server running on each Amazon EC2 instance.</p>
     fn restart_app_server(
         &self,
         input: RestartAppServerMessage,
     ) -> RusotoFuture<(), RestartAppServerError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RestartAppServer");
-        params.put("Version", "2010-12-01");
-        RestartAppServerMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RestartAppServerError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).restart_app_server(input)
     }
-
-    /// <p><p>Retrieves the compiled information from a <a>RequestEnvironmentInfo</a> request.</p> <p>Related Topics</p> <ul> <li> <p> <a>RequestEnvironmentInfo</a> </p> </li> </ul></p>
     fn retrieve_environment_info(
         &self,
         input: RetrieveEnvironmentInfoMessage,
     ) -> RusotoFuture<RetrieveEnvironmentInfoResultMessage, RetrieveEnvironmentInfoError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RetrieveEnvironmentInfo");
-        params.put("Version", "2010-12-01");
-        RetrieveEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RetrieveEnvironmentInfoError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RetrieveEnvironmentInfoResultMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RetrieveEnvironmentInfoResultMessageDeserializer::deserialize(
-                        "RetrieveEnvironmentInfoResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).retrieve_environment_info(input)
     }
-
-    /// <p>Swaps the CNAMEs of two environments.</p>
     fn swap_environment_cnam_es(
         &self,
         input: SwapEnvironmentCNAMEsMessage,
     ) -> RusotoFuture<(), SwapEnvironmentCNAMEsError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SwapEnvironmentCNAMEs");
-        params.put("Version", "2010-12-01");
-        SwapEnvironmentCNAMEsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SwapEnvironmentCNAMEsError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).swap_environment_cnam_es(input)
     }
-
-    /// <p>Terminates the specified environment.</p>
     fn terminate_environment(
         &self,
         input: TerminateEnvironmentMessage,
     ) -> RusotoFuture<EnvironmentDescription, TerminateEnvironmentError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "TerminateEnvironment");
-        params.put("Version", "2010-12-01");
-        TerminateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(TerminateEnvironmentError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EnvironmentDescription::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EnvironmentDescriptionDeserializer::deserialize(
-                        "TerminateEnvironmentResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).terminate_environment(input)
     }
-
-    /// <p><p>Updates the specified application to have the specified properties.</p> <note> <p>If a property (for example, <code>description</code>) is not provided, the value remains unchanged. To clear these properties, specify an empty string.</p> </note></p>
     fn update_application(
         &self,
         input: UpdateApplicationMessage,
     ) -> RusotoFuture<ApplicationDescriptionMessage, UpdateApplicationError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateApplication");
-        params.put("Version", "2010-12-01");
-        UpdateApplicationMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateApplicationError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ApplicationDescriptionMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ApplicationDescriptionMessageDeserializer::deserialize(
-                        "UpdateApplicationResult",
-                        &mut stack,
-                    )?;
+        (**self).update_application(input)
+    }
+    fn update_application_resource_lifecycle(
+        &self,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> RusotoFuture<
+        ApplicationResourceLifecycleDescriptionMessage,
+        UpdateApplicationResourceLifecycleError,
+    > {
+        (**self).update_application_resource_lifecycle(input)
+    }
+    fn update_application_version(
+        &self,
+        input: UpdateApplicationVersionMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionMessage, UpdateApplicationVersionError> {
+        (**self).update_application_version(input)
+    }
+    fn update_configuration_template(
+        &self,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescription, UpdateConfigurationTemplateError> {
+        (**self).update_configuration_template(input)
+    }
+    fn update_environment(
+        &self,
+        input: UpdateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, UpdateEnvironmentError> {
+        (**self).update_environment(input)
+    }
+    fn update_tags_for_resource(
+        &self,
+        input: UpdateTagsForResourceMessage,
+    ) -> RusotoFuture<(), UpdateTagsForResourceError> {
+        (**self).update_tags_for_resource(input)
+    }
+    fn validate_configuration_settings(
+        &self,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> RusotoFuture<ConfigurationSettingsValidationMessages, ValidateConfigurationSettingsError>
+    {
+        (**self).validate_configuration_settings(input)
+    }
+}
+impl<T: ElasticBeanstalk + ?Sized> ElasticBeanstalk for Box<T> {
+    fn abort_environment_update(
+        &self,
+        input: AbortEnvironmentUpdateMessage,
+    ) -> RusotoFuture<(), AbortEnvironmentUpdateError> {
+        (**self).abort_environment_update(input)
+    }
+    fn apply_environment_managed_action(
+        &self,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> RusotoFuture<ApplyEnvironmentManagedActionResult, ApplyEnvironmentManagedActionError> {
+        (**self).apply_environment_managed_action(input)
+    }
+    fn check_dns_availability(
+        &self,
+        input: CheckDNSAvailabilityMessage,
+    ) -> RusotoFuture<CheckDNSAvailabilityResultMessage, CheckDNSAvailabilityError> {
+        (**self).check_dns_availability(input)
+    }
+    fn compose_environments(
+        &self,
+        input: ComposeEnvironmentsMessage,
+    ) -> RusotoFuture<EnvironmentDescriptionsMessage, ComposeEnvironmentsError> {
+        (**self).compose_environments(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationMessage,
+    ) -> RusotoFuture<ApplicationDescriptionMessage, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_application_version(
+        &self,
+        input: CreateApplicationVersionMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionMessage, CreateApplicationVersionError> {
+        (**self).create_application_version(input)
+    }
+    fn create_configuration_template(
+        &self,
+        input: CreateConfigurationTemplateMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescription, CreateConfigurationTemplateError> {
+        (**self).create_configuration_template(input)
+    }
+    fn create_environment(
+        &self,
+        input: CreateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, CreateEnvironmentError> {
+        (**self).create_environment(input)
+    }
+    fn create_platform_version(
+        &self,
+        input: CreatePlatformVersionRequest,
+    ) -> RusotoFuture<CreatePlatformVersionResult, CreatePlatformVersionError> {
+        (**self).create_platform_version(input)
+    }
+    fn create_storage_location(
+        &self,
+    ) -> RusotoFuture<CreateStorageLocationResultMessage, CreateStorageLocationError> {
+        (**self).create_storage_location()
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationMessage,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_application_version(
+        &self,
+        input: DeleteApplicationVersionMessage,
+    ) -> RusotoFuture<(), DeleteApplicationVersionError> {
+        (**self).delete_application_version(input)
+    }
+    fn delete_configuration_template(
+        &self,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> RusotoFuture<(), DeleteConfigurationTemplateError> {
+        (**self).delete_configuration_template(input)
+    }
+    fn delete_environment_configuration(
+        &self,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> RusotoFuture<(), DeleteEnvironmentConfigurationError> {
+        (**self).delete_environment_configuration(input)
+    }
+    fn delete_platform_version(
+        &self,
+        input: DeletePlatformVersionRequest,
+    ) -> RusotoFuture<DeletePlatformVersionResult, DeletePlatformVersionError> {
+        (**self).delete_platform_version(input)
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResult, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes()
+    }
+    fn describe_application_versions(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionsMessage, DescribeApplicationVersionsError> {
+        (**self).describe_application_versions(input)
+    }
+    fn describe_applications(
+        &self,
+        input: DescribeApplicationsMessage,
+    ) -> RusotoFuture<ApplicationDescriptionsMessage, DescribeApplicationsError> {
+        (**self).describe_applications(input)
+    }
+    fn describe_configuration_options(
+        &self,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> RusotoFuture<ConfigurationOptionsDescription, DescribeConfigurationOptionsError> {
+        (**self).describe_configuration_options(input)
+    }
+    fn describe_configuration_settings(
+        &self,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescriptions, DescribeConfigurationSettingsError> {
+        (**self).describe_configuration_settings(input)
+    }
+    fn describe_environment_health(
+        &self,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> RusotoFuture<DescribeEnvironmentHealthResult, DescribeEnvironmentHealthError> {
+        (**self).describe_environment_health(input)
+    }
+    fn describe_environment_managed_action_history(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> RusotoFuture<
+        DescribeEnvironmentManagedActionHistoryResult,
+        DescribeEnvironmentManagedActionHistoryError,
+    > {
+        (**self).describe_environment_managed_action_history(input)
+    }
+    fn describe_environment_managed_actions(
+        &self,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentManagedActionsResult, DescribeEnvironmentManagedActionsError>
+    {
+        (**self).describe_environment_managed_actions(input)
+    }
+    fn describe_environment_resources(
+        &self,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> RusotoFuture<EnvironmentResourceDescriptionsMessage, DescribeEnvironmentResourcesError>
+    {
+        (**self).describe_environment_resources(input)
+    }
+    fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> RusotoFuture<EnvironmentDescriptionsMessage, DescribeEnvironmentsError> {
+        (**self).describe_environments(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventDescriptionsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_instances_health(
+        &self,
+        input: DescribeInstancesHealthRequest,
+    ) -> RusotoFuture<DescribeInstancesHealthResult, DescribeInstancesHealthError> {
+        (**self).describe_instances_health(input)
+    }
+    fn describe_platform_version(
+        &self,
+        input: DescribePlatformVersionRequest,
+    ) -> RusotoFuture<DescribePlatformVersionResult, DescribePlatformVersionError> {
+        (**self).describe_platform_version(input)
+    }
+    fn list_available_solution_stacks(
+        &self,
+    ) -> RusotoFuture<ListAvailableSolutionStacksResultMessage, ListAvailableSolutionStacksError>
+    {
+        (**self).list_available_solution_stacks()
+    }
+    fn list_platform_versions(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> RusotoFuture<ListPlatformVersionsResult, ListPlatformVersionsError> {
+        (**self).list_platform_versions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<ResourceTagsDescriptionMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn rebuild_environment(
+        &self,
+        input: RebuildEnvironmentMessage,
+    ) -> RusotoFuture<(), RebuildEnvironmentError> {
+        (**self).rebuild_environment(input)
+    }
+    fn request_environment_info(
+        &self,
+        input: RequestEnvironmentInfoMessage,
+    ) -> RusotoFuture<(), RequestEnvironmentInfoError> {
+        (**self).request_environment_info(input)
+    }
+    fn restart_app_server(
+        &self,
+        input: RestartAppServerMessage,
+    ) -> RusotoFuture<(), RestartAppServerError> {
+        (**self).restart_app_server(input)
+    }
+    fn retrieve_environment_info(
+        &self,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> RusotoFuture<RetrieveEnvironmentInfoResultMessage, RetrieveEnvironmentInfoError> {
+        (**self).retrieve_environment_info(input)
+    }
+    fn swap_environment_cnam_es(
+        &self,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> RusotoFuture<(), SwapEnvironmentCNAMEsError> {
+        (**self).swap_environment_cnam_es(input)
+    }
+    fn terminate_environment(
+        &self,
+        input: TerminateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, TerminateEnvironmentError> {
+        (**self).terminate_environment(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationMessage,
+    ) -> RusotoFuture<ApplicationDescriptionMessage, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+    fn update_application_resource_lifecycle(
+        &self,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> RusotoFuture<
+        ApplicationResourceLifecycleDescriptionMessage,
+        UpdateApplicationResourceLifecycleError,
+    > {
+        (**self).update_application_resource_lifecycle(input)
+    }
+    fn update_application_version(
+        &self,
+        input: UpdateApplicationVersionMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionMessage, UpdateApplicationVersionError> {
+        (**self).update_application_version(input)
+    }
+    fn update_configuration_template(
+        &self,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescription, UpdateConfigurationTemplateError> {
+        (**self).update_configuration_template(input)
+    }
+    fn update_environment(
+        &self,
+        input: UpdateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, UpdateEnvironmentError> {
+        (**self).update_environment(input)
+    }
+    fn update_tags_for_resource(
+        &self,
+        input: UpdateTagsForResourceMessage,
+    ) -> RusotoFuture<(), UpdateTagsForResourceError> {
+        (**self).update_tags_for_resource(input)
+    }
+    fn validate_configuration_settings(
+        &self,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> RusotoFuture<ConfigurationSettingsValidationMessages, ValidateConfigurationSettingsError>
+    {
+        (**self).validate_configuration_settings(input)
+    }
+}
+impl<T: ElasticBeanstalk + ?Sized> ElasticBeanstalk for ::std::sync::Arc<T> {
+    fn abort_environment_update(
+        &self,
+        input: AbortEnvironmentUpdateMessage,
+    ) -> RusotoFuture<(), AbortEnvironmentUpdateError> {
+        (**self).abort_environment_update(input)
+    }
+    fn apply_environment_managed_action(
+        &self,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> RusotoFuture<ApplyEnvironmentManagedActionResult, ApplyEnvironmentManagedActionError> {
+        (**self).apply_environment_managed_action(input)
+    }
+    fn check_dns_availability(
+        &self,
+        input: CheckDNSAvailabilityMessage,
+    ) -> RusotoFuture<CheckDNSAvailabilityResultMessage, CheckDNSAvailabilityError> {
+        (**self).check_dns_availability(input)
+    }
+    fn compose_environments(
+        &self,
+        input: ComposeEnvironmentsMessage,
+    ) -> RusotoFuture<EnvironmentDescriptionsMessage, ComposeEnvironmentsError> {
+        (**self).compose_environments(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationMessage,
+    ) -> RusotoFuture<ApplicationDescriptionMessage, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_application_version(
+        &self,
+        input: CreateApplicationVersionMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionMessage, CreateApplicationVersionError> {
+        (**self).create_application_version(input)
+    }
+    fn create_configuration_template(
+        &self,
+        input: CreateConfigurationTemplateMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescription, CreateConfigurationTemplateError> {
+        (**self).create_configuration_template(input)
+    }
+    fn create_environment(
+        &self,
+        input: CreateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, CreateEnvironmentError> {
+        (**self).create_environment(input)
+    }
+    fn create_platform_version(
+        &self,
+        input: CreatePlatformVersionRequest,
+    ) -> RusotoFuture<CreatePlatformVersionResult, CreatePlatformVersionError> {
+        (**self).create_platform_version(input)
+    }
+    fn create_storage_location(
+        &self,
+    ) -> RusotoFuture<CreateStorageLocationResultMessage, CreateStorageLocationError> {
+        (**self).create_storage_location()
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationMessage,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_application_version(
+        &self,
+        input: DeleteApplicationVersionMessage,
+    ) -> RusotoFuture<(), DeleteApplicationVersionError> {
+        (**self).delete_application_version(input)
+    }
+    fn delete_configuration_template(
+        &self,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> RusotoFuture<(), DeleteConfigurationTemplateError> {
+        (**self).delete_configuration_template(input)
+    }
+    fn delete_environment_configuration(
+        &self,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> RusotoFuture<(), DeleteEnvironmentConfigurationError> {
+        (**self).delete_environment_configuration(input)
+    }
+    fn delete_platform_version(
+        &self,
+        input: DeletePlatformVersionRequest,
+    ) -> RusotoFuture<DeletePlatformVersionResult, DeletePlatformVersionError> {
+        (**self).delete_platform_version(input)
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResult, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes()
+    }
+    fn describe_application_versions(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionsMessage, DescribeApplicationVersionsError> {
+        (**self).describe_application_versions(input)
+    }
+    fn describe_applications(
+        &self,
+        input: DescribeApplicationsMessage,
+    ) -> RusotoFuture<ApplicationDescriptionsMessage, DescribeApplicationsError> {
+        (**self).describe_applications(input)
+    }
+    fn describe_configuration_options(
+        &self,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> RusotoFuture<ConfigurationOptionsDescription, DescribeConfigurationOptionsError> {
+        (**self).describe_configuration_options(input)
+    }
+    fn describe_configuration_settings(
+        &self,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescriptions, DescribeConfigurationSettingsError> {
+        (**self).describe_configuration_settings(input)
+    }
+    fn describe_environment_health(
+        &self,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> RusotoFuture<DescribeEnvironmentHealthResult, DescribeEnvironmentHealthError> {
+        (**self).describe_environment_health(input)
+    }
+    fn describe_environment_managed_action_history(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> RusotoFuture<
+        DescribeEnvironmentManagedActionHistoryResult,
+        DescribeEnvironmentManagedActionHistoryError,
+    > {
+        (**self).describe_environment_managed_action_history(input)
+    }
+    fn describe_environment_managed_actions(
+        &self,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentManagedActionsResult, DescribeEnvironmentManagedActionsError>
+    {
+        (**self).describe_environment_managed_actions(input)
+    }
+    fn describe_environment_resources(
+        &self,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> RusotoFuture<EnvironmentResourceDescriptionsMessage, DescribeEnvironmentResourcesError>
+    {
+        (**self).describe_environment_resources(input)
+    }
+    fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> RusotoFuture<EnvironmentDescriptionsMessage, DescribeEnvironmentsError> {
+        (**self).describe_environments(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventDescriptionsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_instances_health(
+        &self,
+        input: DescribeInstancesHealthRequest,
+    ) -> RusotoFuture<DescribeInstancesHealthResult, DescribeInstancesHealthError> {
+        (**self).describe_instances_health(input)
+    }
+    fn describe_platform_version(
+        &self,
+        input: DescribePlatformVersionRequest,
+    ) -> RusotoFuture<DescribePlatformVersionResult, DescribePlatformVersionError> {
+        (**self).describe_platform_version(input)
+    }
+    fn list_available_solution_stacks(
+        &self,
+    ) -> RusotoFuture<ListAvailableSolutionStacksResultMessage, ListAvailableSolutionStacksError>
+    {
+        (**self).list_available_solution_stacks()
+    }
+    fn list_platform_versions(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> RusotoFuture<ListPlatformVersionsResult, ListPlatformVersionsError> {
+        (**self).list_platform_versions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<ResourceTagsDescriptionMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn rebuild_environment(
+        &self,
+        input: RebuildEnvironmentMessage,
+    ) -> RusotoFuture<(), RebuildEnvironmentError> {
+        (**self).rebuild_environment(input)
+    }
+    fn request_environment_info(
+        &self,
+        input: RequestEnvironmentInfoMessage,
+    ) -> RusotoFuture<(), RequestEnvironmentInfoError> {
+        (**self).request_environment_info(input)
+    }
+    fn restart_app_server(
+        &self,
+        input: RestartAppServerMessage,
+    ) -> RusotoFuture<(), RestartAppServerError> {
+        (**self).restart_app_server(input)
+    }
+    fn retrieve_environment_info(
+        &self,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> RusotoFuture<RetrieveEnvironmentInfoResultMessage, RetrieveEnvironmentInfoError> {
+        (**self).retrieve_environment_info(input)
+    }
+    fn swap_environment_cnam_es(
+        &self,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> RusotoFuture<(), SwapEnvironmentCNAMEsError> {
+        (**self).swap_environment_cnam_es(input)
+    }
+    fn terminate_environment(
+        &self,
+        input: TerminateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, TerminateEnvironmentError> {
+        (**self).terminate_environment(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationMessage,
+    ) -> RusotoFuture<ApplicationDescriptionMessage, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+    fn update_application_resource_lifecycle(
+        &self,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> RusotoFuture<
+        ApplicationResourceLifecycleDescriptionMessage,
+        UpdateApplicationResourceLifecycleError,
+    > {
+        (**self).update_application_resource_lifecycle(input)
+    }
+    fn update_application_version(
+        &self,
+        input: UpdateApplicationVersionMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionMessage, UpdateApplicationVersionError> {
+        (**self).update_application_version(input)
+    }
+    fn update_configuration_template(
+        &self,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescription, UpdateConfigurationTemplateError> {
+        (**self).update_configuration_template(input)
+    }
+    fn update_environment(
+        &self,
+        input: UpdateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, UpdateEnvironmentError> {
+        (**self).update_environment(input)
+    }
+    fn update_tags_for_resource(
+        &self,
+        input: UpdateTagsForResourceMessage,
+    ) -> RusotoFuture<(), UpdateTagsForResourceError> {
+        (**self).update_tags_for_resource(input)
+    }
+    fn validate_configuration_settings(
+        &self,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> RusotoFuture<ConfigurationSettingsValidationMessages, ValidateConfigurationSettingsError>
+    {
+        (**self).validate_configuration_settings(input)
+    }
+}
+/// A client for the Elastic Beanstalk API.
+#[derive(Clone)]
+pub struct ElasticBeanstalkClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ElasticBeanstalkClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ElasticBeanstalkClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElasticBeanstalkClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ElasticBeanstalkClient {
+        ElasticBeanstalkClient { client, region }
+    }
+}
+
+impl ElasticBeanstalk for ElasticBeanstalkClient {
+    /// <p>Cancels in-progress environment configuration update or application version deployment.</p>
+    fn abort_environment_update(
+        &self,
+        input: AbortEnvironmentUpdateMessage,
+    ) -> RusotoFuture<(), AbortEnvironmentUpdateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("AbortEnvironmentUpdate");
+        let mut params = Params::new();
+
+        params.put("Action", "AbortEnvironmentUpdate");
+        params.put("Version", "2010-12-01");
+        AbortEnvironmentUpdateMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AbortEnvironmentUpdateError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Applies a scheduled managed action immediately. A managed action can be applied only if its status is <code>Scheduled</code>. Get the status and action ID of a managed action with <a>DescribeEnvironmentManagedActions</a>.</p>
+    fn apply_environment_managed_action(
+        &self,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> RusotoFuture<ApplyEnvironmentManagedActionResult, ApplyEnvironmentManagedActionError> {
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ApplyEnvironmentManagedAction");
+        let mut params = Params::new();
+
+        params.put("Action", "ApplyEnvironmentManagedAction");
+        params.put("Version", "2010-12-01");
+        ApplyEnvironmentManagedActionRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ApplyEnvironmentManagedActionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ApplyEnvironmentManagedActionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ApplyEnvironmentManagedActionResultDeserializer::deserialize(
+                        "ApplyEnvironmentManagedActionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ApplyEnvironmentManagedAction", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Checks if the specified CNAME is available.</p>
+    fn check_dns_availability(
+        &self,
+        input: CheckDNSAvailabilityMessage,
+    ) -> RusotoFuture<CheckDNSAvailabilityResultMessage, CheckDNSAvailabilityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CheckDNSAvailability");
+        let mut params = Params::new();
+
+        params.put("Action", "CheckDNSAvailability");
+        params.put("Version", "2010-12-01");
+        CheckDNSAvailabilityMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CheckDNSAvailabilityError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CheckDNSAvailabilityResultMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CheckDNSAvailabilityResultMessageDeserializer::deserialize(
+                        "CheckDNSAvailabilityResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CheckDNSAvailability", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Create or update a group of environments that each run a separate component of a single application. Takes a list of version labels that specify application source bundles for each of the environments to create or update. The name of each environment and other required information must be included in the source bundles in an environment manifest named <code>env.yaml</code>. See <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/environment-mgmt-compose.html">Compose Environments</a> for details.</p>
+    fn compose_environments(
+        &self,
+        input: ComposeEnvironmentsMessage,
+    ) -> RusotoFuture<EnvironmentDescriptionsMessage, ComposeEnvironmentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ComposeEnvironments");
+        let mut params = Params::new();
+
+        params.put("Action", "ComposeEnvironments");
+        params.put("Version", "2010-12-01");
+        ComposeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ComposeEnvironmentsError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EnvironmentDescriptionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EnvironmentDescriptionsMessageDeserializer::deserialize(
+                        "ComposeEnvironmentsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ComposeEnvironments", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p> Creates an application that has one configuration template named <code>default</code> and no application versions. </p>
+    fn create_application(
+        &self,
+        input: CreateApplicationMessage,
+    ) -> RusotoFuture<ApplicationDescriptionMessage, CreateApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateApplication");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateApplication");
+        params.put("Version", "2010-12-01");
+        CreateApplicationMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateApplicationError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ApplicationDescriptionMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ApplicationDescriptionMessageDeserializer::deserialize(
+                        "CreateApplicationResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateApplication", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Creates an application version for the specified application. You can create an application version from a source bundle in Amazon S3, a commit in AWS CodeCommit, or the output of an AWS CodeBuild build as follows:</p> <p>Specify a commit in an AWS CodeCommit repository with <code>SourceBuildInformation</code>.</p> <p>Specify a build in an AWS CodeBuild with <code>SourceBuildInformation</code> and <code>BuildConfiguration</code>.</p> <p>Specify a source bundle in S3 with <code>SourceBundle</code> </p> <p>Omit both <code>SourceBuildInformation</code> and <code>SourceBundle</code> to use the default sample application.</p> <note> <p>Once you create an application version with a specified Amazon S3 bucket and key location, you cannot change that Amazon S3 location. If you change the Amazon S3 location, you receive an exception when you attempt to launch an environment from the application version.</p> </note></p>
+    fn create_application_version(
+        &self,
+        input: CreateApplicationVersionMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionMessage, CreateApplicationVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateApplicationVersion");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateApplicationVersion");
+        params.put("Version", "2010-12-01");
+        CreateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateApplicationVersionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ApplicationVersionDescriptionMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ApplicationVersionDescriptionMessageDeserializer::deserialize(
+                        "CreateApplicationVersionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateApplicationVersion", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Creates a configuration template. Templates are associated with a specific application and are used to deploy different versions of the application with the same configuration settings.</p> <p>Templates aren&#39;t associated with any environment. The <code>EnvironmentName</code> response element is always <code>null</code>.</p> <p>Related Topics</p> <ul> <li> <p> <a>DescribeConfigurationOptions</a> </p> </li> <li> <p> <a>DescribeConfigurationSettings</a> </p> </li> <li> <p> <a>ListAvailableSolutionStacks</a> </p> </li> </ul></p>
+    fn create_configuration_template(
+        &self,
+        input: CreateConfigurationTemplateMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescription, CreateConfigurationTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateConfigurationTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateConfigurationTemplate");
+        params.put("Version", "2010-12-01");
+        CreateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateConfigurationTemplateError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ConfigurationSettingsDescription::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ConfigurationSettingsDescriptionDeserializer::deserialize(
+                        "CreateConfigurationTemplateResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateConfigurationTemplate", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Launches an environment for the specified application using the specified configuration.</p>
+    fn create_environment(
+        &self,
+        input: CreateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, CreateEnvironmentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateEnvironment");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateEnvironment");
+        params.put("Version", "2010-12-01");
+        CreateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateEnvironmentError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EnvironmentDescription::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EnvironmentDescriptionDeserializer::deserialize(
+                        "CreateEnvironmentResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateEnvironment", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Create a new version of your custom platform.</p>
+    fn create_platform_version(
+        &self,
+        input: CreatePlatformVersionRequest,
+    ) -> RusotoFuture<CreatePlatformVersionResult, CreatePlatformVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreatePlatformVersion");
+        let mut params = Params::new();
+
+        params.put("Action", "CreatePlatformVersion");
+        params.put("Version", "2010-12-01");
+        CreatePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreatePlatformVersionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreatePlatformVersionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreatePlatformVersionResultDeserializer::deserialize(
+                        "CreatePlatformVersionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreatePlatformVersion", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a bucket in Amazon S3 to store application versions, logs, and other files used by Elastic Beanstalk environments. The Elastic Beanstalk console and EB CLI call this API the first time you create an environment in a region. If the storage location already exists, <code>CreateStorageLocation</code> still returns the bucket name but does not create a new bucket.</p>
+    fn create_storage_location(
+        &self,
+    ) -> RusotoFuture<CreateStorageLocationResultMessage, CreateStorageLocationError> {
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateStorageLocation");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateStorageLocation");
+        params.put("Version", "2010-12-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateStorageLocationError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateStorageLocationResultMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateStorageLocationResultMessageDeserializer::deserialize(
+                        "CreateStorageLocationResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateStorageLocation", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Deletes the specified application along with all associated versions and configurations. The application versions will not be deleted from your Amazon S3 bucket.</p> <note> <p>You cannot delete an application that has a running environment.</p> </note></p>
+    fn delete_application(
+        &self,
+        input: DeleteApplicationMessage,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteApplication");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteApplication");
+        params.put("Version", "2010-12-01");
+        DeleteApplicationMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteApplicationError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p><p>Deletes the specified version from the specified application.</p> <note> <p>You cannot delete an application version that is associated with a running environment.</p> </note></p>
+    fn delete_application_version(
+        &self,
+        input: DeleteApplicationVersionMessage,
+    ) -> RusotoFuture<(), DeleteApplicationVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteApplicationVersion");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteApplicationVersion");
+        params.put("Version", "2010-12-01");
+        DeleteApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteApplicationVersionError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p><p>Deletes the specified configuration template.</p> <note> <p>When you launch an environment using a configuration template, the environment gets a copy of the template. You can delete or modify the environment&#39;s copy of the template without affecting the running environment.</p> </note></p>
+    fn delete_configuration_template(
+        &self,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> RusotoFuture<(), DeleteConfigurationTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteConfigurationTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteConfigurationTemplate");
+        params.put("Version", "2010-12-01");
+        DeleteConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteConfigurationTemplateError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the draft configuration associated with the running environment.</p> <p>Updating a running environment with any configuration changes creates a draft configuration set. You can get the draft configuration using <a>DescribeConfigurationSettings</a> while the update is in progress or if the update fails. The <code>DeploymentStatus</code> for the draft configuration indicates whether the deployment is in process or has failed. The draft configuration remains in existence until it is deleted with this action.</p>
+    fn delete_environment_configuration(
+        &self,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> RusotoFuture<(), DeleteEnvironmentConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteEnvironmentConfiguration");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteEnvironmentConfiguration");
+        params.put("Version", "2010-12-01");
+        DeleteEnvironmentConfigurationMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteEnvironmentConfigurationError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified version of a custom platform.</p>
+    fn delete_platform_version(
+        &self,
+        input: DeletePlatformVersionRequest,
+    ) -> RusotoFuture<DeletePlatformVersionResult, DeletePlatformVersionError> {
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeletePlatformVersion");
+        let mut params = Params::new();
+
+        params.put("Action", "DeletePlatformVersion");
+        params.put("Version", "2010-12-01");
+        DeletePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeletePlatformVersionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeletePlatformVersionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeletePlatformVersionResultDeserializer::deserialize(
+                        "DeletePlatformVersionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeletePlatformVersion", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns attributes related to AWS Elastic Beanstalk that are associated with the calling AWS account.</p> <p>The result currently has one set of attributes—resource quotas.</p>
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResult, DescribeAccountAttributesError> {
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeAccountAttributes");
+        params.put("Version", "2010-12-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAccountAttributesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeAccountAttributesResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeAccountAttributesResultDeserializer::deserialize(
+                        "DescribeAccountAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAccountAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieve a list of application versions.</p>
+    fn describe_application_versions(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionsMessage, DescribeApplicationVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeApplicationVersions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeApplicationVersions");
+        params.put("Version", "2010-12-01");
+        DescribeApplicationVersionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeApplicationVersionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ApplicationVersionDescriptionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ApplicationVersionDescriptionsMessageDeserializer::deserialize(
+                        "DescribeApplicationVersionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeApplicationVersions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the descriptions of existing applications.</p>
+    fn describe_applications(
+        &self,
+        input: DescribeApplicationsMessage,
+    ) -> RusotoFuture<ApplicationDescriptionsMessage, DescribeApplicationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeApplications");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeApplications");
+        params.put("Version", "2010-12-01");
+        DescribeApplicationsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeApplicationsError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ApplicationDescriptionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ApplicationDescriptionsMessageDeserializer::deserialize(
+                        "DescribeApplicationsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeApplications", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the configuration options that are used in a particular configuration template or environment, or that a specified solution stack defines. The description includes the values the options, their default values, and an indication of the required action on a running environment if an option value is changed.</p>
+    fn describe_configuration_options(
+        &self,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> RusotoFuture<ConfigurationOptionsDescription, DescribeConfigurationOptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeConfigurationOptions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeConfigurationOptions");
+        params.put("Version", "2010-12-01");
+        DescribeConfigurationOptionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeConfigurationOptionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ConfigurationOptionsDescription::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ConfigurationOptionsDescriptionDeserializer::deserialize(
+                        "DescribeConfigurationOptionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeConfigurationOptions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Returns a description of the settings for the specified configuration set, that is, either a configuration template or the configuration set associated with a running environment.</p> <p>When describing the settings for the configuration set associated with a running environment, it is possible to receive two sets of setting descriptions. One is the deployed configuration set, and the other is a draft configuration of an environment that is either in the process of deployment or that failed to deploy.</p> <p>Related Topics</p> <ul> <li> <p> <a>DeleteEnvironmentConfiguration</a> </p> </li> </ul></p>
+    fn describe_configuration_settings(
+        &self,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescriptions, DescribeConfigurationSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeConfigurationSettings");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeConfigurationSettings");
+        params.put("Version", "2010-12-01");
+        DescribeConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeConfigurationSettingsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ConfigurationSettingsDescriptions::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ConfigurationSettingsDescriptionsDeserializer::deserialize(
+                        "DescribeConfigurationSettingsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeConfigurationSettings", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about the overall health of the specified environment. The <b>DescribeEnvironmentHealth</b> operation is only available with AWS Elastic Beanstalk Enhanced Health.</p>
+    fn describe_environment_health(
+        &self,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> RusotoFuture<DescribeEnvironmentHealthResult, DescribeEnvironmentHealthError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentHealth");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEnvironmentHealth");
+        params.put("Version", "2010-12-01");
+        DescribeEnvironmentHealthRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEnvironmentHealthError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeEnvironmentHealthResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeEnvironmentHealthResultDeserializer::deserialize(
+                        "DescribeEnvironmentHealthResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeEnvironmentHealth", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists an environment's completed and failed managed actions.</p>
+    fn describe_environment_managed_action_history(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> RusotoFuture<
+        DescribeEnvironmentManagedActionHistoryResult,
+        DescribeEnvironmentManagedActionHistoryError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentManagedActionHistory");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEnvironmentManagedActionHistory");
+        params.put("Version", "2010-12-01");
+        DescribeEnvironmentManagedActionHistoryRequestSerializer::serialize(
+            &mut params,
+            "",
+            &input,
+        );
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEnvironmentManagedActionHistoryError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeEnvironmentManagedActionHistoryResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        DescribeEnvironmentManagedActionHistoryResultDeserializer::deserialize(
+                            "DescribeEnvironmentManagedActionHistoryResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "DescribeEnvironmentManagedActionHistory",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists an environment's upcoming and in-progress managed actions.</p>
+    fn describe_environment_managed_actions(
+        &self,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentManagedActionsResult, DescribeEnvironmentManagedActionsError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentManagedActions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEnvironmentManagedActions");
+        params.put("Version", "2010-12-01");
+        DescribeEnvironmentManagedActionsRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEnvironmentManagedActionsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeEnvironmentManagedActionsResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeEnvironmentManagedActionsResultDeserializer::deserialize(
+                        "DescribeEnvironmentManagedActionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeEnvironmentManagedActions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns AWS resources for this environment.</p>
+    fn describe_environment_resources(
+        &self,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> RusotoFuture<EnvironmentResourceDescriptionsMessage, DescribeEnvironmentResourcesError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentResources");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEnvironmentResources");
+        params.put("Version", "2010-12-01");
+        DescribeEnvironmentResourcesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEnvironmentResourcesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EnvironmentResourceDescriptionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EnvironmentResourceDescriptionsMessageDeserializer::deserialize(
+                        "DescribeEnvironmentResourcesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeEnvironmentResources", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns descriptions for existing environments.</p>
+    fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> RusotoFuture<EnvironmentDescriptionsMessage, DescribeEnvironmentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironments");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEnvironments");
+        params.put("Version", "2010-12-01");
+        DescribeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeEnvironmentsError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EnvironmentDescriptionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EnvironmentDescriptionsMessageDeserializer::deserialize(
+                        "DescribeEnvironmentsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeEnvironments", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Returns list of event descriptions matching criteria up to the last 6 weeks.</p> <note> <p>This action returns the most recent 1,000 events from the specified <code>NextToken</code>.</p> </note></p>
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventDescriptionsMessage, DescribeEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEvents");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEvents");
+        params.put("Version", "2010-12-01");
+        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeEventsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EventDescriptionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EventDescriptionsMessageDeserializer::deserialize(
+                        "DescribeEventsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeEvents", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieves detailed information about the health of instances in your AWS Elastic Beanstalk. This operation requires <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/health-enhanced.html">enhanced health reporting</a>.</p>
+    fn describe_instances_health(
+        &self,
+        input: DescribeInstancesHealthRequest,
+    ) -> RusotoFuture<DescribeInstancesHealthResult, DescribeInstancesHealthError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeInstancesHealth");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeInstancesHealth");
+        params.put("Version", "2010-12-01");
+        DescribeInstancesHealthRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeInstancesHealthError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeInstancesHealthResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeInstancesHealthResultDeserializer::deserialize(
+                        "DescribeInstancesHealthResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeInstancesHealth", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the version of the platform.</p>
+    fn describe_platform_version(
+        &self,
+        input: DescribePlatformVersionRequest,
+    ) -> RusotoFuture<DescribePlatformVersionResult, DescribePlatformVersionError> {
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribePlatformVersion");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribePlatformVersion");
+        params.put("Version", "2010-12-01");
+        DescribePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribePlatformVersionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribePlatformVersionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribePlatformVersionResultDeserializer::deserialize(
+                        "DescribePlatformVersionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribePlatformVersion", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of the available solution stack names, with the public version first and then in reverse chronological order.</p>
+    fn list_available_solution_stacks(
+        &self,
+    ) -> RusotoFuture<ListAvailableSolutionStacksResultMessage, ListAvailableSolutionStacksError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListAvailableSolutionStacks");
+        let mut params = Params::new();
+
+        params.put("Action", "ListAvailableSolutionStacks");
+        params.put("Version", "2010-12-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAvailableSolutionStacksError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListAvailableSolutionStacksResultMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListAvailableSolutionStacksResultMessageDeserializer::deserialize(
+                        "ListAvailableSolutionStacksResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListAvailableSolutionStacks", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists the available platforms.</p>
+    fn list_platform_versions(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> RusotoFuture<ListPlatformVersionsResult, ListPlatformVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListPlatformVersions");
+        let mut params = Params::new();
+
+        params.put("Action", "ListPlatformVersions");
+        params.put("Version", "2010-12-01");
+        ListPlatformVersionsRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListPlatformVersionsError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListPlatformVersionsResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListPlatformVersionsResultDeserializer::deserialize(
+                        "ListPlatformVersionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListPlatformVersions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the tags applied to an AWS Elastic Beanstalk resource. The response contains a list of tag key-value pairs.</p> <p>Currently, Elastic Beanstalk only supports tagging of Elastic Beanstalk environments. For details about environment tagging, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/using-features.tagging.html">Tagging Resources in Your Elastic Beanstalk Environment</a>.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<ResourceTagsDescriptionMessage, ListTagsForResourceError> {
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+        let mut params = Params::new();
+
+        params.put("Action", "ListTagsForResource");
+        params.put("Version", "2010-12-01");
+        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ResourceTagsDescriptionMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ResourceTagsDescriptionMessageDeserializer::deserialize(
+                        "ListTagsForResourceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListTagsForResource", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes and recreates all of the AWS resources (for example: the Auto Scaling group, load balancer, etc.) for a specified environment and forces a restart.</p>
+    fn rebuild_environment(
+        &self,
+        input: RebuildEnvironmentMessage,
+    ) -> RusotoFuture<(), RebuildEnvironmentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RebuildEnvironment");
+        let mut params = Params::new();
+
+        params.put("Action", "RebuildEnvironment");
+        params.put("Version", "2010-12-01");
+        RebuildEnvironmentMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RebuildEnvironmentError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p><p>Initiates a request to compile the specified type of information of the deployed environment.</p> <p> Setting the <code>InfoType</code> to <code>tail</code> compiles the last lines from the application server log files of every Amazon EC2 instance in your environment. </p> <p> Setting the <code>InfoType</code> to <code>bundle</code> compresses the application server log files for every Amazon EC2 instance into a <code>.zip</code> file. Legacy and .NET containers do not support bundle logs. </p> <p> Use <a>RetrieveEnvironmentInfo</a> to obtain the set of logs. </p> <p>Related Topics</p> <ul> <li> <p> <a>RetrieveEnvironmentInfo</a> </p> </li> </ul></p>
+    fn request_environment_info(
+        &self,
+        input: RequestEnvironmentInfoMessage,
+    ) -> RusotoFuture<(), RequestEnvironmentInfoError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RequestEnvironmentInfo");
+        let mut params = Params::new();
+
+        params.put("Action", "RequestEnvironmentInfo");
+        params.put("Version", "2010-12-01");
+        RequestEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RequestEnvironmentInfoError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Causes the environment to restart the application container server running on each Amazon EC2 instance.</p>
+    fn restart_app_server(
+        &self,
+        input: RestartAppServerMessage,
+    ) -> RusotoFuture<(), RestartAppServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RestartAppServer");
+        let mut params = Params::new();
+
+        params.put("Action", "RestartAppServer");
+        params.put("Version", "2010-12-01");
+        RestartAppServerMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RestartAppServerError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p><p>Retrieves the compiled information from a <a>RequestEnvironmentInfo</a> request.</p> <p>Related Topics</p> <ul> <li> <p> <a>RequestEnvironmentInfo</a> </p> </li> </ul></p>
+    fn retrieve_environment_info(
+        &self,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> RusotoFuture<RetrieveEnvironmentInfoResultMessage, RetrieveEnvironmentInfoError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RetrieveEnvironmentInfo");
+        let mut params = Params::new();
+
+        params.put("Action", "RetrieveEnvironmentInfo");
+        params.put("Version", "2010-12-01");
+        RetrieveEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RetrieveEnvironmentInfoError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RetrieveEnvironmentInfoResultMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RetrieveEnvironmentInfoResultMessageDeserializer::deserialize(
+                        "RetrieveEnvironmentInfoResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RetrieveEnvironmentInfo", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Swaps the CNAMEs of two environments.</p>
+    fn swap_environment_cnam_es(
+        &self,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> RusotoFuture<(), SwapEnvironmentCNAMEsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("SwapEnvironmentCNAMEs");
+        let mut params = Params::new();
+
+        params.put("Action", "SwapEnvironmentCNAMEs");
+        params.put("Version", "2010-12-01");
+        SwapEnvironmentCNAMEsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SwapEnvironmentCNAMEsError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Terminates the specified environment.</p>
+    fn terminate_environment(
+        &self,
+        input: TerminateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, TerminateEnvironmentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("TerminateEnvironment");
+        let mut params = Params::new();
+
+        params.put("Action", "TerminateEnvironment");
+        params.put("Version", "2010-12-01");
+        TerminateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(TerminateEnvironmentError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EnvironmentDescription::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EnvironmentDescriptionDeserializer::deserialize(
+                        "TerminateEnvironmentResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("TerminateEnvironment", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Updates the specified application to have the specified properties.</p> <note> <p>If a property (for example, <code>description</code>) is not provided, the value remains unchanged. To clear these properties, specify an empty string.</p> </note></p>
+    fn update_application(
+        &self,
+        input: UpdateApplicationMessage,
+    ) -> RusotoFuture<ApplicationDescriptionMessage, UpdateApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplication");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateApplication");
+        params.put("Version", "2010-12-01");
+        UpdateApplicationMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateApplicationError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ApplicationDescriptionMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ApplicationDescriptionMessageDeserializer::deserialize(
+                        "UpdateApplicationResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("UpdateApplication", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies lifecycle settings for an application.</p>
+    fn update_application_resource_lifecycle(
+        &self,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> RusotoFuture<
+        ApplicationResourceLifecycleDescriptionMessage,
+        UpdateApplicationResourceLifecycleError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplicationResourceLifecycle");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateApplicationResourceLifecycle");
+        params.put("Version", "2010-12-01");
+        UpdateApplicationResourceLifecycleMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateApplicationResourceLifecycleError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ApplicationResourceLifecycleDescriptionMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        ApplicationResourceLifecycleDescriptionMessageDeserializer::deserialize(
+                            "UpdateApplicationResourceLifecycleResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "UpdateApplicationResourceLifecycle",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Updates the specified application version to have the specified properties.</p> <note> <p>If a property (for example, <code>description</code>) is not provided, the value remains unchanged. To clear properties, specify an empty string.</p> </note></p>
+    fn update_application_version(
+        &self,
+        input: UpdateApplicationVersionMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionMessage, UpdateApplicationVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplicationVersion");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateApplicationVersion");
+        params.put("Version", "2010-12-01");
+        UpdateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateApplicationVersionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ApplicationVersionDescriptionMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ApplicationVersionDescriptionMessageDeserializer::deserialize(
+                        "UpdateApplicationVersionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateApplicationVersion", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Updates the specified configuration template to have the specified properties or configuration option values.</p> <note> <p>If a property (for example, <code>ApplicationName</code>) is not provided, its value remains unchanged. To clear such properties, specify an empty string.</p> </note> <p>Related Topics</p> <ul> <li> <p> <a>DescribeConfigurationOptions</a> </p> </li> </ul></p>
+    fn update_configuration_template(
+        &self,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescription, UpdateConfigurationTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateConfigurationTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateConfigurationTemplate");
+        params.put("Version", "2010-12-01");
+        UpdateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateConfigurationTemplateError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ConfigurationSettingsDescription::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ConfigurationSettingsDescriptionDeserializer::deserialize(
+                        "UpdateConfigurationTemplateResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateConfigurationTemplate", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates the environment description, deploys a new application version, updates the configuration settings to an entirely new configuration template, or updates select configuration option values in the running environment.</p> <p> Attempting to update both the release and configuration is not allowed and AWS Elastic Beanstalk returns an <code>InvalidParameterCombination</code> error. </p> <p> When updating the configuration settings to a new template or individual settings, a draft configuration is created and <a>DescribeConfigurationSettings</a> for this environment returns two setting descriptions with different <code>DeploymentStatus</code> values. </p>
+    fn update_environment(
+        &self,
+        input: UpdateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, UpdateEnvironmentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateEnvironment");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateEnvironment");
+        params.put("Version", "2010-12-01");
+        UpdateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateEnvironmentError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EnvironmentDescription::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EnvironmentDescriptionDeserializer::deserialize(
+                        "UpdateEnvironmentResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("UpdateEnvironment", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Update the list of tags applied to an AWS Elastic Beanstalk resource. Two lists can be passed: <code>TagsToAdd</code> for tags to add or update, and <code>TagsToRemove</code>.</p> <p>Currently, Elastic Beanstalk only supports tagging of Elastic Beanstalk environments. For details about environment tagging, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/using-features.tagging.html">Tagging Resources in Your Elastic Beanstalk Environment</a>.</p> <p>If you create a custom IAM user policy to control permission to this operation, specify one of the following two virtual actions (or both) instead of the API operation name:</p> <dl> <dt>elasticbeanstalk:AddTags</dt> <dd> <p>Controls permission to call <code>UpdateTagsForResource</code> and pass a list of tags to add in the <code>TagsToAdd</code> parameter.</p> </dd> <dt>elasticbeanstalk:RemoveTags</dt> <dd> <p>Controls permission to call <code>UpdateTagsForResource</code> and pass a list of tag keys to remove in the <code>TagsToRemove</code> parameter.</p> </dd> </dl> <p>For details about creating a custom user policy, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/AWSHowTo.iam.managed-policies.html#AWSHowTo.iam.policies">Creating a Custom User Policy</a>.</p>
+    fn update_tags_for_resource(
+        &self,
+        input: UpdateTagsForResourceMessage,
+    ) -> RusotoFuture<(), UpdateTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateTagsForResource");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateTagsForResource");
+        params.put("Version", "2010-12-01");
+        UpdateTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateTagsForResourceError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Takes a set of configuration settings and either a configuration template or environment, and determines whether those values are valid.</p> <p>This action returns a list of messages indicating any errors or warnings associated with the selection of option values.</p>
+    fn validate_configuration_settings(
+        &self,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> RusotoFuture<ConfigurationSettingsValidationMessages, ValidateConfigurationSettingsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ValidateConfigurationSettings");
+        let mut params = Params::new();
+
+        params.put("Action", "ValidateConfigurationSettings");
+        params.put("Version", "2010-12-01");
+        ValidateConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ValidateConfigurationSettingsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ConfigurationSettingsValidationMessages::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ConfigurationSettingsValidationMessagesDeserializer::deserialize(
+                        "ValidateConfigurationSettingsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ValidateConfigurationSettings", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -11607,8 +14385,1496 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             }))
         })
     }
-
-    /// <p>Modifies lifecycle settings for an application.</p>
+}
+
+/// A mock of the Elastic Beanstalk API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockElasticBeanstalk {
+    abort_environment_update: Option<
+        Box<
+            dyn Fn(
+                    AbortEnvironmentUpdateMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<AbortEnvironmentUpdateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    apply_environment_managed_action: Option<
+        Box<
+            dyn Fn(
+                    ApplyEnvironmentManagedActionRequest,
+                ) -> ::std::result::Result<
+                    ApplyEnvironmentManagedActionResult,
+                    RusotoError<ApplyEnvironmentManagedActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    check_dns_availability: Option<
+        Box<
+            dyn Fn(
+                    CheckDNSAvailabilityMessage,
+                ) -> ::std::result::Result<
+                    CheckDNSAvailabilityResultMessage,
+                    RusotoError<CheckDNSAvailabilityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    compose_environments: Option<
+        Box<
+            dyn Fn(
+                    ComposeEnvironmentsMessage,
+                ) -> ::std::result::Result<
+                    EnvironmentDescriptionsMessage,
+                    RusotoError<ComposeEnvironmentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_application: Option<
+        Box<
+            dyn Fn(
+                    CreateApplicationMessage,
+                ) -> ::std::result::Result<
+                    ApplicationDescriptionMessage,
+                    RusotoError<CreateApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_application_version: Option<
+        Box<
+            dyn Fn(
+                    CreateApplicationVersionMessage,
+                ) -> ::std::result::Result<
+                    ApplicationVersionDescriptionMessage,
+                    RusotoError<CreateApplicationVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_configuration_template: Option<
+        Box<
+            dyn Fn(
+                    CreateConfigurationTemplateMessage,
+                ) -> ::std::result::Result<
+                    ConfigurationSettingsDescription,
+                    RusotoError<CreateConfigurationTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_environment: Option<
+        Box<
+            dyn Fn(
+                    CreateEnvironmentMessage,
+                ) -> ::std::result::Result<
+                    EnvironmentDescription,
+                    RusotoError<CreateEnvironmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_platform_version: Option<
+        Box<
+            dyn Fn(
+                    CreatePlatformVersionRequest,
+                ) -> ::std::result::Result<
+                    CreatePlatformVersionResult,
+                    RusotoError<CreatePlatformVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_storage_location: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    CreateStorageLocationResultMessage,
+                    RusotoError<CreateStorageLocationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_application: Option<
+        Box<
+            dyn Fn(
+                    DeleteApplicationMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteApplicationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_application_version: Option<
+        Box<
+            dyn Fn(
+                    DeleteApplicationVersionMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteApplicationVersionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_configuration_template: Option<
+        Box<
+            dyn Fn(
+                    DeleteConfigurationTemplateMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteConfigurationTemplateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_environment_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteEnvironmentConfigurationMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteEnvironmentConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_platform_version: Option<
+        Box<
+            dyn Fn(
+                    DeletePlatformVersionRequest,
+                ) -> ::std::result::Result<
+                    DeletePlatformVersionResult,
+                    RusotoError<DeletePlatformVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_account_attributes: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeAccountAttributesResult,
+                    RusotoError<DescribeAccountAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_application_versions: Option<
+        Box<
+            dyn Fn(
+                    DescribeApplicationVersionsMessage,
+                ) -> ::std::result::Result<
+                    ApplicationVersionDescriptionsMessage,
+                    RusotoError<DescribeApplicationVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_applications: Option<
+        Box<
+            dyn Fn(
+                    DescribeApplicationsMessage,
+                ) -> ::std::result::Result<
+                    ApplicationDescriptionsMessage,
+                    RusotoError<DescribeApplicationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration_options: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationOptionsMessage,
+                ) -> ::std::result::Result<
+                    ConfigurationOptionsDescription,
+                    RusotoError<DescribeConfigurationOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration_settings: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationSettingsMessage,
+                ) -> ::std::result::Result<
+                    ConfigurationSettingsDescriptions,
+                    RusotoError<DescribeConfigurationSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_environment_health: Option<
+        Box<
+            dyn Fn(
+                    DescribeEnvironmentHealthRequest,
+                ) -> ::std::result::Result<
+                    DescribeEnvironmentHealthResult,
+                    RusotoError<DescribeEnvironmentHealthError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_environment_managed_action_history: Option<
+        Box<
+            dyn Fn(
+                    DescribeEnvironmentManagedActionHistoryRequest,
+                ) -> ::std::result::Result<
+                    DescribeEnvironmentManagedActionHistoryResult,
+                    RusotoError<DescribeEnvironmentManagedActionHistoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_environment_managed_actions: Option<
+        Box<
+            dyn Fn(
+                    DescribeEnvironmentManagedActionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeEnvironmentManagedActionsResult,
+                    RusotoError<DescribeEnvironmentManagedActionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_environment_resources: Option<
+        Box<
+            dyn Fn(
+                    DescribeEnvironmentResourcesMessage,
+                ) -> ::std::result::Result<
+                    EnvironmentResourceDescriptionsMessage,
+                    RusotoError<DescribeEnvironmentResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_environments: Option<
+        Box<
+            dyn Fn(
+                    DescribeEnvironmentsMessage,
+                ) -> ::std::result::Result<
+                    EnvironmentDescriptionsMessage,
+                    RusotoError<DescribeEnvironmentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_events: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventsMessage,
+                ) -> ::std::result::Result<
+                    EventDescriptionsMessage,
+                    RusotoError<DescribeEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_instances_health: Option<
+        Box<
+            dyn Fn(
+                    DescribeInstancesHealthRequest,
+                ) -> ::std::result::Result<
+                    DescribeInstancesHealthResult,
+                    RusotoError<DescribeInstancesHealthError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_platform_version: Option<
+        Box<
+            dyn Fn(
+                    DescribePlatformVersionRequest,
+                ) -> ::std::result::Result<
+                    DescribePlatformVersionResult,
+                    RusotoError<DescribePlatformVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_available_solution_stacks: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    ListAvailableSolutionStacksResultMessage,
+                    RusotoError<ListAvailableSolutionStacksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_platform_versions: Option<
+        Box<
+            dyn Fn(
+                    ListPlatformVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListPlatformVersionsResult,
+                    RusotoError<ListPlatformVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceMessage,
+                ) -> ::std::result::Result<
+                    ResourceTagsDescriptionMessage,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    rebuild_environment: Option<
+        Box<
+            dyn Fn(
+                    RebuildEnvironmentMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<RebuildEnvironmentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    request_environment_info: Option<
+        Box<
+            dyn Fn(
+                    RequestEnvironmentInfoMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<RequestEnvironmentInfoError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    restart_app_server: Option<
+        Box<
+            dyn Fn(
+                    RestartAppServerMessage,
+                ) -> ::std::result::Result<(), RusotoError<RestartAppServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    retrieve_environment_info: Option<
+        Box<
+            dyn Fn(
+                    RetrieveEnvironmentInfoMessage,
+                ) -> ::std::result::Result<
+                    RetrieveEnvironmentInfoResultMessage,
+                    RusotoError<RetrieveEnvironmentInfoError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    swap_environment_cnam_es: Option<
+        Box<
+            dyn Fn(
+                    SwapEnvironmentCNAMEsMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<SwapEnvironmentCNAMEsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    terminate_environment: Option<
+        Box<
+            dyn Fn(
+                    TerminateEnvironmentMessage,
+                ) -> ::std::result::Result<
+                    EnvironmentDescription,
+                    RusotoError<TerminateEnvironmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_application: Option<
+        Box<
+            dyn Fn(
+                    UpdateApplicationMessage,
+                ) -> ::std::result::Result<
+                    ApplicationDescriptionMessage,
+                    RusotoError<UpdateApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_application_resource_lifecycle: Option<
+        Box<
+            dyn Fn(
+                    UpdateApplicationResourceLifecycleMessage,
+                ) -> ::std::result::Result<
+                    ApplicationResourceLifecycleDescriptionMessage,
+                    RusotoError<UpdateApplicationResourceLifecycleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_application_version: Option<
+        Box<
+            dyn Fn(
+                    UpdateApplicationVersionMessage,
+                ) -> ::std::result::Result<
+                    ApplicationVersionDescriptionMessage,
+                    RusotoError<UpdateApplicationVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_configuration_template: Option<
+        Box<
+            dyn Fn(
+                    UpdateConfigurationTemplateMessage,
+                ) -> ::std::result::Result<
+                    ConfigurationSettingsDescription,
+                    RusotoError<UpdateConfigurationTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_environment: Option<
+        Box<
+            dyn Fn(
+                    UpdateEnvironmentMessage,
+                ) -> ::std::result::Result<
+                    EnvironmentDescription,
+                    RusotoError<UpdateEnvironmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    UpdateTagsForResourceMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateTagsForResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    validate_configuration_settings: Option<
+        Box<
+            dyn Fn(
+                    ValidateConfigurationSettingsMessage,
+                ) -> ::std::result::Result<
+                    ConfigurationSettingsValidationMessages,
+                    RusotoError<ValidateConfigurationSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockElasticBeanstalk {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockElasticBeanstalk {
+        Default::default()
+    }
+
+    /// Answers `abort_environment_update` with what `handler` returns for its input.
+    pub fn with_abort_environment_update<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                AbortEnvironmentUpdateMessage,
+            ) -> ::std::result::Result<(), RusotoError<AbortEnvironmentUpdateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.abort_environment_update = Some(Box::new(handler));
+        self
+    }
+    /// Answers `apply_environment_managed_action` with what `handler` returns for its input.
+    pub fn with_apply_environment_managed_action<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                ApplyEnvironmentManagedActionRequest,
+            ) -> ::std::result::Result<
+                ApplyEnvironmentManagedActionResult,
+                RusotoError<ApplyEnvironmentManagedActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.apply_environment_managed_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `check_dns_availability` with what `handler` returns for its input.
+    pub fn with_check_dns_availability<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                CheckDNSAvailabilityMessage,
+            ) -> ::std::result::Result<
+                CheckDNSAvailabilityResultMessage,
+                RusotoError<CheckDNSAvailabilityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.check_dns_availability = Some(Box::new(handler));
+        self
+    }
+    /// Answers `compose_environments` with what `handler` returns for its input.
+    pub fn with_compose_environments<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                ComposeEnvironmentsMessage,
+            ) -> ::std::result::Result<
+                EnvironmentDescriptionsMessage,
+                RusotoError<ComposeEnvironmentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.compose_environments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_application` with what `handler` returns for its input.
+    pub fn with_create_application<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                CreateApplicationMessage,
+            ) -> ::std::result::Result<
+                ApplicationDescriptionMessage,
+                RusotoError<CreateApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_application_version` with what `handler` returns for its input.
+    pub fn with_create_application_version<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                CreateApplicationVersionMessage,
+            ) -> ::std::result::Result<
+                ApplicationVersionDescriptionMessage,
+                RusotoError<CreateApplicationVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_application_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_configuration_template` with what `handler` returns for its input.
+    pub fn with_create_configuration_template<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                CreateConfigurationTemplateMessage,
+            ) -> ::std::result::Result<
+                ConfigurationSettingsDescription,
+                RusotoError<CreateConfigurationTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_configuration_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_environment` with what `handler` returns for its input.
+    pub fn with_create_environment<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                CreateEnvironmentMessage,
+            )
+                -> ::std::result::Result<EnvironmentDescription, RusotoError<CreateEnvironmentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_environment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_platform_version` with what `handler` returns for its input.
+    pub fn with_create_platform_version<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                CreatePlatformVersionRequest,
+            ) -> ::std::result::Result<
+                CreatePlatformVersionResult,
+                RusotoError<CreatePlatformVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_platform_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_storage_location` with what `handler` returns.
+    pub fn with_create_storage_location<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn() -> ::std::result::Result<
+                CreateStorageLocationResultMessage,
+                RusotoError<CreateStorageLocationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_storage_location = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_application` with what `handler` returns for its input.
+    pub fn with_delete_application<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DeleteApplicationMessage,
+            ) -> ::std::result::Result<(), RusotoError<DeleteApplicationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_application_version` with what `handler` returns for its input.
+    pub fn with_delete_application_version<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DeleteApplicationVersionMessage,
+            ) -> ::std::result::Result<(), RusotoError<DeleteApplicationVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_application_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_configuration_template` with what `handler` returns for its input.
+    pub fn with_delete_configuration_template<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DeleteConfigurationTemplateMessage,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteConfigurationTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_configuration_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_environment_configuration` with what `handler` returns for its input.
+    pub fn with_delete_environment_configuration<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DeleteEnvironmentConfigurationMessage,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteEnvironmentConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_environment_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_platform_version` with what `handler` returns for its input.
+    pub fn with_delete_platform_version<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DeletePlatformVersionRequest,
+            ) -> ::std::result::Result<
+                DeletePlatformVersionResult,
+                RusotoError<DeletePlatformVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_platform_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account_attributes` with what `handler` returns.
+    pub fn with_describe_account_attributes<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeAccountAttributesResult,
+                RusotoError<DescribeAccountAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_application_versions` with what `handler` returns for its input.
+    pub fn with_describe_application_versions<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeApplicationVersionsMessage,
+            ) -> ::std::result::Result<
+                ApplicationVersionDescriptionsMessage,
+                RusotoError<DescribeApplicationVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_application_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_applications` with what `handler` returns for its input.
+    pub fn with_describe_applications<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeApplicationsMessage,
+            ) -> ::std::result::Result<
+                ApplicationDescriptionsMessage,
+                RusotoError<DescribeApplicationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_applications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration_options` with what `handler` returns for its input.
+    pub fn with_describe_configuration_options<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeConfigurationOptionsMessage,
+            ) -> ::std::result::Result<
+                ConfigurationOptionsDescription,
+                RusotoError<DescribeConfigurationOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration_settings` with what `handler` returns for its input.
+    pub fn with_describe_configuration_settings<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeConfigurationSettingsMessage,
+            ) -> ::std::result::Result<
+                ConfigurationSettingsDescriptions,
+                RusotoError<DescribeConfigurationSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_environment_health` with what `handler` returns for its input.
+    pub fn with_describe_environment_health<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeEnvironmentHealthRequest,
+            ) -> ::std::result::Result<
+                DescribeEnvironmentHealthResult,
+                RusotoError<DescribeEnvironmentHealthError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_environment_health = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_environment_managed_action_history` with what `handler` returns for its input.
+    pub fn with_describe_environment_managed_action_history<F>(
+        mut self,
+        handler: F,
+    ) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeEnvironmentManagedActionHistoryRequest,
+            ) -> ::std::result::Result<
+                DescribeEnvironmentManagedActionHistoryResult,
+                RusotoError<DescribeEnvironmentManagedActionHistoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_environment_managed_action_history = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_environment_managed_actions` with what `handler` returns for its input.
+    pub fn with_describe_environment_managed_actions<F>(
+        mut self,
+        handler: F,
+    ) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeEnvironmentManagedActionsRequest,
+            ) -> ::std::result::Result<
+                DescribeEnvironmentManagedActionsResult,
+                RusotoError<DescribeEnvironmentManagedActionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_environment_managed_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_environment_resources` with what `handler` returns for its input.
+    pub fn with_describe_environment_resources<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeEnvironmentResourcesMessage,
+            ) -> ::std::result::Result<
+                EnvironmentResourceDescriptionsMessage,
+                RusotoError<DescribeEnvironmentResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_environment_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_environments` with what `handler` returns for its input.
+    pub fn with_describe_environments<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeEnvironmentsMessage,
+            ) -> ::std::result::Result<
+                EnvironmentDescriptionsMessage,
+                RusotoError<DescribeEnvironmentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_environments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_events` with what `handler` returns for its input.
+    pub fn with_describe_events<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeEventsMessage,
+            )
+                -> ::std::result::Result<EventDescriptionsMessage, RusotoError<DescribeEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_instances_health` with what `handler` returns for its input.
+    pub fn with_describe_instances_health<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribeInstancesHealthRequest,
+            ) -> ::std::result::Result<
+                DescribeInstancesHealthResult,
+                RusotoError<DescribeInstancesHealthError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_instances_health = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_platform_version` with what `handler` returns for its input.
+    pub fn with_describe_platform_version<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                DescribePlatformVersionRequest,
+            ) -> ::std::result::Result<
+                DescribePlatformVersionResult,
+                RusotoError<DescribePlatformVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_platform_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_available_solution_stacks` with what `handler` returns.
+    pub fn with_list_available_solution_stacks<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn() -> ::std::result::Result<
+                ListAvailableSolutionStacksResultMessage,
+                RusotoError<ListAvailableSolutionStacksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_available_solution_stacks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_platform_versions` with what `handler` returns for its input.
+    pub fn with_list_platform_versions<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                ListPlatformVersionsRequest,
+            ) -> ::std::result::Result<
+                ListPlatformVersionsResult,
+                RusotoError<ListPlatformVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_platform_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                ListTagsForResourceMessage,
+            ) -> ::std::result::Result<
+                ResourceTagsDescriptionMessage,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `rebuild_environment` with what `handler` returns for its input.
+    pub fn with_rebuild_environment<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                RebuildEnvironmentMessage,
+            ) -> ::std::result::Result<(), RusotoError<RebuildEnvironmentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.rebuild_environment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `request_environment_info` with what `handler` returns for its input.
+    pub fn with_request_environment_info<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                RequestEnvironmentInfoMessage,
+            ) -> ::std::result::Result<(), RusotoError<RequestEnvironmentInfoError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.request_environment_info = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restart_app_server` with what `handler` returns for its input.
+    pub fn with_restart_app_server<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                RestartAppServerMessage,
+            ) -> ::std::result::Result<(), RusotoError<RestartAppServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.restart_app_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `retrieve_environment_info` with what `handler` returns for its input.
+    pub fn with_retrieve_environment_info<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                RetrieveEnvironmentInfoMessage,
+            ) -> ::std::result::Result<
+                RetrieveEnvironmentInfoResultMessage,
+                RusotoError<RetrieveEnvironmentInfoError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.retrieve_environment_info = Some(Box::new(handler));
+        self
+    }
+    /// Answers `swap_environment_cnam_es` with what `handler` returns for its input.
+    pub fn with_swap_environment_cnam_es<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                SwapEnvironmentCNAMEsMessage,
+            ) -> ::std::result::Result<(), RusotoError<SwapEnvironmentCNAMEsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.swap_environment_cnam_es = Some(Box::new(handler));
+        self
+    }
+    /// Answers `terminate_environment` with what `handler` returns for its input.
+    pub fn with_terminate_environment<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                TerminateEnvironmentMessage,
+            ) -> ::std::result::Result<
+                EnvironmentDescription,
+                RusotoError<TerminateEnvironmentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.terminate_environment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_application` with what `handler` returns for its input.
+    pub fn with_update_application<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                UpdateApplicationMessage,
+            ) -> ::std::result::Result<
+                ApplicationDescriptionMessage,
+                RusotoError<UpdateApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_application_resource_lifecycle` with what `handler` returns for its input.
+    pub fn with_update_application_resource_lifecycle<F>(
+        mut self,
+        handler: F,
+    ) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                UpdateApplicationResourceLifecycleMessage,
+            ) -> ::std::result::Result<
+                ApplicationResourceLifecycleDescriptionMessage,
+                RusotoError<UpdateApplicationResourceLifecycleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_application_resource_lifecycle = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_application_version` with what `handler` returns for its input.
+    pub fn with_update_application_version<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                UpdateApplicationVersionMessage,
+            ) -> ::std::result::Result<
+                ApplicationVersionDescriptionMessage,
+                RusotoError<UpdateApplicationVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_application_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_configuration_template` with what `handler` returns for its input.
+    pub fn with_update_configuration_template<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                UpdateConfigurationTemplateMessage,
+            ) -> ::std::result::Result<
+                ConfigurationSettingsDescription,
+                RusotoError<UpdateConfigurationTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_configuration_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_environment` with what `handler` returns for its input.
+    pub fn with_update_environment<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                UpdateEnvironmentMessage,
+            )
+                -> ::std::result::Result<EnvironmentDescription, RusotoError<UpdateEnvironmentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_environment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_update_tags_for_resource<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                UpdateTagsForResourceMessage,
+            ) -> ::std::result::Result<(), RusotoError<UpdateTagsForResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `validate_configuration_settings` with what `handler` returns for its input.
+    pub fn with_validate_configuration_settings<F>(mut self, handler: F) -> MockElasticBeanstalk
+    where
+        F: Fn(
+                ValidateConfigurationSettingsMessage,
+            ) -> ::std::result::Result<
+                ConfigurationSettingsValidationMessages,
+                RusotoError<ValidateConfigurationSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.validate_configuration_settings = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ElasticBeanstalk for MockElasticBeanstalk {
+    fn abort_environment_update(
+        &self,
+        input: AbortEnvironmentUpdateMessage,
+    ) -> RusotoFuture<(), AbortEnvironmentUpdateError> {
+        match self.abort_environment_update {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for abort_environment_update"),
+        }
+    }
+    fn apply_environment_managed_action(
+        &self,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> RusotoFuture<ApplyEnvironmentManagedActionResult, ApplyEnvironmentManagedActionError> {
+        match self.apply_environment_managed_action {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElasticBeanstalk has no handler for apply_environment_managed_action")
+            }
+        }
+    }
+    fn check_dns_availability(
+        &self,
+        input: CheckDNSAvailabilityMessage,
+    ) -> RusotoFuture<CheckDNSAvailabilityResultMessage, CheckDNSAvailabilityError> {
+        match self.check_dns_availability {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for check_dns_availability"),
+        }
+    }
+    fn compose_environments(
+        &self,
+        input: ComposeEnvironmentsMessage,
+    ) -> RusotoFuture<EnvironmentDescriptionsMessage, ComposeEnvironmentsError> {
+        match self.compose_environments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for compose_environments"),
+        }
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationMessage,
+    ) -> RusotoFuture<ApplicationDescriptionMessage, CreateApplicationError> {
+        match self.create_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for create_application"),
+        }
+    }
+    fn create_application_version(
+        &self,
+        input: CreateApplicationVersionMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionMessage, CreateApplicationVersionError> {
+        match self.create_application_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for create_application_version"),
+        }
+    }
+    fn create_configuration_template(
+        &self,
+        input: CreateConfigurationTemplateMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescription, CreateConfigurationTemplateError> {
+        match self.create_configuration_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for create_configuration_template"),
+        }
+    }
+    fn create_environment(
+        &self,
+        input: CreateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, CreateEnvironmentError> {
+        match self.create_environment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for create_environment"),
+        }
+    }
+    fn create_platform_version(
+        &self,
+        input: CreatePlatformVersionRequest,
+    ) -> RusotoFuture<CreatePlatformVersionResult, CreatePlatformVersionError> {
+        match self.create_platform_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for create_platform_version"),
+        }
+    }
+    fn create_storage_location(
+        &self,
+    ) -> RusotoFuture<CreateStorageLocationResultMessage, CreateStorageLocationError> {
+        match self.create_storage_location {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockElasticBeanstalk has no handler for create_storage_location"),
+        }
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationMessage,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        match self.delete_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for delete_application"),
+        }
+    }
+    fn delete_application_version(
+        &self,
+        input: DeleteApplicationVersionMessage,
+    ) -> RusotoFuture<(), DeleteApplicationVersionError> {
+        match self.delete_application_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for delete_application_version"),
+        }
+    }
+    fn delete_configuration_template(
+        &self,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> RusotoFuture<(), DeleteConfigurationTemplateError> {
+        match self.delete_configuration_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for delete_configuration_template"),
+        }
+    }
+    fn delete_environment_configuration(
+        &self,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> RusotoFuture<(), DeleteEnvironmentConfigurationError> {
+        match self.delete_environment_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElasticBeanstalk has no handler for delete_environment_configuration")
+            }
+        }
+    }
+    fn delete_platform_version(
+        &self,
+        input: DeletePlatformVersionRequest,
+    ) -> RusotoFuture<DeletePlatformVersionResult, DeletePlatformVersionError> {
+        match self.delete_platform_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for delete_platform_version"),
+        }
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResult, DescribeAccountAttributesError> {
+        match self.describe_account_attributes {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockElasticBeanstalk has no handler for describe_account_attributes"),
+        }
+    }
+    fn describe_application_versions(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionsMessage, DescribeApplicationVersionsError> {
+        match self.describe_application_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for describe_application_versions"),
+        }
+    }
+    fn describe_applications(
+        &self,
+        input: DescribeApplicationsMessage,
+    ) -> RusotoFuture<ApplicationDescriptionsMessage, DescribeApplicationsError> {
+        match self.describe_applications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for describe_applications"),
+        }
+    }
+    fn describe_configuration_options(
+        &self,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> RusotoFuture<ConfigurationOptionsDescription, DescribeConfigurationOptionsError> {
+        match self.describe_configuration_options {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElasticBeanstalk has no handler for describe_configuration_options")
+            }
+        }
+    }
+    fn describe_configuration_settings(
+        &self,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescriptions, DescribeConfigurationSettingsError> {
+        match self.describe_configuration_settings {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElasticBeanstalk has no handler for describe_configuration_settings")
+            }
+        }
+    }
+    fn describe_environment_health(
+        &self,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> RusotoFuture<DescribeEnvironmentHealthResult, DescribeEnvironmentHealthError> {
+        match self.describe_environment_health {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for describe_environment_health"),
+        }
+    }
+    fn describe_environment_managed_action_history(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> RusotoFuture<
+        DescribeEnvironmentManagedActionHistoryResult,
+        DescribeEnvironmentManagedActionHistoryError,
+    > {
+        match self.describe_environment_managed_action_history {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockElasticBeanstalk has no handler for describe_environment_managed_action_history"),
+}
+    }
+    fn describe_environment_managed_actions(
+        &self,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentManagedActionsResult, DescribeEnvironmentManagedActionsError>
+    {
+        match self.describe_environment_managed_actions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockElasticBeanstalk has no handler for describe_environment_managed_actions"
+            ),
+        }
+    }
+    fn describe_environment_resources(
+        &self,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> RusotoFuture<EnvironmentResourceDescriptionsMessage, DescribeEnvironmentResourcesError>
+    {
+        match self.describe_environment_resources {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElasticBeanstalk has no handler for describe_environment_resources")
+            }
+        }
+    }
+    fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> RusotoFuture<EnvironmentDescriptionsMessage, DescribeEnvironmentsError> {
+        match self.describe_environments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for describe_environments"),
+        }
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventDescriptionsMessage, DescribeEventsError> {
+        match self.describe_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for describe_events"),
+        }
+    }
+    fn describe_instances_health(
+        &self,
+        input: DescribeInstancesHealthRequest,
+    ) -> RusotoFuture<DescribeInstancesHealthResult, DescribeInstancesHealthError> {
+        match self.describe_instances_health {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for describe_instances_health"),
+        }
+    }
+    fn describe_platform_version(
+        &self,
+        input: DescribePlatformVersionRequest,
+    ) -> RusotoFuture<DescribePlatformVersionResult, DescribePlatformVersionError> {
+        match self.describe_platform_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for describe_platform_version"),
+        }
+    }
+    fn list_available_solution_stacks(
+        &self,
+    ) -> RusotoFuture<ListAvailableSolutionStacksResultMessage, ListAvailableSolutionStacksError>
+    {
+        match self.list_available_solution_stacks {
+            Some(ref handler) => handler().into(),
+            None => {
+                panic!("MockElasticBeanstalk has no handler for list_available_solution_stacks")
+            }
+        }
+    }
+    fn list_platform_versions(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> RusotoFuture<ListPlatformVersionsResult, ListPlatformVersionsError> {
+        match self.list_platform_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for list_platform_versions"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<ResourceTagsDescriptionMessage, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for list_tags_for_resource"),
+        }
+    }
+    fn rebuild_environment(
+        &self,
+        input: RebuildEnvironmentMessage,
+    ) -> RusotoFuture<(), RebuildEnvironmentError> {
+        match self.rebuild_environment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for rebuild_environment"),
+        }
+    }
+    fn request_environment_info(
+        &self,
+        input: RequestEnvironmentInfoMessage,
+    ) -> RusotoFuture<(), RequestEnvironmentInfoError> {
+        match self.request_environment_info {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for request_environment_info"),
+        }
+    }
+    fn restart_app_server(
+        &self,
+        input: RestartAppServerMessage,
+    ) -> RusotoFuture<(), RestartAppServerError> {
+        match self.restart_app_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for restart_app_server"),
+        }
+    }
+    fn retrieve_environment_info(
+        &self,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> RusotoFuture<RetrieveEnvironmentInfoResultMessage, RetrieveEnvironmentInfoError> {
+        match self.retrieve_environment_info {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for retrieve_environment_info"),
+        }
+    }
+    fn swap_environment_cnam_es(
+        &self,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> RusotoFuture<(), SwapEnvironmentCNAMEsError> {
+        match self.swap_environment_cnam_es {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for swap_environment_cnam_es"),
+        }
+    }
+    fn terminate_environment(
+        &self,
+        input: TerminateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, TerminateEnvironmentError> {
+        match self.terminate_environment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for terminate_environment"),
+        }
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationMessage,
+    ) -> RusotoFuture<ApplicationDescriptionMessage, UpdateApplicationError> {
+        match self.update_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for update_application"),
+        }
+    }
     fn update_application_resource_lifecycle(
         &self,
         input: UpdateApplicationResourceLifecycleMessage,
@@ -11616,271 +15882,60 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         ApplicationResourceLifecycleDescriptionMessage,
         UpdateApplicationResourceLifecycleError,
     > {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateApplicationResourceLifecycle");
-        params.put("Version", "2010-12-01");
-        UpdateApplicationResourceLifecycleMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateApplicationResourceLifecycleError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ApplicationResourceLifecycleDescriptionMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        ApplicationResourceLifecycleDescriptionMessageDeserializer::deserialize(
-                            "UpdateApplicationResourceLifecycleResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_application_resource_lifecycle {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockElasticBeanstalk has no handler for update_application_resource_lifecycle"
+            ),
+        }
     }
-
-    /// <p><p>Updates the specified application version to have the specified properties.</p> <note> <p>If a property (for example, <code>description</code>) is not provided, the value remains unchanged. To clear properties, specify an empty string.</p> </note></p>
     fn update_application_version(
         &self,
         input: UpdateApplicationVersionMessage,
     ) -> RusotoFuture<ApplicationVersionDescriptionMessage, UpdateApplicationVersionError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateApplicationVersion");
-        params.put("Version", "2010-12-01");
-        UpdateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateApplicationVersionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ApplicationVersionDescriptionMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ApplicationVersionDescriptionMessageDeserializer::deserialize(
-                        "UpdateApplicationVersionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_application_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for update_application_version"),
+        }
     }
-
-    /// <p><p>Updates the specified configuration template to have the specified properties or configuration option values.</p> <note> <p>If a property (for example, <code>ApplicationName</code>) is not provided, its value remains unchanged. To clear such properties, specify an empty string.</p> </note> <p>Related Topics</p> <ul> <li> <p> <a>DescribeConfigurationOptions</a> </p> </li> </ul></p>
     fn update_configuration_template(
         &self,
         input: UpdateConfigurationTemplateMessage,
     ) -> RusotoFuture<ConfigurationSettingsDescription, UpdateConfigurationTemplateError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateConfigurationTemplate");
-        params.put("Version", "2010-12-01");
-        UpdateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateConfigurationTemplateError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ConfigurationSettingsDescription::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ConfigurationSettingsDescriptionDeserializer::deserialize(
-                        "UpdateConfigurationTemplateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_configuration_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for update_configuration_template"),
+        }
     }
-
-    /// <p>Updates the environment description, deploys a new application version, updates the configuration settings to an entirely new configuration template, or updates select configuration option values in the running environment.</p> <p> Attempting to update both the release and configuration is not allowed and AWS Elastic Beanstalk returns an <code>InvalidParameterCombination</code> error. </p> <p> When updating the configuration settings to a new template or individual settings, a draft configuration is created and <a>DescribeConfigurationSettings</a> for this environment returns two setting descriptions with different <code>DeploymentStatus</code> values. </p>
     fn update_environment(
         &self,
         input: UpdateEnvironmentMessage,
     ) -> RusotoFuture<EnvironmentDescription, UpdateEnvironmentError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateEnvironment");
-        params.put("Version", "2010-12-01");
-        UpdateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateEnvironmentError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EnvironmentDescription::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EnvironmentDescriptionDeserializer::deserialize(
-                        "UpdateEnvironmentResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_environment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for update_environment"),
+        }
     }
-
-    /// <p>Update the list of tags applied to an AWS Elastic Beanstalk resource. Two lists can be passed: <code>TagsToAdd</code> for tags to add or update, and <code>TagsToRemove</code>.</p> <p>Currently, Elastic Beanstalk only supports tagging of Elastic Beanstalk environments. For details about environment tagging, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/using-features.tagging.html">Tagging Resources in Your Elastic Beanstalk Environment</a>.</p> <p>If you create a custom IAM user policy to control permission to this operation, specify one of the following two virtual actions (or both) instead of the API operation name:</p> <dl> <dt>elasticbeanstalk:AddTags</dt> <dd> <p>Controls permission to call <code>UpdateTagsForResource</code> and pass a list of tags to add in the <code>TagsToAdd</code> parameter.</p> </dd> <dt>elasticbeanstalk:RemoveTags</dt> <dd> <p>Controls permission to call <code>UpdateTagsForResource</code> and pass a list of tag keys to remove in the <code>TagsToRemove</code> parameter.</p> </dd> </dl> <p>For details about creating a custom user policy, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/AWSHowTo.iam.managed-policies.html#AWSHowTo.iam.policies">Creating a Custom User Policy</a>.</p>
     fn update_tags_for_resource(
         &self,
         input: UpdateTagsForResourceMessage,
     ) -> RusotoFuture<(), UpdateTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateTagsForResource");
-        params.put("Version", "2010-12-01");
-        UpdateTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateTagsForResourceError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.update_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElasticBeanstalk has no handler for update_tags_for_resource"),
+        }
     }
-
-    /// <p>Takes a set of configuration settings and either a configuration template or environment, and determines whether those values are valid.</p> <p>This action returns a list of messages indicating any errors or warnings associated with the selection of option values.</p>
     fn validate_configuration_settings(
         &self,
         input: ValidateConfigurationSettingsMessage,
     ) -> RusotoFuture<ConfigurationSettingsValidationMessages, ValidateConfigurationSettingsError>
     {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ValidateConfigurationSettings");
-        params.put("Version", "2010-12-01");
-        ValidateConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ValidateConfigurationSettingsError::from_response(response))
-                }));
+        match self.validate_configuration_settings {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElasticBeanstalk has no handler for validate_configuration_settings")
             }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ConfigurationSettingsValidationMessages::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ConfigurationSettingsValidationMessagesDeserializer::deserialize(
-                        "ValidateConfigurationSettingsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        }
     }
 }
 
@@ -11902,7 +15957,10 @@ mod protocol_tests {
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client =
             ElasticBeanstalkClient::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = CheckDNSAvailabilityMessage::default();
+        let request = CheckDNSAvailabilityMessage {
+            cname_prefix: "xxxx".to_owned(),
+            ..Default::default()
+        };
         let result = client.check_dns_availability(request).sync();
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -11916,7 +15974,11 @@ mod protocol_tests {
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client =
             ElasticBeanstalkClient::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = CreateApplicationVersionMessage::default();
+        let request = CreateApplicationVersionMessage {
+            application_name: "x".to_owned(),
+            version_label: "x".to_owned(),
+            ..Default::default()
+        };
         let result = client.create_application_version(request).sync();
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -11930,7 +15992,10 @@ mod protocol_tests {
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client =
             ElasticBeanstalkClient::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = CreateApplicationMessage::default();
+        let request = CreateApplicationMessage {
+            application_name: "x".to_owned(),
+            ..Default::default()
+        };
         let result = client.create_application(request).sync();
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -11944,7 +16009,11 @@ mod protocol_tests {
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client =
             ElasticBeanstalkClient::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = CreateConfigurationTemplateMessage::default();
+        let request = CreateConfigurationTemplateMessage {
+            application_name: "x".to_owned(),
+            template_name: "x".to_owned(),
+            ..Default::default()
+        };
         let result = client.create_configuration_template(request).sync();
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -11958,7 +16027,10 @@ mod protocol_tests {
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client =
             ElasticBeanstalkClient::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = CreateEnvironmentMessage::default();
+        let request = CreateEnvironmentMessage {
+            application_name: "x".to_owned(),
+            ..Default::default()
+        };
         let result = client.create_environment(request).sync();
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -11986,7 +16058,10 @@ mod protocol_tests {
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client =
             ElasticBeanstalkClient::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = DeleteApplicationMessage::default();
+        let request = DeleteApplicationMessage {
+            application_name: "x".to_owned(),
+            ..Default::default()
+        };
         let result = client.delete_application(request).sync();
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -12112,7 +16187,11 @@ mod protocol_tests {
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client =
             ElasticBeanstalkClient::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = UpdateApplicationVersionMessage::default();
+        let request = UpdateApplicationVersionMessage {
+            application_name: "x".to_owned(),
+            version_label: "x".to_owned(),
+            ..Default::default()
+        };
         let result = client.update_application_version(request).sync();
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -12126,7 +16205,10 @@ mod protocol_tests {
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client =
             ElasticBeanstalkClient::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = UpdateApplicationMessage::default();
+        let request = UpdateApplicationMessage {
+            application_name: "x".to_owned(),
+            ..Default::default()
+        };
         let result = client.update_application(request).sync();
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
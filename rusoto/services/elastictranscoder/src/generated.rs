@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The file to be used as album art. There can be multiple artworks associated with an audio file, to a maximum of 20.</p> <p>To remove artwork or leave the artwork empty, you can either set <code>Artwork</code> to null, or set the <code>Merge Policy</code> to "Replace" and use an empty <code>Artwork</code> array.</p> <p>To pass through existing artwork unchanged, set the <code>Merge Policy</code> to "Prepend", "Append", or "Fallback", and use an empty <code>Artwork</code> array.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -57,6 +58,62 @@ pub struct Artwork {
     pub sizing_policy: Option<String>,
 }
 
+impl Validate for Artwork {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.album_art_format {
+            errors.check_pattern(
+                &field_path(path, "album_art_format"),
+                &value,
+                "(^jpg$)|(^png$)",
+            );
+        }
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+        if let Some(ref value) = self.input_key {
+            errors.check_length(
+                &field_path(path, "input_key"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "input_key"),
+                &value,
+                "(^.{1,1020}.jpg$)|(^.{1,1019}.jpeg$)|(^.{1,1020}.png$)",
+            );
+        }
+        if let Some(ref value) = self.max_height {
+            errors.check_pattern(
+                &field_path(path, "max_height"),
+                &value,
+                "(^auto$)|(^\\d{2,4}$)",
+            );
+        }
+        if let Some(ref value) = self.max_width {
+            errors.check_pattern(
+                &field_path(path, "max_width"),
+                &value,
+                "(^auto$)|(^\\d{2,4}$)",
+            );
+        }
+        if let Some(ref value) = self.padding_policy {
+            errors.check_pattern(
+                &field_path(path, "padding_policy"),
+                &value,
+                "(^Pad$)|(^NoPad$)",
+            );
+        }
+        if let Some(ref value) = self.sizing_policy {
+            errors.check_pattern(
+                &field_path(path, "sizing_policy"),
+                &value,
+                "(^Fit$)|(^Fill$)|(^Stretch$)|(^Keep$)|(^ShrinkToFit$)|(^ShrinkToFill$)",
+            );
+        }
+    }
+}
+
 /// <p>Options associated with your audio codec.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioCodecOptions {
@@ -78,6 +135,35 @@ pub struct AudioCodecOptions {
     pub signed: Option<String>,
 }
 
+impl Validate for AudioCodecOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bit_depth {
+            errors.check_pattern(
+                &field_path(path, "bit_depth"),
+                &value,
+                "(^8$)|(^16$)|(^24$)|(^32$)",
+            );
+        }
+        if let Some(ref value) = self.bit_order {
+            errors.check_pattern(&field_path(path, "bit_order"), &value, "(^LittleEndian$)");
+        }
+        if let Some(ref value) = self.profile {
+            errors.check_pattern(
+                &field_path(path, "profile"),
+                &value,
+                "(^auto$)|(^AAC-LC$)|(^HE-AAC$)|(^HE-AACv2$)",
+            );
+        }
+        if let Some(ref value) = self.signed {
+            errors.check_pattern(
+                &field_path(path, "signed"),
+                &value,
+                "(^Unsigned$)|(^Signed$)",
+            );
+        }
+    }
+}
+
 /// <p>Parameters required for transcoding audio.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioParameters {
@@ -107,17 +193,63 @@ pub struct AudioParameters {
     pub sample_rate: Option<String>,
 }
 
+impl Validate for AudioParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_packing_mode {
+            errors.check_pattern(
+                &field_path(path, "audio_packing_mode"),
+                &value,
+                "(^SingleTrack$)|(^OneChannelPerTrack$)|(^OneChannelPerTrackWithMosTo8Tracks$)",
+            );
+        }
+        if let Some(ref value) = self.bit_rate {
+            errors.check_pattern(&field_path(path, "bit_rate"), &value, "^\\d{1,3}$");
+        }
+        if let Some(ref value) = self.channels {
+            errors.check_pattern(
+                &field_path(path, "channels"),
+                &value,
+                "(^auto$)|(^0$)|(^1$)|(^2$)",
+            );
+        }
+        if let Some(ref value) = self.codec {
+            errors.check_pattern(
+                &field_path(path, "codec"),
+                &value,
+                "(^AAC$)|(^vorbis$)|(^mp3$)|(^mp2$)|(^pcm$)|(^flac$)",
+            );
+        }
+        if let Some(ref value) = self.codec_options {
+            value.validate_at(&field_path(path, "codec_options"), errors);
+        }
+        if let Some(ref value) = self.sample_rate {
+            errors.check_pattern(
+                &field_path(path, "sample_rate"),
+                &value,
+                "(^auto$)|(^22050$)|(^32000$)|(^44100$)|(^48000$)|(^96000$)|(^192000$)",
+            );
+        }
+    }
+}
+
 /// <p>The <code>CancelJobRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelJobRequest {
     /// <p>The identifier of the job that you want to cancel.</p> <p>To get a list of the jobs (including their <code>jobId</code>) that have a status of <code>Submitted</code>, use the <a>ListJobsByStatus</a> API action.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for CancelJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^\\d{13}-\\w{6}$");
+    }
+}
+
 /// <p>The response body contains a JSON object. If the job is successfully canceled, the value of <code>Success</code> is <code>true</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelJobResponse {}
 
 /// <p>The file format of the output captions. If you leave this value blank, Elastic Transcoder returns an error.</p>
@@ -137,6 +269,28 @@ pub struct CaptionFormat {
     pub pattern: Option<String>,
 }
 
+impl Validate for CaptionFormat {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+        if let Some(ref value) = self.format {
+            errors.check_pattern(
+                &field_path(path, "format"),
+                &value,
+                "(^mov-text$)|(^srt$)|(^scc$)|(^webvtt$)|(^dfxp$)|(^cea-708$)",
+            );
+        }
+        if let Some(ref value) = self.pattern {
+            errors.check_pattern(
+                &field_path(path, "pattern"),
+                &value,
+                "(^$)|(^.*\\{language\\}.*$)",
+            );
+        }
+    }
+}
+
 /// <p>A source file for the input sidecar captions used during the transcoding process.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaptionSource {
@@ -162,6 +316,41 @@ pub struct CaptionSource {
     pub time_offset: Option<String>,
 }
 
+impl Validate for CaptionSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.label {
+            errors.check_length(
+                &field_path(path, "label"),
+                value.chars().count(),
+                Some(1),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.language {
+            errors.check_length(
+                &field_path(path, "language"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.time_offset {
+            errors.check_pattern(&field_path(path, "time_offset"), &value, "(^[+-]?\\d{1,5}(\\.\\d{0,3})?$)|(^[+-]?([0-1]?[0-9]:|2[0-3]:)?([0-5]?[0-9]:)?[0-5]?[0-9](\\.\\d{0,3})?$)");
+        }
+    }
+}
+
 /// <p>The captions to be created, if any.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Captions {
@@ -171,6 +360,23 @@ pub struct Captions {
     pub caption_formats: Option<Vec<CaptionFormat>>,
 }
 
+impl Validate for Captions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.caption_formats {
+            errors.check_length(
+                &field_path(path, "caption_formats"),
+                value.len(),
+                None,
+                Some(4),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "caption_formats"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Settings for one clip in a composition. All jobs in a playlist must have the same clip settings.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Clip {
@@ -178,8 +384,17 @@ pub struct Clip {
     pub time_span: Option<TimeSpan>,
 }
 
+impl Validate for Clip {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.time_span {
+            value.validate_at(&field_path(path, "time_span"), errors);
+        }
+    }
+}
+
 /// <p>The <code>CreateJobOutput</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJobOutput {
     /// <p>Information about the album art that you want Elastic Transcoder to add to the file during transcoding. You can specify up to twenty album artworks for each output. Settings for each artwork must be defined in the job for the current output.</p>
     #[serde(rename = "AlbumArt")]
@@ -223,8 +438,64 @@ pub struct CreateJobOutput {
     pub watermarks: Option<Vec<JobWatermark>>,
 }
 
+impl Validate for CreateJobOutput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.album_art {
+            value.validate_at(&field_path(path, "album_art"), errors);
+        }
+        if let Some(ref value) = self.captions {
+            value.validate_at(&field_path(path, "captions"), errors);
+        }
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.preset_id {
+            errors.check_pattern(&field_path(path, "preset_id"), &value, "^\\d{13}-\\w{6}$");
+        }
+        if let Some(ref value) = self.rotate {
+            errors.check_pattern(
+                &field_path(path, "rotate"),
+                &value,
+                "(^auto$)|(^0$)|(^90$)|(^180$)|(^270$)",
+            );
+        }
+        if let Some(ref value) = self.segment_duration {
+            errors.check_pattern(
+                &field_path(path, "segment_duration"),
+                &value,
+                "^\\d{1,5}(\\.\\d{0,5})?$",
+            );
+        }
+        if let Some(ref value) = self.thumbnail_encryption {
+            value.validate_at(&field_path(path, "thumbnail_encryption"), errors);
+        }
+        if let Some(ref value) = self.thumbnail_pattern {
+            errors.check_pattern(
+                &field_path(path, "thumbnail_pattern"),
+                &value,
+                "(^$)|(^.*\\{count\\}.*$)",
+            );
+        }
+        if let Some(ref value) = self.watermarks {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "watermarks"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Information about the master playlist.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJobPlaylist {
     /// <p>The format of the output playlist. Valid formats include <code>HLSv3</code>, <code>HLSv4</code>, and <code>Smooth</code>.</p>
     #[serde(rename = "Format")]
@@ -248,8 +519,47 @@ pub struct CreateJobPlaylist {
     pub play_ready_drm: Option<PlayReadyDrm>,
 }
 
+impl Validate for CreateJobPlaylist {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.format {
+            errors.check_pattern(
+                &field_path(path, "format"),
+                &value,
+                "(^HLSv3$)|(^HLSv4$)|(^Smooth$)|(^MPEG-DASH$)",
+            );
+        }
+        if let Some(ref value) = self.hls_content_protection {
+            value.validate_at(&field_path(path, "hls_content_protection"), errors);
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.output_keys {
+            errors.check_length(
+                &field_path(path, "output_keys"),
+                value.len(),
+                None,
+                Some(30),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "output_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            }
+        }
+        if let Some(ref value) = self.play_ready_drm {
+            value.validate_at(&field_path(path, "play_ready_drm"), errors);
+        }
+    }
+}
+
 /// <p>The <code>CreateJobRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJobRequest {
     /// <p>A section of the request body that provides information about the file that is being transcoded.</p>
     #[serde(rename = "Input")]
@@ -284,9 +594,54 @@ pub struct CreateJobRequest {
     pub user_metadata: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.input {
+            value.validate_at(&field_path(path, "input"), errors);
+        }
+        if let Some(ref value) = self.inputs {
+            errors.check_length(&field_path(path, "inputs"), value.len(), None, Some(200));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "inputs"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.output {
+            value.validate_at(&field_path(path, "output"), errors);
+        }
+        if let Some(ref value) = self.output_key_prefix {
+            errors.check_length(
+                &field_path(path, "output_key_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.outputs {
+            errors.check_length(&field_path(path, "outputs"), value.len(), None, Some(30));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "outputs"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "^\\d{13}-\\w{6}$",
+        );
+        if let Some(ref value) = self.playlists {
+            errors.check_length(&field_path(path, "playlists"), value.len(), None, Some(30));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "playlists"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>The CreateJobResponse structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateJobResponse {
     /// <p>A section of the response body that provides information about the job that is created.</p>
     #[serde(rename = "Job")]
@@ -296,6 +651,7 @@ pub struct CreateJobResponse {
 
 /// <p>The <code>CreatePipelineRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePipelineRequest {
     /// <p>The AWS Key Management Service (AWS KMS) key that you want to use with this pipeline.</p> <p>If you use either <code>s3</code> or <code>s3-aws-kms</code> as your <code>Encryption:Mode</code>, you don't need to provide a key with your job because a default key, known as an AWS-KMS key, is created for you automatically. You need to provide an AWS-KMS key only if you want to use a non-default AWS-KMS key, or if you are using an <code>Encryption:Mode</code> of <code>aes-cbc-pkcs7</code>, <code>aes-ctr</code>, or <code>aes-gcm</code>.</p>
     #[serde(rename = "AwsKmsKeyArn")]
@@ -328,9 +684,54 @@ pub struct CreatePipelineRequest {
     pub thumbnail_config: Option<PipelineOutputConfig>,
 }
 
+impl Validate for CreatePipelineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aws_kms_key_arn {
+            errors.check_length(
+                &field_path(path, "aws_kms_key_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.content_config {
+            value.validate_at(&field_path(path, "content_config"), errors);
+        }
+        errors.check_pattern(
+            &field_path(path, "input_bucket"),
+            &self.input_bucket,
+            "^(\\w|\\.|-){1,255}$",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        if let Some(ref value) = self.notifications {
+            value.validate_at(&field_path(path, "notifications"), errors);
+        }
+        if let Some(ref value) = self.output_bucket {
+            errors.check_pattern(
+                &field_path(path, "output_bucket"),
+                &value,
+                "^(\\w|\\.|-){1,255}$",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "role"),
+            &self.role,
+            "^arn:aws:iam::\\w{12}:role/.+$",
+        );
+        if let Some(ref value) = self.thumbnail_config {
+            value.validate_at(&field_path(path, "thumbnail_config"), errors);
+        }
+    }
+}
+
 /// <p>When you create a pipeline, Elastic Transcoder returns the values that you specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePipelineResponse {
     /// <p>A section of the response body that provides information about the pipeline that is created.</p>
     #[serde(rename = "Pipeline")]
@@ -344,6 +745,7 @@ pub struct CreatePipelineResponse {
 
 /// <p>The <code>CreatePresetRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePresetRequest {
     /// <p>A section of the request body that specifies the audio parameters.</p>
     #[serde(rename = "Audio")]
@@ -369,9 +771,38 @@ pub struct CreatePresetRequest {
     pub video: Option<VideoParameters>,
 }
 
+impl Validate for CreatePresetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio {
+            value.validate_at(&field_path(path, "audio"), errors);
+        }
+        errors.check_pattern(&field_path(path, "container"), &self.container, "(^mp4$)|(^ts$)|(^webm$)|(^mp3$)|(^flac$)|(^oga$)|(^ogg$)|(^fmp4$)|(^mpg$)|(^flv$)|(^gif$)|(^mxf$)|(^wav$)|(^mp2$)");
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        if let Some(ref value) = self.thumbnails {
+            value.validate_at(&field_path(path, "thumbnails"), errors);
+        }
+        if let Some(ref value) = self.video {
+            value.validate_at(&field_path(path, "video"), errors);
+        }
+    }
+}
+
 /// <p>The <code>CreatePresetResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePresetResponse {
     /// <p>A section of the response body that provides information about the preset that is created.</p>
     #[serde(rename = "Preset")]
@@ -385,28 +816,42 @@ pub struct CreatePresetResponse {
 
 /// <p>The <code>DeletePipelineRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePipelineRequest {
     /// <p>The identifier of the pipeline that you want to delete.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for DeletePipelineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^\\d{13}-\\w{6}$");
+    }
+}
+
 /// <p>The <code>DeletePipelineResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeletePipelineResponse {}
 
 /// <p>The <code>DeletePresetRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePresetRequest {
     /// <p>The identifier of the preset for which you want to get detailed information.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for DeletePresetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^\\d{13}-\\w{6}$");
+    }
+}
+
 /// <p>The <code>DeletePresetResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeletePresetResponse {}
 
 /// <p>The detected properties of the input file. Elastic Transcoder identifies these values from the input file.</p>
@@ -434,6 +879,18 @@ pub struct DetectedProperties {
     pub width: Option<i64>,
 }
 
+impl Validate for DetectedProperties {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.frame_rate {
+            errors.check_pattern(
+                &field_path(path, "frame_rate"),
+                &value,
+                "^\\d{1,5}(\\.\\d{0,5})?$",
+            );
+        }
+    }
+}
+
 /// <p>The encryption settings, if any, that are used for decrypting your input files or encrypting your output files. If your input file is encrypted, you must specify the mode that Elastic Transcoder uses to decrypt your file, otherwise you must specify the mode you want Elastic Transcoder to use to encrypt your output files.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Encryption {
@@ -455,6 +912,40 @@ pub struct Encryption {
     pub mode: Option<String>,
 }
 
+impl Validate for Encryption {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.initialization_vector {
+            errors.check_length(
+                &field_path(path, "initialization_vector"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.key {
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "^$|(^(?:[A-Za-z0-9\\+/]{4})*(?:[A-Za-z0-9\\+/]{2}==|[A-Za-z0-9\\+/]{3}=)?$)",
+            );
+        }
+        if let Some(ref value) = self.key_md_5 {
+            errors.check_pattern(
+                &field_path(path, "key_md_5"),
+                &value,
+                "^$|(^(?:[A-Za-z0-9\\+/]{4})*(?:[A-Za-z0-9\\+/]{2}==|[A-Za-z0-9\\+/]{3}=)?$)",
+            );
+        }
+        if let Some(ref value) = self.mode {
+            errors.check_pattern(
+                &field_path(path, "mode"),
+                &value,
+                "(^s3$)|(^s3-aws-kms$)|(^aes-cbc-pkcs7$)|(^aes-ctr$)|(^aes-gcm$)",
+            );
+        }
+    }
+}
+
 /// <p>The HLS content protection settings, if any, that you want Elastic Transcoder to apply to your output files.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsContentProtection {
@@ -484,6 +975,51 @@ pub struct HlsContentProtection {
     pub method: Option<String>,
 }
 
+impl Validate for HlsContentProtection {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.initialization_vector {
+            errors.check_length(
+                &field_path(path, "initialization_vector"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.key {
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "^$|(^(?:[A-Za-z0-9\\+/]{4})*(?:[A-Za-z0-9\\+/]{2}==|[A-Za-z0-9\\+/]{3}=)?$)",
+            );
+        }
+        if let Some(ref value) = self.key_md_5 {
+            errors.check_pattern(
+                &field_path(path, "key_md_5"),
+                &value,
+                "^$|(^(?:[A-Za-z0-9\\+/]{4})*(?:[A-Za-z0-9\\+/]{2}==|[A-Za-z0-9\\+/]{3}=)?$)",
+            );
+        }
+        if let Some(ref value) = self.key_storage_policy {
+            errors.check_pattern(
+                &field_path(path, "key_storage_policy"),
+                &value,
+                "(^NoStore$)|(^WithVariantPlaylists$)",
+            );
+        }
+        if let Some(ref value) = self.license_acquisition_url {
+            errors.check_length(
+                &field_path(path, "license_acquisition_url"),
+                value.chars().count(),
+                None,
+                Some(512),
+            );
+        }
+        if let Some(ref value) = self.method {
+            errors.check_pattern(&field_path(path, "method"), &value, "(^aes-128$)");
+        }
+    }
+}
+
 /// <p>The captions to be created, if any.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputCaptions {
@@ -497,9 +1033,33 @@ pub struct InputCaptions {
     pub merge_policy: Option<String>,
 }
 
+impl Validate for InputCaptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.caption_sources {
+            errors.check_length(
+                &field_path(path, "caption_sources"),
+                value.len(),
+                None,
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "caption_sources"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.merge_policy {
+            errors.check_pattern(
+                &field_path(path, "merge_policy"),
+                &value,
+                "(^MergeOverride$)|(^MergeRetain$)|(^Override$)",
+            );
+        }
+    }
+}
+
 /// <p>A section of the response body that provides information about the job that is created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Job {
     /// <p>The Amazon Resource Name (ARN) for the job.</p>
     #[serde(rename = "Arn")]
@@ -564,6 +1124,24 @@ pub struct JobAlbumArt {
     pub merge_policy: Option<String>,
 }
 
+impl Validate for JobAlbumArt {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.artwork {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "artwork"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.merge_policy {
+            errors.check_pattern(
+                &field_path(path, "merge_policy"),
+                &value,
+                "(^Replace$)|(^Prepend$)|(^Append$)|(^Fallback$)",
+            );
+        }
+    }
+}
+
 /// <p>Information about the file that you're transcoding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JobInput {
@@ -609,9 +1187,65 @@ pub struct JobInput {
     pub time_span: Option<TimeSpan>,
 }
 
+impl Validate for JobInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aspect_ratio {
+            errors.check_pattern(
+                &field_path(path, "aspect_ratio"),
+                &value,
+                "(^auto$)|(^1:1$)|(^4:3$)|(^3:2$)|(^16:9$)",
+            );
+        }
+        if let Some(ref value) = self.container {
+            errors.check_pattern(&field_path(path, "container"), &value, "(^auto$)|(^3gp$)|(^asf$)|(^avi$)|(^divx$)|(^flv$)|(^mkv$)|(^mov$)|(^mp4$)|(^mpeg$)|(^mpeg-ps$)|(^mpeg-ts$)|(^mxf$)|(^ogg$)|(^ts$)|(^vob$)|(^wav$)|(^webm$)|(^mp3$)|(^m4a$)|(^aac$)");
+        }
+        if let Some(ref value) = self.detected_properties {
+            value.validate_at(&field_path(path, "detected_properties"), errors);
+        }
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+        if let Some(ref value) = self.frame_rate {
+            errors.check_pattern(
+                &field_path(path, "frame_rate"),
+                &value,
+                "(^auto$)|(^10$)|(^15$)|(^23.97$)|(^24$)|(^25$)|(^29.97$)|(^30$)|(^50$)|(^60$)",
+            );
+        }
+        if let Some(ref value) = self.input_captions {
+            value.validate_at(&field_path(path, "input_captions"), errors);
+        }
+        if let Some(ref value) = self.interlaced {
+            errors.check_pattern(
+                &field_path(path, "interlaced"),
+                &value,
+                "(^auto$)|(^true$)|(^false$)",
+            );
+        }
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.resolution {
+            errors.check_pattern(
+                &field_path(path, "resolution"),
+                &value,
+                "(^auto$)|(^\\d{1,5}x\\d{1,5}$)",
+            );
+        }
+        if let Some(ref value) = self.time_span {
+            value.validate_at(&field_path(path, "time_span"), errors);
+        }
+    }
+}
+
 /// <p><important> <p>Outputs recommended instead.</p> </important> <p>If you specified one output for a job, information about that output. If you specified multiple outputs for a job, the <code>Output</code> object lists information about the first output. This duplicates the information that is listed for the first output in the <code>Outputs</code> object.</p></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobOutput {
     /// <p>The album art to be associated with the output file, if any.</p>
     #[serde(rename = "AlbumArt")]
@@ -712,8 +1346,38 @@ pub struct JobWatermark {
     pub preset_watermark_id: Option<String>,
 }
 
+impl Validate for JobWatermark {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+        if let Some(ref value) = self.input_key {
+            errors.check_length(
+                &field_path(path, "input_key"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "input_key"),
+                &value,
+                "(^.{1,1020}.jpg$)|(^.{1,1019}.jpeg$)|(^.{1,1020}.png$)",
+            );
+        }
+        if let Some(ref value) = self.preset_watermark_id {
+            errors.check_length(
+                &field_path(path, "preset_watermark_id"),
+                value.chars().count(),
+                Some(1),
+                Some(40),
+            );
+        }
+    }
+}
+
 /// <p>The <code>ListJobsByPipelineRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsByPipelineRequest {
     /// <p> To list jobs in chronological order by the date and time that they were submitted, enter <code>true</code>. To list jobs in reverse chronological order, enter <code>false</code>. </p>
     #[serde(rename = "Ascending")]
@@ -728,9 +1392,25 @@ pub struct ListJobsByPipelineRequest {
     pub pipeline_id: String,
 }
 
+impl Validate for ListJobsByPipelineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ascending {
+            errors.check_pattern(&field_path(path, "ascending"), &value, "(^true$)|(^false$)");
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_pattern(&field_path(path, "page_token"), &value, "^\\d{13}-\\w{6}$");
+        }
+        errors.check_pattern(
+            &field_path(path, "pipeline_id"),
+            &self.pipeline_id,
+            "^\\d{13}-\\w{6}$",
+        );
+    }
+}
+
 /// <p>The <code>ListJobsByPipelineResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJobsByPipelineResponse {
     /// <p>An array of <code>Job</code> objects that are in the specified pipeline.</p>
     #[serde(rename = "Jobs")]
@@ -744,6 +1424,7 @@ pub struct ListJobsByPipelineResponse {
 
 /// <p>The <code>ListJobsByStatusRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsByStatusRequest {
     /// <p> To list jobs in chronological order by the date and time that they were submitted, enter <code>true</code>. To list jobs in reverse chronological order, enter <code>false</code>. </p>
     #[serde(rename = "Ascending")]
@@ -758,9 +1439,25 @@ pub struct ListJobsByStatusRequest {
     pub status: String,
 }
 
+impl Validate for ListJobsByStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ascending {
+            errors.check_pattern(&field_path(path, "ascending"), &value, "(^true$)|(^false$)");
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_pattern(&field_path(path, "page_token"), &value, "^\\d{13}-\\w{6}$");
+        }
+        errors.check_pattern(
+            &field_path(path, "status"),
+            &self.status,
+            "(^Submitted$)|(^Progressing$)|(^Complete$)|(^Canceled$)|(^Error$)",
+        );
+    }
+}
+
 /// <p> The <code>ListJobsByStatusResponse</code> structure. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJobsByStatusResponse {
     /// <p>An array of <code>Job</code> objects that have the specified status.</p>
     #[serde(rename = "Jobs")]
@@ -774,6 +1471,7 @@ pub struct ListJobsByStatusResponse {
 
 /// <p>The <code>ListPipelineRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPipelinesRequest {
     /// <p>To list pipelines in chronological order by the date and time that they were created, enter <code>true</code>. To list pipelines in reverse chronological order, enter <code>false</code>.</p>
     #[serde(rename = "Ascending")]
@@ -785,9 +1483,20 @@ pub struct ListPipelinesRequest {
     pub page_token: Option<String>,
 }
 
+impl Validate for ListPipelinesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ascending {
+            errors.check_pattern(&field_path(path, "ascending"), &value, "(^true$)|(^false$)");
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_pattern(&field_path(path, "page_token"), &value, "^\\d{13}-\\w{6}$");
+        }
+    }
+}
+
 /// <p>A list of the pipelines associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPipelinesResponse {
     /// <p>A value that you use to access the second and subsequent pages of results, if any. When the pipelines fit on one page or when you've reached the last page of results, the value of <code>NextPageToken</code> is <code>null</code>.</p>
     #[serde(rename = "NextPageToken")]
@@ -801,6 +1510,7 @@ pub struct ListPipelinesResponse {
 
 /// <p>The <code>ListPresetsRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPresetsRequest {
     /// <p>To list presets in chronological order by the date and time that they were created, enter <code>true</code>. To list presets in reverse chronological order, enter <code>false</code>.</p>
     #[serde(rename = "Ascending")]
@@ -812,9 +1522,20 @@ pub struct ListPresetsRequest {
     pub page_token: Option<String>,
 }
 
+impl Validate for ListPresetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ascending {
+            errors.check_pattern(&field_path(path, "ascending"), &value, "(^true$)|(^false$)");
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_pattern(&field_path(path, "page_token"), &value, "^\\d{13}-\\w{6}$");
+        }
+    }
+}
+
 /// <p>The <code>ListPresetsResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPresetsResponse {
     /// <p>A value that you use to access the second and subsequent pages of results, if any. When the presets fit on one page or when you've reached the last page of results, the value of <code>NextPageToken</code> is <code>null</code>.</p>
     #[serde(rename = "NextPageToken")]
@@ -847,6 +1568,39 @@ pub struct Notifications {
     pub warning: Option<String>,
 }
 
+impl Validate for Notifications {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.completed {
+            errors.check_pattern(
+                &field_path(path, "completed"),
+                &value,
+                "(^$)|(^arn:aws:sns:.*:\\w{12}:.+$)",
+            );
+        }
+        if let Some(ref value) = self.error {
+            errors.check_pattern(
+                &field_path(path, "error"),
+                &value,
+                "(^$)|(^arn:aws:sns:.*:\\w{12}:.+$)",
+            );
+        }
+        if let Some(ref value) = self.progressing {
+            errors.check_pattern(
+                &field_path(path, "progressing"),
+                &value,
+                "(^$)|(^arn:aws:sns:.*:\\w{12}:.+$)",
+            );
+        }
+        if let Some(ref value) = self.warning {
+            errors.check_pattern(
+                &field_path(path, "warning"),
+                &value,
+                "(^$)|(^arn:aws:sns:.*:\\w{12}:.+$)",
+            );
+        }
+    }
+}
+
 /// <p>The <code>Permission</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Permission {
@@ -864,9 +1618,40 @@ pub struct Permission {
     pub grantee_type: Option<String>,
 }
 
+impl Validate for Permission {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.access {
+            errors.check_length(&field_path(path, "access"), value.len(), None, Some(30));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "access"), i);
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "(^FullControl$)|(^Read$)|(^ReadAcp$)|(^WriteAcp$)",
+                );
+            }
+        }
+        if let Some(ref value) = self.grantee {
+            errors.check_length(
+                &field_path(path, "grantee"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.grantee_type {
+            errors.check_pattern(
+                &field_path(path, "grantee_type"),
+                &value,
+                "(^Canonical$)|(^Email$)|(^Group$)",
+            );
+        }
+    }
+}
+
 /// <p>The pipeline (queue) that is used to manage jobs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Pipeline {
     /// <p>The Amazon Resource Name (ARN) for the pipeline.</p>
     #[serde(rename = "Arn")]
@@ -931,6 +1716,33 @@ pub struct PipelineOutputConfig {
     pub storage_class: Option<String>,
 }
 
+impl Validate for PipelineOutputConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket {
+            errors.check_pattern(&field_path(path, "bucket"), &value, "^(\\w|\\.|-){1,255}$");
+        }
+        if let Some(ref value) = self.permissions {
+            errors.check_length(
+                &field_path(path, "permissions"),
+                value.len(),
+                None,
+                Some(30),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "permissions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.storage_class {
+            errors.check_pattern(
+                &field_path(path, "storage_class"),
+                &value,
+                "(^ReducedRedundancy$)|(^Standard$)",
+            );
+        }
+    }
+}
+
 /// <p>The PlayReady DRM settings, if any, that you want Elastic Transcoder to apply to the output files associated with this playlist.</p> <p>PlayReady DRM encrypts your media files using <code>aes-ctr</code> encryption.</p> <p>If you use DRM for an <code>HLSv3</code> playlist, your outputs must have a master playlist.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayReadyDrm {
@@ -960,9 +1772,54 @@ pub struct PlayReadyDrm {
     pub license_acquisition_url: Option<String>,
 }
 
+impl Validate for PlayReadyDrm {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.format {
+            errors.check_pattern(
+                &field_path(path, "format"),
+                &value,
+                "(^microsoft$)|(^discretix-3.0$)",
+            );
+        }
+        if let Some(ref value) = self.initialization_vector {
+            errors.check_length(
+                &field_path(path, "initialization_vector"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.key {
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "(^(?:[A-Za-z0-9\\+/]{4})*(?:[A-Za-z0-9\\+/]{2}==|[A-Za-z0-9\\+/]{3}=)?$)",
+            );
+        }
+        if let Some(ref value) = self.key_id {
+            errors.check_pattern(&field_path(path, "key_id"), &value, "(^[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{12}$)|(^[0-9A-Fa-f]{32}$)");
+        }
+        if let Some(ref value) = self.key_md_5 {
+            errors.check_pattern(
+                &field_path(path, "key_md_5"),
+                &value,
+                "(^(?:[A-Za-z0-9\\+/]{4})*(?:[A-Za-z0-9\\+/]{2}==|[A-Za-z0-9\\+/]{3}=)?$)",
+            );
+        }
+        if let Some(ref value) = self.license_acquisition_url {
+            errors.check_length(
+                &field_path(path, "license_acquisition_url"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+        }
+    }
+}
+
 /// <p> Use Only for Fragmented MP4 or MPEG-TS Outputs. If you specify a preset for which the value of Container is <code>fmp4</code> (Fragmented MP4) or <code>ts</code> (MPEG-TS), Playlists contains information about the master playlists that you want Elastic Transcoder to create. We recommend that you create only one master playlist per output format. The maximum number of master playlists in a job is 30. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Playlist {
     /// <p>The format of the output playlist. Valid formats include <code>HLSv3</code>, <code>HLSv4</code>, and <code>Smooth</code>.</p>
     #[serde(rename = "Format")]
@@ -996,7 +1853,7 @@ pub struct Playlist {
 
 /// <p>Presets are templates that contain most of the settings for transcoding media files from one format to another. Elastic Transcoder includes some default presets for common formats, for example, several iPod and iPhone versions. You can also create your own presets for formats that aren't included among the default presets. You specify which preset you want to use when you create a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Preset {
     /// <p>The Amazon Resource Name (ARN) for the preset.</p>
     #[serde(rename = "Arn")]
@@ -1081,17 +1938,96 @@ pub struct PresetWatermark {
     pub vertical_offset: Option<String>,
 }
 
+impl Validate for PresetWatermark {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.horizontal_align {
+            errors.check_pattern(
+                &field_path(path, "horizontal_align"),
+                &value,
+                "(^Left$)|(^Right$)|(^Center$)",
+            );
+        }
+        if let Some(ref value) = self.horizontal_offset {
+            errors.check_pattern(
+                &field_path(path, "horizontal_offset"),
+                &value,
+                "(^\\d{1,3}(\\.\\d{0,5})?%$)|(^\\d{1,4}?px$)",
+            );
+        }
+        if let Some(ref value) = self.id {
+            errors.check_length(
+                &field_path(path, "id"),
+                value.chars().count(),
+                Some(1),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.max_height {
+            errors.check_pattern(
+                &field_path(path, "max_height"),
+                &value,
+                "(^\\d{1,3}(\\.\\d{0,5})?%$)|(^\\d{1,4}?px$)",
+            );
+        }
+        if let Some(ref value) = self.max_width {
+            errors.check_pattern(
+                &field_path(path, "max_width"),
+                &value,
+                "(^\\d{1,3}(\\.\\d{0,5})?%$)|(^\\d{1,4}?px$)",
+            );
+        }
+        if let Some(ref value) = self.opacity {
+            errors.check_pattern(
+                &field_path(path, "opacity"),
+                &value,
+                "^\\d{1,3}(\\.\\d{0,20})?$",
+            );
+        }
+        if let Some(ref value) = self.sizing_policy {
+            errors.check_pattern(
+                &field_path(path, "sizing_policy"),
+                &value,
+                "(^Fit$)|(^Stretch$)|(^ShrinkToFit$)",
+            );
+        }
+        if let Some(ref value) = self.target {
+            errors.check_pattern(&field_path(path, "target"), &value, "(^Content$)|(^Frame$)");
+        }
+        if let Some(ref value) = self.vertical_align {
+            errors.check_pattern(
+                &field_path(path, "vertical_align"),
+                &value,
+                "(^Top$)|(^Bottom$)|(^Center$)",
+            );
+        }
+        if let Some(ref value) = self.vertical_offset {
+            errors.check_pattern(
+                &field_path(path, "vertical_offset"),
+                &value,
+                "(^\\d{1,3}(\\.\\d{0,5})?%$)|(^\\d{1,4}?px$)",
+            );
+        }
+    }
+}
+
 /// <p>The <code>ReadJobRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReadJobRequest {
     /// <p>The identifier of the job for which you want to get detailed information.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for ReadJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^\\d{13}-\\w{6}$");
+    }
+}
+
 /// <p>The <code>ReadJobResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReadJobResponse {
     /// <p>A section of the response body that provides information about the job.</p>
     #[serde(rename = "Job")]
@@ -1101,15 +2037,22 @@ pub struct ReadJobResponse {
 
 /// <p>The <code>ReadPipelineRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReadPipelineRequest {
     /// <p>The identifier of the pipeline to read.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for ReadPipelineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^\\d{13}-\\w{6}$");
+    }
+}
+
 /// <p>The <code>ReadPipelineResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReadPipelineResponse {
     /// <p>A section of the response body that provides information about the pipeline.</p>
     #[serde(rename = "Pipeline")]
@@ -1123,15 +2066,22 @@ pub struct ReadPipelineResponse {
 
 /// <p>The <code>ReadPresetRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReadPresetRequest {
     /// <p>The identifier of the preset for which you want to get detailed information.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for ReadPresetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^\\d{13}-\\w{6}$");
+    }
+}
+
 /// <p>The <code>ReadPresetResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReadPresetResponse {
     /// <p>A section of the response body that provides information about the preset.</p>
     #[serde(rename = "Preset")]
@@ -1141,6 +2091,7 @@ pub struct ReadPresetResponse {
 
 /// <p> The <code>TestRoleRequest</code> structure. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestRoleRequest {
     /// <p>The Amazon S3 bucket that contains media files to be transcoded. The action attempts to read from this bucket.</p>
     #[serde(rename = "InputBucket")]
@@ -1156,9 +2107,39 @@ pub struct TestRoleRequest {
     pub topics: Vec<String>,
 }
 
+impl Validate for TestRoleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "input_bucket"),
+            &self.input_bucket,
+            "^(\\w|\\.|-){1,255}$",
+        );
+        errors.check_pattern(
+            &field_path(path, "output_bucket"),
+            &self.output_bucket,
+            "^(\\w|\\.|-){1,255}$",
+        );
+        errors.check_pattern(
+            &field_path(path, "role"),
+            &self.role,
+            "^arn:aws:iam::\\w{12}:role/.+$",
+        );
+        errors.check_length(
+            &field_path(path, "topics"),
+            self.topics.len(),
+            None,
+            Some(30),
+        );
+        for (i, item) in self.topics.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "topics"), i);
+            errors.check_pattern(&item_path, &item, "(^$)|(^arn:aws:sns:.*:\\w{12}:.+$)");
+        }
+    }
+}
+
 /// <p>The <code>TestRoleResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TestRoleResponse {
     /// <p>If the <code>Success</code> element contains <code>false</code>, this value is an array of one or more error messages that were generated during the test process.</p>
     #[serde(rename = "Messages")]
@@ -1207,22 +2188,86 @@ pub struct Thumbnails {
     pub sizing_policy: Option<String>,
 }
 
-/// <p>Settings that determine when a clip begins and how long it lasts.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct TimeSpan {
-    /// <p>The duration of the clip. The format can be either HH:mm:ss.SSS (maximum value: 23:59:59.999; SSS is thousandths of a second) or sssss.SSS (maximum value: 86399.999). If you don't specify a value, Elastic Transcoder creates an output file from StartTime to the end of the file.</p> <p>If you specify a value longer than the duration of the input file, Elastic Transcoder transcodes the file and returns a warning message.</p>
-    #[serde(rename = "Duration")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration: Option<String>,
-    /// <p>The place in the input file where you want a clip to start. The format can be either HH:mm:ss.SSS (maximum value: 23:59:59.999; SSS is thousandths of a second) or sssss.SSS (maximum value: 86399.999). If you don't specify a value, Elastic Transcoder starts at the beginning of the input file.</p>
-    #[serde(rename = "StartTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl Validate for Thumbnails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aspect_ratio {
+            errors.check_pattern(
+                &field_path(path, "aspect_ratio"),
+                &value,
+                "(^auto$)|(^1:1$)|(^4:3$)|(^3:2$)|(^16:9$)",
+            );
+        }
+        if let Some(ref value) = self.format {
+            errors.check_pattern(&field_path(path, "format"), &value, "(^jpg$)|(^png$)");
+        }
+        if let Some(ref value) = self.interval {
+            errors.check_pattern(&field_path(path, "interval"), &value, "^\\d{1,5}$");
+        }
+        if let Some(ref value) = self.max_height {
+            errors.check_pattern(
+                &field_path(path, "max_height"),
+                &value,
+                "(^auto$)|(^\\d{2,4}$)",
+            );
+        }
+        if let Some(ref value) = self.max_width {
+            errors.check_pattern(
+                &field_path(path, "max_width"),
+                &value,
+                "(^auto$)|(^\\d{2,4}$)",
+            );
+        }
+        if let Some(ref value) = self.padding_policy {
+            errors.check_pattern(
+                &field_path(path, "padding_policy"),
+                &value,
+                "(^Pad$)|(^NoPad$)",
+            );
+        }
+        if let Some(ref value) = self.resolution {
+            errors.check_pattern(
+                &field_path(path, "resolution"),
+                &value,
+                "^\\d{1,5}x\\d{1,5}$",
+            );
+        }
+        if let Some(ref value) = self.sizing_policy {
+            errors.check_pattern(
+                &field_path(path, "sizing_policy"),
+                &value,
+                "(^Fit$)|(^Fill$)|(^Stretch$)|(^Keep$)|(^ShrinkToFit$)|(^ShrinkToFill$)",
+            );
+        }
+    }
+}
+
+/// <p>Settings that determine when a clip begins and how long it lasts.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeSpan {
+    /// <p>The duration of the clip. The format can be either HH:mm:ss.SSS (maximum value: 23:59:59.999; SSS is thousandths of a second) or sssss.SSS (maximum value: 86399.999). If you don't specify a value, Elastic Transcoder creates an output file from StartTime to the end of the file.</p> <p>If you specify a value longer than the duration of the input file, Elastic Transcoder transcodes the file and returns a warning message.</p>
+    #[serde(rename = "Duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    /// <p>The place in the input file where you want a clip to start. The format can be either HH:mm:ss.SSS (maximum value: 23:59:59.999; SSS is thousandths of a second) or sssss.SSS (maximum value: 86399.999). If you don't specify a value, Elastic Transcoder starts at the beginning of the input file.</p>
+    #[serde(rename = "StartTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub start_time: Option<String>,
 }
 
+impl Validate for TimeSpan {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration {
+            errors.check_pattern(&field_path(path, "duration"), &value, "(^\\d{1,5}(\\.\\d{0,3})?$)|(^([0-1]?[0-9]:|2[0-3]:)?([0-5]?[0-9]:)?[0-5]?[0-9](\\.\\d{0,3})?$)");
+        }
+        if let Some(ref value) = self.start_time {
+            errors.check_pattern(&field_path(path, "start_time"), &value, "(^\\d{1,5}(\\.\\d{0,3})?$)|(^([0-1]?[0-9]:|2[0-3]:)?([0-5]?[0-9]:)?[0-5]?[0-9](\\.\\d{0,3})?$)");
+        }
+    }
+}
+
 /// <p>Details about the timing of a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Timing {
     /// <p>The time the job finished transcoding, in epoch milliseconds.</p>
     #[serde(rename = "FinishTimeMillis")]
@@ -1240,6 +2285,7 @@ pub struct Timing {
 
 /// <p>The <code>UpdatePipelineNotificationsRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePipelineNotificationsRequest {
     /// <p>The identifier of the pipeline for which you want to change notification settings.</p>
     #[serde(rename = "Id")]
@@ -1249,9 +2295,17 @@ pub struct UpdatePipelineNotificationsRequest {
     pub notifications: Notifications,
 }
 
+impl Validate for UpdatePipelineNotificationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^\\d{13}-\\w{6}$");
+        self.notifications
+            .validate_at(&field_path(path, "notifications"), errors);
+    }
+}
+
 /// <p>The <code>UpdatePipelineNotificationsResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePipelineNotificationsResponse {
     /// <p>A section of the response body that provides information about the pipeline associated with this notification.</p>
     #[serde(rename = "Pipeline")]
@@ -1261,6 +2315,7 @@ pub struct UpdatePipelineNotificationsResponse {
 
 /// <p>The <code>UpdatePipelineRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePipelineRequest {
     /// <p>The AWS Key Management Service (AWS KMS) key that you want to use with this pipeline.</p> <p>If you use either <code>s3</code> or <code>s3-aws-kms</code> as your <code>Encryption:Mode</code>, you don't need to provide a key with your job because a default key, known as an AWS-KMS key, is created for you automatically. You need to provide an AWS-KMS key only if you want to use a non-default AWS-KMS key, or if you are using an <code>Encryption:Mode</code> of <code>aes-cbc-pkcs7</code>, <code>aes-ctr</code>, or <code>aes-gcm</code>.</p>
     #[serde(rename = "AwsKmsKeyArn")]
@@ -1295,9 +2350,54 @@ pub struct UpdatePipelineRequest {
     pub thumbnail_config: Option<PipelineOutputConfig>,
 }
 
+impl Validate for UpdatePipelineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aws_kms_key_arn {
+            errors.check_length(
+                &field_path(path, "aws_kms_key_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.content_config {
+            value.validate_at(&field_path(path, "content_config"), errors);
+        }
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^\\d{13}-\\w{6}$");
+        if let Some(ref value) = self.input_bucket {
+            errors.check_pattern(
+                &field_path(path, "input_bucket"),
+                &value,
+                "^(\\w|\\.|-){1,255}$",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(40),
+            );
+        }
+        if let Some(ref value) = self.notifications {
+            value.validate_at(&field_path(path, "notifications"), errors);
+        }
+        if let Some(ref value) = self.role {
+            errors.check_pattern(
+                &field_path(path, "role"),
+                &value,
+                "^arn:aws:iam::\\w{12}:role/.+$",
+            );
+        }
+        if let Some(ref value) = self.thumbnail_config {
+            value.validate_at(&field_path(path, "thumbnail_config"), errors);
+        }
+    }
+}
+
 /// <p>When you update a pipeline, Elastic Transcoder returns the values that you specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePipelineResponse {
     /// <p>The pipeline updated by this <code>UpdatePipelineResponse</code> call.</p>
     #[serde(rename = "Pipeline")]
@@ -1311,6 +2411,7 @@ pub struct UpdatePipelineResponse {
 
 /// <p>The <code>UpdatePipelineStatusRequest</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePipelineStatusRequest {
     /// <p>The identifier of the pipeline to update.</p>
     #[serde(rename = "Id")]
@@ -1320,9 +2421,20 @@ pub struct UpdatePipelineStatusRequest {
     pub status: String,
 }
 
+impl Validate for UpdatePipelineStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^\\d{13}-\\w{6}$");
+        errors.check_pattern(
+            &field_path(path, "status"),
+            &self.status,
+            "(^Active$)|(^Paused$)",
+        );
+    }
+}
+
 /// <p>When you update status for a pipeline, Elastic Transcoder returns the values that you specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePipelineStatusResponse {
     /// <p>A section of the response body that provides information about the pipeline.</p>
     #[serde(rename = "Pipeline")]
@@ -1395,9 +2507,119 @@ pub struct VideoParameters {
     pub watermarks: Option<Vec<PresetWatermark>>,
 }
 
+impl Validate for VideoParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aspect_ratio {
+            errors.check_pattern(
+                &field_path(path, "aspect_ratio"),
+                &value,
+                "(^auto$)|(^1:1$)|(^4:3$)|(^3:2$)|(^16:9$)",
+            );
+        }
+        if let Some(ref value) = self.bit_rate {
+            errors.check_pattern(
+                &field_path(path, "bit_rate"),
+                &value,
+                "(^\\d{2,5}$)|(^auto$)",
+            );
+        }
+        if let Some(ref value) = self.codec {
+            errors.check_pattern(
+                &field_path(path, "codec"),
+                &value,
+                "(^H\\.264$)|(^vp8$)|(^vp9$)|(^mpeg2$)|(^gif$)",
+            );
+        }
+        if let Some(ref value) = self.codec_options {
+            errors.check_length(
+                &field_path(path, "codec_options"),
+                value.len(),
+                None,
+                Some(30),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "codec_options"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            }
+        }
+        if let Some(ref value) = self.display_aspect_ratio {
+            errors.check_pattern(
+                &field_path(path, "display_aspect_ratio"),
+                &value,
+                "(^auto$)|(^1:1$)|(^4:3$)|(^3:2$)|(^16:9$)",
+            );
+        }
+        if let Some(ref value) = self.fixed_gop {
+            errors.check_pattern(&field_path(path, "fixed_gop"), &value, "(^true$)|(^false$)");
+        }
+        if let Some(ref value) = self.frame_rate {
+            errors.check_pattern(
+                &field_path(path, "frame_rate"),
+                &value,
+                "(^auto$)|(^10$)|(^15$)|(^23.97$)|(^24$)|(^25$)|(^29.97$)|(^30$)|(^50$)|(^60$)",
+            );
+        }
+        if let Some(ref value) = self.keyframes_max_dist {
+            errors.check_pattern(
+                &field_path(path, "keyframes_max_dist"),
+                &value,
+                "^\\d{1,6}$",
+            );
+        }
+        if let Some(ref value) = self.max_frame_rate {
+            errors.check_pattern(
+                &field_path(path, "max_frame_rate"),
+                &value,
+                "(^10$)|(^15$)|(^23.97$)|(^24$)|(^25$)|(^29.97$)|(^30$)|(^50$)|(^60$)",
+            );
+        }
+        if let Some(ref value) = self.max_height {
+            errors.check_pattern(
+                &field_path(path, "max_height"),
+                &value,
+                "(^auto$)|(^\\d{2,4}$)",
+            );
+        }
+        if let Some(ref value) = self.max_width {
+            errors.check_pattern(
+                &field_path(path, "max_width"),
+                &value,
+                "(^auto$)|(^\\d{2,4}$)",
+            );
+        }
+        if let Some(ref value) = self.padding_policy {
+            errors.check_pattern(
+                &field_path(path, "padding_policy"),
+                &value,
+                "(^Pad$)|(^NoPad$)",
+            );
+        }
+        if let Some(ref value) = self.resolution {
+            errors.check_pattern(
+                &field_path(path, "resolution"),
+                &value,
+                "(^auto$)|(^\\d{1,5}x\\d{1,5}$)",
+            );
+        }
+        if let Some(ref value) = self.sizing_policy {
+            errors.check_pattern(
+                &field_path(path, "sizing_policy"),
+                &value,
+                "(^Fit$)|(^Fill$)|(^Stretch$)|(^Keep$)|(^ShrinkToFit$)|(^ShrinkToFill$)",
+            );
+        }
+        if let Some(ref value) = self.watermarks {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "watermarks"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Elastic Transcoder returns a warning if the resources used by your pipeline are not in the same region as the pipeline.</p> <p>Using resources in the same region, such as your Amazon S3 buckets, Amazon SNS notification topics, and AWS KMS key, reduces processing time and prevents cross-regional charges.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Warning {
     /// <p>The code of the cross-regional warning.</p>
     #[serde(rename = "Code")]
@@ -1443,7 +2665,12 @@ impl CancelJobError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CancelJobError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1500,7 +2727,12 @@ impl CreateJobError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateJobError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1557,7 +2789,12 @@ impl CreatePipelineError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreatePipelineError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1609,7 +2846,12 @@ impl CreatePresetError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreatePresetError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1665,7 +2907,12 @@ impl DeletePipelineError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeletePipelineError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1717,7 +2964,12 @@ impl DeletePresetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeletePresetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1770,7 +3022,12 @@ impl ListJobsByPipelineError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListJobsByPipelineError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1823,7 +3080,12 @@ impl ListJobsByStatusError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListJobsByStatusError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1869,7 +3131,12 @@ impl ListPipelinesError {
                 "InternalServiceException" => {
                     return RusotoError::Service(ListPipelinesError::InternalService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1914,7 +3181,12 @@ impl ListPresetsError {
                 "InternalServiceException" => {
                     return RusotoError::Service(ListPresetsError::InternalService(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1964,7 +3236,12 @@ impl ReadJobError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ReadJobError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2015,7 +3292,12 @@ impl ReadPipelineError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ReadPipelineError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2066,7 +3348,12 @@ impl ReadPresetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ReadPresetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2117,7 +3404,12 @@ impl TestRoleError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TestRoleError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2173,7 +3465,12 @@ impl UpdatePipelineError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdatePipelineError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2242,7 +3539,12 @@ impl UpdatePipelineNotificationsError {
                         UpdatePipelineNotificationsError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2305,7 +3607,12 @@ impl UpdatePipelineStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2426,6 +3733,301 @@ pub trait Ets {
         input: UpdatePipelineStatusRequest,
     ) -> RusotoFuture<UpdatePipelineStatusResponse, UpdatePipelineStatusError>;
 }
+const _: Option<&dyn Ets> = None;
+impl<'a, T: Ets + ?Sized> Ets for &'a T {
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
+        (**self).create_preset(input)
+    }
+    fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> RusotoFuture<DeletePipelineResponse, DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> RusotoFuture<DeletePresetResponse, DeletePresetError> {
+        (**self).delete_preset(input)
+    }
+    fn list_jobs_by_pipeline(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> RusotoFuture<ListJobsByPipelineResponse, ListJobsByPipelineError> {
+        (**self).list_jobs_by_pipeline(input)
+    }
+    fn list_jobs_by_status(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> RusotoFuture<ListJobsByStatusResponse, ListJobsByStatusError> {
+        (**self).list_jobs_by_status(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        (**self).list_presets(input)
+    }
+    fn read_job(&self, input: ReadJobRequest) -> RusotoFuture<ReadJobResponse, ReadJobError> {
+        (**self).read_job(input)
+    }
+    fn read_pipeline(
+        &self,
+        input: ReadPipelineRequest,
+    ) -> RusotoFuture<ReadPipelineResponse, ReadPipelineError> {
+        (**self).read_pipeline(input)
+    }
+    fn read_preset(
+        &self,
+        input: ReadPresetRequest,
+    ) -> RusotoFuture<ReadPresetResponse, ReadPresetError> {
+        (**self).read_preset(input)
+    }
+    fn test_role(&self, input: TestRoleRequest) -> RusotoFuture<TestRoleResponse, TestRoleError> {
+        (**self).test_role(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> RusotoFuture<UpdatePipelineResponse, UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+    fn update_pipeline_notifications(
+        &self,
+        input: UpdatePipelineNotificationsRequest,
+    ) -> RusotoFuture<UpdatePipelineNotificationsResponse, UpdatePipelineNotificationsError> {
+        (**self).update_pipeline_notifications(input)
+    }
+    fn update_pipeline_status(
+        &self,
+        input: UpdatePipelineStatusRequest,
+    ) -> RusotoFuture<UpdatePipelineStatusResponse, UpdatePipelineStatusError> {
+        (**self).update_pipeline_status(input)
+    }
+}
+impl<T: Ets + ?Sized> Ets for Box<T> {
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
+        (**self).create_preset(input)
+    }
+    fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> RusotoFuture<DeletePipelineResponse, DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> RusotoFuture<DeletePresetResponse, DeletePresetError> {
+        (**self).delete_preset(input)
+    }
+    fn list_jobs_by_pipeline(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> RusotoFuture<ListJobsByPipelineResponse, ListJobsByPipelineError> {
+        (**self).list_jobs_by_pipeline(input)
+    }
+    fn list_jobs_by_status(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> RusotoFuture<ListJobsByStatusResponse, ListJobsByStatusError> {
+        (**self).list_jobs_by_status(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        (**self).list_presets(input)
+    }
+    fn read_job(&self, input: ReadJobRequest) -> RusotoFuture<ReadJobResponse, ReadJobError> {
+        (**self).read_job(input)
+    }
+    fn read_pipeline(
+        &self,
+        input: ReadPipelineRequest,
+    ) -> RusotoFuture<ReadPipelineResponse, ReadPipelineError> {
+        (**self).read_pipeline(input)
+    }
+    fn read_preset(
+        &self,
+        input: ReadPresetRequest,
+    ) -> RusotoFuture<ReadPresetResponse, ReadPresetError> {
+        (**self).read_preset(input)
+    }
+    fn test_role(&self, input: TestRoleRequest) -> RusotoFuture<TestRoleResponse, TestRoleError> {
+        (**self).test_role(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> RusotoFuture<UpdatePipelineResponse, UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+    fn update_pipeline_notifications(
+        &self,
+        input: UpdatePipelineNotificationsRequest,
+    ) -> RusotoFuture<UpdatePipelineNotificationsResponse, UpdatePipelineNotificationsError> {
+        (**self).update_pipeline_notifications(input)
+    }
+    fn update_pipeline_status(
+        &self,
+        input: UpdatePipelineStatusRequest,
+    ) -> RusotoFuture<UpdatePipelineStatusResponse, UpdatePipelineStatusError> {
+        (**self).update_pipeline_status(input)
+    }
+}
+impl<T: Ets + ?Sized> Ets for ::std::sync::Arc<T> {
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
+        (**self).create_preset(input)
+    }
+    fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> RusotoFuture<DeletePipelineResponse, DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> RusotoFuture<DeletePresetResponse, DeletePresetError> {
+        (**self).delete_preset(input)
+    }
+    fn list_jobs_by_pipeline(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> RusotoFuture<ListJobsByPipelineResponse, ListJobsByPipelineError> {
+        (**self).list_jobs_by_pipeline(input)
+    }
+    fn list_jobs_by_status(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> RusotoFuture<ListJobsByStatusResponse, ListJobsByStatusError> {
+        (**self).list_jobs_by_status(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        (**self).list_presets(input)
+    }
+    fn read_job(&self, input: ReadJobRequest) -> RusotoFuture<ReadJobResponse, ReadJobError> {
+        (**self).read_job(input)
+    }
+    fn read_pipeline(
+        &self,
+        input: ReadPipelineRequest,
+    ) -> RusotoFuture<ReadPipelineResponse, ReadPipelineError> {
+        (**self).read_pipeline(input)
+    }
+    fn read_preset(
+        &self,
+        input: ReadPresetRequest,
+    ) -> RusotoFuture<ReadPresetResponse, ReadPresetError> {
+        (**self).read_preset(input)
+    }
+    fn test_role(&self, input: TestRoleRequest) -> RusotoFuture<TestRoleResponse, TestRoleError> {
+        (**self).test_role(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> RusotoFuture<UpdatePipelineResponse, UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+    fn update_pipeline_notifications(
+        &self,
+        input: UpdatePipelineNotificationsRequest,
+    ) -> RusotoFuture<UpdatePipelineNotificationsResponse, UpdatePipelineNotificationsError> {
+        (**self).update_pipeline_notifications(input)
+    }
+    fn update_pipeline_status(
+        &self,
+        input: UpdatePipelineStatusRequest,
+    ) -> RusotoFuture<UpdatePipelineStatusResponse, UpdatePipelineStatusError> {
+        (**self).update_pipeline_status(input)
+    }
+}
 /// A client for the Amazon Elastic Transcoder API.
 #[derive(Clone)]
 pub struct EtsClient {
@@ -2469,16 +4071,27 @@ impl Ets for EtsClient {
         &self,
         input: CancelJobRequest,
     ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2012-09-25/jobs/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CancelJob");
+        request.add_resource_id("Id", &input.id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 202 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CancelJob")
                         .deserialize::<CancelJobResponse, _>()?;
 
                     Ok(result)
@@ -2499,10 +4112,20 @@ impl Ets for EtsClient {
         &self,
         input: CreateJobRequest,
     ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2012-09-25/jobs";
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreateJob");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2512,6 +4135,7 @@ impl Ets for EtsClient {
             if response.status.as_u16() == 201 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateJob")
                         .deserialize::<CreateJobResponse, _>()?;
 
                     Ok(result)
@@ -2532,10 +4156,20 @@ impl Ets for EtsClient {
         &self,
         input: CreatePipelineRequest,
     ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2012-09-25/pipelines";
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreatePipeline");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2545,6 +4179,7 @@ impl Ets for EtsClient {
             if response.status.as_u16() == 201 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreatePipeline")
                         .deserialize::<CreatePipelineResponse, _>()?;
 
                     Ok(result)
@@ -2565,10 +4200,20 @@ impl Ets for EtsClient {
         &self,
         input: CreatePresetRequest,
     ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2012-09-25/presets";
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreatePreset");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2578,6 +4223,7 @@ impl Ets for EtsClient {
             if response.status.as_u16() == 201 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreatePreset")
                         .deserialize::<CreatePresetResponse, _>()?;
 
                     Ok(result)
@@ -2598,16 +4244,27 @@ impl Ets for EtsClient {
         &self,
         input: DeletePipelineRequest,
     ) -> RusotoFuture<DeletePipelineResponse, DeletePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2012-09-25/pipelines/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("DeletePipeline");
+        request.add_resource_id("Id", &input.id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 202 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeletePipeline")
                         .deserialize::<DeletePipelineResponse, _>()?;
 
                     Ok(result)
@@ -2628,16 +4285,27 @@ impl Ets for EtsClient {
         &self,
         input: DeletePresetRequest,
     ) -> RusotoFuture<DeletePresetResponse, DeletePresetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2012-09-25/presets/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("DeletePreset");
+        request.add_resource_id("Id", &input.id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 202 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeletePreset")
                         .deserialize::<DeletePresetResponse, _>()?;
 
                     Ok(result)
@@ -2658,6 +4326,14 @@ impl Ets for EtsClient {
         &self,
         input: ListJobsByPipelineRequest,
     ) -> RusotoFuture<ListJobsByPipelineResponse, ListJobsByPipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/2012-09-25/jobsByPipeline/{pipeline_id}",
             pipeline_id = input.pipeline_id
@@ -2665,6 +4341,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListJobsByPipeline");
+        request.add_resource_id("PipelineId", &input.pipeline_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2680,6 +4359,7 @@ impl Ets for EtsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListJobsByPipeline")
                         .deserialize::<ListJobsByPipelineResponse, _>()?;
 
                     Ok(result)
@@ -2700,10 +4380,21 @@ impl Ets for EtsClient {
         &self,
         input: ListJobsByStatusRequest,
     ) -> RusotoFuture<ListJobsByStatusResponse, ListJobsByStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2012-09-25/jobsByStatus/{status}", status = input.status);
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListJobsByStatus");
+        request.add_resource_id("Status", &input.status.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2719,6 +4410,7 @@ impl Ets for EtsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListJobsByStatus")
                         .deserialize::<ListJobsByStatusResponse, _>()?;
 
                     Ok(result)
@@ -2739,10 +4431,20 @@ impl Ets for EtsClient {
         &self,
         input: ListPipelinesRequest,
     ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2012-09-25/pipelines";
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListPipelines");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2758,6 +4460,7 @@ impl Ets for EtsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListPipelines")
                         .deserialize::<ListPipelinesResponse, _>()?;
 
                     Ok(result)
@@ -2778,10 +4481,20 @@ impl Ets for EtsClient {
         &self,
         input: ListPresetsRequest,
     ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2012-09-25/presets";
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListPresets");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2797,6 +4510,7 @@ impl Ets for EtsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListPresets")
                         .deserialize::<ListPresetsResponse, _>()?;
 
                     Ok(result)
@@ -2814,16 +4528,28 @@ impl Ets for EtsClient {
 
     /// <p>The ReadJob operation returns detailed information about a job.</p>
     fn read_job(&self, input: ReadJobRequest) -> RusotoFuture<ReadJobResponse, ReadJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2012-09-25/jobs/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadJob");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ReadJob")
                         .deserialize::<ReadJobResponse, _>()?;
 
                     Ok(result)
@@ -2844,16 +4570,28 @@ impl Ets for EtsClient {
         &self,
         input: ReadPipelineRequest,
     ) -> RusotoFuture<ReadPipelineResponse, ReadPipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2012-09-25/pipelines/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadPipeline");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ReadPipeline")
                         .deserialize::<ReadPipelineResponse, _>()?;
 
                     Ok(result)
@@ -2874,16 +4612,28 @@ impl Ets for EtsClient {
         &self,
         input: ReadPresetRequest,
     ) -> RusotoFuture<ReadPresetResponse, ReadPresetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2012-09-25/presets/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadPreset");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ReadPreset")
                         .deserialize::<ReadPresetResponse, _>()?;
 
                     Ok(result)
@@ -2901,10 +4651,20 @@ impl Ets for EtsClient {
 
     /// <p>The TestRole operation tests the IAM role used to create the pipeline.</p> <p>The <code>TestRole</code> action lets you determine whether the IAM role you are using has sufficient permissions to let Elastic Transcoder perform tasks associated with the transcoding process. The action attempts to assume the specified IAM role, checks read access to the input and output buckets, and tries to send a test notification to Amazon SNS topics that you specify.</p>
     fn test_role(&self, input: TestRoleRequest) -> RusotoFuture<TestRoleResponse, TestRoleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2012-09-25/roleTests";
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("TestRole");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2914,6 +4674,7 @@ impl Ets for EtsClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TestRole")
                         .deserialize::<TestRoleResponse, _>()?;
 
                     Ok(result)
@@ -2934,10 +4695,21 @@ impl Ets for EtsClient {
         &self,
         input: UpdatePipelineRequest,
     ) -> RusotoFuture<UpdatePipelineResponse, UpdatePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2012-09-25/pipelines/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("PUT", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipeline");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2947,6 +4719,7 @@ impl Ets for EtsClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdatePipeline")
                         .deserialize::<UpdatePipelineResponse, _>()?;
 
                     Ok(result)
@@ -2967,10 +4740,21 @@ impl Ets for EtsClient {
         &self,
         input: UpdatePipelineNotificationsRequest,
     ) -> RusotoFuture<UpdatePipelineNotificationsResponse, UpdatePipelineNotificationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2012-09-25/pipelines/{id}/notifications", id = input.id);
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipelineNotifications");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2980,6 +4764,7 @@ impl Ets for EtsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdatePipelineNotifications")
                         .deserialize::<UpdatePipelineNotificationsResponse, _>()?;
 
                     Ok(result)
@@ -2997,10 +4782,21 @@ impl Ets for EtsClient {
         &self,
         input: UpdatePipelineStatusRequest,
     ) -> RusotoFuture<UpdatePipelineStatusResponse, UpdatePipelineStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2012-09-25/pipelines/{id}/status", id = input.id);
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipelineStatus");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3010,6 +4806,7 @@ impl Ets for EtsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdatePipelineStatus")
                         .deserialize::<UpdatePipelineStatusResponse, _>()?;
 
                     Ok(result)
@@ -3024,3 +4821,585 @@ impl Ets for EtsClient {
         })
     }
 }
+
+/// A mock of the Amazon Elastic Transcoder API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockEts {
+    cancel_job: Option<
+        Box<
+            dyn Fn(
+                    CancelJobRequest,
+                )
+                    -> ::std::result::Result<CancelJobResponse, RusotoError<CancelJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_job: Option<
+        Box<
+            dyn Fn(
+                    CreateJobRequest,
+                )
+                    -> ::std::result::Result<CreateJobResponse, RusotoError<CreateJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_pipeline: Option<
+        Box<
+            dyn Fn(
+                    CreatePipelineRequest,
+                ) -> ::std::result::Result<
+                    CreatePipelineResponse,
+                    RusotoError<CreatePipelineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_preset: Option<
+        Box<
+            dyn Fn(
+                    CreatePresetRequest,
+                )
+                    -> ::std::result::Result<CreatePresetResponse, RusotoError<CreatePresetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_pipeline: Option<
+        Box<
+            dyn Fn(
+                    DeletePipelineRequest,
+                ) -> ::std::result::Result<
+                    DeletePipelineResponse,
+                    RusotoError<DeletePipelineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_preset: Option<
+        Box<
+            dyn Fn(
+                    DeletePresetRequest,
+                )
+                    -> ::std::result::Result<DeletePresetResponse, RusotoError<DeletePresetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_jobs_by_pipeline: Option<
+        Box<
+            dyn Fn(
+                    ListJobsByPipelineRequest,
+                ) -> ::std::result::Result<
+                    ListJobsByPipelineResponse,
+                    RusotoError<ListJobsByPipelineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_jobs_by_status: Option<
+        Box<
+            dyn Fn(
+                    ListJobsByStatusRequest,
+                ) -> ::std::result::Result<
+                    ListJobsByStatusResponse,
+                    RusotoError<ListJobsByStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_pipelines: Option<
+        Box<
+            dyn Fn(
+                    ListPipelinesRequest,
+                )
+                    -> ::std::result::Result<ListPipelinesResponse, RusotoError<ListPipelinesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_presets: Option<
+        Box<
+            dyn Fn(
+                    ListPresetsRequest,
+                )
+                    -> ::std::result::Result<ListPresetsResponse, RusotoError<ListPresetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    read_job: Option<
+        Box<
+            dyn Fn(
+                    ReadJobRequest,
+                )
+                    -> ::std::result::Result<ReadJobResponse, RusotoError<ReadJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    read_pipeline: Option<
+        Box<
+            dyn Fn(
+                    ReadPipelineRequest,
+                )
+                    -> ::std::result::Result<ReadPipelineResponse, RusotoError<ReadPipelineError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    read_preset: Option<
+        Box<
+            dyn Fn(
+                    ReadPresetRequest,
+                )
+                    -> ::std::result::Result<ReadPresetResponse, RusotoError<ReadPresetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    test_role: Option<
+        Box<
+            dyn Fn(
+                    TestRoleRequest,
+                )
+                    -> ::std::result::Result<TestRoleResponse, RusotoError<TestRoleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_pipeline: Option<
+        Box<
+            dyn Fn(
+                    UpdatePipelineRequest,
+                ) -> ::std::result::Result<
+                    UpdatePipelineResponse,
+                    RusotoError<UpdatePipelineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_pipeline_notifications: Option<
+        Box<
+            dyn Fn(
+                    UpdatePipelineNotificationsRequest,
+                ) -> ::std::result::Result<
+                    UpdatePipelineNotificationsResponse,
+                    RusotoError<UpdatePipelineNotificationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_pipeline_status: Option<
+        Box<
+            dyn Fn(
+                    UpdatePipelineStatusRequest,
+                ) -> ::std::result::Result<
+                    UpdatePipelineStatusResponse,
+                    RusotoError<UpdatePipelineStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockEts {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockEts {
+        Default::default()
+    }
+
+    /// Answers `cancel_job` with what `handler` returns for its input.
+    pub fn with_cancel_job<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                CancelJobRequest,
+            ) -> ::std::result::Result<CancelJobResponse, RusotoError<CancelJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_job` with what `handler` returns for its input.
+    pub fn with_create_job<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                CreateJobRequest,
+            ) -> ::std::result::Result<CreateJobResponse, RusotoError<CreateJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_pipeline` with what `handler` returns for its input.
+    pub fn with_create_pipeline<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                CreatePipelineRequest,
+            )
+                -> ::std::result::Result<CreatePipelineResponse, RusotoError<CreatePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_preset` with what `handler` returns for its input.
+    pub fn with_create_preset<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                CreatePresetRequest,
+            )
+                -> ::std::result::Result<CreatePresetResponse, RusotoError<CreatePresetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_preset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_pipeline` with what `handler` returns for its input.
+    pub fn with_delete_pipeline<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                DeletePipelineRequest,
+            )
+                -> ::std::result::Result<DeletePipelineResponse, RusotoError<DeletePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_preset` with what `handler` returns for its input.
+    pub fn with_delete_preset<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                DeletePresetRequest,
+            )
+                -> ::std::result::Result<DeletePresetResponse, RusotoError<DeletePresetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_preset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_jobs_by_pipeline` with what `handler` returns for its input.
+    pub fn with_list_jobs_by_pipeline<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                ListJobsByPipelineRequest,
+            ) -> ::std::result::Result<
+                ListJobsByPipelineResponse,
+                RusotoError<ListJobsByPipelineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_jobs_by_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_jobs_by_status` with what `handler` returns for its input.
+    pub fn with_list_jobs_by_status<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                ListJobsByStatusRequest,
+            ) -> ::std::result::Result<
+                ListJobsByStatusResponse,
+                RusotoError<ListJobsByStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_jobs_by_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_pipelines` with what `handler` returns for its input.
+    pub fn with_list_pipelines<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                ListPipelinesRequest,
+            )
+                -> ::std::result::Result<ListPipelinesResponse, RusotoError<ListPipelinesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_pipelines = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_presets` with what `handler` returns for its input.
+    pub fn with_list_presets<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                ListPresetsRequest,
+            )
+                -> ::std::result::Result<ListPresetsResponse, RusotoError<ListPresetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_presets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `read_job` with what `handler` returns for its input.
+    pub fn with_read_job<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(ReadJobRequest) -> ::std::result::Result<ReadJobResponse, RusotoError<ReadJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.read_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `read_pipeline` with what `handler` returns for its input.
+    pub fn with_read_pipeline<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                ReadPipelineRequest,
+            )
+                -> ::std::result::Result<ReadPipelineResponse, RusotoError<ReadPipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.read_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `read_preset` with what `handler` returns for its input.
+    pub fn with_read_preset<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                ReadPresetRequest,
+            )
+                -> ::std::result::Result<ReadPresetResponse, RusotoError<ReadPresetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.read_preset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_role` with what `handler` returns for its input.
+    pub fn with_test_role<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                TestRoleRequest,
+            ) -> ::std::result::Result<TestRoleResponse, RusotoError<TestRoleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.test_role = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_pipeline` with what `handler` returns for its input.
+    pub fn with_update_pipeline<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                UpdatePipelineRequest,
+            )
+                -> ::std::result::Result<UpdatePipelineResponse, RusotoError<UpdatePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_pipeline_notifications` with what `handler` returns for its input.
+    pub fn with_update_pipeline_notifications<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                UpdatePipelineNotificationsRequest,
+            ) -> ::std::result::Result<
+                UpdatePipelineNotificationsResponse,
+                RusotoError<UpdatePipelineNotificationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_pipeline_notifications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_pipeline_status` with what `handler` returns for its input.
+    pub fn with_update_pipeline_status<F>(mut self, handler: F) -> MockEts
+    where
+        F: Fn(
+                UpdatePipelineStatusRequest,
+            ) -> ::std::result::Result<
+                UpdatePipelineStatusResponse,
+                RusotoError<UpdatePipelineStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_pipeline_status = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Ets for MockEts {
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        match self.cancel_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for cancel_job"),
+        }
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        match self.create_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for create_job"),
+        }
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        match self.create_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for create_pipeline"),
+        }
+    }
+    fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
+        match self.create_preset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for create_preset"),
+        }
+    }
+    fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> RusotoFuture<DeletePipelineResponse, DeletePipelineError> {
+        match self.delete_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for delete_pipeline"),
+        }
+    }
+    fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> RusotoFuture<DeletePresetResponse, DeletePresetError> {
+        match self.delete_preset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for delete_preset"),
+        }
+    }
+    fn list_jobs_by_pipeline(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> RusotoFuture<ListJobsByPipelineResponse, ListJobsByPipelineError> {
+        match self.list_jobs_by_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for list_jobs_by_pipeline"),
+        }
+    }
+    fn list_jobs_by_status(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> RusotoFuture<ListJobsByStatusResponse, ListJobsByStatusError> {
+        match self.list_jobs_by_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for list_jobs_by_status"),
+        }
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        match self.list_pipelines {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for list_pipelines"),
+        }
+    }
+    fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        match self.list_presets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for list_presets"),
+        }
+    }
+    fn read_job(&self, input: ReadJobRequest) -> RusotoFuture<ReadJobResponse, ReadJobError> {
+        match self.read_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for read_job"),
+        }
+    }
+    fn read_pipeline(
+        &self,
+        input: ReadPipelineRequest,
+    ) -> RusotoFuture<ReadPipelineResponse, ReadPipelineError> {
+        match self.read_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for read_pipeline"),
+        }
+    }
+    fn read_preset(
+        &self,
+        input: ReadPresetRequest,
+    ) -> RusotoFuture<ReadPresetResponse, ReadPresetError> {
+        match self.read_preset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for read_preset"),
+        }
+    }
+    fn test_role(&self, input: TestRoleRequest) -> RusotoFuture<TestRoleResponse, TestRoleError> {
+        match self.test_role {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for test_role"),
+        }
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> RusotoFuture<UpdatePipelineResponse, UpdatePipelineError> {
+        match self.update_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for update_pipeline"),
+        }
+    }
+    fn update_pipeline_notifications(
+        &self,
+        input: UpdatePipelineNotificationsRequest,
+    ) -> RusotoFuture<UpdatePipelineNotificationsResponse, UpdatePipelineNotificationsError> {
+        match self.update_pipeline_notifications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for update_pipeline_notifications"),
+        }
+    }
+    fn update_pipeline_status(
+        &self,
+        input: UpdatePipelineStatusRequest,
+    ) -> RusotoFuture<UpdatePipelineStatusResponse, UpdatePipelineStatusError> {
+        match self.update_pipeline_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEts has no handler for update_pipeline_status"),
+        }
+    }
+}
@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -35,6 +36,8 @@ use xml::EventReader;
 
 /// <p>Information about the <code>AccessLog</code> attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccessLog {
     /// <p>The interval for publishing the access logs. You can specify an interval of either 5 minutes or 60 minutes.</p> <p>Default: 60 minutes</p>
     pub emit_interval: Option<i64>,
@@ -110,7 +113,7 @@ impl AccessLogEnabledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -121,7 +124,7 @@ impl AccessLogIntervalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -154,7 +157,7 @@ impl AccessPointPortDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -162,6 +165,8 @@ impl AccessPointPortDeserializer {
 }
 /// <p>Contains the parameters for EnableAvailabilityZonesForLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddAvailabilityZonesInput {
     /// <p>The Availability Zones. These must be in the same region as the load balancer.</p>
     pub availability_zones: Vec<String>,
@@ -192,6 +197,8 @@ impl AddAvailabilityZonesInputSerializer {
 
 /// <p>Contains the output of EnableAvailabilityZonesForLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddAvailabilityZonesOutput {
     /// <p>The updated list of Availability Zones for the load balancer.</p>
     pub availability_zones: Option<Vec<String>>,
@@ -223,6 +230,8 @@ impl AddAvailabilityZonesOutputDeserializer {
 }
 /// <p>Contains the parameters for AddTags.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsInput {
     /// <p>The name of the load balancer. You can specify one load balancer only.</p>
     pub load_balancer_names: Vec<String>,
@@ -230,6 +239,16 @@ pub struct AddTagsInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for AddTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), Some(1), None);
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `AddTagsInput` contents to a `SignedRequest`.
 struct AddTagsInputSerializer;
 impl AddTagsInputSerializer {
@@ -250,6 +269,8 @@ impl AddTagsInputSerializer {
 
 /// <p>Contains the output of AddTags.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsOutput {}
 
 struct AddTagsOutputDeserializer;
@@ -270,6 +291,8 @@ impl AddTagsOutputDeserializer {
 }
 /// <p>This data type is reserved.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AdditionalAttribute {
     /// <p>This parameter is reserved.</p>
     pub key: Option<String>,
@@ -277,6 +300,29 @@ pub struct AdditionalAttribute {
     pub value: Option<String>,
 }
 
+impl Validate for AdditionalAttribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "key"), &value, "^[a-zA-Z0-9.]+$");
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "value"), &value, "^[a-zA-Z0-9.]+$");
+        }
+    }
+}
+
 struct AdditionalAttributeDeserializer;
 impl AdditionalAttributeDeserializer {
     #[allow(unused_variables)]
@@ -395,6 +441,8 @@ impl AppCookieStickinessPoliciesDeserializer {
 }
 /// <p>Information about a policy for application-controlled session stickiness.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AppCookieStickinessPolicy {
     /// <p>The name of the application cookie used for stickiness.</p>
     pub cookie_name: Option<String>,
@@ -431,6 +479,8 @@ impl AppCookieStickinessPolicyDeserializer {
 }
 /// <p>Contains the parameters for ApplySecurityGroupsToLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplySecurityGroupsToLoadBalancerInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: String,
@@ -461,6 +511,8 @@ impl ApplySecurityGroupsToLoadBalancerInputSerializer {
 
 /// <p>Contains the output of ApplySecurityGroupsToLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplySecurityGroupsToLoadBalancerOutput {
     /// <p>The IDs of the security groups associated with the load balancer.</p>
     pub security_groups: Option<Vec<String>>,
@@ -492,6 +544,8 @@ impl ApplySecurityGroupsToLoadBalancerOutputDeserializer {
 }
 /// <p>Contains the parameters for AttachLoaBalancerToSubnets.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachLoadBalancerToSubnetsInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: String,
@@ -518,6 +572,8 @@ impl AttachLoadBalancerToSubnetsInputSerializer {
 
 /// <p>Contains the output of AttachLoadBalancerToSubnets.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachLoadBalancerToSubnetsOutput {
     /// <p>The IDs of the subnets attached to the load balancer.</p>
     pub subnets: Option<Vec<String>>,
@@ -622,6 +678,8 @@ impl AvailabilityZonesSerializer {
 
 /// <p>Information about the configuration of an EC2 instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BackendServerDescription {
     /// <p>The port on which the EC2 instance is listening.</p>
     pub instance_port: Option<i64>,
@@ -691,6 +749,8 @@ impl CardinalityDeserializer {
 }
 /// <p>Contains the parameters for ConfigureHealthCheck.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigureHealthCheckInput {
     /// <p>The configuration information.</p>
     pub health_check: HealthCheck,
@@ -698,6 +758,13 @@ pub struct ConfigureHealthCheckInput {
     pub load_balancer_name: String,
 }
 
+impl Validate for ConfigureHealthCheckInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.health_check
+            .validate_at(&field_path(path, "health_check"), errors);
+    }
+}
+
 /// Serialize `ConfigureHealthCheckInput` contents to a `SignedRequest`.
 struct ConfigureHealthCheckInputSerializer;
 impl ConfigureHealthCheckInputSerializer {
@@ -721,6 +788,8 @@ impl ConfigureHealthCheckInputSerializer {
 
 /// <p>Contains the output of ConfigureHealthCheck.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigureHealthCheckOutput {
     /// <p>The updated health check.</p>
     pub health_check: Option<HealthCheck>,
@@ -751,6 +820,8 @@ impl ConfigureHealthCheckOutputDeserializer {
 }
 /// <p>Information about the <code>ConnectionDraining</code> attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConnectionDraining {
     /// <p>Specifies whether connection draining is enabled for the load balancer.</p>
     pub enabled: bool,
@@ -804,7 +875,7 @@ impl ConnectionDrainingEnabledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -815,7 +886,7 @@ impl ConnectionDrainingTimeoutDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -823,11 +894,24 @@ impl ConnectionDrainingTimeoutDeserializer {
 }
 /// <p>Information about the <code>ConnectionSettings</code> attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConnectionSettings {
     /// <p>The time, in seconds, that the connection is allowed to be idle (no data has been sent over the connection) before it is closed by the load balancer.</p>
     pub idle_timeout: i64,
 }
 
+impl Validate for ConnectionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "idle_timeout"),
+            self.idle_timeout,
+            Some(1),
+            Some(3600),
+        );
+    }
+}
+
 struct ConnectionSettingsDeserializer;
 impl ConnectionSettingsDeserializer {
     #[allow(unused_variables)]
@@ -865,7 +949,7 @@ impl CookieExpirationPeriodDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -884,6 +968,8 @@ impl CookieNameDeserializer {
 }
 /// <p>Contains the parameters for CreateLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAccessPointInput {
     /// <p>One or more Availability Zones from the same region as the load balancer.</p> <p>You must specify at least one Availability Zone.</p> <p>You can add more Availability Zones after you create the load balancer using <a>EnableAvailabilityZonesForLoadBalancer</a>.</p>
     pub availability_zones: Option<Vec<String>>,
@@ -901,6 +987,22 @@ pub struct CreateAccessPointInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateAccessPointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.listeners.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "listeners"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateAccessPointInput` contents to a `SignedRequest`.
 struct CreateAccessPointInputSerializer;
 impl CreateAccessPointInputSerializer {
@@ -947,6 +1049,8 @@ impl CreateAccessPointInputSerializer {
 
 /// <p>Contains the output for CreateLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAccessPointOutput {
     /// <p>The DNS name of the load balancer.</p>
     pub dns_name: Option<String>,
@@ -976,6 +1080,8 @@ impl CreateAccessPointOutputDeserializer {
 }
 /// <p>Contains the parameters for CreateAppCookieStickinessPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAppCookieStickinessPolicyInput {
     /// <p>The name of the application cookie used for stickiness.</p>
     pub cookie_name: String,
@@ -1005,6 +1111,8 @@ impl CreateAppCookieStickinessPolicyInputSerializer {
 
 /// <p>Contains the output for CreateAppCookieStickinessPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAppCookieStickinessPolicyOutput {}
 
 struct CreateAppCookieStickinessPolicyOutputDeserializer;
@@ -1025,6 +1133,8 @@ impl CreateAppCookieStickinessPolicyOutputDeserializer {
 }
 /// <p>Contains the parameters for CreateLBCookieStickinessPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLBCookieStickinessPolicyInput {
     /// <p>The time period, in seconds, after which the cookie should be considered stale. If you do not specify this parameter, the default value is 0, which indicates that the sticky session should last for the duration of the browser session.</p>
     pub cookie_expiration_period: Option<i64>,
@@ -1059,6 +1169,8 @@ impl CreateLBCookieStickinessPolicyInputSerializer {
 
 /// <p>Contains the output for CreateLBCookieStickinessPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLBCookieStickinessPolicyOutput {}
 
 struct CreateLBCookieStickinessPolicyOutputDeserializer;
@@ -1079,6 +1191,8 @@ impl CreateLBCookieStickinessPolicyOutputDeserializer {
 }
 /// <p>Contains the parameters for CreateLoadBalancerListeners.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLoadBalancerListenerInput {
     /// <p>The listeners.</p>
     pub listeners: Vec<Listener>,
@@ -1086,6 +1200,15 @@ pub struct CreateLoadBalancerListenerInput {
     pub load_balancer_name: String,
 }
 
+impl Validate for CreateLoadBalancerListenerInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.listeners.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "listeners"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `CreateLoadBalancerListenerInput` contents to a `SignedRequest`.
 struct CreateLoadBalancerListenerInputSerializer;
 impl CreateLoadBalancerListenerInputSerializer {
@@ -1109,6 +1232,8 @@ impl CreateLoadBalancerListenerInputSerializer {
 
 /// <p>Contains the parameters for CreateLoadBalancerListener.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLoadBalancerListenerOutput {}
 
 struct CreateLoadBalancerListenerOutputDeserializer;
@@ -1129,6 +1254,8 @@ impl CreateLoadBalancerListenerOutputDeserializer {
 }
 /// <p>Contains the parameters for CreateLoadBalancerPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLoadBalancerPolicyInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: String,
@@ -1170,6 +1297,8 @@ impl CreateLoadBalancerPolicyInputSerializer {
 
 /// <p>Contains the output of CreateLoadBalancerPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLoadBalancerPolicyOutput {}
 
 struct CreateLoadBalancerPolicyOutputDeserializer;
@@ -1201,6 +1330,8 @@ impl CreatedTimeDeserializer {
 }
 /// <p>Information about the <code>CrossZoneLoadBalancing</code> attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CrossZoneLoadBalancing {
     /// <p>Specifies whether cross-zone load balancing is enabled for the load balancer.</p>
     pub enabled: bool,
@@ -1244,7 +1375,7 @@ impl CrossZoneLoadBalancingEnabledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1274,6 +1405,8 @@ impl DefaultValueDeserializer {
 }
 /// <p>Contains the parameters for DeleteLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAccessPointInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: String,
@@ -1297,6 +1430,8 @@ impl DeleteAccessPointInputSerializer {
 
 /// <p>Contains the output of DeleteLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAccessPointOutput {}
 
 struct DeleteAccessPointOutputDeserializer;
@@ -1317,6 +1452,8 @@ impl DeleteAccessPointOutputDeserializer {
 }
 /// <p>Contains the parameters for DeleteLoadBalancerListeners.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLoadBalancerListenerInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: String,
@@ -1347,6 +1484,8 @@ impl DeleteLoadBalancerListenerInputSerializer {
 
 /// <p>Contains the output of DeleteLoadBalancerListeners.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLoadBalancerListenerOutput {}
 
 struct DeleteLoadBalancerListenerOutputDeserializer;
@@ -1367,6 +1506,8 @@ impl DeleteLoadBalancerListenerOutputDeserializer {
 }
 /// <p>Contains the parameters for DeleteLoadBalancerPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLoadBalancerPolicyInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: String,
@@ -1393,6 +1534,8 @@ impl DeleteLoadBalancerPolicyInputSerializer {
 
 /// <p>Contains the output of DeleteLoadBalancerPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLoadBalancerPolicyOutput {}
 
 struct DeleteLoadBalancerPolicyOutputDeserializer;
@@ -1413,6 +1556,8 @@ impl DeleteLoadBalancerPolicyOutputDeserializer {
 }
 /// <p>Contains the parameters for DeregisterInstancesFromLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterEndPointsInput {
     /// <p>The IDs of the instances.</p>
     pub instances: Vec<Instance>,
@@ -1443,6 +1588,8 @@ impl DeregisterEndPointsInputSerializer {
 
 /// <p>Contains the output of DeregisterInstancesFromLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterEndPointsOutput {
     /// <p>The remaining instances registered with the load balancer.</p>
     pub instances: Option<Vec<Instance>>,
@@ -1474,6 +1621,8 @@ impl DeregisterEndPointsOutputDeserializer {
 }
 /// <p>Contains the parameters for DescribeLoadBalancers.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccessPointsInput {
     /// <p>The names of the load balancers.</p>
     pub load_balancer_names: Option<Vec<String>>,
@@ -1483,6 +1632,14 @@ pub struct DescribeAccessPointsInput {
     pub page_size: Option<i64>,
 }
 
+impl Validate for DescribeAccessPointsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, Some(1), Some(400));
+        }
+    }
+}
+
 /// Serialize `DescribeAccessPointsInput` contents to a `SignedRequest`.
 struct DescribeAccessPointsInputSerializer;
 impl DescribeAccessPointsInputSerializer {
@@ -1510,6 +1667,8 @@ impl DescribeAccessPointsInputSerializer {
 
 /// <p>Contains the parameters for DescribeLoadBalancers.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccessPointsOutput {
     /// <p>Information about the load balancers.</p>
     pub load_balancer_descriptions: Option<Vec<LoadBalancerDescription>>,
@@ -1549,6 +1708,8 @@ impl DescribeAccessPointsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountLimitsInput {
     /// <p>The marker for the next set of results. (You received this marker from a previous call.)</p>
     pub marker: Option<String>,
@@ -1556,6 +1717,14 @@ pub struct DescribeAccountLimitsInput {
     pub page_size: Option<i64>,
 }
 
+impl Validate for DescribeAccountLimitsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, Some(1), Some(400));
+        }
+    }
+}
+
 /// Serialize `DescribeAccountLimitsInput` contents to a `SignedRequest`.
 struct DescribeAccountLimitsInputSerializer;
 impl DescribeAccountLimitsInputSerializer {
@@ -1575,6 +1744,8 @@ impl DescribeAccountLimitsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountLimitsOutput {
     /// <p>Information about the limits.</p>
     pub limits: Option<Vec<Limit>>,
@@ -1612,6 +1783,8 @@ impl DescribeAccountLimitsOutputDeserializer {
 }
 /// <p>Contains the parameters for DescribeInstanceHealth.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEndPointStateInput {
     /// <p>The IDs of the instances.</p>
     pub instances: Option<Vec<Instance>>,
@@ -1644,6 +1817,8 @@ impl DescribeEndPointStateInputSerializer {
 
 /// <p>Contains the output for DescribeInstanceHealth.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEndPointStateOutput {
     /// <p>Information about the health of the instances.</p>
     pub instance_states: Option<Vec<InstanceState>>,
@@ -1675,6 +1850,8 @@ impl DescribeEndPointStateOutputDeserializer {
 }
 /// <p>Contains the parameters for DescribeLoadBalancerAttributes.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancerAttributesInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: String,
@@ -1698,6 +1875,8 @@ impl DescribeLoadBalancerAttributesInputSerializer {
 
 /// <p>Contains the output of DescribeLoadBalancerAttributes.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancerAttributesOutput {
     /// <p>Information about the load balancer attributes.</p>
     pub load_balancer_attributes: Option<LoadBalancerAttributes>,
@@ -1731,6 +1910,8 @@ impl DescribeLoadBalancerAttributesOutputDeserializer {
 }
 /// <p>Contains the parameters for DescribeLoadBalancerPolicies.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancerPoliciesInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: Option<String>,
@@ -1762,6 +1943,8 @@ impl DescribeLoadBalancerPoliciesInputSerializer {
 
 /// <p>Contains the output of DescribeLoadBalancerPolicies.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancerPoliciesOutput {
     /// <p>Information about the policies.</p>
     pub policy_descriptions: Option<Vec<PolicyDescription>>,
@@ -1796,6 +1979,8 @@ impl DescribeLoadBalancerPoliciesOutputDeserializer {
 }
 /// <p>Contains the parameters for DescribeLoadBalancerPolicyTypes.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancerPolicyTypesInput {
     /// <p>The names of the policy types. If no names are specified, describes all policy types defined by Elastic Load Balancing.</p>
     pub policy_type_names: Option<Vec<String>>,
@@ -1822,6 +2007,8 @@ impl DescribeLoadBalancerPolicyTypesInputSerializer {
 
 /// <p>Contains the output of DescribeLoadBalancerPolicyTypes.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancerPolicyTypesOutput {
     /// <p>Information about the policy types.</p>
     pub policy_type_descriptions: Option<Vec<PolicyTypeDescription>>,
@@ -1856,11 +2043,24 @@ impl DescribeLoadBalancerPolicyTypesOutputDeserializer {
 }
 /// <p>Contains the parameters for DescribeTags.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsInput {
     /// <p>The names of the load balancers.</p>
     pub load_balancer_names: Vec<String>,
 }
 
+impl Validate for DescribeTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "load_balancer_names"),
+            self.load_balancer_names.len(),
+            Some(1),
+            Some(20),
+        );
+    }
+}
+
 /// Serialize `DescribeTagsInput` contents to a `SignedRequest`.
 struct DescribeTagsInputSerializer;
 impl DescribeTagsInputSerializer {
@@ -1880,6 +2080,8 @@ impl DescribeTagsInputSerializer {
 
 /// <p>Contains the output for DescribeTags.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsOutput {
     /// <p>Information about the tags.</p>
     pub tag_descriptions: Option<Vec<TagDescription>>,
@@ -1918,6 +2120,8 @@ impl DescriptionDeserializer {
 }
 /// <p>Contains the parameters for DetachLoadBalancerFromSubnets.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachLoadBalancerFromSubnetsInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: String,
@@ -1944,6 +2148,8 @@ impl DetachLoadBalancerFromSubnetsInputSerializer {
 
 /// <p>Contains the output of DetachLoadBalancerFromSubnets.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachLoadBalancerFromSubnetsOutput {
     /// <p>The IDs of the remaining subnets for the load balancer.</p>
     pub subnets: Option<Vec<String>>,
@@ -1975,6 +2181,8 @@ impl DetachLoadBalancerFromSubnetsOutputDeserializer {
 }
 /// <p>Information about a health check.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HealthCheck {
     /// <p>The number of consecutive health checks successes required before moving the instance to the <code>Healthy</code> state.</p>
     pub healthy_threshold: i64,
@@ -1988,6 +2196,35 @@ pub struct HealthCheck {
     pub unhealthy_threshold: i64,
 }
 
+impl Validate for HealthCheck {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "healthy_threshold"),
+            self.healthy_threshold,
+            Some(2),
+            Some(10),
+        );
+        errors.check_range(
+            &field_path(path, "interval"),
+            self.interval,
+            Some(5),
+            Some(300),
+        );
+        errors.check_range(
+            &field_path(path, "timeout"),
+            self.timeout,
+            Some(2),
+            Some(60),
+        );
+        errors.check_range(
+            &field_path(path, "unhealthy_threshold"),
+            self.unhealthy_threshold,
+            Some(2),
+            Some(10),
+        );
+    }
+}
+
 struct HealthCheckDeserializer;
 impl HealthCheckDeserializer {
     #[allow(unused_variables)]
@@ -2049,7 +2286,7 @@ impl HealthCheckIntervalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2071,7 +2308,7 @@ impl HealthCheckTimeoutDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2082,7 +2319,7 @@ impl HealthyThresholdDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2093,7 +2330,7 @@ impl IdleTimeoutDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2101,6 +2338,8 @@ impl IdleTimeoutDeserializer {
 }
 /// <p>The ID of an EC2 instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Instance {
     /// <p>The instance ID.</p>
     pub instance_id: Option<String>,
@@ -2157,7 +2396,7 @@ impl InstancePortDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2165,6 +2404,8 @@ impl InstancePortDeserializer {
 }
 /// <p>Information about the state of an EC2 instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceState {
     /// <p><p>A description of the instance state. This string can contain one or more of the following messages.</p> <ul> <li> <p> <code>N/A</code> </p> </li> <li> <p> <code>A transient error occurred. Please try again later.</code> </p> </li> <li> <p> <code>Instance has failed at least the UnhealthyThreshold number of health checks consecutively.</code> </p> </li> <li> <p> <code>Instance has not passed the configured HealthyThreshold number of health checks consecutively.</code> </p> </li> <li> <p> <code>Instance registration is still in progress.</code> </p> </li> <li> <p> <code>Instance is in the EC2 Availability Zone for which LoadBalancer is not configured to route traffic to.</code> </p> </li> <li> <p> <code>Instance is not currently registered with the LoadBalancer.</code> </p> </li> <li> <p> <code>Instance deregistration currently in progress.</code> </p> </li> <li> <p> <code>Disable Availability Zone is currently in progress.</code> </p> </li> <li> <p> <code>Instance is in pending state.</code> </p> </li> <li> <p> <code>Instance is in stopped state.</code> </p> </li> <li> <p> <code>Instance is in terminated state.</code> </p> </li> </ul></p>
     pub description: Option<String>,
@@ -2273,6 +2514,8 @@ impl LBCookieStickinessPoliciesDeserializer {
 }
 /// <p>Information about a policy for duration-based session stickiness.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LBCookieStickinessPolicy {
     /// <p>The time period, in seconds, after which the cookie should be considered stale. If this parameter is not specified, the stickiness session lasts for the duration of the browser session.</p>
     pub cookie_expiration_period: Option<i64>,
@@ -2312,6 +2555,8 @@ impl LBCookieStickinessPolicyDeserializer {
 }
 /// <p>Information about an Elastic Load Balancing resource limit for your AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Limit {
     /// <p>The maximum value of the limit.</p>
     pub max: Option<String>,
@@ -2356,6 +2601,8 @@ impl LimitsDeserializer {
 }
 /// <p>Information about a listener.</p> <p>For information about the protocols and the ports supported by Elastic Load Balancing, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-listener-config.html">Listeners for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Listener {
     /// <p>The port on which the instance is listening.</p>
     pub instance_port: i64,
@@ -2369,6 +2616,17 @@ pub struct Listener {
     pub ssl_certificate_id: Option<String>,
 }
 
+impl Validate for Listener {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "instance_port"),
+            self.instance_port,
+            Some(1),
+            Some(65535),
+        );
+    }
+}
+
 struct ListenerDeserializer;
 impl ListenerDeserializer {
     #[allow(unused_variables)]
@@ -2434,6 +2692,8 @@ impl ListenerSerializer {
 
 /// <p>The policies enabled for a listener.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListenerDescription {
     /// <p>The listener.</p>
     pub listener: Option<Listener>,
@@ -2497,6 +2757,8 @@ impl ListenersSerializer {
 
 /// <p>The attributes for a load balancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoadBalancerAttributes {
     /// <p>If enabled, the load balancer captures detailed information of all requests and delivers the information to the Amazon S3 bucket that you specify.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-access-logs.html">Enable Access Logs</a> in the <i>Classic Load Balancers Guide</i>.</p>
     pub access_log: Option<AccessLog>,
@@ -2510,6 +2772,26 @@ pub struct LoadBalancerAttributes {
     pub cross_zone_load_balancing: Option<CrossZoneLoadBalancing>,
 }
 
+impl Validate for LoadBalancerAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.additional_attributes {
+            errors.check_length(
+                &field_path(path, "additional_attributes"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "additional_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.connection_settings {
+            value.validate_at(&field_path(path, "connection_settings"), errors);
+        }
+    }
+}
+
 struct LoadBalancerAttributesDeserializer;
 impl LoadBalancerAttributesDeserializer {
     #[allow(unused_variables)]
@@ -2605,6 +2887,8 @@ impl LoadBalancerAttributesSerializer {
 
 /// <p>Information about a load balancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoadBalancerDescription {
     /// <p>The Availability Zones for the load balancer.</p>
     pub availability_zones: Option<Vec<String>>,
@@ -2819,6 +3103,8 @@ impl MaxDeserializer {
 }
 /// <p>Contains the parameters for ModifyLoadBalancerAttributes.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyLoadBalancerAttributesInput {
     /// <p>The attributes for the load balancer.</p>
     pub load_balancer_attributes: LoadBalancerAttributes,
@@ -2826,6 +3112,13 @@ pub struct ModifyLoadBalancerAttributesInput {
     pub load_balancer_name: String,
 }
 
+impl Validate for ModifyLoadBalancerAttributesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.load_balancer_attributes
+            .validate_at(&field_path(path, "load_balancer_attributes"), errors);
+    }
+}
+
 /// Serialize `ModifyLoadBalancerAttributesInput` contents to a `SignedRequest`.
 struct ModifyLoadBalancerAttributesInputSerializer;
 impl ModifyLoadBalancerAttributesInputSerializer {
@@ -2849,6 +3142,8 @@ impl ModifyLoadBalancerAttributesInputSerializer {
 
 /// <p>Contains the output of ModifyLoadBalancerAttributes.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyLoadBalancerAttributesOutput {
     /// <p>Information about the load balancer attributes.</p>
     pub load_balancer_attributes: Option<LoadBalancerAttributes>,
@@ -2901,6 +3196,8 @@ impl NameDeserializer {
 }
 /// <p>The policies for a load balancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Policies {
     /// <p>The stickiness policies created using <a>CreateAppCookieStickinessPolicy</a>.</p>
     pub app_cookie_stickiness_policies: Option<Vec<AppCookieStickinessPolicy>>,
@@ -2948,6 +3245,8 @@ impl PoliciesDeserializer {
 }
 /// <p>Information about a policy attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PolicyAttribute {
     /// <p>The name of the attribute.</p>
     pub attribute_name: Option<String>,
@@ -2975,6 +3274,8 @@ impl PolicyAttributeSerializer {
 
 /// <p>Information about a policy attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PolicyAttributeDescription {
     /// <p>The name of the attribute.</p>
     pub attribute_name: Option<String>,
@@ -3034,6 +3335,8 @@ impl PolicyAttributeDescriptionsDeserializer {
 }
 /// <p>Information about a policy attribute type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PolicyAttributeTypeDescription {
     /// <p>The name of the attribute.</p>
     pub attribute_name: Option<String>,
@@ -3125,6 +3428,8 @@ impl PolicyAttributesSerializer {
 
 /// <p>Information about a policy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PolicyDescription {
     /// <p>The policy attributes.</p>
     pub policy_attribute_descriptions: Option<Vec<PolicyAttributeDescription>>,
@@ -3226,6 +3531,8 @@ impl PolicyNamesSerializer {
 
 /// <p>Information about a policy type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PolicyTypeDescription {
     /// <p>A description of the policy type.</p>
     pub description: Option<String>,
@@ -3345,6 +3652,8 @@ impl ReasonCodeDeserializer {
 }
 /// <p>Contains the parameters for RegisterInstancesWithLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterEndPointsInput {
     /// <p>The IDs of the instances.</p>
     pub instances: Vec<Instance>,
@@ -3375,6 +3684,8 @@ impl RegisterEndPointsInputSerializer {
 
 /// <p>Contains the output of RegisterInstancesWithLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterEndPointsOutput {
     /// <p>The updated list of instances for the load balancer.</p>
     pub instances: Option<Vec<Instance>>,
@@ -3406,6 +3717,8 @@ impl RegisterEndPointsOutputDeserializer {
 }
 /// <p>Contains the parameters for DisableAvailabilityZonesForLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveAvailabilityZonesInput {
     /// <p>The Availability Zones.</p>
     pub availability_zones: Vec<String>,
@@ -3436,6 +3749,8 @@ impl RemoveAvailabilityZonesInputSerializer {
 
 /// <p>Contains the output for DisableAvailabilityZonesForLoadBalancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveAvailabilityZonesOutput {
     /// <p>The remaining Availability Zones for the load balancer.</p>
     pub availability_zones: Option<Vec<String>>,
@@ -3467,6 +3782,8 @@ impl RemoveAvailabilityZonesOutputDeserializer {
 }
 /// <p>Contains the parameters for RemoveTags.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsInput {
     /// <p>The name of the load balancer. You can specify a maximum of one load balancer name.</p>
     pub load_balancer_names: Vec<String>,
@@ -3474,6 +3791,16 @@ pub struct RemoveTagsInput {
     pub tags: Vec<TagKeyOnly>,
 }
 
+impl Validate for RemoveTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), Some(1), None);
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `RemoveTagsInput` contents to a `SignedRequest`.
 struct RemoveTagsInputSerializer;
 impl RemoveTagsInputSerializer {
@@ -3494,6 +3821,8 @@ impl RemoveTagsInputSerializer {
 
 /// <p>Contains the output of RemoveTags.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsOutput {}
 
 struct RemoveTagsOutputDeserializer;
@@ -3598,6 +3927,8 @@ impl SecurityGroupsSerializer {
 
 /// <p>Contains the parameters for SetLoadBalancerListenerSSLCertificate.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetLoadBalancerListenerSSLCertificateInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: String,
@@ -3637,6 +3968,8 @@ impl SetLoadBalancerListenerSSLCertificateInputSerializer {
 
 /// <p>Contains the output of SetLoadBalancerListenerSSLCertificate.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetLoadBalancerListenerSSLCertificateOutput {}
 
 struct SetLoadBalancerListenerSSLCertificateOutputDeserializer;
@@ -3657,6 +3990,8 @@ impl SetLoadBalancerListenerSSLCertificateOutputDeserializer {
 }
 /// <p>Contains the parameters for SetLoadBalancerPoliciesForBackendServer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetLoadBalancerPoliciesForBackendServerInput {
     /// <p>The port number associated with the EC2 instance.</p>
     pub instance_port: i64,
@@ -3694,6 +4029,8 @@ impl SetLoadBalancerPoliciesForBackendServerInputSerializer {
 
 /// <p>Contains the output of SetLoadBalancerPoliciesForBackendServer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetLoadBalancerPoliciesForBackendServerOutput {}
 
 struct SetLoadBalancerPoliciesForBackendServerOutputDeserializer;
@@ -3714,6 +4051,8 @@ impl SetLoadBalancerPoliciesForBackendServerOutputDeserializer {
 }
 /// <p>Contains the parameters for SetLoadBalancePoliciesOfListener.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetLoadBalancerPoliciesOfListenerInput {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: String,
@@ -3750,6 +4089,8 @@ impl SetLoadBalancerPoliciesOfListenerInputSerializer {
 
 /// <p>Contains the output of SetLoadBalancePoliciesOfListener.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetLoadBalancerPoliciesOfListenerOutput {}
 
 struct SetLoadBalancerPoliciesOfListenerOutputDeserializer;
@@ -3770,6 +4111,8 @@ impl SetLoadBalancerPoliciesOfListenerOutputDeserializer {
 }
 /// <p>Information about a source security group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SourceSecurityGroup {
     /// <p>The name of the security group.</p>
     pub group_name: Option<String>,
@@ -3857,6 +4200,8 @@ impl SubnetsSerializer {
 
 /// <p>Information about a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>The key of the tag.</p>
     pub key: String,
@@ -3864,6 +4209,35 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
@@ -3901,6 +4275,8 @@ impl TagSerializer {
 
 /// <p>The tags associated with a load balancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagDescription {
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: Option<String>,
@@ -3976,11 +4352,31 @@ impl TagKeyListSerializer {
 
 /// <p>The key of a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagKeyOnly {
     /// <p>The name of the key.</p>
     pub key: Option<String>,
 }
 
+impl Validate for TagKeyOnly {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 /// Serialize `TagKeyOnly` contents to a `SignedRequest`.
 struct TagKeyOnlySerializer;
 impl TagKeyOnlySerializer {
@@ -4041,7 +4437,7 @@ impl UnhealthyThresholdDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6140,208 +6536,1139 @@ pub trait Elb {
         input: SetLoadBalancerPoliciesOfListenerInput,
     ) -> RusotoFuture<SetLoadBalancerPoliciesOfListenerOutput, SetLoadBalancerPoliciesOfListenerError>;
 }
-/// A client for the Elastic Load Balancing API.
-#[derive(Clone)]
-pub struct ElbClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ElbClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ElbClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ElbClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
-        ElbClient { client, region }
-    }
-}
-
-impl Elb for ElbClient {
-    /// <p>Adds the specified tags to the specified load balancer. Each load balancer can have a maximum of 10 tags.</p> <p>Each tag consists of a key and an optional value. If a tag with the same key is already associated with the load balancer, <code>AddTags</code> updates its value.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/add-remove-tags.html">Tag Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+const _: Option<&dyn Elb> = None;
+impl<'a, T: Elb + ?Sized> Elb for &'a T {
     fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddTags");
-        params.put("Version", "2012-06-01");
-        AddTagsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AddTagsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AddTagsOutputDeserializer::deserialize("AddTagsResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).add_tags(input)
     }
-
-    /// <p>Associates one or more security groups with your load balancer in a virtual private cloud (VPC). The specified security groups override the previously associated security groups.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-security-groups.html#elb-vpc-security-groups">Security Groups for Load Balancers in a VPC</a> in the <i>Classic Load Balancers Guide</i>.</p>
     fn apply_security_groups_to_load_balancer(
         &self,
         input: ApplySecurityGroupsToLoadBalancerInput,
     ) -> RusotoFuture<ApplySecurityGroupsToLoadBalancerOutput, ApplySecurityGroupsToLoadBalancerError>
     {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ApplySecurityGroupsToLoadBalancer");
-        params.put("Version", "2012-06-01");
-        ApplySecurityGroupsToLoadBalancerInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ApplySecurityGroupsToLoadBalancerError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ApplySecurityGroupsToLoadBalancerOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ApplySecurityGroupsToLoadBalancerOutputDeserializer::deserialize(
-                        "ApplySecurityGroupsToLoadBalancerResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).apply_security_groups_to_load_balancer(input)
     }
-
-    /// <p>Adds one or more subnets to the set of configured subnets for the specified load balancer.</p> <p>The load balancer evenly distributes requests across all registered subnets. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-manage-subnets.html">Add or Remove Subnets for Your Load Balancer in a VPC</a> in the <i>Classic Load Balancers Guide</i>.</p>
     fn attach_load_balancer_to_subnets(
         &self,
         input: AttachLoadBalancerToSubnetsInput,
     ) -> RusotoFuture<AttachLoadBalancerToSubnetsOutput, AttachLoadBalancerToSubnetsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AttachLoadBalancerToSubnets");
-        params.put("Version", "2012-06-01");
-        AttachLoadBalancerToSubnetsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AttachLoadBalancerToSubnetsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AttachLoadBalancerToSubnetsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AttachLoadBalancerToSubnetsOutputDeserializer::deserialize(
-                        "AttachLoadBalancerToSubnetsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).attach_load_balancer_to_subnets(input)
     }
-
-    /// <p>Specifies the health check settings to use when evaluating the health state of your EC2 instances.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-healthchecks.html">Configure Health Checks for Your Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
     fn configure_health_check(
         &self,
         input: ConfigureHealthCheckInput,
     ) -> RusotoFuture<ConfigureHealthCheckOutput, ConfigureHealthCheckError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ConfigureHealthCheck");
-        params.put("Version", "2012-06-01");
-        ConfigureHealthCheckInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
+        (**self).configure_health_check(input)
+    }
+    fn create_app_cookie_stickiness_policy(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateAppCookieStickinessPolicyOutput, CreateAppCookieStickinessPolicyError>
+    {
+        (**self).create_app_cookie_stickiness_policy(input)
+    }
+    fn create_lb_cookie_stickiness_policy(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateLBCookieStickinessPolicyOutput, CreateLBCookieStickinessPolicyError>
+    {
+        (**self).create_lb_cookie_stickiness_policy(input)
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateAccessPointInput,
+    ) -> RusotoFuture<CreateAccessPointOutput, CreateLoadBalancerError> {
+        (**self).create_load_balancer(input)
+    }
+    fn create_load_balancer_listeners(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> RusotoFuture<CreateLoadBalancerListenerOutput, CreateLoadBalancerListenersError> {
+        (**self).create_load_balancer_listeners(input)
+    }
+    fn create_load_balancer_policy(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> RusotoFuture<CreateLoadBalancerPolicyOutput, CreateLoadBalancerPolicyError> {
+        (**self).create_load_balancer_policy(input)
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteAccessPointInput,
+    ) -> RusotoFuture<DeleteAccessPointOutput, DeleteLoadBalancerError> {
+        (**self).delete_load_balancer(input)
+    }
+    fn delete_load_balancer_listeners(
+        &self,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerListenerOutput, DeleteLoadBalancerListenersError> {
+        (**self).delete_load_balancer_listeners(input)
+    }
+    fn delete_load_balancer_policy(
+        &self,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> RusotoFuture<DeleteLoadBalancerPolicyOutput, DeleteLoadBalancerPolicyError> {
+        (**self).delete_load_balancer_policy(input)
+    }
+    fn deregister_instances_from_load_balancer(
+        &self,
+        input: DeregisterEndPointsInput,
+    ) -> RusotoFuture<DeregisterEndPointsOutput, DeregisterInstancesFromLoadBalancerError> {
+        (**self).deregister_instances_from_load_balancer(input)
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        (**self).describe_account_limits(input)
+    }
+    fn describe_instance_health(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> RusotoFuture<DescribeEndPointStateOutput, DescribeInstanceHealthError> {
+        (**self).describe_instance_health(input)
+    }
+    fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
+        (**self).describe_load_balancer_attributes(input)
+    }
+    fn describe_load_balancer_policies(
+        &self,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPoliciesOutput, DescribeLoadBalancerPoliciesError> {
+        (**self).describe_load_balancer_policies(input)
+    }
+    fn describe_load_balancer_policy_types(
+        &self,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPolicyTypesOutput, DescribeLoadBalancerPolicyTypesError>
+    {
+        (**self).describe_load_balancer_policy_types(input)
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> RusotoFuture<DescribeAccessPointsOutput, DescribeLoadBalancersError> {
+        (**self).describe_load_balancers(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn detach_load_balancer_from_subnets(
+        &self,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> RusotoFuture<DetachLoadBalancerFromSubnetsOutput, DetachLoadBalancerFromSubnetsError> {
+        (**self).detach_load_balancer_from_subnets(input)
+    }
+    fn disable_availability_zones_for_load_balancer(
+        &self,
+        input: RemoveAvailabilityZonesInput,
+    ) -> RusotoFuture<RemoveAvailabilityZonesOutput, DisableAvailabilityZonesForLoadBalancerError>
+    {
+        (**self).disable_availability_zones_for_load_balancer(input)
+    }
+    fn enable_availability_zones_for_load_balancer(
+        &self,
+        input: AddAvailabilityZonesInput,
+    ) -> RusotoFuture<AddAvailabilityZonesOutput, EnableAvailabilityZonesForLoadBalancerError> {
+        (**self).enable_availability_zones_for_load_balancer(input)
+    }
+    fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        (**self).modify_load_balancer_attributes(input)
+    }
+    fn register_instances_with_load_balancer(
+        &self,
+        input: RegisterEndPointsInput,
+    ) -> RusotoFuture<RegisterEndPointsOutput, RegisterInstancesWithLoadBalancerError> {
+        (**self).register_instances_with_load_balancer(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn set_load_balancer_listener_ssl_certificate(
+        &self,
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> RusotoFuture<
+        SetLoadBalancerListenerSSLCertificateOutput,
+        SetLoadBalancerListenerSSLCertificateError,
+    > {
+        (**self).set_load_balancer_listener_ssl_certificate(input)
+    }
+    fn set_load_balancer_policies_for_backend_server(
+        &self,
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> RusotoFuture<
+        SetLoadBalancerPoliciesForBackendServerOutput,
+        SetLoadBalancerPoliciesForBackendServerError,
+    > {
+        (**self).set_load_balancer_policies_for_backend_server(input)
+    }
+    fn set_load_balancer_policies_of_listener(
+        &self,
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> RusotoFuture<SetLoadBalancerPoliciesOfListenerOutput, SetLoadBalancerPoliciesOfListenerError>
+    {
+        (**self).set_load_balancer_policies_of_listener(input)
+    }
+}
+impl<T: Elb + ?Sized> Elb for Box<T> {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn apply_security_groups_to_load_balancer(
+        &self,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> RusotoFuture<ApplySecurityGroupsToLoadBalancerOutput, ApplySecurityGroupsToLoadBalancerError>
+    {
+        (**self).apply_security_groups_to_load_balancer(input)
+    }
+    fn attach_load_balancer_to_subnets(
+        &self,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> RusotoFuture<AttachLoadBalancerToSubnetsOutput, AttachLoadBalancerToSubnetsError> {
+        (**self).attach_load_balancer_to_subnets(input)
+    }
+    fn configure_health_check(
+        &self,
+        input: ConfigureHealthCheckInput,
+    ) -> RusotoFuture<ConfigureHealthCheckOutput, ConfigureHealthCheckError> {
+        (**self).configure_health_check(input)
+    }
+    fn create_app_cookie_stickiness_policy(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateAppCookieStickinessPolicyOutput, CreateAppCookieStickinessPolicyError>
+    {
+        (**self).create_app_cookie_stickiness_policy(input)
+    }
+    fn create_lb_cookie_stickiness_policy(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateLBCookieStickinessPolicyOutput, CreateLBCookieStickinessPolicyError>
+    {
+        (**self).create_lb_cookie_stickiness_policy(input)
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateAccessPointInput,
+    ) -> RusotoFuture<CreateAccessPointOutput, CreateLoadBalancerError> {
+        (**self).create_load_balancer(input)
+    }
+    fn create_load_balancer_listeners(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> RusotoFuture<CreateLoadBalancerListenerOutput, CreateLoadBalancerListenersError> {
+        (**self).create_load_balancer_listeners(input)
+    }
+    fn create_load_balancer_policy(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> RusotoFuture<CreateLoadBalancerPolicyOutput, CreateLoadBalancerPolicyError> {
+        (**self).create_load_balancer_policy(input)
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteAccessPointInput,
+    ) -> RusotoFuture<DeleteAccessPointOutput, DeleteLoadBalancerError> {
+        (**self).delete_load_balancer(input)
+    }
+    fn delete_load_balancer_listeners(
+        &self,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerListenerOutput, DeleteLoadBalancerListenersError> {
+        (**self).delete_load_balancer_listeners(input)
+    }
+    fn delete_load_balancer_policy(
+        &self,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> RusotoFuture<DeleteLoadBalancerPolicyOutput, DeleteLoadBalancerPolicyError> {
+        (**self).delete_load_balancer_policy(input)
+    }
+    fn deregister_instances_from_load_balancer(
+        &self,
+        input: DeregisterEndPointsInput,
+    ) -> RusotoFuture<DeregisterEndPointsOutput, DeregisterInstancesFromLoadBalancerError> {
+        (**self).deregister_instances_from_load_balancer(input)
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        (**self).describe_account_limits(input)
+    }
+    fn describe_instance_health(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> RusotoFuture<DescribeEndPointStateOutput, DescribeInstanceHealthError> {
+        (**self).describe_instance_health(input)
+    }
+    fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
+        (**self).describe_load_balancer_attributes(input)
+    }
+    fn describe_load_balancer_policies(
+        &self,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPoliciesOutput, DescribeLoadBalancerPoliciesError> {
+        (**self).describe_load_balancer_policies(input)
+    }
+    fn describe_load_balancer_policy_types(
+        &self,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPolicyTypesOutput, DescribeLoadBalancerPolicyTypesError>
+    {
+        (**self).describe_load_balancer_policy_types(input)
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> RusotoFuture<DescribeAccessPointsOutput, DescribeLoadBalancersError> {
+        (**self).describe_load_balancers(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn detach_load_balancer_from_subnets(
+        &self,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> RusotoFuture<DetachLoadBalancerFromSubnetsOutput, DetachLoadBalancerFromSubnetsError> {
+        (**self).detach_load_balancer_from_subnets(input)
+    }
+    fn disable_availability_zones_for_load_balancer(
+        &self,
+        input: RemoveAvailabilityZonesInput,
+    ) -> RusotoFuture<RemoveAvailabilityZonesOutput, DisableAvailabilityZonesForLoadBalancerError>
+    {
+        (**self).disable_availability_zones_for_load_balancer(input)
+    }
+    fn enable_availability_zones_for_load_balancer(
+        &self,
+        input: AddAvailabilityZonesInput,
+    ) -> RusotoFuture<AddAvailabilityZonesOutput, EnableAvailabilityZonesForLoadBalancerError> {
+        (**self).enable_availability_zones_for_load_balancer(input)
+    }
+    fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        (**self).modify_load_balancer_attributes(input)
+    }
+    fn register_instances_with_load_balancer(
+        &self,
+        input: RegisterEndPointsInput,
+    ) -> RusotoFuture<RegisterEndPointsOutput, RegisterInstancesWithLoadBalancerError> {
+        (**self).register_instances_with_load_balancer(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn set_load_balancer_listener_ssl_certificate(
+        &self,
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> RusotoFuture<
+        SetLoadBalancerListenerSSLCertificateOutput,
+        SetLoadBalancerListenerSSLCertificateError,
+    > {
+        (**self).set_load_balancer_listener_ssl_certificate(input)
+    }
+    fn set_load_balancer_policies_for_backend_server(
+        &self,
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> RusotoFuture<
+        SetLoadBalancerPoliciesForBackendServerOutput,
+        SetLoadBalancerPoliciesForBackendServerError,
+    > {
+        (**self).set_load_balancer_policies_for_backend_server(input)
+    }
+    fn set_load_balancer_policies_of_listener(
+        &self,
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> RusotoFuture<SetLoadBalancerPoliciesOfListenerOutput, SetLoadBalancerPoliciesOfListenerError>
+    {
+        (**self).set_load_balancer_policies_of_listener(input)
+    }
+}
+impl<T: Elb + ?Sized> Elb for ::std::sync::Arc<T> {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn apply_security_groups_to_load_balancer(
+        &self,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> RusotoFuture<ApplySecurityGroupsToLoadBalancerOutput, ApplySecurityGroupsToLoadBalancerError>
+    {
+        (**self).apply_security_groups_to_load_balancer(input)
+    }
+    fn attach_load_balancer_to_subnets(
+        &self,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> RusotoFuture<AttachLoadBalancerToSubnetsOutput, AttachLoadBalancerToSubnetsError> {
+        (**self).attach_load_balancer_to_subnets(input)
+    }
+    fn configure_health_check(
+        &self,
+        input: ConfigureHealthCheckInput,
+    ) -> RusotoFuture<ConfigureHealthCheckOutput, ConfigureHealthCheckError> {
+        (**self).configure_health_check(input)
+    }
+    fn create_app_cookie_stickiness_policy(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateAppCookieStickinessPolicyOutput, CreateAppCookieStickinessPolicyError>
+    {
+        (**self).create_app_cookie_stickiness_policy(input)
+    }
+    fn create_lb_cookie_stickiness_policy(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateLBCookieStickinessPolicyOutput, CreateLBCookieStickinessPolicyError>
+    {
+        (**self).create_lb_cookie_stickiness_policy(input)
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateAccessPointInput,
+    ) -> RusotoFuture<CreateAccessPointOutput, CreateLoadBalancerError> {
+        (**self).create_load_balancer(input)
+    }
+    fn create_load_balancer_listeners(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> RusotoFuture<CreateLoadBalancerListenerOutput, CreateLoadBalancerListenersError> {
+        (**self).create_load_balancer_listeners(input)
+    }
+    fn create_load_balancer_policy(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> RusotoFuture<CreateLoadBalancerPolicyOutput, CreateLoadBalancerPolicyError> {
+        (**self).create_load_balancer_policy(input)
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteAccessPointInput,
+    ) -> RusotoFuture<DeleteAccessPointOutput, DeleteLoadBalancerError> {
+        (**self).delete_load_balancer(input)
+    }
+    fn delete_load_balancer_listeners(
+        &self,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerListenerOutput, DeleteLoadBalancerListenersError> {
+        (**self).delete_load_balancer_listeners(input)
+    }
+    fn delete_load_balancer_policy(
+        &self,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> RusotoFuture<DeleteLoadBalancerPolicyOutput, DeleteLoadBalancerPolicyError> {
+        (**self).delete_load_balancer_policy(input)
+    }
+    fn deregister_instances_from_load_balancer(
+        &self,
+        input: DeregisterEndPointsInput,
+    ) -> RusotoFuture<DeregisterEndPointsOutput, DeregisterInstancesFromLoadBalancerError> {
+        (**self).deregister_instances_from_load_balancer(input)
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        (**self).describe_account_limits(input)
+    }
+    fn describe_instance_health(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> RusotoFuture<DescribeEndPointStateOutput, DescribeInstanceHealthError> {
+        (**self).describe_instance_health(input)
+    }
+    fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
+        (**self).describe_load_balancer_attributes(input)
+    }
+    fn describe_load_balancer_policies(
+        &self,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPoliciesOutput, DescribeLoadBalancerPoliciesError> {
+        (**self).describe_load_balancer_policies(input)
+    }
+    fn describe_load_balancer_policy_types(
+        &self,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPolicyTypesOutput, DescribeLoadBalancerPolicyTypesError>
+    {
+        (**self).describe_load_balancer_policy_types(input)
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> RusotoFuture<DescribeAccessPointsOutput, DescribeLoadBalancersError> {
+        (**self).describe_load_balancers(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn detach_load_balancer_from_subnets(
+        &self,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> RusotoFuture<DetachLoadBalancerFromSubnetsOutput, DetachLoadBalancerFromSubnetsError> {
+        (**self).detach_load_balancer_from_subnets(input)
+    }
+    fn disable_availability_zones_for_load_balancer(
+        &self,
+        input: RemoveAvailabilityZonesInput,
+    ) -> RusotoFuture<RemoveAvailabilityZonesOutput, DisableAvailabilityZonesForLoadBalancerError>
+    {
+        (**self).disable_availability_zones_for_load_balancer(input)
+    }
+    fn enable_availability_zones_for_load_balancer(
+        &self,
+        input: AddAvailabilityZonesInput,
+    ) -> RusotoFuture<AddAvailabilityZonesOutput, EnableAvailabilityZonesForLoadBalancerError> {
+        (**self).enable_availability_zones_for_load_balancer(input)
+    }
+    fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        (**self).modify_load_balancer_attributes(input)
+    }
+    fn register_instances_with_load_balancer(
+        &self,
+        input: RegisterEndPointsInput,
+    ) -> RusotoFuture<RegisterEndPointsOutput, RegisterInstancesWithLoadBalancerError> {
+        (**self).register_instances_with_load_balancer(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn set_load_balancer_listener_ssl_certificate(
+        &self,
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> RusotoFuture<
+        SetLoadBalancerListenerSSLCertificateOutput,
+        SetLoadBalancerListenerSSLCertificateError,
+    > {
+        (**self).set_load_balancer_listener_ssl_certificate(input)
+    }
+    fn set_load_balancer_policies_for_backend_server(
+        &self,
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> RusotoFuture<
+        SetLoadBalancerPoliciesForBackendServerOutput,
+        SetLoadBalancerPoliciesForBackendServerError,
+    > {
+        (**self).set_load_balancer_policies_for_backend_server(input)
+    }
+    fn set_load_balancer_policies_of_listener(
+        &self,
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> RusotoFuture<SetLoadBalancerPoliciesOfListenerOutput, SetLoadBalancerPoliciesOfListenerError>
+    {
+        (**self).set_load_balancer_policies_of_listener(input)
+    }
+}
+/// A client for the Elastic Load Balancing API.
+#[derive(Clone)]
+pub struct ElbClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ElbClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ElbClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElbClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
+        ElbClient { client, region }
+    }
+}
+
+impl Elb for ElbClient {
+    /// <p>Adds the specified tags to the specified load balancer. Each load balancer can have a maximum of 10 tags.</p> <p>Each tag consists of a key and an optional value. If a tag with the same key is already associated with the load balancer, <code>AddTags</code> updates its value.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/add-remove-tags.html">Tag Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddTags");
+        let mut params = Params::new();
+
+        params.put("Action", "AddTags");
+        params.put("Version", "2012-06-01");
+        AddTagsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AddTagsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AddTagsOutputDeserializer::deserialize("AddTagsResult", &mut stack)
+                        .map_err(|err| stack.parse_error("AddTags", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Associates one or more security groups with your load balancer in a virtual private cloud (VPC). The specified security groups override the previously associated security groups.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-security-groups.html#elb-vpc-security-groups">Security Groups for Load Balancers in a VPC</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn apply_security_groups_to_load_balancer(
+        &self,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> RusotoFuture<ApplySecurityGroupsToLoadBalancerOutput, ApplySecurityGroupsToLoadBalancerError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ApplySecurityGroupsToLoadBalancer");
+        let mut params = Params::new();
+
+        params.put("Action", "ApplySecurityGroupsToLoadBalancer");
+        params.put("Version", "2012-06-01");
+        ApplySecurityGroupsToLoadBalancerInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ApplySecurityGroupsToLoadBalancerError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ApplySecurityGroupsToLoadBalancerOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ApplySecurityGroupsToLoadBalancerOutputDeserializer::deserialize(
+                        "ApplySecurityGroupsToLoadBalancerResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ApplySecurityGroupsToLoadBalancer", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Adds one or more subnets to the set of configured subnets for the specified load balancer.</p> <p>The load balancer evenly distributes requests across all registered subnets. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-manage-subnets.html">Add or Remove Subnets for Your Load Balancer in a VPC</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn attach_load_balancer_to_subnets(
+        &self,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> RusotoFuture<AttachLoadBalancerToSubnetsOutput, AttachLoadBalancerToSubnetsError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AttachLoadBalancerToSubnets");
+        let mut params = Params::new();
+
+        params.put("Action", "AttachLoadBalancerToSubnets");
+        params.put("Version", "2012-06-01");
+        AttachLoadBalancerToSubnetsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AttachLoadBalancerToSubnetsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AttachLoadBalancerToSubnetsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AttachLoadBalancerToSubnetsOutputDeserializer::deserialize(
+                        "AttachLoadBalancerToSubnetsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("AttachLoadBalancerToSubnets", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Specifies the health check settings to use when evaluating the health state of your EC2 instances.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-healthchecks.html">Configure Health Checks for Your Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn configure_health_check(
+        &self,
+        input: ConfigureHealthCheckInput,
+    ) -> RusotoFuture<ConfigureHealthCheckOutput, ConfigureHealthCheckError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ConfigureHealthCheck");
+        let mut params = Params::new();
+
+        params.put("Action", "ConfigureHealthCheck");
+        params.put("Version", "2012-06-01");
+        ConfigureHealthCheckInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ConfigureHealthCheckError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ConfigureHealthCheckOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ConfigureHealthCheckOutputDeserializer::deserialize(
+                        "ConfigureHealthCheckResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ConfigureHealthCheck", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Generates a stickiness policy with sticky session lifetimes that follow that of an application-generated cookie. This policy can be associated only with HTTP/HTTPS listeners.</p> <p>This policy is similar to the policy created by <a>CreateLBCookieStickinessPolicy</a>, except that the lifetime of the special Elastic Load Balancing cookie, <code>AWSELB</code>, follows the lifetime of the application-generated cookie specified in the policy configuration. The load balancer only inserts a new stickiness cookie when the application response includes a new application cookie.</p> <p>If the application cookie is explicitly removed or expires, the session stops being sticky until a new application cookie is issued.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-application">Application-Controlled Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn create_app_cookie_stickiness_policy(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateAppCookieStickinessPolicyOutput, CreateAppCookieStickinessPolicyError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateAppCookieStickinessPolicy");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateAppCookieStickinessPolicy");
+        params.put("Version", "2012-06-01");
+        CreateAppCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateAppCookieStickinessPolicyError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateAppCookieStickinessPolicyOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateAppCookieStickinessPolicyOutputDeserializer::deserialize(
+                        "CreateAppCookieStickinessPolicyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateAppCookieStickinessPolicy", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Generates a stickiness policy with sticky session lifetimes controlled by the lifetime of the browser (user-agent) or a specified expiration period. This policy can be associated only with HTTP/HTTPS listeners.</p> <p>When a load balancer implements this policy, the load balancer uses a special cookie to track the instance for each request. When the load balancer receives a request, it first checks to see if this cookie is present in the request. If so, the load balancer sends the request to the application server specified in the cookie. If not, the load balancer sends the request to a server that is chosen based on the existing load-balancing algorithm.</p> <p>A cookie is inserted into the response for binding subsequent requests from the same user to that server. The validity of the cookie is based on the cookie expiration time, which is specified in the policy configuration.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-duration">Duration-Based Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn create_lb_cookie_stickiness_policy(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateLBCookieStickinessPolicyOutput, CreateLBCookieStickinessPolicyError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLBCookieStickinessPolicy");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateLBCookieStickinessPolicy");
+        params.put("Version", "2012-06-01");
+        CreateLBCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateLBCookieStickinessPolicyError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateLBCookieStickinessPolicyOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateLBCookieStickinessPolicyOutputDeserializer::deserialize(
+                        "CreateLBCookieStickinessPolicyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateLBCookieStickinessPolicy", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a Classic Load Balancer.</p> <p>You can add listeners, security groups, subnets, and tags when you create your load balancer, or you can add them later using <a>CreateLoadBalancerListeners</a>, <a>ApplySecurityGroupsToLoadBalancer</a>, <a>AttachLoadBalancerToSubnets</a>, and <a>AddTags</a>.</p> <p>To describe your current load balancers, see <a>DescribeLoadBalancers</a>. When you are finished with a load balancer, you can delete it using <a>DeleteLoadBalancer</a>.</p> <p>You can create up to 20 load balancers per region per account. You can request an increase for the number of load balancers for your account. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-limits.html">Limits for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn create_load_balancer(
+        &self,
+        input: CreateAccessPointInput,
+    ) -> RusotoFuture<CreateAccessPointOutput, CreateLoadBalancerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateLoadBalancer");
+        params.put("Version", "2012-06-01");
+        CreateAccessPointInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateLoadBalancerError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateAccessPointOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateAccessPointOutputDeserializer::deserialize(
+                        "CreateLoadBalancerResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateLoadBalancer", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates one or more listeners for the specified load balancer. If a listener with the specified port does not already exist, it is created; otherwise, the properties of the new listener must match the properties of the existing listener.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-listener-config.html">Listeners for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn create_load_balancer_listeners(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> RusotoFuture<CreateLoadBalancerListenerOutput, CreateLoadBalancerListenersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancerListeners");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateLoadBalancerListeners");
+        params.put("Version", "2012-06-01");
+        CreateLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateLoadBalancerListenersError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateLoadBalancerListenerOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateLoadBalancerListenerOutputDeserializer::deserialize(
+                        "CreateLoadBalancerListenersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateLoadBalancerListeners", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a policy with the specified attributes for the specified load balancer.</p> <p>Policies are settings that are saved for your load balancer and that can be applied to the listener or the application server, depending on the policy type.</p>
+    fn create_load_balancer_policy(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> RusotoFuture<CreateLoadBalancerPolicyOutput, CreateLoadBalancerPolicyError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancerPolicy");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateLoadBalancerPolicy");
+        params.put("Version", "2012-06-01");
+        CreateLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateLoadBalancerPolicyError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateLoadBalancerPolicyOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateLoadBalancerPolicyOutputDeserializer::deserialize(
+                        "CreateLoadBalancerPolicyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateLoadBalancerPolicy", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified load balancer.</p> <p>If you are attempting to recreate a load balancer, you must reconfigure all settings. The DNS name associated with a deleted load balancer are no longer usable. The name and associated DNS record of the deleted load balancer no longer exist and traffic sent to any of its IP addresses is no longer delivered to your instances.</p> <p>If the load balancer does not exist or has already been deleted, the call to <code>DeleteLoadBalancer</code> still succeeds.</p>
+    fn delete_load_balancer(
+        &self,
+        input: DeleteAccessPointInput,
+    ) -> RusotoFuture<DeleteAccessPointOutput, DeleteLoadBalancerError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteLoadBalancer");
+        params.put("Version", "2012-06-01");
+        DeleteAccessPointInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
                 return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ConfigureHealthCheckError::from_response(response))
-                    }),
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteLoadBalancerError::from_response(response))),
                 );
             }
 
@@ -6349,8 +7676,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = ConfigureHealthCheckOutput::default();
+                    result = DeleteAccessPointOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6359,10 +7687,11 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = ConfigureHealthCheckOutputDeserializer::deserialize(
-                        "ConfigureHealthCheckResult",
+                    result = DeleteAccessPointOutputDeserializer::deserialize(
+                        "DeleteLoadBalancerResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DeleteLoadBalancer", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6372,27 +7701,25 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Generates a stickiness policy with sticky session lifetimes that follow that of an application-generated cookie. This policy can be associated only with HTTP/HTTPS listeners.</p> <p>This policy is similar to the policy created by <a>CreateLBCookieStickinessPolicy</a>, except that the lifetime of the special Elastic Load Balancing cookie, <code>AWSELB</code>, follows the lifetime of the application-generated cookie specified in the policy configuration. The load balancer only inserts a new stickiness cookie when the application response includes a new application cookie.</p> <p>If the application cookie is explicitly removed or expires, the session stops being sticky until a new application cookie is issued.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-application">Application-Controlled Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    fn create_app_cookie_stickiness_policy(
+    /// <p>Deletes the specified listeners from the specified load balancer.</p>
+    fn delete_load_balancer_listeners(
         &self,
-        input: CreateAppCookieStickinessPolicyInput,
-    ) -> RusotoFuture<CreateAppCookieStickinessPolicyOutput, CreateAppCookieStickinessPolicyError>
-    {
+        input: DeleteLoadBalancerListenerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerListenerOutput, DeleteLoadBalancerListenersError> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerListeners");
         let mut params = Params::new();
 
-        params.put("Action", "CreateAppCookieStickinessPolicy");
+        params.put("Action", "DeleteLoadBalancerListeners");
         params.put("Version", "2012-06-01");
-        CreateAppCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
+        DeleteLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateAppCookieStickinessPolicyError::from_response(
-                        response,
-                    ))
+                    Err(DeleteLoadBalancerListenersError::from_response(response))
                 }));
             }
 
@@ -6400,8 +7727,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = CreateAppCookieStickinessPolicyOutput::default();
+                    result = DeleteLoadBalancerListenerOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6410,10 +7738,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateAppCookieStickinessPolicyOutputDeserializer::deserialize(
-                        "CreateAppCookieStickinessPolicyResult",
+                    result = DeleteLoadBalancerListenerOutputDeserializer::deserialize(
+                        "DeleteLoadBalancerListenersResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteLoadBalancerListeners", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6423,25 +7754,25 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Generates a stickiness policy with sticky session lifetimes controlled by the lifetime of the browser (user-agent) or a specified expiration period. This policy can be associated only with HTTP/HTTPS listeners.</p> <p>When a load balancer implements this policy, the load balancer uses a special cookie to track the instance for each request. When the load balancer receives a request, it first checks to see if this cookie is present in the request. If so, the load balancer sends the request to the application server specified in the cookie. If not, the load balancer sends the request to a server that is chosen based on the existing load-balancing algorithm.</p> <p>A cookie is inserted into the response for binding subsequent requests from the same user to that server. The validity of the cookie is based on the cookie expiration time, which is specified in the policy configuration.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-duration">Duration-Based Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    fn create_lb_cookie_stickiness_policy(
+    /// <p>Deletes the specified policy from the specified load balancer. This policy must not be enabled for any listeners.</p>
+    fn delete_load_balancer_policy(
         &self,
-        input: CreateLBCookieStickinessPolicyInput,
-    ) -> RusotoFuture<CreateLBCookieStickinessPolicyOutput, CreateLBCookieStickinessPolicyError>
-    {
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> RusotoFuture<DeleteLoadBalancerPolicyOutput, DeleteLoadBalancerPolicyError> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerPolicy");
         let mut params = Params::new();
 
-        params.put("Action", "CreateLBCookieStickinessPolicy");
+        params.put("Action", "DeleteLoadBalancerPolicy");
         params.put("Version", "2012-06-01");
-        CreateLBCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
+        DeleteLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateLBCookieStickinessPolicyError::from_response(response))
+                    Err(DeleteLoadBalancerPolicyError::from_response(response))
                 }));
             }
 
@@ -6449,8 +7780,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = CreateLBCookieStickinessPolicyOutput::default();
+                    result = DeleteLoadBalancerPolicyOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6459,10 +7791,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateLBCookieStickinessPolicyOutputDeserializer::deserialize(
-                        "CreateLBCookieStickinessPolicyResult",
+                    result = DeleteLoadBalancerPolicyOutputDeserializer::deserialize(
+                        "DeleteLoadBalancerPolicyResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteLoadBalancerPolicy", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6472,36 +7807,37 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Creates a Classic Load Balancer.</p> <p>You can add listeners, security groups, subnets, and tags when you create your load balancer, or you can add them later using <a>CreateLoadBalancerListeners</a>, <a>ApplySecurityGroupsToLoadBalancer</a>, <a>AttachLoadBalancerToSubnets</a>, and <a>AddTags</a>.</p> <p>To describe your current load balancers, see <a>DescribeLoadBalancers</a>. When you are finished with a load balancer, you can delete it using <a>DeleteLoadBalancer</a>.</p> <p>You can create up to 20 load balancers per region per account. You can request an increase for the number of load balancers for your account. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-limits.html">Limits for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    fn create_load_balancer(
+    /// <p>Deregisters the specified instances from the specified load balancer. After the instance is deregistered, it no longer receives traffic from the load balancer.</p> <p>You can use <a>DescribeLoadBalancers</a> to verify that the instance is deregistered from the load balancer.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-deregister-register-instances.html">Register or De-Register EC2 Instances</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn deregister_instances_from_load_balancer(
         &self,
-        input: CreateAccessPointInput,
-    ) -> RusotoFuture<CreateAccessPointOutput, CreateLoadBalancerError> {
+        input: DeregisterEndPointsInput,
+    ) -> RusotoFuture<DeregisterEndPointsOutput, DeregisterInstancesFromLoadBalancerError> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeregisterInstancesFromLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "CreateLoadBalancer");
+        params.put("Action", "DeregisterInstancesFromLoadBalancer");
         params.put("Version", "2012-06-01");
-        CreateAccessPointInputSerializer::serialize(&mut params, "", &input);
+        DeregisterEndPointsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateLoadBalancerError::from_response(response))),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterInstancesFromLoadBalancerError::from_response(
+                        response,
+                    ))
+                }));
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let result;
 
                 if response.body.is_empty() {
-                    result = CreateAccessPointOutput::default();
+                    result = DeregisterEndPointsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6510,10 +7846,17 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateAccessPointOutputDeserializer::deserialize(
-                        "CreateLoadBalancerResult",
+                    result = DeregisterEndPointsOutputDeserializer::deserialize(
+                        "DeregisterInstancesFromLoadBalancerResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "DeregisterInstancesFromLoadBalancer",
+                            err,
+                            &response.body,
+                        )
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6523,24 +7866,33 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Creates one or more listeners for the specified load balancer. If a listener with the specified port does not already exist, it is created; otherwise, the properties of the new listener must match the properties of the existing listener.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-listener-config.html">Listeners for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    fn create_load_balancer_listeners(
+    /// <p>Describes the current Elastic Load Balancing resource limits for your AWS account.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-limits.html">Limits for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn describe_account_limits(
         &self,
-        input: CreateLoadBalancerListenerInput,
-    ) -> RusotoFuture<CreateLoadBalancerListenerOutput, CreateLoadBalancerListenersError> {
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let mut params = Params::new();
 
-        params.put("Action", "CreateLoadBalancerListeners");
+        params.put("Action", "DescribeAccountLimits");
         params.put("Version", "2012-06-01");
-        CreateLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
+        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateLoadBalancerListenersError::from_response(response))
+                    Err(DescribeAccountLimitsError::from_response(response))
                 }));
             }
 
@@ -6548,8 +7900,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = CreateLoadBalancerListenerOutput::default();
+                    result = DescribeAccountLimitsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6558,10 +7911,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateLoadBalancerListenerOutputDeserializer::deserialize(
-                        "CreateLoadBalancerListenersResult",
+                    result = DescribeAccountLimitsOutputDeserializer::deserialize(
+                        "DescribeAccountLimitsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAccountLimits", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6571,24 +7927,25 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Creates a policy with the specified attributes for the specified load balancer.</p> <p>Policies are settings that are saved for your load balancer and that can be applied to the listener or the application server, depending on the policy type.</p>
-    fn create_load_balancer_policy(
+    /// <p>Describes the state of the specified instances with respect to the specified load balancer. If no instances are specified, the call describes the state of all instances that are currently registered with the load balancer. If instances are specified, their state is returned even if they are no longer registered with the load balancer. The state of terminated instances is not returned.</p>
+    fn describe_instance_health(
         &self,
-        input: CreateLoadBalancerPolicyInput,
-    ) -> RusotoFuture<CreateLoadBalancerPolicyOutput, CreateLoadBalancerPolicyError> {
+        input: DescribeEndPointStateInput,
+    ) -> RusotoFuture<DescribeEndPointStateOutput, DescribeInstanceHealthError> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeInstanceHealth");
         let mut params = Params::new();
 
-        params.put("Action", "CreateLoadBalancerPolicy");
+        params.put("Action", "DescribeInstanceHealth");
         params.put("Version", "2012-06-01");
-        CreateLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
+        DescribeEndPointStateInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateLoadBalancerPolicyError::from_response(response))
+                    Err(DescribeInstanceHealthError::from_response(response))
                 }));
             }
 
@@ -6596,8 +7953,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = CreateLoadBalancerPolicyOutput::default();
+                    result = DescribeEndPointStateOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6606,10 +7964,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateLoadBalancerPolicyOutputDeserializer::deserialize(
-                        "CreateLoadBalancerPolicyResult",
+                    result = DescribeEndPointStateOutputDeserializer::deserialize(
+                        "DescribeInstanceHealthResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeInstanceHealth", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6619,36 +7980,36 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Deletes the specified load balancer.</p> <p>If you are attempting to recreate a load balancer, you must reconfigure all settings. The DNS name associated with a deleted load balancer are no longer usable. The name and associated DNS record of the deleted load balancer no longer exist and traffic sent to any of its IP addresses is no longer delivered to your instances.</p> <p>If the load balancer does not exist or has already been deleted, the call to <code>DeleteLoadBalancer</code> still succeeds.</p>
-    fn delete_load_balancer(
+    /// <p>Describes the attributes for the specified load balancer.</p>
+    fn describe_load_balancer_attributes(
         &self,
-        input: DeleteAccessPointInput,
-    ) -> RusotoFuture<DeleteAccessPointOutput, DeleteLoadBalancerError> {
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteLoadBalancer");
+        params.put("Action", "DescribeLoadBalancerAttributes");
         params.put("Version", "2012-06-01");
-        DeleteAccessPointInputSerializer::serialize(&mut params, "", &input);
+        DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteLoadBalancerError::from_response(response))),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeLoadBalancerAttributesError::from_response(response))
+                }));
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DeleteAccessPointOutput::default();
+                    result = DescribeLoadBalancerAttributesOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6657,10 +8018,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteAccessPointOutputDeserializer::deserialize(
-                        "DeleteLoadBalancerResult",
+                    result = DescribeLoadBalancerAttributesOutputDeserializer::deserialize(
+                        "DescribeLoadBalancerAttributesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLoadBalancerAttributes", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6670,24 +8034,25 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Deletes the specified listeners from the specified load balancer.</p>
-    fn delete_load_balancer_listeners(
+    /// <p>Describes the specified policies.</p> <p>If you specify a load balancer name, the action returns the descriptions of all policies created for the load balancer. If you specify a policy name associated with your load balancer, the action returns the description of that policy. If you don't specify a load balancer name, the action returns descriptions of the specified sample policies, or descriptions of all sample policies. The names of the sample policies have the <code>ELBSample-</code> prefix.</p>
+    fn describe_load_balancer_policies(
         &self,
-        input: DeleteLoadBalancerListenerInput,
-    ) -> RusotoFuture<DeleteLoadBalancerListenerOutput, DeleteLoadBalancerListenersError> {
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPoliciesOutput, DescribeLoadBalancerPoliciesError> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerPolicies");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteLoadBalancerListeners");
+        params.put("Action", "DescribeLoadBalancerPolicies");
         params.put("Version", "2012-06-01");
-        DeleteLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
+        DescribeLoadBalancerPoliciesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteLoadBalancerListenersError::from_response(response))
+                    Err(DescribeLoadBalancerPoliciesError::from_response(response))
                 }));
             }
 
@@ -6695,8 +8060,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DeleteLoadBalancerListenerOutput::default();
+                    result = DescribeLoadBalancerPoliciesOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6705,10 +8071,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteLoadBalancerListenerOutputDeserializer::deserialize(
-                        "DeleteLoadBalancerListenersResult",
+                    result = DescribeLoadBalancerPoliciesOutputDeserializer::deserialize(
+                        "DescribeLoadBalancerPoliciesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLoadBalancerPolicies", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6718,24 +8087,28 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Deletes the specified policy from the specified load balancer. This policy must not be enabled for any listeners.</p>
-    fn delete_load_balancer_policy(
+    /// <p>Describes the specified load balancer policy types or all load balancer policy types.</p> <p>The description of each type indicates how it can be used. For example, some policies can be used only with layer 7 listeners, some policies can be used only with layer 4 listeners, and some policies can be used only with your EC2 instances.</p> <p>You can use <a>CreateLoadBalancerPolicy</a> to create a policy configuration for any of these policy types. Then, depending on the policy type, use either <a>SetLoadBalancerPoliciesOfListener</a> or <a>SetLoadBalancerPoliciesForBackendServer</a> to set the policy.</p>
+    fn describe_load_balancer_policy_types(
         &self,
-        input: DeleteLoadBalancerPolicyInput,
-    ) -> RusotoFuture<DeleteLoadBalancerPolicyOutput, DeleteLoadBalancerPolicyError> {
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPolicyTypesOutput, DescribeLoadBalancerPolicyTypesError>
+    {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerPolicyTypes");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteLoadBalancerPolicy");
+        params.put("Action", "DescribeLoadBalancerPolicyTypes");
         params.put("Version", "2012-06-01");
-        DeleteLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
+        DescribeLoadBalancerPolicyTypesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteLoadBalancerPolicyError::from_response(response))
+                    Err(DescribeLoadBalancerPolicyTypesError::from_response(
+                        response,
+                    ))
                 }));
             }
 
@@ -6743,8 +8116,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DeleteLoadBalancerPolicyOutput::default();
+                    result = DescribeLoadBalancerPolicyTypesOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6753,10 +8127,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteLoadBalancerPolicyOutputDeserializer::deserialize(
-                        "DeleteLoadBalancerPolicyResult",
+                    result = DescribeLoadBalancerPolicyTypesOutputDeserializer::deserialize(
+                        "DescribeLoadBalancerPolicyTypesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLoadBalancerPolicyTypes", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6766,26 +8143,33 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Deregisters the specified instances from the specified load balancer. After the instance is deregistered, it no longer receives traffic from the load balancer.</p> <p>You can use <a>DescribeLoadBalancers</a> to verify that the instance is deregistered from the load balancer.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-deregister-register-instances.html">Register or De-Register EC2 Instances</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    fn deregister_instances_from_load_balancer(
+    /// <p>Describes the specified the load balancers. If no load balancers are specified, the call describes all of your load balancers.</p>
+    fn describe_load_balancers(
         &self,
-        input: DeregisterEndPointsInput,
-    ) -> RusotoFuture<DeregisterEndPointsOutput, DeregisterInstancesFromLoadBalancerError> {
+        input: DescribeAccessPointsInput,
+    ) -> RusotoFuture<DescribeAccessPointsOutput, DescribeLoadBalancersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
         let mut params = Params::new();
 
-        params.put("Action", "DeregisterInstancesFromLoadBalancer");
+        params.put("Action", "DescribeLoadBalancers");
         params.put("Version", "2012-06-01");
-        DeregisterEndPointsInputSerializer::serialize(&mut params, "", &input);
+        DescribeAccessPointsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterInstancesFromLoadBalancerError::from_response(
-                        response,
-                    ))
+                    Err(DescribeLoadBalancersError::from_response(response))
                 }));
             }
 
@@ -6793,8 +8177,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DeregisterEndPointsOutput::default();
+                    result = DescribeAccessPointsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6803,10 +8188,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DeregisterEndPointsOutputDeserializer::deserialize(
-                        "DeregisterInstancesFromLoadBalancerResult",
+                    result = DescribeAccessPointsOutputDeserializer::deserialize(
+                        "DescribeLoadBalancersResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLoadBalancers", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6816,33 +8204,46 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Describes the current Elastic Load Balancing resource limits for your AWS account.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-limits.html">Limits for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    fn describe_account_limits(
+    /// <p>Describes the tags associated with the specified load balancers.</p>
+    fn describe_tags(
         &self,
-        input: DescribeAccountLimitsInput,
-    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTags");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAccountLimits");
+        params.put("Action", "DescribeTags");
         params.put("Version", "2012-06-01");
-        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
+        DescribeTagsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAccountLimitsError::from_response(response))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
+                );
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DescribeAccountLimitsOutput::default();
+                    result = DescribeTagsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6851,10 +8252,11 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeAccountLimitsOutputDeserializer::deserialize(
-                        "DescribeAccountLimitsResult",
+                    result = DescribeTagsOutputDeserializer::deserialize(
+                        "DescribeTagsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DescribeTags", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6864,24 +8266,25 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Describes the state of the specified instances with respect to the specified load balancer. If no instances are specified, the call describes the state of all instances that are currently registered with the load balancer. If instances are specified, their state is returned even if they are no longer registered with the load balancer. The state of terminated instances is not returned.</p>
-    fn describe_instance_health(
+    /// <p>Removes the specified subnets from the set of configured subnets for the load balancer.</p> <p>After a subnet is removed, all EC2 instances registered with the load balancer in the removed subnet go into the <code>OutOfService</code> state. Then, the load balancer balances the traffic among the remaining routable subnets.</p>
+    fn detach_load_balancer_from_subnets(
         &self,
-        input: DescribeEndPointStateInput,
-    ) -> RusotoFuture<DescribeEndPointStateOutput, DescribeInstanceHealthError> {
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> RusotoFuture<DetachLoadBalancerFromSubnetsOutput, DetachLoadBalancerFromSubnetsError> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DetachLoadBalancerFromSubnets");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeInstanceHealth");
+        params.put("Action", "DetachLoadBalancerFromSubnets");
         params.put("Version", "2012-06-01");
-        DescribeEndPointStateInputSerializer::serialize(&mut params, "", &input);
+        DetachLoadBalancerFromSubnetsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeInstanceHealthError::from_response(response))
+                    Err(DetachLoadBalancerFromSubnetsError::from_response(response))
                 }));
             }
 
@@ -6889,8 +8292,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DescribeEndPointStateOutput::default();
+                    result = DetachLoadBalancerFromSubnetsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6899,10 +8303,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeEndPointStateOutputDeserializer::deserialize(
-                        "DescribeInstanceHealthResult",
+                    result = DetachLoadBalancerFromSubnetsOutputDeserializer::deserialize(
+                        "DetachLoadBalancerFromSubnetsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DetachLoadBalancerFromSubnets", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6912,25 +8319,28 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Describes the attributes for the specified load balancer.</p>
-    fn describe_load_balancer_attributes(
+    /// <p>Removes the specified Availability Zones from the set of Availability Zones for the specified load balancer in EC2-Classic or a default VPC.</p> <p>For load balancers in a non-default VPC, use <a>DetachLoadBalancerFromSubnets</a>.</p> <p>There must be at least one Availability Zone registered with a load balancer at all times. After an Availability Zone is removed, all instances registered with the load balancer that are in the removed Availability Zone go into the <code>OutOfService</code> state. Then, the load balancer attempts to equally balance the traffic among its remaining Availability Zones.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-az.html">Add or Remove Availability Zones</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn disable_availability_zones_for_load_balancer(
         &self,
-        input: DescribeLoadBalancerAttributesInput,
-    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+        input: RemoveAvailabilityZonesInput,
+    ) -> RusotoFuture<RemoveAvailabilityZonesOutput, DisableAvailabilityZonesForLoadBalancerError>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DisableAvailabilityZonesForLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancerAttributes");
+        params.put("Action", "DisableAvailabilityZonesForLoadBalancer");
         params.put("Version", "2012-06-01");
-        DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
+        RemoveAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLoadBalancerAttributesError::from_response(response))
+                    Err(DisableAvailabilityZonesForLoadBalancerError::from_response(
+                        response,
+                    ))
                 }));
             }
 
@@ -6938,8 +8348,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DescribeLoadBalancerAttributesOutput::default();
+                    result = RemoveAvailabilityZonesOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6948,10 +8359,17 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeLoadBalancerAttributesOutputDeserializer::deserialize(
-                        "DescribeLoadBalancerAttributesResult",
+                    result = RemoveAvailabilityZonesOutputDeserializer::deserialize(
+                        "DisableAvailabilityZonesForLoadBalancerResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "DisableAvailabilityZonesForLoadBalancer",
+                            err,
+                            &response.body,
+                        )
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -6961,24 +8379,27 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Describes the specified policies.</p> <p>If you specify a load balancer name, the action returns the descriptions of all policies created for the load balancer. If you specify a policy name associated with your load balancer, the action returns the description of that policy. If you don't specify a load balancer name, the action returns descriptions of the specified sample policies, or descriptions of all sample policies. The names of the sample policies have the <code>ELBSample-</code> prefix.</p>
-    fn describe_load_balancer_policies(
+    /// <p>Adds the specified Availability Zones to the set of Availability Zones for the specified load balancer in EC2-Classic or a default VPC.</p> <p>For load balancers in a non-default VPC, use <a>AttachLoadBalancerToSubnets</a>.</p> <p>The load balancer evenly distributes requests across all its registered Availability Zones that contain instances. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-az.html">Add or Remove Availability Zones</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn enable_availability_zones_for_load_balancer(
         &self,
-        input: DescribeLoadBalancerPoliciesInput,
-    ) -> RusotoFuture<DescribeLoadBalancerPoliciesOutput, DescribeLoadBalancerPoliciesError> {
+        input: AddAvailabilityZonesInput,
+    ) -> RusotoFuture<AddAvailabilityZonesOutput, EnableAvailabilityZonesForLoadBalancerError> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("EnableAvailabilityZonesForLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancerPolicies");
+        params.put("Action", "EnableAvailabilityZonesForLoadBalancer");
         params.put("Version", "2012-06-01");
-        DescribeLoadBalancerPoliciesInputSerializer::serialize(&mut params, "", &input);
+        AddAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLoadBalancerPoliciesError::from_response(response))
+                    Err(EnableAvailabilityZonesForLoadBalancerError::from_response(
+                        response,
+                    ))
                 }));
             }
 
@@ -6986,8 +8407,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DescribeLoadBalancerPoliciesOutput::default();
+                    result = AddAvailabilityZonesOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -6996,10 +8418,17 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeLoadBalancerPoliciesOutputDeserializer::deserialize(
-                        "DescribeLoadBalancerPoliciesResult",
+                    result = AddAvailabilityZonesOutputDeserializer::deserialize(
+                        "EnableAvailabilityZonesForLoadBalancerResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "EnableAvailabilityZonesForLoadBalancer",
+                            err,
+                            &response.body,
+                        )
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -7009,27 +8438,33 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Describes the specified load balancer policy types or all load balancer policy types.</p> <p>The description of each type indicates how it can be used. For example, some policies can be used only with layer 7 listeners, some policies can be used only with layer 4 listeners, and some policies can be used only with your EC2 instances.</p> <p>You can use <a>CreateLoadBalancerPolicy</a> to create a policy configuration for any of these policy types. Then, depending on the policy type, use either <a>SetLoadBalancerPoliciesOfListener</a> or <a>SetLoadBalancerPoliciesForBackendServer</a> to set the policy.</p>
-    fn describe_load_balancer_policy_types(
+    /// <p><p>Modifies the attributes of the specified load balancer.</p> <p>You can modify the load balancer attributes, such as <code>AccessLogs</code>, <code>ConnectionDraining</code>, and <code>CrossZoneLoadBalancing</code> by either enabling or disabling them. Or, you can modify the load balancer attribute <code>ConnectionSettings</code> by specifying an idle connection timeout value for your load balancer.</p> <p>For more information, see the following in the <i>Classic Load Balancers Guide</i>:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-crosszone-lb.html">Cross-Zone Load Balancing</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/config-conn-drain.html">Connection Draining</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/access-log-collection.html">Access Logs</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/config-idle-timeout.html">Idle Connection Timeout</a> </p> </li> </ul></p>
+    fn modify_load_balancer_attributes(
         &self,
-        input: DescribeLoadBalancerPolicyTypesInput,
-    ) -> RusotoFuture<DescribeLoadBalancerPolicyTypesOutput, DescribeLoadBalancerPolicyTypesError>
-    {
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyLoadBalancerAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancerPolicyTypes");
+        params.put("Action", "ModifyLoadBalancerAttributes");
         params.put("Version", "2012-06-01");
-        DescribeLoadBalancerPolicyTypesInputSerializer::serialize(&mut params, "", &input);
+        ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLoadBalancerPolicyTypesError::from_response(
-                        response,
-                    ))
+                    Err(ModifyLoadBalancerAttributesError::from_response(response))
                 }));
             }
 
@@ -7037,8 +8472,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DescribeLoadBalancerPolicyTypesOutput::default();
+                    result = ModifyLoadBalancerAttributesOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -7047,10 +8483,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeLoadBalancerPolicyTypesOutputDeserializer::deserialize(
-                        "DescribeLoadBalancerPolicyTypesResult",
+                    result = ModifyLoadBalancerAttributesOutputDeserializer::deserialize(
+                        "ModifyLoadBalancerAttributesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyLoadBalancerAttributes", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -7060,24 +8499,27 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Describes the specified the load balancers. If no load balancers are specified, the call describes all of your load balancers.</p>
-    fn describe_load_balancers(
+    /// <p>Adds the specified instances to the specified load balancer.</p> <p>The instance must be a running instance in the same network as the load balancer (EC2-Classic or the same VPC). If you have EC2-Classic instances and a load balancer in a VPC with ClassicLink enabled, you can link the EC2-Classic instances to that VPC and then register the linked EC2-Classic instances with the load balancer in the VPC.</p> <p>Note that <code>RegisterInstanceWithLoadBalancer</code> completes when the request has been registered. Instance registration takes a little time to complete. To check the state of the registered instances, use <a>DescribeLoadBalancers</a> or <a>DescribeInstanceHealth</a>.</p> <p>After the instance is registered, it starts receiving traffic and requests from the load balancer. Any instance that is not in one of the Availability Zones registered for the load balancer is moved to the <code>OutOfService</code> state. If an Availability Zone is added to the load balancer later, any instances registered with the load balancer move to the <code>InService</code> state.</p> <p>To deregister instances from a load balancer, use <a>DeregisterInstancesFromLoadBalancer</a>.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-deregister-register-instances.html">Register or De-Register EC2 Instances</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn register_instances_with_load_balancer(
         &self,
-        input: DescribeAccessPointsInput,
-    ) -> RusotoFuture<DescribeAccessPointsOutput, DescribeLoadBalancersError> {
+        input: RegisterEndPointsInput,
+    ) -> RusotoFuture<RegisterEndPointsOutput, RegisterInstancesWithLoadBalancerError> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RegisterInstancesWithLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancers");
+        params.put("Action", "RegisterInstancesWithLoadBalancer");
         params.put("Version", "2012-06-01");
-        DescribeAccessPointsInputSerializer::serialize(&mut params, "", &input);
+        RegisterEndPointsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLoadBalancersError::from_response(response))
+                    Err(RegisterInstancesWithLoadBalancerError::from_response(
+                        response,
+                    ))
                 }));
             }
 
@@ -7085,8 +8527,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DescribeAccessPointsOutput::default();
+                    result = RegisterEndPointsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -7095,10 +8538,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeAccessPointsOutputDeserializer::deserialize(
-                        "DescribeLoadBalancersResult",
+                    result = RegisterEndPointsOutputDeserializer::deserialize(
+                        "RegisterInstancesWithLoadBalancerResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RegisterInstancesWithLoadBalancer", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -7108,17 +8554,26 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Describes the tags associated with the specified load balancers.</p>
-    fn describe_tags(
+    /// <p>Removes one or more tags from the specified load balancer.</p>
+    fn remove_tags(
         &self,
-        input: DescribeTagsInput,
-    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveTags");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeTags");
+        params.put("Action", "RemoveTags");
         params.put("Version", "2012-06-01");
-        DescribeTagsInputSerializer::serialize(&mut params, "", &input);
+        RemoveTagsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
@@ -7128,7 +8583,7 @@ impl Elb for ElbClient {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
+                        .and_then(|response| Err(RemoveTagsError::from_response(response))),
                 );
             }
 
@@ -7136,8 +8591,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DescribeTagsOutput::default();
+                    result = RemoveTagsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -7146,10 +8602,9 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeTagsOutputDeserializer::deserialize(
-                        "DescribeTagsResult",
-                        &mut stack,
-                    )?;
+                    result =
+                        RemoveTagsOutputDeserializer::deserialize("RemoveTagsResult", &mut stack)
+                            .map_err(|err| stack.parse_error("RemoveTags", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -7159,24 +8614,30 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Removes the specified subnets from the set of configured subnets for the load balancer.</p> <p>After a subnet is removed, all EC2 instances registered with the load balancer in the removed subnet go into the <code>OutOfService</code> state. Then, the load balancer balances the traffic among the remaining routable subnets.</p>
-    fn detach_load_balancer_from_subnets(
+    /// <p>Sets the certificate that terminates the specified listener's SSL connections. The specified certificate replaces any prior certificate that was used on the same load balancer and port.</p> <p>For more information about updating your SSL certificate, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-update-ssl-cert.html">Replace the SSL Certificate for Your Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn set_load_balancer_listener_ssl_certificate(
         &self,
-        input: DetachLoadBalancerFromSubnetsInput,
-    ) -> RusotoFuture<DetachLoadBalancerFromSubnetsOutput, DetachLoadBalancerFromSubnetsError> {
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> RusotoFuture<
+        SetLoadBalancerListenerSSLCertificateOutput,
+        SetLoadBalancerListenerSSLCertificateError,
+    > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerListenerSSLCertificate");
         let mut params = Params::new();
 
-        params.put("Action", "DetachLoadBalancerFromSubnets");
+        params.put("Action", "SetLoadBalancerListenerSSLCertificate");
         params.put("Version", "2012-06-01");
-        DetachLoadBalancerFromSubnetsInputSerializer::serialize(&mut params, "", &input);
+        SetLoadBalancerListenerSSLCertificateInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DetachLoadBalancerFromSubnetsError::from_response(response))
+                    Err(SetLoadBalancerListenerSSLCertificateError::from_response(
+                        response,
+                    ))
                 }));
             }
 
@@ -7184,8 +8645,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DetachLoadBalancerFromSubnetsOutput::default();
+                    result = SetLoadBalancerListenerSSLCertificateOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -7194,10 +8656,17 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = DetachLoadBalancerFromSubnetsOutputDeserializer::deserialize(
-                        "DetachLoadBalancerFromSubnetsResult",
+                    result = SetLoadBalancerListenerSSLCertificateOutputDeserializer::deserialize(
+                        "SetLoadBalancerListenerSSLCertificateResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "SetLoadBalancerListenerSSLCertificate",
+                            err,
+                            &response.body,
+                        )
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -7207,25 +8676,28 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Removes the specified Availability Zones from the set of Availability Zones for the specified load balancer in EC2-Classic or a default VPC.</p> <p>For load balancers in a non-default VPC, use <a>DetachLoadBalancerFromSubnets</a>.</p> <p>There must be at least one Availability Zone registered with a load balancer at all times. After an Availability Zone is removed, all instances registered with the load balancer that are in the removed Availability Zone go into the <code>OutOfService</code> state. Then, the load balancer attempts to equally balance the traffic among its remaining Availability Zones.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-az.html">Add or Remove Availability Zones</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    fn disable_availability_zones_for_load_balancer(
+    /// <p>Replaces the set of policies associated with the specified port on which the EC2 instance is listening with a new set of policies. At this time, only the back-end server authentication policy type can be applied to the instance ports; this policy type is composed of multiple public key policies.</p> <p>Each time you use <code>SetLoadBalancerPoliciesForBackendServer</code> to enable the policies, use the <code>PolicyNames</code> parameter to list the policies that you want to enable.</p> <p>You can use <a>DescribeLoadBalancers</a> or <a>DescribeLoadBalancerPolicies</a> to verify that the policy is associated with the EC2 instance.</p> <p>For more information about enabling back-end instance authentication, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-create-https-ssl-load-balancer.html#configure_backendauth_clt">Configure Back-end Instance Authentication</a> in the <i>Classic Load Balancers Guide</i>. For more information about Proxy Protocol, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-proxy-protocol.html">Configure Proxy Protocol Support</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn set_load_balancer_policies_for_backend_server(
         &self,
-        input: RemoveAvailabilityZonesInput,
-    ) -> RusotoFuture<RemoveAvailabilityZonesOutput, DisableAvailabilityZonesForLoadBalancerError>
-    {
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> RusotoFuture<
+        SetLoadBalancerPoliciesForBackendServerOutput,
+        SetLoadBalancerPoliciesForBackendServerError,
+    > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerPoliciesForBackendServer");
         let mut params = Params::new();
 
-        params.put("Action", "DisableAvailabilityZonesForLoadBalancer");
+        params.put("Action", "SetLoadBalancerPoliciesForBackendServer");
         params.put("Version", "2012-06-01");
-        RemoveAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
+        SetLoadBalancerPoliciesForBackendServerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisableAvailabilityZonesForLoadBalancerError::from_response(
+                    Err(SetLoadBalancerPoliciesForBackendServerError::from_response(
                         response,
                     ))
                 }));
@@ -7235,8 +8707,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = RemoveAvailabilityZonesOutput::default();
+                    result = SetLoadBalancerPoliciesForBackendServerOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -7245,10 +8718,18 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = RemoveAvailabilityZonesOutputDeserializer::deserialize(
-                        "DisableAvailabilityZonesForLoadBalancerResult",
-                        &mut stack,
-                    )?;
+                    result =
+                        SetLoadBalancerPoliciesForBackendServerOutputDeserializer::deserialize(
+                            "SetLoadBalancerPoliciesForBackendServerResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "SetLoadBalancerPoliciesForBackendServer",
+                                err,
+                                &response.body,
+                            )
+                        })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -7258,24 +8739,26 @@ impl Elb for ElbClient {
         })
     }
 
-    /// <p>Adds the specified Availability Zones to the set of Availability Zones for the specified load balancer in EC2-Classic or a default VPC.</p> <p>For load balancers in a non-default VPC, use <a>AttachLoadBalancerToSubnets</a>.</p> <p>The load balancer evenly distributes requests across all its registered Availability Zones that contain instances. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-az.html">Add or Remove Availability Zones</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    fn enable_availability_zones_for_load_balancer(
+    /// <p>Replaces the current set of policies for the specified load balancer port with the specified set of policies.</p> <p>To enable back-end server authentication, use <a>SetLoadBalancerPoliciesForBackendServer</a>.</p> <p>For more information about setting policies, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/ssl-config-update.html">Update the SSL Negotiation Configuration</a>, <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-duration">Duration-Based Session Stickiness</a>, and <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-application">Application-Controlled Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    fn set_load_balancer_policies_of_listener(
         &self,
-        input: AddAvailabilityZonesInput,
-    ) -> RusotoFuture<AddAvailabilityZonesOutput, EnableAvailabilityZonesForLoadBalancerError> {
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> RusotoFuture<SetLoadBalancerPoliciesOfListenerOutput, SetLoadBalancerPoliciesOfListenerError>
+    {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerPoliciesOfListener");
         let mut params = Params::new();
 
-        params.put("Action", "EnableAvailabilityZonesForLoadBalancer");
+        params.put("Action", "SetLoadBalancerPoliciesOfListener");
         params.put("Version", "2012-06-01");
-        AddAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
+        SetLoadBalancerPoliciesOfListenerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(EnableAvailabilityZonesForLoadBalancerError::from_response(
+                    Err(SetLoadBalancerPoliciesOfListenerError::from_response(
                         response,
                     ))
                 }));
@@ -7285,8 +8768,9 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = AddAvailabilityZonesOutput::default();
+                    result = SetLoadBalancerPoliciesOfListenerOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -7295,10 +8779,13 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = AddAvailabilityZonesOutputDeserializer::deserialize(
-                        "EnableAvailabilityZonesForLoadBalancerResult",
+                    result = SetLoadBalancerPoliciesOfListenerOutputDeserializer::deserialize(
+                        "SetLoadBalancerPoliciesOfListenerResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("SetLoadBalancerPoliciesOfListener", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -7307,155 +8794,1010 @@ impl Elb for ElbClient {
             }))
         })
     }
+}
 
-    /// <p><p>Modifies the attributes of the specified load balancer.</p> <p>You can modify the load balancer attributes, such as <code>AccessLogs</code>, <code>ConnectionDraining</code>, and <code>CrossZoneLoadBalancing</code> by either enabling or disabling them. Or, you can modify the load balancer attribute <code>ConnectionSettings</code> by specifying an idle connection timeout value for your load balancer.</p> <p>For more information, see the following in the <i>Classic Load Balancers Guide</i>:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-crosszone-lb.html">Cross-Zone Load Balancing</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/config-conn-drain.html">Connection Draining</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/access-log-collection.html">Access Logs</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/config-idle-timeout.html">Idle Connection Timeout</a> </p> </li> </ul></p>
-    fn modify_load_balancer_attributes(
-        &self,
-        input: ModifyLoadBalancerAttributesInput,
-    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyLoadBalancerAttributes");
-        params.put("Version", "2012-06-01");
-        ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+/// A mock of the Elastic Load Balancing API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockElb {
+    add_tags: Option<
+        Box<
+            dyn Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    apply_security_groups_to_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    ApplySecurityGroupsToLoadBalancerInput,
+                ) -> ::std::result::Result<
+                    ApplySecurityGroupsToLoadBalancerOutput,
+                    RusotoError<ApplySecurityGroupsToLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    attach_load_balancer_to_subnets: Option<
+        Box<
+            dyn Fn(
+                    AttachLoadBalancerToSubnetsInput,
+                ) -> ::std::result::Result<
+                    AttachLoadBalancerToSubnetsOutput,
+                    RusotoError<AttachLoadBalancerToSubnetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    configure_health_check: Option<
+        Box<
+            dyn Fn(
+                    ConfigureHealthCheckInput,
+                ) -> ::std::result::Result<
+                    ConfigureHealthCheckOutput,
+                    RusotoError<ConfigureHealthCheckError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_app_cookie_stickiness_policy: Option<
+        Box<
+            dyn Fn(
+                    CreateAppCookieStickinessPolicyInput,
+                ) -> ::std::result::Result<
+                    CreateAppCookieStickinessPolicyOutput,
+                    RusotoError<CreateAppCookieStickinessPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_lb_cookie_stickiness_policy: Option<
+        Box<
+            dyn Fn(
+                    CreateLBCookieStickinessPolicyInput,
+                ) -> ::std::result::Result<
+                    CreateLBCookieStickinessPolicyOutput,
+                    RusotoError<CreateLBCookieStickinessPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    CreateAccessPointInput,
+                ) -> ::std::result::Result<
+                    CreateAccessPointOutput,
+                    RusotoError<CreateLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_load_balancer_listeners: Option<
+        Box<
+            dyn Fn(
+                    CreateLoadBalancerListenerInput,
+                ) -> ::std::result::Result<
+                    CreateLoadBalancerListenerOutput,
+                    RusotoError<CreateLoadBalancerListenersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_load_balancer_policy: Option<
+        Box<
+            dyn Fn(
+                    CreateLoadBalancerPolicyInput,
+                ) -> ::std::result::Result<
+                    CreateLoadBalancerPolicyOutput,
+                    RusotoError<CreateLoadBalancerPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    DeleteAccessPointInput,
+                ) -> ::std::result::Result<
+                    DeleteAccessPointOutput,
+                    RusotoError<DeleteLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_load_balancer_listeners: Option<
+        Box<
+            dyn Fn(
+                    DeleteLoadBalancerListenerInput,
+                ) -> ::std::result::Result<
+                    DeleteLoadBalancerListenerOutput,
+                    RusotoError<DeleteLoadBalancerListenersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_load_balancer_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteLoadBalancerPolicyInput,
+                ) -> ::std::result::Result<
+                    DeleteLoadBalancerPolicyOutput,
+                    RusotoError<DeleteLoadBalancerPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deregister_instances_from_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    DeregisterEndPointsInput,
+                ) -> ::std::result::Result<
+                    DeregisterEndPointsOutput,
+                    RusotoError<DeregisterInstancesFromLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_account_limits: Option<
+        Box<
+            dyn Fn(
+                    DescribeAccountLimitsInput,
+                ) -> ::std::result::Result<
+                    DescribeAccountLimitsOutput,
+                    RusotoError<DescribeAccountLimitsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_instance_health: Option<
+        Box<
+            dyn Fn(
+                    DescribeEndPointStateInput,
+                ) -> ::std::result::Result<
+                    DescribeEndPointStateOutput,
+                    RusotoError<DescribeInstanceHealthError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_load_balancer_attributes: Option<
+        Box<
+            dyn Fn(
+                    DescribeLoadBalancerAttributesInput,
+                ) -> ::std::result::Result<
+                    DescribeLoadBalancerAttributesOutput,
+                    RusotoError<DescribeLoadBalancerAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_load_balancer_policies: Option<
+        Box<
+            dyn Fn(
+                    DescribeLoadBalancerPoliciesInput,
+                ) -> ::std::result::Result<
+                    DescribeLoadBalancerPoliciesOutput,
+                    RusotoError<DescribeLoadBalancerPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_load_balancer_policy_types: Option<
+        Box<
+            dyn Fn(
+                    DescribeLoadBalancerPolicyTypesInput,
+                ) -> ::std::result::Result<
+                    DescribeLoadBalancerPolicyTypesOutput,
+                    RusotoError<DescribeLoadBalancerPolicyTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_load_balancers: Option<
+        Box<
+            dyn Fn(
+                    DescribeAccessPointsInput,
+                ) -> ::std::result::Result<
+                    DescribeAccessPointsOutput,
+                    RusotoError<DescribeLoadBalancersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tags: Option<
+        Box<
+            dyn Fn(
+                    DescribeTagsInput,
+                )
+                    -> ::std::result::Result<DescribeTagsOutput, RusotoError<DescribeTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    detach_load_balancer_from_subnets: Option<
+        Box<
+            dyn Fn(
+                    DetachLoadBalancerFromSubnetsInput,
+                ) -> ::std::result::Result<
+                    DetachLoadBalancerFromSubnetsOutput,
+                    RusotoError<DetachLoadBalancerFromSubnetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_availability_zones_for_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    RemoveAvailabilityZonesInput,
+                ) -> ::std::result::Result<
+                    RemoveAvailabilityZonesOutput,
+                    RusotoError<DisableAvailabilityZonesForLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_availability_zones_for_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    AddAvailabilityZonesInput,
+                ) -> ::std::result::Result<
+                    AddAvailabilityZonesOutput,
+                    RusotoError<EnableAvailabilityZonesForLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_load_balancer_attributes: Option<
+        Box<
+            dyn Fn(
+                    ModifyLoadBalancerAttributesInput,
+                ) -> ::std::result::Result<
+                    ModifyLoadBalancerAttributesOutput,
+                    RusotoError<ModifyLoadBalancerAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_instances_with_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    RegisterEndPointsInput,
+                ) -> ::std::result::Result<
+                    RegisterEndPointsOutput,
+                    RusotoError<RegisterInstancesWithLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsInput,
+                )
+                    -> ::std::result::Result<RemoveTagsOutput, RusotoError<RemoveTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_load_balancer_listener_ssl_certificate: Option<
+        Box<
+            dyn Fn(
+                    SetLoadBalancerListenerSSLCertificateInput,
+                ) -> ::std::result::Result<
+                    SetLoadBalancerListenerSSLCertificateOutput,
+                    RusotoError<SetLoadBalancerListenerSSLCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_load_balancer_policies_for_backend_server: Option<
+        Box<
+            dyn Fn(
+                    SetLoadBalancerPoliciesForBackendServerInput,
+                ) -> ::std::result::Result<
+                    SetLoadBalancerPoliciesForBackendServerOutput,
+                    RusotoError<SetLoadBalancerPoliciesForBackendServerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_load_balancer_policies_of_listener: Option<
+        Box<
+            dyn Fn(
+                    SetLoadBalancerPoliciesOfListenerInput,
+                ) -> ::std::result::Result<
+                    SetLoadBalancerPoliciesOfListenerOutput,
+                    RusotoError<SetLoadBalancerPoliciesOfListenerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockElb {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockElb {
+        Default::default()
+    }
+
+    /// Answers `add_tags` with what `handler` returns for its input.
+    pub fn with_add_tags<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `apply_security_groups_to_load_balancer` with what `handler` returns for its input.
+    pub fn with_apply_security_groups_to_load_balancer<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                ApplySecurityGroupsToLoadBalancerInput,
+            ) -> ::std::result::Result<
+                ApplySecurityGroupsToLoadBalancerOutput,
+                RusotoError<ApplySecurityGroupsToLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.apply_security_groups_to_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_load_balancer_to_subnets` with what `handler` returns for its input.
+    pub fn with_attach_load_balancer_to_subnets<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                AttachLoadBalancerToSubnetsInput,
+            ) -> ::std::result::Result<
+                AttachLoadBalancerToSubnetsOutput,
+                RusotoError<AttachLoadBalancerToSubnetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_load_balancer_to_subnets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `configure_health_check` with what `handler` returns for its input.
+    pub fn with_configure_health_check<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                ConfigureHealthCheckInput,
+            ) -> ::std::result::Result<
+                ConfigureHealthCheckOutput,
+                RusotoError<ConfigureHealthCheckError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.configure_health_check = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_app_cookie_stickiness_policy` with what `handler` returns for its input.
+    pub fn with_create_app_cookie_stickiness_policy<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                CreateAppCookieStickinessPolicyInput,
+            ) -> ::std::result::Result<
+                CreateAppCookieStickinessPolicyOutput,
+                RusotoError<CreateAppCookieStickinessPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_app_cookie_stickiness_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_lb_cookie_stickiness_policy` with what `handler` returns for its input.
+    pub fn with_create_lb_cookie_stickiness_policy<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                CreateLBCookieStickinessPolicyInput,
+            ) -> ::std::result::Result<
+                CreateLBCookieStickinessPolicyOutput,
+                RusotoError<CreateLBCookieStickinessPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_lb_cookie_stickiness_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_load_balancer` with what `handler` returns for its input.
+    pub fn with_create_load_balancer<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                CreateAccessPointInput,
+            ) -> ::std::result::Result<
+                CreateAccessPointOutput,
+                RusotoError<CreateLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_load_balancer_listeners` with what `handler` returns for its input.
+    pub fn with_create_load_balancer_listeners<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                CreateLoadBalancerListenerInput,
+            ) -> ::std::result::Result<
+                CreateLoadBalancerListenerOutput,
+                RusotoError<CreateLoadBalancerListenersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_load_balancer_listeners = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_load_balancer_policy` with what `handler` returns for its input.
+    pub fn with_create_load_balancer_policy<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                CreateLoadBalancerPolicyInput,
+            ) -> ::std::result::Result<
+                CreateLoadBalancerPolicyOutput,
+                RusotoError<CreateLoadBalancerPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_load_balancer_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_load_balancer` with what `handler` returns for its input.
+    pub fn with_delete_load_balancer<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DeleteAccessPointInput,
+            ) -> ::std::result::Result<
+                DeleteAccessPointOutput,
+                RusotoError<DeleteLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_load_balancer_listeners` with what `handler` returns for its input.
+    pub fn with_delete_load_balancer_listeners<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DeleteLoadBalancerListenerInput,
+            ) -> ::std::result::Result<
+                DeleteLoadBalancerListenerOutput,
+                RusotoError<DeleteLoadBalancerListenersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_load_balancer_listeners = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_load_balancer_policy` with what `handler` returns for its input.
+    pub fn with_delete_load_balancer_policy<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DeleteLoadBalancerPolicyInput,
+            ) -> ::std::result::Result<
+                DeleteLoadBalancerPolicyOutput,
+                RusotoError<DeleteLoadBalancerPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_load_balancer_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_instances_from_load_balancer` with what `handler` returns for its input.
+    pub fn with_deregister_instances_from_load_balancer<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DeregisterEndPointsInput,
+            ) -> ::std::result::Result<
+                DeregisterEndPointsOutput,
+                RusotoError<DeregisterInstancesFromLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_instances_from_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account_limits` with what `handler` returns for its input.
+    pub fn with_describe_account_limits<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeAccountLimitsInput,
+            ) -> ::std::result::Result<
+                DescribeAccountLimitsOutput,
+                RusotoError<DescribeAccountLimitsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account_limits = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_instance_health` with what `handler` returns for its input.
+    pub fn with_describe_instance_health<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeEndPointStateInput,
+            ) -> ::std::result::Result<
+                DescribeEndPointStateOutput,
+                RusotoError<DescribeInstanceHealthError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_instance_health = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_load_balancer_attributes` with what `handler` returns for its input.
+    pub fn with_describe_load_balancer_attributes<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeLoadBalancerAttributesInput,
+            ) -> ::std::result::Result<
+                DescribeLoadBalancerAttributesOutput,
+                RusotoError<DescribeLoadBalancerAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_load_balancer_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_load_balancer_policies` with what `handler` returns for its input.
+    pub fn with_describe_load_balancer_policies<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeLoadBalancerPoliciesInput,
+            ) -> ::std::result::Result<
+                DescribeLoadBalancerPoliciesOutput,
+                RusotoError<DescribeLoadBalancerPoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_load_balancer_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_load_balancer_policy_types` with what `handler` returns for its input.
+    pub fn with_describe_load_balancer_policy_types<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeLoadBalancerPolicyTypesInput,
+            ) -> ::std::result::Result<
+                DescribeLoadBalancerPolicyTypesOutput,
+                RusotoError<DescribeLoadBalancerPolicyTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_load_balancer_policy_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_load_balancers` with what `handler` returns for its input.
+    pub fn with_describe_load_balancers<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeAccessPointsInput,
+            ) -> ::std::result::Result<
+                DescribeAccessPointsOutput,
+                RusotoError<DescribeLoadBalancersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_load_balancers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tags` with what `handler` returns for its input.
+    pub fn with_describe_tags<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeTagsInput,
+            )
+                -> ::std::result::Result<DescribeTagsOutput, RusotoError<DescribeTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_load_balancer_from_subnets` with what `handler` returns for its input.
+    pub fn with_detach_load_balancer_from_subnets<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DetachLoadBalancerFromSubnetsInput,
+            ) -> ::std::result::Result<
+                DetachLoadBalancerFromSubnetsOutput,
+                RusotoError<DetachLoadBalancerFromSubnetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_load_balancer_from_subnets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_availability_zones_for_load_balancer` with what `handler` returns for its input.
+    pub fn with_disable_availability_zones_for_load_balancer<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                RemoveAvailabilityZonesInput,
+            ) -> ::std::result::Result<
+                RemoveAvailabilityZonesOutput,
+                RusotoError<DisableAvailabilityZonesForLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_availability_zones_for_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_availability_zones_for_load_balancer` with what `handler` returns for its input.
+    pub fn with_enable_availability_zones_for_load_balancer<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                AddAvailabilityZonesInput,
+            ) -> ::std::result::Result<
+                AddAvailabilityZonesOutput,
+                RusotoError<EnableAvailabilityZonesForLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_availability_zones_for_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_load_balancer_attributes` with what `handler` returns for its input.
+    pub fn with_modify_load_balancer_attributes<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                ModifyLoadBalancerAttributesInput,
+            ) -> ::std::result::Result<
+                ModifyLoadBalancerAttributesOutput,
+                RusotoError<ModifyLoadBalancerAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_load_balancer_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_instances_with_load_balancer` with what `handler` returns for its input.
+    pub fn with_register_instances_with_load_balancer<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                RegisterEndPointsInput,
+            ) -> ::std::result::Result<
+                RegisterEndPointsOutput,
+                RusotoError<RegisterInstancesWithLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_instances_with_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags` with what `handler` returns for its input.
+    pub fn with_remove_tags<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                RemoveTagsInput,
+            ) -> ::std::result::Result<RemoveTagsOutput, RusotoError<RemoveTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_load_balancer_listener_ssl_certificate` with what `handler` returns for its input.
+    pub fn with_set_load_balancer_listener_ssl_certificate<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                SetLoadBalancerListenerSSLCertificateInput,
+            ) -> ::std::result::Result<
+                SetLoadBalancerListenerSSLCertificateOutput,
+                RusotoError<SetLoadBalancerListenerSSLCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_load_balancer_listener_ssl_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_load_balancer_policies_for_backend_server` with what `handler` returns for its input.
+    pub fn with_set_load_balancer_policies_for_backend_server<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                SetLoadBalancerPoliciesForBackendServerInput,
+            ) -> ::std::result::Result<
+                SetLoadBalancerPoliciesForBackendServerOutput,
+                RusotoError<SetLoadBalancerPoliciesForBackendServerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_load_balancer_policies_for_backend_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_load_balancer_policies_of_listener` with what `handler` returns for its input.
+    pub fn with_set_load_balancer_policies_of_listener<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                SetLoadBalancerPoliciesOfListenerInput,
+            ) -> ::std::result::Result<
+                SetLoadBalancerPoliciesOfListenerOutput,
+                RusotoError<SetLoadBalancerPoliciesOfListenerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_load_balancer_policies_of_listener = Some(Box::new(handler));
+        self
+    }
+}
 
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyLoadBalancerAttributesError::from_response(response))
-                }));
+#[cfg(any(test, feature = "mocks"))]
+impl Elb for MockElb {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        match self.add_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for add_tags"),
+        }
+    }
+    fn apply_security_groups_to_load_balancer(
+        &self,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> RusotoFuture<ApplySecurityGroupsToLoadBalancerOutput, ApplySecurityGroupsToLoadBalancerError>
+    {
+        match self.apply_security_groups_to_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for apply_security_groups_to_load_balancer"),
+        }
+    }
+    fn attach_load_balancer_to_subnets(
+        &self,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> RusotoFuture<AttachLoadBalancerToSubnetsOutput, AttachLoadBalancerToSubnetsError> {
+        match self.attach_load_balancer_to_subnets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for attach_load_balancer_to_subnets"),
+        }
+    }
+    fn configure_health_check(
+        &self,
+        input: ConfigureHealthCheckInput,
+    ) -> RusotoFuture<ConfigureHealthCheckOutput, ConfigureHealthCheckError> {
+        match self.configure_health_check {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for configure_health_check"),
+        }
+    }
+    fn create_app_cookie_stickiness_policy(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateAppCookieStickinessPolicyOutput, CreateAppCookieStickinessPolicyError>
+    {
+        match self.create_app_cookie_stickiness_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for create_app_cookie_stickiness_policy"),
+        }
+    }
+    fn create_lb_cookie_stickiness_policy(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateLBCookieStickinessPolicyOutput, CreateLBCookieStickinessPolicyError>
+    {
+        match self.create_lb_cookie_stickiness_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for create_lb_cookie_stickiness_policy"),
+        }
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateAccessPointInput,
+    ) -> RusotoFuture<CreateAccessPointOutput, CreateLoadBalancerError> {
+        match self.create_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for create_load_balancer"),
+        }
+    }
+    fn create_load_balancer_listeners(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> RusotoFuture<CreateLoadBalancerListenerOutput, CreateLoadBalancerListenersError> {
+        match self.create_load_balancer_listeners {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for create_load_balancer_listeners"),
+        }
+    }
+    fn create_load_balancer_policy(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> RusotoFuture<CreateLoadBalancerPolicyOutput, CreateLoadBalancerPolicyError> {
+        match self.create_load_balancer_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for create_load_balancer_policy"),
+        }
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteAccessPointInput,
+    ) -> RusotoFuture<DeleteAccessPointOutput, DeleteLoadBalancerError> {
+        match self.delete_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for delete_load_balancer"),
+        }
+    }
+    fn delete_load_balancer_listeners(
+        &self,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerListenerOutput, DeleteLoadBalancerListenersError> {
+        match self.delete_load_balancer_listeners {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for delete_load_balancer_listeners"),
+        }
+    }
+    fn delete_load_balancer_policy(
+        &self,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> RusotoFuture<DeleteLoadBalancerPolicyOutput, DeleteLoadBalancerPolicyError> {
+        match self.delete_load_balancer_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for delete_load_balancer_policy"),
+        }
+    }
+    fn deregister_instances_from_load_balancer(
+        &self,
+        input: DeregisterEndPointsInput,
+    ) -> RusotoFuture<DeregisterEndPointsOutput, DeregisterInstancesFromLoadBalancerError> {
+        match self.deregister_instances_from_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for deregister_instances_from_load_balancer"),
+        }
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        match self.describe_account_limits {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_account_limits"),
+        }
+    }
+    fn describe_instance_health(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> RusotoFuture<DescribeEndPointStateOutput, DescribeInstanceHealthError> {
+        match self.describe_instance_health {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_instance_health"),
+        }
+    }
+    fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
+        match self.describe_load_balancer_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_load_balancer_attributes"),
+        }
+    }
+    fn describe_load_balancer_policies(
+        &self,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPoliciesOutput, DescribeLoadBalancerPoliciesError> {
+        match self.describe_load_balancer_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_load_balancer_policies"),
+        }
+    }
+    fn describe_load_balancer_policy_types(
+        &self,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPolicyTypesOutput, DescribeLoadBalancerPolicyTypesError>
+    {
+        match self.describe_load_balancer_policy_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_load_balancer_policy_types"),
+        }
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> RusotoFuture<DescribeAccessPointsOutput, DescribeLoadBalancersError> {
+        match self.describe_load_balancers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_load_balancers"),
+        }
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        match self.describe_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_tags"),
+        }
+    }
+    fn detach_load_balancer_from_subnets(
+        &self,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> RusotoFuture<DetachLoadBalancerFromSubnetsOutput, DetachLoadBalancerFromSubnetsError> {
+        match self.detach_load_balancer_from_subnets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for detach_load_balancer_from_subnets"),
+        }
+    }
+    fn disable_availability_zones_for_load_balancer(
+        &self,
+        input: RemoveAvailabilityZonesInput,
+    ) -> RusotoFuture<RemoveAvailabilityZonesOutput, DisableAvailabilityZonesForLoadBalancerError>
+    {
+        match self.disable_availability_zones_for_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElb has no handler for disable_availability_zones_for_load_balancer")
+            }
+        }
+    }
+    fn enable_availability_zones_for_load_balancer(
+        &self,
+        input: AddAvailabilityZonesInput,
+    ) -> RusotoFuture<AddAvailabilityZonesOutput, EnableAvailabilityZonesForLoadBalancerError> {
+        match self.enable_availability_zones_for_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElb has no handler for enable_availability_zones_for_load_balancer")
             }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyLoadBalancerAttributesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyLoadBalancerAttributesOutputDeserializer::deserialize(
-                        "ModifyLoadBalancerAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        }
+    }
+    fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        match self.modify_load_balancer_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for modify_load_balancer_attributes"),
+        }
     }
-
-    /// <p>Adds the specified instances to the specified load balancer.</p> <p>The instance must be a running instance in the same network as the load balancer (EC2-Classic or the same VPC). If you have EC2-Classic instances and a load balancer in a VPC with ClassicLink enabled, you can link the EC2-Classic instances to that VPC and then register the linked EC2-Classic instances with the load balancer in the VPC.</p> <p>Note that <code>RegisterInstanceWithLoadBalancer</code> completes when the request has been registered. Instance registration takes a little time to complete. To check the state of the registered instances, use <a>DescribeLoadBalancers</a> or <a>DescribeInstanceHealth</a>.</p> <p>After the instance is registered, it starts receiving traffic and requests from the load balancer. Any instance that is not in one of the Availability Zones registered for the load balancer is moved to the <code>OutOfService</code> state. If an Availability Zone is added to the load balancer later, any instances registered with the load balancer move to the <code>InService</code> state.</p> <p>To deregister instances from a load balancer, use <a>DeregisterInstancesFromLoadBalancer</a>.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-deregister-register-instances.html">Register or De-Register EC2 Instances</a> in the <i>Classic Load Balancers Guide</i>.</p>
     fn register_instances_with_load_balancer(
         &self,
         input: RegisterEndPointsInput,
     ) -> RusotoFuture<RegisterEndPointsOutput, RegisterInstancesWithLoadBalancerError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RegisterInstancesWithLoadBalancer");
-        params.put("Version", "2012-06-01");
-        RegisterEndPointsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegisterInstancesWithLoadBalancerError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RegisterEndPointsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RegisterEndPointsOutputDeserializer::deserialize(
-                        "RegisterInstancesWithLoadBalancerResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.register_instances_with_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for register_instances_with_load_balancer"),
+        }
     }
-
-    /// <p>Removes one or more tags from the specified load balancer.</p>
     fn remove_tags(
         &self,
         input: RemoveTagsInput,
     ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RemoveTags");
-        params.put("Version", "2012-06-01");
-        RemoveTagsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RemoveTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RemoveTagsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        RemoveTagsOutputDeserializer::deserialize("RemoveTagsResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.remove_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for remove_tags"),
+        }
     }
-
-    /// <p>Sets the certificate that terminates the specified listener's SSL connections. The specified certificate replaces any prior certificate that was used on the same load balancer and port.</p> <p>For more information about updating your SSL certificate, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-update-ssl-cert.html">Replace the SSL Certificate for Your Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
     fn set_load_balancer_listener_ssl_certificate(
         &self,
         input: SetLoadBalancerListenerSSLCertificateInput,
@@ -7463,52 +9805,11 @@ impl Elb for ElbClient {
         SetLoadBalancerListenerSSLCertificateOutput,
         SetLoadBalancerListenerSSLCertificateError,
     > {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetLoadBalancerListenerSSLCertificate");
-        params.put("Version", "2012-06-01");
-        SetLoadBalancerListenerSSLCertificateInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetLoadBalancerListenerSSLCertificateError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetLoadBalancerListenerSSLCertificateOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetLoadBalancerListenerSSLCertificateOutputDeserializer::deserialize(
-                        "SetLoadBalancerListenerSSLCertificateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.set_load_balancer_listener_ssl_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for set_load_balancer_listener_ssl_certificate"),
+        }
     }
-
-    /// <p>Replaces the set of policies associated with the specified port on which the EC2 instance is listening with a new set of policies. At this time, only the back-end server authentication policy type can be applied to the instance ports; this policy type is composed of multiple public key policies.</p> <p>Each time you use <code>SetLoadBalancerPoliciesForBackendServer</code> to enable the policies, use the <code>PolicyNames</code> parameter to list the policies that you want to enable.</p> <p>You can use <a>DescribeLoadBalancers</a> or <a>DescribeLoadBalancerPolicies</a> to verify that the policy is associated with the EC2 instance.</p> <p>For more information about enabling back-end instance authentication, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-create-https-ssl-load-balancer.html#configure_backendauth_clt">Configure Back-end Instance Authentication</a> in the <i>Classic Load Balancers Guide</i>. For more information about Proxy Protocol, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-proxy-protocol.html">Configure Proxy Protocol Support</a> in the <i>Classic Load Balancers Guide</i>.</p>
     fn set_load_balancer_policies_for_backend_server(
         &self,
         input: SetLoadBalancerPoliciesForBackendServerInput,
@@ -7516,101 +9817,22 @@ impl Elb for ElbClient {
         SetLoadBalancerPoliciesForBackendServerOutput,
         SetLoadBalancerPoliciesForBackendServerError,
     > {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetLoadBalancerPoliciesForBackendServer");
-        params.put("Version", "2012-06-01");
-        SetLoadBalancerPoliciesForBackendServerInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetLoadBalancerPoliciesForBackendServerError::from_response(
-                        response,
-                    ))
-                }));
+        match self.set_load_balancer_policies_for_backend_server {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockElb has no handler for set_load_balancer_policies_for_backend_server")
             }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetLoadBalancerPoliciesForBackendServerOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        SetLoadBalancerPoliciesForBackendServerOutputDeserializer::deserialize(
-                            "SetLoadBalancerPoliciesForBackendServerResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        }
     }
-
-    /// <p>Replaces the current set of policies for the specified load balancer port with the specified set of policies.</p> <p>To enable back-end server authentication, use <a>SetLoadBalancerPoliciesForBackendServer</a>.</p> <p>For more information about setting policies, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/ssl-config-update.html">Update the SSL Negotiation Configuration</a>, <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-duration">Duration-Based Session Stickiness</a>, and <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-application">Application-Controlled Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
     fn set_load_balancer_policies_of_listener(
         &self,
         input: SetLoadBalancerPoliciesOfListenerInput,
     ) -> RusotoFuture<SetLoadBalancerPoliciesOfListenerOutput, SetLoadBalancerPoliciesOfListenerError>
     {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetLoadBalancerPoliciesOfListener");
-        params.put("Version", "2012-06-01");
-        SetLoadBalancerPoliciesOfListenerInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetLoadBalancerPoliciesOfListenerError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetLoadBalancerPoliciesOfListenerOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetLoadBalancerPoliciesOfListenerOutputDeserializer::deserialize(
-                        "SetLoadBalancerPoliciesOfListenerResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.set_load_balancer_policies_of_listener {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for set_load_balancer_policies_of_listener"),
+        }
     }
 }
 
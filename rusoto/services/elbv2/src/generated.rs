@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -35,6 +36,8 @@ use xml::EventReader;
 
 /// <p>Information about an action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Action {
     /// <p>[HTTPS listeners] Information for using Amazon Cognito to authenticate users. Specify only when <code>Type</code> is <code>authenticate-cognito</code>.</p>
     pub authenticate_cognito_config: Option<AuthenticateCognitoActionConfig>,
@@ -52,6 +55,20 @@ pub struct Action {
     pub type_: String,
 }
 
+impl Validate for Action {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.fixed_response_config {
+            value.validate_at(&field_path(path, "fixed_response_config"), errors);
+        }
+        if let Some(ref value) = self.order {
+            errors.check_range(&field_path(path, "order"), *value, Some(1), Some(50000));
+        }
+        if let Some(ref value) = self.redirect_config {
+            value.validate_at(&field_path(path, "redirect_config"), errors);
+        }
+    }
+}
+
 struct ActionDeserializer;
 impl ActionDeserializer {
     #[allow(unused_variables)]
@@ -156,7 +173,7 @@ impl ActionOrderDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -203,6 +220,8 @@ impl ActionsSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddListenerCertificatesInput {
     /// <p>The certificate to add. You can specify one certificate per call. Set <code>CertificateArn</code> to the certificate ARN but do not set <code>IsDefault</code>.</p>
     pub certificates: Vec<Certificate>,
@@ -229,6 +248,8 @@ impl AddListenerCertificatesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddListenerCertificatesOutput {
     /// <p>Information about the certificates in the certificate list.</p>
     pub certificates: Option<Vec<Certificate>>,
@@ -259,6 +280,8 @@ impl AddListenerCertificatesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsInput {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     pub resource_arns: Vec<String>,
@@ -266,6 +289,16 @@ pub struct AddTagsInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for AddTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), Some(1), None);
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `AddTagsInput` contents to a `SignedRequest`.
 struct AddTagsInputSerializer;
 impl AddTagsInputSerializer {
@@ -285,6 +318,8 @@ impl AddTagsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsOutput {}
 
 struct AddTagsOutputDeserializer;
@@ -395,6 +430,8 @@ impl AuthenticateCognitoActionConditionalBehaviorEnumDeserializer {
 }
 /// <p>Request parameters to use when integrating with Amazon Cognito to authenticate users.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AuthenticateCognitoActionConfig {
     /// <p>The query parameters (up to 10) to include in the redirect request to the authorization endpoint.</p>
     pub authentication_request_extra_params: Option<::std::collections::HashMap<String, String>>,
@@ -552,7 +589,7 @@ impl AuthenticateCognitoActionSessionTimeoutDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -705,6 +742,8 @@ impl AuthenticateOidcActionConditionalBehaviorEnumDeserializer {
 }
 /// <p>Request parameters when using an identity provider (IdP) that is compliant with OpenID Connect (OIDC) to authenticate users.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AuthenticateOidcActionConfig {
     /// <p>The query parameters (up to 10) to include in the redirect request to the authorization endpoint.</p>
     pub authentication_request_extra_params: Option<::std::collections::HashMap<String, String>>,
@@ -924,7 +963,7 @@ impl AuthenticateOidcActionSessionTimeoutDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -946,7 +985,7 @@ impl AuthenticateOidcActionUseExistingClientSecretDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -965,6 +1004,8 @@ impl AuthenticateOidcActionUserInfoEndpointDeserializer {
 }
 /// <p>Information about an Availability Zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AvailabilityZone {
     /// <p>[Network Load Balancers] If you need static IP addresses for your load balancer, you can specify one Elastic IP address per Availability Zone when you create the load balancer.</p>
     pub load_balancer_addresses: Option<Vec<LoadBalancerAddress>>,
@@ -1033,6 +1074,8 @@ impl CanonicalHostedZoneIdDeserializer {
 }
 /// <p>Information about an SSL server certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Certificate {
     /// <p>The Amazon Resource Name (ARN) of the certificate.</p>
     pub certificate_arn: Option<String>,
@@ -1125,6 +1168,8 @@ impl CertificateListSerializer {
 
 /// <p>Information about a cipher used in a policy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Cipher {
     /// <p>The name of the cipher.</p>
     pub name: Option<String>,
@@ -1167,7 +1212,7 @@ impl CipherPriorityDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1202,6 +1247,8 @@ impl ConditionFieldNameDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateListenerInput {
     /// <p>[HTTPS and TLS listeners] The default certificate for the listener. You must provide exactly one certificate. Set <code>CertificateArn</code> to the certificate ARN but do not set <code>IsDefault</code>.</p> <p>To create a certificate list for the listener, use <a>AddListenerCertificates</a>.</p>
     pub certificates: Option<Vec<Certificate>>,
@@ -1217,6 +1264,16 @@ pub struct CreateListenerInput {
     pub ssl_policy: Option<String>,
 }
 
+impl Validate for CreateListenerInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.default_actions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "default_actions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_range(&field_path(path, "port"), self.port, Some(1), Some(65535));
+    }
+}
+
 /// Serialize `CreateListenerInput` contents to a `SignedRequest`.
 struct CreateListenerInputSerializer;
 impl CreateListenerInputSerializer {
@@ -1251,6 +1308,8 @@ impl CreateListenerInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateListenerOutput {
     /// <p>Information about the listener.</p>
     pub listeners: Option<Vec<Listener>>,
@@ -1277,6 +1336,8 @@ impl CreateListenerOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLoadBalancerInput {
     /// <p>[Application Load Balancers] The type of IP addresses used by the subnets for your load balancer. The possible values are <code>ipv4</code> (for IPv4 addresses) and <code>dualstack</code> (for IPv4 and IPv6 addresses). Internal load balancers must use <code>ipv4</code>.</p>
     pub ip_address_type: Option<String>,
@@ -1296,6 +1357,18 @@ pub struct CreateLoadBalancerInput {
     pub type_: Option<String>,
 }
 
+impl Validate for CreateLoadBalancerInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateLoadBalancerInput` contents to a `SignedRequest`.
 struct CreateLoadBalancerInputSerializer;
 impl CreateLoadBalancerInputSerializer {
@@ -1339,6 +1412,8 @@ impl CreateLoadBalancerInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLoadBalancerOutput {
     /// <p>Information about the load balancer.</p>
     pub load_balancers: Option<Vec<LoadBalancer>>,
@@ -1369,6 +1444,8 @@ impl CreateLoadBalancerOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRuleInput {
     /// <p>The actions. Each rule must include exactly one of the following types of actions: <code>forward</code>, <code>fixed-response</code>, or <code>redirect</code>.</p> <p>If the action type is <code>forward</code>, you specify a target group. The protocol of the target group must be HTTP or HTTPS for an Application Load Balancer. The protocol of the target group must be TCP, TLS, UDP, or TCP_UDP for a Network Load Balancer.</p> <p>[HTTPS listeners] If the action type is <code>authenticate-oidc</code>, you authenticate users through an identity provider that is OpenID Connect (OIDC) compliant.</p> <p>[HTTPS listeners] If the action type is <code>authenticate-cognito</code>, you authenticate users through the user pools supported by Amazon Cognito.</p> <p>[Application Load Balancer] If the action type is <code>redirect</code>, you redirect specified client requests from one URL to another.</p> <p>[Application Load Balancer] If the action type is <code>fixed-response</code>, you drop specified client requests and return a custom HTTP response.</p>
     pub actions: Vec<Action>,
@@ -1380,6 +1457,25 @@ pub struct CreateRuleInput {
     pub priority: i64,
 }
 
+impl Validate for CreateRuleInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.actions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "actions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        for (i, item) in self.conditions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "conditions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_range(
+            &field_path(path, "priority"),
+            self.priority,
+            Some(1),
+            Some(50000),
+        );
+    }
+}
+
 /// Serialize `CreateRuleInput` contents to a `SignedRequest`.
 struct CreateRuleInputSerializer;
 impl CreateRuleInputSerializer {
@@ -1401,6 +1497,8 @@ impl CreateRuleInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRuleOutput {
     /// <p>Information about the rule.</p>
     pub rules: Option<Vec<Rule>>,
@@ -1427,6 +1525,8 @@ impl CreateRuleOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTargetGroupInput {
     /// <p>Indicates whether health checks are enabled. If the target type is <code>lambda</code>, health checks are disabled by default but can be enabled. If the target type is <code>instance</code> or <code>ip</code>, health checks are always enabled and cannot be disabled.</p>
     pub health_check_enabled: Option<bool>,
@@ -1458,6 +1558,54 @@ pub struct CreateTargetGroupInput {
     pub vpc_id: Option<String>,
 }
 
+impl Validate for CreateTargetGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.health_check_interval_seconds {
+            errors.check_range(
+                &field_path(path, "health_check_interval_seconds"),
+                *value,
+                Some(5),
+                Some(300),
+            );
+        }
+        if let Some(ref value) = self.health_check_path {
+            errors.check_length(
+                &field_path(path, "health_check_path"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.health_check_timeout_seconds {
+            errors.check_range(
+                &field_path(path, "health_check_timeout_seconds"),
+                *value,
+                Some(2),
+                Some(120),
+            );
+        }
+        if let Some(ref value) = self.healthy_threshold_count {
+            errors.check_range(
+                &field_path(path, "healthy_threshold_count"),
+                *value,
+                Some(2),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.port {
+            errors.check_range(&field_path(path, "port"), *value, Some(1), Some(65535));
+        }
+        if let Some(ref value) = self.unhealthy_threshold_count {
+            errors.check_range(
+                &field_path(path, "unhealthy_threshold_count"),
+                *value,
+                Some(2),
+                Some(10),
+            );
+        }
+    }
+}
+
 /// Serialize `CreateTargetGroupInput` contents to a `SignedRequest`.
 struct CreateTargetGroupInputSerializer;
 impl CreateTargetGroupInputSerializer {
@@ -1526,6 +1674,8 @@ impl CreateTargetGroupInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTargetGroupOutput {
     /// <p>Information about the target group.</p>
     pub target_groups: Option<Vec<TargetGroup>>,
@@ -1582,13 +1732,15 @@ impl DefaultDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteListenerInput {
     /// <p>The Amazon Resource Name (ARN) of the listener.</p>
     pub listener_arn: String,
@@ -1608,6 +1760,8 @@ impl DeleteListenerInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteListenerOutput {}
 
 struct DeleteListenerOutputDeserializer;
@@ -1627,6 +1781,8 @@ impl DeleteListenerOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLoadBalancerInput {
     /// <p>The Amazon Resource Name (ARN) of the load balancer.</p>
     pub load_balancer_arn: String,
@@ -1649,6 +1805,8 @@ impl DeleteLoadBalancerInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLoadBalancerOutput {}
 
 struct DeleteLoadBalancerOutputDeserializer;
@@ -1668,6 +1826,8 @@ impl DeleteLoadBalancerOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRuleInput {
     /// <p>The Amazon Resource Name (ARN) of the rule.</p>
     pub rule_arn: String,
@@ -1687,6 +1847,8 @@ impl DeleteRuleInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRuleOutput {}
 
 struct DeleteRuleOutputDeserializer;
@@ -1706,6 +1868,8 @@ impl DeleteRuleOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTargetGroupInput {
     /// <p>The Amazon Resource Name (ARN) of the target group.</p>
     pub target_group_arn: String,
@@ -1728,6 +1892,8 @@ impl DeleteTargetGroupInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTargetGroupOutput {}
 
 struct DeleteTargetGroupOutputDeserializer;
@@ -1747,6 +1913,8 @@ impl DeleteTargetGroupOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterTargetsInput {
     /// <p>The Amazon Resource Name (ARN) of the target group.</p>
     pub target_group_arn: String,
@@ -1754,6 +1922,15 @@ pub struct DeregisterTargetsInput {
     pub targets: Vec<TargetDescription>,
 }
 
+impl Validate for DeregisterTargetsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.targets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `DeregisterTargetsInput` contents to a `SignedRequest`.
 struct DeregisterTargetsInputSerializer;
 impl DeregisterTargetsInputSerializer {
@@ -1776,6 +1953,8 @@ impl DeregisterTargetsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterTargetsOutput {}
 
 struct DeregisterTargetsOutputDeserializer;
@@ -1795,6 +1974,8 @@ impl DeregisterTargetsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountLimitsInput {
     /// <p>The marker for the next set of results. (You received this marker from a previous call.)</p>
     pub marker: Option<String>,
@@ -1802,6 +1983,14 @@ pub struct DescribeAccountLimitsInput {
     pub page_size: Option<i64>,
 }
 
+impl Validate for DescribeAccountLimitsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, Some(1), Some(400));
+        }
+    }
+}
+
 /// Serialize `DescribeAccountLimitsInput` contents to a `SignedRequest`.
 struct DescribeAccountLimitsInputSerializer;
 impl DescribeAccountLimitsInputSerializer {
@@ -1821,6 +2010,8 @@ impl DescribeAccountLimitsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountLimitsOutput {
     /// <p>Information about the limits.</p>
     pub limits: Option<Vec<Limit>>,
@@ -1857,6 +2048,8 @@ impl DescribeAccountLimitsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeListenerCertificatesInput {
     /// <p>The Amazon Resource Names (ARN) of the listener.</p>
     pub listener_arn: String,
@@ -1866,6 +2059,14 @@ pub struct DescribeListenerCertificatesInput {
     pub page_size: Option<i64>,
 }
 
+impl Validate for DescribeListenerCertificatesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, Some(1), Some(400));
+        }
+    }
+}
+
 /// Serialize `DescribeListenerCertificatesInput` contents to a `SignedRequest`.
 struct DescribeListenerCertificatesInputSerializer;
 impl DescribeListenerCertificatesInputSerializer {
@@ -1886,6 +2087,8 @@ impl DescribeListenerCertificatesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeListenerCertificatesOutput {
     /// <p>Information about the certificates.</p>
     pub certificates: Option<Vec<Certificate>>,
@@ -1922,6 +2125,8 @@ impl DescribeListenerCertificatesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeListenersInput {
     /// <p>The Amazon Resource Names (ARN) of the listeners.</p>
     pub listener_arns: Option<Vec<String>>,
@@ -1933,6 +2138,14 @@ pub struct DescribeListenersInput {
     pub page_size: Option<i64>,
 }
 
+impl Validate for DescribeListenersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, Some(1), Some(400));
+        }
+    }
+}
+
 /// Serialize `DescribeListenersInput` contents to a `SignedRequest`.
 struct DescribeListenersInputSerializer;
 impl DescribeListenersInputSerializer {
@@ -1962,6 +2175,8 @@ impl DescribeListenersInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeListenersOutput {
     /// <p>Information about the listeners.</p>
     pub listeners: Option<Vec<Listener>>,
@@ -1998,6 +2213,8 @@ impl DescribeListenersOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancerAttributesInput {
     /// <p>The Amazon Resource Name (ARN) of the load balancer.</p>
     pub load_balancer_arn: String,
@@ -2020,6 +2237,8 @@ impl DescribeLoadBalancerAttributesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancerAttributesOutput {
     /// <p>Information about the load balancer attributes.</p>
     pub attributes: Option<Vec<LoadBalancerAttribute>>,
@@ -2050,6 +2269,8 @@ impl DescribeLoadBalancerAttributesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancersInput {
     /// <p>The Amazon Resource Names (ARN) of the load balancers. You can specify up to 20 load balancers in a single call.</p>
     pub load_balancer_arns: Option<Vec<String>>,
@@ -2061,6 +2282,14 @@ pub struct DescribeLoadBalancersInput {
     pub page_size: Option<i64>,
 }
 
+impl Validate for DescribeLoadBalancersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, Some(1), Some(400));
+        }
+    }
+}
+
 /// Serialize `DescribeLoadBalancersInput` contents to a `SignedRequest`.
 struct DescribeLoadBalancersInputSerializer;
 impl DescribeLoadBalancersInputSerializer {
@@ -2094,6 +2323,8 @@ impl DescribeLoadBalancersInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBalancersOutput {
     /// <p>Information about the load balancers.</p>
     pub load_balancers: Option<Vec<LoadBalancer>>,
@@ -2130,6 +2361,8 @@ impl DescribeLoadBalancersOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRulesInput {
     /// <p>The Amazon Resource Name (ARN) of the listener.</p>
     pub listener_arn: Option<String>,
@@ -2141,6 +2374,14 @@ pub struct DescribeRulesInput {
     pub rule_arns: Option<Vec<String>>,
 }
 
+impl Validate for DescribeRulesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, Some(1), Some(400));
+        }
+    }
+}
+
 /// Serialize `DescribeRulesInput` contents to a `SignedRequest`.
 struct DescribeRulesInputSerializer;
 impl DescribeRulesInputSerializer {
@@ -2170,6 +2411,8 @@ impl DescribeRulesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRulesOutput {
     /// <p>If there are additional results, this is the marker for the next set of results. Otherwise, this is null.</p>
     pub next_marker: Option<String>,
@@ -2201,6 +2444,8 @@ impl DescribeRulesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSSLPoliciesInput {
     /// <p>The marker for the next set of results. (You received this marker from a previous call.)</p>
     pub marker: Option<String>,
@@ -2210,6 +2455,14 @@ pub struct DescribeSSLPoliciesInput {
     pub page_size: Option<i64>,
 }
 
+impl Validate for DescribeSSLPoliciesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, Some(1), Some(400));
+        }
+    }
+}
+
 /// Serialize `DescribeSSLPoliciesInput` contents to a `SignedRequest`.
 struct DescribeSSLPoliciesInputSerializer;
 impl DescribeSSLPoliciesInputSerializer {
@@ -2236,6 +2489,8 @@ impl DescribeSSLPoliciesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSSLPoliciesOutput {
     /// <p>If there are additional results, this is the marker for the next set of results. Otherwise, this is null.</p>
     pub next_marker: Option<String>,
@@ -2272,6 +2527,8 @@ impl DescribeSSLPoliciesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsInput {
     /// <p>The Amazon Resource Names (ARN) of the resources.</p>
     pub resource_arns: Vec<String>,
@@ -2295,6 +2552,8 @@ impl DescribeTagsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsOutput {
     /// <p>Information about the tags.</p>
     pub tag_descriptions: Option<Vec<TagDescription>>,
@@ -2321,6 +2580,8 @@ impl DescribeTagsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTargetGroupAttributesInput {
     /// <p>The Amazon Resource Name (ARN) of the target group.</p>
     pub target_group_arn: String,
@@ -2343,6 +2604,8 @@ impl DescribeTargetGroupAttributesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTargetGroupAttributesOutput {
     /// <p>Information about the target group attributes</p>
     pub attributes: Option<Vec<TargetGroupAttribute>>,
@@ -2373,6 +2636,8 @@ impl DescribeTargetGroupAttributesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTargetGroupsInput {
     /// <p>The Amazon Resource Name (ARN) of the load balancer.</p>
     pub load_balancer_arn: Option<String>,
@@ -2386,6 +2651,14 @@ pub struct DescribeTargetGroupsInput {
     pub target_group_arns: Option<Vec<String>>,
 }
 
+impl Validate for DescribeTargetGroupsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, Some(1), Some(400));
+        }
+    }
+}
+
 /// Serialize `DescribeTargetGroupsInput` contents to a `SignedRequest`.
 struct DescribeTargetGroupsInputSerializer;
 impl DescribeTargetGroupsInputSerializer {
@@ -2422,6 +2695,8 @@ impl DescribeTargetGroupsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTargetGroupsOutput {
     /// <p>If there are additional results, this is the marker for the next set of results. Otherwise, this is null.</p>
     pub next_marker: Option<String>,
@@ -2458,6 +2733,8 @@ impl DescribeTargetGroupsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTargetHealthInput {
     /// <p>The Amazon Resource Name (ARN) of the target group.</p>
     pub target_group_arn: String,
@@ -2465,6 +2742,17 @@ pub struct DescribeTargetHealthInput {
     pub targets: Option<Vec<TargetDescription>>,
 }
 
+impl Validate for DescribeTargetHealthInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.targets {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeTargetHealthInput` contents to a `SignedRequest`.
 struct DescribeTargetHealthInputSerializer;
 impl DescribeTargetHealthInputSerializer {
@@ -2489,6 +2777,8 @@ impl DescribeTargetHealthInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTargetHealthOutput {
     /// <p>Information about the health of the targets.</p>
     pub target_health_descriptions: Option<Vec<TargetHealthDescription>>,
@@ -2534,6 +2824,8 @@ impl DescriptionDeserializer {
 }
 /// <p>Information about an action that returns a custom HTTP response.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FixedResponseActionConfig {
     /// <p>The content type.</p> <p>Valid Values: text/plain | text/css | text/html | application/javascript | application/json</p>
     pub content_type: Option<String>,
@@ -2543,6 +2835,32 @@ pub struct FixedResponseActionConfig {
     pub status_code: String,
 }
 
+impl Validate for FixedResponseActionConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.content_type {
+            errors.check_length(
+                &field_path(path, "content_type"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+        }
+        if let Some(ref value) = self.message_body {
+            errors.check_length(
+                &field_path(path, "message_body"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "status_code"),
+            &self.status_code,
+            "^(2|4|5)\\d\\d$",
+        );
+    }
+}
+
 struct FixedResponseActionConfigDeserializer;
 impl FixedResponseActionConfigDeserializer {
     #[allow(unused_variables)]
@@ -2640,7 +2958,7 @@ impl HealthCheckEnabledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2651,7 +2969,7 @@ impl HealthCheckIntervalSecondsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2673,7 +2991,7 @@ impl HealthCheckThresholdCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2684,7 +3002,7 @@ impl HealthCheckTimeoutSecondsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2692,6 +3010,8 @@ impl HealthCheckTimeoutSecondsDeserializer {
 }
 /// <p>Information about a host header condition.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HostHeaderConditionConfig {
     /// <p>One or more host names. The maximum size of each name is 128 characters. The comparison is case insensitive. The following wildcard characters are supported: * (matches 0 or more characters) and ? (matches exactly 1 character).</p> <p>If you specify multiple strings, the condition is satisfied if one of the strings matches the host name.</p>
     pub values: Option<Vec<String>>,
@@ -2754,6 +3074,8 @@ impl HttpCodeDeserializer {
 }
 /// <p>Information about an HTTP header condition.</p> <p>There is a set of standard HTTP header fields. You can also define custom HTTP header fields.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HttpHeaderConditionConfig {
     /// <p>The name of the HTTP header field. The maximum size is 40 characters. The header name is case insensitive. The allowed characters are specified by RFC 7230. Wildcards are not supported.</p> <p>You can't use an HTTP header condition to specify the host header. Use <a>HostHeaderConditionConfig</a> to specify a host header condition.</p>
     pub http_header_name: Option<String>,
@@ -2828,6 +3150,8 @@ impl HttpHeaderConditionNameDeserializer {
 }
 /// <p>Information about an HTTP method condition.</p> <p>HTTP defines a set of request methods, also referred to as HTTP verbs. For more information, see the <a href="https://www.iana.org/assignments/http-methods/http-methods.xhtml">HTTP Method Registry</a>. You can also define custom HTTP methods.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HttpRequestMethodConditionConfig {
     /// <p>The name of the request method. The maximum size is 40 characters. The allowed characters are A-Z, hyphen (-), and underscore (_). The comparison is case sensitive. Wildcards are not supported; therefore, the method name must be an exact match.</p> <p>If you specify multiple strings, the condition is satisfied if one of the strings matches the HTTP request method. We recommend that you route GET and HEAD requests in the same way, because the response to a HEAD request may be cached.</p>
     pub values: Option<Vec<String>>,
@@ -2904,7 +3228,7 @@ impl IsDefaultDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2912,6 +3236,8 @@ impl IsDefaultDeserializer {
 }
 /// <p>Information about an Elastic Load Balancing resource limit for your AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Limit {
     /// <p>The maximum value of the limit.</p>
     pub max: Option<String>,
@@ -2985,6 +3311,8 @@ impl ListOfStringSerializer {
 
 /// <p>Information about a listener.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Listener {
     /// <p>[HTTPS or TLS listener] The default certificate for the listener.</p>
     pub certificates: Option<Vec<Certificate>>,
@@ -3089,6 +3417,8 @@ impl ListenersDeserializer {
 }
 /// <p>Information about a load balancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoadBalancer {
     /// <p>The Availability Zones for the load balancer.</p>
     pub availability_zones: Option<Vec<AvailabilityZone>>,
@@ -3191,6 +3521,8 @@ impl LoadBalancerDeserializer {
 }
 /// <p>Information about a static IP address for a load balancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoadBalancerAddress {
     /// <p>[Network Load Balancers] The allocation ID of the Elastic IP address.</p>
     pub allocation_id: Option<String>,
@@ -3283,6 +3615,8 @@ impl LoadBalancerArnsSerializer {
 
 /// <p>Information about a load balancer attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoadBalancerAttribute {
     /// <p><p>The name of the attribute.</p> <p>The following attributes are supported by both Application Load Balancers and Network Load Balancers:</p> <ul> <li> <p> <code>access<em>logs.s3.enabled</code> - Indicates whether access logs are enabled. The value is <code>true</code> or <code>false</code>. The default is <code>false</code>.</p> </li> <li> <p> <code>access</em>logs.s3.bucket</code> - The name of the S3 bucket for the access logs. This attribute is required if access logs are enabled. The bucket must exist in the same region as the load balancer and have a bucket policy that grants Elastic Load Balancing permissions to write to the bucket.</p> </li> <li> <p> <code>access<em>logs.s3.prefix</code> - The prefix for the location in the S3 bucket for the access logs.</p> </li> <li> <p> <code>deletion</em>protection.enabled</code> - Indicates whether deletion protection is enabled. The value is <code>true</code> or <code>false</code>. The default is <code>false</code>.</p> </li> </ul> <p>The following attributes are supported by only Application Load Balancers:</p> <ul> <li> <p> <code>idle<em>timeout.timeout</em>seconds</code> - The idle timeout value, in seconds. The valid range is 1-4000 seconds. The default is 60 seconds.</p> </li> <li> <p> <code>routing.http2.enabled</code> - Indicates whether HTTP/2 is enabled. The value is <code>true</code> or <code>false</code>. The default is <code>true</code>.</p> </li> </ul> <p>The following attributes are supported by only Network Load Balancers:</p> <ul> <li> <p> <code>load<em>balancing.cross</em>zone.enabled</code> - Indicates whether cross-zone load balancing is enabled. The value is <code>true</code> or <code>false</code>. The default is <code>false</code>.</p> </li> </ul></p>
     pub key: Option<String>,
@@ -3290,6 +3624,28 @@ pub struct LoadBalancerAttribute {
     pub value: Option<String>,
 }
 
+impl Validate for LoadBalancerAttribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "key"), &value, "^[a-zA-Z0-9._]+$");
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 struct LoadBalancerAttributeDeserializer;
 impl LoadBalancerAttributeDeserializer {
     #[allow(unused_variables)]
@@ -3423,6 +3779,8 @@ impl LoadBalancerSchemeEnumDeserializer {
 }
 /// <p>Information about the state of the load balancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoadBalancerState {
     /// <p>The state code. The initial state of the load balancer is <code>provisioning</code>. After the load balancer is fully set up and ready to route traffic, its state is <code>active</code>. If the load balancer could not be set up, its state is <code>failed</code>.</p>
     pub code: Option<String>,
@@ -3505,6 +3863,8 @@ impl MarkerDeserializer {
 }
 /// <p>Information to use when checking for a successful response from a target.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Matcher {
     /// <p>The HTTP codes.</p> <p>For Application Load Balancers, you can specify values between 200 and 499, and the default value is 200. You can specify multiple values (for example, "200,202") or a range of values (for example, "200-299").</p> <p>For Network Load Balancers, this is 200–399.</p>
     pub http_code: String,
@@ -3554,6 +3914,8 @@ impl MaxDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyListenerInput {
     /// <p>[HTTPS and TLS listeners] The default certificate for the listener. You must provide exactly one certificate. Set <code>CertificateArn</code> to the certificate ARN but do not set <code>IsDefault</code>.</p> <p>To create a certificate list, use <a>AddListenerCertificates</a>.</p>
     pub certificates: Option<Vec<Certificate>>,
@@ -3569,6 +3931,20 @@ pub struct ModifyListenerInput {
     pub ssl_policy: Option<String>,
 }
 
+impl Validate for ModifyListenerInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_actions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "default_actions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.port {
+            errors.check_range(&field_path(path, "port"), *value, Some(1), Some(65535));
+        }
+    }
+}
+
 /// Serialize `ModifyListenerInput` contents to a `SignedRequest`.
 struct ModifyListenerInputSerializer;
 impl ModifyListenerInputSerializer {
@@ -3606,6 +3982,8 @@ impl ModifyListenerInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyListenerOutput {
     /// <p>Information about the modified listener.</p>
     pub listeners: Option<Vec<Listener>>,
@@ -3632,6 +4010,8 @@ impl ModifyListenerOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyLoadBalancerAttributesInput {
     /// <p>The load balancer attributes.</p>
     pub attributes: Vec<LoadBalancerAttribute>,
@@ -3639,6 +4019,21 @@ pub struct ModifyLoadBalancerAttributesInput {
     pub load_balancer_arn: String,
 }
 
+impl Validate for ModifyLoadBalancerAttributesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attributes"),
+            self.attributes.len(),
+            None,
+            Some(20),
+        );
+        for (i, item) in self.attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `ModifyLoadBalancerAttributesInput` contents to a `SignedRequest`.
 struct ModifyLoadBalancerAttributesInputSerializer;
 impl ModifyLoadBalancerAttributesInputSerializer {
@@ -3661,6 +4056,8 @@ impl ModifyLoadBalancerAttributesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyLoadBalancerAttributesOutput {
     /// <p>Information about the load balancer attributes.</p>
     pub attributes: Option<Vec<LoadBalancerAttribute>>,
@@ -3691,6 +4088,8 @@ impl ModifyLoadBalancerAttributesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyRuleInput {
     /// <p>The actions. Each rule must include exactly one of the following types of actions: <code>forward</code>, <code>fixed-response</code>, or <code>redirect</code>.</p> <p>If the action type is <code>forward</code>, you specify a target group. The protocol of the target group must be HTTP or HTTPS for an Application Load Balancer. The protocol of the target group must be TCP, TLS, UDP, or TCP_UDP for a Network Load Balancer.</p> <p>[HTTPS listeners] If the action type is <code>authenticate-oidc</code>, you authenticate users through an identity provider that is OpenID Connect (OIDC) compliant.</p> <p>[HTTPS listeners] If the action type is <code>authenticate-cognito</code>, you authenticate users through the user pools supported by Amazon Cognito.</p> <p>[Application Load Balancer] If the action type is <code>redirect</code>, you redirect specified client requests from one URL to another.</p> <p>[Application Load Balancer] If the action type is <code>fixed-response</code>, you drop specified client requests and return a custom HTTP response.</p>
     pub actions: Option<Vec<Action>>,
@@ -3700,6 +4099,23 @@ pub struct ModifyRuleInput {
     pub rule_arn: String,
 }
 
+impl Validate for ModifyRuleInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.actions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "actions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.conditions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "conditions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `ModifyRuleInput` contents to a `SignedRequest`.
 struct ModifyRuleInputSerializer;
 impl ModifyRuleInputSerializer {
@@ -3724,6 +4140,8 @@ impl ModifyRuleInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyRuleOutput {
     /// <p>Information about the modified rule.</p>
     pub rules: Option<Vec<Rule>>,
@@ -3750,6 +4168,8 @@ impl ModifyRuleOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyTargetGroupAttributesInput {
     /// <p>The attributes.</p>
     pub attributes: Vec<TargetGroupAttribute>,
@@ -3757,6 +4177,15 @@ pub struct ModifyTargetGroupAttributesInput {
     pub target_group_arn: String,
 }
 
+impl Validate for ModifyTargetGroupAttributesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `ModifyTargetGroupAttributesInput` contents to a `SignedRequest`.
 struct ModifyTargetGroupAttributesInputSerializer;
 impl ModifyTargetGroupAttributesInputSerializer {
@@ -3779,6 +4208,8 @@ impl ModifyTargetGroupAttributesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyTargetGroupAttributesOutput {
     /// <p>Information about the attributes.</p>
     pub attributes: Option<Vec<TargetGroupAttribute>>,
@@ -3809,6 +4240,8 @@ impl ModifyTargetGroupAttributesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyTargetGroupInput {
     /// <p>Indicates whether health checks are enabled.</p>
     pub health_check_enabled: Option<bool>,
@@ -3832,6 +4265,51 @@ pub struct ModifyTargetGroupInput {
     pub unhealthy_threshold_count: Option<i64>,
 }
 
+impl Validate for ModifyTargetGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.health_check_interval_seconds {
+            errors.check_range(
+                &field_path(path, "health_check_interval_seconds"),
+                *value,
+                Some(5),
+                Some(300),
+            );
+        }
+        if let Some(ref value) = self.health_check_path {
+            errors.check_length(
+                &field_path(path, "health_check_path"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.health_check_timeout_seconds {
+            errors.check_range(
+                &field_path(path, "health_check_timeout_seconds"),
+                *value,
+                Some(2),
+                Some(120),
+            );
+        }
+        if let Some(ref value) = self.healthy_threshold_count {
+            errors.check_range(
+                &field_path(path, "healthy_threshold_count"),
+                *value,
+                Some(2),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.unhealthy_threshold_count {
+            errors.check_range(
+                &field_path(path, "unhealthy_threshold_count"),
+                *value,
+                Some(2),
+                Some(10),
+            );
+        }
+    }
+}
+
 /// Serialize `ModifyTargetGroupInput` contents to a `SignedRequest`.
 struct ModifyTargetGroupInputSerializer;
 impl ModifyTargetGroupInputSerializer {
@@ -3891,6 +4369,8 @@ impl ModifyTargetGroupInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyTargetGroupOutput {
     /// <p>Information about the modified target group.</p>
     pub target_groups: Option<Vec<TargetGroup>>,
@@ -3944,6 +4424,8 @@ impl PathDeserializer {
 }
 /// <p>Information about a path pattern condition.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PathPatternConditionConfig {
     /// <p>One or more path patterns to compare against the request URL. The maximum size of each string is 128 characters. The comparison is case sensitive. The following wildcard characters are supported: * (matches 0 or more characters) and ? (matches exactly 1 character).</p> <p>If you specify multiple strings, the condition is satisfied if one of them matches the request URL. The path pattern is compared only to the path of the URL, not to its query string. To compare against the query string, use <a>QueryStringConditionConfig</a>.</p>
     pub values: Option<Vec<String>>,
@@ -3998,7 +4480,7 @@ impl PortDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4017,6 +4499,8 @@ impl ProtocolEnumDeserializer {
 }
 /// <p>Information about a query string condition.</p> <p>The query string component of a URI starts after the first '?' character and is terminated by either a '#' character or the end of the URI. A typical query string contains key/value pairs separated by '&amp;' characters. The allowed characters are specified by RFC 3986. Any character can be percentage encoded.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueryStringConditionConfig {
     /// <p>One or more key/value pairs or values to find in the query string. The maximum size of each string is 128 characters. The comparison is case insensitive. The following wildcard characters are supported: * (matches 0 or more characters) and ? (matches exactly 1 character). To search for a literal '*' or '?' character in a query string, you must escape these characters in <code>Values</code> using a '\' character.</p> <p>If you specify multiple key/value pairs or values, the condition is satisfied if one of them is found in the query string.</p>
     pub values: Option<Vec<QueryStringKeyValuePair>>,
@@ -4068,6 +4552,8 @@ impl QueryStringConditionConfigSerializer {
 
 /// <p>Information about a key/value pair.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueryStringKeyValuePair {
     /// <p>The key. You can omit the key.</p>
     pub key: Option<String>,
@@ -4152,6 +4638,8 @@ impl QueryStringKeyValuePairListSerializer {
 
 /// <p>Information about a redirect action.</p> <p>A URI consists of the following components: protocol://hostname:port/path?query. You must modify at least one of the following components to avoid a redirect loop: protocol, hostname, port, or path. Any components that you do not modify retain their original values.</p> <p>You can reuse URI components using the following reserved keywords:</p> <ul> <li> <p>#{protocol}</p> </li> <li> <p>#{host}</p> </li> <li> <p>#{port}</p> </li> <li> <p>#{path} (the leading "/" is removed)</p> </li> <li> <p>#{query}</p> </li> </ul> <p>For example, you can change the path to "/new/#{path}", the hostname to "example.#{host}", or the query to "#{query}&amp;value=xyz".</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RedirectActionConfig {
     /// <p>The hostname. This component is not percent-encoded. The hostname can contain #{host}.</p>
     pub host: Option<String>,
@@ -4167,6 +4655,42 @@ pub struct RedirectActionConfig {
     pub status_code: String,
 }
 
+impl Validate for RedirectActionConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.host {
+            errors.check_length(
+                &field_path(path, "host"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.path {
+            errors.check_length(
+                &field_path(path, "path"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.protocol {
+            errors.check_pattern(
+                &field_path(path, "protocol"),
+                &value,
+                "^(HTTPS?|#\\{protocol\\})$",
+            );
+        }
+        if let Some(ref value) = self.query {
+            errors.check_length(
+                &field_path(path, "query"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+    }
+}
+
 struct RedirectActionConfigDeserializer;
 impl RedirectActionConfigDeserializer {
     #[allow(unused_variables)]
@@ -4301,6 +4825,8 @@ impl RedirectActionStatusCodeEnumDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterTargetsInput {
     /// <p>The Amazon Resource Name (ARN) of the target group.</p>
     pub target_group_arn: String,
@@ -4308,6 +4834,15 @@ pub struct RegisterTargetsInput {
     pub targets: Vec<TargetDescription>,
 }
 
+impl Validate for RegisterTargetsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.targets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `RegisterTargetsInput` contents to a `SignedRequest`.
 struct RegisterTargetsInputSerializer;
 impl RegisterTargetsInputSerializer {
@@ -4330,6 +4865,8 @@ impl RegisterTargetsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterTargetsOutput {}
 
 struct RegisterTargetsOutputDeserializer;
@@ -4349,6 +4886,8 @@ impl RegisterTargetsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveListenerCertificatesInput {
     /// <p>The certificate to remove. You can specify one certificate per call. Set <code>CertificateArn</code> to the certificate ARN but do not set <code>IsDefault</code>.</p>
     pub certificates: Vec<Certificate>,
@@ -4375,6 +4914,8 @@ impl RemoveListenerCertificatesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveListenerCertificatesOutput {}
 
 struct RemoveListenerCertificatesOutputDeserializer;
@@ -4394,6 +4935,8 @@ impl RemoveListenerCertificatesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsInput {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     pub resource_arns: Vec<String>,
@@ -4401,6 +4944,16 @@ pub struct RemoveTagsInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for RemoveTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 /// Serialize `RemoveTagsInput` contents to a `SignedRequest`.
 struct RemoveTagsInputSerializer;
 impl RemoveTagsInputSerializer {
@@ -4420,6 +4973,8 @@ impl RemoveTagsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsOutput {}
 
 struct RemoveTagsOutputDeserializer;
@@ -4463,6 +5018,8 @@ impl ResourceArnsSerializer {
 
 /// <p>Information about a rule.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Rule {
     /// <p>The actions. Each rule must include exactly one of the following types of actions: <code>forward</code>, <code>redirect</code>, or <code>fixed-response</code>, and it must be the last action to be performed.</p>
     pub actions: Option<Vec<Action>>,
@@ -4532,6 +5089,8 @@ impl RuleArnsSerializer {
 
 /// <p>Information about a condition for a rule.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RuleCondition {
     /// <p><p>The field in the HTTP request. The following are the possible values:</p> <ul> <li> <p> <code>http-header</code> </p> </li> <li> <p> <code>http-request-method</code> </p> </li> <li> <p> <code>host-header</code> </p> </li> <li> <p> <code>path-pattern</code> </p> </li> <li> <p> <code>query-string</code> </p> </li> <li> <p> <code>source-ip</code> </p> </li> </ul></p>
     pub field: Option<String>,
@@ -4551,6 +5110,19 @@ pub struct RuleCondition {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for RuleCondition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.field {
+            errors.check_length(
+                &field_path(path, "field"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+    }
+}
+
 struct RuleConditionDeserializer;
 impl RuleConditionDeserializer {
     #[allow(unused_variables)]
@@ -4722,6 +5294,8 @@ impl RulePriorityListSerializer {
 
 /// <p>Information about the priorities for the rules for a listener.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RulePriorityPair {
     /// <p>The rule priority.</p>
     pub priority: Option<i64>,
@@ -4729,6 +5303,14 @@ pub struct RulePriorityPair {
     pub rule_arn: Option<String>,
 }
 
+impl Validate for RulePriorityPair {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.priority {
+            errors.check_range(&field_path(path, "priority"), *value, Some(1), Some(50000));
+        }
+    }
+}
+
 /// Serialize `RulePriorityPair` contents to a `SignedRequest`.
 struct RulePriorityPairSerializer;
 impl RulePriorityPairSerializer {
@@ -4805,6 +5387,8 @@ impl SecurityGroupsSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIpAddressTypeInput {
     /// <p>The IP address type. The possible values are <code>ipv4</code> (for IPv4 addresses) and <code>dualstack</code> (for IPv4 and IPv6 addresses). Internal load balancers must use <code>ipv4</code>. Network Load Balancers must use <code>ipv4</code>.</p>
     pub ip_address_type: String,
@@ -4833,6 +5417,8 @@ impl SetIpAddressTypeInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIpAddressTypeOutput {
     /// <p>The IP address type.</p>
     pub ip_address_type: Option<String>,
@@ -4860,11 +5446,22 @@ impl SetIpAddressTypeOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetRulePrioritiesInput {
     /// <p>The rule priorities.</p>
     pub rule_priorities: Vec<RulePriorityPair>,
 }
 
+impl Validate for SetRulePrioritiesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.rule_priorities.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "rule_priorities"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `SetRulePrioritiesInput` contents to a `SignedRequest`.
 struct SetRulePrioritiesInputSerializer;
 impl SetRulePrioritiesInputSerializer {
@@ -4883,6 +5480,8 @@ impl SetRulePrioritiesInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetRulePrioritiesOutput {
     /// <p>Information about the rules.</p>
     pub rules: Option<Vec<Rule>>,
@@ -4913,6 +5512,8 @@ impl SetRulePrioritiesOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetSecurityGroupsInput {
     /// <p>The Amazon Resource Name (ARN) of the load balancer.</p>
     pub load_balancer_arn: String,
@@ -4942,6 +5543,8 @@ impl SetSecurityGroupsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetSecurityGroupsOutput {
     /// <p>The IDs of the security groups associated with the load balancer.</p>
     pub security_group_ids: Option<Vec<String>>,
@@ -4972,6 +5575,8 @@ impl SetSecurityGroupsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetSubnetsInput {
     /// <p>The Amazon Resource Name (ARN) of the load balancer.</p>
     pub load_balancer_arn: String,
@@ -5008,6 +5613,8 @@ impl SetSubnetsInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetSubnetsOutput {
     /// <p>Information about the subnet and Availability Zone.</p>
     pub availability_zones: Option<Vec<AvailabilityZone>>,
@@ -5035,6 +5642,8 @@ impl SetSubnetsOutputDeserializer {
 }
 /// <p>Information about a source IP condition.</p> <p>You can use this condition to route based on the IP address of the source that connects to the load balancer. If a client is behind a proxy, this is the IP address of the proxy not the IP address of the client.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SourceIpConditionConfig {
     /// <p>One or more source IP addresses, in CIDR format. You can use both IPv4 and IPv6 addresses. Wildcards are not supported.</p> <p>If you specify multiple addresses, the condition is satisfied if the source IP address of the request matches one of the CIDR blocks. This condition is not satisfied by the addresses in the X-Forwarded-For header. To search for addresses in the X-Forwarded-For header, use <a>HttpHeaderConditionConfig</a>.</p>
     pub values: Option<Vec<String>>,
@@ -5103,6 +5712,8 @@ impl SslPoliciesDeserializer {
 }
 /// <p>Information about a policy used for SSL negotiation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SslPolicy {
     /// <p>The ciphers.</p>
     pub ciphers: Option<Vec<Cipher>>,
@@ -5237,6 +5848,8 @@ impl SubnetIdDeserializer {
 }
 /// <p>Information about a subnet mapping.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SubnetMapping {
     /// <p>[Network Load Balancers] The allocation ID of the Elastic IP address.</p>
     pub allocation_id: Option<String>,
@@ -5286,6 +5899,8 @@ impl SubnetsSerializer {
 
 /// <p>Information about a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>The key of the tag.</p>
     pub key: String,
@@ -5293,6 +5908,35 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
@@ -5330,6 +5974,8 @@ impl TagSerializer {
 
 /// <p>The tags associated with a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagDescription {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     pub resource_arn: Option<String>,
@@ -5443,6 +6089,8 @@ impl TagValueDeserializer {
 }
 /// <p>Information about a target.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TargetDescription {
     /// <p>An Availability Zone or <code>all</code>. This determines whether the target receives traffic from the load balancer nodes in the specified Availability Zone or from all enabled Availability Zones for the load balancer.</p> <p>This parameter is not supported if the target type of the target group is <code>instance</code>.</p> <p>If the target type is <code>ip</code> and the IP address is in a subnet of the VPC for the target group, the Availability Zone is automatically detected and this parameter is optional. If the IP address is outside the VPC, this parameter is required.</p> <p>With an Application Load Balancer, if the target type is <code>ip</code> and the IP address is outside the VPC for the target group, the only supported value is <code>all</code>.</p> <p>If the target type is <code>lambda</code>, this parameter is optional and the only supported value is <code>all</code>.</p>
     pub availability_zone: Option<String>,
@@ -5452,6 +6100,14 @@ pub struct TargetDescription {
     pub port: Option<i64>,
 }
 
+impl Validate for TargetDescription {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.port {
+            errors.check_range(&field_path(path, "port"), *value, Some(1), Some(65535));
+        }
+    }
+}
+
 struct TargetDescriptionDeserializer;
 impl TargetDescriptionDeserializer {
     #[allow(unused_variables)]
@@ -5512,6 +6168,8 @@ impl TargetDescriptionsSerializer {
 
 /// <p>Information about a target group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TargetGroup {
     /// <p>Indicates whether health checks are enabled.</p>
     pub health_check_enabled: Option<bool>,
@@ -5672,6 +6330,8 @@ impl TargetGroupArnsSerializer {
 
 /// <p>Information about a target group attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TargetGroupAttribute {
     /// <p><p>The name of the attribute.</p> <p>The following attribute is supported by both Application Load Balancers and Network Load Balancers:</p> <ul> <li> <p> <code>deregistration<em>delay.timeout</em>seconds</code> - The amount of time, in seconds, for Elastic Load Balancing to wait before changing the state of a deregistering target from <code>draining</code> to <code>unused</code>. The range is 0-3600 seconds. The default value is 300 seconds. If the target is a Lambda function, this attribute is not supported.</p> </li> </ul> <p>The following attributes are supported by Application Load Balancers if the target is not a Lambda function:</p> <ul> <li> <p> <code>slow<em>start.duration</em>seconds</code> - The time period, in seconds, during which a newly registered target receives a linearly increasing share of the traffic to the target group. After this time period ends, the target receives its full share of traffic. The range is 30-900 seconds (15 minutes). Slow start mode is disabled by default.</p> </li> <li> <p> <code>stickiness.enabled</code> - Indicates whether sticky sessions are enabled. The value is <code>true</code> or <code>false</code>. The default is <code>false</code>.</p> </li> <li> <p> <code>stickiness.type</code> - The type of sticky sessions. The possible value is <code>lb<em>cookie</code>.</p> </li> <li> <p> <code>stickiness.lb</em>cookie.duration<em>seconds</code> - The time period, in seconds, during which requests from a client should be routed to the same target. After this time period expires, the load balancer-generated cookie is considered stale. The range is 1 second to 1 week (604800 seconds). The default value is 1 day (86400 seconds).</p> </li> </ul> <p>The following attribute is supported only if the target is a Lambda function.</p> <ul> <li> <p> <code>lambda.multi</em>value<em>headers.enabled</code> - Indicates whether the request and response headers exchanged between the load balancer and the Lambda function include arrays of values or strings. The value is <code>true</code> or <code>false</code>. The default is <code>false</code>. If the value is <code>false</code> and the request contains a duplicate header field name or query parameter key, the load balancer uses the last value sent by the client.</p> </li> </ul> <p>The following attribute is supported only by Network Load Balancers:</p> <ul> <li> <p> <code>proxy</em>protocol_v2.enabled</code> - Indicates whether Proxy Protocol version 2 is enabled. The value is <code>true</code> or <code>false</code>. The default is <code>false</code>.</p> </li> </ul></p>
     pub key: Option<String>,
@@ -5679,6 +6339,20 @@ pub struct TargetGroupAttribute {
     pub value: Option<String>,
 }
 
+impl Validate for TargetGroupAttribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "key"), &value, "^[a-zA-Z0-9._]+$");
+        }
+    }
+}
+
 struct TargetGroupAttributeDeserializer;
 impl TargetGroupAttributeDeserializer {
     #[allow(unused_variables)]
@@ -5818,6 +6492,8 @@ impl TargetGroupsDeserializer {
 }
 /// <p>Information about the current health of a target.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TargetHealth {
     /// <p>A description of the target health that provides additional details. If the state is <code>healthy</code>, a description is not provided.</p>
     pub description: Option<String>,
@@ -5858,6 +6534,8 @@ impl TargetHealthDeserializer {
 }
 /// <p>Information about the health of a target.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TargetHealthDescription {
     /// <p>The port to use to connect with the target.</p>
     pub health_check_port: Option<String>,
@@ -8658,1258 +9336,2098 @@ pub trait Elb {
         input: SetSubnetsInput,
     ) -> RusotoFuture<SetSubnetsOutput, SetSubnetsError>;
 }
-/// A client for the Elastic Load Balancing v2 API.
-#[derive(Clone)]
-pub struct ElbClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ElbClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ElbClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ElbClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
-        ElbClient { client, region }
-    }
-}
-
-impl Elb for ElbClient {
-    /// <p>Adds the specified SSL server certificate to the certificate list for the specified HTTPS or TLS listener.</p> <p>If the certificate in already in the certificate list, the call is successful but the certificate is not added again.</p> <p>To get the certificate list for a listener, use <a>DescribeListenerCertificates</a>. To remove certificates from the certificate list for a listener, use <a>RemoveListenerCertificates</a>. To replace the default certificate for a listener, use <a>ModifyListener</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#https-listener-certificates">SSL Certificates</a> in the <i>Application Load Balancers Guide</i>.</p>
+const _: Option<&dyn Elb> = None;
+impl<'a, T: Elb + ?Sized> Elb for &'a T {
     fn add_listener_certificates(
         &self,
         input: AddListenerCertificatesInput,
     ) -> RusotoFuture<AddListenerCertificatesOutput, AddListenerCertificatesError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddListenerCertificates");
-        params.put("Version", "2015-12-01");
-        AddListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AddListenerCertificatesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AddListenerCertificatesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AddListenerCertificatesOutputDeserializer::deserialize(
-                        "AddListenerCertificatesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).add_listener_certificates(input)
     }
-
-    /// <p>Adds the specified tags to the specified Elastic Load Balancing resource. You can tag your Application Load Balancers, Network Load Balancers, and your target groups.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, <code>AddTags</code> updates its value.</p> <p>To list the current tags for your resources, use <a>DescribeTags</a>. To remove tags from your resources, use <a>RemoveTags</a>.</p>
     fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddTags");
-        params.put("Version", "2015-12-01");
-        AddTagsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AddTagsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AddTagsOutputDeserializer::deserialize("AddTagsResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).add_tags(input)
     }
-
-    /// <p>Creates a listener for the specified Application Load Balancer or Network Load Balancer.</p> <p>To update a listener, use <a>ModifyListener</a>. When you are finished with a listener, you can delete it using <a>DeleteListener</a>. If you are finished with both the listener and the load balancer, you can delete them both using <a>DeleteLoadBalancer</a>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple listeners with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-listeners.html">Listeners for Your Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-listeners.html">Listeners for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
     fn create_listener(
         &self,
         input: CreateListenerInput,
     ) -> RusotoFuture<CreateListenerOutput, CreateListenerError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateListener");
-        params.put("Version", "2015-12-01");
-        CreateListenerInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateListenerError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateListenerOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateListenerOutputDeserializer::deserialize(
-                        "CreateListenerResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_listener(input)
     }
-
-    /// <p>Creates an Application Load Balancer or a Network Load Balancer.</p> <p>When you create a load balancer, you can specify security groups, public subnets, IP address type, and tags. Otherwise, you could do so later using <a>SetSecurityGroups</a>, <a>SetSubnets</a>, <a>SetIpAddressType</a>, and <a>AddTags</a>.</p> <p>To create listeners for your load balancer, use <a>CreateListener</a>. To describe your current load balancers, see <a>DescribeLoadBalancers</a>. When you are finished with a load balancer, you can delete it using <a>DeleteLoadBalancer</a>.</p> <p>For limit information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-limits.html">Limits for Your Application Load Balancer</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-limits.html">Limits for Your Network Load Balancer</a> in the <i>Network Load Balancers Guide</i>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple load balancers with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/application-load-balancers.html">Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/network-load-balancers.html">Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
     fn create_load_balancer(
         &self,
         input: CreateLoadBalancerInput,
     ) -> RusotoFuture<CreateLoadBalancerOutput, CreateLoadBalancerError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateLoadBalancer");
-        params.put("Version", "2015-12-01");
-        CreateLoadBalancerInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateLoadBalancerError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateLoadBalancerOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateLoadBalancerOutputDeserializer::deserialize(
-                        "CreateLoadBalancerResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_load_balancer(input)
     }
-
-    /// <p>Creates a rule for the specified listener. The listener must be associated with an Application Load Balancer.</p> <p>Rules are evaluated in priority order, from the lowest value to the highest value. When the conditions for a rule are met, its actions are performed. If the conditions for no rules are met, the actions for the default rule are performed. For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-listeners.html#listener-rules">Listener Rules</a> in the <i>Application Load Balancers Guide</i>.</p> <p>To view your current rules, use <a>DescribeRules</a>. To update a rule, use <a>ModifyRule</a>. To set the priorities of your rules, use <a>SetRulePriorities</a>. To delete a rule, use <a>DeleteRule</a>.</p>
     fn create_rule(
         &self,
         input: CreateRuleInput,
     ) -> RusotoFuture<CreateRuleOutput, CreateRuleError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateRule");
-        params.put("Version", "2015-12-01");
-        CreateRuleInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateRuleError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateRuleOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        CreateRuleOutputDeserializer::deserialize("CreateRuleResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_rule(input)
     }
-
-    /// <p>Creates a target group.</p> <p>To register targets with the target group, use <a>RegisterTargets</a>. To update the health check settings for the target group, use <a>ModifyTargetGroup</a>. To monitor the health of targets in the target group, use <a>DescribeTargetHealth</a>.</p> <p>To route traffic to the targets in a target group, specify the target group in an action using <a>CreateListener</a> or <a>CreateRule</a>.</p> <p>To delete a target group, use <a>DeleteTargetGroup</a>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple target groups with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-target-groups.html">Target Groups for Your Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-target-groups.html">Target Groups for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
     fn create_target_group(
         &self,
         input: CreateTargetGroupInput,
     ) -> RusotoFuture<CreateTargetGroupOutput, CreateTargetGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateTargetGroup");
-        params.put("Version", "2015-12-01");
-        CreateTargetGroupInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTargetGroupError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateTargetGroupOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateTargetGroupOutputDeserializer::deserialize(
-                        "CreateTargetGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_target_group(input)
     }
-
-    /// <p>Deletes the specified listener.</p> <p>Alternatively, your listener is deleted when you delete the load balancer to which it is attached, using <a>DeleteLoadBalancer</a>.</p>
     fn delete_listener(
         &self,
         input: DeleteListenerInput,
     ) -> RusotoFuture<DeleteListenerOutput, DeleteListenerError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteListener");
-        params.put("Version", "2015-12-01");
-        DeleteListenerInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteListenerError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteListenerOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteListenerOutputDeserializer::deserialize(
-                        "DeleteListenerResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_listener(input)
     }
-
-    /// <p>Deletes the specified Application Load Balancer or Network Load Balancer and its attached listeners.</p> <p>You can't delete a load balancer if deletion protection is enabled. If the load balancer does not exist or has already been deleted, the call succeeds.</p> <p>Deleting a load balancer does not affect its registered targets. For example, your EC2 instances continue to run and are still registered to their target groups. If you no longer need these EC2 instances, you can stop or terminate them.</p>
     fn delete_load_balancer(
         &self,
         input: DeleteLoadBalancerInput,
     ) -> RusotoFuture<DeleteLoadBalancerOutput, DeleteLoadBalancerError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteLoadBalancer");
-        params.put("Version", "2015-12-01");
-        DeleteLoadBalancerInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteLoadBalancerError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteLoadBalancerOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteLoadBalancerOutputDeserializer::deserialize(
-                        "DeleteLoadBalancerResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_load_balancer(input)
     }
-
-    /// <p>Deletes the specified rule.</p>
     fn delete_rule(
         &self,
         input: DeleteRuleInput,
     ) -> RusotoFuture<DeleteRuleOutput, DeleteRuleError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteRule");
-        params.put("Version", "2015-12-01");
-        DeleteRuleInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteRuleError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteRuleOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        DeleteRuleOutputDeserializer::deserialize("DeleteRuleResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_rule(input)
     }
-
-    /// <p>Deletes the specified target group.</p> <p>You can delete a target group if it is not referenced by any actions. Deleting a target group also deletes any associated health checks.</p>
     fn delete_target_group(
         &self,
         input: DeleteTargetGroupInput,
     ) -> RusotoFuture<DeleteTargetGroupOutput, DeleteTargetGroupError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteTargetGroup");
-        params.put("Version", "2015-12-01");
-        DeleteTargetGroupInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTargetGroupError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteTargetGroupOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteTargetGroupOutputDeserializer::deserialize(
-                        "DeleteTargetGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_target_group(input)
     }
-
-    /// <p>Deregisters the specified targets from the specified target group. After the targets are deregistered, they no longer receive traffic from the load balancer.</p>
     fn deregister_targets(
         &self,
         input: DeregisterTargetsInput,
     ) -> RusotoFuture<DeregisterTargetsOutput, DeregisterTargetsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeregisterTargets");
-        params.put("Version", "2015-12-01");
-        DeregisterTargetsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeregisterTargetsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeregisterTargetsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeregisterTargetsOutputDeserializer::deserialize(
-                        "DeregisterTargetsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).deregister_targets(input)
     }
-
-    /// <p>Describes the current Elastic Load Balancing resource limits for your AWS account.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-limits.html">Limits for Your Application Load Balancers</a> in the <i>Application Load Balancer Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-limits.html">Limits for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
     fn describe_account_limits(
         &self,
         input: DescribeAccountLimitsInput,
     ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeAccountLimits");
-        params.put("Version", "2015-12-01");
-        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAccountLimitsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeAccountLimitsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeAccountLimitsOutputDeserializer::deserialize(
-                        "DescribeAccountLimitsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_account_limits(input)
     }
-
-    /// <p>Describes the default certificate and the certificate list for the specified HTTPS or TLS listener.</p> <p>If the default certificate is also in the certificate list, it appears twice in the results (once with <code>IsDefault</code> set to true and once with <code>IsDefault</code> set to false).</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#https-listener-certificates">SSL Certificates</a> in the <i>Application Load Balancers Guide</i>.</p>
     fn describe_listener_certificates(
         &self,
         input: DescribeListenerCertificatesInput,
     ) -> RusotoFuture<DescribeListenerCertificatesOutput, DescribeListenerCertificatesError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeListenerCertificates");
-        params.put("Version", "2015-12-01");
-        DescribeListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeListenerCertificatesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeListenerCertificatesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeListenerCertificatesOutputDeserializer::deserialize(
-                        "DescribeListenerCertificatesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_listener_certificates(input)
     }
-
-    /// <p>Describes the specified listeners or the listeners for the specified Application Load Balancer or Network Load Balancer. You must specify either a load balancer or one or more listeners.</p> <p>For an HTTPS or TLS listener, the output includes the default certificate for the listener. To describe the certificate list for the listener, use <a>DescribeListenerCertificates</a>.</p>
     fn describe_listeners(
         &self,
         input: DescribeListenersInput,
     ) -> RusotoFuture<DescribeListenersOutput, DescribeListenersError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeListeners");
-        params.put("Version", "2015-12-01");
-        DescribeListenersInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeListenersError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeListenersOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeListenersOutputDeserializer::deserialize(
-                        "DescribeListenersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_listeners(input)
     }
-
-    /// <p>Describes the attributes for the specified Application Load Balancer or Network Load Balancer.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/application-load-balancers.html#load-balancer-attributes">Load Balancer Attributes</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/network-load-balancers.html#load-balancer-attributes">Load Balancer Attributes</a> in the <i>Network Load Balancers Guide</i>.</p>
     fn describe_load_balancer_attributes(
         &self,
         input: DescribeLoadBalancerAttributesInput,
     ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
     {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeLoadBalancerAttributes");
-        params.put("Version", "2015-12-01");
-        DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLoadBalancerAttributesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeLoadBalancerAttributesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeLoadBalancerAttributesOutputDeserializer::deserialize(
-                        "DescribeLoadBalancerAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_load_balancer_attributes(input)
     }
-
-    /// <p>Describes the specified load balancers or all of your load balancers.</p> <p>To describe the listeners for a load balancer, use <a>DescribeListeners</a>. To describe the attributes for a load balancer, use <a>DescribeLoadBalancerAttributes</a>.</p>
     fn describe_load_balancers(
         &self,
         input: DescribeLoadBalancersInput,
     ) -> RusotoFuture<DescribeLoadBalancersOutput, DescribeLoadBalancersError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeLoadBalancers");
-        params.put("Version", "2015-12-01");
-        DescribeLoadBalancersInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLoadBalancersError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeLoadBalancersOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeLoadBalancersOutputDeserializer::deserialize(
-                        "DescribeLoadBalancersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_load_balancers(input)
     }
-
-    /// <p>Describes the specified rules or the rules for the specified listener. You must specify either a listener or one or more rules.</p>
     fn describe_rules(
         &self,
         input: DescribeRulesInput,
     ) -> RusotoFuture<DescribeRulesOutput, DescribeRulesError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeRules");
-        params.put("Version", "2015-12-01");
-        DescribeRulesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeRulesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeRulesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeRulesOutputDeserializer::deserialize(
-                        "DescribeRulesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_rules(input)
     }
-
-    /// <p>Describes the specified policies or all policies used for SSL negotiation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#describe-ssl-policies">Security Policies</a> in the <i>Application Load Balancers Guide</i>.</p>
     fn describe_ssl_policies(
         &self,
         input: DescribeSSLPoliciesInput,
     ) -> RusotoFuture<DescribeSSLPoliciesOutput, DescribeSSLPoliciesError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeSSLPolicies");
-        params.put("Version", "2015-12-01");
-        DescribeSSLPoliciesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeSSLPoliciesError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeSSLPoliciesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeSSLPoliciesOutputDeserializer::deserialize(
-                        "DescribeSSLPoliciesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_ssl_policies(input)
     }
-
-    /// <p>Describes the tags for the specified resources. You can describe the tags for one or more Application Load Balancers, Network Load Balancers, and target groups.</p>
     fn describe_tags(
         &self,
         input: DescribeTagsInput,
     ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeTags");
-        params.put("Version", "2015-12-01");
-        DescribeTagsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeTagsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeTagsOutputDeserializer::deserialize(
-                        "DescribeTagsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_tags(input)
     }
-
-    /// <p>Describes the attributes for the specified target group.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-target-groups.html#target-group-attributes">Target Group Attributes</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-target-groups.html#target-group-attributes">Target Group Attributes</a> in the <i>Network Load Balancers Guide</i>.</p>
     fn describe_target_group_attributes(
         &self,
         input: DescribeTargetGroupAttributesInput,
     ) -> RusotoFuture<DescribeTargetGroupAttributesOutput, DescribeTargetGroupAttributesError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeTargetGroupAttributes");
-        params.put("Version", "2015-12-01");
-        DescribeTargetGroupAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeTargetGroupAttributesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeTargetGroupAttributesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeTargetGroupAttributesOutputDeserializer::deserialize(
-                        "DescribeTargetGroupAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_target_group_attributes(input)
     }
-
-    /// <p>Describes the specified target groups or all of your target groups. By default, all target groups are described. Alternatively, you can specify one of the following to filter the results: the ARN of the load balancer, the names of one or more target groups, or the ARNs of one or more target groups.</p> <p>To describe the targets for a target group, use <a>DescribeTargetHealth</a>. To describe the attributes of a target group, use <a>DescribeTargetGroupAttributes</a>.</p>
     fn describe_target_groups(
         &self,
         input: DescribeTargetGroupsInput,
     ) -> RusotoFuture<DescribeTargetGroupsOutput, DescribeTargetGroupsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeTargetGroups");
-        params.put("Version", "2015-12-01");
-        DescribeTargetGroupsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeTargetGroupsError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeTargetGroupsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeTargetGroupsOutputDeserializer::deserialize(
-                        "DescribeTargetGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_target_groups(input)
     }
-
-    /// <p>Describes the health of the specified targets or all of your targets.</p>
     fn describe_target_health(
         &self,
         input: DescribeTargetHealthInput,
     ) -> RusotoFuture<DescribeTargetHealthOutput, DescribeTargetHealthError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeTargetHealth");
-        params.put("Version", "2015-12-01");
-        DescribeTargetHealthInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeTargetHealthError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeTargetHealthOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeTargetHealthOutputDeserializer::deserialize(
-                        "DescribeTargetHealthResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_target_health(input)
     }
-
-    /// <p>Modifies the specified properties of the specified listener.</p> <p>Any properties that you do not specify retain their current values. However, changing the protocol from HTTPS to HTTP, or from TLS to TCP, removes the security policy and default certificate properties. If you change the protocol from HTTP to HTTPS, or from TCP to TLS, you must add the security policy and default certificate properties.</p>
     fn modify_listener(
         &self,
         input: ModifyListenerInput,
     ) -> RusotoFuture<ModifyListenerOutput, ModifyListenerError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyListener");
-        params.put("Version", "2015-12-01");
-        ModifyListenerInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ModifyListenerError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyListenerOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyListenerOutputDeserializer::deserialize(
-                        "ModifyListenerResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_listener(input)
     }
-
-    /// <p>Modifies the specified attributes of the specified Application Load Balancer or Network Load Balancer.</p> <p>If any of the specified attributes can't be modified as requested, the call fails. Any existing attributes that you do not modify retain their current values.</p>
     fn modify_load_balancer_attributes(
         &self,
         input: ModifyLoadBalancerAttributesInput,
     ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyLoadBalancerAttributes");
-        params.put("Version", "2015-12-01");
-        ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyLoadBalancerAttributesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyLoadBalancerAttributesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyLoadBalancerAttributesOutputDeserializer::deserialize(
-                        "ModifyLoadBalancerAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_load_balancer_attributes(input)
     }
-
-    /// <p>Modifies the specified rule.</p> <p>Any existing properties that you do not modify retain their current values.</p> <p>To modify the actions for the default rule, use <a>ModifyListener</a>.</p>
     fn modify_rule(
         &self,
         input: ModifyRuleInput,
     ) -> RusotoFuture<ModifyRuleOutput, ModifyRuleError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyRule");
-        params.put("Version", "2015-12-01");
-        ModifyRuleInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ModifyRuleError::from_response(response))),
-                );
-            }
+        (**self).modify_rule(input)
+    }
+    fn modify_target_group(
+        &self,
+        input: ModifyTargetGroupInput,
+    ) -> RusotoFuture<ModifyTargetGroupOutput, ModifyTargetGroupError> {
+        (**self).modify_target_group(input)
+    }
+    fn modify_target_group_attributes(
+        &self,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> RusotoFuture<ModifyTargetGroupAttributesOutput, ModifyTargetGroupAttributesError> {
+        (**self).modify_target_group_attributes(input)
+    }
+    fn register_targets(
+        &self,
+        input: RegisterTargetsInput,
+    ) -> RusotoFuture<RegisterTargetsOutput, RegisterTargetsError> {
+        (**self).register_targets(input)
+    }
+    fn remove_listener_certificates(
+        &self,
+        input: RemoveListenerCertificatesInput,
+    ) -> RusotoFuture<RemoveListenerCertificatesOutput, RemoveListenerCertificatesError> {
+        (**self).remove_listener_certificates(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn set_ip_address_type(
+        &self,
+        input: SetIpAddressTypeInput,
+    ) -> RusotoFuture<SetIpAddressTypeOutput, SetIpAddressTypeError> {
+        (**self).set_ip_address_type(input)
+    }
+    fn set_rule_priorities(
+        &self,
+        input: SetRulePrioritiesInput,
+    ) -> RusotoFuture<SetRulePrioritiesOutput, SetRulePrioritiesError> {
+        (**self).set_rule_priorities(input)
+    }
+    fn set_security_groups(
+        &self,
+        input: SetSecurityGroupsInput,
+    ) -> RusotoFuture<SetSecurityGroupsOutput, SetSecurityGroupsError> {
+        (**self).set_security_groups(input)
+    }
+    fn set_subnets(
+        &self,
+        input: SetSubnetsInput,
+    ) -> RusotoFuture<SetSubnetsOutput, SetSubnetsError> {
+        (**self).set_subnets(input)
+    }
+}
+impl<T: Elb + ?Sized> Elb for Box<T> {
+    fn add_listener_certificates(
+        &self,
+        input: AddListenerCertificatesInput,
+    ) -> RusotoFuture<AddListenerCertificatesOutput, AddListenerCertificatesError> {
+        (**self).add_listener_certificates(input)
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_listener(
+        &self,
+        input: CreateListenerInput,
+    ) -> RusotoFuture<CreateListenerOutput, CreateListenerError> {
+        (**self).create_listener(input)
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerInput,
+    ) -> RusotoFuture<CreateLoadBalancerOutput, CreateLoadBalancerError> {
+        (**self).create_load_balancer(input)
+    }
+    fn create_rule(
+        &self,
+        input: CreateRuleInput,
+    ) -> RusotoFuture<CreateRuleOutput, CreateRuleError> {
+        (**self).create_rule(input)
+    }
+    fn create_target_group(
+        &self,
+        input: CreateTargetGroupInput,
+    ) -> RusotoFuture<CreateTargetGroupOutput, CreateTargetGroupError> {
+        (**self).create_target_group(input)
+    }
+    fn delete_listener(
+        &self,
+        input: DeleteListenerInput,
+    ) -> RusotoFuture<DeleteListenerOutput, DeleteListenerError> {
+        (**self).delete_listener(input)
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerOutput, DeleteLoadBalancerError> {
+        (**self).delete_load_balancer(input)
+    }
+    fn delete_rule(
+        &self,
+        input: DeleteRuleInput,
+    ) -> RusotoFuture<DeleteRuleOutput, DeleteRuleError> {
+        (**self).delete_rule(input)
+    }
+    fn delete_target_group(
+        &self,
+        input: DeleteTargetGroupInput,
+    ) -> RusotoFuture<DeleteTargetGroupOutput, DeleteTargetGroupError> {
+        (**self).delete_target_group(input)
+    }
+    fn deregister_targets(
+        &self,
+        input: DeregisterTargetsInput,
+    ) -> RusotoFuture<DeregisterTargetsOutput, DeregisterTargetsError> {
+        (**self).deregister_targets(input)
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        (**self).describe_account_limits(input)
+    }
+    fn describe_listener_certificates(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> RusotoFuture<DescribeListenerCertificatesOutput, DescribeListenerCertificatesError> {
+        (**self).describe_listener_certificates(input)
+    }
+    fn describe_listeners(
+        &self,
+        input: DescribeListenersInput,
+    ) -> RusotoFuture<DescribeListenersOutput, DescribeListenersError> {
+        (**self).describe_listeners(input)
+    }
+    fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
+        (**self).describe_load_balancer_attributes(input)
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> RusotoFuture<DescribeLoadBalancersOutput, DescribeLoadBalancersError> {
+        (**self).describe_load_balancers(input)
+    }
+    fn describe_rules(
+        &self,
+        input: DescribeRulesInput,
+    ) -> RusotoFuture<DescribeRulesOutput, DescribeRulesError> {
+        (**self).describe_rules(input)
+    }
+    fn describe_ssl_policies(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> RusotoFuture<DescribeSSLPoliciesOutput, DescribeSSLPoliciesError> {
+        (**self).describe_ssl_policies(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_target_group_attributes(
+        &self,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> RusotoFuture<DescribeTargetGroupAttributesOutput, DescribeTargetGroupAttributesError> {
+        (**self).describe_target_group_attributes(input)
+    }
+    fn describe_target_groups(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> RusotoFuture<DescribeTargetGroupsOutput, DescribeTargetGroupsError> {
+        (**self).describe_target_groups(input)
+    }
+    fn describe_target_health(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> RusotoFuture<DescribeTargetHealthOutput, DescribeTargetHealthError> {
+        (**self).describe_target_health(input)
+    }
+    fn modify_listener(
+        &self,
+        input: ModifyListenerInput,
+    ) -> RusotoFuture<ModifyListenerOutput, ModifyListenerError> {
+        (**self).modify_listener(input)
+    }
+    fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        (**self).modify_load_balancer_attributes(input)
+    }
+    fn modify_rule(
+        &self,
+        input: ModifyRuleInput,
+    ) -> RusotoFuture<ModifyRuleOutput, ModifyRuleError> {
+        (**self).modify_rule(input)
+    }
+    fn modify_target_group(
+        &self,
+        input: ModifyTargetGroupInput,
+    ) -> RusotoFuture<ModifyTargetGroupOutput, ModifyTargetGroupError> {
+        (**self).modify_target_group(input)
+    }
+    fn modify_target_group_attributes(
+        &self,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> RusotoFuture<ModifyTargetGroupAttributesOutput, ModifyTargetGroupAttributesError> {
+        (**self).modify_target_group_attributes(input)
+    }
+    fn register_targets(
+        &self,
+        input: RegisterTargetsInput,
+    ) -> RusotoFuture<RegisterTargetsOutput, RegisterTargetsError> {
+        (**self).register_targets(input)
+    }
+    fn remove_listener_certificates(
+        &self,
+        input: RemoveListenerCertificatesInput,
+    ) -> RusotoFuture<RemoveListenerCertificatesOutput, RemoveListenerCertificatesError> {
+        (**self).remove_listener_certificates(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn set_ip_address_type(
+        &self,
+        input: SetIpAddressTypeInput,
+    ) -> RusotoFuture<SetIpAddressTypeOutput, SetIpAddressTypeError> {
+        (**self).set_ip_address_type(input)
+    }
+    fn set_rule_priorities(
+        &self,
+        input: SetRulePrioritiesInput,
+    ) -> RusotoFuture<SetRulePrioritiesOutput, SetRulePrioritiesError> {
+        (**self).set_rule_priorities(input)
+    }
+    fn set_security_groups(
+        &self,
+        input: SetSecurityGroupsInput,
+    ) -> RusotoFuture<SetSecurityGroupsOutput, SetSecurityGroupsError> {
+        (**self).set_security_groups(input)
+    }
+    fn set_subnets(
+        &self,
+        input: SetSubnetsInput,
+    ) -> RusotoFuture<SetSubnetsOutput, SetSubnetsError> {
+        (**self).set_subnets(input)
+    }
+}
+impl<T: Elb + ?Sized> Elb for ::std::sync::Arc<T> {
+    fn add_listener_certificates(
+        &self,
+        input: AddListenerCertificatesInput,
+    ) -> RusotoFuture<AddListenerCertificatesOutput, AddListenerCertificatesError> {
+        (**self).add_listener_certificates(input)
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_listener(
+        &self,
+        input: CreateListenerInput,
+    ) -> RusotoFuture<CreateListenerOutput, CreateListenerError> {
+        (**self).create_listener(input)
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerInput,
+    ) -> RusotoFuture<CreateLoadBalancerOutput, CreateLoadBalancerError> {
+        (**self).create_load_balancer(input)
+    }
+    fn create_rule(
+        &self,
+        input: CreateRuleInput,
+    ) -> RusotoFuture<CreateRuleOutput, CreateRuleError> {
+        (**self).create_rule(input)
+    }
+    fn create_target_group(
+        &self,
+        input: CreateTargetGroupInput,
+    ) -> RusotoFuture<CreateTargetGroupOutput, CreateTargetGroupError> {
+        (**self).create_target_group(input)
+    }
+    fn delete_listener(
+        &self,
+        input: DeleteListenerInput,
+    ) -> RusotoFuture<DeleteListenerOutput, DeleteListenerError> {
+        (**self).delete_listener(input)
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerOutput, DeleteLoadBalancerError> {
+        (**self).delete_load_balancer(input)
+    }
+    fn delete_rule(
+        &self,
+        input: DeleteRuleInput,
+    ) -> RusotoFuture<DeleteRuleOutput, DeleteRuleError> {
+        (**self).delete_rule(input)
+    }
+    fn delete_target_group(
+        &self,
+        input: DeleteTargetGroupInput,
+    ) -> RusotoFuture<DeleteTargetGroupOutput, DeleteTargetGroupError> {
+        (**self).delete_target_group(input)
+    }
+    fn deregister_targets(
+        &self,
+        input: DeregisterTargetsInput,
+    ) -> RusotoFuture<DeregisterTargetsOutput, DeregisterTargetsError> {
+        (**self).deregister_targets(input)
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        (**self).describe_account_limits(input)
+    }
+    fn describe_listener_certificates(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> RusotoFuture<DescribeListenerCertificatesOutput, DescribeListenerCertificatesError> {
+        (**self).describe_listener_certificates(input)
+    }
+    fn describe_listeners(
+        &self,
+        input: DescribeListenersInput,
+    ) -> RusotoFuture<DescribeListenersOutput, DescribeListenersError> {
+        (**self).describe_listeners(input)
+    }
+    fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
+        (**self).describe_load_balancer_attributes(input)
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> RusotoFuture<DescribeLoadBalancersOutput, DescribeLoadBalancersError> {
+        (**self).describe_load_balancers(input)
+    }
+    fn describe_rules(
+        &self,
+        input: DescribeRulesInput,
+    ) -> RusotoFuture<DescribeRulesOutput, DescribeRulesError> {
+        (**self).describe_rules(input)
+    }
+    fn describe_ssl_policies(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> RusotoFuture<DescribeSSLPoliciesOutput, DescribeSSLPoliciesError> {
+        (**self).describe_ssl_policies(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_target_group_attributes(
+        &self,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> RusotoFuture<DescribeTargetGroupAttributesOutput, DescribeTargetGroupAttributesError> {
+        (**self).describe_target_group_attributes(input)
+    }
+    fn describe_target_groups(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> RusotoFuture<DescribeTargetGroupsOutput, DescribeTargetGroupsError> {
+        (**self).describe_target_groups(input)
+    }
+    fn describe_target_health(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> RusotoFuture<DescribeTargetHealthOutput, DescribeTargetHealthError> {
+        (**self).describe_target_health(input)
+    }
+    fn modify_listener(
+        &self,
+        input: ModifyListenerInput,
+    ) -> RusotoFuture<ModifyListenerOutput, ModifyListenerError> {
+        (**self).modify_listener(input)
+    }
+    fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        (**self).modify_load_balancer_attributes(input)
+    }
+    fn modify_rule(
+        &self,
+        input: ModifyRuleInput,
+    ) -> RusotoFuture<ModifyRuleOutput, ModifyRuleError> {
+        (**self).modify_rule(input)
+    }
+    fn modify_target_group(
+        &self,
+        input: ModifyTargetGroupInput,
+    ) -> RusotoFuture<ModifyTargetGroupOutput, ModifyTargetGroupError> {
+        (**self).modify_target_group(input)
+    }
+    fn modify_target_group_attributes(
+        &self,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> RusotoFuture<ModifyTargetGroupAttributesOutput, ModifyTargetGroupAttributesError> {
+        (**self).modify_target_group_attributes(input)
+    }
+    fn register_targets(
+        &self,
+        input: RegisterTargetsInput,
+    ) -> RusotoFuture<RegisterTargetsOutput, RegisterTargetsError> {
+        (**self).register_targets(input)
+    }
+    fn remove_listener_certificates(
+        &self,
+        input: RemoveListenerCertificatesInput,
+    ) -> RusotoFuture<RemoveListenerCertificatesOutput, RemoveListenerCertificatesError> {
+        (**self).remove_listener_certificates(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn set_ip_address_type(
+        &self,
+        input: SetIpAddressTypeInput,
+    ) -> RusotoFuture<SetIpAddressTypeOutput, SetIpAddressTypeError> {
+        (**self).set_ip_address_type(input)
+    }
+    fn set_rule_priorities(
+        &self,
+        input: SetRulePrioritiesInput,
+    ) -> RusotoFuture<SetRulePrioritiesOutput, SetRulePrioritiesError> {
+        (**self).set_rule_priorities(input)
+    }
+    fn set_security_groups(
+        &self,
+        input: SetSecurityGroupsInput,
+    ) -> RusotoFuture<SetSecurityGroupsOutput, SetSecurityGroupsError> {
+        (**self).set_security_groups(input)
+    }
+    fn set_subnets(
+        &self,
+        input: SetSubnetsInput,
+    ) -> RusotoFuture<SetSubnetsOutput, SetSubnetsError> {
+        (**self).set_subnets(input)
+    }
+}
+/// A client for the Elastic Load Balancing v2 API.
+#[derive(Clone)]
+pub struct ElbClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ElbClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ElbClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElbClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
+        ElbClient { client, region }
+    }
+}
+
+impl Elb for ElbClient {
+    /// <p>Adds the specified SSL server certificate to the certificate list for the specified HTTPS or TLS listener.</p> <p>If the certificate in already in the certificate list, the call is successful but the certificate is not added again.</p> <p>To get the certificate list for a listener, use <a>DescribeListenerCertificates</a>. To remove certificates from the certificate list for a listener, use <a>RemoveListenerCertificates</a>. To replace the default certificate for a listener, use <a>ModifyListener</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#https-listener-certificates">SSL Certificates</a> in the <i>Application Load Balancers Guide</i>.</p>
+    fn add_listener_certificates(
+        &self,
+        input: AddListenerCertificatesInput,
+    ) -> RusotoFuture<AddListenerCertificatesOutput, AddListenerCertificatesError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddListenerCertificates");
+        let mut params = Params::new();
+
+        params.put("Action", "AddListenerCertificates");
+        params.put("Version", "2015-12-01");
+        AddListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AddListenerCertificatesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AddListenerCertificatesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AddListenerCertificatesOutputDeserializer::deserialize(
+                        "AddListenerCertificatesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("AddListenerCertificates", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Adds the specified tags to the specified Elastic Load Balancing resource. You can tag your Application Load Balancers, Network Load Balancers, and your target groups.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, <code>AddTags</code> updates its value.</p> <p>To list the current tags for your resources, use <a>DescribeTags</a>. To remove tags from your resources, use <a>RemoveTags</a>.</p>
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddTags");
+        let mut params = Params::new();
+
+        params.put("Action", "AddTags");
+        params.put("Version", "2015-12-01");
+        AddTagsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AddTagsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AddTagsOutputDeserializer::deserialize("AddTagsResult", &mut stack)
+                        .map_err(|err| stack.parse_error("AddTags", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a listener for the specified Application Load Balancer or Network Load Balancer.</p> <p>To update a listener, use <a>ModifyListener</a>. When you are finished with a listener, you can delete it using <a>DeleteListener</a>. If you are finished with both the listener and the load balancer, you can delete them both using <a>DeleteLoadBalancer</a>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple listeners with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-listeners.html">Listeners for Your Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-listeners.html">Listeners for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    fn create_listener(
+        &self,
+        input: CreateListenerInput,
+    ) -> RusotoFuture<CreateListenerOutput, CreateListenerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateListener");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateListener");
+        params.put("Version", "2015-12-01");
+        CreateListenerInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateListenerError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateListenerOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateListenerOutputDeserializer::deserialize(
+                        "CreateListenerResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateListener", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates an Application Load Balancer or a Network Load Balancer.</p> <p>When you create a load balancer, you can specify security groups, public subnets, IP address type, and tags. Otherwise, you could do so later using <a>SetSecurityGroups</a>, <a>SetSubnets</a>, <a>SetIpAddressType</a>, and <a>AddTags</a>.</p> <p>To create listeners for your load balancer, use <a>CreateListener</a>. To describe your current load balancers, see <a>DescribeLoadBalancers</a>. When you are finished with a load balancer, you can delete it using <a>DeleteLoadBalancer</a>.</p> <p>For limit information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-limits.html">Limits for Your Application Load Balancer</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-limits.html">Limits for Your Network Load Balancer</a> in the <i>Network Load Balancers Guide</i>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple load balancers with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/application-load-balancers.html">Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/network-load-balancers.html">Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerInput,
+    ) -> RusotoFuture<CreateLoadBalancerOutput, CreateLoadBalancerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateLoadBalancer");
+        params.put("Version", "2015-12-01");
+        CreateLoadBalancerInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateLoadBalancerError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateLoadBalancerOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateLoadBalancerOutputDeserializer::deserialize(
+                        "CreateLoadBalancerResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateLoadBalancer", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a rule for the specified listener. The listener must be associated with an Application Load Balancer.</p> <p>Rules are evaluated in priority order, from the lowest value to the highest value. When the conditions for a rule are met, its actions are performed. If the conditions for no rules are met, the actions for the default rule are performed. For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-listeners.html#listener-rules">Listener Rules</a> in the <i>Application Load Balancers Guide</i>.</p> <p>To view your current rules, use <a>DescribeRules</a>. To update a rule, use <a>ModifyRule</a>. To set the priorities of your rules, use <a>SetRulePriorities</a>. To delete a rule, use <a>DeleteRule</a>.</p>
+    fn create_rule(
+        &self,
+        input: CreateRuleInput,
+    ) -> RusotoFuture<CreateRuleOutput, CreateRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateRule");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateRule");
+        params.put("Version", "2015-12-01");
+        CreateRuleInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateRuleError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateRuleOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        CreateRuleOutputDeserializer::deserialize("CreateRuleResult", &mut stack)
+                            .map_err(|err| stack.parse_error("CreateRule", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a target group.</p> <p>To register targets with the target group, use <a>RegisterTargets</a>. To update the health check settings for the target group, use <a>ModifyTargetGroup</a>. To monitor the health of targets in the target group, use <a>DescribeTargetHealth</a>.</p> <p>To route traffic to the targets in a target group, specify the target group in an action using <a>CreateListener</a> or <a>CreateRule</a>.</p> <p>To delete a target group, use <a>DeleteTargetGroup</a>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple target groups with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-target-groups.html">Target Groups for Your Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-target-groups.html">Target Groups for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    fn create_target_group(
+        &self,
+        input: CreateTargetGroupInput,
+    ) -> RusotoFuture<CreateTargetGroupOutput, CreateTargetGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateTargetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateTargetGroup");
+        params.put("Version", "2015-12-01");
+        CreateTargetGroupInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTargetGroupError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateTargetGroupOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateTargetGroupOutputDeserializer::deserialize(
+                        "CreateTargetGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateTargetGroup", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified listener.</p> <p>Alternatively, your listener is deleted when you delete the load balancer to which it is attached, using <a>DeleteLoadBalancer</a>.</p>
+    fn delete_listener(
+        &self,
+        input: DeleteListenerInput,
+    ) -> RusotoFuture<DeleteListenerOutput, DeleteListenerError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteListener");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteListener");
+        params.put("Version", "2015-12-01");
+        DeleteListenerInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteListenerError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteListenerOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteListenerOutputDeserializer::deserialize(
+                        "DeleteListenerResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteListener", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified Application Load Balancer or Network Load Balancer and its attached listeners.</p> <p>You can't delete a load balancer if deletion protection is enabled. If the load balancer does not exist or has already been deleted, the call succeeds.</p> <p>Deleting a load balancer does not affect its registered targets. For example, your EC2 instances continue to run and are still registered to their target groups. If you no longer need these EC2 instances, you can stop or terminate them.</p>
+    fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerOutput, DeleteLoadBalancerError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteLoadBalancer");
+        params.put("Version", "2015-12-01");
+        DeleteLoadBalancerInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteLoadBalancerError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteLoadBalancerOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteLoadBalancerOutputDeserializer::deserialize(
+                        "DeleteLoadBalancerResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteLoadBalancer", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified rule.</p>
+    fn delete_rule(
+        &self,
+        input: DeleteRuleInput,
+    ) -> RusotoFuture<DeleteRuleOutput, DeleteRuleError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteRule");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteRule");
+        params.put("Version", "2015-12-01");
+        DeleteRuleInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteRuleError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteRuleOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        DeleteRuleOutputDeserializer::deserialize("DeleteRuleResult", &mut stack)
+                            .map_err(|err| stack.parse_error("DeleteRule", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified target group.</p> <p>You can delete a target group if it is not referenced by any actions. Deleting a target group also deletes any associated health checks.</p>
+    fn delete_target_group(
+        &self,
+        input: DeleteTargetGroupInput,
+    ) -> RusotoFuture<DeleteTargetGroupOutput, DeleteTargetGroupError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteTargetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteTargetGroup");
+        params.put("Version", "2015-12-01");
+        DeleteTargetGroupInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTargetGroupError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteTargetGroupOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteTargetGroupOutputDeserializer::deserialize(
+                        "DeleteTargetGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteTargetGroup", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deregisters the specified targets from the specified target group. After the targets are deregistered, they no longer receive traffic from the load balancer.</p>
+    fn deregister_targets(
+        &self,
+        input: DeregisterTargetsInput,
+    ) -> RusotoFuture<DeregisterTargetsOutput, DeregisterTargetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeregisterTargets");
+        let mut params = Params::new();
+
+        params.put("Action", "DeregisterTargets");
+        params.put("Version", "2015-12-01");
+        DeregisterTargetsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeregisterTargetsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeregisterTargetsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeregisterTargetsOutputDeserializer::deserialize(
+                        "DeregisterTargetsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeregisterTargets", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the current Elastic Load Balancing resource limits for your AWS account.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-limits.html">Limits for Your Application Load Balancers</a> in the <i>Application Load Balancer Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-limits.html">Limits for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeAccountLimits");
+        params.put("Version", "2015-12-01");
+        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAccountLimitsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeAccountLimitsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeAccountLimitsOutputDeserializer::deserialize(
+                        "DescribeAccountLimitsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAccountLimits", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the default certificate and the certificate list for the specified HTTPS or TLS listener.</p> <p>If the default certificate is also in the certificate list, it appears twice in the results (once with <code>IsDefault</code> set to true and once with <code>IsDefault</code> set to false).</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#https-listener-certificates">SSL Certificates</a> in the <i>Application Load Balancers Guide</i>.</p>
+    fn describe_listener_certificates(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> RusotoFuture<DescribeListenerCertificatesOutput, DescribeListenerCertificatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeListenerCertificates");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeListenerCertificates");
+        params.put("Version", "2015-12-01");
+        DescribeListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeListenerCertificatesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeListenerCertificatesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeListenerCertificatesOutputDeserializer::deserialize(
+                        "DescribeListenerCertificatesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeListenerCertificates", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the specified listeners or the listeners for the specified Application Load Balancer or Network Load Balancer. You must specify either a load balancer or one or more listeners.</p> <p>For an HTTPS or TLS listener, the output includes the default certificate for the listener. To describe the certificate list for the listener, use <a>DescribeListenerCertificates</a>.</p>
+    fn describe_listeners(
+        &self,
+        input: DescribeListenersInput,
+    ) -> RusotoFuture<DescribeListenersOutput, DescribeListenersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeListeners");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeListeners");
+        params.put("Version", "2015-12-01");
+        DescribeListenersInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeListenersError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeListenersOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeListenersOutputDeserializer::deserialize(
+                        "DescribeListenersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeListeners", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the attributes for the specified Application Load Balancer or Network Load Balancer.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/application-load-balancers.html#load-balancer-attributes">Load Balancer Attributes</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/network-load-balancers.html#load-balancer-attributes">Load Balancer Attributes</a> in the <i>Network Load Balancers Guide</i>.</p>
+    fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeLoadBalancerAttributes");
+        params.put("Version", "2015-12-01");
+        DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeLoadBalancerAttributesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeLoadBalancerAttributesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeLoadBalancerAttributesOutputDeserializer::deserialize(
+                        "DescribeLoadBalancerAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLoadBalancerAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the specified load balancers or all of your load balancers.</p> <p>To describe the listeners for a load balancer, use <a>DescribeListeners</a>. To describe the attributes for a load balancer, use <a>DescribeLoadBalancerAttributes</a>.</p>
+    fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> RusotoFuture<DescribeLoadBalancersOutput, DescribeLoadBalancersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeLoadBalancers");
+        params.put("Version", "2015-12-01");
+        DescribeLoadBalancersInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeLoadBalancersError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeLoadBalancersOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeLoadBalancersOutputDeserializer::deserialize(
+                        "DescribeLoadBalancersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLoadBalancers", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the specified rules or the rules for the specified listener. You must specify either a listener or one or more rules.</p>
+    fn describe_rules(
+        &self,
+        input: DescribeRulesInput,
+    ) -> RusotoFuture<DescribeRulesOutput, DescribeRulesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeRules");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeRules");
+        params.put("Version", "2015-12-01");
+        DescribeRulesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeRulesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeRulesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeRulesOutputDeserializer::deserialize(
+                        "DescribeRulesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeRules", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the specified policies or all policies used for SSL negotiation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#describe-ssl-policies">Security Policies</a> in the <i>Application Load Balancers Guide</i>.</p>
+    fn describe_ssl_policies(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> RusotoFuture<DescribeSSLPoliciesOutput, DescribeSSLPoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeSSLPolicies");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeSSLPolicies");
+        params.put("Version", "2015-12-01");
+        DescribeSSLPoliciesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeSSLPoliciesError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeSSLPoliciesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeSSLPoliciesOutputDeserializer::deserialize(
+                        "DescribeSSLPoliciesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeSSLPolicies", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the tags for the specified resources. You can describe the tags for one or more Application Load Balancers, Network Load Balancers, and target groups.</p>
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTags");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeTags");
+        params.put("Version", "2015-12-01");
+        DescribeTagsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeTagsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeTagsOutputDeserializer::deserialize(
+                        "DescribeTagsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeTags", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the attributes for the specified target group.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-target-groups.html#target-group-attributes">Target Group Attributes</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-target-groups.html#target-group-attributes">Target Group Attributes</a> in the <i>Network Load Balancers Guide</i>.</p>
+    fn describe_target_group_attributes(
+        &self,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> RusotoFuture<DescribeTargetGroupAttributesOutput, DescribeTargetGroupAttributesError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetGroupAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeTargetGroupAttributes");
+        params.put("Version", "2015-12-01");
+        DescribeTargetGroupAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeTargetGroupAttributesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeTargetGroupAttributesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeTargetGroupAttributesOutputDeserializer::deserialize(
+                        "DescribeTargetGroupAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeTargetGroupAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the specified target groups or all of your target groups. By default, all target groups are described. Alternatively, you can specify one of the following to filter the results: the ARN of the load balancer, the names of one or more target groups, or the ARNs of one or more target groups.</p> <p>To describe the targets for a target group, use <a>DescribeTargetHealth</a>. To describe the attributes of a target group, use <a>DescribeTargetGroupAttributes</a>.</p>
+    fn describe_target_groups(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> RusotoFuture<DescribeTargetGroupsOutput, DescribeTargetGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeTargetGroups");
+        params.put("Version", "2015-12-01");
+        DescribeTargetGroupsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeTargetGroupsError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeTargetGroupsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeTargetGroupsOutputDeserializer::deserialize(
+                        "DescribeTargetGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeTargetGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes the health of the specified targets or all of your targets.</p>
+    fn describe_target_health(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> RusotoFuture<DescribeTargetHealthOutput, DescribeTargetHealthError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetHealth");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeTargetHealth");
+        params.put("Version", "2015-12-01");
+        DescribeTargetHealthInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeTargetHealthError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeTargetHealthOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeTargetHealthOutputDeserializer::deserialize(
+                        "DescribeTargetHealthResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeTargetHealth", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the specified properties of the specified listener.</p> <p>Any properties that you do not specify retain their current values. However, changing the protocol from HTTPS to HTTP, or from TLS to TCP, removes the security policy and default certificate properties. If you change the protocol from HTTP to HTTPS, or from TCP to TLS, you must add the security policy and default certificate properties.</p>
+    fn modify_listener(
+        &self,
+        input: ModifyListenerInput,
+    ) -> RusotoFuture<ModifyListenerOutput, ModifyListenerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyListener");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyListener");
+        params.put("Version", "2015-12-01");
+        ModifyListenerInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ModifyListenerError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyListenerOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyListenerOutputDeserializer::deserialize(
+                        "ModifyListenerResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ModifyListener", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the specified attributes of the specified Application Load Balancer or Network Load Balancer.</p> <p>If any of the specified attributes can't be modified as requested, the call fails. Any existing attributes that you do not modify retain their current values.</p>
+    fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyLoadBalancerAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyLoadBalancerAttributes");
+        params.put("Version", "2015-12-01");
+        ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyLoadBalancerAttributesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyLoadBalancerAttributesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyLoadBalancerAttributesOutputDeserializer::deserialize(
+                        "ModifyLoadBalancerAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyLoadBalancerAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the specified rule.</p> <p>Any existing properties that you do not modify retain their current values.</p> <p>To modify the actions for the default rule, use <a>ModifyListener</a>.</p>
+    fn modify_rule(
+        &self,
+        input: ModifyRuleInput,
+    ) -> RusotoFuture<ModifyRuleOutput, ModifyRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyRule");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyRule");
+        params.put("Version", "2015-12-01");
+        ModifyRuleInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ModifyRuleError::from_response(response))),
+                );
+            }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let result;
@@ -9917,6 +11435,7 @@ impl Elb for ElbClient {
                 if response.body.is_empty() {
                     result = ModifyRuleOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -9926,7 +11445,8 @@ impl Elb for ElbClient {
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
                     result =
-                        ModifyRuleOutputDeserializer::deserialize("ModifyRuleResult", &mut stack)?;
+                        ModifyRuleOutputDeserializer::deserialize("ModifyRuleResult", &mut stack)
+                            .map_err(|err| stack.parse_error("ModifyRule", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -9941,7 +11461,16 @@ impl Elb for ElbClient {
         &self,
         input: ModifyTargetGroupInput,
     ) -> RusotoFuture<ModifyTargetGroupOutput, ModifyTargetGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyTargetGroup");
         let mut params = Params::new();
 
         params.put("Action", "ModifyTargetGroup");
@@ -9966,6 +11495,7 @@ impl Elb for ElbClient {
                 if response.body.is_empty() {
                     result = ModifyTargetGroupOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -9977,7 +11507,8 @@ impl Elb for ElbClient {
                     result = ModifyTargetGroupOutputDeserializer::deserialize(
                         "ModifyTargetGroupResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("ModifyTargetGroup", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -9992,7 +11523,16 @@ impl Elb for ElbClient {
         &self,
         input: ModifyTargetGroupAttributesInput,
     ) -> RusotoFuture<ModifyTargetGroupAttributesOutput, ModifyTargetGroupAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyTargetGroupAttributes");
         let mut params = Params::new();
 
         params.put("Action", "ModifyTargetGroupAttributes");
@@ -10014,6 +11554,7 @@ impl Elb for ElbClient {
                 if response.body.is_empty() {
                     result = ModifyTargetGroupAttributesOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -10025,7 +11566,10 @@ impl Elb for ElbClient {
                     result = ModifyTargetGroupAttributesOutputDeserializer::deserialize(
                         "ModifyTargetGroupAttributesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyTargetGroupAttributes", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -10040,7 +11584,16 @@ impl Elb for ElbClient {
         &self,
         input: RegisterTargetsInput,
     ) -> RusotoFuture<RegisterTargetsOutput, RegisterTargetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RegisterTargets");
         let mut params = Params::new();
 
         params.put("Action", "RegisterTargets");
@@ -10065,6 +11618,7 @@ impl Elb for ElbClient {
                 if response.body.is_empty() {
                     result = RegisterTargetsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -10076,7 +11630,8 @@ impl Elb for ElbClient {
                     result = RegisterTargetsOutputDeserializer::deserialize(
                         "RegisterTargetsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("RegisterTargets", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -10092,6 +11647,7 @@ impl Elb for ElbClient {
         input: RemoveListenerCertificatesInput,
     ) -> RusotoFuture<RemoveListenerCertificatesOutput, RemoveListenerCertificatesError> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveListenerCertificates");
         let mut params = Params::new();
 
         params.put("Action", "RemoveListenerCertificates");
@@ -10111,8 +11667,295 @@ impl Elb for ElbClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = RemoveListenerCertificatesOutput::default();
+                    result = RemoveListenerCertificatesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RemoveListenerCertificatesOutputDeserializer::deserialize(
+                        "RemoveListenerCertificatesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RemoveListenerCertificates", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Removes the specified tags from the specified Elastic Load Balancing resource.</p> <p>To list the current tags for your resources, use <a>DescribeTags</a>.</p>
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveTags");
+        let mut params = Params::new();
+
+        params.put("Action", "RemoveTags");
+        params.put("Version", "2015-12-01");
+        RemoveTagsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RemoveTagsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RemoveTagsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        RemoveTagsOutputDeserializer::deserialize("RemoveTagsResult", &mut stack)
+                            .map_err(|err| stack.parse_error("RemoveTags", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Sets the type of IP addresses used by the subnets of the specified Application Load Balancer or Network Load Balancer.</p>
+    fn set_ip_address_type(
+        &self,
+        input: SetIpAddressTypeInput,
+    ) -> RusotoFuture<SetIpAddressTypeOutput, SetIpAddressTypeError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetIpAddressType");
+        let mut params = Params::new();
+
+        params.put("Action", "SetIpAddressType");
+        params.put("Version", "2015-12-01");
+        SetIpAddressTypeInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetIpAddressTypeError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetIpAddressTypeOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetIpAddressTypeOutputDeserializer::deserialize(
+                        "SetIpAddressTypeResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("SetIpAddressType", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Sets the priorities of the specified rules.</p> <p>You can reorder the rules as long as there are no priority conflicts in the new order. Any existing rules that you do not specify retain their current priority.</p>
+    fn set_rule_priorities(
+        &self,
+        input: SetRulePrioritiesInput,
+    ) -> RusotoFuture<SetRulePrioritiesOutput, SetRulePrioritiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetRulePriorities");
+        let mut params = Params::new();
+
+        params.put("Action", "SetRulePriorities");
+        params.put("Version", "2015-12-01");
+        SetRulePrioritiesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetRulePrioritiesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetRulePrioritiesOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetRulePrioritiesOutputDeserializer::deserialize(
+                        "SetRulePrioritiesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("SetRulePriorities", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Associates the specified security groups with the specified Application Load Balancer. The specified security groups override the previously associated security groups.</p> <p>You can't specify a security group for a Network Load Balancer.</p>
+    fn set_security_groups(
+        &self,
+        input: SetSecurityGroupsInput,
+    ) -> RusotoFuture<SetSecurityGroupsOutput, SetSecurityGroupsError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetSecurityGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "SetSecurityGroups");
+        params.put("Version", "2015-12-01");
+        SetSecurityGroupsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetSecurityGroupsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetSecurityGroupsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetSecurityGroupsOutputDeserializer::deserialize(
+                        "SetSecurityGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("SetSecurityGroups", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Enables the Availability Zone for the specified public subnets for the specified Application Load Balancer. The specified subnets replace the previously enabled subnets.</p> <p>You can't change the subnets for a Network Load Balancer.</p>
+    fn set_subnets(
+        &self,
+        input: SetSubnetsInput,
+    ) -> RusotoFuture<SetSubnetsOutput, SetSubnetsError> {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetSubnets");
+        let mut params = Params::new();
+
+        params.put("Action", "SetSubnets");
+        params.put("Version", "2015-12-01");
+        SetSubnetsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetSubnetsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetSubnetsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -10121,10 +11964,9 @@ impl Elb for ElbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = RemoveListenerCertificatesOutputDeserializer::deserialize(
-                        "RemoveListenerCertificatesResult",
-                        &mut stack,
-                    )?;
+                    result =
+                        SetSubnetsOutputDeserializer::deserialize("SetSubnetsResult", &mut stack)
+                            .map_err(|err| stack.parse_error("SetSubnets", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -10133,256 +11975,1180 @@ impl Elb for ElbClient {
             }))
         })
     }
-
-    /// <p>Removes the specified tags from the specified Elastic Load Balancing resource.</p> <p>To list the current tags for your resources, use <a>DescribeTags</a>.</p>
+}
+
+/// A mock of the Elastic Load Balancing v2 API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockElb {
+    add_listener_certificates: Option<
+        Box<
+            dyn Fn(
+                    AddListenerCertificatesInput,
+                ) -> ::std::result::Result<
+                    AddListenerCertificatesOutput,
+                    RusotoError<AddListenerCertificatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_tags: Option<
+        Box<
+            dyn Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_listener: Option<
+        Box<
+            dyn Fn(
+                    CreateListenerInput,
+                )
+                    -> ::std::result::Result<CreateListenerOutput, RusotoError<CreateListenerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    CreateLoadBalancerInput,
+                ) -> ::std::result::Result<
+                    CreateLoadBalancerOutput,
+                    RusotoError<CreateLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_rule: Option<
+        Box<
+            dyn Fn(
+                    CreateRuleInput,
+                )
+                    -> ::std::result::Result<CreateRuleOutput, RusotoError<CreateRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_target_group: Option<
+        Box<
+            dyn Fn(
+                    CreateTargetGroupInput,
+                ) -> ::std::result::Result<
+                    CreateTargetGroupOutput,
+                    RusotoError<CreateTargetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_listener: Option<
+        Box<
+            dyn Fn(
+                    DeleteListenerInput,
+                )
+                    -> ::std::result::Result<DeleteListenerOutput, RusotoError<DeleteListenerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    DeleteLoadBalancerInput,
+                ) -> ::std::result::Result<
+                    DeleteLoadBalancerOutput,
+                    RusotoError<DeleteLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_rule: Option<
+        Box<
+            dyn Fn(
+                    DeleteRuleInput,
+                )
+                    -> ::std::result::Result<DeleteRuleOutput, RusotoError<DeleteRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_target_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteTargetGroupInput,
+                ) -> ::std::result::Result<
+                    DeleteTargetGroupOutput,
+                    RusotoError<DeleteTargetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deregister_targets: Option<
+        Box<
+            dyn Fn(
+                    DeregisterTargetsInput,
+                ) -> ::std::result::Result<
+                    DeregisterTargetsOutput,
+                    RusotoError<DeregisterTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_account_limits: Option<
+        Box<
+            dyn Fn(
+                    DescribeAccountLimitsInput,
+                ) -> ::std::result::Result<
+                    DescribeAccountLimitsOutput,
+                    RusotoError<DescribeAccountLimitsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_listener_certificates: Option<
+        Box<
+            dyn Fn(
+                    DescribeListenerCertificatesInput,
+                ) -> ::std::result::Result<
+                    DescribeListenerCertificatesOutput,
+                    RusotoError<DescribeListenerCertificatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_listeners: Option<
+        Box<
+            dyn Fn(
+                    DescribeListenersInput,
+                ) -> ::std::result::Result<
+                    DescribeListenersOutput,
+                    RusotoError<DescribeListenersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_load_balancer_attributes: Option<
+        Box<
+            dyn Fn(
+                    DescribeLoadBalancerAttributesInput,
+                ) -> ::std::result::Result<
+                    DescribeLoadBalancerAttributesOutput,
+                    RusotoError<DescribeLoadBalancerAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_load_balancers: Option<
+        Box<
+            dyn Fn(
+                    DescribeLoadBalancersInput,
+                ) -> ::std::result::Result<
+                    DescribeLoadBalancersOutput,
+                    RusotoError<DescribeLoadBalancersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_rules: Option<
+        Box<
+            dyn Fn(
+                    DescribeRulesInput,
+                )
+                    -> ::std::result::Result<DescribeRulesOutput, RusotoError<DescribeRulesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_ssl_policies: Option<
+        Box<
+            dyn Fn(
+                    DescribeSSLPoliciesInput,
+                ) -> ::std::result::Result<
+                    DescribeSSLPoliciesOutput,
+                    RusotoError<DescribeSSLPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tags: Option<
+        Box<
+            dyn Fn(
+                    DescribeTagsInput,
+                )
+                    -> ::std::result::Result<DescribeTagsOutput, RusotoError<DescribeTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_target_group_attributes: Option<
+        Box<
+            dyn Fn(
+                    DescribeTargetGroupAttributesInput,
+                ) -> ::std::result::Result<
+                    DescribeTargetGroupAttributesOutput,
+                    RusotoError<DescribeTargetGroupAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_target_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeTargetGroupsInput,
+                ) -> ::std::result::Result<
+                    DescribeTargetGroupsOutput,
+                    RusotoError<DescribeTargetGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_target_health: Option<
+        Box<
+            dyn Fn(
+                    DescribeTargetHealthInput,
+                ) -> ::std::result::Result<
+                    DescribeTargetHealthOutput,
+                    RusotoError<DescribeTargetHealthError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_listener: Option<
+        Box<
+            dyn Fn(
+                    ModifyListenerInput,
+                )
+                    -> ::std::result::Result<ModifyListenerOutput, RusotoError<ModifyListenerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_load_balancer_attributes: Option<
+        Box<
+            dyn Fn(
+                    ModifyLoadBalancerAttributesInput,
+                ) -> ::std::result::Result<
+                    ModifyLoadBalancerAttributesOutput,
+                    RusotoError<ModifyLoadBalancerAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_rule: Option<
+        Box<
+            dyn Fn(
+                    ModifyRuleInput,
+                )
+                    -> ::std::result::Result<ModifyRuleOutput, RusotoError<ModifyRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_target_group: Option<
+        Box<
+            dyn Fn(
+                    ModifyTargetGroupInput,
+                ) -> ::std::result::Result<
+                    ModifyTargetGroupOutput,
+                    RusotoError<ModifyTargetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_target_group_attributes: Option<
+        Box<
+            dyn Fn(
+                    ModifyTargetGroupAttributesInput,
+                ) -> ::std::result::Result<
+                    ModifyTargetGroupAttributesOutput,
+                    RusotoError<ModifyTargetGroupAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_targets: Option<
+        Box<
+            dyn Fn(
+                    RegisterTargetsInput,
+                ) -> ::std::result::Result<
+                    RegisterTargetsOutput,
+                    RusotoError<RegisterTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_listener_certificates: Option<
+        Box<
+            dyn Fn(
+                    RemoveListenerCertificatesInput,
+                ) -> ::std::result::Result<
+                    RemoveListenerCertificatesOutput,
+                    RusotoError<RemoveListenerCertificatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsInput,
+                )
+                    -> ::std::result::Result<RemoveTagsOutput, RusotoError<RemoveTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_ip_address_type: Option<
+        Box<
+            dyn Fn(
+                    SetIpAddressTypeInput,
+                ) -> ::std::result::Result<
+                    SetIpAddressTypeOutput,
+                    RusotoError<SetIpAddressTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_rule_priorities: Option<
+        Box<
+            dyn Fn(
+                    SetRulePrioritiesInput,
+                ) -> ::std::result::Result<
+                    SetRulePrioritiesOutput,
+                    RusotoError<SetRulePrioritiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_security_groups: Option<
+        Box<
+            dyn Fn(
+                    SetSecurityGroupsInput,
+                ) -> ::std::result::Result<
+                    SetSecurityGroupsOutput,
+                    RusotoError<SetSecurityGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_subnets: Option<
+        Box<
+            dyn Fn(
+                    SetSubnetsInput,
+                )
+                    -> ::std::result::Result<SetSubnetsOutput, RusotoError<SetSubnetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockElb {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockElb {
+        Default::default()
+    }
+
+    /// Answers `add_listener_certificates` with what `handler` returns for its input.
+    pub fn with_add_listener_certificates<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                AddListenerCertificatesInput,
+            ) -> ::std::result::Result<
+                AddListenerCertificatesOutput,
+                RusotoError<AddListenerCertificatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_listener_certificates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_tags` with what `handler` returns for its input.
+    pub fn with_add_tags<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_listener` with what `handler` returns for its input.
+    pub fn with_create_listener<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                CreateListenerInput,
+            )
+                -> ::std::result::Result<CreateListenerOutput, RusotoError<CreateListenerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_listener = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_load_balancer` with what `handler` returns for its input.
+    pub fn with_create_load_balancer<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                CreateLoadBalancerInput,
+            ) -> ::std::result::Result<
+                CreateLoadBalancerOutput,
+                RusotoError<CreateLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_rule` with what `handler` returns for its input.
+    pub fn with_create_rule<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                CreateRuleInput,
+            ) -> ::std::result::Result<CreateRuleOutput, RusotoError<CreateRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_target_group` with what `handler` returns for its input.
+    pub fn with_create_target_group<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                CreateTargetGroupInput,
+            ) -> ::std::result::Result<
+                CreateTargetGroupOutput,
+                RusotoError<CreateTargetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_target_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_listener` with what `handler` returns for its input.
+    pub fn with_delete_listener<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DeleteListenerInput,
+            )
+                -> ::std::result::Result<DeleteListenerOutput, RusotoError<DeleteListenerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_listener = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_load_balancer` with what `handler` returns for its input.
+    pub fn with_delete_load_balancer<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DeleteLoadBalancerInput,
+            ) -> ::std::result::Result<
+                DeleteLoadBalancerOutput,
+                RusotoError<DeleteLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_rule` with what `handler` returns for its input.
+    pub fn with_delete_rule<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DeleteRuleInput,
+            ) -> ::std::result::Result<DeleteRuleOutput, RusotoError<DeleteRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_target_group` with what `handler` returns for its input.
+    pub fn with_delete_target_group<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DeleteTargetGroupInput,
+            ) -> ::std::result::Result<
+                DeleteTargetGroupOutput,
+                RusotoError<DeleteTargetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_target_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_targets` with what `handler` returns for its input.
+    pub fn with_deregister_targets<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DeregisterTargetsInput,
+            ) -> ::std::result::Result<
+                DeregisterTargetsOutput,
+                RusotoError<DeregisterTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account_limits` with what `handler` returns for its input.
+    pub fn with_describe_account_limits<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeAccountLimitsInput,
+            ) -> ::std::result::Result<
+                DescribeAccountLimitsOutput,
+                RusotoError<DescribeAccountLimitsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account_limits = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_listener_certificates` with what `handler` returns for its input.
+    pub fn with_describe_listener_certificates<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeListenerCertificatesInput,
+            ) -> ::std::result::Result<
+                DescribeListenerCertificatesOutput,
+                RusotoError<DescribeListenerCertificatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_listener_certificates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_listeners` with what `handler` returns for its input.
+    pub fn with_describe_listeners<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeListenersInput,
+            ) -> ::std::result::Result<
+                DescribeListenersOutput,
+                RusotoError<DescribeListenersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_listeners = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_load_balancer_attributes` with what `handler` returns for its input.
+    pub fn with_describe_load_balancer_attributes<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeLoadBalancerAttributesInput,
+            ) -> ::std::result::Result<
+                DescribeLoadBalancerAttributesOutput,
+                RusotoError<DescribeLoadBalancerAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_load_balancer_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_load_balancers` with what `handler` returns for its input.
+    pub fn with_describe_load_balancers<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeLoadBalancersInput,
+            ) -> ::std::result::Result<
+                DescribeLoadBalancersOutput,
+                RusotoError<DescribeLoadBalancersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_load_balancers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_rules` with what `handler` returns for its input.
+    pub fn with_describe_rules<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeRulesInput,
+            )
+                -> ::std::result::Result<DescribeRulesOutput, RusotoError<DescribeRulesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_rules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_ssl_policies` with what `handler` returns for its input.
+    pub fn with_describe_ssl_policies<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeSSLPoliciesInput,
+            ) -> ::std::result::Result<
+                DescribeSSLPoliciesOutput,
+                RusotoError<DescribeSSLPoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_ssl_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tags` with what `handler` returns for its input.
+    pub fn with_describe_tags<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeTagsInput,
+            )
+                -> ::std::result::Result<DescribeTagsOutput, RusotoError<DescribeTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_target_group_attributes` with what `handler` returns for its input.
+    pub fn with_describe_target_group_attributes<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeTargetGroupAttributesInput,
+            ) -> ::std::result::Result<
+                DescribeTargetGroupAttributesOutput,
+                RusotoError<DescribeTargetGroupAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_target_group_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_target_groups` with what `handler` returns for its input.
+    pub fn with_describe_target_groups<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeTargetGroupsInput,
+            ) -> ::std::result::Result<
+                DescribeTargetGroupsOutput,
+                RusotoError<DescribeTargetGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_target_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_target_health` with what `handler` returns for its input.
+    pub fn with_describe_target_health<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                DescribeTargetHealthInput,
+            ) -> ::std::result::Result<
+                DescribeTargetHealthOutput,
+                RusotoError<DescribeTargetHealthError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_target_health = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_listener` with what `handler` returns for its input.
+    pub fn with_modify_listener<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                ModifyListenerInput,
+            )
+                -> ::std::result::Result<ModifyListenerOutput, RusotoError<ModifyListenerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_listener = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_load_balancer_attributes` with what `handler` returns for its input.
+    pub fn with_modify_load_balancer_attributes<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                ModifyLoadBalancerAttributesInput,
+            ) -> ::std::result::Result<
+                ModifyLoadBalancerAttributesOutput,
+                RusotoError<ModifyLoadBalancerAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_load_balancer_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_rule` with what `handler` returns for its input.
+    pub fn with_modify_rule<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                ModifyRuleInput,
+            ) -> ::std::result::Result<ModifyRuleOutput, RusotoError<ModifyRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_target_group` with what `handler` returns for its input.
+    pub fn with_modify_target_group<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                ModifyTargetGroupInput,
+            ) -> ::std::result::Result<
+                ModifyTargetGroupOutput,
+                RusotoError<ModifyTargetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_target_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_target_group_attributes` with what `handler` returns for its input.
+    pub fn with_modify_target_group_attributes<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                ModifyTargetGroupAttributesInput,
+            ) -> ::std::result::Result<
+                ModifyTargetGroupAttributesOutput,
+                RusotoError<ModifyTargetGroupAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_target_group_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_targets` with what `handler` returns for its input.
+    pub fn with_register_targets<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                RegisterTargetsInput,
+            )
+                -> ::std::result::Result<RegisterTargetsOutput, RusotoError<RegisterTargetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_listener_certificates` with what `handler` returns for its input.
+    pub fn with_remove_listener_certificates<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                RemoveListenerCertificatesInput,
+            ) -> ::std::result::Result<
+                RemoveListenerCertificatesOutput,
+                RusotoError<RemoveListenerCertificatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_listener_certificates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags` with what `handler` returns for its input.
+    pub fn with_remove_tags<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                RemoveTagsInput,
+            ) -> ::std::result::Result<RemoveTagsOutput, RusotoError<RemoveTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_ip_address_type` with what `handler` returns for its input.
+    pub fn with_set_ip_address_type<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                SetIpAddressTypeInput,
+            )
+                -> ::std::result::Result<SetIpAddressTypeOutput, RusotoError<SetIpAddressTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_ip_address_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_rule_priorities` with what `handler` returns for its input.
+    pub fn with_set_rule_priorities<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                SetRulePrioritiesInput,
+            ) -> ::std::result::Result<
+                SetRulePrioritiesOutput,
+                RusotoError<SetRulePrioritiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_rule_priorities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_security_groups` with what `handler` returns for its input.
+    pub fn with_set_security_groups<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                SetSecurityGroupsInput,
+            ) -> ::std::result::Result<
+                SetSecurityGroupsOutput,
+                RusotoError<SetSecurityGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_security_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_subnets` with what `handler` returns for its input.
+    pub fn with_set_subnets<F>(mut self, handler: F) -> MockElb
+    where
+        F: Fn(
+                SetSubnetsInput,
+            ) -> ::std::result::Result<SetSubnetsOutput, RusotoError<SetSubnetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_subnets = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Elb for MockElb {
+    fn add_listener_certificates(
+        &self,
+        input: AddListenerCertificatesInput,
+    ) -> RusotoFuture<AddListenerCertificatesOutput, AddListenerCertificatesError> {
+        match self.add_listener_certificates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for add_listener_certificates"),
+        }
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        match self.add_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for add_tags"),
+        }
+    }
+    fn create_listener(
+        &self,
+        input: CreateListenerInput,
+    ) -> RusotoFuture<CreateListenerOutput, CreateListenerError> {
+        match self.create_listener {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for create_listener"),
+        }
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerInput,
+    ) -> RusotoFuture<CreateLoadBalancerOutput, CreateLoadBalancerError> {
+        match self.create_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for create_load_balancer"),
+        }
+    }
+    fn create_rule(
+        &self,
+        input: CreateRuleInput,
+    ) -> RusotoFuture<CreateRuleOutput, CreateRuleError> {
+        match self.create_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for create_rule"),
+        }
+    }
+    fn create_target_group(
+        &self,
+        input: CreateTargetGroupInput,
+    ) -> RusotoFuture<CreateTargetGroupOutput, CreateTargetGroupError> {
+        match self.create_target_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for create_target_group"),
+        }
+    }
+    fn delete_listener(
+        &self,
+        input: DeleteListenerInput,
+    ) -> RusotoFuture<DeleteListenerOutput, DeleteListenerError> {
+        match self.delete_listener {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for delete_listener"),
+        }
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerOutput, DeleteLoadBalancerError> {
+        match self.delete_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for delete_load_balancer"),
+        }
+    }
+    fn delete_rule(
+        &self,
+        input: DeleteRuleInput,
+    ) -> RusotoFuture<DeleteRuleOutput, DeleteRuleError> {
+        match self.delete_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for delete_rule"),
+        }
+    }
+    fn delete_target_group(
+        &self,
+        input: DeleteTargetGroupInput,
+    ) -> RusotoFuture<DeleteTargetGroupOutput, DeleteTargetGroupError> {
+        match self.delete_target_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for delete_target_group"),
+        }
+    }
+    fn deregister_targets(
+        &self,
+        input: DeregisterTargetsInput,
+    ) -> RusotoFuture<DeregisterTargetsOutput, DeregisterTargetsError> {
+        match self.deregister_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for deregister_targets"),
+        }
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        match self.describe_account_limits {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_account_limits"),
+        }
+    }
+    fn describe_listener_certificates(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> RusotoFuture<DescribeListenerCertificatesOutput, DescribeListenerCertificatesError> {
+        match self.describe_listener_certificates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_listener_certificates"),
+        }
+    }
+    fn describe_listeners(
+        &self,
+        input: DescribeListenersInput,
+    ) -> RusotoFuture<DescribeListenersOutput, DescribeListenersError> {
+        match self.describe_listeners {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_listeners"),
+        }
+    }
+    fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
+        match self.describe_load_balancer_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_load_balancer_attributes"),
+        }
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> RusotoFuture<DescribeLoadBalancersOutput, DescribeLoadBalancersError> {
+        match self.describe_load_balancers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_load_balancers"),
+        }
+    }
+    fn describe_rules(
+        &self,
+        input: DescribeRulesInput,
+    ) -> RusotoFuture<DescribeRulesOutput, DescribeRulesError> {
+        match self.describe_rules {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_rules"),
+        }
+    }
+    fn describe_ssl_policies(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> RusotoFuture<DescribeSSLPoliciesOutput, DescribeSSLPoliciesError> {
+        match self.describe_ssl_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_ssl_policies"),
+        }
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        match self.describe_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_tags"),
+        }
+    }
+    fn describe_target_group_attributes(
+        &self,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> RusotoFuture<DescribeTargetGroupAttributesOutput, DescribeTargetGroupAttributesError> {
+        match self.describe_target_group_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_target_group_attributes"),
+        }
+    }
+    fn describe_target_groups(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> RusotoFuture<DescribeTargetGroupsOutput, DescribeTargetGroupsError> {
+        match self.describe_target_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_target_groups"),
+        }
+    }
+    fn describe_target_health(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> RusotoFuture<DescribeTargetHealthOutput, DescribeTargetHealthError> {
+        match self.describe_target_health {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for describe_target_health"),
+        }
+    }
+    fn modify_listener(
+        &self,
+        input: ModifyListenerInput,
+    ) -> RusotoFuture<ModifyListenerOutput, ModifyListenerError> {
+        match self.modify_listener {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for modify_listener"),
+        }
+    }
+    fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        match self.modify_load_balancer_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for modify_load_balancer_attributes"),
+        }
+    }
+    fn modify_rule(
+        &self,
+        input: ModifyRuleInput,
+    ) -> RusotoFuture<ModifyRuleOutput, ModifyRuleError> {
+        match self.modify_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for modify_rule"),
+        }
+    }
+    fn modify_target_group(
+        &self,
+        input: ModifyTargetGroupInput,
+    ) -> RusotoFuture<ModifyTargetGroupOutput, ModifyTargetGroupError> {
+        match self.modify_target_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for modify_target_group"),
+        }
+    }
+    fn modify_target_group_attributes(
+        &self,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> RusotoFuture<ModifyTargetGroupAttributesOutput, ModifyTargetGroupAttributesError> {
+        match self.modify_target_group_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for modify_target_group_attributes"),
+        }
+    }
+    fn register_targets(
+        &self,
+        input: RegisterTargetsInput,
+    ) -> RusotoFuture<RegisterTargetsOutput, RegisterTargetsError> {
+        match self.register_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for register_targets"),
+        }
+    }
+    fn remove_listener_certificates(
+        &self,
+        input: RemoveListenerCertificatesInput,
+    ) -> RusotoFuture<RemoveListenerCertificatesOutput, RemoveListenerCertificatesError> {
+        match self.remove_listener_certificates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for remove_listener_certificates"),
+        }
+    }
     fn remove_tags(
         &self,
         input: RemoveTagsInput,
     ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RemoveTags");
-        params.put("Version", "2015-12-01");
-        RemoveTagsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RemoveTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RemoveTagsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        RemoveTagsOutputDeserializer::deserialize("RemoveTagsResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.remove_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for remove_tags"),
+        }
     }
-
-    /// <p>Sets the type of IP addresses used by the subnets of the specified Application Load Balancer or Network Load Balancer.</p>
     fn set_ip_address_type(
         &self,
         input: SetIpAddressTypeInput,
     ) -> RusotoFuture<SetIpAddressTypeOutput, SetIpAddressTypeError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetIpAddressType");
-        params.put("Version", "2015-12-01");
-        SetIpAddressTypeInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetIpAddressTypeError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetIpAddressTypeOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetIpAddressTypeOutputDeserializer::deserialize(
-                        "SetIpAddressTypeResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.set_ip_address_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for set_ip_address_type"),
+        }
     }
-
-    /// <p>Sets the priorities of the specified rules.</p> <p>You can reorder the rules as long as there are no priority conflicts in the new order. Any existing rules that you do not specify retain their current priority.</p>
     fn set_rule_priorities(
         &self,
         input: SetRulePrioritiesInput,
     ) -> RusotoFuture<SetRulePrioritiesOutput, SetRulePrioritiesError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetRulePriorities");
-        params.put("Version", "2015-12-01");
-        SetRulePrioritiesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetRulePrioritiesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetRulePrioritiesOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetRulePrioritiesOutputDeserializer::deserialize(
-                        "SetRulePrioritiesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.set_rule_priorities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for set_rule_priorities"),
+        }
     }
-
-    /// <p>Associates the specified security groups with the specified Application Load Balancer. The specified security groups override the previously associated security groups.</p> <p>You can't specify a security group for a Network Load Balancer.</p>
     fn set_security_groups(
         &self,
         input: SetSecurityGroupsInput,
     ) -> RusotoFuture<SetSecurityGroupsOutput, SetSecurityGroupsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetSecurityGroups");
-        params.put("Version", "2015-12-01");
-        SetSecurityGroupsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetSecurityGroupsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetSecurityGroupsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetSecurityGroupsOutputDeserializer::deserialize(
-                        "SetSecurityGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.set_security_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for set_security_groups"),
+        }
     }
-
-    /// <p>Enables the Availability Zone for the specified public subnets for the specified Application Load Balancer. The specified subnets replace the previously enabled subnets.</p> <p>You can't change the subnets for a Network Load Balancer.</p>
     fn set_subnets(
         &self,
         input: SetSubnetsInput,
     ) -> RusotoFuture<SetSubnetsOutput, SetSubnetsError> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetSubnets");
-        params.put("Version", "2015-12-01");
-        SetSubnetsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetSubnetsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetSubnetsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        SetSubnetsOutputDeserializer::deserialize("SetSubnetsResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.set_subnets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockElb has no handler for set_subnets"),
+        }
     }
 }
 
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddInstanceFleetInput {
     /// <p>The unique identifier of the cluster.</p>
     #[serde(rename = "ClusterId")]
@@ -33,8 +35,26 @@ pub struct AddInstanceFleetInput {
     pub instance_fleet: InstanceFleetConfig,
 }
 
+impl Validate for AddInstanceFleetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_id"),
+            self.cluster_id.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "cluster_id"),
+            &self.cluster_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        self.instance_fleet
+            .validate_at(&field_path(path, "instance_fleet"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddInstanceFleetOutput {
     /// <p>The unique identifier of the cluster.</p>
     #[serde(rename = "ClusterId")]
@@ -48,6 +68,7 @@ pub struct AddInstanceFleetOutput {
 
 /// <p>Input to an AddInstanceGroups call.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddInstanceGroupsInput {
     /// <p>Instance groups to add.</p>
     #[serde(rename = "InstanceGroups")]
@@ -57,9 +78,29 @@ pub struct AddInstanceGroupsInput {
     pub job_flow_id: String,
 }
 
+impl Validate for AddInstanceGroupsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.instance_groups.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "instance_groups"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "job_flow_id"),
+            self.job_flow_id.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_flow_id"),
+            &self.job_flow_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Output from an AddInstanceGroups call.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddInstanceGroupsOutput {
     /// <p>Instance group IDs of the newly created instance groups.</p>
     #[serde(rename = "InstanceGroupIds")]
@@ -73,6 +114,7 @@ pub struct AddInstanceGroupsOutput {
 
 /// <p> The input argument to the <a>AddJobFlowSteps</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddJobFlowStepsInput {
     /// <p>A string that uniquely identifies the job flow. This identifier is returned by <a>RunJobFlow</a> and can also be obtained from <a>ListClusters</a>. </p>
     #[serde(rename = "JobFlowId")]
@@ -82,9 +124,29 @@ pub struct AddJobFlowStepsInput {
     pub steps: Vec<StepConfig>,
 }
 
+impl Validate for AddJobFlowStepsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_flow_id"),
+            self.job_flow_id.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_flow_id"),
+            &self.job_flow_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        for (i, item) in self.steps.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "steps"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p> The output for the <a>AddJobFlowSteps</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddJobFlowStepsOutput {
     /// <p>The identifiers of the list of steps added to the job flow.</p>
     #[serde(rename = "StepIds")]
@@ -94,6 +156,7 @@ pub struct AddJobFlowStepsOutput {
 
 /// <p>This input identifies a cluster and a list of tags to attach.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsInput {
     /// <p>The Amazon EMR resource identifier to which tags will be added. This value must be a cluster identifier.</p>
     #[serde(rename = "ResourceId")]
@@ -105,7 +168,7 @@ pub struct AddTagsInput {
 
 /// <p>This output indicates the result of adding tags to a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsOutput {}
 
 /// <p>With Amazon EMR release version 4.0 and later, the only accepted parameter is the application name. To pass arguments to applications, you use configuration classifications specified using configuration JSON objects. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ReleaseGuide/emr-configure-apps.html">Configuring Applications</a>.</p> <p>With earlier Amazon EMR releases, the application is any Amazon or third-party software that you can add to the cluster. This structure contains a list of strings that indicates the software to use with the cluster and accepts a user argument list. Amazon EMR accepts and forwards the argument list to the corresponding installation script as bootstrap action argument.</p>
@@ -131,6 +194,7 @@ pub struct Application {
 
 /// <p>An automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. An automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric. See <a>PutAutoScalingPolicy</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutoScalingPolicy {
     /// <p>The upper and lower EC2 instance limits for an automatic scaling policy. Automatic scaling activity will not cause an instance group to grow above or below these limits.</p>
     #[serde(rename = "Constraints")]
@@ -142,7 +206,7 @@ pub struct AutoScalingPolicy {
 
 /// <p>An automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric. See <a>PutAutoScalingPolicy</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AutoScalingPolicyDescription {
     /// <p>The upper and lower EC2 instance limits for an automatic scaling policy. Automatic scaling activity will not cause an instance group to grow above or below these limits.</p>
     #[serde(rename = "Constraints")]
@@ -160,7 +224,7 @@ pub struct AutoScalingPolicyDescription {
 
 /// <p>The reason for an <a>AutoScalingPolicyStatus</a> change.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AutoScalingPolicyStateChangeReason {
     /// <p>The code indicating the reason for the change in status.<code>USER_REQUEST</code> indicates that the scaling policy status was changed by a user. <code>PROVISION_FAILURE</code> indicates that the status change was because the policy failed to provision. <code>CLEANUP_FAILURE</code> indicates an error.</p>
     #[serde(rename = "Code")]
@@ -174,7 +238,7 @@ pub struct AutoScalingPolicyStateChangeReason {
 
 /// <p>The status of an automatic scaling policy. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AutoScalingPolicyStatus {
     /// <p>Indicates the status of the automatic scaling policy.</p>
     #[serde(rename = "State")]
@@ -198,9 +262,24 @@ pub struct BlockPublicAccessConfiguration {
     pub permitted_public_security_group_rule_ranges: Option<Vec<PortRange>>,
 }
 
+impl Validate for BlockPublicAccessConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.permitted_public_security_group_rule_ranges {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "permitted_public_security_group_rule_ranges"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Properties that describe the AWS principal that created the <code>BlockPublicAccessConfiguration</code> using the <code>PutBlockPublicAccessConfiguration</code> action as well as the date and time that the configuration was created. Each time a configuration for block public access is updated, Amazon EMR updates this metadata.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BlockPublicAccessConfigurationMetadata {
     /// <p>The Amazon Resource Name that created or last modified the configuration.</p>
     #[serde(rename = "CreatedByArn")]
@@ -221,9 +300,27 @@ pub struct BootstrapActionConfig {
     pub script_bootstrap_action: ScriptBootstrapActionConfig,
 }
 
+impl Validate for BootstrapActionConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        self.script_bootstrap_action
+            .validate_at(&field_path(path, "script_bootstrap_action"), errors);
+    }
+}
+
 /// <p>Reports the configuration of a bootstrap action in a cluster (job flow).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BootstrapActionDetail {
     /// <p>A description of the bootstrap action.</p>
     #[serde(rename = "BootstrapActionConfig")]
@@ -233,7 +330,7 @@ pub struct BootstrapActionDetail {
 
 /// <p>Specification of the status of a CancelSteps request. Available only in Amazon EMR version 4.8.0 and later, excluding version 5.0.0.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelStepsInfo {
     /// <p>The reason for the failure if the CancelSteps request fails.</p>
     #[serde(rename = "Reason")]
@@ -251,6 +348,7 @@ pub struct CancelStepsInfo {
 
 /// <p>The input argument to the <a>CancelSteps</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelStepsInput {
     /// <p>The <code>ClusterID</code> for which specified steps will be canceled. Use <a>RunJobFlow</a> and <a>ListClusters</a> to get ClusterIDs. </p>
     #[serde(rename = "ClusterId")]
@@ -262,9 +360,38 @@ pub struct CancelStepsInput {
     pub step_ids: Option<Vec<String>>,
 }
 
+impl Validate for CancelStepsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cluster_id {
+            errors.check_length(
+                &field_path(path, "cluster_id"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "cluster_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.step_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "step_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// <p> The output for the <a>CancelSteps</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelStepsOutput {
     /// <p>A list of <a>CancelStepsInfo</a>, which shows the status of specified cancel requests for each <code>StepID</code> specified.</p>
     #[serde(rename = "CancelStepsInfoList")]
@@ -311,7 +438,7 @@ pub struct CloudWatchAlarmDefinition {
 
 /// <p>The detailed description of the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Cluster {
     /// <p>The applications installed on this cluster.</p>
     #[serde(rename = "Applications")]
@@ -417,7 +544,7 @@ pub struct Cluster {
 
 /// <p>The reason that the cluster changed to its current state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClusterStateChangeReason {
     /// <p>The programmatic code for the state change reason.</p>
     #[serde(rename = "Code")]
@@ -431,7 +558,7 @@ pub struct ClusterStateChangeReason {
 
 /// <p>The detailed status of the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClusterStatus {
     /// <p>The current state of the cluster.</p>
     #[serde(rename = "State")]
@@ -449,7 +576,7 @@ pub struct ClusterStatus {
 
 /// <p>The summary description of the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClusterSummary {
     /// <p>The unique identifier for the cluster.</p>
     #[serde(rename = "Id")]
@@ -471,7 +598,7 @@ pub struct ClusterSummary {
 
 /// <p>Represents the timeline of the cluster's lifecycle.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClusterTimeline {
     /// <p>The creation date and time of the cluster.</p>
     #[serde(rename = "CreationDateTime")]
@@ -489,7 +616,7 @@ pub struct ClusterTimeline {
 
 /// <p>An entity describing an executable that runs on a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Command {
     /// <p>Arguments for Amazon EMR to pass to the command for execution.</p>
     #[serde(rename = "Args")]
@@ -523,6 +650,7 @@ pub struct Configuration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSecurityConfigurationInput {
     /// <p>The name of the security configuration.</p>
     #[serde(rename = "Name")]
@@ -532,8 +660,24 @@ pub struct CreateSecurityConfigurationInput {
     pub security_configuration: String,
 }
 
+impl Validate for CreateSecurityConfigurationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(10280),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSecurityConfigurationOutput {
     /// <p>The date and time the security configuration was created.</p>
     #[serde(rename = "CreationDateTime")]
@@ -544,18 +688,36 @@ pub struct CreateSecurityConfigurationOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSecurityConfigurationInput {
     /// <p>The name of the security configuration.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteSecurityConfigurationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(10280),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSecurityConfigurationOutput {}
 
 /// <p>This input determines which cluster to describe.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterInput {
     /// <p>The identifier of the cluster to describe.</p>
     #[serde(rename = "ClusterId")]
@@ -564,7 +726,7 @@ pub struct DescribeClusterInput {
 
 /// <p>This output contains the description of the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeClusterOutput {
     /// <p>This output contains the details for the requested cluster.</p>
     #[serde(rename = "Cluster")]
@@ -574,6 +736,7 @@ pub struct DescribeClusterOutput {
 
 /// <p> The input for the <a>DescribeJobFlows</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeJobFlowsInput {
     /// <p>Return only job flows created after this date and time.</p>
     #[serde(rename = "CreatedAfter")]
@@ -593,9 +756,25 @@ pub struct DescribeJobFlowsInput {
     pub job_flow_states: Option<Vec<String>>,
 }
 
+impl Validate for DescribeJobFlowsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.job_flow_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "job_flow_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(10280));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// <p> The output for the <a>DescribeJobFlows</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeJobFlowsOutput {
     /// <p>A list of job flows matching the parameters supplied.</p>
     #[serde(rename = "JobFlows")]
@@ -604,14 +783,31 @@ pub struct DescribeJobFlowsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSecurityConfigurationInput {
     /// <p>The name of the security configuration.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DescribeSecurityConfigurationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(10280),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSecurityConfigurationOutput {
     /// <p>The date and time the security configuration was created</p>
     #[serde(rename = "CreationDateTime")]
@@ -629,6 +825,7 @@ pub struct DescribeSecurityConfigurationOutput {
 
 /// <p>This input determines which step to describe.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStepInput {
     /// <p>The identifier of the cluster with steps to describe.</p>
     #[serde(rename = "ClusterId")]
@@ -640,7 +837,7 @@ pub struct DescribeStepInput {
 
 /// <p>This output contains the description of the cluster step.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStepOutput {
     /// <p>The step details for the requested step identifier.</p>
     #[serde(rename = "Step")]
@@ -650,7 +847,7 @@ pub struct DescribeStepOutput {
 
 /// <p>Configuration of requested EBS block device associated with the instance group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EbsBlockDevice {
     /// <p>The device name that is exposed to the instance, such as /dev/sdh.</p>
     #[serde(rename = "Device")]
@@ -664,6 +861,7 @@ pub struct EbsBlockDevice {
 
 /// <p>Configuration of requested EBS block device associated with the instance group with count of volumes that will be associated to every instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EbsBlockDeviceConfig {
     /// <p>EBS volume specifications such as volume type, IOPS, and size (GiB) that will be requested for the EBS volume attached to an EC2 instance in the cluster.</p>
     #[serde(rename = "VolumeSpecification")]
@@ -676,6 +874,7 @@ pub struct EbsBlockDeviceConfig {
 
 /// <p>The Amazon EBS configuration of a cluster instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EbsConfiguration {
     /// <p>An array of Amazon EBS volume specifications attached to a cluster instance.</p>
     #[serde(rename = "EbsBlockDeviceConfigs")]
@@ -689,7 +888,7 @@ pub struct EbsConfiguration {
 
 /// <p>EBS block device that's attached to an EC2 instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EbsVolume {
     /// <p>The device name that is exposed to the instance, such as /dev/sdh.</p>
     #[serde(rename = "Device")]
@@ -703,7 +902,7 @@ pub struct EbsVolume {
 
 /// <p>Provides information about the EC2 instances in a cluster grouped by category. For example, key name, subnet ID, IAM instance profile, and so on.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Ec2InstanceAttributes {
     /// <p>A list of additional Amazon EC2 security group IDs for the master node.</p>
     #[serde(rename = "AdditionalMasterSecurityGroups")]
@@ -753,7 +952,7 @@ pub struct Ec2InstanceAttributes {
 
 /// <p>The details of the step failure. The service attempts to detect the root cause for many common failures.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailureDetails {
     /// <p>The path to the log file where the step failure root cause was originally recorded.</p>
     #[serde(rename = "LogFile")]
@@ -770,10 +969,11 @@ pub struct FailureDetails {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBlockPublicAccessConfigurationInput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBlockPublicAccessConfigurationOutput {
     /// <p>A configuration for Amazon EMR block public access. The configuration applies to all clusters created in your account for the current Region. The configuration specifies whether block public access is enabled. If block public access is enabled, security groups associated with the cluster cannot have rules that allow inbound traffic from 0.0.0.0/0 or ::/0 on a port, unless the port is specified as an exception using <code>PermittedPublicSecurityGroupRuleRanges</code> in the <code>BlockPublicAccessConfiguration</code>. By default, Port 22 (SSH) is an exception, and public access is allowed on this port. You can change this by updating the block public access configuration to remove the exception.</p>
     #[serde(rename = "BlockPublicAccessConfiguration")]
@@ -803,9 +1003,55 @@ pub struct HadoopJarStepConfig {
     pub properties: Option<Vec<KeyValue>>,
 }
 
+impl Validate for HadoopJarStepConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.args {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "args"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(10280));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        errors.check_length(
+            &field_path(path, "jar"),
+            self.jar.chars().count(),
+            None,
+            Some(10280),
+        );
+        errors.check_pattern(
+            &field_path(path, "jar"),
+            &self.jar,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.main_class {
+            errors.check_length(
+                &field_path(path, "main_class"),
+                value.chars().count(),
+                None,
+                Some(10280),
+            );
+            errors.check_pattern(
+                &field_path(path, "main_class"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.properties {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "properties"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>A cluster step consisting of a JAR file whose main function will be executed. The main function submits a job for Hadoop to execute and waits for the job to finish or fail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HadoopStepConfig {
     /// <p>The list of command line arguments to pass to the JAR file's main function for execution.</p>
     #[serde(rename = "Args")]
@@ -827,7 +1073,7 @@ pub struct HadoopStepConfig {
 
 /// <p>Represents an EC2 instance provisioned as part of cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Instance {
     /// <p>The list of EBS volumes that are attached to this instance.</p>
     #[serde(rename = "EbsVolumes")]
@@ -881,7 +1127,7 @@ pub struct Instance {
 
 /// <p><p>Describes an instance fleet, which is a group of EC2 instances that host a particular node type (master, core, or task) in an Amazon EMR cluster. Instance fleets can consist of a mix of instance types and On-Demand and Spot instances, which are provisioned to meet a defined target capacity. </p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceFleet {
     /// <p>The unique identifier of the instance fleet.</p>
     #[serde(rename = "Id")]
@@ -927,6 +1173,7 @@ pub struct InstanceFleet {
 
 /// <p><p>The configuration that defines an instance fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceFleetConfig {
     /// <p>The node type that the instance fleet hosts. Valid values are MASTER,CORE,and TASK.</p>
     #[serde(rename = "InstanceFleetType")]
@@ -953,8 +1200,33 @@ pub struct InstanceFleetConfig {
     pub target_spot_capacity: Option<i64>,
 }
 
+impl Validate for InstanceFleetConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.instance_type_configs {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_type_configs"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p><p>Configuration parameters for an instance fleet modification request.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceFleetModifyConfig {
     /// <p>A unique identifier for the instance fleet.</p>
     #[serde(rename = "InstanceFleetId")]
@@ -979,7 +1251,7 @@ pub struct InstanceFleetProvisioningSpecifications {
 
 /// <p><p>Provides status change reason details for the instance fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceFleetStateChangeReason {
     /// <p>A code corresponding to the reason the state change occurred.</p>
     #[serde(rename = "Code")]
@@ -993,7 +1265,7 @@ pub struct InstanceFleetStateChangeReason {
 
 /// <p><p>The status of the instance fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceFleetStatus {
     /// <p><p>A code representing the instance fleet status.</p> <ul> <li> <p> <code>PROVISIONING</code>—The instance fleet is provisioning EC2 resources and is not yet ready to run jobs.</p> </li> <li> <p> <code>BOOTSTRAPPING</code>—EC2 instances and other resources have been provisioned and the bootstrap actions specified for the instances are underway.</p> </li> <li> <p> <code>RUNNING</code>—EC2 instances and other resources are running. They are either executing jobs or waiting to execute jobs.</p> </li> <li> <p> <code>RESIZING</code>—A resize operation is underway. EC2 instances are either being added or removed.</p> </li> <li> <p> <code>SUSPENDED</code>—A resize operation could not complete. Existing EC2 instances are running, but instances can&#39;t be added or removed.</p> </li> <li> <p> <code>TERMINATING</code>—The instance fleet is terminating EC2 instances.</p> </li> <li> <p> <code>TERMINATED</code>—The instance fleet is no longer active, and all EC2 instances have been terminated.</p> </li> </ul></p>
     #[serde(rename = "State")]
@@ -1011,7 +1283,7 @@ pub struct InstanceFleetStatus {
 
 /// <p><p>Provides historical timestamps for the instance fleet, including the time of creation, the time it became ready to run jobs, and the time of termination.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceFleetTimeline {
     /// <p>The time and date the instance fleet was created.</p>
     #[serde(rename = "CreationDateTime")]
@@ -1029,7 +1301,7 @@ pub struct InstanceFleetTimeline {
 
 /// <p>This entity represents an instance group, which is a group of instances that have common purpose. For example, CORE instance group is used for HDFS.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceGroup {
     /// <p>An automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric. See PutAutoScalingPolicy.</p>
     #[serde(rename = "AutoScalingPolicy")]
@@ -1103,6 +1375,7 @@ pub struct InstanceGroup {
 
 /// <p>Configuration defining a new instance group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceGroupConfig {
     /// <p>An automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric. See <a>PutAutoScalingPolicy</a>.</p>
     #[serde(rename = "AutoScalingPolicy")]
@@ -1139,9 +1412,51 @@ pub struct InstanceGroupConfig {
     pub name: Option<String>,
 }
 
+impl Validate for InstanceGroupConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bid_price {
+            errors.check_length(
+                &field_path(path, "bid_price"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "bid_price"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "instance_type"),
+            self.instance_type.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_type"),
+            &self.instance_type,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Detailed information about an instance group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceGroupDetail {
     /// <p>The maximum Spot price your are willing to pay for EC2 instances.</p> <p>An optional, nullable field that applies if the <code>MarketType</code> for the instance group is specified as <code>SPOT</code>. Specified in USD. If the value is NULL and <code>SPOT</code> is specified, the maximum Spot price is set equal to the On-Demand price.</p>
     #[serde(rename = "BidPrice")]
@@ -1196,6 +1511,7 @@ pub struct InstanceGroupDetail {
 
 /// <p>Modify the size or configurations of an instance group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceGroupModifyConfig {
     /// <p>A list of new or modified configurations to apply for an instance group.</p>
     #[serde(rename = "Configurations")]
@@ -1218,9 +1534,25 @@ pub struct InstanceGroupModifyConfig {
     pub shrink_policy: Option<ShrinkPolicy>,
 }
 
+impl Validate for InstanceGroupModifyConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_group_id"),
+            self.instance_group_id.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_group_id"),
+            &self.instance_group_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>The status change reason details for the instance group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceGroupStateChangeReason {
     /// <p>The programmable code for the state change reason.</p>
     #[serde(rename = "Code")]
@@ -1234,7 +1566,7 @@ pub struct InstanceGroupStateChangeReason {
 
 /// <p>The details of the instance group status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceGroupStatus {
     /// <p>The current state of the instance group.</p>
     #[serde(rename = "State")]
@@ -1252,7 +1584,7 @@ pub struct InstanceGroupStatus {
 
 /// <p>The timeline of the instance group lifecycle.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceGroupTimeline {
     /// <p>The creation date and time of the instance group.</p>
     #[serde(rename = "CreationDateTime")]
@@ -1287,7 +1619,7 @@ pub struct InstanceResizePolicy {
 
 /// <p>The details of the status change reason for the instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceStateChangeReason {
     /// <p>The programmable code for the state change reason.</p>
     #[serde(rename = "Code")]
@@ -1301,7 +1633,7 @@ pub struct InstanceStateChangeReason {
 
 /// <p>The instance status details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceStatus {
     /// <p>The current state of the instance.</p>
     #[serde(rename = "State")]
@@ -1319,7 +1651,7 @@ pub struct InstanceStatus {
 
 /// <p>The timeline of the instance lifecycle.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceTimeline {
     /// <p>The creation date and time of the instance.</p>
     #[serde(rename = "CreationDateTime")]
@@ -1337,6 +1669,7 @@ pub struct InstanceTimeline {
 
 /// <p><p>An instance type configuration for each instance type in an instance fleet, which determines the EC2 instances Amazon EMR attempts to provision to fulfill On-Demand and Spot target capacities. There can be a maximum of 5 instance type configurations in a fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceTypeConfig {
     /// <p>The bid price for each EC2 Spot instance type as defined by <code>InstanceType</code>. Expressed in USD. If neither <code>BidPrice</code> nor <code>BidPriceAsPercentageOfOnDemandPrice</code> is provided, <code>BidPriceAsPercentageOfOnDemandPrice</code> defaults to 100%. </p>
     #[serde(rename = "BidPrice")]
@@ -1363,9 +1696,38 @@ pub struct InstanceTypeConfig {
     pub weighted_capacity: Option<i64>,
 }
 
+impl Validate for InstanceTypeConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bid_price {
+            errors.check_length(
+                &field_path(path, "bid_price"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "bid_price"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "instance_type"),
+            self.instance_type.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_type"),
+            &self.instance_type,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p><p>The configuration specification for each instance type in an instance fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceTypeSpecification {
     /// <p>The bid price for each EC2 Spot instance type as defined by <code>InstanceType</code>. Expressed in USD.</p>
     #[serde(rename = "BidPrice")]
@@ -1399,7 +1761,7 @@ pub struct InstanceTypeSpecification {
 
 /// <p>A description of a cluster (job flow).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobFlowDetail {
     /// <p>Applies only to Amazon EMR AMI versions 3.x and 2.x. For Amazon EMR releases 4.0 and later, <code>ReleaseLabel</code> is used. To specify a custom AMI, use <code>CustomAmiID</code>.</p>
     #[serde(rename = "AmiVersion")]
@@ -1457,7 +1819,7 @@ pub struct JobFlowDetail {
 
 /// <p>Describes the status of the cluster (job flow).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobFlowExecutionStatusDetail {
     /// <p>The creation date and time of the job flow.</p>
     #[serde(rename = "CreationDateTime")]
@@ -1485,6 +1847,7 @@ pub struct JobFlowExecutionStatusDetail {
 
 /// <p>A description of the Amazon EC2 instance on which the cluster (job flow) runs. A valid JobFlowInstancesConfig must contain either InstanceGroups or InstanceFleets, which is the recommended configuration. They cannot be used together. You may also have MasterInstanceType, SlaveInstanceType, and InstanceCount (all three must be present), but we don't recommend this configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct JobFlowInstancesConfig {
     /// <p>A list of additional Amazon EC2 security group IDs for the master node.</p>
     #[serde(rename = "AdditionalMasterSecurityGroups")]
@@ -1556,9 +1919,174 @@ pub struct JobFlowInstancesConfig {
     pub termination_protected: Option<bool>,
 }
 
+impl Validate for JobFlowInstancesConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.additional_master_security_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "additional_master_security_groups"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.additional_slave_security_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "additional_slave_security_groups"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.ec_2_key_name {
+            errors.check_length(
+                &field_path(path, "ec_2_key_name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "ec_2_key_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.ec_2_subnet_id {
+            errors.check_length(
+                &field_path(path, "ec_2_subnet_id"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "ec_2_subnet_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.ec_2_subnet_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "ec_2_subnet_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.emr_managed_master_security_group {
+            errors.check_length(
+                &field_path(path, "emr_managed_master_security_group"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "emr_managed_master_security_group"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.emr_managed_slave_security_group {
+            errors.check_length(
+                &field_path(path, "emr_managed_slave_security_group"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "emr_managed_slave_security_group"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.hadoop_version {
+            errors.check_length(
+                &field_path(path, "hadoop_version"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "hadoop_version"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.instance_fleets {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_fleets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.instance_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_groups"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.master_instance_type {
+            errors.check_length(
+                &field_path(path, "master_instance_type"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "master_instance_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.placement {
+            value.validate_at(&field_path(path, "placement"), errors);
+        }
+        if let Some(ref value) = self.service_access_security_group {
+            errors.check_length(
+                &field_path(path, "service_access_security_group"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "service_access_security_group"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.slave_instance_type {
+            errors.check_length(
+                &field_path(path, "slave_instance_type"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "slave_instance_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Specify the type of Amazon EC2 instances that the cluster (job flow) runs on.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobFlowInstancesDetail {
     /// <p>The name of an Amazon EC2 key pair that can be used to ssh to the master node.</p>
     #[serde(rename = "Ec2KeyName")]
@@ -1634,6 +2162,72 @@ pub struct KerberosAttributes {
     pub realm: String,
 }
 
+impl Validate for KerberosAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ad_domain_join_password {
+            errors.check_length(
+                &field_path(path, "ad_domain_join_password"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "ad_domain_join_password"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.ad_domain_join_user {
+            errors.check_length(
+                &field_path(path, "ad_domain_join_user"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "ad_domain_join_user"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.cross_realm_trust_principal_password {
+            errors.check_length(
+                &field_path(path, "cross_realm_trust_principal_password"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "cross_realm_trust_principal_password"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "kdc_admin_password"),
+            self.kdc_admin_password.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "kdc_admin_password"),
+            &self.kdc_admin_password,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "realm"),
+            self.realm.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "realm"),
+            &self.realm,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>A key value pair.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyValue {
@@ -1647,8 +2241,40 @@ pub struct KeyValue {
     pub value: Option<String>,
 }
 
+impl Validate for KeyValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                None,
+                Some(10280),
+            );
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(10280),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>This input determines which bootstrap actions to retrieve.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBootstrapActionsInput {
     /// <p>The cluster identifier for the bootstrap actions to list.</p>
     #[serde(rename = "ClusterId")]
@@ -1661,7 +2287,7 @@ pub struct ListBootstrapActionsInput {
 
 /// <p>This output contains the bootstrap actions detail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBootstrapActionsOutput {
     /// <p>The bootstrap actions associated with the cluster.</p>
     #[serde(rename = "BootstrapActions")]
@@ -1675,6 +2301,7 @@ pub struct ListBootstrapActionsOutput {
 
 /// <p>This input determines how the ListClusters action filters the list of clusters that it returns.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListClustersInput {
     /// <p>The cluster state filters to apply when listing clusters.</p>
     #[serde(rename = "ClusterStates")]
@@ -1696,7 +2323,7 @@ pub struct ListClustersInput {
 
 /// <p>This contains a ClusterSummaryList with the cluster details; for example, the cluster IDs, names, and status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListClustersOutput {
     /// <p>The list of clusters for the account based on the given filters.</p>
     #[serde(rename = "Clusters")]
@@ -1709,6 +2336,7 @@ pub struct ListClustersOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInstanceFleetsInput {
     /// <p>The unique identifier of the cluster.</p>
     #[serde(rename = "ClusterId")]
@@ -1720,7 +2348,7 @@ pub struct ListInstanceFleetsInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListInstanceFleetsOutput {
     /// <p>The list of instance fleets for the cluster and given filters.</p>
     #[serde(rename = "InstanceFleets")]
@@ -1734,6 +2362,7 @@ pub struct ListInstanceFleetsOutput {
 
 /// <p>This input determines which instance groups to retrieve.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInstanceGroupsInput {
     /// <p>The identifier of the cluster for which to list the instance groups.</p>
     #[serde(rename = "ClusterId")]
@@ -1746,7 +2375,7 @@ pub struct ListInstanceGroupsInput {
 
 /// <p>This input determines which instance groups to retrieve.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListInstanceGroupsOutput {
     /// <p>The list of instance groups for the cluster and given filters.</p>
     #[serde(rename = "InstanceGroups")]
@@ -1760,6 +2389,7 @@ pub struct ListInstanceGroupsOutput {
 
 /// <p>This input determines which instances to list.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInstancesInput {
     /// <p>The identifier of the cluster for which to list the instances.</p>
     #[serde(rename = "ClusterId")]
@@ -1792,7 +2422,7 @@ pub struct ListInstancesInput {
 
 /// <p>This output contains the list of instances.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListInstancesOutput {
     /// <p>The list of instances for the cluster and given filters.</p>
     #[serde(rename = "Instances")]
@@ -1805,6 +2435,7 @@ pub struct ListInstancesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSecurityConfigurationsInput {
     /// <p>The pagination token that indicates the set of results to retrieve.</p>
     #[serde(rename = "Marker")]
@@ -1813,7 +2444,7 @@ pub struct ListSecurityConfigurationsInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSecurityConfigurationsOutput {
     /// <p>A pagination token that indicates the next set of results to retrieve. Include the marker in the next ListSecurityConfiguration call to retrieve the next page of results, if required.</p>
     #[serde(rename = "Marker")]
@@ -1827,6 +2458,7 @@ pub struct ListSecurityConfigurationsOutput {
 
 /// <p>This input determines which steps to list.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStepsInput {
     /// <p>The identifier of the cluster for which to list the steps.</p>
     #[serde(rename = "ClusterId")]
@@ -1845,9 +2477,25 @@ pub struct ListStepsInput {
     pub step_states: Option<Vec<String>>,
 }
 
+impl Validate for ListStepsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.step_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "step_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(10280));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// <p>This output contains the list of steps returned in reverse order. This means that the last step is the first element in the list.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListStepsOutput {
     /// <p>The pagination token that indicates the next set of results to retrieve.</p>
     #[serde(rename = "Marker")]
@@ -1873,6 +2521,7 @@ pub struct MetricDimension {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyInstanceFleetInput {
     /// <p>The unique identifier of the cluster.</p>
     #[serde(rename = "ClusterId")]
@@ -1884,6 +2533,7 @@ pub struct ModifyInstanceFleetInput {
 
 /// <p>Change the size of some instance groups.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyInstanceGroupsInput {
     /// <p>The ID of the cluster to which the instance group belongs.</p>
     #[serde(rename = "ClusterId")]
@@ -1895,6 +2545,17 @@ pub struct ModifyInstanceGroupsInput {
     pub instance_groups: Option<Vec<InstanceGroupModifyConfig>>,
 }
 
+impl Validate for ModifyInstanceGroupsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.instance_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_groups"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>The Amazon EC2 Availability Zone configuration of the cluster (job flow).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlacementType {
@@ -1908,6 +2569,35 @@ pub struct PlacementType {
     pub availability_zones: Option<Vec<String>>,
 }
 
+impl Validate for PlacementType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.availability_zone {
+            errors.check_length(
+                &field_path(path, "availability_zone"),
+                value.chars().count(),
+                None,
+                Some(10280),
+            );
+            errors.check_pattern(
+                &field_path(path, "availability_zone"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.availability_zones {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "availability_zones"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// <p>A list of port ranges that are permitted to allow inbound traffic from all public IP addresses. To specify a single port, use the same value for <code>MinRange</code> and <code>MaxRange</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PortRange {
@@ -1920,7 +2610,22 @@ pub struct PortRange {
     pub min_range: i64,
 }
 
+impl Validate for PortRange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_range {
+            errors.check_range(&field_path(path, "max_range"), *value, None, Some(65535));
+        }
+        errors.check_range(
+            &field_path(path, "min_range"),
+            self.min_range,
+            None,
+            Some(65535),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutAutoScalingPolicyInput {
     /// <p>Specifies the definition of the automatic scaling policy.</p>
     #[serde(rename = "AutoScalingPolicy")]
@@ -1934,7 +2639,7 @@ pub struct PutAutoScalingPolicyInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutAutoScalingPolicyOutput {
     /// <p>The automatic scaling policy definition.</p>
     #[serde(rename = "AutoScalingPolicy")]
@@ -1951,17 +2656,28 @@ pub struct PutAutoScalingPolicyOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBlockPublicAccessConfigurationInput {
     /// <p>A configuration for Amazon EMR block public access. The configuration applies to all clusters created in your account for the current Region. The configuration specifies whether block public access is enabled. If block public access is enabled, security groups associated with the cluster cannot have rules that allow inbound traffic from 0.0.0.0/0 or ::/0 on a port, unless the port is specified as an exception using <code>PermittedPublicSecurityGroupRuleRanges</code> in the <code>BlockPublicAccessConfiguration</code>. By default, Port 22 (SSH) is an exception, and public access is allowed on this port. You can change this by updating <code>BlockPublicSecurityGroupRules</code> to remove the exception.</p>
     #[serde(rename = "BlockPublicAccessConfiguration")]
     pub block_public_access_configuration: BlockPublicAccessConfiguration,
 }
 
+impl Validate for PutBlockPublicAccessConfigurationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.block_public_access_configuration.validate_at(
+            &field_path(path, "block_public_access_configuration"),
+            errors,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutBlockPublicAccessConfigurationOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveAutoScalingPolicyInput {
     /// <p>Specifies the ID of a cluster. The instance group to which the automatic scaling policy is applied is within this cluster.</p>
     #[serde(rename = "ClusterId")]
@@ -1972,11 +2688,12 @@ pub struct RemoveAutoScalingPolicyInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveAutoScalingPolicyOutput {}
 
 /// <p>This input identifies a cluster and a list of tags to remove.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsInput {
     /// <p>The Amazon EMR resource identifier from which tags will be removed. This value must be a cluster identifier.</p>
     #[serde(rename = "ResourceId")]
@@ -1988,11 +2705,12 @@ pub struct RemoveTagsInput {
 
 /// <p>This output indicates the result of removing tags from a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsOutput {}
 
 /// <p> Input to the <a>RunJobFlow</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RunJobFlowInput {
     /// <p>A JSON string for selecting additional features.</p>
     #[serde(rename = "AdditionalInfo")]
@@ -2086,9 +2804,176 @@ pub struct RunJobFlowInput {
     pub visible_to_all_users: Option<bool>,
 }
 
+impl Validate for RunJobFlowInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.additional_info {
+            errors.check_length(
+                &field_path(path, "additional_info"),
+                value.chars().count(),
+                None,
+                Some(10280),
+            );
+            errors.check_pattern(
+                &field_path(path, "additional_info"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.ami_version {
+            errors.check_length(
+                &field_path(path, "ami_version"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "ami_version"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.auto_scaling_role {
+            errors.check_length(
+                &field_path(path, "auto_scaling_role"),
+                value.chars().count(),
+                None,
+                Some(10280),
+            );
+            errors.check_pattern(
+                &field_path(path, "auto_scaling_role"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.bootstrap_actions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "bootstrap_actions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.custom_ami_id {
+            errors.check_length(
+                &field_path(path, "custom_ami_id"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "custom_ami_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        self.instances
+            .validate_at(&field_path(path, "instances"), errors);
+        if let Some(ref value) = self.job_flow_role {
+            errors.check_length(
+                &field_path(path, "job_flow_role"),
+                value.chars().count(),
+                None,
+                Some(10280),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_flow_role"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.kerberos_attributes {
+            value.validate_at(&field_path(path, "kerberos_attributes"), errors);
+        }
+        if let Some(ref value) = self.log_uri {
+            errors.check_length(
+                &field_path(path, "log_uri"),
+                value.chars().count(),
+                None,
+                Some(10280),
+            );
+            errors.check_pattern(
+                &field_path(path, "log_uri"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+        if let Some(ref value) = self.new_supported_products {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "new_supported_products"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.release_label {
+            errors.check_length(
+                &field_path(path, "release_label"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "release_label"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.security_configuration {
+            errors.check_length(
+                &field_path(path, "security_configuration"),
+                value.chars().count(),
+                None,
+                Some(10280),
+            );
+            errors.check_pattern(
+                &field_path(path, "security_configuration"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.service_role {
+            errors.check_length(
+                &field_path(path, "service_role"),
+                value.chars().count(),
+                None,
+                Some(10280),
+            );
+            errors.check_pattern(
+                &field_path(path, "service_role"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.steps {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "steps"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.supported_products {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "supported_products"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+    }
+}
+
 /// <p> The result of the <a>RunJobFlow</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RunJobFlowOutput {
     /// <p>An unique identifier for the job flow.</p>
     #[serde(rename = "JobFlowId")]
@@ -2157,9 +3042,36 @@ pub struct ScriptBootstrapActionConfig {
     pub path: String,
 }
 
+impl Validate for ScriptBootstrapActionConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.args {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "args"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(10280));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        errors.check_length(
+            &field_path(path, "path"),
+            self.path.chars().count(),
+            None,
+            Some(10280),
+        );
+        errors.check_pattern(
+            &field_path(path, "path"),
+            &self.path,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>The creation date and time, and name, of a security configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SecurityConfigurationSummary {
     /// <p>The date and time the security configuration was created.</p>
     #[serde(rename = "CreationDateTime")]
@@ -2173,6 +3085,7 @@ pub struct SecurityConfigurationSummary {
 
 /// <p> The input argument to the <a>TerminationProtection</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetTerminationProtectionInput {
     /// <p> A list of strings that uniquely identify the clusters to protect. This identifier is returned by <a>RunJobFlow</a> and can also be obtained from <a>DescribeJobFlows</a> . </p>
     #[serde(rename = "JobFlowIds")]
@@ -2182,8 +3095,23 @@ pub struct SetTerminationProtectionInput {
     pub termination_protected: bool,
 }
 
+impl Validate for SetTerminationProtectionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.job_flow_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "job_flow_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(10280));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p> <i>This member will be deprecated.</i> </p> <p>The input to the SetVisibleToAllUsers action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetVisibleToAllUsersInput {
     /// <p>Identifiers of the job flows to receive the new visibility setting.</p>
     #[serde(rename = "JobFlowIds")]
@@ -2193,6 +3121,20 @@ pub struct SetVisibleToAllUsersInput {
     pub visible_to_all_users: bool,
 }
 
+impl Validate for SetVisibleToAllUsersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.job_flow_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "job_flow_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(10280));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Policy for customizing shrink operations. Allows configuration of decommissioning timeout and targeted instance shrinking.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ShrinkPolicy {
@@ -2239,7 +3181,7 @@ pub struct SpotProvisioningSpecification {
 
 /// <p>This represents a step in a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Step {
     /// <p>The action to take when the cluster step fails. Possible values are TERMINATE_CLUSTER, CANCEL_AND_WAIT, and CONTINUE. TERMINATE_JOB_FLOW is provided for backward compatibility. We recommend using TERMINATE_CLUSTER instead.</p>
     #[serde(rename = "ActionOnFailure")]
@@ -2278,9 +3220,27 @@ pub struct StepConfig {
     pub name: String,
 }
 
+impl Validate for StepConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.hadoop_jar_step
+            .validate_at(&field_path(path, "hadoop_jar_step"), errors);
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+        );
+    }
+}
+
 /// <p>Combines the execution state and configuration of a step.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StepDetail {
     /// <p>The description of the step status.</p>
     #[serde(rename = "ExecutionStatusDetail")]
@@ -2292,7 +3252,7 @@ pub struct StepDetail {
 
 /// <p>The execution state of a step.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StepExecutionStatusDetail {
     /// <p>The creation date and time of the step.</p>
     #[serde(rename = "CreationDateTime")]
@@ -2316,7 +3276,7 @@ pub struct StepExecutionStatusDetail {
 
 /// <p>The details of the step state change reason.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StepStateChangeReason {
     /// <p>The programmable code for the state change reason. Note: Currently, the service provides no code for the state change.</p>
     #[serde(rename = "Code")]
@@ -2330,7 +3290,7 @@ pub struct StepStateChangeReason {
 
 /// <p>The execution status details of the cluster step.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StepStatus {
     /// <p>The details for the step failure including reason, message, and log file path where the root cause was identified.</p>
     #[serde(rename = "FailureDetails")]
@@ -2352,7 +3312,7 @@ pub struct StepStatus {
 
 /// <p>The summary of the cluster step.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StepSummary {
     /// <p>The action to take when the cluster step fails. Possible values are TERMINATE_CLUSTER, CANCEL_AND_WAIT, and CONTINUE. TERMINATE_JOB_FLOW is available for backward compatibility. We recommend using TERMINATE_CLUSTER instead.</p>
     #[serde(rename = "ActionOnFailure")]
@@ -2378,7 +3338,7 @@ pub struct StepSummary {
 
 /// <p>The timeline of the cluster step lifecycle.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StepTimeline {
     /// <p>The date and time when the cluster step was created.</p>
     #[serde(rename = "CreationDateTime")]
@@ -2396,6 +3356,7 @@ pub struct StepTimeline {
 
 /// <p>The list of supported product configurations which allow user-supplied arguments. EMR accepts these arguments and forwards them to the corresponding installation script as bootstrap action arguments.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SupportedProductConfig {
     /// <p>The list of user-supplied arguments.</p>
     #[serde(rename = "Args")]
@@ -2407,6 +3368,35 @@ pub struct SupportedProductConfig {
     pub name: Option<String>,
 }
 
+impl Validate for SupportedProductConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.args {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "args"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(10280));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>A key/value pair containing user-defined metadata that you can associate with an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -2422,12 +3412,27 @@ pub struct Tag {
 
 /// <p> Input to the <a>TerminateJobFlows</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminateJobFlowsInput {
     /// <p>A list of job flows to be shutdown.</p>
     #[serde(rename = "JobFlowIds")]
     pub job_flow_ids: Vec<String>,
 }
 
+impl Validate for TerminateJobFlowsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.job_flow_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "job_flow_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(10280));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>EBS volume specifications such as volume type, IOPS, and size (GiB) that will be requested for the EBS volume attached to an EC2 instance in the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VolumeSpecification {
@@ -2462,7 +3467,12 @@ impl AddInstanceFleetError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(AddInstanceFleetError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2498,7 +3508,12 @@ impl AddInstanceGroupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2531,7 +3546,12 @@ impl AddJobFlowStepsError {
                 "InternalServerError" => {
                     return RusotoError::Service(AddJobFlowStepsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2569,7 +3589,12 @@ impl AddTagsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(AddTagsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2608,7 +3633,12 @@ impl CancelStepsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(CancelStepsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2653,7 +3683,12 @@ impl CreateSecurityConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2698,7 +3733,12 @@ impl DeleteSecurityConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2737,7 +3777,12 @@ impl DescribeClusterError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(DescribeClusterError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2773,7 +3818,12 @@ impl DescribeJobFlowsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2817,7 +3867,12 @@ impl DescribeSecurityConfigurationError {
                         DescribeSecurityConfigurationError::InvalidRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2856,7 +3911,12 @@ impl DescribeStepError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(DescribeStepError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2901,7 +3961,12 @@ impl GetBlockPublicAccessConfigurationError {
                         GetBlockPublicAccessConfigurationError::InvalidRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2940,7 +4005,12 @@ impl ListBootstrapActionsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListBootstrapActionsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2979,7 +4049,12 @@ impl ListClustersError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListClustersError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3018,7 +4093,12 @@ impl ListInstanceFleetsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListInstanceFleetsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3057,7 +4137,12 @@ impl ListInstanceGroupsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListInstanceGroupsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3096,7 +4181,12 @@ impl ListInstancesError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListInstancesError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3141,7 +4231,12 @@ impl ListSecurityConfigurationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3180,7 +4275,12 @@ impl ListStepsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListStepsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3219,7 +4319,12 @@ impl ModifyInstanceFleetError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ModifyInstanceFleetError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3255,7 +4360,12 @@ impl ModifyInstanceGroupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3282,7 +4392,12 @@ impl PutAutoScalingPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutAutoScalingPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3324,7 +4439,12 @@ impl PutBlockPublicAccessConfigurationError {
                         PutBlockPublicAccessConfigurationError::InvalidRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3352,7 +4472,12 @@ impl RemoveAutoScalingPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RemoveAutoScalingPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3388,7 +4513,12 @@ impl RemoveTagsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(RemoveTagsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3422,7 +4552,12 @@ impl RunJobFlowError {
                 "InternalServerError" => {
                     return RusotoError::Service(RunJobFlowError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3457,7 +4592,12 @@ impl SetTerminationProtectionError {
                         SetTerminationProtectionError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3492,7 +4632,12 @@ impl SetVisibleToAllUsersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3527,7 +4672,12 @@ impl TerminateJobFlowsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3715,852 +4865,2486 @@ pub trait Emr {
         input: TerminateJobFlowsInput,
     ) -> RusotoFuture<(), TerminateJobFlowsError>;
 }
-/// A client for the Amazon EMR API.
-#[derive(Clone)]
-pub struct EmrClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl EmrClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> EmrClient {
-        Self::new_with_client(Client::shared(), region)
+const _: Option<&dyn Emr> = None;
+impl<'a, T: Emr + ?Sized> Emr for &'a T {
+    fn add_instance_fleet(
+        &self,
+        input: AddInstanceFleetInput,
+    ) -> RusotoFuture<AddInstanceFleetOutput, AddInstanceFleetError> {
+        (**self).add_instance_fleet(input)
     }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> EmrClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> EmrClient {
-        EmrClient { client, region }
-    }
-}
-
-impl Emr for EmrClient {
-    /// <p><p>Adds an instance fleet to a running cluster.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x.</p> </note></p>
-    fn add_instance_fleet(
-        &self,
-        input: AddInstanceFleetInput,
-    ) -> RusotoFuture<AddInstanceFleetOutput, AddInstanceFleetError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddInstanceFleetOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddInstanceFleetError::from_response(response))),
-                )
-            }
-        })
-    }
-
-    /// <p>Adds one or more instance groups to a running cluster.</p>
     fn add_instance_groups(
         &self,
         input: AddInstanceGroupsInput,
     ) -> RusotoFuture<AddInstanceGroupsOutput, AddInstanceGroupsError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddInstanceGroupsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddInstanceGroupsError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_instance_groups(input)
     }
-
-    /// <p>AddJobFlowSteps adds new steps to a running cluster. A maximum of 256 steps are allowed in each job flow.</p> <p>If your cluster is long-running (such as a Hive data warehouse) or complex, you may require more than 256 steps to process your data. You can bypass the 256-step limitation in various ways, including using SSH to connect to the master node and submitting queries directly to the software running on the master node, such as Hive and Hadoop. For more information on how to do this, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/AddMoreThan256Steps.html">Add More than 256 Steps to a Cluster</a> in the <i>Amazon EMR Management Guide</i>.</p> <p>A step specifies the location of a JAR file stored either on the master node of the cluster or in Amazon S3. Each step is performed by the main function of the main class of the JAR file. The main class can be specified either in the manifest of the JAR or by using the MainFunction parameter of the step.</p> <p>Amazon EMR executes each step in the order listed. For a step to be considered complete, the main function must exit with a zero exit code and all Hadoop jobs started while the step was running must have completed and run successfully.</p> <p>You can only add steps to a cluster that is in one of the following states: STARTING, BOOTSTRAPPING, RUNNING, or WAITING.</p>
     fn add_job_flow_steps(
         &self,
         input: AddJobFlowStepsInput,
     ) -> RusotoFuture<AddJobFlowStepsOutput, AddJobFlowStepsError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.AddJobFlowSteps");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddJobFlowStepsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddJobFlowStepsError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_job_flow_steps(input)
     }
-
-    /// <p>Adds tags to an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p>
     fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.AddTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_tags(input)
     }
-
-    /// <p>Cancels a pending step or steps in a running cluster. Available only in Amazon EMR versions 4.8.0 and later, excluding version 5.0.0. A maximum of 256 steps are allowed in each CancelSteps request. CancelSteps is idempotent but asynchronous; it does not guarantee a step will be canceled, even if the request is successfully submitted. You can only cancel steps that are in a <code>PENDING</code> state.</p>
     fn cancel_steps(
         &self,
         input: CancelStepsInput,
     ) -> RusotoFuture<CancelStepsOutput, CancelStepsError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.CancelSteps");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelStepsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelStepsError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_steps(input)
     }
-
-    /// <p>Creates a security configuration, which is stored in the service and can be specified when a cluster is created.</p>
     fn create_security_configuration(
         &self,
         input: CreateSecurityConfigurationInput,
     ) -> RusotoFuture<CreateSecurityConfigurationOutput, CreateSecurityConfigurationError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "ElasticMapReduce.CreateSecurityConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSecurityConfigurationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateSecurityConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_security_configuration(input)
     }
-
-    /// <p>Deletes a security configuration.</p>
     fn delete_security_configuration(
         &self,
         input: DeleteSecurityConfigurationInput,
     ) -> RusotoFuture<DeleteSecurityConfigurationOutput, DeleteSecurityConfigurationError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "ElasticMapReduce.DeleteSecurityConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSecurityConfigurationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteSecurityConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_security_configuration(input)
     }
-
-    /// <p>Provides cluster-level details including status, hardware and software configuration, VPC settings, and so on. </p>
     fn describe_cluster(
         &self,
         input: DescribeClusterInput,
     ) -> RusotoFuture<DescribeClusterOutput, DescribeClusterError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.DescribeCluster");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeClusterOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeClusterError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_cluster(input)
     }
-
-    /// <p>This API is deprecated and will eventually be removed. We recommend you use <a>ListClusters</a>, <a>DescribeCluster</a>, <a>ListSteps</a>, <a>ListInstanceGroups</a> and <a>ListBootstrapActions</a> instead.</p> <p>DescribeJobFlows returns a list of job flows that match all of the supplied parameters. The parameters can include a list of job flow IDs, job flow states, and restrictions on job flow creation date and time.</p> <p>Regardless of supplied parameters, only job flows created within the last two months are returned.</p> <p>If no parameters are supplied, then job flows matching either of the following criteria are returned:</p> <ul> <li> <p>Job flows created and completed in the last two weeks</p> </li> <li> <p> Job flows created within the last two months that are in one of the following states: <code>RUNNING</code>, <code>WAITING</code>, <code>SHUTTING_DOWN</code>, <code>STARTING</code> </p> </li> </ul> <p>Amazon EMR can return a maximum of 512 job flow descriptions.</p>
     fn describe_job_flows(
         &self,
         input: DescribeJobFlowsInput,
     ) -> RusotoFuture<DescribeJobFlowsOutput, DescribeJobFlowsError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.DescribeJobFlows");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeJobFlowsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeJobFlowsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_job_flows(input)
     }
-
-    /// <p>Provides the details of a security configuration by returning the configuration JSON.</p>
     fn describe_security_configuration(
         &self,
         input: DescribeSecurityConfigurationInput,
     ) -> RusotoFuture<DescribeSecurityConfigurationOutput, DescribeSecurityConfigurationError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "ElasticMapReduce.DescribeSecurityConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSecurityConfigurationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeSecurityConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_security_configuration(input)
     }
-
-    /// <p>Provides more detail about the cluster step.</p>
     fn describe_step(
         &self,
         input: DescribeStepInput,
     ) -> RusotoFuture<DescribeStepOutput, DescribeStepError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.DescribeStep");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStepOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
+        (**self).describe_step(input)
+    }
+    fn get_block_public_access_configuration(
+        &self,
+    ) -> RusotoFuture<GetBlockPublicAccessConfigurationOutput, GetBlockPublicAccessConfigurationError>
+    {
+        (**self).get_block_public_access_configuration()
+    }
+    fn list_bootstrap_actions(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> RusotoFuture<ListBootstrapActionsOutput, ListBootstrapActionsError> {
+        (**self).list_bootstrap_actions(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersInput,
+    ) -> RusotoFuture<ListClustersOutput, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_instance_fleets(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> RusotoFuture<ListInstanceFleetsOutput, ListInstanceFleetsError> {
+        (**self).list_instance_fleets(input)
+    }
+    fn list_instance_groups(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> RusotoFuture<ListInstanceGroupsOutput, ListInstanceGroupsError> {
+        (**self).list_instance_groups(input)
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesInput,
+    ) -> RusotoFuture<ListInstancesOutput, ListInstancesError> {
+        (**self).list_instances(input)
+    }
+    fn list_security_configurations(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> RusotoFuture<ListSecurityConfigurationsOutput, ListSecurityConfigurationsError> {
+        (**self).list_security_configurations(input)
+    }
+    fn list_steps(&self, input: ListStepsInput) -> RusotoFuture<ListStepsOutput, ListStepsError> {
+        (**self).list_steps(input)
+    }
+    fn modify_instance_fleet(
+        &self,
+        input: ModifyInstanceFleetInput,
+    ) -> RusotoFuture<(), ModifyInstanceFleetError> {
+        (**self).modify_instance_fleet(input)
+    }
+    fn modify_instance_groups(
+        &self,
+        input: ModifyInstanceGroupsInput,
+    ) -> RusotoFuture<(), ModifyInstanceGroupsError> {
+        (**self).modify_instance_groups(input)
+    }
+    fn put_auto_scaling_policy(
+        &self,
+        input: PutAutoScalingPolicyInput,
+    ) -> RusotoFuture<PutAutoScalingPolicyOutput, PutAutoScalingPolicyError> {
+        (**self).put_auto_scaling_policy(input)
+    }
+    fn put_block_public_access_configuration(
+        &self,
+        input: PutBlockPublicAccessConfigurationInput,
+    ) -> RusotoFuture<PutBlockPublicAccessConfigurationOutput, PutBlockPublicAccessConfigurationError>
+    {
+        (**self).put_block_public_access_configuration(input)
+    }
+    fn remove_auto_scaling_policy(
+        &self,
+        input: RemoveAutoScalingPolicyInput,
+    ) -> RusotoFuture<RemoveAutoScalingPolicyOutput, RemoveAutoScalingPolicyError> {
+        (**self).remove_auto_scaling_policy(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn run_job_flow(
+        &self,
+        input: RunJobFlowInput,
+    ) -> RusotoFuture<RunJobFlowOutput, RunJobFlowError> {
+        (**self).run_job_flow(input)
+    }
+    fn set_termination_protection(
+        &self,
+        input: SetTerminationProtectionInput,
+    ) -> RusotoFuture<(), SetTerminationProtectionError> {
+        (**self).set_termination_protection(input)
+    }
+    fn set_visible_to_all_users(
+        &self,
+        input: SetVisibleToAllUsersInput,
+    ) -> RusotoFuture<(), SetVisibleToAllUsersError> {
+        (**self).set_visible_to_all_users(input)
+    }
+    fn terminate_job_flows(
+        &self,
+        input: TerminateJobFlowsInput,
+    ) -> RusotoFuture<(), TerminateJobFlowsError> {
+        (**self).terminate_job_flows(input)
+    }
+}
+impl<T: Emr + ?Sized> Emr for Box<T> {
+    fn add_instance_fleet(
+        &self,
+        input: AddInstanceFleetInput,
+    ) -> RusotoFuture<AddInstanceFleetOutput, AddInstanceFleetError> {
+        (**self).add_instance_fleet(input)
+    }
+    fn add_instance_groups(
+        &self,
+        input: AddInstanceGroupsInput,
+    ) -> RusotoFuture<AddInstanceGroupsOutput, AddInstanceGroupsError> {
+        (**self).add_instance_groups(input)
+    }
+    fn add_job_flow_steps(
+        &self,
+        input: AddJobFlowStepsInput,
+    ) -> RusotoFuture<AddJobFlowStepsOutput, AddJobFlowStepsError> {
+        (**self).add_job_flow_steps(input)
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn cancel_steps(
+        &self,
+        input: CancelStepsInput,
+    ) -> RusotoFuture<CancelStepsOutput, CancelStepsError> {
+        (**self).cancel_steps(input)
+    }
+    fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationInput,
+    ) -> RusotoFuture<CreateSecurityConfigurationOutput, CreateSecurityConfigurationError> {
+        (**self).create_security_configuration(input)
+    }
+    fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationInput,
+    ) -> RusotoFuture<DeleteSecurityConfigurationOutput, DeleteSecurityConfigurationError> {
+        (**self).delete_security_configuration(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterInput,
+    ) -> RusotoFuture<DescribeClusterOutput, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_job_flows(
+        &self,
+        input: DescribeJobFlowsInput,
+    ) -> RusotoFuture<DescribeJobFlowsOutput, DescribeJobFlowsError> {
+        (**self).describe_job_flows(input)
+    }
+    fn describe_security_configuration(
+        &self,
+        input: DescribeSecurityConfigurationInput,
+    ) -> RusotoFuture<DescribeSecurityConfigurationOutput, DescribeSecurityConfigurationError> {
+        (**self).describe_security_configuration(input)
+    }
+    fn describe_step(
+        &self,
+        input: DescribeStepInput,
+    ) -> RusotoFuture<DescribeStepOutput, DescribeStepError> {
+        (**self).describe_step(input)
+    }
+    fn get_block_public_access_configuration(
+        &self,
+    ) -> RusotoFuture<GetBlockPublicAccessConfigurationOutput, GetBlockPublicAccessConfigurationError>
+    {
+        (**self).get_block_public_access_configuration()
+    }
+    fn list_bootstrap_actions(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> RusotoFuture<ListBootstrapActionsOutput, ListBootstrapActionsError> {
+        (**self).list_bootstrap_actions(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersInput,
+    ) -> RusotoFuture<ListClustersOutput, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_instance_fleets(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> RusotoFuture<ListInstanceFleetsOutput, ListInstanceFleetsError> {
+        (**self).list_instance_fleets(input)
+    }
+    fn list_instance_groups(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> RusotoFuture<ListInstanceGroupsOutput, ListInstanceGroupsError> {
+        (**self).list_instance_groups(input)
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesInput,
+    ) -> RusotoFuture<ListInstancesOutput, ListInstancesError> {
+        (**self).list_instances(input)
+    }
+    fn list_security_configurations(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> RusotoFuture<ListSecurityConfigurationsOutput, ListSecurityConfigurationsError> {
+        (**self).list_security_configurations(input)
+    }
+    fn list_steps(&self, input: ListStepsInput) -> RusotoFuture<ListStepsOutput, ListStepsError> {
+        (**self).list_steps(input)
+    }
+    fn modify_instance_fleet(
+        &self,
+        input: ModifyInstanceFleetInput,
+    ) -> RusotoFuture<(), ModifyInstanceFleetError> {
+        (**self).modify_instance_fleet(input)
+    }
+    fn modify_instance_groups(
+        &self,
+        input: ModifyInstanceGroupsInput,
+    ) -> RusotoFuture<(), ModifyInstanceGroupsError> {
+        (**self).modify_instance_groups(input)
+    }
+    fn put_auto_scaling_policy(
+        &self,
+        input: PutAutoScalingPolicyInput,
+    ) -> RusotoFuture<PutAutoScalingPolicyOutput, PutAutoScalingPolicyError> {
+        (**self).put_auto_scaling_policy(input)
+    }
+    fn put_block_public_access_configuration(
+        &self,
+        input: PutBlockPublicAccessConfigurationInput,
+    ) -> RusotoFuture<PutBlockPublicAccessConfigurationOutput, PutBlockPublicAccessConfigurationError>
+    {
+        (**self).put_block_public_access_configuration(input)
+    }
+    fn remove_auto_scaling_policy(
+        &self,
+        input: RemoveAutoScalingPolicyInput,
+    ) -> RusotoFuture<RemoveAutoScalingPolicyOutput, RemoveAutoScalingPolicyError> {
+        (**self).remove_auto_scaling_policy(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn run_job_flow(
+        &self,
+        input: RunJobFlowInput,
+    ) -> RusotoFuture<RunJobFlowOutput, RunJobFlowError> {
+        (**self).run_job_flow(input)
+    }
+    fn set_termination_protection(
+        &self,
+        input: SetTerminationProtectionInput,
+    ) -> RusotoFuture<(), SetTerminationProtectionError> {
+        (**self).set_termination_protection(input)
+    }
+    fn set_visible_to_all_users(
+        &self,
+        input: SetVisibleToAllUsersInput,
+    ) -> RusotoFuture<(), SetVisibleToAllUsersError> {
+        (**self).set_visible_to_all_users(input)
+    }
+    fn terminate_job_flows(
+        &self,
+        input: TerminateJobFlowsInput,
+    ) -> RusotoFuture<(), TerminateJobFlowsError> {
+        (**self).terminate_job_flows(input)
+    }
+}
+impl<T: Emr + ?Sized> Emr for ::std::sync::Arc<T> {
+    fn add_instance_fleet(
+        &self,
+        input: AddInstanceFleetInput,
+    ) -> RusotoFuture<AddInstanceFleetOutput, AddInstanceFleetError> {
+        (**self).add_instance_fleet(input)
+    }
+    fn add_instance_groups(
+        &self,
+        input: AddInstanceGroupsInput,
+    ) -> RusotoFuture<AddInstanceGroupsOutput, AddInstanceGroupsError> {
+        (**self).add_instance_groups(input)
+    }
+    fn add_job_flow_steps(
+        &self,
+        input: AddJobFlowStepsInput,
+    ) -> RusotoFuture<AddJobFlowStepsOutput, AddJobFlowStepsError> {
+        (**self).add_job_flow_steps(input)
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn cancel_steps(
+        &self,
+        input: CancelStepsInput,
+    ) -> RusotoFuture<CancelStepsOutput, CancelStepsError> {
+        (**self).cancel_steps(input)
+    }
+    fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationInput,
+    ) -> RusotoFuture<CreateSecurityConfigurationOutput, CreateSecurityConfigurationError> {
+        (**self).create_security_configuration(input)
+    }
+    fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationInput,
+    ) -> RusotoFuture<DeleteSecurityConfigurationOutput, DeleteSecurityConfigurationError> {
+        (**self).delete_security_configuration(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterInput,
+    ) -> RusotoFuture<DescribeClusterOutput, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_job_flows(
+        &self,
+        input: DescribeJobFlowsInput,
+    ) -> RusotoFuture<DescribeJobFlowsOutput, DescribeJobFlowsError> {
+        (**self).describe_job_flows(input)
+    }
+    fn describe_security_configuration(
+        &self,
+        input: DescribeSecurityConfigurationInput,
+    ) -> RusotoFuture<DescribeSecurityConfigurationOutput, DescribeSecurityConfigurationError> {
+        (**self).describe_security_configuration(input)
+    }
+    fn describe_step(
+        &self,
+        input: DescribeStepInput,
+    ) -> RusotoFuture<DescribeStepOutput, DescribeStepError> {
+        (**self).describe_step(input)
+    }
+    fn get_block_public_access_configuration(
+        &self,
+    ) -> RusotoFuture<GetBlockPublicAccessConfigurationOutput, GetBlockPublicAccessConfigurationError>
+    {
+        (**self).get_block_public_access_configuration()
+    }
+    fn list_bootstrap_actions(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> RusotoFuture<ListBootstrapActionsOutput, ListBootstrapActionsError> {
+        (**self).list_bootstrap_actions(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersInput,
+    ) -> RusotoFuture<ListClustersOutput, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_instance_fleets(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> RusotoFuture<ListInstanceFleetsOutput, ListInstanceFleetsError> {
+        (**self).list_instance_fleets(input)
+    }
+    fn list_instance_groups(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> RusotoFuture<ListInstanceGroupsOutput, ListInstanceGroupsError> {
+        (**self).list_instance_groups(input)
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesInput,
+    ) -> RusotoFuture<ListInstancesOutput, ListInstancesError> {
+        (**self).list_instances(input)
+    }
+    fn list_security_configurations(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> RusotoFuture<ListSecurityConfigurationsOutput, ListSecurityConfigurationsError> {
+        (**self).list_security_configurations(input)
+    }
+    fn list_steps(&self, input: ListStepsInput) -> RusotoFuture<ListStepsOutput, ListStepsError> {
+        (**self).list_steps(input)
+    }
+    fn modify_instance_fleet(
+        &self,
+        input: ModifyInstanceFleetInput,
+    ) -> RusotoFuture<(), ModifyInstanceFleetError> {
+        (**self).modify_instance_fleet(input)
+    }
+    fn modify_instance_groups(
+        &self,
+        input: ModifyInstanceGroupsInput,
+    ) -> RusotoFuture<(), ModifyInstanceGroupsError> {
+        (**self).modify_instance_groups(input)
+    }
+    fn put_auto_scaling_policy(
+        &self,
+        input: PutAutoScalingPolicyInput,
+    ) -> RusotoFuture<PutAutoScalingPolicyOutput, PutAutoScalingPolicyError> {
+        (**self).put_auto_scaling_policy(input)
+    }
+    fn put_block_public_access_configuration(
+        &self,
+        input: PutBlockPublicAccessConfigurationInput,
+    ) -> RusotoFuture<PutBlockPublicAccessConfigurationOutput, PutBlockPublicAccessConfigurationError>
+    {
+        (**self).put_block_public_access_configuration(input)
+    }
+    fn remove_auto_scaling_policy(
+        &self,
+        input: RemoveAutoScalingPolicyInput,
+    ) -> RusotoFuture<RemoveAutoScalingPolicyOutput, RemoveAutoScalingPolicyError> {
+        (**self).remove_auto_scaling_policy(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn run_job_flow(
+        &self,
+        input: RunJobFlowInput,
+    ) -> RusotoFuture<RunJobFlowOutput, RunJobFlowError> {
+        (**self).run_job_flow(input)
+    }
+    fn set_termination_protection(
+        &self,
+        input: SetTerminationProtectionInput,
+    ) -> RusotoFuture<(), SetTerminationProtectionError> {
+        (**self).set_termination_protection(input)
+    }
+    fn set_visible_to_all_users(
+        &self,
+        input: SetVisibleToAllUsersInput,
+    ) -> RusotoFuture<(), SetVisibleToAllUsersError> {
+        (**self).set_visible_to_all_users(input)
+    }
+    fn terminate_job_flows(
+        &self,
+        input: TerminateJobFlowsInput,
+    ) -> RusotoFuture<(), TerminateJobFlowsError> {
+        (**self).terminate_job_flows(input)
+    }
+}
+/// A client for the Amazon EMR API.
+#[derive(Clone)]
+pub struct EmrClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl EmrClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EmrClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EmrClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> EmrClient {
+        EmrClient { client, region }
+    }
+}
+
+impl Emr for EmrClient {
+    /// <p><p>Adds an instance fleet to a running cluster.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x.</p> </note></p>
+    fn add_instance_fleet(
+        &self,
+        input: AddInstanceFleetInput,
+    ) -> RusotoFuture<AddInstanceFleetOutput, AddInstanceFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddInstanceFleet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceFleet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddInstanceFleetOutput, _>(
+                    response,
+                    "AddInstanceFleet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddInstanceFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds one or more instance groups to a running cluster.</p>
+    fn add_instance_groups(
+        &self,
+        input: AddInstanceGroupsInput,
+    ) -> RusotoFuture<AddInstanceGroupsOutput, AddInstanceGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddInstanceGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddInstanceGroupsOutput, _>(
+                    response,
+                    "AddInstanceGroups",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddInstanceGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>AddJobFlowSteps adds new steps to a running cluster. A maximum of 256 steps are allowed in each job flow.</p> <p>If your cluster is long-running (such as a Hive data warehouse) or complex, you may require more than 256 steps to process your data. You can bypass the 256-step limitation in various ways, including using SSH to connect to the master node and submitting queries directly to the software running on the master node, such as Hive and Hadoop. For more information on how to do this, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/AddMoreThan256Steps.html">Add More than 256 Steps to a Cluster</a> in the <i>Amazon EMR Management Guide</i>.</p> <p>A step specifies the location of a JAR file stored either on the master node of the cluster or in Amazon S3. Each step is performed by the main function of the main class of the JAR file. The main class can be specified either in the manifest of the JAR or by using the MainFunction parameter of the step.</p> <p>Amazon EMR executes each step in the order listed. For a step to be considered complete, the main function must exit with a zero exit code and all Hadoop jobs started while the step was running must have completed and run successfully.</p> <p>You can only add steps to a cluster that is in one of the following states: STARTING, BOOTSTRAPPING, RUNNING, or WAITING.</p>
+    fn add_job_flow_steps(
+        &self,
+        input: AddJobFlowStepsInput,
+    ) -> RusotoFuture<AddJobFlowStepsOutput, AddJobFlowStepsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddJobFlowSteps");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.AddJobFlowSteps");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddJobFlowStepsOutput, _>(
+                    response,
+                    "AddJobFlowSteps",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddJobFlowStepsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds tags to an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p>
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.AddTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddTagsOutput, _>(response, "AddTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Cancels a pending step or steps in a running cluster. Available only in Amazon EMR versions 4.8.0 and later, excluding version 5.0.0. A maximum of 256 steps are allowed in each CancelSteps request. CancelSteps is idempotent but asynchronous; it does not guarantee a step will be canceled, even if the request is successfully submitted. You can only cancel steps that are in a <code>PENDING</code> state.</p>
+    fn cancel_steps(
+        &self,
+        input: CancelStepsInput,
+    ) -> RusotoFuture<CancelStepsOutput, CancelStepsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("CancelSteps");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.CancelSteps");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelStepsOutput, _>(response, "CancelSteps")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelStepsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a security configuration, which is stored in the service and can be specified when a cluster is created.</p>
+    fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationInput,
+    ) -> RusotoFuture<CreateSecurityConfigurationOutput, CreateSecurityConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("CreateSecurityConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "ElasticMapReduce.CreateSecurityConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSecurityConfigurationOutput, _>(
+                    response,
+                    "CreateSecurityConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateSecurityConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a security configuration.</p>
+    fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationInput,
+    ) -> RusotoFuture<DeleteSecurityConfigurationOutput, DeleteSecurityConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DeleteSecurityConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "ElasticMapReduce.DeleteSecurityConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSecurityConfigurationOutput, _>(
+                    response,
+                    "DeleteSecurityConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteSecurityConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides cluster-level details including status, hardware and software configuration, VPC settings, and so on. </p>
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterInput,
+    ) -> RusotoFuture<DescribeClusterOutput, DescribeClusterError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeCluster");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.DescribeCluster");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeClusterOutput, _>(
+                    response,
+                    "DescribeCluster",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeClusterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This API is deprecated and will eventually be removed. We recommend you use <a>ListClusters</a>, <a>DescribeCluster</a>, <a>ListSteps</a>, <a>ListInstanceGroups</a> and <a>ListBootstrapActions</a> instead.</p> <p>DescribeJobFlows returns a list of job flows that match all of the supplied parameters. The parameters can include a list of job flow IDs, job flow states, and restrictions on job flow creation date and time.</p> <p>Regardless of supplied parameters, only job flows created within the last two months are returned.</p> <p>If no parameters are supplied, then job flows matching either of the following criteria are returned:</p> <ul> <li> <p>Job flows created and completed in the last two weeks</p> </li> <li> <p> Job flows created within the last two months that are in one of the following states: <code>RUNNING</code>, <code>WAITING</code>, <code>SHUTTING_DOWN</code>, <code>STARTING</code> </p> </li> </ul> <p>Amazon EMR can return a maximum of 512 job flow descriptions.</p>
+    fn describe_job_flows(
+        &self,
+        input: DescribeJobFlowsInput,
+    ) -> RusotoFuture<DescribeJobFlowsOutput, DescribeJobFlowsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeJobFlows");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.DescribeJobFlows");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeJobFlowsOutput, _>(
+                    response,
+                    "DescribeJobFlows",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeJobFlowsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides the details of a security configuration by returning the configuration JSON.</p>
+    fn describe_security_configuration(
+        &self,
+        input: DescribeSecurityConfigurationInput,
+    ) -> RusotoFuture<DescribeSecurityConfigurationOutput, DescribeSecurityConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeSecurityConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "ElasticMapReduce.DescribeSecurityConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSecurityConfigurationOutput, _>(
+                    response,
+                    "DescribeSecurityConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeSecurityConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides more detail about the cluster step.</p>
+    fn describe_step(
+        &self,
+        input: DescribeStepInput,
+    ) -> RusotoFuture<DescribeStepOutput, DescribeStepError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeStep");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.DescribeStep");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeStepOutput, _>(
+                    response,
+                    "DescribeStep",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeStepError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the Amazon EMR block public access configuration for your AWS account in the current Region. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/configure-block-public-access.html">Configure Block Public Access for Amazon EMR</a> in the <i>Amazon EMR Management Guide</i>.</p>
+    fn get_block_public_access_configuration(
+        &self,
+    ) -> RusotoFuture<GetBlockPublicAccessConfigurationOutput, GetBlockPublicAccessConfigurationError>
+    {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("GetBlockPublicAccessConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "ElasticMapReduce.GetBlockPublicAccessConfiguration",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetBlockPublicAccessConfigurationOutput, _>(
+                    response,
+                    "GetBlockPublicAccessConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetBlockPublicAccessConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides information about the bootstrap actions associated with a cluster.</p>
+    fn list_bootstrap_actions(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> RusotoFuture<ListBootstrapActionsOutput, ListBootstrapActionsError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListBootstrapActions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.ListBootstrapActions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListBootstrapActionsOutput, _>(
+                    response,
+                    "ListBootstrapActions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListBootstrapActionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides the status of all clusters visible to this AWS account. Allows you to filter the list of clusters based on certain criteria; for example, filtering by cluster creation date and time or by status. This call returns a maximum of 50 clusters per call, but returns a marker to track the paging of the cluster list across multiple ListClusters calls.</p>
+    fn list_clusters(
+        &self,
+        input: ListClustersInput,
+    ) -> RusotoFuture<ListClustersOutput, ListClustersError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListClusters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.ListClusters");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListClustersOutput, _>(
+                    response,
+                    "ListClusters",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListClustersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Lists all available details about the instance fleets in a cluster.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
+    fn list_instance_fleets(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> RusotoFuture<ListInstanceFleetsOutput, ListInstanceFleetsError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListInstanceFleets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceFleets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListInstanceFleetsOutput, _>(
+                    response,
+                    "ListInstanceFleets",
+                )
+            } else {
+                Box::new(
+                    response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(DescribeStepError::from_response(response))),
+                        .and_then(|response| Err(ListInstanceFleetsError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p>Returns the Amazon EMR block public access configuration for your AWS account in the current Region. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/configure-block-public-access.html">Configure Block Public Access for Amazon EMR</a> in the <i>Amazon EMR Management Guide</i>.</p>
-    fn get_block_public_access_configuration(
+    /// <p>Provides all available details about the instance groups in a cluster.</p>
+    fn list_instance_groups(
         &self,
-    ) -> RusotoFuture<GetBlockPublicAccessConfigurationOutput, GetBlockPublicAccessConfigurationError>
-    {
+        input: ListInstanceGroupsInput,
+    ) -> RusotoFuture<ListInstanceGroupsOutput, ListInstanceGroupsError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListInstanceGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListInstanceGroupsOutput, _>(
+                    response,
+                    "ListInstanceGroups",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListInstanceGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides information for all active EC2 instances and EC2 instances terminated in the last 30 days, up to a maximum of 2,000. EC2 instances in any of the following states are considered active: AWAITING_FULFILLMENT, PROVISIONING, BOOTSTRAPPING, RUNNING.</p>
+    fn list_instances(
+        &self,
+        input: ListInstancesInput,
+    ) -> RusotoFuture<ListInstancesOutput, ListInstancesError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.ListInstances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListInstancesOutput, _>(
+                    response,
+                    "ListInstances",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListInstancesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all the security configurations visible to this account, providing their creation dates and times, and their names. This call returns a maximum of 50 clusters per call, but returns a marker to track the paging of the cluster list across multiple ListSecurityConfigurations calls.</p>
+    fn list_security_configurations(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> RusotoFuture<ListSecurityConfigurationsOutput, ListSecurityConfigurationsError> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListSecurityConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "ElasticMapReduce.GetBlockPublicAccessConfiguration",
+            "ElasticMapReduce.ListSecurityConfigurations",
         );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBlockPublicAccessConfigurationOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListSecurityConfigurationsOutput, _>(
+                    response,
+                    "ListSecurityConfigurations",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBlockPublicAccessConfigurationError::from_response(
-                        response,
-                    ))
+                    Err(ListSecurityConfigurationsError::from_response(response))
                 }))
             }
         })
     }
 
-    /// <p>Provides information about the bootstrap actions associated with a cluster.</p>
-    fn list_bootstrap_actions(
+    /// <p>Provides a list of steps for the cluster in reverse order unless you specify stepIds with the request.</p>
+    fn list_steps(&self, input: ListStepsInput) -> RusotoFuture<ListStepsOutput, ListStepsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListSteps");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.ListSteps");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListStepsOutput, _>(response, "ListSteps")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListStepsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Modifies the target On-Demand and target Spot capacities for the instance fleet with the specified InstanceFleetID within the cluster specified using ClusterID. The call either succeeds or fails atomically.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
+    fn modify_instance_fleet(
         &self,
-        input: ListBootstrapActionsInput,
-    ) -> RusotoFuture<ListBootstrapActionsOutput, ListBootstrapActionsError> {
+        input: ModifyInstanceFleetInput,
+    ) -> RusotoFuture<(), ModifyInstanceFleetError> {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ModifyInstanceFleet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.ListBootstrapActions");
+        request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBootstrapActionsOutput, _>()
-                }))
+                Box::new(future::ok(::std::mem::drop(response)))
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
-                        Err(ListBootstrapActionsError::from_response(response))
+                        Err(ModifyInstanceFleetError::from_response(response))
                     }),
                 )
             }
         })
     }
 
-    /// <p>Provides the status of all clusters visible to this AWS account. Allows you to filter the list of clusters based on certain criteria; for example, filtering by cluster creation date and time or by status. This call returns a maximum of 50 clusters per call, but returns a marker to track the paging of the cluster list across multiple ListClusters calls.</p>
-    fn list_clusters(
+    /// <p>ModifyInstanceGroups modifies the number of nodes and configuration settings of an instance group. The input parameters include the new target instance count for the group and the instance group ID. The call will either succeed or fail atomically.</p>
+    fn modify_instance_groups(
         &self,
-        input: ListClustersInput,
-    ) -> RusotoFuture<ListClustersOutput, ListClustersError> {
+        input: ModifyInstanceGroupsInput,
+    ) -> RusotoFuture<(), ModifyInstanceGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ModifyInstanceGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.ListClusters");
+        request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ModifyInstanceGroupsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates or updates an automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric.</p>
+    fn put_auto_scaling_policy(
+        &self,
+        input: PutAutoScalingPolicyInput,
+    ) -> RusotoFuture<PutAutoScalingPolicyOutput, PutAutoScalingPolicyError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("PutAutoScalingPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.PutAutoScalingPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutAutoScalingPolicyOutput, _>(
+                    response,
+                    "PutAutoScalingPolicy",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutAutoScalingPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates or updates an Amazon EMR block public access configuration for your AWS account in the current Region. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/configure-block-public-access.html">Configure Block Public Access for Amazon EMR</a> in the <i>Amazon EMR Management Guide</i>.</p>
+    fn put_block_public_access_configuration(
+        &self,
+        input: PutBlockPublicAccessConfigurationInput,
+    ) -> RusotoFuture<PutBlockPublicAccessConfigurationOutput, PutBlockPublicAccessConfigurationError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("PutBlockPublicAccessConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "ElasticMapReduce.PutBlockPublicAccessConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutBlockPublicAccessConfigurationOutput, _>(
+                    response,
+                    "PutBlockPublicAccessConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutBlockPublicAccessConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes an automatic scaling policy from a specified instance group within an EMR cluster.</p>
+    fn remove_auto_scaling_policy(
+        &self,
+        input: RemoveAutoScalingPolicyInput,
+    ) -> RusotoFuture<RemoveAutoScalingPolicyOutput, RemoveAutoScalingPolicyError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("RemoveAutoScalingPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.RemoveAutoScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<RemoveAutoScalingPolicyOutput, _>(
+                    response,
+                    "RemoveAutoScalingPolicy",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListClustersOutput, _>()
+                    Err(RemoveAutoScalingPolicyError::from_response(response))
                 }))
+            }
+        })
+    }
+
+    /// <p>Removes tags from an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p> <p>The following example removes the stack tag with value Prod from a cluster:</p>
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("RemoveTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "ElasticMapReduce.RemoveTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RemoveTagsOutput, _>(response, "RemoveTags")
             } else {
                 Box::new(
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(ListClustersError::from_response(response))),
+                        .and_then(|response| Err(RemoveTagsError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p><p>Lists all available details about the instance fleets in a cluster.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
-    fn list_instance_fleets(
+    /// <p><p>RunJobFlow creates and starts running a new cluster (job flow). The cluster runs the steps specified. After the steps complete, the cluster stops and the HDFS partition is lost. To prevent loss of data, configure the last step of the job flow to store results in Amazon S3. If the <a>JobFlowInstancesConfig</a> <code>KeepJobFlowAliveWhenNoSteps</code> parameter is set to <code>TRUE</code>, the cluster transitions to the WAITING state rather than shutting down after the steps have completed. </p> <p>For additional protection, you can set the <a>JobFlowInstancesConfig</a> <code>TerminationProtected</code> parameter to <code>TRUE</code> to lock the cluster and prevent it from being terminated by API call, user intervention, or in the event of a job flow error.</p> <p>A maximum of 256 steps are allowed in each job flow.</p> <p>If your cluster is long-running (such as a Hive data warehouse) or complex, you may require more than 256 steps to process your data. You can bypass the 256-step limitation in various ways, including using the SSH shell to connect to the master node and submitting queries directly to the software running on the master node, such as Hive and Hadoop. For more information on how to do this, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/AddMoreThan256Steps.html">Add More than 256 Steps to a Cluster</a> in the <i>Amazon EMR Management Guide</i>.</p> <p>For long running clusters, we recommend that you periodically store your results.</p> <note> <p>The instance fleets configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions. The RunJobFlow request can contain InstanceFleets parameters or InstanceGroups parameters, but not both.</p> </note></p>
+    fn run_job_flow(
         &self,
-        input: ListInstanceFleetsInput,
-    ) -> RusotoFuture<ListInstanceFleetsOutput, ListInstanceFleetsError> {
+        input: RunJobFlowInput,
+    ) -> RusotoFuture<RunJobFlowOutput, RunJobFlowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("RunJobFlow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceFleets");
+        request.add_header("x-amz-target", "ElasticMapReduce.RunJobFlow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListInstanceFleetsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<RunJobFlowOutput, _>(response, "RunJobFlow")
             } else {
                 Box::new(
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(ListInstanceFleetsError::from_response(response))),
+                        .and_then(|response| Err(RunJobFlowError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p>Provides all available details about the instance groups in a cluster.</p>
-    fn list_instance_groups(
+    /// <p>SetTerminationProtection locks a cluster (job flow) so the EC2 instances in the cluster cannot be terminated by user intervention, an API call, or in the event of a job-flow error. The cluster still terminates upon successful completion of the job flow. Calling <code>SetTerminationProtection</code> on a cluster is similar to calling the Amazon EC2 <code>DisableAPITermination</code> API on all EC2 instances in a cluster.</p> <p> <code>SetTerminationProtection</code> is used to prevent accidental termination of a cluster and to ensure that in the event of an error, the instances persist so that you can recover any data stored in their ephemeral instance storage.</p> <p> To terminate a cluster that has been locked by setting <code>SetTerminationProtection</code> to <code>true</code>, you must first unlock the job flow by a subsequent call to <code>SetTerminationProtection</code> in which you set the value to <code>false</code>. </p> <p> For more information, see<a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/UsingEMR_TerminationProtection.html">Managing Cluster Termination</a> in the <i>Amazon EMR Management Guide</i>. </p>
+    fn set_termination_protection(
         &self,
-        input: ListInstanceGroupsInput,
-    ) -> RusotoFuture<ListInstanceGroupsOutput, ListInstanceGroupsError> {
+        input: SetTerminationProtectionInput,
+    ) -> RusotoFuture<(), SetTerminationProtectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("SetTerminationProtection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceGroups");
+        request.add_header("x-amz-target", "ElasticMapReduce.SetTerminationProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListInstanceGroupsOutput, _>()
+                    Err(SetTerminationProtectionError::from_response(response))
                 }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListInstanceGroupsError::from_response(response))),
-                )
             }
         })
     }
 
-    /// <p>Provides information for all active EC2 instances and EC2 instances terminated in the last 30 days, up to a maximum of 2,000. EC2 instances in any of the following states are considered active: AWAITING_FULFILLMENT, PROVISIONING, BOOTSTRAPPING, RUNNING.</p>
-    fn list_instances(
+    /// <p> <i>This member will be deprecated.</i> </p> <p>Sets whether all AWS Identity and Access Management (IAM) users under your account can access the specified clusters (job flows). This action works on running clusters. You can also set the visibility of a cluster when you launch it using the <code>VisibleToAllUsers</code> parameter of <a>RunJobFlow</a>. The SetVisibleToAllUsers action can be called only by an IAM user who created the cluster or the AWS account that owns the cluster.</p>
+    fn set_visible_to_all_users(
         &self,
-        input: ListInstancesInput,
-    ) -> RusotoFuture<ListInstancesOutput, ListInstancesError> {
+        input: SetVisibleToAllUsersInput,
+    ) -> RusotoFuture<(), SetVisibleToAllUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("SetVisibleToAllUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.ListInstances");
+        request.add_header("x-amz-target", "ElasticMapReduce.SetVisibleToAllUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListInstancesOutput, _>()
-                }))
+                Box::new(future::ok(::std::mem::drop(response)))
             } else {
                 Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListInstancesError::from_response(response))),
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SetVisibleToAllUsersError::from_response(response))
+                    }),
                 )
             }
         })
     }
 
-    /// <p>Lists all the security configurations visible to this account, providing their creation dates and times, and their names. This call returns a maximum of 50 clusters per call, but returns a marker to track the paging of the cluster list across multiple ListSecurityConfigurations calls.</p>
-    fn list_security_configurations(
+    /// <p>TerminateJobFlows shuts a list of clusters (job flows) down. When a job flow is shut down, any step not yet completed is canceled and the EC2 instances on which the cluster is running are stopped. Any log files not already saved are uploaded to Amazon S3 if a LogUri was specified when the cluster was created.</p> <p>The maximum number of clusters allowed is 10. The call to <code>TerminateJobFlows</code> is asynchronous. Depending on the configuration of the cluster, it may take up to 1-5 minutes for the cluster to completely terminate and release allocated resources, such as Amazon EC2 instances.</p>
+    fn terminate_job_flows(
         &self,
-        input: ListSecurityConfigurationsInput,
-    ) -> RusotoFuture<ListSecurityConfigurationsOutput, ListSecurityConfigurationsError> {
+        input: TerminateJobFlowsInput,
+    ) -> RusotoFuture<(), TerminateJobFlowsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("TerminateJobFlows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "ElasticMapReduce.ListSecurityConfigurations",
-        );
+        request.add_header("x-amz-target", "ElasticMapReduce.TerminateJobFlows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSecurityConfigurationsOutput, _>()
-                }))
+                Box::new(future::ok(::std::mem::drop(response)))
             } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSecurityConfigurationsError::from_response(response))
-                }))
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TerminateJobFlowsError::from_response(response))),
+                )
             }
         })
     }
-
-    /// <p>Provides a list of steps for the cluster in reverse order unless you specify stepIds with the request.</p>
+}
+
+/// A mock of the Amazon EMR API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockEmr {
+    add_instance_fleet: Option<
+        Box<
+            dyn Fn(
+                    AddInstanceFleetInput,
+                ) -> ::std::result::Result<
+                    AddInstanceFleetOutput,
+                    RusotoError<AddInstanceFleetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_instance_groups: Option<
+        Box<
+            dyn Fn(
+                    AddInstanceGroupsInput,
+                ) -> ::std::result::Result<
+                    AddInstanceGroupsOutput,
+                    RusotoError<AddInstanceGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_job_flow_steps: Option<
+        Box<
+            dyn Fn(
+                    AddJobFlowStepsInput,
+                ) -> ::std::result::Result<
+                    AddJobFlowStepsOutput,
+                    RusotoError<AddJobFlowStepsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_tags: Option<
+        Box<
+            dyn Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    cancel_steps: Option<
+        Box<
+            dyn Fn(
+                    CancelStepsInput,
+                )
+                    -> ::std::result::Result<CancelStepsOutput, RusotoError<CancelStepsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_security_configuration: Option<
+        Box<
+            dyn Fn(
+                    CreateSecurityConfigurationInput,
+                ) -> ::std::result::Result<
+                    CreateSecurityConfigurationOutput,
+                    RusotoError<CreateSecurityConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_security_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteSecurityConfigurationInput,
+                ) -> ::std::result::Result<
+                    DeleteSecurityConfigurationOutput,
+                    RusotoError<DeleteSecurityConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterInput,
+                ) -> ::std::result::Result<
+                    DescribeClusterOutput,
+                    RusotoError<DescribeClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_job_flows: Option<
+        Box<
+            dyn Fn(
+                    DescribeJobFlowsInput,
+                ) -> ::std::result::Result<
+                    DescribeJobFlowsOutput,
+                    RusotoError<DescribeJobFlowsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_security_configuration: Option<
+        Box<
+            dyn Fn(
+                    DescribeSecurityConfigurationInput,
+                ) -> ::std::result::Result<
+                    DescribeSecurityConfigurationOutput,
+                    RusotoError<DescribeSecurityConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_step: Option<
+        Box<
+            dyn Fn(
+                    DescribeStepInput,
+                )
+                    -> ::std::result::Result<DescribeStepOutput, RusotoError<DescribeStepError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_block_public_access_configuration: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetBlockPublicAccessConfigurationOutput,
+                    RusotoError<GetBlockPublicAccessConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_bootstrap_actions: Option<
+        Box<
+            dyn Fn(
+                    ListBootstrapActionsInput,
+                ) -> ::std::result::Result<
+                    ListBootstrapActionsOutput,
+                    RusotoError<ListBootstrapActionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_clusters: Option<
+        Box<
+            dyn Fn(
+                    ListClustersInput,
+                )
+                    -> ::std::result::Result<ListClustersOutput, RusotoError<ListClustersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_instance_fleets: Option<
+        Box<
+            dyn Fn(
+                    ListInstanceFleetsInput,
+                ) -> ::std::result::Result<
+                    ListInstanceFleetsOutput,
+                    RusotoError<ListInstanceFleetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_instance_groups: Option<
+        Box<
+            dyn Fn(
+                    ListInstanceGroupsInput,
+                ) -> ::std::result::Result<
+                    ListInstanceGroupsOutput,
+                    RusotoError<ListInstanceGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_instances: Option<
+        Box<
+            dyn Fn(
+                    ListInstancesInput,
+                )
+                    -> ::std::result::Result<ListInstancesOutput, RusotoError<ListInstancesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_security_configurations: Option<
+        Box<
+            dyn Fn(
+                    ListSecurityConfigurationsInput,
+                ) -> ::std::result::Result<
+                    ListSecurityConfigurationsOutput,
+                    RusotoError<ListSecurityConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_steps: Option<
+        Box<
+            dyn Fn(
+                    ListStepsInput,
+                )
+                    -> ::std::result::Result<ListStepsOutput, RusotoError<ListStepsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_instance_fleet: Option<
+        Box<
+            dyn Fn(
+                    ModifyInstanceFleetInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<ModifyInstanceFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_instance_groups: Option<
+        Box<
+            dyn Fn(
+                    ModifyInstanceGroupsInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<ModifyInstanceGroupsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_auto_scaling_policy: Option<
+        Box<
+            dyn Fn(
+                    PutAutoScalingPolicyInput,
+                ) -> ::std::result::Result<
+                    PutAutoScalingPolicyOutput,
+                    RusotoError<PutAutoScalingPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_block_public_access_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutBlockPublicAccessConfigurationInput,
+                ) -> ::std::result::Result<
+                    PutBlockPublicAccessConfigurationOutput,
+                    RusotoError<PutBlockPublicAccessConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_auto_scaling_policy: Option<
+        Box<
+            dyn Fn(
+                    RemoveAutoScalingPolicyInput,
+                ) -> ::std::result::Result<
+                    RemoveAutoScalingPolicyOutput,
+                    RusotoError<RemoveAutoScalingPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsInput,
+                )
+                    -> ::std::result::Result<RemoveTagsOutput, RusotoError<RemoveTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    run_job_flow: Option<
+        Box<
+            dyn Fn(
+                    RunJobFlowInput,
+                )
+                    -> ::std::result::Result<RunJobFlowOutput, RusotoError<RunJobFlowError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_termination_protection: Option<
+        Box<
+            dyn Fn(
+                    SetTerminationProtectionInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetTerminationProtectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_visible_to_all_users: Option<
+        Box<
+            dyn Fn(
+                    SetVisibleToAllUsersInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetVisibleToAllUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    terminate_job_flows: Option<
+        Box<
+            dyn Fn(
+                    TerminateJobFlowsInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<TerminateJobFlowsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockEmr {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockEmr {
+        Default::default()
+    }
+
+    /// Answers `add_instance_fleet` with what `handler` returns for its input.
+    pub fn with_add_instance_fleet<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                AddInstanceFleetInput,
+            )
+                -> ::std::result::Result<AddInstanceFleetOutput, RusotoError<AddInstanceFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_instance_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_instance_groups` with what `handler` returns for its input.
+    pub fn with_add_instance_groups<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                AddInstanceGroupsInput,
+            ) -> ::std::result::Result<
+                AddInstanceGroupsOutput,
+                RusotoError<AddInstanceGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_instance_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_job_flow_steps` with what `handler` returns for its input.
+    pub fn with_add_job_flow_steps<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                AddJobFlowStepsInput,
+            )
+                -> ::std::result::Result<AddJobFlowStepsOutput, RusotoError<AddJobFlowStepsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_job_flow_steps = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_tags` with what `handler` returns for its input.
+    pub fn with_add_tags<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_steps` with what `handler` returns for its input.
+    pub fn with_cancel_steps<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                CancelStepsInput,
+            )
+                -> ::std::result::Result<CancelStepsOutput, RusotoError<CancelStepsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_steps = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_security_configuration` with what `handler` returns for its input.
+    pub fn with_create_security_configuration<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                CreateSecurityConfigurationInput,
+            ) -> ::std::result::Result<
+                CreateSecurityConfigurationOutput,
+                RusotoError<CreateSecurityConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_security_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_security_configuration` with what `handler` returns for its input.
+    pub fn with_delete_security_configuration<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                DeleteSecurityConfigurationInput,
+            ) -> ::std::result::Result<
+                DeleteSecurityConfigurationOutput,
+                RusotoError<DeleteSecurityConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_security_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster` with what `handler` returns for its input.
+    pub fn with_describe_cluster<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                DescribeClusterInput,
+            )
+                -> ::std::result::Result<DescribeClusterOutput, RusotoError<DescribeClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_job_flows` with what `handler` returns for its input.
+    pub fn with_describe_job_flows<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                DescribeJobFlowsInput,
+            )
+                -> ::std::result::Result<DescribeJobFlowsOutput, RusotoError<DescribeJobFlowsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_job_flows = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_security_configuration` with what `handler` returns for its input.
+    pub fn with_describe_security_configuration<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                DescribeSecurityConfigurationInput,
+            ) -> ::std::result::Result<
+                DescribeSecurityConfigurationOutput,
+                RusotoError<DescribeSecurityConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_security_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_step` with what `handler` returns for its input.
+    pub fn with_describe_step<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                DescribeStepInput,
+            )
+                -> ::std::result::Result<DescribeStepOutput, RusotoError<DescribeStepError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_step = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_block_public_access_configuration` with what `handler` returns.
+    pub fn with_get_block_public_access_configuration<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn() -> ::std::result::Result<
+                GetBlockPublicAccessConfigurationOutput,
+                RusotoError<GetBlockPublicAccessConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_block_public_access_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_bootstrap_actions` with what `handler` returns for its input.
+    pub fn with_list_bootstrap_actions<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                ListBootstrapActionsInput,
+            ) -> ::std::result::Result<
+                ListBootstrapActionsOutput,
+                RusotoError<ListBootstrapActionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_bootstrap_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_clusters` with what `handler` returns for its input.
+    pub fn with_list_clusters<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                ListClustersInput,
+            )
+                -> ::std::result::Result<ListClustersOutput, RusotoError<ListClustersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_instance_fleets` with what `handler` returns for its input.
+    pub fn with_list_instance_fleets<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                ListInstanceFleetsInput,
+            ) -> ::std::result::Result<
+                ListInstanceFleetsOutput,
+                RusotoError<ListInstanceFleetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_instance_fleets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_instance_groups` with what `handler` returns for its input.
+    pub fn with_list_instance_groups<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                ListInstanceGroupsInput,
+            ) -> ::std::result::Result<
+                ListInstanceGroupsOutput,
+                RusotoError<ListInstanceGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_instance_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_instances` with what `handler` returns for its input.
+    pub fn with_list_instances<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                ListInstancesInput,
+            )
+                -> ::std::result::Result<ListInstancesOutput, RusotoError<ListInstancesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_security_configurations` with what `handler` returns for its input.
+    pub fn with_list_security_configurations<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                ListSecurityConfigurationsInput,
+            ) -> ::std::result::Result<
+                ListSecurityConfigurationsOutput,
+                RusotoError<ListSecurityConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_security_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_steps` with what `handler` returns for its input.
+    pub fn with_list_steps<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                ListStepsInput,
+            ) -> ::std::result::Result<ListStepsOutput, RusotoError<ListStepsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_steps = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_instance_fleet` with what `handler` returns for its input.
+    pub fn with_modify_instance_fleet<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                ModifyInstanceFleetInput,
+            ) -> ::std::result::Result<(), RusotoError<ModifyInstanceFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_instance_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_instance_groups` with what `handler` returns for its input.
+    pub fn with_modify_instance_groups<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                ModifyInstanceGroupsInput,
+            ) -> ::std::result::Result<(), RusotoError<ModifyInstanceGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_instance_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_auto_scaling_policy` with what `handler` returns for its input.
+    pub fn with_put_auto_scaling_policy<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                PutAutoScalingPolicyInput,
+            ) -> ::std::result::Result<
+                PutAutoScalingPolicyOutput,
+                RusotoError<PutAutoScalingPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_auto_scaling_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_block_public_access_configuration` with what `handler` returns for its input.
+    pub fn with_put_block_public_access_configuration<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                PutBlockPublicAccessConfigurationInput,
+            ) -> ::std::result::Result<
+                PutBlockPublicAccessConfigurationOutput,
+                RusotoError<PutBlockPublicAccessConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_block_public_access_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_auto_scaling_policy` with what `handler` returns for its input.
+    pub fn with_remove_auto_scaling_policy<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                RemoveAutoScalingPolicyInput,
+            ) -> ::std::result::Result<
+                RemoveAutoScalingPolicyOutput,
+                RusotoError<RemoveAutoScalingPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_auto_scaling_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags` with what `handler` returns for its input.
+    pub fn with_remove_tags<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                RemoveTagsInput,
+            ) -> ::std::result::Result<RemoveTagsOutput, RusotoError<RemoveTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `run_job_flow` with what `handler` returns for its input.
+    pub fn with_run_job_flow<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                RunJobFlowInput,
+            ) -> ::std::result::Result<RunJobFlowOutput, RusotoError<RunJobFlowError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.run_job_flow = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_termination_protection` with what `handler` returns for its input.
+    pub fn with_set_termination_protection<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                SetTerminationProtectionInput,
+            ) -> ::std::result::Result<(), RusotoError<SetTerminationProtectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_termination_protection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_visible_to_all_users` with what `handler` returns for its input.
+    pub fn with_set_visible_to_all_users<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                SetVisibleToAllUsersInput,
+            ) -> ::std::result::Result<(), RusotoError<SetVisibleToAllUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_visible_to_all_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `terminate_job_flows` with what `handler` returns for its input.
+    pub fn with_terminate_job_flows<F>(mut self, handler: F) -> MockEmr
+    where
+        F: Fn(
+                TerminateJobFlowsInput,
+            ) -> ::std::result::Result<(), RusotoError<TerminateJobFlowsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.terminate_job_flows = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Emr for MockEmr {
+    fn add_instance_fleet(
+        &self,
+        input: AddInstanceFleetInput,
+    ) -> RusotoFuture<AddInstanceFleetOutput, AddInstanceFleetError> {
+        match self.add_instance_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for add_instance_fleet"),
+        }
+    }
+    fn add_instance_groups(
+        &self,
+        input: AddInstanceGroupsInput,
+    ) -> RusotoFuture<AddInstanceGroupsOutput, AddInstanceGroupsError> {
+        match self.add_instance_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for add_instance_groups"),
+        }
+    }
+    fn add_job_flow_steps(
+        &self,
+        input: AddJobFlowStepsInput,
+    ) -> RusotoFuture<AddJobFlowStepsOutput, AddJobFlowStepsError> {
+        match self.add_job_flow_steps {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for add_job_flow_steps"),
+        }
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        match self.add_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for add_tags"),
+        }
+    }
+    fn cancel_steps(
+        &self,
+        input: CancelStepsInput,
+    ) -> RusotoFuture<CancelStepsOutput, CancelStepsError> {
+        match self.cancel_steps {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for cancel_steps"),
+        }
+    }
+    fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationInput,
+    ) -> RusotoFuture<CreateSecurityConfigurationOutput, CreateSecurityConfigurationError> {
+        match self.create_security_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for create_security_configuration"),
+        }
+    }
+    fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationInput,
+    ) -> RusotoFuture<DeleteSecurityConfigurationOutput, DeleteSecurityConfigurationError> {
+        match self.delete_security_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for delete_security_configuration"),
+        }
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterInput,
+    ) -> RusotoFuture<DescribeClusterOutput, DescribeClusterError> {
+        match self.describe_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for describe_cluster"),
+        }
+    }
+    fn describe_job_flows(
+        &self,
+        input: DescribeJobFlowsInput,
+    ) -> RusotoFuture<DescribeJobFlowsOutput, DescribeJobFlowsError> {
+        match self.describe_job_flows {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for describe_job_flows"),
+        }
+    }
+    fn describe_security_configuration(
+        &self,
+        input: DescribeSecurityConfigurationInput,
+    ) -> RusotoFuture<DescribeSecurityConfigurationOutput, DescribeSecurityConfigurationError> {
+        match self.describe_security_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for describe_security_configuration"),
+        }
+    }
+    fn describe_step(
+        &self,
+        input: DescribeStepInput,
+    ) -> RusotoFuture<DescribeStepOutput, DescribeStepError> {
+        match self.describe_step {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for describe_step"),
+        }
+    }
+    fn get_block_public_access_configuration(
+        &self,
+    ) -> RusotoFuture<GetBlockPublicAccessConfigurationOutput, GetBlockPublicAccessConfigurationError>
+    {
+        match self.get_block_public_access_configuration {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockEmr has no handler for get_block_public_access_configuration"),
+        }
+    }
+    fn list_bootstrap_actions(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> RusotoFuture<ListBootstrapActionsOutput, ListBootstrapActionsError> {
+        match self.list_bootstrap_actions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for list_bootstrap_actions"),
+        }
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersInput,
+    ) -> RusotoFuture<ListClustersOutput, ListClustersError> {
+        match self.list_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for list_clusters"),
+        }
+    }
+    fn list_instance_fleets(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> RusotoFuture<ListInstanceFleetsOutput, ListInstanceFleetsError> {
+        match self.list_instance_fleets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for list_instance_fleets"),
+        }
+    }
+    fn list_instance_groups(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> RusotoFuture<ListInstanceGroupsOutput, ListInstanceGroupsError> {
+        match self.list_instance_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for list_instance_groups"),
+        }
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesInput,
+    ) -> RusotoFuture<ListInstancesOutput, ListInstancesError> {
+        match self.list_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for list_instances"),
+        }
+    }
+    fn list_security_configurations(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> RusotoFuture<ListSecurityConfigurationsOutput, ListSecurityConfigurationsError> {
+        match self.list_security_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for list_security_configurations"),
+        }
+    }
     fn list_steps(&self, input: ListStepsInput) -> RusotoFuture<ListStepsOutput, ListStepsError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.ListSteps");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ListStepsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListStepsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_steps {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for list_steps"),
+        }
     }
-
-    /// <p><p>Modifies the target On-Demand and target Spot capacities for the instance fleet with the specified InstanceFleetID within the cluster specified using ClusterID. The call either succeeds or fails atomically.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
     fn modify_instance_fleet(
         &self,
         input: ModifyInstanceFleetInput,
     ) -> RusotoFuture<(), ModifyInstanceFleetError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ModifyInstanceFleetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.modify_instance_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for modify_instance_fleet"),
+        }
     }
-
-    /// <p>ModifyInstanceGroups modifies the number of nodes and configuration settings of an instance group. The input parameters include the new target instance count for the group and the instance group ID. The call will either succeed or fail atomically.</p>
     fn modify_instance_groups(
         &self,
         input: ModifyInstanceGroupsInput,
     ) -> RusotoFuture<(), ModifyInstanceGroupsError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ModifyInstanceGroupsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.modify_instance_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for modify_instance_groups"),
+        }
     }
-
-    /// <p>Creates or updates an automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric.</p>
     fn put_auto_scaling_policy(
         &self,
         input: PutAutoScalingPolicyInput,
     ) -> RusotoFuture<PutAutoScalingPolicyOutput, PutAutoScalingPolicyError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.PutAutoScalingPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutAutoScalingPolicyOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutAutoScalingPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.put_auto_scaling_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for put_auto_scaling_policy"),
+        }
     }
-
-    /// <p>Creates or updates an Amazon EMR block public access configuration for your AWS account in the current Region. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/configure-block-public-access.html">Configure Block Public Access for Amazon EMR</a> in the <i>Amazon EMR Management Guide</i>.</p>
     fn put_block_public_access_configuration(
         &self,
         input: PutBlockPublicAccessConfigurationInput,
     ) -> RusotoFuture<PutBlockPublicAccessConfigurationOutput, PutBlockPublicAccessConfigurationError>
     {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "ElasticMapReduce.PutBlockPublicAccessConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutBlockPublicAccessConfigurationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutBlockPublicAccessConfigurationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.put_block_public_access_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for put_block_public_access_configuration"),
+        }
     }
-
-    /// <p>Removes an automatic scaling policy from a specified instance group within an EMR cluster.</p>
     fn remove_auto_scaling_policy(
         &self,
         input: RemoveAutoScalingPolicyInput,
     ) -> RusotoFuture<RemoveAutoScalingPolicyOutput, RemoveAutoScalingPolicyError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.RemoveAutoScalingPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveAutoScalingPolicyOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RemoveAutoScalingPolicyError::from_response(response))
-                }))
-            }
-        })
+        match self.remove_auto_scaling_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for remove_auto_scaling_policy"),
+        }
     }
-
-    /// <p>Removes tags from an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p> <p>The following example removes the stack tag with value Prod from a cluster:</p>
     fn remove_tags(
         &self,
         input: RemoveTagsInput,
     ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.RemoveTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveTagsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RemoveTagsError::from_response(response))),
-                )
-            }
-        })
+        match self.remove_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for remove_tags"),
+        }
     }
-
-    /// <p><p>RunJobFlow creates and starts running a new cluster (job flow). The cluster runs the steps specified. After the steps complete, the cluster stops and the HDFS partition is lost. To prevent loss of data, configure the last step of the job flow to store results in Amazon S3. If the <a>JobFlowInstancesConfig</a> <code>KeepJobFlowAliveWhenNoSteps</code> parameter is set to <code>TRUE</code>, the cluster transitions to the WAITING state rather than shutting down after the steps have completed. </p> <p>For additional protection, you can set the <a>JobFlowInstancesConfig</a> <code>TerminationProtected</code> parameter to <code>TRUE</code> to lock the cluster and prevent it from being terminated by API call, user intervention, or in the event of a job flow error.</p> <p>A maximum of 256 steps are allowed in each job flow.</p> <p>If your cluster is long-running (such as a Hive data warehouse) or complex, you may require more than 256 steps to process your data. You can bypass the 256-step limitation in various ways, including using the SSH shell to connect to the master node and submitting queries directly to the software running on the master node, such as Hive and Hadoop. For more information on how to do this, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/AddMoreThan256Steps.html">Add More than 256 Steps to a Cluster</a> in the <i>Amazon EMR Management Guide</i>.</p> <p>For long running clusters, we recommend that you periodically store your results.</p> <note> <p>The instance fleets configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions. The RunJobFlow request can contain InstanceFleets parameters or InstanceGroups parameters, but not both.</p> </note></p>
     fn run_job_flow(
         &self,
         input: RunJobFlowInput,
     ) -> RusotoFuture<RunJobFlowOutput, RunJobFlowError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.RunJobFlow");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RunJobFlowOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RunJobFlowError::from_response(response))),
-                )
-            }
-        })
+        match self.run_job_flow {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for run_job_flow"),
+        }
     }
-
-    /// <p>SetTerminationProtection locks a cluster (job flow) so the EC2 instances in the cluster cannot be terminated by user intervention, an API call, or in the event of a job-flow error. The cluster still terminates upon successful completion of the job flow. Calling <code>SetTerminationProtection</code> on a cluster is similar to calling the Amazon EC2 <code>DisableAPITermination</code> API on all EC2 instances in a cluster.</p> <p> <code>SetTerminationProtection</code> is used to prevent accidental termination of a cluster and to ensure that in the event of an error, the instances persist so that you can recover any data stored in their ephemeral instance storage.</p> <p> To terminate a cluster that has been locked by setting <code>SetTerminationProtection</code> to <code>true</code>, you must first unlock the job flow by a subsequent call to <code>SetTerminationProtection</code> in which you set the value to <code>false</code>. </p> <p> For more information, see<a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/UsingEMR_TerminationProtection.html">Managing Cluster Termination</a> in the <i>Amazon EMR Management Guide</i>. </p>
     fn set_termination_protection(
         &self,
         input: SetTerminationProtectionInput,
     ) -> RusotoFuture<(), SetTerminationProtectionError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.SetTerminationProtection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetTerminationProtectionError::from_response(response))
-                }))
-            }
-        })
+        match self.set_termination_protection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for set_termination_protection"),
+        }
     }
-
-    /// <p> <i>This member will be deprecated.</i> </p> <p>Sets whether all AWS Identity and Access Management (IAM) users under your account can access the specified clusters (job flows). This action works on running clusters. You can also set the visibility of a cluster when you launch it using the <code>VisibleToAllUsers</code> parameter of <a>RunJobFlow</a>. The SetVisibleToAllUsers action can be called only by an IAM user who created the cluster or the AWS account that owns the cluster.</p>
     fn set_visible_to_all_users(
         &self,
         input: SetVisibleToAllUsersInput,
     ) -> RusotoFuture<(), SetVisibleToAllUsersError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.SetVisibleToAllUsers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SetVisibleToAllUsersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.set_visible_to_all_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for set_visible_to_all_users"),
+        }
     }
-
-    /// <p>TerminateJobFlows shuts a list of clusters (job flows) down. When a job flow is shut down, any step not yet completed is canceled and the EC2 instances on which the cluster is running are stopped. Any log files not already saved are uploaded to Amazon S3 if a LogUri was specified when the cluster was created.</p> <p>The maximum number of clusters allowed is 10. The call to <code>TerminateJobFlows</code> is asynchronous. Depending on the configuration of the cluster, it may take up to 1-5 minutes for the cluster to completely terminate and release allocated resources, such as Amazon EC2 instances.</p>
     fn terminate_job_flows(
         &self,
         input: TerminateJobFlowsInput,
     ) -> RusotoFuture<(), TerminateJobFlowsError> {
-        let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "ElasticMapReduce.TerminateJobFlows");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TerminateJobFlowsError::from_response(response))),
-                )
-            }
-        })
+        match self.terminate_job_flows {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEmr has no handler for terminate_job_flows"),
+        }
     }
 }
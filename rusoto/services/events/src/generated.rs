@@ -22,14 +22,32 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ActivateEventSourceRequest {
     /// <p>The name of the partner event source to activate.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for ActivateEventSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "aws\\.partner(/[\\.\\-_A-Za-z0-9]+){2,}",
+        );
+    }
+}
+
 /// <p>This structure specifies the VPC subnets and security groups for the task and whether a public IP address is to be used. This structure is relevant only for ECS tasks that use the <code>awsvpc</code> network mode.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsVpcConfiguration {
@@ -85,6 +103,7 @@ pub struct BatchRetryStrategy {
 
 /// <p>A JSON string that you can use to limit the event bus permissions that you're granting to only accounts that fulfill the condition. Currently, the only supported condition is membership in a certain AWS organization. The string must contain <code>Type</code>, <code>Key</code>, and <code>Value</code> fields. The <code>Value</code> field specifies the ID of the AWS organization. The following is an example value for <code>Condition</code>:</p> <p> <code>'{"Type" : "StringEquals", "Key": "aws:PrincipalOrgID", "Value": "o-1234567890"}'</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Condition {
     /// <p>The key for the condition. Currently, the only supported key is <code>aws:PrincipalOrgID</code>.</p>
     #[serde(rename = "Key")]
@@ -98,6 +117,7 @@ pub struct Condition {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEventBusRequest {
     /// <p>If you're creating a partner event bus, this specifies the partner event source that the new event bus will be matched with.</p>
     #[serde(rename = "EventSourceName")]
@@ -108,8 +128,37 @@ pub struct CreateEventBusRequest {
     pub name: String,
 }
 
+impl Validate for CreateEventBusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_source_name {
+            errors.check_length(
+                &field_path(path, "event_source_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "event_source_name"),
+                &value,
+                "aws\\.partner(/[\\.\\-_A-Za-z0-9]+){2,}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[/\\.\\-_A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateEventBusResponse {
     /// <p>The ARN of the new event bus.</p>
     #[serde(rename = "EventBusArn")]
@@ -118,6 +167,7 @@ pub struct CreateEventBusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePartnerEventSourceRequest {
     /// <p>The AWS account ID of the customer who is permitted to create a matching partner event bus for this partner event source.</p>
     #[serde(rename = "Account")]
@@ -127,8 +177,31 @@ pub struct CreatePartnerEventSourceRequest {
     pub name: String,
 }
 
+impl Validate for CreatePartnerEventSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account"),
+            self.account.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account"), &self.account, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "aws\\.partner(/[\\.\\-_A-Za-z0-9]+){2,}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePartnerEventSourceResponse {
     /// <p>The ARN of the partner event source.</p>
     #[serde(rename = "EventSourceArn")]
@@ -137,20 +210,55 @@ pub struct CreatePartnerEventSourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeactivateEventSourceRequest {
     /// <p>The name of the partner event source to deactivate.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeactivateEventSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "aws\\.partner(/[\\.\\-_A-Za-z0-9]+){2,}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEventBusRequest {
     /// <p>The name of the event bus to delete.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteEventBusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[/\\.\\-_A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePartnerEventSourceRequest {
     /// <p>The AWS account ID of the AWS customer that the event source was created for.</p>
     #[serde(rename = "Account")]
@@ -160,7 +268,31 @@ pub struct DeletePartnerEventSourceRequest {
     pub name: String,
 }
 
+impl Validate for DeletePartnerEventSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account"),
+            self.account.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_pattern(&field_path(path, "account"), &self.account, "\\d{12}");
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "aws\\.partner(/[\\.\\-_A-Za-z0-9]+){2,}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRuleRequest {
     /// <p>The event bus associated with the rule. If you omit this, the default event bus is used.</p>
     #[serde(rename = "EventBusName")]
@@ -175,7 +307,29 @@ pub struct DeleteRuleRequest {
     pub name: String,
 }
 
+impl Validate for DeleteRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\.\\-_A-Za-z0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventBusRequest {
     /// <p>The name of the event bus to show details for. If you omit this, the default event bus is displayed.</p>
     #[serde(rename = "Name")]
@@ -183,8 +337,22 @@ pub struct DescribeEventBusRequest {
     pub name: Option<String>,
 }
 
+impl Validate for DescribeEventBusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventBusResponse {
     /// <p>The Amazon Resource Name (ARN) of the account permitted to write events to the current account.</p>
     #[serde(rename = "Arn")]
@@ -201,14 +369,31 @@ pub struct DescribeEventBusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventSourceRequest {
     /// <p>The name of the partner event source to display the details of.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DescribeEventSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "aws\\.partner(/[\\.\\-_A-Za-z0-9]+){2,}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventSourceResponse {
     /// <p>The ARN of the partner event source.</p>
     #[serde(rename = "Arn")]
@@ -237,14 +422,31 @@ pub struct DescribeEventSourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePartnerEventSourceRequest {
     /// <p>The name of the event source to display.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DescribePartnerEventSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "aws\\.partner(/[\\.\\-_A-Za-z0-9]+){2,}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePartnerEventSourceResponse {
     /// <p>The ARN of the event source.</p>
     #[serde(rename = "Arn")]
@@ -257,6 +459,7 @@ pub struct DescribePartnerEventSourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRuleRequest {
     /// <p>The event bus associated with the rule. If you omit this, the default event bus is used.</p>
     #[serde(rename = "EventBusName")]
@@ -267,8 +470,29 @@ pub struct DescribeRuleRequest {
     pub name: String,
 }
 
+impl Validate for DescribeRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\.\\-_A-Za-z0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRuleResponse {
     /// <p>The Amazon Resource Name (ARN) of the rule.</p>
     #[serde(rename = "Arn")]
@@ -309,6 +533,7 @@ pub struct DescribeRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableRuleRequest {
     /// <p>The event bus associated with the rule. If you omit this, the default event bus is used.</p>
     #[serde(rename = "EventBusName")]
@@ -319,6 +544,27 @@ pub struct DisableRuleRequest {
     pub name: String,
 }
 
+impl Validate for DisableRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\.\\-_A-Za-z0-9]+");
+    }
+}
+
 /// <p>The custom parameters to be used when the target is an Amazon ECS task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EcsParameters {
@@ -347,7 +593,22 @@ pub struct EcsParameters {
     pub task_definition_arn: String,
 }
 
+impl Validate for EcsParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.task_count {
+            errors.check_range(&field_path(path, "task_count"), *value, Some(1), None);
+        }
+        errors.check_length(
+            &field_path(path, "task_definition_arn"),
+            self.task_definition_arn.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableRuleRequest {
     /// <p>The event bus associated with the rule. If you omit this, the default event bus is used.</p>
     #[serde(rename = "EventBusName")]
@@ -358,9 +619,30 @@ pub struct EnableRuleRequest {
     pub name: String,
 }
 
+impl Validate for EnableRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\.\\-_A-Za-z0-9]+");
+    }
+}
+
 /// <p>An event bus receives events from a source and routes them to rules associated with that event bus. Your account's default event bus receives rules from AWS services. A custom event bus can receive rules from AWS services as well as your custom applications and services. A partner event bus receives events from an event source created by an SaaS partner. These events come from the partners services or applications.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventBus {
     /// <p>The ARN of the event bus.</p>
     #[serde(rename = "Arn")]
@@ -378,7 +660,7 @@ pub struct EventBus {
 
 /// <p>A partner event source is created by an SaaS partner. If a customer creates a partner event bus that matches this event source, that AWS account can receive events from the partner's applications or services.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventSource {
     /// <p>The ARN of the event source.</p>
     #[serde(rename = "Arn")]
@@ -418,6 +700,29 @@ pub struct InputTransformer {
     pub input_template: String,
 }
 
+impl Validate for InputTransformer {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.input_paths_map {
+            errors.check_length(
+                &field_path(path, "input_paths_map"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "input_paths_map"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "input_template"),
+            self.input_template.chars().count(),
+            Some(1),
+            Some(8192),
+        );
+    }
+}
+
 /// <p>This object enables you to specify a JSON path to extract from the event and use as the partition key for the Amazon Kinesis data stream so that you can control the shard that the event goes to. If you don't include this parameter, the default is to use the <code>eventId</code> as the partition key.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KinesisParameters {
@@ -426,7 +731,19 @@ pub struct KinesisParameters {
     pub partition_key_path: String,
 }
 
+impl Validate for KinesisParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "partition_key_path"),
+            self.partition_key_path.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEventBusesRequest {
     /// <p>Specifying this limits the number of results returned by this operation. The operation also returns a <code>NextToken</code> that you can use in a subsequent operation to retrieve the next set of results.</p>
     #[serde(rename = "Limit")]
@@ -442,8 +759,37 @@ pub struct ListEventBusesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListEventBusesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_prefix {
+            errors.check_length(
+                &field_path(path, "name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_prefix"),
+                &value,
+                "[/\\.\\-_A-Za-z0-9]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListEventBusesResponse {
     /// <p>This list of event buses.</p>
     #[serde(rename = "EventBuses")]
@@ -456,6 +802,7 @@ pub struct ListEventBusesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEventSourcesRequest {
     /// <p>Specifying this limits the number of results returned by this operation. The operation also returns a <code>NextToken</code> that you can use in a subsequent operation to retrieve the next set of results.</p>
     #[serde(rename = "Limit")]
@@ -471,8 +818,37 @@ pub struct ListEventSourcesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListEventSourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_prefix {
+            errors.check_length(
+                &field_path(path, "name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_prefix"),
+                &value,
+                "[/\\.\\-_A-Za-z0-9]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListEventSourcesResponse {
     /// <p>The list of event sources.</p>
     #[serde(rename = "EventSources")]
@@ -485,6 +861,7 @@ pub struct ListEventSourcesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPartnerEventSourceAccountsRequest {
     /// <p>The name of the partner event source to display account information about.</p>
     #[serde(rename = "EventSourceName")]
@@ -499,8 +876,35 @@ pub struct ListPartnerEventSourceAccountsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListPartnerEventSourceAccountsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "event_source_name"),
+            self.event_source_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "event_source_name"),
+            &self.event_source_name,
+            "aws\\.partner(/[\\.\\-_A-Za-z0-9]+){2,}",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPartnerEventSourceAccountsResponse {
     /// <p>A token you can use in a subsequent operation to retrieve the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -513,6 +917,7 @@ pub struct ListPartnerEventSourceAccountsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPartnerEventSourcesRequest {
     /// <p>pecifying this limits the number of results returned by this operation. The operation also returns a <code>NextToken</code> that you can use in a subsequent operation to retrieve the next set of results.</p>
     #[serde(rename = "Limit")]
@@ -527,8 +932,35 @@ pub struct ListPartnerEventSourcesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListPartnerEventSourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "name_prefix"),
+            self.name_prefix.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name_prefix"),
+            &self.name_prefix,
+            "aws\\.partner/[\\.\\-_A-Za-z0-9]+/[/\\.\\-_A-Za-z0-9]*",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPartnerEventSourcesResponse {
     /// <p>A token you can use in a subsequent operation to retrieve the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -541,6 +973,7 @@ pub struct ListPartnerEventSourcesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRuleNamesByTargetRequest {
     /// <p>Limits the results to show only the rules associated with the specified event bus.</p>
     #[serde(rename = "EventBusName")]
@@ -559,8 +992,39 @@ pub struct ListRuleNamesByTargetRequest {
     pub target_arn: String,
 }
 
+impl Validate for ListRuleNamesByTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "target_arn"),
+            self.target_arn.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRuleNamesByTargetResponse {
     /// <p>Indicates whether there are additional results to retrieve. If there are no more results, the value is null.</p>
     #[serde(rename = "NextToken")]
@@ -573,6 +1037,7 @@ pub struct ListRuleNamesByTargetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRulesRequest {
     /// <p>Limits the results to show only the rules associated with the specified event bus.</p>
     #[serde(rename = "EventBusName")]
@@ -592,8 +1057,46 @@ pub struct ListRulesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListRulesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_prefix {
+            errors.check_length(
+                &field_path(path, "name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_prefix"),
+                &value,
+                "[\\.\\-_A-Za-z0-9]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRulesResponse {
     /// <p>Indicates whether there are additional results to retrieve. If there are no more results, the value is null.</p>
     #[serde(rename = "NextToken")]
@@ -606,14 +1109,26 @@ pub struct ListRulesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The ARN of the rule for which you want to view tags.</p>
     #[serde(rename = "ResourceARN")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The list of tag keys and values associated with the rule that you specified.</p>
     #[serde(rename = "Tags")]
@@ -622,6 +1137,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTargetsByRuleRequest {
     /// <p>The event bus associated with the rule. If you omit this, the default event bus is used.</p>
     #[serde(rename = "EventBusName")]
@@ -640,8 +1156,40 @@ pub struct ListTargetsByRuleRequest {
     pub rule: String,
 }
 
+impl Validate for ListTargetsByRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "rule"),
+            self.rule.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "rule"), &self.rule, "[\\.\\-_A-Za-z0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTargetsByRuleResponse {
     /// <p>Indicates whether there are additional results to retrieve. If there are no more results, the value is null.</p>
     #[serde(rename = "NextToken")]
@@ -664,7 +1212,7 @@ pub struct NetworkConfiguration {
 
 /// <p>A partner event source is created by an SaaS partner. If a customer creates a partner event bus that matches this event source, that AWS account can receive events from the partner's applications or services.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PartnerEventSource {
     /// <p>The ARN of the partner event source.</p>
     #[serde(rename = "Arn")]
@@ -678,7 +1226,7 @@ pub struct PartnerEventSource {
 
 /// <p>The AWS account that a partner event source has been offered to.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PartnerEventSourceAccount {
     /// <p>The AWS account ID that the partner event source was offered to.</p>
     #[serde(rename = "Account")]
@@ -699,14 +1247,31 @@ pub struct PartnerEventSourceAccount {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutEventsRequest {
     /// <p>The entry that defines an event in your system. You can specify several parameters for the entry such as the source and type of the event, resources associated with the event, and so on.</p>
     #[serde(rename = "Entries")]
     pub entries: Vec<PutEventsRequestEntry>,
 }
 
+impl Validate for PutEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entries"),
+            self.entries.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.entries.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "entries"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Represents an event to be submitted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutEventsRequestEntry {
     /// <p>A valid JSON string. There is no other schema imposed. The JSON string can contain fields and nested subobjects.</p>
     #[serde(rename = "Detail")]
@@ -734,8 +1299,28 @@ pub struct PutEventsRequestEntry {
     pub time: Option<f64>,
 }
 
+impl Validate for PutEventsRequestEntry {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[\\.\\-_A-Za-z0-9]+");
+        }
+        if let Some(ref value) = self.resources {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resources"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2048));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutEventsResponse {
     /// <p>The successfully and unsuccessfully ingested events results. If the ingestion was successful, the entry has the event ID in it. Otherwise, you can use the error code and error message to identify the problem with the entry.</p>
     #[serde(rename = "Entries")]
@@ -749,7 +1334,7 @@ pub struct PutEventsResponse {
 
 /// <p>Represents an event that failed to be submitted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutEventsResultEntry {
     /// <p>The error code that indicates why the event submission failed.</p>
     #[serde(rename = "ErrorCode")]
@@ -766,14 +1351,31 @@ pub struct PutEventsResultEntry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutPartnerEventsRequest {
     /// <p>The list of events to write to the event bus.</p>
     #[serde(rename = "Entries")]
     pub entries: Vec<PutPartnerEventsRequestEntry>,
 }
 
+impl Validate for PutPartnerEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entries"),
+            self.entries.len(),
+            Some(1),
+            Some(20),
+        );
+        for (i, item) in self.entries.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "entries"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>The details about an event generated by an SaaS partner.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutPartnerEventsRequestEntry {
     /// <p>A valid JSON string. There is no other schema imposed. The JSON string can contain fields and nested subobjects.</p>
     #[serde(rename = "Detail")]
@@ -797,8 +1399,32 @@ pub struct PutPartnerEventsRequestEntry {
     pub time: Option<f64>,
 }
 
+impl Validate for PutPartnerEventsRequestEntry {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resources {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resources"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2048));
+            }
+        }
+        if let Some(ref value) = self.source {
+            errors.check_length(
+                &field_path(path, "source"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "source"),
+                &value,
+                "aws\\.partner(/[\\.\\-_A-Za-z0-9]+){2,}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutPartnerEventsResponse {
     /// <p>The list of events from this operation that were successfully written to the partner event bus.</p>
     #[serde(rename = "Entries")]
@@ -812,7 +1438,7 @@ pub struct PutPartnerEventsResponse {
 
 /// <p>Represents an event that a partner tried to generate but failed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutPartnerEventsResultEntry {
     /// <p>The error code that indicates why the event submission failed.</p>
     #[serde(rename = "ErrorCode")]
@@ -829,6 +1455,7 @@ pub struct PutPartnerEventsResultEntry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutPermissionRequest {
     /// <p>The action that you're enabling the other account to perform. Currently, this must be <code>events:PutEvents</code>.</p>
     #[serde(rename = "Action")]
@@ -849,7 +1476,59 @@ pub struct PutPermissionRequest {
     pub statement_id: String,
 }
 
+impl Validate for PutPermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "action"),
+            self.action.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "action"),
+            &self.action,
+            "events:[a-zA-Z]+",
+        );
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "event_bus_name"),
+                &value,
+                "[\\.\\-_A-Za-z0-9]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "principal"),
+            self.principal.chars().count(),
+            Some(1),
+            Some(12),
+        );
+        errors.check_pattern(
+            &field_path(path, "principal"),
+            &self.principal,
+            "(\\d{12}|\\*)",
+        );
+        errors.check_length(
+            &field_path(path, "statement_id"),
+            self.statement_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "statement_id"),
+            &self.statement_id,
+            "[a-zA-Z0-9-_]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRuleRequest {
     /// <p>A description of the rule.</p>
     #[serde(rename = "Description")]
@@ -884,8 +1563,67 @@ pub struct PutRuleRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for PutRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(512),
+            );
+        }
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+        if let Some(ref value) = self.event_pattern {
+            errors.check_length(
+                &field_path(path, "event_pattern"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\.\\-_A-Za-z0-9]+");
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+        }
+        if let Some(ref value) = self.schedule_expression {
+            errors.check_length(
+                &field_path(path, "schedule_expression"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRuleResponse {
     /// <p>The Amazon Resource Name (ARN) of the rule.</p>
     #[serde(rename = "RuleArn")]
@@ -894,6 +1632,7 @@ pub struct PutRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutTargetsRequest {
     /// <p>The name of the event bus associated with the rule. If you omit this, the default event bus is used.</p>
     #[serde(rename = "EventBusName")]
@@ -907,8 +1646,39 @@ pub struct PutTargetsRequest {
     pub targets: Vec<Target>,
 }
 
+impl Validate for PutTargetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+        errors.check_length(
+            &field_path(path, "rule"),
+            self.rule.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "rule"), &self.rule, "[\\.\\-_A-Za-z0-9]+");
+        errors.check_length(
+            &field_path(path, "targets"),
+            self.targets.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.targets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutTargetsResponse {
     /// <p>The failed target entries.</p>
     #[serde(rename = "FailedEntries")]
@@ -922,7 +1692,7 @@ pub struct PutTargetsResponse {
 
 /// <p>Represents a target that failed to be added to a rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutTargetsResultEntry {
     /// <p>The error code that indicates why the target addition failed. If the value is <code>ConcurrentModificationException</code>, too many requests were made at the same time.</p>
     #[serde(rename = "ErrorCode")]
@@ -939,6 +1709,7 @@ pub struct PutTargetsResultEntry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemovePermissionRequest {
     /// <p>The name of the event bus to revoke permissions for. If you omit this, the default event bus is used.</p>
     #[serde(rename = "EventBusName")]
@@ -949,7 +1720,37 @@ pub struct RemovePermissionRequest {
     pub statement_id: String,
 }
 
+impl Validate for RemovePermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "event_bus_name"),
+                &value,
+                "[\\.\\-_A-Za-z0-9]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "statement_id"),
+            self.statement_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "statement_id"),
+            &self.statement_id,
+            "[a-zA-Z0-9-_]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTargetsRequest {
     /// <p>The name of the event bus associated with the rule.</p>
     #[serde(rename = "EventBusName")]
@@ -967,8 +1768,35 @@ pub struct RemoveTargetsRequest {
     pub rule: String,
 }
 
+impl Validate for RemoveTargetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_bus_name {
+            errors.check_length(
+                &field_path(path, "event_bus_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "event_bus_name"), &value, "(arn:aws[\\w-]*:events:[a-z]{2}-[a-z]+-[\\w-]+:[0-9]{12}:event-bus\\/)?[/\\.\\-_A-Za-z0-9]+");
+        }
+        errors.check_length(&field_path(path, "ids"), self.ids.len(), Some(1), Some(100));
+        for (i, item) in self.ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            errors.check_pattern(&item_path, &item, "[\\.\\-_A-Za-z0-9]+");
+        }
+        errors.check_length(
+            &field_path(path, "rule"),
+            self.rule.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "rule"), &self.rule, "[\\.\\-_A-Za-z0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTargetsResponse {
     /// <p>The failed target entries.</p>
     #[serde(rename = "FailedEntries")]
@@ -982,7 +1810,7 @@ pub struct RemoveTargetsResponse {
 
 /// <p>Represents a target that failed to be removed from a rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTargetsResultEntry {
     /// <p>The error code that indicates why the target removal failed. If the value is <code>ConcurrentModificationException</code>, too many requests were made at the same time.</p>
     #[serde(rename = "ErrorCode")]
@@ -1000,7 +1828,7 @@ pub struct RemoveTargetsResultEntry {
 
 /// <p>Contains information about a rule in Amazon EventBridge.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Rule {
     /// <p>The Amazon Resource Name (ARN) of the rule.</p>
     #[serde(rename = "Arn")]
@@ -1048,6 +1876,21 @@ pub struct RunCommandParameters {
     pub run_command_targets: Vec<RunCommandTarget>,
 }
 
+impl Validate for RunCommandParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "run_command_targets"),
+            self.run_command_targets.len(),
+            Some(1),
+            Some(5),
+        );
+        for (i, item) in self.run_command_targets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "run_command_targets"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Information about the EC2 instances that are to be sent the command, specified as key-value pairs. Each <code>RunCommandTarget</code> block can include only one key, but this key can specify multiple values.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RunCommandTarget {
@@ -1059,6 +1902,32 @@ pub struct RunCommandTarget {
     pub values: Vec<String>,
 }
 
+impl Validate for RunCommandTarget {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^[\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*$",
+        );
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+        }
+    }
+}
+
 /// <p>This structure includes the custom parameter to be used when the target is an SQS FIFO queue.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SqsParameters {
@@ -1068,6 +1937,19 @@ pub struct SqsParameters {
     pub message_group_id: Option<String>,
 }
 
+impl Validate for SqsParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.message_group_id {
+            errors.check_length(
+                &field_path(path, "message_group_id"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>A key-value pair associated with an AWS resource. In EventBridge, rules support tagging.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -1079,7 +1961,25 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The ARN of the rule that you're adding tags to.</p>
     #[serde(rename = "ResourceARN")]
@@ -1089,8 +1989,23 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>Targets are the resources to be invoked when a rule is triggered. For a complete list of services and resources that can be set as a target, see <a>PutTargets</a>.</p> <p>If you're setting the event bus of another account as the target and that account granted permission to your account through an organization instead of directly by the account ID, you must specify a <code>RoleArn</code> with proper permissions in the <code>Target</code> structure. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p>
@@ -1140,7 +2055,65 @@ pub struct Target {
     pub sqs_parameters: Option<SqsParameters>,
 }
 
+impl Validate for Target {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        if let Some(ref value) = self.ecs_parameters {
+            value.validate_at(&field_path(path, "ecs_parameters"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "[\\.\\-_A-Za-z0-9]+");
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+        }
+        if let Some(ref value) = self.input_path {
+            errors.check_length(
+                &field_path(path, "input_path"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.input_transformer {
+            value.validate_at(&field_path(path, "input_transformer"), errors);
+        }
+        if let Some(ref value) = self.kinesis_parameters {
+            value.validate_at(&field_path(path, "kinesis_parameters"), errors);
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+        }
+        if let Some(ref value) = self.run_command_parameters {
+            value.validate_at(&field_path(path, "run_command_parameters"), errors);
+        }
+        if let Some(ref value) = self.sqs_parameters {
+            value.validate_at(&field_path(path, "sqs_parameters"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestEventPatternRequest {
     /// <p>The event, in JSON format, to test against the event pattern.</p>
     #[serde(rename = "Event")]
@@ -1150,8 +2123,19 @@ pub struct TestEventPatternRequest {
     pub event_pattern: String,
 }
 
+impl Validate for TestEventPatternRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "event_pattern"),
+            self.event_pattern.chars().count(),
+            None,
+            Some(4096),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TestEventPatternResponse {
     /// <p>Indicates whether the event matches the event pattern.</p>
     #[serde(rename = "Result")]
@@ -1160,6 +2144,7 @@ pub struct TestEventPatternResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The ARN of the rule that you're removing tags from.</p>
     #[serde(rename = "ResourceARN")]
@@ -1169,8 +2154,23 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 /// Errors returned by ActivateEventSource
@@ -1199,7 +2199,12 @@ impl ActivateEventSourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1263,7 +2268,12 @@ impl CreateEventBusError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateEventBusError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1322,7 +2332,12 @@ impl CreatePartnerEventSourceError {
                         CreatePartnerEventSourceError::ResourceAlreadyExists(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1370,7 +2385,12 @@ impl DeactivateEventSourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1405,7 +2425,12 @@ impl DeleteEventBusError {
                 "InternalException" => {
                     return RusotoError::Service(DeleteEventBusError::Internal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1438,7 +2463,12 @@ impl DeletePartnerEventSourceError {
                 "InternalException" => {
                     return RusotoError::Service(DeletePartnerEventSourceError::Internal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1486,7 +2516,12 @@ impl DeleteRuleError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteRuleError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1527,7 +2562,12 @@ impl DescribeEventBusError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeEventBusError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1568,7 +2608,12 @@ impl DescribeEventSourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1611,7 +2656,12 @@ impl DescribePartnerEventSourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1650,7 +2700,12 @@ impl DescribeRuleError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeRuleError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1699,7 +2754,12 @@ impl DisableRuleError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DisableRuleError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1750,7 +2810,12 @@ impl EnableRuleError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(EnableRuleError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1786,7 +2851,12 @@ impl ListEventBusesError {
                 "InternalException" => {
                     return RusotoError::Service(ListEventBusesError::Internal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1819,7 +2889,12 @@ impl ListEventSourcesError {
                 "InternalException" => {
                     return RusotoError::Service(ListEventSourcesError::Internal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1863,7 +2938,12 @@ impl ListPartnerEventSourceAccountsError {
                         ListPartnerEventSourceAccountsError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1897,7 +2977,12 @@ impl ListPartnerEventSourcesError {
                 "InternalException" => {
                     return RusotoError::Service(ListPartnerEventSourcesError::Internal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1937,7 +3022,12 @@ impl ListRuleNamesByTargetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1976,7 +3066,12 @@ impl ListRulesError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListRulesError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2017,7 +3112,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2056,7 +3156,12 @@ impl ListTargetsByRuleError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListTargetsByRuleError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2090,7 +3195,12 @@ impl PutEventsError {
                 "InternalException" => {
                     return RusotoError::Service(PutEventsError::Internal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2123,7 +3233,12 @@ impl PutPartnerEventsError {
                 "InternalException" => {
                     return RusotoError::Service(PutPartnerEventsError::Internal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2173,7 +3288,12 @@ impl PutPermissionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(PutPermissionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2234,7 +3354,12 @@ impl PutRuleError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(PutRuleError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2292,7 +3417,12 @@ impl PutTargetsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(PutTargetsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2341,7 +3471,12 @@ impl RemovePermissionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RemovePermissionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2393,7 +3528,12 @@ impl RemoveTargetsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RemoveTargetsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2444,7 +3584,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2487,7 +3632,12 @@ impl TestEventPatternError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2538,7 +3688,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2733,878 +3888,2690 @@ pub trait EventBridge {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
 }
-/// A client for the Amazon EventBridge API.
-#[derive(Clone)]
-pub struct EventBridgeClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl EventBridgeClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> EventBridgeClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> EventBridgeClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> EventBridgeClient {
-        EventBridgeClient { client, region }
-    }
-}
-
-impl EventBridge for EventBridgeClient {
-    /// <p><p>Activates a partner event source that has been deactivated. Once activated, your matching event bus will start receiving events from the event source.</p> <note> <p>This operation is performed by AWS customers, not by SaaS partners.</p> </note></p>
+const _: Option<&dyn EventBridge> = None;
+impl<'a, T: EventBridge + ?Sized> EventBridge for &'a T {
     fn activate_event_source(
         &self,
         input: ActivateEventSourceRequest,
     ) -> RusotoFuture<(), ActivateEventSourceError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.ActivateEventSource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ActivateEventSourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).activate_event_source(input)
     }
-
-    /// <p><p>Creates a new event bus within your account. This can be a custom event bus which you can use to receive events from your own custom applications and services, or it can be a partner event bus which can be matched to a partner event source.</p> <note> <p>This operation is used by AWS customers, not by SaaS partners.</p> </note></p>
     fn create_event_bus(
         &self,
         input: CreateEventBusRequest,
     ) -> RusotoFuture<CreateEventBusResponse, CreateEventBusError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.CreateEventBus");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateEventBusResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateEventBusError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_event_bus(input)
     }
-
-    /// <p><p>Called by an SaaS partner to create a partner event source.</p> <note> <p>This operation is not used by AWS customers.</p> </note> <p>Each partner event source can be used by one AWS account to create a matching partner event bus in that AWS account. A SaaS partner must create one partner event source for each AWS account that wants to receive those event types. </p> <p>A partner event source creates events based on resources in the SaaS partner&#39;s service or application.</p> <p>An AWS account that creates a partner event bus that matches the partner event source can use that event bus to receive events from the partner, and then process them using AWS Events rules and targets.</p> <p>Partner event source names follow this format:</p> <p> <code>aws.partner/<i>partner<em>name</i>/<i>event</em>namespace</i>/<i>event<em>name</i> </code> </p> <ul> <li> <p> <i>partner</em>name</i> is determined during partner registration and identifies the partner to AWS customers.</p> </li> <li> <p>For <i>event<em>namespace</i>, we recommend that partners use a string that identifies the AWS customer within the partner&#39;s system. This should not be the customer&#39;s AWS account ID.</p> </li> <li> <p> <i>event</em>name</i> is determined by the partner, and should uniquely identify an event-generating resource within the partner system. This should help AWS customers decide whether to create an event bus to receive these events.</p> </li> </ul></p>
     fn create_partner_event_source(
         &self,
         input: CreatePartnerEventSourceRequest,
     ) -> RusotoFuture<CreatePartnerEventSourceResponse, CreatePartnerEventSourceError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.CreatePartnerEventSource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePartnerEventSourceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreatePartnerEventSourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_partner_event_source(input)
     }
-
-    /// <p>An AWS customer uses this operation to temporarily stop receiving events from the specified partner event source. The matching event bus isn't deleted. </p> <p>When you deactivate a partner event source, the source goes into <code>PENDING</code> state. If it remains in <code>PENDING</code> state for more than two weeks, it's deleted.</p> <p>To activate a deactivated partner event source, use <a>ActivateEventSource</a>.</p>
     fn deactivate_event_source(
         &self,
         input: DeactivateEventSourceRequest,
     ) -> RusotoFuture<(), DeactivateEventSourceError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.DeactivateEventSource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeactivateEventSourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).deactivate_event_source(input)
     }
-
-    /// <p><p>Deletes the specified custom event bus or partner event bus. All rules associated with this event bus are also deleted. You can&#39;t delete your account&#39;s default event bus.</p> <note> <p>This operation is performed by AWS customers, not by SaaS partners.</p> </note></p>
     fn delete_event_bus(
         &self,
         input: DeleteEventBusRequest,
     ) -> RusotoFuture<(), DeleteEventBusError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.DeleteEventBus");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteEventBusError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_event_bus(input)
     }
-
-    /// <p>This operation is used by SaaS partners to delete a partner event source. AWS customers don't use this operation.</p> <p>When you delete an event source, the status of the corresponding partner event bus in the AWS customer account becomes <code>DELETED</code>.</p>
     fn delete_partner_event_source(
         &self,
         input: DeletePartnerEventSourceRequest,
     ) -> RusotoFuture<(), DeletePartnerEventSourceError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.DeletePartnerEventSource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeletePartnerEventSourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_partner_event_source(input)
     }
-
-    /// <p>Deletes the specified rule.</p> <p>Before you can delete the rule, you must remove all targets, using <a>RemoveTargets</a>.</p> <p>When you delete a rule, incoming events might continue to match to the deleted rule. Allow a short period of time for changes to take effect.</p> <p>Managed rules are rules created and managed by another AWS service on your behalf. These rules are created by those other AWS services to support functionality in those services. You can delete these rules using the <code>Force</code> option, but you should do so only if you're sure that the other service isn't still using that rule.</p>
     fn delete_rule(&self, input: DeleteRuleRequest) -> RusotoFuture<(), DeleteRuleError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.DeleteRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_rule(input)
     }
-
-    /// <p>Displays details about an event bus in your account. This can include the external AWS accounts that are permitted to write events to your default event bus, and the associated policy. For custom event buses and partner event buses, it displays the name, ARN, policy, state, and creation time.</p> <p> To enable your account to receive events from other accounts on its default event bus, use <a>PutPermission</a>.</p> <p>For more information about partner event buses, see <a>CreateEventBus</a>.</p>
     fn describe_event_bus(
         &self,
         input: DescribeEventBusRequest,
     ) -> RusotoFuture<DescribeEventBusResponse, DescribeEventBusError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.DescribeEventBus");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventBusResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeEventBusError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_event_bus(input)
     }
-
-    /// <p><p>This operation lists details about a partner event source that is shared with your account.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
     fn describe_event_source(
         &self,
         input: DescribeEventSourceRequest,
     ) -> RusotoFuture<DescribeEventSourceResponse, DescribeEventSourceError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.DescribeEventSource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventSourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeEventSourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_event_source(input)
     }
-
-    /// <p><p>An SaaS partner can use this operation to list details about a partner event source that they have created.</p> <note> <p>AWS customers do not use this operation. Instead, AWS customers can use <a>DescribeEventSource</a> to see details about a partner event source that is shared with them.</p> </note></p>
     fn describe_partner_event_source(
         &self,
         input: DescribePartnerEventSourceRequest,
     ) -> RusotoFuture<DescribePartnerEventSourceResponse, DescribePartnerEventSourceError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.DescribePartnerEventSource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePartnerEventSourceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribePartnerEventSourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_partner_event_source(input)
     }
-
-    /// <p>Describes the specified rule.</p> <p> <code>DescribeRule</code> doesn't list the targets of a rule. To see the targets associated with a rule, use <a>ListTargetsByRule</a>.</p>
     fn describe_rule(
         &self,
         input: DescribeRuleRequest,
     ) -> RusotoFuture<DescribeRuleResponse, DescribeRuleError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.DescribeRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRuleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_rule(input)
     }
-
-    /// <p>Disables the specified rule. A disabled rule won't match any events and won't self-trigger if it has a schedule expression.</p> <p>When you disable a rule, incoming events might continue to match to the disabled rule. Allow a short period of time for changes to take effect.</p>
     fn disable_rule(&self, input: DisableRuleRequest) -> RusotoFuture<(), DisableRuleError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.DisableRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisableRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).disable_rule(input)
     }
-
-    /// <p>Enables the specified rule. If the rule doesn't exist, the operation fails.</p> <p>When you enable a rule, incoming events might not immediately start matching to a newly enabled rule. Allow a short period of time for changes to take effect.</p>
     fn enable_rule(&self, input: EnableRuleRequest) -> RusotoFuture<(), EnableRuleError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.EnableRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).enable_rule(input)
     }
-
-    /// <p><p>Lists all the event buses in your account, including the default event bus, custom event buses, and partner event buses.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
     fn list_event_buses(
         &self,
         input: ListEventBusesRequest,
     ) -> RusotoFuture<ListEventBusesResponse, ListEventBusesError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.ListEventBuses");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListEventBusesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListEventBusesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_event_buses(input)
     }
-
-    /// <p><p>You can use this to see all the partner event sources that have been shared with your AWS account. For more information about partner event sources, see <a>CreateEventBus</a>.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
     fn list_event_sources(
         &self,
         input: ListEventSourcesRequest,
     ) -> RusotoFuture<ListEventSourcesResponse, ListEventSourcesError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.ListEventSources");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListEventSourcesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListEventSourcesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_event_sources(input)
     }
-
-    /// <p><p>An SaaS partner can use this operation to display the AWS account ID that a particular partner event source name is associated with.</p> <note> <p>This operation is used by SaaS partners, not by AWS customers.</p> </note></p>
     fn list_partner_event_source_accounts(
         &self,
         input: ListPartnerEventSourceAccountsRequest,
     ) -> RusotoFuture<ListPartnerEventSourceAccountsResponse, ListPartnerEventSourceAccountsError>
     {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSourceAccounts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPartnerEventSourceAccountsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListPartnerEventSourceAccountsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_partner_event_source_accounts(input)
     }
-
-    /// <p><p>An SaaS partner can use this operation to list all the partner event source names that they have created.</p> <note> <p>This operation is not used by AWS customers.</p> </note></p>
     fn list_partner_event_sources(
         &self,
         input: ListPartnerEventSourcesRequest,
     ) -> RusotoFuture<ListPartnerEventSourcesResponse, ListPartnerEventSourcesError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSources");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPartnerEventSourcesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListPartnerEventSourcesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_partner_event_sources(input)
     }
-
-    /// <p>Lists the rules for the specified target. You can see which rules can invoke a specific target in your account.</p>
     fn list_rule_names_by_target(
         &self,
         input: ListRuleNamesByTargetRequest,
     ) -> RusotoFuture<ListRuleNamesByTargetResponse, ListRuleNamesByTargetError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.ListRuleNamesByTarget");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRuleNamesByTargetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListRuleNamesByTargetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_rule_names_by_target(input)
     }
-
-    /// <p>Lists your EventBridge rules. You can either list all the rules or provide a prefix to match to the rule names.</p> <p> <code>ListRules</code> doesn't list the targets of a rule. To see the targets associated with a rule, use <a>ListTargetsByRule</a>.</p>
     fn list_rules(
         &self,
         input: ListRulesRequest,
     ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.ListRules");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRulesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRulesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_rules(input)
     }
-
-    /// <p>Displays the tags associated with an EventBridge resource. In EventBridge, rules can be tagged.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Lists the targets assigned to the specified rule.</p>
     fn list_targets_by_rule(
         &self,
         input: ListTargetsByRuleRequest,
     ) -> RusotoFuture<ListTargetsByRuleResponse, ListTargetsByRuleError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.ListTargetsByRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTargetsByRuleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTargetsByRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_targets_by_rule(input)
     }
-
-    /// <p>Sends custom events to EventBridge so that they can be matched to rules. These events can be from your custom applications and services.</p>
     fn put_events(
         &self,
         input: PutEventsRequest,
     ) -> RusotoFuture<PutEventsResponse, PutEventsError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.PutEvents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutEventsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutEventsError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_events(input)
     }
-
-    /// <p><p>This is used by SaaS partners to write events to a customer&#39;s partner event bus.</p> <note> <p>AWS customers do not use this operation. Instead, AWS customers can use <a>PutEvents</a> to write custom events from their own applications to an event bus.</p> </note></p>
     fn put_partner_events(
         &self,
         input: PutPartnerEventsRequest,
     ) -> RusotoFuture<PutPartnerEventsResponse, PutPartnerEventsError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.PutPartnerEvents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutPartnerEventsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutPartnerEventsError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_partner_events(input)
     }
-
-    /// <p>Running <code>PutPermission</code> permits the specified AWS account or AWS organization to put events to the specified <i>event bus</i>. Rules in your account are triggered by these events arriving to an event bus in your account. </p> <p>For another account to send events to your account, that external account must have a rule with your account's event bus as a target.</p> <p>To enable multiple AWS accounts to put events to an event bus, run <code>PutPermission</code> once for each of these accounts. Or, if all the accounts are members of the same AWS organization, you can run <code>PutPermission</code> once specifying <code>Principal</code> as "*" and specifying the AWS organization ID in <code>Condition</code>, to grant permissions to all accounts in that organization.</p> <p>If you grant permissions using an organization, then accounts in that organization must specify a <code>RoleArn</code> with proper permissions when they use <code>PutTarget</code> to add your account's event bus as a target. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>The permission policy on an event bus can't exceed 10 KB in size.</p>
     fn put_permission(&self, input: PutPermissionRequest) -> RusotoFuture<(), PutPermissionError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.PutPermission");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutPermissionError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_permission(input)
     }
-
-    /// <p>Creates or updates the specified rule. Rules are enabled by default or based on value of the state. You can disable a rule using <a>DisableRule</a>.</p> <p>A single rule watches for events from a single event bus. Events generated by AWS services go to your account's default event bus. Events generated by SaaS partner services or applications go to the matching partner event bus. If you have custom applications or services, you can specify whether their events go to your default event bus or a custom event bus that you have created. For more information, see <a>CreateEventBus</a>.</p> <p>If you're updating an existing rule, the rule is replaced with what you specify in this <code>PutRule</code> command. If you omit arguments in <code>PutRule</code>, the old values for those arguments aren't kept. Instead, they're replaced with null values.</p> <p>When you create or update a rule, incoming events might not immediately start matching to new or updated rules. Allow a short period of time for changes to take effect.</p> <p>A rule must contain at least an <code>EventPattern</code> or <code>ScheduleExpression</code>. Rules with <code>EventPatterns</code> are triggered when a matching event is observed. Rules with <code>ScheduleExpressions</code> self-trigger based on the given schedule. A rule can have both an <code>EventPattern</code> and a <code>ScheduleExpression</code>, in which case the rule triggers on matching events as well as on a schedule.</p> <p>When you initially create a rule, you can optionally assign one or more tags to the rule. Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only rules with certain tag values. To use the <code>PutRule</code> operation and assign tags, you must have both the <code>events:PutRule</code> and <code>events:TagResource</code> permissions.</p> <p>If you are updating an existing rule, any tags you specify in the <code>PutRule</code> operation are ignored. To update the tags of an existing rule, use <a>TagResource</a> and <a>UntagResource</a>.</p> <p>Most services in AWS treat <code>:</code> or <code>/</code> as the same character in Amazon Resource Names (ARNs). However, EventBridge uses an exact match in event patterns and rules. Be sure to use the correct ARN characters when creating event patterns so that they match the ARN syntax in the event that you want to match.</p> <p>In EventBridge, you could create rules that lead to infinite loops, where a rule is fired repeatedly. For example, a rule might detect that ACLs have changed on an S3 bucket, and trigger software to change them to the desired state. If you don't write the rule carefully, the subsequent change to the ACLs fires the rule again, creating an infinite loop.</p> <p>To prevent this, write the rules so that the triggered actions don't refire the same rule. For example, your rule could fire only if ACLs are found to be in a bad state, instead of after any change. </p> <p>An infinite loop can quickly cause higher than expected charges. We recommend that you use budgeting, which alerts you when charges exceed your specified limit. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/budgets-managing-costs.html">Managing Your Costs with Budgets</a>.</p>
     fn put_rule(&self, input: PutRuleRequest) -> RusotoFuture<PutRuleResponse, PutRuleError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.PutRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<PutRuleResponse, _>()
-                }))
-            } else {
+        (**self).put_rule(input)
+    }
+    fn put_targets(
+        &self,
+        input: PutTargetsRequest,
+    ) -> RusotoFuture<PutTargetsResponse, PutTargetsError> {
+        (**self).put_targets(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn remove_targets(
+        &self,
+        input: RemoveTargetsRequest,
+    ) -> RusotoFuture<RemoveTargetsResponse, RemoveTargetsError> {
+        (**self).remove_targets(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_event_pattern(
+        &self,
+        input: TestEventPatternRequest,
+    ) -> RusotoFuture<TestEventPatternResponse, TestEventPatternError> {
+        (**self).test_event_pattern(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: EventBridge + ?Sized> EventBridge for Box<T> {
+    fn activate_event_source(
+        &self,
+        input: ActivateEventSourceRequest,
+    ) -> RusotoFuture<(), ActivateEventSourceError> {
+        (**self).activate_event_source(input)
+    }
+    fn create_event_bus(
+        &self,
+        input: CreateEventBusRequest,
+    ) -> RusotoFuture<CreateEventBusResponse, CreateEventBusError> {
+        (**self).create_event_bus(input)
+    }
+    fn create_partner_event_source(
+        &self,
+        input: CreatePartnerEventSourceRequest,
+    ) -> RusotoFuture<CreatePartnerEventSourceResponse, CreatePartnerEventSourceError> {
+        (**self).create_partner_event_source(input)
+    }
+    fn deactivate_event_source(
+        &self,
+        input: DeactivateEventSourceRequest,
+    ) -> RusotoFuture<(), DeactivateEventSourceError> {
+        (**self).deactivate_event_source(input)
+    }
+    fn delete_event_bus(
+        &self,
+        input: DeleteEventBusRequest,
+    ) -> RusotoFuture<(), DeleteEventBusError> {
+        (**self).delete_event_bus(input)
+    }
+    fn delete_partner_event_source(
+        &self,
+        input: DeletePartnerEventSourceRequest,
+    ) -> RusotoFuture<(), DeletePartnerEventSourceError> {
+        (**self).delete_partner_event_source(input)
+    }
+    fn delete_rule(&self, input: DeleteRuleRequest) -> RusotoFuture<(), DeleteRuleError> {
+        (**self).delete_rule(input)
+    }
+    fn describe_event_bus(
+        &self,
+        input: DescribeEventBusRequest,
+    ) -> RusotoFuture<DescribeEventBusResponse, DescribeEventBusError> {
+        (**self).describe_event_bus(input)
+    }
+    fn describe_event_source(
+        &self,
+        input: DescribeEventSourceRequest,
+    ) -> RusotoFuture<DescribeEventSourceResponse, DescribeEventSourceError> {
+        (**self).describe_event_source(input)
+    }
+    fn describe_partner_event_source(
+        &self,
+        input: DescribePartnerEventSourceRequest,
+    ) -> RusotoFuture<DescribePartnerEventSourceResponse, DescribePartnerEventSourceError> {
+        (**self).describe_partner_event_source(input)
+    }
+    fn describe_rule(
+        &self,
+        input: DescribeRuleRequest,
+    ) -> RusotoFuture<DescribeRuleResponse, DescribeRuleError> {
+        (**self).describe_rule(input)
+    }
+    fn disable_rule(&self, input: DisableRuleRequest) -> RusotoFuture<(), DisableRuleError> {
+        (**self).disable_rule(input)
+    }
+    fn enable_rule(&self, input: EnableRuleRequest) -> RusotoFuture<(), EnableRuleError> {
+        (**self).enable_rule(input)
+    }
+    fn list_event_buses(
+        &self,
+        input: ListEventBusesRequest,
+    ) -> RusotoFuture<ListEventBusesResponse, ListEventBusesError> {
+        (**self).list_event_buses(input)
+    }
+    fn list_event_sources(
+        &self,
+        input: ListEventSourcesRequest,
+    ) -> RusotoFuture<ListEventSourcesResponse, ListEventSourcesError> {
+        (**self).list_event_sources(input)
+    }
+    fn list_partner_event_source_accounts(
+        &self,
+        input: ListPartnerEventSourceAccountsRequest,
+    ) -> RusotoFuture<ListPartnerEventSourceAccountsResponse, ListPartnerEventSourceAccountsError>
+    {
+        (**self).list_partner_event_source_accounts(input)
+    }
+    fn list_partner_event_sources(
+        &self,
+        input: ListPartnerEventSourcesRequest,
+    ) -> RusotoFuture<ListPartnerEventSourcesResponse, ListPartnerEventSourcesError> {
+        (**self).list_partner_event_sources(input)
+    }
+    fn list_rule_names_by_target(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> RusotoFuture<ListRuleNamesByTargetResponse, ListRuleNamesByTargetError> {
+        (**self).list_rule_names_by_target(input)
+    }
+    fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
+        (**self).list_rules(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_targets_by_rule(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> RusotoFuture<ListTargetsByRuleResponse, ListTargetsByRuleError> {
+        (**self).list_targets_by_rule(input)
+    }
+    fn put_events(
+        &self,
+        input: PutEventsRequest,
+    ) -> RusotoFuture<PutEventsResponse, PutEventsError> {
+        (**self).put_events(input)
+    }
+    fn put_partner_events(
+        &self,
+        input: PutPartnerEventsRequest,
+    ) -> RusotoFuture<PutPartnerEventsResponse, PutPartnerEventsError> {
+        (**self).put_partner_events(input)
+    }
+    fn put_permission(&self, input: PutPermissionRequest) -> RusotoFuture<(), PutPermissionError> {
+        (**self).put_permission(input)
+    }
+    fn put_rule(&self, input: PutRuleRequest) -> RusotoFuture<PutRuleResponse, PutRuleError> {
+        (**self).put_rule(input)
+    }
+    fn put_targets(
+        &self,
+        input: PutTargetsRequest,
+    ) -> RusotoFuture<PutTargetsResponse, PutTargetsError> {
+        (**self).put_targets(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn remove_targets(
+        &self,
+        input: RemoveTargetsRequest,
+    ) -> RusotoFuture<RemoveTargetsResponse, RemoveTargetsError> {
+        (**self).remove_targets(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_event_pattern(
+        &self,
+        input: TestEventPatternRequest,
+    ) -> RusotoFuture<TestEventPatternResponse, TestEventPatternError> {
+        (**self).test_event_pattern(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: EventBridge + ?Sized> EventBridge for ::std::sync::Arc<T> {
+    fn activate_event_source(
+        &self,
+        input: ActivateEventSourceRequest,
+    ) -> RusotoFuture<(), ActivateEventSourceError> {
+        (**self).activate_event_source(input)
+    }
+    fn create_event_bus(
+        &self,
+        input: CreateEventBusRequest,
+    ) -> RusotoFuture<CreateEventBusResponse, CreateEventBusError> {
+        (**self).create_event_bus(input)
+    }
+    fn create_partner_event_source(
+        &self,
+        input: CreatePartnerEventSourceRequest,
+    ) -> RusotoFuture<CreatePartnerEventSourceResponse, CreatePartnerEventSourceError> {
+        (**self).create_partner_event_source(input)
+    }
+    fn deactivate_event_source(
+        &self,
+        input: DeactivateEventSourceRequest,
+    ) -> RusotoFuture<(), DeactivateEventSourceError> {
+        (**self).deactivate_event_source(input)
+    }
+    fn delete_event_bus(
+        &self,
+        input: DeleteEventBusRequest,
+    ) -> RusotoFuture<(), DeleteEventBusError> {
+        (**self).delete_event_bus(input)
+    }
+    fn delete_partner_event_source(
+        &self,
+        input: DeletePartnerEventSourceRequest,
+    ) -> RusotoFuture<(), DeletePartnerEventSourceError> {
+        (**self).delete_partner_event_source(input)
+    }
+    fn delete_rule(&self, input: DeleteRuleRequest) -> RusotoFuture<(), DeleteRuleError> {
+        (**self).delete_rule(input)
+    }
+    fn describe_event_bus(
+        &self,
+        input: DescribeEventBusRequest,
+    ) -> RusotoFuture<DescribeEventBusResponse, DescribeEventBusError> {
+        (**self).describe_event_bus(input)
+    }
+    fn describe_event_source(
+        &self,
+        input: DescribeEventSourceRequest,
+    ) -> RusotoFuture<DescribeEventSourceResponse, DescribeEventSourceError> {
+        (**self).describe_event_source(input)
+    }
+    fn describe_partner_event_source(
+        &self,
+        input: DescribePartnerEventSourceRequest,
+    ) -> RusotoFuture<DescribePartnerEventSourceResponse, DescribePartnerEventSourceError> {
+        (**self).describe_partner_event_source(input)
+    }
+    fn describe_rule(
+        &self,
+        input: DescribeRuleRequest,
+    ) -> RusotoFuture<DescribeRuleResponse, DescribeRuleError> {
+        (**self).describe_rule(input)
+    }
+    fn disable_rule(&self, input: DisableRuleRequest) -> RusotoFuture<(), DisableRuleError> {
+        (**self).disable_rule(input)
+    }
+    fn enable_rule(&self, input: EnableRuleRequest) -> RusotoFuture<(), EnableRuleError> {
+        (**self).enable_rule(input)
+    }
+    fn list_event_buses(
+        &self,
+        input: ListEventBusesRequest,
+    ) -> RusotoFuture<ListEventBusesResponse, ListEventBusesError> {
+        (**self).list_event_buses(input)
+    }
+    fn list_event_sources(
+        &self,
+        input: ListEventSourcesRequest,
+    ) -> RusotoFuture<ListEventSourcesResponse, ListEventSourcesError> {
+        (**self).list_event_sources(input)
+    }
+    fn list_partner_event_source_accounts(
+        &self,
+        input: ListPartnerEventSourceAccountsRequest,
+    ) -> RusotoFuture<ListPartnerEventSourceAccountsResponse, ListPartnerEventSourceAccountsError>
+    {
+        (**self).list_partner_event_source_accounts(input)
+    }
+    fn list_partner_event_sources(
+        &self,
+        input: ListPartnerEventSourcesRequest,
+    ) -> RusotoFuture<ListPartnerEventSourcesResponse, ListPartnerEventSourcesError> {
+        (**self).list_partner_event_sources(input)
+    }
+    fn list_rule_names_by_target(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> RusotoFuture<ListRuleNamesByTargetResponse, ListRuleNamesByTargetError> {
+        (**self).list_rule_names_by_target(input)
+    }
+    fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
+        (**self).list_rules(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_targets_by_rule(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> RusotoFuture<ListTargetsByRuleResponse, ListTargetsByRuleError> {
+        (**self).list_targets_by_rule(input)
+    }
+    fn put_events(
+        &self,
+        input: PutEventsRequest,
+    ) -> RusotoFuture<PutEventsResponse, PutEventsError> {
+        (**self).put_events(input)
+    }
+    fn put_partner_events(
+        &self,
+        input: PutPartnerEventsRequest,
+    ) -> RusotoFuture<PutPartnerEventsResponse, PutPartnerEventsError> {
+        (**self).put_partner_events(input)
+    }
+    fn put_permission(&self, input: PutPermissionRequest) -> RusotoFuture<(), PutPermissionError> {
+        (**self).put_permission(input)
+    }
+    fn put_rule(&self, input: PutRuleRequest) -> RusotoFuture<PutRuleResponse, PutRuleError> {
+        (**self).put_rule(input)
+    }
+    fn put_targets(
+        &self,
+        input: PutTargetsRequest,
+    ) -> RusotoFuture<PutTargetsResponse, PutTargetsError> {
+        (**self).put_targets(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn remove_targets(
+        &self,
+        input: RemoveTargetsRequest,
+    ) -> RusotoFuture<RemoveTargetsResponse, RemoveTargetsError> {
+        (**self).remove_targets(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_event_pattern(
+        &self,
+        input: TestEventPatternRequest,
+    ) -> RusotoFuture<TestEventPatternResponse, TestEventPatternError> {
+        (**self).test_event_pattern(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// A client for the Amazon EventBridge API.
+#[derive(Clone)]
+pub struct EventBridgeClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl EventBridgeClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EventBridgeClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EventBridgeClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> EventBridgeClient {
+        EventBridgeClient { client, region }
+    }
+}
+
+impl EventBridge for EventBridgeClient {
+    /// <p><p>Activates a partner event source that has been deactivated. Once activated, your matching event bus will start receiving events from the event source.</p> <note> <p>This operation is performed by AWS customers, not by SaaS partners.</p> </note></p>
+    fn activate_event_source(
+        &self,
+        input: ActivateEventSourceRequest,
+    ) -> RusotoFuture<(), ActivateEventSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ActivateEventSource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.ActivateEventSource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ActivateEventSourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a new event bus within your account. This can be a custom event bus which you can use to receive events from your own custom applications and services, or it can be a partner event bus which can be matched to a partner event source.</p> <note> <p>This operation is used by AWS customers, not by SaaS partners.</p> </note></p>
+    fn create_event_bus(
+        &self,
+        input: CreateEventBusRequest,
+    ) -> RusotoFuture<CreateEventBusResponse, CreateEventBusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("CreateEventBus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.CreateEventBus");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateEventBusResponse, _>(
+                    response,
+                    "CreateEventBus",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateEventBusError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Called by an SaaS partner to create a partner event source.</p> <note> <p>This operation is not used by AWS customers.</p> </note> <p>Each partner event source can be used by one AWS account to create a matching partner event bus in that AWS account. A SaaS partner must create one partner event source for each AWS account that wants to receive those event types. </p> <p>A partner event source creates events based on resources in the SaaS partner&#39;s service or application.</p> <p>An AWS account that creates a partner event bus that matches the partner event source can use that event bus to receive events from the partner, and then process them using AWS Events rules and targets.</p> <p>Partner event source names follow this format:</p> <p> <code>aws.partner/<i>partner<em>name</i>/<i>event</em>namespace</i>/<i>event<em>name</i> </code> </p> <ul> <li> <p> <i>partner</em>name</i> is determined during partner registration and identifies the partner to AWS customers.</p> </li> <li> <p>For <i>event<em>namespace</i>, we recommend that partners use a string that identifies the AWS customer within the partner&#39;s system. This should not be the customer&#39;s AWS account ID.</p> </li> <li> <p> <i>event</em>name</i> is determined by the partner, and should uniquely identify an event-generating resource within the partner system. This should help AWS customers decide whether to create an event bus to receive these events.</p> </li> </ul></p>
+    fn create_partner_event_source(
+        &self,
+        input: CreatePartnerEventSourceRequest,
+    ) -> RusotoFuture<CreatePartnerEventSourceResponse, CreatePartnerEventSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("CreatePartnerEventSource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.CreatePartnerEventSource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePartnerEventSourceResponse, _>(
+                    response,
+                    "CreatePartnerEventSource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreatePartnerEventSourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>An AWS customer uses this operation to temporarily stop receiving events from the specified partner event source. The matching event bus isn't deleted. </p> <p>When you deactivate a partner event source, the source goes into <code>PENDING</code> state. If it remains in <code>PENDING</code> state for more than two weeks, it's deleted.</p> <p>To activate a deactivated partner event source, use <a>ActivateEventSource</a>.</p>
+    fn deactivate_event_source(
+        &self,
+        input: DeactivateEventSourceRequest,
+    ) -> RusotoFuture<(), DeactivateEventSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeactivateEventSource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.DeactivateEventSource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeactivateEventSourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes the specified custom event bus or partner event bus. All rules associated with this event bus are also deleted. You can&#39;t delete your account&#39;s default event bus.</p> <note> <p>This operation is performed by AWS customers, not by SaaS partners.</p> </note></p>
+    fn delete_event_bus(
+        &self,
+        input: DeleteEventBusRequest,
+    ) -> RusotoFuture<(), DeleteEventBusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeleteEventBus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.DeleteEventBus");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteEventBusError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation is used by SaaS partners to delete a partner event source. AWS customers don't use this operation.</p> <p>When you delete an event source, the status of the corresponding partner event bus in the AWS customer account becomes <code>DELETED</code>.</p>
+    fn delete_partner_event_source(
+        &self,
+        input: DeletePartnerEventSourceRequest,
+    ) -> RusotoFuture<(), DeletePartnerEventSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeletePartnerEventSource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.DeletePartnerEventSource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeletePartnerEventSourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified rule.</p> <p>Before you can delete the rule, you must remove all targets, using <a>RemoveTargets</a>.</p> <p>When you delete a rule, incoming events might continue to match to the deleted rule. Allow a short period of time for changes to take effect.</p> <p>Managed rules are rules created and managed by another AWS service on your behalf. These rules are created by those other AWS services to support functionality in those services. You can delete these rules using the <code>Force</code> option, but you should do so only if you're sure that the other service isn't still using that rule.</p>
+    fn delete_rule(&self, input: DeleteRuleRequest) -> RusotoFuture<(), DeleteRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeleteRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.DeleteRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Displays details about an event bus in your account. This can include the external AWS accounts that are permitted to write events to your default event bus, and the associated policy. For custom event buses and partner event buses, it displays the name, ARN, policy, state, and creation time.</p> <p> To enable your account to receive events from other accounts on its default event bus, use <a>PutPermission</a>.</p> <p>For more information about partner event buses, see <a>CreateEventBus</a>.</p>
+    fn describe_event_bus(
+        &self,
+        input: DescribeEventBusRequest,
+    ) -> RusotoFuture<DescribeEventBusResponse, DescribeEventBusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribeEventBus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.DescribeEventBus");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEventBusResponse, _>(
+                    response,
+                    "DescribeEventBus",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeEventBusError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>This operation lists details about a partner event source that is shared with your account.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
+    fn describe_event_source(
+        &self,
+        input: DescribeEventSourceRequest,
+    ) -> RusotoFuture<DescribeEventSourceResponse, DescribeEventSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribeEventSource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.DescribeEventSource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEventSourceResponse, _>(
+                    response,
+                    "DescribeEventSource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeEventSourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>An SaaS partner can use this operation to list details about a partner event source that they have created.</p> <note> <p>AWS customers do not use this operation. Instead, AWS customers can use <a>DescribeEventSource</a> to see details about a partner event source that is shared with them.</p> </note></p>
+    fn describe_partner_event_source(
+        &self,
+        input: DescribePartnerEventSourceRequest,
+    ) -> RusotoFuture<DescribePartnerEventSourceResponse, DescribePartnerEventSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribePartnerEventSource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.DescribePartnerEventSource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePartnerEventSourceResponse, _>(
+                    response,
+                    "DescribePartnerEventSource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribePartnerEventSourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the specified rule.</p> <p> <code>DescribeRule</code> doesn't list the targets of a rule. To see the targets associated with a rule, use <a>ListTargetsByRule</a>.</p>
+    fn describe_rule(
+        &self,
+        input: DescribeRuleRequest,
+    ) -> RusotoFuture<DescribeRuleResponse, DescribeRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribeRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.DescribeRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRuleResponse, _>(
+                    response,
+                    "DescribeRule",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables the specified rule. A disabled rule won't match any events and won't self-trigger if it has a schedule expression.</p> <p>When you disable a rule, incoming events might continue to match to the disabled rule. Allow a short period of time for changes to take effect.</p>
+    fn disable_rule(&self, input: DisableRuleRequest) -> RusotoFuture<(), DisableRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DisableRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.DisableRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisableRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables the specified rule. If the rule doesn't exist, the operation fails.</p> <p>When you enable a rule, incoming events might not immediately start matching to a newly enabled rule. Allow a short period of time for changes to take effect.</p>
+    fn enable_rule(&self, input: EnableRuleRequest) -> RusotoFuture<(), EnableRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("EnableRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.EnableRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Lists all the event buses in your account, including the default event bus, custom event buses, and partner event buses.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
+    fn list_event_buses(
+        &self,
+        input: ListEventBusesRequest,
+    ) -> RusotoFuture<ListEventBusesResponse, ListEventBusesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListEventBuses");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.ListEventBuses");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListEventBusesResponse, _>(
+                    response,
+                    "ListEventBuses",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListEventBusesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>You can use this to see all the partner event sources that have been shared with your AWS account. For more information about partner event sources, see <a>CreateEventBus</a>.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
+    fn list_event_sources(
+        &self,
+        input: ListEventSourcesRequest,
+    ) -> RusotoFuture<ListEventSourcesResponse, ListEventSourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListEventSources");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.ListEventSources");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListEventSourcesResponse, _>(
+                    response,
+                    "ListEventSources",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListEventSourcesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>An SaaS partner can use this operation to display the AWS account ID that a particular partner event source name is associated with.</p> <note> <p>This operation is used by SaaS partners, not by AWS customers.</p> </note></p>
+    fn list_partner_event_source_accounts(
+        &self,
+        input: ListPartnerEventSourceAccountsRequest,
+    ) -> RusotoFuture<ListPartnerEventSourceAccountsResponse, ListPartnerEventSourceAccountsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListPartnerEventSourceAccounts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSourceAccounts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPartnerEventSourceAccountsResponse, _>(
+                    response,
+                    "ListPartnerEventSourceAccounts",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListPartnerEventSourceAccountsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>An SaaS partner can use this operation to list all the partner event source names that they have created.</p> <note> <p>This operation is not used by AWS customers.</p> </note></p>
+    fn list_partner_event_sources(
+        &self,
+        input: ListPartnerEventSourcesRequest,
+    ) -> RusotoFuture<ListPartnerEventSourcesResponse, ListPartnerEventSourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListPartnerEventSources");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSources");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPartnerEventSourcesResponse, _>(
+                    response,
+                    "ListPartnerEventSources",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListPartnerEventSourcesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the rules for the specified target. You can see which rules can invoke a specific target in your account.</p>
+    fn list_rule_names_by_target(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> RusotoFuture<ListRuleNamesByTargetResponse, ListRuleNamesByTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListRuleNamesByTarget");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.ListRuleNamesByTarget");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRuleNamesByTargetResponse, _>(
+                    response,
+                    "ListRuleNamesByTarget",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListRuleNamesByTargetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists your EventBridge rules. You can either list all the rules or provide a prefix to match to the rule names.</p> <p> <code>ListRules</code> doesn't list the targets of a rule. To see the targets associated with a rule, use <a>ListTargetsByRule</a>.</p>
+    fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListRules");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.ListRules");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRulesResponse, _>(response, "ListRules")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRulesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Displays the tags associated with an EventBridge resource. In EventBridge, rules can be tagged.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the targets assigned to the specified rule.</p>
+    fn list_targets_by_rule(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> RusotoFuture<ListTargetsByRuleResponse, ListTargetsByRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListTargetsByRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.ListTargetsByRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTargetsByRuleResponse, _>(
+                    response,
+                    "ListTargetsByRule",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTargetsByRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sends custom events to EventBridge so that they can be matched to rules. These events can be from your custom applications and services.</p>
+    fn put_events(
+        &self,
+        input: PutEventsRequest,
+    ) -> RusotoFuture<PutEventsResponse, PutEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.PutEvents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutEventsResponse, _>(response, "PutEvents")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutEventsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>This is used by SaaS partners to write events to a customer&#39;s partner event bus.</p> <note> <p>AWS customers do not use this operation. Instead, AWS customers can use <a>PutEvents</a> to write custom events from their own applications to an event bus.</p> </note></p>
+    fn put_partner_events(
+        &self,
+        input: PutPartnerEventsRequest,
+    ) -> RusotoFuture<PutPartnerEventsResponse, PutPartnerEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutPartnerEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.PutPartnerEvents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutPartnerEventsResponse, _>(
+                    response,
+                    "PutPartnerEvents",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutPartnerEventsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Running <code>PutPermission</code> permits the specified AWS account or AWS organization to put events to the specified <i>event bus</i>. Rules in your account are triggered by these events arriving to an event bus in your account. </p> <p>For another account to send events to your account, that external account must have a rule with your account's event bus as a target.</p> <p>To enable multiple AWS accounts to put events to an event bus, run <code>PutPermission</code> once for each of these accounts. Or, if all the accounts are members of the same AWS organization, you can run <code>PutPermission</code> once specifying <code>Principal</code> as "*" and specifying the AWS organization ID in <code>Condition</code>, to grant permissions to all accounts in that organization.</p> <p>If you grant permissions using an organization, then accounts in that organization must specify a <code>RoleArn</code> with proper permissions when they use <code>PutTarget</code> to add your account's event bus as a target. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>The permission policy on an event bus can't exceed 10 KB in size.</p>
+    fn put_permission(&self, input: PutPermissionRequest) -> RusotoFuture<(), PutPermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutPermission");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.PutPermission");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutPermissionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates or updates the specified rule. Rules are enabled by default or based on value of the state. You can disable a rule using <a>DisableRule</a>.</p> <p>A single rule watches for events from a single event bus. Events generated by AWS services go to your account's default event bus. Events generated by SaaS partner services or applications go to the matching partner event bus. If you have custom applications or services, you can specify whether their events go to your default event bus or a custom event bus that you have created. For more information, see <a>CreateEventBus</a>.</p> <p>If you're updating an existing rule, the rule is replaced with what you specify in this <code>PutRule</code> command. If you omit arguments in <code>PutRule</code>, the old values for those arguments aren't kept. Instead, they're replaced with null values.</p> <p>When you create or update a rule, incoming events might not immediately start matching to new or updated rules. Allow a short period of time for changes to take effect.</p> <p>A rule must contain at least an <code>EventPattern</code> or <code>ScheduleExpression</code>. Rules with <code>EventPatterns</code> are triggered when a matching event is observed. Rules with <code>ScheduleExpressions</code> self-trigger based on the given schedule. A rule can have both an <code>EventPattern</code> and a <code>ScheduleExpression</code>, in which case the rule triggers on matching events as well as on a schedule.</p> <p>When you initially create a rule, you can optionally assign one or more tags to the rule. Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only rules with certain tag values. To use the <code>PutRule</code> operation and assign tags, you must have both the <code>events:PutRule</code> and <code>events:TagResource</code> permissions.</p> <p>If you are updating an existing rule, any tags you specify in the <code>PutRule</code> operation are ignored. To update the tags of an existing rule, use <a>TagResource</a> and <a>UntagResource</a>.</p> <p>Most services in AWS treat <code>:</code> or <code>/</code> as the same character in Amazon Resource Names (ARNs). However, EventBridge uses an exact match in event patterns and rules. Be sure to use the correct ARN characters when creating event patterns so that they match the ARN syntax in the event that you want to match.</p> <p>In EventBridge, you could create rules that lead to infinite loops, where a rule is fired repeatedly. For example, a rule might detect that ACLs have changed on an S3 bucket, and trigger software to change them to the desired state. If you don't write the rule carefully, the subsequent change to the ACLs fires the rule again, creating an infinite loop.</p> <p>To prevent this, write the rules so that the triggered actions don't refire the same rule. For example, your rule could fire only if ACLs are found to be in a bad state, instead of after any change. </p> <p>An infinite loop can quickly cause higher than expected charges. We recommend that you use budgeting, which alerts you when charges exceed your specified limit. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/budgets-managing-costs.html">Managing Your Costs with Budgets</a>.</p>
+    fn put_rule(&self, input: PutRuleRequest) -> RusotoFuture<PutRuleResponse, PutRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.PutRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutRuleResponse, _>(response, "PutRule")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds the specified targets to the specified rule, or updates the targets if they're already associated with the rule.</p> <p>Targets are the resources that are invoked when a rule is triggered.</p> <p>You can configure the following as targets in EventBridge:</p> <ul> <li> <p>EC2 instances</p> </li> <li> <p>SSM Run Command</p> </li> <li> <p>SSM Automation</p> </li> <li> <p>AWS Lambda functions</p> </li> <li> <p>Data streams in Amazon Kinesis Data Streams</p> </li> <li> <p>Data delivery streams in Amazon Kinesis Data Firehose</p> </li> <li> <p>Amazon ECS tasks</p> </li> <li> <p>AWS Step Functions state machines</p> </li> <li> <p>AWS Batch jobs</p> </li> <li> <p>AWS CodeBuild projects</p> </li> <li> <p>Pipelines in AWS CodePipeline</p> </li> <li> <p>Amazon Inspector assessment templates</p> </li> <li> <p>Amazon SNS topics</p> </li> <li> <p>Amazon SQS queues, including FIFO queues</p> </li> <li> <p>The default event bus of another AWS account</p> </li> </ul> <p>Creating rules with built-in targets is supported only on the AWS Management Console. The built-in targets are <code>EC2 CreateSnapshot API call</code>, <code>EC2 RebootInstances API call</code>, <code>EC2 StopInstances API call</code>, and <code>EC2 TerminateInstances API call</code>. </p> <p>For some target types, <code>PutTargets</code> provides target-specific parameters. If the target is a Kinesis data stream, you can optionally specify which shard the event goes to by using the <code>KinesisParameters</code> argument. To invoke a command on multiple EC2 instances with one rule, you can use the <code>RunCommandParameters</code> field.</p> <p>To be able to make API calls against the resources that you own, Amazon EventBridge needs the appropriate permissions. For AWS Lambda and Amazon SNS resources, EventBridge relies on resource-based policies. For EC2 instances, Kinesis data streams, and AWS Step Functions state machines, EventBridge relies on IAM roles that you specify in the <code>RoleARN</code> argument in <code>PutTargets</code>. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/auth-and-access-control-eventbridge.html">Authentication and Access Control</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>If another AWS account is in the same Region and has granted you permission (using <code>PutPermission</code>), you can send events to that account. Set that account's event bus as a target of the rules in your account. To send the matched events to the other account, specify that account's event bus as the <code>Arn</code> value when you run <code>PutTargets</code>. If your account sends events to another account, your account is charged for each sent event. Each event sent to another account is charged as a custom event. The account receiving the event isn't charged. For more information, see <a href="https://aws.amazon.com/eventbridge/pricing/">Amazon EventBridge Pricing</a>.</p> <p>If you're setting an event bus in another account as the target and that account granted permission to your account through an organization instead of directly by the account ID, you must specify a <code>RoleArn</code> with proper permissions in the <code>Target</code> structure. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>For more information about enabling cross-account events, see <a>PutPermission</a>.</p> <p> <code>Input</code>, <code>InputPath</code>, and <code>InputTransformer</code> are mutually exclusive and optional parameters of a target. When a rule is triggered due to a matched event:</p> <ul> <li> <p>If none of the following arguments are specified for a target, the entire event is passed to the target in JSON format (unless the target is Amazon EC2 Run Command or Amazon ECS task, in which case nothing from the event is passed to the target).</p> </li> <li> <p>If <code>Input</code> is specified in the form of valid JSON, then the matched event is overridden with this constant.</p> </li> <li> <p>If <code>InputPath</code> is specified in the form of JSONPath (for example, <code>$.detail</code>), only the part of the event specified in the path is passed to the target (for example, only the detail part of the event is passed).</p> </li> <li> <p>If <code>InputTransformer</code> is specified, one or more specified JSONPaths are extracted from the event and used as values in a template that you specify as the input to the target.</p> </li> </ul> <p>When you specify <code>InputPath</code> or <code>InputTransformer</code>, you must use JSON dot notation, not bracket notation.</p> <p>When you add targets to a rule and the associated rule triggers soon after, new or updated targets might not be immediately invoked. Allow a short period of time for changes to take effect.</p> <p>This action can partially fail if too many requests are made at the same time. If that happens, <code>FailedEntryCount</code> is nonzero in the response, and each entry in <code>FailedEntries</code> provides the ID of the failed target and the error code.</p>
+    fn put_targets(
+        &self,
+        input: PutTargetsRequest,
+    ) -> RusotoFuture<PutTargetsResponse, PutTargetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutTargets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.PutTargets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutTargetsResponse, _>(response, "PutTargets")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutTargetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Revokes the permission of another AWS account to be able to put events to the specified event bus. Specify the account to revoke by the <code>StatementId</code> value that you associated with the account when you granted it permission with <code>PutPermission</code>. You can find the <code>StatementId</code> by using <a>DescribeEventBus</a>.</p>
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("RemovePermission");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.RemovePermission");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
                 Box::new(
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(PutRuleError::from_response(response))),
+                        .and_then(|response| Err(RemovePermissionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the specified targets from the specified rule. When the rule is triggered, those targets are no longer be invoked.</p> <p>When you remove a target, when the associated rule triggers, removed targets might continue to be invoked. Allow a short period of time for changes to take effect.</p> <p>This action can partially fail if too many requests are made at the same time. If that happens, <code>FailedEntryCount</code> is non-zero in the response and each entry in <code>FailedEntries</code> provides the ID of the failed target and the error code.</p>
+    fn remove_targets(
+        &self,
+        input: RemoveTargetsRequest,
+    ) -> RusotoFuture<RemoveTargetsResponse, RemoveTargetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("RemoveTargets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.RemoveTargets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RemoveTargetsResponse, _>(
+                    response,
+                    "RemoveTargets",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RemoveTargetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Assigns one or more tags (key-value pairs) to the specified EventBridge resource. Tags can help you organize and categorize your resources. You can also use them to scope user permissions by granting a user permission to access or change only resources with certain tag values. In EventBridge, rules can be tagged.</p> <p>Tags don't have any semantic meaning to AWS and are interpreted strictly as strings of characters.</p> <p>You can use the <code>TagResource</code> action with a rule that already has tags. If you specify a new tag key for the rule, this tag is appended to the list of tags associated with the rule. If you specify a tag key that is already associated with the rule, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a resource.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Tests whether the specified event pattern matches the provided event.</p> <p>Most services in AWS treat <code>:</code> or <code>/</code> as the same character in Amazon Resource Names (ARNs). However, EventBridge uses an exact match in event patterns and rules. Be sure to use the correct ARN characters when creating event patterns so that they match the ARN syntax in the event that you want to match.</p>
+    fn test_event_pattern(
+        &self,
+        input: TestEventPatternRequest,
+    ) -> RusotoFuture<TestEventPatternResponse, TestEventPatternError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("TestEventPattern");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.TestEventPattern");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TestEventPatternResponse, _>(
+                    response,
+                    "TestEventPattern",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TestEventPatternError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes one or more tags from the specified EventBridge resource. In EventBridge, rules can be tagged.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSEvents.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
                 )
             }
         })
     }
-
-    /// <p>Adds the specified targets to the specified rule, or updates the targets if they're already associated with the rule.</p> <p>Targets are the resources that are invoked when a rule is triggered.</p> <p>You can configure the following as targets in EventBridge:</p> <ul> <li> <p>EC2 instances</p> </li> <li> <p>SSM Run Command</p> </li> <li> <p>SSM Automation</p> </li> <li> <p>AWS Lambda functions</p> </li> <li> <p>Data streams in Amazon Kinesis Data Streams</p> </li> <li> <p>Data delivery streams in Amazon Kinesis Data Firehose</p> </li> <li> <p>Amazon ECS tasks</p> </li> <li> <p>AWS Step Functions state machines</p> </li> <li> <p>AWS Batch jobs</p> </li> <li> <p>AWS CodeBuild projects</p> </li> <li> <p>Pipelines in AWS CodePipeline</p> </li> <li> <p>Amazon Inspector assessment templates</p> </li> <li> <p>Amazon SNS topics</p> </li> <li> <p>Amazon SQS queues, including FIFO queues</p> </li> <li> <p>The default event bus of another AWS account</p> </li> </ul> <p>Creating rules with built-in targets is supported only on the AWS Management Console. The built-in targets are <code>EC2 CreateSnapshot API call</code>, <code>EC2 RebootInstances API call</code>, <code>EC2 StopInstances API call</code>, and <code>EC2 TerminateInstances API call</code>. </p> <p>For some target types, <code>PutTargets</code> provides target-specific parameters. If the target is a Kinesis data stream, you can optionally specify which shard the event goes to by using the <code>KinesisParameters</code> argument. To invoke a command on multiple EC2 instances with one rule, you can use the <code>RunCommandParameters</code> field.</p> <p>To be able to make API calls against the resources that you own, Amazon EventBridge needs the appropriate permissions. For AWS Lambda and Amazon SNS resources, EventBridge relies on resource-based policies. For EC2 instances, Kinesis data streams, and AWS Step Functions state machines, EventBridge relies on IAM roles that you specify in the <code>RoleARN</code> argument in <code>PutTargets</code>. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/auth-and-access-control-eventbridge.html">Authentication and Access Control</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>If another AWS account is in the same Region and has granted you permission (using <code>PutPermission</code>), you can send events to that account. Set that account's event bus as a target of the rules in your account. To send the matched events to the other account, specify that account's event bus as the <code>Arn</code> value when you run <code>PutTargets</code>. If your account sends events to another account, your account is charged for each sent event. Each event sent to another account is charged as a custom event. The account receiving the event isn't charged. For more information, see <a href="https://aws.amazon.com/eventbridge/pricing/">Amazon EventBridge Pricing</a>.</p> <p>If you're setting an event bus in another account as the target and that account granted permission to your account through an organization instead of directly by the account ID, you must specify a <code>RoleArn</code> with proper permissions in the <code>Target</code> structure. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>For more information about enabling cross-account events, see <a>PutPermission</a>.</p> <p> <code>Input</code>, <code>InputPath</code>, and <code>InputTransformer</code> are mutually exclusive and optional parameters of a target. When a rule is triggered due to a matched event:</p> <ul> <li> <p>If none of the following arguments are specified for a target, the entire event is passed to the target in JSON format (unless the target is Amazon EC2 Run Command or Amazon ECS task, in which case nothing from the event is passed to the target).</p> </li> <li> <p>If <code>Input</code> is specified in the form of valid JSON, then the matched event is overridden with this constant.</p> </li> <li> <p>If <code>InputPath</code> is specified in the form of JSONPath (for example, <code>$.detail</code>), only the part of the event specified in the path is passed to the target (for example, only the detail part of the event is passed).</p> </li> <li> <p>If <code>InputTransformer</code> is specified, one or more specified JSONPaths are extracted from the event and used as values in a template that you specify as the input to the target.</p> </li> </ul> <p>When you specify <code>InputPath</code> or <code>InputTransformer</code>, you must use JSON dot notation, not bracket notation.</p> <p>When you add targets to a rule and the associated rule triggers soon after, new or updated targets might not be immediately invoked. Allow a short period of time for changes to take effect.</p> <p>This action can partially fail if too many requests are made at the same time. If that happens, <code>FailedEntryCount</code> is nonzero in the response, and each entry in <code>FailedEntries</code> provides the ID of the failed target and the error code.</p>
+}
+
+/// A mock of the Amazon EventBridge API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockEventBridge {
+    activate_event_source: Option<
+        Box<
+            dyn Fn(
+                    ActivateEventSourceRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<ActivateEventSourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_event_bus: Option<
+        Box<
+            dyn Fn(
+                    CreateEventBusRequest,
+                ) -> ::std::result::Result<
+                    CreateEventBusResponse,
+                    RusotoError<CreateEventBusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_partner_event_source: Option<
+        Box<
+            dyn Fn(
+                    CreatePartnerEventSourceRequest,
+                ) -> ::std::result::Result<
+                    CreatePartnerEventSourceResponse,
+                    RusotoError<CreatePartnerEventSourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deactivate_event_source: Option<
+        Box<
+            dyn Fn(
+                    DeactivateEventSourceRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeactivateEventSourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_event_bus: Option<
+        Box<
+            dyn Fn(
+                    DeleteEventBusRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteEventBusError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_partner_event_source: Option<
+        Box<
+            dyn Fn(
+                    DeletePartnerEventSourceRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeletePartnerEventSourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_rule: Option<
+        Box<
+            dyn Fn(DeleteRuleRequest) -> ::std::result::Result<(), RusotoError<DeleteRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_event_bus: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventBusRequest,
+                ) -> ::std::result::Result<
+                    DescribeEventBusResponse,
+                    RusotoError<DescribeEventBusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_event_source: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventSourceRequest,
+                ) -> ::std::result::Result<
+                    DescribeEventSourceResponse,
+                    RusotoError<DescribeEventSourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_partner_event_source: Option<
+        Box<
+            dyn Fn(
+                    DescribePartnerEventSourceRequest,
+                ) -> ::std::result::Result<
+                    DescribePartnerEventSourceResponse,
+                    RusotoError<DescribePartnerEventSourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_rule: Option<
+        Box<
+            dyn Fn(
+                    DescribeRuleRequest,
+                )
+                    -> ::std::result::Result<DescribeRuleResponse, RusotoError<DescribeRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_rule: Option<
+        Box<
+            dyn Fn(DisableRuleRequest) -> ::std::result::Result<(), RusotoError<DisableRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    enable_rule: Option<
+        Box<
+            dyn Fn(EnableRuleRequest) -> ::std::result::Result<(), RusotoError<EnableRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_event_buses: Option<
+        Box<
+            dyn Fn(
+                    ListEventBusesRequest,
+                ) -> ::std::result::Result<
+                    ListEventBusesResponse,
+                    RusotoError<ListEventBusesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_event_sources: Option<
+        Box<
+            dyn Fn(
+                    ListEventSourcesRequest,
+                ) -> ::std::result::Result<
+                    ListEventSourcesResponse,
+                    RusotoError<ListEventSourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_partner_event_source_accounts: Option<
+        Box<
+            dyn Fn(
+                    ListPartnerEventSourceAccountsRequest,
+                ) -> ::std::result::Result<
+                    ListPartnerEventSourceAccountsResponse,
+                    RusotoError<ListPartnerEventSourceAccountsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_partner_event_sources: Option<
+        Box<
+            dyn Fn(
+                    ListPartnerEventSourcesRequest,
+                ) -> ::std::result::Result<
+                    ListPartnerEventSourcesResponse,
+                    RusotoError<ListPartnerEventSourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_rule_names_by_target: Option<
+        Box<
+            dyn Fn(
+                    ListRuleNamesByTargetRequest,
+                ) -> ::std::result::Result<
+                    ListRuleNamesByTargetResponse,
+                    RusotoError<ListRuleNamesByTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_rules: Option<
+        Box<
+            dyn Fn(
+                    ListRulesRequest,
+                )
+                    -> ::std::result::Result<ListRulesResponse, RusotoError<ListRulesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_targets_by_rule: Option<
+        Box<
+            dyn Fn(
+                    ListTargetsByRuleRequest,
+                ) -> ::std::result::Result<
+                    ListTargetsByRuleResponse,
+                    RusotoError<ListTargetsByRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_events: Option<
+        Box<
+            dyn Fn(
+                    PutEventsRequest,
+                )
+                    -> ::std::result::Result<PutEventsResponse, RusotoError<PutEventsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_partner_events: Option<
+        Box<
+            dyn Fn(
+                    PutPartnerEventsRequest,
+                ) -> ::std::result::Result<
+                    PutPartnerEventsResponse,
+                    RusotoError<PutPartnerEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_permission: Option<
+        Box<
+            dyn Fn(
+                    PutPermissionRequest,
+                ) -> ::std::result::Result<(), RusotoError<PutPermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_rule: Option<
+        Box<
+            dyn Fn(
+                    PutRuleRequest,
+                )
+                    -> ::std::result::Result<PutRuleResponse, RusotoError<PutRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_targets: Option<
+        Box<
+            dyn Fn(
+                    PutTargetsRequest,
+                )
+                    -> ::std::result::Result<PutTargetsResponse, RusotoError<PutTargetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_permission: Option<
+        Box<
+            dyn Fn(
+                    RemovePermissionRequest,
+                ) -> ::std::result::Result<(), RusotoError<RemovePermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_targets: Option<
+        Box<
+            dyn Fn(
+                    RemoveTargetsRequest,
+                )
+                    -> ::std::result::Result<RemoveTargetsResponse, RusotoError<RemoveTargetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    test_event_pattern: Option<
+        Box<
+            dyn Fn(
+                    TestEventPatternRequest,
+                ) -> ::std::result::Result<
+                    TestEventPatternResponse,
+                    RusotoError<TestEventPatternError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockEventBridge {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockEventBridge {
+        Default::default()
+    }
+
+    /// Answers `activate_event_source` with what `handler` returns for its input.
+    pub fn with_activate_event_source<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                ActivateEventSourceRequest,
+            ) -> ::std::result::Result<(), RusotoError<ActivateEventSourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.activate_event_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_event_bus` with what `handler` returns for its input.
+    pub fn with_create_event_bus<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                CreateEventBusRequest,
+            )
+                -> ::std::result::Result<CreateEventBusResponse, RusotoError<CreateEventBusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_event_bus = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_partner_event_source` with what `handler` returns for its input.
+    pub fn with_create_partner_event_source<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                CreatePartnerEventSourceRequest,
+            ) -> ::std::result::Result<
+                CreatePartnerEventSourceResponse,
+                RusotoError<CreatePartnerEventSourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_partner_event_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deactivate_event_source` with what `handler` returns for its input.
+    pub fn with_deactivate_event_source<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                DeactivateEventSourceRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeactivateEventSourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deactivate_event_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_event_bus` with what `handler` returns for its input.
+    pub fn with_delete_event_bus<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(DeleteEventBusRequest) -> ::std::result::Result<(), RusotoError<DeleteEventBusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_event_bus = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_partner_event_source` with what `handler` returns for its input.
+    pub fn with_delete_partner_event_source<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                DeletePartnerEventSourceRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeletePartnerEventSourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_partner_event_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_rule` with what `handler` returns for its input.
+    pub fn with_delete_rule<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(DeleteRuleRequest) -> ::std::result::Result<(), RusotoError<DeleteRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_bus` with what `handler` returns for its input.
+    pub fn with_describe_event_bus<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                DescribeEventBusRequest,
+            ) -> ::std::result::Result<
+                DescribeEventBusResponse,
+                RusotoError<DescribeEventBusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_bus = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_source` with what `handler` returns for its input.
+    pub fn with_describe_event_source<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                DescribeEventSourceRequest,
+            ) -> ::std::result::Result<
+                DescribeEventSourceResponse,
+                RusotoError<DescribeEventSourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_partner_event_source` with what `handler` returns for its input.
+    pub fn with_describe_partner_event_source<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                DescribePartnerEventSourceRequest,
+            ) -> ::std::result::Result<
+                DescribePartnerEventSourceResponse,
+                RusotoError<DescribePartnerEventSourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_partner_event_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_rule` with what `handler` returns for its input.
+    pub fn with_describe_rule<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                DescribeRuleRequest,
+            )
+                -> ::std::result::Result<DescribeRuleResponse, RusotoError<DescribeRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_rule` with what `handler` returns for its input.
+    pub fn with_disable_rule<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(DisableRuleRequest) -> ::std::result::Result<(), RusotoError<DisableRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_rule` with what `handler` returns for its input.
+    pub fn with_enable_rule<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(EnableRuleRequest) -> ::std::result::Result<(), RusotoError<EnableRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_event_buses` with what `handler` returns for its input.
+    pub fn with_list_event_buses<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                ListEventBusesRequest,
+            )
+                -> ::std::result::Result<ListEventBusesResponse, RusotoError<ListEventBusesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_event_buses = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_event_sources` with what `handler` returns for its input.
+    pub fn with_list_event_sources<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                ListEventSourcesRequest,
+            ) -> ::std::result::Result<
+                ListEventSourcesResponse,
+                RusotoError<ListEventSourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_event_sources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_partner_event_source_accounts` with what `handler` returns for its input.
+    pub fn with_list_partner_event_source_accounts<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                ListPartnerEventSourceAccountsRequest,
+            ) -> ::std::result::Result<
+                ListPartnerEventSourceAccountsResponse,
+                RusotoError<ListPartnerEventSourceAccountsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_partner_event_source_accounts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_partner_event_sources` with what `handler` returns for its input.
+    pub fn with_list_partner_event_sources<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                ListPartnerEventSourcesRequest,
+            ) -> ::std::result::Result<
+                ListPartnerEventSourcesResponse,
+                RusotoError<ListPartnerEventSourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_partner_event_sources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_rule_names_by_target` with what `handler` returns for its input.
+    pub fn with_list_rule_names_by_target<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                ListRuleNamesByTargetRequest,
+            ) -> ::std::result::Result<
+                ListRuleNamesByTargetResponse,
+                RusotoError<ListRuleNamesByTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_rule_names_by_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_rules` with what `handler` returns for its input.
+    pub fn with_list_rules<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                ListRulesRequest,
+            ) -> ::std::result::Result<ListRulesResponse, RusotoError<ListRulesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_rules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_targets_by_rule` with what `handler` returns for its input.
+    pub fn with_list_targets_by_rule<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                ListTargetsByRuleRequest,
+            ) -> ::std::result::Result<
+                ListTargetsByRuleResponse,
+                RusotoError<ListTargetsByRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_targets_by_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_events` with what `handler` returns for its input.
+    pub fn with_put_events<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                PutEventsRequest,
+            ) -> ::std::result::Result<PutEventsResponse, RusotoError<PutEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_partner_events` with what `handler` returns for its input.
+    pub fn with_put_partner_events<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                PutPartnerEventsRequest,
+            ) -> ::std::result::Result<
+                PutPartnerEventsResponse,
+                RusotoError<PutPartnerEventsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_partner_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_permission` with what `handler` returns for its input.
+    pub fn with_put_permission<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(PutPermissionRequest) -> ::std::result::Result<(), RusotoError<PutPermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_rule` with what `handler` returns for its input.
+    pub fn with_put_rule<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(PutRuleRequest) -> ::std::result::Result<PutRuleResponse, RusotoError<PutRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_targets` with what `handler` returns for its input.
+    pub fn with_put_targets<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                PutTargetsRequest,
+            )
+                -> ::std::result::Result<PutTargetsResponse, RusotoError<PutTargetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_permission` with what `handler` returns for its input.
+    pub fn with_remove_permission<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                RemovePermissionRequest,
+            ) -> ::std::result::Result<(), RusotoError<RemovePermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_targets` with what `handler` returns for its input.
+    pub fn with_remove_targets<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                RemoveTargetsRequest,
+            )
+                -> ::std::result::Result<RemoveTargetsResponse, RusotoError<RemoveTargetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_event_pattern` with what `handler` returns for its input.
+    pub fn with_test_event_pattern<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                TestEventPatternRequest,
+            ) -> ::std::result::Result<
+                TestEventPatternResponse,
+                RusotoError<TestEventPatternError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.test_event_pattern = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockEventBridge
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl EventBridge for MockEventBridge {
+    fn activate_event_source(
+        &self,
+        input: ActivateEventSourceRequest,
+    ) -> RusotoFuture<(), ActivateEventSourceError> {
+        match self.activate_event_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for activate_event_source"),
+        }
+    }
+    fn create_event_bus(
+        &self,
+        input: CreateEventBusRequest,
+    ) -> RusotoFuture<CreateEventBusResponse, CreateEventBusError> {
+        match self.create_event_bus {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for create_event_bus"),
+        }
+    }
+    fn create_partner_event_source(
+        &self,
+        input: CreatePartnerEventSourceRequest,
+    ) -> RusotoFuture<CreatePartnerEventSourceResponse, CreatePartnerEventSourceError> {
+        match self.create_partner_event_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for create_partner_event_source"),
+        }
+    }
+    fn deactivate_event_source(
+        &self,
+        input: DeactivateEventSourceRequest,
+    ) -> RusotoFuture<(), DeactivateEventSourceError> {
+        match self.deactivate_event_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for deactivate_event_source"),
+        }
+    }
+    fn delete_event_bus(
+        &self,
+        input: DeleteEventBusRequest,
+    ) -> RusotoFuture<(), DeleteEventBusError> {
+        match self.delete_event_bus {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for delete_event_bus"),
+        }
+    }
+    fn delete_partner_event_source(
+        &self,
+        input: DeletePartnerEventSourceRequest,
+    ) -> RusotoFuture<(), DeletePartnerEventSourceError> {
+        match self.delete_partner_event_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for delete_partner_event_source"),
+        }
+    }
+    fn delete_rule(&self, input: DeleteRuleRequest) -> RusotoFuture<(), DeleteRuleError> {
+        match self.delete_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for delete_rule"),
+        }
+    }
+    fn describe_event_bus(
+        &self,
+        input: DescribeEventBusRequest,
+    ) -> RusotoFuture<DescribeEventBusResponse, DescribeEventBusError> {
+        match self.describe_event_bus {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for describe_event_bus"),
+        }
+    }
+    fn describe_event_source(
+        &self,
+        input: DescribeEventSourceRequest,
+    ) -> RusotoFuture<DescribeEventSourceResponse, DescribeEventSourceError> {
+        match self.describe_event_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for describe_event_source"),
+        }
+    }
+    fn describe_partner_event_source(
+        &self,
+        input: DescribePartnerEventSourceRequest,
+    ) -> RusotoFuture<DescribePartnerEventSourceResponse, DescribePartnerEventSourceError> {
+        match self.describe_partner_event_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for describe_partner_event_source"),
+        }
+    }
+    fn describe_rule(
+        &self,
+        input: DescribeRuleRequest,
+    ) -> RusotoFuture<DescribeRuleResponse, DescribeRuleError> {
+        match self.describe_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for describe_rule"),
+        }
+    }
+    fn disable_rule(&self, input: DisableRuleRequest) -> RusotoFuture<(), DisableRuleError> {
+        match self.disable_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for disable_rule"),
+        }
+    }
+    fn enable_rule(&self, input: EnableRuleRequest) -> RusotoFuture<(), EnableRuleError> {
+        match self.enable_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for enable_rule"),
+        }
+    }
+    fn list_event_buses(
+        &self,
+        input: ListEventBusesRequest,
+    ) -> RusotoFuture<ListEventBusesResponse, ListEventBusesError> {
+        match self.list_event_buses {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for list_event_buses"),
+        }
+    }
+    fn list_event_sources(
+        &self,
+        input: ListEventSourcesRequest,
+    ) -> RusotoFuture<ListEventSourcesResponse, ListEventSourcesError> {
+        match self.list_event_sources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for list_event_sources"),
+        }
+    }
+    fn list_partner_event_source_accounts(
+        &self,
+        input: ListPartnerEventSourceAccountsRequest,
+    ) -> RusotoFuture<ListPartnerEventSourceAccountsResponse, ListPartnerEventSourceAccountsError>
+    {
+        match self.list_partner_event_source_accounts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for list_partner_event_source_accounts"),
+        }
+    }
+    fn list_partner_event_sources(
+        &self,
+        input: ListPartnerEventSourcesRequest,
+    ) -> RusotoFuture<ListPartnerEventSourcesResponse, ListPartnerEventSourcesError> {
+        match self.list_partner_event_sources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for list_partner_event_sources"),
+        }
+    }
+    fn list_rule_names_by_target(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> RusotoFuture<ListRuleNamesByTargetResponse, ListRuleNamesByTargetError> {
+        match self.list_rule_names_by_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for list_rule_names_by_target"),
+        }
+    }
+    fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
+        match self.list_rules {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for list_rules"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_targets_by_rule(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> RusotoFuture<ListTargetsByRuleResponse, ListTargetsByRuleError> {
+        match self.list_targets_by_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for list_targets_by_rule"),
+        }
+    }
+    fn put_events(
+        &self,
+        input: PutEventsRequest,
+    ) -> RusotoFuture<PutEventsResponse, PutEventsError> {
+        match self.put_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for put_events"),
+        }
+    }
+    fn put_partner_events(
+        &self,
+        input: PutPartnerEventsRequest,
+    ) -> RusotoFuture<PutPartnerEventsResponse, PutPartnerEventsError> {
+        match self.put_partner_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for put_partner_events"),
+        }
+    }
+    fn put_permission(&self, input: PutPermissionRequest) -> RusotoFuture<(), PutPermissionError> {
+        match self.put_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for put_permission"),
+        }
+    }
+    fn put_rule(&self, input: PutRuleRequest) -> RusotoFuture<PutRuleResponse, PutRuleError> {
+        match self.put_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for put_rule"),
+        }
+    }
     fn put_targets(
         &self,
         input: PutTargetsRequest,
     ) -> RusotoFuture<PutTargetsResponse, PutTargetsError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.PutTargets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutTargetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutTargetsError::from_response(response))),
-                )
-            }
-        })
+        match self.put_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for put_targets"),
+        }
     }
-
-    /// <p>Revokes the permission of another AWS account to be able to put events to the specified event bus. Specify the account to revoke by the <code>StatementId</code> value that you associated with the account when you granted it permission with <code>PutPermission</code>. You can find the <code>StatementId</code> by using <a>DescribeEventBus</a>.</p>
     fn remove_permission(
         &self,
         input: RemovePermissionRequest,
     ) -> RusotoFuture<(), RemovePermissionError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.RemovePermission");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RemovePermissionError::from_response(response))),
-                )
-            }
-        })
+        match self.remove_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for remove_permission"),
+        }
     }
-
-    /// <p>Removes the specified targets from the specified rule. When the rule is triggered, those targets are no longer be invoked.</p> <p>When you remove a target, when the associated rule triggers, removed targets might continue to be invoked. Allow a short period of time for changes to take effect.</p> <p>This action can partially fail if too many requests are made at the same time. If that happens, <code>FailedEntryCount</code> is non-zero in the response and each entry in <code>FailedEntries</code> provides the ID of the failed target and the error code.</p>
     fn remove_targets(
         &self,
         input: RemoveTargetsRequest,
     ) -> RusotoFuture<RemoveTargetsResponse, RemoveTargetsError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.RemoveTargets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveTargetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RemoveTargetsError::from_response(response))),
-                )
-            }
-        })
+        match self.remove_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for remove_targets"),
+        }
     }
-
-    /// <p>Assigns one or more tags (key-value pairs) to the specified EventBridge resource. Tags can help you organize and categorize your resources. You can also use them to scope user permissions by granting a user permission to access or change only resources with certain tag values. In EventBridge, rules can be tagged.</p> <p>Tags don't have any semantic meaning to AWS and are interpreted strictly as strings of characters.</p> <p>You can use the <code>TagResource</code> action with a rule that already has tags. If you specify a new tag key for the rule, this tag is appended to the list of tags associated with the rule. If you specify a tag key that is already associated with the rule, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a resource.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Tests whether the specified event pattern matches the provided event.</p> <p>Most services in AWS treat <code>:</code> or <code>/</code> as the same character in Amazon Resource Names (ARNs). However, EventBridge uses an exact match in event patterns and rules. Be sure to use the correct ARN characters when creating event patterns so that they match the ARN syntax in the event that you want to match.</p>
     fn test_event_pattern(
         &self,
         input: TestEventPatternRequest,
     ) -> RusotoFuture<TestEventPatternResponse, TestEventPatternError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.TestEventPattern");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TestEventPatternResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TestEventPatternError::from_response(response))),
-                )
-            }
-        })
+        match self.test_event_pattern {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for test_event_pattern"),
+        }
     }
-
-    /// <p>Removes one or more tags from the specified EventBridge resource. In EventBridge, rules can be tagged.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "events", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSEvents.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockEventBridge has no handler for untag_resource"),
+        }
     }
 }
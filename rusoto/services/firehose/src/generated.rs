@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Describes hints for the buffering to perform before delivering data to the destination. These options are treated as hints, and therefore Kinesis Data Firehose might choose to use different values when it is optimal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -36,6 +37,27 @@ pub struct BufferingHints {
     pub size_in_m_bs: Option<i64>,
 }
 
+impl Validate for BufferingHints {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.interval_in_seconds {
+            errors.check_range(
+                &field_path(path, "interval_in_seconds"),
+                *value,
+                None,
+                Some(900),
+            );
+        }
+        if let Some(ref value) = self.size_in_m_bs {
+            errors.check_range(
+                &field_path(path, "size_in_m_bs"),
+                *value,
+                Some(1),
+                Some(128),
+            );
+        }
+    }
+}
+
 /// <p>Describes the Amazon CloudWatch logging options for your delivery stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CloudWatchLoggingOptions {
@@ -53,6 +75,33 @@ pub struct CloudWatchLoggingOptions {
     pub log_stream_name: Option<String>,
 }
 
+impl Validate for CloudWatchLoggingOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.log_group_name {
+            errors.check_length(
+                &field_path(path, "log_group_name"),
+                value.chars().count(),
+                None,
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "log_group_name"),
+                &value,
+                "[\\.\\-_/#A-Za-z0-9]*",
+            );
+        }
+        if let Some(ref value) = self.log_stream_name {
+            errors.check_length(
+                &field_path(path, "log_stream_name"),
+                value.chars().count(),
+                None,
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "log_stream_name"), &value, "[^:*]*");
+        }
+    }
+}
+
 /// <p>Describes a <code>COPY</code> command for Amazon Redshift.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CopyCommand {
@@ -69,7 +118,42 @@ pub struct CopyCommand {
     pub data_table_name: String,
 }
 
+impl Validate for CopyCommand {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.copy_options {
+            errors.check_length(
+                &field_path(path, "copy_options"),
+                value.chars().count(),
+                None,
+                Some(204800),
+            );
+            errors.check_pattern(&field_path(path, "copy_options"), &value, ".*");
+        }
+        if let Some(ref value) = self.data_table_columns {
+            errors.check_length(
+                &field_path(path, "data_table_columns"),
+                value.chars().count(),
+                None,
+                Some(204800),
+            );
+            errors.check_pattern(&field_path(path, "data_table_columns"), &value, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "data_table_name"),
+            self.data_table_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_table_name"),
+            &self.data_table_name,
+            ".*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeliveryStreamInput {
     /// <p>The name of the delivery stream. This name must be unique per AWS account in the same AWS Region. If the delivery streams are in different accounts or different Regions, you can have multiple delivery streams with the same name.</p>
     #[serde(rename = "DeliveryStreamName")]
@@ -104,8 +188,61 @@ pub struct CreateDeliveryStreamInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateDeliveryStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.elasticsearch_destination_configuration {
+            value.validate_at(
+                &field_path(path, "elasticsearch_destination_configuration"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.extended_s3_destination_configuration {
+            value.validate_at(
+                &field_path(path, "extended_s3_destination_configuration"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.kinesis_stream_source_configuration {
+            value.validate_at(
+                &field_path(path, "kinesis_stream_source_configuration"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.redshift_destination_configuration {
+            value.validate_at(
+                &field_path(path, "redshift_destination_configuration"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.splunk_destination_configuration {
+            value.validate_at(
+                &field_path(path, "splunk_destination_configuration"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDeliveryStreamOutput {
     /// <p>The ARN of the delivery stream.</p>
     #[serde(rename = "DeliveryStreamARN")]
@@ -134,20 +271,51 @@ pub struct DataFormatConversionConfiguration {
     pub schema_configuration: Option<SchemaConfiguration>,
 }
 
+impl Validate for DataFormatConversionConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.input_format_configuration {
+            value.validate_at(&field_path(path, "input_format_configuration"), errors);
+        }
+        if let Some(ref value) = self.output_format_configuration {
+            value.validate_at(&field_path(path, "output_format_configuration"), errors);
+        }
+        if let Some(ref value) = self.schema_configuration {
+            value.validate_at(&field_path(path, "schema_configuration"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDeliveryStreamInput {
     /// <p>The name of the delivery stream.</p>
     #[serde(rename = "DeliveryStreamName")]
     pub delivery_stream_name: String,
 }
 
+impl Validate for DeleteDeliveryStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDeliveryStreamOutput {}
 
 /// <p>Contains information about a delivery stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeliveryStreamDescription {
     /// <p>The date and time that the delivery stream was created.</p>
     #[serde(rename = "CreateTimestamp")]
@@ -190,7 +358,7 @@ pub struct DeliveryStreamDescription {
 
 /// <p>Indicates the server-side encryption (SSE) status for the delivery stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeliveryStreamEncryptionConfiguration {
     /// <p>For a full description of the different values of this status, see <a>StartDeliveryStreamEncryption</a> and <a>StopDeliveryStreamEncryption</a>.</p>
     #[serde(rename = "Status")]
@@ -199,6 +367,7 @@ pub struct DeliveryStreamEncryptionConfiguration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDeliveryStreamInput {
     /// <p>The name of the delivery stream.</p>
     #[serde(rename = "DeliveryStreamName")]
@@ -213,8 +382,40 @@ pub struct DescribeDeliveryStreamInput {
     pub limit: Option<i64>,
 }
 
+impl Validate for DescribeDeliveryStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.exclusive_start_destination_id {
+            errors.check_length(
+                &field_path(path, "exclusive_start_destination_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "exclusive_start_destination_id"),
+                &value,
+                "[a-zA-Z0-9-]+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(10000));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDeliveryStreamOutput {
     /// <p>Information about the delivery stream.</p>
     #[serde(rename = "DeliveryStreamDescription")]
@@ -234,9 +435,20 @@ pub struct Deserializer {
     pub open_x_json_ser_de: Option<OpenXJsonSerDe>,
 }
 
+impl Validate for Deserializer {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hive_json_ser_de {
+            value.validate_at(&field_path(path, "hive_json_ser_de"), errors);
+        }
+        if let Some(ref value) = self.open_x_json_ser_de {
+            value.validate_at(&field_path(path, "open_x_json_ser_de"), errors);
+        }
+    }
+}
+
 /// <p>Describes the destination for a delivery stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DestinationDescription {
     /// <p>The ID of the destination.</p>
     #[serde(rename = "DestinationId")]
@@ -276,8 +488,30 @@ pub struct ElasticsearchBufferingHints {
     pub size_in_m_bs: Option<i64>,
 }
 
+impl Validate for ElasticsearchBufferingHints {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.interval_in_seconds {
+            errors.check_range(
+                &field_path(path, "interval_in_seconds"),
+                *value,
+                None,
+                Some(900),
+            );
+        }
+        if let Some(ref value) = self.size_in_m_bs {
+            errors.check_range(
+                &field_path(path, "size_in_m_bs"),
+                *value,
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Describes the configuration of a destination in Amazon ES.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ElasticsearchDestinationConfiguration {
     /// <p>The buffering options. If no value is specified, the default values for <code>ElasticsearchBufferingHints</code> are used.</p>
     #[serde(rename = "BufferingHints")]
@@ -320,9 +554,56 @@ pub struct ElasticsearchDestinationConfiguration {
     pub type_name: String,
 }
 
+impl Validate for ElasticsearchDestinationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.buffering_hints {
+            value.validate_at(&field_path(path, "buffering_hints"), errors);
+        }
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            value.validate_at(&field_path(path, "cloud_watch_logging_options"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "domain_arn"),
+            self.domain_arn.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "domain_arn"), &self.domain_arn, "arn:.*");
+        errors.check_length(
+            &field_path(path, "index_name"),
+            self.index_name.chars().count(),
+            Some(1),
+            Some(80),
+        );
+        errors.check_pattern(&field_path(path, "index_name"), &self.index_name, ".*");
+        if let Some(ref value) = self.processing_configuration {
+            value.validate_at(&field_path(path, "processing_configuration"), errors);
+        }
+        if let Some(ref value) = self.retry_options {
+            value.validate_at(&field_path(path, "retry_options"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "role_arn"), &self.role_arn, "arn:.*");
+        self.s3_configuration
+            .validate_at(&field_path(path, "s3_configuration"), errors);
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "type_name"), &self.type_name, ".*");
+    }
+}
+
 /// <p>The destination description in Amazon ES.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ElasticsearchDestinationDescription {
     /// <p>The buffering options.</p>
     #[serde(rename = "BufferingHints")]
@@ -372,6 +653,7 @@ pub struct ElasticsearchDestinationDescription {
 
 /// <p>Describes an update for a destination in Amazon ES.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ElasticsearchDestinationUpdate {
     /// <p>The buffering options. If no value is specified, <code>ElasticsearchBufferingHints</code> object default values are used. </p>
     #[serde(rename = "BufferingHints")]
@@ -415,6 +697,62 @@ pub struct ElasticsearchDestinationUpdate {
     pub type_name: Option<String>,
 }
 
+impl Validate for ElasticsearchDestinationUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.buffering_hints {
+            value.validate_at(&field_path(path, "buffering_hints"), errors);
+        }
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            value.validate_at(&field_path(path, "cloud_watch_logging_options"), errors);
+        }
+        if let Some(ref value) = self.domain_arn {
+            errors.check_length(
+                &field_path(path, "domain_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "domain_arn"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.index_name {
+            errors.check_length(
+                &field_path(path, "index_name"),
+                value.chars().count(),
+                Some(1),
+                Some(80),
+            );
+            errors.check_pattern(&field_path(path, "index_name"), &value, ".*");
+        }
+        if let Some(ref value) = self.processing_configuration {
+            value.validate_at(&field_path(path, "processing_configuration"), errors);
+        }
+        if let Some(ref value) = self.retry_options {
+            value.validate_at(&field_path(path, "retry_options"), errors);
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "role_arn"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.s3_update {
+            value.validate_at(&field_path(path, "s3_update"), errors);
+        }
+        if let Some(ref value) = self.type_name {
+            errors.check_length(
+                &field_path(path, "type_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "type_name"), &value, ".*");
+        }
+    }
+}
+
 /// <p>Configures retry behavior in case Kinesis Data Firehose is unable to deliver documents to Amazon ES.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ElasticsearchRetryOptions {
@@ -424,6 +762,19 @@ pub struct ElasticsearchRetryOptions {
     pub duration_in_seconds: Option<i64>,
 }
 
+impl Validate for ElasticsearchRetryOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration_in_seconds {
+            errors.check_range(
+                &field_path(path, "duration_in_seconds"),
+                *value,
+                None,
+                Some(7200),
+            );
+        }
+    }
+}
+
 /// <p>Describes the encryption for a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionConfiguration {
@@ -437,8 +788,17 @@ pub struct EncryptionConfiguration {
     pub no_encryption_config: Option<String>,
 }
 
+impl Validate for EncryptionConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_encryption_config {
+            value.validate_at(&field_path(path, "kms_encryption_config"), errors);
+        }
+    }
+}
+
 /// <p>Describes the configuration of a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExtendedS3DestinationConfiguration {
     /// <p>The ARN of the S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "BucketARN")]
@@ -488,9 +848,67 @@ pub struct ExtendedS3DestinationConfiguration {
     pub s3_backup_mode: Option<String>,
 }
 
+impl Validate for ExtendedS3DestinationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bucket_arn"),
+            self.bucket_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "bucket_arn"), &self.bucket_arn, "arn:.*");
+        if let Some(ref value) = self.buffering_hints {
+            value.validate_at(&field_path(path, "buffering_hints"), errors);
+        }
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            value.validate_at(&field_path(path, "cloud_watch_logging_options"), errors);
+        }
+        if let Some(ref value) = self.data_format_conversion_configuration {
+            value.validate_at(
+                &field_path(path, "data_format_conversion_configuration"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.encryption_configuration {
+            value.validate_at(&field_path(path, "encryption_configuration"), errors);
+        }
+        if let Some(ref value) = self.error_output_prefix {
+            errors.check_length(
+                &field_path(path, "error_output_prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "error_output_prefix"), &value, ".*");
+        }
+        if let Some(ref value) = self.prefix {
+            errors.check_length(
+                &field_path(path, "prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "prefix"), &value, ".*");
+        }
+        if let Some(ref value) = self.processing_configuration {
+            value.validate_at(&field_path(path, "processing_configuration"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "role_arn"), &self.role_arn, "arn:.*");
+        if let Some(ref value) = self.s3_backup_configuration {
+            value.validate_at(&field_path(path, "s3_backup_configuration"), errors);
+        }
+    }
+}
+
 /// <p>Describes a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExtendedS3DestinationDescription {
     /// <p>The ARN of the S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "BucketARN")]
@@ -539,6 +957,7 @@ pub struct ExtendedS3DestinationDescription {
 
 /// <p>Describes an update for a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExtendedS3DestinationUpdate {
     /// <p>The ARN of the S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "BucketARN")]
@@ -590,6 +1009,68 @@ pub struct ExtendedS3DestinationUpdate {
     pub s3_backup_update: Option<S3DestinationUpdate>,
 }
 
+impl Validate for ExtendedS3DestinationUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket_arn {
+            errors.check_length(
+                &field_path(path, "bucket_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "bucket_arn"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.buffering_hints {
+            value.validate_at(&field_path(path, "buffering_hints"), errors);
+        }
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            value.validate_at(&field_path(path, "cloud_watch_logging_options"), errors);
+        }
+        if let Some(ref value) = self.data_format_conversion_configuration {
+            value.validate_at(
+                &field_path(path, "data_format_conversion_configuration"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.encryption_configuration {
+            value.validate_at(&field_path(path, "encryption_configuration"), errors);
+        }
+        if let Some(ref value) = self.error_output_prefix {
+            errors.check_length(
+                &field_path(path, "error_output_prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "error_output_prefix"), &value, ".*");
+        }
+        if let Some(ref value) = self.prefix {
+            errors.check_length(
+                &field_path(path, "prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "prefix"), &value, ".*");
+        }
+        if let Some(ref value) = self.processing_configuration {
+            value.validate_at(&field_path(path, "processing_configuration"), errors);
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "role_arn"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.s3_backup_update {
+            value.validate_at(&field_path(path, "s3_backup_update"), errors);
+        }
+    }
+}
+
 /// <p>The native Hive / HCatalog JsonSerDe. Used by Kinesis Data Firehose for deserializing data, which means converting it from the JSON format in preparation for serializing it to the Parquet or ORC format. This is one of two deserializers you can choose, depending on which one offers the functionality you need. The other option is the OpenX SerDe.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HiveJsonSerDe {
@@ -599,6 +1080,18 @@ pub struct HiveJsonSerDe {
     pub timestamp_formats: Option<Vec<String>>,
 }
 
+impl Validate for HiveJsonSerDe {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.timestamp_formats {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "timestamp_formats"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(&item_path, &item, "^(?!\\s*$).+");
+            }
+        }
+    }
+}
+
 /// <p>Specifies the deserializer you want to use to convert the format of the input data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputFormatConfiguration {
@@ -608,6 +1101,14 @@ pub struct InputFormatConfiguration {
     pub deserializer: Option<Deserializer>,
 }
 
+impl Validate for InputFormatConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.deserializer {
+            value.validate_at(&field_path(path, "deserializer"), errors);
+        }
+    }
+}
+
 /// <p>Describes an encryption key for a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KMSEncryptionConfig {
@@ -616,8 +1117,25 @@ pub struct KMSEncryptionConfig {
     pub awskms_key_arn: String,
 }
 
+impl Validate for KMSEncryptionConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "awskms_key_arn"),
+            self.awskms_key_arn.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "awskms_key_arn"),
+            &self.awskms_key_arn,
+            "arn:.*",
+        );
+    }
+}
+
 /// <p>The stream and role Amazon Resource Names (ARNs) for a Kinesis data stream used as the source for a delivery stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KinesisStreamSourceConfiguration {
     /// <p>The ARN of the source Kinesis data stream. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-kinesis-streams">Amazon Kinesis Data Streams ARN Format</a>.</p>
     #[serde(rename = "KinesisStreamARN")]
@@ -627,9 +1145,32 @@ pub struct KinesisStreamSourceConfiguration {
     pub role_arn: String,
 }
 
+impl Validate for KinesisStreamSourceConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "kinesis_stream_arn"),
+            self.kinesis_stream_arn.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "kinesis_stream_arn"),
+            &self.kinesis_stream_arn,
+            "arn:.*",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "role_arn"), &self.role_arn, "arn:.*");
+    }
+}
+
 /// <p>Details about a Kinesis data stream used as the source for a Kinesis Data Firehose delivery stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KinesisStreamSourceDescription {
     /// <p>Kinesis Data Firehose starts retrieving records from the Kinesis data stream starting with this timestamp.</p>
     #[serde(rename = "DeliveryStartTimestamp")]
@@ -646,6 +1187,7 @@ pub struct KinesisStreamSourceDescription {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeliveryStreamsInput {
     /// <p>The delivery stream type. This can be one of the following values:</p> <ul> <li> <p> <code>DirectPut</code>: Provider applications access the delivery stream directly.</p> </li> <li> <p> <code>KinesisStreamAsSource</code>: The delivery stream uses a Kinesis data stream as a source.</p> </li> </ul> <p>This parameter is optional. If this parameter is omitted, delivery streams of all types are returned.</p>
     #[serde(rename = "DeliveryStreamType")]
@@ -661,8 +1203,29 @@ pub struct ListDeliveryStreamsInput {
     pub limit: Option<i64>,
 }
 
+impl Validate for ListDeliveryStreamsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_delivery_stream_name {
+            errors.check_length(
+                &field_path(path, "exclusive_start_delivery_stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "exclusive_start_delivery_stream_name"),
+                &value,
+                "[a-zA-Z0-9_.-]+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(10000));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeliveryStreamsOutput {
     /// <p>The names of the delivery streams.</p>
     #[serde(rename = "DeliveryStreamNames")]
@@ -673,6 +1236,7 @@ pub struct ListDeliveryStreamsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForDeliveryStreamInput {
     /// <p>The name of the delivery stream whose tags you want to list.</p>
     #[serde(rename = "DeliveryStreamName")]
@@ -687,8 +1251,40 @@ pub struct ListTagsForDeliveryStreamInput {
     pub limit: Option<i64>,
 }
 
+impl Validate for ListTagsForDeliveryStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.exclusive_start_tag_key {
+            errors.check_length(
+                &field_path(path, "exclusive_start_tag_key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "exclusive_start_tag_key"),
+                &value,
+                "^(?!aws:)[\\p{L}\\p{Z}\\p{N}_.:\\/=+\\-@%]*$",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForDeliveryStreamOutput {
     /// <p>If this is <code>true</code> in the response, more tags are available. To list the remaining tags, set <code>ExclusiveStartTagKey</code> to the key of the last tag returned and call <code>ListTagsForDeliveryStream</code> again.</p>
     #[serde(rename = "HasMoreTags")]
@@ -715,6 +1311,22 @@ pub struct OpenXJsonSerDe {
     pub convert_dots_in_json_keys_to_underscores: Option<bool>,
 }
 
+impl Validate for OpenXJsonSerDe {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.column_to_json_key_mappings {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "column_to_json_key_mappings"),
+                    key
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(&item_path, &item, "^(?!\\s*$).+");
+            }
+        }
+    }
+}
+
 /// <p>A serializer to use for converting data to the ORC format before storing it in Amazon S3. For more information, see <a href="https://orc.apache.org/docs/">Apache ORC</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrcSerDe {
@@ -760,6 +1372,66 @@ pub struct OrcSerDe {
     pub stripe_size_bytes: Option<i64>,
 }
 
+impl Validate for OrcSerDe {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.block_size_bytes {
+            errors.check_range(
+                &field_path(path, "block_size_bytes"),
+                *value,
+                Some(67108864),
+                None,
+            );
+        }
+        if let Some(ref value) = self.bloom_filter_columns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "bloom_filter_columns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(&item_path, &item, "^\\S+$");
+            }
+        }
+        if let Some(ref value) = self.bloom_filter_false_positive_probability {
+            errors.check_range(
+                &field_path(path, "bloom_filter_false_positive_probability"),
+                *value,
+                None,
+                Some(1.0),
+            );
+        }
+        if let Some(ref value) = self.dictionary_key_threshold {
+            errors.check_range(
+                &field_path(path, "dictionary_key_threshold"),
+                *value,
+                None,
+                Some(1.0),
+            );
+        }
+        if let Some(ref value) = self.padding_tolerance {
+            errors.check_range(
+                &field_path(path, "padding_tolerance"),
+                *value,
+                None,
+                Some(1.0),
+            );
+        }
+        if let Some(ref value) = self.row_index_stride {
+            errors.check_range(
+                &field_path(path, "row_index_stride"),
+                *value,
+                Some(1000),
+                None,
+            );
+        }
+        if let Some(ref value) = self.stripe_size_bytes {
+            errors.check_range(
+                &field_path(path, "stripe_size_bytes"),
+                *value,
+                Some(8388608),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Specifies the serializer that you want Kinesis Data Firehose to use to convert the format of your data before it writes it to Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputFormatConfiguration {
@@ -769,6 +1441,14 @@ pub struct OutputFormatConfiguration {
     pub serializer: Option<Serializer>,
 }
 
+impl Validate for OutputFormatConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.serializer {
+            value.validate_at(&field_path(path, "serializer"), errors);
+        }
+    }
+}
+
 /// <p>A serializer to use for converting data to the Parquet format before storing it in Amazon S3. For more information, see <a href="https://parquet.apache.org/documentation/latest/">Apache Parquet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParquetSerDe {
@@ -798,6 +1478,27 @@ pub struct ParquetSerDe {
     pub writer_version: Option<String>,
 }
 
+impl Validate for ParquetSerDe {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.block_size_bytes {
+            errors.check_range(
+                &field_path(path, "block_size_bytes"),
+                *value,
+                Some(67108864),
+                None,
+            );
+        }
+        if let Some(ref value) = self.page_size_bytes {
+            errors.check_range(
+                &field_path(path, "page_size_bytes"),
+                *value,
+                Some(65536),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Describes a data processing configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProcessingConfiguration {
@@ -811,6 +1512,17 @@ pub struct ProcessingConfiguration {
     pub processors: Option<Vec<Processor>>,
 }
 
+impl Validate for ProcessingConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.processors {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "processors"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Describes a data processor.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Processor {
@@ -823,6 +1535,17 @@ pub struct Processor {
     pub type_: String,
 }
 
+impl Validate for Processor {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.parameters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Describes the processor parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProcessorParameter {
@@ -834,7 +1557,24 @@ pub struct ProcessorParameter {
     pub parameter_value: String,
 }
 
+impl Validate for ProcessorParameter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "parameter_value"),
+            self.parameter_value.chars().count(),
+            Some(1),
+            Some(5120),
+        );
+        errors.check_pattern(
+            &field_path(path, "parameter_value"),
+            &self.parameter_value,
+            "^(?!\\s*$).+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRecordBatchInput {
     /// <p>The name of the delivery stream.</p>
     #[serde(rename = "DeliveryStreamName")]
@@ -844,8 +1584,34 @@ pub struct PutRecordBatchInput {
     pub records: Vec<Record>,
 }
 
+impl Validate for PutRecordBatchInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "records"),
+            self.records.len(),
+            Some(1),
+            Some(500),
+        );
+        for (i, item) in self.records.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "records"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRecordBatchOutput {
     /// <p>Indicates whether server-side encryption (SSE) was enabled during this operation.</p>
     #[serde(rename = "Encrypted")]
@@ -861,7 +1627,7 @@ pub struct PutRecordBatchOutput {
 
 /// <p>Contains the result for an individual record from a <a>PutRecordBatch</a> request. If the record is successfully added to your delivery stream, it receives a record ID. If the record fails to be added to your delivery stream, the result includes an error code and an error message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRecordBatchResponseEntry {
     /// <p>The error code for an individual record result.</p>
     #[serde(rename = "ErrorCode")]
@@ -878,6 +1644,7 @@ pub struct PutRecordBatchResponseEntry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRecordInput {
     /// <p>The name of the delivery stream.</p>
     #[serde(rename = "DeliveryStreamName")]
@@ -887,8 +1654,25 @@ pub struct PutRecordInput {
     pub record: Record,
 }
 
+impl Validate for PutRecordInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        self.record.validate_at(&field_path(path, "record"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRecordOutput {
     /// <p>Indicates whether server-side encryption (SSE) was enabled during this operation.</p>
     #[serde(rename = "Encrypted")]
@@ -901,6 +1685,7 @@ pub struct PutRecordOutput {
 
 /// <p>The unit of data in a delivery stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Record {
     /// <p>The data blob, which is base64-encoded when the blob is serialized. The maximum size of the data blob, before base64-encoding, is 1,000 KiB.</p>
     #[serde(rename = "Data")]
@@ -912,8 +1697,20 @@ pub struct Record {
     pub data: bytes::Bytes,
 }
 
+impl Validate for Record {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data"),
+            self.data.len(),
+            None,
+            Some(1024000),
+        );
+    }
+}
+
 /// <p>Describes the configuration of a destination in Amazon Redshift.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RedshiftDestinationConfiguration {
     /// <p>The CloudWatch logging options for your delivery stream.</p>
     #[serde(rename = "CloudWatchLoggingOptions")]
@@ -955,9 +1752,58 @@ pub struct RedshiftDestinationConfiguration {
     pub username: String,
 }
 
+impl Validate for RedshiftDestinationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            value.validate_at(&field_path(path, "cloud_watch_logging_options"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "cluster_jdbcurl"),
+            self.cluster_jdbcurl.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "cluster_jdbcurl"), &self.cluster_jdbcurl, "jdbc:(redshift|postgresql)://((?!-)[A-Za-z0-9-]{1,63}(?<!-)\\.)+(redshift(-serverless)?)\\.([a-zA-Z0-9\\.]+):\\d{1,5}/[a-zA-Z0-9_$-]+");
+        self.copy_command
+            .validate_at(&field_path(path, "copy_command"), errors);
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            Some(6),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "password"), &self.password, ".*");
+        if let Some(ref value) = self.processing_configuration {
+            value.validate_at(&field_path(path, "processing_configuration"), errors);
+        }
+        if let Some(ref value) = self.retry_options {
+            value.validate_at(&field_path(path, "retry_options"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "role_arn"), &self.role_arn, "arn:.*");
+        if let Some(ref value) = self.s3_backup_configuration {
+            value.validate_at(&field_path(path, "s3_backup_configuration"), errors);
+        }
+        self.s3_configuration
+            .validate_at(&field_path(path, "s3_configuration"), errors);
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "username"), &self.username, ".*");
+    }
+}
+
 /// <p>Describes a destination in Amazon Redshift.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RedshiftDestinationDescription {
     /// <p>The Amazon CloudWatch logging options for your delivery stream.</p>
     #[serde(rename = "CloudWatchLoggingOptions")]
@@ -998,6 +1844,7 @@ pub struct RedshiftDestinationDescription {
 
 /// <p>Describes an update for a destination in Amazon Redshift.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RedshiftDestinationUpdate {
     /// <p>The Amazon CloudWatch logging options for your delivery stream.</p>
     #[serde(rename = "CloudWatchLoggingOptions")]
@@ -1045,6 +1892,65 @@ pub struct RedshiftDestinationUpdate {
     pub username: Option<String>,
 }
 
+impl Validate for RedshiftDestinationUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            value.validate_at(&field_path(path, "cloud_watch_logging_options"), errors);
+        }
+        if let Some(ref value) = self.cluster_jdbcurl {
+            errors.check_length(
+                &field_path(path, "cluster_jdbcurl"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "cluster_jdbcurl"), &value, "jdbc:(redshift|postgresql)://((?!-)[A-Za-z0-9-]{1,63}(?<!-)\\.)+(redshift(-serverless)?)\\.([a-zA-Z0-9\\.]+):\\d{1,5}/[a-zA-Z0-9_$-]+");
+        }
+        if let Some(ref value) = self.copy_command {
+            value.validate_at(&field_path(path, "copy_command"), errors);
+        }
+        if let Some(ref value) = self.password {
+            errors.check_length(
+                &field_path(path, "password"),
+                value.chars().count(),
+                Some(6),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "password"), &value, ".*");
+        }
+        if let Some(ref value) = self.processing_configuration {
+            value.validate_at(&field_path(path, "processing_configuration"), errors);
+        }
+        if let Some(ref value) = self.retry_options {
+            value.validate_at(&field_path(path, "retry_options"), errors);
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "role_arn"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.s3_backup_update {
+            value.validate_at(&field_path(path, "s3_backup_update"), errors);
+        }
+        if let Some(ref value) = self.s3_update {
+            value.validate_at(&field_path(path, "s3_update"), errors);
+        }
+        if let Some(ref value) = self.username {
+            errors.check_length(
+                &field_path(path, "username"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "username"), &value, ".*");
+        }
+    }
+}
+
 /// <p>Configures retry behavior in case Kinesis Data Firehose is unable to deliver documents to Amazon Redshift.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RedshiftRetryOptions {
@@ -1054,8 +1960,22 @@ pub struct RedshiftRetryOptions {
     pub duration_in_seconds: Option<i64>,
 }
 
+impl Validate for RedshiftRetryOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration_in_seconds {
+            errors.check_range(
+                &field_path(path, "duration_in_seconds"),
+                *value,
+                None,
+                Some(7200),
+            );
+        }
+    }
+}
+
 /// <p>Describes the configuration of a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3DestinationConfiguration {
     /// <p>The ARN of the S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "BucketARN")]
@@ -1089,9 +2009,55 @@ pub struct S3DestinationConfiguration {
     pub role_arn: String,
 }
 
+impl Validate for S3DestinationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bucket_arn"),
+            self.bucket_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "bucket_arn"), &self.bucket_arn, "arn:.*");
+        if let Some(ref value) = self.buffering_hints {
+            value.validate_at(&field_path(path, "buffering_hints"), errors);
+        }
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            value.validate_at(&field_path(path, "cloud_watch_logging_options"), errors);
+        }
+        if let Some(ref value) = self.encryption_configuration {
+            value.validate_at(&field_path(path, "encryption_configuration"), errors);
+        }
+        if let Some(ref value) = self.error_output_prefix {
+            errors.check_length(
+                &field_path(path, "error_output_prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "error_output_prefix"), &value, ".*");
+        }
+        if let Some(ref value) = self.prefix {
+            errors.check_length(
+                &field_path(path, "prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "prefix"), &value, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "role_arn"), &self.role_arn, "arn:.*");
+    }
+}
+
 /// <p>Describes a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct S3DestinationDescription {
     /// <p>The ARN of the S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "BucketARN")]
@@ -1124,6 +2090,7 @@ pub struct S3DestinationDescription {
 
 /// <p>Describes an update for a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3DestinationUpdate {
     /// <p>The ARN of the S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "BucketARN")]
@@ -1159,6 +2126,56 @@ pub struct S3DestinationUpdate {
     pub role_arn: Option<String>,
 }
 
+impl Validate for S3DestinationUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket_arn {
+            errors.check_length(
+                &field_path(path, "bucket_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "bucket_arn"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.buffering_hints {
+            value.validate_at(&field_path(path, "buffering_hints"), errors);
+        }
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            value.validate_at(&field_path(path, "cloud_watch_logging_options"), errors);
+        }
+        if let Some(ref value) = self.encryption_configuration {
+            value.validate_at(&field_path(path, "encryption_configuration"), errors);
+        }
+        if let Some(ref value) = self.error_output_prefix {
+            errors.check_length(
+                &field_path(path, "error_output_prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "error_output_prefix"), &value, ".*");
+        }
+        if let Some(ref value) = self.prefix {
+            errors.check_length(
+                &field_path(path, "prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "prefix"), &value, ".*");
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "role_arn"), &value, "arn:.*");
+        }
+    }
+}
+
 /// <p>Specifies the schema to which you want Kinesis Data Firehose to configure your data before it writes it to Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SchemaConfiguration {
@@ -1188,12 +2205,71 @@ pub struct SchemaConfiguration {
     pub version_id: Option<String>,
 }
 
-/// <p>The serializer that you want Kinesis Data Firehose to use to convert data to the target format before writing it to Amazon S3. Kinesis Data Firehose supports two types of serializers: the <a href="https://hive.apache.org/javadocs/r1.2.2/api/org/apache/hadoop/hive/ql/io/orc/OrcSerde.html">ORC SerDe</a> and the <a href="https://hive.apache.org/javadocs/r1.2.2/api/org/apache/hadoop/hive/ql/io/parquet/serde/ParquetHiveSerDe.html">Parquet SerDe</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Serializer {
-    /// <p>A serializer to use for converting data to the ORC format before storing it in Amazon S3. For more information, see <a href="https://orc.apache.org/docs/">Apache ORC</a>.</p>
-    #[serde(rename = "OrcSerDe")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl Validate for SchemaConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "catalog_id"), &value, "^\\S+$");
+        }
+        if let Some(ref value) = self.database_name {
+            errors.check_length(
+                &field_path(path, "database_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "database_name"), &value, "^\\S+$");
+        }
+        if let Some(ref value) = self.region {
+            errors.check_length(
+                &field_path(path, "region"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "region"), &value, "^\\S+$");
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "role_arn"), &value, "^\\S+$");
+        }
+        if let Some(ref value) = self.table_name {
+            errors.check_length(
+                &field_path(path, "table_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "table_name"), &value, "^\\S+$");
+        }
+        if let Some(ref value) = self.version_id {
+            errors.check_length(
+                &field_path(path, "version_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "version_id"), &value, "^\\S+$");
+        }
+    }
+}
+
+/// <p>The serializer that you want Kinesis Data Firehose to use to convert data to the target format before writing it to Amazon S3. Kinesis Data Firehose supports two types of serializers: the <a href="https://hive.apache.org/javadocs/r1.2.2/api/org/apache/hadoop/hive/ql/io/orc/OrcSerde.html">ORC SerDe</a> and the <a href="https://hive.apache.org/javadocs/r1.2.2/api/org/apache/hadoop/hive/ql/io/parquet/serde/ParquetHiveSerDe.html">Parquet SerDe</a>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Serializer {
+    /// <p>A serializer to use for converting data to the ORC format before storing it in Amazon S3. For more information, see <a href="https://orc.apache.org/docs/">Apache ORC</a>.</p>
+    #[serde(rename = "OrcSerDe")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub orc_ser_de: Option<OrcSerDe>,
     /// <p>A serializer to use for converting data to the Parquet format before storing it in Amazon S3. For more information, see <a href="https://parquet.apache.org/documentation/latest/">Apache Parquet</a>.</p>
     #[serde(rename = "ParquetSerDe")]
@@ -1201,9 +2277,20 @@ pub struct Serializer {
     pub parquet_ser_de: Option<ParquetSerDe>,
 }
 
+impl Validate for Serializer {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.orc_ser_de {
+            value.validate_at(&field_path(path, "orc_ser_de"), errors);
+        }
+        if let Some(ref value) = self.parquet_ser_de {
+            value.validate_at(&field_path(path, "parquet_ser_de"), errors);
+        }
+    }
+}
+
 /// <p>Details about a Kinesis data stream used as the source for a Kinesis Data Firehose delivery stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SourceDescription {
     /// <p>The <a>KinesisStreamSourceDescription</a> value for the source Kinesis data stream.</p>
     #[serde(rename = "KinesisStreamSourceDescription")]
@@ -1213,6 +2300,7 @@ pub struct SourceDescription {
 
 /// <p>Describes the configuration of a destination in Splunk.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SplunkDestinationConfiguration {
     /// <p>The Amazon CloudWatch logging options for your delivery stream.</p>
     #[serde(rename = "CloudWatchLoggingOptions")]
@@ -1248,9 +2336,47 @@ pub struct SplunkDestinationConfiguration {
     pub s3_configuration: S3DestinationConfiguration,
 }
 
+impl Validate for SplunkDestinationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            value.validate_at(&field_path(path, "cloud_watch_logging_options"), errors);
+        }
+        if let Some(ref value) = self.hec_acknowledgment_timeout_in_seconds {
+            errors.check_range(
+                &field_path(path, "hec_acknowledgment_timeout_in_seconds"),
+                *value,
+                Some(180),
+                Some(600),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "hec_endpoint"),
+            self.hec_endpoint.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "hec_endpoint"), &self.hec_endpoint, ".*");
+        errors.check_length(
+            &field_path(path, "hec_token"),
+            self.hec_token.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "hec_token"), &self.hec_token, ".*");
+        if let Some(ref value) = self.processing_configuration {
+            value.validate_at(&field_path(path, "processing_configuration"), errors);
+        }
+        if let Some(ref value) = self.retry_options {
+            value.validate_at(&field_path(path, "retry_options"), errors);
+        }
+        self.s3_configuration
+            .validate_at(&field_path(path, "s3_configuration"), errors);
+    }
+}
+
 /// <p>Describes a destination in Splunk.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SplunkDestinationDescription {
     /// <p>The Amazon CloudWatch logging options for your delivery stream.</p>
     #[serde(rename = "CloudWatchLoggingOptions")]
@@ -1292,6 +2418,7 @@ pub struct SplunkDestinationDescription {
 
 /// <p>Describes an update for a destination in Splunk.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SplunkDestinationUpdate {
     /// <p>The Amazon CloudWatch logging options for your delivery stream.</p>
     #[serde(rename = "CloudWatchLoggingOptions")]
@@ -1331,6 +2458,49 @@ pub struct SplunkDestinationUpdate {
     pub s3_update: Option<S3DestinationUpdate>,
 }
 
+impl Validate for SplunkDestinationUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            value.validate_at(&field_path(path, "cloud_watch_logging_options"), errors);
+        }
+        if let Some(ref value) = self.hec_acknowledgment_timeout_in_seconds {
+            errors.check_range(
+                &field_path(path, "hec_acknowledgment_timeout_in_seconds"),
+                *value,
+                Some(180),
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.hec_endpoint {
+            errors.check_length(
+                &field_path(path, "hec_endpoint"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "hec_endpoint"), &value, ".*");
+        }
+        if let Some(ref value) = self.hec_token {
+            errors.check_length(
+                &field_path(path, "hec_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "hec_token"), &value, ".*");
+        }
+        if let Some(ref value) = self.processing_configuration {
+            value.validate_at(&field_path(path, "processing_configuration"), errors);
+        }
+        if let Some(ref value) = self.retry_options {
+            value.validate_at(&field_path(path, "retry_options"), errors);
+        }
+        if let Some(ref value) = self.s3_update {
+            value.validate_at(&field_path(path, "s3_update"), errors);
+        }
+    }
+}
+
 /// <p>Configures retry behavior in case Kinesis Data Firehose is unable to deliver documents to Splunk, or if it doesn't receive an acknowledgment from Splunk.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SplunkRetryOptions {
@@ -1340,26 +2510,73 @@ pub struct SplunkRetryOptions {
     pub duration_in_seconds: Option<i64>,
 }
 
+impl Validate for SplunkRetryOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration_in_seconds {
+            errors.check_range(
+                &field_path(path, "duration_in_seconds"),
+                *value,
+                None,
+                Some(7200),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartDeliveryStreamEncryptionInput {
     /// <p>The name of the delivery stream for which you want to enable server-side encryption (SSE).</p>
     #[serde(rename = "DeliveryStreamName")]
     pub delivery_stream_name: String,
 }
 
+impl Validate for StartDeliveryStreamEncryptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartDeliveryStreamEncryptionOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopDeliveryStreamEncryptionInput {
     /// <p>The name of the delivery stream for which you want to disable server-side encryption (SSE).</p>
     #[serde(rename = "DeliveryStreamName")]
     pub delivery_stream_name: String,
 }
 
+impl Validate for StopDeliveryStreamEncryptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopDeliveryStreamEncryptionOutput {}
 
 /// <p>Metadata that you can assign to a delivery stream, consisting of a key-value pair.</p>
@@ -1374,7 +2591,37 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^(?!aws:)[\\p{L}\\p{Z}\\p{N}_.:\\/=+\\-@%]*$",
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^[\\p{L}\\p{Z}\\p{N}_.:\\/=+\\-@%]*$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagDeliveryStreamInput {
     /// <p>The name of the delivery stream to which you want to add the tags.</p>
     #[serde(rename = "DeliveryStreamName")]
@@ -1384,11 +2631,38 @@ pub struct TagDeliveryStreamInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagDeliveryStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagDeliveryStreamOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagDeliveryStreamInput {
     /// <p>The name of the delivery stream.</p>
     #[serde(rename = "DeliveryStreamName")]
@@ -1398,11 +2672,43 @@ pub struct UntagDeliveryStreamInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagDeliveryStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "^(?!aws:)[\\p{L}\\p{Z}\\p{N}_.:\\/=+\\-@%]*$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagDeliveryStreamOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDestinationInput {
     /// <p>Obtain this value from the <code>VersionId</code> result of <a>DeliveryStreamDescription</a>. This value is required, and helps the service perform conditional operations. For example, if there is an interleaving update and this value is null, then the update destination fails. After the update is successful, the <code>VersionId</code> value is updated. The service then performs a merge of the old configuration with the new configuration.</p>
     #[serde(rename = "CurrentDeliveryStreamVersionId")]
@@ -1431,8 +2737,61 @@ pub struct UpdateDestinationInput {
     pub splunk_destination_update: Option<SplunkDestinationUpdate>,
 }
 
+impl Validate for UpdateDestinationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "current_delivery_stream_version_id"),
+            self.current_delivery_stream_version_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "current_delivery_stream_version_id"),
+            &self.current_delivery_stream_version_id,
+            "[0-9]+",
+        );
+        errors.check_length(
+            &field_path(path, "delivery_stream_name"),
+            self.delivery_stream_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "delivery_stream_name"),
+            &self.delivery_stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "destination_id"),
+            self.destination_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "destination_id"),
+            &self.destination_id,
+            "[a-zA-Z0-9-]+",
+        );
+        if let Some(ref value) = self.elasticsearch_destination_update {
+            value.validate_at(
+                &field_path(path, "elasticsearch_destination_update"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.extended_s3_destination_update {
+            value.validate_at(&field_path(path, "extended_s3_destination_update"), errors);
+        }
+        if let Some(ref value) = self.redshift_destination_update {
+            value.validate_at(&field_path(path, "redshift_destination_update"), errors);
+        }
+        if let Some(ref value) = self.splunk_destination_update {
+            value.validate_at(&field_path(path, "splunk_destination_update"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDestinationOutput {}
 
 /// Errors returned by CreateDeliveryStream
@@ -1461,7 +2820,12 @@ impl CreateDeliveryStreamError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(CreateDeliveryStreamError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1503,7 +2867,12 @@ impl DeleteDeliveryStreamError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1539,7 +2908,12 @@ impl DescribeDeliveryStreamError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1566,7 +2940,12 @@ impl ListDeliveryStreamsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListDeliveryStreamsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1613,7 +2992,12 @@ impl ListTagsForDeliveryStreamError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1658,7 +3042,12 @@ impl PutRecordError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(PutRecordError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1703,7 +3092,12 @@ impl PutRecordBatchError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(PutRecordBatchError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1763,7 +3157,12 @@ impl StartDeliveryStreamEncryptionError {
                         StartDeliveryStreamEncryptionError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1824,7 +3223,12 @@ impl StopDeliveryStreamEncryptionError {
                         StopDeliveryStreamEncryptionError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1875,7 +3279,12 @@ impl TagDeliveryStreamError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagDeliveryStreamError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1928,7 +3337,12 @@ impl UntagDeliveryStreamError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1981,7 +3395,12 @@ impl UpdateDestinationError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateDestinationError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2074,6 +3493,220 @@ pub trait KinesisFirehose {
         input: UpdateDestinationInput,
     ) -> RusotoFuture<UpdateDestinationOutput, UpdateDestinationError>;
 }
+const _: Option<&dyn KinesisFirehose> = None;
+impl<'a, T: KinesisFirehose + ?Sized> KinesisFirehose for &'a T {
+    fn create_delivery_stream(
+        &self,
+        input: CreateDeliveryStreamInput,
+    ) -> RusotoFuture<CreateDeliveryStreamOutput, CreateDeliveryStreamError> {
+        (**self).create_delivery_stream(input)
+    }
+    fn delete_delivery_stream(
+        &self,
+        input: DeleteDeliveryStreamInput,
+    ) -> RusotoFuture<DeleteDeliveryStreamOutput, DeleteDeliveryStreamError> {
+        (**self).delete_delivery_stream(input)
+    }
+    fn describe_delivery_stream(
+        &self,
+        input: DescribeDeliveryStreamInput,
+    ) -> RusotoFuture<DescribeDeliveryStreamOutput, DescribeDeliveryStreamError> {
+        (**self).describe_delivery_stream(input)
+    }
+    fn list_delivery_streams(
+        &self,
+        input: ListDeliveryStreamsInput,
+    ) -> RusotoFuture<ListDeliveryStreamsOutput, ListDeliveryStreamsError> {
+        (**self).list_delivery_streams(input)
+    }
+    fn list_tags_for_delivery_stream(
+        &self,
+        input: ListTagsForDeliveryStreamInput,
+    ) -> RusotoFuture<ListTagsForDeliveryStreamOutput, ListTagsForDeliveryStreamError> {
+        (**self).list_tags_for_delivery_stream(input)
+    }
+    fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        (**self).put_record(input)
+    }
+    fn put_record_batch(
+        &self,
+        input: PutRecordBatchInput,
+    ) -> RusotoFuture<PutRecordBatchOutput, PutRecordBatchError> {
+        (**self).put_record_batch(input)
+    }
+    fn start_delivery_stream_encryption(
+        &self,
+        input: StartDeliveryStreamEncryptionInput,
+    ) -> RusotoFuture<StartDeliveryStreamEncryptionOutput, StartDeliveryStreamEncryptionError> {
+        (**self).start_delivery_stream_encryption(input)
+    }
+    fn stop_delivery_stream_encryption(
+        &self,
+        input: StopDeliveryStreamEncryptionInput,
+    ) -> RusotoFuture<StopDeliveryStreamEncryptionOutput, StopDeliveryStreamEncryptionError> {
+        (**self).stop_delivery_stream_encryption(input)
+    }
+    fn tag_delivery_stream(
+        &self,
+        input: TagDeliveryStreamInput,
+    ) -> RusotoFuture<TagDeliveryStreamOutput, TagDeliveryStreamError> {
+        (**self).tag_delivery_stream(input)
+    }
+    fn untag_delivery_stream(
+        &self,
+        input: UntagDeliveryStreamInput,
+    ) -> RusotoFuture<UntagDeliveryStreamOutput, UntagDeliveryStreamError> {
+        (**self).untag_delivery_stream(input)
+    }
+    fn update_destination(
+        &self,
+        input: UpdateDestinationInput,
+    ) -> RusotoFuture<UpdateDestinationOutput, UpdateDestinationError> {
+        (**self).update_destination(input)
+    }
+}
+impl<T: KinesisFirehose + ?Sized> KinesisFirehose for Box<T> {
+    fn create_delivery_stream(
+        &self,
+        input: CreateDeliveryStreamInput,
+    ) -> RusotoFuture<CreateDeliveryStreamOutput, CreateDeliveryStreamError> {
+        (**self).create_delivery_stream(input)
+    }
+    fn delete_delivery_stream(
+        &self,
+        input: DeleteDeliveryStreamInput,
+    ) -> RusotoFuture<DeleteDeliveryStreamOutput, DeleteDeliveryStreamError> {
+        (**self).delete_delivery_stream(input)
+    }
+    fn describe_delivery_stream(
+        &self,
+        input: DescribeDeliveryStreamInput,
+    ) -> RusotoFuture<DescribeDeliveryStreamOutput, DescribeDeliveryStreamError> {
+        (**self).describe_delivery_stream(input)
+    }
+    fn list_delivery_streams(
+        &self,
+        input: ListDeliveryStreamsInput,
+    ) -> RusotoFuture<ListDeliveryStreamsOutput, ListDeliveryStreamsError> {
+        (**self).list_delivery_streams(input)
+    }
+    fn list_tags_for_delivery_stream(
+        &self,
+        input: ListTagsForDeliveryStreamInput,
+    ) -> RusotoFuture<ListTagsForDeliveryStreamOutput, ListTagsForDeliveryStreamError> {
+        (**self).list_tags_for_delivery_stream(input)
+    }
+    fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        (**self).put_record(input)
+    }
+    fn put_record_batch(
+        &self,
+        input: PutRecordBatchInput,
+    ) -> RusotoFuture<PutRecordBatchOutput, PutRecordBatchError> {
+        (**self).put_record_batch(input)
+    }
+    fn start_delivery_stream_encryption(
+        &self,
+        input: StartDeliveryStreamEncryptionInput,
+    ) -> RusotoFuture<StartDeliveryStreamEncryptionOutput, StartDeliveryStreamEncryptionError> {
+        (**self).start_delivery_stream_encryption(input)
+    }
+    fn stop_delivery_stream_encryption(
+        &self,
+        input: StopDeliveryStreamEncryptionInput,
+    ) -> RusotoFuture<StopDeliveryStreamEncryptionOutput, StopDeliveryStreamEncryptionError> {
+        (**self).stop_delivery_stream_encryption(input)
+    }
+    fn tag_delivery_stream(
+        &self,
+        input: TagDeliveryStreamInput,
+    ) -> RusotoFuture<TagDeliveryStreamOutput, TagDeliveryStreamError> {
+        (**self).tag_delivery_stream(input)
+    }
+    fn untag_delivery_stream(
+        &self,
+        input: UntagDeliveryStreamInput,
+    ) -> RusotoFuture<UntagDeliveryStreamOutput, UntagDeliveryStreamError> {
+        (**self).untag_delivery_stream(input)
+    }
+    fn update_destination(
+        &self,
+        input: UpdateDestinationInput,
+    ) -> RusotoFuture<UpdateDestinationOutput, UpdateDestinationError> {
+        (**self).update_destination(input)
+    }
+}
+impl<T: KinesisFirehose + ?Sized> KinesisFirehose for ::std::sync::Arc<T> {
+    fn create_delivery_stream(
+        &self,
+        input: CreateDeliveryStreamInput,
+    ) -> RusotoFuture<CreateDeliveryStreamOutput, CreateDeliveryStreamError> {
+        (**self).create_delivery_stream(input)
+    }
+    fn delete_delivery_stream(
+        &self,
+        input: DeleteDeliveryStreamInput,
+    ) -> RusotoFuture<DeleteDeliveryStreamOutput, DeleteDeliveryStreamError> {
+        (**self).delete_delivery_stream(input)
+    }
+    fn describe_delivery_stream(
+        &self,
+        input: DescribeDeliveryStreamInput,
+    ) -> RusotoFuture<DescribeDeliveryStreamOutput, DescribeDeliveryStreamError> {
+        (**self).describe_delivery_stream(input)
+    }
+    fn list_delivery_streams(
+        &self,
+        input: ListDeliveryStreamsInput,
+    ) -> RusotoFuture<ListDeliveryStreamsOutput, ListDeliveryStreamsError> {
+        (**self).list_delivery_streams(input)
+    }
+    fn list_tags_for_delivery_stream(
+        &self,
+        input: ListTagsForDeliveryStreamInput,
+    ) -> RusotoFuture<ListTagsForDeliveryStreamOutput, ListTagsForDeliveryStreamError> {
+        (**self).list_tags_for_delivery_stream(input)
+    }
+    fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        (**self).put_record(input)
+    }
+    fn put_record_batch(
+        &self,
+        input: PutRecordBatchInput,
+    ) -> RusotoFuture<PutRecordBatchOutput, PutRecordBatchError> {
+        (**self).put_record_batch(input)
+    }
+    fn start_delivery_stream_encryption(
+        &self,
+        input: StartDeliveryStreamEncryptionInput,
+    ) -> RusotoFuture<StartDeliveryStreamEncryptionOutput, StartDeliveryStreamEncryptionError> {
+        (**self).start_delivery_stream_encryption(input)
+    }
+    fn stop_delivery_stream_encryption(
+        &self,
+        input: StopDeliveryStreamEncryptionInput,
+    ) -> RusotoFuture<StopDeliveryStreamEncryptionOutput, StopDeliveryStreamEncryptionError> {
+        (**self).stop_delivery_stream_encryption(input)
+    }
+    fn tag_delivery_stream(
+        &self,
+        input: TagDeliveryStreamInput,
+    ) -> RusotoFuture<TagDeliveryStreamOutput, TagDeliveryStreamError> {
+        (**self).tag_delivery_stream(input)
+    }
+    fn untag_delivery_stream(
+        &self,
+        input: UntagDeliveryStreamInput,
+    ) -> RusotoFuture<UntagDeliveryStreamOutput, UntagDeliveryStreamError> {
+        (**self).untag_delivery_stream(input)
+    }
+    fn update_destination(
+        &self,
+        input: UpdateDestinationInput,
+    ) -> RusotoFuture<UpdateDestinationOutput, UpdateDestinationError> {
+        (**self).update_destination(input)
+    }
+}
 /// A client for the Firehose API.
 #[derive(Clone)]
 pub struct KinesisFirehoseClient {
@@ -2117,7 +3750,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: CreateDeliveryStreamInput,
     ) -> RusotoFuture<CreateDeliveryStreamOutput, CreateDeliveryStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("CreateDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.CreateDeliveryStream");
@@ -2126,10 +3768,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDeliveryStreamOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateDeliveryStreamOutput, _>(
+                    response,
+                    "CreateDeliveryStream",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2145,7 +3787,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: DeleteDeliveryStreamInput,
     ) -> RusotoFuture<DeleteDeliveryStreamOutput, DeleteDeliveryStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("DeleteDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.DeleteDeliveryStream");
@@ -2154,10 +3805,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDeliveryStreamOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteDeliveryStreamOutput, _>(
+                    response,
+                    "DeleteDeliveryStream",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2173,7 +3824,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: DescribeDeliveryStreamInput,
     ) -> RusotoFuture<DescribeDeliveryStreamOutput, DescribeDeliveryStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("DescribeDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.DescribeDeliveryStream");
@@ -2182,10 +3842,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDeliveryStreamOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeDeliveryStreamOutput, _>(
+                    response,
+                    "DescribeDeliveryStream",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2201,7 +3861,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: ListDeliveryStreamsInput,
     ) -> RusotoFuture<ListDeliveryStreamsOutput, ListDeliveryStreamsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("ListDeliveryStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.ListDeliveryStreams");
@@ -2210,10 +3879,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeliveryStreamsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListDeliveryStreamsOutput, _>(
+                    response,
+                    "ListDeliveryStreams",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2229,7 +3898,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: ListTagsForDeliveryStreamInput,
     ) -> RusotoFuture<ListTagsForDeliveryStreamOutput, ListTagsForDeliveryStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("ListTagsForDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2241,10 +3919,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForDeliveryStreamOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForDeliveryStreamOutput, _>(
+                    response,
+                    "ListTagsForDeliveryStream",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ListTagsForDeliveryStreamError::from_response(response))
@@ -2255,7 +3933,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
     /// <p><p>Writes a single data record into an Amazon Kinesis Data Firehose delivery stream. To write multiple data records into a delivery stream, use <a>PutRecordBatch</a>. Applications using these operations are referred to as producers.</p> <p>By default, each delivery stream can take in up to 2,000 transactions per second, 5,000 records per second, or 5 MB per second. If you use <a>PutRecord</a> and <a>PutRecordBatch</a>, the limits are an aggregate across these two operations for each delivery stream. For more information about limits and how to request an increase, see <a href="http://docs.aws.amazon.com/firehose/latest/dev/limits.html">Amazon Kinesis Data Firehose Limits</a>. </p> <p>You must specify the name of the delivery stream and the data record when using <a>PutRecord</a>. The data record consists of a data blob that can be up to 1,000 KB in size, and any kind of data. For example, it can be a segment from a log file, geographic location data, website clickstream data, and so on.</p> <p>Kinesis Data Firehose buffers records before delivering them to the destination. To disambiguate the data blobs at the destination, a common solution is to use delimiters in the data, such as a newline (<code>\n</code>) or some other character unique within the data. This allows the consumer application to parse individual data items when reading the data from the destination.</p> <p>The <code>PutRecord</code> operation returns a <code>RecordId</code>, which is a unique string assigned to each record. Producer applications can use this ID for purposes such as auditability and investigation.</p> <p>If the <code>PutRecord</code> operation throws a <code>ServiceUnavailableException</code>, back off and retry. If the exception persists, it is possible that the throughput limits have been exceeded for the delivery stream. </p> <p>Data records sent to Kinesis Data Firehose are stored for 24 hours from the time they are added to a delivery stream as it tries to send the records to the destination. If the destination is unreachable for more than 24 hours, the data is no longer available.</p> <important> <p>Don&#39;t concatenate two or more base64 strings to form the data fields of your records. Instead, concatenate the raw data, then perform base64 encoding.</p> </important></p>
     fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("PutRecord");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.PutRecord");
@@ -2264,9 +3951,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<PutRecordOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<PutRecordOutput, _>(response, "PutRecord")
             } else {
                 Box::new(
                     response
@@ -2283,7 +3968,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: PutRecordBatchInput,
     ) -> RusotoFuture<PutRecordBatchOutput, PutRecordBatchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("PutRecordBatch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.PutRecordBatch");
@@ -2292,10 +3986,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutRecordBatchOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<PutRecordBatchOutput, _>(
+                    response,
+                    "PutRecordBatch",
+                )
             } else {
                 Box::new(
                     response
@@ -2312,7 +4006,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: StartDeliveryStreamEncryptionInput,
     ) -> RusotoFuture<StartDeliveryStreamEncryptionOutput, StartDeliveryStreamEncryptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("StartDeliveryStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2324,10 +4027,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartDeliveryStreamEncryptionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<StartDeliveryStreamEncryptionOutput, _>(
+                    response,
+                    "StartDeliveryStreamEncryption",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(StartDeliveryStreamEncryptionError::from_response(response))
@@ -2341,7 +4044,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: StopDeliveryStreamEncryptionInput,
     ) -> RusotoFuture<StopDeliveryStreamEncryptionOutput, StopDeliveryStreamEncryptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("StopDeliveryStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2353,10 +4065,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopDeliveryStreamEncryptionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<StopDeliveryStreamEncryptionOutput, _>(
+                    response,
+                    "StopDeliveryStreamEncryption",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(StopDeliveryStreamEncryptionError::from_response(response))
@@ -2370,7 +4082,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: TagDeliveryStreamInput,
     ) -> RusotoFuture<TagDeliveryStreamOutput, TagDeliveryStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("TagDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.TagDeliveryStream");
@@ -2379,10 +4100,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagDeliveryStreamOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<TagDeliveryStreamOutput, _>(
+                    response,
+                    "TagDeliveryStream",
+                )
             } else {
                 Box::new(
                     response
@@ -2399,7 +4120,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: UntagDeliveryStreamInput,
     ) -> RusotoFuture<UntagDeliveryStreamOutput, UntagDeliveryStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("UntagDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.UntagDeliveryStream");
@@ -2408,10 +4138,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagDeliveryStreamOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagDeliveryStreamOutput, _>(
+                    response,
+                    "UntagDeliveryStream",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2427,7 +4157,16 @@ impl KinesisFirehose for KinesisFirehoseClient {
         &self,
         input: UpdateDestinationInput,
     ) -> RusotoFuture<UpdateDestinationOutput, UpdateDestinationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("UpdateDestination");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.UpdateDestination");
@@ -2436,10 +4175,10 @@ impl KinesisFirehose for KinesisFirehoseClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDestinationOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateDestinationOutput, _>(
+                    response,
+                    "UpdateDestination",
+                )
             } else {
                 Box::new(
                     response
@@ -2451,3 +4190,441 @@ impl KinesisFirehose for KinesisFirehoseClient {
         })
     }
 }
+
+/// A mock of the Firehose API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockKinesisFirehose {
+    create_delivery_stream: Option<
+        Box<
+            dyn Fn(
+                    CreateDeliveryStreamInput,
+                ) -> ::std::result::Result<
+                    CreateDeliveryStreamOutput,
+                    RusotoError<CreateDeliveryStreamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_delivery_stream: Option<
+        Box<
+            dyn Fn(
+                    DeleteDeliveryStreamInput,
+                ) -> ::std::result::Result<
+                    DeleteDeliveryStreamOutput,
+                    RusotoError<DeleteDeliveryStreamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_delivery_stream: Option<
+        Box<
+            dyn Fn(
+                    DescribeDeliveryStreamInput,
+                ) -> ::std::result::Result<
+                    DescribeDeliveryStreamOutput,
+                    RusotoError<DescribeDeliveryStreamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_delivery_streams: Option<
+        Box<
+            dyn Fn(
+                    ListDeliveryStreamsInput,
+                ) -> ::std::result::Result<
+                    ListDeliveryStreamsOutput,
+                    RusotoError<ListDeliveryStreamsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_delivery_stream: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForDeliveryStreamInput,
+                ) -> ::std::result::Result<
+                    ListTagsForDeliveryStreamOutput,
+                    RusotoError<ListTagsForDeliveryStreamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_record: Option<
+        Box<
+            dyn Fn(
+                    PutRecordInput,
+                )
+                    -> ::std::result::Result<PutRecordOutput, RusotoError<PutRecordError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_record_batch: Option<
+        Box<
+            dyn Fn(
+                    PutRecordBatchInput,
+                )
+                    -> ::std::result::Result<PutRecordBatchOutput, RusotoError<PutRecordBatchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_delivery_stream_encryption: Option<
+        Box<
+            dyn Fn(
+                    StartDeliveryStreamEncryptionInput,
+                ) -> ::std::result::Result<
+                    StartDeliveryStreamEncryptionOutput,
+                    RusotoError<StartDeliveryStreamEncryptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_delivery_stream_encryption: Option<
+        Box<
+            dyn Fn(
+                    StopDeliveryStreamEncryptionInput,
+                ) -> ::std::result::Result<
+                    StopDeliveryStreamEncryptionOutput,
+                    RusotoError<StopDeliveryStreamEncryptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_delivery_stream: Option<
+        Box<
+            dyn Fn(
+                    TagDeliveryStreamInput,
+                ) -> ::std::result::Result<
+                    TagDeliveryStreamOutput,
+                    RusotoError<TagDeliveryStreamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    untag_delivery_stream: Option<
+        Box<
+            dyn Fn(
+                    UntagDeliveryStreamInput,
+                ) -> ::std::result::Result<
+                    UntagDeliveryStreamOutput,
+                    RusotoError<UntagDeliveryStreamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_destination: Option<
+        Box<
+            dyn Fn(
+                    UpdateDestinationInput,
+                ) -> ::std::result::Result<
+                    UpdateDestinationOutput,
+                    RusotoError<UpdateDestinationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockKinesisFirehose {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockKinesisFirehose {
+        Default::default()
+    }
+
+    /// Answers `create_delivery_stream` with what `handler` returns for its input.
+    pub fn with_create_delivery_stream<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                CreateDeliveryStreamInput,
+            ) -> ::std::result::Result<
+                CreateDeliveryStreamOutput,
+                RusotoError<CreateDeliveryStreamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_delivery_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_delivery_stream` with what `handler` returns for its input.
+    pub fn with_delete_delivery_stream<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                DeleteDeliveryStreamInput,
+            ) -> ::std::result::Result<
+                DeleteDeliveryStreamOutput,
+                RusotoError<DeleteDeliveryStreamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_delivery_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_delivery_stream` with what `handler` returns for its input.
+    pub fn with_describe_delivery_stream<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                DescribeDeliveryStreamInput,
+            ) -> ::std::result::Result<
+                DescribeDeliveryStreamOutput,
+                RusotoError<DescribeDeliveryStreamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_delivery_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_delivery_streams` with what `handler` returns for its input.
+    pub fn with_list_delivery_streams<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                ListDeliveryStreamsInput,
+            ) -> ::std::result::Result<
+                ListDeliveryStreamsOutput,
+                RusotoError<ListDeliveryStreamsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_delivery_streams = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_delivery_stream` with what `handler` returns for its input.
+    pub fn with_list_tags_for_delivery_stream<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                ListTagsForDeliveryStreamInput,
+            ) -> ::std::result::Result<
+                ListTagsForDeliveryStreamOutput,
+                RusotoError<ListTagsForDeliveryStreamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_delivery_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_record` with what `handler` returns for its input.
+    pub fn with_put_record<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                PutRecordInput,
+            ) -> ::std::result::Result<PutRecordOutput, RusotoError<PutRecordError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_record = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_record_batch` with what `handler` returns for its input.
+    pub fn with_put_record_batch<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                PutRecordBatchInput,
+            )
+                -> ::std::result::Result<PutRecordBatchOutput, RusotoError<PutRecordBatchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_record_batch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_delivery_stream_encryption` with what `handler` returns for its input.
+    pub fn with_start_delivery_stream_encryption<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                StartDeliveryStreamEncryptionInput,
+            ) -> ::std::result::Result<
+                StartDeliveryStreamEncryptionOutput,
+                RusotoError<StartDeliveryStreamEncryptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_delivery_stream_encryption = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_delivery_stream_encryption` with what `handler` returns for its input.
+    pub fn with_stop_delivery_stream_encryption<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                StopDeliveryStreamEncryptionInput,
+            ) -> ::std::result::Result<
+                StopDeliveryStreamEncryptionOutput,
+                RusotoError<StopDeliveryStreamEncryptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_delivery_stream_encryption = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_delivery_stream` with what `handler` returns for its input.
+    pub fn with_tag_delivery_stream<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                TagDeliveryStreamInput,
+            ) -> ::std::result::Result<
+                TagDeliveryStreamOutput,
+                RusotoError<TagDeliveryStreamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_delivery_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_delivery_stream` with what `handler` returns for its input.
+    pub fn with_untag_delivery_stream<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                UntagDeliveryStreamInput,
+            ) -> ::std::result::Result<
+                UntagDeliveryStreamOutput,
+                RusotoError<UntagDeliveryStreamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_delivery_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_destination` with what `handler` returns for its input.
+    pub fn with_update_destination<F>(mut self, handler: F) -> MockKinesisFirehose
+    where
+        F: Fn(
+                UpdateDestinationInput,
+            ) -> ::std::result::Result<
+                UpdateDestinationOutput,
+                RusotoError<UpdateDestinationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_destination = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl KinesisFirehose for MockKinesisFirehose {
+    fn create_delivery_stream(
+        &self,
+        input: CreateDeliveryStreamInput,
+    ) -> RusotoFuture<CreateDeliveryStreamOutput, CreateDeliveryStreamError> {
+        match self.create_delivery_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisFirehose has no handler for create_delivery_stream"),
+        }
+    }
+    fn delete_delivery_stream(
+        &self,
+        input: DeleteDeliveryStreamInput,
+    ) -> RusotoFuture<DeleteDeliveryStreamOutput, DeleteDeliveryStreamError> {
+        match self.delete_delivery_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisFirehose has no handler for delete_delivery_stream"),
+        }
+    }
+    fn describe_delivery_stream(
+        &self,
+        input: DescribeDeliveryStreamInput,
+    ) -> RusotoFuture<DescribeDeliveryStreamOutput, DescribeDeliveryStreamError> {
+        match self.describe_delivery_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisFirehose has no handler for describe_delivery_stream"),
+        }
+    }
+    fn list_delivery_streams(
+        &self,
+        input: ListDeliveryStreamsInput,
+    ) -> RusotoFuture<ListDeliveryStreamsOutput, ListDeliveryStreamsError> {
+        match self.list_delivery_streams {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisFirehose has no handler for list_delivery_streams"),
+        }
+    }
+    fn list_tags_for_delivery_stream(
+        &self,
+        input: ListTagsForDeliveryStreamInput,
+    ) -> RusotoFuture<ListTagsForDeliveryStreamOutput, ListTagsForDeliveryStreamError> {
+        match self.list_tags_for_delivery_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisFirehose has no handler for list_tags_for_delivery_stream"),
+        }
+    }
+    fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        match self.put_record {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisFirehose has no handler for put_record"),
+        }
+    }
+    fn put_record_batch(
+        &self,
+        input: PutRecordBatchInput,
+    ) -> RusotoFuture<PutRecordBatchOutput, PutRecordBatchError> {
+        match self.put_record_batch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisFirehose has no handler for put_record_batch"),
+        }
+    }
+    fn start_delivery_stream_encryption(
+        &self,
+        input: StartDeliveryStreamEncryptionInput,
+    ) -> RusotoFuture<StartDeliveryStreamEncryptionOutput, StartDeliveryStreamEncryptionError> {
+        match self.start_delivery_stream_encryption {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockKinesisFirehose has no handler for start_delivery_stream_encryption")
+            }
+        }
+    }
+    fn stop_delivery_stream_encryption(
+        &self,
+        input: StopDeliveryStreamEncryptionInput,
+    ) -> RusotoFuture<StopDeliveryStreamEncryptionOutput, StopDeliveryStreamEncryptionError> {
+        match self.stop_delivery_stream_encryption {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockKinesisFirehose has no handler for stop_delivery_stream_encryption")
+            }
+        }
+    }
+    fn tag_delivery_stream(
+        &self,
+        input: TagDeliveryStreamInput,
+    ) -> RusotoFuture<TagDeliveryStreamOutput, TagDeliveryStreamError> {
+        match self.tag_delivery_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisFirehose has no handler for tag_delivery_stream"),
+        }
+    }
+    fn untag_delivery_stream(
+        &self,
+        input: UntagDeliveryStreamInput,
+    ) -> RusotoFuture<UntagDeliveryStreamOutput, UntagDeliveryStreamError> {
+        match self.untag_delivery_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisFirehose has no handler for untag_delivery_stream"),
+        }
+    }
+    fn update_destination(
+        &self,
+        input: UpdateDestinationInput,
+    ) -> RusotoFuture<UpdateDestinationOutput, UpdateDestinationError> {
+        match self.update_destination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisFirehose has no handler for update_destination"),
+        }
+    }
+}
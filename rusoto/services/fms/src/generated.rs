@@ -22,17 +22,35 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateAdminAccountRequest {
     /// <p>The AWS account ID to associate with AWS Firewall Manager as the AWS Firewall Manager administrator account. This can be an AWS Organizations master account or a member account. For more information about AWS Organizations and master accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts.html">Managing the AWS Accounts in Your Organization</a>.</p>
     #[serde(rename = "AdminAccount")]
     pub admin_account: String,
 }
 
+impl Validate for AssociateAdminAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "admin_account"),
+            self.admin_account.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "admin_account"),
+            &self.admin_account,
+            "^[0-9]+$",
+        );
+    }
+}
+
 /// <p>Details of the resource that is not protected by the policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComplianceViolator {
     /// <p>The resource ID.</p>
     #[serde(rename = "ResourceId")]
@@ -49,9 +67,11 @@ pub struct ComplianceViolator {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteNotificationChannelRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePolicyRequest {
     /// <p>If <code>True</code>, the request will also perform a clean-up process that will:</p> <ul> <li> <p>Delete rule groups created by AWS Firewall Manager</p> </li> <li> <p>Remove web ACLs from in-scope resources</p> </li> <li> <p>Delete web ACLs that contain no rules or rule groups</p> </li> </ul> <p>After the cleanup, in-scope resources will no longer be protected by web ACLs in this policy. Protection of out-of-scope resources will remain unchanged. Scope is determined by tags and accounts associated with the policy. When creating the policy, if you specified that only resources in specific accounts or with specific tags be protected by the policy, those resources are in-scope. All others are out of scope. If you did not specify tags or accounts, all resources are in-scope. </p>
     #[serde(rename = "DeleteAllPolicyResources")]
@@ -62,12 +82,29 @@ pub struct DeletePolicyRequest {
     pub policy_id: String,
 }
 
+impl Validate for DeletePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^[a-z0-9A-Z-]{36}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateAdminAccountRequest {}
 
 /// <p>Describes the compliance status for the account. An account is considered non-compliant if it includes resources that are not protected by the specified policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EvaluationResult {
     /// <p>Describes an AWS account's compliance with the AWS Firewall Manager policy.</p>
     #[serde(rename = "ComplianceStatus")]
@@ -84,10 +121,11 @@ pub struct EvaluationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAdminAccountRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAdminAccountResponse {
     /// <p>The AWS account that is set as the AWS Firewall Manager administrator.</p>
     #[serde(rename = "AdminAccount")]
@@ -100,6 +138,7 @@ pub struct GetAdminAccountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetComplianceDetailRequest {
     /// <p>The AWS account that owns the resources that you want to get the details for.</p>
     #[serde(rename = "MemberAccount")]
@@ -109,8 +148,35 @@ pub struct GetComplianceDetailRequest {
     pub policy_id: String,
 }
 
+impl Validate for GetComplianceDetailRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "member_account"),
+            self.member_account.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "member_account"),
+            &self.member_account,
+            "^[0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^[a-z0-9A-Z-]{36}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetComplianceDetailResponse {
     /// <p>Information about the resources and the policy that you specified in the <code>GetComplianceDetail</code> request.</p>
     #[serde(rename = "PolicyComplianceDetail")]
@@ -119,10 +185,11 @@ pub struct GetComplianceDetailResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetNotificationChannelRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetNotificationChannelResponse {
     /// <p>The IAM role that is used by AWS Firewall Manager to record activity to SNS.</p>
     #[serde(rename = "SnsRoleName")]
@@ -135,14 +202,31 @@ pub struct GetNotificationChannelResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPolicyRequest {
     /// <p>The ID of the AWS Firewall Manager policy that you want the details for.</p>
     #[serde(rename = "PolicyId")]
     pub policy_id: String,
 }
 
+impl Validate for GetPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^[a-z0-9A-Z-]{36}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPolicyResponse {
     /// <p>Information about the specified AWS Firewall Manager policy.</p>
     #[serde(rename = "Policy")]
@@ -155,6 +239,7 @@ pub struct GetPolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetProtectionStatusRequest {
     /// <p>The end of the time period to query for the attacks. This is a <code>timestamp</code> type. The sample request above indicates a number type because the default used by AWS Firewall Manager is Unix time in seconds. However, any valid <code>timestamp</code> format is allowed.</p>
     #[serde(rename = "EndTime")]
@@ -181,8 +266,49 @@ pub struct GetProtectionStatusRequest {
     pub start_time: Option<f64>,
 }
 
+impl Validate for GetProtectionStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.member_account_id {
+            errors.check_length(
+                &field_path(path, "member_account_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "member_account_id"), &value, "^[0-9]+$");
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^[a-z0-9A-Z-]{36}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetProtectionStatusResponse {
     /// <p>The ID of the AWS Firewall administrator account for this policy.</p>
     #[serde(rename = "AdminAccountId")]
@@ -203,6 +329,7 @@ pub struct GetProtectionStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListComplianceStatusRequest {
     /// <p>Specifies the number of <code>PolicyComplianceStatus</code> objects that you want AWS Firewall Manager to return for this request. If you have more <code>PolicyComplianceStatus</code> objects than the number that you specify for <code>MaxResults</code>, the response includes a <code>NextToken</code> value that you can use to get another batch of <code>PolicyComplianceStatus</code> objects.</p>
     #[serde(rename = "MaxResults")]
@@ -217,8 +344,40 @@ pub struct ListComplianceStatusRequest {
     pub policy_id: String,
 }
 
+impl Validate for ListComplianceStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^[a-z0-9A-Z-]{36}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListComplianceStatusResponse {
     /// <p>If you have more <code>PolicyComplianceStatus</code> objects than the number that you specified for <code>MaxResults</code> in the request, the response includes a <code>NextToken</code> value. To list more <code>PolicyComplianceStatus</code> objects, submit another <code>ListComplianceStatus</code> request, and specify the <code>NextToken</code> value from the response in the <code>NextToken</code> value in the next request.</p>
     #[serde(rename = "NextToken")]
@@ -231,6 +390,7 @@ pub struct ListComplianceStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMemberAccountsRequest {
     /// <p>Specifies the number of member account IDs that you want AWS Firewall Manager to return for this request. If you have more IDs than the number that you specify for <code>MaxResults</code>, the response includes a <code>NextToken</code> value that you can use to get another batch of member account IDs.</p>
     #[serde(rename = "MaxResults")]
@@ -242,8 +402,29 @@ pub struct ListMemberAccountsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListMemberAccountsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListMemberAccountsResponse {
     /// <p>An array of account IDs.</p>
     #[serde(rename = "MemberAccounts")]
@@ -256,6 +437,7 @@ pub struct ListMemberAccountsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPoliciesRequest {
     /// <p>Specifies the number of <code>PolicySummary</code> objects that you want AWS Firewall Manager to return for this request. If you have more <code>PolicySummary</code> objects than the number that you specify for <code>MaxResults</code>, the response includes a <code>NextToken</code> value that you can use to get another batch of <code>PolicySummary</code> objects.</p>
     #[serde(rename = "MaxResults")]
@@ -267,8 +449,29 @@ pub struct ListPoliciesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListPoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPoliciesResponse {
     /// <p>If you have more <code>PolicySummary</code> objects than the number that you specified for <code>MaxResults</code> in the request, the response includes a <code>NextToken</code> value. To list more <code>PolicySummary</code> objects, submit another <code>ListPolicies</code> request, and specify the <code>NextToken</code> value from the response in the <code>NextToken</code> value in the next request.</p>
     #[serde(rename = "NextToken")]
@@ -324,9 +527,107 @@ pub struct Policy {
     pub security_service_policy_data: SecurityServicePolicyData,
 }
 
+impl Validate for Policy {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclude_map {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "exclude_map"), key);
+                for (i2, item2) in item.iter().enumerate() {
+                    let item_path2 = format!("{}[{}]", item_path, i2);
+                    errors.check_length(&item_path2, item2.chars().count(), Some(1), Some(1024));
+                    errors.check_pattern(
+                        &item_path2,
+                        &item2,
+                        "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+                    );
+                }
+            }
+        }
+        if let Some(ref value) = self.include_map {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "include_map"), key);
+                for (i2, item2) in item.iter().enumerate() {
+                    let item_path2 = format!("{}[{}]", item_path, i2);
+                    errors.check_length(&item_path2, item2.chars().count(), Some(1), Some(1024));
+                    errors.check_pattern(
+                        &item_path2,
+                        &item2,
+                        "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+                    );
+                }
+            }
+        }
+        if let Some(ref value) = self.policy_id {
+            errors.check_length(
+                &field_path(path, "policy_id"),
+                value.chars().count(),
+                Some(36),
+                Some(36),
+            );
+            errors.check_pattern(&field_path(path, "policy_id"), &value, "^[a-z0-9A-Z-]{36}$");
+        }
+        errors.check_length(
+            &field_path(path, "policy_name"),
+            self.policy_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_name"),
+            &self.policy_name,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        if let Some(ref value) = self.policy_update_token {
+            errors.check_length(
+                &field_path(path, "policy_update_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "policy_update_token"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+        if let Some(ref value) = self.resource_tags {
+            errors.check_length(
+                &field_path(path, "resource_tags"),
+                value.len(),
+                None,
+                Some(8),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "resource_type"),
+            self.resource_type.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_type"),
+            &self.resource_type,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        if let Some(ref value) = self.resource_type_list {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_type_list"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+        self.security_service_policy_data
+            .validate_at(&field_path(path, "security_service_policy_data"), errors);
+    }
+}
+
 /// <p>Describes the non-compliant resources in a member account for a specific AWS Firewall Manager policy. A maximum of 100 entries are displayed. If more than 100 resources are non-compliant, <code>EvaluationLimitExceeded</code> is set to <code>True</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PolicyComplianceDetail {
     /// <p>Indicates if over 100 resources are non-compliant with the AWS Firewall Manager policy.</p>
     #[serde(rename = "EvaluationLimitExceeded")]
@@ -360,7 +661,7 @@ pub struct PolicyComplianceDetail {
 
 /// <p>Indicates whether the account is compliant with the specified policy. An account is considered non-compliant if it includes resources that are not protected by the policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PolicyComplianceStatus {
     /// <p>An array of <code>EvaluationResult</code> objects.</p>
     #[serde(rename = "EvaluationResults")]
@@ -394,7 +695,7 @@ pub struct PolicyComplianceStatus {
 
 /// <p>Details of the AWS Firewall Manager policy. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PolicySummary {
     /// <p>The Amazon Resource Name (ARN) of the specified policy.</p>
     #[serde(rename = "PolicyArn")]
@@ -423,6 +724,7 @@ pub struct PolicySummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutNotificationChannelRequest {
     /// <p>The Amazon Resource Name (ARN) of the IAM role that allows Amazon SNS to record AWS Firewall Manager activity. </p>
     #[serde(rename = "SnsRoleName")]
@@ -432,15 +734,49 @@ pub struct PutNotificationChannelRequest {
     pub sns_topic_arn: String,
 }
 
+impl Validate for PutNotificationChannelRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "sns_role_name"),
+            self.sns_role_name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "sns_role_name"),
+            &self.sns_role_name,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        errors.check_length(
+            &field_path(path, "sns_topic_arn"),
+            self.sns_topic_arn.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "sns_topic_arn"),
+            &self.sns_topic_arn,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutPolicyRequest {
     /// <p>The details of the AWS Firewall Manager policy to be created.</p>
     #[serde(rename = "Policy")]
     pub policy: Policy,
 }
 
+impl Validate for PutPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.policy.validate_at(&field_path(path, "policy"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutPolicyResponse {
     /// <p>The details of the AWS Firewall Manager policy that was created.</p>
     #[serde(rename = "Policy")]
@@ -464,6 +800,35 @@ pub struct ResourceTag {
     pub value: Option<String>,
 }
 
+impl Validate for ResourceTag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 /// <p>Details about the security service that is being used to protect the resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SecurityServicePolicyData {
@@ -476,6 +841,24 @@ pub struct SecurityServicePolicyData {
     pub type_: String,
 }
 
+impl Validate for SecurityServicePolicyData {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.managed_service_data {
+            errors.check_length(
+                &field_path(path, "managed_service_data"),
+                value.chars().count(),
+                Some(1),
+                Some(10000),
+            );
+            errors.check_pattern(
+                &field_path(path, "managed_service_data"),
+                &value,
+                "^((?!\\\\[nr]).)+",
+            );
+        }
+    }
+}
+
 /// Errors returned by AssociateAdminAccount
 #[derive(Debug, PartialEq)]
 pub enum AssociateAdminAccountError {
@@ -509,7 +892,12 @@ impl AssociateAdminAccountError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -561,7 +949,12 @@ impl DeleteNotificationChannelError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -606,7 +999,12 @@ impl DeletePolicyError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeletePolicyError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -657,7 +1055,12 @@ impl DisassociateAdminAccountError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -702,7 +1105,12 @@ impl GetAdminAccountError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetAdminAccountError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -744,7 +1152,12 @@ impl GetComplianceDetailError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -794,7 +1207,12 @@ impl GetNotificationChannelError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -844,7 +1262,12 @@ impl GetPolicyError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -892,7 +1315,12 @@ impl GetProtectionStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -934,7 +1362,12 @@ impl ListComplianceStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -973,7 +1406,12 @@ impl ListMemberAccountsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListMemberAccountsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1022,7 +1460,12 @@ impl ListPoliciesError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListPoliciesError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1074,7 +1517,12 @@ impl PutNotificationChannelError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1134,7 +1582,12 @@ impl PutPolicyError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(PutPolicyError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1231,6 +1684,226 @@ pub trait Fms {
         input: PutPolicyRequest,
     ) -> RusotoFuture<PutPolicyResponse, PutPolicyError>;
 }
+const _: Option<&dyn Fms> = None;
+impl<'a, T: Fms + ?Sized> Fms for &'a T {
+    fn associate_admin_account(
+        &self,
+        input: AssociateAdminAccountRequest,
+    ) -> RusotoFuture<(), AssociateAdminAccountError> {
+        (**self).associate_admin_account(input)
+    }
+    fn delete_notification_channel(&self) -> RusotoFuture<(), DeleteNotificationChannelError> {
+        (**self).delete_notification_channel()
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn disassociate_admin_account(&self) -> RusotoFuture<(), DisassociateAdminAccountError> {
+        (**self).disassociate_admin_account()
+    }
+    fn get_admin_account(&self) -> RusotoFuture<GetAdminAccountResponse, GetAdminAccountError> {
+        (**self).get_admin_account()
+    }
+    fn get_compliance_detail(
+        &self,
+        input: GetComplianceDetailRequest,
+    ) -> RusotoFuture<GetComplianceDetailResponse, GetComplianceDetailError> {
+        (**self).get_compliance_detail(input)
+    }
+    fn get_notification_channel(
+        &self,
+    ) -> RusotoFuture<GetNotificationChannelResponse, GetNotificationChannelError> {
+        (**self).get_notification_channel()
+    }
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        (**self).get_policy(input)
+    }
+    fn get_protection_status(
+        &self,
+        input: GetProtectionStatusRequest,
+    ) -> RusotoFuture<GetProtectionStatusResponse, GetProtectionStatusError> {
+        (**self).get_protection_status(input)
+    }
+    fn list_compliance_status(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> RusotoFuture<ListComplianceStatusResponse, ListComplianceStatusError> {
+        (**self).list_compliance_status(input)
+    }
+    fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> RusotoFuture<ListMemberAccountsResponse, ListMemberAccountsError> {
+        (**self).list_member_accounts(input)
+    }
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        (**self).list_policies(input)
+    }
+    fn put_notification_channel(
+        &self,
+        input: PutNotificationChannelRequest,
+    ) -> RusotoFuture<(), PutNotificationChannelError> {
+        (**self).put_notification_channel(input)
+    }
+    fn put_policy(
+        &self,
+        input: PutPolicyRequest,
+    ) -> RusotoFuture<PutPolicyResponse, PutPolicyError> {
+        (**self).put_policy(input)
+    }
+}
+impl<T: Fms + ?Sized> Fms for Box<T> {
+    fn associate_admin_account(
+        &self,
+        input: AssociateAdminAccountRequest,
+    ) -> RusotoFuture<(), AssociateAdminAccountError> {
+        (**self).associate_admin_account(input)
+    }
+    fn delete_notification_channel(&self) -> RusotoFuture<(), DeleteNotificationChannelError> {
+        (**self).delete_notification_channel()
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn disassociate_admin_account(&self) -> RusotoFuture<(), DisassociateAdminAccountError> {
+        (**self).disassociate_admin_account()
+    }
+    fn get_admin_account(&self) -> RusotoFuture<GetAdminAccountResponse, GetAdminAccountError> {
+        (**self).get_admin_account()
+    }
+    fn get_compliance_detail(
+        &self,
+        input: GetComplianceDetailRequest,
+    ) -> RusotoFuture<GetComplianceDetailResponse, GetComplianceDetailError> {
+        (**self).get_compliance_detail(input)
+    }
+    fn get_notification_channel(
+        &self,
+    ) -> RusotoFuture<GetNotificationChannelResponse, GetNotificationChannelError> {
+        (**self).get_notification_channel()
+    }
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        (**self).get_policy(input)
+    }
+    fn get_protection_status(
+        &self,
+        input: GetProtectionStatusRequest,
+    ) -> RusotoFuture<GetProtectionStatusResponse, GetProtectionStatusError> {
+        (**self).get_protection_status(input)
+    }
+    fn list_compliance_status(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> RusotoFuture<ListComplianceStatusResponse, ListComplianceStatusError> {
+        (**self).list_compliance_status(input)
+    }
+    fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> RusotoFuture<ListMemberAccountsResponse, ListMemberAccountsError> {
+        (**self).list_member_accounts(input)
+    }
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        (**self).list_policies(input)
+    }
+    fn put_notification_channel(
+        &self,
+        input: PutNotificationChannelRequest,
+    ) -> RusotoFuture<(), PutNotificationChannelError> {
+        (**self).put_notification_channel(input)
+    }
+    fn put_policy(
+        &self,
+        input: PutPolicyRequest,
+    ) -> RusotoFuture<PutPolicyResponse, PutPolicyError> {
+        (**self).put_policy(input)
+    }
+}
+impl<T: Fms + ?Sized> Fms for ::std::sync::Arc<T> {
+    fn associate_admin_account(
+        &self,
+        input: AssociateAdminAccountRequest,
+    ) -> RusotoFuture<(), AssociateAdminAccountError> {
+        (**self).associate_admin_account(input)
+    }
+    fn delete_notification_channel(&self) -> RusotoFuture<(), DeleteNotificationChannelError> {
+        (**self).delete_notification_channel()
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn disassociate_admin_account(&self) -> RusotoFuture<(), DisassociateAdminAccountError> {
+        (**self).disassociate_admin_account()
+    }
+    fn get_admin_account(&self) -> RusotoFuture<GetAdminAccountResponse, GetAdminAccountError> {
+        (**self).get_admin_account()
+    }
+    fn get_compliance_detail(
+        &self,
+        input: GetComplianceDetailRequest,
+    ) -> RusotoFuture<GetComplianceDetailResponse, GetComplianceDetailError> {
+        (**self).get_compliance_detail(input)
+    }
+    fn get_notification_channel(
+        &self,
+    ) -> RusotoFuture<GetNotificationChannelResponse, GetNotificationChannelError> {
+        (**self).get_notification_channel()
+    }
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        (**self).get_policy(input)
+    }
+    fn get_protection_status(
+        &self,
+        input: GetProtectionStatusRequest,
+    ) -> RusotoFuture<GetProtectionStatusResponse, GetProtectionStatusError> {
+        (**self).get_protection_status(input)
+    }
+    fn list_compliance_status(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> RusotoFuture<ListComplianceStatusResponse, ListComplianceStatusError> {
+        (**self).list_compliance_status(input)
+    }
+    fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> RusotoFuture<ListMemberAccountsResponse, ListMemberAccountsError> {
+        (**self).list_member_accounts(input)
+    }
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        (**self).list_policies(input)
+    }
+    fn put_notification_channel(
+        &self,
+        input: PutNotificationChannelRequest,
+    ) -> RusotoFuture<(), PutNotificationChannelError> {
+        (**self).put_notification_channel(input)
+    }
+    fn put_policy(
+        &self,
+        input: PutPolicyRequest,
+    ) -> RusotoFuture<PutPolicyResponse, PutPolicyError> {
+        (**self).put_policy(input)
+    }
+}
 /// A client for the FMS API.
 #[derive(Clone)]
 pub struct FmsClient {
@@ -1274,7 +1947,16 @@ impl Fms for FmsClient {
         &self,
         input: AssociateAdminAccountRequest,
     ) -> RusotoFuture<(), AssociateAdminAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("AssociateAdminAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.AssociateAdminAccount");
@@ -1297,6 +1979,7 @@ impl Fms for FmsClient {
     /// <p>Deletes an AWS Firewall Manager association with the IAM role and the Amazon Simple Notification Service (SNS) topic that is used to record AWS Firewall Manager SNS logs.</p>
     fn delete_notification_channel(&self) -> RusotoFuture<(), DeleteNotificationChannelError> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("DeleteNotificationChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.DeleteNotificationChannel");
@@ -1315,7 +1998,16 @@ impl Fms for FmsClient {
 
     /// <p>Permanently deletes an AWS Firewall Manager policy. </p>
     fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("DeletePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.DeletePolicy");
@@ -1339,6 +2031,7 @@ impl Fms for FmsClient {
     /// <p>Disassociates the account that has been set as the AWS Firewall Manager administrator account. To set a different account as the administrator account, you must submit an <code>AssociateAdminAccount</code> request .</p>
     fn disassociate_admin_account(&self) -> RusotoFuture<(), DisassociateAdminAccountError> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("DisassociateAdminAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.DisassociateAdminAccount");
@@ -1358,6 +2051,7 @@ impl Fms for FmsClient {
     /// <p>Returns the AWS Organizations master account that is associated with AWS Firewall Manager as the AWS Firewall Manager administrator.</p>
     fn get_admin_account(&self) -> RusotoFuture<GetAdminAccountResponse, GetAdminAccountError> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetAdminAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetAdminAccount");
@@ -1365,10 +2059,10 @@ impl Fms for FmsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAdminAccountResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetAdminAccountResponse, _>(
+                    response,
+                    "GetAdminAccount",
+                )
             } else {
                 Box::new(
                     response
@@ -1385,7 +2079,16 @@ impl Fms for FmsClient {
         &self,
         input: GetComplianceDetailRequest,
     ) -> RusotoFuture<GetComplianceDetailResponse, GetComplianceDetailError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetComplianceDetail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetComplianceDetail");
@@ -1394,10 +2097,10 @@ impl Fms for FmsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetComplianceDetailResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetComplianceDetailResponse, _>(
+                    response,
+                    "GetComplianceDetail",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1413,6 +2116,7 @@ impl Fms for FmsClient {
         &self,
     ) -> RusotoFuture<GetNotificationChannelResponse, GetNotificationChannelError> {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetNotificationChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetNotificationChannel");
@@ -1420,10 +2124,10 @@ impl Fms for FmsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetNotificationChannelResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetNotificationChannelResponse, _>(
+                    response,
+                    "GetNotificationChannel",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1439,7 +2143,16 @@ impl Fms for FmsClient {
         &self,
         input: GetPolicyRequest,
     ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetPolicy");
@@ -1448,10 +2161,7 @@ impl Fms for FmsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetPolicyResponse, _>(response, "GetPolicy")
             } else {
                 Box::new(
                     response
@@ -1468,7 +2178,16 @@ impl Fms for FmsClient {
         &self,
         input: GetProtectionStatusRequest,
     ) -> RusotoFuture<GetProtectionStatusResponse, GetProtectionStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetProtectionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetProtectionStatus");
@@ -1477,10 +2196,10 @@ impl Fms for FmsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetProtectionStatusResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetProtectionStatusResponse, _>(
+                    response,
+                    "GetProtectionStatus",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1496,7 +2215,16 @@ impl Fms for FmsClient {
         &self,
         input: ListComplianceStatusRequest,
     ) -> RusotoFuture<ListComplianceStatusResponse, ListComplianceStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListComplianceStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListComplianceStatus");
@@ -1505,10 +2233,10 @@ impl Fms for FmsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListComplianceStatusResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListComplianceStatusResponse, _>(
+                    response,
+                    "ListComplianceStatus",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1524,7 +2252,16 @@ impl Fms for FmsClient {
         &self,
         input: ListMemberAccountsRequest,
     ) -> RusotoFuture<ListMemberAccountsResponse, ListMemberAccountsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListMemberAccounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListMemberAccounts");
@@ -1533,10 +2270,10 @@ impl Fms for FmsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListMemberAccountsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListMemberAccountsResponse, _>(
+                    response,
+                    "ListMemberAccounts",
+                )
             } else {
                 Box::new(
                     response
@@ -1553,7 +2290,16 @@ impl Fms for FmsClient {
         &self,
         input: ListPoliciesRequest,
     ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListPolicies");
@@ -1562,10 +2308,10 @@ impl Fms for FmsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPoliciesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListPoliciesResponse, _>(
+                    response,
+                    "ListPolicies",
+                )
             } else {
                 Box::new(
                     response
@@ -1582,7 +2328,16 @@ impl Fms for FmsClient {
         &self,
         input: PutNotificationChannelRequest,
     ) -> RusotoFuture<(), PutNotificationChannelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("PutNotificationChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.PutNotificationChannel");
@@ -1607,7 +2362,16 @@ impl Fms for FmsClient {
         &self,
         input: PutPolicyRequest,
     ) -> RusotoFuture<PutPolicyResponse, PutPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("PutPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.PutPolicy");
@@ -1616,10 +2380,7 @@ impl Fms for FmsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutPolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<PutPolicyResponse, _>(response, "PutPolicy")
             } else {
                 Box::new(
                     response
@@ -1631,3 +2392,455 @@ impl Fms for FmsClient {
         })
     }
 }
+
+/// A mock of the FMS API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockFms {
+    associate_admin_account: Option<
+        Box<
+            dyn Fn(
+                    AssociateAdminAccountRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<AssociateAdminAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_notification_channel: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<(), RusotoError<DeleteNotificationChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_policy: Option<
+        Box<
+            dyn Fn(DeletePolicyRequest) -> ::std::result::Result<(), RusotoError<DeletePolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disassociate_admin_account: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<(), RusotoError<DisassociateAdminAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_admin_account: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetAdminAccountResponse,
+                    RusotoError<GetAdminAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_compliance_detail: Option<
+        Box<
+            dyn Fn(
+                    GetComplianceDetailRequest,
+                ) -> ::std::result::Result<
+                    GetComplianceDetailResponse,
+                    RusotoError<GetComplianceDetailError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_notification_channel: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetNotificationChannelResponse,
+                    RusotoError<GetNotificationChannelError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_policy: Option<
+        Box<
+            dyn Fn(
+                    GetPolicyRequest,
+                )
+                    -> ::std::result::Result<GetPolicyResponse, RusotoError<GetPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_protection_status: Option<
+        Box<
+            dyn Fn(
+                    GetProtectionStatusRequest,
+                ) -> ::std::result::Result<
+                    GetProtectionStatusResponse,
+                    RusotoError<GetProtectionStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_compliance_status: Option<
+        Box<
+            dyn Fn(
+                    ListComplianceStatusRequest,
+                ) -> ::std::result::Result<
+                    ListComplianceStatusResponse,
+                    RusotoError<ListComplianceStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_member_accounts: Option<
+        Box<
+            dyn Fn(
+                    ListMemberAccountsRequest,
+                ) -> ::std::result::Result<
+                    ListMemberAccountsResponse,
+                    RusotoError<ListMemberAccountsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_policies: Option<
+        Box<
+            dyn Fn(
+                    ListPoliciesRequest,
+                )
+                    -> ::std::result::Result<ListPoliciesResponse, RusotoError<ListPoliciesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_notification_channel: Option<
+        Box<
+            dyn Fn(
+                    PutNotificationChannelRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutNotificationChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_policy: Option<
+        Box<
+            dyn Fn(
+                    PutPolicyRequest,
+                )
+                    -> ::std::result::Result<PutPolicyResponse, RusotoError<PutPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockFms {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockFms {
+        Default::default()
+    }
+
+    /// Answers `associate_admin_account` with what `handler` returns for its input.
+    pub fn with_associate_admin_account<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn(
+                AssociateAdminAccountRequest,
+            ) -> ::std::result::Result<(), RusotoError<AssociateAdminAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_admin_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_notification_channel` with what `handler` returns.
+    pub fn with_delete_notification_channel<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn() -> ::std::result::Result<(), RusotoError<DeleteNotificationChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_notification_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_policy` with what `handler` returns for its input.
+    pub fn with_delete_policy<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn(DeletePolicyRequest) -> ::std::result::Result<(), RusotoError<DeletePolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_admin_account` with what `handler` returns.
+    pub fn with_disassociate_admin_account<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn() -> ::std::result::Result<(), RusotoError<DisassociateAdminAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_admin_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_admin_account` with what `handler` returns.
+    pub fn with_get_admin_account<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn()
+                -> ::std::result::Result<GetAdminAccountResponse, RusotoError<GetAdminAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_admin_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_compliance_detail` with what `handler` returns for its input.
+    pub fn with_get_compliance_detail<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn(
+                GetComplianceDetailRequest,
+            ) -> ::std::result::Result<
+                GetComplianceDetailResponse,
+                RusotoError<GetComplianceDetailError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_compliance_detail = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_notification_channel` with what `handler` returns.
+    pub fn with_get_notification_channel<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn() -> ::std::result::Result<
+                GetNotificationChannelResponse,
+                RusotoError<GetNotificationChannelError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_notification_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_policy` with what `handler` returns for its input.
+    pub fn with_get_policy<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn(
+                GetPolicyRequest,
+            ) -> ::std::result::Result<GetPolicyResponse, RusotoError<GetPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_protection_status` with what `handler` returns for its input.
+    pub fn with_get_protection_status<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn(
+                GetProtectionStatusRequest,
+            ) -> ::std::result::Result<
+                GetProtectionStatusResponse,
+                RusotoError<GetProtectionStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_protection_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_compliance_status` with what `handler` returns for its input.
+    pub fn with_list_compliance_status<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn(
+                ListComplianceStatusRequest,
+            ) -> ::std::result::Result<
+                ListComplianceStatusResponse,
+                RusotoError<ListComplianceStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_compliance_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_member_accounts` with what `handler` returns for its input.
+    pub fn with_list_member_accounts<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn(
+                ListMemberAccountsRequest,
+            ) -> ::std::result::Result<
+                ListMemberAccountsResponse,
+                RusotoError<ListMemberAccountsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_member_accounts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_policies` with what `handler` returns for its input.
+    pub fn with_list_policies<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn(
+                ListPoliciesRequest,
+            )
+                -> ::std::result::Result<ListPoliciesResponse, RusotoError<ListPoliciesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_notification_channel` with what `handler` returns for its input.
+    pub fn with_put_notification_channel<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn(
+                PutNotificationChannelRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutNotificationChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_notification_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_policy` with what `handler` returns for its input.
+    pub fn with_put_policy<F>(mut self, handler: F) -> MockFms
+    where
+        F: Fn(
+                PutPolicyRequest,
+            ) -> ::std::result::Result<PutPolicyResponse, RusotoError<PutPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_policy = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Fms for MockFms {
+    fn associate_admin_account(
+        &self,
+        input: AssociateAdminAccountRequest,
+    ) -> RusotoFuture<(), AssociateAdminAccountError> {
+        match self.associate_admin_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFms has no handler for associate_admin_account"),
+        }
+    }
+    fn delete_notification_channel(&self) -> RusotoFuture<(), DeleteNotificationChannelError> {
+        match self.delete_notification_channel {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockFms has no handler for delete_notification_channel"),
+        }
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        match self.delete_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFms has no handler for delete_policy"),
+        }
+    }
+    fn disassociate_admin_account(&self) -> RusotoFuture<(), DisassociateAdminAccountError> {
+        match self.disassociate_admin_account {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockFms has no handler for disassociate_admin_account"),
+        }
+    }
+    fn get_admin_account(&self) -> RusotoFuture<GetAdminAccountResponse, GetAdminAccountError> {
+        match self.get_admin_account {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockFms has no handler for get_admin_account"),
+        }
+    }
+    fn get_compliance_detail(
+        &self,
+        input: GetComplianceDetailRequest,
+    ) -> RusotoFuture<GetComplianceDetailResponse, GetComplianceDetailError> {
+        match self.get_compliance_detail {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFms has no handler for get_compliance_detail"),
+        }
+    }
+    fn get_notification_channel(
+        &self,
+    ) -> RusotoFuture<GetNotificationChannelResponse, GetNotificationChannelError> {
+        match self.get_notification_channel {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockFms has no handler for get_notification_channel"),
+        }
+    }
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        match self.get_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFms has no handler for get_policy"),
+        }
+    }
+    fn get_protection_status(
+        &self,
+        input: GetProtectionStatusRequest,
+    ) -> RusotoFuture<GetProtectionStatusResponse, GetProtectionStatusError> {
+        match self.get_protection_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFms has no handler for get_protection_status"),
+        }
+    }
+    fn list_compliance_status(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> RusotoFuture<ListComplianceStatusResponse, ListComplianceStatusError> {
+        match self.list_compliance_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFms has no handler for list_compliance_status"),
+        }
+    }
+    fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> RusotoFuture<ListMemberAccountsResponse, ListMemberAccountsError> {
+        match self.list_member_accounts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFms has no handler for list_member_accounts"),
+        }
+    }
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        match self.list_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFms has no handler for list_policies"),
+        }
+    }
+    fn put_notification_channel(
+        &self,
+        input: PutNotificationChannelRequest,
+    ) -> RusotoFuture<(), PutNotificationChannelError> {
+        match self.put_notification_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFms has no handler for put_notification_channel"),
+        }
+    }
+    fn put_policy(
+        &self,
+        input: PutPolicyRequest,
+    ) -> RusotoFuture<PutPolicyResponse, PutPolicyError> {
+        match self.put_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFms has no handler for put_policy"),
+        }
+    }
+}
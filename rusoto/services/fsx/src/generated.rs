@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The Microsoft AD attributes of the Amazon FSx for Windows File Server file system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActiveDirectoryBackupAttributes {
     /// <p>The ID of the AWS Managed Microsoft Active Directory instance to which the file system is joined.</p>
     #[serde(rename = "ActiveDirectoryId")]
@@ -39,7 +40,7 @@ pub struct ActiveDirectoryBackupAttributes {
 
 /// <p>A backup of an Amazon FSx for Windows File Server file system. You can create a new file system from a backup to protect against data loss.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Backup {
     /// <p>The ID of the backup.</p>
     #[serde(rename = "BackupId")]
@@ -83,7 +84,7 @@ pub struct Backup {
 
 /// <p>If backup creation fails, this structure contains the details of that failure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BackupFailureDetails {
     /// <p>A message describing the backup creation failure.</p>
     #[serde(rename = "Message")]
@@ -93,6 +94,7 @@ pub struct BackupFailureDetails {
 
 /// <p>The request object for the <code>CreateBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBackupRequest {
     /// <p>(Optional) A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent creation. This string is automatically filled on your behalf when you use the AWS Command Line Interface (AWS CLI) or an AWS SDK.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -107,9 +109,45 @@ pub struct CreateBackupRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateBackupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[A-za-z0-9_.-]{0,63}$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            Some(11),
+            Some(21),
+        );
+        errors.check_pattern(
+            &field_path(path, "file_system_id"),
+            &self.file_system_id,
+            "^(fs-[0-9a-f]{8,})$",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>The response object for the <code>CreateBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBackupResponse {
     /// <p>A description of the backup.</p>
     #[serde(rename = "Backup")]
@@ -119,6 +157,7 @@ pub struct CreateBackupResponse {
 
 /// <p>The request object for the <code>CreateFileSystemFromBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFileSystemFromBackupRequest {
     #[serde(rename = "BackupId")]
     pub backup_id: String,
@@ -143,9 +182,72 @@ pub struct CreateFileSystemFromBackupRequest {
     pub windows_configuration: Option<CreateFileSystemWindowsConfiguration>,
 }
 
+impl Validate for CreateFileSystemFromBackupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "backup_id"),
+            self.backup_id.chars().count(),
+            Some(12),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "backup_id"),
+            &self.backup_id,
+            "^(backup-[0-9a-f]{8,})$",
+        );
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[A-za-z0-9_.-]{0,63}$",
+            );
+        }
+        if let Some(ref value) = self.security_group_ids {
+            errors.check_length(
+                &field_path(path, "security_group_ids"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "security_group_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(11), Some(20));
+                errors.check_pattern(&item_path, &item, "^(sg-[0-9a-f]{8,})$");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "subnet_ids"),
+            self.subnet_ids.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.subnet_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subnet_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(15), Some(24));
+            errors.check_pattern(&item_path, &item, "^(subnet-[0-9a-f]{8,})$");
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.windows_configuration {
+            value.validate_at(&field_path(path, "windows_configuration"), errors);
+        }
+    }
+}
+
 /// <p>The response object for the <code>CreateFileSystemFromBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFileSystemFromBackupResponse {
     /// <p>A description of the file system.</p>
     #[serde(rename = "FileSystem")]
@@ -155,6 +257,7 @@ pub struct CreateFileSystemFromBackupResponse {
 
 /// <p>The Lustre configuration for the file system being created. This value is required if <code>FileSystemType</code> is set to <code>LUSTRE</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFileSystemLustreConfiguration {
     /// <p>(Optional) The path in Amazon S3 where the root of your Amazon FSx file system is exported. The path must use the same Amazon S3 bucket as specified in ImportPath. You can provide an optional prefix to which new and changed data is to be exported from your Amazon FSx for Lustre file system. If an <code>ExportPath</code> value is not provided, Amazon FSx sets a default export path, <code>s3://import-bucket/FSxLustre[creation-timestamp]</code>. The timestamp is in UTC format, for example <code>s3://import-bucket/FSxLustre20181105T222312Z</code>.</p> <p>The Amazon S3 export bucket must be the same as the import bucket specified by <code>ImportPath</code>. If you only specify a bucket name, such as <code>s3://import-bucket</code>, you get a 1:1 mapping of file system objects to S3 bucket objects. This mapping means that the input data in S3 is overwritten on export. If you provide a custom prefix in the export path, such as <code>s3://import-bucket/[custom-optional-prefix]</code>, Amazon FSx exports the contents of your file system to that export prefix in the Amazon S3 bucket.</p>
     #[serde(rename = "ExportPath")]
@@ -174,8 +277,61 @@ pub struct CreateFileSystemLustreConfiguration {
     pub weekly_maintenance_start_time: Option<String>,
 }
 
+impl Validate for CreateFileSystemLustreConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.export_path {
+            errors.check_length(
+                &field_path(path, "export_path"),
+                value.chars().count(),
+                Some(3),
+                Some(4357),
+            );
+            errors.check_pattern(
+                &field_path(path, "export_path"),
+                &value,
+                "^[^\\u0000\\u0085\\u2028\\u2029\\r\\n]{3,4357}$",
+            );
+        }
+        if let Some(ref value) = self.import_path {
+            errors.check_length(
+                &field_path(path, "import_path"),
+                value.chars().count(),
+                Some(3),
+                Some(4357),
+            );
+            errors.check_pattern(
+                &field_path(path, "import_path"),
+                &value,
+                "^[^\\u0000\\u0085\\u2028\\u2029\\r\\n]{3,4357}$",
+            );
+        }
+        if let Some(ref value) = self.imported_file_chunk_size {
+            errors.check_range(
+                &field_path(path, "imported_file_chunk_size"),
+                *value,
+                Some(1),
+                Some(512000),
+            );
+        }
+        if let Some(ref value) = self.weekly_maintenance_start_time {
+            errors.check_length(
+                &field_path(path, "weekly_maintenance_start_time"),
+                value.chars().count(),
+                Some(7),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "weekly_maintenance_start_time"),
+                &value,
+                "^[1-7]:([01]\\d|2[0-3]):?([0-5]\\d)$",
+            );
+        }
+    }
+}
+
 /// <p>The request object used to create a new Amazon FSx file system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFileSystemRequest {
     /// <p>(Optional) A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent creation. This string is automatically filled on your behalf when you use the AWS Command Line Interface (AWS CLI) or an AWS SDK.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -210,9 +366,79 @@ pub struct CreateFileSystemRequest {
     pub windows_configuration: Option<CreateFileSystemWindowsConfiguration>,
 }
 
+impl Validate for CreateFileSystemRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[A-za-z0-9_.-]{0,63}$",
+            );
+        }
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key_id"), &value, "^.{1,2048}$");
+        }
+        if let Some(ref value) = self.lustre_configuration {
+            value.validate_at(&field_path(path, "lustre_configuration"), errors);
+        }
+        if let Some(ref value) = self.security_group_ids {
+            errors.check_length(
+                &field_path(path, "security_group_ids"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "security_group_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(11), Some(20));
+                errors.check_pattern(&item_path, &item, "^(sg-[0-9a-f]{8,})$");
+            }
+        }
+        errors.check_range(
+            &field_path(path, "storage_capacity"),
+            self.storage_capacity,
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "subnet_ids"),
+            self.subnet_ids.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.subnet_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subnet_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(15), Some(24));
+            errors.check_pattern(&item_path, &item, "^(subnet-[0-9a-f]{8,})$");
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.windows_configuration {
+            value.validate_at(&field_path(path, "windows_configuration"), errors);
+        }
+    }
+}
+
 /// <p>The response object returned after the file system is created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFileSystemResponse {
     /// <p>The configuration of the file system that was created.</p>
     #[serde(rename = "FileSystem")]
@@ -222,6 +448,7 @@ pub struct CreateFileSystemResponse {
 
 /// <p>The configuration object for the Microsoft Windows file system used in <code>CreateFileSystem</code> and <code>CreateFileSystemFromBackup</code> operations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFileSystemWindowsConfiguration {
     /// <p>The ID for an existing AWS Managed Microsoft Active Directory (AD) instance that the file system should join when it's created.</p>
     #[serde(rename = "ActiveDirectoryId")]
@@ -252,9 +479,73 @@ pub struct CreateFileSystemWindowsConfiguration {
     pub weekly_maintenance_start_time: Option<String>,
 }
 
+impl Validate for CreateFileSystemWindowsConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.active_directory_id {
+            errors.check_length(
+                &field_path(path, "active_directory_id"),
+                value.chars().count(),
+                Some(12),
+                Some(12),
+            );
+            errors.check_pattern(
+                &field_path(path, "active_directory_id"),
+                &value,
+                "^d-[0-9a-f]{10}$",
+            );
+        }
+        if let Some(ref value) = self.automatic_backup_retention_days {
+            errors.check_range(
+                &field_path(path, "automatic_backup_retention_days"),
+                *value,
+                None,
+                Some(90),
+            );
+        }
+        if let Some(ref value) = self.daily_automatic_backup_start_time {
+            errors.check_length(
+                &field_path(path, "daily_automatic_backup_start_time"),
+                value.chars().count(),
+                Some(5),
+                Some(5),
+            );
+            errors.check_pattern(
+                &field_path(path, "daily_automatic_backup_start_time"),
+                &value,
+                "^([01]\\d|2[0-3]):?([0-5]\\d)$",
+            );
+        }
+        if let Some(ref value) = self.self_managed_active_directory_configuration {
+            value.validate_at(
+                &field_path(path, "self_managed_active_directory_configuration"),
+                errors,
+            );
+        }
+        errors.check_range(
+            &field_path(path, "throughput_capacity"),
+            self.throughput_capacity,
+            Some(8),
+            Some(100000),
+        );
+        if let Some(ref value) = self.weekly_maintenance_start_time {
+            errors.check_length(
+                &field_path(path, "weekly_maintenance_start_time"),
+                value.chars().count(),
+                Some(7),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "weekly_maintenance_start_time"),
+                &value,
+                "^[1-7]:([01]\\d|2[0-3]):?([0-5]\\d)$",
+            );
+        }
+    }
+}
+
 /// <p>The data repository configuration object for Lustre file systems returned in the response of the <code>CreateFileSystem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DataRepositoryConfiguration {
     /// <p>The export path to the Amazon S3 bucket (and prefix) that you are using to store new and changed Lustre file system files in S3.</p>
     #[serde(rename = "ExportPath")]
@@ -272,6 +563,7 @@ pub struct DataRepositoryConfiguration {
 
 /// <p>The request object for <code>DeleteBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBackupRequest {
     /// <p>The ID of the backup you want to delete.</p>
     #[serde(rename = "BackupId")]
@@ -282,9 +574,38 @@ pub struct DeleteBackupRequest {
     pub client_request_token: Option<String>,
 }
 
+impl Validate for DeleteBackupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "backup_id"),
+            self.backup_id.chars().count(),
+            Some(12),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "backup_id"),
+            &self.backup_id,
+            "^(backup-[0-9a-f]{8,})$",
+        );
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[A-za-z0-9_.-]{0,63}$",
+            );
+        }
+    }
+}
+
 /// <p>The response object for <code>DeleteBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBackupResponse {
     /// <p>The ID of the backup deleted.</p>
     #[serde(rename = "BackupId")]
@@ -298,6 +619,7 @@ pub struct DeleteBackupResponse {
 
 /// <p>The request object for <code>DeleteFileSystem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFileSystemRequest {
     /// <p>(Optional) A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent deletion. This is automatically filled on your behalf when using the AWS CLI or SDK.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -311,9 +633,41 @@ pub struct DeleteFileSystemRequest {
     pub windows_configuration: Option<DeleteFileSystemWindowsConfiguration>,
 }
 
+impl Validate for DeleteFileSystemRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[A-za-z0-9_.-]{0,63}$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            Some(11),
+            Some(21),
+        );
+        errors.check_pattern(
+            &field_path(path, "file_system_id"),
+            &self.file_system_id,
+            "^(fs-[0-9a-f]{8,})$",
+        );
+        if let Some(ref value) = self.windows_configuration {
+            value.validate_at(&field_path(path, "windows_configuration"), errors);
+        }
+    }
+}
+
 /// <p>The response object for the <code>DeleteFileSystem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFileSystemResponse {
     /// <p>The ID of the file system being deleted.</p>
     #[serde(rename = "FileSystemId")]
@@ -330,6 +684,7 @@ pub struct DeleteFileSystemResponse {
 
 /// <p>The configuration object for the Microsoft Windows file system used in the <code>DeleteFileSystem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFileSystemWindowsConfiguration {
     /// <p>A set of tags for your final backup.</p>
     #[serde(rename = "FinalBackupTags")]
@@ -341,9 +696,26 @@ pub struct DeleteFileSystemWindowsConfiguration {
     pub skip_final_backup: Option<bool>,
 }
 
+impl Validate for DeleteFileSystemWindowsConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.final_backup_tags {
+            errors.check_length(
+                &field_path(path, "final_backup_tags"),
+                value.len(),
+                Some(1),
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "final_backup_tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>The response object for the Microsoft Windows file system used in the <code>DeleteFileSystem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFileSystemWindowsResponse {
     /// <p>The ID of the final backup for this file system.</p>
     #[serde(rename = "FinalBackupId")]
@@ -357,6 +729,7 @@ pub struct DeleteFileSystemWindowsResponse {
 
 /// <p>The request object for <code>DescribeBackups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBackupsRequest {
     /// <p>(Optional) IDs of the backups you want to retrieve (String). This overrides any filters. If any IDs are not found, BackupNotFound will be thrown.</p>
     #[serde(rename = "BackupIds")]
@@ -376,9 +749,50 @@ pub struct DescribeBackupsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeBackupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.backup_ids {
+            errors.check_length(&field_path(path, "backup_ids"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "backup_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(12), Some(128));
+                errors.check_pattern(&item_path, &item, "^(backup-[0-9a-f]{8,})$");
+            }
+        }
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(10));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^(?:[A-Za-z0-9+\\/]{4})*(?:[A-Za-z0-9+\\/]{2}==|[A-Za-z0-9+\\/]{3}=)?$",
+            );
+        }
+    }
+}
+
 /// <p>Response object for <code>DescribeBackups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBackupsResponse {
     /// <p>Any array of backups.</p>
     #[serde(rename = "Backups")]
@@ -392,6 +806,7 @@ pub struct DescribeBackupsResponse {
 
 /// <p>The request object for <code>DescribeFileSystems</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFileSystemsRequest {
     /// <p>(Optional) IDs of the file systems whose descriptions you want to retrieve (String).</p>
     #[serde(rename = "FileSystemIds")]
@@ -407,9 +822,48 @@ pub struct DescribeFileSystemsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeFileSystemsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.file_system_ids {
+            errors.check_length(
+                &field_path(path, "file_system_ids"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "file_system_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(11), Some(21));
+                errors.check_pattern(&item_path, &item, "^(fs-[0-9a-f]{8,})$");
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^(?:[A-Za-z0-9+\\/]{4})*(?:[A-Za-z0-9+\\/]{2}==|[A-Za-z0-9+\\/]{3}=)?$",
+            );
+        }
+    }
+}
+
 /// <p>The response object for <code>DescribeFileSystems</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFileSystemsResponse {
     /// <p>An array of file system descriptions.</p>
     #[serde(rename = "FileSystems")]
@@ -423,7 +877,7 @@ pub struct DescribeFileSystemsResponse {
 
 /// <p>A description of a specific Amazon FSx file system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FileSystem {
     /// <p>The time that the file system was created, in seconds (since 1970-01-01T00:00:00Z), also known as Unix time.</p>
     #[serde(rename = "CreationTime")]
@@ -491,7 +945,7 @@ pub struct FileSystem {
 
 /// <p>A structure providing details of any failures that occur when creating the file system has failed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FileSystemFailureDetails {
     /// <p>A message describing any failures that occurred during file system creation.</p>
     #[serde(rename = "Message")]
@@ -501,6 +955,7 @@ pub struct FileSystemFailureDetails {
 
 /// <p>A filter used to restrict the results of describe calls. You can use multiple filters to return results that meet all applied filter requirements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filter {
     /// <p>The name for this filter.</p>
     #[serde(rename = "Name")]
@@ -512,8 +967,22 @@ pub struct Filter {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for Filter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.values {
+            errors.check_length(&field_path(path, "values"), value.len(), None, Some(20));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, "^[0-9a-zA-Z\\*\\.\\\\/\\?\\-\\_]*$");
+            }
+        }
+    }
+}
+
 /// <p>The request object for <code>ListTagsForResource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>(Optional) Maximum number of tags to return in the response (integer). This parameter value must be greater than 0. The number of items that Amazon FSx returns is the minimum of the <code>MaxResults</code> parameter specified in the request and the service's internal maximum number of items per page.</p>
     #[serde(rename = "MaxResults")]
@@ -528,9 +997,42 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^(?:[A-Za-z0-9+\\/]{4})*(?:[A-Za-z0-9+\\/]{2}==|[A-Za-z0-9+\\/]{3}=)?$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(8),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "^arn:(?=[^:]+:fsx:[^:]+:\\d{12}:)((|(?=[a-z0-9-.]{1,63})(?!\\d{1,3}(\\.\\d{1,3}){3})(?![^:]*-{2})(?![^:]*-\\.)(?![^:]*\\.-)[a-z0-9].*(?<!-)):){4}(?!/).{0,1024}$");
+    }
+}
+
 /// <p>The response object for <code>ListTagsForResource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>This is present if there are more tags than returned in the response (String). You can use the <code>NextToken</code> value in the later request to fetch the tags. </p>
     #[serde(rename = "NextToken")]
@@ -544,7 +1046,7 @@ pub struct ListTagsForResourceResponse {
 
 /// <p>The configuration for the Amazon FSx for Lustre file system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LustreFileSystemConfiguration {
     #[serde(rename = "DataRepositoryConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -557,7 +1059,7 @@ pub struct LustreFileSystemConfiguration {
 
 /// <p>The configuration of the self-managed Microsoft Active Directory (AD) directory to which the Windows File Server instance is joined.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SelfManagedActiveDirectoryAttributes {
     /// <p>A list of up to two IP addresses of DNS servers or domain controllers in the self-managed AD directory.</p>
     #[serde(rename = "DnsIps")]
@@ -583,6 +1085,7 @@ pub struct SelfManagedActiveDirectoryAttributes {
 
 /// <p>The configuration that Amazon FSx uses to join the Windows File Server instance to your self-managed (including on-premises) Microsoft Active Directory (AD) directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SelfManagedActiveDirectoryConfiguration {
     /// <p><p>A list of up to two IP addresses of DNS servers or domain controllers in the self-managed AD directory. The IP addresses need to be either in the same VPC CIDR range as the one in which your Amazon FSx file system is being created, or in the private IP version 4 (Iv4) address ranges, as specified in <a href="http://www.faqs.org/rfcs/rfc1918.html">RFC 1918</a>:</p> <ul> <li> <p>10.0.0.0 - 10.255.255.255 (10/8 prefix)</p> </li> <li> <p>172.16.0.0 - 172.31.255.255 (172.16/12 prefix)</p> </li> <li> <p>192.168.0.0 - 192.168.255.255 (192.168/16 prefix)</p> </li> </ul></p>
     #[serde(rename = "DnsIps")]
@@ -606,8 +1109,80 @@ pub struct SelfManagedActiveDirectoryConfiguration {
     pub user_name: String,
 }
 
+impl Validate for SelfManagedActiveDirectoryConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dns_ips"),
+            self.dns_ips.len(),
+            Some(1),
+            Some(3),
+        );
+        for (i, item) in self.dns_ips.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "dns_ips"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(7), Some(15));
+            errors.check_pattern(&item_path, &item, "^(([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])$");
+        }
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "^[^\\u0000\\u0085\\u2028\\u2029\\r\\n]{1,255}$",
+        );
+        if let Some(ref value) = self.file_system_administrators_group {
+            errors.check_length(
+                &field_path(path, "file_system_administrators_group"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "file_system_administrators_group"),
+                &value,
+                "^[^\\u0000\\u0085\\u2028\\u2029\\r\\n]{1,256}$",
+            );
+        }
+        if let Some(ref value) = self.organizational_unit_distinguished_name {
+            errors.check_length(
+                &field_path(path, "organizational_unit_distinguished_name"),
+                value.chars().count(),
+                Some(1),
+                Some(2000),
+            );
+            errors.check_pattern(
+                &field_path(path, "organizational_unit_distinguished_name"),
+                &value,
+                "^[^\\u0000\\u0085\\u2028\\u2029\\r\\n]{1,2000}$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "password"), &self.password, "^.{1,256}$");
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "^[^\\u0000\\u0085\\u2028\\u2029\\r\\n]{1,256}$",
+        );
+    }
+}
+
 /// <p>The configuration that Amazon FSx uses to join the Windows File Server instance to the self-managed Microsoft Active Directory (AD) directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SelfManagedActiveDirectoryConfigurationUpdates {
     /// <p>A list of up to two IP addresses of DNS servers or domain controllers in the self-managed AD directory.</p>
     #[serde(rename = "DnsIps")]
@@ -623,6 +1198,41 @@ pub struct SelfManagedActiveDirectoryConfigurationUpdates {
     pub user_name: Option<String>,
 }
 
+impl Validate for SelfManagedActiveDirectoryConfigurationUpdates {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dns_ips {
+            errors.check_length(&field_path(path, "dns_ips"), value.len(), Some(1), Some(3));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dns_ips"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(7), Some(15));
+                errors.check_pattern(&item_path, &item, "^(([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])$");
+            }
+        }
+        if let Some(ref value) = self.password {
+            errors.check_length(
+                &field_path(path, "password"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "password"), &value, "^.{1,256}$");
+        }
+        if let Some(ref value) = self.user_name {
+            errors.check_length(
+                &field_path(path, "user_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "user_name"),
+                &value,
+                "^[^\\u0000\\u0085\\u2028\\u2029\\r\\n]{1,256}$",
+            );
+        }
+    }
+}
+
 /// <p>Specifies a key-value pair for a resource tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -636,8 +1246,40 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 /// <p>The request object for the <code>TagResource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the Amazon FSx resource that you want to tag.</p>
     #[serde(rename = "ResourceARN")]
@@ -647,13 +1289,36 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(8),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "^arn:(?=[^:]+:fsx:[^:]+:\\d{12}:)((|(?=[a-z0-9-.]{1,63})(?!\\d{1,3}(\\.\\d{1,3}){3})(?![^:]*-{2})(?![^:]*-\\.)(?![^:]*\\.-)[a-z0-9].*(?<!-)):){4}(?!/).{0,1024}$");
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>The response object for the <code>TagResource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>The request object for <code>UntagResource</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The ARN of the Amazon FSx resource to untag.</p>
     #[serde(rename = "ResourceARN")]
@@ -663,13 +1328,37 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(8),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "^arn:(?=[^:]+:fsx:[^:]+:\\d{12}:)((|(?=[a-z0-9-.]{1,63})(?!\\d{1,3}(\\.\\d{1,3}){3})(?![^:]*-{2})(?![^:]*-\\.)(?![^:]*\\.-)[a-z0-9].*(?<!-)):){4}(?!/).{0,1024}$");
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 /// <p>The response object for <code>UntagResource</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 /// <p>The configuration object for Amazon FSx for Lustre file systems used in the <code>UpdateFileSystem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFileSystemLustreConfiguration {
     /// <p>The preferred time to perform weekly maintenance, in the UTC time zone.</p>
     #[serde(rename = "WeeklyMaintenanceStartTime")]
@@ -677,8 +1366,27 @@ pub struct UpdateFileSystemLustreConfiguration {
     pub weekly_maintenance_start_time: Option<String>,
 }
 
+impl Validate for UpdateFileSystemLustreConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.weekly_maintenance_start_time {
+            errors.check_length(
+                &field_path(path, "weekly_maintenance_start_time"),
+                value.chars().count(),
+                Some(7),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "weekly_maintenance_start_time"),
+                &value,
+                "^[1-7]:([01]\\d|2[0-3]):?([0-5]\\d)$",
+            );
+        }
+    }
+}
+
 /// <p>The request object for the <code>UpdateFileSystem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFileSystemRequest {
     /// <p>(Optional) A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent updates. This string is automatically filled on your behalf when you use the AWS Command Line Interface (AWS CLI) or an AWS SDK.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -695,9 +1403,44 @@ pub struct UpdateFileSystemRequest {
     pub windows_configuration: Option<UpdateFileSystemWindowsConfiguration>,
 }
 
+impl Validate for UpdateFileSystemRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "[A-za-z0-9_.-]{0,63}$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            Some(11),
+            Some(21),
+        );
+        errors.check_pattern(
+            &field_path(path, "file_system_id"),
+            &self.file_system_id,
+            "^(fs-[0-9a-f]{8,})$",
+        );
+        if let Some(ref value) = self.lustre_configuration {
+            value.validate_at(&field_path(path, "lustre_configuration"), errors);
+        }
+        if let Some(ref value) = self.windows_configuration {
+            value.validate_at(&field_path(path, "windows_configuration"), errors);
+        }
+    }
+}
+
 /// <p>The response object for the <code>UpdateFileSystem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFileSystemResponse {
     /// <p>A description of the file system that was updated.</p>
     #[serde(rename = "FileSystem")]
@@ -707,6 +1450,7 @@ pub struct UpdateFileSystemResponse {
 
 /// <p>Updates the Microsoft Windows configuration for an existing Amazon FSx for Windows File Server file system. Amazon FSx overwrites existing properties with non-null values provided in the request. If you don't specify a non-null value for a property, that property is not updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFileSystemWindowsConfiguration {
     /// <p>The number of days to retain automatic backups. Setting this to 0 disables automatic backups. You can retain automatic backups for a maximum of 35 days.</p>
     #[serde(rename = "AutomaticBackupRetentionDays")]
@@ -727,9 +1471,54 @@ pub struct UpdateFileSystemWindowsConfiguration {
     pub weekly_maintenance_start_time: Option<String>,
 }
 
+impl Validate for UpdateFileSystemWindowsConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.automatic_backup_retention_days {
+            errors.check_range(
+                &field_path(path, "automatic_backup_retention_days"),
+                *value,
+                None,
+                Some(90),
+            );
+        }
+        if let Some(ref value) = self.daily_automatic_backup_start_time {
+            errors.check_length(
+                &field_path(path, "daily_automatic_backup_start_time"),
+                value.chars().count(),
+                Some(5),
+                Some(5),
+            );
+            errors.check_pattern(
+                &field_path(path, "daily_automatic_backup_start_time"),
+                &value,
+                "^([01]\\d|2[0-3]):?([0-5]\\d)$",
+            );
+        }
+        if let Some(ref value) = self.self_managed_active_directory_configuration {
+            value.validate_at(
+                &field_path(path, "self_managed_active_directory_configuration"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.weekly_maintenance_start_time {
+            errors.check_length(
+                &field_path(path, "weekly_maintenance_start_time"),
+                value.chars().count(),
+                Some(7),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "weekly_maintenance_start_time"),
+                &value,
+                "^[1-7]:([01]\\d|2[0-3]):?([0-5]\\d)$",
+            );
+        }
+    }
+}
+
 /// <p>The configuration for this Microsoft Windows file system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WindowsFileSystemConfiguration {
     /// <p>The ID for an existing Microsoft Active Directory instance that the file system should join when it's created.</p>
     #[serde(rename = "ActiveDirectoryId")]
@@ -810,7 +1599,12 @@ impl CreateBackupError {
                 "UnsupportedOperation" => {
                     return RusotoError::Service(CreateBackupError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -901,7 +1695,12 @@ impl CreateFileSystemError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -995,7 +1794,12 @@ impl CreateFileSystemFromBackupError {
                         CreateFileSystemFromBackupError::ServiceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1062,7 +1866,12 @@ impl DeleteBackupError {
                 "InternalServerError" => {
                     return RusotoError::Service(DeleteBackupError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1126,7 +1935,12 @@ impl DeleteFileSystemError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1178,7 +1992,12 @@ impl DescribeBackupsError {
                 "InternalServerError" => {
                     return RusotoError::Service(DescribeBackupsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1228,7 +2047,12 @@ impl DescribeFileSystemsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1291,7 +2115,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1348,7 +2177,12 @@ impl TagResourceError {
                 "ResourceNotFound" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1409,7 +2243,12 @@ impl UntagResourceError {
                 "ResourceNotFound" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1479,7 +2318,12 @@ impl UpdateFileSystemError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1571,6 +2415,211 @@ pub trait Fsx {
         input: UpdateFileSystemRequest,
     ) -> RusotoFuture<UpdateFileSystemResponse, UpdateFileSystemError>;
 }
+const _: Option<&dyn Fsx> = None;
+impl<'a, T: Fsx + ?Sized> Fsx for &'a T {
+    fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        (**self).create_backup(input)
+    }
+    fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> RusotoFuture<CreateFileSystemResponse, CreateFileSystemError> {
+        (**self).create_file_system(input)
+    }
+    fn create_file_system_from_backup(
+        &self,
+        input: CreateFileSystemFromBackupRequest,
+    ) -> RusotoFuture<CreateFileSystemFromBackupResponse, CreateFileSystemFromBackupError> {
+        (**self).create_file_system_from_backup(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> RusotoFuture<DeleteFileSystemResponse, DeleteFileSystemError> {
+        (**self).delete_file_system(input)
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        (**self).describe_file_systems(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> RusotoFuture<UpdateFileSystemResponse, UpdateFileSystemError> {
+        (**self).update_file_system(input)
+    }
+}
+impl<T: Fsx + ?Sized> Fsx for Box<T> {
+    fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        (**self).create_backup(input)
+    }
+    fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> RusotoFuture<CreateFileSystemResponse, CreateFileSystemError> {
+        (**self).create_file_system(input)
+    }
+    fn create_file_system_from_backup(
+        &self,
+        input: CreateFileSystemFromBackupRequest,
+    ) -> RusotoFuture<CreateFileSystemFromBackupResponse, CreateFileSystemFromBackupError> {
+        (**self).create_file_system_from_backup(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> RusotoFuture<DeleteFileSystemResponse, DeleteFileSystemError> {
+        (**self).delete_file_system(input)
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        (**self).describe_file_systems(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> RusotoFuture<UpdateFileSystemResponse, UpdateFileSystemError> {
+        (**self).update_file_system(input)
+    }
+}
+impl<T: Fsx + ?Sized> Fsx for ::std::sync::Arc<T> {
+    fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        (**self).create_backup(input)
+    }
+    fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> RusotoFuture<CreateFileSystemResponse, CreateFileSystemError> {
+        (**self).create_file_system(input)
+    }
+    fn create_file_system_from_backup(
+        &self,
+        input: CreateFileSystemFromBackupRequest,
+    ) -> RusotoFuture<CreateFileSystemFromBackupResponse, CreateFileSystemFromBackupError> {
+        (**self).create_file_system_from_backup(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> RusotoFuture<DeleteFileSystemResponse, DeleteFileSystemError> {
+        (**self).delete_file_system(input)
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        (**self).describe_file_systems(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> RusotoFuture<UpdateFileSystemResponse, UpdateFileSystemError> {
+        (**self).update_file_system(input)
+    }
+}
 /// A client for the Amazon FSx API.
 #[derive(Clone)]
 pub struct FsxClient {
@@ -1614,7 +2663,16 @@ impl Fsx for FsxClient {
         &self,
         input: CreateBackupRequest,
     ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.CreateBackup");
@@ -1623,10 +2681,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBackupResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateBackupResponse, _>(
+                    response,
+                    "CreateBackup",
+                )
             } else {
                 Box::new(
                     response
@@ -1643,7 +2701,16 @@ impl Fsx for FsxClient {
         &self,
         input: CreateFileSystemRequest,
     ) -> RusotoFuture<CreateFileSystemResponse, CreateFileSystemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateFileSystem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1655,10 +2722,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFileSystemResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateFileSystemResponse, _>(
+                    response,
+                    "CreateFileSystem",
+                )
             } else {
                 Box::new(
                     response
@@ -1675,7 +2742,16 @@ impl Fsx for FsxClient {
         &self,
         input: CreateFileSystemFromBackupRequest,
     ) -> RusotoFuture<CreateFileSystemFromBackupResponse, CreateFileSystemFromBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateFileSystemFromBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1687,10 +2763,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFileSystemFromBackupResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateFileSystemFromBackupResponse, _>(
+                    response,
+                    "CreateFileSystemFromBackup",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(CreateFileSystemFromBackupError::from_response(response))
@@ -1704,7 +2780,16 @@ impl Fsx for FsxClient {
         &self,
         input: DeleteBackupRequest,
     ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DeleteBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.DeleteBackup");
@@ -1713,10 +2798,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBackupResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteBackupResponse, _>(
+                    response,
+                    "DeleteBackup",
+                )
             } else {
                 Box::new(
                     response
@@ -1733,7 +2818,16 @@ impl Fsx for FsxClient {
         &self,
         input: DeleteFileSystemRequest,
     ) -> RusotoFuture<DeleteFileSystemResponse, DeleteFileSystemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DeleteFileSystem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1745,10 +2839,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteFileSystemResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteFileSystemResponse, _>(
+                    response,
+                    "DeleteFileSystem",
+                )
             } else {
                 Box::new(
                     response
@@ -1765,7 +2859,16 @@ impl Fsx for FsxClient {
         &self,
         input: DescribeBackupsRequest,
     ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DescribeBackups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1777,10 +2880,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBackupsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeBackupsResponse, _>(
+                    response,
+                    "DescribeBackups",
+                )
             } else {
                 Box::new(
                     response
@@ -1797,7 +2900,16 @@ impl Fsx for FsxClient {
         &self,
         input: DescribeFileSystemsRequest,
     ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DescribeFileSystems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1809,10 +2921,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeFileSystemsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeFileSystemsResponse, _>(
+                    response,
+                    "DescribeFileSystems",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1828,7 +2940,16 @@ impl Fsx for FsxClient {
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1840,10 +2961,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1859,7 +2980,16 @@ impl Fsx for FsxClient {
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.TagResource");
@@ -1868,10 +2998,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -1888,7 +3018,16 @@ impl Fsx for FsxClient {
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.UntagResource");
@@ -1897,10 +3036,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -1917,7 +3056,16 @@ impl Fsx for FsxClient {
         &self,
         input: UpdateFileSystemRequest,
     ) -> RusotoFuture<UpdateFileSystemResponse, UpdateFileSystemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("UpdateFileSystem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1929,10 +3077,10 @@ impl Fsx for FsxClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFileSystemResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateFileSystemResponse, _>(
+                    response,
+                    "UpdateFileSystem",
+                )
             } else {
                 Box::new(
                     response
@@ -1944,3 +3092,401 @@ impl Fsx for FsxClient {
         })
     }
 }
+
+/// A mock of the Amazon FSx API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockFsx {
+    create_backup: Option<
+        Box<
+            dyn Fn(
+                    CreateBackupRequest,
+                )
+                    -> ::std::result::Result<CreateBackupResponse, RusotoError<CreateBackupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_file_system: Option<
+        Box<
+            dyn Fn(
+                    CreateFileSystemRequest,
+                ) -> ::std::result::Result<
+                    CreateFileSystemResponse,
+                    RusotoError<CreateFileSystemError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_file_system_from_backup: Option<
+        Box<
+            dyn Fn(
+                    CreateFileSystemFromBackupRequest,
+                ) -> ::std::result::Result<
+                    CreateFileSystemFromBackupResponse,
+                    RusotoError<CreateFileSystemFromBackupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_backup: Option<
+        Box<
+            dyn Fn(
+                    DeleteBackupRequest,
+                )
+                    -> ::std::result::Result<DeleteBackupResponse, RusotoError<DeleteBackupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_file_system: Option<
+        Box<
+            dyn Fn(
+                    DeleteFileSystemRequest,
+                ) -> ::std::result::Result<
+                    DeleteFileSystemResponse,
+                    RusotoError<DeleteFileSystemError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_backups: Option<
+        Box<
+            dyn Fn(
+                    DescribeBackupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeBackupsResponse,
+                    RusotoError<DescribeBackupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_file_systems: Option<
+        Box<
+            dyn Fn(
+                    DescribeFileSystemsRequest,
+                ) -> ::std::result::Result<
+                    DescribeFileSystemsResponse,
+                    RusotoError<DescribeFileSystemsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_file_system: Option<
+        Box<
+            dyn Fn(
+                    UpdateFileSystemRequest,
+                ) -> ::std::result::Result<
+                    UpdateFileSystemResponse,
+                    RusotoError<UpdateFileSystemError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockFsx {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockFsx {
+        Default::default()
+    }
+
+    /// Answers `create_backup` with what `handler` returns for its input.
+    pub fn with_create_backup<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                CreateBackupRequest,
+            )
+                -> ::std::result::Result<CreateBackupResponse, RusotoError<CreateBackupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_file_system` with what `handler` returns for its input.
+    pub fn with_create_file_system<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                CreateFileSystemRequest,
+            ) -> ::std::result::Result<
+                CreateFileSystemResponse,
+                RusotoError<CreateFileSystemError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_file_system = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_file_system_from_backup` with what `handler` returns for its input.
+    pub fn with_create_file_system_from_backup<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                CreateFileSystemFromBackupRequest,
+            ) -> ::std::result::Result<
+                CreateFileSystemFromBackupResponse,
+                RusotoError<CreateFileSystemFromBackupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_file_system_from_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_backup` with what `handler` returns for its input.
+    pub fn with_delete_backup<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                DeleteBackupRequest,
+            )
+                -> ::std::result::Result<DeleteBackupResponse, RusotoError<DeleteBackupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_file_system` with what `handler` returns for its input.
+    pub fn with_delete_file_system<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                DeleteFileSystemRequest,
+            ) -> ::std::result::Result<
+                DeleteFileSystemResponse,
+                RusotoError<DeleteFileSystemError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_file_system = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_backups` with what `handler` returns for its input.
+    pub fn with_describe_backups<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                DescribeBackupsRequest,
+            )
+                -> ::std::result::Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_backups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_file_systems` with what `handler` returns for its input.
+    pub fn with_describe_file_systems<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                DescribeFileSystemsRequest,
+            ) -> ::std::result::Result<
+                DescribeFileSystemsResponse,
+                RusotoError<DescribeFileSystemsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_file_systems = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_file_system` with what `handler` returns for its input.
+    pub fn with_update_file_system<F>(mut self, handler: F) -> MockFsx
+    where
+        F: Fn(
+                UpdateFileSystemRequest,
+            ) -> ::std::result::Result<
+                UpdateFileSystemResponse,
+                RusotoError<UpdateFileSystemError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_file_system = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Fsx for MockFsx {
+    fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        match self.create_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for create_backup"),
+        }
+    }
+    fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> RusotoFuture<CreateFileSystemResponse, CreateFileSystemError> {
+        match self.create_file_system {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for create_file_system"),
+        }
+    }
+    fn create_file_system_from_backup(
+        &self,
+        input: CreateFileSystemFromBackupRequest,
+    ) -> RusotoFuture<CreateFileSystemFromBackupResponse, CreateFileSystemFromBackupError> {
+        match self.create_file_system_from_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for create_file_system_from_backup"),
+        }
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        match self.delete_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for delete_backup"),
+        }
+    }
+    fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> RusotoFuture<DeleteFileSystemResponse, DeleteFileSystemError> {
+        match self.delete_file_system {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for delete_file_system"),
+        }
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        match self.describe_backups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for describe_backups"),
+        }
+    }
+    fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        match self.describe_file_systems {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for describe_file_systems"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for list_tags_for_resource"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for untag_resource"),
+        }
+    }
+    fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> RusotoFuture<UpdateFileSystemResponse, UpdateFileSystemError> {
+        match self.update_file_system {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockFsx has no handler for update_file_system"),
+        }
+    }
+}
@@ -22,9 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptMatchInput {
     /// <p>Player response to the proposed match.</p>
     #[serde(rename = "AcceptanceType")]
@@ -37,13 +39,33 @@ pub struct AcceptMatchInput {
     pub ticket_id: String,
 }
 
+impl Validate for AcceptMatchInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.player_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "player_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+        }
+        errors.check_length(
+            &field_path(path, "ticket_id"),
+            self.ticket_id.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "ticket_id"),
+            &self.ticket_id,
+            "[a-zA-Z0-9-\\.]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcceptMatchOutput {}
 
 /// <p><p>Properties describing a fleet alias.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Alias {
     /// <p>Unique identifier for an alias; alias ARNs are unique across all regions.</p>
     #[serde(rename = "AliasArn")]
@@ -96,9 +118,28 @@ pub struct AttributeValue {
     pub sl: Option<Vec<String>>,
 }
 
+impl Validate for AttributeValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.s {
+            errors.check_length(
+                &field_path(path, "s"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.sl {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sl"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+            }
+        }
+    }
+}
+
 /// <p>Temporary access credentials used for uploading game build files to Amazon GameLift. They are valid for a limited time. If they expire before you upload your game build, get a new set by calling <a>RequestUploadCredentials</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AwsCredentials {
     /// <p>Temporary key allowing access to the Amazon GameLift S3 account.</p>
     #[serde(rename = "AccessKeyId")]
@@ -116,7 +157,7 @@ pub struct AwsCredentials {
 
 /// <p><p>Properties describing a custom game build.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Build {
     /// <p>Unique identifier for a build.</p>
     #[serde(rename = "BuildId")]
@@ -156,6 +197,7 @@ pub struct CertificateConfiguration {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAliasInput {
     /// <p>Human-readable description of an alias.</p>
     #[serde(rename = "Description")]
@@ -169,9 +211,31 @@ pub struct CreateAliasInput {
     pub routing_strategy: RoutingStrategy,
 }
 
+impl Validate for CreateAliasInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+        self.routing_strategy
+            .validate_at(&field_path(path, "routing_strategy"), errors);
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAliasOutput {
     /// <p>Object that describes the newly created alias record.</p>
     #[serde(rename = "Alias")]
@@ -181,6 +245,7 @@ pub struct CreateAliasOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBuildInput {
     /// <p>Descriptive label that is associated with a build. Build names do not need to be unique. You can use <a>UpdateBuild</a> to change this value later. </p>
     #[serde(rename = "Name")]
@@ -200,9 +265,33 @@ pub struct CreateBuildInput {
     pub version: Option<String>,
 }
 
+impl Validate for CreateBuildInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.storage_location {
+            value.validate_at(&field_path(path, "storage_location"), errors);
+        }
+        if let Some(ref value) = self.version {
+            errors.check_length(
+                &field_path(path, "version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBuildOutput {
     /// <p>The newly created build record, including a unique build ID and status. </p>
     #[serde(rename = "Build")]
@@ -220,6 +309,7 @@ pub struct CreateBuildOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFleetInput {
     /// <p>Unique identifier for a build to be deployed on the new fleet. The custom game server build must have been successfully uploaded to Amazon GameLift and be in a <code>READY</code> status. This fleet setting cannot be changed once the fleet is created.</p>
     #[serde(rename = "BuildId")]
@@ -292,9 +382,125 @@ pub struct CreateFleetInput {
     pub server_launch_path: Option<String>,
 }
 
+impl Validate for CreateFleetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.build_id {
+            errors.check_pattern(
+                &field_path(path, "build_id"),
+                &value,
+                "^build-\\S+|^arn:.*:build\\/build-\\S+",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.ec2_inbound_permissions {
+            errors.check_length(
+                &field_path(path, "ec2_inbound_permissions"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "ec2_inbound_permissions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.instance_role_arn {
+            errors.check_length(
+                &field_path(path, "instance_role_arn"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.log_paths {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "log_paths"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            }
+        }
+        if let Some(ref value) = self.metric_groups {
+            errors.check_length(
+                &field_path(path, "metric_groups"),
+                value.len(),
+                None,
+                Some(1),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "metric_groups"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        if let Some(ref value) = self.peer_vpc_aws_account_id {
+            errors.check_length(
+                &field_path(path, "peer_vpc_aws_account_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.peer_vpc_id {
+            errors.check_length(
+                &field_path(path, "peer_vpc_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.runtime_configuration {
+            value.validate_at(&field_path(path, "runtime_configuration"), errors);
+        }
+        if let Some(ref value) = self.script_id {
+            errors.check_pattern(
+                &field_path(path, "script_id"),
+                &value,
+                "^script-\\S+|^arn:.*:script\\/script-\\S+",
+            );
+        }
+        if let Some(ref value) = self.server_launch_parameters {
+            errors.check_length(
+                &field_path(path, "server_launch_parameters"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "server_launch_parameters"),
+                &value,
+                "[A-Za-z0-9_:.+\\/\\\\\\- =@;{},?'\\[\\]\"]+",
+            );
+        }
+        if let Some(ref value) = self.server_launch_path {
+            errors.check_length(
+                &field_path(path, "server_launch_path"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "server_launch_path"),
+                &value,
+                "[A-Za-z0-9_:.+\\/\\\\\\- ]+",
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFleetOutput {
     /// <p>Properties for the newly created fleet.</p>
     #[serde(rename = "FleetAttributes")]
@@ -304,6 +510,7 @@ pub struct CreateFleetOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGameSessionInput {
     /// <p>Unique identifier for an alias associated with the fleet to create a game session in. Each request must reference either a fleet ID or alias ID, but not both.</p>
     #[serde(rename = "AliasId")]
@@ -342,9 +549,90 @@ pub struct CreateGameSessionInput {
     pub name: Option<String>,
 }
 
+impl Validate for CreateGameSessionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.alias_id {
+            errors.check_pattern(
+                &field_path(path, "alias_id"),
+                &value,
+                "^alias-\\S+|^arn:.*:alias\\/alias-\\S+",
+            );
+        }
+        if let Some(ref value) = self.creator_id {
+            errors.check_length(
+                &field_path(path, "creator_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.fleet_id {
+            errors.check_pattern(
+                &field_path(path, "fleet_id"),
+                &value,
+                "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+            );
+        }
+        if let Some(ref value) = self.game_properties {
+            errors.check_length(
+                &field_path(path, "game_properties"),
+                value.len(),
+                None,
+                Some(16),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "game_properties"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.game_session_data {
+            errors.check_length(
+                &field_path(path, "game_session_data"),
+                value.chars().count(),
+                Some(1),
+                Some(262144),
+            );
+        }
+        if let Some(ref value) = self.game_session_id {
+            errors.check_length(
+                &field_path(path, "game_session_id"),
+                value.chars().count(),
+                Some(1),
+                Some(48),
+            );
+            errors.check_pattern(
+                &field_path(path, "game_session_id"),
+                &value,
+                "[a-zA-Z0-9-]+",
+            );
+        }
+        if let Some(ref value) = self.idempotency_token {
+            errors.check_length(
+                &field_path(path, "idempotency_token"),
+                value.chars().count(),
+                Some(1),
+                Some(48),
+            );
+            errors.check_pattern(
+                &field_path(path, "idempotency_token"),
+                &value,
+                "[a-zA-Z0-9-]+",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGameSessionOutput {
     /// <p>Object that describes the newly created game session record.</p>
     #[serde(rename = "GameSession")]
@@ -354,6 +642,7 @@ pub struct CreateGameSessionOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGameSessionQueueInput {
     /// <p>List of fleets that can be used to fulfill game session placement requests in the queue. Fleets are identified by either a fleet ARN or a fleet alias ARN. Destinations are listed in default preference order.</p>
     #[serde(rename = "Destinations")]
@@ -372,9 +661,27 @@ pub struct CreateGameSessionQueueInput {
     pub timeout_in_seconds: Option<i64>,
 }
 
+impl Validate for CreateGameSessionQueueInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destinations {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "destinations"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9-]+");
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGameSessionQueueOutput {
     /// <p>Object that describes the newly created game session queue.</p>
     #[serde(rename = "GameSessionQueue")]
@@ -384,6 +691,7 @@ pub struct CreateGameSessionQueueOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMatchmakingConfigurationInput {
     /// <p>Flag that determines whether a match that was created with this configuration must be accepted by the matched players. To require acceptance, set to TRUE.</p>
     #[serde(rename = "AcceptanceRequired")]
@@ -434,9 +742,100 @@ pub struct CreateMatchmakingConfigurationInput {
     pub rule_set_name: String,
 }
 
+impl Validate for CreateMatchmakingConfigurationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.acceptance_timeout_seconds {
+            errors.check_range(
+                &field_path(path, "acceptance_timeout_seconds"),
+                *value,
+                Some(1),
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.custom_event_data {
+            errors.check_length(
+                &field_path(path, "custom_event_data"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.game_properties {
+            errors.check_length(
+                &field_path(path, "game_properties"),
+                value.len(),
+                None,
+                Some(16),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "game_properties"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.game_session_data {
+            errors.check_length(
+                &field_path(path, "game_session_data"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+        }
+        for (i, item) in self.game_session_queue_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "game_session_queue_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            errors.check_pattern(&item_path, &item, "[a-zA-Z0-9:/-]+");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9-\\.]*");
+        if let Some(ref value) = self.notification_target {
+            errors.check_length(
+                &field_path(path, "notification_target"),
+                value.chars().count(),
+                None,
+                Some(300),
+            );
+            errors.check_pattern(
+                &field_path(path, "notification_target"),
+                &value,
+                "[a-zA-Z0-9:_/-]*(.fifo)?",
+            );
+        }
+        errors.check_range(
+            &field_path(path, "request_timeout_seconds"),
+            self.request_timeout_seconds,
+            Some(1),
+            Some(43200),
+        );
+        errors.check_length(
+            &field_path(path, "rule_set_name"),
+            self.rule_set_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "rule_set_name"),
+            &self.rule_set_name,
+            "[a-zA-Z0-9-\\.]*|^arn:.*:matchmakingruleset\\/[a-zA-Z0-9-\\.]*",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateMatchmakingConfigurationOutput {
     /// <p>Object that describes the newly created matchmaking configuration.</p>
     #[serde(rename = "Configuration")]
@@ -446,6 +845,7 @@ pub struct CreateMatchmakingConfigurationOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMatchmakingRuleSetInput {
     /// <p>Unique identifier for a matchmaking rule set. A matchmaking configuration identifies the rule set it uses by this name value. (Note: The rule set name is different from the optional "name" field in the rule set body.) </p>
     #[serde(rename = "Name")]
@@ -455,9 +855,27 @@ pub struct CreateMatchmakingRuleSetInput {
     pub rule_set_body: String,
 }
 
+impl Validate for CreateMatchmakingRuleSetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9-\\.]*");
+        errors.check_length(
+            &field_path(path, "rule_set_body"),
+            self.rule_set_body.chars().count(),
+            Some(1),
+            Some(65535),
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateMatchmakingRuleSetOutput {
     /// <p>Object that describes the newly created matchmaking rule set.</p>
     #[serde(rename = "RuleSet")]
@@ -466,6 +884,7 @@ pub struct CreateMatchmakingRuleSetOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePlayerSessionInput {
     /// <p>Unique identifier for the game session to add a player to.</p>
     #[serde(rename = "GameSessionId")]
@@ -479,9 +898,39 @@ pub struct CreatePlayerSessionInput {
     pub player_id: String,
 }
 
+impl Validate for CreatePlayerSessionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "game_session_id"),
+            self.game_session_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "game_session_id"),
+            &self.game_session_id,
+            "[a-zA-Z0-9:/-]+",
+        );
+        if let Some(ref value) = self.player_data {
+            errors.check_length(
+                &field_path(path, "player_data"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "player_id"),
+            self.player_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePlayerSessionOutput {
     /// <p>Object that describes the newly created player session record.</p>
     #[serde(rename = "PlayerSession")]
@@ -491,6 +940,7 @@ pub struct CreatePlayerSessionOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePlayerSessionsInput {
     /// <p>Unique identifier for the game session to add players to.</p>
     #[serde(rename = "GameSessionId")]
@@ -504,9 +954,41 @@ pub struct CreatePlayerSessionsInput {
     pub player_ids: Vec<String>,
 }
 
+impl Validate for CreatePlayerSessionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "game_session_id"),
+            self.game_session_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "game_session_id"),
+            &self.game_session_id,
+            "[a-zA-Z0-9:/-]+",
+        );
+        if let Some(ref value) = self.player_data_map {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "player_data_map"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "player_ids"),
+            self.player_ids.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.player_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "player_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePlayerSessionsOutput {
     /// <p>Collection of player session objects created for the added players.</p>
     #[serde(rename = "PlayerSessions")]
@@ -515,6 +997,7 @@ pub struct CreatePlayerSessionsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateScriptInput {
     /// <p>Descriptive label that is associated with a script. Script names do not need to be unique. You can use <a>UpdateScript</a> to change this value later. </p>
     #[serde(rename = "Name")]
@@ -539,8 +1022,40 @@ pub struct CreateScriptInput {
     pub zip_file: Option<bytes::Bytes>,
 }
 
+impl Validate for CreateScriptInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.storage_location {
+            value.validate_at(&field_path(path, "storage_location"), errors);
+        }
+        if let Some(ref value) = self.version {
+            errors.check_length(
+                &field_path(path, "version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.zip_file {
+            errors.check_length(
+                &field_path(path, "zip_file"),
+                value.len(),
+                None,
+                Some(5000000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateScriptOutput {
     /// <p>The newly created script record with a unique script ID. The new script's storage location reflects an Amazon S3 location: (1) If the script was uploaded from an S3 bucket under your account, the storage location reflects the information that was provided in the <i>CreateScript</i> request; (2) If the script file was uploaded from a local zip file, the storage location reflects an S3 location controls by the Amazon GameLift service.</p>
     #[serde(rename = "Script")]
@@ -550,6 +1065,7 @@ pub struct CreateScriptOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVpcPeeringAuthorizationInput {
     /// <p>Unique identifier for the AWS account that you use to manage your Amazon GameLift fleet. You can find your Account ID in the AWS Management Console under account settings.</p>
     #[serde(rename = "GameLiftAwsAccountId")]
@@ -559,9 +1075,26 @@ pub struct CreateVpcPeeringAuthorizationInput {
     pub peer_vpc_id: String,
 }
 
+impl Validate for CreateVpcPeeringAuthorizationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "game_lift_aws_account_id"),
+            self.game_lift_aws_account_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "peer_vpc_id"),
+            self.peer_vpc_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateVpcPeeringAuthorizationOutput {
     /// <p>Details on the requested VPC peering authorization, including expiration.</p>
     #[serde(rename = "VpcPeeringAuthorization")]
@@ -571,6 +1104,7 @@ pub struct CreateVpcPeeringAuthorizationOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVpcPeeringConnectionInput {
     /// <p>Unique identifier for a fleet. This tells Amazon GameLift which GameLift VPC to peer with. </p>
     #[serde(rename = "FleetId")]
@@ -583,73 +1117,176 @@ pub struct CreateVpcPeeringConnectionInput {
     pub peer_vpc_id: String,
 }
 
+impl Validate for CreateVpcPeeringConnectionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "fleet_id"), &self.fleet_id, "^fleet-\\S+");
+        errors.check_length(
+            &field_path(path, "peer_vpc_aws_account_id"),
+            self.peer_vpc_aws_account_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "peer_vpc_id"),
+            self.peer_vpc_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateVpcPeeringConnectionOutput {}
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAliasInput {
     /// <p>Unique identifier for a fleet alias. Specify the alias you want to delete.</p>
     #[serde(rename = "AliasId")]
     pub alias_id: String,
 }
 
+impl Validate for DeleteAliasInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "alias_id"),
+            &self.alias_id,
+            "^alias-\\S+|^arn:.*:alias\\/alias-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBuildInput {
     /// <p>Unique identifier for a build to delete.</p>
     #[serde(rename = "BuildId")]
     pub build_id: String,
 }
 
+impl Validate for DeleteBuildInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "build_id"),
+            &self.build_id,
+            "^build-\\S+|^arn:.*:build\\/build-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFleetInput {
     /// <p>Unique identifier for a fleet to be deleted.</p>
     #[serde(rename = "FleetId")]
     pub fleet_id: String,
 }
 
+impl Validate for DeleteFleetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGameSessionQueueInput {
     /// <p>Descriptive label that is associated with game session queue. Queue names must be unique within each region.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteGameSessionQueueInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[a-zA-Z0-9-]+|^arn:.*:gamesessionqueue\\/[a-zA-Z0-9-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteGameSessionQueueOutput {}
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMatchmakingConfigurationInput {
     /// <p>Unique identifier for a matchmaking configuration</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteMatchmakingConfigurationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[a-zA-Z0-9-\\.]*|^arn:.*:matchmakingconfiguration\\/[a-zA-Z0-9-\\.]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteMatchmakingConfigurationOutput {}
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMatchmakingRuleSetInput {
     /// <p>Unique identifier for a matchmaking rule set to be deleted. (Note: The rule set name is different from the optional "name" field in the rule set body.) </p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteMatchmakingRuleSetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[a-zA-Z0-9-\\.]*|^arn:.*:matchmakingruleset\\/[a-zA-Z0-9-\\.]*",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteMatchmakingRuleSetOutput {}
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteScalingPolicyInput {
     /// <p>Unique identifier for a fleet to be deleted.</p>
     #[serde(rename = "FleetId")]
@@ -659,15 +1296,43 @@ pub struct DeleteScalingPolicyInput {
     pub name: String,
 }
 
+impl Validate for DeleteScalingPolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteScriptInput {
     /// <p>Unique identifier for a Realtime script to delete.</p>
     #[serde(rename = "ScriptId")]
     pub script_id: String,
 }
 
+impl Validate for DeleteScriptInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "script_id"),
+            &self.script_id,
+            "^script-\\S+|^arn:.*:script\\/script-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVpcPeeringAuthorizationInput {
     /// <p>Unique identifier for the AWS account that you use to manage your Amazon GameLift fleet. You can find your Account ID in the AWS Management Console under account settings.</p>
     #[serde(rename = "GameLiftAwsAccountId")]
@@ -677,12 +1342,30 @@ pub struct DeleteVpcPeeringAuthorizationInput {
     pub peer_vpc_id: String,
 }
 
+impl Validate for DeleteVpcPeeringAuthorizationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "game_lift_aws_account_id"),
+            self.game_lift_aws_account_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "peer_vpc_id"),
+            self.peer_vpc_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteVpcPeeringAuthorizationOutput {}
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVpcPeeringConnectionInput {
     /// <p>Unique identifier for a fleet. This value must match the fleet ID referenced in the VPC peering connection record.</p>
     #[serde(rename = "FleetId")]
@@ -692,21 +1375,44 @@ pub struct DeleteVpcPeeringConnectionInput {
     pub vpc_peering_connection_id: String,
 }
 
+impl Validate for DeleteVpcPeeringConnectionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "fleet_id"), &self.fleet_id, "^fleet-\\S+");
+        errors.check_length(
+            &field_path(path, "vpc_peering_connection_id"),
+            self.vpc_peering_connection_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteVpcPeeringConnectionOutput {}
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAliasInput {
     /// <p>Unique identifier for a fleet alias. Specify the alias you want to retrieve.</p>
     #[serde(rename = "AliasId")]
     pub alias_id: String,
 }
 
+impl Validate for DescribeAliasInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "alias_id"),
+            &self.alias_id,
+            "^alias-\\S+|^arn:.*:alias\\/alias-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAliasOutput {
     /// <p>Object that contains the requested alias.</p>
     #[serde(rename = "Alias")]
@@ -716,15 +1422,26 @@ pub struct DescribeAliasOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBuildInput {
     /// <p>Unique identifier for a build to retrieve properties for.</p>
     #[serde(rename = "BuildId")]
     pub build_id: String,
 }
 
+impl Validate for DescribeBuildInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "build_id"),
+            &self.build_id,
+            "^build-\\S+|^arn:.*:build\\/build-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBuildOutput {
     /// <p>Set of properties describing the requested build.</p>
     #[serde(rename = "Build")]
@@ -734,6 +1451,7 @@ pub struct DescribeBuildOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEC2InstanceLimitsInput {
     /// <p>Name of an EC2 instance type that is supported in Amazon GameLift. A fleet instance type determines the computing resources of each instance in the fleet, including CPU, memory, storage, and networking capacity. Amazon GameLift supports the following EC2 instance types. See <a href="http://aws.amazon.com/ec2/instance-types/">Amazon EC2 Instance Types</a> for detailed descriptions. Leave this parameter blank to retrieve limits for all types.</p>
     #[serde(rename = "EC2InstanceType")]
@@ -743,7 +1461,7 @@ pub struct DescribeEC2InstanceLimitsInput {
 
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEC2InstanceLimitsOutput {
     /// <p>Object that contains the maximum number of instances for the specified instance type.</p>
     #[serde(rename = "EC2InstanceLimits")]
@@ -753,6 +1471,7 @@ pub struct DescribeEC2InstanceLimitsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFleetAttributesInput {
     /// <p>Unique identifier for a fleet(s) to retrieve attributes for. To request attributes for all fleets, leave this parameter empty.</p>
     #[serde(rename = "FleetIds")]
@@ -768,9 +1487,32 @@ pub struct DescribeFleetAttributesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeFleetAttributesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.fleet_ids {
+            errors.check_length(&field_path(path, "fleet_ids"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "fleet_ids"), i);
+                errors.check_pattern(&item_path, &item, "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+");
+            }
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFleetAttributesOutput {
     /// <p>Collection of objects containing attribute metadata for each requested fleet ID.</p>
     #[serde(rename = "FleetAttributes")]
@@ -784,6 +1526,7 @@ pub struct DescribeFleetAttributesOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFleetCapacityInput {
     /// <p>Unique identifier for a fleet(s) to retrieve capacity information for. To request capacity information for all fleets, leave this parameter empty.</p>
     #[serde(rename = "FleetIds")]
@@ -799,9 +1542,32 @@ pub struct DescribeFleetCapacityInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeFleetCapacityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.fleet_ids {
+            errors.check_length(&field_path(path, "fleet_ids"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "fleet_ids"), i);
+                errors.check_pattern(&item_path, &item, "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+");
+            }
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFleetCapacityOutput {
     /// <p>Collection of objects containing capacity information for each requested fleet ID. Leave this parameter empty to retrieve capacity information for all fleets.</p>
     #[serde(rename = "FleetCapacity")]
@@ -815,6 +1581,7 @@ pub struct DescribeFleetCapacityOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFleetEventsInput {
     /// <p>Most recent date to retrieve event logs for. If no end time is specified, this call returns entries from the specified start time up to the present. Format is a number expressed in Unix time as milliseconds (ex: "1469498468.057").</p>
     #[serde(rename = "EndTime")]
@@ -837,9 +1604,30 @@ pub struct DescribeFleetEventsInput {
     pub start_time: Option<f64>,
 }
 
+impl Validate for DescribeFleetEventsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFleetEventsOutput {
     /// <p>Collection of objects containing event log entries for the specified fleet.</p>
     #[serde(rename = "Events")]
@@ -853,15 +1641,26 @@ pub struct DescribeFleetEventsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFleetPortSettingsInput {
     /// <p>Unique identifier for a fleet to retrieve port settings for.</p>
     #[serde(rename = "FleetId")]
     pub fleet_id: String,
 }
 
+impl Validate for DescribeFleetPortSettingsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFleetPortSettingsOutput {
     /// <p>Object that contains port settings for the requested fleet ID.</p>
     #[serde(rename = "InboundPermissions")]
@@ -871,6 +1670,7 @@ pub struct DescribeFleetPortSettingsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFleetUtilizationInput {
     /// <p>Unique identifier for a fleet(s) to retrieve utilization data for. To request utilization data for all fleets, leave this parameter empty.</p>
     #[serde(rename = "FleetIds")]
@@ -886,9 +1686,32 @@ pub struct DescribeFleetUtilizationInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeFleetUtilizationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.fleet_ids {
+            errors.check_length(&field_path(path, "fleet_ids"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "fleet_ids"), i);
+                errors.check_pattern(&item_path, &item, "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+");
+            }
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFleetUtilizationOutput {
     /// <p>Collection of objects containing utilization information for each requested fleet ID.</p>
     #[serde(rename = "FleetUtilization")]
@@ -902,6 +1725,7 @@ pub struct DescribeFleetUtilizationOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeGameSessionDetailsInput {
     /// <p>Unique identifier for an alias associated with the fleet to retrieve all game sessions for.</p>
     #[serde(rename = "AliasId")]
@@ -929,9 +1753,60 @@ pub struct DescribeGameSessionDetailsInput {
     pub status_filter: Option<String>,
 }
 
+impl Validate for DescribeGameSessionDetailsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.alias_id {
+            errors.check_pattern(
+                &field_path(path, "alias_id"),
+                &value,
+                "^alias-\\S+|^arn:.*:alias\\/alias-\\S+",
+            );
+        }
+        if let Some(ref value) = self.fleet_id {
+            errors.check_pattern(
+                &field_path(path, "fleet_id"),
+                &value,
+                "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+            );
+        }
+        if let Some(ref value) = self.game_session_id {
+            errors.check_length(
+                &field_path(path, "game_session_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "game_session_id"),
+                &value,
+                "[a-zA-Z0-9:/-]+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.status_filter {
+            errors.check_length(
+                &field_path(path, "status_filter"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeGameSessionDetailsOutput {
     /// <p>Collection of objects containing game session properties and the protection policy currently in force for each session matching the request.</p>
     #[serde(rename = "GameSessionDetails")]
@@ -945,15 +1820,32 @@ pub struct DescribeGameSessionDetailsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeGameSessionPlacementInput {
     /// <p>Unique identifier for a game session placement to retrieve.</p>
     #[serde(rename = "PlacementId")]
     pub placement_id: String,
 }
 
+impl Validate for DescribeGameSessionPlacementInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "placement_id"),
+            self.placement_id.chars().count(),
+            Some(1),
+            Some(48),
+        );
+        errors.check_pattern(
+            &field_path(path, "placement_id"),
+            &self.placement_id,
+            "[a-zA-Z0-9-]+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeGameSessionPlacementOutput {
     /// <p>Object that describes the requested game session placement.</p>
     #[serde(rename = "GameSessionPlacement")]
@@ -963,6 +1855,7 @@ pub struct DescribeGameSessionPlacementOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeGameSessionQueuesInput {
     /// <p>Maximum number of results to return. Use this parameter with <code>NextToken</code> to get results as a set of sequential pages.</p>
     #[serde(rename = "Limit")]
@@ -978,9 +1871,36 @@ pub struct DescribeGameSessionQueuesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeGameSessionQueuesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[a-zA-Z0-9-]+|^arn:.*:gamesessionqueue\\/[a-zA-Z0-9-]+",
+                );
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeGameSessionQueuesOutput {
     /// <p>Collection of objects that describes the requested game session queues.</p>
     #[serde(rename = "GameSessionQueues")]
@@ -994,6 +1914,7 @@ pub struct DescribeGameSessionQueuesOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeGameSessionsInput {
     /// <p>Unique identifier for an alias associated with the fleet to retrieve all game sessions for. </p>
     #[serde(rename = "AliasId")]
@@ -1021,9 +1942,60 @@ pub struct DescribeGameSessionsInput {
     pub status_filter: Option<String>,
 }
 
+impl Validate for DescribeGameSessionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.alias_id {
+            errors.check_pattern(
+                &field_path(path, "alias_id"),
+                &value,
+                "^alias-\\S+|^arn:.*:alias\\/alias-\\S+",
+            );
+        }
+        if let Some(ref value) = self.fleet_id {
+            errors.check_pattern(
+                &field_path(path, "fleet_id"),
+                &value,
+                "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+            );
+        }
+        if let Some(ref value) = self.game_session_id {
+            errors.check_length(
+                &field_path(path, "game_session_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "game_session_id"),
+                &value,
+                "[a-zA-Z0-9:/-]+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.status_filter {
+            errors.check_length(
+                &field_path(path, "status_filter"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeGameSessionsOutput {
     /// <p>Collection of objects containing game session properties for each session matching the request.</p>
     #[serde(rename = "GameSessions")]
@@ -1037,6 +2009,7 @@ pub struct DescribeGameSessionsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInstancesInput {
     /// <p>Unique identifier for a fleet to retrieve instance information for.</p>
     #[serde(rename = "FleetId")]
@@ -1055,9 +2028,33 @@ pub struct DescribeInstancesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeInstancesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+        if let Some(ref value) = self.instance_id {
+            errors.check_pattern(&field_path(path, "instance_id"), &value, "[a-zA-Z0-9\\.-]+");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInstancesOutput {
     /// <p>Collection of objects containing properties for each instance returned.</p>
     #[serde(rename = "Instances")]
@@ -1071,6 +2068,7 @@ pub struct DescribeInstancesOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMatchmakingConfigurationsInput {
     /// <p>Maximum number of results to return. Use this parameter with <code>NextToken</code> to get results as a set of sequential pages. This parameter is limited to 10.</p>
     #[serde(rename = "Limit")]
@@ -1090,9 +2088,49 @@ pub struct DescribeMatchmakingConfigurationsInput {
     pub rule_set_name: Option<String>,
 }
 
+impl Validate for DescribeMatchmakingConfigurationsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[a-zA-Z0-9-\\.]*|^arn:.*:matchmakingconfiguration\\/[a-zA-Z0-9-\\.]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.rule_set_name {
+            errors.check_length(
+                &field_path(path, "rule_set_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "rule_set_name"),
+                &value,
+                "[a-zA-Z0-9-\\.]*|^arn:.*:matchmakingruleset\\/[a-zA-Z0-9-\\.]*",
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMatchmakingConfigurationsOutput {
     /// <p>Collection of requested matchmaking configuration objects.</p>
     #[serde(rename = "Configurations")]
@@ -1106,15 +2144,26 @@ pub struct DescribeMatchmakingConfigurationsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMatchmakingInput {
     /// <p>Unique identifier for a matchmaking ticket. You can include up to 10 ID values. </p>
     #[serde(rename = "TicketIds")]
     pub ticket_ids: Vec<String>,
 }
 
+impl Validate for DescribeMatchmakingInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.ticket_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "ticket_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(128));
+            errors.check_pattern(&item_path, &item, "[a-zA-Z0-9-\\.]*");
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMatchmakingOutput {
     /// <p>Collection of existing matchmaking ticket objects matching the request.</p>
     #[serde(rename = "TicketList")]
@@ -1124,6 +2173,7 @@ pub struct DescribeMatchmakingOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMatchmakingRuleSetsInput {
     /// <p>Maximum number of results to return. Use this parameter with <code>NextToken</code> to get results as a set of sequential pages.</p>
     #[serde(rename = "Limit")]
@@ -1139,9 +2189,37 @@ pub struct DescribeMatchmakingRuleSetsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeMatchmakingRuleSetsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(10));
+        }
+        if let Some(ref value) = self.names {
+            errors.check_length(&field_path(path, "names"), value.len(), Some(1), Some(10));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[a-zA-Z0-9-\\.]*|^arn:.*:matchmakingruleset\\/[a-zA-Z0-9-\\.]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMatchmakingRuleSetsOutput {
     /// <p>Token that indicates where to resume retrieving results on the next call to this action. If no token is returned, these results represent the end of the list.</p>
     #[serde(rename = "NextToken")]
@@ -1154,6 +2232,7 @@ pub struct DescribeMatchmakingRuleSetsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePlayerSessionsInput {
     /// <p>Unique identifier for the game session to retrieve player sessions for.</p>
     #[serde(rename = "GameSessionId")]
@@ -1181,9 +2260,61 @@ pub struct DescribePlayerSessionsInput {
     pub player_session_status_filter: Option<String>,
 }
 
+impl Validate for DescribePlayerSessionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.game_session_id {
+            errors.check_length(
+                &field_path(path, "game_session_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "game_session_id"),
+                &value,
+                "[a-zA-Z0-9:/-]+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.player_id {
+            errors.check_length(
+                &field_path(path, "player_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.player_session_id {
+            errors.check_pattern(
+                &field_path(path, "player_session_id"),
+                &value,
+                "^psess-\\S+",
+            );
+        }
+        if let Some(ref value) = self.player_session_status_filter {
+            errors.check_length(
+                &field_path(path, "player_session_status_filter"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePlayerSessionsOutput {
     /// <p>Token that indicates where to resume retrieving results on the next call to this action. If no token is returned, these results represent the end of the list.</p>
     #[serde(rename = "NextToken")]
@@ -1197,15 +2328,26 @@ pub struct DescribePlayerSessionsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRuntimeConfigurationInput {
     /// <p>Unique identifier for a fleet to get the run-time configuration for.</p>
     #[serde(rename = "FleetId")]
     pub fleet_id: String,
 }
 
+impl Validate for DescribeRuntimeConfigurationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRuntimeConfigurationOutput {
     /// <p>Instructions describing how server processes should be launched and maintained on each instance in the fleet.</p>
     #[serde(rename = "RuntimeConfiguration")]
@@ -1215,6 +2357,7 @@ pub struct DescribeRuntimeConfigurationOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScalingPoliciesInput {
     /// <p>Unique identifier for a fleet to retrieve scaling policies for.</p>
     #[serde(rename = "FleetId")]
@@ -1233,9 +2376,30 @@ pub struct DescribeScalingPoliciesInput {
     pub status_filter: Option<String>,
 }
 
+impl Validate for DescribeScalingPoliciesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeScalingPoliciesOutput {
     /// <p>Token that indicates where to resume retrieving results on the next call to this action. If no token is returned, these results represent the end of the list.</p>
     #[serde(rename = "NextToken")]
@@ -1248,14 +2412,25 @@ pub struct DescribeScalingPoliciesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScriptInput {
     /// <p>Unique identifier for a Realtime script to retrieve properties for.</p>
     #[serde(rename = "ScriptId")]
     pub script_id: String,
 }
 
+impl Validate for DescribeScriptInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "script_id"),
+            &self.script_id,
+            "^script-\\S+|^arn:.*:script\\/script-\\S+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeScriptOutput {
     /// <p>Set of properties describing the requested script.</p>
     #[serde(rename = "Script")]
@@ -1264,10 +2439,11 @@ pub struct DescribeScriptOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeVpcPeeringAuthorizationsInput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeVpcPeeringAuthorizationsOutput {
     /// <p>Collection of objects that describe all valid VPC peering operations for the current AWS account.</p>
     #[serde(rename = "VpcPeeringAuthorizations")]
@@ -1277,6 +2453,7 @@ pub struct DescribeVpcPeeringAuthorizationsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeVpcPeeringConnectionsInput {
     /// <p>Unique identifier for a fleet.</p>
     #[serde(rename = "FleetId")]
@@ -1284,9 +2461,17 @@ pub struct DescribeVpcPeeringConnectionsInput {
     pub fleet_id: Option<String>,
 }
 
+impl Validate for DescribeVpcPeeringConnectionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.fleet_id {
+            errors.check_pattern(&field_path(path, "fleet_id"), &value, "^fleet-\\S+");
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeVpcPeeringConnectionsOutput {
     /// <p>Collection of VPC peering connection records that match the request.</p>
     #[serde(rename = "VpcPeeringConnections")]
@@ -1296,6 +2481,7 @@ pub struct DescribeVpcPeeringConnectionsOutput {
 
 /// <p>Player information for use when creating player sessions using a game session placement request with <a>StartGameSessionPlacement</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DesiredPlayerSession {
     /// <p>Developer-defined information related to a player. Amazon GameLift does not use this data, so it can be formatted as needed for use in the game.</p>
     #[serde(rename = "PlayerData")]
@@ -1307,9 +2493,30 @@ pub struct DesiredPlayerSession {
     pub player_id: Option<String>,
 }
 
+impl Validate for DesiredPlayerSession {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.player_data {
+            errors.check_length(
+                &field_path(path, "player_data"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.player_id {
+            errors.check_length(
+                &field_path(path, "player_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p><p>Current status of fleet capacity. The number of active instances should match or be in the process of matching the number of desired instances. Pending and terminating counts are non-zero only if fleet capacity is adjusting to an <a>UpdateFleetCapacity</a> request, or if access to resources is temporarily affected.</p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EC2InstanceCounts {
     /// <p>Actual number of active instances in the fleet.</p>
     #[serde(rename = "ACTIVE")]
@@ -1343,7 +2550,7 @@ pub struct EC2InstanceCounts {
 
 /// <p>Maximum number of instances allowed based on the Amazon Elastic Compute Cloud (Amazon EC2) instance type. Instance limits can be retrieved by calling <a>DescribeEC2InstanceLimits</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EC2InstanceLimit {
     /// <p>Number of instances of the specified type that are currently in use by this AWS account.</p>
     #[serde(rename = "CurrentInstances")]
@@ -1361,7 +2568,7 @@ pub struct EC2InstanceLimit {
 
 /// <p>Log entry describing an event that involves Amazon GameLift resources (such as a fleet). In addition to tracking activity, event codes and messages can provide additional information for troubleshooting and debugging problems.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Event {
     /// <p><p>Type of event being logged. The following events are currently in use:</p> <p> <b>Fleet creation events:</b> </p> <ul> <li> <p>FLEET<em>CREATED -- A fleet record was successfully created with a status of <code>NEW</code>. Event messaging includes the fleet ID.</p> </li> <li> <p>FLEET</em>STATE<em>DOWNLOADING -- Fleet status changed from <code>NEW</code> to <code>DOWNLOADING</code>. The compressed build has started downloading to a fleet instance for installation.</p> </li> <li> <p> FLEET</em>BINARY<em>DOWNLOAD</em>FAILED -- The build failed to download to the fleet instance.</p> </li> <li> <p>FLEET<em>CREATION</em>EXTRACTING<em>BUILD – The game server build was successfully downloaded to an instance, and the build files are now being extracted from the uploaded build and saved to an instance. Failure at this stage prevents a fleet from moving to <code>ACTIVE</code> status. Logs for this stage display a list of the files that are extracted and saved on the instance. Access the logs by using the URL in <i>PreSignedLogUrl</i>.</p> </li> <li> <p>FLEET</em>CREATION<em>RUNNING</em>INSTALLER – The game server build files were successfully extracted, and the Amazon GameLift is now running the build&#39;s install script (if one is included). Failure in this stage prevents a fleet from moving to <code>ACTIVE</code> status. Logs for this stage list the installation steps and whether or not the install completed successfully. Access the logs by using the URL in <i>PreSignedLogUrl</i>. </p> </li> <li> <p>FLEET<em>CREATION</em>VALIDATING<em>RUNTIME</em>CONFIG -- The build process was successful, and the Amazon GameLift is now verifying that the game server launch paths, which are specified in the fleet&#39;s run-time configuration, exist. If any listed launch path exists, Amazon GameLift tries to launch a game server process and waits for the process to report ready. Failures in this stage prevent a fleet from moving to <code>ACTIVE</code> status. Logs for this stage list the launch paths in the run-time configuration and indicate whether each is found. Access the logs by using the URL in <i>PreSignedLogUrl</i>. </p> </li> <li> <p>FLEET<em>STATE</em>VALIDATING -- Fleet status changed from <code>DOWNLOADING</code> to <code>VALIDATING</code>.</p> </li> <li> <p> FLEET<em>VALIDATION</em>LAUNCH<em>PATH</em>NOT<em>FOUND -- Validation of the run-time configuration failed because the executable specified in a launch path does not exist on the instance.</p> </li> <li> <p>FLEET</em>STATE<em>BUILDING -- Fleet status changed from <code>VALIDATING</code> to <code>BUILDING</code>.</p> </li> <li> <p>FLEET</em>VALIDATION<em>EXECUTABLE</em>RUNTIME<em>FAILURE -- Validation of the run-time configuration failed because the executable specified in a launch path failed to run on the fleet instance.</p> </li> <li> <p>FLEET</em>STATE<em>ACTIVATING -- Fleet status changed from <code>BUILDING</code> to <code>ACTIVATING</code>. </p> </li> <li> <p> FLEET</em>ACTIVATION<em>FAILED - The fleet failed to successfully complete one of the steps in the fleet activation process. This event code indicates that the game build was successfully downloaded to a fleet instance, built, and validated, but was not able to start a server process. A possible reason for failure is that the game server is not reporting &quot;process ready&quot; to the Amazon GameLift service.</p> </li> <li> <p>FLEET</em>STATE<em>ACTIVE -- The fleet&#39;s status changed from <code>ACTIVATING</code> to <code>ACTIVE</code>. The fleet is now ready to host game sessions.</p> </li> </ul> <p> <b>VPC peering events:</b> </p> <ul> <li> <p>FLEET</em>VPC<em>PEERING</em>SUCCEEDED -- A VPC peering connection has been established between the VPC for an Amazon GameLift fleet and a VPC in your AWS account.</p> </li> <li> <p>FLEET<em>VPC</em>PEERING<em>FAILED -- A requested VPC peering connection has failed. Event details and status information (see <a>DescribeVpcPeeringConnections</a>) provide additional detail. A common reason for peering failure is that the two VPCs have overlapping CIDR blocks of IPv4 addresses. To resolve this, change the CIDR block for the VPC in your AWS account. For more information on VPC peering failures, see <a href="https://docs.aws.amazon.com/AmazonVPC/latest/PeeringGuide/invalid-peering-configurations.html">https://docs.aws.amazon.com/AmazonVPC/latest/PeeringGuide/invalid-peering-configurations.html</a> </p> </li> <li> <p>FLEET</em>VPC<em>PEERING</em>DELETED -- A VPC peering connection has been successfully deleted.</p> </li> </ul> <p> <b>Spot instance events:</b> </p> <ul> <li> <p> INSTANCE<em>INTERRUPTED -- A spot instance was interrupted by EC2 with a two-minute notification.</p> </li> </ul> <p> <b>Other fleet events:</b> </p> <ul> <li> <p>FLEET</em>SCALING<em>EVENT -- A change was made to the fleet&#39;s capacity settings (desired instances, minimum/maximum scaling limits). Event messaging includes the new capacity settings.</p> </li> <li> <p>FLEET</em>NEW<em>GAME</em>SESSION<em>PROTECTION</em>POLICY<em>UPDATED -- A change was made to the fleet&#39;s game session protection policy setting. Event messaging includes both the old and new policy setting. </p> </li> <li> <p>FLEET</em>DELETED -- A request to delete a fleet was initiated.</p> </li> <li> <p> GENERIC_EVENT -- An unspecified event has occurred.</p> </li> </ul></p>
     #[serde(rename = "EventCode")]
@@ -1391,7 +2598,7 @@ pub struct Event {
 
 /// <p><p>General properties describing a fleet.</p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FleetAttributes {
     /// <p>Unique identifier for a build.</p>
     #[serde(rename = "BuildId")]
@@ -1480,7 +2687,7 @@ pub struct FleetAttributes {
 
 /// <p><p>Information about the fleet&#39;s capacity. Fleet capacity is measured in EC2 instances. By default, new fleets have a capacity of one instance, but can be updated as needed. The maximum number of instances for a fleet is determined by the fleet&#39;s instance type.</p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FleetCapacity {
     /// <p>Unique identifier for a fleet.</p>
     #[serde(rename = "FleetId")]
@@ -1498,7 +2705,7 @@ pub struct FleetCapacity {
 
 /// <p><p>Current status of fleet utilization, including the number of game and player sessions being hosted.</p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FleetUtilization {
     /// <p>Number of active game sessions currently being hosted on all instances in the fleet.</p>
     #[serde(rename = "ActiveGameSessionCount")]
@@ -1533,9 +2740,26 @@ pub struct GameProperty {
     pub value: String,
 }
 
+impl Validate for GameProperty {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            None,
+            Some(32),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(96),
+        );
+    }
+}
+
 /// <p><p>Properties describing a game session.</p> <p>A game session in ACTIVE status can host players. When a game session ends, its status is set to <code>TERMINATED</code>. </p> <p>Once the session ends, the game session object is retained for 30 days. This means you can reuse idempotency token values after this time. Game session logs are retained for 14 days.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GameSession {
     /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
@@ -1608,7 +2832,7 @@ pub struct GameSession {
 
 /// <p>Connection information for the new game session that is created with matchmaking. (with <a>StartMatchmaking</a>). Once a match is set, the FlexMatch engine places the match and creates a new game session for it. This information, including the game session endpoint and player sessions for each player in the original matchmaking request, is added to the <a>MatchmakingTicket</a>, which can be retrieved by calling <a>DescribeMatchmaking</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GameSessionConnectionInfo {
     #[serde(rename = "DnsName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1633,7 +2857,7 @@ pub struct GameSessionConnectionInfo {
 
 /// <p>A game session's properties plus the protection policy currently in force.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GameSessionDetail {
     /// <p>Object that describes a game session.</p>
     #[serde(rename = "GameSession")]
@@ -1647,7 +2871,7 @@ pub struct GameSessionDetail {
 
 /// <p><p>Object that describes a <a>StartGameSessionPlacement</a> request. This object includes the full details of the original request plus the current status and start/end time stamps.</p> <p>Game session placement-related operations include:</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GameSessionPlacement {
     #[serde(rename = "DnsName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1724,7 +2948,7 @@ pub struct GameSessionPlacement {
 
 /// <p><p>Configuration of a queue that is used to process game session placement requests. The queue configuration identifies several game features:</p> <ul> <li> <p>The destinations where a new game session can potentially be hosted. Amazon GameLift tries these destinations in an order based on either the queue&#39;s default order or player latency information, if provided in a placement request. With latency information, Amazon GameLift can place game sessions where the majority of players are reporting the lowest possible latency. </p> </li> <li> <p>The length of time that placement requests can wait in the queue before timing out. </p> </li> <li> <p>A set of optional latency policies that protect individual players from high latencies, preventing game sessions from being placed where any individual player is reporting latency higher than a policy&#39;s maximum.</p> </li> </ul> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GameSessionQueue {
     /// <p>List of fleets that can be used to fulfill game session placement requests in the queue. Fleets are identified by either a fleet ARN or a fleet alias ARN. Destinations are listed in default preference order.</p>
     #[serde(rename = "Destinations")]
@@ -1757,17 +2981,52 @@ pub struct GameSessionQueueDestination {
     pub destination_arn: Option<String>,
 }
 
+impl Validate for GameSessionQueueDestination {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination_arn {
+            errors.check_length(
+                &field_path(path, "destination_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "destination_arn"),
+                &value,
+                "[a-zA-Z0-9:/-]+",
+            );
+        }
+    }
+}
+
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGameSessionLogUrlInput {
     /// <p>Unique identifier for the game session to get logs for.</p>
     #[serde(rename = "GameSessionId")]
     pub game_session_id: String,
 }
 
+impl Validate for GetGameSessionLogUrlInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "game_session_id"),
+            self.game_session_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "game_session_id"),
+            &self.game_session_id,
+            "[a-zA-Z0-9:/-]+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGameSessionLogUrlOutput {
     /// <p>Location of the requested game session logs, available for download. This URL is valid for 15 minutes, after which S3 will reject any download request using this URL. You can request a new URL any time within the 14-day period that the logs are retained.</p>
     #[serde(rename = "PreSignedUrl")]
@@ -1777,6 +3036,7 @@ pub struct GetGameSessionLogUrlOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstanceAccessInput {
     /// <p>Unique identifier for a fleet that contains the instance you want access to. The fleet can be in any of the following statuses: <code>ACTIVATING</code>, <code>ACTIVE</code>, or <code>ERROR</code>. Fleets with an <code>ERROR</code> status may be accessible for a short time before they are deleted.</p>
     #[serde(rename = "FleetId")]
@@ -1786,9 +3046,24 @@ pub struct GetInstanceAccessInput {
     pub instance_id: String,
 }
 
+impl Validate for GetInstanceAccessInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "[a-zA-Z0-9\\.-]+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstanceAccessOutput {
     /// <p>Object that contains connection information for a fleet instance, including IP address and access credentials.</p>
     #[serde(rename = "InstanceAccess")]
@@ -1798,7 +3073,7 @@ pub struct GetInstanceAccessOutput {
 
 /// <p>Properties that describe an instance of a virtual computing resource that hosts one or more game servers. A fleet may contain zero or more instances.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Instance {
     /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
@@ -1835,7 +3110,7 @@ pub struct Instance {
 
 /// <p>Information required to remotely connect to a fleet instance. Access is requested by calling <a>GetInstanceAccess</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceAccess {
     /// <p>Credentials required to access the instance.</p>
     #[serde(rename = "Credentials")]
@@ -1861,7 +3136,7 @@ pub struct InstanceAccess {
 
 /// <p>Set of credentials required to remotely access a fleet instance. Access credentials are requested by calling <a>GetInstanceAccess</a> and returned in an <a>InstanceAccess</a> object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceCredentials {
     /// <p>Secret string. For Windows instances, the secret is a password for use with Windows Remote Desktop. For Linux instances, it is a private key (which must be saved as a <code>.pem</code> file) for use with SSH.</p>
     #[serde(rename = "Secret")]
@@ -1890,8 +3165,27 @@ pub struct IpPermission {
     pub to_port: i64,
 }
 
+impl Validate for IpPermission {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "from_port"),
+            self.from_port,
+            Some(1),
+            Some(60000),
+        );
+        errors.check_pattern(&field_path(path, "ip_range"), &self.ip_range, "[^\\s]+");
+        errors.check_range(
+            &field_path(path, "to_port"),
+            self.to_port,
+            Some(1),
+            Some(60000),
+        );
+    }
+}
+
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAliasesInput {
     /// <p>Maximum number of results to return. Use this parameter with <code>NextToken</code> to get results as a set of sequential pages.</p>
     #[serde(rename = "Limit")]
@@ -1911,9 +3205,33 @@ pub struct ListAliasesInput {
     pub routing_strategy_type: Option<String>,
 }
 
-/// <p>Represents the returned data in response to a request action.</p>
+impl Validate for ListAliasesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
+/// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAliasesOutput {
     /// <p>Collection of alias records that match the list request.</p>
     #[serde(rename = "Aliases")]
@@ -1927,6 +3245,7 @@ pub struct ListAliasesOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBuildsInput {
     /// <p>Maximum number of results to return. Use this parameter with <code>NextToken</code> to get results as a set of sequential pages.</p>
     #[serde(rename = "Limit")]
@@ -1942,9 +3261,25 @@ pub struct ListBuildsInput {
     pub status: Option<String>,
 }
 
+impl Validate for ListBuildsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBuildsOutput {
     /// <p>Collection of build records that match the request.</p>
     #[serde(rename = "Builds")]
@@ -1958,6 +3293,7 @@ pub struct ListBuildsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFleetsInput {
     /// <p>Unique identifier for a build to return fleets for. Use this parameter to return only fleets using the specified build. To retrieve all fleets, leave this parameter empty.</p>
     #[serde(rename = "BuildId")]
@@ -1977,9 +3313,39 @@ pub struct ListFleetsInput {
     pub script_id: Option<String>,
 }
 
+impl Validate for ListFleetsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.build_id {
+            errors.check_pattern(
+                &field_path(path, "build_id"),
+                &value,
+                "^build-\\S+|^arn:.*:build\\/build-\\S+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.script_id {
+            errors.check_pattern(
+                &field_path(path, "script_id"),
+                &value,
+                "^script-\\S+|^arn:.*:script\\/script-\\S+",
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFleetsOutput {
     /// <p>Set of fleet IDs matching the list request. You can retrieve additional information about all returned fleets by passing this result set to a call to <a>DescribeFleetAttributes</a>, <a>DescribeFleetCapacity</a>, or <a>DescribeFleetUtilization</a>.</p>
     #[serde(rename = "FleetIds")]
@@ -1992,6 +3358,7 @@ pub struct ListFleetsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListScriptsInput {
     /// <p>Maximum number of results to return. Use this parameter with <code>NextToken</code> to get results as a set of sequential pages.</p>
     #[serde(rename = "Limit")]
@@ -2003,8 +3370,24 @@ pub struct ListScriptsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListScriptsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListScriptsOutput {
     /// <p>Token that indicates where to resume retrieving results on the next call to this action. If no token is returned, these results represent the end of the list.</p>
     #[serde(rename = "NextToken")]
@@ -2018,7 +3401,7 @@ pub struct ListScriptsOutput {
 
 /// <p>Represents a new player session that is created as a result of a successful FlexMatch match. A successful match automatically creates new player sessions for every player ID in the original matchmaking request. </p> <p>When players connect to the match's game session, they must include both player ID and player session ID in order to claim their assigned player slot.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MatchedPlayerSession {
     /// <p>Unique identifier for a player </p>
     #[serde(rename = "PlayerId")]
@@ -2032,7 +3415,7 @@ pub struct MatchedPlayerSession {
 
 /// <p>Guidelines for use with FlexMatch to match players into games. All matchmaking requests must specify a matchmaking configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MatchmakingConfiguration {
     /// <p>Flag that determines whether a match that was created with this configuration must be accepted by the matched players. To require acceptance, set to TRUE.</p>
     #[serde(rename = "AcceptanceRequired")]
@@ -2094,7 +3477,7 @@ pub struct MatchmakingConfiguration {
 
 /// <p><p>Set of rule statements, used with FlexMatch, that determine how to build your player matches. Each rule set describes a type of group to be created and defines the parameters for acceptable player matches. Rule sets are used in <a>MatchmakingConfiguration</a> objects.</p> <p>A rule set may define the following elements for a match. For detailed information and examples showing how to construct a rule set, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a FlexMatch Rule Set</a>. </p> <ul> <li> <p>Teams -- Required. A rule set must define one or multiple teams for the match and set minimum and maximum team sizes. For example, a rule set might describe a 4x4 match that requires all eight slots to be filled. </p> </li> <li> <p>Player attributes -- Optional. These attributes specify a set of player characteristics to evaluate when looking for a match. Matchmaking requests that use a rule set with player attributes must provide the corresponding attribute values. For example, an attribute might specify a player&#39;s skill or level.</p> </li> <li> <p>Rules -- Optional. Rules define how to evaluate potential players for a match based on player attributes. A rule might specify minimum requirements for individual players, teams, or entire matches. For example, a rule might require each player to meet a certain skill level, each team to have at least one player in a certain role, or the match to have a minimum average skill level. or may describe an entire group--such as all teams must be evenly matched or have at least one player in a certain role. </p> </li> <li> <p>Expansions -- Optional. Expansions allow you to relax the rules after a period of time when no acceptable matches are found. This feature lets you balance getting players into games in a reasonable amount of time instead of making them wait indefinitely for the best possible match. For example, you might use an expansion to increase the maximum skill variance between players after 30 seconds.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MatchmakingRuleSet {
     /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
@@ -2111,7 +3494,7 @@ pub struct MatchmakingRuleSet {
 
 /// <p>Ticket generated to track the progress of a matchmaking request. Each ticket is uniquely identified by a ticket ID, supplied by the requester, when creating a matchmaking request with <a>StartMatchmaking</a>. Tickets can be retrieved by calling <a>DescribeMatchmaking</a> with the ticket ID.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MatchmakingTicket {
     /// <p>Name of the <a>MatchmakingConfiguration</a> that is used with this ticket. Matchmaking configurations determine how players are grouped into a match and how a new game session is created for the match.</p>
     #[serde(rename = "ConfigurationName")]
@@ -2157,7 +3540,7 @@ pub struct MatchmakingTicket {
 
 /// <p><p>Information about a player session that was created as part of a <a>StartGameSessionPlacement</a> request. This object contains only the player ID and player session ID. To retrieve full details on a player session, call <a>DescribePlayerSessions</a> with the player session ID.</p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PlacedPlayerSession {
     /// <p>Unique identifier for a player that is associated with this player session.</p>
     #[serde(rename = "PlayerId")]
@@ -2190,6 +3573,39 @@ pub struct Player {
     pub team: Option<String>,
 }
 
+impl Validate for Player {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.latency_in_ms {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "latency_in_ms"), key);
+                errors.check_range(&item_path, *item, Some(1), None);
+            }
+        }
+        if let Some(ref value) = self.player_attributes {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "player_attributes"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.player_id {
+            errors.check_length(
+                &field_path(path, "player_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.team {
+            errors.check_length(
+                &field_path(path, "team"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Regional latency information for a player, used when requesting a new game session with <a>StartGameSessionPlacement</a>. This value indicates the amount of time lag that exists when the player is connected to a fleet in the specified region. The relative difference between a player's latency values for multiple regions are used to determine which fleets are best suited to place a new game session for the player. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayerLatency {
@@ -2207,6 +3623,27 @@ pub struct PlayerLatency {
     pub region_identifier: Option<String>,
 }
 
+impl Validate for PlayerLatency {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.player_id {
+            errors.check_length(
+                &field_path(path, "player_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.region_identifier {
+            errors.check_length(
+                &field_path(path, "region_identifier"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p><p>Queue setting that determines the highest latency allowed for individual players when placing a game session. When a latency policy is in force, a game session cannot be placed at any destination in a region where a player is reporting latency higher than the cap. Latency policies are only enforced when the placement request contains player latency information.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayerLatencyPolicy {
@@ -2222,7 +3659,7 @@ pub struct PlayerLatencyPolicy {
 
 /// <p><p>Properties describing a player session. Player session objects are created either by creating a player session for a specific game session, or as part of a game session placement. A player session represents either a player reservation for a game session (status <code>RESERVED</code>) or actual player activity in a game session (status <code>ACTIVE</code>). A player session object (including player data) is automatically passed to a game session when the player connects to the game session and is validated.</p> <p>When a player disconnects, the player session status changes to <code>COMPLETED</code>. Once the session ends, the player session object is retained for 30 days and then removed.</p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PlayerSession {
     /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
@@ -2271,6 +3708,7 @@ pub struct PlayerSession {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutScalingPolicyInput {
     /// <p>Comparison operator to use when measuring the metric against the threshold value.</p>
     #[serde(rename = "ComparisonOperator")]
@@ -2311,9 +3749,33 @@ pub struct PutScalingPolicyInput {
     pub threshold: Option<f64>,
 }
 
+impl Validate for PutScalingPolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.evaluation_periods {
+            errors.check_range(
+                &field_path(path, "evaluation_periods"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutScalingPolicyOutput {
     /// <p>Descriptive label that is associated with a scaling policy. Policy names do not need to be unique.</p>
     #[serde(rename = "Name")]
@@ -2323,15 +3785,26 @@ pub struct PutScalingPolicyOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RequestUploadCredentialsInput {
     /// <p>Unique identifier for a build to get credentials for.</p>
     #[serde(rename = "BuildId")]
     pub build_id: String,
 }
 
+impl Validate for RequestUploadCredentialsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "build_id"),
+            &self.build_id,
+            "^build-\\S+|^arn:.*:build\\/build-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RequestUploadCredentialsOutput {
     /// <p>Amazon S3 path and key, identifying where the game build files are stored.</p>
     #[serde(rename = "StorageLocation")]
@@ -2345,15 +3818,26 @@ pub struct RequestUploadCredentialsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResolveAliasInput {
     /// <p>Unique identifier for the alias you want to resolve.</p>
     #[serde(rename = "AliasId")]
     pub alias_id: String,
 }
 
+impl Validate for ResolveAliasInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "alias_id"),
+            &self.alias_id,
+            "^alias-\\S+|^arn:.*:alias\\/alias-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResolveAliasOutput {
     /// <p>Fleet identifier that is associated with the requested alias.</p>
     #[serde(rename = "FleetId")]
@@ -2391,6 +3875,14 @@ pub struct RoutingStrategy {
     pub type_: Option<String>,
 }
 
+impl Validate for RoutingStrategy {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.fleet_id {
+            errors.check_pattern(&field_path(path, "fleet_id"), &value, "^fleet-\\S+");
+        }
+    }
+}
+
 /// <p><p>A collection of server process configurations that describe what processes to run on each instance in a fleet. Server processes run either a custom game build executable or a Realtime Servers script. Each instance in the fleet starts the specified server processes and continues to start new processes as existing processes end. An instance regularly checks for an updated run-time configuration. </p> <p>The run-time configuration enables the instances in a fleet to run multiple processes simultaneously. Learn more about <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-multiprocess.html"> Running Multiple Processes on a Fleet </a>.</p> <p>A Amazon GameLift instance is limited to 50 processes running simultaneously. To calculate the total number of processes in a run-time configuration, add the values of the <code>ConcurrentExecutions</code> parameter for each <a>ServerProcess</a> object.</p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RuntimeConfiguration {
@@ -2408,6 +3900,39 @@ pub struct RuntimeConfiguration {
     pub server_processes: Option<Vec<ServerProcess>>,
 }
 
+impl Validate for RuntimeConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.game_session_activation_timeout_seconds {
+            errors.check_range(
+                &field_path(path, "game_session_activation_timeout_seconds"),
+                *value,
+                Some(1),
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.max_concurrent_game_session_activations {
+            errors.check_range(
+                &field_path(path, "max_concurrent_game_session_activations"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.server_processes {
+            errors.check_length(
+                &field_path(path, "server_processes"),
+                value.len(),
+                Some(1),
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "server_processes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Location in Amazon Simple Storage Service (Amazon S3) where build or script files are stored for access by Amazon GameLift. This location is specified in <a>CreateBuild</a>, <a>CreateScript</a>, and <a>UpdateScript</a> requests. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3Location {
@@ -2429,9 +3954,46 @@ pub struct S3Location {
     pub role_arn: Option<String>,
 }
 
+impl Validate for S3Location {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket {
+            errors.check_length(
+                &field_path(path, "bucket"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.object_version {
+            errors.check_length(
+                &field_path(path, "object_version"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p><p>Rule that controls how a fleet is scaled. Scaling policies are uniquely identified by the combination of name and fleet ID.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScalingPolicy {
     /// <p>Comparison operator to use when measuring a metric against the threshold value.</p>
     #[serde(rename = "ComparisonOperator")]
@@ -2481,7 +4043,7 @@ pub struct ScalingPolicy {
 
 /// <p><p>Properties describing a Realtime script.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Script {
     /// <p>Time stamp indicating when this data object was created. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
@@ -2510,6 +4072,7 @@ pub struct Script {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchGameSessionsInput {
     /// <p>Unique identifier for an alias associated with the fleet to search for active game sessions. Each request must reference either a fleet ID or alias ID, but not both.</p>
     #[serde(rename = "AliasId")]
@@ -2537,9 +4100,55 @@ pub struct SearchGameSessionsInput {
     pub sort_expression: Option<String>,
 }
 
+impl Validate for SearchGameSessionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.alias_id {
+            errors.check_pattern(
+                &field_path(path, "alias_id"),
+                &value,
+                "^alias-\\S+|^arn:.*:alias\\/alias-\\S+",
+            );
+        }
+        if let Some(ref value) = self.filter_expression {
+            errors.check_length(
+                &field_path(path, "filter_expression"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.fleet_id {
+            errors.check_pattern(
+                &field_path(path, "fleet_id"),
+                &value,
+                "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.sort_expression {
+            errors.check_length(
+                &field_path(path, "sort_expression"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchGameSessionsOutput {
     /// <p>Collection of objects containing game session properties for each session matching the request.</p>
     #[serde(rename = "GameSessions")]
@@ -2566,7 +4175,43 @@ pub struct ServerProcess {
     pub parameters: Option<String>,
 }
 
+impl Validate for ServerProcess {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "concurrent_executions"),
+            self.concurrent_executions,
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "launch_path"),
+            self.launch_path.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "launch_path"),
+            &self.launch_path,
+            "[A-Za-z0-9_:.+\\/\\\\\\- ]+",
+        );
+        if let Some(ref value) = self.parameters {
+            errors.check_length(
+                &field_path(path, "parameters"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "parameters"),
+                &value,
+                "[A-Za-z0-9_:.+\\/\\\\\\- =@;{},?'\\[\\]\"]+",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartFleetActionsInput {
     /// <p>List of actions to restart on the fleet.</p>
     #[serde(rename = "Actions")]
@@ -2576,12 +4221,29 @@ pub struct StartFleetActionsInput {
     pub fleet_id: String,
 }
 
+impl Validate for StartFleetActionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "actions"),
+            self.actions.len(),
+            Some(1),
+            Some(1),
+        );
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartFleetActionsOutput {}
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartGameSessionPlacementInput {
     /// <p>Set of information on each player to create a player session for.</p>
     #[serde(rename = "DesiredPlayerSessions")]
@@ -2614,9 +4276,76 @@ pub struct StartGameSessionPlacementInput {
     pub player_latencies: Option<Vec<PlayerLatency>>,
 }
 
+impl Validate for StartGameSessionPlacementInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.desired_player_sessions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "desired_player_sessions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.game_properties {
+            errors.check_length(
+                &field_path(path, "game_properties"),
+                value.len(),
+                None,
+                Some(16),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "game_properties"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.game_session_data {
+            errors.check_length(
+                &field_path(path, "game_session_data"),
+                value.chars().count(),
+                Some(1),
+                Some(262144),
+            );
+        }
+        if let Some(ref value) = self.game_session_name {
+            errors.check_length(
+                &field_path(path, "game_session_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "game_session_queue_name"),
+            self.game_session_queue_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "game_session_queue_name"),
+            &self.game_session_queue_name,
+            "[a-zA-Z0-9-]+|^arn:.*:gamesessionqueue\\/[a-zA-Z0-9-]+",
+        );
+        errors.check_length(
+            &field_path(path, "placement_id"),
+            self.placement_id.chars().count(),
+            Some(1),
+            Some(48),
+        );
+        errors.check_pattern(
+            &field_path(path, "placement_id"),
+            &self.placement_id,
+            "[a-zA-Z0-9-]+",
+        );
+        if let Some(ref value) = self.player_latencies {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "player_latencies"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartGameSessionPlacementOutput {
     /// <p>Object that describes the newly created game session placement. This object includes all the information provided in the request, as well as start/end time stamps and placement status. </p>
     #[serde(rename = "GameSessionPlacement")]
@@ -2626,6 +4355,7 @@ pub struct StartGameSessionPlacementOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartMatchBackfillInput {
     /// <p>Name of the matchmaker to use for this request. The name of the matchmaker that was used with the original game session is listed in the <a>GameSession</a> object, <code>MatchmakerData</code> property. This property contains a matchmaking configuration ARN value, which includes the matchmaker name. (In the ARN value "arn:aws:gamelift:us-west-2:111122223333:matchmakingconfiguration/MM-4v4", the matchmaking configuration name is "MM-4v4".) Use only the name for this parameter.</p>
     #[serde(rename = "ConfigurationName")]
@@ -2642,9 +4372,49 @@ pub struct StartMatchBackfillInput {
     pub ticket_id: Option<String>,
 }
 
+impl Validate for StartMatchBackfillInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_name"),
+            self.configuration_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_name"),
+            &self.configuration_name,
+            "[a-zA-Z0-9-\\.]*|^arn:.*:matchmakingconfiguration\\/[a-zA-Z0-9-\\.]*",
+        );
+        errors.check_length(
+            &field_path(path, "game_session_arn"),
+            self.game_session_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "game_session_arn"),
+            &self.game_session_arn,
+            "[a-zA-Z0-9:/-]+",
+        );
+        for (i, item) in self.players.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "players"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.ticket_id {
+            errors.check_length(
+                &field_path(path, "ticket_id"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "ticket_id"), &value, "[a-zA-Z0-9-\\.]*");
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartMatchBackfillOutput {
     /// <p>Ticket representing the backfill matchmaking request. This object includes the information in the request, ticket status, and match results as generated during the matchmaking process.</p>
     #[serde(rename = "MatchmakingTicket")]
@@ -2654,6 +4424,7 @@ pub struct StartMatchBackfillOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartMatchmakingInput {
     /// <p>Name of the matchmaking configuration to use for this request. Matchmaking configurations must exist in the same region as this request.</p>
     #[serde(rename = "ConfigurationName")]
@@ -2667,9 +4438,38 @@ pub struct StartMatchmakingInput {
     pub ticket_id: Option<String>,
 }
 
+impl Validate for StartMatchmakingInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_name"),
+            self.configuration_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_name"),
+            &self.configuration_name,
+            "[a-zA-Z0-9-\\.]*|^arn:.*:matchmakingconfiguration\\/[a-zA-Z0-9-\\.]*",
+        );
+        for (i, item) in self.players.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "players"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.ticket_id {
+            errors.check_length(
+                &field_path(path, "ticket_id"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "ticket_id"), &value, "[a-zA-Z0-9-\\.]*");
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartMatchmakingOutput {
     /// <p>Ticket representing the matchmaking request. This object include the information included in the request, ticket status, and match results as generated during the matchmaking process.</p>
     #[serde(rename = "MatchmakingTicket")]
@@ -2678,6 +4478,7 @@ pub struct StartMatchmakingOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopFleetActionsInput {
     /// <p>List of actions to suspend on the fleet. </p>
     #[serde(rename = "Actions")]
@@ -2687,21 +4488,54 @@ pub struct StopFleetActionsInput {
     pub fleet_id: String,
 }
 
+impl Validate for StopFleetActionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "actions"),
+            self.actions.len(),
+            Some(1),
+            Some(1),
+        );
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopFleetActionsOutput {}
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopGameSessionPlacementInput {
     /// <p>Unique identifier for a game session placement to cancel.</p>
     #[serde(rename = "PlacementId")]
     pub placement_id: String,
 }
 
+impl Validate for StopGameSessionPlacementInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "placement_id"),
+            self.placement_id.chars().count(),
+            Some(1),
+            Some(48),
+        );
+        errors.check_pattern(
+            &field_path(path, "placement_id"),
+            &self.placement_id,
+            "[a-zA-Z0-9-]+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopGameSessionPlacementOutput {
     /// <p>Object that describes the canceled game session placement, with <code>CANCELLED</code> status and an end time stamp. </p>
     #[serde(rename = "GameSessionPlacement")]
@@ -2711,14 +4545,31 @@ pub struct StopGameSessionPlacementOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopMatchmakingInput {
     /// <p>Unique identifier for a matchmaking ticket.</p>
     #[serde(rename = "TicketId")]
     pub ticket_id: String,
 }
 
+impl Validate for StopMatchmakingInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ticket_id"),
+            self.ticket_id.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "ticket_id"),
+            &self.ticket_id,
+            "[a-zA-Z0-9-\\.]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopMatchmakingOutput {}
 
 /// <p><p>Settings for a target-based scaling policy (see <a>ScalingPolicy</a>. A target-based policy tracks a particular fleet metric specifies a target value for the metric. As player usage changes, the policy triggers Amazon GameLift to adjust capacity so that the metric returns to the target value. The target configuration specifies settings as needed for the target based policy, including the target value. </p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
@@ -2731,6 +4582,7 @@ pub struct TargetConfiguration {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAliasInput {
     /// <p>Unique identifier for a fleet alias. Specify the alias you want to update.</p>
     #[serde(rename = "AliasId")]
@@ -2749,9 +4601,39 @@ pub struct UpdateAliasInput {
     pub routing_strategy: Option<RoutingStrategy>,
 }
 
+impl Validate for UpdateAliasInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "alias_id"),
+            &self.alias_id,
+            "^alias-\\S+|^arn:.*:alias\\/alias-\\S+",
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.routing_strategy {
+            value.validate_at(&field_path(path, "routing_strategy"), errors);
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAliasOutput {
     /// <p>Object that contains the updated alias configuration.</p>
     #[serde(rename = "Alias")]
@@ -2761,6 +4643,7 @@ pub struct UpdateAliasOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateBuildInput {
     /// <p>Unique identifier for a build to update.</p>
     #[serde(rename = "BuildId")]
@@ -2775,9 +4658,35 @@ pub struct UpdateBuildInput {
     pub version: Option<String>,
 }
 
+impl Validate for UpdateBuildInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "build_id"),
+            &self.build_id,
+            "^build-\\S+|^arn:.*:build\\/build-\\S+",
+        );
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.version {
+            errors.check_length(
+                &field_path(path, "version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateBuildOutput {
     /// <p>Object that contains the updated build record.</p>
     #[serde(rename = "Build")]
@@ -2787,6 +4696,7 @@ pub struct UpdateBuildOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFleetAttributesInput {
     /// <p>Human-readable description of a fleet.</p>
     #[serde(rename = "Description")]
@@ -2813,9 +4723,47 @@ pub struct UpdateFleetAttributesInput {
     pub resource_creation_limit_policy: Option<ResourceCreationLimitPolicy>,
 }
 
+impl Validate for UpdateFleetAttributesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+        if let Some(ref value) = self.metric_groups {
+            errors.check_length(
+                &field_path(path, "metric_groups"),
+                value.len(),
+                None,
+                Some(1),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "metric_groups"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            }
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFleetAttributesOutput {
     /// <p>Unique identifier for a fleet that was updated.</p>
     #[serde(rename = "FleetId")]
@@ -2825,6 +4773,7 @@ pub struct UpdateFleetAttributesOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFleetCapacityInput {
     /// <p>Number of EC2 instances you want this fleet to host.</p>
     #[serde(rename = "DesiredInstances")]
@@ -2843,9 +4792,19 @@ pub struct UpdateFleetCapacityInput {
     pub min_size: Option<i64>,
 }
 
+impl Validate for UpdateFleetCapacityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFleetCapacityOutput {
     /// <p>Unique identifier for a fleet that was updated.</p>
     #[serde(rename = "FleetId")]
@@ -2855,6 +4814,7 @@ pub struct UpdateFleetCapacityOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFleetPortSettingsInput {
     /// <p>Unique identifier for a fleet to update port settings for.</p>
     #[serde(rename = "FleetId")]
@@ -2869,9 +4829,51 @@ pub struct UpdateFleetPortSettingsInput {
     pub inbound_permission_revocations: Option<Vec<IpPermission>>,
 }
 
+impl Validate for UpdateFleetPortSettingsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+        if let Some(ref value) = self.inbound_permission_authorizations {
+            errors.check_length(
+                &field_path(path, "inbound_permission_authorizations"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "inbound_permission_authorizations"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.inbound_permission_revocations {
+            errors.check_length(
+                &field_path(path, "inbound_permission_revocations"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "inbound_permission_revocations"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFleetPortSettingsOutput {
     /// <p>Unique identifier for a fleet that was updated.</p>
     #[serde(rename = "FleetId")]
@@ -2881,6 +4883,7 @@ pub struct UpdateFleetPortSettingsOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGameSessionInput {
     /// <p>Unique identifier for the game session to update.</p>
     #[serde(rename = "GameSessionId")]
@@ -2903,9 +4906,33 @@ pub struct UpdateGameSessionInput {
     pub protection_policy: Option<String>,
 }
 
+impl Validate for UpdateGameSessionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "game_session_id"),
+            self.game_session_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "game_session_id"),
+            &self.game_session_id,
+            "[a-zA-Z0-9:/-]+",
+        );
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGameSessionOutput {
     /// <p>Object that contains the updated game session metadata.</p>
     #[serde(rename = "GameSession")]
@@ -2915,6 +4942,7 @@ pub struct UpdateGameSessionOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGameSessionQueueInput {
     /// <p>List of fleets that can be used to fulfill game session placement requests in the queue. Fleets are identified by either a fleet ARN or a fleet alias ARN. Destinations are listed in default preference order. When updating this list, provide a complete list of destinations.</p>
     #[serde(rename = "Destinations")]
@@ -2933,9 +4961,31 @@ pub struct UpdateGameSessionQueueInput {
     pub timeout_in_seconds: Option<i64>,
 }
 
+impl Validate for UpdateGameSessionQueueInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destinations {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "destinations"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[a-zA-Z0-9-]+|^arn:.*:gamesessionqueue\\/[a-zA-Z0-9-]+",
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGameSessionQueueOutput {
     /// <p>Object that describes the newly updated game session queue.</p>
     #[serde(rename = "GameSessionQueue")]
@@ -2945,6 +4995,7 @@ pub struct UpdateGameSessionQueueOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMatchmakingConfigurationInput {
     /// <p>Flag that determines whether a match that was created with this configuration must be accepted by the matched players. To require acceptance, set to TRUE.</p>
     #[serde(rename = "AcceptanceRequired")]
@@ -2999,9 +5050,110 @@ pub struct UpdateMatchmakingConfigurationInput {
     pub rule_set_name: Option<String>,
 }
 
+impl Validate for UpdateMatchmakingConfigurationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.acceptance_timeout_seconds {
+            errors.check_range(
+                &field_path(path, "acceptance_timeout_seconds"),
+                *value,
+                Some(1),
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.custom_event_data {
+            errors.check_length(
+                &field_path(path, "custom_event_data"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.game_properties {
+            errors.check_length(
+                &field_path(path, "game_properties"),
+                value.len(),
+                None,
+                Some(16),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "game_properties"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.game_session_data {
+            errors.check_length(
+                &field_path(path, "game_session_data"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+        }
+        if let Some(ref value) = self.game_session_queue_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "game_session_queue_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(&item_path, &item, "[a-zA-Z0-9:/-]+");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[a-zA-Z0-9-\\.]*|^arn:.*:matchmakingconfiguration\\/[a-zA-Z0-9-\\.]*",
+        );
+        if let Some(ref value) = self.notification_target {
+            errors.check_length(
+                &field_path(path, "notification_target"),
+                value.chars().count(),
+                None,
+                Some(300),
+            );
+            errors.check_pattern(
+                &field_path(path, "notification_target"),
+                &value,
+                "[a-zA-Z0-9:_/-]*(.fifo)?",
+            );
+        }
+        if let Some(ref value) = self.request_timeout_seconds {
+            errors.check_range(
+                &field_path(path, "request_timeout_seconds"),
+                *value,
+                Some(1),
+                Some(43200),
+            );
+        }
+        if let Some(ref value) = self.rule_set_name {
+            errors.check_length(
+                &field_path(path, "rule_set_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "rule_set_name"),
+                &value,
+                "[a-zA-Z0-9-\\.]*|^arn:.*:matchmakingruleset\\/[a-zA-Z0-9-\\.]*",
+            );
+        }
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateMatchmakingConfigurationOutput {
     /// <p>Object that describes the updated matchmaking configuration.</p>
     #[serde(rename = "Configuration")]
@@ -3011,6 +5163,7 @@ pub struct UpdateMatchmakingConfigurationOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRuntimeConfigurationInput {
     /// <p>Unique identifier for a fleet to update run-time configuration for.</p>
     #[serde(rename = "FleetId")]
@@ -3020,9 +5173,21 @@ pub struct UpdateRuntimeConfigurationInput {
     pub runtime_configuration: RuntimeConfiguration,
 }
 
+impl Validate for UpdateRuntimeConfigurationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "fleet_id"),
+            &self.fleet_id,
+            "^fleet-\\S+|^arn:.*:fleet\\/fleet-\\S+",
+        );
+        self.runtime_configuration
+            .validate_at(&field_path(path, "runtime_configuration"), errors);
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRuntimeConfigurationOutput {
     /// <p>The run-time configuration currently in force. If the update was successful, this object matches the one in the request.</p>
     #[serde(rename = "RuntimeConfiguration")]
@@ -3031,6 +5196,7 @@ pub struct UpdateRuntimeConfigurationOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateScriptInput {
     /// <p>Descriptive label that is associated with a script. Script names do not need to be unique.</p>
     #[serde(rename = "Name")]
@@ -3058,8 +5224,45 @@ pub struct UpdateScriptInput {
     pub zip_file: Option<bytes::Bytes>,
 }
 
+impl Validate for UpdateScriptInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "script_id"),
+            &self.script_id,
+            "^script-\\S+|^arn:.*:script\\/script-\\S+",
+        );
+        if let Some(ref value) = self.storage_location {
+            value.validate_at(&field_path(path, "storage_location"), errors);
+        }
+        if let Some(ref value) = self.version {
+            errors.check_length(
+                &field_path(path, "version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.zip_file {
+            errors.check_length(
+                &field_path(path, "zip_file"),
+                value.len(),
+                None,
+                Some(5000000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateScriptOutput {
     /// <p>The newly created script record with a unique script ID. The new script's storage location reflects an Amazon S3 location: (1) If the script was uploaded from an S3 bucket under your account, the storage location reflects the information that was provided in the <i>CreateScript</i> request; (2) If the script file was uploaded from a local zip file, the storage location reflects an S3 location controls by the Amazon GameLift service.</p>
     #[serde(rename = "Script")]
@@ -3069,15 +5272,27 @@ pub struct UpdateScriptOutput {
 
 /// <p>Represents the input for a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ValidateMatchmakingRuleSetInput {
     /// <p>Collection of matchmaking rules to validate, formatted as a JSON string.</p>
     #[serde(rename = "RuleSetBody")]
     pub rule_set_body: String,
 }
 
+impl Validate for ValidateMatchmakingRuleSetInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "rule_set_body"),
+            self.rule_set_body.chars().count(),
+            Some(1),
+            Some(65535),
+        );
+    }
+}
+
 /// <p>Represents the returned data in response to a request action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ValidateMatchmakingRuleSetOutput {
     /// <p>Response indicating whether the rule set is valid.</p>
     #[serde(rename = "Valid")]
@@ -3087,7 +5302,7 @@ pub struct ValidateMatchmakingRuleSetOutput {
 
 /// <p><p>Represents an authorization for a VPC peering connection between the VPC for an Amazon GameLift fleet and another VPC on an account you have access to. This authorization must exist and be valid for the peering connection to be established. Authorizations are valid for 24 hours after they are issued.</p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VpcPeeringAuthorization {
     /// <p>Time stamp indicating when this authorization was issued. Format is a number expressed in Unix time as milliseconds (for example "1469498468.057").</p>
     #[serde(rename = "CreationTime")]
@@ -3113,7 +5328,7 @@ pub struct VpcPeeringAuthorization {
 
 /// <p><p>Represents a peering connection between a VPC on one of your AWS accounts and the VPC for your Amazon GameLift fleets. This record may be for an active peering connection or a pending connection that has not yet been established.</p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VpcPeeringConnection {
     /// <p>Unique identifier for a fleet. This ID determines the ID of the Amazon GameLift VPC for your fleet.</p>
     #[serde(rename = "FleetId")]
@@ -3143,7 +5358,7 @@ pub struct VpcPeeringConnection {
 
 /// <p>Represents status information for a VPC peering connection. Status is associated with a <a>VpcPeeringConnection</a> object. Status codes and messages are provided from EC2 (see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_VpcPeeringConnectionStateReason.html">VpcPeeringConnectionStateReason</a>). Connection status information is also communicated as a fleet <a>Event</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VpcPeeringConnectionStatus {
     /// <p>Code indicating the status of a VPC peering connection.</p>
     #[serde(rename = "Code")]
@@ -3184,7 +5399,12 @@ impl AcceptMatchError {
                 "UnsupportedRegionException" => {
                     return RusotoError::Service(AcceptMatchError::UnsupportedRegion(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3240,7 +5460,12 @@ impl CreateAliasError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateAliasError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3292,7 +5517,12 @@ impl CreateBuildError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateBuildError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3353,7 +5583,12 @@ impl CreateFleetError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateFleetError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3444,7 +5679,12 @@ impl CreateGameSessionError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateGameSessionError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3507,7 +5747,12 @@ impl CreateGameSessionQueueError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateGameSessionQueueError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3575,7 +5820,12 @@ impl CreateMatchmakingConfigurationError {
                         CreateMatchmakingConfigurationError::UnsupportedRegion(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3628,7 +5878,12 @@ impl CreateMatchmakingRuleSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3697,7 +5952,12 @@ impl CreatePlayerSessionError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreatePlayerSessionError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3774,7 +6034,12 @@ impl CreatePlayerSessionsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreatePlayerSessionsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3828,7 +6093,12 @@ impl CreateScriptError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateScriptError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3889,7 +6159,12 @@ impl CreateVpcPeeringAuthorizationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3948,7 +6223,12 @@ impl CreateVpcPeeringConnectionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3999,7 +6279,12 @@ impl DeleteAliasError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteAliasError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4050,7 +6335,12 @@ impl DeleteBuildError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteBuildError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4106,7 +6396,12 @@ impl DeleteFleetError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteFleetError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4162,7 +6457,12 @@ impl DeleteGameSessionQueueError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteGameSessionQueueError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4223,7 +6523,12 @@ impl DeleteMatchmakingConfigurationError {
                         DeleteMatchmakingConfigurationError::UnsupportedRegion(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4280,7 +6585,12 @@ impl DeleteMatchmakingRuleSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4331,7 +6641,12 @@ impl DeleteScalingPolicyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteScalingPolicyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4382,7 +6697,12 @@ impl DeleteScriptError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteScriptError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4443,7 +6763,12 @@ impl DeleteVpcPeeringAuthorizationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4502,7 +6827,12 @@ impl DeleteVpcPeeringConnectionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4553,7 +6883,12 @@ impl DescribeAliasError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeAliasError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4604,7 +6939,12 @@ impl DescribeBuildError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeBuildError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4656,7 +6996,12 @@ impl DescribeEC2InstanceLimitsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4712,7 +7057,12 @@ impl DescribeFleetAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4767,7 +7117,12 @@ impl DescribeFleetCapacityError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeFleetCapacityError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4818,7 +7173,12 @@ impl DescribeFleetEventsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeFleetEventsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4875,7 +7235,12 @@ impl DescribeFleetPortSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4932,7 +7297,12 @@ impl DescribeFleetUtilizationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4998,7 +7368,12 @@ impl DescribeGameSessionDetailsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5060,7 +7435,12 @@ impl DescribeGameSessionPlacementError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5117,7 +7497,12 @@ impl DescribeGameSessionQueuesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5177,7 +7562,12 @@ impl DescribeGameSessionsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeGameSessionsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5229,7 +7619,12 @@ impl DescribeInstancesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeInstancesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5277,7 +7672,12 @@ impl DescribeMatchmakingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5330,7 +7730,12 @@ impl DescribeMatchmakingConfigurationsError {
                         DescribeMatchmakingConfigurationsError::UnsupportedRegion(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5390,7 +7795,12 @@ impl DescribeMatchmakingRuleSetsError {
                         DescribeMatchmakingRuleSetsError::UnsupportedRegion(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5445,7 +7855,12 @@ impl DescribePlayerSessionsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribePlayerSessionsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5506,7 +7921,12 @@ impl DescribeRuntimeConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5563,7 +7983,12 @@ impl DescribeScalingPoliciesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5614,7 +8039,12 @@ impl DescribeScriptError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeScriptError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5668,7 +8098,12 @@ impl DescribeVpcPeeringAuthorizationsError {
                         DescribeVpcPeeringAuthorizationsError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5728,7 +8163,12 @@ impl DescribeVpcPeeringConnectionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5781,7 +8221,12 @@ impl GetGameSessionLogUrlError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetGameSessionLogUrlError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5832,7 +8277,12 @@ impl GetInstanceAccessError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetInstanceAccessError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5878,7 +8328,12 @@ impl ListAliasesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListAliasesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5923,7 +8378,12 @@ impl ListBuildsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListBuildsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5973,7 +8433,12 @@ impl ListFleetsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListFleetsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6019,7 +8484,12 @@ impl ListScriptsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListScriptsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6069,7 +8539,12 @@ impl PutScalingPolicyError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(PutScalingPolicyError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6126,7 +8601,12 @@ impl RequestUploadCredentialsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6184,7 +8664,12 @@ impl ResolveAliasError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ResolveAliasError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6243,7 +8728,12 @@ impl SearchGameSessionsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(SearchGameSessionsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6295,7 +8785,12 @@ impl StartFleetActionsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(StartFleetActionsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6352,7 +8847,12 @@ impl StartGameSessionPlacementError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6405,7 +8905,12 @@ impl StartMatchBackfillError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6456,7 +8961,12 @@ impl StartMatchmakingError {
                 "UnsupportedRegionException" => {
                     return RusotoError::Service(StartMatchmakingError::UnsupportedRegion(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6507,7 +9017,12 @@ impl StopFleetActionsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(StopFleetActionsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6564,7 +9079,12 @@ impl StopGameSessionPlacementError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6615,7 +9135,12 @@ impl StopMatchmakingError {
                 "UnsupportedRegionException" => {
                     return RusotoError::Service(StopMatchmakingError::UnsupportedRegion(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6666,7 +9191,12 @@ impl UpdateAliasError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateAliasError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6717,7 +9247,12 @@ impl UpdateBuildError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateBuildError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6789,7 +9324,12 @@ impl UpdateFleetAttributesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateFleetAttributesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6860,7 +9400,12 @@ impl UpdateFleetCapacityError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateFleetCapacityError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6939,7 +9484,12 @@ impl UpdateFleetPortSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7005,7 +9555,12 @@ impl UpdateGameSessionError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateGameSessionError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7062,7 +9617,12 @@ impl UpdateGameSessionQueueError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateGameSessionQueueError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7123,7 +9683,12 @@ impl UpdateMatchmakingConfigurationError {
                         UpdateMatchmakingConfigurationError::UnsupportedRegion(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7189,7 +9754,12 @@ impl UpdateRuntimeConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7241,7 +9811,12 @@ impl UpdateScriptError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateScriptError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7295,7 +9870,12 @@ impl ValidateMatchmakingRuleSetError {
                         ValidateMatchmakingRuleSetError::UnsupportedRegion(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7743,2040 +10323,6440 @@ pub trait GameLift {
         input: ValidateMatchmakingRuleSetInput,
     ) -> RusotoFuture<ValidateMatchmakingRuleSetOutput, ValidateMatchmakingRuleSetError>;
 }
-/// A client for the Amazon GameLift API.
-#[derive(Clone)]
-pub struct GameLiftClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl GameLiftClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> GameLiftClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> GameLiftClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> GameLiftClient {
-        GameLiftClient { client, region }
-    }
-}
-
-impl GameLift for GameLiftClient {
-    /// <p><p>Registers a player&#39;s acceptance or rejection of a proposed FlexMatch match. A matchmaking configuration may require player acceptance; if so, then matches built with that configuration cannot be completed unless all players accept the proposed match within a specified time limit. </p> <p>When FlexMatch builds a match, all the matchmaking tickets involved in the proposed match are placed into status <code>REQUIRES_ACCEPTANCE</code>. This is a trigger for your game to get acceptance from all players in the ticket. Acceptances are only valid for tickets when they are in this status; all other acceptances result in an error.</p> <p>To register acceptance, specify the ticket ID, a response, and one or more players. Once all players have registered acceptance, the matchmaking tickets advance to status <code>PLACING</code>, where a new game session is created for the match. </p> <p>If any player rejects the match, or if acceptances are not received before a specified timeout, the proposed match is dropped. The matchmaking tickets are then handled in one of two ways: For tickets where one or more players rejected the match, the ticket status is returned to <code>SEARCHING</code> to find a new match. For tickets where one or more players failed to respond, the ticket status is set to <code>CANCELLED</code>, and processing is terminated. A new matchmaking request for these players can be submitted as needed. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-events.html"> FlexMatch Events Reference</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+const _: Option<&dyn GameLift> = None;
+impl<'a, T: GameLift + ?Sized> GameLift for &'a T {
     fn accept_match(
         &self,
         input: AcceptMatchInput,
     ) -> RusotoFuture<AcceptMatchOutput, AcceptMatchError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.AcceptMatch");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AcceptMatchOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AcceptMatchError::from_response(response))),
-                )
-            }
-        })
+        (**self).accept_match(input)
     }
-
-    /// <p><p>Creates an alias for a fleet. In most situations, you can use an alias ID in place of a fleet ID. By using a fleet alias instead of a specific fleet ID, you can switch gameplay and players to a new fleet without changing your game client or other game components. For example, for games in production, using an alias allows you to seamlessly redirect your player base to a new game server update. </p> <p>Amazon GameLift supports two types of routing strategies for aliases: simple and terminal. A simple alias points to an active fleet. A terminal alias is used to display messaging or link to a URL instead of routing players to an active fleet. For example, you might use a terminal alias when a game version is no longer supported and you want to direct players to an upgrade site. </p> <p>To create a fleet alias, specify an alias name, routing strategy, and optional description. Each simple alias can point to only one fleet, but a fleet can have multiple aliases. If successful, a new alias record is returned, including an alias ID, which you can reference when creating a game session. You can reassign an alias to another fleet by calling <code>UpdateAlias</code>.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
     fn create_alias(
         &self,
         input: CreateAliasInput,
     ) -> RusotoFuture<CreateAliasOutput, CreateAliasError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAliasOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_alias(input)
     }
-
-    /// <p><p>Creates a new Amazon GameLift build record for your game server binary files and points to the location of your game server build files in an Amazon Simple Storage Service (Amazon S3) location. </p> <p>Game server binaries must be combined into a <code>.zip</code> file for use with Amazon GameLift. </p> <important> <p>To create new builds quickly and easily, use the AWS CLI command <b> <a href="https://docs.aws.amazon.com/cli/latest/reference/gamelift/upload-build.html">upload-build</a> </b>. This helper command uploads your build and creates a new build record in one step, and automatically handles the necessary permissions. </p> </important> <p>The <code>CreateBuild</code> operation should be used only when you need to manually upload your build files, as in the following scenarios:</p> <ul> <li> <p>Store a build file in an Amazon S3 bucket under your own AWS account. To use this option, you must first give Amazon GameLift access to that Amazon S3 bucket. To create a new build record using files in your Amazon S3 bucket, call <code>CreateBuild</code> and specify a build name, operating system, and the storage location of your game build.</p> </li> <li> <p>Upload a build file directly to Amazon GameLift&#39;s Amazon S3 account. To use this option, you first call <code>CreateBuild</code> with a build name and operating system. This action creates a new build record and returns an Amazon S3 storage location (bucket and key only) and temporary access credentials. Use the credentials to manually upload your build file to the storage location (see the Amazon S3 topic <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UploadingObjects.html">Uploading Objects</a>). You can upload files to a location only once. </p> </li> </ul> <p>If successful, this operation creates a new build record with a unique build ID and places it in <code>INITIALIZED</code> status. You can use <a>DescribeBuild</a> to check the status of your build. A build must be in <code>READY</code> status before it can be used to create fleets.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html">Uploading Your Game</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-cli-uploading.html#gamelift-build-cli-uploading-create-build"> Create a Build with Files in Amazon S3</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
     fn create_build(
         &self,
         input: CreateBuildInput,
     ) -> RusotoFuture<CreateBuildOutput, CreateBuildError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateBuild");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBuildOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBuildError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_build(input)
     }
-
-    /// <p><p>Creates a new fleet to run your game servers. whether they are custom game builds or Realtime Servers with game-specific script. A fleet is a set of Amazon Elastic Compute Cloud (Amazon EC2) instances, each of which can host multiple game sessions. When creating a fleet, you choose the hardware specifications, set some configuration options, and specify the game server to deploy on the new fleet. </p> <p>To create a new fleet, you must provide the following: (1) a fleet name, (2) an EC2 instance type and fleet type (spot or on-demand), (3) the build ID for your game build or script ID if using Realtime Servers, and (4) a run-time configuration, which determines how game servers will run on each instance in the fleet. </p> <note> <p>When creating a Realtime Servers fleet, we recommend using a minimal version of the Realtime script (see this <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-script.html#realtime-script-examples"> working code example </a>). This will make it much easier to troubleshoot any fleet creation issues. Once the fleet is active, you can update your Realtime script as needed.</p> </note> <p>If the <code>CreateFleet</code> call is successful, Amazon GameLift performs the following tasks. You can track the process of a fleet by checking the fleet status or by monitoring fleet creation events:</p> <ul> <li> <p>Creates a fleet record. Status: <code>NEW</code>.</p> </li> <li> <p>Begins writing events to the fleet event log, which can be accessed in the Amazon GameLift console.</p> <p>Sets the fleet&#39;s target capacity to 1 (desired instances), which triggers Amazon GameLift to start one new EC2 instance.</p> </li> <li> <p>Downloads the game build or Realtime script to the new instance and installs it. Statuses: <code>DOWNLOADING</code>, <code>VALIDATING</code>, <code>BUILDING</code>. </p> </li> <li> <p>Starts launching server processes on the instance. If the fleet is configured to run multiple server processes per instance, Amazon GameLift staggers each launch by a few seconds. Status: <code>ACTIVATING</code>.</p> </li> <li> <p>Sets the fleet&#39;s status to <code>ACTIVE</code> as soon as one server process is ready to host a game session.</p> </li> </ul> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-creating-debug.html"> Debug Fleet Creation Issues</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn create_fleet(
         &self,
         input: CreateFleetInput,
     ) -> RusotoFuture<CreateFleetOutput, CreateFleetError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFleetOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateFleetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_fleet(input)
     }
-
-    /// <p><p>Creates a multiplayer game session for players. This action creates a game session record and assigns an available server process in the specified fleet to host the game session. A fleet must have an <code>ACTIVE</code> status before a game session can be created in it.</p> <p>To create a game session, specify either fleet ID or alias ID and indicate a maximum number of players to allow in the game session. You can also provide a name and game-specific properties for this game session. If successful, a <a>GameSession</a> object is returned containing the game session properties and other settings you specified.</p> <p> <b>Idempotency tokens.</b> You can add a token that uniquely identifies game session requests. This is useful for ensuring that game session requests are idempotent. Multiple requests with the same idempotency token are processed only once; subsequent requests return the original result. All response values are the same with the exception of game session status, which may change.</p> <p> <b>Resource creation limits.</b> If you are creating a game session on a fleet with a resource creation limit policy in force, then you must specify a creator ID. Without this ID, Amazon GameLift has no way to evaluate the policy for this new game session request.</p> <p> <b>Player acceptance policy.</b> By default, newly created game sessions are open to new players. You can restrict new player access by using <a>UpdateGameSession</a> to change the game session&#39;s player session creation policy.</p> <p> <b>Game session logs.</b> Logs are retained for all active game sessions for 14 days. To access the logs, call <a>GetGameSessionLogUrl</a> to download the log files.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn create_game_session(
         &self,
         input: CreateGameSessionInput,
     ) -> RusotoFuture<CreateGameSessionOutput, CreateGameSessionError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateGameSession");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGameSessionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGameSessionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_game_session(input)
     }
-
-    /// <p><p>Establishes a new queue for processing requests to place new game sessions. A queue identifies where new game sessions can be hosted -- by specifying a list of destinations (fleets or aliases) -- and how long requests can wait in the queue before timing out. You can set up a queue to try to place game sessions on fleets in multiple regions. To add placement requests to a queue, call <a>StartGameSessionPlacement</a> and reference the queue name.</p> <p> <b>Destination order.</b> When processing a request for a game session, Amazon GameLift tries each destination in order until it finds one with available resources to host the new game session. A queue&#39;s default order is determined by how destinations are listed. The default order is overridden when a game session placement request provides player latency information. Player latency information enables Amazon GameLift to prioritize destinations where players report the lowest average latency, as a result placing the new game session where the majority of players will have the best possible gameplay experience.</p> <p> <b>Player latency policies.</b> For placement requests containing player latency information, use player latency policies to protect individual players from very high latencies. With a latency cap, even when a destination can deliver a low latency for most players, the game is not placed where any individual player is reporting latency higher than a policy&#39;s maximum. A queue can have multiple latency policies, which are enforced consecutively starting with the policy with the lowest latency cap. Use multiple policies to gradually relax latency controls; for example, you might set a policy with a low latency cap for the first 60 seconds, a second policy with a higher cap for the next 60 seconds, etc. </p> <p>To create a new queue, provide a name, timeout value, a list of destinations and, if desired, a set of latency policies. If successful, a new queue object is returned.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
     fn create_game_session_queue(
         &self,
         input: CreateGameSessionQueueInput,
     ) -> RusotoFuture<CreateGameSessionQueueOutput, CreateGameSessionQueueError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateGameSessionQueue");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGameSessionQueueOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateGameSessionQueueError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_game_session_queue(input)
     }
-
-    /// <p><p>Defines a new matchmaking configuration for use with FlexMatch. A matchmaking configuration sets out guidelines for matching players and getting the matches into games. You can set up multiple matchmaking configurations to handle the scenarios needed for your game. Each matchmaking ticket (<a>StartMatchmaking</a> or <a>StartMatchBackfill</a>) specifies a configuration for the match and provides player attributes to support the configuration being used. </p> <p>To create a matchmaking configuration, at a minimum you must specify the following: configuration name; a rule set that governs how to evaluate players and find acceptable matches; a game session queue to use when placing a new game session for the match; and the maximum time allowed for a matchmaking attempt.</p> <p>There are two ways to track the progress of matchmaking tickets: (1) polling ticket status with <a>DescribeMatchmaking</a>; or (2) receiving notifications with Amazon Simple Notification Service (SNS). To use notifications, you first need to set up an SNS topic to receive the notifications, and provide the topic ARN in the matchmaking configuration. Since notifications promise only &quot;best effort&quot; delivery, we recommend calling <code>DescribeMatchmaking</code> if no notifications are received within 30 seconds.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html"> Design a FlexMatch Matchmaker</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Setting up Notifications for Matchmaking</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
     fn create_matchmaking_configuration(
         &self,
         input: CreateMatchmakingConfigurationInput,
     ) -> RusotoFuture<CreateMatchmakingConfigurationOutput, CreateMatchmakingConfigurationError>
     {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateMatchmakingConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateMatchmakingConfigurationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateMatchmakingConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_matchmaking_configuration(input)
     }
-
-    /// <p><p>Creates a new rule set for FlexMatch matchmaking. A rule set describes the type of match to create, such as the number and size of teams, and sets the parameters for acceptable player matches, such as minimum skill level or character type. A rule set is used by a <a>MatchmakingConfiguration</a>. </p> <p>To create a matchmaking rule set, provide unique rule set name and the rule set body in JSON format. Rule sets must be defined in the same region as the matchmaking configuration they are used with.</p> <p>Since matchmaking rule sets cannot be edited, it is a good idea to check the rule set syntax using <a>ValidateMatchmakingRuleSet</a> before creating a new rule set.</p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html">Design a Matchmaker</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-intro.html">Matchmaking with FlexMatch</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
     fn create_matchmaking_rule_set(
         &self,
         input: CreateMatchmakingRuleSetInput,
     ) -> RusotoFuture<CreateMatchmakingRuleSetOutput, CreateMatchmakingRuleSetError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateMatchmakingRuleSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateMatchmakingRuleSetOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateMatchmakingRuleSetError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_matchmaking_rule_set(input)
     }
-
-    /// <p><p>Reserves an open player slot in an active game session. Before a player can be added, a game session must have an <code>ACTIVE</code> status, have a creation policy of <code>ALLOW_ALL</code>, and have an open player slot. To add a group of players to a game session, use <a>CreatePlayerSessions</a>. When the player connects to the game server and references a player session ID, the game server contacts the Amazon GameLift service to validate the player reservation and accept the player.</p> <p>To create a player session, specify a game session ID, player ID, and optionally a string of player data. If successful, a slot is reserved in the game session for the player and a new <a>PlayerSession</a> object is returned. Player sessions cannot be updated. </p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn create_player_session(
         &self,
         input: CreatePlayerSessionInput,
     ) -> RusotoFuture<CreatePlayerSessionOutput, CreatePlayerSessionError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreatePlayerSession");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePlayerSessionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreatePlayerSessionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_player_session(input)
     }
-
-    /// <p><p>Reserves open slots in a game session for a group of players. Before players can be added, a game session must have an <code>ACTIVE</code> status, have a creation policy of <code>ALLOW_ALL</code>, and have an open player slot. To add a single player to a game session, use <a>CreatePlayerSession</a>. When a player connects to the game server and references a player session ID, the game server contacts the Amazon GameLift service to validate the player reservation and accept the player.</p> <p>To create player sessions, specify a game session ID, a list of player IDs, and optionally a set of player data strings. If successful, a slot is reserved in the game session for each player and a set of new <a>PlayerSession</a> objects is returned. Player sessions cannot be updated.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn create_player_sessions(
         &self,
         input: CreatePlayerSessionsInput,
     ) -> RusotoFuture<CreatePlayerSessionsOutput, CreatePlayerSessionsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreatePlayerSessions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePlayerSessionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreatePlayerSessionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_player_sessions(input)
     }
-
-    /// <p><p>Creates a new script record for your Realtime Servers script. Realtime scripts are JavaScript that provide configuration settings and optional custom game logic for your game. The script is deployed when you create a Realtime Servers fleet to host your game sessions. Script logic is executed during an active game session. </p> <p>To create a new script record, specify a script name and provide the script file(s). The script files and all dependencies must be zipped into a single file. You can pull the zip file from either of these locations: </p> <ul> <li> <p>A locally available directory. Use the <i>ZipFile</i> parameter for this option.</p> </li> <li> <p>An Amazon Simple Storage Service (Amazon S3) bucket under your AWS account. Use the <i>StorageLocation</i> parameter for this option. You&#39;ll need to have an Identity Access Management (IAM) role that allows the Amazon GameLift service to access your S3 bucket. </p> </li> </ul> <p>If the call is successful, a new script record is created with a unique script ID. If the script file is provided as a local file, the file is uploaded to an Amazon GameLift-owned S3 bucket and the script record&#39;s storage location reflects this location. If the script file is provided as an S3 bucket, Amazon GameLift accesses the file at this storage location as needed for deployment.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/setting-up-role.html">Set Up a Role for Amazon GameLift Access</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
     fn create_script(
         &self,
         input: CreateScriptInput,
     ) -> RusotoFuture<CreateScriptOutput, CreateScriptError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateScript");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateScriptOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateScriptError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_script(input)
     }
-
-    /// <p><p>Requests authorization to create or delete a peer connection between the VPC for your Amazon GameLift fleet and a virtual private cloud (VPC) in your AWS account. VPC peering enables the game servers on your fleet to communicate directly with other AWS resources. Once you&#39;ve received authorization, call <a>CreateVpcPeeringConnection</a> to establish the peering connection. For more information, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/vpc-peering.html">VPC Peering with Amazon GameLift Fleets</a>.</p> <p>You can peer with VPCs that are owned by any AWS account you have access to, including the account that you use to manage your Amazon GameLift fleets. You cannot peer with VPCs that are in different regions.</p> <p>To request authorization to create a connection, call this operation from the AWS account with the VPC that you want to peer to your Amazon GameLift fleet. For example, to enable your game servers to retrieve data from a DynamoDB table, use the account that manages that DynamoDB resource. Identify the following values: (1) The ID of the VPC that you want to peer with, and (2) the ID of the AWS account that you use to manage Amazon GameLift. If successful, VPC peering is authorized for the specified VPC. </p> <p>To request authorization to delete a connection, call this operation from the AWS account with the VPC that is peered with your Amazon GameLift fleet. Identify the following values: (1) VPC ID that you want to delete the peering connection for, and (2) ID of the AWS account that you use to manage Amazon GameLift. </p> <p>The authorization remains valid for 24 hours unless it is canceled by a call to <a>DeleteVpcPeeringAuthorization</a>. You must create or delete the peering connection while the authorization is valid. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
     fn create_vpc_peering_authorization(
         &self,
         input: CreateVpcPeeringAuthorizationInput,
     ) -> RusotoFuture<CreateVpcPeeringAuthorizationOutput, CreateVpcPeeringAuthorizationError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateVpcPeeringAuthorization");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateVpcPeeringAuthorizationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateVpcPeeringAuthorizationError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_vpc_peering_authorization(input)
     }
-
-    /// <p><p>Establishes a VPC peering connection between a virtual private cloud (VPC) in an AWS account with the VPC for your Amazon GameLift fleet. VPC peering enables the game servers on your fleet to communicate directly with other AWS resources. You can peer with VPCs in any AWS account that you have access to, including the account that you use to manage your Amazon GameLift fleets. You cannot peer with VPCs that are in different regions. For more information, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/vpc-peering.html">VPC Peering with Amazon GameLift Fleets</a>.</p> <p>Before calling this operation to establish the peering connection, you first need to call <a>CreateVpcPeeringAuthorization</a> and identify the VPC you want to peer with. Once the authorization for the specified VPC is issued, you have 24 hours to establish the connection. These two operations handle all tasks necessary to peer the two VPCs, including acceptance, updating routing tables, etc. </p> <p>To establish the connection, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Identify the following values: (1) The ID of the fleet you want to be enable a VPC peering connection for; (2) The AWS account with the VPC that you want to peer with; and (3) The ID of the VPC you want to peer with. This operation is asynchronous. If successful, a <a>VpcPeeringConnection</a> request is created. You can use continuous polling to track the request&#39;s status using <a>DescribeVpcPeeringConnections</a>, or by monitoring fleet events for success or failure using <a>DescribeFleetEvents</a>. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
     fn create_vpc_peering_connection(
         &self,
         input: CreateVpcPeeringConnectionInput,
     ) -> RusotoFuture<CreateVpcPeeringConnectionOutput, CreateVpcPeeringConnectionError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateVpcPeeringConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateVpcPeeringConnectionOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateVpcPeeringConnectionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_vpc_peering_connection(input)
     }
-
-    /// <p><p>Deletes an alias. This action removes all record of the alias. Game clients attempting to access a server process using the deleted alias receive an error. To delete an alias, specify the alias ID to be deleted.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
     fn delete_alias(&self, input: DeleteAliasInput) -> RusotoFuture<(), DeleteAliasError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DeleteAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_alias(input)
     }
-
-    /// <p><p>Deletes a build. This action permanently deletes the build record and any uploaded build files.</p> <p>To delete a build, specify its ID. Deleting a build does not affect the status of any active fleets using the build, but you can no longer create new fleets with the deleted build.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
     fn delete_build(&self, input: DeleteBuildInput) -> RusotoFuture<(), DeleteBuildError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DeleteBuild");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBuildError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_build(input)
     }
-
-    /// <p><p>Deletes everything related to a fleet. Before deleting a fleet, you must set the fleet&#39;s desired capacity to zero. See <a>UpdateFleetCapacity</a>.</p> <p>If the fleet being deleted has a VPC peering connection, you first need to get a valid authorization (good for 24 hours) by calling <a>CreateVpcPeeringAuthorization</a>. You do not need to explicitly delete the VPC peering connection--this is done as part of the delete fleet process.</p> <p>This action removes the fleet&#39;s resources and the fleet record. Once a fleet is deleted, you can no longer use that fleet.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn delete_fleet(&self, input: DeleteFleetInput) -> RusotoFuture<(), DeleteFleetError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DeleteFleet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFleetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_fleet(input)
     }
-
-    /// <p><p>Deletes a game session queue. This action means that any <a>StartGameSessionPlacement</a> requests that reference this queue will fail. To delete a queue, specify the queue name.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
     fn delete_game_session_queue(
         &self,
         input: DeleteGameSessionQueueInput,
     ) -> RusotoFuture<DeleteGameSessionQueueOutput, DeleteGameSessionQueueError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DeleteGameSessionQueue");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteGameSessionQueueOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteGameSessionQueueError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_game_session_queue(input)
     }
-
-    /// <p><p>Permanently removes a FlexMatch matchmaking configuration. To delete, specify the configuration name. A matchmaking configuration cannot be deleted if it is being used in any active matchmaking tickets.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
     fn delete_matchmaking_configuration(
         &self,
         input: DeleteMatchmakingConfigurationInput,
     ) -> RusotoFuture<DeleteMatchmakingConfigurationOutput, DeleteMatchmakingConfigurationError>
     {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DeleteMatchmakingConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteMatchmakingConfigurationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteMatchmakingConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_matchmaking_configuration(input)
     }
-
-    /// <p><p>Deletes an existing matchmaking rule set. To delete the rule set, provide the rule set name. Rule sets cannot be deleted if they are currently being used by a matchmaking configuration. </p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
     fn delete_matchmaking_rule_set(
         &self,
         input: DeleteMatchmakingRuleSetInput,
     ) -> RusotoFuture<DeleteMatchmakingRuleSetOutput, DeleteMatchmakingRuleSetError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DeleteMatchmakingRuleSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteMatchmakingRuleSetOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteMatchmakingRuleSetError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_matchmaking_rule_set(input)
     }
-
-    /// <p><p>Deletes a fleet scaling policy. This action means that the policy is no longer in force and removes all record of it. To delete a scaling policy, specify both the scaling policy name and the fleet ID it is associated with.</p> <p>To temporarily suspend scaling policies, call <a>StopFleetActions</a>. This operation suspends all policies for the fleet.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn delete_scaling_policy(
         &self,
         input: DeleteScalingPolicyInput,
     ) -> RusotoFuture<(), DeleteScalingPolicyError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DeleteScalingPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteScalingPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_scaling_policy(input)
     }
-
-    /// <p><p>Deletes a Realtime script. This action permanently deletes the script record. If script files were uploaded, they are also deleted (files stored in an S3 bucket are not deleted). </p> <p>To delete a script, specify the script ID. Before deleting a script, be sure to terminate all fleets that are deployed with the script being deleted. Fleet instances periodically check for script updates, and if the script record no longer exists, the instance will go into an error state and be unable to host game sessions.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
     fn delete_script(&self, input: DeleteScriptInput) -> RusotoFuture<(), DeleteScriptError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DeleteScript");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteScriptError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_script(input)
     }
-
-    /// <p><p>Cancels a pending VPC peering authorization for the specified VPC. If you need to delete an existing VPC peering connection, call <a>DeleteVpcPeeringConnection</a>. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
     fn delete_vpc_peering_authorization(
         &self,
         input: DeleteVpcPeeringAuthorizationInput,
     ) -> RusotoFuture<DeleteVpcPeeringAuthorizationOutput, DeleteVpcPeeringAuthorizationError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringAuthorization");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteVpcPeeringAuthorizationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteVpcPeeringAuthorizationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_vpc_peering_authorization(input)
     }
-
-    /// <p><p>Removes a VPC peering connection. To delete the connection, you must have a valid authorization for the VPC peering connection that you want to delete. You can check for an authorization by calling <a>DescribeVpcPeeringAuthorizations</a> or request a new one using <a>CreateVpcPeeringAuthorization</a>. </p> <p>Once a valid authorization exists, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Identify the connection to delete by the connection ID and fleet ID. If successful, the connection is removed. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
     fn delete_vpc_peering_connection(
         &self,
         input: DeleteVpcPeeringConnectionInput,
     ) -> RusotoFuture<DeleteVpcPeeringConnectionOutput, DeleteVpcPeeringConnectionError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteVpcPeeringConnectionOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteVpcPeeringConnectionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_vpc_peering_connection(input)
     }
-
-    /// <p><p>Retrieves properties for an alias. This operation returns all alias metadata and settings. To get an alias&#39;s target fleet ID only, use <code>ResolveAlias</code>. </p> <p>To get alias properties, specify the alias ID. If successful, the requested alias record is returned.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
     fn describe_alias(
         &self,
         input: DescribeAliasInput,
     ) -> RusotoFuture<DescribeAliasOutput, DescribeAliasError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAliasOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_alias(input)
     }
-
-    /// <p><p>Retrieves properties for a build. To request a build record, specify a build ID. If successful, an object containing the build properties is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
     fn describe_build(
         &self,
         input: DescribeBuildInput,
     ) -> RusotoFuture<DescribeBuildOutput, DescribeBuildError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeBuild");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBuildOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeBuildError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_build(input)
     }
-
-    /// <p><p>Retrieves the following information for the specified EC2 instance type:</p> <ul> <li> <p>maximum number of instances allowed per AWS account (service limit)</p> </li> <li> <p>current usage level for the AWS account</p> </li> </ul> <p>Service limits vary depending on region. Available regions for Amazon GameLift can be found in the AWS Management Console for Amazon GameLift (see the drop-down list in the upper right corner).</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn describe_ec2_instance_limits(
         &self,
         input: DescribeEC2InstanceLimitsInput,
     ) -> RusotoFuture<DescribeEC2InstanceLimitsOutput, DescribeEC2InstanceLimitsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeEC2InstanceLimits");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEC2InstanceLimitsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEC2InstanceLimitsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_ec2_instance_limits(input)
     }
-
-    /// <p><p>Retrieves fleet properties, including metadata, status, and configuration, for one or more fleets. You can request attributes for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetAttributes</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, attribute objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn describe_fleet_attributes(
         &self,
         input: DescribeFleetAttributesInput,
     ) -> RusotoFuture<DescribeFleetAttributesOutput, DescribeFleetAttributesError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeFleetAttributes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeFleetAttributesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeFleetAttributesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_fleet_attributes(input)
     }
-
-    /// <p><p>Retrieves the current status of fleet capacity for one or more fleets. This information includes the number of instances that have been requested for the fleet and the number currently active. You can request capacity for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetCapacity</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, attribute objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn describe_fleet_capacity(
         &self,
         input: DescribeFleetCapacityInput,
     ) -> RusotoFuture<DescribeFleetCapacityOutput, DescribeFleetCapacityError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeFleetCapacity");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeFleetCapacityOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeFleetCapacityError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_fleet_capacity(input)
     }
-
-    /// <p><p>Retrieves entries from the specified fleet&#39;s event log. You can specify a time range to limit the result set. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a collection of event log entries matching the request are returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn describe_fleet_events(
         &self,
         input: DescribeFleetEventsInput,
     ) -> RusotoFuture<DescribeFleetEventsOutput, DescribeFleetEventsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeFleetEvents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeFleetEventsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeFleetEventsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_fleet_events(input)
     }
-
-    /// <p><p>Retrieves the inbound connection permissions for a fleet. Connection permissions include a range of IP addresses and port settings that incoming traffic can use to access server processes in the fleet. To get a fleet&#39;s inbound connection permissions, specify a fleet ID. If successful, a collection of <a>IpPermission</a> objects is returned for the requested fleet ID. If the requested fleet has been deleted, the result set is empty.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn describe_fleet_port_settings(
         &self,
         input: DescribeFleetPortSettingsInput,
     ) -> RusotoFuture<DescribeFleetPortSettingsOutput, DescribeFleetPortSettingsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeFleetPortSettings");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeFleetPortSettingsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeFleetPortSettingsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_fleet_port_settings(input)
     }
-
-    /// <p><p>Retrieves utilization statistics for one or more fleets. You can request utilization data for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetUtilization</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, utilization objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn describe_fleet_utilization(
         &self,
         input: DescribeFleetUtilizationInput,
     ) -> RusotoFuture<DescribeFleetUtilizationOutput, DescribeFleetUtilizationError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeFleetUtilization");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeFleetUtilizationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeFleetUtilizationError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_fleet_utilization(input)
     }
-
-    /// <p><p>Retrieves properties, including the protection policy in force, for one or more game sessions. This action can be used in several ways: (1) provide a <code>GameSessionId</code> or <code>GameSessionArn</code> to request details for a specific game session; (2) provide either a <code>FleetId</code> or an <code>AliasId</code> to request properties for all game sessions running on a fleet. </p> <p>To get game session record(s), specify just one of the following: game session ID, fleet ID, or alias ID. You can filter this request by game session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSessionDetail</a> object is returned for each session matching the request.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn describe_game_session_details(
         &self,
         input: DescribeGameSessionDetailsInput,
     ) -> RusotoFuture<DescribeGameSessionDetailsOutput, DescribeGameSessionDetailsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeGameSessionDetails");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGameSessionDetailsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeGameSessionDetailsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_game_session_details(input)
     }
-
-    /// <p><p>Retrieves properties and current status of a game session placement request. To get game session placement details, specify the placement ID. If successful, a <a>GameSessionPlacement</a> object is returned.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn describe_game_session_placement(
         &self,
         input: DescribeGameSessionPlacementInput,
     ) -> RusotoFuture<DescribeGameSessionPlacementOutput, DescribeGameSessionPlacementError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeGameSessionPlacement");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGameSessionPlacementOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeGameSessionPlacementError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_game_session_placement(input)
     }
-
-    /// <p><p>Retrieves the properties for one or more game session queues. When requesting multiple queues, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSessionQueue</a> object is returned for each requested queue. When specifying a list of queues, objects are returned only for queues that currently exist in the region.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
     fn describe_game_session_queues(
         &self,
         input: DescribeGameSessionQueuesInput,
     ) -> RusotoFuture<DescribeGameSessionQueuesOutput, DescribeGameSessionQueuesError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeGameSessionQueues");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGameSessionQueuesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeGameSessionQueuesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_game_session_queues(input)
     }
-
-    /// <p><p>Retrieves a set of one or more game sessions. Request a specific game session or request all game sessions on a fleet. Alternatively, use <a>SearchGameSessions</a> to request a set of active game sessions that are filtered by certain criteria. To retrieve protection policy settings for game sessions, use <a>DescribeGameSessionDetails</a>.</p> <p>To get game sessions, specify one of the following: game session ID, fleet ID, or alias ID. You can filter this request by game session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSession</a> object is returned for each game session matching the request.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn describe_game_sessions(
         &self,
         input: DescribeGameSessionsInput,
     ) -> RusotoFuture<DescribeGameSessionsOutput, DescribeGameSessionsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeGameSessions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGameSessionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeGameSessionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_game_sessions(input)
     }
-
-    /// <p>Retrieves information about a fleet's instances, including instance IDs. Use this action to get details on all instances in the fleet or get details on one specific instance.</p> <p>To get a specific instance, specify fleet ID and instance ID. To get all instances in a fleet, specify a fleet ID only. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, an <a>Instance</a> object is returned for each result.</p>
     fn describe_instances(
         &self,
         input: DescribeInstancesInput,
     ) -> RusotoFuture<DescribeInstancesOutput, DescribeInstancesError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeInstances");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInstancesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeInstancesError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_instances(input)
     }
-
-    /// <p><p>Retrieves one or more matchmaking tickets. Use this operation to retrieve ticket information, including status and--once a successful match is made--acquire connection information for the resulting new game session. </p> <p>You can use this operation to track the progress of matchmaking requests (through polling) as an alternative to using event notifications. See more details on tracking matchmaking requests through polling or notifications in <a>StartMatchmaking</a>. </p> <p>To request matchmaking tickets, provide a list of up to 10 ticket IDs. If the request is successful, a ticket object is returned for each requested ID that currently exists.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguidematch-notification.html"> Set Up FlexMatch Event Notification</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
     fn describe_matchmaking(
         &self,
         input: DescribeMatchmakingInput,
     ) -> RusotoFuture<DescribeMatchmakingOutput, DescribeMatchmakingError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeMatchmaking");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMatchmakingOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeMatchmakingError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_matchmaking(input)
     }
-
-    /// <p><p>Retrieves the details of FlexMatch matchmaking configurations. With this operation, you have the following options: (1) retrieve all existing configurations, (2) provide the names of one or more configurations to retrieve, or (3) retrieve all configurations that use a specified rule set name. When requesting multiple items, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a configuration is returned for each requested name. When specifying a list of names, only configurations that currently exist are returned. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/matchmaker-build.html"> Setting Up FlexMatch Matchmakers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
     fn describe_matchmaking_configurations(
         &self,
         input: DescribeMatchmakingConfigurationsInput,
     ) -> RusotoFuture<DescribeMatchmakingConfigurationsOutput, DescribeMatchmakingConfigurationsError>
     {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeMatchmakingConfigurations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMatchmakingConfigurationsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMatchmakingConfigurationsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_matchmaking_configurations(input)
     }
-
-    /// <p><p>Retrieves the details for FlexMatch matchmaking rule sets. You can request all existing rule sets for the region, or provide a list of one or more rule set names. When requesting multiple items, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a rule set is returned for each requested name. </p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
     fn describe_matchmaking_rule_sets(
         &self,
         input: DescribeMatchmakingRuleSetsInput,
     ) -> RusotoFuture<DescribeMatchmakingRuleSetsOutput, DescribeMatchmakingRuleSetsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeMatchmakingRuleSets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMatchmakingRuleSetsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMatchmakingRuleSetsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_matchmaking_rule_sets(input)
     }
-
-    /// <p><p>Retrieves properties for one or more player sessions. This action can be used in several ways: (1) provide a <code>PlayerSessionId</code> to request properties for a specific player session; (2) provide a <code>GameSessionId</code> to request properties for all player sessions in the specified game session; (3) provide a <code>PlayerId</code> to request properties for all player sessions of a specified player. </p> <p>To get game session record(s), specify only one of the following: a player session ID, a game session ID, or a player ID. You can filter this request by player session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>PlayerSession</a> object is returned for each session matching the request.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn describe_player_sessions(
         &self,
         input: DescribePlayerSessionsInput,
     ) -> RusotoFuture<DescribePlayerSessionsOutput, DescribePlayerSessionsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribePlayerSessions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePlayerSessionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribePlayerSessionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_player_sessions(input)
     }
-
-    /// <p><p>Retrieves the current run-time configuration for the specified fleet. The run-time configuration tells Amazon GameLift how to launch server processes on instances in the fleet.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn describe_runtime_configuration(
         &self,
         input: DescribeRuntimeConfigurationInput,
     ) -> RusotoFuture<DescribeRuntimeConfigurationOutput, DescribeRuntimeConfigurationError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeRuntimeConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRuntimeConfigurationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeRuntimeConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_runtime_configuration(input)
     }
-
-    /// <p><p>Retrieves all scaling policies applied to a fleet.</p> <p>To get a fleet&#39;s scaling policies, specify the fleet ID. You can filter this request by policy status, such as to retrieve only active scaling policies. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, set of <a>ScalingPolicy</a> objects is returned for the fleet.</p> <p>A fleet may have all of its scaling policies suspended (<a>StopFleetActions</a>). This action does not affect the status of the scaling policies, which remains ACTIVE. To see whether a fleet&#39;s scaling policies are in force or suspended, call <a>DescribeFleetAttributes</a> and check the stopped actions.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn describe_scaling_policies(
         &self,
         input: DescribeScalingPoliciesInput,
     ) -> RusotoFuture<DescribeScalingPoliciesOutput, DescribeScalingPoliciesError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeScalingPolicies");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeScalingPoliciesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeScalingPoliciesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_scaling_policies(input)
     }
-
-    /// <p><p>Retrieves properties for a Realtime script. </p> <p>To request a script record, specify the script ID. If successful, an object containing the script properties is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
     fn describe_script(
         &self,
         input: DescribeScriptInput,
     ) -> RusotoFuture<DescribeScriptOutput, DescribeScriptError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeScript");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeScriptOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeScriptError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_script(input)
     }
-
-    /// <p><p>Retrieves valid VPC peering authorizations that are pending for the AWS account. This operation returns all VPC peering authorizations and requests for peering. This includes those initiated and received by this account. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
     fn describe_vpc_peering_authorizations(
         &self,
     ) -> RusotoFuture<DescribeVpcPeeringAuthorizationsOutput, DescribeVpcPeeringAuthorizationsError>
     {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringAuthorizations");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeVpcPeeringAuthorizationsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeVpcPeeringAuthorizationsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_vpc_peering_authorizations()
     }
-
-    /// <p><p>Retrieves information on VPC peering connections. Use this operation to get peering information for all fleets or for one specific fleet ID. </p> <p>To retrieve connection information, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Specify a fleet ID or leave the parameter empty to retrieve all connection records. If successful, the retrieved information includes both active and pending connections. Active connections identify the IpV4 CIDR block that the VPC uses to connect. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
     fn describe_vpc_peering_connections(
         &self,
         input: DescribeVpcPeeringConnectionsInput,
     ) -> RusotoFuture<DescribeVpcPeeringConnectionsOutput, DescribeVpcPeeringConnectionsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringConnections");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeVpcPeeringConnectionsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeVpcPeeringConnectionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_vpc_peering_connections(input)
     }
-
-    /// <p><p>Retrieves the location of stored game session logs for a specified game session. When a game session is terminated, Amazon GameLift automatically stores the logs in Amazon S3 and retains them for 14 days. Use this URL to download the logs.</p> <note> <p>See the <a href="https://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_gamelift">AWS Service Limits</a> page for maximum log file sizes. Log files that exceed this limit are not saved.</p> </note> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn get_game_session_log_url(
         &self,
         input: GetGameSessionLogUrlInput,
     ) -> RusotoFuture<GetGameSessionLogUrlOutput, GetGameSessionLogUrlError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.GetGameSessionLogUrl");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGameSessionLogUrlOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetGameSessionLogUrlError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_game_session_log_url(input)
     }
-
-    /// <p>Requests remote access to a fleet instance. Remote access is useful for debugging, gathering benchmarking data, or watching activity in real time. </p> <p>Access requires credentials that match the operating system of the instance. For a Windows instance, Amazon GameLift returns a user name and password as strings for use with a Windows Remote Desktop client. For a Linux instance, Amazon GameLift returns a user name and RSA private key, also as strings, for use with an SSH client. The private key must be saved in the proper format to a <code>.pem</code> file before using. If you're making this request using the AWS CLI, saving the secret can be handled as part of the GetInstanceAccess request. (See the example later in this topic). For more information on remote access, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-remote-access.html">Remotely Accessing an Instance</a>.</p> <p>To request access to a specific instance, specify the IDs of both the instance and the fleet it belongs to. You can retrieve a fleet's instance IDs by calling <a>DescribeInstances</a>. If successful, an <a>InstanceAccess</a> object is returned containing the instance's IP address and a set of credentials.</p>
     fn get_instance_access(
         &self,
         input: GetInstanceAccessInput,
     ) -> RusotoFuture<GetInstanceAccessOutput, GetInstanceAccessError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.GetInstanceAccess");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstanceAccessOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetInstanceAccessError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_instance_access(input)
     }
-
-    /// <p><p>Retrieves all aliases for this AWS account. You can filter the result set by alias name and/or routing strategy type. Use the pagination parameters to retrieve results in sequential pages.</p> <note> <p>Returned aliases are not listed in any particular order.</p> </note> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
     fn list_aliases(
         &self,
         input: ListAliasesInput,
     ) -> RusotoFuture<ListAliasesOutput, ListAliasesError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.ListAliases");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAliasesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAliasesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_aliases(input)
     }
-
-    /// <p><p>Retrieves build records for all builds associated with the AWS account in use. You can limit results to builds that are in a specific status by using the <code>Status</code> parameter. Use the pagination parameters to retrieve results in a set of sequential pages. </p> <note> <p>Build records are not listed in any particular order.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
     fn list_builds(
         &self,
         input: ListBuildsInput,
     ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.ListBuilds");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBuildsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListBuildsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_builds(input)
     }
-
-    /// <p><p>Retrieves a collection of fleet records for this AWS account. You can filter the result set to find only those fleets that are deployed with a specific build or script. Use the pagination parameters to retrieve results in sequential pages.</p> <note> <p>Fleet records are not listed in a particular order.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Set Up Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn list_fleets(
         &self,
         input: ListFleetsInput,
     ) -> RusotoFuture<ListFleetsOutput, ListFleetsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.ListFleets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFleetsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListFleetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_fleets(input)
     }
-
-    /// <p><p>Retrieves script records for all Realtime scripts that are associated with the AWS account in use. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
     fn list_scripts(
         &self,
         input: ListScriptsInput,
     ) -> RusotoFuture<ListScriptsOutput, ListScriptsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.ListScripts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListScriptsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListScriptsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_scripts(input)
     }
-
-    /// <p><p>Creates or updates a scaling policy for a fleet. Scaling policies are used to automatically scale a fleet&#39;s hosting capacity to meet player demand. An active scaling policy instructs Amazon GameLift to track a fleet metric and automatically change the fleet&#39;s capacity when a certain threshold is reached. There are two types of scaling policies: target-based and rule-based. Use a target-based policy to quickly and efficiently manage fleet scaling; this option is the most commonly used. Use rule-based policies when you need to exert fine-grained control over auto-scaling. </p> <p>Fleets can have multiple scaling policies of each type in force at the same time; you can have one target-based policy, one or multiple rule-based scaling policies, or both. We recommend caution, however, because multiple auto-scaling policies can have unintended consequences.</p> <p>You can temporarily suspend all scaling policies for a fleet by calling <a>StopFleetActions</a> with the fleet action AUTO_SCALING. To resume scaling policies, call <a>StartFleetActions</a> with the same fleet action. To stop just one scaling policy--or to permanently remove it, you must delete the policy with <a>DeleteScalingPolicy</a>.</p> <p>Learn more about how to work with auto-scaling in <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-autoscaling.html">Set Up Fleet Automatic Scaling</a>.</p> <p> <b>Target-based policy</b> </p> <p>A target-based policy tracks a single metric: PercentAvailableGameSessions. This metric tells us how much of a fleet&#39;s hosting capacity is ready to host game sessions but is not currently in use. This is the fleet&#39;s buffer; it measures the additional player demand that the fleet could handle at current capacity. With a target-based policy, you set your ideal buffer size and leave it to Amazon GameLift to take whatever action is needed to maintain that target. </p> <p>For example, you might choose to maintain a 10% buffer for a fleet that has the capacity to host 100 simultaneous game sessions. This policy tells Amazon GameLift to take action whenever the fleet&#39;s available capacity falls below or rises above 10 game sessions. Amazon GameLift will start new instances or stop unused instances in order to return to the 10% buffer. </p> <p>To create or update a target-based policy, specify a fleet ID and name, and set the policy type to &quot;TargetBased&quot;. Specify the metric to track (PercentAvailableGameSessions) and reference a <a>TargetConfiguration</a> object with your desired buffer value. Exclude all other parameters. On a successful request, the policy name is returned. The scaling policy is automatically in force as soon as it&#39;s successfully created. If the fleet&#39;s auto-scaling actions are temporarily suspended, the new policy will be in force once the fleet actions are restarted.</p> <p> <b>Rule-based policy</b> </p> <p>A rule-based policy tracks specified fleet metric, sets a threshold value, and specifies the type of action to initiate when triggered. With a rule-based policy, you can select from several available fleet metrics. Each policy specifies whether to scale up or scale down (and by how much), so you need one policy for each type of action. </p> <p>For example, a policy may make the following statement: &quot;If the percentage of idle instances is greater than 20% for more than 15 minutes, then reduce the fleet capacity by 10%.&quot;</p> <p>A policy&#39;s rule statement has the following structure:</p> <p>If <code>[MetricName]</code> is <code>[ComparisonOperator]</code> <code>[Threshold]</code> for <code>[EvaluationPeriods]</code> minutes, then <code>[ScalingAdjustmentType]</code> to/by <code>[ScalingAdjustment]</code>.</p> <p>To implement the example, the rule statement would look like this:</p> <p>If <code>[PercentIdleInstances]</code> is <code>[GreaterThanThreshold]</code> <code>[20]</code> for <code>[15]</code> minutes, then <code>[PercentChangeInCapacity]</code> to/by <code>[10]</code>.</p> <p>To create or update a scaling policy, specify a unique combination of name and fleet ID, and set the policy type to &quot;RuleBased&quot;. Specify the parameter values for a policy rule statement. On a successful request, the policy name is returned. Scaling policies are automatically in force as soon as they&#39;re successfully created. If the fleet&#39;s auto-scaling actions are temporarily suspended, the new policy will be in force once the fleet actions are restarted.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn put_scaling_policy(
         &self,
         input: PutScalingPolicyInput,
     ) -> RusotoFuture<PutScalingPolicyOutput, PutScalingPolicyError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.PutScalingPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutScalingPolicyOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutScalingPolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_scaling_policy(input)
     }
-
-    /// <p><p>Retrieves a fresh set of credentials for use when uploading a new set of game build files to Amazon GameLift&#39;s Amazon S3. This is done as part of the build creation process; see <a>CreateBuild</a>.</p> <p>To request new credentials, specify the build ID as returned with an initial <code>CreateBuild</code> request. If successful, a new set of credentials are returned, along with the S3 storage location associated with the build ID.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html">Uploading Your Game</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
     fn request_upload_credentials(
         &self,
         input: RequestUploadCredentialsInput,
     ) -> RusotoFuture<RequestUploadCredentialsOutput, RequestUploadCredentialsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.RequestUploadCredentials");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RequestUploadCredentialsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RequestUploadCredentialsError::from_response(response))
-                }))
-            }
-        })
+        (**self).request_upload_credentials(input)
     }
-
-    /// <p><p>Retrieves the fleet ID that a specified alias is currently pointing to.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
     fn resolve_alias(
         &self,
         input: ResolveAliasInput,
     ) -> RusotoFuture<ResolveAliasOutput, ResolveAliasError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.ResolveAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResolveAliasOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResolveAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).resolve_alias(input)
     }
-
-    /// <p><p>Retrieves all active game sessions that match a set of search criteria and sorts them in a specified order. You can search or sort by the following game session attributes:</p> <ul> <li> <p> <b>gameSessionId</b> -- Unique identifier for the game session. You can use either a <code>GameSessionId</code> or <code>GameSessionArn</code> value. </p> </li> <li> <p> <b>gameSessionName</b> -- Name assigned to a game session. This value is set when requesting a new game session with <a>CreateGameSession</a> or updating with <a>UpdateGameSession</a>. Game session names do not need to be unique to a game session.</p> </li> <li> <p> <b>gameSessionProperties</b> -- Custom data defined in a game session&#39;s <code>GameProperty</code> parameter. <code>GameProperty</code> values are stored as key:value pairs; the filter expression must indicate the key and a string to search the data values for. For example, to search for game sessions with custom data containing the key:value pair &quot;gameMode:brawl&quot;, specify the following: <code>gameSessionProperties.gameMode = &quot;brawl&quot;</code>. All custom data values are searched as strings.</p> </li> <li> <p> <b>maximumSessions</b> -- Maximum number of player sessions allowed for a game session. This value is set when requesting a new game session with <a>CreateGameSession</a> or updating with <a>UpdateGameSession</a>.</p> </li> <li> <p> <b>creationTimeMillis</b> -- Value indicating when a game session was created. It is expressed in Unix time as milliseconds.</p> </li> <li> <p> <b>playerSessionCount</b> -- Number of players currently connected to a game session. This value changes rapidly as players join the session or drop out.</p> </li> <li> <p> <b>hasAvailablePlayerSessions</b> -- Boolean value indicating whether a game session has reached its maximum number of players. It is highly recommended that all search requests include this filter attribute to optimize search performance and return only sessions that players can join. </p> </li> </ul> <note> <p>Returned values for <code>playerSessionCount</code> and <code>hasAvailablePlayerSessions</code> change quickly as players join sessions and others drop out. Results should be considered a snapshot in time. Be sure to refresh search results often, and handle sessions that fill up before a player can join. </p> </note> <p>To search or sort, specify either a fleet ID or an alias ID, and provide a search filter expression, a sort expression, or both. If successful, a collection of <a>GameSession</a> objects matching the request is returned. Use the pagination parameters to retrieve results as a set of sequential pages. </p> <p>You can search for game sessions one fleet at a time only. To find game sessions across multiple fleets, you must search each fleet separately and combine the results. This search feature finds only game sessions that are in <code>ACTIVE</code> status. To locate games in statuses other than active, use <a>DescribeGameSessionDetails</a>.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn search_game_sessions(
         &self,
         input: SearchGameSessionsInput,
     ) -> RusotoFuture<SearchGameSessionsOutput, SearchGameSessionsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.SearchGameSessions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchGameSessionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchGameSessionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_game_sessions(input)
     }
-
-    /// <p><p>Resumes activity on a fleet that was suspended with <a>StopFleetActions</a>. Currently, this operation is used to restart a fleet&#39;s auto-scaling activity. </p> <p>To start fleet actions, specify the fleet ID and the type of actions to restart. When auto-scaling fleet actions are restarted, Amazon GameLift once again initiates scaling events as triggered by the fleet&#39;s scaling policies. If actions on the fleet were never stopped, this operation will have no effect. You can view a fleet&#39;s stopped actions using <a>DescribeFleetAttributes</a>.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn start_fleet_actions(
         &self,
         input: StartFleetActionsInput,
     ) -> RusotoFuture<StartFleetActionsOutput, StartFleetActionsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.StartFleetActions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartFleetActionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartFleetActionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_fleet_actions(input)
     }
-
-    /// <p><p>Places a request for a new game session in a queue (see <a>CreateGameSessionQueue</a>). When processing a placement request, Amazon GameLift searches for available resources on the queue&#39;s destinations, scanning each until it finds resources or the placement request times out.</p> <p>A game session placement request can also request player sessions. When a new game session is successfully created, Amazon GameLift creates a player session for each player included in the request.</p> <p>When placing a game session, by default Amazon GameLift tries each fleet in the order they are listed in the queue configuration. Ideally, a queue&#39;s destinations are listed in preference order.</p> <p>Alternatively, when requesting a game session with players, you can also provide latency data for each player in relevant regions. Latency data indicates the performance lag a player experiences when connected to a fleet in the region. Amazon GameLift uses latency data to reorder the list of destinations to place the game session in a region with minimal lag. If latency data is provided for multiple players, Amazon GameLift calculates each region&#39;s average lag for all players and reorders to get the best game play across all players. </p> <p>To place a new game session request, specify the following:</p> <ul> <li> <p>The queue name and a set of game session properties and settings</p> </li> <li> <p>A unique ID (such as a UUID) for the placement. You use this ID to track the status of the placement request</p> </li> <li> <p>(Optional) A set of player data and a unique player ID for each player that you are joining to the new game session (player data is optional, but if you include it, you must also provide a unique ID for each player)</p> </li> <li> <p>Latency data for all players (if you want to optimize game play for the players)</p> </li> </ul> <p>If successful, a new game session placement is created.</p> <p>To track the status of a placement request, call <a>DescribeGameSessionPlacement</a> and check the request&#39;s status. If the status is <code>FULFILLED</code>, a new game session has been created and a game session ARN and region are referenced. If the placement request times out, you can resubmit the request or retry it with a different queue. </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn start_game_session_placement(
         &self,
         input: StartGameSessionPlacementInput,
     ) -> RusotoFuture<StartGameSessionPlacementOutput, StartGameSessionPlacementError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.StartGameSessionPlacement");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartGameSessionPlacementOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartGameSessionPlacementError::from_response(response))
-                }))
-            }
-        })
+        (**self).start_game_session_placement(input)
     }
-
-    /// <p><p>Finds new players to fill open slots in an existing game session. This operation can be used to add players to matched games that start with fewer than the maximum number of players or to replace players when they drop out. By backfilling with the same matchmaker used to create the original match, you ensure that new players meet the match criteria and maintain a consistent experience throughout the game session. You can backfill a match anytime after a game session has been created. </p> <p>To request a match backfill, specify a unique ticket ID, the existing game session&#39;s ARN, a matchmaking configuration, and a set of data that describes all current players in the game session. If successful, a match backfill ticket is created and returned with status set to QUEUED. The ticket is placed in the matchmaker&#39;s ticket pool and processed. Track the status of the ticket to respond as needed. </p> <p>The process of finding backfill matches is essentially identical to the initial matchmaking process. The matchmaker searches the pool and groups tickets together to form potential matches, allowing only one backfill ticket per potential match. Once the a match is formed, the matchmaker creates player sessions for the new players. All tickets in the match are updated with the game session&#39;s connection information, and the <a>GameSession</a> object is updated to include matchmaker data on the new players. For more detail on how match backfill requests are processed, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How Amazon GameLift FlexMatch Works</a>. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-backfill.html"> Backfill Existing Games with FlexMatch</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How GameLift FlexMatch Works</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
     fn start_match_backfill(
         &self,
         input: StartMatchBackfillInput,
     ) -> RusotoFuture<StartMatchBackfillOutput, StartMatchBackfillError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.StartMatchBackfill");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartMatchBackfillOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartMatchBackfillError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_match_backfill(input)
     }
-
-    /// <p><p>Uses FlexMatch to create a game match for a group of players based on custom matchmaking rules, and starts a new game for the matched players. Each matchmaking request specifies the type of match to build (team configuration, rules for an acceptable match, etc.). The request also specifies the players to find a match for and where to host the new game session for optimal performance. A matchmaking request might start with a single player or a group of players who want to play together. FlexMatch finds additional players as needed to fill the match. Match type, rules, and the queue used to place a new game session are defined in a <code>MatchmakingConfiguration</code>. </p> <p>To start matchmaking, provide a unique ticket ID, specify a matchmaking configuration, and include the players to be matched. You must also include a set of player attributes relevant for the matchmaking configuration. If successful, a matchmaking ticket is returned with status set to <code>QUEUED</code>. Track the status of the ticket to respond as needed and acquire game session connection information for successfully completed matches.</p> <p> <b>Tracking ticket status</b> -- A couple of options are available for tracking the status of matchmaking requests: </p> <ul> <li> <p>Polling -- Call <code>DescribeMatchmaking</code>. This operation returns the full ticket object, including current status and (for completed tickets) game session connection info. We recommend polling no more than once every 10 seconds.</p> </li> <li> <p>Notifications -- Get event notifications for changes in ticket status using Amazon Simple Notification Service (SNS). Notifications are easy to set up (see <a>CreateMatchmakingConfiguration</a>) and typically deliver match status changes faster and more efficiently than polling. We recommend that you use polling to back up to notifications (since delivery is not guaranteed) and call <code>DescribeMatchmaking</code> only when notifications are not received within 30 seconds.</p> </li> </ul> <p> <b>Processing a matchmaking request</b> -- FlexMatch handles a matchmaking request as follows: </p> <ol> <li> <p>Your client code submits a <code>StartMatchmaking</code> request for one or more players and tracks the status of the request ticket. </p> </li> <li> <p>FlexMatch uses this ticket and others in process to build an acceptable match. When a potential match is identified, all tickets in the proposed match are advanced to the next status. </p> </li> <li> <p>If the match requires player acceptance (set in the matchmaking configuration), the tickets move into status <code>REQUIRES_ACCEPTANCE</code>. This status triggers your client code to solicit acceptance from all players in every ticket involved in the match, and then call <a>AcceptMatch</a> for each player. If any player rejects or fails to accept the match before a specified timeout, the proposed match is dropped (see <code>AcceptMatch</code> for more details).</p> </li> <li> <p>Once a match is proposed and accepted, the matchmaking tickets move into status <code>PLACING</code>. FlexMatch locates resources for a new game session using the game session queue (set in the matchmaking configuration) and creates the game session based on the match data. </p> </li> <li> <p>When the match is successfully placed, the matchmaking tickets move into <code>COMPLETED</code> status. Connection information (including game session endpoint and player session) is added to the matchmaking tickets. Matched players can use the connection information to join the game. </p> </li> </ol> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Set Up FlexMatch Event Notification</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-tasks.html"> FlexMatch Integration Roadmap</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How GameLift FlexMatch Works</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
     fn start_matchmaking(
         &self,
         input: StartMatchmakingInput,
     ) -> RusotoFuture<StartMatchmakingOutput, StartMatchmakingError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.StartMatchmaking");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartMatchmakingOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartMatchmakingError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_matchmaking(input)
     }
-
-    /// <p><p>Suspends activity on a fleet. Currently, this operation is used to stop a fleet&#39;s auto-scaling activity. It is used to temporarily stop scaling events triggered by the fleet&#39;s scaling policies. The policies can be retained and auto-scaling activity can be restarted using <a>StartFleetActions</a>. You can view a fleet&#39;s stopped actions using <a>DescribeFleetAttributes</a>.</p> <p>To stop fleet actions, specify the fleet ID and the type of actions to suspend. When auto-scaling fleet actions are stopped, Amazon GameLift no longer initiates scaling events except to maintain the fleet&#39;s desired instances setting (<a>FleetCapacity</a>. Changes to the fleet&#39;s capacity must be done manually using <a>UpdateFleetCapacity</a>. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn stop_fleet_actions(
         &self,
         input: StopFleetActionsInput,
     ) -> RusotoFuture<StopFleetActionsOutput, StopFleetActionsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.StopFleetActions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopFleetActionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopFleetActionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).stop_fleet_actions(input)
     }
-
-    /// <p><p>Cancels a game session placement that is in <code>PENDING</code> status. To stop a placement, provide the placement ID values. If successful, the placement is moved to <code>CANCELLED</code> status.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn stop_game_session_placement(
         &self,
         input: StopGameSessionPlacementInput,
     ) -> RusotoFuture<StopGameSessionPlacementOutput, StopGameSessionPlacementError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.StopGameSessionPlacement");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopGameSessionPlacementOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopGameSessionPlacementError::from_response(response))
-                }))
-            }
-        })
+        (**self).stop_game_session_placement(input)
     }
-
-    /// <p><p>Cancels a matchmaking ticket or match backfill ticket that is currently being processed. To stop the matchmaking operation, specify the ticket ID. If successful, work on the ticket is stopped, and the ticket status is changed to <code>CANCELLED</code>.</p> <p>This call is also used to turn off automatic backfill for an individual game session. This is for game sessions that are created with a matchmaking configuration that has automatic backfill enabled. The ticket ID is included in the <code>MatchmakerData</code> of an updated game session object, which is provided to the game server.</p> <note> <p>If the action is successful, the service sends back an empty JSON struct with the HTTP 200 response (not an empty HTTP body).</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
     fn stop_matchmaking(
         &self,
         input: StopMatchmakingInput,
     ) -> RusotoFuture<StopMatchmakingOutput, StopMatchmakingError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.StopMatchmaking");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopMatchmakingOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopMatchmakingError::from_response(response))),
-                )
-            }
-        })
+        (**self).stop_matchmaking(input)
+    }
+    fn update_alias(
+        &self,
+        input: UpdateAliasInput,
+    ) -> RusotoFuture<UpdateAliasOutput, UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_build(
+        &self,
+        input: UpdateBuildInput,
+    ) -> RusotoFuture<UpdateBuildOutput, UpdateBuildError> {
+        (**self).update_build(input)
+    }
+    fn update_fleet_attributes(
+        &self,
+        input: UpdateFleetAttributesInput,
+    ) -> RusotoFuture<UpdateFleetAttributesOutput, UpdateFleetAttributesError> {
+        (**self).update_fleet_attributes(input)
+    }
+    fn update_fleet_capacity(
+        &self,
+        input: UpdateFleetCapacityInput,
+    ) -> RusotoFuture<UpdateFleetCapacityOutput, UpdateFleetCapacityError> {
+        (**self).update_fleet_capacity(input)
+    }
+    fn update_fleet_port_settings(
+        &self,
+        input: UpdateFleetPortSettingsInput,
+    ) -> RusotoFuture<UpdateFleetPortSettingsOutput, UpdateFleetPortSettingsError> {
+        (**self).update_fleet_port_settings(input)
+    }
+    fn update_game_session(
+        &self,
+        input: UpdateGameSessionInput,
+    ) -> RusotoFuture<UpdateGameSessionOutput, UpdateGameSessionError> {
+        (**self).update_game_session(input)
+    }
+    fn update_game_session_queue(
+        &self,
+        input: UpdateGameSessionQueueInput,
+    ) -> RusotoFuture<UpdateGameSessionQueueOutput, UpdateGameSessionQueueError> {
+        (**self).update_game_session_queue(input)
+    }
+    fn update_matchmaking_configuration(
+        &self,
+        input: UpdateMatchmakingConfigurationInput,
+    ) -> RusotoFuture<UpdateMatchmakingConfigurationOutput, UpdateMatchmakingConfigurationError>
+    {
+        (**self).update_matchmaking_configuration(input)
+    }
+    fn update_runtime_configuration(
+        &self,
+        input: UpdateRuntimeConfigurationInput,
+    ) -> RusotoFuture<UpdateRuntimeConfigurationOutput, UpdateRuntimeConfigurationError> {
+        (**self).update_runtime_configuration(input)
+    }
+    fn update_script(
+        &self,
+        input: UpdateScriptInput,
+    ) -> RusotoFuture<UpdateScriptOutput, UpdateScriptError> {
+        (**self).update_script(input)
+    }
+    fn validate_matchmaking_rule_set(
+        &self,
+        input: ValidateMatchmakingRuleSetInput,
+    ) -> RusotoFuture<ValidateMatchmakingRuleSetOutput, ValidateMatchmakingRuleSetError> {
+        (**self).validate_matchmaking_rule_set(input)
+    }
+}
+impl<T: GameLift + ?Sized> GameLift for Box<T> {
+    fn accept_match(
+        &self,
+        input: AcceptMatchInput,
+    ) -> RusotoFuture<AcceptMatchOutput, AcceptMatchError> {
+        (**self).accept_match(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasInput,
+    ) -> RusotoFuture<CreateAliasOutput, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_build(
+        &self,
+        input: CreateBuildInput,
+    ) -> RusotoFuture<CreateBuildOutput, CreateBuildError> {
+        (**self).create_build(input)
+    }
+    fn create_fleet(
+        &self,
+        input: CreateFleetInput,
+    ) -> RusotoFuture<CreateFleetOutput, CreateFleetError> {
+        (**self).create_fleet(input)
+    }
+    fn create_game_session(
+        &self,
+        input: CreateGameSessionInput,
+    ) -> RusotoFuture<CreateGameSessionOutput, CreateGameSessionError> {
+        (**self).create_game_session(input)
+    }
+    fn create_game_session_queue(
+        &self,
+        input: CreateGameSessionQueueInput,
+    ) -> RusotoFuture<CreateGameSessionQueueOutput, CreateGameSessionQueueError> {
+        (**self).create_game_session_queue(input)
+    }
+    fn create_matchmaking_configuration(
+        &self,
+        input: CreateMatchmakingConfigurationInput,
+    ) -> RusotoFuture<CreateMatchmakingConfigurationOutput, CreateMatchmakingConfigurationError>
+    {
+        (**self).create_matchmaking_configuration(input)
+    }
+    fn create_matchmaking_rule_set(
+        &self,
+        input: CreateMatchmakingRuleSetInput,
+    ) -> RusotoFuture<CreateMatchmakingRuleSetOutput, CreateMatchmakingRuleSetError> {
+        (**self).create_matchmaking_rule_set(input)
+    }
+    fn create_player_session(
+        &self,
+        input: CreatePlayerSessionInput,
+    ) -> RusotoFuture<CreatePlayerSessionOutput, CreatePlayerSessionError> {
+        (**self).create_player_session(input)
+    }
+    fn create_player_sessions(
+        &self,
+        input: CreatePlayerSessionsInput,
+    ) -> RusotoFuture<CreatePlayerSessionsOutput, CreatePlayerSessionsError> {
+        (**self).create_player_sessions(input)
+    }
+    fn create_script(
+        &self,
+        input: CreateScriptInput,
+    ) -> RusotoFuture<CreateScriptOutput, CreateScriptError> {
+        (**self).create_script(input)
+    }
+    fn create_vpc_peering_authorization(
+        &self,
+        input: CreateVpcPeeringAuthorizationInput,
+    ) -> RusotoFuture<CreateVpcPeeringAuthorizationOutput, CreateVpcPeeringAuthorizationError> {
+        (**self).create_vpc_peering_authorization(input)
+    }
+    fn create_vpc_peering_connection(
+        &self,
+        input: CreateVpcPeeringConnectionInput,
+    ) -> RusotoFuture<CreateVpcPeeringConnectionOutput, CreateVpcPeeringConnectionError> {
+        (**self).create_vpc_peering_connection(input)
+    }
+    fn delete_alias(&self, input: DeleteAliasInput) -> RusotoFuture<(), DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_build(&self, input: DeleteBuildInput) -> RusotoFuture<(), DeleteBuildError> {
+        (**self).delete_build(input)
+    }
+    fn delete_fleet(&self, input: DeleteFleetInput) -> RusotoFuture<(), DeleteFleetError> {
+        (**self).delete_fleet(input)
+    }
+    fn delete_game_session_queue(
+        &self,
+        input: DeleteGameSessionQueueInput,
+    ) -> RusotoFuture<DeleteGameSessionQueueOutput, DeleteGameSessionQueueError> {
+        (**self).delete_game_session_queue(input)
+    }
+    fn delete_matchmaking_configuration(
+        &self,
+        input: DeleteMatchmakingConfigurationInput,
+    ) -> RusotoFuture<DeleteMatchmakingConfigurationOutput, DeleteMatchmakingConfigurationError>
+    {
+        (**self).delete_matchmaking_configuration(input)
+    }
+    fn delete_matchmaking_rule_set(
+        &self,
+        input: DeleteMatchmakingRuleSetInput,
+    ) -> RusotoFuture<DeleteMatchmakingRuleSetOutput, DeleteMatchmakingRuleSetError> {
+        (**self).delete_matchmaking_rule_set(input)
+    }
+    fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyInput,
+    ) -> RusotoFuture<(), DeleteScalingPolicyError> {
+        (**self).delete_scaling_policy(input)
+    }
+    fn delete_script(&self, input: DeleteScriptInput) -> RusotoFuture<(), DeleteScriptError> {
+        (**self).delete_script(input)
+    }
+    fn delete_vpc_peering_authorization(
+        &self,
+        input: DeleteVpcPeeringAuthorizationInput,
+    ) -> RusotoFuture<DeleteVpcPeeringAuthorizationOutput, DeleteVpcPeeringAuthorizationError> {
+        (**self).delete_vpc_peering_authorization(input)
+    }
+    fn delete_vpc_peering_connection(
+        &self,
+        input: DeleteVpcPeeringConnectionInput,
+    ) -> RusotoFuture<DeleteVpcPeeringConnectionOutput, DeleteVpcPeeringConnectionError> {
+        (**self).delete_vpc_peering_connection(input)
+    }
+    fn describe_alias(
+        &self,
+        input: DescribeAliasInput,
+    ) -> RusotoFuture<DescribeAliasOutput, DescribeAliasError> {
+        (**self).describe_alias(input)
+    }
+    fn describe_build(
+        &self,
+        input: DescribeBuildInput,
+    ) -> RusotoFuture<DescribeBuildOutput, DescribeBuildError> {
+        (**self).describe_build(input)
+    }
+    fn describe_ec2_instance_limits(
+        &self,
+        input: DescribeEC2InstanceLimitsInput,
+    ) -> RusotoFuture<DescribeEC2InstanceLimitsOutput, DescribeEC2InstanceLimitsError> {
+        (**self).describe_ec2_instance_limits(input)
+    }
+    fn describe_fleet_attributes(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> RusotoFuture<DescribeFleetAttributesOutput, DescribeFleetAttributesError> {
+        (**self).describe_fleet_attributes(input)
+    }
+    fn describe_fleet_capacity(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> RusotoFuture<DescribeFleetCapacityOutput, DescribeFleetCapacityError> {
+        (**self).describe_fleet_capacity(input)
+    }
+    fn describe_fleet_events(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> RusotoFuture<DescribeFleetEventsOutput, DescribeFleetEventsError> {
+        (**self).describe_fleet_events(input)
+    }
+    fn describe_fleet_port_settings(
+        &self,
+        input: DescribeFleetPortSettingsInput,
+    ) -> RusotoFuture<DescribeFleetPortSettingsOutput, DescribeFleetPortSettingsError> {
+        (**self).describe_fleet_port_settings(input)
+    }
+    fn describe_fleet_utilization(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> RusotoFuture<DescribeFleetUtilizationOutput, DescribeFleetUtilizationError> {
+        (**self).describe_fleet_utilization(input)
+    }
+    fn describe_game_session_details(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> RusotoFuture<DescribeGameSessionDetailsOutput, DescribeGameSessionDetailsError> {
+        (**self).describe_game_session_details(input)
+    }
+    fn describe_game_session_placement(
+        &self,
+        input: DescribeGameSessionPlacementInput,
+    ) -> RusotoFuture<DescribeGameSessionPlacementOutput, DescribeGameSessionPlacementError> {
+        (**self).describe_game_session_placement(input)
+    }
+    fn describe_game_session_queues(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> RusotoFuture<DescribeGameSessionQueuesOutput, DescribeGameSessionQueuesError> {
+        (**self).describe_game_session_queues(input)
+    }
+    fn describe_game_sessions(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> RusotoFuture<DescribeGameSessionsOutput, DescribeGameSessionsError> {
+        (**self).describe_game_sessions(input)
+    }
+    fn describe_instances(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> RusotoFuture<DescribeInstancesOutput, DescribeInstancesError> {
+        (**self).describe_instances(input)
+    }
+    fn describe_matchmaking(
+        &self,
+        input: DescribeMatchmakingInput,
+    ) -> RusotoFuture<DescribeMatchmakingOutput, DescribeMatchmakingError> {
+        (**self).describe_matchmaking(input)
+    }
+    fn describe_matchmaking_configurations(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> RusotoFuture<DescribeMatchmakingConfigurationsOutput, DescribeMatchmakingConfigurationsError>
+    {
+        (**self).describe_matchmaking_configurations(input)
+    }
+    fn describe_matchmaking_rule_sets(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> RusotoFuture<DescribeMatchmakingRuleSetsOutput, DescribeMatchmakingRuleSetsError> {
+        (**self).describe_matchmaking_rule_sets(input)
+    }
+    fn describe_player_sessions(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> RusotoFuture<DescribePlayerSessionsOutput, DescribePlayerSessionsError> {
+        (**self).describe_player_sessions(input)
+    }
+    fn describe_runtime_configuration(
+        &self,
+        input: DescribeRuntimeConfigurationInput,
+    ) -> RusotoFuture<DescribeRuntimeConfigurationOutput, DescribeRuntimeConfigurationError> {
+        (**self).describe_runtime_configuration(input)
+    }
+    fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> RusotoFuture<DescribeScalingPoliciesOutput, DescribeScalingPoliciesError> {
+        (**self).describe_scaling_policies(input)
+    }
+    fn describe_script(
+        &self,
+        input: DescribeScriptInput,
+    ) -> RusotoFuture<DescribeScriptOutput, DescribeScriptError> {
+        (**self).describe_script(input)
+    }
+    fn describe_vpc_peering_authorizations(
+        &self,
+    ) -> RusotoFuture<DescribeVpcPeeringAuthorizationsOutput, DescribeVpcPeeringAuthorizationsError>
+    {
+        (**self).describe_vpc_peering_authorizations()
+    }
+    fn describe_vpc_peering_connections(
+        &self,
+        input: DescribeVpcPeeringConnectionsInput,
+    ) -> RusotoFuture<DescribeVpcPeeringConnectionsOutput, DescribeVpcPeeringConnectionsError> {
+        (**self).describe_vpc_peering_connections(input)
+    }
+    fn get_game_session_log_url(
+        &self,
+        input: GetGameSessionLogUrlInput,
+    ) -> RusotoFuture<GetGameSessionLogUrlOutput, GetGameSessionLogUrlError> {
+        (**self).get_game_session_log_url(input)
+    }
+    fn get_instance_access(
+        &self,
+        input: GetInstanceAccessInput,
+    ) -> RusotoFuture<GetInstanceAccessOutput, GetInstanceAccessError> {
+        (**self).get_instance_access(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesInput,
+    ) -> RusotoFuture<ListAliasesOutput, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
+        (**self).list_builds(input)
+    }
+    fn list_fleets(
+        &self,
+        input: ListFleetsInput,
+    ) -> RusotoFuture<ListFleetsOutput, ListFleetsError> {
+        (**self).list_fleets(input)
+    }
+    fn list_scripts(
+        &self,
+        input: ListScriptsInput,
+    ) -> RusotoFuture<ListScriptsOutput, ListScriptsError> {
+        (**self).list_scripts(input)
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyInput,
+    ) -> RusotoFuture<PutScalingPolicyOutput, PutScalingPolicyError> {
+        (**self).put_scaling_policy(input)
+    }
+    fn request_upload_credentials(
+        &self,
+        input: RequestUploadCredentialsInput,
+    ) -> RusotoFuture<RequestUploadCredentialsOutput, RequestUploadCredentialsError> {
+        (**self).request_upload_credentials(input)
+    }
+    fn resolve_alias(
+        &self,
+        input: ResolveAliasInput,
+    ) -> RusotoFuture<ResolveAliasOutput, ResolveAliasError> {
+        (**self).resolve_alias(input)
+    }
+    fn search_game_sessions(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> RusotoFuture<SearchGameSessionsOutput, SearchGameSessionsError> {
+        (**self).search_game_sessions(input)
+    }
+    fn start_fleet_actions(
+        &self,
+        input: StartFleetActionsInput,
+    ) -> RusotoFuture<StartFleetActionsOutput, StartFleetActionsError> {
+        (**self).start_fleet_actions(input)
+    }
+    fn start_game_session_placement(
+        &self,
+        input: StartGameSessionPlacementInput,
+    ) -> RusotoFuture<StartGameSessionPlacementOutput, StartGameSessionPlacementError> {
+        (**self).start_game_session_placement(input)
+    }
+    fn start_match_backfill(
+        &self,
+        input: StartMatchBackfillInput,
+    ) -> RusotoFuture<StartMatchBackfillOutput, StartMatchBackfillError> {
+        (**self).start_match_backfill(input)
+    }
+    fn start_matchmaking(
+        &self,
+        input: StartMatchmakingInput,
+    ) -> RusotoFuture<StartMatchmakingOutput, StartMatchmakingError> {
+        (**self).start_matchmaking(input)
+    }
+    fn stop_fleet_actions(
+        &self,
+        input: StopFleetActionsInput,
+    ) -> RusotoFuture<StopFleetActionsOutput, StopFleetActionsError> {
+        (**self).stop_fleet_actions(input)
+    }
+    fn stop_game_session_placement(
+        &self,
+        input: StopGameSessionPlacementInput,
+    ) -> RusotoFuture<StopGameSessionPlacementOutput, StopGameSessionPlacementError> {
+        (**self).stop_game_session_placement(input)
+    }
+    fn stop_matchmaking(
+        &self,
+        input: StopMatchmakingInput,
+    ) -> RusotoFuture<StopMatchmakingOutput, StopMatchmakingError> {
+        (**self).stop_matchmaking(input)
+    }
+    fn update_alias(
+        &self,
+        input: UpdateAliasInput,
+    ) -> RusotoFuture<UpdateAliasOutput, UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_build(
+        &self,
+        input: UpdateBuildInput,
+    ) -> RusotoFuture<UpdateBuildOutput, UpdateBuildError> {
+        (**self).update_build(input)
+    }
+    fn update_fleet_attributes(
+        &self,
+        input: UpdateFleetAttributesInput,
+    ) -> RusotoFuture<UpdateFleetAttributesOutput, UpdateFleetAttributesError> {
+        (**self).update_fleet_attributes(input)
+    }
+    fn update_fleet_capacity(
+        &self,
+        input: UpdateFleetCapacityInput,
+    ) -> RusotoFuture<UpdateFleetCapacityOutput, UpdateFleetCapacityError> {
+        (**self).update_fleet_capacity(input)
+    }
+    fn update_fleet_port_settings(
+        &self,
+        input: UpdateFleetPortSettingsInput,
+    ) -> RusotoFuture<UpdateFleetPortSettingsOutput, UpdateFleetPortSettingsError> {
+        (**self).update_fleet_port_settings(input)
+    }
+    fn update_game_session(
+        &self,
+        input: UpdateGameSessionInput,
+    ) -> RusotoFuture<UpdateGameSessionOutput, UpdateGameSessionError> {
+        (**self).update_game_session(input)
+    }
+    fn update_game_session_queue(
+        &self,
+        input: UpdateGameSessionQueueInput,
+    ) -> RusotoFuture<UpdateGameSessionQueueOutput, UpdateGameSessionQueueError> {
+        (**self).update_game_session_queue(input)
+    }
+    fn update_matchmaking_configuration(
+        &self,
+        input: UpdateMatchmakingConfigurationInput,
+    ) -> RusotoFuture<UpdateMatchmakingConfigurationOutput, UpdateMatchmakingConfigurationError>
+    {
+        (**self).update_matchmaking_configuration(input)
+    }
+    fn update_runtime_configuration(
+        &self,
+        input: UpdateRuntimeConfigurationInput,
+    ) -> RusotoFuture<UpdateRuntimeConfigurationOutput, UpdateRuntimeConfigurationError> {
+        (**self).update_runtime_configuration(input)
+    }
+    fn update_script(
+        &self,
+        input: UpdateScriptInput,
+    ) -> RusotoFuture<UpdateScriptOutput, UpdateScriptError> {
+        (**self).update_script(input)
+    }
+    fn validate_matchmaking_rule_set(
+        &self,
+        input: ValidateMatchmakingRuleSetInput,
+    ) -> RusotoFuture<ValidateMatchmakingRuleSetOutput, ValidateMatchmakingRuleSetError> {
+        (**self).validate_matchmaking_rule_set(input)
+    }
+}
+impl<T: GameLift + ?Sized> GameLift for ::std::sync::Arc<T> {
+    fn accept_match(
+        &self,
+        input: AcceptMatchInput,
+    ) -> RusotoFuture<AcceptMatchOutput, AcceptMatchError> {
+        (**self).accept_match(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasInput,
+    ) -> RusotoFuture<CreateAliasOutput, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_build(
+        &self,
+        input: CreateBuildInput,
+    ) -> RusotoFuture<CreateBuildOutput, CreateBuildError> {
+        (**self).create_build(input)
+    }
+    fn create_fleet(
+        &self,
+        input: CreateFleetInput,
+    ) -> RusotoFuture<CreateFleetOutput, CreateFleetError> {
+        (**self).create_fleet(input)
+    }
+    fn create_game_session(
+        &self,
+        input: CreateGameSessionInput,
+    ) -> RusotoFuture<CreateGameSessionOutput, CreateGameSessionError> {
+        (**self).create_game_session(input)
+    }
+    fn create_game_session_queue(
+        &self,
+        input: CreateGameSessionQueueInput,
+    ) -> RusotoFuture<CreateGameSessionQueueOutput, CreateGameSessionQueueError> {
+        (**self).create_game_session_queue(input)
+    }
+    fn create_matchmaking_configuration(
+        &self,
+        input: CreateMatchmakingConfigurationInput,
+    ) -> RusotoFuture<CreateMatchmakingConfigurationOutput, CreateMatchmakingConfigurationError>
+    {
+        (**self).create_matchmaking_configuration(input)
+    }
+    fn create_matchmaking_rule_set(
+        &self,
+        input: CreateMatchmakingRuleSetInput,
+    ) -> RusotoFuture<CreateMatchmakingRuleSetOutput, CreateMatchmakingRuleSetError> {
+        (**self).create_matchmaking_rule_set(input)
+    }
+    fn create_player_session(
+        &self,
+        input: CreatePlayerSessionInput,
+    ) -> RusotoFuture<CreatePlayerSessionOutput, CreatePlayerSessionError> {
+        (**self).create_player_session(input)
+    }
+    fn create_player_sessions(
+        &self,
+        input: CreatePlayerSessionsInput,
+    ) -> RusotoFuture<CreatePlayerSessionsOutput, CreatePlayerSessionsError> {
+        (**self).create_player_sessions(input)
+    }
+    fn create_script(
+        &self,
+        input: CreateScriptInput,
+    ) -> RusotoFuture<CreateScriptOutput, CreateScriptError> {
+        (**self).create_script(input)
+    }
+    fn create_vpc_peering_authorization(
+        &self,
+        input: CreateVpcPeeringAuthorizationInput,
+    ) -> RusotoFuture<CreateVpcPeeringAuthorizationOutput, CreateVpcPeeringAuthorizationError> {
+        (**self).create_vpc_peering_authorization(input)
+    }
+    fn create_vpc_peering_connection(
+        &self,
+        input: CreateVpcPeeringConnectionInput,
+    ) -> RusotoFuture<CreateVpcPeeringConnectionOutput, CreateVpcPeeringConnectionError> {
+        (**self).create_vpc_peering_connection(input)
+    }
+    fn delete_alias(&self, input: DeleteAliasInput) -> RusotoFuture<(), DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_build(&self, input: DeleteBuildInput) -> RusotoFuture<(), DeleteBuildError> {
+        (**self).delete_build(input)
+    }
+    fn delete_fleet(&self, input: DeleteFleetInput) -> RusotoFuture<(), DeleteFleetError> {
+        (**self).delete_fleet(input)
+    }
+    fn delete_game_session_queue(
+        &self,
+        input: DeleteGameSessionQueueInput,
+    ) -> RusotoFuture<DeleteGameSessionQueueOutput, DeleteGameSessionQueueError> {
+        (**self).delete_game_session_queue(input)
+    }
+    fn delete_matchmaking_configuration(
+        &self,
+        input: DeleteMatchmakingConfigurationInput,
+    ) -> RusotoFuture<DeleteMatchmakingConfigurationOutput, DeleteMatchmakingConfigurationError>
+    {
+        (**self).delete_matchmaking_configuration(input)
+    }
+    fn delete_matchmaking_rule_set(
+        &self,
+        input: DeleteMatchmakingRuleSetInput,
+    ) -> RusotoFuture<DeleteMatchmakingRuleSetOutput, DeleteMatchmakingRuleSetError> {
+        (**self).delete_matchmaking_rule_set(input)
+    }
+    fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyInput,
+    ) -> RusotoFuture<(), DeleteScalingPolicyError> {
+        (**self).delete_scaling_policy(input)
+    }
+    fn delete_script(&self, input: DeleteScriptInput) -> RusotoFuture<(), DeleteScriptError> {
+        (**self).delete_script(input)
+    }
+    fn delete_vpc_peering_authorization(
+        &self,
+        input: DeleteVpcPeeringAuthorizationInput,
+    ) -> RusotoFuture<DeleteVpcPeeringAuthorizationOutput, DeleteVpcPeeringAuthorizationError> {
+        (**self).delete_vpc_peering_authorization(input)
+    }
+    fn delete_vpc_peering_connection(
+        &self,
+        input: DeleteVpcPeeringConnectionInput,
+    ) -> RusotoFuture<DeleteVpcPeeringConnectionOutput, DeleteVpcPeeringConnectionError> {
+        (**self).delete_vpc_peering_connection(input)
+    }
+    fn describe_alias(
+        &self,
+        input: DescribeAliasInput,
+    ) -> RusotoFuture<DescribeAliasOutput, DescribeAliasError> {
+        (**self).describe_alias(input)
+    }
+    fn describe_build(
+        &self,
+        input: DescribeBuildInput,
+    ) -> RusotoFuture<DescribeBuildOutput, DescribeBuildError> {
+        (**self).describe_build(input)
+    }
+    fn describe_ec2_instance_limits(
+        &self,
+        input: DescribeEC2InstanceLimitsInput,
+    ) -> RusotoFuture<DescribeEC2InstanceLimitsOutput, DescribeEC2InstanceLimitsError> {
+        (**self).describe_ec2_instance_limits(input)
+    }
+    fn describe_fleet_attributes(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> RusotoFuture<DescribeFleetAttributesOutput, DescribeFleetAttributesError> {
+        (**self).describe_fleet_attributes(input)
+    }
+    fn describe_fleet_capacity(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> RusotoFuture<DescribeFleetCapacityOutput, DescribeFleetCapacityError> {
+        (**self).describe_fleet_capacity(input)
+    }
+    fn describe_fleet_events(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> RusotoFuture<DescribeFleetEventsOutput, DescribeFleetEventsError> {
+        (**self).describe_fleet_events(input)
+    }
+    fn describe_fleet_port_settings(
+        &self,
+        input: DescribeFleetPortSettingsInput,
+    ) -> RusotoFuture<DescribeFleetPortSettingsOutput, DescribeFleetPortSettingsError> {
+        (**self).describe_fleet_port_settings(input)
+    }
+    fn describe_fleet_utilization(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> RusotoFuture<DescribeFleetUtilizationOutput, DescribeFleetUtilizationError> {
+        (**self).describe_fleet_utilization(input)
+    }
+    fn describe_game_session_details(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> RusotoFuture<DescribeGameSessionDetailsOutput, DescribeGameSessionDetailsError> {
+        (**self).describe_game_session_details(input)
+    }
+    fn describe_game_session_placement(
+        &self,
+        input: DescribeGameSessionPlacementInput,
+    ) -> RusotoFuture<DescribeGameSessionPlacementOutput, DescribeGameSessionPlacementError> {
+        (**self).describe_game_session_placement(input)
+    }
+    fn describe_game_session_queues(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> RusotoFuture<DescribeGameSessionQueuesOutput, DescribeGameSessionQueuesError> {
+        (**self).describe_game_session_queues(input)
+    }
+    fn describe_game_sessions(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> RusotoFuture<DescribeGameSessionsOutput, DescribeGameSessionsError> {
+        (**self).describe_game_sessions(input)
+    }
+    fn describe_instances(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> RusotoFuture<DescribeInstancesOutput, DescribeInstancesError> {
+        (**self).describe_instances(input)
+    }
+    fn describe_matchmaking(
+        &self,
+        input: DescribeMatchmakingInput,
+    ) -> RusotoFuture<DescribeMatchmakingOutput, DescribeMatchmakingError> {
+        (**self).describe_matchmaking(input)
+    }
+    fn describe_matchmaking_configurations(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> RusotoFuture<DescribeMatchmakingConfigurationsOutput, DescribeMatchmakingConfigurationsError>
+    {
+        (**self).describe_matchmaking_configurations(input)
+    }
+    fn describe_matchmaking_rule_sets(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> RusotoFuture<DescribeMatchmakingRuleSetsOutput, DescribeMatchmakingRuleSetsError> {
+        (**self).describe_matchmaking_rule_sets(input)
+    }
+    fn describe_player_sessions(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> RusotoFuture<DescribePlayerSessionsOutput, DescribePlayerSessionsError> {
+        (**self).describe_player_sessions(input)
+    }
+    fn describe_runtime_configuration(
+        &self,
+        input: DescribeRuntimeConfigurationInput,
+    ) -> RusotoFuture<DescribeRuntimeConfigurationOutput, DescribeRuntimeConfigurationError> {
+        (**self).describe_runtime_configuration(input)
+    }
+    fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> RusotoFuture<DescribeScalingPoliciesOutput, DescribeScalingPoliciesError> {
+        (**self).describe_scaling_policies(input)
+    }
+    fn describe_script(
+        &self,
+        input: DescribeScriptInput,
+    ) -> RusotoFuture<DescribeScriptOutput, DescribeScriptError> {
+        (**self).describe_script(input)
+    }
+    fn describe_vpc_peering_authorizations(
+        &self,
+    ) -> RusotoFuture<DescribeVpcPeeringAuthorizationsOutput, DescribeVpcPeeringAuthorizationsError>
+    {
+        (**self).describe_vpc_peering_authorizations()
+    }
+    fn describe_vpc_peering_connections(
+        &self,
+        input: DescribeVpcPeeringConnectionsInput,
+    ) -> RusotoFuture<DescribeVpcPeeringConnectionsOutput, DescribeVpcPeeringConnectionsError> {
+        (**self).describe_vpc_peering_connections(input)
+    }
+    fn get_game_session_log_url(
+        &self,
+        input: GetGameSessionLogUrlInput,
+    ) -> RusotoFuture<GetGameSessionLogUrlOutput, GetGameSessionLogUrlError> {
+        (**self).get_game_session_log_url(input)
+    }
+    fn get_instance_access(
+        &self,
+        input: GetInstanceAccessInput,
+    ) -> RusotoFuture<GetInstanceAccessOutput, GetInstanceAccessError> {
+        (**self).get_instance_access(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesInput,
+    ) -> RusotoFuture<ListAliasesOutput, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
+        (**self).list_builds(input)
+    }
+    fn list_fleets(
+        &self,
+        input: ListFleetsInput,
+    ) -> RusotoFuture<ListFleetsOutput, ListFleetsError> {
+        (**self).list_fleets(input)
+    }
+    fn list_scripts(
+        &self,
+        input: ListScriptsInput,
+    ) -> RusotoFuture<ListScriptsOutput, ListScriptsError> {
+        (**self).list_scripts(input)
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyInput,
+    ) -> RusotoFuture<PutScalingPolicyOutput, PutScalingPolicyError> {
+        (**self).put_scaling_policy(input)
+    }
+    fn request_upload_credentials(
+        &self,
+        input: RequestUploadCredentialsInput,
+    ) -> RusotoFuture<RequestUploadCredentialsOutput, RequestUploadCredentialsError> {
+        (**self).request_upload_credentials(input)
+    }
+    fn resolve_alias(
+        &self,
+        input: ResolveAliasInput,
+    ) -> RusotoFuture<ResolveAliasOutput, ResolveAliasError> {
+        (**self).resolve_alias(input)
+    }
+    fn search_game_sessions(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> RusotoFuture<SearchGameSessionsOutput, SearchGameSessionsError> {
+        (**self).search_game_sessions(input)
+    }
+    fn start_fleet_actions(
+        &self,
+        input: StartFleetActionsInput,
+    ) -> RusotoFuture<StartFleetActionsOutput, StartFleetActionsError> {
+        (**self).start_fleet_actions(input)
+    }
+    fn start_game_session_placement(
+        &self,
+        input: StartGameSessionPlacementInput,
+    ) -> RusotoFuture<StartGameSessionPlacementOutput, StartGameSessionPlacementError> {
+        (**self).start_game_session_placement(input)
+    }
+    fn start_match_backfill(
+        &self,
+        input: StartMatchBackfillInput,
+    ) -> RusotoFuture<StartMatchBackfillOutput, StartMatchBackfillError> {
+        (**self).start_match_backfill(input)
+    }
+    fn start_matchmaking(
+        &self,
+        input: StartMatchmakingInput,
+    ) -> RusotoFuture<StartMatchmakingOutput, StartMatchmakingError> {
+        (**self).start_matchmaking(input)
+    }
+    fn stop_fleet_actions(
+        &self,
+        input: StopFleetActionsInput,
+    ) -> RusotoFuture<StopFleetActionsOutput, StopFleetActionsError> {
+        (**self).stop_fleet_actions(input)
+    }
+    fn stop_game_session_placement(
+        &self,
+        input: StopGameSessionPlacementInput,
+    ) -> RusotoFuture<StopGameSessionPlacementOutput, StopGameSessionPlacementError> {
+        (**self).stop_game_session_placement(input)
+    }
+    fn stop_matchmaking(
+        &self,
+        input: StopMatchmakingInput,
+    ) -> RusotoFuture<StopMatchmakingOutput, StopMatchmakingError> {
+        (**self).stop_matchmaking(input)
+    }
+    fn update_alias(
+        &self,
+        input: UpdateAliasInput,
+    ) -> RusotoFuture<UpdateAliasOutput, UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_build(
+        &self,
+        input: UpdateBuildInput,
+    ) -> RusotoFuture<UpdateBuildOutput, UpdateBuildError> {
+        (**self).update_build(input)
+    }
+    fn update_fleet_attributes(
+        &self,
+        input: UpdateFleetAttributesInput,
+    ) -> RusotoFuture<UpdateFleetAttributesOutput, UpdateFleetAttributesError> {
+        (**self).update_fleet_attributes(input)
+    }
+    fn update_fleet_capacity(
+        &self,
+        input: UpdateFleetCapacityInput,
+    ) -> RusotoFuture<UpdateFleetCapacityOutput, UpdateFleetCapacityError> {
+        (**self).update_fleet_capacity(input)
+    }
+    fn update_fleet_port_settings(
+        &self,
+        input: UpdateFleetPortSettingsInput,
+    ) -> RusotoFuture<UpdateFleetPortSettingsOutput, UpdateFleetPortSettingsError> {
+        (**self).update_fleet_port_settings(input)
+    }
+    fn update_game_session(
+        &self,
+        input: UpdateGameSessionInput,
+    ) -> RusotoFuture<UpdateGameSessionOutput, UpdateGameSessionError> {
+        (**self).update_game_session(input)
+    }
+    fn update_game_session_queue(
+        &self,
+        input: UpdateGameSessionQueueInput,
+    ) -> RusotoFuture<UpdateGameSessionQueueOutput, UpdateGameSessionQueueError> {
+        (**self).update_game_session_queue(input)
+    }
+    fn update_matchmaking_configuration(
+        &self,
+        input: UpdateMatchmakingConfigurationInput,
+    ) -> RusotoFuture<UpdateMatchmakingConfigurationOutput, UpdateMatchmakingConfigurationError>
+    {
+        (**self).update_matchmaking_configuration(input)
+    }
+    fn update_runtime_configuration(
+        &self,
+        input: UpdateRuntimeConfigurationInput,
+    ) -> RusotoFuture<UpdateRuntimeConfigurationOutput, UpdateRuntimeConfigurationError> {
+        (**self).update_runtime_configuration(input)
+    }
+    fn update_script(
+        &self,
+        input: UpdateScriptInput,
+    ) -> RusotoFuture<UpdateScriptOutput, UpdateScriptError> {
+        (**self).update_script(input)
+    }
+    fn validate_matchmaking_rule_set(
+        &self,
+        input: ValidateMatchmakingRuleSetInput,
+    ) -> RusotoFuture<ValidateMatchmakingRuleSetOutput, ValidateMatchmakingRuleSetError> {
+        (**self).validate_matchmaking_rule_set(input)
+    }
+}
+/// A client for the Amazon GameLift API.
+#[derive(Clone)]
+pub struct GameLiftClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl GameLiftClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GameLiftClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GameLiftClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> GameLiftClient {
+        GameLiftClient { client, region }
+    }
+}
+
+impl GameLift for GameLiftClient {
+    /// <p><p>Registers a player&#39;s acceptance or rejection of a proposed FlexMatch match. A matchmaking configuration may require player acceptance; if so, then matches built with that configuration cannot be completed unless all players accept the proposed match within a specified time limit. </p> <p>When FlexMatch builds a match, all the matchmaking tickets involved in the proposed match are placed into status <code>REQUIRES_ACCEPTANCE</code>. This is a trigger for your game to get acceptance from all players in the ticket. Acceptances are only valid for tickets when they are in this status; all other acceptances result in an error.</p> <p>To register acceptance, specify the ticket ID, a response, and one or more players. Once all players have registered acceptance, the matchmaking tickets advance to status <code>PLACING</code>, where a new game session is created for the match. </p> <p>If any player rejects the match, or if acceptances are not received before a specified timeout, the proposed match is dropped. The matchmaking tickets are then handled in one of two ways: For tickets where one or more players rejected the match, the ticket status is returned to <code>SEARCHING</code> to find a new match. For tickets where one or more players failed to respond, the ticket status is set to <code>CANCELLED</code>, and processing is terminated. A new matchmaking request for these players can be submitted as needed. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-events.html"> FlexMatch Events Reference</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    fn accept_match(
+        &self,
+        input: AcceptMatchInput,
+    ) -> RusotoFuture<AcceptMatchOutput, AcceptMatchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("AcceptMatch");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.AcceptMatch");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AcceptMatchOutput, _>(response, "AcceptMatch")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AcceptMatchError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates an alias for a fleet. In most situations, you can use an alias ID in place of a fleet ID. By using a fleet alias instead of a specific fleet ID, you can switch gameplay and players to a new fleet without changing your game client or other game components. For example, for games in production, using an alias allows you to seamlessly redirect your player base to a new game server update. </p> <p>Amazon GameLift supports two types of routing strategies for aliases: simple and terminal. A simple alias points to an active fleet. A terminal alias is used to display messaging or link to a URL instead of routing players to an active fleet. For example, you might use a terminal alias when a game version is no longer supported and you want to direct players to an upgrade site. </p> <p>To create a fleet alias, specify an alias name, routing strategy, and optional description. Each simple alias can point to only one fleet, but a fleet can have multiple aliases. If successful, a new alias record is returned, including an alias ID, which you can reference when creating a game session. You can reassign an alias to another fleet by calling <code>UpdateAlias</code>.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    fn create_alias(
+        &self,
+        input: CreateAliasInput,
+    ) -> RusotoFuture<CreateAliasOutput, CreateAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAliasOutput, _>(response, "CreateAlias")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a new Amazon GameLift build record for your game server binary files and points to the location of your game server build files in an Amazon Simple Storage Service (Amazon S3) location. </p> <p>Game server binaries must be combined into a <code>.zip</code> file for use with Amazon GameLift. </p> <important> <p>To create new builds quickly and easily, use the AWS CLI command <b> <a href="https://docs.aws.amazon.com/cli/latest/reference/gamelift/upload-build.html">upload-build</a> </b>. This helper command uploads your build and creates a new build record in one step, and automatically handles the necessary permissions. </p> </important> <p>The <code>CreateBuild</code> operation should be used only when you need to manually upload your build files, as in the following scenarios:</p> <ul> <li> <p>Store a build file in an Amazon S3 bucket under your own AWS account. To use this option, you must first give Amazon GameLift access to that Amazon S3 bucket. To create a new build record using files in your Amazon S3 bucket, call <code>CreateBuild</code> and specify a build name, operating system, and the storage location of your game build.</p> </li> <li> <p>Upload a build file directly to Amazon GameLift&#39;s Amazon S3 account. To use this option, you first call <code>CreateBuild</code> with a build name and operating system. This action creates a new build record and returns an Amazon S3 storage location (bucket and key only) and temporary access credentials. Use the credentials to manually upload your build file to the storage location (see the Amazon S3 topic <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UploadingObjects.html">Uploading Objects</a>). You can upload files to a location only once. </p> </li> </ul> <p>If successful, this operation creates a new build record with a unique build ID and places it in <code>INITIALIZED</code> status. You can use <a>DescribeBuild</a> to check the status of your build. A build must be in <code>READY</code> status before it can be used to create fleets.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html">Uploading Your Game</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-cli-uploading.html#gamelift-build-cli-uploading-create-build"> Create a Build with Files in Amazon S3</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    fn create_build(
+        &self,
+        input: CreateBuildInput,
+    ) -> RusotoFuture<CreateBuildOutput, CreateBuildError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateBuild");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateBuild");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateBuildOutput, _>(response, "CreateBuild")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBuildError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a new fleet to run your game servers. whether they are custom game builds or Realtime Servers with game-specific script. A fleet is a set of Amazon Elastic Compute Cloud (Amazon EC2) instances, each of which can host multiple game sessions. When creating a fleet, you choose the hardware specifications, set some configuration options, and specify the game server to deploy on the new fleet. </p> <p>To create a new fleet, you must provide the following: (1) a fleet name, (2) an EC2 instance type and fleet type (spot or on-demand), (3) the build ID for your game build or script ID if using Realtime Servers, and (4) a run-time configuration, which determines how game servers will run on each instance in the fleet. </p> <note> <p>When creating a Realtime Servers fleet, we recommend using a minimal version of the Realtime script (see this <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-script.html#realtime-script-examples"> working code example </a>). This will make it much easier to troubleshoot any fleet creation issues. Once the fleet is active, you can update your Realtime script as needed.</p> </note> <p>If the <code>CreateFleet</code> call is successful, Amazon GameLift performs the following tasks. You can track the process of a fleet by checking the fleet status or by monitoring fleet creation events:</p> <ul> <li> <p>Creates a fleet record. Status: <code>NEW</code>.</p> </li> <li> <p>Begins writing events to the fleet event log, which can be accessed in the Amazon GameLift console.</p> <p>Sets the fleet&#39;s target capacity to 1 (desired instances), which triggers Amazon GameLift to start one new EC2 instance.</p> </li> <li> <p>Downloads the game build or Realtime script to the new instance and installs it. Statuses: <code>DOWNLOADING</code>, <code>VALIDATING</code>, <code>BUILDING</code>. </p> </li> <li> <p>Starts launching server processes on the instance. If the fleet is configured to run multiple server processes per instance, Amazon GameLift staggers each launch by a few seconds. Status: <code>ACTIVATING</code>.</p> </li> <li> <p>Sets the fleet&#39;s status to <code>ACTIVE</code> as soon as one server process is ready to host a game session.</p> </li> </ul> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-creating-debug.html"> Debug Fleet Creation Issues</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn create_fleet(
+        &self,
+        input: CreateFleetInput,
+    ) -> RusotoFuture<CreateFleetOutput, CreateFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateFleet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateFleet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateFleetOutput, _>(response, "CreateFleet")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a multiplayer game session for players. This action creates a game session record and assigns an available server process in the specified fleet to host the game session. A fleet must have an <code>ACTIVE</code> status before a game session can be created in it.</p> <p>To create a game session, specify either fleet ID or alias ID and indicate a maximum number of players to allow in the game session. You can also provide a name and game-specific properties for this game session. If successful, a <a>GameSession</a> object is returned containing the game session properties and other settings you specified.</p> <p> <b>Idempotency tokens.</b> You can add a token that uniquely identifies game session requests. This is useful for ensuring that game session requests are idempotent. Multiple requests with the same idempotency token are processed only once; subsequent requests return the original result. All response values are the same with the exception of game session status, which may change.</p> <p> <b>Resource creation limits.</b> If you are creating a game session on a fleet with a resource creation limit policy in force, then you must specify a creator ID. Without this ID, Amazon GameLift has no way to evaluate the policy for this new game session request.</p> <p> <b>Player acceptance policy.</b> By default, newly created game sessions are open to new players. You can restrict new player access by using <a>UpdateGameSession</a> to change the game session&#39;s player session creation policy.</p> <p> <b>Game session logs.</b> Logs are retained for all active game sessions for 14 days. To access the logs, call <a>GetGameSessionLogUrl</a> to download the log files.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn create_game_session(
+        &self,
+        input: CreateGameSessionInput,
+    ) -> RusotoFuture<CreateGameSessionOutput, CreateGameSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateGameSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateGameSession");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateGameSessionOutput, _>(
+                    response,
+                    "CreateGameSession",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGameSessionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Establishes a new queue for processing requests to place new game sessions. A queue identifies where new game sessions can be hosted -- by specifying a list of destinations (fleets or aliases) -- and how long requests can wait in the queue before timing out. You can set up a queue to try to place game sessions on fleets in multiple regions. To add placement requests to a queue, call <a>StartGameSessionPlacement</a> and reference the queue name.</p> <p> <b>Destination order.</b> When processing a request for a game session, Amazon GameLift tries each destination in order until it finds one with available resources to host the new game session. A queue&#39;s default order is determined by how destinations are listed. The default order is overridden when a game session placement request provides player latency information. Player latency information enables Amazon GameLift to prioritize destinations where players report the lowest average latency, as a result placing the new game session where the majority of players will have the best possible gameplay experience.</p> <p> <b>Player latency policies.</b> For placement requests containing player latency information, use player latency policies to protect individual players from very high latencies. With a latency cap, even when a destination can deliver a low latency for most players, the game is not placed where any individual player is reporting latency higher than a policy&#39;s maximum. A queue can have multiple latency policies, which are enforced consecutively starting with the policy with the lowest latency cap. Use multiple policies to gradually relax latency controls; for example, you might set a policy with a low latency cap for the first 60 seconds, a second policy with a higher cap for the next 60 seconds, etc. </p> <p>To create a new queue, provide a name, timeout value, a list of destinations and, if desired, a set of latency policies. If successful, a new queue object is returned.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    fn create_game_session_queue(
+        &self,
+        input: CreateGameSessionQueueInput,
+    ) -> RusotoFuture<CreateGameSessionQueueOutput, CreateGameSessionQueueError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateGameSessionQueue");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateGameSessionQueue");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateGameSessionQueueOutput, _>(
+                    response,
+                    "CreateGameSessionQueue",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateGameSessionQueueError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Defines a new matchmaking configuration for use with FlexMatch. A matchmaking configuration sets out guidelines for matching players and getting the matches into games. You can set up multiple matchmaking configurations to handle the scenarios needed for your game. Each matchmaking ticket (<a>StartMatchmaking</a> or <a>StartMatchBackfill</a>) specifies a configuration for the match and provides player attributes to support the configuration being used. </p> <p>To create a matchmaking configuration, at a minimum you must specify the following: configuration name; a rule set that governs how to evaluate players and find acceptable matches; a game session queue to use when placing a new game session for the match; and the maximum time allowed for a matchmaking attempt.</p> <p>There are two ways to track the progress of matchmaking tickets: (1) polling ticket status with <a>DescribeMatchmaking</a>; or (2) receiving notifications with Amazon Simple Notification Service (SNS). To use notifications, you first need to set up an SNS topic to receive the notifications, and provide the topic ARN in the matchmaking configuration. Since notifications promise only &quot;best effort&quot; delivery, we recommend calling <code>DescribeMatchmaking</code> if no notifications are received within 30 seconds.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html"> Design a FlexMatch Matchmaker</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Setting up Notifications for Matchmaking</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    fn create_matchmaking_configuration(
+        &self,
+        input: CreateMatchmakingConfigurationInput,
+    ) -> RusotoFuture<CreateMatchmakingConfigurationOutput, CreateMatchmakingConfigurationError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateMatchmakingConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateMatchmakingConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateMatchmakingConfigurationOutput, _>(
+                    response,
+                    "CreateMatchmakingConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateMatchmakingConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates a new rule set for FlexMatch matchmaking. A rule set describes the type of match to create, such as the number and size of teams, and sets the parameters for acceptable player matches, such as minimum skill level or character type. A rule set is used by a <a>MatchmakingConfiguration</a>. </p> <p>To create a matchmaking rule set, provide unique rule set name and the rule set body in JSON format. Rule sets must be defined in the same region as the matchmaking configuration they are used with.</p> <p>Since matchmaking rule sets cannot be edited, it is a good idea to check the rule set syntax using <a>ValidateMatchmakingRuleSet</a> before creating a new rule set.</p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html">Design a Matchmaker</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-intro.html">Matchmaking with FlexMatch</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    fn create_matchmaking_rule_set(
+        &self,
+        input: CreateMatchmakingRuleSetInput,
+    ) -> RusotoFuture<CreateMatchmakingRuleSetOutput, CreateMatchmakingRuleSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateMatchmakingRuleSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateMatchmakingRuleSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateMatchmakingRuleSetOutput, _>(
+                    response,
+                    "CreateMatchmakingRuleSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateMatchmakingRuleSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Reserves an open player slot in an active game session. Before a player can be added, a game session must have an <code>ACTIVE</code> status, have a creation policy of <code>ALLOW_ALL</code>, and have an open player slot. To add a group of players to a game session, use <a>CreatePlayerSessions</a>. When the player connects to the game server and references a player session ID, the game server contacts the Amazon GameLift service to validate the player reservation and accept the player.</p> <p>To create a player session, specify a game session ID, player ID, and optionally a string of player data. If successful, a slot is reserved in the game session for the player and a new <a>PlayerSession</a> object is returned. Player sessions cannot be updated. </p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn create_player_session(
+        &self,
+        input: CreatePlayerSessionInput,
+    ) -> RusotoFuture<CreatePlayerSessionOutput, CreatePlayerSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreatePlayerSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreatePlayerSession");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePlayerSessionOutput, _>(
+                    response,
+                    "CreatePlayerSession",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreatePlayerSessionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Reserves open slots in a game session for a group of players. Before players can be added, a game session must have an <code>ACTIVE</code> status, have a creation policy of <code>ALLOW_ALL</code>, and have an open player slot. To add a single player to a game session, use <a>CreatePlayerSession</a>. When a player connects to the game server and references a player session ID, the game server contacts the Amazon GameLift service to validate the player reservation and accept the player.</p> <p>To create player sessions, specify a game session ID, a list of player IDs, and optionally a set of player data strings. If successful, a slot is reserved in the game session for each player and a set of new <a>PlayerSession</a> objects is returned. Player sessions cannot be updated.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn create_player_sessions(
+        &self,
+        input: CreatePlayerSessionsInput,
+    ) -> RusotoFuture<CreatePlayerSessionsOutput, CreatePlayerSessionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreatePlayerSessions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreatePlayerSessions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePlayerSessionsOutput, _>(
+                    response,
+                    "CreatePlayerSessions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreatePlayerSessionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a new script record for your Realtime Servers script. Realtime scripts are JavaScript that provide configuration settings and optional custom game logic for your game. The script is deployed when you create a Realtime Servers fleet to host your game sessions. Script logic is executed during an active game session. </p> <p>To create a new script record, specify a script name and provide the script file(s). The script files and all dependencies must be zipped into a single file. You can pull the zip file from either of these locations: </p> <ul> <li> <p>A locally available directory. Use the <i>ZipFile</i> parameter for this option.</p> </li> <li> <p>An Amazon Simple Storage Service (Amazon S3) bucket under your AWS account. Use the <i>StorageLocation</i> parameter for this option. You&#39;ll need to have an Identity Access Management (IAM) role that allows the Amazon GameLift service to access your S3 bucket. </p> </li> </ul> <p>If the call is successful, a new script record is created with a unique script ID. If the script file is provided as a local file, the file is uploaded to an Amazon GameLift-owned S3 bucket and the script record&#39;s storage location reflects this location. If the script file is provided as an S3 bucket, Amazon GameLift accesses the file at this storage location as needed for deployment.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/setting-up-role.html">Set Up a Role for Amazon GameLift Access</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    fn create_script(
+        &self,
+        input: CreateScriptInput,
+    ) -> RusotoFuture<CreateScriptOutput, CreateScriptError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateScript");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateScript");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateScriptOutput, _>(
+                    response,
+                    "CreateScript",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateScriptError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Requests authorization to create or delete a peer connection between the VPC for your Amazon GameLift fleet and a virtual private cloud (VPC) in your AWS account. VPC peering enables the game servers on your fleet to communicate directly with other AWS resources. Once you&#39;ve received authorization, call <a>CreateVpcPeeringConnection</a> to establish the peering connection. For more information, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/vpc-peering.html">VPC Peering with Amazon GameLift Fleets</a>.</p> <p>You can peer with VPCs that are owned by any AWS account you have access to, including the account that you use to manage your Amazon GameLift fleets. You cannot peer with VPCs that are in different regions.</p> <p>To request authorization to create a connection, call this operation from the AWS account with the VPC that you want to peer to your Amazon GameLift fleet. For example, to enable your game servers to retrieve data from a DynamoDB table, use the account that manages that DynamoDB resource. Identify the following values: (1) The ID of the VPC that you want to peer with, and (2) the ID of the AWS account that you use to manage Amazon GameLift. If successful, VPC peering is authorized for the specified VPC. </p> <p>To request authorization to delete a connection, call this operation from the AWS account with the VPC that is peered with your Amazon GameLift fleet. Identify the following values: (1) VPC ID that you want to delete the peering connection for, and (2) ID of the AWS account that you use to manage Amazon GameLift. </p> <p>The authorization remains valid for 24 hours unless it is canceled by a call to <a>DeleteVpcPeeringAuthorization</a>. You must create or delete the peering connection while the authorization is valid. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    fn create_vpc_peering_authorization(
+        &self,
+        input: CreateVpcPeeringAuthorizationInput,
+    ) -> RusotoFuture<CreateVpcPeeringAuthorizationOutput, CreateVpcPeeringAuthorizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateVpcPeeringAuthorization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateVpcPeeringAuthorization");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateVpcPeeringAuthorizationOutput, _>(
+                    response,
+                    "CreateVpcPeeringAuthorization",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateVpcPeeringAuthorizationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Establishes a VPC peering connection between a virtual private cloud (VPC) in an AWS account with the VPC for your Amazon GameLift fleet. VPC peering enables the game servers on your fleet to communicate directly with other AWS resources. You can peer with VPCs in any AWS account that you have access to, including the account that you use to manage your Amazon GameLift fleets. You cannot peer with VPCs that are in different regions. For more information, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/vpc-peering.html">VPC Peering with Amazon GameLift Fleets</a>.</p> <p>Before calling this operation to establish the peering connection, you first need to call <a>CreateVpcPeeringAuthorization</a> and identify the VPC you want to peer with. Once the authorization for the specified VPC is issued, you have 24 hours to establish the connection. These two operations handle all tasks necessary to peer the two VPCs, including acceptance, updating routing tables, etc. </p> <p>To establish the connection, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Identify the following values: (1) The ID of the fleet you want to be enable a VPC peering connection for; (2) The AWS account with the VPC that you want to peer with; and (3) The ID of the VPC you want to peer with. This operation is asynchronous. If successful, a <a>VpcPeeringConnection</a> request is created. You can use continuous polling to track the request&#39;s status using <a>DescribeVpcPeeringConnections</a>, or by monitoring fleet events for success or failure using <a>DescribeFleetEvents</a>. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    fn create_vpc_peering_connection(
+        &self,
+        input: CreateVpcPeeringConnectionInput,
+    ) -> RusotoFuture<CreateVpcPeeringConnectionOutput, CreateVpcPeeringConnectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateVpcPeeringConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateVpcPeeringConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateVpcPeeringConnectionOutput, _>(
+                    response,
+                    "CreateVpcPeeringConnection",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateVpcPeeringConnectionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes an alias. This action removes all record of the alias. Game clients attempting to access a server process using the deleted alias receive an error. To delete an alias, specify the alias ID to be deleted.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    fn delete_alias(&self, input: DeleteAliasInput) -> RusotoFuture<(), DeleteAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DeleteAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a build. This action permanently deletes the build record and any uploaded build files.</p> <p>To delete a build, specify its ID. Deleting a build does not affect the status of any active fleets using the build, but you can no longer create new fleets with the deleted build.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    fn delete_build(&self, input: DeleteBuildInput) -> RusotoFuture<(), DeleteBuildError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteBuild");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DeleteBuild");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBuildError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes everything related to a fleet. Before deleting a fleet, you must set the fleet&#39;s desired capacity to zero. See <a>UpdateFleetCapacity</a>.</p> <p>If the fleet being deleted has a VPC peering connection, you first need to get a valid authorization (good for 24 hours) by calling <a>CreateVpcPeeringAuthorization</a>. You do not need to explicitly delete the VPC peering connection--this is done as part of the delete fleet process.</p> <p>This action removes the fleet&#39;s resources and the fleet record. Once a fleet is deleted, you can no longer use that fleet.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn delete_fleet(&self, input: DeleteFleetInput) -> RusotoFuture<(), DeleteFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteFleet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DeleteFleet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a game session queue. This action means that any <a>StartGameSessionPlacement</a> requests that reference this queue will fail. To delete a queue, specify the queue name.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    fn delete_game_session_queue(
+        &self,
+        input: DeleteGameSessionQueueInput,
+    ) -> RusotoFuture<DeleteGameSessionQueueOutput, DeleteGameSessionQueueError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteGameSessionQueue");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DeleteGameSessionQueue");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteGameSessionQueueOutput, _>(
+                    response,
+                    "DeleteGameSessionQueue",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteGameSessionQueueError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Permanently removes a FlexMatch matchmaking configuration. To delete, specify the configuration name. A matchmaking configuration cannot be deleted if it is being used in any active matchmaking tickets.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    fn delete_matchmaking_configuration(
+        &self,
+        input: DeleteMatchmakingConfigurationInput,
+    ) -> RusotoFuture<DeleteMatchmakingConfigurationOutput, DeleteMatchmakingConfigurationError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteMatchmakingConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DeleteMatchmakingConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteMatchmakingConfigurationOutput, _>(
+                    response,
+                    "DeleteMatchmakingConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteMatchmakingConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes an existing matchmaking rule set. To delete the rule set, provide the rule set name. Rule sets cannot be deleted if they are currently being used by a matchmaking configuration. </p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    fn delete_matchmaking_rule_set(
+        &self,
+        input: DeleteMatchmakingRuleSetInput,
+    ) -> RusotoFuture<DeleteMatchmakingRuleSetOutput, DeleteMatchmakingRuleSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteMatchmakingRuleSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DeleteMatchmakingRuleSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteMatchmakingRuleSetOutput, _>(
+                    response,
+                    "DeleteMatchmakingRuleSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteMatchmakingRuleSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes a fleet scaling policy. This action means that the policy is no longer in force and removes all record of it. To delete a scaling policy, specify both the scaling policy name and the fleet ID it is associated with.</p> <p>To temporarily suspend scaling policies, call <a>StopFleetActions</a>. This operation suspends all policies for the fleet.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyInput,
+    ) -> RusotoFuture<(), DeleteScalingPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteScalingPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DeleteScalingPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteScalingPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a Realtime script. This action permanently deletes the script record. If script files were uploaded, they are also deleted (files stored in an S3 bucket are not deleted). </p> <p>To delete a script, specify the script ID. Before deleting a script, be sure to terminate all fleets that are deployed with the script being deleted. Fleet instances periodically check for script updates, and if the script record no longer exists, the instance will go into an error state and be unable to host game sessions.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    fn delete_script(&self, input: DeleteScriptInput) -> RusotoFuture<(), DeleteScriptError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteScript");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DeleteScript");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteScriptError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Cancels a pending VPC peering authorization for the specified VPC. If you need to delete an existing VPC peering connection, call <a>DeleteVpcPeeringConnection</a>. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    fn delete_vpc_peering_authorization(
+        &self,
+        input: DeleteVpcPeeringAuthorizationInput,
+    ) -> RusotoFuture<DeleteVpcPeeringAuthorizationOutput, DeleteVpcPeeringAuthorizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteVpcPeeringAuthorization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringAuthorization");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteVpcPeeringAuthorizationOutput, _>(
+                    response,
+                    "DeleteVpcPeeringAuthorization",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteVpcPeeringAuthorizationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Removes a VPC peering connection. To delete the connection, you must have a valid authorization for the VPC peering connection that you want to delete. You can check for an authorization by calling <a>DescribeVpcPeeringAuthorizations</a> or request a new one using <a>CreateVpcPeeringAuthorization</a>. </p> <p>Once a valid authorization exists, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Identify the connection to delete by the connection ID and fleet ID. If successful, the connection is removed. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    fn delete_vpc_peering_connection(
+        &self,
+        input: DeleteVpcPeeringConnectionInput,
+    ) -> RusotoFuture<DeleteVpcPeeringConnectionOutput, DeleteVpcPeeringConnectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteVpcPeeringConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteVpcPeeringConnectionOutput, _>(
+                    response,
+                    "DeleteVpcPeeringConnection",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteVpcPeeringConnectionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves properties for an alias. This operation returns all alias metadata and settings. To get an alias&#39;s target fleet ID only, use <code>ResolveAlias</code>. </p> <p>To get alias properties, specify the alias ID. If successful, the requested alias record is returned.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    fn describe_alias(
+        &self,
+        input: DescribeAliasInput,
+    ) -> RusotoFuture<DescribeAliasOutput, DescribeAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAliasOutput, _>(
+                    response,
+                    "DescribeAlias",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves properties for a build. To request a build record, specify a build ID. If successful, an object containing the build properties is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    fn describe_build(
+        &self,
+        input: DescribeBuildInput,
+    ) -> RusotoFuture<DescribeBuildOutput, DescribeBuildError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeBuild");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeBuild");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeBuildOutput, _>(
+                    response,
+                    "DescribeBuild",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeBuildError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the following information for the specified EC2 instance type:</p> <ul> <li> <p>maximum number of instances allowed per AWS account (service limit)</p> </li> <li> <p>current usage level for the AWS account</p> </li> </ul> <p>Service limits vary depending on region. Available regions for Amazon GameLift can be found in the AWS Management Console for Amazon GameLift (see the drop-down list in the upper right corner).</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_ec2_instance_limits(
+        &self,
+        input: DescribeEC2InstanceLimitsInput,
+    ) -> RusotoFuture<DescribeEC2InstanceLimitsOutput, DescribeEC2InstanceLimitsError> {
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeEC2InstanceLimits");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeEC2InstanceLimits");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEC2InstanceLimitsOutput, _>(
+                    response,
+                    "DescribeEC2InstanceLimits",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEC2InstanceLimitsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves fleet properties, including metadata, status, and configuration, for one or more fleets. You can request attributes for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetAttributes</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, attribute objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_fleet_attributes(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> RusotoFuture<DescribeFleetAttributesOutput, DescribeFleetAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeFleetAttributes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeFleetAttributesOutput, _>(
+                    response,
+                    "DescribeFleetAttributes",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeFleetAttributesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the current status of fleet capacity for one or more fleets. This information includes the number of instances that have been requested for the fleet and the number currently active. You can request capacity for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetCapacity</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, attribute objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_fleet_capacity(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> RusotoFuture<DescribeFleetCapacityOutput, DescribeFleetCapacityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetCapacity");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeFleetCapacity");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeFleetCapacityOutput, _>(
+                    response,
+                    "DescribeFleetCapacity",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeFleetCapacityError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves entries from the specified fleet&#39;s event log. You can specify a time range to limit the result set. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a collection of event log entries matching the request are returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_fleet_events(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> RusotoFuture<DescribeFleetEventsOutput, DescribeFleetEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeFleetEvents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeFleetEventsOutput, _>(
+                    response,
+                    "DescribeFleetEvents",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeFleetEventsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the inbound connection permissions for a fleet. Connection permissions include a range of IP addresses and port settings that incoming traffic can use to access server processes in the fleet. To get a fleet&#39;s inbound connection permissions, specify a fleet ID. If successful, a collection of <a>IpPermission</a> objects is returned for the requested fleet ID. If the requested fleet has been deleted, the result set is empty.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_fleet_port_settings(
+        &self,
+        input: DescribeFleetPortSettingsInput,
+    ) -> RusotoFuture<DescribeFleetPortSettingsOutput, DescribeFleetPortSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetPortSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeFleetPortSettings");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeFleetPortSettingsOutput, _>(
+                    response,
+                    "DescribeFleetPortSettings",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeFleetPortSettingsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves utilization statistics for one or more fleets. You can request utilization data for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetUtilization</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, utilization objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_fleet_utilization(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> RusotoFuture<DescribeFleetUtilizationOutput, DescribeFleetUtilizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetUtilization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeFleetUtilization");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeFleetUtilizationOutput, _>(
+                    response,
+                    "DescribeFleetUtilization",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeFleetUtilizationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves properties, including the protection policy in force, for one or more game sessions. This action can be used in several ways: (1) provide a <code>GameSessionId</code> or <code>GameSessionArn</code> to request details for a specific game session; (2) provide either a <code>FleetId</code> or an <code>AliasId</code> to request properties for all game sessions running on a fleet. </p> <p>To get game session record(s), specify just one of the following: game session ID, fleet ID, or alias ID. You can filter this request by game session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSessionDetail</a> object is returned for each session matching the request.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_game_session_details(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> RusotoFuture<DescribeGameSessionDetailsOutput, DescribeGameSessionDetailsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessionDetails");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeGameSessionDetails");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeGameSessionDetailsOutput, _>(
+                    response,
+                    "DescribeGameSessionDetails",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeGameSessionDetailsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves properties and current status of a game session placement request. To get game session placement details, specify the placement ID. If successful, a <a>GameSessionPlacement</a> object is returned.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_game_session_placement(
+        &self,
+        input: DescribeGameSessionPlacementInput,
+    ) -> RusotoFuture<DescribeGameSessionPlacementOutput, DescribeGameSessionPlacementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessionPlacement");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeGameSessionPlacement");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeGameSessionPlacementOutput, _>(
+                    response,
+                    "DescribeGameSessionPlacement",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeGameSessionPlacementError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the properties for one or more game session queues. When requesting multiple queues, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSessionQueue</a> object is returned for each requested queue. When specifying a list of queues, objects are returned only for queues that currently exist in the region.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    fn describe_game_session_queues(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> RusotoFuture<DescribeGameSessionQueuesOutput, DescribeGameSessionQueuesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessionQueues");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeGameSessionQueues");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeGameSessionQueuesOutput, _>(
+                    response,
+                    "DescribeGameSessionQueues",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeGameSessionQueuesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves a set of one or more game sessions. Request a specific game session or request all game sessions on a fleet. Alternatively, use <a>SearchGameSessions</a> to request a set of active game sessions that are filtered by certain criteria. To retrieve protection policy settings for game sessions, use <a>DescribeGameSessionDetails</a>.</p> <p>To get game sessions, specify one of the following: game session ID, fleet ID, or alias ID. You can filter this request by game session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSession</a> object is returned for each game session matching the request.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_game_sessions(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> RusotoFuture<DescribeGameSessionsOutput, DescribeGameSessionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeGameSessions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeGameSessionsOutput, _>(
+                    response,
+                    "DescribeGameSessions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeGameSessionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a fleet's instances, including instance IDs. Use this action to get details on all instances in the fleet or get details on one specific instance.</p> <p>To get a specific instance, specify fleet ID and instance ID. To get all instances in a fleet, specify a fleet ID only. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, an <a>Instance</a> object is returned for each result.</p>
+    fn describe_instances(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> RusotoFuture<DescribeInstancesOutput, DescribeInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeInstances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeInstancesOutput, _>(
+                    response,
+                    "DescribeInstances",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeInstancesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves one or more matchmaking tickets. Use this operation to retrieve ticket information, including status and--once a successful match is made--acquire connection information for the resulting new game session. </p> <p>You can use this operation to track the progress of matchmaking requests (through polling) as an alternative to using event notifications. See more details on tracking matchmaking requests through polling or notifications in <a>StartMatchmaking</a>. </p> <p>To request matchmaking tickets, provide a list of up to 10 ticket IDs. If the request is successful, a ticket object is returned for each requested ID that currently exists.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguidematch-notification.html"> Set Up FlexMatch Event Notification</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    fn describe_matchmaking(
+        &self,
+        input: DescribeMatchmakingInput,
+    ) -> RusotoFuture<DescribeMatchmakingOutput, DescribeMatchmakingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeMatchmaking");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeMatchmaking");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMatchmakingOutput, _>(
+                    response,
+                    "DescribeMatchmaking",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeMatchmakingError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the details of FlexMatch matchmaking configurations. With this operation, you have the following options: (1) retrieve all existing configurations, (2) provide the names of one or more configurations to retrieve, or (3) retrieve all configurations that use a specified rule set name. When requesting multiple items, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a configuration is returned for each requested name. When specifying a list of names, only configurations that currently exist are returned. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/matchmaker-build.html"> Setting Up FlexMatch Matchmakers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    fn describe_matchmaking_configurations(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> RusotoFuture<DescribeMatchmakingConfigurationsOutput, DescribeMatchmakingConfigurationsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeMatchmakingConfigurations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeMatchmakingConfigurations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMatchmakingConfigurationsOutput, _>(
+                    response,
+                    "DescribeMatchmakingConfigurations",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMatchmakingConfigurationsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the details for FlexMatch matchmaking rule sets. You can request all existing rule sets for the region, or provide a list of one or more rule set names. When requesting multiple items, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a rule set is returned for each requested name. </p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    fn describe_matchmaking_rule_sets(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> RusotoFuture<DescribeMatchmakingRuleSetsOutput, DescribeMatchmakingRuleSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeMatchmakingRuleSets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeMatchmakingRuleSets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMatchmakingRuleSetsOutput, _>(
+                    response,
+                    "DescribeMatchmakingRuleSets",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMatchmakingRuleSetsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves properties for one or more player sessions. This action can be used in several ways: (1) provide a <code>PlayerSessionId</code> to request properties for a specific player session; (2) provide a <code>GameSessionId</code> to request properties for all player sessions in the specified game session; (3) provide a <code>PlayerId</code> to request properties for all player sessions of a specified player. </p> <p>To get game session record(s), specify only one of the following: a player session ID, a game session ID, or a player ID. You can filter this request by player session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>PlayerSession</a> object is returned for each session matching the request.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_player_sessions(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> RusotoFuture<DescribePlayerSessionsOutput, DescribePlayerSessionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribePlayerSessions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribePlayerSessions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePlayerSessionsOutput, _>(
+                    response,
+                    "DescribePlayerSessions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribePlayerSessionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the current run-time configuration for the specified fleet. The run-time configuration tells Amazon GameLift how to launch server processes on instances in the fleet.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_runtime_configuration(
+        &self,
+        input: DescribeRuntimeConfigurationInput,
+    ) -> RusotoFuture<DescribeRuntimeConfigurationOutput, DescribeRuntimeConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeRuntimeConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeRuntimeConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRuntimeConfigurationOutput, _>(
+                    response,
+                    "DescribeRuntimeConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeRuntimeConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves all scaling policies applied to a fleet.</p> <p>To get a fleet&#39;s scaling policies, specify the fleet ID. You can filter this request by policy status, such as to retrieve only active scaling policies. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, set of <a>ScalingPolicy</a> objects is returned for the fleet.</p> <p>A fleet may have all of its scaling policies suspended (<a>StopFleetActions</a>). This action does not affect the status of the scaling policies, which remains ACTIVE. To see whether a fleet&#39;s scaling policies are in force or suspended, call <a>DescribeFleetAttributes</a> and check the stopped actions.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> RusotoFuture<DescribeScalingPoliciesOutput, DescribeScalingPoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeScalingPolicies");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeScalingPolicies");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeScalingPoliciesOutput, _>(
+                    response,
+                    "DescribeScalingPolicies",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeScalingPoliciesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves properties for a Realtime script. </p> <p>To request a script record, specify the script ID. If successful, an object containing the script properties is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    fn describe_script(
+        &self,
+        input: DescribeScriptInput,
+    ) -> RusotoFuture<DescribeScriptOutput, DescribeScriptError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeScript");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeScript");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeScriptOutput, _>(
+                    response,
+                    "DescribeScript",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeScriptError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves valid VPC peering authorizations that are pending for the AWS account. This operation returns all VPC peering authorizations and requests for peering. This includes those initiated and received by this account. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    fn describe_vpc_peering_authorizations(
+        &self,
+    ) -> RusotoFuture<DescribeVpcPeeringAuthorizationsOutput, DescribeVpcPeeringAuthorizationsError>
+    {
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeVpcPeeringAuthorizations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringAuthorizations");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeVpcPeeringAuthorizationsOutput, _>(
+                    response,
+                    "DescribeVpcPeeringAuthorizations",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeVpcPeeringAuthorizationsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves information on VPC peering connections. Use this operation to get peering information for all fleets or for one specific fleet ID. </p> <p>To retrieve connection information, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Specify a fleet ID or leave the parameter empty to retrieve all connection records. If successful, the retrieved information includes both active and pending connections. Active connections identify the IpV4 CIDR block that the VPC uses to connect. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    fn describe_vpc_peering_connections(
+        &self,
+        input: DescribeVpcPeeringConnectionsInput,
+    ) -> RusotoFuture<DescribeVpcPeeringConnectionsOutput, DescribeVpcPeeringConnectionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeVpcPeeringConnections");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringConnections");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeVpcPeeringConnectionsOutput, _>(
+                    response,
+                    "DescribeVpcPeeringConnections",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeVpcPeeringConnectionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the location of stored game session logs for a specified game session. When a game session is terminated, Amazon GameLift automatically stores the logs in Amazon S3 and retains them for 14 days. Use this URL to download the logs.</p> <note> <p>See the <a href="https://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_gamelift">AWS Service Limits</a> page for maximum log file sizes. Log files that exceed this limit are not saved.</p> </note> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn get_game_session_log_url(
+        &self,
+        input: GetGameSessionLogUrlInput,
+    ) -> RusotoFuture<GetGameSessionLogUrlOutput, GetGameSessionLogUrlError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("GetGameSessionLogUrl");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.GetGameSessionLogUrl");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetGameSessionLogUrlOutput, _>(
+                    response,
+                    "GetGameSessionLogUrl",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetGameSessionLogUrlError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Requests remote access to a fleet instance. Remote access is useful for debugging, gathering benchmarking data, or watching activity in real time. </p> <p>Access requires credentials that match the operating system of the instance. For a Windows instance, Amazon GameLift returns a user name and password as strings for use with a Windows Remote Desktop client. For a Linux instance, Amazon GameLift returns a user name and RSA private key, also as strings, for use with an SSH client. The private key must be saved in the proper format to a <code>.pem</code> file before using. If you're making this request using the AWS CLI, saving the secret can be handled as part of the GetInstanceAccess request. (See the example later in this topic). For more information on remote access, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-remote-access.html">Remotely Accessing an Instance</a>.</p> <p>To request access to a specific instance, specify the IDs of both the instance and the fleet it belongs to. You can retrieve a fleet's instance IDs by calling <a>DescribeInstances</a>. If successful, an <a>InstanceAccess</a> object is returned containing the instance's IP address and a set of credentials.</p>
+    fn get_instance_access(
+        &self,
+        input: GetInstanceAccessInput,
+    ) -> RusotoFuture<GetInstanceAccessOutput, GetInstanceAccessError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("GetInstanceAccess");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.GetInstanceAccess");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInstanceAccessOutput, _>(
+                    response,
+                    "GetInstanceAccess",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetInstanceAccessError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves all aliases for this AWS account. You can filter the result set by alias name and/or routing strategy type. Use the pagination parameters to retrieve results in sequential pages.</p> <note> <p>Returned aliases are not listed in any particular order.</p> </note> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    fn list_aliases(
+        &self,
+        input: ListAliasesInput,
+    ) -> RusotoFuture<ListAliasesOutput, ListAliasesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListAliases");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.ListAliases");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAliasesOutput, _>(response, "ListAliases")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAliasesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves build records for all builds associated with the AWS account in use. You can limit results to builds that are in a specific status by using the <code>Status</code> parameter. Use the pagination parameters to retrieve results in a set of sequential pages. </p> <note> <p>Build records are not listed in any particular order.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListBuilds");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.ListBuilds");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListBuildsOutput, _>(response, "ListBuilds")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListBuildsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves a collection of fleet records for this AWS account. You can filter the result set to find only those fleets that are deployed with a specific build or script. Use the pagination parameters to retrieve results in sequential pages.</p> <note> <p>Fleet records are not listed in a particular order.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Set Up Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn list_fleets(
+        &self,
+        input: ListFleetsInput,
+    ) -> RusotoFuture<ListFleetsOutput, ListFleetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListFleets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.ListFleets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListFleetsOutput, _>(response, "ListFleets")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListFleetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves script records for all Realtime scripts that are associated with the AWS account in use. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    fn list_scripts(
+        &self,
+        input: ListScriptsInput,
+    ) -> RusotoFuture<ListScriptsOutput, ListScriptsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListScripts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.ListScripts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListScriptsOutput, _>(response, "ListScripts")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListScriptsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates or updates a scaling policy for a fleet. Scaling policies are used to automatically scale a fleet&#39;s hosting capacity to meet player demand. An active scaling policy instructs Amazon GameLift to track a fleet metric and automatically change the fleet&#39;s capacity when a certain threshold is reached. There are two types of scaling policies: target-based and rule-based. Use a target-based policy to quickly and efficiently manage fleet scaling; this option is the most commonly used. Use rule-based policies when you need to exert fine-grained control over auto-scaling. </p> <p>Fleets can have multiple scaling policies of each type in force at the same time; you can have one target-based policy, one or multiple rule-based scaling policies, or both. We recommend caution, however, because multiple auto-scaling policies can have unintended consequences.</p> <p>You can temporarily suspend all scaling policies for a fleet by calling <a>StopFleetActions</a> with the fleet action AUTO_SCALING. To resume scaling policies, call <a>StartFleetActions</a> with the same fleet action. To stop just one scaling policy--or to permanently remove it, you must delete the policy with <a>DeleteScalingPolicy</a>.</p> <p>Learn more about how to work with auto-scaling in <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-autoscaling.html">Set Up Fleet Automatic Scaling</a>.</p> <p> <b>Target-based policy</b> </p> <p>A target-based policy tracks a single metric: PercentAvailableGameSessions. This metric tells us how much of a fleet&#39;s hosting capacity is ready to host game sessions but is not currently in use. This is the fleet&#39;s buffer; it measures the additional player demand that the fleet could handle at current capacity. With a target-based policy, you set your ideal buffer size and leave it to Amazon GameLift to take whatever action is needed to maintain that target. </p> <p>For example, you might choose to maintain a 10% buffer for a fleet that has the capacity to host 100 simultaneous game sessions. This policy tells Amazon GameLift to take action whenever the fleet&#39;s available capacity falls below or rises above 10 game sessions. Amazon GameLift will start new instances or stop unused instances in order to return to the 10% buffer. </p> <p>To create or update a target-based policy, specify a fleet ID and name, and set the policy type to &quot;TargetBased&quot;. Specify the metric to track (PercentAvailableGameSessions) and reference a <a>TargetConfiguration</a> object with your desired buffer value. Exclude all other parameters. On a successful request, the policy name is returned. The scaling policy is automatically in force as soon as it&#39;s successfully created. If the fleet&#39;s auto-scaling actions are temporarily suspended, the new policy will be in force once the fleet actions are restarted.</p> <p> <b>Rule-based policy</b> </p> <p>A rule-based policy tracks specified fleet metric, sets a threshold value, and specifies the type of action to initiate when triggered. With a rule-based policy, you can select from several available fleet metrics. Each policy specifies whether to scale up or scale down (and by how much), so you need one policy for each type of action. </p> <p>For example, a policy may make the following statement: &quot;If the percentage of idle instances is greater than 20% for more than 15 minutes, then reduce the fleet capacity by 10%.&quot;</p> <p>A policy&#39;s rule statement has the following structure:</p> <p>If <code>[MetricName]</code> is <code>[ComparisonOperator]</code> <code>[Threshold]</code> for <code>[EvaluationPeriods]</code> minutes, then <code>[ScalingAdjustmentType]</code> to/by <code>[ScalingAdjustment]</code>.</p> <p>To implement the example, the rule statement would look like this:</p> <p>If <code>[PercentIdleInstances]</code> is <code>[GreaterThanThreshold]</code> <code>[20]</code> for <code>[15]</code> minutes, then <code>[PercentChangeInCapacity]</code> to/by <code>[10]</code>.</p> <p>To create or update a scaling policy, specify a unique combination of name and fleet ID, and set the policy type to &quot;RuleBased&quot;. Specify the parameter values for a policy rule statement. On a successful request, the policy name is returned. Scaling policies are automatically in force as soon as they&#39;re successfully created. If the fleet&#39;s auto-scaling actions are temporarily suspended, the new policy will be in force once the fleet actions are restarted.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyInput,
+    ) -> RusotoFuture<PutScalingPolicyOutput, PutScalingPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("PutScalingPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.PutScalingPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutScalingPolicyOutput, _>(
+                    response,
+                    "PutScalingPolicy",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutScalingPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves a fresh set of credentials for use when uploading a new set of game build files to Amazon GameLift&#39;s Amazon S3. This is done as part of the build creation process; see <a>CreateBuild</a>.</p> <p>To request new credentials, specify the build ID as returned with an initial <code>CreateBuild</code> request. If successful, a new set of credentials are returned, along with the S3 storage location associated with the build ID.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html">Uploading Your Game</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    fn request_upload_credentials(
+        &self,
+        input: RequestUploadCredentialsInput,
+    ) -> RusotoFuture<RequestUploadCredentialsOutput, RequestUploadCredentialsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("RequestUploadCredentials");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.RequestUploadCredentials");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RequestUploadCredentialsOutput, _>(
+                    response,
+                    "RequestUploadCredentials",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RequestUploadCredentialsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the fleet ID that a specified alias is currently pointing to.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    fn resolve_alias(
+        &self,
+        input: ResolveAliasInput,
+    ) -> RusotoFuture<ResolveAliasOutput, ResolveAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ResolveAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.ResolveAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ResolveAliasOutput, _>(
+                    response,
+                    "ResolveAlias",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResolveAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves all active game sessions that match a set of search criteria and sorts them in a specified order. You can search or sort by the following game session attributes:</p> <ul> <li> <p> <b>gameSessionId</b> -- Unique identifier for the game session. You can use either a <code>GameSessionId</code> or <code>GameSessionArn</code> value. </p> </li> <li> <p> <b>gameSessionName</b> -- Name assigned to a game session. This value is set when requesting a new game session with <a>CreateGameSession</a> or updating with <a>UpdateGameSession</a>. Game session names do not need to be unique to a game session.</p> </li> <li> <p> <b>gameSessionProperties</b> -- Custom data defined in a game session&#39;s <code>GameProperty</code> parameter. <code>GameProperty</code> values are stored as key:value pairs; the filter expression must indicate the key and a string to search the data values for. For example, to search for game sessions with custom data containing the key:value pair &quot;gameMode:brawl&quot;, specify the following: <code>gameSessionProperties.gameMode = &quot;brawl&quot;</code>. All custom data values are searched as strings.</p> </li> <li> <p> <b>maximumSessions</b> -- Maximum number of player sessions allowed for a game session. This value is set when requesting a new game session with <a>CreateGameSession</a> or updating with <a>UpdateGameSession</a>.</p> </li> <li> <p> <b>creationTimeMillis</b> -- Value indicating when a game session was created. It is expressed in Unix time as milliseconds.</p> </li> <li> <p> <b>playerSessionCount</b> -- Number of players currently connected to a game session. This value changes rapidly as players join the session or drop out.</p> </li> <li> <p> <b>hasAvailablePlayerSessions</b> -- Boolean value indicating whether a game session has reached its maximum number of players. It is highly recommended that all search requests include this filter attribute to optimize search performance and return only sessions that players can join. </p> </li> </ul> <note> <p>Returned values for <code>playerSessionCount</code> and <code>hasAvailablePlayerSessions</code> change quickly as players join sessions and others drop out. Results should be considered a snapshot in time. Be sure to refresh search results often, and handle sessions that fill up before a player can join. </p> </note> <p>To search or sort, specify either a fleet ID or an alias ID, and provide a search filter expression, a sort expression, or both. If successful, a collection of <a>GameSession</a> objects matching the request is returned. Use the pagination parameters to retrieve results as a set of sequential pages. </p> <p>You can search for game sessions one fleet at a time only. To find game sessions across multiple fleets, you must search each fleet separately and combine the results. This search feature finds only game sessions that are in <code>ACTIVE</code> status. To locate games in statuses other than active, use <a>DescribeGameSessionDetails</a>.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn search_game_sessions(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> RusotoFuture<SearchGameSessionsOutput, SearchGameSessionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("SearchGameSessions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.SearchGameSessions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchGameSessionsOutput, _>(
+                    response,
+                    "SearchGameSessions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchGameSessionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Resumes activity on a fleet that was suspended with <a>StopFleetActions</a>. Currently, this operation is used to restart a fleet&#39;s auto-scaling activity. </p> <p>To start fleet actions, specify the fleet ID and the type of actions to restart. When auto-scaling fleet actions are restarted, Amazon GameLift once again initiates scaling events as triggered by the fleet&#39;s scaling policies. If actions on the fleet were never stopped, this operation will have no effect. You can view a fleet&#39;s stopped actions using <a>DescribeFleetAttributes</a>.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn start_fleet_actions(
+        &self,
+        input: StartFleetActionsInput,
+    ) -> RusotoFuture<StartFleetActionsOutput, StartFleetActionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartFleetActions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.StartFleetActions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartFleetActionsOutput, _>(
+                    response,
+                    "StartFleetActions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartFleetActionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Places a request for a new game session in a queue (see <a>CreateGameSessionQueue</a>). When processing a placement request, Amazon GameLift searches for available resources on the queue&#39;s destinations, scanning each until it finds resources or the placement request times out.</p> <p>A game session placement request can also request player sessions. When a new game session is successfully created, Amazon GameLift creates a player session for each player included in the request.</p> <p>When placing a game session, by default Amazon GameLift tries each fleet in the order they are listed in the queue configuration. Ideally, a queue&#39;s destinations are listed in preference order.</p> <p>Alternatively, when requesting a game session with players, you can also provide latency data for each player in relevant regions. Latency data indicates the performance lag a player experiences when connected to a fleet in the region. Amazon GameLift uses latency data to reorder the list of destinations to place the game session in a region with minimal lag. If latency data is provided for multiple players, Amazon GameLift calculates each region&#39;s average lag for all players and reorders to get the best game play across all players. </p> <p>To place a new game session request, specify the following:</p> <ul> <li> <p>The queue name and a set of game session properties and settings</p> </li> <li> <p>A unique ID (such as a UUID) for the placement. You use this ID to track the status of the placement request</p> </li> <li> <p>(Optional) A set of player data and a unique player ID for each player that you are joining to the new game session (player data is optional, but if you include it, you must also provide a unique ID for each player)</p> </li> <li> <p>Latency data for all players (if you want to optimize game play for the players)</p> </li> </ul> <p>If successful, a new game session placement is created.</p> <p>To track the status of a placement request, call <a>DescribeGameSessionPlacement</a> and check the request&#39;s status. If the status is <code>FULFILLED</code>, a new game session has been created and a game session ARN and region are referenced. If the placement request times out, you can resubmit the request or retry it with a different queue. </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn start_game_session_placement(
+        &self,
+        input: StartGameSessionPlacementInput,
+    ) -> RusotoFuture<StartGameSessionPlacementOutput, StartGameSessionPlacementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartGameSessionPlacement");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.StartGameSessionPlacement");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartGameSessionPlacementOutput, _>(
+                    response,
+                    "StartGameSessionPlacement",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartGameSessionPlacementError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Finds new players to fill open slots in an existing game session. This operation can be used to add players to matched games that start with fewer than the maximum number of players or to replace players when they drop out. By backfilling with the same matchmaker used to create the original match, you ensure that new players meet the match criteria and maintain a consistent experience throughout the game session. You can backfill a match anytime after a game session has been created. </p> <p>To request a match backfill, specify a unique ticket ID, the existing game session&#39;s ARN, a matchmaking configuration, and a set of data that describes all current players in the game session. If successful, a match backfill ticket is created and returned with status set to QUEUED. The ticket is placed in the matchmaker&#39;s ticket pool and processed. Track the status of the ticket to respond as needed. </p> <p>The process of finding backfill matches is essentially identical to the initial matchmaking process. The matchmaker searches the pool and groups tickets together to form potential matches, allowing only one backfill ticket per potential match. Once the a match is formed, the matchmaker creates player sessions for the new players. All tickets in the match are updated with the game session&#39;s connection information, and the <a>GameSession</a> object is updated to include matchmaker data on the new players. For more detail on how match backfill requests are processed, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How Amazon GameLift FlexMatch Works</a>. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-backfill.html"> Backfill Existing Games with FlexMatch</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How GameLift FlexMatch Works</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    fn start_match_backfill(
+        &self,
+        input: StartMatchBackfillInput,
+    ) -> RusotoFuture<StartMatchBackfillOutput, StartMatchBackfillError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartMatchBackfill");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.StartMatchBackfill");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartMatchBackfillOutput, _>(
+                    response,
+                    "StartMatchBackfill",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartMatchBackfillError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Uses FlexMatch to create a game match for a group of players based on custom matchmaking rules, and starts a new game for the matched players. Each matchmaking request specifies the type of match to build (team configuration, rules for an acceptable match, etc.). The request also specifies the players to find a match for and where to host the new game session for optimal performance. A matchmaking request might start with a single player or a group of players who want to play together. FlexMatch finds additional players as needed to fill the match. Match type, rules, and the queue used to place a new game session are defined in a <code>MatchmakingConfiguration</code>. </p> <p>To start matchmaking, provide a unique ticket ID, specify a matchmaking configuration, and include the players to be matched. You must also include a set of player attributes relevant for the matchmaking configuration. If successful, a matchmaking ticket is returned with status set to <code>QUEUED</code>. Track the status of the ticket to respond as needed and acquire game session connection information for successfully completed matches.</p> <p> <b>Tracking ticket status</b> -- A couple of options are available for tracking the status of matchmaking requests: </p> <ul> <li> <p>Polling -- Call <code>DescribeMatchmaking</code>. This operation returns the full ticket object, including current status and (for completed tickets) game session connection info. We recommend polling no more than once every 10 seconds.</p> </li> <li> <p>Notifications -- Get event notifications for changes in ticket status using Amazon Simple Notification Service (SNS). Notifications are easy to set up (see <a>CreateMatchmakingConfiguration</a>) and typically deliver match status changes faster and more efficiently than polling. We recommend that you use polling to back up to notifications (since delivery is not guaranteed) and call <code>DescribeMatchmaking</code> only when notifications are not received within 30 seconds.</p> </li> </ul> <p> <b>Processing a matchmaking request</b> -- FlexMatch handles a matchmaking request as follows: </p> <ol> <li> <p>Your client code submits a <code>StartMatchmaking</code> request for one or more players and tracks the status of the request ticket. </p> </li> <li> <p>FlexMatch uses this ticket and others in process to build an acceptable match. When a potential match is identified, all tickets in the proposed match are advanced to the next status. </p> </li> <li> <p>If the match requires player acceptance (set in the matchmaking configuration), the tickets move into status <code>REQUIRES_ACCEPTANCE</code>. This status triggers your client code to solicit acceptance from all players in every ticket involved in the match, and then call <a>AcceptMatch</a> for each player. If any player rejects or fails to accept the match before a specified timeout, the proposed match is dropped (see <code>AcceptMatch</code> for more details).</p> </li> <li> <p>Once a match is proposed and accepted, the matchmaking tickets move into status <code>PLACING</code>. FlexMatch locates resources for a new game session using the game session queue (set in the matchmaking configuration) and creates the game session based on the match data. </p> </li> <li> <p>When the match is successfully placed, the matchmaking tickets move into <code>COMPLETED</code> status. Connection information (including game session endpoint and player session) is added to the matchmaking tickets. Matched players can use the connection information to join the game. </p> </li> </ol> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Set Up FlexMatch Event Notification</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-tasks.html"> FlexMatch Integration Roadmap</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How GameLift FlexMatch Works</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    fn start_matchmaking(
+        &self,
+        input: StartMatchmakingInput,
+    ) -> RusotoFuture<StartMatchmakingOutput, StartMatchmakingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartMatchmaking");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.StartMatchmaking");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartMatchmakingOutput, _>(
+                    response,
+                    "StartMatchmaking",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartMatchmakingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Suspends activity on a fleet. Currently, this operation is used to stop a fleet&#39;s auto-scaling activity. It is used to temporarily stop scaling events triggered by the fleet&#39;s scaling policies. The policies can be retained and auto-scaling activity can be restarted using <a>StartFleetActions</a>. You can view a fleet&#39;s stopped actions using <a>DescribeFleetAttributes</a>.</p> <p>To stop fleet actions, specify the fleet ID and the type of actions to suspend. When auto-scaling fleet actions are stopped, Amazon GameLift no longer initiates scaling events except to maintain the fleet&#39;s desired instances setting (<a>FleetCapacity</a>. Changes to the fleet&#39;s capacity must be done manually using <a>UpdateFleetCapacity</a>. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn stop_fleet_actions(
+        &self,
+        input: StopFleetActionsInput,
+    ) -> RusotoFuture<StopFleetActionsOutput, StopFleetActionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StopFleetActions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.StopFleetActions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopFleetActionsOutput, _>(
+                    response,
+                    "StopFleetActions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopFleetActionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Cancels a game session placement that is in <code>PENDING</code> status. To stop a placement, provide the placement ID values. If successful, the placement is moved to <code>CANCELLED</code> status.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn stop_game_session_placement(
+        &self,
+        input: StopGameSessionPlacementInput,
+    ) -> RusotoFuture<StopGameSessionPlacementOutput, StopGameSessionPlacementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StopGameSessionPlacement");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.StopGameSessionPlacement");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopGameSessionPlacementOutput, _>(
+                    response,
+                    "StopGameSessionPlacement",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopGameSessionPlacementError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Cancels a matchmaking ticket or match backfill ticket that is currently being processed. To stop the matchmaking operation, specify the ticket ID. If successful, work on the ticket is stopped, and the ticket status is changed to <code>CANCELLED</code>.</p> <p>This call is also used to turn off automatic backfill for an individual game session. This is for game sessions that are created with a matchmaking configuration that has automatic backfill enabled. The ticket ID is included in the <code>MatchmakerData</code> of an updated game session object, which is provided to the game server.</p> <note> <p>If the action is successful, the service sends back an empty JSON struct with the HTTP 200 response (not an empty HTTP body).</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    fn stop_matchmaking(
+        &self,
+        input: StopMatchmakingInput,
+    ) -> RusotoFuture<StopMatchmakingOutput, StopMatchmakingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StopMatchmaking");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.StopMatchmaking");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopMatchmakingOutput, _>(
+                    response,
+                    "StopMatchmaking",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopMatchmakingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates properties for an alias. To update properties, specify the alias ID to be updated and provide the information to be changed. To reassign an alias to another fleet, provide an updated routing strategy. If successful, the updated alias record is returned.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    fn update_alias(
+        &self,
+        input: UpdateAliasInput,
+    ) -> RusotoFuture<UpdateAliasOutput, UpdateAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.UpdateAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateAliasOutput, _>(response, "UpdateAlias")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates metadata in a build record, including the build name and version. To update the metadata, specify the build ID to update and provide the new values. If successful, a build object containing the updated metadata is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    fn update_build(
+        &self,
+        input: UpdateBuildInput,
+    ) -> RusotoFuture<UpdateBuildOutput, UpdateBuildError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateBuild");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.UpdateBuild");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateBuildOutput, _>(response, "UpdateBuild")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateBuildError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates fleet properties, including name and description, for a fleet. To update metadata, specify the fleet ID and the property values that you want to change. If successful, the fleet ID for the updated fleet is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn update_fleet_attributes(
+        &self,
+        input: UpdateFleetAttributesInput,
+    ) -> RusotoFuture<UpdateFleetAttributesOutput, UpdateFleetAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateFleetAttributes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.UpdateFleetAttributes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateFleetAttributesOutput, _>(
+                    response,
+                    "UpdateFleetAttributes",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateFleetAttributesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates capacity settings for a fleet. Use this action to specify the number of EC2 instances (hosts) that you want this fleet to contain. Before calling this action, you may want to call <a>DescribeEC2InstanceLimits</a> to get the maximum capacity based on the fleet&#39;s EC2 instance type.</p> <p>Specify minimum and maximum number of instances. Amazon GameLift will not change fleet capacity to values fall outside of this range. This is particularly important when using auto-scaling (see <a>PutScalingPolicy</a>) to allow capacity to adjust based on player demand while imposing limits on automatic adjustments.</p> <p>To update fleet capacity, specify the fleet ID and the number of instances you want the fleet to host. If successful, Amazon GameLift starts or terminates instances so that the fleet&#39;s active instance count matches the desired instance count. You can view a fleet&#39;s current capacity information by calling <a>DescribeFleetCapacity</a>. If the desired instance count is higher than the instance type&#39;s limit, the &quot;Limit Exceeded&quot; exception occurs.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn update_fleet_capacity(
+        &self,
+        input: UpdateFleetCapacityInput,
+    ) -> RusotoFuture<UpdateFleetCapacityOutput, UpdateFleetCapacityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateFleetCapacity");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.UpdateFleetCapacity");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateFleetCapacityOutput, _>(
+                    response,
+                    "UpdateFleetCapacity",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateFleetCapacityError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates port settings for a fleet. To update settings, specify the fleet ID to be updated and list the permissions you want to update. List the permissions you want to add in <code>InboundPermissionAuthorizations</code>, and permissions you want to remove in <code>InboundPermissionRevocations</code>. Permissions to be removed must match existing fleet permissions. If successful, the fleet ID for the updated fleet is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn update_fleet_port_settings(
+        &self,
+        input: UpdateFleetPortSettingsInput,
+    ) -> RusotoFuture<UpdateFleetPortSettingsOutput, UpdateFleetPortSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateFleetPortSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.UpdateFleetPortSettings");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateFleetPortSettingsOutput, _>(
+                    response,
+                    "UpdateFleetPortSettings",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateFleetPortSettingsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Updates game session properties. This includes the session name, maximum player count, protection policy, which controls whether or not an active game session can be terminated during a scale-down event, and the player session creation policy, which controls whether or not new players can join the session. To update a game session, specify the game session ID and the values you want to change. If successful, an updated <a>GameSession</a> object is returned. </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    fn update_game_session(
+        &self,
+        input: UpdateGameSessionInput,
+    ) -> RusotoFuture<UpdateGameSessionOutput, UpdateGameSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateGameSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.UpdateGameSession");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateGameSessionOutput, _>(
+                    response,
+                    "UpdateGameSession",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateGameSessionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates settings for a game session queue, which determines how new game session requests in the queue are processed. To update settings, specify the queue name to be updated and provide the new settings. When updating destinations, provide a complete list of destinations. </p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    fn update_game_session_queue(
+        &self,
+        input: UpdateGameSessionQueueInput,
+    ) -> RusotoFuture<UpdateGameSessionQueueOutput, UpdateGameSessionQueueError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateGameSessionQueue");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.UpdateGameSessionQueue");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateGameSessionQueueOutput, _>(
+                    response,
+                    "UpdateGameSessionQueue",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateGameSessionQueueError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates settings for a FlexMatch matchmaking configuration. These changes affect all matches and game sessions that are created after the update. To update settings, specify the configuration name to be updated and provide the new settings. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html"> Design a FlexMatch Matchmaker</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    fn update_matchmaking_configuration(
+        &self,
+        input: UpdateMatchmakingConfigurationInput,
+    ) -> RusotoFuture<UpdateMatchmakingConfigurationOutput, UpdateMatchmakingConfigurationError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateMatchmakingConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.UpdateMatchmakingConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateMatchmakingConfigurationOutput, _>(
+                    response,
+                    "UpdateMatchmakingConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateMatchmakingConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Updates the current run-time configuration for the specified fleet, which tells Amazon GameLift how to launch server processes on instances in the fleet. You can update a fleet&#39;s run-time configuration at any time after the fleet is created; it does not need to be in an <code>ACTIVE</code> status.</p> <p>To update run-time configuration, specify the fleet ID and provide a <code>RuntimeConfiguration</code> object with an updated set of server process configurations.</p> <p>Each instance in a Amazon GameLift fleet checks regularly for an updated run-time configuration and changes how it launches server processes to comply with the latest version. Existing server processes are not affected by the update; run-time configuration changes are applied gradually as existing processes shut down and new processes are launched during Amazon GameLift&#39;s normal process recycling activity.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    fn update_runtime_configuration(
+        &self,
+        input: UpdateRuntimeConfigurationInput,
+    ) -> RusotoFuture<UpdateRuntimeConfigurationOutput, UpdateRuntimeConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateRuntimeConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.UpdateRuntimeConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateRuntimeConfigurationOutput, _>(
+                    response,
+                    "UpdateRuntimeConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateRuntimeConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Updates Realtime script metadata and content.</p> <p>To update script metadata, specify the script ID and provide updated name and/or version values. </p> <p>To update script content, provide an updated zip file by pointing to either a local file or an Amazon S3 bucket location. You can use either method regardless of how the original script was uploaded. Use the <i>Version</i> parameter to track updates to the script.</p> <p>If the call is successful, the updated metadata is stored in the script record and a revised script is uploaded to the Amazon GameLift service. Once the script is updated and acquired by a fleet instance, the new version is used for all new game sessions. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    fn update_script(
+        &self,
+        input: UpdateScriptInput,
+    ) -> RusotoFuture<UpdateScriptOutput, UpdateScriptError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateScript");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.UpdateScript");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateScriptOutput, _>(
+                    response,
+                    "UpdateScript",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateScriptError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Validates the syntax of a matchmaking rule or rule set. This operation checks that the rule set is using syntactically correct JSON and that it conforms to allowed property expressions. To validate syntax, provide a rule set JSON string.</p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    fn validate_matchmaking_rule_set(
+        &self,
+        input: ValidateMatchmakingRuleSetInput,
+    ) -> RusotoFuture<ValidateMatchmakingRuleSetOutput, ValidateMatchmakingRuleSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ValidateMatchmakingRuleSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.ValidateMatchmakingRuleSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ValidateMatchmakingRuleSetOutput, _>(
+                    response,
+                    "ValidateMatchmakingRuleSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ValidateMatchmakingRuleSetError::from_response(response))
+                }))
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon GameLift API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockGameLift {
+    accept_match: Option<
+        Box<
+            dyn Fn(
+                    AcceptMatchInput,
+                )
+                    -> ::std::result::Result<AcceptMatchOutput, RusotoError<AcceptMatchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_alias: Option<
+        Box<
+            dyn Fn(
+                    CreateAliasInput,
+                )
+                    -> ::std::result::Result<CreateAliasOutput, RusotoError<CreateAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_build: Option<
+        Box<
+            dyn Fn(
+                    CreateBuildInput,
+                )
+                    -> ::std::result::Result<CreateBuildOutput, RusotoError<CreateBuildError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_fleet: Option<
+        Box<
+            dyn Fn(
+                    CreateFleetInput,
+                )
+                    -> ::std::result::Result<CreateFleetOutput, RusotoError<CreateFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_game_session: Option<
+        Box<
+            dyn Fn(
+                    CreateGameSessionInput,
+                ) -> ::std::result::Result<
+                    CreateGameSessionOutput,
+                    RusotoError<CreateGameSessionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_game_session_queue: Option<
+        Box<
+            dyn Fn(
+                    CreateGameSessionQueueInput,
+                ) -> ::std::result::Result<
+                    CreateGameSessionQueueOutput,
+                    RusotoError<CreateGameSessionQueueError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_matchmaking_configuration: Option<
+        Box<
+            dyn Fn(
+                    CreateMatchmakingConfigurationInput,
+                ) -> ::std::result::Result<
+                    CreateMatchmakingConfigurationOutput,
+                    RusotoError<CreateMatchmakingConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_matchmaking_rule_set: Option<
+        Box<
+            dyn Fn(
+                    CreateMatchmakingRuleSetInput,
+                ) -> ::std::result::Result<
+                    CreateMatchmakingRuleSetOutput,
+                    RusotoError<CreateMatchmakingRuleSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_player_session: Option<
+        Box<
+            dyn Fn(
+                    CreatePlayerSessionInput,
+                ) -> ::std::result::Result<
+                    CreatePlayerSessionOutput,
+                    RusotoError<CreatePlayerSessionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_player_sessions: Option<
+        Box<
+            dyn Fn(
+                    CreatePlayerSessionsInput,
+                ) -> ::std::result::Result<
+                    CreatePlayerSessionsOutput,
+                    RusotoError<CreatePlayerSessionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_script: Option<
+        Box<
+            dyn Fn(
+                    CreateScriptInput,
+                )
+                    -> ::std::result::Result<CreateScriptOutput, RusotoError<CreateScriptError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_vpc_peering_authorization: Option<
+        Box<
+            dyn Fn(
+                    CreateVpcPeeringAuthorizationInput,
+                ) -> ::std::result::Result<
+                    CreateVpcPeeringAuthorizationOutput,
+                    RusotoError<CreateVpcPeeringAuthorizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_vpc_peering_connection: Option<
+        Box<
+            dyn Fn(
+                    CreateVpcPeeringConnectionInput,
+                ) -> ::std::result::Result<
+                    CreateVpcPeeringConnectionOutput,
+                    RusotoError<CreateVpcPeeringConnectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_alias: Option<
+        Box<
+            dyn Fn(DeleteAliasInput) -> ::std::result::Result<(), RusotoError<DeleteAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_build: Option<
+        Box<
+            dyn Fn(DeleteBuildInput) -> ::std::result::Result<(), RusotoError<DeleteBuildError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_fleet: Option<
+        Box<
+            dyn Fn(DeleteFleetInput) -> ::std::result::Result<(), RusotoError<DeleteFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_game_session_queue: Option<
+        Box<
+            dyn Fn(
+                    DeleteGameSessionQueueInput,
+                ) -> ::std::result::Result<
+                    DeleteGameSessionQueueOutput,
+                    RusotoError<DeleteGameSessionQueueError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_matchmaking_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteMatchmakingConfigurationInput,
+                ) -> ::std::result::Result<
+                    DeleteMatchmakingConfigurationOutput,
+                    RusotoError<DeleteMatchmakingConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_matchmaking_rule_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteMatchmakingRuleSetInput,
+                ) -> ::std::result::Result<
+                    DeleteMatchmakingRuleSetOutput,
+                    RusotoError<DeleteMatchmakingRuleSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_scaling_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteScalingPolicyInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteScalingPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_script: Option<
+        Box<
+            dyn Fn(DeleteScriptInput) -> ::std::result::Result<(), RusotoError<DeleteScriptError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_vpc_peering_authorization: Option<
+        Box<
+            dyn Fn(
+                    DeleteVpcPeeringAuthorizationInput,
+                ) -> ::std::result::Result<
+                    DeleteVpcPeeringAuthorizationOutput,
+                    RusotoError<DeleteVpcPeeringAuthorizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_vpc_peering_connection: Option<
+        Box<
+            dyn Fn(
+                    DeleteVpcPeeringConnectionInput,
+                ) -> ::std::result::Result<
+                    DeleteVpcPeeringConnectionOutput,
+                    RusotoError<DeleteVpcPeeringConnectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_alias: Option<
+        Box<
+            dyn Fn(
+                    DescribeAliasInput,
+                )
+                    -> ::std::result::Result<DescribeAliasOutput, RusotoError<DescribeAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_build: Option<
+        Box<
+            dyn Fn(
+                    DescribeBuildInput,
+                )
+                    -> ::std::result::Result<DescribeBuildOutput, RusotoError<DescribeBuildError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_ec2_instance_limits: Option<
+        Box<
+            dyn Fn(
+                    DescribeEC2InstanceLimitsInput,
+                ) -> ::std::result::Result<
+                    DescribeEC2InstanceLimitsOutput,
+                    RusotoError<DescribeEC2InstanceLimitsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_fleet_attributes: Option<
+        Box<
+            dyn Fn(
+                    DescribeFleetAttributesInput,
+                ) -> ::std::result::Result<
+                    DescribeFleetAttributesOutput,
+                    RusotoError<DescribeFleetAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_fleet_capacity: Option<
+        Box<
+            dyn Fn(
+                    DescribeFleetCapacityInput,
+                ) -> ::std::result::Result<
+                    DescribeFleetCapacityOutput,
+                    RusotoError<DescribeFleetCapacityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_fleet_events: Option<
+        Box<
+            dyn Fn(
+                    DescribeFleetEventsInput,
+                ) -> ::std::result::Result<
+                    DescribeFleetEventsOutput,
+                    RusotoError<DescribeFleetEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_fleet_port_settings: Option<
+        Box<
+            dyn Fn(
+                    DescribeFleetPortSettingsInput,
+                ) -> ::std::result::Result<
+                    DescribeFleetPortSettingsOutput,
+                    RusotoError<DescribeFleetPortSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_fleet_utilization: Option<
+        Box<
+            dyn Fn(
+                    DescribeFleetUtilizationInput,
+                ) -> ::std::result::Result<
+                    DescribeFleetUtilizationOutput,
+                    RusotoError<DescribeFleetUtilizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_game_session_details: Option<
+        Box<
+            dyn Fn(
+                    DescribeGameSessionDetailsInput,
+                ) -> ::std::result::Result<
+                    DescribeGameSessionDetailsOutput,
+                    RusotoError<DescribeGameSessionDetailsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_game_session_placement: Option<
+        Box<
+            dyn Fn(
+                    DescribeGameSessionPlacementInput,
+                ) -> ::std::result::Result<
+                    DescribeGameSessionPlacementOutput,
+                    RusotoError<DescribeGameSessionPlacementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_game_session_queues: Option<
+        Box<
+            dyn Fn(
+                    DescribeGameSessionQueuesInput,
+                ) -> ::std::result::Result<
+                    DescribeGameSessionQueuesOutput,
+                    RusotoError<DescribeGameSessionQueuesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_game_sessions: Option<
+        Box<
+            dyn Fn(
+                    DescribeGameSessionsInput,
+                ) -> ::std::result::Result<
+                    DescribeGameSessionsOutput,
+                    RusotoError<DescribeGameSessionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_instances: Option<
+        Box<
+            dyn Fn(
+                    DescribeInstancesInput,
+                ) -> ::std::result::Result<
+                    DescribeInstancesOutput,
+                    RusotoError<DescribeInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_matchmaking: Option<
+        Box<
+            dyn Fn(
+                    DescribeMatchmakingInput,
+                ) -> ::std::result::Result<
+                    DescribeMatchmakingOutput,
+                    RusotoError<DescribeMatchmakingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_matchmaking_configurations: Option<
+        Box<
+            dyn Fn(
+                    DescribeMatchmakingConfigurationsInput,
+                ) -> ::std::result::Result<
+                    DescribeMatchmakingConfigurationsOutput,
+                    RusotoError<DescribeMatchmakingConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_matchmaking_rule_sets: Option<
+        Box<
+            dyn Fn(
+                    DescribeMatchmakingRuleSetsInput,
+                ) -> ::std::result::Result<
+                    DescribeMatchmakingRuleSetsOutput,
+                    RusotoError<DescribeMatchmakingRuleSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_player_sessions: Option<
+        Box<
+            dyn Fn(
+                    DescribePlayerSessionsInput,
+                ) -> ::std::result::Result<
+                    DescribePlayerSessionsOutput,
+                    RusotoError<DescribePlayerSessionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_runtime_configuration: Option<
+        Box<
+            dyn Fn(
+                    DescribeRuntimeConfigurationInput,
+                ) -> ::std::result::Result<
+                    DescribeRuntimeConfigurationOutput,
+                    RusotoError<DescribeRuntimeConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_scaling_policies: Option<
+        Box<
+            dyn Fn(
+                    DescribeScalingPoliciesInput,
+                ) -> ::std::result::Result<
+                    DescribeScalingPoliciesOutput,
+                    RusotoError<DescribeScalingPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_script: Option<
+        Box<
+            dyn Fn(
+                    DescribeScriptInput,
+                )
+                    -> ::std::result::Result<DescribeScriptOutput, RusotoError<DescribeScriptError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_vpc_peering_authorizations: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeVpcPeeringAuthorizationsOutput,
+                    RusotoError<DescribeVpcPeeringAuthorizationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_vpc_peering_connections: Option<
+        Box<
+            dyn Fn(
+                    DescribeVpcPeeringConnectionsInput,
+                ) -> ::std::result::Result<
+                    DescribeVpcPeeringConnectionsOutput,
+                    RusotoError<DescribeVpcPeeringConnectionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_game_session_log_url: Option<
+        Box<
+            dyn Fn(
+                    GetGameSessionLogUrlInput,
+                ) -> ::std::result::Result<
+                    GetGameSessionLogUrlOutput,
+                    RusotoError<GetGameSessionLogUrlError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_instance_access: Option<
+        Box<
+            dyn Fn(
+                    GetInstanceAccessInput,
+                ) -> ::std::result::Result<
+                    GetInstanceAccessOutput,
+                    RusotoError<GetInstanceAccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_aliases: Option<
+        Box<
+            dyn Fn(
+                    ListAliasesInput,
+                )
+                    -> ::std::result::Result<ListAliasesOutput, RusotoError<ListAliasesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_builds: Option<
+        Box<
+            dyn Fn(
+                    ListBuildsInput,
+                )
+                    -> ::std::result::Result<ListBuildsOutput, RusotoError<ListBuildsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_fleets: Option<
+        Box<
+            dyn Fn(
+                    ListFleetsInput,
+                )
+                    -> ::std::result::Result<ListFleetsOutput, RusotoError<ListFleetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_scripts: Option<
+        Box<
+            dyn Fn(
+                    ListScriptsInput,
+                )
+                    -> ::std::result::Result<ListScriptsOutput, RusotoError<ListScriptsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_scaling_policy: Option<
+        Box<
+            dyn Fn(
+                    PutScalingPolicyInput,
+                ) -> ::std::result::Result<
+                    PutScalingPolicyOutput,
+                    RusotoError<PutScalingPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    request_upload_credentials: Option<
+        Box<
+            dyn Fn(
+                    RequestUploadCredentialsInput,
+                ) -> ::std::result::Result<
+                    RequestUploadCredentialsOutput,
+                    RusotoError<RequestUploadCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    resolve_alias: Option<
+        Box<
+            dyn Fn(
+                    ResolveAliasInput,
+                )
+                    -> ::std::result::Result<ResolveAliasOutput, RusotoError<ResolveAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    search_game_sessions: Option<
+        Box<
+            dyn Fn(
+                    SearchGameSessionsInput,
+                ) -> ::std::result::Result<
+                    SearchGameSessionsOutput,
+                    RusotoError<SearchGameSessionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_fleet_actions: Option<
+        Box<
+            dyn Fn(
+                    StartFleetActionsInput,
+                ) -> ::std::result::Result<
+                    StartFleetActionsOutput,
+                    RusotoError<StartFleetActionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_game_session_placement: Option<
+        Box<
+            dyn Fn(
+                    StartGameSessionPlacementInput,
+                ) -> ::std::result::Result<
+                    StartGameSessionPlacementOutput,
+                    RusotoError<StartGameSessionPlacementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_match_backfill: Option<
+        Box<
+            dyn Fn(
+                    StartMatchBackfillInput,
+                ) -> ::std::result::Result<
+                    StartMatchBackfillOutput,
+                    RusotoError<StartMatchBackfillError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_matchmaking: Option<
+        Box<
+            dyn Fn(
+                    StartMatchmakingInput,
+                ) -> ::std::result::Result<
+                    StartMatchmakingOutput,
+                    RusotoError<StartMatchmakingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_fleet_actions: Option<
+        Box<
+            dyn Fn(
+                    StopFleetActionsInput,
+                ) -> ::std::result::Result<
+                    StopFleetActionsOutput,
+                    RusotoError<StopFleetActionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_game_session_placement: Option<
+        Box<
+            dyn Fn(
+                    StopGameSessionPlacementInput,
+                ) -> ::std::result::Result<
+                    StopGameSessionPlacementOutput,
+                    RusotoError<StopGameSessionPlacementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_matchmaking: Option<
+        Box<
+            dyn Fn(
+                    StopMatchmakingInput,
+                ) -> ::std::result::Result<
+                    StopMatchmakingOutput,
+                    RusotoError<StopMatchmakingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_alias: Option<
+        Box<
+            dyn Fn(
+                    UpdateAliasInput,
+                )
+                    -> ::std::result::Result<UpdateAliasOutput, RusotoError<UpdateAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_build: Option<
+        Box<
+            dyn Fn(
+                    UpdateBuildInput,
+                )
+                    -> ::std::result::Result<UpdateBuildOutput, RusotoError<UpdateBuildError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_fleet_attributes: Option<
+        Box<
+            dyn Fn(
+                    UpdateFleetAttributesInput,
+                ) -> ::std::result::Result<
+                    UpdateFleetAttributesOutput,
+                    RusotoError<UpdateFleetAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_fleet_capacity: Option<
+        Box<
+            dyn Fn(
+                    UpdateFleetCapacityInput,
+                ) -> ::std::result::Result<
+                    UpdateFleetCapacityOutput,
+                    RusotoError<UpdateFleetCapacityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_fleet_port_settings: Option<
+        Box<
+            dyn Fn(
+                    UpdateFleetPortSettingsInput,
+                ) -> ::std::result::Result<
+                    UpdateFleetPortSettingsOutput,
+                    RusotoError<UpdateFleetPortSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_game_session: Option<
+        Box<
+            dyn Fn(
+                    UpdateGameSessionInput,
+                ) -> ::std::result::Result<
+                    UpdateGameSessionOutput,
+                    RusotoError<UpdateGameSessionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_game_session_queue: Option<
+        Box<
+            dyn Fn(
+                    UpdateGameSessionQueueInput,
+                ) -> ::std::result::Result<
+                    UpdateGameSessionQueueOutput,
+                    RusotoError<UpdateGameSessionQueueError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_matchmaking_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateMatchmakingConfigurationInput,
+                ) -> ::std::result::Result<
+                    UpdateMatchmakingConfigurationOutput,
+                    RusotoError<UpdateMatchmakingConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_runtime_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateRuntimeConfigurationInput,
+                ) -> ::std::result::Result<
+                    UpdateRuntimeConfigurationOutput,
+                    RusotoError<UpdateRuntimeConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_script: Option<
+        Box<
+            dyn Fn(
+                    UpdateScriptInput,
+                )
+                    -> ::std::result::Result<UpdateScriptOutput, RusotoError<UpdateScriptError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    validate_matchmaking_rule_set: Option<
+        Box<
+            dyn Fn(
+                    ValidateMatchmakingRuleSetInput,
+                ) -> ::std::result::Result<
+                    ValidateMatchmakingRuleSetOutput,
+                    RusotoError<ValidateMatchmakingRuleSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockGameLift {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockGameLift {
+        Default::default()
+    }
+
+    /// Answers `accept_match` with what `handler` returns for its input.
+    pub fn with_accept_match<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                AcceptMatchInput,
+            )
+                -> ::std::result::Result<AcceptMatchOutput, RusotoError<AcceptMatchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.accept_match = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_alias` with what `handler` returns for its input.
+    pub fn with_create_alias<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreateAliasInput,
+            )
+                -> ::std::result::Result<CreateAliasOutput, RusotoError<CreateAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_build` with what `handler` returns for its input.
+    pub fn with_create_build<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreateBuildInput,
+            )
+                -> ::std::result::Result<CreateBuildOutput, RusotoError<CreateBuildError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_build = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_fleet` with what `handler` returns for its input.
+    pub fn with_create_fleet<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreateFleetInput,
+            )
+                -> ::std::result::Result<CreateFleetOutput, RusotoError<CreateFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_game_session` with what `handler` returns for its input.
+    pub fn with_create_game_session<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreateGameSessionInput,
+            ) -> ::std::result::Result<
+                CreateGameSessionOutput,
+                RusotoError<CreateGameSessionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_game_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_game_session_queue` with what `handler` returns for its input.
+    pub fn with_create_game_session_queue<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreateGameSessionQueueInput,
+            ) -> ::std::result::Result<
+                CreateGameSessionQueueOutput,
+                RusotoError<CreateGameSessionQueueError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_game_session_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_matchmaking_configuration` with what `handler` returns for its input.
+    pub fn with_create_matchmaking_configuration<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreateMatchmakingConfigurationInput,
+            ) -> ::std::result::Result<
+                CreateMatchmakingConfigurationOutput,
+                RusotoError<CreateMatchmakingConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_matchmaking_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_matchmaking_rule_set` with what `handler` returns for its input.
+    pub fn with_create_matchmaking_rule_set<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreateMatchmakingRuleSetInput,
+            ) -> ::std::result::Result<
+                CreateMatchmakingRuleSetOutput,
+                RusotoError<CreateMatchmakingRuleSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_matchmaking_rule_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_player_session` with what `handler` returns for its input.
+    pub fn with_create_player_session<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreatePlayerSessionInput,
+            ) -> ::std::result::Result<
+                CreatePlayerSessionOutput,
+                RusotoError<CreatePlayerSessionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_player_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_player_sessions` with what `handler` returns for its input.
+    pub fn with_create_player_sessions<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreatePlayerSessionsInput,
+            ) -> ::std::result::Result<
+                CreatePlayerSessionsOutput,
+                RusotoError<CreatePlayerSessionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_player_sessions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_script` with what `handler` returns for its input.
+    pub fn with_create_script<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreateScriptInput,
+            )
+                -> ::std::result::Result<CreateScriptOutput, RusotoError<CreateScriptError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_script = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_vpc_peering_authorization` with what `handler` returns for its input.
+    pub fn with_create_vpc_peering_authorization<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreateVpcPeeringAuthorizationInput,
+            ) -> ::std::result::Result<
+                CreateVpcPeeringAuthorizationOutput,
+                RusotoError<CreateVpcPeeringAuthorizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_vpc_peering_authorization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_vpc_peering_connection` with what `handler` returns for its input.
+    pub fn with_create_vpc_peering_connection<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                CreateVpcPeeringConnectionInput,
+            ) -> ::std::result::Result<
+                CreateVpcPeeringConnectionOutput,
+                RusotoError<CreateVpcPeeringConnectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_vpc_peering_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_alias` with what `handler` returns for its input.
+    pub fn with_delete_alias<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(DeleteAliasInput) -> ::std::result::Result<(), RusotoError<DeleteAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_build` with what `handler` returns for its input.
+    pub fn with_delete_build<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(DeleteBuildInput) -> ::std::result::Result<(), RusotoError<DeleteBuildError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_build = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_fleet` with what `handler` returns for its input.
+    pub fn with_delete_fleet<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(DeleteFleetInput) -> ::std::result::Result<(), RusotoError<DeleteFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_game_session_queue` with what `handler` returns for its input.
+    pub fn with_delete_game_session_queue<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DeleteGameSessionQueueInput,
+            ) -> ::std::result::Result<
+                DeleteGameSessionQueueOutput,
+                RusotoError<DeleteGameSessionQueueError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_game_session_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_matchmaking_configuration` with what `handler` returns for its input.
+    pub fn with_delete_matchmaking_configuration<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DeleteMatchmakingConfigurationInput,
+            ) -> ::std::result::Result<
+                DeleteMatchmakingConfigurationOutput,
+                RusotoError<DeleteMatchmakingConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_matchmaking_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_matchmaking_rule_set` with what `handler` returns for its input.
+    pub fn with_delete_matchmaking_rule_set<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DeleteMatchmakingRuleSetInput,
+            ) -> ::std::result::Result<
+                DeleteMatchmakingRuleSetOutput,
+                RusotoError<DeleteMatchmakingRuleSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_matchmaking_rule_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_scaling_policy` with what `handler` returns for its input.
+    pub fn with_delete_scaling_policy<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DeleteScalingPolicyInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteScalingPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_scaling_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_script` with what `handler` returns for its input.
+    pub fn with_delete_script<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(DeleteScriptInput) -> ::std::result::Result<(), RusotoError<DeleteScriptError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_script = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_vpc_peering_authorization` with what `handler` returns for its input.
+    pub fn with_delete_vpc_peering_authorization<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DeleteVpcPeeringAuthorizationInput,
+            ) -> ::std::result::Result<
+                DeleteVpcPeeringAuthorizationOutput,
+                RusotoError<DeleteVpcPeeringAuthorizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_vpc_peering_authorization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_vpc_peering_connection` with what `handler` returns for its input.
+    pub fn with_delete_vpc_peering_connection<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DeleteVpcPeeringConnectionInput,
+            ) -> ::std::result::Result<
+                DeleteVpcPeeringConnectionOutput,
+                RusotoError<DeleteVpcPeeringConnectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_vpc_peering_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_alias` with what `handler` returns for its input.
+    pub fn with_describe_alias<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeAliasInput,
+            )
+                -> ::std::result::Result<DescribeAliasOutput, RusotoError<DescribeAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_build` with what `handler` returns for its input.
+    pub fn with_describe_build<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeBuildInput,
+            )
+                -> ::std::result::Result<DescribeBuildOutput, RusotoError<DescribeBuildError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_build = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_ec2_instance_limits` with what `handler` returns for its input.
+    pub fn with_describe_ec2_instance_limits<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeEC2InstanceLimitsInput,
+            ) -> ::std::result::Result<
+                DescribeEC2InstanceLimitsOutput,
+                RusotoError<DescribeEC2InstanceLimitsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_ec2_instance_limits = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_fleet_attributes` with what `handler` returns for its input.
+    pub fn with_describe_fleet_attributes<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeFleetAttributesInput,
+            ) -> ::std::result::Result<
+                DescribeFleetAttributesOutput,
+                RusotoError<DescribeFleetAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_fleet_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_fleet_capacity` with what `handler` returns for its input.
+    pub fn with_describe_fleet_capacity<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeFleetCapacityInput,
+            ) -> ::std::result::Result<
+                DescribeFleetCapacityOutput,
+                RusotoError<DescribeFleetCapacityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_fleet_capacity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_fleet_events` with what `handler` returns for its input.
+    pub fn with_describe_fleet_events<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeFleetEventsInput,
+            ) -> ::std::result::Result<
+                DescribeFleetEventsOutput,
+                RusotoError<DescribeFleetEventsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_fleet_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_fleet_port_settings` with what `handler` returns for its input.
+    pub fn with_describe_fleet_port_settings<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeFleetPortSettingsInput,
+            ) -> ::std::result::Result<
+                DescribeFleetPortSettingsOutput,
+                RusotoError<DescribeFleetPortSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_fleet_port_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_fleet_utilization` with what `handler` returns for its input.
+    pub fn with_describe_fleet_utilization<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeFleetUtilizationInput,
+            ) -> ::std::result::Result<
+                DescribeFleetUtilizationOutput,
+                RusotoError<DescribeFleetUtilizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_fleet_utilization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_game_session_details` with what `handler` returns for its input.
+    pub fn with_describe_game_session_details<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeGameSessionDetailsInput,
+            ) -> ::std::result::Result<
+                DescribeGameSessionDetailsOutput,
+                RusotoError<DescribeGameSessionDetailsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_game_session_details = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_game_session_placement` with what `handler` returns for its input.
+    pub fn with_describe_game_session_placement<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeGameSessionPlacementInput,
+            ) -> ::std::result::Result<
+                DescribeGameSessionPlacementOutput,
+                RusotoError<DescribeGameSessionPlacementError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_game_session_placement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_game_session_queues` with what `handler` returns for its input.
+    pub fn with_describe_game_session_queues<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeGameSessionQueuesInput,
+            ) -> ::std::result::Result<
+                DescribeGameSessionQueuesOutput,
+                RusotoError<DescribeGameSessionQueuesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_game_session_queues = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_game_sessions` with what `handler` returns for its input.
+    pub fn with_describe_game_sessions<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeGameSessionsInput,
+            ) -> ::std::result::Result<
+                DescribeGameSessionsOutput,
+                RusotoError<DescribeGameSessionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_game_sessions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_instances` with what `handler` returns for its input.
+    pub fn with_describe_instances<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeInstancesInput,
+            ) -> ::std::result::Result<
+                DescribeInstancesOutput,
+                RusotoError<DescribeInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_matchmaking` with what `handler` returns for its input.
+    pub fn with_describe_matchmaking<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeMatchmakingInput,
+            ) -> ::std::result::Result<
+                DescribeMatchmakingOutput,
+                RusotoError<DescribeMatchmakingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_matchmaking = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_matchmaking_configurations` with what `handler` returns for its input.
+    pub fn with_describe_matchmaking_configurations<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeMatchmakingConfigurationsInput,
+            ) -> ::std::result::Result<
+                DescribeMatchmakingConfigurationsOutput,
+                RusotoError<DescribeMatchmakingConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_matchmaking_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_matchmaking_rule_sets` with what `handler` returns for its input.
+    pub fn with_describe_matchmaking_rule_sets<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeMatchmakingRuleSetsInput,
+            ) -> ::std::result::Result<
+                DescribeMatchmakingRuleSetsOutput,
+                RusotoError<DescribeMatchmakingRuleSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_matchmaking_rule_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_player_sessions` with what `handler` returns for its input.
+    pub fn with_describe_player_sessions<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribePlayerSessionsInput,
+            ) -> ::std::result::Result<
+                DescribePlayerSessionsOutput,
+                RusotoError<DescribePlayerSessionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_player_sessions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_runtime_configuration` with what `handler` returns for its input.
+    pub fn with_describe_runtime_configuration<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeRuntimeConfigurationInput,
+            ) -> ::std::result::Result<
+                DescribeRuntimeConfigurationOutput,
+                RusotoError<DescribeRuntimeConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_runtime_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_scaling_policies` with what `handler` returns for its input.
+    pub fn with_describe_scaling_policies<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeScalingPoliciesInput,
+            ) -> ::std::result::Result<
+                DescribeScalingPoliciesOutput,
+                RusotoError<DescribeScalingPoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_scaling_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_script` with what `handler` returns for its input.
+    pub fn with_describe_script<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeScriptInput,
+            )
+                -> ::std::result::Result<DescribeScriptOutput, RusotoError<DescribeScriptError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_script = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_vpc_peering_authorizations` with what `handler` returns.
+    pub fn with_describe_vpc_peering_authorizations<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeVpcPeeringAuthorizationsOutput,
+                RusotoError<DescribeVpcPeeringAuthorizationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_vpc_peering_authorizations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_vpc_peering_connections` with what `handler` returns for its input.
+    pub fn with_describe_vpc_peering_connections<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                DescribeVpcPeeringConnectionsInput,
+            ) -> ::std::result::Result<
+                DescribeVpcPeeringConnectionsOutput,
+                RusotoError<DescribeVpcPeeringConnectionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_vpc_peering_connections = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_game_session_log_url` with what `handler` returns for its input.
+    pub fn with_get_game_session_log_url<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                GetGameSessionLogUrlInput,
+            ) -> ::std::result::Result<
+                GetGameSessionLogUrlOutput,
+                RusotoError<GetGameSessionLogUrlError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_game_session_log_url = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instance_access` with what `handler` returns for its input.
+    pub fn with_get_instance_access<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                GetInstanceAccessInput,
+            ) -> ::std::result::Result<
+                GetInstanceAccessOutput,
+                RusotoError<GetInstanceAccessError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instance_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_aliases` with what `handler` returns for its input.
+    pub fn with_list_aliases<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                ListAliasesInput,
+            )
+                -> ::std::result::Result<ListAliasesOutput, RusotoError<ListAliasesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_aliases = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_builds` with what `handler` returns for its input.
+    pub fn with_list_builds<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                ListBuildsInput,
+            ) -> ::std::result::Result<ListBuildsOutput, RusotoError<ListBuildsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_builds = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_fleets` with what `handler` returns for its input.
+    pub fn with_list_fleets<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                ListFleetsInput,
+            ) -> ::std::result::Result<ListFleetsOutput, RusotoError<ListFleetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_fleets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_scripts` with what `handler` returns for its input.
+    pub fn with_list_scripts<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                ListScriptsInput,
+            )
+                -> ::std::result::Result<ListScriptsOutput, RusotoError<ListScriptsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_scripts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_scaling_policy` with what `handler` returns for its input.
+    pub fn with_put_scaling_policy<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                PutScalingPolicyInput,
+            )
+                -> ::std::result::Result<PutScalingPolicyOutput, RusotoError<PutScalingPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_scaling_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `request_upload_credentials` with what `handler` returns for its input.
+    pub fn with_request_upload_credentials<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                RequestUploadCredentialsInput,
+            ) -> ::std::result::Result<
+                RequestUploadCredentialsOutput,
+                RusotoError<RequestUploadCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.request_upload_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `resolve_alias` with what `handler` returns for its input.
+    pub fn with_resolve_alias<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                ResolveAliasInput,
+            )
+                -> ::std::result::Result<ResolveAliasOutput, RusotoError<ResolveAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resolve_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_game_sessions` with what `handler` returns for its input.
+    pub fn with_search_game_sessions<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                SearchGameSessionsInput,
+            ) -> ::std::result::Result<
+                SearchGameSessionsOutput,
+                RusotoError<SearchGameSessionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.search_game_sessions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_fleet_actions` with what `handler` returns for its input.
+    pub fn with_start_fleet_actions<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                StartFleetActionsInput,
+            ) -> ::std::result::Result<
+                StartFleetActionsOutput,
+                RusotoError<StartFleetActionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_fleet_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_game_session_placement` with what `handler` returns for its input.
+    pub fn with_start_game_session_placement<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                StartGameSessionPlacementInput,
+            ) -> ::std::result::Result<
+                StartGameSessionPlacementOutput,
+                RusotoError<StartGameSessionPlacementError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_game_session_placement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_match_backfill` with what `handler` returns for its input.
+    pub fn with_start_match_backfill<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                StartMatchBackfillInput,
+            ) -> ::std::result::Result<
+                StartMatchBackfillOutput,
+                RusotoError<StartMatchBackfillError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_match_backfill = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_matchmaking` with what `handler` returns for its input.
+    pub fn with_start_matchmaking<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                StartMatchmakingInput,
+            )
+                -> ::std::result::Result<StartMatchmakingOutput, RusotoError<StartMatchmakingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_matchmaking = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_fleet_actions` with what `handler` returns for its input.
+    pub fn with_stop_fleet_actions<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                StopFleetActionsInput,
+            )
+                -> ::std::result::Result<StopFleetActionsOutput, RusotoError<StopFleetActionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_fleet_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_game_session_placement` with what `handler` returns for its input.
+    pub fn with_stop_game_session_placement<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                StopGameSessionPlacementInput,
+            ) -> ::std::result::Result<
+                StopGameSessionPlacementOutput,
+                RusotoError<StopGameSessionPlacementError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_game_session_placement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_matchmaking` with what `handler` returns for its input.
+    pub fn with_stop_matchmaking<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                StopMatchmakingInput,
+            )
+                -> ::std::result::Result<StopMatchmakingOutput, RusotoError<StopMatchmakingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_matchmaking = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_alias` with what `handler` returns for its input.
+    pub fn with_update_alias<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                UpdateAliasInput,
+            )
+                -> ::std::result::Result<UpdateAliasOutput, RusotoError<UpdateAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_build` with what `handler` returns for its input.
+    pub fn with_update_build<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                UpdateBuildInput,
+            )
+                -> ::std::result::Result<UpdateBuildOutput, RusotoError<UpdateBuildError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_build = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_fleet_attributes` with what `handler` returns for its input.
+    pub fn with_update_fleet_attributes<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                UpdateFleetAttributesInput,
+            ) -> ::std::result::Result<
+                UpdateFleetAttributesOutput,
+                RusotoError<UpdateFleetAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_fleet_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_fleet_capacity` with what `handler` returns for its input.
+    pub fn with_update_fleet_capacity<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                UpdateFleetCapacityInput,
+            ) -> ::std::result::Result<
+                UpdateFleetCapacityOutput,
+                RusotoError<UpdateFleetCapacityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_fleet_capacity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_fleet_port_settings` with what `handler` returns for its input.
+    pub fn with_update_fleet_port_settings<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                UpdateFleetPortSettingsInput,
+            ) -> ::std::result::Result<
+                UpdateFleetPortSettingsOutput,
+                RusotoError<UpdateFleetPortSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_fleet_port_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_game_session` with what `handler` returns for its input.
+    pub fn with_update_game_session<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                UpdateGameSessionInput,
+            ) -> ::std::result::Result<
+                UpdateGameSessionOutput,
+                RusotoError<UpdateGameSessionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_game_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_game_session_queue` with what `handler` returns for its input.
+    pub fn with_update_game_session_queue<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                UpdateGameSessionQueueInput,
+            ) -> ::std::result::Result<
+                UpdateGameSessionQueueOutput,
+                RusotoError<UpdateGameSessionQueueError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_game_session_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_matchmaking_configuration` with what `handler` returns for its input.
+    pub fn with_update_matchmaking_configuration<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                UpdateMatchmakingConfigurationInput,
+            ) -> ::std::result::Result<
+                UpdateMatchmakingConfigurationOutput,
+                RusotoError<UpdateMatchmakingConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_matchmaking_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_runtime_configuration` with what `handler` returns for its input.
+    pub fn with_update_runtime_configuration<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                UpdateRuntimeConfigurationInput,
+            ) -> ::std::result::Result<
+                UpdateRuntimeConfigurationOutput,
+                RusotoError<UpdateRuntimeConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_runtime_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_script` with what `handler` returns for its input.
+    pub fn with_update_script<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                UpdateScriptInput,
+            )
+                -> ::std::result::Result<UpdateScriptOutput, RusotoError<UpdateScriptError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_script = Some(Box::new(handler));
+        self
+    }
+    /// Answers `validate_matchmaking_rule_set` with what `handler` returns for its input.
+    pub fn with_validate_matchmaking_rule_set<F>(mut self, handler: F) -> MockGameLift
+    where
+        F: Fn(
+                ValidateMatchmakingRuleSetInput,
+            ) -> ::std::result::Result<
+                ValidateMatchmakingRuleSetOutput,
+                RusotoError<ValidateMatchmakingRuleSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.validate_matchmaking_rule_set = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl GameLift for MockGameLift {
+    fn accept_match(
+        &self,
+        input: AcceptMatchInput,
+    ) -> RusotoFuture<AcceptMatchOutput, AcceptMatchError> {
+        match self.accept_match {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for accept_match"),
+        }
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasInput,
+    ) -> RusotoFuture<CreateAliasOutput, CreateAliasError> {
+        match self.create_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_alias"),
+        }
+    }
+    fn create_build(
+        &self,
+        input: CreateBuildInput,
+    ) -> RusotoFuture<CreateBuildOutput, CreateBuildError> {
+        match self.create_build {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_build"),
+        }
+    }
+    fn create_fleet(
+        &self,
+        input: CreateFleetInput,
+    ) -> RusotoFuture<CreateFleetOutput, CreateFleetError> {
+        match self.create_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_fleet"),
+        }
+    }
+    fn create_game_session(
+        &self,
+        input: CreateGameSessionInput,
+    ) -> RusotoFuture<CreateGameSessionOutput, CreateGameSessionError> {
+        match self.create_game_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_game_session"),
+        }
+    }
+    fn create_game_session_queue(
+        &self,
+        input: CreateGameSessionQueueInput,
+    ) -> RusotoFuture<CreateGameSessionQueueOutput, CreateGameSessionQueueError> {
+        match self.create_game_session_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_game_session_queue"),
+        }
+    }
+    fn create_matchmaking_configuration(
+        &self,
+        input: CreateMatchmakingConfigurationInput,
+    ) -> RusotoFuture<CreateMatchmakingConfigurationOutput, CreateMatchmakingConfigurationError>
+    {
+        match self.create_matchmaking_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_matchmaking_configuration"),
+        }
+    }
+    fn create_matchmaking_rule_set(
+        &self,
+        input: CreateMatchmakingRuleSetInput,
+    ) -> RusotoFuture<CreateMatchmakingRuleSetOutput, CreateMatchmakingRuleSetError> {
+        match self.create_matchmaking_rule_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_matchmaking_rule_set"),
+        }
+    }
+    fn create_player_session(
+        &self,
+        input: CreatePlayerSessionInput,
+    ) -> RusotoFuture<CreatePlayerSessionOutput, CreatePlayerSessionError> {
+        match self.create_player_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_player_session"),
+        }
+    }
+    fn create_player_sessions(
+        &self,
+        input: CreatePlayerSessionsInput,
+    ) -> RusotoFuture<CreatePlayerSessionsOutput, CreatePlayerSessionsError> {
+        match self.create_player_sessions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_player_sessions"),
+        }
+    }
+    fn create_script(
+        &self,
+        input: CreateScriptInput,
+    ) -> RusotoFuture<CreateScriptOutput, CreateScriptError> {
+        match self.create_script {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_script"),
+        }
+    }
+    fn create_vpc_peering_authorization(
+        &self,
+        input: CreateVpcPeeringAuthorizationInput,
+    ) -> RusotoFuture<CreateVpcPeeringAuthorizationOutput, CreateVpcPeeringAuthorizationError> {
+        match self.create_vpc_peering_authorization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_vpc_peering_authorization"),
+        }
+    }
+    fn create_vpc_peering_connection(
+        &self,
+        input: CreateVpcPeeringConnectionInput,
+    ) -> RusotoFuture<CreateVpcPeeringConnectionOutput, CreateVpcPeeringConnectionError> {
+        match self.create_vpc_peering_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for create_vpc_peering_connection"),
+        }
+    }
+    fn delete_alias(&self, input: DeleteAliasInput) -> RusotoFuture<(), DeleteAliasError> {
+        match self.delete_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for delete_alias"),
+        }
+    }
+    fn delete_build(&self, input: DeleteBuildInput) -> RusotoFuture<(), DeleteBuildError> {
+        match self.delete_build {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for delete_build"),
+        }
+    }
+    fn delete_fleet(&self, input: DeleteFleetInput) -> RusotoFuture<(), DeleteFleetError> {
+        match self.delete_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for delete_fleet"),
+        }
+    }
+    fn delete_game_session_queue(
+        &self,
+        input: DeleteGameSessionQueueInput,
+    ) -> RusotoFuture<DeleteGameSessionQueueOutput, DeleteGameSessionQueueError> {
+        match self.delete_game_session_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for delete_game_session_queue"),
+        }
+    }
+    fn delete_matchmaking_configuration(
+        &self,
+        input: DeleteMatchmakingConfigurationInput,
+    ) -> RusotoFuture<DeleteMatchmakingConfigurationOutput, DeleteMatchmakingConfigurationError>
+    {
+        match self.delete_matchmaking_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for delete_matchmaking_configuration"),
+        }
+    }
+    fn delete_matchmaking_rule_set(
+        &self,
+        input: DeleteMatchmakingRuleSetInput,
+    ) -> RusotoFuture<DeleteMatchmakingRuleSetOutput, DeleteMatchmakingRuleSetError> {
+        match self.delete_matchmaking_rule_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for delete_matchmaking_rule_set"),
+        }
+    }
+    fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyInput,
+    ) -> RusotoFuture<(), DeleteScalingPolicyError> {
+        match self.delete_scaling_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for delete_scaling_policy"),
+        }
+    }
+    fn delete_script(&self, input: DeleteScriptInput) -> RusotoFuture<(), DeleteScriptError> {
+        match self.delete_script {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for delete_script"),
+        }
+    }
+    fn delete_vpc_peering_authorization(
+        &self,
+        input: DeleteVpcPeeringAuthorizationInput,
+    ) -> RusotoFuture<DeleteVpcPeeringAuthorizationOutput, DeleteVpcPeeringAuthorizationError> {
+        match self.delete_vpc_peering_authorization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for delete_vpc_peering_authorization"),
+        }
+    }
+    fn delete_vpc_peering_connection(
+        &self,
+        input: DeleteVpcPeeringConnectionInput,
+    ) -> RusotoFuture<DeleteVpcPeeringConnectionOutput, DeleteVpcPeeringConnectionError> {
+        match self.delete_vpc_peering_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for delete_vpc_peering_connection"),
+        }
+    }
+    fn describe_alias(
+        &self,
+        input: DescribeAliasInput,
+    ) -> RusotoFuture<DescribeAliasOutput, DescribeAliasError> {
+        match self.describe_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_alias"),
+        }
+    }
+    fn describe_build(
+        &self,
+        input: DescribeBuildInput,
+    ) -> RusotoFuture<DescribeBuildOutput, DescribeBuildError> {
+        match self.describe_build {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_build"),
+        }
+    }
+    fn describe_ec2_instance_limits(
+        &self,
+        input: DescribeEC2InstanceLimitsInput,
+    ) -> RusotoFuture<DescribeEC2InstanceLimitsOutput, DescribeEC2InstanceLimitsError> {
+        match self.describe_ec2_instance_limits {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_ec2_instance_limits"),
+        }
+    }
+    fn describe_fleet_attributes(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> RusotoFuture<DescribeFleetAttributesOutput, DescribeFleetAttributesError> {
+        match self.describe_fleet_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_fleet_attributes"),
+        }
+    }
+    fn describe_fleet_capacity(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> RusotoFuture<DescribeFleetCapacityOutput, DescribeFleetCapacityError> {
+        match self.describe_fleet_capacity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_fleet_capacity"),
+        }
+    }
+    fn describe_fleet_events(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> RusotoFuture<DescribeFleetEventsOutput, DescribeFleetEventsError> {
+        match self.describe_fleet_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_fleet_events"),
+        }
+    }
+    fn describe_fleet_port_settings(
+        &self,
+        input: DescribeFleetPortSettingsInput,
+    ) -> RusotoFuture<DescribeFleetPortSettingsOutput, DescribeFleetPortSettingsError> {
+        match self.describe_fleet_port_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_fleet_port_settings"),
+        }
+    }
+    fn describe_fleet_utilization(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> RusotoFuture<DescribeFleetUtilizationOutput, DescribeFleetUtilizationError> {
+        match self.describe_fleet_utilization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_fleet_utilization"),
+        }
+    }
+    fn describe_game_session_details(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> RusotoFuture<DescribeGameSessionDetailsOutput, DescribeGameSessionDetailsError> {
+        match self.describe_game_session_details {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_game_session_details"),
+        }
+    }
+    fn describe_game_session_placement(
+        &self,
+        input: DescribeGameSessionPlacementInput,
+    ) -> RusotoFuture<DescribeGameSessionPlacementOutput, DescribeGameSessionPlacementError> {
+        match self.describe_game_session_placement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_game_session_placement"),
+        }
+    }
+    fn describe_game_session_queues(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> RusotoFuture<DescribeGameSessionQueuesOutput, DescribeGameSessionQueuesError> {
+        match self.describe_game_session_queues {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_game_session_queues"),
+        }
+    }
+    fn describe_game_sessions(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> RusotoFuture<DescribeGameSessionsOutput, DescribeGameSessionsError> {
+        match self.describe_game_sessions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_game_sessions"),
+        }
+    }
+    fn describe_instances(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> RusotoFuture<DescribeInstancesOutput, DescribeInstancesError> {
+        match self.describe_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_instances"),
+        }
+    }
+    fn describe_matchmaking(
+        &self,
+        input: DescribeMatchmakingInput,
+    ) -> RusotoFuture<DescribeMatchmakingOutput, DescribeMatchmakingError> {
+        match self.describe_matchmaking {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_matchmaking"),
+        }
+    }
+    fn describe_matchmaking_configurations(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> RusotoFuture<DescribeMatchmakingConfigurationsOutput, DescribeMatchmakingConfigurationsError>
+    {
+        match self.describe_matchmaking_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_matchmaking_configurations"),
+        }
+    }
+    fn describe_matchmaking_rule_sets(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> RusotoFuture<DescribeMatchmakingRuleSetsOutput, DescribeMatchmakingRuleSetsError> {
+        match self.describe_matchmaking_rule_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_matchmaking_rule_sets"),
+        }
+    }
+    fn describe_player_sessions(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> RusotoFuture<DescribePlayerSessionsOutput, DescribePlayerSessionsError> {
+        match self.describe_player_sessions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_player_sessions"),
+        }
+    }
+    fn describe_runtime_configuration(
+        &self,
+        input: DescribeRuntimeConfigurationInput,
+    ) -> RusotoFuture<DescribeRuntimeConfigurationOutput, DescribeRuntimeConfigurationError> {
+        match self.describe_runtime_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_runtime_configuration"),
+        }
+    }
+    fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> RusotoFuture<DescribeScalingPoliciesOutput, DescribeScalingPoliciesError> {
+        match self.describe_scaling_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_scaling_policies"),
+        }
+    }
+    fn describe_script(
+        &self,
+        input: DescribeScriptInput,
+    ) -> RusotoFuture<DescribeScriptOutput, DescribeScriptError> {
+        match self.describe_script {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_script"),
+        }
+    }
+    fn describe_vpc_peering_authorizations(
+        &self,
+    ) -> RusotoFuture<DescribeVpcPeeringAuthorizationsOutput, DescribeVpcPeeringAuthorizationsError>
+    {
+        match self.describe_vpc_peering_authorizations {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockGameLift has no handler for describe_vpc_peering_authorizations"),
+        }
+    }
+    fn describe_vpc_peering_connections(
+        &self,
+        input: DescribeVpcPeeringConnectionsInput,
+    ) -> RusotoFuture<DescribeVpcPeeringConnectionsOutput, DescribeVpcPeeringConnectionsError> {
+        match self.describe_vpc_peering_connections {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for describe_vpc_peering_connections"),
+        }
+    }
+    fn get_game_session_log_url(
+        &self,
+        input: GetGameSessionLogUrlInput,
+    ) -> RusotoFuture<GetGameSessionLogUrlOutput, GetGameSessionLogUrlError> {
+        match self.get_game_session_log_url {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for get_game_session_log_url"),
+        }
+    }
+    fn get_instance_access(
+        &self,
+        input: GetInstanceAccessInput,
+    ) -> RusotoFuture<GetInstanceAccessOutput, GetInstanceAccessError> {
+        match self.get_instance_access {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for get_instance_access"),
+        }
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesInput,
+    ) -> RusotoFuture<ListAliasesOutput, ListAliasesError> {
+        match self.list_aliases {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for list_aliases"),
+        }
+    }
+    fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
+        match self.list_builds {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for list_builds"),
+        }
+    }
+    fn list_fleets(
+        &self,
+        input: ListFleetsInput,
+    ) -> RusotoFuture<ListFleetsOutput, ListFleetsError> {
+        match self.list_fleets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for list_fleets"),
+        }
+    }
+    fn list_scripts(
+        &self,
+        input: ListScriptsInput,
+    ) -> RusotoFuture<ListScriptsOutput, ListScriptsError> {
+        match self.list_scripts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for list_scripts"),
+        }
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyInput,
+    ) -> RusotoFuture<PutScalingPolicyOutput, PutScalingPolicyError> {
+        match self.put_scaling_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for put_scaling_policy"),
+        }
+    }
+    fn request_upload_credentials(
+        &self,
+        input: RequestUploadCredentialsInput,
+    ) -> RusotoFuture<RequestUploadCredentialsOutput, RequestUploadCredentialsError> {
+        match self.request_upload_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for request_upload_credentials"),
+        }
+    }
+    fn resolve_alias(
+        &self,
+        input: ResolveAliasInput,
+    ) -> RusotoFuture<ResolveAliasOutput, ResolveAliasError> {
+        match self.resolve_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for resolve_alias"),
+        }
+    }
+    fn search_game_sessions(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> RusotoFuture<SearchGameSessionsOutput, SearchGameSessionsError> {
+        match self.search_game_sessions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for search_game_sessions"),
+        }
+    }
+    fn start_fleet_actions(
+        &self,
+        input: StartFleetActionsInput,
+    ) -> RusotoFuture<StartFleetActionsOutput, StartFleetActionsError> {
+        match self.start_fleet_actions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for start_fleet_actions"),
+        }
+    }
+    fn start_game_session_placement(
+        &self,
+        input: StartGameSessionPlacementInput,
+    ) -> RusotoFuture<StartGameSessionPlacementOutput, StartGameSessionPlacementError> {
+        match self.start_game_session_placement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for start_game_session_placement"),
+        }
+    }
+    fn start_match_backfill(
+        &self,
+        input: StartMatchBackfillInput,
+    ) -> RusotoFuture<StartMatchBackfillOutput, StartMatchBackfillError> {
+        match self.start_match_backfill {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for start_match_backfill"),
+        }
+    }
+    fn start_matchmaking(
+        &self,
+        input: StartMatchmakingInput,
+    ) -> RusotoFuture<StartMatchmakingOutput, StartMatchmakingError> {
+        match self.start_matchmaking {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for start_matchmaking"),
+        }
+    }
+    fn stop_fleet_actions(
+        &self,
+        input: StopFleetActionsInput,
+    ) -> RusotoFuture<StopFleetActionsOutput, StopFleetActionsError> {
+        match self.stop_fleet_actions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for stop_fleet_actions"),
+        }
+    }
+    fn stop_game_session_placement(
+        &self,
+        input: StopGameSessionPlacementInput,
+    ) -> RusotoFuture<StopGameSessionPlacementOutput, StopGameSessionPlacementError> {
+        match self.stop_game_session_placement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for stop_game_session_placement"),
+        }
+    }
+    fn stop_matchmaking(
+        &self,
+        input: StopMatchmakingInput,
+    ) -> RusotoFuture<StopMatchmakingOutput, StopMatchmakingError> {
+        match self.stop_matchmaking {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for stop_matchmaking"),
+        }
     }
-
-    /// <p><p>Updates properties for an alias. To update properties, specify the alias ID to be updated and provide the information to be changed. To reassign an alias to another fleet, provide an updated routing strategy. If successful, the updated alias record is returned.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
     fn update_alias(
         &self,
         input: UpdateAliasInput,
     ) -> RusotoFuture<UpdateAliasOutput, UpdateAliasError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.UpdateAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAliasOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAliasError::from_response(response))),
-                )
-            }
-        })
+        match self.update_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for update_alias"),
+        }
     }
-
-    /// <p><p>Updates metadata in a build record, including the build name and version. To update the metadata, specify the build ID to update and provide the new values. If successful, a build object containing the updated metadata is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
     fn update_build(
         &self,
         input: UpdateBuildInput,
     ) -> RusotoFuture<UpdateBuildOutput, UpdateBuildError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.UpdateBuild");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateBuildOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateBuildError::from_response(response))),
-                )
-            }
-        })
+        match self.update_build {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for update_build"),
+        }
     }
-
-    /// <p><p>Updates fleet properties, including name and description, for a fleet. To update metadata, specify the fleet ID and the property values that you want to change. If successful, the fleet ID for the updated fleet is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn update_fleet_attributes(
         &self,
         input: UpdateFleetAttributesInput,
     ) -> RusotoFuture<UpdateFleetAttributesOutput, UpdateFleetAttributesError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.UpdateFleetAttributes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFleetAttributesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateFleetAttributesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_fleet_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for update_fleet_attributes"),
+        }
     }
-
-    /// <p><p>Updates capacity settings for a fleet. Use this action to specify the number of EC2 instances (hosts) that you want this fleet to contain. Before calling this action, you may want to call <a>DescribeEC2InstanceLimits</a> to get the maximum capacity based on the fleet&#39;s EC2 instance type.</p> <p>Specify minimum and maximum number of instances. Amazon GameLift will not change fleet capacity to values fall outside of this range. This is particularly important when using auto-scaling (see <a>PutScalingPolicy</a>) to allow capacity to adjust based on player demand while imposing limits on automatic adjustments.</p> <p>To update fleet capacity, specify the fleet ID and the number of instances you want the fleet to host. If successful, Amazon GameLift starts or terminates instances so that the fleet&#39;s active instance count matches the desired instance count. You can view a fleet&#39;s current capacity information by calling <a>DescribeFleetCapacity</a>. If the desired instance count is higher than the instance type&#39;s limit, the &quot;Limit Exceeded&quot; exception occurs.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn update_fleet_capacity(
         &self,
         input: UpdateFleetCapacityInput,
     ) -> RusotoFuture<UpdateFleetCapacityOutput, UpdateFleetCapacityError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.UpdateFleetCapacity");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFleetCapacityOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateFleetCapacityError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_fleet_capacity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for update_fleet_capacity"),
+        }
     }
-
-    /// <p><p>Updates port settings for a fleet. To update settings, specify the fleet ID to be updated and list the permissions you want to update. List the permissions you want to add in <code>InboundPermissionAuthorizations</code>, and permissions you want to remove in <code>InboundPermissionRevocations</code>. Permissions to be removed must match existing fleet permissions. If successful, the fleet ID for the updated fleet is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn update_fleet_port_settings(
         &self,
         input: UpdateFleetPortSettingsInput,
     ) -> RusotoFuture<UpdateFleetPortSettingsOutput, UpdateFleetPortSettingsError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.UpdateFleetPortSettings");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFleetPortSettingsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateFleetPortSettingsError::from_response(response))
-                }))
-            }
-        })
+        match self.update_fleet_port_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for update_fleet_port_settings"),
+        }
     }
-
-    /// <p><p>Updates game session properties. This includes the session name, maximum player count, protection policy, which controls whether or not an active game session can be terminated during a scale-down event, and the player session creation policy, which controls whether or not new players can join the session. To update a game session, specify the game session ID and the values you want to change. If successful, an updated <a>GameSession</a> object is returned. </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
     fn update_game_session(
         &self,
         input: UpdateGameSessionInput,
     ) -> RusotoFuture<UpdateGameSessionOutput, UpdateGameSessionError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.UpdateGameSession");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGameSessionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateGameSessionError::from_response(response))),
-                )
-            }
-        })
+        match self.update_game_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for update_game_session"),
+        }
     }
-
-    /// <p><p>Updates settings for a game session queue, which determines how new game session requests in the queue are processed. To update settings, specify the queue name to be updated and provide the new settings. When updating destinations, provide a complete list of destinations. </p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
     fn update_game_session_queue(
         &self,
         input: UpdateGameSessionQueueInput,
     ) -> RusotoFuture<UpdateGameSessionQueueOutput, UpdateGameSessionQueueError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.UpdateGameSessionQueue");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGameSessionQueueOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateGameSessionQueueError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_game_session_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for update_game_session_queue"),
+        }
     }
-
-    /// <p><p>Updates settings for a FlexMatch matchmaking configuration. These changes affect all matches and game sessions that are created after the update. To update settings, specify the configuration name to be updated and provide the new settings. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html"> Design a FlexMatch Matchmaker</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
     fn update_matchmaking_configuration(
         &self,
         input: UpdateMatchmakingConfigurationInput,
     ) -> RusotoFuture<UpdateMatchmakingConfigurationOutput, UpdateMatchmakingConfigurationError>
     {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.UpdateMatchmakingConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateMatchmakingConfigurationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateMatchmakingConfigurationError::from_response(response))
-                }))
-            }
-        })
+        match self.update_matchmaking_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for update_matchmaking_configuration"),
+        }
     }
-
-    /// <p><p>Updates the current run-time configuration for the specified fleet, which tells Amazon GameLift how to launch server processes on instances in the fleet. You can update a fleet&#39;s run-time configuration at any time after the fleet is created; it does not need to be in an <code>ACTIVE</code> status.</p> <p>To update run-time configuration, specify the fleet ID and provide a <code>RuntimeConfiguration</code> object with an updated set of server process configurations.</p> <p>Each instance in a Amazon GameLift fleet checks regularly for an updated run-time configuration and changes how it launches server processes to comply with the latest version. Existing server processes are not affected by the update; run-time configuration changes are applied gradually as existing processes shut down and new processes are launched during Amazon GameLift&#39;s normal process recycling activity.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
     fn update_runtime_configuration(
         &self,
         input: UpdateRuntimeConfigurationInput,
     ) -> RusotoFuture<UpdateRuntimeConfigurationOutput, UpdateRuntimeConfigurationError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.UpdateRuntimeConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRuntimeConfigurationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateRuntimeConfigurationError::from_response(response))
-                }))
-            }
-        })
+        match self.update_runtime_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for update_runtime_configuration"),
+        }
     }
-
-    /// <p><p>Updates Realtime script metadata and content.</p> <p>To update script metadata, specify the script ID and provide updated name and/or version values. </p> <p>To update script content, provide an updated zip file by pointing to either a local file or an Amazon S3 bucket location. You can use either method regardless of how the original script was uploaded. Use the <i>Version</i> parameter to track updates to the script.</p> <p>If the call is successful, the updated metadata is stored in the script record and a revised script is uploaded to the Amazon GameLift service. Once the script is updated and acquired by a fleet instance, the new version is used for all new game sessions. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
     fn update_script(
         &self,
         input: UpdateScriptInput,
     ) -> RusotoFuture<UpdateScriptOutput, UpdateScriptError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.UpdateScript");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateScriptOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateScriptError::from_response(response))),
-                )
-            }
-        })
+        match self.update_script {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for update_script"),
+        }
     }
-
-    /// <p><p>Validates the syntax of a matchmaking rule or rule set. This operation checks that the rule set is using syntactically correct JSON and that it conforms to allowed property expressions. To validate syntax, provide a rule set JSON string.</p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
     fn validate_matchmaking_rule_set(
         &self,
         input: ValidateMatchmakingRuleSetInput,
     ) -> RusotoFuture<ValidateMatchmakingRuleSetOutput, ValidateMatchmakingRuleSetError> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.ValidateMatchmakingRuleSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ValidateMatchmakingRuleSetOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ValidateMatchmakingRuleSetError::from_response(response))
-                }))
-            }
-        })
+        match self.validate_matchmaking_rule_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGameLift has no handler for validate_matchmaking_rule_set"),
+        }
     }
 }
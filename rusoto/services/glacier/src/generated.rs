@@ -26,6 +26,7 @@ use rusoto_core::signature::SignedRequest;
 use serde_json;
 /// <p>Provides options to abort a multipart upload identified by the upload ID.</p> <p>For information about the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-abort-upload.html">Abort Multipart Upload</a>. For conceptual information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AbortMultipartUploadInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -40,6 +41,7 @@ pub struct AbortMultipartUploadInput {
 
 /// <p>The input values for <code>AbortVaultLock</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AbortVaultLockInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID. This value must match the AWS account ID associated with the credentials used to sign the request. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon Glacier uses the AWS account ID associated with the credentials used to sign the request. If you specify your account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -51,6 +53,7 @@ pub struct AbortVaultLockInput {
 
 /// <p>The input values for <code>AddTagsToVault</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToVaultInput {
     /// <p>The tags to add to the vault. Each tag is composed of a key and a value. The value can be an empty string.</p>
     #[serde(rename = "Tags")]
@@ -66,7 +69,7 @@ pub struct AddTagsToVaultInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p> <p>For information about the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-post.html">Upload Archive</a>. For conceptual information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ArchiveCreationOutput {
     /// <p>The ID of the archive. This value is also included as part of the location.</p>
     #[serde(rename = "archiveId")]
@@ -138,6 +141,7 @@ pub struct CSVOutput {
 
 /// <p>Provides options to complete a multipart upload operation. This informs Amazon Glacier that all the archive parts have been uploaded and Amazon S3 Glacier (Glacier) can now assemble the archive from the uploaded parts. After assembling and saving the archive to the vault, Glacier returns the URI path of the newly created archive resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompleteMultipartUploadInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -160,6 +164,7 @@ pub struct CompleteMultipartUploadInput {
 
 /// <p>The input values for <code>CompleteVaultLock</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompleteVaultLockInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID. This value must match the AWS account ID associated with the credentials used to sign the request. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon Glacier uses the AWS account ID associated with the credentials used to sign the request. If you specify your account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -174,6 +179,7 @@ pub struct CompleteVaultLockInput {
 
 /// <p>Provides options to create a vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVaultInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID. This value must match the AWS account ID associated with the credentials used to sign the request. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you specify your account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -185,7 +191,7 @@ pub struct CreateVaultInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateVaultOutput {
     /// <p>The URI of the vault that was created.</p>
     #[serde(rename = "location")]
@@ -217,6 +223,7 @@ pub struct DataRetrievalRule {
 
 /// <p>Provides options for deleting an archive from an Amazon S3 Glacier vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteArchiveInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -231,6 +238,7 @@ pub struct DeleteArchiveInput {
 
 /// <p>DeleteVaultAccessPolicy input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVaultAccessPolicyInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -242,6 +250,7 @@ pub struct DeleteVaultAccessPolicyInput {
 
 /// <p>Provides options for deleting a vault from Amazon S3 Glacier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVaultInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -253,6 +262,7 @@ pub struct DeleteVaultInput {
 
 /// <p>Provides options for deleting a vault notification configuration from an Amazon Glacier vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVaultNotificationsInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -264,6 +274,7 @@ pub struct DeleteVaultNotificationsInput {
 
 /// <p>Provides options for retrieving a job description.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeJobInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -278,6 +289,7 @@ pub struct DescribeJobInput {
 
 /// <p>Provides options for retrieving metadata for a specific vault in Amazon Glacier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeVaultInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -289,7 +301,7 @@ pub struct DescribeVaultInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeVaultOutput {
     /// <p>The Universal Coordinated Time (UTC) date when the vault was created. This value should be a string in the ISO 8601 date format, for example <code>2012-03-20T17:03:43.221Z</code>.</p>
     #[serde(rename = "CreationDate")]
@@ -336,6 +348,7 @@ pub struct Encryption {
 
 /// <p>Input for GetDataRetrievalPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDataRetrievalPolicyInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID. This value must match the AWS account ID associated with the credentials used to sign the request. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon Glacier uses the AWS account ID associated with the credentials used to sign the request. If you specify your account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -344,7 +357,7 @@ pub struct GetDataRetrievalPolicyInput {
 
 /// <p>Contains the Amazon S3 Glacier response to the <code>GetDataRetrievalPolicy</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDataRetrievalPolicyOutput {
     /// <p>Contains the returned data retrieval policy in JSON format.</p>
     #[serde(rename = "Policy")]
@@ -354,6 +367,7 @@ pub struct GetDataRetrievalPolicyOutput {
 
 /// <p>Provides options for downloading output of an Amazon S3 Glacier job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobOutputInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -391,6 +405,7 @@ pub struct GetJobOutputOutput {
 
 /// <p>Input for GetVaultAccessPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVaultAccessPolicyInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -402,7 +417,7 @@ pub struct GetVaultAccessPolicyInput {
 
 /// <p>Output for GetVaultAccessPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetVaultAccessPolicyOutput {
     /// <p>Contains the returned vault access policy as a JSON string.</p>
     #[serde(rename = "policy")]
@@ -412,6 +427,7 @@ pub struct GetVaultAccessPolicyOutput {
 
 /// <p>The input values for <code>GetVaultLock</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVaultLockInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -423,7 +439,7 @@ pub struct GetVaultLockInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetVaultLockOutput {
     /// <p>The UTC date and time at which the vault lock was put into the <code>InProgress</code> state.</p>
     #[serde(rename = "CreationDate")]
@@ -445,6 +461,7 @@ pub struct GetVaultLockOutput {
 
 /// <p>Provides options for retrieving the notification configuration set on an Amazon Glacier vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVaultNotificationsInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -456,7 +473,7 @@ pub struct GetVaultNotificationsInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetVaultNotificationsOutput {
     /// <p>Returns the notification configuration set on the vault.</p>
     #[serde(rename = "vaultNotificationConfig")]
@@ -466,7 +483,7 @@ pub struct GetVaultNotificationsOutput {
 
 /// <p>Contains the description of an Amazon S3 Glacier job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GlacierJobDescription {
     /// <p>The job type. This value is either <code>ArchiveRetrieval</code>, <code>InventoryRetrieval</code>, or <code>Select</code>. </p>
     #[serde(rename = "Action")]
@@ -593,6 +610,7 @@ pub struct Grantee {
 
 /// <p>Provides options for initiating an Amazon S3 Glacier job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InitiateJobInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -608,7 +626,7 @@ pub struct InitiateJobInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InitiateJobOutput {
     /// <p>The ID of the job.</p>
     #[serde(rename = "jobId")]
@@ -626,6 +644,7 @@ pub struct InitiateJobOutput {
 
 /// <p>Provides options for initiating a multipart upload to an Amazon S3 Glacier vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InitiateMultipartUploadInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -645,7 +664,7 @@ pub struct InitiateMultipartUploadInput {
 
 /// <p>The Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InitiateMultipartUploadOutput {
     /// <p>The relative URI path of the multipart upload ID Amazon S3 Glacier created.</p>
     #[serde(rename = "location")]
@@ -659,6 +678,7 @@ pub struct InitiateMultipartUploadOutput {
 
 /// <p>The input values for <code>InitiateVaultLock</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InitiateVaultLockInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID. This value must match the AWS account ID associated with the credentials used to sign the request. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon Glacier uses the AWS account ID associated with the credentials used to sign the request. If you specify your account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -674,7 +694,7 @@ pub struct InitiateVaultLockInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InitiateVaultLockOutput {
     /// <p>The lock ID, which is used to complete the vault locking process.</p>
     #[serde(rename = "lockId")]
@@ -693,7 +713,7 @@ pub struct InputSerialization {
 
 /// <p>Describes the options for a range inventory retrieval job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InventoryRetrievalJobDescription {
     /// <p>The end of the date range in UTC for vault inventory retrieval that includes archives created before this date. This value should be a string in the ISO 8601 date format, for example <code>2013-03-20T17:03:43Z</code>.</p>
     #[serde(rename = "EndDate")]
@@ -719,6 +739,7 @@ pub struct InventoryRetrievalJobDescription {
 
 /// <p>Provides options for specifying a range inventory retrieval job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryRetrievalJobInput {
     /// <p>The end of the date range in UTC for vault inventory retrieval that includes archives created before this date. This value should be a string in the ISO 8601 date format, for example <code>2013-03-20T17:03:43Z</code>.</p>
     #[serde(rename = "EndDate")]
@@ -740,6 +761,7 @@ pub struct InventoryRetrievalJobInput {
 
 /// <p>Provides options for defining a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct JobParameters {
     /// <p>The ID of the archive that you want to retrieve. This field is required only if <code>Type</code> is set to <code>select</code> or <code>archive-retrieval</code>code&gt;. An error occurs if you specify this request parameter for an inventory retrieval job request. </p>
     #[serde(rename = "ArchiveId")]
@@ -785,6 +807,7 @@ pub struct JobParameters {
 
 /// <p>Provides options for retrieving a job list for an Amazon S3 Glacier vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -812,7 +835,7 @@ pub struct ListJobsInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJobsOutput {
     /// <p>A list of job objects. Each job object contains metadata describing the job.</p>
     #[serde(rename = "JobList")]
@@ -826,6 +849,7 @@ pub struct ListJobsOutput {
 
 /// <p>Provides options for retrieving list of in-progress multipart uploads for an Amazon Glacier vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMultipartUploadsInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -845,7 +869,7 @@ pub struct ListMultipartUploadsInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListMultipartUploadsOutput {
     /// <p>An opaque string that represents where to continue pagination of the results. You use the marker in a new List Multipart Uploads request to obtain more uploads in the list. If there are no more uploads, this value is <code>null</code>.</p>
     #[serde(rename = "Marker")]
@@ -859,6 +883,7 @@ pub struct ListMultipartUploadsOutput {
 
 /// <p>Provides options for retrieving a list of parts of an archive that have been uploaded in a specific multipart upload.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPartsInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -881,7 +906,7 @@ pub struct ListPartsInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPartsOutput {
     /// <p>The description of the archive that was specified in the Initiate Multipart Upload request.</p>
     #[serde(rename = "ArchiveDescription")]
@@ -914,6 +939,7 @@ pub struct ListPartsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProvisionedCapacityInput {
     /// <p>The AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '-' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, don't include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -921,7 +947,7 @@ pub struct ListProvisionedCapacityInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProvisionedCapacityOutput {
     /// <p>The response body contains the following JSON fields.</p>
     #[serde(rename = "ProvisionedCapacityList")]
@@ -931,6 +957,7 @@ pub struct ListProvisionedCapacityOutput {
 
 /// <p>The input value for <code>ListTagsForVaultInput</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForVaultInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -942,7 +969,7 @@ pub struct ListTagsForVaultInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForVaultOutput {
     /// <p>The tags attached to the vault. Each tag is composed of a key and a value.</p>
     #[serde(rename = "Tags")]
@@ -952,6 +979,7 @@ pub struct ListTagsForVaultOutput {
 
 /// <p>Provides options to retrieve the vault list owned by the calling user's account. The list provides metadata information for each vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVaultsInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID. This value must match the AWS account ID associated with the credentials used to sign the request. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon Glacier uses the AWS account ID associated with the credentials used to sign the request. If you specify your account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -968,7 +996,7 @@ pub struct ListVaultsInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVaultsOutput {
     /// <p>The vault ARN at which to continue pagination of the results. You use the marker in another List Vaults request to obtain more vaults in the list.</p>
     #[serde(rename = "Marker")]
@@ -1000,7 +1028,7 @@ pub struct OutputSerialization {
 
 /// <p>A list of the part sizes of the multipart upload.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PartListElement {
     /// <p>The byte range of a part, inclusive of the upper value of the range.</p>
     #[serde(rename = "RangeInBytes")]
@@ -1014,7 +1042,7 @@ pub struct PartListElement {
 
 /// <p>The definition for a provisioned capacity unit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisionedCapacityDescription {
     /// <p>The ID that identifies the provisioned capacity unit.</p>
     #[serde(rename = "CapacityId")]
@@ -1031,6 +1059,7 @@ pub struct ProvisionedCapacityDescription {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PurchaseProvisionedCapacityInput {
     /// <p>The AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '-' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, don't include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -1038,7 +1067,7 @@ pub struct PurchaseProvisionedCapacityInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PurchaseProvisionedCapacityOutput {
     /// <p>The ID that identifies the provisioned capacity unit.</p>
     #[serde(rename = "capacityId")]
@@ -1048,6 +1077,7 @@ pub struct PurchaseProvisionedCapacityOutput {
 
 /// <p>The input value for <code>RemoveTagsFromVaultInput</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromVaultInput {
     /// <p>A list of tag keys. Each corresponding tag is removed from the vault.</p>
     #[serde(rename = "TagKeys")]
@@ -1121,6 +1151,7 @@ pub struct SelectParameters {
 
 /// <p>SetDataRetrievalPolicy input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetDataRetrievalPolicyInput {
     /// <p>The data retrieval policy in JSON format.</p>
     #[serde(rename = "Policy")]
@@ -1133,6 +1164,7 @@ pub struct SetDataRetrievalPolicyInput {
 
 /// <p>SetVaultAccessPolicy input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetVaultAccessPolicyInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -1148,6 +1180,7 @@ pub struct SetVaultAccessPolicyInput {
 
 /// <p>Provides options to configure notifications that will be sent when specific events happen to a vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetVaultNotificationsInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
     #[serde(rename = "accountId")]
@@ -1163,6 +1196,7 @@ pub struct SetVaultNotificationsInput {
 
 /// <p>Provides options to add an archive to a vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UploadArchiveInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -1191,7 +1225,7 @@ pub struct UploadArchiveInput {
 
 /// <p>A list of in-progress multipart uploads for a vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UploadListElement {
     /// <p>The description of the archive that was specified in the Initiate Multipart Upload request.</p>
     #[serde(rename = "ArchiveDescription")]
@@ -1217,6 +1251,7 @@ pub struct UploadListElement {
 
 /// <p>Provides options to upload a part of an archive in a multipart upload operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UploadMultipartPartInput {
     /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
     #[serde(rename = "accountId")]
@@ -1248,7 +1283,7 @@ pub struct UploadMultipartPartInput {
 
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UploadMultipartPartOutput {
     /// <p>The SHA256 tree hash that Amazon S3 Glacier computed for the uploaded part.</p>
     #[serde(rename = "checksum")]
@@ -1267,6 +1302,7 @@ pub struct VaultAccessPolicy {
 
 /// <p>Contains the vault lock policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VaultLockPolicy {
     /// <p>The vault lock policy.</p>
     #[serde(rename = "Policy")]
@@ -1324,7 +1360,12 @@ impl AbortMultipartUploadError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1379,7 +1420,12 @@ impl AbortVaultLockError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(AbortVaultLockError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1439,7 +1485,12 @@ impl AddTagsToVaultError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(AddTagsToVaultError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1499,7 +1550,12 @@ impl CompleteMultipartUploadError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1556,7 +1612,12 @@ impl CompleteVaultLockError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1607,7 +1668,12 @@ impl CreateVaultError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(CreateVaultError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1658,7 +1724,12 @@ impl DeleteArchiveError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DeleteArchiveError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1709,7 +1780,12 @@ impl DeleteVaultError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DeleteVaultError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1768,7 +1844,12 @@ impl DeleteVaultAccessPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1827,7 +1908,12 @@ impl DeleteVaultNotificationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1878,7 +1964,12 @@ impl DescribeJobError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DescribeJobError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1929,7 +2020,12 @@ impl DescribeVaultError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DescribeVaultError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1981,7 +2077,12 @@ impl GetDataRetrievalPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2031,7 +2132,12 @@ impl GetJobOutputError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(GetJobOutputError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2090,7 +2196,12 @@ impl GetVaultAccessPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2141,7 +2252,12 @@ impl GetVaultLockError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(GetVaultLockError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2200,7 +2316,12 @@ impl GetVaultNotificationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2261,7 +2382,12 @@ impl InitiateJobError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(InitiateJobError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2322,7 +2448,12 @@ impl InitiateMultipartUploadError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2379,7 +2510,12 @@ impl InitiateVaultLockError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2430,7 +2566,12 @@ impl ListJobsError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(ListJobsError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2489,7 +2630,12 @@ impl ListMultipartUploadsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2540,7 +2686,12 @@ impl ListPartsError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(ListPartsError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2592,7 +2743,12 @@ impl ListProvisionedCapacityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2646,7 +2802,12 @@ impl ListTagsForVaultError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(ListTagsForVaultError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2697,7 +2858,12 @@ impl ListVaultsError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(ListVaultsError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2758,7 +2924,12 @@ impl PurchaseProvisionedCapacityError {
                         PurchaseProvisionedCapacityError::ServiceUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2817,7 +2988,12 @@ impl RemoveTagsFromVaultError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2869,7 +3045,12 @@ impl SetDataRetrievalPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2927,7 +3108,12 @@ impl SetVaultAccessPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2986,7 +3172,12 @@ impl SetVaultNotificationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3042,7 +3233,12 @@ impl UploadArchiveError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(UploadArchiveError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3107,7 +3303,12 @@ impl UploadMultipartPartError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3316,1321 +3517,3107 @@ pub trait Glacier {
         input: UploadMultipartPartInput,
     ) -> RusotoFuture<UploadMultipartPartOutput, UploadMultipartPartError>;
 }
-/// A client for the Amazon Glacier API.
-#[derive(Clone)]
-pub struct GlacierClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl GlacierClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> GlacierClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> GlacierClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> GlacierClient {
-        GlacierClient { client, region }
-    }
-}
-
-impl Glacier for GlacierClient {
-    /// <p>This operation aborts a multipart upload identified by the upload ID.</p> <p>After the Abort Multipart Upload request succeeds, you cannot upload any more parts to the multipart upload or complete the multipart upload. Aborting a completed upload fails. However, aborting an already-aborted upload will succeed, for a short time. For more information about uploading a part and completing a multipart upload, see <a>UploadMultipartPart</a> and <a>CompleteMultipartUpload</a>.</p> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-abort-upload.html">Abort Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+const _: Option<&dyn Glacier> = None;
+impl<'a, T: Glacier + ?Sized> Glacier for &'a T {
     fn abort_multipart_upload(
         &self,
         input: AbortMultipartUploadInput,
     ) -> RusotoFuture<(), AbortMultipartUploadError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
-            account_id = input.account_id,
-            upload_id = input.upload_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AbortMultipartUploadError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).abort_multipart_upload(input)
     }
-
-    /// <p>This operation aborts the vault locking process if the vault lock is not in the <code>Locked</code> state. If the vault lock is in the <code>Locked</code> state when this operation is requested, the operation returns an <code>AccessDeniedException</code> error. Aborting the vault locking process removes the vault lock policy from the specified vault. </p> <p>A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. A vault lock is put into the <code>Locked</code> state by calling <a>CompleteVaultLock</a>. You can get the state of a vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. For more information about vault lock policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p> <p>This operation is idempotent. You can successfully invoke this operation multiple times, if the vault lock is in the <code>InProgress</code> state or if there is no policy associated with the vault.</p>
     fn abort_vault_lock(
         &self,
         input: AbortVaultLockInput,
     ) -> RusotoFuture<(), AbortVaultLockError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/lock-policy",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AbortVaultLockError::from_response(response))),
-                )
-            }
-        })
+        (**self).abort_vault_lock(input)
     }
-
-    /// <p>This operation adds the specified tags to a vault. Each tag is composed of a key and a value. Each vault can have up to 10 tags. If your request would cause the tag limit for the vault to be exceeded, the operation throws the <code>LimitExceededException</code> error. If a tag already exists on the vault under a specified key, the existing key value will be overwritten. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>. </p>
     fn add_tags_to_vault(
         &self,
         input: AddTagsToVaultInput,
     ) -> RusotoFuture<(), AddTagsToVaultError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/tags",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "add");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsToVaultError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_tags_to_vault(input)
     }
-
-    /// <p>You call this operation to inform Amazon S3 Glacier (Glacier) that all the archive parts have been uploaded and that Glacier can now assemble the archive from the uploaded parts. After assembling and saving the archive to the vault, Glacier returns the URI path of the newly created archive resource. Using the URI path, you can then access the archive. After you upload an archive, you should save the archive ID returned to retrieve the archive at a later point. You can also get the vault inventory to obtain a list of archive IDs in a vault. For more information, see <a>InitiateJob</a>.</p> <p>In the request, you must include the computed SHA256 tree hash of the entire archive you have uploaded. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>. On the server side, Glacier also constructs the SHA256 tree hash of the assembled archive. If the values match, Glacier saves the archive to the vault; otherwise, it returns an error, and the operation fails. The <a>ListParts</a> operation returns a list of parts uploaded for a specific multipart upload. It includes checksum information for each uploaded part that can be used to debug a bad checksum issue.</p> <p>Additionally, Glacier also checks for any missing content ranges when assembling the archive, if missing content ranges are found, Glacier returns an error and the operation fails.</p> <p>Complete Multipart Upload is an idempotent operation. After your first successful complete multipart upload, if you call the operation again within a short period, the operation will succeed and return the same archive ID. This is useful in the event you experience a network issue that causes an aborted connection or receive a 500 server error, in which case you can repeat your Complete Multipart Upload request and get the same archive ID without creating duplicate archives. Note, however, that after the multipart upload completes, you cannot call the List Parts operation and the multipart upload will not appear in List Multipart Uploads response, even if idempotent complete is possible.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-complete-upload.html">Complete Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
     fn complete_multipart_upload(
         &self,
         input: CompleteMultipartUploadInput,
     ) -> RusotoFuture<ArchiveCreationOutput, CompleteMultipartUploadError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
-            account_id = input.account_id,
-            upload_id = input.upload_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        if let Some(ref archive_size) = input.archive_size {
-            request.add_header("x-amz-archive-size", &archive_size.to_string());
-        }
-
-        if let Some(ref checksum) = input.checksum {
-            request.add_header("x-amz-sha256-tree-hash", &checksum.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ArchiveCreationOutput, _>()?;
-                    if let Some(archive_id) = response.headers.get("x-amz-archive-id") {
-                        let value = archive_id.to_owned();
-                        result.archive_id = Some(value)
-                    };
-                    if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
-                        let value = checksum.to_owned();
-                        result.checksum = Some(value)
-                    };
-                    if let Some(location) = response.headers.get("Location") {
-                        let value = location.to_owned();
-                        result.location = Some(value)
-                    };
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CompleteMultipartUploadError::from_response(response))
-                }))
-            }
-        })
+        (**self).complete_multipart_upload(input)
     }
-
-    /// <p>This operation completes the vault locking process by transitioning the vault lock from the <code>InProgress</code> state to the <code>Locked</code> state, which causes the vault lock policy to become unchangeable. A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. You can obtain the state of the vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. </p> <p>This operation is idempotent. This request is always successful if the vault lock is in the <code>Locked</code> state and the provided lock ID matches the lock ID originally used to lock the vault.</p> <p>If an invalid lock ID is passed in the request when the vault lock is in the <code>Locked</code> state, the operation returns an <code>AccessDeniedException</code> error. If an invalid lock ID is passed in the request when the vault lock is in the <code>InProgress</code> state, the operation throws an <code>InvalidParameter</code> error.</p>
     fn complete_vault_lock(
         &self,
         input: CompleteVaultLockInput,
     ) -> RusotoFuture<(), CompleteVaultLockError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/lock-policy/{lock_id}",
-            account_id = input.account_id,
-            lock_id = input.lock_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CompleteVaultLockError::from_response(response))),
-                )
-            }
-        })
+        (**self).complete_vault_lock(input)
     }
-
-    /// <p>This operation creates a new vault with the specified name. The name of the vault must be unique within a region for an AWS account. You can create up to 1,000 vaults per account. If you need to create more vaults, contact Amazon S3 Glacier.</p> <p>You must use the following guidelines when naming a vault.</p> <ul> <li> <p>Names can be between 1 and 255 characters long.</p> </li> <li> <p>Allowed characters are a-z, A-Z, 0-9, '_' (underscore), '-' (hyphen), and '.' (period).</p> </li> </ul> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/creating-vaults.html">Creating a Vault in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-put.html">Create Vault </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
     fn create_vault(
         &self,
         input: CreateVaultInput,
     ) -> RusotoFuture<CreateVaultOutput, CreateVaultError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateVaultOutput, _>()?;
-                    if let Some(location) = response.headers.get("Location") {
-                        let value = location.to_owned();
-                        result.location = Some(value)
-                    };
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateVaultError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_vault(input)
     }
-
-    /// <p>This operation deletes an archive from a vault. Subsequent requests to initiate a retrieval of this archive will fail. Archive retrievals that are in progress for this archive ID may or may not succeed according to the following scenarios:</p> <ul> <li> <p>If the archive retrieval job is actively preparing the data for download when Amazon S3 Glacier receives the delete archive request, the archival retrieval operation might fail.</p> </li> <li> <p>If the archive retrieval job has successfully prepared the archive for download when Amazon S3 Glacier receives the delete archive request, you will be able to download the output.</p> </li> </ul> <p>This operation is idempotent. Attempting to delete an already-deleted archive does not result in an error.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/deleting-an-archive.html">Deleting an Archive in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-delete.html">Delete Archive</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
     fn delete_archive(&self, input: DeleteArchiveInput) -> RusotoFuture<(), DeleteArchiveError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/archives/{archive_id}",
-            account_id = input.account_id,
-            archive_id = input.archive_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteArchiveError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_archive(input)
     }
-
-    /// <p>This operation deletes a vault. Amazon S3 Glacier will delete a vault only if there are no archives in the vault as of the last inventory and there have been no writes to the vault since the last inventory. If either of these conditions is not satisfied, the vault deletion fails (that is, the vault is not removed) and Amazon S3 Glacier returns an error. You can use <a>DescribeVault</a> to return the number of archives in a vault, and you can use <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-initiate-job-post.html">Initiate a Job (POST jobs)</a> to initiate a new inventory retrieval for a vault. The inventory contains the archive IDs you use to delete archives using <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-delete.html">Delete Archive (DELETE archive)</a>.</p> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/deleting-vaults.html">Deleting a Vault in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-delete.html">Delete Vault </a> in the <i>Amazon S3 Glacier Developer Guide</i>. </p>
     fn delete_vault(&self, input: DeleteVaultInput) -> RusotoFuture<(), DeleteVaultError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteVaultError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_vault(input)
     }
-
-    /// <p>This operation deletes the access policy associated with the specified vault. The operation is eventually consistent; that is, it might take some time for Amazon S3 Glacier to completely remove the access policy, and you might still see the effect of the policy for a short time after you send the delete request.</p> <p>This operation is idempotent. You can invoke delete multiple times, even if there is no policy associated with the vault. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>. </p>
     fn delete_vault_access_policy(
         &self,
         input: DeleteVaultAccessPolicyInput,
     ) -> RusotoFuture<(), DeleteVaultAccessPolicyError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/access-policy",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteVaultAccessPolicyError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_vault_access_policy(input)
     }
-
-    /// <p>This operation deletes the notification configuration set for a vault. The operation is eventually consistent; that is, it might take some time for Amazon S3 Glacier to completely disable the notifications and you might still receive some notifications for a short time after you send the delete request.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-delete.html">Delete Vault Notification Configuration </a> in the Amazon S3 Glacier Developer Guide. </p>
     fn delete_vault_notifications(
         &self,
         input: DeleteVaultNotificationsInput,
     ) -> RusotoFuture<(), DeleteVaultNotificationsError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/notification-configuration",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteVaultNotificationsError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_vault_notifications(input)
     }
-
-    /// <p>This operation returns information about a job you previously initiated, including the job initiation date, the user who initiated the job, the job status code/message and the Amazon SNS topic to notify after Amazon S3 Glacier (Glacier) completes the job. For more information about initiating a job, see <a>InitiateJob</a>. </p> <note> <p>This operation enables you to check the status of your job. However, it is strongly recommended that you set up an Amazon SNS topic and specify it in your initiate job request so that Glacier can notify the topic after it completes the job.</p> </note> <p>A job ID will not expire for at least 24 hours after Glacier completes the job.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-describe-job-get.html">Describe Job</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
     fn describe_job(
         &self,
         input: DescribeJobInput,
     ) -> RusotoFuture<GlacierJobDescription, DescribeJobError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/jobs/{job_id}",
-            account_id = input.account_id,
-            job_id = input.job_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GlacierJobDescription, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_job(input)
     }
-
-    /// <p>This operation returns information about a vault, including the vault's Amazon Resource Name (ARN), the date the vault was created, the number of archives it contains, and the total size of all the archives in the vault. The number of archives and their total size are as of the last inventory generation. This means that if you add or remove an archive from a vault, and then immediately use Describe Vault, the change in contents will not be immediately reflected. If you want to retrieve the latest inventory of the vault, use <a>InitiateJob</a>. Amazon S3 Glacier generates vault inventories approximately daily. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-inventory.html">Downloading a Vault Inventory in Amazon S3 Glacier</a>. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/retrieving-vault-info.html">Retrieving Vault Metadata in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-get.html">Describe Vault </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
     fn describe_vault(
         &self,
         input: DescribeVaultInput,
     ) -> RusotoFuture<DescribeVaultOutput, DescribeVaultError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeVaultOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeVaultError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_vault(input)
     }
-
-    /// <p>This operation returns the current data retrieval policy for the account and region specified in the GET request. For more information about data retrieval policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/data-retrieval-policy.html">Amazon Glacier Data Retrieval Policies</a>.</p>
     fn get_data_retrieval_policy(
         &self,
         input: GetDataRetrievalPolicyInput,
     ) -> RusotoFuture<GetDataRetrievalPolicyOutput, GetDataRetrievalPolicyError> {
-        let request_uri = format!(
-            "/{account_id}/policies/data-retrieval",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDataRetrievalPolicyOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetDataRetrievalPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_data_retrieval_policy(input)
     }
-
-    /// <p>This operation downloads the output of the job you initiated using <a>InitiateJob</a>. Depending on the job type you specified when you initiated the job, the output will be either the content of an archive or a vault inventory.</p> <p>You can download all the job output or download a portion of the output by specifying a byte range. In the case of an archive retrieval job, depending on the byte range you specify, Amazon S3 Glacier (Glacier) returns the checksum for the portion of the data. You can compute the checksum on the client and verify that the values match to ensure the portion you downloaded is the correct data.</p> <p>A job ID will not expire for at least 24 hours after Glacier completes the job. That a byte range. For both archive and inventory retrieval jobs, you should verify the downloaded size against the size returned in the headers from the <b>Get Job Output</b> response.</p> <p>For archive retrieval jobs, you should also verify that the size is what you expected. If you download a portion of the output, the expected size is based on the range of bytes you specified. For example, if you specify a range of <code>bytes=0-1048575</code>, you should verify your download size is 1,048,576 bytes. If you download an entire archive, the expected size is the size of the archive when you uploaded it to Amazon S3 Glacier The expected size is also returned in the headers from the <b>Get Job Output</b> response.</p> <p>In the case of an archive retrieval job, depending on the byte range you specify, Glacier returns the checksum for the portion of the data. To ensure the portion you downloaded is the correct data, compute the checksum on the client, verify that the values match, and verify that the size is what you expected.</p> <p>A job ID does not expire for at least 24 hours after Glacier completes the job. That is, you can download the job output within the 24 hours period after Amazon Glacier completes the job.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-inventory.html">Downloading a Vault Inventory</a>, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/downloading-an-archive.html">Downloading an Archive</a>, and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-job-output-get.html">Get Job Output </a> </p>
     fn get_job_output(
         &self,
         input: GetJobOutputInput,
     ) -> RusotoFuture<GetJobOutputOutput, GetJobOutputError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/jobs/{job_id}/output",
-            account_id = input.account_id,
-            job_id = input.job_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        if let Some(ref range) = input.range {
-            request.add_header("Range", &range.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = GetJobOutputOutput::default();
-                    result.body = Some(response.body);
-
-                    if let Some(accept_ranges) = response.headers.get("Accept-Ranges") {
-                        let value = accept_ranges.to_owned();
-                        result.accept_ranges = Some(value)
-                    };
-                    if let Some(archive_description) =
-                        response.headers.get("x-amz-archive-description")
-                    {
-                        let value = archive_description.to_owned();
-                        result.archive_description = Some(value)
-                    };
-                    if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
-                        let value = checksum.to_owned();
-                        result.checksum = Some(value)
-                    };
-                    if let Some(content_range) = response.headers.get("Content-Range") {
-                        let value = content_range.to_owned();
-                        result.content_range = Some(value)
-                    };
-                    if let Some(content_type) = response.headers.get("Content-Type") {
-                        let value = content_type.to_owned();
-                        result.content_type = Some(value)
-                    };
-                    result.status = Some(response.status.as_u16() as i64);
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetJobOutputError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_job_output(input)
     }
-
-    /// <p>This operation retrieves the <code>access-policy</code> subresource set on the vault; for more information on setting this subresource, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-SetVaultAccessPolicy.html">Set Vault Access Policy (PUT access-policy)</a>. If there is no access policy set on the vault, the operation returns a <code>404 Not found</code> error. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>.</p>
     fn get_vault_access_policy(
         &self,
         input: GetVaultAccessPolicyInput,
     ) -> RusotoFuture<GetVaultAccessPolicyOutput, GetVaultAccessPolicyError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/access-policy",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetVaultAccessPolicyOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetVaultAccessPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_vault_access_policy(input)
     }
-
-    /// <p>This operation retrieves the following attributes from the <code>lock-policy</code> subresource set on the specified vault: </p> <ul> <li> <p>The vault lock policy set on the vault.</p> </li> <li> <p>The state of the vault lock, which is either <code>InProgess</code> or <code>Locked</code>.</p> </li> <li> <p>When the lock ID expires. The lock ID is used to complete the vault locking process.</p> </li> <li> <p>When the vault lock was initiated and put into the <code>InProgress</code> state.</p> </li> </ul> <p>A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. A vault lock is put into the <code>Locked</code> state by calling <a>CompleteVaultLock</a>. You can abort the vault locking process by calling <a>AbortVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. </p> <p>If there is no vault lock policy set on the vault, the operation returns a <code>404 Not found</code> error. For more information about vault lock policies, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p>
     fn get_vault_lock(
         &self,
         input: GetVaultLockInput,
     ) -> RusotoFuture<GetVaultLockOutput, GetVaultLockError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/lock-policy",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetVaultLockOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetVaultLockError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_vault_lock(input)
     }
-
-    /// <p>This operation retrieves the <code>notification-configuration</code> subresource of the specified vault.</p> <p>For information about setting a notification configuration on a vault, see <a>SetVaultNotifications</a>. If a notification configuration for a vault is not set, the operation returns a <code>404 Not Found</code> error. For more information about vault notifications, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a>. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-get.html">Get Vault Notification Configuration </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
     fn get_vault_notifications(
         &self,
         input: GetVaultNotificationsInput,
     ) -> RusotoFuture<GetVaultNotificationsOutput, GetVaultNotificationsError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/notification-configuration",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetVaultNotificationsOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetVaultNotificationsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_vault_notifications(input)
     }
-
-    /// <p>This operation initiates a job of the specified type, which can be a select, an archival retrieval, or a vault retrieval. For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-initiate-job-post.html">Initiate a Job</a>. </p>
     fn initiate_job(
         &self,
         input: InitiateJobInput,
     ) -> RusotoFuture<InitiateJobOutput, InitiateJobError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/jobs",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let encoded = Some(serde_json::to_vec(&input.job_parameters).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InitiateJobOutput, _>()?;
-                    if let Some(job_id) = response.headers.get("x-amz-job-id") {
-                        let value = job_id.to_owned();
-                        result.job_id = Some(value)
-                    };
-                    if let Some(job_output_path) = response.headers.get("x-amz-job-output-path") {
-                        let value = job_output_path.to_owned();
-                        result.job_output_path = Some(value)
-                    };
-                    if let Some(location) = response.headers.get("Location") {
-                        let value = location.to_owned();
-                        result.location = Some(value)
-                    };
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(InitiateJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).initiate_job(input)
     }
-
-    /// <p>This operation initiates a multipart upload. Amazon S3 Glacier creates a multipart upload resource and returns its ID in the response. The multipart upload ID is used in subsequent requests to upload parts of an archive (see <a>UploadMultipartPart</a>).</p> <p>When you initiate a multipart upload, you specify the part size in number of bytes. The part size must be a megabyte (1024 KB) multiplied by a power of 2-for example, 1048576 (1 MB), 2097152 (2 MB), 4194304 (4 MB), 8388608 (8 MB), and so on. The minimum allowable part size is 1 MB, and the maximum is 4 GB.</p> <p>Every part you upload to this resource (see <a>UploadMultipartPart</a>), except the last one, must have the same size. The last one can be the same size or smaller. For example, suppose you want to upload a 16.2 MB file. If you initiate the multipart upload with a part size of 4 MB, you will upload four parts of 4 MB each and one part of 0.2 MB. </p> <note> <p>You don't need to know the size of the archive when you start a multipart upload because Amazon S3 Glacier does not require you to specify the overall archive size.</p> </note> <p>After you complete the multipart upload, Amazon S3 Glacier (Glacier) removes the multipart upload resource referenced by the ID. Glacier also removes the multipart upload resource if you cancel the multipart upload or it may be removed if there is no activity for a period of 24 hours.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-initiate-upload.html">Initiate Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>.</p>
     fn initiate_multipart_upload(
         &self,
         input: InitiateMultipartUploadInput,
     ) -> RusotoFuture<InitiateMultipartUploadOutput, InitiateMultipartUploadError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        if let Some(ref archive_description) = input.archive_description {
-            request.add_header(
-                "x-amz-archive-description",
-                &archive_description.to_string(),
-            );
-        }
-
-        if let Some(ref part_size) = input.part_size {
-            request.add_header("x-amz-part-size", &part_size.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InitiateMultipartUploadOutput, _>()?;
-                    if let Some(location) = response.headers.get("Location") {
-                        let value = location.to_owned();
-                        result.location = Some(value)
-                    };
-                    if let Some(upload_id) = response.headers.get("x-amz-multipart-upload-id") {
-                        let value = upload_id.to_owned();
-                        result.upload_id = Some(value)
-                    };
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(InitiateMultipartUploadError::from_response(response))
-                }))
-            }
-        })
+        (**self).initiate_multipart_upload(input)
     }
-
-    /// <p>This operation initiates the vault locking process by doing the following:</p> <ul> <li> <p>Installing a vault lock policy on the specified vault.</p> </li> <li> <p>Setting the lock state of vault lock to <code>InProgress</code>.</p> </li> <li> <p>Returning a lock ID, which is used to complete the vault locking process.</p> </li> </ul> <p>You can set one vault lock policy for each vault and this policy can be up to 20 KB in size. For more information about vault lock policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p> <p>You must complete the vault locking process within 24 hours after the vault lock enters the <code>InProgress</code> state. After the 24 hour window ends, the lock ID expires, the vault automatically exits the <code>InProgress</code> state, and the vault lock policy is removed from the vault. You call <a>CompleteVaultLock</a> to complete the vault locking process by setting the state of the vault lock to <code>Locked</code>. </p> <p>After a vault lock is in the <code>Locked</code> state, you cannot initiate a new vault lock for the vault.</p> <p>You can abort the vault locking process by calling <a>AbortVaultLock</a>. You can get the state of the vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>.</p> <p>If this operation is called when the vault lock is in the <code>InProgress</code> state, the operation returns an <code>AccessDeniedException</code> error. When the vault lock is in the <code>InProgress</code> state you must call <a>AbortVaultLock</a> before you can initiate a new vault lock policy. </p>
     fn initiate_vault_lock(
         &self,
         input: InitiateVaultLockInput,
     ) -> RusotoFuture<InitiateVaultLockOutput, InitiateVaultLockError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/lock-policy",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let encoded = Some(serde_json::to_vec(&input.policy).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InitiateVaultLockOutput, _>()?;
-                    if let Some(lock_id) = response.headers.get("x-amz-lock-id") {
-                        let value = lock_id.to_owned();
-                        result.lock_id = Some(value)
-                    };
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(InitiateVaultLockError::from_response(response))),
-                )
-            }
-        })
+        (**self).initiate_vault_lock(input)
     }
-
-    /// <p>This operation lists jobs for a vault, including jobs that are in-progress and jobs that have recently finished. The List Job operation returns a list of these jobs sorted by job initiation time.</p> <note> <p>Amazon Glacier retains recently completed jobs for a period before deleting them; however, it eventually removes completed jobs. The output of completed jobs can be retrieved. Retaining completed jobs for a period of time after they have completed enables you to get a job output in the event you miss the job completion notification or your first attempt to download it fails. For example, suppose you start an archive retrieval job to download an archive. After the job completes, you start to download the archive but encounter a network error. In this scenario, you can retry and download the archive while the job exists.</p> </note> <p>The List Jobs operation supports pagination. You should always check the response <code>Marker</code> field. If there are no more jobs to list, the <code>Marker</code> field is set to <code>null</code>. If there are more jobs to list, the <code>Marker</code> field is set to a non-null value, which you can use to continue the pagination of the list. To return a list of jobs that begins at a specific job, set the marker request parameter to the <code>Marker</code> value for that job that you obtained from a previous List Jobs request.</p> <p>You can set a maximum limit for the number of jobs returned in the response by specifying the <code>limit</code> parameter in the request. The default limit is 50. The number of jobs returned might be fewer than the limit, but the number of returned jobs never exceeds the limit.</p> <p>Additionally, you can filter the jobs list returned by specifying the optional <code>statuscode</code> parameter or <code>completed</code> parameter, or both. Using the <code>statuscode</code> parameter, you can specify to return only jobs that match either the <code>InProgress</code>, <code>Succeeded</code>, or <code>Failed</code> status. Using the <code>completed</code> parameter, you can specify to return only jobs that were completed (<code>true</code>) or jobs that were not completed (<code>false</code>).</p> <p>For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-jobs-get.html">List Jobs</a>. </p>
     fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/jobs",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.completed {
-            params.put("completed", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        if let Some(ref x) = input.statuscode {
-            params.put("statuscode", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListJobsOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListJobsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_jobs(input)
     }
-
-    /// <p>This operation lists in-progress multipart uploads for the specified vault. An in-progress multipart upload is a multipart upload that has been initiated by an <a>InitiateMultipartUpload</a> request, but has not yet been completed or aborted. The list returned in the List Multipart Upload response has no guaranteed order. </p> <p>The List Multipart Uploads operation supports pagination. By default, this operation returns up to 50 multipart uploads in the response. You should always check the response for a <code>marker</code> at which to continue the list; if there are no more items the <code>marker</code> is <code>null</code>. To return a list of multipart uploads that begins at a specific upload, set the <code>marker</code> request parameter to the value you obtained from a previous List Multipart Upload request. You can also limit the number of uploads returned in the response by specifying the <code>limit</code> parameter in the request.</p> <p>Note the difference between this operation and listing parts (<a>ListParts</a>). The List Multipart Uploads operation lists all multipart uploads for a vault and does not require a multipart upload ID. The List Parts operation requires a multipart upload ID since parts are associated with a single upload.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-list-uploads.html">List Multipart Uploads </a> in the <i>Amazon Glacier Developer Guide</i>.</p>
     fn list_multipart_uploads(
         &self,
         input: ListMultipartUploadsInput,
     ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListMultipartUploadsOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListMultipartUploadsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_multipart_uploads(input)
     }
-
-    /// <p>This operation lists the parts of an archive that have been uploaded in a specific multipart upload. You can make this request at any time during an in-progress multipart upload before you complete the upload (see <a>CompleteMultipartUpload</a>. List Parts returns an error for completed uploads. The list returned in the List Parts response is sorted by part range. </p> <p>The List Parts operation supports pagination. By default, this operation returns up to 50 uploaded parts in the response. You should always check the response for a <code>marker</code> at which to continue the list; if there are no more items the <code>marker</code> is <code>null</code>. To return a list of parts that begins at a specific part, set the <code>marker</code> request parameter to the value you obtained from a previous List Parts request. You can also limit the number of parts returned in the response by specifying the <code>limit</code> parameter in the request. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-list-parts.html">List Parts</a> in the <i>Amazon Glacier Developer Guide</i>.</p>
     fn list_parts(&self, input: ListPartsInput) -> RusotoFuture<ListPartsOutput, ListPartsError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
-            account_id = input.account_id,
-            upload_id = input.upload_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPartsOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListPartsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_parts(input)
     }
-
-    /// <p>This operation lists the provisioned capacity units for the specified AWS account.</p>
     fn list_provisioned_capacity(
         &self,
         input: ListProvisionedCapacityInput,
     ) -> RusotoFuture<ListProvisionedCapacityOutput, ListProvisionedCapacityError> {
-        let request_uri = format!(
-            "/{account_id}/provisioned-capacity",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListProvisionedCapacityOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListProvisionedCapacityError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_provisioned_capacity(input)
     }
-
-    /// <p>This operation lists all the tags attached to a vault. The operation returns an empty map if there are no tags. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>.</p>
     fn list_tags_for_vault(
         &self,
         input: ListTagsForVaultInput,
     ) -> RusotoFuture<ListTagsForVaultOutput, ListTagsForVaultError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/tags",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForVaultOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTagsForVaultError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tags_for_vault(input)
     }
-
-    /// <p>This operation lists all vaults owned by the calling user's account. The list returned in the response is ASCII-sorted by vault name.</p> <p>By default, this operation returns up to 10 items. If there are more vaults to list, the response <code>marker</code> field contains the vault Amazon Resource Name (ARN) at which to continue the list with a new List Vaults request; otherwise, the <code>marker</code> field is <code>null</code>. To return a list of vaults that begins at a specific vault, set the <code>marker</code> request parameter to the vault ARN you obtained from a previous List Vaults request. You can also limit the number of vaults returned in the response by specifying the <code>limit</code> parameter in the request. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/retrieving-vault-info.html">Retrieving Vault Metadata in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vaults-get.html">List Vaults </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
     fn list_vaults(
         &self,
         input: ListVaultsInput,
     ) -> RusotoFuture<ListVaultsOutput, ListVaultsError> {
-        let request_uri = format!("/{account_id}/vaults", account_id = input.account_id);
-
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVaultsOutput, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListVaultsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_vaults(input)
     }
-
-    /// <p>This operation purchases a provisioned capacity unit for an AWS account. </p>
     fn purchase_provisioned_capacity(
         &self,
         input: PurchaseProvisionedCapacityInput,
     ) -> RusotoFuture<PurchaseProvisionedCapacityOutput, PurchaseProvisionedCapacityError> {
-        let request_uri = format!(
-            "/{account_id}/provisioned-capacity",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PurchaseProvisionedCapacityOutput, _>()?;
-                    if let Some(capacity_id) = response.headers.get("x-amz-capacity-id") {
-                        let value = capacity_id.to_owned();
-                        result.capacity_id = Some(value)
-                    };
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PurchaseProvisionedCapacityError::from_response(response))
-                }))
-            }
-        })
+        (**self).purchase_provisioned_capacity(input)
+    }
+    fn remove_tags_from_vault(
+        &self,
+        input: RemoveTagsFromVaultInput,
+    ) -> RusotoFuture<(), RemoveTagsFromVaultError> {
+        (**self).remove_tags_from_vault(input)
+    }
+    fn set_data_retrieval_policy(
+        &self,
+        input: SetDataRetrievalPolicyInput,
+    ) -> RusotoFuture<(), SetDataRetrievalPolicyError> {
+        (**self).set_data_retrieval_policy(input)
+    }
+    fn set_vault_access_policy(
+        &self,
+        input: SetVaultAccessPolicyInput,
+    ) -> RusotoFuture<(), SetVaultAccessPolicyError> {
+        (**self).set_vault_access_policy(input)
+    }
+    fn set_vault_notifications(
+        &self,
+        input: SetVaultNotificationsInput,
+    ) -> RusotoFuture<(), SetVaultNotificationsError> {
+        (**self).set_vault_notifications(input)
+    }
+    fn upload_archive(
+        &self,
+        input: UploadArchiveInput,
+    ) -> RusotoFuture<ArchiveCreationOutput, UploadArchiveError> {
+        (**self).upload_archive(input)
+    }
+    fn upload_multipart_part(
+        &self,
+        input: UploadMultipartPartInput,
+    ) -> RusotoFuture<UploadMultipartPartOutput, UploadMultipartPartError> {
+        (**self).upload_multipart_part(input)
+    }
+}
+impl<T: Glacier + ?Sized> Glacier for Box<T> {
+    fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadInput,
+    ) -> RusotoFuture<(), AbortMultipartUploadError> {
+        (**self).abort_multipart_upload(input)
+    }
+    fn abort_vault_lock(
+        &self,
+        input: AbortVaultLockInput,
+    ) -> RusotoFuture<(), AbortVaultLockError> {
+        (**self).abort_vault_lock(input)
+    }
+    fn add_tags_to_vault(
+        &self,
+        input: AddTagsToVaultInput,
+    ) -> RusotoFuture<(), AddTagsToVaultError> {
+        (**self).add_tags_to_vault(input)
+    }
+    fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadInput,
+    ) -> RusotoFuture<ArchiveCreationOutput, CompleteMultipartUploadError> {
+        (**self).complete_multipart_upload(input)
+    }
+    fn complete_vault_lock(
+        &self,
+        input: CompleteVaultLockInput,
+    ) -> RusotoFuture<(), CompleteVaultLockError> {
+        (**self).complete_vault_lock(input)
+    }
+    fn create_vault(
+        &self,
+        input: CreateVaultInput,
+    ) -> RusotoFuture<CreateVaultOutput, CreateVaultError> {
+        (**self).create_vault(input)
+    }
+    fn delete_archive(&self, input: DeleteArchiveInput) -> RusotoFuture<(), DeleteArchiveError> {
+        (**self).delete_archive(input)
+    }
+    fn delete_vault(&self, input: DeleteVaultInput) -> RusotoFuture<(), DeleteVaultError> {
+        (**self).delete_vault(input)
+    }
+    fn delete_vault_access_policy(
+        &self,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> RusotoFuture<(), DeleteVaultAccessPolicyError> {
+        (**self).delete_vault_access_policy(input)
+    }
+    fn delete_vault_notifications(
+        &self,
+        input: DeleteVaultNotificationsInput,
+    ) -> RusotoFuture<(), DeleteVaultNotificationsError> {
+        (**self).delete_vault_notifications(input)
+    }
+    fn describe_job(
+        &self,
+        input: DescribeJobInput,
+    ) -> RusotoFuture<GlacierJobDescription, DescribeJobError> {
+        (**self).describe_job(input)
+    }
+    fn describe_vault(
+        &self,
+        input: DescribeVaultInput,
+    ) -> RusotoFuture<DescribeVaultOutput, DescribeVaultError> {
+        (**self).describe_vault(input)
+    }
+    fn get_data_retrieval_policy(
+        &self,
+        input: GetDataRetrievalPolicyInput,
+    ) -> RusotoFuture<GetDataRetrievalPolicyOutput, GetDataRetrievalPolicyError> {
+        (**self).get_data_retrieval_policy(input)
+    }
+    fn get_job_output(
+        &self,
+        input: GetJobOutputInput,
+    ) -> RusotoFuture<GetJobOutputOutput, GetJobOutputError> {
+        (**self).get_job_output(input)
+    }
+    fn get_vault_access_policy(
+        &self,
+        input: GetVaultAccessPolicyInput,
+    ) -> RusotoFuture<GetVaultAccessPolicyOutput, GetVaultAccessPolicyError> {
+        (**self).get_vault_access_policy(input)
+    }
+    fn get_vault_lock(
+        &self,
+        input: GetVaultLockInput,
+    ) -> RusotoFuture<GetVaultLockOutput, GetVaultLockError> {
+        (**self).get_vault_lock(input)
+    }
+    fn get_vault_notifications(
+        &self,
+        input: GetVaultNotificationsInput,
+    ) -> RusotoFuture<GetVaultNotificationsOutput, GetVaultNotificationsError> {
+        (**self).get_vault_notifications(input)
+    }
+    fn initiate_job(
+        &self,
+        input: InitiateJobInput,
+    ) -> RusotoFuture<InitiateJobOutput, InitiateJobError> {
+        (**self).initiate_job(input)
+    }
+    fn initiate_multipart_upload(
+        &self,
+        input: InitiateMultipartUploadInput,
+    ) -> RusotoFuture<InitiateMultipartUploadOutput, InitiateMultipartUploadError> {
+        (**self).initiate_multipart_upload(input)
+    }
+    fn initiate_vault_lock(
+        &self,
+        input: InitiateVaultLockInput,
+    ) -> RusotoFuture<InitiateVaultLockOutput, InitiateVaultLockError> {
+        (**self).initiate_vault_lock(input)
+    }
+    fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        (**self).list_multipart_uploads(input)
+    }
+    fn list_parts(&self, input: ListPartsInput) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        (**self).list_parts(input)
+    }
+    fn list_provisioned_capacity(
+        &self,
+        input: ListProvisionedCapacityInput,
+    ) -> RusotoFuture<ListProvisionedCapacityOutput, ListProvisionedCapacityError> {
+        (**self).list_provisioned_capacity(input)
+    }
+    fn list_tags_for_vault(
+        &self,
+        input: ListTagsForVaultInput,
+    ) -> RusotoFuture<ListTagsForVaultOutput, ListTagsForVaultError> {
+        (**self).list_tags_for_vault(input)
+    }
+    fn list_vaults(
+        &self,
+        input: ListVaultsInput,
+    ) -> RusotoFuture<ListVaultsOutput, ListVaultsError> {
+        (**self).list_vaults(input)
+    }
+    fn purchase_provisioned_capacity(
+        &self,
+        input: PurchaseProvisionedCapacityInput,
+    ) -> RusotoFuture<PurchaseProvisionedCapacityOutput, PurchaseProvisionedCapacityError> {
+        (**self).purchase_provisioned_capacity(input)
+    }
+    fn remove_tags_from_vault(
+        &self,
+        input: RemoveTagsFromVaultInput,
+    ) -> RusotoFuture<(), RemoveTagsFromVaultError> {
+        (**self).remove_tags_from_vault(input)
+    }
+    fn set_data_retrieval_policy(
+        &self,
+        input: SetDataRetrievalPolicyInput,
+    ) -> RusotoFuture<(), SetDataRetrievalPolicyError> {
+        (**self).set_data_retrieval_policy(input)
+    }
+    fn set_vault_access_policy(
+        &self,
+        input: SetVaultAccessPolicyInput,
+    ) -> RusotoFuture<(), SetVaultAccessPolicyError> {
+        (**self).set_vault_access_policy(input)
+    }
+    fn set_vault_notifications(
+        &self,
+        input: SetVaultNotificationsInput,
+    ) -> RusotoFuture<(), SetVaultNotificationsError> {
+        (**self).set_vault_notifications(input)
+    }
+    fn upload_archive(
+        &self,
+        input: UploadArchiveInput,
+    ) -> RusotoFuture<ArchiveCreationOutput, UploadArchiveError> {
+        (**self).upload_archive(input)
+    }
+    fn upload_multipart_part(
+        &self,
+        input: UploadMultipartPartInput,
+    ) -> RusotoFuture<UploadMultipartPartOutput, UploadMultipartPartError> {
+        (**self).upload_multipart_part(input)
+    }
+}
+impl<T: Glacier + ?Sized> Glacier for ::std::sync::Arc<T> {
+    fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadInput,
+    ) -> RusotoFuture<(), AbortMultipartUploadError> {
+        (**self).abort_multipart_upload(input)
+    }
+    fn abort_vault_lock(
+        &self,
+        input: AbortVaultLockInput,
+    ) -> RusotoFuture<(), AbortVaultLockError> {
+        (**self).abort_vault_lock(input)
+    }
+    fn add_tags_to_vault(
+        &self,
+        input: AddTagsToVaultInput,
+    ) -> RusotoFuture<(), AddTagsToVaultError> {
+        (**self).add_tags_to_vault(input)
+    }
+    fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadInput,
+    ) -> RusotoFuture<ArchiveCreationOutput, CompleteMultipartUploadError> {
+        (**self).complete_multipart_upload(input)
+    }
+    fn complete_vault_lock(
+        &self,
+        input: CompleteVaultLockInput,
+    ) -> RusotoFuture<(), CompleteVaultLockError> {
+        (**self).complete_vault_lock(input)
+    }
+    fn create_vault(
+        &self,
+        input: CreateVaultInput,
+    ) -> RusotoFuture<CreateVaultOutput, CreateVaultError> {
+        (**self).create_vault(input)
+    }
+    fn delete_archive(&self, input: DeleteArchiveInput) -> RusotoFuture<(), DeleteArchiveError> {
+        (**self).delete_archive(input)
+    }
+    fn delete_vault(&self, input: DeleteVaultInput) -> RusotoFuture<(), DeleteVaultError> {
+        (**self).delete_vault(input)
+    }
+    fn delete_vault_access_policy(
+        &self,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> RusotoFuture<(), DeleteVaultAccessPolicyError> {
+        (**self).delete_vault_access_policy(input)
+    }
+    fn delete_vault_notifications(
+        &self,
+        input: DeleteVaultNotificationsInput,
+    ) -> RusotoFuture<(), DeleteVaultNotificationsError> {
+        (**self).delete_vault_notifications(input)
+    }
+    fn describe_job(
+        &self,
+        input: DescribeJobInput,
+    ) -> RusotoFuture<GlacierJobDescription, DescribeJobError> {
+        (**self).describe_job(input)
+    }
+    fn describe_vault(
+        &self,
+        input: DescribeVaultInput,
+    ) -> RusotoFuture<DescribeVaultOutput, DescribeVaultError> {
+        (**self).describe_vault(input)
+    }
+    fn get_data_retrieval_policy(
+        &self,
+        input: GetDataRetrievalPolicyInput,
+    ) -> RusotoFuture<GetDataRetrievalPolicyOutput, GetDataRetrievalPolicyError> {
+        (**self).get_data_retrieval_policy(input)
+    }
+    fn get_job_output(
+        &self,
+        input: GetJobOutputInput,
+    ) -> RusotoFuture<GetJobOutputOutput, GetJobOutputError> {
+        (**self).get_job_output(input)
+    }
+    fn get_vault_access_policy(
+        &self,
+        input: GetVaultAccessPolicyInput,
+    ) -> RusotoFuture<GetVaultAccessPolicyOutput, GetVaultAccessPolicyError> {
+        (**self).get_vault_access_policy(input)
+    }
+    fn get_vault_lock(
+        &self,
+        input: GetVaultLockInput,
+    ) -> RusotoFuture<GetVaultLockOutput, GetVaultLockError> {
+        (**self).get_vault_lock(input)
+    }
+    fn get_vault_notifications(
+        &self,
+        input: GetVaultNotificationsInput,
+    ) -> RusotoFuture<GetVaultNotificationsOutput, GetVaultNotificationsError> {
+        (**self).get_vault_notifications(input)
+    }
+    fn initiate_job(
+        &self,
+        input: InitiateJobInput,
+    ) -> RusotoFuture<InitiateJobOutput, InitiateJobError> {
+        (**self).initiate_job(input)
+    }
+    fn initiate_multipart_upload(
+        &self,
+        input: InitiateMultipartUploadInput,
+    ) -> RusotoFuture<InitiateMultipartUploadOutput, InitiateMultipartUploadError> {
+        (**self).initiate_multipart_upload(input)
+    }
+    fn initiate_vault_lock(
+        &self,
+        input: InitiateVaultLockInput,
+    ) -> RusotoFuture<InitiateVaultLockOutput, InitiateVaultLockError> {
+        (**self).initiate_vault_lock(input)
+    }
+    fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        (**self).list_multipart_uploads(input)
+    }
+    fn list_parts(&self, input: ListPartsInput) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        (**self).list_parts(input)
+    }
+    fn list_provisioned_capacity(
+        &self,
+        input: ListProvisionedCapacityInput,
+    ) -> RusotoFuture<ListProvisionedCapacityOutput, ListProvisionedCapacityError> {
+        (**self).list_provisioned_capacity(input)
+    }
+    fn list_tags_for_vault(
+        &self,
+        input: ListTagsForVaultInput,
+    ) -> RusotoFuture<ListTagsForVaultOutput, ListTagsForVaultError> {
+        (**self).list_tags_for_vault(input)
+    }
+    fn list_vaults(
+        &self,
+        input: ListVaultsInput,
+    ) -> RusotoFuture<ListVaultsOutput, ListVaultsError> {
+        (**self).list_vaults(input)
+    }
+    fn purchase_provisioned_capacity(
+        &self,
+        input: PurchaseProvisionedCapacityInput,
+    ) -> RusotoFuture<PurchaseProvisionedCapacityOutput, PurchaseProvisionedCapacityError> {
+        (**self).purchase_provisioned_capacity(input)
+    }
+    fn remove_tags_from_vault(
+        &self,
+        input: RemoveTagsFromVaultInput,
+    ) -> RusotoFuture<(), RemoveTagsFromVaultError> {
+        (**self).remove_tags_from_vault(input)
+    }
+    fn set_data_retrieval_policy(
+        &self,
+        input: SetDataRetrievalPolicyInput,
+    ) -> RusotoFuture<(), SetDataRetrievalPolicyError> {
+        (**self).set_data_retrieval_policy(input)
+    }
+    fn set_vault_access_policy(
+        &self,
+        input: SetVaultAccessPolicyInput,
+    ) -> RusotoFuture<(), SetVaultAccessPolicyError> {
+        (**self).set_vault_access_policy(input)
+    }
+    fn set_vault_notifications(
+        &self,
+        input: SetVaultNotificationsInput,
+    ) -> RusotoFuture<(), SetVaultNotificationsError> {
+        (**self).set_vault_notifications(input)
+    }
+    fn upload_archive(
+        &self,
+        input: UploadArchiveInput,
+    ) -> RusotoFuture<ArchiveCreationOutput, UploadArchiveError> {
+        (**self).upload_archive(input)
+    }
+    fn upload_multipart_part(
+        &self,
+        input: UploadMultipartPartInput,
+    ) -> RusotoFuture<UploadMultipartPartOutput, UploadMultipartPartError> {
+        (**self).upload_multipart_part(input)
+    }
+}
+/// A client for the Amazon Glacier API.
+#[derive(Clone)]
+pub struct GlacierClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl GlacierClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GlacierClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GlacierClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> GlacierClient {
+        GlacierClient { client, region }
+    }
+}
+
+impl Glacier for GlacierClient {
+    /// <p>This operation aborts a multipart upload identified by the upload ID.</p> <p>After the Abort Multipart Upload request succeeds, you cannot upload any more parts to the multipart upload or complete the multipart upload. Aborting a completed upload fails. However, aborting an already-aborted upload will succeed, for a short time. For more information about uploading a part and completing a multipart upload, see <a>UploadMultipartPart</a> and <a>CompleteMultipartUpload</a>.</p> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-abort-upload.html">Abort Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadInput,
+    ) -> RusotoFuture<(), AbortMultipartUploadError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
+            account_id = input.account_id,
+            upload_id = input.upload_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("AbortMultipartUpload");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("uploadId", &input.upload_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AbortMultipartUploadError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation aborts the vault locking process if the vault lock is not in the <code>Locked</code> state. If the vault lock is in the <code>Locked</code> state when this operation is requested, the operation returns an <code>AccessDeniedException</code> error. Aborting the vault locking process removes the vault lock policy from the specified vault. </p> <p>A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. A vault lock is put into the <code>Locked</code> state by calling <a>CompleteVaultLock</a>. You can get the state of a vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. For more information about vault lock policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p> <p>This operation is idempotent. You can successfully invoke this operation multiple times, if the vault lock is in the <code>InProgress</code> state or if there is no policy associated with the vault.</p>
+    fn abort_vault_lock(
+        &self,
+        input: AbortVaultLockInput,
+    ) -> RusotoFuture<(), AbortVaultLockError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/lock-policy",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("AbortVaultLock");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AbortVaultLockError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation adds the specified tags to a vault. Each tag is composed of a key and a value. Each vault can have up to 10 tags. If your request would cause the tag limit for the vault to be exceeded, the operation throws the <code>LimitExceededException</code> error. If a tag already exists on the vault under a specified key, the existing key value will be overwritten. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>. </p>
+    fn add_tags_to_vault(
+        &self,
+        input: AddTagsToVaultInput,
+    ) -> RusotoFuture<(), AddTagsToVaultError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/tags",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("AddTagsToVault");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "add");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsToVaultError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>You call this operation to inform Amazon S3 Glacier (Glacier) that all the archive parts have been uploaded and that Glacier can now assemble the archive from the uploaded parts. After assembling and saving the archive to the vault, Glacier returns the URI path of the newly created archive resource. Using the URI path, you can then access the archive. After you upload an archive, you should save the archive ID returned to retrieve the archive at a later point. You can also get the vault inventory to obtain a list of archive IDs in a vault. For more information, see <a>InitiateJob</a>.</p> <p>In the request, you must include the computed SHA256 tree hash of the entire archive you have uploaded. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>. On the server side, Glacier also constructs the SHA256 tree hash of the assembled archive. If the values match, Glacier saves the archive to the vault; otherwise, it returns an error, and the operation fails. The <a>ListParts</a> operation returns a list of parts uploaded for a specific multipart upload. It includes checksum information for each uploaded part that can be used to debug a bad checksum issue.</p> <p>Additionally, Glacier also checks for any missing content ranges when assembling the archive, if missing content ranges are found, Glacier returns an error and the operation fails.</p> <p>Complete Multipart Upload is an idempotent operation. After your first successful complete multipart upload, if you call the operation again within a short period, the operation will succeed and return the same archive ID. This is useful in the event you experience a network issue that causes an aborted connection or receive a 500 server error, in which case you can repeat your Complete Multipart Upload request and get the same archive ID without creating duplicate archives. Note, however, that after the multipart upload completes, you cannot call the List Parts operation and the multipart upload will not appear in List Multipart Uploads response, even if idempotent complete is possible.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-complete-upload.html">Complete Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadInput,
+    ) -> RusotoFuture<ArchiveCreationOutput, CompleteMultipartUploadError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
+            account_id = input.account_id,
+            upload_id = input.upload_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("CompleteMultipartUpload");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("uploadId", &input.upload_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        if let Some(ref archive_size) = input.archive_size {
+            request.add_header("x-amz-archive-size", &archive_size.to_string());
+        }
+
+        if let Some(ref checksum) = input.checksum {
+            request.add_header("x-amz-sha256-tree-hash", &checksum.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CompleteMultipartUpload")
+                        .deserialize::<ArchiveCreationOutput, _>()?;
+                    if let Some(archive_id) = response.headers.get("x-amz-archive-id") {
+                        let value = archive_id.to_owned();
+                        result.archive_id = Some(value)
+                    };
+                    if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
+                        let value = checksum.to_owned();
+                        result.checksum = Some(value)
+                    };
+                    if let Some(location) = response.headers.get("Location") {
+                        let value = location.to_owned();
+                        result.location = Some(value)
+                    };
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CompleteMultipartUploadError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>This operation completes the vault locking process by transitioning the vault lock from the <code>InProgress</code> state to the <code>Locked</code> state, which causes the vault lock policy to become unchangeable. A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. You can obtain the state of the vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. </p> <p>This operation is idempotent. This request is always successful if the vault lock is in the <code>Locked</code> state and the provided lock ID matches the lock ID originally used to lock the vault.</p> <p>If an invalid lock ID is passed in the request when the vault lock is in the <code>Locked</code> state, the operation returns an <code>AccessDeniedException</code> error. If an invalid lock ID is passed in the request when the vault lock is in the <code>InProgress</code> state, the operation throws an <code>InvalidParameter</code> error.</p>
+    fn complete_vault_lock(
+        &self,
+        input: CompleteVaultLockInput,
+    ) -> RusotoFuture<(), CompleteVaultLockError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/lock-policy/{lock_id}",
+            account_id = input.account_id,
+            lock_id = input.lock_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("CompleteVaultLock");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("lockId", &input.lock_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CompleteVaultLockError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation creates a new vault with the specified name. The name of the vault must be unique within a region for an AWS account. You can create up to 1,000 vaults per account. If you need to create more vaults, contact Amazon S3 Glacier.</p> <p>You must use the following guidelines when naming a vault.</p> <ul> <li> <p>Names can be between 1 and 255 characters long.</p> </li> <li> <p>Allowed characters are a-z, A-Z, 0-9, '_' (underscore), '-' (hyphen), and '.' (period).</p> </li> </ul> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/creating-vaults.html">Creating a Vault in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-put.html">Create Vault </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    fn create_vault(
+        &self,
+        input: CreateVaultInput,
+    ) -> RusotoFuture<CreateVaultOutput, CreateVaultError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("CreateVault");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateVault")
+                        .deserialize::<CreateVaultOutput, _>()?;
+                    if let Some(location) = response.headers.get("Location") {
+                        let value = location.to_owned();
+                        result.location = Some(value)
+                    };
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateVaultError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation deletes an archive from a vault. Subsequent requests to initiate a retrieval of this archive will fail. Archive retrievals that are in progress for this archive ID may or may not succeed according to the following scenarios:</p> <ul> <li> <p>If the archive retrieval job is actively preparing the data for download when Amazon S3 Glacier receives the delete archive request, the archival retrieval operation might fail.</p> </li> <li> <p>If the archive retrieval job has successfully prepared the archive for download when Amazon S3 Glacier receives the delete archive request, you will be able to download the output.</p> </li> </ul> <p>This operation is idempotent. Attempting to delete an already-deleted archive does not result in an error.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/deleting-an-archive.html">Deleting an Archive in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-delete.html">Delete Archive</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    fn delete_archive(&self, input: DeleteArchiveInput) -> RusotoFuture<(), DeleteArchiveError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/archives/{archive_id}",
+            account_id = input.account_id,
+            archive_id = input.archive_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteArchive");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("archiveId", &input.archive_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteArchiveError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation deletes a vault. Amazon S3 Glacier will delete a vault only if there are no archives in the vault as of the last inventory and there have been no writes to the vault since the last inventory. If either of these conditions is not satisfied, the vault deletion fails (that is, the vault is not removed) and Amazon S3 Glacier returns an error. You can use <a>DescribeVault</a> to return the number of archives in a vault, and you can use <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-initiate-job-post.html">Initiate a Job (POST jobs)</a> to initiate a new inventory retrieval for a vault. The inventory contains the archive IDs you use to delete archives using <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-delete.html">Delete Archive (DELETE archive)</a>.</p> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/deleting-vaults.html">Deleting a Vault in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-delete.html">Delete Vault </a> in the <i>Amazon S3 Glacier Developer Guide</i>. </p>
+    fn delete_vault(&self, input: DeleteVaultInput) -> RusotoFuture<(), DeleteVaultError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVault");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteVaultError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation deletes the access policy associated with the specified vault. The operation is eventually consistent; that is, it might take some time for Amazon S3 Glacier to completely remove the access policy, and you might still see the effect of the policy for a short time after you send the delete request.</p> <p>This operation is idempotent. You can invoke delete multiple times, even if there is no policy associated with the vault. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>. </p>
+    fn delete_vault_access_policy(
+        &self,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> RusotoFuture<(), DeleteVaultAccessPolicyError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/access-policy",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVaultAccessPolicy");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteVaultAccessPolicyError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>This operation deletes the notification configuration set for a vault. The operation is eventually consistent; that is, it might take some time for Amazon S3 Glacier to completely disable the notifications and you might still receive some notifications for a short time after you send the delete request.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-delete.html">Delete Vault Notification Configuration </a> in the Amazon S3 Glacier Developer Guide. </p>
+    fn delete_vault_notifications(
+        &self,
+        input: DeleteVaultNotificationsInput,
+    ) -> RusotoFuture<(), DeleteVaultNotificationsError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/notification-configuration",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVaultNotifications");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteVaultNotificationsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>This operation returns information about a job you previously initiated, including the job initiation date, the user who initiated the job, the job status code/message and the Amazon SNS topic to notify after Amazon S3 Glacier (Glacier) completes the job. For more information about initiating a job, see <a>InitiateJob</a>. </p> <note> <p>This operation enables you to check the status of your job. However, it is strongly recommended that you set up an Amazon SNS topic and specify it in your initiate job request so that Glacier can notify the topic after it completes the job.</p> </note> <p>A job ID will not expire for at least 24 hours after Glacier completes the job.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-describe-job-get.html">Describe Job</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    fn describe_job(
+        &self,
+        input: DescribeJobInput,
+    ) -> RusotoFuture<GlacierJobDescription, DescribeJobError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/jobs/{job_id}",
+            account_id = input.account_id,
+            job_id = input.job_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("DescribeJob");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("jobId", &input.job_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeJob")
+                        .deserialize::<GlacierJobDescription, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation returns information about a vault, including the vault's Amazon Resource Name (ARN), the date the vault was created, the number of archives it contains, and the total size of all the archives in the vault. The number of archives and their total size are as of the last inventory generation. This means that if you add or remove an archive from a vault, and then immediately use Describe Vault, the change in contents will not be immediately reflected. If you want to retrieve the latest inventory of the vault, use <a>InitiateJob</a>. Amazon S3 Glacier generates vault inventories approximately daily. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-inventory.html">Downloading a Vault Inventory in Amazon S3 Glacier</a>. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/retrieving-vault-info.html">Retrieving Vault Metadata in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-get.html">Describe Vault </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    fn describe_vault(
+        &self,
+        input: DescribeVaultInput,
+    ) -> RusotoFuture<DescribeVaultOutput, DescribeVaultError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("DescribeVault");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeVault")
+                        .deserialize::<DescribeVaultOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeVaultError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation returns the current data retrieval policy for the account and region specified in the GET request. For more information about data retrieval policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/data-retrieval-policy.html">Amazon Glacier Data Retrieval Policies</a>.</p>
+    fn get_data_retrieval_policy(
+        &self,
+        input: GetDataRetrievalPolicyInput,
+    ) -> RusotoFuture<GetDataRetrievalPolicyOutput, GetDataRetrievalPolicyError> {
+        let request_uri = format!(
+            "/{account_id}/policies/data-retrieval",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetDataRetrievalPolicy");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDataRetrievalPolicy")
+                        .deserialize::<GetDataRetrievalPolicyOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetDataRetrievalPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation downloads the output of the job you initiated using <a>InitiateJob</a>. Depending on the job type you specified when you initiated the job, the output will be either the content of an archive or a vault inventory.</p> <p>You can download all the job output or download a portion of the output by specifying a byte range. In the case of an archive retrieval job, depending on the byte range you specify, Amazon S3 Glacier (Glacier) returns the checksum for the portion of the data. You can compute the checksum on the client and verify that the values match to ensure the portion you downloaded is the correct data.</p> <p>A job ID will not expire for at least 24 hours after Glacier completes the job. That a byte range. For both archive and inventory retrieval jobs, you should verify the downloaded size against the size returned in the headers from the <b>Get Job Output</b> response.</p> <p>For archive retrieval jobs, you should also verify that the size is what you expected. If you download a portion of the output, the expected size is based on the range of bytes you specified. For example, if you specify a range of <code>bytes=0-1048575</code>, you should verify your download size is 1,048,576 bytes. If you download an entire archive, the expected size is the size of the archive when you uploaded it to Amazon S3 Glacier The expected size is also returned in the headers from the <b>Get Job Output</b> response.</p> <p>In the case of an archive retrieval job, depending on the byte range you specify, Glacier returns the checksum for the portion of the data. To ensure the portion you downloaded is the correct data, compute the checksum on the client, verify that the values match, and verify that the size is what you expected.</p> <p>A job ID does not expire for at least 24 hours after Glacier completes the job. That is, you can download the job output within the 24 hours period after Amazon Glacier completes the job.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-inventory.html">Downloading a Vault Inventory</a>, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/downloading-an-archive.html">Downloading an Archive</a>, and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-job-output-get.html">Get Job Output </a> </p>
+    fn get_job_output(
+        &self,
+        input: GetJobOutputInput,
+    ) -> RusotoFuture<GetJobOutputOutput, GetJobOutputError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/jobs/{job_id}/output",
+            account_id = input.account_id,
+            job_id = input.job_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetJobOutput");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("jobId", &input.job_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        if let Some(ref range) = input.range {
+            request.add_header("Range", &range.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = GetJobOutputOutput::default();
+                    result.body = Some(response.body);
+
+                    if let Some(accept_ranges) = response.headers.get("Accept-Ranges") {
+                        let value = accept_ranges.to_owned();
+                        result.accept_ranges = Some(value)
+                    };
+                    if let Some(archive_description) =
+                        response.headers.get("x-amz-archive-description")
+                    {
+                        let value = archive_description.to_owned();
+                        result.archive_description = Some(value)
+                    };
+                    if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
+                        let value = checksum.to_owned();
+                        result.checksum = Some(value)
+                    };
+                    if let Some(content_range) = response.headers.get("Content-Range") {
+                        let value = content_range.to_owned();
+                        result.content_range = Some(value)
+                    };
+                    if let Some(content_type) = response.headers.get("Content-Type") {
+                        let value = content_type.to_owned();
+                        result.content_type = Some(value)
+                    };
+                    result.status = Some(response.status.as_u16() as i64);
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetJobOutputError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation retrieves the <code>access-policy</code> subresource set on the vault; for more information on setting this subresource, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-SetVaultAccessPolicy.html">Set Vault Access Policy (PUT access-policy)</a>. If there is no access policy set on the vault, the operation returns a <code>404 Not found</code> error. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>.</p>
+    fn get_vault_access_policy(
+        &self,
+        input: GetVaultAccessPolicyInput,
+    ) -> RusotoFuture<GetVaultAccessPolicyOutput, GetVaultAccessPolicyError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/access-policy",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultAccessPolicy");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetVaultAccessPolicy")
+                        .deserialize::<GetVaultAccessPolicyOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetVaultAccessPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation retrieves the following attributes from the <code>lock-policy</code> subresource set on the specified vault: </p> <ul> <li> <p>The vault lock policy set on the vault.</p> </li> <li> <p>The state of the vault lock, which is either <code>InProgess</code> or <code>Locked</code>.</p> </li> <li> <p>When the lock ID expires. The lock ID is used to complete the vault locking process.</p> </li> <li> <p>When the vault lock was initiated and put into the <code>InProgress</code> state.</p> </li> </ul> <p>A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. A vault lock is put into the <code>Locked</code> state by calling <a>CompleteVaultLock</a>. You can abort the vault locking process by calling <a>AbortVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. </p> <p>If there is no vault lock policy set on the vault, the operation returns a <code>404 Not found</code> error. For more information about vault lock policies, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p>
+    fn get_vault_lock(
+        &self,
+        input: GetVaultLockInput,
+    ) -> RusotoFuture<GetVaultLockOutput, GetVaultLockError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/lock-policy",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultLock");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetVaultLock")
+                        .deserialize::<GetVaultLockOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetVaultLockError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation retrieves the <code>notification-configuration</code> subresource of the specified vault.</p> <p>For information about setting a notification configuration on a vault, see <a>SetVaultNotifications</a>. If a notification configuration for a vault is not set, the operation returns a <code>404 Not Found</code> error. For more information about vault notifications, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a>. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-get.html">Get Vault Notification Configuration </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    fn get_vault_notifications(
+        &self,
+        input: GetVaultNotificationsInput,
+    ) -> RusotoFuture<GetVaultNotificationsOutput, GetVaultNotificationsError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/notification-configuration",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultNotifications");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetVaultNotifications")
+                        .deserialize::<GetVaultNotificationsOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetVaultNotificationsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation initiates a job of the specified type, which can be a select, an archival retrieval, or a vault retrieval. For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-initiate-job-post.html">Initiate a Job</a>. </p>
+    fn initiate_job(
+        &self,
+        input: InitiateJobInput,
+    ) -> RusotoFuture<InitiateJobOutput, InitiateJobError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/jobs",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateJob");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let encoded = Some(serde_json::to_vec(&input.job_parameters).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("InitiateJob")
+                        .deserialize::<InitiateJobOutput, _>()?;
+                    if let Some(job_id) = response.headers.get("x-amz-job-id") {
+                        let value = job_id.to_owned();
+                        result.job_id = Some(value)
+                    };
+                    if let Some(job_output_path) = response.headers.get("x-amz-job-output-path") {
+                        let value = job_output_path.to_owned();
+                        result.job_output_path = Some(value)
+                    };
+                    if let Some(location) = response.headers.get("Location") {
+                        let value = location.to_owned();
+                        result.location = Some(value)
+                    };
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(InitiateJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation initiates a multipart upload. Amazon S3 Glacier creates a multipart upload resource and returns its ID in the response. The multipart upload ID is used in subsequent requests to upload parts of an archive (see <a>UploadMultipartPart</a>).</p> <p>When you initiate a multipart upload, you specify the part size in number of bytes. The part size must be a megabyte (1024 KB) multiplied by a power of 2-for example, 1048576 (1 MB), 2097152 (2 MB), 4194304 (4 MB), 8388608 (8 MB), and so on. The minimum allowable part size is 1 MB, and the maximum is 4 GB.</p> <p>Every part you upload to this resource (see <a>UploadMultipartPart</a>), except the last one, must have the same size. The last one can be the same size or smaller. For example, suppose you want to upload a 16.2 MB file. If you initiate the multipart upload with a part size of 4 MB, you will upload four parts of 4 MB each and one part of 0.2 MB. </p> <note> <p>You don't need to know the size of the archive when you start a multipart upload because Amazon S3 Glacier does not require you to specify the overall archive size.</p> </note> <p>After you complete the multipart upload, Amazon S3 Glacier (Glacier) removes the multipart upload resource referenced by the ID. Glacier also removes the multipart upload resource if you cancel the multipart upload or it may be removed if there is no activity for a period of 24 hours.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-initiate-upload.html">Initiate Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    fn initiate_multipart_upload(
+        &self,
+        input: InitiateMultipartUploadInput,
+    ) -> RusotoFuture<InitiateMultipartUploadOutput, InitiateMultipartUploadError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/multipart-uploads",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateMultipartUpload");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        if let Some(ref archive_description) = input.archive_description {
+            request.add_header(
+                "x-amz-archive-description",
+                &archive_description.to_string(),
+            );
+        }
+
+        if let Some(ref part_size) = input.part_size {
+            request.add_header("x-amz-part-size", &part_size.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("InitiateMultipartUpload")
+                        .deserialize::<InitiateMultipartUploadOutput, _>()?;
+                    if let Some(location) = response.headers.get("Location") {
+                        let value = location.to_owned();
+                        result.location = Some(value)
+                    };
+                    if let Some(upload_id) = response.headers.get("x-amz-multipart-upload-id") {
+                        let value = upload_id.to_owned();
+                        result.upload_id = Some(value)
+                    };
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(InitiateMultipartUploadError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>This operation initiates the vault locking process by doing the following:</p> <ul> <li> <p>Installing a vault lock policy on the specified vault.</p> </li> <li> <p>Setting the lock state of vault lock to <code>InProgress</code>.</p> </li> <li> <p>Returning a lock ID, which is used to complete the vault locking process.</p> </li> </ul> <p>You can set one vault lock policy for each vault and this policy can be up to 20 KB in size. For more information about vault lock policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p> <p>You must complete the vault locking process within 24 hours after the vault lock enters the <code>InProgress</code> state. After the 24 hour window ends, the lock ID expires, the vault automatically exits the <code>InProgress</code> state, and the vault lock policy is removed from the vault. You call <a>CompleteVaultLock</a> to complete the vault locking process by setting the state of the vault lock to <code>Locked</code>. </p> <p>After a vault lock is in the <code>Locked</code> state, you cannot initiate a new vault lock for the vault.</p> <p>You can abort the vault locking process by calling <a>AbortVaultLock</a>. You can get the state of the vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>.</p> <p>If this operation is called when the vault lock is in the <code>InProgress</code> state, the operation returns an <code>AccessDeniedException</code> error. When the vault lock is in the <code>InProgress</code> state you must call <a>AbortVaultLock</a> before you can initiate a new vault lock policy. </p>
+    fn initiate_vault_lock(
+        &self,
+        input: InitiateVaultLockInput,
+    ) -> RusotoFuture<InitiateVaultLockOutput, InitiateVaultLockError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/lock-policy",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateVaultLock");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let encoded = Some(serde_json::to_vec(&input.policy).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("InitiateVaultLock")
+                        .deserialize::<InitiateVaultLockOutput, _>()?;
+                    if let Some(lock_id) = response.headers.get("x-amz-lock-id") {
+                        let value = lock_id.to_owned();
+                        result.lock_id = Some(value)
+                    };
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(InitiateVaultLockError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation lists jobs for a vault, including jobs that are in-progress and jobs that have recently finished. The List Job operation returns a list of these jobs sorted by job initiation time.</p> <note> <p>Amazon Glacier retains recently completed jobs for a period before deleting them; however, it eventually removes completed jobs. The output of completed jobs can be retrieved. Retaining completed jobs for a period of time after they have completed enables you to get a job output in the event you miss the job completion notification or your first attempt to download it fails. For example, suppose you start an archive retrieval job to download an archive. After the job completes, you start to download the archive but encounter a network error. In this scenario, you can retry and download the archive while the job exists.</p> </note> <p>The List Jobs operation supports pagination. You should always check the response <code>Marker</code> field. If there are no more jobs to list, the <code>Marker</code> field is set to <code>null</code>. If there are more jobs to list, the <code>Marker</code> field is set to a non-null value, which you can use to continue the pagination of the list. To return a list of jobs that begins at a specific job, set the marker request parameter to the <code>Marker</code> value for that job that you obtained from a previous List Jobs request.</p> <p>You can set a maximum limit for the number of jobs returned in the response by specifying the <code>limit</code> parameter in the request. The default limit is 50. The number of jobs returned might be fewer than the limit, but the number of returned jobs never exceeds the limit.</p> <p>Additionally, you can filter the jobs list returned by specifying the optional <code>statuscode</code> parameter or <code>completed</code> parameter, or both. Using the <code>statuscode</code> parameter, you can specify to return only jobs that match either the <code>InProgress</code>, <code>Succeeded</code>, or <code>Failed</code> status. Using the <code>completed</code> parameter, you can specify to return only jobs that were completed (<code>true</code>) or jobs that were not completed (<code>false</code>).</p> <p>For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-jobs-get.html">List Jobs</a>. </p>
+    fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/jobs",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListJobs");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.completed {
+            params.put("completed", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.statuscode {
+            params.put("statuscode", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListJobs")
+                        .deserialize::<ListJobsOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation lists in-progress multipart uploads for the specified vault. An in-progress multipart upload is a multipart upload that has been initiated by an <a>InitiateMultipartUpload</a> request, but has not yet been completed or aborted. The list returned in the List Multipart Upload response has no guaranteed order. </p> <p>The List Multipart Uploads operation supports pagination. By default, this operation returns up to 50 multipart uploads in the response. You should always check the response for a <code>marker</code> at which to continue the list; if there are no more items the <code>marker</code> is <code>null</code>. To return a list of multipart uploads that begins at a specific upload, set the <code>marker</code> request parameter to the value you obtained from a previous List Multipart Upload request. You can also limit the number of uploads returned in the response by specifying the <code>limit</code> parameter in the request.</p> <p>Note the difference between this operation and listing parts (<a>ListParts</a>). The List Multipart Uploads operation lists all multipart uploads for a vault and does not require a multipart upload ID. The List Parts operation requires a multipart upload ID since parts are associated with a single upload.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-list-uploads.html">List Multipart Uploads </a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/multipart-uploads",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListMultipartUploads");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListMultipartUploads")
+                        .deserialize::<ListMultipartUploadsOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListMultipartUploadsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation lists the parts of an archive that have been uploaded in a specific multipart upload. You can make this request at any time during an in-progress multipart upload before you complete the upload (see <a>CompleteMultipartUpload</a>. List Parts returns an error for completed uploads. The list returned in the List Parts response is sorted by part range. </p> <p>The List Parts operation supports pagination. By default, this operation returns up to 50 uploaded parts in the response. You should always check the response for a <code>marker</code> at which to continue the list; if there are no more items the <code>marker</code> is <code>null</code>. To return a list of parts that begins at a specific part, set the <code>marker</code> request parameter to the value you obtained from a previous List Parts request. You can also limit the number of parts returned in the response by specifying the <code>limit</code> parameter in the request. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-list-parts.html">List Parts</a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    fn list_parts(&self, input: ListPartsInput) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
+            account_id = input.account_id,
+            upload_id = input.upload_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListParts");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("uploadId", &input.upload_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListParts")
+                        .deserialize::<ListPartsOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListPartsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation lists the provisioned capacity units for the specified AWS account.</p>
+    fn list_provisioned_capacity(
+        &self,
+        input: ListProvisionedCapacityInput,
+    ) -> RusotoFuture<ListProvisionedCapacityOutput, ListProvisionedCapacityError> {
+        let request_uri = format!(
+            "/{account_id}/provisioned-capacity",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListProvisionedCapacity");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListProvisionedCapacity")
+                        .deserialize::<ListProvisionedCapacityOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListProvisionedCapacityError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>This operation lists all the tags attached to a vault. The operation returns an empty map if there are no tags. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>.</p>
+    fn list_tags_for_vault(
+        &self,
+        input: ListTagsForVaultInput,
+    ) -> RusotoFuture<ListTagsForVaultOutput, ListTagsForVaultError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/tags",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListTagsForVault");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForVault")
+                        .deserialize::<ListTagsForVaultOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTagsForVaultError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation lists all vaults owned by the calling user's account. The list returned in the response is ASCII-sorted by vault name.</p> <p>By default, this operation returns up to 10 items. If there are more vaults to list, the response <code>marker</code> field contains the vault Amazon Resource Name (ARN) at which to continue the list with a new List Vaults request; otherwise, the <code>marker</code> field is <code>null</code>. To return a list of vaults that begins at a specific vault, set the <code>marker</code> request parameter to the vault ARN you obtained from a previous List Vaults request. You can also limit the number of vaults returned in the response by specifying the <code>limit</code> parameter in the request. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/retrieving-vault-info.html">Retrieving Vault Metadata in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vaults-get.html">List Vaults </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    fn list_vaults(
+        &self,
+        input: ListVaultsInput,
+    ) -> RusotoFuture<ListVaultsOutput, ListVaultsError> {
+        let request_uri = format!("/{account_id}/vaults", account_id = input.account_id);
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListVaults");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListVaults")
+                        .deserialize::<ListVaultsOutput, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListVaultsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation purchases a provisioned capacity unit for an AWS account. </p>
+    fn purchase_provisioned_capacity(
+        &self,
+        input: PurchaseProvisionedCapacityInput,
+    ) -> RusotoFuture<PurchaseProvisionedCapacityOutput, PurchaseProvisionedCapacityError> {
+        let request_uri = format!(
+            "/{account_id}/provisioned-capacity",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("PurchaseProvisionedCapacity");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PurchaseProvisionedCapacity")
+                        .deserialize::<PurchaseProvisionedCapacityOutput, _>()?;
+                    if let Some(capacity_id) = response.headers.get("x-amz-capacity-id") {
+                        let value = capacity_id.to_owned();
+                        result.capacity_id = Some(value)
+                    };
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PurchaseProvisionedCapacityError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>This operation removes one or more tags from the set of tags attached to a vault. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>. This operation is idempotent. The operation will be successful, even if there are no tags attached to the vault. </p>
+    fn remove_tags_from_vault(
+        &self,
+        input: RemoveTagsFromVaultInput,
+    ) -> RusotoFuture<(), RemoveTagsFromVaultError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/tags",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("RemoveTagsFromVault");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("operation", "remove");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RemoveTagsFromVaultError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation sets and then enacts a data retrieval policy in the region specified in the PUT request. You can set one policy per region for an AWS account. The policy is enacted within a few minutes of a successful PUT operation.</p> <p>The set policy operation does not affect retrieval jobs that were in progress before the policy was enacted. For more information about data retrieval policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/data-retrieval-policy.html">Amazon Glacier Data Retrieval Policies</a>. </p>
+    fn set_data_retrieval_policy(
+        &self,
+        input: SetDataRetrievalPolicyInput,
+    ) -> RusotoFuture<(), SetDataRetrievalPolicyError> {
+        let request_uri = format!(
+            "/{account_id}/policies/data-retrieval",
+            account_id = input.account_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetDataRetrievalPolicy");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SetDataRetrievalPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation configures an access policy for a vault and will overwrite an existing policy. To configure a vault access policy, send a PUT request to the <code>access-policy</code> subresource of the vault. An access policy is specific to a vault and is also called a vault subresource. You can set one access policy per vault and the policy can be up to 20 KB in size. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>. </p>
+    fn set_vault_access_policy(
+        &self,
+        input: SetVaultAccessPolicyInput,
+    ) -> RusotoFuture<(), SetVaultAccessPolicyError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/access-policy",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetVaultAccessPolicy");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let encoded = Some(serde_json::to_vec(&input.policy).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SetVaultAccessPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation configures notifications that will be sent when specific events happen to a vault. By default, you don't get any notifications.</p> <p>To configure vault notifications, send a PUT request to the <code>notification-configuration</code> subresource of the vault. The request should include a JSON document that provides an Amazon SNS topic and specific events for which you want Amazon S3 Glacier to send notifications to the topic.</p> <p>Amazon SNS topics must grant permission to the vault to be allowed to publish notifications to the topic. You can configure a vault to publish a notification for the following vault events:</p> <ul> <li> <p> <b>ArchiveRetrievalCompleted</b> This event occurs when a job that was initiated for an archive retrieval is completed (<a>InitiateJob</a>). The status of the completed job can be "Succeeded" or "Failed". The notification sent to the SNS topic is the same output as returned from <a>DescribeJob</a>. </p> </li> <li> <p> <b>InventoryRetrievalCompleted</b> This event occurs when a job that was initiated for an inventory retrieval is completed (<a>InitiateJob</a>). The status of the completed job can be "Succeeded" or "Failed". The notification sent to the SNS topic is the same output as returned from <a>DescribeJob</a>. </p> </li> </ul> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-put.html">Set Vault Notification Configuration </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    fn set_vault_notifications(
+        &self,
+        input: SetVaultNotificationsInput,
+    ) -> RusotoFuture<(), SetVaultNotificationsError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/notification-configuration",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetVaultNotifications");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let encoded = Some(serde_json::to_vec(&input.vault_notification_config).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SetVaultNotificationsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation adds an archive to a vault. This is a synchronous operation, and for a successful upload, your data is durably persisted. Amazon S3 Glacier returns the archive ID in the <code>x-amz-archive-id</code> header of the response. </p> <p>You must use the archive ID to access your data in Amazon S3 Glacier. After you upload an archive, you should save the archive ID returned so that you can retrieve or delete the archive later. Besides saving the archive ID, you can also index it and give it a friendly name to allow for better searching. You can also use the optional archive description field to specify how the archive is referred to in an external index of archives, such as you might create in Amazon DynamoDB. You can also get the vault inventory to obtain a list of archive IDs in a vault. For more information, see <a>InitiateJob</a>. </p> <p>You must provide a SHA256 tree hash of the data you are uploading. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>. </p> <p>You can optionally specify an archive description of up to 1,024 printable ASCII characters. You can get the archive description when you either retrieve the archive or get the vault inventory. For more information, see <a>InitiateJob</a>. Amazon Glacier does not interpret the description in any way. An archive description does not need to be unique. You cannot use the description to retrieve or sort the archive list. </p> <p>Archives are immutable. After you upload an archive, you cannot edit the archive or its description.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-an-archive.html">Uploading an Archive in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-post.html">Upload Archive</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    fn upload_archive(
+        &self,
+        input: UploadArchiveInput,
+    ) -> RusotoFuture<ArchiveCreationOutput, UploadArchiveError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/archives",
+            account_id = input.account_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("UploadArchive");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let encoded = if let Some(ref payload) = input.body {
+            Some(payload.to_owned())
+        } else {
+            None
+        };
+        request.set_payload(encoded);
+
+        if let Some(ref archive_description) = input.archive_description {
+            request.add_header(
+                "x-amz-archive-description",
+                &archive_description.to_string(),
+            );
+        }
+
+        if let Some(ref checksum) = input.checksum {
+            request.add_header("x-amz-sha256-tree-hash", &checksum.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UploadArchive")
+                        .deserialize::<ArchiveCreationOutput, _>()?;
+                    if let Some(archive_id) = response.headers.get("x-amz-archive-id") {
+                        let value = archive_id.to_owned();
+                        result.archive_id = Some(value)
+                    };
+                    if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
+                        let value = checksum.to_owned();
+                        result.checksum = Some(value)
+                    };
+                    if let Some(location) = response.headers.get("Location") {
+                        let value = location.to_owned();
+                        result.location = Some(value)
+                    };
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UploadArchiveError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>This operation uploads a part of an archive. You can upload archive parts in any order. You can also upload them in parallel. You can upload up to 10,000 parts for a multipart upload.</p> <p>Amazon Glacier rejects your upload part request if any of the following conditions is true:</p> <ul> <li> <p> <b>SHA256 tree hash does not match</b>To ensure that part data is not corrupted in transmission, you compute a SHA256 tree hash of the part and include it in your request. Upon receiving the part data, Amazon S3 Glacier also computes a SHA256 tree hash. If these hash values don't match, the operation fails. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>.</p> </li> <li> <p> <b>Part size does not match</b>The size of each part except the last must match the size specified in the corresponding <a>InitiateMultipartUpload</a> request. The size of the last part must be the same size as, or smaller than, the specified size.</p> <note> <p>If you upload a part whose size is smaller than the part size you specified in your initiate multipart upload request and that part is not the last part, then the upload part request will succeed. However, the subsequent Complete Multipart Upload request will fail.</p> </note> </li> <li> <p> <b>Range does not align</b>The byte range value in the request does not align with the part size specified in the corresponding initiate request. For example, if you specify a part size of 4194304 bytes (4 MB), then 0 to 4194303 bytes (4 MB - 1) and 4194304 (4 MB) to 8388607 (8 MB - 1) are valid part ranges. However, if you set a range value of 2 MB to 6 MB, the range does not align with the part size and the upload will fail. </p> </li> </ul> <p>This operation is idempotent. If you upload the same part multiple times, the data included in the most recent request overwrites the previously uploaded data.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-upload-part.html">Upload Part </a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    fn upload_multipart_part(
+        &self,
+        input: UploadMultipartPartInput,
+    ) -> RusotoFuture<UploadMultipartPartOutput, UploadMultipartPartError> {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
+            account_id = input.account_id,
+            upload_id = input.upload_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("UploadMultipartPart");
+        request.add_resource_id("accountId", &input.account_id.to_string());
+        request.add_resource_id("uploadId", &input.upload_id.to_string());
+        request.add_resource_id("vaultName", &input.vault_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let encoded = if let Some(ref payload) = input.body {
+            Some(payload.to_owned())
+        } else {
+            None
+        };
+        request.set_payload(encoded);
+
+        if let Some(ref checksum) = input.checksum {
+            request.add_header("x-amz-sha256-tree-hash", &checksum.to_string());
+        }
+
+        if let Some(ref range) = input.range {
+            request.add_header("Content-Range", &range.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UploadMultipartPart")
+                        .deserialize::<UploadMultipartPartOutput, _>()?;
+                    if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
+                        let value = checksum.to_owned();
+                        result.checksum = Some(value)
+                    };
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UploadMultipartPartError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon Glacier API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockGlacier {
+    abort_multipart_upload: Option<
+        Box<
+            dyn Fn(
+                    AbortMultipartUploadInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<AbortMultipartUploadError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    abort_vault_lock: Option<
+        Box<
+            dyn Fn(
+                    AbortVaultLockInput,
+                ) -> ::std::result::Result<(), RusotoError<AbortVaultLockError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    add_tags_to_vault: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToVaultInput,
+                ) -> ::std::result::Result<(), RusotoError<AddTagsToVaultError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    complete_multipart_upload: Option<
+        Box<
+            dyn Fn(
+                    CompleteMultipartUploadInput,
+                ) -> ::std::result::Result<
+                    ArchiveCreationOutput,
+                    RusotoError<CompleteMultipartUploadError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    complete_vault_lock: Option<
+        Box<
+            dyn Fn(
+                    CompleteVaultLockInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<CompleteVaultLockError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_vault: Option<
+        Box<
+            dyn Fn(
+                    CreateVaultInput,
+                )
+                    -> ::std::result::Result<CreateVaultOutput, RusotoError<CreateVaultError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_archive: Option<
+        Box<
+            dyn Fn(DeleteArchiveInput) -> ::std::result::Result<(), RusotoError<DeleteArchiveError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_vault: Option<
+        Box<
+            dyn Fn(DeleteVaultInput) -> ::std::result::Result<(), RusotoError<DeleteVaultError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_vault_access_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteVaultAccessPolicyInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteVaultAccessPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_vault_notifications: Option<
+        Box<
+            dyn Fn(
+                    DeleteVaultNotificationsInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteVaultNotificationsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeJobInput,
+                )
+                    -> ::std::result::Result<GlacierJobDescription, RusotoError<DescribeJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_vault: Option<
+        Box<
+            dyn Fn(
+                    DescribeVaultInput,
+                )
+                    -> ::std::result::Result<DescribeVaultOutput, RusotoError<DescribeVaultError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_data_retrieval_policy: Option<
+        Box<
+            dyn Fn(
+                    GetDataRetrievalPolicyInput,
+                ) -> ::std::result::Result<
+                    GetDataRetrievalPolicyOutput,
+                    RusotoError<GetDataRetrievalPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_job_output: Option<
+        Box<
+            dyn Fn(
+                    GetJobOutputInput,
+                )
+                    -> ::std::result::Result<GetJobOutputOutput, RusotoError<GetJobOutputError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_vault_access_policy: Option<
+        Box<
+            dyn Fn(
+                    GetVaultAccessPolicyInput,
+                ) -> ::std::result::Result<
+                    GetVaultAccessPolicyOutput,
+                    RusotoError<GetVaultAccessPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_vault_lock: Option<
+        Box<
+            dyn Fn(
+                    GetVaultLockInput,
+                )
+                    -> ::std::result::Result<GetVaultLockOutput, RusotoError<GetVaultLockError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_vault_notifications: Option<
+        Box<
+            dyn Fn(
+                    GetVaultNotificationsInput,
+                ) -> ::std::result::Result<
+                    GetVaultNotificationsOutput,
+                    RusotoError<GetVaultNotificationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    initiate_job: Option<
+        Box<
+            dyn Fn(
+                    InitiateJobInput,
+                )
+                    -> ::std::result::Result<InitiateJobOutput, RusotoError<InitiateJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    initiate_multipart_upload: Option<
+        Box<
+            dyn Fn(
+                    InitiateMultipartUploadInput,
+                ) -> ::std::result::Result<
+                    InitiateMultipartUploadOutput,
+                    RusotoError<InitiateMultipartUploadError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    initiate_vault_lock: Option<
+        Box<
+            dyn Fn(
+                    InitiateVaultLockInput,
+                ) -> ::std::result::Result<
+                    InitiateVaultLockOutput,
+                    RusotoError<InitiateVaultLockError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListJobsInput,
+                )
+                    -> ::std::result::Result<ListJobsOutput, RusotoError<ListJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_multipart_uploads: Option<
+        Box<
+            dyn Fn(
+                    ListMultipartUploadsInput,
+                ) -> ::std::result::Result<
+                    ListMultipartUploadsOutput,
+                    RusotoError<ListMultipartUploadsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_parts: Option<
+        Box<
+            dyn Fn(
+                    ListPartsInput,
+                )
+                    -> ::std::result::Result<ListPartsOutput, RusotoError<ListPartsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_provisioned_capacity: Option<
+        Box<
+            dyn Fn(
+                    ListProvisionedCapacityInput,
+                ) -> ::std::result::Result<
+                    ListProvisionedCapacityOutput,
+                    RusotoError<ListProvisionedCapacityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_vault: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForVaultInput,
+                ) -> ::std::result::Result<
+                    ListTagsForVaultOutput,
+                    RusotoError<ListTagsForVaultError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_vaults: Option<
+        Box<
+            dyn Fn(
+                    ListVaultsInput,
+                )
+                    -> ::std::result::Result<ListVaultsOutput, RusotoError<ListVaultsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    purchase_provisioned_capacity: Option<
+        Box<
+            dyn Fn(
+                    PurchaseProvisionedCapacityInput,
+                ) -> ::std::result::Result<
+                    PurchaseProvisionedCapacityOutput,
+                    RusotoError<PurchaseProvisionedCapacityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_vault: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromVaultInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RemoveTagsFromVaultError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_data_retrieval_policy: Option<
+        Box<
+            dyn Fn(
+                    SetDataRetrievalPolicyInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetDataRetrievalPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_vault_access_policy: Option<
+        Box<
+            dyn Fn(
+                    SetVaultAccessPolicyInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetVaultAccessPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_vault_notifications: Option<
+        Box<
+            dyn Fn(
+                    SetVaultNotificationsInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetVaultNotificationsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    upload_archive: Option<
+        Box<
+            dyn Fn(
+                    UploadArchiveInput,
+                )
+                    -> ::std::result::Result<ArchiveCreationOutput, RusotoError<UploadArchiveError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    upload_multipart_part: Option<
+        Box<
+            dyn Fn(
+                    UploadMultipartPartInput,
+                ) -> ::std::result::Result<
+                    UploadMultipartPartOutput,
+                    RusotoError<UploadMultipartPartError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockGlacier {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockGlacier {
+        Default::default()
+    }
+
+    /// Answers `abort_multipart_upload` with what `handler` returns for its input.
+    pub fn with_abort_multipart_upload<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                AbortMultipartUploadInput,
+            ) -> ::std::result::Result<(), RusotoError<AbortMultipartUploadError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.abort_multipart_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `abort_vault_lock` with what `handler` returns for its input.
+    pub fn with_abort_vault_lock<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(AbortVaultLockInput) -> ::std::result::Result<(), RusotoError<AbortVaultLockError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.abort_vault_lock = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_tags_to_vault` with what `handler` returns for its input.
+    pub fn with_add_tags_to_vault<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(AddTagsToVaultInput) -> ::std::result::Result<(), RusotoError<AddTagsToVaultError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_vault = Some(Box::new(handler));
+        self
+    }
+    /// Answers `complete_multipart_upload` with what `handler` returns for its input.
+    pub fn with_complete_multipart_upload<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                CompleteMultipartUploadInput,
+            ) -> ::std::result::Result<
+                ArchiveCreationOutput,
+                RusotoError<CompleteMultipartUploadError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.complete_multipart_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `complete_vault_lock` with what `handler` returns for its input.
+    pub fn with_complete_vault_lock<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                CompleteVaultLockInput,
+            ) -> ::std::result::Result<(), RusotoError<CompleteVaultLockError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.complete_vault_lock = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_vault` with what `handler` returns for its input.
+    pub fn with_create_vault<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                CreateVaultInput,
+            )
+                -> ::std::result::Result<CreateVaultOutput, RusotoError<CreateVaultError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_vault = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_archive` with what `handler` returns for its input.
+    pub fn with_delete_archive<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(DeleteArchiveInput) -> ::std::result::Result<(), RusotoError<DeleteArchiveError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_archive = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_vault` with what `handler` returns for its input.
+    pub fn with_delete_vault<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(DeleteVaultInput) -> ::std::result::Result<(), RusotoError<DeleteVaultError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_vault = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_vault_access_policy` with what `handler` returns for its input.
+    pub fn with_delete_vault_access_policy<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                DeleteVaultAccessPolicyInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteVaultAccessPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_vault_access_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_vault_notifications` with what `handler` returns for its input.
+    pub fn with_delete_vault_notifications<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                DeleteVaultNotificationsInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteVaultNotificationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_vault_notifications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_job` with what `handler` returns for its input.
+    pub fn with_describe_job<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                DescribeJobInput,
+            )
+                -> ::std::result::Result<GlacierJobDescription, RusotoError<DescribeJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_vault` with what `handler` returns for its input.
+    pub fn with_describe_vault<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                DescribeVaultInput,
+            )
+                -> ::std::result::Result<DescribeVaultOutput, RusotoError<DescribeVaultError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_vault = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_data_retrieval_policy` with what `handler` returns for its input.
+    pub fn with_get_data_retrieval_policy<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                GetDataRetrievalPolicyInput,
+            ) -> ::std::result::Result<
+                GetDataRetrievalPolicyOutput,
+                RusotoError<GetDataRetrievalPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_data_retrieval_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job_output` with what `handler` returns for its input.
+    pub fn with_get_job_output<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                GetJobOutputInput,
+            )
+                -> ::std::result::Result<GetJobOutputOutput, RusotoError<GetJobOutputError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job_output = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_vault_access_policy` with what `handler` returns for its input.
+    pub fn with_get_vault_access_policy<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                GetVaultAccessPolicyInput,
+            ) -> ::std::result::Result<
+                GetVaultAccessPolicyOutput,
+                RusotoError<GetVaultAccessPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_vault_access_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_vault_lock` with what `handler` returns for its input.
+    pub fn with_get_vault_lock<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                GetVaultLockInput,
+            )
+                -> ::std::result::Result<GetVaultLockOutput, RusotoError<GetVaultLockError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_vault_lock = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_vault_notifications` with what `handler` returns for its input.
+    pub fn with_get_vault_notifications<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                GetVaultNotificationsInput,
+            ) -> ::std::result::Result<
+                GetVaultNotificationsOutput,
+                RusotoError<GetVaultNotificationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_vault_notifications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `initiate_job` with what `handler` returns for its input.
+    pub fn with_initiate_job<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                InitiateJobInput,
+            )
+                -> ::std::result::Result<InitiateJobOutput, RusotoError<InitiateJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.initiate_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `initiate_multipart_upload` with what `handler` returns for its input.
+    pub fn with_initiate_multipart_upload<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                InitiateMultipartUploadInput,
+            ) -> ::std::result::Result<
+                InitiateMultipartUploadOutput,
+                RusotoError<InitiateMultipartUploadError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.initiate_multipart_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `initiate_vault_lock` with what `handler` returns for its input.
+    pub fn with_initiate_vault_lock<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                InitiateVaultLockInput,
+            ) -> ::std::result::Result<
+                InitiateVaultLockOutput,
+                RusotoError<InitiateVaultLockError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.initiate_vault_lock = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_jobs` with what `handler` returns for its input.
+    pub fn with_list_jobs<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(ListJobsInput) -> ::std::result::Result<ListJobsOutput, RusotoError<ListJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_multipart_uploads` with what `handler` returns for its input.
+    pub fn with_list_multipart_uploads<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                ListMultipartUploadsInput,
+            ) -> ::std::result::Result<
+                ListMultipartUploadsOutput,
+                RusotoError<ListMultipartUploadsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_multipart_uploads = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_parts` with what `handler` returns for its input.
+    pub fn with_list_parts<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                ListPartsInput,
+            ) -> ::std::result::Result<ListPartsOutput, RusotoError<ListPartsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_parts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_provisioned_capacity` with what `handler` returns for its input.
+    pub fn with_list_provisioned_capacity<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                ListProvisionedCapacityInput,
+            ) -> ::std::result::Result<
+                ListProvisionedCapacityOutput,
+                RusotoError<ListProvisionedCapacityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_provisioned_capacity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_vault` with what `handler` returns for its input.
+    pub fn with_list_tags_for_vault<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                ListTagsForVaultInput,
+            )
+                -> ::std::result::Result<ListTagsForVaultOutput, RusotoError<ListTagsForVaultError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_vault = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_vaults` with what `handler` returns for its input.
+    pub fn with_list_vaults<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                ListVaultsInput,
+            ) -> ::std::result::Result<ListVaultsOutput, RusotoError<ListVaultsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_vaults = Some(Box::new(handler));
+        self
+    }
+    /// Answers `purchase_provisioned_capacity` with what `handler` returns for its input.
+    pub fn with_purchase_provisioned_capacity<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                PurchaseProvisionedCapacityInput,
+            ) -> ::std::result::Result<
+                PurchaseProvisionedCapacityOutput,
+                RusotoError<PurchaseProvisionedCapacityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.purchase_provisioned_capacity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_vault` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_vault<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                RemoveTagsFromVaultInput,
+            ) -> ::std::result::Result<(), RusotoError<RemoveTagsFromVaultError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_vault = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_data_retrieval_policy` with what `handler` returns for its input.
+    pub fn with_set_data_retrieval_policy<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                SetDataRetrievalPolicyInput,
+            ) -> ::std::result::Result<(), RusotoError<SetDataRetrievalPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_data_retrieval_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_vault_access_policy` with what `handler` returns for its input.
+    pub fn with_set_vault_access_policy<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                SetVaultAccessPolicyInput,
+            ) -> ::std::result::Result<(), RusotoError<SetVaultAccessPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_vault_access_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_vault_notifications` with what `handler` returns for its input.
+    pub fn with_set_vault_notifications<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                SetVaultNotificationsInput,
+            ) -> ::std::result::Result<(), RusotoError<SetVaultNotificationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_vault_notifications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `upload_archive` with what `handler` returns for its input.
+    pub fn with_upload_archive<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                UploadArchiveInput,
+            )
+                -> ::std::result::Result<ArchiveCreationOutput, RusotoError<UploadArchiveError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.upload_archive = Some(Box::new(handler));
+        self
+    }
+    /// Answers `upload_multipart_part` with what `handler` returns for its input.
+    pub fn with_upload_multipart_part<F>(mut self, handler: F) -> MockGlacier
+    where
+        F: Fn(
+                UploadMultipartPartInput,
+            ) -> ::std::result::Result<
+                UploadMultipartPartOutput,
+                RusotoError<UploadMultipartPartError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.upload_multipart_part = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Glacier for MockGlacier {
+    fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadInput,
+    ) -> RusotoFuture<(), AbortMultipartUploadError> {
+        match self.abort_multipart_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for abort_multipart_upload"),
+        }
+    }
+    fn abort_vault_lock(
+        &self,
+        input: AbortVaultLockInput,
+    ) -> RusotoFuture<(), AbortVaultLockError> {
+        match self.abort_vault_lock {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for abort_vault_lock"),
+        }
+    }
+    fn add_tags_to_vault(
+        &self,
+        input: AddTagsToVaultInput,
+    ) -> RusotoFuture<(), AddTagsToVaultError> {
+        match self.add_tags_to_vault {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for add_tags_to_vault"),
+        }
+    }
+    fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadInput,
+    ) -> RusotoFuture<ArchiveCreationOutput, CompleteMultipartUploadError> {
+        match self.complete_multipart_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for complete_multipart_upload"),
+        }
+    }
+    fn complete_vault_lock(
+        &self,
+        input: CompleteVaultLockInput,
+    ) -> RusotoFuture<(), CompleteVaultLockError> {
+        match self.complete_vault_lock {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for complete_vault_lock"),
+        }
+    }
+    fn create_vault(
+        &self,
+        input: CreateVaultInput,
+    ) -> RusotoFuture<CreateVaultOutput, CreateVaultError> {
+        match self.create_vault {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for create_vault"),
+        }
+    }
+    fn delete_archive(&self, input: DeleteArchiveInput) -> RusotoFuture<(), DeleteArchiveError> {
+        match self.delete_archive {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for delete_archive"),
+        }
+    }
+    fn delete_vault(&self, input: DeleteVaultInput) -> RusotoFuture<(), DeleteVaultError> {
+        match self.delete_vault {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for delete_vault"),
+        }
+    }
+    fn delete_vault_access_policy(
+        &self,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> RusotoFuture<(), DeleteVaultAccessPolicyError> {
+        match self.delete_vault_access_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for delete_vault_access_policy"),
+        }
+    }
+    fn delete_vault_notifications(
+        &self,
+        input: DeleteVaultNotificationsInput,
+    ) -> RusotoFuture<(), DeleteVaultNotificationsError> {
+        match self.delete_vault_notifications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for delete_vault_notifications"),
+        }
+    }
+    fn describe_job(
+        &self,
+        input: DescribeJobInput,
+    ) -> RusotoFuture<GlacierJobDescription, DescribeJobError> {
+        match self.describe_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for describe_job"),
+        }
+    }
+    fn describe_vault(
+        &self,
+        input: DescribeVaultInput,
+    ) -> RusotoFuture<DescribeVaultOutput, DescribeVaultError> {
+        match self.describe_vault {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for describe_vault"),
+        }
+    }
+    fn get_data_retrieval_policy(
+        &self,
+        input: GetDataRetrievalPolicyInput,
+    ) -> RusotoFuture<GetDataRetrievalPolicyOutput, GetDataRetrievalPolicyError> {
+        match self.get_data_retrieval_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for get_data_retrieval_policy"),
+        }
+    }
+    fn get_job_output(
+        &self,
+        input: GetJobOutputInput,
+    ) -> RusotoFuture<GetJobOutputOutput, GetJobOutputError> {
+        match self.get_job_output {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for get_job_output"),
+        }
+    }
+    fn get_vault_access_policy(
+        &self,
+        input: GetVaultAccessPolicyInput,
+    ) -> RusotoFuture<GetVaultAccessPolicyOutput, GetVaultAccessPolicyError> {
+        match self.get_vault_access_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for get_vault_access_policy"),
+        }
+    }
+    fn get_vault_lock(
+        &self,
+        input: GetVaultLockInput,
+    ) -> RusotoFuture<GetVaultLockOutput, GetVaultLockError> {
+        match self.get_vault_lock {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for get_vault_lock"),
+        }
+    }
+    fn get_vault_notifications(
+        &self,
+        input: GetVaultNotificationsInput,
+    ) -> RusotoFuture<GetVaultNotificationsOutput, GetVaultNotificationsError> {
+        match self.get_vault_notifications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for get_vault_notifications"),
+        }
+    }
+    fn initiate_job(
+        &self,
+        input: InitiateJobInput,
+    ) -> RusotoFuture<InitiateJobOutput, InitiateJobError> {
+        match self.initiate_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for initiate_job"),
+        }
+    }
+    fn initiate_multipart_upload(
+        &self,
+        input: InitiateMultipartUploadInput,
+    ) -> RusotoFuture<InitiateMultipartUploadOutput, InitiateMultipartUploadError> {
+        match self.initiate_multipart_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for initiate_multipart_upload"),
+        }
+    }
+    fn initiate_vault_lock(
+        &self,
+        input: InitiateVaultLockInput,
+    ) -> RusotoFuture<InitiateVaultLockOutput, InitiateVaultLockError> {
+        match self.initiate_vault_lock {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for initiate_vault_lock"),
+        }
+    }
+    fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
+        match self.list_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for list_jobs"),
+        }
+    }
+    fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        match self.list_multipart_uploads {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for list_multipart_uploads"),
+        }
+    }
+    fn list_parts(&self, input: ListPartsInput) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        match self.list_parts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for list_parts"),
+        }
+    }
+    fn list_provisioned_capacity(
+        &self,
+        input: ListProvisionedCapacityInput,
+    ) -> RusotoFuture<ListProvisionedCapacityOutput, ListProvisionedCapacityError> {
+        match self.list_provisioned_capacity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for list_provisioned_capacity"),
+        }
+    }
+    fn list_tags_for_vault(
+        &self,
+        input: ListTagsForVaultInput,
+    ) -> RusotoFuture<ListTagsForVaultOutput, ListTagsForVaultError> {
+        match self.list_tags_for_vault {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for list_tags_for_vault"),
+        }
+    }
+    fn list_vaults(
+        &self,
+        input: ListVaultsInput,
+    ) -> RusotoFuture<ListVaultsOutput, ListVaultsError> {
+        match self.list_vaults {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for list_vaults"),
+        }
+    }
+    fn purchase_provisioned_capacity(
+        &self,
+        input: PurchaseProvisionedCapacityInput,
+    ) -> RusotoFuture<PurchaseProvisionedCapacityOutput, PurchaseProvisionedCapacityError> {
+        match self.purchase_provisioned_capacity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for purchase_provisioned_capacity"),
+        }
     }
-
-    /// <p>This operation removes one or more tags from the set of tags attached to a vault. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>. This operation is idempotent. The operation will be successful, even if there are no tags attached to the vault. </p>
     fn remove_tags_from_vault(
         &self,
         input: RemoveTagsFromVaultInput,
     ) -> RusotoFuture<(), RemoveTagsFromVaultError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/tags",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("operation", "remove");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RemoveTagsFromVaultError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.remove_tags_from_vault {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for remove_tags_from_vault"),
+        }
     }
-
-    /// <p>This operation sets and then enacts a data retrieval policy in the region specified in the PUT request. You can set one policy per region for an AWS account. The policy is enacted within a few minutes of a successful PUT operation.</p> <p>The set policy operation does not affect retrieval jobs that were in progress before the policy was enacted. For more information about data retrieval policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/data-retrieval-policy.html">Amazon Glacier Data Retrieval Policies</a>. </p>
     fn set_data_retrieval_policy(
         &self,
         input: SetDataRetrievalPolicyInput,
     ) -> RusotoFuture<(), SetDataRetrievalPolicyError> {
-        let request_uri = format!(
-            "/{account_id}/policies/data-retrieval",
-            account_id = input.account_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SetDataRetrievalPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.set_data_retrieval_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for set_data_retrieval_policy"),
+        }
     }
-
-    /// <p>This operation configures an access policy for a vault and will overwrite an existing policy. To configure a vault access policy, send a PUT request to the <code>access-policy</code> subresource of the vault. An access policy is specific to a vault and is also called a vault subresource. You can set one access policy per vault and the policy can be up to 20 KB in size. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>. </p>
     fn set_vault_access_policy(
         &self,
         input: SetVaultAccessPolicyInput,
     ) -> RusotoFuture<(), SetVaultAccessPolicyError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/access-policy",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let encoded = Some(serde_json::to_vec(&input.policy).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SetVaultAccessPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.set_vault_access_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for set_vault_access_policy"),
+        }
     }
-
-    /// <p>This operation configures notifications that will be sent when specific events happen to a vault. By default, you don't get any notifications.</p> <p>To configure vault notifications, send a PUT request to the <code>notification-configuration</code> subresource of the vault. The request should include a JSON document that provides an Amazon SNS topic and specific events for which you want Amazon S3 Glacier to send notifications to the topic.</p> <p>Amazon SNS topics must grant permission to the vault to be allowed to publish notifications to the topic. You can configure a vault to publish a notification for the following vault events:</p> <ul> <li> <p> <b>ArchiveRetrievalCompleted</b> This event occurs when a job that was initiated for an archive retrieval is completed (<a>InitiateJob</a>). The status of the completed job can be "Succeeded" or "Failed". The notification sent to the SNS topic is the same output as returned from <a>DescribeJob</a>. </p> </li> <li> <p> <b>InventoryRetrievalCompleted</b> This event occurs when a job that was initiated for an inventory retrieval is completed (<a>InitiateJob</a>). The status of the completed job can be "Succeeded" or "Failed". The notification sent to the SNS topic is the same output as returned from <a>DescribeJob</a>. </p> </li> </ul> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-put.html">Set Vault Notification Configuration </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
     fn set_vault_notifications(
         &self,
         input: SetVaultNotificationsInput,
     ) -> RusotoFuture<(), SetVaultNotificationsError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/notification-configuration",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let encoded = Some(serde_json::to_vec(&input.vault_notification_config).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SetVaultNotificationsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.set_vault_notifications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for set_vault_notifications"),
+        }
     }
-
-    /// <p>This operation adds an archive to a vault. This is a synchronous operation, and for a successful upload, your data is durably persisted. Amazon S3 Glacier returns the archive ID in the <code>x-amz-archive-id</code> header of the response. </p> <p>You must use the archive ID to access your data in Amazon S3 Glacier. After you upload an archive, you should save the archive ID returned so that you can retrieve or delete the archive later. Besides saving the archive ID, you can also index it and give it a friendly name to allow for better searching. You can also use the optional archive description field to specify how the archive is referred to in an external index of archives, such as you might create in Amazon DynamoDB. You can also get the vault inventory to obtain a list of archive IDs in a vault. For more information, see <a>InitiateJob</a>. </p> <p>You must provide a SHA256 tree hash of the data you are uploading. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>. </p> <p>You can optionally specify an archive description of up to 1,024 printable ASCII characters. You can get the archive description when you either retrieve the archive or get the vault inventory. For more information, see <a>InitiateJob</a>. Amazon Glacier does not interpret the description in any way. An archive description does not need to be unique. You cannot use the description to retrieve or sort the archive list. </p> <p>Archives are immutable. After you upload an archive, you cannot edit the archive or its description.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-an-archive.html">Uploading an Archive in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-post.html">Upload Archive</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
     fn upload_archive(
         &self,
         input: UploadArchiveInput,
     ) -> RusotoFuture<ArchiveCreationOutput, UploadArchiveError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/archives",
-            account_id = input.account_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let encoded = if let Some(ref payload) = input.body {
-            Some(payload.to_owned())
-        } else {
-            None
-        };
-        request.set_payload(encoded);
-
-        if let Some(ref archive_description) = input.archive_description {
-            request.add_header(
-                "x-amz-archive-description",
-                &archive_description.to_string(),
-            );
-        }
-
-        if let Some(ref checksum) = input.checksum {
-            request.add_header("x-amz-sha256-tree-hash", &checksum.to_string());
+        match self.upload_archive {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for upload_archive"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ArchiveCreationOutput, _>()?;
-                    if let Some(archive_id) = response.headers.get("x-amz-archive-id") {
-                        let value = archive_id.to_owned();
-                        result.archive_id = Some(value)
-                    };
-                    if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
-                        let value = checksum.to_owned();
-                        result.checksum = Some(value)
-                    };
-                    if let Some(location) = response.headers.get("Location") {
-                        let value = location.to_owned();
-                        result.location = Some(value)
-                    };
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UploadArchiveError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>This operation uploads a part of an archive. You can upload archive parts in any order. You can also upload them in parallel. You can upload up to 10,000 parts for a multipart upload.</p> <p>Amazon Glacier rejects your upload part request if any of the following conditions is true:</p> <ul> <li> <p> <b>SHA256 tree hash does not match</b>To ensure that part data is not corrupted in transmission, you compute a SHA256 tree hash of the part and include it in your request. Upon receiving the part data, Amazon S3 Glacier also computes a SHA256 tree hash. If these hash values don't match, the operation fails. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>.</p> </li> <li> <p> <b>Part size does not match</b>The size of each part except the last must match the size specified in the corresponding <a>InitiateMultipartUpload</a> request. The size of the last part must be the same size as, or smaller than, the specified size.</p> <note> <p>If you upload a part whose size is smaller than the part size you specified in your initiate multipart upload request and that part is not the last part, then the upload part request will succeed. However, the subsequent Complete Multipart Upload request will fail.</p> </note> </li> <li> <p> <b>Range does not align</b>The byte range value in the request does not align with the part size specified in the corresponding initiate request. For example, if you specify a part size of 4194304 bytes (4 MB), then 0 to 4194303 bytes (4 MB - 1) and 4194304 (4 MB) to 8388607 (8 MB - 1) are valid part ranges. However, if you set a range value of 2 MB to 6 MB, the range does not align with the part size and the upload will fail. </p> </li> </ul> <p>This operation is idempotent. If you upload the same part multiple times, the data included in the most recent request overwrites the previously uploaded data.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-upload-part.html">Upload Part </a> in the <i>Amazon Glacier Developer Guide</i>.</p>
     fn upload_multipart_part(
         &self,
         input: UploadMultipartPartInput,
     ) -> RusotoFuture<UploadMultipartPartOutput, UploadMultipartPartError> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
-            account_id = input.account_id,
-            upload_id = input.upload_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
-
-        let encoded = if let Some(ref payload) = input.body {
-            Some(payload.to_owned())
-        } else {
-            None
-        };
-        request.set_payload(encoded);
-
-        if let Some(ref checksum) = input.checksum {
-            request.add_header("x-amz-sha256-tree-hash", &checksum.to_string());
-        }
-
-        if let Some(ref range) = input.range {
-            request.add_header("Content-Range", &range.to_string());
+        match self.upload_multipart_part {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlacier has no handler for upload_multipart_part"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UploadMultipartPartOutput, _>()?;
-                    if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
-                        let value = checksum.to_owned();
-                        result.checksum = Some(value)
-                    };
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UploadMultipartPartError::from_response(response))
-                    }),
-                )
-            }
-        })
     }
 }
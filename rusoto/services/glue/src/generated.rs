@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Defines an action to be initiated by a trigger.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -52,7 +53,58 @@ pub struct Action {
     pub timeout: Option<i64>,
 }
 
+impl Validate for Action {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.crawler_name {
+            errors.check_length(
+                &field_path(path, "crawler_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "crawler_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.notification_property {
+            value.validate_at(&field_path(path, "notification_property"), errors);
+        }
+        if let Some(ref value) = self.security_configuration {
+            errors.check_length(
+                &field_path(path, "security_configuration"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "security_configuration"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.timeout {
+            errors.check_range(&field_path(path, "timeout"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchCreatePartitionRequest {
     /// <p>The ID of the catalog in which the partition is to be created. Currently, this should be the AWS account ID.</p>
     #[serde(rename = "CatalogId")]
@@ -69,8 +121,58 @@ pub struct BatchCreatePartitionRequest {
     pub table_name: String,
 }
 
+impl Validate for BatchCreatePartitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "partition_input_list"),
+            self.partition_input_list.len(),
+            None,
+            Some(100),
+        );
+        for (i, item) in self.partition_input_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "partition_input_list"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchCreatePartitionResponse {
     /// <p>The errors encountered when trying to create the requested partitions.</p>
     #[serde(rename = "Errors")]
@@ -79,6 +181,7 @@ pub struct BatchCreatePartitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteConnectionRequest {
     /// <p>The ID of the Data Catalog in which the connections reside. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -89,8 +192,41 @@ pub struct BatchDeleteConnectionRequest {
     pub connection_name_list: Vec<String>,
 }
 
+impl Validate for BatchDeleteConnectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "connection_name_list"),
+            self.connection_name_list.len(),
+            None,
+            Some(25),
+        );
+        for (i, item) in self.connection_name_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "connection_name_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDeleteConnectionResponse {
     /// <p>A map of the names of connections that were not successfully deleted to error details.</p>
     #[serde(rename = "Errors")]
@@ -103,6 +239,7 @@ pub struct BatchDeleteConnectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeletePartitionRequest {
     /// <p>The ID of the Data Catalog where the partition to be deleted resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -119,8 +256,58 @@ pub struct BatchDeletePartitionRequest {
     pub table_name: String,
 }
 
+impl Validate for BatchDeletePartitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "partitions_to_delete"),
+            self.partitions_to_delete.len(),
+            None,
+            Some(25),
+        );
+        for (i, item) in self.partitions_to_delete.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "partitions_to_delete"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDeletePartitionResponse {
     /// <p>The errors encountered when trying to delete the requested partitions.</p>
     #[serde(rename = "Errors")]
@@ -129,6 +316,7 @@ pub struct BatchDeletePartitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteTableRequest {
     /// <p>The ID of the Data Catalog where the table resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -142,8 +330,52 @@ pub struct BatchDeleteTableRequest {
     pub tables_to_delete: Vec<String>,
 }
 
+impl Validate for BatchDeleteTableRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "tables_to_delete"),
+            self.tables_to_delete.len(),
+            None,
+            Some(100),
+        );
+        for (i, item) in self.tables_to_delete.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tables_to_delete"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDeleteTableResponse {
     /// <p>A list of errors encountered in attempting to delete the specified tables.</p>
     #[serde(rename = "Errors")]
@@ -152,6 +384,7 @@ pub struct BatchDeleteTableResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteTableVersionRequest {
     /// <p>The ID of the Data Catalog where the tables reside. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -168,8 +401,63 @@ pub struct BatchDeleteTableVersionRequest {
     pub version_ids: Vec<String>,
 }
 
+impl Validate for BatchDeleteTableVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "version_ids"),
+            self.version_ids.len(),
+            None,
+            Some(100),
+        );
+        for (i, item) in self.version_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "version_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDeleteTableVersionResponse {
     /// <p>A list of errors encountered while trying to delete the specified table versions.</p>
     #[serde(rename = "Errors")]
@@ -178,14 +466,35 @@ pub struct BatchDeleteTableVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetCrawlersRequest {
     /// <p>A list of crawler names, which might be the names returned from the <code>ListCrawlers</code> operation.</p>
     #[serde(rename = "CrawlerNames")]
     pub crawler_names: Vec<String>,
 }
 
+impl Validate for BatchGetCrawlersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "crawler_names"),
+            self.crawler_names.len(),
+            None,
+            Some(100),
+        );
+        for (i, item) in self.crawler_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "crawler_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetCrawlersResponse {
     /// <p>A list of crawler definitions.</p>
     #[serde(rename = "Crawlers")]
@@ -198,14 +507,26 @@ pub struct BatchGetCrawlersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetDevEndpointsRequest {
     /// <p>The list of <code>DevEndpoint</code> names, which might be the names returned from the <code>ListDevEndpoint</code> operation.</p>
     #[serde(rename = "DevEndpointNames")]
     pub dev_endpoint_names: Vec<String>,
 }
 
+impl Validate for BatchGetDevEndpointsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dev_endpoint_names"),
+            self.dev_endpoint_names.len(),
+            Some(1),
+            Some(25),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetDevEndpointsResponse {
     /// <p>A list of <code>DevEndpoint</code> definitions.</p>
     #[serde(rename = "DevEndpoints")]
@@ -218,14 +539,29 @@ pub struct BatchGetDevEndpointsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetJobsRequest {
     /// <p>A list of job names, which might be the names returned from the <code>ListJobs</code> operation.</p>
     #[serde(rename = "JobNames")]
     pub job_names: Vec<String>,
 }
 
+impl Validate for BatchGetJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.job_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "job_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetJobsResponse {
     /// <p>A list of job definitions.</p>
     #[serde(rename = "Jobs")]
@@ -238,6 +574,7 @@ pub struct BatchGetJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetPartitionRequest {
     /// <p>The ID of the Data Catalog where the partitions in question reside. If none is supplied, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -254,8 +591,58 @@ pub struct BatchGetPartitionRequest {
     pub table_name: String,
 }
 
+impl Validate for BatchGetPartitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "partitions_to_get"),
+            self.partitions_to_get.len(),
+            None,
+            Some(1000),
+        );
+        for (i, item) in self.partitions_to_get.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "partitions_to_get"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetPartitionResponse {
     /// <p>A list of the requested partitions.</p>
     #[serde(rename = "Partitions")]
@@ -268,14 +655,29 @@ pub struct BatchGetPartitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetTriggersRequest {
     /// <p>A list of trigger names, which may be the names returned from the <code>ListTriggers</code> operation.</p>
     #[serde(rename = "TriggerNames")]
     pub trigger_names: Vec<String>,
 }
 
+impl Validate for BatchGetTriggersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.trigger_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "trigger_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetTriggersResponse {
     /// <p>A list of trigger definitions.</p>
     #[serde(rename = "Triggers")]
@@ -288,6 +690,7 @@ pub struct BatchGetTriggersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetWorkflowsRequest {
     /// <p>Specifies whether to include a graph when returning the workflow resource metadata.</p>
     #[serde(rename = "IncludeGraph")]
@@ -298,8 +701,28 @@ pub struct BatchGetWorkflowsRequest {
     pub names: Vec<String>,
 }
 
+impl Validate for BatchGetWorkflowsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "names"),
+            self.names.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetWorkflowsResponse {
     /// <p>A list of names of workflows not found.</p>
     #[serde(rename = "MissingWorkflows")]
@@ -313,7 +736,7 @@ pub struct BatchGetWorkflowsResponse {
 
 /// <p>Records an error that occurred when attempting to stop a specified job run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchStopJobRunError {
     /// <p>Specifies details about the error that was encountered.</p>
     #[serde(rename = "ErrorDetail")]
@@ -330,6 +753,7 @@ pub struct BatchStopJobRunError {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchStopJobRunRequest {
     /// <p>The name of the job definition for which to stop job runs.</p>
     #[serde(rename = "JobName")]
@@ -339,8 +763,39 @@ pub struct BatchStopJobRunRequest {
     pub job_run_ids: Vec<String>,
 }
 
+impl Validate for BatchStopJobRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_name"),
+            self.job_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_name"),
+            &self.job_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "job_run_ids"),
+            self.job_run_ids.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.job_run_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "job_run_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchStopJobRunResponse {
     /// <p>A list of the errors that were encountered in trying to stop <code>JobRuns</code>, including the <code>JobRunId</code> for which each error was encountered and details about the error.</p>
     #[serde(rename = "Errors")]
@@ -354,7 +809,7 @@ pub struct BatchStopJobRunResponse {
 
 /// <p>Records a successful request to stop a specified <code>JobRun</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchStopJobRunSuccessfulSubmission {
     /// <p>The name of the job definition used in the job run that was stopped.</p>
     #[serde(rename = "JobName")]
@@ -367,6 +822,7 @@ pub struct BatchStopJobRunSuccessfulSubmission {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelMLTaskRunRequest {
     /// <p>A unique identifier for the task run.</p>
     #[serde(rename = "TaskRunId")]
@@ -376,8 +832,35 @@ pub struct CancelMLTaskRunRequest {
     pub transform_id: String,
 }
 
+impl Validate for CancelMLTaskRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "task_run_id"),
+            self.task_run_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "task_run_id"),
+            &self.task_run_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "transform_id"),
+            self.transform_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_id"),
+            &self.transform_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelMLTaskRunResponse {
     /// <p>The status for this run.</p>
     #[serde(rename = "Status")]
@@ -395,6 +878,7 @@ pub struct CancelMLTaskRunResponse {
 
 /// <p>Specifies a table definition in the AWS Glue Data Catalog.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CatalogEntry {
     /// <p>The database in which the table metadata resides.</p>
     #[serde(rename = "DatabaseName")]
@@ -404,9 +888,36 @@ pub struct CatalogEntry {
     pub table_name: String,
 }
 
+impl Validate for CatalogEntry {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 /// <p>A structure containing migration status information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CatalogImportStatus {
     /// <p> <code>True</code> if the migration has completed, or <code>False</code> otherwise.</p>
     #[serde(rename = "ImportCompleted")]
@@ -433,9 +944,40 @@ pub struct CatalogTarget {
     pub tables: Vec<String>,
 }
 
+impl Validate for CatalogTarget {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "tables"),
+            self.tables.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.tables.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tables"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Classifiers are triggered during a crawl task. A classifier checks whether a given file is in a format it can handle. If it is, the classifier creates a schema in the form of a <code>StructType</code> object that matches that data format.</p> <p>You can use the standard classifiers that AWS Glue provides, or you can write your own classifiers to best categorize your data sources and specify the appropriate schemas to use for them. A classifier can be a <code>grok</code> classifier, an <code>XML</code> classifier, a <code>JSON</code> classifier, or a custom <code>CSV</code> classifier, as specified in one of the fields in the <code>Classifier</code> object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Classifier {
     /// <p>A classifier for comma-separated values (CSV).</p>
     #[serde(rename = "CsvClassifier")]
@@ -468,6 +1010,14 @@ pub struct CloudWatchEncryption {
     pub kms_key_arn: Option<String>,
 }
 
+impl Validate for CloudWatchEncryption {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_arn {
+            errors.check_pattern(&field_path(path, "kms_key_arn"), &value, "arn:aws:kms:.*");
+        }
+    }
+}
+
 /// <p>Represents a directional edge in a directed acyclic graph (DAG).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CodeGenEdge {
@@ -483,6 +1033,33 @@ pub struct CodeGenEdge {
     pub target_parameter: Option<String>,
 }
 
+impl Validate for CodeGenEdge {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "source"),
+            self.source.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "source"),
+            &self.source,
+            "[A-Za-z_][A-Za-z0-9_]*",
+        );
+        errors.check_length(
+            &field_path(path, "target"),
+            self.target.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "target"),
+            &self.target,
+            "[A-Za-z_][A-Za-z0-9_]*",
+        );
+    }
+}
+
 /// <p>Represents a node in a directed acyclic graph (DAG)</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CodeGenNode {
@@ -501,6 +1078,19 @@ pub struct CodeGenNode {
     pub node_type: String,
 }
 
+impl Validate for CodeGenNode {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "args"), self.args.len(), None, Some(50));
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "[A-Za-z_][A-Za-z0-9_]*");
+    }
+}
+
 /// <p>An argument or property of a node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CodeGenNodeArg {
@@ -536,6 +1126,54 @@ pub struct Column {
     pub type_: Option<String>,
 }
 
+impl Validate for Column {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.comment {
+            errors.check_length(
+                &field_path(path, "comment"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "comment"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(512000));
+            }
+        }
+        if let Some(ref value) = self.type_ {
+            errors.check_length(
+                &field_path(path, "type_"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+            errors.check_pattern(
+                &field_path(path, "type_"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Defines a condition under which a trigger fires.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Condition {
@@ -561,9 +1199,40 @@ pub struct Condition {
     pub state: Option<String>,
 }
 
+impl Validate for Condition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.crawler_name {
+            errors.check_length(
+                &field_path(path, "crawler_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "crawler_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.job_name {
+            errors.check_length(
+                &field_path(path, "job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>The confusion matrix shows you what your transform is predicting accurately and what types of errors it is making.</p> <p>For more information, see <a href="https://en.wikipedia.org/wiki/Confusion_matrix">Confusion matrix</a> in Wikipedia.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfusionMatrix {
     /// <p>The number of matches in the data that the transform didn't find, in the confusion matrix for your transform.</p>
     #[serde(rename = "NumFalseNegatives")]
@@ -585,7 +1254,7 @@ pub struct ConfusionMatrix {
 
 /// <p>Defines a connection to a data source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Connection {
     /// <p><p>These key-value pairs define parameters for the connection:</p> <ul> <li> <p> <code>HOST</code> - The host URI: either the fully qualified domain name (FQDN) or the IPv4 address of the database host.</p> </li> <li> <p> <code>PORT</code> - The port number, between 1024 and 65535, of the port on which the database host is listening for database connections.</p> </li> <li> <p> <code>USER<em>NAME</code> - The name under which to log in to the database. The value string for <code>USER</em>NAME</code> is &quot;<code>USERNAME</code>&quot;.</p> </li> <li> <p> <code>PASSWORD</code> - A password, if one is used, for the user name.</p> </li> <li> <p> <code>ENCRYPTED<em>PASSWORD</code> - When you enable connection password protection by setting <code>ConnectionPasswordEncryption</code> in the Data Catalog encryption settings, this field stores the encrypted password.</p> </li> <li> <p> <code>JDBC</em>DRIVER<em>JAR</em>URI</code> - The Amazon Simple Storage Service (Amazon S3) path of the JAR file that contains the JDBC driver to use.</p> </li> <li> <p> <code>JDBC<em>DRIVER</em>CLASS<em>NAME</code> - The class name of the JDBC driver to use.</p> </li> <li> <p> <code>JDBC</em>ENGINE</code> - The name of the JDBC engine to use.</p> </li> <li> <p> <code>JDBC<em>ENGINE</em>VERSION</code> - The version of the JDBC engine to use.</p> </li> <li> <p> <code>CONFIG<em>FILES</code> - (Reserved for future use.)</p> </li> <li> <p> <code>INSTANCE</em>ID</code> - The instance ID to use.</p> </li> <li> <p> <code>JDBC<em>CONNECTION</em>URL</code> - The URL for the JDBC connection.</p> </li> <li> <p> <code>JDBC<em>ENFORCE</em>SSL</code> - A Boolean string (true, false) specifying whether Secure Sockets Layer (SSL) with hostname matching is enforced for the JDBC connection on the client. The default is false.</p> </li> </ul></p>
     #[serde(rename = "ConnectionProperties")]
@@ -627,6 +1296,7 @@ pub struct Connection {
 
 /// <p>A structure that is used to specify a connection to create or update.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConnectionInput {
     /// <p>These key-value pairs define parameters for the connection.</p>
     #[serde(rename = "ConnectionProperties")]
@@ -651,6 +1321,68 @@ pub struct ConnectionInput {
     pub physical_connection_requirements: Option<PhysicalConnectionRequirements>,
 }
 
+impl Validate for ConnectionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "connection_properties"),
+            self.connection_properties.len(),
+            None,
+            Some(100),
+        );
+        for (key, item) in self.connection_properties.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "connection_properties"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.match_criteria {
+            errors.check_length(
+                &field_path(path, "match_criteria"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "match_criteria"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+                );
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.physical_connection_requirements {
+            value.validate_at(
+                &field_path(path, "physical_connection_requirements"),
+                errors,
+            );
+        }
+    }
+}
+
 /// <p>The data structure used by the Data Catalog to encrypt the password as part of <code>CreateConnection</code> or <code>UpdateConnection</code> and store it in the <code>ENCRYPTED_PASSWORD</code> field in the connection properties. You can enable catalog encryption or only password encryption.</p> <p>When a <code>CreationConnection</code> request arrives containing a password, the Data Catalog first encrypts the password using your AWS KMS key. It then encrypts the whole connection object again if catalog encryption is also enabled.</p> <p>This encryption requires that you set AWS KMS key permissions to enable or restrict access on the password key according to your security requirements. For example, you might want only administrators to have decrypt permission on the password key.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConnectionPasswordEncryption {
@@ -663,6 +1395,24 @@ pub struct ConnectionPasswordEncryption {
     pub return_connection_password_encrypted: bool,
 }
 
+impl Validate for ConnectionPasswordEncryption {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aws_kms_key_id {
+            errors.check_length(
+                &field_path(path, "aws_kms_key_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "aws_kms_key_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Specifies the connections used by a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConnectionsList {
@@ -674,7 +1424,7 @@ pub struct ConnectionsList {
 
 /// <p>The details of a crawl in the workflow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Crawl {
     /// <p>The date and time on which the crawl completed.</p>
     #[serde(rename = "CompletedOn")]
@@ -704,7 +1454,7 @@ pub struct Crawl {
 
 /// <p>Specifies a crawler program that examines a data source and uses classifiers to try to determine its schema. If successful, the crawler records metadata concerning the data source in the AWS Glue Data Catalog.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Crawler {
     /// <p>A list of UTF-8 strings that specify the custom classifiers that are associated with the crawler.</p>
     #[serde(rename = "Classifiers")]
@@ -778,7 +1528,7 @@ pub struct Crawler {
 
 /// <p>Metrics for a specified crawler.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CrawlerMetrics {
     /// <p>The name of the crawler.</p>
     #[serde(rename = "CrawlerName")]
@@ -816,7 +1566,7 @@ pub struct CrawlerMetrics {
 
 /// <p>The details of a Crawler node present in the workflow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CrawlerNodeDetails {
     /// <p>A list of crawls represented by the crawl node.</p>
     #[serde(rename = "Crawls")]
@@ -845,7 +1595,19 @@ pub struct CrawlerTargets {
     pub s3_targets: Option<Vec<S3Target>>,
 }
 
+impl Validate for CrawlerTargets {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_targets {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "catalog_targets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClassifierRequest {
     /// <p>A <code>CsvClassifier</code> object specifying the classifier to create.</p>
     #[serde(rename = "CsvClassifier")]
@@ -865,11 +1627,29 @@ pub struct CreateClassifierRequest {
     pub xml_classifier: Option<CreateXMLClassifierRequest>,
 }
 
+impl Validate for CreateClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.csv_classifier {
+            value.validate_at(&field_path(path, "csv_classifier"), errors);
+        }
+        if let Some(ref value) = self.grok_classifier {
+            value.validate_at(&field_path(path, "grok_classifier"), errors);
+        }
+        if let Some(ref value) = self.json_classifier {
+            value.validate_at(&field_path(path, "json_classifier"), errors);
+        }
+        if let Some(ref value) = self.xml_classifier {
+            value.validate_at(&field_path(path, "xml_classifier"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateClassifierResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConnectionRequest {
     /// <p>The ID of the Data Catalog in which to create the connection. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -880,11 +1660,32 @@ pub struct CreateConnectionRequest {
     pub connection_input: ConnectionInput,
 }
 
+impl Validate for CreateConnectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        self.connection_input
+            .validate_at(&field_path(path, "connection_input"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateConnectionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCrawlerRequest {
     /// <p>A list of custom classifiers that the user has registered. By default, all built-in classifiers are included in a crawl, but these custom classifiers always override the default classifiers for a given classification.</p>
     #[serde(rename = "Classifiers")]
@@ -933,12 +1734,78 @@ pub struct CreateCrawlerRequest {
     pub targets: CrawlerTargets,
 }
 
+impl Validate for CreateCrawlerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.classifiers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "classifiers"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.crawler_security_configuration {
+            errors.check_length(
+                &field_path(path, "crawler_security_configuration"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.table_prefix {
+            errors.check_length(
+                &field_path(path, "table_prefix"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        self.targets
+            .validate_at(&field_path(path, "targets"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCrawlerResponse {}
 
 /// <p>Specifies a custom CSV classifier for <code>CreateClassifier</code> to create.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCsvClassifierRequest {
     /// <p>Enables the processing of files that contain only one column.</p>
     #[serde(rename = "AllowSingleColumn")]
@@ -969,7 +1836,53 @@ pub struct CreateCsvClassifierRequest {
     pub quote_symbol: Option<String>,
 }
 
+impl Validate for CreateCsvClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.delimiter {
+            errors.check_length(
+                &field_path(path, "delimiter"),
+                value.chars().count(),
+                Some(1),
+                Some(1),
+            );
+            errors.check_pattern(&field_path(path, "delimiter"), &value, "[^\\r\\n]");
+        }
+        if let Some(ref value) = self.header {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "header"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+                );
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.quote_symbol {
+            errors.check_length(
+                &field_path(path, "quote_symbol"),
+                value.chars().count(),
+                Some(1),
+                Some(1),
+            );
+            errors.check_pattern(&field_path(path, "quote_symbol"), &value, "[^\\r\\n]");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDatabaseRequest {
     /// <p>The ID of the Data Catalog in which to create the database. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -980,11 +1893,32 @@ pub struct CreateDatabaseRequest {
     pub database_input: DatabaseInput,
 }
 
+impl Validate for CreateDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        self.database_input
+            .validate_at(&field_path(path, "database_input"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDatabaseResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDevEndpointRequest {
     /// <p>A map of arguments used to configure the <code>DevEndpoint</code>.</p>
     #[serde(rename = "Arguments")]
@@ -1042,8 +1976,44 @@ pub struct CreateDevEndpointRequest {
     pub worker_type: Option<String>,
 }
 
+impl Validate for CreateDevEndpointRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.arguments {
+            errors.check_length(&field_path(path, "arguments"), value.len(), None, Some(100));
+        }
+        if let Some(ref value) = self.public_keys {
+            errors.check_length(&field_path(path, "public_keys"), value.len(), None, Some(5));
+        }
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/.*",
+        );
+        if let Some(ref value) = self.security_configuration {
+            errors.check_length(
+                &field_path(path, "security_configuration"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "security_configuration"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDevEndpointResponse {
     /// <p>The map of arguments used to configure this <code>DevEndpoint</code>.</p>
     #[serde(rename = "Arguments")]
@@ -1121,6 +2091,7 @@ pub struct CreateDevEndpointResponse {
 
 /// <p>Specifies a <code>grok</code> classifier for <code>CreateClassifier</code> to create.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGrokClassifierRequest {
     /// <p>An identifier of the data format that the classifier matches, such as Twitter, JSON, Omniture logs, Amazon CloudWatch Logs, and so on.</p>
     #[serde(rename = "Classification")]
@@ -1137,7 +2108,48 @@ pub struct CreateGrokClassifierRequest {
     pub name: String,
 }
 
+impl Validate for CreateGrokClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_patterns {
+            errors.check_length(
+                &field_path(path, "custom_patterns"),
+                value.chars().count(),
+                None,
+                Some(16000),
+            );
+            errors.check_pattern(
+                &field_path(path, "custom_patterns"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "grok_pattern"),
+            self.grok_pattern.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "grok_pattern"),
+            &self.grok_pattern,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJobRequest {
     /// <p>The <code>JobCommand</code> that executes this job.</p>
     #[serde(rename = "Command")]
@@ -1206,8 +2218,74 @@ pub struct CreateJobRequest {
     pub worker_type: Option<String>,
 }
 
+impl Validate for CreateJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.command
+            .validate_at(&field_path(path, "command"), errors);
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.glue_version {
+            errors.check_length(
+                &field_path(path, "glue_version"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "glue_version"), &value, "^\\w+\\.\\w+$");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.notification_property {
+            value.validate_at(&field_path(path, "notification_property"), errors);
+        }
+        if let Some(ref value) = self.security_configuration {
+            errors.check_length(
+                &field_path(path, "security_configuration"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "security_configuration"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        if let Some(ref value) = self.timeout {
+            errors.check_range(&field_path(path, "timeout"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateJobResponse {
     /// <p>The unique name that was provided for this job definition.</p>
     #[serde(rename = "Name")]
@@ -1217,6 +2295,7 @@ pub struct CreateJobResponse {
 
 /// <p>Specifies a JSON classifier for <code>CreateClassifier</code> to create.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJsonClassifierRequest {
     /// <p>A <code>JsonPath</code> string defining the JSON data for the classifier to classify. AWS Glue supports a subset of <code>JsonPath</code>, as described in <a href="https://docs.aws.amazon.com/glue/latest/dg/custom-classifier.html#custom-classifier-json">Writing JsonPath Custom Classifiers</a>.</p>
     #[serde(rename = "JsonPath")]
@@ -1226,7 +2305,24 @@ pub struct CreateJsonClassifierRequest {
     pub name: String,
 }
 
+impl Validate for CreateJsonClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMLTransformRequest {
     /// <p>A description of the machine learning transform that is being defined. The default is an empty string.</p>
     #[serde(rename = "Description")]
@@ -1266,8 +2362,52 @@ pub struct CreateMLTransformRequest {
     pub worker_type: Option<String>,
 }
 
+impl Validate for CreateMLTransformRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "input_record_tables"),
+            self.input_record_tables.len(),
+            None,
+            Some(10),
+        );
+        for (i, item) in self.input_record_tables.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "input_record_tables"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        self.parameters
+            .validate_at(&field_path(path, "parameters"), errors);
+        if let Some(ref value) = self.timeout {
+            errors.check_range(&field_path(path, "timeout"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateMLTransformResponse {
     /// <p>A unique identifier that is generated for the transform.</p>
     #[serde(rename = "TransformId")]
@@ -1276,6 +2416,7 @@ pub struct CreateMLTransformResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePartitionRequest {
     /// <p>The AWS account ID of the catalog in which the partition is to be created.</p>
     #[serde(rename = "CatalogId")]
@@ -1292,11 +2433,54 @@ pub struct CreatePartitionRequest {
     pub table_name: String,
 }
 
+impl Validate for CreatePartitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        self.partition_input
+            .validate_at(&field_path(path, "partition_input"), errors);
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePartitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateScriptRequest {
     /// <p>A list of the edges in the DAG.</p>
     #[serde(rename = "DagEdges")]
@@ -1312,8 +2496,25 @@ pub struct CreateScriptRequest {
     pub language: Option<String>,
 }
 
+impl Validate for CreateScriptRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dag_edges {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dag_edges"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.dag_nodes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dag_nodes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateScriptResponse {
     /// <p>The Python script generated from the DAG.</p>
     #[serde(rename = "PythonScript")]
@@ -1326,6 +2527,7 @@ pub struct CreateScriptResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSecurityConfigurationRequest {
     /// <p>The encryption configuration for the new security configuration.</p>
     #[serde(rename = "EncryptionConfiguration")]
@@ -1335,8 +2537,26 @@ pub struct CreateSecurityConfigurationRequest {
     pub name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl Validate for CreateSecurityConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.encryption_configuration
+            .validate_at(&field_path(path, "encryption_configuration"), errors);
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSecurityConfigurationResponse {
     /// <p>The time at which the new security configuration was created.</p>
     #[serde(rename = "CreatedTimestamp")]
@@ -1349,6 +2569,7 @@ pub struct CreateSecurityConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTableRequest {
     /// <p>The ID of the Data Catalog in which to create the <code>Table</code>. If none is supplied, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -1362,11 +2583,43 @@ pub struct CreateTableRequest {
     pub table_input: TableInput,
 }
 
+impl Validate for CreateTableRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        self.table_input
+            .validate_at(&field_path(path, "table_input"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTableResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTriggerRequest {
     /// <p>The actions initiated by this trigger when it fires.</p>
     #[serde(rename = "Actions")]
@@ -1403,8 +2656,64 @@ pub struct CreateTriggerRequest {
     pub workflow_name: Option<String>,
 }
 
+impl Validate for CreateTriggerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.actions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "actions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.predicate {
+            value.validate_at(&field_path(path, "predicate"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        if let Some(ref value) = self.workflow_name {
+            errors.check_length(
+                &field_path(path, "workflow_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "workflow_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTriggerResponse {
     /// <p>The name of the trigger.</p>
     #[serde(rename = "Name")]
@@ -1413,6 +2722,7 @@ pub struct CreateTriggerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserDefinedFunctionRequest {
     /// <p>The ID of the Data Catalog in which to create the function. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -1426,11 +2736,43 @@ pub struct CreateUserDefinedFunctionRequest {
     pub function_input: UserDefinedFunctionInput,
 }
 
+impl Validate for CreateUserDefinedFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        self.function_input
+            .validate_at(&field_path(path, "function_input"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserDefinedFunctionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateWorkflowRequest {
     /// <p>A collection of properties to be used as part of each execution of the workflow.</p>
     #[serde(rename = "DefaultRunProperties")]
@@ -1449,8 +2791,31 @@ pub struct CreateWorkflowRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateWorkflowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateWorkflowResponse {
     /// <p>The name of the workflow which was provided as part of the request.</p>
     #[serde(rename = "Name")]
@@ -1460,6 +2825,7 @@ pub struct CreateWorkflowResponse {
 
 /// <p>Specifies an XML classifier for <code>CreateClassifier</code> to create.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateXMLClassifierRequest {
     /// <p>An identifier of the data format that the classifier matches.</p>
     #[serde(rename = "Classification")]
@@ -1473,9 +2839,25 @@ pub struct CreateXMLClassifierRequest {
     pub row_tag: Option<String>,
 }
 
+impl Validate for CreateXMLClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 /// <p>A classifier for custom <code>CSV</code> content.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CsvClassifier {
     /// <p>Enables the processing of files that contain only one column.</p>
     #[serde(rename = "AllowSingleColumn")]
@@ -1531,6 +2913,17 @@ pub struct DataCatalogEncryptionSettings {
     pub encryption_at_rest: Option<EncryptionAtRest>,
 }
 
+impl Validate for DataCatalogEncryptionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.connection_password_encryption {
+            value.validate_at(&field_path(path, "connection_password_encryption"), errors);
+        }
+        if let Some(ref value) = self.encryption_at_rest {
+            value.validate_at(&field_path(path, "encryption_at_rest"), errors);
+        }
+    }
+}
+
 /// <p>The AWS Lake Formation principal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataLakePrincipal {
@@ -1540,9 +2933,22 @@ pub struct DataLakePrincipal {
     pub data_lake_principal_identifier: Option<String>,
 }
 
+impl Validate for DataLakePrincipal {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.data_lake_principal_identifier {
+            errors.check_length(
+                &field_path(path, "data_lake_principal_identifier"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 /// <p>The <code>Database</code> object represents a logical grouping of tables that might reside in a Hive metastore or an RDBMS.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Database {
     /// <p>Creates a set of default permissions on the table for principals. </p>
     #[serde(rename = "CreateTableDefaultPermissions")]
@@ -1571,6 +2977,7 @@ pub struct Database {
 
 /// <p>The structure used to create or update a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DatabaseInput {
     /// <p>Creates a set of default permissions on the table for principals. </p>
     #[serde(rename = "CreateTableDefaultPermissions")]
@@ -1593,18 +3000,94 @@ pub struct DatabaseInput {
     pub parameters: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for DatabaseInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.create_table_default_permissions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "create_table_default_permissions"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.location_uri {
+            errors.check_length(
+                &field_path(path, "location_uri"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "location_uri"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(512000));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClassifierRequest {
     /// <p>Name of the classifier to remove.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteClassifierResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConnectionRequest {
     /// <p>The ID of the Data Catalog in which the connection resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -1615,22 +3098,69 @@ pub struct DeleteConnectionRequest {
     pub connection_name: String,
 }
 
+impl Validate for DeleteConnectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "connection_name"),
+            self.connection_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "connection_name"),
+            &self.connection_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteConnectionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCrawlerRequest {
     /// <p>The name of the crawler to remove.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteCrawlerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteCrawlerResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDatabaseRequest {
     /// <p>The ID of the Data Catalog in which the database resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -1641,11 +3171,41 @@ pub struct DeleteDatabaseRequest {
     pub name: String,
 }
 
+impl Validate for DeleteDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDatabaseResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDevEndpointRequest {
     /// <p>The name of the <code>DevEndpoint</code>.</p>
     #[serde(rename = "EndpointName")]
@@ -1653,18 +3213,35 @@ pub struct DeleteDevEndpointRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDevEndpointResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteJobRequest {
     /// <p>The name of the job definition to delete.</p>
     #[serde(rename = "JobName")]
     pub job_name: String,
 }
 
+impl Validate for DeleteJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_name"),
+            self.job_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_name"),
+            &self.job_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteJobResponse {
     /// <p>The name of the job definition that was deleted.</p>
     #[serde(rename = "JobName")]
@@ -1673,14 +3250,31 @@ pub struct DeleteJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMLTransformRequest {
     /// <p>The unique identifier of the transform to delete.</p>
     #[serde(rename = "TransformId")]
     pub transform_id: String,
 }
 
+impl Validate for DeleteMLTransformRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transform_id"),
+            self.transform_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_id"),
+            &self.transform_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteMLTransformResponse {
     /// <p>The unique identifier of the transform that was deleted.</p>
     #[serde(rename = "TransformId")]
@@ -1689,6 +3283,7 @@ pub struct DeleteMLTransformResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePartitionRequest {
     /// <p>The ID of the Data Catalog where the partition to be deleted resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -1705,11 +3300,56 @@ pub struct DeletePartitionRequest {
     pub table_name: String,
 }
 
+impl Validate for DeletePartitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        for (i, item) in self.partition_values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "partition_values"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeletePartitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteResourcePolicyRequest {
     /// <p>The hash value returned when this policy was set.</p>
     #[serde(rename = "PolicyHashCondition")]
@@ -1717,22 +3357,58 @@ pub struct DeleteResourcePolicyRequest {
     pub policy_hash_condition: Option<String>,
 }
 
+impl Validate for DeleteResourcePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.policy_hash_condition {
+            errors.check_length(
+                &field_path(path, "policy_hash_condition"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "policy_hash_condition"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteResourcePolicyResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSecurityConfigurationRequest {
     /// <p>The name of the security configuration to delete.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteSecurityConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSecurityConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTableRequest {
     /// <p>The ID of the Data Catalog where the table resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -1746,11 +3422,52 @@ pub struct DeleteTableRequest {
     pub name: String,
 }
 
+impl Validate for DeleteTableRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTableResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTableVersionRequest {
     /// <p>The ID of the Data Catalog where the tables reside. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -1767,19 +3484,87 @@ pub struct DeleteTableVersionRequest {
     pub version_id: String,
 }
 
+impl Validate for DeleteTableVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "version_id"),
+            self.version_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "version_id"),
+            &self.version_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTableVersionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTriggerRequest {
     /// <p>The name of the trigger to delete.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteTriggerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTriggerResponse {
     /// <p>The name of the trigger that was deleted.</p>
     #[serde(rename = "Name")]
@@ -1788,6 +3573,7 @@ pub struct DeleteTriggerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserDefinedFunctionRequest {
     /// <p>The ID of the Data Catalog where the function to be deleted is located. If none is supplied, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -1801,19 +3587,76 @@ pub struct DeleteUserDefinedFunctionRequest {
     pub function_name: String,
 }
 
+impl Validate for DeleteUserDefinedFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "function_name"),
+            &self.function_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUserDefinedFunctionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteWorkflowRequest {
     /// <p>Name of the workflow to be deleted.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteWorkflowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteWorkflowResponse {
     /// <p>Name of the workflow specified in input.</p>
     #[serde(rename = "Name")]
@@ -1823,7 +3666,7 @@ pub struct DeleteWorkflowResponse {
 
 /// <p>A development endpoint where a developer can remotely debug extract, transform, and load (ETL) scripts.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DevEndpoint {
     /// <p>A map of arguments used to configure the <code>DevEndpoint</code>.</p> <p>Currently, only <code>"--enable-glue-datacatalog": ""</code> is supported as a valid argument.</p>
     #[serde(rename = "Arguments")]
@@ -1925,6 +3768,7 @@ pub struct DevEndpoint {
 
 /// <p>Custom libraries to be loaded into a development endpoint.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DevEndpointCustomLibraries {
     /// <p><p>The path to one or more Java <code>.jar</code> files in an S3 bucket that should be loaded in your <code>DevEndpoint</code>.</p> <note> <p>You can only use pure Java/Scala libraries with a <code>DevEndpoint</code>.</p> </note></p>
     #[serde(rename = "ExtraJarsS3Path")]
@@ -1947,7 +3791,7 @@ pub struct DynamoDBTarget {
 
 /// <p>An edge represents a directed connection between two AWS Glue components which are part of the workflow the edge belongs to.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Edge {
     /// <p>The unique of the node within the workflow where the edge ends.</p>
     #[serde(rename = "DestinationId")]
@@ -1971,6 +3815,24 @@ pub struct EncryptionAtRest {
     pub sse_aws_kms_key_id: Option<String>,
 }
 
+impl Validate for EncryptionAtRest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.sse_aws_kms_key_id {
+            errors.check_length(
+                &field_path(path, "sse_aws_kms_key_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "sse_aws_kms_key_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Specifies an encryption configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionConfiguration {
@@ -1988,9 +3850,26 @@ pub struct EncryptionConfiguration {
     pub s3_encryption: Option<Vec<S3Encryption>>,
 }
 
+impl Validate for EncryptionConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cloud_watch_encryption {
+            value.validate_at(&field_path(path, "cloud_watch_encryption"), errors);
+        }
+        if let Some(ref value) = self.job_bookmarks_encryption {
+            value.validate_at(&field_path(path, "job_bookmarks_encryption"), errors);
+        }
+        if let Some(ref value) = self.s3_encryption {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "s3_encryption"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Contains details about an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ErrorDetail {
     /// <p>The code associated with this error.</p>
     #[serde(rename = "ErrorCode")]
@@ -2004,7 +3883,7 @@ pub struct ErrorDetail {
 
 /// <p>Evaluation metrics provide an estimate of the quality of your machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EvaluationMetrics {
     /// <p>The evaluation metrics for the find matches algorithm.</p>
     #[serde(rename = "FindMatchesMetrics")]
@@ -2026,7 +3905,7 @@ pub struct ExecutionProperty {
 
 /// <p>Specifies configuration properties for an exporting labels task run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportLabelsTaskRunProperties {
     /// <p>The Amazon Simple Storage Service (Amazon S3) path where you will export the labels.</p>
     #[serde(rename = "OutputS3Path")]
@@ -2036,7 +3915,7 @@ pub struct ExportLabelsTaskRunProperties {
 
 /// <p>The evaluation metrics for the find matches algorithm. The quality of your machine learning transform is measured by getting your transform to predict some matches and comparing the results to known matches from the same dataset. The quality metrics are based on a subset of your data, so they are not precise.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FindMatchesMetrics {
     /// <p>The area under the precision/recall curve (AUPRC) is a single number measuring the overall quality of the transform, that is independent of the choice made for precision vs. recall. Higher values indicate that you have a more attractive precision vs. recall tradeoff.</p> <p>For more information, see <a href="https://en.wikipedia.org/wiki/Precision_and_recall">Precision and recall</a> in Wikipedia.</p>
     #[serde(rename = "AreaUnderPRCurve")]
@@ -2081,9 +3960,43 @@ pub struct FindMatchesParameters {
     pub primary_key_column_name: Option<String>,
 }
 
+impl Validate for FindMatchesParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accuracy_cost_tradeoff {
+            errors.check_range(
+                &field_path(path, "accuracy_cost_tradeoff"),
+                *value,
+                None,
+                Some(1.0),
+            );
+        }
+        if let Some(ref value) = self.precision_recall_tradeoff {
+            errors.check_range(
+                &field_path(path, "precision_recall_tradeoff"),
+                *value,
+                None,
+                Some(1.0),
+            );
+        }
+        if let Some(ref value) = self.primary_key_column_name {
+            errors.check_length(
+                &field_path(path, "primary_key_column_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "primary_key_column_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Specifies configuration properties for a Find Matches task run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FindMatchesTaskRunProperties {
     /// <p>The job ID for the Find Matches task run.</p>
     #[serde(rename = "JobId")]
@@ -2100,6 +4013,7 @@ pub struct FindMatchesTaskRunProperties {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCatalogImportStatusRequest {
     /// <p>The ID of the catalog to migrate. Currently, this should be the AWS account ID.</p>
     #[serde(rename = "CatalogId")]
@@ -2107,8 +4021,26 @@ pub struct GetCatalogImportStatusRequest {
     pub catalog_id: Option<String>,
 }
 
+impl Validate for GetCatalogImportStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCatalogImportStatusResponse {
     /// <p>The status of the specified catalog migration.</p>
     #[serde(rename = "ImportStatus")]
@@ -2117,14 +4049,31 @@ pub struct GetCatalogImportStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetClassifierRequest {
     /// <p>Name of the classifier to retrieve.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for GetClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetClassifierResponse {
     /// <p>The requested classifier.</p>
     #[serde(rename = "Classifier")]
@@ -2133,6 +4082,7 @@ pub struct GetClassifierResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetClassifiersRequest {
     /// <p>The size of the list to return (optional).</p>
     #[serde(rename = "MaxResults")]
@@ -2144,8 +4094,21 @@ pub struct GetClassifiersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetClassifiersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetClassifiersResponse {
     /// <p>The requested list of classifier objects.</p>
     #[serde(rename = "Classifiers")]
@@ -2158,6 +4121,7 @@ pub struct GetClassifiersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConnectionRequest {
     /// <p>The ID of the Data Catalog in which the connection resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -2172,8 +4136,37 @@ pub struct GetConnectionRequest {
     pub name: String,
 }
 
+impl Validate for GetConnectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConnectionResponse {
     /// <p>The requested connection definition.</p>
     #[serde(rename = "Connection")]
@@ -2183,6 +4176,7 @@ pub struct GetConnectionResponse {
 
 /// <p>Filters the connection definitions that are returned by the <code>GetConnections</code> API operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConnectionsFilter {
     /// <p>The type of connections to return. Currently, only JDBC is supported; SFTP is not supported.</p>
     #[serde(rename = "ConnectionType")]
@@ -2194,7 +4188,30 @@ pub struct GetConnectionsFilter {
     pub match_criteria: Option<Vec<String>>,
 }
 
+impl Validate for GetConnectionsFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.match_criteria {
+            errors.check_length(
+                &field_path(path, "match_criteria"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "match_criteria"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+                );
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConnectionsRequest {
     /// <p>The ID of the Data Catalog in which the connections reside. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -2218,8 +4235,37 @@ pub struct GetConnectionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetConnectionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConnectionsResponse {
     /// <p>A list of requested connection definitions.</p>
     #[serde(rename = "ConnectionList")]
@@ -2232,6 +4278,7 @@ pub struct GetConnectionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCrawlerMetricsRequest {
     /// <p>A list of the names of crawlers about which to retrieve metrics.</p>
     #[serde(rename = "CrawlerNameList")]
@@ -2247,8 +4294,38 @@ pub struct GetCrawlerMetricsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetCrawlerMetricsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.crawler_name_list {
+            errors.check_length(
+                &field_path(path, "crawler_name_list"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "crawler_name_list"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCrawlerMetricsResponse {
     /// <p>A list of metrics for the specified crawler.</p>
     #[serde(rename = "CrawlerMetricsList")]
@@ -2261,14 +4338,31 @@ pub struct GetCrawlerMetricsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCrawlerRequest {
     /// <p>The name of the crawler to retrieve metadata for.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for GetCrawlerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCrawlerResponse {
     /// <p>The metadata for the specified crawler.</p>
     #[serde(rename = "Crawler")]
@@ -2277,6 +4371,7 @@ pub struct GetCrawlerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCrawlersRequest {
     /// <p>The number of crawlers to return on each call.</p>
     #[serde(rename = "MaxResults")]
@@ -2288,8 +4383,21 @@ pub struct GetCrawlersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetCrawlersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCrawlersResponse {
     /// <p>A list of crawler metadata.</p>
     #[serde(rename = "Crawlers")]
@@ -2302,6 +4410,7 @@ pub struct GetCrawlersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDataCatalogEncryptionSettingsRequest {
     /// <p>The ID of the Data Catalog to retrieve the security configuration for. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -2309,8 +4418,26 @@ pub struct GetDataCatalogEncryptionSettingsRequest {
     pub catalog_id: Option<String>,
 }
 
+impl Validate for GetDataCatalogEncryptionSettingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDataCatalogEncryptionSettingsResponse {
     /// <p>The requested security configuration.</p>
     #[serde(rename = "DataCatalogEncryptionSettings")]
@@ -2319,6 +4446,7 @@ pub struct GetDataCatalogEncryptionSettingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDatabaseRequest {
     /// <p>The ID of the Data Catalog in which the database resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -2329,8 +4457,37 @@ pub struct GetDatabaseRequest {
     pub name: String,
 }
 
+impl Validate for GetDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDatabaseResponse {
     /// <p>The definition of the specified database in the Data Catalog.</p>
     #[serde(rename = "Database")]
@@ -2339,6 +4496,7 @@ pub struct GetDatabaseResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDatabasesRequest {
     /// <p>The ID of the Data Catalog from which to retrieve <code>Databases</code>. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -2354,8 +4512,29 @@ pub struct GetDatabasesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetDatabasesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDatabasesResponse {
     /// <p>A list of <code>Database</code> objects from the specified catalog.</p>
     #[serde(rename = "DatabaseList")]
@@ -2367,6 +4546,7 @@ pub struct GetDatabasesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDataflowGraphRequest {
     /// <p>The Python script to transform.</p>
     #[serde(rename = "PythonScript")]
@@ -2375,7 +4555,7 @@ pub struct GetDataflowGraphRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDataflowGraphResponse {
     /// <p>A list of the edges in the resulting DAG.</p>
     #[serde(rename = "DagEdges")]
@@ -2388,6 +4568,7 @@ pub struct GetDataflowGraphResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDevEndpointRequest {
     /// <p>Name of the <code>DevEndpoint</code> to retrieve information for.</p>
     #[serde(rename = "EndpointName")]
@@ -2395,7 +4576,7 @@ pub struct GetDevEndpointRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDevEndpointResponse {
     /// <p>A <code>DevEndpoint</code> definition.</p>
     #[serde(rename = "DevEndpoint")]
@@ -2404,6 +4585,7 @@ pub struct GetDevEndpointResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDevEndpointsRequest {
     /// <p>The maximum size of information to return.</p>
     #[serde(rename = "MaxResults")]
@@ -2415,8 +4597,21 @@ pub struct GetDevEndpointsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetDevEndpointsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDevEndpointsResponse {
     /// <p>A list of <code>DevEndpoint</code> definitions.</p>
     #[serde(rename = "DevEndpoints")]
@@ -2429,6 +4624,7 @@ pub struct GetDevEndpointsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobBookmarkRequest {
     /// <p>The name of the job in question.</p>
     #[serde(rename = "JobName")]
@@ -2440,7 +4636,7 @@ pub struct GetJobBookmarkRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobBookmarkResponse {
     /// <p>A structure that defines a point that a job can resume processing.</p>
     #[serde(rename = "JobBookmarkEntry")]
@@ -2449,14 +4645,31 @@ pub struct GetJobBookmarkResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobRequest {
     /// <p>The name of the job definition to retrieve.</p>
     #[serde(rename = "JobName")]
     pub job_name: String,
 }
 
+impl Validate for GetJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_name"),
+            self.job_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_name"),
+            &self.job_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobResponse {
     /// <p>The requested job definition.</p>
     #[serde(rename = "Job")]
@@ -2465,6 +4678,7 @@ pub struct GetJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobRunRequest {
     /// <p>Name of the job definition being run.</p>
     #[serde(rename = "JobName")]
@@ -2478,8 +4692,35 @@ pub struct GetJobRunRequest {
     pub run_id: String,
 }
 
+impl Validate for GetJobRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_name"),
+            self.job_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_name"),
+            &self.job_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "run_id"),
+            self.run_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "run_id"),
+            &self.run_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobRunResponse {
     /// <p>The requested job-run metadata.</p>
     #[serde(rename = "JobRun")]
@@ -2488,6 +4729,7 @@ pub struct GetJobRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobRunsRequest {
     /// <p>The name of the job definition for which to retrieve all job runs.</p>
     #[serde(rename = "JobName")]
@@ -2502,8 +4744,32 @@ pub struct GetJobRunsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetJobRunsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_name"),
+            self.job_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_name"),
+            &self.job_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobRunsResponse {
     /// <p>A list of job-run metadata objects.</p>
     #[serde(rename = "JobRuns")]
@@ -2516,6 +4782,7 @@ pub struct GetJobRunsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobsRequest {
     /// <p>The maximum size of the response.</p>
     #[serde(rename = "MaxResults")]
@@ -2527,8 +4794,21 @@ pub struct GetJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobsResponse {
     /// <p>A list of job definitions.</p>
     #[serde(rename = "Jobs")]
@@ -2541,6 +4821,7 @@ pub struct GetJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMLTaskRunRequest {
     /// <p>The unique identifier of the task run.</p>
     #[serde(rename = "TaskRunId")]
@@ -2550,13 +4831,40 @@ pub struct GetMLTaskRunRequest {
     pub transform_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetMLTaskRunResponse {
-    /// <p>The date and time when this task run was completed.</p>
-    #[serde(rename = "CompletedOn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+impl Validate for GetMLTaskRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "task_run_id"),
+            self.task_run_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "task_run_id"),
+            &self.task_run_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "transform_id"),
+            self.transform_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_id"),
+            &self.transform_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct GetMLTaskRunResponse {
+    /// <p>The date and time when this task run was completed.</p>
+    #[serde(rename = "CompletedOn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_on: Option<f64>,
     /// <p>The error strings that are associated with the task run.</p>
     #[serde(rename = "ErrorString")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2596,6 +4904,7 @@ pub struct GetMLTaskRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMLTaskRunsRequest {
     /// <p>The filter criteria, in the <code>TaskRunFilterCriteria</code> structure, for the task run.</p>
     #[serde(rename = "Filter")]
@@ -2618,8 +4927,32 @@ pub struct GetMLTaskRunsRequest {
     pub transform_id: String,
 }
 
+impl Validate for GetMLTaskRunsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "transform_id"),
+            self.transform_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_id"),
+            &self.transform_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMLTaskRunsResponse {
     /// <p>A pagination token, if more results are available.</p>
     #[serde(rename = "NextToken")]
@@ -2632,14 +4965,31 @@ pub struct GetMLTaskRunsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMLTransformRequest {
     /// <p>The unique identifier of the transform, generated at the time that the transform was created.</p>
     #[serde(rename = "TransformId")]
     pub transform_id: String,
 }
 
+impl Validate for GetMLTransformRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transform_id"),
+            self.transform_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_id"),
+            &self.transform_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMLTransformResponse {
     /// <p>The date and time when the transform was created.</p>
     #[serde(rename = "CreatedOn")]
@@ -2712,6 +5062,7 @@ pub struct GetMLTransformResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMLTransformsRequest {
     /// <p>The filter transformation criteria.</p>
     #[serde(rename = "Filter")]
@@ -2731,8 +5082,24 @@ pub struct GetMLTransformsRequest {
     pub sort: Option<TransformSortCriteria>,
 }
 
+impl Validate for GetMLTransformsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMLTransformsResponse {
     /// <p>A pagination token, if more results are available.</p>
     #[serde(rename = "NextToken")]
@@ -2744,6 +5111,7 @@ pub struct GetMLTransformsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMappingRequest {
     /// <p>Parameters for the mapping.</p>
     #[serde(rename = "Location")]
@@ -2758,8 +5126,23 @@ pub struct GetMappingRequest {
     pub source: CatalogEntry,
 }
 
+impl Validate for GetMappingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.location {
+            value.validate_at(&field_path(path, "location"), errors);
+        }
+        if let Some(ref value) = self.sinks {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sinks"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.source.validate_at(&field_path(path, "source"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMappingResponse {
     /// <p>A list of mappings to the specified targets.</p>
     #[serde(rename = "Mapping")]
@@ -2767,6 +5150,7 @@ pub struct GetMappingResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPartitionRequest {
     /// <p>The ID of the Data Catalog where the partition in question resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -2783,8 +5167,52 @@ pub struct GetPartitionRequest {
     pub table_name: String,
 }
 
+impl Validate for GetPartitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        for (i, item) in self.partition_values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "partition_values"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPartitionResponse {
     /// <p>The requested information, in the form of a <code>Partition</code> object.</p>
     #[serde(rename = "Partition")]
@@ -2793,6 +5221,7 @@ pub struct GetPartitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPartitionsRequest {
     /// <p>The ID of the Data Catalog where the partitions in question reside. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -2822,8 +5251,72 @@ pub struct GetPartitionsRequest {
     pub table_name: String,
 }
 
+impl Validate for GetPartitionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.expression {
+            errors.check_length(
+                &field_path(path, "expression"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "expression"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.segment {
+            value.validate_at(&field_path(path, "segment"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPartitionsResponse {
     /// <p>A continuation token, if the returned list of partitions does not include the last one.</p>
     #[serde(rename = "NextToken")]
@@ -2836,6 +5329,7 @@ pub struct GetPartitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPlanRequest {
     /// <p>The programming language of the code to perform the mapping.</p>
     #[serde(rename = "Language")]
@@ -2857,8 +5351,23 @@ pub struct GetPlanRequest {
     pub source: CatalogEntry,
 }
 
+impl Validate for GetPlanRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.location {
+            value.validate_at(&field_path(path, "location"), errors);
+        }
+        if let Some(ref value) = self.sinks {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sinks"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.source.validate_at(&field_path(path, "source"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPlanResponse {
     /// <p>A Python script to perform the mapping.</p>
     #[serde(rename = "PythonScript")]
@@ -2871,10 +5380,11 @@ pub struct GetPlanResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourcePolicyRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourcePolicyResponse {
     /// <p>The date and time at which the policy was created.</p>
     #[serde(rename = "CreateTime")]
@@ -2895,14 +5405,31 @@ pub struct GetResourcePolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSecurityConfigurationRequest {
     /// <p>The name of the security configuration to retrieve.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for GetSecurityConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSecurityConfigurationResponse {
     /// <p>The requested security configuration.</p>
     #[serde(rename = "SecurityConfiguration")]
@@ -2911,6 +5438,7 @@ pub struct GetSecurityConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSecurityConfigurationsRequest {
     /// <p>The maximum number of results to return.</p>
     #[serde(rename = "MaxResults")]
@@ -2922,8 +5450,21 @@ pub struct GetSecurityConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetSecurityConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSecurityConfigurationsResponse {
     /// <p>A continuation token, if there are more security configurations to return.</p>
     #[serde(rename = "NextToken")]
@@ -2936,6 +5477,7 @@ pub struct GetSecurityConfigurationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTableRequest {
     /// <p>The ID of the Data Catalog where the table resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -2949,8 +5491,48 @@ pub struct GetTableRequest {
     pub name: String,
 }
 
+impl Validate for GetTableRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTableResponse {
     /// <p>The <code>Table</code> object that defines the specified table.</p>
     #[serde(rename = "Table")]
@@ -2959,6 +5541,7 @@ pub struct GetTableResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTableVersionRequest {
     /// <p>The ID of the Data Catalog where the tables reside. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -2976,8 +5559,61 @@ pub struct GetTableVersionRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for GetTableVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.version_id {
+            errors.check_length(
+                &field_path(path, "version_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "version_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTableVersionResponse {
     /// <p>The requested table version.</p>
     #[serde(rename = "TableVersion")]
@@ -2986,6 +5622,7 @@ pub struct GetTableVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTableVersionsRequest {
     /// <p>The ID of the Data Catalog where the tables reside. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -3007,8 +5644,51 @@ pub struct GetTableVersionsRequest {
     pub table_name: String,
 }
 
+impl Validate for GetTableVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTableVersionsResponse {
     /// <p>A continuation token, if the list of available versions does not include the last one.</p>
     #[serde(rename = "NextToken")]
@@ -3021,6 +5701,7 @@ pub struct GetTableVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTablesRequest {
     /// <p>The ID of the Data Catalog where the tables reside. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -3043,8 +5724,53 @@ pub struct GetTablesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetTablesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.expression {
+            errors.check_length(
+                &field_path(path, "expression"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "expression"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTablesResponse {
     /// <p>A continuation token, present if the current list segment is not the last.</p>
     #[serde(rename = "NextToken")]
@@ -3057,14 +5783,31 @@ pub struct GetTablesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTagsRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource for which to retrieve tags.</p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for GetTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(10240),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:(aws|aws-us-gov|aws-cn):glue:.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTagsResponse {
     /// <p>The requested tags.</p>
     #[serde(rename = "Tags")]
@@ -3073,14 +5816,31 @@ pub struct GetTagsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTriggerRequest {
     /// <p>The name of the trigger to retrieve.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for GetTriggerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTriggerResponse {
     /// <p>The requested trigger definition.</p>
     #[serde(rename = "Trigger")]
@@ -3089,6 +5849,7 @@ pub struct GetTriggerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTriggersRequest {
     /// <p>The name of the job to retrieve triggers for. The trigger that can start this job is returned, and if there is no such trigger, all triggers are returned.</p>
     #[serde(rename = "DependentJobName")]
@@ -3104,8 +5865,34 @@ pub struct GetTriggersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetTriggersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dependent_job_name {
+            errors.check_length(
+                &field_path(path, "dependent_job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "dependent_job_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTriggersResponse {
     /// <p>A continuation token, if not all the requested triggers have yet been returned.</p>
     #[serde(rename = "NextToken")]
@@ -3118,6 +5905,7 @@ pub struct GetTriggersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUserDefinedFunctionRequest {
     /// <p>The ID of the Data Catalog where the function to be retrieved is located. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -3131,8 +5919,48 @@ pub struct GetUserDefinedFunctionRequest {
     pub function_name: String,
 }
 
+impl Validate for GetUserDefinedFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "function_name"),
+            &self.function_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUserDefinedFunctionResponse {
     /// <p>The requested function definition.</p>
     #[serde(rename = "UserDefinedFunction")]
@@ -3141,6 +5969,7 @@ pub struct GetUserDefinedFunctionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUserDefinedFunctionsRequest {
     /// <p>The ID of the Data Catalog where the functions to be retrieved are located. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -3162,8 +5991,51 @@ pub struct GetUserDefinedFunctionsRequest {
     pub pattern: String,
 }
 
+impl Validate for GetUserDefinedFunctionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "pattern"),
+            self.pattern.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "pattern"),
+            &self.pattern,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUserDefinedFunctionsResponse {
     /// <p>A continuation token, if the list of functions returned does not include the last requested function.</p>
     #[serde(rename = "NextToken")]
@@ -3176,6 +6048,7 @@ pub struct GetUserDefinedFunctionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetWorkflowRequest {
     /// <p>Specifies whether to include a graph when returning the workflow resource metadata.</p>
     #[serde(rename = "IncludeGraph")]
@@ -3186,8 +6059,24 @@ pub struct GetWorkflowRequest {
     pub name: String,
 }
 
+impl Validate for GetWorkflowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetWorkflowResponse {
     /// <p>The resource metadata for the workflow.</p>
     #[serde(rename = "Workflow")]
@@ -3196,6 +6085,7 @@ pub struct GetWorkflowResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetWorkflowRunPropertiesRequest {
     /// <p>Name of the workflow which was run.</p>
     #[serde(rename = "Name")]
@@ -3205,8 +6095,35 @@ pub struct GetWorkflowRunPropertiesRequest {
     pub run_id: String,
 }
 
+impl Validate for GetWorkflowRunPropertiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "run_id"),
+            self.run_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "run_id"),
+            &self.run_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetWorkflowRunPropertiesResponse {
     /// <p>The workflow run properties which were set during the specified run.</p>
     #[serde(rename = "RunProperties")]
@@ -3215,6 +6132,7 @@ pub struct GetWorkflowRunPropertiesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetWorkflowRunRequest {
     /// <p>Specifies whether to include the workflow graph in response or not.</p>
     #[serde(rename = "IncludeGraph")]
@@ -3228,8 +6146,35 @@ pub struct GetWorkflowRunRequest {
     pub run_id: String,
 }
 
+impl Validate for GetWorkflowRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "run_id"),
+            self.run_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "run_id"),
+            &self.run_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetWorkflowRunResponse {
     /// <p>The requested workflow run metadata.</p>
     #[serde(rename = "Run")]
@@ -3238,6 +6183,7 @@ pub struct GetWorkflowRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetWorkflowRunsRequest {
     /// <p>Specifies whether to include the workflow graph in response or not.</p>
     #[serde(rename = "IncludeGraph")]
@@ -3256,8 +6202,32 @@ pub struct GetWorkflowRunsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetWorkflowRunsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetWorkflowRunsResponse {
     /// <p>A continuation token, if not all requested workflow runs have been returned.</p>
     #[serde(rename = "NextToken")]
@@ -3288,9 +6258,62 @@ pub struct GlueTable {
     pub table_name: String,
 }
 
+impl Validate for GlueTable {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.connection_name {
+            errors.check_length(
+                &field_path(path, "connection_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "connection_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 /// <p>A classifier that uses <code>grok</code> patterns.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GrokClassifier {
     /// <p>An identifier of the data format that the classifier matches, such as Twitter, JSON, Omniture logs, and so on.</p>
     #[serde(rename = "Classification")]
@@ -3320,6 +6343,7 @@ pub struct GrokClassifier {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportCatalogToGlueRequest {
     /// <p>The ID of the catalog to import. Currently, this should be the AWS account ID.</p>
     #[serde(rename = "CatalogId")]
@@ -3327,13 +6351,31 @@ pub struct ImportCatalogToGlueRequest {
     pub catalog_id: Option<String>,
 }
 
+impl Validate for ImportCatalogToGlueRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportCatalogToGlueResponse {}
 
 /// <p>Specifies configuration properties for an importing labels task run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportLabelsTaskRunProperties {
     /// <p>The Amazon Simple Storage Service (Amazon S3) path from where you will import the labels.</p>
     #[serde(rename = "InputS3Path")]
@@ -3364,7 +6406,7 @@ pub struct JdbcTarget {
 
 /// <p>Specifies a job definition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Job {
     /// <p>The <code>JobCommand</code> that executes this job.</p>
     #[serde(rename = "Command")]
@@ -3442,7 +6484,7 @@ pub struct Job {
 
 /// <p>Defines a point that a job can resume processing.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobBookmarkEntry {
     /// <p>The attempt ID number.</p>
     #[serde(rename = "Attempt")]
@@ -3487,6 +6529,14 @@ pub struct JobBookmarksEncryption {
     pub kms_key_arn: Option<String>,
 }
 
+impl Validate for JobBookmarksEncryption {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_arn {
+            errors.check_pattern(&field_path(path, "kms_key_arn"), &value, "arn:aws:kms:.*");
+        }
+    }
+}
+
 /// <p>Specifies code executed when a job is run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JobCommand {
@@ -3504,9 +6554,29 @@ pub struct JobCommand {
     pub script_location: Option<String>,
 }
 
+impl Validate for JobCommand {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.python_version {
+            errors.check_pattern(
+                &field_path(path, "python_version"),
+                &value,
+                "^([2-3]|3[.]9)$",
+            );
+        }
+        if let Some(ref value) = self.script_location {
+            errors.check_length(
+                &field_path(path, "script_location"),
+                value.chars().count(),
+                None,
+                Some(400000),
+            );
+        }
+    }
+}
+
 /// <p>The details of a Job node present in the workflow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobNodeDetails {
     /// <p>The information for the job runs represented by the job node.</p>
     #[serde(rename = "JobRuns")]
@@ -3516,7 +6586,7 @@ pub struct JobNodeDetails {
 
 /// <p>Contains information about a job run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobRun {
     /// <p>The job arguments associated with this run. For this job run, they replace the default arguments set in the job definition itself.</p> <p>You can specify arguments here that your own job-execution script consumes, as well as arguments that AWS Glue itself consumes.</p> <p>For information about how to specify and consume your own job arguments, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-python-calling.html">Calling AWS Glue APIs in Python</a> topic in the developer guide.</p> <p>For information about the key-value pairs that AWS Glue consumes to set up your job, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-etl-glue-arguments.html">Special Parameters Used by AWS Glue</a> topic in the developer guide.</p>
     #[serde(rename = "Arguments")]
@@ -3606,6 +6676,7 @@ pub struct JobRun {
 
 /// <p>Specifies information used to update an existing job definition. The previous job definition is completely overwritten by this information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct JobUpdate {
     /// <p>The <code>JobCommand</code> that executes this job (required).</p>
     #[serde(rename = "Command")]
@@ -3669,9 +6740,58 @@ pub struct JobUpdate {
     pub worker_type: Option<String>,
 }
 
+impl Validate for JobUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.command {
+            value.validate_at(&field_path(path, "command"), errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.glue_version {
+            errors.check_length(
+                &field_path(path, "glue_version"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "glue_version"), &value, "^\\w+\\.\\w+$");
+        }
+        if let Some(ref value) = self.notification_property {
+            value.validate_at(&field_path(path, "notification_property"), errors);
+        }
+        if let Some(ref value) = self.security_configuration {
+            errors.check_length(
+                &field_path(path, "security_configuration"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "security_configuration"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.timeout {
+            errors.check_range(&field_path(path, "timeout"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>A classifier for <code>JSON</code> content.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JsonClassifier {
     /// <p>The time that this classifier was registered.</p>
     #[serde(rename = "CreationTime")]
@@ -3695,7 +6815,7 @@ pub struct JsonClassifier {
 
 /// <p>Specifies configuration properties for a labeling set generation task run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LabelingSetGenerationTaskRunProperties {
     /// <p>The Amazon Simple Storage Service (Amazon S3) path where you will generate the labeling set.</p>
     #[serde(rename = "OutputS3Path")]
@@ -3705,7 +6825,7 @@ pub struct LabelingSetGenerationTaskRunProperties {
 
 /// <p>Status and error information about the most recent crawl.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LastCrawlInfo {
     /// <p>If an error occurred, the error information about the last crawl.</p>
     #[serde(rename = "ErrorMessage")]
@@ -3734,6 +6854,7 @@ pub struct LastCrawlInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCrawlersRequest {
     /// <p>The maximum size of a list to return.</p>
     #[serde(rename = "MaxResults")]
@@ -3749,8 +6870,28 @@ pub struct ListCrawlersRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for ListCrawlersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCrawlersResponse {
     /// <p>The names of all crawlers in the account, or the crawlers with the specified tags.</p>
     #[serde(rename = "CrawlerNames")]
@@ -3763,6 +6904,7 @@ pub struct ListCrawlersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDevEndpointsRequest {
     /// <p>The maximum size of a list to return.</p>
     #[serde(rename = "MaxResults")]
@@ -3778,8 +6920,28 @@ pub struct ListDevEndpointsRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for ListDevEndpointsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDevEndpointsResponse {
     /// <p>The names of all the <code>DevEndpoint</code>s in the account, or the <code>DevEndpoint</code>s with the specified tags.</p>
     #[serde(rename = "DevEndpointNames")]
@@ -3792,6 +6954,7 @@ pub struct ListDevEndpointsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsRequest {
     /// <p>The maximum size of a list to return.</p>
     #[serde(rename = "MaxResults")]
@@ -3807,8 +6970,28 @@ pub struct ListJobsRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for ListJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJobsResponse {
     /// <p>The names of all jobs in the account, or the jobs with the specified tags.</p>
     #[serde(rename = "JobNames")]
@@ -3821,6 +7004,7 @@ pub struct ListJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTriggersRequest {
     /// <p> The name of the job for which to retrieve triggers. The trigger that can start this job is returned. If there is no such trigger, all triggers are returned.</p>
     #[serde(rename = "DependentJobName")]
@@ -3840,8 +7024,41 @@ pub struct ListTriggersRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for ListTriggersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dependent_job_name {
+            errors.check_length(
+                &field_path(path, "dependent_job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "dependent_job_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTriggersResponse {
     /// <p>A continuation token, if the returned list does not contain the last metric available.</p>
     #[serde(rename = "NextToken")]
@@ -3854,6 +7071,7 @@ pub struct ListTriggersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWorkflowsRequest {
     /// <p>The maximum size of a list to return.</p>
     #[serde(rename = "MaxResults")]
@@ -3865,8 +7083,21 @@ pub struct ListWorkflowsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListWorkflowsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWorkflowsResponse {
     /// <p>A continuation token, if not all workflow names have been returned.</p>
     #[serde(rename = "NextToken")]
@@ -3880,6 +7111,7 @@ pub struct ListWorkflowsResponse {
 
 /// <p>The location of resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Location {
     /// <p>An Amazon DynamoDB table location.</p>
     #[serde(rename = "DynamoDB")]
@@ -3895,9 +7127,23 @@ pub struct Location {
     pub s3: Option<Vec<CodeGenNodeArg>>,
 }
 
+impl Validate for Location {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dynamo_db {
+            errors.check_length(&field_path(path, "dynamo_db"), value.len(), None, Some(50));
+        }
+        if let Some(ref value) = self.jdbc {
+            errors.check_length(&field_path(path, "jdbc"), value.len(), None, Some(50));
+        }
+        if let Some(ref value) = self.s3 {
+            errors.check_length(&field_path(path, "s3"), value.len(), None, Some(50));
+        }
+    }
+}
+
 /// <p>A structure for a machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MLTransform {
     /// <p>A timestamp. The time and date that this machine learning transform was created.</p>
     #[serde(rename = "CreatedOn")]
@@ -4000,7 +7246,7 @@ pub struct MappingEntry {
 
 /// <p>A node represents an AWS Glue component like Trigger, Job etc. which is part of a workflow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Node {
     /// <p>Details of the crawler when the node represents a crawler.</p>
     #[serde(rename = "CrawlerDetails")]
@@ -4037,6 +7283,19 @@ pub struct NotificationProperty {
     pub notify_delay_after: Option<i64>,
 }
 
+impl Validate for NotificationProperty {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.notify_delay_after {
+            errors.check_range(
+                &field_path(path, "notify_delay_after"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Specifies the sort order of a sorted column.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Order {
@@ -4048,9 +7307,31 @@ pub struct Order {
     pub sort_order: i64,
 }
 
+impl Validate for Order {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "column"),
+            self.column.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "column"),
+            &self.column,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_range(
+            &field_path(path, "sort_order"),
+            self.sort_order,
+            None,
+            Some(1),
+        );
+    }
+}
+
 /// <p>Represents a slice of table data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Partition {
     /// <p>The time at which the partition was created.</p>
     #[serde(rename = "CreationTime")]
@@ -4088,7 +7369,7 @@ pub struct Partition {
 
 /// <p>Contains information about a partition error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PartitionError {
     /// <p>The details about the partition error.</p>
     #[serde(rename = "ErrorDetail")]
@@ -4102,6 +7383,7 @@ pub struct PartitionError {
 
 /// <p>The structure used to create and update a partition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PartitionInput {
     /// <p>The last time at which the partition was accessed.</p>
     #[serde(rename = "LastAccessTime")]
@@ -4125,6 +7407,26 @@ pub struct PartitionInput {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for PartitionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(512000));
+            }
+        }
+        if let Some(ref value) = self.storage_descriptor {
+            value.validate_at(&field_path(path, "storage_descriptor"), errors);
+        }
+        if let Some(ref value) = self.values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+            }
+        }
+    }
+}
+
 /// <p>Contains a list of values defining partitions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PartitionValueList {
@@ -4133,6 +7435,15 @@ pub struct PartitionValueList {
     pub values: Vec<String>,
 }
 
+impl Validate for PartitionValueList {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+        }
+    }
+}
+
 /// <p>Specifies the physical requirements for a connection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PhysicalConnectionRequirements {
@@ -4150,9 +7461,57 @@ pub struct PhysicalConnectionRequirements {
     pub subnet_id: Option<String>,
 }
 
+impl Validate for PhysicalConnectionRequirements {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.availability_zone {
+            errors.check_length(
+                &field_path(path, "availability_zone"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "availability_zone"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.security_group_id_list {
+            errors.check_length(
+                &field_path(path, "security_group_id_list"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "security_group_id_list"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.subnet_id {
+            errors.check_length(
+                &field_path(path, "subnet_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "subnet_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>A job run that was used in the predicate of a conditional trigger that triggered this job run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Predecessor {
     /// <p>The name of the job definition used by the predecessor job run.</p>
     #[serde(rename = "JobName")]
@@ -4177,6 +7536,17 @@ pub struct Predicate {
     pub logical: Option<String>,
 }
 
+impl Validate for Predicate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.conditions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "conditions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Permissions granted to a principal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PrincipalPermissions {
@@ -4190,8 +7560,17 @@ pub struct PrincipalPermissions {
     pub principal: Option<DataLakePrincipal>,
 }
 
+impl Validate for PrincipalPermissions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.principal {
+            value.validate_at(&field_path(path, "principal"), errors);
+        }
+    }
+}
+
 /// <p>Defines a property predicate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PropertyPredicate {
     /// <p>The comparator used to compare this property to others.</p>
     #[serde(rename = "Comparator")]
@@ -4207,7 +7586,29 @@ pub struct PropertyPredicate {
     pub value: Option<String>,
 }
 
+impl Validate for PropertyPredicate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutDataCatalogEncryptionSettingsRequest {
     /// <p>The ID of the Data Catalog to set the security configuration for. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -4218,11 +7619,34 @@ pub struct PutDataCatalogEncryptionSettingsRequest {
     pub data_catalog_encryption_settings: DataCatalogEncryptionSettings,
 }
 
+impl Validate for PutDataCatalogEncryptionSettingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        self.data_catalog_encryption_settings.validate_at(
+            &field_path(path, "data_catalog_encryption_settings"),
+            errors,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutDataCatalogEncryptionSettingsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutResourcePolicyRequest {
     /// <p>A value of <code>MUST_EXIST</code> is used to update a policy. A value of <code>NOT_EXIST</code> is used to create a new policy. If a value of <code>NONE</code> or a null value is used, the call will not depend on the existence of a policy.</p>
     #[serde(rename = "PolicyExistsCondition")]
@@ -4237,8 +7661,32 @@ pub struct PutResourcePolicyRequest {
     pub policy_in_json: String,
 }
 
+impl Validate for PutResourcePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.policy_hash_condition {
+            errors.check_length(
+                &field_path(path, "policy_hash_condition"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "policy_hash_condition"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "policy_in_json"),
+            self.policy_in_json.chars().count(),
+            Some(2),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutResourcePolicyResponse {
     /// <p>A hash of the policy that has just been set. This must be included in a subsequent call that overwrites or updates this policy.</p>
     #[serde(rename = "PolicyHash")]
@@ -4247,6 +7695,7 @@ pub struct PutResourcePolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutWorkflowRunPropertiesRequest {
     /// <p>Name of the workflow which was run.</p>
     #[serde(rename = "Name")]
@@ -4259,11 +7708,39 @@ pub struct PutWorkflowRunPropertiesRequest {
     pub run_properties: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for PutWorkflowRunPropertiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "run_id"),
+            self.run_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "run_id"),
+            &self.run_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutWorkflowRunPropertiesResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResetJobBookmarkRequest {
     /// <p>The name of the job in question.</p>
     #[serde(rename = "JobName")]
@@ -4275,7 +7752,7 @@ pub struct ResetJobBookmarkRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResetJobBookmarkResponse {
     /// <p>The reset bookmark entry.</p>
     #[serde(rename = "JobBookmarkEntry")]
@@ -4296,6 +7773,24 @@ pub struct ResourceUri {
     pub uri: Option<String>,
 }
 
+impl Validate for ResourceUri {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.uri {
+            errors.check_length(
+                &field_path(path, "uri"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "uri"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Specifies how Amazon Simple Storage Service (Amazon S3) data should be encrypted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3Encryption {
@@ -4309,6 +7804,14 @@ pub struct S3Encryption {
     pub s3_encryption_mode: Option<String>,
 }
 
+impl Validate for S3Encryption {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_arn {
+            errors.check_pattern(&field_path(path, "kms_key_arn"), &value, "arn:aws:kms:.*");
+        }
+    }
+}
+
 /// <p>Specifies a data store in Amazon Simple Storage Service (Amazon S3).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3Target {
@@ -4324,7 +7827,7 @@ pub struct S3Target {
 
 /// <p>A scheduling object using a <code>cron</code> statement to schedule an event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Schedule {
     /// <p>A <code>cron</code> expression used to specify the schedule. For more information, see <a href="http://docs.aws.amazon.com/glue/latest/dg/monitor-data-warehouse-schedule.html">Time-Based Schedules for Jobs and Crawlers</a>. For example, to run something every day at 12:15 UTC, specify <code>cron(15 12 * * ? *)</code>.</p>
     #[serde(rename = "ScheduleExpression")]
@@ -4362,7 +7865,39 @@ pub struct SchemaColumn {
     pub name: Option<String>,
 }
 
+impl Validate for SchemaColumn {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.data_type {
+            errors.check_length(
+                &field_path(path, "data_type"),
+                value.chars().count(),
+                None,
+                Some(131072),
+            );
+            errors.check_pattern(
+                &field_path(path, "data_type"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchTablesRequest {
     /// <p>A unique identifier, consisting of <code> <i>account_id</i>/datalake</code>.</p>
     #[serde(rename = "CatalogId")]
@@ -4390,8 +7925,60 @@ pub struct SearchTablesRequest {
     pub sort_criteria: Option<Vec<SortCriterion>>,
 }
 
+impl Validate for SearchTablesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.search_text {
+            errors.check_length(
+                &field_path(path, "search_text"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.sort_criteria {
+            errors.check_length(
+                &field_path(path, "sort_criteria"),
+                value.len(),
+                None,
+                Some(1),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_criteria"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchTablesResponse {
     /// <p>A continuation token, present if the current list segment is not the last.</p>
     #[serde(rename = "NextToken")]
@@ -4405,7 +7992,7 @@ pub struct SearchTablesResponse {
 
 /// <p>Specifies a security configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SecurityConfiguration {
     /// <p>The time at which this security configuration was created.</p>
     #[serde(rename = "CreatedTimeStamp")]
@@ -4423,6 +8010,7 @@ pub struct SecurityConfiguration {
 
 /// <p>Defines a non-overlapping region of a table's partitions, allowing multiple requests to be executed in parallel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Segment {
     /// <p>The zero-based index number of the segment. For example, if the total number of segments is 4, <code>SegmentNumber</code> values range from 0 through 3.</p>
     #[serde(rename = "SegmentNumber")]
@@ -4432,6 +8020,17 @@ pub struct Segment {
     pub total_segments: i64,
 }
 
+impl Validate for Segment {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "total_segments"),
+            self.total_segments,
+            Some(1),
+            Some(10),
+        );
+    }
+}
+
 /// <p>Information about a serialization/deserialization program (SerDe) that serves as an extractor and loader.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SerDeInfo {
@@ -4449,6 +8048,43 @@ pub struct SerDeInfo {
     pub serialization_library: Option<String>,
 }
 
+impl Validate for SerDeInfo {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(512000));
+            }
+        }
+        if let Some(ref value) = self.serialization_library {
+            errors.check_length(
+                &field_path(path, "serialization_library"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "serialization_library"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+    }
+}
+
 /// <p>Specifies skewed values in a table. Skewed values are those that occur with very high frequency.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SkewedInfo {
@@ -4466,7 +8102,24 @@ pub struct SkewedInfo {
     pub skewed_column_values: Option<Vec<String>>,
 }
 
+impl Validate for SkewedInfo {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.skewed_column_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "skewed_column_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+                );
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SortCriterion {
     #[serde(rename = "FieldName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4476,29 +8129,77 @@ pub struct SortCriterion {
     pub sort: Option<String>,
 }
 
+impl Validate for SortCriterion {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.field_name {
+            errors.check_length(
+                &field_path(path, "field_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartCrawlerRequest {
     /// <p>Name of the crawler to start.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for StartCrawlerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartCrawlerResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartCrawlerScheduleRequest {
     /// <p>Name of the crawler to schedule.</p>
     #[serde(rename = "CrawlerName")]
     pub crawler_name: String,
 }
 
+impl Validate for StartCrawlerScheduleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "crawler_name"),
+            self.crawler_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "crawler_name"),
+            &self.crawler_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartCrawlerScheduleResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartExportLabelsTaskRunRequest {
     /// <p>The Amazon S3 path where you export the labels.</p>
     #[serde(rename = "OutputS3Path")]
@@ -4508,8 +8209,24 @@ pub struct StartExportLabelsTaskRunRequest {
     pub transform_id: String,
 }
 
+impl Validate for StartExportLabelsTaskRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transform_id"),
+            self.transform_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_id"),
+            &self.transform_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartExportLabelsTaskRunResponse {
     /// <p>The unique identifier for the task run.</p>
     #[serde(rename = "TaskRunId")]
@@ -4518,6 +8235,7 @@ pub struct StartExportLabelsTaskRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartImportLabelsTaskRunRequest {
     /// <p>The Amazon Simple Storage Service (Amazon S3) path from where you import the labels.</p>
     #[serde(rename = "InputS3Path")]
@@ -4531,8 +8249,24 @@ pub struct StartImportLabelsTaskRunRequest {
     pub transform_id: String,
 }
 
+impl Validate for StartImportLabelsTaskRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transform_id"),
+            self.transform_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_id"),
+            &self.transform_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartImportLabelsTaskRunResponse {
     /// <p>The unique identifier for the task run.</p>
     #[serde(rename = "TaskRunId")]
@@ -4541,6 +8275,7 @@ pub struct StartImportLabelsTaskRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartJobRunRequest {
     /// <p>The job arguments specifically for this run. For this job run, they replace the default arguments set in the job definition itself.</p> <p>You can specify arguments here that your own job-execution script consumes, as well as arguments that AWS Glue itself consumes.</p> <p>For information about how to specify and consume your own Job arguments, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-python-calling.html">Calling AWS Glue APIs in Python</a> topic in the developer guide.</p> <p>For information about the key-value pairs that AWS Glue consumes to set up your job, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-etl-glue-arguments.html">Special Parameters Used by AWS Glue</a> topic in the developer guide.</p>
     #[serde(rename = "Arguments")]
@@ -4579,8 +8314,56 @@ pub struct StartJobRunRequest {
     pub worker_type: Option<String>,
 }
 
+impl Validate for StartJobRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_name"),
+            self.job_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_name"),
+            &self.job_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.job_run_id {
+            errors.check_length(
+                &field_path(path, "job_run_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_run_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.notification_property {
+            value.validate_at(&field_path(path, "notification_property"), errors);
+        }
+        if let Some(ref value) = self.security_configuration {
+            errors.check_length(
+                &field_path(path, "security_configuration"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "security_configuration"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.timeout {
+            errors.check_range(&field_path(path, "timeout"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartJobRunResponse {
     /// <p>The ID assigned to this job run.</p>
     #[serde(rename = "JobRunId")]
@@ -4589,14 +8372,31 @@ pub struct StartJobRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartMLEvaluationTaskRunRequest {
     /// <p>The unique identifier of the machine learning transform.</p>
     #[serde(rename = "TransformId")]
     pub transform_id: String,
 }
 
+impl Validate for StartMLEvaluationTaskRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transform_id"),
+            self.transform_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_id"),
+            &self.transform_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartMLEvaluationTaskRunResponse {
     /// <p>The unique identifier associated with this run.</p>
     #[serde(rename = "TaskRunId")]
@@ -4605,6 +8405,7 @@ pub struct StartMLEvaluationTaskRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartMLLabelingSetGenerationTaskRunRequest {
     /// <p>The Amazon Simple Storage Service (Amazon S3) path where you generate the labeling set.</p>
     #[serde(rename = "OutputS3Path")]
@@ -4614,8 +8415,24 @@ pub struct StartMLLabelingSetGenerationTaskRunRequest {
     pub transform_id: String,
 }
 
+impl Validate for StartMLLabelingSetGenerationTaskRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transform_id"),
+            self.transform_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_id"),
+            &self.transform_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartMLLabelingSetGenerationTaskRunResponse {
     /// <p>The unique run identifier that is associated with this task run.</p>
     #[serde(rename = "TaskRunId")]
@@ -4624,14 +8441,31 @@ pub struct StartMLLabelingSetGenerationTaskRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartTriggerRequest {
     /// <p>The name of the trigger to start.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for StartTriggerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartTriggerResponse {
     /// <p>The name of the trigger that was started.</p>
     #[serde(rename = "Name")]
@@ -4640,14 +8474,31 @@ pub struct StartTriggerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartWorkflowRunRequest {
     /// <p>The name of the workflow to start.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for StartWorkflowRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartWorkflowRunResponse {
     /// <p>An Id for the new run.</p>
     #[serde(rename = "RunId")]
@@ -4656,36 +8507,87 @@ pub struct StartWorkflowRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopCrawlerRequest {
     /// <p>Name of the crawler to stop.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for StopCrawlerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopCrawlerResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopCrawlerScheduleRequest {
     /// <p>Name of the crawler whose schedule state to set.</p>
     #[serde(rename = "CrawlerName")]
     pub crawler_name: String,
 }
 
+impl Validate for StopCrawlerScheduleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "crawler_name"),
+            self.crawler_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "crawler_name"),
+            &self.crawler_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopCrawlerScheduleResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopTriggerRequest {
     /// <p>The name of the trigger to stop.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for StopTriggerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopTriggerResponse {
     /// <p>The name of the trigger that was stopped.</p>
     #[serde(rename = "Name")]
@@ -4746,9 +8648,88 @@ pub struct StorageDescriptor {
     pub stored_as_sub_directories: Option<bool>,
 }
 
+impl Validate for StorageDescriptor {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket_columns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "bucket_columns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.columns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "columns"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.input_format {
+            errors.check_length(
+                &field_path(path, "input_format"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "input_format"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.location {
+            errors.check_length(
+                &field_path(path, "location"),
+                value.chars().count(),
+                None,
+                Some(2056),
+            );
+            errors.check_pattern(
+                &field_path(path, "location"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.output_format {
+            errors.check_length(
+                &field_path(path, "output_format"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "output_format"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(512000));
+            }
+        }
+        if let Some(ref value) = self.serde_info {
+            value.validate_at(&field_path(path, "serde_info"), errors);
+        }
+        if let Some(ref value) = self.skewed_info {
+            value.validate_at(&field_path(path, "skewed_info"), errors);
+        }
+        if let Some(ref value) = self.sort_columns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_columns"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Represents a collection of related data organized in columns and rows.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Table {
     /// <p>The time when the table definition was created in the Data Catalog.</p>
     #[serde(rename = "CreateTime")]
@@ -4821,7 +8802,7 @@ pub struct Table {
 
 /// <p>An error record for table operations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TableError {
     /// <p>The details about the error.</p>
     #[serde(rename = "ErrorDetail")]
@@ -4835,6 +8816,7 @@ pub struct TableError {
 
 /// <p>A structure used to define a table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TableInput {
     /// <p>A description of the table.</p>
     #[serde(rename = "Description")]
@@ -4885,9 +8867,90 @@ pub struct TableInput {
     pub view_original_text: Option<String>,
 }
 
+impl Validate for TableInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.owner {
+            errors.check_length(
+                &field_path(path, "owner"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "owner"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(512000));
+            }
+        }
+        if let Some(ref value) = self.partition_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "partition_keys"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.storage_descriptor {
+            value.validate_at(&field_path(path, "storage_descriptor"), errors);
+        }
+        if let Some(ref value) = self.table_type {
+            errors.check_length(
+                &field_path(path, "table_type"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.view_expanded_text {
+            errors.check_length(
+                &field_path(path, "view_expanded_text"),
+                value.chars().count(),
+                None,
+                Some(409600),
+            );
+        }
+        if let Some(ref value) = self.view_original_text {
+            errors.check_length(
+                &field_path(path, "view_original_text"),
+                value.chars().count(),
+                None,
+                Some(409600),
+            );
+        }
+    }
+}
+
 /// <p>Specifies a version of a table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TableVersion {
     /// <p>The table in question.</p>
     #[serde(rename = "Table")]
@@ -4901,7 +8964,7 @@ pub struct TableVersion {
 
 /// <p>An error record for table-version operations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TableVersionError {
     /// <p>The details about the error.</p>
     #[serde(rename = "ErrorDetail")]
@@ -4918,6 +8981,7 @@ pub struct TableVersionError {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The ARN of the AWS Glue resource to which to add the tags. For more information about AWS Glue resource ARNs, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-common.html#aws-glue-api-regex-aws-glue-arn-id">AWS Glue ARN string pattern</a>.</p>
     #[serde(rename = "ResourceArn")]
@@ -4927,13 +8991,39 @@ pub struct TagResourceRequest {
     pub tags_to_add: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(10240),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:(aws|aws-us-gov|aws-cn):glue:.*",
+        );
+        errors.check_length(
+            &field_path(path, "tags_to_add"),
+            self.tags_to_add.len(),
+            None,
+            Some(50),
+        );
+        for (key, item) in self.tags_to_add.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags_to_add"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>The sampling parameters that are associated with the machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskRun {
     /// <p>The last point in time that the requested task run was completed.</p>
     #[serde(rename = "CompletedOn")]
@@ -4979,6 +9069,7 @@ pub struct TaskRun {
 
 /// <p>The criteria that are used to filter the task runs for the machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TaskRunFilterCriteria {
     /// <p>Filter on task runs started after this date.</p>
     #[serde(rename = "StartedAfter")]
@@ -5000,7 +9091,7 @@ pub struct TaskRunFilterCriteria {
 
 /// <p>The configuration properties for the task run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskRunProperties {
     /// <p>The configuration properties for an exporting labels task run.</p>
     #[serde(rename = "ExportLabelsTaskRunProperties")]
@@ -5026,6 +9117,7 @@ pub struct TaskRunProperties {
 
 /// <p>The sorting criteria that are used to sort the list of task runs for the machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TaskRunSortCriteria {
     /// <p>The column to be used to sort the list of task runs for the machine learning transform.</p>
     #[serde(rename = "Column")]
@@ -5037,6 +9129,7 @@ pub struct TaskRunSortCriteria {
 
 /// <p>The criteria used to filter the machine learning transforms.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TransformFilterCriteria {
     /// <p>The time and date after which the transforms were created.</p>
     #[serde(rename = "CreatedAfter")]
@@ -5072,6 +9165,31 @@ pub struct TransformFilterCriteria {
     pub transform_type: Option<String>,
 }
 
+impl Validate for TransformFilterCriteria {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.schema {
+            errors.check_length(&field_path(path, "schema"), value.len(), None, Some(100));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "schema"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>The algorithm-specific parameters that are associated with the machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransformParameters {
@@ -5084,8 +9202,17 @@ pub struct TransformParameters {
     pub transform_type: String,
 }
 
+impl Validate for TransformParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.find_matches_parameters {
+            value.validate_at(&field_path(path, "find_matches_parameters"), errors);
+        }
+    }
+}
+
 /// <p>The sorting criteria that are associated with the machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TransformSortCriteria {
     /// <p>The column to be used in the sorting criteria that are associated with the machine learning transform.</p>
     #[serde(rename = "Column")]
@@ -5097,7 +9224,7 @@ pub struct TransformSortCriteria {
 
 /// <p>Information about a specific trigger.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Trigger {
     /// <p>The actions initiated by this trigger.</p>
     #[serde(rename = "Actions")]
@@ -5139,7 +9266,7 @@ pub struct Trigger {
 
 /// <p>The details of a Trigger node present in the workflow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TriggerNodeDetails {
     /// <p>The information of the trigger represented by the trigger node.</p>
     #[serde(rename = "Trigger")]
@@ -5149,6 +9276,7 @@ pub struct TriggerNodeDetails {
 
 /// <p>A structure used to provide information used to update a trigger. This object updates the previous trigger definition by overwriting it completely.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TriggerUpdate {
     /// <p>The actions initiated by this trigger.</p>
     #[serde(rename = "Actions")]
@@ -5172,7 +9300,48 @@ pub struct TriggerUpdate {
     pub schedule: Option<String>,
 }
 
+impl Validate for TriggerUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.actions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "actions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.predicate {
+            value.validate_at(&field_path(path, "predicate"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource from which to remove the tags.</p>
     #[serde(rename = "ResourceArn")]
@@ -5182,11 +9351,38 @@ pub struct UntagResourceRequest {
     pub tags_to_remove: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(10240),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:(aws|aws-us-gov|aws-cn):glue:.*",
+        );
+        errors.check_length(
+            &field_path(path, "tags_to_remove"),
+            self.tags_to_remove.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.tags_to_remove.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags_to_remove"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateClassifierRequest {
     /// <p>A <code>CsvClassifier</code> object with updated fields.</p>
     #[serde(rename = "CsvClassifier")]
@@ -5206,11 +9402,29 @@ pub struct UpdateClassifierRequest {
     pub xml_classifier: Option<UpdateXMLClassifierRequest>,
 }
 
+impl Validate for UpdateClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.csv_classifier {
+            value.validate_at(&field_path(path, "csv_classifier"), errors);
+        }
+        if let Some(ref value) = self.grok_classifier {
+            value.validate_at(&field_path(path, "grok_classifier"), errors);
+        }
+        if let Some(ref value) = self.json_classifier {
+            value.validate_at(&field_path(path, "json_classifier"), errors);
+        }
+        if let Some(ref value) = self.xml_classifier {
+            value.validate_at(&field_path(path, "xml_classifier"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateClassifierResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConnectionRequest {
     /// <p>The ID of the Data Catalog in which the connection resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -5224,11 +9438,43 @@ pub struct UpdateConnectionRequest {
     pub name: String,
 }
 
+impl Validate for UpdateConnectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        self.connection_input
+            .validate_at(&field_path(path, "connection_input"), errors);
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateConnectionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCrawlerRequest {
     /// <p>A list of custom classifiers that the user has registered. By default, all built-in classifiers are included in a crawl, but these custom classifiers always override the default classifiers for a given classification.</p>
     #[serde(rename = "Classifiers")]
@@ -5275,11 +9521,71 @@ pub struct UpdateCrawlerRequest {
     pub targets: Option<CrawlerTargets>,
 }
 
+impl Validate for UpdateCrawlerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.classifiers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "classifiers"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+                );
+            }
+        }
+        if let Some(ref value) = self.crawler_security_configuration {
+            errors.check_length(
+                &field_path(path, "crawler_security_configuration"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.table_prefix {
+            errors.check_length(
+                &field_path(path, "table_prefix"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.targets {
+            value.validate_at(&field_path(path, "targets"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateCrawlerResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCrawlerScheduleRequest {
     /// <p>The name of the crawler whose schedule to update.</p>
     #[serde(rename = "CrawlerName")]
@@ -5290,12 +9596,29 @@ pub struct UpdateCrawlerScheduleRequest {
     pub schedule: Option<String>,
 }
 
+impl Validate for UpdateCrawlerScheduleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "crawler_name"),
+            self.crawler_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "crawler_name"),
+            &self.crawler_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateCrawlerScheduleResponse {}
 
 /// <p>Specifies a custom CSV classifier to be updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCsvClassifierRequest {
     /// <p>Enables the processing of files that contain only one column.</p>
     #[serde(rename = "AllowSingleColumn")]
@@ -5326,7 +9649,53 @@ pub struct UpdateCsvClassifierRequest {
     pub quote_symbol: Option<String>,
 }
 
+impl Validate for UpdateCsvClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.delimiter {
+            errors.check_length(
+                &field_path(path, "delimiter"),
+                value.chars().count(),
+                Some(1),
+                Some(1),
+            );
+            errors.check_pattern(&field_path(path, "delimiter"), &value, "[^\\r\\n]");
+        }
+        if let Some(ref value) = self.header {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "header"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+                );
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        if let Some(ref value) = self.quote_symbol {
+            errors.check_length(
+                &field_path(path, "quote_symbol"),
+                value.chars().count(),
+                Some(1),
+                Some(1),
+            );
+            errors.check_pattern(&field_path(path, "quote_symbol"), &value, "[^\\r\\n]");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDatabaseRequest {
     /// <p>The ID of the Data Catalog in which the metadata database resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -5340,11 +9709,43 @@ pub struct UpdateDatabaseRequest {
     pub name: String,
 }
 
+impl Validate for UpdateDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        self.database_input
+            .validate_at(&field_path(path, "database_input"), errors);
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDatabaseResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDevEndpointRequest {
     /// <p>The map of arguments to add the map of arguments used to configure the <code>DevEndpoint</code>.</p>
     #[serde(rename = "AddArguments")]
@@ -5379,12 +9780,42 @@ pub struct UpdateDevEndpointRequest {
     pub update_etl_libraries: Option<bool>,
 }
 
+impl Validate for UpdateDevEndpointRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.add_arguments {
+            errors.check_length(
+                &field_path(path, "add_arguments"),
+                value.len(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.add_public_keys {
+            errors.check_length(
+                &field_path(path, "add_public_keys"),
+                value.len(),
+                None,
+                Some(5),
+            );
+        }
+        if let Some(ref value) = self.delete_public_keys {
+            errors.check_length(
+                &field_path(path, "delete_public_keys"),
+                value.len(),
+                None,
+                Some(5),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDevEndpointResponse {}
 
 /// <p>Specifies a grok classifier to update when passed to <code>UpdateClassifier</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGrokClassifierRequest {
     /// <p>An identifier of the data format that the classifier matches, such as Twitter, JSON, Omniture logs, Amazon CloudWatch Logs, and so on.</p>
     #[serde(rename = "Classification")]
@@ -5403,18 +9834,79 @@ pub struct UpdateGrokClassifierRequest {
     pub name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateJobRequest {
-    /// <p>The name of the job definition to update.</p>
-    #[serde(rename = "JobName")]
-    pub job_name: String,
+impl Validate for UpdateGrokClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_patterns {
+            errors.check_length(
+                &field_path(path, "custom_patterns"),
+                value.chars().count(),
+                None,
+                Some(16000),
+            );
+            errors.check_pattern(
+                &field_path(path, "custom_patterns"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.grok_pattern {
+            errors.check_length(
+                &field_path(path, "grok_pattern"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "grok_pattern"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
+pub struct UpdateJobRequest {
+    /// <p>The name of the job definition to update.</p>
+    #[serde(rename = "JobName")]
+    pub job_name: String,
     /// <p>Specifies the values with which to update the job definition.</p>
     #[serde(rename = "JobUpdate")]
     pub job_update: JobUpdate,
 }
 
+impl Validate for UpdateJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_name"),
+            self.job_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_name"),
+            &self.job_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        self.job_update
+            .validate_at(&field_path(path, "job_update"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateJobResponse {
     /// <p>Returns the name of the updated job definition.</p>
     #[serde(rename = "JobName")]
@@ -5424,6 +9916,7 @@ pub struct UpdateJobResponse {
 
 /// <p>Specifies a JSON classifier to be updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateJsonClassifierRequest {
     /// <p>A <code>JsonPath</code> string defining the JSON data for the classifier to classify. AWS Glue supports a subset of <code>JsonPath</code>, as described in <a href="https://docs.aws.amazon.com/glue/latest/dg/custom-classifier.html#custom-classifier-json">Writing JsonPath Custom Classifiers</a>.</p>
     #[serde(rename = "JsonPath")]
@@ -5434,7 +9927,24 @@ pub struct UpdateJsonClassifierRequest {
     pub name: String,
 }
 
+impl Validate for UpdateJsonClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMLTransformRequest {
     /// <p>A description of the transform. The default is an empty string.</p>
     #[serde(rename = "Description")]
@@ -5477,8 +9987,56 @@ pub struct UpdateMLTransformRequest {
     pub worker_type: Option<String>,
 }
 
+impl Validate for UpdateMLTransformRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\r\\n\\t]*",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.parameters {
+            value.validate_at(&field_path(path, "parameters"), errors);
+        }
+        if let Some(ref value) = self.timeout {
+            errors.check_range(&field_path(path, "timeout"), *value, Some(1), None);
+        }
+        errors.check_length(
+            &field_path(path, "transform_id"),
+            self.transform_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_id"),
+            &self.transform_id,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateMLTransformResponse {
     /// <p>The unique identifier for the transform that was updated.</p>
     #[serde(rename = "TransformId")]
@@ -5487,6 +10045,7 @@ pub struct UpdateMLTransformResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePartitionRequest {
     /// <p>The ID of the Data Catalog where the partition to be updated resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -5506,11 +10065,64 @@ pub struct UpdatePartitionRequest {
     pub table_name: String,
 }
 
+impl Validate for UpdatePartitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        self.partition_input
+            .validate_at(&field_path(path, "partition_input"), errors);
+        errors.check_length(
+            &field_path(path, "partition_value_list"),
+            self.partition_value_list.len(),
+            None,
+            Some(100),
+        );
+        for (i, item) in self.partition_value_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "partition_value_list"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePartitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTableRequest {
     /// <p>The ID of the Data Catalog where the table resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -5528,11 +10140,43 @@ pub struct UpdateTableRequest {
     pub table_input: TableInput,
 }
 
+impl Validate for UpdateTableRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        self.table_input
+            .validate_at(&field_path(path, "table_input"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTableResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTriggerRequest {
     /// <p>The name of the trigger to update.</p>
     #[serde(rename = "Name")]
@@ -5542,8 +10186,26 @@ pub struct UpdateTriggerRequest {
     pub trigger_update: TriggerUpdate,
 }
 
+impl Validate for UpdateTriggerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        self.trigger_update
+            .validate_at(&field_path(path, "trigger_update"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTriggerResponse {
     /// <p>The resulting trigger definition.</p>
     #[serde(rename = "Trigger")]
@@ -5552,6 +10214,7 @@ pub struct UpdateTriggerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserDefinedFunctionRequest {
     /// <p>The ID of the Data Catalog where the function to be updated is located. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -5568,11 +10231,54 @@ pub struct UpdateUserDefinedFunctionRequest {
     pub function_name: String,
 }
 
+impl Validate for UpdateUserDefinedFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.catalog_id {
+            errors.check_length(
+                &field_path(path, "catalog_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "catalog_id"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        self.function_input
+            .validate_at(&field_path(path, "function_input"), errors);
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "function_name"),
+            &self.function_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUserDefinedFunctionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateWorkflowRequest {
     /// <p>A collection of properties to be used as part of each execution of the workflow.</p>
     #[serde(rename = "DefaultRunProperties")]
@@ -5587,8 +10293,24 @@ pub struct UpdateWorkflowRequest {
     pub name: String,
 }
 
+impl Validate for UpdateWorkflowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateWorkflowResponse {
     /// <p>The name of the workflow which was specified in input.</p>
     #[serde(rename = "Name")]
@@ -5598,6 +10320,7 @@ pub struct UpdateWorkflowResponse {
 
 /// <p>Specifies an XML classifier to be updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateXMLClassifierRequest {
     /// <p>An identifier of the data format that the classifier matches.</p>
     #[serde(rename = "Classification")]
@@ -5612,9 +10335,25 @@ pub struct UpdateXMLClassifierRequest {
     pub row_tag: Option<String>,
 }
 
+impl Validate for UpdateXMLClassifierRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 /// <p>Represents the equivalent of a Hive user-defined function (<code>UDF</code>) definition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserDefinedFunction {
     /// <p>The Java class that contains the function code.</p>
     #[serde(rename = "ClassName")]
@@ -5644,6 +10383,7 @@ pub struct UserDefinedFunction {
 
 /// <p>A structure used to create or update a user-defined function.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UserDefinedFunctionInput {
     /// <p>The Java class that contains the function code.</p>
     #[serde(rename = "ClassName")]
@@ -5667,9 +10407,65 @@ pub struct UserDefinedFunctionInput {
     pub resource_uris: Option<Vec<ResourceUri>>,
 }
 
+impl Validate for UserDefinedFunctionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.class_name {
+            errors.check_length(
+                &field_path(path, "class_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "class_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.function_name {
+            errors.check_length(
+                &field_path(path, "function_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "function_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.owner_name {
+            errors.check_length(
+                &field_path(path, "owner_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "owner_name"),
+                &value,
+                "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+            );
+        }
+        if let Some(ref value) = self.resource_uris {
+            errors.check_length(
+                &field_path(path, "resource_uris"),
+                value.len(),
+                None,
+                Some(1000),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_uris"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>A workflow represents a flow in which AWS Glue components should be executed to complete a logical task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Workflow {
     /// <p>The date and time when the workflow was created.</p>
     #[serde(rename = "CreatedOn")]
@@ -5703,7 +10499,7 @@ pub struct Workflow {
 
 /// <p>A workflow graph represents the complete workflow containing all the AWS Glue components present in the workflow and all the directed connections between them.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowGraph {
     /// <p>A list of all the directed connections between the nodes belonging to the workflow.</p>
     #[serde(rename = "Edges")]
@@ -5717,7 +10513,7 @@ pub struct WorkflowGraph {
 
 /// <p>A workflow run is an execution of a workflow providing all the runtime information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowRun {
     /// <p>The date and time when the workflow run completed.</p>
     #[serde(rename = "CompletedOn")]
@@ -5755,7 +10551,7 @@ pub struct WorkflowRun {
 
 /// <p>Workflow run statistics provides statistics about the workflow run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowRunStatistics {
     /// <p>Total number of Actions which have failed.</p>
     #[serde(rename = "FailedActions")]
@@ -5785,7 +10581,7 @@ pub struct WorkflowRunStatistics {
 
 /// <p>A classifier for <code>XML</code> content.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct XMLClassifier {
     /// <p>An identifier of the data format that the classifier matches.</p>
     #[serde(rename = "Classification")]
@@ -5861,7 +10657,12 @@ impl BatchCreatePartitionError {
                         BatchCreatePartitionError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5909,7 +10710,12 @@ impl BatchDeleteConnectionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5962,7 +10768,12 @@ impl BatchDeletePartitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6013,7 +10824,12 @@ impl BatchDeleteTableError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(BatchDeleteTableError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6072,7 +10888,12 @@ impl BatchDeleteTableVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6113,7 +10934,12 @@ impl BatchGetCrawlersError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(BatchGetCrawlersError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6166,7 +10992,12 @@ impl BatchGetDevEndpointsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6212,7 +11043,12 @@ impl BatchGetJobsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(BatchGetJobsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6267,7 +11103,12 @@ impl BatchGetPartitionError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(BatchGetPartitionError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6314,7 +11155,12 @@ impl BatchGetTriggersError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(BatchGetTriggersError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6359,7 +11205,12 @@ impl BatchGetWorkflowsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(BatchGetWorkflowsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6406,7 +11257,12 @@ impl GlueBatchStopJobRunError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6456,7 +11312,12 @@ impl CancelMLTaskRunError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(CancelMLTaskRunError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6502,7 +11363,12 @@ impl CreateClassifierError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(CreateClassifierError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6559,7 +11425,12 @@ impl CreateConnectionError {
                         CreateConnectionError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6613,7 +11484,12 @@ impl CreateCrawlerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6676,7 +11552,12 @@ impl CreateDatabaseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6748,7 +11629,12 @@ impl CreateDevEndpointError {
                         CreateDevEndpointError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6821,7 +11707,12 @@ impl CreateJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6894,7 +11785,12 @@ impl CreateMLTransformError {
                         CreateMLTransformError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6965,7 +11861,12 @@ impl CreatePartitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7014,7 +11915,12 @@ impl CreateScriptError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(CreateScriptError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7081,7 +11987,12 @@ impl CreateSecurityConfigurationError {
                         CreateSecurityConfigurationError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7150,7 +12061,12 @@ impl CreateTableError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7230,7 +12146,12 @@ impl CreateTriggerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7314,7 +12235,12 @@ impl CreateUserDefinedFunctionError {
                         CreateUserDefinedFunctionError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7382,7 +12308,12 @@ impl CreateWorkflowError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7425,7 +12356,12 @@ impl DeleteClassifierError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeleteClassifierError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7464,7 +12400,12 @@ impl DeleteConnectionError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeleteConnectionError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7515,7 +12456,12 @@ impl DeleteCrawlerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7566,7 +12512,12 @@ impl DeleteDatabaseError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeleteDatabaseError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7617,7 +12568,12 @@ impl DeleteDevEndpointError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeleteDevEndpointError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7663,7 +12619,12 @@ impl DeleteJobError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeleteJobError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7713,7 +12674,12 @@ impl DeleteMLTransformError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeleteMLTransformError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7764,7 +12730,12 @@ impl DeletePartitionError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeletePartitionError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7826,7 +12797,12 @@ impl DeleteResourcePolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7888,7 +12864,12 @@ impl DeleteSecurityConfigurationError {
                         DeleteSecurityConfigurationError::OperationTimeout(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7939,7 +12920,12 @@ impl DeleteTableError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeleteTableError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7990,7 +12976,12 @@ impl DeleteTableVersionError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeleteTableVersionError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8043,7 +13034,12 @@ impl DeleteTriggerError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeleteTriggerError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8102,7 +13098,12 @@ impl DeleteUserDefinedFunctionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8155,7 +13156,12 @@ impl DeleteWorkflowError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(DeleteWorkflowError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8200,7 +13206,12 @@ impl GetCatalogImportStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8239,7 +13250,12 @@ impl GetClassifierError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetClassifierError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8273,7 +13289,12 @@ impl GetClassifiersError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetClassifiersError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8321,7 +13342,12 @@ impl GetConnectionError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetConnectionError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8372,7 +13398,12 @@ impl GetConnectionsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetConnectionsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8413,7 +13444,12 @@ impl GetCrawlerError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetCrawlerError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8447,7 +13483,12 @@ impl GetCrawlerMetricsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetCrawlerMetricsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8480,7 +13521,12 @@ impl GetCrawlersError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetCrawlersError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8531,7 +13577,12 @@ impl GetDataCatalogEncryptionSettingsError {
                         GetDataCatalogEncryptionSettingsError::OperationTimeout(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8586,7 +13637,12 @@ impl GetDatabaseError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetDatabaseError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8638,7 +13694,12 @@ impl GetDatabasesError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetDatabasesError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8684,7 +13745,12 @@ impl GetDataflowGraphError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetDataflowGraphError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8734,7 +13800,12 @@ impl GetDevEndpointError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetDevEndpointError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8785,7 +13856,12 @@ impl GetDevEndpointsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetDevEndpointsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8836,7 +13912,12 @@ impl GetJobError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetJobError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8887,7 +13968,12 @@ impl GetJobBookmarkError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetJobBookmarkError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8938,7 +14024,12 @@ impl GetJobRunError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetJobRunError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8989,7 +14080,12 @@ impl GetJobRunsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetJobRunsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9040,7 +14136,12 @@ impl GetJobsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetJobsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9091,7 +14192,12 @@ impl GetMLTaskRunError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetMLTaskRunError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9142,7 +14248,12 @@ impl GetMLTaskRunsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetMLTaskRunsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9193,7 +14304,12 @@ impl GetMLTransformError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetMLTransformError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9244,7 +14360,12 @@ impl GetMLTransformsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetMLTransformsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9295,7 +14416,12 @@ impl GetMappingError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetMappingError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9351,7 +14477,12 @@ impl GetPartitionError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetPartitionError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9408,7 +14539,12 @@ impl GetPartitionsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetPartitionsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9455,7 +14591,12 @@ impl GetPlanError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetPlanError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9505,7 +14646,12 @@ impl GetResourcePolicyError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetResourcePolicyError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9564,7 +14710,12 @@ impl GetSecurityConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9623,7 +14774,12 @@ impl GetSecurityConfigurationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9679,7 +14835,12 @@ impl GetTableError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetTableError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9736,7 +14897,12 @@ impl GetTableVersionError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetTableVersionError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9793,7 +14959,12 @@ impl GetTableVersionsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetTableVersionsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9850,7 +15021,12 @@ impl GetTablesError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetTablesError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9902,7 +15078,12 @@ impl GetTagsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetTagsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9953,7 +15134,12 @@ impl GetTriggerError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetTriggerError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10004,7 +15190,12 @@ impl GetTriggersError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetTriggersError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10068,7 +15259,12 @@ impl GetUserDefinedFunctionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10135,7 +15331,12 @@ impl GetUserDefinedFunctionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10187,7 +15388,12 @@ impl GetWorkflowError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetWorkflowError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10238,7 +15444,12 @@ impl GetWorkflowRunError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetWorkflowRunError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10297,7 +15508,12 @@ impl GetWorkflowRunPropertiesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10348,7 +15564,12 @@ impl GetWorkflowRunsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(GetWorkflowRunsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10391,7 +15612,12 @@ impl ImportCatalogToGlueError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10425,7 +15651,12 @@ impl ListCrawlersError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(ListCrawlersError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10473,7 +15704,12 @@ impl ListDevEndpointsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(ListDevEndpointsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10524,7 +15760,12 @@ impl ListJobsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(ListJobsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10575,7 +15816,12 @@ impl ListTriggersError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(ListTriggersError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10621,7 +15867,12 @@ impl ListWorkflowsError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(ListWorkflowsError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10674,7 +15925,12 @@ impl PutDataCatalogEncryptionSettingsError {
                         PutDataCatalogEncryptionSettingsError::OperationTimeout(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10731,7 +15987,12 @@ impl PutResourcePolicyError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(PutResourcePolicyError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10812,7 +16073,12 @@ impl PutWorkflowRunPropertiesError {
                         PutWorkflowRunPropertiesError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10866,7 +16132,12 @@ impl ResetJobBookmarkError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(ResetJobBookmarkError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10912,7 +16183,12 @@ impl SearchTablesError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(SearchTablesError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10957,7 +16233,12 @@ impl StartCrawlerError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(StartCrawlerError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11018,7 +16299,12 @@ impl StartCrawlerScheduleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11078,7 +16364,12 @@ impl StartExportLabelsTaskRunError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11144,7 +16435,12 @@ impl StartImportLabelsTaskRunError {
                         StartImportLabelsTaskRunError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11208,7 +16504,12 @@ impl StartJobRunError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11283,7 +16584,12 @@ impl StartMLEvaluationTaskRunError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11353,7 +16659,12 @@ impl StartMLLabelingSetGenerationTaskRunError {
                         StartMLLabelingSetGenerationTaskRunError::OperationTimeout(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11417,7 +16728,12 @@ impl StartTriggerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11484,7 +16800,12 @@ impl StartWorkflowRunError {
                         StartWorkflowRunError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11537,7 +16858,12 @@ impl StopCrawlerError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(StopCrawlerError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11594,7 +16920,12 @@ impl StopCrawlerScheduleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11650,7 +16981,12 @@ impl StopTriggerError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(StopTriggerError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11702,7 +17038,12 @@ impl TagResourceError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(TagResourceError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11753,7 +17094,12 @@ impl UntagResourceError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(UntagResourceError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11804,7 +17150,12 @@ impl UpdateClassifierError {
                 "VersionMismatchException" => {
                     return RusotoError::Service(UpdateClassifierError::VersionMismatch(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11855,7 +17206,12 @@ impl UpdateConnectionError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(UpdateConnectionError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11911,7 +17267,12 @@ impl UpdateCrawlerError {
                 "VersionMismatchException" => {
                     return RusotoError::Service(UpdateCrawlerError::VersionMismatch(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11976,7 +17337,12 @@ impl UpdateCrawlerScheduleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12033,7 +17399,12 @@ impl UpdateDatabaseError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(UpdateDatabaseError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12085,7 +17456,12 @@ impl UpdateDevEndpointError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(UpdateDevEndpointError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12141,7 +17517,12 @@ impl UpdateJobError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(UpdateJobError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12198,7 +17579,12 @@ impl UpdateMLTransformError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(UpdateMLTransformError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12255,7 +17641,12 @@ impl UpdatePartitionError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(UpdatePartitionError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12324,7 +17715,12 @@ impl UpdateTableError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12385,7 +17781,12 @@ impl UpdateTriggerError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(UpdateTriggerError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12452,7 +17853,12 @@ impl UpdateUserDefinedFunctionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12511,7 +17917,12 @@ impl UpdateWorkflowError {
                 "OperationTimeoutException" => {
                     return RusotoError::Service(UpdateWorkflowError::OperationTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13258,2930 +18669,10710 @@ pub trait Glue {
         input: UpdateWorkflowRequest,
     ) -> RusotoFuture<UpdateWorkflowResponse, UpdateWorkflowError>;
 }
-/// A client for the AWS Glue API.
-#[derive(Clone)]
-pub struct GlueClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl GlueClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> GlueClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> GlueClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> GlueClient {
-        GlueClient { client, region }
-    }
-}
-
-impl Glue for GlueClient {
-    /// <p>Creates one or more partitions in a batch operation.</p>
+const _: Option<&dyn Glue> = None;
+impl<'a, T: Glue + ?Sized> Glue for &'a T {
     fn batch_create_partition(
         &self,
         input: BatchCreatePartitionRequest,
     ) -> RusotoFuture<BatchCreatePartitionResponse, BatchCreatePartitionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchCreatePartition");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchCreatePartitionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchCreatePartitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_create_partition(input)
     }
-
-    /// <p>Deletes a list of connection definitions from the Data Catalog.</p>
     fn batch_delete_connection(
         &self,
         input: BatchDeleteConnectionRequest,
     ) -> RusotoFuture<BatchDeleteConnectionResponse, BatchDeleteConnectionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchDeleteConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDeleteConnectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchDeleteConnectionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_delete_connection(input)
     }
-
-    /// <p>Deletes one or more partitions in a batch operation.</p>
     fn batch_delete_partition(
         &self,
         input: BatchDeletePartitionRequest,
     ) -> RusotoFuture<BatchDeletePartitionResponse, BatchDeletePartitionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchDeletePartition");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDeletePartitionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchDeletePartitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_delete_partition(input)
     }
-
-    /// <p><p>Deletes multiple tables at once.</p> <note> <p>After completing this operation, you no longer have access to the table versions and partitions that belong to the deleted table. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>BatchDeleteTable</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, and <code>DeletePartition</code> or <code>BatchDeletePartition</code>, to delete any resources that belong to the table.</p> </note></p>
     fn batch_delete_table(
         &self,
         input: BatchDeleteTableRequest,
     ) -> RusotoFuture<BatchDeleteTableResponse, BatchDeleteTableError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchDeleteTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDeleteTableResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchDeleteTableError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_delete_table(input)
     }
-
-    /// <p>Deletes a specified batch of versions of a table.</p>
     fn batch_delete_table_version(
         &self,
         input: BatchDeleteTableVersionRequest,
     ) -> RusotoFuture<BatchDeleteTableVersionResponse, BatchDeleteTableVersionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchDeleteTableVersion");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDeleteTableVersionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchDeleteTableVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).batch_delete_table_version(input)
     }
-
-    /// <p>Returns a list of resource metadata for a given list of crawler names. After calling the <code>ListCrawlers</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
     fn batch_get_crawlers(
         &self,
         input: BatchGetCrawlersRequest,
     ) -> RusotoFuture<BatchGetCrawlersResponse, BatchGetCrawlersError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchGetCrawlers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetCrawlersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetCrawlersError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_crawlers(input)
     }
-
-    /// <p>Returns a list of resource metadata for a given list of development endpoint names. After calling the <code>ListDevEndpoints</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
     fn batch_get_dev_endpoints(
         &self,
         input: BatchGetDevEndpointsRequest,
     ) -> RusotoFuture<BatchGetDevEndpointsResponse, BatchGetDevEndpointsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchGetDevEndpoints");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetDevEndpointsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchGetDevEndpointsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_get_dev_endpoints(input)
     }
-
-    /// <p>Returns a list of resource metadata for a given list of job names. After calling the <code>ListJobs</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags. </p>
     fn batch_get_jobs(
         &self,
         input: BatchGetJobsRequest,
     ) -> RusotoFuture<BatchGetJobsResponse, BatchGetJobsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchGetJobs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetJobsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetJobsError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_jobs(input)
     }
-
-    /// <p>Retrieves partitions in a batch request.</p>
     fn batch_get_partition(
         &self,
         input: BatchGetPartitionRequest,
     ) -> RusotoFuture<BatchGetPartitionResponse, BatchGetPartitionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchGetPartition");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetPartitionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetPartitionError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_partition(input)
     }
-
-    /// <p>Returns a list of resource metadata for a given list of trigger names. After calling the <code>ListTriggers</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
     fn batch_get_triggers(
         &self,
         input: BatchGetTriggersRequest,
     ) -> RusotoFuture<BatchGetTriggersResponse, BatchGetTriggersError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchGetTriggers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetTriggersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetTriggersError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_triggers(input)
     }
-
-    /// <p>Returns a list of resource metadata for a given list of workflow names. After calling the <code>ListWorkflows</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
     fn batch_get_workflows(
         &self,
         input: BatchGetWorkflowsRequest,
     ) -> RusotoFuture<BatchGetWorkflowsResponse, BatchGetWorkflowsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchGetWorkflows");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetWorkflowsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetWorkflowsError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_workflows(input)
     }
-
-    /// <p>Stops one or more job runs for a specified job definition.</p>
     fn batch_stop_job_run(
         &self,
         input: BatchStopJobRunRequest,
     ) -> RusotoFuture<BatchStopJobRunResponse, GlueBatchStopJobRunError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.BatchStopJobRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchStopJobRunResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GlueBatchStopJobRunError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_stop_job_run(input)
     }
-
-    /// <p>Cancels (stops) a task run. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can cancel a machine learning task run at any time by calling <code>CancelMLTaskRun</code> with a task run's parent transform's <code>TransformID</code> and the task run's <code>TaskRunId</code>. </p>
     fn cancel_ml_task_run(
         &self,
         input: CancelMLTaskRunRequest,
     ) -> RusotoFuture<CancelMLTaskRunResponse, CancelMLTaskRunError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CancelMLTaskRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelMLTaskRunResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelMLTaskRunError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_ml_task_run(input)
     }
-
-    /// <p>Creates a classifier in the user's account. This can be a <code>GrokClassifier</code>, an <code>XMLClassifier</code>, a <code>JsonClassifier</code>, or a <code>CsvClassifier</code>, depending on which field of the request is present.</p>
     fn create_classifier(
         &self,
         input: CreateClassifierRequest,
     ) -> RusotoFuture<CreateClassifierResponse, CreateClassifierError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateClassifier");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateClassifierResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateClassifierError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_classifier(input)
     }
-
-    /// <p>Creates a connection definition in the Data Catalog.</p>
     fn create_connection(
         &self,
         input: CreateConnectionRequest,
     ) -> RusotoFuture<CreateConnectionResponse, CreateConnectionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateConnectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateConnectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_connection(input)
     }
-
-    /// <p>Creates a new crawler with specified targets, role, configuration, and optional schedule. At least one crawl target must be specified, in the <code>s3Targets</code> field, the <code>jdbcTargets</code> field, or the <code>DynamoDBTargets</code> field.</p>
     fn create_crawler(
         &self,
         input: CreateCrawlerRequest,
     ) -> RusotoFuture<CreateCrawlerResponse, CreateCrawlerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateCrawler");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCrawlerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateCrawlerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_crawler(input)
     }
-
-    /// <p>Creates a new database in a Data Catalog.</p>
     fn create_database(
         &self,
         input: CreateDatabaseRequest,
     ) -> RusotoFuture<CreateDatabaseResponse, CreateDatabaseError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateDatabase");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDatabaseResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDatabaseError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_database(input)
     }
-
-    /// <p>Creates a new development endpoint.</p>
     fn create_dev_endpoint(
         &self,
         input: CreateDevEndpointRequest,
     ) -> RusotoFuture<CreateDevEndpointResponse, CreateDevEndpointError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateDevEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDevEndpointResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDevEndpointError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_dev_endpoint(input)
     }
-
-    /// <p>Creates a new job definition.</p>
     fn create_job(
         &self,
         input: CreateJobRequest,
     ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_job(input)
     }
-
-    /// <p>Creates an AWS Glue machine learning transform. This operation creates the transform and all the necessary parameters to train it.</p> <p>Call this operation as the first step in the process of using a machine learning transform (such as the <code>FindMatches</code> transform) for deduplicating data. You can provide an optional <code>Description</code>, in addition to the parameters that you want to use for your algorithm.</p> <p>You must also specify certain parameters for the tasks that AWS Glue runs on your behalf as part of learning from your data and creating a high-quality machine learning transform. These parameters include <code>Role</code>, and optionally, <code>AllocatedCapacity</code>, <code>Timeout</code>, and <code>MaxRetries</code>. For more information, see <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-jobs-job.html">Jobs</a>.</p>
     fn create_ml_transform(
         &self,
         input: CreateMLTransformRequest,
     ) -> RusotoFuture<CreateMLTransformResponse, CreateMLTransformError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateMLTransform");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateMLTransformResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateMLTransformError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_ml_transform(input)
     }
-
-    /// <p>Creates a new partition.</p>
     fn create_partition(
         &self,
         input: CreatePartitionRequest,
     ) -> RusotoFuture<CreatePartitionResponse, CreatePartitionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreatePartition");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePartitionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreatePartitionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_partition(input)
     }
-
-    /// <p>Transforms a directed acyclic graph (DAG) into code.</p>
     fn create_script(
         &self,
         input: CreateScriptRequest,
     ) -> RusotoFuture<CreateScriptResponse, CreateScriptError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateScript");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateScriptResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateScriptError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_script(input)
     }
-
-    /// <p>Creates a new security configuration. A security configuration is a set of security properties that can be used by AWS Glue. You can use a security configuration to encrypt data at rest. For information about using security configurations in AWS Glue, see <a href="https://docs.aws.amazon.com/glue/latest/dg/encryption-security-configuration.html">Encrypting Data Written by Crawlers, Jobs, and Development Endpoints</a>.</p>
     fn create_security_configuration(
         &self,
         input: CreateSecurityConfigurationRequest,
     ) -> RusotoFuture<CreateSecurityConfigurationResponse, CreateSecurityConfigurationError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateSecurityConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSecurityConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateSecurityConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_security_configuration(input)
     }
-
-    /// <p>Creates a new table definition in the Data Catalog.</p>
     fn create_table(
         &self,
         input: CreateTableRequest,
     ) -> RusotoFuture<CreateTableResponse, CreateTableError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTableResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTableError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_table(input)
     }
-
-    /// <p>Creates a new trigger.</p>
     fn create_trigger(
         &self,
         input: CreateTriggerRequest,
     ) -> RusotoFuture<CreateTriggerResponse, CreateTriggerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateTrigger");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTriggerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTriggerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_trigger(input)
     }
-
-    /// <p>Creates a new function definition in the Data Catalog.</p>
     fn create_user_defined_function(
         &self,
         input: CreateUserDefinedFunctionRequest,
     ) -> RusotoFuture<CreateUserDefinedFunctionResponse, CreateUserDefinedFunctionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateUserDefinedFunction");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserDefinedFunctionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateUserDefinedFunctionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_user_defined_function(input)
     }
-
-    /// <p>Creates a new workflow.</p>
     fn create_workflow(
         &self,
         input: CreateWorkflowRequest,
     ) -> RusotoFuture<CreateWorkflowResponse, CreateWorkflowError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.CreateWorkflow");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateWorkflowResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateWorkflowError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_workflow(input)
     }
-
-    /// <p>Removes a classifier from the Data Catalog.</p>
     fn delete_classifier(
         &self,
         input: DeleteClassifierRequest,
     ) -> RusotoFuture<DeleteClassifierResponse, DeleteClassifierError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteClassifier");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteClassifierResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteClassifierError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_classifier(input)
     }
-
-    /// <p>Deletes a connection from the Data Catalog.</p>
     fn delete_connection(
         &self,
         input: DeleteConnectionRequest,
     ) -> RusotoFuture<DeleteConnectionResponse, DeleteConnectionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteConnectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteConnectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_connection(input)
     }
-
-    /// <p>Removes a specified crawler from the AWS Glue Data Catalog, unless the crawler state is <code>RUNNING</code>.</p>
     fn delete_crawler(
         &self,
         input: DeleteCrawlerRequest,
     ) -> RusotoFuture<DeleteCrawlerResponse, DeleteCrawlerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteCrawler");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteCrawlerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteCrawlerError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_crawler(input)
     }
-
-    /// <p><p>Removes a specified database from a Data Catalog.</p> <note> <p>After completing this operation, you no longer have access to the tables (and all table versions and partitions that might belong to the tables) and the user-defined functions in the deleted database. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>DeleteDatabase</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, <code>DeletePartition</code> or <code>BatchDeletePartition</code>, <code>DeleteUserDefinedFunction</code>, and <code>DeleteTable</code> or <code>BatchDeleteTable</code>, to delete any resources that belong to the database.</p> </note></p>
     fn delete_database(
         &self,
         input: DeleteDatabaseRequest,
     ) -> RusotoFuture<DeleteDatabaseResponse, DeleteDatabaseError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteDatabase");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDatabaseResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDatabaseError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_database(input)
     }
-
-    /// <p>Deletes a specified development endpoint.</p>
     fn delete_dev_endpoint(
         &self,
         input: DeleteDevEndpointRequest,
     ) -> RusotoFuture<DeleteDevEndpointResponse, DeleteDevEndpointError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteDevEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDevEndpointResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDevEndpointError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_dev_endpoint(input)
     }
-
-    /// <p>Deletes a specified job definition. If the job definition is not found, no exception is thrown.</p>
     fn delete_job(
         &self,
         input: DeleteJobRequest,
     ) -> RusotoFuture<DeleteJobResponse, DeleteJobError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_job(input)
     }
-
-    /// <p>Deletes an AWS Glue machine learning transform. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue. If you no longer need a transform, you can delete it by calling <code>DeleteMLTransforms</code>. However, any AWS Glue jobs that still reference the deleted transform will no longer succeed.</p>
     fn delete_ml_transform(
         &self,
         input: DeleteMLTransformRequest,
     ) -> RusotoFuture<DeleteMLTransformResponse, DeleteMLTransformError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteMLTransform");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteMLTransformResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteMLTransformError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_ml_transform(input)
     }
-
-    /// <p>Deletes a specified partition.</p>
     fn delete_partition(
         &self,
         input: DeletePartitionRequest,
     ) -> RusotoFuture<DeletePartitionResponse, DeletePartitionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeletePartition");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeletePartitionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeletePartitionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_partition(input)
     }
-
-    /// <p>Deletes a specified policy.</p>
     fn delete_resource_policy(
         &self,
         input: DeleteResourcePolicyRequest,
     ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteResourcePolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteResourcePolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteResourcePolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_resource_policy(input)
     }
-
-    /// <p>Deletes a specified security configuration.</p>
     fn delete_security_configuration(
         &self,
         input: DeleteSecurityConfigurationRequest,
     ) -> RusotoFuture<DeleteSecurityConfigurationResponse, DeleteSecurityConfigurationError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteSecurityConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSecurityConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteSecurityConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_security_configuration(input)
     }
-
-    /// <p><p>Removes a table definition from the Data Catalog.</p> <note> <p>After completing this operation, you no longer have access to the table versions and partitions that belong to the deleted table. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>DeleteTable</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, and <code>DeletePartition</code> or <code>BatchDeletePartition</code>, to delete any resources that belong to the table.</p> </note></p>
     fn delete_table(
         &self,
         input: DeleteTableRequest,
     ) -> RusotoFuture<DeleteTableResponse, DeleteTableError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTableResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTableError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_table(input)
     }
-
-    /// <p>Deletes a specified version of a table.</p>
     fn delete_table_version(
         &self,
         input: DeleteTableVersionRequest,
     ) -> RusotoFuture<DeleteTableVersionResponse, DeleteTableVersionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteTableVersion");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTableVersionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTableVersionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_table_version(input)
     }
-
-    /// <p>Deletes a specified trigger. If the trigger is not found, no exception is thrown.</p>
     fn delete_trigger(
         &self,
         input: DeleteTriggerRequest,
     ) -> RusotoFuture<DeleteTriggerResponse, DeleteTriggerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteTrigger");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTriggerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTriggerError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_trigger(input)
     }
-
-    /// <p>Deletes an existing function definition from the Data Catalog.</p>
     fn delete_user_defined_function(
         &self,
         input: DeleteUserDefinedFunctionRequest,
     ) -> RusotoFuture<DeleteUserDefinedFunctionResponse, DeleteUserDefinedFunctionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteUserDefinedFunction");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteUserDefinedFunctionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteUserDefinedFunctionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_user_defined_function(input)
     }
-
-    /// <p>Deletes a workflow.</p>
     fn delete_workflow(
         &self,
         input: DeleteWorkflowRequest,
     ) -> RusotoFuture<DeleteWorkflowResponse, DeleteWorkflowError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.DeleteWorkflow");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteWorkflowResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteWorkflowError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_workflow(input)
     }
-
-    /// <p>Retrieves the status of a migration operation.</p>
     fn get_catalog_import_status(
         &self,
         input: GetCatalogImportStatusRequest,
     ) -> RusotoFuture<GetCatalogImportStatusResponse, GetCatalogImportStatusError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetCatalogImportStatus");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCatalogImportStatusResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetCatalogImportStatusError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_catalog_import_status(input)
     }
-
-    /// <p>Retrieve a classifier by name.</p>
     fn get_classifier(
         &self,
         input: GetClassifierRequest,
     ) -> RusotoFuture<GetClassifierResponse, GetClassifierError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetClassifier");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetClassifierResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetClassifierError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_classifier(input)
     }
-
-    /// <p>Lists all classifier objects in the Data Catalog.</p>
     fn get_classifiers(
         &self,
         input: GetClassifiersRequest,
     ) -> RusotoFuture<GetClassifiersResponse, GetClassifiersError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetClassifiers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetClassifiersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetClassifiersError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_classifiers(input)
     }
-
-    /// <p>Retrieves a connection definition from the Data Catalog.</p>
     fn get_connection(
         &self,
         input: GetConnectionRequest,
     ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetConnectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetConnectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_connection(input)
     }
-
-    /// <p>Retrieves a list of connection definitions from the Data Catalog.</p>
     fn get_connections(
         &self,
         input: GetConnectionsRequest,
     ) -> RusotoFuture<GetConnectionsResponse, GetConnectionsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetConnections");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetConnectionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetConnectionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_connections(input)
     }
-
-    /// <p>Retrieves metadata for a specified crawler.</p>
     fn get_crawler(
         &self,
         input: GetCrawlerRequest,
     ) -> RusotoFuture<GetCrawlerResponse, GetCrawlerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetCrawler");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCrawlerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetCrawlerError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_crawler(input)
     }
-
-    /// <p>Retrieves metrics about specified crawlers.</p>
     fn get_crawler_metrics(
         &self,
         input: GetCrawlerMetricsRequest,
     ) -> RusotoFuture<GetCrawlerMetricsResponse, GetCrawlerMetricsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetCrawlerMetrics");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCrawlerMetricsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetCrawlerMetricsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_crawler_metrics(input)
     }
-
-    /// <p>Retrieves metadata for all crawlers defined in the customer account.</p>
     fn get_crawlers(
         &self,
         input: GetCrawlersRequest,
     ) -> RusotoFuture<GetCrawlersResponse, GetCrawlersError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetCrawlers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCrawlersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetCrawlersError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_crawlers(input)
     }
-
-    /// <p>Retrieves the security configuration for a specified catalog.</p>
     fn get_data_catalog_encryption_settings(
         &self,
         input: GetDataCatalogEncryptionSettingsRequest,
     ) -> RusotoFuture<GetDataCatalogEncryptionSettingsResponse, GetDataCatalogEncryptionSettingsError>
     {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetDataCatalogEncryptionSettings");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDataCatalogEncryptionSettingsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetDataCatalogEncryptionSettingsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_data_catalog_encryption_settings(input)
     }
-
-    /// <p>Retrieves the definition of a specified database.</p>
     fn get_database(
         &self,
         input: GetDatabaseRequest,
     ) -> RusotoFuture<GetDatabaseResponse, GetDatabaseError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetDatabase");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDatabaseResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDatabaseError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_database(input)
     }
-
-    /// <p>Retrieves all databases defined in a given Data Catalog.</p>
     fn get_databases(
         &self,
         input: GetDatabasesRequest,
     ) -> RusotoFuture<GetDatabasesResponse, GetDatabasesError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetDatabases");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDatabasesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDatabasesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_databases(input)
     }
-
-    /// <p>Transforms a Python script into a directed acyclic graph (DAG). </p>
     fn get_dataflow_graph(
         &self,
         input: GetDataflowGraphRequest,
     ) -> RusotoFuture<GetDataflowGraphResponse, GetDataflowGraphError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetDataflowGraph");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDataflowGraphResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDataflowGraphError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_dataflow_graph(input)
     }
-
-    /// <p><p>Retrieves information about a specified development endpoint.</p> <note> <p>When you create a development endpoint in a virtual private cloud (VPC), AWS Glue returns only a private IP address, and the public IP address field is not populated. When you create a non-VPC development endpoint, AWS Glue returns only a public IP address.</p> </note></p>
     fn get_dev_endpoint(
         &self,
         input: GetDevEndpointRequest,
     ) -> RusotoFuture<GetDevEndpointResponse, GetDevEndpointError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetDevEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDevEndpointResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDevEndpointError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_dev_endpoint(input)
     }
-
-    /// <p><p>Retrieves all the development endpoints in this AWS account.</p> <note> <p>When you create a development endpoint in a virtual private cloud (VPC), AWS Glue returns only a private IP address and the public IP address field is not populated. When you create a non-VPC development endpoint, AWS Glue returns only a public IP address.</p> </note></p>
     fn get_dev_endpoints(
         &self,
         input: GetDevEndpointsRequest,
     ) -> RusotoFuture<GetDevEndpointsResponse, GetDevEndpointsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetDevEndpoints");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDevEndpointsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDevEndpointsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_dev_endpoints(input)
     }
-
-    /// <p>Retrieves an existing job definition.</p>
     fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResponse, GetJobError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_job(input)
     }
-
-    /// <p>Returns information on a job bookmark entry.</p>
     fn get_job_bookmark(
         &self,
         input: GetJobBookmarkRequest,
     ) -> RusotoFuture<GetJobBookmarkResponse, GetJobBookmarkError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetJobBookmark");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetJobBookmarkResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetJobBookmarkError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_job_bookmark(input)
     }
-
-    /// <p>Retrieves the metadata for a given job run.</p>
     fn get_job_run(
         &self,
         input: GetJobRunRequest,
     ) -> RusotoFuture<GetJobRunResponse, GetJobRunError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetJobRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetJobRunResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetJobRunError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_job_run(input)
     }
-
-    /// <p>Retrieves metadata for all runs of a given job definition.</p>
     fn get_job_runs(
         &self,
         input: GetJobRunsRequest,
     ) -> RusotoFuture<GetJobRunsResponse, GetJobRunsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetJobRuns");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetJobRunsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetJobRunsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_job_runs(input)
     }
-
-    /// <p>Retrieves all current job definitions.</p>
     fn get_jobs(&self, input: GetJobsRequest) -> RusotoFuture<GetJobsResponse, GetJobsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetJobs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetJobsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetJobsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_jobs(input)
     }
-
-    /// <p>Gets details for a specific task run on a machine learning transform. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can check the stats of any task run by calling <code>GetMLTaskRun</code> with the <code>TaskRunID</code> and its parent transform's <code>TransformID</code>.</p>
     fn get_ml_task_run(
         &self,
         input: GetMLTaskRunRequest,
     ) -> RusotoFuture<GetMLTaskRunResponse, GetMLTaskRunError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetMLTaskRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMLTaskRunResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMLTaskRunError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_ml_task_run(input)
     }
-
-    /// <p>Gets a list of runs for a machine learning transform. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can get a sortable, filterable list of machine learning task runs by calling <code>GetMLTaskRuns</code> with their parent transform's <code>TransformID</code> and other optional parameters as documented in this section.</p> <p>This operation returns a list of historic runs and must be paginated.</p>
     fn get_ml_task_runs(
         &self,
         input: GetMLTaskRunsRequest,
     ) -> RusotoFuture<GetMLTaskRunsResponse, GetMLTaskRunsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetMLTaskRuns");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMLTaskRunsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMLTaskRunsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_ml_task_runs(input)
     }
-
-    /// <p>Gets an AWS Glue machine learning transform artifact and all its corresponding metadata. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue. You can retrieve their metadata by calling <code>GetMLTransform</code>.</p>
     fn get_ml_transform(
         &self,
         input: GetMLTransformRequest,
     ) -> RusotoFuture<GetMLTransformResponse, GetMLTransformError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetMLTransform");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMLTransformResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMLTransformError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_ml_transform(input)
     }
-
-    /// <p>Gets a sortable, filterable list of existing AWS Glue machine learning transforms. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue, and you can retrieve their metadata by calling <code>GetMLTransforms</code>.</p>
     fn get_ml_transforms(
         &self,
         input: GetMLTransformsRequest,
     ) -> RusotoFuture<GetMLTransformsResponse, GetMLTransformsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetMLTransforms");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMLTransformsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMLTransformsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_ml_transforms(input)
     }
-
-    /// <p>Creates mappings.</p>
     fn get_mapping(
         &self,
         input: GetMappingRequest,
     ) -> RusotoFuture<GetMappingResponse, GetMappingError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetMapping");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMappingResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMappingError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_mapping(input)
     }
-
-    /// <p>Retrieves information about a specified partition.</p>
     fn get_partition(
         &self,
         input: GetPartitionRequest,
     ) -> RusotoFuture<GetPartitionResponse, GetPartitionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetPartition");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPartitionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPartitionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_partition(input)
     }
-
-    /// <p>Retrieves information about the partitions in a table.</p>
     fn get_partitions(
         &self,
         input: GetPartitionsRequest,
     ) -> RusotoFuture<GetPartitionsResponse, GetPartitionsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetPartitions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPartitionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPartitionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_partitions(input)
     }
-
-    /// <p>Gets code to perform a specified mapping.</p>
     fn get_plan(&self, input: GetPlanRequest) -> RusotoFuture<GetPlanResponse, GetPlanError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetPlan");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetPlanResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPlanError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_plan(input)
     }
-
-    /// <p>Retrieves a specified resource policy.</p>
     fn get_resource_policy(
         &self,
     ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetResourcePolicy");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetResourcePolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetResourcePolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_resource_policy()
     }
-
-    /// <p>Retrieves a specified security configuration.</p>
     fn get_security_configuration(
         &self,
         input: GetSecurityConfigurationRequest,
     ) -> RusotoFuture<GetSecurityConfigurationResponse, GetSecurityConfigurationError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetSecurityConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSecurityConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetSecurityConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_security_configuration(input)
     }
-
-    /// <p>Retrieves a list of all security configurations.</p>
     fn get_security_configurations(
         &self,
         input: GetSecurityConfigurationsRequest,
     ) -> RusotoFuture<GetSecurityConfigurationsResponse, GetSecurityConfigurationsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetSecurityConfigurations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSecurityConfigurationsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetSecurityConfigurationsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_security_configurations(input)
     }
-
-    /// <p>Retrieves the <code>Table</code> definition in a Data Catalog for a specified table.</p>
     fn get_table(&self, input: GetTableRequest) -> RusotoFuture<GetTableResponse, GetTableError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTableResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTableError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_table(input)
     }
-
-    /// <p>Retrieves a specified version of a table.</p>
     fn get_table_version(
         &self,
         input: GetTableVersionRequest,
     ) -> RusotoFuture<GetTableVersionResponse, GetTableVersionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetTableVersion");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTableVersionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTableVersionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_table_version(input)
     }
-
-    /// <p>Retrieves a list of strings that identify available versions of a specified table.</p>
     fn get_table_versions(
         &self,
         input: GetTableVersionsRequest,
     ) -> RusotoFuture<GetTableVersionsResponse, GetTableVersionsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetTableVersions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTableVersionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTableVersionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_table_versions(input)
     }
-
-    /// <p>Retrieves the definitions of some or all of the tables in a given <code>Database</code>.</p>
     fn get_tables(
         &self,
         input: GetTablesRequest,
     ) -> RusotoFuture<GetTablesResponse, GetTablesError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetTables");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTablesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTablesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_tables(input)
     }
-
-    /// <p>Retrieves a list of tags associated with a resource.</p>
     fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_tags(input)
     }
-
-    /// <p>Retrieves the definition of a trigger.</p>
     fn get_trigger(
         &self,
         input: GetTriggerRequest,
     ) -> RusotoFuture<GetTriggerResponse, GetTriggerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetTrigger");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTriggerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTriggerError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_trigger(input)
     }
-
-    /// <p>Gets all the triggers associated with a job.</p>
     fn get_triggers(
         &self,
         input: GetTriggersRequest,
     ) -> RusotoFuture<GetTriggersResponse, GetTriggersError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetTriggers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTriggersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTriggersError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_triggers(input)
     }
-
-    /// <p>Retrieves a specified function definition from the Data Catalog.</p>
     fn get_user_defined_function(
         &self,
         input: GetUserDefinedFunctionRequest,
     ) -> RusotoFuture<GetUserDefinedFunctionResponse, GetUserDefinedFunctionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunction");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetUserDefinedFunctionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetUserDefinedFunctionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_user_defined_function(input)
     }
-
-    /// <p>Retrieves multiple function definitions from the Data Catalog.</p>
     fn get_user_defined_functions(
         &self,
         input: GetUserDefinedFunctionsRequest,
     ) -> RusotoFuture<GetUserDefinedFunctionsResponse, GetUserDefinedFunctionsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunctions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetUserDefinedFunctionsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetUserDefinedFunctionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_user_defined_functions(input)
     }
-
-    /// <p>Retrieves resource metadata for a workflow.</p>
     fn get_workflow(
         &self,
         input: GetWorkflowRequest,
     ) -> RusotoFuture<GetWorkflowResponse, GetWorkflowError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetWorkflow");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetWorkflowResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetWorkflowError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_workflow(input)
     }
-
-    /// <p>Retrieves the metadata for a given workflow run. </p>
     fn get_workflow_run(
         &self,
         input: GetWorkflowRunRequest,
     ) -> RusotoFuture<GetWorkflowRunResponse, GetWorkflowRunError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetWorkflowRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetWorkflowRunResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetWorkflowRunError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_workflow_run(input)
     }
-
-    /// <p>Retrieves the workflow run properties which were set during the run.</p>
     fn get_workflow_run_properties(
         &self,
         input: GetWorkflowRunPropertiesRequest,
     ) -> RusotoFuture<GetWorkflowRunPropertiesResponse, GetWorkflowRunPropertiesError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetWorkflowRunProperties");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetWorkflowRunPropertiesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetWorkflowRunPropertiesError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_workflow_run_properties(input)
     }
-
-    /// <p>Retrieves metadata for all runs of a given workflow.</p>
     fn get_workflow_runs(
         &self,
         input: GetWorkflowRunsRequest,
     ) -> RusotoFuture<GetWorkflowRunsResponse, GetWorkflowRunsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetWorkflowRuns");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetWorkflowRunsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetWorkflowRunsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_workflow_runs(input)
     }
-
-    /// <p>Imports an existing Amazon Athena Data Catalog to AWS Glue</p>
     fn import_catalog_to_glue(
         &self,
         input: ImportCatalogToGlueRequest,
     ) -> RusotoFuture<ImportCatalogToGlueResponse, ImportCatalogToGlueError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.ImportCatalogToGlue");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportCatalogToGlueResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ImportCatalogToGlueError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).import_catalog_to_glue(input)
     }
-
-    /// <p>Retrieves the names of all crawler resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
     fn list_crawlers(
         &self,
         input: ListCrawlersRequest,
     ) -> RusotoFuture<ListCrawlersResponse, ListCrawlersError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.ListCrawlers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCrawlersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListCrawlersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_crawlers(input)
     }
-
-    /// <p>Retrieves the names of all <code>DevEndpoint</code> resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
     fn list_dev_endpoints(
         &self,
         input: ListDevEndpointsRequest,
     ) -> RusotoFuture<ListDevEndpointsResponse, ListDevEndpointsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.ListDevEndpoints");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDevEndpointsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDevEndpointsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_dev_endpoints(input)
     }
-
-    /// <p>Retrieves the names of all job resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
     fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.ListJobs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListJobsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListJobsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_jobs(input)
     }
-
-    /// <p>Retrieves the names of all trigger resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
     fn list_triggers(
         &self,
         input: ListTriggersRequest,
     ) -> RusotoFuture<ListTriggersResponse, ListTriggersError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.ListTriggers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTriggersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTriggersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_triggers(input)
+    }
+    fn list_workflows(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> RusotoFuture<ListWorkflowsResponse, ListWorkflowsError> {
+        (**self).list_workflows(input)
+    }
+    fn put_data_catalog_encryption_settings(
+        &self,
+        input: PutDataCatalogEncryptionSettingsRequest,
+    ) -> RusotoFuture<PutDataCatalogEncryptionSettingsResponse, PutDataCatalogEncryptionSettingsError>
+    {
+        (**self).put_data_catalog_encryption_settings(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_workflow_run_properties(
+        &self,
+        input: PutWorkflowRunPropertiesRequest,
+    ) -> RusotoFuture<PutWorkflowRunPropertiesResponse, PutWorkflowRunPropertiesError> {
+        (**self).put_workflow_run_properties(input)
+    }
+    fn reset_job_bookmark(
+        &self,
+        input: ResetJobBookmarkRequest,
+    ) -> RusotoFuture<ResetJobBookmarkResponse, ResetJobBookmarkError> {
+        (**self).reset_job_bookmark(input)
+    }
+    fn search_tables(
+        &self,
+        input: SearchTablesRequest,
+    ) -> RusotoFuture<SearchTablesResponse, SearchTablesError> {
+        (**self).search_tables(input)
+    }
+    fn start_crawler(
+        &self,
+        input: StartCrawlerRequest,
+    ) -> RusotoFuture<StartCrawlerResponse, StartCrawlerError> {
+        (**self).start_crawler(input)
+    }
+    fn start_crawler_schedule(
+        &self,
+        input: StartCrawlerScheduleRequest,
+    ) -> RusotoFuture<StartCrawlerScheduleResponse, StartCrawlerScheduleError> {
+        (**self).start_crawler_schedule(input)
+    }
+    fn start_export_labels_task_run(
+        &self,
+        input: StartExportLabelsTaskRunRequest,
+    ) -> RusotoFuture<StartExportLabelsTaskRunResponse, StartExportLabelsTaskRunError> {
+        (**self).start_export_labels_task_run(input)
+    }
+    fn start_import_labels_task_run(
+        &self,
+        input: StartImportLabelsTaskRunRequest,
+    ) -> RusotoFuture<StartImportLabelsTaskRunResponse, StartImportLabelsTaskRunError> {
+        (**self).start_import_labels_task_run(input)
+    }
+    fn start_job_run(
+        &self,
+        input: StartJobRunRequest,
+    ) -> RusotoFuture<StartJobRunResponse, StartJobRunError> {
+        (**self).start_job_run(input)
+    }
+    fn start_ml_evaluation_task_run(
+        &self,
+        input: StartMLEvaluationTaskRunRequest,
+    ) -> RusotoFuture<StartMLEvaluationTaskRunResponse, StartMLEvaluationTaskRunError> {
+        (**self).start_ml_evaluation_task_run(input)
+    }
+    fn start_ml_labeling_set_generation_task_run(
+        &self,
+        input: StartMLLabelingSetGenerationTaskRunRequest,
+    ) -> RusotoFuture<
+        StartMLLabelingSetGenerationTaskRunResponse,
+        StartMLLabelingSetGenerationTaskRunError,
+    > {
+        (**self).start_ml_labeling_set_generation_task_run(input)
+    }
+    fn start_trigger(
+        &self,
+        input: StartTriggerRequest,
+    ) -> RusotoFuture<StartTriggerResponse, StartTriggerError> {
+        (**self).start_trigger(input)
+    }
+    fn start_workflow_run(
+        &self,
+        input: StartWorkflowRunRequest,
+    ) -> RusotoFuture<StartWorkflowRunResponse, StartWorkflowRunError> {
+        (**self).start_workflow_run(input)
+    }
+    fn stop_crawler(
+        &self,
+        input: StopCrawlerRequest,
+    ) -> RusotoFuture<StopCrawlerResponse, StopCrawlerError> {
+        (**self).stop_crawler(input)
+    }
+    fn stop_crawler_schedule(
+        &self,
+        input: StopCrawlerScheduleRequest,
+    ) -> RusotoFuture<StopCrawlerScheduleResponse, StopCrawlerScheduleError> {
+        (**self).stop_crawler_schedule(input)
+    }
+    fn stop_trigger(
+        &self,
+        input: StopTriggerRequest,
+    ) -> RusotoFuture<StopTriggerResponse, StopTriggerError> {
+        (**self).stop_trigger(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_classifier(
+        &self,
+        input: UpdateClassifierRequest,
+    ) -> RusotoFuture<UpdateClassifierResponse, UpdateClassifierError> {
+        (**self).update_classifier(input)
+    }
+    fn update_connection(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> RusotoFuture<UpdateConnectionResponse, UpdateConnectionError> {
+        (**self).update_connection(input)
+    }
+    fn update_crawler(
+        &self,
+        input: UpdateCrawlerRequest,
+    ) -> RusotoFuture<UpdateCrawlerResponse, UpdateCrawlerError> {
+        (**self).update_crawler(input)
+    }
+    fn update_crawler_schedule(
+        &self,
+        input: UpdateCrawlerScheduleRequest,
+    ) -> RusotoFuture<UpdateCrawlerScheduleResponse, UpdateCrawlerScheduleError> {
+        (**self).update_crawler_schedule(input)
+    }
+    fn update_database(
+        &self,
+        input: UpdateDatabaseRequest,
+    ) -> RusotoFuture<UpdateDatabaseResponse, UpdateDatabaseError> {
+        (**self).update_database(input)
+    }
+    fn update_dev_endpoint(
+        &self,
+        input: UpdateDevEndpointRequest,
+    ) -> RusotoFuture<UpdateDevEndpointResponse, UpdateDevEndpointError> {
+        (**self).update_dev_endpoint(input)
+    }
+    fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> RusotoFuture<UpdateJobResponse, UpdateJobError> {
+        (**self).update_job(input)
+    }
+    fn update_ml_transform(
+        &self,
+        input: UpdateMLTransformRequest,
+    ) -> RusotoFuture<UpdateMLTransformResponse, UpdateMLTransformError> {
+        (**self).update_ml_transform(input)
+    }
+    fn update_partition(
+        &self,
+        input: UpdatePartitionRequest,
+    ) -> RusotoFuture<UpdatePartitionResponse, UpdatePartitionError> {
+        (**self).update_partition(input)
+    }
+    fn update_table(
+        &self,
+        input: UpdateTableRequest,
+    ) -> RusotoFuture<UpdateTableResponse, UpdateTableError> {
+        (**self).update_table(input)
+    }
+    fn update_trigger(
+        &self,
+        input: UpdateTriggerRequest,
+    ) -> RusotoFuture<UpdateTriggerResponse, UpdateTriggerError> {
+        (**self).update_trigger(input)
+    }
+    fn update_user_defined_function(
+        &self,
+        input: UpdateUserDefinedFunctionRequest,
+    ) -> RusotoFuture<UpdateUserDefinedFunctionResponse, UpdateUserDefinedFunctionError> {
+        (**self).update_user_defined_function(input)
+    }
+    fn update_workflow(
+        &self,
+        input: UpdateWorkflowRequest,
+    ) -> RusotoFuture<UpdateWorkflowResponse, UpdateWorkflowError> {
+        (**self).update_workflow(input)
+    }
+}
+impl<T: Glue + ?Sized> Glue for Box<T> {
+    fn batch_create_partition(
+        &self,
+        input: BatchCreatePartitionRequest,
+    ) -> RusotoFuture<BatchCreatePartitionResponse, BatchCreatePartitionError> {
+        (**self).batch_create_partition(input)
+    }
+    fn batch_delete_connection(
+        &self,
+        input: BatchDeleteConnectionRequest,
+    ) -> RusotoFuture<BatchDeleteConnectionResponse, BatchDeleteConnectionError> {
+        (**self).batch_delete_connection(input)
+    }
+    fn batch_delete_partition(
+        &self,
+        input: BatchDeletePartitionRequest,
+    ) -> RusotoFuture<BatchDeletePartitionResponse, BatchDeletePartitionError> {
+        (**self).batch_delete_partition(input)
+    }
+    fn batch_delete_table(
+        &self,
+        input: BatchDeleteTableRequest,
+    ) -> RusotoFuture<BatchDeleteTableResponse, BatchDeleteTableError> {
+        (**self).batch_delete_table(input)
+    }
+    fn batch_delete_table_version(
+        &self,
+        input: BatchDeleteTableVersionRequest,
+    ) -> RusotoFuture<BatchDeleteTableVersionResponse, BatchDeleteTableVersionError> {
+        (**self).batch_delete_table_version(input)
+    }
+    fn batch_get_crawlers(
+        &self,
+        input: BatchGetCrawlersRequest,
+    ) -> RusotoFuture<BatchGetCrawlersResponse, BatchGetCrawlersError> {
+        (**self).batch_get_crawlers(input)
+    }
+    fn batch_get_dev_endpoints(
+        &self,
+        input: BatchGetDevEndpointsRequest,
+    ) -> RusotoFuture<BatchGetDevEndpointsResponse, BatchGetDevEndpointsError> {
+        (**self).batch_get_dev_endpoints(input)
+    }
+    fn batch_get_jobs(
+        &self,
+        input: BatchGetJobsRequest,
+    ) -> RusotoFuture<BatchGetJobsResponse, BatchGetJobsError> {
+        (**self).batch_get_jobs(input)
+    }
+    fn batch_get_partition(
+        &self,
+        input: BatchGetPartitionRequest,
+    ) -> RusotoFuture<BatchGetPartitionResponse, BatchGetPartitionError> {
+        (**self).batch_get_partition(input)
+    }
+    fn batch_get_triggers(
+        &self,
+        input: BatchGetTriggersRequest,
+    ) -> RusotoFuture<BatchGetTriggersResponse, BatchGetTriggersError> {
+        (**self).batch_get_triggers(input)
+    }
+    fn batch_get_workflows(
+        &self,
+        input: BatchGetWorkflowsRequest,
+    ) -> RusotoFuture<BatchGetWorkflowsResponse, BatchGetWorkflowsError> {
+        (**self).batch_get_workflows(input)
+    }
+    fn batch_stop_job_run(
+        &self,
+        input: BatchStopJobRunRequest,
+    ) -> RusotoFuture<BatchStopJobRunResponse, GlueBatchStopJobRunError> {
+        (**self).batch_stop_job_run(input)
+    }
+    fn cancel_ml_task_run(
+        &self,
+        input: CancelMLTaskRunRequest,
+    ) -> RusotoFuture<CancelMLTaskRunResponse, CancelMLTaskRunError> {
+        (**self).cancel_ml_task_run(input)
+    }
+    fn create_classifier(
+        &self,
+        input: CreateClassifierRequest,
+    ) -> RusotoFuture<CreateClassifierResponse, CreateClassifierError> {
+        (**self).create_classifier(input)
+    }
+    fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> RusotoFuture<CreateConnectionResponse, CreateConnectionError> {
+        (**self).create_connection(input)
+    }
+    fn create_crawler(
+        &self,
+        input: CreateCrawlerRequest,
+    ) -> RusotoFuture<CreateCrawlerResponse, CreateCrawlerError> {
+        (**self).create_crawler(input)
+    }
+    fn create_database(
+        &self,
+        input: CreateDatabaseRequest,
+    ) -> RusotoFuture<CreateDatabaseResponse, CreateDatabaseError> {
+        (**self).create_database(input)
+    }
+    fn create_dev_endpoint(
+        &self,
+        input: CreateDevEndpointRequest,
+    ) -> RusotoFuture<CreateDevEndpointResponse, CreateDevEndpointError> {
+        (**self).create_dev_endpoint(input)
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn create_ml_transform(
+        &self,
+        input: CreateMLTransformRequest,
+    ) -> RusotoFuture<CreateMLTransformResponse, CreateMLTransformError> {
+        (**self).create_ml_transform(input)
+    }
+    fn create_partition(
+        &self,
+        input: CreatePartitionRequest,
+    ) -> RusotoFuture<CreatePartitionResponse, CreatePartitionError> {
+        (**self).create_partition(input)
+    }
+    fn create_script(
+        &self,
+        input: CreateScriptRequest,
+    ) -> RusotoFuture<CreateScriptResponse, CreateScriptError> {
+        (**self).create_script(input)
+    }
+    fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationRequest,
+    ) -> RusotoFuture<CreateSecurityConfigurationResponse, CreateSecurityConfigurationError> {
+        (**self).create_security_configuration(input)
+    }
+    fn create_table(
+        &self,
+        input: CreateTableRequest,
+    ) -> RusotoFuture<CreateTableResponse, CreateTableError> {
+        (**self).create_table(input)
+    }
+    fn create_trigger(
+        &self,
+        input: CreateTriggerRequest,
+    ) -> RusotoFuture<CreateTriggerResponse, CreateTriggerError> {
+        (**self).create_trigger(input)
+    }
+    fn create_user_defined_function(
+        &self,
+        input: CreateUserDefinedFunctionRequest,
+    ) -> RusotoFuture<CreateUserDefinedFunctionResponse, CreateUserDefinedFunctionError> {
+        (**self).create_user_defined_function(input)
+    }
+    fn create_workflow(
+        &self,
+        input: CreateWorkflowRequest,
+    ) -> RusotoFuture<CreateWorkflowResponse, CreateWorkflowError> {
+        (**self).create_workflow(input)
+    }
+    fn delete_classifier(
+        &self,
+        input: DeleteClassifierRequest,
+    ) -> RusotoFuture<DeleteClassifierResponse, DeleteClassifierError> {
+        (**self).delete_classifier(input)
+    }
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<DeleteConnectionResponse, DeleteConnectionError> {
+        (**self).delete_connection(input)
+    }
+    fn delete_crawler(
+        &self,
+        input: DeleteCrawlerRequest,
+    ) -> RusotoFuture<DeleteCrawlerResponse, DeleteCrawlerError> {
+        (**self).delete_crawler(input)
+    }
+    fn delete_database(
+        &self,
+        input: DeleteDatabaseRequest,
+    ) -> RusotoFuture<DeleteDatabaseResponse, DeleteDatabaseError> {
+        (**self).delete_database(input)
+    }
+    fn delete_dev_endpoint(
+        &self,
+        input: DeleteDevEndpointRequest,
+    ) -> RusotoFuture<DeleteDevEndpointResponse, DeleteDevEndpointError> {
+        (**self).delete_dev_endpoint(input)
+    }
+    fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> RusotoFuture<DeleteJobResponse, DeleteJobError> {
+        (**self).delete_job(input)
+    }
+    fn delete_ml_transform(
+        &self,
+        input: DeleteMLTransformRequest,
+    ) -> RusotoFuture<DeleteMLTransformResponse, DeleteMLTransformError> {
+        (**self).delete_ml_transform(input)
+    }
+    fn delete_partition(
+        &self,
+        input: DeletePartitionRequest,
+    ) -> RusotoFuture<DeletePartitionResponse, DeletePartitionError> {
+        (**self).delete_partition(input)
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
+        (**self).delete_resource_policy(input)
+    }
+    fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationRequest,
+    ) -> RusotoFuture<DeleteSecurityConfigurationResponse, DeleteSecurityConfigurationError> {
+        (**self).delete_security_configuration(input)
+    }
+    fn delete_table(
+        &self,
+        input: DeleteTableRequest,
+    ) -> RusotoFuture<DeleteTableResponse, DeleteTableError> {
+        (**self).delete_table(input)
+    }
+    fn delete_table_version(
+        &self,
+        input: DeleteTableVersionRequest,
+    ) -> RusotoFuture<DeleteTableVersionResponse, DeleteTableVersionError> {
+        (**self).delete_table_version(input)
+    }
+    fn delete_trigger(
+        &self,
+        input: DeleteTriggerRequest,
+    ) -> RusotoFuture<DeleteTriggerResponse, DeleteTriggerError> {
+        (**self).delete_trigger(input)
+    }
+    fn delete_user_defined_function(
+        &self,
+        input: DeleteUserDefinedFunctionRequest,
+    ) -> RusotoFuture<DeleteUserDefinedFunctionResponse, DeleteUserDefinedFunctionError> {
+        (**self).delete_user_defined_function(input)
+    }
+    fn delete_workflow(
+        &self,
+        input: DeleteWorkflowRequest,
+    ) -> RusotoFuture<DeleteWorkflowResponse, DeleteWorkflowError> {
+        (**self).delete_workflow(input)
+    }
+    fn get_catalog_import_status(
+        &self,
+        input: GetCatalogImportStatusRequest,
+    ) -> RusotoFuture<GetCatalogImportStatusResponse, GetCatalogImportStatusError> {
+        (**self).get_catalog_import_status(input)
+    }
+    fn get_classifier(
+        &self,
+        input: GetClassifierRequest,
+    ) -> RusotoFuture<GetClassifierResponse, GetClassifierError> {
+        (**self).get_classifier(input)
+    }
+    fn get_classifiers(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> RusotoFuture<GetClassifiersResponse, GetClassifiersError> {
+        (**self).get_classifiers(input)
+    }
+    fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
+        (**self).get_connection(input)
+    }
+    fn get_connections(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> RusotoFuture<GetConnectionsResponse, GetConnectionsError> {
+        (**self).get_connections(input)
+    }
+    fn get_crawler(
+        &self,
+        input: GetCrawlerRequest,
+    ) -> RusotoFuture<GetCrawlerResponse, GetCrawlerError> {
+        (**self).get_crawler(input)
+    }
+    fn get_crawler_metrics(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> RusotoFuture<GetCrawlerMetricsResponse, GetCrawlerMetricsError> {
+        (**self).get_crawler_metrics(input)
+    }
+    fn get_crawlers(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> RusotoFuture<GetCrawlersResponse, GetCrawlersError> {
+        (**self).get_crawlers(input)
+    }
+    fn get_data_catalog_encryption_settings(
+        &self,
+        input: GetDataCatalogEncryptionSettingsRequest,
+    ) -> RusotoFuture<GetDataCatalogEncryptionSettingsResponse, GetDataCatalogEncryptionSettingsError>
+    {
+        (**self).get_data_catalog_encryption_settings(input)
+    }
+    fn get_database(
+        &self,
+        input: GetDatabaseRequest,
+    ) -> RusotoFuture<GetDatabaseResponse, GetDatabaseError> {
+        (**self).get_database(input)
+    }
+    fn get_databases(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> RusotoFuture<GetDatabasesResponse, GetDatabasesError> {
+        (**self).get_databases(input)
+    }
+    fn get_dataflow_graph(
+        &self,
+        input: GetDataflowGraphRequest,
+    ) -> RusotoFuture<GetDataflowGraphResponse, GetDataflowGraphError> {
+        (**self).get_dataflow_graph(input)
+    }
+    fn get_dev_endpoint(
+        &self,
+        input: GetDevEndpointRequest,
+    ) -> RusotoFuture<GetDevEndpointResponse, GetDevEndpointError> {
+        (**self).get_dev_endpoint(input)
+    }
+    fn get_dev_endpoints(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> RusotoFuture<GetDevEndpointsResponse, GetDevEndpointsError> {
+        (**self).get_dev_endpoints(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResponse, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_job_bookmark(
+        &self,
+        input: GetJobBookmarkRequest,
+    ) -> RusotoFuture<GetJobBookmarkResponse, GetJobBookmarkError> {
+        (**self).get_job_bookmark(input)
+    }
+    fn get_job_run(
+        &self,
+        input: GetJobRunRequest,
+    ) -> RusotoFuture<GetJobRunResponse, GetJobRunError> {
+        (**self).get_job_run(input)
+    }
+    fn get_job_runs(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> RusotoFuture<GetJobRunsResponse, GetJobRunsError> {
+        (**self).get_job_runs(input)
+    }
+    fn get_jobs(&self, input: GetJobsRequest) -> RusotoFuture<GetJobsResponse, GetJobsError> {
+        (**self).get_jobs(input)
+    }
+    fn get_ml_task_run(
+        &self,
+        input: GetMLTaskRunRequest,
+    ) -> RusotoFuture<GetMLTaskRunResponse, GetMLTaskRunError> {
+        (**self).get_ml_task_run(input)
+    }
+    fn get_ml_task_runs(
+        &self,
+        input: GetMLTaskRunsRequest,
+    ) -> RusotoFuture<GetMLTaskRunsResponse, GetMLTaskRunsError> {
+        (**self).get_ml_task_runs(input)
+    }
+    fn get_ml_transform(
+        &self,
+        input: GetMLTransformRequest,
+    ) -> RusotoFuture<GetMLTransformResponse, GetMLTransformError> {
+        (**self).get_ml_transform(input)
+    }
+    fn get_ml_transforms(
+        &self,
+        input: GetMLTransformsRequest,
+    ) -> RusotoFuture<GetMLTransformsResponse, GetMLTransformsError> {
+        (**self).get_ml_transforms(input)
+    }
+    fn get_mapping(
+        &self,
+        input: GetMappingRequest,
+    ) -> RusotoFuture<GetMappingResponse, GetMappingError> {
+        (**self).get_mapping(input)
+    }
+    fn get_partition(
+        &self,
+        input: GetPartitionRequest,
+    ) -> RusotoFuture<GetPartitionResponse, GetPartitionError> {
+        (**self).get_partition(input)
+    }
+    fn get_partitions(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> RusotoFuture<GetPartitionsResponse, GetPartitionsError> {
+        (**self).get_partitions(input)
+    }
+    fn get_plan(&self, input: GetPlanRequest) -> RusotoFuture<GetPlanResponse, GetPlanError> {
+        (**self).get_plan(input)
+    }
+    fn get_resource_policy(
+        &self,
+    ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        (**self).get_resource_policy()
+    }
+    fn get_security_configuration(
+        &self,
+        input: GetSecurityConfigurationRequest,
+    ) -> RusotoFuture<GetSecurityConfigurationResponse, GetSecurityConfigurationError> {
+        (**self).get_security_configuration(input)
+    }
+    fn get_security_configurations(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> RusotoFuture<GetSecurityConfigurationsResponse, GetSecurityConfigurationsError> {
+        (**self).get_security_configurations(input)
+    }
+    fn get_table(&self, input: GetTableRequest) -> RusotoFuture<GetTableResponse, GetTableError> {
+        (**self).get_table(input)
+    }
+    fn get_table_version(
+        &self,
+        input: GetTableVersionRequest,
+    ) -> RusotoFuture<GetTableVersionResponse, GetTableVersionError> {
+        (**self).get_table_version(input)
+    }
+    fn get_table_versions(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> RusotoFuture<GetTableVersionsResponse, GetTableVersionsError> {
+        (**self).get_table_versions(input)
+    }
+    fn get_tables(
+        &self,
+        input: GetTablesRequest,
+    ) -> RusotoFuture<GetTablesResponse, GetTablesError> {
+        (**self).get_tables(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn get_trigger(
+        &self,
+        input: GetTriggerRequest,
+    ) -> RusotoFuture<GetTriggerResponse, GetTriggerError> {
+        (**self).get_trigger(input)
+    }
+    fn get_triggers(
+        &self,
+        input: GetTriggersRequest,
+    ) -> RusotoFuture<GetTriggersResponse, GetTriggersError> {
+        (**self).get_triggers(input)
+    }
+    fn get_user_defined_function(
+        &self,
+        input: GetUserDefinedFunctionRequest,
+    ) -> RusotoFuture<GetUserDefinedFunctionResponse, GetUserDefinedFunctionError> {
+        (**self).get_user_defined_function(input)
+    }
+    fn get_user_defined_functions(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> RusotoFuture<GetUserDefinedFunctionsResponse, GetUserDefinedFunctionsError> {
+        (**self).get_user_defined_functions(input)
+    }
+    fn get_workflow(
+        &self,
+        input: GetWorkflowRequest,
+    ) -> RusotoFuture<GetWorkflowResponse, GetWorkflowError> {
+        (**self).get_workflow(input)
+    }
+    fn get_workflow_run(
+        &self,
+        input: GetWorkflowRunRequest,
+    ) -> RusotoFuture<GetWorkflowRunResponse, GetWorkflowRunError> {
+        (**self).get_workflow_run(input)
+    }
+    fn get_workflow_run_properties(
+        &self,
+        input: GetWorkflowRunPropertiesRequest,
+    ) -> RusotoFuture<GetWorkflowRunPropertiesResponse, GetWorkflowRunPropertiesError> {
+        (**self).get_workflow_run_properties(input)
+    }
+    fn get_workflow_runs(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> RusotoFuture<GetWorkflowRunsResponse, GetWorkflowRunsError> {
+        (**self).get_workflow_runs(input)
+    }
+    fn import_catalog_to_glue(
+        &self,
+        input: ImportCatalogToGlueRequest,
+    ) -> RusotoFuture<ImportCatalogToGlueResponse, ImportCatalogToGlueError> {
+        (**self).import_catalog_to_glue(input)
+    }
+    fn list_crawlers(
+        &self,
+        input: ListCrawlersRequest,
+    ) -> RusotoFuture<ListCrawlersResponse, ListCrawlersError> {
+        (**self).list_crawlers(input)
+    }
+    fn list_dev_endpoints(
+        &self,
+        input: ListDevEndpointsRequest,
+    ) -> RusotoFuture<ListDevEndpointsResponse, ListDevEndpointsError> {
+        (**self).list_dev_endpoints(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_triggers(
+        &self,
+        input: ListTriggersRequest,
+    ) -> RusotoFuture<ListTriggersResponse, ListTriggersError> {
+        (**self).list_triggers(input)
+    }
+    fn list_workflows(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> RusotoFuture<ListWorkflowsResponse, ListWorkflowsError> {
+        (**self).list_workflows(input)
+    }
+    fn put_data_catalog_encryption_settings(
+        &self,
+        input: PutDataCatalogEncryptionSettingsRequest,
+    ) -> RusotoFuture<PutDataCatalogEncryptionSettingsResponse, PutDataCatalogEncryptionSettingsError>
+    {
+        (**self).put_data_catalog_encryption_settings(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_workflow_run_properties(
+        &self,
+        input: PutWorkflowRunPropertiesRequest,
+    ) -> RusotoFuture<PutWorkflowRunPropertiesResponse, PutWorkflowRunPropertiesError> {
+        (**self).put_workflow_run_properties(input)
+    }
+    fn reset_job_bookmark(
+        &self,
+        input: ResetJobBookmarkRequest,
+    ) -> RusotoFuture<ResetJobBookmarkResponse, ResetJobBookmarkError> {
+        (**self).reset_job_bookmark(input)
+    }
+    fn search_tables(
+        &self,
+        input: SearchTablesRequest,
+    ) -> RusotoFuture<SearchTablesResponse, SearchTablesError> {
+        (**self).search_tables(input)
+    }
+    fn start_crawler(
+        &self,
+        input: StartCrawlerRequest,
+    ) -> RusotoFuture<StartCrawlerResponse, StartCrawlerError> {
+        (**self).start_crawler(input)
+    }
+    fn start_crawler_schedule(
+        &self,
+        input: StartCrawlerScheduleRequest,
+    ) -> RusotoFuture<StartCrawlerScheduleResponse, StartCrawlerScheduleError> {
+        (**self).start_crawler_schedule(input)
+    }
+    fn start_export_labels_task_run(
+        &self,
+        input: StartExportLabelsTaskRunRequest,
+    ) -> RusotoFuture<StartExportLabelsTaskRunResponse, StartExportLabelsTaskRunError> {
+        (**self).start_export_labels_task_run(input)
+    }
+    fn start_import_labels_task_run(
+        &self,
+        input: StartImportLabelsTaskRunRequest,
+    ) -> RusotoFuture<StartImportLabelsTaskRunResponse, StartImportLabelsTaskRunError> {
+        (**self).start_import_labels_task_run(input)
+    }
+    fn start_job_run(
+        &self,
+        input: StartJobRunRequest,
+    ) -> RusotoFuture<StartJobRunResponse, StartJobRunError> {
+        (**self).start_job_run(input)
+    }
+    fn start_ml_evaluation_task_run(
+        &self,
+        input: StartMLEvaluationTaskRunRequest,
+    ) -> RusotoFuture<StartMLEvaluationTaskRunResponse, StartMLEvaluationTaskRunError> {
+        (**self).start_ml_evaluation_task_run(input)
+    }
+    fn start_ml_labeling_set_generation_task_run(
+        &self,
+        input: StartMLLabelingSetGenerationTaskRunRequest,
+    ) -> RusotoFuture<
+        StartMLLabelingSetGenerationTaskRunResponse,
+        StartMLLabelingSetGenerationTaskRunError,
+    > {
+        (**self).start_ml_labeling_set_generation_task_run(input)
+    }
+    fn start_trigger(
+        &self,
+        input: StartTriggerRequest,
+    ) -> RusotoFuture<StartTriggerResponse, StartTriggerError> {
+        (**self).start_trigger(input)
+    }
+    fn start_workflow_run(
+        &self,
+        input: StartWorkflowRunRequest,
+    ) -> RusotoFuture<StartWorkflowRunResponse, StartWorkflowRunError> {
+        (**self).start_workflow_run(input)
+    }
+    fn stop_crawler(
+        &self,
+        input: StopCrawlerRequest,
+    ) -> RusotoFuture<StopCrawlerResponse, StopCrawlerError> {
+        (**self).stop_crawler(input)
+    }
+    fn stop_crawler_schedule(
+        &self,
+        input: StopCrawlerScheduleRequest,
+    ) -> RusotoFuture<StopCrawlerScheduleResponse, StopCrawlerScheduleError> {
+        (**self).stop_crawler_schedule(input)
+    }
+    fn stop_trigger(
+        &self,
+        input: StopTriggerRequest,
+    ) -> RusotoFuture<StopTriggerResponse, StopTriggerError> {
+        (**self).stop_trigger(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_classifier(
+        &self,
+        input: UpdateClassifierRequest,
+    ) -> RusotoFuture<UpdateClassifierResponse, UpdateClassifierError> {
+        (**self).update_classifier(input)
+    }
+    fn update_connection(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> RusotoFuture<UpdateConnectionResponse, UpdateConnectionError> {
+        (**self).update_connection(input)
+    }
+    fn update_crawler(
+        &self,
+        input: UpdateCrawlerRequest,
+    ) -> RusotoFuture<UpdateCrawlerResponse, UpdateCrawlerError> {
+        (**self).update_crawler(input)
+    }
+    fn update_crawler_schedule(
+        &self,
+        input: UpdateCrawlerScheduleRequest,
+    ) -> RusotoFuture<UpdateCrawlerScheduleResponse, UpdateCrawlerScheduleError> {
+        (**self).update_crawler_schedule(input)
+    }
+    fn update_database(
+        &self,
+        input: UpdateDatabaseRequest,
+    ) -> RusotoFuture<UpdateDatabaseResponse, UpdateDatabaseError> {
+        (**self).update_database(input)
+    }
+    fn update_dev_endpoint(
+        &self,
+        input: UpdateDevEndpointRequest,
+    ) -> RusotoFuture<UpdateDevEndpointResponse, UpdateDevEndpointError> {
+        (**self).update_dev_endpoint(input)
+    }
+    fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> RusotoFuture<UpdateJobResponse, UpdateJobError> {
+        (**self).update_job(input)
+    }
+    fn update_ml_transform(
+        &self,
+        input: UpdateMLTransformRequest,
+    ) -> RusotoFuture<UpdateMLTransformResponse, UpdateMLTransformError> {
+        (**self).update_ml_transform(input)
+    }
+    fn update_partition(
+        &self,
+        input: UpdatePartitionRequest,
+    ) -> RusotoFuture<UpdatePartitionResponse, UpdatePartitionError> {
+        (**self).update_partition(input)
+    }
+    fn update_table(
+        &self,
+        input: UpdateTableRequest,
+    ) -> RusotoFuture<UpdateTableResponse, UpdateTableError> {
+        (**self).update_table(input)
+    }
+    fn update_trigger(
+        &self,
+        input: UpdateTriggerRequest,
+    ) -> RusotoFuture<UpdateTriggerResponse, UpdateTriggerError> {
+        (**self).update_trigger(input)
+    }
+    fn update_user_defined_function(
+        &self,
+        input: UpdateUserDefinedFunctionRequest,
+    ) -> RusotoFuture<UpdateUserDefinedFunctionResponse, UpdateUserDefinedFunctionError> {
+        (**self).update_user_defined_function(input)
+    }
+    fn update_workflow(
+        &self,
+        input: UpdateWorkflowRequest,
+    ) -> RusotoFuture<UpdateWorkflowResponse, UpdateWorkflowError> {
+        (**self).update_workflow(input)
+    }
+}
+impl<T: Glue + ?Sized> Glue for ::std::sync::Arc<T> {
+    fn batch_create_partition(
+        &self,
+        input: BatchCreatePartitionRequest,
+    ) -> RusotoFuture<BatchCreatePartitionResponse, BatchCreatePartitionError> {
+        (**self).batch_create_partition(input)
+    }
+    fn batch_delete_connection(
+        &self,
+        input: BatchDeleteConnectionRequest,
+    ) -> RusotoFuture<BatchDeleteConnectionResponse, BatchDeleteConnectionError> {
+        (**self).batch_delete_connection(input)
+    }
+    fn batch_delete_partition(
+        &self,
+        input: BatchDeletePartitionRequest,
+    ) -> RusotoFuture<BatchDeletePartitionResponse, BatchDeletePartitionError> {
+        (**self).batch_delete_partition(input)
+    }
+    fn batch_delete_table(
+        &self,
+        input: BatchDeleteTableRequest,
+    ) -> RusotoFuture<BatchDeleteTableResponse, BatchDeleteTableError> {
+        (**self).batch_delete_table(input)
+    }
+    fn batch_delete_table_version(
+        &self,
+        input: BatchDeleteTableVersionRequest,
+    ) -> RusotoFuture<BatchDeleteTableVersionResponse, BatchDeleteTableVersionError> {
+        (**self).batch_delete_table_version(input)
+    }
+    fn batch_get_crawlers(
+        &self,
+        input: BatchGetCrawlersRequest,
+    ) -> RusotoFuture<BatchGetCrawlersResponse, BatchGetCrawlersError> {
+        (**self).batch_get_crawlers(input)
+    }
+    fn batch_get_dev_endpoints(
+        &self,
+        input: BatchGetDevEndpointsRequest,
+    ) -> RusotoFuture<BatchGetDevEndpointsResponse, BatchGetDevEndpointsError> {
+        (**self).batch_get_dev_endpoints(input)
+    }
+    fn batch_get_jobs(
+        &self,
+        input: BatchGetJobsRequest,
+    ) -> RusotoFuture<BatchGetJobsResponse, BatchGetJobsError> {
+        (**self).batch_get_jobs(input)
+    }
+    fn batch_get_partition(
+        &self,
+        input: BatchGetPartitionRequest,
+    ) -> RusotoFuture<BatchGetPartitionResponse, BatchGetPartitionError> {
+        (**self).batch_get_partition(input)
+    }
+    fn batch_get_triggers(
+        &self,
+        input: BatchGetTriggersRequest,
+    ) -> RusotoFuture<BatchGetTriggersResponse, BatchGetTriggersError> {
+        (**self).batch_get_triggers(input)
+    }
+    fn batch_get_workflows(
+        &self,
+        input: BatchGetWorkflowsRequest,
+    ) -> RusotoFuture<BatchGetWorkflowsResponse, BatchGetWorkflowsError> {
+        (**self).batch_get_workflows(input)
+    }
+    fn batch_stop_job_run(
+        &self,
+        input: BatchStopJobRunRequest,
+    ) -> RusotoFuture<BatchStopJobRunResponse, GlueBatchStopJobRunError> {
+        (**self).batch_stop_job_run(input)
+    }
+    fn cancel_ml_task_run(
+        &self,
+        input: CancelMLTaskRunRequest,
+    ) -> RusotoFuture<CancelMLTaskRunResponse, CancelMLTaskRunError> {
+        (**self).cancel_ml_task_run(input)
+    }
+    fn create_classifier(
+        &self,
+        input: CreateClassifierRequest,
+    ) -> RusotoFuture<CreateClassifierResponse, CreateClassifierError> {
+        (**self).create_classifier(input)
+    }
+    fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> RusotoFuture<CreateConnectionResponse, CreateConnectionError> {
+        (**self).create_connection(input)
+    }
+    fn create_crawler(
+        &self,
+        input: CreateCrawlerRequest,
+    ) -> RusotoFuture<CreateCrawlerResponse, CreateCrawlerError> {
+        (**self).create_crawler(input)
+    }
+    fn create_database(
+        &self,
+        input: CreateDatabaseRequest,
+    ) -> RusotoFuture<CreateDatabaseResponse, CreateDatabaseError> {
+        (**self).create_database(input)
+    }
+    fn create_dev_endpoint(
+        &self,
+        input: CreateDevEndpointRequest,
+    ) -> RusotoFuture<CreateDevEndpointResponse, CreateDevEndpointError> {
+        (**self).create_dev_endpoint(input)
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn create_ml_transform(
+        &self,
+        input: CreateMLTransformRequest,
+    ) -> RusotoFuture<CreateMLTransformResponse, CreateMLTransformError> {
+        (**self).create_ml_transform(input)
+    }
+    fn create_partition(
+        &self,
+        input: CreatePartitionRequest,
+    ) -> RusotoFuture<CreatePartitionResponse, CreatePartitionError> {
+        (**self).create_partition(input)
+    }
+    fn create_script(
+        &self,
+        input: CreateScriptRequest,
+    ) -> RusotoFuture<CreateScriptResponse, CreateScriptError> {
+        (**self).create_script(input)
+    }
+    fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationRequest,
+    ) -> RusotoFuture<CreateSecurityConfigurationResponse, CreateSecurityConfigurationError> {
+        (**self).create_security_configuration(input)
+    }
+    fn create_table(
+        &self,
+        input: CreateTableRequest,
+    ) -> RusotoFuture<CreateTableResponse, CreateTableError> {
+        (**self).create_table(input)
+    }
+    fn create_trigger(
+        &self,
+        input: CreateTriggerRequest,
+    ) -> RusotoFuture<CreateTriggerResponse, CreateTriggerError> {
+        (**self).create_trigger(input)
+    }
+    fn create_user_defined_function(
+        &self,
+        input: CreateUserDefinedFunctionRequest,
+    ) -> RusotoFuture<CreateUserDefinedFunctionResponse, CreateUserDefinedFunctionError> {
+        (**self).create_user_defined_function(input)
+    }
+    fn create_workflow(
+        &self,
+        input: CreateWorkflowRequest,
+    ) -> RusotoFuture<CreateWorkflowResponse, CreateWorkflowError> {
+        (**self).create_workflow(input)
+    }
+    fn delete_classifier(
+        &self,
+        input: DeleteClassifierRequest,
+    ) -> RusotoFuture<DeleteClassifierResponse, DeleteClassifierError> {
+        (**self).delete_classifier(input)
+    }
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<DeleteConnectionResponse, DeleteConnectionError> {
+        (**self).delete_connection(input)
+    }
+    fn delete_crawler(
+        &self,
+        input: DeleteCrawlerRequest,
+    ) -> RusotoFuture<DeleteCrawlerResponse, DeleteCrawlerError> {
+        (**self).delete_crawler(input)
+    }
+    fn delete_database(
+        &self,
+        input: DeleteDatabaseRequest,
+    ) -> RusotoFuture<DeleteDatabaseResponse, DeleteDatabaseError> {
+        (**self).delete_database(input)
+    }
+    fn delete_dev_endpoint(
+        &self,
+        input: DeleteDevEndpointRequest,
+    ) -> RusotoFuture<DeleteDevEndpointResponse, DeleteDevEndpointError> {
+        (**self).delete_dev_endpoint(input)
+    }
+    fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> RusotoFuture<DeleteJobResponse, DeleteJobError> {
+        (**self).delete_job(input)
+    }
+    fn delete_ml_transform(
+        &self,
+        input: DeleteMLTransformRequest,
+    ) -> RusotoFuture<DeleteMLTransformResponse, DeleteMLTransformError> {
+        (**self).delete_ml_transform(input)
+    }
+    fn delete_partition(
+        &self,
+        input: DeletePartitionRequest,
+    ) -> RusotoFuture<DeletePartitionResponse, DeletePartitionError> {
+        (**self).delete_partition(input)
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
+        (**self).delete_resource_policy(input)
+    }
+    fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationRequest,
+    ) -> RusotoFuture<DeleteSecurityConfigurationResponse, DeleteSecurityConfigurationError> {
+        (**self).delete_security_configuration(input)
+    }
+    fn delete_table(
+        &self,
+        input: DeleteTableRequest,
+    ) -> RusotoFuture<DeleteTableResponse, DeleteTableError> {
+        (**self).delete_table(input)
+    }
+    fn delete_table_version(
+        &self,
+        input: DeleteTableVersionRequest,
+    ) -> RusotoFuture<DeleteTableVersionResponse, DeleteTableVersionError> {
+        (**self).delete_table_version(input)
+    }
+    fn delete_trigger(
+        &self,
+        input: DeleteTriggerRequest,
+    ) -> RusotoFuture<DeleteTriggerResponse, DeleteTriggerError> {
+        (**self).delete_trigger(input)
+    }
+    fn delete_user_defined_function(
+        &self,
+        input: DeleteUserDefinedFunctionRequest,
+    ) -> RusotoFuture<DeleteUserDefinedFunctionResponse, DeleteUserDefinedFunctionError> {
+        (**self).delete_user_defined_function(input)
+    }
+    fn delete_workflow(
+        &self,
+        input: DeleteWorkflowRequest,
+    ) -> RusotoFuture<DeleteWorkflowResponse, DeleteWorkflowError> {
+        (**self).delete_workflow(input)
+    }
+    fn get_catalog_import_status(
+        &self,
+        input: GetCatalogImportStatusRequest,
+    ) -> RusotoFuture<GetCatalogImportStatusResponse, GetCatalogImportStatusError> {
+        (**self).get_catalog_import_status(input)
+    }
+    fn get_classifier(
+        &self,
+        input: GetClassifierRequest,
+    ) -> RusotoFuture<GetClassifierResponse, GetClassifierError> {
+        (**self).get_classifier(input)
+    }
+    fn get_classifiers(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> RusotoFuture<GetClassifiersResponse, GetClassifiersError> {
+        (**self).get_classifiers(input)
+    }
+    fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
+        (**self).get_connection(input)
+    }
+    fn get_connections(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> RusotoFuture<GetConnectionsResponse, GetConnectionsError> {
+        (**self).get_connections(input)
+    }
+    fn get_crawler(
+        &self,
+        input: GetCrawlerRequest,
+    ) -> RusotoFuture<GetCrawlerResponse, GetCrawlerError> {
+        (**self).get_crawler(input)
+    }
+    fn get_crawler_metrics(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> RusotoFuture<GetCrawlerMetricsResponse, GetCrawlerMetricsError> {
+        (**self).get_crawler_metrics(input)
+    }
+    fn get_crawlers(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> RusotoFuture<GetCrawlersResponse, GetCrawlersError> {
+        (**self).get_crawlers(input)
+    }
+    fn get_data_catalog_encryption_settings(
+        &self,
+        input: GetDataCatalogEncryptionSettingsRequest,
+    ) -> RusotoFuture<GetDataCatalogEncryptionSettingsResponse, GetDataCatalogEncryptionSettingsError>
+    {
+        (**self).get_data_catalog_encryption_settings(input)
+    }
+    fn get_database(
+        &self,
+        input: GetDatabaseRequest,
+    ) -> RusotoFuture<GetDatabaseResponse, GetDatabaseError> {
+        (**self).get_database(input)
+    }
+    fn get_databases(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> RusotoFuture<GetDatabasesResponse, GetDatabasesError> {
+        (**self).get_databases(input)
+    }
+    fn get_dataflow_graph(
+        &self,
+        input: GetDataflowGraphRequest,
+    ) -> RusotoFuture<GetDataflowGraphResponse, GetDataflowGraphError> {
+        (**self).get_dataflow_graph(input)
+    }
+    fn get_dev_endpoint(
+        &self,
+        input: GetDevEndpointRequest,
+    ) -> RusotoFuture<GetDevEndpointResponse, GetDevEndpointError> {
+        (**self).get_dev_endpoint(input)
+    }
+    fn get_dev_endpoints(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> RusotoFuture<GetDevEndpointsResponse, GetDevEndpointsError> {
+        (**self).get_dev_endpoints(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResponse, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_job_bookmark(
+        &self,
+        input: GetJobBookmarkRequest,
+    ) -> RusotoFuture<GetJobBookmarkResponse, GetJobBookmarkError> {
+        (**self).get_job_bookmark(input)
+    }
+    fn get_job_run(
+        &self,
+        input: GetJobRunRequest,
+    ) -> RusotoFuture<GetJobRunResponse, GetJobRunError> {
+        (**self).get_job_run(input)
+    }
+    fn get_job_runs(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> RusotoFuture<GetJobRunsResponse, GetJobRunsError> {
+        (**self).get_job_runs(input)
+    }
+    fn get_jobs(&self, input: GetJobsRequest) -> RusotoFuture<GetJobsResponse, GetJobsError> {
+        (**self).get_jobs(input)
+    }
+    fn get_ml_task_run(
+        &self,
+        input: GetMLTaskRunRequest,
+    ) -> RusotoFuture<GetMLTaskRunResponse, GetMLTaskRunError> {
+        (**self).get_ml_task_run(input)
+    }
+    fn get_ml_task_runs(
+        &self,
+        input: GetMLTaskRunsRequest,
+    ) -> RusotoFuture<GetMLTaskRunsResponse, GetMLTaskRunsError> {
+        (**self).get_ml_task_runs(input)
+    }
+    fn get_ml_transform(
+        &self,
+        input: GetMLTransformRequest,
+    ) -> RusotoFuture<GetMLTransformResponse, GetMLTransformError> {
+        (**self).get_ml_transform(input)
+    }
+    fn get_ml_transforms(
+        &self,
+        input: GetMLTransformsRequest,
+    ) -> RusotoFuture<GetMLTransformsResponse, GetMLTransformsError> {
+        (**self).get_ml_transforms(input)
+    }
+    fn get_mapping(
+        &self,
+        input: GetMappingRequest,
+    ) -> RusotoFuture<GetMappingResponse, GetMappingError> {
+        (**self).get_mapping(input)
+    }
+    fn get_partition(
+        &self,
+        input: GetPartitionRequest,
+    ) -> RusotoFuture<GetPartitionResponse, GetPartitionError> {
+        (**self).get_partition(input)
+    }
+    fn get_partitions(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> RusotoFuture<GetPartitionsResponse, GetPartitionsError> {
+        (**self).get_partitions(input)
+    }
+    fn get_plan(&self, input: GetPlanRequest) -> RusotoFuture<GetPlanResponse, GetPlanError> {
+        (**self).get_plan(input)
+    }
+    fn get_resource_policy(
+        &self,
+    ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        (**self).get_resource_policy()
+    }
+    fn get_security_configuration(
+        &self,
+        input: GetSecurityConfigurationRequest,
+    ) -> RusotoFuture<GetSecurityConfigurationResponse, GetSecurityConfigurationError> {
+        (**self).get_security_configuration(input)
+    }
+    fn get_security_configurations(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> RusotoFuture<GetSecurityConfigurationsResponse, GetSecurityConfigurationsError> {
+        (**self).get_security_configurations(input)
+    }
+    fn get_table(&self, input: GetTableRequest) -> RusotoFuture<GetTableResponse, GetTableError> {
+        (**self).get_table(input)
+    }
+    fn get_table_version(
+        &self,
+        input: GetTableVersionRequest,
+    ) -> RusotoFuture<GetTableVersionResponse, GetTableVersionError> {
+        (**self).get_table_version(input)
+    }
+    fn get_table_versions(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> RusotoFuture<GetTableVersionsResponse, GetTableVersionsError> {
+        (**self).get_table_versions(input)
+    }
+    fn get_tables(
+        &self,
+        input: GetTablesRequest,
+    ) -> RusotoFuture<GetTablesResponse, GetTablesError> {
+        (**self).get_tables(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn get_trigger(
+        &self,
+        input: GetTriggerRequest,
+    ) -> RusotoFuture<GetTriggerResponse, GetTriggerError> {
+        (**self).get_trigger(input)
+    }
+    fn get_triggers(
+        &self,
+        input: GetTriggersRequest,
+    ) -> RusotoFuture<GetTriggersResponse, GetTriggersError> {
+        (**self).get_triggers(input)
+    }
+    fn get_user_defined_function(
+        &self,
+        input: GetUserDefinedFunctionRequest,
+    ) -> RusotoFuture<GetUserDefinedFunctionResponse, GetUserDefinedFunctionError> {
+        (**self).get_user_defined_function(input)
+    }
+    fn get_user_defined_functions(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> RusotoFuture<GetUserDefinedFunctionsResponse, GetUserDefinedFunctionsError> {
+        (**self).get_user_defined_functions(input)
+    }
+    fn get_workflow(
+        &self,
+        input: GetWorkflowRequest,
+    ) -> RusotoFuture<GetWorkflowResponse, GetWorkflowError> {
+        (**self).get_workflow(input)
+    }
+    fn get_workflow_run(
+        &self,
+        input: GetWorkflowRunRequest,
+    ) -> RusotoFuture<GetWorkflowRunResponse, GetWorkflowRunError> {
+        (**self).get_workflow_run(input)
+    }
+    fn get_workflow_run_properties(
+        &self,
+        input: GetWorkflowRunPropertiesRequest,
+    ) -> RusotoFuture<GetWorkflowRunPropertiesResponse, GetWorkflowRunPropertiesError> {
+        (**self).get_workflow_run_properties(input)
+    }
+    fn get_workflow_runs(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> RusotoFuture<GetWorkflowRunsResponse, GetWorkflowRunsError> {
+        (**self).get_workflow_runs(input)
+    }
+    fn import_catalog_to_glue(
+        &self,
+        input: ImportCatalogToGlueRequest,
+    ) -> RusotoFuture<ImportCatalogToGlueResponse, ImportCatalogToGlueError> {
+        (**self).import_catalog_to_glue(input)
+    }
+    fn list_crawlers(
+        &self,
+        input: ListCrawlersRequest,
+    ) -> RusotoFuture<ListCrawlersResponse, ListCrawlersError> {
+        (**self).list_crawlers(input)
+    }
+    fn list_dev_endpoints(
+        &self,
+        input: ListDevEndpointsRequest,
+    ) -> RusotoFuture<ListDevEndpointsResponse, ListDevEndpointsError> {
+        (**self).list_dev_endpoints(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_triggers(
+        &self,
+        input: ListTriggersRequest,
+    ) -> RusotoFuture<ListTriggersResponse, ListTriggersError> {
+        (**self).list_triggers(input)
+    }
+    fn list_workflows(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> RusotoFuture<ListWorkflowsResponse, ListWorkflowsError> {
+        (**self).list_workflows(input)
+    }
+    fn put_data_catalog_encryption_settings(
+        &self,
+        input: PutDataCatalogEncryptionSettingsRequest,
+    ) -> RusotoFuture<PutDataCatalogEncryptionSettingsResponse, PutDataCatalogEncryptionSettingsError>
+    {
+        (**self).put_data_catalog_encryption_settings(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_workflow_run_properties(
+        &self,
+        input: PutWorkflowRunPropertiesRequest,
+    ) -> RusotoFuture<PutWorkflowRunPropertiesResponse, PutWorkflowRunPropertiesError> {
+        (**self).put_workflow_run_properties(input)
+    }
+    fn reset_job_bookmark(
+        &self,
+        input: ResetJobBookmarkRequest,
+    ) -> RusotoFuture<ResetJobBookmarkResponse, ResetJobBookmarkError> {
+        (**self).reset_job_bookmark(input)
+    }
+    fn search_tables(
+        &self,
+        input: SearchTablesRequest,
+    ) -> RusotoFuture<SearchTablesResponse, SearchTablesError> {
+        (**self).search_tables(input)
+    }
+    fn start_crawler(
+        &self,
+        input: StartCrawlerRequest,
+    ) -> RusotoFuture<StartCrawlerResponse, StartCrawlerError> {
+        (**self).start_crawler(input)
+    }
+    fn start_crawler_schedule(
+        &self,
+        input: StartCrawlerScheduleRequest,
+    ) -> RusotoFuture<StartCrawlerScheduleResponse, StartCrawlerScheduleError> {
+        (**self).start_crawler_schedule(input)
+    }
+    fn start_export_labels_task_run(
+        &self,
+        input: StartExportLabelsTaskRunRequest,
+    ) -> RusotoFuture<StartExportLabelsTaskRunResponse, StartExportLabelsTaskRunError> {
+        (**self).start_export_labels_task_run(input)
+    }
+    fn start_import_labels_task_run(
+        &self,
+        input: StartImportLabelsTaskRunRequest,
+    ) -> RusotoFuture<StartImportLabelsTaskRunResponse, StartImportLabelsTaskRunError> {
+        (**self).start_import_labels_task_run(input)
+    }
+    fn start_job_run(
+        &self,
+        input: StartJobRunRequest,
+    ) -> RusotoFuture<StartJobRunResponse, StartJobRunError> {
+        (**self).start_job_run(input)
+    }
+    fn start_ml_evaluation_task_run(
+        &self,
+        input: StartMLEvaluationTaskRunRequest,
+    ) -> RusotoFuture<StartMLEvaluationTaskRunResponse, StartMLEvaluationTaskRunError> {
+        (**self).start_ml_evaluation_task_run(input)
+    }
+    fn start_ml_labeling_set_generation_task_run(
+        &self,
+        input: StartMLLabelingSetGenerationTaskRunRequest,
+    ) -> RusotoFuture<
+        StartMLLabelingSetGenerationTaskRunResponse,
+        StartMLLabelingSetGenerationTaskRunError,
+    > {
+        (**self).start_ml_labeling_set_generation_task_run(input)
+    }
+    fn start_trigger(
+        &self,
+        input: StartTriggerRequest,
+    ) -> RusotoFuture<StartTriggerResponse, StartTriggerError> {
+        (**self).start_trigger(input)
+    }
+    fn start_workflow_run(
+        &self,
+        input: StartWorkflowRunRequest,
+    ) -> RusotoFuture<StartWorkflowRunResponse, StartWorkflowRunError> {
+        (**self).start_workflow_run(input)
+    }
+    fn stop_crawler(
+        &self,
+        input: StopCrawlerRequest,
+    ) -> RusotoFuture<StopCrawlerResponse, StopCrawlerError> {
+        (**self).stop_crawler(input)
+    }
+    fn stop_crawler_schedule(
+        &self,
+        input: StopCrawlerScheduleRequest,
+    ) -> RusotoFuture<StopCrawlerScheduleResponse, StopCrawlerScheduleError> {
+        (**self).stop_crawler_schedule(input)
+    }
+    fn stop_trigger(
+        &self,
+        input: StopTriggerRequest,
+    ) -> RusotoFuture<StopTriggerResponse, StopTriggerError> {
+        (**self).stop_trigger(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_classifier(
+        &self,
+        input: UpdateClassifierRequest,
+    ) -> RusotoFuture<UpdateClassifierResponse, UpdateClassifierError> {
+        (**self).update_classifier(input)
+    }
+    fn update_connection(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> RusotoFuture<UpdateConnectionResponse, UpdateConnectionError> {
+        (**self).update_connection(input)
+    }
+    fn update_crawler(
+        &self,
+        input: UpdateCrawlerRequest,
+    ) -> RusotoFuture<UpdateCrawlerResponse, UpdateCrawlerError> {
+        (**self).update_crawler(input)
+    }
+    fn update_crawler_schedule(
+        &self,
+        input: UpdateCrawlerScheduleRequest,
+    ) -> RusotoFuture<UpdateCrawlerScheduleResponse, UpdateCrawlerScheduleError> {
+        (**self).update_crawler_schedule(input)
+    }
+    fn update_database(
+        &self,
+        input: UpdateDatabaseRequest,
+    ) -> RusotoFuture<UpdateDatabaseResponse, UpdateDatabaseError> {
+        (**self).update_database(input)
+    }
+    fn update_dev_endpoint(
+        &self,
+        input: UpdateDevEndpointRequest,
+    ) -> RusotoFuture<UpdateDevEndpointResponse, UpdateDevEndpointError> {
+        (**self).update_dev_endpoint(input)
+    }
+    fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> RusotoFuture<UpdateJobResponse, UpdateJobError> {
+        (**self).update_job(input)
+    }
+    fn update_ml_transform(
+        &self,
+        input: UpdateMLTransformRequest,
+    ) -> RusotoFuture<UpdateMLTransformResponse, UpdateMLTransformError> {
+        (**self).update_ml_transform(input)
+    }
+    fn update_partition(
+        &self,
+        input: UpdatePartitionRequest,
+    ) -> RusotoFuture<UpdatePartitionResponse, UpdatePartitionError> {
+        (**self).update_partition(input)
+    }
+    fn update_table(
+        &self,
+        input: UpdateTableRequest,
+    ) -> RusotoFuture<UpdateTableResponse, UpdateTableError> {
+        (**self).update_table(input)
+    }
+    fn update_trigger(
+        &self,
+        input: UpdateTriggerRequest,
+    ) -> RusotoFuture<UpdateTriggerResponse, UpdateTriggerError> {
+        (**self).update_trigger(input)
+    }
+    fn update_user_defined_function(
+        &self,
+        input: UpdateUserDefinedFunctionRequest,
+    ) -> RusotoFuture<UpdateUserDefinedFunctionResponse, UpdateUserDefinedFunctionError> {
+        (**self).update_user_defined_function(input)
+    }
+    fn update_workflow(
+        &self,
+        input: UpdateWorkflowRequest,
+    ) -> RusotoFuture<UpdateWorkflowResponse, UpdateWorkflowError> {
+        (**self).update_workflow(input)
+    }
+}
+/// A client for the AWS Glue API.
+#[derive(Clone)]
+pub struct GlueClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl GlueClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GlueClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GlueClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> GlueClient {
+        GlueClient { client, region }
+    }
+}
+
+impl Glue for GlueClient {
+    /// <p>Creates one or more partitions in a batch operation.</p>
+    fn batch_create_partition(
+        &self,
+        input: BatchCreatePartitionRequest,
+    ) -> RusotoFuture<BatchCreatePartitionResponse, BatchCreatePartitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchCreatePartition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchCreatePartition");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchCreatePartitionResponse, _>(
+                    response,
+                    "BatchCreatePartition",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchCreatePartitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a list of connection definitions from the Data Catalog.</p>
+    fn batch_delete_connection(
+        &self,
+        input: BatchDeleteConnectionRequest,
+    ) -> RusotoFuture<BatchDeleteConnectionResponse, BatchDeleteConnectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeleteConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchDeleteConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDeleteConnectionResponse, _>(
+                    response,
+                    "BatchDeleteConnection",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchDeleteConnectionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes one or more partitions in a batch operation.</p>
+    fn batch_delete_partition(
+        &self,
+        input: BatchDeletePartitionRequest,
+    ) -> RusotoFuture<BatchDeletePartitionResponse, BatchDeletePartitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeletePartition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchDeletePartition");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDeletePartitionResponse, _>(
+                    response,
+                    "BatchDeletePartition",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchDeletePartitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes multiple tables at once.</p> <note> <p>After completing this operation, you no longer have access to the table versions and partitions that belong to the deleted table. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>BatchDeleteTable</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, and <code>DeletePartition</code> or <code>BatchDeletePartition</code>, to delete any resources that belong to the table.</p> </note></p>
+    fn batch_delete_table(
+        &self,
+        input: BatchDeleteTableRequest,
+    ) -> RusotoFuture<BatchDeleteTableResponse, BatchDeleteTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeleteTable");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchDeleteTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDeleteTableResponse, _>(
+                    response,
+                    "BatchDeleteTable",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchDeleteTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified batch of versions of a table.</p>
+    fn batch_delete_table_version(
+        &self,
+        input: BatchDeleteTableVersionRequest,
+    ) -> RusotoFuture<BatchDeleteTableVersionResponse, BatchDeleteTableVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeleteTableVersion");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchDeleteTableVersion");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchDeleteTableVersionResponse, _>(
+                    response,
+                    "BatchDeleteTableVersion",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchDeleteTableVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of crawler names. After calling the <code>ListCrawlers</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    fn batch_get_crawlers(
+        &self,
+        input: BatchGetCrawlersRequest,
+    ) -> RusotoFuture<BatchGetCrawlersResponse, BatchGetCrawlersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetCrawlers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchGetCrawlers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetCrawlersResponse, _>(
+                    response,
+                    "BatchGetCrawlers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetCrawlersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of development endpoint names. After calling the <code>ListDevEndpoints</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    fn batch_get_dev_endpoints(
+        &self,
+        input: BatchGetDevEndpointsRequest,
+    ) -> RusotoFuture<BatchGetDevEndpointsResponse, BatchGetDevEndpointsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetDevEndpoints");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchGetDevEndpoints");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetDevEndpointsResponse, _>(
+                    response,
+                    "BatchGetDevEndpoints",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchGetDevEndpointsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of job names. After calling the <code>ListJobs</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags. </p>
+    fn batch_get_jobs(
+        &self,
+        input: BatchGetJobsRequest,
+    ) -> RusotoFuture<BatchGetJobsResponse, BatchGetJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchGetJobs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetJobsResponse, _>(
+                    response,
+                    "BatchGetJobs",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves partitions in a batch request.</p>
+    fn batch_get_partition(
+        &self,
+        input: BatchGetPartitionRequest,
+    ) -> RusotoFuture<BatchGetPartitionResponse, BatchGetPartitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetPartition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchGetPartition");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetPartitionResponse, _>(
+                    response,
+                    "BatchGetPartition",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetPartitionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of trigger names. After calling the <code>ListTriggers</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    fn batch_get_triggers(
+        &self,
+        input: BatchGetTriggersRequest,
+    ) -> RusotoFuture<BatchGetTriggersResponse, BatchGetTriggersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetTriggers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchGetTriggers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetTriggersResponse, _>(
+                    response,
+                    "BatchGetTriggers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetTriggersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of workflow names. After calling the <code>ListWorkflows</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    fn batch_get_workflows(
+        &self,
+        input: BatchGetWorkflowsRequest,
+    ) -> RusotoFuture<BatchGetWorkflowsResponse, BatchGetWorkflowsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetWorkflows");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchGetWorkflows");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchGetWorkflowsResponse, _>(
+                    response,
+                    "BatchGetWorkflows",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetWorkflowsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops one or more job runs for a specified job definition.</p>
+    fn batch_stop_job_run(
+        &self,
+        input: BatchStopJobRunRequest,
+    ) -> RusotoFuture<BatchStopJobRunResponse, GlueBatchStopJobRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchStopJobRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.BatchStopJobRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<BatchStopJobRunResponse, _>(
+                    response,
+                    "BatchStopJobRun",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GlueBatchStopJobRunError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Cancels (stops) a task run. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can cancel a machine learning task run at any time by calling <code>CancelMLTaskRun</code> with a task run's parent transform's <code>TransformID</code> and the task run's <code>TaskRunId</code>. </p>
+    fn cancel_ml_task_run(
+        &self,
+        input: CancelMLTaskRunRequest,
+    ) -> RusotoFuture<CancelMLTaskRunResponse, CancelMLTaskRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CancelMLTaskRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CancelMLTaskRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelMLTaskRunResponse, _>(
+                    response,
+                    "CancelMLTaskRun",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelMLTaskRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a classifier in the user's account. This can be a <code>GrokClassifier</code>, an <code>XMLClassifier</code>, a <code>JsonClassifier</code>, or a <code>CsvClassifier</code>, depending on which field of the request is present.</p>
+    fn create_classifier(
+        &self,
+        input: CreateClassifierRequest,
+    ) -> RusotoFuture<CreateClassifierResponse, CreateClassifierError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateClassifier");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateClassifier");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateClassifierResponse, _>(
+                    response,
+                    "CreateClassifier",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateClassifierError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a connection definition in the Data Catalog.</p>
+    fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> RusotoFuture<CreateConnectionResponse, CreateConnectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateConnectionResponse, _>(
+                    response,
+                    "CreateConnection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateConnectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new crawler with specified targets, role, configuration, and optional schedule. At least one crawl target must be specified, in the <code>s3Targets</code> field, the <code>jdbcTargets</code> field, or the <code>DynamoDBTargets</code> field.</p>
+    fn create_crawler(
+        &self,
+        input: CreateCrawlerRequest,
+    ) -> RusotoFuture<CreateCrawlerResponse, CreateCrawlerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateCrawler");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateCrawler");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateCrawlerResponse, _>(
+                    response,
+                    "CreateCrawler",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateCrawlerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new database in a Data Catalog.</p>
+    fn create_database(
+        &self,
+        input: CreateDatabaseRequest,
+    ) -> RusotoFuture<CreateDatabaseResponse, CreateDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateDatabase");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDatabaseResponse, _>(
+                    response,
+                    "CreateDatabase",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDatabaseError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new development endpoint.</p>
+    fn create_dev_endpoint(
+        &self,
+        input: CreateDevEndpointRequest,
+    ) -> RusotoFuture<CreateDevEndpointResponse, CreateDevEndpointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateDevEndpoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateDevEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDevEndpointResponse, _>(
+                    response,
+                    "CreateDevEndpoint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDevEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new job definition.</p>
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateJobResponse, _>(response, "CreateJob")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an AWS Glue machine learning transform. This operation creates the transform and all the necessary parameters to train it.</p> <p>Call this operation as the first step in the process of using a machine learning transform (such as the <code>FindMatches</code> transform) for deduplicating data. You can provide an optional <code>Description</code>, in addition to the parameters that you want to use for your algorithm.</p> <p>You must also specify certain parameters for the tasks that AWS Glue runs on your behalf as part of learning from your data and creating a high-quality machine learning transform. These parameters include <code>Role</code>, and optionally, <code>AllocatedCapacity</code>, <code>Timeout</code>, and <code>MaxRetries</code>. For more information, see <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-jobs-job.html">Jobs</a>.</p>
+    fn create_ml_transform(
+        &self,
+        input: CreateMLTransformRequest,
+    ) -> RusotoFuture<CreateMLTransformResponse, CreateMLTransformError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateMLTransform");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateMLTransform");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateMLTransformResponse, _>(
+                    response,
+                    "CreateMLTransform",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateMLTransformError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new partition.</p>
+    fn create_partition(
+        &self,
+        input: CreatePartitionRequest,
+    ) -> RusotoFuture<CreatePartitionResponse, CreatePartitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreatePartition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreatePartition");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePartitionResponse, _>(
+                    response,
+                    "CreatePartition",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreatePartitionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Transforms a directed acyclic graph (DAG) into code.</p>
+    fn create_script(
+        &self,
+        input: CreateScriptRequest,
+    ) -> RusotoFuture<CreateScriptResponse, CreateScriptError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateScript");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateScript");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateScriptResponse, _>(
+                    response,
+                    "CreateScript",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateScriptError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new security configuration. A security configuration is a set of security properties that can be used by AWS Glue. You can use a security configuration to encrypt data at rest. For information about using security configurations in AWS Glue, see <a href="https://docs.aws.amazon.com/glue/latest/dg/encryption-security-configuration.html">Encrypting Data Written by Crawlers, Jobs, and Development Endpoints</a>.</p>
+    fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationRequest,
+    ) -> RusotoFuture<CreateSecurityConfigurationResponse, CreateSecurityConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateSecurityConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateSecurityConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSecurityConfigurationResponse, _>(
+                    response,
+                    "CreateSecurityConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateSecurityConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a new table definition in the Data Catalog.</p>
+    fn create_table(
+        &self,
+        input: CreateTableRequest,
+    ) -> RusotoFuture<CreateTableResponse, CreateTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateTable");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTableResponse, _>(
+                    response,
+                    "CreateTable",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new trigger.</p>
+    fn create_trigger(
+        &self,
+        input: CreateTriggerRequest,
+    ) -> RusotoFuture<CreateTriggerResponse, CreateTriggerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateTrigger");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateTrigger");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTriggerResponse, _>(
+                    response,
+                    "CreateTrigger",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTriggerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new function definition in the Data Catalog.</p>
+    fn create_user_defined_function(
+        &self,
+        input: CreateUserDefinedFunctionRequest,
+    ) -> RusotoFuture<CreateUserDefinedFunctionResponse, CreateUserDefinedFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateUserDefinedFunction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateUserDefinedFunction");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserDefinedFunctionResponse, _>(
+                    response,
+                    "CreateUserDefinedFunction",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateUserDefinedFunctionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a new workflow.</p>
+    fn create_workflow(
+        &self,
+        input: CreateWorkflowRequest,
+    ) -> RusotoFuture<CreateWorkflowResponse, CreateWorkflowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateWorkflow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.CreateWorkflow");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateWorkflowResponse, _>(
+                    response,
+                    "CreateWorkflow",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateWorkflowError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes a classifier from the Data Catalog.</p>
+    fn delete_classifier(
+        &self,
+        input: DeleteClassifierRequest,
+    ) -> RusotoFuture<DeleteClassifierResponse, DeleteClassifierError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteClassifier");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteClassifier");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteClassifierResponse, _>(
+                    response,
+                    "DeleteClassifier",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteClassifierError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a connection from the Data Catalog.</p>
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<DeleteConnectionResponse, DeleteConnectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteConnectionResponse, _>(
+                    response,
+                    "DeleteConnection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteConnectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes a specified crawler from the AWS Glue Data Catalog, unless the crawler state is <code>RUNNING</code>.</p>
+    fn delete_crawler(
+        &self,
+        input: DeleteCrawlerRequest,
+    ) -> RusotoFuture<DeleteCrawlerResponse, DeleteCrawlerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteCrawler");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteCrawler");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteCrawlerResponse, _>(
+                    response,
+                    "DeleteCrawler",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteCrawlerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Removes a specified database from a Data Catalog.</p> <note> <p>After completing this operation, you no longer have access to the tables (and all table versions and partitions that might belong to the tables) and the user-defined functions in the deleted database. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>DeleteDatabase</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, <code>DeletePartition</code> or <code>BatchDeletePartition</code>, <code>DeleteUserDefinedFunction</code>, and <code>DeleteTable</code> or <code>BatchDeleteTable</code>, to delete any resources that belong to the database.</p> </note></p>
+    fn delete_database(
+        &self,
+        input: DeleteDatabaseRequest,
+    ) -> RusotoFuture<DeleteDatabaseResponse, DeleteDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteDatabase");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDatabaseResponse, _>(
+                    response,
+                    "DeleteDatabase",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDatabaseError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified development endpoint.</p>
+    fn delete_dev_endpoint(
+        &self,
+        input: DeleteDevEndpointRequest,
+    ) -> RusotoFuture<DeleteDevEndpointResponse, DeleteDevEndpointError> {
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteDevEndpoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteDevEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDevEndpointResponse, _>(
+                    response,
+                    "DeleteDevEndpoint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDevEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified job definition. If the job definition is not found, no exception is thrown.</p>
+    fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> RusotoFuture<DeleteJobResponse, DeleteJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteJobResponse, _>(response, "DeleteJob")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an AWS Glue machine learning transform. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue. If you no longer need a transform, you can delete it by calling <code>DeleteMLTransforms</code>. However, any AWS Glue jobs that still reference the deleted transform will no longer succeed.</p>
+    fn delete_ml_transform(
+        &self,
+        input: DeleteMLTransformRequest,
+    ) -> RusotoFuture<DeleteMLTransformResponse, DeleteMLTransformError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteMLTransform");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteMLTransform");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteMLTransformResponse, _>(
+                    response,
+                    "DeleteMLTransform",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteMLTransformError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified partition.</p>
+    fn delete_partition(
+        &self,
+        input: DeletePartitionRequest,
+    ) -> RusotoFuture<DeletePartitionResponse, DeletePartitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeletePartition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeletePartition");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeletePartitionResponse, _>(
+                    response,
+                    "DeletePartition",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeletePartitionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified policy.</p>
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteResourcePolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteResourcePolicyResponse, _>(
+                    response,
+                    "DeleteResourcePolicy",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteResourcePolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified security configuration.</p>
+    fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationRequest,
+    ) -> RusotoFuture<DeleteSecurityConfigurationResponse, DeleteSecurityConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteSecurityConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteSecurityConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSecurityConfigurationResponse, _>(
+                    response,
+                    "DeleteSecurityConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteSecurityConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Removes a table definition from the Data Catalog.</p> <note> <p>After completing this operation, you no longer have access to the table versions and partitions that belong to the deleted table. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>DeleteTable</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, and <code>DeletePartition</code> or <code>BatchDeletePartition</code>, to delete any resources that belong to the table.</p> </note></p>
+    fn delete_table(
+        &self,
+        input: DeleteTableRequest,
+    ) -> RusotoFuture<DeleteTableResponse, DeleteTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteTable");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTableResponse, _>(
+                    response,
+                    "DeleteTable",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified version of a table.</p>
+    fn delete_table_version(
+        &self,
+        input: DeleteTableVersionRequest,
+    ) -> RusotoFuture<DeleteTableVersionResponse, DeleteTableVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteTableVersion");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteTableVersion");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTableVersionResponse, _>(
+                    response,
+                    "DeleteTableVersion",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTableVersionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified trigger. If the trigger is not found, no exception is thrown.</p>
+    fn delete_trigger(
+        &self,
+        input: DeleteTriggerRequest,
+    ) -> RusotoFuture<DeleteTriggerResponse, DeleteTriggerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteTrigger");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteTrigger");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTriggerResponse, _>(
+                    response,
+                    "DeleteTrigger",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTriggerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing function definition from the Data Catalog.</p>
+    fn delete_user_defined_function(
+        &self,
+        input: DeleteUserDefinedFunctionRequest,
+    ) -> RusotoFuture<DeleteUserDefinedFunctionResponse, DeleteUserDefinedFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteUserDefinedFunction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteUserDefinedFunction");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteUserDefinedFunctionResponse, _>(
+                    response,
+                    "DeleteUserDefinedFunction",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteUserDefinedFunctionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a workflow.</p>
+    fn delete_workflow(
+        &self,
+        input: DeleteWorkflowRequest,
+    ) -> RusotoFuture<DeleteWorkflowResponse, DeleteWorkflowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteWorkflow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.DeleteWorkflow");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteWorkflowResponse, _>(
+                    response,
+                    "DeleteWorkflow",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteWorkflowError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the status of a migration operation.</p>
+    fn get_catalog_import_status(
+        &self,
+        input: GetCatalogImportStatusRequest,
+    ) -> RusotoFuture<GetCatalogImportStatusResponse, GetCatalogImportStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCatalogImportStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetCatalogImportStatus");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCatalogImportStatusResponse, _>(
+                    response,
+                    "GetCatalogImportStatus",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetCatalogImportStatusError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieve a classifier by name.</p>
+    fn get_classifier(
+        &self,
+        input: GetClassifierRequest,
+    ) -> RusotoFuture<GetClassifierResponse, GetClassifierError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetClassifier");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetClassifier");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetClassifierResponse, _>(
+                    response,
+                    "GetClassifier",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetClassifierError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all classifier objects in the Data Catalog.</p>
+    fn get_classifiers(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> RusotoFuture<GetClassifiersResponse, GetClassifiersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetClassifiers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetClassifiers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetClassifiersResponse, _>(
+                    response,
+                    "GetClassifiers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetClassifiersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a connection definition from the Data Catalog.</p>
+    fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetConnectionResponse, _>(
+                    response,
+                    "GetConnection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetConnectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of connection definitions from the Data Catalog.</p>
+    fn get_connections(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> RusotoFuture<GetConnectionsResponse, GetConnectionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetConnections");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetConnections");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetConnectionsResponse, _>(
+                    response,
+                    "GetConnections",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetConnectionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves metadata for a specified crawler.</p>
+    fn get_crawler(
+        &self,
+        input: GetCrawlerRequest,
+    ) -> RusotoFuture<GetCrawlerResponse, GetCrawlerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCrawler");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetCrawler");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCrawlerResponse, _>(response, "GetCrawler")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetCrawlerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves metrics about specified crawlers.</p>
+    fn get_crawler_metrics(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> RusotoFuture<GetCrawlerMetricsResponse, GetCrawlerMetricsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCrawlerMetrics");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetCrawlerMetrics");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCrawlerMetricsResponse, _>(
+                    response,
+                    "GetCrawlerMetrics",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetCrawlerMetricsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves metadata for all crawlers defined in the customer account.</p>
+    fn get_crawlers(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> RusotoFuture<GetCrawlersResponse, GetCrawlersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCrawlers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetCrawlers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCrawlersResponse, _>(
+                    response,
+                    "GetCrawlers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetCrawlersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the security configuration for a specified catalog.</p>
+    fn get_data_catalog_encryption_settings(
+        &self,
+        input: GetDataCatalogEncryptionSettingsRequest,
+    ) -> RusotoFuture<GetDataCatalogEncryptionSettingsResponse, GetDataCatalogEncryptionSettingsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDataCatalogEncryptionSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetDataCatalogEncryptionSettings");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDataCatalogEncryptionSettingsResponse, _>(
+                    response,
+                    "GetDataCatalogEncryptionSettings",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetDataCatalogEncryptionSettingsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the definition of a specified database.</p>
+    fn get_database(
+        &self,
+        input: GetDatabaseRequest,
+    ) -> RusotoFuture<GetDatabaseResponse, GetDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetDatabase");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDatabaseResponse, _>(
+                    response,
+                    "GetDatabase",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDatabaseError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves all databases defined in a given Data Catalog.</p>
+    fn get_databases(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> RusotoFuture<GetDatabasesResponse, GetDatabasesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDatabases");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetDatabases");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDatabasesResponse, _>(
+                    response,
+                    "GetDatabases",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDatabasesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Transforms a Python script into a directed acyclic graph (DAG). </p>
+    fn get_dataflow_graph(
+        &self,
+        input: GetDataflowGraphRequest,
+    ) -> RusotoFuture<GetDataflowGraphResponse, GetDataflowGraphError> {
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDataflowGraph");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetDataflowGraph");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDataflowGraphResponse, _>(
+                    response,
+                    "GetDataflowGraph",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDataflowGraphError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves information about a specified development endpoint.</p> <note> <p>When you create a development endpoint in a virtual private cloud (VPC), AWS Glue returns only a private IP address, and the public IP address field is not populated. When you create a non-VPC development endpoint, AWS Glue returns only a public IP address.</p> </note></p>
+    fn get_dev_endpoint(
+        &self,
+        input: GetDevEndpointRequest,
+    ) -> RusotoFuture<GetDevEndpointResponse, GetDevEndpointError> {
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDevEndpoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetDevEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDevEndpointResponse, _>(
+                    response,
+                    "GetDevEndpoint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDevEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves all the development endpoints in this AWS account.</p> <note> <p>When you create a development endpoint in a virtual private cloud (VPC), AWS Glue returns only a private IP address and the public IP address field is not populated. When you create a non-VPC development endpoint, AWS Glue returns only a public IP address.</p> </note></p>
+    fn get_dev_endpoints(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> RusotoFuture<GetDevEndpointsResponse, GetDevEndpointsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDevEndpoints");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetDevEndpoints");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDevEndpointsResponse, _>(
+                    response,
+                    "GetDevEndpoints",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDevEndpointsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves an existing job definition.</p>
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResponse, GetJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetJobResponse, _>(response, "GetJob")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information on a job bookmark entry.</p>
+    fn get_job_bookmark(
+        &self,
+        input: GetJobBookmarkRequest,
+    ) -> RusotoFuture<GetJobBookmarkResponse, GetJobBookmarkError> {
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobBookmark");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetJobBookmark");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetJobBookmarkResponse, _>(
+                    response,
+                    "GetJobBookmark",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetJobBookmarkError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the metadata for a given job run.</p>
+    fn get_job_run(
+        &self,
+        input: GetJobRunRequest,
+    ) -> RusotoFuture<GetJobRunResponse, GetJobRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetJobRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetJobRunResponse, _>(response, "GetJobRun")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetJobRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves metadata for all runs of a given job definition.</p>
+    fn get_job_runs(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> RusotoFuture<GetJobRunsResponse, GetJobRunsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobRuns");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetJobRuns");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetJobRunsResponse, _>(response, "GetJobRuns")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetJobRunsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves all current job definitions.</p>
+    fn get_jobs(&self, input: GetJobsRequest) -> RusotoFuture<GetJobsResponse, GetJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetJobs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetJobsResponse, _>(response, "GetJobs")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets details for a specific task run on a machine learning transform. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can check the stats of any task run by calling <code>GetMLTaskRun</code> with the <code>TaskRunID</code> and its parent transform's <code>TransformID</code>.</p>
+    fn get_ml_task_run(
+        &self,
+        input: GetMLTaskRunRequest,
+    ) -> RusotoFuture<GetMLTaskRunResponse, GetMLTaskRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTaskRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetMLTaskRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMLTaskRunResponse, _>(
+                    response,
+                    "GetMLTaskRun",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMLTaskRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of runs for a machine learning transform. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can get a sortable, filterable list of machine learning task runs by calling <code>GetMLTaskRuns</code> with their parent transform's <code>TransformID</code> and other optional parameters as documented in this section.</p> <p>This operation returns a list of historic runs and must be paginated.</p>
+    fn get_ml_task_runs(
+        &self,
+        input: GetMLTaskRunsRequest,
+    ) -> RusotoFuture<GetMLTaskRunsResponse, GetMLTaskRunsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTaskRuns");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetMLTaskRuns");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMLTaskRunsResponse, _>(
+                    response,
+                    "GetMLTaskRuns",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMLTaskRunsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets an AWS Glue machine learning transform artifact and all its corresponding metadata. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue. You can retrieve their metadata by calling <code>GetMLTransform</code>.</p>
+    fn get_ml_transform(
+        &self,
+        input: GetMLTransformRequest,
+    ) -> RusotoFuture<GetMLTransformResponse, GetMLTransformError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTransform");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetMLTransform");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMLTransformResponse, _>(
+                    response,
+                    "GetMLTransform",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMLTransformError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a sortable, filterable list of existing AWS Glue machine learning transforms. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue, and you can retrieve their metadata by calling <code>GetMLTransforms</code>.</p>
+    fn get_ml_transforms(
+        &self,
+        input: GetMLTransformsRequest,
+    ) -> RusotoFuture<GetMLTransformsResponse, GetMLTransformsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTransforms");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetMLTransforms");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMLTransformsResponse, _>(
+                    response,
+                    "GetMLTransforms",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMLTransformsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates mappings.</p>
+    fn get_mapping(
+        &self,
+        input: GetMappingRequest,
+    ) -> RusotoFuture<GetMappingResponse, GetMappingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMapping");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetMapping");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMappingResponse, _>(response, "GetMapping")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMappingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a specified partition.</p>
+    fn get_partition(
+        &self,
+        input: GetPartitionRequest,
+    ) -> RusotoFuture<GetPartitionResponse, GetPartitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetPartition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetPartition");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPartitionResponse, _>(
+                    response,
+                    "GetPartition",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPartitionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about the partitions in a table.</p>
+    fn get_partitions(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> RusotoFuture<GetPartitionsResponse, GetPartitionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetPartitions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetPartitions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPartitionsResponse, _>(
+                    response,
+                    "GetPartitions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPartitionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets code to perform a specified mapping.</p>
+    fn get_plan(&self, input: GetPlanRequest) -> RusotoFuture<GetPlanResponse, GetPlanError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetPlan");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetPlan");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPlanResponse, _>(response, "GetPlan")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPlanError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a specified resource policy.</p>
+    fn get_resource_policy(
+        &self,
+    ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetResourcePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetResourcePolicy");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetResourcePolicyResponse, _>(
+                    response,
+                    "GetResourcePolicy",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetResourcePolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a specified security configuration.</p>
+    fn get_security_configuration(
+        &self,
+        input: GetSecurityConfigurationRequest,
+    ) -> RusotoFuture<GetSecurityConfigurationResponse, GetSecurityConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetSecurityConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetSecurityConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetSecurityConfigurationResponse, _>(
+                    response,
+                    "GetSecurityConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetSecurityConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of all security configurations.</p>
+    fn get_security_configurations(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> RusotoFuture<GetSecurityConfigurationsResponse, GetSecurityConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetSecurityConfigurations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetSecurityConfigurations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetSecurityConfigurationsResponse, _>(
+                    response,
+                    "GetSecurityConfigurations",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetSecurityConfigurationsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the <code>Table</code> definition in a Data Catalog for a specified table.</p>
+    fn get_table(&self, input: GetTableRequest) -> RusotoFuture<GetTableResponse, GetTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTable");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetTableResponse, _>(response, "GetTable")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a specified version of a table.</p>
+    fn get_table_version(
+        &self,
+        input: GetTableVersionRequest,
+    ) -> RusotoFuture<GetTableVersionResponse, GetTableVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTableVersion");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetTableVersion");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetTableVersionResponse, _>(
+                    response,
+                    "GetTableVersion",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTableVersionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of strings that identify available versions of a specified table.</p>
+    fn get_table_versions(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> RusotoFuture<GetTableVersionsResponse, GetTableVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTableVersions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetTableVersions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetTableVersionsResponse, _>(
+                    response,
+                    "GetTableVersions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTableVersionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the definitions of some or all of the tables in a given <code>Database</code>.</p>
+    fn get_tables(
+        &self,
+        input: GetTablesRequest,
+    ) -> RusotoFuture<GetTablesResponse, GetTablesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTables");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetTables");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetTablesResponse, _>(response, "GetTables")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTablesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of tags associated with a resource.</p>
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetTagsResponse, _>(response, "GetTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the definition of a trigger.</p>
+    fn get_trigger(
+        &self,
+        input: GetTriggerRequest,
+    ) -> RusotoFuture<GetTriggerResponse, GetTriggerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTrigger");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetTrigger");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetTriggerResponse, _>(response, "GetTrigger")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTriggerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets all the triggers associated with a job.</p>
+    fn get_triggers(
+        &self,
+        input: GetTriggersRequest,
+    ) -> RusotoFuture<GetTriggersResponse, GetTriggersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTriggers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetTriggers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetTriggersResponse, _>(
+                    response,
+                    "GetTriggers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTriggersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a specified function definition from the Data Catalog.</p>
+    fn get_user_defined_function(
+        &self,
+        input: GetUserDefinedFunctionRequest,
+    ) -> RusotoFuture<GetUserDefinedFunctionResponse, GetUserDefinedFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetUserDefinedFunction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunction");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetUserDefinedFunctionResponse, _>(
+                    response,
+                    "GetUserDefinedFunction",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetUserDefinedFunctionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves multiple function definitions from the Data Catalog.</p>
+    fn get_user_defined_functions(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> RusotoFuture<GetUserDefinedFunctionsResponse, GetUserDefinedFunctionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetUserDefinedFunctions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunctions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetUserDefinedFunctionsResponse, _>(
+                    response,
+                    "GetUserDefinedFunctions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetUserDefinedFunctionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves resource metadata for a workflow.</p>
+    fn get_workflow(
+        &self,
+        input: GetWorkflowRequest,
+    ) -> RusotoFuture<GetWorkflowResponse, GetWorkflowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetWorkflow");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetWorkflowResponse, _>(
+                    response,
+                    "GetWorkflow",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetWorkflowError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the metadata for a given workflow run. </p>
+    fn get_workflow_run(
+        &self,
+        input: GetWorkflowRunRequest,
+    ) -> RusotoFuture<GetWorkflowRunResponse, GetWorkflowRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflowRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetWorkflowRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetWorkflowRunResponse, _>(
+                    response,
+                    "GetWorkflowRun",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetWorkflowRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the workflow run properties which were set during the run.</p>
+    fn get_workflow_run_properties(
+        &self,
+        input: GetWorkflowRunPropertiesRequest,
+    ) -> RusotoFuture<GetWorkflowRunPropertiesResponse, GetWorkflowRunPropertiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflowRunProperties");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetWorkflowRunProperties");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetWorkflowRunPropertiesResponse, _>(
+                    response,
+                    "GetWorkflowRunProperties",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetWorkflowRunPropertiesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves metadata for all runs of a given workflow.</p>
+    fn get_workflow_runs(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> RusotoFuture<GetWorkflowRunsResponse, GetWorkflowRunsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflowRuns");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetWorkflowRuns");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetWorkflowRunsResponse, _>(
+                    response,
+                    "GetWorkflowRuns",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetWorkflowRunsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Imports an existing Amazon Athena Data Catalog to AWS Glue</p>
+    fn import_catalog_to_glue(
+        &self,
+        input: ImportCatalogToGlueRequest,
+    ) -> RusotoFuture<ImportCatalogToGlueResponse, ImportCatalogToGlueError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ImportCatalogToGlue");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.ImportCatalogToGlue");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ImportCatalogToGlueResponse, _>(
+                    response,
+                    "ImportCatalogToGlue",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ImportCatalogToGlueError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the names of all crawler resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    fn list_crawlers(
+        &self,
+        input: ListCrawlersRequest,
+    ) -> RusotoFuture<ListCrawlersResponse, ListCrawlersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListCrawlers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.ListCrawlers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListCrawlersResponse, _>(
+                    response,
+                    "ListCrawlers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListCrawlersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the names of all <code>DevEndpoint</code> resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    fn list_dev_endpoints(
+        &self,
+        input: ListDevEndpointsRequest,
+    ) -> RusotoFuture<ListDevEndpointsResponse, ListDevEndpointsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListDevEndpoints");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.ListDevEndpoints");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDevEndpointsResponse, _>(
+                    response,
+                    "ListDevEndpoints",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDevEndpointsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the names of all job resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.ListJobs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListJobsResponse, _>(response, "ListJobs")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the names of all trigger resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    fn list_triggers(
+        &self,
+        input: ListTriggersRequest,
+    ) -> RusotoFuture<ListTriggersResponse, ListTriggersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListTriggers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.ListTriggers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTriggersResponse, _>(
+                    response,
+                    "ListTriggers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTriggersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists names of workflows created in the account.</p>
+    fn list_workflows(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> RusotoFuture<ListWorkflowsResponse, ListWorkflowsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListWorkflows");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.ListWorkflows");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListWorkflowsResponse, _>(
+                    response,
+                    "ListWorkflows",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListWorkflowsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets the security configuration for a specified catalog. After the configuration has been set, the specified encryption is applied to every catalog write thereafter.</p>
+    fn put_data_catalog_encryption_settings(
+        &self,
+        input: PutDataCatalogEncryptionSettingsRequest,
+    ) -> RusotoFuture<PutDataCatalogEncryptionSettingsResponse, PutDataCatalogEncryptionSettingsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("PutDataCatalogEncryptionSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.PutDataCatalogEncryptionSettings");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutDataCatalogEncryptionSettingsResponse, _>(
+                    response,
+                    "PutDataCatalogEncryptionSettings",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutDataCatalogEncryptionSettingsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Sets the Data Catalog resource policy for access control.</p>
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.PutResourcePolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutResourcePolicyResponse, _>(
+                    response,
+                    "PutResourcePolicy",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutResourcePolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Puts the specified workflow run properties for the given workflow run. If a property already exists for the specified run, then it overrides the value otherwise adds the property to existing properties.</p>
+    fn put_workflow_run_properties(
+        &self,
+        input: PutWorkflowRunPropertiesRequest,
+    ) -> RusotoFuture<PutWorkflowRunPropertiesResponse, PutWorkflowRunPropertiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("PutWorkflowRunProperties");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.PutWorkflowRunProperties");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutWorkflowRunPropertiesResponse, _>(
+                    response,
+                    "PutWorkflowRunProperties",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutWorkflowRunPropertiesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Resets a bookmark entry.</p>
+    fn reset_job_bookmark(
+        &self,
+        input: ResetJobBookmarkRequest,
+    ) -> RusotoFuture<ResetJobBookmarkResponse, ResetJobBookmarkError> {
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ResetJobBookmark");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.ResetJobBookmark");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ResetJobBookmarkResponse, _>(
+                    response,
+                    "ResetJobBookmark",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResetJobBookmarkError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Searches a set of tables based on properties in the table metadata as well as on the parent database. You can search against text or filter conditions. </p> <p>You can only get tables that you have access to based on the security policies defined in Lake Formation. You need at least a read-only access to the table for it to be returned. If you do not have access to all the columns in the table, these columns will not be searched against when returning the list of tables back to you. If you have access to the columns but not the data in the columns, those columns and the associated metadata for those columns will be included in the search. </p>
+    fn search_tables(
+        &self,
+        input: SearchTablesRequest,
+    ) -> RusotoFuture<SearchTablesResponse, SearchTablesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("SearchTables");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.SearchTables");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchTablesResponse, _>(
+                    response,
+                    "SearchTables",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchTablesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a crawl using the specified crawler, regardless of what is scheduled. If the crawler is already running, returns a <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-exceptions.html#aws-glue-api-exceptions-CrawlerRunningException">CrawlerRunningException</a>.</p>
+    fn start_crawler(
+        &self,
+        input: StartCrawlerRequest,
+    ) -> RusotoFuture<StartCrawlerResponse, StartCrawlerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartCrawler");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StartCrawler");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartCrawlerResponse, _>(
+                    response,
+                    "StartCrawler",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartCrawlerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes the schedule state of the specified crawler to <code>SCHEDULED</code>, unless the crawler is already running or the schedule state is already <code>SCHEDULED</code>.</p>
+    fn start_crawler_schedule(
+        &self,
+        input: StartCrawlerScheduleRequest,
+    ) -> RusotoFuture<StartCrawlerScheduleResponse, StartCrawlerScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartCrawlerSchedule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StartCrawlerSchedule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartCrawlerScheduleResponse, _>(
+                    response,
+                    "StartCrawlerSchedule",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartCrawlerScheduleError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Begins an asynchronous task to export all labeled data for a particular transform. This task is the only label-related API call that is not part of the typical active learning workflow. You typically use <code>StartExportLabelsTaskRun</code> when you want to work with all of your existing labels at the same time, such as when you want to remove or change labels that were previously submitted as truth. This API operation accepts the <code>TransformId</code> whose labels you want to export and an Amazon Simple Storage Service (Amazon S3) path to export the labels to. The operation returns a <code>TaskRunId</code>. You can check on the status of your task run by calling the <code>GetMLTaskRun</code> API.</p>
+    fn start_export_labels_task_run(
+        &self,
+        input: StartExportLabelsTaskRunRequest,
+    ) -> RusotoFuture<StartExportLabelsTaskRunResponse, StartExportLabelsTaskRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartExportLabelsTaskRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StartExportLabelsTaskRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartExportLabelsTaskRunResponse, _>(
+                    response,
+                    "StartExportLabelsTaskRun",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartExportLabelsTaskRunError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Enables you to provide additional labels (examples of truth) to be used to teach the machine learning transform and improve its quality. This API operation is generally used as part of the active learning workflow that starts with the <code>StartMLLabelingSetGenerationTaskRun</code> call and that ultimately results in improving the quality of your machine learning transform. </p> <p>After the <code>StartMLLabelingSetGenerationTaskRun</code> finishes, AWS Glue machine learning will have generated a series of questions for humans to answer. (Answering these questions is often called 'labeling' in the machine learning workflows). In the case of the <code>FindMatches</code> transform, these questions are of the form, “What is the correct way to group these rows together into groups composed entirely of matching records?” After the labeling process is finished, users upload their answers/labels with a call to <code>StartImportLabelsTaskRun</code>. After <code>StartImportLabelsTaskRun</code> finishes, all future runs of the machine learning transform use the new and improved labels and perform a higher-quality transformation.</p> <p>By default, <code>StartMLLabelingSetGenerationTaskRun</code> continually learns from and combines all labels that you upload unless you set <code>Replace</code> to true. If you set <code>Replace</code> to true, <code>StartImportLabelsTaskRun</code> deletes and forgets all previously uploaded labels and learns only from the exact set that you upload. Replacing labels can be helpful if you realize that you previously uploaded incorrect labels, and you believe that they are having a negative effect on your transform quality.</p> <p>You can check on the status of your task run by calling the <code>GetMLTaskRun</code> operation. </p>
+    fn start_import_labels_task_run(
+        &self,
+        input: StartImportLabelsTaskRunRequest,
+    ) -> RusotoFuture<StartImportLabelsTaskRunResponse, StartImportLabelsTaskRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartImportLabelsTaskRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StartImportLabelsTaskRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartImportLabelsTaskRunResponse, _>(
+                    response,
+                    "StartImportLabelsTaskRun",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartImportLabelsTaskRunError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts a job run using a job definition.</p>
+    fn start_job_run(
+        &self,
+        input: StartJobRunRequest,
+    ) -> RusotoFuture<StartJobRunResponse, StartJobRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartJobRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StartJobRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartJobRunResponse, _>(
+                    response,
+                    "StartJobRun",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartJobRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a task to estimate the quality of the transform. </p> <p>When you provide label sets as examples of truth, AWS Glue machine learning uses some of those examples to learn from them. The rest of the labels are used as a test to estimate quality.</p> <p>Returns a unique identifier for the run. You can call <code>GetMLTaskRun</code> to get more information about the stats of the <code>EvaluationTaskRun</code>.</p>
+    fn start_ml_evaluation_task_run(
+        &self,
+        input: StartMLEvaluationTaskRunRequest,
+    ) -> RusotoFuture<StartMLEvaluationTaskRunResponse, StartMLEvaluationTaskRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartMLEvaluationTaskRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StartMLEvaluationTaskRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartMLEvaluationTaskRunResponse, _>(
+                    response,
+                    "StartMLEvaluationTaskRun",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartMLEvaluationTaskRunError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts the active learning workflow for your machine learning transform to improve the transform's quality by generating label sets and adding labels.</p> <p>When the <code>StartMLLabelingSetGenerationTaskRun</code> finishes, AWS Glue will have generated a "labeling set" or a set of questions for humans to answer.</p> <p>In the case of the <code>FindMatches</code> transform, these questions are of the form, “What is the correct way to group these rows together into groups composed entirely of matching records?” </p> <p>After the labeling process is finished, you can upload your labels with a call to <code>StartImportLabelsTaskRun</code>. After <code>StartImportLabelsTaskRun</code> finishes, all future runs of the machine learning transform will use the new and improved labels and perform a higher-quality transformation.</p>
+    fn start_ml_labeling_set_generation_task_run(
+        &self,
+        input: StartMLLabelingSetGenerationTaskRunRequest,
+    ) -> RusotoFuture<
+        StartMLLabelingSetGenerationTaskRunResponse,
+        StartMLLabelingSetGenerationTaskRunError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartMLLabelingSetGenerationTaskRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSGlue.StartMLLabelingSetGenerationTaskRun",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartMLLabelingSetGenerationTaskRunResponse, _>(response, "StartMLLabelingSetGenerationTaskRun")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartMLLabelingSetGenerationTaskRunError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts an existing trigger. See <a href="https://docs.aws.amazon.com/glue/latest/dg/trigger-job.html">Triggering Jobs</a> for information about how different types of trigger are started.</p>
+    fn start_trigger(
+        &self,
+        input: StartTriggerRequest,
+    ) -> RusotoFuture<StartTriggerResponse, StartTriggerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartTrigger");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StartTrigger");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartTriggerResponse, _>(
+                    response,
+                    "StartTrigger",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartTriggerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a new run of the specified workflow.</p>
+    fn start_workflow_run(
+        &self,
+        input: StartWorkflowRunRequest,
+    ) -> RusotoFuture<StartWorkflowRunResponse, StartWorkflowRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartWorkflowRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StartWorkflowRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartWorkflowRunResponse, _>(
+                    response,
+                    "StartWorkflowRun",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartWorkflowRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>If the specified crawler is running, stops the crawl.</p>
+    fn stop_crawler(
+        &self,
+        input: StopCrawlerRequest,
+    ) -> RusotoFuture<StopCrawlerResponse, StopCrawlerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StopCrawler");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StopCrawler");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopCrawlerResponse, _>(
+                    response,
+                    "StopCrawler",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopCrawlerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets the schedule state of the specified crawler to <code>NOT_SCHEDULED</code>, but does not stop the crawler if it is already running.</p>
+    fn stop_crawler_schedule(
+        &self,
+        input: StopCrawlerScheduleRequest,
+    ) -> RusotoFuture<StopCrawlerScheduleResponse, StopCrawlerScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StopCrawlerSchedule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StopCrawlerSchedule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopCrawlerScheduleResponse, _>(
+                    response,
+                    "StopCrawlerSchedule",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StopCrawlerScheduleError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a specified trigger.</p>
+    fn stop_trigger(
+        &self,
+        input: StopTriggerRequest,
+    ) -> RusotoFuture<StopTriggerResponse, StopTriggerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StopTrigger");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.StopTrigger");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopTriggerResponse, _>(
+                    response,
+                    "StopTrigger",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopTriggerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds tags to a resource. A tag is a label you can assign to an AWS resource. In AWS Glue, you can tag only certain resources. For information about what resources you can tag, see <a href="https://docs.aws.amazon.com/glue/latest/dg/monitor-tags.html">AWS Tags in AWS Glue</a>.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes tags from a resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies an existing classifier (a <code>GrokClassifier</code>, an <code>XMLClassifier</code>, a <code>JsonClassifier</code>, or a <code>CsvClassifier</code>, depending on which field is present).</p>
+    fn update_classifier(
+        &self,
+        input: UpdateClassifierRequest,
+    ) -> RusotoFuture<UpdateClassifierResponse, UpdateClassifierError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateClassifier");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateClassifier");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateClassifierResponse, _>(
+                    response,
+                    "UpdateClassifier",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateClassifierError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a connection definition in the Data Catalog.</p>
+    fn update_connection(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> RusotoFuture<UpdateConnectionResponse, UpdateConnectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateConnection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateConnection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateConnectionResponse, _>(
+                    response,
+                    "UpdateConnection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateConnectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a crawler. If a crawler is running, you must stop it using <code>StopCrawler</code> before updating it.</p>
+    fn update_crawler(
+        &self,
+        input: UpdateCrawlerRequest,
+    ) -> RusotoFuture<UpdateCrawlerResponse, UpdateCrawlerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateCrawler");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateCrawler");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateCrawlerResponse, _>(
+                    response,
+                    "UpdateCrawler",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateCrawlerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the schedule of a crawler using a <code>cron</code> expression. </p>
+    fn update_crawler_schedule(
+        &self,
+        input: UpdateCrawlerScheduleRequest,
+    ) -> RusotoFuture<UpdateCrawlerScheduleResponse, UpdateCrawlerScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateCrawlerSchedule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateCrawlerSchedule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateCrawlerScheduleResponse, _>(
+                    response,
+                    "UpdateCrawlerSchedule",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateCrawlerScheduleError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing database definition in a Data Catalog.</p>
+    fn update_database(
+        &self,
+        input: UpdateDatabaseRequest,
+    ) -> RusotoFuture<UpdateDatabaseResponse, UpdateDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateDatabase");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDatabaseResponse, _>(
+                    response,
+                    "UpdateDatabase",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDatabaseError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a specified development endpoint.</p>
+    fn update_dev_endpoint(
+        &self,
+        input: UpdateDevEndpointRequest,
+    ) -> RusotoFuture<UpdateDevEndpointResponse, UpdateDevEndpointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateDevEndpoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateDevEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDevEndpointResponse, _>(
+                    response,
+                    "UpdateDevEndpoint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDevEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing job definition.</p>
+    fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> RusotoFuture<UpdateJobResponse, UpdateJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateJobResponse, _>(response, "UpdateJob")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing machine learning transform. Call this operation to tune the algorithm parameters to achieve better results.</p> <p>After calling this operation, you can call the <code>StartMLEvaluationTaskRun</code> operation to assess how well your new parameters achieved your goals (such as improving the quality of your machine learning transform, or making it more cost-effective).</p>
+    fn update_ml_transform(
+        &self,
+        input: UpdateMLTransformRequest,
+    ) -> RusotoFuture<UpdateMLTransformResponse, UpdateMLTransformError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateMLTransform");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateMLTransform");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateMLTransformResponse, _>(
+                    response,
+                    "UpdateMLTransform",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateMLTransformError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a partition.</p>
+    fn update_partition(
+        &self,
+        input: UpdatePartitionRequest,
+    ) -> RusotoFuture<UpdatePartitionResponse, UpdatePartitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdatePartition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdatePartition");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdatePartitionResponse, _>(
+                    response,
+                    "UpdatePartition",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdatePartitionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a metadata table in the Data Catalog.</p>
+    fn update_table(
+        &self,
+        input: UpdateTableRequest,
+    ) -> RusotoFuture<UpdateTableResponse, UpdateTableError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateTable");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateTable");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateTableResponse, _>(
+                    response,
+                    "UpdateTable",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateTableError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a trigger definition.</p>
+    fn update_trigger(
+        &self,
+        input: UpdateTriggerRequest,
+    ) -> RusotoFuture<UpdateTriggerResponse, UpdateTriggerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateTrigger");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateTrigger");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateTriggerResponse, _>(
+                    response,
+                    "UpdateTrigger",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateTriggerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an existing function definition in the Data Catalog.</p>
+    fn update_user_defined_function(
+        &self,
+        input: UpdateUserDefinedFunctionRequest,
+    ) -> RusotoFuture<UpdateUserDefinedFunctionResponse, UpdateUserDefinedFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateUserDefinedFunction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateUserDefinedFunction");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateUserDefinedFunctionResponse, _>(
+                    response,
+                    "UpdateUserDefinedFunction",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateUserDefinedFunctionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates an existing workflow.</p>
+    fn update_workflow(
+        &self,
+        input: UpdateWorkflowRequest,
+    ) -> RusotoFuture<UpdateWorkflowResponse, UpdateWorkflowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateWorkflow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.UpdateWorkflow");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateWorkflowResponse, _>(
+                    response,
+                    "UpdateWorkflow",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateWorkflowError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the AWS Glue API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockGlue {
+    batch_create_partition: Option<
+        Box<
+            dyn Fn(
+                    BatchCreatePartitionRequest,
+                ) -> ::std::result::Result<
+                    BatchCreatePartitionResponse,
+                    RusotoError<BatchCreatePartitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_delete_connection: Option<
+        Box<
+            dyn Fn(
+                    BatchDeleteConnectionRequest,
+                ) -> ::std::result::Result<
+                    BatchDeleteConnectionResponse,
+                    RusotoError<BatchDeleteConnectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_delete_partition: Option<
+        Box<
+            dyn Fn(
+                    BatchDeletePartitionRequest,
+                ) -> ::std::result::Result<
+                    BatchDeletePartitionResponse,
+                    RusotoError<BatchDeletePartitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_delete_table: Option<
+        Box<
+            dyn Fn(
+                    BatchDeleteTableRequest,
+                ) -> ::std::result::Result<
+                    BatchDeleteTableResponse,
+                    RusotoError<BatchDeleteTableError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_delete_table_version: Option<
+        Box<
+            dyn Fn(
+                    BatchDeleteTableVersionRequest,
+                ) -> ::std::result::Result<
+                    BatchDeleteTableVersionResponse,
+                    RusotoError<BatchDeleteTableVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_crawlers: Option<
+        Box<
+            dyn Fn(
+                    BatchGetCrawlersRequest,
+                ) -> ::std::result::Result<
+                    BatchGetCrawlersResponse,
+                    RusotoError<BatchGetCrawlersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_dev_endpoints: Option<
+        Box<
+            dyn Fn(
+                    BatchGetDevEndpointsRequest,
+                ) -> ::std::result::Result<
+                    BatchGetDevEndpointsResponse,
+                    RusotoError<BatchGetDevEndpointsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_jobs: Option<
+        Box<
+            dyn Fn(
+                    BatchGetJobsRequest,
+                )
+                    -> ::std::result::Result<BatchGetJobsResponse, RusotoError<BatchGetJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    batch_get_partition: Option<
+        Box<
+            dyn Fn(
+                    BatchGetPartitionRequest,
+                ) -> ::std::result::Result<
+                    BatchGetPartitionResponse,
+                    RusotoError<BatchGetPartitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_triggers: Option<
+        Box<
+            dyn Fn(
+                    BatchGetTriggersRequest,
+                ) -> ::std::result::Result<
+                    BatchGetTriggersResponse,
+                    RusotoError<BatchGetTriggersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_get_workflows: Option<
+        Box<
+            dyn Fn(
+                    BatchGetWorkflowsRequest,
+                ) -> ::std::result::Result<
+                    BatchGetWorkflowsResponse,
+                    RusotoError<BatchGetWorkflowsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_stop_job_run: Option<
+        Box<
+            dyn Fn(
+                    BatchStopJobRunRequest,
+                ) -> ::std::result::Result<
+                    BatchStopJobRunResponse,
+                    RusotoError<GlueBatchStopJobRunError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    cancel_ml_task_run: Option<
+        Box<
+            dyn Fn(
+                    CancelMLTaskRunRequest,
+                ) -> ::std::result::Result<
+                    CancelMLTaskRunResponse,
+                    RusotoError<CancelMLTaskRunError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_classifier: Option<
+        Box<
+            dyn Fn(
+                    CreateClassifierRequest,
+                ) -> ::std::result::Result<
+                    CreateClassifierResponse,
+                    RusotoError<CreateClassifierError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_connection: Option<
+        Box<
+            dyn Fn(
+                    CreateConnectionRequest,
+                ) -> ::std::result::Result<
+                    CreateConnectionResponse,
+                    RusotoError<CreateConnectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_crawler: Option<
+        Box<
+            dyn Fn(
+                    CreateCrawlerRequest,
+                )
+                    -> ::std::result::Result<CreateCrawlerResponse, RusotoError<CreateCrawlerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_database: Option<
+        Box<
+            dyn Fn(
+                    CreateDatabaseRequest,
+                ) -> ::std::result::Result<
+                    CreateDatabaseResponse,
+                    RusotoError<CreateDatabaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_dev_endpoint: Option<
+        Box<
+            dyn Fn(
+                    CreateDevEndpointRequest,
+                ) -> ::std::result::Result<
+                    CreateDevEndpointResponse,
+                    RusotoError<CreateDevEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_job: Option<
+        Box<
+            dyn Fn(
+                    CreateJobRequest,
+                )
+                    -> ::std::result::Result<CreateJobResponse, RusotoError<CreateJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_ml_transform: Option<
+        Box<
+            dyn Fn(
+                    CreateMLTransformRequest,
+                ) -> ::std::result::Result<
+                    CreateMLTransformResponse,
+                    RusotoError<CreateMLTransformError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_partition: Option<
+        Box<
+            dyn Fn(
+                    CreatePartitionRequest,
+                ) -> ::std::result::Result<
+                    CreatePartitionResponse,
+                    RusotoError<CreatePartitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_script: Option<
+        Box<
+            dyn Fn(
+                    CreateScriptRequest,
+                )
+                    -> ::std::result::Result<CreateScriptResponse, RusotoError<CreateScriptError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_security_configuration: Option<
+        Box<
+            dyn Fn(
+                    CreateSecurityConfigurationRequest,
+                ) -> ::std::result::Result<
+                    CreateSecurityConfigurationResponse,
+                    RusotoError<CreateSecurityConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_table: Option<
+        Box<
+            dyn Fn(
+                    CreateTableRequest,
+                )
+                    -> ::std::result::Result<CreateTableResponse, RusotoError<CreateTableError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_trigger: Option<
+        Box<
+            dyn Fn(
+                    CreateTriggerRequest,
+                )
+                    -> ::std::result::Result<CreateTriggerResponse, RusotoError<CreateTriggerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_user_defined_function: Option<
+        Box<
+            dyn Fn(
+                    CreateUserDefinedFunctionRequest,
+                ) -> ::std::result::Result<
+                    CreateUserDefinedFunctionResponse,
+                    RusotoError<CreateUserDefinedFunctionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_workflow: Option<
+        Box<
+            dyn Fn(
+                    CreateWorkflowRequest,
+                ) -> ::std::result::Result<
+                    CreateWorkflowResponse,
+                    RusotoError<CreateWorkflowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_classifier: Option<
+        Box<
+            dyn Fn(
+                    DeleteClassifierRequest,
+                ) -> ::std::result::Result<
+                    DeleteClassifierResponse,
+                    RusotoError<DeleteClassifierError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_connection: Option<
+        Box<
+            dyn Fn(
+                    DeleteConnectionRequest,
+                ) -> ::std::result::Result<
+                    DeleteConnectionResponse,
+                    RusotoError<DeleteConnectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_crawler: Option<
+        Box<
+            dyn Fn(
+                    DeleteCrawlerRequest,
+                )
+                    -> ::std::result::Result<DeleteCrawlerResponse, RusotoError<DeleteCrawlerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_database: Option<
+        Box<
+            dyn Fn(
+                    DeleteDatabaseRequest,
+                ) -> ::std::result::Result<
+                    DeleteDatabaseResponse,
+                    RusotoError<DeleteDatabaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_dev_endpoint: Option<
+        Box<
+            dyn Fn(
+                    DeleteDevEndpointRequest,
+                ) -> ::std::result::Result<
+                    DeleteDevEndpointResponse,
+                    RusotoError<DeleteDevEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_job: Option<
+        Box<
+            dyn Fn(
+                    DeleteJobRequest,
+                )
+                    -> ::std::result::Result<DeleteJobResponse, RusotoError<DeleteJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_ml_transform: Option<
+        Box<
+            dyn Fn(
+                    DeleteMLTransformRequest,
+                ) -> ::std::result::Result<
+                    DeleteMLTransformResponse,
+                    RusotoError<DeleteMLTransformError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_partition: Option<
+        Box<
+            dyn Fn(
+                    DeletePartitionRequest,
+                ) -> ::std::result::Result<
+                    DeletePartitionResponse,
+                    RusotoError<DeletePartitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_resource_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteResourcePolicyRequest,
+                ) -> ::std::result::Result<
+                    DeleteResourcePolicyResponse,
+                    RusotoError<DeleteResourcePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_security_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteSecurityConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DeleteSecurityConfigurationResponse,
+                    RusotoError<DeleteSecurityConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_table: Option<
+        Box<
+            dyn Fn(
+                    DeleteTableRequest,
+                )
+                    -> ::std::result::Result<DeleteTableResponse, RusotoError<DeleteTableError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_table_version: Option<
+        Box<
+            dyn Fn(
+                    DeleteTableVersionRequest,
+                ) -> ::std::result::Result<
+                    DeleteTableVersionResponse,
+                    RusotoError<DeleteTableVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_trigger: Option<
+        Box<
+            dyn Fn(
+                    DeleteTriggerRequest,
+                )
+                    -> ::std::result::Result<DeleteTriggerResponse, RusotoError<DeleteTriggerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user_defined_function: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserDefinedFunctionRequest,
+                ) -> ::std::result::Result<
+                    DeleteUserDefinedFunctionResponse,
+                    RusotoError<DeleteUserDefinedFunctionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_workflow: Option<
+        Box<
+            dyn Fn(
+                    DeleteWorkflowRequest,
+                ) -> ::std::result::Result<
+                    DeleteWorkflowResponse,
+                    RusotoError<DeleteWorkflowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_catalog_import_status: Option<
+        Box<
+            dyn Fn(
+                    GetCatalogImportStatusRequest,
+                ) -> ::std::result::Result<
+                    GetCatalogImportStatusResponse,
+                    RusotoError<GetCatalogImportStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_classifier: Option<
+        Box<
+            dyn Fn(
+                    GetClassifierRequest,
+                )
+                    -> ::std::result::Result<GetClassifierResponse, RusotoError<GetClassifierError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_classifiers: Option<
+        Box<
+            dyn Fn(
+                    GetClassifiersRequest,
+                ) -> ::std::result::Result<
+                    GetClassifiersResponse,
+                    RusotoError<GetClassifiersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_connection: Option<
+        Box<
+            dyn Fn(
+                    GetConnectionRequest,
+                )
+                    -> ::std::result::Result<GetConnectionResponse, RusotoError<GetConnectionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_connections: Option<
+        Box<
+            dyn Fn(
+                    GetConnectionsRequest,
+                ) -> ::std::result::Result<
+                    GetConnectionsResponse,
+                    RusotoError<GetConnectionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_crawler: Option<
+        Box<
+            dyn Fn(
+                    GetCrawlerRequest,
+                )
+                    -> ::std::result::Result<GetCrawlerResponse, RusotoError<GetCrawlerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_crawler_metrics: Option<
+        Box<
+            dyn Fn(
+                    GetCrawlerMetricsRequest,
+                ) -> ::std::result::Result<
+                    GetCrawlerMetricsResponse,
+                    RusotoError<GetCrawlerMetricsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_crawlers: Option<
+        Box<
+            dyn Fn(
+                    GetCrawlersRequest,
+                )
+                    -> ::std::result::Result<GetCrawlersResponse, RusotoError<GetCrawlersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_data_catalog_encryption_settings: Option<
+        Box<
+            dyn Fn(
+                    GetDataCatalogEncryptionSettingsRequest,
+                ) -> ::std::result::Result<
+                    GetDataCatalogEncryptionSettingsResponse,
+                    RusotoError<GetDataCatalogEncryptionSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_database: Option<
+        Box<
+            dyn Fn(
+                    GetDatabaseRequest,
+                )
+                    -> ::std::result::Result<GetDatabaseResponse, RusotoError<GetDatabaseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_databases: Option<
+        Box<
+            dyn Fn(
+                    GetDatabasesRequest,
+                )
+                    -> ::std::result::Result<GetDatabasesResponse, RusotoError<GetDatabasesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_dataflow_graph: Option<
+        Box<
+            dyn Fn(
+                    GetDataflowGraphRequest,
+                ) -> ::std::result::Result<
+                    GetDataflowGraphResponse,
+                    RusotoError<GetDataflowGraphError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_dev_endpoint: Option<
+        Box<
+            dyn Fn(
+                    GetDevEndpointRequest,
+                ) -> ::std::result::Result<
+                    GetDevEndpointResponse,
+                    RusotoError<GetDevEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_dev_endpoints: Option<
+        Box<
+            dyn Fn(
+                    GetDevEndpointsRequest,
+                ) -> ::std::result::Result<
+                    GetDevEndpointsResponse,
+                    RusotoError<GetDevEndpointsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_job: Option<
+        Box<
+            dyn Fn(GetJobRequest) -> ::std::result::Result<GetJobResponse, RusotoError<GetJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_job_bookmark: Option<
+        Box<
+            dyn Fn(
+                    GetJobBookmarkRequest,
+                ) -> ::std::result::Result<
+                    GetJobBookmarkResponse,
+                    RusotoError<GetJobBookmarkError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_job_run: Option<
+        Box<
+            dyn Fn(
+                    GetJobRunRequest,
+                )
+                    -> ::std::result::Result<GetJobRunResponse, RusotoError<GetJobRunError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_job_runs: Option<
+        Box<
+            dyn Fn(
+                    GetJobRunsRequest,
+                )
+                    -> ::std::result::Result<GetJobRunsResponse, RusotoError<GetJobRunsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_jobs: Option<
+        Box<
+            dyn Fn(
+                    GetJobsRequest,
+                )
+                    -> ::std::result::Result<GetJobsResponse, RusotoError<GetJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_ml_task_run: Option<
+        Box<
+            dyn Fn(
+                    GetMLTaskRunRequest,
+                )
+                    -> ::std::result::Result<GetMLTaskRunResponse, RusotoError<GetMLTaskRunError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_ml_task_runs: Option<
+        Box<
+            dyn Fn(
+                    GetMLTaskRunsRequest,
+                )
+                    -> ::std::result::Result<GetMLTaskRunsResponse, RusotoError<GetMLTaskRunsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_ml_transform: Option<
+        Box<
+            dyn Fn(
+                    GetMLTransformRequest,
+                ) -> ::std::result::Result<
+                    GetMLTransformResponse,
+                    RusotoError<GetMLTransformError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_ml_transforms: Option<
+        Box<
+            dyn Fn(
+                    GetMLTransformsRequest,
+                ) -> ::std::result::Result<
+                    GetMLTransformsResponse,
+                    RusotoError<GetMLTransformsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_mapping: Option<
+        Box<
+            dyn Fn(
+                    GetMappingRequest,
+                )
+                    -> ::std::result::Result<GetMappingResponse, RusotoError<GetMappingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_partition: Option<
+        Box<
+            dyn Fn(
+                    GetPartitionRequest,
+                )
+                    -> ::std::result::Result<GetPartitionResponse, RusotoError<GetPartitionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_partitions: Option<
+        Box<
+            dyn Fn(
+                    GetPartitionsRequest,
+                )
+                    -> ::std::result::Result<GetPartitionsResponse, RusotoError<GetPartitionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_plan: Option<
+        Box<
+            dyn Fn(
+                    GetPlanRequest,
+                )
+                    -> ::std::result::Result<GetPlanResponse, RusotoError<GetPlanError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_resource_policy: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetResourcePolicyResponse,
+                    RusotoError<GetResourcePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_security_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetSecurityConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetSecurityConfigurationResponse,
+                    RusotoError<GetSecurityConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_security_configurations: Option<
+        Box<
+            dyn Fn(
+                    GetSecurityConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    GetSecurityConfigurationsResponse,
+                    RusotoError<GetSecurityConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_table: Option<
+        Box<
+            dyn Fn(
+                    GetTableRequest,
+                )
+                    -> ::std::result::Result<GetTableResponse, RusotoError<GetTableError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_table_version: Option<
+        Box<
+            dyn Fn(
+                    GetTableVersionRequest,
+                ) -> ::std::result::Result<
+                    GetTableVersionResponse,
+                    RusotoError<GetTableVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_table_versions: Option<
+        Box<
+            dyn Fn(
+                    GetTableVersionsRequest,
+                ) -> ::std::result::Result<
+                    GetTableVersionsResponse,
+                    RusotoError<GetTableVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_tables: Option<
+        Box<
+            dyn Fn(
+                    GetTablesRequest,
+                )
+                    -> ::std::result::Result<GetTablesResponse, RusotoError<GetTablesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_tags: Option<
+        Box<
+            dyn Fn(
+                    GetTagsRequest,
+                )
+                    -> ::std::result::Result<GetTagsResponse, RusotoError<GetTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_trigger: Option<
+        Box<
+            dyn Fn(
+                    GetTriggerRequest,
+                )
+                    -> ::std::result::Result<GetTriggerResponse, RusotoError<GetTriggerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_triggers: Option<
+        Box<
+            dyn Fn(
+                    GetTriggersRequest,
+                )
+                    -> ::std::result::Result<GetTriggersResponse, RusotoError<GetTriggersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_user_defined_function: Option<
+        Box<
+            dyn Fn(
+                    GetUserDefinedFunctionRequest,
+                ) -> ::std::result::Result<
+                    GetUserDefinedFunctionResponse,
+                    RusotoError<GetUserDefinedFunctionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_user_defined_functions: Option<
+        Box<
+            dyn Fn(
+                    GetUserDefinedFunctionsRequest,
+                ) -> ::std::result::Result<
+                    GetUserDefinedFunctionsResponse,
+                    RusotoError<GetUserDefinedFunctionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_workflow: Option<
+        Box<
+            dyn Fn(
+                    GetWorkflowRequest,
+                )
+                    -> ::std::result::Result<GetWorkflowResponse, RusotoError<GetWorkflowError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_workflow_run: Option<
+        Box<
+            dyn Fn(
+                    GetWorkflowRunRequest,
+                ) -> ::std::result::Result<
+                    GetWorkflowRunResponse,
+                    RusotoError<GetWorkflowRunError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_workflow_run_properties: Option<
+        Box<
+            dyn Fn(
+                    GetWorkflowRunPropertiesRequest,
+                ) -> ::std::result::Result<
+                    GetWorkflowRunPropertiesResponse,
+                    RusotoError<GetWorkflowRunPropertiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_workflow_runs: Option<
+        Box<
+            dyn Fn(
+                    GetWorkflowRunsRequest,
+                ) -> ::std::result::Result<
+                    GetWorkflowRunsResponse,
+                    RusotoError<GetWorkflowRunsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    import_catalog_to_glue: Option<
+        Box<
+            dyn Fn(
+                    ImportCatalogToGlueRequest,
+                ) -> ::std::result::Result<
+                    ImportCatalogToGlueResponse,
+                    RusotoError<ImportCatalogToGlueError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_crawlers: Option<
+        Box<
+            dyn Fn(
+                    ListCrawlersRequest,
+                )
+                    -> ::std::result::Result<ListCrawlersResponse, RusotoError<ListCrawlersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_dev_endpoints: Option<
+        Box<
+            dyn Fn(
+                    ListDevEndpointsRequest,
+                ) -> ::std::result::Result<
+                    ListDevEndpointsResponse,
+                    RusotoError<ListDevEndpointsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListJobsRequest,
+                )
+                    -> ::std::result::Result<ListJobsResponse, RusotoError<ListJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_triggers: Option<
+        Box<
+            dyn Fn(
+                    ListTriggersRequest,
+                )
+                    -> ::std::result::Result<ListTriggersResponse, RusotoError<ListTriggersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_workflows: Option<
+        Box<
+            dyn Fn(
+                    ListWorkflowsRequest,
+                )
+                    -> ::std::result::Result<ListWorkflowsResponse, RusotoError<ListWorkflowsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_data_catalog_encryption_settings: Option<
+        Box<
+            dyn Fn(
+                    PutDataCatalogEncryptionSettingsRequest,
+                ) -> ::std::result::Result<
+                    PutDataCatalogEncryptionSettingsResponse,
+                    RusotoError<PutDataCatalogEncryptionSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_resource_policy: Option<
+        Box<
+            dyn Fn(
+                    PutResourcePolicyRequest,
+                ) -> ::std::result::Result<
+                    PutResourcePolicyResponse,
+                    RusotoError<PutResourcePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_workflow_run_properties: Option<
+        Box<
+            dyn Fn(
+                    PutWorkflowRunPropertiesRequest,
+                ) -> ::std::result::Result<
+                    PutWorkflowRunPropertiesResponse,
+                    RusotoError<PutWorkflowRunPropertiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reset_job_bookmark: Option<
+        Box<
+            dyn Fn(
+                    ResetJobBookmarkRequest,
+                ) -> ::std::result::Result<
+                    ResetJobBookmarkResponse,
+                    RusotoError<ResetJobBookmarkError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_tables: Option<
+        Box<
+            dyn Fn(
+                    SearchTablesRequest,
+                )
+                    -> ::std::result::Result<SearchTablesResponse, RusotoError<SearchTablesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_crawler: Option<
+        Box<
+            dyn Fn(
+                    StartCrawlerRequest,
+                )
+                    -> ::std::result::Result<StartCrawlerResponse, RusotoError<StartCrawlerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_crawler_schedule: Option<
+        Box<
+            dyn Fn(
+                    StartCrawlerScheduleRequest,
+                ) -> ::std::result::Result<
+                    StartCrawlerScheduleResponse,
+                    RusotoError<StartCrawlerScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_export_labels_task_run: Option<
+        Box<
+            dyn Fn(
+                    StartExportLabelsTaskRunRequest,
+                ) -> ::std::result::Result<
+                    StartExportLabelsTaskRunResponse,
+                    RusotoError<StartExportLabelsTaskRunError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_import_labels_task_run: Option<
+        Box<
+            dyn Fn(
+                    StartImportLabelsTaskRunRequest,
+                ) -> ::std::result::Result<
+                    StartImportLabelsTaskRunResponse,
+                    RusotoError<StartImportLabelsTaskRunError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_job_run: Option<
+        Box<
+            dyn Fn(
+                    StartJobRunRequest,
+                )
+                    -> ::std::result::Result<StartJobRunResponse, RusotoError<StartJobRunError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_ml_evaluation_task_run: Option<
+        Box<
+            dyn Fn(
+                    StartMLEvaluationTaskRunRequest,
+                ) -> ::std::result::Result<
+                    StartMLEvaluationTaskRunResponse,
+                    RusotoError<StartMLEvaluationTaskRunError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_ml_labeling_set_generation_task_run: Option<
+        Box<
+            dyn Fn(
+                    StartMLLabelingSetGenerationTaskRunRequest,
+                ) -> ::std::result::Result<
+                    StartMLLabelingSetGenerationTaskRunResponse,
+                    RusotoError<StartMLLabelingSetGenerationTaskRunError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_trigger: Option<
+        Box<
+            dyn Fn(
+                    StartTriggerRequest,
+                )
+                    -> ::std::result::Result<StartTriggerResponse, RusotoError<StartTriggerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_workflow_run: Option<
+        Box<
+            dyn Fn(
+                    StartWorkflowRunRequest,
+                ) -> ::std::result::Result<
+                    StartWorkflowRunResponse,
+                    RusotoError<StartWorkflowRunError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_crawler: Option<
+        Box<
+            dyn Fn(
+                    StopCrawlerRequest,
+                )
+                    -> ::std::result::Result<StopCrawlerResponse, RusotoError<StopCrawlerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_crawler_schedule: Option<
+        Box<
+            dyn Fn(
+                    StopCrawlerScheduleRequest,
+                ) -> ::std::result::Result<
+                    StopCrawlerScheduleResponse,
+                    RusotoError<StopCrawlerScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_trigger: Option<
+        Box<
+            dyn Fn(
+                    StopTriggerRequest,
+                )
+                    -> ::std::result::Result<StopTriggerResponse, RusotoError<StopTriggerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_classifier: Option<
+        Box<
+            dyn Fn(
+                    UpdateClassifierRequest,
+                ) -> ::std::result::Result<
+                    UpdateClassifierResponse,
+                    RusotoError<UpdateClassifierError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_connection: Option<
+        Box<
+            dyn Fn(
+                    UpdateConnectionRequest,
+                ) -> ::std::result::Result<
+                    UpdateConnectionResponse,
+                    RusotoError<UpdateConnectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_crawler: Option<
+        Box<
+            dyn Fn(
+                    UpdateCrawlerRequest,
+                )
+                    -> ::std::result::Result<UpdateCrawlerResponse, RusotoError<UpdateCrawlerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_crawler_schedule: Option<
+        Box<
+            dyn Fn(
+                    UpdateCrawlerScheduleRequest,
+                ) -> ::std::result::Result<
+                    UpdateCrawlerScheduleResponse,
+                    RusotoError<UpdateCrawlerScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_database: Option<
+        Box<
+            dyn Fn(
+                    UpdateDatabaseRequest,
+                ) -> ::std::result::Result<
+                    UpdateDatabaseResponse,
+                    RusotoError<UpdateDatabaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_dev_endpoint: Option<
+        Box<
+            dyn Fn(
+                    UpdateDevEndpointRequest,
+                ) -> ::std::result::Result<
+                    UpdateDevEndpointResponse,
+                    RusotoError<UpdateDevEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_job: Option<
+        Box<
+            dyn Fn(
+                    UpdateJobRequest,
+                )
+                    -> ::std::result::Result<UpdateJobResponse, RusotoError<UpdateJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_ml_transform: Option<
+        Box<
+            dyn Fn(
+                    UpdateMLTransformRequest,
+                ) -> ::std::result::Result<
+                    UpdateMLTransformResponse,
+                    RusotoError<UpdateMLTransformError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_partition: Option<
+        Box<
+            dyn Fn(
+                    UpdatePartitionRequest,
+                ) -> ::std::result::Result<
+                    UpdatePartitionResponse,
+                    RusotoError<UpdatePartitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_table: Option<
+        Box<
+            dyn Fn(
+                    UpdateTableRequest,
+                )
+                    -> ::std::result::Result<UpdateTableResponse, RusotoError<UpdateTableError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_trigger: Option<
+        Box<
+            dyn Fn(
+                    UpdateTriggerRequest,
+                )
+                    -> ::std::result::Result<UpdateTriggerResponse, RusotoError<UpdateTriggerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_user_defined_function: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserDefinedFunctionRequest,
+                ) -> ::std::result::Result<
+                    UpdateUserDefinedFunctionResponse,
+                    RusotoError<UpdateUserDefinedFunctionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_workflow: Option<
+        Box<
+            dyn Fn(
+                    UpdateWorkflowRequest,
+                ) -> ::std::result::Result<
+                    UpdateWorkflowResponse,
+                    RusotoError<UpdateWorkflowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockGlue {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockGlue {
+        Default::default()
+    }
+
+    /// Answers `batch_create_partition` with what `handler` returns for its input.
+    pub fn with_batch_create_partition<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchCreatePartitionRequest,
+            ) -> ::std::result::Result<
+                BatchCreatePartitionResponse,
+                RusotoError<BatchCreatePartitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_create_partition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_delete_connection` with what `handler` returns for its input.
+    pub fn with_batch_delete_connection<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchDeleteConnectionRequest,
+            ) -> ::std::result::Result<
+                BatchDeleteConnectionResponse,
+                RusotoError<BatchDeleteConnectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_delete_partition` with what `handler` returns for its input.
+    pub fn with_batch_delete_partition<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchDeletePartitionRequest,
+            ) -> ::std::result::Result<
+                BatchDeletePartitionResponse,
+                RusotoError<BatchDeletePartitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_partition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_delete_table` with what `handler` returns for its input.
+    pub fn with_batch_delete_table<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchDeleteTableRequest,
+            ) -> ::std::result::Result<
+                BatchDeleteTableResponse,
+                RusotoError<BatchDeleteTableError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_delete_table_version` with what `handler` returns for its input.
+    pub fn with_batch_delete_table_version<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchDeleteTableVersionRequest,
+            ) -> ::std::result::Result<
+                BatchDeleteTableVersionResponse,
+                RusotoError<BatchDeleteTableVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_table_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_crawlers` with what `handler` returns for its input.
+    pub fn with_batch_get_crawlers<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchGetCrawlersRequest,
+            ) -> ::std::result::Result<
+                BatchGetCrawlersResponse,
+                RusotoError<BatchGetCrawlersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_crawlers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_dev_endpoints` with what `handler` returns for its input.
+    pub fn with_batch_get_dev_endpoints<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchGetDevEndpointsRequest,
+            ) -> ::std::result::Result<
+                BatchGetDevEndpointsResponse,
+                RusotoError<BatchGetDevEndpointsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_dev_endpoints = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_jobs` with what `handler` returns for its input.
+    pub fn with_batch_get_jobs<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchGetJobsRequest,
+            )
+                -> ::std::result::Result<BatchGetJobsResponse, RusotoError<BatchGetJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_partition` with what `handler` returns for its input.
+    pub fn with_batch_get_partition<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchGetPartitionRequest,
+            ) -> ::std::result::Result<
+                BatchGetPartitionResponse,
+                RusotoError<BatchGetPartitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_partition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_triggers` with what `handler` returns for its input.
+    pub fn with_batch_get_triggers<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchGetTriggersRequest,
+            ) -> ::std::result::Result<
+                BatchGetTriggersResponse,
+                RusotoError<BatchGetTriggersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_triggers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_get_workflows` with what `handler` returns for its input.
+    pub fn with_batch_get_workflows<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchGetWorkflowsRequest,
+            ) -> ::std::result::Result<
+                BatchGetWorkflowsResponse,
+                RusotoError<BatchGetWorkflowsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_workflows = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_stop_job_run` with what `handler` returns for its input.
+    pub fn with_batch_stop_job_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                BatchStopJobRunRequest,
+            ) -> ::std::result::Result<
+                BatchStopJobRunResponse,
+                RusotoError<GlueBatchStopJobRunError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_stop_job_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_ml_task_run` with what `handler` returns for its input.
+    pub fn with_cancel_ml_task_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CancelMLTaskRunRequest,
+            )
+                -> ::std::result::Result<CancelMLTaskRunResponse, RusotoError<CancelMLTaskRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_ml_task_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_classifier` with what `handler` returns for its input.
+    pub fn with_create_classifier<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateClassifierRequest,
+            ) -> ::std::result::Result<
+                CreateClassifierResponse,
+                RusotoError<CreateClassifierError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_classifier = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_connection` with what `handler` returns for its input.
+    pub fn with_create_connection<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateConnectionRequest,
+            ) -> ::std::result::Result<
+                CreateConnectionResponse,
+                RusotoError<CreateConnectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_crawler` with what `handler` returns for its input.
+    pub fn with_create_crawler<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateCrawlerRequest,
+            )
+                -> ::std::result::Result<CreateCrawlerResponse, RusotoError<CreateCrawlerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_crawler = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_database` with what `handler` returns for its input.
+    pub fn with_create_database<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateDatabaseRequest,
+            )
+                -> ::std::result::Result<CreateDatabaseResponse, RusotoError<CreateDatabaseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_dev_endpoint` with what `handler` returns for its input.
+    pub fn with_create_dev_endpoint<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateDevEndpointRequest,
+            ) -> ::std::result::Result<
+                CreateDevEndpointResponse,
+                RusotoError<CreateDevEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_dev_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_job` with what `handler` returns for its input.
+    pub fn with_create_job<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateJobRequest,
+            ) -> ::std::result::Result<CreateJobResponse, RusotoError<CreateJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_ml_transform` with what `handler` returns for its input.
+    pub fn with_create_ml_transform<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateMLTransformRequest,
+            ) -> ::std::result::Result<
+                CreateMLTransformResponse,
+                RusotoError<CreateMLTransformError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_ml_transform = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_partition` with what `handler` returns for its input.
+    pub fn with_create_partition<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreatePartitionRequest,
+            )
+                -> ::std::result::Result<CreatePartitionResponse, RusotoError<CreatePartitionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_partition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_script` with what `handler` returns for its input.
+    pub fn with_create_script<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateScriptRequest,
+            )
+                -> ::std::result::Result<CreateScriptResponse, RusotoError<CreateScriptError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_script = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_security_configuration` with what `handler` returns for its input.
+    pub fn with_create_security_configuration<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateSecurityConfigurationRequest,
+            ) -> ::std::result::Result<
+                CreateSecurityConfigurationResponse,
+                RusotoError<CreateSecurityConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_security_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_table` with what `handler` returns for its input.
+    pub fn with_create_table<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateTableRequest,
+            )
+                -> ::std::result::Result<CreateTableResponse, RusotoError<CreateTableError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_trigger` with what `handler` returns for its input.
+    pub fn with_create_trigger<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateTriggerRequest,
+            )
+                -> ::std::result::Result<CreateTriggerResponse, RusotoError<CreateTriggerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_trigger = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user_defined_function` with what `handler` returns for its input.
+    pub fn with_create_user_defined_function<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateUserDefinedFunctionRequest,
+            ) -> ::std::result::Result<
+                CreateUserDefinedFunctionResponse,
+                RusotoError<CreateUserDefinedFunctionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user_defined_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_workflow` with what `handler` returns for its input.
+    pub fn with_create_workflow<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                CreateWorkflowRequest,
+            )
+                -> ::std::result::Result<CreateWorkflowResponse, RusotoError<CreateWorkflowError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_workflow = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_classifier` with what `handler` returns for its input.
+    pub fn with_delete_classifier<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteClassifierRequest,
+            ) -> ::std::result::Result<
+                DeleteClassifierResponse,
+                RusotoError<DeleteClassifierError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_classifier = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_connection` with what `handler` returns for its input.
+    pub fn with_delete_connection<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteConnectionRequest,
+            ) -> ::std::result::Result<
+                DeleteConnectionResponse,
+                RusotoError<DeleteConnectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_crawler` with what `handler` returns for its input.
+    pub fn with_delete_crawler<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteCrawlerRequest,
+            )
+                -> ::std::result::Result<DeleteCrawlerResponse, RusotoError<DeleteCrawlerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_crawler = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_database` with what `handler` returns for its input.
+    pub fn with_delete_database<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteDatabaseRequest,
+            )
+                -> ::std::result::Result<DeleteDatabaseResponse, RusotoError<DeleteDatabaseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_dev_endpoint` with what `handler` returns for its input.
+    pub fn with_delete_dev_endpoint<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteDevEndpointRequest,
+            ) -> ::std::result::Result<
+                DeleteDevEndpointResponse,
+                RusotoError<DeleteDevEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_dev_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_job` with what `handler` returns for its input.
+    pub fn with_delete_job<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteJobRequest,
+            ) -> ::std::result::Result<DeleteJobResponse, RusotoError<DeleteJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_ml_transform` with what `handler` returns for its input.
+    pub fn with_delete_ml_transform<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteMLTransformRequest,
+            ) -> ::std::result::Result<
+                DeleteMLTransformResponse,
+                RusotoError<DeleteMLTransformError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_ml_transform = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_partition` with what `handler` returns for its input.
+    pub fn with_delete_partition<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeletePartitionRequest,
+            )
+                -> ::std::result::Result<DeletePartitionResponse, RusotoError<DeletePartitionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_partition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_resource_policy` with what `handler` returns for its input.
+    pub fn with_delete_resource_policy<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteResourcePolicyRequest,
+            ) -> ::std::result::Result<
+                DeleteResourcePolicyResponse,
+                RusotoError<DeleteResourcePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_resource_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_security_configuration` with what `handler` returns for its input.
+    pub fn with_delete_security_configuration<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteSecurityConfigurationRequest,
+            ) -> ::std::result::Result<
+                DeleteSecurityConfigurationResponse,
+                RusotoError<DeleteSecurityConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_security_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_table` with what `handler` returns for its input.
+    pub fn with_delete_table<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteTableRequest,
+            )
+                -> ::std::result::Result<DeleteTableResponse, RusotoError<DeleteTableError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_table_version` with what `handler` returns for its input.
+    pub fn with_delete_table_version<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteTableVersionRequest,
+            ) -> ::std::result::Result<
+                DeleteTableVersionResponse,
+                RusotoError<DeleteTableVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_table_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_trigger` with what `handler` returns for its input.
+    pub fn with_delete_trigger<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteTriggerRequest,
+            )
+                -> ::std::result::Result<DeleteTriggerResponse, RusotoError<DeleteTriggerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_trigger = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user_defined_function` with what `handler` returns for its input.
+    pub fn with_delete_user_defined_function<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteUserDefinedFunctionRequest,
+            ) -> ::std::result::Result<
+                DeleteUserDefinedFunctionResponse,
+                RusotoError<DeleteUserDefinedFunctionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user_defined_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_workflow` with what `handler` returns for its input.
+    pub fn with_delete_workflow<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                DeleteWorkflowRequest,
+            )
+                -> ::std::result::Result<DeleteWorkflowResponse, RusotoError<DeleteWorkflowError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_workflow = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_catalog_import_status` with what `handler` returns for its input.
+    pub fn with_get_catalog_import_status<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetCatalogImportStatusRequest,
+            ) -> ::std::result::Result<
+                GetCatalogImportStatusResponse,
+                RusotoError<GetCatalogImportStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_catalog_import_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_classifier` with what `handler` returns for its input.
+    pub fn with_get_classifier<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetClassifierRequest,
+            )
+                -> ::std::result::Result<GetClassifierResponse, RusotoError<GetClassifierError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_classifier = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_classifiers` with what `handler` returns for its input.
+    pub fn with_get_classifiers<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetClassifiersRequest,
+            )
+                -> ::std::result::Result<GetClassifiersResponse, RusotoError<GetClassifiersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_classifiers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_connection` with what `handler` returns for its input.
+    pub fn with_get_connection<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetConnectionRequest,
+            )
+                -> ::std::result::Result<GetConnectionResponse, RusotoError<GetConnectionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_connections` with what `handler` returns for its input.
+    pub fn with_get_connections<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetConnectionsRequest,
+            )
+                -> ::std::result::Result<GetConnectionsResponse, RusotoError<GetConnectionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_connections = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_crawler` with what `handler` returns for its input.
+    pub fn with_get_crawler<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetCrawlerRequest,
+            )
+                -> ::std::result::Result<GetCrawlerResponse, RusotoError<GetCrawlerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_crawler = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_crawler_metrics` with what `handler` returns for its input.
+    pub fn with_get_crawler_metrics<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetCrawlerMetricsRequest,
+            ) -> ::std::result::Result<
+                GetCrawlerMetricsResponse,
+                RusotoError<GetCrawlerMetricsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_crawler_metrics = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_crawlers` with what `handler` returns for its input.
+    pub fn with_get_crawlers<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetCrawlersRequest,
+            )
+                -> ::std::result::Result<GetCrawlersResponse, RusotoError<GetCrawlersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_crawlers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_data_catalog_encryption_settings` with what `handler` returns for its input.
+    pub fn with_get_data_catalog_encryption_settings<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetDataCatalogEncryptionSettingsRequest,
+            ) -> ::std::result::Result<
+                GetDataCatalogEncryptionSettingsResponse,
+                RusotoError<GetDataCatalogEncryptionSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_data_catalog_encryption_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_database` with what `handler` returns for its input.
+    pub fn with_get_database<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetDatabaseRequest,
+            )
+                -> ::std::result::Result<GetDatabaseResponse, RusotoError<GetDatabaseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_databases` with what `handler` returns for its input.
+    pub fn with_get_databases<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetDatabasesRequest,
+            )
+                -> ::std::result::Result<GetDatabasesResponse, RusotoError<GetDatabasesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_databases = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_dataflow_graph` with what `handler` returns for its input.
+    pub fn with_get_dataflow_graph<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetDataflowGraphRequest,
+            ) -> ::std::result::Result<
+                GetDataflowGraphResponse,
+                RusotoError<GetDataflowGraphError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_dataflow_graph = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_dev_endpoint` with what `handler` returns for its input.
+    pub fn with_get_dev_endpoint<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetDevEndpointRequest,
+            )
+                -> ::std::result::Result<GetDevEndpointResponse, RusotoError<GetDevEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_dev_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_dev_endpoints` with what `handler` returns for its input.
+    pub fn with_get_dev_endpoints<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetDevEndpointsRequest,
+            )
+                -> ::std::result::Result<GetDevEndpointsResponse, RusotoError<GetDevEndpointsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_dev_endpoints = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job` with what `handler` returns for its input.
+    pub fn with_get_job<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(GetJobRequest) -> ::std::result::Result<GetJobResponse, RusotoError<GetJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job_bookmark` with what `handler` returns for its input.
+    pub fn with_get_job_bookmark<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetJobBookmarkRequest,
+            )
+                -> ::std::result::Result<GetJobBookmarkResponse, RusotoError<GetJobBookmarkError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job_bookmark = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job_run` with what `handler` returns for its input.
+    pub fn with_get_job_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetJobRunRequest,
+            ) -> ::std::result::Result<GetJobRunResponse, RusotoError<GetJobRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job_runs` with what `handler` returns for its input.
+    pub fn with_get_job_runs<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetJobRunsRequest,
+            )
+                -> ::std::result::Result<GetJobRunsResponse, RusotoError<GetJobRunsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job_runs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_jobs` with what `handler` returns for its input.
+    pub fn with_get_jobs<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(GetJobsRequest) -> ::std::result::Result<GetJobsResponse, RusotoError<GetJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_ml_task_run` with what `handler` returns for its input.
+    pub fn with_get_ml_task_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetMLTaskRunRequest,
+            )
+                -> ::std::result::Result<GetMLTaskRunResponse, RusotoError<GetMLTaskRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_ml_task_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_ml_task_runs` with what `handler` returns for its input.
+    pub fn with_get_ml_task_runs<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetMLTaskRunsRequest,
+            )
+                -> ::std::result::Result<GetMLTaskRunsResponse, RusotoError<GetMLTaskRunsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_ml_task_runs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_ml_transform` with what `handler` returns for its input.
+    pub fn with_get_ml_transform<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetMLTransformRequest,
+            )
+                -> ::std::result::Result<GetMLTransformResponse, RusotoError<GetMLTransformError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_ml_transform = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_ml_transforms` with what `handler` returns for its input.
+    pub fn with_get_ml_transforms<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetMLTransformsRequest,
+            )
+                -> ::std::result::Result<GetMLTransformsResponse, RusotoError<GetMLTransformsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_ml_transforms = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_mapping` with what `handler` returns for its input.
+    pub fn with_get_mapping<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetMappingRequest,
+            )
+                -> ::std::result::Result<GetMappingResponse, RusotoError<GetMappingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_partition` with what `handler` returns for its input.
+    pub fn with_get_partition<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetPartitionRequest,
+            )
+                -> ::std::result::Result<GetPartitionResponse, RusotoError<GetPartitionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_partition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_partitions` with what `handler` returns for its input.
+    pub fn with_get_partitions<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetPartitionsRequest,
+            )
+                -> ::std::result::Result<GetPartitionsResponse, RusotoError<GetPartitionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_partitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_plan` with what `handler` returns for its input.
+    pub fn with_get_plan<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(GetPlanRequest) -> ::std::result::Result<GetPlanResponse, RusotoError<GetPlanError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource_policy` with what `handler` returns.
+    pub fn with_get_resource_policy<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn() -> ::std::result::Result<
+                GetResourcePolicyResponse,
+                RusotoError<GetResourcePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_security_configuration` with what `handler` returns for its input.
+    pub fn with_get_security_configuration<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetSecurityConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetSecurityConfigurationResponse,
+                RusotoError<GetSecurityConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_security_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_security_configurations` with what `handler` returns for its input.
+    pub fn with_get_security_configurations<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetSecurityConfigurationsRequest,
+            ) -> ::std::result::Result<
+                GetSecurityConfigurationsResponse,
+                RusotoError<GetSecurityConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_security_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_table` with what `handler` returns for its input.
+    pub fn with_get_table<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetTableRequest,
+            ) -> ::std::result::Result<GetTableResponse, RusotoError<GetTableError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_table_version` with what `handler` returns for its input.
+    pub fn with_get_table_version<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetTableVersionRequest,
+            )
+                -> ::std::result::Result<GetTableVersionResponse, RusotoError<GetTableVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_table_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_table_versions` with what `handler` returns for its input.
+    pub fn with_get_table_versions<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetTableVersionsRequest,
+            ) -> ::std::result::Result<
+                GetTableVersionsResponse,
+                RusotoError<GetTableVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_table_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_tables` with what `handler` returns for its input.
+    pub fn with_get_tables<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetTablesRequest,
+            ) -> ::std::result::Result<GetTablesResponse, RusotoError<GetTablesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_tables = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_tags` with what `handler` returns for its input.
+    pub fn with_get_tags<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(GetTagsRequest) -> ::std::result::Result<GetTagsResponse, RusotoError<GetTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_trigger` with what `handler` returns for its input.
+    pub fn with_get_trigger<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetTriggerRequest,
+            )
+                -> ::std::result::Result<GetTriggerResponse, RusotoError<GetTriggerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_trigger = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_triggers` with what `handler` returns for its input.
+    pub fn with_get_triggers<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetTriggersRequest,
+            )
+                -> ::std::result::Result<GetTriggersResponse, RusotoError<GetTriggersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_triggers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_user_defined_function` with what `handler` returns for its input.
+    pub fn with_get_user_defined_function<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetUserDefinedFunctionRequest,
+            ) -> ::std::result::Result<
+                GetUserDefinedFunctionResponse,
+                RusotoError<GetUserDefinedFunctionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_user_defined_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_user_defined_functions` with what `handler` returns for its input.
+    pub fn with_get_user_defined_functions<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetUserDefinedFunctionsRequest,
+            ) -> ::std::result::Result<
+                GetUserDefinedFunctionsResponse,
+                RusotoError<GetUserDefinedFunctionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_user_defined_functions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_workflow` with what `handler` returns for its input.
+    pub fn with_get_workflow<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetWorkflowRequest,
+            )
+                -> ::std::result::Result<GetWorkflowResponse, RusotoError<GetWorkflowError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_workflow = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_workflow_run` with what `handler` returns for its input.
+    pub fn with_get_workflow_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetWorkflowRunRequest,
+            )
+                -> ::std::result::Result<GetWorkflowRunResponse, RusotoError<GetWorkflowRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_workflow_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_workflow_run_properties` with what `handler` returns for its input.
+    pub fn with_get_workflow_run_properties<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetWorkflowRunPropertiesRequest,
+            ) -> ::std::result::Result<
+                GetWorkflowRunPropertiesResponse,
+                RusotoError<GetWorkflowRunPropertiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_workflow_run_properties = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_workflow_runs` with what `handler` returns for its input.
+    pub fn with_get_workflow_runs<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                GetWorkflowRunsRequest,
+            )
+                -> ::std::result::Result<GetWorkflowRunsResponse, RusotoError<GetWorkflowRunsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_workflow_runs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_catalog_to_glue` with what `handler` returns for its input.
+    pub fn with_import_catalog_to_glue<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                ImportCatalogToGlueRequest,
+            ) -> ::std::result::Result<
+                ImportCatalogToGlueResponse,
+                RusotoError<ImportCatalogToGlueError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_catalog_to_glue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_crawlers` with what `handler` returns for its input.
+    pub fn with_list_crawlers<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                ListCrawlersRequest,
+            )
+                -> ::std::result::Result<ListCrawlersResponse, RusotoError<ListCrawlersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_crawlers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_dev_endpoints` with what `handler` returns for its input.
+    pub fn with_list_dev_endpoints<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                ListDevEndpointsRequest,
+            ) -> ::std::result::Result<
+                ListDevEndpointsResponse,
+                RusotoError<ListDevEndpointsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_dev_endpoints = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_jobs` with what `handler` returns for its input.
+    pub fn with_list_jobs<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                ListJobsRequest,
+            ) -> ::std::result::Result<ListJobsResponse, RusotoError<ListJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_triggers` with what `handler` returns for its input.
+    pub fn with_list_triggers<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                ListTriggersRequest,
+            )
+                -> ::std::result::Result<ListTriggersResponse, RusotoError<ListTriggersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_triggers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_workflows` with what `handler` returns for its input.
+    pub fn with_list_workflows<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                ListWorkflowsRequest,
+            )
+                -> ::std::result::Result<ListWorkflowsResponse, RusotoError<ListWorkflowsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_workflows = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_data_catalog_encryption_settings` with what `handler` returns for its input.
+    pub fn with_put_data_catalog_encryption_settings<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                PutDataCatalogEncryptionSettingsRequest,
+            ) -> ::std::result::Result<
+                PutDataCatalogEncryptionSettingsResponse,
+                RusotoError<PutDataCatalogEncryptionSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_data_catalog_encryption_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_resource_policy` with what `handler` returns for its input.
+    pub fn with_put_resource_policy<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                PutResourcePolicyRequest,
+            ) -> ::std::result::Result<
+                PutResourcePolicyResponse,
+                RusotoError<PutResourcePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_resource_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_workflow_run_properties` with what `handler` returns for its input.
+    pub fn with_put_workflow_run_properties<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                PutWorkflowRunPropertiesRequest,
+            ) -> ::std::result::Result<
+                PutWorkflowRunPropertiesResponse,
+                RusotoError<PutWorkflowRunPropertiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_workflow_run_properties = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reset_job_bookmark` with what `handler` returns for its input.
+    pub fn with_reset_job_bookmark<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                ResetJobBookmarkRequest,
+            ) -> ::std::result::Result<
+                ResetJobBookmarkResponse,
+                RusotoError<ResetJobBookmarkError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reset_job_bookmark = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_tables` with what `handler` returns for its input.
+    pub fn with_search_tables<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                SearchTablesRequest,
+            )
+                -> ::std::result::Result<SearchTablesResponse, RusotoError<SearchTablesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search_tables = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_crawler` with what `handler` returns for its input.
+    pub fn with_start_crawler<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StartCrawlerRequest,
+            )
+                -> ::std::result::Result<StartCrawlerResponse, RusotoError<StartCrawlerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_crawler = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_crawler_schedule` with what `handler` returns for its input.
+    pub fn with_start_crawler_schedule<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StartCrawlerScheduleRequest,
+            ) -> ::std::result::Result<
+                StartCrawlerScheduleResponse,
+                RusotoError<StartCrawlerScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_crawler_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_export_labels_task_run` with what `handler` returns for its input.
+    pub fn with_start_export_labels_task_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StartExportLabelsTaskRunRequest,
+            ) -> ::std::result::Result<
+                StartExportLabelsTaskRunResponse,
+                RusotoError<StartExportLabelsTaskRunError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_export_labels_task_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_import_labels_task_run` with what `handler` returns for its input.
+    pub fn with_start_import_labels_task_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StartImportLabelsTaskRunRequest,
+            ) -> ::std::result::Result<
+                StartImportLabelsTaskRunResponse,
+                RusotoError<StartImportLabelsTaskRunError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_import_labels_task_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_job_run` with what `handler` returns for its input.
+    pub fn with_start_job_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StartJobRunRequest,
+            )
+                -> ::std::result::Result<StartJobRunResponse, RusotoError<StartJobRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_job_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_ml_evaluation_task_run` with what `handler` returns for its input.
+    pub fn with_start_ml_evaluation_task_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StartMLEvaluationTaskRunRequest,
+            ) -> ::std::result::Result<
+                StartMLEvaluationTaskRunResponse,
+                RusotoError<StartMLEvaluationTaskRunError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_ml_evaluation_task_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_ml_labeling_set_generation_task_run` with what `handler` returns for its input.
+    pub fn with_start_ml_labeling_set_generation_task_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StartMLLabelingSetGenerationTaskRunRequest,
+            ) -> ::std::result::Result<
+                StartMLLabelingSetGenerationTaskRunResponse,
+                RusotoError<StartMLLabelingSetGenerationTaskRunError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_ml_labeling_set_generation_task_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_trigger` with what `handler` returns for its input.
+    pub fn with_start_trigger<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StartTriggerRequest,
+            )
+                -> ::std::result::Result<StartTriggerResponse, RusotoError<StartTriggerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_trigger = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_workflow_run` with what `handler` returns for its input.
+    pub fn with_start_workflow_run<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StartWorkflowRunRequest,
+            ) -> ::std::result::Result<
+                StartWorkflowRunResponse,
+                RusotoError<StartWorkflowRunError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_workflow_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_crawler` with what `handler` returns for its input.
+    pub fn with_stop_crawler<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StopCrawlerRequest,
+            )
+                -> ::std::result::Result<StopCrawlerResponse, RusotoError<StopCrawlerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_crawler = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_crawler_schedule` with what `handler` returns for its input.
+    pub fn with_stop_crawler_schedule<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StopCrawlerScheduleRequest,
+            ) -> ::std::result::Result<
+                StopCrawlerScheduleResponse,
+                RusotoError<StopCrawlerScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_crawler_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_trigger` with what `handler` returns for its input.
+    pub fn with_stop_trigger<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                StopTriggerRequest,
+            )
+                -> ::std::result::Result<StopTriggerResponse, RusotoError<StopTriggerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_trigger = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_classifier` with what `handler` returns for its input.
+    pub fn with_update_classifier<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateClassifierRequest,
+            ) -> ::std::result::Result<
+                UpdateClassifierResponse,
+                RusotoError<UpdateClassifierError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_classifier = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_connection` with what `handler` returns for its input.
+    pub fn with_update_connection<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateConnectionRequest,
+            ) -> ::std::result::Result<
+                UpdateConnectionResponse,
+                RusotoError<UpdateConnectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_connection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_crawler` with what `handler` returns for its input.
+    pub fn with_update_crawler<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateCrawlerRequest,
+            )
+                -> ::std::result::Result<UpdateCrawlerResponse, RusotoError<UpdateCrawlerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_crawler = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_crawler_schedule` with what `handler` returns for its input.
+    pub fn with_update_crawler_schedule<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateCrawlerScheduleRequest,
+            ) -> ::std::result::Result<
+                UpdateCrawlerScheduleResponse,
+                RusotoError<UpdateCrawlerScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_crawler_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_database` with what `handler` returns for its input.
+    pub fn with_update_database<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateDatabaseRequest,
+            )
+                -> ::std::result::Result<UpdateDatabaseResponse, RusotoError<UpdateDatabaseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_dev_endpoint` with what `handler` returns for its input.
+    pub fn with_update_dev_endpoint<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateDevEndpointRequest,
+            ) -> ::std::result::Result<
+                UpdateDevEndpointResponse,
+                RusotoError<UpdateDevEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_dev_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_job` with what `handler` returns for its input.
+    pub fn with_update_job<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateJobRequest,
+            ) -> ::std::result::Result<UpdateJobResponse, RusotoError<UpdateJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_ml_transform` with what `handler` returns for its input.
+    pub fn with_update_ml_transform<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateMLTransformRequest,
+            ) -> ::std::result::Result<
+                UpdateMLTransformResponse,
+                RusotoError<UpdateMLTransformError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_ml_transform = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_partition` with what `handler` returns for its input.
+    pub fn with_update_partition<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdatePartitionRequest,
+            )
+                -> ::std::result::Result<UpdatePartitionResponse, RusotoError<UpdatePartitionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_partition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_table` with what `handler` returns for its input.
+    pub fn with_update_table<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateTableRequest,
+            )
+                -> ::std::result::Result<UpdateTableResponse, RusotoError<UpdateTableError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_table = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_trigger` with what `handler` returns for its input.
+    pub fn with_update_trigger<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateTriggerRequest,
+            )
+                -> ::std::result::Result<UpdateTriggerResponse, RusotoError<UpdateTriggerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_trigger = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_defined_function` with what `handler` returns for its input.
+    pub fn with_update_user_defined_function<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateUserDefinedFunctionRequest,
+            ) -> ::std::result::Result<
+                UpdateUserDefinedFunctionResponse,
+                RusotoError<UpdateUserDefinedFunctionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_defined_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_workflow` with what `handler` returns for its input.
+    pub fn with_update_workflow<F>(mut self, handler: F) -> MockGlue
+    where
+        F: Fn(
+                UpdateWorkflowRequest,
+            )
+                -> ::std::result::Result<UpdateWorkflowResponse, RusotoError<UpdateWorkflowError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_workflow = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Glue for MockGlue {
+    fn batch_create_partition(
+        &self,
+        input: BatchCreatePartitionRequest,
+    ) -> RusotoFuture<BatchCreatePartitionResponse, BatchCreatePartitionError> {
+        match self.batch_create_partition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_create_partition"),
+        }
+    }
+    fn batch_delete_connection(
+        &self,
+        input: BatchDeleteConnectionRequest,
+    ) -> RusotoFuture<BatchDeleteConnectionResponse, BatchDeleteConnectionError> {
+        match self.batch_delete_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_delete_connection"),
+        }
+    }
+    fn batch_delete_partition(
+        &self,
+        input: BatchDeletePartitionRequest,
+    ) -> RusotoFuture<BatchDeletePartitionResponse, BatchDeletePartitionError> {
+        match self.batch_delete_partition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_delete_partition"),
+        }
+    }
+    fn batch_delete_table(
+        &self,
+        input: BatchDeleteTableRequest,
+    ) -> RusotoFuture<BatchDeleteTableResponse, BatchDeleteTableError> {
+        match self.batch_delete_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_delete_table"),
+        }
+    }
+    fn batch_delete_table_version(
+        &self,
+        input: BatchDeleteTableVersionRequest,
+    ) -> RusotoFuture<BatchDeleteTableVersionResponse, BatchDeleteTableVersionError> {
+        match self.batch_delete_table_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_delete_table_version"),
+        }
+    }
+    fn batch_get_crawlers(
+        &self,
+        input: BatchGetCrawlersRequest,
+    ) -> RusotoFuture<BatchGetCrawlersResponse, BatchGetCrawlersError> {
+        match self.batch_get_crawlers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_get_crawlers"),
+        }
+    }
+    fn batch_get_dev_endpoints(
+        &self,
+        input: BatchGetDevEndpointsRequest,
+    ) -> RusotoFuture<BatchGetDevEndpointsResponse, BatchGetDevEndpointsError> {
+        match self.batch_get_dev_endpoints {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_get_dev_endpoints"),
+        }
+    }
+    fn batch_get_jobs(
+        &self,
+        input: BatchGetJobsRequest,
+    ) -> RusotoFuture<BatchGetJobsResponse, BatchGetJobsError> {
+        match self.batch_get_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_get_jobs"),
+        }
+    }
+    fn batch_get_partition(
+        &self,
+        input: BatchGetPartitionRequest,
+    ) -> RusotoFuture<BatchGetPartitionResponse, BatchGetPartitionError> {
+        match self.batch_get_partition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_get_partition"),
+        }
+    }
+    fn batch_get_triggers(
+        &self,
+        input: BatchGetTriggersRequest,
+    ) -> RusotoFuture<BatchGetTriggersResponse, BatchGetTriggersError> {
+        match self.batch_get_triggers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_get_triggers"),
+        }
+    }
+    fn batch_get_workflows(
+        &self,
+        input: BatchGetWorkflowsRequest,
+    ) -> RusotoFuture<BatchGetWorkflowsResponse, BatchGetWorkflowsError> {
+        match self.batch_get_workflows {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_get_workflows"),
+        }
+    }
+    fn batch_stop_job_run(
+        &self,
+        input: BatchStopJobRunRequest,
+    ) -> RusotoFuture<BatchStopJobRunResponse, GlueBatchStopJobRunError> {
+        match self.batch_stop_job_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for batch_stop_job_run"),
+        }
+    }
+    fn cancel_ml_task_run(
+        &self,
+        input: CancelMLTaskRunRequest,
+    ) -> RusotoFuture<CancelMLTaskRunResponse, CancelMLTaskRunError> {
+        match self.cancel_ml_task_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for cancel_ml_task_run"),
+        }
+    }
+    fn create_classifier(
+        &self,
+        input: CreateClassifierRequest,
+    ) -> RusotoFuture<CreateClassifierResponse, CreateClassifierError> {
+        match self.create_classifier {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_classifier"),
+        }
+    }
+    fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> RusotoFuture<CreateConnectionResponse, CreateConnectionError> {
+        match self.create_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_connection"),
+        }
+    }
+    fn create_crawler(
+        &self,
+        input: CreateCrawlerRequest,
+    ) -> RusotoFuture<CreateCrawlerResponse, CreateCrawlerError> {
+        match self.create_crawler {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_crawler"),
+        }
+    }
+    fn create_database(
+        &self,
+        input: CreateDatabaseRequest,
+    ) -> RusotoFuture<CreateDatabaseResponse, CreateDatabaseError> {
+        match self.create_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_database"),
+        }
+    }
+    fn create_dev_endpoint(
+        &self,
+        input: CreateDevEndpointRequest,
+    ) -> RusotoFuture<CreateDevEndpointResponse, CreateDevEndpointError> {
+        match self.create_dev_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_dev_endpoint"),
+        }
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        match self.create_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_job"),
+        }
+    }
+    fn create_ml_transform(
+        &self,
+        input: CreateMLTransformRequest,
+    ) -> RusotoFuture<CreateMLTransformResponse, CreateMLTransformError> {
+        match self.create_ml_transform {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_ml_transform"),
+        }
+    }
+    fn create_partition(
+        &self,
+        input: CreatePartitionRequest,
+    ) -> RusotoFuture<CreatePartitionResponse, CreatePartitionError> {
+        match self.create_partition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_partition"),
+        }
+    }
+    fn create_script(
+        &self,
+        input: CreateScriptRequest,
+    ) -> RusotoFuture<CreateScriptResponse, CreateScriptError> {
+        match self.create_script {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_script"),
+        }
+    }
+    fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationRequest,
+    ) -> RusotoFuture<CreateSecurityConfigurationResponse, CreateSecurityConfigurationError> {
+        match self.create_security_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_security_configuration"),
+        }
+    }
+    fn create_table(
+        &self,
+        input: CreateTableRequest,
+    ) -> RusotoFuture<CreateTableResponse, CreateTableError> {
+        match self.create_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_table"),
+        }
+    }
+    fn create_trigger(
+        &self,
+        input: CreateTriggerRequest,
+    ) -> RusotoFuture<CreateTriggerResponse, CreateTriggerError> {
+        match self.create_trigger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_trigger"),
+        }
+    }
+    fn create_user_defined_function(
+        &self,
+        input: CreateUserDefinedFunctionRequest,
+    ) -> RusotoFuture<CreateUserDefinedFunctionResponse, CreateUserDefinedFunctionError> {
+        match self.create_user_defined_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_user_defined_function"),
+        }
+    }
+    fn create_workflow(
+        &self,
+        input: CreateWorkflowRequest,
+    ) -> RusotoFuture<CreateWorkflowResponse, CreateWorkflowError> {
+        match self.create_workflow {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for create_workflow"),
+        }
+    }
+    fn delete_classifier(
+        &self,
+        input: DeleteClassifierRequest,
+    ) -> RusotoFuture<DeleteClassifierResponse, DeleteClassifierError> {
+        match self.delete_classifier {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_classifier"),
+        }
+    }
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<DeleteConnectionResponse, DeleteConnectionError> {
+        match self.delete_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_connection"),
+        }
+    }
+    fn delete_crawler(
+        &self,
+        input: DeleteCrawlerRequest,
+    ) -> RusotoFuture<DeleteCrawlerResponse, DeleteCrawlerError> {
+        match self.delete_crawler {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_crawler"),
+        }
+    }
+    fn delete_database(
+        &self,
+        input: DeleteDatabaseRequest,
+    ) -> RusotoFuture<DeleteDatabaseResponse, DeleteDatabaseError> {
+        match self.delete_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_database"),
+        }
+    }
+    fn delete_dev_endpoint(
+        &self,
+        input: DeleteDevEndpointRequest,
+    ) -> RusotoFuture<DeleteDevEndpointResponse, DeleteDevEndpointError> {
+        match self.delete_dev_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_dev_endpoint"),
+        }
+    }
+    fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> RusotoFuture<DeleteJobResponse, DeleteJobError> {
+        match self.delete_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_job"),
+        }
+    }
+    fn delete_ml_transform(
+        &self,
+        input: DeleteMLTransformRequest,
+    ) -> RusotoFuture<DeleteMLTransformResponse, DeleteMLTransformError> {
+        match self.delete_ml_transform {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_ml_transform"),
+        }
+    }
+    fn delete_partition(
+        &self,
+        input: DeletePartitionRequest,
+    ) -> RusotoFuture<DeletePartitionResponse, DeletePartitionError> {
+        match self.delete_partition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_partition"),
+        }
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
+        match self.delete_resource_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_resource_policy"),
+        }
+    }
+    fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationRequest,
+    ) -> RusotoFuture<DeleteSecurityConfigurationResponse, DeleteSecurityConfigurationError> {
+        match self.delete_security_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_security_configuration"),
+        }
+    }
+    fn delete_table(
+        &self,
+        input: DeleteTableRequest,
+    ) -> RusotoFuture<DeleteTableResponse, DeleteTableError> {
+        match self.delete_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_table"),
+        }
+    }
+    fn delete_table_version(
+        &self,
+        input: DeleteTableVersionRequest,
+    ) -> RusotoFuture<DeleteTableVersionResponse, DeleteTableVersionError> {
+        match self.delete_table_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_table_version"),
+        }
+    }
+    fn delete_trigger(
+        &self,
+        input: DeleteTriggerRequest,
+    ) -> RusotoFuture<DeleteTriggerResponse, DeleteTriggerError> {
+        match self.delete_trigger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_trigger"),
+        }
+    }
+    fn delete_user_defined_function(
+        &self,
+        input: DeleteUserDefinedFunctionRequest,
+    ) -> RusotoFuture<DeleteUserDefinedFunctionResponse, DeleteUserDefinedFunctionError> {
+        match self.delete_user_defined_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_user_defined_function"),
+        }
+    }
+    fn delete_workflow(
+        &self,
+        input: DeleteWorkflowRequest,
+    ) -> RusotoFuture<DeleteWorkflowResponse, DeleteWorkflowError> {
+        match self.delete_workflow {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for delete_workflow"),
+        }
+    }
+    fn get_catalog_import_status(
+        &self,
+        input: GetCatalogImportStatusRequest,
+    ) -> RusotoFuture<GetCatalogImportStatusResponse, GetCatalogImportStatusError> {
+        match self.get_catalog_import_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_catalog_import_status"),
+        }
+    }
+    fn get_classifier(
+        &self,
+        input: GetClassifierRequest,
+    ) -> RusotoFuture<GetClassifierResponse, GetClassifierError> {
+        match self.get_classifier {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_classifier"),
+        }
+    }
+    fn get_classifiers(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> RusotoFuture<GetClassifiersResponse, GetClassifiersError> {
+        match self.get_classifiers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_classifiers"),
+        }
+    }
+    fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
+        match self.get_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_connection"),
+        }
+    }
+    fn get_connections(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> RusotoFuture<GetConnectionsResponse, GetConnectionsError> {
+        match self.get_connections {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_connections"),
+        }
+    }
+    fn get_crawler(
+        &self,
+        input: GetCrawlerRequest,
+    ) -> RusotoFuture<GetCrawlerResponse, GetCrawlerError> {
+        match self.get_crawler {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_crawler"),
+        }
+    }
+    fn get_crawler_metrics(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> RusotoFuture<GetCrawlerMetricsResponse, GetCrawlerMetricsError> {
+        match self.get_crawler_metrics {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_crawler_metrics"),
+        }
+    }
+    fn get_crawlers(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> RusotoFuture<GetCrawlersResponse, GetCrawlersError> {
+        match self.get_crawlers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_crawlers"),
+        }
+    }
+    fn get_data_catalog_encryption_settings(
+        &self,
+        input: GetDataCatalogEncryptionSettingsRequest,
+    ) -> RusotoFuture<GetDataCatalogEncryptionSettingsResponse, GetDataCatalogEncryptionSettingsError>
+    {
+        match self.get_data_catalog_encryption_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_data_catalog_encryption_settings"),
+        }
+    }
+    fn get_database(
+        &self,
+        input: GetDatabaseRequest,
+    ) -> RusotoFuture<GetDatabaseResponse, GetDatabaseError> {
+        match self.get_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_database"),
+        }
+    }
+    fn get_databases(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> RusotoFuture<GetDatabasesResponse, GetDatabasesError> {
+        match self.get_databases {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_databases"),
+        }
+    }
+    fn get_dataflow_graph(
+        &self,
+        input: GetDataflowGraphRequest,
+    ) -> RusotoFuture<GetDataflowGraphResponse, GetDataflowGraphError> {
+        match self.get_dataflow_graph {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_dataflow_graph"),
+        }
+    }
+    fn get_dev_endpoint(
+        &self,
+        input: GetDevEndpointRequest,
+    ) -> RusotoFuture<GetDevEndpointResponse, GetDevEndpointError> {
+        match self.get_dev_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_dev_endpoint"),
+        }
+    }
+    fn get_dev_endpoints(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> RusotoFuture<GetDevEndpointsResponse, GetDevEndpointsError> {
+        match self.get_dev_endpoints {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_dev_endpoints"),
+        }
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResponse, GetJobError> {
+        match self.get_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_job"),
+        }
+    }
+    fn get_job_bookmark(
+        &self,
+        input: GetJobBookmarkRequest,
+    ) -> RusotoFuture<GetJobBookmarkResponse, GetJobBookmarkError> {
+        match self.get_job_bookmark {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_job_bookmark"),
+        }
+    }
+    fn get_job_run(
+        &self,
+        input: GetJobRunRequest,
+    ) -> RusotoFuture<GetJobRunResponse, GetJobRunError> {
+        match self.get_job_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_job_run"),
+        }
+    }
+    fn get_job_runs(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> RusotoFuture<GetJobRunsResponse, GetJobRunsError> {
+        match self.get_job_runs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_job_runs"),
+        }
+    }
+    fn get_jobs(&self, input: GetJobsRequest) -> RusotoFuture<GetJobsResponse, GetJobsError> {
+        match self.get_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_jobs"),
+        }
+    }
+    fn get_ml_task_run(
+        &self,
+        input: GetMLTaskRunRequest,
+    ) -> RusotoFuture<GetMLTaskRunResponse, GetMLTaskRunError> {
+        match self.get_ml_task_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_ml_task_run"),
+        }
+    }
+    fn get_ml_task_runs(
+        &self,
+        input: GetMLTaskRunsRequest,
+    ) -> RusotoFuture<GetMLTaskRunsResponse, GetMLTaskRunsError> {
+        match self.get_ml_task_runs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_ml_task_runs"),
+        }
+    }
+    fn get_ml_transform(
+        &self,
+        input: GetMLTransformRequest,
+    ) -> RusotoFuture<GetMLTransformResponse, GetMLTransformError> {
+        match self.get_ml_transform {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_ml_transform"),
+        }
+    }
+    fn get_ml_transforms(
+        &self,
+        input: GetMLTransformsRequest,
+    ) -> RusotoFuture<GetMLTransformsResponse, GetMLTransformsError> {
+        match self.get_ml_transforms {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_ml_transforms"),
+        }
+    }
+    fn get_mapping(
+        &self,
+        input: GetMappingRequest,
+    ) -> RusotoFuture<GetMappingResponse, GetMappingError> {
+        match self.get_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_mapping"),
+        }
+    }
+    fn get_partition(
+        &self,
+        input: GetPartitionRequest,
+    ) -> RusotoFuture<GetPartitionResponse, GetPartitionError> {
+        match self.get_partition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_partition"),
+        }
+    }
+    fn get_partitions(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> RusotoFuture<GetPartitionsResponse, GetPartitionsError> {
+        match self.get_partitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_partitions"),
+        }
+    }
+    fn get_plan(&self, input: GetPlanRequest) -> RusotoFuture<GetPlanResponse, GetPlanError> {
+        match self.get_plan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_plan"),
+        }
+    }
+    fn get_resource_policy(
+        &self,
+    ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        match self.get_resource_policy {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockGlue has no handler for get_resource_policy"),
+        }
+    }
+    fn get_security_configuration(
+        &self,
+        input: GetSecurityConfigurationRequest,
+    ) -> RusotoFuture<GetSecurityConfigurationResponse, GetSecurityConfigurationError> {
+        match self.get_security_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_security_configuration"),
+        }
+    }
+    fn get_security_configurations(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> RusotoFuture<GetSecurityConfigurationsResponse, GetSecurityConfigurationsError> {
+        match self.get_security_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_security_configurations"),
+        }
+    }
+    fn get_table(&self, input: GetTableRequest) -> RusotoFuture<GetTableResponse, GetTableError> {
+        match self.get_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_table"),
+        }
+    }
+    fn get_table_version(
+        &self,
+        input: GetTableVersionRequest,
+    ) -> RusotoFuture<GetTableVersionResponse, GetTableVersionError> {
+        match self.get_table_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_table_version"),
+        }
+    }
+    fn get_table_versions(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> RusotoFuture<GetTableVersionsResponse, GetTableVersionsError> {
+        match self.get_table_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_table_versions"),
+        }
+    }
+    fn get_tables(
+        &self,
+        input: GetTablesRequest,
+    ) -> RusotoFuture<GetTablesResponse, GetTablesError> {
+        match self.get_tables {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_tables"),
+        }
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        match self.get_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_tags"),
+        }
+    }
+    fn get_trigger(
+        &self,
+        input: GetTriggerRequest,
+    ) -> RusotoFuture<GetTriggerResponse, GetTriggerError> {
+        match self.get_trigger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_trigger"),
+        }
+    }
+    fn get_triggers(
+        &self,
+        input: GetTriggersRequest,
+    ) -> RusotoFuture<GetTriggersResponse, GetTriggersError> {
+        match self.get_triggers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_triggers"),
+        }
+    }
+    fn get_user_defined_function(
+        &self,
+        input: GetUserDefinedFunctionRequest,
+    ) -> RusotoFuture<GetUserDefinedFunctionResponse, GetUserDefinedFunctionError> {
+        match self.get_user_defined_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_user_defined_function"),
+        }
+    }
+    fn get_user_defined_functions(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> RusotoFuture<GetUserDefinedFunctionsResponse, GetUserDefinedFunctionsError> {
+        match self.get_user_defined_functions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_user_defined_functions"),
+        }
+    }
+    fn get_workflow(
+        &self,
+        input: GetWorkflowRequest,
+    ) -> RusotoFuture<GetWorkflowResponse, GetWorkflowError> {
+        match self.get_workflow {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_workflow"),
+        }
+    }
+    fn get_workflow_run(
+        &self,
+        input: GetWorkflowRunRequest,
+    ) -> RusotoFuture<GetWorkflowRunResponse, GetWorkflowRunError> {
+        match self.get_workflow_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_workflow_run"),
+        }
+    }
+    fn get_workflow_run_properties(
+        &self,
+        input: GetWorkflowRunPropertiesRequest,
+    ) -> RusotoFuture<GetWorkflowRunPropertiesResponse, GetWorkflowRunPropertiesError> {
+        match self.get_workflow_run_properties {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_workflow_run_properties"),
+        }
+    }
+    fn get_workflow_runs(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> RusotoFuture<GetWorkflowRunsResponse, GetWorkflowRunsError> {
+        match self.get_workflow_runs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for get_workflow_runs"),
+        }
+    }
+    fn import_catalog_to_glue(
+        &self,
+        input: ImportCatalogToGlueRequest,
+    ) -> RusotoFuture<ImportCatalogToGlueResponse, ImportCatalogToGlueError> {
+        match self.import_catalog_to_glue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for import_catalog_to_glue"),
+        }
+    }
+    fn list_crawlers(
+        &self,
+        input: ListCrawlersRequest,
+    ) -> RusotoFuture<ListCrawlersResponse, ListCrawlersError> {
+        match self.list_crawlers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for list_crawlers"),
+        }
+    }
+    fn list_dev_endpoints(
+        &self,
+        input: ListDevEndpointsRequest,
+    ) -> RusotoFuture<ListDevEndpointsResponse, ListDevEndpointsError> {
+        match self.list_dev_endpoints {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for list_dev_endpoints"),
+        }
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        match self.list_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for list_jobs"),
+        }
+    }
+    fn list_triggers(
+        &self,
+        input: ListTriggersRequest,
+    ) -> RusotoFuture<ListTriggersResponse, ListTriggersError> {
+        match self.list_triggers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for list_triggers"),
+        }
     }
-
-    /// <p>Lists names of workflows created in the account.</p>
     fn list_workflows(
         &self,
         input: ListWorkflowsRequest,
     ) -> RusotoFuture<ListWorkflowsResponse, ListWorkflowsError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.ListWorkflows");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListWorkflowsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListWorkflowsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_workflows {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for list_workflows"),
+        }
     }
-
-    /// <p>Sets the security configuration for a specified catalog. After the configuration has been set, the specified encryption is applied to every catalog write thereafter.</p>
     fn put_data_catalog_encryption_settings(
         &self,
         input: PutDataCatalogEncryptionSettingsRequest,
     ) -> RusotoFuture<PutDataCatalogEncryptionSettingsResponse, PutDataCatalogEncryptionSettingsError>
     {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.PutDataCatalogEncryptionSettings");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutDataCatalogEncryptionSettingsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutDataCatalogEncryptionSettingsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.put_data_catalog_encryption_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for put_data_catalog_encryption_settings"),
+        }
     }
-
-    /// <p>Sets the Data Catalog resource policy for access control.</p>
     fn put_resource_policy(
         &self,
         input: PutResourcePolicyRequest,
     ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.PutResourcePolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutResourcePolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutResourcePolicyError::from_response(response))),
-                )
-            }
-        })
+        match self.put_resource_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for put_resource_policy"),
+        }
     }
-
-    /// <p>Puts the specified workflow run properties for the given workflow run. If a property already exists for the specified run, then it overrides the value otherwise adds the property to existing properties.</p>
     fn put_workflow_run_properties(
         &self,
         input: PutWorkflowRunPropertiesRequest,
     ) -> RusotoFuture<PutWorkflowRunPropertiesResponse, PutWorkflowRunPropertiesError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.PutWorkflowRunProperties");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutWorkflowRunPropertiesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutWorkflowRunPropertiesError::from_response(response))
-                }))
-            }
-        })
+        match self.put_workflow_run_properties {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for put_workflow_run_properties"),
+        }
     }
-
-    /// <p>Resets a bookmark entry.</p>
     fn reset_job_bookmark(
         &self,
         input: ResetJobBookmarkRequest,
     ) -> RusotoFuture<ResetJobBookmarkResponse, ResetJobBookmarkError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.ResetJobBookmark");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResetJobBookmarkResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResetJobBookmarkError::from_response(response))),
-                )
-            }
-        })
+        match self.reset_job_bookmark {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for reset_job_bookmark"),
+        }
     }
-
-    /// <p>Searches a set of tables based on properties in the table metadata as well as on the parent database. You can search against text or filter conditions. </p> <p>You can only get tables that you have access to based on the security policies defined in Lake Formation. You need at least a read-only access to the table for it to be returned. If you do not have access to all the columns in the table, these columns will not be searched against when returning the list of tables back to you. If you have access to the columns but not the data in the columns, those columns and the associated metadata for those columns will be included in the search. </p>
     fn search_tables(
         &self,
         input: SearchTablesRequest,
     ) -> RusotoFuture<SearchTablesResponse, SearchTablesError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.SearchTables");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchTablesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchTablesError::from_response(response))),
-                )
-            }
-        })
+        match self.search_tables {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for search_tables"),
+        }
     }
-
-    /// <p>Starts a crawl using the specified crawler, regardless of what is scheduled. If the crawler is already running, returns a <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-exceptions.html#aws-glue-api-exceptions-CrawlerRunningException">CrawlerRunningException</a>.</p>
     fn start_crawler(
         &self,
         input: StartCrawlerRequest,
     ) -> RusotoFuture<StartCrawlerResponse, StartCrawlerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StartCrawler");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartCrawlerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartCrawlerError::from_response(response))),
-                )
-            }
-        })
+        match self.start_crawler {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for start_crawler"),
+        }
     }
-
-    /// <p>Changes the schedule state of the specified crawler to <code>SCHEDULED</code>, unless the crawler is already running or the schedule state is already <code>SCHEDULED</code>.</p>
     fn start_crawler_schedule(
         &self,
         input: StartCrawlerScheduleRequest,
     ) -> RusotoFuture<StartCrawlerScheduleResponse, StartCrawlerScheduleError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StartCrawlerSchedule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartCrawlerScheduleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartCrawlerScheduleError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.start_crawler_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for start_crawler_schedule"),
+        }
     }
-
-    /// <p>Begins an asynchronous task to export all labeled data for a particular transform. This task is the only label-related API call that is not part of the typical active learning workflow. You typically use <code>StartExportLabelsTaskRun</code> when you want to work with all of your existing labels at the same time, such as when you want to remove or change labels that were previously submitted as truth. This API operation accepts the <code>TransformId</code> whose labels you want to export and an Amazon Simple Storage Service (Amazon S3) path to export the labels to. The operation returns a <code>TaskRunId</code>. You can check on the status of your task run by calling the <code>GetMLTaskRun</code> API.</p>
     fn start_export_labels_task_run(
         &self,
         input: StartExportLabelsTaskRunRequest,
     ) -> RusotoFuture<StartExportLabelsTaskRunResponse, StartExportLabelsTaskRunError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StartExportLabelsTaskRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartExportLabelsTaskRunResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartExportLabelsTaskRunError::from_response(response))
-                }))
-            }
-        })
+        match self.start_export_labels_task_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for start_export_labels_task_run"),
+        }
     }
-
-    /// <p>Enables you to provide additional labels (examples of truth) to be used to teach the machine learning transform and improve its quality. This API operation is generally used as part of the active learning workflow that starts with the <code>StartMLLabelingSetGenerationTaskRun</code> call and that ultimately results in improving the quality of your machine learning transform. </p> <p>After the <code>StartMLLabelingSetGenerationTaskRun</code> finishes, AWS Glue machine learning will have generated a series of questions for humans to answer. (Answering these questions is often called 'labeling' in the machine learning workflows). In the case of the <code>FindMatches</code> transform, these questions are of the form, “What is the correct way to group these rows together into groups composed entirely of matching records?” After the labeling process is finished, users upload their answers/labels with a call to <code>StartImportLabelsTaskRun</code>. After <code>StartImportLabelsTaskRun</code> finishes, all future runs of the machine learning transform use the new and improved labels and perform a higher-quality transformation.</p> <p>By default, <code>StartMLLabelingSetGenerationTaskRun</code> continually learns from and combines all labels that you upload unless you set <code>Replace</code> to true. If you set <code>Replace</code> to true, <code>StartImportLabelsTaskRun</code> deletes and forgets all previously uploaded labels and learns only from the exact set that you upload. Replacing labels can be helpful if you realize that you previously uploaded incorrect labels, and you believe that they are having a negative effect on your transform quality.</p> <p>You can check on the status of your task run by calling the <code>GetMLTaskRun</code> operation. </p>
     fn start_import_labels_task_run(
         &self,
         input: StartImportLabelsTaskRunRequest,
     ) -> RusotoFuture<StartImportLabelsTaskRunResponse, StartImportLabelsTaskRunError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StartImportLabelsTaskRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartImportLabelsTaskRunResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartImportLabelsTaskRunError::from_response(response))
-                }))
-            }
-        })
+        match self.start_import_labels_task_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for start_import_labels_task_run"),
+        }
     }
-
-    /// <p>Starts a job run using a job definition.</p>
     fn start_job_run(
         &self,
         input: StartJobRunRequest,
-    ) -> RusotoFuture<StartJobRunResponse, StartJobRunError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StartJobRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartJobRunResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartJobRunError::from_response(response))),
-                )
-            }
-        })
+    ) -> RusotoFuture<StartJobRunResponse, StartJobRunError> {
+        match self.start_job_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for start_job_run"),
+        }
     }
-
-    /// <p>Starts a task to estimate the quality of the transform. </p> <p>When you provide label sets as examples of truth, AWS Glue machine learning uses some of those examples to learn from them. The rest of the labels are used as a test to estimate quality.</p> <p>Returns a unique identifier for the run. You can call <code>GetMLTaskRun</code> to get more information about the stats of the <code>EvaluationTaskRun</code>.</p>
     fn start_ml_evaluation_task_run(
         &self,
         input: StartMLEvaluationTaskRunRequest,
     ) -> RusotoFuture<StartMLEvaluationTaskRunResponse, StartMLEvaluationTaskRunError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StartMLEvaluationTaskRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartMLEvaluationTaskRunResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartMLEvaluationTaskRunError::from_response(response))
-                }))
-            }
-        })
+        match self.start_ml_evaluation_task_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for start_ml_evaluation_task_run"),
+        }
     }
-
-    /// <p>Starts the active learning workflow for your machine learning transform to improve the transform's quality by generating label sets and adding labels.</p> <p>When the <code>StartMLLabelingSetGenerationTaskRun</code> finishes, AWS Glue will have generated a "labeling set" or a set of questions for humans to answer.</p> <p>In the case of the <code>FindMatches</code> transform, these questions are of the form, “What is the correct way to group these rows together into groups composed entirely of matching records?” </p> <p>After the labeling process is finished, you can upload your labels with a call to <code>StartImportLabelsTaskRun</code>. After <code>StartImportLabelsTaskRun</code> finishes, all future runs of the machine learning transform will use the new and improved labels and perform a higher-quality transformation.</p>
     fn start_ml_labeling_set_generation_task_run(
         &self,
         input: StartMLLabelingSetGenerationTaskRunRequest,
@@ -16189,604 +29380,189 @@ impl Glue for GlueClient {
         StartMLLabelingSetGenerationTaskRunResponse,
         StartMLLabelingSetGenerationTaskRunError,
     > {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSGlue.StartMLLabelingSetGenerationTaskRun",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartMLLabelingSetGenerationTaskRunResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartMLLabelingSetGenerationTaskRunError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.start_ml_labeling_set_generation_task_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for start_ml_labeling_set_generation_task_run"),
+        }
     }
-
-    /// <p>Starts an existing trigger. See <a href="https://docs.aws.amazon.com/glue/latest/dg/trigger-job.html">Triggering Jobs</a> for information about how different types of trigger are started.</p>
     fn start_trigger(
         &self,
         input: StartTriggerRequest,
     ) -> RusotoFuture<StartTriggerResponse, StartTriggerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StartTrigger");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartTriggerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartTriggerError::from_response(response))),
-                )
-            }
-        })
+        match self.start_trigger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for start_trigger"),
+        }
     }
-
-    /// <p>Starts a new run of the specified workflow.</p>
     fn start_workflow_run(
         &self,
         input: StartWorkflowRunRequest,
     ) -> RusotoFuture<StartWorkflowRunResponse, StartWorkflowRunError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StartWorkflowRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartWorkflowRunResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartWorkflowRunError::from_response(response))),
-                )
-            }
-        })
+        match self.start_workflow_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for start_workflow_run"),
+        }
     }
-
-    /// <p>If the specified crawler is running, stops the crawl.</p>
     fn stop_crawler(
         &self,
         input: StopCrawlerRequest,
     ) -> RusotoFuture<StopCrawlerResponse, StopCrawlerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StopCrawler");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopCrawlerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopCrawlerError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_crawler {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for stop_crawler"),
+        }
     }
-
-    /// <p>Sets the schedule state of the specified crawler to <code>NOT_SCHEDULED</code>, but does not stop the crawler if it is already running.</p>
     fn stop_crawler_schedule(
         &self,
         input: StopCrawlerScheduleRequest,
     ) -> RusotoFuture<StopCrawlerScheduleResponse, StopCrawlerScheduleError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StopCrawlerSchedule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopCrawlerScheduleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StopCrawlerScheduleError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.stop_crawler_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for stop_crawler_schedule"),
+        }
     }
-
-    /// <p>Stops a specified trigger.</p>
     fn stop_trigger(
         &self,
         input: StopTriggerRequest,
     ) -> RusotoFuture<StopTriggerResponse, StopTriggerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.StopTrigger");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopTriggerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopTriggerError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_trigger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for stop_trigger"),
+        }
     }
-
-    /// <p>Adds tags to a resource. A tag is a label you can assign to an AWS resource. In AWS Glue, you can tag only certain resources. For information about what resources you can tag, see <a href="https://docs.aws.amazon.com/glue/latest/dg/monitor-tags.html">AWS Tags in AWS Glue</a>.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Removes tags from a resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Modifies an existing classifier (a <code>GrokClassifier</code>, an <code>XMLClassifier</code>, a <code>JsonClassifier</code>, or a <code>CsvClassifier</code>, depending on which field is present).</p>
     fn update_classifier(
         &self,
         input: UpdateClassifierRequest,
     ) -> RusotoFuture<UpdateClassifierResponse, UpdateClassifierError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateClassifier");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateClassifierResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateClassifierError::from_response(response))),
-                )
-            }
-        })
-    }
-
-    /// <p>Updates a connection definition in the Data Catalog.</p>
-    fn update_connection(
-        &self,
-        input: UpdateConnectionRequest,
-    ) -> RusotoFuture<UpdateConnectionResponse, UpdateConnectionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateConnection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateConnectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateConnectionError::from_response(response))),
-                )
-            }
-        })
+        match self.update_classifier {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_classifier"),
+        }
+    }
+    fn update_connection(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> RusotoFuture<UpdateConnectionResponse, UpdateConnectionError> {
+        match self.update_connection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_connection"),
+        }
     }
-
-    /// <p>Updates a crawler. If a crawler is running, you must stop it using <code>StopCrawler</code> before updating it.</p>
     fn update_crawler(
         &self,
         input: UpdateCrawlerRequest,
     ) -> RusotoFuture<UpdateCrawlerResponse, UpdateCrawlerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateCrawler");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateCrawlerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateCrawlerError::from_response(response))),
-                )
-            }
-        })
+        match self.update_crawler {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_crawler"),
+        }
     }
-
-    /// <p>Updates the schedule of a crawler using a <code>cron</code> expression. </p>
     fn update_crawler_schedule(
         &self,
         input: UpdateCrawlerScheduleRequest,
     ) -> RusotoFuture<UpdateCrawlerScheduleResponse, UpdateCrawlerScheduleError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateCrawlerSchedule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateCrawlerScheduleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateCrawlerScheduleError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_crawler_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_crawler_schedule"),
+        }
     }
-
-    /// <p>Updates an existing database definition in a Data Catalog.</p>
     fn update_database(
         &self,
         input: UpdateDatabaseRequest,
     ) -> RusotoFuture<UpdateDatabaseResponse, UpdateDatabaseError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateDatabase");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDatabaseResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDatabaseError::from_response(response))),
-                )
-            }
-        })
+        match self.update_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_database"),
+        }
     }
-
-    /// <p>Updates a specified development endpoint.</p>
     fn update_dev_endpoint(
         &self,
         input: UpdateDevEndpointRequest,
     ) -> RusotoFuture<UpdateDevEndpointResponse, UpdateDevEndpointError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateDevEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDevEndpointResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDevEndpointError::from_response(response))),
-                )
-            }
-        })
+        match self.update_dev_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_dev_endpoint"),
+        }
     }
-
-    /// <p>Updates an existing job definition.</p>
     fn update_job(
         &self,
         input: UpdateJobRequest,
     ) -> RusotoFuture<UpdateJobResponse, UpdateJobError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateJobError::from_response(response))),
-                )
-            }
-        })
+        match self.update_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_job"),
+        }
     }
-
-    /// <p>Updates an existing machine learning transform. Call this operation to tune the algorithm parameters to achieve better results.</p> <p>After calling this operation, you can call the <code>StartMLEvaluationTaskRun</code> operation to assess how well your new parameters achieved your goals (such as improving the quality of your machine learning transform, or making it more cost-effective).</p>
     fn update_ml_transform(
         &self,
         input: UpdateMLTransformRequest,
     ) -> RusotoFuture<UpdateMLTransformResponse, UpdateMLTransformError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateMLTransform");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateMLTransformResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateMLTransformError::from_response(response))),
-                )
-            }
-        })
+        match self.update_ml_transform {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_ml_transform"),
+        }
     }
-
-    /// <p>Updates a partition.</p>
     fn update_partition(
         &self,
         input: UpdatePartitionRequest,
     ) -> RusotoFuture<UpdatePartitionResponse, UpdatePartitionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdatePartition");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdatePartitionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdatePartitionError::from_response(response))),
-                )
-            }
-        })
+        match self.update_partition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_partition"),
+        }
     }
-
-    /// <p>Updates a metadata table in the Data Catalog.</p>
     fn update_table(
         &self,
         input: UpdateTableRequest,
     ) -> RusotoFuture<UpdateTableResponse, UpdateTableError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateTable");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTableResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTableError::from_response(response))),
-                )
-            }
-        })
+        match self.update_table {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_table"),
+        }
     }
-
-    /// <p>Updates a trigger definition.</p>
     fn update_trigger(
         &self,
         input: UpdateTriggerRequest,
     ) -> RusotoFuture<UpdateTriggerResponse, UpdateTriggerError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateTrigger");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTriggerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTriggerError::from_response(response))),
-                )
-            }
-        })
+        match self.update_trigger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_trigger"),
+        }
     }
-
-    /// <p>Updates an existing function definition in the Data Catalog.</p>
     fn update_user_defined_function(
         &self,
         input: UpdateUserDefinedFunctionRequest,
     ) -> RusotoFuture<UpdateUserDefinedFunctionResponse, UpdateUserDefinedFunctionError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateUserDefinedFunction");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateUserDefinedFunctionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateUserDefinedFunctionError::from_response(response))
-                }))
-            }
-        })
+        match self.update_user_defined_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_user_defined_function"),
+        }
     }
-
-    /// <p>Updates an existing workflow.</p>
     fn update_workflow(
         &self,
         input: UpdateWorkflowRequest,
     ) -> RusotoFuture<UpdateWorkflowResponse, UpdateWorkflowError> {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.UpdateWorkflow");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateWorkflowResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateWorkflowError::from_response(response))),
-                )
-            }
-        })
+        match self.update_workflow {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGlue has no handler for update_workflow"),
+        }
     }
 }
@@ -25,6 +25,7 @@ use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateRoleToGroupRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -35,7 +36,7 @@ pub struct AssociateRoleToGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateRoleToGroupResponse {
     /// <p>The time, in milliseconds since the epoch, when the role ARN was associated with the group.</p>
     #[serde(rename = "AssociatedAt")]
@@ -44,6 +45,7 @@ pub struct AssociateRoleToGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateServiceRoleToAccountRequest {
     /// <p>The ARN of the service role you wish to associate with your account.</p>
     #[serde(rename = "RoleArn")]
@@ -51,7 +53,7 @@ pub struct AssociateServiceRoleToAccountRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateServiceRoleToAccountResponse {
     /// <p>The time when the service role was associated with the account.</p>
     #[serde(rename = "AssociatedAt")]
@@ -61,7 +63,7 @@ pub struct AssociateServiceRoleToAccountResponse {
 
 /// <p>Information about a bulk deployment. You cannot start a new bulk deployment while another one is still running or in a non-terminal state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BulkDeployment {
     /// <p>The ARN of the bulk deployment.</p>
     #[serde(rename = "BulkDeploymentArn")]
@@ -79,7 +81,7 @@ pub struct BulkDeployment {
 
 /// <p>Relevant metrics on input records processed during bulk deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BulkDeploymentMetrics {
     /// <p>The total number of records that returned a non-retryable error. For example, this can occur if a group record from the input file uses an invalid format or specifies a nonexistent group version, or if the execution role doesn&#39;t grant permission to deploy a group or group version.</p>
     #[serde(rename = "InvalidInputRecords")]
@@ -97,7 +99,7 @@ pub struct BulkDeploymentMetrics {
 
 /// <p>Information about an individual group deployment in a bulk deployment operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BulkDeploymentResult {
     /// <p>The time, in ISO format, when the deployment was created.</p>
     #[serde(rename = "CreatedAt")]
@@ -206,6 +208,7 @@ pub struct CoreDefinitionVersion {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConnectorDefinitionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -226,7 +229,7 @@ pub struct CreateConnectorDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateConnectorDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -259,6 +262,7 @@ pub struct CreateConnectorDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConnectorDefinitionVersionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -274,7 +278,7 @@ pub struct CreateConnectorDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateConnectorDefinitionVersionResponse {
     /// <p>The ARN of the version.</p>
     #[serde(rename = "Arn")]
@@ -296,6 +300,7 @@ pub struct CreateConnectorDefinitionVersionResponse {
 
 /// <p>Information needed to create a core definition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCoreDefinitionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -316,7 +321,7 @@ pub struct CreateCoreDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCoreDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -349,6 +354,7 @@ pub struct CreateCoreDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCoreDefinitionVersionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -364,7 +370,7 @@ pub struct CreateCoreDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCoreDefinitionVersionResponse {
     /// <p>The ARN of the version.</p>
     #[serde(rename = "Arn")]
@@ -385,6 +391,7 @@ pub struct CreateCoreDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeploymentRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -407,7 +414,7 @@ pub struct CreateDeploymentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDeploymentResponse {
     /// <p>The ARN of the deployment.</p>
     #[serde(rename = "DeploymentArn")]
@@ -420,6 +427,7 @@ pub struct CreateDeploymentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeviceDefinitionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -440,7 +448,7 @@ pub struct CreateDeviceDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDeviceDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -473,6 +481,7 @@ pub struct CreateDeviceDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeviceDefinitionVersionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -488,7 +497,7 @@ pub struct CreateDeviceDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDeviceDefinitionVersionResponse {
     /// <p>The ARN of the version.</p>
     #[serde(rename = "Arn")]
@@ -509,6 +518,7 @@ pub struct CreateDeviceDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFunctionDefinitionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -529,7 +539,7 @@ pub struct CreateFunctionDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFunctionDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -563,6 +573,7 @@ pub struct CreateFunctionDefinitionResponse {
 
 /// <p>Information needed to create a function definition version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFunctionDefinitionVersionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -582,7 +593,7 @@ pub struct CreateFunctionDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFunctionDefinitionVersionResponse {
     /// <p>The ARN of the version.</p>
     #[serde(rename = "Arn")]
@@ -603,6 +614,7 @@ pub struct CreateFunctionDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGroupCertificateAuthorityRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -614,7 +626,7 @@ pub struct CreateGroupCertificateAuthorityRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGroupCertificateAuthorityResponse {
     /// <p>The ARN of the group certificate authority.</p>
     #[serde(rename = "GroupCertificateAuthorityArn")]
@@ -623,6 +635,7 @@ pub struct CreateGroupCertificateAuthorityResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGroupRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -643,7 +656,7 @@ pub struct CreateGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGroupResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -676,6 +689,7 @@ pub struct CreateGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGroupVersionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -715,7 +729,7 @@ pub struct CreateGroupVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGroupVersionResponse {
     /// <p>The ARN of the version.</p>
     #[serde(rename = "Arn")]
@@ -736,6 +750,7 @@ pub struct CreateGroupVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLoggerDefinitionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -756,7 +771,7 @@ pub struct CreateLoggerDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLoggerDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -789,6 +804,7 @@ pub struct CreateLoggerDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLoggerDefinitionVersionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -804,7 +820,7 @@ pub struct CreateLoggerDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLoggerDefinitionVersionResponse {
     /// <p>The ARN of the version.</p>
     #[serde(rename = "Arn")]
@@ -825,6 +841,7 @@ pub struct CreateLoggerDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateResourceDefinitionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -845,7 +862,7 @@ pub struct CreateResourceDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateResourceDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -878,6 +895,7 @@ pub struct CreateResourceDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateResourceDefinitionVersionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -893,7 +911,7 @@ pub struct CreateResourceDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateResourceDefinitionVersionResponse {
     /// <p>The ARN of the version.</p>
     #[serde(rename = "Arn")]
@@ -914,6 +932,7 @@ pub struct CreateResourceDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSoftwareUpdateJobRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -935,7 +954,7 @@ pub struct CreateSoftwareUpdateJobRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSoftwareUpdateJobResponse {
     /// <p>The IoT Job ARN corresponding to this update.</p>
     #[serde(rename = "IotJobArn")]
@@ -948,6 +967,7 @@ pub struct CreateSoftwareUpdateJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSubscriptionDefinitionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -968,7 +988,7 @@ pub struct CreateSubscriptionDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSubscriptionDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -1001,6 +1021,7 @@ pub struct CreateSubscriptionDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSubscriptionDefinitionVersionRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -1016,7 +1037,7 @@ pub struct CreateSubscriptionDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSubscriptionDefinitionVersionResponse {
     /// <p>The ARN of the version.</p>
     #[serde(rename = "Arn")]
@@ -1038,7 +1059,7 @@ pub struct CreateSubscriptionDefinitionVersionResponse {
 
 /// <p>Information about a definition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DefinitionInformation {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -1075,6 +1096,7 @@ pub struct DefinitionInformation {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConnectorDefinitionRequest {
     /// <p>The ID of the connector definition.</p>
     #[serde(rename = "ConnectorDefinitionId")]
@@ -1082,10 +1104,11 @@ pub struct DeleteConnectorDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteConnectorDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCoreDefinitionRequest {
     /// <p>The ID of the core definition.</p>
     #[serde(rename = "CoreDefinitionId")]
@@ -1093,10 +1116,11 @@ pub struct DeleteCoreDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteCoreDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDeviceDefinitionRequest {
     /// <p>The ID of the device definition.</p>
     #[serde(rename = "DeviceDefinitionId")]
@@ -1104,10 +1128,11 @@ pub struct DeleteDeviceDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDeviceDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFunctionDefinitionRequest {
     /// <p>The ID of the Lambda function definition.</p>
     #[serde(rename = "FunctionDefinitionId")]
@@ -1115,10 +1140,11 @@ pub struct DeleteFunctionDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFunctionDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGroupRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -1126,10 +1152,11 @@ pub struct DeleteGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteGroupResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLoggerDefinitionRequest {
     /// <p>The ID of the logger definition.</p>
     #[serde(rename = "LoggerDefinitionId")]
@@ -1137,10 +1164,11 @@ pub struct DeleteLoggerDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLoggerDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteResourceDefinitionRequest {
     /// <p>The ID of the resource definition.</p>
     #[serde(rename = "ResourceDefinitionId")]
@@ -1148,10 +1176,11 @@ pub struct DeleteResourceDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteResourceDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSubscriptionDefinitionRequest {
     /// <p>The ID of the subscription definition.</p>
     #[serde(rename = "SubscriptionDefinitionId")]
@@ -1159,12 +1188,12 @@ pub struct DeleteSubscriptionDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSubscriptionDefinitionResponse {}
 
 /// <p>Information about a deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Deployment {
     /// <p>The time, in milliseconds since the epoch, when the deployment was created.</p>
     #[serde(rename = "CreatedAt")]
@@ -1216,6 +1245,7 @@ pub struct DeviceDefinitionVersion {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateRoleFromGroupRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -1223,7 +1253,7 @@ pub struct DisassociateRoleFromGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateRoleFromGroupResponse {
     /// <p>The time, in milliseconds since the epoch, when the role was disassociated from the group.</p>
     #[serde(rename = "DisassociatedAt")]
@@ -1232,10 +1262,11 @@ pub struct DisassociateRoleFromGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateServiceRoleFromAccountRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateServiceRoleFromAccountResponse {
     /// <p>The time when the service role was disassociated from the account.</p>
     #[serde(rename = "DisassociatedAt")]
@@ -1245,7 +1276,7 @@ pub struct DisassociateServiceRoleFromAccountResponse {
 
 /// <p>Details about the error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ErrorDetail {
     /// <p>A detailed error code.</p>
     #[serde(rename = "DetailedErrorCode")]
@@ -1384,6 +1415,7 @@ pub struct FunctionRunAsConfig {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAssociatedRoleRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -1391,7 +1423,7 @@ pub struct GetAssociatedRoleRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAssociatedRoleResponse {
     /// <p>The time when the role was associated with the group.</p>
     #[serde(rename = "AssociatedAt")]
@@ -1404,6 +1436,7 @@ pub struct GetAssociatedRoleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBulkDeploymentStatusRequest {
     /// <p>The ID of the bulk deployment.</p>
     #[serde(rename = "BulkDeploymentId")]
@@ -1411,7 +1444,7 @@ pub struct GetBulkDeploymentStatusRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBulkDeploymentStatusResponse {
     /// <p>Relevant metrics on input records processed during bulk deployment.</p>
     #[serde(rename = "BulkDeploymentMetrics")]
@@ -1440,6 +1473,7 @@ pub struct GetBulkDeploymentStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConnectivityInfoRequest {
     /// <p>The thing name.</p>
     #[serde(rename = "ThingName")]
@@ -1447,7 +1481,7 @@ pub struct GetConnectivityInfoRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConnectivityInfoResponse {
     /// <p>Connectivity info list.</p>
     #[serde(rename = "ConnectivityInfo")]
@@ -1460,6 +1494,7 @@ pub struct GetConnectivityInfoResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConnectorDefinitionRequest {
     /// <p>The ID of the connector definition.</p>
     #[serde(rename = "ConnectorDefinitionId")]
@@ -1467,7 +1502,7 @@ pub struct GetConnectorDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConnectorDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -1504,6 +1539,7 @@ pub struct GetConnectorDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConnectorDefinitionVersionRequest {
     /// <p>The ID of the connector definition.</p>
     #[serde(rename = "ConnectorDefinitionId")]
@@ -1518,7 +1554,7 @@ pub struct GetConnectorDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConnectorDefinitionVersionResponse {
     /// <p>The ARN of the connector definition version.</p>
     #[serde(rename = "Arn")]
@@ -1547,6 +1583,7 @@ pub struct GetConnectorDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCoreDefinitionRequest {
     /// <p>The ID of the core definition.</p>
     #[serde(rename = "CoreDefinitionId")]
@@ -1554,7 +1591,7 @@ pub struct GetCoreDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCoreDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -1591,6 +1628,7 @@ pub struct GetCoreDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCoreDefinitionVersionRequest {
     /// <p>The ID of the core definition.</p>
     #[serde(rename = "CoreDefinitionId")]
@@ -1601,7 +1639,7 @@ pub struct GetCoreDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCoreDefinitionVersionResponse {
     /// <p>The ARN of the core definition version.</p>
     #[serde(rename = "Arn")]
@@ -1630,6 +1668,7 @@ pub struct GetCoreDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeploymentStatusRequest {
     /// <p>The ID of the deployment.</p>
     #[serde(rename = "DeploymentId")]
@@ -1640,7 +1679,7 @@ pub struct GetDeploymentStatusRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeploymentStatusResponse {
     /// <p>The status of the deployment: &#39;&#39;InProgress&#39;&#39;, &#39;&#39;Building&#39;&#39;, &#39;&#39;Success&#39;&#39;, or &#39;&#39;Failure&#39;&#39;.</p>
     #[serde(rename = "DeploymentStatus")]
@@ -1665,6 +1704,7 @@ pub struct GetDeploymentStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeviceDefinitionRequest {
     /// <p>The ID of the device definition.</p>
     #[serde(rename = "DeviceDefinitionId")]
@@ -1672,7 +1712,7 @@ pub struct GetDeviceDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeviceDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -1709,6 +1749,7 @@ pub struct GetDeviceDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeviceDefinitionVersionRequest {
     /// <p>The ID of the device definition.</p>
     #[serde(rename = "DeviceDefinitionId")]
@@ -1723,7 +1764,7 @@ pub struct GetDeviceDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeviceDefinitionVersionResponse {
     /// <p>The ARN of the device definition version.</p>
     #[serde(rename = "Arn")]
@@ -1752,6 +1793,7 @@ pub struct GetDeviceDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFunctionDefinitionRequest {
     /// <p>The ID of the Lambda function definition.</p>
     #[serde(rename = "FunctionDefinitionId")]
@@ -1759,7 +1801,7 @@ pub struct GetFunctionDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFunctionDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -1796,6 +1838,7 @@ pub struct GetFunctionDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFunctionDefinitionVersionRequest {
     /// <p>The ID of the Lambda function definition.</p>
     #[serde(rename = "FunctionDefinitionId")]
@@ -1810,7 +1853,7 @@ pub struct GetFunctionDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFunctionDefinitionVersionResponse {
     /// <p>The ARN of the function definition version.</p>
     #[serde(rename = "Arn")]
@@ -1839,6 +1882,7 @@ pub struct GetFunctionDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGroupCertificateAuthorityRequest {
     /// <p>The ID of the certificate authority.</p>
     #[serde(rename = "CertificateAuthorityId")]
@@ -1849,7 +1893,7 @@ pub struct GetGroupCertificateAuthorityRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGroupCertificateAuthorityResponse {
     /// <p>The ARN of the certificate authority for the group.</p>
     #[serde(rename = "GroupCertificateAuthorityArn")]
@@ -1866,6 +1910,7 @@ pub struct GetGroupCertificateAuthorityResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGroupCertificateConfigurationRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -1873,7 +1918,7 @@ pub struct GetGroupCertificateConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGroupCertificateConfigurationResponse {
     /// <p>The amount of time remaining before the certificate authority expires, in milliseconds.</p>
     #[serde(rename = "CertificateAuthorityExpiryInMilliseconds")]
@@ -1890,6 +1935,7 @@ pub struct GetGroupCertificateConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGroupRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -1897,7 +1943,7 @@ pub struct GetGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGroupResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -1934,6 +1980,7 @@ pub struct GetGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGroupVersionRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -1944,7 +1991,7 @@ pub struct GetGroupVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGroupVersionResponse {
     /// <p>The ARN of the group version.</p>
     #[serde(rename = "Arn")]
@@ -1969,6 +2016,7 @@ pub struct GetGroupVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLoggerDefinitionRequest {
     /// <p>The ID of the logger definition.</p>
     #[serde(rename = "LoggerDefinitionId")]
@@ -1976,7 +2024,7 @@ pub struct GetLoggerDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLoggerDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -2013,6 +2061,7 @@ pub struct GetLoggerDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLoggerDefinitionVersionRequest {
     /// <p>The ID of the logger definition.</p>
     #[serde(rename = "LoggerDefinitionId")]
@@ -2027,7 +2076,7 @@ pub struct GetLoggerDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLoggerDefinitionVersionResponse {
     /// <p>The ARN of the logger definition version.</p>
     #[serde(rename = "Arn")]
@@ -2052,6 +2101,7 @@ pub struct GetLoggerDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourceDefinitionRequest {
     /// <p>The ID of the resource definition.</p>
     #[serde(rename = "ResourceDefinitionId")]
@@ -2059,7 +2109,7 @@ pub struct GetResourceDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourceDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -2096,6 +2146,7 @@ pub struct GetResourceDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourceDefinitionVersionRequest {
     /// <p>The ID of the resource definition.</p>
     #[serde(rename = "ResourceDefinitionId")]
@@ -2106,7 +2157,7 @@ pub struct GetResourceDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourceDefinitionVersionResponse {
     /// <p>Arn of the resource definition version.</p>
     #[serde(rename = "Arn")]
@@ -2131,10 +2182,11 @@ pub struct GetResourceDefinitionVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetServiceRoleForAccountRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetServiceRoleForAccountResponse {
     /// <p>The time when the service role was associated with the account.</p>
     #[serde(rename = "AssociatedAt")]
@@ -2147,6 +2199,7 @@ pub struct GetServiceRoleForAccountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSubscriptionDefinitionRequest {
     /// <p>The ID of the subscription definition.</p>
     #[serde(rename = "SubscriptionDefinitionId")]
@@ -2154,7 +2207,7 @@ pub struct GetSubscriptionDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSubscriptionDefinitionResponse {
     /// <p>The ARN of the definition.</p>
     #[serde(rename = "Arn")]
@@ -2191,6 +2244,7 @@ pub struct GetSubscriptionDefinitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSubscriptionDefinitionVersionRequest {
     /// <p>The token for the next set of results, or &#39;&#39;null&#39;&#39; if there are no additional results.</p>
     #[serde(rename = "NextToken")]
@@ -2205,7 +2259,7 @@ pub struct GetSubscriptionDefinitionVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSubscriptionDefinitionVersionResponse {
     /// <p>The ARN of the subscription definition version.</p>
     #[serde(rename = "Arn")]
@@ -2235,7 +2289,7 @@ pub struct GetSubscriptionDefinitionVersionResponse {
 
 /// <p>Information about a certificate authority for a group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GroupCertificateAuthorityProperties {
     /// <p>The ARN of the certificate authority for the group.</p>
     #[serde(rename = "GroupCertificateAuthorityArn")]
@@ -2249,7 +2303,7 @@ pub struct GroupCertificateAuthorityProperties {
 
 /// <p>Information about a group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GroupInformation {
     /// <p>The ARN of the group.</p>
     #[serde(rename = "Arn")]
@@ -2328,6 +2382,7 @@ pub struct GroupVersion {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBulkDeploymentDetailedReportsRequest {
     /// <p>The ID of the bulk deployment.</p>
     #[serde(rename = "BulkDeploymentId")]
@@ -2343,7 +2398,7 @@ pub struct ListBulkDeploymentDetailedReportsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBulkDeploymentDetailedReportsResponse {
     /// <p>A list of the individual group deployments in the bulk deployment operation.</p>
     #[serde(rename = "Deployments")]
@@ -2356,6 +2411,7 @@ pub struct ListBulkDeploymentDetailedReportsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBulkDeploymentsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2368,7 +2424,7 @@ pub struct ListBulkDeploymentsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBulkDeploymentsResponse {
     /// <p>A list of bulk deployments.</p>
     #[serde(rename = "BulkDeployments")]
@@ -2381,6 +2437,7 @@ pub struct ListBulkDeploymentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConnectorDefinitionVersionsRequest {
     /// <p>The ID of the connector definition.</p>
     #[serde(rename = "ConnectorDefinitionId")]
@@ -2396,7 +2453,7 @@ pub struct ListConnectorDefinitionVersionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListConnectorDefinitionVersionsResponse {
     /// <p>The token for the next set of results, or &#39;&#39;null&#39;&#39; if there are no additional results.</p>
     #[serde(rename = "NextToken")]
@@ -2409,6 +2466,7 @@ pub struct ListConnectorDefinitionVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConnectorDefinitionsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2421,7 +2479,7 @@ pub struct ListConnectorDefinitionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListConnectorDefinitionsResponse {
     /// <p>Information about a definition.</p>
     #[serde(rename = "Definitions")]
@@ -2434,6 +2492,7 @@ pub struct ListConnectorDefinitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCoreDefinitionVersionsRequest {
     /// <p>The ID of the core definition.</p>
     #[serde(rename = "CoreDefinitionId")]
@@ -2449,7 +2508,7 @@ pub struct ListCoreDefinitionVersionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCoreDefinitionVersionsResponse {
     /// <p>The token for the next set of results, or &#39;&#39;null&#39;&#39; if there are no additional results.</p>
     #[serde(rename = "NextToken")]
@@ -2462,6 +2521,7 @@ pub struct ListCoreDefinitionVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCoreDefinitionsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2474,7 +2534,7 @@ pub struct ListCoreDefinitionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCoreDefinitionsResponse {
     /// <p>Information about a definition.</p>
     #[serde(rename = "Definitions")]
@@ -2487,6 +2547,7 @@ pub struct ListCoreDefinitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeploymentsRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -2502,7 +2563,7 @@ pub struct ListDeploymentsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeploymentsResponse {
     /// <p>A list of deployments for the requested groups.</p>
     #[serde(rename = "Deployments")]
@@ -2515,6 +2576,7 @@ pub struct ListDeploymentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeviceDefinitionVersionsRequest {
     /// <p>The ID of the device definition.</p>
     #[serde(rename = "DeviceDefinitionId")]
@@ -2530,7 +2592,7 @@ pub struct ListDeviceDefinitionVersionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeviceDefinitionVersionsResponse {
     /// <p>The token for the next set of results, or &#39;&#39;null&#39;&#39; if there are no additional results.</p>
     #[serde(rename = "NextToken")]
@@ -2543,6 +2605,7 @@ pub struct ListDeviceDefinitionVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeviceDefinitionsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2555,7 +2618,7 @@ pub struct ListDeviceDefinitionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeviceDefinitionsResponse {
     /// <p>Information about a definition.</p>
     #[serde(rename = "Definitions")]
@@ -2568,6 +2631,7 @@ pub struct ListDeviceDefinitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFunctionDefinitionVersionsRequest {
     /// <p>The ID of the Lambda function definition.</p>
     #[serde(rename = "FunctionDefinitionId")]
@@ -2583,7 +2647,7 @@ pub struct ListFunctionDefinitionVersionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFunctionDefinitionVersionsResponse {
     /// <p>The token for the next set of results, or &#39;&#39;null&#39;&#39; if there are no additional results.</p>
     #[serde(rename = "NextToken")]
@@ -2596,6 +2660,7 @@ pub struct ListFunctionDefinitionVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFunctionDefinitionsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2608,7 +2673,7 @@ pub struct ListFunctionDefinitionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFunctionDefinitionsResponse {
     /// <p>Information about a definition.</p>
     #[serde(rename = "Definitions")]
@@ -2621,6 +2686,7 @@ pub struct ListFunctionDefinitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGroupCertificateAuthoritiesRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -2628,7 +2694,7 @@ pub struct ListGroupCertificateAuthoritiesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGroupCertificateAuthoritiesResponse {
     /// <p>A list of certificate authorities associated with the group.</p>
     #[serde(rename = "GroupCertificateAuthorities")]
@@ -2637,6 +2703,7 @@ pub struct ListGroupCertificateAuthoritiesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGroupVersionsRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -2652,7 +2719,7 @@ pub struct ListGroupVersionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGroupVersionsResponse {
     /// <p>The token for the next set of results, or &#39;&#39;null&#39;&#39; if there are no additional results.</p>
     #[serde(rename = "NextToken")]
@@ -2665,6 +2732,7 @@ pub struct ListGroupVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGroupsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2677,7 +2745,7 @@ pub struct ListGroupsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGroupsResponse {
     /// <p>Information about a group.</p>
     #[serde(rename = "Groups")]
@@ -2690,6 +2758,7 @@ pub struct ListGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLoggerDefinitionVersionsRequest {
     /// <p>The ID of the logger definition.</p>
     #[serde(rename = "LoggerDefinitionId")]
@@ -2705,7 +2774,7 @@ pub struct ListLoggerDefinitionVersionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLoggerDefinitionVersionsResponse {
     /// <p>The token for the next set of results, or &#39;&#39;null&#39;&#39; if there are no additional results.</p>
     #[serde(rename = "NextToken")]
@@ -2718,6 +2787,7 @@ pub struct ListLoggerDefinitionVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLoggerDefinitionsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2730,7 +2800,7 @@ pub struct ListLoggerDefinitionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLoggerDefinitionsResponse {
     /// <p>Information about a definition.</p>
     #[serde(rename = "Definitions")]
@@ -2743,6 +2813,7 @@ pub struct ListLoggerDefinitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourceDefinitionVersionsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2758,7 +2829,7 @@ pub struct ListResourceDefinitionVersionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourceDefinitionVersionsResponse {
     /// <p>The token for the next set of results, or &#39;&#39;null&#39;&#39; if there are no additional results.</p>
     #[serde(rename = "NextToken")]
@@ -2771,6 +2842,7 @@ pub struct ListResourceDefinitionVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourceDefinitionsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2783,7 +2855,7 @@ pub struct ListResourceDefinitionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourceDefinitionsResponse {
     /// <p>Information about a definition.</p>
     #[serde(rename = "Definitions")]
@@ -2796,6 +2868,7 @@ pub struct ListResourceDefinitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSubscriptionDefinitionVersionsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2811,7 +2884,7 @@ pub struct ListSubscriptionDefinitionVersionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSubscriptionDefinitionVersionsResponse {
     /// <p>The token for the next set of results, or &#39;&#39;null&#39;&#39; if there are no additional results.</p>
     #[serde(rename = "NextToken")]
@@ -2824,6 +2897,7 @@ pub struct ListSubscriptionDefinitionVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSubscriptionDefinitionsRequest {
     /// <p>The maximum number of results to be returned per request.</p>
     #[serde(rename = "MaxResults")]
@@ -2836,7 +2910,7 @@ pub struct ListSubscriptionDefinitionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSubscriptionDefinitionsResponse {
     /// <p>Information about a definition.</p>
     #[serde(rename = "Definitions")]
@@ -2849,6 +2923,7 @@ pub struct ListSubscriptionDefinitionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -2856,7 +2931,7 @@ pub struct ListTagsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2925,6 +3000,7 @@ pub struct LoggerDefinitionVersion {
 
 /// <p>Information needed to reset deployments.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResetDeploymentsRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -2940,7 +3016,7 @@ pub struct ResetDeploymentsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResetDeploymentsResponse {
     /// <p>The ARN of the deployment.</p>
     #[serde(rename = "DeploymentArn")]
@@ -3053,6 +3129,7 @@ pub struct SecretsManagerSecretResourceData {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartBulkDeploymentRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -3071,7 +3148,7 @@ pub struct StartBulkDeploymentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartBulkDeploymentResponse {
     /// <p>The ARN of the bulk deployment.</p>
     #[serde(rename = "BulkDeploymentArn")]
@@ -3084,6 +3161,7 @@ pub struct StartBulkDeploymentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopBulkDeploymentRequest {
     /// <p>The ID of the bulk deployment.</p>
     #[serde(rename = "BulkDeploymentId")]
@@ -3091,7 +3169,7 @@ pub struct StopBulkDeploymentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopBulkDeploymentResponse {}
 
 /// <p>Information about a subscription.</p>
@@ -3122,6 +3200,7 @@ pub struct SubscriptionDefinitionVersion {
 
 /// <p>A map of the key-value pairs for the resource tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -3132,6 +3211,7 @@ pub struct TagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -3143,6 +3223,7 @@ pub struct UntagResourceRequest {
 
 /// <p>Connectivity information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConnectivityInfoRequest {
     /// <p>A list of connectivity info.</p>
     #[serde(rename = "ConnectivityInfo")]
@@ -3154,7 +3235,7 @@ pub struct UpdateConnectivityInfoRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateConnectivityInfoResponse {
     /// <p>A message about the connectivity info update request.</p>
     #[serde(rename = "Message")]
@@ -3167,6 +3248,7 @@ pub struct UpdateConnectivityInfoResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConnectorDefinitionRequest {
     /// <p>The ID of the connector definition.</p>
     #[serde(rename = "ConnectorDefinitionId")]
@@ -3178,10 +3260,11 @@ pub struct UpdateConnectorDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateConnectorDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCoreDefinitionRequest {
     /// <p>The ID of the core definition.</p>
     #[serde(rename = "CoreDefinitionId")]
@@ -3193,10 +3276,11 @@ pub struct UpdateCoreDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateCoreDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDeviceDefinitionRequest {
     /// <p>The ID of the device definition.</p>
     #[serde(rename = "DeviceDefinitionId")]
@@ -3208,10 +3292,11 @@ pub struct UpdateDeviceDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDeviceDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFunctionDefinitionRequest {
     /// <p>The ID of the Lambda function definition.</p>
     #[serde(rename = "FunctionDefinitionId")]
@@ -3223,10 +3308,11 @@ pub struct UpdateFunctionDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFunctionDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGroupCertificateConfigurationRequest {
     /// <p>The amount of time remaining before the certificate expires, in milliseconds.</p>
     #[serde(rename = "CertificateExpiryInMilliseconds")]
@@ -3238,7 +3324,7 @@ pub struct UpdateGroupCertificateConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGroupCertificateConfigurationResponse {
     /// <p>The amount of time remaining before the certificate authority expires, in milliseconds.</p>
     #[serde(rename = "CertificateAuthorityExpiryInMilliseconds")]
@@ -3255,6 +3341,7 @@ pub struct UpdateGroupCertificateConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGroupRequest {
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
@@ -3266,10 +3353,11 @@ pub struct UpdateGroupRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGroupResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateLoggerDefinitionRequest {
     /// <p>The ID of the logger definition.</p>
     #[serde(rename = "LoggerDefinitionId")]
@@ -3281,10 +3369,11 @@ pub struct UpdateLoggerDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateLoggerDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateResourceDefinitionRequest {
     /// <p>The name of the definition.</p>
     #[serde(rename = "Name")]
@@ -3296,10 +3385,11 @@ pub struct UpdateResourceDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateResourceDefinitionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSubscriptionDefinitionRequest {
     /// <p>The name of the definition.</p>
     #[serde(rename = "Name")]
@@ -3311,12 +3401,12 @@ pub struct UpdateSubscriptionDefinitionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSubscriptionDefinitionResponse {}
 
 /// <p>Information about a version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VersionInformation {
     /// <p>The ARN of the version.</p>
     #[serde(rename = "Arn")]
@@ -3357,7 +3447,12 @@ impl AssociateRoleToGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3402,7 +3497,12 @@ impl AssociateServiceRoleToAccountError {
                         AssociateServiceRoleToAccountError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3438,7 +3538,12 @@ impl CreateConnectorDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3475,7 +3580,12 @@ impl CreateConnectorDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3508,7 +3618,12 @@ impl CreateCoreDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(CreateCoreDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3545,7 +3660,12 @@ impl CreateCoreDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3578,7 +3698,12 @@ impl CreateDeploymentError {
                 "BadRequestException" => {
                     return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3611,7 +3736,12 @@ impl CreateDeviceDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(CreateDeviceDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3648,7 +3778,12 @@ impl CreateDeviceDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3681,7 +3816,12 @@ impl CreateFunctionDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(CreateFunctionDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3718,7 +3858,12 @@ impl CreateFunctionDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3751,7 +3896,12 @@ impl CreateGroupError {
                 "BadRequestException" => {
                     return RusotoError::Service(CreateGroupError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3795,7 +3945,12 @@ impl CreateGroupCertificateAuthorityError {
                         CreateGroupCertificateAuthorityError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3829,7 +3984,12 @@ impl CreateGroupVersionError {
                 "BadRequestException" => {
                     return RusotoError::Service(CreateGroupVersionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3862,7 +4022,12 @@ impl CreateLoggerDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(CreateLoggerDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3899,7 +4064,12 @@ impl CreateLoggerDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3932,7 +4102,12 @@ impl CreateResourceDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(CreateResourceDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3969,7 +4144,12 @@ impl CreateResourceDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4009,7 +4189,12 @@ impl CreateSoftwareUpdateJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4047,7 +4232,12 @@ impl CreateSubscriptionDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4084,7 +4274,12 @@ impl CreateSubscriptionDefinitionVersionError {
                         CreateSubscriptionDefinitionVersionError::BadRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4119,7 +4314,12 @@ impl DeleteConnectorDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4152,7 +4352,12 @@ impl DeleteCoreDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(DeleteCoreDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4185,7 +4390,12 @@ impl DeleteDeviceDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(DeleteDeviceDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4218,7 +4428,12 @@ impl DeleteFunctionDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(DeleteFunctionDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4251,7 +4466,12 @@ impl DeleteGroupError {
                 "BadRequestException" => {
                     return RusotoError::Service(DeleteGroupError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4284,7 +4504,12 @@ impl DeleteLoggerDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(DeleteLoggerDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4317,7 +4542,12 @@ impl DeleteResourceDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(DeleteResourceDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4354,7 +4584,12 @@ impl DeleteSubscriptionDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4396,7 +4631,12 @@ impl DisassociateRoleFromGroupError {
                         DisassociateRoleFromGroupError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4434,7 +4674,12 @@ impl DisassociateServiceRoleFromAccountError {
                         DisassociateServiceRoleFromAccountError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4474,7 +4719,12 @@ impl GetAssociatedRoleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4508,7 +4758,12 @@ impl GetBulkDeploymentStatusError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetBulkDeploymentStatusError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4548,7 +4803,12 @@ impl GetConnectivityInfoError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4582,7 +4842,12 @@ impl GetConnectorDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetConnectorDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4619,7 +4884,12 @@ impl GetConnectorDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4652,7 +4922,12 @@ impl GetCoreDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetCoreDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4685,7 +4960,12 @@ impl GetCoreDefinitionVersionError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetCoreDefinitionVersionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4718,7 +4998,12 @@ impl GetDeploymentStatusError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetDeploymentStatusError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4751,7 +5036,12 @@ impl GetDeviceDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetDeviceDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4788,7 +5078,12 @@ impl GetDeviceDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4821,7 +5116,12 @@ impl GetFunctionDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetFunctionDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4858,7 +5158,12 @@ impl GetFunctionDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4891,7 +5196,12 @@ impl GetGroupError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetGroupError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4935,7 +5245,12 @@ impl GetGroupCertificateAuthorityError {
                         GetGroupCertificateAuthorityError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4980,7 +5295,12 @@ impl GetGroupCertificateConfigurationError {
                         GetGroupCertificateConfigurationError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5014,7 +5334,12 @@ impl GetGroupVersionError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetGroupVersionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5047,7 +5372,12 @@ impl GetLoggerDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetLoggerDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5084,7 +5414,12 @@ impl GetLoggerDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5117,7 +5452,12 @@ impl GetResourceDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(GetResourceDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5154,7 +5494,12 @@ impl GetResourceDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5189,7 +5534,12 @@ impl GetServiceRoleForAccountError {
                         GetServiceRoleForAccountError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5224,7 +5574,12 @@ impl GetSubscriptionDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5261,7 +5616,12 @@ impl GetSubscriptionDefinitionVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5298,7 +5658,12 @@ impl ListBulkDeploymentDetailedReportsError {
                         ListBulkDeploymentDetailedReportsError::BadRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5331,7 +5696,12 @@ impl ListBulkDeploymentsError {
                 "BadRequestException" => {
                     return RusotoError::Service(ListBulkDeploymentsError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5368,7 +5738,12 @@ impl ListConnectorDefinitionVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5395,7 +5770,12 @@ impl ListConnectorDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListConnectorDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5430,7 +5810,12 @@ impl ListCoreDefinitionVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5457,7 +5842,12 @@ impl ListCoreDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListCoreDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5488,7 +5878,12 @@ impl ListDeploymentsError {
                 "BadRequestException" => {
                     return RusotoError::Service(ListDeploymentsError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5525,7 +5920,12 @@ impl ListDeviceDefinitionVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5552,7 +5952,12 @@ impl ListDeviceDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListDeviceDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5587,7 +5992,12 @@ impl ListFunctionDefinitionVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5614,7 +6024,12 @@ impl ListFunctionDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListFunctionDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5656,7 +6071,12 @@ impl ListGroupCertificateAuthoritiesError {
                         ListGroupCertificateAuthoritiesError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5690,7 +6110,12 @@ impl ListGroupVersionsError {
                 "BadRequestException" => {
                     return RusotoError::Service(ListGroupVersionsError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5717,7 +6142,12 @@ impl ListGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListGroupsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5752,7 +6182,12 @@ impl ListLoggerDefinitionVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5779,7 +6214,12 @@ impl ListLoggerDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListLoggerDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5814,7 +6254,12 @@ impl ListResourceDefinitionVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5841,7 +6286,12 @@ impl ListResourceDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListResourceDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5876,7 +6326,12 @@ impl ListSubscriptionDefinitionVersionsError {
                         ListSubscriptionDefinitionVersionsError::BadRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5905,7 +6360,12 @@ impl ListSubscriptionDefinitionsError {
     ) -> RusotoError<ListSubscriptionDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5936,7 +6396,12 @@ impl ListTagsForResourceError {
                 "BadRequestException" => {
                     return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5969,7 +6434,12 @@ impl ResetDeploymentsError {
                 "BadRequestException" => {
                     return RusotoError::Service(ResetDeploymentsError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6002,7 +6472,12 @@ impl StartBulkDeploymentError {
                 "BadRequestException" => {
                     return RusotoError::Service(StartBulkDeploymentError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6035,7 +6510,12 @@ impl StopBulkDeploymentError {
                 "BadRequestException" => {
                     return RusotoError::Service(StopBulkDeploymentError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6068,7 +6548,12 @@ impl TagResourceError {
                 "BadRequestException" => {
                     return RusotoError::Service(TagResourceError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6101,7 +6586,12 @@ impl UntagResourceError {
                 "BadRequestException" => {
                     return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6141,7 +6631,12 @@ impl UpdateConnectivityInfoError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6177,7 +6672,12 @@ impl UpdateConnectorDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6210,7 +6710,12 @@ impl UpdateCoreDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(UpdateCoreDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6243,7 +6748,12 @@ impl UpdateDeviceDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(UpdateDeviceDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6276,7 +6786,12 @@ impl UpdateFunctionDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(UpdateFunctionDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6309,7 +6824,12 @@ impl UpdateGroupError {
                 "BadRequestException" => {
                     return RusotoError::Service(UpdateGroupError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6353,7 +6873,12 @@ impl UpdateGroupCertificateConfigurationError {
                         UpdateGroupCertificateConfigurationError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6387,7 +6912,12 @@ impl UpdateLoggerDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(UpdateLoggerDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6420,7 +6950,12 @@ impl UpdateResourceDefinitionError {
                 "BadRequestException" => {
                     return RusotoError::Service(UpdateResourceDefinitionError::BadRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6457,7 +6992,12 @@ impl UpdateSubscriptionDefinitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7022,756 +7562,135 @@ pub trait GreenGrass {
         input: UpdateSubscriptionDefinitionRequest,
     ) -> RusotoFuture<UpdateSubscriptionDefinitionResponse, UpdateSubscriptionDefinitionError>;
 }
-/// A client for the AWS Greengrass API.
-#[derive(Clone)]
-pub struct GreenGrassClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl GreenGrassClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> GreenGrassClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> GreenGrassClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> GreenGrassClient {
-        GreenGrassClient { client, region }
-    }
-}
-
-impl GreenGrass for GreenGrassClient {
-    /// <p>Associates a role with a group. Your Greengrass core will use the role to access AWS cloud services. The role&#39;s permissions should allow Greengrass core Lambda functions to perform actions against the cloud.</p>
+const _: Option<&dyn GreenGrass> = None;
+impl<'a, T: GreenGrass + ?Sized> GreenGrass for &'a T {
     fn associate_role_to_group(
         &self,
         input: AssociateRoleToGroupRequest,
     ) -> RusotoFuture<AssociateRoleToGroupResponse, AssociateRoleToGroupError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/role",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateRoleToGroupResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AssociateRoleToGroupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).associate_role_to_group(input)
     }
-
-    /// <p>Associates a role with your account. AWS IoT Greengrass will use the role to access your Lambda functions and AWS IoT resources. This is necessary for deployments to succeed. The role must have at least minimum permissions in the policy &#39;&#39;AWSGreengrassResourceAccessRolePolicy&#39;&#39;.</p>
     fn associate_service_role_to_account(
         &self,
         input: AssociateServiceRoleToAccountRequest,
     ) -> RusotoFuture<AssociateServiceRoleToAccountResponse, AssociateServiceRoleToAccountError>
     {
-        let request_uri = "/greengrass/servicerole";
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateServiceRoleToAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateServiceRoleToAccountError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_service_role_to_account(input)
     }
-
-    /// <p>Creates a connector definition. You may provide the initial version of the connector definition now or use &#39;&#39;CreateConnectorDefinitionVersion&#39;&#39; at a later time.</p>
     fn create_connector_definition(
         &self,
         input: CreateConnectorDefinitionRequest,
     ) -> RusotoFuture<CreateConnectorDefinitionResponse, CreateConnectorDefinitionError> {
-        let request_uri = "/greengrass/definition/connectors";
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateConnectorDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateConnectorDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_connector_definition(input)
     }
-
-    /// <p>Creates a version of a connector definition which has already been defined.</p>
     fn create_connector_definition_version(
         &self,
         input: CreateConnectorDefinitionVersionRequest,
     ) -> RusotoFuture<CreateConnectorDefinitionVersionResponse, CreateConnectorDefinitionVersionError>
     {
-        let request_uri = format!(
-            "/greengrass/definition/connectors/{connector_definition_id}/versions",
-            connector_definition_id = input.connector_definition_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateConnectorDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateConnectorDefinitionVersionError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_connector_definition_version(input)
     }
-
-    /// <p>Creates a core definition. You may provide the initial version of the core definition now or use &#39;&#39;CreateCoreDefinitionVersion&#39;&#39; at a later time. Greengrass groups must each contain exactly one Greengrass core.</p>
     fn create_core_definition(
         &self,
         input: CreateCoreDefinitionRequest,
     ) -> RusotoFuture<CreateCoreDefinitionResponse, CreateCoreDefinitionError> {
-        let request_uri = "/greengrass/definition/cores";
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCoreDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateCoreDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_core_definition(input)
     }
-
-    /// <p>Creates a version of a core definition that has already been defined. Greengrass groups must each contain exactly one Greengrass core.</p>
     fn create_core_definition_version(
         &self,
         input: CreateCoreDefinitionVersionRequest,
     ) -> RusotoFuture<CreateCoreDefinitionVersionResponse, CreateCoreDefinitionVersionError> {
-        let request_uri = format!(
-            "/greengrass/definition/cores/{core_definition_id}/versions",
-            core_definition_id = input.core_definition_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCoreDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateCoreDefinitionVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_core_definition_version(input)
     }
-
-    /// <p>Creates a deployment. &#39;&#39;CreateDeployment&#39;&#39; requests are idempotent with respect to the &#39;&#39;X-Amzn-Client-Token&#39;&#39; token and the request parameters.</p>
     fn create_deployment(
         &self,
         input: CreateDeploymentRequest,
     ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/deployments",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDeploymentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_deployment(input)
     }
-
-    /// <p>Creates a device definition. You may provide the initial version of the device definition now or use &#39;&#39;CreateDeviceDefinitionVersion&#39;&#39; at a later time.</p>
     fn create_device_definition(
         &self,
         input: CreateDeviceDefinitionRequest,
     ) -> RusotoFuture<CreateDeviceDefinitionResponse, CreateDeviceDefinitionError> {
-        let request_uri = "/greengrass/definition/devices";
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDeviceDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateDeviceDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_device_definition(input)
     }
-
-    /// <p>Creates a version of a device definition that has already been defined.</p>
     fn create_device_definition_version(
         &self,
         input: CreateDeviceDefinitionVersionRequest,
     ) -> RusotoFuture<CreateDeviceDefinitionVersionResponse, CreateDeviceDefinitionVersionError>
     {
-        let request_uri = format!(
-            "/greengrass/definition/devices/{device_definition_id}/versions",
-            device_definition_id = input.device_definition_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDeviceDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDeviceDefinitionVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_device_definition_version(input)
     }
-
-    /// <p>Creates a Lambda function definition which contains a list of Lambda functions and their configurations to be used in a group. You can create an initial version of the definition by providing a list of Lambda functions and their configurations now, or use &#39;&#39;CreateFunctionDefinitionVersion&#39;&#39; later.</p>
     fn create_function_definition(
         &self,
         input: CreateFunctionDefinitionRequest,
     ) -> RusotoFuture<CreateFunctionDefinitionResponse, CreateFunctionDefinitionError> {
-        let request_uri = "/greengrass/definition/functions";
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFunctionDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateFunctionDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_function_definition(input)
     }
-
-    /// <p>Creates a version of a Lambda function definition that has already been defined.</p>
     fn create_function_definition_version(
         &self,
         input: CreateFunctionDefinitionVersionRequest,
     ) -> RusotoFuture<CreateFunctionDefinitionVersionResponse, CreateFunctionDefinitionVersionError>
     {
-        let request_uri = format!(
-            "/greengrass/definition/functions/{function_definition_id}/versions",
-            function_definition_id = input.function_definition_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFunctionDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateFunctionDefinitionVersionError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_function_definition_version(input)
     }
-
-    /// <p>Creates a group. You may provide the initial version of the group or use &#39;&#39;CreateGroupVersion&#39;&#39; at a later time. Tip: You can use the &#39;&#39;gg<em>group</em>setup&#39;&#39; package (https://github.com/awslabs/aws-greengrass-group-setup) as a library or command-line application to create and deploy Greengrass groups.</p>
     fn create_group(
         &self,
         input: CreateGroupRequest,
     ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
-        let request_uri = "/greengrass/groups";
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGroupResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_group(input)
     }
-
-    /// <p>Creates a CA for the group. If a CA already exists, it will rotate the existing CA.</p>
     fn create_group_certificate_authority(
         &self,
         input: CreateGroupCertificateAuthorityRequest,
     ) -> RusotoFuture<CreateGroupCertificateAuthorityResponse, CreateGroupCertificateAuthorityError>
     {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/certificateauthorities",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGroupCertificateAuthorityResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateGroupCertificateAuthorityError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_group_certificate_authority(input)
     }
-
-    /// <p>Creates a version of a group which has already been defined.</p>
     fn create_group_version(
         &self,
         input: CreateGroupVersionRequest,
     ) -> RusotoFuture<CreateGroupVersionResponse, CreateGroupVersionError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/versions",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGroupVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGroupVersionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_group_version(input)
     }
-
-    /// <p>Creates a logger definition. You may provide the initial version of the logger definition now or use &#39;&#39;CreateLoggerDefinitionVersion&#39;&#39; at a later time.</p>
     fn create_logger_definition(
         &self,
         input: CreateLoggerDefinitionRequest,
     ) -> RusotoFuture<CreateLoggerDefinitionResponse, CreateLoggerDefinitionError> {
-        let request_uri = "/greengrass/definition/loggers";
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateLoggerDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateLoggerDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_logger_definition(input)
     }
-
-    /// <p>Creates a version of a logger definition that has already been defined.</p>
     fn create_logger_definition_version(
         &self,
         input: CreateLoggerDefinitionVersionRequest,
     ) -> RusotoFuture<CreateLoggerDefinitionVersionResponse, CreateLoggerDefinitionVersionError>
     {
-        let request_uri = format!(
-            "/greengrass/definition/loggers/{logger_definition_id}/versions",
-            logger_definition_id = input.logger_definition_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateLoggerDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateLoggerDefinitionVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_logger_definition_version(input)
     }
-
-    /// <p>Creates a resource definition which contains a list of resources to be used in a group. You can create an initial version of the definition by providing a list of resources now, or use &#39;&#39;CreateResourceDefinitionVersion&#39;&#39; later.</p>
     fn create_resource_definition(
         &self,
         input: CreateResourceDefinitionRequest,
     ) -> RusotoFuture<CreateResourceDefinitionResponse, CreateResourceDefinitionError> {
-        let request_uri = "/greengrass/definition/resources";
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateResourceDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateResourceDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_resource_definition(input)
     }
-
-    /// <p>Creates a version of a resource definition that has already been defined.</p>
     fn create_resource_definition_version(
         &self,
         input: CreateResourceDefinitionVersionRequest,
     ) -> RusotoFuture<CreateResourceDefinitionVersionResponse, CreateResourceDefinitionVersionError>
     {
-        let request_uri = format!(
-            "/greengrass/definition/resources/{resource_definition_id}/versions",
-            resource_definition_id = input.resource_definition_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateResourceDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateResourceDefinitionVersionError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_resource_definition_version(input)
     }
-
-    /// <p>Creates a software update for a core or group of cores (specified as an IoT thing group.) Use this to update the OTA Agent as well as the Greengrass core software. It makes use of the IoT Jobs feature which provides additional commands to manage a Greengrass core software update job.</p>
     fn create_software_update_job(
         &self,
         input: CreateSoftwareUpdateJobRequest,
     ) -> RusotoFuture<CreateSoftwareUpdateJobResponse, CreateSoftwareUpdateJobError> {
-        let request_uri = "/greengrass/updates";
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSoftwareUpdateJobResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateSoftwareUpdateJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_software_update_job(input)
     }
-
-    /// <p>Creates a subscription definition. You may provide the initial version of the subscription definition now or use &#39;&#39;CreateSubscriptionDefinitionVersion&#39;&#39; at a later time.</p>
     fn create_subscription_definition(
         &self,
         input: CreateSubscriptionDefinitionRequest,
     ) -> RusotoFuture<CreateSubscriptionDefinitionResponse, CreateSubscriptionDefinitionError> {
-        let request_uri = "/greengrass/definition/subscriptions";
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSubscriptionDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateSubscriptionDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_subscription_definition(input)
     }
-
-    /// <p>Creates a version of a subscription definition which has already been defined.</p>
     fn create_subscription_definition_version(
         &self,
         input: CreateSubscriptionDefinitionVersionRequest,
@@ -7779,1039 +7698,207 @@ impl GreenGrass for GreenGrassClient {
         CreateSubscriptionDefinitionVersionResponse,
         CreateSubscriptionDefinitionVersionError,
     > {
-        let request_uri = format!(
-            "/greengrass/definition/subscriptions/{subscription_definition_id}/versions",
-            subscription_definition_id = input.subscription_definition_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSubscriptionDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateSubscriptionDefinitionVersionError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_subscription_definition_version(input)
     }
-
-    /// <p>Deletes a connector definition.</p>
     fn delete_connector_definition(
         &self,
         input: DeleteConnectorDefinitionRequest,
     ) -> RusotoFuture<DeleteConnectorDefinitionResponse, DeleteConnectorDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/connectors/{connector_definition_id}",
-            connector_definition_id = input.connector_definition_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteConnectorDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteConnectorDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_connector_definition(input)
     }
-
-    /// <p>Deletes a core definition.</p>
     fn delete_core_definition(
         &self,
         input: DeleteCoreDefinitionRequest,
     ) -> RusotoFuture<DeleteCoreDefinitionResponse, DeleteCoreDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/cores/{core_definition_id}",
-            core_definition_id = input.core_definition_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteCoreDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteCoreDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_core_definition(input)
     }
-
-    /// <p>Deletes a device definition.</p>
     fn delete_device_definition(
         &self,
         input: DeleteDeviceDefinitionRequest,
     ) -> RusotoFuture<DeleteDeviceDefinitionResponse, DeleteDeviceDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/devices/{device_definition_id}",
-            device_definition_id = input.device_definition_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDeviceDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteDeviceDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_device_definition(input)
     }
-
-    /// <p>Deletes a Lambda function definition.</p>
     fn delete_function_definition(
         &self,
         input: DeleteFunctionDefinitionRequest,
     ) -> RusotoFuture<DeleteFunctionDefinitionResponse, DeleteFunctionDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/functions/{function_definition_id}",
-            function_definition_id = input.function_definition_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteFunctionDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteFunctionDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_function_definition(input)
     }
-
-    /// <p>Deletes a group.</p>
     fn delete_group(
         &self,
         input: DeleteGroupRequest,
     ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
-        let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteGroupResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_group(input)
     }
-
-    /// <p>Deletes a logger definition.</p>
     fn delete_logger_definition(
         &self,
         input: DeleteLoggerDefinitionRequest,
     ) -> RusotoFuture<DeleteLoggerDefinitionResponse, DeleteLoggerDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/loggers/{logger_definition_id}",
-            logger_definition_id = input.logger_definition_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteLoggerDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteLoggerDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_logger_definition(input)
     }
-
-    /// <p>Deletes a resource definition.</p>
     fn delete_resource_definition(
         &self,
         input: DeleteResourceDefinitionRequest,
     ) -> RusotoFuture<DeleteResourceDefinitionResponse, DeleteResourceDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/resources/{resource_definition_id}",
-            resource_definition_id = input.resource_definition_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteResourceDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteResourceDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_resource_definition(input)
     }
-
-    /// <p>Deletes a subscription definition.</p>
     fn delete_subscription_definition(
         &self,
         input: DeleteSubscriptionDefinitionRequest,
     ) -> RusotoFuture<DeleteSubscriptionDefinitionResponse, DeleteSubscriptionDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/subscriptions/{subscription_definition_id}",
-            subscription_definition_id = input.subscription_definition_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSubscriptionDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteSubscriptionDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_subscription_definition(input)
     }
-
-    /// <p>Disassociates the role from a group.</p>
     fn disassociate_role_from_group(
         &self,
         input: DisassociateRoleFromGroupRequest,
     ) -> RusotoFuture<DisassociateRoleFromGroupResponse, DisassociateRoleFromGroupError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/role",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateRoleFromGroupResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateRoleFromGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_role_from_group(input)
     }
-
-    /// <p>Disassociates the service role from your account. Without a service role, deployments will not work.</p>
     fn disassociate_service_role_from_account(
         &self,
     ) -> RusotoFuture<
         DisassociateServiceRoleFromAccountResponse,
         DisassociateServiceRoleFromAccountError,
     > {
-        let request_uri = "/greengrass/servicerole";
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateServiceRoleFromAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateServiceRoleFromAccountError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disassociate_service_role_from_account()
     }
-
-    /// <p>Retrieves the role associated with a particular group.</p>
     fn get_associated_role(
         &self,
         input: GetAssociatedRoleRequest,
     ) -> RusotoFuture<GetAssociatedRoleResponse, GetAssociatedRoleError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/role",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAssociatedRoleResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAssociatedRoleError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_associated_role(input)
     }
-
-    /// <p>Returns the status of a bulk deployment.</p>
     fn get_bulk_deployment_status(
         &self,
         input: GetBulkDeploymentStatusRequest,
     ) -> RusotoFuture<GetBulkDeploymentStatusResponse, GetBulkDeploymentStatusError> {
-        let request_uri = format!(
-            "/greengrass/bulk/deployments/{bulk_deployment_id}/status",
-            bulk_deployment_id = input.bulk_deployment_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBulkDeploymentStatusResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBulkDeploymentStatusError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_bulk_deployment_status(input)
     }
-
-    /// <p>Retrieves the connectivity information for a core.</p>
     fn get_connectivity_info(
         &self,
         input: GetConnectivityInfoRequest,
     ) -> RusotoFuture<GetConnectivityInfoResponse, GetConnectivityInfoError> {
-        let request_uri = format!(
-            "/greengrass/things/{thing_name}/connectivityInfo",
-            thing_name = input.thing_name
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetConnectivityInfoResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetConnectivityInfoError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_connectivity_info(input)
     }
-
-    /// <p>Retrieves information about a connector definition.</p>
     fn get_connector_definition(
         &self,
         input: GetConnectorDefinitionRequest,
     ) -> RusotoFuture<GetConnectorDefinitionResponse, GetConnectorDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/connectors/{connector_definition_id}",
-            connector_definition_id = input.connector_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetConnectorDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetConnectorDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_connector_definition(input)
     }
-
-    /// <p>Retrieves information about a connector definition version, including the connectors that the version contains. Connectors are prebuilt modules that interact with local infrastructure, device protocols, AWS, and other cloud services.</p>
     fn get_connector_definition_version(
         &self,
         input: GetConnectorDefinitionVersionRequest,
     ) -> RusotoFuture<GetConnectorDefinitionVersionResponse, GetConnectorDefinitionVersionError>
     {
-        let request_uri = format!("/greengrass/definition/connectors/{connector_definition_id}/versions/{connector_definition_version_id}", connector_definition_id = input.connector_definition_id, connector_definition_version_id = input.connector_definition_version_id);
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetConnectorDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetConnectorDefinitionVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_connector_definition_version(input)
     }
-
-    /// <p>Retrieves information about a core definition version.</p>
     fn get_core_definition(
         &self,
         input: GetCoreDefinitionRequest,
     ) -> RusotoFuture<GetCoreDefinitionResponse, GetCoreDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/cores/{core_definition_id}",
-            core_definition_id = input.core_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCoreDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetCoreDefinitionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_core_definition(input)
     }
-
-    /// <p>Retrieves information about a core definition version.</p>
     fn get_core_definition_version(
         &self,
         input: GetCoreDefinitionVersionRequest,
     ) -> RusotoFuture<GetCoreDefinitionVersionResponse, GetCoreDefinitionVersionError> {
-        let request_uri = format!("/greengrass/definition/cores/{core_definition_id}/versions/{core_definition_version_id}", core_definition_id = input.core_definition_id, core_definition_version_id = input.core_definition_version_id);
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCoreDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetCoreDefinitionVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_core_definition_version(input)
     }
-
-    /// <p>Returns the status of a deployment.</p>
     fn get_deployment_status(
         &self,
         input: GetDeploymentStatusRequest,
     ) -> RusotoFuture<GetDeploymentStatusResponse, GetDeploymentStatusError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/deployments/{deployment_id}/status",
-            deployment_id = input.deployment_id,
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeploymentStatusResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetDeploymentStatusError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_deployment_status(input)
     }
-
-    /// <p>Retrieves information about a device definition.</p>
     fn get_device_definition(
         &self,
         input: GetDeviceDefinitionRequest,
     ) -> RusotoFuture<GetDeviceDefinitionResponse, GetDeviceDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/devices/{device_definition_id}",
-            device_definition_id = input.device_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeviceDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetDeviceDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_device_definition(input)
     }
-
-    /// <p>Retrieves information about a device definition version.</p>
     fn get_device_definition_version(
         &self,
         input: GetDeviceDefinitionVersionRequest,
     ) -> RusotoFuture<GetDeviceDefinitionVersionResponse, GetDeviceDefinitionVersionError> {
-        let request_uri = format!("/greengrass/definition/devices/{device_definition_id}/versions/{device_definition_version_id}", device_definition_id = input.device_definition_id, device_definition_version_id = input.device_definition_version_id);
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeviceDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetDeviceDefinitionVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_device_definition_version(input)
     }
-
-    /// <p>Retrieves information about a Lambda function definition, including its creation time and latest version.</p>
     fn get_function_definition(
         &self,
         input: GetFunctionDefinitionRequest,
     ) -> RusotoFuture<GetFunctionDefinitionResponse, GetFunctionDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/functions/{function_definition_id}",
-            function_definition_id = input.function_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFunctionDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetFunctionDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_function_definition(input)
     }
-
-    /// <p>Retrieves information about a Lambda function definition version, including which Lambda functions are included in the version and their configurations.</p>
     fn get_function_definition_version(
         &self,
         input: GetFunctionDefinitionVersionRequest,
     ) -> RusotoFuture<GetFunctionDefinitionVersionResponse, GetFunctionDefinitionVersionError> {
-        let request_uri = format!("/greengrass/definition/functions/{function_definition_id}/versions/{function_definition_version_id}", function_definition_id = input.function_definition_id, function_definition_version_id = input.function_definition_version_id);
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFunctionDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetFunctionDefinitionVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_function_definition_version(input)
     }
-
-    /// <p>Retrieves information about a group.</p>
     fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
-        let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGroupResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_group(input)
     }
-
-    /// <p>Retreives the CA associated with a group. Returns the public key of the CA.</p>
     fn get_group_certificate_authority(
         &self,
         input: GetGroupCertificateAuthorityRequest,
     ) -> RusotoFuture<GetGroupCertificateAuthorityResponse, GetGroupCertificateAuthorityError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/certificateauthorities/{certificate_authority_id}",
-            certificate_authority_id = input.certificate_authority_id,
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGroupCertificateAuthorityResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetGroupCertificateAuthorityError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_group_certificate_authority(input)
     }
-
-    /// <p>Retrieves the current configuration for the CA used by the group.</p>
     fn get_group_certificate_configuration(
         &self,
         input: GetGroupCertificateConfigurationRequest,
     ) -> RusotoFuture<GetGroupCertificateConfigurationResponse, GetGroupCertificateConfigurationError>
     {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/certificateauthorities/configuration/expiry",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGroupCertificateConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetGroupCertificateConfigurationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_group_certificate_configuration(input)
     }
-
-    /// <p>Retrieves information about a group version.</p>
     fn get_group_version(
         &self,
         input: GetGroupVersionRequest,
     ) -> RusotoFuture<GetGroupVersionResponse, GetGroupVersionError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/versions/{group_version_id}",
-            group_id = input.group_id,
-            group_version_id = input.group_version_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGroupVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetGroupVersionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_group_version(input)
     }
-
-    /// <p>Retrieves information about a logger definition.</p>
     fn get_logger_definition(
         &self,
         input: GetLoggerDefinitionRequest,
     ) -> RusotoFuture<GetLoggerDefinitionResponse, GetLoggerDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/loggers/{logger_definition_id}",
-            logger_definition_id = input.logger_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLoggerDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetLoggerDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_logger_definition(input)
     }
-
-    /// <p>Retrieves information about a logger definition version.</p>
     fn get_logger_definition_version(
         &self,
         input: GetLoggerDefinitionVersionRequest,
     ) -> RusotoFuture<GetLoggerDefinitionVersionResponse, GetLoggerDefinitionVersionError> {
-        let request_uri = format!("/greengrass/definition/loggers/{logger_definition_id}/versions/{logger_definition_version_id}", logger_definition_id = input.logger_definition_id, logger_definition_version_id = input.logger_definition_version_id);
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLoggerDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetLoggerDefinitionVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_logger_definition_version(input)
     }
-
-    /// <p>Retrieves information about a resource definition, including its creation time and latest version.</p>
     fn get_resource_definition(
         &self,
         input: GetResourceDefinitionRequest,
     ) -> RusotoFuture<GetResourceDefinitionResponse, GetResourceDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/resources/{resource_definition_id}",
-            resource_definition_id = input.resource_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetResourceDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetResourceDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_resource_definition(input)
     }
-
-    /// <p>Retrieves information about a resource definition version, including which resources are included in the version.</p>
     fn get_resource_definition_version(
         &self,
         input: GetResourceDefinitionVersionRequest,
     ) -> RusotoFuture<GetResourceDefinitionVersionResponse, GetResourceDefinitionVersionError> {
-        let request_uri = format!("/greengrass/definition/resources/{resource_definition_id}/versions/{resource_definition_version_id}", resource_definition_id = input.resource_definition_id, resource_definition_version_id = input.resource_definition_version_id);
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetResourceDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetResourceDefinitionVersionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_resource_definition_version(input)
     }
-
-    /// <p>Retrieves the service role that is attached to your account.</p>
     fn get_service_role_for_account(
         &self,
     ) -> RusotoFuture<GetServiceRoleForAccountResponse, GetServiceRoleForAccountError> {
-        let request_uri = "/greengrass/servicerole";
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetServiceRoleForAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetServiceRoleForAccountError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_service_role_for_account()
     }
-
-    /// <p>Retrieves information about a subscription definition.</p>
     fn get_subscription_definition(
         &self,
         input: GetSubscriptionDefinitionRequest,
     ) -> RusotoFuture<GetSubscriptionDefinitionResponse, GetSubscriptionDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/subscriptions/{subscription_definition_id}",
-            subscription_definition_id = input.subscription_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSubscriptionDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetSubscriptionDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_subscription_definition(input)
     }
-
-    /// <p>Retrieves information about a subscription definition version.</p>
     fn get_subscription_definition_version(
         &self,
         input: GetSubscriptionDefinitionVersionRequest,
     ) -> RusotoFuture<GetSubscriptionDefinitionVersionResponse, GetSubscriptionDefinitionVersionError>
     {
-        let request_uri = format!("/greengrass/definition/subscriptions/{subscription_definition_id}/versions/{subscription_definition_version_id}", subscription_definition_id = input.subscription_definition_id, subscription_definition_version_id = input.subscription_definition_version_id);
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSubscriptionDefinitionVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetSubscriptionDefinitionVersionError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_subscription_definition_version(input)
     }
-
-    /// <p>Gets a paginated list of the deployments that have been started in a bulk deployment operation, and their current deployment status.</p>
     fn list_bulk_deployment_detailed_reports(
         &self,
         input: ListBulkDeploymentDetailedReportsRequest,
@@ -8819,680 +7906,114 @@ impl GreenGrass for GreenGrassClient {
         ListBulkDeploymentDetailedReportsResponse,
         ListBulkDeploymentDetailedReportsError,
     > {
-        let request_uri = format!(
-            "/greengrass/bulk/deployments/{bulk_deployment_id}/detailed-reports",
-            bulk_deployment_id = input.bulk_deployment_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBulkDeploymentDetailedReportsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListBulkDeploymentDetailedReportsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_bulk_deployment_detailed_reports(input)
     }
-
-    /// <p>Returns a list of bulk deployments.</p>
     fn list_bulk_deployments(
         &self,
         input: ListBulkDeploymentsRequest,
     ) -> RusotoFuture<ListBulkDeploymentsResponse, ListBulkDeploymentsError> {
-        let request_uri = "/greengrass/bulk/deployments";
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBulkDeploymentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListBulkDeploymentsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_bulk_deployments(input)
     }
-
-    /// <p>Lists the versions of a connector definition, which are containers for connectors. Connectors run on the Greengrass core and contain built-in integration with local infrastructure, device protocols, AWS, and other cloud services.</p>
     fn list_connector_definition_versions(
         &self,
         input: ListConnectorDefinitionVersionsRequest,
     ) -> RusotoFuture<ListConnectorDefinitionVersionsResponse, ListConnectorDefinitionVersionsError>
     {
-        let request_uri = format!(
-            "/greengrass/definition/connectors/{connector_definition_id}/versions",
-            connector_definition_id = input.connector_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListConnectorDefinitionVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListConnectorDefinitionVersionsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_connector_definition_versions(input)
     }
-
-    /// <p>Retrieves a list of connector definitions.</p>
     fn list_connector_definitions(
         &self,
         input: ListConnectorDefinitionsRequest,
     ) -> RusotoFuture<ListConnectorDefinitionsResponse, ListConnectorDefinitionsError> {
-        let request_uri = "/greengrass/definition/connectors";
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListConnectorDefinitionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListConnectorDefinitionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_connector_definitions(input)
     }
-
-    /// <p>Lists the versions of a core definition.</p>
     fn list_core_definition_versions(
         &self,
         input: ListCoreDefinitionVersionsRequest,
     ) -> RusotoFuture<ListCoreDefinitionVersionsResponse, ListCoreDefinitionVersionsError> {
-        let request_uri = format!(
-            "/greengrass/definition/cores/{core_definition_id}/versions",
-            core_definition_id = input.core_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCoreDefinitionVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListCoreDefinitionVersionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_core_definition_versions(input)
     }
-
-    /// <p>Retrieves a list of core definitions.</p>
     fn list_core_definitions(
         &self,
         input: ListCoreDefinitionsRequest,
     ) -> RusotoFuture<ListCoreDefinitionsResponse, ListCoreDefinitionsError> {
-        let request_uri = "/greengrass/definition/cores";
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCoreDefinitionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListCoreDefinitionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_core_definitions(input)
     }
-
-    /// <p>Returns a history of deployments for the group.</p>
     fn list_deployments(
         &self,
         input: ListDeploymentsRequest,
     ) -> RusotoFuture<ListDeploymentsResponse, ListDeploymentsError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/deployments",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeploymentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDeploymentsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_deployments(input)
     }
-
-    /// <p>Lists the versions of a device definition.</p>
     fn list_device_definition_versions(
         &self,
         input: ListDeviceDefinitionVersionsRequest,
     ) -> RusotoFuture<ListDeviceDefinitionVersionsResponse, ListDeviceDefinitionVersionsError> {
-        let request_uri = format!(
-            "/greengrass/definition/devices/{device_definition_id}/versions",
-            device_definition_id = input.device_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeviceDefinitionVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListDeviceDefinitionVersionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_device_definition_versions(input)
     }
-
-    /// <p>Retrieves a list of device definitions.</p>
     fn list_device_definitions(
         &self,
         input: ListDeviceDefinitionsRequest,
     ) -> RusotoFuture<ListDeviceDefinitionsResponse, ListDeviceDefinitionsError> {
-        let request_uri = "/greengrass/definition/devices";
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDeviceDefinitionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListDeviceDefinitionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_device_definitions(input)
     }
-
-    /// <p>Lists the versions of a Lambda function definition.</p>
     fn list_function_definition_versions(
         &self,
         input: ListFunctionDefinitionVersionsRequest,
     ) -> RusotoFuture<ListFunctionDefinitionVersionsResponse, ListFunctionDefinitionVersionsError>
     {
-        let request_uri = format!(
-            "/greengrass/definition/functions/{function_definition_id}/versions",
-            function_definition_id = input.function_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFunctionDefinitionVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListFunctionDefinitionVersionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_function_definition_versions(input)
     }
-
-    /// <p>Retrieves a list of Lambda function definitions.</p>
     fn list_function_definitions(
         &self,
         input: ListFunctionDefinitionsRequest,
     ) -> RusotoFuture<ListFunctionDefinitionsResponse, ListFunctionDefinitionsError> {
-        let request_uri = "/greengrass/definition/functions";
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFunctionDefinitionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListFunctionDefinitionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_function_definitions(input)
     }
-
-    /// <p>Retrieves the current CAs for a group.</p>
     fn list_group_certificate_authorities(
         &self,
         input: ListGroupCertificateAuthoritiesRequest,
     ) -> RusotoFuture<ListGroupCertificateAuthoritiesResponse, ListGroupCertificateAuthoritiesError>
     {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/certificateauthorities",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGroupCertificateAuthoritiesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListGroupCertificateAuthoritiesError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_group_certificate_authorities(input)
     }
-
-    /// <p>Lists the versions of a group.</p>
     fn list_group_versions(
         &self,
         input: ListGroupVersionsRequest,
     ) -> RusotoFuture<ListGroupVersionsResponse, ListGroupVersionsError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/versions",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGroupVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGroupVersionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_group_versions(input)
     }
-
-    /// <p>Retrieves a list of groups.</p>
     fn list_groups(
         &self,
         input: ListGroupsRequest,
     ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
-        let request_uri = "/greengrass/groups";
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGroupsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGroupsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_groups(input)
     }
-
-    /// <p>Lists the versions of a logger definition.</p>
     fn list_logger_definition_versions(
         &self,
         input: ListLoggerDefinitionVersionsRequest,
     ) -> RusotoFuture<ListLoggerDefinitionVersionsResponse, ListLoggerDefinitionVersionsError> {
-        let request_uri = format!(
-            "/greengrass/definition/loggers/{logger_definition_id}/versions",
-            logger_definition_id = input.logger_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLoggerDefinitionVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListLoggerDefinitionVersionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_logger_definition_versions(input)
     }
-
-    /// <p>Retrieves a list of logger definitions.</p>
     fn list_logger_definitions(
         &self,
         input: ListLoggerDefinitionsRequest,
     ) -> RusotoFuture<ListLoggerDefinitionsResponse, ListLoggerDefinitionsError> {
-        let request_uri = "/greengrass/definition/loggers";
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLoggerDefinitionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListLoggerDefinitionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_logger_definitions(input)
     }
-
-    /// <p>Lists the versions of a resource definition.</p>
     fn list_resource_definition_versions(
         &self,
         input: ListResourceDefinitionVersionsRequest,
     ) -> RusotoFuture<ListResourceDefinitionVersionsResponse, ListResourceDefinitionVersionsError>
     {
-        let request_uri = format!(
-            "/greengrass/definition/resources/{resource_definition_id}/versions",
-            resource_definition_id = input.resource_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourceDefinitionVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListResourceDefinitionVersionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_resource_definition_versions(input)
     }
-
-    /// <p>Retrieves a list of resource definitions.</p>
     fn list_resource_definitions(
         &self,
         input: ListResourceDefinitionsRequest,
     ) -> RusotoFuture<ListResourceDefinitionsResponse, ListResourceDefinitionsError> {
-        let request_uri = "/greengrass/definition/resources";
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourceDefinitionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListResourceDefinitionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_resource_definitions(input)
     }
-
-    /// <p>Lists the versions of a subscription definition.</p>
     fn list_subscription_definition_versions(
         &self,
         input: ListSubscriptionDefinitionVersionsRequest,
@@ -9500,468 +8021,7884 @@ impl GreenGrass for GreenGrassClient {
         ListSubscriptionDefinitionVersionsResponse,
         ListSubscriptionDefinitionVersionsError,
     > {
-        let request_uri = format!(
-            "/greengrass/definition/subscriptions/{subscription_definition_id}/versions",
-            subscription_definition_id = input.subscription_definition_id
-        );
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSubscriptionDefinitionVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSubscriptionDefinitionVersionsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_subscription_definition_versions(input)
     }
-
-    /// <p>Retrieves a list of subscription definitions.</p>
     fn list_subscription_definitions(
         &self,
         input: ListSubscriptionDefinitionsRequest,
     ) -> RusotoFuture<ListSubscriptionDefinitionsResponse, ListSubscriptionDefinitionsError> {
-        let request_uri = "/greengrass/definition/subscriptions";
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSubscriptionDefinitionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSubscriptionDefinitionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_subscription_definitions(input)
     }
-
-    /// <p>Retrieves a list of resource tags for a resource arn.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Resets a group&#39;s deployments.</p>
     fn reset_deployments(
         &self,
         input: ResetDeploymentsRequest,
     ) -> RusotoFuture<ResetDeploymentsResponse, ResetDeploymentsError> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/deployments/$reset",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResetDeploymentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResetDeploymentsError::from_response(response))),
-                )
-            }
-        })
+        (**self).reset_deployments(input)
     }
-
-    /// <p>Deploys multiple groups in one operation. This action starts the bulk deployment of a specified set of group versions. Each group version deployment will be triggered with an adaptive rate that has a fixed upper limit. We recommend that you include an &#39;&#39;X-Amzn-Client-Token&#39;&#39; token in every &#39;&#39;StartBulkDeployment&#39;&#39; request. These requests are idempotent with respect to the token and the request parameters.</p>
     fn start_bulk_deployment(
         &self,
         input: StartBulkDeploymentRequest,
     ) -> RusotoFuture<StartBulkDeploymentResponse, StartBulkDeploymentError> {
-        let request_uri = "/greengrass/bulk/deployments";
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartBulkDeploymentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartBulkDeploymentError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).start_bulk_deployment(input)
     }
-
-    /// <p>Stops the execution of a bulk deployment. This action returns a status of &#39;&#39;Stopping&#39;&#39; until the deployment is stopped. You cannot start a new bulk deployment while a previous deployment is in the &#39;&#39;Stopping&#39;&#39; state. This action doesn&#39;t rollback completed deployments or cancel pending deployments.</p>
     fn stop_bulk_deployment(
         &self,
         input: StopBulkDeploymentRequest,
     ) -> RusotoFuture<StopBulkDeploymentResponse, StopBulkDeploymentError> {
-        let request_uri = format!(
-            "/greengrass/bulk/deployments/{bulk_deployment_id}/$stop",
-            bulk_deployment_id = input.bulk_deployment_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopBulkDeploymentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopBulkDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).stop_bulk_deployment(input)
     }
-
-    /// <p>Add resource tags to a Greengrass Resource. Valid resources are Group, Connector, Core, Device, Function, Logger, Subscription, and Resource Defintions, and also BulkDeploymentIds.</p>
     fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).tag_resource(input)
     }
-
-    /// <p>Remove resource tags from a Greengrass Resource.</p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).untag_resource(input)
     }
-
-    /// <p>Updates the connectivity information for the core. Any devices that belong to the group which has this core will receive this information in order to find the location of the core and connect to it.</p>
     fn update_connectivity_info(
         &self,
         input: UpdateConnectivityInfoRequest,
     ) -> RusotoFuture<UpdateConnectivityInfoResponse, UpdateConnectivityInfoError> {
-        let request_uri = format!(
-            "/greengrass/things/{thing_name}/connectivityInfo",
-            thing_name = input.thing_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateConnectivityInfoResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateConnectivityInfoError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).update_connectivity_info(input)
     }
-
-    /// <p>Updates a connector definition.</p>
     fn update_connector_definition(
         &self,
         input: UpdateConnectorDefinitionRequest,
     ) -> RusotoFuture<UpdateConnectorDefinitionResponse, UpdateConnectorDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/connectors/{connector_definition_id}",
-            connector_definition_id = input.connector_definition_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateConnectorDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateConnectorDefinitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).update_connector_definition(input)
+    }
+    fn update_core_definition(
+        &self,
+        input: UpdateCoreDefinitionRequest,
+    ) -> RusotoFuture<UpdateCoreDefinitionResponse, UpdateCoreDefinitionError> {
+        (**self).update_core_definition(input)
+    }
+    fn update_device_definition(
+        &self,
+        input: UpdateDeviceDefinitionRequest,
+    ) -> RusotoFuture<UpdateDeviceDefinitionResponse, UpdateDeviceDefinitionError> {
+        (**self).update_device_definition(input)
+    }
+    fn update_function_definition(
+        &self,
+        input: UpdateFunctionDefinitionRequest,
+    ) -> RusotoFuture<UpdateFunctionDefinitionResponse, UpdateFunctionDefinitionError> {
+        (**self).update_function_definition(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_group_certificate_configuration(
+        &self,
+        input: UpdateGroupCertificateConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateGroupCertificateConfigurationResponse,
+        UpdateGroupCertificateConfigurationError,
+    > {
+        (**self).update_group_certificate_configuration(input)
+    }
+    fn update_logger_definition(
+        &self,
+        input: UpdateLoggerDefinitionRequest,
+    ) -> RusotoFuture<UpdateLoggerDefinitionResponse, UpdateLoggerDefinitionError> {
+        (**self).update_logger_definition(input)
+    }
+    fn update_resource_definition(
+        &self,
+        input: UpdateResourceDefinitionRequest,
+    ) -> RusotoFuture<UpdateResourceDefinitionResponse, UpdateResourceDefinitionError> {
+        (**self).update_resource_definition(input)
+    }
+    fn update_subscription_definition(
+        &self,
+        input: UpdateSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<UpdateSubscriptionDefinitionResponse, UpdateSubscriptionDefinitionError> {
+        (**self).update_subscription_definition(input)
+    }
+}
+impl<T: GreenGrass + ?Sized> GreenGrass for Box<T> {
+    fn associate_role_to_group(
+        &self,
+        input: AssociateRoleToGroupRequest,
+    ) -> RusotoFuture<AssociateRoleToGroupResponse, AssociateRoleToGroupError> {
+        (**self).associate_role_to_group(input)
+    }
+    fn associate_service_role_to_account(
+        &self,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> RusotoFuture<AssociateServiceRoleToAccountResponse, AssociateServiceRoleToAccountError>
+    {
+        (**self).associate_service_role_to_account(input)
+    }
+    fn create_connector_definition(
+        &self,
+        input: CreateConnectorDefinitionRequest,
+    ) -> RusotoFuture<CreateConnectorDefinitionResponse, CreateConnectorDefinitionError> {
+        (**self).create_connector_definition(input)
+    }
+    fn create_connector_definition_version(
+        &self,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateConnectorDefinitionVersionResponse, CreateConnectorDefinitionVersionError>
+    {
+        (**self).create_connector_definition_version(input)
+    }
+    fn create_core_definition(
+        &self,
+        input: CreateCoreDefinitionRequest,
+    ) -> RusotoFuture<CreateCoreDefinitionResponse, CreateCoreDefinitionError> {
+        (**self).create_core_definition(input)
+    }
+    fn create_core_definition_version(
+        &self,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateCoreDefinitionVersionResponse, CreateCoreDefinitionVersionError> {
+        (**self).create_core_definition_version(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_device_definition(
+        &self,
+        input: CreateDeviceDefinitionRequest,
+    ) -> RusotoFuture<CreateDeviceDefinitionResponse, CreateDeviceDefinitionError> {
+        (**self).create_device_definition(input)
+    }
+    fn create_device_definition_version(
+        &self,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateDeviceDefinitionVersionResponse, CreateDeviceDefinitionVersionError>
+    {
+        (**self).create_device_definition_version(input)
+    }
+    fn create_function_definition(
+        &self,
+        input: CreateFunctionDefinitionRequest,
+    ) -> RusotoFuture<CreateFunctionDefinitionResponse, CreateFunctionDefinitionError> {
+        (**self).create_function_definition(input)
+    }
+    fn create_function_definition_version(
+        &self,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateFunctionDefinitionVersionResponse, CreateFunctionDefinitionVersionError>
+    {
+        (**self).create_function_definition_version(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_group_certificate_authority(
+        &self,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> RusotoFuture<CreateGroupCertificateAuthorityResponse, CreateGroupCertificateAuthorityError>
+    {
+        (**self).create_group_certificate_authority(input)
+    }
+    fn create_group_version(
+        &self,
+        input: CreateGroupVersionRequest,
+    ) -> RusotoFuture<CreateGroupVersionResponse, CreateGroupVersionError> {
+        (**self).create_group_version(input)
+    }
+    fn create_logger_definition(
+        &self,
+        input: CreateLoggerDefinitionRequest,
+    ) -> RusotoFuture<CreateLoggerDefinitionResponse, CreateLoggerDefinitionError> {
+        (**self).create_logger_definition(input)
+    }
+    fn create_logger_definition_version(
+        &self,
+        input: CreateLoggerDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateLoggerDefinitionVersionResponse, CreateLoggerDefinitionVersionError>
+    {
+        (**self).create_logger_definition_version(input)
+    }
+    fn create_resource_definition(
+        &self,
+        input: CreateResourceDefinitionRequest,
+    ) -> RusotoFuture<CreateResourceDefinitionResponse, CreateResourceDefinitionError> {
+        (**self).create_resource_definition(input)
+    }
+    fn create_resource_definition_version(
+        &self,
+        input: CreateResourceDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateResourceDefinitionVersionResponse, CreateResourceDefinitionVersionError>
+    {
+        (**self).create_resource_definition_version(input)
+    }
+    fn create_software_update_job(
+        &self,
+        input: CreateSoftwareUpdateJobRequest,
+    ) -> RusotoFuture<CreateSoftwareUpdateJobResponse, CreateSoftwareUpdateJobError> {
+        (**self).create_software_update_job(input)
+    }
+    fn create_subscription_definition(
+        &self,
+        input: CreateSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<CreateSubscriptionDefinitionResponse, CreateSubscriptionDefinitionError> {
+        (**self).create_subscription_definition(input)
+    }
+    fn create_subscription_definition_version(
+        &self,
+        input: CreateSubscriptionDefinitionVersionRequest,
+    ) -> RusotoFuture<
+        CreateSubscriptionDefinitionVersionResponse,
+        CreateSubscriptionDefinitionVersionError,
+    > {
+        (**self).create_subscription_definition_version(input)
+    }
+    fn delete_connector_definition(
+        &self,
+        input: DeleteConnectorDefinitionRequest,
+    ) -> RusotoFuture<DeleteConnectorDefinitionResponse, DeleteConnectorDefinitionError> {
+        (**self).delete_connector_definition(input)
+    }
+    fn delete_core_definition(
+        &self,
+        input: DeleteCoreDefinitionRequest,
+    ) -> RusotoFuture<DeleteCoreDefinitionResponse, DeleteCoreDefinitionError> {
+        (**self).delete_core_definition(input)
+    }
+    fn delete_device_definition(
+        &self,
+        input: DeleteDeviceDefinitionRequest,
+    ) -> RusotoFuture<DeleteDeviceDefinitionResponse, DeleteDeviceDefinitionError> {
+        (**self).delete_device_definition(input)
+    }
+    fn delete_function_definition(
+        &self,
+        input: DeleteFunctionDefinitionRequest,
+    ) -> RusotoFuture<DeleteFunctionDefinitionResponse, DeleteFunctionDefinitionError> {
+        (**self).delete_function_definition(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_logger_definition(
+        &self,
+        input: DeleteLoggerDefinitionRequest,
+    ) -> RusotoFuture<DeleteLoggerDefinitionResponse, DeleteLoggerDefinitionError> {
+        (**self).delete_logger_definition(input)
+    }
+    fn delete_resource_definition(
+        &self,
+        input: DeleteResourceDefinitionRequest,
+    ) -> RusotoFuture<DeleteResourceDefinitionResponse, DeleteResourceDefinitionError> {
+        (**self).delete_resource_definition(input)
+    }
+    fn delete_subscription_definition(
+        &self,
+        input: DeleteSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<DeleteSubscriptionDefinitionResponse, DeleteSubscriptionDefinitionError> {
+        (**self).delete_subscription_definition(input)
+    }
+    fn disassociate_role_from_group(
+        &self,
+        input: DisassociateRoleFromGroupRequest,
+    ) -> RusotoFuture<DisassociateRoleFromGroupResponse, DisassociateRoleFromGroupError> {
+        (**self).disassociate_role_from_group(input)
+    }
+    fn disassociate_service_role_from_account(
+        &self,
+    ) -> RusotoFuture<
+        DisassociateServiceRoleFromAccountResponse,
+        DisassociateServiceRoleFromAccountError,
+    > {
+        (**self).disassociate_service_role_from_account()
+    }
+    fn get_associated_role(
+        &self,
+        input: GetAssociatedRoleRequest,
+    ) -> RusotoFuture<GetAssociatedRoleResponse, GetAssociatedRoleError> {
+        (**self).get_associated_role(input)
+    }
+    fn get_bulk_deployment_status(
+        &self,
+        input: GetBulkDeploymentStatusRequest,
+    ) -> RusotoFuture<GetBulkDeploymentStatusResponse, GetBulkDeploymentStatusError> {
+        (**self).get_bulk_deployment_status(input)
+    }
+    fn get_connectivity_info(
+        &self,
+        input: GetConnectivityInfoRequest,
+    ) -> RusotoFuture<GetConnectivityInfoResponse, GetConnectivityInfoError> {
+        (**self).get_connectivity_info(input)
+    }
+    fn get_connector_definition(
+        &self,
+        input: GetConnectorDefinitionRequest,
+    ) -> RusotoFuture<GetConnectorDefinitionResponse, GetConnectorDefinitionError> {
+        (**self).get_connector_definition(input)
+    }
+    fn get_connector_definition_version(
+        &self,
+        input: GetConnectorDefinitionVersionRequest,
+    ) -> RusotoFuture<GetConnectorDefinitionVersionResponse, GetConnectorDefinitionVersionError>
+    {
+        (**self).get_connector_definition_version(input)
+    }
+    fn get_core_definition(
+        &self,
+        input: GetCoreDefinitionRequest,
+    ) -> RusotoFuture<GetCoreDefinitionResponse, GetCoreDefinitionError> {
+        (**self).get_core_definition(input)
+    }
+    fn get_core_definition_version(
+        &self,
+        input: GetCoreDefinitionVersionRequest,
+    ) -> RusotoFuture<GetCoreDefinitionVersionResponse, GetCoreDefinitionVersionError> {
+        (**self).get_core_definition_version(input)
+    }
+    fn get_deployment_status(
+        &self,
+        input: GetDeploymentStatusRequest,
+    ) -> RusotoFuture<GetDeploymentStatusResponse, GetDeploymentStatusError> {
+        (**self).get_deployment_status(input)
+    }
+    fn get_device_definition(
+        &self,
+        input: GetDeviceDefinitionRequest,
+    ) -> RusotoFuture<GetDeviceDefinitionResponse, GetDeviceDefinitionError> {
+        (**self).get_device_definition(input)
+    }
+    fn get_device_definition_version(
+        &self,
+        input: GetDeviceDefinitionVersionRequest,
+    ) -> RusotoFuture<GetDeviceDefinitionVersionResponse, GetDeviceDefinitionVersionError> {
+        (**self).get_device_definition_version(input)
+    }
+    fn get_function_definition(
+        &self,
+        input: GetFunctionDefinitionRequest,
+    ) -> RusotoFuture<GetFunctionDefinitionResponse, GetFunctionDefinitionError> {
+        (**self).get_function_definition(input)
+    }
+    fn get_function_definition_version(
+        &self,
+        input: GetFunctionDefinitionVersionRequest,
+    ) -> RusotoFuture<GetFunctionDefinitionVersionResponse, GetFunctionDefinitionVersionError> {
+        (**self).get_function_definition_version(input)
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_group_certificate_authority(
+        &self,
+        input: GetGroupCertificateAuthorityRequest,
+    ) -> RusotoFuture<GetGroupCertificateAuthorityResponse, GetGroupCertificateAuthorityError> {
+        (**self).get_group_certificate_authority(input)
+    }
+    fn get_group_certificate_configuration(
+        &self,
+        input: GetGroupCertificateConfigurationRequest,
+    ) -> RusotoFuture<GetGroupCertificateConfigurationResponse, GetGroupCertificateConfigurationError>
+    {
+        (**self).get_group_certificate_configuration(input)
+    }
+    fn get_group_version(
+        &self,
+        input: GetGroupVersionRequest,
+    ) -> RusotoFuture<GetGroupVersionResponse, GetGroupVersionError> {
+        (**self).get_group_version(input)
+    }
+    fn get_logger_definition(
+        &self,
+        input: GetLoggerDefinitionRequest,
+    ) -> RusotoFuture<GetLoggerDefinitionResponse, GetLoggerDefinitionError> {
+        (**self).get_logger_definition(input)
+    }
+    fn get_logger_definition_version(
+        &self,
+        input: GetLoggerDefinitionVersionRequest,
+    ) -> RusotoFuture<GetLoggerDefinitionVersionResponse, GetLoggerDefinitionVersionError> {
+        (**self).get_logger_definition_version(input)
+    }
+    fn get_resource_definition(
+        &self,
+        input: GetResourceDefinitionRequest,
+    ) -> RusotoFuture<GetResourceDefinitionResponse, GetResourceDefinitionError> {
+        (**self).get_resource_definition(input)
+    }
+    fn get_resource_definition_version(
+        &self,
+        input: GetResourceDefinitionVersionRequest,
+    ) -> RusotoFuture<GetResourceDefinitionVersionResponse, GetResourceDefinitionVersionError> {
+        (**self).get_resource_definition_version(input)
+    }
+    fn get_service_role_for_account(
+        &self,
+    ) -> RusotoFuture<GetServiceRoleForAccountResponse, GetServiceRoleForAccountError> {
+        (**self).get_service_role_for_account()
+    }
+    fn get_subscription_definition(
+        &self,
+        input: GetSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<GetSubscriptionDefinitionResponse, GetSubscriptionDefinitionError> {
+        (**self).get_subscription_definition(input)
+    }
+    fn get_subscription_definition_version(
+        &self,
+        input: GetSubscriptionDefinitionVersionRequest,
+    ) -> RusotoFuture<GetSubscriptionDefinitionVersionResponse, GetSubscriptionDefinitionVersionError>
+    {
+        (**self).get_subscription_definition_version(input)
+    }
+    fn list_bulk_deployment_detailed_reports(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> RusotoFuture<
+        ListBulkDeploymentDetailedReportsResponse,
+        ListBulkDeploymentDetailedReportsError,
+    > {
+        (**self).list_bulk_deployment_detailed_reports(input)
+    }
+    fn list_bulk_deployments(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> RusotoFuture<ListBulkDeploymentsResponse, ListBulkDeploymentsError> {
+        (**self).list_bulk_deployments(input)
+    }
+    fn list_connector_definition_versions(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListConnectorDefinitionVersionsResponse, ListConnectorDefinitionVersionsError>
+    {
+        (**self).list_connector_definition_versions(input)
+    }
+    fn list_connector_definitions(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> RusotoFuture<ListConnectorDefinitionsResponse, ListConnectorDefinitionsError> {
+        (**self).list_connector_definitions(input)
+    }
+    fn list_core_definition_versions(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListCoreDefinitionVersionsResponse, ListCoreDefinitionVersionsError> {
+        (**self).list_core_definition_versions(input)
+    }
+    fn list_core_definitions(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> RusotoFuture<ListCoreDefinitionsResponse, ListCoreDefinitionsError> {
+        (**self).list_core_definitions(input)
+    }
+    fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> RusotoFuture<ListDeploymentsResponse, ListDeploymentsError> {
+        (**self).list_deployments(input)
+    }
+    fn list_device_definition_versions(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListDeviceDefinitionVersionsResponse, ListDeviceDefinitionVersionsError> {
+        (**self).list_device_definition_versions(input)
+    }
+    fn list_device_definitions(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> RusotoFuture<ListDeviceDefinitionsResponse, ListDeviceDefinitionsError> {
+        (**self).list_device_definitions(input)
+    }
+    fn list_function_definition_versions(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListFunctionDefinitionVersionsResponse, ListFunctionDefinitionVersionsError>
+    {
+        (**self).list_function_definition_versions(input)
+    }
+    fn list_function_definitions(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> RusotoFuture<ListFunctionDefinitionsResponse, ListFunctionDefinitionsError> {
+        (**self).list_function_definitions(input)
+    }
+    fn list_group_certificate_authorities(
+        &self,
+        input: ListGroupCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<ListGroupCertificateAuthoritiesResponse, ListGroupCertificateAuthoritiesError>
+    {
+        (**self).list_group_certificate_authorities(input)
+    }
+    fn list_group_versions(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> RusotoFuture<ListGroupVersionsResponse, ListGroupVersionsError> {
+        (**self).list_group_versions(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn list_logger_definition_versions(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListLoggerDefinitionVersionsResponse, ListLoggerDefinitionVersionsError> {
+        (**self).list_logger_definition_versions(input)
+    }
+    fn list_logger_definitions(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> RusotoFuture<ListLoggerDefinitionsResponse, ListLoggerDefinitionsError> {
+        (**self).list_logger_definitions(input)
+    }
+    fn list_resource_definition_versions(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListResourceDefinitionVersionsResponse, ListResourceDefinitionVersionsError>
+    {
+        (**self).list_resource_definition_versions(input)
+    }
+    fn list_resource_definitions(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> RusotoFuture<ListResourceDefinitionsResponse, ListResourceDefinitionsError> {
+        (**self).list_resource_definitions(input)
+    }
+    fn list_subscription_definition_versions(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> RusotoFuture<
+        ListSubscriptionDefinitionVersionsResponse,
+        ListSubscriptionDefinitionVersionsError,
+    > {
+        (**self).list_subscription_definition_versions(input)
+    }
+    fn list_subscription_definitions(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> RusotoFuture<ListSubscriptionDefinitionsResponse, ListSubscriptionDefinitionsError> {
+        (**self).list_subscription_definitions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn reset_deployments(
+        &self,
+        input: ResetDeploymentsRequest,
+    ) -> RusotoFuture<ResetDeploymentsResponse, ResetDeploymentsError> {
+        (**self).reset_deployments(input)
+    }
+    fn start_bulk_deployment(
+        &self,
+        input: StartBulkDeploymentRequest,
+    ) -> RusotoFuture<StartBulkDeploymentResponse, StartBulkDeploymentError> {
+        (**self).start_bulk_deployment(input)
+    }
+    fn stop_bulk_deployment(
+        &self,
+        input: StopBulkDeploymentRequest,
+    ) -> RusotoFuture<StopBulkDeploymentResponse, StopBulkDeploymentError> {
+        (**self).stop_bulk_deployment(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_connectivity_info(
+        &self,
+        input: UpdateConnectivityInfoRequest,
+    ) -> RusotoFuture<UpdateConnectivityInfoResponse, UpdateConnectivityInfoError> {
+        (**self).update_connectivity_info(input)
+    }
+    fn update_connector_definition(
+        &self,
+        input: UpdateConnectorDefinitionRequest,
+    ) -> RusotoFuture<UpdateConnectorDefinitionResponse, UpdateConnectorDefinitionError> {
+        (**self).update_connector_definition(input)
+    }
+    fn update_core_definition(
+        &self,
+        input: UpdateCoreDefinitionRequest,
+    ) -> RusotoFuture<UpdateCoreDefinitionResponse, UpdateCoreDefinitionError> {
+        (**self).update_core_definition(input)
+    }
+    fn update_device_definition(
+        &self,
+        input: UpdateDeviceDefinitionRequest,
+    ) -> RusotoFuture<UpdateDeviceDefinitionResponse, UpdateDeviceDefinitionError> {
+        (**self).update_device_definition(input)
+    }
+    fn update_function_definition(
+        &self,
+        input: UpdateFunctionDefinitionRequest,
+    ) -> RusotoFuture<UpdateFunctionDefinitionResponse, UpdateFunctionDefinitionError> {
+        (**self).update_function_definition(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_group_certificate_configuration(
+        &self,
+        input: UpdateGroupCertificateConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateGroupCertificateConfigurationResponse,
+        UpdateGroupCertificateConfigurationError,
+    > {
+        (**self).update_group_certificate_configuration(input)
+    }
+    fn update_logger_definition(
+        &self,
+        input: UpdateLoggerDefinitionRequest,
+    ) -> RusotoFuture<UpdateLoggerDefinitionResponse, UpdateLoggerDefinitionError> {
+        (**self).update_logger_definition(input)
+    }
+    fn update_resource_definition(
+        &self,
+        input: UpdateResourceDefinitionRequest,
+    ) -> RusotoFuture<UpdateResourceDefinitionResponse, UpdateResourceDefinitionError> {
+        (**self).update_resource_definition(input)
+    }
+    fn update_subscription_definition(
+        &self,
+        input: UpdateSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<UpdateSubscriptionDefinitionResponse, UpdateSubscriptionDefinitionError> {
+        (**self).update_subscription_definition(input)
+    }
+}
+impl<T: GreenGrass + ?Sized> GreenGrass for ::std::sync::Arc<T> {
+    fn associate_role_to_group(
+        &self,
+        input: AssociateRoleToGroupRequest,
+    ) -> RusotoFuture<AssociateRoleToGroupResponse, AssociateRoleToGroupError> {
+        (**self).associate_role_to_group(input)
+    }
+    fn associate_service_role_to_account(
+        &self,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> RusotoFuture<AssociateServiceRoleToAccountResponse, AssociateServiceRoleToAccountError>
+    {
+        (**self).associate_service_role_to_account(input)
+    }
+    fn create_connector_definition(
+        &self,
+        input: CreateConnectorDefinitionRequest,
+    ) -> RusotoFuture<CreateConnectorDefinitionResponse, CreateConnectorDefinitionError> {
+        (**self).create_connector_definition(input)
+    }
+    fn create_connector_definition_version(
+        &self,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateConnectorDefinitionVersionResponse, CreateConnectorDefinitionVersionError>
+    {
+        (**self).create_connector_definition_version(input)
+    }
+    fn create_core_definition(
+        &self,
+        input: CreateCoreDefinitionRequest,
+    ) -> RusotoFuture<CreateCoreDefinitionResponse, CreateCoreDefinitionError> {
+        (**self).create_core_definition(input)
+    }
+    fn create_core_definition_version(
+        &self,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateCoreDefinitionVersionResponse, CreateCoreDefinitionVersionError> {
+        (**self).create_core_definition_version(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_device_definition(
+        &self,
+        input: CreateDeviceDefinitionRequest,
+    ) -> RusotoFuture<CreateDeviceDefinitionResponse, CreateDeviceDefinitionError> {
+        (**self).create_device_definition(input)
+    }
+    fn create_device_definition_version(
+        &self,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateDeviceDefinitionVersionResponse, CreateDeviceDefinitionVersionError>
+    {
+        (**self).create_device_definition_version(input)
+    }
+    fn create_function_definition(
+        &self,
+        input: CreateFunctionDefinitionRequest,
+    ) -> RusotoFuture<CreateFunctionDefinitionResponse, CreateFunctionDefinitionError> {
+        (**self).create_function_definition(input)
+    }
+    fn create_function_definition_version(
+        &self,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateFunctionDefinitionVersionResponse, CreateFunctionDefinitionVersionError>
+    {
+        (**self).create_function_definition_version(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_group_certificate_authority(
+        &self,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> RusotoFuture<CreateGroupCertificateAuthorityResponse, CreateGroupCertificateAuthorityError>
+    {
+        (**self).create_group_certificate_authority(input)
+    }
+    fn create_group_version(
+        &self,
+        input: CreateGroupVersionRequest,
+    ) -> RusotoFuture<CreateGroupVersionResponse, CreateGroupVersionError> {
+        (**self).create_group_version(input)
+    }
+    fn create_logger_definition(
+        &self,
+        input: CreateLoggerDefinitionRequest,
+    ) -> RusotoFuture<CreateLoggerDefinitionResponse, CreateLoggerDefinitionError> {
+        (**self).create_logger_definition(input)
+    }
+    fn create_logger_definition_version(
+        &self,
+        input: CreateLoggerDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateLoggerDefinitionVersionResponse, CreateLoggerDefinitionVersionError>
+    {
+        (**self).create_logger_definition_version(input)
+    }
+    fn create_resource_definition(
+        &self,
+        input: CreateResourceDefinitionRequest,
+    ) -> RusotoFuture<CreateResourceDefinitionResponse, CreateResourceDefinitionError> {
+        (**self).create_resource_definition(input)
+    }
+    fn create_resource_definition_version(
+        &self,
+        input: CreateResourceDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateResourceDefinitionVersionResponse, CreateResourceDefinitionVersionError>
+    {
+        (**self).create_resource_definition_version(input)
+    }
+    fn create_software_update_job(
+        &self,
+        input: CreateSoftwareUpdateJobRequest,
+    ) -> RusotoFuture<CreateSoftwareUpdateJobResponse, CreateSoftwareUpdateJobError> {
+        (**self).create_software_update_job(input)
+    }
+    fn create_subscription_definition(
+        &self,
+        input: CreateSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<CreateSubscriptionDefinitionResponse, CreateSubscriptionDefinitionError> {
+        (**self).create_subscription_definition(input)
+    }
+    fn create_subscription_definition_version(
+        &self,
+        input: CreateSubscriptionDefinitionVersionRequest,
+    ) -> RusotoFuture<
+        CreateSubscriptionDefinitionVersionResponse,
+        CreateSubscriptionDefinitionVersionError,
+    > {
+        (**self).create_subscription_definition_version(input)
+    }
+    fn delete_connector_definition(
+        &self,
+        input: DeleteConnectorDefinitionRequest,
+    ) -> RusotoFuture<DeleteConnectorDefinitionResponse, DeleteConnectorDefinitionError> {
+        (**self).delete_connector_definition(input)
+    }
+    fn delete_core_definition(
+        &self,
+        input: DeleteCoreDefinitionRequest,
+    ) -> RusotoFuture<DeleteCoreDefinitionResponse, DeleteCoreDefinitionError> {
+        (**self).delete_core_definition(input)
+    }
+    fn delete_device_definition(
+        &self,
+        input: DeleteDeviceDefinitionRequest,
+    ) -> RusotoFuture<DeleteDeviceDefinitionResponse, DeleteDeviceDefinitionError> {
+        (**self).delete_device_definition(input)
+    }
+    fn delete_function_definition(
+        &self,
+        input: DeleteFunctionDefinitionRequest,
+    ) -> RusotoFuture<DeleteFunctionDefinitionResponse, DeleteFunctionDefinitionError> {
+        (**self).delete_function_definition(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_logger_definition(
+        &self,
+        input: DeleteLoggerDefinitionRequest,
+    ) -> RusotoFuture<DeleteLoggerDefinitionResponse, DeleteLoggerDefinitionError> {
+        (**self).delete_logger_definition(input)
+    }
+    fn delete_resource_definition(
+        &self,
+        input: DeleteResourceDefinitionRequest,
+    ) -> RusotoFuture<DeleteResourceDefinitionResponse, DeleteResourceDefinitionError> {
+        (**self).delete_resource_definition(input)
+    }
+    fn delete_subscription_definition(
+        &self,
+        input: DeleteSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<DeleteSubscriptionDefinitionResponse, DeleteSubscriptionDefinitionError> {
+        (**self).delete_subscription_definition(input)
+    }
+    fn disassociate_role_from_group(
+        &self,
+        input: DisassociateRoleFromGroupRequest,
+    ) -> RusotoFuture<DisassociateRoleFromGroupResponse, DisassociateRoleFromGroupError> {
+        (**self).disassociate_role_from_group(input)
+    }
+    fn disassociate_service_role_from_account(
+        &self,
+    ) -> RusotoFuture<
+        DisassociateServiceRoleFromAccountResponse,
+        DisassociateServiceRoleFromAccountError,
+    > {
+        (**self).disassociate_service_role_from_account()
+    }
+    fn get_associated_role(
+        &self,
+        input: GetAssociatedRoleRequest,
+    ) -> RusotoFuture<GetAssociatedRoleResponse, GetAssociatedRoleError> {
+        (**self).get_associated_role(input)
+    }
+    fn get_bulk_deployment_status(
+        &self,
+        input: GetBulkDeploymentStatusRequest,
+    ) -> RusotoFuture<GetBulkDeploymentStatusResponse, GetBulkDeploymentStatusError> {
+        (**self).get_bulk_deployment_status(input)
+    }
+    fn get_connectivity_info(
+        &self,
+        input: GetConnectivityInfoRequest,
+    ) -> RusotoFuture<GetConnectivityInfoResponse, GetConnectivityInfoError> {
+        (**self).get_connectivity_info(input)
+    }
+    fn get_connector_definition(
+        &self,
+        input: GetConnectorDefinitionRequest,
+    ) -> RusotoFuture<GetConnectorDefinitionResponse, GetConnectorDefinitionError> {
+        (**self).get_connector_definition(input)
+    }
+    fn get_connector_definition_version(
+        &self,
+        input: GetConnectorDefinitionVersionRequest,
+    ) -> RusotoFuture<GetConnectorDefinitionVersionResponse, GetConnectorDefinitionVersionError>
+    {
+        (**self).get_connector_definition_version(input)
+    }
+    fn get_core_definition(
+        &self,
+        input: GetCoreDefinitionRequest,
+    ) -> RusotoFuture<GetCoreDefinitionResponse, GetCoreDefinitionError> {
+        (**self).get_core_definition(input)
+    }
+    fn get_core_definition_version(
+        &self,
+        input: GetCoreDefinitionVersionRequest,
+    ) -> RusotoFuture<GetCoreDefinitionVersionResponse, GetCoreDefinitionVersionError> {
+        (**self).get_core_definition_version(input)
+    }
+    fn get_deployment_status(
+        &self,
+        input: GetDeploymentStatusRequest,
+    ) -> RusotoFuture<GetDeploymentStatusResponse, GetDeploymentStatusError> {
+        (**self).get_deployment_status(input)
+    }
+    fn get_device_definition(
+        &self,
+        input: GetDeviceDefinitionRequest,
+    ) -> RusotoFuture<GetDeviceDefinitionResponse, GetDeviceDefinitionError> {
+        (**self).get_device_definition(input)
+    }
+    fn get_device_definition_version(
+        &self,
+        input: GetDeviceDefinitionVersionRequest,
+    ) -> RusotoFuture<GetDeviceDefinitionVersionResponse, GetDeviceDefinitionVersionError> {
+        (**self).get_device_definition_version(input)
+    }
+    fn get_function_definition(
+        &self,
+        input: GetFunctionDefinitionRequest,
+    ) -> RusotoFuture<GetFunctionDefinitionResponse, GetFunctionDefinitionError> {
+        (**self).get_function_definition(input)
+    }
+    fn get_function_definition_version(
+        &self,
+        input: GetFunctionDefinitionVersionRequest,
+    ) -> RusotoFuture<GetFunctionDefinitionVersionResponse, GetFunctionDefinitionVersionError> {
+        (**self).get_function_definition_version(input)
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_group_certificate_authority(
+        &self,
+        input: GetGroupCertificateAuthorityRequest,
+    ) -> RusotoFuture<GetGroupCertificateAuthorityResponse, GetGroupCertificateAuthorityError> {
+        (**self).get_group_certificate_authority(input)
+    }
+    fn get_group_certificate_configuration(
+        &self,
+        input: GetGroupCertificateConfigurationRequest,
+    ) -> RusotoFuture<GetGroupCertificateConfigurationResponse, GetGroupCertificateConfigurationError>
+    {
+        (**self).get_group_certificate_configuration(input)
+    }
+    fn get_group_version(
+        &self,
+        input: GetGroupVersionRequest,
+    ) -> RusotoFuture<GetGroupVersionResponse, GetGroupVersionError> {
+        (**self).get_group_version(input)
+    }
+    fn get_logger_definition(
+        &self,
+        input: GetLoggerDefinitionRequest,
+    ) -> RusotoFuture<GetLoggerDefinitionResponse, GetLoggerDefinitionError> {
+        (**self).get_logger_definition(input)
+    }
+    fn get_logger_definition_version(
+        &self,
+        input: GetLoggerDefinitionVersionRequest,
+    ) -> RusotoFuture<GetLoggerDefinitionVersionResponse, GetLoggerDefinitionVersionError> {
+        (**self).get_logger_definition_version(input)
+    }
+    fn get_resource_definition(
+        &self,
+        input: GetResourceDefinitionRequest,
+    ) -> RusotoFuture<GetResourceDefinitionResponse, GetResourceDefinitionError> {
+        (**self).get_resource_definition(input)
+    }
+    fn get_resource_definition_version(
+        &self,
+        input: GetResourceDefinitionVersionRequest,
+    ) -> RusotoFuture<GetResourceDefinitionVersionResponse, GetResourceDefinitionVersionError> {
+        (**self).get_resource_definition_version(input)
+    }
+    fn get_service_role_for_account(
+        &self,
+    ) -> RusotoFuture<GetServiceRoleForAccountResponse, GetServiceRoleForAccountError> {
+        (**self).get_service_role_for_account()
+    }
+    fn get_subscription_definition(
+        &self,
+        input: GetSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<GetSubscriptionDefinitionResponse, GetSubscriptionDefinitionError> {
+        (**self).get_subscription_definition(input)
+    }
+    fn get_subscription_definition_version(
+        &self,
+        input: GetSubscriptionDefinitionVersionRequest,
+    ) -> RusotoFuture<GetSubscriptionDefinitionVersionResponse, GetSubscriptionDefinitionVersionError>
+    {
+        (**self).get_subscription_definition_version(input)
+    }
+    fn list_bulk_deployment_detailed_reports(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> RusotoFuture<
+        ListBulkDeploymentDetailedReportsResponse,
+        ListBulkDeploymentDetailedReportsError,
+    > {
+        (**self).list_bulk_deployment_detailed_reports(input)
+    }
+    fn list_bulk_deployments(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> RusotoFuture<ListBulkDeploymentsResponse, ListBulkDeploymentsError> {
+        (**self).list_bulk_deployments(input)
+    }
+    fn list_connector_definition_versions(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListConnectorDefinitionVersionsResponse, ListConnectorDefinitionVersionsError>
+    {
+        (**self).list_connector_definition_versions(input)
+    }
+    fn list_connector_definitions(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> RusotoFuture<ListConnectorDefinitionsResponse, ListConnectorDefinitionsError> {
+        (**self).list_connector_definitions(input)
+    }
+    fn list_core_definition_versions(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListCoreDefinitionVersionsResponse, ListCoreDefinitionVersionsError> {
+        (**self).list_core_definition_versions(input)
+    }
+    fn list_core_definitions(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> RusotoFuture<ListCoreDefinitionsResponse, ListCoreDefinitionsError> {
+        (**self).list_core_definitions(input)
+    }
+    fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> RusotoFuture<ListDeploymentsResponse, ListDeploymentsError> {
+        (**self).list_deployments(input)
+    }
+    fn list_device_definition_versions(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListDeviceDefinitionVersionsResponse, ListDeviceDefinitionVersionsError> {
+        (**self).list_device_definition_versions(input)
+    }
+    fn list_device_definitions(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> RusotoFuture<ListDeviceDefinitionsResponse, ListDeviceDefinitionsError> {
+        (**self).list_device_definitions(input)
+    }
+    fn list_function_definition_versions(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListFunctionDefinitionVersionsResponse, ListFunctionDefinitionVersionsError>
+    {
+        (**self).list_function_definition_versions(input)
+    }
+    fn list_function_definitions(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> RusotoFuture<ListFunctionDefinitionsResponse, ListFunctionDefinitionsError> {
+        (**self).list_function_definitions(input)
+    }
+    fn list_group_certificate_authorities(
+        &self,
+        input: ListGroupCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<ListGroupCertificateAuthoritiesResponse, ListGroupCertificateAuthoritiesError>
+    {
+        (**self).list_group_certificate_authorities(input)
+    }
+    fn list_group_versions(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> RusotoFuture<ListGroupVersionsResponse, ListGroupVersionsError> {
+        (**self).list_group_versions(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn list_logger_definition_versions(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListLoggerDefinitionVersionsResponse, ListLoggerDefinitionVersionsError> {
+        (**self).list_logger_definition_versions(input)
+    }
+    fn list_logger_definitions(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> RusotoFuture<ListLoggerDefinitionsResponse, ListLoggerDefinitionsError> {
+        (**self).list_logger_definitions(input)
+    }
+    fn list_resource_definition_versions(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListResourceDefinitionVersionsResponse, ListResourceDefinitionVersionsError>
+    {
+        (**self).list_resource_definition_versions(input)
+    }
+    fn list_resource_definitions(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> RusotoFuture<ListResourceDefinitionsResponse, ListResourceDefinitionsError> {
+        (**self).list_resource_definitions(input)
+    }
+    fn list_subscription_definition_versions(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> RusotoFuture<
+        ListSubscriptionDefinitionVersionsResponse,
+        ListSubscriptionDefinitionVersionsError,
+    > {
+        (**self).list_subscription_definition_versions(input)
+    }
+    fn list_subscription_definitions(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> RusotoFuture<ListSubscriptionDefinitionsResponse, ListSubscriptionDefinitionsError> {
+        (**self).list_subscription_definitions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn reset_deployments(
+        &self,
+        input: ResetDeploymentsRequest,
+    ) -> RusotoFuture<ResetDeploymentsResponse, ResetDeploymentsError> {
+        (**self).reset_deployments(input)
+    }
+    fn start_bulk_deployment(
+        &self,
+        input: StartBulkDeploymentRequest,
+    ) -> RusotoFuture<StartBulkDeploymentResponse, StartBulkDeploymentError> {
+        (**self).start_bulk_deployment(input)
+    }
+    fn stop_bulk_deployment(
+        &self,
+        input: StopBulkDeploymentRequest,
+    ) -> RusotoFuture<StopBulkDeploymentResponse, StopBulkDeploymentError> {
+        (**self).stop_bulk_deployment(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_connectivity_info(
+        &self,
+        input: UpdateConnectivityInfoRequest,
+    ) -> RusotoFuture<UpdateConnectivityInfoResponse, UpdateConnectivityInfoError> {
+        (**self).update_connectivity_info(input)
+    }
+    fn update_connector_definition(
+        &self,
+        input: UpdateConnectorDefinitionRequest,
+    ) -> RusotoFuture<UpdateConnectorDefinitionResponse, UpdateConnectorDefinitionError> {
+        (**self).update_connector_definition(input)
+    }
+    fn update_core_definition(
+        &self,
+        input: UpdateCoreDefinitionRequest,
+    ) -> RusotoFuture<UpdateCoreDefinitionResponse, UpdateCoreDefinitionError> {
+        (**self).update_core_definition(input)
+    }
+    fn update_device_definition(
+        &self,
+        input: UpdateDeviceDefinitionRequest,
+    ) -> RusotoFuture<UpdateDeviceDefinitionResponse, UpdateDeviceDefinitionError> {
+        (**self).update_device_definition(input)
+    }
+    fn update_function_definition(
+        &self,
+        input: UpdateFunctionDefinitionRequest,
+    ) -> RusotoFuture<UpdateFunctionDefinitionResponse, UpdateFunctionDefinitionError> {
+        (**self).update_function_definition(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_group_certificate_configuration(
+        &self,
+        input: UpdateGroupCertificateConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateGroupCertificateConfigurationResponse,
+        UpdateGroupCertificateConfigurationError,
+    > {
+        (**self).update_group_certificate_configuration(input)
+    }
+    fn update_logger_definition(
+        &self,
+        input: UpdateLoggerDefinitionRequest,
+    ) -> RusotoFuture<UpdateLoggerDefinitionResponse, UpdateLoggerDefinitionError> {
+        (**self).update_logger_definition(input)
+    }
+    fn update_resource_definition(
+        &self,
+        input: UpdateResourceDefinitionRequest,
+    ) -> RusotoFuture<UpdateResourceDefinitionResponse, UpdateResourceDefinitionError> {
+        (**self).update_resource_definition(input)
+    }
+    fn update_subscription_definition(
+        &self,
+        input: UpdateSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<UpdateSubscriptionDefinitionResponse, UpdateSubscriptionDefinitionError> {
+        (**self).update_subscription_definition(input)
+    }
+}
+/// A client for the AWS Greengrass API.
+#[derive(Clone)]
+pub struct GreenGrassClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl GreenGrassClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GreenGrassClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GreenGrassClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> GreenGrassClient {
+        GreenGrassClient { client, region }
+    }
+}
+
+impl GreenGrass for GreenGrassClient {
+    /// <p>Associates a role with a group. Your Greengrass core will use the role to access AWS cloud services. The role&#39;s permissions should allow Greengrass core Lambda functions to perform actions against the cloud.</p>
+    fn associate_role_to_group(
+        &self,
+        input: AssociateRoleToGroupRequest,
+    ) -> RusotoFuture<AssociateRoleToGroupResponse, AssociateRoleToGroupError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/role",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("AssociateRoleToGroup");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AssociateRoleToGroup")
+                        .deserialize::<AssociateRoleToGroupResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AssociateRoleToGroupError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates a role with your account. AWS IoT Greengrass will use the role to access your Lambda functions and AWS IoT resources. This is necessary for deployments to succeed. The role must have at least minimum permissions in the policy &#39;&#39;AWSGreengrassResourceAccessRolePolicy&#39;&#39;.</p>
+    fn associate_service_role_to_account(
+        &self,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> RusotoFuture<AssociateServiceRoleToAccountResponse, AssociateServiceRoleToAccountError>
+    {
+        let request_uri = "/greengrass/servicerole";
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("AssociateServiceRoleToAccount");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AssociateServiceRoleToAccount")
+                        .deserialize::<AssociateServiceRoleToAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateServiceRoleToAccountError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a connector definition. You may provide the initial version of the connector definition now or use &#39;&#39;CreateConnectorDefinitionVersion&#39;&#39; at a later time.</p>
+    fn create_connector_definition(
+        &self,
+        input: CreateConnectorDefinitionRequest,
+    ) -> RusotoFuture<CreateConnectorDefinitionResponse, CreateConnectorDefinitionError> {
+        let request_uri = "/greengrass/definition/connectors";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateConnectorDefinition");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateConnectorDefinition")
+                        .deserialize::<CreateConnectorDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateConnectorDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a version of a connector definition which has already been defined.</p>
+    fn create_connector_definition_version(
+        &self,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateConnectorDefinitionVersionResponse, CreateConnectorDefinitionVersionError>
+    {
+        let request_uri = format!(
+            "/greengrass/definition/connectors/{connector_definition_id}/versions",
+            connector_definition_id = input.connector_definition_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateConnectorDefinitionVersion");
+        request.add_resource_id(
+            "ConnectorDefinitionId",
+            &input.connector_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateConnectorDefinitionVersion")
+                        .deserialize::<CreateConnectorDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateConnectorDefinitionVersionError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a core definition. You may provide the initial version of the core definition now or use &#39;&#39;CreateCoreDefinitionVersion&#39;&#39; at a later time. Greengrass groups must each contain exactly one Greengrass core.</p>
+    fn create_core_definition(
+        &self,
+        input: CreateCoreDefinitionRequest,
+    ) -> RusotoFuture<CreateCoreDefinitionResponse, CreateCoreDefinitionError> {
+        let request_uri = "/greengrass/definition/cores";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateCoreDefinition");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateCoreDefinition")
+                        .deserialize::<CreateCoreDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateCoreDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a version of a core definition that has already been defined. Greengrass groups must each contain exactly one Greengrass core.</p>
+    fn create_core_definition_version(
+        &self,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateCoreDefinitionVersionResponse, CreateCoreDefinitionVersionError> {
+        let request_uri = format!(
+            "/greengrass/definition/cores/{core_definition_id}/versions",
+            core_definition_id = input.core_definition_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateCoreDefinitionVersion");
+        request.add_resource_id("CoreDefinitionId", &input.core_definition_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateCoreDefinitionVersion")
+                        .deserialize::<CreateCoreDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateCoreDefinitionVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a deployment. &#39;&#39;CreateDeployment&#39;&#39; requests are idempotent with respect to the &#39;&#39;X-Amzn-Client-Token&#39;&#39; token and the request parameters.</p>
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/deployments",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDeployment")
+                        .deserialize::<CreateDeploymentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a device definition. You may provide the initial version of the device definition now or use &#39;&#39;CreateDeviceDefinitionVersion&#39;&#39; at a later time.</p>
+    fn create_device_definition(
+        &self,
+        input: CreateDeviceDefinitionRequest,
+    ) -> RusotoFuture<CreateDeviceDefinitionResponse, CreateDeviceDefinitionError> {
+        let request_uri = "/greengrass/definition/devices";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeviceDefinition");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDeviceDefinition")
+                        .deserialize::<CreateDeviceDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateDeviceDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a version of a device definition that has already been defined.</p>
+    fn create_device_definition_version(
+        &self,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateDeviceDefinitionVersionResponse, CreateDeviceDefinitionVersionError>
+    {
+        let request_uri = format!(
+            "/greengrass/definition/devices/{device_definition_id}/versions",
+            device_definition_id = input.device_definition_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeviceDefinitionVersion");
+        request.add_resource_id(
+            "DeviceDefinitionId",
+            &input.device_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDeviceDefinitionVersion")
+                        .deserialize::<CreateDeviceDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDeviceDefinitionVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a Lambda function definition which contains a list of Lambda functions and their configurations to be used in a group. You can create an initial version of the definition by providing a list of Lambda functions and their configurations now, or use &#39;&#39;CreateFunctionDefinitionVersion&#39;&#39; later.</p>
+    fn create_function_definition(
+        &self,
+        input: CreateFunctionDefinitionRequest,
+    ) -> RusotoFuture<CreateFunctionDefinitionResponse, CreateFunctionDefinitionError> {
+        let request_uri = "/greengrass/definition/functions";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateFunctionDefinition");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateFunctionDefinition")
+                        .deserialize::<CreateFunctionDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateFunctionDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a version of a Lambda function definition that has already been defined.</p>
+    fn create_function_definition_version(
+        &self,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateFunctionDefinitionVersionResponse, CreateFunctionDefinitionVersionError>
+    {
+        let request_uri = format!(
+            "/greengrass/definition/functions/{function_definition_id}/versions",
+            function_definition_id = input.function_definition_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateFunctionDefinitionVersion");
+        request.add_resource_id(
+            "FunctionDefinitionId",
+            &input.function_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateFunctionDefinitionVersion")
+                        .deserialize::<CreateFunctionDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateFunctionDefinitionVersionError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a group. You may provide the initial version of the group or use &#39;&#39;CreateGroupVersion&#39;&#39; at a later time. Tip: You can use the &#39;&#39;gg<em>group</em>setup&#39;&#39; package (https://github.com/awslabs/aws-greengrass-group-setup) as a library or command-line application to create and deploy Greengrass groups.</p>
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        let request_uri = "/greengrass/groups";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateGroup")
+                        .deserialize::<CreateGroupResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a CA for the group. If a CA already exists, it will rotate the existing CA.</p>
+    fn create_group_certificate_authority(
+        &self,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> RusotoFuture<CreateGroupCertificateAuthorityResponse, CreateGroupCertificateAuthorityError>
+    {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/certificateauthorities",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroupCertificateAuthority");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateGroupCertificateAuthority")
+                        .deserialize::<CreateGroupCertificateAuthorityResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateGroupCertificateAuthorityError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a version of a group which has already been defined.</p>
+    fn create_group_version(
+        &self,
+        input: CreateGroupVersionRequest,
+    ) -> RusotoFuture<CreateGroupVersionResponse, CreateGroupVersionError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/versions",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroupVersion");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateGroupVersion")
+                        .deserialize::<CreateGroupVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGroupVersionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a logger definition. You may provide the initial version of the logger definition now or use &#39;&#39;CreateLoggerDefinitionVersion&#39;&#39; at a later time.</p>
+    fn create_logger_definition(
+        &self,
+        input: CreateLoggerDefinitionRequest,
+    ) -> RusotoFuture<CreateLoggerDefinitionResponse, CreateLoggerDefinitionError> {
+        let request_uri = "/greengrass/definition/loggers";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateLoggerDefinition");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateLoggerDefinition")
+                        .deserialize::<CreateLoggerDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateLoggerDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a version of a logger definition that has already been defined.</p>
+    fn create_logger_definition_version(
+        &self,
+        input: CreateLoggerDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateLoggerDefinitionVersionResponse, CreateLoggerDefinitionVersionError>
+    {
+        let request_uri = format!(
+            "/greengrass/definition/loggers/{logger_definition_id}/versions",
+            logger_definition_id = input.logger_definition_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateLoggerDefinitionVersion");
+        request.add_resource_id(
+            "LoggerDefinitionId",
+            &input.logger_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateLoggerDefinitionVersion")
+                        .deserialize::<CreateLoggerDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateLoggerDefinitionVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a resource definition which contains a list of resources to be used in a group. You can create an initial version of the definition by providing a list of resources now, or use &#39;&#39;CreateResourceDefinitionVersion&#39;&#39; later.</p>
+    fn create_resource_definition(
+        &self,
+        input: CreateResourceDefinitionRequest,
+    ) -> RusotoFuture<CreateResourceDefinitionResponse, CreateResourceDefinitionError> {
+        let request_uri = "/greengrass/definition/resources";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateResourceDefinition");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateResourceDefinition")
+                        .deserialize::<CreateResourceDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateResourceDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a version of a resource definition that has already been defined.</p>
+    fn create_resource_definition_version(
+        &self,
+        input: CreateResourceDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateResourceDefinitionVersionResponse, CreateResourceDefinitionVersionError>
+    {
+        let request_uri = format!(
+            "/greengrass/definition/resources/{resource_definition_id}/versions",
+            resource_definition_id = input.resource_definition_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateResourceDefinitionVersion");
+        request.add_resource_id(
+            "ResourceDefinitionId",
+            &input.resource_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateResourceDefinitionVersion")
+                        .deserialize::<CreateResourceDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateResourceDefinitionVersionError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a software update for a core or group of cores (specified as an IoT thing group.) Use this to update the OTA Agent as well as the Greengrass core software. It makes use of the IoT Jobs feature which provides additional commands to manage a Greengrass core software update job.</p>
+    fn create_software_update_job(
+        &self,
+        input: CreateSoftwareUpdateJobRequest,
+    ) -> RusotoFuture<CreateSoftwareUpdateJobResponse, CreateSoftwareUpdateJobError> {
+        let request_uri = "/greengrass/updates";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSoftwareUpdateJob");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateSoftwareUpdateJob")
+                        .deserialize::<CreateSoftwareUpdateJobResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateSoftwareUpdateJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a subscription definition. You may provide the initial version of the subscription definition now or use &#39;&#39;CreateSubscriptionDefinitionVersion&#39;&#39; at a later time.</p>
+    fn create_subscription_definition(
+        &self,
+        input: CreateSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<CreateSubscriptionDefinitionResponse, CreateSubscriptionDefinitionError> {
+        let request_uri = "/greengrass/definition/subscriptions";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSubscriptionDefinition");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateSubscriptionDefinition")
+                        .deserialize::<CreateSubscriptionDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateSubscriptionDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a version of a subscription definition which has already been defined.</p>
+    fn create_subscription_definition_version(
+        &self,
+        input: CreateSubscriptionDefinitionVersionRequest,
+    ) -> RusotoFuture<
+        CreateSubscriptionDefinitionVersionResponse,
+        CreateSubscriptionDefinitionVersionError,
+    > {
+        let request_uri = format!(
+            "/greengrass/definition/subscriptions/{subscription_definition_id}/versions",
+            subscription_definition_id = input.subscription_definition_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSubscriptionDefinitionVersion");
+        request.add_resource_id(
+            "SubscriptionDefinitionId",
+            &input.subscription_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateSubscriptionDefinitionVersion")
+                        .deserialize::<CreateSubscriptionDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateSubscriptionDefinitionVersionError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a connector definition.</p>
+    fn delete_connector_definition(
+        &self,
+        input: DeleteConnectorDefinitionRequest,
+    ) -> RusotoFuture<DeleteConnectorDefinitionResponse, DeleteConnectorDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/connectors/{connector_definition_id}",
+            connector_definition_id = input.connector_definition_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteConnectorDefinition");
+        request.add_resource_id(
+            "ConnectorDefinitionId",
+            &input.connector_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteConnectorDefinition")
+                        .deserialize::<DeleteConnectorDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteConnectorDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a core definition.</p>
+    fn delete_core_definition(
+        &self,
+        input: DeleteCoreDefinitionRequest,
+    ) -> RusotoFuture<DeleteCoreDefinitionResponse, DeleteCoreDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/cores/{core_definition_id}",
+            core_definition_id = input.core_definition_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteCoreDefinition");
+        request.add_resource_id("CoreDefinitionId", &input.core_definition_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteCoreDefinition")
+                        .deserialize::<DeleteCoreDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteCoreDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a device definition.</p>
+    fn delete_device_definition(
+        &self,
+        input: DeleteDeviceDefinitionRequest,
+    ) -> RusotoFuture<DeleteDeviceDefinitionResponse, DeleteDeviceDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/devices/{device_definition_id}",
+            device_definition_id = input.device_definition_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteDeviceDefinition");
+        request.add_resource_id(
+            "DeviceDefinitionId",
+            &input.device_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteDeviceDefinition")
+                        .deserialize::<DeleteDeviceDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteDeviceDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a Lambda function definition.</p>
+    fn delete_function_definition(
+        &self,
+        input: DeleteFunctionDefinitionRequest,
+    ) -> RusotoFuture<DeleteFunctionDefinitionResponse, DeleteFunctionDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/functions/{function_definition_id}",
+            function_definition_id = input.function_definition_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionDefinition");
+        request.add_resource_id(
+            "FunctionDefinitionId",
+            &input.function_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteFunctionDefinition")
+                        .deserialize::<DeleteFunctionDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteFunctionDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a group.</p>
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
+        let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteGroup")
+                        .deserialize::<DeleteGroupResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a logger definition.</p>
+    fn delete_logger_definition(
+        &self,
+        input: DeleteLoggerDefinitionRequest,
+    ) -> RusotoFuture<DeleteLoggerDefinitionResponse, DeleteLoggerDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/loggers/{logger_definition_id}",
+            logger_definition_id = input.logger_definition_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteLoggerDefinition");
+        request.add_resource_id(
+            "LoggerDefinitionId",
+            &input.logger_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteLoggerDefinition")
+                        .deserialize::<DeleteLoggerDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteLoggerDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a resource definition.</p>
+    fn delete_resource_definition(
+        &self,
+        input: DeleteResourceDefinitionRequest,
+    ) -> RusotoFuture<DeleteResourceDefinitionResponse, DeleteResourceDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/resources/{resource_definition_id}",
+            resource_definition_id = input.resource_definition_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteResourceDefinition");
+        request.add_resource_id(
+            "ResourceDefinitionId",
+            &input.resource_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteResourceDefinition")
+                        .deserialize::<DeleteResourceDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteResourceDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a subscription definition.</p>
+    fn delete_subscription_definition(
+        &self,
+        input: DeleteSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<DeleteSubscriptionDefinitionResponse, DeleteSubscriptionDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/subscriptions/{subscription_definition_id}",
+            subscription_definition_id = input.subscription_definition_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteSubscriptionDefinition");
+        request.add_resource_id(
+            "SubscriptionDefinitionId",
+            &input.subscription_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteSubscriptionDefinition")
+                        .deserialize::<DeleteSubscriptionDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteSubscriptionDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the role from a group.</p>
+    fn disassociate_role_from_group(
+        &self,
+        input: DisassociateRoleFromGroupRequest,
+    ) -> RusotoFuture<DisassociateRoleFromGroupResponse, DisassociateRoleFromGroupError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/role",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DisassociateRoleFromGroup");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateRoleFromGroup")
+                        .deserialize::<DisassociateRoleFromGroupResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateRoleFromGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the service role from your account. Without a service role, deployments will not work.</p>
+    fn disassociate_service_role_from_account(
+        &self,
+    ) -> RusotoFuture<
+        DisassociateServiceRoleFromAccountResponse,
+        DisassociateServiceRoleFromAccountError,
+    > {
+        let request_uri = "/greengrass/servicerole";
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DisassociateServiceRoleFromAccount");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateServiceRoleFromAccount")
+                        .deserialize::<DisassociateServiceRoleFromAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateServiceRoleFromAccountError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the role associated with a particular group.</p>
+    fn get_associated_role(
+        &self,
+        input: GetAssociatedRoleRequest,
+    ) -> RusotoFuture<GetAssociatedRoleResponse, GetAssociatedRoleError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/role",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetAssociatedRole");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAssociatedRole")
+                        .deserialize::<GetAssociatedRoleResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAssociatedRoleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the status of a bulk deployment.</p>
+    fn get_bulk_deployment_status(
+        &self,
+        input: GetBulkDeploymentStatusRequest,
+    ) -> RusotoFuture<GetBulkDeploymentStatusResponse, GetBulkDeploymentStatusError> {
+        let request_uri = format!(
+            "/greengrass/bulk/deployments/{bulk_deployment_id}/status",
+            bulk_deployment_id = input.bulk_deployment_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetBulkDeploymentStatus");
+        request.add_resource_id("BulkDeploymentId", &input.bulk_deployment_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBulkDeploymentStatus")
+                        .deserialize::<GetBulkDeploymentStatusResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetBulkDeploymentStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the connectivity information for a core.</p>
+    fn get_connectivity_info(
+        &self,
+        input: GetConnectivityInfoRequest,
+    ) -> RusotoFuture<GetConnectivityInfoResponse, GetConnectivityInfoError> {
+        let request_uri = format!(
+            "/greengrass/things/{thing_name}/connectivityInfo",
+            thing_name = input.thing_name
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectivityInfo");
+        request.add_resource_id("ThingName", &input.thing_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetConnectivityInfo")
+                        .deserialize::<GetConnectivityInfoResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetConnectivityInfoError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a connector definition.</p>
+    fn get_connector_definition(
+        &self,
+        input: GetConnectorDefinitionRequest,
+    ) -> RusotoFuture<GetConnectorDefinitionResponse, GetConnectorDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/connectors/{connector_definition_id}",
+            connector_definition_id = input.connector_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectorDefinition");
+        request.add_resource_id(
+            "ConnectorDefinitionId",
+            &input.connector_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetConnectorDefinition")
+                        .deserialize::<GetConnectorDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetConnectorDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a connector definition version, including the connectors that the version contains. Connectors are prebuilt modules that interact with local infrastructure, device protocols, AWS, and other cloud services.</p>
+    fn get_connector_definition_version(
+        &self,
+        input: GetConnectorDefinitionVersionRequest,
+    ) -> RusotoFuture<GetConnectorDefinitionVersionResponse, GetConnectorDefinitionVersionError>
+    {
+        let request_uri = format!("/greengrass/definition/connectors/{connector_definition_id}/versions/{connector_definition_version_id}", connector_definition_id = input.connector_definition_id, connector_definition_version_id = input.connector_definition_version_id);
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectorDefinitionVersion");
+        request.add_resource_id(
+            "ConnectorDefinitionId",
+            &input.connector_definition_id.to_string(),
+        );
+        request.add_resource_id(
+            "ConnectorDefinitionVersionId",
+            &input.connector_definition_version_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetConnectorDefinitionVersion")
+                        .deserialize::<GetConnectorDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetConnectorDefinitionVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a core definition version.</p>
+    fn get_core_definition(
+        &self,
+        input: GetCoreDefinitionRequest,
+    ) -> RusotoFuture<GetCoreDefinitionResponse, GetCoreDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/cores/{core_definition_id}",
+            core_definition_id = input.core_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetCoreDefinition");
+        request.add_resource_id("CoreDefinitionId", &input.core_definition_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetCoreDefinition")
+                        .deserialize::<GetCoreDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetCoreDefinitionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a core definition version.</p>
+    fn get_core_definition_version(
+        &self,
+        input: GetCoreDefinitionVersionRequest,
+    ) -> RusotoFuture<GetCoreDefinitionVersionResponse, GetCoreDefinitionVersionError> {
+        let request_uri = format!("/greengrass/definition/cores/{core_definition_id}/versions/{core_definition_version_id}", core_definition_id = input.core_definition_id, core_definition_version_id = input.core_definition_version_id);
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetCoreDefinitionVersion");
+        request.add_resource_id("CoreDefinitionId", &input.core_definition_id.to_string());
+        request.add_resource_id(
+            "CoreDefinitionVersionId",
+            &input.core_definition_version_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetCoreDefinitionVersion")
+                        .deserialize::<GetCoreDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetCoreDefinitionVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the status of a deployment.</p>
+    fn get_deployment_status(
+        &self,
+        input: GetDeploymentStatusRequest,
+    ) -> RusotoFuture<GetDeploymentStatusResponse, GetDeploymentStatusError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/deployments/{deployment_id}/status",
+            deployment_id = input.deployment_id,
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeploymentStatus");
+        request.add_resource_id("DeploymentId", &input.deployment_id.to_string());
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDeploymentStatus")
+                        .deserialize::<GetDeploymentStatusResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetDeploymentStatusError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a device definition.</p>
+    fn get_device_definition(
+        &self,
+        input: GetDeviceDefinitionRequest,
+    ) -> RusotoFuture<GetDeviceDefinitionResponse, GetDeviceDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/devices/{device_definition_id}",
+            device_definition_id = input.device_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeviceDefinition");
+        request.add_resource_id(
+            "DeviceDefinitionId",
+            &input.device_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDeviceDefinition")
+                        .deserialize::<GetDeviceDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetDeviceDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a device definition version.</p>
+    fn get_device_definition_version(
+        &self,
+        input: GetDeviceDefinitionVersionRequest,
+    ) -> RusotoFuture<GetDeviceDefinitionVersionResponse, GetDeviceDefinitionVersionError> {
+        let request_uri = format!("/greengrass/definition/devices/{device_definition_id}/versions/{device_definition_version_id}", device_definition_id = input.device_definition_id, device_definition_version_id = input.device_definition_version_id);
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeviceDefinitionVersion");
+        request.add_resource_id(
+            "DeviceDefinitionId",
+            &input.device_definition_id.to_string(),
+        );
+        request.add_resource_id(
+            "DeviceDefinitionVersionId",
+            &input.device_definition_version_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDeviceDefinitionVersion")
+                        .deserialize::<GetDeviceDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetDeviceDefinitionVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a Lambda function definition, including its creation time and latest version.</p>
+    fn get_function_definition(
+        &self,
+        input: GetFunctionDefinitionRequest,
+    ) -> RusotoFuture<GetFunctionDefinitionResponse, GetFunctionDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/functions/{function_definition_id}",
+            function_definition_id = input.function_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetFunctionDefinition");
+        request.add_resource_id(
+            "FunctionDefinitionId",
+            &input.function_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFunctionDefinition")
+                        .deserialize::<GetFunctionDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetFunctionDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a Lambda function definition version, including which Lambda functions are included in the version and their configurations.</p>
+    fn get_function_definition_version(
+        &self,
+        input: GetFunctionDefinitionVersionRequest,
+    ) -> RusotoFuture<GetFunctionDefinitionVersionResponse, GetFunctionDefinitionVersionError> {
+        let request_uri = format!("/greengrass/definition/functions/{function_definition_id}/versions/{function_definition_version_id}", function_definition_id = input.function_definition_id, function_definition_version_id = input.function_definition_version_id);
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetFunctionDefinitionVersion");
+        request.add_resource_id(
+            "FunctionDefinitionId",
+            &input.function_definition_id.to_string(),
+        );
+        request.add_resource_id(
+            "FunctionDefinitionVersionId",
+            &input.function_definition_version_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFunctionDefinitionVersion")
+                        .deserialize::<GetFunctionDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetFunctionDefinitionVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a group.</p>
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroup");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGroup")
+                        .deserialize::<GetGroupResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retreives the CA associated with a group. Returns the public key of the CA.</p>
+    fn get_group_certificate_authority(
+        &self,
+        input: GetGroupCertificateAuthorityRequest,
+    ) -> RusotoFuture<GetGroupCertificateAuthorityResponse, GetGroupCertificateAuthorityError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/certificateauthorities/{certificate_authority_id}",
+            certificate_authority_id = input.certificate_authority_id,
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupCertificateAuthority");
+        request.add_resource_id(
+            "CertificateAuthorityId",
+            &input.certificate_authority_id.to_string(),
+        );
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGroupCertificateAuthority")
+                        .deserialize::<GetGroupCertificateAuthorityResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetGroupCertificateAuthorityError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the current configuration for the CA used by the group.</p>
+    fn get_group_certificate_configuration(
+        &self,
+        input: GetGroupCertificateConfigurationRequest,
+    ) -> RusotoFuture<GetGroupCertificateConfigurationResponse, GetGroupCertificateConfigurationError>
+    {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/certificateauthorities/configuration/expiry",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupCertificateConfiguration");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGroupCertificateConfiguration")
+                        .deserialize::<GetGroupCertificateConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetGroupCertificateConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a group version.</p>
+    fn get_group_version(
+        &self,
+        input: GetGroupVersionRequest,
+    ) -> RusotoFuture<GetGroupVersionResponse, GetGroupVersionError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/versions/{group_version_id}",
+            group_id = input.group_id,
+            group_version_id = input.group_version_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupVersion");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.add_resource_id("GroupVersionId", &input.group_version_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGroupVersion")
+                        .deserialize::<GetGroupVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetGroupVersionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a logger definition.</p>
+    fn get_logger_definition(
+        &self,
+        input: GetLoggerDefinitionRequest,
+    ) -> RusotoFuture<GetLoggerDefinitionResponse, GetLoggerDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/loggers/{logger_definition_id}",
+            logger_definition_id = input.logger_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetLoggerDefinition");
+        request.add_resource_id(
+            "LoggerDefinitionId",
+            &input.logger_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetLoggerDefinition")
+                        .deserialize::<GetLoggerDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetLoggerDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a logger definition version.</p>
+    fn get_logger_definition_version(
+        &self,
+        input: GetLoggerDefinitionVersionRequest,
+    ) -> RusotoFuture<GetLoggerDefinitionVersionResponse, GetLoggerDefinitionVersionError> {
+        let request_uri = format!("/greengrass/definition/loggers/{logger_definition_id}/versions/{logger_definition_version_id}", logger_definition_id = input.logger_definition_id, logger_definition_version_id = input.logger_definition_version_id);
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetLoggerDefinitionVersion");
+        request.add_resource_id(
+            "LoggerDefinitionId",
+            &input.logger_definition_id.to_string(),
+        );
+        request.add_resource_id(
+            "LoggerDefinitionVersionId",
+            &input.logger_definition_version_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetLoggerDefinitionVersion")
+                        .deserialize::<GetLoggerDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetLoggerDefinitionVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a resource definition, including its creation time and latest version.</p>
+    fn get_resource_definition(
+        &self,
+        input: GetResourceDefinitionRequest,
+    ) -> RusotoFuture<GetResourceDefinitionResponse, GetResourceDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/resources/{resource_definition_id}",
+            resource_definition_id = input.resource_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetResourceDefinition");
+        request.add_resource_id(
+            "ResourceDefinitionId",
+            &input.resource_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetResourceDefinition")
+                        .deserialize::<GetResourceDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetResourceDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a resource definition version, including which resources are included in the version.</p>
+    fn get_resource_definition_version(
+        &self,
+        input: GetResourceDefinitionVersionRequest,
+    ) -> RusotoFuture<GetResourceDefinitionVersionResponse, GetResourceDefinitionVersionError> {
+        let request_uri = format!("/greengrass/definition/resources/{resource_definition_id}/versions/{resource_definition_version_id}", resource_definition_id = input.resource_definition_id, resource_definition_version_id = input.resource_definition_version_id);
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetResourceDefinitionVersion");
+        request.add_resource_id(
+            "ResourceDefinitionId",
+            &input.resource_definition_id.to_string(),
+        );
+        request.add_resource_id(
+            "ResourceDefinitionVersionId",
+            &input.resource_definition_version_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetResourceDefinitionVersion")
+                        .deserialize::<GetResourceDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetResourceDefinitionVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the service role that is attached to your account.</p>
+    fn get_service_role_for_account(
+        &self,
+    ) -> RusotoFuture<GetServiceRoleForAccountResponse, GetServiceRoleForAccountError> {
+        let request_uri = "/greengrass/servicerole";
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetServiceRoleForAccount");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetServiceRoleForAccount")
+                        .deserialize::<GetServiceRoleForAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetServiceRoleForAccountError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a subscription definition.</p>
+    fn get_subscription_definition(
+        &self,
+        input: GetSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<GetSubscriptionDefinitionResponse, GetSubscriptionDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/subscriptions/{subscription_definition_id}",
+            subscription_definition_id = input.subscription_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetSubscriptionDefinition");
+        request.add_resource_id(
+            "SubscriptionDefinitionId",
+            &input.subscription_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSubscriptionDefinition")
+                        .deserialize::<GetSubscriptionDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetSubscriptionDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a subscription definition version.</p>
+    fn get_subscription_definition_version(
+        &self,
+        input: GetSubscriptionDefinitionVersionRequest,
+    ) -> RusotoFuture<GetSubscriptionDefinitionVersionResponse, GetSubscriptionDefinitionVersionError>
+    {
+        let request_uri = format!("/greengrass/definition/subscriptions/{subscription_definition_id}/versions/{subscription_definition_version_id}", subscription_definition_id = input.subscription_definition_id, subscription_definition_version_id = input.subscription_definition_version_id);
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetSubscriptionDefinitionVersion");
+        request.add_resource_id(
+            "SubscriptionDefinitionId",
+            &input.subscription_definition_id.to_string(),
+        );
+        request.add_resource_id(
+            "SubscriptionDefinitionVersionId",
+            &input.subscription_definition_version_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSubscriptionDefinitionVersion")
+                        .deserialize::<GetSubscriptionDefinitionVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetSubscriptionDefinitionVersionError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets a paginated list of the deployments that have been started in a bulk deployment operation, and their current deployment status.</p>
+    fn list_bulk_deployment_detailed_reports(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> RusotoFuture<
+        ListBulkDeploymentDetailedReportsResponse,
+        ListBulkDeploymentDetailedReportsError,
+    > {
+        let request_uri = format!(
+            "/greengrass/bulk/deployments/{bulk_deployment_id}/detailed-reports",
+            bulk_deployment_id = input.bulk_deployment_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListBulkDeploymentDetailedReports");
+        request.add_resource_id("BulkDeploymentId", &input.bulk_deployment_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListBulkDeploymentDetailedReports")
+                        .deserialize::<ListBulkDeploymentDetailedReportsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListBulkDeploymentDetailedReportsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of bulk deployments.</p>
+    fn list_bulk_deployments(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> RusotoFuture<ListBulkDeploymentsResponse, ListBulkDeploymentsError> {
+        let request_uri = "/greengrass/bulk/deployments";
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListBulkDeployments");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListBulkDeployments")
+                        .deserialize::<ListBulkDeploymentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListBulkDeploymentsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the versions of a connector definition, which are containers for connectors. Connectors run on the Greengrass core and contain built-in integration with local infrastructure, device protocols, AWS, and other cloud services.</p>
+    fn list_connector_definition_versions(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListConnectorDefinitionVersionsResponse, ListConnectorDefinitionVersionsError>
+    {
+        let request_uri = format!(
+            "/greengrass/definition/connectors/{connector_definition_id}/versions",
+            connector_definition_id = input.connector_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListConnectorDefinitionVersions");
+        request.add_resource_id(
+            "ConnectorDefinitionId",
+            &input.connector_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListConnectorDefinitionVersions")
+                        .deserialize::<ListConnectorDefinitionVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListConnectorDefinitionVersionsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of connector definitions.</p>
+    fn list_connector_definitions(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> RusotoFuture<ListConnectorDefinitionsResponse, ListConnectorDefinitionsError> {
+        let request_uri = "/greengrass/definition/connectors";
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListConnectorDefinitions");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListConnectorDefinitions")
+                        .deserialize::<ListConnectorDefinitionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListConnectorDefinitionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the versions of a core definition.</p>
+    fn list_core_definition_versions(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListCoreDefinitionVersionsResponse, ListCoreDefinitionVersionsError> {
+        let request_uri = format!(
+            "/greengrass/definition/cores/{core_definition_id}/versions",
+            core_definition_id = input.core_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListCoreDefinitionVersions");
+        request.add_resource_id("CoreDefinitionId", &input.core_definition_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListCoreDefinitionVersions")
+                        .deserialize::<ListCoreDefinitionVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListCoreDefinitionVersionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of core definitions.</p>
+    fn list_core_definitions(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> RusotoFuture<ListCoreDefinitionsResponse, ListCoreDefinitionsError> {
+        let request_uri = "/greengrass/definition/cores";
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListCoreDefinitions");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListCoreDefinitions")
+                        .deserialize::<ListCoreDefinitionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListCoreDefinitionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a history of deployments for the group.</p>
+    fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> RusotoFuture<ListDeploymentsResponse, ListDeploymentsError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/deployments",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeployments");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDeployments")
+                        .deserialize::<ListDeploymentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDeploymentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the versions of a device definition.</p>
+    fn list_device_definition_versions(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListDeviceDefinitionVersionsResponse, ListDeviceDefinitionVersionsError> {
+        let request_uri = format!(
+            "/greengrass/definition/devices/{device_definition_id}/versions",
+            device_definition_id = input.device_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeviceDefinitionVersions");
+        request.add_resource_id(
+            "DeviceDefinitionId",
+            &input.device_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDeviceDefinitionVersions")
+                        .deserialize::<ListDeviceDefinitionVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListDeviceDefinitionVersionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of device definitions.</p>
+    fn list_device_definitions(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> RusotoFuture<ListDeviceDefinitionsResponse, ListDeviceDefinitionsError> {
+        let request_uri = "/greengrass/definition/devices";
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeviceDefinitions");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDeviceDefinitions")
+                        .deserialize::<ListDeviceDefinitionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListDeviceDefinitionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the versions of a Lambda function definition.</p>
+    fn list_function_definition_versions(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListFunctionDefinitionVersionsResponse, ListFunctionDefinitionVersionsError>
+    {
+        let request_uri = format!(
+            "/greengrass/definition/functions/{function_definition_id}/versions",
+            function_definition_id = input.function_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListFunctionDefinitionVersions");
+        request.add_resource_id(
+            "FunctionDefinitionId",
+            &input.function_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListFunctionDefinitionVersions")
+                        .deserialize::<ListFunctionDefinitionVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListFunctionDefinitionVersionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of Lambda function definitions.</p>
+    fn list_function_definitions(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> RusotoFuture<ListFunctionDefinitionsResponse, ListFunctionDefinitionsError> {
+        let request_uri = "/greengrass/definition/functions";
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListFunctionDefinitions");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListFunctionDefinitions")
+                        .deserialize::<ListFunctionDefinitionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListFunctionDefinitionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the current CAs for a group.</p>
+    fn list_group_certificate_authorities(
+        &self,
+        input: ListGroupCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<ListGroupCertificateAuthoritiesResponse, ListGroupCertificateAuthoritiesError>
+    {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/certificateauthorities",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroupCertificateAuthorities");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListGroupCertificateAuthorities")
+                        .deserialize::<ListGroupCertificateAuthoritiesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListGroupCertificateAuthoritiesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the versions of a group.</p>
+    fn list_group_versions(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> RusotoFuture<ListGroupVersionsResponse, ListGroupVersionsError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/versions",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroupVersions");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListGroupVersions")
+                        .deserialize::<ListGroupVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGroupVersionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of groups.</p>
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        let request_uri = "/greengrass/groups";
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroups");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListGroups")
+                        .deserialize::<ListGroupsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the versions of a logger definition.</p>
+    fn list_logger_definition_versions(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListLoggerDefinitionVersionsResponse, ListLoggerDefinitionVersionsError> {
+        let request_uri = format!(
+            "/greengrass/definition/loggers/{logger_definition_id}/versions",
+            logger_definition_id = input.logger_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListLoggerDefinitionVersions");
+        request.add_resource_id(
+            "LoggerDefinitionId",
+            &input.logger_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListLoggerDefinitionVersions")
+                        .deserialize::<ListLoggerDefinitionVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListLoggerDefinitionVersionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of logger definitions.</p>
+    fn list_logger_definitions(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> RusotoFuture<ListLoggerDefinitionsResponse, ListLoggerDefinitionsError> {
+        let request_uri = "/greengrass/definition/loggers";
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListLoggerDefinitions");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListLoggerDefinitions")
+                        .deserialize::<ListLoggerDefinitionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListLoggerDefinitionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the versions of a resource definition.</p>
+    fn list_resource_definition_versions(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListResourceDefinitionVersionsResponse, ListResourceDefinitionVersionsError>
+    {
+        let request_uri = format!(
+            "/greengrass/definition/resources/{resource_definition_id}/versions",
+            resource_definition_id = input.resource_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListResourceDefinitionVersions");
+        request.add_resource_id(
+            "ResourceDefinitionId",
+            &input.resource_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListResourceDefinitionVersions")
+                        .deserialize::<ListResourceDefinitionVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListResourceDefinitionVersionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of resource definitions.</p>
+    fn list_resource_definitions(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> RusotoFuture<ListResourceDefinitionsResponse, ListResourceDefinitionsError> {
+        let request_uri = "/greengrass/definition/resources";
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListResourceDefinitions");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListResourceDefinitions")
+                        .deserialize::<ListResourceDefinitionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListResourceDefinitionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the versions of a subscription definition.</p>
+    fn list_subscription_definition_versions(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> RusotoFuture<
+        ListSubscriptionDefinitionVersionsResponse,
+        ListSubscriptionDefinitionVersionsError,
+    > {
+        let request_uri = format!(
+            "/greengrass/definition/subscriptions/{subscription_definition_id}/versions",
+            subscription_definition_id = input.subscription_definition_id
+        );
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListSubscriptionDefinitionVersions");
+        request.add_resource_id(
+            "SubscriptionDefinitionId",
+            &input.subscription_definition_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListSubscriptionDefinitionVersions")
+                        .deserialize::<ListSubscriptionDefinitionVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListSubscriptionDefinitionVersionsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of subscription definitions.</p>
+    fn list_subscription_definitions(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> RusotoFuture<ListSubscriptionDefinitionsResponse, ListSubscriptionDefinitionsError> {
+        let request_uri = "/greengrass/definition/subscriptions";
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListSubscriptionDefinitions");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListSubscriptionDefinitions")
+                        .deserialize::<ListSubscriptionDefinitionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListSubscriptionDefinitionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of resource tags for a resource arn.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
+                        .deserialize::<ListTagsForResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Resets a group&#39;s deployments.</p>
+    fn reset_deployments(
+        &self,
+        input: ResetDeploymentsRequest,
+    ) -> RusotoFuture<ResetDeploymentsResponse, ResetDeploymentsError> {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/deployments/$reset",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("ResetDeployments");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ResetDeployments")
+                        .deserialize::<ResetDeploymentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResetDeploymentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deploys multiple groups in one operation. This action starts the bulk deployment of a specified set of group versions. Each group version deployment will be triggered with an adaptive rate that has a fixed upper limit. We recommend that you include an &#39;&#39;X-Amzn-Client-Token&#39;&#39; token in every &#39;&#39;StartBulkDeployment&#39;&#39; request. These requests are idempotent with respect to the token and the request parameters.</p>
+    fn start_bulk_deployment(
+        &self,
+        input: StartBulkDeploymentRequest,
+    ) -> RusotoFuture<StartBulkDeploymentResponse, StartBulkDeploymentError> {
+        let request_uri = "/greengrass/bulk/deployments";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("StartBulkDeployment");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartBulkDeployment")
+                        .deserialize::<StartBulkDeploymentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartBulkDeploymentError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops the execution of a bulk deployment. This action returns a status of &#39;&#39;Stopping&#39;&#39; until the deployment is stopped. You cannot start a new bulk deployment while a previous deployment is in the &#39;&#39;Stopping&#39;&#39; state. This action doesn&#39;t rollback completed deployments or cancel pending deployments.</p>
+    fn stop_bulk_deployment(
+        &self,
+        input: StopBulkDeploymentRequest,
+    ) -> RusotoFuture<StopBulkDeploymentResponse, StopBulkDeploymentError> {
+        let request_uri = format!(
+            "/greengrass/bulk/deployments/{bulk_deployment_id}/$stop",
+            bulk_deployment_id = input.bulk_deployment_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("StopBulkDeployment");
+        request.add_resource_id("BulkDeploymentId", &input.bulk_deployment_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StopBulkDeployment")
+                        .deserialize::<StopBulkDeploymentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopBulkDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Add resource tags to a Greengrass Resource. Valid resources are Group, Connector, Core, Device, Function, Logger, Subscription, and Resource Defintions, and also BulkDeploymentIds.</p>
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Remove resource tags from a Greengrass Resource.</p>
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the connectivity information for the core. Any devices that belong to the group which has this core will receive this information in order to find the location of the core and connect to it.</p>
+    fn update_connectivity_info(
+        &self,
+        input: UpdateConnectivityInfoRequest,
+    ) -> RusotoFuture<UpdateConnectivityInfoResponse, UpdateConnectivityInfoError> {
+        let request_uri = format!(
+            "/greengrass/things/{thing_name}/connectivityInfo",
+            thing_name = input.thing_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateConnectivityInfo");
+        request.add_resource_id("ThingName", &input.thing_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateConnectivityInfo")
+                        .deserialize::<UpdateConnectivityInfoResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateConnectivityInfoError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a connector definition.</p>
+    fn update_connector_definition(
+        &self,
+        input: UpdateConnectorDefinitionRequest,
+    ) -> RusotoFuture<UpdateConnectorDefinitionResponse, UpdateConnectorDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/connectors/{connector_definition_id}",
+            connector_definition_id = input.connector_definition_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateConnectorDefinition");
+        request.add_resource_id(
+            "ConnectorDefinitionId",
+            &input.connector_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateConnectorDefinition")
+                        .deserialize::<UpdateConnectorDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateConnectorDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates a core definition.</p>
+    fn update_core_definition(
+        &self,
+        input: UpdateCoreDefinitionRequest,
+    ) -> RusotoFuture<UpdateCoreDefinitionResponse, UpdateCoreDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/cores/{core_definition_id}",
+            core_definition_id = input.core_definition_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateCoreDefinition");
+        request.add_resource_id("CoreDefinitionId", &input.core_definition_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateCoreDefinition")
+                        .deserialize::<UpdateCoreDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateCoreDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a device definition.</p>
+    fn update_device_definition(
+        &self,
+        input: UpdateDeviceDefinitionRequest,
+    ) -> RusotoFuture<UpdateDeviceDefinitionResponse, UpdateDeviceDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/devices/{device_definition_id}",
+            device_definition_id = input.device_definition_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateDeviceDefinition");
+        request.add_resource_id(
+            "DeviceDefinitionId",
+            &input.device_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDeviceDefinition")
+                        .deserialize::<UpdateDeviceDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateDeviceDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a Lambda function definition.</p>
+    fn update_function_definition(
+        &self,
+        input: UpdateFunctionDefinitionRequest,
+    ) -> RusotoFuture<UpdateFunctionDefinitionResponse, UpdateFunctionDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/functions/{function_definition_id}",
+            function_definition_id = input.function_definition_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionDefinition");
+        request.add_resource_id(
+            "FunctionDefinitionId",
+            &input.function_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateFunctionDefinition")
+                        .deserialize::<UpdateFunctionDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateFunctionDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates a group.</p>
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
+        let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateGroup")
+                        .deserialize::<UpdateGroupResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the Certificate expiry time for a group.</p>
+    fn update_group_certificate_configuration(
+        &self,
+        input: UpdateGroupCertificateConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateGroupCertificateConfigurationResponse,
+        UpdateGroupCertificateConfigurationError,
+    > {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/certificateauthorities/configuration/expiry",
+            group_id = input.group_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateGroupCertificateConfiguration");
+        request.add_resource_id("GroupId", &input.group_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateGroupCertificateConfiguration")
+                        .deserialize::<UpdateGroupCertificateConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateGroupCertificateConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates a logger definition.</p>
+    fn update_logger_definition(
+        &self,
+        input: UpdateLoggerDefinitionRequest,
+    ) -> RusotoFuture<UpdateLoggerDefinitionResponse, UpdateLoggerDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/loggers/{logger_definition_id}",
+            logger_definition_id = input.logger_definition_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateLoggerDefinition");
+        request.add_resource_id(
+            "LoggerDefinitionId",
+            &input.logger_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateLoggerDefinition")
+                        .deserialize::<UpdateLoggerDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateLoggerDefinitionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a resource definition.</p>
+    fn update_resource_definition(
+        &self,
+        input: UpdateResourceDefinitionRequest,
+    ) -> RusotoFuture<UpdateResourceDefinitionResponse, UpdateResourceDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/resources/{resource_definition_id}",
+            resource_definition_id = input.resource_definition_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateResourceDefinition");
+        request.add_resource_id(
+            "ResourceDefinitionId",
+            &input.resource_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateResourceDefinition")
+                        .deserialize::<UpdateResourceDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateResourceDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates a subscription definition.</p>
+    fn update_subscription_definition(
+        &self,
+        input: UpdateSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<UpdateSubscriptionDefinitionResponse, UpdateSubscriptionDefinitionError> {
+        let request_uri = format!(
+            "/greengrass/definition/subscriptions/{subscription_definition_id}",
+            subscription_definition_id = input.subscription_definition_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateSubscriptionDefinition");
+        request.add_resource_id(
+            "SubscriptionDefinitionId",
+            &input.subscription_definition_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateSubscriptionDefinition")
+                        .deserialize::<UpdateSubscriptionDefinitionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateSubscriptionDefinitionError::from_response(response))
+                }))
+            }
+        })
+    }
+}
+
+/// A mock of the AWS Greengrass API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockGreenGrass {
+    associate_role_to_group: Option<
+        Box<
+            dyn Fn(
+                    AssociateRoleToGroupRequest,
+                ) -> ::std::result::Result<
+                    AssociateRoleToGroupResponse,
+                    RusotoError<AssociateRoleToGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_service_role_to_account: Option<
+        Box<
+            dyn Fn(
+                    AssociateServiceRoleToAccountRequest,
+                ) -> ::std::result::Result<
+                    AssociateServiceRoleToAccountResponse,
+                    RusotoError<AssociateServiceRoleToAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_connector_definition: Option<
+        Box<
+            dyn Fn(
+                    CreateConnectorDefinitionRequest,
+                ) -> ::std::result::Result<
+                    CreateConnectorDefinitionResponse,
+                    RusotoError<CreateConnectorDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_connector_definition_version: Option<
+        Box<
+            dyn Fn(
+                    CreateConnectorDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateConnectorDefinitionVersionResponse,
+                    RusotoError<CreateConnectorDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_core_definition: Option<
+        Box<
+            dyn Fn(
+                    CreateCoreDefinitionRequest,
+                ) -> ::std::result::Result<
+                    CreateCoreDefinitionResponse,
+                    RusotoError<CreateCoreDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_core_definition_version: Option<
+        Box<
+            dyn Fn(
+                    CreateCoreDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateCoreDefinitionVersionResponse,
+                    RusotoError<CreateCoreDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_deployment: Option<
+        Box<
+            dyn Fn(
+                    CreateDeploymentRequest,
+                ) -> ::std::result::Result<
+                    CreateDeploymentResponse,
+                    RusotoError<CreateDeploymentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_device_definition: Option<
+        Box<
+            dyn Fn(
+                    CreateDeviceDefinitionRequest,
+                ) -> ::std::result::Result<
+                    CreateDeviceDefinitionResponse,
+                    RusotoError<CreateDeviceDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_device_definition_version: Option<
+        Box<
+            dyn Fn(
+                    CreateDeviceDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateDeviceDefinitionVersionResponse,
+                    RusotoError<CreateDeviceDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_function_definition: Option<
+        Box<
+            dyn Fn(
+                    CreateFunctionDefinitionRequest,
+                ) -> ::std::result::Result<
+                    CreateFunctionDefinitionResponse,
+                    RusotoError<CreateFunctionDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_function_definition_version: Option<
+        Box<
+            dyn Fn(
+                    CreateFunctionDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateFunctionDefinitionVersionResponse,
+                    RusotoError<CreateFunctionDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_group: Option<
+        Box<
+            dyn Fn(
+                    CreateGroupRequest,
+                )
+                    -> ::std::result::Result<CreateGroupResponse, RusotoError<CreateGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_group_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    CreateGroupCertificateAuthorityRequest,
+                ) -> ::std::result::Result<
+                    CreateGroupCertificateAuthorityResponse,
+                    RusotoError<CreateGroupCertificateAuthorityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_group_version: Option<
+        Box<
+            dyn Fn(
+                    CreateGroupVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateGroupVersionResponse,
+                    RusotoError<CreateGroupVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_logger_definition: Option<
+        Box<
+            dyn Fn(
+                    CreateLoggerDefinitionRequest,
+                ) -> ::std::result::Result<
+                    CreateLoggerDefinitionResponse,
+                    RusotoError<CreateLoggerDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_logger_definition_version: Option<
+        Box<
+            dyn Fn(
+                    CreateLoggerDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateLoggerDefinitionVersionResponse,
+                    RusotoError<CreateLoggerDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_resource_definition: Option<
+        Box<
+            dyn Fn(
+                    CreateResourceDefinitionRequest,
+                ) -> ::std::result::Result<
+                    CreateResourceDefinitionResponse,
+                    RusotoError<CreateResourceDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_resource_definition_version: Option<
+        Box<
+            dyn Fn(
+                    CreateResourceDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateResourceDefinitionVersionResponse,
+                    RusotoError<CreateResourceDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_software_update_job: Option<
+        Box<
+            dyn Fn(
+                    CreateSoftwareUpdateJobRequest,
+                ) -> ::std::result::Result<
+                    CreateSoftwareUpdateJobResponse,
+                    RusotoError<CreateSoftwareUpdateJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_subscription_definition: Option<
+        Box<
+            dyn Fn(
+                    CreateSubscriptionDefinitionRequest,
+                ) -> ::std::result::Result<
+                    CreateSubscriptionDefinitionResponse,
+                    RusotoError<CreateSubscriptionDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_subscription_definition_version: Option<
+        Box<
+            dyn Fn(
+                    CreateSubscriptionDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateSubscriptionDefinitionVersionResponse,
+                    RusotoError<CreateSubscriptionDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_connector_definition: Option<
+        Box<
+            dyn Fn(
+                    DeleteConnectorDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DeleteConnectorDefinitionResponse,
+                    RusotoError<DeleteConnectorDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_core_definition: Option<
+        Box<
+            dyn Fn(
+                    DeleteCoreDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DeleteCoreDefinitionResponse,
+                    RusotoError<DeleteCoreDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_device_definition: Option<
+        Box<
+            dyn Fn(
+                    DeleteDeviceDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DeleteDeviceDefinitionResponse,
+                    RusotoError<DeleteDeviceDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_function_definition: Option<
+        Box<
+            dyn Fn(
+                    DeleteFunctionDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DeleteFunctionDefinitionResponse,
+                    RusotoError<DeleteFunctionDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteGroupRequest,
+                )
+                    -> ::std::result::Result<DeleteGroupResponse, RusotoError<DeleteGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_logger_definition: Option<
+        Box<
+            dyn Fn(
+                    DeleteLoggerDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DeleteLoggerDefinitionResponse,
+                    RusotoError<DeleteLoggerDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_resource_definition: Option<
+        Box<
+            dyn Fn(
+                    DeleteResourceDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DeleteResourceDefinitionResponse,
+                    RusotoError<DeleteResourceDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_subscription_definition: Option<
+        Box<
+            dyn Fn(
+                    DeleteSubscriptionDefinitionRequest,
+                ) -> ::std::result::Result<
+                    DeleteSubscriptionDefinitionResponse,
+                    RusotoError<DeleteSubscriptionDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_role_from_group: Option<
+        Box<
+            dyn Fn(
+                    DisassociateRoleFromGroupRequest,
+                ) -> ::std::result::Result<
+                    DisassociateRoleFromGroupResponse,
+                    RusotoError<DisassociateRoleFromGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_service_role_from_account: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DisassociateServiceRoleFromAccountResponse,
+                    RusotoError<DisassociateServiceRoleFromAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_associated_role: Option<
+        Box<
+            dyn Fn(
+                    GetAssociatedRoleRequest,
+                ) -> ::std::result::Result<
+                    GetAssociatedRoleResponse,
+                    RusotoError<GetAssociatedRoleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bulk_deployment_status: Option<
+        Box<
+            dyn Fn(
+                    GetBulkDeploymentStatusRequest,
+                ) -> ::std::result::Result<
+                    GetBulkDeploymentStatusResponse,
+                    RusotoError<GetBulkDeploymentStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_connectivity_info: Option<
+        Box<
+            dyn Fn(
+                    GetConnectivityInfoRequest,
+                ) -> ::std::result::Result<
+                    GetConnectivityInfoResponse,
+                    RusotoError<GetConnectivityInfoError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_connector_definition: Option<
+        Box<
+            dyn Fn(
+                    GetConnectorDefinitionRequest,
+                ) -> ::std::result::Result<
+                    GetConnectorDefinitionResponse,
+                    RusotoError<GetConnectorDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_connector_definition_version: Option<
+        Box<
+            dyn Fn(
+                    GetConnectorDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    GetConnectorDefinitionVersionResponse,
+                    RusotoError<GetConnectorDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_core_definition: Option<
+        Box<
+            dyn Fn(
+                    GetCoreDefinitionRequest,
+                ) -> ::std::result::Result<
+                    GetCoreDefinitionResponse,
+                    RusotoError<GetCoreDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_core_definition_version: Option<
+        Box<
+            dyn Fn(
+                    GetCoreDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    GetCoreDefinitionVersionResponse,
+                    RusotoError<GetCoreDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_deployment_status: Option<
+        Box<
+            dyn Fn(
+                    GetDeploymentStatusRequest,
+                ) -> ::std::result::Result<
+                    GetDeploymentStatusResponse,
+                    RusotoError<GetDeploymentStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_device_definition: Option<
+        Box<
+            dyn Fn(
+                    GetDeviceDefinitionRequest,
+                ) -> ::std::result::Result<
+                    GetDeviceDefinitionResponse,
+                    RusotoError<GetDeviceDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_device_definition_version: Option<
+        Box<
+            dyn Fn(
+                    GetDeviceDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    GetDeviceDefinitionVersionResponse,
+                    RusotoError<GetDeviceDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_function_definition: Option<
+        Box<
+            dyn Fn(
+                    GetFunctionDefinitionRequest,
+                ) -> ::std::result::Result<
+                    GetFunctionDefinitionResponse,
+                    RusotoError<GetFunctionDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_function_definition_version: Option<
+        Box<
+            dyn Fn(
+                    GetFunctionDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    GetFunctionDefinitionVersionResponse,
+                    RusotoError<GetFunctionDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_group: Option<
+        Box<
+            dyn Fn(
+                    GetGroupRequest,
+                )
+                    -> ::std::result::Result<GetGroupResponse, RusotoError<GetGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_group_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    GetGroupCertificateAuthorityRequest,
+                ) -> ::std::result::Result<
+                    GetGroupCertificateAuthorityResponse,
+                    RusotoError<GetGroupCertificateAuthorityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_group_certificate_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetGroupCertificateConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetGroupCertificateConfigurationResponse,
+                    RusotoError<GetGroupCertificateConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_group_version: Option<
+        Box<
+            dyn Fn(
+                    GetGroupVersionRequest,
+                ) -> ::std::result::Result<
+                    GetGroupVersionResponse,
+                    RusotoError<GetGroupVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_logger_definition: Option<
+        Box<
+            dyn Fn(
+                    GetLoggerDefinitionRequest,
+                ) -> ::std::result::Result<
+                    GetLoggerDefinitionResponse,
+                    RusotoError<GetLoggerDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_logger_definition_version: Option<
+        Box<
+            dyn Fn(
+                    GetLoggerDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    GetLoggerDefinitionVersionResponse,
+                    RusotoError<GetLoggerDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resource_definition: Option<
+        Box<
+            dyn Fn(
+                    GetResourceDefinitionRequest,
+                ) -> ::std::result::Result<
+                    GetResourceDefinitionResponse,
+                    RusotoError<GetResourceDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resource_definition_version: Option<
+        Box<
+            dyn Fn(
+                    GetResourceDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    GetResourceDefinitionVersionResponse,
+                    RusotoError<GetResourceDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_service_role_for_account: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetServiceRoleForAccountResponse,
+                    RusotoError<GetServiceRoleForAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_subscription_definition: Option<
+        Box<
+            dyn Fn(
+                    GetSubscriptionDefinitionRequest,
+                ) -> ::std::result::Result<
+                    GetSubscriptionDefinitionResponse,
+                    RusotoError<GetSubscriptionDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_subscription_definition_version: Option<
+        Box<
+            dyn Fn(
+                    GetSubscriptionDefinitionVersionRequest,
+                ) -> ::std::result::Result<
+                    GetSubscriptionDefinitionVersionResponse,
+                    RusotoError<GetSubscriptionDefinitionVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_bulk_deployment_detailed_reports: Option<
+        Box<
+            dyn Fn(
+                    ListBulkDeploymentDetailedReportsRequest,
+                ) -> ::std::result::Result<
+                    ListBulkDeploymentDetailedReportsResponse,
+                    RusotoError<ListBulkDeploymentDetailedReportsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_bulk_deployments: Option<
+        Box<
+            dyn Fn(
+                    ListBulkDeploymentsRequest,
+                ) -> ::std::result::Result<
+                    ListBulkDeploymentsResponse,
+                    RusotoError<ListBulkDeploymentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_connector_definition_versions: Option<
+        Box<
+            dyn Fn(
+                    ListConnectorDefinitionVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListConnectorDefinitionVersionsResponse,
+                    RusotoError<ListConnectorDefinitionVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_connector_definitions: Option<
+        Box<
+            dyn Fn(
+                    ListConnectorDefinitionsRequest,
+                ) -> ::std::result::Result<
+                    ListConnectorDefinitionsResponse,
+                    RusotoError<ListConnectorDefinitionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_core_definition_versions: Option<
+        Box<
+            dyn Fn(
+                    ListCoreDefinitionVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListCoreDefinitionVersionsResponse,
+                    RusotoError<ListCoreDefinitionVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_core_definitions: Option<
+        Box<
+            dyn Fn(
+                    ListCoreDefinitionsRequest,
+                ) -> ::std::result::Result<
+                    ListCoreDefinitionsResponse,
+                    RusotoError<ListCoreDefinitionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_deployments: Option<
+        Box<
+            dyn Fn(
+                    ListDeploymentsRequest,
+                ) -> ::std::result::Result<
+                    ListDeploymentsResponse,
+                    RusotoError<ListDeploymentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_device_definition_versions: Option<
+        Box<
+            dyn Fn(
+                    ListDeviceDefinitionVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListDeviceDefinitionVersionsResponse,
+                    RusotoError<ListDeviceDefinitionVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_device_definitions: Option<
+        Box<
+            dyn Fn(
+                    ListDeviceDefinitionsRequest,
+                ) -> ::std::result::Result<
+                    ListDeviceDefinitionsResponse,
+                    RusotoError<ListDeviceDefinitionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_function_definition_versions: Option<
+        Box<
+            dyn Fn(
+                    ListFunctionDefinitionVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListFunctionDefinitionVersionsResponse,
+                    RusotoError<ListFunctionDefinitionVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_function_definitions: Option<
+        Box<
+            dyn Fn(
+                    ListFunctionDefinitionsRequest,
+                ) -> ::std::result::Result<
+                    ListFunctionDefinitionsResponse,
+                    RusotoError<ListFunctionDefinitionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_group_certificate_authorities: Option<
+        Box<
+            dyn Fn(
+                    ListGroupCertificateAuthoritiesRequest,
+                ) -> ::std::result::Result<
+                    ListGroupCertificateAuthoritiesResponse,
+                    RusotoError<ListGroupCertificateAuthoritiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_group_versions: Option<
+        Box<
+            dyn Fn(
+                    ListGroupVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListGroupVersionsResponse,
+                    RusotoError<ListGroupVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_groups: Option<
+        Box<
+            dyn Fn(
+                    ListGroupsRequest,
+                )
+                    -> ::std::result::Result<ListGroupsResponse, RusotoError<ListGroupsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_logger_definition_versions: Option<
+        Box<
+            dyn Fn(
+                    ListLoggerDefinitionVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListLoggerDefinitionVersionsResponse,
+                    RusotoError<ListLoggerDefinitionVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_logger_definitions: Option<
+        Box<
+            dyn Fn(
+                    ListLoggerDefinitionsRequest,
+                ) -> ::std::result::Result<
+                    ListLoggerDefinitionsResponse,
+                    RusotoError<ListLoggerDefinitionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resource_definition_versions: Option<
+        Box<
+            dyn Fn(
+                    ListResourceDefinitionVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListResourceDefinitionVersionsResponse,
+                    RusotoError<ListResourceDefinitionVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resource_definitions: Option<
+        Box<
+            dyn Fn(
+                    ListResourceDefinitionsRequest,
+                ) -> ::std::result::Result<
+                    ListResourceDefinitionsResponse,
+                    RusotoError<ListResourceDefinitionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_subscription_definition_versions: Option<
+        Box<
+            dyn Fn(
+                    ListSubscriptionDefinitionVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListSubscriptionDefinitionVersionsResponse,
+                    RusotoError<ListSubscriptionDefinitionVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_subscription_definitions: Option<
+        Box<
+            dyn Fn(
+                    ListSubscriptionDefinitionsRequest,
+                ) -> ::std::result::Result<
+                    ListSubscriptionDefinitionsResponse,
+                    RusotoError<ListSubscriptionDefinitionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reset_deployments: Option<
+        Box<
+            dyn Fn(
+                    ResetDeploymentsRequest,
+                ) -> ::std::result::Result<
+                    ResetDeploymentsResponse,
+                    RusotoError<ResetDeploymentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_bulk_deployment: Option<
+        Box<
+            dyn Fn(
+                    StartBulkDeploymentRequest,
+                ) -> ::std::result::Result<
+                    StartBulkDeploymentResponse,
+                    RusotoError<StartBulkDeploymentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_bulk_deployment: Option<
+        Box<
+            dyn Fn(
+                    StopBulkDeploymentRequest,
+                ) -> ::std::result::Result<
+                    StopBulkDeploymentResponse,
+                    RusotoError<StopBulkDeploymentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_connectivity_info: Option<
+        Box<
+            dyn Fn(
+                    UpdateConnectivityInfoRequest,
+                ) -> ::std::result::Result<
+                    UpdateConnectivityInfoResponse,
+                    RusotoError<UpdateConnectivityInfoError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_connector_definition: Option<
+        Box<
+            dyn Fn(
+                    UpdateConnectorDefinitionRequest,
+                ) -> ::std::result::Result<
+                    UpdateConnectorDefinitionResponse,
+                    RusotoError<UpdateConnectorDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_core_definition: Option<
+        Box<
+            dyn Fn(
+                    UpdateCoreDefinitionRequest,
+                ) -> ::std::result::Result<
+                    UpdateCoreDefinitionResponse,
+                    RusotoError<UpdateCoreDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_device_definition: Option<
+        Box<
+            dyn Fn(
+                    UpdateDeviceDefinitionRequest,
+                ) -> ::std::result::Result<
+                    UpdateDeviceDefinitionResponse,
+                    RusotoError<UpdateDeviceDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_function_definition: Option<
+        Box<
+            dyn Fn(
+                    UpdateFunctionDefinitionRequest,
+                ) -> ::std::result::Result<
+                    UpdateFunctionDefinitionResponse,
+                    RusotoError<UpdateFunctionDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateGroupRequest,
+                )
+                    -> ::std::result::Result<UpdateGroupResponse, RusotoError<UpdateGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_group_certificate_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateGroupCertificateConfigurationRequest,
+                ) -> ::std::result::Result<
+                    UpdateGroupCertificateConfigurationResponse,
+                    RusotoError<UpdateGroupCertificateConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_logger_definition: Option<
+        Box<
+            dyn Fn(
+                    UpdateLoggerDefinitionRequest,
+                ) -> ::std::result::Result<
+                    UpdateLoggerDefinitionResponse,
+                    RusotoError<UpdateLoggerDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_resource_definition: Option<
+        Box<
+            dyn Fn(
+                    UpdateResourceDefinitionRequest,
+                ) -> ::std::result::Result<
+                    UpdateResourceDefinitionResponse,
+                    RusotoError<UpdateResourceDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_subscription_definition: Option<
+        Box<
+            dyn Fn(
+                    UpdateSubscriptionDefinitionRequest,
+                ) -> ::std::result::Result<
+                    UpdateSubscriptionDefinitionResponse,
+                    RusotoError<UpdateSubscriptionDefinitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockGreenGrass {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockGreenGrass {
+        Default::default()
+    }
+
+    /// Answers `associate_role_to_group` with what `handler` returns for its input.
+    pub fn with_associate_role_to_group<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                AssociateRoleToGroupRequest,
+            ) -> ::std::result::Result<
+                AssociateRoleToGroupResponse,
+                RusotoError<AssociateRoleToGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_role_to_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_service_role_to_account` with what `handler` returns for its input.
+    pub fn with_associate_service_role_to_account<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                AssociateServiceRoleToAccountRequest,
+            ) -> ::std::result::Result<
+                AssociateServiceRoleToAccountResponse,
+                RusotoError<AssociateServiceRoleToAccountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_service_role_to_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_connector_definition` with what `handler` returns for its input.
+    pub fn with_create_connector_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateConnectorDefinitionRequest,
+            ) -> ::std::result::Result<
+                CreateConnectorDefinitionResponse,
+                RusotoError<CreateConnectorDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_connector_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_connector_definition_version` with what `handler` returns for its input.
+    pub fn with_create_connector_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateConnectorDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                CreateConnectorDefinitionVersionResponse,
+                RusotoError<CreateConnectorDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_connector_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_core_definition` with what `handler` returns for its input.
+    pub fn with_create_core_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateCoreDefinitionRequest,
+            ) -> ::std::result::Result<
+                CreateCoreDefinitionResponse,
+                RusotoError<CreateCoreDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_core_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_core_definition_version` with what `handler` returns for its input.
+    pub fn with_create_core_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateCoreDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                CreateCoreDefinitionVersionResponse,
+                RusotoError<CreateCoreDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_core_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_deployment` with what `handler` returns for its input.
+    pub fn with_create_deployment<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateDeploymentRequest,
+            ) -> ::std::result::Result<
+                CreateDeploymentResponse,
+                RusotoError<CreateDeploymentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_device_definition` with what `handler` returns for its input.
+    pub fn with_create_device_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateDeviceDefinitionRequest,
+            ) -> ::std::result::Result<
+                CreateDeviceDefinitionResponse,
+                RusotoError<CreateDeviceDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_device_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_device_definition_version` with what `handler` returns for its input.
+    pub fn with_create_device_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateDeviceDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                CreateDeviceDefinitionVersionResponse,
+                RusotoError<CreateDeviceDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_device_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_function_definition` with what `handler` returns for its input.
+    pub fn with_create_function_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateFunctionDefinitionRequest,
+            ) -> ::std::result::Result<
+                CreateFunctionDefinitionResponse,
+                RusotoError<CreateFunctionDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_function_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_function_definition_version` with what `handler` returns for its input.
+    pub fn with_create_function_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateFunctionDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                CreateFunctionDefinitionVersionResponse,
+                RusotoError<CreateFunctionDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_function_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_group` with what `handler` returns for its input.
+    pub fn with_create_group<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateGroupRequest,
+            )
+                -> ::std::result::Result<CreateGroupResponse, RusotoError<CreateGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_group_certificate_authority` with what `handler` returns for its input.
+    pub fn with_create_group_certificate_authority<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateGroupCertificateAuthorityRequest,
+            ) -> ::std::result::Result<
+                CreateGroupCertificateAuthorityResponse,
+                RusotoError<CreateGroupCertificateAuthorityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_group_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_group_version` with what `handler` returns for its input.
+    pub fn with_create_group_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateGroupVersionRequest,
+            ) -> ::std::result::Result<
+                CreateGroupVersionResponse,
+                RusotoError<CreateGroupVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_group_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_logger_definition` with what `handler` returns for its input.
+    pub fn with_create_logger_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateLoggerDefinitionRequest,
+            ) -> ::std::result::Result<
+                CreateLoggerDefinitionResponse,
+                RusotoError<CreateLoggerDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_logger_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_logger_definition_version` with what `handler` returns for its input.
+    pub fn with_create_logger_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateLoggerDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                CreateLoggerDefinitionVersionResponse,
+                RusotoError<CreateLoggerDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_logger_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_resource_definition` with what `handler` returns for its input.
+    pub fn with_create_resource_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateResourceDefinitionRequest,
+            ) -> ::std::result::Result<
+                CreateResourceDefinitionResponse,
+                RusotoError<CreateResourceDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_resource_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_resource_definition_version` with what `handler` returns for its input.
+    pub fn with_create_resource_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateResourceDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                CreateResourceDefinitionVersionResponse,
+                RusotoError<CreateResourceDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_resource_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_software_update_job` with what `handler` returns for its input.
+    pub fn with_create_software_update_job<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateSoftwareUpdateJobRequest,
+            ) -> ::std::result::Result<
+                CreateSoftwareUpdateJobResponse,
+                RusotoError<CreateSoftwareUpdateJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_software_update_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_subscription_definition` with what `handler` returns for its input.
+    pub fn with_create_subscription_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateSubscriptionDefinitionRequest,
+            ) -> ::std::result::Result<
+                CreateSubscriptionDefinitionResponse,
+                RusotoError<CreateSubscriptionDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_subscription_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_subscription_definition_version` with what `handler` returns for its input.
+    pub fn with_create_subscription_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                CreateSubscriptionDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                CreateSubscriptionDefinitionVersionResponse,
+                RusotoError<CreateSubscriptionDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_subscription_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_connector_definition` with what `handler` returns for its input.
+    pub fn with_delete_connector_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                DeleteConnectorDefinitionRequest,
+            ) -> ::std::result::Result<
+                DeleteConnectorDefinitionResponse,
+                RusotoError<DeleteConnectorDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_connector_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_core_definition` with what `handler` returns for its input.
+    pub fn with_delete_core_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                DeleteCoreDefinitionRequest,
+            ) -> ::std::result::Result<
+                DeleteCoreDefinitionResponse,
+                RusotoError<DeleteCoreDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_core_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_device_definition` with what `handler` returns for its input.
+    pub fn with_delete_device_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                DeleteDeviceDefinitionRequest,
+            ) -> ::std::result::Result<
+                DeleteDeviceDefinitionResponse,
+                RusotoError<DeleteDeviceDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_device_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_function_definition` with what `handler` returns for its input.
+    pub fn with_delete_function_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                DeleteFunctionDefinitionRequest,
+            ) -> ::std::result::Result<
+                DeleteFunctionDefinitionResponse,
+                RusotoError<DeleteFunctionDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_function_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_group` with what `handler` returns for its input.
+    pub fn with_delete_group<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                DeleteGroupRequest,
+            )
+                -> ::std::result::Result<DeleteGroupResponse, RusotoError<DeleteGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_logger_definition` with what `handler` returns for its input.
+    pub fn with_delete_logger_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                DeleteLoggerDefinitionRequest,
+            ) -> ::std::result::Result<
+                DeleteLoggerDefinitionResponse,
+                RusotoError<DeleteLoggerDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_logger_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_resource_definition` with what `handler` returns for its input.
+    pub fn with_delete_resource_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                DeleteResourceDefinitionRequest,
+            ) -> ::std::result::Result<
+                DeleteResourceDefinitionResponse,
+                RusotoError<DeleteResourceDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_resource_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_subscription_definition` with what `handler` returns for its input.
+    pub fn with_delete_subscription_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                DeleteSubscriptionDefinitionRequest,
+            ) -> ::std::result::Result<
+                DeleteSubscriptionDefinitionResponse,
+                RusotoError<DeleteSubscriptionDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_subscription_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_role_from_group` with what `handler` returns for its input.
+    pub fn with_disassociate_role_from_group<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                DisassociateRoleFromGroupRequest,
+            ) -> ::std::result::Result<
+                DisassociateRoleFromGroupResponse,
+                RusotoError<DisassociateRoleFromGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_role_from_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_service_role_from_account` with what `handler` returns.
+    pub fn with_disassociate_service_role_from_account<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn() -> ::std::result::Result<
+                DisassociateServiceRoleFromAccountResponse,
+                RusotoError<DisassociateServiceRoleFromAccountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_service_role_from_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_associated_role` with what `handler` returns for its input.
+    pub fn with_get_associated_role<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetAssociatedRoleRequest,
+            ) -> ::std::result::Result<
+                GetAssociatedRoleResponse,
+                RusotoError<GetAssociatedRoleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_associated_role = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bulk_deployment_status` with what `handler` returns for its input.
+    pub fn with_get_bulk_deployment_status<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetBulkDeploymentStatusRequest,
+            ) -> ::std::result::Result<
+                GetBulkDeploymentStatusResponse,
+                RusotoError<GetBulkDeploymentStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bulk_deployment_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_connectivity_info` with what `handler` returns for its input.
+    pub fn with_get_connectivity_info<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetConnectivityInfoRequest,
+            ) -> ::std::result::Result<
+                GetConnectivityInfoResponse,
+                RusotoError<GetConnectivityInfoError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_connectivity_info = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_connector_definition` with what `handler` returns for its input.
+    pub fn with_get_connector_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetConnectorDefinitionRequest,
+            ) -> ::std::result::Result<
+                GetConnectorDefinitionResponse,
+                RusotoError<GetConnectorDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_connector_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_connector_definition_version` with what `handler` returns for its input.
+    pub fn with_get_connector_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetConnectorDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                GetConnectorDefinitionVersionResponse,
+                RusotoError<GetConnectorDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_connector_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_core_definition` with what `handler` returns for its input.
+    pub fn with_get_core_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetCoreDefinitionRequest,
+            ) -> ::std::result::Result<
+                GetCoreDefinitionResponse,
+                RusotoError<GetCoreDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_core_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_core_definition_version` with what `handler` returns for its input.
+    pub fn with_get_core_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetCoreDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                GetCoreDefinitionVersionResponse,
+                RusotoError<GetCoreDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_core_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployment_status` with what `handler` returns for its input.
+    pub fn with_get_deployment_status<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetDeploymentStatusRequest,
+            ) -> ::std::result::Result<
+                GetDeploymentStatusResponse,
+                RusotoError<GetDeploymentStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployment_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_device_definition` with what `handler` returns for its input.
+    pub fn with_get_device_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetDeviceDefinitionRequest,
+            ) -> ::std::result::Result<
+                GetDeviceDefinitionResponse,
+                RusotoError<GetDeviceDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_device_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_device_definition_version` with what `handler` returns for its input.
+    pub fn with_get_device_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetDeviceDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                GetDeviceDefinitionVersionResponse,
+                RusotoError<GetDeviceDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_device_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_function_definition` with what `handler` returns for its input.
+    pub fn with_get_function_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetFunctionDefinitionRequest,
+            ) -> ::std::result::Result<
+                GetFunctionDefinitionResponse,
+                RusotoError<GetFunctionDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_function_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_function_definition_version` with what `handler` returns for its input.
+    pub fn with_get_function_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetFunctionDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                GetFunctionDefinitionVersionResponse,
+                RusotoError<GetFunctionDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_function_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_group` with what `handler` returns for its input.
+    pub fn with_get_group<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetGroupRequest,
+            ) -> ::std::result::Result<GetGroupResponse, RusotoError<GetGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_group_certificate_authority` with what `handler` returns for its input.
+    pub fn with_get_group_certificate_authority<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetGroupCertificateAuthorityRequest,
+            ) -> ::std::result::Result<
+                GetGroupCertificateAuthorityResponse,
+                RusotoError<GetGroupCertificateAuthorityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_group_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_group_certificate_configuration` with what `handler` returns for its input.
+    pub fn with_get_group_certificate_configuration<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetGroupCertificateConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetGroupCertificateConfigurationResponse,
+                RusotoError<GetGroupCertificateConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_group_certificate_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_group_version` with what `handler` returns for its input.
+    pub fn with_get_group_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetGroupVersionRequest,
+            )
+                -> ::std::result::Result<GetGroupVersionResponse, RusotoError<GetGroupVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_group_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_logger_definition` with what `handler` returns for its input.
+    pub fn with_get_logger_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetLoggerDefinitionRequest,
+            ) -> ::std::result::Result<
+                GetLoggerDefinitionResponse,
+                RusotoError<GetLoggerDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_logger_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_logger_definition_version` with what `handler` returns for its input.
+    pub fn with_get_logger_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetLoggerDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                GetLoggerDefinitionVersionResponse,
+                RusotoError<GetLoggerDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_logger_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource_definition` with what `handler` returns for its input.
+    pub fn with_get_resource_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetResourceDefinitionRequest,
+            ) -> ::std::result::Result<
+                GetResourceDefinitionResponse,
+                RusotoError<GetResourceDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource_definition_version` with what `handler` returns for its input.
+    pub fn with_get_resource_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetResourceDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                GetResourceDefinitionVersionResponse,
+                RusotoError<GetResourceDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_service_role_for_account` with what `handler` returns.
+    pub fn with_get_service_role_for_account<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn() -> ::std::result::Result<
+                GetServiceRoleForAccountResponse,
+                RusotoError<GetServiceRoleForAccountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_service_role_for_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_subscription_definition` with what `handler` returns for its input.
+    pub fn with_get_subscription_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetSubscriptionDefinitionRequest,
+            ) -> ::std::result::Result<
+                GetSubscriptionDefinitionResponse,
+                RusotoError<GetSubscriptionDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_subscription_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_subscription_definition_version` with what `handler` returns for its input.
+    pub fn with_get_subscription_definition_version<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                GetSubscriptionDefinitionVersionRequest,
+            ) -> ::std::result::Result<
+                GetSubscriptionDefinitionVersionResponse,
+                RusotoError<GetSubscriptionDefinitionVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_subscription_definition_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_bulk_deployment_detailed_reports` with what `handler` returns for its input.
+    pub fn with_list_bulk_deployment_detailed_reports<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListBulkDeploymentDetailedReportsRequest,
+            ) -> ::std::result::Result<
+                ListBulkDeploymentDetailedReportsResponse,
+                RusotoError<ListBulkDeploymentDetailedReportsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_bulk_deployment_detailed_reports = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_bulk_deployments` with what `handler` returns for its input.
+    pub fn with_list_bulk_deployments<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListBulkDeploymentsRequest,
+            ) -> ::std::result::Result<
+                ListBulkDeploymentsResponse,
+                RusotoError<ListBulkDeploymentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_bulk_deployments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_connector_definition_versions` with what `handler` returns for its input.
+    pub fn with_list_connector_definition_versions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListConnectorDefinitionVersionsRequest,
+            ) -> ::std::result::Result<
+                ListConnectorDefinitionVersionsResponse,
+                RusotoError<ListConnectorDefinitionVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_connector_definition_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_connector_definitions` with what `handler` returns for its input.
+    pub fn with_list_connector_definitions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListConnectorDefinitionsRequest,
+            ) -> ::std::result::Result<
+                ListConnectorDefinitionsResponse,
+                RusotoError<ListConnectorDefinitionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_connector_definitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_core_definition_versions` with what `handler` returns for its input.
+    pub fn with_list_core_definition_versions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListCoreDefinitionVersionsRequest,
+            ) -> ::std::result::Result<
+                ListCoreDefinitionVersionsResponse,
+                RusotoError<ListCoreDefinitionVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_core_definition_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_core_definitions` with what `handler` returns for its input.
+    pub fn with_list_core_definitions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListCoreDefinitionsRequest,
+            ) -> ::std::result::Result<
+                ListCoreDefinitionsResponse,
+                RusotoError<ListCoreDefinitionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_core_definitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_deployments` with what `handler` returns for its input.
+    pub fn with_list_deployments<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListDeploymentsRequest,
+            )
+                -> ::std::result::Result<ListDeploymentsResponse, RusotoError<ListDeploymentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_deployments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_device_definition_versions` with what `handler` returns for its input.
+    pub fn with_list_device_definition_versions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListDeviceDefinitionVersionsRequest,
+            ) -> ::std::result::Result<
+                ListDeviceDefinitionVersionsResponse,
+                RusotoError<ListDeviceDefinitionVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_device_definition_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_device_definitions` with what `handler` returns for its input.
+    pub fn with_list_device_definitions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListDeviceDefinitionsRequest,
+            ) -> ::std::result::Result<
+                ListDeviceDefinitionsResponse,
+                RusotoError<ListDeviceDefinitionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_device_definitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_function_definition_versions` with what `handler` returns for its input.
+    pub fn with_list_function_definition_versions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListFunctionDefinitionVersionsRequest,
+            ) -> ::std::result::Result<
+                ListFunctionDefinitionVersionsResponse,
+                RusotoError<ListFunctionDefinitionVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_function_definition_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_function_definitions` with what `handler` returns for its input.
+    pub fn with_list_function_definitions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListFunctionDefinitionsRequest,
+            ) -> ::std::result::Result<
+                ListFunctionDefinitionsResponse,
+                RusotoError<ListFunctionDefinitionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_function_definitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_group_certificate_authorities` with what `handler` returns for its input.
+    pub fn with_list_group_certificate_authorities<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListGroupCertificateAuthoritiesRequest,
+            ) -> ::std::result::Result<
+                ListGroupCertificateAuthoritiesResponse,
+                RusotoError<ListGroupCertificateAuthoritiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_group_certificate_authorities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_group_versions` with what `handler` returns for its input.
+    pub fn with_list_group_versions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListGroupVersionsRequest,
+            ) -> ::std::result::Result<
+                ListGroupVersionsResponse,
+                RusotoError<ListGroupVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_group_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_groups` with what `handler` returns for its input.
+    pub fn with_list_groups<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListGroupsRequest,
+            )
+                -> ::std::result::Result<ListGroupsResponse, RusotoError<ListGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_logger_definition_versions` with what `handler` returns for its input.
+    pub fn with_list_logger_definition_versions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListLoggerDefinitionVersionsRequest,
+            ) -> ::std::result::Result<
+                ListLoggerDefinitionVersionsResponse,
+                RusotoError<ListLoggerDefinitionVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_logger_definition_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_logger_definitions` with what `handler` returns for its input.
+    pub fn with_list_logger_definitions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListLoggerDefinitionsRequest,
+            ) -> ::std::result::Result<
+                ListLoggerDefinitionsResponse,
+                RusotoError<ListLoggerDefinitionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_logger_definitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resource_definition_versions` with what `handler` returns for its input.
+    pub fn with_list_resource_definition_versions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListResourceDefinitionVersionsRequest,
+            ) -> ::std::result::Result<
+                ListResourceDefinitionVersionsResponse,
+                RusotoError<ListResourceDefinitionVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resource_definition_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resource_definitions` with what `handler` returns for its input.
+    pub fn with_list_resource_definitions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListResourceDefinitionsRequest,
+            ) -> ::std::result::Result<
+                ListResourceDefinitionsResponse,
+                RusotoError<ListResourceDefinitionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resource_definitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_subscription_definition_versions` with what `handler` returns for its input.
+    pub fn with_list_subscription_definition_versions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListSubscriptionDefinitionVersionsRequest,
+            ) -> ::std::result::Result<
+                ListSubscriptionDefinitionVersionsResponse,
+                RusotoError<ListSubscriptionDefinitionVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_subscription_definition_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_subscription_definitions` with what `handler` returns for its input.
+    pub fn with_list_subscription_definitions<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListSubscriptionDefinitionsRequest,
+            ) -> ::std::result::Result<
+                ListSubscriptionDefinitionsResponse,
+                RusotoError<ListSubscriptionDefinitionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_subscription_definitions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reset_deployments` with what `handler` returns for its input.
+    pub fn with_reset_deployments<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                ResetDeploymentsRequest,
+            ) -> ::std::result::Result<
+                ResetDeploymentsResponse,
+                RusotoError<ResetDeploymentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reset_deployments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_bulk_deployment` with what `handler` returns for its input.
+    pub fn with_start_bulk_deployment<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                StartBulkDeploymentRequest,
+            ) -> ::std::result::Result<
+                StartBulkDeploymentResponse,
+                RusotoError<StartBulkDeploymentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_bulk_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_bulk_deployment` with what `handler` returns for its input.
+    pub fn with_stop_bulk_deployment<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                StopBulkDeploymentRequest,
+            ) -> ::std::result::Result<
+                StopBulkDeploymentResponse,
+                RusotoError<StopBulkDeploymentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_bulk_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_connectivity_info` with what `handler` returns for its input.
+    pub fn with_update_connectivity_info<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                UpdateConnectivityInfoRequest,
+            ) -> ::std::result::Result<
+                UpdateConnectivityInfoResponse,
+                RusotoError<UpdateConnectivityInfoError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_connectivity_info = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_connector_definition` with what `handler` returns for its input.
+    pub fn with_update_connector_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                UpdateConnectorDefinitionRequest,
+            ) -> ::std::result::Result<
+                UpdateConnectorDefinitionResponse,
+                RusotoError<UpdateConnectorDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_connector_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_core_definition` with what `handler` returns for its input.
+    pub fn with_update_core_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                UpdateCoreDefinitionRequest,
+            ) -> ::std::result::Result<
+                UpdateCoreDefinitionResponse,
+                RusotoError<UpdateCoreDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_core_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_device_definition` with what `handler` returns for its input.
+    pub fn with_update_device_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                UpdateDeviceDefinitionRequest,
+            ) -> ::std::result::Result<
+                UpdateDeviceDefinitionResponse,
+                RusotoError<UpdateDeviceDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_device_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_function_definition` with what `handler` returns for its input.
+    pub fn with_update_function_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                UpdateFunctionDefinitionRequest,
+            ) -> ::std::result::Result<
+                UpdateFunctionDefinitionResponse,
+                RusotoError<UpdateFunctionDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_function_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_group` with what `handler` returns for its input.
+    pub fn with_update_group<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                UpdateGroupRequest,
+            )
+                -> ::std::result::Result<UpdateGroupResponse, RusotoError<UpdateGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_group_certificate_configuration` with what `handler` returns for its input.
+    pub fn with_update_group_certificate_configuration<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                UpdateGroupCertificateConfigurationRequest,
+            ) -> ::std::result::Result<
+                UpdateGroupCertificateConfigurationResponse,
+                RusotoError<UpdateGroupCertificateConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_group_certificate_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_logger_definition` with what `handler` returns for its input.
+    pub fn with_update_logger_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                UpdateLoggerDefinitionRequest,
+            ) -> ::std::result::Result<
+                UpdateLoggerDefinitionResponse,
+                RusotoError<UpdateLoggerDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_logger_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_resource_definition` with what `handler` returns for its input.
+    pub fn with_update_resource_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                UpdateResourceDefinitionRequest,
+            ) -> ::std::result::Result<
+                UpdateResourceDefinitionResponse,
+                RusotoError<UpdateResourceDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_resource_definition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_subscription_definition` with what `handler` returns for its input.
+    pub fn with_update_subscription_definition<F>(mut self, handler: F) -> MockGreenGrass
+    where
+        F: Fn(
+                UpdateSubscriptionDefinitionRequest,
+            ) -> ::std::result::Result<
+                UpdateSubscriptionDefinitionResponse,
+                RusotoError<UpdateSubscriptionDefinitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_subscription_definition = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl GreenGrass for MockGreenGrass {
+    fn associate_role_to_group(
+        &self,
+        input: AssociateRoleToGroupRequest,
+    ) -> RusotoFuture<AssociateRoleToGroupResponse, AssociateRoleToGroupError> {
+        match self.associate_role_to_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for associate_role_to_group"),
+        }
+    }
+    fn associate_service_role_to_account(
+        &self,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> RusotoFuture<AssociateServiceRoleToAccountResponse, AssociateServiceRoleToAccountError>
+    {
+        match self.associate_service_role_to_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for associate_service_role_to_account"),
+        }
+    }
+    fn create_connector_definition(
+        &self,
+        input: CreateConnectorDefinitionRequest,
+    ) -> RusotoFuture<CreateConnectorDefinitionResponse, CreateConnectorDefinitionError> {
+        match self.create_connector_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_connector_definition"),
+        }
+    }
+    fn create_connector_definition_version(
+        &self,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateConnectorDefinitionVersionResponse, CreateConnectorDefinitionVersionError>
+    {
+        match self.create_connector_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_connector_definition_version"),
+        }
+    }
+    fn create_core_definition(
+        &self,
+        input: CreateCoreDefinitionRequest,
+    ) -> RusotoFuture<CreateCoreDefinitionResponse, CreateCoreDefinitionError> {
+        match self.create_core_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_core_definition"),
+        }
+    }
+    fn create_core_definition_version(
+        &self,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateCoreDefinitionVersionResponse, CreateCoreDefinitionVersionError> {
+        match self.create_core_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_core_definition_version"),
+        }
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
+        match self.create_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_deployment"),
+        }
+    }
+    fn create_device_definition(
+        &self,
+        input: CreateDeviceDefinitionRequest,
+    ) -> RusotoFuture<CreateDeviceDefinitionResponse, CreateDeviceDefinitionError> {
+        match self.create_device_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_device_definition"),
+        }
+    }
+    fn create_device_definition_version(
+        &self,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateDeviceDefinitionVersionResponse, CreateDeviceDefinitionVersionError>
+    {
+        match self.create_device_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_device_definition_version"),
+        }
+    }
+    fn create_function_definition(
+        &self,
+        input: CreateFunctionDefinitionRequest,
+    ) -> RusotoFuture<CreateFunctionDefinitionResponse, CreateFunctionDefinitionError> {
+        match self.create_function_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_function_definition"),
+        }
+    }
+    fn create_function_definition_version(
+        &self,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateFunctionDefinitionVersionResponse, CreateFunctionDefinitionVersionError>
+    {
+        match self.create_function_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_function_definition_version"),
+        }
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        match self.create_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_group"),
+        }
+    }
+    fn create_group_certificate_authority(
+        &self,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> RusotoFuture<CreateGroupCertificateAuthorityResponse, CreateGroupCertificateAuthorityError>
+    {
+        match self.create_group_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_group_certificate_authority"),
+        }
+    }
+    fn create_group_version(
+        &self,
+        input: CreateGroupVersionRequest,
+    ) -> RusotoFuture<CreateGroupVersionResponse, CreateGroupVersionError> {
+        match self.create_group_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_group_version"),
+        }
+    }
+    fn create_logger_definition(
+        &self,
+        input: CreateLoggerDefinitionRequest,
+    ) -> RusotoFuture<CreateLoggerDefinitionResponse, CreateLoggerDefinitionError> {
+        match self.create_logger_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_logger_definition"),
+        }
+    }
+    fn create_logger_definition_version(
+        &self,
+        input: CreateLoggerDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateLoggerDefinitionVersionResponse, CreateLoggerDefinitionVersionError>
+    {
+        match self.create_logger_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_logger_definition_version"),
+        }
+    }
+    fn create_resource_definition(
+        &self,
+        input: CreateResourceDefinitionRequest,
+    ) -> RusotoFuture<CreateResourceDefinitionResponse, CreateResourceDefinitionError> {
+        match self.create_resource_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_resource_definition"),
+        }
+    }
+    fn create_resource_definition_version(
+        &self,
+        input: CreateResourceDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateResourceDefinitionVersionResponse, CreateResourceDefinitionVersionError>
+    {
+        match self.create_resource_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_resource_definition_version"),
+        }
+    }
+    fn create_software_update_job(
+        &self,
+        input: CreateSoftwareUpdateJobRequest,
+    ) -> RusotoFuture<CreateSoftwareUpdateJobResponse, CreateSoftwareUpdateJobError> {
+        match self.create_software_update_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_software_update_job"),
+        }
+    }
+    fn create_subscription_definition(
+        &self,
+        input: CreateSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<CreateSubscriptionDefinitionResponse, CreateSubscriptionDefinitionError> {
+        match self.create_subscription_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for create_subscription_definition"),
+        }
+    }
+    fn create_subscription_definition_version(
+        &self,
+        input: CreateSubscriptionDefinitionVersionRequest,
+    ) -> RusotoFuture<
+        CreateSubscriptionDefinitionVersionResponse,
+        CreateSubscriptionDefinitionVersionError,
+    > {
+        match self.create_subscription_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockGreenGrass has no handler for create_subscription_definition_version")
+            }
+        }
+    }
+    fn delete_connector_definition(
+        &self,
+        input: DeleteConnectorDefinitionRequest,
+    ) -> RusotoFuture<DeleteConnectorDefinitionResponse, DeleteConnectorDefinitionError> {
+        match self.delete_connector_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for delete_connector_definition"),
+        }
+    }
+    fn delete_core_definition(
+        &self,
+        input: DeleteCoreDefinitionRequest,
+    ) -> RusotoFuture<DeleteCoreDefinitionResponse, DeleteCoreDefinitionError> {
+        match self.delete_core_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for delete_core_definition"),
+        }
+    }
+    fn delete_device_definition(
+        &self,
+        input: DeleteDeviceDefinitionRequest,
+    ) -> RusotoFuture<DeleteDeviceDefinitionResponse, DeleteDeviceDefinitionError> {
+        match self.delete_device_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for delete_device_definition"),
+        }
+    }
+    fn delete_function_definition(
+        &self,
+        input: DeleteFunctionDefinitionRequest,
+    ) -> RusotoFuture<DeleteFunctionDefinitionResponse, DeleteFunctionDefinitionError> {
+        match self.delete_function_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for delete_function_definition"),
+        }
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
+        match self.delete_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for delete_group"),
+        }
+    }
+    fn delete_logger_definition(
+        &self,
+        input: DeleteLoggerDefinitionRequest,
+    ) -> RusotoFuture<DeleteLoggerDefinitionResponse, DeleteLoggerDefinitionError> {
+        match self.delete_logger_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for delete_logger_definition"),
+        }
+    }
+    fn delete_resource_definition(
+        &self,
+        input: DeleteResourceDefinitionRequest,
+    ) -> RusotoFuture<DeleteResourceDefinitionResponse, DeleteResourceDefinitionError> {
+        match self.delete_resource_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for delete_resource_definition"),
+        }
+    }
+    fn delete_subscription_definition(
+        &self,
+        input: DeleteSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<DeleteSubscriptionDefinitionResponse, DeleteSubscriptionDefinitionError> {
+        match self.delete_subscription_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for delete_subscription_definition"),
+        }
+    }
+    fn disassociate_role_from_group(
+        &self,
+        input: DisassociateRoleFromGroupRequest,
+    ) -> RusotoFuture<DisassociateRoleFromGroupResponse, DisassociateRoleFromGroupError> {
+        match self.disassociate_role_from_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for disassociate_role_from_group"),
+        }
+    }
+    fn disassociate_service_role_from_account(
+        &self,
+    ) -> RusotoFuture<
+        DisassociateServiceRoleFromAccountResponse,
+        DisassociateServiceRoleFromAccountError,
+    > {
+        match self.disassociate_service_role_from_account {
+            Some(ref handler) => handler().into(),
+            None => {
+                panic!("MockGreenGrass has no handler for disassociate_service_role_from_account")
+            }
+        }
+    }
+    fn get_associated_role(
+        &self,
+        input: GetAssociatedRoleRequest,
+    ) -> RusotoFuture<GetAssociatedRoleResponse, GetAssociatedRoleError> {
+        match self.get_associated_role {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_associated_role"),
+        }
+    }
+    fn get_bulk_deployment_status(
+        &self,
+        input: GetBulkDeploymentStatusRequest,
+    ) -> RusotoFuture<GetBulkDeploymentStatusResponse, GetBulkDeploymentStatusError> {
+        match self.get_bulk_deployment_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_bulk_deployment_status"),
+        }
+    }
+    fn get_connectivity_info(
+        &self,
+        input: GetConnectivityInfoRequest,
+    ) -> RusotoFuture<GetConnectivityInfoResponse, GetConnectivityInfoError> {
+        match self.get_connectivity_info {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_connectivity_info"),
+        }
+    }
+    fn get_connector_definition(
+        &self,
+        input: GetConnectorDefinitionRequest,
+    ) -> RusotoFuture<GetConnectorDefinitionResponse, GetConnectorDefinitionError> {
+        match self.get_connector_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_connector_definition"),
+        }
+    }
+    fn get_connector_definition_version(
+        &self,
+        input: GetConnectorDefinitionVersionRequest,
+    ) -> RusotoFuture<GetConnectorDefinitionVersionResponse, GetConnectorDefinitionVersionError>
+    {
+        match self.get_connector_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_connector_definition_version"),
+        }
+    }
+    fn get_core_definition(
+        &self,
+        input: GetCoreDefinitionRequest,
+    ) -> RusotoFuture<GetCoreDefinitionResponse, GetCoreDefinitionError> {
+        match self.get_core_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_core_definition"),
+        }
+    }
+    fn get_core_definition_version(
+        &self,
+        input: GetCoreDefinitionVersionRequest,
+    ) -> RusotoFuture<GetCoreDefinitionVersionResponse, GetCoreDefinitionVersionError> {
+        match self.get_core_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_core_definition_version"),
+        }
+    }
+    fn get_deployment_status(
+        &self,
+        input: GetDeploymentStatusRequest,
+    ) -> RusotoFuture<GetDeploymentStatusResponse, GetDeploymentStatusError> {
+        match self.get_deployment_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_deployment_status"),
+        }
+    }
+    fn get_device_definition(
+        &self,
+        input: GetDeviceDefinitionRequest,
+    ) -> RusotoFuture<GetDeviceDefinitionResponse, GetDeviceDefinitionError> {
+        match self.get_device_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_device_definition"),
+        }
+    }
+    fn get_device_definition_version(
+        &self,
+        input: GetDeviceDefinitionVersionRequest,
+    ) -> RusotoFuture<GetDeviceDefinitionVersionResponse, GetDeviceDefinitionVersionError> {
+        match self.get_device_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_device_definition_version"),
+        }
+    }
+    fn get_function_definition(
+        &self,
+        input: GetFunctionDefinitionRequest,
+    ) -> RusotoFuture<GetFunctionDefinitionResponse, GetFunctionDefinitionError> {
+        match self.get_function_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_function_definition"),
+        }
+    }
+    fn get_function_definition_version(
+        &self,
+        input: GetFunctionDefinitionVersionRequest,
+    ) -> RusotoFuture<GetFunctionDefinitionVersionResponse, GetFunctionDefinitionVersionError> {
+        match self.get_function_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_function_definition_version"),
+        }
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        match self.get_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_group"),
+        }
+    }
+    fn get_group_certificate_authority(
+        &self,
+        input: GetGroupCertificateAuthorityRequest,
+    ) -> RusotoFuture<GetGroupCertificateAuthorityResponse, GetGroupCertificateAuthorityError> {
+        match self.get_group_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_group_certificate_authority"),
+        }
+    }
+    fn get_group_certificate_configuration(
+        &self,
+        input: GetGroupCertificateConfigurationRequest,
+    ) -> RusotoFuture<GetGroupCertificateConfigurationResponse, GetGroupCertificateConfigurationError>
+    {
+        match self.get_group_certificate_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_group_certificate_configuration"),
+        }
+    }
+    fn get_group_version(
+        &self,
+        input: GetGroupVersionRequest,
+    ) -> RusotoFuture<GetGroupVersionResponse, GetGroupVersionError> {
+        match self.get_group_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_group_version"),
+        }
+    }
+    fn get_logger_definition(
+        &self,
+        input: GetLoggerDefinitionRequest,
+    ) -> RusotoFuture<GetLoggerDefinitionResponse, GetLoggerDefinitionError> {
+        match self.get_logger_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_logger_definition"),
+        }
+    }
+    fn get_logger_definition_version(
+        &self,
+        input: GetLoggerDefinitionVersionRequest,
+    ) -> RusotoFuture<GetLoggerDefinitionVersionResponse, GetLoggerDefinitionVersionError> {
+        match self.get_logger_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_logger_definition_version"),
+        }
+    }
+    fn get_resource_definition(
+        &self,
+        input: GetResourceDefinitionRequest,
+    ) -> RusotoFuture<GetResourceDefinitionResponse, GetResourceDefinitionError> {
+        match self.get_resource_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_resource_definition"),
+        }
+    }
+    fn get_resource_definition_version(
+        &self,
+        input: GetResourceDefinitionVersionRequest,
+    ) -> RusotoFuture<GetResourceDefinitionVersionResponse, GetResourceDefinitionVersionError> {
+        match self.get_resource_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_resource_definition_version"),
+        }
+    }
+    fn get_service_role_for_account(
+        &self,
+    ) -> RusotoFuture<GetServiceRoleForAccountResponse, GetServiceRoleForAccountError> {
+        match self.get_service_role_for_account {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockGreenGrass has no handler for get_service_role_for_account"),
+        }
+    }
+    fn get_subscription_definition(
+        &self,
+        input: GetSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<GetSubscriptionDefinitionResponse, GetSubscriptionDefinitionError> {
+        match self.get_subscription_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_subscription_definition"),
+        }
+    }
+    fn get_subscription_definition_version(
+        &self,
+        input: GetSubscriptionDefinitionVersionRequest,
+    ) -> RusotoFuture<GetSubscriptionDefinitionVersionResponse, GetSubscriptionDefinitionVersionError>
+    {
+        match self.get_subscription_definition_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for get_subscription_definition_version"),
+        }
+    }
+    fn list_bulk_deployment_detailed_reports(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> RusotoFuture<
+        ListBulkDeploymentDetailedReportsResponse,
+        ListBulkDeploymentDetailedReportsError,
+    > {
+        match self.list_bulk_deployment_detailed_reports {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockGreenGrass has no handler for list_bulk_deployment_detailed_reports")
+            }
+        }
+    }
+    fn list_bulk_deployments(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> RusotoFuture<ListBulkDeploymentsResponse, ListBulkDeploymentsError> {
+        match self.list_bulk_deployments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_bulk_deployments"),
+        }
+    }
+    fn list_connector_definition_versions(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListConnectorDefinitionVersionsResponse, ListConnectorDefinitionVersionsError>
+    {
+        match self.list_connector_definition_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_connector_definition_versions"),
+        }
+    }
+    fn list_connector_definitions(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> RusotoFuture<ListConnectorDefinitionsResponse, ListConnectorDefinitionsError> {
+        match self.list_connector_definitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_connector_definitions"),
+        }
+    }
+    fn list_core_definition_versions(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListCoreDefinitionVersionsResponse, ListCoreDefinitionVersionsError> {
+        match self.list_core_definition_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_core_definition_versions"),
+        }
+    }
+    fn list_core_definitions(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> RusotoFuture<ListCoreDefinitionsResponse, ListCoreDefinitionsError> {
+        match self.list_core_definitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_core_definitions"),
+        }
+    }
+    fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> RusotoFuture<ListDeploymentsResponse, ListDeploymentsError> {
+        match self.list_deployments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_deployments"),
+        }
+    }
+    fn list_device_definition_versions(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListDeviceDefinitionVersionsResponse, ListDeviceDefinitionVersionsError> {
+        match self.list_device_definition_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_device_definition_versions"),
+        }
+    }
+    fn list_device_definitions(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> RusotoFuture<ListDeviceDefinitionsResponse, ListDeviceDefinitionsError> {
+        match self.list_device_definitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_device_definitions"),
+        }
+    }
+    fn list_function_definition_versions(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListFunctionDefinitionVersionsResponse, ListFunctionDefinitionVersionsError>
+    {
+        match self.list_function_definition_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_function_definition_versions"),
+        }
+    }
+    fn list_function_definitions(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> RusotoFuture<ListFunctionDefinitionsResponse, ListFunctionDefinitionsError> {
+        match self.list_function_definitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_function_definitions"),
+        }
+    }
+    fn list_group_certificate_authorities(
+        &self,
+        input: ListGroupCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<ListGroupCertificateAuthoritiesResponse, ListGroupCertificateAuthoritiesError>
+    {
+        match self.list_group_certificate_authorities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_group_certificate_authorities"),
+        }
+    }
+    fn list_group_versions(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> RusotoFuture<ListGroupVersionsResponse, ListGroupVersionsError> {
+        match self.list_group_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_group_versions"),
+        }
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        match self.list_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_groups"),
+        }
+    }
+    fn list_logger_definition_versions(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListLoggerDefinitionVersionsResponse, ListLoggerDefinitionVersionsError> {
+        match self.list_logger_definition_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_logger_definition_versions"),
+        }
+    }
+    fn list_logger_definitions(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> RusotoFuture<ListLoggerDefinitionsResponse, ListLoggerDefinitionsError> {
+        match self.list_logger_definitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_logger_definitions"),
+        }
+    }
+    fn list_resource_definition_versions(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListResourceDefinitionVersionsResponse, ListResourceDefinitionVersionsError>
+    {
+        match self.list_resource_definition_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_resource_definition_versions"),
+        }
+    }
+    fn list_resource_definitions(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> RusotoFuture<ListResourceDefinitionsResponse, ListResourceDefinitionsError> {
+        match self.list_resource_definitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_resource_definitions"),
+        }
+    }
+    fn list_subscription_definition_versions(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> RusotoFuture<
+        ListSubscriptionDefinitionVersionsResponse,
+        ListSubscriptionDefinitionVersionsError,
+    > {
+        match self.list_subscription_definition_versions {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockGreenGrass has no handler for list_subscription_definition_versions")
+            }
+        }
+    }
+    fn list_subscription_definitions(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> RusotoFuture<ListSubscriptionDefinitionsResponse, ListSubscriptionDefinitionsError> {
+        match self.list_subscription_definitions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_subscription_definitions"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for list_tags_for_resource"),
+        }
+    }
+    fn reset_deployments(
+        &self,
+        input: ResetDeploymentsRequest,
+    ) -> RusotoFuture<ResetDeploymentsResponse, ResetDeploymentsError> {
+        match self.reset_deployments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for reset_deployments"),
+        }
+    }
+    fn start_bulk_deployment(
+        &self,
+        input: StartBulkDeploymentRequest,
+    ) -> RusotoFuture<StartBulkDeploymentResponse, StartBulkDeploymentError> {
+        match self.start_bulk_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for start_bulk_deployment"),
+        }
+    }
+    fn stop_bulk_deployment(
+        &self,
+        input: StopBulkDeploymentRequest,
+    ) -> RusotoFuture<StopBulkDeploymentResponse, StopBulkDeploymentError> {
+        match self.stop_bulk_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for stop_bulk_deployment"),
+        }
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for untag_resource"),
+        }
+    }
+    fn update_connectivity_info(
+        &self,
+        input: UpdateConnectivityInfoRequest,
+    ) -> RusotoFuture<UpdateConnectivityInfoResponse, UpdateConnectivityInfoError> {
+        match self.update_connectivity_info {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for update_connectivity_info"),
+        }
+    }
+    fn update_connector_definition(
+        &self,
+        input: UpdateConnectorDefinitionRequest,
+    ) -> RusotoFuture<UpdateConnectorDefinitionResponse, UpdateConnectorDefinitionError> {
+        match self.update_connector_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for update_connector_definition"),
+        }
     }
-
-    /// <p>Updates a core definition.</p>
     fn update_core_definition(
         &self,
         input: UpdateCoreDefinitionRequest,
     ) -> RusotoFuture<UpdateCoreDefinitionResponse, UpdateCoreDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/cores/{core_definition_id}",
-            core_definition_id = input.core_definition_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateCoreDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateCoreDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_core_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for update_core_definition"),
+        }
     }
-
-    /// <p>Updates a device definition.</p>
     fn update_device_definition(
         &self,
         input: UpdateDeviceDefinitionRequest,
     ) -> RusotoFuture<UpdateDeviceDefinitionResponse, UpdateDeviceDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/devices/{device_definition_id}",
-            device_definition_id = input.device_definition_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDeviceDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateDeviceDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_device_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for update_device_definition"),
+        }
     }
-
-    /// <p>Updates a Lambda function definition.</p>
     fn update_function_definition(
         &self,
         input: UpdateFunctionDefinitionRequest,
     ) -> RusotoFuture<UpdateFunctionDefinitionResponse, UpdateFunctionDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/functions/{function_definition_id}",
-            function_definition_id = input.function_definition_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFunctionDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateFunctionDefinitionError::from_response(response))
-                }))
-            }
-        })
+        match self.update_function_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for update_function_definition"),
+        }
     }
-
-    /// <p>Updates a group.</p>
     fn update_group(
         &self,
         input: UpdateGroupRequest,
     ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
-        let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGroupResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateGroupError::from_response(response))),
-                )
-            }
-        })
+        match self.update_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for update_group"),
+        }
     }
-
-    /// <p>Updates the Certificate expiry time for a group.</p>
     fn update_group_certificate_configuration(
         &self,
         input: UpdateGroupCertificateConfigurationRequest,
@@ -9969,130 +15906,38 @@ impl GreenGrass for GreenGrassClient {
         UpdateGroupCertificateConfigurationResponse,
         UpdateGroupCertificateConfigurationError,
     > {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/certificateauthorities/configuration/expiry",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGroupCertificateConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateGroupCertificateConfigurationError::from_response(
-                        response,
-                    ))
-                }))
+        match self.update_group_certificate_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockGreenGrass has no handler for update_group_certificate_configuration")
             }
-        })
+        }
     }
-
-    /// <p>Updates a logger definition.</p>
     fn update_logger_definition(
         &self,
         input: UpdateLoggerDefinitionRequest,
     ) -> RusotoFuture<UpdateLoggerDefinitionResponse, UpdateLoggerDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/loggers/{logger_definition_id}",
-            logger_definition_id = input.logger_definition_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateLoggerDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateLoggerDefinitionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_logger_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for update_logger_definition"),
+        }
     }
-
-    /// <p>Updates a resource definition.</p>
     fn update_resource_definition(
         &self,
         input: UpdateResourceDefinitionRequest,
     ) -> RusotoFuture<UpdateResourceDefinitionResponse, UpdateResourceDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/resources/{resource_definition_id}",
-            resource_definition_id = input.resource_definition_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateResourceDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateResourceDefinitionError::from_response(response))
-                }))
-            }
-        })
+        match self.update_resource_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for update_resource_definition"),
+        }
     }
-
-    /// <p>Updates a subscription definition.</p>
     fn update_subscription_definition(
         &self,
         input: UpdateSubscriptionDefinitionRequest,
     ) -> RusotoFuture<UpdateSubscriptionDefinitionResponse, UpdateSubscriptionDefinitionError> {
-        let request_uri = format!(
-            "/greengrass/definition/subscriptions/{subscription_definition_id}",
-            subscription_definition_id = input.subscription_definition_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSubscriptionDefinitionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateSubscriptionDefinitionError::from_response(response))
-                }))
-            }
-        })
+        match self.update_subscription_definition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGreenGrass has no handler for update_subscription_definition"),
+        }
     }
 }
@@ -23,8 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptInvitationRequest {
     /// <p>The unique ID of the detector of the GuardDuty member account.</p>
     #[serde(rename = "DetectorId")]
@@ -37,13 +39,24 @@ pub struct AcceptInvitationRequest {
     pub master_id: String,
 }
 
+impl Validate for AcceptInvitationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcceptInvitationResponse {}
 
 /// <p>Contains information about the access keys.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AccessKeyDetails {
     /// <p>Access key ID of the user.</p>
     #[serde(rename = "AccessKeyId")]
@@ -65,6 +78,7 @@ pub struct AccessKeyDetails {
 
 /// <p>Contains information about the account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccountDetail {
     /// <p>Member account ID.</p>
     #[serde(rename = "AccountId")]
@@ -74,9 +88,26 @@ pub struct AccountDetail {
     pub email: String,
 }
 
+impl Validate for AccountDetail {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            Some(12),
+            Some(12),
+        );
+        errors.check_length(
+            &field_path(path, "email"),
+            self.email.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// <p>Contains information about action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Action {
     /// <p>GuardDuty Finding activity type.</p>
     #[serde(rename = "ActionType")]
@@ -101,6 +132,7 @@ pub struct Action {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ArchiveFindingsRequest {
     /// <p>The ID of the detector that specifies the GuardDuty service whose findings you want to archive.</p>
     #[serde(rename = "DetectorId")]
@@ -110,13 +142,34 @@ pub struct ArchiveFindingsRequest {
     pub finding_ids: Vec<String>,
 }
 
+impl Validate for ArchiveFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "finding_ids"),
+            self.finding_ids.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.finding_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "finding_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ArchiveFindingsResponse {}
 
 /// <p>Contains information about the API operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AwsApiCallAction {
     /// <p>AWS API name.</p>
     #[serde(rename = "Api")]
@@ -142,7 +195,7 @@ pub struct AwsApiCallAction {
 
 /// <p>Contains information about the city associated with the IP address.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct City {
     /// <p>City name of the remote IP address.</p>
     #[serde(rename = "CityName")]
@@ -181,7 +234,7 @@ pub struct Condition {
 
 /// <p>Contains information about the country.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Country {
     /// <p>Country code of the remote IP address.</p>
     #[serde(rename = "CountryCode")]
@@ -194,6 +247,7 @@ pub struct Country {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDetectorRequest {
     /// <p>The idempotency token for the create request.</p>
     #[serde(rename = "ClientToken")]
@@ -212,8 +266,28 @@ pub struct CreateDetectorRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateDetectorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(200));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDetectorResponse {
     /// <p>The unique ID of the created detector.</p>
     #[serde(rename = "DetectorId")]
@@ -222,6 +296,7 @@ pub struct CreateDetectorResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFilterRequest {
     /// <p>Specifies the action that is to be applied to the findings that match the filter.</p>
     #[serde(rename = "Action")]
@@ -254,8 +329,59 @@ pub struct CreateFilterRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateFilterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.action {
+            errors.check_length(
+                &field_path(path, "action"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(512),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(3),
+            Some(64),
+        );
+        if let Some(ref value) = self.rank {
+            errors.check_range(&field_path(path, "rank"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(200));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFilterResponse {
     /// <p>The name of the successfully created filter.</p>
     #[serde(rename = "Name")]
@@ -263,6 +389,7 @@ pub struct CreateFilterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateIPSetRequest {
     /// <p>A boolean value that indicates whether GuardDuty is to start using the uploaded IPSet.</p>
     #[serde(rename = "Activate")]
@@ -289,8 +416,52 @@ pub struct CreateIPSetRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateIPSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "format"),
+            self.format.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "location"),
+            self.location.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(200));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateIPSetResponse {
     /// <p>The ID of the IPSet resource.</p>
     #[serde(rename = "IpSetId")]
@@ -298,6 +469,7 @@ pub struct CreateIPSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMembersRequest {
     /// <p>A list of account ID and email address pairs of the accounts that you want to associate with the master GuardDuty account.</p>
     #[serde(rename = "AccountDetails")]
@@ -307,8 +479,29 @@ pub struct CreateMembersRequest {
     pub detector_id: String,
 }
 
+impl Validate for CreateMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_details"),
+            self.account_details.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.account_details.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_details"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateMembersResponse {
     /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -316,6 +509,7 @@ pub struct CreateMembersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSampleFindingsRequest {
     /// <p>The ID of the detector to create sample findings for.</p>
     #[serde(rename = "DetectorId")]
@@ -326,11 +520,35 @@ pub struct CreateSampleFindingsRequest {
     pub finding_types: Option<Vec<String>>,
 }
 
+impl Validate for CreateSampleFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.finding_types {
+            errors.check_length(
+                &field_path(path, "finding_types"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "finding_types"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(50));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSampleFindingsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateThreatIntelSetRequest {
     /// <p>A boolean value that indicates whether GuardDuty is to start using the uploaded ThreatIntelSet.</p>
     #[serde(rename = "Activate")]
@@ -357,8 +575,52 @@ pub struct CreateThreatIntelSetRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateThreatIntelSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "format"),
+            self.format.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "location"),
+            self.location.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(200));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateThreatIntelSetResponse {
     /// <p>The ID of the ThreatIntelSet resource.</p>
     #[serde(rename = "ThreatIntelSetId")]
@@ -366,14 +628,30 @@ pub struct CreateThreatIntelSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeclineInvitationsRequest {
     /// <p>A list of account IDs of the AWS accounts that sent invitations to the current member account that you want to decline invitations from.</p>
     #[serde(rename = "AccountIds")]
     pub account_ids: Vec<String>,
 }
 
+impl Validate for DeclineInvitationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_ids"),
+            self.account_ids.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(12), Some(12));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeclineInvitationsResponse {
     /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -381,17 +659,30 @@ pub struct DeclineInvitationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDetectorRequest {
     /// <p>The unique ID of the detector that you want to delete.</p>
     #[serde(rename = "DetectorId")]
     pub detector_id: String,
 }
 
+impl Validate for DeleteDetectorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDetectorResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFilterRequest {
     /// <p>The unique ID of the detector the filter is associated with.</p>
     #[serde(rename = "DetectorId")]
@@ -401,11 +692,23 @@ pub struct DeleteFilterRequest {
     pub filter_name: String,
 }
 
+impl Validate for DeleteFilterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFilterResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIPSetRequest {
     /// <p>The unique ID of the detector the ipSet is associated with.</p>
     #[serde(rename = "DetectorId")]
@@ -415,19 +718,46 @@ pub struct DeleteIPSetRequest {
     pub ip_set_id: String,
 }
 
+impl Validate for DeleteIPSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteIPSetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInvitationsRequest {
     /// <p>A list of account IDs of the AWS accounts that sent invitations to the current member account that you want to delete invitations from.</p>
     #[serde(rename = "AccountIds")]
     pub account_ids: Vec<String>,
 }
 
+impl Validate for DeleteInvitationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_ids"),
+            self.account_ids.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(12), Some(12));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteInvitationsResponse {
     /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -435,6 +765,7 @@ pub struct DeleteInvitationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMembersRequest {
     /// <p>A list of account IDs of the GuardDuty member accounts that you want to delete.</p>
     #[serde(rename = "AccountIds")]
@@ -444,8 +775,29 @@ pub struct DeleteMembersRequest {
     pub detector_id: String,
 }
 
+impl Validate for DeleteMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_ids"),
+            self.account_ids.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(12), Some(12));
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteMembersResponse {
     /// <p>The accounts that could not be processed.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -453,6 +805,7 @@ pub struct DeleteMembersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteThreatIntelSetRequest {
     /// <p>The unique ID of the detector the threatIntelSet is associated with.</p>
     #[serde(rename = "DetectorId")]
@@ -462,22 +815,46 @@ pub struct DeleteThreatIntelSetRequest {
     pub threat_intel_set_id: String,
 }
 
+impl Validate for DeleteThreatIntelSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteThreatIntelSetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateFromMasterAccountRequest {
     /// <p>The unique ID of the detector of the GuardDuty member account.</p>
     #[serde(rename = "DetectorId")]
     pub detector_id: String,
 }
 
+impl Validate for DisassociateFromMasterAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateFromMasterAccountResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateMembersRequest {
     /// <p>A list of account IDs of the GuardDuty member accounts that you want to disassociate from master.</p>
     #[serde(rename = "AccountIds")]
@@ -487,8 +864,29 @@ pub struct DisassociateMembersRequest {
     pub detector_id: String,
 }
 
+impl Validate for DisassociateMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_ids"),
+            self.account_ids.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(12), Some(12));
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateMembersResponse {
     /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -497,7 +895,7 @@ pub struct DisassociateMembersResponse {
 
 /// <p>Contains information about the DNS request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DnsRequestAction {
     /// <p>Domain information for the DNS request.</p>
     #[serde(rename = "Domain")]
@@ -507,7 +905,7 @@ pub struct DnsRequestAction {
 
 /// <p>Contains information about the domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainDetails {
     /// <p>Domain information for the AWS API call.</p>
     #[serde(rename = "Domain")]
@@ -517,7 +915,7 @@ pub struct DomainDetails {
 
 /// <p>Contains information about the reason that the finding was generated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Evidence {
     /// <p>A list of threat intelligence details related to the evidence.</p>
     #[serde(rename = "ThreatIntelligenceDetails")]
@@ -527,7 +925,7 @@ pub struct Evidence {
 
 /// <p>Contains information about the finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Finding {
     /// <p>The ID of the account in which the finding was generated.</p>
     #[serde(rename = "AccountId")]
@@ -590,7 +988,7 @@ pub struct FindingCriteria {
 
 /// <p>Contains information about finding statistics.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FindingStatistics {
     /// <p>Represents a map of severity to count statistic for a set of findings</p>
     #[serde(rename = "CountBySeverity")]
@@ -600,7 +998,7 @@ pub struct FindingStatistics {
 
 /// <p>Contains information about the </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GeoLocation {
     /// <p>Latitude information of remote IP address.</p>
     #[serde(rename = "Lat")]
@@ -613,14 +1011,26 @@ pub struct GeoLocation {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDetectorRequest {
     /// <p>The unique ID of the detector that you want to get.</p>
     #[serde(rename = "DetectorId")]
     pub detector_id: String,
 }
 
+impl Validate for GetDetectorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDetectorResponse {
     /// <p>Detector creation timestamp.</p>
     #[serde(rename = "CreatedAt")]
@@ -647,6 +1057,7 @@ pub struct GetDetectorResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFilterRequest {
     /// <p>The unique ID of the detector the filter is associated with.</p>
     #[serde(rename = "DetectorId")]
@@ -656,8 +1067,19 @@ pub struct GetFilterRequest {
     pub filter_name: String,
 }
 
+impl Validate for GetFilterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFilterResponse {
     /// <p>Specifies the action that is to be applied to the findings that match the filter.</p>
     #[serde(rename = "Action")]
@@ -683,6 +1105,7 @@ pub struct GetFilterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFindingsRequest {
     /// <p>The ID of the detector that specifies the GuardDuty service whose findings you want to retrieve.</p>
     #[serde(rename = "DetectorId")]
@@ -696,8 +1119,29 @@ pub struct GetFindingsRequest {
     pub sort_criteria: Option<SortCriteria>,
 }
 
+impl Validate for GetFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "finding_ids"),
+            self.finding_ids.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.finding_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "finding_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFindingsResponse {
     /// <p>A list of findings.</p>
     #[serde(rename = "Findings")]
@@ -705,6 +1149,7 @@ pub struct GetFindingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFindingsStatisticsRequest {
     /// <p>The ID of the detector that specifies the GuardDuty service whose findings' statistics you want to retrieve.</p>
     #[serde(rename = "DetectorId")]
@@ -718,8 +1163,25 @@ pub struct GetFindingsStatisticsRequest {
     pub finding_statistic_types: Vec<String>,
 }
 
+impl Validate for GetFindingsStatisticsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "finding_statistic_types"),
+            self.finding_statistic_types.len(),
+            None,
+            Some(10),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFindingsStatisticsResponse {
     /// <p>Finding statistics object.</p>
     #[serde(rename = "FindingStatistics")]
@@ -727,6 +1189,7 @@ pub struct GetFindingsStatisticsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIPSetRequest {
     /// <p>The unique ID of the detector the ipSet is associated with.</p>
     #[serde(rename = "DetectorId")]
@@ -736,8 +1199,19 @@ pub struct GetIPSetRequest {
     pub ip_set_id: String,
 }
 
+impl Validate for GetIPSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIPSetResponse {
     /// <p>The format of the file that contains the IPSet.</p>
     #[serde(rename = "Format")]
@@ -758,10 +1232,11 @@ pub struct GetIPSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInvitationsCountRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInvitationsCountResponse {
     /// <p>The number of received invitations.</p>
     #[serde(rename = "InvitationsCount")]
@@ -770,14 +1245,26 @@ pub struct GetInvitationsCountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMasterAccountRequest {
     /// <p>The unique ID of the detector of the GuardDuty member account.</p>
     #[serde(rename = "DetectorId")]
     pub detector_id: String,
 }
 
+impl Validate for GetMasterAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMasterAccountResponse {
     /// <p>Master account details.</p>
     #[serde(rename = "Master")]
@@ -785,6 +1272,7 @@ pub struct GetMasterAccountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMembersRequest {
     /// <p>A list of account IDs of the GuardDuty member accounts that you want to describe.</p>
     #[serde(rename = "AccountIds")]
@@ -794,8 +1282,29 @@ pub struct GetMembersRequest {
     pub detector_id: String,
 }
 
+impl Validate for GetMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_ids"),
+            self.account_ids.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(12), Some(12));
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMembersResponse {
     /// <p>A list of members.</p>
     #[serde(rename = "Members")]
@@ -806,6 +1315,7 @@ pub struct GetMembersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetThreatIntelSetRequest {
     /// <p>The unique ID of the detector the threatIntelSet is associated with.</p>
     #[serde(rename = "DetectorId")]
@@ -815,8 +1325,19 @@ pub struct GetThreatIntelSetRequest {
     pub threat_intel_set_id: String,
 }
 
+impl Validate for GetThreatIntelSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetThreatIntelSetResponse {
     /// <p>The format of the threatIntelSet.</p>
     #[serde(rename = "Format")]
@@ -838,7 +1359,7 @@ pub struct GetThreatIntelSetResponse {
 
 /// <p>Contains information about the instance profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IamInstanceProfile {
     /// <p>AWS EC2 instance profile ARN.</p>
     #[serde(rename = "Arn")]
@@ -852,7 +1373,7 @@ pub struct IamInstanceProfile {
 
 /// <p>Contains information about the details of an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceDetails {
     /// <p>The availability zone of the EC2 instance.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -906,7 +1427,7 @@ pub struct InstanceDetails {
 
 /// <p>Contains information about the invitation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Invitation {
     /// <p>Inviter account ID</p>
     #[serde(rename = "AccountId")]
@@ -927,6 +1448,7 @@ pub struct Invitation {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InviteMembersRequest {
     /// <p>A list of account IDs of the accounts that you want to invite to GuardDuty as members.</p>
     #[serde(rename = "AccountIds")]
@@ -944,8 +1466,29 @@ pub struct InviteMembersRequest {
     pub message: Option<String>,
 }
 
+impl Validate for InviteMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_ids"),
+            self.account_ids.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(12), Some(12));
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InviteMembersResponse {
     /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -953,6 +1496,7 @@ pub struct InviteMembersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDetectorsRequest {
     /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 50. The maximum value is 50.</p>
     #[serde(rename = "MaxResults")]
@@ -964,8 +1508,16 @@ pub struct ListDetectorsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDetectorsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDetectorsResponse {
     /// <p>A list of detector Ids.</p>
     #[serde(rename = "DetectorIds")]
@@ -977,6 +1529,7 @@ pub struct ListDetectorsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFiltersRequest {
     /// <p>The unique ID of the detector the filter is associated with.</p>
     #[serde(rename = "DetectorId")]
@@ -991,8 +1544,22 @@ pub struct ListFiltersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListFiltersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFiltersResponse {
     /// <p>A list of filter names</p>
     #[serde(rename = "FilterNames")]
@@ -1004,6 +1571,7 @@ pub struct ListFiltersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFindingsRequest {
     /// <p>The ID of the detector that specifies the GuardDuty service whose findings you want to list.</p>
     #[serde(rename = "DetectorId")]
@@ -1026,8 +1594,22 @@ pub struct ListFindingsRequest {
     pub sort_criteria: Option<SortCriteria>,
 }
 
+impl Validate for ListFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFindingsResponse {
     /// <p>The IDs of the findings you are listing.</p>
     #[serde(rename = "FindingIds")]
@@ -1039,6 +1621,7 @@ pub struct ListFindingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIPSetsRequest {
     /// <p>The unique ID of the detector the ipSet is associated with.</p>
     #[serde(rename = "DetectorId")]
@@ -1053,8 +1636,22 @@ pub struct ListIPSetsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListIPSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListIPSetsResponse {
     /// <p>The IDs of the IPSet resources.</p>
     #[serde(rename = "IpSetIds")]
@@ -1066,6 +1663,7 @@ pub struct ListIPSetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInvitationsRequest {
     /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 50. The maximum value is 50.</p>
     #[serde(rename = "MaxResults")]
@@ -1077,8 +1675,16 @@ pub struct ListInvitationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListInvitationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListInvitationsResponse {
     /// <p>A list of invitation descriptions.</p>
     #[serde(rename = "Invitations")]
@@ -1091,6 +1697,7 @@ pub struct ListInvitationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMembersRequest {
     /// <p>The unique ID of the detector the member is associated with.</p>
     #[serde(rename = "DetectorId")]
@@ -1109,8 +1716,22 @@ pub struct ListMembersRequest {
     pub only_associated: Option<String>,
 }
 
+impl Validate for ListMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListMembersResponse {
     /// <p>A list of members.</p>
     #[serde(rename = "Members")]
@@ -1123,14 +1744,21 @@ pub struct ListMembersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) for the given GuardDuty resource </p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "^arn:[A-Za-z_.-]{1,20}:guardduty:[A-Za-z0-9_/.-]{0,63}:\\d+:detector/[A-Za-z0-9_/.-]{32,264}$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags associated with the resource.</p>
     #[serde(rename = "Tags")]
@@ -1139,6 +1767,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListThreatIntelSetsRequest {
     /// <p>The unique ID of the detector the threatIntelSet is associated with.</p>
     #[serde(rename = "DetectorId")]
@@ -1153,8 +1782,22 @@ pub struct ListThreatIntelSetsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListThreatIntelSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListThreatIntelSetsResponse {
     /// <p>Pagination parameter to be used on the next list operation to retrieve more items.</p>
     #[serde(rename = "NextToken")]
@@ -1167,7 +1810,7 @@ pub struct ListThreatIntelSetsResponse {
 
 /// <p>Contains information about the port for the local connection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LocalPortDetails {
     /// <p>Port number of the local connection.</p>
     #[serde(rename = "Port")]
@@ -1181,7 +1824,7 @@ pub struct LocalPortDetails {
 
 /// <p>Contains information about the Master account and invitation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Master {
     /// <p>The ID of the account used as the Master account.</p>
     #[serde(rename = "AccountId")]
@@ -1203,7 +1846,7 @@ pub struct Master {
 
 /// <p>Continas information about the member account </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Member {
     /// <p>Member account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1232,7 +1875,7 @@ pub struct Member {
 
 /// <p>Contains information about the network connection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkConnectionAction {
     /// <p>Network connection blocked information.</p>
     #[serde(rename = "Blocked")]
@@ -1262,7 +1905,7 @@ pub struct NetworkConnectionAction {
 
 /// <p>Contains information about the network interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkInterface {
     /// <p>A list of EC2 instance IPv6 address information.</p>
     #[serde(rename = "Ipv6Addresses")]
@@ -1308,7 +1951,7 @@ pub struct NetworkInterface {
 
 /// <p>Continas information about the organization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Organization {
     /// <p>Autonomous system number of the internet provider of the remote IP address.</p>
     #[serde(rename = "Asn")]
@@ -1330,7 +1973,7 @@ pub struct Organization {
 
 /// <p>Contains information about the port probe.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PortProbeAction {
     /// <p>Port probe blocked information.</p>
     #[serde(rename = "Blocked")]
@@ -1344,7 +1987,7 @@ pub struct PortProbeAction {
 
 /// <p>Contains information about the port probe details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PortProbeDetail {
     /// <p>Local port information of the connection.</p>
     #[serde(rename = "LocalPortDetails")]
@@ -1358,7 +2001,7 @@ pub struct PortProbeDetail {
 
 /// <p>Contains information about the private IP address.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PrivateIpAddressDetails {
     /// <p>Private DNS name of the EC2 instance.</p>
     #[serde(rename = "PrivateDnsName")]
@@ -1372,7 +2015,7 @@ pub struct PrivateIpAddressDetails {
 
 /// <p>Contains information about the product code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProductCode {
     /// <p>Product code information.</p>
     #[serde(rename = "Code")]
@@ -1386,7 +2029,7 @@ pub struct ProductCode {
 
 /// <p>Continas information about the remote IP address.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoteIpDetails {
     /// <p>City information of the remote IP address.</p>
     #[serde(rename = "City")]
@@ -1412,7 +2055,7 @@ pub struct RemoteIpDetails {
 
 /// <p>Contains information about the remote port.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemotePortDetails {
     /// <p>Port number of the remote connection.</p>
     #[serde(rename = "Port")]
@@ -1426,7 +2069,7 @@ pub struct RemotePortDetails {
 
 /// <p>Contains information about the resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Resource {
     /// <p>The IAM access key details (IAM user information) of a user that engaged in the activity that prompted GuardDuty to generate a finding.</p>
     #[serde(rename = "AccessKeyDetails")]
@@ -1444,7 +2087,7 @@ pub struct Resource {
 
 /// <p>Contains information about the security group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SecurityGroup {
     /// <p>EC2 instance's security group ID.</p>
     #[serde(rename = "GroupId")]
@@ -1458,7 +2101,7 @@ pub struct SecurityGroup {
 
 /// <p>Contains information about the service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Service {
     /// <p>Information about the activity described in a finding.</p>
     #[serde(rename = "Action")]
@@ -1504,6 +2147,7 @@ pub struct Service {
 
 /// <p>Contains information about the criteria for sorting.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SortCriteria {
     /// <p>Represents the finding attribute (for example, accountId) by which to sort findings.</p>
     #[serde(rename = "AttributeName")]
@@ -1516,6 +2160,7 @@ pub struct SortCriteria {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartMonitoringMembersRequest {
     /// <p>A list of account IDs of the GuardDuty member accounts whose findings you want the master account to monitor.</p>
     #[serde(rename = "AccountIds")]
@@ -1525,8 +2170,29 @@ pub struct StartMonitoringMembersRequest {
     pub detector_id: String,
 }
 
+impl Validate for StartMonitoringMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_ids"),
+            self.account_ids.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(12), Some(12));
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartMonitoringMembersResponse {
     /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -1534,6 +2200,7 @@ pub struct StartMonitoringMembersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopMonitoringMembersRequest {
     /// <p>A list of account IDs of the GuardDuty member accounts whose findings you want the master account to stop monitoring.</p>
     #[serde(rename = "AccountIds")]
@@ -1543,8 +2210,29 @@ pub struct StopMonitoringMembersRequest {
     pub detector_id: String,
 }
 
+impl Validate for StopMonitoringMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_ids"),
+            self.account_ids.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(12), Some(12));
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopMonitoringMembersResponse {
     /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -1553,7 +2241,7 @@ pub struct StopMonitoringMembersResponse {
 
 /// <p>Contains information about the tag associated with the resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Tag {
     /// <p>EC2 instance tag key.</p>
     #[serde(rename = "Key")]
@@ -1566,6 +2254,7 @@ pub struct Tag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) for the given GuardDuty resource </p>
     #[serde(rename = "ResourceArn")]
@@ -1575,13 +2264,29 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "^arn:[A-Za-z_.-]{1,20}:guardduty:[A-Za-z0-9_/.-]{0,63}:\\d+:detector/[A-Za-z0-9_/.-]{32,264}$");
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(200),
+        );
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>An instance of a threat intelligence detail that constitutes evidence for the finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ThreatIntelligenceDetail {
     /// <p>The name of the threat intelligence list that triggered the finding.</p>
     #[serde(rename = "ThreatListName")]
@@ -1594,6 +2299,7 @@ pub struct ThreatIntelligenceDetail {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnarchiveFindingsRequest {
     /// <p>The ID of the detector that specifies the GuardDuty service whose findings you want to unarchive.</p>
     #[serde(rename = "DetectorId")]
@@ -1603,13 +2309,34 @@ pub struct UnarchiveFindingsRequest {
     pub finding_ids: Vec<String>,
 }
 
+impl Validate for UnarchiveFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "finding_ids"),
+            self.finding_ids.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.finding_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "finding_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnarchiveFindingsResponse {}
 
 /// <p>Contains information about the accounts that were not processed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnprocessedAccount {
     /// <p>AWS Account ID.</p>
     #[serde(rename = "AccountId")]
@@ -1620,6 +2347,7 @@ pub struct UnprocessedAccount {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) for the given GuardDuty resource </p>
     #[serde(rename = "ResourceArn")]
@@ -1629,11 +2357,29 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "^arn:[A-Za-z_.-]{1,20}:guardduty:[A-Za-z0-9_/.-]{0,63}:\\d+:detector/[A-Za-z0-9_/.-]{32,264}$");
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(200),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^(?!aws:)[a-zA-Z+-=._:/]+$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDetectorRequest {
     /// <p>The unique ID of the detector that you want to update.</p>
     #[serde(rename = "DetectorId")]
@@ -1648,11 +2394,23 @@ pub struct UpdateDetectorRequest {
     pub finding_publishing_frequency: Option<String>,
 }
 
+impl Validate for UpdateDetectorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDetectorResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFilterRequest {
     /// <p>Specifies the action that is to be applied to the findings that match the filter.</p>
     #[serde(rename = "Action")]
@@ -1678,8 +2436,38 @@ pub struct UpdateFilterRequest {
     pub rank: Option<i64>,
 }
 
+impl Validate for UpdateFilterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.action {
+            errors.check_length(
+                &field_path(path, "action"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(512),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.rank {
+            errors.check_range(&field_path(path, "rank"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFilterResponse {
     /// <p>The name of the filter.</p>
     #[serde(rename = "Name")]
@@ -1687,6 +2475,7 @@ pub struct UpdateFilterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFindingsFeedbackRequest {
     /// <p>Additional feedback about the GuardDuty findings.</p>
     #[serde(rename = "Comments")]
@@ -1703,11 +2492,33 @@ pub struct UpdateFindingsFeedbackRequest {
     pub finding_ids: Vec<String>,
 }
 
+impl Validate for UpdateFindingsFeedbackRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "finding_ids"),
+            self.finding_ids.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.finding_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "finding_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFindingsFeedbackResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateIPSetRequest {
     /// <p>The updated boolean value that specifies whether the IPSet is active or not.</p>
     #[serde(rename = "Activate")]
@@ -1729,11 +2540,39 @@ pub struct UpdateIPSetRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateIPSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.location {
+            errors.check_length(
+                &field_path(path, "location"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateIPSetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateThreatIntelSetRequest {
     /// <p>The updated boolean value that specifies whether the ThreateIntelSet is active or not.</p>
     #[serde(rename = "Activate")]
@@ -1755,8 +2594,35 @@ pub struct UpdateThreatIntelSetRequest {
     pub threat_intel_set_id: String,
 }
 
+impl Validate for UpdateThreatIntelSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "detector_id"),
+            self.detector_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.location {
+            errors.check_length(
+                &field_path(path, "location"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateThreatIntelSetResponse {}
 
 /// Errors returned by AcceptInvitation
@@ -1780,7 +2646,12 @@ impl AcceptInvitationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1819,7 +2690,12 @@ impl ArchiveFindingsError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(ArchiveFindingsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1858,7 +2734,12 @@ impl CreateDetectorError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(CreateDetectorError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1897,7 +2778,12 @@ impl CreateFilterError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(CreateFilterError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1936,7 +2822,12 @@ impl CreateIPSetError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(CreateIPSetError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1975,7 +2866,12 @@ impl CreateMembersError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(CreateMembersError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2016,7 +2912,12 @@ impl CreateSampleFindingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2057,7 +2958,12 @@ impl CreateThreatIntelSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2098,7 +3004,12 @@ impl DeclineInvitationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2137,7 +3048,12 @@ impl DeleteDetectorError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(DeleteDetectorError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2176,7 +3092,12 @@ impl DeleteFilterError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(DeleteFilterError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2215,7 +3136,12 @@ impl DeleteIPSetError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(DeleteIPSetError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2256,7 +3182,12 @@ impl DeleteInvitationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2295,7 +3226,12 @@ impl DeleteMembersError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(DeleteMembersError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2336,7 +3272,12 @@ impl DeleteThreatIntelSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2381,7 +3322,12 @@ impl DisassociateFromMasterAccountError {
                         DisassociateFromMasterAccountError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2422,7 +3368,12 @@ impl DisassociateMembersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2461,7 +3412,12 @@ impl GetDetectorError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(GetDetectorError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2500,7 +3456,12 @@ impl GetFilterError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(GetFilterError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2539,7 +3500,12 @@ impl GetFindingsError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(GetFindingsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2580,7 +3546,12 @@ impl GetFindingsStatisticsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2619,7 +3590,12 @@ impl GetIPSetError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(GetIPSetError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2660,7 +3636,12 @@ impl GetInvitationsCountError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2701,7 +3682,12 @@ impl GetMasterAccountError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2740,7 +3726,12 @@ impl GetMembersError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(GetMembersError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2781,7 +3772,12 @@ impl GetThreatIntelSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2820,7 +3816,12 @@ impl InviteMembersError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(InviteMembersError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2859,7 +3860,12 @@ impl ListDetectorsError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(ListDetectorsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2898,7 +3904,12 @@ impl ListFiltersError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(ListFiltersError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2937,7 +3948,12 @@ impl ListFindingsError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(ListFindingsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2976,7 +3992,12 @@ impl ListIPSetsError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(ListIPSetsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3015,7 +4036,12 @@ impl ListInvitationsError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(ListInvitationsError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3054,7 +4080,12 @@ impl ListMembersError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(ListMembersError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3095,7 +4126,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3136,7 +4172,12 @@ impl ListThreatIntelSetsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3177,7 +4218,12 @@ impl StartMonitoringMembersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3218,7 +4264,12 @@ impl StopMonitoringMembersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3257,7 +4308,12 @@ impl TagResourceError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3298,7 +4354,12 @@ impl UnarchiveFindingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3337,7 +4398,12 @@ impl UntagResourceError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3376,7 +4442,12 @@ impl UpdateDetectorError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(UpdateDetectorError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3415,7 +4486,12 @@ impl UpdateFilterError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(UpdateFilterError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3456,7 +4532,12 @@ impl UpdateFindingsFeedbackError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3495,7 +4576,12 @@ impl UpdateIPSetError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(UpdateIPSetError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3536,7 +4622,12 @@ impl UpdateThreatIntelSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3824,1577 +4915,4446 @@ pub trait GuardDuty {
         input: UpdateThreatIntelSetRequest,
     ) -> RusotoFuture<UpdateThreatIntelSetResponse, UpdateThreatIntelSetError>;
 }
-/// A client for the Amazon GuardDuty API.
-#[derive(Clone)]
-pub struct GuardDutyClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl GuardDutyClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> GuardDutyClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> GuardDutyClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> GuardDutyClient {
-        GuardDutyClient { client, region }
-    }
-}
-
-impl GuardDuty for GuardDutyClient {
-    /// <p>Accepts the invitation to be monitored by a master GuardDuty account.</p>
+const _: Option<&dyn GuardDuty> = None;
+impl<'a, T: GuardDuty + ?Sized> GuardDuty for &'a T {
     fn accept_invitation(
         &self,
         input: AcceptInvitationRequest,
     ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/master",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AcceptInvitationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AcceptInvitationError::from_response(response))),
-                )
-            }
-        })
+        (**self).accept_invitation(input)
     }
-
-    /// <p><p>Archives GuardDuty findings specified by the list of finding IDs.</p> <note> <p>Only the master account can archive findings. Member accounts do not have permission to archive findings from their accounts.</p> </note></p>
     fn archive_findings(
         &self,
         input: ArchiveFindingsRequest,
     ) -> RusotoFuture<ArchiveFindingsResponse, ArchiveFindingsError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/findings/archive",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ArchiveFindingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ArchiveFindingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).archive_findings(input)
     }
-
-    /// <p>Creates a single Amazon GuardDuty detector. A detector is a resource that represents the GuardDuty service. To start using GuardDuty, you must create a detector in each region that you enable the service. You can have only one detector per account per region.</p>
     fn create_detector(
         &self,
         input: CreateDetectorRequest,
     ) -> RusotoFuture<CreateDetectorResponse, CreateDetectorError> {
-        let request_uri = "/detector";
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDetectorResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDetectorError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_detector(input)
     }
-
-    /// <p>Creates a filter using the specified finding criteria.</p>
     fn create_filter(
         &self,
         input: CreateFilterRequest,
     ) -> RusotoFuture<CreateFilterResponse, CreateFilterError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/filter",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFilterResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateFilterError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_filter(input)
     }
-
-    /// <p>Creates a new IPSet - a list of trusted IP addresses that have been whitelisted for secure communication with AWS infrastructure and applications.</p>
     fn create_ip_set(
         &self,
         input: CreateIPSetRequest,
     ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/ipset",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateIPSetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateIPSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_ip_set(input)
     }
-
-    /// <p>Creates member accounts of the current AWS account by specifying a list of AWS account IDs. The current AWS account can then invite these members to manage GuardDuty in their accounts.</p>
     fn create_members(
         &self,
         input: CreateMembersRequest,
     ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/member",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateMembersError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_members(input)
     }
-
-    /// <p>Generates example findings of types specified by the list of finding types. If 'NULL' is specified for findingTypes, the API generates example findings of all supported finding types.</p>
     fn create_sample_findings(
         &self,
         input: CreateSampleFindingsRequest,
     ) -> RusotoFuture<CreateSampleFindingsResponse, CreateSampleFindingsError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/findings/create",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSampleFindingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateSampleFindingsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_sample_findings(input)
     }
-
-    /// <p>Create a new ThreatIntelSet. ThreatIntelSets consist of known malicious IP addresses. GuardDuty generates findings based on ThreatIntelSets.</p>
     fn create_threat_intel_set(
         &self,
         input: CreateThreatIntelSetRequest,
     ) -> RusotoFuture<CreateThreatIntelSetResponse, CreateThreatIntelSetError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/threatintelset",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateThreatIntelSetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateThreatIntelSetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_threat_intel_set(input)
     }
-
-    /// <p>Declines invitations sent to the current member account by AWS account specified by their account IDs.</p>
     fn decline_invitations(
         &self,
         input: DeclineInvitationsRequest,
     ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
-        let request_uri = "/invitation/decline";
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeclineInvitationsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeclineInvitationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).decline_invitations(input)
     }
-
-    /// <p>Deletes a Amazon GuardDuty detector specified by the detector ID.</p>
     fn delete_detector(
         &self,
         input: DeleteDetectorRequest,
     ) -> RusotoFuture<DeleteDetectorResponse, DeleteDetectorError> {
-        let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
-
-        let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDetectorResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDetectorError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_detector(input)
     }
-
-    /// <p>Deletes the filter specified by the filter name.</p>
     fn delete_filter(
         &self,
         input: DeleteFilterRequest,
     ) -> RusotoFuture<DeleteFilterResponse, DeleteFilterError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/filter/{filter_name}",
-            detector_id = input.detector_id,
-            filter_name = input.filter_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteFilterResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFilterError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_filter(input)
     }
-
-    /// <p>Deletes the IPSet specified by the IPSet ID.</p>
     fn delete_ip_set(
         &self,
         input: DeleteIPSetRequest,
     ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/ipset/{ip_set_id}",
-            detector_id = input.detector_id,
-            ip_set_id = input.ip_set_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteIPSetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteIPSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_ip_set(input)
     }
-
-    /// <p>Deletes invitations sent to the current member account by AWS accounts specified by their account IDs.</p>
     fn delete_invitations(
         &self,
         input: DeleteInvitationsRequest,
     ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
-        let request_uri = "/invitation/delete";
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteInvitationsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteInvitationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_invitations(input)
     }
-
-    /// <p>Deletes GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
     fn delete_members(
         &self,
         input: DeleteMembersRequest,
     ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/member/delete",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteMembersError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_members(input)
     }
-
-    /// <p>Deletes ThreatIntelSet specified by the ThreatIntelSet ID.</p>
     fn delete_threat_intel_set(
         &self,
         input: DeleteThreatIntelSetRequest,
     ) -> RusotoFuture<DeleteThreatIntelSetResponse, DeleteThreatIntelSetError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/threatintelset/{threat_intel_set_id}",
-            detector_id = input.detector_id,
-            threat_intel_set_id = input.threat_intel_set_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteThreatIntelSetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteThreatIntelSetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_threat_intel_set(input)
     }
-
-    /// <p>Disassociates the current GuardDuty member account from its master account.</p>
     fn disassociate_from_master_account(
         &self,
         input: DisassociateFromMasterAccountRequest,
     ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
     {
-        let request_uri = format!(
-            "/detector/{detector_id}/master/disassociate",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateFromMasterAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateFromMasterAccountError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_from_master_account(input)
     }
-
-    /// <p>Disassociates GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
     fn disassociate_members(
         &self,
         input: DisassociateMembersRequest,
     ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/member/disassociate",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DisassociateMembersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).disassociate_members(input)
     }
-
-    /// <p>Retrieves an Amazon GuardDuty detector specified by the detectorId.</p>
     fn get_detector(
         &self,
         input: GetDetectorRequest,
     ) -> RusotoFuture<GetDetectorResponse, GetDetectorError> {
-        let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDetectorResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDetectorError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_detector(input)
     }
-
-    /// <p>Returns the details of the filter specified by the filter name.</p>
     fn get_filter(
         &self,
         input: GetFilterRequest,
     ) -> RusotoFuture<GetFilterResponse, GetFilterError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/filter/{filter_name}",
-            detector_id = input.detector_id,
-            filter_name = input.filter_name
-        );
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFilterResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFilterError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_filter(input)
     }
-
-    /// <p>Describes Amazon GuardDuty findings specified by finding IDs.</p>
     fn get_findings(
         &self,
         input: GetFindingsRequest,
     ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/findings/get",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFindingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFindingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_findings(input)
     }
-
-    /// <p>Lists Amazon GuardDuty findings' statistics for the specified detector ID.</p>
     fn get_findings_statistics(
         &self,
         input: GetFindingsStatisticsRequest,
     ) -> RusotoFuture<GetFindingsStatisticsResponse, GetFindingsStatisticsError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/findings/statistics",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFindingsStatisticsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetFindingsStatisticsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_findings_statistics(input)
     }
-
-    /// <p>Retrieves the IPSet specified by the IPSet ID.</p>
     fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/ipset/{ip_set_id}",
-            detector_id = input.detector_id,
-            ip_set_id = input.ip_set_id
-        );
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIPSetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetIPSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_ip_set(input)
     }
-
-    /// <p>Returns the count of all GuardDuty membership invitations that were sent to the current member account except the currently accepted invitation.</p>
     fn get_invitations_count(
         &self,
     ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
-        let request_uri = "/invitation/count";
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInvitationsCountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetInvitationsCountError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_invitations_count()
     }
-
-    /// <p>Provides the details for the GuardDuty master account associated with the current GuardDuty member account.</p>
     fn get_master_account(
         &self,
         input: GetMasterAccountRequest,
     ) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/master",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMasterAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMasterAccountError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_master_account(input)
     }
-
-    /// <p>Retrieves GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
     fn get_members(
         &self,
         input: GetMembersRequest,
     ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/member/get",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMembersError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_members(input)
     }
-
-    /// <p>Retrieves the ThreatIntelSet that is specified by the ThreatIntelSet ID.</p>
     fn get_threat_intel_set(
         &self,
         input: GetThreatIntelSetRequest,
     ) -> RusotoFuture<GetThreatIntelSetResponse, GetThreatIntelSetError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/threatintelset/{threat_intel_set_id}",
-            detector_id = input.detector_id,
-            threat_intel_set_id = input.threat_intel_set_id
-        );
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetThreatIntelSetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetThreatIntelSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_threat_intel_set(input)
     }
-
-    /// <p>Invites other AWS accounts (created as members of the current AWS account by CreateMembers) to enable GuardDuty and allow the current AWS account to view and manage these accounts' GuardDuty findings on their behalf as the master account.</p>
     fn invite_members(
         &self,
         input: InviteMembersRequest,
     ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/member/invite",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InviteMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(InviteMembersError::from_response(response))),
-                )
-            }
-        })
+        (**self).invite_members(input)
     }
-
-    /// <p>Lists detectorIds of all the existing Amazon GuardDuty detector resources.</p>
     fn list_detectors(
         &self,
         input: ListDetectorsRequest,
     ) -> RusotoFuture<ListDetectorsResponse, ListDetectorsError> {
-        let request_uri = "/detector";
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDetectorsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDetectorsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_detectors(input)
     }
-
-    /// <p>Returns a paginated list of the current filters.</p>
     fn list_filters(
         &self,
         input: ListFiltersRequest,
     ) -> RusotoFuture<ListFiltersResponse, ListFiltersError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/filter",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFiltersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListFiltersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_filters(input)
     }
-
-    /// <p>Lists Amazon GuardDuty findings for the specified detector ID.</p>
     fn list_findings(
         &self,
         input: ListFindingsRequest,
     ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/findings",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFindingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListFindingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_findings(input)
     }
-
-    /// <p>Lists the IPSets of the GuardDuty service specified by the detector ID.</p>
     fn list_ip_sets(
         &self,
         input: ListIPSetsRequest,
     ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/ipset",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListIPSetsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListIPSetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_ip_sets(input)
     }
-
-    /// <p>Lists all GuardDuty membership invitations that were sent to the current AWS account.</p>
     fn list_invitations(
         &self,
         input: ListInvitationsRequest,
     ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
-        let request_uri = "/invitation";
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListInvitationsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListInvitationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_invitations(input)
     }
-
-    /// <p>Lists details about all member accounts for the current GuardDuty master account.</p>
     fn list_members(
         &self,
         input: ListMembersRequest,
     ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/member",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        if let Some(ref x) = input.only_associated {
-            params.put("onlyAssociated", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListMembersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_members(input)
     }
-
-    /// <p>Lists tags for a resource. Tagging is currently supported for detectors, finding filters, IP sets, and Threat Intel sets, with a limit of 50 tags per resource. When invoked, this operation returns all assigned tags for a given resource..</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Lists the ThreatIntelSets of the GuardDuty service specified by the detector ID.</p>
     fn list_threat_intel_sets(
         &self,
         input: ListThreatIntelSetsRequest,
     ) -> RusotoFuture<ListThreatIntelSetsResponse, ListThreatIntelSetsError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/threatintelset",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListThreatIntelSetsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListThreatIntelSetsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_threat_intel_sets(input)
     }
-
-    /// <p>Re-enables GuardDuty to monitor findings of the member accounts specified by the account IDs. A master GuardDuty account can run this command after disabling GuardDuty from monitoring these members' findings by running StopMonitoringMembers.</p>
     fn start_monitoring_members(
         &self,
         input: StartMonitoringMembersRequest,
     ) -> RusotoFuture<StartMonitoringMembersResponse, StartMonitoringMembersError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/member/start",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartMonitoringMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartMonitoringMembersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).start_monitoring_members(input)
     }
-
-    /// <p>Disables GuardDuty from monitoring findings of the member accounts specified by the account IDs. After running this command, a master GuardDuty account can run StartMonitoringMembers to re-enable GuardDuty to monitor these members’ findings.</p>
     fn stop_monitoring_members(
         &self,
         input: StopMonitoringMembersRequest,
     ) -> RusotoFuture<StopMonitoringMembersResponse, StopMonitoringMembersError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/member/stop",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopMonitoringMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StopMonitoringMembersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).stop_monitoring_members(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unarchive_findings(
+        &self,
+        input: UnarchiveFindingsRequest,
+    ) -> RusotoFuture<UnarchiveFindingsResponse, UnarchiveFindingsError> {
+        (**self).unarchive_findings(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_detector(
+        &self,
+        input: UpdateDetectorRequest,
+    ) -> RusotoFuture<UpdateDetectorResponse, UpdateDetectorError> {
+        (**self).update_detector(input)
+    }
+    fn update_filter(
+        &self,
+        input: UpdateFilterRequest,
+    ) -> RusotoFuture<UpdateFilterResponse, UpdateFilterError> {
+        (**self).update_filter(input)
+    }
+    fn update_findings_feedback(
+        &self,
+        input: UpdateFindingsFeedbackRequest,
+    ) -> RusotoFuture<UpdateFindingsFeedbackResponse, UpdateFindingsFeedbackError> {
+        (**self).update_findings_feedback(input)
+    }
+    fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
+        (**self).update_ip_set(input)
+    }
+    fn update_threat_intel_set(
+        &self,
+        input: UpdateThreatIntelSetRequest,
+    ) -> RusotoFuture<UpdateThreatIntelSetResponse, UpdateThreatIntelSetError> {
+        (**self).update_threat_intel_set(input)
+    }
+}
+impl<T: GuardDuty + ?Sized> GuardDuty for Box<T> {
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
+        (**self).accept_invitation(input)
+    }
+    fn archive_findings(
+        &self,
+        input: ArchiveFindingsRequest,
+    ) -> RusotoFuture<ArchiveFindingsResponse, ArchiveFindingsError> {
+        (**self).archive_findings(input)
+    }
+    fn create_detector(
+        &self,
+        input: CreateDetectorRequest,
+    ) -> RusotoFuture<CreateDetectorResponse, CreateDetectorError> {
+        (**self).create_detector(input)
+    }
+    fn create_filter(
+        &self,
+        input: CreateFilterRequest,
+    ) -> RusotoFuture<CreateFilterResponse, CreateFilterError> {
+        (**self).create_filter(input)
+    }
+    fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
+        (**self).create_ip_set(input)
+    }
+    fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
+        (**self).create_members(input)
+    }
+    fn create_sample_findings(
+        &self,
+        input: CreateSampleFindingsRequest,
+    ) -> RusotoFuture<CreateSampleFindingsResponse, CreateSampleFindingsError> {
+        (**self).create_sample_findings(input)
+    }
+    fn create_threat_intel_set(
+        &self,
+        input: CreateThreatIntelSetRequest,
+    ) -> RusotoFuture<CreateThreatIntelSetResponse, CreateThreatIntelSetError> {
+        (**self).create_threat_intel_set(input)
+    }
+    fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
+        (**self).decline_invitations(input)
+    }
+    fn delete_detector(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> RusotoFuture<DeleteDetectorResponse, DeleteDetectorError> {
+        (**self).delete_detector(input)
+    }
+    fn delete_filter(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> RusotoFuture<DeleteFilterResponse, DeleteFilterError> {
+        (**self).delete_filter(input)
+    }
+    fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
+        (**self).delete_ip_set(input)
+    }
+    fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
+        (**self).delete_invitations(input)
+    }
+    fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
+        (**self).delete_members(input)
+    }
+    fn delete_threat_intel_set(
+        &self,
+        input: DeleteThreatIntelSetRequest,
+    ) -> RusotoFuture<DeleteThreatIntelSetResponse, DeleteThreatIntelSetError> {
+        (**self).delete_threat_intel_set(input)
+    }
+    fn disassociate_from_master_account(
+        &self,
+        input: DisassociateFromMasterAccountRequest,
+    ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
+    {
+        (**self).disassociate_from_master_account(input)
+    }
+    fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
+        (**self).disassociate_members(input)
+    }
+    fn get_detector(
+        &self,
+        input: GetDetectorRequest,
+    ) -> RusotoFuture<GetDetectorResponse, GetDetectorError> {
+        (**self).get_detector(input)
+    }
+    fn get_filter(
+        &self,
+        input: GetFilterRequest,
+    ) -> RusotoFuture<GetFilterResponse, GetFilterError> {
+        (**self).get_filter(input)
+    }
+    fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
+        (**self).get_findings(input)
+    }
+    fn get_findings_statistics(
+        &self,
+        input: GetFindingsStatisticsRequest,
+    ) -> RusotoFuture<GetFindingsStatisticsResponse, GetFindingsStatisticsError> {
+        (**self).get_findings_statistics(input)
+    }
+    fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
+        (**self).get_ip_set(input)
+    }
+    fn get_invitations_count(
+        &self,
+    ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
+        (**self).get_invitations_count()
+    }
+    fn get_master_account(
+        &self,
+        input: GetMasterAccountRequest,
+    ) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
+        (**self).get_master_account(input)
+    }
+    fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
+        (**self).get_members(input)
+    }
+    fn get_threat_intel_set(
+        &self,
+        input: GetThreatIntelSetRequest,
+    ) -> RusotoFuture<GetThreatIntelSetResponse, GetThreatIntelSetError> {
+        (**self).get_threat_intel_set(input)
+    }
+    fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
+        (**self).invite_members(input)
+    }
+    fn list_detectors(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> RusotoFuture<ListDetectorsResponse, ListDetectorsError> {
+        (**self).list_detectors(input)
+    }
+    fn list_filters(
+        &self,
+        input: ListFiltersRequest,
+    ) -> RusotoFuture<ListFiltersResponse, ListFiltersError> {
+        (**self).list_filters(input)
+    }
+    fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
+        (**self).list_findings(input)
+    }
+    fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
+        (**self).list_ip_sets(input)
+    }
+    fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
+        (**self).list_invitations(input)
+    }
+    fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
+        (**self).list_members(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_threat_intel_sets(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> RusotoFuture<ListThreatIntelSetsResponse, ListThreatIntelSetsError> {
+        (**self).list_threat_intel_sets(input)
+    }
+    fn start_monitoring_members(
+        &self,
+        input: StartMonitoringMembersRequest,
+    ) -> RusotoFuture<StartMonitoringMembersResponse, StartMonitoringMembersError> {
+        (**self).start_monitoring_members(input)
+    }
+    fn stop_monitoring_members(
+        &self,
+        input: StopMonitoringMembersRequest,
+    ) -> RusotoFuture<StopMonitoringMembersResponse, StopMonitoringMembersError> {
+        (**self).stop_monitoring_members(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unarchive_findings(
+        &self,
+        input: UnarchiveFindingsRequest,
+    ) -> RusotoFuture<UnarchiveFindingsResponse, UnarchiveFindingsError> {
+        (**self).unarchive_findings(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_detector(
+        &self,
+        input: UpdateDetectorRequest,
+    ) -> RusotoFuture<UpdateDetectorResponse, UpdateDetectorError> {
+        (**self).update_detector(input)
+    }
+    fn update_filter(
+        &self,
+        input: UpdateFilterRequest,
+    ) -> RusotoFuture<UpdateFilterResponse, UpdateFilterError> {
+        (**self).update_filter(input)
+    }
+    fn update_findings_feedback(
+        &self,
+        input: UpdateFindingsFeedbackRequest,
+    ) -> RusotoFuture<UpdateFindingsFeedbackResponse, UpdateFindingsFeedbackError> {
+        (**self).update_findings_feedback(input)
+    }
+    fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
+        (**self).update_ip_set(input)
+    }
+    fn update_threat_intel_set(
+        &self,
+        input: UpdateThreatIntelSetRequest,
+    ) -> RusotoFuture<UpdateThreatIntelSetResponse, UpdateThreatIntelSetError> {
+        (**self).update_threat_intel_set(input)
+    }
+}
+impl<T: GuardDuty + ?Sized> GuardDuty for ::std::sync::Arc<T> {
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
+        (**self).accept_invitation(input)
+    }
+    fn archive_findings(
+        &self,
+        input: ArchiveFindingsRequest,
+    ) -> RusotoFuture<ArchiveFindingsResponse, ArchiveFindingsError> {
+        (**self).archive_findings(input)
+    }
+    fn create_detector(
+        &self,
+        input: CreateDetectorRequest,
+    ) -> RusotoFuture<CreateDetectorResponse, CreateDetectorError> {
+        (**self).create_detector(input)
+    }
+    fn create_filter(
+        &self,
+        input: CreateFilterRequest,
+    ) -> RusotoFuture<CreateFilterResponse, CreateFilterError> {
+        (**self).create_filter(input)
+    }
+    fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
+        (**self).create_ip_set(input)
+    }
+    fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
+        (**self).create_members(input)
+    }
+    fn create_sample_findings(
+        &self,
+        input: CreateSampleFindingsRequest,
+    ) -> RusotoFuture<CreateSampleFindingsResponse, CreateSampleFindingsError> {
+        (**self).create_sample_findings(input)
+    }
+    fn create_threat_intel_set(
+        &self,
+        input: CreateThreatIntelSetRequest,
+    ) -> RusotoFuture<CreateThreatIntelSetResponse, CreateThreatIntelSetError> {
+        (**self).create_threat_intel_set(input)
+    }
+    fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
+        (**self).decline_invitations(input)
+    }
+    fn delete_detector(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> RusotoFuture<DeleteDetectorResponse, DeleteDetectorError> {
+        (**self).delete_detector(input)
+    }
+    fn delete_filter(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> RusotoFuture<DeleteFilterResponse, DeleteFilterError> {
+        (**self).delete_filter(input)
+    }
+    fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
+        (**self).delete_ip_set(input)
+    }
+    fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
+        (**self).delete_invitations(input)
+    }
+    fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
+        (**self).delete_members(input)
+    }
+    fn delete_threat_intel_set(
+        &self,
+        input: DeleteThreatIntelSetRequest,
+    ) -> RusotoFuture<DeleteThreatIntelSetResponse, DeleteThreatIntelSetError> {
+        (**self).delete_threat_intel_set(input)
+    }
+    fn disassociate_from_master_account(
+        &self,
+        input: DisassociateFromMasterAccountRequest,
+    ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
+    {
+        (**self).disassociate_from_master_account(input)
+    }
+    fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
+        (**self).disassociate_members(input)
+    }
+    fn get_detector(
+        &self,
+        input: GetDetectorRequest,
+    ) -> RusotoFuture<GetDetectorResponse, GetDetectorError> {
+        (**self).get_detector(input)
+    }
+    fn get_filter(
+        &self,
+        input: GetFilterRequest,
+    ) -> RusotoFuture<GetFilterResponse, GetFilterError> {
+        (**self).get_filter(input)
+    }
+    fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
+        (**self).get_findings(input)
+    }
+    fn get_findings_statistics(
+        &self,
+        input: GetFindingsStatisticsRequest,
+    ) -> RusotoFuture<GetFindingsStatisticsResponse, GetFindingsStatisticsError> {
+        (**self).get_findings_statistics(input)
+    }
+    fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
+        (**self).get_ip_set(input)
+    }
+    fn get_invitations_count(
+        &self,
+    ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
+        (**self).get_invitations_count()
+    }
+    fn get_master_account(
+        &self,
+        input: GetMasterAccountRequest,
+    ) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
+        (**self).get_master_account(input)
+    }
+    fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
+        (**self).get_members(input)
+    }
+    fn get_threat_intel_set(
+        &self,
+        input: GetThreatIntelSetRequest,
+    ) -> RusotoFuture<GetThreatIntelSetResponse, GetThreatIntelSetError> {
+        (**self).get_threat_intel_set(input)
+    }
+    fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
+        (**self).invite_members(input)
+    }
+    fn list_detectors(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> RusotoFuture<ListDetectorsResponse, ListDetectorsError> {
+        (**self).list_detectors(input)
+    }
+    fn list_filters(
+        &self,
+        input: ListFiltersRequest,
+    ) -> RusotoFuture<ListFiltersResponse, ListFiltersError> {
+        (**self).list_filters(input)
+    }
+    fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
+        (**self).list_findings(input)
+    }
+    fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
+        (**self).list_ip_sets(input)
+    }
+    fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
+        (**self).list_invitations(input)
+    }
+    fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
+        (**self).list_members(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_threat_intel_sets(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> RusotoFuture<ListThreatIntelSetsResponse, ListThreatIntelSetsError> {
+        (**self).list_threat_intel_sets(input)
+    }
+    fn start_monitoring_members(
+        &self,
+        input: StartMonitoringMembersRequest,
+    ) -> RusotoFuture<StartMonitoringMembersResponse, StartMonitoringMembersError> {
+        (**self).start_monitoring_members(input)
+    }
+    fn stop_monitoring_members(
+        &self,
+        input: StopMonitoringMembersRequest,
+    ) -> RusotoFuture<StopMonitoringMembersResponse, StopMonitoringMembersError> {
+        (**self).stop_monitoring_members(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unarchive_findings(
+        &self,
+        input: UnarchiveFindingsRequest,
+    ) -> RusotoFuture<UnarchiveFindingsResponse, UnarchiveFindingsError> {
+        (**self).unarchive_findings(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_detector(
+        &self,
+        input: UpdateDetectorRequest,
+    ) -> RusotoFuture<UpdateDetectorResponse, UpdateDetectorError> {
+        (**self).update_detector(input)
+    }
+    fn update_filter(
+        &self,
+        input: UpdateFilterRequest,
+    ) -> RusotoFuture<UpdateFilterResponse, UpdateFilterError> {
+        (**self).update_filter(input)
+    }
+    fn update_findings_feedback(
+        &self,
+        input: UpdateFindingsFeedbackRequest,
+    ) -> RusotoFuture<UpdateFindingsFeedbackResponse, UpdateFindingsFeedbackError> {
+        (**self).update_findings_feedback(input)
+    }
+    fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
+        (**self).update_ip_set(input)
+    }
+    fn update_threat_intel_set(
+        &self,
+        input: UpdateThreatIntelSetRequest,
+    ) -> RusotoFuture<UpdateThreatIntelSetResponse, UpdateThreatIntelSetError> {
+        (**self).update_threat_intel_set(input)
+    }
+}
+/// A client for the Amazon GuardDuty API.
+#[derive(Clone)]
+pub struct GuardDutyClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl GuardDutyClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GuardDutyClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GuardDutyClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> GuardDutyClient {
+        GuardDutyClient { client, region }
+    }
+}
+
+impl GuardDuty for GuardDutyClient {
+    /// <p>Accepts the invitation to be monitored by a master GuardDuty account.</p>
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/master",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AcceptInvitation")
+                        .deserialize::<AcceptInvitationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AcceptInvitationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Archives GuardDuty findings specified by the list of finding IDs.</p> <note> <p>Only the master account can archive findings. Member accounts do not have permission to archive findings from their accounts.</p> </note></p>
+    fn archive_findings(
+        &self,
+        input: ArchiveFindingsRequest,
+    ) -> RusotoFuture<ArchiveFindingsResponse, ArchiveFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/findings/archive",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("ArchiveFindings");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ArchiveFindings")
+                        .deserialize::<ArchiveFindingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ArchiveFindingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a single Amazon GuardDuty detector. A detector is a resource that represents the GuardDuty service. To start using GuardDuty, you must create a detector in each region that you enable the service. You can have only one detector per account per region.</p>
+    fn create_detector(
+        &self,
+        input: CreateDetectorRequest,
+    ) -> RusotoFuture<CreateDetectorResponse, CreateDetectorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/detector";
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateDetector");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDetector")
+                        .deserialize::<CreateDetectorResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDetectorError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a filter using the specified finding criteria.</p>
+    fn create_filter(
+        &self,
+        input: CreateFilterRequest,
+    ) -> RusotoFuture<CreateFilterResponse, CreateFilterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/filter",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateFilter");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateFilter")
+                        .deserialize::<CreateFilterResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateFilterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new IPSet - a list of trusted IP addresses that have been whitelisted for secure communication with AWS infrastructure and applications.</p>
+    fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/ipset",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateIPSet");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateIPSet")
+                        .deserialize::<CreateIPSetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateIPSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates member accounts of the current AWS account by specifying a list of AWS account IDs. The current AWS account can then invite these members to manage GuardDuty in their accounts.</p>
+    fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/member",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateMembers")
+                        .deserialize::<CreateMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Generates example findings of types specified by the list of finding types. If 'NULL' is specified for findingTypes, the API generates example findings of all supported finding types.</p>
+    fn create_sample_findings(
+        &self,
+        input: CreateSampleFindingsRequest,
+    ) -> RusotoFuture<CreateSampleFindingsResponse, CreateSampleFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/findings/create",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateSampleFindings");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateSampleFindings")
+                        .deserialize::<CreateSampleFindingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateSampleFindingsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Create a new ThreatIntelSet. ThreatIntelSets consist of known malicious IP addresses. GuardDuty generates findings based on ThreatIntelSets.</p>
+    fn create_threat_intel_set(
+        &self,
+        input: CreateThreatIntelSetRequest,
+    ) -> RusotoFuture<CreateThreatIntelSetResponse, CreateThreatIntelSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/threatintelset",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateThreatIntelSet");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateThreatIntelSet")
+                        .deserialize::<CreateThreatIntelSetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateThreatIntelSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Declines invitations sent to the current member account by AWS account specified by their account IDs.</p>
+    fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/invitation/decline";
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeclineInvitations");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeclineInvitations")
+                        .deserialize::<DeclineInvitationsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeclineInvitationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a Amazon GuardDuty detector specified by the detector ID.</p>
+    fn delete_detector(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> RusotoFuture<DeleteDetectorResponse, DeleteDetectorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
+
+        let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteDetector");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteDetector")
+                        .deserialize::<DeleteDetectorResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDetectorError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the filter specified by the filter name.</p>
+    fn delete_filter(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> RusotoFuture<DeleteFilterResponse, DeleteFilterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/filter/{filter_name}",
+            detector_id = input.detector_id,
+            filter_name = input.filter_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteFilter");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.add_resource_id("FilterName", &input.filter_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteFilter")
+                        .deserialize::<DeleteFilterResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFilterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the IPSet specified by the IPSet ID.</p>
+    fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/ipset/{ip_set_id}",
+            detector_id = input.detector_id,
+            ip_set_id = input.ip_set_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteIPSet");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.add_resource_id("IpSetId", &input.ip_set_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteIPSet")
+                        .deserialize::<DeleteIPSetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteIPSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes invitations sent to the current member account by AWS accounts specified by their account IDs.</p>
+    fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/invitation/delete";
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteInvitations");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteInvitations")
+                        .deserialize::<DeleteInvitationsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteInvitationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
+    fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/member/delete",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteMembers")
+                        .deserialize::<DeleteMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes ThreatIntelSet specified by the ThreatIntelSet ID.</p>
+    fn delete_threat_intel_set(
+        &self,
+        input: DeleteThreatIntelSetRequest,
+    ) -> RusotoFuture<DeleteThreatIntelSetResponse, DeleteThreatIntelSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/threatintelset/{threat_intel_set_id}",
+            detector_id = input.detector_id,
+            threat_intel_set_id = input.threat_intel_set_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteThreatIntelSet");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.add_resource_id("ThreatIntelSetId", &input.threat_intel_set_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteThreatIntelSet")
+                        .deserialize::<DeleteThreatIntelSetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteThreatIntelSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Disassociates the current GuardDuty member account from its master account.</p>
+    fn disassociate_from_master_account(
+        &self,
+        input: DisassociateFromMasterAccountRequest,
+    ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/master/disassociate",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisassociateFromMasterAccount");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateFromMasterAccount")
+                        .deserialize::<DisassociateFromMasterAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateFromMasterAccountError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
+    fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/member/disassociate",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisassociateMembers");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateMembers")
+                        .deserialize::<DisassociateMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DisassociateMembersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves an Amazon GuardDuty detector specified by the detectorId.</p>
+    fn get_detector(
+        &self,
+        input: GetDetectorRequest,
+    ) -> RusotoFuture<GetDetectorResponse, GetDetectorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetDetector");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDetector")
+                        .deserialize::<GetDetectorResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDetectorError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the details of the filter specified by the filter name.</p>
+    fn get_filter(
+        &self,
+        input: GetFilterRequest,
+    ) -> RusotoFuture<GetFilterResponse, GetFilterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/filter/{filter_name}",
+            detector_id = input.detector_id,
+            filter_name = input.filter_name
+        );
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFilter");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.add_resource_id("FilterName", &input.filter_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFilter")
+                        .deserialize::<GetFilterResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFilterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes Amazon GuardDuty findings specified by finding IDs.</p>
+    fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/findings/get",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFindings");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFindings")
+                        .deserialize::<GetFindingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFindingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists Amazon GuardDuty findings' statistics for the specified detector ID.</p>
+    fn get_findings_statistics(
+        &self,
+        input: GetFindingsStatisticsRequest,
+    ) -> RusotoFuture<GetFindingsStatisticsResponse, GetFindingsStatisticsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/findings/statistics",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFindingsStatistics");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFindingsStatistics")
+                        .deserialize::<GetFindingsStatisticsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetFindingsStatisticsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the IPSet specified by the IPSet ID.</p>
+    fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/ipset/{ip_set_id}",
+            detector_id = input.detector_id,
+            ip_set_id = input.ip_set_id
+        );
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetIPSet");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.add_resource_id("IpSetId", &input.ip_set_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIPSet")
+                        .deserialize::<GetIPSetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetIPSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the count of all GuardDuty membership invitations that were sent to the current member account except the currently accepted invitation.</p>
+    fn get_invitations_count(
+        &self,
+    ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
+        let request_uri = "/invitation/count";
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetInvitationsCount");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetInvitationsCount")
+                        .deserialize::<GetInvitationsCountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetInvitationsCountError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides the details for the GuardDuty master account associated with the current GuardDuty member account.</p>
+    fn get_master_account(
+        &self,
+        input: GetMasterAccountRequest,
+    ) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/master",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMasterAccount");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetMasterAccount")
+                        .deserialize::<GetMasterAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMasterAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
+    fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/member/get",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMembers");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetMembers")
+                        .deserialize::<GetMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the ThreatIntelSet that is specified by the ThreatIntelSet ID.</p>
+    fn get_threat_intel_set(
+        &self,
+        input: GetThreatIntelSetRequest,
+    ) -> RusotoFuture<GetThreatIntelSetResponse, GetThreatIntelSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/threatintelset/{threat_intel_set_id}",
+            detector_id = input.detector_id,
+            threat_intel_set_id = input.threat_intel_set_id
+        );
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetThreatIntelSet");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.add_resource_id("ThreatIntelSetId", &input.threat_intel_set_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetThreatIntelSet")
+                        .deserialize::<GetThreatIntelSetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetThreatIntelSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Invites other AWS accounts (created as members of the current AWS account by CreateMembers) to enable GuardDuty and allow the current AWS account to view and manage these accounts' GuardDuty findings on their behalf as the master account.</p>
+    fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/member/invite",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("InviteMembers");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("InviteMembers")
+                        .deserialize::<InviteMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(InviteMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists detectorIds of all the existing Amazon GuardDuty detector resources.</p>
+    fn list_detectors(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> RusotoFuture<ListDetectorsResponse, ListDetectorsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/detector";
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListDetectors");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDetectors")
+                        .deserialize::<ListDetectorsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDetectorsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a paginated list of the current filters.</p>
+    fn list_filters(
+        &self,
+        input: ListFiltersRequest,
+    ) -> RusotoFuture<ListFiltersResponse, ListFiltersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/filter",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListFilters");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListFilters")
+                        .deserialize::<ListFiltersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListFiltersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists Amazon GuardDuty findings for the specified detector ID.</p>
+    fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/findings",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListFindings");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListFindings")
+                        .deserialize::<ListFindingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListFindingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the IPSets of the GuardDuty service specified by the detector ID.</p>
+    fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/ipset",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListIPSets");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListIPSets")
+                        .deserialize::<ListIPSetsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListIPSetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all GuardDuty membership invitations that were sent to the current AWS account.</p>
+    fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/invitation";
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListInvitations")
+                        .deserialize::<ListInvitationsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListInvitationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists details about all member accounts for the current GuardDuty master account.</p>
+    fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/member",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListMembers");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        if let Some(ref x) = input.only_associated {
+            params.put("onlyAssociated", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListMembers")
+                        .deserialize::<ListMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists tags for a resource. Tagging is currently supported for detectors, finding filters, IP sets, and Threat Intel sets, with a limit of 50 tags per resource. When invoked, this operation returns all assigned tags for a given resource..</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
+                        .deserialize::<ListTagsForResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the ThreatIntelSets of the GuardDuty service specified by the detector ID.</p>
+    fn list_threat_intel_sets(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> RusotoFuture<ListThreatIntelSetsResponse, ListThreatIntelSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/threatintelset",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListThreatIntelSets");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListThreatIntelSets")
+                        .deserialize::<ListThreatIntelSetsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListThreatIntelSetsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Re-enables GuardDuty to monitor findings of the member accounts specified by the account IDs. A master GuardDuty account can run this command after disabling GuardDuty from monitoring these members' findings by running StopMonitoringMembers.</p>
+    fn start_monitoring_members(
+        &self,
+        input: StartMonitoringMembersRequest,
+    ) -> RusotoFuture<StartMonitoringMembersResponse, StartMonitoringMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/member/start",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("StartMonitoringMembers");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartMonitoringMembers")
+                        .deserialize::<StartMonitoringMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartMonitoringMembersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables GuardDuty from monitoring findings of the member accounts specified by the account IDs. After running this command, a master GuardDuty account can run StartMonitoringMembers to re-enable GuardDuty to monitor these members’ findings.</p>
+    fn stop_monitoring_members(
+        &self,
+        input: StopMonitoringMembersRequest,
+    ) -> RusotoFuture<StopMonitoringMembersResponse, StopMonitoringMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/member/stop",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("StopMonitoringMembers");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StopMonitoringMembers")
+                        .deserialize::<StopMonitoringMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StopMonitoringMembersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds tags to a resource.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
+                        .deserialize::<TagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Unarchives Amazon GuardDuty findings specified by the list of finding IDs.</p>
+    fn unarchive_findings(
+        &self,
+        input: UnarchiveFindingsRequest,
+    ) -> RusotoFuture<UnarchiveFindingsResponse, UnarchiveFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/findings/unarchive",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UnarchiveFindings");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UnarchiveFindings")
+                        .deserialize::<UnarchiveFindingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UnarchiveFindingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes tags from a resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
+                        .deserialize::<UntagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an Amazon GuardDuty detector specified by the detectorId.</p>
+    fn update_detector(
+        &self,
+        input: UpdateDetectorRequest,
+    ) -> RusotoFuture<UpdateDetectorResponse, UpdateDetectorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateDetector");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDetector")
+                        .deserialize::<UpdateDetectorResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDetectorError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the filter specified by the filter name.</p>
+    fn update_filter(
+        &self,
+        input: UpdateFilterRequest,
+    ) -> RusotoFuture<UpdateFilterResponse, UpdateFilterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/filter/{filter_name}",
+            detector_id = input.detector_id,
+            filter_name = input.filter_name
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateFilter");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.add_resource_id("FilterName", &input.filter_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateFilter")
+                        .deserialize::<UpdateFilterResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateFilterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Marks specified Amazon GuardDuty findings as useful or not useful.</p>
+    fn update_findings_feedback(
+        &self,
+        input: UpdateFindingsFeedbackRequest,
+    ) -> RusotoFuture<UpdateFindingsFeedbackResponse, UpdateFindingsFeedbackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/findings/feedback",
+            detector_id = input.detector_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateFindingsFeedback");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateFindingsFeedback")
+                        .deserialize::<UpdateFindingsFeedbackResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateFindingsFeedbackError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the IPSet specified by the IPSet ID.</p>
+    fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/ipset/{ip_set_id}",
+            detector_id = input.detector_id,
+            ip_set_id = input.ip_set_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateIPSet");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.add_resource_id("IpSetId", &input.ip_set_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateIPSet")
+                        .deserialize::<UpdateIPSetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateIPSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the ThreatIntelSet specified by ThreatIntelSet ID.</p>
+    fn update_threat_intel_set(
+        &self,
+        input: UpdateThreatIntelSetRequest,
+    ) -> RusotoFuture<UpdateThreatIntelSetResponse, UpdateThreatIntelSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/detector/{detector_id}/threatintelset/{threat_intel_set_id}",
+            detector_id = input.detector_id,
+            threat_intel_set_id = input.threat_intel_set_id
+        );
+
+        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateThreatIntelSet");
+        request.add_resource_id("DetectorId", &input.detector_id.to_string());
+        request.add_resource_id("ThreatIntelSetId", &input.threat_intel_set_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateThreatIntelSet")
+                        .deserialize::<UpdateThreatIntelSetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateThreatIntelSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon GuardDuty API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockGuardDuty {
+    accept_invitation: Option<
+        Box<
+            dyn Fn(
+                    AcceptInvitationRequest,
+                ) -> ::std::result::Result<
+                    AcceptInvitationResponse,
+                    RusotoError<AcceptInvitationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    archive_findings: Option<
+        Box<
+            dyn Fn(
+                    ArchiveFindingsRequest,
+                ) -> ::std::result::Result<
+                    ArchiveFindingsResponse,
+                    RusotoError<ArchiveFindingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_detector: Option<
+        Box<
+            dyn Fn(
+                    CreateDetectorRequest,
+                ) -> ::std::result::Result<
+                    CreateDetectorResponse,
+                    RusotoError<CreateDetectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_filter: Option<
+        Box<
+            dyn Fn(
+                    CreateFilterRequest,
+                )
+                    -> ::std::result::Result<CreateFilterResponse, RusotoError<CreateFilterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_ip_set: Option<
+        Box<
+            dyn Fn(
+                    CreateIPSetRequest,
+                )
+                    -> ::std::result::Result<CreateIPSetResponse, RusotoError<CreateIPSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_members: Option<
+        Box<
+            dyn Fn(
+                    CreateMembersRequest,
+                )
+                    -> ::std::result::Result<CreateMembersResponse, RusotoError<CreateMembersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_sample_findings: Option<
+        Box<
+            dyn Fn(
+                    CreateSampleFindingsRequest,
+                ) -> ::std::result::Result<
+                    CreateSampleFindingsResponse,
+                    RusotoError<CreateSampleFindingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_threat_intel_set: Option<
+        Box<
+            dyn Fn(
+                    CreateThreatIntelSetRequest,
+                ) -> ::std::result::Result<
+                    CreateThreatIntelSetResponse,
+                    RusotoError<CreateThreatIntelSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    decline_invitations: Option<
+        Box<
+            dyn Fn(
+                    DeclineInvitationsRequest,
+                ) -> ::std::result::Result<
+                    DeclineInvitationsResponse,
+                    RusotoError<DeclineInvitationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_detector: Option<
+        Box<
+            dyn Fn(
+                    DeleteDetectorRequest,
+                ) -> ::std::result::Result<
+                    DeleteDetectorResponse,
+                    RusotoError<DeleteDetectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_filter: Option<
+        Box<
+            dyn Fn(
+                    DeleteFilterRequest,
+                )
+                    -> ::std::result::Result<DeleteFilterResponse, RusotoError<DeleteFilterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_ip_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteIPSetRequest,
+                )
+                    -> ::std::result::Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_invitations: Option<
+        Box<
+            dyn Fn(
+                    DeleteInvitationsRequest,
+                ) -> ::std::result::Result<
+                    DeleteInvitationsResponse,
+                    RusotoError<DeleteInvitationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_members: Option<
+        Box<
+            dyn Fn(
+                    DeleteMembersRequest,
+                )
+                    -> ::std::result::Result<DeleteMembersResponse, RusotoError<DeleteMembersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_threat_intel_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteThreatIntelSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteThreatIntelSetResponse,
+                    RusotoError<DeleteThreatIntelSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_from_master_account: Option<
+        Box<
+            dyn Fn(
+                    DisassociateFromMasterAccountRequest,
+                ) -> ::std::result::Result<
+                    DisassociateFromMasterAccountResponse,
+                    RusotoError<DisassociateFromMasterAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_members: Option<
+        Box<
+            dyn Fn(
+                    DisassociateMembersRequest,
+                ) -> ::std::result::Result<
+                    DisassociateMembersResponse,
+                    RusotoError<DisassociateMembersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_detector: Option<
+        Box<
+            dyn Fn(
+                    GetDetectorRequest,
+                )
+                    -> ::std::result::Result<GetDetectorResponse, RusotoError<GetDetectorError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_filter: Option<
+        Box<
+            dyn Fn(
+                    GetFilterRequest,
+                )
+                    -> ::std::result::Result<GetFilterResponse, RusotoError<GetFilterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_findings: Option<
+        Box<
+            dyn Fn(
+                    GetFindingsRequest,
+                )
+                    -> ::std::result::Result<GetFindingsResponse, RusotoError<GetFindingsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_findings_statistics: Option<
+        Box<
+            dyn Fn(
+                    GetFindingsStatisticsRequest,
+                ) -> ::std::result::Result<
+                    GetFindingsStatisticsResponse,
+                    RusotoError<GetFindingsStatisticsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_ip_set: Option<
+        Box<
+            dyn Fn(
+                    GetIPSetRequest,
+                )
+                    -> ::std::result::Result<GetIPSetResponse, RusotoError<GetIPSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_invitations_count: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetInvitationsCountResponse,
+                    RusotoError<GetInvitationsCountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_master_account: Option<
+        Box<
+            dyn Fn(
+                    GetMasterAccountRequest,
+                ) -> ::std::result::Result<
+                    GetMasterAccountResponse,
+                    RusotoError<GetMasterAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_members: Option<
+        Box<
+            dyn Fn(
+                    GetMembersRequest,
+                )
+                    -> ::std::result::Result<GetMembersResponse, RusotoError<GetMembersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_threat_intel_set: Option<
+        Box<
+            dyn Fn(
+                    GetThreatIntelSetRequest,
+                ) -> ::std::result::Result<
+                    GetThreatIntelSetResponse,
+                    RusotoError<GetThreatIntelSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    invite_members: Option<
+        Box<
+            dyn Fn(
+                    InviteMembersRequest,
+                )
+                    -> ::std::result::Result<InviteMembersResponse, RusotoError<InviteMembersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_detectors: Option<
+        Box<
+            dyn Fn(
+                    ListDetectorsRequest,
+                )
+                    -> ::std::result::Result<ListDetectorsResponse, RusotoError<ListDetectorsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_filters: Option<
+        Box<
+            dyn Fn(
+                    ListFiltersRequest,
+                )
+                    -> ::std::result::Result<ListFiltersResponse, RusotoError<ListFiltersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_findings: Option<
+        Box<
+            dyn Fn(
+                    ListFindingsRequest,
+                )
+                    -> ::std::result::Result<ListFindingsResponse, RusotoError<ListFindingsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_ip_sets: Option<
+        Box<
+            dyn Fn(
+                    ListIPSetsRequest,
+                )
+                    -> ::std::result::Result<ListIPSetsResponse, RusotoError<ListIPSetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_invitations: Option<
+        Box<
+            dyn Fn(
+                    ListInvitationsRequest,
+                ) -> ::std::result::Result<
+                    ListInvitationsResponse,
+                    RusotoError<ListInvitationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_members: Option<
+        Box<
+            dyn Fn(
+                    ListMembersRequest,
+                )
+                    -> ::std::result::Result<ListMembersResponse, RusotoError<ListMembersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_threat_intel_sets: Option<
+        Box<
+            dyn Fn(
+                    ListThreatIntelSetsRequest,
+                ) -> ::std::result::Result<
+                    ListThreatIntelSetsResponse,
+                    RusotoError<ListThreatIntelSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_monitoring_members: Option<
+        Box<
+            dyn Fn(
+                    StartMonitoringMembersRequest,
+                ) -> ::std::result::Result<
+                    StartMonitoringMembersResponse,
+                    RusotoError<StartMonitoringMembersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_monitoring_members: Option<
+        Box<
+            dyn Fn(
+                    StopMonitoringMembersRequest,
+                ) -> ::std::result::Result<
+                    StopMonitoringMembersResponse,
+                    RusotoError<StopMonitoringMembersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    unarchive_findings: Option<
+        Box<
+            dyn Fn(
+                    UnarchiveFindingsRequest,
+                ) -> ::std::result::Result<
+                    UnarchiveFindingsResponse,
+                    RusotoError<UnarchiveFindingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_detector: Option<
+        Box<
+            dyn Fn(
+                    UpdateDetectorRequest,
+                ) -> ::std::result::Result<
+                    UpdateDetectorResponse,
+                    RusotoError<UpdateDetectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_filter: Option<
+        Box<
+            dyn Fn(
+                    UpdateFilterRequest,
+                )
+                    -> ::std::result::Result<UpdateFilterResponse, RusotoError<UpdateFilterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_findings_feedback: Option<
+        Box<
+            dyn Fn(
+                    UpdateFindingsFeedbackRequest,
+                ) -> ::std::result::Result<
+                    UpdateFindingsFeedbackResponse,
+                    RusotoError<UpdateFindingsFeedbackError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_ip_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateIPSetRequest,
+                )
+                    -> ::std::result::Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_threat_intel_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateThreatIntelSetRequest,
+                ) -> ::std::result::Result<
+                    UpdateThreatIntelSetResponse,
+                    RusotoError<UpdateThreatIntelSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockGuardDuty {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockGuardDuty {
+        Default::default()
+    }
+
+    /// Answers `accept_invitation` with what `handler` returns for its input.
+    pub fn with_accept_invitation<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                AcceptInvitationRequest,
+            ) -> ::std::result::Result<
+                AcceptInvitationResponse,
+                RusotoError<AcceptInvitationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.accept_invitation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `archive_findings` with what `handler` returns for its input.
+    pub fn with_archive_findings<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                ArchiveFindingsRequest,
+            )
+                -> ::std::result::Result<ArchiveFindingsResponse, RusotoError<ArchiveFindingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.archive_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_detector` with what `handler` returns for its input.
+    pub fn with_create_detector<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                CreateDetectorRequest,
+            )
+                -> ::std::result::Result<CreateDetectorResponse, RusotoError<CreateDetectorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_detector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_filter` with what `handler` returns for its input.
+    pub fn with_create_filter<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                CreateFilterRequest,
+            )
+                -> ::std::result::Result<CreateFilterResponse, RusotoError<CreateFilterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_ip_set` with what `handler` returns for its input.
+    pub fn with_create_ip_set<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                CreateIPSetRequest,
+            )
+                -> ::std::result::Result<CreateIPSetResponse, RusotoError<CreateIPSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_ip_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_members` with what `handler` returns for its input.
+    pub fn with_create_members<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                CreateMembersRequest,
+            )
+                -> ::std::result::Result<CreateMembersResponse, RusotoError<CreateMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_sample_findings` with what `handler` returns for its input.
+    pub fn with_create_sample_findings<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                CreateSampleFindingsRequest,
+            ) -> ::std::result::Result<
+                CreateSampleFindingsResponse,
+                RusotoError<CreateSampleFindingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_sample_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_threat_intel_set` with what `handler` returns for its input.
+    pub fn with_create_threat_intel_set<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                CreateThreatIntelSetRequest,
+            ) -> ::std::result::Result<
+                CreateThreatIntelSetResponse,
+                RusotoError<CreateThreatIntelSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_threat_intel_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `decline_invitations` with what `handler` returns for its input.
+    pub fn with_decline_invitations<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                DeclineInvitationsRequest,
+            ) -> ::std::result::Result<
+                DeclineInvitationsResponse,
+                RusotoError<DeclineInvitationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.decline_invitations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_detector` with what `handler` returns for its input.
+    pub fn with_delete_detector<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                DeleteDetectorRequest,
+            )
+                -> ::std::result::Result<DeleteDetectorResponse, RusotoError<DeleteDetectorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_detector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_filter` with what `handler` returns for its input.
+    pub fn with_delete_filter<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                DeleteFilterRequest,
+            )
+                -> ::std::result::Result<DeleteFilterResponse, RusotoError<DeleteFilterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_ip_set` with what `handler` returns for its input.
+    pub fn with_delete_ip_set<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                DeleteIPSetRequest,
+            )
+                -> ::std::result::Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_ip_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_invitations` with what `handler` returns for its input.
+    pub fn with_delete_invitations<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                DeleteInvitationsRequest,
+            ) -> ::std::result::Result<
+                DeleteInvitationsResponse,
+                RusotoError<DeleteInvitationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_invitations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_members` with what `handler` returns for its input.
+    pub fn with_delete_members<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                DeleteMembersRequest,
+            )
+                -> ::std::result::Result<DeleteMembersResponse, RusotoError<DeleteMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_threat_intel_set` with what `handler` returns for its input.
+    pub fn with_delete_threat_intel_set<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                DeleteThreatIntelSetRequest,
+            ) -> ::std::result::Result<
+                DeleteThreatIntelSetResponse,
+                RusotoError<DeleteThreatIntelSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_threat_intel_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_from_master_account` with what `handler` returns for its input.
+    pub fn with_disassociate_from_master_account<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                DisassociateFromMasterAccountRequest,
+            ) -> ::std::result::Result<
+                DisassociateFromMasterAccountResponse,
+                RusotoError<DisassociateFromMasterAccountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_from_master_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_members` with what `handler` returns for its input.
+    pub fn with_disassociate_members<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                DisassociateMembersRequest,
+            ) -> ::std::result::Result<
+                DisassociateMembersResponse,
+                RusotoError<DisassociateMembersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_detector` with what `handler` returns for its input.
+    pub fn with_get_detector<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                GetDetectorRequest,
+            )
+                -> ::std::result::Result<GetDetectorResponse, RusotoError<GetDetectorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_detector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_filter` with what `handler` returns for its input.
+    pub fn with_get_filter<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                GetFilterRequest,
+            ) -> ::std::result::Result<GetFilterResponse, RusotoError<GetFilterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_findings` with what `handler` returns for its input.
+    pub fn with_get_findings<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                GetFindingsRequest,
+            )
+                -> ::std::result::Result<GetFindingsResponse, RusotoError<GetFindingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_findings_statistics` with what `handler` returns for its input.
+    pub fn with_get_findings_statistics<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                GetFindingsStatisticsRequest,
+            ) -> ::std::result::Result<
+                GetFindingsStatisticsResponse,
+                RusotoError<GetFindingsStatisticsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_findings_statistics = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_ip_set` with what `handler` returns for its input.
+    pub fn with_get_ip_set<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                GetIPSetRequest,
+            ) -> ::std::result::Result<GetIPSetResponse, RusotoError<GetIPSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_ip_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_invitations_count` with what `handler` returns.
+    pub fn with_get_invitations_count<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn() -> ::std::result::Result<
+                GetInvitationsCountResponse,
+                RusotoError<GetInvitationsCountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_invitations_count = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_master_account` with what `handler` returns for its input.
+    pub fn with_get_master_account<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                GetMasterAccountRequest,
+            ) -> ::std::result::Result<
+                GetMasterAccountResponse,
+                RusotoError<GetMasterAccountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_master_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_members` with what `handler` returns for its input.
+    pub fn with_get_members<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                GetMembersRequest,
+            )
+                -> ::std::result::Result<GetMembersResponse, RusotoError<GetMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_threat_intel_set` with what `handler` returns for its input.
+    pub fn with_get_threat_intel_set<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                GetThreatIntelSetRequest,
+            ) -> ::std::result::Result<
+                GetThreatIntelSetResponse,
+                RusotoError<GetThreatIntelSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_threat_intel_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `invite_members` with what `handler` returns for its input.
+    pub fn with_invite_members<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                InviteMembersRequest,
+            )
+                -> ::std::result::Result<InviteMembersResponse, RusotoError<InviteMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.invite_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_detectors` with what `handler` returns for its input.
+    pub fn with_list_detectors<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                ListDetectorsRequest,
+            )
+                -> ::std::result::Result<ListDetectorsResponse, RusotoError<ListDetectorsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_detectors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_filters` with what `handler` returns for its input.
+    pub fn with_list_filters<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                ListFiltersRequest,
+            )
+                -> ::std::result::Result<ListFiltersResponse, RusotoError<ListFiltersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_filters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_findings` with what `handler` returns for its input.
+    pub fn with_list_findings<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                ListFindingsRequest,
+            )
+                -> ::std::result::Result<ListFindingsResponse, RusotoError<ListFindingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_ip_sets` with what `handler` returns for its input.
+    pub fn with_list_ip_sets<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                ListIPSetsRequest,
+            )
+                -> ::std::result::Result<ListIPSetsResponse, RusotoError<ListIPSetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_ip_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_invitations` with what `handler` returns for its input.
+    pub fn with_list_invitations<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                ListInvitationsRequest,
+            )
+                -> ::std::result::Result<ListInvitationsResponse, RusotoError<ListInvitationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_invitations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_members` with what `handler` returns for its input.
+    pub fn with_list_members<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                ListMembersRequest,
+            )
+                -> ::std::result::Result<ListMembersResponse, RusotoError<ListMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_threat_intel_sets` with what `handler` returns for its input.
+    pub fn with_list_threat_intel_sets<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                ListThreatIntelSetsRequest,
+            ) -> ::std::result::Result<
+                ListThreatIntelSetsResponse,
+                RusotoError<ListThreatIntelSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_threat_intel_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_monitoring_members` with what `handler` returns for its input.
+    pub fn with_start_monitoring_members<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                StartMonitoringMembersRequest,
+            ) -> ::std::result::Result<
+                StartMonitoringMembersResponse,
+                RusotoError<StartMonitoringMembersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_monitoring_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_monitoring_members` with what `handler` returns for its input.
+    pub fn with_stop_monitoring_members<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                StopMonitoringMembersRequest,
+            ) -> ::std::result::Result<
+                StopMonitoringMembersResponse,
+                RusotoError<StopMonitoringMembersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_monitoring_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unarchive_findings` with what `handler` returns for its input.
+    pub fn with_unarchive_findings<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                UnarchiveFindingsRequest,
+            ) -> ::std::result::Result<
+                UnarchiveFindingsResponse,
+                RusotoError<UnarchiveFindingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.unarchive_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_detector` with what `handler` returns for its input.
+    pub fn with_update_detector<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                UpdateDetectorRequest,
+            )
+                -> ::std::result::Result<UpdateDetectorResponse, RusotoError<UpdateDetectorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_detector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_filter` with what `handler` returns for its input.
+    pub fn with_update_filter<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                UpdateFilterRequest,
+            )
+                -> ::std::result::Result<UpdateFilterResponse, RusotoError<UpdateFilterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_findings_feedback` with what `handler` returns for its input.
+    pub fn with_update_findings_feedback<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                UpdateFindingsFeedbackRequest,
+            ) -> ::std::result::Result<
+                UpdateFindingsFeedbackResponse,
+                RusotoError<UpdateFindingsFeedbackError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_findings_feedback = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_ip_set` with what `handler` returns for its input.
+    pub fn with_update_ip_set<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                UpdateIPSetRequest,
+            )
+                -> ::std::result::Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_ip_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_threat_intel_set` with what `handler` returns for its input.
+    pub fn with_update_threat_intel_set<F>(mut self, handler: F) -> MockGuardDuty
+    where
+        F: Fn(
+                UpdateThreatIntelSetRequest,
+            ) -> ::std::result::Result<
+                UpdateThreatIntelSetResponse,
+                RusotoError<UpdateThreatIntelSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_threat_intel_set = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl GuardDuty for MockGuardDuty {
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
+        match self.accept_invitation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for accept_invitation"),
+        }
+    }
+    fn archive_findings(
+        &self,
+        input: ArchiveFindingsRequest,
+    ) -> RusotoFuture<ArchiveFindingsResponse, ArchiveFindingsError> {
+        match self.archive_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for archive_findings"),
+        }
+    }
+    fn create_detector(
+        &self,
+        input: CreateDetectorRequest,
+    ) -> RusotoFuture<CreateDetectorResponse, CreateDetectorError> {
+        match self.create_detector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for create_detector"),
+        }
+    }
+    fn create_filter(
+        &self,
+        input: CreateFilterRequest,
+    ) -> RusotoFuture<CreateFilterResponse, CreateFilterError> {
+        match self.create_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for create_filter"),
+        }
+    }
+    fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
+        match self.create_ip_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for create_ip_set"),
+        }
+    }
+    fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
+        match self.create_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for create_members"),
+        }
+    }
+    fn create_sample_findings(
+        &self,
+        input: CreateSampleFindingsRequest,
+    ) -> RusotoFuture<CreateSampleFindingsResponse, CreateSampleFindingsError> {
+        match self.create_sample_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for create_sample_findings"),
+        }
+    }
+    fn create_threat_intel_set(
+        &self,
+        input: CreateThreatIntelSetRequest,
+    ) -> RusotoFuture<CreateThreatIntelSetResponse, CreateThreatIntelSetError> {
+        match self.create_threat_intel_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for create_threat_intel_set"),
+        }
+    }
+    fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
+        match self.decline_invitations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for decline_invitations"),
+        }
+    }
+    fn delete_detector(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> RusotoFuture<DeleteDetectorResponse, DeleteDetectorError> {
+        match self.delete_detector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for delete_detector"),
+        }
+    }
+    fn delete_filter(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> RusotoFuture<DeleteFilterResponse, DeleteFilterError> {
+        match self.delete_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for delete_filter"),
+        }
+    }
+    fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
+        match self.delete_ip_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for delete_ip_set"),
+        }
+    }
+    fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
+        match self.delete_invitations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for delete_invitations"),
+        }
+    }
+    fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
+        match self.delete_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for delete_members"),
+        }
+    }
+    fn delete_threat_intel_set(
+        &self,
+        input: DeleteThreatIntelSetRequest,
+    ) -> RusotoFuture<DeleteThreatIntelSetResponse, DeleteThreatIntelSetError> {
+        match self.delete_threat_intel_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for delete_threat_intel_set"),
+        }
+    }
+    fn disassociate_from_master_account(
+        &self,
+        input: DisassociateFromMasterAccountRequest,
+    ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
+    {
+        match self.disassociate_from_master_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for disassociate_from_master_account"),
+        }
+    }
+    fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
+        match self.disassociate_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for disassociate_members"),
+        }
+    }
+    fn get_detector(
+        &self,
+        input: GetDetectorRequest,
+    ) -> RusotoFuture<GetDetectorResponse, GetDetectorError> {
+        match self.get_detector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for get_detector"),
+        }
+    }
+    fn get_filter(
+        &self,
+        input: GetFilterRequest,
+    ) -> RusotoFuture<GetFilterResponse, GetFilterError> {
+        match self.get_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for get_filter"),
+        }
+    }
+    fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
+        match self.get_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for get_findings"),
+        }
+    }
+    fn get_findings_statistics(
+        &self,
+        input: GetFindingsStatisticsRequest,
+    ) -> RusotoFuture<GetFindingsStatisticsResponse, GetFindingsStatisticsError> {
+        match self.get_findings_statistics {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for get_findings_statistics"),
+        }
+    }
+    fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
+        match self.get_ip_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for get_ip_set"),
+        }
+    }
+    fn get_invitations_count(
+        &self,
+    ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
+        match self.get_invitations_count {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockGuardDuty has no handler for get_invitations_count"),
+        }
+    }
+    fn get_master_account(
+        &self,
+        input: GetMasterAccountRequest,
+    ) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
+        match self.get_master_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for get_master_account"),
+        }
+    }
+    fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
+        match self.get_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for get_members"),
+        }
+    }
+    fn get_threat_intel_set(
+        &self,
+        input: GetThreatIntelSetRequest,
+    ) -> RusotoFuture<GetThreatIntelSetResponse, GetThreatIntelSetError> {
+        match self.get_threat_intel_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for get_threat_intel_set"),
+        }
+    }
+    fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
+        match self.invite_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for invite_members"),
+        }
+    }
+    fn list_detectors(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> RusotoFuture<ListDetectorsResponse, ListDetectorsError> {
+        match self.list_detectors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for list_detectors"),
+        }
+    }
+    fn list_filters(
+        &self,
+        input: ListFiltersRequest,
+    ) -> RusotoFuture<ListFiltersResponse, ListFiltersError> {
+        match self.list_filters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for list_filters"),
+        }
+    }
+    fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
+        match self.list_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for list_findings"),
+        }
+    }
+    fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
+        match self.list_ip_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for list_ip_sets"),
+        }
+    }
+    fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
+        match self.list_invitations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for list_invitations"),
+        }
+    }
+    fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
+        match self.list_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for list_members"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_threat_intel_sets(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> RusotoFuture<ListThreatIntelSetsResponse, ListThreatIntelSetsError> {
+        match self.list_threat_intel_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for list_threat_intel_sets"),
+        }
+    }
+    fn start_monitoring_members(
+        &self,
+        input: StartMonitoringMembersRequest,
+    ) -> RusotoFuture<StartMonitoringMembersResponse, StartMonitoringMembersError> {
+        match self.start_monitoring_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for start_monitoring_members"),
+        }
+    }
+    fn stop_monitoring_members(
+        &self,
+        input: StopMonitoringMembersRequest,
+    ) -> RusotoFuture<StopMonitoringMembersResponse, StopMonitoringMembersError> {
+        match self.stop_monitoring_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for stop_monitoring_members"),
+        }
     }
-
-    /// <p>Adds tags to a resource.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Unarchives Amazon GuardDuty findings specified by the list of finding IDs.</p>
     fn unarchive_findings(
         &self,
         input: UnarchiveFindingsRequest,
     ) -> RusotoFuture<UnarchiveFindingsResponse, UnarchiveFindingsError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/findings/unarchive",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UnarchiveFindingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UnarchiveFindingsError::from_response(response))),
-                )
-            }
-        })
+        match self.unarchive_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for unarchive_findings"),
+        }
     }
-
-    /// <p>Removes tags from a resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for untag_resource"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Updates an Amazon GuardDuty detector specified by the detectorId.</p>
     fn update_detector(
         &self,
         input: UpdateDetectorRequest,
     ) -> RusotoFuture<UpdateDetectorResponse, UpdateDetectorError> {
-        let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDetectorResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDetectorError::from_response(response))),
-                )
-            }
-        })
+        match self.update_detector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for update_detector"),
+        }
     }
-
-    /// <p>Updates the filter specified by the filter name.</p>
     fn update_filter(
         &self,
         input: UpdateFilterRequest,
     ) -> RusotoFuture<UpdateFilterResponse, UpdateFilterError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/filter/{filter_name}",
-            detector_id = input.detector_id,
-            filter_name = input.filter_name
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFilterResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateFilterError::from_response(response))),
-                )
-            }
-        })
+        match self.update_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for update_filter"),
+        }
     }
-
-    /// <p>Marks specified Amazon GuardDuty findings as useful or not useful.</p>
     fn update_findings_feedback(
         &self,
         input: UpdateFindingsFeedbackRequest,
     ) -> RusotoFuture<UpdateFindingsFeedbackResponse, UpdateFindingsFeedbackError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/findings/feedback",
-            detector_id = input.detector_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFindingsFeedbackResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateFindingsFeedbackError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_findings_feedback {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for update_findings_feedback"),
+        }
     }
-
-    /// <p>Updates the IPSet specified by the IPSet ID.</p>
     fn update_ip_set(
         &self,
         input: UpdateIPSetRequest,
     ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/ipset/{ip_set_id}",
-            detector_id = input.detector_id,
-            ip_set_id = input.ip_set_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateIPSetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateIPSetError::from_response(response))),
-                )
-            }
-        })
+        match self.update_ip_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for update_ip_set"),
+        }
     }
-
-    /// <p>Updates the ThreatIntelSet specified by ThreatIntelSet ID.</p>
     fn update_threat_intel_set(
         &self,
         input: UpdateThreatIntelSetRequest,
     ) -> RusotoFuture<UpdateThreatIntelSetResponse, UpdateThreatIntelSetError> {
-        let request_uri = format!(
-            "/detector/{detector_id}/threatintelset/{threat_intel_set_id}",
-            detector_id = input.detector_id,
-            threat_intel_set_id = input.threat_intel_set_id
-        );
-
-        let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateThreatIntelSetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateThreatIntelSetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_threat_intel_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockGuardDuty has no handler for update_threat_intel_set"),
+        }
     }
 }
@@ -0,0 +1,416 @@
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::generated::{
+    AWSHealth, AWSHealthClient, DateTimeRange, DescribeEventDetailsError,
+    DescribeEventDetailsRequest, DescribeEventTypesRequest, DescribeEventsError,
+    DescribeEventsRequest, DescribeEventsResponse, Event, EventFilter,
+};
+use rusoto_core::audit::AuditRecord;
+use rusoto_core::budget::{BudgetExceeded, BudgetScope, CallBudget};
+use rusoto_core::request::HttpDispatchErrorKind;
+use rusoto_core::signature::SignedRequestPayload;
+use rusoto_core::{Client, Region, RusotoError};
+use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+#[test]
+fn too_many_event_arns_fail_before_dispatch() {
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(200).with_request_checker(|_| {
+            panic!("oversized request was sent");
+        }),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+
+    let request = DescribeEventDetailsRequest {
+        event_arns: (0..11)
+            .map(|i| {
+                format!(
+                    "arn:aws:health:us-east-1::event/EC2/EC2_INSTANCE_RETIREMENT_SCHEDULED/{}",
+                    i
+                )
+            })
+            .collect(),
+        ..Default::default()
+    };
+    match client.describe_event_details(request).sync() {
+        Err(RusotoError::Validation { message, .. }) => assert_eq!(
+            message,
+            "event_arns: length 11 is greater than the maximum of 10"
+        ),
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn event_arns_within_limits_are_sent() {
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(200).with_body(r#"{"successfulSet": []}"#),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+
+    let request = DescribeEventDetailsRequest {
+        event_arns: vec![
+            "arn:aws:health:us-east-1::event/EC2/EC2_INSTANCE_RETIREMENT_SCHEDULED/1".to_owned(),
+        ],
+        ..Default::default()
+    };
+    assert!(client.describe_event_details(request).sync().is_ok());
+}
+
+fn describe_event_details_failing_with(
+    body: &'static str,
+) -> RusotoError<DescribeEventDetailsError> {
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(400).with_body(body),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let request = DescribeEventDetailsRequest {
+        event_arns: vec!["arn".to_owned()],
+        ..Default::default()
+    };
+    client.describe_event_details(request).sync().unwrap_err()
+}
+
+#[test]
+fn validation_errors_of_the_service_keep_their_field_list() {
+    let err = describe_event_details_failing_with(
+        r#"{"__type":"ValidationException","message":"Invalid request",
+        "fieldList":[{"name":"eventArns","message":"Value 'arn' is not a valid ARN"}]}"#,
+    );
+    match err {
+        RusotoError::Validation { message, fields } => {
+            assert_eq!(message, "Invalid request");
+            assert_eq!(fields.len(), 1);
+            assert_eq!(fields[0].field, "eventArns");
+            assert_eq!(fields[0].message, "Value 'arn' is not a valid ARN");
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn validation_errors_of_the_service_without_details() {
+    let err = describe_event_details_failing_with(
+        r#"{"__type":"ValidationException","message":"Invalid request"}"#,
+    );
+    assert_eq!(
+        err,
+        RusotoError::Validation {
+            message: "Invalid request".to_owned(),
+            fields: Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn requests_round_trip_through_json_as_sent() {
+    let request = DescribeEventsRequest {
+        filter: Some(EventFilter {
+            services: Some(vec!["EC2".to_owned()]),
+            start_times: Some(vec![DateTimeRange {
+                from: Some(1_546_300_800.0),
+                to: None,
+            }]),
+            ..Default::default()
+        }),
+        max_results: Some(10),
+        ..Default::default()
+    };
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let body = sent.clone();
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(200)
+            .with_body("{}")
+            .with_request_checker(move |request| match request.payload {
+                Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                    *body.lock().unwrap() = buffer.to_vec()
+                }
+                _ => panic!("request without a body"),
+            }),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    client.describe_events(request.clone()).sync().unwrap();
+
+    let sent = sent.lock().unwrap();
+    assert_eq!(serde_json::to_vec(&request).unwrap(), *sent);
+    let reloaded: DescribeEventsRequest = serde_json::from_slice(&sent).unwrap();
+    assert_eq!(reloaded, request);
+}
+
+#[test]
+fn responses_round_trip_through_json() {
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(200).with_body(
+            r#"{"events": [{"arn": "arn:aws:health:us-east-1::event/EC2/EC2_INSTANCE_RETIREMENT_SCHEDULED/1",
+            "service": "EC2", "statusCode": "upcoming", "startTime": 1546300800.0}]}"#,
+        ),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let response = client
+        .describe_events(DescribeEventsRequest::default())
+        .sync()
+        .unwrap();
+    assert_eq!(
+        response.events,
+        Some(vec![Event {
+            arn: Some(
+                "arn:aws:health:us-east-1::event/EC2/EC2_INSTANCE_RETIREMENT_SCHEDULED/1"
+                    .to_owned()
+            ),
+            service: Some("EC2".to_owned()),
+            status_code: Some("upcoming".to_owned()),
+            start_time: Some(1_546_300_800.0),
+            ..Default::default()
+        }])
+    );
+
+    let stored = serde_json::to_string(&response).unwrap();
+    let reloaded: DescribeEventsResponse = serde_json::from_str(&stored).unwrap();
+    assert_eq!(reloaded, response);
+}
+
+#[test]
+fn customized_requests_sign_their_added_headers() {
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(200)
+            .with_body("{}")
+            .with_request_checker(|request| {
+                assert_eq!(
+                    request.headers["x-amz-expected-bucket-owner"],
+                    vec![b"123456789012".to_vec()]
+                );
+                let authorization =
+                    String::from_utf8(request.headers["authorization"][0].clone()).unwrap();
+                let signed_headers = authorization
+                    .split(", ")
+                    .find(|part| part.starts_with("SignedHeaders="))
+                    .unwrap();
+                assert!(
+                    signed_headers
+                        .trim_start_matches("SignedHeaders=")
+                        .split(';')
+                        .any(|name| name == "x-amz-expected-bucket-owner"),
+                    "{}",
+                    authorization
+                );
+            }),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    client
+        .describe_events(DescribeEventsRequest::default())
+        .customize(|request| request.add_header("x-amz-expected-bucket-owner", "123456789012"))
+        .sync()
+        .unwrap();
+}
+
+/// A token with characters that URL decoding or trimming would alter.
+const PAGINATION_TOKEN: &str = " AbC+dEf/gH==\n\t%2B ";
+
+#[test]
+fn pagination_tokens_round_trip_byte_for_byte() {
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let body = sent.clone();
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(200)
+            .with_body(&serde_json::json!({ "nextToken": PAGINATION_TOKEN }).to_string())
+            .with_request_checker(move |request| match request.payload {
+                Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                    *body.lock().unwrap() = buffer.to_vec()
+                }
+                _ => panic!("request without a body"),
+            }),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let request = DescribeEventsRequest {
+        next_token: Some(PAGINATION_TOKEN.to_owned()),
+        ..Default::default()
+    };
+    let response = client.describe_events(request).sync().unwrap();
+
+    let sent: serde_json::Value = serde_json::from_slice(&sent.lock().unwrap()).unwrap();
+    assert_eq!(sent["nextToken"], PAGINATION_TOKEN);
+    assert_eq!(
+        response.next_token.as_ref().map(String::as_str),
+        Some(PAGINATION_TOKEN)
+    );
+}
+
+#[test]
+fn corrupted_pagination_tokens_fail_with_their_error() {
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(400).with_body(
+            r#"{"__type":"InvalidPaginationToken","message":"Invalid pagination token"}"#,
+        ),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let request = DescribeEventsRequest {
+        next_token: Some("AbC dEf gH==".to_owned()),
+        ..Default::default()
+    };
+    assert_eq!(
+        client.describe_events(request).sync().unwrap_err(),
+        RusotoError::Service(DescribeEventsError::InvalidPaginationToken(
+            "Invalid pagination token".to_owned()
+        ))
+    );
+}
+
+const CAPTIVE_PORTAL_PAGE: &str =
+    "<!DOCTYPE html><html><head><title>Sign in to the network</title></head></html>";
+
+#[test]
+fn html_pages_fail_with_their_content_type() {
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(200)
+            .with_header("content-type", "text/html; charset=UTF-8")
+            .with_body(CAPTIVE_PORTAL_PAGE),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let err = client
+        .describe_events(DescribeEventsRequest::default())
+        .sync()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "response is not in the format of the service, it may come from a proxy \
+             (200 text/html; charset=UTF-8): {}",
+            CAPTIVE_PORTAL_PAGE
+        )
+    );
+    match err {
+        RusotoError::UnexpectedContentType {
+            status,
+            content_type,
+            body,
+        } => {
+            assert_eq!(status.as_u16(), 200);
+            assert_eq!(content_type, "text/html; charset=UTF-8");
+            assert_eq!(body, CAPTIVE_PORTAL_PAGE);
+        }
+        other => panic!("expected an unexpected content type, got {:?}", other),
+    }
+}
+
+#[test]
+fn mismatched_responses_fail_with_the_path_of_the_field() {
+    let body = r#"{"events": [{"arn": "arn:aws:health:us-east-1::event/1"}, {"arn": 42}]}"#;
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(200)
+            .with_header("content-type", "application/x-amz-json-1.1")
+            .with_body(body),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    match client
+        .describe_events(DescribeEventsRequest::default())
+        .sync()
+    {
+        Err(RusotoError::ParseError(message)) => assert_eq!(
+            message,
+            format!(
+                "Failed to parse the response of DescribeEvents at `events[1].arn`: \
+                 invalid type: integer `42`, expected a string at line 1 column 68; body: {}",
+                body
+            )
+        ),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn unparseable_errors_display_their_status_and_body() {
+    let client = AWSHealthClient::new_with(
+        MockRequestDispatcher::with_status(403).with_body("Forbidden by proxy policy"),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let err = client
+        .describe_events(DescribeEventsRequest::default())
+        .sync()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "HTTP 403: Forbidden by proxy policy");
+    match err {
+        RusotoError::Unknown(response) => assert_eq!(response.status.as_u16(), 403),
+        other => panic!("expected an unknown error, got {:?}", other),
+    }
+}
+
+#[test]
+fn audit_records_name_the_operation() {
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let recorded = records.clone();
+    let client = AWSHealthClient::new_with_client(
+        Client::new_with(
+            MockCredentialsProvider,
+            MockRequestDispatcher::with_status(200).with_body(r#"{"events": []}"#),
+        )
+        .with_audit_sink(move |record: &AuditRecord| recorded.lock().unwrap().push(record.clone())),
+        Region::UsEast1,
+    );
+    client
+        .describe_events(DescribeEventsRequest::default())
+        .sync()
+        .unwrap();
+
+    let records = records.lock().unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].service, "health");
+    assert_eq!(records[0].operation, Some("DescribeEvents".to_owned()));
+    // every call of a JSON API is a POST, so only the operation tells reads apart
+    assert_eq!(records[0].method, "POST");
+    assert!(records[0].is_read_only());
+}
+
+#[test]
+fn operation_limits_of_call_budgets_apply() {
+    let budget = CallBudget::new(Duration::from_secs(60)).with_operation_limit(
+        "health",
+        "DescribeEvents",
+        1,
+    );
+    let client = AWSHealthClient::new_with_client(
+        Client::new_with(
+            MockCredentialsProvider,
+            MockRequestDispatcher::with_status(200).with_body("{}"),
+        )
+        .with_call_budget(budget),
+        Region::UsEast1,
+    );
+    assert!(client
+        .describe_events(DescribeEventsRequest::default())
+        .sync()
+        .is_ok());
+
+    let err = match client
+        .describe_events(DescribeEventsRequest::default())
+        .sync()
+    {
+        Err(RusotoError::HttpDispatch(err)) => err,
+        other => panic!("expected a dispatch error, got {:?}", other),
+    };
+    assert_eq!(err.kind(), HttpDispatchErrorKind::BudgetExceeded);
+    let exceeded = err
+        .source()
+        .and_then(|source| source.downcast_ref::<BudgetExceeded>())
+        .unwrap();
+    assert_eq!(exceeded.operation, Some("DescribeEvents".to_owned()));
+    assert_eq!(exceeded.scope, BudgetScope::Operation);
+
+    // other operations of the service aren't limited
+    assert!(client
+        .describe_event_types(DescribeEventTypesRequest::default())
+        .sync()
+        .is_ok());
+}
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Information about an entity that is affected by a Health event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AffectedEntity {
     /// <p>The 12-digit AWS account number that contains the affected entity.</p>
     #[serde(rename = "awsAccountId")]
@@ -62,6 +63,7 @@ pub struct AffectedEntity {
 
 /// <p>A range of dates and times that is used by the <a>EventFilter</a> and <a>EntityFilter</a> objects. If <code>from</code> is set and <code>to</code> is set: match items where the timestamp (<code>startTime</code>, <code>endTime</code>, or <code>lastUpdatedTime</code>) is between <code>from</code> and <code>to</code> inclusive. If <code>from</code> is set and <code>to</code> is not set: match items where the timestamp value is equal to or after <code>from</code>. If <code>from</code> is not set and <code>to</code> is set: match items where the timestamp value is equal to or before <code>to</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DateTimeRange {
     /// <p>The starting date and time of a time range.</p>
     #[serde(rename = "from")]
@@ -74,6 +76,7 @@ pub struct DateTimeRange {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAffectedEntitiesRequest {
     /// <p>Values to narrow the results returned. At least one event ARN is required. </p>
     #[serde(rename = "filter")]
@@ -92,8 +95,44 @@ pub struct DescribeAffectedEntitiesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAffectedEntitiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.filter.validate_at(&field_path(path, "filter"), errors);
+        if let Some(ref value) = self.locale {
+            errors.check_length(
+                &field_path(path, "locale"),
+                value.chars().count(),
+                Some(2),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "locale"), &value, ".{2,256}");
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(10000),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[a-zA-Z0-9=/+_.-]{4,10000}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAffectedEntitiesResponse {
     /// <p>The entities that match the filter criteria.</p>
     #[serde(rename = "entities")]
@@ -106,6 +145,7 @@ pub struct DescribeAffectedEntitiesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEntityAggregatesRequest {
     /// <p>A list of event ARNs (unique identifiers). For example: <code>"arn:aws:health:us-east-1::event/EC2/EC2_INSTANCE_RETIREMENT_SCHEDULED/EC2_INSTANCE_RETIREMENT_SCHEDULED_ABC123-CDE456", "arn:aws:health:us-west-1::event/EBS/AWS_EBS_LOST_VOLUME/AWS_EBS_LOST_VOLUME_CHI789_JKL101"</code> </p>
     #[serde(rename = "eventArns")]
@@ -113,8 +153,30 @@ pub struct DescribeEntityAggregatesRequest {
     pub event_arns: Option<Vec<String>>,
 }
 
+impl Validate for DescribeEntityAggregatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_arns {
+            errors.check_length(
+                &field_path(path, "event_arns"),
+                value.len(),
+                Some(1),
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "event_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1600));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "arn:aws(-[a-z]+(-[a-z]+)?)?:health:[^:]*:[^:]*:event(?:/[\\w-]+){3}",
+                );
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEntityAggregatesResponse {
     /// <p>The number of entities that are affected by each of the specified events.</p>
     #[serde(rename = "entityAggregates")]
@@ -123,6 +185,7 @@ pub struct DescribeEntityAggregatesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventAggregatesRequest {
     /// <p>The only currently supported value is <code>eventTypeCategory</code>.</p>
     #[serde(rename = "aggregateField")]
@@ -141,8 +204,37 @@ pub struct DescribeEventAggregatesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeEventAggregatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(10000),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[a-zA-Z0-9=/+_.-]{4,10000}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventAggregatesResponse {
     /// <p>The number of events in each category that meet the optional filter criteria.</p>
     #[serde(rename = "eventAggregates")]
@@ -155,6 +247,7 @@ pub struct DescribeEventAggregatesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventDetailsRequest {
     /// <p>A list of event ARNs (unique identifiers). For example: <code>"arn:aws:health:us-east-1::event/EC2/EC2_INSTANCE_RETIREMENT_SCHEDULED/EC2_INSTANCE_RETIREMENT_SCHEDULED_ABC123-CDE456", "arn:aws:health:us-west-1::event/EBS/AWS_EBS_LOST_VOLUME/AWS_EBS_LOST_VOLUME_CHI789_JKL101"</code> </p>
     #[serde(rename = "eventArns")]
@@ -165,8 +258,37 @@ pub struct DescribeEventDetailsRequest {
     pub locale: Option<String>,
 }
 
+impl Validate for DescribeEventDetailsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "event_arns"),
+            self.event_arns.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.event_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "event_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1600));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "arn:aws(-[a-z]+(-[a-z]+)?)?:health:[^:]*:[^:]*:event(?:/[\\w-]+){3}",
+            );
+        }
+        if let Some(ref value) = self.locale {
+            errors.check_length(
+                &field_path(path, "locale"),
+                value.chars().count(),
+                Some(2),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "locale"), &value, ".{2,256}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventDetailsResponse {
     /// <p>Error messages for any events that could not be retrieved.</p>
     #[serde(rename = "failedSet")]
@@ -179,6 +301,7 @@ pub struct DescribeEventDetailsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventTypesRequest {
     /// <p>Values to narrow the results returned.</p>
     #[serde(rename = "filter")]
@@ -198,8 +321,46 @@ pub struct DescribeEventTypesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeEventTypesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.locale {
+            errors.check_length(
+                &field_path(path, "locale"),
+                value.chars().count(),
+                Some(2),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "locale"), &value, ".{2,256}");
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(10000),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[a-zA-Z0-9=/+_.-]{4,10000}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventTypesResponse {
     /// <p>A list of event types that match the filter criteria. Event types have a category (<code>issue</code>, <code>accountNotification</code>, or <code>scheduledChange</code>), a service (for example, <code>EC2</code>, <code>RDS</code>, <code>DATAPIPELINE</code>, <code>BILLING</code>), and a code (in the format <code>AWS_<i>SERVICE</i>_<i>DESCRIPTION</i> </code>; for example, <code>AWS_EC2_SYSTEM_MAINTENANCE_EVENT</code>).</p>
     #[serde(rename = "eventTypes")]
@@ -212,6 +373,7 @@ pub struct DescribeEventTypesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventsRequest {
     /// <p>Values to narrow the results returned.</p>
     #[serde(rename = "filter")]
@@ -231,8 +393,46 @@ pub struct DescribeEventsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.locale {
+            errors.check_length(
+                &field_path(path, "locale"),
+                value.chars().count(),
+                Some(2),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "locale"), &value, ".{2,256}");
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(10000),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[a-zA-Z0-9=/+_.-]{4,10000}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventsResponse {
     /// <p>The events that match the specified filter criteria.</p>
     #[serde(rename = "events")]
@@ -246,7 +446,7 @@ pub struct DescribeEventsResponse {
 
 /// <p>The number of entities that are affected by one or more events. Returned by the <a>DescribeEntityAggregates</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EntityAggregate {
     /// <p>The number entities that match the criteria for the specified events.</p>
     #[serde(rename = "count")]
@@ -260,6 +460,7 @@ pub struct EntityAggregate {
 
 /// <p>The values to use to filter results from the <a>DescribeAffectedEntities</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EntityFilter {
     /// <p>A list of entity ARNs (unique identifiers).</p>
     #[serde(rename = "entityArns")]
@@ -286,9 +487,83 @@ pub struct EntityFilter {
     pub tags: Option<Vec<::std::collections::HashMap<String, String>>>,
 }
 
+impl Validate for EntityFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.entity_arns {
+            errors.check_length(
+                &field_path(path, "entity_arns"),
+                value.len(),
+                Some(1),
+                Some(99),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "entity_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1600));
+                errors.check_pattern(&item_path, &item, ".{0,1600}");
+            }
+        }
+        if let Some(ref value) = self.entity_values {
+            errors.check_length(
+                &field_path(path, "entity_values"),
+                value.len(),
+                Some(1),
+                Some(99),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "entity_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1224));
+                errors.check_pattern(&item_path, &item, ".{0,1224}");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "event_arns"),
+            self.event_arns.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.event_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "event_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1600));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "arn:aws(-[a-z]+(-[a-z]+)?)?:health:[^:]*:[^:]*:event(?:/[\\w-]+){3}",
+            );
+        }
+        if let Some(ref value) = self.last_updated_times {
+            errors.check_length(
+                &field_path(path, "last_updated_times"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.status_codes {
+            errors.check_length(
+                &field_path(path, "status_codes"),
+                value.len(),
+                Some(1),
+                Some(5),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                errors.check_length(&item_path, item.len(), None, Some(50));
+                for (key2, item2) in item.iter() {
+                    let item_path2 = format!("{}[{}]", item_path, key2);
+                    errors.check_length(&item_path2, item2.chars().count(), None, Some(255));
+                    errors.check_pattern(&item_path2, &item2, ".{0,255}");
+                }
+            }
+        }
+    }
+}
+
 /// <p>Summary information about an event, returned by the <a>DescribeEvents</a> operation. The <a>DescribeEventDetails</a> operation also returns this information, as well as the <a>EventDescription</a> and additional event metadata.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Event {
     /// <p>The unique identifier for the event. Format: <code>arn:aws:health:<i>event-region</i>::event/<i>SERVICE</i>/<i>EVENT_TYPE_CODE</i>/<i>EVENT_TYPE_PLUS_ID</i> </code>. Example: <code>Example: arn:aws:health:us-east-1::event/EC2/EC2_INSTANCE_RETIREMENT_SCHEDULED/EC2_INSTANCE_RETIREMENT_SCHEDULED_ABC123-DEF456</code> </p>
     #[serde(rename = "arn")]
@@ -334,7 +609,7 @@ pub struct Event {
 
 /// <p>The number of events of each issue type. Returned by the <a>DescribeEventAggregates</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventAggregate {
     /// <p>The issue type for the associated count.</p>
     #[serde(rename = "aggregateValue")]
@@ -348,7 +623,7 @@ pub struct EventAggregate {
 
 /// <p>Detailed information about an event. A combination of an <a>Event</a> object, an <a>EventDescription</a> object, and additional metadata about the event. Returned by the <a>DescribeEventDetails</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventDetails {
     /// <p>Summary information about the event.</p>
     #[serde(rename = "event")]
@@ -366,7 +641,7 @@ pub struct EventDetails {
 
 /// <p>Error information returned when a <a>DescribeEventDetails</a> operation cannot find a specified event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventDetailsErrorItem {
     /// <p>A message that describes the error.</p>
     #[serde(rename = "errorMessage")]
@@ -384,6 +659,7 @@ pub struct EventDetailsErrorItem {
 
 /// <p>The values to use to filter results from the <a>DescribeEvents</a> and <a>DescribeEventAggregates</a> operations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventFilter {
     /// <p>A list of AWS availability zones.</p>
     #[serde(rename = "availabilityZones")]
@@ -439,8 +715,154 @@ pub struct EventFilter {
     pub tags: Option<Vec<::std::collections::HashMap<String, String>>>,
 }
 
+impl Validate for EventFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.availability_zones {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "availability_zones"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(6), Some(18));
+                errors.check_pattern(&item_path, &item, "[a-z]{2}\\-[0-9a-z\\-]{4,16}");
+            }
+        }
+        if let Some(ref value) = self.end_times {
+            errors.check_length(
+                &field_path(path, "end_times"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.entity_arns {
+            errors.check_length(
+                &field_path(path, "entity_arns"),
+                value.len(),
+                Some(1),
+                Some(99),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "entity_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1600));
+                errors.check_pattern(&item_path, &item, ".{0,1600}");
+            }
+        }
+        if let Some(ref value) = self.entity_values {
+            errors.check_length(
+                &field_path(path, "entity_values"),
+                value.len(),
+                Some(1),
+                Some(99),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "entity_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1224));
+                errors.check_pattern(&item_path, &item, ".{0,1224}");
+            }
+        }
+        if let Some(ref value) = self.event_arns {
+            errors.check_length(
+                &field_path(path, "event_arns"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "event_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1600));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "arn:aws(-[a-z]+(-[a-z]+)?)?:health:[^:]*:[^:]*:event(?:/[\\w-]+){3}",
+                );
+            }
+        }
+        if let Some(ref value) = self.event_status_codes {
+            errors.check_length(
+                &field_path(path, "event_status_codes"),
+                value.len(),
+                Some(1),
+                Some(6),
+            );
+        }
+        if let Some(ref value) = self.event_type_categories {
+            errors.check_length(
+                &field_path(path, "event_type_categories"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "event_type_categories"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(3), Some(255));
+            }
+        }
+        if let Some(ref value) = self.event_type_codes {
+            errors.check_length(
+                &field_path(path, "event_type_codes"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "event_type_codes"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(3), Some(100));
+                errors.check_pattern(&item_path, &item, "[^:/]{3,100}");
+            }
+        }
+        if let Some(ref value) = self.last_updated_times {
+            errors.check_length(
+                &field_path(path, "last_updated_times"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.regions {
+            errors.check_length(&field_path(path, "regions"), value.len(), Some(1), Some(10));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "regions"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(2), Some(25));
+                errors.check_pattern(&item_path, &item, "[^:/]{2,25}");
+            }
+        }
+        if let Some(ref value) = self.services {
+            errors.check_length(
+                &field_path(path, "services"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "services"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(2), Some(30));
+                errors.check_pattern(&item_path, &item, "[^:/]{2,30}");
+            }
+        }
+        if let Some(ref value) = self.start_times {
+            errors.check_length(
+                &field_path(path, "start_times"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                errors.check_length(&item_path, item.len(), None, Some(50));
+                for (key2, item2) in item.iter() {
+                    let item_path2 = format!("{}[{}]", item_path, key2);
+                    errors.check_length(&item_path2, item2.chars().count(), None, Some(255));
+                    errors.check_pattern(&item_path2, &item2, ".{0,255}");
+                }
+            }
+        }
+    }
+}
+
 /// <p>The values to use to filter results from the <a>DescribeEventTypes</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventTypeFilter {
     /// <p>A list of event type category codes (<code>issue</code>, <code>scheduledChange</code>, or <code>accountNotification</code>).</p>
     #[serde(rename = "eventTypeCategories")]
@@ -456,6 +878,49 @@ pub struct EventTypeFilter {
     pub services: Option<Vec<String>>,
 }
 
+impl Validate for EventTypeFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_type_categories {
+            errors.check_length(
+                &field_path(path, "event_type_categories"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "event_type_categories"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(3), Some(255));
+            }
+        }
+        if let Some(ref value) = self.event_type_codes {
+            errors.check_length(
+                &field_path(path, "event_type_codes"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "event_type_codes"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(3), Some(100));
+                errors.check_pattern(&item_path, &item, "[a-zA-Z0-9\\_\\-]{3,100}");
+            }
+        }
+        if let Some(ref value) = self.services {
+            errors.check_length(
+                &field_path(path, "services"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "services"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(2), Some(30));
+                errors.check_pattern(&item_path, &item, "[^:/]{2,30}");
+            }
+        }
+    }
+}
+
 /// Errors returned by DescribeAffectedEntities
 #[derive(Debug, PartialEq)]
 pub enum DescribeAffectedEntitiesError {
@@ -479,7 +944,12 @@ impl DescribeAffectedEntitiesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -507,7 +977,12 @@ impl DescribeEntityAggregatesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEntityAggregatesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -540,7 +1015,12 @@ impl DescribeEventAggregatesError {
                         DescribeEventAggregatesError::InvalidPaginationToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -575,7 +1055,12 @@ impl DescribeEventDetailsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -617,7 +1102,12 @@ impl DescribeEventTypesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -658,7 +1148,12 @@ impl DescribeEventsError {
                 "UnsupportedLocale" => {
                     return RusotoError::Service(DescribeEventsError::UnsupportedLocale(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -716,6 +1211,121 @@ pub trait AWSHealth {
         input: DescribeEventsRequest,
     ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError>;
 }
+const _: Option<&dyn AWSHealth> = None;
+impl<'a, T: AWSHealth + ?Sized> AWSHealth for &'a T {
+    fn describe_affected_entities(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> RusotoFuture<DescribeAffectedEntitiesResponse, DescribeAffectedEntitiesError> {
+        (**self).describe_affected_entities(input)
+    }
+    fn describe_entity_aggregates(
+        &self,
+        input: DescribeEntityAggregatesRequest,
+    ) -> RusotoFuture<DescribeEntityAggregatesResponse, DescribeEntityAggregatesError> {
+        (**self).describe_entity_aggregates(input)
+    }
+    fn describe_event_aggregates(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> RusotoFuture<DescribeEventAggregatesResponse, DescribeEventAggregatesError> {
+        (**self).describe_event_aggregates(input)
+    }
+    fn describe_event_details(
+        &self,
+        input: DescribeEventDetailsRequest,
+    ) -> RusotoFuture<DescribeEventDetailsResponse, DescribeEventDetailsError> {
+        (**self).describe_event_details(input)
+    }
+    fn describe_event_types(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> RusotoFuture<DescribeEventTypesResponse, DescribeEventTypesError> {
+        (**self).describe_event_types(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+}
+impl<T: AWSHealth + ?Sized> AWSHealth for Box<T> {
+    fn describe_affected_entities(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> RusotoFuture<DescribeAffectedEntitiesResponse, DescribeAffectedEntitiesError> {
+        (**self).describe_affected_entities(input)
+    }
+    fn describe_entity_aggregates(
+        &self,
+        input: DescribeEntityAggregatesRequest,
+    ) -> RusotoFuture<DescribeEntityAggregatesResponse, DescribeEntityAggregatesError> {
+        (**self).describe_entity_aggregates(input)
+    }
+    fn describe_event_aggregates(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> RusotoFuture<DescribeEventAggregatesResponse, DescribeEventAggregatesError> {
+        (**self).describe_event_aggregates(input)
+    }
+    fn describe_event_details(
+        &self,
+        input: DescribeEventDetailsRequest,
+    ) -> RusotoFuture<DescribeEventDetailsResponse, DescribeEventDetailsError> {
+        (**self).describe_event_details(input)
+    }
+    fn describe_event_types(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> RusotoFuture<DescribeEventTypesResponse, DescribeEventTypesError> {
+        (**self).describe_event_types(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+}
+impl<T: AWSHealth + ?Sized> AWSHealth for ::std::sync::Arc<T> {
+    fn describe_affected_entities(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> RusotoFuture<DescribeAffectedEntitiesResponse, DescribeAffectedEntitiesError> {
+        (**self).describe_affected_entities(input)
+    }
+    fn describe_entity_aggregates(
+        &self,
+        input: DescribeEntityAggregatesRequest,
+    ) -> RusotoFuture<DescribeEntityAggregatesResponse, DescribeEntityAggregatesError> {
+        (**self).describe_entity_aggregates(input)
+    }
+    fn describe_event_aggregates(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> RusotoFuture<DescribeEventAggregatesResponse, DescribeEventAggregatesError> {
+        (**self).describe_event_aggregates(input)
+    }
+    fn describe_event_details(
+        &self,
+        input: DescribeEventDetailsRequest,
+    ) -> RusotoFuture<DescribeEventDetailsResponse, DescribeEventDetailsError> {
+        (**self).describe_event_details(input)
+    }
+    fn describe_event_types(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> RusotoFuture<DescribeEventTypesResponse, DescribeEventTypesError> {
+        (**self).describe_event_types(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+}
 /// A client for the AWSHealth API.
 #[derive(Clone)]
 pub struct AWSHealthClient {
@@ -759,7 +1369,16 @@ impl AWSHealth for AWSHealthClient {
         &self,
         input: DescribeAffectedEntitiesRequest,
     ) -> RusotoFuture<DescribeAffectedEntitiesResponse, DescribeAffectedEntitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeAffectedEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -771,10 +1390,10 @@ impl AWSHealth for AWSHealthClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAffectedEntitiesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeAffectedEntitiesResponse, _>(
+                    response,
+                    "DescribeAffectedEntities",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeAffectedEntitiesError::from_response(response))
@@ -788,7 +1407,16 @@ impl AWSHealth for AWSHealthClient {
         &self,
         input: DescribeEntityAggregatesRequest,
     ) -> RusotoFuture<DescribeEntityAggregatesResponse, DescribeEntityAggregatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEntityAggregates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -800,10 +1428,10 @@ impl AWSHealth for AWSHealthClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEntityAggregatesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeEntityAggregatesResponse, _>(
+                    response,
+                    "DescribeEntityAggregates",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeEntityAggregatesError::from_response(response))
@@ -817,7 +1445,16 @@ impl AWSHealth for AWSHealthClient {
         &self,
         input: DescribeEventAggregatesRequest,
     ) -> RusotoFuture<DescribeEventAggregatesResponse, DescribeEventAggregatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventAggregates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventAggregates");
@@ -826,10 +1463,10 @@ impl AWSHealth for AWSHealthClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventAggregatesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeEventAggregatesResponse, _>(
+                    response,
+                    "DescribeEventAggregates",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeEventAggregatesError::from_response(response))
@@ -843,7 +1480,16 @@ impl AWSHealth for AWSHealthClient {
         &self,
         input: DescribeEventDetailsRequest,
     ) -> RusotoFuture<DescribeEventDetailsResponse, DescribeEventDetailsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventDetails");
@@ -852,10 +1498,10 @@ impl AWSHealth for AWSHealthClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventDetailsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeEventDetailsResponse, _>(
+                    response,
+                    "DescribeEventDetails",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -871,7 +1517,16 @@ impl AWSHealth for AWSHealthClient {
         &self,
         input: DescribeEventTypesRequest,
     ) -> RusotoFuture<DescribeEventTypesResponse, DescribeEventTypesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventTypes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventTypes");
@@ -880,10 +1535,10 @@ impl AWSHealth for AWSHealthClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventTypesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeEventTypesResponse, _>(
+                    response,
+                    "DescribeEventTypes",
+                )
             } else {
                 Box::new(
                     response
@@ -900,7 +1555,16 @@ impl AWSHealth for AWSHealthClient {
         &self,
         input: DescribeEventsRequest,
     ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEvents");
@@ -909,10 +1573,10 @@ impl AWSHealth for AWSHealthClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeEventsResponse, _>(
+                    response,
+                    "DescribeEvents",
+                )
             } else {
                 Box::new(
                     response
@@ -924,3 +1588,234 @@ impl AWSHealth for AWSHealthClient {
         })
     }
 }
+
+/// A mock of the AWSHealth API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAWSHealth {
+    describe_affected_entities: Option<
+        Box<
+            dyn Fn(
+                    DescribeAffectedEntitiesRequest,
+                ) -> ::std::result::Result<
+                    DescribeAffectedEntitiesResponse,
+                    RusotoError<DescribeAffectedEntitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_entity_aggregates: Option<
+        Box<
+            dyn Fn(
+                    DescribeEntityAggregatesRequest,
+                ) -> ::std::result::Result<
+                    DescribeEntityAggregatesResponse,
+                    RusotoError<DescribeEntityAggregatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_event_aggregates: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventAggregatesRequest,
+                ) -> ::std::result::Result<
+                    DescribeEventAggregatesResponse,
+                    RusotoError<DescribeEventAggregatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_event_details: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventDetailsRequest,
+                ) -> ::std::result::Result<
+                    DescribeEventDetailsResponse,
+                    RusotoError<DescribeEventDetailsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_event_types: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventTypesRequest,
+                ) -> ::std::result::Result<
+                    DescribeEventTypesResponse,
+                    RusotoError<DescribeEventTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_events: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventsRequest,
+                ) -> ::std::result::Result<
+                    DescribeEventsResponse,
+                    RusotoError<DescribeEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAWSHealth {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAWSHealth {
+        Default::default()
+    }
+
+    /// Answers `describe_affected_entities` with what `handler` returns for its input.
+    pub fn with_describe_affected_entities<F>(mut self, handler: F) -> MockAWSHealth
+    where
+        F: Fn(
+                DescribeAffectedEntitiesRequest,
+            ) -> ::std::result::Result<
+                DescribeAffectedEntitiesResponse,
+                RusotoError<DescribeAffectedEntitiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_affected_entities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_entity_aggregates` with what `handler` returns for its input.
+    pub fn with_describe_entity_aggregates<F>(mut self, handler: F) -> MockAWSHealth
+    where
+        F: Fn(
+                DescribeEntityAggregatesRequest,
+            ) -> ::std::result::Result<
+                DescribeEntityAggregatesResponse,
+                RusotoError<DescribeEntityAggregatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_entity_aggregates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_aggregates` with what `handler` returns for its input.
+    pub fn with_describe_event_aggregates<F>(mut self, handler: F) -> MockAWSHealth
+    where
+        F: Fn(
+                DescribeEventAggregatesRequest,
+            ) -> ::std::result::Result<
+                DescribeEventAggregatesResponse,
+                RusotoError<DescribeEventAggregatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_aggregates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_details` with what `handler` returns for its input.
+    pub fn with_describe_event_details<F>(mut self, handler: F) -> MockAWSHealth
+    where
+        F: Fn(
+                DescribeEventDetailsRequest,
+            ) -> ::std::result::Result<
+                DescribeEventDetailsResponse,
+                RusotoError<DescribeEventDetailsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_details = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_types` with what `handler` returns for its input.
+    pub fn with_describe_event_types<F>(mut self, handler: F) -> MockAWSHealth
+    where
+        F: Fn(
+                DescribeEventTypesRequest,
+            ) -> ::std::result::Result<
+                DescribeEventTypesResponse,
+                RusotoError<DescribeEventTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_events` with what `handler` returns for its input.
+    pub fn with_describe_events<F>(mut self, handler: F) -> MockAWSHealth
+    where
+        F: Fn(
+                DescribeEventsRequest,
+            )
+                -> ::std::result::Result<DescribeEventsResponse, RusotoError<DescribeEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_events = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl AWSHealth for MockAWSHealth {
+    fn describe_affected_entities(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> RusotoFuture<DescribeAffectedEntitiesResponse, DescribeAffectedEntitiesError> {
+        match self.describe_affected_entities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSHealth has no handler for describe_affected_entities"),
+        }
+    }
+    fn describe_entity_aggregates(
+        &self,
+        input: DescribeEntityAggregatesRequest,
+    ) -> RusotoFuture<DescribeEntityAggregatesResponse, DescribeEntityAggregatesError> {
+        match self.describe_entity_aggregates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSHealth has no handler for describe_entity_aggregates"),
+        }
+    }
+    fn describe_event_aggregates(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> RusotoFuture<DescribeEventAggregatesResponse, DescribeEventAggregatesError> {
+        match self.describe_event_aggregates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSHealth has no handler for describe_event_aggregates"),
+        }
+    }
+    fn describe_event_details(
+        &self,
+        input: DescribeEventDetailsRequest,
+    ) -> RusotoFuture<DescribeEventDetailsResponse, DescribeEventDetailsError> {
+        match self.describe_event_details {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSHealth has no handler for describe_event_details"),
+        }
+    }
+    fn describe_event_types(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> RusotoFuture<DescribeEventTypesResponse, DescribeEventTypesError> {
+        match self.describe_event_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSHealth has no handler for describe_event_types"),
+        }
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        match self.describe_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSHealth has no handler for describe_events"),
+        }
+    }
+}
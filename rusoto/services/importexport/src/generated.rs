@@ -23,8 +23,8 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
@@ -35,6 +35,8 @@ use xml::EventReader;
 
 /// <p>A discrete item that contains the description and URL of an artifact (such as a PDF).</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Artifact {
     pub description: Option<String>,
     pub url: Option<String>,
@@ -81,6 +83,8 @@ impl ArtifactListDeserializer {
 }
 /// <p>Input structure for the CancelJob operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelJobInput {
     pub api_version: Option<String>,
     pub job_id: String,
@@ -104,6 +108,8 @@ impl CancelJobInputSerializer {
 
 /// <p>Output structure for the CancelJob operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelJobOutput {
     pub success: Option<bool>,
 }
@@ -139,6 +145,8 @@ impl CarrierDeserializer {
 }
 /// <p>Input structure for the CreateJob operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJobInput {
     pub api_version: Option<String>,
     pub job_type: String,
@@ -170,6 +178,8 @@ impl CreateJobInputSerializer {
 
 /// <p>Output structure for the CreateJob operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJobOutput {
     pub artifact_list: Option<Vec<Artifact>>,
     pub job_id: Option<String>,
@@ -259,7 +269,7 @@ impl ErrorCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -277,6 +287,8 @@ impl GenericStringDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetShippingLabelInput {
     pub api_version: Option<String>,
     pub city: Option<String>,
@@ -339,6 +351,8 @@ impl GetShippingLabelInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetShippingLabelOutput {
     pub shipping_label_url: Option<String>,
     pub warning: Option<String>,
@@ -370,6 +384,8 @@ impl GetShippingLabelOutputDeserializer {
 }
 /// <p>Input structure for the GetStatus operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStatusInput {
     pub api_version: Option<String>,
     pub job_id: String,
@@ -393,6 +409,8 @@ impl GetStatusInputSerializer {
 
 /// <p>Output structure for the GetStatus operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStatusOutput {
     pub artifact_list: Option<Vec<Artifact>>,
     pub carrier: Option<String>,
@@ -507,7 +525,7 @@ impl IsCanceledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -518,7 +536,7 @@ impl IsTruncatedDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -526,6 +544,8 @@ impl IsTruncatedDeserializer {
 }
 /// <p>Representation of a job returned by the ListJobs operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Job {
     pub creation_date: Option<String>,
     pub is_canceled: Option<bool>,
@@ -614,6 +634,8 @@ impl JobsListDeserializer {
 }
 /// <p>Input structure for the ListJobs operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsInput {
     pub api_version: Option<String>,
     pub marker: Option<String>,
@@ -643,6 +665,8 @@ impl ListJobsInputSerializer {
 
 /// <p>Output structure for the ListJobs operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsOutput {
     pub is_truncated: Option<bool>,
     pub jobs: Option<Vec<Job>>,
@@ -765,7 +789,7 @@ impl SuccessDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -795,6 +819,8 @@ impl URLDeserializer {
 }
 /// <p>Input structure for the UpateJob operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateJobInput {
     pub api_version: Option<String>,
     pub job_id: String,
@@ -824,6 +850,8 @@ impl UpdateJobInputSerializer {
 
 /// <p>Output structure for the UpateJob operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateJobOutput {
     pub artifact_list: Option<Vec<Artifact>>,
     pub success: Option<bool>,
@@ -1567,6 +1595,76 @@ pub trait ImportExport {
     /// <p>You use this operation to change the parameters specified in the original manifest file by supplying a new manifest file. The manifest file attached to this request replaces the original manifest file. You can only use the operation after a CreateJob request but before the data transfer starts and you can only use it on jobs you own.</p>
     fn update_job(&self, input: UpdateJobInput) -> RusotoFuture<UpdateJobOutput, UpdateJobError>;
 }
+const _: Option<&dyn ImportExport> = None;
+impl<'a, T: ImportExport + ?Sized> ImportExport for &'a T {
+    fn cancel_job(&self, input: CancelJobInput) -> RusotoFuture<CancelJobOutput, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_job(&self, input: CreateJobInput) -> RusotoFuture<CreateJobOutput, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn get_shipping_label(
+        &self,
+        input: GetShippingLabelInput,
+    ) -> RusotoFuture<GetShippingLabelOutput, GetShippingLabelError> {
+        (**self).get_shipping_label(input)
+    }
+    fn get_status(&self, input: GetStatusInput) -> RusotoFuture<GetStatusOutput, GetStatusError> {
+        (**self).get_status(input)
+    }
+    fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn update_job(&self, input: UpdateJobInput) -> RusotoFuture<UpdateJobOutput, UpdateJobError> {
+        (**self).update_job(input)
+    }
+}
+impl<T: ImportExport + ?Sized> ImportExport for Box<T> {
+    fn cancel_job(&self, input: CancelJobInput) -> RusotoFuture<CancelJobOutput, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_job(&self, input: CreateJobInput) -> RusotoFuture<CreateJobOutput, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn get_shipping_label(
+        &self,
+        input: GetShippingLabelInput,
+    ) -> RusotoFuture<GetShippingLabelOutput, GetShippingLabelError> {
+        (**self).get_shipping_label(input)
+    }
+    fn get_status(&self, input: GetStatusInput) -> RusotoFuture<GetStatusOutput, GetStatusError> {
+        (**self).get_status(input)
+    }
+    fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn update_job(&self, input: UpdateJobInput) -> RusotoFuture<UpdateJobOutput, UpdateJobError> {
+        (**self).update_job(input)
+    }
+}
+impl<T: ImportExport + ?Sized> ImportExport for ::std::sync::Arc<T> {
+    fn cancel_job(&self, input: CancelJobInput) -> RusotoFuture<CancelJobOutput, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_job(&self, input: CreateJobInput) -> RusotoFuture<CreateJobOutput, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn get_shipping_label(
+        &self,
+        input: GetShippingLabelInput,
+    ) -> RusotoFuture<GetShippingLabelOutput, GetShippingLabelError> {
+        (**self).get_shipping_label(input)
+    }
+    fn get_status(&self, input: GetStatusInput) -> RusotoFuture<GetStatusOutput, GetStatusError> {
+        (**self).get_status(input)
+    }
+    fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn update_job(&self, input: UpdateJobInput) -> RusotoFuture<UpdateJobOutput, UpdateJobError> {
+        (**self).update_job(input)
+    }
+}
 /// A client for the AWS Import/Export API.
 #[derive(Clone)]
 pub struct ImportExportClient {
@@ -1613,6 +1711,7 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=CancelJob",
         );
+        request.set_operation("CancelJob");
         let mut params = Params::new();
 
         params.put("Action", "CancelJob");
@@ -1637,6 +1736,7 @@ impl ImportExport for ImportExportClient {
                 if response.body.is_empty() {
                     result = CancelJobOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1646,7 +1746,8 @@ impl ImportExport for ImportExportClient {
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
                     result =
-                        CancelJobOutputDeserializer::deserialize("CancelJobResult", &mut stack)?;
+                        CancelJobOutputDeserializer::deserialize("CancelJobResult", &mut stack)
+                            .map_err(|err| stack.parse_error("CancelJob", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1664,6 +1765,7 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=CreateJob",
         );
+        request.set_operation("CreateJob");
         let mut params = Params::new();
 
         params.put("Action", "CreateJob");
@@ -1688,6 +1790,7 @@ impl ImportExport for ImportExportClient {
                 if response.body.is_empty() {
                     result = CreateJobOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1697,7 +1800,8 @@ impl ImportExport for ImportExportClient {
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
                     result =
-                        CreateJobOutputDeserializer::deserialize("CreateJobResult", &mut stack)?;
+                        CreateJobOutputDeserializer::deserialize("CreateJobResult", &mut stack)
+                            .map_err(|err| stack.parse_error("CreateJob", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1718,6 +1822,7 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=GetShippingLabel",
         );
+        request.set_operation("GetShippingLabel");
         let mut params = Params::new();
 
         params.put("Action", "GetShippingLabel");
@@ -1742,6 +1847,7 @@ impl ImportExport for ImportExportClient {
                 if response.body.is_empty() {
                     result = GetShippingLabelOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1753,7 +1859,8 @@ impl ImportExport for ImportExportClient {
                     result = GetShippingLabelOutputDeserializer::deserialize(
                         "GetShippingLabelResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetShippingLabel", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1771,6 +1878,7 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=GetStatus",
         );
+        request.set_operation("GetStatus");
         let mut params = Params::new();
 
         params.put("Action", "GetStatus");
@@ -1795,6 +1903,7 @@ impl ImportExport for ImportExportClient {
                 if response.body.is_empty() {
                     result = GetStatusOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1804,7 +1913,8 @@ impl ImportExport for ImportExportClient {
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
                     result =
-                        GetStatusOutputDeserializer::deserialize("GetStatusResult", &mut stack)?;
+                        GetStatusOutputDeserializer::deserialize("GetStatusResult", &mut stack)
+                            .map_err(|err| stack.parse_error("GetStatus", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1818,6 +1928,7 @@ impl ImportExport for ImportExportClient {
     fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
         let mut request =
             SignedRequest::new("POST", "importexport", &self.region, "/?Operation=ListJobs");
+        request.set_operation("ListJobs");
         let mut params = Params::new();
 
         params.put("Action", "ListJobs");
@@ -1842,6 +1953,7 @@ impl ImportExport for ImportExportClient {
                 if response.body.is_empty() {
                     result = ListJobsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1850,7 +1962,8 @@ impl ImportExport for ImportExportClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = ListJobsOutputDeserializer::deserialize("ListJobsResult", &mut stack)?;
+                    result = ListJobsOutputDeserializer::deserialize("ListJobsResult", &mut stack)
+                        .map_err(|err| stack.parse_error("ListJobs", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1868,6 +1981,7 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=UpdateJob",
         );
+        request.set_operation("UpdateJob");
         let mut params = Params::new();
 
         params.put("Action", "UpdateJob");
@@ -1892,6 +2006,7 @@ impl ImportExport for ImportExportClient {
                 if response.body.is_empty() {
                     result = UpdateJobOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1901,7 +2016,8 @@ impl ImportExport for ImportExportClient {
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
                     result =
-                        UpdateJobOutputDeserializer::deserialize("UpdateJobResult", &mut stack)?;
+                        UpdateJobOutputDeserializer::deserialize("UpdateJobResult", &mut stack)
+                            .map_err(|err| stack.parse_error("UpdateJob", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1912,6 +2028,205 @@ impl ImportExport for ImportExportClient {
     }
 }
 
+/// A mock of the AWS Import/Export API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockImportExport {
+    cancel_job: Option<
+        Box<
+            dyn Fn(
+                    CancelJobInput,
+                )
+                    -> ::std::result::Result<CancelJobOutput, RusotoError<CancelJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_job: Option<
+        Box<
+            dyn Fn(
+                    CreateJobInput,
+                )
+                    -> ::std::result::Result<CreateJobOutput, RusotoError<CreateJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_shipping_label: Option<
+        Box<
+            dyn Fn(
+                    GetShippingLabelInput,
+                ) -> ::std::result::Result<
+                    GetShippingLabelOutput,
+                    RusotoError<GetShippingLabelError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_status: Option<
+        Box<
+            dyn Fn(
+                    GetStatusInput,
+                )
+                    -> ::std::result::Result<GetStatusOutput, RusotoError<GetStatusError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListJobsInput,
+                )
+                    -> ::std::result::Result<ListJobsOutput, RusotoError<ListJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_job: Option<
+        Box<
+            dyn Fn(
+                    UpdateJobInput,
+                )
+                    -> ::std::result::Result<UpdateJobOutput, RusotoError<UpdateJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockImportExport {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockImportExport {
+        Default::default()
+    }
+
+    /// Answers `cancel_job` with what `handler` returns for its input.
+    pub fn with_cancel_job<F>(mut self, handler: F) -> MockImportExport
+    where
+        F: Fn(
+                CancelJobInput,
+            ) -> ::std::result::Result<CancelJobOutput, RusotoError<CancelJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_job` with what `handler` returns for its input.
+    pub fn with_create_job<F>(mut self, handler: F) -> MockImportExport
+    where
+        F: Fn(
+                CreateJobInput,
+            ) -> ::std::result::Result<CreateJobOutput, RusotoError<CreateJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_shipping_label` with what `handler` returns for its input.
+    pub fn with_get_shipping_label<F>(mut self, handler: F) -> MockImportExport
+    where
+        F: Fn(
+                GetShippingLabelInput,
+            )
+                -> ::std::result::Result<GetShippingLabelOutput, RusotoError<GetShippingLabelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_shipping_label = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_status` with what `handler` returns for its input.
+    pub fn with_get_status<F>(mut self, handler: F) -> MockImportExport
+    where
+        F: Fn(
+                GetStatusInput,
+            ) -> ::std::result::Result<GetStatusOutput, RusotoError<GetStatusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_jobs` with what `handler` returns for its input.
+    pub fn with_list_jobs<F>(mut self, handler: F) -> MockImportExport
+    where
+        F: Fn(ListJobsInput) -> ::std::result::Result<ListJobsOutput, RusotoError<ListJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_job` with what `handler` returns for its input.
+    pub fn with_update_job<F>(mut self, handler: F) -> MockImportExport
+    where
+        F: Fn(
+                UpdateJobInput,
+            ) -> ::std::result::Result<UpdateJobOutput, RusotoError<UpdateJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_job = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ImportExport for MockImportExport {
+    fn cancel_job(&self, input: CancelJobInput) -> RusotoFuture<CancelJobOutput, CancelJobError> {
+        match self.cancel_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockImportExport has no handler for cancel_job"),
+        }
+    }
+    fn create_job(&self, input: CreateJobInput) -> RusotoFuture<CreateJobOutput, CreateJobError> {
+        match self.create_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockImportExport has no handler for create_job"),
+        }
+    }
+    fn get_shipping_label(
+        &self,
+        input: GetShippingLabelInput,
+    ) -> RusotoFuture<GetShippingLabelOutput, GetShippingLabelError> {
+        match self.get_shipping_label {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockImportExport has no handler for get_shipping_label"),
+        }
+    }
+    fn get_status(&self, input: GetStatusInput) -> RusotoFuture<GetStatusOutput, GetStatusError> {
+        match self.get_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockImportExport has no handler for get_status"),
+        }
+    }
+    fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
+        match self.list_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockImportExport has no handler for list_jobs"),
+        }
+    }
+    fn update_job(&self, input: UpdateJobInput) -> RusotoFuture<UpdateJobOutput, UpdateJobError> {
+        match self.update_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockImportExport has no handler for update_job"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddAttributesToFindingsRequest {
     /// <p>The array of attributes that you want to assign to specified findings.</p>
     #[serde(rename = "attributes")]
@@ -33,8 +35,33 @@ pub struct AddAttributesToFindingsRequest {
     pub finding_arns: Vec<String>,
 }
 
+impl Validate for AddAttributesToFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attributes"),
+            self.attributes.len(),
+            None,
+            Some(10),
+        );
+        for (i, item) in self.attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "finding_arns"),
+            self.finding_arns.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.finding_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "finding_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddAttributesToFindingsResponse {
     /// <p>Attribute details that cannot be described. An error code is provided for each failed item.</p>
     #[serde(rename = "failedItems")]
@@ -52,6 +79,7 @@ pub struct AgentAlreadyRunningAssessment {
 
 /// <p>Contains information about an Amazon Inspector agent. This data type is used as a request parameter in the <a>ListAssessmentRunAgents</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AgentFilter {
     /// <p>The detailed health state of the agent. Values can be set to <b>IDLE</b>, <b>RUNNING</b>, <b>SHUTDOWN</b>, <b>UNHEALTHY</b>, <b>THROTTLED</b>, and <b>UNKNOWN</b>. </p>
     #[serde(rename = "agentHealthCodes")]
@@ -61,9 +89,26 @@ pub struct AgentFilter {
     pub agent_healths: Vec<String>,
 }
 
+impl Validate for AgentFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "agent_health_codes"),
+            self.agent_health_codes.len(),
+            None,
+            Some(10),
+        );
+        errors.check_length(
+            &field_path(path, "agent_healths"),
+            self.agent_healths.len(),
+            None,
+            Some(10),
+        );
+    }
+}
+
 /// <p>Used as a response element in the <a>PreviewAgents</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AgentPreview {
     /// <p>The health status of the Amazon Inspector Agent.</p>
     #[serde(rename = "agentHealth")]
@@ -100,7 +145,7 @@ pub struct AgentPreview {
 
 /// <p>A snapshot of an Amazon Inspector assessment run that contains the findings of the assessment run .</p> <p>Used as the response element in the <a>DescribeAssessmentRuns</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssessmentRun {
     /// <p>The ARN of the assessment run.</p>
     #[serde(rename = "arn")]
@@ -153,7 +198,7 @@ pub struct AssessmentRun {
 
 /// <p>Contains information about an Amazon Inspector agent. This data type is used as a response element in the <a>ListAssessmentRunAgents</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssessmentRunAgent {
     /// <p>The current health state of the agent.</p>
     #[serde(rename = "agentHealth")]
@@ -182,6 +227,7 @@ pub struct AssessmentRunAgent {
 
 /// <p>Used as the request parameter in the <a>ListAssessmentRuns</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssessmentRunFilter {
     /// <p>For a record to match a filter, the value that is specified for this data type property must inclusively match any value between the specified minimum and maximum values of the <b>completedAt</b> property of the <a>AssessmentRun</a> data type.</p>
     #[serde(rename = "completionTimeRange")]
@@ -213,9 +259,40 @@ pub struct AssessmentRunFilter {
     pub states: Option<Vec<String>>,
 }
 
+impl Validate for AssessmentRunFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration_range {
+            value.validate_at(&field_path(path, "duration_range"), errors);
+        }
+        if let Some(ref value) = self.name_pattern {
+            errors.check_length(
+                &field_path(path, "name_pattern"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+        }
+        if let Some(ref value) = self.rules_package_arns {
+            errors.check_length(
+                &field_path(path, "rules_package_arns"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "rules_package_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+            }
+        }
+        if let Some(ref value) = self.states {
+            errors.check_length(&field_path(path, "states"), value.len(), None, Some(50));
+        }
+    }
+}
+
 /// <p>Used as one of the elements of the <a>AssessmentRun</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssessmentRunNotification {
     /// <p>The date of the notification.</p>
     #[serde(rename = "date")]
@@ -242,7 +319,7 @@ pub struct AssessmentRunNotification {
 
 /// <p>Used as one of the elements of the <a>AssessmentRun</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssessmentRunStateChange {
     /// <p>The assessment run state.</p>
     #[serde(rename = "state")]
@@ -254,7 +331,7 @@ pub struct AssessmentRunStateChange {
 
 /// <p>Contains information about an Amazon Inspector application. This data type is used as the response element in the <a>DescribeAssessmentTargets</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssessmentTarget {
     /// <p>The ARN that specifies the Amazon Inspector assessment target.</p>
     #[serde(rename = "arn")]
@@ -276,6 +353,7 @@ pub struct AssessmentTarget {
 
 /// <p>Used as the request parameter in the <a>ListAssessmentTargets</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssessmentTargetFilter {
     /// <p>For a record to match a filter, an explicit value or a string that contains a wildcard that is specified for this data type property must match the value of the <b>assessmentTargetName</b> property of the <a>AssessmentTarget</a> data type.</p>
     #[serde(rename = "assessmentTargetNamePattern")]
@@ -283,9 +361,22 @@ pub struct AssessmentTargetFilter {
     pub assessment_target_name_pattern: Option<String>,
 }
 
+impl Validate for AssessmentTargetFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.assessment_target_name_pattern {
+            errors.check_length(
+                &field_path(path, "assessment_target_name_pattern"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+        }
+    }
+}
+
 /// <p>Contains information about an Amazon Inspector assessment template. This data type is used as the response element in the <a>DescribeAssessmentTemplates</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssessmentTemplate {
     /// <p>The ARN of the assessment template.</p>
     #[serde(rename = "arn")]
@@ -319,6 +410,7 @@ pub struct AssessmentTemplate {
 
 /// <p>Used as the request parameter in the <a>ListAssessmentTemplates</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssessmentTemplateFilter {
     /// <p>For a record to match a filter, the value specified for this data type property must inclusively match any value between the specified minimum and maximum values of the <b>durationInSeconds</b> property of the <a>AssessmentTemplate</a> data type.</p>
     #[serde(rename = "durationRange")]
@@ -334,9 +426,37 @@ pub struct AssessmentTemplateFilter {
     pub rules_package_arns: Option<Vec<String>>,
 }
 
+impl Validate for AssessmentTemplateFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration_range {
+            value.validate_at(&field_path(path, "duration_range"), errors);
+        }
+        if let Some(ref value) = self.name_pattern {
+            errors.check_length(
+                &field_path(path, "name_pattern"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+        }
+        if let Some(ref value) = self.rules_package_arns {
+            errors.check_length(
+                &field_path(path, "rules_package_arns"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "rules_package_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+            }
+        }
+    }
+}
+
 /// <p>A collection of attributes of the host from which the finding is generated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssetAttributes {
     /// <p>The ID of the agent that is installed on the EC2 instance where the finding is generated.</p>
     #[serde(rename = "agentId")]
@@ -383,7 +503,27 @@ pub struct Attribute {
     pub value: Option<String>,
 }
 
+impl Validate for Attribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAssessmentTargetRequest {
     /// <p>The user-defined name that identifies the assessment target that you want to create. The name must be unique within the AWS account.</p>
     #[serde(rename = "assessmentTargetName")]
@@ -394,8 +534,27 @@ pub struct CreateAssessmentTargetRequest {
     pub resource_group_arn: Option<String>,
 }
 
+impl Validate for CreateAssessmentTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_target_name"),
+            self.assessment_target_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        if let Some(ref value) = self.resource_group_arn {
+            errors.check_length(
+                &field_path(path, "resource_group_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAssessmentTargetResponse {
     /// <p>The ARN that specifies the assessment target that is created.</p>
     #[serde(rename = "assessmentTargetArn")]
@@ -403,6 +562,7 @@ pub struct CreateAssessmentTargetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAssessmentTemplateRequest {
     /// <p>The ARN that specifies the assessment target for which you want to create the assessment template.</p>
     #[serde(rename = "assessmentTargetArn")]
@@ -422,8 +582,57 @@ pub struct CreateAssessmentTemplateRequest {
     pub user_attributes_for_findings: Option<Vec<Attribute>>,
 }
 
+impl Validate for CreateAssessmentTemplateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_target_arn"),
+            self.assessment_target_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "assessment_template_name"),
+            self.assessment_template_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_range(
+            &field_path(path, "duration_in_seconds"),
+            self.duration_in_seconds,
+            Some(180),
+            Some(86400),
+        );
+        errors.check_length(
+            &field_path(path, "rules_package_arns"),
+            self.rules_package_arns.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.rules_package_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "rules_package_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+        if let Some(ref value) = self.user_attributes_for_findings {
+            errors.check_length(
+                &field_path(path, "user_attributes_for_findings"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "user_attributes_for_findings"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAssessmentTemplateResponse {
     /// <p>The ARN that specifies the assessment template that is created.</p>
     #[serde(rename = "assessmentTemplateArn")]
@@ -431,14 +640,26 @@ pub struct CreateAssessmentTemplateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateExclusionsPreviewRequest {
     /// <p>The ARN that specifies the assessment template for which you want to create an exclusions preview.</p>
     #[serde(rename = "assessmentTemplateArn")]
     pub assessment_template_arn: String,
 }
 
+impl Validate for CreateExclusionsPreviewRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_template_arn"),
+            self.assessment_template_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateExclusionsPreviewResponse {
     /// <p>Specifies the unique identifier of the requested exclusions preview. You can use the unique identifier to retrieve the exclusions preview when running the GetExclusionsPreview API.</p>
     #[serde(rename = "previewToken")]
@@ -446,14 +667,30 @@ pub struct CreateExclusionsPreviewResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateResourceGroupRequest {
     /// <p>A collection of keys and an array of possible values, '[{"key":"key1","values":["Value1","Value2"]},{"key":"Key2","values":["Value3"]}]'.</p> <p>For example,'[{"key":"Name","values":["TestEC2Instance"]}]'.</p>
     #[serde(rename = "resourceGroupTags")]
     pub resource_group_tags: Vec<ResourceGroupTag>,
 }
 
+impl Validate for CreateResourceGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_group_tags"),
+            self.resource_group_tags.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.resource_group_tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_group_tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateResourceGroupResponse {
     /// <p>The ARN that specifies the resource group that is created.</p>
     #[serde(rename = "resourceGroupArn")]
@@ -461,35 +698,87 @@ pub struct CreateResourceGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAssessmentRunRequest {
     /// <p>The ARN that specifies the assessment run that you want to delete.</p>
     #[serde(rename = "assessmentRunArn")]
     pub assessment_run_arn: String,
 }
 
+impl Validate for DeleteAssessmentRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_run_arn"),
+            self.assessment_run_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAssessmentTargetRequest {
     /// <p>The ARN that specifies the assessment target that you want to delete.</p>
     #[serde(rename = "assessmentTargetArn")]
     pub assessment_target_arn: String,
 }
 
+impl Validate for DeleteAssessmentTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_target_arn"),
+            self.assessment_target_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAssessmentTemplateRequest {
     /// <p>The ARN that specifies the assessment template that you want to delete.</p>
     #[serde(rename = "assessmentTemplateArn")]
     pub assessment_template_arn: String,
 }
 
+impl Validate for DeleteAssessmentTemplateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_template_arn"),
+            self.assessment_template_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAssessmentRunsRequest {
     /// <p>The ARN that specifies the assessment run that you want to describe.</p>
     #[serde(rename = "assessmentRunArns")]
     pub assessment_run_arns: Vec<String>,
 }
 
+impl Validate for DescribeAssessmentRunsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_run_arns"),
+            self.assessment_run_arns.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.assessment_run_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "assessment_run_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAssessmentRunsResponse {
     /// <p>Information about the assessment run.</p>
     #[serde(rename = "assessmentRuns")]
@@ -500,14 +789,30 @@ pub struct DescribeAssessmentRunsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAssessmentTargetsRequest {
     /// <p>The ARNs that specifies the assessment targets that you want to describe.</p>
     #[serde(rename = "assessmentTargetArns")]
     pub assessment_target_arns: Vec<String>,
 }
 
+impl Validate for DescribeAssessmentTargetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_target_arns"),
+            self.assessment_target_arns.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.assessment_target_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "assessment_target_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAssessmentTargetsResponse {
     /// <p>Information about the assessment targets.</p>
     #[serde(rename = "assessmentTargets")]
@@ -518,13 +823,29 @@ pub struct DescribeAssessmentTargetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAssessmentTemplatesRequest {
     #[serde(rename = "assessmentTemplateArns")]
     pub assessment_template_arns: Vec<String>,
 }
 
+impl Validate for DescribeAssessmentTemplatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_template_arns"),
+            self.assessment_template_arns.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.assessment_template_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "assessment_template_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAssessmentTemplatesResponse {
     /// <p>Information about the assessment templates.</p>
     #[serde(rename = "assessmentTemplates")]
@@ -535,7 +856,7 @@ pub struct DescribeAssessmentTemplatesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCrossAccountAccessRoleResponse {
     /// <p>The date when the cross-account access role was registered.</p>
     #[serde(rename = "registeredAt")]
@@ -549,6 +870,7 @@ pub struct DescribeCrossAccountAccessRoleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeExclusionsRequest {
     /// <p>The list of ARNs that specify the exclusions that you want to describe.</p>
     #[serde(rename = "exclusionArns")]
@@ -559,8 +881,23 @@ pub struct DescribeExclusionsRequest {
     pub locale: Option<String>,
 }
 
+impl Validate for DescribeExclusionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "exclusion_arns"),
+            self.exclusion_arns.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.exclusion_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "exclusion_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeExclusionsResponse {
     /// <p>Information about the exclusions.</p>
     #[serde(rename = "exclusions")]
@@ -571,6 +908,7 @@ pub struct DescribeExclusionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFindingsRequest {
     /// <p>The ARN that specifies the finding that you want to describe.</p>
     #[serde(rename = "findingArns")]
@@ -581,8 +919,23 @@ pub struct DescribeFindingsRequest {
     pub locale: Option<String>,
 }
 
+impl Validate for DescribeFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "finding_arns"),
+            self.finding_arns.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.finding_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "finding_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFindingsResponse {
     /// <p>Finding details that cannot be described. An error code is provided for each failed item.</p>
     #[serde(rename = "failedItems")]
@@ -593,14 +946,30 @@ pub struct DescribeFindingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeResourceGroupsRequest {
     /// <p>The ARN that specifies the resource group that you want to describe.</p>
     #[serde(rename = "resourceGroupArns")]
     pub resource_group_arns: Vec<String>,
 }
 
+impl Validate for DescribeResourceGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_group_arns"),
+            self.resource_group_arns.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.resource_group_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_group_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeResourceGroupsResponse {
     /// <p>Resource group details that cannot be described. An error code is provided for each failed item.</p>
     #[serde(rename = "failedItems")]
@@ -611,6 +980,7 @@ pub struct DescribeResourceGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRulesPackagesRequest {
     /// <p>The locale that you want to translate a rules package description into.</p>
     #[serde(rename = "locale")]
@@ -621,8 +991,23 @@ pub struct DescribeRulesPackagesRequest {
     pub rules_package_arns: Vec<String>,
 }
 
+impl Validate for DescribeRulesPackagesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "rules_package_arns"),
+            self.rules_package_arns.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.rules_package_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "rules_package_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRulesPackagesResponse {
     /// <p>Rules package details that cannot be described. An error code is provided for each failed item.</p>
     #[serde(rename = "failedItems")]
@@ -634,6 +1019,7 @@ pub struct DescribeRulesPackagesResponse {
 
 /// <p>This data type is used in the <a>AssessmentTemplateFilter</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DurationRange {
     /// <p>The maximum value of the duration range. Must be less than or equal to 604800 seconds (1 week).</p>
     #[serde(rename = "maxSeconds")]
@@ -645,9 +1031,30 @@ pub struct DurationRange {
     pub min_seconds: Option<i64>,
 }
 
+impl Validate for DurationRange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_seconds {
+            errors.check_range(
+                &field_path(path, "max_seconds"),
+                *value,
+                Some(180),
+                Some(86400),
+            );
+        }
+        if let Some(ref value) = self.min_seconds {
+            errors.check_range(
+                &field_path(path, "min_seconds"),
+                *value,
+                Some(180),
+                Some(86400),
+            );
+        }
+    }
+}
+
 /// <p>This data type is used in the <a>Subscription</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventSubscription {
     /// <p>The event for which Amazon Simple Notification Service (SNS) notifications are sent.</p>
     #[serde(rename = "event")]
@@ -659,7 +1066,7 @@ pub struct EventSubscription {
 
 /// <p>Contains information about what was excluded from an assessment run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Exclusion {
     /// <p>The ARN that specifies the exclusion.</p>
     #[serde(rename = "arn")]
@@ -684,7 +1091,7 @@ pub struct Exclusion {
 
 /// <p>Contains information about what is excluded from an assessment run given the current state of the assessment template.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExclusionPreview {
     /// <p>The system-defined attributes for the exclusion preview.</p>
     #[serde(rename = "attributes")]
@@ -706,7 +1113,7 @@ pub struct ExclusionPreview {
 
 /// <p>Includes details about the failed items.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailedItemDetails {
     /// <p>The status code of a failed item.</p>
     #[serde(rename = "failureCode")]
@@ -718,7 +1125,7 @@ pub struct FailedItemDetails {
 
 /// <p>Contains information about an Amazon Inspector finding. This data type is used as the response element in the <a>DescribeFindings</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Finding {
     /// <p>The ARN that specifies the finding.</p>
     #[serde(rename = "arn")]
@@ -791,6 +1198,7 @@ pub struct Finding {
 
 /// <p>This data type is used as a request parameter in the <a>ListFindings</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FindingFilter {
     /// <p>For a record to match a filter, one of the values that is specified for this data type property must be the exact match of the value of the <b>agentId</b> property of the <a>Finding</a> data type.</p>
     #[serde(rename = "agentIds")]
@@ -826,7 +1234,73 @@ pub struct FindingFilter {
     pub user_attributes: Option<Vec<Attribute>>,
 }
 
+impl Validate for FindingFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.agent_ids {
+            errors.check_length(&field_path(path, "agent_ids"), value.len(), None, Some(99));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "agent_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            }
+        }
+        if let Some(ref value) = self.attributes {
+            errors.check_length(&field_path(path, "attributes"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.auto_scaling_groups {
+            errors.check_length(
+                &field_path(path, "auto_scaling_groups"),
+                value.len(),
+                None,
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "auto_scaling_groups"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+        if let Some(ref value) = self.rule_names {
+            errors.check_length(&field_path(path, "rule_names"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "rule_names"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1000));
+            }
+        }
+        if let Some(ref value) = self.rules_package_arns {
+            errors.check_length(
+                &field_path(path, "rules_package_arns"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "rules_package_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+            }
+        }
+        if let Some(ref value) = self.severities {
+            errors.check_length(&field_path(path, "severities"), value.len(), None, Some(50));
+        }
+        if let Some(ref value) = self.user_attributes {
+            errors.check_length(
+                &field_path(path, "user_attributes"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "user_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAssessmentReportRequest {
     /// <p>The ARN that specifies the assessment run for which you want to generate a report.</p>
     #[serde(rename = "assessmentRunArn")]
@@ -839,8 +1313,19 @@ pub struct GetAssessmentReportRequest {
     pub report_type: String,
 }
 
+impl Validate for GetAssessmentReportRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_run_arn"),
+            self.assessment_run_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAssessmentReportResponse {
     /// <p>Specifies the status of the request to generate an assessment report. </p>
     #[serde(rename = "status")]
@@ -852,6 +1337,7 @@ pub struct GetAssessmentReportResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetExclusionsPreviewRequest {
     /// <p>The ARN that specifies the assessment template for which the exclusions preview was requested.</p>
     #[serde(rename = "assessmentTemplateArn")]
@@ -873,8 +1359,32 @@ pub struct GetExclusionsPreviewRequest {
     pub preview_token: String,
 }
 
+impl Validate for GetExclusionsPreviewRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_template_arn"),
+            self.assessment_template_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "preview_token"),
+            &self.preview_token,
+            "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetExclusionsPreviewResponse {
     /// <p>Information about the exclusions included in the preview.</p>
     #[serde(rename = "exclusionPreviews")]
@@ -890,14 +1400,26 @@ pub struct GetExclusionsPreviewResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTelemetryMetadataRequest {
     /// <p>The ARN that specifies the assessment run that has the telemetry data that you want to obtain.</p>
     #[serde(rename = "assessmentRunArn")]
     pub assessment_run_arn: String,
 }
 
+impl Validate for GetTelemetryMetadataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_run_arn"),
+            self.assessment_run_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTelemetryMetadataResponse {
     /// <p>Telemetry details.</p>
     #[serde(rename = "telemetryMetadata")]
@@ -906,7 +1428,7 @@ pub struct GetTelemetryMetadataResponse {
 
 /// <p>This data type is used in the <a>Finding</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InspectorServiceAttributes {
     /// <p>The ARN of the assessment run during which the finding is generated.</p>
     #[serde(rename = "assessmentRunArn")]
@@ -922,6 +1444,7 @@ pub struct InspectorServiceAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAssessmentRunAgentsRequest {
     /// <p>The ARN that specifies the assessment run whose agents you want to list.</p>
     #[serde(rename = "assessmentRunArn")]
@@ -940,8 +1463,30 @@ pub struct ListAssessmentRunAgentsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAssessmentRunAgentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_run_arn"),
+            self.assessment_run_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAssessmentRunAgentsResponse {
     /// <p>A list of ARNs that specifies the agents returned by the action.</p>
     #[serde(rename = "assessmentRunAgents")]
@@ -953,6 +1498,7 @@ pub struct ListAssessmentRunAgentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAssessmentRunsRequest {
     /// <p>The ARNs that specify the assessment templates whose assessment runs you want to list.</p>
     #[serde(rename = "assessmentTemplateArns")]
@@ -972,8 +1518,36 @@ pub struct ListAssessmentRunsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAssessmentRunsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.assessment_template_arns {
+            errors.check_length(
+                &field_path(path, "assessment_template_arns"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "assessment_template_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+            }
+        }
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAssessmentRunsResponse {
     /// <p>A list of ARNs that specifies the assessment runs that are returned by the action.</p>
     #[serde(rename = "assessmentRunArns")]
@@ -985,6 +1559,7 @@ pub struct ListAssessmentRunsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAssessmentTargetsRequest {
     /// <p>You can use this parameter to specify a subset of data to be included in the action's response.</p> <p>For a record to match a filter, all specified filter attributes must match. When multiple values are specified for a filter attribute, any of the values can match.</p>
     #[serde(rename = "filter")]
@@ -1000,8 +1575,24 @@ pub struct ListAssessmentTargetsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAssessmentTargetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAssessmentTargetsResponse {
     /// <p>A list of ARNs that specifies the assessment targets that are returned by the action.</p>
     #[serde(rename = "assessmentTargetArns")]
@@ -1013,6 +1604,7 @@ pub struct ListAssessmentTargetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAssessmentTemplatesRequest {
     /// <p>A list of ARNs that specifies the assessment targets whose assessment templates you want to list.</p>
     #[serde(rename = "assessmentTargetArns")]
@@ -1032,8 +1624,36 @@ pub struct ListAssessmentTemplatesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAssessmentTemplatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.assessment_target_arns {
+            errors.check_length(
+                &field_path(path, "assessment_target_arns"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "assessment_target_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+            }
+        }
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAssessmentTemplatesResponse {
     /// <p>A list of ARNs that specifies the assessment templates returned by the action.</p>
     #[serde(rename = "assessmentTemplateArns")]
@@ -1045,6 +1665,7 @@ pub struct ListAssessmentTemplatesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEventSubscriptionsRequest {
     /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 10. The maximum value is 500.</p>
     #[serde(rename = "maxResults")]
@@ -1060,8 +1681,29 @@ pub struct ListEventSubscriptionsRequest {
     pub resource_arn: Option<String>,
 }
 
+impl Validate for ListEventSubscriptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+        if let Some(ref value) = self.resource_arn {
+            errors.check_length(
+                &field_path(path, "resource_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListEventSubscriptionsResponse {
     /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
     #[serde(rename = "nextToken")]
@@ -1073,6 +1715,7 @@ pub struct ListEventSubscriptionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListExclusionsRequest {
     /// <p>The ARN of the assessment run that generated the exclusions that you want to list.</p>
     #[serde(rename = "assessmentRunArn")]
@@ -1087,8 +1730,27 @@ pub struct ListExclusionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListExclusionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_run_arn"),
+            self.assessment_run_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListExclusionsResponse {
     /// <p>A list of exclusions' ARNs returned by the action.</p>
     #[serde(rename = "exclusionArns")]
@@ -1100,6 +1762,7 @@ pub struct ListExclusionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFindingsRequest {
     /// <p>The ARNs of the assessment runs that generate the findings that you want to list.</p>
     #[serde(rename = "assessmentRunArns")]
@@ -1119,8 +1782,36 @@ pub struct ListFindingsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.assessment_run_arns {
+            errors.check_length(
+                &field_path(path, "assessment_run_arns"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "assessment_run_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+            }
+        }
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFindingsResponse {
     /// <p>A list of ARNs that specifies the findings returned by the action.</p>
     #[serde(rename = "findingArns")]
@@ -1132,6 +1823,7 @@ pub struct ListFindingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRulesPackagesRequest {
     /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 10. The maximum value is 500.</p>
     #[serde(rename = "maxResults")]
@@ -1143,8 +1835,21 @@ pub struct ListRulesPackagesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListRulesPackagesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRulesPackagesResponse {
     /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
     #[serde(rename = "nextToken")]
@@ -1156,14 +1861,26 @@ pub struct ListRulesPackagesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The ARN that specifies the assessment template whose tags you want to list.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>A collection of key and value pairs.</p>
     #[serde(rename = "tags")]
@@ -1172,7 +1889,7 @@ pub struct ListTagsForResourceResponse {
 
 /// <p>Contains information about the network interfaces interacting with an EC2 instance. This data type is used as one of the elements of the <a>AssetAttributes</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkInterface {
     /// <p>The IP addresses associated with the network interface.</p>
     #[serde(rename = "ipv6Addresses")]
@@ -1217,6 +1934,7 @@ pub struct NetworkInterface {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PreviewAgentsRequest {
     /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 10. The maximum value is 500.</p>
     #[serde(rename = "maxResults")]
@@ -1231,8 +1949,27 @@ pub struct PreviewAgentsRequest {
     pub preview_agents_arn: String,
 }
 
+impl Validate for PreviewAgentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "preview_agents_arn"),
+            self.preview_agents_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PreviewAgentsResponse {
     /// <p>The resulting list of agents.</p>
     #[serde(rename = "agentPreviews")]
@@ -1245,7 +1982,7 @@ pub struct PreviewAgentsResponse {
 
 /// <p>Contains information about a private IP address associated with a network interface. This data type is used as a response element in the <a>DescribeFindings</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PrivateIp {
     /// <p>The DNS name of the private IP address.</p>
     #[serde(rename = "privateDnsName")]
@@ -1258,13 +1995,26 @@ pub struct PrivateIp {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterCrossAccountAccessRoleRequest {
     /// <p>The ARN of the IAM role that grants Amazon Inspector access to AWS Services needed to perform security assessments. </p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
 }
 
+impl Validate for RegisterCrossAccountAccessRoleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveAttributesFromFindingsRequest {
     /// <p>The array of attribute keys that you want to remove from specified findings.</p>
     #[serde(rename = "attributeKeys")]
@@ -1274,8 +2024,33 @@ pub struct RemoveAttributesFromFindingsRequest {
     pub finding_arns: Vec<String>,
 }
 
+impl Validate for RemoveAttributesFromFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute_keys"),
+            self.attribute_keys.len(),
+            None,
+            Some(10),
+        );
+        for (i, item) in self.attribute_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attribute_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+        errors.check_length(
+            &field_path(path, "finding_arns"),
+            self.finding_arns.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.finding_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "finding_arns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveAttributesFromFindingsResponse {
     /// <p>Attributes details that cannot be described. An error code is provided for each failed item.</p>
     #[serde(rename = "failedItems")]
@@ -1284,7 +2059,7 @@ pub struct RemoveAttributesFromFindingsResponse {
 
 /// <p>Contains information about a resource group. The resource group defines a set of tags that, when queried, identify the AWS resources that make up the assessment target. This data type is used as the response element in the <a>DescribeResourceGroups</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceGroup {
     /// <p>The ARN of the resource group.</p>
     #[serde(rename = "arn")]
@@ -1309,9 +2084,28 @@ pub struct ResourceGroupTag {
     pub value: Option<String>,
 }
 
+impl Validate for ResourceGroupTag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>Contains information about an Amazon Inspector rules package. This data type is used as the response element in the <a>DescribeRulesPackages</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RulesPackage {
     /// <p>The ARN of the rules package.</p>
     #[serde(rename = "arn")]
@@ -1333,7 +2127,7 @@ pub struct RulesPackage {
 
 /// <p>This data type contains key-value pairs that identify various Amazon resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Scope {
     /// <p>The type of the scope.</p>
     #[serde(rename = "key")]
@@ -1347,7 +2141,7 @@ pub struct Scope {
 
 /// <p>Contains information about a security group associated with a network interface. This data type is used as one of the elements of the <a>NetworkInterface</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SecurityGroup {
     /// <p>The ID of the security group.</p>
     #[serde(rename = "groupId")]
@@ -1360,6 +2154,7 @@ pub struct SecurityGroup {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetTagsForResourceRequest {
     /// <p>The ARN of the assessment template that you want to set tags to.</p>
     #[serde(rename = "resourceArn")]
@@ -1370,7 +2165,26 @@ pub struct SetTagsForResourceRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for SetTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(10));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartAssessmentRunRequest {
     /// <p>You can specify the name for the assessment run. The name must be unique for the assessment template whose ARN is used to start the assessment run.</p>
     #[serde(rename = "assessmentRunName")]
@@ -1381,8 +2195,27 @@ pub struct StartAssessmentRunRequest {
     pub assessment_template_arn: String,
 }
 
+impl Validate for StartAssessmentRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.assessment_run_name {
+            errors.check_length(
+                &field_path(path, "assessment_run_name"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "assessment_template_arn"),
+            self.assessment_template_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartAssessmentRunResponse {
     /// <p>The ARN of the assessment run that has been started.</p>
     #[serde(rename = "assessmentRunArn")]
@@ -1390,6 +2223,7 @@ pub struct StartAssessmentRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopAssessmentRunRequest {
     /// <p>The ARN of the assessment run that you want to stop.</p>
     #[serde(rename = "assessmentRunArn")]
@@ -1400,7 +2234,19 @@ pub struct StopAssessmentRunRequest {
     pub stop_action: Option<String>,
 }
 
+impl Validate for StopAssessmentRunRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_run_arn"),
+            self.assessment_run_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SubscribeToEventRequest {
     /// <p>The event for which you want to receive SNS notifications.</p>
     #[serde(rename = "event")]
@@ -1413,9 +2259,26 @@ pub struct SubscribeToEventRequest {
     pub topic_arn: String,
 }
 
+impl Validate for SubscribeToEventRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "topic_arn"),
+            self.topic_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 /// <p>This data type is used as a response element in the <a>ListEventSubscriptions</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Subscription {
     /// <p>The list of existing event subscriptions.</p>
     #[serde(rename = "eventSubscriptions")]
@@ -1440,9 +2303,28 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>The metadata about the Amazon Inspector application data metrics collected by the agent. This data type is used as the response element in the <a>GetTelemetryMetadata</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TelemetryMetadata {
     /// <p>The count of messages that the agent sends to the Amazon Inspector service.</p>
     #[serde(rename = "count")]
@@ -1458,6 +2340,7 @@ pub struct TelemetryMetadata {
 
 /// <p>This data type is used in the <a>AssessmentRunFilter</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TimestampRange {
     /// <p>The minimum value of the timestamp range.</p>
     #[serde(rename = "beginDate")]
@@ -1470,6 +2353,7 @@ pub struct TimestampRange {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnsubscribeFromEventRequest {
     /// <p>The event for which you want to stop receiving SNS notifications.</p>
     #[serde(rename = "event")]
@@ -1482,7 +2366,25 @@ pub struct UnsubscribeFromEventRequest {
     pub topic_arn: String,
 }
 
+impl Validate for UnsubscribeFromEventRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "topic_arn"),
+            self.topic_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAssessmentTargetRequest {
     /// <p>The ARN of the assessment target that you want to update.</p>
     #[serde(rename = "assessmentTargetArn")]
@@ -1496,6 +2398,31 @@ pub struct UpdateAssessmentTargetRequest {
     pub resource_group_arn: Option<String>,
 }
 
+impl Validate for UpdateAssessmentTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assessment_target_arn"),
+            self.assessment_target_arn.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "assessment_target_name"),
+            self.assessment_target_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        if let Some(ref value) = self.resource_group_arn {
+            errors.check_length(
+                &field_path(path, "resource_group_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+    }
+}
+
 /// Errors returned by AddAttributesToFindings
 #[derive(Debug, PartialEq)]
 pub enum AddAttributesToFindingsError {
@@ -1538,7 +2465,12 @@ impl AddAttributesToFindingsError {
                         AddAttributesToFindingsError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1611,7 +2543,12 @@ impl CreateAssessmentTargetError {
                         CreateAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1685,7 +2622,12 @@ impl CreateAssessmentTemplateError {
                         CreateAssessmentTemplateError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1758,7 +2700,12 @@ impl CreateExclusionsPreviewError {
                         CreateExclusionsPreviewError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1818,7 +2765,12 @@ impl CreateResourceGroupError {
                         CreateResourceGroupError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1884,7 +2836,12 @@ impl DeleteAssessmentRunError {
                         DeleteAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1951,7 +2908,12 @@ impl DeleteAssessmentTargetError {
                         DeleteAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2024,7 +2986,12 @@ impl DeleteAssessmentTemplateError {
                         DeleteAssessmentTemplateError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2067,7 +3034,12 @@ impl DescribeAssessmentRunsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(DescribeAssessmentRunsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2108,7 +3080,12 @@ impl DescribeAssessmentTargetsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2153,7 +3130,12 @@ impl DescribeAssessmentTemplatesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2191,7 +3173,12 @@ impl DescribeCrossAccountAccessRoleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2229,7 +3216,12 @@ impl DescribeExclusionsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(DescribeExclusionsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2268,7 +3260,12 @@ impl DescribeFindingsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(DescribeFindingsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2307,7 +3304,12 @@ impl DescribeResourceGroupsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(DescribeResourceGroupsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2346,7 +3348,12 @@ impl DescribeRulesPackagesError {
                 "InvalidInputException" => {
                     return RusotoError::Service(DescribeRulesPackagesError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2416,7 +3423,12 @@ impl GetAssessmentReportError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2470,7 +3482,12 @@ impl GetExclusionsPreviewError {
                 "NoSuchEntityException" => {
                     return RusotoError::Service(GetExclusionsPreviewError::NoSuchEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2521,7 +3538,12 @@ impl GetTelemetryMetadataError {
                 "NoSuchEntityException" => {
                     return RusotoError::Service(GetTelemetryMetadataError::NoSuchEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2578,7 +3600,12 @@ impl ListAssessmentRunAgentsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2629,7 +3656,12 @@ impl ListAssessmentRunsError {
                 "NoSuchEntityException" => {
                     return RusotoError::Service(ListAssessmentRunsError::NoSuchEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2675,7 +3707,12 @@ impl ListAssessmentTargetsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(ListAssessmentTargetsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2731,7 +3768,12 @@ impl ListAssessmentTemplatesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2782,7 +3824,12 @@ impl ListEventSubscriptionsError {
                 "NoSuchEntityException" => {
                     return RusotoError::Service(ListEventSubscriptionsError::NoSuchEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2833,7 +3880,12 @@ impl ListExclusionsError {
                 "NoSuchEntityException" => {
                     return RusotoError::Service(ListExclusionsError::NoSuchEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2884,7 +3936,12 @@ impl ListFindingsError {
                 "NoSuchEntityException" => {
                     return RusotoError::Service(ListFindingsError::NoSuchEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2930,7 +3987,12 @@ impl ListRulesPackagesError {
                 "InvalidInputException" => {
                     return RusotoError::Service(ListRulesPackagesError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2980,7 +4042,12 @@ impl ListTagsForResourceError {
                 "NoSuchEntityException" => {
                     return RusotoError::Service(ListTagsForResourceError::NoSuchEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3038,7 +4105,12 @@ impl PreviewAgentsError {
                 "NoSuchEntityException" => {
                     return RusotoError::Service(PreviewAgentsError::NoSuchEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3107,7 +4179,12 @@ impl RegisterCrossAccountAccessRoleError {
                         RegisterCrossAccountAccessRoleError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3176,7 +4253,12 @@ impl RemoveAttributesFromFindingsError {
                         RemoveAttributesFromFindingsError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3235,7 +4317,12 @@ impl SetTagsForResourceError {
                         SetTagsForResourceError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3313,7 +4400,12 @@ impl StartAssessmentRunError {
                         StartAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3375,7 +4467,12 @@ impl StopAssessmentRunError {
                         StopAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3439,7 +4536,12 @@ impl SubscribeToEventError {
                         SubscribeToEventError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3499,7 +4601,12 @@ impl UnsubscribeFromEventError {
                         UnsubscribeFromEventError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3558,7 +4665,12 @@ impl UpdateAssessmentTargetError {
                         UpdateAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3804,969 +4916,1940 @@ pub trait Inspector {
         input: UpdateAssessmentTargetRequest,
     ) -> RusotoFuture<(), UpdateAssessmentTargetError>;
 }
-/// A client for the Amazon Inspector API.
-#[derive(Clone)]
-pub struct InspectorClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl InspectorClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> InspectorClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> InspectorClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> InspectorClient {
-        InspectorClient { client, region }
-    }
-}
-
-impl Inspector for InspectorClient {
-    /// <p>Assigns attributes (key and value pairs) to the findings that are specified by the ARNs of the findings.</p>
+const _: Option<&dyn Inspector> = None;
+impl<'a, T: Inspector + ?Sized> Inspector for &'a T {
     fn add_attributes_to_findings(
         &self,
         input: AddAttributesToFindingsRequest,
     ) -> RusotoFuture<AddAttributesToFindingsResponse, AddAttributesToFindingsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.AddAttributesToFindings");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddAttributesToFindingsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AddAttributesToFindingsError::from_response(response))
-                }))
-            }
-        })
+        (**self).add_attributes_to_findings(input)
     }
-
-    /// <p>Creates a new assessment target using the ARN of the resource group that is generated by <a>CreateResourceGroup</a>. If resourceGroupArn is not specified, all EC2 instances in the current AWS account and region are included in the assessment target. If the <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_slr.html">service-linked role</a> isn’t already registered, this action also creates and registers a service-linked role to grant Amazon Inspector access to AWS Services needed to perform security assessments. You can create up to 50 assessment targets per AWS account. You can run up to 500 concurrent agents per AWS account. For more information, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_applications.html"> Amazon Inspector Assessment Targets</a>.</p>
     fn create_assessment_target(
         &self,
         input: CreateAssessmentTargetRequest,
     ) -> RusotoFuture<CreateAssessmentTargetResponse, CreateAssessmentTargetError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.CreateAssessmentTarget");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAssessmentTargetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateAssessmentTargetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_assessment_target(input)
     }
-
-    /// <p>Creates an assessment template for the assessment target that is specified by the ARN of the assessment target. If the <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_slr.html">service-linked role</a> isn’t already registered, this action also creates and registers a service-linked role to grant Amazon Inspector access to AWS Services needed to perform security assessments.</p>
     fn create_assessment_template(
         &self,
         input: CreateAssessmentTemplateRequest,
     ) -> RusotoFuture<CreateAssessmentTemplateResponse, CreateAssessmentTemplateError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.CreateAssessmentTemplate");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAssessmentTemplateResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateAssessmentTemplateError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_assessment_template(input)
     }
-
-    /// <p>Starts the generation of an exclusions preview for the specified assessment template. The exclusions preview lists the potential exclusions (ExclusionPreview) that Inspector can detect before it runs the assessment. </p>
     fn create_exclusions_preview(
         &self,
         input: CreateExclusionsPreviewRequest,
     ) -> RusotoFuture<CreateExclusionsPreviewResponse, CreateExclusionsPreviewError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.CreateExclusionsPreview");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateExclusionsPreviewResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateExclusionsPreviewError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_exclusions_preview(input)
     }
-
-    /// <p>Creates a resource group using the specified set of tags (key and value pairs) that are used to select the EC2 instances to be included in an Amazon Inspector assessment target. The created resource group is then used to create an Amazon Inspector assessment target. For more information, see <a>CreateAssessmentTarget</a>.</p>
     fn create_resource_group(
         &self,
         input: CreateResourceGroupRequest,
     ) -> RusotoFuture<CreateResourceGroupResponse, CreateResourceGroupError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.CreateResourceGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateResourceGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateResourceGroupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_resource_group(input)
     }
-
-    /// <p>Deletes the assessment run that is specified by the ARN of the assessment run.</p>
     fn delete_assessment_run(
         &self,
         input: DeleteAssessmentRunRequest,
     ) -> RusotoFuture<(), DeleteAssessmentRunError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.DeleteAssessmentRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteAssessmentRunError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_assessment_run(input)
     }
-
-    /// <p>Deletes the assessment target that is specified by the ARN of the assessment target.</p>
     fn delete_assessment_target(
         &self,
         input: DeleteAssessmentTargetRequest,
     ) -> RusotoFuture<(), DeleteAssessmentTargetError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTarget");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteAssessmentTargetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_assessment_target(input)
     }
-
-    /// <p>Deletes the assessment template that is specified by the ARN of the assessment template.</p>
     fn delete_assessment_template(
         &self,
         input: DeleteAssessmentTemplateRequest,
     ) -> RusotoFuture<(), DeleteAssessmentTemplateError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTemplate");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteAssessmentTemplateError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_assessment_template(input)
     }
-
-    /// <p>Describes the assessment runs that are specified by the ARNs of the assessment runs.</p>
     fn describe_assessment_runs(
         &self,
         input: DescribeAssessmentRunsRequest,
     ) -> RusotoFuture<DescribeAssessmentRunsResponse, DescribeAssessmentRunsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.DescribeAssessmentRuns");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAssessmentRunsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeAssessmentRunsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_assessment_runs(input)
     }
-
-    /// <p>Describes the assessment targets that are specified by the ARNs of the assessment targets.</p>
     fn describe_assessment_targets(
         &self,
         input: DescribeAssessmentTargetsRequest,
     ) -> RusotoFuture<DescribeAssessmentTargetsResponse, DescribeAssessmentTargetsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.DescribeAssessmentTargets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAssessmentTargetsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAssessmentTargetsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_assessment_targets(input)
     }
-
-    /// <p>Describes the assessment templates that are specified by the ARNs of the assessment templates.</p>
     fn describe_assessment_templates(
         &self,
         input: DescribeAssessmentTemplatesRequest,
     ) -> RusotoFuture<DescribeAssessmentTemplatesResponse, DescribeAssessmentTemplatesError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "InspectorService.DescribeAssessmentTemplates",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAssessmentTemplatesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAssessmentTemplatesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_assessment_templates(input)
     }
-
-    /// <p>Describes the IAM role that enables Amazon Inspector to access your AWS account.</p>
     fn describe_cross_account_access_role(
         &self,
     ) -> RusotoFuture<DescribeCrossAccountAccessRoleResponse, DescribeCrossAccountAccessRoleError>
     {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "InspectorService.DescribeCrossAccountAccessRole",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCrossAccountAccessRoleResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCrossAccountAccessRoleError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_cross_account_access_role()
     }
-
-    /// <p>Describes the exclusions that are specified by the exclusions' ARNs.</p>
     fn describe_exclusions(
         &self,
         input: DescribeExclusionsRequest,
     ) -> RusotoFuture<DescribeExclusionsResponse, DescribeExclusionsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.DescribeExclusions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeExclusionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeExclusionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_exclusions(input)
     }
-
-    /// <p>Describes the findings that are specified by the ARNs of the findings.</p>
     fn describe_findings(
         &self,
         input: DescribeFindingsRequest,
     ) -> RusotoFuture<DescribeFindingsResponse, DescribeFindingsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.DescribeFindings");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeFindingsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeFindingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_findings(input)
     }
-
-    /// <p>Describes the resource groups that are specified by the ARNs of the resource groups.</p>
     fn describe_resource_groups(
         &self,
         input: DescribeResourceGroupsRequest,
     ) -> RusotoFuture<DescribeResourceGroupsResponse, DescribeResourceGroupsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.DescribeResourceGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeResourceGroupsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeResourceGroupsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_resource_groups(input)
     }
-
-    /// <p>Describes the rules packages that are specified by the ARNs of the rules packages.</p>
     fn describe_rules_packages(
         &self,
         input: DescribeRulesPackagesRequest,
     ) -> RusotoFuture<DescribeRulesPackagesResponse, DescribeRulesPackagesError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.DescribeRulesPackages");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRulesPackagesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeRulesPackagesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_rules_packages(input)
     }
-
-    /// <p>Produces an assessment report that includes detailed and comprehensive results of a specified assessment run. </p>
     fn get_assessment_report(
         &self,
         input: GetAssessmentReportRequest,
     ) -> RusotoFuture<GetAssessmentReportResponse, GetAssessmentReportError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.GetAssessmentReport");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAssessmentReportResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetAssessmentReportError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_assessment_report(input)
     }
-
-    /// <p>Retrieves the exclusions preview (a list of ExclusionPreview objects) specified by the preview token. You can obtain the preview token by running the CreateExclusionsPreview API.</p>
     fn get_exclusions_preview(
         &self,
         input: GetExclusionsPreviewRequest,
     ) -> RusotoFuture<GetExclusionsPreviewResponse, GetExclusionsPreviewError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.GetExclusionsPreview");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetExclusionsPreviewResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetExclusionsPreviewError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_exclusions_preview(input)
     }
-
-    /// <p>Information about the data that is collected for the specified assessment run.</p>
     fn get_telemetry_metadata(
         &self,
         input: GetTelemetryMetadataRequest,
     ) -> RusotoFuture<GetTelemetryMetadataResponse, GetTelemetryMetadataError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.GetTelemetryMetadata");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTelemetryMetadataResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetTelemetryMetadataError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_telemetry_metadata(input)
     }
-
-    /// <p>Lists the agents of the assessment runs that are specified by the ARNs of the assessment runs.</p>
     fn list_assessment_run_agents(
         &self,
         input: ListAssessmentRunAgentsRequest,
     ) -> RusotoFuture<ListAssessmentRunAgentsResponse, ListAssessmentRunAgentsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.ListAssessmentRunAgents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAssessmentRunAgentsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListAssessmentRunAgentsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_assessment_run_agents(input)
     }
-
-    /// <p>Lists the assessment runs that correspond to the assessment templates that are specified by the ARNs of the assessment templates.</p>
     fn list_assessment_runs(
         &self,
         input: ListAssessmentRunsRequest,
     ) -> RusotoFuture<ListAssessmentRunsResponse, ListAssessmentRunsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.ListAssessmentRuns");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAssessmentRunsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAssessmentRunsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_assessment_runs(input)
     }
-
-    /// <p>Lists the ARNs of the assessment targets within this AWS account. For more information about assessment targets, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_applications.html">Amazon Inspector Assessment Targets</a>.</p>
     fn list_assessment_targets(
         &self,
         input: ListAssessmentTargetsRequest,
     ) -> RusotoFuture<ListAssessmentTargetsResponse, ListAssessmentTargetsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.ListAssessmentTargets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAssessmentTargetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListAssessmentTargetsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_assessment_targets(input)
     }
-
-    /// <p>Lists the assessment templates that correspond to the assessment targets that are specified by the ARNs of the assessment targets.</p>
     fn list_assessment_templates(
         &self,
         input: ListAssessmentTemplatesRequest,
     ) -> RusotoFuture<ListAssessmentTemplatesResponse, ListAssessmentTemplatesError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.ListAssessmentTemplates");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAssessmentTemplatesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListAssessmentTemplatesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_assessment_templates(input)
     }
-
-    /// <p>Lists all the event subscriptions for the assessment template that is specified by the ARN of the assessment template. For more information, see <a>SubscribeToEvent</a> and <a>UnsubscribeFromEvent</a>.</p>
     fn list_event_subscriptions(
         &self,
         input: ListEventSubscriptionsRequest,
     ) -> RusotoFuture<ListEventSubscriptionsResponse, ListEventSubscriptionsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.ListEventSubscriptions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListEventSubscriptionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListEventSubscriptionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_event_subscriptions(input)
     }
-
-    /// <p>List exclusions that are generated by the assessment run.</p>
     fn list_exclusions(
         &self,
         input: ListExclusionsRequest,
     ) -> RusotoFuture<ListExclusionsResponse, ListExclusionsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.ListExclusions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListExclusionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListExclusionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_exclusions(input)
     }
-
-    /// <p>Lists findings that are generated by the assessment runs that are specified by the ARNs of the assessment runs.</p>
     fn list_findings(
         &self,
         input: ListFindingsRequest,
     ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.ListFindings");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFindingsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListFindingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_findings(input)
     }
-
-    /// <p>Lists all available Amazon Inspector rules packages.</p>
     fn list_rules_packages(
         &self,
         input: ListRulesPackagesRequest,
     ) -> RusotoFuture<ListRulesPackagesResponse, ListRulesPackagesError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.ListRulesPackages");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRulesPackagesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRulesPackagesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_rules_packages(input)
     }
-
-    /// <p>Lists all tags associated with an assessment template.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Previews the agents installed on the EC2 instances that are part of the specified assessment target.</p>
     fn preview_agents(
         &self,
         input: PreviewAgentsRequest,
     ) -> RusotoFuture<PreviewAgentsResponse, PreviewAgentsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.PreviewAgents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PreviewAgentsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PreviewAgentsError::from_response(response))),
-                )
-            }
-        })
+        (**self).preview_agents(input)
     }
-
-    /// <p>Registers the IAM role that grants Amazon Inspector access to AWS Services needed to perform security assessments.</p>
     fn register_cross_account_access_role(
         &self,
         input: RegisterCrossAccountAccessRoleRequest,
     ) -> RusotoFuture<(), RegisterCrossAccountAccessRoleError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "InspectorService.RegisterCrossAccountAccessRole",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegisterCrossAccountAccessRoleError::from_response(response))
-                }))
-            }
-        })
+        (**self).register_cross_account_access_role(input)
     }
-
-    /// <p>Removes entire attributes (key and value pairs) from the findings that are specified by the ARNs of the findings where an attribute with the specified key exists.</p>
     fn remove_attributes_from_findings(
         &self,
         input: RemoveAttributesFromFindingsRequest,
     ) -> RusotoFuture<RemoveAttributesFromFindingsResponse, RemoveAttributesFromFindingsError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "InspectorService.RemoveAttributesFromFindings",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveAttributesFromFindingsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RemoveAttributesFromFindingsError::from_response(response))
-                }))
-            }
-        })
+        (**self).remove_attributes_from_findings(input)
     }
-
-    /// <p>Sets tags (key and value pairs) to the assessment template that is specified by the ARN of the assessment template.</p>
     fn set_tags_for_resource(
         &self,
         input: SetTagsForResourceRequest,
     ) -> RusotoFuture<(), SetTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.SetTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetTagsForResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).set_tags_for_resource(input)
     }
-
-    /// <p>Starts the assessment run specified by the ARN of the assessment template. For this API to function properly, you must not exceed the limit of running up to 500 concurrent agents per AWS account.</p>
     fn start_assessment_run(
         &self,
         input: StartAssessmentRunRequest,
     ) -> RusotoFuture<StartAssessmentRunResponse, StartAssessmentRunError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.StartAssessmentRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartAssessmentRunResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartAssessmentRunError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_assessment_run(input)
     }
-
-    /// <p>Stops the assessment run that is specified by the ARN of the assessment run.</p>
     fn stop_assessment_run(
         &self,
         input: StopAssessmentRunRequest,
     ) -> RusotoFuture<(), StopAssessmentRunError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.StopAssessmentRun");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
+        (**self).stop_assessment_run(input)
+    }
+    fn subscribe_to_event(
+        &self,
+        input: SubscribeToEventRequest,
+    ) -> RusotoFuture<(), SubscribeToEventError> {
+        (**self).subscribe_to_event(input)
+    }
+    fn unsubscribe_from_event(
+        &self,
+        input: UnsubscribeFromEventRequest,
+    ) -> RusotoFuture<(), UnsubscribeFromEventError> {
+        (**self).unsubscribe_from_event(input)
+    }
+    fn update_assessment_target(
+        &self,
+        input: UpdateAssessmentTargetRequest,
+    ) -> RusotoFuture<(), UpdateAssessmentTargetError> {
+        (**self).update_assessment_target(input)
+    }
+}
+impl<T: Inspector + ?Sized> Inspector for Box<T> {
+    fn add_attributes_to_findings(
+        &self,
+        input: AddAttributesToFindingsRequest,
+    ) -> RusotoFuture<AddAttributesToFindingsResponse, AddAttributesToFindingsError> {
+        (**self).add_attributes_to_findings(input)
+    }
+    fn create_assessment_target(
+        &self,
+        input: CreateAssessmentTargetRequest,
+    ) -> RusotoFuture<CreateAssessmentTargetResponse, CreateAssessmentTargetError> {
+        (**self).create_assessment_target(input)
+    }
+    fn create_assessment_template(
+        &self,
+        input: CreateAssessmentTemplateRequest,
+    ) -> RusotoFuture<CreateAssessmentTemplateResponse, CreateAssessmentTemplateError> {
+        (**self).create_assessment_template(input)
+    }
+    fn create_exclusions_preview(
+        &self,
+        input: CreateExclusionsPreviewRequest,
+    ) -> RusotoFuture<CreateExclusionsPreviewResponse, CreateExclusionsPreviewError> {
+        (**self).create_exclusions_preview(input)
+    }
+    fn create_resource_group(
+        &self,
+        input: CreateResourceGroupRequest,
+    ) -> RusotoFuture<CreateResourceGroupResponse, CreateResourceGroupError> {
+        (**self).create_resource_group(input)
+    }
+    fn delete_assessment_run(
+        &self,
+        input: DeleteAssessmentRunRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentRunError> {
+        (**self).delete_assessment_run(input)
+    }
+    fn delete_assessment_target(
+        &self,
+        input: DeleteAssessmentTargetRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentTargetError> {
+        (**self).delete_assessment_target(input)
+    }
+    fn delete_assessment_template(
+        &self,
+        input: DeleteAssessmentTemplateRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentTemplateError> {
+        (**self).delete_assessment_template(input)
+    }
+    fn describe_assessment_runs(
+        &self,
+        input: DescribeAssessmentRunsRequest,
+    ) -> RusotoFuture<DescribeAssessmentRunsResponse, DescribeAssessmentRunsError> {
+        (**self).describe_assessment_runs(input)
+    }
+    fn describe_assessment_targets(
+        &self,
+        input: DescribeAssessmentTargetsRequest,
+    ) -> RusotoFuture<DescribeAssessmentTargetsResponse, DescribeAssessmentTargetsError> {
+        (**self).describe_assessment_targets(input)
+    }
+    fn describe_assessment_templates(
+        &self,
+        input: DescribeAssessmentTemplatesRequest,
+    ) -> RusotoFuture<DescribeAssessmentTemplatesResponse, DescribeAssessmentTemplatesError> {
+        (**self).describe_assessment_templates(input)
+    }
+    fn describe_cross_account_access_role(
+        &self,
+    ) -> RusotoFuture<DescribeCrossAccountAccessRoleResponse, DescribeCrossAccountAccessRoleError>
+    {
+        (**self).describe_cross_account_access_role()
+    }
+    fn describe_exclusions(
+        &self,
+        input: DescribeExclusionsRequest,
+    ) -> RusotoFuture<DescribeExclusionsResponse, DescribeExclusionsError> {
+        (**self).describe_exclusions(input)
+    }
+    fn describe_findings(
+        &self,
+        input: DescribeFindingsRequest,
+    ) -> RusotoFuture<DescribeFindingsResponse, DescribeFindingsError> {
+        (**self).describe_findings(input)
+    }
+    fn describe_resource_groups(
+        &self,
+        input: DescribeResourceGroupsRequest,
+    ) -> RusotoFuture<DescribeResourceGroupsResponse, DescribeResourceGroupsError> {
+        (**self).describe_resource_groups(input)
+    }
+    fn describe_rules_packages(
+        &self,
+        input: DescribeRulesPackagesRequest,
+    ) -> RusotoFuture<DescribeRulesPackagesResponse, DescribeRulesPackagesError> {
+        (**self).describe_rules_packages(input)
+    }
+    fn get_assessment_report(
+        &self,
+        input: GetAssessmentReportRequest,
+    ) -> RusotoFuture<GetAssessmentReportResponse, GetAssessmentReportError> {
+        (**self).get_assessment_report(input)
+    }
+    fn get_exclusions_preview(
+        &self,
+        input: GetExclusionsPreviewRequest,
+    ) -> RusotoFuture<GetExclusionsPreviewResponse, GetExclusionsPreviewError> {
+        (**self).get_exclusions_preview(input)
+    }
+    fn get_telemetry_metadata(
+        &self,
+        input: GetTelemetryMetadataRequest,
+    ) -> RusotoFuture<GetTelemetryMetadataResponse, GetTelemetryMetadataError> {
+        (**self).get_telemetry_metadata(input)
+    }
+    fn list_assessment_run_agents(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> RusotoFuture<ListAssessmentRunAgentsResponse, ListAssessmentRunAgentsError> {
+        (**self).list_assessment_run_agents(input)
+    }
+    fn list_assessment_runs(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> RusotoFuture<ListAssessmentRunsResponse, ListAssessmentRunsError> {
+        (**self).list_assessment_runs(input)
+    }
+    fn list_assessment_targets(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> RusotoFuture<ListAssessmentTargetsResponse, ListAssessmentTargetsError> {
+        (**self).list_assessment_targets(input)
+    }
+    fn list_assessment_templates(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> RusotoFuture<ListAssessmentTemplatesResponse, ListAssessmentTemplatesError> {
+        (**self).list_assessment_templates(input)
+    }
+    fn list_event_subscriptions(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> RusotoFuture<ListEventSubscriptionsResponse, ListEventSubscriptionsError> {
+        (**self).list_event_subscriptions(input)
+    }
+    fn list_exclusions(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> RusotoFuture<ListExclusionsResponse, ListExclusionsError> {
+        (**self).list_exclusions(input)
+    }
+    fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
+        (**self).list_findings(input)
+    }
+    fn list_rules_packages(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> RusotoFuture<ListRulesPackagesResponse, ListRulesPackagesError> {
+        (**self).list_rules_packages(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn preview_agents(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> RusotoFuture<PreviewAgentsResponse, PreviewAgentsError> {
+        (**self).preview_agents(input)
+    }
+    fn register_cross_account_access_role(
+        &self,
+        input: RegisterCrossAccountAccessRoleRequest,
+    ) -> RusotoFuture<(), RegisterCrossAccountAccessRoleError> {
+        (**self).register_cross_account_access_role(input)
+    }
+    fn remove_attributes_from_findings(
+        &self,
+        input: RemoveAttributesFromFindingsRequest,
+    ) -> RusotoFuture<RemoveAttributesFromFindingsResponse, RemoveAttributesFromFindingsError> {
+        (**self).remove_attributes_from_findings(input)
+    }
+    fn set_tags_for_resource(
+        &self,
+        input: SetTagsForResourceRequest,
+    ) -> RusotoFuture<(), SetTagsForResourceError> {
+        (**self).set_tags_for_resource(input)
+    }
+    fn start_assessment_run(
+        &self,
+        input: StartAssessmentRunRequest,
+    ) -> RusotoFuture<StartAssessmentRunResponse, StartAssessmentRunError> {
+        (**self).start_assessment_run(input)
+    }
+    fn stop_assessment_run(
+        &self,
+        input: StopAssessmentRunRequest,
+    ) -> RusotoFuture<(), StopAssessmentRunError> {
+        (**self).stop_assessment_run(input)
+    }
+    fn subscribe_to_event(
+        &self,
+        input: SubscribeToEventRequest,
+    ) -> RusotoFuture<(), SubscribeToEventError> {
+        (**self).subscribe_to_event(input)
+    }
+    fn unsubscribe_from_event(
+        &self,
+        input: UnsubscribeFromEventRequest,
+    ) -> RusotoFuture<(), UnsubscribeFromEventError> {
+        (**self).unsubscribe_from_event(input)
+    }
+    fn update_assessment_target(
+        &self,
+        input: UpdateAssessmentTargetRequest,
+    ) -> RusotoFuture<(), UpdateAssessmentTargetError> {
+        (**self).update_assessment_target(input)
+    }
+}
+impl<T: Inspector + ?Sized> Inspector for ::std::sync::Arc<T> {
+    fn add_attributes_to_findings(
+        &self,
+        input: AddAttributesToFindingsRequest,
+    ) -> RusotoFuture<AddAttributesToFindingsResponse, AddAttributesToFindingsError> {
+        (**self).add_attributes_to_findings(input)
+    }
+    fn create_assessment_target(
+        &self,
+        input: CreateAssessmentTargetRequest,
+    ) -> RusotoFuture<CreateAssessmentTargetResponse, CreateAssessmentTargetError> {
+        (**self).create_assessment_target(input)
+    }
+    fn create_assessment_template(
+        &self,
+        input: CreateAssessmentTemplateRequest,
+    ) -> RusotoFuture<CreateAssessmentTemplateResponse, CreateAssessmentTemplateError> {
+        (**self).create_assessment_template(input)
+    }
+    fn create_exclusions_preview(
+        &self,
+        input: CreateExclusionsPreviewRequest,
+    ) -> RusotoFuture<CreateExclusionsPreviewResponse, CreateExclusionsPreviewError> {
+        (**self).create_exclusions_preview(input)
+    }
+    fn create_resource_group(
+        &self,
+        input: CreateResourceGroupRequest,
+    ) -> RusotoFuture<CreateResourceGroupResponse, CreateResourceGroupError> {
+        (**self).create_resource_group(input)
+    }
+    fn delete_assessment_run(
+        &self,
+        input: DeleteAssessmentRunRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentRunError> {
+        (**self).delete_assessment_run(input)
+    }
+    fn delete_assessment_target(
+        &self,
+        input: DeleteAssessmentTargetRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentTargetError> {
+        (**self).delete_assessment_target(input)
+    }
+    fn delete_assessment_template(
+        &self,
+        input: DeleteAssessmentTemplateRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentTemplateError> {
+        (**self).delete_assessment_template(input)
+    }
+    fn describe_assessment_runs(
+        &self,
+        input: DescribeAssessmentRunsRequest,
+    ) -> RusotoFuture<DescribeAssessmentRunsResponse, DescribeAssessmentRunsError> {
+        (**self).describe_assessment_runs(input)
+    }
+    fn describe_assessment_targets(
+        &self,
+        input: DescribeAssessmentTargetsRequest,
+    ) -> RusotoFuture<DescribeAssessmentTargetsResponse, DescribeAssessmentTargetsError> {
+        (**self).describe_assessment_targets(input)
+    }
+    fn describe_assessment_templates(
+        &self,
+        input: DescribeAssessmentTemplatesRequest,
+    ) -> RusotoFuture<DescribeAssessmentTemplatesResponse, DescribeAssessmentTemplatesError> {
+        (**self).describe_assessment_templates(input)
+    }
+    fn describe_cross_account_access_role(
+        &self,
+    ) -> RusotoFuture<DescribeCrossAccountAccessRoleResponse, DescribeCrossAccountAccessRoleError>
+    {
+        (**self).describe_cross_account_access_role()
+    }
+    fn describe_exclusions(
+        &self,
+        input: DescribeExclusionsRequest,
+    ) -> RusotoFuture<DescribeExclusionsResponse, DescribeExclusionsError> {
+        (**self).describe_exclusions(input)
+    }
+    fn describe_findings(
+        &self,
+        input: DescribeFindingsRequest,
+    ) -> RusotoFuture<DescribeFindingsResponse, DescribeFindingsError> {
+        (**self).describe_findings(input)
+    }
+    fn describe_resource_groups(
+        &self,
+        input: DescribeResourceGroupsRequest,
+    ) -> RusotoFuture<DescribeResourceGroupsResponse, DescribeResourceGroupsError> {
+        (**self).describe_resource_groups(input)
+    }
+    fn describe_rules_packages(
+        &self,
+        input: DescribeRulesPackagesRequest,
+    ) -> RusotoFuture<DescribeRulesPackagesResponse, DescribeRulesPackagesError> {
+        (**self).describe_rules_packages(input)
+    }
+    fn get_assessment_report(
+        &self,
+        input: GetAssessmentReportRequest,
+    ) -> RusotoFuture<GetAssessmentReportResponse, GetAssessmentReportError> {
+        (**self).get_assessment_report(input)
+    }
+    fn get_exclusions_preview(
+        &self,
+        input: GetExclusionsPreviewRequest,
+    ) -> RusotoFuture<GetExclusionsPreviewResponse, GetExclusionsPreviewError> {
+        (**self).get_exclusions_preview(input)
+    }
+    fn get_telemetry_metadata(
+        &self,
+        input: GetTelemetryMetadataRequest,
+    ) -> RusotoFuture<GetTelemetryMetadataResponse, GetTelemetryMetadataError> {
+        (**self).get_telemetry_metadata(input)
+    }
+    fn list_assessment_run_agents(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> RusotoFuture<ListAssessmentRunAgentsResponse, ListAssessmentRunAgentsError> {
+        (**self).list_assessment_run_agents(input)
+    }
+    fn list_assessment_runs(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> RusotoFuture<ListAssessmentRunsResponse, ListAssessmentRunsError> {
+        (**self).list_assessment_runs(input)
+    }
+    fn list_assessment_targets(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> RusotoFuture<ListAssessmentTargetsResponse, ListAssessmentTargetsError> {
+        (**self).list_assessment_targets(input)
+    }
+    fn list_assessment_templates(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> RusotoFuture<ListAssessmentTemplatesResponse, ListAssessmentTemplatesError> {
+        (**self).list_assessment_templates(input)
+    }
+    fn list_event_subscriptions(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> RusotoFuture<ListEventSubscriptionsResponse, ListEventSubscriptionsError> {
+        (**self).list_event_subscriptions(input)
+    }
+    fn list_exclusions(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> RusotoFuture<ListExclusionsResponse, ListExclusionsError> {
+        (**self).list_exclusions(input)
+    }
+    fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
+        (**self).list_findings(input)
+    }
+    fn list_rules_packages(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> RusotoFuture<ListRulesPackagesResponse, ListRulesPackagesError> {
+        (**self).list_rules_packages(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn preview_agents(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> RusotoFuture<PreviewAgentsResponse, PreviewAgentsError> {
+        (**self).preview_agents(input)
+    }
+    fn register_cross_account_access_role(
+        &self,
+        input: RegisterCrossAccountAccessRoleRequest,
+    ) -> RusotoFuture<(), RegisterCrossAccountAccessRoleError> {
+        (**self).register_cross_account_access_role(input)
+    }
+    fn remove_attributes_from_findings(
+        &self,
+        input: RemoveAttributesFromFindingsRequest,
+    ) -> RusotoFuture<RemoveAttributesFromFindingsResponse, RemoveAttributesFromFindingsError> {
+        (**self).remove_attributes_from_findings(input)
+    }
+    fn set_tags_for_resource(
+        &self,
+        input: SetTagsForResourceRequest,
+    ) -> RusotoFuture<(), SetTagsForResourceError> {
+        (**self).set_tags_for_resource(input)
+    }
+    fn start_assessment_run(
+        &self,
+        input: StartAssessmentRunRequest,
+    ) -> RusotoFuture<StartAssessmentRunResponse, StartAssessmentRunError> {
+        (**self).start_assessment_run(input)
+    }
+    fn stop_assessment_run(
+        &self,
+        input: StopAssessmentRunRequest,
+    ) -> RusotoFuture<(), StopAssessmentRunError> {
+        (**self).stop_assessment_run(input)
+    }
+    fn subscribe_to_event(
+        &self,
+        input: SubscribeToEventRequest,
+    ) -> RusotoFuture<(), SubscribeToEventError> {
+        (**self).subscribe_to_event(input)
+    }
+    fn unsubscribe_from_event(
+        &self,
+        input: UnsubscribeFromEventRequest,
+    ) -> RusotoFuture<(), UnsubscribeFromEventError> {
+        (**self).unsubscribe_from_event(input)
+    }
+    fn update_assessment_target(
+        &self,
+        input: UpdateAssessmentTargetRequest,
+    ) -> RusotoFuture<(), UpdateAssessmentTargetError> {
+        (**self).update_assessment_target(input)
+    }
+}
+/// A client for the Amazon Inspector API.
+#[derive(Clone)]
+pub struct InspectorClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl InspectorClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> InspectorClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> InspectorClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> InspectorClient {
+        InspectorClient { client, region }
+    }
+}
+
+impl Inspector for InspectorClient {
+    /// <p>Assigns attributes (key and value pairs) to the findings that are specified by the ARNs of the findings.</p>
+    fn add_attributes_to_findings(
+        &self,
+        input: AddAttributesToFindingsRequest,
+    ) -> RusotoFuture<AddAttributesToFindingsResponse, AddAttributesToFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("AddAttributesToFindings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.AddAttributesToFindings");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddAttributesToFindingsResponse, _>(
+                    response,
+                    "AddAttributesToFindings",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AddAttributesToFindingsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a new assessment target using the ARN of the resource group that is generated by <a>CreateResourceGroup</a>. If resourceGroupArn is not specified, all EC2 instances in the current AWS account and region are included in the assessment target. If the <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_slr.html">service-linked role</a> isn’t already registered, this action also creates and registers a service-linked role to grant Amazon Inspector access to AWS Services needed to perform security assessments. You can create up to 50 assessment targets per AWS account. You can run up to 500 concurrent agents per AWS account. For more information, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_applications.html"> Amazon Inspector Assessment Targets</a>.</p>
+    fn create_assessment_target(
+        &self,
+        input: CreateAssessmentTargetRequest,
+    ) -> RusotoFuture<CreateAssessmentTargetResponse, CreateAssessmentTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateAssessmentTarget");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.CreateAssessmentTarget");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAssessmentTargetResponse, _>(
+                    response,
+                    "CreateAssessmentTarget",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateAssessmentTargetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an assessment template for the assessment target that is specified by the ARN of the assessment target. If the <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_slr.html">service-linked role</a> isn’t already registered, this action also creates and registers a service-linked role to grant Amazon Inspector access to AWS Services needed to perform security assessments.</p>
+    fn create_assessment_template(
+        &self,
+        input: CreateAssessmentTemplateRequest,
+    ) -> RusotoFuture<CreateAssessmentTemplateResponse, CreateAssessmentTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateAssessmentTemplate");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.CreateAssessmentTemplate");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAssessmentTemplateResponse, _>(
+                    response,
+                    "CreateAssessmentTemplate",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateAssessmentTemplateError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts the generation of an exclusions preview for the specified assessment template. The exclusions preview lists the potential exclusions (ExclusionPreview) that Inspector can detect before it runs the assessment. </p>
+    fn create_exclusions_preview(
+        &self,
+        input: CreateExclusionsPreviewRequest,
+    ) -> RusotoFuture<CreateExclusionsPreviewResponse, CreateExclusionsPreviewError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateExclusionsPreview");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.CreateExclusionsPreview");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateExclusionsPreviewResponse, _>(
+                    response,
+                    "CreateExclusionsPreview",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateExclusionsPreviewError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a resource group using the specified set of tags (key and value pairs) that are used to select the EC2 instances to be included in an Amazon Inspector assessment target. The created resource group is then used to create an Amazon Inspector assessment target. For more information, see <a>CreateAssessmentTarget</a>.</p>
+    fn create_resource_group(
+        &self,
+        input: CreateResourceGroupRequest,
+    ) -> RusotoFuture<CreateResourceGroupResponse, CreateResourceGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateResourceGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.CreateResourceGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateResourceGroupResponse, _>(
+                    response,
+                    "CreateResourceGroup",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateResourceGroupError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the assessment run that is specified by the ARN of the assessment run.</p>
+    fn delete_assessment_run(
+        &self,
+        input: DeleteAssessmentRunRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DeleteAssessmentRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.DeleteAssessmentRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteAssessmentRunError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the assessment target that is specified by the ARN of the assessment target.</p>
+    fn delete_assessment_target(
+        &self,
+        input: DeleteAssessmentTargetRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DeleteAssessmentTarget");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTarget");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteAssessmentTargetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the assessment template that is specified by the ARN of the assessment template.</p>
+    fn delete_assessment_template(
+        &self,
+        input: DeleteAssessmentTemplateRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DeleteAssessmentTemplate");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTemplate");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteAssessmentTemplateError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the assessment runs that are specified by the ARNs of the assessment runs.</p>
+    fn describe_assessment_runs(
+        &self,
+        input: DescribeAssessmentRunsRequest,
+    ) -> RusotoFuture<DescribeAssessmentRunsResponse, DescribeAssessmentRunsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeAssessmentRuns");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.DescribeAssessmentRuns");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAssessmentRunsResponse, _>(
+                    response,
+                    "DescribeAssessmentRuns",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeAssessmentRunsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the assessment targets that are specified by the ARNs of the assessment targets.</p>
+    fn describe_assessment_targets(
+        &self,
+        input: DescribeAssessmentTargetsRequest,
+    ) -> RusotoFuture<DescribeAssessmentTargetsResponse, DescribeAssessmentTargetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeAssessmentTargets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.DescribeAssessmentTargets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAssessmentTargetsResponse, _>(
+                    response,
+                    "DescribeAssessmentTargets",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAssessmentTargetsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the assessment templates that are specified by the ARNs of the assessment templates.</p>
+    fn describe_assessment_templates(
+        &self,
+        input: DescribeAssessmentTemplatesRequest,
+    ) -> RusotoFuture<DescribeAssessmentTemplatesResponse, DescribeAssessmentTemplatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeAssessmentTemplates");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "InspectorService.DescribeAssessmentTemplates",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAssessmentTemplatesResponse, _>(
+                    response,
+                    "DescribeAssessmentTemplates",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAssessmentTemplatesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the IAM role that enables Amazon Inspector to access your AWS account.</p>
+    fn describe_cross_account_access_role(
+        &self,
+    ) -> RusotoFuture<DescribeCrossAccountAccessRoleResponse, DescribeCrossAccountAccessRoleError>
+    {
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeCrossAccountAccessRole");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "InspectorService.DescribeCrossAccountAccessRole",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCrossAccountAccessRoleResponse, _>(
+                    response,
+                    "DescribeCrossAccountAccessRole",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCrossAccountAccessRoleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the exclusions that are specified by the exclusions' ARNs.</p>
+    fn describe_exclusions(
+        &self,
+        input: DescribeExclusionsRequest,
+    ) -> RusotoFuture<DescribeExclusionsResponse, DescribeExclusionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeExclusions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.DescribeExclusions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeExclusionsResponse, _>(
+                    response,
+                    "DescribeExclusions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeExclusionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the findings that are specified by the ARNs of the findings.</p>
+    fn describe_findings(
+        &self,
+        input: DescribeFindingsRequest,
+    ) -> RusotoFuture<DescribeFindingsResponse, DescribeFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeFindings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.DescribeFindings");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeFindingsResponse, _>(
+                    response,
+                    "DescribeFindings",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeFindingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the resource groups that are specified by the ARNs of the resource groups.</p>
+    fn describe_resource_groups(
+        &self,
+        input: DescribeResourceGroupsRequest,
+    ) -> RusotoFuture<DescribeResourceGroupsResponse, DescribeResourceGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeResourceGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.DescribeResourceGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeResourceGroupsResponse, _>(
+                    response,
+                    "DescribeResourceGroups",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeResourceGroupsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the rules packages that are specified by the ARNs of the rules packages.</p>
+    fn describe_rules_packages(
+        &self,
+        input: DescribeRulesPackagesRequest,
+    ) -> RusotoFuture<DescribeRulesPackagesResponse, DescribeRulesPackagesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeRulesPackages");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.DescribeRulesPackages");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRulesPackagesResponse, _>(
+                    response,
+                    "DescribeRulesPackages",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeRulesPackagesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Produces an assessment report that includes detailed and comprehensive results of a specified assessment run. </p>
+    fn get_assessment_report(
+        &self,
+        input: GetAssessmentReportRequest,
+    ) -> RusotoFuture<GetAssessmentReportResponse, GetAssessmentReportError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("GetAssessmentReport");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.GetAssessmentReport");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAssessmentReportResponse, _>(
+                    response,
+                    "GetAssessmentReport",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetAssessmentReportError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the exclusions preview (a list of ExclusionPreview objects) specified by the preview token. You can obtain the preview token by running the CreateExclusionsPreview API.</p>
+    fn get_exclusions_preview(
+        &self,
+        input: GetExclusionsPreviewRequest,
+    ) -> RusotoFuture<GetExclusionsPreviewResponse, GetExclusionsPreviewError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("GetExclusionsPreview");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.GetExclusionsPreview");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetExclusionsPreviewResponse, _>(
+                    response,
+                    "GetExclusionsPreview",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetExclusionsPreviewError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Information about the data that is collected for the specified assessment run.</p>
+    fn get_telemetry_metadata(
+        &self,
+        input: GetTelemetryMetadataRequest,
+    ) -> RusotoFuture<GetTelemetryMetadataResponse, GetTelemetryMetadataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("GetTelemetryMetadata");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.GetTelemetryMetadata");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetTelemetryMetadataResponse, _>(
+                    response,
+                    "GetTelemetryMetadata",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetTelemetryMetadataError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the agents of the assessment runs that are specified by the ARNs of the assessment runs.</p>
+    fn list_assessment_run_agents(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> RusotoFuture<ListAssessmentRunAgentsResponse, ListAssessmentRunAgentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentRunAgents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.ListAssessmentRunAgents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAssessmentRunAgentsResponse, _>(
+                    response,
+                    "ListAssessmentRunAgents",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAssessmentRunAgentsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the assessment runs that correspond to the assessment templates that are specified by the ARNs of the assessment templates.</p>
+    fn list_assessment_runs(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> RusotoFuture<ListAssessmentRunsResponse, ListAssessmentRunsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentRuns");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.ListAssessmentRuns");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAssessmentRunsResponse, _>(
+                    response,
+                    "ListAssessmentRuns",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAssessmentRunsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the ARNs of the assessment targets within this AWS account. For more information about assessment targets, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_applications.html">Amazon Inspector Assessment Targets</a>.</p>
+    fn list_assessment_targets(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> RusotoFuture<ListAssessmentTargetsResponse, ListAssessmentTargetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentTargets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.ListAssessmentTargets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAssessmentTargetsResponse, _>(
+                    response,
+                    "ListAssessmentTargets",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListAssessmentTargetsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the assessment templates that correspond to the assessment targets that are specified by the ARNs of the assessment targets.</p>
+    fn list_assessment_templates(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> RusotoFuture<ListAssessmentTemplatesResponse, ListAssessmentTemplatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentTemplates");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.ListAssessmentTemplates");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAssessmentTemplatesResponse, _>(
+                    response,
+                    "ListAssessmentTemplates",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAssessmentTemplatesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all the event subscriptions for the assessment template that is specified by the ARN of the assessment template. For more information, see <a>SubscribeToEvent</a> and <a>UnsubscribeFromEvent</a>.</p>
+    fn list_event_subscriptions(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> RusotoFuture<ListEventSubscriptionsResponse, ListEventSubscriptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListEventSubscriptions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.ListEventSubscriptions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListEventSubscriptionsResponse, _>(
+                    response,
+                    "ListEventSubscriptions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListEventSubscriptionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>List exclusions that are generated by the assessment run.</p>
+    fn list_exclusions(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> RusotoFuture<ListExclusionsResponse, ListExclusionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListExclusions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.ListExclusions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListExclusionsResponse, _>(
+                    response,
+                    "ListExclusions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListExclusionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists findings that are generated by the assessment runs that are specified by the ARNs of the assessment runs.</p>
+    fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListFindings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.ListFindings");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListFindingsResponse, _>(
+                    response,
+                    "ListFindings",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListFindingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all available Amazon Inspector rules packages.</p>
+    fn list_rules_packages(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> RusotoFuture<ListRulesPackagesResponse, ListRulesPackagesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListRulesPackages");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.ListRulesPackages");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRulesPackagesResponse, _>(
+                    response,
+                    "ListRulesPackages",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRulesPackagesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all tags associated with an assessment template.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Previews the agents installed on the EC2 instances that are part of the specified assessment target.</p>
+    fn preview_agents(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> RusotoFuture<PreviewAgentsResponse, PreviewAgentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("PreviewAgents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.PreviewAgents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PreviewAgentsResponse, _>(
+                    response,
+                    "PreviewAgents",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PreviewAgentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers the IAM role that grants Amazon Inspector access to AWS Services needed to perform security assessments.</p>
+    fn register_cross_account_access_role(
+        &self,
+        input: RegisterCrossAccountAccessRoleRequest,
+    ) -> RusotoFuture<(), RegisterCrossAccountAccessRoleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("RegisterCrossAccountAccessRole");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "InspectorService.RegisterCrossAccountAccessRole",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RegisterCrossAccountAccessRoleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes entire attributes (key and value pairs) from the findings that are specified by the ARNs of the findings where an attribute with the specified key exists.</p>
+    fn remove_attributes_from_findings(
+        &self,
+        input: RemoveAttributesFromFindingsRequest,
+    ) -> RusotoFuture<RemoveAttributesFromFindingsResponse, RemoveAttributesFromFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("RemoveAttributesFromFindings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "InspectorService.RemoveAttributesFromFindings",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RemoveAttributesFromFindingsResponse, _>(
+                    response,
+                    "RemoveAttributesFromFindings",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RemoveAttributesFromFindingsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Sets tags (key and value pairs) to the assessment template that is specified by the ARN of the assessment template.</p>
+    fn set_tags_for_resource(
+        &self,
+        input: SetTagsForResourceRequest,
+    ) -> RusotoFuture<(), SetTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("SetTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.SetTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetTagsForResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts the assessment run specified by the ARN of the assessment template. For this API to function properly, you must not exceed the limit of running up to 500 concurrent agents per AWS account.</p>
+    fn start_assessment_run(
+        &self,
+        input: StartAssessmentRunRequest,
+    ) -> RusotoFuture<StartAssessmentRunResponse, StartAssessmentRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("StartAssessmentRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.StartAssessmentRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartAssessmentRunResponse, _>(
+                    response,
+                    "StartAssessmentRun",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartAssessmentRunError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops the assessment run that is specified by the ARN of the assessment run.</p>
+    fn stop_assessment_run(
+        &self,
+        input: StopAssessmentRunRequest,
+    ) -> RusotoFuture<(), StopAssessmentRunError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("StopAssessmentRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.StopAssessmentRun");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
                         .buffer()
                         .from_err()
                         .and_then(|response| Err(StopAssessmentRunError::from_response(response))),
@@ -4774,80 +6857,1388 @@ impl Inspector for InspectorClient {
             }
         })
     }
-
-    /// <p>Enables the process of sending Amazon Simple Notification Service (SNS) notifications about a specified event to a specified SNS topic.</p>
+
+    /// <p>Enables the process of sending Amazon Simple Notification Service (SNS) notifications about a specified event to a specified SNS topic.</p>
+    fn subscribe_to_event(
+        &self,
+        input: SubscribeToEventRequest,
+    ) -> RusotoFuture<(), SubscribeToEventError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("SubscribeToEvent");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.SubscribeToEvent");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SubscribeToEventError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables the process of sending Amazon Simple Notification Service (SNS) notifications about a specified event to a specified SNS topic.</p>
+    fn unsubscribe_from_event(
+        &self,
+        input: UnsubscribeFromEventRequest,
+    ) -> RusotoFuture<(), UnsubscribeFromEventError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("UnsubscribeFromEvent");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.UnsubscribeFromEvent");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UnsubscribeFromEventError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the assessment target that is specified by the ARN of the assessment target.</p> <p>If resourceGroupArn is not specified, all EC2 instances in the current AWS account and region are included in the assessment target.</p>
+    fn update_assessment_target(
+        &self,
+        input: UpdateAssessmentTargetRequest,
+    ) -> RusotoFuture<(), UpdateAssessmentTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("UpdateAssessmentTarget");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "InspectorService.UpdateAssessmentTarget");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateAssessmentTargetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon Inspector API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockInspector {
+    add_attributes_to_findings: Option<
+        Box<
+            dyn Fn(
+                    AddAttributesToFindingsRequest,
+                ) -> ::std::result::Result<
+                    AddAttributesToFindingsResponse,
+                    RusotoError<AddAttributesToFindingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_assessment_target: Option<
+        Box<
+            dyn Fn(
+                    CreateAssessmentTargetRequest,
+                ) -> ::std::result::Result<
+                    CreateAssessmentTargetResponse,
+                    RusotoError<CreateAssessmentTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_assessment_template: Option<
+        Box<
+            dyn Fn(
+                    CreateAssessmentTemplateRequest,
+                ) -> ::std::result::Result<
+                    CreateAssessmentTemplateResponse,
+                    RusotoError<CreateAssessmentTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_exclusions_preview: Option<
+        Box<
+            dyn Fn(
+                    CreateExclusionsPreviewRequest,
+                ) -> ::std::result::Result<
+                    CreateExclusionsPreviewResponse,
+                    RusotoError<CreateExclusionsPreviewError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_resource_group: Option<
+        Box<
+            dyn Fn(
+                    CreateResourceGroupRequest,
+                ) -> ::std::result::Result<
+                    CreateResourceGroupResponse,
+                    RusotoError<CreateResourceGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_assessment_run: Option<
+        Box<
+            dyn Fn(
+                    DeleteAssessmentRunRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteAssessmentRunError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_assessment_target: Option<
+        Box<
+            dyn Fn(
+                    DeleteAssessmentTargetRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteAssessmentTargetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_assessment_template: Option<
+        Box<
+            dyn Fn(
+                    DeleteAssessmentTemplateRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteAssessmentTemplateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_assessment_runs: Option<
+        Box<
+            dyn Fn(
+                    DescribeAssessmentRunsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAssessmentRunsResponse,
+                    RusotoError<DescribeAssessmentRunsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_assessment_targets: Option<
+        Box<
+            dyn Fn(
+                    DescribeAssessmentTargetsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAssessmentTargetsResponse,
+                    RusotoError<DescribeAssessmentTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_assessment_templates: Option<
+        Box<
+            dyn Fn(
+                    DescribeAssessmentTemplatesRequest,
+                ) -> ::std::result::Result<
+                    DescribeAssessmentTemplatesResponse,
+                    RusotoError<DescribeAssessmentTemplatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cross_account_access_role: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeCrossAccountAccessRoleResponse,
+                    RusotoError<DescribeCrossAccountAccessRoleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_exclusions: Option<
+        Box<
+            dyn Fn(
+                    DescribeExclusionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeExclusionsResponse,
+                    RusotoError<DescribeExclusionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_findings: Option<
+        Box<
+            dyn Fn(
+                    DescribeFindingsRequest,
+                ) -> ::std::result::Result<
+                    DescribeFindingsResponse,
+                    RusotoError<DescribeFindingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_resource_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeResourceGroupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeResourceGroupsResponse,
+                    RusotoError<DescribeResourceGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_rules_packages: Option<
+        Box<
+            dyn Fn(
+                    DescribeRulesPackagesRequest,
+                ) -> ::std::result::Result<
+                    DescribeRulesPackagesResponse,
+                    RusotoError<DescribeRulesPackagesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_assessment_report: Option<
+        Box<
+            dyn Fn(
+                    GetAssessmentReportRequest,
+                ) -> ::std::result::Result<
+                    GetAssessmentReportResponse,
+                    RusotoError<GetAssessmentReportError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_exclusions_preview: Option<
+        Box<
+            dyn Fn(
+                    GetExclusionsPreviewRequest,
+                ) -> ::std::result::Result<
+                    GetExclusionsPreviewResponse,
+                    RusotoError<GetExclusionsPreviewError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_telemetry_metadata: Option<
+        Box<
+            dyn Fn(
+                    GetTelemetryMetadataRequest,
+                ) -> ::std::result::Result<
+                    GetTelemetryMetadataResponse,
+                    RusotoError<GetTelemetryMetadataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_assessment_run_agents: Option<
+        Box<
+            dyn Fn(
+                    ListAssessmentRunAgentsRequest,
+                ) -> ::std::result::Result<
+                    ListAssessmentRunAgentsResponse,
+                    RusotoError<ListAssessmentRunAgentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_assessment_runs: Option<
+        Box<
+            dyn Fn(
+                    ListAssessmentRunsRequest,
+                ) -> ::std::result::Result<
+                    ListAssessmentRunsResponse,
+                    RusotoError<ListAssessmentRunsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_assessment_targets: Option<
+        Box<
+            dyn Fn(
+                    ListAssessmentTargetsRequest,
+                ) -> ::std::result::Result<
+                    ListAssessmentTargetsResponse,
+                    RusotoError<ListAssessmentTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_assessment_templates: Option<
+        Box<
+            dyn Fn(
+                    ListAssessmentTemplatesRequest,
+                ) -> ::std::result::Result<
+                    ListAssessmentTemplatesResponse,
+                    RusotoError<ListAssessmentTemplatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_event_subscriptions: Option<
+        Box<
+            dyn Fn(
+                    ListEventSubscriptionsRequest,
+                ) -> ::std::result::Result<
+                    ListEventSubscriptionsResponse,
+                    RusotoError<ListEventSubscriptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_exclusions: Option<
+        Box<
+            dyn Fn(
+                    ListExclusionsRequest,
+                ) -> ::std::result::Result<
+                    ListExclusionsResponse,
+                    RusotoError<ListExclusionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_findings: Option<
+        Box<
+            dyn Fn(
+                    ListFindingsRequest,
+                )
+                    -> ::std::result::Result<ListFindingsResponse, RusotoError<ListFindingsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_rules_packages: Option<
+        Box<
+            dyn Fn(
+                    ListRulesPackagesRequest,
+                ) -> ::std::result::Result<
+                    ListRulesPackagesResponse,
+                    RusotoError<ListRulesPackagesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    preview_agents: Option<
+        Box<
+            dyn Fn(
+                    PreviewAgentsRequest,
+                )
+                    -> ::std::result::Result<PreviewAgentsResponse, RusotoError<PreviewAgentsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_cross_account_access_role: Option<
+        Box<
+            dyn Fn(
+                    RegisterCrossAccountAccessRoleRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<RegisterCrossAccountAccessRoleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_attributes_from_findings: Option<
+        Box<
+            dyn Fn(
+                    RemoveAttributesFromFindingsRequest,
+                ) -> ::std::result::Result<
+                    RemoveAttributesFromFindingsResponse,
+                    RusotoError<RemoveAttributesFromFindingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    SetTagsForResourceRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetTagsForResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_assessment_run: Option<
+        Box<
+            dyn Fn(
+                    StartAssessmentRunRequest,
+                ) -> ::std::result::Result<
+                    StartAssessmentRunResponse,
+                    RusotoError<StartAssessmentRunError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_assessment_run: Option<
+        Box<
+            dyn Fn(
+                    StopAssessmentRunRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<StopAssessmentRunError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    subscribe_to_event: Option<
+        Box<
+            dyn Fn(
+                    SubscribeToEventRequest,
+                ) -> ::std::result::Result<(), RusotoError<SubscribeToEventError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    unsubscribe_from_event: Option<
+        Box<
+            dyn Fn(
+                    UnsubscribeFromEventRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UnsubscribeFromEventError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_assessment_target: Option<
+        Box<
+            dyn Fn(
+                    UpdateAssessmentTargetRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateAssessmentTargetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockInspector {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockInspector {
+        Default::default()
+    }
+
+    /// Answers `add_attributes_to_findings` with what `handler` returns for its input.
+    pub fn with_add_attributes_to_findings<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                AddAttributesToFindingsRequest,
+            ) -> ::std::result::Result<
+                AddAttributesToFindingsResponse,
+                RusotoError<AddAttributesToFindingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_attributes_to_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_assessment_target` with what `handler` returns for its input.
+    pub fn with_create_assessment_target<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                CreateAssessmentTargetRequest,
+            ) -> ::std::result::Result<
+                CreateAssessmentTargetResponse,
+                RusotoError<CreateAssessmentTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_assessment_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_assessment_template` with what `handler` returns for its input.
+    pub fn with_create_assessment_template<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                CreateAssessmentTemplateRequest,
+            ) -> ::std::result::Result<
+                CreateAssessmentTemplateResponse,
+                RusotoError<CreateAssessmentTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_assessment_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_exclusions_preview` with what `handler` returns for its input.
+    pub fn with_create_exclusions_preview<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                CreateExclusionsPreviewRequest,
+            ) -> ::std::result::Result<
+                CreateExclusionsPreviewResponse,
+                RusotoError<CreateExclusionsPreviewError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_exclusions_preview = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_resource_group` with what `handler` returns for its input.
+    pub fn with_create_resource_group<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                CreateResourceGroupRequest,
+            ) -> ::std::result::Result<
+                CreateResourceGroupResponse,
+                RusotoError<CreateResourceGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_resource_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_assessment_run` with what `handler` returns for its input.
+    pub fn with_delete_assessment_run<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                DeleteAssessmentRunRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteAssessmentRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_assessment_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_assessment_target` with what `handler` returns for its input.
+    pub fn with_delete_assessment_target<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                DeleteAssessmentTargetRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteAssessmentTargetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_assessment_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_assessment_template` with what `handler` returns for its input.
+    pub fn with_delete_assessment_template<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                DeleteAssessmentTemplateRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteAssessmentTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_assessment_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_assessment_runs` with what `handler` returns for its input.
+    pub fn with_describe_assessment_runs<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                DescribeAssessmentRunsRequest,
+            ) -> ::std::result::Result<
+                DescribeAssessmentRunsResponse,
+                RusotoError<DescribeAssessmentRunsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_assessment_runs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_assessment_targets` with what `handler` returns for its input.
+    pub fn with_describe_assessment_targets<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                DescribeAssessmentTargetsRequest,
+            ) -> ::std::result::Result<
+                DescribeAssessmentTargetsResponse,
+                RusotoError<DescribeAssessmentTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_assessment_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_assessment_templates` with what `handler` returns for its input.
+    pub fn with_describe_assessment_templates<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                DescribeAssessmentTemplatesRequest,
+            ) -> ::std::result::Result<
+                DescribeAssessmentTemplatesResponse,
+                RusotoError<DescribeAssessmentTemplatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_assessment_templates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cross_account_access_role` with what `handler` returns.
+    pub fn with_describe_cross_account_access_role<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeCrossAccountAccessRoleResponse,
+                RusotoError<DescribeCrossAccountAccessRoleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cross_account_access_role = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_exclusions` with what `handler` returns for its input.
+    pub fn with_describe_exclusions<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                DescribeExclusionsRequest,
+            ) -> ::std::result::Result<
+                DescribeExclusionsResponse,
+                RusotoError<DescribeExclusionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_exclusions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_findings` with what `handler` returns for its input.
+    pub fn with_describe_findings<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                DescribeFindingsRequest,
+            ) -> ::std::result::Result<
+                DescribeFindingsResponse,
+                RusotoError<DescribeFindingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_resource_groups` with what `handler` returns for its input.
+    pub fn with_describe_resource_groups<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                DescribeResourceGroupsRequest,
+            ) -> ::std::result::Result<
+                DescribeResourceGroupsResponse,
+                RusotoError<DescribeResourceGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_resource_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_rules_packages` with what `handler` returns for its input.
+    pub fn with_describe_rules_packages<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                DescribeRulesPackagesRequest,
+            ) -> ::std::result::Result<
+                DescribeRulesPackagesResponse,
+                RusotoError<DescribeRulesPackagesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_rules_packages = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_assessment_report` with what `handler` returns for its input.
+    pub fn with_get_assessment_report<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                GetAssessmentReportRequest,
+            ) -> ::std::result::Result<
+                GetAssessmentReportResponse,
+                RusotoError<GetAssessmentReportError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_assessment_report = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_exclusions_preview` with what `handler` returns for its input.
+    pub fn with_get_exclusions_preview<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                GetExclusionsPreviewRequest,
+            ) -> ::std::result::Result<
+                GetExclusionsPreviewResponse,
+                RusotoError<GetExclusionsPreviewError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_exclusions_preview = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_telemetry_metadata` with what `handler` returns for its input.
+    pub fn with_get_telemetry_metadata<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                GetTelemetryMetadataRequest,
+            ) -> ::std::result::Result<
+                GetTelemetryMetadataResponse,
+                RusotoError<GetTelemetryMetadataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_telemetry_metadata = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_assessment_run_agents` with what `handler` returns for its input.
+    pub fn with_list_assessment_run_agents<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                ListAssessmentRunAgentsRequest,
+            ) -> ::std::result::Result<
+                ListAssessmentRunAgentsResponse,
+                RusotoError<ListAssessmentRunAgentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_assessment_run_agents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_assessment_runs` with what `handler` returns for its input.
+    pub fn with_list_assessment_runs<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                ListAssessmentRunsRequest,
+            ) -> ::std::result::Result<
+                ListAssessmentRunsResponse,
+                RusotoError<ListAssessmentRunsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_assessment_runs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_assessment_targets` with what `handler` returns for its input.
+    pub fn with_list_assessment_targets<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                ListAssessmentTargetsRequest,
+            ) -> ::std::result::Result<
+                ListAssessmentTargetsResponse,
+                RusotoError<ListAssessmentTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_assessment_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_assessment_templates` with what `handler` returns for its input.
+    pub fn with_list_assessment_templates<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                ListAssessmentTemplatesRequest,
+            ) -> ::std::result::Result<
+                ListAssessmentTemplatesResponse,
+                RusotoError<ListAssessmentTemplatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_assessment_templates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_event_subscriptions` with what `handler` returns for its input.
+    pub fn with_list_event_subscriptions<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                ListEventSubscriptionsRequest,
+            ) -> ::std::result::Result<
+                ListEventSubscriptionsResponse,
+                RusotoError<ListEventSubscriptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_event_subscriptions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_exclusions` with what `handler` returns for its input.
+    pub fn with_list_exclusions<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                ListExclusionsRequest,
+            )
+                -> ::std::result::Result<ListExclusionsResponse, RusotoError<ListExclusionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_exclusions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_findings` with what `handler` returns for its input.
+    pub fn with_list_findings<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                ListFindingsRequest,
+            )
+                -> ::std::result::Result<ListFindingsResponse, RusotoError<ListFindingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_rules_packages` with what `handler` returns for its input.
+    pub fn with_list_rules_packages<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                ListRulesPackagesRequest,
+            ) -> ::std::result::Result<
+                ListRulesPackagesResponse,
+                RusotoError<ListRulesPackagesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_rules_packages = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `preview_agents` with what `handler` returns for its input.
+    pub fn with_preview_agents<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                PreviewAgentsRequest,
+            )
+                -> ::std::result::Result<PreviewAgentsResponse, RusotoError<PreviewAgentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.preview_agents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_cross_account_access_role` with what `handler` returns for its input.
+    pub fn with_register_cross_account_access_role<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                RegisterCrossAccountAccessRoleRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<RegisterCrossAccountAccessRoleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_cross_account_access_role = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_attributes_from_findings` with what `handler` returns for its input.
+    pub fn with_remove_attributes_from_findings<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                RemoveAttributesFromFindingsRequest,
+            ) -> ::std::result::Result<
+                RemoveAttributesFromFindingsResponse,
+                RusotoError<RemoveAttributesFromFindingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_attributes_from_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_set_tags_for_resource<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                SetTagsForResourceRequest,
+            ) -> ::std::result::Result<(), RusotoError<SetTagsForResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_assessment_run` with what `handler` returns for its input.
+    pub fn with_start_assessment_run<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                StartAssessmentRunRequest,
+            ) -> ::std::result::Result<
+                StartAssessmentRunResponse,
+                RusotoError<StartAssessmentRunError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_assessment_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_assessment_run` with what `handler` returns for its input.
+    pub fn with_stop_assessment_run<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                StopAssessmentRunRequest,
+            ) -> ::std::result::Result<(), RusotoError<StopAssessmentRunError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_assessment_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `subscribe_to_event` with what `handler` returns for its input.
+    pub fn with_subscribe_to_event<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                SubscribeToEventRequest,
+            ) -> ::std::result::Result<(), RusotoError<SubscribeToEventError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.subscribe_to_event = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unsubscribe_from_event` with what `handler` returns for its input.
+    pub fn with_unsubscribe_from_event<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                UnsubscribeFromEventRequest,
+            ) -> ::std::result::Result<(), RusotoError<UnsubscribeFromEventError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.unsubscribe_from_event = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_assessment_target` with what `handler` returns for its input.
+    pub fn with_update_assessment_target<F>(mut self, handler: F) -> MockInspector
+    where
+        F: Fn(
+                UpdateAssessmentTargetRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateAssessmentTargetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_assessment_target = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Inspector for MockInspector {
+    fn add_attributes_to_findings(
+        &self,
+        input: AddAttributesToFindingsRequest,
+    ) -> RusotoFuture<AddAttributesToFindingsResponse, AddAttributesToFindingsError> {
+        match self.add_attributes_to_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for add_attributes_to_findings"),
+        }
+    }
+    fn create_assessment_target(
+        &self,
+        input: CreateAssessmentTargetRequest,
+    ) -> RusotoFuture<CreateAssessmentTargetResponse, CreateAssessmentTargetError> {
+        match self.create_assessment_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for create_assessment_target"),
+        }
+    }
+    fn create_assessment_template(
+        &self,
+        input: CreateAssessmentTemplateRequest,
+    ) -> RusotoFuture<CreateAssessmentTemplateResponse, CreateAssessmentTemplateError> {
+        match self.create_assessment_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for create_assessment_template"),
+        }
+    }
+    fn create_exclusions_preview(
+        &self,
+        input: CreateExclusionsPreviewRequest,
+    ) -> RusotoFuture<CreateExclusionsPreviewResponse, CreateExclusionsPreviewError> {
+        match self.create_exclusions_preview {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for create_exclusions_preview"),
+        }
+    }
+    fn create_resource_group(
+        &self,
+        input: CreateResourceGroupRequest,
+    ) -> RusotoFuture<CreateResourceGroupResponse, CreateResourceGroupError> {
+        match self.create_resource_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for create_resource_group"),
+        }
+    }
+    fn delete_assessment_run(
+        &self,
+        input: DeleteAssessmentRunRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentRunError> {
+        match self.delete_assessment_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for delete_assessment_run"),
+        }
+    }
+    fn delete_assessment_target(
+        &self,
+        input: DeleteAssessmentTargetRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentTargetError> {
+        match self.delete_assessment_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for delete_assessment_target"),
+        }
+    }
+    fn delete_assessment_template(
+        &self,
+        input: DeleteAssessmentTemplateRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentTemplateError> {
+        match self.delete_assessment_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for delete_assessment_template"),
+        }
+    }
+    fn describe_assessment_runs(
+        &self,
+        input: DescribeAssessmentRunsRequest,
+    ) -> RusotoFuture<DescribeAssessmentRunsResponse, DescribeAssessmentRunsError> {
+        match self.describe_assessment_runs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for describe_assessment_runs"),
+        }
+    }
+    fn describe_assessment_targets(
+        &self,
+        input: DescribeAssessmentTargetsRequest,
+    ) -> RusotoFuture<DescribeAssessmentTargetsResponse, DescribeAssessmentTargetsError> {
+        match self.describe_assessment_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for describe_assessment_targets"),
+        }
+    }
+    fn describe_assessment_templates(
+        &self,
+        input: DescribeAssessmentTemplatesRequest,
+    ) -> RusotoFuture<DescribeAssessmentTemplatesResponse, DescribeAssessmentTemplatesError> {
+        match self.describe_assessment_templates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for describe_assessment_templates"),
+        }
+    }
+    fn describe_cross_account_access_role(
+        &self,
+    ) -> RusotoFuture<DescribeCrossAccountAccessRoleResponse, DescribeCrossAccountAccessRoleError>
+    {
+        match self.describe_cross_account_access_role {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockInspector has no handler for describe_cross_account_access_role"),
+        }
+    }
+    fn describe_exclusions(
+        &self,
+        input: DescribeExclusionsRequest,
+    ) -> RusotoFuture<DescribeExclusionsResponse, DescribeExclusionsError> {
+        match self.describe_exclusions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for describe_exclusions"),
+        }
+    }
+    fn describe_findings(
+        &self,
+        input: DescribeFindingsRequest,
+    ) -> RusotoFuture<DescribeFindingsResponse, DescribeFindingsError> {
+        match self.describe_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for describe_findings"),
+        }
+    }
+    fn describe_resource_groups(
+        &self,
+        input: DescribeResourceGroupsRequest,
+    ) -> RusotoFuture<DescribeResourceGroupsResponse, DescribeResourceGroupsError> {
+        match self.describe_resource_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for describe_resource_groups"),
+        }
+    }
+    fn describe_rules_packages(
+        &self,
+        input: DescribeRulesPackagesRequest,
+    ) -> RusotoFuture<DescribeRulesPackagesResponse, DescribeRulesPackagesError> {
+        match self.describe_rules_packages {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for describe_rules_packages"),
+        }
+    }
+    fn get_assessment_report(
+        &self,
+        input: GetAssessmentReportRequest,
+    ) -> RusotoFuture<GetAssessmentReportResponse, GetAssessmentReportError> {
+        match self.get_assessment_report {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for get_assessment_report"),
+        }
+    }
+    fn get_exclusions_preview(
+        &self,
+        input: GetExclusionsPreviewRequest,
+    ) -> RusotoFuture<GetExclusionsPreviewResponse, GetExclusionsPreviewError> {
+        match self.get_exclusions_preview {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for get_exclusions_preview"),
+        }
+    }
+    fn get_telemetry_metadata(
+        &self,
+        input: GetTelemetryMetadataRequest,
+    ) -> RusotoFuture<GetTelemetryMetadataResponse, GetTelemetryMetadataError> {
+        match self.get_telemetry_metadata {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for get_telemetry_metadata"),
+        }
+    }
+    fn list_assessment_run_agents(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> RusotoFuture<ListAssessmentRunAgentsResponse, ListAssessmentRunAgentsError> {
+        match self.list_assessment_run_agents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for list_assessment_run_agents"),
+        }
+    }
+    fn list_assessment_runs(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> RusotoFuture<ListAssessmentRunsResponse, ListAssessmentRunsError> {
+        match self.list_assessment_runs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for list_assessment_runs"),
+        }
+    }
+    fn list_assessment_targets(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> RusotoFuture<ListAssessmentTargetsResponse, ListAssessmentTargetsError> {
+        match self.list_assessment_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for list_assessment_targets"),
+        }
+    }
+    fn list_assessment_templates(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> RusotoFuture<ListAssessmentTemplatesResponse, ListAssessmentTemplatesError> {
+        match self.list_assessment_templates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for list_assessment_templates"),
+        }
+    }
+    fn list_event_subscriptions(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> RusotoFuture<ListEventSubscriptionsResponse, ListEventSubscriptionsError> {
+        match self.list_event_subscriptions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for list_event_subscriptions"),
+        }
+    }
+    fn list_exclusions(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> RusotoFuture<ListExclusionsResponse, ListExclusionsError> {
+        match self.list_exclusions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for list_exclusions"),
+        }
+    }
+    fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
+        match self.list_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for list_findings"),
+        }
+    }
+    fn list_rules_packages(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> RusotoFuture<ListRulesPackagesResponse, ListRulesPackagesError> {
+        match self.list_rules_packages {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for list_rules_packages"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for list_tags_for_resource"),
+        }
+    }
+    fn preview_agents(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> RusotoFuture<PreviewAgentsResponse, PreviewAgentsError> {
+        match self.preview_agents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for preview_agents"),
+        }
+    }
+    fn register_cross_account_access_role(
+        &self,
+        input: RegisterCrossAccountAccessRoleRequest,
+    ) -> RusotoFuture<(), RegisterCrossAccountAccessRoleError> {
+        match self.register_cross_account_access_role {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for register_cross_account_access_role"),
+        }
+    }
+    fn remove_attributes_from_findings(
+        &self,
+        input: RemoveAttributesFromFindingsRequest,
+    ) -> RusotoFuture<RemoveAttributesFromFindingsResponse, RemoveAttributesFromFindingsError> {
+        match self.remove_attributes_from_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for remove_attributes_from_findings"),
+        }
+    }
+    fn set_tags_for_resource(
+        &self,
+        input: SetTagsForResourceRequest,
+    ) -> RusotoFuture<(), SetTagsForResourceError> {
+        match self.set_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for set_tags_for_resource"),
+        }
+    }
+    fn start_assessment_run(
+        &self,
+        input: StartAssessmentRunRequest,
+    ) -> RusotoFuture<StartAssessmentRunResponse, StartAssessmentRunError> {
+        match self.start_assessment_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for start_assessment_run"),
+        }
+    }
+    fn stop_assessment_run(
+        &self,
+        input: StopAssessmentRunRequest,
+    ) -> RusotoFuture<(), StopAssessmentRunError> {
+        match self.stop_assessment_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for stop_assessment_run"),
+        }
+    }
     fn subscribe_to_event(
         &self,
         input: SubscribeToEventRequest,
     ) -> RusotoFuture<(), SubscribeToEventError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.SubscribeToEvent");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SubscribeToEventError::from_response(response))),
-                )
-            }
-        })
+        match self.subscribe_to_event {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for subscribe_to_event"),
+        }
     }
-
-    /// <p>Disables the process of sending Amazon Simple Notification Service (SNS) notifications about a specified event to a specified SNS topic.</p>
     fn unsubscribe_from_event(
         &self,
         input: UnsubscribeFromEventRequest,
     ) -> RusotoFuture<(), UnsubscribeFromEventError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.UnsubscribeFromEvent");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UnsubscribeFromEventError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.unsubscribe_from_event {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for unsubscribe_from_event"),
+        }
     }
-
-    /// <p>Updates the assessment target that is specified by the ARN of the assessment target.</p> <p>If resourceGroupArn is not specified, all EC2 instances in the current AWS account and region are included in the assessment target.</p>
     fn update_assessment_target(
         &self,
         input: UpdateAssessmentTargetRequest,
     ) -> RusotoFuture<(), UpdateAssessmentTargetError> {
-        let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "InspectorService.UpdateAssessmentTarget");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateAssessmentTargetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_assessment_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockInspector has no handler for update_assessment_target"),
+        }
     }
 }
@@ -23,14 +23,32 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 /// <p>The input for the DeleteThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteThingShadowRequest {
     /// <p>The name of the thing.</p>
     #[serde(rename = "thingName")]
     pub thing_name: String,
 }
 
+impl Validate for DeleteThingShadowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "thing_name"),
+            self.thing_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "thing_name"),
+            &self.thing_name,
+            "[a-zA-Z0-9:_-]+",
+        );
+    }
+}
+
 /// <p>The output from the DeleteThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteThingShadowResponse {
@@ -40,12 +58,29 @@ pub struct DeleteThingShadowResponse {
 
 /// <p>The input for the GetThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetThingShadowRequest {
     /// <p>The name of the thing.</p>
     #[serde(rename = "thingName")]
     pub thing_name: String,
 }
 
+impl Validate for GetThingShadowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "thing_name"),
+            self.thing_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "thing_name"),
+            &self.thing_name,
+            "[a-zA-Z0-9:_-]+",
+        );
+    }
+}
+
 /// <p>The output from the GetThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetThingShadowResponse {
@@ -55,6 +90,7 @@ pub struct GetThingShadowResponse {
 
 /// <p>The input for the Publish operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublishRequest {
     /// <p>The state information, in JSON format.</p>
     #[serde(rename = "payload")]
@@ -74,8 +110,17 @@ pub struct PublishRequest {
     pub topic: String,
 }
 
+impl Validate for PublishRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.qos {
+            errors.check_range(&field_path(path, "qos"), *value, None, Some(1));
+        }
+    }
+}
+
 /// <p>The input for the UpdateThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateThingShadowRequest {
     /// <p>The state information, in JSON format.</p>
     #[serde(rename = "payload")]
@@ -90,6 +135,22 @@ pub struct UpdateThingShadowRequest {
     pub thing_name: String,
 }
 
+impl Validate for UpdateThingShadowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "thing_name"),
+            self.thing_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "thing_name"),
+            &self.thing_name,
+            "[a-zA-Z0-9:_-]+",
+        );
+    }
+}
+
 /// <p>The output from the UpdateThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateThingShadowResponse {
@@ -150,7 +211,12 @@ impl DeleteThingShadowError {
                         DeleteThingShadowError::UnsupportedDocumentEncoding(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -227,7 +293,12 @@ impl GetThingShadowError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -282,7 +353,12 @@ impl PublishError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(PublishError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -364,7 +440,12 @@ impl UpdateThingShadowError {
                         UpdateThingShadowError::UnsupportedDocumentEncoding(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -414,6 +495,76 @@ pub trait IotData {
         input: UpdateThingShadowRequest,
     ) -> RusotoFuture<UpdateThingShadowResponse, UpdateThingShadowError>;
 }
+const _: Option<&dyn IotData> = None;
+impl<'a, T: IotData + ?Sized> IotData for &'a T {
+    fn delete_thing_shadow(
+        &self,
+        input: DeleteThingShadowRequest,
+    ) -> RusotoFuture<DeleteThingShadowResponse, DeleteThingShadowError> {
+        (**self).delete_thing_shadow(input)
+    }
+    fn get_thing_shadow(
+        &self,
+        input: GetThingShadowRequest,
+    ) -> RusotoFuture<GetThingShadowResponse, GetThingShadowError> {
+        (**self).get_thing_shadow(input)
+    }
+    fn publish(&self, input: PublishRequest) -> RusotoFuture<(), PublishError> {
+        (**self).publish(input)
+    }
+    fn update_thing_shadow(
+        &self,
+        input: UpdateThingShadowRequest,
+    ) -> RusotoFuture<UpdateThingShadowResponse, UpdateThingShadowError> {
+        (**self).update_thing_shadow(input)
+    }
+}
+impl<T: IotData + ?Sized> IotData for Box<T> {
+    fn delete_thing_shadow(
+        &self,
+        input: DeleteThingShadowRequest,
+    ) -> RusotoFuture<DeleteThingShadowResponse, DeleteThingShadowError> {
+        (**self).delete_thing_shadow(input)
+    }
+    fn get_thing_shadow(
+        &self,
+        input: GetThingShadowRequest,
+    ) -> RusotoFuture<GetThingShadowResponse, GetThingShadowError> {
+        (**self).get_thing_shadow(input)
+    }
+    fn publish(&self, input: PublishRequest) -> RusotoFuture<(), PublishError> {
+        (**self).publish(input)
+    }
+    fn update_thing_shadow(
+        &self,
+        input: UpdateThingShadowRequest,
+    ) -> RusotoFuture<UpdateThingShadowResponse, UpdateThingShadowError> {
+        (**self).update_thing_shadow(input)
+    }
+}
+impl<T: IotData + ?Sized> IotData for ::std::sync::Arc<T> {
+    fn delete_thing_shadow(
+        &self,
+        input: DeleteThingShadowRequest,
+    ) -> RusotoFuture<DeleteThingShadowResponse, DeleteThingShadowError> {
+        (**self).delete_thing_shadow(input)
+    }
+    fn get_thing_shadow(
+        &self,
+        input: GetThingShadowRequest,
+    ) -> RusotoFuture<GetThingShadowResponse, GetThingShadowError> {
+        (**self).get_thing_shadow(input)
+    }
+    fn publish(&self, input: PublishRequest) -> RusotoFuture<(), PublishError> {
+        (**self).publish(input)
+    }
+    fn update_thing_shadow(
+        &self,
+        input: UpdateThingShadowRequest,
+    ) -> RusotoFuture<UpdateThingShadowResponse, UpdateThingShadowError> {
+        (**self).update_thing_shadow(input)
+    }
+}
 /// A client for the AWS IoT Data Plane API.
 #[derive(Clone)]
 pub struct IotDataClient {
@@ -457,9 +608,19 @@ impl IotData for IotDataClient {
         &self,
         input: DeleteThingShadowRequest,
     ) -> RusotoFuture<DeleteThingShadowResponse, DeleteThingShadowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("DELETE", "iotdata", &self.region, &request_uri);
+        request.set_operation("DeleteThingShadow");
+        request.add_resource_id("thingName", &input.thing_name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -488,9 +649,19 @@ impl IotData for IotDataClient {
         &self,
         input: GetThingShadowRequest,
     ) -> RusotoFuture<GetThingShadowResponse, GetThingShadowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iotdata", &self.region, &request_uri);
+        request.set_operation("GetThingShadow");
+        request.add_resource_id("thingName", &input.thing_name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -516,9 +687,19 @@ impl IotData for IotDataClient {
 
     /// <p>Publishes state information.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/protocols.html#http">HTTP Protocol</a> in the <i>AWS IoT Developer Guide</i>.</p>
     fn publish(&self, input: PublishRequest) -> RusotoFuture<(), PublishError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/topics/{topic}", topic = input.topic);
 
         let mut request = SignedRequest::new("POST", "iotdata", &self.region, &request_uri);
+        request.set_operation("Publish");
+        request.add_resource_id("topic", &input.topic.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -558,9 +739,19 @@ impl IotData for IotDataClient {
         &self,
         input: UpdateThingShadowRequest,
     ) -> RusotoFuture<UpdateThingShadowResponse, UpdateThingShadowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("POST", "iotdata", &self.region, &request_uri);
+        request.set_operation("UpdateThingShadow");
+        request.add_resource_id("thingName", &input.thing_name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -586,3 +777,153 @@ impl IotData for IotDataClient {
         })
     }
 }
+
+/// A mock of the AWS IoT Data Plane API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockIotData {
+    delete_thing_shadow: Option<
+        Box<
+            dyn Fn(
+                    DeleteThingShadowRequest,
+                ) -> ::std::result::Result<
+                    DeleteThingShadowResponse,
+                    RusotoError<DeleteThingShadowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_thing_shadow: Option<
+        Box<
+            dyn Fn(
+                    GetThingShadowRequest,
+                ) -> ::std::result::Result<
+                    GetThingShadowResponse,
+                    RusotoError<GetThingShadowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    publish: Option<
+        Box<
+            dyn Fn(PublishRequest) -> ::std::result::Result<(), RusotoError<PublishError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_thing_shadow: Option<
+        Box<
+            dyn Fn(
+                    UpdateThingShadowRequest,
+                ) -> ::std::result::Result<
+                    UpdateThingShadowResponse,
+                    RusotoError<UpdateThingShadowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockIotData {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockIotData {
+        Default::default()
+    }
+
+    /// Answers `delete_thing_shadow` with what `handler` returns for its input.
+    pub fn with_delete_thing_shadow<F>(mut self, handler: F) -> MockIotData
+    where
+        F: Fn(
+                DeleteThingShadowRequest,
+            ) -> ::std::result::Result<
+                DeleteThingShadowResponse,
+                RusotoError<DeleteThingShadowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_thing_shadow = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_thing_shadow` with what `handler` returns for its input.
+    pub fn with_get_thing_shadow<F>(mut self, handler: F) -> MockIotData
+    where
+        F: Fn(
+                GetThingShadowRequest,
+            )
+                -> ::std::result::Result<GetThingShadowResponse, RusotoError<GetThingShadowError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_thing_shadow = Some(Box::new(handler));
+        self
+    }
+    /// Answers `publish` with what `handler` returns for its input.
+    pub fn with_publish<F>(mut self, handler: F) -> MockIotData
+    where
+        F: Fn(PublishRequest) -> ::std::result::Result<(), RusotoError<PublishError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.publish = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_thing_shadow` with what `handler` returns for its input.
+    pub fn with_update_thing_shadow<F>(mut self, handler: F) -> MockIotData
+    where
+        F: Fn(
+                UpdateThingShadowRequest,
+            ) -> ::std::result::Result<
+                UpdateThingShadowResponse,
+                RusotoError<UpdateThingShadowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_thing_shadow = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl IotData for MockIotData {
+    fn delete_thing_shadow(
+        &self,
+        input: DeleteThingShadowRequest,
+    ) -> RusotoFuture<DeleteThingShadowResponse, DeleteThingShadowError> {
+        match self.delete_thing_shadow {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotData has no handler for delete_thing_shadow"),
+        }
+    }
+    fn get_thing_shadow(
+        &self,
+        input: GetThingShadowRequest,
+    ) -> RusotoFuture<GetThingShadowResponse, GetThingShadowError> {
+        match self.get_thing_shadow {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotData has no handler for get_thing_shadow"),
+        }
+    }
+    fn publish(&self, input: PublishRequest) -> RusotoFuture<(), PublishError> {
+        match self.publish {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotData has no handler for publish"),
+        }
+    }
+    fn update_thing_shadow(
+        &self,
+        input: UpdateThingShadowRequest,
+    ) -> RusotoFuture<UpdateThingShadowResponse, UpdateThingShadowError> {
+        match self.update_thing_shadow {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotData has no handler for update_thing_shadow"),
+        }
+    }
+}
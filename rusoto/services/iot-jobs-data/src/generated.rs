@@ -23,8 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeJobExecutionRequest {
     /// <p>Optional. A number that identifies a particular job execution on a particular device. If not specified, the latest job execution is returned.</p>
     #[serde(rename = "executionNumber")]
@@ -42,8 +44,29 @@ pub struct DescribeJobExecutionRequest {
     pub thing_name: String,
 }
 
+impl Validate for DescribeJobExecutionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "[a-zA-Z0-9_-]+|^\\$next",
+        );
+        errors.check_length(
+            &field_path(path, "thing_name"),
+            self.thing_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "thing_name"),
+            &self.thing_name,
+            "[a-zA-Z0-9:_-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeJobExecutionResponse {
     /// <p>Contains data about a job execution.</p>
     #[serde(rename = "execution")]
@@ -52,14 +75,31 @@ pub struct DescribeJobExecutionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPendingJobExecutionsRequest {
     /// <p>The name of the thing that is executing the job.</p>
     #[serde(rename = "thingName")]
     pub thing_name: String,
 }
 
+impl Validate for GetPendingJobExecutionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "thing_name"),
+            self.thing_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "thing_name"),
+            &self.thing_name,
+            "[a-zA-Z0-9:_-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPendingJobExecutionsResponse {
     /// <p>A list of JobExecutionSummary objects with status IN_PROGRESS.</p>
     #[serde(rename = "inProgressJobs")]
@@ -73,7 +113,7 @@ pub struct GetPendingJobExecutionsResponse {
 
 /// <p>Contains data about a job execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobExecution {
     /// <p>The estimated number of seconds that remain before the job execution status will be changed to <code>TIMED_OUT</code>.</p>
     #[serde(rename = "approximateSecondsBeforeTimedOut")]
@@ -123,7 +163,7 @@ pub struct JobExecution {
 
 /// <p>Contains data about the state of a job execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobExecutionState {
     /// <p>The status of the job execution. Can be one of: "QUEUED", "IN_PROGRESS", "FAILED", "SUCCESS", "CANCELED", "REJECTED", or "REMOVED".</p>
     #[serde(rename = "status")]
@@ -141,7 +181,7 @@ pub struct JobExecutionState {
 
 /// <p>Contains a subset of information about a job execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobExecutionSummary {
     /// <p>A number that identifies a particular job execution on a particular device.</p>
     #[serde(rename = "executionNumber")]
@@ -170,6 +210,7 @@ pub struct JobExecutionSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartNextPendingJobExecutionRequest {
     /// <p>A collection of name/value pairs that describe the status of the job execution. If not specified, the statusDetails are unchanged.</p>
     #[serde(rename = "statusDetails")]
@@ -184,8 +225,31 @@ pub struct StartNextPendingJobExecutionRequest {
     pub thing_name: String,
 }
 
+impl Validate for StartNextPendingJobExecutionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.status_details {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "status_details"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(&item_path, &item, "[^\\p{C}]*+");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "thing_name"),
+            self.thing_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "thing_name"),
+            &self.thing_name,
+            "[a-zA-Z0-9:_-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartNextPendingJobExecutionResponse {
     /// <p>A JobExecution object.</p>
     #[serde(rename = "execution")]
@@ -194,6 +258,7 @@ pub struct StartNextPendingJobExecutionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateJobExecutionRequest {
     /// <p>Optional. A number that identifies a particular job execution on a particular device.</p>
     #[serde(rename = "executionNumber")]
@@ -230,8 +295,38 @@ pub struct UpdateJobExecutionRequest {
     pub thing_name: String,
 }
 
+impl Validate for UpdateJobExecutionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "job_id"), &self.job_id, "[a-zA-Z0-9_-]+");
+        if let Some(ref value) = self.status_details {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "status_details"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(&item_path, &item, "[^\\p{C}]*+");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "thing_name"),
+            self.thing_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "thing_name"),
+            &self.thing_name,
+            "[a-zA-Z0-9:_-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateJobExecutionResponse {
     /// <p>A JobExecutionState object.</p>
     #[serde(rename = "executionState")]
@@ -288,7 +383,12 @@ impl DescribeJobExecutionError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DescribeJobExecutionError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -354,7 +454,12 @@ impl GetPendingJobExecutionsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetPendingJobExecutionsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -423,7 +528,12 @@ impl StartNextPendingJobExecutionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -491,7 +601,12 @@ impl UpdateJobExecutionError {
                 "ThrottlingException" => {
                     return RusotoError::Service(UpdateJobExecutionError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -541,6 +656,85 @@ pub trait IotJobsData {
         input: UpdateJobExecutionRequest,
     ) -> RusotoFuture<UpdateJobExecutionResponse, UpdateJobExecutionError>;
 }
+const _: Option<&dyn IotJobsData> = None;
+impl<'a, T: IotJobsData + ?Sized> IotJobsData for &'a T {
+    fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> RusotoFuture<DescribeJobExecutionResponse, DescribeJobExecutionError> {
+        (**self).describe_job_execution(input)
+    }
+    fn get_pending_job_executions(
+        &self,
+        input: GetPendingJobExecutionsRequest,
+    ) -> RusotoFuture<GetPendingJobExecutionsResponse, GetPendingJobExecutionsError> {
+        (**self).get_pending_job_executions(input)
+    }
+    fn start_next_pending_job_execution(
+        &self,
+        input: StartNextPendingJobExecutionRequest,
+    ) -> RusotoFuture<StartNextPendingJobExecutionResponse, StartNextPendingJobExecutionError> {
+        (**self).start_next_pending_job_execution(input)
+    }
+    fn update_job_execution(
+        &self,
+        input: UpdateJobExecutionRequest,
+    ) -> RusotoFuture<UpdateJobExecutionResponse, UpdateJobExecutionError> {
+        (**self).update_job_execution(input)
+    }
+}
+impl<T: IotJobsData + ?Sized> IotJobsData for Box<T> {
+    fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> RusotoFuture<DescribeJobExecutionResponse, DescribeJobExecutionError> {
+        (**self).describe_job_execution(input)
+    }
+    fn get_pending_job_executions(
+        &self,
+        input: GetPendingJobExecutionsRequest,
+    ) -> RusotoFuture<GetPendingJobExecutionsResponse, GetPendingJobExecutionsError> {
+        (**self).get_pending_job_executions(input)
+    }
+    fn start_next_pending_job_execution(
+        &self,
+        input: StartNextPendingJobExecutionRequest,
+    ) -> RusotoFuture<StartNextPendingJobExecutionResponse, StartNextPendingJobExecutionError> {
+        (**self).start_next_pending_job_execution(input)
+    }
+    fn update_job_execution(
+        &self,
+        input: UpdateJobExecutionRequest,
+    ) -> RusotoFuture<UpdateJobExecutionResponse, UpdateJobExecutionError> {
+        (**self).update_job_execution(input)
+    }
+}
+impl<T: IotJobsData + ?Sized> IotJobsData for ::std::sync::Arc<T> {
+    fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> RusotoFuture<DescribeJobExecutionResponse, DescribeJobExecutionError> {
+        (**self).describe_job_execution(input)
+    }
+    fn get_pending_job_executions(
+        &self,
+        input: GetPendingJobExecutionsRequest,
+    ) -> RusotoFuture<GetPendingJobExecutionsResponse, GetPendingJobExecutionsError> {
+        (**self).get_pending_job_executions(input)
+    }
+    fn start_next_pending_job_execution(
+        &self,
+        input: StartNextPendingJobExecutionRequest,
+    ) -> RusotoFuture<StartNextPendingJobExecutionResponse, StartNextPendingJobExecutionError> {
+        (**self).start_next_pending_job_execution(input)
+    }
+    fn update_job_execution(
+        &self,
+        input: UpdateJobExecutionRequest,
+    ) -> RusotoFuture<UpdateJobExecutionResponse, UpdateJobExecutionError> {
+        (**self).update_job_execution(input)
+    }
+}
 /// A client for the AWS IoT Jobs Data Plane API.
 #[derive(Clone)]
 pub struct IotJobsDataClient {
@@ -584,6 +778,14 @@ impl IotJobsData for IotJobsDataClient {
         &self,
         input: DescribeJobExecutionRequest,
     ) -> RusotoFuture<DescribeJobExecutionResponse, DescribeJobExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/things/{thing_name}/jobs/{job_id}",
             job_id = input.job_id,
@@ -591,6 +793,10 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("DescribeJobExecution");
+        request.add_resource_id("jobId", &input.job_id.to_string());
+        request.add_resource_id("thingName", &input.thing_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -608,6 +814,7 @@ impl IotJobsData for IotJobsDataClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeJobExecution")
                         .deserialize::<DescribeJobExecutionResponse, _>()?;
 
                     Ok(result)
@@ -627,9 +834,20 @@ impl IotJobsData for IotJobsDataClient {
         &self,
         input: GetPendingJobExecutionsRequest,
     ) -> RusotoFuture<GetPendingJobExecutionsResponse, GetPendingJobExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/things/{thing_name}/jobs", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("GetPendingJobExecutions");
+        request.add_resource_id("thingName", &input.thing_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -638,6 +856,7 @@ impl IotJobsData for IotJobsDataClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetPendingJobExecutions")
                         .deserialize::<GetPendingJobExecutionsResponse, _>()?;
 
                     Ok(result)
@@ -655,12 +874,23 @@ impl IotJobsData for IotJobsDataClient {
         &self,
         input: StartNextPendingJobExecutionRequest,
     ) -> RusotoFuture<StartNextPendingJobExecutionResponse, StartNextPendingJobExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/things/{thing_name}/jobs/$next",
             thing_name = input.thing_name
         );
 
         let mut request = SignedRequest::new("PUT", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("StartNextPendingJobExecution");
+        request.add_resource_id("thingName", &input.thing_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -671,6 +901,7 @@ impl IotJobsData for IotJobsDataClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartNextPendingJobExecution")
                         .deserialize::<StartNextPendingJobExecutionResponse, _>()?;
 
                     Ok(result)
@@ -688,6 +919,14 @@ impl IotJobsData for IotJobsDataClient {
         &self,
         input: UpdateJobExecutionRequest,
     ) -> RusotoFuture<UpdateJobExecutionResponse, UpdateJobExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/things/{thing_name}/jobs/{job_id}",
             job_id = input.job_id,
@@ -695,6 +934,10 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("POST", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("UpdateJobExecution");
+        request.add_resource_id("jobId", &input.job_id.to_string());
+        request.add_resource_id("thingName", &input.thing_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -705,6 +948,7 @@ impl IotJobsData for IotJobsDataClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateJobExecution")
                         .deserialize::<UpdateJobExecutionResponse, _>()?;
 
                     Ok(result)
@@ -720,3 +964,165 @@ impl IotJobsData for IotJobsDataClient {
         })
     }
 }
+
+/// A mock of the AWS IoT Jobs Data Plane API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockIotJobsData {
+    describe_job_execution: Option<
+        Box<
+            dyn Fn(
+                    DescribeJobExecutionRequest,
+                ) -> ::std::result::Result<
+                    DescribeJobExecutionResponse,
+                    RusotoError<DescribeJobExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_pending_job_executions: Option<
+        Box<
+            dyn Fn(
+                    GetPendingJobExecutionsRequest,
+                ) -> ::std::result::Result<
+                    GetPendingJobExecutionsResponse,
+                    RusotoError<GetPendingJobExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_next_pending_job_execution: Option<
+        Box<
+            dyn Fn(
+                    StartNextPendingJobExecutionRequest,
+                ) -> ::std::result::Result<
+                    StartNextPendingJobExecutionResponse,
+                    RusotoError<StartNextPendingJobExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_job_execution: Option<
+        Box<
+            dyn Fn(
+                    UpdateJobExecutionRequest,
+                ) -> ::std::result::Result<
+                    UpdateJobExecutionResponse,
+                    RusotoError<UpdateJobExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockIotJobsData {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockIotJobsData {
+        Default::default()
+    }
+
+    /// Answers `describe_job_execution` with what `handler` returns for its input.
+    pub fn with_describe_job_execution<F>(mut self, handler: F) -> MockIotJobsData
+    where
+        F: Fn(
+                DescribeJobExecutionRequest,
+            ) -> ::std::result::Result<
+                DescribeJobExecutionResponse,
+                RusotoError<DescribeJobExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_job_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_pending_job_executions` with what `handler` returns for its input.
+    pub fn with_get_pending_job_executions<F>(mut self, handler: F) -> MockIotJobsData
+    where
+        F: Fn(
+                GetPendingJobExecutionsRequest,
+            ) -> ::std::result::Result<
+                GetPendingJobExecutionsResponse,
+                RusotoError<GetPendingJobExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_pending_job_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_next_pending_job_execution` with what `handler` returns for its input.
+    pub fn with_start_next_pending_job_execution<F>(mut self, handler: F) -> MockIotJobsData
+    where
+        F: Fn(
+                StartNextPendingJobExecutionRequest,
+            ) -> ::std::result::Result<
+                StartNextPendingJobExecutionResponse,
+                RusotoError<StartNextPendingJobExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_next_pending_job_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_job_execution` with what `handler` returns for its input.
+    pub fn with_update_job_execution<F>(mut self, handler: F) -> MockIotJobsData
+    where
+        F: Fn(
+                UpdateJobExecutionRequest,
+            ) -> ::std::result::Result<
+                UpdateJobExecutionResponse,
+                RusotoError<UpdateJobExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_job_execution = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl IotJobsData for MockIotJobsData {
+    fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> RusotoFuture<DescribeJobExecutionResponse, DescribeJobExecutionError> {
+        match self.describe_job_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotJobsData has no handler for describe_job_execution"),
+        }
+    }
+    fn get_pending_job_executions(
+        &self,
+        input: GetPendingJobExecutionsRequest,
+    ) -> RusotoFuture<GetPendingJobExecutionsResponse, GetPendingJobExecutionsError> {
+        match self.get_pending_job_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotJobsData has no handler for get_pending_job_executions"),
+        }
+    }
+    fn start_next_pending_job_execution(
+        &self,
+        input: StartNextPendingJobExecutionRequest,
+    ) -> RusotoFuture<StartNextPendingJobExecutionResponse, StartNextPendingJobExecutionError> {
+        match self.start_next_pending_job_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotJobsData has no handler for start_next_pending_job_execution"),
+        }
+    }
+    fn update_job_execution(
+        &self,
+        input: UpdateJobExecutionRequest,
+    ) -> RusotoFuture<UpdateJobExecutionResponse, UpdateJobExecutionError> {
+        match self.update_job_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotJobsData has no handler for update_job_execution"),
+        }
+    }
+}
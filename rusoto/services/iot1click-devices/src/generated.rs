@@ -23,12 +23,14 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Attributes {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClaimDevicesByClaimCodeRequest {
     /// <p>The claim code, starting with "C-", as provided by the device manufacturer.</p>
     #[serde(rename = "ClaimCode")]
@@ -36,7 +38,7 @@ pub struct ClaimDevicesByClaimCodeRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClaimDevicesByClaimCodeResponse {
     /// <p>The claim code provided by the device manufacturer.</p>
     #[serde(rename = "ClaimCode")]
@@ -50,6 +52,7 @@ pub struct ClaimDevicesByClaimCodeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDeviceRequest {
     /// <p>The unique identifier of the device.</p>
     #[serde(rename = "DeviceId")]
@@ -57,7 +60,7 @@ pub struct DescribeDeviceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDeviceResponse {
     /// <p>Device details.</p>
     #[serde(rename = "DeviceDescription")]
@@ -66,7 +69,7 @@ pub struct DescribeDeviceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Device {
     /// <p>The user specified attributes associated with the device for an event.</p>
     #[serde(rename = "Attributes")]
@@ -83,7 +86,7 @@ pub struct Device {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceDescription {
     /// <p>The ARN of the device.</p>
     #[serde(rename = "Arn")]
@@ -118,7 +121,7 @@ pub struct DeviceDescription {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceEvent {
     /// <p>An object representing the device associated with the event.</p>
     #[serde(rename = "Device")]
@@ -143,6 +146,7 @@ pub struct DeviceMethod {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FinalizeDeviceClaimRequest {
     /// <p>The unique identifier of the device.</p>
     #[serde(rename = "DeviceId")]
@@ -158,7 +162,7 @@ pub struct FinalizeDeviceClaimRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FinalizeDeviceClaimResponse {
     /// <p>The device's final claim state.</p>
     #[serde(rename = "State")]
@@ -167,6 +171,7 @@ pub struct FinalizeDeviceClaimResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeviceMethodsRequest {
     /// <p>The unique identifier of the device.</p>
     #[serde(rename = "DeviceId")]
@@ -174,7 +179,7 @@ pub struct GetDeviceMethodsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeviceMethodsResponse {
     /// <p>List of available device APIs.</p>
     #[serde(rename = "DeviceMethods")]
@@ -183,6 +188,7 @@ pub struct GetDeviceMethodsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InitiateDeviceClaimRequest {
     /// <p>The unique identifier of the device.</p>
     #[serde(rename = "DeviceId")]
@@ -190,7 +196,7 @@ pub struct InitiateDeviceClaimRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InitiateDeviceClaimResponse {
     /// <p>The device's final claim state.</p>
     #[serde(rename = "State")]
@@ -199,6 +205,7 @@ pub struct InitiateDeviceClaimResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InvokeDeviceMethodRequest {
     /// <p>The unique identifier of the device.</p>
     #[serde(rename = "DeviceId")]
@@ -214,7 +221,7 @@ pub struct InvokeDeviceMethodRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InvokeDeviceMethodResponse {
     /// <p>A JSON encoded string containing the device method response.</p>
     #[serde(rename = "DeviceMethodResponse")]
@@ -223,6 +230,7 @@ pub struct InvokeDeviceMethodResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeviceEventsRequest {
     /// <p>The unique identifier of the device.</p>
     #[serde(rename = "DeviceId")]
@@ -248,8 +256,16 @@ pub struct ListDeviceEventsRequest {
     pub to_time_stamp: f64,
 }
 
+impl Validate for ListDeviceEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(250));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDeviceEventsResponse {
     /// <p>An array of zero or more elements describing the event(s) associated with the
     /// device.</p>
@@ -263,6 +279,7 @@ pub struct ListDeviceEventsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDevicesRequest {
     /// <p>The type of the device, such as "button".</p>
     #[serde(rename = "DeviceType")]
@@ -279,8 +296,16 @@ pub struct ListDevicesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDevicesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(250));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDevicesResponse {
     /// <p>A list of devices.</p>
     #[serde(rename = "Devices")]
@@ -293,6 +318,7 @@ pub struct ListDevicesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The ARN of the resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -300,7 +326,7 @@ pub struct ListTagsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>A collection of key/value pairs defining the resource tags. For example, {
     /// "tags": {"key1": "value1", "key2": "value2"} }. For more information, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS
@@ -313,6 +339,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The ARN of the resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -327,6 +354,7 @@ pub struct TagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnclaimDeviceRequest {
     /// <p>The unique identifier of the device.</p>
     #[serde(rename = "DeviceId")]
@@ -334,7 +362,7 @@ pub struct UnclaimDeviceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnclaimDeviceResponse {
     /// <p>The device's final claim state.</p>
     #[serde(rename = "State")]
@@ -343,6 +371,7 @@ pub struct UnclaimDeviceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The ARN of the resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -353,6 +382,7 @@ pub struct UntagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDeviceStateRequest {
     /// <p>The unique identifier of the device.</p>
     #[serde(rename = "DeviceId")]
@@ -365,7 +395,7 @@ pub struct UpdateDeviceStateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDeviceStateResponse {}
 
 /// Errors returned by ClaimDevicesByClaimCode
@@ -395,7 +425,12 @@ impl ClaimDevicesByClaimCodeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -439,7 +474,12 @@ impl DescribeDeviceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeDeviceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -499,7 +539,12 @@ impl FinalizeDeviceClaimError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -545,7 +590,12 @@ impl GetDeviceMethodsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetDeviceMethodsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -598,7 +648,12 @@ impl InitiateDeviceClaimError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -662,7 +717,12 @@ impl InvokeDeviceMethodError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(InvokeDeviceMethodError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -716,7 +776,12 @@ impl ListDeviceEventsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListDeviceEventsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -761,7 +826,12 @@ impl ListDevicesError {
                 "RangeNotSatisfiableException" => {
                     return RusotoError::Service(ListDevicesError::RangeNotSatisfiable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -802,7 +872,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -845,7 +920,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -889,7 +969,12 @@ impl UnclaimDeviceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UnclaimDeviceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -933,7 +1018,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -977,7 +1067,12 @@ impl UpdateDeviceStateError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateDeviceStateError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1089,6 +1184,229 @@ pub trait Iot1ClickDevices {
         input: UpdateDeviceStateRequest,
     ) -> RusotoFuture<UpdateDeviceStateResponse, UpdateDeviceStateError>;
 }
+const _: Option<&dyn Iot1ClickDevices> = None;
+impl<'a, T: Iot1ClickDevices + ?Sized> Iot1ClickDevices for &'a T {
+    fn claim_devices_by_claim_code(
+        &self,
+        input: ClaimDevicesByClaimCodeRequest,
+    ) -> RusotoFuture<ClaimDevicesByClaimCodeResponse, ClaimDevicesByClaimCodeError> {
+        (**self).claim_devices_by_claim_code(input)
+    }
+    fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
+        (**self).describe_device(input)
+    }
+    fn finalize_device_claim(
+        &self,
+        input: FinalizeDeviceClaimRequest,
+    ) -> RusotoFuture<FinalizeDeviceClaimResponse, FinalizeDeviceClaimError> {
+        (**self).finalize_device_claim(input)
+    }
+    fn get_device_methods(
+        &self,
+        input: GetDeviceMethodsRequest,
+    ) -> RusotoFuture<GetDeviceMethodsResponse, GetDeviceMethodsError> {
+        (**self).get_device_methods(input)
+    }
+    fn initiate_device_claim(
+        &self,
+        input: InitiateDeviceClaimRequest,
+    ) -> RusotoFuture<InitiateDeviceClaimResponse, InitiateDeviceClaimError> {
+        (**self).initiate_device_claim(input)
+    }
+    fn invoke_device_method(
+        &self,
+        input: InvokeDeviceMethodRequest,
+    ) -> RusotoFuture<InvokeDeviceMethodResponse, InvokeDeviceMethodError> {
+        (**self).invoke_device_method(input)
+    }
+    fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        (**self).list_device_events(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unclaim_device(
+        &self,
+        input: UnclaimDeviceRequest,
+    ) -> RusotoFuture<UnclaimDeviceResponse, UnclaimDeviceError> {
+        (**self).unclaim_device(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_device_state(
+        &self,
+        input: UpdateDeviceStateRequest,
+    ) -> RusotoFuture<UpdateDeviceStateResponse, UpdateDeviceStateError> {
+        (**self).update_device_state(input)
+    }
+}
+impl<T: Iot1ClickDevices + ?Sized> Iot1ClickDevices for Box<T> {
+    fn claim_devices_by_claim_code(
+        &self,
+        input: ClaimDevicesByClaimCodeRequest,
+    ) -> RusotoFuture<ClaimDevicesByClaimCodeResponse, ClaimDevicesByClaimCodeError> {
+        (**self).claim_devices_by_claim_code(input)
+    }
+    fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
+        (**self).describe_device(input)
+    }
+    fn finalize_device_claim(
+        &self,
+        input: FinalizeDeviceClaimRequest,
+    ) -> RusotoFuture<FinalizeDeviceClaimResponse, FinalizeDeviceClaimError> {
+        (**self).finalize_device_claim(input)
+    }
+    fn get_device_methods(
+        &self,
+        input: GetDeviceMethodsRequest,
+    ) -> RusotoFuture<GetDeviceMethodsResponse, GetDeviceMethodsError> {
+        (**self).get_device_methods(input)
+    }
+    fn initiate_device_claim(
+        &self,
+        input: InitiateDeviceClaimRequest,
+    ) -> RusotoFuture<InitiateDeviceClaimResponse, InitiateDeviceClaimError> {
+        (**self).initiate_device_claim(input)
+    }
+    fn invoke_device_method(
+        &self,
+        input: InvokeDeviceMethodRequest,
+    ) -> RusotoFuture<InvokeDeviceMethodResponse, InvokeDeviceMethodError> {
+        (**self).invoke_device_method(input)
+    }
+    fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        (**self).list_device_events(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unclaim_device(
+        &self,
+        input: UnclaimDeviceRequest,
+    ) -> RusotoFuture<UnclaimDeviceResponse, UnclaimDeviceError> {
+        (**self).unclaim_device(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_device_state(
+        &self,
+        input: UpdateDeviceStateRequest,
+    ) -> RusotoFuture<UpdateDeviceStateResponse, UpdateDeviceStateError> {
+        (**self).update_device_state(input)
+    }
+}
+impl<T: Iot1ClickDevices + ?Sized> Iot1ClickDevices for ::std::sync::Arc<T> {
+    fn claim_devices_by_claim_code(
+        &self,
+        input: ClaimDevicesByClaimCodeRequest,
+    ) -> RusotoFuture<ClaimDevicesByClaimCodeResponse, ClaimDevicesByClaimCodeError> {
+        (**self).claim_devices_by_claim_code(input)
+    }
+    fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
+        (**self).describe_device(input)
+    }
+    fn finalize_device_claim(
+        &self,
+        input: FinalizeDeviceClaimRequest,
+    ) -> RusotoFuture<FinalizeDeviceClaimResponse, FinalizeDeviceClaimError> {
+        (**self).finalize_device_claim(input)
+    }
+    fn get_device_methods(
+        &self,
+        input: GetDeviceMethodsRequest,
+    ) -> RusotoFuture<GetDeviceMethodsResponse, GetDeviceMethodsError> {
+        (**self).get_device_methods(input)
+    }
+    fn initiate_device_claim(
+        &self,
+        input: InitiateDeviceClaimRequest,
+    ) -> RusotoFuture<InitiateDeviceClaimResponse, InitiateDeviceClaimError> {
+        (**self).initiate_device_claim(input)
+    }
+    fn invoke_device_method(
+        &self,
+        input: InvokeDeviceMethodRequest,
+    ) -> RusotoFuture<InvokeDeviceMethodResponse, InvokeDeviceMethodError> {
+        (**self).invoke_device_method(input)
+    }
+    fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        (**self).list_device_events(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unclaim_device(
+        &self,
+        input: UnclaimDeviceRequest,
+    ) -> RusotoFuture<UnclaimDeviceResponse, UnclaimDeviceError> {
+        (**self).unclaim_device(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_device_state(
+        &self,
+        input: UpdateDeviceStateRequest,
+    ) -> RusotoFuture<UpdateDeviceStateResponse, UpdateDeviceStateError> {
+        (**self).update_device_state(input)
+    }
+}
 /// A client for the AWS IoT 1-Click Devices Service API.
 #[derive(Clone)]
 pub struct Iot1ClickDevicesClient {
@@ -1136,6 +1454,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/claims/{claim_code}", claim_code = input.claim_code);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("ClaimDevicesByClaimCode");
+        request.add_resource_id("ClaimCode", &input.claim_code.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1144,6 +1465,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ClaimDevicesByClaimCode")
                         .deserialize::<ClaimDevicesByClaimCodeResponse, _>()?;
 
                     Ok(result)
@@ -1165,6 +1487,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribeDevice");
+        request.add_resource_id("DeviceId", &input.device_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1173,6 +1498,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeDevice")
                         .deserialize::<DescribeDeviceResponse, _>()?;
 
                     Ok(result)
@@ -1204,6 +1530,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("FinalizeDeviceClaim");
+        request.add_resource_id("DeviceId", &input.device_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1214,6 +1543,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("FinalizeDeviceClaim")
                         .deserialize::<FinalizeDeviceClaimResponse, _>()?;
 
                     Ok(result)
@@ -1236,6 +1566,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("GetDeviceMethods");
+        request.add_resource_id("DeviceId", &input.device_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1244,6 +1577,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDeviceMethods")
                         .deserialize::<GetDeviceMethodsResponse, _>()?;
 
                     Ok(result)
@@ -1275,6 +1609,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("InitiateDeviceClaim");
+        request.add_resource_id("DeviceId", &input.device_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1283,6 +1620,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("InitiateDeviceClaim")
                         .deserialize::<InitiateDeviceClaimResponse, _>()?;
 
                     Ok(result)
@@ -1306,6 +1644,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("InvokeDeviceMethod");
+        request.add_resource_id("DeviceId", &input.device_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1316,6 +1657,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("InvokeDeviceMethod")
                         .deserialize::<InvokeDeviceMethodResponse, _>()?;
 
                     Ok(result)
@@ -1337,9 +1679,20 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         &self,
         input: ListDeviceEventsRequest,
     ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/devices/{device_id}/events", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListDeviceEvents");
+        request.add_resource_id("DeviceId", &input.device_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1359,6 +1712,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDeviceEvents")
                         .deserialize::<ListDeviceEventsResponse, _>()?;
 
                     Ok(result)
@@ -1379,9 +1733,19 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         &self,
         input: ListDevicesRequest,
     ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/devices";
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListDevices");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1402,6 +1766,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDevices")
                         .deserialize::<ListDevicesResponse, _>()?;
 
                     Ok(result)
@@ -1425,6 +1790,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1433,6 +1801,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
                         .deserialize::<ListTagsForResourceResponse, _>()?;
 
                     Ok(result)
@@ -1453,6 +1822,8 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1485,6 +1856,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/unclaim", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UnclaimDevice");
+        request.add_resource_id("DeviceId", &input.device_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1493,6 +1867,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UnclaimDevice")
                         .deserialize::<UnclaimDeviceResponse, _>()?;
 
                     Ok(result)
@@ -1514,6 +1889,8 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1551,6 +1928,8 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/state", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdateDeviceState");
+        request.add_resource_id("DeviceId", &input.device_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1561,6 +1940,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDeviceState")
                         .deserialize::<UpdateDeviceStateResponse, _>()?;
 
                     Ok(result)
@@ -1576,3 +1956,455 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         })
     }
 }
+
+/// A mock of the AWS IoT 1-Click Devices Service API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockIot1ClickDevices {
+    claim_devices_by_claim_code: Option<
+        Box<
+            dyn Fn(
+                    ClaimDevicesByClaimCodeRequest,
+                ) -> ::std::result::Result<
+                    ClaimDevicesByClaimCodeResponse,
+                    RusotoError<ClaimDevicesByClaimCodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_device: Option<
+        Box<
+            dyn Fn(
+                    DescribeDeviceRequest,
+                ) -> ::std::result::Result<
+                    DescribeDeviceResponse,
+                    RusotoError<DescribeDeviceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    finalize_device_claim: Option<
+        Box<
+            dyn Fn(
+                    FinalizeDeviceClaimRequest,
+                ) -> ::std::result::Result<
+                    FinalizeDeviceClaimResponse,
+                    RusotoError<FinalizeDeviceClaimError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_device_methods: Option<
+        Box<
+            dyn Fn(
+                    GetDeviceMethodsRequest,
+                ) -> ::std::result::Result<
+                    GetDeviceMethodsResponse,
+                    RusotoError<GetDeviceMethodsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    initiate_device_claim: Option<
+        Box<
+            dyn Fn(
+                    InitiateDeviceClaimRequest,
+                ) -> ::std::result::Result<
+                    InitiateDeviceClaimResponse,
+                    RusotoError<InitiateDeviceClaimError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    invoke_device_method: Option<
+        Box<
+            dyn Fn(
+                    InvokeDeviceMethodRequest,
+                ) -> ::std::result::Result<
+                    InvokeDeviceMethodResponse,
+                    RusotoError<InvokeDeviceMethodError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_device_events: Option<
+        Box<
+            dyn Fn(
+                    ListDeviceEventsRequest,
+                ) -> ::std::result::Result<
+                    ListDeviceEventsResponse,
+                    RusotoError<ListDeviceEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_devices: Option<
+        Box<
+            dyn Fn(
+                    ListDevicesRequest,
+                )
+                    -> ::std::result::Result<ListDevicesResponse, RusotoError<ListDevicesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    unclaim_device: Option<
+        Box<
+            dyn Fn(
+                    UnclaimDeviceRequest,
+                )
+                    -> ::std::result::Result<UnclaimDeviceResponse, RusotoError<UnclaimDeviceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_device_state: Option<
+        Box<
+            dyn Fn(
+                    UpdateDeviceStateRequest,
+                ) -> ::std::result::Result<
+                    UpdateDeviceStateResponse,
+                    RusotoError<UpdateDeviceStateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockIot1ClickDevices {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockIot1ClickDevices {
+        Default::default()
+    }
+
+    /// Answers `claim_devices_by_claim_code` with what `handler` returns for its input.
+    pub fn with_claim_devices_by_claim_code<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                ClaimDevicesByClaimCodeRequest,
+            ) -> ::std::result::Result<
+                ClaimDevicesByClaimCodeResponse,
+                RusotoError<ClaimDevicesByClaimCodeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.claim_devices_by_claim_code = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_device` with what `handler` returns for its input.
+    pub fn with_describe_device<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                DescribeDeviceRequest,
+            )
+                -> ::std::result::Result<DescribeDeviceResponse, RusotoError<DescribeDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `finalize_device_claim` with what `handler` returns for its input.
+    pub fn with_finalize_device_claim<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                FinalizeDeviceClaimRequest,
+            ) -> ::std::result::Result<
+                FinalizeDeviceClaimResponse,
+                RusotoError<FinalizeDeviceClaimError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.finalize_device_claim = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_device_methods` with what `handler` returns for its input.
+    pub fn with_get_device_methods<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                GetDeviceMethodsRequest,
+            ) -> ::std::result::Result<
+                GetDeviceMethodsResponse,
+                RusotoError<GetDeviceMethodsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_device_methods = Some(Box::new(handler));
+        self
+    }
+    /// Answers `initiate_device_claim` with what `handler` returns for its input.
+    pub fn with_initiate_device_claim<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                InitiateDeviceClaimRequest,
+            ) -> ::std::result::Result<
+                InitiateDeviceClaimResponse,
+                RusotoError<InitiateDeviceClaimError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.initiate_device_claim = Some(Box::new(handler));
+        self
+    }
+    /// Answers `invoke_device_method` with what `handler` returns for its input.
+    pub fn with_invoke_device_method<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                InvokeDeviceMethodRequest,
+            ) -> ::std::result::Result<
+                InvokeDeviceMethodResponse,
+                RusotoError<InvokeDeviceMethodError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.invoke_device_method = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_device_events` with what `handler` returns for its input.
+    pub fn with_list_device_events<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                ListDeviceEventsRequest,
+            ) -> ::std::result::Result<
+                ListDeviceEventsResponse,
+                RusotoError<ListDeviceEventsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_device_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_devices` with what `handler` returns for its input.
+    pub fn with_list_devices<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                ListDevicesRequest,
+            )
+                -> ::std::result::Result<ListDevicesResponse, RusotoError<ListDevicesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_devices = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unclaim_device` with what `handler` returns for its input.
+    pub fn with_unclaim_device<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                UnclaimDeviceRequest,
+            )
+                -> ::std::result::Result<UnclaimDeviceResponse, RusotoError<UnclaimDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.unclaim_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_device_state` with what `handler` returns for its input.
+    pub fn with_update_device_state<F>(mut self, handler: F) -> MockIot1ClickDevices
+    where
+        F: Fn(
+                UpdateDeviceStateRequest,
+            ) -> ::std::result::Result<
+                UpdateDeviceStateResponse,
+                RusotoError<UpdateDeviceStateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_device_state = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Iot1ClickDevices for MockIot1ClickDevices {
+    fn claim_devices_by_claim_code(
+        &self,
+        input: ClaimDevicesByClaimCodeRequest,
+    ) -> RusotoFuture<ClaimDevicesByClaimCodeResponse, ClaimDevicesByClaimCodeError> {
+        match self.claim_devices_by_claim_code {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for claim_devices_by_claim_code"),
+        }
+    }
+    fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
+        match self.describe_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for describe_device"),
+        }
+    }
+    fn finalize_device_claim(
+        &self,
+        input: FinalizeDeviceClaimRequest,
+    ) -> RusotoFuture<FinalizeDeviceClaimResponse, FinalizeDeviceClaimError> {
+        match self.finalize_device_claim {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for finalize_device_claim"),
+        }
+    }
+    fn get_device_methods(
+        &self,
+        input: GetDeviceMethodsRequest,
+    ) -> RusotoFuture<GetDeviceMethodsResponse, GetDeviceMethodsError> {
+        match self.get_device_methods {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for get_device_methods"),
+        }
+    }
+    fn initiate_device_claim(
+        &self,
+        input: InitiateDeviceClaimRequest,
+    ) -> RusotoFuture<InitiateDeviceClaimResponse, InitiateDeviceClaimError> {
+        match self.initiate_device_claim {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for initiate_device_claim"),
+        }
+    }
+    fn invoke_device_method(
+        &self,
+        input: InvokeDeviceMethodRequest,
+    ) -> RusotoFuture<InvokeDeviceMethodResponse, InvokeDeviceMethodError> {
+        match self.invoke_device_method {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for invoke_device_method"),
+        }
+    }
+    fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        match self.list_device_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for list_device_events"),
+        }
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        match self.list_devices {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for list_devices"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for list_tags_for_resource"),
+        }
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for tag_resource"),
+        }
+    }
+    fn unclaim_device(
+        &self,
+        input: UnclaimDeviceRequest,
+    ) -> RusotoFuture<UnclaimDeviceResponse, UnclaimDeviceError> {
+        match self.unclaim_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for unclaim_device"),
+        }
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for untag_resource"),
+        }
+    }
+    fn update_device_state(
+        &self,
+        input: UpdateDeviceStateRequest,
+    ) -> RusotoFuture<UpdateDeviceStateResponse, UpdateDeviceStateError> {
+        match self.update_device_state {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickDevices has no handler for update_device_state"),
+        }
+    }
+}
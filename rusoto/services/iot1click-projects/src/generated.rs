@@ -23,8 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateDeviceWithPlacementRequest {
     /// <p>The ID of the physical device to be associated with the given placement in the project. Note that a mandatory 4 character prefix is required for all <code>deviceId</code> values.</p>
     #[serde(rename = "deviceId")]
@@ -40,11 +42,56 @@ pub struct AssociateDeviceWithPlacementRequest {
     pub project_name: String,
 }
 
+impl Validate for AssociateDeviceWithPlacementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "device_id"),
+            self.device_id.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_length(
+            &field_path(path, "device_template_name"),
+            self.device_template_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_template_name"),
+            &self.device_template_name,
+            "^[a-zA-Z0-9_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "placement_name"),
+            self.placement_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "placement_name"),
+            &self.placement_name,
+            "^[a-zA-Z0-9_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateDeviceWithPlacementResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePlacementRequest {
     /// <p>Optional user-defined key/value pairs providing contextual data (such as location or function) for the placement.</p>
     #[serde(rename = "attributes")]
@@ -58,11 +105,45 @@ pub struct CreatePlacementRequest {
     pub project_name: String,
 }
 
+impl Validate for CreatePlacementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(800));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "placement_name"),
+            self.placement_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "placement_name"),
+            &self.placement_name,
+            "^[a-zA-Z0-9_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePlacementResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProjectRequest {
     /// <p>An optional description for the project.</p>
     #[serde(rename = "description")]
@@ -81,11 +162,46 @@ pub struct CreateProjectRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.placement_template {
+            value.validate_at(&field_path(path, "placement_template"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProjectResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePlacementRequest {
     /// <p>The name of the empty placement to delete.</p>
     #[serde(rename = "placementName")]
@@ -95,22 +211,67 @@ pub struct DeletePlacementRequest {
     pub project_name: String,
 }
 
+impl Validate for DeletePlacementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "placement_name"),
+            self.placement_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "placement_name"),
+            &self.placement_name,
+            "^[a-zA-Z0-9_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeletePlacementResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProjectRequest {
     /// <p>The name of the empty project to delete.</p>
     #[serde(rename = "projectName")]
     pub project_name: String,
 }
 
+impl Validate for DeleteProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProjectResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePlacementRequest {
     /// <p>The name of the placement within a project.</p>
     #[serde(rename = "placementName")]
@@ -120,8 +281,35 @@ pub struct DescribePlacementRequest {
     pub project_name: String,
 }
 
+impl Validate for DescribePlacementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "placement_name"),
+            self.placement_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "placement_name"),
+            &self.placement_name,
+            "^[a-zA-Z0-9_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePlacementResponse {
     /// <p>An object describing the placement.</p>
     #[serde(rename = "placement")]
@@ -129,14 +317,31 @@ pub struct DescribePlacementResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProjectRequest {
     /// <p>The name of the project to be described.</p>
     #[serde(rename = "projectName")]
     pub project_name: String,
 }
 
+impl Validate for DescribeProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProjectResponse {
     /// <p>An object describing the project.</p>
     #[serde(rename = "project")]
@@ -156,7 +361,27 @@ pub struct DeviceTemplate {
     pub device_type: Option<String>,
 }
 
+impl Validate for DeviceTemplate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.callback_overrides {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "callback_overrides"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(200));
+            }
+        }
+        if let Some(ref value) = self.device_type {
+            errors.check_length(
+                &field_path(path, "device_type"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateDeviceFromPlacementRequest {
     /// <p>The device ID that should be removed from the placement.</p>
     #[serde(rename = "deviceTemplateName")]
@@ -169,11 +394,50 @@ pub struct DisassociateDeviceFromPlacementRequest {
     pub project_name: String,
 }
 
+impl Validate for DisassociateDeviceFromPlacementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "device_template_name"),
+            self.device_template_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "device_template_name"),
+            &self.device_template_name,
+            "^[a-zA-Z0-9_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "placement_name"),
+            self.placement_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "placement_name"),
+            &self.placement_name,
+            "^[a-zA-Z0-9_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateDeviceFromPlacementResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDevicesInPlacementRequest {
     /// <p>The name of the placement to get the devices from.</p>
     #[serde(rename = "placementName")]
@@ -183,8 +447,35 @@ pub struct GetDevicesInPlacementRequest {
     pub project_name: String,
 }
 
+impl Validate for GetDevicesInPlacementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "placement_name"),
+            self.placement_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "placement_name"),
+            &self.placement_name,
+            "^[a-zA-Z0-9_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDevicesInPlacementResponse {
     /// <p>An object containing the devices (zero or more) within the placement.</p>
     #[serde(rename = "devices")]
@@ -192,6 +483,7 @@ pub struct GetDevicesInPlacementResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPlacementsRequest {
     /// <p>The maximum number of results to return per request. If not set, a default value of 100 is used.</p>
     #[serde(rename = "maxResults")]
@@ -206,8 +498,35 @@ pub struct ListPlacementsRequest {
     pub project_name: String,
 }
 
+impl Validate for ListPlacementsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(250));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPlacementsResponse {
     /// <p>The token used to retrieve the next set of results - will be effectively empty if there are no further results.</p>
     #[serde(rename = "nextToken")]
@@ -219,6 +538,7 @@ pub struct ListPlacementsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProjectsRequest {
     /// <p>The maximum number of results to return per request. If not set, a default value of 100 is used.</p>
     #[serde(rename = "maxResults")]
@@ -230,8 +550,24 @@ pub struct ListProjectsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListProjectsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(250));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProjectsResponse {
     /// <p>The token used to retrieve the next set of results - will be effectively empty if there are no further results.</p>
     #[serde(rename = "nextToken")]
@@ -243,14 +579,25 @@ pub struct ListProjectsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The ARN of the resource whose tags you want to list.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:iot1click:[A-Za-z0-9_/.-]{0,63}:\\d+:projects/[0-9A-Za-z_-]{1,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags (metadata key/value pairs) which you have assigned to the resource.</p>
     #[serde(rename = "tags")]
@@ -260,7 +607,7 @@ pub struct ListTagsForResourceResponse {
 
 /// <p>An object describing a project's placement.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PlacementDescription {
     /// <p>The user-defined attributes associated with the placement.</p>
     #[serde(rename = "attributes")]
@@ -281,7 +628,7 @@ pub struct PlacementDescription {
 
 /// <p>An object providing summary information for a particular placement.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PlacementSummary {
     /// <p>The date when the placement was originally created, in UNIX epoch time format.</p>
     #[serde(rename = "createdDate")]
@@ -310,9 +657,26 @@ pub struct PlacementTemplate {
     pub device_templates: Option<::std::collections::HashMap<String, DeviceTemplate>>,
 }
 
+impl Validate for PlacementTemplate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_attributes {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "default_attributes"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(800));
+            }
+        }
+        if let Some(ref value) = self.device_templates {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "device_templates"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>An object providing detailed information for a particular project associated with an AWS account and region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProjectDescription {
     /// <p>The ARN of the project.</p>
     #[serde(rename = "arn")]
@@ -343,7 +707,7 @@ pub struct ProjectDescription {
 
 /// <p>An object providing summary information for a particular project for an associated AWS account and region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProjectSummary {
     /// <p>The ARN of the project.</p>
     #[serde(rename = "arn")]
@@ -365,6 +729,7 @@ pub struct ProjectSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The ARN of the resouce for which tag(s) should be added or modified.</p>
     #[serde(rename = "resourceArn")]
@@ -374,11 +739,32 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:iot1click:[A-Za-z0-9_/.-]{0,63}:\\d+:projects/[0-9A-Za-z_-]{1,128}$",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The ARN of the resource whose tag you want to remove.</p>
     #[serde(rename = "resourceArn")]
@@ -388,11 +774,33 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:iot1click:[A-Za-z0-9_/.-]{0,63}:\\d+:projects/[0-9A-Za-z_-]{1,128}$",
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^(?!aws:)[a-zA-Z+-=._:/]+$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePlacementRequest {
     /// <p>The user-defined object of attributes used to update the placement. The maximum number of key/value pairs is 50.</p>
     #[serde(rename = "attributes")]
@@ -406,11 +814,45 @@ pub struct UpdatePlacementRequest {
     pub project_name: String,
 }
 
+impl Validate for UpdatePlacementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(800));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "placement_name"),
+            self.placement_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "placement_name"),
+            &self.placement_name,
+            "^[a-zA-Z0-9_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePlacementResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProjectRequest {
     /// <p>An optional user-defined description for the project.</p>
     #[serde(rename = "description")]
@@ -425,8 +867,35 @@ pub struct UpdateProjectRequest {
     pub project_name: String,
 }
 
+impl Validate for UpdateProjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.placement_template {
+            value.validate_at(&field_path(path, "placement_template"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "project_name"),
+            self.project_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "project_name"),
+            &self.project_name,
+            "^[0-9A-Za-z_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateProjectResponse {}
 
 /// Errors returned by AssociateDeviceWithPlacement
@@ -468,7 +937,12 @@ impl AssociateDeviceWithPlacementError {
                         AssociateDeviceWithPlacementError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -519,7 +993,12 @@ impl CreatePlacementError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreatePlacementError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -565,7 +1044,12 @@ impl CreateProjectError {
                 "ResourceConflictException" => {
                     return RusotoError::Service(CreateProjectError::ResourceConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -615,7 +1099,12 @@ impl DeletePlacementError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeletePlacementError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -666,7 +1155,12 @@ impl DeleteProjectError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteProjectError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -712,7 +1206,12 @@ impl DescribePlacementError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribePlacementError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -757,7 +1256,12 @@ impl DescribeProjectError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeProjectError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -817,7 +1321,12 @@ impl DisassociateDeviceFromPlacementError {
                         DisassociateDeviceFromPlacementError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -869,7 +1378,12 @@ impl GetDevicesInPlacementError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -914,7 +1428,12 @@ impl ListPlacementsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListPlacementsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -954,7 +1473,12 @@ impl ListProjectsError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(ListProjectsError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1000,7 +1524,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1045,7 +1574,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1090,7 +1624,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1140,7 +1679,12 @@ impl UpdatePlacementError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdatePlacementError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1191,7 +1735,12 @@ impl UpdateProjectError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateProjectError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1311,135 +1860,470 @@ pub trait Iot1ClickProjects {
         input: UpdateProjectRequest,
     ) -> RusotoFuture<UpdateProjectResponse, UpdateProjectError>;
 }
-/// A client for the AWS IoT 1-Click Projects API.
-#[derive(Clone)]
-pub struct Iot1ClickProjectsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl Iot1ClickProjectsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> Iot1ClickProjectsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> Iot1ClickProjectsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> Iot1ClickProjectsClient {
-        Iot1ClickProjectsClient { client, region }
-    }
-}
-
-impl Iot1ClickProjects for Iot1ClickProjectsClient {
-    /// <p>Associates a physical device with a placement.</p>
+const _: Option<&dyn Iot1ClickProjects> = None;
+impl<'a, T: Iot1ClickProjects + ?Sized> Iot1ClickProjects for &'a T {
     fn associate_device_with_placement(
         &self,
         input: AssociateDeviceWithPlacementRequest,
     ) -> RusotoFuture<AssociateDeviceWithPlacementResponse, AssociateDeviceWithPlacementError> {
-        let request_uri = format!(
-            "/projects/{project_name}/placements/{placement_name}/devices/{device_template_name}",
-            device_template_name = input.device_template_name,
-            placement_name = input.placement_name,
-            project_name = input.project_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("projects.iot1click".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateDeviceWithPlacementResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateDeviceWithPlacementError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_device_with_placement(input)
     }
-
-    /// <p>Creates an empty placement.</p>
     fn create_placement(
         &self,
         input: CreatePlacementRequest,
     ) -> RusotoFuture<CreatePlacementResponse, CreatePlacementError> {
-        let request_uri = format!(
-            "/projects/{project_name}/placements",
-            project_name = input.project_name
-        );
-
-        let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("projects.iot1click".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePlacementResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreatePlacementError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_placement(input)
     }
-
-    /// <p>Creates an empty project with a placement template. A project contains zero or more placements that adhere to the placement template defined in the project.</p>
     fn create_project(
         &self,
         input: CreateProjectRequest,
     ) -> RusotoFuture<CreateProjectResponse, CreateProjectError> {
-        let request_uri = "/projects";
-
-        let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("projects.iot1click".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateProjectResponse, _>()?;
-
+        (**self).create_project(input)
+    }
+    fn delete_placement(
+        &self,
+        input: DeletePlacementRequest,
+    ) -> RusotoFuture<DeletePlacementResponse, DeletePlacementError> {
+        (**self).delete_placement(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResponse, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn describe_placement(
+        &self,
+        input: DescribePlacementRequest,
+    ) -> RusotoFuture<DescribePlacementResponse, DescribePlacementError> {
+        (**self).describe_placement(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResponse, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn disassociate_device_from_placement(
+        &self,
+        input: DisassociateDeviceFromPlacementRequest,
+    ) -> RusotoFuture<DisassociateDeviceFromPlacementResponse, DisassociateDeviceFromPlacementError>
+    {
+        (**self).disassociate_device_from_placement(input)
+    }
+    fn get_devices_in_placement(
+        &self,
+        input: GetDevicesInPlacementRequest,
+    ) -> RusotoFuture<GetDevicesInPlacementResponse, GetDevicesInPlacementError> {
+        (**self).get_devices_in_placement(input)
+    }
+    fn list_placements(
+        &self,
+        input: ListPlacementsRequest,
+    ) -> RusotoFuture<ListPlacementsResponse, ListPlacementsError> {
+        (**self).list_placements(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResponse, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_placement(
+        &self,
+        input: UpdatePlacementRequest,
+    ) -> RusotoFuture<UpdatePlacementResponse, UpdatePlacementError> {
+        (**self).update_placement(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResponse, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+}
+impl<T: Iot1ClickProjects + ?Sized> Iot1ClickProjects for Box<T> {
+    fn associate_device_with_placement(
+        &self,
+        input: AssociateDeviceWithPlacementRequest,
+    ) -> RusotoFuture<AssociateDeviceWithPlacementResponse, AssociateDeviceWithPlacementError> {
+        (**self).associate_device_with_placement(input)
+    }
+    fn create_placement(
+        &self,
+        input: CreatePlacementRequest,
+    ) -> RusotoFuture<CreatePlacementResponse, CreatePlacementError> {
+        (**self).create_placement(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResponse, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn delete_placement(
+        &self,
+        input: DeletePlacementRequest,
+    ) -> RusotoFuture<DeletePlacementResponse, DeletePlacementError> {
+        (**self).delete_placement(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResponse, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn describe_placement(
+        &self,
+        input: DescribePlacementRequest,
+    ) -> RusotoFuture<DescribePlacementResponse, DescribePlacementError> {
+        (**self).describe_placement(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResponse, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn disassociate_device_from_placement(
+        &self,
+        input: DisassociateDeviceFromPlacementRequest,
+    ) -> RusotoFuture<DisassociateDeviceFromPlacementResponse, DisassociateDeviceFromPlacementError>
+    {
+        (**self).disassociate_device_from_placement(input)
+    }
+    fn get_devices_in_placement(
+        &self,
+        input: GetDevicesInPlacementRequest,
+    ) -> RusotoFuture<GetDevicesInPlacementResponse, GetDevicesInPlacementError> {
+        (**self).get_devices_in_placement(input)
+    }
+    fn list_placements(
+        &self,
+        input: ListPlacementsRequest,
+    ) -> RusotoFuture<ListPlacementsResponse, ListPlacementsError> {
+        (**self).list_placements(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResponse, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_placement(
+        &self,
+        input: UpdatePlacementRequest,
+    ) -> RusotoFuture<UpdatePlacementResponse, UpdatePlacementError> {
+        (**self).update_placement(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResponse, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+}
+impl<T: Iot1ClickProjects + ?Sized> Iot1ClickProjects for ::std::sync::Arc<T> {
+    fn associate_device_with_placement(
+        &self,
+        input: AssociateDeviceWithPlacementRequest,
+    ) -> RusotoFuture<AssociateDeviceWithPlacementResponse, AssociateDeviceWithPlacementError> {
+        (**self).associate_device_with_placement(input)
+    }
+    fn create_placement(
+        &self,
+        input: CreatePlacementRequest,
+    ) -> RusotoFuture<CreatePlacementResponse, CreatePlacementError> {
+        (**self).create_placement(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResponse, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn delete_placement(
+        &self,
+        input: DeletePlacementRequest,
+    ) -> RusotoFuture<DeletePlacementResponse, DeletePlacementError> {
+        (**self).delete_placement(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResponse, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn describe_placement(
+        &self,
+        input: DescribePlacementRequest,
+    ) -> RusotoFuture<DescribePlacementResponse, DescribePlacementError> {
+        (**self).describe_placement(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResponse, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn disassociate_device_from_placement(
+        &self,
+        input: DisassociateDeviceFromPlacementRequest,
+    ) -> RusotoFuture<DisassociateDeviceFromPlacementResponse, DisassociateDeviceFromPlacementError>
+    {
+        (**self).disassociate_device_from_placement(input)
+    }
+    fn get_devices_in_placement(
+        &self,
+        input: GetDevicesInPlacementRequest,
+    ) -> RusotoFuture<GetDevicesInPlacementResponse, GetDevicesInPlacementError> {
+        (**self).get_devices_in_placement(input)
+    }
+    fn list_placements(
+        &self,
+        input: ListPlacementsRequest,
+    ) -> RusotoFuture<ListPlacementsResponse, ListPlacementsError> {
+        (**self).list_placements(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResponse, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_placement(
+        &self,
+        input: UpdatePlacementRequest,
+    ) -> RusotoFuture<UpdatePlacementResponse, UpdatePlacementError> {
+        (**self).update_placement(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResponse, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+}
+/// A client for the AWS IoT 1-Click Projects API.
+#[derive(Clone)]
+pub struct Iot1ClickProjectsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl Iot1ClickProjectsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Iot1ClickProjectsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Iot1ClickProjectsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> Iot1ClickProjectsClient {
+        Iot1ClickProjectsClient { client, region }
+    }
+}
+
+impl Iot1ClickProjects for Iot1ClickProjectsClient {
+    /// <p>Associates a physical device with a placement.</p>
+    fn associate_device_with_placement(
+        &self,
+        input: AssociateDeviceWithPlacementRequest,
+    ) -> RusotoFuture<AssociateDeviceWithPlacementResponse, AssociateDeviceWithPlacementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/projects/{project_name}/placements/{placement_name}/devices/{device_template_name}",
+            device_template_name = input.device_template_name,
+            placement_name = input.placement_name,
+            project_name = input.project_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("AssociateDeviceWithPlacement");
+        request.add_resource_id(
+            "deviceTemplateName",
+            &input.device_template_name.to_string(),
+        );
+        request.add_resource_id("placementName", &input.placement_name.to_string());
+        request.add_resource_id("projectName", &input.project_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("projects.iot1click".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AssociateDeviceWithPlacement")
+                        .deserialize::<AssociateDeviceWithPlacementResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateDeviceWithPlacementError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates an empty placement.</p>
+    fn create_placement(
+        &self,
+        input: CreatePlacementRequest,
+    ) -> RusotoFuture<CreatePlacementResponse, CreatePlacementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/projects/{project_name}/placements",
+            project_name = input.project_name
+        );
+
+        let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("CreatePlacement");
+        request.add_resource_id("projectName", &input.project_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("projects.iot1click".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreatePlacement")
+                        .deserialize::<CreatePlacementResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreatePlacementError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an empty project with a placement template. A project contains zero or more placements that adhere to the placement template defined in the project.</p>
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResponse, CreateProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/projects";
+
+        let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("CreateProject");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("projects.iot1click".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateProject")
+                        .deserialize::<CreateProjectResponse, _>()?;
+
                     Ok(result)
                 }))
             } else {
@@ -1458,6 +2342,14 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: DeletePlacementRequest,
     ) -> RusotoFuture<DeletePlacementResponse, DeletePlacementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}",
             placement_name = input.placement_name,
@@ -1465,6 +2357,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("DeletePlacement");
+        request.add_resource_id("placementName", &input.placement_name.to_string());
+        request.add_resource_id("projectName", &input.project_name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1473,6 +2368,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeletePlacement")
                         .deserialize::<DeletePlacementResponse, _>()?;
 
                     Ok(result)
@@ -1493,12 +2389,22 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: DeleteProjectRequest,
     ) -> RusotoFuture<DeleteProjectResponse, DeleteProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/projects/{project_name}",
             project_name = input.project_name
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("DeleteProject");
+        request.add_resource_id("projectName", &input.project_name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1507,6 +2413,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteProject")
                         .deserialize::<DeleteProjectResponse, _>()?;
 
                     Ok(result)
@@ -1527,6 +2434,14 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: DescribePlacementRequest,
     ) -> RusotoFuture<DescribePlacementResponse, DescribePlacementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}",
             placement_name = input.placement_name,
@@ -1534,6 +2449,10 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribePlacement");
+        request.add_resource_id("placementName", &input.placement_name.to_string());
+        request.add_resource_id("projectName", &input.project_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1542,6 +2461,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribePlacement")
                         .deserialize::<DescribePlacementResponse, _>()?;
 
                     Ok(result)
@@ -1562,12 +2482,23 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: DescribeProjectRequest,
     ) -> RusotoFuture<DescribeProjectResponse, DescribeProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/projects/{project_name}",
             project_name = input.project_name
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribeProject");
+        request.add_resource_id("projectName", &input.project_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1576,6 +2507,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeProject")
                         .deserialize::<DescribeProjectResponse, _>()?;
 
                     Ok(result)
@@ -1597,6 +2529,14 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         input: DisassociateDeviceFromPlacementRequest,
     ) -> RusotoFuture<DisassociateDeviceFromPlacementResponse, DisassociateDeviceFromPlacementError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}/devices/{device_template_name}",
             device_template_name = input.device_template_name,
@@ -1605,6 +2545,13 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("DisassociateDeviceFromPlacement");
+        request.add_resource_id(
+            "deviceTemplateName",
+            &input.device_template_name.to_string(),
+        );
+        request.add_resource_id("placementName", &input.placement_name.to_string());
+        request.add_resource_id("projectName", &input.project_name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1613,6 +2560,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateDeviceFromPlacement")
                         .deserialize::<DisassociateDeviceFromPlacementResponse, _>()?;
 
                     Ok(result)
@@ -1632,6 +2580,14 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: GetDevicesInPlacementRequest,
     ) -> RusotoFuture<GetDevicesInPlacementResponse, GetDevicesInPlacementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}/devices",
             placement_name = input.placement_name,
@@ -1639,6 +2595,10 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("GetDevicesInPlacement");
+        request.add_resource_id("placementName", &input.placement_name.to_string());
+        request.add_resource_id("projectName", &input.project_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1647,6 +2607,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDevicesInPlacement")
                         .deserialize::<GetDevicesInPlacementResponse, _>()?;
 
                     Ok(result)
@@ -1666,12 +2627,23 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: ListPlacementsRequest,
     ) -> RusotoFuture<ListPlacementsResponse, ListPlacementsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/projects/{project_name}/placements",
             project_name = input.project_name
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListPlacements");
+        request.add_resource_id("projectName", &input.project_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1689,6 +2661,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListPlacements")
                         .deserialize::<ListPlacementsResponse, _>()?;
 
                     Ok(result)
@@ -1709,9 +2682,19 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: ListProjectsRequest,
     ) -> RusotoFuture<ListProjectsResponse, ListProjectsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/projects";
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListProjects");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1729,6 +2712,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListProjects")
                         .deserialize::<ListProjectsResponse, _>()?;
 
                     Ok(result)
@@ -1749,9 +2733,20 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1760,6 +2755,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
                         .deserialize::<ListTagsForResourceResponse, _>()?;
 
                     Ok(result)
@@ -1779,9 +2775,19 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1792,6 +2798,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
                         .deserialize::<TagResourceResponse, _>()?;
 
                     Ok(result)
@@ -1812,9 +2819,19 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("resourceArn", &input.resource_arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1829,6 +2846,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
                         .deserialize::<UntagResourceResponse, _>()?;
 
                     Ok(result)
@@ -1849,6 +2867,14 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: UpdatePlacementRequest,
     ) -> RusotoFuture<UpdatePlacementResponse, UpdatePlacementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}",
             placement_name = input.placement_name,
@@ -1856,6 +2882,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdatePlacement");
+        request.add_resource_id("placementName", &input.placement_name.to_string());
+        request.add_resource_id("projectName", &input.project_name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1866,6 +2895,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdatePlacement")
                         .deserialize::<UpdatePlacementResponse, _>()?;
 
                     Ok(result)
@@ -1886,12 +2916,22 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         &self,
         input: UpdateProjectRequest,
     ) -> RusotoFuture<UpdateProjectResponse, UpdateProjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/projects/{project_name}",
             project_name = input.project_name
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdateProject");
+        request.add_resource_id("projectName", &input.project_name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1902,6 +2942,7 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateProject")
                         .deserialize::<UpdateProjectResponse, _>()?;
 
                     Ok(result)
@@ -1917,3 +2958,573 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         })
     }
 }
+
+/// A mock of the AWS IoT 1-Click Projects API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockIot1ClickProjects {
+    associate_device_with_placement: Option<
+        Box<
+            dyn Fn(
+                    AssociateDeviceWithPlacementRequest,
+                ) -> ::std::result::Result<
+                    AssociateDeviceWithPlacementResponse,
+                    RusotoError<AssociateDeviceWithPlacementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_placement: Option<
+        Box<
+            dyn Fn(
+                    CreatePlacementRequest,
+                ) -> ::std::result::Result<
+                    CreatePlacementResponse,
+                    RusotoError<CreatePlacementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_project: Option<
+        Box<
+            dyn Fn(
+                    CreateProjectRequest,
+                )
+                    -> ::std::result::Result<CreateProjectResponse, RusotoError<CreateProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_placement: Option<
+        Box<
+            dyn Fn(
+                    DeletePlacementRequest,
+                ) -> ::std::result::Result<
+                    DeletePlacementResponse,
+                    RusotoError<DeletePlacementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_project: Option<
+        Box<
+            dyn Fn(
+                    DeleteProjectRequest,
+                )
+                    -> ::std::result::Result<DeleteProjectResponse, RusotoError<DeleteProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_placement: Option<
+        Box<
+            dyn Fn(
+                    DescribePlacementRequest,
+                ) -> ::std::result::Result<
+                    DescribePlacementResponse,
+                    RusotoError<DescribePlacementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_project: Option<
+        Box<
+            dyn Fn(
+                    DescribeProjectRequest,
+                ) -> ::std::result::Result<
+                    DescribeProjectResponse,
+                    RusotoError<DescribeProjectError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_device_from_placement: Option<
+        Box<
+            dyn Fn(
+                    DisassociateDeviceFromPlacementRequest,
+                ) -> ::std::result::Result<
+                    DisassociateDeviceFromPlacementResponse,
+                    RusotoError<DisassociateDeviceFromPlacementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_devices_in_placement: Option<
+        Box<
+            dyn Fn(
+                    GetDevicesInPlacementRequest,
+                ) -> ::std::result::Result<
+                    GetDevicesInPlacementResponse,
+                    RusotoError<GetDevicesInPlacementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_placements: Option<
+        Box<
+            dyn Fn(
+                    ListPlacementsRequest,
+                ) -> ::std::result::Result<
+                    ListPlacementsResponse,
+                    RusotoError<ListPlacementsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_projects: Option<
+        Box<
+            dyn Fn(
+                    ListProjectsRequest,
+                )
+                    -> ::std::result::Result<ListProjectsResponse, RusotoError<ListProjectsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_placement: Option<
+        Box<
+            dyn Fn(
+                    UpdatePlacementRequest,
+                ) -> ::std::result::Result<
+                    UpdatePlacementResponse,
+                    RusotoError<UpdatePlacementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_project: Option<
+        Box<
+            dyn Fn(
+                    UpdateProjectRequest,
+                )
+                    -> ::std::result::Result<UpdateProjectResponse, RusotoError<UpdateProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockIot1ClickProjects {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockIot1ClickProjects {
+        Default::default()
+    }
+
+    /// Answers `associate_device_with_placement` with what `handler` returns for its input.
+    pub fn with_associate_device_with_placement<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                AssociateDeviceWithPlacementRequest,
+            ) -> ::std::result::Result<
+                AssociateDeviceWithPlacementResponse,
+                RusotoError<AssociateDeviceWithPlacementError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_device_with_placement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_placement` with what `handler` returns for its input.
+    pub fn with_create_placement<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                CreatePlacementRequest,
+            )
+                -> ::std::result::Result<CreatePlacementResponse, RusotoError<CreatePlacementError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_placement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_project` with what `handler` returns for its input.
+    pub fn with_create_project<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                CreateProjectRequest,
+            )
+                -> ::std::result::Result<CreateProjectResponse, RusotoError<CreateProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_placement` with what `handler` returns for its input.
+    pub fn with_delete_placement<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                DeletePlacementRequest,
+            )
+                -> ::std::result::Result<DeletePlacementResponse, RusotoError<DeletePlacementError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_placement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_project` with what `handler` returns for its input.
+    pub fn with_delete_project<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                DeleteProjectRequest,
+            )
+                -> ::std::result::Result<DeleteProjectResponse, RusotoError<DeleteProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_placement` with what `handler` returns for its input.
+    pub fn with_describe_placement<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                DescribePlacementRequest,
+            ) -> ::std::result::Result<
+                DescribePlacementResponse,
+                RusotoError<DescribePlacementError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_placement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_project` with what `handler` returns for its input.
+    pub fn with_describe_project<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                DescribeProjectRequest,
+            )
+                -> ::std::result::Result<DescribeProjectResponse, RusotoError<DescribeProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_device_from_placement` with what `handler` returns for its input.
+    pub fn with_disassociate_device_from_placement<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                DisassociateDeviceFromPlacementRequest,
+            ) -> ::std::result::Result<
+                DisassociateDeviceFromPlacementResponse,
+                RusotoError<DisassociateDeviceFromPlacementError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_device_from_placement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_devices_in_placement` with what `handler` returns for its input.
+    pub fn with_get_devices_in_placement<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                GetDevicesInPlacementRequest,
+            ) -> ::std::result::Result<
+                GetDevicesInPlacementResponse,
+                RusotoError<GetDevicesInPlacementError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_devices_in_placement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_placements` with what `handler` returns for its input.
+    pub fn with_list_placements<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                ListPlacementsRequest,
+            )
+                -> ::std::result::Result<ListPlacementsResponse, RusotoError<ListPlacementsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_placements = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_projects` with what `handler` returns for its input.
+    pub fn with_list_projects<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                ListProjectsRequest,
+            )
+                -> ::std::result::Result<ListProjectsResponse, RusotoError<ListProjectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_projects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_placement` with what `handler` returns for its input.
+    pub fn with_update_placement<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                UpdatePlacementRequest,
+            )
+                -> ::std::result::Result<UpdatePlacementResponse, RusotoError<UpdatePlacementError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_placement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_project` with what `handler` returns for its input.
+    pub fn with_update_project<F>(mut self, handler: F) -> MockIot1ClickProjects
+    where
+        F: Fn(
+                UpdateProjectRequest,
+            )
+                -> ::std::result::Result<UpdateProjectResponse, RusotoError<UpdateProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_project = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Iot1ClickProjects for MockIot1ClickProjects {
+    fn associate_device_with_placement(
+        &self,
+        input: AssociateDeviceWithPlacementRequest,
+    ) -> RusotoFuture<AssociateDeviceWithPlacementResponse, AssociateDeviceWithPlacementError> {
+        match self.associate_device_with_placement {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockIot1ClickProjects has no handler for associate_device_with_placement")
+            }
+        }
+    }
+    fn create_placement(
+        &self,
+        input: CreatePlacementRequest,
+    ) -> RusotoFuture<CreatePlacementResponse, CreatePlacementError> {
+        match self.create_placement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for create_placement"),
+        }
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResponse, CreateProjectError> {
+        match self.create_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for create_project"),
+        }
+    }
+    fn delete_placement(
+        &self,
+        input: DeletePlacementRequest,
+    ) -> RusotoFuture<DeletePlacementResponse, DeletePlacementError> {
+        match self.delete_placement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for delete_placement"),
+        }
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResponse, DeleteProjectError> {
+        match self.delete_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for delete_project"),
+        }
+    }
+    fn describe_placement(
+        &self,
+        input: DescribePlacementRequest,
+    ) -> RusotoFuture<DescribePlacementResponse, DescribePlacementError> {
+        match self.describe_placement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for describe_placement"),
+        }
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResponse, DescribeProjectError> {
+        match self.describe_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for describe_project"),
+        }
+    }
+    fn disassociate_device_from_placement(
+        &self,
+        input: DisassociateDeviceFromPlacementRequest,
+    ) -> RusotoFuture<DisassociateDeviceFromPlacementResponse, DisassociateDeviceFromPlacementError>
+    {
+        match self.disassociate_device_from_placement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockIot1ClickProjects has no handler for disassociate_device_from_placement"
+            ),
+        }
+    }
+    fn get_devices_in_placement(
+        &self,
+        input: GetDevicesInPlacementRequest,
+    ) -> RusotoFuture<GetDevicesInPlacementResponse, GetDevicesInPlacementError> {
+        match self.get_devices_in_placement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for get_devices_in_placement"),
+        }
+    }
+    fn list_placements(
+        &self,
+        input: ListPlacementsRequest,
+    ) -> RusotoFuture<ListPlacementsResponse, ListPlacementsError> {
+        match self.list_placements {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for list_placements"),
+        }
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResponse, ListProjectsError> {
+        match self.list_projects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for list_projects"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for list_tags_for_resource"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for untag_resource"),
+        }
+    }
+    fn update_placement(
+        &self,
+        input: UpdatePlacementRequest,
+    ) -> RusotoFuture<UpdatePlacementResponse, UpdatePlacementError> {
+        match self.update_placement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for update_placement"),
+        }
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResponse, UpdateProjectError> {
+        match self.update_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIot1ClickProjects has no handler for update_project"),
+        }
+    }
+}
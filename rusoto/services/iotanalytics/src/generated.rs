@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>An activity that adds other attributes based on existing attributes in the message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -39,9 +40,38 @@ pub struct AddAttributesActivity {
     pub next: Option<String>,
 }
 
+impl Validate for AddAttributesActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attributes"),
+            self.attributes.len(),
+            Some(1),
+            Some(50),
+        );
+        for (key, item) in self.attributes.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), key);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.next {
+            errors.check_length(
+                &field_path(path, "next"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+    }
+}
+
 /// <p>Contains informations about errors.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchPutMessageErrorEntry {
     /// <p>The code associated with the error.</p>
     #[serde(rename = "errorCode")]
@@ -58,6 +88,7 @@ pub struct BatchPutMessageErrorEntry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchPutMessageRequest {
     /// <p>The name of the channel where the messages are sent.</p>
     #[serde(rename = "channelName")]
@@ -67,8 +98,28 @@ pub struct BatchPutMessageRequest {
     pub messages: Vec<Message>,
 }
 
+impl Validate for BatchPutMessageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "channel_name"),
+            self.channel_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "channel_name"),
+            &self.channel_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        for (i, item) in self.messages.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "messages"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchPutMessageResponse {
     /// <p>A list of any errors encountered when sending the messages to the channel.</p>
     #[serde(rename = "batchPutMessageErrorEntries")]
@@ -77,6 +128,7 @@ pub struct BatchPutMessageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelPipelineReprocessingRequest {
     /// <p>The name of pipeline for which data reprocessing is canceled.</p>
     #[serde(rename = "pipelineName")]
@@ -86,13 +138,29 @@ pub struct CancelPipelineReprocessingRequest {
     pub reprocessing_id: String,
 }
 
+impl Validate for CancelPipelineReprocessingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelPipelineReprocessingResponse {}
 
 /// <p>A collection of data from an MQTT topic. Channels archive the raw, unprocessed messages before publishing the data to a pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Channel {
     /// <p>The ARN of the channel.</p>
     #[serde(rename = "arn")]
@@ -139,9 +207,39 @@ pub struct ChannelActivity {
     pub next: Option<String>,
 }
 
+impl Validate for ChannelActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "channel_name"),
+            self.channel_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "channel_name"),
+            &self.channel_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.next {
+            errors.check_length(
+                &field_path(path, "next"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+    }
+}
+
 /// <p>Statistics information about the channel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChannelStatistics {
     /// <p>The estimated size of the channel.</p>
     #[serde(rename = "size")]
@@ -162,9 +260,17 @@ pub struct ChannelStorage {
     pub service_managed_s3: Option<ServiceManagedChannelS3Storage>,
 }
 
+impl Validate for ChannelStorage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.customer_managed_s3 {
+            value.validate_at(&field_path(path, "customer_managed_s3"), errors);
+        }
+    }
+}
+
 /// <p>Where channel data is stored.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChannelStorageSummary {
     /// <p>Used to store channel data in an S3 bucket that you manage.</p>
     #[serde(rename = "customerManagedS3")]
@@ -178,7 +284,7 @@ pub struct ChannelStorageSummary {
 
 /// <p>A summary of information about a channel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChannelSummary {
     /// <p>The name of the channel.</p>
     #[serde(rename = "channelName")]
@@ -220,7 +326,34 @@ pub struct ContainerDatasetAction {
     pub variables: Option<Vec<Variable>>,
 }
 
+impl Validate for ContainerDatasetAction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "execution_role_arn"),
+            self.execution_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "image"),
+            self.image.chars().count(),
+            None,
+            Some(255),
+        );
+        self.resource_configuration
+            .validate_at(&field_path(path, "resource_configuration"), errors);
+        if let Some(ref value) = self.variables {
+            errors.check_length(&field_path(path, "variables"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "variables"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateChannelRequest {
     /// <p>The name of the channel.</p>
     #[serde(rename = "channelName")]
@@ -239,8 +372,37 @@ pub struct CreateChannelRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateChannelRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "channel_name"),
+            self.channel_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "channel_name"),
+            &self.channel_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.channel_storage {
+            value.validate_at(&field_path(path, "channel_storage"), errors);
+        }
+        if let Some(ref value) = self.retention_period {
+            value.validate_at(&field_path(path, "retention_period"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateChannelResponse {
     /// <p>The ARN of the channel.</p>
     #[serde(rename = "channelArn")]
@@ -257,14 +419,31 @@ pub struct CreateChannelResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDatasetContentRequest {
     /// <p>The name of the data set.</p>
     #[serde(rename = "datasetName")]
     pub dataset_name: String,
 }
 
+impl Validate for CreateDatasetContentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDatasetContentResponse {
     /// <p>The version ID of the data set contents which are being created.</p>
     #[serde(rename = "versionId")]
@@ -273,6 +452,7 @@ pub struct CreateDatasetContentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDatasetRequest {
     /// <p>A list of actions that create the data set contents.</p>
     #[serde(rename = "actions")]
@@ -302,8 +482,66 @@ pub struct CreateDatasetRequest {
     pub versioning_configuration: Option<VersioningConfiguration>,
 }
 
+impl Validate for CreateDatasetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "actions"),
+            self.actions.len(),
+            Some(1),
+            Some(1),
+        );
+        for (i, item) in self.actions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "actions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.content_delivery_rules {
+            errors.check_length(
+                &field_path(path, "content_delivery_rules"),
+                value.len(),
+                None,
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "content_delivery_rules"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.retention_period {
+            value.validate_at(&field_path(path, "retention_period"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.triggers {
+            errors.check_length(&field_path(path, "triggers"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "triggers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.versioning_configuration {
+            value.validate_at(&field_path(path, "versioning_configuration"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDatasetResponse {
     /// <p>The ARN of the data set.</p>
     #[serde(rename = "datasetArn")]
@@ -320,6 +558,7 @@ pub struct CreateDatasetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDatastoreRequest {
     /// <p>The name of the data store.</p>
     #[serde(rename = "datastoreName")]
@@ -338,8 +577,37 @@ pub struct CreateDatastoreRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateDatastoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "datastore_name"),
+            self.datastore_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "datastore_name"),
+            &self.datastore_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.datastore_storage {
+            value.validate_at(&field_path(path, "datastore_storage"), errors);
+        }
+        if let Some(ref value) = self.retention_period {
+            value.validate_at(&field_path(path, "retention_period"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDatastoreResponse {
     /// <p>The ARN of the data store.</p>
     #[serde(rename = "datastoreArn")]
@@ -356,6 +624,7 @@ pub struct CreateDatastoreResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePipelineRequest {
     /// <p>A list of "PipelineActivity" objects. Activities perform transformations on your messages, such as removing, renaming or adding message attributes; filtering messages based on attribute values; invoking your Lambda functions on messages for advanced processing; or performing mathematical transformations to normalize device data.</p> <p>The list can be 2-25 <b>PipelineActivity</b> objects and must contain both a <code>channel</code> and a <code>datastore</code> activity. Each entry in the list must contain only one activity, for example:</p> <p> <code>pipelineActivities = [ { "channel": { ... } }, { "lambda": { ... } }, ... ]</code> </p>
     #[serde(rename = "pipelineActivities")]
@@ -369,8 +638,41 @@ pub struct CreatePipelineRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreatePipelineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_activities"),
+            self.pipeline_activities.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.pipeline_activities.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "pipeline_activities"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePipelineResponse {
     /// <p>The ARN of the pipeline.</p>
     #[serde(rename = "pipelineArn")]
@@ -397,9 +699,44 @@ pub struct CustomerManagedChannelS3Storage {
     pub role_arn: String,
 }
 
+impl Validate for CustomerManagedChannelS3Storage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bucket"),
+            self.bucket.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "bucket"),
+            &self.bucket,
+            "^[a-zA-Z0-9.\\-_]*$",
+        );
+        if let Some(ref value) = self.key_prefix {
+            errors.check_length(
+                &field_path(path, "key_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "key_prefix"),
+                &value,
+                "^[a-zA-Z0-9!_.*'()/{}:-]*/$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 /// <p>Used to store channel data in an S3 bucket that you manage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CustomerManagedChannelS3StorageSummary {
     /// <p>The name of the Amazon S3 bucket in which channel data is stored.</p>
     #[serde(rename = "bucket")]
@@ -430,9 +767,44 @@ pub struct CustomerManagedDatastoreS3Storage {
     pub role_arn: String,
 }
 
+impl Validate for CustomerManagedDatastoreS3Storage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bucket"),
+            self.bucket.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "bucket"),
+            &self.bucket,
+            "^[a-zA-Z0-9.\\-_]*$",
+        );
+        if let Some(ref value) = self.key_prefix {
+            errors.check_length(
+                &field_path(path, "key_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "key_prefix"),
+                &value,
+                "^[a-zA-Z0-9!_.*'()/{}:-]*/$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 /// <p>Used to store data store data in an S3 bucket that you manage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CustomerManagedDatastoreS3StorageSummary {
     /// <p>The name of the Amazon S3 bucket in which data store data is stored.</p>
     #[serde(rename = "bucket")]
@@ -450,7 +822,7 @@ pub struct CustomerManagedDatastoreS3StorageSummary {
 
 /// <p>Information about a data set.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Dataset {
     /// <p>The "DatasetAction" objects that automatically create the data set contents.</p>
     #[serde(rename = "actions")]
@@ -511,9 +883,29 @@ pub struct DatasetAction {
     pub query_action: Option<SqlQueryDatasetAction>,
 }
 
+impl Validate for DatasetAction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.action_name {
+            errors.check_length(
+                &field_path(path, "action_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "action_name"), &value, "^[a-zA-Z0-9_]+$");
+        }
+        if let Some(ref value) = self.container_action {
+            value.validate_at(&field_path(path, "container_action"), errors);
+        }
+        if let Some(ref value) = self.query_action {
+            value.validate_at(&field_path(path, "query_action"), errors);
+        }
+    }
+}
+
 /// <p>Information about the action which automatically creates the data set's contents.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DatasetActionSummary {
     /// <p>The name of the action which automatically creates the data set's contents.</p>
     #[serde(rename = "actionName")]
@@ -538,6 +930,20 @@ pub struct DatasetContentDeliveryDestination {
     pub s_3_destination_configuration: Option<S3DestinationConfiguration>,
 }
 
+impl Validate for DatasetContentDeliveryDestination {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.iot_events_destination_configuration {
+            value.validate_at(
+                &field_path(path, "iot_events_destination_configuration"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.s_3_destination_configuration {
+            value.validate_at(&field_path(path, "s_3_destination_configuration"), errors);
+        }
+    }
+}
+
 /// <p>When data set contents are created they are delivered to destination specified here.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DatasetContentDeliveryRule {
@@ -550,9 +956,16 @@ pub struct DatasetContentDeliveryRule {
     pub entry_name: Option<String>,
 }
 
+impl Validate for DatasetContentDeliveryRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.destination
+            .validate_at(&field_path(path, "destination"), errors);
+    }
+}
+
 /// <p>The state of the data set contents and the reason they are in this state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DatasetContentStatus {
     /// <p>The reason the data set contents are in this state.</p>
     #[serde(rename = "reason")]
@@ -566,7 +979,7 @@ pub struct DatasetContentStatus {
 
 /// <p>Summary information about data set contents.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DatasetContentSummary {
     /// <p>The actual time the creation of the data set contents was started.</p>
     #[serde(rename = "creationTime")]
@@ -594,9 +1007,25 @@ pub struct DatasetContentVersionValue {
     pub dataset_name: String,
 }
 
+impl Validate for DatasetContentVersionValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 /// <p>The reference to a data set entry.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DatasetEntry {
     /// <p>The pre-signed URI of the data set item.</p>
     #[serde(rename = "dataURI")]
@@ -610,7 +1039,7 @@ pub struct DatasetEntry {
 
 /// <p>A summary of information about a data set.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DatasetSummary {
     /// <p>A list of "DataActionSummary" objects.</p>
     #[serde(rename = "actions")]
@@ -651,9 +1080,17 @@ pub struct DatasetTrigger {
     pub schedule: Option<Schedule>,
 }
 
+impl Validate for DatasetTrigger {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dataset {
+            value.validate_at(&field_path(path, "dataset"), errors);
+        }
+    }
+}
+
 /// <p>Information about a data store.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Datastore {
     /// <p>The ARN of the data store.</p>
     #[serde(rename = "arn")]
@@ -696,9 +1133,31 @@ pub struct DatastoreActivity {
     pub name: String,
 }
 
+impl Validate for DatastoreActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "datastore_name"),
+            self.datastore_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "datastore_name"),
+            &self.datastore_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+    }
+}
+
 /// <p>Statistical information about the data store.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DatastoreStatistics {
     /// <p>The estimated size of the data store.</p>
     #[serde(rename = "size")]
@@ -719,9 +1178,17 @@ pub struct DatastoreStorage {
     pub service_managed_s3: Option<ServiceManagedDatastoreS3Storage>,
 }
 
+impl Validate for DatastoreStorage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.customer_managed_s3 {
+            value.validate_at(&field_path(path, "customer_managed_s3"), errors);
+        }
+    }
+}
+
 /// <p>Where data store data is stored.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DatastoreStorageSummary {
     /// <p>Used to store data store data in an S3 bucket that you manage.</p>
     #[serde(rename = "customerManagedS3")]
@@ -735,7 +1202,7 @@ pub struct DatastoreStorageSummary {
 
 /// <p>A summary of information about a data store.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DatastoreSummary {
     /// <p>When the data store was created.</p>
     #[serde(rename = "creationTime")]
@@ -760,13 +1227,31 @@ pub struct DatastoreSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteChannelRequest {
     /// <p>The name of the channel to delete.</p>
     #[serde(rename = "channelName")]
     pub channel_name: String,
 }
 
+impl Validate for DeleteChannelRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "channel_name"),
+            self.channel_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "channel_name"),
+            &self.channel_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDatasetContentRequest {
     /// <p>The name of the data set whose content is deleted.</p>
     #[serde(rename = "datasetName")]
@@ -777,27 +1262,102 @@ pub struct DeleteDatasetContentRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for DeleteDatasetContentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.version_id {
+            errors.check_length(
+                &field_path(path, "version_id"),
+                value.chars().count(),
+                Some(7),
+                Some(36),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDatasetRequest {
     /// <p>The name of the data set to delete.</p>
     #[serde(rename = "datasetName")]
     pub dataset_name: String,
 }
 
+impl Validate for DeleteDatasetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDatastoreRequest {
     /// <p>The name of the data store to delete.</p>
     #[serde(rename = "datastoreName")]
     pub datastore_name: String,
 }
 
+impl Validate for DeleteDatastoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "datastore_name"),
+            self.datastore_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "datastore_name"),
+            &self.datastore_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePipelineRequest {
     /// <p>The name of the pipeline to delete.</p>
     #[serde(rename = "pipelineName")]
     pub pipeline_name: String,
 }
 
+impl Validate for DeletePipelineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 /// <p>Used to limit data to that which has arrived since the last execution of the action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeltaTime {
@@ -810,6 +1370,7 @@ pub struct DeltaTime {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeChannelRequest {
     /// <p>The name of the channel whose information is retrieved.</p>
     #[serde(rename = "channelName")]
@@ -820,8 +1381,24 @@ pub struct DescribeChannelRequest {
     pub include_statistics: Option<bool>,
 }
 
+impl Validate for DescribeChannelRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "channel_name"),
+            self.channel_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "channel_name"),
+            &self.channel_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeChannelResponse {
     /// <p>An object that contains information about the channel.</p>
     #[serde(rename = "channel")]
@@ -834,14 +1411,31 @@ pub struct DescribeChannelResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDatasetRequest {
     /// <p>The name of the data set whose information is retrieved.</p>
     #[serde(rename = "datasetName")]
     pub dataset_name: String,
 }
 
+impl Validate for DescribeDatasetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDatasetResponse {
     /// <p>An object that contains information about the data set.</p>
     #[serde(rename = "dataset")]
@@ -850,6 +1444,7 @@ pub struct DescribeDatasetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDatastoreRequest {
     /// <p>The name of the data store</p>
     #[serde(rename = "datastoreName")]
@@ -860,8 +1455,24 @@ pub struct DescribeDatastoreRequest {
     pub include_statistics: Option<bool>,
 }
 
+impl Validate for DescribeDatastoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "datastore_name"),
+            self.datastore_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "datastore_name"),
+            &self.datastore_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDatastoreResponse {
     /// <p>Information about the data store.</p>
     #[serde(rename = "datastore")]
@@ -874,10 +1485,11 @@ pub struct DescribeDatastoreResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoggingOptionsRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeLoggingOptionsResponse {
     /// <p>The current settings of the AWS IoT Analytics logging options.</p>
     #[serde(rename = "loggingOptions")]
@@ -886,14 +1498,31 @@ pub struct DescribeLoggingOptionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePipelineRequest {
     /// <p>The name of the pipeline whose information is retrieved.</p>
     #[serde(rename = "pipelineName")]
     pub pipeline_name: String,
 }
 
+impl Validate for DescribePipelineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePipelineResponse {
     /// <p>A "Pipeline" object that contains information about the pipeline.</p>
     #[serde(rename = "pipeline")]
@@ -922,6 +1551,43 @@ pub struct DeviceRegistryEnrichActivity {
     pub thing_name: String,
 }
 
+impl Validate for DeviceRegistryEnrichActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute"),
+            self.attribute.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.next {
+            errors.check_length(
+                &field_path(path, "next"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "thing_name"),
+            self.thing_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>An activity that adds information from the AWS IoT Device Shadows service to a message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeviceShadowEnrichActivity {
@@ -943,9 +1609,46 @@ pub struct DeviceShadowEnrichActivity {
     pub thing_name: String,
 }
 
+impl Validate for DeviceShadowEnrichActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute"),
+            self.attribute.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.next {
+            errors.check_length(
+                &field_path(path, "next"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "thing_name"),
+            self.thing_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>The estimated size of the resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EstimatedResourceSize {
     /// <p>The time when the estimate of the size of the resource was made.</p>
     #[serde(rename = "estimatedOn")]
@@ -972,7 +1675,33 @@ pub struct FilterActivity {
     pub next: Option<String>,
 }
 
+impl Validate for FilterActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "filter"),
+            self.filter.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.next {
+            errors.check_length(
+                &field_path(path, "next"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDatasetContentRequest {
     /// <p>The name of the data set whose contents are retrieved.</p>
     #[serde(rename = "datasetName")]
@@ -983,8 +1712,32 @@ pub struct GetDatasetContentRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for GetDatasetContentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.version_id {
+            errors.check_length(
+                &field_path(path, "version_id"),
+                value.chars().count(),
+                Some(7),
+                Some(36),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDatasetContentResponse {
     /// <p>A list of "DatasetEntry" objects.</p>
     #[serde(rename = "entries")]
@@ -1011,6 +1764,33 @@ pub struct GlueConfiguration {
     pub table_name: String,
 }
 
+impl Validate for GlueConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(150),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(150),
+        );
+        errors.check_pattern(
+            &field_path(path, "table_name"),
+            &self.table_name,
+            "[\\u0020-\\uD7FF\\uE000-\\uFFFD\\uD800\\uDC00-\\uDBFF\\uDFFF\\t]*",
+        );
+    }
+}
+
 /// <p>Configuration information for delivery of data set contents to AWS IoT Events.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IotEventsDestinationConfiguration {
@@ -1022,6 +1802,28 @@ pub struct IotEventsDestinationConfiguration {
     pub role_arn: String,
 }
 
+impl Validate for IotEventsDestinationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "input_name"),
+            self.input_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "input_name"),
+            &self.input_name,
+            "^[a-zA-Z][a-zA-Z0-9_]*$",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 /// <p>An activity that runs a Lambda function to modify the message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LambdaActivity {
@@ -1040,7 +1842,44 @@ pub struct LambdaActivity {
     pub next: Option<String>,
 }
 
+impl Validate for LambdaActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "batch_size"),
+            self.batch_size,
+            Some(1),
+            Some(1000),
+        );
+        errors.check_length(
+            &field_path(path, "lambda_name"),
+            self.lambda_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "lambda_name"),
+            &self.lambda_name,
+            "^[a-zA-Z0-9_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.next {
+            errors.check_length(
+                &field_path(path, "next"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListChannelsRequest {
     /// <p>The maximum number of results to return in this request.</p> <p>The default value is 100.</p>
     #[serde(rename = "maxResults")]
@@ -1052,8 +1891,16 @@ pub struct ListChannelsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListChannelsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(250));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListChannelsResponse {
     /// <p>A list of "ChannelSummary" objects.</p>
     #[serde(rename = "channelSummaries")]
@@ -1066,6 +1913,7 @@ pub struct ListChannelsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDatasetContentsRequest {
     /// <p>The name of the data set whose contents information you want to list.</p>
     #[serde(rename = "datasetName")]
@@ -1088,8 +1936,27 @@ pub struct ListDatasetContentsRequest {
     pub scheduled_on_or_after: Option<f64>,
 }
 
+impl Validate for ListDatasetContentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(250));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDatasetContentsResponse {
     /// <p>Summary information about data set contents that have been created.</p>
     #[serde(rename = "datasetContentSummaries")]
@@ -1102,6 +1969,7 @@ pub struct ListDatasetContentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDatasetsRequest {
     /// <p>The maximum number of results to return in this request.</p> <p>The default value is 100.</p>
     #[serde(rename = "maxResults")]
@@ -1113,8 +1981,16 @@ pub struct ListDatasetsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDatasetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(250));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDatasetsResponse {
     /// <p>A list of "DatasetSummary" objects.</p>
     #[serde(rename = "datasetSummaries")]
@@ -1127,6 +2003,7 @@ pub struct ListDatasetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDatastoresRequest {
     /// <p>The maximum number of results to return in this request.</p> <p>The default value is 100.</p>
     #[serde(rename = "maxResults")]
@@ -1138,8 +2015,16 @@ pub struct ListDatastoresRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDatastoresRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(250));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDatastoresResponse {
     /// <p>A list of "DatastoreSummary" objects.</p>
     #[serde(rename = "datastoreSummaries")]
@@ -1152,6 +2037,7 @@ pub struct ListDatastoresResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPipelinesRequest {
     /// <p>The maximum number of results to return in this request.</p> <p>The default value is 100.</p>
     #[serde(rename = "maxResults")]
@@ -1163,8 +2049,16 @@ pub struct ListPipelinesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListPipelinesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(250));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPipelinesResponse {
     /// <p>The token to retrieve the next set of results, or <code>null</code> if there are no more results.</p>
     #[serde(rename = "nextToken")]
@@ -1177,14 +2071,26 @@ pub struct ListPipelinesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The ARN of the resource whose tags you want to list.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags (metadata) which you have assigned to the resource.</p>
     #[serde(rename = "tags")]
@@ -1206,6 +2112,17 @@ pub struct LoggingOptions {
     pub role_arn: String,
 }
 
+impl Validate for LoggingOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 /// <p>An activity that computes an arithmetic expression using the message's attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MathActivity {
@@ -1224,8 +2141,40 @@ pub struct MathActivity {
     pub next: Option<String>,
 }
 
-/// <p>Information about a message.</p>
+impl Validate for MathActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute"),
+            self.attribute.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "math"),
+            self.math.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.next {
+            errors.check_length(
+                &field_path(path, "next"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+    }
+}
+
+/// <p>Information about a message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Message {
     /// <p>The ID you wish to assign to the message. Each "messageId" must be unique within each batch sent.</p>
     #[serde(rename = "messageId")]
@@ -1240,6 +2189,22 @@ pub struct Message {
     pub payload: bytes::Bytes,
 }
 
+impl Validate for Message {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "message_id"),
+            self.message_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "message_id"),
+            &self.message_id,
+            "\\p{ASCII}*",
+        );
+    }
+}
+
 /// <p>The value of the variable as a structure that specifies an output file URI.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputFileUriValue {
@@ -1248,9 +2213,19 @@ pub struct OutputFileUriValue {
     pub file_name: String,
 }
 
+impl Validate for OutputFileUriValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "file_name"),
+            &self.file_name,
+            "[\\w\\.-]{1,255}",
+        );
+    }
+}
+
 /// <p>Contains information about a pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Pipeline {
     /// <p>The activities that perform transformations on the messages.</p>
     #[serde(rename = "activities")]
@@ -1323,9 +2298,44 @@ pub struct PipelineActivity {
     pub select_attributes: Option<SelectAttributesActivity>,
 }
 
+impl Validate for PipelineActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.add_attributes {
+            value.validate_at(&field_path(path, "add_attributes"), errors);
+        }
+        if let Some(ref value) = self.channel {
+            value.validate_at(&field_path(path, "channel"), errors);
+        }
+        if let Some(ref value) = self.datastore {
+            value.validate_at(&field_path(path, "datastore"), errors);
+        }
+        if let Some(ref value) = self.device_registry_enrich {
+            value.validate_at(&field_path(path, "device_registry_enrich"), errors);
+        }
+        if let Some(ref value) = self.device_shadow_enrich {
+            value.validate_at(&field_path(path, "device_shadow_enrich"), errors);
+        }
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.lambda {
+            value.validate_at(&field_path(path, "lambda"), errors);
+        }
+        if let Some(ref value) = self.math {
+            value.validate_at(&field_path(path, "math"), errors);
+        }
+        if let Some(ref value) = self.remove_attributes {
+            value.validate_at(&field_path(path, "remove_attributes"), errors);
+        }
+        if let Some(ref value) = self.select_attributes {
+            value.validate_at(&field_path(path, "select_attributes"), errors);
+        }
+    }
+}
+
 /// <p>A summary of information about a pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PipelineSummary {
     /// <p>When the pipeline was created.</p>
     #[serde(rename = "creationTime")]
@@ -1346,12 +2356,20 @@ pub struct PipelineSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutLoggingOptionsRequest {
     /// <p>The new values of the AWS IoT Analytics logging options.</p>
     #[serde(rename = "loggingOptions")]
     pub logging_options: LoggingOptions,
 }
 
+impl Validate for PutLoggingOptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.logging_options
+            .validate_at(&field_path(path, "logging_options"), errors);
+    }
+}
+
 /// <p>Information which is used to filter message data, to segregate it according to the time frame in which it arrives.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QueryFilter {
@@ -1376,9 +2394,38 @@ pub struct RemoveAttributesActivity {
     pub next: Option<String>,
 }
 
+impl Validate for RemoveAttributesActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attributes"),
+            self.attributes.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.next {
+            errors.check_length(
+                &field_path(path, "next"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+    }
+}
+
 /// <p>Information about pipeline reprocessing.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReprocessingSummary {
     /// <p>The time the pipeline reprocessing was created.</p>
     #[serde(rename = "creationTime")]
@@ -1405,6 +2452,17 @@ pub struct ResourceConfiguration {
     pub volume_size_in_gb: i64,
 }
 
+impl Validate for ResourceConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "volume_size_in_gb"),
+            self.volume_size_in_gb,
+            Some(1),
+            Some(50),
+        );
+    }
+}
+
 /// <p>How long, in days, message data is kept.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RetentionPeriod {
@@ -1418,7 +2476,16 @@ pub struct RetentionPeriod {
     pub unlimited: Option<bool>,
 }
 
+impl Validate for RetentionPeriod {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.number_of_days {
+            errors.check_range(&field_path(path, "number_of_days"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RunPipelineActivityRequest {
     /// <p>The sample message payloads on which the pipeline activity is run.</p>
     #[serde(rename = "payloads")]
@@ -1433,8 +2500,21 @@ pub struct RunPipelineActivityRequest {
     pub pipeline_activity: PipelineActivity,
 }
 
+impl Validate for RunPipelineActivityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "payloads"),
+            self.payloads.len(),
+            Some(1),
+            Some(10),
+        );
+        self.pipeline_activity
+            .validate_at(&field_path(path, "pipeline_activity"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RunPipelineActivityResponse {
     /// <p>In case the pipeline activity fails, the log message that is generated.</p>
     #[serde(rename = "logResult")]
@@ -1469,7 +2549,44 @@ pub struct S3DestinationConfiguration {
     pub role_arn: String,
 }
 
+impl Validate for S3DestinationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bucket"),
+            self.bucket.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "bucket"),
+            &self.bucket,
+            "^[a-zA-Z0-9.\\-_]*$",
+        );
+        if let Some(ref value) = self.glue_configuration {
+            value.validate_at(&field_path(path, "glue_configuration"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^[a-zA-Z0-9!_.*'()/{}:-]*$",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SampleChannelDataRequest {
     /// <p>The name of the channel whose message samples are retrieved.</p>
     #[serde(rename = "channelName")]
@@ -1488,8 +2605,27 @@ pub struct SampleChannelDataRequest {
     pub start_time: Option<f64>,
 }
 
+impl Validate for SampleChannelDataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "channel_name"),
+            self.channel_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "channel_name"),
+            &self.channel_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.max_messages {
+            errors.check_range(&field_path(path, "max_messages"), *value, Some(1), Some(10));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SampleChannelDataResponse {
     /// <p>The list of message samples. Each sample message is returned as a base64-encoded string.</p>
     #[serde(rename = "payloads")]
@@ -1526,13 +2662,42 @@ pub struct SelectAttributesActivity {
     pub next: Option<String>,
 }
 
+impl Validate for SelectAttributesActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attributes"),
+            self.attributes.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.next {
+            errors.check_length(
+                &field_path(path, "next"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+    }
+}
+
 /// <p>Use this to store channel data in an S3 bucket managed by the AWS IoT Analytics service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServiceManagedChannelS3Storage {}
 
 /// <p>Used to store channel data in an S3 bucket managed by the AWS IoT Analytics service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceManagedChannelS3StorageSummary {}
 
 /// <p>Use this to store data store data in an S3 bucket managed by the AWS IoT Analytics service.</p>
@@ -1541,7 +2706,7 @@ pub struct ServiceManagedDatastoreS3Storage {}
 
 /// <p>Used to store data store data in an S3 bucket managed by the AWS IoT Analytics service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceManagedDatastoreS3StorageSummary {}
 
 /// <p>The SQL query to modify the message.</p>
@@ -1556,7 +2721,16 @@ pub struct SqlQueryDatasetAction {
     pub sql_query: String,
 }
 
+impl Validate for SqlQueryDatasetAction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(1));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartPipelineReprocessingRequest {
     /// <p>The end time (exclusive) of raw message data that is reprocessed.</p>
     #[serde(rename = "endTime")]
@@ -1571,8 +2745,24 @@ pub struct StartPipelineReprocessingRequest {
     pub start_time: Option<f64>,
 }
 
+impl Validate for StartPipelineReprocessingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartPipelineReprocessingResponse {
     /// <p>The ID of the pipeline reprocessing activity that was started.</p>
     #[serde(rename = "reprocessingId")]
@@ -1591,7 +2781,25 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The ARN of the resource whose tags you want to modify.</p>
     #[serde(rename = "resourceArn")]
@@ -1601,8 +2809,29 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>Information about the data set whose content generation triggers the new data set content generation.</p>
@@ -1613,7 +2842,24 @@ pub struct TriggeringDataset {
     pub name: String,
 }
 
+impl Validate for TriggeringDataset {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The ARN of the resource whose tags you want to remove.</p>
     #[serde(rename = "resourceArn")]
@@ -1623,11 +2869,33 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateChannelRequest {
     /// <p>The name of the channel to be updated.</p>
     #[serde(rename = "channelName")]
@@ -1642,7 +2910,30 @@ pub struct UpdateChannelRequest {
     pub retention_period: Option<RetentionPeriod>,
 }
 
+impl Validate for UpdateChannelRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "channel_name"),
+            self.channel_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "channel_name"),
+            &self.channel_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.channel_storage {
+            value.validate_at(&field_path(path, "channel_storage"), errors);
+        }
+        if let Some(ref value) = self.retention_period {
+            value.validate_at(&field_path(path, "retention_period"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDatasetRequest {
     /// <p>A list of "DatasetAction" objects.</p>
     #[serde(rename = "actions")]
@@ -1668,7 +2959,59 @@ pub struct UpdateDatasetRequest {
     pub versioning_configuration: Option<VersioningConfiguration>,
 }
 
+impl Validate for UpdateDatasetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "actions"),
+            self.actions.len(),
+            Some(1),
+            Some(1),
+        );
+        for (i, item) in self.actions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "actions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.content_delivery_rules {
+            errors.check_length(
+                &field_path(path, "content_delivery_rules"),
+                value.len(),
+                None,
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "content_delivery_rules"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "dataset_name"),
+            self.dataset_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "dataset_name"),
+            &self.dataset_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.retention_period {
+            value.validate_at(&field_path(path, "retention_period"), errors);
+        }
+        if let Some(ref value) = self.triggers {
+            errors.check_length(&field_path(path, "triggers"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "triggers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.versioning_configuration {
+            value.validate_at(&field_path(path, "versioning_configuration"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDatastoreRequest {
     /// <p>The name of the data store to be updated.</p>
     #[serde(rename = "datastoreName")]
@@ -1683,7 +3026,30 @@ pub struct UpdateDatastoreRequest {
     pub retention_period: Option<RetentionPeriod>,
 }
 
+impl Validate for UpdateDatastoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "datastore_name"),
+            self.datastore_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "datastore_name"),
+            &self.datastore_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+        if let Some(ref value) = self.datastore_storage {
+            value.validate_at(&field_path(path, "datastore_storage"), errors);
+        }
+        if let Some(ref value) = self.retention_period {
+            value.validate_at(&field_path(path, "retention_period"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePipelineRequest {
     /// <p>A list of "PipelineActivity" objects. Activities perform transformations on your messages, such as removing, renaming or adding message attributes; filtering messages based on attribute values; invoking your Lambda functions on messages for advanced processing; or performing mathematical transformations to normalize device data.</p> <p>The list can be 2-25 <b>PipelineActivity</b> objects and must contain both a <code>channel</code> and a <code>datastore</code> activity. Each entry in the list must contain only one activity, for example:</p> <p> <code>pipelineActivities = [ { "channel": { ... } }, { "lambda": { ... } }, ... ]</code> </p>
     #[serde(rename = "pipelineActivities")]
@@ -1693,6 +3059,32 @@ pub struct UpdatePipelineRequest {
     pub pipeline_name: String,
 }
 
+impl Validate for UpdatePipelineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pipeline_activities"),
+            self.pipeline_activities.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.pipeline_activities.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "pipeline_activities"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "pipeline_name"),
+            self.pipeline_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "pipeline_name"),
+            &self.pipeline_name,
+            "(^(?!_{2}))(^[a-zA-Z0-9_]+$)",
+        );
+    }
+}
+
 /// <p>An instance of a variable to be passed to the "containerAction" execution. Each variable must have a name and a value given by one of "stringValue", "datasetContentVersionValue", or "outputFileUriValue".</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Variable {
@@ -1717,6 +3109,31 @@ pub struct Variable {
     pub string_value: Option<String>,
 }
 
+impl Validate for Variable {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dataset_content_version_value {
+            value.validate_at(&field_path(path, "dataset_content_version_value"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.output_file_uri_value {
+            value.validate_at(&field_path(path, "output_file_uri_value"), errors);
+        }
+        if let Some(ref value) = self.string_value {
+            errors.check_length(
+                &field_path(path, "string_value"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Information about the versioning of data set contents.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VersioningConfiguration {
@@ -1730,6 +3147,19 @@ pub struct VersioningConfiguration {
     pub unlimited: Option<bool>,
 }
 
+impl Validate for VersioningConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_versions {
+            errors.check_range(
+                &field_path(path, "max_versions"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// Errors returned by BatchPutMessage
 #[derive(Debug, PartialEq)]
 pub enum BatchPutMessageError {
@@ -1764,7 +3194,12 @@ impl BatchPutMessageError {
                 "ThrottlingException" => {
                     return RusotoError::Service(BatchPutMessageError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1833,7 +3268,12 @@ impl CancelPipelineReprocessingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1895,7 +3335,12 @@ impl CreateChannelError {
                 "ThrottlingException" => {
                     return RusotoError::Service(CreateChannelError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1958,7 +3403,12 @@ impl CreateDatasetError {
                 "ThrottlingException" => {
                     return RusotoError::Service(CreateDatasetError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2022,7 +3472,12 @@ impl CreateDatasetContentError {
                 "ThrottlingException" => {
                     return RusotoError::Service(CreateDatasetContentError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2086,7 +3541,12 @@ impl CreateDatastoreError {
                 "ThrottlingException" => {
                     return RusotoError::Service(CreateDatastoreError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2151,7 +3611,12 @@ impl CreatePipelineError {
                 "ThrottlingException" => {
                     return RusotoError::Service(CreatePipelineError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2209,7 +3674,12 @@ impl DeleteChannelError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DeleteChannelError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2266,7 +3736,12 @@ impl DeleteDatasetError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DeleteDatasetError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2329,7 +3804,12 @@ impl DeleteDatasetContentError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DeleteDatasetContentError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2386,7 +3866,12 @@ impl DeleteDatastoreError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DeleteDatastoreError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2443,7 +3928,12 @@ impl DeletePipelineError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DeletePipelineError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2500,7 +3990,12 @@ impl DescribeChannelError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DescribeChannelError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2557,7 +4052,12 @@ impl DescribeDatasetError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DescribeDatasetError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2616,7 +4116,12 @@ impl DescribeDatastoreError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DescribeDatastoreError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2681,7 +4186,12 @@ impl DescribeLoggingOptionsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DescribeLoggingOptionsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2738,7 +4248,12 @@ impl DescribePipelineError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DescribePipelineError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2797,7 +4312,12 @@ impl GetDatasetContentError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetDatasetContentError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2849,7 +4369,12 @@ impl ListChannelsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListChannelsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2909,7 +4434,12 @@ impl ListDatasetContentsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListDatasetContentsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2961,7 +4491,12 @@ impl ListDatasetsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListDatasetsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3012,7 +4547,12 @@ impl ListDatastoresError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListDatastoresError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3063,7 +4603,12 @@ impl ListPipelinesError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListPipelinesError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3128,7 +4673,12 @@ impl ListTagsForResourceError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3183,7 +4733,12 @@ impl PutLoggingOptionsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(PutLoggingOptionsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3236,7 +4791,12 @@ impl RunPipelineActivityError {
                 "ThrottlingException" => {
                     return RusotoError::Service(RunPipelineActivityError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3294,7 +4854,12 @@ impl SampleChannelDataError {
                 "ThrottlingException" => {
                     return RusotoError::Service(SampleChannelDataError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3368,7 +4933,12 @@ impl StartPipelineReprocessingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3431,7 +5001,12 @@ impl TagResourceError {
                 "ThrottlingException" => {
                     return RusotoError::Service(TagResourceError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3494,7 +5069,12 @@ impl UntagResourceError {
                 "ThrottlingException" => {
                     return RusotoError::Service(UntagResourceError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3552,7 +5132,12 @@ impl UpdateChannelError {
                 "ThrottlingException" => {
                     return RusotoError::Service(UpdateChannelError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3609,7 +5194,12 @@ impl UpdateDatasetError {
                 "ThrottlingException" => {
                     return RusotoError::Service(UpdateDatasetError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3666,7 +5256,12 @@ impl UpdateDatastoreError {
                 "ThrottlingException" => {
                     return RusotoError::Service(UpdateDatastoreError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3728,7 +5323,12 @@ impl UpdatePipelineError {
                 "ThrottlingException" => {
                     return RusotoError::Service(UpdatePipelineError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3945,1191 +5545,3259 @@ pub trait IotAnalytics {
         input: UpdatePipelineRequest,
     ) -> RusotoFuture<(), UpdatePipelineError>;
 }
-/// A client for the AWS IoT Analytics API.
-#[derive(Clone)]
-pub struct IotAnalyticsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl IotAnalyticsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> IotAnalyticsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> IotAnalyticsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> IotAnalyticsClient {
-        IotAnalyticsClient { client, region }
-    }
-}
-
-impl IotAnalytics for IotAnalyticsClient {
-    /// <p>Sends messages to a channel.</p>
+const _: Option<&dyn IotAnalytics> = None;
+impl<'a, T: IotAnalytics + ?Sized> IotAnalytics for &'a T {
     fn batch_put_message(
         &self,
         input: BatchPutMessageRequest,
     ) -> RusotoFuture<BatchPutMessageResponse, BatchPutMessageError> {
-        let request_uri = "/messages/batch";
-
-        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchPutMessageResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchPutMessageError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_put_message(input)
     }
-
-    /// <p>Cancels the reprocessing of data through the pipeline.</p>
     fn cancel_pipeline_reprocessing(
         &self,
         input: CancelPipelineReprocessingRequest,
     ) -> RusotoFuture<CancelPipelineReprocessingResponse, CancelPipelineReprocessingError> {
-        let request_uri = format!(
-            "/pipelines/{pipeline_name}/reprocessing/{reprocessing_id}",
-            pipeline_name = input.pipeline_name,
-            reprocessing_id = input.reprocessing_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelPipelineReprocessingResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CancelPipelineReprocessingError::from_response(response))
-                }))
-            }
-        })
+        (**self).cancel_pipeline_reprocessing(input)
     }
-
-    /// <p>Creates a channel. A channel collects data from an MQTT topic and archives the raw, unprocessed messages before publishing the data to a pipeline.</p>
     fn create_channel(
         &self,
         input: CreateChannelRequest,
     ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
-        let request_uri = "/channels";
-
-        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateChannelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateChannelError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_channel(input)
     }
-
-    /// <p>Creates a data set. A data set stores data retrieved from a data store by applying a "queryAction" (a SQL query) or a "containerAction" (executing a containerized application). This operation creates the skeleton of a data set. The data set can be populated manually by calling "CreateDatasetContent" or automatically according to a "trigger" you specify.</p>
     fn create_dataset(
         &self,
         input: CreateDatasetRequest,
     ) -> RusotoFuture<CreateDatasetResponse, CreateDatasetError> {
-        let request_uri = "/datasets";
-
-        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDatasetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDatasetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_dataset(input)
     }
-
-    /// <p>Creates the content of a data set by applying a "queryAction" (a SQL query) or a "containerAction" (executing a containerized application).</p>
     fn create_dataset_content(
         &self,
         input: CreateDatasetContentRequest,
     ) -> RusotoFuture<CreateDatasetContentResponse, CreateDatasetContentError> {
-        let request_uri = format!(
-            "/datasets/{dataset_name}/content",
-            dataset_name = input.dataset_name
-        );
-
-        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDatasetContentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateDatasetContentError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_dataset_content(input)
     }
-
-    /// <p>Creates a data store, which is a repository for messages.</p>
     fn create_datastore(
         &self,
         input: CreateDatastoreRequest,
     ) -> RusotoFuture<CreateDatastoreResponse, CreateDatastoreError> {
-        let request_uri = "/datastores";
-
-        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDatastoreResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDatastoreError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_datastore(input)
     }
-
-    /// <p>Creates a pipeline. A pipeline consumes messages from one or more channels and allows you to process the messages before storing them in a data store. You must specify both a <code>channel</code> and a <code>datastore</code> activity and, optionally, as many as 23 additional activities in the <code>pipelineActivities</code> array.</p>
     fn create_pipeline(
         &self,
         input: CreatePipelineRequest,
     ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
-        let request_uri = "/pipelines";
-
-        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePipelineResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreatePipelineError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_pipeline(input)
     }
-
-    /// <p>Deletes the specified channel.</p>
     fn delete_channel(&self, input: DeleteChannelRequest) -> RusotoFuture<(), DeleteChannelError> {
-        let request_uri = format!(
-            "/channels/{channel_name}",
-            channel_name = input.channel_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteChannelError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_channel(input)
     }
-
-    /// <p>Deletes the specified data set.</p> <p>You do not have to delete the content of the data set before you perform this operation.</p>
     fn delete_dataset(&self, input: DeleteDatasetRequest) -> RusotoFuture<(), DeleteDatasetError> {
-        let request_uri = format!(
-            "/datasets/{dataset_name}",
-            dataset_name = input.dataset_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDatasetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_dataset(input)
     }
-
-    /// <p>Deletes the content of the specified data set.</p>
     fn delete_dataset_content(
         &self,
         input: DeleteDatasetContentRequest,
     ) -> RusotoFuture<(), DeleteDatasetContentError> {
-        let request_uri = format!(
-            "/datasets/{dataset_name}/content",
-            dataset_name = input.dataset_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteDatasetContentError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_dataset_content(input)
     }
-
-    /// <p>Deletes the specified data store.</p>
     fn delete_datastore(
         &self,
         input: DeleteDatastoreRequest,
     ) -> RusotoFuture<(), DeleteDatastoreError> {
-        let request_uri = format!(
-            "/datastores/{datastore_name}",
-            datastore_name = input.datastore_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDatastoreError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_datastore(input)
     }
-
-    /// <p>Deletes the specified pipeline.</p>
     fn delete_pipeline(
         &self,
         input: DeletePipelineRequest,
     ) -> RusotoFuture<(), DeletePipelineError> {
-        let request_uri = format!(
-            "/pipelines/{pipeline_name}",
-            pipeline_name = input.pipeline_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeletePipelineError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_pipeline(input)
     }
-
-    /// <p>Retrieves information about a channel.</p>
     fn describe_channel(
         &self,
         input: DescribeChannelRequest,
     ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
-        let request_uri = format!(
-            "/channels/{channel_name}",
-            channel_name = input.channel_name
-        );
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.include_statistics {
-            params.put("includeStatistics", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeChannelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeChannelError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_channel(input)
     }
-
-    /// <p>Retrieves information about a data set.</p>
     fn describe_dataset(
         &self,
         input: DescribeDatasetRequest,
     ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
-        let request_uri = format!(
-            "/datasets/{dataset_name}",
-            dataset_name = input.dataset_name
-        );
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDatasetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeDatasetError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_dataset(input)
     }
-
-    /// <p>Retrieves information about a data store.</p>
     fn describe_datastore(
         &self,
         input: DescribeDatastoreRequest,
     ) -> RusotoFuture<DescribeDatastoreResponse, DescribeDatastoreError> {
-        let request_uri = format!(
-            "/datastores/{datastore_name}",
-            datastore_name = input.datastore_name
-        );
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.include_statistics {
-            params.put("includeStatistics", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDatastoreResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeDatastoreError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_datastore(input)
     }
-
-    /// <p>Retrieves the current settings of the AWS IoT Analytics logging options.</p>
     fn describe_logging_options(
         &self,
     ) -> RusotoFuture<DescribeLoggingOptionsResponse, DescribeLoggingOptionsError> {
-        let request_uri = "/logging";
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeLoggingOptionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeLoggingOptionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_logging_options()
     }
-
-    /// <p>Retrieves information about a pipeline.</p>
     fn describe_pipeline(
         &self,
         input: DescribePipelineRequest,
     ) -> RusotoFuture<DescribePipelineResponse, DescribePipelineError> {
-        let request_uri = format!(
-            "/pipelines/{pipeline_name}",
-            pipeline_name = input.pipeline_name
-        );
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePipelineResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribePipelineError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_pipeline(input)
     }
-
-    /// <p>Retrieves the contents of a data set as pre-signed URIs.</p>
     fn get_dataset_content(
         &self,
         input: GetDatasetContentRequest,
     ) -> RusotoFuture<GetDatasetContentResponse, GetDatasetContentError> {
-        let request_uri = format!(
-            "/datasets/{dataset_name}/content",
-            dataset_name = input.dataset_name
-        );
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDatasetContentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDatasetContentError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_dataset_content(input)
     }
-
-    /// <p>Retrieves a list of channels.</p>
     fn list_channels(
         &self,
         input: ListChannelsRequest,
     ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
-        let request_uri = "/channels";
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListChannelsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListChannelsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_channels(input)
     }
-
-    /// <p>Lists information about data set contents that have been created.</p>
     fn list_dataset_contents(
         &self,
         input: ListDatasetContentsRequest,
     ) -> RusotoFuture<ListDatasetContentsResponse, ListDatasetContentsError> {
-        let request_uri = format!(
-            "/datasets/{dataset_name}/contents",
-            dataset_name = input.dataset_name
-        );
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        if let Some(ref x) = input.scheduled_before {
-            params.put("scheduledBefore", x);
-        }
-        if let Some(ref x) = input.scheduled_on_or_after {
-            params.put("scheduledOnOrAfter", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDatasetContentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListDatasetContentsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_dataset_contents(input)
     }
-
-    /// <p>Retrieves information about data sets.</p>
     fn list_datasets(
         &self,
         input: ListDatasetsRequest,
     ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
-        let request_uri = "/datasets";
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDatasetsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDatasetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_datasets(input)
     }
-
-    /// <p>Retrieves a list of data stores.</p>
     fn list_datastores(
         &self,
         input: ListDatastoresRequest,
     ) -> RusotoFuture<ListDatastoresResponse, ListDatastoresError> {
-        let request_uri = "/datastores";
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDatastoresResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDatastoresError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_datastores(input)
     }
-
-    /// <p>Retrieves a list of pipelines.</p>
     fn list_pipelines(
         &self,
         input: ListPipelinesRequest,
     ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
-        let request_uri = "/pipelines";
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPipelinesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListPipelinesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_pipelines(input)
     }
-
-    /// <p>Lists the tags (metadata) which you have assigned to the resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let request_uri = "/tags";
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("resourceArn", &input.resource_arn);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Sets or updates the AWS IoT Analytics logging options.</p> <p>Note that if you update the value of any <code>loggingOptions</code> field, it takes up to one minute for the change to take effect. Also, if you change the policy attached to the role you specified in the roleArn field (for example, to correct an invalid policy) it takes up to 5 minutes for that change to take effect. </p>
     fn put_logging_options(
         &self,
         input: PutLoggingOptionsRequest,
     ) -> RusotoFuture<(), PutLoggingOptionsError> {
-        let request_uri = "/logging";
-
-        let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutLoggingOptionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_logging_options(input)
     }
-
-    /// <p>Simulates the results of running a pipeline activity on a message payload.</p>
     fn run_pipeline_activity(
         &self,
         input: RunPipelineActivityRequest,
     ) -> RusotoFuture<RunPipelineActivityResponse, RunPipelineActivityError> {
-        let request_uri = "/pipelineactivities/run";
-
-        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RunPipelineActivityResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RunPipelineActivityError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).run_pipeline_activity(input)
     }
-
-    /// <p>Retrieves a sample of messages from the specified channel ingested during the specified timeframe. Up to 10 messages can be retrieved.</p>
     fn sample_channel_data(
         &self,
         input: SampleChannelDataRequest,
     ) -> RusotoFuture<SampleChannelDataResponse, SampleChannelDataError> {
-        let request_uri = format!(
-            "/channels/{channel_name}/sample",
-            channel_name = input.channel_name
-        );
-
-        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.end_time {
-            params.put("endTime", x);
-        }
-        if let Some(ref x) = input.max_messages {
-            params.put("maxMessages", x);
-        }
-        if let Some(ref x) = input.start_time {
-            params.put("startTime", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SampleChannelDataResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SampleChannelDataError::from_response(response))),
-                )
-            }
-        })
+        (**self).sample_channel_data(input)
     }
-
-    /// <p>Starts the reprocessing of raw message data through the pipeline.</p>
     fn start_pipeline_reprocessing(
         &self,
         input: StartPipelineReprocessingRequest,
     ) -> RusotoFuture<StartPipelineReprocessingResponse, StartPipelineReprocessingError> {
-        let request_uri = format!(
-            "/pipelines/{pipeline_name}/reprocessing",
-            pipeline_name = input.pipeline_name
-        );
-
-        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
+        (**self).start_pipeline_reprocessing(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_channel(&self, input: UpdateChannelRequest) -> RusotoFuture<(), UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_dataset(&self, input: UpdateDatasetRequest) -> RusotoFuture<(), UpdateDatasetError> {
+        (**self).update_dataset(input)
+    }
+    fn update_datastore(
+        &self,
+        input: UpdateDatastoreRequest,
+    ) -> RusotoFuture<(), UpdateDatastoreError> {
+        (**self).update_datastore(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> RusotoFuture<(), UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+}
+impl<T: IotAnalytics + ?Sized> IotAnalytics for Box<T> {
+    fn batch_put_message(
+        &self,
+        input: BatchPutMessageRequest,
+    ) -> RusotoFuture<BatchPutMessageResponse, BatchPutMessageError> {
+        (**self).batch_put_message(input)
+    }
+    fn cancel_pipeline_reprocessing(
+        &self,
+        input: CancelPipelineReprocessingRequest,
+    ) -> RusotoFuture<CancelPipelineReprocessingResponse, CancelPipelineReprocessingError> {
+        (**self).cancel_pipeline_reprocessing(input)
+    }
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        (**self).create_channel(input)
+    }
+    fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> RusotoFuture<CreateDatasetResponse, CreateDatasetError> {
+        (**self).create_dataset(input)
+    }
+    fn create_dataset_content(
+        &self,
+        input: CreateDatasetContentRequest,
+    ) -> RusotoFuture<CreateDatasetContentResponse, CreateDatasetContentError> {
+        (**self).create_dataset_content(input)
+    }
+    fn create_datastore(
+        &self,
+        input: CreateDatastoreRequest,
+    ) -> RusotoFuture<CreateDatastoreResponse, CreateDatastoreError> {
+        (**self).create_datastore(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn delete_channel(&self, input: DeleteChannelRequest) -> RusotoFuture<(), DeleteChannelError> {
+        (**self).delete_channel(input)
+    }
+    fn delete_dataset(&self, input: DeleteDatasetRequest) -> RusotoFuture<(), DeleteDatasetError> {
+        (**self).delete_dataset(input)
+    }
+    fn delete_dataset_content(
+        &self,
+        input: DeleteDatasetContentRequest,
+    ) -> RusotoFuture<(), DeleteDatasetContentError> {
+        (**self).delete_dataset_content(input)
+    }
+    fn delete_datastore(
+        &self,
+        input: DeleteDatastoreRequest,
+    ) -> RusotoFuture<(), DeleteDatastoreError> {
+        (**self).delete_datastore(input)
+    }
+    fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> RusotoFuture<(), DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        (**self).describe_channel(input)
+    }
+    fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
+        (**self).describe_dataset(input)
+    }
+    fn describe_datastore(
+        &self,
+        input: DescribeDatastoreRequest,
+    ) -> RusotoFuture<DescribeDatastoreResponse, DescribeDatastoreError> {
+        (**self).describe_datastore(input)
+    }
+    fn describe_logging_options(
+        &self,
+    ) -> RusotoFuture<DescribeLoggingOptionsResponse, DescribeLoggingOptionsError> {
+        (**self).describe_logging_options()
+    }
+    fn describe_pipeline(
+        &self,
+        input: DescribePipelineRequest,
+    ) -> RusotoFuture<DescribePipelineResponse, DescribePipelineError> {
+        (**self).describe_pipeline(input)
+    }
+    fn get_dataset_content(
+        &self,
+        input: GetDatasetContentRequest,
+    ) -> RusotoFuture<GetDatasetContentResponse, GetDatasetContentError> {
+        (**self).get_dataset_content(input)
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        (**self).list_channels(input)
+    }
+    fn list_dataset_contents(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> RusotoFuture<ListDatasetContentsResponse, ListDatasetContentsError> {
+        (**self).list_dataset_contents(input)
+    }
+    fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        (**self).list_datasets(input)
+    }
+    fn list_datastores(
+        &self,
+        input: ListDatastoresRequest,
+    ) -> RusotoFuture<ListDatastoresResponse, ListDatastoresError> {
+        (**self).list_datastores(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_logging_options(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> RusotoFuture<(), PutLoggingOptionsError> {
+        (**self).put_logging_options(input)
+    }
+    fn run_pipeline_activity(
+        &self,
+        input: RunPipelineActivityRequest,
+    ) -> RusotoFuture<RunPipelineActivityResponse, RunPipelineActivityError> {
+        (**self).run_pipeline_activity(input)
+    }
+    fn sample_channel_data(
+        &self,
+        input: SampleChannelDataRequest,
+    ) -> RusotoFuture<SampleChannelDataResponse, SampleChannelDataError> {
+        (**self).sample_channel_data(input)
+    }
+    fn start_pipeline_reprocessing(
+        &self,
+        input: StartPipelineReprocessingRequest,
+    ) -> RusotoFuture<StartPipelineReprocessingResponse, StartPipelineReprocessingError> {
+        (**self).start_pipeline_reprocessing(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_channel(&self, input: UpdateChannelRequest) -> RusotoFuture<(), UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_dataset(&self, input: UpdateDatasetRequest) -> RusotoFuture<(), UpdateDatasetError> {
+        (**self).update_dataset(input)
+    }
+    fn update_datastore(
+        &self,
+        input: UpdateDatastoreRequest,
+    ) -> RusotoFuture<(), UpdateDatastoreError> {
+        (**self).update_datastore(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> RusotoFuture<(), UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+}
+impl<T: IotAnalytics + ?Sized> IotAnalytics for ::std::sync::Arc<T> {
+    fn batch_put_message(
+        &self,
+        input: BatchPutMessageRequest,
+    ) -> RusotoFuture<BatchPutMessageResponse, BatchPutMessageError> {
+        (**self).batch_put_message(input)
+    }
+    fn cancel_pipeline_reprocessing(
+        &self,
+        input: CancelPipelineReprocessingRequest,
+    ) -> RusotoFuture<CancelPipelineReprocessingResponse, CancelPipelineReprocessingError> {
+        (**self).cancel_pipeline_reprocessing(input)
+    }
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        (**self).create_channel(input)
+    }
+    fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> RusotoFuture<CreateDatasetResponse, CreateDatasetError> {
+        (**self).create_dataset(input)
+    }
+    fn create_dataset_content(
+        &self,
+        input: CreateDatasetContentRequest,
+    ) -> RusotoFuture<CreateDatasetContentResponse, CreateDatasetContentError> {
+        (**self).create_dataset_content(input)
+    }
+    fn create_datastore(
+        &self,
+        input: CreateDatastoreRequest,
+    ) -> RusotoFuture<CreateDatastoreResponse, CreateDatastoreError> {
+        (**self).create_datastore(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn delete_channel(&self, input: DeleteChannelRequest) -> RusotoFuture<(), DeleteChannelError> {
+        (**self).delete_channel(input)
+    }
+    fn delete_dataset(&self, input: DeleteDatasetRequest) -> RusotoFuture<(), DeleteDatasetError> {
+        (**self).delete_dataset(input)
+    }
+    fn delete_dataset_content(
+        &self,
+        input: DeleteDatasetContentRequest,
+    ) -> RusotoFuture<(), DeleteDatasetContentError> {
+        (**self).delete_dataset_content(input)
+    }
+    fn delete_datastore(
+        &self,
+        input: DeleteDatastoreRequest,
+    ) -> RusotoFuture<(), DeleteDatastoreError> {
+        (**self).delete_datastore(input)
+    }
+    fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> RusotoFuture<(), DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        (**self).describe_channel(input)
+    }
+    fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
+        (**self).describe_dataset(input)
+    }
+    fn describe_datastore(
+        &self,
+        input: DescribeDatastoreRequest,
+    ) -> RusotoFuture<DescribeDatastoreResponse, DescribeDatastoreError> {
+        (**self).describe_datastore(input)
+    }
+    fn describe_logging_options(
+        &self,
+    ) -> RusotoFuture<DescribeLoggingOptionsResponse, DescribeLoggingOptionsError> {
+        (**self).describe_logging_options()
+    }
+    fn describe_pipeline(
+        &self,
+        input: DescribePipelineRequest,
+    ) -> RusotoFuture<DescribePipelineResponse, DescribePipelineError> {
+        (**self).describe_pipeline(input)
+    }
+    fn get_dataset_content(
+        &self,
+        input: GetDatasetContentRequest,
+    ) -> RusotoFuture<GetDatasetContentResponse, GetDatasetContentError> {
+        (**self).get_dataset_content(input)
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        (**self).list_channels(input)
+    }
+    fn list_dataset_contents(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> RusotoFuture<ListDatasetContentsResponse, ListDatasetContentsError> {
+        (**self).list_dataset_contents(input)
+    }
+    fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        (**self).list_datasets(input)
+    }
+    fn list_datastores(
+        &self,
+        input: ListDatastoresRequest,
+    ) -> RusotoFuture<ListDatastoresResponse, ListDatastoresError> {
+        (**self).list_datastores(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_logging_options(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> RusotoFuture<(), PutLoggingOptionsError> {
+        (**self).put_logging_options(input)
+    }
+    fn run_pipeline_activity(
+        &self,
+        input: RunPipelineActivityRequest,
+    ) -> RusotoFuture<RunPipelineActivityResponse, RunPipelineActivityError> {
+        (**self).run_pipeline_activity(input)
+    }
+    fn sample_channel_data(
+        &self,
+        input: SampleChannelDataRequest,
+    ) -> RusotoFuture<SampleChannelDataResponse, SampleChannelDataError> {
+        (**self).sample_channel_data(input)
+    }
+    fn start_pipeline_reprocessing(
+        &self,
+        input: StartPipelineReprocessingRequest,
+    ) -> RusotoFuture<StartPipelineReprocessingResponse, StartPipelineReprocessingError> {
+        (**self).start_pipeline_reprocessing(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_channel(&self, input: UpdateChannelRequest) -> RusotoFuture<(), UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_dataset(&self, input: UpdateDatasetRequest) -> RusotoFuture<(), UpdateDatasetError> {
+        (**self).update_dataset(input)
+    }
+    fn update_datastore(
+        &self,
+        input: UpdateDatastoreRequest,
+    ) -> RusotoFuture<(), UpdateDatastoreError> {
+        (**self).update_datastore(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> RusotoFuture<(), UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+}
+/// A client for the AWS IoT Analytics API.
+#[derive(Clone)]
+pub struct IotAnalyticsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl IotAnalyticsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IotAnalyticsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotAnalyticsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> IotAnalyticsClient {
+        IotAnalyticsClient { client, region }
+    }
+}
+
+impl IotAnalytics for IotAnalyticsClient {
+    /// <p>Sends messages to a channel.</p>
+    fn batch_put_message(
+        &self,
+        input: BatchPutMessageRequest,
+    ) -> RusotoFuture<BatchPutMessageResponse, BatchPutMessageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/messages/batch";
+
+        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("BatchPutMessage");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchPutMessage")
+                        .deserialize::<BatchPutMessageResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchPutMessageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Cancels the reprocessing of data through the pipeline.</p>
+    fn cancel_pipeline_reprocessing(
+        &self,
+        input: CancelPipelineReprocessingRequest,
+    ) -> RusotoFuture<CancelPipelineReprocessingResponse, CancelPipelineReprocessingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/pipelines/{pipeline_name}/reprocessing/{reprocessing_id}",
+            pipeline_name = input.pipeline_name,
+            reprocessing_id = input.reprocessing_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CancelPipelineReprocessing");
+        request.add_resource_id("pipelineName", &input.pipeline_name.to_string());
+        request.add_resource_id("reprocessingId", &input.reprocessing_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CancelPipelineReprocessing")
+                        .deserialize::<CancelPipelineReprocessingResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CancelPipelineReprocessingError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a channel. A channel collects data from an MQTT topic and archives the raw, unprocessed messages before publishing the data to a pipeline.</p>
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/channels";
+
+        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateChannel")
+                        .deserialize::<CreateChannelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a data set. A data set stores data retrieved from a data store by applying a "queryAction" (a SQL query) or a "containerAction" (executing a containerized application). This operation creates the skeleton of a data set. The data set can be populated manually by calling "CreateDatasetContent" or automatically according to a "trigger" you specify.</p>
+    fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> RusotoFuture<CreateDatasetResponse, CreateDatasetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/datasets";
+
+        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateDataset");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDataset")
+                        .deserialize::<CreateDatasetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDatasetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates the content of a data set by applying a "queryAction" (a SQL query) or a "containerAction" (executing a containerized application).</p>
+    fn create_dataset_content(
+        &self,
+        input: CreateDatasetContentRequest,
+    ) -> RusotoFuture<CreateDatasetContentResponse, CreateDatasetContentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/datasets/{dataset_name}/content",
+            dataset_name = input.dataset_name
+        );
+
+        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateDatasetContent");
+        request.add_resource_id("datasetName", &input.dataset_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDatasetContent")
+                        .deserialize::<CreateDatasetContentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateDatasetContentError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a data store, which is a repository for messages.</p>
+    fn create_datastore(
+        &self,
+        input: CreateDatastoreRequest,
+    ) -> RusotoFuture<CreateDatastoreResponse, CreateDatastoreError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/datastores";
+
+        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateDatastore");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateDatastore")
+                        .deserialize::<CreateDatastoreResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDatastoreError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a pipeline. A pipeline consumes messages from one or more channels and allows you to process the messages before storing them in a data store. You must specify both a <code>channel</code> and a <code>datastore</code> activity and, optionally, as many as 23 additional activities in the <code>pipelineActivities</code> array.</p>
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/pipelines";
+
+        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreatePipeline");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreatePipeline")
+                        .deserialize::<CreatePipelineResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreatePipelineError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified channel.</p>
+    fn delete_channel(&self, input: DeleteChannelRequest) -> RusotoFuture<(), DeleteChannelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/channels/{channel_name}",
+            channel_name = input.channel_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
+        request.add_resource_id("channelName", &input.channel_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified data set.</p> <p>You do not have to delete the content of the data set before you perform this operation.</p>
+    fn delete_dataset(&self, input: DeleteDatasetRequest) -> RusotoFuture<(), DeleteDatasetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/datasets/{dataset_name}",
+            dataset_name = input.dataset_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteDataset");
+        request.add_resource_id("datasetName", &input.dataset_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDatasetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the content of the specified data set.</p>
+    fn delete_dataset_content(
+        &self,
+        input: DeleteDatasetContentRequest,
+    ) -> RusotoFuture<(), DeleteDatasetContentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/datasets/{dataset_name}/content",
+            dataset_name = input.dataset_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteDatasetContent");
+        request.add_resource_id("datasetName", &input.dataset_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteDatasetContentError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified data store.</p>
+    fn delete_datastore(
+        &self,
+        input: DeleteDatastoreRequest,
+    ) -> RusotoFuture<(), DeleteDatastoreError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/datastores/{datastore_name}",
+            datastore_name = input.datastore_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteDatastore");
+        request.add_resource_id("datastoreName", &input.datastore_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDatastoreError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified pipeline.</p>
+    fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> RusotoFuture<(), DeletePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/pipelines/{pipeline_name}",
+            pipeline_name = input.pipeline_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeletePipeline");
+        request.add_resource_id("pipelineName", &input.pipeline_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeletePipelineError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a channel.</p>
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/channels/{channel_name}",
+            channel_name = input.channel_name
+        );
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
+        request.add_resource_id("channelName", &input.channel_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.include_statistics {
+            params.put("includeStatistics", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeChannel")
+                        .deserialize::<DescribeChannelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a data set.</p>
+    fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/datasets/{dataset_name}",
+            dataset_name = input.dataset_name
+        );
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeDataset");
+        request.add_resource_id("datasetName", &input.dataset_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeDataset")
+                        .deserialize::<DescribeDatasetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeDatasetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a data store.</p>
+    fn describe_datastore(
+        &self,
+        input: DescribeDatastoreRequest,
+    ) -> RusotoFuture<DescribeDatastoreResponse, DescribeDatastoreError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/datastores/{datastore_name}",
+            datastore_name = input.datastore_name
+        );
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeDatastore");
+        request.add_resource_id("datastoreName", &input.datastore_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.include_statistics {
+            params.put("includeStatistics", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeDatastore")
+                        .deserialize::<DescribeDatastoreResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeDatastoreError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the current settings of the AWS IoT Analytics logging options.</p>
+    fn describe_logging_options(
+        &self,
+    ) -> RusotoFuture<DescribeLoggingOptionsResponse, DescribeLoggingOptionsError> {
+        let request_uri = "/logging";
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeLoggingOptions");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeLoggingOptions")
+                        .deserialize::<DescribeLoggingOptionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeLoggingOptionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a pipeline.</p>
+    fn describe_pipeline(
+        &self,
+        input: DescribePipelineRequest,
+    ) -> RusotoFuture<DescribePipelineResponse, DescribePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/pipelines/{pipeline_name}",
+            pipeline_name = input.pipeline_name
+        );
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribePipeline");
+        request.add_resource_id("pipelineName", &input.pipeline_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribePipeline")
+                        .deserialize::<DescribePipelineResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribePipelineError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the contents of a data set as pre-signed URIs.</p>
+    fn get_dataset_content(
+        &self,
+        input: GetDatasetContentRequest,
+    ) -> RusotoFuture<GetDatasetContentResponse, GetDatasetContentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/datasets/{dataset_name}/content",
+            dataset_name = input.dataset_name
+        );
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("GetDatasetContent");
+        request.add_resource_id("datasetName", &input.dataset_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDatasetContent")
+                        .deserialize::<GetDatasetContentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDatasetContentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of channels.</p>
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/channels";
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListChannels");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListChannels")
+                        .deserialize::<ListChannelsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListChannelsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists information about data set contents that have been created.</p>
+    fn list_dataset_contents(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> RusotoFuture<ListDatasetContentsResponse, ListDatasetContentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/datasets/{dataset_name}/contents",
+            dataset_name = input.dataset_name
+        );
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListDatasetContents");
+        request.add_resource_id("datasetName", &input.dataset_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        if let Some(ref x) = input.scheduled_before {
+            params.put("scheduledBefore", x);
+        }
+        if let Some(ref x) = input.scheduled_on_or_after {
+            params.put("scheduledOnOrAfter", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDatasetContents")
+                        .deserialize::<ListDatasetContentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListDatasetContentsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about data sets.</p>
+    fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/datasets";
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListDatasets");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDatasets")
+                        .deserialize::<ListDatasetsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDatasetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of data stores.</p>
+    fn list_datastores(
+        &self,
+        input: ListDatastoresRequest,
+    ) -> RusotoFuture<ListDatastoresResponse, ListDatastoresError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/datastores";
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListDatastores");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDatastores")
+                        .deserialize::<ListDatastoresResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDatastoresError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of pipelines.</p>
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/pipelines";
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListPipelines");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListPipelines")
+                        .deserialize::<ListPipelinesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListPipelinesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the tags (metadata) which you have assigned to the resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/tags";
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("resourceArn", &input.resource_arn);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
+                        .deserialize::<ListTagsForResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets or updates the AWS IoT Analytics logging options.</p> <p>Note that if you update the value of any <code>loggingOptions</code> field, it takes up to one minute for the change to take effect. Also, if you change the policy attached to the role you specified in the roleArn field (for example, to correct an invalid policy) it takes up to 5 minutes for that change to take effect. </p>
+    fn put_logging_options(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> RusotoFuture<(), PutLoggingOptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/logging";
+
+        let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("PutLoggingOptions");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutLoggingOptionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Simulates the results of running a pipeline activity on a message payload.</p>
+    fn run_pipeline_activity(
+        &self,
+        input: RunPipelineActivityRequest,
+    ) -> RusotoFuture<RunPipelineActivityResponse, RunPipelineActivityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/pipelineactivities/run";
+
+        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("RunPipelineActivity");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RunPipelineActivity")
+                        .deserialize::<RunPipelineActivityResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RunPipelineActivityError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a sample of messages from the specified channel ingested during the specified timeframe. Up to 10 messages can be retrieved.</p>
+    fn sample_channel_data(
+        &self,
+        input: SampleChannelDataRequest,
+    ) -> RusotoFuture<SampleChannelDataResponse, SampleChannelDataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/channels/{channel_name}/sample",
+            channel_name = input.channel_name
+        );
+
+        let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("SampleChannelData");
+        request.add_resource_id("channelName", &input.channel_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.end_time {
+            params.put("endTime", x);
+        }
+        if let Some(ref x) = input.max_messages {
+            params.put("maxMessages", x);
+        }
+        if let Some(ref x) = input.start_time {
+            params.put("startTime", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("SampleChannelData")
+                        .deserialize::<SampleChannelDataResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SampleChannelDataError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts the reprocessing of raw message data through the pipeline.</p>
+    fn start_pipeline_reprocessing(
+        &self,
+        input: StartPipelineReprocessingRequest,
+    ) -> RusotoFuture<StartPipelineReprocessingResponse, StartPipelineReprocessingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/pipelines/{pipeline_name}/reprocessing",
+            pipeline_name = input.pipeline_name
+        );
+
+        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("StartPipelineReprocessing");
+        request.add_resource_id("pipelineName", &input.pipeline_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartPipelineReprocessing")
+                        .deserialize::<StartPipelineReprocessingResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartPipelineReprocessingError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata which can be used to manage a resource.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/tags";
+
+        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        params.put("resourceArn", &input.resource_arn);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
+                        .deserialize::<TagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the given tags (metadata) from the resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/tags";
+
+        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("resourceArn", &input.resource_arn);
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
+                        .deserialize::<UntagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the settings of a channel.</p>
+    fn update_channel(&self, input: UpdateChannelRequest) -> RusotoFuture<(), UpdateChannelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/channels/{channel_name}",
+            channel_name = input.channel_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
+        request.add_resource_id("channelName", &input.channel_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the settings of a data set.</p>
+    fn update_dataset(&self, input: UpdateDatasetRequest) -> RusotoFuture<(), UpdateDatasetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/datasets/{dataset_name}",
+            dataset_name = input.dataset_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdateDataset");
+        request.add_resource_id("datasetName", &input.dataset_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDatasetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the settings of a data store.</p>
+    fn update_datastore(
+        &self,
+        input: UpdateDatastoreRequest,
+    ) -> RusotoFuture<(), UpdateDatastoreError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/datastores/{datastore_name}",
+            datastore_name = input.datastore_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdateDatastore");
+        request.add_resource_id("datastoreName", &input.datastore_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartPipelineReprocessingResponse, _>()?;
+                    let result = ::std::mem::drop(response);
 
                     Ok(result)
                 }))
             } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDatastoreError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the settings of a pipeline. You must specify both a <code>channel</code> and a <code>datastore</code> activity and, optionally, as many as 23 additional activities in the <code>pipelineActivities</code> array.</p>
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> RusotoFuture<(), UpdatePipelineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/pipelines/{pipeline_name}",
+            pipeline_name = input.pipeline_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdatePipeline");
+        request.add_resource_id("pipelineName", &input.pipeline_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartPipelineReprocessingError::from_response(response))
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
                 }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdatePipelineError::from_response(response))),
+                )
             }
         })
     }
-
-    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata which can be used to manage a resource.</p>
+}
+
+/// A mock of the AWS IoT Analytics API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockIotAnalytics {
+    batch_put_message: Option<
+        Box<
+            dyn Fn(
+                    BatchPutMessageRequest,
+                ) -> ::std::result::Result<
+                    BatchPutMessageResponse,
+                    RusotoError<BatchPutMessageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    cancel_pipeline_reprocessing: Option<
+        Box<
+            dyn Fn(
+                    CancelPipelineReprocessingRequest,
+                ) -> ::std::result::Result<
+                    CancelPipelineReprocessingResponse,
+                    RusotoError<CancelPipelineReprocessingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_channel: Option<
+        Box<
+            dyn Fn(
+                    CreateChannelRequest,
+                )
+                    -> ::std::result::Result<CreateChannelResponse, RusotoError<CreateChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_dataset: Option<
+        Box<
+            dyn Fn(
+                    CreateDatasetRequest,
+                )
+                    -> ::std::result::Result<CreateDatasetResponse, RusotoError<CreateDatasetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_dataset_content: Option<
+        Box<
+            dyn Fn(
+                    CreateDatasetContentRequest,
+                ) -> ::std::result::Result<
+                    CreateDatasetContentResponse,
+                    RusotoError<CreateDatasetContentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_datastore: Option<
+        Box<
+            dyn Fn(
+                    CreateDatastoreRequest,
+                ) -> ::std::result::Result<
+                    CreateDatastoreResponse,
+                    RusotoError<CreateDatastoreError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_pipeline: Option<
+        Box<
+            dyn Fn(
+                    CreatePipelineRequest,
+                ) -> ::std::result::Result<
+                    CreatePipelineResponse,
+                    RusotoError<CreatePipelineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_channel: Option<
+        Box<
+            dyn Fn(
+                    DeleteChannelRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_dataset: Option<
+        Box<
+            dyn Fn(
+                    DeleteDatasetRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteDatasetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_dataset_content: Option<
+        Box<
+            dyn Fn(
+                    DeleteDatasetContentRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteDatasetContentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_datastore: Option<
+        Box<
+            dyn Fn(
+                    DeleteDatastoreRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteDatastoreError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_pipeline: Option<
+        Box<
+            dyn Fn(
+                    DeletePipelineRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeletePipelineError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_channel: Option<
+        Box<
+            dyn Fn(
+                    DescribeChannelRequest,
+                ) -> ::std::result::Result<
+                    DescribeChannelResponse,
+                    RusotoError<DescribeChannelError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_dataset: Option<
+        Box<
+            dyn Fn(
+                    DescribeDatasetRequest,
+                ) -> ::std::result::Result<
+                    DescribeDatasetResponse,
+                    RusotoError<DescribeDatasetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_datastore: Option<
+        Box<
+            dyn Fn(
+                    DescribeDatastoreRequest,
+                ) -> ::std::result::Result<
+                    DescribeDatastoreResponse,
+                    RusotoError<DescribeDatastoreError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_logging_options: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeLoggingOptionsResponse,
+                    RusotoError<DescribeLoggingOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_pipeline: Option<
+        Box<
+            dyn Fn(
+                    DescribePipelineRequest,
+                ) -> ::std::result::Result<
+                    DescribePipelineResponse,
+                    RusotoError<DescribePipelineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_dataset_content: Option<
+        Box<
+            dyn Fn(
+                    GetDatasetContentRequest,
+                ) -> ::std::result::Result<
+                    GetDatasetContentResponse,
+                    RusotoError<GetDatasetContentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_channels: Option<
+        Box<
+            dyn Fn(
+                    ListChannelsRequest,
+                )
+                    -> ::std::result::Result<ListChannelsResponse, RusotoError<ListChannelsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_dataset_contents: Option<
+        Box<
+            dyn Fn(
+                    ListDatasetContentsRequest,
+                ) -> ::std::result::Result<
+                    ListDatasetContentsResponse,
+                    RusotoError<ListDatasetContentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_datasets: Option<
+        Box<
+            dyn Fn(
+                    ListDatasetsRequest,
+                )
+                    -> ::std::result::Result<ListDatasetsResponse, RusotoError<ListDatasetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_datastores: Option<
+        Box<
+            dyn Fn(
+                    ListDatastoresRequest,
+                ) -> ::std::result::Result<
+                    ListDatastoresResponse,
+                    RusotoError<ListDatastoresError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_pipelines: Option<
+        Box<
+            dyn Fn(
+                    ListPipelinesRequest,
+                )
+                    -> ::std::result::Result<ListPipelinesResponse, RusotoError<ListPipelinesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_logging_options: Option<
+        Box<
+            dyn Fn(
+                    PutLoggingOptionsRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutLoggingOptionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    run_pipeline_activity: Option<
+        Box<
+            dyn Fn(
+                    RunPipelineActivityRequest,
+                ) -> ::std::result::Result<
+                    RunPipelineActivityResponse,
+                    RusotoError<RunPipelineActivityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    sample_channel_data: Option<
+        Box<
+            dyn Fn(
+                    SampleChannelDataRequest,
+                ) -> ::std::result::Result<
+                    SampleChannelDataResponse,
+                    RusotoError<SampleChannelDataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_pipeline_reprocessing: Option<
+        Box<
+            dyn Fn(
+                    StartPipelineReprocessingRequest,
+                ) -> ::std::result::Result<
+                    StartPipelineReprocessingResponse,
+                    RusotoError<StartPipelineReprocessingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_channel: Option<
+        Box<
+            dyn Fn(
+                    UpdateChannelRequest,
+                ) -> ::std::result::Result<(), RusotoError<UpdateChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_dataset: Option<
+        Box<
+            dyn Fn(
+                    UpdateDatasetRequest,
+                ) -> ::std::result::Result<(), RusotoError<UpdateDatasetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_datastore: Option<
+        Box<
+            dyn Fn(
+                    UpdateDatastoreRequest,
+                ) -> ::std::result::Result<(), RusotoError<UpdateDatastoreError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_pipeline: Option<
+        Box<
+            dyn Fn(
+                    UpdatePipelineRequest,
+                ) -> ::std::result::Result<(), RusotoError<UpdatePipelineError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockIotAnalytics {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockIotAnalytics {
+        Default::default()
+    }
+
+    /// Answers `batch_put_message` with what `handler` returns for its input.
+    pub fn with_batch_put_message<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                BatchPutMessageRequest,
+            )
+                -> ::std::result::Result<BatchPutMessageResponse, RusotoError<BatchPutMessageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_put_message = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_pipeline_reprocessing` with what `handler` returns for its input.
+    pub fn with_cancel_pipeline_reprocessing<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                CancelPipelineReprocessingRequest,
+            ) -> ::std::result::Result<
+                CancelPipelineReprocessingResponse,
+                RusotoError<CancelPipelineReprocessingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_pipeline_reprocessing = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_channel` with what `handler` returns for its input.
+    pub fn with_create_channel<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                CreateChannelRequest,
+            )
+                -> ::std::result::Result<CreateChannelResponse, RusotoError<CreateChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_dataset` with what `handler` returns for its input.
+    pub fn with_create_dataset<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                CreateDatasetRequest,
+            )
+                -> ::std::result::Result<CreateDatasetResponse, RusotoError<CreateDatasetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_dataset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_dataset_content` with what `handler` returns for its input.
+    pub fn with_create_dataset_content<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                CreateDatasetContentRequest,
+            ) -> ::std::result::Result<
+                CreateDatasetContentResponse,
+                RusotoError<CreateDatasetContentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_dataset_content = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_datastore` with what `handler` returns for its input.
+    pub fn with_create_datastore<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                CreateDatastoreRequest,
+            )
+                -> ::std::result::Result<CreateDatastoreResponse, RusotoError<CreateDatastoreError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_datastore = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_pipeline` with what `handler` returns for its input.
+    pub fn with_create_pipeline<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                CreatePipelineRequest,
+            )
+                -> ::std::result::Result<CreatePipelineResponse, RusotoError<CreatePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_channel` with what `handler` returns for its input.
+    pub fn with_delete_channel<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(DeleteChannelRequest) -> ::std::result::Result<(), RusotoError<DeleteChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_dataset` with what `handler` returns for its input.
+    pub fn with_delete_dataset<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(DeleteDatasetRequest) -> ::std::result::Result<(), RusotoError<DeleteDatasetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_dataset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_dataset_content` with what `handler` returns for its input.
+    pub fn with_delete_dataset_content<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                DeleteDatasetContentRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDatasetContentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_dataset_content = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_datastore` with what `handler` returns for its input.
+    pub fn with_delete_datastore<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                DeleteDatastoreRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDatastoreError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_datastore = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_pipeline` with what `handler` returns for its input.
+    pub fn with_delete_pipeline<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(DeletePipelineRequest) -> ::std::result::Result<(), RusotoError<DeletePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_channel` with what `handler` returns for its input.
+    pub fn with_describe_channel<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                DescribeChannelRequest,
+            )
+                -> ::std::result::Result<DescribeChannelResponse, RusotoError<DescribeChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_dataset` with what `handler` returns for its input.
+    pub fn with_describe_dataset<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                DescribeDatasetRequest,
+            )
+                -> ::std::result::Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_dataset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_datastore` with what `handler` returns for its input.
+    pub fn with_describe_datastore<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                DescribeDatastoreRequest,
+            ) -> ::std::result::Result<
+                DescribeDatastoreResponse,
+                RusotoError<DescribeDatastoreError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_datastore = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_logging_options` with what `handler` returns.
+    pub fn with_describe_logging_options<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeLoggingOptionsResponse,
+                RusotoError<DescribeLoggingOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_logging_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_pipeline` with what `handler` returns for its input.
+    pub fn with_describe_pipeline<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                DescribePipelineRequest,
+            ) -> ::std::result::Result<
+                DescribePipelineResponse,
+                RusotoError<DescribePipelineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_pipeline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_dataset_content` with what `handler` returns for its input.
+    pub fn with_get_dataset_content<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                GetDatasetContentRequest,
+            ) -> ::std::result::Result<
+                GetDatasetContentResponse,
+                RusotoError<GetDatasetContentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_dataset_content = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_channels` with what `handler` returns for its input.
+    pub fn with_list_channels<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                ListChannelsRequest,
+            )
+                -> ::std::result::Result<ListChannelsResponse, RusotoError<ListChannelsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_channels = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_dataset_contents` with what `handler` returns for its input.
+    pub fn with_list_dataset_contents<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                ListDatasetContentsRequest,
+            ) -> ::std::result::Result<
+                ListDatasetContentsResponse,
+                RusotoError<ListDatasetContentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_dataset_contents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_datasets` with what `handler` returns for its input.
+    pub fn with_list_datasets<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                ListDatasetsRequest,
+            )
+                -> ::std::result::Result<ListDatasetsResponse, RusotoError<ListDatasetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_datasets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_datastores` with what `handler` returns for its input.
+    pub fn with_list_datastores<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                ListDatastoresRequest,
+            )
+                -> ::std::result::Result<ListDatastoresResponse, RusotoError<ListDatastoresError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_datastores = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_pipelines` with what `handler` returns for its input.
+    pub fn with_list_pipelines<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                ListPipelinesRequest,
+            )
+                -> ::std::result::Result<ListPipelinesResponse, RusotoError<ListPipelinesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_pipelines = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_logging_options` with what `handler` returns for its input.
+    pub fn with_put_logging_options<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                PutLoggingOptionsRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutLoggingOptionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_logging_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `run_pipeline_activity` with what `handler` returns for its input.
+    pub fn with_run_pipeline_activity<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                RunPipelineActivityRequest,
+            ) -> ::std::result::Result<
+                RunPipelineActivityResponse,
+                RusotoError<RunPipelineActivityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.run_pipeline_activity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `sample_channel_data` with what `handler` returns for its input.
+    pub fn with_sample_channel_data<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                SampleChannelDataRequest,
+            ) -> ::std::result::Result<
+                SampleChannelDataResponse,
+                RusotoError<SampleChannelDataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.sample_channel_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_pipeline_reprocessing` with what `handler` returns for its input.
+    pub fn with_start_pipeline_reprocessing<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                StartPipelineReprocessingRequest,
+            ) -> ::std::result::Result<
+                StartPipelineReprocessingResponse,
+                RusotoError<StartPipelineReprocessingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_pipeline_reprocessing = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_channel` with what `handler` returns for its input.
+    pub fn with_update_channel<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(UpdateChannelRequest) -> ::std::result::Result<(), RusotoError<UpdateChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_dataset` with what `handler` returns for its input.
+    pub fn with_update_dataset<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(UpdateDatasetRequest) -> ::std::result::Result<(), RusotoError<UpdateDatasetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_dataset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_datastore` with what `handler` returns for its input.
+    pub fn with_update_datastore<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(
+                UpdateDatastoreRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateDatastoreError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_datastore = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_pipeline` with what `handler` returns for its input.
+    pub fn with_update_pipeline<F>(mut self, handler: F) -> MockIotAnalytics
+    where
+        F: Fn(UpdatePipelineRequest) -> ::std::result::Result<(), RusotoError<UpdatePipelineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_pipeline = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl IotAnalytics for MockIotAnalytics {
+    fn batch_put_message(
+        &self,
+        input: BatchPutMessageRequest,
+    ) -> RusotoFuture<BatchPutMessageResponse, BatchPutMessageError> {
+        match self.batch_put_message {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for batch_put_message"),
+        }
+    }
+    fn cancel_pipeline_reprocessing(
+        &self,
+        input: CancelPipelineReprocessingRequest,
+    ) -> RusotoFuture<CancelPipelineReprocessingResponse, CancelPipelineReprocessingError> {
+        match self.cancel_pipeline_reprocessing {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for cancel_pipeline_reprocessing"),
+        }
+    }
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        match self.create_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for create_channel"),
+        }
+    }
+    fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> RusotoFuture<CreateDatasetResponse, CreateDatasetError> {
+        match self.create_dataset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for create_dataset"),
+        }
+    }
+    fn create_dataset_content(
+        &self,
+        input: CreateDatasetContentRequest,
+    ) -> RusotoFuture<CreateDatasetContentResponse, CreateDatasetContentError> {
+        match self.create_dataset_content {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for create_dataset_content"),
+        }
+    }
+    fn create_datastore(
+        &self,
+        input: CreateDatastoreRequest,
+    ) -> RusotoFuture<CreateDatastoreResponse, CreateDatastoreError> {
+        match self.create_datastore {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for create_datastore"),
+        }
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        match self.create_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for create_pipeline"),
+        }
+    }
+    fn delete_channel(&self, input: DeleteChannelRequest) -> RusotoFuture<(), DeleteChannelError> {
+        match self.delete_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for delete_channel"),
+        }
+    }
+    fn delete_dataset(&self, input: DeleteDatasetRequest) -> RusotoFuture<(), DeleteDatasetError> {
+        match self.delete_dataset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for delete_dataset"),
+        }
+    }
+    fn delete_dataset_content(
+        &self,
+        input: DeleteDatasetContentRequest,
+    ) -> RusotoFuture<(), DeleteDatasetContentError> {
+        match self.delete_dataset_content {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for delete_dataset_content"),
+        }
+    }
+    fn delete_datastore(
+        &self,
+        input: DeleteDatastoreRequest,
+    ) -> RusotoFuture<(), DeleteDatastoreError> {
+        match self.delete_datastore {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for delete_datastore"),
+        }
+    }
+    fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> RusotoFuture<(), DeletePipelineError> {
+        match self.delete_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for delete_pipeline"),
+        }
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        match self.describe_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for describe_channel"),
+        }
+    }
+    fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
+        match self.describe_dataset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for describe_dataset"),
+        }
+    }
+    fn describe_datastore(
+        &self,
+        input: DescribeDatastoreRequest,
+    ) -> RusotoFuture<DescribeDatastoreResponse, DescribeDatastoreError> {
+        match self.describe_datastore {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for describe_datastore"),
+        }
+    }
+    fn describe_logging_options(
+        &self,
+    ) -> RusotoFuture<DescribeLoggingOptionsResponse, DescribeLoggingOptionsError> {
+        match self.describe_logging_options {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockIotAnalytics has no handler for describe_logging_options"),
+        }
+    }
+    fn describe_pipeline(
+        &self,
+        input: DescribePipelineRequest,
+    ) -> RusotoFuture<DescribePipelineResponse, DescribePipelineError> {
+        match self.describe_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for describe_pipeline"),
+        }
+    }
+    fn get_dataset_content(
+        &self,
+        input: GetDatasetContentRequest,
+    ) -> RusotoFuture<GetDatasetContentResponse, GetDatasetContentError> {
+        match self.get_dataset_content {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for get_dataset_content"),
+        }
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        match self.list_channels {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for list_channels"),
+        }
+    }
+    fn list_dataset_contents(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> RusotoFuture<ListDatasetContentsResponse, ListDatasetContentsError> {
+        match self.list_dataset_contents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for list_dataset_contents"),
+        }
+    }
+    fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        match self.list_datasets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for list_datasets"),
+        }
+    }
+    fn list_datastores(
+        &self,
+        input: ListDatastoresRequest,
+    ) -> RusotoFuture<ListDatastoresResponse, ListDatastoresError> {
+        match self.list_datastores {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for list_datastores"),
+        }
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        match self.list_pipelines {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for list_pipelines"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for list_tags_for_resource"),
+        }
+    }
+    fn put_logging_options(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> RusotoFuture<(), PutLoggingOptionsError> {
+        match self.put_logging_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for put_logging_options"),
+        }
+    }
+    fn run_pipeline_activity(
+        &self,
+        input: RunPipelineActivityRequest,
+    ) -> RusotoFuture<RunPipelineActivityResponse, RunPipelineActivityError> {
+        match self.run_pipeline_activity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for run_pipeline_activity"),
+        }
+    }
+    fn sample_channel_data(
+        &self,
+        input: SampleChannelDataRequest,
+    ) -> RusotoFuture<SampleChannelDataResponse, SampleChannelDataError> {
+        match self.sample_channel_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for sample_channel_data"),
+        }
+    }
+    fn start_pipeline_reprocessing(
+        &self,
+        input: StartPipelineReprocessingRequest,
+    ) -> RusotoFuture<StartPipelineReprocessingResponse, StartPipelineReprocessingError> {
+        match self.start_pipeline_reprocessing {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for start_pipeline_reprocessing"),
+        }
+    }
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let request_uri = "/tags";
-
-        let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        params.put("resourceArn", &input.resource_arn);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Removes the given tags (metadata) from the resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let request_uri = "/tags";
-
-        let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("resourceArn", &input.resource_arn);
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for untag_resource"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Updates the settings of a channel.</p>
     fn update_channel(&self, input: UpdateChannelRequest) -> RusotoFuture<(), UpdateChannelError> {
-        let request_uri = format!(
-            "/channels/{channel_name}",
-            channel_name = input.channel_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateChannelError::from_response(response))),
-                )
-            }
-        })
+        match self.update_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for update_channel"),
+        }
     }
-
-    /// <p>Updates the settings of a data set.</p>
     fn update_dataset(&self, input: UpdateDatasetRequest) -> RusotoFuture<(), UpdateDatasetError> {
-        let request_uri = format!(
-            "/datasets/{dataset_name}",
-            dataset_name = input.dataset_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDatasetError::from_response(response))),
-                )
-            }
-        })
+        match self.update_dataset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for update_dataset"),
+        }
     }
-
-    /// <p>Updates the settings of a data store.</p>
     fn update_datastore(
         &self,
         input: UpdateDatastoreRequest,
     ) -> RusotoFuture<(), UpdateDatastoreError> {
-        let request_uri = format!(
-            "/datastores/{datastore_name}",
-            datastore_name = input.datastore_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDatastoreError::from_response(response))),
-                )
-            }
-        })
+        match self.update_datastore {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for update_datastore"),
+        }
     }
-
-    /// <p>Updates the settings of a pipeline. You must specify both a <code>channel</code> and a <code>datastore</code> activity and, optionally, as many as 23 additional activities in the <code>pipelineActivities</code> array.</p>
     fn update_pipeline(
         &self,
         input: UpdatePipelineRequest,
     ) -> RusotoFuture<(), UpdatePipelineError> {
-        let request_uri = format!(
-            "/pipelines/{pipeline_name}",
-            pipeline_name = input.pipeline_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdatePipelineError::from_response(response))),
-                )
-            }
-        })
+        match self.update_pipeline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockIotAnalytics has no handler for update_pipeline"),
+        }
     }
 }
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <pre><code>        &lt;p&gt;Specifies the EBS volume upgrade information. The broker identifier must be set to the keyword ALL. This means the changes apply to all the brokers in the cluster.&lt;/p&gt;
 /// </code></pre>
@@ -69,10 +70,24 @@ pub struct BrokerNodeGroupInfo {
     pub storage_info: Option<StorageInfo>,
 }
 
+impl Validate for BrokerNodeGroupInfo {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_type"),
+            self.instance_type.chars().count(),
+            Some(5),
+            Some(32),
+        );
+        if let Some(ref value) = self.storage_info {
+            value.validate_at(&field_path(path, "storage_info"), errors);
+        }
+    }
+}
+
 /// <pre><code>        &lt;p&gt;BrokerNodeInfo&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BrokerNodeInfo {
     /// <pre><code>        &lt;p&gt;The attached elastic network interface of the broker.&lt;/p&gt;
     /// </code></pre>
@@ -109,7 +124,7 @@ pub struct BrokerNodeInfo {
 /// <pre><code>        &lt;p&gt;Information about the current software installed on the cluster.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BrokerSoftwareInfo {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) of the configuration used for the cluster. This field isn&#39;t visible in this preview release.&lt;/p&gt;
     /// </code></pre>
@@ -142,7 +157,7 @@ pub struct ClientAuthentication {
 /// <pre><code>        &lt;p&gt;Returns information about a cluster.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClusterInfo {
     /// <pre><code>        &lt;p&gt;Arn of active cluster operation.&lt;/p&gt;
     /// </code></pre>
@@ -219,7 +234,7 @@ pub struct ClusterInfo {
 /// <pre><code>        &lt;p&gt;Returns information about a cluster operation.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClusterOperationInfo {
     /// <pre><code>        &lt;p&gt;The ID of the API request that triggered this operation.&lt;/p&gt;
     /// </code></pre>
@@ -276,7 +291,7 @@ pub struct ClusterOperationInfo {
 /// <pre><code>        &lt;p&gt;Represents an MSK Configuration.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Configuration {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) of the configuration.&lt;/p&gt;
     /// </code></pre>
@@ -321,7 +336,7 @@ pub struct ConfigurationInfo {
 /// <pre><code>        &lt;p&gt;Describes a configuration revision.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfigurationRevision {
     /// <pre><code>        &lt;p&gt;The time when the configuration revision was created.&lt;/p&gt;
     /// </code></pre>
@@ -339,6 +354,7 @@ pub struct ConfigurationRevision {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterRequest {
     /// <pre><code>        &lt;p&gt;Information about the broker nodes in the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -383,8 +399,33 @@ pub struct CreateClusterRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateClusterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.broker_node_group_info
+            .validate_at(&field_path(path, "broker_node_group_info"), errors);
+        errors.check_length(
+            &field_path(path, "cluster_name"),
+            self.cluster_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "kafka_version"),
+            self.kafka_version.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_range(
+            &field_path(path, "number_of_broker_nodes"),
+            self.number_of_broker_nodes,
+            Some(1),
+            Some(15),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateClusterResponse {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) of the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -404,6 +445,7 @@ pub struct CreateClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConfigurationRequest {
     /// <pre><code>        &lt;p&gt;The description of the configuration.&lt;/p&gt;
     /// </code></pre>
@@ -431,7 +473,7 @@ pub struct CreateConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateConfigurationResponse {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) of the configuration.&lt;/p&gt;
     /// </code></pre>
@@ -456,6 +498,7 @@ pub struct CreateConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -469,7 +512,7 @@ pub struct DeleteClusterRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteClusterResponse {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) of the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -484,6 +527,7 @@ pub struct DeleteClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterOperationRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the MSK cluster operation.&lt;/p&gt;
     /// </code></pre>
@@ -492,7 +536,7 @@ pub struct DescribeClusterOperationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeClusterOperationResponse {
     /// <pre><code>        &lt;p&gt;Cluster operation information&lt;/p&gt;
     /// </code></pre>
@@ -502,6 +546,7 @@ pub struct DescribeClusterOperationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -510,7 +555,7 @@ pub struct DescribeClusterRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeClusterResponse {
     /// <pre><code>        &lt;p&gt;The cluster information.&lt;/p&gt;
     /// </code></pre>
@@ -520,6 +565,7 @@ pub struct DescribeClusterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies an MSK configuration and all of its revisions.&lt;/p&gt;
     /// </code></pre>
@@ -528,7 +574,7 @@ pub struct DescribeConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigurationResponse {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) of the configuration.&lt;/p&gt;
     /// </code></pre>
@@ -563,6 +609,7 @@ pub struct DescribeConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationRevisionRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies an MSK configuration and all of its revisions.&lt;/p&gt;
     /// </code></pre>
@@ -575,7 +622,7 @@ pub struct DescribeConfigurationRevisionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigurationRevisionResponse {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) of the configuration.&lt;/p&gt;
     /// </code></pre>
@@ -621,6 +668,19 @@ pub struct EBSStorageInfo {
     pub volume_size: Option<i64>,
 }
 
+impl Validate for EBSStorageInfo {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.volume_size {
+            errors.check_range(
+                &field_path(path, "volume_size"),
+                *value,
+                Some(1),
+                Some(16384),
+            );
+        }
+    }
+}
+
 /// <pre><code>        &lt;p&gt;The data-volume encryption details.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -674,7 +734,7 @@ pub struct EncryptionInfo {
 /// <pre><code>        &lt;p&gt;Returns information about an error state of the cluster.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ErrorInfo {
     /// <pre><code>        &lt;p&gt;A number describing the error programmatically.&lt;/p&gt;
     /// </code></pre>
@@ -689,6 +749,7 @@ pub struct ErrorInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBootstrapBrokersRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -697,7 +758,7 @@ pub struct GetBootstrapBrokersRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBootstrapBrokersResponse {
     /// <pre><code>        &lt;p&gt;A string containing one or more hostname:port pairs.&lt;/p&gt;
     /// </code></pre>
@@ -712,6 +773,7 @@ pub struct GetBootstrapBrokersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListClusterOperationsRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -730,8 +792,16 @@ pub struct ListClusterOperationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListClusterOperationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListClusterOperationsResponse {
     /// <pre><code>        &lt;p&gt;An array of cluster operation information objects.&lt;/p&gt;
     /// </code></pre>
@@ -746,6 +816,7 @@ pub struct ListClusterOperationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListClustersRequest {
     /// <pre><code>        &lt;p&gt;Specify a prefix of the name of the clusters that you want to list. The service lists all the clusters whose names start with this prefix.&lt;/p&gt;
     /// </code></pre>
@@ -765,8 +836,16 @@ pub struct ListClustersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListClustersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListClustersResponse {
     /// <pre><code>        &lt;p&gt;Information on each of the MSK clusters in the response.&lt;/p&gt;
     /// </code></pre>
@@ -782,6 +861,7 @@ pub struct ListClustersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConfigurationRevisionsRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies an MSK configuration and all of its revisions.&lt;/p&gt;
     /// </code></pre>
@@ -800,8 +880,16 @@ pub struct ListConfigurationRevisionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListConfigurationRevisionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListConfigurationRevisionsResponse {
     /// <pre><code>        &lt;p&gt;Paginated results marker.&lt;/p&gt;
     /// </code></pre>
@@ -816,6 +904,7 @@ pub struct ListConfigurationRevisionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConfigurationsRequest {
     /// <pre><code>        &lt;p&gt;The maximum number of results to return in the response. If there are more results, the response includes a NextToken parameter.&lt;/p&gt;
     /// </code></pre>
@@ -830,8 +919,16 @@ pub struct ListConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListConfigurationsResponse {
     /// <pre><code>        &lt;p&gt;An array of MSK configurations.&lt;/p&gt;
     /// </code></pre>
@@ -847,6 +944,7 @@ pub struct ListConfigurationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListNodesRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -865,8 +963,16 @@ pub struct ListNodesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListNodesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListNodesResponse {
     /// <pre><code>        &lt;p&gt;The paginated results marker. When the result of a ListNodes operation is truncated, the call returns NextToken in the response.
     /// To get another batch of nodes, provide this token in your next request.&lt;/p&gt;
@@ -882,6 +988,7 @@ pub struct ListNodesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the resource that&#39;s associated with the tags.&lt;/p&gt;
     /// </code></pre>
@@ -890,7 +997,7 @@ pub struct ListTagsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <pre><code>        &lt;p&gt;The key-value pair for the resource tag.&lt;/p&gt;
     /// </code></pre>
@@ -902,7 +1009,7 @@ pub struct ListTagsForResourceResponse {
 /// <pre><code>        &lt;p&gt;Information about cluster attributes that can be updated via update APIs.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MutableClusterInfo {
     /// <pre><code>        &lt;p&gt;Specifies the size of the EBS volume and the ID of the associated broker.&lt;/p&gt;
     /// </code></pre>
@@ -924,7 +1031,7 @@ pub struct MutableClusterInfo {
 /// <pre><code>        &lt;p&gt;The node information object.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NodeInfo {
     /// <pre><code>        &lt;p&gt;The start time.&lt;/p&gt;
     /// </code></pre>
@@ -969,7 +1076,16 @@ pub struct StorageInfo {
     pub ebs_storage_info: Option<EBSStorageInfo>,
 }
 
+impl Validate for StorageInfo {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ebs_storage_info {
+            value.validate_at(&field_path(path, "ebs_storage_info"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the resource that&#39;s associated with the tags.&lt;/p&gt;
     /// </code></pre>
@@ -993,6 +1109,7 @@ pub struct Tls {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the resource that&#39;s associated with the tags.&lt;/p&gt;
     /// </code></pre>
@@ -1024,6 +1141,7 @@ pub struct UntagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateBrokerStorageRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -1040,7 +1158,7 @@ pub struct UpdateBrokerStorageRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateBrokerStorageResponse {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) of the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -1055,6 +1173,7 @@ pub struct UpdateBrokerStorageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateClusterConfigurationRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -1071,7 +1190,7 @@ pub struct UpdateClusterConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateClusterConfigurationResponse {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) of the cluster.&lt;/p&gt;
     /// </code></pre>
@@ -1088,7 +1207,7 @@ pub struct UpdateClusterConfigurationResponse {
 /// <pre><code>        &lt;p&gt;Zookeeper node information.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ZookeeperNodeInfo {
     /// <pre><code>        &lt;p&gt;The attached elastic network interface of the broker.&lt;/p&gt;
     /// </code></pre>
@@ -1168,7 +1287,12 @@ impl CreateClusterError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateClusterError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1248,7 +1372,12 @@ impl CreateConfigurationError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateConfigurationError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1306,7 +1435,12 @@ impl DeleteClusterError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteClusterError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1367,7 +1501,12 @@ impl DescribeClusterError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeClusterError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1433,7 +1572,12 @@ impl DescribeClusterOperationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1505,7 +1649,12 @@ impl DescribeConfigurationError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeConfigurationError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1588,7 +1737,12 @@ impl DescribeConfigurationRevisionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1653,7 +1807,12 @@ impl GetBootstrapBrokersError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(GetBootstrapBrokersError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1711,7 +1870,12 @@ impl ListClusterOperationsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListClusterOperationsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1766,7 +1930,12 @@ impl ListClustersError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListClustersError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1845,7 +2014,12 @@ impl ListConfigurationRevisionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1912,7 +2086,12 @@ impl ListConfigurationsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListConfigurationsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1968,7 +2147,12 @@ impl ListNodesError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListNodesError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2019,7 +2203,12 @@ impl ListTagsForResourceError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2067,7 +2256,12 @@ impl TagResourceError {
                 "NotFoundException" => {
                     return RusotoError::Service(TagResourceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2115,7 +2309,12 @@ impl UntagResourceError {
                 "NotFoundException" => {
                     return RusotoError::Service(UntagResourceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2179,7 +2378,12 @@ impl UpdateBrokerStorageError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateBrokerStorageError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2259,7 +2463,12 @@ impl UpdateClusterConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2405,6 +2614,322 @@ pub trait Kafka {
         input: UpdateClusterConfigurationRequest,
     ) -> RusotoFuture<UpdateClusterConfigurationResponse, UpdateClusterConfigurationError>;
 }
+const _: Option<&dyn Kafka> = None;
+impl<'a, T: Kafka + ?Sized> Kafka for &'a T {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
+        (**self).create_configuration(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResponse, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_cluster_operation(
+        &self,
+        input: DescribeClusterOperationRequest,
+    ) -> RusotoFuture<DescribeClusterOperationResponse, DescribeClusterOperationError> {
+        (**self).describe_cluster_operation(input)
+    }
+    fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> RusotoFuture<DescribeConfigurationResponse, DescribeConfigurationError> {
+        (**self).describe_configuration(input)
+    }
+    fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> RusotoFuture<DescribeConfigurationRevisionResponse, DescribeConfigurationRevisionError>
+    {
+        (**self).describe_configuration_revision(input)
+    }
+    fn get_bootstrap_brokers(
+        &self,
+        input: GetBootstrapBrokersRequest,
+    ) -> RusotoFuture<GetBootstrapBrokersResponse, GetBootstrapBrokersError> {
+        (**self).get_bootstrap_brokers(input)
+    }
+    fn list_cluster_operations(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> RusotoFuture<ListClusterOperationsResponse, ListClusterOperationsError> {
+        (**self).list_cluster_operations(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        (**self).list_configuration_revisions(input)
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_nodes(
+        &self,
+        input: ListNodesRequest,
+    ) -> RusotoFuture<ListNodesResponse, ListNodesError> {
+        (**self).list_nodes(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_broker_storage(
+        &self,
+        input: UpdateBrokerStorageRequest,
+    ) -> RusotoFuture<UpdateBrokerStorageResponse, UpdateBrokerStorageError> {
+        (**self).update_broker_storage(input)
+    }
+    fn update_cluster_configuration(
+        &self,
+        input: UpdateClusterConfigurationRequest,
+    ) -> RusotoFuture<UpdateClusterConfigurationResponse, UpdateClusterConfigurationError> {
+        (**self).update_cluster_configuration(input)
+    }
+}
+impl<T: Kafka + ?Sized> Kafka for Box<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
+        (**self).create_configuration(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResponse, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_cluster_operation(
+        &self,
+        input: DescribeClusterOperationRequest,
+    ) -> RusotoFuture<DescribeClusterOperationResponse, DescribeClusterOperationError> {
+        (**self).describe_cluster_operation(input)
+    }
+    fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> RusotoFuture<DescribeConfigurationResponse, DescribeConfigurationError> {
+        (**self).describe_configuration(input)
+    }
+    fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> RusotoFuture<DescribeConfigurationRevisionResponse, DescribeConfigurationRevisionError>
+    {
+        (**self).describe_configuration_revision(input)
+    }
+    fn get_bootstrap_brokers(
+        &self,
+        input: GetBootstrapBrokersRequest,
+    ) -> RusotoFuture<GetBootstrapBrokersResponse, GetBootstrapBrokersError> {
+        (**self).get_bootstrap_brokers(input)
+    }
+    fn list_cluster_operations(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> RusotoFuture<ListClusterOperationsResponse, ListClusterOperationsError> {
+        (**self).list_cluster_operations(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        (**self).list_configuration_revisions(input)
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_nodes(
+        &self,
+        input: ListNodesRequest,
+    ) -> RusotoFuture<ListNodesResponse, ListNodesError> {
+        (**self).list_nodes(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_broker_storage(
+        &self,
+        input: UpdateBrokerStorageRequest,
+    ) -> RusotoFuture<UpdateBrokerStorageResponse, UpdateBrokerStorageError> {
+        (**self).update_broker_storage(input)
+    }
+    fn update_cluster_configuration(
+        &self,
+        input: UpdateClusterConfigurationRequest,
+    ) -> RusotoFuture<UpdateClusterConfigurationResponse, UpdateClusterConfigurationError> {
+        (**self).update_cluster_configuration(input)
+    }
+}
+impl<T: Kafka + ?Sized> Kafka for ::std::sync::Arc<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
+        (**self).create_configuration(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResponse, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_cluster_operation(
+        &self,
+        input: DescribeClusterOperationRequest,
+    ) -> RusotoFuture<DescribeClusterOperationResponse, DescribeClusterOperationError> {
+        (**self).describe_cluster_operation(input)
+    }
+    fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> RusotoFuture<DescribeConfigurationResponse, DescribeConfigurationError> {
+        (**self).describe_configuration(input)
+    }
+    fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> RusotoFuture<DescribeConfigurationRevisionResponse, DescribeConfigurationRevisionError>
+    {
+        (**self).describe_configuration_revision(input)
+    }
+    fn get_bootstrap_brokers(
+        &self,
+        input: GetBootstrapBrokersRequest,
+    ) -> RusotoFuture<GetBootstrapBrokersResponse, GetBootstrapBrokersError> {
+        (**self).get_bootstrap_brokers(input)
+    }
+    fn list_cluster_operations(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> RusotoFuture<ListClusterOperationsResponse, ListClusterOperationsError> {
+        (**self).list_cluster_operations(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        (**self).list_configuration_revisions(input)
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_nodes(
+        &self,
+        input: ListNodesRequest,
+    ) -> RusotoFuture<ListNodesResponse, ListNodesError> {
+        (**self).list_nodes(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_broker_storage(
+        &self,
+        input: UpdateBrokerStorageRequest,
+    ) -> RusotoFuture<UpdateBrokerStorageResponse, UpdateBrokerStorageError> {
+        (**self).update_broker_storage(input)
+    }
+    fn update_cluster_configuration(
+        &self,
+        input: UpdateClusterConfigurationRequest,
+    ) -> RusotoFuture<UpdateClusterConfigurationResponse, UpdateClusterConfigurationError> {
+        (**self).update_cluster_configuration(input)
+    }
+}
 /// A client for the Kafka API.
 #[derive(Clone)]
 pub struct KafkaClient {
@@ -2449,9 +2974,19 @@ impl Kafka for KafkaClient {
         &self,
         input: CreateClusterRequest,
     ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/clusters";
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("CreateCluster");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2461,6 +2996,7 @@ impl Kafka for KafkaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateCluster")
                         .deserialize::<CreateClusterResponse, _>()?;
 
                     Ok(result)
@@ -2485,6 +3021,8 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("CreateConfiguration");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2494,6 +3032,7 @@ impl Kafka for KafkaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateConfiguration")
                         .deserialize::<CreateConfigurationResponse, _>()?;
 
                     Ok(result)
@@ -2520,6 +3059,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation("DeleteCluster");
+        request.add_resource_id("ClusterArn", &input.cluster_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2532,6 +3074,7 @@ impl Kafka for KafkaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteCluster")
                         .deserialize::<DeleteClusterResponse, _>()?;
 
                     Ok(result)
@@ -2559,12 +3102,16 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeCluster");
+        request.add_resource_id("ClusterArn", &input.cluster_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeCluster")
                         .deserialize::<DescribeClusterResponse, _>()?;
 
                     Ok(result)
@@ -2592,12 +3139,19 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeClusterOperation");
+        request.add_resource_id(
+            "ClusterOperationArn",
+            &input.cluster_operation_arn.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeClusterOperation")
                         .deserialize::<DescribeClusterOperationResponse, _>()?;
 
                     Ok(result)
@@ -2619,12 +3173,16 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/configurations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeConfiguration");
+        request.add_resource_id("Arn", &input.arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeConfiguration")
                         .deserialize::<DescribeConfigurationResponse, _>()?;
 
                     Ok(result)
@@ -2653,12 +3211,17 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeConfigurationRevision");
+        request.add_resource_id("Arn", &input.arn.to_string());
+        request.add_resource_id("Revision", &input.revision.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeConfigurationRevision")
                         .deserialize::<DescribeConfigurationRevisionResponse, _>()?;
 
                     Ok(result)
@@ -2683,12 +3246,16 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("GetBootstrapBrokers");
+        request.add_resource_id("ClusterArn", &input.cluster_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBootstrapBrokers")
                         .deserialize::<GetBootstrapBrokersResponse, _>()?;
 
                     Ok(result)
@@ -2709,12 +3276,23 @@ impl Kafka for KafkaClient {
         &self,
         input: ListClusterOperationsRequest,
     ) -> RusotoFuture<ListClusterOperationsResponse, ListClusterOperationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}/operations",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListClusterOperations");
+        request.add_resource_id("ClusterArn", &input.cluster_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2730,6 +3308,7 @@ impl Kafka for KafkaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListClusterOperations")
                         .deserialize::<ListClusterOperationsResponse, _>()?;
 
                     Ok(result)
@@ -2750,9 +3329,19 @@ impl Kafka for KafkaClient {
         &self,
         input: ListClustersRequest,
     ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/clusters";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListClusters");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2771,6 +3360,7 @@ impl Kafka for KafkaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListClusters")
                         .deserialize::<ListClustersResponse, _>()?;
 
                     Ok(result)
@@ -2792,9 +3382,20 @@ impl Kafka for KafkaClient {
         &self,
         input: ListConfigurationRevisionsRequest,
     ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/v1/configurations/{arn}/revisions", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListConfigurationRevisions");
+        request.add_resource_id("Arn", &input.arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2810,6 +3411,7 @@ impl Kafka for KafkaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListConfigurationRevisions")
                         .deserialize::<ListConfigurationRevisionsResponse, _>()?;
 
                     Ok(result)
@@ -2828,9 +3430,19 @@ impl Kafka for KafkaClient {
         &self,
         input: ListConfigurationsRequest,
     ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListConfigurations");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2846,6 +3458,7 @@ impl Kafka for KafkaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListConfigurations")
                         .deserialize::<ListConfigurationsResponse, _>()?;
 
                     Ok(result)
@@ -2867,12 +3480,23 @@ impl Kafka for KafkaClient {
         &self,
         input: ListNodesRequest,
     ) -> RusotoFuture<ListNodesResponse, ListNodesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}/nodes",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListNodes");
+        request.add_resource_id("ClusterArn", &input.cluster_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2888,6 +3512,7 @@ impl Kafka for KafkaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListNodes")
                         .deserialize::<ListNodesResponse, _>()?;
 
                     Ok(result)
@@ -2912,12 +3537,16 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
                         .deserialize::<ListTagsForResourceResponse, _>()?;
 
                     Ok(result)
@@ -2938,6 +3567,8 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2967,6 +3598,8 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3005,6 +3638,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateBrokerStorage");
+        request.add_resource_id("ClusterArn", &input.cluster_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3014,6 +3650,7 @@ impl Kafka for KafkaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateBrokerStorage")
                         .deserialize::<UpdateBrokerStorageResponse, _>()?;
 
                     Ok(result)
@@ -3040,6 +3677,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateClusterConfiguration");
+        request.add_resource_id("ClusterArn", &input.cluster_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3049,6 +3689,7 @@ impl Kafka for KafkaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateClusterConfiguration")
                         .deserialize::<UpdateClusterConfigurationResponse, _>()?;
 
                     Ok(result)
@@ -3061,3 +3702,626 @@ impl Kafka for KafkaClient {
         })
     }
 }
+
+/// A mock of the Kafka API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockKafka {
+    create_cluster: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterRequest,
+                )
+                    -> ::std::result::Result<CreateClusterResponse, RusotoError<CreateClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_configuration: Option<
+        Box<
+            dyn Fn(
+                    CreateConfigurationRequest,
+                ) -> ::std::result::Result<
+                    CreateConfigurationResponse,
+                    RusotoError<CreateConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_cluster: Option<
+        Box<
+            dyn Fn(
+                    DeleteClusterRequest,
+                )
+                    -> ::std::result::Result<DeleteClusterResponse, RusotoError<DeleteClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterRequest,
+                ) -> ::std::result::Result<
+                    DescribeClusterResponse,
+                    RusotoError<DescribeClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster_operation: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterOperationRequest,
+                ) -> ::std::result::Result<
+                    DescribeClusterOperationResponse,
+                    RusotoError<DescribeClusterOperationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigurationResponse,
+                    RusotoError<DescribeConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration_revision: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationRevisionRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigurationRevisionResponse,
+                    RusotoError<DescribeConfigurationRevisionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bootstrap_brokers: Option<
+        Box<
+            dyn Fn(
+                    GetBootstrapBrokersRequest,
+                ) -> ::std::result::Result<
+                    GetBootstrapBrokersResponse,
+                    RusotoError<GetBootstrapBrokersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_cluster_operations: Option<
+        Box<
+            dyn Fn(
+                    ListClusterOperationsRequest,
+                ) -> ::std::result::Result<
+                    ListClusterOperationsResponse,
+                    RusotoError<ListClusterOperationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_clusters: Option<
+        Box<
+            dyn Fn(
+                    ListClustersRequest,
+                )
+                    -> ::std::result::Result<ListClustersResponse, RusotoError<ListClustersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_configuration_revisions: Option<
+        Box<
+            dyn Fn(
+                    ListConfigurationRevisionsRequest,
+                ) -> ::std::result::Result<
+                    ListConfigurationRevisionsResponse,
+                    RusotoError<ListConfigurationRevisionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_configurations: Option<
+        Box<
+            dyn Fn(
+                    ListConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    ListConfigurationsResponse,
+                    RusotoError<ListConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_nodes: Option<
+        Box<
+            dyn Fn(
+                    ListNodesRequest,
+                )
+                    -> ::std::result::Result<ListNodesResponse, RusotoError<ListNodesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_broker_storage: Option<
+        Box<
+            dyn Fn(
+                    UpdateBrokerStorageRequest,
+                ) -> ::std::result::Result<
+                    UpdateBrokerStorageResponse,
+                    RusotoError<UpdateBrokerStorageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_cluster_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateClusterConfigurationRequest,
+                ) -> ::std::result::Result<
+                    UpdateClusterConfigurationResponse,
+                    RusotoError<UpdateClusterConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockKafka {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockKafka {
+        Default::default()
+    }
+
+    /// Answers `create_cluster` with what `handler` returns for its input.
+    pub fn with_create_cluster<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                CreateClusterRequest,
+            )
+                -> ::std::result::Result<CreateClusterResponse, RusotoError<CreateClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_configuration` with what `handler` returns for its input.
+    pub fn with_create_configuration<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                CreateConfigurationRequest,
+            ) -> ::std::result::Result<
+                CreateConfigurationResponse,
+                RusotoError<CreateConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cluster` with what `handler` returns for its input.
+    pub fn with_delete_cluster<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                DeleteClusterRequest,
+            )
+                -> ::std::result::Result<DeleteClusterResponse, RusotoError<DeleteClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster` with what `handler` returns for its input.
+    pub fn with_describe_cluster<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                DescribeClusterRequest,
+            )
+                -> ::std::result::Result<DescribeClusterResponse, RusotoError<DescribeClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster_operation` with what `handler` returns for its input.
+    pub fn with_describe_cluster_operation<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                DescribeClusterOperationRequest,
+            ) -> ::std::result::Result<
+                DescribeClusterOperationResponse,
+                RusotoError<DescribeClusterOperationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster_operation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration` with what `handler` returns for its input.
+    pub fn with_describe_configuration<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                DescribeConfigurationRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigurationResponse,
+                RusotoError<DescribeConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration_revision` with what `handler` returns for its input.
+    pub fn with_describe_configuration_revision<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                DescribeConfigurationRevisionRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigurationRevisionResponse,
+                RusotoError<DescribeConfigurationRevisionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration_revision = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bootstrap_brokers` with what `handler` returns for its input.
+    pub fn with_get_bootstrap_brokers<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                GetBootstrapBrokersRequest,
+            ) -> ::std::result::Result<
+                GetBootstrapBrokersResponse,
+                RusotoError<GetBootstrapBrokersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bootstrap_brokers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_cluster_operations` with what `handler` returns for its input.
+    pub fn with_list_cluster_operations<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                ListClusterOperationsRequest,
+            ) -> ::std::result::Result<
+                ListClusterOperationsResponse,
+                RusotoError<ListClusterOperationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_cluster_operations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_clusters` with what `handler` returns for its input.
+    pub fn with_list_clusters<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                ListClustersRequest,
+            )
+                -> ::std::result::Result<ListClustersResponse, RusotoError<ListClustersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_configuration_revisions` with what `handler` returns for its input.
+    pub fn with_list_configuration_revisions<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                ListConfigurationRevisionsRequest,
+            ) -> ::std::result::Result<
+                ListConfigurationRevisionsResponse,
+                RusotoError<ListConfigurationRevisionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_configuration_revisions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_configurations` with what `handler` returns for its input.
+    pub fn with_list_configurations<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                ListConfigurationsRequest,
+            ) -> ::std::result::Result<
+                ListConfigurationsResponse,
+                RusotoError<ListConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_nodes` with what `handler` returns for its input.
+    pub fn with_list_nodes<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                ListNodesRequest,
+            ) -> ::std::result::Result<ListNodesResponse, RusotoError<ListNodesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_nodes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_broker_storage` with what `handler` returns for its input.
+    pub fn with_update_broker_storage<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                UpdateBrokerStorageRequest,
+            ) -> ::std::result::Result<
+                UpdateBrokerStorageResponse,
+                RusotoError<UpdateBrokerStorageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_broker_storage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_cluster_configuration` with what `handler` returns for its input.
+    pub fn with_update_cluster_configuration<F>(mut self, handler: F) -> MockKafka
+    where
+        F: Fn(
+                UpdateClusterConfigurationRequest,
+            ) -> ::std::result::Result<
+                UpdateClusterConfigurationResponse,
+                RusotoError<UpdateClusterConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_cluster_configuration = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Kafka for MockKafka {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        match self.create_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for create_cluster"),
+        }
+    }
+    fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
+        match self.create_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for create_configuration"),
+        }
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        match self.delete_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for delete_cluster"),
+        }
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResponse, DescribeClusterError> {
+        match self.describe_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for describe_cluster"),
+        }
+    }
+    fn describe_cluster_operation(
+        &self,
+        input: DescribeClusterOperationRequest,
+    ) -> RusotoFuture<DescribeClusterOperationResponse, DescribeClusterOperationError> {
+        match self.describe_cluster_operation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for describe_cluster_operation"),
+        }
+    }
+    fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> RusotoFuture<DescribeConfigurationResponse, DescribeConfigurationError> {
+        match self.describe_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for describe_configuration"),
+        }
+    }
+    fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> RusotoFuture<DescribeConfigurationRevisionResponse, DescribeConfigurationRevisionError>
+    {
+        match self.describe_configuration_revision {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for describe_configuration_revision"),
+        }
+    }
+    fn get_bootstrap_brokers(
+        &self,
+        input: GetBootstrapBrokersRequest,
+    ) -> RusotoFuture<GetBootstrapBrokersResponse, GetBootstrapBrokersError> {
+        match self.get_bootstrap_brokers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for get_bootstrap_brokers"),
+        }
+    }
+    fn list_cluster_operations(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> RusotoFuture<ListClusterOperationsResponse, ListClusterOperationsError> {
+        match self.list_cluster_operations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for list_cluster_operations"),
+        }
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        match self.list_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for list_clusters"),
+        }
+    }
+    fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        match self.list_configuration_revisions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for list_configuration_revisions"),
+        }
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        match self.list_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for list_configurations"),
+        }
+    }
+    fn list_nodes(
+        &self,
+        input: ListNodesRequest,
+    ) -> RusotoFuture<ListNodesResponse, ListNodesError> {
+        match self.list_nodes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for list_nodes"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for list_tags_for_resource"),
+        }
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for untag_resource"),
+        }
+    }
+    fn update_broker_storage(
+        &self,
+        input: UpdateBrokerStorageRequest,
+    ) -> RusotoFuture<UpdateBrokerStorageResponse, UpdateBrokerStorageError> {
+        match self.update_broker_storage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for update_broker_storage"),
+        }
+    }
+    fn update_cluster_configuration(
+        &self,
+        input: UpdateClusterConfigurationRequest,
+    ) -> RusotoFuture<UpdateClusterConfigurationResponse, UpdateClusterConfigurationError> {
+        match self.update_cluster_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKafka has no handler for update_cluster_configuration"),
+        }
+    }
+}
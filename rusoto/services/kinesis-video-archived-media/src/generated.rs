@@ -22,9 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Contains the range of timestamps for the requested media, and the source of the timestamps. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DASHFragmentSelector {
     /// <p>The source of the timestamps for the requested media.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetDASHStreamingSessionURLInput$PlaybackMode</a> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>, the first fragment ingested with a producer timestamp within the specified <a>FragmentSelector$TimestampRange</a> is included in the media playlist. In addition, the fragments with producer timestamps within the <code>TimestampRange</code> ingested immediately following the first fragment (up to the <a>GetDASHStreamingSessionURLInput$MaxManifestFragmentResults</a> value) are included. </p> <p>Fragments that have duplicate producer timestamps are deduplicated. This means that if producers are producing a stream of fragments with producer timestamps that are approximately equal to the true clock time, the MPEG-DASH manifest will contain all of the fragments within the requested timestamp range. If some fragments are ingested within the same time range and very different points in time, only the oldest ingested collection of fragments are returned.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetDASHStreamingSessionURLInput$PlaybackMode</a> is <code>LIVE</code>, the producer timestamps are used in the MP4 fragments and for deduplication. But the most recently ingested fragments based on server timestamps are included in the MPEG-DASH manifest. This means that even if fragments ingested in the past have producer timestamps with values now, they are not included in the HLS media playlist.</p> <p>The default is <code>SERVER_TIMESTAMP</code>.</p>
     #[serde(rename = "FragmentSelectorType")]
@@ -38,6 +40,7 @@ pub struct DASHFragmentSelector {
 
 /// <p><p>The start and end of the timestamp range for the requested media.</p> <p>This value should not be present if <code>PlaybackType</code> is <code>LIVE</code>.</p> <note> <p>The values in the <code>DASHimestampRange</code> are inclusive. Fragments that begin before the start time but continue past it, or fragments that begin before the end time but continue past it, are included in the session.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DASHTimestampRange {
     /// <p><p>The end of the timestamp range for the requested media. This value must be within 3 hours of the specified <code>StartTimestamp</code>, and it must be later than the <code>StartTimestamp</code> value.</p> <p>If <code>FragmentSelectorType</code> for the request is <code>SERVER<em>TIMESTAMP</code>, this value must be in the past.</p> <p>The <code>EndTimestamp</code> value is required for <code>ON</em>DEMAND</code> mode, but optional for <code>LIVE<em>REPLAY</code> mode. If the <code>EndTimestamp</code> is not set for <code>LIVE</em>REPLAY</code> mode then the session will continue to include newly ingested fragments until the session expires.</p> <note> <p>This value is inclusive. The <code>EndTimestamp</code> is compared to the (starting) timestamp of the fragment. Fragments that start before the <code>EndTimestamp</code> value and continue past it are included in the session.</p> </note></p>
     #[serde(rename = "EndTimestamp")]
@@ -51,7 +54,7 @@ pub struct DASHTimestampRange {
 
 /// <p>Represents a segment of video or other time-delimited data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Fragment {
     /// <p>The playback duration or other time value associated with the fragment.</p>
     #[serde(rename = "FragmentLengthInMilliseconds")]
@@ -77,6 +80,7 @@ pub struct Fragment {
 
 /// <p>Describes the timestamp range and timestamp origin of a range of fragments.</p> <p>Only fragments with a start timestamp greater than or equal to the given start time and less than or equal to the end time are returned. For example, if a stream contains fragments with the following start timestamps: </p> <ul> <li> <p>00:00:00</p> </li> <li> <p>00:00:02</p> </li> <li> <p>00:00:04</p> </li> <li> <p>00:00:06</p> </li> </ul> <p> A fragment selector range with a start time of 00:00:01 and end time of 00:00:04 would return the fragments with start times of 00:00:02 and 00:00:04. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FragmentSelector {
     /// <p>The origin of the timestamps to use (Server or Producer).</p>
     #[serde(rename = "FragmentSelectorType")]
@@ -87,6 +91,7 @@ pub struct FragmentSelector {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDASHStreamingSessionURLInput {
     /// <p>The time range of the requested fragment, and the source of the timestamps.</p> <p>This parameter is required if <code>PlaybackMode</code> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>. This parameter is optional if PlaybackMode is<code/> <code>LIVE</code>. If <code>PlaybackMode</code> is <code>LIVE</code>, the <code>FragmentSelectorType</code> can be set, but the <code>TimestampRange</code> should not be set. If <code>PlaybackMode</code> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>, both <code>FragmentSelectorType</code> and <code>TimestampRange</code> must be set.</p>
     #[serde(rename = "DASHFragmentSelector")]
@@ -122,8 +127,46 @@ pub struct GetDASHStreamingSessionURLInput {
     pub stream_name: Option<String>,
 }
 
+impl Validate for GetDASHStreamingSessionURLInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expires {
+            errors.check_range(&field_path(path, "expires"), *value, Some(300), Some(43200));
+        }
+        if let Some(ref value) = self.max_manifest_fragment_results {
+            errors.check_range(
+                &field_path(path, "max_manifest_fragment_results"),
+                *value,
+                Some(1),
+                Some(5000),
+            );
+        }
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:[a-z\\d-]+:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDASHStreamingSessionURLOutput {
     /// <p>The URL (containing the session token) that a media player can use to retrieve the MPEG-DASH manifest.</p>
     #[serde(rename = "DASHStreamingSessionURL")]
@@ -132,6 +175,7 @@ pub struct GetDASHStreamingSessionURLOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHLSStreamingSessionURLInput {
     /// <p>Specifies which format should be used for packaging the media. Specifying the <code>FRAGMENTED_MP4</code> container format packages the media into MP4 fragments (fMP4 or CMAF). This is the recommended packaging because there is minimal packaging overhead. The other container format option is <code>MPEG_TS</code>. HLS has supported MPEG TS chunks since it was released and is sometimes the only supported packaging on older HLS players. MPEG TS typically has a 5-25 percent packaging overhead. This means MPEG TS typically requires 5-25 percent more bandwidth and cost than fMP4.</p> <p>The default is <code>FRAGMENTED_MP4</code>.</p>
     #[serde(rename = "ContainerFormat")]
@@ -171,8 +215,46 @@ pub struct GetHLSStreamingSessionURLInput {
     pub stream_name: Option<String>,
 }
 
+impl Validate for GetHLSStreamingSessionURLInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.expires {
+            errors.check_range(&field_path(path, "expires"), *value, Some(300), Some(43200));
+        }
+        if let Some(ref value) = self.max_media_playlist_fragment_results {
+            errors.check_range(
+                &field_path(path, "max_media_playlist_fragment_results"),
+                *value,
+                Some(1),
+                Some(5000),
+            );
+        }
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:[a-z\\d-]+:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetHLSStreamingSessionURLOutput {
     /// <p>The URL (containing the session token) that a media player can use to retrieve the HLS master playlist.</p>
     #[serde(rename = "HLSStreamingSessionURL")]
@@ -181,6 +263,7 @@ pub struct GetHLSStreamingSessionURLOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMediaForFragmentListInput {
     /// <p>A list of the numbers of fragments for which to retrieve media. You retrieve these values with <a>ListFragments</a>.</p>
     #[serde(rename = "Fragments")]
@@ -190,6 +273,33 @@ pub struct GetMediaForFragmentListInput {
     pub stream_name: String,
 }
 
+impl Validate for GetMediaForFragmentListInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fragments"),
+            self.fragments.len(),
+            Some(1),
+            Some(1000),
+        );
+        for (i, item) in self.fragments.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "fragments"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^[0-9]+$");
+        }
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetMediaForFragmentListOutput {
     /// <p>The content type of the requested media.</p>
@@ -200,6 +310,7 @@ pub struct GetMediaForFragmentListOutput {
 
 /// <p>Contains the range of timestamps for the requested media, and the source of the timestamps.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HLSFragmentSelector {
     /// <p>The source of the timestamps for the requested media.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetHLSStreamingSessionURLInput$PlaybackMode</a> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>, the first fragment ingested with a producer timestamp within the specified <a>FragmentSelector$TimestampRange</a> is included in the media playlist. In addition, the fragments with producer timestamps within the <code>TimestampRange</code> ingested immediately following the first fragment (up to the <a>GetHLSStreamingSessionURLInput$MaxMediaPlaylistFragmentResults</a> value) are included. </p> <p>Fragments that have duplicate producer timestamps are deduplicated. This means that if producers are producing a stream of fragments with producer timestamps that are approximately equal to the true clock time, the HLS media playlists will contain all of the fragments within the requested timestamp range. If some fragments are ingested within the same time range and very different points in time, only the oldest ingested collection of fragments are returned.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetHLSStreamingSessionURLInput$PlaybackMode</a> is <code>LIVE</code>, the producer timestamps are used in the MP4 fragments and for deduplication. But the most recently ingested fragments based on server timestamps are included in the HLS media playlist. This means that even if fragments ingested in the past have producer timestamps with values now, they are not included in the HLS media playlist.</p> <p>The default is <code>SERVER_TIMESTAMP</code>.</p>
     #[serde(rename = "FragmentSelectorType")]
@@ -213,6 +324,7 @@ pub struct HLSFragmentSelector {
 
 /// <p><p>The start and end of the timestamp range for the requested media.</p> <p>This value should not be present if <code>PlaybackType</code> is <code>LIVE</code>.</p> <note> <p>The values in the <code>HLSTimestampRange</code> are inclusive. Fragments that begin before the start time but continue past it, or fragments that begin before the end time but continue past it, are included in the session.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HLSTimestampRange {
     /// <p><p>The end of the timestamp range for the requested media. This value must be within 3 hours of the specified <code>StartTimestamp</code>, and it must be later than the <code>StartTimestamp</code> value.</p> <p>If <code>FragmentSelectorType</code> for the request is <code>SERVER<em>TIMESTAMP</code>, this value must be in the past.</p> <p>The <code>EndTimestamp</code> value is required for <code>ON</em>DEMAND</code> mode, but optional for <code>LIVE<em>REPLAY</code> mode. If the <code>EndTimestamp</code> is not set for <code>LIVE</em>REPLAY</code> mode then the session will continue to include newly ingested fragments until the session expires.</p> <note> <p>This value is inclusive. The <code>EndTimestamp</code> is compared to the (starting) timestamp of the fragment. Fragments that start before the <code>EndTimestamp</code> value and continue past it are included in the session.</p> </note></p>
     #[serde(rename = "EndTimestamp")]
@@ -225,6 +337,7 @@ pub struct HLSTimestampRange {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFragmentsInput {
     /// <p>Describes the timestamp range and timestamp origin for the range of fragments to return.</p>
     #[serde(rename = "FragmentSelector")]
@@ -243,8 +356,45 @@ pub struct ListFragmentsInput {
     pub stream_name: String,
 }
 
+impl Validate for ListFragmentsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[a-zA-Z0-9+/]+={0,2}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFragmentsOutput {
     /// <p>A list of archived <a>Fragment</a> objects from the stream that meet the selector criteria. Results are in no specific order, even across pages.</p>
     #[serde(rename = "Fragments")]
@@ -258,6 +408,7 @@ pub struct ListFragmentsOutput {
 
 /// <p>The range of timestamps for which to return fragments.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TimestampRange {
     /// <p>The ending timestamp in the range of timestamps for which to return fragments.</p>
     #[serde(rename = "EndTimestamp")]
@@ -334,7 +485,12 @@ impl GetDASHStreamingSessionURLError {
                         GetDASHStreamingSessionURLError::UnsupportedStreamMediaType(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -425,7 +581,12 @@ impl GetHLSStreamingSessionURLError {
                         GetHLSStreamingSessionURLError::UnsupportedStreamMediaType(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -488,7 +649,12 @@ impl GetMediaForFragmentListError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -539,7 +705,12 @@ impl ListFragmentsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListFragmentsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -587,6 +758,85 @@ pub trait KinesisVideoArchivedMedia {
         input: ListFragmentsInput,
     ) -> RusotoFuture<ListFragmentsOutput, ListFragmentsError>;
 }
+const _: Option<&dyn KinesisVideoArchivedMedia> = None;
+impl<'a, T: KinesisVideoArchivedMedia + ?Sized> KinesisVideoArchivedMedia for &'a T {
+    fn get_dash_streaming_session_url(
+        &self,
+        input: GetDASHStreamingSessionURLInput,
+    ) -> RusotoFuture<GetDASHStreamingSessionURLOutput, GetDASHStreamingSessionURLError> {
+        (**self).get_dash_streaming_session_url(input)
+    }
+    fn get_hls_streaming_session_url(
+        &self,
+        input: GetHLSStreamingSessionURLInput,
+    ) -> RusotoFuture<GetHLSStreamingSessionURLOutput, GetHLSStreamingSessionURLError> {
+        (**self).get_hls_streaming_session_url(input)
+    }
+    fn get_media_for_fragment_list(
+        &self,
+        input: GetMediaForFragmentListInput,
+    ) -> RusotoFuture<GetMediaForFragmentListOutput, GetMediaForFragmentListError> {
+        (**self).get_media_for_fragment_list(input)
+    }
+    fn list_fragments(
+        &self,
+        input: ListFragmentsInput,
+    ) -> RusotoFuture<ListFragmentsOutput, ListFragmentsError> {
+        (**self).list_fragments(input)
+    }
+}
+impl<T: KinesisVideoArchivedMedia + ?Sized> KinesisVideoArchivedMedia for Box<T> {
+    fn get_dash_streaming_session_url(
+        &self,
+        input: GetDASHStreamingSessionURLInput,
+    ) -> RusotoFuture<GetDASHStreamingSessionURLOutput, GetDASHStreamingSessionURLError> {
+        (**self).get_dash_streaming_session_url(input)
+    }
+    fn get_hls_streaming_session_url(
+        &self,
+        input: GetHLSStreamingSessionURLInput,
+    ) -> RusotoFuture<GetHLSStreamingSessionURLOutput, GetHLSStreamingSessionURLError> {
+        (**self).get_hls_streaming_session_url(input)
+    }
+    fn get_media_for_fragment_list(
+        &self,
+        input: GetMediaForFragmentListInput,
+    ) -> RusotoFuture<GetMediaForFragmentListOutput, GetMediaForFragmentListError> {
+        (**self).get_media_for_fragment_list(input)
+    }
+    fn list_fragments(
+        &self,
+        input: ListFragmentsInput,
+    ) -> RusotoFuture<ListFragmentsOutput, ListFragmentsError> {
+        (**self).list_fragments(input)
+    }
+}
+impl<T: KinesisVideoArchivedMedia + ?Sized> KinesisVideoArchivedMedia for ::std::sync::Arc<T> {
+    fn get_dash_streaming_session_url(
+        &self,
+        input: GetDASHStreamingSessionURLInput,
+    ) -> RusotoFuture<GetDASHStreamingSessionURLOutput, GetDASHStreamingSessionURLError> {
+        (**self).get_dash_streaming_session_url(input)
+    }
+    fn get_hls_streaming_session_url(
+        &self,
+        input: GetHLSStreamingSessionURLInput,
+    ) -> RusotoFuture<GetHLSStreamingSessionURLOutput, GetHLSStreamingSessionURLError> {
+        (**self).get_hls_streaming_session_url(input)
+    }
+    fn get_media_for_fragment_list(
+        &self,
+        input: GetMediaForFragmentListInput,
+    ) -> RusotoFuture<GetMediaForFragmentListOutput, GetMediaForFragmentListError> {
+        (**self).get_media_for_fragment_list(input)
+    }
+    fn list_fragments(
+        &self,
+        input: ListFragmentsInput,
+    ) -> RusotoFuture<ListFragmentsOutput, ListFragmentsError> {
+        (**self).list_fragments(input)
+    }
+}
 /// A client for the Kinesis Video Archived Media API.
 #[derive(Clone)]
 pub struct KinesisVideoArchivedMediaClient {
@@ -633,9 +883,19 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         &self,
         input: GetDASHStreamingSessionURLInput,
     ) -> RusotoFuture<GetDASHStreamingSessionURLOutput, GetDASHStreamingSessionURLError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/getDASHStreamingSessionURL";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetDASHStreamingSessionURL");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -645,6 +905,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDASHStreamingSessionURL")
                         .deserialize::<GetDASHStreamingSessionURLOutput, _>()?;
 
                     Ok(result)
@@ -662,9 +923,19 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         &self,
         input: GetHLSStreamingSessionURLInput,
     ) -> RusotoFuture<GetHLSStreamingSessionURLOutput, GetHLSStreamingSessionURLError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/getHLSStreamingSessionURL";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetHLSStreamingSessionURL");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -674,6 +945,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetHLSStreamingSessionURL")
                         .deserialize::<GetHLSStreamingSessionURLOutput, _>()?;
 
                     Ok(result)
@@ -691,9 +963,18 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         &self,
         input: GetMediaForFragmentListInput,
     ) -> RusotoFuture<GetMediaForFragmentListOutput, GetMediaForFragmentListError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/getMediaForFragmentList";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetMediaForFragmentList");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -725,9 +1006,19 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         &self,
         input: ListFragmentsInput,
     ) -> RusotoFuture<ListFragmentsOutput, ListFragmentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/listFragments";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListFragments");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -737,6 +1028,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListFragments")
                         .deserialize::<ListFragmentsOutput, _>()?;
 
                     Ok(result)
@@ -752,3 +1044,178 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         })
     }
 }
+
+/// A mock of the Kinesis Video Archived Media API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockKinesisVideoArchivedMedia {
+    get_dash_streaming_session_url: Option<
+        Box<
+            dyn Fn(
+                    GetDASHStreamingSessionURLInput,
+                ) -> ::std::result::Result<
+                    GetDASHStreamingSessionURLOutput,
+                    RusotoError<GetDASHStreamingSessionURLError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_hls_streaming_session_url: Option<
+        Box<
+            dyn Fn(
+                    GetHLSStreamingSessionURLInput,
+                ) -> ::std::result::Result<
+                    GetHLSStreamingSessionURLOutput,
+                    RusotoError<GetHLSStreamingSessionURLError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_media_for_fragment_list: Option<
+        Box<
+            dyn Fn(
+                    GetMediaForFragmentListInput,
+                ) -> ::std::result::Result<
+                    GetMediaForFragmentListOutput,
+                    RusotoError<GetMediaForFragmentListError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_fragments: Option<
+        Box<
+            dyn Fn(
+                    ListFragmentsInput,
+                )
+                    -> ::std::result::Result<ListFragmentsOutput, RusotoError<ListFragmentsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockKinesisVideoArchivedMedia {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockKinesisVideoArchivedMedia {
+        Default::default()
+    }
+
+    /// Answers `get_dash_streaming_session_url` with what `handler` returns for its input.
+    pub fn with_get_dash_streaming_session_url<F>(
+        mut self,
+        handler: F,
+    ) -> MockKinesisVideoArchivedMedia
+    where
+        F: Fn(
+                GetDASHStreamingSessionURLInput,
+            ) -> ::std::result::Result<
+                GetDASHStreamingSessionURLOutput,
+                RusotoError<GetDASHStreamingSessionURLError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_dash_streaming_session_url = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_hls_streaming_session_url` with what `handler` returns for its input.
+    pub fn with_get_hls_streaming_session_url<F>(
+        mut self,
+        handler: F,
+    ) -> MockKinesisVideoArchivedMedia
+    where
+        F: Fn(
+                GetHLSStreamingSessionURLInput,
+            ) -> ::std::result::Result<
+                GetHLSStreamingSessionURLOutput,
+                RusotoError<GetHLSStreamingSessionURLError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_hls_streaming_session_url = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_media_for_fragment_list` with what `handler` returns for its input.
+    pub fn with_get_media_for_fragment_list<F>(
+        mut self,
+        handler: F,
+    ) -> MockKinesisVideoArchivedMedia
+    where
+        F: Fn(
+                GetMediaForFragmentListInput,
+            ) -> ::std::result::Result<
+                GetMediaForFragmentListOutput,
+                RusotoError<GetMediaForFragmentListError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_media_for_fragment_list = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_fragments` with what `handler` returns for its input.
+    pub fn with_list_fragments<F>(mut self, handler: F) -> MockKinesisVideoArchivedMedia
+    where
+        F: Fn(
+                ListFragmentsInput,
+            )
+                -> ::std::result::Result<ListFragmentsOutput, RusotoError<ListFragmentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_fragments = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl KinesisVideoArchivedMedia for MockKinesisVideoArchivedMedia {
+    fn get_dash_streaming_session_url(
+        &self,
+        input: GetDASHStreamingSessionURLInput,
+    ) -> RusotoFuture<GetDASHStreamingSessionURLOutput, GetDASHStreamingSessionURLError> {
+        match self.get_dash_streaming_session_url {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockKinesisVideoArchivedMedia has no handler for get_dash_streaming_session_url"
+            ),
+        }
+    }
+    fn get_hls_streaming_session_url(
+        &self,
+        input: GetHLSStreamingSessionURLInput,
+    ) -> RusotoFuture<GetHLSStreamingSessionURLOutput, GetHLSStreamingSessionURLError> {
+        match self.get_hls_streaming_session_url {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockKinesisVideoArchivedMedia has no handler for get_hls_streaming_session_url"
+            ),
+        }
+    }
+    fn get_media_for_fragment_list(
+        &self,
+        input: GetMediaForFragmentListInput,
+    ) -> RusotoFuture<GetMediaForFragmentListOutput, GetMediaForFragmentListError> {
+        match self.get_media_for_fragment_list {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockKinesisVideoArchivedMedia has no handler for get_media_for_fragment_list"
+            ),
+        }
+    }
+    fn list_fragments(
+        &self,
+        input: ListFragmentsInput,
+    ) -> RusotoFuture<ListFragmentsOutput, ListFragmentsError> {
+        match self.list_fragments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideoArchivedMedia has no handler for list_fragments"),
+        }
+    }
+}
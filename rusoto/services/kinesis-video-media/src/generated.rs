@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMediaInput {
     /// <p>Identifies the starting chunk to get from the specified stream. </p>
     #[serde(rename = "StartSelector")]
@@ -38,6 +40,35 @@ pub struct GetMediaInput {
     pub stream_name: Option<String>,
 }
 
+impl Validate for GetMediaInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.start_selector
+            .validate_at(&field_path(path, "start_selector"), errors);
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:aws:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetMediaOutput {
     /// <p>The content type of the requested media.</p>
@@ -48,6 +79,7 @@ pub struct GetMediaOutput {
 
 /// <p><p>Identifies the chunk on the Kinesis video stream where you want the <code>GetMedia</code> API to start returning media data. You have the following options to identify the starting chunk: </p> <ul> <li> <p>Choose the latest (or oldest) chunk.</p> </li> <li> <p>Identify a specific chunk. You can identify a specific chunk either by providing a fragment number or timestamp (server or producer). </p> </li> <li> <p>Each chunk&#39;s metadata includes a continuation token as a Matroska (MKV) tag (<code>AWS<em>KINESISVIDEO</em>CONTINUATION_TOKEN</code>). If your previous <code>GetMedia</code> request terminated, you can use this tag value in your next <code>GetMedia</code> request. The API then starts returning chunks starting where the last API ended.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartSelector {
     /// <p>Specifies the fragment number from where you want the <code>GetMedia</code> API to start returning the fragments. </p>
     #[serde(rename = "AfterFragmentNumber")]
@@ -66,6 +98,37 @@ pub struct StartSelector {
     pub start_timestamp: Option<f64>,
 }
 
+impl Validate for StartSelector {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.after_fragment_number {
+            errors.check_length(
+                &field_path(path, "after_fragment_number"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "after_fragment_number"),
+                &value,
+                "^[0-9]+$",
+            );
+        }
+        if let Some(ref value) = self.continuation_token {
+            errors.check_length(
+                &field_path(path, "continuation_token"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "continuation_token"),
+                &value,
+                "^[a-zA-Z0-9_\\.\\-]+$",
+            );
+        }
+    }
+}
+
 /// Errors returned by GetMedia
 #[derive(Debug, PartialEq)]
 pub enum GetMediaError {
@@ -105,7 +168,12 @@ impl GetMediaError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetMediaError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -134,6 +202,22 @@ pub trait KinesisVideoMedia {
     /// <p><p> Use this API to retrieve media content from a Kinesis video stream. In the request, you identify the stream name or stream Amazon Resource Name (ARN), and the starting chunk. Kinesis Video Streams then returns a stream of chunks in order by fragment number.</p> <note> <p>You must first call the <code>GetDataEndpoint</code> API to get an endpoint. Then send the <code>GetMedia</code> requests to this endpoint using the <a href="https://docs.aws.amazon.com/cli/latest/reference/">--endpoint-url parameter</a>. </p> </note> <p>When you put media data (fragments) on a stream, Kinesis Video Streams stores each incoming fragment and related metadata in what is called a &quot;chunk.&quot; For more information, see <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_dataplane_PutMedia.html">PutMedia</a>. The <code>GetMedia</code> API returns a stream of these chunks starting from the chunk that you specify in the request. </p> <p>The following limits apply when using the <code>GetMedia</code> API:</p> <ul> <li> <p>A client can call <code>GetMedia</code> up to five times per second per stream. </p> </li> <li> <p>Kinesis Video Streams sends media data at a rate of up to 25 megabytes per second (or 200 megabits per second) during a <code>GetMedia</code> session. </p> </li> </ul> <note> <p>If an error is thrown after invoking a Kinesis Video Streams media API, in addition to the HTTP status code and the response body, it includes the following pieces of information: </p> <ul> <li> <p> <code>x-amz-ErrorType</code> HTTP header – contains a more specific error type in addition to what the HTTP status code provides. </p> </li> <li> <p> <code>x-amz-RequestId</code> HTTP header – if you want to report an issue to AWS, the support team can better diagnose the problem if given the Request Id.</p> </li> </ul> <p>Both the HTTP status code and the ErrorType header can be utilized to make programmatic decisions about whether errors are retry-able and under what conditions, as well as provide information on what actions the client programmer might need to take in order to successfully try again.</p> <p>For more information, see the <b>Errors</b> section at the bottom of this topic, as well as <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/CommonErrors.html">Common Errors</a>. </p> </note></p>
     fn get_media(&self, input: GetMediaInput) -> RusotoFuture<GetMediaOutput, GetMediaError>;
 }
+const _: Option<&dyn KinesisVideoMedia> = None;
+impl<'a, T: KinesisVideoMedia + ?Sized> KinesisVideoMedia for &'a T {
+    fn get_media(&self, input: GetMediaInput) -> RusotoFuture<GetMediaOutput, GetMediaError> {
+        (**self).get_media(input)
+    }
+}
+impl<T: KinesisVideoMedia + ?Sized> KinesisVideoMedia for Box<T> {
+    fn get_media(&self, input: GetMediaInput) -> RusotoFuture<GetMediaOutput, GetMediaError> {
+        (**self).get_media(input)
+    }
+}
+impl<T: KinesisVideoMedia + ?Sized> KinesisVideoMedia for ::std::sync::Arc<T> {
+    fn get_media(&self, input: GetMediaInput) -> RusotoFuture<GetMediaOutput, GetMediaError> {
+        (**self).get_media(input)
+    }
+}
 /// A client for the Kinesis Video Media API.
 #[derive(Clone)]
 pub struct KinesisVideoMediaClient {
@@ -174,9 +258,18 @@ impl KinesisVideoMediaClient {
 impl KinesisVideoMedia for KinesisVideoMediaClient {
     /// <p><p> Use this API to retrieve media content from a Kinesis video stream. In the request, you identify the stream name or stream Amazon Resource Name (ARN), and the starting chunk. Kinesis Video Streams then returns a stream of chunks in order by fragment number.</p> <note> <p>You must first call the <code>GetDataEndpoint</code> API to get an endpoint. Then send the <code>GetMedia</code> requests to this endpoint using the <a href="https://docs.aws.amazon.com/cli/latest/reference/">--endpoint-url parameter</a>. </p> </note> <p>When you put media data (fragments) on a stream, Kinesis Video Streams stores each incoming fragment and related metadata in what is called a &quot;chunk.&quot; For more information, see <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_dataplane_PutMedia.html">PutMedia</a>. The <code>GetMedia</code> API returns a stream of these chunks starting from the chunk that you specify in the request. </p> <p>The following limits apply when using the <code>GetMedia</code> API:</p> <ul> <li> <p>A client can call <code>GetMedia</code> up to five times per second per stream. </p> </li> <li> <p>Kinesis Video Streams sends media data at a rate of up to 25 megabytes per second (or 200 megabits per second) during a <code>GetMedia</code> session. </p> </li> </ul> <note> <p>If an error is thrown after invoking a Kinesis Video Streams media API, in addition to the HTTP status code and the response body, it includes the following pieces of information: </p> <ul> <li> <p> <code>x-amz-ErrorType</code> HTTP header – contains a more specific error type in addition to what the HTTP status code provides. </p> </li> <li> <p> <code>x-amz-RequestId</code> HTTP header – if you want to report an issue to AWS, the support team can better diagnose the problem if given the Request Id.</p> </li> </ul> <p>Both the HTTP status code and the ErrorType header can be utilized to make programmatic decisions about whether errors are retry-able and under what conditions, as well as provide information on what actions the client programmer might need to take in order to successfully try again.</p> <p>For more information, see the <b>Errors</b> section at the bottom of this topic, as well as <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/CommonErrors.html">Common Errors</a>. </p> </note></p>
     fn get_media(&self, input: GetMediaInput) -> RusotoFuture<GetMediaOutput, GetMediaError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/getMedia";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetMedia");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -206,3 +299,52 @@ impl KinesisVideoMedia for KinesisVideoMediaClient {
         })
     }
 }
+
+/// A mock of the Kinesis Video Media API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockKinesisVideoMedia {
+    get_media: Option<
+        Box<
+            dyn Fn(
+                    GetMediaInput,
+                )
+                    -> ::std::result::Result<GetMediaOutput, RusotoError<GetMediaError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockKinesisVideoMedia {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockKinesisVideoMedia {
+        Default::default()
+    }
+
+    /// Answers `get_media` with what `handler` returns for its input.
+    pub fn with_get_media<F>(mut self, handler: F) -> MockKinesisVideoMedia
+    where
+        F: Fn(GetMediaInput) -> ::std::result::Result<GetMediaOutput, RusotoError<GetMediaError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_media = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl KinesisVideoMedia for MockKinesisVideoMedia {
+    fn get_media(&self, input: GetMediaInput) -> RusotoFuture<GetMediaOutput, GetMediaError> {
+        match self.get_media {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideoMedia has no handler for get_media"),
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! Aggregation of many small user records into Kinesis records in the format of the
+//! [Kinesis Producer Library](https://github.com/awslabs/amazon-kinesis-producer/blob/master/aggregation-format.md).
+//!
+//! An aggregated record starts with the magic bytes `F3 89 9A C2`, followed by a
+//! protobuf `AggregatedRecord` message and the MD5 checksum of that message. The
+//! Kinesis Client Library de-aggregates such records transparently, and `deaggregate`
+//! does the same for records fetched with `get_records`.
+
+use std::collections::HashMap;
+
+use bytes::Bytes;
+
+use crate::generated::{PutRecordsRequestEntry, Record};
+
+/// The bytes every aggregated record starts with.
+pub const MAGIC: [u8; 4] = [0xF3, 0x89, 0x9A, 0xC2];
+
+/// The largest Kinesis record, counting both its data and its partition key.
+pub const MAX_RECORD_SIZE: usize = 1024 * 1024;
+
+const CHECKSUM_SIZE: usize = 16;
+
+// field numbers of the AggregatedRecord message
+const PARTITION_KEY_TABLE: u64 = 1;
+const EXPLICIT_HASH_KEY_TABLE: u64 = 2;
+const RECORDS: u64 = 3;
+
+// field numbers of the Record message
+const PARTITION_KEY_INDEX: u64 = 1;
+const EXPLICIT_HASH_KEY_INDEX: u64 = 2;
+const DATA: u64 = 3;
+
+const VARINT: u64 = 0;
+const FIXED_64: u64 = 1;
+const LENGTH_DELIMITED: u64 = 2;
+const FIXED_32: u64 = 5;
+
+/// A record put by a producer, possibly one of many in an aggregated Kinesis record.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserRecord {
+    /// The partition key the producer put the record with.
+    pub partition_key: String,
+    /// The explicit hash key the producer put the record with, if any.
+    pub explicit_hash_key: Option<String>,
+    /// The data of the record.
+    pub data: Bytes,
+    /// The sequence number of the Kinesis record containing this record.
+    pub sequence_number: String,
+    /// The position of this record within the Kinesis record containing it.
+    pub sub_sequence_number: u64,
+    /// Whether this record was part of an aggregated Kinesis record.
+    pub aggregated: bool,
+}
+
+/// Aggregates `(partition key, data)` pairs into as few `put_records` entries as possible.
+///
+/// Records are packed in order, and a new entry is started whenever the next record
+/// would take the current one over `MAX_RECORD_SIZE`. Each entry is put with the
+/// partition key of its first record and the explicit hash key Kinesis derives from
+/// that partition key, so that an entry lands on the shard its first record would have.
+/// A single record too large to fit on its own still gets its own entry, which Kinesis
+/// will reject.
+pub fn aggregate_records(records: Vec<(String, Bytes)>) -> Vec<PutRecordsRequestEntry> {
+    let mut entries = Vec::new();
+    let mut aggregate = Aggregate::default();
+    for (partition_key, data) in records {
+        if !aggregate.fits(&partition_key, &data) {
+            entries.push(aggregate.into_entry());
+            aggregate = Aggregate::default();
+        }
+        aggregate.add(partition_key, data);
+    }
+    if !aggregate.records.is_empty() {
+        entries.push(aggregate.into_entry());
+    }
+    entries
+}
+
+/// Splits a record fetched from Kinesis into the user records it aggregates.
+///
+/// Records which aren't aggregated, or whose checksum doesn't match, are returned as
+/// a single user record.
+pub fn deaggregate(record: Record) -> Vec<UserRecord> {
+    match decode(&record.data) {
+        Some(Decoded {
+            partition_keys,
+            explicit_hash_keys,
+            records,
+        }) => records
+            .into_iter()
+            .enumerate()
+            .map(|(i, (key_index, hash_key_index, data))| UserRecord {
+                partition_key: partition_keys[key_index].clone(),
+                explicit_hash_key: hash_key_index.map(|index| explicit_hash_keys[index].clone()),
+                data,
+                sequence_number: record.sequence_number.clone(),
+                sub_sequence_number: i as u64,
+                aggregated: true,
+            })
+            .collect(),
+        None => vec![UserRecord {
+            partition_key: record.partition_key,
+            explicit_hash_key: None,
+            data: record.data,
+            sequence_number: record.sequence_number,
+            sub_sequence_number: 0,
+            aggregated: false,
+        }],
+    }
+}
+
+/// The explicit hash key Kinesis derives from a partition key: the MD5 digest of the key
+/// read as a big-endian 128-bit integer.
+pub fn explicit_hash_key(partition_key: &str) -> String {
+    u128::from_be_bytes(md5::compute(partition_key.as_bytes()).0).to_string()
+}
+
+#[derive(Default)]
+struct Aggregate {
+    partition_keys: Vec<String>,
+    key_indices: HashMap<String, u64>,
+    records: Vec<(u64, Bytes)>,
+    // encoded size of the AggregatedRecord message
+    size: usize,
+}
+
+impl Aggregate {
+    fn fits(&self, partition_key: &str, data: &[u8]) -> bool {
+        match self.partition_keys.first() {
+            None => true,
+            Some(first_key) => {
+                let size = self.size + self.added_size(partition_key, data);
+                MAGIC.len() + size + CHECKSUM_SIZE + first_key.len() <= MAX_RECORD_SIZE
+            }
+        }
+    }
+
+    fn added_size(&self, partition_key: &str, data: &[u8]) -> usize {
+        let (key_size, index) = match self.key_indices.get(partition_key) {
+            Some(&index) => (0, index),
+            None => (
+                field_size(partition_key.len()),
+                self.partition_keys.len() as u64,
+            ),
+        };
+        key_size + field_size(record_size(index, data.len()))
+    }
+
+    fn add(&mut self, partition_key: String, data: Bytes) {
+        self.size += self.added_size(&partition_key, &data);
+        let next_index = self.partition_keys.len() as u64;
+        let index = *self
+            .key_indices
+            .entry(partition_key.clone())
+            .or_insert(next_index);
+        if index == next_index {
+            self.partition_keys.push(partition_key);
+        }
+        self.records.push((index, data));
+    }
+
+    fn into_entry(self) -> PutRecordsRequestEntry {
+        let mut message = Vec::with_capacity(self.size);
+        for key in &self.partition_keys {
+            put_bytes(&mut message, PARTITION_KEY_TABLE, key.as_bytes());
+        }
+        for (index, data) in &self.records {
+            put_key(&mut message, RECORDS, LENGTH_DELIMITED);
+            put_varint(&mut message, record_size(*index, data.len()) as u64);
+            put_key(&mut message, PARTITION_KEY_INDEX, VARINT);
+            put_varint(&mut message, *index);
+            put_bytes(&mut message, DATA, data);
+        }
+        debug_assert_eq!(message.len(), self.size);
+
+        let mut data = Vec::with_capacity(MAGIC.len() + message.len() + CHECKSUM_SIZE);
+        data.extend_from_slice(&MAGIC);
+        data.extend_from_slice(&message);
+        data.extend_from_slice(&md5::compute(&message).0);
+
+        let partition_key = self.partition_keys.into_iter().next().unwrap_or_default();
+        PutRecordsRequestEntry {
+            data: data.into(),
+            explicit_hash_key: Some(explicit_hash_key(&partition_key)),
+            partition_key,
+        }
+    }
+}
+
+fn varint_size(mut value: u64) -> usize {
+    let mut size = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        size += 1;
+    }
+    size
+}
+
+// size of a length-delimited field with a single byte key
+fn field_size(len: usize) -> usize {
+    1 + varint_size(len as u64) + len
+}
+
+fn record_size(index: u64, data_len: usize) -> usize {
+    1 + varint_size(index) + field_size(data_len)
+}
+
+fn put_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn put_key(buf: &mut Vec<u8>, field: u64, wire_type: u64) {
+    put_varint(buf, field << 3 | wire_type);
+}
+
+fn put_bytes(buf: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    put_key(buf, field, LENGTH_DELIMITED);
+    put_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+struct Decoded {
+    partition_keys: Vec<String>,
+    explicit_hash_keys: Vec<String>,
+    // partition key index, explicit hash key index and data of each record
+    records: Vec<(usize, Option<usize>, Bytes)>,
+}
+
+fn decode(data: &Bytes) -> Option<Decoded> {
+    if data.len() < MAGIC.len() + CHECKSUM_SIZE || data[..MAGIC.len()] != MAGIC {
+        return None;
+    }
+    let checksum_start = data.len() - CHECKSUM_SIZE;
+    let message = data.slice(MAGIC.len(), checksum_start);
+    if md5::compute(&message).0[..] != data[checksum_start..] {
+        return None;
+    }
+
+    let mut decoded = Decoded {
+        partition_keys: Vec::new(),
+        explicit_hash_keys: Vec::new(),
+        records: Vec::new(),
+    };
+    let mut reader = Reader::new(message);
+    while let Some((field, value)) = reader.next_field()? {
+        match (field, value) {
+            (PARTITION_KEY_TABLE, Value::Bytes(key)) => decoded
+                .partition_keys
+                .push(String::from_utf8(key.to_vec()).ok()?),
+            (EXPLICIT_HASH_KEY_TABLE, Value::Bytes(key)) => decoded
+                .explicit_hash_keys
+                .push(String::from_utf8(key.to_vec()).ok()?),
+            (RECORDS, Value::Bytes(record)) => decoded.records.push(decode_record(record)?),
+            _ => {}
+        }
+    }
+
+    // every index must point into its table
+    let valid = decoded
+        .records
+        .iter()
+        .all(|&(key_index, hash_key_index, _)| {
+            key_index < decoded.partition_keys.len()
+                && hash_key_index
+                    .iter()
+                    .all(|index| *index < decoded.explicit_hash_keys.len())
+        });
+    if valid {
+        Some(decoded)
+    } else {
+        None
+    }
+}
+
+fn decode_record(record: Bytes) -> Option<(usize, Option<usize>, Bytes)> {
+    let mut key_index = None;
+    let mut hash_key_index = None;
+    let mut data = None;
+    let mut reader = Reader::new(record);
+    while let Some((field, value)) = reader.next_field()? {
+        match (field, value) {
+            (PARTITION_KEY_INDEX, Value::Varint(index)) => key_index = Some(index as usize),
+            (EXPLICIT_HASH_KEY_INDEX, Value::Varint(index)) => {
+                hash_key_index = Some(index as usize)
+            }
+            (DATA, Value::Bytes(bytes)) => data = Some(bytes),
+            _ => {}
+        }
+    }
+    Some((key_index?, hash_key_index, data?))
+}
+
+enum Value {
+    Varint(u64),
+    Bytes(Bytes),
+    Fixed,
+}
+
+struct Reader {
+    buf: Bytes,
+    pos: usize,
+}
+
+impl Reader {
+    fn new(buf: Bytes) -> Self {
+        Reader { buf, pos: 0 }
+    }
+
+    /// The next field, `Some(None)` at the end of the message and `None` if it is malformed.
+    fn next_field(&mut self) -> Option<Option<(u64, Value)>> {
+        if self.pos == self.buf.len() {
+            return Some(None);
+        }
+        let key = self.varint()?;
+        let value = match key & 0x7 {
+            VARINT => Value::Varint(self.varint()?),
+            LENGTH_DELIMITED => {
+                let len = self.varint()? as usize;
+                let end = self
+                    .pos
+                    .checked_add(len)
+                    .filter(|end| *end <= self.buf.len())?;
+                let bytes = self.buf.slice(self.pos, end);
+                self.pos = end;
+                Value::Bytes(bytes)
+            }
+            FIXED_64 => self.skip(8)?,
+            FIXED_32 => self.skip(4)?,
+            _ => return None,
+        };
+        Some(Some((key >> 3, value)))
+    }
+
+    fn varint(&mut self) -> Option<u64> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.buf.get(self.pos)?;
+            self.pos += 1;
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn skip(&mut self, len: usize) -> Option<Value> {
+        if self.buf.len() - self.pos < len {
+            return None;
+        }
+        self.pos += len;
+        Some(Value::Fixed)
+    }
+}
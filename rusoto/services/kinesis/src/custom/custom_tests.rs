@@ -0,0 +1,178 @@
+use bytes::Bytes;
+
+use crate::aggregation::{
+    aggregate_records, deaggregate, explicit_hash_key, UserRecord, MAGIC, MAX_RECORD_SIZE,
+};
+use crate::generated::{PutRecordsRequestEntry, Record};
+
+// Records "hello" and "!" with partition key "a" and "world" with partition key "b" and an
+// explicit hash key, encoded following the KPL aggregation format documentation.
+const AGGREGATED_FIXTURE: [u8; 98] = [
+    0xF3, 0x89, 0x9A, 0xC2, 0x0A, 0x01, 0x61, 0x0A, 0x01, 0x62, 0x12, 0x27, 0x31, 0x37, 0x30, 0x31,
+    0x34, 0x31, 0x31, 0x38, 0x33, 0x34, 0x36, 0x30, 0x34, 0x36, 0x39, 0x32, 0x33, 0x31, 0x37, 0x33,
+    0x31, 0x36, 0x38, 0x37, 0x33, 0x30, 0x33, 0x37, 0x31, 0x35, 0x38, 0x38, 0x34, 0x31, 0x30, 0x35,
+    0x37, 0x32, 0x38, 0x1A, 0x09, 0x08, 0x00, 0x1A, 0x05, 0x68, 0x65, 0x6C, 0x6C, 0x6F, 0x1A, 0x0B,
+    0x08, 0x01, 0x10, 0x00, 0x1A, 0x05, 0x77, 0x6F, 0x72, 0x6C, 0x64, 0x1A, 0x05, 0x08, 0x00, 0x1A,
+    0x01, 0x21, 0x90, 0x6D, 0x9A, 0x69, 0xBF, 0x2A, 0xC7, 0x33, 0xB1, 0x48, 0x3E, 0x5C, 0xEC, 0x97,
+    0x43, 0x19,
+];
+
+fn fetched(entry: PutRecordsRequestEntry) -> Record {
+    Record {
+        data: entry.data,
+        partition_key: entry.partition_key,
+        sequence_number: "49590338271490256608559692538361571095921575989136588898".to_owned(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn aggregated_records_round_trip() {
+    let records = vec![
+        ("a".to_owned(), Bytes::from_static(b"hello")),
+        ("b".to_owned(), Bytes::from_static(b"world")),
+        ("a".to_owned(), Bytes::from_static(b"!")),
+    ];
+
+    let entries = aggregate_records(records.clone());
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].partition_key, "a");
+    assert_eq!(
+        entries[0].explicit_hash_key,
+        Some("16955237001963240173058271559858726497".to_owned())
+    );
+    assert_eq!(entries[0].data[..4], MAGIC);
+
+    let user_records = deaggregate(fetched(entries.into_iter().next().unwrap()));
+    let round_tripped: Vec<(String, Bytes)> = user_records
+        .iter()
+        .map(|r| (r.partition_key.clone(), r.data.clone()))
+        .collect();
+    assert_eq!(round_tripped, records);
+    let sub_sequence_numbers: Vec<u64> =
+        user_records.iter().map(|r| r.sub_sequence_number).collect();
+    assert_eq!(sub_sequence_numbers, vec![0, 1, 2]);
+    assert!(user_records.iter().all(|r| r.aggregated));
+}
+
+#[test]
+fn aggregation_respects_the_record_size_limit() {
+    let data = Bytes::from(vec![0u8; 300 * 1024]);
+    let records = (0..10)
+        .map(|i| (format!("key-{}", i), data.clone()))
+        .collect::<Vec<_>>();
+
+    let entries = aggregate_records(records);
+    assert_eq!(entries.len(), 4);
+    for entry in &entries {
+        assert!(entry.data.len() + entry.partition_key.len() <= MAX_RECORD_SIZE);
+        assert_eq!(
+            entry.explicit_hash_key,
+            Some(explicit_hash_key(&entry.partition_key))
+        );
+    }
+    let partition_keys: Vec<&str> = entries.iter().map(|e| e.partition_key.as_str()).collect();
+    assert_eq!(partition_keys, vec!["key-0", "key-3", "key-6", "key-9"]);
+
+    let count: usize = entries
+        .into_iter()
+        .map(|entry| deaggregate(fetched(entry)).len())
+        .sum();
+    assert_eq!(count, 10);
+}
+
+#[test]
+fn deaggregates_fixture() {
+    let record = Record {
+        data: Bytes::from(&AGGREGATED_FIXTURE[..]),
+        partition_key: "a".to_owned(),
+        sequence_number: "1".to_owned(),
+        ..Default::default()
+    };
+
+    let user_records = deaggregate(record);
+    assert_eq!(
+        user_records,
+        vec![
+            UserRecord {
+                partition_key: "a".to_owned(),
+                explicit_hash_key: None,
+                data: Bytes::from_static(b"hello"),
+                sequence_number: "1".to_owned(),
+                sub_sequence_number: 0,
+                aggregated: true,
+            },
+            UserRecord {
+                partition_key: "b".to_owned(),
+                explicit_hash_key: Some("170141183460469231731687303715884105728".to_owned()),
+                data: Bytes::from_static(b"world"),
+                sequence_number: "1".to_owned(),
+                sub_sequence_number: 1,
+                aggregated: true,
+            },
+            UserRecord {
+                partition_key: "a".to_owned(),
+                explicit_hash_key: None,
+                data: Bytes::from_static(b"!"),
+                sequence_number: "1".to_owned(),
+                sub_sequence_number: 2,
+                aggregated: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn aggregation_matches_fixture_encoding() {
+    let entries = aggregate_records(vec![
+        ("a".to_owned(), Bytes::from_static(b"hello")),
+        ("a".to_owned(), Bytes::from_static(b"!")),
+    ]);
+    // the fixture without its explicit hash key table and second record
+    let expected_message: &[u8] = &[
+        0x0A, 0x01, 0x61, 0x1A, 0x09, 0x08, 0x00, 0x1A, 0x05, 0x68, 0x65, 0x6C, 0x6C, 0x6F, 0x1A,
+        0x05, 0x08, 0x00, 0x1A, 0x01, 0x21,
+    ];
+    let data = &entries[0].data;
+    assert_eq!(&data[4..data.len() - 16], expected_message);
+}
+
+#[test]
+fn records_with_a_bad_checksum_are_not_deaggregated() {
+    let mut data = AGGREGATED_FIXTURE.to_vec();
+    let last = data.len() - 1;
+    data[last] ^= 0xFF;
+    let record = Record {
+        data: Bytes::from(data.clone()),
+        partition_key: "a".to_owned(),
+        sequence_number: "1".to_owned(),
+        ..Default::default()
+    };
+
+    let user_records = deaggregate(record);
+    assert_eq!(user_records.len(), 1);
+    assert_eq!(user_records[0].data, Bytes::from(data));
+    assert!(!user_records[0].aggregated);
+}
+
+#[test]
+fn plain_records_are_returned_as_is() {
+    let record = Record {
+        data: Bytes::from_static(b"plain"),
+        partition_key: "key".to_owned(),
+        sequence_number: "7".to_owned(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        deaggregate(record),
+        vec![UserRecord {
+            partition_key: "key".to_owned(),
+            explicit_hash_key: None,
+            data: Bytes::from_static(b"plain"),
+            sequence_number: "7".to_owned(),
+            sub_sequence_number: 0,
+            aggregated: false,
+        }]
+    );
+}
@@ -1 +1,5 @@
+/// Aggregation of user records in the format of the Kinesis Producer Library
+pub mod aggregation;
 
+#[cfg(test)]
+mod custom_tests;